@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AccountBrands {
     pub client: Client,
@@ -24,23 +22,21 @@ impl AccountBrands {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `exclude_distributor_brand: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_logos: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &AccountBrandsBrandsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn brands_get(
+    pub async fn brands_get_with(
         &self,
         account_id: &str,
-        exclude_distributor_brand: &str,
-        include_logos: &str,
+        params: &AccountBrandsBrandsGetParams,
     ) -> Result<crate::types::AccountBrands> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_distributor_brand.is_empty() {
+        if let Some(exclude_distributor_brand) = &params.exclude_distributor_brand {
             query_args.push((
                 "exclude_distributor_brand".to_string(),
                 exclude_distributor_brand.to_string(),
             ));
         }
-        if !include_logos.is_empty() {
+        if let Some(include_logos) = &params.include_logos {
             query_args.push(("include_logos".to_string(), include_logos.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -53,6 +49,24 @@ impl AccountBrands {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `brands_get_with` and pass the query parameters as a `AccountBrandsBrandsGetParams` instead.
+     */
+    #[deprecated(note = "use `brands_get_with` instead")]
+    pub async fn brands_get(
+        &self,
+        account_id: &str,
+        exclude_distributor_brand: &str,
+        include_logos: &str,
+    ) -> Result<crate::types::AccountBrands> {
+        let params = AccountBrandsBrandsGetParams {
+            exclude_distributor_brand: Some(exclude_distributor_brand.to_string())
+                .filter(|s| !s.is_empty()),
+            include_logos: Some(include_logos.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.brands_get_with(account_id, &params).await
+    }
+
     /**
      * Creates one or more brand profiles for an account.
      *
@@ -126,24 +140,22 @@ impl AccountBrands {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `brand_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_external_references: &str` -- When **true**, the landing pages and links associated with the brand are included in the response.
-     * * `include_logos: &str` -- When **true**, the URIs for the logos associated with the brand are included in the response.
+     * * `params: &AccountBrandsBrandGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn brand_get(
+    pub async fn brand_get_with(
         &self,
         account_id: &str,
         brand_id: &str,
-        include_external_references: &str,
-        include_logos: &str,
+        params: &AccountBrandsBrandGetParams,
     ) -> Result<crate::types::Brand> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_external_references.is_empty() {
+        if let Some(include_external_references) = &params.include_external_references {
             query_args.push((
                 "include_external_references".to_string(),
                 include_external_references.to_string(),
             ));
         }
-        if !include_logos.is_empty() {
+        if let Some(include_logos) = &params.include_logos {
             query_args.push(("include_logos".to_string(), include_logos.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -157,6 +169,25 @@ impl AccountBrands {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `brand_get_with` and pass the query parameters as a `AccountBrandsBrandGetParams` instead.
+     */
+    #[deprecated(note = "use `brand_get_with` instead")]
+    pub async fn brand_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        include_external_references: &str,
+        include_logos: &str,
+    ) -> Result<crate::types::Brand> {
+        let params = AccountBrandsBrandGetParams {
+            include_external_references: Some(include_external_references.to_string())
+                .filter(|s| !s.is_empty()),
+            include_logos: Some(include_logos.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.brand_get_with(account_id, brand_id, &params).await
+    }
+
     /**
      * Updates an existing brand.
      *
@@ -256,7 +287,7 @@ impl AccountBrands {
         account_id: &str,
         brand_id: &str,
         logo_type: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
         let url = format!(
             "/v2.1/accounts/{}/brands/{}/logos/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -264,7 +295,38 @@ impl AccountBrands {
             crate::progenitor_support::encode_path(logo_type),
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Gets a brand logo.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/brands/{brandId}/logos/{logoType}` endpoint.
+     *
+     * As opposed to `brand_logo_get`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * This method returns a specific logo that is used in a brand.
+     *
+     * **Note**: Branding for either signing or sending must be enabled for the account (`canSelfBrandSend` , `canSelfBrandSign`, or both of these account settings must be **true**).
+     */
+    pub async fn brand_logo_get_stream(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<crate::DownloadStream> {
+        let url = format!(
+            "/v2.1/accounts/{}/brands/{}/logos/{}",
+            crate::progenitor_support::encode_path(account_id),
+            crate::progenitor_support::encode_path(brand_id),
+            crate::progenitor_support::encode_path(logo_type),
+        );
+
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
     }
 
     /**
@@ -286,7 +348,7 @@ impl AccountBrands {
      * * `brand_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `logo_type: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      */
-    pub async fn brand_logo_put<T: Into<reqwest::Body>>(
+    pub async fn brand_logo_put(
         &self,
         account_id: &str,
         brand_id: &str,
@@ -385,22 +447,20 @@ impl AccountBrands {
      *   - `signing`
      *   - `email`
      *   - `signing_captive`.
-     * * `langcode: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `return_master: &str` -- Specifies which resource file data to return. When **true**, only the master resource file is returned. When **false**, only the elements that you modified are returned.
+     * * `params: &AccountBrandsBrandResourcesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn brand_resources_get(
+    pub async fn brand_resources_get_with(
         &self,
         account_id: &str,
         brand_id: &str,
         resource_content_type: &str,
-        langcode: &str,
-        return_master: &str,
+        params: &AccountBrandsBrandResourcesGetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !langcode.is_empty() {
+        if let Some(langcode) = &params.langcode {
             query_args.push(("langcode".to_string(), langcode.to_string()));
         }
-        if !return_master.is_empty() {
+        if let Some(return_master) = &params.return_master {
             query_args.push(("return_master".to_string(), return_master.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -415,6 +475,26 @@ impl AccountBrands {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `brand_resources_get_with` and pass the query parameters as a `AccountBrandsBrandResourcesGetParams` instead.
+     */
+    #[deprecated(note = "use `brand_resources_get_with` instead")]
+    pub async fn brand_resources_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+        langcode: &str,
+        return_master: &str,
+    ) -> Result<()> {
+        let params = AccountBrandsBrandResourcesGetParams {
+            langcode: Some(langcode.to_string()).filter(|s| !s.is_empty()),
+            return_master: Some(return_master.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.brand_resources_get_with(account_id, brand_id, resource_content_type, &params)
+            .await
+    }
+
     /**
      * Updates a branding resource file.
      *
@@ -459,3 +539,299 @@ impl AccountBrands {
         self.client.put(&url, None).await
     }
 }
+
+/// The query parameters of `AccountBrands::brands_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountBrandsBrandsGetParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub exclude_distributor_brand: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub include_logos: Option<String>,
+}
+
+impl AccountBrandsBrandsGetParams {
+    pub fn exclude_distributor_brand<T: ToString>(mut self, exclude_distributor_brand: T) -> Self {
+        self.exclude_distributor_brand = Some(exclude_distributor_brand.to_string());
+        self
+    }
+
+    pub fn include_logos<T: ToString>(mut self, include_logos: T) -> Self {
+        self.include_logos = Some(include_logos.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AccountBrands::brand_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountBrandsBrandGetParams {
+    /// When **true**, the landing pages and links associated with the brand are included in the response.
+    pub include_external_references: Option<String>,
+    /// When **true**, the URIs for the logos associated with the brand are included in the response.
+    pub include_logos: Option<String>,
+}
+
+impl AccountBrandsBrandGetParams {
+    pub fn include_external_references<T: ToString>(
+        mut self,
+        include_external_references: T,
+    ) -> Self {
+        self.include_external_references = Some(include_external_references.to_string());
+        self
+    }
+
+    pub fn include_logos<T: ToString>(mut self, include_logos: T) -> Self {
+        self.include_logos = Some(include_logos.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AccountBrands::brand_resources_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountBrandsBrandResourcesGetParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub langcode: Option<String>,
+    /// Specifies which resource file data to return. When **true**, only the master resource file is returned. When **false**, only the elements that you modified are returned.
+    pub return_master: Option<String>,
+}
+
+impl AccountBrandsBrandResourcesGetParams {
+    pub fn langcode<T: ToString>(mut self, langcode: T) -> Self {
+        self.langcode = Some(langcode.to_string());
+        self
+    }
+
+    pub fn return_master<T: ToString>(mut self, return_master: T) -> Self {
+        self.return_master = Some(return_master.to_string());
+        self
+    }
+}
+
+/// The functions of [`AccountBrands`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAccountBrandsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccountBrandsApi: Send + Sync {
+    /// See [`AccountBrands::brands_get_with`].
+    async fn brands_get_with(
+        &self,
+        account_id: &str,
+        params: &AccountBrandsBrandsGetParams,
+    ) -> Result<crate::types::AccountBrands>;
+
+    /// See [`AccountBrands::brands_post`].
+    async fn brands_post(
+        &self,
+        account_id: &str,
+        body: &crate::types::Brand,
+    ) -> Result<crate::types::AccountBrands>;
+
+    /// See [`AccountBrands::brands_delete`].
+    async fn brands_delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::AccountBrands>;
+
+    /// See [`AccountBrands::brand_get_with`].
+    async fn brand_get_with(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        params: &AccountBrandsBrandGetParams,
+    ) -> Result<crate::types::Brand>;
+
+    /// See [`AccountBrands::brand_put`].
+    async fn brand_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        body: &crate::types::Brand,
+    ) -> Result<crate::types::Brand>;
+
+    /// See [`AccountBrands::brand_delete`].
+    async fn brand_delete(&self, account_id: &str, brand_id: &str) -> Result<()>;
+
+    /// See [`AccountBrands::brand_export_get_file`].
+    async fn brand_export_get_file(&self, account_id: &str, brand_id: &str) -> Result<()>;
+
+    /// See [`AccountBrands::brand_logo_get`].
+    async fn brand_logo_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<crate::Download>;
+
+    /// See [`AccountBrands::brand_logo_get_stream`].
+    async fn brand_logo_get_stream(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`AccountBrands::brand_logo_put`].
+    async fn brand_logo_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+        body: bytes::Bytes,
+    ) -> Result<()>;
+
+    /// See [`AccountBrands::brand_logo_delete`].
+    async fn brand_logo_delete(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<()>;
+
+    /// See [`AccountBrands::brand_resources_get_list`].
+    async fn brand_resources_get_list(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> Result<crate::types::BrandResourcesList>;
+
+    /// See [`AccountBrands::brand_resources_get_with`].
+    async fn brand_resources_get_with(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+        params: &AccountBrandsBrandResourcesGetParams,
+    ) -> Result<()>;
+
+    /// See [`AccountBrands::brand_resources_put`].
+    async fn brand_resources_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+    ) -> Result<crate::types::BrandResources>;
+}
+
+#[async_trait::async_trait]
+impl AccountBrandsApi for AccountBrands {
+    async fn brands_get_with(
+        &self,
+        account_id: &str,
+        params: &AccountBrandsBrandsGetParams,
+    ) -> Result<crate::types::AccountBrands> {
+        AccountBrands::brands_get_with(self, account_id, params).await
+    }
+
+    async fn brands_post(
+        &self,
+        account_id: &str,
+        body: &crate::types::Brand,
+    ) -> Result<crate::types::AccountBrands> {
+        AccountBrands::brands_post(self, account_id, body).await
+    }
+
+    async fn brands_delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::AccountBrands> {
+        AccountBrands::brands_delete(self, account_id, body).await
+    }
+
+    async fn brand_get_with(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        params: &AccountBrandsBrandGetParams,
+    ) -> Result<crate::types::Brand> {
+        AccountBrands::brand_get_with(self, account_id, brand_id, params).await
+    }
+
+    async fn brand_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        body: &crate::types::Brand,
+    ) -> Result<crate::types::Brand> {
+        AccountBrands::brand_put(self, account_id, brand_id, body).await
+    }
+
+    async fn brand_delete(&self, account_id: &str, brand_id: &str) -> Result<()> {
+        AccountBrands::brand_delete(self, account_id, brand_id).await
+    }
+
+    async fn brand_export_get_file(&self, account_id: &str, brand_id: &str) -> Result<()> {
+        AccountBrands::brand_export_get_file(self, account_id, brand_id).await
+    }
+
+    async fn brand_logo_get(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<crate::Download> {
+        AccountBrands::brand_logo_get(self, account_id, brand_id, logo_type).await
+    }
+
+    async fn brand_logo_get_stream(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<crate::DownloadStream> {
+        AccountBrands::brand_logo_get_stream(self, account_id, brand_id, logo_type).await
+    }
+
+    async fn brand_logo_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+        body: bytes::Bytes,
+    ) -> Result<()> {
+        AccountBrands::brand_logo_put(self, account_id, brand_id, logo_type, body).await
+    }
+
+    async fn brand_logo_delete(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        logo_type: &str,
+    ) -> Result<()> {
+        AccountBrands::brand_logo_delete(self, account_id, brand_id, logo_type).await
+    }
+
+    async fn brand_resources_get_list(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+    ) -> Result<crate::types::BrandResourcesList> {
+        AccountBrands::brand_resources_get_list(self, account_id, brand_id).await
+    }
+
+    async fn brand_resources_get_with(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+        params: &AccountBrandsBrandResourcesGetParams,
+    ) -> Result<()> {
+        AccountBrands::brand_resources_get_with(
+            self,
+            account_id,
+            brand_id,
+            resource_content_type,
+            params,
+        )
+        .await
+    }
+
+    async fn brand_resources_put(
+        &self,
+        account_id: &str,
+        brand_id: &str,
+        resource_content_type: &str,
+    ) -> Result<crate::types::BrandResources> {
+        AccountBrands::brand_resources_put(self, account_id, brand_id, resource_content_type).await
+    }
+}
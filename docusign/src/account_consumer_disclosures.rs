@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AccountConsumerDisclosures {
     pub client: Client,
@@ -26,61 +24,15 @@ impl AccountConsumerDisclosures {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `lang_code: &str` -- (Optional) The code for the signer language version of the disclosure that you want to retrieve. The following languages are supported:
-     *   
-     *   - Arabic (`ar`)
-     *   - Bulgarian (`bg`)
-     *   - Czech (`cs`)
-     *   - Chinese Simplified (`zh_CN`)
-     *   - Chinese Traditional (`zh_TW`)
-     *   - Croatian (`hr`)
-     *   - Danish (`da`)
-     *   - Dutch (`nl`)
-     *   - English US (`en`)
-     *   - English UK (`en_GB`)
-     *   - Estonian (`et`)
-     *   - Farsi (`fa`)
-     *   - Finnish (`fi`)
-     *   - French (`fr`)
-     *   - French Canadian (`fr_CA`)
-     *   - German (`de`)
-     *   - Greek (`el`)
-     *   - Hebrew (`he`)
-     *   - Hindi (`hi`)
-     *   - Hungarian (`hu`)
-     *   - Bahasa Indonesian (`id`)
-     *   - Italian (`it`)
-     *   - Japanese (`ja`)
-     *   - Korean (`ko`)
-     *   - Latvian (`lv`)
-     *   - Lithuanian (`lt`)
-     *   - Bahasa Melayu (`ms`)
-     *   - Norwegian (`no`)
-     *   - Polish (`pl`)
-     *   - Portuguese (`pt`)
-     *   - Portuguese Brazil (`pt_BR`)
-     *   - Romanian (`ro`)
-     *   - Russian (`ru`)
-     *   - Serbian (`sr`)
-     *   - Slovak (`sk`)
-     *   - Slovenian (`sl`)
-     *   - Spanish (`es`)
-     *   - Spanish Latin America (`es_MX`)
-     *   - Swedish (`sv`)
-     *   - Thai (`th`)
-     *   - Turkish (`tr`)
-     *   - Ukrainian (`uk`)
-     *   - Vietnamese (`vi`)
-     *   
-     *   Additionally, you can automatically detect the browser language being used by the viewer and display the disclosure in that language by setting the value to `browser`.
+     * * `params: &AccountConsumerDisclosuresConsumerDisclosureGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn consumer_disclosure_get(
+    pub async fn consumer_disclosure_get_with(
         &self,
         account_id: &str,
-        lang_code: &str,
+        params: &AccountConsumerDisclosuresConsumerDisclosureGetParams,
     ) -> Result<crate::types::AccountConsumerDisclosures> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !lang_code.is_empty() {
+        if let Some(lang_code) = &params.lang_code {
             query_args.push(("langCode".to_string(), lang_code.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -93,6 +45,21 @@ impl AccountConsumerDisclosures {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `consumer_disclosure_get_with` and pass the query parameters as a `AccountConsumerDisclosuresConsumerDisclosureGetParams` instead.
+     */
+    #[deprecated(note = "use `consumer_disclosure_get_with` instead")]
+    pub async fn consumer_disclosure_get(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> Result<crate::types::AccountConsumerDisclosures> {
+        let params = AccountConsumerDisclosuresConsumerDisclosureGetParams {
+            lang_code: Some(lang_code.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.consumer_disclosure_get_with(account_id, &params).await
+    }
+
     /**
      * Gets the Electronic Record and Signature Disclosure for an account.
      *
@@ -275,17 +242,17 @@ impl AccountConsumerDisclosures {
      *   - Vietnamese (`vi`)
      *   
      *   Additionally, you can automatically detect the browser language being used by the viewer and display the disclosure in that language by setting the value to `browser`.
-     * * `include_metadata: &str` -- (Optional) When set to true, the response includes metadata indicating which properties are editable.
+     * * `params: &AccountConsumerDisclosuresConsumerDisclosurePutParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn consumer_disclosure_put(
+    pub async fn consumer_disclosure_put_with(
         &self,
         account_id: &str,
         lang_code: &str,
-        include_metadata: &str,
+        params: &AccountConsumerDisclosuresConsumerDisclosurePutParams,
         body: &crate::types::ConsumerDisclosure,
     ) -> Result<crate::types::ConsumerDisclosure> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_metadata.is_empty() {
+        if let Some(include_metadata) = &params.include_metadata {
             query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -300,4 +267,158 @@ impl AccountConsumerDisclosures {
             .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `consumer_disclosure_put_with` and pass the query parameters as a `AccountConsumerDisclosuresConsumerDisclosurePutParams` instead.
+     */
+    #[deprecated(note = "use `consumer_disclosure_put_with` instead")]
+    pub async fn consumer_disclosure_put(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+        include_metadata: &str,
+        body: &crate::types::ConsumerDisclosure,
+    ) -> Result<crate::types::ConsumerDisclosure> {
+        let params = AccountConsumerDisclosuresConsumerDisclosurePutParams {
+            include_metadata: Some(include_metadata.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.consumer_disclosure_put_with(account_id, lang_code, &params, body)
+            .await
+    }
+}
+
+/// The query parameters of `AccountConsumerDisclosures::consumer_disclosure_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountConsumerDisclosuresConsumerDisclosureGetParams {
+    /// (Optional) The code for the signer language version of the disclosure that you want to retrieve. The following languages are supported:
+    ///
+    /// - Arabic (`ar`)
+    /// - Bulgarian (`bg`)
+    /// - Czech (`cs`)
+    /// - Chinese Simplified (`zh_CN`)
+    /// - Chinese Traditional (`zh_TW`)
+    /// - Croatian (`hr`)
+    /// - Danish (`da`)
+    /// - Dutch (`nl`)
+    /// - English US (`en`)
+    /// - English UK (`en_GB`)
+    /// - Estonian (`et`)
+    /// - Farsi (`fa`)
+    /// - Finnish (`fi`)
+    /// - French (`fr`)
+    /// - French Canadian (`fr_CA`)
+    /// - German (`de`)
+    /// - Greek (`el`)
+    /// - Hebrew (`he`)
+    /// - Hindi (`hi`)
+    /// - Hungarian (`hu`)
+    /// - Bahasa Indonesian (`id`)
+    /// - Italian (`it`)
+    /// - Japanese (`ja`)
+    /// - Korean (`ko`)
+    /// - Latvian (`lv`)
+    /// - Lithuanian (`lt`)
+    /// - Bahasa Melayu (`ms`)
+    /// - Norwegian (`no`)
+    /// - Polish (`pl`)
+    /// - Portuguese (`pt`)
+    /// - Portuguese Brazil (`pt_BR`)
+    /// - Romanian (`ro`)
+    /// - Russian (`ru`)
+    /// - Serbian (`sr`)
+    /// - Slovak (`sk`)
+    /// - Slovenian (`sl`)
+    /// - Spanish (`es`)
+    /// - Spanish Latin America (`es_MX`)
+    /// - Swedish (`sv`)
+    /// - Thai (`th`)
+    /// - Turkish (`tr`)
+    /// - Ukrainian (`uk`)
+    /// - Vietnamese (`vi`)
+    ///
+    ///   Additionally, you can automatically detect the browser language being used by the viewer and display the disclosure in that language by setting the value to `browser`.
+    pub lang_code: Option<String>,
+}
+
+impl AccountConsumerDisclosuresConsumerDisclosureGetParams {
+    pub fn lang_code<T: ToString>(mut self, lang_code: T) -> Self {
+        self.lang_code = Some(lang_code.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AccountConsumerDisclosures::consumer_disclosure_put_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountConsumerDisclosuresConsumerDisclosurePutParams {
+    /// (Optional) When set to true, the response includes metadata indicating which properties are editable.
+    pub include_metadata: Option<String>,
+}
+
+impl AccountConsumerDisclosuresConsumerDisclosurePutParams {
+    pub fn include_metadata<T: ToString>(mut self, include_metadata: T) -> Self {
+        self.include_metadata = Some(include_metadata.to_string());
+        self
+    }
+}
+
+/// The functions of [`AccountConsumerDisclosures`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAccountConsumerDisclosuresApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccountConsumerDisclosuresApi: Send + Sync {
+    /// See [`AccountConsumerDisclosures::consumer_disclosure_get_with`].
+    async fn consumer_disclosure_get_with(
+        &self,
+        account_id: &str,
+        params: &AccountConsumerDisclosuresConsumerDisclosureGetParams,
+    ) -> Result<crate::types::AccountConsumerDisclosures>;
+
+    /// See [`AccountConsumerDisclosures::consumer_disclosure_get_lang_code`].
+    async fn consumer_disclosure_get_lang_code(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> Result<crate::types::AccountConsumerDisclosures>;
+
+    /// See [`AccountConsumerDisclosures::consumer_disclosure_put_with`].
+    async fn consumer_disclosure_put_with(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+        params: &AccountConsumerDisclosuresConsumerDisclosurePutParams,
+        body: &crate::types::ConsumerDisclosure,
+    ) -> Result<crate::types::ConsumerDisclosure>;
+}
+
+#[async_trait::async_trait]
+impl AccountConsumerDisclosuresApi for AccountConsumerDisclosures {
+    async fn consumer_disclosure_get_with(
+        &self,
+        account_id: &str,
+        params: &AccountConsumerDisclosuresConsumerDisclosureGetParams,
+    ) -> Result<crate::types::AccountConsumerDisclosures> {
+        AccountConsumerDisclosures::consumer_disclosure_get_with(self, account_id, params).await
+    }
+
+    async fn consumer_disclosure_get_lang_code(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+    ) -> Result<crate::types::AccountConsumerDisclosures> {
+        AccountConsumerDisclosures::consumer_disclosure_get_lang_code(self, account_id, lang_code)
+            .await
+    }
+
+    async fn consumer_disclosure_put_with(
+        &self,
+        account_id: &str,
+        lang_code: &str,
+        params: &AccountConsumerDisclosuresConsumerDisclosurePutParams,
+        body: &crate::types::ConsumerDisclosure,
+    ) -> Result<crate::types::ConsumerDisclosure> {
+        AccountConsumerDisclosures::consumer_disclosure_put_with(
+            self, account_id, lang_code, params, body,
+        )
+        .await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AccountCustomFields {
     pub client: Client,
@@ -42,16 +40,16 @@ impl AccountCustomFields {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `apply_to_templates: &str` -- (Optional) When set to **true**, the new custom field is applied to all of the templates on the account.
+     * * `params: &AccountCustomFieldsPostParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn post(
+    pub async fn post_with(
         &self,
         account_id: &str,
-        apply_to_templates: &str,
+        params: &AccountCustomFieldsPostParams,
         body: &crate::types::CustomField,
     ) -> Result<crate::types::AccountCustomFields> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !apply_to_templates.is_empty() {
+        if let Some(apply_to_templates) = &params.apply_to_templates {
             query_args.push((
                 "apply_to_templates".to_string(),
                 apply_to_templates.to_string(),
@@ -69,6 +67,22 @@ impl AccountCustomFields {
             .await
     }
 
+    /**
+     * Deprecated: use `post_with` and pass the query parameters as a `AccountCustomFieldsPostParams` instead.
+     */
+    #[deprecated(note = "use `post_with` instead")]
+    pub async fn post(
+        &self,
+        account_id: &str,
+        apply_to_templates: &str,
+        body: &crate::types::CustomField,
+    ) -> Result<crate::types::AccountCustomFields> {
+        let params = AccountCustomFieldsPostParams {
+            apply_to_templates: Some(apply_to_templates.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.post_with(account_id, &params, body).await
+    }
+
     /**
      * Updates an account custom field.
      *
@@ -80,17 +94,17 @@ impl AccountCustomFields {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `custom_field_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `apply_to_templates: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &AccountCustomFieldsPutParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn put(
+    pub async fn put_with(
         &self,
         account_id: &str,
         custom_field_id: &str,
-        apply_to_templates: &str,
+        params: &AccountCustomFieldsPutParams,
         body: &crate::types::CustomField,
     ) -> Result<crate::types::AccountCustomFields> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !apply_to_templates.is_empty() {
+        if let Some(apply_to_templates) = &params.apply_to_templates {
             query_args.push((
                 "apply_to_templates".to_string(),
                 apply_to_templates.to_string(),
@@ -109,6 +123,24 @@ impl AccountCustomFields {
             .await
     }
 
+    /**
+     * Deprecated: use `put_with` and pass the query parameters as a `AccountCustomFieldsPutParams` instead.
+     */
+    #[deprecated(note = "use `put_with` instead")]
+    pub async fn put(
+        &self,
+        account_id: &str,
+        custom_field_id: &str,
+        apply_to_templates: &str,
+        body: &crate::types::CustomField,
+    ) -> Result<crate::types::AccountCustomFields> {
+        let params = AccountCustomFieldsPutParams {
+            apply_to_templates: Some(apply_to_templates.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.put_with(account_id, custom_field_id, &params, body)
+            .await
+    }
+
     /**
      * Deletes an account custom field.
      *
@@ -120,16 +152,16 @@ impl AccountCustomFields {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `custom_field_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `apply_to_templates: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &AccountCustomFieldsDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete(
+    pub async fn delete_with(
         &self,
         account_id: &str,
         custom_field_id: &str,
-        apply_to_templates: &str,
+        params: &AccountCustomFieldsDeleteParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !apply_to_templates.is_empty() {
+        if let Some(apply_to_templates) = &params.apply_to_templates {
             query_args.push((
                 "apply_to_templates".to_string(),
                 apply_to_templates.to_string(),
@@ -145,4 +177,131 @@ impl AccountCustomFields {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Deprecated: use `delete_with` and pass the query parameters as a `AccountCustomFieldsDeleteParams` instead.
+     */
+    #[deprecated(note = "use `delete_with` instead")]
+    pub async fn delete(
+        &self,
+        account_id: &str,
+        custom_field_id: &str,
+        apply_to_templates: &str,
+    ) -> Result<()> {
+        let params = AccountCustomFieldsDeleteParams {
+            apply_to_templates: Some(apply_to_templates.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_with(account_id, custom_field_id, &params).await
+    }
+}
+
+/// The query parameters of `AccountCustomFields::post_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountCustomFieldsPostParams {
+    /// (Optional) When set to **true**, the new custom field is applied to all of the templates on the account.
+    pub apply_to_templates: Option<String>,
+}
+
+impl AccountCustomFieldsPostParams {
+    pub fn apply_to_templates<T: ToString>(mut self, apply_to_templates: T) -> Self {
+        self.apply_to_templates = Some(apply_to_templates.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AccountCustomFields::put_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountCustomFieldsPutParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub apply_to_templates: Option<String>,
+}
+
+impl AccountCustomFieldsPutParams {
+    pub fn apply_to_templates<T: ToString>(mut self, apply_to_templates: T) -> Self {
+        self.apply_to_templates = Some(apply_to_templates.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AccountCustomFields::delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountCustomFieldsDeleteParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub apply_to_templates: Option<String>,
+}
+
+impl AccountCustomFieldsDeleteParams {
+    pub fn apply_to_templates<T: ToString>(mut self, apply_to_templates: T) -> Self {
+        self.apply_to_templates = Some(apply_to_templates.to_string());
+        self
+    }
+}
+
+/// The functions of [`AccountCustomFields`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAccountCustomFieldsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccountCustomFieldsApi: Send + Sync {
+    /// See [`AccountCustomFields::get`].
+    async fn get(&self, account_id: &str) -> Result<crate::types::AccountCustomFields>;
+
+    /// See [`AccountCustomFields::post_with`].
+    async fn post_with(
+        &self,
+        account_id: &str,
+        params: &AccountCustomFieldsPostParams,
+        body: &crate::types::CustomField,
+    ) -> Result<crate::types::AccountCustomFields>;
+
+    /// See [`AccountCustomFields::put_with`].
+    async fn put_with(
+        &self,
+        account_id: &str,
+        custom_field_id: &str,
+        params: &AccountCustomFieldsPutParams,
+        body: &crate::types::CustomField,
+    ) -> Result<crate::types::AccountCustomFields>;
+
+    /// See [`AccountCustomFields::delete_with`].
+    async fn delete_with(
+        &self,
+        account_id: &str,
+        custom_field_id: &str,
+        params: &AccountCustomFieldsDeleteParams,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl AccountCustomFieldsApi for AccountCustomFields {
+    async fn get(&self, account_id: &str) -> Result<crate::types::AccountCustomFields> {
+        AccountCustomFields::get(self, account_id).await
+    }
+
+    async fn post_with(
+        &self,
+        account_id: &str,
+        params: &AccountCustomFieldsPostParams,
+        body: &crate::types::CustomField,
+    ) -> Result<crate::types::AccountCustomFields> {
+        AccountCustomFields::post_with(self, account_id, params, body).await
+    }
+
+    async fn put_with(
+        &self,
+        account_id: &str,
+        custom_field_id: &str,
+        params: &AccountCustomFieldsPutParams,
+        body: &crate::types::CustomField,
+    ) -> Result<crate::types::AccountCustomFields> {
+        AccountCustomFields::put_with(self, account_id, custom_field_id, params, body).await
+    }
+
+    async fn delete_with(
+        &self,
+        account_id: &str,
+        custom_field_id: &str,
+        params: &AccountCustomFieldsDeleteParams,
+    ) -> Result<()> {
+        AccountCustomFields::delete_with(self, account_id, custom_field_id, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AccountPasswordRules {
     pub client: Client,
@@ -72,3 +70,41 @@ impl AccountPasswordRules {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`AccountPasswordRules`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAccountPasswordRulesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccountPasswordRulesApi: Send + Sync {
+    /// See [`AccountPasswordRules::get`].
+    async fn get(&self, account_id: &str) -> Result<crate::types::AccountPasswordRulesData>;
+
+    /// See [`AccountPasswordRules::put`].
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPasswordRulesData,
+    ) -> Result<crate::types::AccountPasswordRulesData>;
+
+    /// See [`AccountPasswordRules::password_rules_get`].
+    async fn password_rules_get(&self) -> Result<crate::types::UserPasswordRules>;
+}
+
+#[async_trait::async_trait]
+impl AccountPasswordRulesApi for AccountPasswordRules {
+    async fn get(&self, account_id: &str) -> Result<crate::types::AccountPasswordRulesData> {
+        AccountPasswordRules::get(self, account_id).await
+    }
+
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountPasswordRulesData,
+    ) -> Result<crate::types::AccountPasswordRulesData> {
+        AccountPasswordRules::put(self, account_id, body).await
+    }
+
+    async fn password_rules_get(&self) -> Result<crate::types::UserPasswordRules> {
+        AccountPasswordRules::password_rules_get(self).await
+    }
+}
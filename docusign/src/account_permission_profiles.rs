@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AccountPermissionProfiles {
     pub client: Client,
@@ -22,23 +20,15 @@ impl AccountPermissionProfiles {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include: &str` -- A comma-separated list of additional properties to return in the response. Valid values are:
-     *   
-     *   - `user_count`: The total number of users associated with the permission profile.
-     *   - `closed_users`: Includes closed users in the `user_count`.
-     *   - `account_management`: The account management settings.
-     *   - `metadata`: Metadata indicating whether the properties associated with the account permission profile are editable.
-     *   
-     *   Example: `user_count,closed_users`
-     *   .
+     * * `params: &AccountPermissionProfilesPermissionProfilesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn permission_profiles_get(
+    pub async fn permission_profiles_get_with(
         &self,
         account_id: &str,
-        include: &str,
+        params: &AccountPermissionProfilesPermissionProfilesGetParams,
     ) -> Result<crate::types::PermissionProfileInformation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -51,6 +41,21 @@ impl AccountPermissionProfiles {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `permission_profiles_get_with` and pass the query parameters as a `AccountPermissionProfilesPermissionProfilesGetParams` instead.
+     */
+    #[deprecated(note = "use `permission_profiles_get_with` instead")]
+    pub async fn permission_profiles_get(
+        &self,
+        account_id: &str,
+        include: &str,
+    ) -> Result<crate::types::PermissionProfileInformation> {
+        let params = AccountPermissionProfilesPermissionProfilesGetParams {
+            include: Some(include.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.permission_profiles_get_with(account_id, &params).await
+    }
+
     /**
      * Creates a new permission profile for an account.
      *
@@ -61,16 +66,16 @@ impl AccountPermissionProfiles {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include: &str` -- A comma-separated list of additional properties to return in the response. The only valid value for this request is `metadata`, which returns metadata indicating whether the properties associated with the account permission profile are editable.
+     * * `params: &AccountPermissionProfilesPermissionProfilesPostParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn permission_profiles_post(
+    pub async fn permission_profiles_post_with(
         &self,
         account_id: &str,
-        include: &str,
+        params: &AccountPermissionProfilesPermissionProfilesPostParams,
         body: &crate::types::PermissionProfile,
     ) -> Result<crate::types::PermissionProfile> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -85,6 +90,23 @@ impl AccountPermissionProfiles {
             .await
     }
 
+    /**
+     * Deprecated: use `permission_profiles_post_with` and pass the query parameters as a `AccountPermissionProfilesPermissionProfilesPostParams` instead.
+     */
+    #[deprecated(note = "use `permission_profiles_post_with` instead")]
+    pub async fn permission_profiles_post(
+        &self,
+        account_id: &str,
+        include: &str,
+        body: &crate::types::PermissionProfile,
+    ) -> Result<crate::types::PermissionProfile> {
+        let params = AccountPermissionProfilesPermissionProfilesPostParams {
+            include: Some(include.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.permission_profiles_post_with(account_id, &params, body)
+            .await
+    }
+
     /**
      * Returns a permission profile for an account.
      *
@@ -102,16 +124,16 @@ impl AccountPermissionProfiles {
      *   - `2301414` (for the `Account Administrator` profile)
      *   
      *   In addition, any custom permission profiles associated with your account will have an automatically generated `permissionProfileId`.
-     * * `include: &str` -- A comma-separated list of additional properties to return in the response. The only valid value for this request is `metadata`, which returns metadata indicating whether the properties associated with the account permission profile are editable.
+     * * `params: &AccountPermissionProfilesPermissionProfilesGetProfileParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn permission_profiles_get_profile(
+    pub async fn permission_profiles_get_profile_with(
         &self,
         account_id: &str,
         permission_profile_id: &str,
-        include: &str,
+        params: &AccountPermissionProfilesPermissionProfilesGetProfileParams,
     ) -> Result<crate::types::PermissionProfile> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -125,6 +147,23 @@ impl AccountPermissionProfiles {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `permission_profiles_get_profile_with` and pass the query parameters as a `AccountPermissionProfilesPermissionProfilesGetProfileParams` instead.
+     */
+    #[deprecated(note = "use `permission_profiles_get_profile_with` instead")]
+    pub async fn permission_profiles_get_profile(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        include: &str,
+    ) -> Result<crate::types::PermissionProfile> {
+        let params = AccountPermissionProfilesPermissionProfilesGetProfileParams {
+            include: Some(include.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.permission_profiles_get_profile_with(account_id, permission_profile_id, &params)
+            .await
+    }
+
     /**
      * Updates a permission profile.
      *
@@ -142,17 +181,17 @@ impl AccountPermissionProfiles {
      *   - `2301414` (for the `Account Administrator` profile)
      *   
      *   In addition, any custom permission profiles associated with your account will have an automatically generated `permissionProfileId`.
-     * * `include: &str` -- A comma-separated list of additional properties to return in the response. The only valid value for this request is `metadata`, which returns metadata indicating whether the properties associated with the account permission profile are editable.
+     * * `params: &AccountPermissionProfilesPermissionProfilesPutParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn permission_profiles_put(
+    pub async fn permission_profiles_put_with(
         &self,
         account_id: &str,
         permission_profile_id: &str,
-        include: &str,
+        params: &AccountPermissionProfilesPermissionProfilesPutParams,
         body: &crate::types::PermissionProfile,
     ) -> Result<crate::types::PermissionProfile> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -168,6 +207,24 @@ impl AccountPermissionProfiles {
             .await
     }
 
+    /**
+     * Deprecated: use `permission_profiles_put_with` and pass the query parameters as a `AccountPermissionProfilesPermissionProfilesPutParams` instead.
+     */
+    #[deprecated(note = "use `permission_profiles_put_with` instead")]
+    pub async fn permission_profiles_put(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        include: &str,
+        body: &crate::types::PermissionProfile,
+    ) -> Result<crate::types::PermissionProfile> {
+        let params = AccountPermissionProfilesPermissionProfilesPutParams {
+            include: Some(include.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.permission_profiles_put_with(account_id, permission_profile_id, &params, body)
+            .await
+    }
+
     /**
      * Deletes a permission profile from an account.
      *
@@ -187,16 +244,16 @@ impl AccountPermissionProfiles {
      *   - `2301414` (for the `Account Administrator` profile)
      *   
      *   In addition, any custom permission profiles associated with your account will have an automatically generated `permissionProfileId`.
-     * * `move_users_to: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &AccountPermissionProfilesPermissionProfilesDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn permission_profiles_delete(
+    pub async fn permission_profiles_delete_with(
         &self,
         account_id: &str,
         permission_profile_id: &str,
-        move_users_to: &str,
+        params: &AccountPermissionProfilesPermissionProfilesDeleteParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !move_users_to.is_empty() {
+        if let Some(move_users_to) = &params.move_users_to {
             query_args.push(("move_users_to".to_string(), move_users_to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -209,4 +266,213 @@ impl AccountPermissionProfiles {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Deprecated: use `permission_profiles_delete_with` and pass the query parameters as a `AccountPermissionProfilesPermissionProfilesDeleteParams` instead.
+     */
+    #[deprecated(note = "use `permission_profiles_delete_with` instead")]
+    pub async fn permission_profiles_delete(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        move_users_to: &str,
+    ) -> Result<()> {
+        let params = AccountPermissionProfilesPermissionProfilesDeleteParams {
+            move_users_to: Some(move_users_to.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.permission_profiles_delete_with(account_id, permission_profile_id, &params)
+            .await
+    }
+}
+
+/// The query parameters of `AccountPermissionProfiles::permission_profiles_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountPermissionProfilesPermissionProfilesGetParams {
+    /// A comma-separated list of additional properties to return in the response. Valid values are:
+    ///
+    /// - `user_count`: The total number of users associated with the permission profile.
+    /// - `closed_users`: Includes closed users in the `user_count`.
+    /// - `account_management`: The account management settings.
+    /// - `metadata`: Metadata indicating whether the properties associated with the account permission profile are editable.
+    ///
+    ///   Example: `user_count,closed_users`
+    ///   .
+    pub include: Option<String>,
+}
+
+impl AccountPermissionProfilesPermissionProfilesGetParams {
+    pub fn include<T: ToString>(mut self, include: T) -> Self {
+        self.include = Some(include.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AccountPermissionProfiles::permission_profiles_post_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountPermissionProfilesPermissionProfilesPostParams {
+    /// A comma-separated list of additional properties to return in the response. The only valid value for this request is `metadata`, which returns metadata indicating whether the properties associated with the account permission profile are editable.
+    pub include: Option<String>,
+}
+
+impl AccountPermissionProfilesPermissionProfilesPostParams {
+    pub fn include<T: ToString>(mut self, include: T) -> Self {
+        self.include = Some(include.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AccountPermissionProfiles::permission_profiles_get_profile_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountPermissionProfilesPermissionProfilesGetProfileParams {
+    /// A comma-separated list of additional properties to return in the response. The only valid value for this request is `metadata`, which returns metadata indicating whether the properties associated with the account permission profile are editable.
+    pub include: Option<String>,
+}
+
+impl AccountPermissionProfilesPermissionProfilesGetProfileParams {
+    pub fn include<T: ToString>(mut self, include: T) -> Self {
+        self.include = Some(include.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AccountPermissionProfiles::permission_profiles_put_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountPermissionProfilesPermissionProfilesPutParams {
+    /// A comma-separated list of additional properties to return in the response. The only valid value for this request is `metadata`, which returns metadata indicating whether the properties associated with the account permission profile are editable.
+    pub include: Option<String>,
+}
+
+impl AccountPermissionProfilesPermissionProfilesPutParams {
+    pub fn include<T: ToString>(mut self, include: T) -> Self {
+        self.include = Some(include.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AccountPermissionProfiles::permission_profiles_delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountPermissionProfilesPermissionProfilesDeleteParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub move_users_to: Option<String>,
+}
+
+impl AccountPermissionProfilesPermissionProfilesDeleteParams {
+    pub fn move_users_to<T: ToString>(mut self, move_users_to: T) -> Self {
+        self.move_users_to = Some(move_users_to.to_string());
+        self
+    }
+}
+
+/// The functions of [`AccountPermissionProfiles`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAccountPermissionProfilesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccountPermissionProfilesApi: Send + Sync {
+    /// See [`AccountPermissionProfiles::permission_profiles_get_with`].
+    async fn permission_profiles_get_with(
+        &self,
+        account_id: &str,
+        params: &AccountPermissionProfilesPermissionProfilesGetParams,
+    ) -> Result<crate::types::PermissionProfileInformation>;
+
+    /// See [`AccountPermissionProfiles::permission_profiles_post_with`].
+    async fn permission_profiles_post_with(
+        &self,
+        account_id: &str,
+        params: &AccountPermissionProfilesPermissionProfilesPostParams,
+        body: &crate::types::PermissionProfile,
+    ) -> Result<crate::types::PermissionProfile>;
+
+    /// See [`AccountPermissionProfiles::permission_profiles_get_profile_with`].
+    async fn permission_profiles_get_profile_with(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        params: &AccountPermissionProfilesPermissionProfilesGetProfileParams,
+    ) -> Result<crate::types::PermissionProfile>;
+
+    /// See [`AccountPermissionProfiles::permission_profiles_put_with`].
+    async fn permission_profiles_put_with(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        params: &AccountPermissionProfilesPermissionProfilesPutParams,
+        body: &crate::types::PermissionProfile,
+    ) -> Result<crate::types::PermissionProfile>;
+
+    /// See [`AccountPermissionProfiles::permission_profiles_delete_with`].
+    async fn permission_profiles_delete_with(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        params: &AccountPermissionProfilesPermissionProfilesDeleteParams,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl AccountPermissionProfilesApi for AccountPermissionProfiles {
+    async fn permission_profiles_get_with(
+        &self,
+        account_id: &str,
+        params: &AccountPermissionProfilesPermissionProfilesGetParams,
+    ) -> Result<crate::types::PermissionProfileInformation> {
+        AccountPermissionProfiles::permission_profiles_get_with(self, account_id, params).await
+    }
+
+    async fn permission_profiles_post_with(
+        &self,
+        account_id: &str,
+        params: &AccountPermissionProfilesPermissionProfilesPostParams,
+        body: &crate::types::PermissionProfile,
+    ) -> Result<crate::types::PermissionProfile> {
+        AccountPermissionProfiles::permission_profiles_post_with(self, account_id, params, body)
+            .await
+    }
+
+    async fn permission_profiles_get_profile_with(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        params: &AccountPermissionProfilesPermissionProfilesGetProfileParams,
+    ) -> Result<crate::types::PermissionProfile> {
+        AccountPermissionProfiles::permission_profiles_get_profile_with(
+            self,
+            account_id,
+            permission_profile_id,
+            params,
+        )
+        .await
+    }
+
+    async fn permission_profiles_put_with(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        params: &AccountPermissionProfilesPermissionProfilesPutParams,
+        body: &crate::types::PermissionProfile,
+    ) -> Result<crate::types::PermissionProfile> {
+        AccountPermissionProfiles::permission_profiles_put_with(
+            self,
+            account_id,
+            permission_profile_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn permission_profiles_delete_with(
+        &self,
+        account_id: &str,
+        permission_profile_id: &str,
+        params: &AccountPermissionProfilesPermissionProfilesDeleteParams,
+    ) -> Result<()> {
+        AccountPermissionProfiles::permission_profiles_delete_with(
+            self,
+            account_id,
+            permission_profile_id,
+            params,
+        )
+        .await
+    }
 }
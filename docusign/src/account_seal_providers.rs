@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AccountSealProviders {
     pub client: Client,
@@ -35,3 +33,25 @@ impl AccountSealProviders {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`AccountSealProviders`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAccountSealProvidersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccountSealProvidersApi: Send + Sync {
+    /// See [`AccountSealProviders::account_signature_providers_get_seal`].
+    async fn account_signature_providers_get_seal(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountSealProviders>;
+}
+
+#[async_trait::async_trait]
+impl AccountSealProvidersApi for AccountSealProviders {
+    async fn account_signature_providers_get_seal(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountSealProviders> {
+        AccountSealProviders::account_signature_providers_get_seal(self, account_id).await
+    }
+}
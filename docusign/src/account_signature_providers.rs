@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AccountSignatureProviders {
     pub client: Client,
@@ -35,3 +33,19 @@ impl AccountSignatureProviders {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`AccountSignatureProviders`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAccountSignatureProvidersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccountSignatureProvidersApi: Send + Sync {
+    /// See [`AccountSignatureProviders::get`].
+    async fn get(&self, account_id: &str) -> Result<crate::types::AccountSignatureProvidersData>;
+}
+
+#[async_trait::async_trait]
+impl AccountSignatureProvidersApi for AccountSignatureProviders {
+    async fn get(&self, account_id: &str) -> Result<crate::types::AccountSignatureProvidersData> {
+        AccountSignatureProviders::get(self, account_id).await
+    }
+}
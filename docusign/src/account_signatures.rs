@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AccountSignatures {
     pub client: Client,
@@ -22,25 +20,21 @@ impl AccountSignatures {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `stamp_format: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `stamp_name: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `stamp_type: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &AccountSignaturesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         account_id: &str,
-        stamp_format: &str,
-        stamp_name: &str,
-        stamp_type: &str,
+        params: &AccountSignaturesGetParams,
     ) -> Result<crate::types::AccountSignaturesInformation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !stamp_format.is_empty() {
+        if let Some(stamp_format) = &params.stamp_format {
             query_args.push(("stamp_format".to_string(), stamp_format.to_string()));
         }
-        if !stamp_name.is_empty() {
+        if let Some(stamp_name) = &params.stamp_name {
             query_args.push(("stamp_name".to_string(), stamp_name.to_string()));
         }
-        if !stamp_type.is_empty() {
+        if let Some(stamp_type) = &params.stamp_type {
             query_args.push(("stamp_type".to_string(), stamp_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -53,6 +47,25 @@ impl AccountSignatures {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `AccountSignaturesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        account_id: &str,
+        stamp_format: &str,
+        stamp_name: &str,
+        stamp_type: &str,
+    ) -> Result<crate::types::AccountSignaturesInformation> {
+        let params = AccountSignaturesGetParams {
+            stamp_format: Some(stamp_format.to_string()).filter(|s| !s.is_empty()),
+            stamp_name: Some(stamp_name.to_string()).filter(|s| !s.is_empty()),
+            stamp_type: Some(stamp_type.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(account_id, &params).await
+    }
+
     /**
     * Updates an account signature.
     .
@@ -91,16 +104,16 @@ impl AccountSignatures {
     * **Parameters:**
     *
     * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-    * * `decode_only: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+    * * `params: &AccountSignaturesPostParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn post(
+    pub async fn post_with(
         &self,
         account_id: &str,
-        decode_only: &str,
+        params: &AccountSignaturesPostParams,
         body: &crate::types::AccountSignaturesInformation,
     ) -> Result<crate::types::AccountSignaturesInformation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !decode_only.is_empty() {
+        if let Some(decode_only) = &params.decode_only {
             query_args.push(("decode_only".to_string(), decode_only.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -115,6 +128,22 @@ impl AccountSignatures {
             .await
     }
 
+    /**
+     * Deprecated: use `post_with` and pass the query parameters as a `AccountSignaturesPostParams` instead.
+     */
+    #[deprecated(note = "use `post_with` instead")]
+    pub async fn post(
+        &self,
+        account_id: &str,
+        decode_only: &str,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> Result<crate::types::AccountSignaturesInformation> {
+        let params = AccountSignaturesPostParams {
+            decode_only: Some(decode_only.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.post_with(account_id, &params, body).await
+    }
+
     /**
      * Returns information about the specified signature.
      *
@@ -152,17 +181,17 @@ impl AccountSignatures {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `signature_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `close_existing_signature: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &AccountSignaturesPutSignatureAccountSignaturesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn put_signature_account_signatures(
+    pub async fn put_signature_account_signatures_with(
         &self,
         account_id: &str,
         signature_id: &str,
-        close_existing_signature: &str,
+        params: &AccountSignaturesPutSignatureAccountSignaturesParams,
         body: &crate::types::AccountSignatureDefinition,
     ) -> Result<crate::types::AccountSignature> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !close_existing_signature.is_empty() {
+        if let Some(close_existing_signature) = &params.close_existing_signature {
             query_args.push((
                 "close_existing_signature".to_string(),
                 close_existing_signature.to_string(),
@@ -181,6 +210,25 @@ impl AccountSignatures {
             .await
     }
 
+    /**
+     * Deprecated: use `put_signature_account_signatures_with` and pass the query parameters as a `AccountSignaturesPutSignatureAccountSignaturesParams` instead.
+     */
+    #[deprecated(note = "use `put_signature_account_signatures_with` instead")]
+    pub async fn put_signature_account_signatures(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        close_existing_signature: &str,
+        body: &crate::types::AccountSignatureDefinition,
+    ) -> Result<crate::types::AccountSignature> {
+        let params = AccountSignaturesPutSignatureAccountSignaturesParams {
+            close_existing_signature: Some(close_existing_signature.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.put_signature_account_signatures_with(account_id, signature_id, &params, body)
+            .await
+    }
+
     /**
      * Close the specified signature by ID.
      *
@@ -218,17 +266,73 @@ impl AccountSignatures {
      *   - `signature_image`
      *   - `initials_image`.
      * * `signature_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_chrome: &str` -- When **true**, the chrome (or frame containing the added line and identifier) is included with the signature image.
+     * * `params: &AccountSignaturesGetSignatureImageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_signature_image_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        params: &AccountSignaturesGetSignatureImageParams,
+    ) -> Result<crate::Download> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(include_chrome) = &params.include_chrome {
+            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/signatures/{}/{}?{}",
+            crate::progenitor_support::encode_path(account_id),
+            crate::progenitor_support::encode_path(signature_id),
+            crate::progenitor_support::encode_path(image_type),
+            query_
+        );
+
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `get_signature_image_with` and pass the query parameters as a `AccountSignaturesGetSignatureImageParams` instead.
      */
+    #[deprecated(note = "use `get_signature_image_with` instead")]
     pub async fn get_signature_image(
         &self,
         account_id: &str,
         image_type: &str,
         signature_id: &str,
         include_chrome: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
+        let params = AccountSignaturesGetSignatureImageParams {
+            include_chrome: Some(include_chrome.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_signature_image_with(account_id, image_type, signature_id, &params)
+            .await
+    }
+
+    /**
+     * Returns a signature image, initials, or stamp.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     * As opposed to `get_signature_image`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     *
+     *
+     * **Parameters:**
+     *
+     * * `params: &AccountSignaturesGetSignatureImageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_signature_image_stream_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        params: &AccountSignaturesGetSignatureImageParams,
+    ) -> Result<crate::DownloadStream> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_chrome.is_empty() {
+        if let Some(include_chrome) = &params.include_chrome {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -240,7 +344,27 @@ impl AccountSignatures {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `get_signature_image_stream_with` and pass the query parameters as a `AccountSignaturesGetSignatureImageParams` instead.
+     */
+    #[deprecated(note = "use `get_signature_image_stream_with` instead")]
+    pub async fn get_signature_image_stream(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        include_chrome: &str,
+    ) -> Result<crate::DownloadStream> {
+        let params = AccountSignaturesGetSignatureImageParams {
+            include_chrome: Some(include_chrome.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_signature_image_stream_with(account_id, image_type, signature_id, &params)
+            .await
     }
 
     /**
@@ -258,17 +382,17 @@ impl AccountSignatures {
      *   - `signature_image`
      *   - `initials_image`.
      * * `signature_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `transparent_png: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &AccountSignaturesPutSignatureImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn put_signature_image(
+    pub async fn put_signature_image_with(
         &self,
         account_id: &str,
         image_type: &str,
         signature_id: &str,
-        transparent_png: &str,
+        params: &AccountSignaturesPutSignatureImageParams,
     ) -> Result<crate::types::AccountSignature> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !transparent_png.is_empty() {
+        if let Some(transparent_png) = &params.transparent_png {
             query_args.push(("transparent_png".to_string(), transparent_png.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -283,6 +407,24 @@ impl AccountSignatures {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `put_signature_image_with` and pass the query parameters as a `AccountSignaturesPutSignatureImageParams` instead.
+     */
+    #[deprecated(note = "use `put_signature_image_with` instead")]
+    pub async fn put_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        transparent_png: &str,
+    ) -> Result<crate::types::AccountSignature> {
+        let params = AccountSignaturesPutSignatureImageParams {
+            transparent_png: Some(transparent_png.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.put_signature_image_with(account_id, image_type, signature_id, &params)
+            .await
+    }
+
     /**
      * Deletes a signature image, initials, or stamp.
      *
@@ -315,3 +457,286 @@ impl AccountSignatures {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `AccountSignatures::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountSignaturesGetParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub stamp_format: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub stamp_name: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub stamp_type: Option<String>,
+}
+
+impl AccountSignaturesGetParams {
+    pub fn stamp_format<T: ToString>(mut self, stamp_format: T) -> Self {
+        self.stamp_format = Some(stamp_format.to_string());
+        self
+    }
+
+    pub fn stamp_name<T: ToString>(mut self, stamp_name: T) -> Self {
+        self.stamp_name = Some(stamp_name.to_string());
+        self
+    }
+
+    pub fn stamp_type<T: ToString>(mut self, stamp_type: T) -> Self {
+        self.stamp_type = Some(stamp_type.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AccountSignatures::post_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountSignaturesPostParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub decode_only: Option<String>,
+}
+
+impl AccountSignaturesPostParams {
+    pub fn decode_only<T: ToString>(mut self, decode_only: T) -> Self {
+        self.decode_only = Some(decode_only.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AccountSignatures::put_signature_account_signatures_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountSignaturesPutSignatureAccountSignaturesParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub close_existing_signature: Option<String>,
+}
+
+impl AccountSignaturesPutSignatureAccountSignaturesParams {
+    pub fn close_existing_signature<T: ToString>(mut self, close_existing_signature: T) -> Self {
+        self.close_existing_signature = Some(close_existing_signature.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AccountSignatures::get_signature_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountSignaturesGetSignatureImageParams {
+    /// When **true**, the chrome (or frame containing the added line and identifier) is included with the signature image.
+    pub include_chrome: Option<String>,
+}
+
+impl AccountSignaturesGetSignatureImageParams {
+    pub fn include_chrome<T: ToString>(mut self, include_chrome: T) -> Self {
+        self.include_chrome = Some(include_chrome.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AccountSignatures::put_signature_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountSignaturesPutSignatureImageParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub transparent_png: Option<String>,
+}
+
+impl AccountSignaturesPutSignatureImageParams {
+    pub fn transparent_png<T: ToString>(mut self, transparent_png: T) -> Self {
+        self.transparent_png = Some(transparent_png.to_string());
+        self
+    }
+}
+
+/// The functions of [`AccountSignatures`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAccountSignaturesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccountSignaturesApi: Send + Sync {
+    /// See [`AccountSignatures::get_with`].
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &AccountSignaturesGetParams,
+    ) -> Result<crate::types::AccountSignaturesInformation>;
+
+    /// See [`AccountSignatures::put_signature`].
+    async fn put_signature(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> Result<crate::types::AccountSignaturesInformation>;
+
+    /// See [`AccountSignatures::post_with`].
+    async fn post_with(
+        &self,
+        account_id: &str,
+        params: &AccountSignaturesPostParams,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> Result<crate::types::AccountSignaturesInformation>;
+
+    /// See [`AccountSignatures::get_signature`].
+    async fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> Result<crate::types::AccountSignature>;
+
+    /// See [`AccountSignatures::put_signature_account_signatures_with`].
+    async fn put_signature_account_signatures_with(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        params: &AccountSignaturesPutSignatureAccountSignaturesParams,
+        body: &crate::types::AccountSignatureDefinition,
+    ) -> Result<crate::types::AccountSignature>;
+
+    /// See [`AccountSignatures::delete_signature`].
+    async fn delete_signature(&self, account_id: &str, signature_id: &str) -> Result<()>;
+
+    /// See [`AccountSignatures::get_signature_image_with`].
+    async fn get_signature_image_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        params: &AccountSignaturesGetSignatureImageParams,
+    ) -> Result<crate::Download>;
+
+    /// See [`AccountSignatures::get_signature_image_stream_with`].
+    async fn get_signature_image_stream_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        params: &AccountSignaturesGetSignatureImageParams,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`AccountSignatures::put_signature_image_with`].
+    async fn put_signature_image_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        params: &AccountSignaturesPutSignatureImageParams,
+    ) -> Result<crate::types::AccountSignature>;
+
+    /// See [`AccountSignatures::delete_signature_image`].
+    async fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+    ) -> Result<crate::types::AccountSignature>;
+}
+
+#[async_trait::async_trait]
+impl AccountSignaturesApi for AccountSignatures {
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &AccountSignaturesGetParams,
+    ) -> Result<crate::types::AccountSignaturesInformation> {
+        AccountSignatures::get_with(self, account_id, params).await
+    }
+
+    async fn put_signature(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> Result<crate::types::AccountSignaturesInformation> {
+        AccountSignatures::put_signature(self, account_id, body).await
+    }
+
+    async fn post_with(
+        &self,
+        account_id: &str,
+        params: &AccountSignaturesPostParams,
+        body: &crate::types::AccountSignaturesInformation,
+    ) -> Result<crate::types::AccountSignaturesInformation> {
+        AccountSignatures::post_with(self, account_id, params, body).await
+    }
+
+    async fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+    ) -> Result<crate::types::AccountSignature> {
+        AccountSignatures::get_signature(self, account_id, signature_id).await
+    }
+
+    async fn put_signature_account_signatures_with(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        params: &AccountSignaturesPutSignatureAccountSignaturesParams,
+        body: &crate::types::AccountSignatureDefinition,
+    ) -> Result<crate::types::AccountSignature> {
+        AccountSignatures::put_signature_account_signatures_with(
+            self,
+            account_id,
+            signature_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn delete_signature(&self, account_id: &str, signature_id: &str) -> Result<()> {
+        AccountSignatures::delete_signature(self, account_id, signature_id).await
+    }
+
+    async fn get_signature_image_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        params: &AccountSignaturesGetSignatureImageParams,
+    ) -> Result<crate::Download> {
+        AccountSignatures::get_signature_image_with(
+            self,
+            account_id,
+            image_type,
+            signature_id,
+            params,
+        )
+        .await
+    }
+
+    async fn get_signature_image_stream_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        params: &AccountSignaturesGetSignatureImageParams,
+    ) -> Result<crate::DownloadStream> {
+        AccountSignatures::get_signature_image_stream_with(
+            self,
+            account_id,
+            image_type,
+            signature_id,
+            params,
+        )
+        .await
+    }
+
+    async fn put_signature_image_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        params: &AccountSignaturesPutSignatureImageParams,
+    ) -> Result<crate::types::AccountSignature> {
+        AccountSignatures::put_signature_image_with(
+            self,
+            account_id,
+            image_type,
+            signature_id,
+            params,
+        )
+        .await
+    }
+
+    async fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+    ) -> Result<crate::types::AccountSignature> {
+        AccountSignatures::delete_signature_image(self, account_id, image_type, signature_id).await
+    }
+}
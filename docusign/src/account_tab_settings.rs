@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AccountTabSettings {
     pub client: Client,
@@ -61,3 +59,34 @@ impl AccountTabSettings {
             .await
     }
 }
+
+/// The functions of [`AccountTabSettings`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAccountTabSettingsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccountTabSettingsApi: Send + Sync {
+    /// See [`AccountTabSettings::tab_settings_get`].
+    async fn tab_settings_get(&self, account_id: &str) -> Result<crate::types::TabAccountSettings>;
+
+    /// See [`AccountTabSettings::tab_settings_put`].
+    async fn tab_settings_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabAccountSettings,
+    ) -> Result<crate::types::TabAccountSettings>;
+}
+
+#[async_trait::async_trait]
+impl AccountTabSettingsApi for AccountTabSettings {
+    async fn tab_settings_get(&self, account_id: &str) -> Result<crate::types::TabAccountSettings> {
+        AccountTabSettings::tab_settings_get(self, account_id).await
+    }
+
+    async fn tab_settings_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabAccountSettings,
+    ) -> Result<crate::types::TabAccountSettings> {
+        AccountTabSettings::tab_settings_put(self, account_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AccountWatermarks {
     pub client: Client,
@@ -84,3 +82,49 @@ impl AccountWatermarks {
             .await
     }
 }
+
+/// The functions of [`AccountWatermarks`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAccountWatermarksApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccountWatermarksApi: Send + Sync {
+    /// See [`AccountWatermarks::watermark_get`].
+    async fn watermark_get(&self, account_id: &str) -> Result<crate::types::Watermark>;
+
+    /// See [`AccountWatermarks::watermark_put`].
+    async fn watermark_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> Result<crate::types::Watermark>;
+
+    /// See [`AccountWatermarks::watermark_preview_put`].
+    async fn watermark_preview_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> Result<crate::types::Watermark>;
+}
+
+#[async_trait::async_trait]
+impl AccountWatermarksApi for AccountWatermarks {
+    async fn watermark_get(&self, account_id: &str) -> Result<crate::types::Watermark> {
+        AccountWatermarks::watermark_get(self, account_id).await
+    }
+
+    async fn watermark_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> Result<crate::types::Watermark> {
+        AccountWatermarks::watermark_put(self, account_id, body).await
+    }
+
+    async fn watermark_preview_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::Watermark,
+    ) -> Result<crate::types::Watermark> {
+        AccountWatermarks::watermark_preview_put(self, account_id, body).await
+    }
+}
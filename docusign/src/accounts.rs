@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Accounts {
     pub client: Client,
@@ -177,16 +175,15 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_account_settings: &str` -- When set to **true**, includes account settings
-     *   in the response. If you omit this parameter, the default behavior is **false**.
+     * * `params: &AccountsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         account_id: &str,
-        include_account_settings: &str,
+        params: &AccountsGetParams,
     ) -> Result<crate::types::AccountInformation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_account_settings.is_empty() {
+        if let Some(include_account_settings) = &params.include_account_settings {
             query_args.push((
                 "include_account_settings".to_string(),
                 include_account_settings.to_string(),
@@ -202,6 +199,22 @@ impl Accounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `AccountsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        account_id: &str,
+        include_account_settings: &str,
+    ) -> Result<crate::types::AccountInformation> {
+        let params = AccountsGetParams {
+            include_account_settings: Some(include_account_settings.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.get_with(account_id, &params).await
+    }
+
     /**
      * Deletes the specified account.
      *
@@ -234,20 +247,15 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_charges: &str` -- Specifies which billing charges to return.
-     *   Valid values are:
-     *   
-     *   * envelopes
-     *   * seats
-     *   .
+     * * `params: &AccountsBillingChargesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn billing_charges_get(
+    pub async fn billing_charges_get_with(
         &self,
         account_id: &str,
-        include_charges: &str,
+        params: &AccountsBillingChargesGetParams,
     ) -> Result<crate::types::BillingChargeResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_charges.is_empty() {
+        if let Some(include_charges) = &params.include_charges {
             query_args.push(("include_charges".to_string(), include_charges.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -260,6 +268,21 @@ impl Accounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `billing_charges_get_with` and pass the query parameters as a `AccountsBillingChargesGetParams` instead.
+     */
+    #[deprecated(note = "use `billing_charges_get_with` instead")]
+    pub async fn billing_charges_get(
+        &self,
+        account_id: &str,
+        include_charges: &str,
+    ) -> Result<crate::types::BillingChargeResponse> {
+        let params = AccountsBillingChargesGetParams {
+            include_charges: Some(include_charges.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.billing_charges_get_with(account_id, &params).await
+    }
+
     /**
      * Deletes the signature for one or more captive recipient records.
      *
@@ -299,15 +322,15 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `email: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &AccountsRecipientNamesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recipient_names_get(
+    pub async fn recipient_names_get_with(
         &self,
         account_id: &str,
-        email: &str,
+        params: &AccountsRecipientNamesGetParams,
     ) -> Result<crate::types::RecipientNamesResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !email.is_empty() {
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -320,6 +343,21 @@ impl Accounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `recipient_names_get_with` and pass the query parameters as a `AccountsRecipientNamesGetParams` instead.
+     */
+    #[deprecated(note = "use `recipient_names_get_with` instead")]
+    pub async fn recipient_names_get(
+        &self,
+        account_id: &str,
+        email: &str,
+    ) -> Result<crate::types::RecipientNamesResponse> {
+        let params = AccountsRecipientNamesGetParams {
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipient_names_get_with(account_id, &params).await
+    }
+
     /**
      * Gets account settings information.
      *
@@ -485,73 +523,39 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- Specifies the maximum number of results included in the response. If no value is specified, this defaults to 1000.
-     * * `envelopes_not_shared_user_status: &str` -- This query parameter works in conjunction with `user_ids`. When you specify one of the following user statuses, the query limits the results to only users that match the specified status:
-     *   - `ActivationRequired`: Membership Activation required
-     *   - `ActivationSent`: Membership activation sent to user
-     *   - `Active`: User Membership is active
-     *   - `Closed`: User Membership is closed
-     *   - `Disabled`: User Membership is disabled.
-     * * `folder_ids: &str` -- A comma-separated list of folder IDs for which to return shared item information. If `item_type` is set to `folders`, at least one folder ID is required.
-     * * `item_type: &str` -- Specifies the type of shared item being requested. The possible values are:
-     *   
-     *   - `envelopes`: Get information about envelope sharing between users.
-     *   - `templates`: Get information about template sharing among users and groups.
-     *   - `folders`: Get information about folder sharing among users and groups.
-     *   .
-     * * `search_text: &str` -- Filter user names based on the specified string. The wild-card '*' (asterisk) can be used in the string.
-     * * `shared: &str` -- A comma-separated list of sharing filters that specifies which users appear in the response.
-     *   
-     *   - `not_shared`: The response lists users who do not share items of `item_type` with the current user.
-     *   
-     *   - `shared_to`: The response lists users in `user_list` who are sharing items to current user.
-     *   
-     *   - `shared_from`: The response lists users in `user_list` who are sharing items from the current user.
-     *   
-     *   - `shared_to_and_from`: The response lists users in `user_list` who are sharing items to and from the current user.
-     *   
-     *   If the current user does not have administrative privileges, only the `shared_to` option is valid.
-     * * `start_position: &str` -- If the number of responses is greater than `count`, this specifies the number of responses to skip. Typically this value is a multiple of `count`. The default is 0.
-     * * `user_ids: &str` -- A comma-separated list of user IDs for whom the shared item information is being requested.
+     * * `params: &AccountsSharedAccessGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn shared_access_get(
+    pub async fn shared_access_get_with(
         &self,
         account_id: &str,
-        count: &str,
-        envelopes_not_shared_user_status: &str,
-        folder_ids: &str,
-        item_type: &str,
-        search_text: &str,
-        shared: &str,
-        start_position: &str,
-        user_ids: &str,
+        params: &AccountsSharedAccessGetParams,
     ) -> Result<crate::types::AccountSharedAccess> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !envelopes_not_shared_user_status.is_empty() {
+        if let Some(envelopes_not_shared_user_status) = &params.envelopes_not_shared_user_status {
             query_args.push((
                 "envelopes_not_shared_user_status".to_string(),
                 envelopes_not_shared_user_status.to_string(),
             ));
         }
-        if !folder_ids.is_empty() {
+        if let Some(folder_ids) = &params.folder_ids {
             query_args.push(("folder_ids".to_string(), folder_ids.to_string()));
         }
-        if !item_type.is_empty() {
+        if let Some(item_type) = &params.item_type {
             query_args.push(("item_type".to_string(), item_type.to_string()));
         }
-        if !search_text.is_empty() {
+        if let Some(search_text) = &params.search_text {
             query_args.push(("search_text".to_string(), search_text.to_string()));
         }
-        if !shared.is_empty() {
+        if let Some(shared) = &params.shared {
             query_args.push(("shared".to_string(), shared.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
-        if !user_ids.is_empty() {
+        if let Some(user_ids) = &params.user_ids {
             query_args.push(("user_ids".to_string(), user_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -564,6 +568,36 @@ impl Accounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `shared_access_get_with` and pass the query parameters as a `AccountsSharedAccessGetParams` instead.
+     */
+    #[deprecated(note = "use `shared_access_get_with` instead")]
+    pub async fn shared_access_get(
+        &self,
+        account_id: &str,
+        count: &str,
+        envelopes_not_shared_user_status: &str,
+        folder_ids: &str,
+        item_type: &str,
+        search_text: &str,
+        shared: &str,
+        start_position: &str,
+        user_ids: &str,
+    ) -> Result<crate::types::AccountSharedAccess> {
+        let params = AccountsSharedAccessGetParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            envelopes_not_shared_user_status: Some(envelopes_not_shared_user_status.to_string())
+                .filter(|s| !s.is_empty()),
+            folder_ids: Some(folder_ids.to_string()).filter(|s| !s.is_empty()),
+            item_type: Some(item_type.to_string()).filter(|s| !s.is_empty()),
+            search_text: Some(search_text.to_string()).filter(|s| !s.is_empty()),
+            shared: Some(shared.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+            user_ids: Some(user_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.shared_access_get_with(account_id, &params).await
+    }
+
     /**
      * Reserved: Sets the shared access information for users.
      *
@@ -586,33 +620,25 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `item_type: &str` -- Specifies the type of shared item being set:
-     *   - `envelopes`: Set envelope sharing between users.
-     *   - `templates`: Set information about template sharing among users and groups.
-     *   - `folders`: Get information about folder sharing among users and groups.
-     *   .
-     * * `preserve_existing_shared_access: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `user_ids: &str` -- A comma-separated list of IDs for users whose shared item access is being set.
+     * * `params: &AccountsSharedAccessPutParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn shared_access_put(
+    pub async fn shared_access_put_with(
         &self,
         account_id: &str,
-        item_type: &str,
-        preserve_existing_shared_access: &str,
-        user_ids: &str,
+        params: &AccountsSharedAccessPutParams,
         body: &crate::types::AccountSharedAccess,
     ) -> Result<crate::types::AccountSharedAccess> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !item_type.is_empty() {
+        if let Some(item_type) = &params.item_type {
             query_args.push(("item_type".to_string(), item_type.to_string()));
         }
-        if !preserve_existing_shared_access.is_empty() {
+        if let Some(preserve_existing_shared_access) = &params.preserve_existing_shared_access {
             query_args.push((
                 "preserve_existing_shared_access".to_string(),
                 preserve_existing_shared_access.to_string(),
             ));
         }
-        if !user_ids.is_empty() {
+        if let Some(user_ids) = &params.user_ids {
             query_args.push(("user_ids".to_string(), user_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -627,6 +653,27 @@ impl Accounts {
             .await
     }
 
+    /**
+     * Deprecated: use `shared_access_put_with` and pass the query parameters as a `AccountsSharedAccessPutParams` instead.
+     */
+    #[deprecated(note = "use `shared_access_put_with` instead")]
+    pub async fn shared_access_put(
+        &self,
+        account_id: &str,
+        item_type: &str,
+        preserve_existing_shared_access: &str,
+        user_ids: &str,
+        body: &crate::types::AccountSharedAccess,
+    ) -> Result<crate::types::AccountSharedAccess> {
+        let params = AccountsSharedAccessPutParams {
+            item_type: Some(item_type.to_string()).filter(|s| !s.is_empty()),
+            preserve_existing_shared_access: Some(preserve_existing_shared_access.to_string())
+                .filter(|s| !s.is_empty()),
+            user_ids: Some(user_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.shared_access_put_with(account_id, &params, body).await
+    }
+
     /**
      * Gets the supported languages for envelope recipients.
      *
@@ -705,3 +752,427 @@ impl Accounts {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Accounts::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsGetParams {
+    /// When set to **true**, includes account settings
+    /// in the response. If you omit this parameter, the default behavior is **false**.
+    pub include_account_settings: Option<String>,
+}
+
+impl AccountsGetParams {
+    pub fn include_account_settings<T: ToString>(mut self, include_account_settings: T) -> Self {
+        self.include_account_settings = Some(include_account_settings.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Accounts::billing_charges_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsBillingChargesGetParams {
+    /// Specifies which billing charges to return.
+    /// Valid values are:
+    ///
+    /// * envelopes
+    /// * seats
+    ///   .
+    pub include_charges: Option<String>,
+}
+
+impl AccountsBillingChargesGetParams {
+    pub fn include_charges<T: ToString>(mut self, include_charges: T) -> Self {
+        self.include_charges = Some(include_charges.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Accounts::recipient_names_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsRecipientNamesGetParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub email: Option<String>,
+}
+
+impl AccountsRecipientNamesGetParams {
+    pub fn email<T: ToString>(mut self, email: T) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Accounts::shared_access_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsSharedAccessGetParams {
+    /// Specifies the maximum number of results included in the response. If no value is specified, this defaults to 1000.
+    pub count: Option<String>,
+    /// This query parameter works in conjunction with `user_ids`. When you specify one of the following user statuses, the query limits the results to only users that match the specified status:
+    /// - `ActivationRequired`: Membership Activation required
+    /// - `ActivationSent`: Membership activation sent to user
+    /// - `Active`: User Membership is active
+    /// - `Closed`: User Membership is closed
+    /// - `Disabled`: User Membership is disabled.
+    pub envelopes_not_shared_user_status: Option<String>,
+    /// A comma-separated list of folder IDs for which to return shared item information. If `item_type` is set to `folders`, at least one folder ID is required.
+    pub folder_ids: Option<String>,
+    /// Specifies the type of shared item being requested. The possible values are:
+    ///
+    /// - `envelopes`: Get information about envelope sharing between users.
+    /// - `templates`: Get information about template sharing among users and groups.
+    /// - `folders`: Get information about folder sharing among users and groups.
+    ///   .
+    pub item_type: Option<String>,
+    /// Filter user names based on the specified string. The wild-card '*' (asterisk) can be used in the string.
+    pub search_text: Option<String>,
+    /// A comma-separated list of sharing filters that specifies which users appear in the response.
+    ///
+    /// - `not_shared`: The response lists users who do not share items of `item_type` with the current user.
+    ///
+    /// - `shared_to`: The response lists users in `user_list` who are sharing items to current user.
+    ///
+    /// - `shared_from`: The response lists users in `user_list` who are sharing items from the current user.
+    ///
+    /// - `shared_to_and_from`: The response lists users in `user_list` who are sharing items to and from the current user.
+    ///
+    ///   If the current user does not have administrative privileges, only the `shared_to` option is valid.
+    pub shared: Option<String>,
+    /// If the number of responses is greater than `count`, this specifies the number of responses to skip. Typically this value is a multiple of `count`. The default is 0.
+    pub start_position: Option<String>,
+    /// A comma-separated list of user IDs for whom the shared item information is being requested.
+    pub user_ids: Option<String>,
+}
+
+impl AccountsSharedAccessGetParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn envelopes_not_shared_user_status<T: ToString>(
+        mut self,
+        envelopes_not_shared_user_status: T,
+    ) -> Self {
+        self.envelopes_not_shared_user_status = Some(envelopes_not_shared_user_status.to_string());
+        self
+    }
+
+    pub fn folder_ids<T: ToString>(mut self, folder_ids: T) -> Self {
+        self.folder_ids = Some(folder_ids.to_string());
+        self
+    }
+
+    pub fn item_type<T: ToString>(mut self, item_type: T) -> Self {
+        self.item_type = Some(item_type.to_string());
+        self
+    }
+
+    pub fn search_text<T: ToString>(mut self, search_text: T) -> Self {
+        self.search_text = Some(search_text.to_string());
+        self
+    }
+
+    pub fn shared<T: ToString>(mut self, shared: T) -> Self {
+        self.shared = Some(shared.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+
+    pub fn user_ids<T: ToString>(mut self, user_ids: T) -> Self {
+        self.user_ids = Some(user_ids.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Accounts::shared_access_put_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsSharedAccessPutParams {
+    /// Specifies the type of shared item being set:
+    /// - `envelopes`: Set envelope sharing between users.
+    /// - `templates`: Set information about template sharing among users and groups.
+    /// - `folders`: Get information about folder sharing among users and groups.
+    ///   .
+    pub item_type: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub preserve_existing_shared_access: Option<String>,
+    /// A comma-separated list of IDs for users whose shared item access is being set.
+    pub user_ids: Option<String>,
+}
+
+impl AccountsSharedAccessPutParams {
+    pub fn item_type<T: ToString>(mut self, item_type: T) -> Self {
+        self.item_type = Some(item_type.to_string());
+        self
+    }
+
+    pub fn preserve_existing_shared_access<T: ToString>(
+        mut self,
+        preserve_existing_shared_access: T,
+    ) -> Self {
+        self.preserve_existing_shared_access = Some(preserve_existing_shared_access.to_string());
+        self
+    }
+
+    pub fn user_ids<T: ToString>(mut self, user_ids: T) -> Self {
+        self.user_ids = Some(user_ids.to_string());
+        self
+    }
+}
+
+/// The functions of [`Accounts`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAccountsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccountsApi: Send + Sync {
+    /// See [`Accounts::post`].
+    async fn post(
+        &self,
+        body: &crate::types::NewAccountDefinition,
+    ) -> Result<crate::types::NewAccountSummary>;
+
+    /// See [`Accounts::get_provisioning`].
+    async fn get_provisioning(&self) -> Result<crate::types::ProvisioningInformation>;
+
+    /// See [`Accounts::get_with`].
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &AccountsGetParams,
+    ) -> Result<crate::types::AccountInformation>;
+
+    /// See [`Accounts::delete`].
+    async fn delete(&self, account_id: &str) -> Result<()>;
+
+    /// See [`Accounts::billing_charges_get_with`].
+    async fn billing_charges_get_with(
+        &self,
+        account_id: &str,
+        params: &AccountsBillingChargesGetParams,
+    ) -> Result<crate::types::BillingChargeResponse>;
+
+    /// See [`Accounts::captive_recipients_delete_part`].
+    async fn captive_recipients_delete_part(
+        &self,
+        account_id: &str,
+        recipient_part: &str,
+        body: &crate::types::CaptiveRecipientInformation,
+    ) -> Result<crate::types::CaptiveRecipientInformation>;
+
+    /// See [`Accounts::recipient_names_get_with`].
+    async fn recipient_names_get_with(
+        &self,
+        account_id: &str,
+        params: &AccountsRecipientNamesGetParams,
+    ) -> Result<crate::types::RecipientNamesResponse>;
+
+    /// See [`Accounts::settings_get`].
+    async fn settings_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountSettingsInformation>;
+
+    /// See [`Accounts::settings_put`].
+    async fn settings_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSettingsInformation,
+    ) -> Result<()>;
+
+    /// See [`Accounts::envelope_purge_configuration_get`].
+    async fn envelope_purge_configuration_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::EnvelopePurgeConfiguration>;
+
+    /// See [`Accounts::envelope_purge_configuration_put`].
+    async fn envelope_purge_configuration_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopePurgeConfiguration,
+    ) -> Result<crate::types::EnvelopePurgeConfiguration>;
+
+    /// See [`Accounts::notification_defaults_get`].
+    async fn notification_defaults_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::NotificationDefaultsData>;
+
+    /// See [`Accounts::notification_defaults_put`].
+    async fn notification_defaults_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::NotificationDefaultsData,
+    ) -> Result<crate::types::NotificationDefaultsData>;
+
+    /// See [`Accounts::shared_access_get_with`].
+    async fn shared_access_get_with(
+        &self,
+        account_id: &str,
+        params: &AccountsSharedAccessGetParams,
+    ) -> Result<crate::types::AccountSharedAccess>;
+
+    /// See [`Accounts::shared_access_put_with`].
+    async fn shared_access_put_with(
+        &self,
+        account_id: &str,
+        params: &AccountsSharedAccessPutParams,
+        body: &crate::types::AccountSharedAccess,
+    ) -> Result<crate::types::AccountSharedAccess>;
+
+    /// See [`Accounts::supported_languages_get`].
+    async fn supported_languages_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::SupportedLanguages>;
+
+    /// See [`Accounts::unsupported_file_types_get`].
+    async fn unsupported_file_types_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::FileTypeList>;
+
+    /// See [`Accounts::organization_exports_get_settings_export`].
+    async fn organization_exports_get_settings_export(
+        &self,
+        organization_id: &str,
+        result_id: &str,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl AccountsApi for Accounts {
+    async fn post(
+        &self,
+        body: &crate::types::NewAccountDefinition,
+    ) -> Result<crate::types::NewAccountSummary> {
+        Accounts::post(self, body).await
+    }
+
+    async fn get_provisioning(&self) -> Result<crate::types::ProvisioningInformation> {
+        Accounts::get_provisioning(self).await
+    }
+
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &AccountsGetParams,
+    ) -> Result<crate::types::AccountInformation> {
+        Accounts::get_with(self, account_id, params).await
+    }
+
+    async fn delete(&self, account_id: &str) -> Result<()> {
+        Accounts::delete(self, account_id).await
+    }
+
+    async fn billing_charges_get_with(
+        &self,
+        account_id: &str,
+        params: &AccountsBillingChargesGetParams,
+    ) -> Result<crate::types::BillingChargeResponse> {
+        Accounts::billing_charges_get_with(self, account_id, params).await
+    }
+
+    async fn captive_recipients_delete_part(
+        &self,
+        account_id: &str,
+        recipient_part: &str,
+        body: &crate::types::CaptiveRecipientInformation,
+    ) -> Result<crate::types::CaptiveRecipientInformation> {
+        Accounts::captive_recipients_delete_part(self, account_id, recipient_part, body).await
+    }
+
+    async fn recipient_names_get_with(
+        &self,
+        account_id: &str,
+        params: &AccountsRecipientNamesGetParams,
+    ) -> Result<crate::types::RecipientNamesResponse> {
+        Accounts::recipient_names_get_with(self, account_id, params).await
+    }
+
+    async fn settings_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountSettingsInformation> {
+        Accounts::settings_get(self, account_id).await
+    }
+
+    async fn settings_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::AccountSettingsInformation,
+    ) -> Result<()> {
+        Accounts::settings_put(self, account_id, body).await
+    }
+
+    async fn envelope_purge_configuration_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::EnvelopePurgeConfiguration> {
+        Accounts::envelope_purge_configuration_get(self, account_id).await
+    }
+
+    async fn envelope_purge_configuration_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopePurgeConfiguration,
+    ) -> Result<crate::types::EnvelopePurgeConfiguration> {
+        Accounts::envelope_purge_configuration_put(self, account_id, body).await
+    }
+
+    async fn notification_defaults_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::NotificationDefaultsData> {
+        Accounts::notification_defaults_get(self, account_id).await
+    }
+
+    async fn notification_defaults_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::NotificationDefaultsData,
+    ) -> Result<crate::types::NotificationDefaultsData> {
+        Accounts::notification_defaults_put(self, account_id, body).await
+    }
+
+    async fn shared_access_get_with(
+        &self,
+        account_id: &str,
+        params: &AccountsSharedAccessGetParams,
+    ) -> Result<crate::types::AccountSharedAccess> {
+        Accounts::shared_access_get_with(self, account_id, params).await
+    }
+
+    async fn shared_access_put_with(
+        &self,
+        account_id: &str,
+        params: &AccountsSharedAccessPutParams,
+        body: &crate::types::AccountSharedAccess,
+    ) -> Result<crate::types::AccountSharedAccess> {
+        Accounts::shared_access_put_with(self, account_id, params, body).await
+    }
+
+    async fn supported_languages_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::SupportedLanguages> {
+        Accounts::supported_languages_get(self, account_id).await
+    }
+
+    async fn unsupported_file_types_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::FileTypeList> {
+        Accounts::unsupported_file_types_get(self, account_id).await
+    }
+
+    async fn organization_exports_get_settings_export(
+        &self,
+        organization_id: &str,
+        result_id: &str,
+    ) -> Result<()> {
+        Accounts::organization_exports_get_settings_export(self, organization_id, result_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ApplianceInfo {
     pub client: Client,
@@ -26,7 +24,7 @@ impl ApplianceInfo {
     pub async fn envelope_get_dynamic_system_setting(
         &self,
         account_id: &str,
-    ) -> Result<crate::types::ApplianceInfo> {
+    ) -> Result<crate::types::DisplayApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/display_appliance_info/dynamicsystemsettings",
             crate::progenitor_support::encode_path(account_id),
@@ -49,7 +47,7 @@ impl ApplianceInfo {
     pub async fn envelope_get_template(
         &self,
         account_id: &str,
-    ) -> Result<crate::types::ApplianceInfo> {
+    ) -> Result<crate::types::DisplayApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/display_appliance_info/templateInfo",
             crate::progenitor_support::encode_path(account_id),
@@ -74,7 +72,7 @@ impl ApplianceInfo {
         &self,
         account_id: &str,
         envelope_id: &str,
-    ) -> Result<crate::types::ApplianceInfo> {
+    ) -> Result<crate::types::DisplayApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info",
             crate::progenitor_support::encode_path(account_id),
@@ -126,7 +124,7 @@ impl ApplianceInfo {
         &self,
         account_id: &str,
         envelope_id: &str,
-    ) -> Result<crate::types::ApplianceInfo> {
+    ) -> Result<crate::types::DisplayApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/custom_fields",
             crate::progenitor_support::encode_path(account_id),
@@ -178,7 +176,7 @@ impl ApplianceInfo {
         &self,
         account_id: &str,
         envelope_id: &str,
-    ) -> Result<crate::types::ApplianceInfo> {
+    ) -> Result<crate::types::DisplayApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/date_signed",
             crate::progenitor_support::encode_path(account_id),
@@ -262,7 +260,7 @@ impl ApplianceInfo {
         &self,
         account_id: &str,
         envelope_id: &str,
-    ) -> Result<crate::types::ApplianceInfo> {
+    ) -> Result<crate::types::DisplayApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/document_page_list",
             crate::progenitor_support::encode_path(account_id),
@@ -288,7 +286,7 @@ impl ApplianceInfo {
         &self,
         account_id: &str,
         envelope_id: &str,
-    ) -> Result<crate::types::ApplianceInfo> {
+    ) -> Result<crate::types::DisplayApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/image",
             crate::progenitor_support::encode_path(account_id),
@@ -317,7 +315,7 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
         user_id: &str,
-    ) -> Result<crate::types::ApplianceInfo> {
+    ) -> Result<crate::types::DisplayApplianceInfo> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/localepolicy/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -410,7 +408,7 @@ impl ApplianceInfo {
         &self,
         account_id: &str,
         envelope_id: &str,
-    ) -> Result<crate::types::ApplianceInfo> {
+    ) -> Result<crate::types::DisplayAppliancePdf> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf",
             crate::progenitor_support::encode_path(account_id),
@@ -440,7 +438,7 @@ impl ApplianceInfo {
         account_id: &str,
         envelope_id: &str,
         pdf_id: &str,
-    ) -> Result<crate::types::ApplianceInfo> {
+    ) -> Result<crate::types::DisplayAppliancePdf> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf/{}",
             crate::progenitor_support::encode_path(account_id),
@@ -467,7 +465,7 @@ impl ApplianceInfo {
         &self,
         account_id: &str,
         envelope_id: &str,
-    ) -> Result<crate::types::ApplianceInfo> {
+    ) -> Result<crate::types::DisplayAppliancePdf> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
             crate::progenitor_support::encode_path(account_id),
@@ -515,7 +513,7 @@ impl ApplianceInfo {
         &self,
         account_id: &str,
         envelope_id: &str,
-    ) -> Result<crate::types::ApplianceInfo> {
+    ) -> Result<crate::types::DisplayAppliancePdf> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/pdf_blobs",
             crate::progenitor_support::encode_path(account_id),
@@ -593,7 +591,7 @@ impl ApplianceInfo {
         &self,
         account_id: &str,
         envelope_id: &str,
-    ) -> Result<crate::types::ApplianceInfo> {
+    ) -> Result<crate::types::DisplayApplianceSignerAttachment> {
         let url = format!(
             "/v2.1/accounts/{}/envelopes/{}/display_appliance_info/signer_attachment_info",
             crate::progenitor_support::encode_path(account_id),
@@ -648,8 +646,365 @@ impl ApplianceInfo {
      *
      *
      */
-    pub async fn envelope_post_redeem(&self) -> Result<crate::types::ApplianceInfo> {
+    pub async fn envelope_post_redeem(&self) -> Result<crate::types::DisplayApplianceInfo> {
         let url = "/v2.1/display_appliance_info/redeem".to_string();
         self.client.post(&url, None).await
     }
 }
+
+/// The functions of [`ApplianceInfo`], for code that takes them as a trait, so that it can be
+/// tested with the `MockApplianceInfoApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ApplianceInfoApi: Send + Sync {
+    /// See [`ApplianceInfo::envelope_get_dynamic_system_setting`].
+    async fn envelope_get_dynamic_system_setting(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo>;
+
+    /// See [`ApplianceInfo::envelope_get_template`].
+    async fn envelope_get_template(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo>;
+
+    /// See [`ApplianceInfo::envelope_get`].
+    async fn envelope_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo>;
+
+    /// See [`ApplianceInfo::envelope_get_account`].
+    async fn envelope_get_account(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceAccount>;
+
+    /// See [`ApplianceInfo::envelope_get_custom_field`].
+    async fn envelope_get_custom_field(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo>;
+
+    /// See [`ApplianceInfo::envelope_delete_custom_fields`].
+    async fn envelope_delete_custom_fields(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ApplianceInfo::envelope_get_date_signed`].
+    async fn envelope_get_date_signed(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo>;
+
+    /// See [`ApplianceInfo::envelope_put_document`].
+    async fn envelope_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ApplianceInfo::envelope_delete_document`].
+    async fn envelope_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ApplianceInfo::envelope_get_document_page`].
+    async fn envelope_get_document_page(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo>;
+
+    /// See [`ApplianceInfo::envelope_get_image`].
+    async fn envelope_get_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo>;
+
+    /// See [`ApplianceInfo::envelope_get_locale_policy`].
+    async fn envelope_get_locale_policy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo>;
+
+    /// See [`ApplianceInfo::envelope_put_page`].
+    async fn envelope_put_page(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+
+    /// See [`ApplianceInfo::envelope_post_page`].
+    async fn envelope_post_page(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+
+    /// See [`ApplianceInfo::envelope_delete_page`].
+    async fn envelope_delete_page(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+
+    /// See [`ApplianceInfo::envelope_put_pdf`].
+    async fn envelope_put_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayAppliancePdf>;
+
+    /// See [`ApplianceInfo::envelope_get_pdf`].
+    async fn envelope_get_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        pdf_id: &str,
+    ) -> Result<crate::types::DisplayAppliancePdf>;
+
+    /// See [`ApplianceInfo::envelope_get_pdf_blob`].
+    async fn envelope_get_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayAppliancePdf>;
+
+    /// See [`ApplianceInfo::envelope_put_pdf_blob`].
+    async fn envelope_put_pdf_blob(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+
+    /// See [`ApplianceInfo::envelope_post_pdf_blob`].
+    async fn envelope_post_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayAppliancePdf>;
+
+    /// See [`ApplianceInfo::envelope_put_recipient_denied_document_copy`].
+    async fn envelope_put_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ApplianceInfo::envelope_delete_recipient_denied_document_copy`].
+    async fn envelope_delete_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ApplianceInfo::envelope_get_signer_attachment`].
+    async fn envelope_get_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceSignerAttachment>;
+
+    /// See [`ApplianceInfo::envelope_delete_signer_attachment`].
+    async fn envelope_delete_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ApplianceInfo::envelope_post_error`].
+    async fn envelope_post_error(&self) -> Result<()>;
+
+    /// See [`ApplianceInfo::envelope_post_redeem`].
+    async fn envelope_post_redeem(&self) -> Result<crate::types::DisplayApplianceInfo>;
+}
+
+#[async_trait::async_trait]
+impl ApplianceInfoApi for ApplianceInfo {
+    async fn envelope_get_dynamic_system_setting(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo> {
+        ApplianceInfo::envelope_get_dynamic_system_setting(self, account_id).await
+    }
+
+    async fn envelope_get_template(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo> {
+        ApplianceInfo::envelope_get_template(self, account_id).await
+    }
+
+    async fn envelope_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo> {
+        ApplianceInfo::envelope_get(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_get_account(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceAccount> {
+        ApplianceInfo::envelope_get_account(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_get_custom_field(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo> {
+        ApplianceInfo::envelope_get_custom_field(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_delete_custom_fields(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        ApplianceInfo::envelope_delete_custom_fields(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_get_date_signed(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo> {
+        ApplianceInfo::envelope_get_date_signed(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        ApplianceInfo::envelope_put_document(self, account_id, document_id, envelope_id).await
+    }
+
+    async fn envelope_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        ApplianceInfo::envelope_delete_document(self, account_id, document_id, envelope_id).await
+    }
+
+    async fn envelope_get_document_page(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo> {
+        ApplianceInfo::envelope_get_document_page(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_get_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo> {
+        ApplianceInfo::envelope_get_image(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_get_locale_policy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::DisplayApplianceInfo> {
+        ApplianceInfo::envelope_get_locale_policy(self, account_id, envelope_id, user_id).await
+    }
+
+    async fn envelope_put_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        ApplianceInfo::envelope_put_page(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_post_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        ApplianceInfo::envelope_post_page(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_delete_page(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        ApplianceInfo::envelope_delete_page(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_put_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayAppliancePdf> {
+        ApplianceInfo::envelope_put_pdf(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_get_pdf(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        pdf_id: &str,
+    ) -> Result<crate::types::DisplayAppliancePdf> {
+        ApplianceInfo::envelope_get_pdf(self, account_id, envelope_id, pdf_id).await
+    }
+
+    async fn envelope_get_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayAppliancePdf> {
+        ApplianceInfo::envelope_get_pdf_blob(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_put_pdf_blob(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        ApplianceInfo::envelope_put_pdf_blob(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_post_pdf_blob(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayAppliancePdf> {
+        ApplianceInfo::envelope_post_pdf_blob(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_put_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        ApplianceInfo::envelope_put_recipient_denied_document_copy(self, account_id, envelope_id)
+            .await
+    }
+
+    async fn envelope_delete_recipient_denied_document_copy(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        ApplianceInfo::envelope_delete_recipient_denied_document_copy(self, account_id, envelope_id)
+            .await
+    }
+
+    async fn envelope_get_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DisplayApplianceSignerAttachment> {
+        ApplianceInfo::envelope_get_signer_attachment(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_delete_signer_attachment(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        ApplianceInfo::envelope_delete_signer_attachment(self, account_id, envelope_id).await
+    }
+
+    async fn envelope_post_error(&self) -> Result<()> {
+        ApplianceInfo::envelope_post_error(self).await
+    }
+
+    async fn envelope_post_redeem(&self) -> Result<crate::types::DisplayApplianceInfo> {
+        ApplianceInfo::envelope_post_redeem(self).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct BccEmailArchive {
     pub client: Client,
@@ -22,20 +20,18 @@ impl BccEmailArchive {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- (Optional) The index position within the total result set from which to start returning values. The default value is `0`.
+     * * `params: &BccEmailArchiveGetListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_list(
+    pub async fn get_list_with(
         &self,
         account_id: &str,
-        count: &str,
-        start_position: &str,
+        params: &BccEmailArchiveGetListParams,
     ) -> Result<crate::types::BccEmailArchiveList> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -48,6 +44,23 @@ impl BccEmailArchive {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_list_with` and pass the query parameters as a `BccEmailArchiveGetListParams` instead.
+     */
+    #[deprecated(note = "use `get_list_with` instead")]
+    pub async fn get_list(
+        &self,
+        account_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::BccEmailArchiveList> {
+        let params = BccEmailArchiveGetListParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_list_with(account_id, &params).await
+    }
+
     /**
      * Creates a BCC email archive configuration.
      *
@@ -90,21 +103,19 @@ impl BccEmailArchive {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `bcc_email_archive_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- (Optional) The index position within the total result set from which to start returning values. The default value is `0`.
+     * * `params: &BccEmailArchiveGetHistoryListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_history_list(
+    pub async fn get_history_list_with(
         &self,
         account_id: &str,
         bcc_email_archive_id: &str,
-        count: &str,
-        start_position: &str,
+        params: &BccEmailArchiveGetHistoryListParams,
     ) -> Result<crate::types::BccEmailArchiveHistoryList> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -118,6 +129,25 @@ impl BccEmailArchive {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_history_list_with` and pass the query parameters as a `BccEmailArchiveGetHistoryListParams` instead.
+     */
+    #[deprecated(note = "use `get_history_list_with` instead")]
+    pub async fn get_history_list(
+        &self,
+        account_id: &str,
+        bcc_email_archive_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::BccEmailArchiveHistoryList> {
+        let params = BccEmailArchiveGetHistoryListParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_history_list_with(account_id, bcc_email_archive_id, &params)
+            .await
+    }
+
     /**
      * Deletes a BCC email archive configuration.
      *
@@ -143,3 +173,108 @@ impl BccEmailArchive {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `BccEmailArchive::get_list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BccEmailArchiveGetListParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub count: Option<String>,
+    /// (Optional) The index position within the total result set from which to start returning values. The default value is `0`.
+    pub start_position: Option<String>,
+}
+
+impl BccEmailArchiveGetListParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+}
+
+/// The query parameters of `BccEmailArchive::get_history_list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BccEmailArchiveGetHistoryListParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub count: Option<String>,
+    /// (Optional) The index position within the total result set from which to start returning values. The default value is `0`.
+    pub start_position: Option<String>,
+}
+
+impl BccEmailArchiveGetHistoryListParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+}
+
+/// The functions of [`BccEmailArchive`], for code that takes them as a trait, so that it can be
+/// tested with the `MockBccEmailArchiveApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait BccEmailArchiveApi: Send + Sync {
+    /// See [`BccEmailArchive::get_list_with`].
+    async fn get_list_with(
+        &self,
+        account_id: &str,
+        params: &BccEmailArchiveGetListParams,
+    ) -> Result<crate::types::BccEmailArchiveList>;
+
+    /// See [`BccEmailArchive::post`].
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::BccEmailArchiveData,
+    ) -> Result<crate::types::BccEmailArchiveData>;
+
+    /// See [`BccEmailArchive::get_history_list_with`].
+    async fn get_history_list_with(
+        &self,
+        account_id: &str,
+        bcc_email_archive_id: &str,
+        params: &BccEmailArchiveGetHistoryListParams,
+    ) -> Result<crate::types::BccEmailArchiveHistoryList>;
+
+    /// See [`BccEmailArchive::delete`].
+    async fn delete(&self, account_id: &str, bcc_email_archive_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl BccEmailArchiveApi for BccEmailArchive {
+    async fn get_list_with(
+        &self,
+        account_id: &str,
+        params: &BccEmailArchiveGetListParams,
+    ) -> Result<crate::types::BccEmailArchiveList> {
+        BccEmailArchive::get_list_with(self, account_id, params).await
+    }
+
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::BccEmailArchiveData,
+    ) -> Result<crate::types::BccEmailArchiveData> {
+        BccEmailArchive::post(self, account_id, body).await
+    }
+
+    async fn get_history_list_with(
+        &self,
+        account_id: &str,
+        bcc_email_archive_id: &str,
+        params: &BccEmailArchiveGetHistoryListParams,
+    ) -> Result<crate::types::BccEmailArchiveHistoryList> {
+        BccEmailArchive::get_history_list_with(self, account_id, bcc_email_archive_id, params).await
+    }
+
+    async fn delete(&self, account_id: &str, bcc_email_archive_id: &str) -> Result<()> {
+        BccEmailArchive::delete(self, account_id, bcc_email_archive_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct BillingPlans {
     pub client: Client,
@@ -31,28 +29,24 @@ impl BillingPlans {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_credit_card_information: &str` -- When set to **true**, payment information including credit card information will show in the return.
-     * * `include_metadata: &str` -- When set to **true**, the `canUpgrade` and `renewalStatus` properities are included the response and an array of `supportedCountries` is added to the `billingAddress` information. .
-     * * `include_successor_plans: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &BillingPlansGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         account_id: &str,
-        include_credit_card_information: &str,
-        include_metadata: &str,
-        include_successor_plans: &str,
+        params: &BillingPlansGetParams,
     ) -> Result<crate::types::AccountBillingPlanResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_credit_card_information.is_empty() {
+        if let Some(include_credit_card_information) = &params.include_credit_card_information {
             query_args.push((
                 "include_credit_card_information".to_string(),
                 include_credit_card_information.to_string(),
             ));
         }
-        if !include_metadata.is_empty() {
+        if let Some(include_metadata) = &params.include_metadata {
             query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
         }
-        if !include_successor_plans.is_empty() {
+        if let Some(include_successor_plans) = &params.include_successor_plans {
             query_args.push((
                 "include_successor_plans".to_string(),
                 include_successor_plans.to_string(),
@@ -68,6 +62,27 @@ impl BillingPlans {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `BillingPlansGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        account_id: &str,
+        include_credit_card_information: &str,
+        include_metadata: &str,
+        include_successor_plans: &str,
+    ) -> Result<crate::types::AccountBillingPlanResponse> {
+        let params = BillingPlansGetParams {
+            include_credit_card_information: Some(include_credit_card_information.to_string())
+                .filter(|s| !s.is_empty()),
+            include_metadata: Some(include_metadata.to_string()).filter(|s| !s.is_empty()),
+            include_successor_plans: Some(include_successor_plans.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.get_with(account_id, &params).await
+    }
+
     /**
      * Updates an account billing plan.
      *
@@ -78,16 +93,16 @@ impl BillingPlans {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `preview_billing_plan: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &BillingPlansPutParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn put(
+    pub async fn put_with(
         &self,
         account_id: &str,
-        preview_billing_plan: &str,
+        params: &BillingPlansPutParams,
         body: &crate::types::BillingPlanInformation,
     ) -> Result<crate::types::BillingPlanUpdateResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !preview_billing_plan.is_empty() {
+        if let Some(preview_billing_plan) = &params.preview_billing_plan {
             query_args.push((
                 "preview_billing_plan".to_string(),
                 preview_billing_plan.to_string(),
@@ -105,6 +120,22 @@ impl BillingPlans {
             .await
     }
 
+    /**
+     * Deprecated: use `put_with` and pass the query parameters as a `BillingPlansPutParams` instead.
+     */
+    #[deprecated(note = "use `put_with` instead")]
+    pub async fn put(
+        &self,
+        account_id: &str,
+        preview_billing_plan: &str,
+        body: &crate::types::BillingPlanInformation,
+    ) -> Result<crate::types::BillingPlanUpdateResponse> {
+        let params = BillingPlansPutParams {
+            preview_billing_plan: Some(preview_billing_plan.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.put_with(account_id, &params, body).await
+    }
+
     /**
      * Get credit card information.
      *
@@ -238,3 +269,159 @@ impl BillingPlans {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `BillingPlans::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingPlansGetParams {
+    /// When set to **true**, payment information including credit card information will show in the return.
+    pub include_credit_card_information: Option<String>,
+    /// When set to **true**, the `canUpgrade` and `renewalStatus` properities are included the response and an array of `supportedCountries` is added to the `billingAddress` information. .
+    pub include_metadata: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub include_successor_plans: Option<String>,
+}
+
+impl BillingPlansGetParams {
+    pub fn include_credit_card_information<T: ToString>(
+        mut self,
+        include_credit_card_information: T,
+    ) -> Self {
+        self.include_credit_card_information = Some(include_credit_card_information.to_string());
+        self
+    }
+
+    pub fn include_metadata<T: ToString>(mut self, include_metadata: T) -> Self {
+        self.include_metadata = Some(include_metadata.to_string());
+        self
+    }
+
+    pub fn include_successor_plans<T: ToString>(mut self, include_successor_plans: T) -> Self {
+        self.include_successor_plans = Some(include_successor_plans.to_string());
+        self
+    }
+}
+
+/// The query parameters of `BillingPlans::put_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingPlansPutParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub preview_billing_plan: Option<String>,
+}
+
+impl BillingPlansPutParams {
+    pub fn preview_billing_plan<T: ToString>(mut self, preview_billing_plan: T) -> Self {
+        self.preview_billing_plan = Some(preview_billing_plan.to_string());
+        self
+    }
+}
+
+/// The functions of [`BillingPlans`], for code that takes them as a trait, so that it can be
+/// tested with the `MockBillingPlansApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait BillingPlansApi: Send + Sync {
+    /// See [`BillingPlans::get_with`].
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &BillingPlansGetParams,
+    ) -> Result<crate::types::AccountBillingPlanResponse>;
+
+    /// See [`BillingPlans::put_with`].
+    async fn put_with(
+        &self,
+        account_id: &str,
+        params: &BillingPlansPutParams,
+        body: &crate::types::BillingPlanInformation,
+    ) -> Result<crate::types::BillingPlanUpdateResponse>;
+
+    /// See [`BillingPlans::get_credit_card_info`].
+    async fn get_credit_card_info(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::CreditCardInformation>;
+
+    /// See [`BillingPlans::get_downgrade_request_info`].
+    async fn get_downgrade_request_info(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::DowngradRequestBillingInfoResponse>;
+
+    /// See [`BillingPlans::put_downgrade_account`].
+    async fn put_downgrade_account(
+        &self,
+        account_id: &str,
+        body: &crate::types::DowngradeBillingPlanInformation,
+    ) -> Result<crate::types::DowngradePlanUpdateResponse>;
+
+    /// See [`BillingPlans::purchased_envelopes_put`].
+    async fn purchased_envelopes_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::PurchasedEnvelopesInformation,
+    ) -> Result<()>;
+
+    /// See [`BillingPlans::get_billing_plans`].
+    async fn get_billing_plans(&self) -> Result<crate::types::BillingPlansResponse>;
+
+    /// See [`BillingPlans::get_plan`].
+    async fn get_plan(&self, billing_plan_id: &str) -> Result<crate::types::BillingPlanResponse>;
+}
+
+#[async_trait::async_trait]
+impl BillingPlansApi for BillingPlans {
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &BillingPlansGetParams,
+    ) -> Result<crate::types::AccountBillingPlanResponse> {
+        BillingPlans::get_with(self, account_id, params).await
+    }
+
+    async fn put_with(
+        &self,
+        account_id: &str,
+        params: &BillingPlansPutParams,
+        body: &crate::types::BillingPlanInformation,
+    ) -> Result<crate::types::BillingPlanUpdateResponse> {
+        BillingPlans::put_with(self, account_id, params, body).await
+    }
+
+    async fn get_credit_card_info(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::CreditCardInformation> {
+        BillingPlans::get_credit_card_info(self, account_id).await
+    }
+
+    async fn get_downgrade_request_info(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::DowngradRequestBillingInfoResponse> {
+        BillingPlans::get_downgrade_request_info(self, account_id).await
+    }
+
+    async fn put_downgrade_account(
+        &self,
+        account_id: &str,
+        body: &crate::types::DowngradeBillingPlanInformation,
+    ) -> Result<crate::types::DowngradePlanUpdateResponse> {
+        BillingPlans::put_downgrade_account(self, account_id, body).await
+    }
+
+    async fn purchased_envelopes_put(
+        &self,
+        account_id: &str,
+        body: &crate::types::PurchasedEnvelopesInformation,
+    ) -> Result<()> {
+        BillingPlans::purchased_envelopes_put(self, account_id, body).await
+    }
+
+    async fn get_billing_plans(&self) -> Result<crate::types::BillingPlansResponse> {
+        BillingPlans::get_billing_plans(self).await
+    }
+
+    async fn get_plan(&self, billing_plan_id: &str) -> Result<crate::types::BillingPlanResponse> {
+        BillingPlans::get_plan(self, billing_plan_id).await
+    }
+}
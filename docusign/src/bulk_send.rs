@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct BulkSend {
     pub client: Client,
@@ -24,37 +22,24 @@ impl BulkSend {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `batch_ids: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- The number of results to report. Must be a value from 1 to 1000.
-     *   
-     *   Default: 100.
-     * * `start_position: &str` -- The start position for results. Essentially the number of results to skip before collecting them. Must be a value greater than 0.
-     * * `status: &str` -- The kind of results to collect. Must be one of:
-     *   
-     *   - all
-     *   - failed
-     *   - sent
-     *   - queued.
+     * * `params: &BulkSendBatchGetBatcheParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn batch_get_batche(
+    pub async fn batch_get_batche_with(
         &self,
         account_id: &str,
-        batch_ids: &str,
-        count: &str,
-        start_position: &str,
-        status: &str,
+        params: &BulkSendBatchGetBatcheParams,
     ) -> Result<crate::types::BulkSendBatchSummaries> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !batch_ids.is_empty() {
+        if let Some(batch_ids) = &params.batch_ids {
             query_args.push(("batch_ids".to_string(), batch_ids.to_string()));
         }
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -67,6 +52,27 @@ impl BulkSend {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `batch_get_batche_with` and pass the query parameters as a `BulkSendBatchGetBatcheParams` instead.
+     */
+    #[deprecated(note = "use `batch_get_batche_with` instead")]
+    pub async fn batch_get_batche(
+        &self,
+        account_id: &str,
+        batch_ids: &str,
+        count: &str,
+        start_position: &str,
+        status: &str,
+    ) -> Result<crate::types::BulkSendBatchSummaries> {
+        let params = BulkSendBatchGetBatcheParams {
+            batch_ids: Some(batch_ids.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.batch_get_batche_with(account_id, &params).await
+    }
+
     /**
      * Gets the status of a specific bulk send batch.
      *
@@ -416,3 +422,210 @@ impl BulkSend {
             .await
     }
 }
+
+/// The query parameters of `BulkSend::batch_get_batche_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BulkSendBatchGetBatcheParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub batch_ids: Option<String>,
+    /// The number of results to report. Must be a value from 1 to 1000.
+    ///
+    /// Default: 100.
+    pub count: Option<String>,
+    /// The start position for results. Essentially the number of results to skip before collecting them. Must be a value greater than 0.
+    pub start_position: Option<String>,
+    /// The kind of results to collect. Must be one of:
+    ///
+    /// - all
+    /// - failed
+    /// - sent
+    /// - queued.
+    pub status: Option<String>,
+}
+
+impl BulkSendBatchGetBatcheParams {
+    pub fn batch_ids<T: ToString>(mut self, batch_ids: T) -> Self {
+        self.batch_ids = Some(batch_ids.to_string());
+        self
+    }
+
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The functions of [`BulkSend`], for code that takes them as a trait, so that it can be
+/// tested with the `MockBulkSendApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait BulkSendApi: Send + Sync {
+    /// See [`BulkSend::batch_get_batche_with`].
+    async fn batch_get_batche_with(
+        &self,
+        account_id: &str,
+        params: &BulkSendBatchGetBatcheParams,
+    ) -> Result<crate::types::BulkSendBatchSummaries>;
+
+    /// See [`BulkSend::batch_get_statu`].
+    async fn batch_get_statu(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+    ) -> Result<crate::types::BulkSendBatchStatus>;
+
+    /// See [`BulkSend::batch_put_status`].
+    async fn batch_put_status(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+        body: &crate::types::BulkSendBatchRequest,
+    ) -> Result<crate::types::BulkSendBatchStatus>;
+
+    /// See [`BulkSend::v_2crud_get_list`].
+    async fn v_2crud_get_list(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::BulkSendingListSummaries>;
+
+    /// See [`BulkSend::v_2crud_post_list`].
+    async fn v_2crud_post_list(
+        &self,
+        account_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> Result<crate::types::BulkSendingList>;
+
+    /// See [`BulkSend::v_2crud_get_list_bulk_send`].
+    async fn v_2crud_get_list_bulk_send(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> Result<crate::types::BulkSendingList>;
+
+    /// See [`BulkSend::v_2crud_put_list`].
+    async fn v_2crud_put_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> Result<crate::types::BulkSendingList>;
+
+    /// See [`BulkSend::v_2crud_delete_list`].
+    async fn v_2crud_delete_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> Result<crate::types::BulkSendingListSummaries>;
+
+    /// See [`BulkSend::post_request`].
+    async fn post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> Result<crate::types::BulkSendResponse>;
+
+    /// See [`BulkSend::test_post_request`].
+    async fn test_post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> Result<crate::types::BulkSendTestResponse>;
+}
+
+#[async_trait::async_trait]
+impl BulkSendApi for BulkSend {
+    async fn batch_get_batche_with(
+        &self,
+        account_id: &str,
+        params: &BulkSendBatchGetBatcheParams,
+    ) -> Result<crate::types::BulkSendBatchSummaries> {
+        BulkSend::batch_get_batche_with(self, account_id, params).await
+    }
+
+    async fn batch_get_statu(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+    ) -> Result<crate::types::BulkSendBatchStatus> {
+        BulkSend::batch_get_statu(self, account_id, bulk_send_batch_id).await
+    }
+
+    async fn batch_put_status(
+        &self,
+        account_id: &str,
+        bulk_send_batch_id: &str,
+        body: &crate::types::BulkSendBatchRequest,
+    ) -> Result<crate::types::BulkSendBatchStatus> {
+        BulkSend::batch_put_status(self, account_id, bulk_send_batch_id, body).await
+    }
+
+    async fn v_2crud_get_list(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::BulkSendingListSummaries> {
+        BulkSend::v_2crud_get_list(self, account_id).await
+    }
+
+    async fn v_2crud_post_list(
+        &self,
+        account_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> Result<crate::types::BulkSendingList> {
+        BulkSend::v_2crud_post_list(self, account_id, body).await
+    }
+
+    async fn v_2crud_get_list_bulk_send(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> Result<crate::types::BulkSendingList> {
+        BulkSend::v_2crud_get_list_bulk_send(self, account_id, bulk_send_list_id).await
+    }
+
+    async fn v_2crud_put_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendingList,
+    ) -> Result<crate::types::BulkSendingList> {
+        BulkSend::v_2crud_put_list(self, account_id, bulk_send_list_id, body).await
+    }
+
+    async fn v_2crud_delete_list(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+    ) -> Result<crate::types::BulkSendingListSummaries> {
+        BulkSend::v_2crud_delete_list(self, account_id, bulk_send_list_id).await
+    }
+
+    async fn post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> Result<crate::types::BulkSendResponse> {
+        BulkSend::post_request(self, account_id, bulk_send_list_id, body).await
+    }
+
+    async fn test_post_request(
+        &self,
+        account_id: &str,
+        bulk_send_list_id: &str,
+        body: &crate::types::BulkSendRequest,
+    ) -> Result<crate::types::BulkSendTestResponse> {
+        BulkSend::test_post_request(self, account_id, bulk_send_list_id, body).await
+    }
+}
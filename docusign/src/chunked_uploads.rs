@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ChunkedUploads {
     pub client: Client,
@@ -51,16 +49,16 @@ impl ChunkedUploads {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `chunked_upload_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include: &str` -- (Optional) This parameter enables you to include additional attribute data in the response. The valid value for this method is `checksum`, which returns an SHA256 checksum of the content of the chunked upload in the response. You can use compare this checksum against your own checksum of the original content to verify that there are no missing parts before you attempt to commit the chunked upload.
+     * * `params: &ChunkedUploadsGetUploadParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_upload(
+    pub async fn get_upload_with(
         &self,
         account_id: &str,
         chunked_upload_id: &str,
-        include: &str,
+        params: &ChunkedUploadsGetUploadParams,
     ) -> Result<crate::types::ChunkedUploadResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -74,6 +72,23 @@ impl ChunkedUploads {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_upload_with` and pass the query parameters as a `ChunkedUploadsGetUploadParams` instead.
+     */
+    #[deprecated(note = "use `get_upload_with` instead")]
+    pub async fn get_upload(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        include: &str,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        let params = ChunkedUploadsGetUploadParams {
+            include: Some(include.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_upload_with(account_id, chunked_upload_id, &params)
+            .await
+    }
+
     /**
      * Commit a chunked upload.
      *
@@ -89,16 +104,16 @@ impl ChunkedUploads {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `chunked_upload_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `action: &str` -- (Required) You must use this query parameter with the value `commit`, which affirms the request to validate and prepare the chunked upload for use with other API calls.
+     * * `params: &ChunkedUploadsPutParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn put(
+    pub async fn put_with(
         &self,
         account_id: &str,
         chunked_upload_id: &str,
-        action: &str,
+        params: &ChunkedUploadsPutParams,
     ) -> Result<crate::types::ChunkedUploadResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !action.is_empty() {
+        if let Some(action) = &params.action {
             query_args.push(("action".to_string(), action.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -112,6 +127,22 @@ impl ChunkedUploads {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `put_with` and pass the query parameters as a `ChunkedUploadsPutParams` instead.
+     */
+    #[deprecated(note = "use `put_with` instead")]
+    pub async fn put(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        action: &str,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        let params = ChunkedUploadsPutParams {
+            action: Some(action.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.put_with(account_id, chunked_upload_id, &params).await
+    }
+
     /**
      * Deletes a chunked upload.
      *
@@ -194,3 +225,130 @@ impl ChunkedUploads {
             .await
     }
 }
+
+/// The query parameters of `ChunkedUploads::get_upload_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChunkedUploadsGetUploadParams {
+    /// (Optional) This parameter enables you to include additional attribute data in the response. The valid value for this method is `checksum`, which returns an SHA256 checksum of the content of the chunked upload in the response. You can use compare this checksum against your own checksum of the original content to verify that there are no missing parts before you attempt to commit the chunked upload.
+    pub include: Option<String>,
+}
+
+impl ChunkedUploadsGetUploadParams {
+    pub fn include<T: ToString>(mut self, include: T) -> Self {
+        self.include = Some(include.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ChunkedUploads::put_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChunkedUploadsPutParams {
+    /// (Required) You must use this query parameter with the value `commit`, which affirms the request to validate and prepare the chunked upload for use with other API calls.
+    pub action: Option<String>,
+}
+
+impl ChunkedUploadsPutParams {
+    pub fn action<T: ToString>(mut self, action: T) -> Self {
+        self.action = Some(action.to_string());
+        self
+    }
+}
+
+/// The functions of [`ChunkedUploads`], for code that takes them as a trait, so that it can be
+/// tested with the `MockChunkedUploadsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ChunkedUploadsApi: Send + Sync {
+    /// See [`ChunkedUploads::post`].
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> Result<crate::types::ChunkedUploadResponse>;
+
+    /// See [`ChunkedUploads::get_upload_with`].
+    async fn get_upload_with(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        params: &ChunkedUploadsGetUploadParams,
+    ) -> Result<crate::types::ChunkedUploadResponse>;
+
+    /// See [`ChunkedUploads::put_with`].
+    async fn put_with(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        params: &ChunkedUploadsPutParams,
+    ) -> Result<crate::types::ChunkedUploadResponse>;
+
+    /// See [`ChunkedUploads::delete_upload`].
+    async fn delete_upload(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+    ) -> Result<crate::types::ChunkedUploadResponse>;
+
+    /// See [`ChunkedUploads::put_upload_part`].
+    async fn put_upload_part(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        chunked_upload_part_seq: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> Result<crate::types::ChunkedUploadResponse>;
+}
+
+#[async_trait::async_trait]
+impl ChunkedUploadsApi for ChunkedUploads {
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        ChunkedUploads::post(self, account_id, body).await
+    }
+
+    async fn get_upload_with(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        params: &ChunkedUploadsGetUploadParams,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        ChunkedUploads::get_upload_with(self, account_id, chunked_upload_id, params).await
+    }
+
+    async fn put_with(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        params: &ChunkedUploadsPutParams,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        ChunkedUploads::put_with(self, account_id, chunked_upload_id, params).await
+    }
+
+    async fn delete_upload(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        ChunkedUploads::delete_upload(self, account_id, chunked_upload_id).await
+    }
+
+    async fn put_upload_part(
+        &self,
+        account_id: &str,
+        chunked_upload_id: &str,
+        chunked_upload_part_seq: &str,
+        body: &crate::types::ChunkedUploadRequest,
+    ) -> Result<crate::types::ChunkedUploadResponse> {
+        ChunkedUploads::put_upload_part(
+            self,
+            account_id,
+            chunked_upload_id,
+            chunked_upload_part_seq,
+            body,
+        )
+        .await
+    }
+}
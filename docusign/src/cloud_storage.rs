@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CloudStorage {
     pub client: Client,
@@ -27,58 +25,35 @@ impl CloudStorage {
      *   Valid values are the service name ("Box") or the numerical serviceId ("4136").
      * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
      *   .
-     * * `cloud_storage_folder_path: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- An optional value that sets how many items are included in the response.
-     *   
-     *   The default setting for this is 25.
-     * * `order: &str` -- (Optional) The order in which to sort the results.
-     *   
-     *   Valid values are:
-     *   
-     *   
-     *   * `asc`: Ascending order.
-     *   * `desc`: Descending order.
-     * * `order_by: &str` -- (Optional) The file attribute to use to sort the results.
-     *   
-     *   Valid values are:
-     *   
-     *   * `modified`
-     *   * `name`.
-     * * `search_text: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- Indicates the starting point of the first item included in the response set. It uses a 0-based index. The default setting for this is 0.  .
+     * * `params: &CloudStorageFolderGetAllParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn folder_get_all(
+    pub async fn folder_get_all_with(
         &self,
         account_id: &str,
         service_id: &str,
         user_id: &str,
-        cloud_storage_folder_path: &str,
-        count: &str,
-        order: &str,
-        order_by: &str,
-        search_text: &str,
-        start_position: &str,
+        params: &CloudStorageFolderGetAllParams,
     ) -> Result<crate::types::ExternalFolder> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cloud_storage_folder_path.is_empty() {
+        if let Some(cloud_storage_folder_path) = &params.cloud_storage_folder_path {
             query_args.push((
                 "cloud_storage_folder_path".to_string(),
                 cloud_storage_folder_path.to_string(),
             ));
         }
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !order_by.is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("order_by".to_string(), order_by.to_string()));
         }
-        if !search_text.is_empty() {
+        if let Some(search_text) = &params.search_text {
             query_args.push(("search_text".to_string(), search_text.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -93,6 +68,35 @@ impl CloudStorage {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `folder_get_all_with` and pass the query parameters as a `CloudStorageFolderGetAllParams` instead.
+     */
+    #[deprecated(note = "use `folder_get_all_with` instead")]
+    pub async fn folder_get_all(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        cloud_storage_folder_path: &str,
+        count: &str,
+        order: &str,
+        order_by: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::ExternalFolder> {
+        let params = CloudStorageFolderGetAllParams {
+            cloud_storage_folder_path: Some(cloud_storage_folder_path.to_string())
+                .filter(|s| !s.is_empty()),
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            order_by: Some(order_by.to_string()).filter(|s| !s.is_empty()),
+            search_text: Some(search_text.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.folder_get_all_with(account_id, service_id, user_id, &params)
+            .await
+    }
+
     /**
      * Gets a list of items from a cloud storage provider.
      *
@@ -111,67 +115,42 @@ impl CloudStorage {
      *   Valid values are the service name ("Box") or the numerical serviceId ("4136").
      * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
      *   .
-     * * `cloud_storage_folder_path: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `cloud_storage_folderid_plain: &str` -- A plain-text folder id that you can use as an alternative to the existing folder id. This property is mainly used for rooms. Enter multiple folder ids as a comma-separated list.
-     * * `count: &str` -- An optional value that sets how many items are included in the response.
-     *   
-     *   The default setting for this is 25.
-     * * `order: &str` -- (Optional) The order in which to sort the results.
-     *   
-     *   Valid values are:
-     *   
-     *   
-     *   * `asc`: Ascending order.
-     *   * `desc`: Descending order.
-     * * `order_by: &str` -- (Optional) The file attribute to use to sort the results.
-     *   
-     *   Valid values are:
-     *   
-     *   * `modified`
-     *   * `name`.
-     * * `search_text: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- The starting index position in the result set from which to start returning values. The default setting is `0`.
+     * * `params: &CloudStorageFolderGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn folder_get(
+    pub async fn folder_get_with(
         &self,
         account_id: &str,
         folder_id: &str,
         service_id: &str,
         user_id: &str,
-        cloud_storage_folder_path: &str,
-        cloud_storage_folderid_plain: &str,
-        count: &str,
-        order: &str,
-        order_by: &str,
-        search_text: &str,
-        start_position: &str,
+        params: &CloudStorageFolderGetParams,
     ) -> Result<crate::types::ExternalFolder> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cloud_storage_folder_path.is_empty() {
+        if let Some(cloud_storage_folder_path) = &params.cloud_storage_folder_path {
             query_args.push((
                 "cloud_storage_folder_path".to_string(),
                 cloud_storage_folder_path.to_string(),
             ));
         }
-        if !cloud_storage_folderid_plain.is_empty() {
+        if let Some(cloud_storage_folderid_plain) = &params.cloud_storage_folderid_plain {
             query_args.push((
                 "cloud_storage_folderid_plain".to_string(),
                 cloud_storage_folderid_plain.to_string(),
             ));
         }
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !order_by.is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("order_by".to_string(), order_by.to_string()));
         }
-        if !search_text.is_empty() {
+        if let Some(search_text) = &params.search_text {
             query_args.push(("search_text".to_string(), search_text.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -186,4 +165,221 @@ impl CloudStorage {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `folder_get_with` and pass the query parameters as a `CloudStorageFolderGetParams` instead.
+     */
+    #[deprecated(note = "use `folder_get_with` instead")]
+    pub async fn folder_get(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        service_id: &str,
+        user_id: &str,
+        cloud_storage_folder_path: &str,
+        cloud_storage_folderid_plain: &str,
+        count: &str,
+        order: &str,
+        order_by: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::ExternalFolder> {
+        let params = CloudStorageFolderGetParams {
+            cloud_storage_folder_path: Some(cloud_storage_folder_path.to_string())
+                .filter(|s| !s.is_empty()),
+            cloud_storage_folderid_plain: Some(cloud_storage_folderid_plain.to_string())
+                .filter(|s| !s.is_empty()),
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            order_by: Some(order_by.to_string()).filter(|s| !s.is_empty()),
+            search_text: Some(search_text.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.folder_get_with(account_id, folder_id, service_id, user_id, &params)
+            .await
+    }
+}
+
+/// The query parameters of `CloudStorage::folder_get_all_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CloudStorageFolderGetAllParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub cloud_storage_folder_path: Option<String>,
+    /// An optional value that sets how many items are included in the response.
+    ///
+    /// The default setting for this is 25.
+    pub count: Option<String>,
+    /// (Optional) The order in which to sort the results.
+    ///
+    /// Valid values are:
+    ///
+    ///
+    /// * `asc`: Ascending order.
+    /// * `desc`: Descending order.
+    pub order: Option<String>,
+    /// (Optional) The file attribute to use to sort the results.
+    ///
+    /// Valid values are:
+    ///
+    /// * `modified`
+    /// * `name`.
+    pub order_by: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub search_text: Option<String>,
+    /// Indicates the starting point of the first item included in the response set. It uses a 0-based index. The default setting for this is 0.  .
+    pub start_position: Option<String>,
+}
+
+impl CloudStorageFolderGetAllParams {
+    pub fn cloud_storage_folder_path<T: ToString>(mut self, cloud_storage_folder_path: T) -> Self {
+        self.cloud_storage_folder_path = Some(cloud_storage_folder_path.to_string());
+        self
+    }
+
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn order_by<T: ToString>(mut self, order_by: T) -> Self {
+        self.order_by = Some(order_by.to_string());
+        self
+    }
+
+    pub fn search_text<T: ToString>(mut self, search_text: T) -> Self {
+        self.search_text = Some(search_text.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+}
+
+/// The query parameters of `CloudStorage::folder_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CloudStorageFolderGetParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub cloud_storage_folder_path: Option<String>,
+    /// A plain-text folder id that you can use as an alternative to the existing folder id. This property is mainly used for rooms. Enter multiple folder ids as a comma-separated list.
+    pub cloud_storage_folderid_plain: Option<String>,
+    /// An optional value that sets how many items are included in the response.
+    ///
+    /// The default setting for this is 25.
+    pub count: Option<String>,
+    /// (Optional) The order in which to sort the results.
+    ///
+    /// Valid values are:
+    ///
+    ///
+    /// * `asc`: Ascending order.
+    /// * `desc`: Descending order.
+    pub order: Option<String>,
+    /// (Optional) The file attribute to use to sort the results.
+    ///
+    /// Valid values are:
+    ///
+    /// * `modified`
+    /// * `name`.
+    pub order_by: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub search_text: Option<String>,
+    /// The starting index position in the result set from which to start returning values. The default setting is `0`.
+    pub start_position: Option<String>,
+}
+
+impl CloudStorageFolderGetParams {
+    pub fn cloud_storage_folder_path<T: ToString>(mut self, cloud_storage_folder_path: T) -> Self {
+        self.cloud_storage_folder_path = Some(cloud_storage_folder_path.to_string());
+        self
+    }
+
+    pub fn cloud_storage_folderid_plain<T: ToString>(
+        mut self,
+        cloud_storage_folderid_plain: T,
+    ) -> Self {
+        self.cloud_storage_folderid_plain = Some(cloud_storage_folderid_plain.to_string());
+        self
+    }
+
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn order_by<T: ToString>(mut self, order_by: T) -> Self {
+        self.order_by = Some(order_by.to_string());
+        self
+    }
+
+    pub fn search_text<T: ToString>(mut self, search_text: T) -> Self {
+        self.search_text = Some(search_text.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+}
+
+/// The functions of [`CloudStorage`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCloudStorageApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CloudStorageApi: Send + Sync {
+    /// See [`CloudStorage::folder_get_all_with`].
+    async fn folder_get_all_with(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        params: &CloudStorageFolderGetAllParams,
+    ) -> Result<crate::types::ExternalFolder>;
+
+    /// See [`CloudStorage::folder_get_with`].
+    async fn folder_get_with(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        service_id: &str,
+        user_id: &str,
+        params: &CloudStorageFolderGetParams,
+    ) -> Result<crate::types::ExternalFolder>;
+}
+
+#[async_trait::async_trait]
+impl CloudStorageApi for CloudStorage {
+    async fn folder_get_all_with(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        params: &CloudStorageFolderGetAllParams,
+    ) -> Result<crate::types::ExternalFolder> {
+        CloudStorage::folder_get_all_with(self, account_id, service_id, user_id, params).await
+    }
+
+    async fn folder_get_with(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        service_id: &str,
+        user_id: &str,
+        params: &CloudStorageFolderGetParams,
+    ) -> Result<crate::types::ExternalFolder> {
+        CloudStorage::folder_get_with(self, account_id, folder_id, service_id, user_id, params)
+            .await
+    }
 }
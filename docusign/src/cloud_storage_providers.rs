@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CloudStorageProviders {
     pub client: Client,
@@ -25,19 +23,16 @@ impl CloudStorageProviders {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
      *   .
-     * * `redirect_url: &str` --  The URL the user is redirected to after the cloud storage provider authenticates the user. Using this will append the redirectUrl to the authenticationUrl.
-     *   
-     *   The redirectUrl is restricted to URLs in the docusign.com or docusign.net domains.
-     *    .
+     * * `params: &CloudStorageProvidersCloudStorageGetProviderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn cloud_storage_get_provider(
+    pub async fn cloud_storage_get_provider_with(
         &self,
         account_id: &str,
         user_id: &str,
-        redirect_url: &str,
+        params: &CloudStorageProvidersCloudStorageGetProviderParams,
     ) -> Result<crate::types::CloudStorageProvidersData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !redirect_url.is_empty() {
+        if let Some(redirect_url) = &params.redirect_url {
             query_args.push(("redirectUrl".to_string(), redirect_url.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -51,6 +46,23 @@ impl CloudStorageProviders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `cloud_storage_get_provider_with` and pass the query parameters as a `CloudStorageProvidersCloudStorageGetProviderParams` instead.
+     */
+    #[deprecated(note = "use `cloud_storage_get_provider_with` instead")]
+    pub async fn cloud_storage_get_provider(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        redirect_url: &str,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        let params = CloudStorageProvidersCloudStorageGetProviderParams {
+            redirect_url: Some(redirect_url.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.cloud_storage_get_provider_with(account_id, user_id, &params)
+            .await
+    }
+
     /**
      * Configures the redirect URL information  for one or more cloud storage providers for the specified user.
      *
@@ -126,20 +138,17 @@ impl CloudStorageProviders {
      *   Valid values are the service name ("Box") or the numerical serviceId ("4136").
      * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
      *   .
-     * * `redirect_url: &str` --  The URL the user is redirected to after the cloud storage provider authenticates the user. Using this will append the redirectUrl to the authenticationUrl.
-     *   
-     *   The redirectUrl is restricted to URLs in the docusign.com or docusign.net domains.
-     *    .
+     * * `params: &CloudStorageProvidersCloudStorageGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn cloud_storage_get(
+    pub async fn cloud_storage_get_with(
         &self,
         account_id: &str,
         service_id: &str,
         user_id: &str,
-        redirect_url: &str,
+        params: &CloudStorageProvidersCloudStorageGetParams,
     ) -> Result<crate::types::CloudStorageProvidersData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !redirect_url.is_empty() {
+        if let Some(redirect_url) = &params.redirect_url {
             query_args.push(("redirectUrl".to_string(), redirect_url.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -154,6 +163,24 @@ impl CloudStorageProviders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `cloud_storage_get_with` and pass the query parameters as a `CloudStorageProvidersCloudStorageGetParams` instead.
+     */
+    #[deprecated(note = "use `cloud_storage_get_with` instead")]
+    pub async fn cloud_storage_get(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        redirect_url: &str,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        let params = CloudStorageProvidersCloudStorageGetParams {
+            redirect_url: Some(redirect_url.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.cloud_storage_get_with(account_id, service_id, user_id, &params)
+            .await
+    }
+
     /**
      * Deletes the user authentication information for the specified cloud storage provider.
      *
@@ -186,3 +213,135 @@ impl CloudStorageProviders {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `CloudStorageProviders::cloud_storage_get_provider_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CloudStorageProvidersCloudStorageGetProviderParams {
+    ///  The URL the user is redirected to after the cloud storage provider authenticates the user. Using this will append the redirectUrl to the authenticationUrl.
+    ///
+    ///  The redirectUrl is restricted to URLs in the docusign.com or docusign.net domains.
+    ///  .
+    pub redirect_url: Option<String>,
+}
+
+impl CloudStorageProvidersCloudStorageGetProviderParams {
+    pub fn redirect_url<T: ToString>(mut self, redirect_url: T) -> Self {
+        self.redirect_url = Some(redirect_url.to_string());
+        self
+    }
+}
+
+/// The query parameters of `CloudStorageProviders::cloud_storage_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CloudStorageProvidersCloudStorageGetParams {
+    ///  The URL the user is redirected to after the cloud storage provider authenticates the user. Using this will append the redirectUrl to the authenticationUrl.
+    ///
+    ///  The redirectUrl is restricted to URLs in the docusign.com or docusign.net domains.
+    ///  .
+    pub redirect_url: Option<String>,
+}
+
+impl CloudStorageProvidersCloudStorageGetParams {
+    pub fn redirect_url<T: ToString>(mut self, redirect_url: T) -> Self {
+        self.redirect_url = Some(redirect_url.to_string());
+        self
+    }
+}
+
+/// The functions of [`CloudStorageProviders`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCloudStorageProvidersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CloudStorageProvidersApi: Send + Sync {
+    /// See [`CloudStorageProviders::cloud_storage_get_provider_with`].
+    async fn cloud_storage_get_provider_with(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        params: &CloudStorageProvidersCloudStorageGetProviderParams,
+    ) -> Result<crate::types::CloudStorageProvidersData>;
+
+    /// See [`CloudStorageProviders::cloud_storage_post`].
+    async fn cloud_storage_post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> Result<crate::types::CloudStorageProvidersData>;
+
+    /// See [`CloudStorageProviders::cloud_storage_delete_providers`].
+    async fn cloud_storage_delete_providers(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> Result<crate::types::CloudStorageProvidersData>;
+
+    /// See [`CloudStorageProviders::cloud_storage_get_with`].
+    async fn cloud_storage_get_with(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        params: &CloudStorageProvidersCloudStorageGetParams,
+    ) -> Result<crate::types::CloudStorageProvidersData>;
+
+    /// See [`CloudStorageProviders::cloud_storage_delete`].
+    async fn cloud_storage_delete(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::CloudStorageProvidersData>;
+}
+
+#[async_trait::async_trait]
+impl CloudStorageProvidersApi for CloudStorageProviders {
+    async fn cloud_storage_get_provider_with(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        params: &CloudStorageProvidersCloudStorageGetProviderParams,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        CloudStorageProviders::cloud_storage_get_provider_with(self, account_id, user_id, params)
+            .await
+    }
+
+    async fn cloud_storage_post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        CloudStorageProviders::cloud_storage_post(self, account_id, user_id, body).await
+    }
+
+    async fn cloud_storage_delete_providers(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CloudStorageProvidersData,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        CloudStorageProviders::cloud_storage_delete_providers(self, account_id, user_id, body).await
+    }
+
+    async fn cloud_storage_get_with(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+        params: &CloudStorageProvidersCloudStorageGetParams,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        CloudStorageProviders::cloud_storage_get_with(self, account_id, service_id, user_id, params)
+            .await
+    }
+
+    async fn cloud_storage_delete(
+        &self,
+        account_id: &str,
+        service_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::CloudStorageProvidersData> {
+        CloudStorageProviders::cloud_storage_delete(self, account_id, service_id, user_id).await
+    }
+}
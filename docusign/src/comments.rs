@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Comments {
     pub client: Client,
@@ -25,16 +23,71 @@ impl Comments {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `encoding: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &CommentsGetTranscriptParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_transcript_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &CommentsGetTranscriptParams,
+    ) -> Result<crate::Download> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(encoding) = &params.encoding {
+            query_args.push(("encoding".to_string(), encoding.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/comments/transcript?{}",
+            crate::progenitor_support::encode_path(account_id),
+            crate::progenitor_support::encode_path(envelope_id),
+            query_
+        );
+
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `get_transcript_with` and pass the query parameters as a `CommentsGetTranscriptParams` instead.
+     */
+    #[deprecated(note = "use `get_transcript_with` instead")]
     pub async fn get_transcript(
         &self,
         account_id: &str,
         envelope_id: &str,
         encoding: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
+        let params = CommentsGetTranscriptParams {
+            encoding: Some(encoding.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_transcript_with(account_id, envelope_id, &params)
+            .await
+    }
+
+    /**
+     * Gets a PDF transcript of all of the comments in an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/comments/transcript` endpoint.
+     *
+     * As opposed to `get_transcript`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Retrieves a PDF file containing all of the comments that senders and recipients have added to the documents in an envelope.
+     *
+     * **Note**: Comments are disabled by default. To use the comments feature, an account administrator must enable comments on the account (in the `accountSettingsInformation` object, set the `enableSigningExtensionComments` property to **true**).
+     *
+     * **Parameters:**
+     *
+     * * `params: &CommentsGetTranscriptParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_transcript_stream_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &CommentsGetTranscriptParams,
+    ) -> Result<crate::DownloadStream> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !encoding.is_empty() {
+        if let Some(encoding) = &params.encoding {
             query_args.push(("encoding".to_string(), encoding.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -45,6 +98,82 @@ impl Comments {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `get_transcript_stream_with` and pass the query parameters as a `CommentsGetTranscriptParams` instead.
+     */
+    #[deprecated(note = "use `get_transcript_stream_with` instead")]
+    pub async fn get_transcript_stream(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        encoding: &str,
+    ) -> Result<crate::DownloadStream> {
+        let params = CommentsGetTranscriptParams {
+            encoding: Some(encoding.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_transcript_stream_with(account_id, envelope_id, &params)
+            .await
+    }
+}
+
+/// The query parameters of `Comments::get_transcript_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CommentsGetTranscriptParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub encoding: Option<String>,
+}
+
+impl CommentsGetTranscriptParams {
+    pub fn encoding<T: ToString>(mut self, encoding: T) -> Self {
+        self.encoding = Some(encoding.to_string());
+        self
+    }
+}
+
+/// The functions of [`Comments`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCommentsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CommentsApi: Send + Sync {
+    /// See [`Comments::get_transcript_with`].
+    async fn get_transcript_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &CommentsGetTranscriptParams,
+    ) -> Result<crate::Download>;
+
+    /// See [`Comments::get_transcript_stream_with`].
+    async fn get_transcript_stream_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &CommentsGetTranscriptParams,
+    ) -> Result<crate::DownloadStream>;
+}
+
+#[async_trait::async_trait]
+impl CommentsApi for Comments {
+    async fn get_transcript_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &CommentsGetTranscriptParams,
+    ) -> Result<crate::Download> {
+        Comments::get_transcript_with(self, account_id, envelope_id, params).await
+    }
+
+    async fn get_transcript_stream_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &CommentsGetTranscriptParams,
+    ) -> Result<crate::DownloadStream> {
+        Comments::get_transcript_stream_with(self, account_id, envelope_id, params).await
     }
 }
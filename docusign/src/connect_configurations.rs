@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ConnectConfigurations {
     pub client: Client,
@@ -161,55 +159,34 @@ impl ConnectConfigurations {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `connect_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `email_substring: &str` -- Filters returned user records by full email address or a substring of email address.
-     * * `list_included_users: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
-     * * `status: &str` -- Filters the results by user status.
-     *   You can specify a comma-separated
-     *   list of the following statuses:
-     *   
-     *   * ActivationRequired
-     *   * ActivationSent
-     *   * Active
-     *   * Closed
-     *   * Disabled
-     *   .
-     * * `user_name_substring: &str` -- Filters results based on a full or partial user name.
-     *   
-     *   **Note**: When you enter a partial user name, you do not use a wildcard character.
+     * * `params: &ConnectConfigurationsConnectGetUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn connect_get_user(
+    pub async fn connect_get_user_with(
         &self,
         account_id: &str,
         connect_id: &str,
-        count: &str,
-        email_substring: &str,
-        list_included_users: &str,
-        start_position: &str,
-        status: &str,
-        user_name_substring: &str,
+        params: &ConnectConfigurationsConnectGetUserParams,
     ) -> Result<crate::types::IntegratedUserInfoList> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !email_substring.is_empty() {
+        if let Some(email_substring) = &params.email_substring {
             query_args.push(("email_substring".to_string(), email_substring.to_string()));
         }
-        if !list_included_users.is_empty() {
+        if let Some(list_included_users) = &params.list_included_users {
             query_args.push((
                 "list_included_users".to_string(),
                 list_included_users.to_string(),
             ));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !user_name_substring.is_empty() {
+        if let Some(user_name_substring) = &params.user_name_substring {
             query_args.push((
                 "user_name_substring".to_string(),
                 user_name_substring.to_string(),
@@ -225,4 +202,185 @@ impl ConnectConfigurations {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `connect_get_user_with` and pass the query parameters as a `ConnectConfigurationsConnectGetUserParams` instead.
+     */
+    #[deprecated(note = "use `connect_get_user_with` instead")]
+    pub async fn connect_get_user(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+        count: &str,
+        email_substring: &str,
+        list_included_users: &str,
+        start_position: &str,
+        status: &str,
+        user_name_substring: &str,
+    ) -> Result<crate::types::IntegratedUserInfoList> {
+        let params = ConnectConfigurationsConnectGetUserParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            email_substring: Some(email_substring.to_string()).filter(|s| !s.is_empty()),
+            list_included_users: Some(list_included_users.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            user_name_substring: Some(user_name_substring.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.connect_get_user_with(account_id, connect_id, &params)
+            .await
+    }
+}
+
+/// The query parameters of `ConnectConfigurations::connect_get_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectConfigurationsConnectGetUserParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub count: Option<String>,
+    /// Filters returned user records by full email address or a substring of email address.
+    pub email_substring: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub list_included_users: Option<String>,
+    /// The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
+    pub start_position: Option<String>,
+    /// Filters the results by user status.
+    /// You can specify a comma-separated
+    /// list of the following statuses:
+    ///
+    /// * ActivationRequired
+    /// * ActivationSent
+    /// * Active
+    /// * Closed
+    /// * Disabled
+    ///   .
+    pub status: Option<String>,
+    /// Filters results based on a full or partial user name.
+    ///
+    /// **Note**: When you enter a partial user name, you do not use a wildcard character.
+    pub user_name_substring: Option<String>,
+}
+
+impl ConnectConfigurationsConnectGetUserParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn email_substring<T: ToString>(mut self, email_substring: T) -> Self {
+        self.email_substring = Some(email_substring.to_string());
+        self
+    }
+
+    pub fn list_included_users<T: ToString>(mut self, list_included_users: T) -> Self {
+        self.list_included_users = Some(list_included_users.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn user_name_substring<T: ToString>(mut self, user_name_substring: T) -> Self {
+        self.user_name_substring = Some(user_name_substring.to_string());
+        self
+    }
+}
+
+/// The functions of [`ConnectConfigurations`], for code that takes them as a trait, so that it can be
+/// tested with the `MockConnectConfigurationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ConnectConfigurationsApi: Send + Sync {
+    /// See [`ConnectConfigurations::connect_get_config`].
+    async fn connect_get_config(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::ConnectConfigResults>;
+
+    /// See [`ConnectConfigurations::connect_put_configuration`].
+    async fn connect_put_configuration(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> Result<crate::types::ConnectCustomConfiguration>;
+
+    /// See [`ConnectConfigurations::connect_post_configuration`].
+    async fn connect_post_configuration(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> Result<crate::types::ConnectCustomConfiguration>;
+
+    /// See [`ConnectConfigurations::connect_get_config_connect_configurations`].
+    async fn connect_get_config_connect_configurations(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> Result<crate::types::ConnectConfigResults>;
+
+    /// See [`ConnectConfigurations::connect_delete_config`].
+    async fn connect_delete_config(&self, account_id: &str, connect_id: &str) -> Result<()>;
+
+    /// See [`ConnectConfigurations::connect_get_user_with`].
+    async fn connect_get_user_with(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+        params: &ConnectConfigurationsConnectGetUserParams,
+    ) -> Result<crate::types::IntegratedUserInfoList>;
+}
+
+#[async_trait::async_trait]
+impl ConnectConfigurationsApi for ConnectConfigurations {
+    async fn connect_get_config(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::ConnectConfigResults> {
+        ConnectConfigurations::connect_get_config(self, account_id).await
+    }
+
+    async fn connect_put_configuration(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> Result<crate::types::ConnectCustomConfiguration> {
+        ConnectConfigurations::connect_put_configuration(self, account_id, body).await
+    }
+
+    async fn connect_post_configuration(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectCustomConfiguration,
+    ) -> Result<crate::types::ConnectCustomConfiguration> {
+        ConnectConfigurations::connect_post_configuration(self, account_id, body).await
+    }
+
+    async fn connect_get_config_connect_configurations(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+    ) -> Result<crate::types::ConnectConfigResults> {
+        ConnectConfigurations::connect_get_config_connect_configurations(
+            self, account_id, connect_id,
+        )
+        .await
+    }
+
+    async fn connect_delete_config(&self, account_id: &str, connect_id: &str) -> Result<()> {
+        ConnectConfigurations::connect_delete_config(self, account_id, connect_id).await
+    }
+
+    async fn connect_get_user_with(
+        &self,
+        account_id: &str,
+        connect_id: &str,
+        params: &ConnectConfigurationsConnectGetUserParams,
+    ) -> Result<crate::types::IntegratedUserInfoList> {
+        ConnectConfigurations::connect_get_user_with(self, account_id, connect_id, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ConnectEvents {
     pub client: Client,
@@ -75,24 +73,18 @@ impl ConnectEvents {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `from_date: &str` -- The start date for a date range in UTC DateTime format.
-     *   
-     *   **Note**: If this property is null, no date filtering is applied.
-     * * `to_date: &str` -- The end of a search date range in UTC DateTime format. When you use this parameter, only templates created up to this date and time are returned.
-     *   
-     *   **Note**: If this property is null, the value defaults to the current date.
+     * * `params: &ConnectEventsConnectFailuresGetLogParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn connect_failures_get_log(
+    pub async fn connect_failures_get_log_with(
         &self,
         account_id: &str,
-        from_date: &str,
-        to_date: &str,
+        params: &ConnectEventsConnectFailuresGetLogParams,
     ) -> Result<crate::types::ConnectLogs> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from_date.is_empty() {
+        if let Some(from_date) = &params.from_date {
             query_args.push(("from_date".to_string(), from_date.to_string()));
         }
-        if !to_date.is_empty() {
+        if let Some(to_date) = &params.to_date {
             query_args.push(("to_date".to_string(), to_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -105,6 +97,24 @@ impl ConnectEvents {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `connect_failures_get_log_with` and pass the query parameters as a `ConnectEventsConnectFailuresGetLogParams` instead.
+     */
+    #[deprecated(note = "use `connect_failures_get_log_with` instead")]
+    pub async fn connect_failures_get_log(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::ConnectLogs> {
+        let params = ConnectEventsConnectFailuresGetLogParams {
+            from_date: Some(from_date.to_string()).filter(|s| !s.is_empty()),
+            to_date: Some(to_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.connect_failures_get_log_with(account_id, &params)
+            .await
+    }
+
     /**
      * Deletes a Connect failure log entry.
      *
@@ -143,24 +153,18 @@ impl ConnectEvents {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `from_date: &str` -- The start date for a date range in UTC DateTime format.
-     *   
-     *   **Note**: If this property is null, no date filtering is applied.
-     * * `to_date: &str` -- The end of a search date range in UTC DateTime format. When you use this parameter, only templates created up to this date and time are returned.
-     *   
-     *   **Note**: If this property is null, the value defaults to the current date.
+     * * `params: &ConnectEventsConnectLogGetLogParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn connect_log_get_log(
+    pub async fn connect_log_get_log_with(
         &self,
         account_id: &str,
-        from_date: &str,
-        to_date: &str,
+        params: &ConnectEventsConnectLogGetLogParams,
     ) -> Result<crate::types::ConnectLogs> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from_date.is_empty() {
+        if let Some(from_date) = &params.from_date {
             query_args.push(("from_date".to_string(), from_date.to_string()));
         }
-        if !to_date.is_empty() {
+        if let Some(to_date) = &params.to_date {
             query_args.push(("to_date".to_string(), to_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -173,6 +177,23 @@ impl ConnectEvents {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `connect_log_get_log_with` and pass the query parameters as a `ConnectEventsConnectLogGetLogParams` instead.
+     */
+    #[deprecated(note = "use `connect_log_get_log_with` instead")]
+    pub async fn connect_log_get_log(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::ConnectLogs> {
+        let params = ConnectEventsConnectLogGetLogParams {
+            from_date: Some(from_date.to_string()).filter(|s| !s.is_empty()),
+            to_date: Some(to_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.connect_log_get_log_with(account_id, &params).await
+    }
+
     /**
      * Deletes a list of Connect log entries.
      *
@@ -206,16 +227,16 @@ impl ConnectEvents {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `log_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `additional_info: &str` -- When set to **true**, the response includes the `connectDebugLog` information.
+     * * `params: &ConnectEventsConnectLogGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn connect_log_get(
+    pub async fn connect_log_get_with(
         &self,
         account_id: &str,
         log_id: &str,
-        additional_info: &str,
+        params: &ConnectEventsConnectLogGetParams,
     ) -> Result<crate::types::ConnectLog> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !additional_info.is_empty() {
+        if let Some(additional_info) = &params.additional_info {
             query_args.push(("additional_info".to_string(), additional_info.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -229,6 +250,22 @@ impl ConnectEvents {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `connect_log_get_with` and pass the query parameters as a `ConnectEventsConnectLogGetParams` instead.
+     */
+    #[deprecated(note = "use `connect_log_get_with` instead")]
+    pub async fn connect_log_get(
+        &self,
+        account_id: &str,
+        log_id: &str,
+        additional_info: &str,
+    ) -> Result<crate::types::ConnectLog> {
+        let params = ConnectEventsConnectLogGetParams {
+            additional_info: Some(additional_info.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.connect_log_get_with(account_id, log_id, &params).await
+    }
+
     /**
      * Deletes a specified Connect log entry.
      *
@@ -252,3 +289,182 @@ impl ConnectEvents {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `ConnectEvents::connect_failures_get_log_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectEventsConnectFailuresGetLogParams {
+    /// The start date for a date range in UTC DateTime format.
+    ///
+    /// **Note**: If this property is null, no date filtering is applied.
+    pub from_date: Option<String>,
+    /// The end of a search date range in UTC DateTime format. When you use this parameter, only templates created up to this date and time are returned.
+    ///
+    /// **Note**: If this property is null, the value defaults to the current date.
+    pub to_date: Option<String>,
+}
+
+impl ConnectEventsConnectFailuresGetLogParams {
+    pub fn from_date<T: ToString>(mut self, from_date: T) -> Self {
+        self.from_date = Some(from_date.to_string());
+        self
+    }
+
+    pub fn to_date<T: ToString>(mut self, to_date: T) -> Self {
+        self.to_date = Some(to_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ConnectEvents::connect_log_get_log_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectEventsConnectLogGetLogParams {
+    /// The start date for a date range in UTC DateTime format.
+    ///
+    /// **Note**: If this property is null, no date filtering is applied.
+    pub from_date: Option<String>,
+    /// The end of a search date range in UTC DateTime format. When you use this parameter, only templates created up to this date and time are returned.
+    ///
+    /// **Note**: If this property is null, the value defaults to the current date.
+    pub to_date: Option<String>,
+}
+
+impl ConnectEventsConnectLogGetLogParams {
+    pub fn from_date<T: ToString>(mut self, from_date: T) -> Self {
+        self.from_date = Some(from_date.to_string());
+        self
+    }
+
+    pub fn to_date<T: ToString>(mut self, to_date: T) -> Self {
+        self.to_date = Some(to_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ConnectEvents::connect_log_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectEventsConnectLogGetParams {
+    /// When set to **true**, the response includes the `connectDebugLog` information.
+    pub additional_info: Option<String>,
+}
+
+impl ConnectEventsConnectLogGetParams {
+    pub fn additional_info<T: ToString>(mut self, additional_info: T) -> Self {
+        self.additional_info = Some(additional_info.to_string());
+        self
+    }
+}
+
+/// The functions of [`ConnectEvents`], for code that takes them as a trait, so that it can be
+/// tested with the `MockConnectEventsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ConnectEventsApi: Send + Sync {
+    /// See [`ConnectEvents::connect_publish_put_retry`].
+    async fn connect_publish_put_retry(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectFailureFilter,
+    ) -> Result<crate::types::ConnectFailureResults>;
+
+    /// See [`ConnectEvents::connect_publish_put_retry_envelope`].
+    async fn connect_publish_put_retry_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ConnectFailureResults>;
+
+    /// See [`ConnectEvents::connect_failures_get_log_with`].
+    async fn connect_failures_get_log_with(
+        &self,
+        account_id: &str,
+        params: &ConnectEventsConnectFailuresGetLogParams,
+    ) -> Result<crate::types::ConnectLogs>;
+
+    /// See [`ConnectEvents::connect_failures_delete_failure_log`].
+    async fn connect_failures_delete_failure_log(
+        &self,
+        account_id: &str,
+        failure_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ConnectEvents::connect_log_get_log_with`].
+    async fn connect_log_get_log_with(
+        &self,
+        account_id: &str,
+        params: &ConnectEventsConnectLogGetLogParams,
+    ) -> Result<crate::types::ConnectLogs>;
+
+    /// See [`ConnectEvents::connect_log_delete_logs`].
+    async fn connect_log_delete_logs(&self, account_id: &str) -> Result<()>;
+
+    /// See [`ConnectEvents::connect_log_get_with`].
+    async fn connect_log_get_with(
+        &self,
+        account_id: &str,
+        log_id: &str,
+        params: &ConnectEventsConnectLogGetParams,
+    ) -> Result<crate::types::ConnectLog>;
+
+    /// See [`ConnectEvents::connect_log_delete`].
+    async fn connect_log_delete(&self, account_id: &str, log_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ConnectEventsApi for ConnectEvents {
+    async fn connect_publish_put_retry(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConnectFailureFilter,
+    ) -> Result<crate::types::ConnectFailureResults> {
+        ConnectEvents::connect_publish_put_retry(self, account_id, body).await
+    }
+
+    async fn connect_publish_put_retry_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::ConnectFailureResults> {
+        ConnectEvents::connect_publish_put_retry_envelope(self, account_id, envelope_id).await
+    }
+
+    async fn connect_failures_get_log_with(
+        &self,
+        account_id: &str,
+        params: &ConnectEventsConnectFailuresGetLogParams,
+    ) -> Result<crate::types::ConnectLogs> {
+        ConnectEvents::connect_failures_get_log_with(self, account_id, params).await
+    }
+
+    async fn connect_failures_delete_failure_log(
+        &self,
+        account_id: &str,
+        failure_id: &str,
+    ) -> Result<()> {
+        ConnectEvents::connect_failures_delete_failure_log(self, account_id, failure_id).await
+    }
+
+    async fn connect_log_get_log_with(
+        &self,
+        account_id: &str,
+        params: &ConnectEventsConnectLogGetLogParams,
+    ) -> Result<crate::types::ConnectLogs> {
+        ConnectEvents::connect_log_get_log_with(self, account_id, params).await
+    }
+
+    async fn connect_log_delete_logs(&self, account_id: &str) -> Result<()> {
+        ConnectEvents::connect_log_delete_logs(self, account_id).await
+    }
+
+    async fn connect_log_get_with(
+        &self,
+        account_id: &str,
+        log_id: &str,
+        params: &ConnectEventsConnectLogGetParams,
+    ) -> Result<crate::types::ConnectLog> {
+        ConnectEvents::connect_log_get_with(self, account_id, log_id, params).await
+    }
+
+    async fn connect_log_delete(&self, account_id: &str, log_id: &str) -> Result<()> {
+        ConnectEvents::connect_log_delete(self, account_id, log_id).await
+    }
+}
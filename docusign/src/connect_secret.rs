@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ConnectSecret {
     pub client: Client,
@@ -34,3 +32,19 @@ impl ConnectSecret {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`ConnectSecret`], for code that takes them as a trait, so that it can be
+/// tested with the `MockConnectSecretApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ConnectSecretApi: Send + Sync {
+    /// See [`ConnectSecret::connect_hmac_delete_secret`].
+    async fn connect_hmac_delete_secret(&self, account_id: &str, key_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ConnectSecretApi for ConnectSecret {
+    async fn connect_hmac_delete_secret(&self, account_id: &str, key_id: &str) -> Result<()> {
+        ConnectSecret::connect_hmac_delete_secret(self, account_id, key_id).await
+    }
+}
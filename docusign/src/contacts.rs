@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Contacts {
     pub client: Client,
@@ -114,19 +112,16 @@ impl Contacts {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `contact_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `cloud_provider: &str` -- (Optional) The cloud provider from which to retrieve the contacts. Valid values are:
-     *   
-     *   - `rooms`
-     *   - `docusignCore` (default).
+     * * `params: &ContactsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         account_id: &str,
         contact_id: &str,
-        cloud_provider: &str,
+        params: &ContactsGetParams,
     ) -> Result<crate::types::ContactGetResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cloud_provider.is_empty() {
+        if let Some(cloud_provider) = &params.cloud_provider {
             query_args.push(("cloud_provider".to_string(), cloud_provider.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -140,6 +135,22 @@ impl Contacts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `ContactsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+        cloud_provider: &str,
+    ) -> Result<crate::types::ContactGetResponse> {
+        let params = ContactsGetParams {
+            cloud_provider: Some(cloud_provider.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(account_id, contact_id, &params).await
+    }
+
     /**
      * Deletes a contact.
      *
@@ -166,3 +177,106 @@ impl Contacts {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Contacts::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ContactsGetParams {
+    /// (Optional) The cloud provider from which to retrieve the contacts. Valid values are:
+    ///
+    /// - `rooms`
+    /// - `docusignCore` (default).
+    pub cloud_provider: Option<String>,
+}
+
+impl ContactsGetParams {
+    pub fn cloud_provider<T: ToString>(mut self, cloud_provider: T) -> Self {
+        self.cloud_provider = Some(cloud_provider.to_string());
+        self
+    }
+}
+
+/// The functions of [`Contacts`], for code that takes them as a trait, so that it can be
+/// tested with the `MockContactsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ContactsApi: Send + Sync {
+    /// See [`Contacts::put`].
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse>;
+
+    /// See [`Contacts::post`].
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse>;
+
+    /// See [`Contacts::delete`].
+    async fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse>;
+
+    /// See [`Contacts::get_with`].
+    async fn get_with(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+        params: &ContactsGetParams,
+    ) -> Result<crate::types::ContactGetResponse>;
+
+    /// See [`Contacts::delete_contacts`].
+    async fn delete_contacts(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+    ) -> Result<crate::types::ContactUpdateResponse>;
+}
+
+#[async_trait::async_trait]
+impl ContactsApi for Contacts {
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse> {
+        Contacts::put(self, account_id, body).await
+    }
+
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse> {
+        Contacts::post(self, account_id, body).await
+    }
+
+    async fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::ContactModRequest,
+    ) -> Result<crate::types::ContactUpdateResponse> {
+        Contacts::delete(self, account_id, body).await
+    }
+
+    async fn get_with(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+        params: &ContactsGetParams,
+    ) -> Result<crate::types::ContactGetResponse> {
+        Contacts::get_with(self, account_id, contact_id, params).await
+    }
+
+    async fn delete_contacts(
+        &self,
+        account_id: &str,
+        contact_id: &str,
+    ) -> Result<crate::types::ContactUpdateResponse> {
+        Contacts::delete_contacts(self, account_id, contact_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CustomTabs {
     pub client: Client,
@@ -22,15 +20,15 @@ impl CustomTabs {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `custom_tab_only: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &CustomTabsTabsGetTabDefinitionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn tabs_get_tab_definition(
+    pub async fn tabs_get_tab_definition_with(
         &self,
         account_id: &str,
-        custom_tab_only: &str,
+        params: &CustomTabsTabsGetTabDefinitionParams,
     ) -> Result<crate::types::TabMetadataList> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_tab_only.is_empty() {
+        if let Some(custom_tab_only) = &params.custom_tab_only {
             query_args.push(("custom_tab_only".to_string(), custom_tab_only.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -43,6 +41,21 @@ impl CustomTabs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `tabs_get_tab_definition_with` and pass the query parameters as a `CustomTabsTabsGetTabDefinitionParams` instead.
+     */
+    #[deprecated(note = "use `tabs_get_tab_definition_with` instead")]
+    pub async fn tabs_get_tab_definition(
+        &self,
+        account_id: &str,
+        custom_tab_only: &str,
+    ) -> Result<crate::types::TabMetadataList> {
+        let params = CustomTabsTabsGetTabDefinitionParams {
+            custom_tab_only: Some(custom_tab_only.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.tabs_get_tab_definition_with(account_id, &params).await
+    }
+
     /**
      * Creates a custom tab.
      *
@@ -150,3 +163,95 @@ impl CustomTabs {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `CustomTabs::tabs_get_tab_definition_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomTabsTabsGetTabDefinitionParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub custom_tab_only: Option<String>,
+}
+
+impl CustomTabsTabsGetTabDefinitionParams {
+    pub fn custom_tab_only<T: ToString>(mut self, custom_tab_only: T) -> Self {
+        self.custom_tab_only = Some(custom_tab_only.to_string());
+        self
+    }
+}
+
+/// The functions of [`CustomTabs`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCustomTabsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CustomTabsApi: Send + Sync {
+    /// See [`CustomTabs::tabs_get_tab_definition_with`].
+    async fn tabs_get_tab_definition_with(
+        &self,
+        account_id: &str,
+        params: &CustomTabsTabsGetTabDefinitionParams,
+    ) -> Result<crate::types::TabMetadataList>;
+
+    /// See [`CustomTabs::tabs_post_tab_definitions`].
+    async fn tabs_post_tab_definitions(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> Result<crate::types::TabMetadata>;
+
+    /// See [`CustomTabs::tab_get_custom`].
+    async fn tab_get_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> Result<crate::types::TabMetadata>;
+
+    /// See [`CustomTabs::tab_put_custom`].
+    async fn tab_put_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> Result<crate::types::TabMetadata>;
+
+    /// See [`CustomTabs::tab_delete_custom`].
+    async fn tab_delete_custom(&self, account_id: &str, custom_tab_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl CustomTabsApi for CustomTabs {
+    async fn tabs_get_tab_definition_with(
+        &self,
+        account_id: &str,
+        params: &CustomTabsTabsGetTabDefinitionParams,
+    ) -> Result<crate::types::TabMetadataList> {
+        CustomTabs::tabs_get_tab_definition_with(self, account_id, params).await
+    }
+
+    async fn tabs_post_tab_definitions(
+        &self,
+        account_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> Result<crate::types::TabMetadata> {
+        CustomTabs::tabs_post_tab_definitions(self, account_id, body).await
+    }
+
+    async fn tab_get_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+    ) -> Result<crate::types::TabMetadata> {
+        CustomTabs::tab_get_custom(self, account_id, custom_tab_id).await
+    }
+
+    async fn tab_put_custom(
+        &self,
+        account_id: &str,
+        custom_tab_id: &str,
+        body: &crate::types::TabMetadata,
+    ) -> Result<crate::types::TabMetadata> {
+        CustomTabs::tab_put_custom(self, account_id, custom_tab_id, body).await
+    }
+
+    async fn tab_delete_custom(&self, account_id: &str, custom_tab_id: &str) -> Result<()> {
+        CustomTabs::tab_delete_custom(self, account_id, custom_tab_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct DocumentResponsiveHtmlPreview {
     pub client: Client,
@@ -50,3 +48,38 @@ impl DocumentResponsiveHtmlPreview {
             .await
     }
 }
+
+/// The functions of [`DocumentResponsiveHtmlPreview`], for code that takes them as a trait, so that it can be
+/// tested with the `MockDocumentResponsiveHtmlPreviewApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait DocumentResponsiveHtmlPreviewApi: Send + Sync {
+    /// See [`DocumentResponsiveHtmlPreview::responsive_html_post_document_preview`].
+    async fn responsive_html_post_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions>;
+}
+
+#[async_trait::async_trait]
+impl DocumentResponsiveHtmlPreviewApi for DocumentResponsiveHtmlPreview {
+    async fn responsive_html_post_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        DocumentResponsiveHtmlPreview::responsive_html_post_document_preview(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        )
+        .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ENoteConfigurations {
     pub client: Client,
@@ -78,3 +76,41 @@ impl ENoteConfigurations {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`ENoteConfigurations`], for code that takes them as a trait, so that it can be
+/// tested with the `MockENoteConfigurationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ENoteConfigurationsApi: Send + Sync {
+    /// See [`ENoteConfigurations::get`].
+    async fn get(&self, account_id: &str) -> Result<crate::types::ENoteConfiguration>;
+
+    /// See [`ENoteConfigurations::put`].
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::ENoteConfiguration,
+    ) -> Result<crate::types::ENoteConfiguration>;
+
+    /// See [`ENoteConfigurations::delete`].
+    async fn delete(&self, account_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ENoteConfigurationsApi for ENoteConfigurations {
+    async fn get(&self, account_id: &str) -> Result<crate::types::ENoteConfiguration> {
+        ENoteConfigurations::get(self, account_id).await
+    }
+
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::ENoteConfiguration,
+    ) -> Result<crate::types::ENoteConfiguration> {
+        ENoteConfigurations::put(self, account_id, body).await
+    }
+
+    async fn delete(&self, account_id: &str) -> Result<()> {
+        ENoteConfigurations::delete(self, account_id).await
+    }
+}
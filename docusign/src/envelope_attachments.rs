@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeAttachments {
     pub client: Client,
@@ -157,3 +155,110 @@ impl EnvelopeAttachments {
             .await
     }
 }
+
+/// The functions of [`EnvelopeAttachments`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeAttachmentsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeAttachmentsApi: Send + Sync {
+    /// See [`EnvelopeAttachments::attachments_get`].
+    async fn attachments_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult>;
+
+    /// See [`EnvelopeAttachments::attachments_put`].
+    async fn attachments_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult>;
+
+    /// See [`EnvelopeAttachments::attachments_delete`].
+    async fn attachments_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult>;
+
+    /// See [`EnvelopeAttachments::attachments_get_attachment`].
+    async fn attachments_get_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+    ) -> Result<()>;
+
+    /// See [`EnvelopeAttachments::attachments_put_attachment`].
+    async fn attachments_put_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Attachment,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeAttachmentsApi for EnvelopeAttachments {
+    async fn attachments_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        EnvelopeAttachments::attachments_get(self, account_id, envelope_id).await
+    }
+
+    async fn attachments_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        EnvelopeAttachments::attachments_put(self, account_id, envelope_id, body).await
+    }
+
+    async fn attachments_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeAttachmentsRequest,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        EnvelopeAttachments::attachments_delete(self, account_id, envelope_id, body).await
+    }
+
+    async fn attachments_get_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+    ) -> Result<()> {
+        EnvelopeAttachments::attachments_get_attachment(
+            self,
+            account_id,
+            attachment_id,
+            envelope_id,
+        )
+        .await
+    }
+
+    async fn attachments_put_attachment(
+        &self,
+        account_id: &str,
+        attachment_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Attachment,
+    ) -> Result<crate::types::EnvelopeAttachmentsResult> {
+        EnvelopeAttachments::attachments_put_attachment(
+            self,
+            account_id,
+            attachment_id,
+            envelope_id,
+            body,
+        )
+        .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeConsumerDisclosures {
     pub client: Client,
@@ -28,63 +26,17 @@ impl EnvelopeConsumerDisclosures {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `recipient_id: &str` -- A local reference that senders use to map recipients to other objects, such as specific document tabs. Within an envelope, each `recipientId` must be unique, but there is no uniqueness requirement across envelopes. For example, many envelopes assign the first recipient a `recipientId` of `1`.
-     * * `lang_code: &str` -- (Optional) The code for the signer language version of the disclosure that you want to retrieve. The following languages are supported:
-     *   
-     *   - Arabic (`ar`)
-     *   - Bulgarian (`bg`)
-     *   - Czech (`cs`)
-     *   - Chinese Simplified (`zh_CN`)
-     *   - Chinese Traditional (`zh_TW`)
-     *   - Croatian (`hr`)
-     *   - Danish (`da`)
-     *   - Dutch (`nl`)
-     *   - English US (`en`)
-     *   - English UK (`en_GB`)
-     *   - Estonian (`et`)
-     *   - Farsi (`fa`)
-     *   - Finnish (`fi`)
-     *   - French (`fr`)
-     *   - French Canadian (`fr_CA`)
-     *   - German (`de`)
-     *   - Greek (`el`)
-     *   - Hebrew (`he`)
-     *   - Hindi (`hi`)
-     *   - Hungarian (`hu`)
-     *   - Bahasa Indonesian (`id`)
-     *   - Italian (`it`)
-     *   - Japanese (`ja`)
-     *   - Korean (`ko`)
-     *   - Latvian (`lv`)
-     *   - Lithuanian (`lt`)
-     *   - Bahasa Melayu (`ms`)
-     *   - Norwegian (`no`)
-     *   - Polish (`pl`)
-     *   - Portuguese (`pt`)
-     *   - Portuguese Brazil (`pt_BR`)
-     *   - Romanian (`ro`)
-     *   - Russian (`ru`)
-     *   - Serbian (`sr`)
-     *   - Slovak (`sk`)
-     *   - Slovenian (`sl`)
-     *   - Spanish (`es`)
-     *   - Spanish Latin America (`es_MX`)
-     *   - Swedish (`sv`)
-     *   - Thai (`th`)
-     *   - Turkish (`tr`)
-     *   - Ukrainian (`uk`)
-     *   - Vietnamese (`vi`)
-     *   
-     *   Additionally, you can automatically detect the browser language being used by the viewer and display the disclosure in that language by setting the value to `browser`.
+     * * `params: &EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn consumer_disclosure_get_envelope_recipient(
+    pub async fn consumer_disclosure_get_envelope_recipient_with(
         &self,
         account_id: &str,
         envelope_id: &str,
         recipient_id: &str,
-        lang_code: &str,
+        params: &EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientParams,
     ) -> Result<crate::types::ConsumerDisclosure> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !lang_code.is_empty() {
+        if let Some(lang_code) = &params.lang_code {
             query_args.push(("langCode".to_string(), lang_code.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -99,6 +51,29 @@ impl EnvelopeConsumerDisclosures {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `consumer_disclosure_get_envelope_recipient_with` and pass the query parameters as a `EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientParams` instead.
+     */
+    #[deprecated(note = "use `consumer_disclosure_get_envelope_recipient_with` instead")]
+    pub async fn consumer_disclosure_get_envelope_recipient(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        lang_code: &str,
+    ) -> Result<crate::types::ConsumerDisclosure> {
+        let params = EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientParams {
+            lang_code: Some(lang_code.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.consumer_disclosure_get_envelope_recipient_with(
+            account_id,
+            envelope_id,
+            recipient_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Gets the Electronic Record and Signature Disclosure for a specific envelope recipient.
      *
@@ -226,3 +201,124 @@ impl EnvelopeConsumerDisclosures {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientParams {
+    /// (Optional) The code for the signer language version of the disclosure that you want to retrieve. The following languages are supported:
+    ///
+    /// - Arabic (`ar`)
+    /// - Bulgarian (`bg`)
+    /// - Czech (`cs`)
+    /// - Chinese Simplified (`zh_CN`)
+    /// - Chinese Traditional (`zh_TW`)
+    /// - Croatian (`hr`)
+    /// - Danish (`da`)
+    /// - Dutch (`nl`)
+    /// - English US (`en`)
+    /// - English UK (`en_GB`)
+    /// - Estonian (`et`)
+    /// - Farsi (`fa`)
+    /// - Finnish (`fi`)
+    /// - French (`fr`)
+    /// - French Canadian (`fr_CA`)
+    /// - German (`de`)
+    /// - Greek (`el`)
+    /// - Hebrew (`he`)
+    /// - Hindi (`hi`)
+    /// - Hungarian (`hu`)
+    /// - Bahasa Indonesian (`id`)
+    /// - Italian (`it`)
+    /// - Japanese (`ja`)
+    /// - Korean (`ko`)
+    /// - Latvian (`lv`)
+    /// - Lithuanian (`lt`)
+    /// - Bahasa Melayu (`ms`)
+    /// - Norwegian (`no`)
+    /// - Polish (`pl`)
+    /// - Portuguese (`pt`)
+    /// - Portuguese Brazil (`pt_BR`)
+    /// - Romanian (`ro`)
+    /// - Russian (`ru`)
+    /// - Serbian (`sr`)
+    /// - Slovak (`sk`)
+    /// - Slovenian (`sl`)
+    /// - Spanish (`es`)
+    /// - Spanish Latin America (`es_MX`)
+    /// - Swedish (`sv`)
+    /// - Thai (`th`)
+    /// - Turkish (`tr`)
+    /// - Ukrainian (`uk`)
+    /// - Vietnamese (`vi`)
+    ///
+    ///   Additionally, you can automatically detect the browser language being used by the viewer and display the disclosure in that language by setting the value to `browser`.
+    pub lang_code: Option<String>,
+}
+
+impl EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientParams {
+    pub fn lang_code<T: ToString>(mut self, lang_code: T) -> Self {
+        self.lang_code = Some(lang_code.to_string());
+        self
+    }
+}
+
+/// The functions of [`EnvelopeConsumerDisclosures`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeConsumerDisclosuresApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeConsumerDisclosuresApi: Send + Sync {
+    /// See [`EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient_with`].
+    async fn consumer_disclosure_get_envelope_recipient_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientParams,
+    ) -> Result<crate::types::ConsumerDisclosure>;
+
+    /// See [`EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient_lang_code`].
+    async fn consumer_disclosure_get_envelope_recipient_lang_code(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        lang_code: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ConsumerDisclosure>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeConsumerDisclosuresApi for EnvelopeConsumerDisclosures {
+    async fn consumer_disclosure_get_envelope_recipient_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopeConsumerDisclosuresConsumerDisclosureGetEnvelopeRecipientParams,
+    ) -> Result<crate::types::ConsumerDisclosure> {
+        EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient_with(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            params,
+        )
+        .await
+    }
+
+    async fn consumer_disclosure_get_envelope_recipient_lang_code(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        lang_code: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ConsumerDisclosure> {
+        EnvelopeConsumerDisclosures::consumer_disclosure_get_envelope_recipient_lang_code(
+            self,
+            account_id,
+            envelope_id,
+            lang_code,
+            recipient_id,
+        )
+        .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeCustomFields {
     pub client: Client,
@@ -133,3 +131,78 @@ impl EnvelopeCustomFields {
             .await
     }
 }
+
+/// The functions of [`EnvelopeCustomFields`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeCustomFieldsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeCustomFieldsApi: Send + Sync {
+    /// See [`EnvelopeCustomFields::custom_fields_get`].
+    async fn custom_fields_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::CustomFieldsEnvelope>;
+
+    /// See [`EnvelopeCustomFields::custom_fields_put`].
+    async fn custom_fields_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields>;
+
+    /// See [`EnvelopeCustomFields::custom_fields_post`].
+    async fn custom_fields_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields>;
+
+    /// See [`EnvelopeCustomFields::custom_fields_delete`].
+    async fn custom_fields_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeCustomFieldsApi for EnvelopeCustomFields {
+    async fn custom_fields_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::CustomFieldsEnvelope> {
+        EnvelopeCustomFields::custom_fields_get(self, account_id, envelope_id).await
+    }
+
+    async fn custom_fields_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields> {
+        EnvelopeCustomFields::custom_fields_put(self, account_id, envelope_id, body).await
+    }
+
+    async fn custom_fields_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields> {
+        EnvelopeCustomFields::custom_fields_post(self, account_id, envelope_id, body).await
+    }
+
+    async fn custom_fields_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeCustomFields,
+    ) -> Result<crate::types::EnvelopeCustomFields> {
+        EnvelopeCustomFields::custom_fields_delete(self, account_id, envelope_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeDocumentFields {
     pub client: Client,
@@ -137,3 +135,108 @@ impl EnvelopeDocumentFields {
             .await
     }
 }
+
+/// The functions of [`EnvelopeDocumentFields`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeDocumentFieldsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeDocumentFieldsApi: Send + Sync {
+    /// See [`EnvelopeDocumentFields::document_fields_get`].
+    async fn document_fields_get(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeDocumentFields>;
+
+    /// See [`EnvelopeDocumentFields::document_fields_put`].
+    async fn document_fields_put(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields>;
+
+    /// See [`EnvelopeDocumentFields::document_fields_post`].
+    async fn document_fields_post(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields>;
+
+    /// See [`EnvelopeDocumentFields::document_fields_delete`].
+    async fn document_fields_delete(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeDocumentFieldsApi for EnvelopeDocumentFields {
+    async fn document_fields_get(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeDocumentFields> {
+        EnvelopeDocumentFields::document_fields_get(self, account_id, document_id, envelope_id)
+            .await
+    }
+
+    async fn document_fields_put(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields> {
+        EnvelopeDocumentFields::document_fields_put(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        )
+        .await
+    }
+
+    async fn document_fields_post(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields> {
+        EnvelopeDocumentFields::document_fields_post(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        )
+        .await
+    }
+
+    async fn document_fields_delete(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDocumentFields,
+    ) -> Result<crate::types::EnvelopeDocumentFields> {
+        EnvelopeDocumentFields::document_fields_delete(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            body,
+        )
+        .await
+    }
+}
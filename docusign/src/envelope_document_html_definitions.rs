@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeDocumentHtmlDefinitions {
     pub client: Client,
@@ -42,3 +40,35 @@ impl EnvelopeDocumentHtmlDefinitions {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`EnvelopeDocumentHtmlDefinitions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeDocumentHtmlDefinitionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeDocumentHtmlDefinitionsApi: Send + Sync {
+    /// See [`EnvelopeDocumentHtmlDefinitions::responsive_html_get_envelope_document_definition`].
+    async fn responsive_html_get_envelope_document_definition(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DocumentHtmlDefinitionOriginals>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeDocumentHtmlDefinitionsApi for EnvelopeDocumentHtmlDefinitions {
+    async fn responsive_html_get_envelope_document_definition(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DocumentHtmlDefinitionOriginals> {
+        EnvelopeDocumentHtmlDefinitions::responsive_html_get_envelope_document_definition(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+        )
+        .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeDocumentTabs {
     pub client: Client,
@@ -56,26 +54,20 @@ impl EnvelopeDocumentTabs {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `document_id: &str` -- The `documentId` is set by the API client. It is an integer that falls between `1` and 2,147,483,647. The value is encoded as a string without commas. The values `1`, `2`, `3`, and so on are typically used to identify the first few documents in an envelope. Tab definitions include a `documentId` property that specifies the document on which to place the tab.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_metadata: &str` -- When set to **true**, the response includes metadata indicating which properties are editable.
-     * * `page_numbers: &str` -- Filters for tabs that occur on the pages that you specify. Enter as a comma-separated list of page GUIDs.
-     *   
-     *   Example: `page_numbers=2,6`
-     *   
-     *   Note: You can only enter individual page numbers, and not a page range.
+     * * `params: &EnvelopeDocumentTabsTabsGetDocumentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn tabs_get_document(
+    pub async fn tabs_get_document_with(
         &self,
         account_id: &str,
         document_id: &str,
         envelope_id: &str,
-        include_metadata: &str,
-        page_numbers: &str,
+        params: &EnvelopeDocumentTabsTabsGetDocumentParams,
     ) -> Result<crate::types::EnvelopeDocumentTabs> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_metadata.is_empty() {
+        if let Some(include_metadata) = &params.include_metadata {
             query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
         }
-        if !page_numbers.is_empty() {
+        if let Some(page_numbers) = &params.page_numbers {
             query_args.push(("page_numbers".to_string(), page_numbers.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -90,6 +82,26 @@ impl EnvelopeDocumentTabs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `tabs_get_document_with` and pass the query parameters as a `EnvelopeDocumentTabsTabsGetDocumentParams` instead.
+     */
+    #[deprecated(note = "use `tabs_get_document_with` instead")]
+    pub async fn tabs_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        include_metadata: &str,
+        page_numbers: &str,
+    ) -> Result<crate::types::EnvelopeDocumentTabs> {
+        let params = EnvelopeDocumentTabsTabsGetDocumentParams {
+            include_metadata: Some(include_metadata.to_string()).filter(|s| !s.is_empty()),
+            page_numbers: Some(page_numbers.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.tabs_get_document_with(account_id, document_id, envelope_id, &params)
+            .await
+    }
+
     /**
      * Updates the tabs for an envelope document.
      *
@@ -186,3 +198,143 @@ impl EnvelopeDocumentTabs {
             .await
     }
 }
+
+/// The query parameters of `EnvelopeDocumentTabs::tabs_get_document_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopeDocumentTabsTabsGetDocumentParams {
+    /// When set to **true**, the response includes metadata indicating which properties are editable.
+    pub include_metadata: Option<String>,
+    /// Filters for tabs that occur on the pages that you specify. Enter as a comma-separated list of page GUIDs.
+    ///
+    /// Example: `page_numbers=2,6`
+    ///
+    /// Note: You can only enter individual page numbers, and not a page range.
+    pub page_numbers: Option<String>,
+}
+
+impl EnvelopeDocumentTabsTabsGetDocumentParams {
+    pub fn include_metadata<T: ToString>(mut self, include_metadata: T) -> Self {
+        self.include_metadata = Some(include_metadata.to_string());
+        self
+    }
+
+    pub fn page_numbers<T: ToString>(mut self, page_numbers: T) -> Self {
+        self.page_numbers = Some(page_numbers.to_string());
+        self
+    }
+}
+
+/// The functions of [`EnvelopeDocumentTabs`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeDocumentTabsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeDocumentTabsApi: Send + Sync {
+    /// See [`EnvelopeDocumentTabs::tabs_get_page`].
+    async fn tabs_get_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<crate::types::EnvelopeDocumentTabs>;
+
+    /// See [`EnvelopeDocumentTabs::tabs_get_document_with`].
+    async fn tabs_get_document_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeDocumentTabsTabsGetDocumentParams,
+    ) -> Result<crate::types::EnvelopeDocumentTabs>;
+
+    /// See [`EnvelopeDocumentTabs::tabs_put_document`].
+    async fn tabs_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs>;
+
+    /// See [`EnvelopeDocumentTabs::tabs_post_document`].
+    async fn tabs_post_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs>;
+
+    /// See [`EnvelopeDocumentTabs::tabs_delete_document`].
+    async fn tabs_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeDocumentTabsApi for EnvelopeDocumentTabs {
+    async fn tabs_get_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<crate::types::EnvelopeDocumentTabs> {
+        EnvelopeDocumentTabs::tabs_get_page(self, account_id, document_id, envelope_id, page_number)
+            .await
+    }
+
+    async fn tabs_get_document_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeDocumentTabsTabsGetDocumentParams,
+    ) -> Result<crate::types::EnvelopeDocumentTabs> {
+        EnvelopeDocumentTabs::tabs_get_document_with(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            params,
+        )
+        .await
+    }
+
+    async fn tabs_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs> {
+        EnvelopeDocumentTabs::tabs_put_document(self, account_id, document_id, envelope_id, body)
+            .await
+    }
+
+    async fn tabs_post_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs> {
+        EnvelopeDocumentTabs::tabs_post_document(self, account_id, document_id, envelope_id, body)
+            .await
+    }
+
+    async fn tabs_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Tabs,
+    ) -> Result<crate::types::Tabs> {
+        EnvelopeDocumentTabs::tabs_delete_document(self, account_id, document_id, envelope_id, body)
+            .await
+    }
+}
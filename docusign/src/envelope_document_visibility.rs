@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeDocumentVisibility {
     pub client: Client,
@@ -75,3 +73,61 @@ impl EnvelopeDocumentVisibility {
             .await
     }
 }
+
+/// The functions of [`EnvelopeDocumentVisibility`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeDocumentVisibilityApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeDocumentVisibilityApi: Send + Sync {
+    /// See [`EnvelopeDocumentVisibility::recipients_get_recipient_document_visibility`].
+    async fn recipients_get_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::DocumentVisibilityList>;
+
+    /// See [`EnvelopeDocumentVisibility::recipients_put_recipient_document_visibility`].
+    async fn recipients_put_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> Result<crate::types::DocumentVisibilityList>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeDocumentVisibilityApi for EnvelopeDocumentVisibility {
+    async fn recipients_get_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::DocumentVisibilityList> {
+        EnvelopeDocumentVisibility::recipients_get_recipient_document_visibility(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+        )
+        .await
+    }
+
+    async fn recipients_put_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> Result<crate::types::DocumentVisibilityList> {
+        EnvelopeDocumentVisibility::recipients_put_recipient_document_visibility(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            body,
+        )
+        .await
+    }
+}
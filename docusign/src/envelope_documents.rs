@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeDocuments {
     pub client: Client,
@@ -23,47 +21,37 @@ impl EnvelopeDocuments {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `documents_by_userid: &str` -- When set to **true**, allows recipients to get documents by their user id. For example, if a user is included in two different routing orders with different visibilities, using this parameter returns all of the documents from both routing orders.
-     * * `include_document_size: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_metadata: &str` -- When set to **true**, the response includes metadata that indicates which properties the sender can edit.
-     * * `include_tabs: &str` -- When set to **true**, information about the tabs associated with the documents are included in the response.
-     * * `recipient_id: &str` -- Allows the sender to retrieve the documents as one of the recipients that they control. The `documents_by_userid` parameter must be set to **false** for this to work.
-     * * `shared_user_id: &str` -- The ID of a shared user that you want to impersonate in order to retrieve their view of the list of documents. This parameter is used in the context of a shared inbox (i.e., when you share envelopes from one user to another through the RADmin console).
+     * * `params: &EnvelopeDocumentsDocumentsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn documents_get(
+    pub async fn documents_get_with(
         &self,
         account_id: &str,
         envelope_id: &str,
-        documents_by_userid: &str,
-        include_document_size: &str,
-        include_metadata: &str,
-        include_tabs: &str,
-        recipient_id: &str,
-        shared_user_id: &str,
+        params: &EnvelopeDocumentsDocumentsGetParams,
     ) -> Result<crate::types::EnvelopeDocumentsResult> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !documents_by_userid.is_empty() {
+        if let Some(documents_by_userid) = &params.documents_by_userid {
             query_args.push((
                 "documents_by_userid".to_string(),
                 documents_by_userid.to_string(),
             ));
         }
-        if !include_document_size.is_empty() {
+        if let Some(include_document_size) = &params.include_document_size {
             query_args.push((
                 "include_document_size".to_string(),
                 include_document_size.to_string(),
             ));
         }
-        if !include_metadata.is_empty() {
+        if let Some(include_metadata) = &params.include_metadata {
             query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
         }
-        if !include_tabs.is_empty() {
+        if let Some(include_tabs) = &params.include_tabs {
             query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
         }
-        if !recipient_id.is_empty() {
+        if let Some(recipient_id) = &params.recipient_id {
             query_args.push(("recipient_id".to_string(), recipient_id.to_string()));
         }
-        if !shared_user_id.is_empty() {
+        if let Some(shared_user_id) = &params.shared_user_id {
             query_args.push(("shared_user_id".to_string(), shared_user_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -77,6 +65,34 @@ impl EnvelopeDocuments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `documents_get_with` and pass the query parameters as a `EnvelopeDocumentsDocumentsGetParams` instead.
+     */
+    #[deprecated(note = "use `documents_get_with` instead")]
+    pub async fn documents_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        documents_by_userid: &str,
+        include_document_size: &str,
+        include_metadata: &str,
+        include_tabs: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+    ) -> Result<crate::types::EnvelopeDocumentsResult> {
+        let params = EnvelopeDocumentsDocumentsGetParams {
+            documents_by_userid: Some(documents_by_userid.to_string()).filter(|s| !s.is_empty()),
+            include_document_size: Some(include_document_size.to_string())
+                .filter(|s| !s.is_empty()),
+            include_metadata: Some(include_metadata.to_string()).filter(|s| !s.is_empty()),
+            include_tabs: Some(include_tabs.to_string()).filter(|s| !s.is_empty()),
+            recipient_id: Some(recipient_id.to_string()).filter(|s| !s.is_empty()),
+            shared_user_id: Some(shared_user_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.documents_get_with(account_id, envelope_id, &params)
+            .await
+    }
+
     /**
      * Adds one or more documents to an existing envelope document.
      *
@@ -183,16 +199,64 @@ impl EnvelopeDocuments {
      *   - `archive`: Retrieves a ZIP archive that contains all of the PDF documents and the Certificate of Completion.
      *   .
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `certificate: &str` -- When set to **false**, the envelope signing certificate is removed from the download.
-     * * `documents_by_userid: &str` -- When set to **true**, allows recipients to get documents by their user id. For example, if a user is included in two different routing orders with different visibilities, using this parameter returns all of the documents from both routing orders.
-     * * `encoding: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `encrypt: &str` -- When set to **true**, the PDF bytes returned in the response are encrypted for all the key managers configured on your DocuSign account. You can decrypt the documents by using the Key Manager DecryptDocument API method. For more information about Key Manager, see the DocuSign Security Appliance Installation Guide that your organization received from DocuSign.
-     * * `language: &str` -- Specifies the language for the Certificate of Completion in the response. The supported languages are: Chinese Simplified (zh_CN), Chinese Traditional (zh_TW), Dutch (nl), English US (en), French (fr), German (de), Italian (it), Japanese (ja), Korean (ko), Portuguese (pt), Portuguese (Brazil) (pt_BR), Russian (ru), Spanish (es). .
-     * * `recipient_id: &str` -- Allows the sender to retrieve the documents as one of the recipients that they control. The `documents_by_userid` parameter must be set to **false** for this functionality to work.
-     * * `shared_user_id: &str` -- The ID of a shared user that you want to impersonate in order to retrieve their view of the list of documents. This parameter is used in the context of a shared inbox (i.e., when you share envelopes from one user to another through the RADmin console).
-     * * `show_changes: &str` -- When set to **true**, any changed fields for the returned PDF are highlighted in yellow and optional signatures or initials outlined in red. .
-     * * `watermark: &str` -- When set to **true**, the account has the watermark feature enabled, and the envelope is not complete, then the watermark for the account is added to the PDF documents. This option can remove the watermark. .
+     * * `params: &EnvelopeDocumentsDocumentsGetDocumentParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn documents_get_document_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeDocumentsDocumentsGetDocumentParams,
+    ) -> Result<crate::Download> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(certificate) = &params.certificate {
+            query_args.push(("certificate".to_string(), certificate.to_string()));
+        }
+        if let Some(documents_by_userid) = &params.documents_by_userid {
+            query_args.push((
+                "documents_by_userid".to_string(),
+                documents_by_userid.to_string(),
+            ));
+        }
+        if let Some(encoding) = &params.encoding {
+            query_args.push(("encoding".to_string(), encoding.to_string()));
+        }
+        if let Some(encrypt) = &params.encrypt {
+            query_args.push(("encrypt".to_string(), encrypt.to_string()));
+        }
+        if let Some(language) = &params.language {
+            query_args.push(("language".to_string(), language.to_string()));
+        }
+        if let Some(recipient_id) = &params.recipient_id {
+            query_args.push(("recipient_id".to_string(), recipient_id.to_string()));
+        }
+        if let Some(shared_user_id) = &params.shared_user_id {
+            query_args.push(("shared_user_id".to_string(), shared_user_id.to_string()));
+        }
+        if let Some(show_changes) = &params.show_changes {
+            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+        }
+        if let Some(watermark) = &params.watermark {
+            query_args.push(("watermark".to_string(), watermark.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}?{}",
+            crate::progenitor_support::encode_path(account_id),
+            crate::progenitor_support::encode_path(envelope_id),
+            crate::progenitor_support::encode_path(document_id),
+            query_
+        );
+
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `documents_get_document_with` and pass the query parameters as a `EnvelopeDocumentsDocumentsGetDocumentParams` instead.
+     */
+    #[deprecated(note = "use `documents_get_document_with` instead")]
     pub async fn documents_get_document(
         &self,
         account_id: &str,
@@ -207,36 +271,71 @@ impl EnvelopeDocuments {
         shared_user_id: &str,
         show_changes: &str,
         watermark: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
+        let params = EnvelopeDocumentsDocumentsGetDocumentParams {
+            certificate: Some(certificate.to_string()).filter(|s| !s.is_empty()),
+            documents_by_userid: Some(documents_by_userid.to_string()).filter(|s| !s.is_empty()),
+            encoding: Some(encoding.to_string()).filter(|s| !s.is_empty()),
+            encrypt: Some(encrypt.to_string()).filter(|s| !s.is_empty()),
+            language: Some(language.to_string()).filter(|s| !s.is_empty()),
+            recipient_id: Some(recipient_id.to_string()).filter(|s| !s.is_empty()),
+            shared_user_id: Some(shared_user_id.to_string()).filter(|s| !s.is_empty()),
+            show_changes: Some(show_changes.to_string()).filter(|s| !s.is_empty()),
+            watermark: Some(watermark.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.documents_get_document_with(account_id, document_id, envelope_id, &params)
+            .await
+    }
+
+    /**
+     * Gets a document from an envelope.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}` endpoint.
+     *
+     * As opposed to `documents_get_document`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Retrieves the specified document from the envelope. If the account has the Highlight Data Changes feature enabled, there is an option to request that any changes in the envelope be highlighted.
+     *
+     * **Parameters:**
+     *
+     * * `params: &EnvelopeDocumentsDocumentsGetDocumentParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn documents_get_document_stream_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeDocumentsDocumentsGetDocumentParams,
+    ) -> Result<crate::DownloadStream> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !certificate.is_empty() {
+        if let Some(certificate) = &params.certificate {
             query_args.push(("certificate".to_string(), certificate.to_string()));
         }
-        if !documents_by_userid.is_empty() {
+        if let Some(documents_by_userid) = &params.documents_by_userid {
             query_args.push((
                 "documents_by_userid".to_string(),
                 documents_by_userid.to_string(),
             ));
         }
-        if !encoding.is_empty() {
+        if let Some(encoding) = &params.encoding {
             query_args.push(("encoding".to_string(), encoding.to_string()));
         }
-        if !encrypt.is_empty() {
+        if let Some(encrypt) = &params.encrypt {
             query_args.push(("encrypt".to_string(), encrypt.to_string()));
         }
-        if !language.is_empty() {
+        if let Some(language) = &params.language {
             query_args.push(("language".to_string(), language.to_string()));
         }
-        if !recipient_id.is_empty() {
+        if let Some(recipient_id) = &params.recipient_id {
             query_args.push(("recipient_id".to_string(), recipient_id.to_string()));
         }
-        if !shared_user_id.is_empty() {
+        if let Some(shared_user_id) = &params.shared_user_id {
             query_args.push(("shared_user_id".to_string(), shared_user_id.to_string()));
         }
-        if !show_changes.is_empty() {
+        if let Some(show_changes) = &params.show_changes {
             query_args.push(("show_changes".to_string(), show_changes.to_string()));
         }
-        if !watermark.is_empty() {
+        if let Some(watermark) = &params.watermark {
             query_args.push(("watermark".to_string(), watermark.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -248,7 +347,43 @@ impl EnvelopeDocuments {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `documents_get_document_stream_with` and pass the query parameters as a `EnvelopeDocumentsDocumentsGetDocumentParams` instead.
+     */
+    #[deprecated(note = "use `documents_get_document_stream_with` instead")]
+    pub async fn documents_get_document_stream(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        certificate: &str,
+        documents_by_userid: &str,
+        encoding: &str,
+        encrypt: &str,
+        language: &str,
+        recipient_id: &str,
+        shared_user_id: &str,
+        show_changes: &str,
+        watermark: &str,
+    ) -> Result<crate::DownloadStream> {
+        let params = EnvelopeDocumentsDocumentsGetDocumentParams {
+            certificate: Some(certificate.to_string()).filter(|s| !s.is_empty()),
+            documents_by_userid: Some(documents_by_userid.to_string()).filter(|s| !s.is_empty()),
+            encoding: Some(encoding.to_string()).filter(|s| !s.is_empty()),
+            encrypt: Some(encrypt.to_string()).filter(|s| !s.is_empty()),
+            language: Some(language.to_string()).filter(|s| !s.is_empty()),
+            recipient_id: Some(recipient_id.to_string()).filter(|s| !s.is_empty()),
+            shared_user_id: Some(shared_user_id.to_string()).filter(|s| !s.is_empty()),
+            show_changes: Some(show_changes.to_string()).filter(|s| !s.is_empty()),
+            watermark: Some(watermark.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.documents_get_document_stream_with(account_id, document_id, envelope_id, &params)
+            .await
     }
 
     /**
@@ -296,3 +431,251 @@ impl EnvelopeDocuments {
         self.client.put(&url, None).await
     }
 }
+
+/// The query parameters of `EnvelopeDocuments::documents_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopeDocumentsDocumentsGetParams {
+    /// When set to **true**, allows recipients to get documents by their user id. For example, if a user is included in two different routing orders with different visibilities, using this parameter returns all of the documents from both routing orders.
+    pub documents_by_userid: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub include_document_size: Option<String>,
+    /// When set to **true**, the response includes metadata that indicates which properties the sender can edit.
+    pub include_metadata: Option<String>,
+    /// When set to **true**, information about the tabs associated with the documents are included in the response.
+    pub include_tabs: Option<String>,
+    /// Allows the sender to retrieve the documents as one of the recipients that they control. The `documents_by_userid` parameter must be set to **false** for this to work.
+    pub recipient_id: Option<String>,
+    /// The ID of a shared user that you want to impersonate in order to retrieve their view of the list of documents. This parameter is used in the context of a shared inbox (i.e., when you share envelopes from one user to another through the RADmin console).
+    pub shared_user_id: Option<String>,
+}
+
+impl EnvelopeDocumentsDocumentsGetParams {
+    pub fn documents_by_userid<T: ToString>(mut self, documents_by_userid: T) -> Self {
+        self.documents_by_userid = Some(documents_by_userid.to_string());
+        self
+    }
+
+    pub fn include_document_size<T: ToString>(mut self, include_document_size: T) -> Self {
+        self.include_document_size = Some(include_document_size.to_string());
+        self
+    }
+
+    pub fn include_metadata<T: ToString>(mut self, include_metadata: T) -> Self {
+        self.include_metadata = Some(include_metadata.to_string());
+        self
+    }
+
+    pub fn include_tabs<T: ToString>(mut self, include_tabs: T) -> Self {
+        self.include_tabs = Some(include_tabs.to_string());
+        self
+    }
+
+    pub fn recipient_id<T: ToString>(mut self, recipient_id: T) -> Self {
+        self.recipient_id = Some(recipient_id.to_string());
+        self
+    }
+
+    pub fn shared_user_id<T: ToString>(mut self, shared_user_id: T) -> Self {
+        self.shared_user_id = Some(shared_user_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `EnvelopeDocuments::documents_get_document_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopeDocumentsDocumentsGetDocumentParams {
+    /// When set to **false**, the envelope signing certificate is removed from the download.
+    pub certificate: Option<String>,
+    /// When set to **true**, allows recipients to get documents by their user id. For example, if a user is included in two different routing orders with different visibilities, using this parameter returns all of the documents from both routing orders.
+    pub documents_by_userid: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub encoding: Option<String>,
+    /// When set to **true**, the PDF bytes returned in the response are encrypted for all the key managers configured on your DocuSign account. You can decrypt the documents by using the Key Manager DecryptDocument API method. For more information about Key Manager, see the DocuSign Security Appliance Installation Guide that your organization received from DocuSign.
+    pub encrypt: Option<String>,
+    /// Specifies the language for the Certificate of Completion in the response. The supported languages are: Chinese Simplified (zh_CN), Chinese Traditional (zh_TW), Dutch (nl), English US (en), French (fr), German (de), Italian (it), Japanese (ja), Korean (ko), Portuguese (pt), Portuguese (Brazil) (pt_BR), Russian (ru), Spanish (es). .
+    pub language: Option<String>,
+    /// Allows the sender to retrieve the documents as one of the recipients that they control. The `documents_by_userid` parameter must be set to **false** for this functionality to work.
+    pub recipient_id: Option<String>,
+    /// The ID of a shared user that you want to impersonate in order to retrieve their view of the list of documents. This parameter is used in the context of a shared inbox (i.e., when you share envelopes from one user to another through the RADmin console).
+    pub shared_user_id: Option<String>,
+    /// When set to **true**, any changed fields for the returned PDF are highlighted in yellow and optional signatures or initials outlined in red. .
+    pub show_changes: Option<String>,
+    /// When set to **true**, the account has the watermark feature enabled, and the envelope is not complete, then the watermark for the account is added to the PDF documents. This option can remove the watermark. .
+    pub watermark: Option<String>,
+}
+
+impl EnvelopeDocumentsDocumentsGetDocumentParams {
+    pub fn certificate<T: ToString>(mut self, certificate: T) -> Self {
+        self.certificate = Some(certificate.to_string());
+        self
+    }
+
+    pub fn documents_by_userid<T: ToString>(mut self, documents_by_userid: T) -> Self {
+        self.documents_by_userid = Some(documents_by_userid.to_string());
+        self
+    }
+
+    pub fn encoding<T: ToString>(mut self, encoding: T) -> Self {
+        self.encoding = Some(encoding.to_string());
+        self
+    }
+
+    pub fn encrypt<T: ToString>(mut self, encrypt: T) -> Self {
+        self.encrypt = Some(encrypt.to_string());
+        self
+    }
+
+    pub fn language<T: ToString>(mut self, language: T) -> Self {
+        self.language = Some(language.to_string());
+        self
+    }
+
+    pub fn recipient_id<T: ToString>(mut self, recipient_id: T) -> Self {
+        self.recipient_id = Some(recipient_id.to_string());
+        self
+    }
+
+    pub fn shared_user_id<T: ToString>(mut self, shared_user_id: T) -> Self {
+        self.shared_user_id = Some(shared_user_id.to_string());
+        self
+    }
+
+    pub fn show_changes<T: ToString>(mut self, show_changes: T) -> Self {
+        self.show_changes = Some(show_changes.to_string());
+        self
+    }
+
+    pub fn watermark<T: ToString>(mut self, watermark: T) -> Self {
+        self.watermark = Some(watermark.to_string());
+        self
+    }
+}
+
+/// The functions of [`EnvelopeDocuments`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeDocumentsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeDocumentsApi: Send + Sync {
+    /// See [`EnvelopeDocuments::documents_get_with`].
+    async fn documents_get_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeDocumentsDocumentsGetParams,
+    ) -> Result<crate::types::EnvelopeDocumentsResult>;
+
+    /// See [`EnvelopeDocuments::documents_put`].
+    async fn documents_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocumentsResult>;
+
+    /// See [`EnvelopeDocuments::documents_delete`].
+    async fn documents_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocumentsResult>;
+
+    /// See [`EnvelopeDocuments::documents_get_document_with`].
+    async fn documents_get_document_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeDocumentsDocumentsGetDocumentParams,
+    ) -> Result<crate::Download>;
+
+    /// See [`EnvelopeDocuments::documents_get_document_stream_with`].
+    async fn documents_get_document_stream_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeDocumentsDocumentsGetDocumentParams,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`EnvelopeDocuments::documents_put_document`].
+    async fn documents_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeDocument>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeDocumentsApi for EnvelopeDocuments {
+    async fn documents_get_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeDocumentsDocumentsGetParams,
+    ) -> Result<crate::types::EnvelopeDocumentsResult> {
+        EnvelopeDocuments::documents_get_with(self, account_id, envelope_id, params).await
+    }
+
+    async fn documents_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocumentsResult> {
+        EnvelopeDocuments::documents_put(self, account_id, envelope_id, body).await
+    }
+
+    async fn documents_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocumentsResult> {
+        EnvelopeDocuments::documents_delete(self, account_id, envelope_id, body).await
+    }
+
+    async fn documents_get_document_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeDocumentsDocumentsGetDocumentParams,
+    ) -> Result<crate::Download> {
+        EnvelopeDocuments::documents_get_document_with(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            params,
+        )
+        .await
+    }
+
+    async fn documents_get_document_stream_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeDocumentsDocumentsGetDocumentParams,
+    ) -> Result<crate::DownloadStream> {
+        EnvelopeDocuments::documents_get_document_stream_with(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            params,
+        )
+        .await
+    }
+
+    async fn documents_put_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeDocument> {
+        EnvelopeDocuments::documents_put_document(self, account_id, document_id, envelope_id).await
+    }
+}
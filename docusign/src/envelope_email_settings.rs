@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeEmailSettings {
     pub client: Client,
@@ -126,3 +124,76 @@ impl EnvelopeEmailSettings {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`EnvelopeEmailSettings`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeEmailSettingsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeEmailSettingsApi: Send + Sync {
+    /// See [`EnvelopeEmailSettings::email_settings_get`].
+    async fn email_settings_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EmailSettings>;
+
+    /// See [`EnvelopeEmailSettings::email_settings_put`].
+    async fn email_settings_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> Result<crate::types::EmailSettings>;
+
+    /// See [`EnvelopeEmailSettings::email_settings_post`].
+    async fn email_settings_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> Result<crate::types::EmailSettings>;
+
+    /// See [`EnvelopeEmailSettings::email_settings_delete`].
+    async fn email_settings_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EmailSettings>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeEmailSettingsApi for EnvelopeEmailSettings {
+    async fn email_settings_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EmailSettings> {
+        EnvelopeEmailSettings::email_settings_get(self, account_id, envelope_id).await
+    }
+
+    async fn email_settings_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> Result<crate::types::EmailSettings> {
+        EnvelopeEmailSettings::email_settings_put(self, account_id, envelope_id, body).await
+    }
+
+    async fn email_settings_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EmailSettings,
+    ) -> Result<crate::types::EmailSettings> {
+        EnvelopeEmailSettings::email_settings_post(self, account_id, envelope_id, body).await
+    }
+
+    async fn email_settings_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EmailSettings> {
+        EnvelopeEmailSettings::email_settings_delete(self, account_id, envelope_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeFormData {
     pub client: Client,
@@ -40,3 +38,27 @@ impl EnvelopeFormData {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`EnvelopeFormData`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeFormDataApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeFormDataApi: Send + Sync {
+    /// See [`EnvelopeFormData::form_data_get`].
+    async fn form_data_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeFormDataType>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeFormDataApi for EnvelopeFormData {
+    async fn form_data_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeFormDataType> {
+        EnvelopeFormData::form_data_get(self, account_id, envelope_id).await
+    }
+}
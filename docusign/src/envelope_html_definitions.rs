@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeHtmlDefinitions {
     pub client: Client,
@@ -38,3 +36,32 @@ impl EnvelopeHtmlDefinitions {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`EnvelopeHtmlDefinitions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeHtmlDefinitionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeHtmlDefinitionsApi: Send + Sync {
+    /// See [`EnvelopeHtmlDefinitions::responsive_html_get_envelope_definition`].
+    async fn responsive_html_get_envelope_definition(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DocumentHtmlDefinitionOriginals>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeHtmlDefinitionsApi for EnvelopeHtmlDefinitions {
+    async fn responsive_html_get_envelope_definition(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::DocumentHtmlDefinitionOriginals> {
+        EnvelopeHtmlDefinitions::responsive_html_get_envelope_definition(
+            self,
+            account_id,
+            envelope_id,
+        )
+        .await
+    }
+}
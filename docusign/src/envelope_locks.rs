@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeLocks {
     pub client: Client,
@@ -228,3 +226,76 @@ impl EnvelopeLocks {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`EnvelopeLocks`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeLocksApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeLocksApi: Send + Sync {
+    /// See [`EnvelopeLocks::lock_get_envelope`].
+    async fn lock_get_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeLocks>;
+
+    /// See [`EnvelopeLocks::lock_put_envelope`].
+    async fn lock_put_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::EnvelopeLocks>;
+
+    /// See [`EnvelopeLocks::lock_post_envelope`].
+    async fn lock_post_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::EnvelopeLocks>;
+
+    /// See [`EnvelopeLocks::lock_delete_envelope`].
+    async fn lock_delete_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeLocks>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeLocksApi for EnvelopeLocks {
+    async fn lock_get_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeLocks> {
+        EnvelopeLocks::lock_get_envelope(self, account_id, envelope_id).await
+    }
+
+    async fn lock_put_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::EnvelopeLocks> {
+        EnvelopeLocks::lock_put_envelope(self, account_id, envelope_id, body).await
+    }
+
+    async fn lock_post_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::EnvelopeLocks> {
+        EnvelopeLocks::lock_post_envelope(self, account_id, envelope_id, body).await
+    }
+
+    async fn lock_delete_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeLocks> {
+        EnvelopeLocks::lock_delete_envelope(self, account_id, envelope_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeRecipientTabs {
     pub client: Client,
@@ -30,25 +28,23 @@ impl EnvelopeRecipientTabs {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `recipient_id: &str` -- A local reference that senders use to map recipients to other objects, such as specific document tabs. Within an envelope, each `recipientId` must be unique, but there is no uniqueness requirement across envelopes. For example, many envelopes assign the first recipient a `recipientId` of `1`.
-     * * `include_anchor_tab_locations: &str` -- When set to **true**, all tabs with anchor tab properties are included in the response. The default value is **false**.
-     * * `include_metadata: &str` -- When set to **true**, the response includes metadata indicating which properties are editable.
+     * * `params: &EnvelopeRecipientTabsRecipientsGetRecipientTabParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recipients_get_recipient_tab(
+    pub async fn recipients_get_recipient_tab_with(
         &self,
         account_id: &str,
         envelope_id: &str,
         recipient_id: &str,
-        include_anchor_tab_locations: &str,
-        include_metadata: &str,
+        params: &EnvelopeRecipientTabsRecipientsGetRecipientTabParams,
     ) -> Result<crate::types::EnvelopeRecipientTabs> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_anchor_tab_locations.is_empty() {
+        if let Some(include_anchor_tab_locations) = &params.include_anchor_tab_locations {
             query_args.push((
                 "include_anchor_tab_locations".to_string(),
                 include_anchor_tab_locations.to_string(),
             ));
         }
-        if !include_metadata.is_empty() {
+        if let Some(include_metadata) = &params.include_metadata {
             query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -63,6 +59,27 @@ impl EnvelopeRecipientTabs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `recipients_get_recipient_tab_with` and pass the query parameters as a `EnvelopeRecipientTabsRecipientsGetRecipientTabParams` instead.
+     */
+    #[deprecated(note = "use `recipients_get_recipient_tab_with` instead")]
+    pub async fn recipients_get_recipient_tab(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_anchor_tab_locations: &str,
+        include_metadata: &str,
+    ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        let params = EnvelopeRecipientTabsRecipientsGetRecipientTabParams {
+            include_anchor_tab_locations: Some(include_anchor_tab_locations.to_string())
+                .filter(|s| !s.is_empty()),
+            include_metadata: Some(include_metadata.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipients_get_recipient_tab_with(account_id, envelope_id, recipient_id, &params)
+            .await
+    }
+
     /**
     * Updates the tabs for a recipient.
 
@@ -162,3 +179,140 @@ impl EnvelopeRecipientTabs {
             .await
     }
 }
+
+/// The query parameters of `EnvelopeRecipientTabs::recipients_get_recipient_tab_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopeRecipientTabsRecipientsGetRecipientTabParams {
+    /// When set to **true**, all tabs with anchor tab properties are included in the response. The default value is **false**.
+    pub include_anchor_tab_locations: Option<String>,
+    /// When set to **true**, the response includes metadata indicating which properties are editable.
+    pub include_metadata: Option<String>,
+}
+
+impl EnvelopeRecipientTabsRecipientsGetRecipientTabParams {
+    pub fn include_anchor_tab_locations<T: ToString>(
+        mut self,
+        include_anchor_tab_locations: T,
+    ) -> Self {
+        self.include_anchor_tab_locations = Some(include_anchor_tab_locations.to_string());
+        self
+    }
+
+    pub fn include_metadata<T: ToString>(mut self, include_metadata: T) -> Self {
+        self.include_metadata = Some(include_metadata.to_string());
+        self
+    }
+}
+
+/// The functions of [`EnvelopeRecipientTabs`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeRecipientTabsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeRecipientTabsApi: Send + Sync {
+    /// See [`EnvelopeRecipientTabs::recipients_get_recipient_tab_with`].
+    async fn recipients_get_recipient_tab_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopeRecipientTabsRecipientsGetRecipientTabParams,
+    ) -> Result<crate::types::EnvelopeRecipientTabs>;
+
+    /// See [`EnvelopeRecipientTabs::recipients_put_recipient_tabs`].
+    async fn recipients_put_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs>;
+
+    /// See [`EnvelopeRecipientTabs::recipients_post_recipient_tabs`].
+    async fn recipients_post_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs>;
+
+    /// See [`EnvelopeRecipientTabs::recipients_delete_recipient_tabs`].
+    async fn recipients_delete_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeRecipientTabsApi for EnvelopeRecipientTabs {
+    async fn recipients_get_recipient_tab_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopeRecipientTabsRecipientsGetRecipientTabParams,
+    ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        EnvelopeRecipientTabs::recipients_get_recipient_tab_with(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            params,
+        )
+        .await
+    }
+
+    async fn recipients_put_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        EnvelopeRecipientTabs::recipients_put_recipient_tabs(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            body,
+        )
+        .await
+    }
+
+    async fn recipients_post_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        EnvelopeRecipientTabs::recipients_post_recipient_tabs(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            body,
+        )
+        .await
+    }
+
+    async fn recipients_delete_recipient_tabs(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        body: &crate::types::EnvelopeRecipientTabs,
+    ) -> Result<crate::types::EnvelopeRecipientTabs> {
+        EnvelopeRecipientTabs::recipients_delete_recipient_tabs(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            body,
+        )
+        .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeRecipients {
     pub client: Client,
@@ -25,34 +23,28 @@ impl EnvelopeRecipients {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_anchor_tab_locations: &str` --  When set to **true** and `include_tabs` value is set to **true**, all tabs with anchor tab properties are included in the response. .
-     * * `include_extended: &str` --  When set to **true**, the extended properties are included in the response. .
-     * * `include_metadata: &str` -- Boolean value that specifies whether to include metadata associated with the recipients (for envelopes only, not templates).
-     * * `include_tabs: &str` -- When set to **true**, the tab information associated with the recipient is included in the response.
+     * * `params: &EnvelopeRecipientsRecipientsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recipients_get(
+    pub async fn recipients_get_with(
         &self,
         account_id: &str,
         envelope_id: &str,
-        include_anchor_tab_locations: &str,
-        include_extended: &str,
-        include_metadata: &str,
-        include_tabs: &str,
+        params: &EnvelopeRecipientsRecipientsGetParams,
     ) -> Result<crate::types::EnvelopeRecipients> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_anchor_tab_locations.is_empty() {
+        if let Some(include_anchor_tab_locations) = &params.include_anchor_tab_locations {
             query_args.push((
                 "include_anchor_tab_locations".to_string(),
                 include_anchor_tab_locations.to_string(),
             ));
         }
-        if !include_extended.is_empty() {
+        if let Some(include_extended) = &params.include_extended {
             query_args.push(("include_extended".to_string(), include_extended.to_string()));
         }
-        if !include_metadata.is_empty() {
+        if let Some(include_metadata) = &params.include_metadata {
             query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
         }
-        if !include_tabs.is_empty() {
+        if let Some(include_tabs) = &params.include_tabs {
             query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -66,6 +58,30 @@ impl EnvelopeRecipients {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `recipients_get_with` and pass the query parameters as a `EnvelopeRecipientsRecipientsGetParams` instead.
+     */
+    #[deprecated(note = "use `recipients_get_with` instead")]
+    pub async fn recipients_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        include_anchor_tab_locations: &str,
+        include_extended: &str,
+        include_metadata: &str,
+        include_tabs: &str,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        let params = EnvelopeRecipientsRecipientsGetParams {
+            include_anchor_tab_locations: Some(include_anchor_tab_locations.to_string())
+                .filter(|s| !s.is_empty()),
+            include_extended: Some(include_extended.to_string()).filter(|s| !s.is_empty()),
+            include_metadata: Some(include_metadata.to_string()).filter(|s| !s.is_empty()),
+            include_tabs: Some(include_tabs.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipients_get_with(account_id, envelope_id, &params)
+            .await
+    }
+
     /**
      * Updates recipients in a draft envelope or corrects recipient information for an in-process envelope.
      *
@@ -106,30 +122,26 @@ impl EnvelopeRecipients {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `combine_same_order_recipients: &str` -- When set to **true**, recipients are combined or merged with matching recipients. Recipient matching occurs as part of [template matching](https://docs.docusign.com/DocuSignHelp/Content/automatic-template-matching.htm), and is based on Recipient Role and Routing Order.
-     * * `offline_signing: &str` -- Indicates if offline signing is enabled for the recipient when a network connection is unavailable. .
-     * * `resend_envelope: &str` -- When set to **true**, resends the   envelope if the new recipient's routing order is before or the same as the envelope's next recipient.
+     * * `params: &EnvelopeRecipientsRecipientsPutParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recipients_put(
+    pub async fn recipients_put_with(
         &self,
         account_id: &str,
         envelope_id: &str,
-        combine_same_order_recipients: &str,
-        offline_signing: &str,
-        resend_envelope: &str,
+        params: &EnvelopeRecipientsRecipientsPutParams,
         body: &crate::types::EnvelopeRecipients,
     ) -> Result<crate::types::RecipientsUpdateSummary> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !combine_same_order_recipients.is_empty() {
+        if let Some(combine_same_order_recipients) = &params.combine_same_order_recipients {
             query_args.push((
                 "combine_same_order_recipients".to_string(),
                 combine_same_order_recipients.to_string(),
             ));
         }
-        if !offline_signing.is_empty() {
+        if let Some(offline_signing) = &params.offline_signing {
             query_args.push(("offline_signing".to_string(), offline_signing.to_string()));
         }
-        if !resend_envelope.is_empty() {
+        if let Some(resend_envelope) = &params.resend_envelope {
             query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -145,6 +157,29 @@ impl EnvelopeRecipients {
             .await
     }
 
+    /**
+     * Deprecated: use `recipients_put_with` and pass the query parameters as a `EnvelopeRecipientsRecipientsPutParams` instead.
+     */
+    #[deprecated(note = "use `recipients_put_with` instead")]
+    pub async fn recipients_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        combine_same_order_recipients: &str,
+        offline_signing: &str,
+        resend_envelope: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::RecipientsUpdateSummary> {
+        let params = EnvelopeRecipientsRecipientsPutParams {
+            combine_same_order_recipients: Some(combine_same_order_recipients.to_string())
+                .filter(|s| !s.is_empty()),
+            offline_signing: Some(offline_signing.to_string()).filter(|s| !s.is_empty()),
+            resend_envelope: Some(resend_envelope.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipients_put_with(account_id, envelope_id, &params, body)
+            .await
+    }
+
     /**
      * Adds one or more recipients to an envelope.
      *
@@ -158,17 +193,17 @@ impl EnvelopeRecipients {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `resend_envelope: &str` -- When set to **true**, resends the   envelope if the new recipient's routing order is before or the same as the envelope's next recipient.
+     * * `params: &EnvelopeRecipientsRecipientsPostParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recipients_post(
+    pub async fn recipients_post_with(
         &self,
         account_id: &str,
         envelope_id: &str,
-        resend_envelope: &str,
+        params: &EnvelopeRecipientsRecipientsPostParams,
         body: &crate::types::EnvelopeRecipients,
     ) -> Result<crate::types::EnvelopeRecipients> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !resend_envelope.is_empty() {
+        if let Some(resend_envelope) = &params.resend_envelope {
             query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -184,6 +219,24 @@ impl EnvelopeRecipients {
             .await
     }
 
+    /**
+     * Deprecated: use `recipients_post_with` and pass the query parameters as a `EnvelopeRecipientsRecipientsPostParams` instead.
+     */
+    #[deprecated(note = "use `recipients_post_with` instead")]
+    pub async fn recipients_post(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        let params = EnvelopeRecipientsRecipientsPostParams {
+            resend_envelope: Some(resend_envelope.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipients_post_with(account_id, envelope_id, &params, body)
+            .await
+    }
+
     /**
      * Deletes recipients from an envelope.
      *
@@ -379,3 +432,266 @@ impl EnvelopeRecipients {
             .await
     }
 }
+
+/// The query parameters of `EnvelopeRecipients::recipients_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopeRecipientsRecipientsGetParams {
+    ///  When set to **true** and `include_tabs` value is set to **true**, all tabs with anchor tab properties are included in the response. .
+    pub include_anchor_tab_locations: Option<String>,
+    ///  When set to **true**, the extended properties are included in the response. .
+    pub include_extended: Option<String>,
+    /// Boolean value that specifies whether to include metadata associated with the recipients (for envelopes only, not templates).
+    pub include_metadata: Option<String>,
+    /// When set to **true**, the tab information associated with the recipient is included in the response.
+    pub include_tabs: Option<String>,
+}
+
+impl EnvelopeRecipientsRecipientsGetParams {
+    pub fn include_anchor_tab_locations<T: ToString>(
+        mut self,
+        include_anchor_tab_locations: T,
+    ) -> Self {
+        self.include_anchor_tab_locations = Some(include_anchor_tab_locations.to_string());
+        self
+    }
+
+    pub fn include_extended<T: ToString>(mut self, include_extended: T) -> Self {
+        self.include_extended = Some(include_extended.to_string());
+        self
+    }
+
+    pub fn include_metadata<T: ToString>(mut self, include_metadata: T) -> Self {
+        self.include_metadata = Some(include_metadata.to_string());
+        self
+    }
+
+    pub fn include_tabs<T: ToString>(mut self, include_tabs: T) -> Self {
+        self.include_tabs = Some(include_tabs.to_string());
+        self
+    }
+}
+
+/// The query parameters of `EnvelopeRecipients::recipients_put_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopeRecipientsRecipientsPutParams {
+    /// When set to **true**, recipients are combined or merged with matching recipients. Recipient matching occurs as part of [template matching](https://docs.docusign.com/DocuSignHelp/Content/automatic-template-matching.htm), and is based on Recipient Role and Routing Order.
+    pub combine_same_order_recipients: Option<String>,
+    /// Indicates if offline signing is enabled for the recipient when a network connection is unavailable. .
+    pub offline_signing: Option<String>,
+    /// When set to **true**, resends the   envelope if the new recipient's routing order is before or the same as the envelope's next recipient.
+    pub resend_envelope: Option<String>,
+}
+
+impl EnvelopeRecipientsRecipientsPutParams {
+    pub fn combine_same_order_recipients<T: ToString>(
+        mut self,
+        combine_same_order_recipients: T,
+    ) -> Self {
+        self.combine_same_order_recipients = Some(combine_same_order_recipients.to_string());
+        self
+    }
+
+    pub fn offline_signing<T: ToString>(mut self, offline_signing: T) -> Self {
+        self.offline_signing = Some(offline_signing.to_string());
+        self
+    }
+
+    pub fn resend_envelope<T: ToString>(mut self, resend_envelope: T) -> Self {
+        self.resend_envelope = Some(resend_envelope.to_string());
+        self
+    }
+}
+
+/// The query parameters of `EnvelopeRecipients::recipients_post_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopeRecipientsRecipientsPostParams {
+    /// When set to **true**, resends the   envelope if the new recipient's routing order is before or the same as the envelope's next recipient.
+    pub resend_envelope: Option<String>,
+}
+
+impl EnvelopeRecipientsRecipientsPostParams {
+    pub fn resend_envelope<T: ToString>(mut self, resend_envelope: T) -> Self {
+        self.resend_envelope = Some(resend_envelope.to_string());
+        self
+    }
+}
+
+/// The functions of [`EnvelopeRecipients`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeRecipientsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeRecipientsApi: Send + Sync {
+    /// See [`EnvelopeRecipients::recipients_get_with`].
+    async fn recipients_get_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeRecipientsRecipientsGetParams,
+    ) -> Result<crate::types::EnvelopeRecipients>;
+
+    /// See [`EnvelopeRecipients::recipients_put_with`].
+    async fn recipients_put_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeRecipientsRecipientsPutParams,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::RecipientsUpdateSummary>;
+
+    /// See [`EnvelopeRecipients::recipients_post_with`].
+    async fn recipients_post_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeRecipientsRecipientsPostParams,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::EnvelopeRecipients>;
+
+    /// See [`EnvelopeRecipients::recipients_delete`].
+    async fn recipients_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::EnvelopeRecipients>;
+
+    /// See [`EnvelopeRecipients::recipients_put_document_visibility`].
+    async fn recipients_put_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> Result<crate::types::DocumentVisibilityList>;
+
+    /// See [`EnvelopeRecipients::recipients_delete_recipient`].
+    async fn recipients_delete_recipient(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::EnvelopeRecipients>;
+
+    /// See [`EnvelopeRecipients::recipients_post_recipient_proof_file_resource_token`].
+    async fn recipients_post_recipient_proof_file_resource_token(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ProofServiceResourceToken>;
+
+    /// See [`EnvelopeRecipients::views_post_recipient_manual_review_view`].
+    async fn views_post_recipient_manual_review_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ViewUrl>;
+
+    /// See [`EnvelopeRecipients::views_post_preview`].
+    async fn views_post_preview(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> Result<crate::types::ViewUrl>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeRecipientsApi for EnvelopeRecipients {
+    async fn recipients_get_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeRecipientsRecipientsGetParams,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        EnvelopeRecipients::recipients_get_with(self, account_id, envelope_id, params).await
+    }
+
+    async fn recipients_put_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeRecipientsRecipientsPutParams,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::RecipientsUpdateSummary> {
+        EnvelopeRecipients::recipients_put_with(self, account_id, envelope_id, params, body).await
+    }
+
+    async fn recipients_post_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeRecipientsRecipientsPostParams,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        EnvelopeRecipients::recipients_post_with(self, account_id, envelope_id, params, body).await
+    }
+
+    async fn recipients_delete(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeRecipients,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        EnvelopeRecipients::recipients_delete(self, account_id, envelope_id, body).await
+    }
+
+    async fn recipients_put_document_visibility(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentVisibilityList,
+    ) -> Result<crate::types::DocumentVisibilityList> {
+        EnvelopeRecipients::recipients_put_document_visibility(self, account_id, envelope_id, body)
+            .await
+    }
+
+    async fn recipients_delete_recipient(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::EnvelopeRecipients> {
+        EnvelopeRecipients::recipients_delete_recipient(self, account_id, envelope_id, recipient_id)
+            .await
+    }
+
+    async fn recipients_post_recipient_proof_file_resource_token(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ProofServiceResourceToken> {
+        EnvelopeRecipients::recipients_post_recipient_proof_file_resource_token(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+        )
+        .await
+    }
+
+    async fn views_post_recipient_manual_review_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::ViewUrl> {
+        EnvelopeRecipients::views_post_recipient_manual_review_view(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+        )
+        .await
+    }
+
+    async fn views_post_preview(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> Result<crate::types::ViewUrl> {
+        EnvelopeRecipients::views_post_preview(self, account_id, envelope_id, body).await
+    }
+}
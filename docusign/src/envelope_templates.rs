@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeTemplates {
     pub client: Client,
@@ -24,22 +22,17 @@ impl EnvelopeTemplates {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `document_id: &str` -- The `documentId` is set by the API client. It is an integer that falls between `1` and 2,147,483,647. The value is encoded as a string without commas. The values `1`, `2`, `3`, and so on are typically used to identify the first few documents in an envelope. Tab definitions include a `documentId` property that specifies the document on which to place the tab.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include: &str` -- A comma-separated list that limits the results.
-     *   Valid values are:
-     *   
-     *   * `applied`
-     *   * `matched`
-     *   .
+     * * `params: &EnvelopeTemplatesTemplatesGetDocumentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn templates_get_document(
+    pub async fn templates_get_document_with(
         &self,
         account_id: &str,
         document_id: &str,
         envelope_id: &str,
-        include: &str,
+        params: &EnvelopeTemplatesTemplatesGetDocumentParams,
     ) -> Result<crate::types::TemplateInformation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -54,6 +47,24 @@ impl EnvelopeTemplates {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `templates_get_document_with` and pass the query parameters as a `EnvelopeTemplatesTemplatesGetDocumentParams` instead.
+     */
+    #[deprecated(note = "use `templates_get_document_with` instead")]
+    pub async fn templates_get_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        include: &str,
+    ) -> Result<crate::types::TemplateInformation> {
+        let params = EnvelopeTemplatesTemplatesGetDocumentParams {
+            include: Some(include.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.templates_get_document_with(account_id, document_id, envelope_id, &params)
+            .await
+    }
+
     /**
      * Adds templates to a document in an  envelope.
      *
@@ -66,18 +77,18 @@ impl EnvelopeTemplates {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `document_id: &str` -- The `documentId` is set by the API client. It is an integer that falls between `1` and 2,147,483,647. The value is encoded as a string without commas. The values `1`, `2`, `3`, and so on are typically used to identify the first few documents in an envelope. Tab definitions include a `documentId` property that specifies the document on which to place the tab.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `preserve_template_recipient: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &EnvelopeTemplatesTemplatesPostDocumentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn templates_post_document(
+    pub async fn templates_post_document_with(
         &self,
         account_id: &str,
         document_id: &str,
         envelope_id: &str,
-        preserve_template_recipient: &str,
+        params: &EnvelopeTemplatesTemplatesPostDocumentParams,
         body: &crate::types::DocumentTemplateList,
     ) -> Result<crate::types::DocumentTemplateList> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !preserve_template_recipient.is_empty() {
+        if let Some(preserve_template_recipient) = &params.preserve_template_recipient {
             query_args.push((
                 "preserve_template_recipient".to_string(),
                 preserve_template_recipient.to_string(),
@@ -97,6 +108,26 @@ impl EnvelopeTemplates {
             .await
     }
 
+    /**
+     * Deprecated: use `templates_post_document_with` and pass the query parameters as a `EnvelopeTemplatesTemplatesPostDocumentParams` instead.
+     */
+    #[deprecated(note = "use `templates_post_document_with` instead")]
+    pub async fn templates_post_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        preserve_template_recipient: &str,
+        body: &crate::types::DocumentTemplateList,
+    ) -> Result<crate::types::DocumentTemplateList> {
+        let params = EnvelopeTemplatesTemplatesPostDocumentParams {
+            preserve_template_recipient: Some(preserve_template_recipient.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.templates_post_document_with(account_id, document_id, envelope_id, &params, body)
+            .await
+    }
+
     /**
      * Deletes a template from a document in an existing envelope.
      *
@@ -141,16 +172,16 @@ impl EnvelopeTemplates {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include: &str` -- The possible value is `matching_applied`, which returns template matching information for the template.
+     * * `params: &EnvelopeTemplatesTemplatesGetEnvelopeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn templates_get_envelope(
+    pub async fn templates_get_envelope_with(
         &self,
         account_id: &str,
         envelope_id: &str,
-        include: &str,
+        params: &EnvelopeTemplatesTemplatesGetEnvelopeParams,
     ) -> Result<crate::types::TemplateInformation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -164,6 +195,23 @@ impl EnvelopeTemplates {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `templates_get_envelope_with` and pass the query parameters as a `EnvelopeTemplatesTemplatesGetEnvelopeParams` instead.
+     */
+    #[deprecated(note = "use `templates_get_envelope_with` instead")]
+    pub async fn templates_get_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        include: &str,
+    ) -> Result<crate::types::TemplateInformation> {
+        let params = EnvelopeTemplatesTemplatesGetEnvelopeParams {
+            include: Some(include.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.templates_get_envelope_with(account_id, envelope_id, &params)
+            .await
+    }
+
     /**
      * Adds templates to an envelope.
      *
@@ -175,17 +223,17 @@ impl EnvelopeTemplates {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `preserve_template_recipient: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &EnvelopeTemplatesTemplatesPostEnvelopeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn templates_post_envelope(
+    pub async fn templates_post_envelope_with(
         &self,
         account_id: &str,
         envelope_id: &str,
-        preserve_template_recipient: &str,
+        params: &EnvelopeTemplatesTemplatesPostEnvelopeParams,
         body: &crate::types::DocumentTemplateList,
     ) -> Result<crate::types::DocumentTemplateList> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !preserve_template_recipient.is_empty() {
+        if let Some(preserve_template_recipient) = &params.preserve_template_recipient {
             query_args.push((
                 "preserve_template_recipient".to_string(),
                 preserve_template_recipient.to_string(),
@@ -203,4 +251,217 @@ impl EnvelopeTemplates {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `templates_post_envelope_with` and pass the query parameters as a `EnvelopeTemplatesTemplatesPostEnvelopeParams` instead.
+     */
+    #[deprecated(note = "use `templates_post_envelope_with` instead")]
+    pub async fn templates_post_envelope(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        preserve_template_recipient: &str,
+        body: &crate::types::DocumentTemplateList,
+    ) -> Result<crate::types::DocumentTemplateList> {
+        let params = EnvelopeTemplatesTemplatesPostEnvelopeParams {
+            preserve_template_recipient: Some(preserve_template_recipient.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.templates_post_envelope_with(account_id, envelope_id, &params, body)
+            .await
+    }
+}
+
+/// The query parameters of `EnvelopeTemplates::templates_get_document_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopeTemplatesTemplatesGetDocumentParams {
+    /// A comma-separated list that limits the results.
+    /// Valid values are:
+    ///
+    /// * `applied`
+    /// * `matched`
+    ///   .
+    pub include: Option<String>,
+}
+
+impl EnvelopeTemplatesTemplatesGetDocumentParams {
+    pub fn include<T: ToString>(mut self, include: T) -> Self {
+        self.include = Some(include.to_string());
+        self
+    }
+}
+
+/// The query parameters of `EnvelopeTemplates::templates_post_document_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopeTemplatesTemplatesPostDocumentParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub preserve_template_recipient: Option<String>,
+}
+
+impl EnvelopeTemplatesTemplatesPostDocumentParams {
+    pub fn preserve_template_recipient<T: ToString>(
+        mut self,
+        preserve_template_recipient: T,
+    ) -> Self {
+        self.preserve_template_recipient = Some(preserve_template_recipient.to_string());
+        self
+    }
+}
+
+/// The query parameters of `EnvelopeTemplates::templates_get_envelope_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopeTemplatesTemplatesGetEnvelopeParams {
+    /// The possible value is `matching_applied`, which returns template matching information for the template.
+    pub include: Option<String>,
+}
+
+impl EnvelopeTemplatesTemplatesGetEnvelopeParams {
+    pub fn include<T: ToString>(mut self, include: T) -> Self {
+        self.include = Some(include.to_string());
+        self
+    }
+}
+
+/// The query parameters of `EnvelopeTemplates::templates_post_envelope_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopeTemplatesTemplatesPostEnvelopeParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub preserve_template_recipient: Option<String>,
+}
+
+impl EnvelopeTemplatesTemplatesPostEnvelopeParams {
+    pub fn preserve_template_recipient<T: ToString>(
+        mut self,
+        preserve_template_recipient: T,
+    ) -> Self {
+        self.preserve_template_recipient = Some(preserve_template_recipient.to_string());
+        self
+    }
+}
+
+/// The functions of [`EnvelopeTemplates`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeTemplatesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeTemplatesApi: Send + Sync {
+    /// See [`EnvelopeTemplates::templates_get_document_with`].
+    async fn templates_get_document_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeTemplatesTemplatesGetDocumentParams,
+    ) -> Result<crate::types::TemplateInformation>;
+
+    /// See [`EnvelopeTemplates::templates_post_document_with`].
+    async fn templates_post_document_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeTemplatesTemplatesPostDocumentParams,
+        body: &crate::types::DocumentTemplateList,
+    ) -> Result<crate::types::DocumentTemplateList>;
+
+    /// See [`EnvelopeTemplates::templates_delete_document`].
+    async fn templates_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        template_id: &str,
+    ) -> Result<()>;
+
+    /// See [`EnvelopeTemplates::templates_get_envelope_with`].
+    async fn templates_get_envelope_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeTemplatesTemplatesGetEnvelopeParams,
+    ) -> Result<crate::types::TemplateInformation>;
+
+    /// See [`EnvelopeTemplates::templates_post_envelope_with`].
+    async fn templates_post_envelope_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeTemplatesTemplatesPostEnvelopeParams,
+        body: &crate::types::DocumentTemplateList,
+    ) -> Result<crate::types::DocumentTemplateList>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeTemplatesApi for EnvelopeTemplates {
+    async fn templates_get_document_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeTemplatesTemplatesGetDocumentParams,
+    ) -> Result<crate::types::TemplateInformation> {
+        EnvelopeTemplates::templates_get_document_with(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            params,
+        )
+        .await
+    }
+
+    async fn templates_post_document_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeTemplatesTemplatesPostDocumentParams,
+        body: &crate::types::DocumentTemplateList,
+    ) -> Result<crate::types::DocumentTemplateList> {
+        EnvelopeTemplates::templates_post_document_with(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn templates_delete_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        template_id: &str,
+    ) -> Result<()> {
+        EnvelopeTemplates::templates_delete_document(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            template_id,
+        )
+        .await
+    }
+
+    async fn templates_get_envelope_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeTemplatesTemplatesGetEnvelopeParams,
+    ) -> Result<crate::types::TemplateInformation> {
+        EnvelopeTemplates::templates_get_envelope_with(self, account_id, envelope_id, params).await
+    }
+
+    async fn templates_post_envelope_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopeTemplatesTemplatesPostEnvelopeParams,
+        body: &crate::types::DocumentTemplateList,
+    ) -> Result<crate::types::DocumentTemplateList> {
+        EnvelopeTemplates::templates_post_envelope_with(self, account_id, envelope_id, params, body)
+            .await
+    }
 }
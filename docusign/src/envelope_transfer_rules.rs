@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeTransferRules {
     pub client: Client,
@@ -24,20 +22,18 @@ impl EnvelopeTransferRules {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- (Optional) The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
+     * * `params: &EnvelopeTransferRulesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         account_id: &str,
-        count: &str,
-        start_position: &str,
+        params: &EnvelopeTransferRulesGetParams,
     ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -50,6 +46,23 @@ impl EnvelopeTransferRules {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `EnvelopeTransferRulesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
+        let params = EnvelopeTransferRulesGetParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(account_id, &params).await
+    }
+
     /**
      * Changes the status of multiple envelope transfer rules.
      *
@@ -172,3 +185,102 @@ impl EnvelopeTransferRules {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `EnvelopeTransferRules::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopeTransferRulesGetParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub count: Option<String>,
+    /// (Optional) The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
+    pub start_position: Option<String>,
+}
+
+impl EnvelopeTransferRulesGetParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+}
+
+/// The functions of [`EnvelopeTransferRules`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeTransferRulesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeTransferRulesApi: Send + Sync {
+    /// See [`EnvelopeTransferRules::get_with`].
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &EnvelopeTransferRulesGetParams,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation>;
+
+    /// See [`EnvelopeTransferRules::put`].
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleInformation,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation>;
+
+    /// See [`EnvelopeTransferRules::post`].
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleRequest,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation>;
+
+    /// See [`EnvelopeTransferRules::put_rule`].
+    async fn put_rule(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+        body: &crate::types::EnvelopeTransferRule,
+    ) -> Result<crate::types::EnvelopeTransferRule>;
+
+    /// See [`EnvelopeTransferRules::delete`].
+    async fn delete(&self, account_id: &str, envelope_transfer_rule_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeTransferRulesApi for EnvelopeTransferRules {
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &EnvelopeTransferRulesGetParams,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
+        EnvelopeTransferRules::get_with(self, account_id, params).await
+    }
+
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleInformation,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
+        EnvelopeTransferRules::put(self, account_id, body).await
+    }
+
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTransferRuleRequest,
+    ) -> Result<crate::types::EnvelopeTransferRuleInformation> {
+        EnvelopeTransferRules::post(self, account_id, body).await
+    }
+
+    async fn put_rule(
+        &self,
+        account_id: &str,
+        envelope_transfer_rule_id: &str,
+        body: &crate::types::EnvelopeTransferRule,
+    ) -> Result<crate::types::EnvelopeTransferRule> {
+        EnvelopeTransferRules::put_rule(self, account_id, envelope_transfer_rule_id, body).await
+    }
+
+    async fn delete(&self, account_id: &str, envelope_transfer_rule_id: &str) -> Result<()> {
+        EnvelopeTransferRules::delete(self, account_id, envelope_transfer_rule_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeViews {
     pub client: Client,
@@ -308,3 +306,135 @@ impl EnvelopeViews {
             .await
     }
 }
+
+/// The functions of [`EnvelopeViews`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeViewsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeViewsApi: Send + Sync {
+    /// See [`EnvelopeViews::views_post_envelope_correct_view`].
+    async fn views_post_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<crate::types::EnvelopeViews>;
+
+    /// See [`EnvelopeViews::views_delete_envelope_correct_view`].
+    async fn views_delete_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<()>;
+
+    /// See [`EnvelopeViews::views_post_envelope_edit_view`].
+    async fn views_post_envelope_edit_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::EnvelopeViews>;
+
+    /// See [`EnvelopeViews::views_post_envelope_recipient_view`].
+    async fn views_post_envelope_recipient_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> Result<crate::types::EnvelopeViews>;
+
+    /// See [`EnvelopeViews::views_post_envelope_sender_view`].
+    async fn views_post_envelope_sender_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::EnvelopeViews>;
+
+    /// See [`EnvelopeViews::views_post_envelope_recipient_shared_view`].
+    async fn views_post_envelope_recipient_shared_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> Result<crate::types::ViewUrl>;
+
+    /// See [`EnvelopeViews::views_post_account_console_view`].
+    async fn views_post_account_console_view(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConsoleViewRequest,
+    ) -> Result<crate::types::EnvelopeViews>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeViewsApi for EnvelopeViews {
+    async fn views_post_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        EnvelopeViews::views_post_envelope_correct_view(self, account_id, envelope_id, body).await
+    }
+
+    async fn views_delete_envelope_correct_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::CorrectViewRequest,
+    ) -> Result<()> {
+        EnvelopeViews::views_delete_envelope_correct_view(self, account_id, envelope_id, body).await
+    }
+
+    async fn views_post_envelope_edit_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        EnvelopeViews::views_post_envelope_edit_view(self, account_id, envelope_id, body).await
+    }
+
+    async fn views_post_envelope_recipient_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        EnvelopeViews::views_post_envelope_recipient_view(self, account_id, envelope_id, body).await
+    }
+
+    async fn views_post_envelope_sender_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        EnvelopeViews::views_post_envelope_sender_view(self, account_id, envelope_id, body).await
+    }
+
+    async fn views_post_envelope_recipient_shared_view(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::RecipientViewRequest,
+    ) -> Result<crate::types::ViewUrl> {
+        EnvelopeViews::views_post_envelope_recipient_shared_view(
+            self,
+            account_id,
+            envelope_id,
+            body,
+        )
+        .await
+    }
+
+    async fn views_post_account_console_view(
+        &self,
+        account_id: &str,
+        body: &crate::types::ConsoleViewRequest,
+    ) -> Result<crate::types::EnvelopeViews> {
+        EnvelopeViews::views_post_account_console_view(self, account_id, body).await
+    }
+}
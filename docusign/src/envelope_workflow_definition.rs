@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnvelopeWorkflowDefinition {
     pub client: Client,
@@ -163,3 +161,82 @@ impl EnvelopeWorkflowDefinition {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`EnvelopeWorkflowDefinition`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopeWorkflowDefinitionApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopeWorkflowDefinitionApi: Send + Sync {
+    /// See [`EnvelopeWorkflowDefinition::get`].
+    async fn get(&self, account_id: &str, envelope_id: &str) -> Result<crate::types::Workflow>;
+
+    /// See [`EnvelopeWorkflowDefinition::put`].
+    async fn put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Workflow,
+    ) -> Result<crate::types::Workflow>;
+
+    /// See [`EnvelopeWorkflowDefinition::delete`].
+    async fn delete(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+
+    /// See [`EnvelopeWorkflowDefinition::get_template`].
+    async fn get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::Workflow>;
+
+    /// See [`EnvelopeWorkflowDefinition::put_template`].
+    async fn put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::Workflow,
+    ) -> Result<crate::types::Workflow>;
+
+    /// See [`EnvelopeWorkflowDefinition::delete_template`].
+    async fn delete_template(&self, account_id: &str, template_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopeWorkflowDefinitionApi for EnvelopeWorkflowDefinition {
+    async fn get(&self, account_id: &str, envelope_id: &str) -> Result<crate::types::Workflow> {
+        EnvelopeWorkflowDefinition::get(self, account_id, envelope_id).await
+    }
+
+    async fn put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::Workflow,
+    ) -> Result<crate::types::Workflow> {
+        EnvelopeWorkflowDefinition::put(self, account_id, envelope_id, body).await
+    }
+
+    async fn delete(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        EnvelopeWorkflowDefinition::delete(self, account_id, envelope_id).await
+    }
+
+    async fn get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::Workflow> {
+        EnvelopeWorkflowDefinition::get_template(self, account_id, template_id).await
+    }
+
+    async fn put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::Workflow,
+    ) -> Result<crate::types::Workflow> {
+        EnvelopeWorkflowDefinition::put_template(self, account_id, template_id, body).await
+    }
+
+    async fn delete_template(&self, account_id: &str, template_id: &str) -> Result<()> {
+        EnvelopeWorkflowDefinition::delete_template(self, account_id, template_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Envelopes {
     pub client: Client,
@@ -25,9 +23,6 @@ impl Envelopes {
      *
      * Your request must include one or more of the following parameters:
      *
-     * * `from_date`
-     * * `envelope_ids`
-     * * `transaction_ids`
      *
      *
      * Getting envelope status using `transaction_ids` is useful
@@ -93,320 +88,117 @@ impl Envelopes {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `ac_status: &str` -- Specifies the Authoritative Copy Status for the envelopes. The possible values are: Unknown, Original, Transferred, AuthoritativeCopy, AuthoritativeCopyExportPending, AuthoritativeCopyExported, DepositPending, Deposited, DepositedEO, or DepositFailed.
-     * * `block: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `cdse_mode: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `continuation_token: &str` -- A token returned in the response to a previous API call that is used to resume a search query from a specific point.
-     * * `count: &str` -- Optional. Number of items to return. Currently there is no implicit maximum limit of the number of items that can be returned.
-     *   .
-     * * `custom_field: &str` -- Optional. Specifies a envelope custom field name and value searched for in the envelopes. Format: `custom_envelope_field_name=desired_value`
-     *   
-     *   Example: If you have an envelope custom field named "Region" and you want to search for all envelopes where the value is "West" you would use set this parameter to `Region=West`.
-     *   
-     *   .
-     * * `email: &str` -- Limit results to envelopes
-     *   sent by the account user
-     *   with this email address.
-     *   
-     *   `user_name` must be given as well,
-     *   and both `email` and `user_name`
-     *   must refer to an existing account user.
-     *   .
-     * * `envelope_ids: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `exclude: &str` -- Excludes information from the response. Enter  as a comma-separated list (e.g., `folders,powerforms`). Valid values are:
-     *   
-     *   - `recipients`
-     *   - `powerforms`
-     *   - `folders`.
-     * * `folder_ids: &str` -- Returns the envelopes from specific folders. Enter as a comma-separated list of either valid folder Guids or the following values:
-     *   
-     *   - `awaiting_my_signature`
-     *   - `completed`
-     *   - `draft`
-     *   - `drafts`
-     *   - `expiring_soon`
-     *   - `inbox`
-     *   - `out_for_signature`
-     *   - `recyclebin`
-     *   - `sentitems`
-     *   - `waiting_for_others`.
-     * * `folder_types: &str` -- A comma-separated list of folder types you want to retrieve envelopes from. Valid values are:
-     *   
-     *   - `normal`
-     *   - `inbox`
-     *   - `sentitems`
-     *   - `draft`
-     *   - `templates`.
-     * * `from_date: &str` -- Specifies the date and time
-     *   to start looking for status changes.
-     *   This parameter is required
-     *   unless `envelopeIds` or `transactionIds`
-     *   are set.
-     *   
-     *   
-     *   Although you can use any date format
-     *   supported by the .NET system library's
-     *   [`DateTime.Parse()`][msoft] function,
-     *   DocuSign recommends
-     *   using [ISO 8601][] format dates
-     *   with an explicit time zone offset
-     *   If you do not provide
-     *   a time zone offset,
-     *   the method uses the server's time zone.
-     *   
-     *   For example, the following dates and times refer to the same instant:
-     *   
-     *   * `2017-05-02T01:44Z`
-     *   * `2017-05-01T21:44-04:00`
-     *   * `2017-05-01T18:44-07:00`
-     *   
-     *   
-     *   [msoft]: https://msdn.microsoft.com/en-us/library/system.datetime.parse(v=vs.110).aspx#StringToParse
-     *   [ISO 8601]: https://en.wikipedia.org/wiki/ISO_8601.
-     * * `from_to_status: &str` -- This is the status type checked for in the `from_date`/`to_date` period. If `changed` is specified, then envelopes that changed status during the period are found. If for example, `created` is specified, then envelopes created during the period are found. Default is `changed`.
-     *   
-     *   Possible values are: Voided, Changed, Created, Deleted, Sent, Delivered, Signed, Completed, Declined, TimedOut and Processing.
-     * * `include: &str` -- Specifies additional information to return  about the envelopes. Enter a comma-separated list, such as `tabs,recipients`. Valid values are:
-     *   
-     *   - `custom_fields`: The custom fields associated with the envelope.
-     *   - `documents`: The documents associated with the envelope.
-     *   - `attachments`: The attachments associated with the envelope.
-     *   - `extensions`: Information about the email settings associated with the envelope.
-     *   - `folders`: The folders where the envelope exists.
-     *   - `recipients`: The recipients associated with the envelope.
-     *   - `powerform`: The PowerForms associated with the envelope.
-     *   - `payment_tabs`: The payment tabs associated with the envelope.
-     *   .
-     * * `include_purge_information: &str` -- When set to **true**, information about envelopes that have been deleted is included in the response.
-     * * `intersecting_folder_ids: &str` -- A comma-separated list of folders that you want want to get envelopes from. Valid values are:
-     *   
-     *   - `normal`
-     *   - `inbox`
-     *   - `sentitems`
-     *   - `draft`
-     *   - `templates`.
-     * * `last_queried_date: &str` -- Returns envelopes that were modified prior to the specified date and time.
-     *   
-     *   Example: `2020-05-09T21:56:12.2500000Z`.
-     * * `order: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `order_by: &str` -- Sorts results according to a specific property. Valid values are:
-     *   
-     *   - `last_modified`
-     *   - `action_required`
-     *   - `created`
-     *   - `completed`
-     *   - `envelope_name`
-     *   - `expire`
-     *   - `sent`
-     *   - `signer_list`
-     *   - `status`
-     *   - `subject`
-     *   - `user_name`
-     *   - `status_changed`
-     *   - `last_modified`.
-     * * `powerformids: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `query_budget: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `requester_date_format: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `search_text: &str` -- Free text search criteria that you can use to filter the list of envelopes that is returned.
-     * * `start_position: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `status: &str` -- A comma-separated list of current envelope statuses to included in the response. Possible values are:
-     *   
-     *   * `completed`
-     *   * `created`
-     *   * `declined`
-     *   * `deleted`
-     *   * `delivered`
-     *   * `processing`
-     *   * `sent`
-     *   * `signed`
-     *   * `timedout`
-     *   * `voided`
-     *   
-     *   The `any` value is equivalent to any status.
-     *   .
-     * * `to_date: &str` -- Specifies the date and time
-     *   to stop looking for status changes.
-     *   The default is the current date and time.
-     *   
-     *   Although you can use any date format
-     *   supported by the .NET system library's
-     *   [`DateTime.Parse()`][msoft] function,
-     *   DocuSign recommends
-     *   using [ISO 8601][] format dates
-     *   with an explicit time zone offset
-     *   If you do not provide
-     *   a time zone offset,
-     *   the method uses the server's time zone.
-     *   
-     *   For example, the following dates and times refer to the same instant:
-     *   
-     *   * `2017-05-02T01:44Z`
-     *   * `2017-05-01T21:44-04:00`
-     *   * `2017-05-01T18:44-07:00`
-     *   
-     *   
-     *   [msoft]: https://msdn.microsoft.com/en-us/library/system.datetime.parse(v=vs.110).aspx#StringToParse
-     *   [ISO 8601]: https://en.wikipedia.org/wiki/ISO_8601
-     *   .
-     * * `transaction_ids: &str` -- If included in the query string, this is a comma separated list of envelope `transactionId`s.
-     *   
-     *   If included in the `request_body`, this is a list of envelope `transactionId`s.
-     *   
-     *   ###### Note: `transactionId`s are only valid in the DocuSign system for seven days.
-     *   .
-     * * `user_filter: &str` -- Returns envelopes where the current user is the recipient, the sender, or the recipient only. (For example, `user_filter=sender`.) Valid values are:
-     *   
-     *   - `sender`
-     *   - `recipient`
-     *   - `recipient_only`.
-     * * `user_id: &str` -- The ID of the user who created the envelopes to be retrieved. Note that an account can have multiple users, and any user with account access can retrieve envelopes by user_id from the account.
-     * * `user_name: &str` -- Limit results to envelopes
-     *   sent by the account user
-     *   with this user name.
-     *   
-     *   `email` must be given as well,
-     *   and both `email` and `user_name`
-     *   must refer to an existing account user.
-     *   .
+     * * `params: &EnvelopesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         account_id: &str,
-        ac_status: &str,
-        block: &str,
-        cdse_mode: &str,
-        continuation_token: &str,
-        count: &str,
-        custom_field: &str,
-        email: &str,
-        envelope_ids: &str,
-        exclude: &str,
-        folder_ids: &str,
-        folder_types: &str,
-        from_date: &str,
-        from_to_status: &str,
-        include: &str,
-        include_purge_information: &str,
-        intersecting_folder_ids: &str,
-        last_queried_date: &str,
-        order: &str,
-        order_by: &str,
-        powerformids: &str,
-        query_budget: &str,
-        requester_date_format: &str,
-        search_text: &str,
-        start_position: &str,
-        status: &str,
-        to_date: &str,
-        transaction_ids: &str,
-        user_filter: &str,
-        user_id: &str,
-        user_name: &str,
+        params: &EnvelopesGetParams,
     ) -> Result<crate::types::EnvelopesInformation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ac_status.is_empty() {
+        if let Some(ac_status) = &params.ac_status {
             query_args.push(("ac_status".to_string(), ac_status.to_string()));
         }
-        if !block.is_empty() {
+        if let Some(block) = &params.block {
             query_args.push(("block".to_string(), block.to_string()));
         }
-        if !cdse_mode.is_empty() {
+        if let Some(cdse_mode) = &params.cdse_mode {
             query_args.push(("cdse_mode".to_string(), cdse_mode.to_string()));
         }
-        if !continuation_token.is_empty() {
+        if let Some(continuation_token) = &params.continuation_token {
             query_args.push((
                 "continuation_token".to_string(),
                 continuation_token.to_string(),
             ));
         }
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !custom_field.is_empty() {
+        if let Some(custom_field) = &params.custom_field {
             query_args.push(("custom_field".to_string(), custom_field.to_string()));
         }
-        if !email.is_empty() {
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
-        if !envelope_ids.is_empty() {
+        if let Some(envelope_ids) = &params.envelope_ids {
             query_args.push(("envelope_ids".to_string(), envelope_ids.to_string()));
         }
-        if !exclude.is_empty() {
+        if let Some(exclude) = &params.exclude {
             query_args.push(("exclude".to_string(), exclude.to_string()));
         }
-        if !folder_ids.is_empty() {
+        if let Some(folder_ids) = &params.folder_ids {
             query_args.push(("folder_ids".to_string(), folder_ids.to_string()));
         }
-        if !folder_types.is_empty() {
+        if let Some(folder_types) = &params.folder_types {
             query_args.push(("folder_types".to_string(), folder_types.to_string()));
         }
-        if !from_date.is_empty() {
+        if let Some(from_date) = &params.from_date {
             query_args.push(("from_date".to_string(), from_date.to_string()));
         }
-        if !from_to_status.is_empty() {
+        if let Some(from_to_status) = &params.from_to_status {
             query_args.push(("from_to_status".to_string(), from_to_status.to_string()));
         }
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
-        if !include_purge_information.is_empty() {
+        if let Some(include_purge_information) = &params.include_purge_information {
             query_args.push((
                 "include_purge_information".to_string(),
                 include_purge_information.to_string(),
             ));
         }
-        if !intersecting_folder_ids.is_empty() {
+        if let Some(intersecting_folder_ids) = &params.intersecting_folder_ids {
             query_args.push((
                 "intersecting_folder_ids".to_string(),
                 intersecting_folder_ids.to_string(),
             ));
         }
-        if !last_queried_date.is_empty() {
+        if let Some(last_queried_date) = &params.last_queried_date {
             query_args.push((
                 "last_queried_date".to_string(),
                 last_queried_date.to_string(),
             ));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !order_by.is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("order_by".to_string(), order_by.to_string()));
         }
-        if !powerformids.is_empty() {
+        if let Some(powerformids) = &params.powerformids {
             query_args.push(("powerformids".to_string(), powerformids.to_string()));
         }
-        if !query_budget.is_empty() {
+        if let Some(query_budget) = &params.query_budget {
             query_args.push(("query_budget".to_string(), query_budget.to_string()));
         }
-        if !requester_date_format.is_empty() {
+        if let Some(requester_date_format) = &params.requester_date_format {
             query_args.push((
                 "requester_date_format".to_string(),
                 requester_date_format.to_string(),
             ));
         }
-        if !search_text.is_empty() {
+        if let Some(search_text) = &params.search_text {
             query_args.push(("search_text".to_string(), search_text.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !to_date.is_empty() {
+        if let Some(to_date) = &params.to_date {
             query_args.push(("to_date".to_string(), to_date.to_string()));
         }
-        if !transaction_ids.is_empty() {
+        if let Some(transaction_ids) = &params.transaction_ids {
             query_args.push(("transaction_ids".to_string(), transaction_ids.to_string()));
         }
-        if !user_filter.is_empty() {
+        if let Some(user_filter) = &params.user_filter {
             query_args.push(("user_filter".to_string(), user_filter.to_string()));
         }
-        if !user_id.is_empty() {
+        if let Some(user_id) = &params.user_id {
             query_args.push(("user_id".to_string(), user_id.to_string()));
         }
-        if !user_name.is_empty() {
+        if let Some(user_name) = &params.user_name {
             query_args.push(("user_name".to_string(), user_name.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -419,6 +211,82 @@ impl Envelopes {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `EnvelopesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        account_id: &str,
+        ac_status: &str,
+        block: &str,
+        cdse_mode: &str,
+        continuation_token: &str,
+        count: &str,
+        custom_field: &str,
+        email: &str,
+        envelope_ids: &str,
+        exclude: &str,
+        folder_ids: &str,
+        folder_types: &str,
+        from_date: &str,
+        from_to_status: &str,
+        include: &str,
+        include_purge_information: &str,
+        intersecting_folder_ids: &str,
+        last_queried_date: &str,
+        order: &str,
+        order_by: &str,
+        powerformids: &str,
+        query_budget: &str,
+        requester_date_format: &str,
+        search_text: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+        transaction_ids: &str,
+        user_filter: &str,
+        user_id: &str,
+        user_name: &str,
+    ) -> Result<crate::types::EnvelopesInformation> {
+        let params = EnvelopesGetParams {
+            ac_status: Some(ac_status.to_string()).filter(|s| !s.is_empty()),
+            block: Some(block.to_string()).filter(|s| !s.is_empty()),
+            cdse_mode: Some(cdse_mode.to_string()).filter(|s| !s.is_empty()),
+            continuation_token: Some(continuation_token.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            custom_field: Some(custom_field.to_string()).filter(|s| !s.is_empty()),
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+            envelope_ids: Some(envelope_ids.to_string()).filter(|s| !s.is_empty()),
+            exclude: Some(exclude.to_string()).filter(|s| !s.is_empty()),
+            folder_ids: Some(folder_ids.to_string()).filter(|s| !s.is_empty()),
+            folder_types: Some(folder_types.to_string()).filter(|s| !s.is_empty()),
+            from_date: Some(from_date.to_string()).filter(|s| !s.is_empty()),
+            from_to_status: Some(from_to_status.to_string()).filter(|s| !s.is_empty()),
+            include: Some(include.to_string()).filter(|s| !s.is_empty()),
+            include_purge_information: Some(include_purge_information.to_string())
+                .filter(|s| !s.is_empty()),
+            intersecting_folder_ids: Some(intersecting_folder_ids.to_string())
+                .filter(|s| !s.is_empty()),
+            last_queried_date: Some(last_queried_date.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            order_by: Some(order_by.to_string()).filter(|s| !s.is_empty()),
+            powerformids: Some(powerformids.to_string()).filter(|s| !s.is_empty()),
+            query_budget: Some(query_budget.to_string()).filter(|s| !s.is_empty()),
+            requester_date_format: Some(requester_date_format.to_string())
+                .filter(|s| !s.is_empty()),
+            search_text: Some(search_text.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            to_date: Some(to_date.to_string()).filter(|s| !s.is_empty()),
+            transaction_ids: Some(transaction_ids.to_string()).filter(|s| !s.is_empty()),
+            user_filter: Some(user_filter.to_string()).filter(|s| !s.is_empty()),
+            user_id: Some(user_id.to_string()).filter(|s| !s.is_empty()),
+            user_name: Some(user_name.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(account_id, &params).await
+    }
+
     /**
      * Creates an envelope.
      *
@@ -498,39 +366,31 @@ impl Envelopes {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `cdse_mode: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `change_routing_order: &str` -- When true, users can define the routing order of recipients while sending documents for signature.
-     * * `completed_documents_only: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `merge_roles_on_draft: &str` -- When set to **true**, template roles will be merged, and empty recipients will be removed. This parameter applies when you create a draft envelope with multiple templates. (To create a draft envelope, the `status` field is set to `created`.)
-     *   
-     *   **Note**: DocuSign recommends that this parameter should be set to **true** whenever you create a draft envelope with multiple templates.
+     * * `params: &EnvelopesPostParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn post(
+    pub async fn post_with(
         &self,
         account_id: &str,
-        cdse_mode: &str,
-        change_routing_order: &str,
-        completed_documents_only: &str,
-        merge_roles_on_draft: &str,
+        params: &EnvelopesPostParams,
         body: &crate::types::EnvelopeDefinition,
     ) -> Result<crate::types::EnvelopeSummary> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cdse_mode.is_empty() {
+        if let Some(cdse_mode) = &params.cdse_mode {
             query_args.push(("cdse_mode".to_string(), cdse_mode.to_string()));
         }
-        if !change_routing_order.is_empty() {
+        if let Some(change_routing_order) = &params.change_routing_order {
             query_args.push((
                 "change_routing_order".to_string(),
                 change_routing_order.to_string(),
             ));
         }
-        if !completed_documents_only.is_empty() {
+        if let Some(completed_documents_only) = &params.completed_documents_only {
             query_args.push((
                 "completed_documents_only".to_string(),
                 completed_documents_only.to_string(),
             ));
         }
-        if !merge_roles_on_draft.is_empty() {
+        if let Some(merge_roles_on_draft) = &params.merge_roles_on_draft {
             query_args.push((
                 "merge_roles_on_draft".to_string(),
                 merge_roles_on_draft.to_string(),
@@ -548,6 +408,29 @@ impl Envelopes {
             .await
     }
 
+    /**
+     * Deprecated: use `post_with` and pass the query parameters as a `EnvelopesPostParams` instead.
+     */
+    #[deprecated(note = "use `post_with` instead")]
+    pub async fn post(
+        &self,
+        account_id: &str,
+        cdse_mode: &str,
+        change_routing_order: &str,
+        completed_documents_only: &str,
+        merge_roles_on_draft: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeSummary> {
+        let params = EnvelopesPostParams {
+            cdse_mode: Some(cdse_mode.to_string()).filter(|s| !s.is_empty()),
+            change_routing_order: Some(change_routing_order.to_string()).filter(|s| !s.is_empty()),
+            completed_documents_only: Some(completed_documents_only.to_string())
+                .filter(|s| !s.is_empty()),
+            merge_roles_on_draft: Some(merge_roles_on_draft.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.post_with(account_id, &params, body).await
+    }
+
     /**
      * Gets envelope statuses for a set of envelopes.
      *
@@ -585,134 +468,49 @@ impl Envelopes {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `ac_status: &str` -- Specifies the Authoritative Copy Status for the envelopes. The possible values are:
-     *   
-     *   - `Unknown`
-     *   - `Original`
-     *   - `Transferred`
-     *   - `AuthoritativeCopy`
-     *   - `AuthoritativeCopyExportPending`
-     *   - `AuthoritativeCopyExported`
-     *   - `DepositPending`
-     *   - `Deposited`
-     *   - `DepositedEO`
-     *   - `DepositFailed`.
-     * * `block: &str` -- If set to **true**, removes any results that match one of the provided `transaction_ids`.
-     * * `count: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `email: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `envelope_ids: &str` -- The envelope IDs to include in the results.
-     *   
-     *   The value of this property can be:
-     *   - A comma-separated list of envelope IDs
-     *   - The special value `request_body`. In this case, the method uses the envelope IDs in the request body.
-     * * `from_date: &str` -- The date/time setting that specifies when the request begins checking for status changes for envelopes in the account. This is required unless parameters `envelope_ids` and/or `transaction_Ids` are provided.
-     *   
-     *   ****Note****: This parameter must be set to a valid  `DateTime`, or  `envelope_ids` and/or `transaction_ids` must be specified.
-     * * `from_to_status: &str` -- The envelope status that you are checking for. Possible values are:
-     *   
-     *   
-     *   - `Changed` (default)
-     *   - `Completed`
-     *   - `Created`
-     *   - `Declined`
-     *   - `Deleted`
-     *   - `Delivered`
-     *   - `Processing`
-     *   - `Sent`
-     *   - `Signed`
-     *   - `TimedOut`
-     *   - `Voided`
-     *   
-     *   For example, if you specify `Changed`, this method
-     *   returns a list of envelopes that changed status
-     *   during the `from_date` to `to_date` time period.
-     *   .
-     * * `start_position: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `status: &str` -- A comma-separated list of envelope status to search for. Possible values are:
-     *   
-     *   - `completed`
-     *   - `created`
-     *   - `declined`
-     *   - `deleted`
-     *   - `delivered`
-     *   - `processing`
-     *   - `sent`
-     *   - `signed`
-     *   - `template`
-     *   - `voided`
-     *   .
-     * * `to_date: &str` -- Optional date/time setting
-     *   that specifies the last date/time
-     *   or envelope status changes in the result set.
-     *   
-     *   The default value is the time that you call the method.
-     *   .
-     * * `transaction_ids: &str` -- The transaction IDs to include in the results. Note that transaction IDs are valid for seven days.
-     *   
-     *   The value of this property can be:
-     *   - A list of comma-separated transaction IDs
-     *   - The special value `request_body`. In this case, this method uses the transaction IDs in the request body.
-     * * `user_name: &str` -- Limits results to envelopes
-     *   sent by the account user
-     *   with this user name.
-     *   
-     *   `email` must be given as well,
-     *   and both `email` and `user_name`
-     *   must refer to an existing account user.
-     *   .
+     * * `params: &EnvelopesPutStatusParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn put_status(
+    pub async fn put_status_with(
         &self,
         account_id: &str,
-        ac_status: &str,
-        block: &str,
-        count: &str,
-        email: &str,
-        envelope_ids: &str,
-        from_date: &str,
-        from_to_status: &str,
-        start_position: &str,
-        status: &str,
-        to_date: &str,
-        transaction_ids: &str,
-        user_name: &str,
+        params: &EnvelopesPutStatusParams,
         body: &crate::types::EnvelopeIdsRequest,
     ) -> Result<crate::types::EnvelopesInformation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ac_status.is_empty() {
+        if let Some(ac_status) = &params.ac_status {
             query_args.push(("ac_status".to_string(), ac_status.to_string()));
         }
-        if !block.is_empty() {
+        if let Some(block) = &params.block {
             query_args.push(("block".to_string(), block.to_string()));
         }
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !email.is_empty() {
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
-        if !envelope_ids.is_empty() {
+        if let Some(envelope_ids) = &params.envelope_ids {
             query_args.push(("envelope_ids".to_string(), envelope_ids.to_string()));
         }
-        if !from_date.is_empty() {
+        if let Some(from_date) = &params.from_date {
             query_args.push(("from_date".to_string(), from_date.to_string()));
         }
-        if !from_to_status.is_empty() {
+        if let Some(from_to_status) = &params.from_to_status {
             query_args.push(("from_to_status".to_string(), from_to_status.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !to_date.is_empty() {
+        if let Some(to_date) = &params.to_date {
             query_args.push(("to_date".to_string(), to_date.to_string()));
         }
-        if !transaction_ids.is_empty() {
+        if let Some(transaction_ids) = &params.transaction_ids {
             query_args.push(("transaction_ids".to_string(), transaction_ids.to_string()));
         }
-        if !user_name.is_empty() {
+        if let Some(user_name) = &params.user_name {
             query_args.push(("user_name".to_string(), user_name.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -727,6 +525,44 @@ impl Envelopes {
             .await
     }
 
+    /**
+     * Deprecated: use `put_status_with` and pass the query parameters as a `EnvelopesPutStatusParams` instead.
+     */
+    #[deprecated(note = "use `put_status_with` instead")]
+    pub async fn put_status(
+        &self,
+        account_id: &str,
+        ac_status: &str,
+        block: &str,
+        count: &str,
+        email: &str,
+        envelope_ids: &str,
+        from_date: &str,
+        from_to_status: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+        transaction_ids: &str,
+        user_name: &str,
+        body: &crate::types::EnvelopeIdsRequest,
+    ) -> Result<crate::types::EnvelopesInformation> {
+        let params = EnvelopesPutStatusParams {
+            ac_status: Some(ac_status.to_string()).filter(|s| !s.is_empty()),
+            block: Some(block.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+            envelope_ids: Some(envelope_ids.to_string()).filter(|s| !s.is_empty()),
+            from_date: Some(from_date.to_string()).filter(|s| !s.is_empty()),
+            from_to_status: Some(from_to_status.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            to_date: Some(to_date.to_string()).filter(|s| !s.is_empty()),
+            transaction_ids: Some(transaction_ids.to_string()).filter(|s| !s.is_empty()),
+            user_name: Some(user_name.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.put_status_with(account_id, &params, body).await
+    }
+
     /**
      * Gets the status of a single envelope.
      *
@@ -740,32 +576,19 @@ impl Envelopes {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `advanced_update: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include: &str` -- Specifies additional information about the envelope to return. Enter a comma-separated list, such as `tabs,recipients`. Valid values are:
-     *   
-     *   - `custom_fields`: The custom fields associated with the envelope.
-     *   - `documents`: The documents associated with the envelope.
-     *   - `attachments`: The attachments associated with the envelope.
-     *   - `extensions`: Information about the email settings associated with the envelope.
-     *   - `folders`: The folder where the envelope exists.
-     *   - `recipients`: The recipients associated with the envelope.
-     *   - `powerform`: The PowerForms associated with the envelope.
-     *   - `tabs`: The tabs associated with the envelope.
-     *   - `payment_tabs`: The payment tabs associated with the envelope.
-     *   .
+     * * `params: &EnvelopesGetEnvelopesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_envelopes(
+    pub async fn get_envelopes_with(
         &self,
         account_id: &str,
         envelope_id: &str,
-        advanced_update: &str,
-        include: &str,
+        params: &EnvelopesGetEnvelopesParams,
     ) -> Result<crate::types::Envelope> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !advanced_update.is_empty() {
+        if let Some(advanced_update) = &params.advanced_update {
             query_args.push(("advanced_update".to_string(), advanced_update.to_string()));
         }
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -779,6 +602,25 @@ impl Envelopes {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_envelopes_with` and pass the query parameters as a `EnvelopesGetEnvelopesParams` instead.
+     */
+    #[deprecated(note = "use `get_envelopes_with` instead")]
+    pub async fn get_envelopes(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        advanced_update: &str,
+        include: &str,
+    ) -> Result<crate::types::Envelope> {
+        let params = EnvelopesGetEnvelopesParams {
+            advanced_update: Some(advanced_update.to_string()).filter(|s| !s.is_empty()),
+            include: Some(include.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_envelopes_with(account_id, envelope_id, &params)
+            .await
+    }
+
     /**
      * Send, void, or modify a draft envelope. Purge documents from a completed envelope.
      *
@@ -954,22 +796,20 @@ impl Envelopes {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `advanced_update: &str` -- When set to **true**, allows the caller to update recipients, tabs, custom fields, notification, email settings and other envelope attributes.
-     * * `resend_envelope: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &EnvelopesPutParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn put(
+    pub async fn put_with(
         &self,
         account_id: &str,
         envelope_id: &str,
-        advanced_update: &str,
-        resend_envelope: &str,
+        params: &EnvelopesPutParams,
         body: &crate::types::Envelope,
     ) -> Result<crate::types::EnvelopeUpdateSummary> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !advanced_update.is_empty() {
+        if let Some(advanced_update) = &params.advanced_update {
             query_args.push(("advanced_update".to_string(), advanced_update.to_string()));
         }
-        if !resend_envelope.is_empty() {
+        if let Some(resend_envelope) = &params.resend_envelope {
             query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -985,6 +825,25 @@ impl Envelopes {
             .await
     }
 
+    /**
+     * Deprecated: use `put_with` and pass the query parameters as a `EnvelopesPutParams` instead.
+     */
+    #[deprecated(note = "use `put_with` instead")]
+    pub async fn put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        advanced_update: &str,
+        resend_envelope: &str,
+        body: &crate::types::Envelope,
+    ) -> Result<crate::types::EnvelopeUpdateSummary> {
+        let params = EnvelopesPutParams {
+            advanced_update: Some(advanced_update.to_string()).filter(|s| !s.is_empty()),
+            resend_envelope: Some(resend_envelope.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.put_with(account_id, envelope_id, &params, body).await
+    }
+
     /**
      * Gets the envelope audit events for an envelope.
      *
@@ -1023,47 +882,35 @@ impl Envelopes {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `document_id: &str` -- The `documentId` is set by the API client. It is an integer that falls between `1` and 2,147,483,647. The value is encoded as a string without commas. The values `1`, `2`, `3`, and so on are typically used to identify the first few documents in an envelope. Tab definitions include a `documentId` property that specifies the document on which to place the tab.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `dpi: &str` -- The number of dots per inch (DPI) for the resulting images. Valid values are 1-310 DPI. The default value is 94.
-     * * `max_height: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `max_width: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `nocache: &str` -- If **true**, using cache is disabled and image information is retrieved from a database. **True** is the default value. .
-     * * `show_changes: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
+     * * `params: &EnvelopesPagesGetPageImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn pages_get_page_image(
+    pub async fn pages_get_page_image_with(
         &self,
         account_id: &str,
         document_id: &str,
         envelope_id: &str,
-        count: &str,
-        dpi: &str,
-        max_height: &str,
-        max_width: &str,
-        nocache: &str,
-        show_changes: &str,
-        start_position: &str,
+        params: &EnvelopesPagesGetPageImageParams,
     ) -> Result<crate::types::PageImages> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !dpi.is_empty() {
+        if let Some(dpi) = &params.dpi {
             query_args.push(("dpi".to_string(), dpi.to_string()));
         }
-        if !max_height.is_empty() {
+        if let Some(max_height) = &params.max_height {
             query_args.push(("max_height".to_string(), max_height.to_string()));
         }
-        if !max_width.is_empty() {
+        if let Some(max_width) = &params.max_width {
             query_args.push(("max_width".to_string(), max_width.to_string()));
         }
-        if !nocache.is_empty() {
+        if let Some(nocache) = &params.nocache {
             query_args.push(("nocache".to_string(), nocache.to_string()));
         }
-        if !show_changes.is_empty() {
+        if let Some(show_changes) = &params.show_changes {
             query_args.push(("show_changes".to_string(), show_changes.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1078,6 +925,36 @@ impl Envelopes {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `pages_get_page_image_with` and pass the query parameters as a `EnvelopesPagesGetPageImageParams` instead.
+     */
+    #[deprecated(note = "use `pages_get_page_image_with` instead")]
+    pub async fn pages_get_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        nocache: &str,
+        show_changes: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PageImages> {
+        let params = EnvelopesPagesGetPageImageParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            dpi: Some(dpi.to_string()).filter(|s| !s.is_empty()),
+            max_height: Some(max_height.to_string()).filter(|s| !s.is_empty()),
+            max_width: Some(max_width.to_string()).filter(|s| !s.is_empty()),
+            nocache: Some(nocache.to_string()).filter(|s| !s.is_empty()),
+            show_changes: Some(show_changes.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.pages_get_page_image_with(account_id, document_id, envelope_id, &params)
+            .await
+    }
+
     /**
      * Deletes a page from a document in an envelope.
      *
@@ -1123,11 +1000,48 @@ impl Envelopes {
      * * `document_id: &str` -- The `documentId` is set by the API client. It is an integer that falls between `1` and 2,147,483,647. The value is encoded as a string without commas. The values `1`, `2`, `3`, and so on are typically used to identify the first few documents in an envelope. Tab definitions include a `documentId` property that specifies the document on which to place the tab.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `page_number: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `dpi: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `max_height: &str` -- Sets the maximum height for the page image in pixels. The DPI is recalculated based on this setting.
-     * * `max_width: &str` -- Sets the maximum width for the page image in pixels. The DPI is recalculated based on this setting.
-     * * `show_changes: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &EnvelopesPagesGetPageImageEnvelopesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn pages_get_page_image_envelopes_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        params: &EnvelopesPagesGetPageImageEnvelopesParams,
+    ) -> Result<crate::Download> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(dpi) = &params.dpi {
+            query_args.push(("dpi".to_string(), dpi.to_string()));
+        }
+        if let Some(max_height) = &params.max_height {
+            query_args.push(("max_height".to_string(), max_height.to_string()));
+        }
+        if let Some(max_width) = &params.max_width {
+            query_args.push(("max_width".to_string(), max_width.to_string()));
+        }
+        if let Some(show_changes) = &params.show_changes {
+            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/documents/{}/pages/{}/page_image?{}",
+            crate::progenitor_support::encode_path(account_id),
+            crate::progenitor_support::encode_path(envelope_id),
+            crate::progenitor_support::encode_path(document_id),
+            crate::progenitor_support::encode_path(page_number),
+            query_
+        );
+
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `pages_get_page_image_envelopes_with` and pass the query parameters as a `EnvelopesPagesGetPageImageEnvelopesParams` instead.
      */
+    #[deprecated(note = "use `pages_get_page_image_envelopes_with` instead")]
     pub async fn pages_get_page_image_envelopes(
         &self,
         account_id: &str,
@@ -1138,18 +1052,55 @@ impl Envelopes {
         max_height: &str,
         max_width: &str,
         show_changes: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
+        let params = EnvelopesPagesGetPageImageEnvelopesParams {
+            dpi: Some(dpi.to_string()).filter(|s| !s.is_empty()),
+            max_height: Some(max_height.to_string()).filter(|s| !s.is_empty()),
+            max_width: Some(max_width.to_string()).filter(|s| !s.is_empty()),
+            show_changes: Some(show_changes.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.pages_get_page_image_envelopes_with(
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+            &params,
+        )
+        .await
+    }
+
+    /**
+     * Gets a page image from an envelope for display.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/documents/{documentId}/pages/{pageNumber}/page_image` endpoint.
+     *
+     * As opposed to `pages_get_page_image_envelopes`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Returns an image of a page in a document for display.
+     *
+     * **Parameters:**
+     *
+     * * `params: &EnvelopesPagesGetPageImageEnvelopesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn pages_get_page_image_envelopes_stream_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        params: &EnvelopesPagesGetPageImageEnvelopesParams,
+    ) -> Result<crate::DownloadStream> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !dpi.is_empty() {
+        if let Some(dpi) = &params.dpi {
             query_args.push(("dpi".to_string(), dpi.to_string()));
         }
-        if !max_height.is_empty() {
+        if let Some(max_height) = &params.max_height {
             query_args.push(("max_height".to_string(), max_height.to_string()));
         }
-        if !max_width.is_empty() {
+        if let Some(max_width) = &params.max_width {
             query_args.push(("max_width".to_string(), max_width.to_string()));
         }
-        if !show_changes.is_empty() {
+        if let Some(show_changes) = &params.show_changes {
             query_args.push(("show_changes".to_string(), show_changes.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1162,7 +1113,40 @@ impl Envelopes {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `pages_get_page_image_envelopes_stream_with` and pass the query parameters as a `EnvelopesPagesGetPageImageEnvelopesParams` instead.
+     */
+    #[deprecated(note = "use `pages_get_page_image_envelopes_stream_with` instead")]
+    pub async fn pages_get_page_image_envelopes_stream(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> Result<crate::DownloadStream> {
+        let params = EnvelopesPagesGetPageImageEnvelopesParams {
+            dpi: Some(dpi.to_string()).filter(|s| !s.is_empty()),
+            max_height: Some(max_height.to_string()).filter(|s| !s.is_empty()),
+            max_width: Some(max_width.to_string()).filter(|s| !s.is_empty()),
+            show_changes: Some(show_changes.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.pages_get_page_image_envelopes_stream_with(
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+            &params,
+        )
+        .await
     }
 
     /**
@@ -1277,17 +1261,17 @@ impl Envelopes {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `recipient_id: &str` -- A local reference that senders use to map recipients to other objects, such as specific document tabs. Within an envelope, each `recipientId` must be unique, but there is no uniqueness requirement across envelopes. For example, many envelopes assign the first recipient a `recipientId` of `1`.
-     * * `include_chrome: &str` -- The added line and identifier around the initial image. Note: Older envelopes might only have chromed images. If getting the non-chromed image fails, try getting the chromed image.
+     * * `params: &EnvelopesRecipientsGetRecipientInitialsImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recipients_get_recipient_initials_image(
+    pub async fn recipients_get_recipient_initials_image_with(
         &self,
         account_id: &str,
         envelope_id: &str,
         recipient_id: &str,
-        include_chrome: &str,
-    ) -> Result<()> {
+        params: &EnvelopesRecipientsGetRecipientInitialsImageParams,
+    ) -> Result<crate::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_chrome.is_empty() {
+        if let Some(include_chrome) = &params.include_chrome {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1299,17 +1283,111 @@ impl Envelopes {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
     }
 
     /**
-     * Sets the initials image for an accountless signer.
-     *
-     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image` endpoint.
-     *
-     * Updates the initials image for a signer that does not have a DocuSign account. The supported image formats for this file are: gif, png, jpeg, and bmp. The file size must be less than 200K.
-     *
-     * For the Authentication/Authorization for this call, the credentials must match the sender of the envelope, the recipient must be an accountless signer or in person signer. The account must have the `CanSendEnvelope` property set to **true** and the `ExpressSendOnly` property in `SendingUser` structure must be set to **false**.
+     * Deprecated: use `recipients_get_recipient_initials_image_with` and pass the query parameters as a `EnvelopesRecipientsGetRecipientInitialsImageParams` instead.
+     */
+    #[deprecated(note = "use `recipients_get_recipient_initials_image_with` instead")]
+    pub async fn recipients_get_recipient_initials_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> Result<crate::Download> {
+        let params = EnvelopesRecipientsGetRecipientInitialsImageParams {
+            include_chrome: Some(include_chrome.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipients_get_recipient_initials_image_with(
+            account_id,
+            envelope_id,
+            recipient_id,
+            &params,
+        )
+        .await
+    }
+
+    /**
+     * Gets the initials image for a user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image` endpoint.
+     *
+     * As opposed to `recipients_get_recipient_initials_image`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Retrieves the initials image for the specified user. The image is returned in the same format as it was uploaded. In the request you can specify if the chrome (the added line and identifier around the initial image) is returned with the image.
+     *
+     * The userId specified in the endpoint must match the authenticated user's user id and the user must be a member of the account.
+     *
+     * The `signatureIdOrName` paramter accepts signature ID or signature name. DocuSign recommends you use signature ID (`signatureId`), since some names contain characters that do not properly URL encode. If you use the user name, it is likely that the name includes spaces and you might need to URL encode the name before using it in the endpoint.
+     *
+     * For example: "Bob Smith" to "Bob%20Smith"
+     *
+     * Older envelopes might only contain chromed images. If getting the non-chromed image fails, try getting the chromed image.
+     *
+     * **Parameters:**
+     *
+     * * `params: &EnvelopesRecipientsGetRecipientInitialsImageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn recipients_get_recipient_initials_image_stream_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopesRecipientsGetRecipientInitialsImageParams,
+    ) -> Result<crate::DownloadStream> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(include_chrome) = &params.include_chrome {
+            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/initials_image?{}",
+            crate::progenitor_support::encode_path(account_id),
+            crate::progenitor_support::encode_path(envelope_id),
+            crate::progenitor_support::encode_path(recipient_id),
+            query_
+        );
+
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `recipients_get_recipient_initials_image_stream_with` and pass the query parameters as a `EnvelopesRecipientsGetRecipientInitialsImageParams` instead.
+     */
+    #[deprecated(note = "use `recipients_get_recipient_initials_image_stream_with` instead")]
+    pub async fn recipients_get_recipient_initials_image_stream(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> Result<crate::DownloadStream> {
+        let params = EnvelopesRecipientsGetRecipientInitialsImageParams {
+            include_chrome: Some(include_chrome.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipients_get_recipient_initials_image_stream_with(
+            account_id,
+            envelope_id,
+            recipient_id,
+            &params,
+        )
+        .await
+    }
+
+    /**
+     * Sets the initials image for an accountless signer.
+     *
+     * This function performs a `PUT` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/initials_image` endpoint.
+     *
+     * Updates the initials image for a signer that does not have a DocuSign account. The supported image formats for this file are: gif, png, jpeg, and bmp. The file size must be less than 200K.
+     *
+     * For the Authentication/Authorization for this call, the credentials must match the sender of the envelope, the recipient must be an accountless signer or in person signer. The account must have the `CanSendEnvelope` property set to **true** and the `ExpressSendOnly` property in `SendingUser` structure must be set to **false**.
      *
      * **Parameters:**
      *
@@ -1382,17 +1460,86 @@ impl Envelopes {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `envelope_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `recipient_id: &str` -- A local reference that senders use to map recipients to other objects, such as specific document tabs. Within an envelope, each `recipientId` must be unique, but there is no uniqueness requirement across envelopes. For example, many envelopes assign the first recipient a `recipientId` of `1`.
-     * * `include_chrome: &str` -- When set to **true**, the response includes the chromed version of the signature image.
+     * * `params: &EnvelopesRecipientsGetRecipientSignatureImageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn recipients_get_recipient_signature_image_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopesRecipientsGetRecipientSignatureImageParams,
+    ) -> Result<crate::Download> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(include_chrome) = &params.include_chrome {
+            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/envelopes/{}/recipients/{}/signature_image?{}",
+            crate::progenitor_support::encode_path(account_id),
+            crate::progenitor_support::encode_path(envelope_id),
+            crate::progenitor_support::encode_path(recipient_id),
+            query_
+        );
+
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `recipients_get_recipient_signature_image_with` and pass the query parameters as a `EnvelopesRecipientsGetRecipientSignatureImageParams` instead.
      */
+    #[deprecated(note = "use `recipients_get_recipient_signature_image_with` instead")]
     pub async fn recipients_get_recipient_signature_image(
         &self,
         account_id: &str,
         envelope_id: &str,
         recipient_id: &str,
         include_chrome: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
+        let params = EnvelopesRecipientsGetRecipientSignatureImageParams {
+            include_chrome: Some(include_chrome.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipients_get_recipient_signature_image_with(
+            account_id,
+            envelope_id,
+            recipient_id,
+            &params,
+        )
+        .await
+    }
+
+    /**
+     * Retrieve signature image information for a signer/sign-in-person recipient.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/envelopes/{envelopeId}/recipients/{recipientId}/signature_image` endpoint.
+     *
+     * As opposed to `recipients_get_recipient_signature_image`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Retrieves the specified user signature image. The image is returned in the same format as uploaded. In the request you can specify if the chrome (the added line and identifier around the initial image) is returned with the image.
+     *
+     * The userId specified in the endpoint must match the authenticated user's user ID and the user must be a member of the account.
+     *
+     * The `signatureIdOrName` parameter accepts signature ID or signature name. DocuSign recommends you use signature ID (`signatureId`), since some names contain characters that don't properly URL encode. If you use the user name, it is likely that the name includes spaces and you might need to URL encode the name before using it in the endpoint.
+     *
+     * For example: "Bob Smith" to "Bob%20Smith"
+     *
+     * Older envelopes might only have chromed images. If getting the non-chromed image fails, try getting the chromed image.
+     *
+     * **Parameters:**
+     *
+     * * `params: &EnvelopesRecipientsGetRecipientSignatureImageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn recipients_get_recipient_signature_image_stream_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopesRecipientsGetRecipientSignatureImageParams,
+    ) -> Result<crate::DownloadStream> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_chrome.is_empty() {
+        if let Some(include_chrome) = &params.include_chrome {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1404,7 +1551,32 @@ impl Envelopes {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `recipients_get_recipient_signature_image_stream_with` and pass the query parameters as a `EnvelopesRecipientsGetRecipientSignatureImageParams` instead.
+     */
+    #[deprecated(note = "use `recipients_get_recipient_signature_image_stream_with` instead")]
+    pub async fn recipients_get_recipient_signature_image_stream(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        include_chrome: &str,
+    ) -> Result<crate::DownloadStream> {
+        let params = EnvelopesRecipientsGetRecipientSignatureImageParams {
+            include_chrome: Some(include_chrome.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipients_get_recipient_signature_image_stream_with(
+            account_id,
+            envelope_id,
+            recipient_id,
+            &params,
+        )
+        .await
     }
 
     /**
@@ -1438,3 +1610,1163 @@ impl Envelopes {
         self.client.put(&url, None).await
     }
 }
+
+/// The query parameters of `Envelopes::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopesGetParams {
+    /// Specifies the Authoritative Copy Status for the envelopes. The possible values are: Unknown, Original, Transferred, AuthoritativeCopy, AuthoritativeCopyExportPending, AuthoritativeCopyExported, DepositPending, Deposited, DepositedEO, or DepositFailed.
+    pub ac_status: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub block: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub cdse_mode: Option<String>,
+    /// A token returned in the response to a previous API call that is used to resume a search query from a specific point.
+    pub continuation_token: Option<String>,
+    /// Optional. Number of items to return. Currently there is no implicit maximum limit of the number of items that can be returned.
+    /// .
+    pub count: Option<String>,
+    /// Optional. Specifies a envelope custom field name and value searched for in the envelopes. Format: `custom_envelope_field_name=desired_value`
+    ///
+    /// Example: If you have an envelope custom field named "Region" and you want to search for all envelopes where the value is "West" you would use set this parameter to `Region=West`.
+    ///
+    /// .
+    pub custom_field: Option<String>,
+    /// Limit results to envelopes
+    /// sent by the account user
+    /// with this email address.
+    ///
+    /// `user_name` must be given as well,
+    /// and both `email` and `user_name`
+    /// must refer to an existing account user.
+    /// .
+    pub email: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub envelope_ids: Option<String>,
+    /// Excludes information from the response. Enter  as a comma-separated list (e.g., `folders,powerforms`). Valid values are:
+    ///
+    /// - `recipients`
+    /// - `powerforms`
+    /// - `folders`.
+    pub exclude: Option<String>,
+    /// Returns the envelopes from specific folders. Enter as a comma-separated list of either valid folder Guids or the following values:
+    ///
+    /// - `awaiting_my_signature`
+    /// - `completed`
+    /// - `draft`
+    /// - `drafts`
+    /// - `expiring_soon`
+    /// - `inbox`
+    /// - `out_for_signature`
+    /// - `recyclebin`
+    /// - `sentitems`
+    /// - `waiting_for_others`.
+    pub folder_ids: Option<String>,
+    /// A comma-separated list of folder types you want to retrieve envelopes from. Valid values are:
+    ///
+    /// - `normal`
+    /// - `inbox`
+    /// - `sentitems`
+    /// - `draft`
+    /// - `templates`.
+    pub folder_types: Option<String>,
+    /// Specifies the date and time
+    /// to start looking for status changes.
+    /// This parameter is required
+    /// unless `envelopeIds` or `transactionIds`
+    /// are set.
+    ///
+    ///
+    /// Although you can use any date format
+    /// supported by the .NET system library's
+    /// [`DateTime.Parse()`][msoft] function,
+    /// DocuSign recommends
+    /// using [ISO 8601][] format dates
+    /// with an explicit time zone offset
+    /// If you do not provide
+    /// a time zone offset,
+    /// the method uses the server's time zone.
+    ///
+    /// For example, the following dates and times refer to the same instant:
+    ///
+    /// * `2017-05-02T01:44Z`
+    /// * `2017-05-01T21:44-04:00`
+    /// * `2017-05-01T18:44-07:00`
+    ///
+    ///
+    /// [msoft]: https://msdn.microsoft.com/en-us/library/system.datetime.parse(v=vs.110).aspx#StringToParse
+    /// [ISO 8601]: https://en.wikipedia.org/wiki/ISO_8601.
+    pub from_date: Option<String>,
+    /// This is the status type checked for in the `from_date`/`to_date` period. If `changed` is specified, then envelopes that changed status during the period are found. If for example, `created` is specified, then envelopes created during the period are found. Default is `changed`.
+    ///
+    /// Possible values are: Voided, Changed, Created, Deleted, Sent, Delivered, Signed, Completed, Declined, TimedOut and Processing.
+    pub from_to_status: Option<String>,
+    /// Specifies additional information to return  about the envelopes. Enter a comma-separated list, such as `tabs,recipients`. Valid values are:
+    ///
+    /// - `custom_fields`: The custom fields associated with the envelope.
+    /// - `documents`: The documents associated with the envelope.
+    /// - `attachments`: The attachments associated with the envelope.
+    /// - `extensions`: Information about the email settings associated with the envelope.
+    /// - `folders`: The folders where the envelope exists.
+    /// - `recipients`: The recipients associated with the envelope.
+    /// - `powerform`: The PowerForms associated with the envelope.
+    /// - `payment_tabs`: The payment tabs associated with the envelope.
+    ///   .
+    pub include: Option<String>,
+    /// When set to **true**, information about envelopes that have been deleted is included in the response.
+    pub include_purge_information: Option<String>,
+    /// A comma-separated list of folders that you want want to get envelopes from. Valid values are:
+    ///
+    /// - `normal`
+    /// - `inbox`
+    /// - `sentitems`
+    /// - `draft`
+    /// - `templates`.
+    pub intersecting_folder_ids: Option<String>,
+    /// Returns envelopes that were modified prior to the specified date and time.
+    ///
+    /// Example: `2020-05-09T21:56:12.2500000Z`.
+    pub last_queried_date: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub order: Option<String>,
+    /// Sorts results according to a specific property. Valid values are:
+    ///
+    /// - `last_modified`
+    /// - `action_required`
+    /// - `created`
+    /// - `completed`
+    /// - `envelope_name`
+    /// - `expire`
+    /// - `sent`
+    /// - `signer_list`
+    /// - `status`
+    /// - `subject`
+    /// - `user_name`
+    /// - `status_changed`
+    /// - `last_modified`.
+    pub order_by: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub powerformids: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub query_budget: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub requester_date_format: Option<String>,
+    /// Free text search criteria that you can use to filter the list of envelopes that is returned.
+    pub search_text: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub start_position: Option<String>,
+    /// A comma-separated list of current envelope statuses to included in the response. Possible values are:
+    ///
+    /// * `completed`
+    /// * `created`
+    /// * `declined`
+    /// * `deleted`
+    /// * `delivered`
+    /// * `processing`
+    /// * `sent`
+    /// * `signed`
+    /// * `timedout`
+    /// * `voided`
+    ///
+    ///   The `any` value is equivalent to any status.
+    ///   .
+    pub status: Option<String>,
+    /// Specifies the date and time
+    /// to stop looking for status changes.
+    /// The default is the current date and time.
+    ///
+    /// Although you can use any date format
+    /// supported by the .NET system library's
+    /// [`DateTime.Parse()`][msoft] function,
+    /// DocuSign recommends
+    /// using [ISO 8601][] format dates
+    /// with an explicit time zone offset
+    /// If you do not provide
+    /// a time zone offset,
+    /// the method uses the server's time zone.
+    ///
+    /// For example, the following dates and times refer to the same instant:
+    ///
+    /// * `2017-05-02T01:44Z`
+    /// * `2017-05-01T21:44-04:00`
+    /// * `2017-05-01T18:44-07:00`
+    ///
+    ///
+    /// [msoft]: https://msdn.microsoft.com/en-us/library/system.datetime.parse(v=vs.110).aspx#StringToParse
+    /// [ISO 8601]: https://en.wikipedia.org/wiki/ISO_8601
+    /// .
+    pub to_date: Option<String>,
+    /// If included in the query string, this is a comma separated list of envelope `transactionId`s.
+    ///
+    /// If included in the `request_body`, this is a list of envelope `transactionId`s.
+    ///
+    /// ###### Note: `transactionId`s are only valid in the DocuSign system for seven days.
+    /// .
+    pub transaction_ids: Option<String>,
+    /// Returns envelopes where the current user is the recipient, the sender, or the recipient only. (For example, `user_filter=sender`.) Valid values are:
+    ///
+    /// - `sender`
+    /// - `recipient`
+    /// - `recipient_only`.
+    pub user_filter: Option<String>,
+    /// The ID of the user who created the envelopes to be retrieved. Note that an account can have multiple users, and any user with account access can retrieve envelopes by user_id from the account.
+    pub user_id: Option<String>,
+    /// Limit results to envelopes
+    /// sent by the account user
+    /// with this user name.
+    ///
+    /// `email` must be given as well,
+    /// and both `email` and `user_name`
+    /// must refer to an existing account user.
+    /// .
+    pub user_name: Option<String>,
+}
+
+impl EnvelopesGetParams {
+    pub fn ac_status<T: ToString>(mut self, ac_status: T) -> Self {
+        self.ac_status = Some(ac_status.to_string());
+        self
+    }
+
+    pub fn block<T: ToString>(mut self, block: T) -> Self {
+        self.block = Some(block.to_string());
+        self
+    }
+
+    pub fn cdse_mode<T: ToString>(mut self, cdse_mode: T) -> Self {
+        self.cdse_mode = Some(cdse_mode.to_string());
+        self
+    }
+
+    pub fn continuation_token<T: ToString>(mut self, continuation_token: T) -> Self {
+        self.continuation_token = Some(continuation_token.to_string());
+        self
+    }
+
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn custom_field<T: ToString>(mut self, custom_field: T) -> Self {
+        self.custom_field = Some(custom_field.to_string());
+        self
+    }
+
+    pub fn email<T: ToString>(mut self, email: T) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+
+    pub fn envelope_ids<T: ToString>(mut self, envelope_ids: T) -> Self {
+        self.envelope_ids = Some(envelope_ids.to_string());
+        self
+    }
+
+    pub fn exclude<T: ToString>(mut self, exclude: T) -> Self {
+        self.exclude = Some(exclude.to_string());
+        self
+    }
+
+    pub fn folder_ids<T: ToString>(mut self, folder_ids: T) -> Self {
+        self.folder_ids = Some(folder_ids.to_string());
+        self
+    }
+
+    pub fn folder_types<T: ToString>(mut self, folder_types: T) -> Self {
+        self.folder_types = Some(folder_types.to_string());
+        self
+    }
+
+    pub fn from_date<T: ToString>(mut self, from_date: T) -> Self {
+        self.from_date = Some(from_date.to_string());
+        self
+    }
+
+    pub fn from_to_status<T: ToString>(mut self, from_to_status: T) -> Self {
+        self.from_to_status = Some(from_to_status.to_string());
+        self
+    }
+
+    pub fn include<T: ToString>(mut self, include: T) -> Self {
+        self.include = Some(include.to_string());
+        self
+    }
+
+    pub fn include_purge_information<T: ToString>(mut self, include_purge_information: T) -> Self {
+        self.include_purge_information = Some(include_purge_information.to_string());
+        self
+    }
+
+    pub fn intersecting_folder_ids<T: ToString>(mut self, intersecting_folder_ids: T) -> Self {
+        self.intersecting_folder_ids = Some(intersecting_folder_ids.to_string());
+        self
+    }
+
+    pub fn last_queried_date<T: ToString>(mut self, last_queried_date: T) -> Self {
+        self.last_queried_date = Some(last_queried_date.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn order_by<T: ToString>(mut self, order_by: T) -> Self {
+        self.order_by = Some(order_by.to_string());
+        self
+    }
+
+    pub fn powerformids<T: ToString>(mut self, powerformids: T) -> Self {
+        self.powerformids = Some(powerformids.to_string());
+        self
+    }
+
+    pub fn query_budget<T: ToString>(mut self, query_budget: T) -> Self {
+        self.query_budget = Some(query_budget.to_string());
+        self
+    }
+
+    pub fn requester_date_format<T: ToString>(mut self, requester_date_format: T) -> Self {
+        self.requester_date_format = Some(requester_date_format.to_string());
+        self
+    }
+
+    pub fn search_text<T: ToString>(mut self, search_text: T) -> Self {
+        self.search_text = Some(search_text.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn to_date<T: ToString>(mut self, to_date: T) -> Self {
+        self.to_date = Some(to_date.to_string());
+        self
+    }
+
+    pub fn transaction_ids<T: ToString>(mut self, transaction_ids: T) -> Self {
+        self.transaction_ids = Some(transaction_ids.to_string());
+        self
+    }
+
+    pub fn user_filter<T: ToString>(mut self, user_filter: T) -> Self {
+        self.user_filter = Some(user_filter.to_string());
+        self
+    }
+
+    pub fn user_id<T: ToString>(mut self, user_id: T) -> Self {
+        self.user_id = Some(user_id.to_string());
+        self
+    }
+
+    pub fn user_name<T: ToString>(mut self, user_name: T) -> Self {
+        self.user_name = Some(user_name.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Envelopes::post_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopesPostParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub cdse_mode: Option<String>,
+    /// When true, users can define the routing order of recipients while sending documents for signature.
+    pub change_routing_order: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub completed_documents_only: Option<String>,
+    /// When set to **true**, template roles will be merged, and empty recipients will be removed. This parameter applies when you create a draft envelope with multiple templates. (To create a draft envelope, the `status` field is set to `created`.)
+    ///
+    /// **Note**: DocuSign recommends that this parameter should be set to **true** whenever you create a draft envelope with multiple templates.
+    pub merge_roles_on_draft: Option<String>,
+}
+
+impl EnvelopesPostParams {
+    pub fn cdse_mode<T: ToString>(mut self, cdse_mode: T) -> Self {
+        self.cdse_mode = Some(cdse_mode.to_string());
+        self
+    }
+
+    pub fn change_routing_order<T: ToString>(mut self, change_routing_order: T) -> Self {
+        self.change_routing_order = Some(change_routing_order.to_string());
+        self
+    }
+
+    pub fn completed_documents_only<T: ToString>(mut self, completed_documents_only: T) -> Self {
+        self.completed_documents_only = Some(completed_documents_only.to_string());
+        self
+    }
+
+    pub fn merge_roles_on_draft<T: ToString>(mut self, merge_roles_on_draft: T) -> Self {
+        self.merge_roles_on_draft = Some(merge_roles_on_draft.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Envelopes::put_status_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopesPutStatusParams {
+    /// Specifies the Authoritative Copy Status for the envelopes. The possible values are:
+    ///
+    /// - `Unknown`
+    /// - `Original`
+    /// - `Transferred`
+    /// - `AuthoritativeCopy`
+    /// - `AuthoritativeCopyExportPending`
+    /// - `AuthoritativeCopyExported`
+    /// - `DepositPending`
+    /// - `Deposited`
+    /// - `DepositedEO`
+    /// - `DepositFailed`.
+    pub ac_status: Option<String>,
+    /// If set to **true**, removes any results that match one of the provided `transaction_ids`.
+    pub block: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub count: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub email: Option<String>,
+    /// The envelope IDs to include in the results.
+    ///
+    /// The value of this property can be:
+    /// - A comma-separated list of envelope IDs
+    /// - The special value `request_body`. In this case, the method uses the envelope IDs in the request body.
+    pub envelope_ids: Option<String>,
+    /// The date/time setting that specifies when the request begins checking for status changes for envelopes in the account. This is required unless parameters `envelope_ids` and/or `transaction_Ids` are provided.
+    ///
+    /// ****Note****: This parameter must be set to a valid  `DateTime`, or  `envelope_ids` and/or `transaction_ids` must be specified.
+    pub from_date: Option<String>,
+    /// The envelope status that you are checking for. Possible values are:
+    ///
+    ///
+    /// - `Changed` (default)
+    /// - `Completed`
+    /// - `Created`
+    /// - `Declined`
+    /// - `Deleted`
+    /// - `Delivered`
+    /// - `Processing`
+    /// - `Sent`
+    /// - `Signed`
+    /// - `TimedOut`
+    /// - `Voided`
+    ///
+    ///   For example, if you specify `Changed`, this method
+    ///   returns a list of envelopes that changed status
+    ///   during the `from_date` to `to_date` time period.
+    ///   .
+    pub from_to_status: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub start_position: Option<String>,
+    /// A comma-separated list of envelope status to search for. Possible values are:
+    ///
+    /// - `completed`
+    /// - `created`
+    /// - `declined`
+    /// - `deleted`
+    /// - `delivered`
+    /// - `processing`
+    /// - `sent`
+    /// - `signed`
+    /// - `template`
+    /// - `voided`
+    ///   .
+    pub status: Option<String>,
+    /// Optional date/time setting
+    /// that specifies the last date/time
+    /// or envelope status changes in the result set.
+    ///
+    /// The default value is the time that you call the method.
+    /// .
+    pub to_date: Option<String>,
+    /// The transaction IDs to include in the results. Note that transaction IDs are valid for seven days.
+    ///
+    /// The value of this property can be:
+    /// - A list of comma-separated transaction IDs
+    /// - The special value `request_body`. In this case, this method uses the transaction IDs in the request body.
+    pub transaction_ids: Option<String>,
+    /// Limits results to envelopes
+    /// sent by the account user
+    /// with this user name.
+    ///
+    /// `email` must be given as well,
+    /// and both `email` and `user_name`
+    /// must refer to an existing account user.
+    /// .
+    pub user_name: Option<String>,
+}
+
+impl EnvelopesPutStatusParams {
+    pub fn ac_status<T: ToString>(mut self, ac_status: T) -> Self {
+        self.ac_status = Some(ac_status.to_string());
+        self
+    }
+
+    pub fn block<T: ToString>(mut self, block: T) -> Self {
+        self.block = Some(block.to_string());
+        self
+    }
+
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn email<T: ToString>(mut self, email: T) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+
+    pub fn envelope_ids<T: ToString>(mut self, envelope_ids: T) -> Self {
+        self.envelope_ids = Some(envelope_ids.to_string());
+        self
+    }
+
+    pub fn from_date<T: ToString>(mut self, from_date: T) -> Self {
+        self.from_date = Some(from_date.to_string());
+        self
+    }
+
+    pub fn from_to_status<T: ToString>(mut self, from_to_status: T) -> Self {
+        self.from_to_status = Some(from_to_status.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn to_date<T: ToString>(mut self, to_date: T) -> Self {
+        self.to_date = Some(to_date.to_string());
+        self
+    }
+
+    pub fn transaction_ids<T: ToString>(mut self, transaction_ids: T) -> Self {
+        self.transaction_ids = Some(transaction_ids.to_string());
+        self
+    }
+
+    pub fn user_name<T: ToString>(mut self, user_name: T) -> Self {
+        self.user_name = Some(user_name.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Envelopes::get_envelopes_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopesGetEnvelopesParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub advanced_update: Option<String>,
+    /// Specifies additional information about the envelope to return. Enter a comma-separated list, such as `tabs,recipients`. Valid values are:
+    ///
+    /// - `custom_fields`: The custom fields associated with the envelope.
+    /// - `documents`: The documents associated with the envelope.
+    /// - `attachments`: The attachments associated with the envelope.
+    /// - `extensions`: Information about the email settings associated with the envelope.
+    /// - `folders`: The folder where the envelope exists.
+    /// - `recipients`: The recipients associated with the envelope.
+    /// - `powerform`: The PowerForms associated with the envelope.
+    /// - `tabs`: The tabs associated with the envelope.
+    /// - `payment_tabs`: The payment tabs associated with the envelope.
+    ///   .
+    pub include: Option<String>,
+}
+
+impl EnvelopesGetEnvelopesParams {
+    pub fn advanced_update<T: ToString>(mut self, advanced_update: T) -> Self {
+        self.advanced_update = Some(advanced_update.to_string());
+        self
+    }
+
+    pub fn include<T: ToString>(mut self, include: T) -> Self {
+        self.include = Some(include.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Envelopes::put_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopesPutParams {
+    /// When set to **true**, allows the caller to update recipients, tabs, custom fields, notification, email settings and other envelope attributes.
+    pub advanced_update: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub resend_envelope: Option<String>,
+}
+
+impl EnvelopesPutParams {
+    pub fn advanced_update<T: ToString>(mut self, advanced_update: T) -> Self {
+        self.advanced_update = Some(advanced_update.to_string());
+        self
+    }
+
+    pub fn resend_envelope<T: ToString>(mut self, resend_envelope: T) -> Self {
+        self.resend_envelope = Some(resend_envelope.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Envelopes::pages_get_page_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopesPagesGetPageImageParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub count: Option<String>,
+    /// The number of dots per inch (DPI) for the resulting images. Valid values are 1-310 DPI. The default value is 94.
+    pub dpi: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub max_height: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub max_width: Option<String>,
+    /// If **true**, using cache is disabled and image information is retrieved from a database. **True** is the default value. .
+    pub nocache: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub show_changes: Option<String>,
+    /// The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
+    pub start_position: Option<String>,
+}
+
+impl EnvelopesPagesGetPageImageParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn dpi<T: ToString>(mut self, dpi: T) -> Self {
+        self.dpi = Some(dpi.to_string());
+        self
+    }
+
+    pub fn max_height<T: ToString>(mut self, max_height: T) -> Self {
+        self.max_height = Some(max_height.to_string());
+        self
+    }
+
+    pub fn max_width<T: ToString>(mut self, max_width: T) -> Self {
+        self.max_width = Some(max_width.to_string());
+        self
+    }
+
+    pub fn nocache<T: ToString>(mut self, nocache: T) -> Self {
+        self.nocache = Some(nocache.to_string());
+        self
+    }
+
+    pub fn show_changes<T: ToString>(mut self, show_changes: T) -> Self {
+        self.show_changes = Some(show_changes.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Envelopes::pages_get_page_image_envelopes_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopesPagesGetPageImageEnvelopesParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub dpi: Option<String>,
+    /// Sets the maximum height for the page image in pixels. The DPI is recalculated based on this setting.
+    pub max_height: Option<String>,
+    /// Sets the maximum width for the page image in pixels. The DPI is recalculated based on this setting.
+    pub max_width: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub show_changes: Option<String>,
+}
+
+impl EnvelopesPagesGetPageImageEnvelopesParams {
+    pub fn dpi<T: ToString>(mut self, dpi: T) -> Self {
+        self.dpi = Some(dpi.to_string());
+        self
+    }
+
+    pub fn max_height<T: ToString>(mut self, max_height: T) -> Self {
+        self.max_height = Some(max_height.to_string());
+        self
+    }
+
+    pub fn max_width<T: ToString>(mut self, max_width: T) -> Self {
+        self.max_width = Some(max_width.to_string());
+        self
+    }
+
+    pub fn show_changes<T: ToString>(mut self, show_changes: T) -> Self {
+        self.show_changes = Some(show_changes.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Envelopes::recipients_get_recipient_initials_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopesRecipientsGetRecipientInitialsImageParams {
+    /// The added line and identifier around the initial image. Note: Older envelopes might only have chromed images. If getting the non-chromed image fails, try getting the chromed image.
+    pub include_chrome: Option<String>,
+}
+
+impl EnvelopesRecipientsGetRecipientInitialsImageParams {
+    pub fn include_chrome<T: ToString>(mut self, include_chrome: T) -> Self {
+        self.include_chrome = Some(include_chrome.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Envelopes::recipients_get_recipient_signature_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvelopesRecipientsGetRecipientSignatureImageParams {
+    /// When set to **true**, the response includes the chromed version of the signature image.
+    pub include_chrome: Option<String>,
+}
+
+impl EnvelopesRecipientsGetRecipientSignatureImageParams {
+    pub fn include_chrome<T: ToString>(mut self, include_chrome: T) -> Self {
+        self.include_chrome = Some(include_chrome.to_string());
+        self
+    }
+}
+
+/// The functions of [`Envelopes`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnvelopesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnvelopesApi: Send + Sync {
+    /// See [`Envelopes::get_with`].
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &EnvelopesGetParams,
+    ) -> Result<crate::types::EnvelopesInformation>;
+
+    /// See [`Envelopes::post_with`].
+    async fn post_with(
+        &self,
+        account_id: &str,
+        params: &EnvelopesPostParams,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeSummary>;
+
+    /// See [`Envelopes::put_status_with`].
+    async fn put_status_with(
+        &self,
+        account_id: &str,
+        params: &EnvelopesPutStatusParams,
+        body: &crate::types::EnvelopeIdsRequest,
+    ) -> Result<crate::types::EnvelopesInformation>;
+
+    /// See [`Envelopes::get_envelopes_with`].
+    async fn get_envelopes_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopesGetEnvelopesParams,
+    ) -> Result<crate::types::Envelope>;
+
+    /// See [`Envelopes::put_with`].
+    async fn put_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopesPutParams,
+        body: &crate::types::Envelope,
+    ) -> Result<crate::types::EnvelopeUpdateSummary>;
+
+    /// See [`Envelopes::audit_events_get`].
+    async fn audit_events_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeAuditEventResponse>;
+
+    /// See [`Envelopes::pages_get_page_image_with`].
+    async fn pages_get_page_image_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopesPagesGetPageImageParams,
+    ) -> Result<crate::types::PageImages>;
+
+    /// See [`Envelopes::pages_delete_page`].
+    async fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<()>;
+
+    /// See [`Envelopes::pages_get_page_image_envelopes_with`].
+    async fn pages_get_page_image_envelopes_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        params: &EnvelopesPagesGetPageImageEnvelopesParams,
+    ) -> Result<crate::Download>;
+
+    /// See [`Envelopes::pages_get_page_image_envelopes_stream_with`].
+    async fn pages_get_page_image_envelopes_stream_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        params: &EnvelopesPagesGetPageImageEnvelopesParams,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`Envelopes::pages_put_page_image`].
+    async fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()>;
+
+    /// See [`Envelopes::notification_get`].
+    async fn notification_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::Notification>;
+
+    /// See [`Envelopes::notification_put`].
+    async fn notification_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeNotificationRequest,
+    ) -> Result<crate::types::Notification>;
+
+    /// See [`Envelopes::recipients_get_recipient_initials_image_with`].
+    async fn recipients_get_recipient_initials_image_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopesRecipientsGetRecipientInitialsImageParams,
+    ) -> Result<crate::Download>;
+
+    /// See [`Envelopes::recipients_get_recipient_initials_image_stream_with`].
+    async fn recipients_get_recipient_initials_image_stream_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopesRecipientsGetRecipientInitialsImageParams,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`Envelopes::recipients_put_recipient_initials_image`].
+    async fn recipients_put_recipient_initials_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Envelopes::recipients_get_recipient_signature`].
+    async fn recipients_get_recipient_signature(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::UserSignature>;
+
+    /// See [`Envelopes::recipients_get_recipient_signature_image_with`].
+    async fn recipients_get_recipient_signature_image_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopesRecipientsGetRecipientSignatureImageParams,
+    ) -> Result<crate::Download>;
+
+    /// See [`Envelopes::recipients_get_recipient_signature_image_stream_with`].
+    async fn recipients_get_recipient_signature_image_stream_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopesRecipientsGetRecipientSignatureImageParams,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`Envelopes::recipients_put_recipient_signature_image`].
+    async fn recipients_put_recipient_signature_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl EnvelopesApi for Envelopes {
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &EnvelopesGetParams,
+    ) -> Result<crate::types::EnvelopesInformation> {
+        Envelopes::get_with(self, account_id, params).await
+    }
+
+    async fn post_with(
+        &self,
+        account_id: &str,
+        params: &EnvelopesPostParams,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeSummary> {
+        Envelopes::post_with(self, account_id, params, body).await
+    }
+
+    async fn put_status_with(
+        &self,
+        account_id: &str,
+        params: &EnvelopesPutStatusParams,
+        body: &crate::types::EnvelopeIdsRequest,
+    ) -> Result<crate::types::EnvelopesInformation> {
+        Envelopes::put_status_with(self, account_id, params, body).await
+    }
+
+    async fn get_envelopes_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopesGetEnvelopesParams,
+    ) -> Result<crate::types::Envelope> {
+        Envelopes::get_envelopes_with(self, account_id, envelope_id, params).await
+    }
+
+    async fn put_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        params: &EnvelopesPutParams,
+        body: &crate::types::Envelope,
+    ) -> Result<crate::types::EnvelopeUpdateSummary> {
+        Envelopes::put_with(self, account_id, envelope_id, params, body).await
+    }
+
+    async fn audit_events_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::EnvelopeAuditEventResponse> {
+        Envelopes::audit_events_get(self, account_id, envelope_id).await
+    }
+
+    async fn pages_get_page_image_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        params: &EnvelopesPagesGetPageImageParams,
+    ) -> Result<crate::types::PageImages> {
+        Envelopes::pages_get_page_image_with(self, account_id, document_id, envelope_id, params)
+            .await
+    }
+
+    async fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+    ) -> Result<()> {
+        Envelopes::pages_delete_page(self, account_id, document_id, envelope_id, page_number).await
+    }
+
+    async fn pages_get_page_image_envelopes_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        params: &EnvelopesPagesGetPageImageEnvelopesParams,
+    ) -> Result<crate::Download> {
+        Envelopes::pages_get_page_image_envelopes_with(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+            params,
+        )
+        .await
+    }
+
+    async fn pages_get_page_image_envelopes_stream_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        params: &EnvelopesPagesGetPageImageEnvelopesParams,
+    ) -> Result<crate::DownloadStream> {
+        Envelopes::pages_get_page_image_envelopes_stream_with(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+            params,
+        )
+        .await
+    }
+
+    async fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        envelope_id: &str,
+        page_number: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()> {
+        Envelopes::pages_put_page_image(
+            self,
+            account_id,
+            document_id,
+            envelope_id,
+            page_number,
+            body,
+        )
+        .await
+    }
+
+    async fn notification_get(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+    ) -> Result<crate::types::Notification> {
+        Envelopes::notification_get(self, account_id, envelope_id).await
+    }
+
+    async fn notification_put(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::EnvelopeNotificationRequest,
+    ) -> Result<crate::types::Notification> {
+        Envelopes::notification_put(self, account_id, envelope_id, body).await
+    }
+
+    async fn recipients_get_recipient_initials_image_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopesRecipientsGetRecipientInitialsImageParams,
+    ) -> Result<crate::Download> {
+        Envelopes::recipients_get_recipient_initials_image_with(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            params,
+        )
+        .await
+    }
+
+    async fn recipients_get_recipient_initials_image_stream_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopesRecipientsGetRecipientInitialsImageParams,
+    ) -> Result<crate::DownloadStream> {
+        Envelopes::recipients_get_recipient_initials_image_stream_with(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            params,
+        )
+        .await
+    }
+
+    async fn recipients_put_recipient_initials_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<()> {
+        Envelopes::recipients_put_recipient_initials_image(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+        )
+        .await
+    }
+
+    async fn recipients_get_recipient_signature(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<crate::types::UserSignature> {
+        Envelopes::recipients_get_recipient_signature(self, account_id, envelope_id, recipient_id)
+            .await
+    }
+
+    async fn recipients_get_recipient_signature_image_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopesRecipientsGetRecipientSignatureImageParams,
+    ) -> Result<crate::Download> {
+        Envelopes::recipients_get_recipient_signature_image_with(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            params,
+        )
+        .await
+    }
+
+    async fn recipients_get_recipient_signature_image_stream_with(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+        params: &EnvelopesRecipientsGetRecipientSignatureImageParams,
+    ) -> Result<crate::DownloadStream> {
+        Envelopes::recipients_get_recipient_signature_image_stream_with(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+            params,
+        )
+        .await
+    }
+
+    async fn recipients_put_recipient_signature_image(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        recipient_id: &str,
+    ) -> Result<()> {
+        Envelopes::recipients_put_recipient_signature_image(
+            self,
+            account_id,
+            envelope_id,
+            recipient_id,
+        )
+        .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct FavoriteTemplates {
     pub client: Client,
@@ -85,3 +83,49 @@ impl FavoriteTemplates {
             .await
     }
 }
+
+/// The functions of [`FavoriteTemplates`], for code that takes them as a trait, so that it can be
+/// tested with the `MockFavoriteTemplatesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait FavoriteTemplatesApi: Send + Sync {
+    /// See [`FavoriteTemplates::get`].
+    async fn get(&self, account_id: &str) -> Result<crate::types::FavoriteTemplatesInfo>;
+
+    /// See [`FavoriteTemplates::put_template`].
+    async fn put_template(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplatesInfo,
+    ) -> Result<crate::types::FavoriteTemplatesInfo>;
+
+    /// See [`FavoriteTemplates::un_template`].
+    async fn un_template(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplatesInfo,
+    ) -> Result<crate::types::FavoriteTemplatesInfo>;
+}
+
+#[async_trait::async_trait]
+impl FavoriteTemplatesApi for FavoriteTemplates {
+    async fn get(&self, account_id: &str) -> Result<crate::types::FavoriteTemplatesInfo> {
+        FavoriteTemplates::get(self, account_id).await
+    }
+
+    async fn put_template(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplatesInfo,
+    ) -> Result<crate::types::FavoriteTemplatesInfo> {
+        FavoriteTemplates::put_template(self, account_id, body).await
+    }
+
+    async fn un_template(
+        &self,
+        account_id: &str,
+        body: &crate::types::FavoriteTemplatesInfo,
+    ) -> Result<crate::types::FavoriteTemplatesInfo> {
+        FavoriteTemplates::un_template(self, account_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Folders {
     pub client: Client,
@@ -22,46 +20,27 @@ impl Folders {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include: &str` -- A comma-separated list of folder types to include in the response.
-     *   Valid values are:
-     *   
-     *   - `envelope_folders`: Returns a list of envelope folders. (Default)
-     *   - `template_folders`: Returns a list of template folders.
-     *   - `shared_template_folders`: Returns a list of shared template folders.
-     *   .
-     * * `include_items: &str` -- Indicates whether folder items are included in the response. If this parameter is omitted, the default is false.
-     * * `start_position: &str` -- The position within the total result set from which to start returning values.
-     * * `template: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `user_filter: &str` -- Narrows down the resulting folder list by the following values:
-     *   
-     *   - `all`: Returns all templates owned or shared with the user. (default)
-     *   - `owned_by_me`: Returns only  templates the user owns.
-     *   - `shared_with_me`: Returns only templates that are shared with the user.
-     *   .
+     * * `params: &FoldersGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         account_id: &str,
-        include: &str,
-        include_items: &str,
-        start_position: &str,
-        template: &str,
-        user_filter: &str,
+        params: &FoldersGetParams,
     ) -> Result<crate::types::FoldersResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
-        if !include_items.is_empty() {
+        if let Some(include_items) = &params.include_items {
             query_args.push(("include_items".to_string(), include_items.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
-        if !template.is_empty() {
+        if let Some(template) = &params.template {
             query_args.push(("template".to_string(), template.to_string()));
         }
-        if !user_filter.is_empty() {
+        if let Some(user_filter) = &params.user_filter {
             query_args.push(("user_filter".to_string(), user_filter.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -74,6 +53,29 @@ impl Folders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `FoldersGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        account_id: &str,
+        include: &str,
+        include_items: &str,
+        start_position: &str,
+        template: &str,
+        user_filter: &str,
+    ) -> Result<crate::types::FoldersResponse> {
+        let params = FoldersGetParams {
+            include: Some(include.to_string()).filter(|s| !s.is_empty()),
+            include_items: Some(include_items.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+            template: Some(template.to_string()).filter(|s| !s.is_empty()),
+            user_filter: Some(user_filter.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(account_id, &params).await
+    }
+
     /**
      * Gets a list of the envelopes in the specified folder.
      *
@@ -85,51 +87,37 @@ impl Folders {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `folder_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `from_date: &str` -- The billing period end date in UTC timedate format.
-     * * `include_items: &str` -- Indicates whether folder items are included in the response. If this parameter is omitted, the default is false.
-     * * `owner_email: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `owner_name: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `search_text: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `status: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `to_date: &str` -- The billing period end date in UTC timedate format.
+     * * `params: &FoldersGetItemsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_items(
+    pub async fn get_items_with(
         &self,
         account_id: &str,
         folder_id: &str,
-        from_date: &str,
-        include_items: &str,
-        owner_email: &str,
-        owner_name: &str,
-        search_text: &str,
-        start_position: &str,
-        status: &str,
-        to_date: &str,
+        params: &FoldersGetItemsParams,
     ) -> Result<crate::types::FolderItemsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from_date.is_empty() {
+        if let Some(from_date) = &params.from_date {
             query_args.push(("from_date".to_string(), from_date.to_string()));
         }
-        if !include_items.is_empty() {
+        if let Some(include_items) = &params.include_items {
             query_args.push(("include_items".to_string(), include_items.to_string()));
         }
-        if !owner_email.is_empty() {
+        if let Some(owner_email) = &params.owner_email {
             query_args.push(("owner_email".to_string(), owner_email.to_string()));
         }
-        if !owner_name.is_empty() {
+        if let Some(owner_name) = &params.owner_name {
             query_args.push(("owner_name".to_string(), owner_name.to_string()));
         }
-        if !search_text.is_empty() {
+        if let Some(search_text) = &params.search_text {
             query_args.push(("search_text".to_string(), search_text.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !to_date.is_empty() {
+        if let Some(to_date) = &params.to_date {
             query_args.push(("to_date".to_string(), to_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -143,6 +131,36 @@ impl Folders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_items_with` and pass the query parameters as a `FoldersGetItemsParams` instead.
+     */
+    #[deprecated(note = "use `get_items_with` instead")]
+    pub async fn get_items(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        from_date: &str,
+        include_items: &str,
+        owner_email: &str,
+        owner_name: &str,
+        search_text: &str,
+        start_position: &str,
+        status: &str,
+        to_date: &str,
+    ) -> Result<crate::types::FolderItemsResponse> {
+        let params = FoldersGetItemsParams {
+            from_date: Some(from_date.to_string()).filter(|s| !s.is_empty()),
+            include_items: Some(include_items.to_string()).filter(|s| !s.is_empty()),
+            owner_email: Some(owner_email.to_string()).filter(|s| !s.is_empty()),
+            owner_name: Some(owner_name.to_string()).filter(|s| !s.is_empty()),
+            search_text: Some(search_text.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            to_date: Some(to_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_items_with(account_id, folder_id, &params).await
+    }
+
     /**
      * Moves an envelope from its current folder to the specified folder.
      *
@@ -194,54 +212,40 @@ impl Folders {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `search_folder_id: &str` -- Specifies the envelope group that is searched by the request. These are logical groupings, not actual folder names. Valid values are: drafts, awaiting_my_signature, completed, out_for_signature.
-     * * `all: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- Specifies the number of records returned in the cache. The number must be greater than 0 and less than or equal to 100.
-     * * `from_date: &str` -- Specifies the start of the date range to return. If no value is provided, the default search is the previous 30 days.
-     * * `include_recipients: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `order: &str` -- Specifies the order in which the list is returned. Valid values are: `asc` for ascending order, and `desc` for descending order.
-     * * `order_by: &str` -- Specifies the property used to sort the list. Valid values are: `action_required`, `created`, `completed`, `sent`, `signer_list`, `status`, or `subject`.
-     * * `start_position: &str` -- Specifies the the starting location in the result set of the items that are returned.
-     * * `to_date: &str` -- The billing period end date in UTC timedate format.
+     * * `params: &FoldersSearchGetContentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn search_get_contents(
+    pub async fn search_get_contents_with(
         &self,
         account_id: &str,
         search_folder_id: &str,
-        all: &str,
-        count: &str,
-        from_date: &str,
-        include_recipients: &str,
-        order: &str,
-        order_by: &str,
-        start_position: &str,
-        to_date: &str,
+        params: &FoldersSearchGetContentsParams,
     ) -> Result<crate::types::FolderItemResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !all.is_empty() {
+        if let Some(all) = &params.all {
             query_args.push(("all".to_string(), all.to_string()));
         }
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !from_date.is_empty() {
+        if let Some(from_date) = &params.from_date {
             query_args.push(("from_date".to_string(), from_date.to_string()));
         }
-        if !include_recipients.is_empty() {
+        if let Some(include_recipients) = &params.include_recipients {
             query_args.push((
                 "include_recipients".to_string(),
                 include_recipients.to_string(),
             ));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !order_by.is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("order_by".to_string(), order_by.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
-        if !to_date.is_empty() {
+        if let Some(to_date) = &params.to_date {
             query_args.push(("to_date".to_string(), to_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -254,4 +258,289 @@ impl Folders {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `search_get_contents_with` and pass the query parameters as a `FoldersSearchGetContentsParams` instead.
+     */
+    #[deprecated(note = "use `search_get_contents_with` instead")]
+    pub async fn search_get_contents(
+        &self,
+        account_id: &str,
+        search_folder_id: &str,
+        all: &str,
+        count: &str,
+        from_date: &str,
+        include_recipients: &str,
+        order: &str,
+        order_by: &str,
+        start_position: &str,
+        to_date: &str,
+    ) -> Result<crate::types::FolderItemResponse> {
+        let params = FoldersSearchGetContentsParams {
+            all: Some(all.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            from_date: Some(from_date.to_string()).filter(|s| !s.is_empty()),
+            include_recipients: Some(include_recipients.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            order_by: Some(order_by.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+            to_date: Some(to_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.search_get_contents_with(account_id, search_folder_id, &params)
+            .await
+    }
+}
+
+/// The query parameters of `Folders::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FoldersGetParams {
+    /// A comma-separated list of folder types to include in the response.
+    /// Valid values are:
+    ///
+    /// - `envelope_folders`: Returns a list of envelope folders. (Default)
+    /// - `template_folders`: Returns a list of template folders.
+    /// - `shared_template_folders`: Returns a list of shared template folders.
+    ///   .
+    pub include: Option<String>,
+    /// Indicates whether folder items are included in the response. If this parameter is omitted, the default is false.
+    pub include_items: Option<String>,
+    /// The position within the total result set from which to start returning values.
+    pub start_position: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub template: Option<String>,
+    /// Narrows down the resulting folder list by the following values:
+    ///
+    /// - `all`: Returns all templates owned or shared with the user. (default)
+    /// - `owned_by_me`: Returns only  templates the user owns.
+    /// - `shared_with_me`: Returns only templates that are shared with the user.
+    ///   .
+    pub user_filter: Option<String>,
+}
+
+impl FoldersGetParams {
+    pub fn include<T: ToString>(mut self, include: T) -> Self {
+        self.include = Some(include.to_string());
+        self
+    }
+
+    pub fn include_items<T: ToString>(mut self, include_items: T) -> Self {
+        self.include_items = Some(include_items.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+
+    pub fn template<T: ToString>(mut self, template: T) -> Self {
+        self.template = Some(template.to_string());
+        self
+    }
+
+    pub fn user_filter<T: ToString>(mut self, user_filter: T) -> Self {
+        self.user_filter = Some(user_filter.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Folders::get_items_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FoldersGetItemsParams {
+    /// The billing period end date in UTC timedate format.
+    pub from_date: Option<String>,
+    /// Indicates whether folder items are included in the response. If this parameter is omitted, the default is false.
+    pub include_items: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub owner_email: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub owner_name: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub search_text: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub start_position: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub status: Option<String>,
+    /// The billing period end date in UTC timedate format.
+    pub to_date: Option<String>,
+}
+
+impl FoldersGetItemsParams {
+    pub fn from_date<T: ToString>(mut self, from_date: T) -> Self {
+        self.from_date = Some(from_date.to_string());
+        self
+    }
+
+    pub fn include_items<T: ToString>(mut self, include_items: T) -> Self {
+        self.include_items = Some(include_items.to_string());
+        self
+    }
+
+    pub fn owner_email<T: ToString>(mut self, owner_email: T) -> Self {
+        self.owner_email = Some(owner_email.to_string());
+        self
+    }
+
+    pub fn owner_name<T: ToString>(mut self, owner_name: T) -> Self {
+        self.owner_name = Some(owner_name.to_string());
+        self
+    }
+
+    pub fn search_text<T: ToString>(mut self, search_text: T) -> Self {
+        self.search_text = Some(search_text.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn to_date<T: ToString>(mut self, to_date: T) -> Self {
+        self.to_date = Some(to_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Folders::search_get_contents_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FoldersSearchGetContentsParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub all: Option<String>,
+    /// Specifies the number of records returned in the cache. The number must be greater than 0 and less than or equal to 100.
+    pub count: Option<String>,
+    /// Specifies the start of the date range to return. If no value is provided, the default search is the previous 30 days.
+    pub from_date: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub include_recipients: Option<String>,
+    /// Specifies the order in which the list is returned. Valid values are: `asc` for ascending order, and `desc` for descending order.
+    pub order: Option<String>,
+    /// Specifies the property used to sort the list. Valid values are: `action_required`, `created`, `completed`, `sent`, `signer_list`, `status`, or `subject`.
+    pub order_by: Option<String>,
+    /// Specifies the the starting location in the result set of the items that are returned.
+    pub start_position: Option<String>,
+    /// The billing period end date in UTC timedate format.
+    pub to_date: Option<String>,
+}
+
+impl FoldersSearchGetContentsParams {
+    pub fn all<T: ToString>(mut self, all: T) -> Self {
+        self.all = Some(all.to_string());
+        self
+    }
+
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn from_date<T: ToString>(mut self, from_date: T) -> Self {
+        self.from_date = Some(from_date.to_string());
+        self
+    }
+
+    pub fn include_recipients<T: ToString>(mut self, include_recipients: T) -> Self {
+        self.include_recipients = Some(include_recipients.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn order_by<T: ToString>(mut self, order_by: T) -> Self {
+        self.order_by = Some(order_by.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+
+    pub fn to_date<T: ToString>(mut self, to_date: T) -> Self {
+        self.to_date = Some(to_date.to_string());
+        self
+    }
+}
+
+/// The functions of [`Folders`], for code that takes them as a trait, so that it can be
+/// tested with the `MockFoldersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait FoldersApi: Send + Sync {
+    /// See [`Folders::get_with`].
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &FoldersGetParams,
+    ) -> Result<crate::types::FoldersResponse>;
+
+    /// See [`Folders::get_items_with`].
+    async fn get_items_with(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        params: &FoldersGetItemsParams,
+    ) -> Result<crate::types::FolderItemsResponse>;
+
+    /// See [`Folders::put`].
+    async fn put(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        body: &crate::types::FoldersRequest,
+    ) -> Result<crate::types::FoldersResponse>;
+
+    /// See [`Folders::search_get_contents_with`].
+    async fn search_get_contents_with(
+        &self,
+        account_id: &str,
+        search_folder_id: &str,
+        params: &FoldersSearchGetContentsParams,
+    ) -> Result<crate::types::FolderItemResponse>;
+}
+
+#[async_trait::async_trait]
+impl FoldersApi for Folders {
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &FoldersGetParams,
+    ) -> Result<crate::types::FoldersResponse> {
+        Folders::get_with(self, account_id, params).await
+    }
+
+    async fn get_items_with(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        params: &FoldersGetItemsParams,
+    ) -> Result<crate::types::FolderItemsResponse> {
+        Folders::get_items_with(self, account_id, folder_id, params).await
+    }
+
+    async fn put(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        body: &crate::types::FoldersRequest,
+    ) -> Result<crate::types::FoldersResponse> {
+        Folders::put(self, account_id, folder_id, body).await
+    }
+
+    async fn search_get_contents_with(
+        &self,
+        account_id: &str,
+        search_folder_id: &str,
+        params: &FoldersSearchGetContentsParams,
+    ) -> Result<crate::types::FolderItemResponse> {
+        Folders::search_get_contents_with(self, account_id, search_folder_id, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct GroupBrands {
     pub client: Client,
@@ -96,3 +94,61 @@ impl GroupBrands {
             .await
     }
 }
+
+/// The functions of [`GroupBrands`], for code that takes them as a trait, so that it can be
+/// tested with the `MockGroupBrandsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait GroupBrandsApi: Send + Sync {
+    /// See [`GroupBrands::brands_get_group`].
+    async fn brands_get_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+    ) -> Result<crate::types::GroupBrands>;
+
+    /// See [`GroupBrands::brands_put_group`].
+    async fn brands_put_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::GroupBrands>;
+
+    /// See [`GroupBrands::brands_delete_group`].
+    async fn brands_delete_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::GroupBrands>;
+}
+
+#[async_trait::async_trait]
+impl GroupBrandsApi for GroupBrands {
+    async fn brands_get_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+    ) -> Result<crate::types::GroupBrands> {
+        GroupBrands::brands_get_group(self, account_id, group_id).await
+    }
+
+    async fn brands_put_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::GroupBrands> {
+        GroupBrands::brands_put_group(self, account_id, group_id, body).await
+    }
+
+    async fn brands_delete_group(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::BrandsRequest,
+    ) -> Result<crate::types::GroupBrands> {
+        GroupBrands::brands_delete_group(self, account_id, group_id, body).await
+    }
+}
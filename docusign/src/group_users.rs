@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct GroupUsers {
     pub client: Client,
@@ -23,21 +21,19 @@ impl GroupUsers {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `group_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- Number of records to return. The number must be greater than 1 and less than or equal to 100. .
-     * * `start_position: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &GroupUsersGroupsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn groups_get(
+    pub async fn groups_get_with(
         &self,
         account_id: &str,
         group_id: &str,
-        count: &str,
-        start_position: &str,
+        params: &GroupUsersGroupsGetParams,
     ) -> Result<crate::types::UsersResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -51,6 +47,24 @@ impl GroupUsers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `groups_get_with` and pass the query parameters as a `GroupUsersGroupsGetParams` instead.
+     */
+    #[deprecated(note = "use `groups_get_with` instead")]
+    pub async fn groups_get(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        count: &str,
+        start_position: &str,
+    ) -> Result<crate::types::UsersResponse> {
+        let params = GroupUsersGroupsGetParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.groups_get_with(account_id, group_id, &params).await
+    }
+
     /**
      * Adds one or more users to an existing group.
      *
@@ -110,3 +124,84 @@ impl GroupUsers {
             .await
     }
 }
+
+/// The query parameters of `GroupUsers::groups_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupUsersGroupsGetParams {
+    /// Number of records to return. The number must be greater than 1 and less than or equal to 100. .
+    pub count: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub start_position: Option<String>,
+}
+
+impl GroupUsersGroupsGetParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+}
+
+/// The functions of [`GroupUsers`], for code that takes them as a trait, so that it can be
+/// tested with the `MockGroupUsersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait GroupUsersApi: Send + Sync {
+    /// See [`GroupUsers::groups_get_with`].
+    async fn groups_get_with(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        params: &GroupUsersGroupsGetParams,
+    ) -> Result<crate::types::UsersResponse>;
+
+    /// See [`GroupUsers::groups_put`].
+    async fn groups_put(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse>;
+
+    /// See [`GroupUsers::groups_delete`].
+    async fn groups_delete(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse>;
+}
+
+#[async_trait::async_trait]
+impl GroupUsersApi for GroupUsers {
+    async fn groups_get_with(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        params: &GroupUsersGroupsGetParams,
+    ) -> Result<crate::types::UsersResponse> {
+        GroupUsers::groups_get_with(self, account_id, group_id, params).await
+    }
+
+    async fn groups_put(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse> {
+        GroupUsers::groups_put(self, account_id, group_id, body).await
+    }
+
+    async fn groups_delete(
+        &self,
+        account_id: &str,
+        group_id: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse> {
+        GroupUsers::groups_delete(self, account_id, group_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Groups {
     pub client: Client,
@@ -22,38 +20,30 @@ impl Groups {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- Number of records to return. The number must be greater than 1 and less than or equal to 100.
-     * * `group_type: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_usercount: &str` -- When set to **true**, every group returned in the response includes a `userCount` property that contains the total number of users in the group. The default is **true**.
-     * * `search_text: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &GroupsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         account_id: &str,
-        count: &str,
-        group_type: &str,
-        include_usercount: &str,
-        search_text: &str,
-        start_position: &str,
+        params: &GroupsGetParams,
     ) -> Result<crate::types::GroupInformation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !group_type.is_empty() {
+        if let Some(group_type) = &params.group_type {
             query_args.push(("group_type".to_string(), group_type.to_string()));
         }
-        if !include_usercount.is_empty() {
+        if let Some(include_usercount) = &params.include_usercount {
             query_args.push((
                 "include_usercount".to_string(),
                 include_usercount.to_string(),
             ));
         }
-        if !search_text.is_empty() {
+        if let Some(search_text) = &params.search_text {
             query_args.push(("search_text".to_string(), search_text.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -66,6 +56,29 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `GroupsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        group_type: &str,
+        include_usercount: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::GroupInformation> {
+        let params = GroupsGetParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            group_type: Some(group_type.to_string()).filter(|s| !s.is_empty()),
+            include_usercount: Some(include_usercount.to_string()).filter(|s| !s.is_empty()),
+            search_text: Some(search_text.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(account_id, &params).await
+    }
+
     /**
      * Updates the group information for a group.
      *
@@ -160,3 +173,114 @@ impl Groups {
             .await
     }
 }
+
+/// The query parameters of `Groups::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsGetParams {
+    /// Number of records to return. The number must be greater than 1 and less than or equal to 100.
+    pub count: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub group_type: Option<String>,
+    /// When set to **true**, every group returned in the response includes a `userCount` property that contains the total number of users in the group. The default is **true**.
+    pub include_usercount: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub search_text: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub start_position: Option<String>,
+}
+
+impl GroupsGetParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn group_type<T: ToString>(mut self, group_type: T) -> Self {
+        self.group_type = Some(group_type.to_string());
+        self
+    }
+
+    pub fn include_usercount<T: ToString>(mut self, include_usercount: T) -> Self {
+        self.include_usercount = Some(include_usercount.to_string());
+        self
+    }
+
+    pub fn search_text<T: ToString>(mut self, search_text: T) -> Self {
+        self.search_text = Some(search_text.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+}
+
+/// The functions of [`Groups`], for code that takes them as a trait, so that it can be
+/// tested with the `MockGroupsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait GroupsApi: Send + Sync {
+    /// See [`Groups::get_with`].
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &GroupsGetParams,
+    ) -> Result<crate::types::GroupInformation>;
+
+    /// See [`Groups::put`].
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation>;
+
+    /// See [`Groups::post`].
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation>;
+
+    /// See [`Groups::delete`].
+    async fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation>;
+}
+
+#[async_trait::async_trait]
+impl GroupsApi for Groups {
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &GroupsGetParams,
+    ) -> Result<crate::types::GroupInformation> {
+        Groups::get_with(self, account_id, params).await
+    }
+
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        Groups::put(self, account_id, body).await
+    }
+
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        Groups::post(self, account_id, body).await
+    }
+
+    async fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        Groups::delete(self, account_id, body).await
+    }
+}
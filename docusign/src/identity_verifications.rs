@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct IdentityVerifications {
     pub client: Client,
@@ -37,3 +35,25 @@ impl IdentityVerifications {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`IdentityVerifications`], for code that takes them as a trait, so that it can be
+/// tested with the `MockIdentityVerificationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait IdentityVerificationsApi: Send + Sync {
+    /// See [`IdentityVerifications::account_get`].
+    async fn account_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountIdentityVerificationResponse>;
+}
+
+#[async_trait::async_trait]
+impl IdentityVerificationsApi for IdentityVerifications {
+    async fn account_get(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::AccountIdentityVerificationResponse> {
+        IdentityVerifications::account_get(self, account_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Invoices {
     pub client: Client,
@@ -24,20 +22,18 @@ impl Invoices {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `from_date: &str` -- Specifies the date/time of the earliest invoice in the account to retrieve.
-     * * `to_date: &str` -- Specifies the date/time of the latest invoice in the account to retrieve.
+     * * `params: &InvoicesBillingGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn billing_get(
+    pub async fn billing_get_with(
         &self,
         account_id: &str,
-        from_date: &str,
-        to_date: &str,
+        params: &InvoicesBillingGetParams,
     ) -> Result<crate::types::BillingInvoicesResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from_date.is_empty() {
+        if let Some(from_date) = &params.from_date {
             query_args.push(("from_date".to_string(), from_date.to_string()));
         }
-        if !to_date.is_empty() {
+        if let Some(to_date) = &params.to_date {
             query_args.push(("to_date".to_string(), to_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -50,6 +46,23 @@ impl Invoices {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `billing_get_with` and pass the query parameters as a `InvoicesBillingGetParams` instead.
+     */
+    #[deprecated(note = "use `billing_get_with` instead")]
+    pub async fn billing_get(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::BillingInvoicesResponse> {
+        let params = InvoicesBillingGetParams {
+            from_date: Some(from_date.to_string()).filter(|s| !s.is_empty()),
+            to_date: Some(to_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.billing_get_with(account_id, &params).await
+    }
+
     /**
      * Retrieves a billing invoice.
      *
@@ -134,3 +147,76 @@ impl Invoices {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Invoices::billing_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InvoicesBillingGetParams {
+    /// Specifies the date/time of the earliest invoice in the account to retrieve.
+    pub from_date: Option<String>,
+    /// Specifies the date/time of the latest invoice in the account to retrieve.
+    pub to_date: Option<String>,
+}
+
+impl InvoicesBillingGetParams {
+    pub fn from_date<T: ToString>(mut self, from_date: T) -> Self {
+        self.from_date = Some(from_date.to_string());
+        self
+    }
+
+    pub fn to_date<T: ToString>(mut self, to_date: T) -> Self {
+        self.to_date = Some(to_date.to_string());
+        self
+    }
+}
+
+/// The functions of [`Invoices`], for code that takes them as a trait, so that it can be
+/// tested with the `MockInvoicesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait InvoicesApi: Send + Sync {
+    /// See [`Invoices::billing_get_with`].
+    async fn billing_get_with(
+        &self,
+        account_id: &str,
+        params: &InvoicesBillingGetParams,
+    ) -> Result<crate::types::BillingInvoicesResponse>;
+
+    /// See [`Invoices::billing_get_invoices`].
+    async fn billing_get_invoices(
+        &self,
+        account_id: &str,
+        invoice_id: &str,
+    ) -> Result<crate::types::BillingInvoice>;
+
+    /// See [`Invoices::billing_get_past_due`].
+    async fn billing_get_past_due(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::BillingInvoicesSummary>;
+}
+
+#[async_trait::async_trait]
+impl InvoicesApi for Invoices {
+    async fn billing_get_with(
+        &self,
+        account_id: &str,
+        params: &InvoicesBillingGetParams,
+    ) -> Result<crate::types::BillingInvoicesResponse> {
+        Invoices::billing_get_with(self, account_id, params).await
+    }
+
+    async fn billing_get_invoices(
+        &self,
+        account_id: &str,
+        invoice_id: &str,
+    ) -> Result<crate::types::BillingInvoice> {
+        Invoices::billing_get_invoices(self, account_id, invoice_id).await
+    }
+
+    async fn billing_get_past_due(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::BillingInvoicesSummary> {
+        Invoices::billing_get_past_due(self, account_id).await
+    }
+}
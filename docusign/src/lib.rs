@@ -24,6 +24,30 @@
 //! up to date as features are added. The documentation for the crate is generated
 //! along with the code to make this library easy to use.
 //!
+//! Each tag of the API is a cargo feature of its own, all of them enabled by the
+//! default `full` feature. To build only the tags you use, turn off the default
+//! features and enable those tags, along with `rustls-tls` or `native-tls`.
+//!
+//! The functions of each tag are also a trait, named after the tag, like `UsersApi`
+//! for `users`. Code that takes the trait can be tested with the mocks of the `mock`
+//! feature, like `MockUsersApi`, rather than a server.
+//!
+//! Requests wait out the rate limits of the API. Those that are safe to send twice
+//! are sent again after the `Retry-After` of rate limited responses, and all of them
+//! wait for the limit to reset when none of it is left. `Client::rate_limit_status` tells what the API last said about its
+//! limits.
+//!
+//! Requests that time out, can't connect or get a `5xx` that may not last are
+//! retried, waiting longer before each attempt, but only those that are safe to send
+//! twice: `GET`s, `HEAD`s, `OPTIONS` and `TRACE`s. `Client::builder().retry_policy(...)`
+//! changes how many times, how long to wait and which methods. For the APIs that take
+//! idempotency keys, the `POST`s of `Client::with_idempotency_key` are retried too.
+//!
+//! `Client::builder()` also sets the timeouts, proxies, root certificates, client
+//! certificate and `User-Agent` of the HTTP client, or takes a
+//! `reqwest_middleware::ClientWithMiddleware` of your own. Its `build` takes what
+//! `Client::new` does.
+//!
 //!
 //! To install the library, add the following to your `Cargo.toml` file.
 //!
@@ -91,9 +115,40 @@
 //!     access_token = docusign.refresh_access_token().await.unwrap();
 //! }
 //! ```
+//!
+//! With `set_auto_access_token_refresh(true)`, the client refreshes the access token
+//! before requests once it expires. With `set_refresh_on_unauthorized(true)`, it also
+//! does when a request is rejected with `401 Unauthorized` because of the access
+//! token, and sends the request again. The clones of a client that need a refresh at
+//! the same time wait for a single one.
+//!
+//! ## Keeping tokens
+//!
+//! The client only has its tokens in memory, and some providers give a new refresh
+//! token with each refresh, which makes the old one useless. To keep the tokens across
+//! restarts, give the client a `TokenStore`, like a file. It starts with the tokens
+//! saved there, if any, and saves the new ones it gets.
+//!
+//! ```
+//! use docusign::{token_store::FileTokenStore, Client};
+//!
+//! async fn do_call() {
+//!     let docusign = Client::new(
+//!         String::from("client-id"),
+//!         String::from("client-secret"),
+//!         String::from("redirect-uri"),
+//!         String::from("token"),
+//!         String::from("refresh-token"),
+//!     )
+//!     .with_token_store(FileTokenStore::new("tokens.json"))
+//!     .await
+//!     .unwrap();
+//! }
+//! ```
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::nonstandard_macro_braces)]
 #![allow(clippy::large_enum_variant)]
+#![allow(clippy::result_large_err)]
 #![allow(clippy::tabs_in_doc_comments)]
 #![allow(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -105,6 +160,8 @@
 ///The DocuSign Account Custom Branding feature enables you to set the colors, logo, and text that recipients see at the account level. The settings associated with a brand are applied to all of the envelopes that use the brand. You can create multiple brand profiles for different corporate brands or internal departments.
 ///
 ///**Note**: To use this resource, branding for either signing or sending must be enabled for the account (either `canSelfBrandSend`, `canSelfBrandSign`, or both of these account settings must be set to **true**).
+#[cfg(feature = "account_brands")]
+#[cfg_attr(docsrs, doc(cfg(feature = "account_brands")))]
 pub mod account_brands;
 /// The `AccountConsumerDisclosures` resource provides methods that enable you to enable, retrieve, and manage the Electronic Record and Signature Consent Disclosure (ERSD) options for your account. This is the disclosure that displays to each new recipient who is going to sign or add other information, or who is required to view the documents you send to them. The recipient must read and agree to the terms of the disclosure before they can access and take action on the documents you send. The ERSD does not apply to copy-only recipients, but does apply to recipients who must sign or view your documents.
 ///
@@ -118,6 +175,8 @@ pub mod account_brands;
 ///2. Provide the `esignAgreementText` and `esignText` in the language associated with the `langCode`.
 ///
 ///For more information, see [Legal Disclosure](https://support.docusign.com/en/guides/ndse-admin-guide-legal-disclosure).
+#[cfg(feature = "account_consumer_disclosures")]
+#[cfg_attr(docsrs, doc(cfg(feature = "account_consumer_disclosures")))]
 pub mod account_consumer_disclosures;
 /// Custom fields enable you to record custom information about envelopes that you can then use for sorting, organizing, searching, and other downstream processes.
 ///
@@ -146,8 +205,12 @@ pub mod account_consumer_disclosures;
 ///
 ///- `text`: Enables the sender to enter the value for the field.
 ///- `list`: Enables the sender to select the value of the field from a predetermined list.
+#[cfg(feature = "account_custom_fields")]
+#[cfg_attr(docsrs, doc(cfg(feature = "account_custom_fields")))]
 pub mod account_custom_fields;
 /// The AccountPasswordRules resource provides methods that allow you to obtain and update account password rules, as well as membership and account rules.
+#[cfg(feature = "account_password_rules")]
+#[cfg_attr(docsrs, doc(cfg(feature = "account_password_rules")))]
 pub mod account_password_rules;
 /// An account permission profile is assigned to a group of users, enabling you to set permissions for all of the users in the group at the same time. You are not required to set Permission Profiles for a group, but it makes it easier to manage user permissions for a large number of users.
 ///
@@ -159,8 +222,12 @@ pub mod account_password_rules;
 ///
 ///You can also create your own custom account permission profiles.
 ///.
+#[cfg(feature = "account_permission_profiles")]
+#[cfg_attr(docsrs, doc(cfg(feature = "account_permission_profiles")))]
 pub mod account_permission_profiles;
 /// .
+#[cfg(feature = "account_seal_providers")]
+#[cfg_attr(docsrs, doc(cfg(feature = "account_seal_providers")))]
 pub mod account_seal_providers;
 /// Standards-Based Signatures (SBS) is the label used to describe DocuSign's suite of signatures that comply with regional and industry regulations, such as the electronic IDentification, Authentication and trust Services (eIDAS) regulation in Europe.
 ///
@@ -187,26 +254,42 @@ pub mod account_seal_providers;
 ///- **Wet Signing**: Wet-signed documents are added as new documents to envelopes. This behavior results in the uploaded or faxed, physically signed document being added as a new document to the envelope. This new document gets only a platform seal.
 ///
 ///For more information, see [Standards Based Signatures](https://developers.docusign.com/esign-rest-api/guides/standards-based-signatures).
+#[cfg(feature = "account_signature_providers")]
+#[cfg_attr(docsrs, doc(cfg(feature = "account_signature_providers")))]
 pub mod account_signature_providers;
 /// .
+#[cfg(feature = "account_signatures")]
+#[cfg_attr(docsrs, doc(cfg(feature = "account_signatures")))]
 pub mod account_signatures;
 /// The AccountTabSettings resource provides methods that allow you to manage
 ///tab settings for an account.
 ///.
+#[cfg(feature = "account_tab_settings")]
+#[cfg_attr(docsrs, doc(cfg(feature = "account_tab_settings")))]
 pub mod account_tab_settings;
 /// The AccountWatermarks resource provides methods that allow you to obtain, preview and update watermark information.
+#[cfg(feature = "account_watermarks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "account_watermarks")))]
 pub mod account_watermarks;
 /// The Accounts resource provides methods that allow you to create, delete, and manage your accounts.
+#[cfg(feature = "accounts")]
+#[cfg_attr(docsrs, doc(cfg(feature = "accounts")))]
 pub mod accounts;
 /// .
+#[cfg(feature = "appliance_info")]
+#[cfg_attr(docsrs, doc(cfg(feature = "appliance_info")))]
 pub mod appliance_info;
 /// You can configure automatic archiving of emails sent from all of your DocuSign accounts.
 ///
 ///For more information, see [Email Archive Configuration](https://support.docusign.com/en/guides/ndse-admin-guide-email-archive-configuration).
 ///
 ///**Note**: This feature is only available for certain account plans and must be enabled by DocuSign.
+#[cfg(feature = "bcc_email_archive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bcc_email_archive")))]
 pub mod bcc_email_archive;
 /// The Billing resource provides methods that allow you to manage the billing plans,associated with an account.
+#[cfg(feature = "billing_plans")]
+#[cfg_attr(docsrs, doc(cfg(feature = "billing_plans")))]
 pub mod billing_plans;
 /// You can use bulk send lists for anything that you need to send to a large number of recipients on a
 ///recurring basis, such as:
@@ -275,6 +358,8 @@ pub mod billing_plans;
 ///
 ///For more information about using bulk send, see [Bulk Sending Envelopes](https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/envelopes/bulk-send/).
 ///.
+#[cfg(feature = "bulk_send")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bulk_send")))]
 pub mod bulk_send;
 /// A chunked upload is a temporary file that you upload in parts and stage at DocuSign, then refer to as the content for other API calls. For example, you might use it for document content when assembling an envelope or template.
 ///
@@ -304,8 +389,12 @@ pub mod bulk_send;
 ///- The maximum total size of all of a member's unexpired, unconsumed  ChunkedUploads. The default value is 1 GB.
 ///- The amount of time that a chunked upload is active after you initialize it. The default value is 20 minutes.
 ///.
+#[cfg(feature = "chunked_uploads")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chunked_uploads")))]
 pub mod chunked_uploads;
 /// The CloudStorage resource provides methods that allow you to list files stored on your cloud storage provider.
+#[cfg(feature = "cloud_storage")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cloud_storage")))]
 pub mod cloud_storage;
 /// The following providers are supported:
 ///
@@ -316,12 +405,16 @@ pub mod cloud_storage;
 ///* OneDrive
 ///
 ///To use cloud storage files, you must first give DocuSign access to your cloud storage provider. You can disconnect authorized a cloud storage provider at any time.
+#[cfg(feature = "cloud_storage_providers")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cloud_storage_providers")))]
 pub mod cloud_storage_providers;
 /// The comments resource provides a method that enables you to download a PDF file containing all of the comments that recipients and the sender have added to the documents in an envelope.
 ///
 ///Comments are disabled by default. To use the comments feature, an account administrator must enable comments on the account (in the `accountSettingsInformation` object, set the `enableSigningExtensionComments` property to **true**).
 ///
 ///For more information, see [Comments Settings](https://support.docusign.com/en/guides/ndse-admin-guide-comments-settings).
+#[cfg(feature = "comments")]
+#[cfg_attr(docsrs, doc(cfg(feature = "comments")))]
 pub mod comments;
 /// The `ConnectConfigurations` resource enables you to configure the DocuSign Connect service for your account.
 ///
@@ -340,30 +433,46 @@ pub mod comments;
 ///When you enable Send Individual Messages (SIM) mode on a Connect configuration, DocuSign will deliver notifications for all envelope events individually. In contrast with aggregated messages, when a final recipient completes an envelope, your listener will receive a single Recipient: complete event followed by a single Envelope: complete event for the final participating party on the agreement.  If you need more granular control over event notifications, you can enable SIM mode in the Admin area of the RADmin console. For more information about SIM mode, see [Using Connect's New Send Individual Messages Feature](https://www.docusign.com/blog/dsdev-connect-send-individual-messages/).
 ///
 ///**Note**: To create an envelope-level webhook instead of using account-level webhooks, use the Envelopes::Create method and add an `eventNotification` object to an envelope object.
+#[cfg(feature = "connect_configurations")]
+#[cfg_attr(docsrs, doc(cfg(feature = "connect_configurations")))]
 pub mod connect_configurations;
 /// The ConnectEvents resource provides methods that allow you to read, delete, and republish the connect logs associated with an envelope.
+#[cfg(feature = "connect_events")]
+#[cfg_attr(docsrs, doc(cfg(feature = "connect_events")))]
 pub mod connect_events;
 /// .
+#[cfg(feature = "connect_secret")]
+#[cfg_attr(docsrs, doc(cfg(feature = "connect_secret")))]
 pub mod connect_secret;
 /// DocuSign eSignature includes a contacts list (also referred to as an address book) to help make sending envelopes even easier. When you send an envelope, the recipients' names and email addresses are automatically added to your contacts list. You can use the contacts list to quickly add recipients to the envelopes you send. The `Contacts` resource provides methods that enable you to manage your contacts.
 ///
 ///.
+#[cfg(feature = "contacts")]
+#[cfg_attr(docsrs, doc(cfg(feature = "contacts")))]
 pub mod contacts;
 /// The CustomTabs resource provides methods that allow you create and manage custom tabs based on the existing DocuSign tabs.
 ///
 ///You can create a tab with pre-defined properties, such as a text tab with a certain font type and validation pattern. Users can access the custom tabs when sending documents through the DocuSign web application.
 ///
 ///Custom tabs can be created based on the  approve, checkbox, company, date, date signed, decline, email, email address, envelope ID, first name, formula, full name, initial here, last name, list, note, number, radio, sign here, signer attachment, SSN, text, title, and zip tabs.
+#[cfg(feature = "custom_tabs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "custom_tabs")))]
 pub mod custom_tabs;
 /// **Note**: Responsive Signing is disabled by default. To use this functionality, an account administrator must switch the account setting `enableResponsiveSigning` to **true**.
 ///Also note that Smart Sections (creating a signable HTML document that uses collapsible sections and rotating tables) are premium features. To request them, contact your DocuSign account manager.
+#[cfg(feature = "document_responsive_html_preview")]
+#[cfg_attr(docsrs, doc(cfg(feature = "document_responsive_html_preview")))]
 pub mod document_responsive_html_preview;
 /// The ENoteConfigurations resource provides methods that allow you to manage
 ///information for the eNote eOriginal integration.
 ///.
+#[cfg(feature = "e_note_configurations")]
+#[cfg_attr(docsrs, doc(cfg(feature = "e_note_configurations")))]
 pub mod e_note_configurations;
 /// The EnvelopeAttachments resource provides methods that allow you to manage attachments.
 ///.
+#[cfg(feature = "envelope_attachments")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_attachments")))]
 pub mod envelope_attachments;
 /// The `EnvelopeConsumerDisclosures` resource provides methods that enable you to retrieve the Electronic Record and Signature Disclosure (ERSD) for an envelope recipient. This is the disclosure that displays to each new recipient who is going to sign or add other information, or who is required to view the documents you send to them. The recipient must read and agree to the terms of the disclosure before they can access and take action on the documents you send. The ERSD does not apply to copy-only recipients, but does apply to recipients who must sign or view your documents.
 ///
@@ -376,6 +485,8 @@ pub mod envelope_attachments;
 ///**Important**: The system does not translate the ERSD for you. An account administrator must create a version of the account-level disclosure for each language that your signers use.
 ///
 ///For more information, see [Legal Disclosure](https://support.docusign.com/en/guides/ndse-admin-guide-legal-disclosure).
+#[cfg(feature = "envelope_consumer_disclosures")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_consumer_disclosures")))]
 pub mod envelope_consumer_disclosures;
 /// The EnvelopeCustomFields resource provides methods that allow you manage custom fields in an envelope.
 ///
@@ -387,19 +498,29 @@ pub mod envelope_consumer_disclosures;
 ///- `list`: Enables the sender to select the value of the field from a predetermined list.
 ///
 ///You may assign up to three envelope custom fields to an envelope. This limit does not include account (document) custom fields.
+#[cfg(feature = "envelope_custom_fields")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_custom_fields")))]
 pub mod envelope_custom_fields;
 /// The EnvelopeDocumentFields resource provides methods that allow you to manage custom fields on a document.
 ///
 ///You can create custom versions of standard fields that combine of field properties, such as font type or size, or a validation setting.
 ///
 ///Note: Some advanced features and options are supported only in certain DocuSign plans. Your account plan might not support some options discussed in this help topic. For more information about which options are available for your account, check your account plan or contact your Account Manager.
+#[cfg(feature = "envelope_document_fields")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_document_fields")))]
 pub mod envelope_document_fields;
 /// .
+#[cfg(feature = "envelope_document_html_definitions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_document_html_definitions")))]
 pub mod envelope_document_html_definitions;
 /// The EnvelopeDocumentTabs resource provides methods that enable you to manage tabs in envelopes. For a complete list of options, see the following Properties section.
+#[cfg(feature = "envelope_document_tabs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_document_tabs")))]
 pub mod envelope_document_tabs;
 /// The Envelope Document Visibility resource provides methods for managing document visibility by recipient.
 ///.
+#[cfg(feature = "envelope_document_visibility")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_document_visibility")))]
 pub mod envelope_document_visibility;
 /// <!-- resources aren't rendered the same way
 ///     as other pages. This is a little hack to
@@ -428,16 +549,24 @@ pub mod envelope_document_visibility;
 ///[envelopescreate]: https://developers.docusign.com/docs/esign-rest-api/reference/Envelopes/Envelopes/create/
 ///
 ///.
+#[cfg(feature = "envelope_documents")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_documents")))]
 pub mod envelope_documents;
 /// The EnvelopeEmailSettings provide methods that allow you to manage the email override settings for an envelope.
 ///
 ///Email override settings change the reply to email address, name, or the BCC for email archive information, for the envelope. Note that changing email settings will only affect email communications that occur after the addition was made.
 ///
 ///The BCC Email address feature is designed to provide a copy of all email communications for external archiving purposes. DocuSign recommends that envelopes sent using the BCC for Email Archive feature, including the BCC Email Override option, include additional signer authentication options. To send a copy of the envelope to a recipient who does not need to sign, use a Carbon Copies or Certified Deliveries Recipient Type.
+#[cfg(feature = "envelope_email_settings")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_email_settings")))]
 pub mod envelope_email_settings;
 /// The EnvelopeFormData resource provides a method for downloading the data that users have entered into a form associated with an envelope.
+#[cfg(feature = "envelope_form_data")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_form_data")))]
 pub mod envelope_form_data;
 /// .
+#[cfg(feature = "envelope_html_definitions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_html_definitions")))]
 pub mod envelope_html_definitions;
 /// The EnvelopeLocks resource provides methods that allow you to
 ///manage locks on an envelope.
@@ -453,6 +582,8 @@ pub mod envelope_html_definitions;
 ///3. Delete the envelope lock and save the changes. If the envelope is based on a template that has a password, you must supply the template password to save the changes.
 ///
 ///**Note**: To use envelope locks, the user must have envelope locking capability enabled.
+#[cfg(feature = "envelope_locks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_locks")))]
 pub mod envelope_locks;
 /// The EnvelopeRecipientTabs resource provides methods that enable you
 ///to add,
@@ -1083,6 +1214,8 @@ pub mod envelope_locks;
 ///  of the `Name` Text tabs,
 ///  the `bold` property must be set to the same value for both tabs.
 ///.
+#[cfg(feature = "envelope_recipient_tabs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_recipient_tabs")))]
 pub mod envelope_recipient_tabs;
 /// The EnvelopeRecipients resource enables you manage the recipients of an envelope. All recipient types share a set of [core parameters](#core-recipient-parameters), but some recipient types have additional parameters. You specify the recipient type using the `recipientType` parameter. The recipient types are as follows:
 ///
@@ -1350,12 +1483,18 @@ pub mod envelope_recipient_tabs;
 ///
 ///[ListLang]: https://developers.docusign.com/docs/esign-rest-api/reference/Accounts/Accounts/listSupportedLanguages/
 ///.
+#[cfg(feature = "envelope_recipients")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_recipients")))]
 pub mod envelope_recipients;
 /// The EnvelopeTemplates resource provides methods that allow you to add and delete templates on envelopes and documents.
+#[cfg(feature = "envelope_templates")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_templates")))]
 pub mod envelope_templates;
 /// Envelope transfer rules enable administrators to transfer envelopes and templates from user to another. For example, you might do this when an employee leaves the company. To transfer ownership of envelopes and templates, the **Transfer Custody** feature must be enabled for your account.
 ///
 ///For more information about this functionality, see [Transfer Envelopes and Templates](https://support.docusign.com/en/guides/ndse-admin-guide-transfer-envelopes-templates).
+#[cfg(feature = "envelope_transfer_rules")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_transfer_rules")))]
 pub mod envelope_transfer_rules;
 /// The `EnvelopeViews` resource provides methods that return URLs that you can embed into your application to provide access to the DocuSign UI.
 ///
@@ -1368,8 +1507,12 @@ pub mod envelope_transfer_rules;
 ///-  Recipient View: The view the recipient sees in the DocuSign UI.
 ///-  Shared Recipient View: The view a user sees of a shared envelope in the DocuSign UI.
 ///-  Sender View: The sending view of the DocuSign UI.
+#[cfg(feature = "envelope_views")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_views")))]
 pub mod envelope_views;
 /// .
+#[cfg(feature = "envelope_workflow_definition")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelope_workflow_definition")))]
 pub mod envelope_workflow_definition;
 /// The Envelope resource provides methods that allow you to manipulate and monitor envelopes.
 ///
@@ -1389,18 +1532,31 @@ pub mod envelope_workflow_definition;
 ///If you need to delete a page from a document in an envelope, use the Envelope: deleteDocumentPage method.
 ///
 ///The resource also includes a number of methods that allow you to retrieve and set the initials and signature for certain types of recipients on the document.
+#[cfg(feature = "envelopes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "envelopes")))]
 pub mod envelopes;
+mod error;
 /// .
+#[cfg(feature = "favorite_templates")]
+#[cfg_attr(docsrs, doc(cfg(feature = "favorite_templates")))]
 pub mod favorite_templates;
 /// The Folders resource provides methods that allow you to view contents of folders on the account and move envelopes between folders.
+#[cfg(feature = "folders")]
+#[cfg_attr(docsrs, doc(cfg(feature = "folders")))]
 pub mod folders;
 /// For the custom groups you define for your account, you can assign brands to specify the ones that group members can use. Group members can use the available brands when they send envelopes or create templates. For more information, see [Assign Brands to Groups](https://support.docusign.com/en/guides/ndse-admin-guide-assign-brands-to-groups).
+#[cfg(feature = "group_brands")]
+#[cfg_attr(docsrs, doc(cfg(feature = "group_brands")))]
 pub mod group_brands;
 /// The GroupUsers resource provides methods that allow you to manage the users in a group.
+#[cfg(feature = "group_users")]
+#[cfg_attr(docsrs, doc(cfg(feature = "group_users")))]
 pub mod group_users;
 /// The Groups resource provides methods that allow you to manage groups for the account.
 ///
 ///Groups can be used to help manage users by associating users with a group. A group can be associated with a Permission Profile, which sets the user permissions for users in that group without having to set the `userSettings` property for each user. You are not required to set Permission Profiles for a group, but this makes it easier to manage user permissions for a large number of users. Groups can also be used with template sharing to limit user access to templates.
+#[cfg(feature = "groups")]
+#[cfg_attr(docsrs, doc(cfg(feature = "groups")))]
 pub mod groups;
 /// The DocuSign Identity Verification process requires a signer to submit an image of their valid government ID and wait for the image to be uploaded and verified before they can access a document.
 ///
@@ -1409,8 +1565,12 @@ pub mod groups;
 ///To use Identity Verification, the [DocuSign Identity Verification](https://www.docusign.com/products/identify) product must be enabled for your account.
 ///
 ///For more information, see [Can I see (a photo of) your ID? Digital verification of real-world IDs](https://www.docusign.com/blog/can-i-see-a-photo-of-your-id-digital-verification-of-real-world-ids/).
+#[cfg(feature = "identity_verifications")]
+#[cfg_attr(docsrs, doc(cfg(feature = "identity_verifications")))]
 pub mod identity_verifications;
 /// The Invoices resource provides methods that allow you to manage the invoices for an account.
+#[cfg(feature = "invoices")]
+#[cfg_attr(docsrs, doc(cfg(feature = "invoices")))]
 pub mod invoices;
 /// DocuSign eNotary makes the notarization process fully digital
 ///for senders, signers, and notaries.
@@ -1420,19 +1580,32 @@ pub mod invoices;
 ///to see which jurisdictions are supported.
 ///
 ///.
+#[cfg(feature = "notary")]
+#[cfg_attr(docsrs, doc(cfg(feature = "notary")))]
 pub mod notary;
 /// .
+#[cfg(feature = "notary_journals")]
+#[cfg_attr(docsrs, doc(cfg(feature = "notary_journals")))]
 pub mod notary_journals;
 /// Creating, updating, and deleting notary jurisdiction objects.
+#[cfg(feature = "notary_jurisdiction")]
+#[cfg_attr(docsrs, doc(cfg(feature = "notary_jurisdiction")))]
 pub mod notary_jurisdiction;
+pub mod pagination;
 /// .
+#[cfg(feature = "payment_gateway_accounts")]
+#[cfg_attr(docsrs, doc(cfg(feature = "payment_gateway_accounts")))]
 pub mod payment_gateway_accounts;
 /// The Payments resource provides methods that allow you to manage payments for an account.
 ///
 ///These calls can only be used by users with account administrator privileges.
+#[cfg(feature = "payments")]
+#[cfg_attr(docsrs, doc(cfg(feature = "payments")))]
 pub mod payments;
 /// The PowerFormData resource provides a method for accessing PowerForm data.
 ///.
+#[cfg(feature = "power_form_data")]
+#[cfg_attr(docsrs, doc(cfg(feature = "power_form_data")))]
 pub mod power_form_data;
 /// PowerForms enable you to create self-service documents for signature. A PowerForm is an envelope initiated from a URL that you make available for signers to complete. You can either add a PowerForm to your website or email the URL to recipients. DocuSign saves the data that recipients enter so  you can easily integrate it with your other applications.
 ///
@@ -1458,21 +1631,37 @@ pub mod power_form_data;
 ///- `PowerForms_PowerFormId_Required`: A `powerFormId` is required.
 ///- `PowerForms_PowerFormId_Mismatch`: A `powerFormId` mismatch has occurred.
 ///.
+#[cfg(feature = "power_forms")]
+#[cfg_attr(docsrs, doc(cfg(feature = "power_forms")))]
 pub mod power_forms;
+pub mod rate_limiter;
 /// .
+#[cfg(feature = "reports")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reports")))]
 pub mod reports;
 /// The RequestLogs resource provide methods that allow you to retrieve and delete the API request log files.
 ///
 ///The log files contain the API requests associated with your integration. They can aid you in troubleshooting specific issues within an integration, or if DocuSign Support requests an API trace log.
+#[cfg(feature = "request_logs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "request_logs")))]
 pub mod request_logs;
 /// The Resources resource provides a method which retrieves the base resources that are available.
+#[cfg(feature = "resources")]
+#[cfg_attr(docsrs, doc(cfg(feature = "resources")))]
 pub mod resources;
 /// **Note**: Responsive Signing is disabled by default. To use this functionality, an account administrator must switch the account setting `enableResponsiveSigning` to **true**.
 ///Also note that Smart Sections (creating a signable HTML document that uses collapsible sections and rotating tables) are premium features. To request them, contact your DocuSign account manager.
+#[cfg(feature = "responsive_html_preview")]
+#[cfg_attr(docsrs, doc(cfg(feature = "responsive_html_preview")))]
 pub mod responsive_html_preview;
+pub mod retry;
 /// The Services resource provides a method that allow you to retrieve the available service versions.
+#[cfg(feature = "services")]
+#[cfg_attr(docsrs, doc(cfg(feature = "services")))]
 pub mod services;
 /// The SigningGroupUsers resource provides methods that allow you to manage users in Signing Groups.
+#[cfg(feature = "signing_group_users")]
+#[cfg_attr(docsrs, doc(cfg(feature = "signing_group_users")))]
 pub mod signing_group_users;
 /// The SigningGroups resource provides methods that allow you manage signing groups.
 ///
@@ -1488,14 +1677,20 @@ pub mod signing_group_users;
 ///The Signing Groups feature is only supported in certain DocuSign Enterprise and System Automated Premium plans. Your account might not support this option. To access this functionality, contact your Account Manager or DocuSign Support (support@docusign.com) for assistance.
 ///
 ///For more information, see [Signing Groups](https://support.docusign.com/en/guides/ndse-user-guide-signing-groups).
+#[cfg(feature = "signing_groups")]
+#[cfg_attr(docsrs, doc(cfg(feature = "signing_groups")))]
 pub mod signing_groups;
 /// .
+#[cfg(feature = "tabs_blob")]
+#[cfg_attr(docsrs, doc(cfg(feature = "tabs_blob")))]
 pub mod tabs_blob;
 /// The TemplateBulkRecipients resource provide methods that allow you manage the bulk recipient file for an template.
 ///
 ///The bulk recipient CSV (Comma Separated Value) file contains the list of recipient names and email addresses that you can add to an template to send the same document to a large number of recipients.
 ///
 ///The required and optional information that can be included the file is described in the BulkEnvelope: updateRecipients method.
+#[cfg(feature = "template_bulk_recipients")]
+#[cfg_attr(docsrs, doc(cfg(feature = "template_bulk_recipients")))]
 pub mod template_bulk_recipients;
 /// The TemplateCustomFields resource provides methods that allow you manage custom fields in an template.
 ///
@@ -1505,22 +1700,37 @@ pub mod template_bulk_recipients;
 ///
 ///- `text`: Enables the sender to enter the value for the field.
 ///- `list`: Enables the sender to select the value of the field from a predetermined list.
+#[cfg(feature = "template_custom_fields")]
+#[cfg_attr(docsrs, doc(cfg(feature = "template_custom_fields")))]
 pub mod template_custom_fields;
 /// The TemplateDocumentFields resource provides methods that allow you to manage custom fields on a document.
 ///
 ///You can create custom versions of standard fields that combine of field properties, such as font type or size, or a validation setting.
 ///
 ///Note: Some advanced features and options are supported only in certain DocuSign plans. Your account plan might not support some options discussed in this help topic. For more information about which options are available for your account, check your account plan or contact your Account Manager.
+#[cfg(feature = "template_document_fields")]
+#[cfg_attr(docsrs, doc(cfg(feature = "template_document_fields")))]
 pub mod template_document_fields;
 /// .
+#[cfg(feature = "template_document_html_definitions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "template_document_html_definitions")))]
 pub mod template_document_html_definitions;
 /// **Note**: Responsive Signing is disabled by default. To use this functionality, an account administrator must switch the account setting `enableResponsiveSigning` to **true**.
 ///Also note that Smart Sections (creating a signable HTML document that uses collapsible sections and rotating tables) are premium features. To request them, contact your DocuSign account manager.
+#[cfg(feature = "template_document_responsive_html_preview")]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(feature = "template_document_responsive_html_preview"))
+)]
 pub mod template_document_responsive_html_preview;
 /// The EnvelopeDocumentTabs resource provides methods that enable you to manage tabs in a template.
+#[cfg(feature = "template_document_tabs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "template_document_tabs")))]
 pub mod template_document_tabs;
 /// The Template Document Visibility resource provides methods for managing template document visibility by recipient.
 ///.
+#[cfg(feature = "template_document_visibility")]
+#[cfg_attr(docsrs, doc(cfg(feature = "template_document_visibility")))]
 pub mod template_document_visibility;
 /// <!-- resources aren't rendered the same way
 ///     as other pages. This is a little hack to
@@ -1547,8 +1757,12 @@ pub mod template_document_visibility;
 ///
 ///[templatescreate]: https://developers.docusign.com/docs/esign-rest-api/reference/Templates/Templates/create/
 ///.
+#[cfg(feature = "template_documents")]
+#[cfg_attr(docsrs, doc(cfg(feature = "template_documents")))]
 pub mod template_documents;
 /// .
+#[cfg(feature = "template_html_definitions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "template_html_definitions")))]
 pub mod template_html_definitions;
 /// The TemplateLocks resource provides methods that allow you to
 ///manage locks on a template.
@@ -1564,6 +1778,8 @@ pub mod template_html_definitions;
 ///3. Delete the template lock and save the changes. If the template has a password, you must supply the template password to save the changes.
 ///
 ///**Note**: To use template locks, the user must have envelope locking capability enabled.
+#[cfg(feature = "template_locks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "template_locks")))]
 pub mod template_locks;
 /// <!-- resources aren't rendered the same way
 ///     as other pages. This is a little hack to
@@ -1735,6 +1951,8 @@ pub mod template_locks;
 ///  of the `Name` Text tabs,
 ///  the `bold` property must be set to the same value for both tabs.
 ///.
+#[cfg(feature = "template_recipient_tabs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "template_recipient_tabs")))]
 pub mod template_recipient_tabs;
 /// The TemplateRecipients resource allows you manage the recipients of an template.
 ///
@@ -2091,9 +2309,13 @@ pub mod template_recipient_tabs;
 ///| signedDateTime | No | DateTime | Reserved: For DocuSign use only. |
 ///| offlineAttributes | No | &nbsp; | Reserved: For DocuSign use only.|
 ///.
+#[cfg(feature = "template_recipients")]
+#[cfg_attr(docsrs, doc(cfg(feature = "template_recipients")))]
 pub mod template_recipients;
 /// **Note**: Responsive Signing is disabled by default. To use this functionality, an account administrator must switch the account setting `enableResponsiveSigning` to **true**.
 ///Also note that Smart Sections (creating a signable HTML document that uses collapsible sections and rotating tables) are premium features. To request them, contact your DocuSign account manager.
+#[cfg(feature = "template_responsive_html_preview")]
+#[cfg_attr(docsrs, doc(cfg(feature = "template_responsive_html_preview")))]
 pub mod template_responsive_html_preview;
 /// The TemplateViews resource
 ///provides a method that returns a URL
@@ -2110,6 +2332,8 @@ pub mod template_responsive_html_preview;
 ///
 ///[envelopeviews]: https://developers.docusign.com/docs/esign-rest-api/reference/Envelopes/EnvelopeViews/
 ///.
+#[cfg(feature = "template_views")]
+#[cfg_attr(docsrs, doc(cfg(feature = "template_views")))]
 pub mod template_views;
 /// The Template resource provides methods that allow you to manipulate and monitor templates.
 ///
@@ -2126,19 +2350,28 @@ pub mod template_views;
 ///If you need to delete a page from a document in a template, use the Template: deleteDocumentPage method.
 ///
 ///The resource also includes a number of methods that allow you to retrieve and set the initials and signature for certain types of recipients on the document.
+#[cfg(feature = "templates")]
+#[cfg_attr(docsrs, doc(cfg(feature = "templates")))]
 pub mod templates;
 #[cfg(test)]
 mod tests;
+pub mod token_store;
 pub mod types;
 /// The UserCustomSettings resource provides methods that allow you to manage the custom settings for a user.
 ///
 ///Custom settings are a flexible way to store and retrieve custom user information that can be used in your own system.
 ///
 ///There is a limit on the size for all the custom user settings for a single user. All the custom user settings for a single user is limited to 4,000 characters, which includes the xml and json structure for the settings.
+#[cfg(feature = "user_custom_settings")]
+#[cfg_attr(docsrs, doc(cfg(feature = "user_custom_settings")))]
 pub mod user_custom_settings;
 /// The UserProfiles resource provides methods that allow you to manage a user's profile.
+#[cfg(feature = "user_profiles")]
+#[cfg_attr(docsrs, doc(cfg(feature = "user_profiles")))]
 pub mod user_profiles;
 /// The UserSignatures resource provides methods that allow you manage the  intials and signature images for a user.
+#[cfg(feature = "user_signatures")]
+#[cfg_attr(docsrs, doc(cfg(feature = "user_signatures")))]
 pub mod user_signatures;
 /// The Users resource provides methods that enable you to manage users for an account.
 ///
@@ -2157,12 +2390,16 @@ pub mod user_signatures;
 ///- updateSettings
 ///
 ///Instead, you can retrieve and set the user's job title by using the UserProfiles:Get and UserProfiles:Update methods. For more information, see [UserProfiles](https://developers.docusign.com/docs/esign-rest-api/reference/Users/UserProfiles).
+#[cfg(feature = "users")]
+#[cfg_attr(docsrs, doc(cfg(feature = "users")))]
 pub mod users;
 #[doc(hidden)]
 pub mod utils;
 /// The WorkspaceItems resource provides methods that enable you to manage
 ///workspace items.
 ///.
+#[cfg(feature = "workspace_items")]
+#[cfg_attr(docsrs, doc(cfg(feature = "workspace_items")))]
 pub mod workspace_items;
 /// A workspace is a collaborative space for sharing documents and managing workflows. A workspace has a single owner who must be a DocuSign user. The owner can invite others to the workspace as collaborators. Individuals who are not DocuSign users must create a DocuSign account to join a workspace as a collaborator.
 ///
@@ -2176,11 +2413,13 @@ pub mod workspace_items;
 ///- **Transactions**: A transaction is a series of workflow events related to one or more documents. These events route the documents to one or more individuals for the purposes of doing business. Each transaction has a single owner (the sender).
 ///
 ///**Note**: Documents in a template are not individually listed as files.
+#[cfg(feature = "workspaces")]
+#[cfg_attr(docsrs, doc(cfg(feature = "workspaces")))]
 pub mod workspaces;
 
-use anyhow::{anyhow, Error, Result};
+pub use error::{Error, ErrorBody, Result};
 
-pub const DEFAULT_HOST: &str = "https://na4.docusign.net";
+pub const DEFAULT_HOST: &str = "https://na4.docusign.net/restapi";
 
 mod progenitor_support {
     use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
@@ -2202,6 +2441,176 @@ mod progenitor_support {
     }
 }
 
+/// The body of a response that is not JSON, such as a PDF, an image or an archive.
+#[derive(Debug, Clone, Default)]
+pub struct Download {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: bytes::Bytes,
+}
+
+/// The body of a response that is not JSON, returned as a stream of chunks rather
+/// than read into memory all at once.
+pub struct DownloadStream {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: std::pin::Pin<Box<dyn futures::Stream<Item = Result<bytes::Bytes>> + Send>>,
+}
+
+impl std::fmt::Debug for DownloadStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DownloadStream")
+            .field("content_type", &self.content_type)
+            .finish()
+    }
+}
+
+/// Configures the HTTP client of a `Client`, with the defaults of `Client::new` unless
+/// told otherwise. Its `build` takes what `Client::new` does.
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    retry_policy: crate::retry::RetryPolicy,
+    max_rate_limit_wait: Option<std::time::Duration>,
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    user_agent: Option<String>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    root_certificates: Vec<reqwest::Certificate>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    identity: Option<reqwest::Identity>,
+    http_client: Option<reqwest_middleware::ClientWithMiddleware>,
+}
+
+impl ClientBuilder {
+    /// Set how failed requests are retried.
+    pub fn retry_policy(mut self, retry_policy: crate::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set how long requests wait for a rate limit to reset, at most,
+    /// `rate_limiter::MAX_WAIT` unless set.
+    pub fn max_rate_limit_wait(mut self, max_wait: std::time::Duration) -> Self {
+        self.max_rate_limit_wait = Some(max_wait);
+        self
+    }
+
+    /// Set how long each attempt at a request can take, from connecting to reading the
+    /// whole response. There is no limit unless set.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set how long connecting to the API can take.
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests through a proxy, like `reqwest::Proxy::https(url)`. Without one,
+    /// the proxies of the `HTTP_PROXY` and `HTTPS_PROXY` environment variables are used.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Set the `User-Agent` of requests.
+    pub fn user_agent<U>(mut self, user_agent: U) -> Self
+    where
+        U: ToString,
+    {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Trust the certificates signed by a certificate authority, along with those of
+    /// the system, like a private one of `reqwest::Certificate::from_pem(pem)`.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Authenticate to the API with a client certificate, for mutual TLS.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn identity(mut self, identity: reqwest::Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Send requests with a client of your own, with its own middleware. The other
+    /// settings of the builder don't apply to it, and it only retries or waits out
+    /// rate limits if it has a `retry::Retry` or a `rate_limiter::RateLimiter` of its
+    /// own.
+    pub fn http_client(mut self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /*
+     * The HTTP client, along with the rate limiter in its middleware.
+     */
+    fn http(
+        self,
+    ) -> Result<(
+        reqwest_middleware::ClientWithMiddleware,
+        crate::rate_limiter::RateLimiter,
+    )> {
+        if let Some(client) = self.http_client {
+            return Ok((client, Default::default()));
+        }
+
+        let mut rate_limiter =
+            crate::rate_limiter::RateLimiter::new().with_retry_policy(self.retry_policy.clone());
+        if let Some(max_wait) = self.max_rate_limit_wait {
+            rate_limiter = rate_limiter.with_max_wait(max_wait);
+        }
+
+        let mut http = reqwest::Client::builder();
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        for proxy in self.proxies {
+            http = http.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(user_agent);
+        }
+        #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+        {
+            for certificate in self.root_certificates {
+                http = http.add_root_certificate(certificate);
+            }
+            if let Some(identity) = self.identity {
+                http = http.identity(identity);
+            }
+        }
+
+        let client = reqwest_middleware::ClientBuilder::new(http.build()?)
+            // Trace HTTP requests. See the tracing crate to make use of these traces.
+            .with(reqwest_tracing::TracingMiddleware::default())
+            // Retry failed requests.
+            .with(crate::retry::Retry::new(self.retry_policy))
+            // Wait out the rate limits of the API, before the retries see them.
+            .with(rate_limiter.clone())
+            .build();
+
+        Ok((client, rate_limiter))
+    }
+}
+
+impl Client {
+    /// Configure the client before creating it.
+    pub fn builder() -> ClientBuilder {
+        Default::default()
+    }
+}
+
 use std::{
     convert::TryInto,
     env,
@@ -2224,8 +2633,16 @@ pub struct Client {
     client_secret: String,
     redirect_uri: String,
 
+    jwt_grant: Option<JwtGrant>,
+    environment: Environment,
+    account_id: String,
     auto_refresh: bool,
+    refresh_on_unauthorized: bool,
+    // The last refresh, which the others wait for while it is in flight.
+    refreshing: Arc<tokio::sync::Mutex<Option<AccessToken>>>,
+    token_store: Arc<dyn crate::token_store::TokenStore>,
     client: reqwest_middleware::ClientWithMiddleware,
+    rate_limiter: crate::rate_limiter::RateLimiter,
 }
 
 use schemars::JsonSchema;
@@ -2266,6 +2683,141 @@ pub struct AccessToken {
     pub scope: String,
 }
 
+/// The DocuSign environment whose account server issues the access tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    /// `account.docusign.com`
+    Production,
+    /// `account-d.docusign.com`, for developer accounts.
+    Demo,
+}
+
+impl Environment {
+    /// Return the host of the account server of the environment.
+    pub fn account_server(&self) -> &'static str {
+        match self {
+            Environment::Production => "account.docusign.com",
+            Environment::Demo => "account-d.docusign.com",
+        }
+    }
+}
+
+/// The settings to authenticate with the JWT Grant, where the integration signs
+/// an assertion with its private key to act on behalf of a user that consented
+/// to the `impersonation` scope.
+#[derive(Clone)]
+pub struct JwtGrant {
+    /// The ID of the user to impersonate.
+    pub user_id: String,
+    /// The PEM encoded RSA private key of the integration.
+    pub private_key: String,
+    /// The scopes to request, ie. `signature` and `impersonation`.
+    pub scopes: Vec<String>,
+}
+
+impl std::fmt::Debug for JwtGrant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtGrant")
+            .field("user_id", &self.user_id)
+            .field("scopes", &self.scopes)
+            .finish()
+    }
+}
+
+#[derive(Serialize)]
+struct JwtGrantClaims {
+    iss: String,
+    sub: String,
+    aud: String,
+    iat: i64,
+    exp: i64,
+    scope: String,
+}
+
+/// The user an access token belongs to and the accounts they can access, as
+/// returned by `/oauth/userinfo`.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct UserInfo {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sub: String,
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accounts: Vec<UserInfoAccount>,
+}
+
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct UserInfoAccount {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub account_id: String,
+
+    #[serde(default)]
+    pub is_default: bool,
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub account_name: String,
+
+    /// The base URI of the account, ie. `https://demo.docusign.net`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub base_uri: String,
+}
+
+/// The error code of the API for an invalid access token, as the JSON pointer of where
+/// it is in the body of a `401 Unauthorized` and its value.
+const INVALID_TOKEN_CODE: Option<(&str, &str)> =
+    Some(("/errorCode", "AUTHORIZATION_INVALID_TOKEN"));
+
+/// Whether the `WWW-Authenticate` header of a `401 Unauthorized` says the access token
+/// is invalid, or expired.
+fn is_invalid_token(headers: &reqwest::header::HeaderMap) -> bool {
+    headers
+        .get_all(http::header::WWW_AUTHENTICATE)
+        .iter()
+        .filter_map(|h| h.to_str().ok())
+        .any(|h| h.contains("invalid_token"))
+}
+
+fn has_error_code(body: &[u8], (pointer, code): (&str, &str)) -> bool {
+    match serde_json::from_slice::<serde_json::Value>(body)
+        .ok()
+        .as_ref()
+        .and_then(|v| v.pointer(pointer))
+    {
+        Some(serde_json::Value::String(s)) => s == code,
+        Some(serde_json::Value::Number(n)) => n.to_string() == code,
+        _ => false,
+    }
+}
+
 /// Time in seconds before the access token expiration point that a refresh should
 /// be performed. This value is subtracted from the `expires_in` value returned by
 /// the provider prior to storing
@@ -2278,6 +2830,75 @@ struct InnerToken {
     expires_at: Option<Instant>,
 }
 
+impl From<&InnerToken> for crate::token_store::StoredToken {
+    fn from(t: &InnerToken) -> Self {
+        crate::token_store::StoredToken {
+            access_token: t.access_token.clone(),
+            refresh_token: t.refresh_token.clone(),
+            expires_at: t.expires_at.map(|i| {
+                let left = i.saturating_duration_since(Instant::now());
+                chrono::Utc::now()
+                    + chrono::Duration::from_std(left).unwrap_or_else(|_| chrono::Duration::zero())
+            }),
+        }
+    }
+}
+
+impl From<crate::token_store::StoredToken> for InnerToken {
+    fn from(t: crate::token_store::StoredToken) -> Self {
+        InnerToken {
+            access_token: t.access_token,
+            refresh_token: t.refresh_token,
+            expires_at: t
+                .expires_at
+                .map(|e| Instant::now() + (e - chrono::Utc::now()).to_std().unwrap_or_default()),
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Create the client, see `Client::new`.
+    pub fn build<I, K, R, T, Q>(
+        self,
+        client_id: I,
+        client_secret: K,
+        redirect_uri: R,
+        token: T,
+        refresh_token: Q,
+    ) -> Result<Client>
+    where
+        I: ToString,
+        K: ToString,
+        R: ToString,
+        T: ToString,
+        Q: ToString,
+    {
+        let (client, rate_limiter) = self.http()?;
+
+        Ok(Client {
+            host: DEFAULT_HOST.to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            token: Arc::new(RwLock::new(InnerToken {
+                access_token: token.to_string(),
+                refresh_token: refresh_token.to_string(),
+                expires_at: None,
+            })),
+
+            jwt_grant: None,
+            environment: Environment::Production,
+            account_id: String::new(),
+            auto_refresh: false,
+            refresh_on_unauthorized: false,
+            refreshing: Default::default(),
+            token_store: Arc::new(crate::token_store::MemoryTokenStore::new()),
+            client,
+            rate_limiter,
+        })
+    }
+}
+
 impl Client {
     /// Create a new Client struct. It takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -2296,37 +2917,8 @@ impl Client {
         T: ToString,
         Q: ToString,
     {
-        // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest::Client::builder().build();
-        match client {
-            Ok(c) => {
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
-                    .build();
-
-                Client {
-                    host: DEFAULT_HOST.to_string(),
-                    client_id: client_id.to_string(),
-                    client_secret: client_secret.to_string(),
-                    redirect_uri: redirect_uri.to_string(),
-                    token: Arc::new(RwLock::new(InnerToken {
-                        access_token: token.to_string(),
-                        refresh_token: refresh_token.to_string(),
-                        expires_at: None,
-                    })),
-
-                    auto_refresh: false,
-                    client,
-                }
-            }
+        match Self::builder().build(client_id, client_secret, redirect_uri, token, refresh_token) {
+            Ok(c) => c,
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
         }
     }
@@ -2337,6 +2929,14 @@ impl Client {
         self
     }
 
+    /// Enables or disables refreshing the access token when a request is rejected with
+    /// `401 Unauthorized` because of it, like when its expiration is unknown, and sending
+    /// the request again with the new one. Requests with a streamed body aren't.
+    pub fn set_refresh_on_unauthorized(&mut self, enabled: bool) -> &mut Self {
+        self.refresh_on_unauthorized = enabled;
+        self
+    }
+
     /// Sets a specific `Instant` at which the access token should be considered expired.
     /// The expiration value will only be used when automatic access token refreshing is
     /// also enabled. `None` may be passed in if the expiration is unknown. In this case
@@ -2401,6 +3001,28 @@ impl Client {
         c
     }
 
+    /// Keep the tokens of the client in a store. The tokens saved there, if any, replace
+    /// those the client was created with, and new tokens are saved to it.
+    pub async fn with_token_store<S>(mut self, store: S) -> Result<Self>
+    where
+        S: crate::token_store::TokenStore + 'static,
+    {
+        if let Some(t) = store.load().await? {
+            self.token = Arc::new(RwLock::new(t.into()));
+        }
+        self.token_store = Arc::new(store);
+        Ok(self)
+    }
+
+    /*
+     * Save the tokens of the client to its store.
+     */
+    async fn save_token(&self) -> Result<crate::token_store::StoredToken> {
+        let token = crate::token_store::StoredToken::from(&*self.token.read().await);
+        self.token_store.save(&token).await?;
+        Ok(token)
+    }
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -2428,7 +3050,10 @@ impl Client {
 
         let url = format!(
             "{}?client_id={}&response_type=code&redirect_uri={}&state={}",
-            USER_CONSENT_ENDPOINT, self.client_id, self.redirect_uri, state
+            self.user_consent_endpoint(),
+            self.client_id,
+            self.redirect_uri,
+            state
         );
 
         if scopes.is_empty() {
@@ -2440,13 +3065,45 @@ impl Client {
     }
 
     /// Refresh an access token from a refresh token. Client must have a refresh token
-    /// for this to work.
+    /// for this to work. The clones of a client refresh once when they do at the same
+    /// time, those that wait for another refresh get its token.
     pub async fn refresh_access_token(&self) -> Result<AccessToken> {
+        let stale = self.token.read().await.access_token.clone();
+        self.refresh_access_token_once(&stale).await
+    }
+
+    /*
+     * Refresh the `stale` access token, unless another refresh replaced it while this one
+     * waited for it.
+     */
+    async fn refresh_access_token_once(&self, stale: &str) -> Result<AccessToken> {
+        let mut last = self.refreshing.lock().await;
+        if let Some(t) = last.as_ref() {
+            let current = &self.token.read().await.access_token;
+            if current != stale && *current == t.access_token {
+                return Ok(t.clone());
+            }
+        }
+
+        let t = self.request_refreshed_access_token().await?;
+        *last = Some(t.clone());
+        Ok(t)
+    }
+
+    async fn request_refreshed_access_token(&self) -> Result<AccessToken> {
+        // The JWT Grant doesn't return refresh tokens, we sign a new assertion instead.
+        if self.jwt_grant.is_some() {
+            let t = self.request_jwt_access_token().await?;
+            let token = crate::token_store::StoredToken::from(&*self.token.read().await);
+            self.token_store.on_refresh(&token).await;
+            return Ok(t);
+        }
+
         let response = {
             let refresh_token = &self.token.read().await.refresh_token;
 
             if refresh_token.is_empty() {
-                anyhow!("refresh token cannot be empty");
+                return Err(Error::Auth("refresh token cannot be empty".to_string()));
             }
 
             let mut headers = reqwest::header::HeaderMap::new();
@@ -2464,7 +3121,7 @@ impl Client {
             ];
             let client = reqwest::Client::new();
             client
-                .post(TOKEN_ENDPOINT)
+                .post(self.token_endpoint())
                 .headers(headers)
                 .form(&params)
                 .basic_auth(&self.client_id, Some(&self.client_secret))
@@ -2472,10 +3129,21 @@ impl Client {
                 .await?
         };
 
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await?;
+            return Err(Error::Auth(format!("code: {}, error: {:?}", status, body)));
+        }
+
         // Unwrap the response.
         let t: AccessToken = response.json().await?;
 
-        let refresh_token = self.token.read().await.refresh_token.clone();
+        // Providers that rotate refresh tokens send a new one, the old one no longer works.
+        let refresh_token = if t.refresh_token.is_empty() {
+            self.token.read().await.refresh_token.clone()
+        } else {
+            t.refresh_token.clone()
+        };
 
         *self.token.write().await = InnerToken {
             access_token: t.access_token.clone(),
@@ -2483,6 +3151,9 @@ impl Client {
             expires_at: Self::compute_expires_at(t.expires_in),
         };
 
+        let token = self.save_token().await?;
+        self.token_store.on_refresh(&token).await;
+
         Ok(t)
     }
 
@@ -2505,13 +3176,19 @@ impl Client {
         ];
         let client = reqwest::Client::new();
         let resp = client
-            .post(TOKEN_ENDPOINT)
+            .post(self.token_endpoint())
             .headers(headers)
             .form(&params)
             .basic_auth(&self.client_id, Some(&self.client_secret))
             .send()
             .await?;
 
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await?;
+            return Err(Error::Auth(format!("code: {}, error: {:?}", status, body)));
+        }
+
         // Unwrap the response.
         let t: AccessToken = resp.json().await?;
 
@@ -2521,9 +3198,187 @@ impl Client {
             expires_at: Self::compute_expires_at(t.expires_in),
         };
 
+        self.save_token().await?;
+
+        self.configure_from_user_info().await?;
+
+        Ok(t)
+    }
+
+    /// Create a new Client struct that authenticates with the JWT Grant. Call
+    /// `get_jwt_access_token` to get a token and configure the account of the user.
+    pub fn for_jwt_grant<I>(client_id: I, jwt_grant: JwtGrant, environment: Environment) -> Self
+    where
+        I: ToString,
+    {
+        let mut c = Client::new(client_id, "", "", "", "");
+        c.jwt_grant = Some(jwt_grant);
+        c.environment = environment;
+        c
+    }
+
+    /// Create a new Client struct that authenticates with the JWT Grant from
+    /// environment variables. The integration is read from `DOCUSIGN_CLIENT_ID`,
+    /// the user from `DOCUSIGN_USER_ID`, the PEM encoded key from
+    /// `DOCUSIGN_PRIVATE_KEY`, the space separated scopes from `DOCUSIGN_SCOPES`
+    /// (default `signature impersonation`) and the environment from
+    /// `DOCUSIGN_ENVIRONMENT`, either `production` (default) or `demo`.
+    pub fn jwt_grant_from_env() -> Self {
+        let client_id = env::var("DOCUSIGN_CLIENT_ID").expect("must set DOCUSIGN_CLIENT_ID");
+        let user_id = env::var("DOCUSIGN_USER_ID").expect("must set DOCUSIGN_USER_ID");
+        let private_key = env::var("DOCUSIGN_PRIVATE_KEY").expect("must set DOCUSIGN_PRIVATE_KEY");
+        let scopes =
+            env::var("DOCUSIGN_SCOPES").unwrap_or_else(|_| "signature impersonation".to_string());
+        let environment = match env::var("DOCUSIGN_ENVIRONMENT")
+            .unwrap_or_default()
+            .as_str()
+        {
+            "demo" => Environment::Demo,
+            _ => Environment::Production,
+        };
+
+        Client::for_jwt_grant(
+            client_id,
+            JwtGrant {
+                user_id,
+                private_key,
+                scopes: scopes.split_whitespace().map(|s| s.to_string()).collect(),
+            },
+            environment,
+        )
+    }
+
+    /// Use the account server of a different environment.
+    pub fn with_environment(&self, environment: Environment) -> Self {
+        let mut c = self.clone();
+        c.environment = environment;
+        c
+    }
+
+    /// Return the ID of the account the client was configured for by `get_access_token`,
+    /// `get_jwt_access_token` or `configure_from_user_info`.
+    pub fn account_id(&self) -> &str {
+        &self.account_id
+    }
+
+    fn token_endpoint(&self) -> String {
+        TOKEN_ENDPOINT.replace("account.docusign.com", self.environment.account_server())
+    }
+
+    fn user_consent_endpoint(&self) -> String {
+        USER_CONSENT_ENDPOINT.replace("account.docusign.com", self.environment.account_server())
+    }
+
+    /// Get an access token with the JWT Grant and configure the client for the
+    /// default account of the impersonated user.
+    pub async fn get_jwt_access_token(&mut self) -> Result<AccessToken> {
+        let t = self.request_jwt_access_token().await?;
+        self.configure_from_user_info().await?;
+
+        Ok(t)
+    }
+
+    async fn request_jwt_access_token(&self) -> Result<AccessToken> {
+        let jwt_grant = self.jwt_grant.as_ref().ok_or_else(|| {
+            Error::Auth("the client is not configured for the JWT Grant".to_string())
+        })?;
+
+        let now = chrono::Utc::now().timestamp();
+        let claims = JwtGrantClaims {
+            iss: self.client_id.clone(),
+            sub: jwt_grant.user_id.clone(),
+            aud: self.environment.account_server().to_string(),
+            iat: now,
+            // DocuSign rejects assertions that are valid for more than an hour.
+            exp: now + 3600,
+            scope: jwt_grant.scopes.join(" "),
+        };
+        let key = jsonwebtoken::EncodingKey::from_rsa_pem(jwt_grant.private_key.as_bytes())
+            .map_err(|e| Error::Auth(format!("invalid private key: {}", e)))?;
+        let assertion = jsonwebtoken::encode(
+            &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256),
+            &claims,
+            &key,
+        )
+        .map_err(|e| Error::Auth(format!("signing the assertion failed: {}", e)))?;
+
+        let params = [
+            ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+            ("assertion", &assertion),
+        ];
+        let client = reqwest::Client::new();
+        let resp = client
+            .post(self.token_endpoint())
+            .header(
+                reqwest::header::ACCEPT,
+                reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .form(&params)
+            .send()
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await?;
+            return Err(Error::Auth(format!("code: {}, error: {:?}", status, body)));
+        }
+
+        // Unwrap the response.
+        let t: AccessToken = resp.json().await?;
+
+        *self.token.write().await = InnerToken {
+            access_token: t.access_token.clone(),
+            refresh_token: String::new(),
+            expires_at: Self::compute_expires_at(t.expires_in),
+        };
+
+        self.save_token().await?;
+
         Ok(t)
     }
 
+    /// Get the user the access token belongs to and the accounts they can access.
+    pub async fn user_info(&self) -> Result<UserInfo> {
+        let auth = format!("Bearer {}", self.token.read().await.access_token);
+
+        let client = reqwest::Client::new();
+        let resp = client
+            .get(format!(
+                "https://{}/oauth/userinfo",
+                self.environment.account_server()
+            ))
+            .header(reqwest::header::ACCEPT, "application/json")
+            .header(reqwest::header::AUTHORIZATION, auth)
+            .send()
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await?;
+            return Err(Error::Auth(format!("code: {}, error: {:?}", status, body)));
+        }
+
+        Ok(resp.json().await?)
+    }
+
+    /// Configure the client for the default account of the user the access token
+    /// belongs to: requests are sent to the base URI of the account and
+    /// `account_id` returns its ID.
+    pub async fn configure_from_user_info(&mut self) -> Result<UserInfo> {
+        let user_info = self.user_info().await?;
+
+        let account = user_info
+            .accounts
+            .iter()
+            .find(|a| a.is_default)
+            .or_else(|| user_info.accounts.first())
+            .ok_or_else(|| Error::Auth(format!("user {} has no accounts", user_info.sub)))?;
+        self.host = format!("{}/restapi", account.base_uri.trim_end_matches('/'));
+        self.account_id = account.account_id.clone();
+
+        Ok(user_info)
+    }
+
     async fn url_and_auth(&self, uri: &str) -> Result<(reqwest::Url, Option<String>)> {
         let parsed_url = uri.parse::<reqwest::Url>();
 
@@ -2536,6 +3391,8 @@ impl Client {
         method: &reqwest::Method,
         uri: &str,
         body: Option<reqwest::Body>,
+        accept: &str,
+        content_type: &str,
     ) -> Result<reqwest::Request> {
         let u = if uri.starts_with("https://") {
             uri.to_string()
@@ -2551,11 +3408,11 @@ impl Client {
         // Set the default headers.
         req = req.header(
             reqwest::header::ACCEPT,
-            reqwest::header::HeaderValue::from_static("application/json"),
+            reqwest::header::HeaderValue::from_str(accept)?,
         );
         req = req.header(
             reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
+            reqwest::header::HeaderValue::from_str(content_type)?,
         );
 
         if let Some(auth_str) = auth {
@@ -2579,6 +3436,18 @@ impl Client {
         method: reqwest::Method,
         uri: &str,
         body: Option<reqwest::Body>,
+    ) -> Result<reqwest::Response> {
+        self.request_raw_with_media_types(method, uri, body, "application/json", "application/json")
+            .await
+    }
+
+    async fn request_raw_with_media_types(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+        accept: &str,
+        content_type: &str,
     ) -> Result<reqwest::Response> {
         if self.auto_refresh {
             let expired = self.is_expired().await;
@@ -2587,7 +3456,8 @@ impl Client {
                 // We have a known expired token, we know we need to perform a refresh prior to
                 // attempting to make a request
                 Some(true) => {
-                    self.refresh_access_token().await?;
+                    let stale = self.token.read().await.access_token.clone();
+                    self.refresh_access_token_once(&stale).await?;
                 }
 
                 // We have a (theoretically) known good token available. We make an optimistic
@@ -2607,10 +3477,61 @@ impl Client {
             }
         }
 
-        let req = self.make_request(&method, uri, body).await?;
+        let stale = self.token.read().await.access_token.clone();
+        let req = self
+            .make_request(&method, uri, body, accept, content_type)
+            .await?;
+        let replay = if self.refresh_on_unauthorized {
+            req.try_clone()
+        } else {
+            None
+        };
         let resp = self.client.execute(req).await?;
 
-        Ok(resp)
+        let mut replay = match replay {
+            Some(replay) if resp.status() == http::StatusCode::UNAUTHORIZED => replay,
+            _ => return Ok(resp),
+        };
+
+        if !is_invalid_token(resp.headers()) {
+            // The API may only say so in the body, which we have to read to find out.
+            let code = match INVALID_TOKEN_CODE {
+                Some(code) => code,
+                None => return Ok(resp),
+            };
+
+            use reqwest::ResponseBuilderExt;
+            let mut builder = http::Response::builder()
+                .status(resp.status())
+                .version(resp.version())
+                .url(resp.url().clone());
+            if let Some(headers) = builder.headers_mut() {
+                *headers = resp.headers().clone();
+            }
+            let body = resp.bytes().await?;
+            if !has_error_code(&body, code) {
+                // Hand on the response as it was.
+                return builder
+                    .body(body)
+                    .map(reqwest::Response::from)
+                    .map_err(|e| Error::InvalidRequest(e.to_string()));
+            }
+        }
+
+        self.refresh_access_token_once(&stale).await?;
+        let (_, auth) = self.url_and_auth(replay.url().as_str()).await?;
+        if let Some(auth_str) = auth {
+            replay.headers_mut().insert(
+                http::header::AUTHORIZATION,
+                reqwest::header::HeaderValue::from_str(&auth_str)?,
+            );
+        }
+        Ok(self.client.execute(replay).await?)
+    }
+
+    /// The status of the rate limits of the API, as of the last responses.
+    pub fn rate_limit_status(&self) -> Vec<crate::rate_limiter::RateLimitStatus> {
+        self.rate_limiter.status()
     }
 
     async fn request<Out>(
@@ -2625,6 +3546,7 @@ impl Client {
         let response = self.request_raw(method, uri, body).await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -2633,26 +3555,58 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn request_form<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        form: String,
+    ) -> Result<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let response = self
+            .request_raw_with_media_types(
+                method,
+                uri,
+                Some(form.into()),
+                "application/json",
+                "application/x-www-form-urlencoded",
+            )
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
 
-            Err(error)
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            log::debug!(
+                "response payload {}",
+                String::from_utf8_lossy(&response_body)
+            );
+            if status == http::StatusCode::NO_CONTENT
+                || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
+            {
+                crate::error::decode(b"null")
+            } else {
+                crate::error::decode::<Out>(&response_body)
+            }
+        } else {
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -2668,6 +3622,7 @@ impl Client {
         let response = self.request_raw(method, uri, body).await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -2685,22 +3640,13 @@ impl Client {
             let parsed_response = if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
+                crate::error::decode::<Out>(&response_body)
             };
-            parsed_response.map(|out| (link, out)).map_err(Error::from)
+            parsed_response.map(|out| (link, out))
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -2737,6 +3683,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -2745,31 +3692,19 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {
                 // Parse the output as a string.
-                serde_json::from_value(serde_json::json!(&String::from_utf8(
-                    response_body.to_vec()
-                )?))
+                serde_json::from_value(serde_json::json!(String::from_utf8_lossy(&response_body)))
+                    .map_err(Error::from)
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -2808,6 +3743,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -2816,31 +3752,19 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {
                 // Parse the output as a string.
-                serde_json::from_value(serde_json::json!(&String::from_utf8(
-                    response_body.to_vec()
-                )?))
+                serde_json::from_value(serde_json::json!(String::from_utf8_lossy(&response_body)))
+                    .map_err(Error::from)
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -2900,6 +3824,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -2908,26 +3833,82 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    #[allow(dead_code)]
+    async fn request_download(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::Download> {
+        let response = self
+            .request_raw_with_media_types(method, uri, body, "*/*", "application/json")
+            .await?;
 
-            Err(error)
+        let status = response.status();
+        let headers = response.headers().clone();
+        let content_type = response
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|c| c.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            Ok(crate::Download {
+                content_type,
+                body: response_body,
+            })
+        } else {
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    #[allow(dead_code)]
+    async fn request_download_stream(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::DownloadStream> {
+        let response = self
+            .request_raw_with_media_types(method, uri, body, "*/*", "application/json")
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        if status.is_success() {
+            let content_type = response
+                .headers()
+                .get(http::header::CONTENT_TYPE)
+                .and_then(|c| c.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+
+            Ok(crate::DownloadStream {
+                content_type,
+                body: Box::pin(futures::TryStreamExt::map_err(
+                    response.bytes_stream(),
+                    Error::from,
+                )),
+            })
+        } else {
+            let response_body = response.bytes().await?;
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -3053,6 +4034,8 @@ impl Client {
     ///The DocuSign Account Custom Branding feature enables you to set the colors, logo, and text that recipients see at the account level. The settings associated with a brand are applied to all of the envelopes that use the brand. You can create multiple brand profiles for different corporate brands or internal departments.
     ///
     ///**Note**: To use this resource, branding for either signing or sending must be enabled for the account (either `canSelfBrandSend`, `canSelfBrandSign`, or both of these account settings must be set to **true**).
+    #[cfg(feature = "account_brands")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "account_brands")))]
     pub fn account_brands(&self) -> account_brands::AccountBrands {
         account_brands::AccountBrands::new(self.clone())
     }
@@ -3069,6 +4052,8 @@ impl Client {
     ///2. Provide the `esignAgreementText` and `esignText` in the language associated with the `langCode`.
     ///
     ///For more information, see [Legal Disclosure](https://support.docusign.com/en/guides/ndse-admin-guide-legal-disclosure).
+    #[cfg(feature = "account_consumer_disclosures")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "account_consumer_disclosures")))]
     pub fn account_consumer_disclosures(
         &self,
     ) -> account_consumer_disclosures::AccountConsumerDisclosures {
@@ -3102,6 +4087,8 @@ impl Client {
     ///
     ///- `text`: Enables the sender to enter the value for the field.
     ///- `list`: Enables the sender to select the value of the field from a predetermined list.
+    #[cfg(feature = "account_custom_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "account_custom_fields")))]
     pub fn account_custom_fields(&self) -> account_custom_fields::AccountCustomFields {
         account_custom_fields::AccountCustomFields::new(self.clone())
     }
@@ -3116,6 +4103,8 @@ impl Client {
     ///- `list`: Enables the sender to select the value of the field from a predetermined list.
     ///
     ///You may assign up to three envelope custom fields to an envelope. This limit does not include account (document) custom fields.
+    #[cfg(feature = "envelope_custom_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_custom_fields")))]
     pub fn envelope_custom_fields(&self) -> envelope_custom_fields::EnvelopeCustomFields {
         envelope_custom_fields::EnvelopeCustomFields::new(self.clone())
     }
@@ -3125,6 +4114,8 @@ impl Client {
     ///You can create custom versions of standard fields that combine of field properties, such as font type or size, or a validation setting.
     ///
     ///Note: Some advanced features and options are supported only in certain DocuSign plans. Your account plan might not support some options discussed in this help topic. For more information about which options are available for your account, check your account plan or contact your Account Manager.
+    #[cfg(feature = "envelope_document_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_document_fields")))]
     pub fn envelope_document_fields(&self) -> envelope_document_fields::EnvelopeDocumentFields {
         envelope_document_fields::EnvelopeDocumentFields::new(self.clone())
     }
@@ -3143,6 +4134,8 @@ impl Client {
     ///3. Delete the envelope lock and save the changes. If the envelope is based on a template that has a password, you must supply the template password to save the changes.
     ///
     ///**Note**: To use envelope locks, the user must have envelope locking capability enabled.
+    #[cfg(feature = "envelope_locks")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_locks")))]
     pub fn envelope_locks(&self) -> envelope_locks::EnvelopeLocks {
         envelope_locks::EnvelopeLocks::new(self.clone())
     }
@@ -3413,6 +4406,8 @@ impl Client {
     ///
     ///[ListLang]: https://developers.docusign.com/docs/esign-rest-api/reference/Accounts/Accounts/listSupportedLanguages/
     ///.
+    #[cfg(feature = "envelope_recipients")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_recipients")))]
     pub fn envelope_recipients(&self) -> envelope_recipients::EnvelopeRecipients {
         envelope_recipients::EnvelopeRecipients::new(self.clone())
     }
@@ -4046,16 +5041,22 @@ impl Client {
     ///  of the `Name` Text tabs,
     ///  the `bold` property must be set to the same value for both tabs.
     ///.
+    #[cfg(feature = "envelope_recipient_tabs")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_recipient_tabs")))]
     pub fn envelope_recipient_tabs(&self) -> envelope_recipient_tabs::EnvelopeRecipientTabs {
         envelope_recipient_tabs::EnvelopeRecipientTabs::new(self.clone())
     }
 
     /// The EnvelopeDocumentTabs resource provides methods that enable you to manage tabs in envelopes. For a complete list of options, see the following Properties section.
+    #[cfg(feature = "envelope_document_tabs")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_document_tabs")))]
     pub fn envelope_document_tabs(&self) -> envelope_document_tabs::EnvelopeDocumentTabs {
         envelope_document_tabs::EnvelopeDocumentTabs::new(self.clone())
     }
 
     /// The EnvelopeDocumentTabs resource provides methods that enable you to manage tabs in a template.
+    #[cfg(feature = "template_document_tabs")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template_document_tabs")))]
     pub fn template_document_tabs(&self) -> template_document_tabs::TemplateDocumentTabs {
         template_document_tabs::TemplateDocumentTabs::new(self.clone())
     }
@@ -4071,16 +5072,22 @@ impl Client {
     ///-  Recipient View: The view the recipient sees in the DocuSign UI.
     ///-  Shared Recipient View: The view a user sees of a shared envelope in the DocuSign UI.
     ///-  Sender View: The sending view of the DocuSign UI.
+    #[cfg(feature = "envelope_views")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_views")))]
     pub fn envelope_views(&self) -> envelope_views::EnvelopeViews {
         envelope_views::EnvelopeViews::new(self.clone())
     }
 
     /// .
+    #[cfg(feature = "account_seal_providers")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "account_seal_providers")))]
     pub fn account_seal_providers(&self) -> account_seal_providers::AccountSealProviders {
         account_seal_providers::AccountSealProviders::new(self.clone())
     }
 
     /// The Accounts resource provides methods that allow you to create, delete, and manage your accounts.
+    #[cfg(feature = "accounts")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "accounts")))]
     pub fn accounts(&self) -> accounts::Accounts {
         accounts::Accounts::new(self.clone())
     }
@@ -4110,6 +5117,8 @@ impl Client {
     ///- **Wet Signing**: Wet-signed documents are added as new documents to envelopes. This behavior results in the uploaded or faxed, physically signed document being added as a new document to the envelope. This new document gets only a platform seal.
     ///
     ///For more information, see [Standards Based Signatures](https://developers.docusign.com/esign-rest-api/guides/standards-based-signatures).
+    #[cfg(feature = "account_signature_providers")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "account_signature_providers")))]
     pub fn account_signature_providers(
         &self,
     ) -> account_signature_providers::AccountSignatureProviders {
@@ -4117,11 +5126,15 @@ impl Client {
     }
 
     /// The Billing resource provides methods that allow you to manage the billing plans,associated with an account.
+    #[cfg(feature = "billing_plans")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "billing_plans")))]
     pub fn billing_plans(&self) -> billing_plans::BillingPlans {
         billing_plans::BillingPlans::new(self.clone())
     }
 
     /// The Invoices resource provides methods that allow you to manage the invoices for an account.
+    #[cfg(feature = "invoices")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "invoices")))]
     pub fn invoices(&self) -> invoices::Invoices {
         invoices::Invoices::new(self.clone())
     }
@@ -4129,11 +5142,15 @@ impl Client {
     /// The Payments resource provides methods that allow you to manage payments for an account.
     ///
     ///These calls can only be used by users with account administrator privileges.
+    #[cfg(feature = "payments")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "payments")))]
     pub fn payments(&self) -> payments::Payments {
         payments::Payments::new(self.clone())
     }
 
     /// The CloudStorage resource provides methods that allow you to list files stored on your cloud storage provider.
+    #[cfg(feature = "cloud_storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cloud_storage")))]
     pub fn cloud_storage(&self) -> cloud_storage::CloudStorage {
         cloud_storage::CloudStorage::new(self.clone())
     }
@@ -4147,6 +5164,8 @@ impl Client {
     ///* OneDrive
     ///
     ///To use cloud storage files, you must first give DocuSign access to your cloud storage provider. You can disconnect authorized a cloud storage provider at any time.
+    #[cfg(feature = "cloud_storage_providers")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cloud_storage_providers")))]
     pub fn cloud_storage_providers(&self) -> cloud_storage_providers::CloudStorageProviders {
         cloud_storage_providers::CloudStorageProviders::new(self.clone())
     }
@@ -4168,11 +5187,15 @@ impl Client {
     ///When you enable Send Individual Messages (SIM) mode on a Connect configuration, DocuSign will deliver notifications for all envelope events individually. In contrast with aggregated messages, when a final recipient completes an envelope, your listener will receive a single Recipient: complete event followed by a single Envelope: complete event for the final participating party on the agreement.  If you need more granular control over event notifications, you can enable SIM mode in the Admin area of the RADmin console. For more information about SIM mode, see [Using Connect's New Send Individual Messages Feature](https://www.docusign.com/blog/dsdev-connect-send-individual-messages/).
     ///
     ///**Note**: To create an envelope-level webhook instead of using account-level webhooks, use the Envelopes::Create method and add an `eventNotification` object to an envelope object.
+    #[cfg(feature = "connect_configurations")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "connect_configurations")))]
     pub fn connect_configurations(&self) -> connect_configurations::ConnectConfigurations {
         connect_configurations::ConnectConfigurations::new(self.clone())
     }
 
     /// The ConnectEvents resource provides methods that allow you to read, delete, and republish the connect logs associated with an envelope.
+    #[cfg(feature = "connect_events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "connect_events")))]
     pub fn connect_events(&self) -> connect_events::ConnectEvents {
         connect_events::ConnectEvents::new(self.clone())
     }
@@ -4182,6 +5205,8 @@ impl Client {
     ///You can create a tab with pre-defined properties, such as a text tab with a certain font type and validation pattern. Users can access the custom tabs when sending documents through the DocuSign web application.
     ///
     ///Custom tabs can be created based on the  approve, checkbox, company, date, date signed, decline, email, email address, envelope ID, first name, formula, full name, initial here, last name, list, note, number, radio, sign here, signer attachment, SSN, text, title, and zip tabs.
+    #[cfg(feature = "custom_tabs")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "custom_tabs")))]
     pub fn custom_tabs(&self) -> custom_tabs::CustomTabs {
         custom_tabs::CustomTabs::new(self.clone())
     }
@@ -4189,16 +5214,22 @@ impl Client {
     /// The RequestLogs resource provide methods that allow you to retrieve and delete the API request log files.
     ///
     ///The log files contain the API requests associated with your integration. They can aid you in troubleshooting specific issues within an integration, or if DocuSign Support requests an API trace log.
+    #[cfg(feature = "request_logs")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "request_logs")))]
     pub fn request_logs(&self) -> request_logs::RequestLogs {
         request_logs::RequestLogs::new(self.clone())
     }
 
     /// The Resources resource provides a method which retrieves the base resources that are available.
+    #[cfg(feature = "resources")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "resources")))]
     pub fn resources(&self) -> resources::Resources {
         resources::Resources::new(self.clone())
     }
 
     /// The Services resource provides a method that allow you to retrieve the available service versions.
+    #[cfg(feature = "services")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "services")))]
     pub fn services(&self) -> services::Services {
         services::Services::new(self.clone())
     }
@@ -4214,6 +5245,8 @@ impl Client {
     ///**Important**: The system does not translate the ERSD for you. An account administrator must create a version of the account-level disclosure for each language that your signers use.
     ///
     ///For more information, see [Legal Disclosure](https://support.docusign.com/en/guides/ndse-admin-guide-legal-disclosure).
+    #[cfg(feature = "envelope_consumer_disclosures")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_consumer_disclosures")))]
     pub fn envelope_consumer_disclosures(
         &self,
     ) -> envelope_consumer_disclosures::EnvelopeConsumerDisclosures {
@@ -4247,6 +5280,8 @@ impl Client {
     ///[envelopescreate]: https://developers.docusign.com/docs/esign-rest-api/reference/Envelopes/Envelopes/create/
     ///
     ///.
+    #[cfg(feature = "envelope_documents")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_documents")))]
     pub fn envelope_documents(&self) -> envelope_documents::EnvelopeDocuments {
         envelope_documents::EnvelopeDocuments::new(self.clone())
     }
@@ -4256,6 +5291,8 @@ impl Client {
     ///Email override settings change the reply to email address, name, or the BCC for email archive information, for the envelope. Note that changing email settings will only affect email communications that occur after the addition was made.
     ///
     ///The BCC Email address feature is designed to provide a copy of all email communications for external archiving purposes. DocuSign recommends that envelopes sent using the BCC for Email Archive feature, including the BCC Email Override option, include additional signer authentication options. To send a copy of the envelope to a recipient who does not need to sign, use a Carbon Copies or Certified Deliveries Recipient Type.
+    #[cfg(feature = "envelope_email_settings")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_email_settings")))]
     pub fn envelope_email_settings(&self) -> envelope_email_settings::EnvelopeEmailSettings {
         envelope_email_settings::EnvelopeEmailSettings::new(self.clone())
     }
@@ -4278,21 +5315,29 @@ impl Client {
     ///If you need to delete a page from a document in an envelope, use the Envelope: deleteDocumentPage method.
     ///
     ///The resource also includes a number of methods that allow you to retrieve and set the initials and signature for certain types of recipients on the document.
+    #[cfg(feature = "envelopes")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelopes")))]
     pub fn envelopes(&self) -> envelopes::Envelopes {
         envelopes::Envelopes::new(self.clone())
     }
 
     /// The EnvelopeTemplates resource provides methods that allow you to add and delete templates on envelopes and documents.
+    #[cfg(feature = "envelope_templates")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_templates")))]
     pub fn envelope_templates(&self) -> envelope_templates::EnvelopeTemplates {
         envelope_templates::EnvelopeTemplates::new(self.clone())
     }
 
     /// The Folders resource provides methods that allow you to view contents of folders on the account and move envelopes between folders.
+    #[cfg(feature = "folders")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "folders")))]
     pub fn folders(&self) -> folders::Folders {
         folders::Folders::new(self.clone())
     }
 
     /// For the custom groups you define for your account, you can assign brands to specify the ones that group members can use. Group members can use the available brands when they send envelopes or create templates. For more information, see [Assign Brands to Groups](https://support.docusign.com/en/guides/ndse-admin-guide-assign-brands-to-groups).
+    #[cfg(feature = "group_brands")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "group_brands")))]
     pub fn group_brands(&self) -> group_brands::GroupBrands {
         group_brands::GroupBrands::new(self.clone())
     }
@@ -4300,11 +5345,15 @@ impl Client {
     /// The Groups resource provides methods that allow you to manage groups for the account.
     ///
     ///Groups can be used to help manage users by associating users with a group. A group can be associated with a Permission Profile, which sets the user permissions for users in that group without having to set the `userSettings` property for each user. You are not required to set Permission Profiles for a group, but this makes it easier to manage user permissions for a large number of users. Groups can also be used with template sharing to limit user access to templates.
+    #[cfg(feature = "groups")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "groups")))]
     pub fn groups(&self) -> groups::Groups {
         groups::Groups::new(self.clone())
     }
 
     /// The GroupUsers resource provides methods that allow you to manage the users in a group.
+    #[cfg(feature = "group_users")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "group_users")))]
     pub fn group_users(&self) -> group_users::GroupUsers {
         group_users::GroupUsers::new(self.clone())
     }
@@ -4323,11 +5372,15 @@ impl Client {
     ///The Signing Groups feature is only supported in certain DocuSign Enterprise and System Automated Premium plans. Your account might not support this option. To access this functionality, contact your Account Manager or DocuSign Support (support@docusign.com) for assistance.
     ///
     ///For more information, see [Signing Groups](https://support.docusign.com/en/guides/ndse-user-guide-signing-groups).
+    #[cfg(feature = "signing_groups")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "signing_groups")))]
     pub fn signing_groups(&self) -> signing_groups::SigningGroups {
         signing_groups::SigningGroups::new(self.clone())
     }
 
     /// The SigningGroupUsers resource provides methods that allow you to manage users in Signing Groups.
+    #[cfg(feature = "signing_group_users")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "signing_group_users")))]
     pub fn signing_group_users(&self) -> signing_group_users::SigningGroupUsers {
         signing_group_users::SigningGroupUsers::new(self.clone())
     }
@@ -4687,6 +5740,8 @@ impl Client {
     ///| signedDateTime | No | DateTime | Reserved: For DocuSign use only. |
     ///| offlineAttributes | No | &nbsp; | Reserved: For DocuSign use only.|
     ///.
+    #[cfg(feature = "template_recipients")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template_recipients")))]
     pub fn template_recipients(&self) -> template_recipients::TemplateRecipients {
         template_recipients::TemplateRecipients::new(self.clone())
     }
@@ -4696,6 +5751,8 @@ impl Client {
     ///The bulk recipient CSV (Comma Separated Value) file contains the list of recipient names and email addresses that you can add to an template to send the same document to a large number of recipients.
     ///
     ///The required and optional information that can be included the file is described in the BulkEnvelope: updateRecipients method.
+    #[cfg(feature = "template_bulk_recipients")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template_bulk_recipients")))]
     pub fn template_bulk_recipients(&self) -> template_bulk_recipients::TemplateBulkRecipients {
         template_bulk_recipients::TemplateBulkRecipients::new(self.clone())
     }
@@ -4708,6 +5765,8 @@ impl Client {
     ///
     ///- `text`: Enables the sender to enter the value for the field.
     ///- `list`: Enables the sender to select the value of the field from a predetermined list.
+    #[cfg(feature = "template_custom_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template_custom_fields")))]
     pub fn template_custom_fields(&self) -> template_custom_fields::TemplateCustomFields {
         template_custom_fields::TemplateCustomFields::new(self.clone())
     }
@@ -4717,6 +5776,8 @@ impl Client {
     ///You can create custom versions of standard fields that combine of field properties, such as font type or size, or a validation setting.
     ///
     ///Note: Some advanced features and options are supported only in certain DocuSign plans. Your account plan might not support some options discussed in this help topic. For more information about which options are available for your account, check your account plan or contact your Account Manager.
+    #[cfg(feature = "template_document_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template_document_fields")))]
     pub fn template_document_fields(&self) -> template_document_fields::TemplateDocumentFields {
         template_document_fields::TemplateDocumentFields::new(self.clone())
     }
@@ -4746,6 +5807,8 @@ impl Client {
     ///
     ///[templatescreate]: https://developers.docusign.com/docs/esign-rest-api/reference/Templates/Templates/create/
     ///.
+    #[cfg(feature = "template_documents")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template_documents")))]
     pub fn template_documents(&self) -> template_documents::TemplateDocuments {
         template_documents::TemplateDocuments::new(self.clone())
     }
@@ -4764,6 +5827,8 @@ impl Client {
     ///3. Delete the template lock and save the changes. If the template has a password, you must supply the template password to save the changes.
     ///
     ///**Note**: To use template locks, the user must have envelope locking capability enabled.
+    #[cfg(feature = "template_locks")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template_locks")))]
     pub fn template_locks(&self) -> template_locks::TemplateLocks {
         template_locks::TemplateLocks::new(self.clone())
     }
@@ -4783,6 +5848,8 @@ impl Client {
     ///If you need to delete a page from a document in a template, use the Template: deleteDocumentPage method.
     ///
     ///The resource also includes a number of methods that allow you to retrieve and set the initials and signature for certain types of recipients on the document.
+    #[cfg(feature = "templates")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "templates")))]
     pub fn templates(&self) -> templates::Templates {
         templates::Templates::new(self.clone())
     }
@@ -4957,6 +6024,8 @@ impl Client {
     ///  of the `Name` Text tabs,
     ///  the `bold` property must be set to the same value for both tabs.
     ///.
+    #[cfg(feature = "template_recipient_tabs")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template_recipient_tabs")))]
     pub fn template_recipient_tabs(&self) -> template_recipient_tabs::TemplateRecipientTabs {
         template_recipient_tabs::TemplateRecipientTabs::new(self.clone())
     }
@@ -4976,6 +6045,8 @@ impl Client {
     ///
     ///[envelopeviews]: https://developers.docusign.com/docs/esign-rest-api/reference/Envelopes/EnvelopeViews/
     ///.
+    #[cfg(feature = "template_views")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template_views")))]
     pub fn template_views(&self) -> template_views::TemplateViews {
         template_views::TemplateViews::new(self.clone())
     }
@@ -4985,11 +6056,15 @@ impl Client {
     ///Custom settings are a flexible way to store and retrieve custom user information that can be used in your own system.
     ///
     ///There is a limit on the size for all the custom user settings for a single user. All the custom user settings for a single user is limited to 4,000 characters, which includes the xml and json structure for the settings.
+    #[cfg(feature = "user_custom_settings")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "user_custom_settings")))]
     pub fn user_custom_settings(&self) -> user_custom_settings::UserCustomSettings {
         user_custom_settings::UserCustomSettings::new(self.clone())
     }
 
     /// The UserProfiles resource provides methods that allow you to manage a user's profile.
+    #[cfg(feature = "user_profiles")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "user_profiles")))]
     pub fn user_profiles(&self) -> user_profiles::UserProfiles {
         user_profiles::UserProfiles::new(self.clone())
     }
@@ -5011,11 +6086,15 @@ impl Client {
     ///- updateSettings
     ///
     ///Instead, you can retrieve and set the user's job title by using the UserProfiles:Get and UserProfiles:Update methods. For more information, see [UserProfiles](https://developers.docusign.com/docs/esign-rest-api/reference/Users/UserProfiles).
+    #[cfg(feature = "users")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "users")))]
     pub fn users(&self) -> users::Users {
         users::Users::new(self.clone())
     }
 
     /// The UserSignatures resource provides methods that allow you manage the  intials and signature images for a user.
+    #[cfg(feature = "user_signatures")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "user_signatures")))]
     pub fn user_signatures(&self) -> user_signatures::UserSignatures {
         user_signatures::UserSignatures::new(self.clone())
     }
@@ -5023,12 +6102,16 @@ impl Client {
     /// DocuSign eSignature includes a contacts list (also referred to as an address book) to help make sending envelopes even easier. When you send an envelope, the recipients' names and email addresses are automatically added to your contacts list. You can use the contacts list to quickly add recipients to the envelopes you send. The `Contacts` resource provides methods that enable you to manage your contacts.
     ///
     ///.
+    #[cfg(feature = "contacts")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "contacts")))]
     pub fn contacts(&self) -> contacts::Contacts {
         contacts::Contacts::new(self.clone())
     }
 
     /// The EnvelopeAttachments resource provides methods that allow you to manage attachments.
     ///.
+    #[cfg(feature = "envelope_attachments")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_attachments")))]
     pub fn envelope_attachments(&self) -> envelope_attachments::EnvelopeAttachments {
         envelope_attachments::EnvelopeAttachments::new(self.clone())
     }
@@ -5043,6 +6126,8 @@ impl Client {
     ///
     ///You can also create your own custom account permission profiles.
     ///.
+    #[cfg(feature = "account_permission_profiles")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "account_permission_profiles")))]
     pub fn account_permission_profiles(
         &self,
     ) -> account_permission_profiles::AccountPermissionProfiles {
@@ -5073,12 +6158,16 @@ impl Client {
     ///- `PowerForms_PowerFormId_Required`: A `powerFormId` is required.
     ///- `PowerForms_PowerFormId_Mismatch`: A `powerFormId` mismatch has occurred.
     ///.
+    #[cfg(feature = "power_forms")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "power_forms")))]
     pub fn power_forms(&self) -> power_forms::PowerForms {
         power_forms::PowerForms::new(self.clone())
     }
 
     /// The PowerFormData resource provides a method for accessing PowerForm data.
     ///.
+    #[cfg(feature = "power_form_data")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "power_form_data")))]
     pub fn power_form_data(&self) -> power_form_data::PowerFormData {
         power_form_data::PowerFormData::new(self.clone())
     }
@@ -5086,6 +6175,8 @@ impl Client {
     /// The AccountTabSettings resource provides methods that allow you to manage
     ///tab settings for an account.
     ///.
+    #[cfg(feature = "account_tab_settings")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "account_tab_settings")))]
     pub fn account_tab_settings(&self) -> account_tab_settings::AccountTabSettings {
         account_tab_settings::AccountTabSettings::new(self.clone())
     }
@@ -5093,6 +6184,8 @@ impl Client {
     /// The ENoteConfigurations resource provides methods that allow you to manage
     ///information for the eNote eOriginal integration.
     ///.
+    #[cfg(feature = "e_note_configurations")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "e_note_configurations")))]
     pub fn e_note_configurations(&self) -> e_note_configurations::ENoteConfigurations {
         e_note_configurations::ENoteConfigurations::new(self.clone())
     }
@@ -5100,6 +6193,8 @@ impl Client {
     /// The WorkspaceItems resource provides methods that enable you to manage
     ///workspace items.
     ///.
+    #[cfg(feature = "workspace_items")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "workspace_items")))]
     pub fn workspace_items(&self) -> workspace_items::WorkspaceItems {
         workspace_items::WorkspaceItems::new(self.clone())
     }
@@ -5116,6 +6211,8 @@ impl Client {
     ///- **Transactions**: A transaction is a series of workflow events related to one or more documents. These events route the documents to one or more individuals for the purposes of doing business. Each transaction has a single owner (the sender).
     ///
     ///**Note**: Documents in a template are not individually listed as files.
+    #[cfg(feature = "workspaces")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "workspaces")))]
     pub fn workspaces(&self) -> workspaces::Workspaces {
         workspaces::Workspaces::new(self.clone())
     }
@@ -5148,17 +6245,23 @@ impl Client {
     ///- The maximum total size of all of a member's unexpired, unconsumed  ChunkedUploads. The default value is 1 GB.
     ///- The amount of time that a chunked upload is active after you initialize it. The default value is 20 minutes.
     ///.
+    #[cfg(feature = "chunked_uploads")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chunked_uploads")))]
     pub fn chunked_uploads(&self) -> chunked_uploads::ChunkedUploads {
         chunked_uploads::ChunkedUploads::new(self.clone())
     }
 
     /// The EnvelopeFormData resource provides a method for downloading the data that users have entered into a form associated with an envelope.
+    #[cfg(feature = "envelope_form_data")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_form_data")))]
     pub fn envelope_form_data(&self) -> envelope_form_data::EnvelopeFormData {
         envelope_form_data::EnvelopeFormData::new(self.clone())
     }
 
     /// The Envelope Document Visibility resource provides methods for managing document visibility by recipient.
     ///.
+    #[cfg(feature = "envelope_document_visibility")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_document_visibility")))]
     pub fn envelope_document_visibility(
         &self,
     ) -> envelope_document_visibility::EnvelopeDocumentVisibility {
@@ -5167,6 +6270,8 @@ impl Client {
 
     /// The Template Document Visibility resource provides methods for managing template document visibility by recipient.
     ///.
+    #[cfg(feature = "template_document_visibility")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template_document_visibility")))]
     pub fn template_document_visibility(
         &self,
     ) -> template_document_visibility::TemplateDocumentVisibility {
@@ -5174,21 +6279,29 @@ impl Client {
     }
 
     /// The AccountPasswordRules resource provides methods that allow you to obtain and update account password rules, as well as membership and account rules.
+    #[cfg(feature = "account_password_rules")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "account_password_rules")))]
     pub fn account_password_rules(&self) -> account_password_rules::AccountPasswordRules {
         account_password_rules::AccountPasswordRules::new(self.clone())
     }
 
     /// The AccountWatermarks resource provides methods that allow you to obtain, preview and update watermark information.
+    #[cfg(feature = "account_watermarks")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "account_watermarks")))]
     pub fn account_watermarks(&self) -> account_watermarks::AccountWatermarks {
         account_watermarks::AccountWatermarks::new(self.clone())
     }
 
     /// .
+    #[cfg(feature = "payment_gateway_accounts")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "payment_gateway_accounts")))]
     pub fn payment_gateway_accounts(&self) -> payment_gateway_accounts::PaymentGatewayAccounts {
         payment_gateway_accounts::PaymentGatewayAccounts::new(self.clone())
     }
 
     /// .
+    #[cfg(feature = "notary_journals")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "notary_journals")))]
     pub fn notary_journals(&self) -> notary_journals::NotaryJournals {
         notary_journals::NotaryJournals::new(self.clone())
     }
@@ -5200,11 +6313,15 @@ impl Client {
     ///To use Identity Verification, the [DocuSign Identity Verification](https://www.docusign.com/products/identify) product must be enabled for your account.
     ///
     ///For more information, see [Can I see (a photo of) your ID? Digital verification of real-world IDs](https://www.docusign.com/blog/can-i-see-a-photo-of-your-id-digital-verification-of-real-world-ids/).
+    #[cfg(feature = "identity_verifications")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "identity_verifications")))]
     pub fn identity_verifications(&self) -> identity_verifications::IdentityVerifications {
         identity_verifications::IdentityVerifications::new(self.clone())
     }
 
     /// .
+    #[cfg(feature = "envelope_document_html_definitions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_document_html_definitions")))]
     pub fn envelope_document_html_definitions(
         &self,
     ) -> envelope_document_html_definitions::EnvelopeDocumentHtmlDefinitions {
@@ -5213,6 +6330,8 @@ impl Client {
 
     /// **Note**: Responsive Signing is disabled by default. To use this functionality, an account administrator must switch the account setting `enableResponsiveSigning` to **true**.
     ///Also note that Smart Sections (creating a signable HTML document that uses collapsible sections and rotating tables) are premium features. To request them, contact your DocuSign account manager.
+    #[cfg(feature = "document_responsive_html_preview")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "document_responsive_html_preview")))]
     pub fn document_responsive_html_preview(
         &self,
     ) -> document_responsive_html_preview::DocumentResponsiveHtmlPreview {
@@ -5220,18 +6339,27 @@ impl Client {
     }
 
     /// .
+    #[cfg(feature = "envelope_html_definitions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_html_definitions")))]
     pub fn envelope_html_definitions(&self) -> envelope_html_definitions::EnvelopeHtmlDefinitions {
         envelope_html_definitions::EnvelopeHtmlDefinitions::new(self.clone())
     }
 
     /// **Note**: Responsive Signing is disabled by default. To use this functionality, an account administrator must switch the account setting `enableResponsiveSigning` to **true**.
     ///Also note that Smart Sections (creating a signable HTML document that uses collapsible sections and rotating tables) are premium features. To request them, contact your DocuSign account manager.
+    #[cfg(feature = "responsive_html_preview")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "responsive_html_preview")))]
     pub fn responsive_html_preview(&self) -> responsive_html_preview::ResponsiveHtmlPreview {
         responsive_html_preview::ResponsiveHtmlPreview::new(self.clone())
     }
 
     /// **Note**: Responsive Signing is disabled by default. To use this functionality, an account administrator must switch the account setting `enableResponsiveSigning` to **true**.
     ///Also note that Smart Sections (creating a signable HTML document that uses collapsible sections and rotating tables) are premium features. To request them, contact your DocuSign account manager.
+    #[cfg(feature = "template_document_responsive_html_preview")]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(feature = "template_document_responsive_html_preview"))
+    )]
     pub fn template_document_responsive_html_preview(
         &self,
     ) -> template_document_responsive_html_preview::TemplateDocumentResponsiveHtmlPreview {
@@ -5242,6 +6370,8 @@ impl Client {
 
     /// **Note**: Responsive Signing is disabled by default. To use this functionality, an account administrator must switch the account setting `enableResponsiveSigning` to **true**.
     ///Also note that Smart Sections (creating a signable HTML document that uses collapsible sections and rotating tables) are premium features. To request them, contact your DocuSign account manager.
+    #[cfg(feature = "template_responsive_html_preview")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template_responsive_html_preview")))]
     pub fn template_responsive_html_preview(
         &self,
     ) -> template_responsive_html_preview::TemplateResponsiveHtmlPreview {
@@ -5249,6 +6379,8 @@ impl Client {
     }
 
     /// .
+    #[cfg(feature = "template_document_html_definitions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template_document_html_definitions")))]
     pub fn template_document_html_definitions(
         &self,
     ) -> template_document_html_definitions::TemplateDocumentHtmlDefinitions {
@@ -5256,6 +6388,8 @@ impl Client {
     }
 
     /// .
+    #[cfg(feature = "template_html_definitions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "template_html_definitions")))]
     pub fn template_html_definitions(&self) -> template_html_definitions::TemplateHtmlDefinitions {
         template_html_definitions::TemplateHtmlDefinitions::new(self.clone())
     }
@@ -5263,6 +6397,8 @@ impl Client {
     /// Envelope transfer rules enable administrators to transfer envelopes and templates from user to another. For example, you might do this when an employee leaves the company. To transfer ownership of envelopes and templates, the **Transfer Custody** feature must be enabled for your account.
     ///
     ///For more information about this functionality, see [Transfer Envelopes and Templates](https://support.docusign.com/en/guides/ndse-admin-guide-transfer-envelopes-templates).
+    #[cfg(feature = "envelope_transfer_rules")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_transfer_rules")))]
     pub fn envelope_transfer_rules(&self) -> envelope_transfer_rules::EnvelopeTransferRules {
         envelope_transfer_rules::EnvelopeTransferRules::new(self.clone())
     }
@@ -5334,6 +6470,8 @@ impl Client {
     ///
     ///For more information about using bulk send, see [Bulk Sending Envelopes](https://developers.docusign.com/docs/esign-rest-api/esign101/concepts/envelopes/bulk-send/).
     ///.
+    #[cfg(feature = "bulk_send")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bulk_send")))]
     pub fn bulk_send(&self) -> bulk_send::BulkSend {
         bulk_send::BulkSend::new(self.clone())
     }
@@ -5343,11 +6481,15 @@ impl Client {
     ///For more information, see [Email Archive Configuration](https://support.docusign.com/en/guides/ndse-admin-guide-email-archive-configuration).
     ///
     ///**Note**: This feature is only available for certain account plans and must be enabled by DocuSign.
+    #[cfg(feature = "bcc_email_archive")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bcc_email_archive")))]
     pub fn bcc_email_archive(&self) -> bcc_email_archive::BccEmailArchive {
         bcc_email_archive::BccEmailArchive::new(self.clone())
     }
 
     /// .
+    #[cfg(feature = "tabs_blob")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tabs_blob")))]
     pub fn tabs_blob(&self) -> tabs_blob::TabsBlob {
         tabs_blob::TabsBlob::new(self.clone())
     }
@@ -5357,11 +6499,15 @@ impl Client {
     ///Comments are disabled by default. To use the comments feature, an account administrator must enable comments on the account (in the `accountSettingsInformation` object, set the `enableSigningExtensionComments` property to **true**).
     ///
     ///For more information, see [Comments Settings](https://support.docusign.com/en/guides/ndse-admin-guide-comments-settings).
+    #[cfg(feature = "comments")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "comments")))]
     pub fn comments(&self) -> comments::Comments {
         comments::Comments::new(self.clone())
     }
 
     /// .
+    #[cfg(feature = "favorite_templates")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "favorite_templates")))]
     pub fn favorite_templates(&self) -> favorite_templates::FavoriteTemplates {
         favorite_templates::FavoriteTemplates::new(self.clone())
     }
@@ -5374,16 +6520,22 @@ impl Client {
     ///to see which jurisdictions are supported.
     ///
     ///.
+    #[cfg(feature = "notary")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "notary")))]
     pub fn notary(&self) -> notary::Notary {
         notary::Notary::new(self.clone())
     }
 
     /// Creating, updating, and deleting notary jurisdiction objects.
+    #[cfg(feature = "notary_jurisdiction")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "notary_jurisdiction")))]
     pub fn notary_jurisdiction(&self) -> notary_jurisdiction::NotaryJurisdiction {
         notary_jurisdiction::NotaryJurisdiction::new(self.clone())
     }
 
     /// .
+    #[cfg(feature = "envelope_workflow_definition")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "envelope_workflow_definition")))]
     pub fn envelope_workflow_definition(
         &self,
     ) -> envelope_workflow_definition::EnvelopeWorkflowDefinition {
@@ -5391,21 +6543,29 @@ impl Client {
     }
 
     /// .
+    #[cfg(feature = "reports")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "reports")))]
     pub fn reports(&self) -> reports::Reports {
         reports::Reports::new(self.clone())
     }
 
     /// .
+    #[cfg(feature = "account_signatures")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "account_signatures")))]
     pub fn account_signatures(&self) -> account_signatures::AccountSignatures {
         account_signatures::AccountSignatures::new(self.clone())
     }
 
     /// .
+    #[cfg(feature = "appliance_info")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "appliance_info")))]
     pub fn appliance_info(&self) -> appliance_info::ApplianceInfo {
         appliance_info::ApplianceInfo::new(self.clone())
     }
 
     /// .
+    #[cfg(feature = "connect_secret")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "connect_secret")))]
     pub fn connect_secret(&self) -> connect_secret::ConnectSecret {
         connect_secret::ConnectSecret::new(self.clone())
     }
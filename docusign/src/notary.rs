@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Notary {
     pub client: Client,
@@ -22,11 +20,11 @@ impl Notary {
      *
      * **Parameters:**
      *
-     * * `include_jurisdictions: &str` -- If **true**, the response will include a `jurisdiction` property that contains an array of all supported jurisdictions for the current user.
+     * * `params: &NotaryGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(&self, include_jurisdictions: &str) -> Result<crate::types::NotaryResult> {
+    pub async fn get_with(&self, params: &NotaryGetParams) -> Result<crate::types::NotaryResult> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_jurisdictions.is_empty() {
+        if let Some(include_jurisdictions) = &params.include_jurisdictions {
             query_args.push((
                 "include_jurisdictions".to_string(),
                 include_jurisdictions.to_string(),
@@ -38,6 +36,18 @@ impl Notary {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `NotaryGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(&self, include_jurisdictions: &str) -> Result<crate::types::NotaryResult> {
+        let params = NotaryGetParams {
+            include_jurisdictions: Some(include_jurisdictions.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Updates notary information for the current user.
      *
@@ -66,3 +76,47 @@ impl Notary {
             .await
     }
 }
+
+/// The query parameters of `Notary::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct NotaryGetParams {
+    /// If **true**, the response will include a `jurisdiction` property that contains an array of all supported jurisdictions for the current user.
+    pub include_jurisdictions: Option<String>,
+}
+
+impl NotaryGetParams {
+    pub fn include_jurisdictions<T: ToString>(mut self, include_jurisdictions: T) -> Self {
+        self.include_jurisdictions = Some(include_jurisdictions.to_string());
+        self
+    }
+}
+
+/// The functions of [`Notary`], for code that takes them as a trait, so that it can be
+/// tested with the `MockNotaryApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait NotaryApi: Send + Sync {
+    /// See [`Notary::get_with`].
+    async fn get_with(&self, params: &NotaryGetParams) -> Result<crate::types::NotaryResult>;
+
+    /// See [`Notary::put`].
+    async fn put(&self, body: &crate::types::NotaryData) -> Result<crate::types::NotaryData>;
+
+    /// See [`Notary::post`].
+    async fn post(&self, body: &crate::types::NotaryData) -> Result<crate::types::NotaryData>;
+}
+
+#[async_trait::async_trait]
+impl NotaryApi for Notary {
+    async fn get_with(&self, params: &NotaryGetParams) -> Result<crate::types::NotaryResult> {
+        Notary::get_with(self, params).await
+    }
+
+    async fn put(&self, body: &crate::types::NotaryData) -> Result<crate::types::NotaryData> {
+        Notary::put(self, body).await
+    }
+
+    async fn post(&self, body: &crate::types::NotaryData) -> Result<crate::types::NotaryData> {
+        Notary::post(self, body).await
+    }
+}
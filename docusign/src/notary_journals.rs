@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct NotaryJournals {
     pub client: Client,
@@ -21,24 +19,20 @@ impl NotaryJournals {
      *
      * **Parameters:**
      *
-     * * `count: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `search_text: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
+     * * `params: &NotaryJournalsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        count: &str,
-        search_text: &str,
-        start_position: &str,
+        params: &NotaryJournalsGetParams,
     ) -> Result<crate::types::NotaryJournalList> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !search_text.is_empty() {
+        if let Some(search_text) = &params.search_text {
             query_args.push(("search_text".to_string(), search_text.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -46,4 +40,72 @@ impl NotaryJournals {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `NotaryJournalsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        count: &str,
+        search_text: &str,
+        start_position: &str,
+    ) -> Result<crate::types::NotaryJournalList> {
+        let params = NotaryJournalsGetParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            search_text: Some(search_text.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(&params).await
+    }
+}
+
+/// The query parameters of `NotaryJournals::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct NotaryJournalsGetParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub count: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub search_text: Option<String>,
+    /// The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
+    pub start_position: Option<String>,
+}
+
+impl NotaryJournalsGetParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn search_text<T: ToString>(mut self, search_text: T) -> Self {
+        self.search_text = Some(search_text.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+}
+
+/// The functions of [`NotaryJournals`], for code that takes them as a trait, so that it can be
+/// tested with the `MockNotaryJournalsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait NotaryJournalsApi: Send + Sync {
+    /// See [`NotaryJournals::get_with`].
+    async fn get_with(
+        &self,
+        params: &NotaryJournalsGetParams,
+    ) -> Result<crate::types::NotaryJournalList>;
+}
+
+#[async_trait::async_trait]
+impl NotaryJournalsApi for NotaryJournals {
+    async fn get_with(
+        &self,
+        params: &NotaryJournalsGetParams,
+    ) -> Result<crate::types::NotaryJournalList> {
+        NotaryJournals::get_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct NotaryJurisdiction {
     pub client: Client,
@@ -257,3 +255,67 @@ impl NotaryJurisdiction {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`NotaryJurisdiction`], for code that takes them as a trait, so that it can be
+/// tested with the `MockNotaryJurisdictionApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait NotaryJurisdictionApi: Send + Sync {
+    /// See [`NotaryJurisdiction::s_get`].
+    async fn s_get(&self) -> Result<crate::types::NotaryJurisdictionList>;
+
+    /// See [`NotaryJurisdiction::s_post`].
+    async fn s_post(
+        &self,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> Result<crate::types::NotaryJurisdictionData>;
+
+    /// See [`NotaryJurisdiction::s_get_jurisdiction`].
+    async fn s_get_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+    ) -> Result<crate::types::NotaryJurisdictionData>;
+
+    /// See [`NotaryJurisdiction::s_put_jurisdiction`].
+    async fn s_put_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> Result<crate::types::NotaryJurisdictionData>;
+
+    /// See [`NotaryJurisdiction::s_delete_jurisdiction`].
+    async fn s_delete_jurisdiction(&self, jurisdiction_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl NotaryJurisdictionApi for NotaryJurisdiction {
+    async fn s_get(&self) -> Result<crate::types::NotaryJurisdictionList> {
+        NotaryJurisdiction::s_get(self).await
+    }
+
+    async fn s_post(
+        &self,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> Result<crate::types::NotaryJurisdictionData> {
+        NotaryJurisdiction::s_post(self, body).await
+    }
+
+    async fn s_get_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+    ) -> Result<crate::types::NotaryJurisdictionData> {
+        NotaryJurisdiction::s_get_jurisdiction(self, jurisdiction_id).await
+    }
+
+    async fn s_put_jurisdiction(
+        &self,
+        jurisdiction_id: &str,
+        body: &crate::types::NotaryJurisdictionData,
+    ) -> Result<crate::types::NotaryJurisdictionData> {
+        NotaryJurisdiction::s_put_jurisdiction(self, jurisdiction_id, body).await
+    }
+
+    async fn s_delete_jurisdiction(&self, jurisdiction_id: &str) -> Result<()> {
+        NotaryJurisdiction::s_delete_jurisdiction(self, jurisdiction_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct PaymentGatewayAccounts {
     pub client: Client,
@@ -35,3 +33,19 @@ impl PaymentGatewayAccounts {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`PaymentGatewayAccounts`], for code that takes them as a trait, so that it can be
+/// tested with the `MockPaymentGatewayAccountsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait PaymentGatewayAccountsApi: Send + Sync {
+    /// See [`PaymentGatewayAccounts::get_all`].
+    async fn get_all(&self, account_id: &str) -> Result<crate::types::PaymentGatewayAccountsInfo>;
+}
+
+#[async_trait::async_trait]
+impl PaymentGatewayAccountsApi for PaymentGatewayAccounts {
+    async fn get_all(&self, account_id: &str) -> Result<crate::types::PaymentGatewayAccountsInfo> {
+        PaymentGatewayAccounts::get_all(self, account_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Payments {
     pub client: Client,
@@ -24,20 +22,18 @@ impl Payments {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `from_date: &str` -- Specifies the date/time of the earliest payment in the account to retrieve.
-     * * `to_date: &str` -- Specifies the date/time of the latest payment in the account to retrieve.
+     * * `params: &PaymentsBillingGetListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn billing_get_list(
+    pub async fn billing_get_list_with(
         &self,
         account_id: &str,
-        from_date: &str,
-        to_date: &str,
+        params: &PaymentsBillingGetListParams,
     ) -> Result<crate::types::BillingPaymentsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from_date.is_empty() {
+        if let Some(from_date) = &params.from_date {
             query_args.push(("from_date".to_string(), from_date.to_string()));
         }
-        if !to_date.is_empty() {
+        if let Some(to_date) = &params.to_date {
             query_args.push(("to_date".to_string(), to_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -50,6 +46,23 @@ impl Payments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `billing_get_list_with` and pass the query parameters as a `PaymentsBillingGetListParams` instead.
+     */
+    #[deprecated(note = "use `billing_get_list_with` instead")]
+    pub async fn billing_get_list(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::BillingPaymentsResponse> {
+        let params = PaymentsBillingGetListParams {
+            from_date: Some(from_date.to_string()).filter(|s| !s.is_empty()),
+            to_date: Some(to_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.billing_get_list_with(account_id, &params).await
+    }
+
     /**
      * Posts a payment to a past due invoice.
      *
@@ -111,3 +124,78 @@ impl Payments {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Payments::billing_get_list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PaymentsBillingGetListParams {
+    /// Specifies the date/time of the earliest payment in the account to retrieve.
+    pub from_date: Option<String>,
+    /// Specifies the date/time of the latest payment in the account to retrieve.
+    pub to_date: Option<String>,
+}
+
+impl PaymentsBillingGetListParams {
+    pub fn from_date<T: ToString>(mut self, from_date: T) -> Self {
+        self.from_date = Some(from_date.to_string());
+        self
+    }
+
+    pub fn to_date<T: ToString>(mut self, to_date: T) -> Self {
+        self.to_date = Some(to_date.to_string());
+        self
+    }
+}
+
+/// The functions of [`Payments`], for code that takes them as a trait, so that it can be
+/// tested with the `MockPaymentsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait PaymentsApi: Send + Sync {
+    /// See [`Payments::billing_get_list_with`].
+    async fn billing_get_list_with(
+        &self,
+        account_id: &str,
+        params: &PaymentsBillingGetListParams,
+    ) -> Result<crate::types::BillingPaymentsResponse>;
+
+    /// See [`Payments::billing_post`].
+    async fn billing_post(
+        &self,
+        account_id: &str,
+        body: &crate::types::BillingPaymentRequest,
+    ) -> Result<crate::types::BillingPaymentResponse>;
+
+    /// See [`Payments::billing_get`].
+    async fn billing_get(
+        &self,
+        account_id: &str,
+        payment_id: &str,
+    ) -> Result<crate::types::BillingPaymentItem>;
+}
+
+#[async_trait::async_trait]
+impl PaymentsApi for Payments {
+    async fn billing_get_list_with(
+        &self,
+        account_id: &str,
+        params: &PaymentsBillingGetListParams,
+    ) -> Result<crate::types::BillingPaymentsResponse> {
+        Payments::billing_get_list_with(self, account_id, params).await
+    }
+
+    async fn billing_post(
+        &self,
+        account_id: &str,
+        body: &crate::types::BillingPaymentRequest,
+    ) -> Result<crate::types::BillingPaymentResponse> {
+        Payments::billing_post(self, account_id, body).await
+    }
+
+    async fn billing_get(
+        &self,
+        account_id: &str,
+        payment_id: &str,
+    ) -> Result<crate::types::BillingPaymentItem> {
+        Payments::billing_get(self, account_id, payment_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct PowerFormData {
     pub client: Client,
@@ -32,33 +30,22 @@ impl PowerFormData {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `power_form_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `data_layout: &str` -- The layout in which to return the PowerForm data. Valid values are:
-     *   
-     *   - `Native`
-     *   - `Csv_Classic`
-     *   - `Csv_One_Envelope_Per_Line`
-     *   - `Xml_Classic`.
-     * * `from_date: &str` -- The start date for a date range in UTC DateTime format.
-     *   
-     *   **Note**: If this property is null, no date filtering is applied.
-     * * `to_date: &str` -- The end date of a date range in UTC DateTime format. The default value is `UtcNow`.
+     * * `params: &PowerFormDataPowerFormsGetFormDataParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn power_forms_get_form_data(
+    pub async fn power_forms_get_form_data_with(
         &self,
         account_id: &str,
         power_form_id: &str,
-        data_layout: &str,
-        from_date: &str,
-        to_date: &str,
+        params: &PowerFormDataPowerFormsGetFormDataParams,
     ) -> Result<crate::types::PowerFormsFormDataResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !data_layout.is_empty() {
+        if let Some(data_layout) = &params.data_layout {
             query_args.push(("data_layout".to_string(), data_layout.to_string()));
         }
-        if !from_date.is_empty() {
+        if let Some(from_date) = &params.from_date {
             query_args.push(("from_date".to_string(), from_date.to_string()));
         }
-        if !to_date.is_empty() {
+        if let Some(to_date) = &params.to_date {
             query_args.push(("to_date".to_string(), to_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -71,4 +58,86 @@ impl PowerFormData {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `power_forms_get_form_data_with` and pass the query parameters as a `PowerFormDataPowerFormsGetFormDataParams` instead.
+     */
+    #[deprecated(note = "use `power_forms_get_form_data_with` instead")]
+    pub async fn power_forms_get_form_data(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        data_layout: &str,
+        from_date: &str,
+        to_date: &str,
+    ) -> Result<crate::types::PowerFormsFormDataResponse> {
+        let params = PowerFormDataPowerFormsGetFormDataParams {
+            data_layout: Some(data_layout.to_string()).filter(|s| !s.is_empty()),
+            from_date: Some(from_date.to_string()).filter(|s| !s.is_empty()),
+            to_date: Some(to_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.power_forms_get_form_data_with(account_id, power_form_id, &params)
+            .await
+    }
+}
+
+/// The query parameters of `PowerFormData::power_forms_get_form_data_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PowerFormDataPowerFormsGetFormDataParams {
+    /// The layout in which to return the PowerForm data. Valid values are:
+    ///
+    /// - `Native`
+    /// - `Csv_Classic`
+    /// - `Csv_One_Envelope_Per_Line`
+    /// - `Xml_Classic`.
+    pub data_layout: Option<String>,
+    /// The start date for a date range in UTC DateTime format.
+    ///
+    /// **Note**: If this property is null, no date filtering is applied.
+    pub from_date: Option<String>,
+    /// The end date of a date range in UTC DateTime format. The default value is `UtcNow`.
+    pub to_date: Option<String>,
+}
+
+impl PowerFormDataPowerFormsGetFormDataParams {
+    pub fn data_layout<T: ToString>(mut self, data_layout: T) -> Self {
+        self.data_layout = Some(data_layout.to_string());
+        self
+    }
+
+    pub fn from_date<T: ToString>(mut self, from_date: T) -> Self {
+        self.from_date = Some(from_date.to_string());
+        self
+    }
+
+    pub fn to_date<T: ToString>(mut self, to_date: T) -> Self {
+        self.to_date = Some(to_date.to_string());
+        self
+    }
+}
+
+/// The functions of [`PowerFormData`], for code that takes them as a trait, so that it can be
+/// tested with the `MockPowerFormDataApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait PowerFormDataApi: Send + Sync {
+    /// See [`PowerFormData::power_forms_get_form_data_with`].
+    async fn power_forms_get_form_data_with(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        params: &PowerFormDataPowerFormsGetFormDataParams,
+    ) -> Result<crate::types::PowerFormsFormDataResponse>;
+}
+
+#[async_trait::async_trait]
+impl PowerFormDataApi for PowerFormData {
+    async fn power_forms_get_form_data_with(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        params: &PowerFormDataPowerFormsGetFormDataParams,
+    ) -> Result<crate::types::PowerFormsFormDataResponse> {
+        PowerFormData::power_forms_get_form_data_with(self, account_id, power_form_id, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct PowerForms {
     pub client: Client,
@@ -22,45 +20,24 @@ impl PowerForms {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `from_date: &str` -- (Optional) The start date for a date range.
-     *   
-     *   **Note**: If no value is provided, no date filtering is applied.
-     * * `order: &str` -- (Optional) The order in which to sort the results.
-     *   
-     *   Valid values are:
-     *   
-     *   
-     *   * `asc`: Ascending order.
-     *   * `desc`: Descending order.
-     * * `order_by: &str` -- (Optional) The file attribute to use to sort the results.
-     *   
-     *   Valid values are:
-     *   
-     *   * `modified`
-     *   * `name`.
-     * * `to_date: &str` -- (Optional) The end date for a date range.
-     *   
-     *   **Note**: If no value is provided, this property defaults to the current date.
+     * * `params: &PowerFormsGetListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_list(
+    pub async fn get_list_with(
         &self,
         account_id: &str,
-        from_date: &str,
-        order: &str,
-        order_by: &str,
-        to_date: &str,
+        params: &PowerFormsGetListParams,
     ) -> Result<crate::types::PowerFormsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from_date.is_empty() {
+        if let Some(from_date) = &params.from_date {
             query_args.push(("from_date".to_string(), from_date.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !order_by.is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("order_by".to_string(), order_by.to_string()));
         }
-        if !to_date.is_empty() {
+        if let Some(to_date) = &params.to_date {
             query_args.push(("to_date".to_string(), to_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -73,6 +50,27 @@ impl PowerForms {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_list_with` and pass the query parameters as a `PowerFormsGetListParams` instead.
+     */
+    #[deprecated(note = "use `get_list_with` instead")]
+    pub async fn get_list(
+        &self,
+        account_id: &str,
+        from_date: &str,
+        order: &str,
+        order_by: &str,
+        to_date: &str,
+    ) -> Result<crate::types::PowerFormsResponse> {
+        let params = PowerFormsGetListParams {
+            from_date: Some(from_date.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            order_by: Some(order_by.to_string()).filter(|s| !s.is_empty()),
+            to_date: Some(to_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_list_with(account_id, &params).await
+    }
+
     /**
      * Creates a new PowerForm.
      *
@@ -174,15 +172,15 @@ impl PowerForms {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
+     * * `params: &PowerFormsGetSendersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_senders(
+    pub async fn get_senders_with(
         &self,
         account_id: &str,
-        start_position: &str,
+        params: &PowerFormsGetSendersParams,
     ) -> Result<crate::types::PowerFormSendersResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -195,6 +193,21 @@ impl PowerForms {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_senders_with` and pass the query parameters as a `PowerFormsGetSendersParams` instead.
+     */
+    #[deprecated(note = "use `get_senders_with` instead")]
+    pub async fn get_senders(
+        &self,
+        account_id: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PowerFormSendersResponse> {
+        let params = PowerFormsGetSendersParams {
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_senders_with(account_id, &params).await
+    }
+
     /**
      * Returns a single PowerForm.
      *
@@ -272,3 +285,175 @@ impl PowerForms {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `PowerForms::get_list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PowerFormsGetListParams {
+    /// (Optional) The start date for a date range.
+    ///
+    /// **Note**: If no value is provided, no date filtering is applied.
+    pub from_date: Option<String>,
+    /// (Optional) The order in which to sort the results.
+    ///
+    /// Valid values are:
+    ///
+    ///
+    /// * `asc`: Ascending order.
+    /// * `desc`: Descending order.
+    pub order: Option<String>,
+    /// (Optional) The file attribute to use to sort the results.
+    ///
+    /// Valid values are:
+    ///
+    /// * `modified`
+    /// * `name`.
+    pub order_by: Option<String>,
+    /// (Optional) The end date for a date range.
+    ///
+    /// **Note**: If no value is provided, this property defaults to the current date.
+    pub to_date: Option<String>,
+}
+
+impl PowerFormsGetListParams {
+    pub fn from_date<T: ToString>(mut self, from_date: T) -> Self {
+        self.from_date = Some(from_date.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn order_by<T: ToString>(mut self, order_by: T) -> Self {
+        self.order_by = Some(order_by.to_string());
+        self
+    }
+
+    pub fn to_date<T: ToString>(mut self, to_date: T) -> Self {
+        self.to_date = Some(to_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `PowerForms::get_senders_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PowerFormsGetSendersParams {
+    /// The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
+    pub start_position: Option<String>,
+}
+
+impl PowerFormsGetSendersParams {
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+}
+
+/// The functions of [`PowerForms`], for code that takes them as a trait, so that it can be
+/// tested with the `MockPowerFormsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait PowerFormsApi: Send + Sync {
+    /// See [`PowerForms::get_list_with`].
+    async fn get_list_with(
+        &self,
+        account_id: &str,
+        params: &PowerFormsGetListParams,
+    ) -> Result<crate::types::PowerFormsResponse>;
+
+    /// See [`PowerForms::post_form`].
+    async fn post_form(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> Result<crate::types::PowerForm>;
+
+    /// See [`PowerForms::delete_list`].
+    async fn delete_list(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerFormsRequest,
+    ) -> Result<crate::types::PowerFormsResponse>;
+
+    /// See [`PowerForms::get_senders_with`].
+    async fn get_senders_with(
+        &self,
+        account_id: &str,
+        params: &PowerFormsGetSendersParams,
+    ) -> Result<crate::types::PowerFormSendersResponse>;
+
+    /// See [`PowerForms::get_form`].
+    async fn get_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> Result<crate::types::PowerForm>;
+
+    /// See [`PowerForms::put_form`].
+    async fn put_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> Result<crate::types::PowerForm>;
+
+    /// See [`PowerForms::delete_form`].
+    async fn delete_form(&self, account_id: &str, power_form_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl PowerFormsApi for PowerForms {
+    async fn get_list_with(
+        &self,
+        account_id: &str,
+        params: &PowerFormsGetListParams,
+    ) -> Result<crate::types::PowerFormsResponse> {
+        PowerForms::get_list_with(self, account_id, params).await
+    }
+
+    async fn post_form(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> Result<crate::types::PowerForm> {
+        PowerForms::post_form(self, account_id, body).await
+    }
+
+    async fn delete_list(
+        &self,
+        account_id: &str,
+        body: &crate::types::PowerFormsRequest,
+    ) -> Result<crate::types::PowerFormsResponse> {
+        PowerForms::delete_list(self, account_id, body).await
+    }
+
+    async fn get_senders_with(
+        &self,
+        account_id: &str,
+        params: &PowerFormsGetSendersParams,
+    ) -> Result<crate::types::PowerFormSendersResponse> {
+        PowerForms::get_senders_with(self, account_id, params).await
+    }
+
+    async fn get_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+    ) -> Result<crate::types::PowerForm> {
+        PowerForms::get_form(self, account_id, power_form_id).await
+    }
+
+    async fn put_form(
+        &self,
+        account_id: &str,
+        power_form_id: &str,
+        body: &crate::types::PowerForm,
+    ) -> Result<crate::types::PowerForm> {
+        PowerForms::put_form(self, account_id, power_form_id, body).await
+    }
+
+    async fn delete_form(&self, account_id: &str, power_form_id: &str) -> Result<()> {
+        PowerForms::delete_form(self, account_id, power_form_id).await
+    }
+}
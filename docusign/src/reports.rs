@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Reports {
     pub client: Client,
@@ -195,3 +193,115 @@ impl Reports {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`Reports`], for code that takes them as a trait, so that it can be
+/// tested with the `MockReportsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReportsApi: Send + Sync {
+    /// See [`Reports::product_get_list`].
+    async fn product_get_list(&self, account_id: &str)
+        -> Result<crate::types::ReportInProductList>;
+
+    /// See [`Reports::product_post_create`].
+    async fn product_post_create(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductSaveResponse>;
+
+    /// See [`Reports::product_put_run_results`].
+    async fn product_put_run_results(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductRunResponse>;
+
+    /// See [`Reports::product_put_results_csv`].
+    async fn product_put_results_csv(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductCsvRunRequest,
+    ) -> Result<()>;
+
+    /// See [`Reports::product_get`].
+    async fn product_get(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> Result<crate::types::ReportInProductGet>;
+
+    /// See [`Reports::product_put_save`].
+    async fn product_put_save(
+        &self,
+        account_id: &str,
+        id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductSaveResponse>;
+
+    /// See [`Reports::product_delete`].
+    async fn product_delete(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> Result<crate::types::ReportInProductSaveResponse>;
+}
+
+#[async_trait::async_trait]
+impl ReportsApi for Reports {
+    async fn product_get_list(
+        &self,
+        account_id: &str,
+    ) -> Result<crate::types::ReportInProductList> {
+        Reports::product_get_list(self, account_id).await
+    }
+
+    async fn product_post_create(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductSaveResponse> {
+        Reports::product_post_create(self, account_id, body).await
+    }
+
+    async fn product_put_run_results(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductRunResponse> {
+        Reports::product_put_run_results(self, account_id, body).await
+    }
+
+    async fn product_put_results_csv(
+        &self,
+        account_id: &str,
+        body: &crate::types::ReportInProductCsvRunRequest,
+    ) -> Result<()> {
+        Reports::product_put_results_csv(self, account_id, body).await
+    }
+
+    async fn product_get(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> Result<crate::types::ReportInProductGet> {
+        Reports::product_get(self, account_id, id).await
+    }
+
+    async fn product_put_save(
+        &self,
+        account_id: &str,
+        id: &str,
+        body: &crate::types::ReportInProductRunRequest,
+    ) -> Result<crate::types::ReportInProductSaveResponse> {
+        Reports::product_put_save(self, account_id, id, body).await
+    }
+
+    async fn product_delete(
+        &self,
+        account_id: &str,
+        id: &str,
+    ) -> Result<crate::types::ReportInProductSaveResponse> {
+        Reports::product_delete(self, account_id, id).await
+    }
+}
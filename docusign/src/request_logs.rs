@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct RequestLogs {
     pub client: Client,
@@ -25,11 +23,14 @@ impl RequestLogs {
      *
      * **Parameters:**
      *
-     * * `encoding: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &RequestLogsApiGetLogParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn api_get_log(&self, encoding: &str) -> Result<crate::types::ApiRequestLogsResult> {
+    pub async fn api_get_log_with(
+        &self,
+        params: &RequestLogsApiGetLogParams,
+    ) -> Result<crate::types::ApiRequestLogsResult> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !encoding.is_empty() {
+        if let Some(encoding) = &params.encoding {
             query_args.push(("encoding".to_string(), encoding.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -38,6 +39,17 @@ impl RequestLogs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `api_get_log_with` and pass the query parameters as a `RequestLogsApiGetLogParams` instead.
+     */
+    #[deprecated(note = "use `api_get_log_with` instead")]
+    pub async fn api_get_log(&self, encoding: &str) -> Result<crate::types::ApiRequestLogsResult> {
+        let params = RequestLogsApiGetLogParams {
+            encoding: Some(encoding.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.api_get_log_with(&params).await
+    }
+
     /**
      * Deletes the request log files.
      *
@@ -119,3 +131,73 @@ impl RequestLogs {
             .await
     }
 }
+
+/// The query parameters of `RequestLogs::api_get_log_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RequestLogsApiGetLogParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub encoding: Option<String>,
+}
+
+impl RequestLogsApiGetLogParams {
+    pub fn encoding<T: ToString>(mut self, encoding: T) -> Self {
+        self.encoding = Some(encoding.to_string());
+        self
+    }
+}
+
+/// The functions of [`RequestLogs`], for code that takes them as a trait, so that it can be
+/// tested with the `MockRequestLogsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait RequestLogsApi: Send + Sync {
+    /// See [`RequestLogs::api_get_log_with`].
+    async fn api_get_log_with(
+        &self,
+        params: &RequestLogsApiGetLogParams,
+    ) -> Result<crate::types::ApiRequestLogsResult>;
+
+    /// See [`RequestLogs::api_delete_logs`].
+    async fn api_delete_logs(&self) -> Result<()>;
+
+    /// See [`RequestLogs::api_get`].
+    async fn api_get(&self, request_log_id: &str) -> Result<bytes::Bytes>;
+
+    /// See [`RequestLogs::api_get_setting`].
+    async fn api_get_setting(&self) -> Result<crate::types::DiagnosticsSettingsInformation>;
+
+    /// See [`RequestLogs::api_put_settings`].
+    async fn api_put_settings(
+        &self,
+        body: &crate::types::DiagnosticsSettingsInformation,
+    ) -> Result<crate::types::DiagnosticsSettingsInformation>;
+}
+
+#[async_trait::async_trait]
+impl RequestLogsApi for RequestLogs {
+    async fn api_get_log_with(
+        &self,
+        params: &RequestLogsApiGetLogParams,
+    ) -> Result<crate::types::ApiRequestLogsResult> {
+        RequestLogs::api_get_log_with(self, params).await
+    }
+
+    async fn api_delete_logs(&self) -> Result<()> {
+        RequestLogs::api_delete_logs(self).await
+    }
+
+    async fn api_get(&self, request_log_id: &str) -> Result<bytes::Bytes> {
+        RequestLogs::api_get(self, request_log_id).await
+    }
+
+    async fn api_get_setting(&self) -> Result<crate::types::DiagnosticsSettingsInformation> {
+        RequestLogs::api_get_setting(self).await
+    }
+
+    async fn api_put_settings(
+        &self,
+        body: &crate::types::DiagnosticsSettingsInformation,
+    ) -> Result<crate::types::DiagnosticsSettingsInformation> {
+        RequestLogs::api_put_settings(self, body).await
+    }
+}
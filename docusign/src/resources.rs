@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Resources {
     pub client: Client,
@@ -32,3 +30,19 @@ impl Resources {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`Resources`], for code that takes them as a trait, so that it can be
+/// tested with the `MockResourcesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ResourcesApi: Send + Sync {
+    /// See [`Resources::service_information_get`].
+    async fn service_information_get(&self) -> Result<crate::types::ResourceInformation>;
+}
+
+#[async_trait::async_trait]
+impl ResourcesApi for Resources {
+    async fn service_information_get(&self) -> Result<crate::types::ResourceInformation> {
+        Resources::service_information_get(self).await
+    }
+}
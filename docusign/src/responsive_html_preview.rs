@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ResponsiveHtmlPreview {
     pub client: Client,
@@ -52,3 +50,30 @@ impl ResponsiveHtmlPreview {
             .await
     }
 }
+
+/// The functions of [`ResponsiveHtmlPreview`], for code that takes them as a trait, so that it can be
+/// tested with the `MockResponsiveHtmlPreviewApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ResponsiveHtmlPreviewApi: Send + Sync {
+    /// See [`ResponsiveHtmlPreview::responsive_html_post_preview`].
+    async fn responsive_html_post_preview(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions>;
+}
+
+#[async_trait::async_trait]
+impl ResponsiveHtmlPreviewApi for ResponsiveHtmlPreview {
+    async fn responsive_html_post_preview(
+        &self,
+        account_id: &str,
+        envelope_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        ResponsiveHtmlPreview::responsive_html_post_preview(self, account_id, envelope_id, body)
+            .await
+    }
+}
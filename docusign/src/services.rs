@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Services {
     pub client: Client,
@@ -29,3 +27,19 @@ impl Services {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`Services`], for code that takes them as a trait, so that it can be
+/// tested with the `MockServicesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ServicesApi: Send + Sync {
+    /// See [`Services::information_get`].
+    async fn information_get(&self) -> Result<crate::types::ServiceInformation>;
+}
+
+#[async_trait::async_trait]
+impl ServicesApi for Services {
+    async fn information_get(&self) -> Result<crate::types::ServiceInformation> {
+        Services::information_get(self).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SigningGroupUsers {
     pub client: Client,
@@ -102,3 +100,68 @@ impl SigningGroupUsers {
             .await
     }
 }
+
+/// The functions of [`SigningGroupUsers`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSigningGroupUsersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SigningGroupUsersApi: Send + Sync {
+    /// See [`SigningGroupUsers::signing_groups_get_group_user`].
+    async fn signing_groups_get_group_user(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> Result<crate::types::SigningGroupUsersData>;
+
+    /// See [`SigningGroupUsers::signing_groups_put_group_users`].
+    async fn signing_groups_put_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> Result<crate::types::SigningGroupUsersData>;
+
+    /// See [`SigningGroupUsers::signing_groups_delete_group_users`].
+    async fn signing_groups_delete_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> Result<crate::types::SigningGroupUsersData>;
+}
+
+#[async_trait::async_trait]
+impl SigningGroupUsersApi for SigningGroupUsers {
+    async fn signing_groups_get_group_user(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> Result<crate::types::SigningGroupUsersData> {
+        SigningGroupUsers::signing_groups_get_group_user(self, account_id, signing_group_id).await
+    }
+
+    async fn signing_groups_put_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> Result<crate::types::SigningGroupUsersData> {
+        SigningGroupUsers::signing_groups_put_group_users(self, account_id, signing_group_id, body)
+            .await
+    }
+
+    async fn signing_groups_delete_group_users(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroupUsersData,
+    ) -> Result<crate::types::SigningGroupUsersData> {
+        SigningGroupUsers::signing_groups_delete_group_users(
+            self,
+            account_id,
+            signing_group_id,
+            body,
+        )
+        .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SigningGroups {
     pub client: Client,
@@ -22,20 +20,18 @@ impl SigningGroups {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `group_type: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_users: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &SigningGroupsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         account_id: &str,
-        group_type: &str,
-        include_users: &str,
+        params: &SigningGroupsGetParams,
     ) -> Result<crate::types::SigningGroupInformation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !group_type.is_empty() {
+        if let Some(group_type) = &params.group_type {
             query_args.push(("group_type".to_string(), group_type.to_string()));
         }
-        if !include_users.is_empty() {
+        if let Some(include_users) = &params.include_users {
             query_args.push(("include_users".to_string(), include_users.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -48,6 +44,23 @@ impl SigningGroups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `SigningGroupsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        account_id: &str,
+        group_type: &str,
+        include_users: &str,
+    ) -> Result<crate::types::SigningGroupInformation> {
+        let params = SigningGroupsGetParams {
+            group_type: Some(group_type.to_string()).filter(|s| !s.is_empty()),
+            include_users: Some(include_users.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(account_id, &params).await
+    }
+
     /**
      * Updates signing group names.
      *
@@ -191,3 +204,125 @@ impl SigningGroups {
             .await
     }
 }
+
+/// The query parameters of `SigningGroups::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SigningGroupsGetParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub group_type: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub include_users: Option<String>,
+}
+
+impl SigningGroupsGetParams {
+    pub fn group_type<T: ToString>(mut self, group_type: T) -> Self {
+        self.group_type = Some(group_type.to_string());
+        self
+    }
+
+    pub fn include_users<T: ToString>(mut self, include_users: T) -> Self {
+        self.include_users = Some(include_users.to_string());
+        self
+    }
+}
+
+/// The functions of [`SigningGroups`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSigningGroupsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SigningGroupsApi: Send + Sync {
+    /// See [`SigningGroups::get_with`].
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &SigningGroupsGetParams,
+    ) -> Result<crate::types::SigningGroupInformation>;
+
+    /// See [`SigningGroups::put`].
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation>;
+
+    /// See [`SigningGroups::post`].
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation>;
+
+    /// See [`SigningGroups::delete`].
+    async fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation>;
+
+    /// See [`SigningGroups::get_group`].
+    async fn get_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> Result<crate::types::SigningGroup>;
+
+    /// See [`SigningGroups::put_group`].
+    async fn put_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroup,
+    ) -> Result<crate::types::SigningGroup>;
+}
+
+#[async_trait::async_trait]
+impl SigningGroupsApi for SigningGroups {
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &SigningGroupsGetParams,
+    ) -> Result<crate::types::SigningGroupInformation> {
+        SigningGroups::get_with(self, account_id, params).await
+    }
+
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation> {
+        SigningGroups::put(self, account_id, body).await
+    }
+
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation> {
+        SigningGroups::post(self, account_id, body).await
+    }
+
+    async fn delete(
+        &self,
+        account_id: &str,
+        body: &crate::types::SigningGroupInformation,
+    ) -> Result<crate::types::SigningGroupInformation> {
+        SigningGroups::delete(self, account_id, body).await
+    }
+
+    async fn get_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+    ) -> Result<crate::types::SigningGroup> {
+        SigningGroups::get_group(self, account_id, signing_group_id).await
+    }
+
+    async fn put_group(
+        &self,
+        account_id: &str,
+        signing_group_id: &str,
+        body: &crate::types::SigningGroup,
+    ) -> Result<crate::types::SigningGroup> {
+        SigningGroups::put_group(self, account_id, signing_group_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TabsBlob {
     pub client: Client,
@@ -56,3 +54,26 @@ impl TabsBlob {
         self.client.put(&url, None).await
     }
 }
+
+/// The functions of [`TabsBlob`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTabsBlobApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TabsBlobApi: Send + Sync {
+    /// See [`TabsBlob::get`].
+    async fn get(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+
+    /// See [`TabsBlob::put`].
+    async fn put(&self, account_id: &str, envelope_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl TabsBlobApi for TabsBlob {
+    async fn get(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        TabsBlob::get(self, account_id, envelope_id).await
+    }
+
+    async fn put(&self, account_id: &str, envelope_id: &str) -> Result<()> {
+        TabsBlob::put(self, account_id, envelope_id).await
+    }
+}
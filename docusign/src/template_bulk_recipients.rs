@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateBulkRecipients {
     pub client: Client,
@@ -24,22 +22,20 @@ impl TemplateBulkRecipients {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `recipient_id: &str` -- A local reference that senders use to map recipients to other objects, such as specific document tabs. Within an envelope, each `recipientId` must be unique, but there is no uniqueness requirement across envelopes. For example, many envelopes assign the first recipient a `recipientId` of `1`.
      * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_tabs: &str` -- When set to **true**, the tab information associated with the recipient is included in the response. If you do not specify this parameter, the effect is the default behavior (**false**).
-     * * `start_position: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &TemplateBulkRecipientsRecipientsGetTemplateBulkParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recipients_get_template_bulk(
+    pub async fn recipients_get_template_bulk_with(
         &self,
         account_id: &str,
         recipient_id: &str,
         template_id: &str,
-        include_tabs: &str,
-        start_position: &str,
+        params: &TemplateBulkRecipientsRecipientsGetTemplateBulkParams,
     ) -> Result<crate::types::BulkRecipientsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_tabs.is_empty() {
+        if let Some(include_tabs) = &params.include_tabs {
             query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -54,6 +50,26 @@ impl TemplateBulkRecipients {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `recipients_get_template_bulk_with` and pass the query parameters as a `TemplateBulkRecipientsRecipientsGetTemplateBulkParams` instead.
+     */
+    #[deprecated(note = "use `recipients_get_template_bulk_with` instead")]
+    pub async fn recipients_get_template_bulk(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        include_tabs: &str,
+        start_position: &str,
+    ) -> Result<crate::types::BulkRecipientsResponse> {
+        let params = TemplateBulkRecipientsRecipientsGetTemplateBulkParams {
+            include_tabs: Some(include_tabs.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipients_get_template_bulk_with(account_id, recipient_id, template_id, &params)
+            .await
+    }
+
     /**
      * Adds or replaces the bulk recipients list in a template.
      *
@@ -117,3 +133,108 @@ impl TemplateBulkRecipients {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `TemplateBulkRecipients::recipients_get_template_bulk_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateBulkRecipientsRecipientsGetTemplateBulkParams {
+    /// When set to **true**, the tab information associated with the recipient is included in the response. If you do not specify this parameter, the effect is the default behavior (**false**).
+    pub include_tabs: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub start_position: Option<String>,
+}
+
+impl TemplateBulkRecipientsRecipientsGetTemplateBulkParams {
+    pub fn include_tabs<T: ToString>(mut self, include_tabs: T) -> Self {
+        self.include_tabs = Some(include_tabs.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+}
+
+/// The functions of [`TemplateBulkRecipients`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateBulkRecipientsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateBulkRecipientsApi: Send + Sync {
+    /// See [`TemplateBulkRecipients::recipients_get_template_bulk_with`].
+    async fn recipients_get_template_bulk_with(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        params: &TemplateBulkRecipientsRecipientsGetTemplateBulkParams,
+    ) -> Result<crate::types::BulkRecipientsResponse>;
+
+    /// See [`TemplateBulkRecipients::recipients_put_template_bulk`].
+    async fn recipients_put_template_bulk(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::BulkRecipientsRequest,
+    ) -> Result<crate::types::BulkRecipientsSummaryResponse>;
+
+    /// See [`TemplateBulkRecipients::recipients_delete_template_bulk_file`].
+    async fn recipients_delete_template_bulk_file(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::BulkRecipientsUpdateResponse>;
+}
+
+#[async_trait::async_trait]
+impl TemplateBulkRecipientsApi for TemplateBulkRecipients {
+    async fn recipients_get_template_bulk_with(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        params: &TemplateBulkRecipientsRecipientsGetTemplateBulkParams,
+    ) -> Result<crate::types::BulkRecipientsResponse> {
+        TemplateBulkRecipients::recipients_get_template_bulk_with(
+            self,
+            account_id,
+            recipient_id,
+            template_id,
+            params,
+        )
+        .await
+    }
+
+    async fn recipients_put_template_bulk(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::BulkRecipientsRequest,
+    ) -> Result<crate::types::BulkRecipientsSummaryResponse> {
+        TemplateBulkRecipients::recipients_put_template_bulk(
+            self,
+            account_id,
+            recipient_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+
+    async fn recipients_delete_template_bulk_file(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::BulkRecipientsUpdateResponse> {
+        TemplateBulkRecipients::recipients_delete_template_bulk_file(
+            self,
+            account_id,
+            recipient_id,
+            template_id,
+        )
+        .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateCustomFields {
     pub client: Client,
@@ -127,3 +125,79 @@ impl TemplateCustomFields {
             .await
     }
 }
+
+/// The functions of [`TemplateCustomFields`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateCustomFieldsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateCustomFieldsApi: Send + Sync {
+    /// See [`TemplateCustomFields::custom_fields_get_template`].
+    async fn custom_fields_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::CustomFields>;
+
+    /// See [`TemplateCustomFields::custom_fields_put_template`].
+    async fn custom_fields_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateCustomFieldsData,
+    ) -> Result<crate::types::CustomFields>;
+
+    /// See [`TemplateCustomFields::custom_fields_post_template`].
+    async fn custom_fields_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateCustomFieldsData,
+    ) -> Result<crate::types::CustomFields>;
+
+    /// See [`TemplateCustomFields::custom_fields_delete_template`].
+    async fn custom_fields_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateCustomFieldsData,
+    ) -> Result<crate::types::CustomFields>;
+}
+
+#[async_trait::async_trait]
+impl TemplateCustomFieldsApi for TemplateCustomFields {
+    async fn custom_fields_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::CustomFields> {
+        TemplateCustomFields::custom_fields_get_template(self, account_id, template_id).await
+    }
+
+    async fn custom_fields_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateCustomFieldsData,
+    ) -> Result<crate::types::CustomFields> {
+        TemplateCustomFields::custom_fields_put_template(self, account_id, template_id, body).await
+    }
+
+    async fn custom_fields_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateCustomFieldsData,
+    ) -> Result<crate::types::CustomFields> {
+        TemplateCustomFields::custom_fields_post_template(self, account_id, template_id, body).await
+    }
+
+    async fn custom_fields_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateCustomFieldsData,
+    ) -> Result<crate::types::CustomFields> {
+        TemplateCustomFields::custom_fields_delete_template(self, account_id, template_id, body)
+            .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateDocumentFields {
     pub client: Client,
@@ -137,3 +135,113 @@ impl TemplateDocumentFields {
             .await
     }
 }
+
+/// The functions of [`TemplateDocumentFields`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateDocumentFieldsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateDocumentFieldsApi: Send + Sync {
+    /// See [`TemplateDocumentFields::document_fields_get_template`].
+    async fn document_fields_get_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentFieldsInformation>;
+
+    /// See [`TemplateDocumentFields::document_fields_put_template`].
+    async fn document_fields_put_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation>;
+
+    /// See [`TemplateDocumentFields::document_fields_post_template`].
+    async fn document_fields_post_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation>;
+
+    /// See [`TemplateDocumentFields::document_fields_delete_template`].
+    async fn document_fields_delete_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation>;
+}
+
+#[async_trait::async_trait]
+impl TemplateDocumentFieldsApi for TemplateDocumentFields {
+    async fn document_fields_get_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentFieldsInformation> {
+        TemplateDocumentFields::document_fields_get_template(
+            self,
+            account_id,
+            document_id,
+            template_id,
+        )
+        .await
+    }
+
+    async fn document_fields_put_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation> {
+        TemplateDocumentFields::document_fields_put_template(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+
+    async fn document_fields_post_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation> {
+        TemplateDocumentFields::document_fields_post_template(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+
+    async fn document_fields_delete_template(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentFieldsInformation,
+    ) -> Result<crate::types::DocumentFieldsInformation> {
+        TemplateDocumentFields::document_fields_delete_template(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+}
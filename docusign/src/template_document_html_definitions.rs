@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateDocumentHtmlDefinitions {
     pub client: Client,
@@ -41,3 +39,35 @@ impl TemplateDocumentHtmlDefinitions {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`TemplateDocumentHtmlDefinitions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateDocumentHtmlDefinitionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateDocumentHtmlDefinitionsApi: Send + Sync {
+    /// See [`TemplateDocumentHtmlDefinitions::responsive_html_get_template_document_definition`].
+    async fn responsive_html_get_template_document_definition(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentHtmlDefinitionOriginals>;
+}
+
+#[async_trait::async_trait]
+impl TemplateDocumentHtmlDefinitionsApi for TemplateDocumentHtmlDefinitions {
+    async fn responsive_html_get_template_document_definition(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentHtmlDefinitionOriginals> {
+        TemplateDocumentHtmlDefinitions::responsive_html_get_template_document_definition(
+            self,
+            account_id,
+            document_id,
+            template_id,
+        )
+        .await
+    }
+}
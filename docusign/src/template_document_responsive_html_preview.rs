@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateDocumentResponsiveHtmlPreview {
     pub client: Client,
@@ -54,3 +52,38 @@ impl TemplateDocumentResponsiveHtmlPreview {
             .await
     }
 }
+
+/// The functions of [`TemplateDocumentResponsiveHtmlPreview`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateDocumentResponsiveHtmlPreviewApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateDocumentResponsiveHtmlPreviewApi: Send + Sync {
+    /// See [`TemplateDocumentResponsiveHtmlPreview::responsive_html_post_template_document_preview`].
+    async fn responsive_html_post_template_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions>;
+}
+
+#[async_trait::async_trait]
+impl TemplateDocumentResponsiveHtmlPreviewApi for TemplateDocumentResponsiveHtmlPreview {
+    async fn responsive_html_post_template_document_preview(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        TemplateDocumentResponsiveHtmlPreview::responsive_html_post_template_document_preview(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+}
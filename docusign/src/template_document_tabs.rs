@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateDocumentTabs {
     pub client: Client,
@@ -56,19 +54,17 @@ impl TemplateDocumentTabs {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `document_id: &str` -- The `documentId` is set by the API client. It is an integer that falls between `1` and 2,147,483,647. The value is encoded as a string without commas. The values `1`, `2`, `3`, and so on are typically used to identify the first few documents in an envelope. Tab definitions include a `documentId` property that specifies the document on which to place the tab.
      * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `page_numbers: &str` -- Filters for tabs that occur on the pages that you specify. Enter as a comma-separated list of page Guids.
-     *   
-     *   Example: `page_numbers=2,6`.
+     * * `params: &TemplateDocumentTabsTabsGetTemplateDocumentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn tabs_get_template_document(
+    pub async fn tabs_get_template_document_with(
         &self,
         account_id: &str,
         document_id: &str,
         template_id: &str,
-        page_numbers: &str,
+        params: &TemplateDocumentTabsTabsGetTemplateDocumentParams,
     ) -> Result<crate::types::TemplateDocumentTabs> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !page_numbers.is_empty() {
+        if let Some(page_numbers) = &params.page_numbers {
             query_args.push(("page_numbers".to_string(), page_numbers.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -83,6 +79,24 @@ impl TemplateDocumentTabs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `tabs_get_template_document_with` and pass the query parameters as a `TemplateDocumentTabsTabsGetTemplateDocumentParams` instead.
+     */
+    #[deprecated(note = "use `tabs_get_template_document_with` instead")]
+    pub async fn tabs_get_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        page_numbers: &str,
+    ) -> Result<crate::types::TemplateDocumentTabs> {
+        let params = TemplateDocumentTabsTabsGetTemplateDocumentParams {
+            page_numbers: Some(page_numbers.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.tabs_get_template_document_with(account_id, document_id, template_id, &params)
+            .await
+    }
+
     /**
      * Updates the tabs for a template.
      *
@@ -179,3 +193,158 @@ impl TemplateDocumentTabs {
             .await
     }
 }
+
+/// The query parameters of `TemplateDocumentTabs::tabs_get_template_document_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateDocumentTabsTabsGetTemplateDocumentParams {
+    /// Filters for tabs that occur on the pages that you specify. Enter as a comma-separated list of page Guids.
+    ///
+    /// Example: `page_numbers=2,6`.
+    pub page_numbers: Option<String>,
+}
+
+impl TemplateDocumentTabsTabsGetTemplateDocumentParams {
+    pub fn page_numbers<T: ToString>(mut self, page_numbers: T) -> Self {
+        self.page_numbers = Some(page_numbers.to_string());
+        self
+    }
+}
+
+/// The functions of [`TemplateDocumentTabs`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateDocumentTabsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateDocumentTabsApi: Send + Sync {
+    /// See [`TemplateDocumentTabs::tabs_get_template_page`].
+    async fn tabs_get_template_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+    ) -> Result<crate::types::TemplateDocumentTabs>;
+
+    /// See [`TemplateDocumentTabs::tabs_get_template_document_with`].
+    async fn tabs_get_template_document_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        params: &TemplateDocumentTabsTabsGetTemplateDocumentParams,
+    ) -> Result<crate::types::TemplateDocumentTabs>;
+
+    /// See [`TemplateDocumentTabs::tabs_put_template_document`].
+    async fn tabs_put_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs>;
+
+    /// See [`TemplateDocumentTabs::tabs_post_template_document`].
+    async fn tabs_post_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs>;
+
+    /// See [`TemplateDocumentTabs::tabs_delete_template_document`].
+    async fn tabs_delete_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs>;
+}
+
+#[async_trait::async_trait]
+impl TemplateDocumentTabsApi for TemplateDocumentTabs {
+    async fn tabs_get_template_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+    ) -> Result<crate::types::TemplateDocumentTabs> {
+        TemplateDocumentTabs::tabs_get_template_page(
+            self,
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+        )
+        .await
+    }
+
+    async fn tabs_get_template_document_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        params: &TemplateDocumentTabsTabsGetTemplateDocumentParams,
+    ) -> Result<crate::types::TemplateDocumentTabs> {
+        TemplateDocumentTabs::tabs_get_template_document_with(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            params,
+        )
+        .await
+    }
+
+    async fn tabs_put_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        TemplateDocumentTabs::tabs_put_template_document(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+
+    async fn tabs_post_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        TemplateDocumentTabs::tabs_post_template_document(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+
+    async fn tabs_delete_template_document(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        TemplateDocumentTabs::tabs_delete_template_document(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateDocumentVisibility {
     pub client: Client,
@@ -107,3 +105,78 @@ impl TemplateDocumentVisibility {
             .await
     }
 }
+
+/// The functions of [`TemplateDocumentVisibility`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateDocumentVisibilityApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateDocumentVisibilityApi: Send + Sync {
+    /// See [`TemplateDocumentVisibility::recipients_put`].
+    async fn recipients_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> Result<crate::types::TemplateDocumentVisibilityList>;
+
+    /// See [`TemplateDocumentVisibility::recipients_get_template_recipient_document_visibility`].
+    async fn recipients_get_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentVisibilityList>;
+
+    /// See [`TemplateDocumentVisibility::recipients_put_template_recipient_document_visibility`].
+    async fn recipients_put_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> Result<crate::types::TemplateDocumentVisibilityList>;
+}
+
+#[async_trait::async_trait]
+impl TemplateDocumentVisibilityApi for TemplateDocumentVisibility {
+    async fn recipients_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> Result<crate::types::TemplateDocumentVisibilityList> {
+        TemplateDocumentVisibility::recipients_put(self, account_id, template_id, body).await
+    }
+
+    async fn recipients_get_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentVisibilityList> {
+        TemplateDocumentVisibility::recipients_get_template_recipient_document_visibility(
+            self,
+            account_id,
+            recipient_id,
+            template_id,
+        )
+        .await
+    }
+
+    async fn recipients_put_template_recipient_document_visibility(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateDocumentVisibilityList,
+    ) -> Result<crate::types::TemplateDocumentVisibilityList> {
+        TemplateDocumentVisibility::recipients_put_template_recipient_document_visibility(
+            self,
+            account_id,
+            recipient_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+}
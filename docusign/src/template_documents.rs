@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateDocuments {
     pub client: Client,
@@ -23,16 +21,16 @@ impl TemplateDocuments {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_tabs: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &TemplateDocumentsDocumentsGetTemplateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn documents_get_template(
+    pub async fn documents_get_template_with(
         &self,
         account_id: &str,
         template_id: &str,
-        include_tabs: &str,
+        params: &TemplateDocumentsDocumentsGetTemplateParams,
     ) -> Result<crate::types::TemplateDocumentsResult> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_tabs.is_empty() {
+        if let Some(include_tabs) = &params.include_tabs {
             query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -46,6 +44,23 @@ impl TemplateDocuments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `documents_get_template_with` and pass the query parameters as a `TemplateDocumentsDocumentsGetTemplateParams` instead.
+     */
+    #[deprecated(note = "use `documents_get_template_with` instead")]
+    pub async fn documents_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include_tabs: &str,
+    ) -> Result<crate::types::TemplateDocumentsResult> {
+        let params = TemplateDocumentsDocumentsGetTemplateParams {
+            include_tabs: Some(include_tabs.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.documents_get_template_with(account_id, template_id, &params)
+            .await
+    }
+
     /**
      * Adds documents to a template document.
      *
@@ -132,9 +147,40 @@ impl TemplateDocuments {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `document_id: &str` -- The `documentId` is set by the API client. It is an integer that falls between `1` and 2,147,483,647. The value is encoded as a string without commas. The values `1`, `2`, `3`, and so on are typically used to identify the first few documents in an envelope. Tab definitions include a `documentId` property that specifies the document on which to place the tab.
      * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `encrypt: &str` -- When set to **true**, the PDF bytes returned in the response are encrypted for all the key managers configured on your DocuSign account. You can decrypt the documents by using the Key Manager DecryptDocument API method. For more information about Key Manager, see the DocuSign Security Appliance Installation Guide that your organization received from DocuSign.
-     * * `show_changes: &str` -- When set to **true**, any document fields that a recipient changed are highlighted in yellow in the returned PDF document, and optional signatures or initials are outlined in red.
+     * * `params: &TemplateDocumentsDocumentsGetParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn documents_get_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        params: &TemplateDocumentsDocumentsGetParams,
+    ) -> Result<crate::Download> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(encrypt) = &params.encrypt {
+            query_args.push(("encrypt".to_string(), encrypt.to_string()));
+        }
+        if let Some(show_changes) = &params.show_changes {
+            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}?{}",
+            crate::progenitor_support::encode_path(account_id),
+            crate::progenitor_support::encode_path(template_id),
+            crate::progenitor_support::encode_path(document_id),
+            query_
+        );
+
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `documents_get_with` and pass the query parameters as a `TemplateDocumentsDocumentsGetParams` instead.
+     */
+    #[deprecated(note = "use `documents_get_with` instead")]
     pub async fn documents_get(
         &self,
         account_id: &str,
@@ -142,12 +188,42 @@ impl TemplateDocuments {
         template_id: &str,
         encrypt: &str,
         show_changes: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
+        let params = TemplateDocumentsDocumentsGetParams {
+            encrypt: Some(encrypt.to_string()).filter(|s| !s.is_empty()),
+            show_changes: Some(show_changes.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.documents_get_with(account_id, document_id, template_id, &params)
+            .await
+    }
+
+    /**
+     * Gets PDF documents from a template.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}` endpoint.
+     *
+     * As opposed to `documents_get`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * This method retrieves one or more PDF documents from the template that you specify.
+     *
+     * You can specify the ID of the document to retrieve, or pass in the value `combined` to retrieve all documents in the template as a single PDF file.
+     *
+     * **Parameters:**
+     *
+     * * `params: &TemplateDocumentsDocumentsGetParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn documents_get_stream_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        params: &TemplateDocumentsDocumentsGetParams,
+    ) -> Result<crate::DownloadStream> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !encrypt.is_empty() {
+        if let Some(encrypt) = &params.encrypt {
             query_args.push(("encrypt".to_string(), encrypt.to_string()));
         }
-        if !show_changes.is_empty() {
+        if let Some(show_changes) = &params.show_changes {
             query_args.push(("show_changes".to_string(), show_changes.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -159,7 +235,29 @@ impl TemplateDocuments {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `documents_get_stream_with` and pass the query parameters as a `TemplateDocumentsDocumentsGetParams` instead.
+     */
+    #[deprecated(note = "use `documents_get_stream_with` instead")]
+    pub async fn documents_get_stream(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        encrypt: &str,
+        show_changes: &str,
+    ) -> Result<crate::DownloadStream> {
+        let params = TemplateDocumentsDocumentsGetParams {
+            encrypt: Some(encrypt.to_string()).filter(|s| !s.is_empty()),
+            show_changes: Some(show_changes.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.documents_get_stream_with(account_id, document_id, template_id, &params)
+            .await
     }
 
     /**
@@ -174,18 +272,18 @@ impl TemplateDocuments {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `document_id: &str` -- The `documentId` is set by the API client. It is an integer that falls between `1` and 2,147,483,647. The value is encoded as a string without commas. The values `1`, `2`, `3`, and so on are typically used to identify the first few documents in an envelope. Tab definitions include a `documentId` property that specifies the document on which to place the tab.
      * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `is_envelope_definition: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &TemplateDocumentsDocumentsPutParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn documents_put(
+    pub async fn documents_put_with(
         &self,
         account_id: &str,
         document_id: &str,
         template_id: &str,
-        is_envelope_definition: &str,
+        params: &TemplateDocumentsDocumentsPutParams,
         body: &crate::types::EnvelopeDefinition,
     ) -> Result<crate::types::EnvelopeDocument> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !is_envelope_definition.is_empty() {
+        if let Some(is_envelope_definition) = &params.is_envelope_definition {
             query_args.push((
                 "is_envelope_definition".to_string(),
                 is_envelope_definition.to_string(),
@@ -204,4 +302,208 @@ impl TemplateDocuments {
             .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `documents_put_with` and pass the query parameters as a `TemplateDocumentsDocumentsPutParams` instead.
+     */
+    #[deprecated(note = "use `documents_put_with` instead")]
+    pub async fn documents_put(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        is_envelope_definition: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocument> {
+        let params = TemplateDocumentsDocumentsPutParams {
+            is_envelope_definition: Some(is_envelope_definition.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.documents_put_with(account_id, document_id, template_id, &params, body)
+            .await
+    }
+}
+
+/// The query parameters of `TemplateDocuments::documents_get_template_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateDocumentsDocumentsGetTemplateParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub include_tabs: Option<String>,
+}
+
+impl TemplateDocumentsDocumentsGetTemplateParams {
+    pub fn include_tabs<T: ToString>(mut self, include_tabs: T) -> Self {
+        self.include_tabs = Some(include_tabs.to_string());
+        self
+    }
+}
+
+/// The query parameters of `TemplateDocuments::documents_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateDocumentsDocumentsGetParams {
+    /// When set to **true**, the PDF bytes returned in the response are encrypted for all the key managers configured on your DocuSign account. You can decrypt the documents by using the Key Manager DecryptDocument API method. For more information about Key Manager, see the DocuSign Security Appliance Installation Guide that your organization received from DocuSign.
+    pub encrypt: Option<String>,
+    /// When set to **true**, any document fields that a recipient changed are highlighted in yellow in the returned PDF document, and optional signatures or initials are outlined in red.
+    pub show_changes: Option<String>,
+}
+
+impl TemplateDocumentsDocumentsGetParams {
+    pub fn encrypt<T: ToString>(mut self, encrypt: T) -> Self {
+        self.encrypt = Some(encrypt.to_string());
+        self
+    }
+
+    pub fn show_changes<T: ToString>(mut self, show_changes: T) -> Self {
+        self.show_changes = Some(show_changes.to_string());
+        self
+    }
+}
+
+/// The query parameters of `TemplateDocuments::documents_put_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateDocumentsDocumentsPutParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub is_envelope_definition: Option<String>,
+}
+
+impl TemplateDocumentsDocumentsPutParams {
+    pub fn is_envelope_definition<T: ToString>(mut self, is_envelope_definition: T) -> Self {
+        self.is_envelope_definition = Some(is_envelope_definition.to_string());
+        self
+    }
+}
+
+/// The functions of [`TemplateDocuments`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateDocumentsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateDocumentsApi: Send + Sync {
+    /// See [`TemplateDocuments::documents_get_template_with`].
+    async fn documents_get_template_with(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        params: &TemplateDocumentsDocumentsGetTemplateParams,
+    ) -> Result<crate::types::TemplateDocumentsResult>;
+
+    /// See [`TemplateDocuments::documents_put_template`].
+    async fn documents_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::TemplateDocumentsResult>;
+
+    /// See [`TemplateDocuments::documents_delete_template`].
+    async fn documents_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::TemplateDocumentsResult>;
+
+    /// See [`TemplateDocuments::documents_get_with`].
+    async fn documents_get_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        params: &TemplateDocumentsDocumentsGetParams,
+    ) -> Result<crate::Download>;
+
+    /// See [`TemplateDocuments::documents_get_stream_with`].
+    async fn documents_get_stream_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        params: &TemplateDocumentsDocumentsGetParams,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`TemplateDocuments::documents_put_with`].
+    async fn documents_put_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        params: &TemplateDocumentsDocumentsPutParams,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocument>;
+}
+
+#[async_trait::async_trait]
+impl TemplateDocumentsApi for TemplateDocuments {
+    async fn documents_get_template_with(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        params: &TemplateDocumentsDocumentsGetTemplateParams,
+    ) -> Result<crate::types::TemplateDocumentsResult> {
+        TemplateDocuments::documents_get_template_with(self, account_id, template_id, params).await
+    }
+
+    async fn documents_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::TemplateDocumentsResult> {
+        TemplateDocuments::documents_put_template(self, account_id, template_id, body).await
+    }
+
+    async fn documents_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::TemplateDocumentsResult> {
+        TemplateDocuments::documents_delete_template(self, account_id, template_id, body).await
+    }
+
+    async fn documents_get_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        params: &TemplateDocumentsDocumentsGetParams,
+    ) -> Result<crate::Download> {
+        TemplateDocuments::documents_get_with(self, account_id, document_id, template_id, params)
+            .await
+    }
+
+    async fn documents_get_stream_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        params: &TemplateDocumentsDocumentsGetParams,
+    ) -> Result<crate::DownloadStream> {
+        TemplateDocuments::documents_get_stream_with(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            params,
+        )
+        .await
+    }
+
+    async fn documents_put_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        params: &TemplateDocumentsDocumentsPutParams,
+        body: &crate::types::EnvelopeDefinition,
+    ) -> Result<crate::types::EnvelopeDocument> {
+        TemplateDocuments::documents_put_with(
+            self,
+            account_id,
+            document_id,
+            template_id,
+            params,
+            body,
+        )
+        .await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateHtmlDefinitions {
     pub client: Client,
@@ -38,3 +36,32 @@ impl TemplateHtmlDefinitions {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`TemplateHtmlDefinitions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateHtmlDefinitionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateHtmlDefinitionsApi: Send + Sync {
+    /// See [`TemplateHtmlDefinitions::responsive_html_get_template_definition`].
+    async fn responsive_html_get_template_definition(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentHtmlDefinitionOriginals>;
+}
+
+#[async_trait::async_trait]
+impl TemplateHtmlDefinitionsApi for TemplateHtmlDefinitions {
+    async fn responsive_html_get_template_definition(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::DocumentHtmlDefinitionOriginals> {
+        TemplateHtmlDefinitions::responsive_html_get_template_definition(
+            self,
+            account_id,
+            template_id,
+        )
+        .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateLocks {
     pub client: Client,
@@ -233,3 +231,78 @@ impl TemplateLocks {
             .await
     }
 }
+
+/// The functions of [`TemplateLocks`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateLocksApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateLocksApi: Send + Sync {
+    /// See [`TemplateLocks::lock_get_template`].
+    async fn lock_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::LockInformation>;
+
+    /// See [`TemplateLocks::lock_put_template`].
+    async fn lock_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::LockInformation>;
+
+    /// See [`TemplateLocks::lock_post_template`].
+    async fn lock_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::LockInformation>;
+
+    /// See [`TemplateLocks::lock_delete_template`].
+    async fn lock_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::LockInformation>;
+}
+
+#[async_trait::async_trait]
+impl TemplateLocksApi for TemplateLocks {
+    async fn lock_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::LockInformation> {
+        TemplateLocks::lock_get_template(self, account_id, template_id).await
+    }
+
+    async fn lock_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::LockInformation> {
+        TemplateLocks::lock_put_template(self, account_id, template_id, body).await
+    }
+
+    async fn lock_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::LockInformation> {
+        TemplateLocks::lock_post_template(self, account_id, template_id, body).await
+    }
+
+    async fn lock_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::LockRequest,
+    ) -> Result<crate::types::LockInformation> {
+        TemplateLocks::lock_delete_template(self, account_id, template_id, body).await
+    }
+}
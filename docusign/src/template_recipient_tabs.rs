@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateRecipientTabs {
     pub client: Client,
@@ -24,25 +22,23 @@ impl TemplateRecipientTabs {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `recipient_id: &str` -- A local reference that senders use to map recipients to other objects, such as specific document tabs. Within an envelope, each `recipientId` must be unique, but there is no uniqueness requirement across envelopes. For example, many envelopes assign the first recipient a `recipientId` of `1`.
      * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_anchor_tab_locations: &str` -- When set to **true**, all tabs with anchor tab properties are included in the response. The default value is **false**.
-     * * `include_metadata: &str` -- When set to **true**, the response includes metadata indicating which properties are editable.
+     * * `params: &TemplateRecipientTabsRecipientsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recipients_get(
+    pub async fn recipients_get_with(
         &self,
         account_id: &str,
         recipient_id: &str,
         template_id: &str,
-        include_anchor_tab_locations: &str,
-        include_metadata: &str,
+        params: &TemplateRecipientTabsRecipientsGetParams,
     ) -> Result<crate::types::Tabs> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_anchor_tab_locations.is_empty() {
+        if let Some(include_anchor_tab_locations) = &params.include_anchor_tab_locations {
             query_args.push((
                 "include_anchor_tab_locations".to_string(),
                 include_anchor_tab_locations.to_string(),
             ));
         }
-        if !include_metadata.is_empty() {
+        if let Some(include_metadata) = &params.include_metadata {
             query_args.push(("include_metadata".to_string(), include_metadata.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -57,6 +53,27 @@ impl TemplateRecipientTabs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `recipients_get_with` and pass the query parameters as a `TemplateRecipientTabsRecipientsGetParams` instead.
+     */
+    #[deprecated(note = "use `recipients_get_with` instead")]
+    pub async fn recipients_get(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        include_anchor_tab_locations: &str,
+        include_metadata: &str,
+    ) -> Result<crate::types::Tabs> {
+        let params = TemplateRecipientTabsRecipientsGetParams {
+            include_anchor_tab_locations: Some(include_anchor_tab_locations.to_string())
+                .filter(|s| !s.is_empty()),
+            include_metadata: Some(include_metadata.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipients_get_with(account_id, recipient_id, template_id, &params)
+            .await
+    }
+
     /**
      * Updates the tabs for a recipient.
      *
@@ -153,3 +170,122 @@ impl TemplateRecipientTabs {
             .await
     }
 }
+
+/// The query parameters of `TemplateRecipientTabs::recipients_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateRecipientTabsRecipientsGetParams {
+    /// When set to **true**, all tabs with anchor tab properties are included in the response. The default value is **false**.
+    pub include_anchor_tab_locations: Option<String>,
+    /// When set to **true**, the response includes metadata indicating which properties are editable.
+    pub include_metadata: Option<String>,
+}
+
+impl TemplateRecipientTabsRecipientsGetParams {
+    pub fn include_anchor_tab_locations<T: ToString>(
+        mut self,
+        include_anchor_tab_locations: T,
+    ) -> Self {
+        self.include_anchor_tab_locations = Some(include_anchor_tab_locations.to_string());
+        self
+    }
+
+    pub fn include_metadata<T: ToString>(mut self, include_metadata: T) -> Self {
+        self.include_metadata = Some(include_metadata.to_string());
+        self
+    }
+}
+
+/// The functions of [`TemplateRecipientTabs`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateRecipientTabsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateRecipientTabsApi: Send + Sync {
+    /// See [`TemplateRecipientTabs::recipients_get_with`].
+    async fn recipients_get_with(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        params: &TemplateRecipientTabsRecipientsGetParams,
+    ) -> Result<crate::types::Tabs>;
+
+    /// See [`TemplateRecipientTabs::recipients_put`].
+    async fn recipients_put(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs>;
+
+    /// See [`TemplateRecipientTabs::recipients_post`].
+    async fn recipients_post(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs>;
+
+    /// See [`TemplateRecipientTabs::recipients_delete`].
+    async fn recipients_delete(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs>;
+}
+
+#[async_trait::async_trait]
+impl TemplateRecipientTabsApi for TemplateRecipientTabs {
+    async fn recipients_get_with(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        params: &TemplateRecipientTabsRecipientsGetParams,
+    ) -> Result<crate::types::Tabs> {
+        TemplateRecipientTabs::recipients_get_with(
+            self,
+            account_id,
+            recipient_id,
+            template_id,
+            params,
+        )
+        .await
+    }
+
+    async fn recipients_put(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        TemplateRecipientTabs::recipients_put(self, account_id, recipient_id, template_id, body)
+            .await
+    }
+
+    async fn recipients_post(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        TemplateRecipientTabs::recipients_post(self, account_id, recipient_id, template_id, body)
+            .await
+    }
+
+    async fn recipients_delete(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateTabs,
+    ) -> Result<crate::types::Tabs> {
+        TemplateRecipientTabs::recipients_delete(self, account_id, recipient_id, template_id, body)
+            .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateRecipients {
     pub client: Client,
@@ -23,29 +21,25 @@ impl TemplateRecipients {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_anchor_tab_locations: &str` --  When set to **true** and `include_tabs` is set to **true**, all tabs with anchor tab properties are included in the response. .
-     * * `include_extended: &str` --  When set to **true**, the extended properties are included in the response. .
-     * * `include_tabs: &str` -- When set to **true**, the tab information associated with the recipient is included in the response.
+     * * `params: &TemplateRecipientsRecipientsGetTemplateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recipients_get_template(
+    pub async fn recipients_get_template_with(
         &self,
         account_id: &str,
         template_id: &str,
-        include_anchor_tab_locations: &str,
-        include_extended: &str,
-        include_tabs: &str,
+        params: &TemplateRecipientsRecipientsGetTemplateParams,
     ) -> Result<crate::types::Recipients> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_anchor_tab_locations.is_empty() {
+        if let Some(include_anchor_tab_locations) = &params.include_anchor_tab_locations {
             query_args.push((
                 "include_anchor_tab_locations".to_string(),
                 include_anchor_tab_locations.to_string(),
             ));
         }
-        if !include_extended.is_empty() {
+        if let Some(include_extended) = &params.include_extended {
             query_args.push(("include_extended".to_string(), include_extended.to_string()));
         }
-        if !include_tabs.is_empty() {
+        if let Some(include_tabs) = &params.include_tabs {
             query_args.push(("include_tabs".to_string(), include_tabs.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -59,6 +53,28 @@ impl TemplateRecipients {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `recipients_get_template_with` and pass the query parameters as a `TemplateRecipientsRecipientsGetTemplateParams` instead.
+     */
+    #[deprecated(note = "use `recipients_get_template_with` instead")]
+    pub async fn recipients_get_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include_anchor_tab_locations: &str,
+        include_extended: &str,
+        include_tabs: &str,
+    ) -> Result<crate::types::Recipients> {
+        let params = TemplateRecipientsRecipientsGetTemplateParams {
+            include_anchor_tab_locations: Some(include_anchor_tab_locations.to_string())
+                .filter(|s| !s.is_empty()),
+            include_extended: Some(include_extended.to_string()).filter(|s| !s.is_empty()),
+            include_tabs: Some(include_tabs.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipients_get_template_with(account_id, template_id, &params)
+            .await
+    }
+
     /**
      * Updates recipients in a template.
      *
@@ -72,19 +88,17 @@ impl TemplateRecipients {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `resend_envelope: &str` -- When set to **true**, resends the envelope to the recipients that you specify in the request body. You use this parameter to resend the envelope to a recipient who deleted the original email notification.
-     *   
-     *   **Note**: Correcting an envelope is a different process. DocuSign always resends an envelope when you correct it, regardless of the value that you enter here.
+     * * `params: &TemplateRecipientsRecipientsPutTemplateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recipients_put_template(
+    pub async fn recipients_put_template_with(
         &self,
         account_id: &str,
         template_id: &str,
-        resend_envelope: &str,
+        params: &TemplateRecipientsRecipientsPutTemplateParams,
         body: &crate::types::TemplateRecipientsData,
     ) -> Result<crate::types::RecipientsUpdateSummary> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !resend_envelope.is_empty() {
+        if let Some(resend_envelope) = &params.resend_envelope {
             query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -100,6 +114,24 @@ impl TemplateRecipients {
             .await
     }
 
+    /**
+     * Deprecated: use `recipients_put_template_with` and pass the query parameters as a `TemplateRecipientsRecipientsPutTemplateParams` instead.
+     */
+    #[deprecated(note = "use `recipients_put_template_with` instead")]
+    pub async fn recipients_put_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::RecipientsUpdateSummary> {
+        let params = TemplateRecipientsRecipientsPutTemplateParams {
+            resend_envelope: Some(resend_envelope.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipients_put_template_with(account_id, template_id, &params, body)
+            .await
+    }
+
     /**
      * Adds tabs for a recipient.
      *
@@ -111,19 +143,17 @@ impl TemplateRecipients {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `resend_envelope: &str` -- When set to **true**, resends the envelope to the recipients that you specify in the request body. You use this parameter to resend the envelope to a recipient who deleted the original email notification.
-     *   
-     *   **Note**: Correcting an envelope is a different process. DocuSign always resends an envelope when you correct it, regardless of the value that you enter here.
+     * * `params: &TemplateRecipientsRecipientsPostTemplateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recipients_post_template(
+    pub async fn recipients_post_template_with(
         &self,
         account_id: &str,
         template_id: &str,
-        resend_envelope: &str,
+        params: &TemplateRecipientsRecipientsPostTemplateParams,
         body: &crate::types::TemplateRecipientsData,
     ) -> Result<crate::types::Recipients> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !resend_envelope.is_empty() {
+        if let Some(resend_envelope) = &params.resend_envelope {
             query_args.push(("resend_envelope".to_string(), resend_envelope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -139,6 +169,24 @@ impl TemplateRecipients {
             .await
     }
 
+    /**
+     * Deprecated: use `recipients_post_template_with` and pass the query parameters as a `TemplateRecipientsRecipientsPostTemplateParams` instead.
+     */
+    #[deprecated(note = "use `recipients_post_template_with` instead")]
+    pub async fn recipients_post_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        resend_envelope: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients> {
+        let params = TemplateRecipientsRecipientsPostTemplateParams {
+            resend_envelope: Some(resend_envelope.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recipients_post_template_with(account_id, template_id, &params, body)
+            .await
+    }
+
     /**
      * Deletes recipients from a template.
      *
@@ -231,3 +279,199 @@ impl TemplateRecipients {
             .await
     }
 }
+
+/// The query parameters of `TemplateRecipients::recipients_get_template_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateRecipientsRecipientsGetTemplateParams {
+    ///  When set to **true** and `include_tabs` is set to **true**, all tabs with anchor tab properties are included in the response. .
+    pub include_anchor_tab_locations: Option<String>,
+    ///  When set to **true**, the extended properties are included in the response. .
+    pub include_extended: Option<String>,
+    /// When set to **true**, the tab information associated with the recipient is included in the response.
+    pub include_tabs: Option<String>,
+}
+
+impl TemplateRecipientsRecipientsGetTemplateParams {
+    pub fn include_anchor_tab_locations<T: ToString>(
+        mut self,
+        include_anchor_tab_locations: T,
+    ) -> Self {
+        self.include_anchor_tab_locations = Some(include_anchor_tab_locations.to_string());
+        self
+    }
+
+    pub fn include_extended<T: ToString>(mut self, include_extended: T) -> Self {
+        self.include_extended = Some(include_extended.to_string());
+        self
+    }
+
+    pub fn include_tabs<T: ToString>(mut self, include_tabs: T) -> Self {
+        self.include_tabs = Some(include_tabs.to_string());
+        self
+    }
+}
+
+/// The query parameters of `TemplateRecipients::recipients_put_template_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateRecipientsRecipientsPutTemplateParams {
+    /// When set to **true**, resends the envelope to the recipients that you specify in the request body. You use this parameter to resend the envelope to a recipient who deleted the original email notification.
+    ///
+    /// **Note**: Correcting an envelope is a different process. DocuSign always resends an envelope when you correct it, regardless of the value that you enter here.
+    pub resend_envelope: Option<String>,
+}
+
+impl TemplateRecipientsRecipientsPutTemplateParams {
+    pub fn resend_envelope<T: ToString>(mut self, resend_envelope: T) -> Self {
+        self.resend_envelope = Some(resend_envelope.to_string());
+        self
+    }
+}
+
+/// The query parameters of `TemplateRecipients::recipients_post_template_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateRecipientsRecipientsPostTemplateParams {
+    /// When set to **true**, resends the envelope to the recipients that you specify in the request body. You use this parameter to resend the envelope to a recipient who deleted the original email notification.
+    ///
+    /// **Note**: Correcting an envelope is a different process. DocuSign always resends an envelope when you correct it, regardless of the value that you enter here.
+    pub resend_envelope: Option<String>,
+}
+
+impl TemplateRecipientsRecipientsPostTemplateParams {
+    pub fn resend_envelope<T: ToString>(mut self, resend_envelope: T) -> Self {
+        self.resend_envelope = Some(resend_envelope.to_string());
+        self
+    }
+}
+
+/// The functions of [`TemplateRecipients`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateRecipientsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateRecipientsApi: Send + Sync {
+    /// See [`TemplateRecipients::recipients_get_template_with`].
+    async fn recipients_get_template_with(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        params: &TemplateRecipientsRecipientsGetTemplateParams,
+    ) -> Result<crate::types::Recipients>;
+
+    /// See [`TemplateRecipients::recipients_put_template_with`].
+    async fn recipients_put_template_with(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        params: &TemplateRecipientsRecipientsPutTemplateParams,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::RecipientsUpdateSummary>;
+
+    /// See [`TemplateRecipients::recipients_post_template_with`].
+    async fn recipients_post_template_with(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        params: &TemplateRecipientsRecipientsPostTemplateParams,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients>;
+
+    /// See [`TemplateRecipients::recipients_delete_template`].
+    async fn recipients_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients>;
+
+    /// See [`TemplateRecipients::recipients_delete`].
+    async fn recipients_delete(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients>;
+
+    /// See [`TemplateRecipients::views_post_preview`].
+    async fn views_post_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> Result<crate::types::ViewUrl>;
+}
+
+#[async_trait::async_trait]
+impl TemplateRecipientsApi for TemplateRecipients {
+    async fn recipients_get_template_with(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        params: &TemplateRecipientsRecipientsGetTemplateParams,
+    ) -> Result<crate::types::Recipients> {
+        TemplateRecipients::recipients_get_template_with(self, account_id, template_id, params)
+            .await
+    }
+
+    async fn recipients_put_template_with(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        params: &TemplateRecipientsRecipientsPutTemplateParams,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::RecipientsUpdateSummary> {
+        TemplateRecipients::recipients_put_template_with(
+            self,
+            account_id,
+            template_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn recipients_post_template_with(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        params: &TemplateRecipientsRecipientsPostTemplateParams,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients> {
+        TemplateRecipients::recipients_post_template_with(
+            self,
+            account_id,
+            template_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn recipients_delete_template(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients> {
+        TemplateRecipients::recipients_delete_template(self, account_id, template_id, body).await
+    }
+
+    async fn recipients_delete(
+        &self,
+        account_id: &str,
+        recipient_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateRecipientsData,
+    ) -> Result<crate::types::Recipients> {
+        TemplateRecipients::recipients_delete(self, account_id, recipient_id, template_id, body)
+            .await
+    }
+
+    async fn views_post_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::RecipientPreviewRequest,
+    ) -> Result<crate::types::ViewUrl> {
+        TemplateRecipients::views_post_preview(self, account_id, template_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateResponsiveHtmlPreview {
     pub client: Client,
@@ -51,3 +49,35 @@ impl TemplateResponsiveHtmlPreview {
             .await
     }
 }
+
+/// The functions of [`TemplateResponsiveHtmlPreview`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateResponsiveHtmlPreviewApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateResponsiveHtmlPreviewApi: Send + Sync {
+    /// See [`TemplateResponsiveHtmlPreview::responsive_html_post_template_preview`].
+    async fn responsive_html_post_template_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions>;
+}
+
+#[async_trait::async_trait]
+impl TemplateResponsiveHtmlPreviewApi for TemplateResponsiveHtmlPreview {
+    async fn responsive_html_post_template_preview(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::DocumentHtmlDefinition,
+    ) -> Result<crate::types::DocumentHtmlDefinitions> {
+        TemplateResponsiveHtmlPreview::responsive_html_post_template_preview(
+            self,
+            account_id,
+            template_id,
+            body,
+        )
+        .await
+    }
+}
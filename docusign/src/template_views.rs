@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TemplateViews {
     pub client: Client,
@@ -41,3 +39,29 @@ impl TemplateViews {
             .await
     }
 }
+
+/// The functions of [`TemplateViews`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplateViewsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplateViewsApi: Send + Sync {
+    /// See [`TemplateViews::views_post_template_edit_view`].
+    async fn views_post_template_edit_view(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::ViewUrl>;
+}
+
+#[async_trait::async_trait]
+impl TemplateViewsApi for TemplateViews {
+    async fn views_post_template_edit_view(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::ReturnUrlRequest,
+    ) -> Result<crate::types::ViewUrl> {
+        TemplateViews::views_post_template_edit_view(self, account_id, template_id, body).await
+    }
+}
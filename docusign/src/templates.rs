@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Templates {
     pub client: Client,
@@ -22,179 +20,90 @@ impl Templates {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `created_from_date: &str` -- The billing period end date in UTC timedate format.
-     * * `created_to_date: &str` -- The billing period end date in UTC timedate format.
-     * * `folder_ids: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `folder_types: &str` -- The type of folder to return templates for. Possible values are:
-     *   
-     *   - `templates`: Templates in the **My Templates** folder.
-     *     Templates in the **Shared Templates**  and **All Template** folders (if the request id from and Admin) are excluded.
-     *   - `templates_root`: Templates in the root level of the **My Templates** folder, but not in an actual folder. Note that the **My Templates** folder is not a real folder.
-     *   - `recylebin`: Templates that have been deleted.
-     *   .
-     * * `from_date: &str` -- Start of the search date range. Only returns templates created on or after this date/time. If no value is specified, there is no limit on the earliest date created.
-     * * `include: &str` -- A comma-separated list
-     *   of additional template attributes
-     *   to include in the response.
-     *   Valid values are:
-     *   
-     *   - `powerforms`: Includes details about the PowerForms associated with the templates.
-     *   - `documents`: Includes information about template documents.
-     *   - `folders`: Includes information about the folder that holds the template.
-     *   - `favorite_template_status`: Includes the template `favoritedByMe` property. **Note**: You can mark a template as a favorite only in eSignature v2.1.
-     *   - `advanced_templates`: Includes information about advanced templates.
-     *   - `recipients`: Includes information about template recipients.
-     *   - `custom_fields`: Includes information about template custom fields.
-     *   - `notifications`: Includes information about the notification settings for templates.
-     * * `is_deleted_template_only: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `is_download: &str` -- When set to **true**, downloads the templates listed in `template_ids` as a collection of JSON definitions in a single zip file.
-     *   
-     *   The `Content-Disposition` header is set in the response. The value of the header provides the filename of the file.
-     *   
-     *   The default is **false**.
-     *   
-     *   **Note**: This parameter only works when you specify a list of templates in the `template_ids` parameter.
-     * * `modified_from_date: &str` -- The billing period end date in UTC timedate format.
-     * * `modified_to_date: &str` -- The billing period end date in UTC timedate format.
-     * * `order: &str` -- Specifies the sort order of the search results.
-     *   Valid values are:
-     *   
-     *   - `asc`: Ascending (A to Z)
-     *   - `desc`: Descending (Z to A).
-     * * `order_by: &str` -- Specifies how the search results are listed.
-     *   Valid values are:
-     *   
-     *   - `name`: template name
-     *   - `modified`: date/time template was last modified
-     *   - `used`: date/time the template was last used.
-     * * `search_fields: &str` -- A comma-separated list of additional template properties to search.
-     *   
-     *   
-     *   - `sender`: Include sender name and email in the search.
-     *   - `recipients`: Include recipient names and emails in the search.
-     *   - `envelope`: Not used in template searches.
-     *   .
-     * * `search_text: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `shared_by_me: &str` -- If **true**, the response only includes templates shared by the user. If false, the response only returns template not shared by the user. If not specified, the response is not affected.
-     * * `start_position: &str` -- The starting zero-based index position for the first template to show in the response. This value must be greater than or equal to `0` (zero).
-     * * `template_ids: &str` -- A comma-separated list of template ids to download. This value is valid only when `is_download` is **true**.
-     * * `to_date: &str` -- The end of a search date range in UTC DateTime format. When you use this parameter, only templates created up to this date and time are returned.
-     *   
-     *   **Note**: If this property is null, the value defaults to the current date.
-     * * `used_from_date: &str` -- Start of the search date range. Only returns templates used or edited on or after this date/time. If no value is specified, there is no limit on the earliest date used.
-     * * `used_to_date: &str` -- End of the search date range. Only returns templates used or edited up to this date/time. If no value is provided, this defaults to the current date.
-     * * `user_filter: &str` -- Filters the templates in the response. Valid values are:
-     *   
-     *   - `owned_by_me`: Results include only templates owned by the user.
-     *   - `shared_with_me`: Results include only templates owned by the user.
-     *   - `all`:  Results include all templates owned or shared with the user.
-     * * `user_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &TemplatesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         account_id: &str,
-        count: &str,
-        created_from_date: &str,
-        created_to_date: &str,
-        folder_ids: &str,
-        folder_types: &str,
-        from_date: &str,
-        include: &str,
-        is_deleted_template_only: &str,
-        is_download: &str,
-        modified_from_date: &str,
-        modified_to_date: &str,
-        order: &str,
-        order_by: &str,
-        search_fields: &str,
-        search_text: &str,
-        shared_by_me: &str,
-        start_position: &str,
-        template_ids: &str,
-        to_date: &str,
-        used_from_date: &str,
-        used_to_date: &str,
-        user_filter: &str,
-        user_id: &str,
+        params: &TemplatesGetParams,
     ) -> Result<crate::types::EnvelopeTemplateResults> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !created_from_date.is_empty() {
+        if let Some(created_from_date) = &params.created_from_date {
             query_args.push((
                 "created_from_date".to_string(),
                 created_from_date.to_string(),
             ));
         }
-        if !created_to_date.is_empty() {
+        if let Some(created_to_date) = &params.created_to_date {
             query_args.push(("created_to_date".to_string(), created_to_date.to_string()));
         }
-        if !folder_ids.is_empty() {
+        if let Some(folder_ids) = &params.folder_ids {
             query_args.push(("folder_ids".to_string(), folder_ids.to_string()));
         }
-        if !folder_types.is_empty() {
+        if let Some(folder_types) = &params.folder_types {
             query_args.push(("folder_types".to_string(), folder_types.to_string()));
         }
-        if !from_date.is_empty() {
+        if let Some(from_date) = &params.from_date {
             query_args.push(("from_date".to_string(), from_date.to_string()));
         }
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
-        if !is_deleted_template_only.is_empty() {
+        if let Some(is_deleted_template_only) = &params.is_deleted_template_only {
             query_args.push((
                 "is_deleted_template_only".to_string(),
                 is_deleted_template_only.to_string(),
             ));
         }
-        if !is_download.is_empty() {
+        if let Some(is_download) = &params.is_download {
             query_args.push(("is_download".to_string(), is_download.to_string()));
         }
-        if !modified_from_date.is_empty() {
+        if let Some(modified_from_date) = &params.modified_from_date {
             query_args.push((
                 "modified_from_date".to_string(),
                 modified_from_date.to_string(),
             ));
         }
-        if !modified_to_date.is_empty() {
+        if let Some(modified_to_date) = &params.modified_to_date {
             query_args.push(("modified_to_date".to_string(), modified_to_date.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !order_by.is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("order_by".to_string(), order_by.to_string()));
         }
-        if !search_fields.is_empty() {
+        if let Some(search_fields) = &params.search_fields {
             query_args.push(("search_fields".to_string(), search_fields.to_string()));
         }
-        if !search_text.is_empty() {
+        if let Some(search_text) = &params.search_text {
             query_args.push(("search_text".to_string(), search_text.to_string()));
         }
-        if !shared_by_me.is_empty() {
+        if let Some(shared_by_me) = &params.shared_by_me {
             query_args.push(("shared_by_me".to_string(), shared_by_me.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
-        if !template_ids.is_empty() {
+        if let Some(template_ids) = &params.template_ids {
             query_args.push(("template_ids".to_string(), template_ids.to_string()));
         }
-        if !to_date.is_empty() {
+        if let Some(to_date) = &params.to_date {
             query_args.push(("to_date".to_string(), to_date.to_string()));
         }
-        if !used_from_date.is_empty() {
+        if let Some(used_from_date) = &params.used_from_date {
             query_args.push(("used_from_date".to_string(), used_from_date.to_string()));
         }
-        if !used_to_date.is_empty() {
+        if let Some(used_to_date) = &params.used_to_date {
             query_args.push(("used_to_date".to_string(), used_to_date.to_string()));
         }
-        if !user_filter.is_empty() {
+        if let Some(user_filter) = &params.user_filter {
             query_args.push(("user_filter".to_string(), user_filter.to_string()));
         }
-        if !user_id.is_empty() {
+        if let Some(user_id) = &params.user_id {
             query_args.push(("user_id".to_string(), user_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -207,6 +116,66 @@ impl Templates {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `TemplatesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        account_id: &str,
+        count: &str,
+        created_from_date: &str,
+        created_to_date: &str,
+        folder_ids: &str,
+        folder_types: &str,
+        from_date: &str,
+        include: &str,
+        is_deleted_template_only: &str,
+        is_download: &str,
+        modified_from_date: &str,
+        modified_to_date: &str,
+        order: &str,
+        order_by: &str,
+        search_fields: &str,
+        search_text: &str,
+        shared_by_me: &str,
+        start_position: &str,
+        template_ids: &str,
+        to_date: &str,
+        used_from_date: &str,
+        used_to_date: &str,
+        user_filter: &str,
+        user_id: &str,
+    ) -> Result<crate::types::EnvelopeTemplateResults> {
+        let params = TemplatesGetParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            created_from_date: Some(created_from_date.to_string()).filter(|s| !s.is_empty()),
+            created_to_date: Some(created_to_date.to_string()).filter(|s| !s.is_empty()),
+            folder_ids: Some(folder_ids.to_string()).filter(|s| !s.is_empty()),
+            folder_types: Some(folder_types.to_string()).filter(|s| !s.is_empty()),
+            from_date: Some(from_date.to_string()).filter(|s| !s.is_empty()),
+            include: Some(include.to_string()).filter(|s| !s.is_empty()),
+            is_deleted_template_only: Some(is_deleted_template_only.to_string())
+                .filter(|s| !s.is_empty()),
+            is_download: Some(is_download.to_string()).filter(|s| !s.is_empty()),
+            modified_from_date: Some(modified_from_date.to_string()).filter(|s| !s.is_empty()),
+            modified_to_date: Some(modified_to_date.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            order_by: Some(order_by.to_string()).filter(|s| !s.is_empty()),
+            search_fields: Some(search_fields.to_string()).filter(|s| !s.is_empty()),
+            search_text: Some(search_text.to_string()).filter(|s| !s.is_empty()),
+            shared_by_me: Some(shared_by_me.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+            template_ids: Some(template_ids.to_string()).filter(|s| !s.is_empty()),
+            to_date: Some(to_date.to_string()).filter(|s| !s.is_empty()),
+            used_from_date: Some(used_from_date.to_string()).filter(|s| !s.is_empty()),
+            used_to_date: Some(used_to_date.to_string()).filter(|s| !s.is_empty()),
+            user_filter: Some(user_filter.to_string()).filter(|s| !s.is_empty()),
+            user_id: Some(user_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(account_id, &params).await
+    }
+
     /**
      * Creates one or more templates.
      *
@@ -294,24 +263,16 @@ impl Templates {
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include: &str` -- A comma-separated list
-     *   of additional template attributes
-     *   to include in the response.
-     *   Valid values are:
-     *   
-     *   - `powerforms`: Includes information about PowerForms.
-     *   - `tabs`: Includes information about tabs.
-     *   - `documents`: Includes information about documents.
-     *   - `favorite_template_status`: : Includes the template `favoritedByMe` property in the response. **Note**: You can mark a template as a favorite only in eSignature v2.1.
+     * * `params: &TemplatesGetTemplatesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_templates(
+    pub async fn get_templates_with(
         &self,
         account_id: &str,
         template_id: &str,
-        include: &str,
+        params: &TemplatesGetTemplatesParams,
     ) -> Result<crate::types::EnvelopeTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -325,6 +286,23 @@ impl Templates {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_templates_with` and pass the query parameters as a `TemplatesGetTemplatesParams` instead.
+     */
+    #[deprecated(note = "use `get_templates_with` instead")]
+    pub async fn get_templates(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        include: &str,
+    ) -> Result<crate::types::EnvelopeTemplate> {
+        let params = TemplatesGetTemplatesParams {
+            include: Some(include.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_templates_with(account_id, template_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing template.
      *
@@ -366,47 +344,35 @@ impl Templates {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `document_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `dpi: &str` -- The number of dots per inch (DPI) for the resulting images. Valid values are 1-310 DPI. The default value is 94.
-     * * `max_height: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `max_width: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `nocache: &str` -- If **true**, using cache is disabled and image information is retrieved from a database. **True** is the default value. .
-     * * `show_changes: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
+     * * `params: &TemplatesPagesGetPageImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn pages_get_page_image(
+    pub async fn pages_get_page_image_with(
         &self,
         account_id: &str,
         document_id: &str,
         template_id: &str,
-        count: &str,
-        dpi: &str,
-        max_height: &str,
-        max_width: &str,
-        nocache: &str,
-        show_changes: &str,
-        start_position: &str,
+        params: &TemplatesPagesGetPageImageParams,
     ) -> Result<crate::types::PageImages> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !dpi.is_empty() {
+        if let Some(dpi) = &params.dpi {
             query_args.push(("dpi".to_string(), dpi.to_string()));
         }
-        if !max_height.is_empty() {
+        if let Some(max_height) = &params.max_height {
             query_args.push(("max_height".to_string(), max_height.to_string()));
         }
-        if !max_width.is_empty() {
+        if let Some(max_width) = &params.max_width {
             query_args.push(("max_width".to_string(), max_width.to_string()));
         }
-        if !nocache.is_empty() {
+        if let Some(nocache) = &params.nocache {
             query_args.push(("nocache".to_string(), nocache.to_string()));
         }
-        if !show_changes.is_empty() {
+        if let Some(show_changes) = &params.show_changes {
             query_args.push(("show_changes".to_string(), show_changes.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -421,6 +387,36 @@ impl Templates {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `pages_get_page_image_with` and pass the query parameters as a `TemplatesPagesGetPageImageParams` instead.
+     */
+    #[deprecated(note = "use `pages_get_page_image_with` instead")]
+    pub async fn pages_get_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        nocache: &str,
+        show_changes: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PageImages> {
+        let params = TemplatesPagesGetPageImageParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            dpi: Some(dpi.to_string()).filter(|s| !s.is_empty()),
+            max_height: Some(max_height.to_string()).filter(|s| !s.is_empty()),
+            max_width: Some(max_width.to_string()).filter(|s| !s.is_empty()),
+            nocache: Some(nocache.to_string()).filter(|s| !s.is_empty()),
+            show_changes: Some(show_changes.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.pages_get_page_image_with(account_id, document_id, template_id, &params)
+            .await
+    }
+
     /**
      * Deletes a page from a document in an template.
      *
@@ -469,11 +465,48 @@ impl Templates {
      * * `document_id: &str` -- The `documentId` is set by the API client. It is an integer that falls between `1` and 2,147,483,647. The value is encoded as a string without commas. The values `1`, `2`, `3`, and so on are typically used to identify the first few documents in an envelope. Tab definitions include a `documentId` property that specifies the document on which to place the tab.
      * * `page_number: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `template_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `dpi: &str` -- The number of dots per inch (DPI) for the resulting images. Valid values are 1-310 DPI. The default value is 94.
-     * * `max_height: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `max_width: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `show_changes: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &TemplatesPagesGetPageImageTemplatesParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn pages_get_page_image_templates_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        params: &TemplatesPagesGetPageImageTemplatesParams,
+    ) -> Result<crate::Download> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(dpi) = &params.dpi {
+            query_args.push(("dpi".to_string(), dpi.to_string()));
+        }
+        if let Some(max_height) = &params.max_height {
+            query_args.push(("max_height".to_string(), max_height.to_string()));
+        }
+        if let Some(max_width) = &params.max_width {
+            query_args.push(("max_width".to_string(), max_width.to_string()));
+        }
+        if let Some(show_changes) = &params.show_changes {
+            query_args.push(("show_changes".to_string(), show_changes.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/templates/{}/documents/{}/pages/{}/page_image?{}",
+            crate::progenitor_support::encode_path(account_id),
+            crate::progenitor_support::encode_path(template_id),
+            crate::progenitor_support::encode_path(document_id),
+            crate::progenitor_support::encode_path(page_number),
+            query_
+        );
+
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `pages_get_page_image_templates_with` and pass the query parameters as a `TemplatesPagesGetPageImageTemplatesParams` instead.
+     */
+    #[deprecated(note = "use `pages_get_page_image_templates_with` instead")]
     pub async fn pages_get_page_image_templates(
         &self,
         account_id: &str,
@@ -484,18 +517,55 @@ impl Templates {
         max_height: &str,
         max_width: &str,
         show_changes: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
+        let params = TemplatesPagesGetPageImageTemplatesParams {
+            dpi: Some(dpi.to_string()).filter(|s| !s.is_empty()),
+            max_height: Some(max_height.to_string()).filter(|s| !s.is_empty()),
+            max_width: Some(max_width.to_string()).filter(|s| !s.is_empty()),
+            show_changes: Some(show_changes.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.pages_get_page_image_templates_with(
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            &params,
+        )
+        .await
+    }
+
+    /**
+     * Gets a page image from a template for display.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/templates/{templateId}/documents/{documentId}/pages/{pageNumber}/page_image` endpoint.
+     *
+     * As opposed to `pages_get_page_image_templates`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Retrieves a page image for display from the specified template.
+     *
+     * **Parameters:**
+     *
+     * * `params: &TemplatesPagesGetPageImageTemplatesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn pages_get_page_image_templates_stream_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        params: &TemplatesPagesGetPageImageTemplatesParams,
+    ) -> Result<crate::DownloadStream> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !dpi.is_empty() {
+        if let Some(dpi) = &params.dpi {
             query_args.push(("dpi".to_string(), dpi.to_string()));
         }
-        if !max_height.is_empty() {
+        if let Some(max_height) = &params.max_height {
             query_args.push(("max_height".to_string(), max_height.to_string()));
         }
-        if !max_width.is_empty() {
+        if let Some(max_width) = &params.max_width {
             query_args.push(("max_width".to_string(), max_width.to_string()));
         }
-        if !show_changes.is_empty() {
+        if let Some(show_changes) = &params.show_changes {
             query_args.push(("show_changes".to_string(), show_changes.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -508,7 +578,40 @@ impl Templates {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `pages_get_page_image_templates_stream_with` and pass the query parameters as a `TemplatesPagesGetPageImageTemplatesParams` instead.
+     */
+    #[deprecated(note = "use `pages_get_page_image_templates_stream_with` instead")]
+    pub async fn pages_get_page_image_templates_stream(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        show_changes: &str,
+    ) -> Result<crate::DownloadStream> {
+        let params = TemplatesPagesGetPageImageTemplatesParams {
+            dpi: Some(dpi.to_string()).filter(|s| !s.is_empty()),
+            max_height: Some(max_height.to_string()).filter(|s| !s.is_empty()),
+            max_width: Some(max_width.to_string()).filter(|s| !s.is_empty()),
+            show_changes: Some(show_changes.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.pages_get_page_image_templates_stream_with(
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            &params,
+        )
+        .await
     }
 
     /**
@@ -667,3 +770,608 @@ impl Templates {
             .await
     }
 }
+
+/// The query parameters of `Templates::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplatesGetParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub count: Option<String>,
+    /// The billing period end date in UTC timedate format.
+    pub created_from_date: Option<String>,
+    /// The billing period end date in UTC timedate format.
+    pub created_to_date: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub folder_ids: Option<String>,
+    /// The type of folder to return templates for. Possible values are:
+    ///
+    /// - `templates`: Templates in the **My Templates** folder.
+    ///   Templates in the **Shared Templates**  and **All Template** folders (if the request id from and Admin) are excluded.
+    /// - `templates_root`: Templates in the root level of the **My Templates** folder, but not in an actual folder. Note that the **My Templates** folder is not a real folder.
+    /// - `recylebin`: Templates that have been deleted.
+    ///   .
+    pub folder_types: Option<String>,
+    /// Start of the search date range. Only returns templates created on or after this date/time. If no value is specified, there is no limit on the earliest date created.
+    pub from_date: Option<String>,
+    /// A comma-separated list
+    /// of additional template attributes
+    /// to include in the response.
+    /// Valid values are:
+    ///
+    /// - `powerforms`: Includes details about the PowerForms associated with the templates.
+    /// - `documents`: Includes information about template documents.
+    /// - `folders`: Includes information about the folder that holds the template.
+    /// - `favorite_template_status`: Includes the template `favoritedByMe` property. **Note**: You can mark a template as a favorite only in eSignature v2.1.
+    /// - `advanced_templates`: Includes information about advanced templates.
+    /// - `recipients`: Includes information about template recipients.
+    /// - `custom_fields`: Includes information about template custom fields.
+    /// - `notifications`: Includes information about the notification settings for templates.
+    pub include: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub is_deleted_template_only: Option<String>,
+    /// When set to **true**, downloads the templates listed in `template_ids` as a collection of JSON definitions in a single zip file.
+    ///
+    /// The `Content-Disposition` header is set in the response. The value of the header provides the filename of the file.
+    ///
+    /// The default is **false**.
+    ///
+    /// **Note**: This parameter only works when you specify a list of templates in the `template_ids` parameter.
+    pub is_download: Option<String>,
+    /// The billing period end date in UTC timedate format.
+    pub modified_from_date: Option<String>,
+    /// The billing period end date in UTC timedate format.
+    pub modified_to_date: Option<String>,
+    /// Specifies the sort order of the search results.
+    /// Valid values are:
+    ///
+    /// - `asc`: Ascending (A to Z)
+    /// - `desc`: Descending (Z to A).
+    pub order: Option<String>,
+    /// Specifies how the search results are listed.
+    /// Valid values are:
+    ///
+    /// - `name`: template name
+    /// - `modified`: date/time template was last modified
+    /// - `used`: date/time the template was last used.
+    pub order_by: Option<String>,
+    /// A comma-separated list of additional template properties to search.
+    ///
+    ///
+    /// - `sender`: Include sender name and email in the search.
+    /// - `recipients`: Include recipient names and emails in the search.
+    /// - `envelope`: Not used in template searches.
+    ///   .
+    pub search_fields: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub search_text: Option<String>,
+    /// If **true**, the response only includes templates shared by the user. If false, the response only returns template not shared by the user. If not specified, the response is not affected.
+    pub shared_by_me: Option<String>,
+    /// The starting zero-based index position for the first template to show in the response. This value must be greater than or equal to `0` (zero).
+    pub start_position: Option<String>,
+    /// A comma-separated list of template ids to download. This value is valid only when `is_download` is **true**.
+    pub template_ids: Option<String>,
+    /// The end of a search date range in UTC DateTime format. When you use this parameter, only templates created up to this date and time are returned.
+    ///
+    /// **Note**: If this property is null, the value defaults to the current date.
+    pub to_date: Option<String>,
+    /// Start of the search date range. Only returns templates used or edited on or after this date/time. If no value is specified, there is no limit on the earliest date used.
+    pub used_from_date: Option<String>,
+    /// End of the search date range. Only returns templates used or edited up to this date/time. If no value is provided, this defaults to the current date.
+    pub used_to_date: Option<String>,
+    /// Filters the templates in the response. Valid values are:
+    ///
+    /// - `owned_by_me`: Results include only templates owned by the user.
+    /// - `shared_with_me`: Results include only templates owned by the user.
+    /// - `all`:  Results include all templates owned or shared with the user.
+    pub user_filter: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub user_id: Option<String>,
+}
+
+impl TemplatesGetParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn created_from_date<T: ToString>(mut self, created_from_date: T) -> Self {
+        self.created_from_date = Some(created_from_date.to_string());
+        self
+    }
+
+    pub fn created_to_date<T: ToString>(mut self, created_to_date: T) -> Self {
+        self.created_to_date = Some(created_to_date.to_string());
+        self
+    }
+
+    pub fn folder_ids<T: ToString>(mut self, folder_ids: T) -> Self {
+        self.folder_ids = Some(folder_ids.to_string());
+        self
+    }
+
+    pub fn folder_types<T: ToString>(mut self, folder_types: T) -> Self {
+        self.folder_types = Some(folder_types.to_string());
+        self
+    }
+
+    pub fn from_date<T: ToString>(mut self, from_date: T) -> Self {
+        self.from_date = Some(from_date.to_string());
+        self
+    }
+
+    pub fn include<T: ToString>(mut self, include: T) -> Self {
+        self.include = Some(include.to_string());
+        self
+    }
+
+    pub fn is_deleted_template_only<T: ToString>(mut self, is_deleted_template_only: T) -> Self {
+        self.is_deleted_template_only = Some(is_deleted_template_only.to_string());
+        self
+    }
+
+    pub fn is_download<T: ToString>(mut self, is_download: T) -> Self {
+        self.is_download = Some(is_download.to_string());
+        self
+    }
+
+    pub fn modified_from_date<T: ToString>(mut self, modified_from_date: T) -> Self {
+        self.modified_from_date = Some(modified_from_date.to_string());
+        self
+    }
+
+    pub fn modified_to_date<T: ToString>(mut self, modified_to_date: T) -> Self {
+        self.modified_to_date = Some(modified_to_date.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn order_by<T: ToString>(mut self, order_by: T) -> Self {
+        self.order_by = Some(order_by.to_string());
+        self
+    }
+
+    pub fn search_fields<T: ToString>(mut self, search_fields: T) -> Self {
+        self.search_fields = Some(search_fields.to_string());
+        self
+    }
+
+    pub fn search_text<T: ToString>(mut self, search_text: T) -> Self {
+        self.search_text = Some(search_text.to_string());
+        self
+    }
+
+    pub fn shared_by_me<T: ToString>(mut self, shared_by_me: T) -> Self {
+        self.shared_by_me = Some(shared_by_me.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+
+    pub fn template_ids<T: ToString>(mut self, template_ids: T) -> Self {
+        self.template_ids = Some(template_ids.to_string());
+        self
+    }
+
+    pub fn to_date<T: ToString>(mut self, to_date: T) -> Self {
+        self.to_date = Some(to_date.to_string());
+        self
+    }
+
+    pub fn used_from_date<T: ToString>(mut self, used_from_date: T) -> Self {
+        self.used_from_date = Some(used_from_date.to_string());
+        self
+    }
+
+    pub fn used_to_date<T: ToString>(mut self, used_to_date: T) -> Self {
+        self.used_to_date = Some(used_to_date.to_string());
+        self
+    }
+
+    pub fn user_filter<T: ToString>(mut self, user_filter: T) -> Self {
+        self.user_filter = Some(user_filter.to_string());
+        self
+    }
+
+    pub fn user_id<T: ToString>(mut self, user_id: T) -> Self {
+        self.user_id = Some(user_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Templates::get_templates_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplatesGetTemplatesParams {
+    /// A comma-separated list
+    /// of additional template attributes
+    /// to include in the response.
+    /// Valid values are:
+    ///
+    /// - `powerforms`: Includes information about PowerForms.
+    /// - `tabs`: Includes information about tabs.
+    /// - `documents`: Includes information about documents.
+    /// - `favorite_template_status`: : Includes the template `favoritedByMe` property in the response. **Note**: You can mark a template as a favorite only in eSignature v2.1.
+    pub include: Option<String>,
+}
+
+impl TemplatesGetTemplatesParams {
+    pub fn include<T: ToString>(mut self, include: T) -> Self {
+        self.include = Some(include.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Templates::pages_get_page_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplatesPagesGetPageImageParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub count: Option<String>,
+    /// The number of dots per inch (DPI) for the resulting images. Valid values are 1-310 DPI. The default value is 94.
+    pub dpi: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub max_height: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub max_width: Option<String>,
+    /// If **true**, using cache is disabled and image information is retrieved from a database. **True** is the default value. .
+    pub nocache: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub show_changes: Option<String>,
+    /// The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
+    pub start_position: Option<String>,
+}
+
+impl TemplatesPagesGetPageImageParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn dpi<T: ToString>(mut self, dpi: T) -> Self {
+        self.dpi = Some(dpi.to_string());
+        self
+    }
+
+    pub fn max_height<T: ToString>(mut self, max_height: T) -> Self {
+        self.max_height = Some(max_height.to_string());
+        self
+    }
+
+    pub fn max_width<T: ToString>(mut self, max_width: T) -> Self {
+        self.max_width = Some(max_width.to_string());
+        self
+    }
+
+    pub fn nocache<T: ToString>(mut self, nocache: T) -> Self {
+        self.nocache = Some(nocache.to_string());
+        self
+    }
+
+    pub fn show_changes<T: ToString>(mut self, show_changes: T) -> Self {
+        self.show_changes = Some(show_changes.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Templates::pages_get_page_image_templates_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplatesPagesGetPageImageTemplatesParams {
+    /// The number of dots per inch (DPI) for the resulting images. Valid values are 1-310 DPI. The default value is 94.
+    pub dpi: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub max_height: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub max_width: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub show_changes: Option<String>,
+}
+
+impl TemplatesPagesGetPageImageTemplatesParams {
+    pub fn dpi<T: ToString>(mut self, dpi: T) -> Self {
+        self.dpi = Some(dpi.to_string());
+        self
+    }
+
+    pub fn max_height<T: ToString>(mut self, max_height: T) -> Self {
+        self.max_height = Some(max_height.to_string());
+        self
+    }
+
+    pub fn max_width<T: ToString>(mut self, max_width: T) -> Self {
+        self.max_width = Some(max_width.to_string());
+        self
+    }
+
+    pub fn show_changes<T: ToString>(mut self, show_changes: T) -> Self {
+        self.show_changes = Some(show_changes.to_string());
+        self
+    }
+}
+
+/// The functions of [`Templates`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplatesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplatesApi: Send + Sync {
+    /// See [`Templates::get_with`].
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &TemplatesGetParams,
+    ) -> Result<crate::types::EnvelopeTemplateResults>;
+
+    /// See [`Templates::post`].
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> Result<crate::types::TemplateSummary>;
+
+    /// See [`Templates::get_templates_with`].
+    async fn get_templates_with(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        params: &TemplatesGetTemplatesParams,
+    ) -> Result<crate::types::EnvelopeTemplate>;
+
+    /// See [`Templates::put`].
+    async fn put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> Result<crate::types::TemplateUpdateSummary>;
+
+    /// See [`Templates::pages_get_page_image_with`].
+    async fn pages_get_page_image_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        params: &TemplatesPagesGetPageImageParams,
+    ) -> Result<crate::types::PageImages>;
+
+    /// See [`Templates::pages_delete_page`].
+    async fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()>;
+
+    /// See [`Templates::pages_get_page_image_templates_with`].
+    async fn pages_get_page_image_templates_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        params: &TemplatesPagesGetPageImageTemplatesParams,
+    ) -> Result<crate::Download>;
+
+    /// See [`Templates::pages_get_page_image_templates_stream_with`].
+    async fn pages_get_page_image_templates_stream_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        params: &TemplatesPagesGetPageImageTemplatesParams,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`Templates::pages_put_page_image`].
+    async fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()>;
+
+    /// See [`Templates::notification_get`].
+    async fn notification_get(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::Notification>;
+
+    /// See [`Templates::notification_put`].
+    async fn notification_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateNotificationRequest,
+    ) -> Result<crate::types::Notification>;
+
+    /// See [`Templates::put_part`].
+    async fn put_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation>;
+
+    /// See [`Templates::delete_part`].
+    async fn delete_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation>;
+}
+
+#[async_trait::async_trait]
+impl TemplatesApi for Templates {
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &TemplatesGetParams,
+    ) -> Result<crate::types::EnvelopeTemplateResults> {
+        Templates::get_with(self, account_id, params).await
+    }
+
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> Result<crate::types::TemplateSummary> {
+        Templates::post(self, account_id, body).await
+    }
+
+    async fn get_templates_with(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        params: &TemplatesGetTemplatesParams,
+    ) -> Result<crate::types::EnvelopeTemplate> {
+        Templates::get_templates_with(self, account_id, template_id, params).await
+    }
+
+    async fn put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::EnvelopeTemplate,
+    ) -> Result<crate::types::TemplateUpdateSummary> {
+        Templates::put(self, account_id, template_id, body).await
+    }
+
+    async fn pages_get_page_image_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        template_id: &str,
+        params: &TemplatesPagesGetPageImageParams,
+    ) -> Result<crate::types::PageImages> {
+        Templates::pages_get_page_image_with(self, account_id, document_id, template_id, params)
+            .await
+    }
+
+    async fn pages_delete_page(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()> {
+        Templates::pages_delete_page(
+            self,
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            body,
+        )
+        .await
+    }
+
+    async fn pages_get_page_image_templates_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        params: &TemplatesPagesGetPageImageTemplatesParams,
+    ) -> Result<crate::Download> {
+        Templates::pages_get_page_image_templates_with(
+            self,
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            params,
+        )
+        .await
+    }
+
+    async fn pages_get_page_image_templates_stream_with(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        params: &TemplatesPagesGetPageImageTemplatesParams,
+    ) -> Result<crate::DownloadStream> {
+        Templates::pages_get_page_image_templates_stream_with(
+            self,
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            params,
+        )
+        .await
+    }
+
+    async fn pages_put_page_image(
+        &self,
+        account_id: &str,
+        document_id: &str,
+        page_number: &str,
+        template_id: &str,
+        body: &crate::types::PageRequest,
+    ) -> Result<()> {
+        Templates::pages_put_page_image(
+            self,
+            account_id,
+            document_id,
+            page_number,
+            template_id,
+            body,
+        )
+        .await
+    }
+
+    async fn notification_get(
+        &self,
+        account_id: &str,
+        template_id: &str,
+    ) -> Result<crate::types::Notification> {
+        Templates::notification_get(self, account_id, template_id).await
+    }
+
+    async fn notification_put(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        body: &crate::types::TemplateNotificationRequest,
+    ) -> Result<crate::types::Notification> {
+        Templates::notification_put(self, account_id, template_id, body).await
+    }
+
+    async fn put_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        Templates::put_part(self, account_id, template_id, template_part, body).await
+    }
+
+    async fn delete_part(
+        &self,
+        account_id: &str,
+        template_id: &str,
+        template_part: &str,
+        body: &crate::types::GroupInformation,
+    ) -> Result<crate::types::GroupInformation> {
+        Templates::delete_part(self, account_id, template_id, template_part, body).await
+    }
+}
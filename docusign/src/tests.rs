@@ -1 +1,35 @@
+#[test]
+fn test_environment() {
+    let docusign = crate::Client::new("client-id", "", "redirect-uri", "", "");
+    assert_eq!(
+        docusign.token_endpoint(),
+        "https://account.docusign.com/oauth/token"
+    );
 
+    let docusign = docusign.with_environment(crate::Environment::Demo);
+    assert_eq!(
+        docusign.token_endpoint(),
+        "https://account-d.docusign.com/oauth/token"
+    );
+    assert!(docusign
+        .user_consent_url(&[])
+        .starts_with("https://account-d.docusign.com/oauth/auth?client_id=client-id&"));
+}
+
+#[tokio::test]
+async fn test_jwt_grant_invalid_private_key() {
+    let mut docusign = crate::Client::for_jwt_grant(
+        "client-id",
+        crate::JwtGrant {
+            user_id: "user-id".to_string(),
+            private_key: "not a key".to_string(),
+            scopes: vec!["signature".to_string(), "impersonation".to_string()],
+        },
+        crate::Environment::Demo,
+    );
+
+    match docusign.get_jwt_access_token().await {
+        Err(crate::Error::Auth(e)) => assert!(e.starts_with("invalid private key")),
+        r => panic!("expected an auth error, got {:?}", r.map(|t| t.token_type)),
+    }
+}
@@ -0,0 +1,19 @@
+//! The data types of the `account_brands` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Details about one or more brands.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BrandsRequest {
+    /**
+     * Details about one or more brands.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub brands: Vec<BrandRequest>,
+}
@@ -0,0 +1,282 @@
+//! The data types of the `account_consumer_disclosures` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Details about consumer disclosures.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConsumerDisclosure {
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "accountEsignId"
+    )]
+    pub account_esign_id: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "allowCDWithdraw"
+    )]
+    pub allow_cd_withdraw: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "allowCDWithdrawMetadata"
+    )]
+    pub allow_cd_withdraw_metadata: Option<SettingsMetadata>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "changeEmail"
+    )]
+    pub change_email: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "changeEmailOther"
+    )]
+    pub change_email_other: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "companyName"
+    )]
+    pub company_name: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "companyPhone"
+    )]
+    pub company_phone: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "copyCostPerPage"
+    )]
+    pub copy_cost_per_page: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "copyFeeCollectionMethod"
+    )]
+    pub copy_fee_collection_method: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "copyRequestEmail"
+    )]
+    pub copy_request_email: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "enableEsign"
+    )]
+    pub enable_esign: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "esignAgreement"
+    )]
+    pub esign_agreement: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "esignText")]
+    pub esign_text: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "languageCode"
+    )]
+    pub language_code: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "mustAgreeToEsign"
+    )]
+    pub must_agree_to_esign: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "pdfId")]
+    pub pdf_id: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "useBrand")]
+    pub use_brand: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "useConsumerDisclosureWithinAccount"
+    )]
+    pub use_consumer_disclosure_within_account: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "useConsumerDisclosureWithinAccountMetadata"
+    )]
+    pub use_consumer_disclosure_within_account_metadata: Option<SettingsMetadata>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "withdrawAddressLine1"
+    )]
+    pub withdraw_address_line_1: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "withdrawAddressLine2"
+    )]
+    pub withdraw_address_line_2: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "withdrawByEmail"
+    )]
+    pub withdraw_by_email: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "withdrawByMail"
+    )]
+    pub withdraw_by_mail: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "withdrawByPhone"
+    )]
+    pub withdraw_by_phone: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "withdrawCity"
+    )]
+    pub withdraw_city: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "withdrawConsequences"
+    )]
+    pub withdraw_consequences: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "withdrawEmail"
+    )]
+    pub withdraw_email: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "withdrawOther"
+    )]
+    pub withdraw_other: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "withdrawPhone"
+    )]
+    pub withdraw_phone: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "withdrawPostalCode"
+    )]
+    pub withdraw_postal_code: Option<String>,
+    /**
+     * Details about consumer disclosures.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "withdrawState"
+    )]
+    pub withdraw_state: Option<String>,
+}
@@ -0,0 +1,63 @@
+//! The data types of the `account_custom_fields` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// This object provides details about a custom field.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CustomField {
+    /**
+     * This object provides details about a custom field.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "customFieldType"
+    )]
+    pub custom_field_type: Option<String>,
+    /**
+     * This object provides details about a custom field.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "errorDetails"
+    )]
+    pub error_details: Option<ErrorDetails>,
+    /**
+     * This object provides details about a custom field.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "fieldId")]
+    pub field_id: Option<String>,
+    /**
+     * This object provides details about a custom field.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "listItems"
+    )]
+    pub list_items: Vec<String>,
+    /**
+     * This object provides details about a custom field.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /**
+     * This object provides details about a custom field.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<String>,
+    /**
+     * This object provides details about a custom field.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub show: Option<String>,
+    /**
+     * This object provides details about a custom field.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
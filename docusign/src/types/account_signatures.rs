@@ -0,0 +1,159 @@
+//! The data types of the `account_signatures` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AccountSignatureDefinition {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "dateStampProperties"
+    )]
+    pub date_stamp_properties: Option<DateStampProperties>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "disallowUserResizeStamp"
+    )]
+    pub disallow_user_resize_stamp: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "externalID"
+    )]
+    pub external_id: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "imageType")]
+    pub image_type: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "isDefault")]
+    pub is_default: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "nrdsId")]
+    pub nrds_id: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "nrdsLastName"
+    )]
+    pub nrds_last_name: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "phoneticName"
+    )]
+    pub phonetic_name: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "signatureFont"
+    )]
+    pub signature_font: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "signatureGroups"
+    )]
+    pub signature_groups: Vec<SignatureGroupDef>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "signatureId"
+    )]
+    pub signature_id: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "signatureInitials"
+    )]
+    pub signature_initials: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "signatureName"
+    )]
+    pub signature_name: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "signatureUsers"
+    )]
+    pub signature_users: Vec<SignatureUserDef>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "stampFormat"
+    )]
+    pub stamp_format: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "stampSizeMM"
+    )]
+    pub stamp_size_mm: Option<String>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AccountSignaturesInformation {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "accountSignatures"
+    )]
+    pub account_signatures: Vec<AccountSignature>,
+}
@@ -0,0 +1,67 @@
+//! The data types of the `account_watermarks` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Watermark {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "displayAngle"
+    )]
+    pub display_angle: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub font: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "fontColor")]
+    pub font_color: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "fontSize")]
+    pub font_size: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "imageBase64"
+    )]
+    pub image_base_64: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transparency: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "watermarkText"
+    )]
+    pub watermark_text: Option<String>,
+}
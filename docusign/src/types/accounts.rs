@@ -0,0 +1,348 @@
+//! The data types of the `accounts` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Contains shared access information.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AccountSharedAccess {
+    /**
+     * Contains shared access information.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "accountId")]
+    pub account_id: Option<String>,
+    /**
+     * Contains shared access information.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "endPosition"
+    )]
+    pub end_position: Option<String>,
+    /**
+     * Contains shared access information.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "errorDetails"
+    )]
+    pub error_details: Option<ErrorDetails>,
+    /**
+     * Contains shared access information.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "nextUri")]
+    pub next_uri: Option<String>,
+    /**
+     * Contains shared access information.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "previousUri"
+    )]
+    pub previous_uri: Option<String>,
+    /**
+     * Contains shared access information.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "resultSetSize"
+    )]
+    pub result_set_size: Option<String>,
+    /**
+     * Contains shared access information.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "sharedAccess"
+    )]
+    pub shared_access: Vec<MemberSharedItems>,
+    /**
+     * Contains shared access information.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "startPosition"
+    )]
+    pub start_position: Option<String>,
+    /**
+     * Contains shared access information.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "totalSetSize"
+    )]
+    pub total_set_size: Option<String>,
+}
+
+/// Contains information about captive (embedded) recipients.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CaptiveRecipientInformation {
+    /**
+     * Contains information about captive (embedded) recipients.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "captiveRecipients"
+    )]
+    pub captive_recipients: Vec<CaptiveRecipient>,
+}
+
+/// Contains information about the current envelope purge configuration for an account, which enables account administrators to purge documents from completed and voided envelopes after a set number of days (`retentionDays`).
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnvelopePurgeConfiguration {
+    /**
+     * Contains information about the current envelope purge configuration for an account, which enables account administrators to purge documents from completed and voided envelopes after a set number of days (`retentionDays`).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "purgeEnvelopes"
+    )]
+    pub purge_envelopes: Option<String>,
+    /**
+     * Contains information about the current envelope purge configuration for an account, which enables account administrators to purge documents from completed and voided envelopes after a set number of days (`retentionDays`).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "redactPII")]
+    pub redact_pii: Option<String>,
+    /**
+     * Contains information about the current envelope purge configuration for an account, which enables account administrators to purge documents from completed and voided envelopes after a set number of days (`retentionDays`).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "removeTabsAndEnvelopeAttachments"
+    )]
+    pub remove_tabs_and_envelope_attachments: Option<String>,
+    /**
+     * Contains information about the current envelope purge configuration for an account, which enables account administrators to purge documents from completed and voided envelopes after a set number of days (`retentionDays`).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "retentionDays"
+    )]
+    pub retention_days: Option<String>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct NewAccountDefinition {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "accountName"
+    )]
+    pub account_name: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "accountSettings"
+    )]
+    pub account_settings: Option<AccountSettingsInformation>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "addressInformation"
+    )]
+    pub address_information: Option<AccountAddress>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "creditCardInformation"
+    )]
+    pub credit_card_information: Option<CreditCardInformation>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "directDebitProcessorInformation"
+    )]
+    pub direct_debit_processor_information: Option<DirectDebitProcessorInformation>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "distributorCode"
+    )]
+    pub distributor_code: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "distributorPassword"
+    )]
+    pub distributor_password: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "envelopePartitionId"
+    )]
+    pub envelope_partition_id: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "initialUser"
+    )]
+    pub initial_user: Option<UserInformation>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "paymentMethod"
+    )]
+    pub payment_method: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "paymentProcessorInformation"
+    )]
+    pub payment_processor_information: Option<PaymentProcessorInformation>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "planInformation"
+    )]
+    pub plan_information: Option<PlanInformation>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "referralInformation"
+    )]
+    pub referral_information: Option<ReferralInformation>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "socialAccountInformation"
+    )]
+    pub social_account_information: Option<SocialAccountInformation>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct NotificationDefaultsData {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "apiEmailNotifications"
+    )]
+    pub api_email_notifications: Option<NotificationDefaultSettings>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "emailNotifications"
+    )]
+    pub email_notifications: Option<NotificationDefaultSettings>,
+}
+
+/// An object used to identify the features and attributes of the account being created.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PlanInformation {
+    /**
+     * An object used to identify the features and attributes of the account being created.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "addOns"
+    )]
+    pub add_ons: Vec<AddOn>,
+    /**
+     * An object used to identify the features and attributes of the account being created.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "currencyCode"
+    )]
+    pub currency_code: Option<String>,
+    /**
+     * An object used to identify the features and attributes of the account being created.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "freeTrialDaysOverride"
+    )]
+    pub free_trial_days_override: Option<String>,
+    /**
+     * An object used to identify the features and attributes of the account being created.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "planFeatureSets"
+    )]
+    pub plan_feature_sets: Vec<FeatureSet>,
+    /**
+     * An object used to identify the features and attributes of the account being created.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "planId")]
+    pub plan_id: Option<String>,
+    /**
+     * An object used to identify the features and attributes of the account being created.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "recipientDomains"
+    )]
+    pub recipient_domains: Vec<RecipientDomain>,
+}
@@ -0,0 +1,257 @@
+//! The data types of the `billing_plans` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// This object contains details about a billing plan.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BillingPlanInformation {
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "appStoreReceipt"
+    )]
+    pub app_store_receipt: Option<AppStoreReceipt>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "billingAddress"
+    )]
+    pub billing_address: Option<AccountAddress>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "creditCardInformation"
+    )]
+    pub credit_card_information: Option<CreditCardInformation>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "directDebitProcessorInformation"
+    )]
+    pub direct_debit_processor_information: Option<DirectDebitProcessorInformation>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "downgradeReason"
+    )]
+    pub downgrade_reason: Option<String>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "enableSupport"
+    )]
+    pub enable_support: Option<String>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includedSeats"
+    )]
+    pub included_seats: Option<String>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "incrementalSeats"
+    )]
+    pub incremental_seats: Option<String>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "paymentMethod"
+    )]
+    pub payment_method: Option<String>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "paymentProcessorInformation"
+    )]
+    pub payment_processor_information: Option<PaymentProcessorInformation>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "planInformation"
+    )]
+    pub plan_information: Option<PlanInformation>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "referralInformation"
+    )]
+    pub referral_information: Option<ReferralInformation>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "renewalStatus"
+    )]
+    pub renewal_status: Option<String>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "saleDiscountAmount"
+    )]
+    pub sale_discount_amount: Option<String>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "saleDiscountFixedAmount"
+    )]
+    pub sale_discount_fixed_amount: Option<String>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "saleDiscountPercent"
+    )]
+    pub sale_discount_percent: Option<String>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "saleDiscountPeriods"
+    )]
+    pub sale_discount_periods: Option<String>,
+    /**
+     * This object contains details about a billing plan.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "saleDiscountSeatPriceOverride"
+    )]
+    pub sale_discount_seat_price_override: Option<String>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DowngradeBillingPlanInformation {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "downgradeEventType"
+    )]
+    pub downgrade_event_type: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "planInformation"
+    )]
+    pub plan_information: Option<PlanInformation>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PurchasedEnvelopesInformation {
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "appName")]
+    pub app_name: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "currencyCode"
+    )]
+    pub currency_code: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "productId")]
+    pub product_id: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "receiptData"
+    )]
+    pub receipt_data: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "storeName")]
+    pub store_name: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "transactionId"
+    )]
+    pub transaction_id: Option<String>,
+}
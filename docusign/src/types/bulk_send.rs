@@ -0,0 +1,59 @@
+//! The data types of the `bulk_send` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BulkSendBatchRequest {
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "batchName")]
+    pub batch_name: Option<String>,
+}
+
+/// This object contains information about the envelope or template that you want to send in bulk.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BulkSendRequest {
+    /**
+     * This object contains information about the envelope or template that you want to send in bulk.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "batchName")]
+    pub batch_name: Option<String>,
+    /**
+     * This object contains information about the envelope or template that you want to send in bulk.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "envelopeOrTemplateId"
+    )]
+    pub envelope_or_template_id: Option<String>,
+}
+
+/// This object contains the details for the bulk send list.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BulkSendingList {
+    /**
+     * This object contains the details for the bulk send list.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "bulkCopies"
+    )]
+    pub bulk_copies: Vec<BulkSendingCopy>,
+    /**
+     * This object contains the details for the bulk send list.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "listId")]
+    pub list_id: Option<String>,
+    /**
+     * This object contains the details for the bulk send list.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
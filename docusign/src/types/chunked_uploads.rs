@@ -0,0 +1,24 @@
+//! The data types of the `chunked_uploads` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// This is the request object for uploading a chunked upload.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChunkedUploadRequest {
+    /**
+     * This is the request object for uploading a chunked upload.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "chunkedUploadId"
+    )]
+    pub chunked_upload_id: Option<String>,
+    /**
+     * This is the request object for uploading a chunked upload.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+}
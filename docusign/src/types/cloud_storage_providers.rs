@@ -0,0 +1,20 @@
+//! The data types of the `cloud_storage_providers` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CloudStorageProvidersData {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "storageProviders"
+    )]
+    pub storage_providers: Vec<CloudStorageProvider>,
+}
@@ -0,0 +1,25 @@
+//! The data types of the `connect_events` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// A list of failed envelope IDs to retry.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConnectFailureFilter {
+    /**
+     * A list of failed envelope IDs to retry.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "envelopeIds"
+    )]
+    pub envelope_ids: Vec<String>,
+    /**
+     * A list of failed envelope IDs to retry.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub synchronous: Option<String>,
+}
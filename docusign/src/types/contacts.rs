@@ -0,0 +1,20 @@
+//! The data types of the `contacts` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// The request object containing the new information for the contacts.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContactModRequest {
+    /**
+     * The request object containing the new information for the contacts.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "contactList"
+    )]
+    pub contact_list: Vec<Contact>,
+}
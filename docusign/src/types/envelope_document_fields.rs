@@ -0,0 +1,20 @@
+//! The data types of the `envelope_document_fields` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Envelope document fields
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnvelopeDocumentFields {
+    /**
+     * Envelope document fields
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "documentFields"
+    )]
+    pub document_fields: Vec<NameValue>,
+}
@@ -0,0 +1,877 @@
+//! The data types of the `envelope_documents` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Envelope object definition.
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnvelopeDefinition {
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "accessControlListBase64"
+    )]
+    pub access_control_list_base_64: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accessibility: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "allowComments"
+    )]
+    pub allow_comments: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "allowMarkup"
+    )]
+    pub allow_markup: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "allowReassign"
+    )]
+    pub allow_reassign: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "allowRecipientRecursion"
+    )]
+    pub allow_recipient_recursion: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "allowViewHistory"
+    )]
+    pub allow_view_history: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "anySigner")]
+    pub any_signer: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asynchronous: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub attachments: Vec<Attachment>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "attachmentsUri"
+    )]
+    pub attachments_uri: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authoritativeCopy"
+    )]
+    pub authoritative_copy: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authoritativeCopyDefault"
+    )]
+    pub authoritative_copy_default: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "autoNavigation"
+    )]
+    pub auto_navigation: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "brandId")]
+    pub brand_id: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "brandLock")]
+    pub brand_lock: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "certificateUri"
+    )]
+    pub certificate_uri: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "completedDateTime"
+    )]
+    pub completed_date_time: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "compositeTemplates"
+    )]
+    pub composite_templates: Vec<CompositeTemplate>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "copyRecipientData"
+    )]
+    pub copy_recipient_data: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "createdDateTime"
+    )]
+    pub created_date_time: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "customFields"
+    )]
+    pub custom_fields: Option<AccountCustomFields>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "customFieldsUri"
+    )]
+    pub custom_fields_uri: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "declinedDateTime"
+    )]
+    pub declined_date_time: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "deletedDateTime"
+    )]
+    pub deleted_date_time: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "deliveredDateTime"
+    )]
+    pub delivered_date_time: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "disableResponsiveDocument"
+    )]
+    pub disable_responsive_document: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub documents: Vec<Document>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "documentsCombinedUri"
+    )]
+    pub documents_combined_uri: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "documentsUri"
+    )]
+    pub documents_uri: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "emailBlurb"
+    )]
+    pub email_blurb: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "emailSettings"
+    )]
+    pub email_settings: Option<EmailSettings>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "emailSubject"
+    )]
+    pub email_subject: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "enableWetSign"
+    )]
+    pub enable_wet_sign: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "enforceSignerVisibility"
+    )]
+    pub enforce_signer_visibility: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "envelopeAttachments"
+    )]
+    pub envelope_attachments: Vec<Attachment>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "envelopeDocuments"
+    )]
+    pub envelope_documents: Vec<EnvelopeDocument>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "envelopeId"
+    )]
+    pub envelope_id: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "envelopeIdStamping"
+    )]
+    pub envelope_id_stamping: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "envelopeLocation"
+    )]
+    pub envelope_location: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "envelopeMetadata"
+    )]
+    pub envelope_metadata: Option<EnvelopeMetadata>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "envelopeUri"
+    )]
+    pub envelope_uri: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "eventNotification"
+    )]
+    pub event_notification: Option<EventNotification>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "expireAfter"
+    )]
+    pub expire_after: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "expireDateTime"
+    )]
+    pub expire_date_time: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "expireEnabled"
+    )]
+    pub expire_enabled: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "externalEnvelopeId"
+    )]
+    pub external_envelope_id: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub folders: Vec<Folder>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "hasComments"
+    )]
+    pub has_comments: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "hasFormDataChanged"
+    )]
+    pub has_form_data_changed: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "hasWavFile"
+    )]
+    pub has_wav_file: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holder: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "initialSentDateTime"
+    )]
+    pub initial_sent_date_time: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "is21CFRPart11"
+    )]
+    pub is_21cfr_part_11: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "isDynamicEnvelope"
+    )]
+    pub is_dynamic_envelope: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "isSignatureProviderEnvelope"
+    )]
+    pub is_signature_provider_envelope: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "lastModifiedDateTime"
+    )]
+    pub last_modified_date_time: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "lockInformation"
+    )]
+    pub lock_information: Option<EnvelopeLocks>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "messageLock"
+    )]
+    pub message_lock: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notification: Option<Notification>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "notificationUri"
+    )]
+    pub notification_uri: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "powerForm")]
+    pub power_form: Option<PowerForm>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize",
+        rename = "purgeCompletedDate"
+    )]
+    pub purge_completed_date: Option<chrono::NaiveDate>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize",
+        rename = "purgeRequestDate"
+    )]
+    pub purge_request_date: Option<chrono::NaiveDate>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "purgeState"
+    )]
+    pub purge_state: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recipients: Option<EnvelopeRecipients>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "recipientsLock"
+    )]
+    pub recipients_lock: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "recipientsUri"
+    )]
+    pub recipients_uri: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender: Option<UserInfo>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "sentDateTime"
+    )]
+    pub sent_date_time: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "signerCanSignOnMobile"
+    )]
+    pub signer_can_sign_on_mobile: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "signingLocation"
+    )]
+    pub signing_location: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "statusChangedDateTime"
+    )]
+    pub status_changed_date_time: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "statusDateTime"
+    )]
+    pub status_date_time: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "templateId"
+    )]
+    pub template_id: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "templateRoles"
+    )]
+    pub template_roles: Vec<TemplateRole>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "templatesUri"
+    )]
+    pub templates_uri: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "transactionId"
+    )]
+    pub transaction_id: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "useDisclosure"
+    )]
+    pub use_disclosure: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "voidedDateTime"
+    )]
+    pub voided_date_time: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "voidedReason"
+    )]
+    pub voided_reason: Option<String>,
+    /**
+     * Envelope object definition.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow: Option<Workflow>,
+}
+
+/// Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EventNotification {
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "envelopeEvents"
+    )]
+    pub envelope_events: Vec<EnvelopeEvent>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "eventData")]
+    pub event_data: Option<ConnectEventData>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeCertificateOfCompletion"
+    )]
+    pub include_certificate_of_completion: Option<String>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeCertificateWithSoap"
+    )]
+    pub include_certificate_with_soap: Option<String>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeDocumentFields"
+    )]
+    pub include_document_fields: Option<String>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeDocuments"
+    )]
+    pub include_documents: Option<String>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeEnvelopeVoidReason"
+    )]
+    pub include_envelope_void_reason: Option<String>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeHMAC"
+    )]
+    pub include_hmac: Option<String>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeSenderAccountAsCustomField"
+    )]
+    pub include_sender_account_as_custom_field: Option<String>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeTimeZone"
+    )]
+    pub include_time_zone: Option<String>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "loggingEnabled"
+    )]
+    pub logging_enabled: Option<String>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "recipientEvents"
+    )]
+    pub recipient_events: Vec<RecipientEvent>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "requireAcknowledgment"
+    )]
+    pub require_acknowledgment: Option<String>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "signMessageWithX509Cert"
+    )]
+    pub sign_message_with_x509_cert: Option<String>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "soapNameSpace"
+    )]
+    pub soap_name_space: Option<String>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /**
+     * Use this object to configure a [DocuSign Connect webhook](https://developers.docusign.com/platform/webhooks/connect/).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "useSoapInterface"
+    )]
+    pub use_soap_interface: Option<String>,
+}
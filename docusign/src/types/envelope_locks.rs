@@ -0,0 +1,51 @@
+//! The data types of the `envelope_locks` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// This request object contains information about the lock that you want to create or update.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LockRequest {
+    /**
+     * This request object contains information about the lock that you want to create or update.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "lockDurationInSeconds"
+    )]
+    pub lock_duration_in_seconds: Option<String>,
+    /**
+     * This request object contains information about the lock that you want to create or update.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "lockType")]
+    pub lock_type: Option<String>,
+    /**
+     * This request object contains information about the lock that you want to create or update.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "lockedByApp"
+    )]
+    pub locked_by_app: Option<String>,
+    /**
+     * This request object contains information about the lock that you want to create or update.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "templatePassword"
+    )]
+    pub template_password: Option<String>,
+    /**
+     * This request object contains information about the lock that you want to create or update.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "useScratchPad"
+    )]
+    pub use_scratch_pad: Option<String>,
+}
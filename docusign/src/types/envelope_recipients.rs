@@ -0,0 +1,107 @@
+//! The data types of the `envelope_recipients` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// A list of `documentVisibility` objects that specify whether documents are visible to recipients.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DocumentVisibilityList {
+    /**
+     * A list of `documentVisibility` objects that specify whether documents are visible to recipients.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "documentVisibility"
+    )]
+    pub document_visibility: Vec<DocumentVisibility>,
+}
+
+/// This request object contains the information necessary to create a recipient preview.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RecipientPreviewRequest {
+    /**
+     * This request object contains the information necessary to create a recipient preview.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "assertionId"
+    )]
+    pub assertion_id: Option<String>,
+    /**
+     * This request object contains the information necessary to create a recipient preview.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authenticationInstant"
+    )]
+    pub authentication_instant: Option<String>,
+    /**
+     * This request object contains the information necessary to create a recipient preview.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authenticationMethod"
+    )]
+    pub authentication_method: Option<String>,
+    /**
+     * This request object contains the information necessary to create a recipient preview.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "pingFrequency"
+    )]
+    pub ping_frequency: Option<String>,
+    /**
+     * This request object contains the information necessary to create a recipient preview.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "pingUrl")]
+    pub ping_url: Option<String>,
+    /**
+     * This request object contains the information necessary to create a recipient preview.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "recipientId"
+    )]
+    pub recipient_id: Option<String>,
+    /**
+     * This request object contains the information necessary to create a recipient preview.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "returnUrl")]
+    pub return_url: Option<String>,
+    /**
+     * This request object contains the information necessary to create a recipient preview.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "securityDomain"
+    )]
+    pub security_domain: Option<String>,
+    /**
+     * This request object contains the information necessary to create a recipient preview.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "xFrameOptions"
+    )]
+    pub x_frame_options: Option<String>,
+    /**
+     * This request object contains the information necessary to create a recipient preview.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "xFrameOptionsAllowFromUrl"
+    )]
+    pub x_frame_options_allow_from_url: Option<String>,
+}
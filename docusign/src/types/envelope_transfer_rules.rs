@@ -0,0 +1,152 @@
+//! The data types of the `envelope_transfer_rules` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnvelopeTransferRuleInformation {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "endPosition"
+    )]
+    pub end_position: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "envelopeTransferRules"
+    )]
+    pub envelope_transfer_rules: Vec<EnvelopeTransferRule>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "nextUri")]
+    pub next_uri: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "previousUri"
+    )]
+    pub previous_uri: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "resultSetSize"
+    )]
+    pub result_set_size: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "startPosition"
+    )]
+    pub start_position: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "totalSetSize"
+    )]
+    pub total_set_size: Option<String>,
+}
+
+/// This object contains details about the envelope transfer rule that you want to create.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnvelopeTransferRuleRequest {
+    /**
+     * This object contains details about the envelope transfer rule that you want to create.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "carbonCopyOriginalOwner"
+    )]
+    pub carbon_copy_original_owner: Option<String>,
+    /**
+     * This object contains details about the envelope transfer rule that you want to create.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<String>,
+    /**
+     * This object contains details about the envelope transfer rule that you want to create.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "envelopeTransferRuleId"
+    )]
+    pub envelope_transfer_rule_id: Option<String>,
+    /**
+     * This object contains details about the envelope transfer rule that you want to create.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "eventType")]
+    pub event_type: Option<String>,
+    /**
+     * This object contains details about the envelope transfer rule that you want to create.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "fromGroups"
+    )]
+    pub from_groups: Vec<Group>,
+    /**
+     * This object contains details about the envelope transfer rule that you want to create.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "fromUsers"
+    )]
+    pub from_users: Vec<UserInformation>,
+    /**
+     * This object contains details about the envelope transfer rule that you want to create.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize",
+        rename = "modifiedDate"
+    )]
+    pub modified_date: Option<chrono::NaiveDate>,
+    /**
+     * This object contains details about the envelope transfer rule that you want to create.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "modifiedUser"
+    )]
+    pub modified_user: Option<UserInformation>,
+    /**
+     * This object contains details about the envelope transfer rule that you want to create.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "toFolder")]
+    pub to_folder: Option<Folder>,
+    /**
+     * This object contains details about the envelope transfer rule that you want to create.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "toUser")]
+    pub to_user: Option<UserInformation>,
+}
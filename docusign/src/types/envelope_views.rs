@@ -0,0 +1,189 @@
+//! The data types of the `envelope_views` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// The request object for the EnvelopeViews::createConsole method.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConsoleViewRequest {
+    /**
+     * The request object for the EnvelopeViews::createConsole method.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "envelopeId"
+    )]
+    pub envelope_id: Option<String>,
+    /**
+     * The request object for the EnvelopeViews::createConsole method.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "returnUrl")]
+    pub return_url: Option<String>,
+}
+
+/// The request body for the EnvelopeViews::createCorrect method.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CorrectViewRequest {
+    /**
+     * The request body for the EnvelopeViews::createCorrect method.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "returnUrl")]
+    pub return_url: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createCorrect method.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "suppressNavigation"
+    )]
+    pub suppress_navigation: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createCorrect method.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "viewUrl")]
+    pub view_url: Option<String>,
+}
+
+/// The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RecipientViewRequest {
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "assertionId"
+    )]
+    pub assertion_id: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authenticationInstant"
+    )]
+    pub authentication_instant: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authenticationMethod"
+    )]
+    pub authentication_method: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "clientUserId"
+    )]
+    pub client_user_id: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "frameAncestors"
+    )]
+    pub frame_ancestors: Vec<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "messageOrigins"
+    )]
+    pub message_origins: Vec<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "pingFrequency"
+    )]
+    pub ping_frequency: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "pingUrl")]
+    pub ping_url: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "recipientId"
+    )]
+    pub recipient_id: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "returnUrl")]
+    pub return_url: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "securityDomain"
+    )]
+    pub security_domain: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userId")]
+    pub user_id: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userName")]
+    pub user_name: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "xFrameOptions"
+    )]
+    pub x_frame_options: Option<String>,
+    /**
+     * The request body for the EnvelopeViews::createRecipient and EnvelopeViews::createSharedRecipient methods.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "xFrameOptionsAllowFromUrl"
+    )]
+    pub x_frame_options_allow_from_url: Option<String>,
+}
+
+/// The request body for the EnvelopeViews::createSender method.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReturnUrlRequest {
+    /**
+     * The request body for the EnvelopeViews::createSender method.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "returnUrl")]
+    pub return_url: Option<String>,
+}
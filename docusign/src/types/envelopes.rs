@@ -0,0 +1,69 @@
+//! The data types of the `envelopes` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Lists of envelope and transaction IDs to use in the results.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnvelopeIdsRequest {
+    /**
+     * Lists of envelope and transaction IDs to use in the results.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "envelopeIds"
+    )]
+    pub envelope_ids: Vec<String>,
+    /**
+     * Lists of envelope and transaction IDs to use in the results.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "transactionIds"
+    )]
+    pub transaction_ids: Vec<String>,
+}
+
+/// A complex element that specifies the notification settings for the envelope.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnvelopeNotificationRequest {
+    /**
+     * A complex element that specifies the notification settings for the envelope.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expirations: Option<Expirations>,
+    /**
+     * A complex element that specifies the notification settings for the envelope.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reminders: Option<Reminders>,
+    /**
+     * A complex element that specifies the notification settings for the envelope.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "useAccountDefaults"
+    )]
+    pub use_account_defaults: Option<String>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PageRequest {
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotate: Option<String>,
+}
@@ -0,0 +1,39 @@
+//! The data types of the `favorite_templates` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FavoriteTemplatesInfo {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "errorDetails"
+    )]
+    pub error_details: Option<ErrorDetails>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "favoriteTemplates"
+    )]
+    pub favorite_templates: Vec<FavoriteTemplatesContentItem>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "templatesUpdatedCount"
+    )]
+    pub templates_updated_count: i64,
+}
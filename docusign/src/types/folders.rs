@@ -0,0 +1,38 @@
+//! The data types of the `folders` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Information for a folder request.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FoldersRequest {
+    /**
+     * Information for a folder request.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "envelopeIds"
+    )]
+    pub envelope_ids: Vec<String>,
+    /**
+     * Information for a folder request.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub folders: Vec<Folder>,
+    /**
+     * Information for a folder request.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "fromFolderId"
+    )]
+    pub from_folder_id: Option<String>,
+}
@@ -0,0 +1,37 @@
+//! The data types of the `request_logs` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DiagnosticsSettingsInformation {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "apiRequestLogMaxEntries"
+    )]
+    pub api_request_log_max_entries: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "apiRequestLogRemainingEntries"
+    )]
+    pub api_request_log_remaining_entries: Option<String>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "apiRequestLogging"
+    )]
+    pub api_request_logging: Option<String>,
+}
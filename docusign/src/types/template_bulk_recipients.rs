@@ -0,0 +1,20 @@
+//! The data types of the `template_bulk_recipients` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BulkRecipientsRequest {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "bulkRecipients"
+    )]
+    pub bulk_recipients: Vec<BulkRecipient>,
+}
@@ -0,0 +1,20 @@
+//! The data types of the `template_document_visibility` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// A list of `documentVisibility` objects that specify whether the documents associated with a template are visible to recipients.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TemplateDocumentVisibilityList {
+    /**
+     * A list of `documentVisibility` objects that specify whether the documents associated with a template are visible to recipients.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "documentVisibility"
+    )]
+    pub document_visibility: Vec<DocumentVisibility>,
+}
@@ -0,0 +1,84 @@
+//! The data types of the `users` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct NewUsersDefinition {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "newUsers"
+    )]
+    pub new_users: Vec<UserInformation>,
+}
+
+/// Contains a list of account users.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserInformationList {
+    /**
+     * Contains a list of account users.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "endPosition"
+    )]
+    pub end_position: Option<String>,
+    /**
+     * Contains a list of account users.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "nextUri")]
+    pub next_uri: Option<String>,
+    /**
+     * Contains a list of account users.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "previousUri"
+    )]
+    pub previous_uri: Option<String>,
+    /**
+     * Contains a list of account users.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "resultSetSize"
+    )]
+    pub result_set_size: Option<String>,
+    /**
+     * Contains a list of account users.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "startPosition"
+    )]
+    pub start_position: Option<String>,
+    /**
+     * Contains a list of account users.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "totalSetSize"
+    )]
+    pub total_set_size: Option<String>,
+    /**
+     * Contains a list of account users.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub users: Vec<UserInformation>,
+}
@@ -0,0 +1,19 @@
+//! The data types of the `workspace_items` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// An array of objects that describe the items in a workspace.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkspaceItemList {
+    /**
+     * An array of objects that describe the items in a workspace.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub items: Vec<WorkspaceItem>,
+}
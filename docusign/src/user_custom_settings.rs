@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct UserCustomSettings {
     pub client: Client,
@@ -143,3 +141,61 @@ impl UserCustomSettings {
             .await
     }
 }
+
+/// The functions of [`UserCustomSettings`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUserCustomSettingsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UserCustomSettingsApi: Send + Sync {
+    /// See [`UserCustomSettings::get`].
+    async fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::CustomSettingsInformation>;
+
+    /// See [`UserCustomSettings::put`].
+    async fn put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> Result<crate::types::CustomSettingsInformation>;
+
+    /// See [`UserCustomSettings::delete`].
+    async fn delete(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> Result<crate::types::CustomSettingsInformation>;
+}
+
+#[async_trait::async_trait]
+impl UserCustomSettingsApi for UserCustomSettings {
+    async fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::CustomSettingsInformation> {
+        UserCustomSettings::get(self, account_id, user_id).await
+    }
+
+    async fn put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> Result<crate::types::CustomSettingsInformation> {
+        UserCustomSettings::put(self, account_id, user_id, body).await
+    }
+
+    async fn delete(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::CustomSettingsInformation,
+    ) -> Result<crate::types::CustomSettingsInformation> {
+        UserCustomSettings::delete(self, account_id, user_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct UserProfiles {
     pub client: Client,
@@ -69,3 +67,36 @@ impl UserProfiles {
             .await
     }
 }
+
+/// The functions of [`UserProfiles`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUserProfilesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UserProfilesApi: Send + Sync {
+    /// See [`UserProfiles::get`].
+    async fn get(&self, account_id: &str, user_id: &str) -> Result<crate::types::UserProfile>;
+
+    /// See [`UserProfiles::put`].
+    async fn put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserProfile,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl UserProfilesApi for UserProfiles {
+    async fn get(&self, account_id: &str, user_id: &str) -> Result<crate::types::UserProfile> {
+        UserProfiles::get(self, account_id, user_id).await
+    }
+
+    async fn put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserProfile,
+    ) -> Result<()> {
+        UserProfiles::put(self, account_id, user_id, body).await
+    }
+}
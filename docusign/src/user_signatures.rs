@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct UserSignatures {
     pub client: Client,
@@ -30,20 +28,16 @@ impl UserSignatures {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
      *   .
-     * * `stamp_type: &str` -- The type of stamps to return. Valid values are:
-     *   
-     *   - `signature`: Returns information about signature images only. This is the default value.
-     *   - `stamp`: Returns information about eHanko and custom stamps only.
-     *   - null.
+     * * `params: &UserSignaturesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         account_id: &str,
         user_id: &str,
-        stamp_type: &str,
+        params: &UserSignaturesGetParams,
     ) -> Result<crate::types::UserSignaturesInformation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !stamp_type.is_empty() {
+        if let Some(stamp_type) = &params.stamp_type {
             query_args.push(("stamp_type".to_string(), stamp_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -57,6 +51,22 @@ impl UserSignatures {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `UserSignaturesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        stamp_type: &str,
+    ) -> Result<crate::types::UserSignaturesInformation> {
+        let params = UserSignaturesGetParams {
+            stamp_type: Some(stamp_type.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(account_id, user_id, &params).await
+    }
+
     /**
      * Adds/updates a user signature.
      *
@@ -192,18 +202,18 @@ impl UserSignatures {
      * * `signature_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
      *   .
-     * * `close_existing_signature: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &UserSignaturesPutSignatureUserSignaturesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn put_signature_user_signatures(
+    pub async fn put_signature_user_signatures_with(
         &self,
         account_id: &str,
         signature_id: &str,
         user_id: &str,
-        close_existing_signature: &str,
+        params: &UserSignaturesPutSignatureUserSignaturesParams,
         body: &crate::types::UserSignatureDefinition,
     ) -> Result<crate::types::UserSignature> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !close_existing_signature.is_empty() {
+        if let Some(close_existing_signature) = &params.close_existing_signature {
             query_args.push((
                 "close_existing_signature".to_string(),
                 close_existing_signature.to_string(),
@@ -223,6 +233,26 @@ impl UserSignatures {
             .await
     }
 
+    /**
+     * Deprecated: use `put_signature_user_signatures_with` and pass the query parameters as a `UserSignaturesPutSignatureUserSignaturesParams` instead.
+     */
+    #[deprecated(note = "use `put_signature_user_signatures_with` instead")]
+    pub async fn put_signature_user_signatures(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+        close_existing_signature: &str,
+        body: &crate::types::UserSignatureDefinition,
+    ) -> Result<crate::types::UserSignature> {
+        let params = UserSignaturesPutSignatureUserSignaturesParams {
+            close_existing_signature: Some(close_existing_signature.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.put_signature_user_signatures_with(account_id, signature_id, user_id, &params, body)
+            .await
+    }
+
     /**
      * Removes removes signature information for the specified user.
      *
@@ -284,8 +314,39 @@ impl UserSignatures {
      * * `signature_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
      *   .
-     * * `include_chrome: &str` -- When **true**, the chrome (or frame containing the added line and identifier) is included with the signature image.
+     * * `params: &UserSignaturesGetSignatureImageParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_signature_image_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        params: &UserSignaturesGetSignatureImageParams,
+    ) -> Result<crate::Download> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(include_chrome) = &params.include_chrome {
+            query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/signatures/{}/{}?{}",
+            crate::progenitor_support::encode_path(account_id),
+            crate::progenitor_support::encode_path(user_id),
+            crate::progenitor_support::encode_path(signature_id),
+            crate::progenitor_support::encode_path(image_type),
+            query_
+        );
+
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `get_signature_image_with` and pass the query parameters as a `UserSignaturesGetSignatureImageParams` instead.
+     */
+    #[deprecated(note = "use `get_signature_image_with` instead")]
     pub async fn get_signature_image(
         &self,
         account_id: &str,
@@ -293,9 +354,45 @@ impl UserSignatures {
         signature_id: &str,
         user_id: &str,
         include_chrome: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
+        let params = UserSignaturesGetSignatureImageParams {
+            include_chrome: Some(include_chrome.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_signature_image_with(account_id, image_type, signature_id, user_id, &params)
+            .await
+    }
+
+    /**
+     * Retrieves the user initials image or the  user signature image for the specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/signatures/{signatureId}/{imageType}` endpoint.
+     *
+     * As opposed to `get_signature_image`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Retrieves the specified initials image or signature image for the specified user. The image is returned in the same format in which it was uploaded. In the request you can specify if the chrome (the added line and identifier around the initial image) is returned with the image.
+     *
+     * The userId property specified in the endpoint must match the authenticated user's user ID and the user must be a member of the account.
+     *
+     * The `signatureId` parameter accepts a signature ID or a signature name. DocuSign recommends you use signature ID (`signatureId`), since some names contain characters that do not properly encode into a URL. If you use the user name, it is likely that the name includes spaces. In that case, URL encode the name before using it in the endpoint.
+     *
+     * For example encode "Bob Smith" as "Bob%20Smith".
+     *
+     * **Note**: Older envelopes might only have chromed images. If getting the non-chromed image fails, try getting the chromed image.
+     *
+     * **Parameters:**
+     *
+     * * `params: &UserSignaturesGetSignatureImageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_signature_image_stream_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        params: &UserSignaturesGetSignatureImageParams,
+    ) -> Result<crate::DownloadStream> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_chrome.is_empty() {
+        if let Some(include_chrome) = &params.include_chrome {
             query_args.push(("include_chrome".to_string(), include_chrome.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -308,7 +405,28 @@ impl UserSignatures {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `get_signature_image_stream_with` and pass the query parameters as a `UserSignaturesGetSignatureImageParams` instead.
+     */
+    #[deprecated(note = "use `get_signature_image_stream_with` instead")]
+    pub async fn get_signature_image_stream(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        include_chrome: &str,
+    ) -> Result<crate::DownloadStream> {
+        let params = UserSignaturesGetSignatureImageParams {
+            include_chrome: Some(include_chrome.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_signature_image_stream_with(account_id, image_type, signature_id, user_id, &params)
+            .await
     }
 
     /**
@@ -335,18 +453,18 @@ impl UserSignatures {
      * * `signature_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
      *   .
-     * * `transparent_png: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &UserSignaturesPutSignatureImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn put_signature_image(
+    pub async fn put_signature_image_with(
         &self,
         account_id: &str,
         image_type: &str,
         signature_id: &str,
         user_id: &str,
-        transparent_png: &str,
+        params: &UserSignaturesPutSignatureImageParams,
     ) -> Result<crate::types::UserSignature> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !transparent_png.is_empty() {
+        if let Some(transparent_png) = &params.transparent_png {
             query_args.push(("transparent_png".to_string(), transparent_png.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -362,6 +480,25 @@ impl UserSignatures {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `put_signature_image_with` and pass the query parameters as a `UserSignaturesPutSignatureImageParams` instead.
+     */
+    #[deprecated(note = "use `put_signature_image_with` instead")]
+    pub async fn put_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        transparent_png: &str,
+    ) -> Result<crate::types::UserSignature> {
+        let params = UserSignaturesPutSignatureImageParams {
+            transparent_png: Some(transparent_png.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.put_signature_image_with(account_id, image_type, signature_id, user_id, &params)
+            .await
+    }
+
     /**
      * Deletes the user initials image or the  user signature image for the specified user.
      *
@@ -406,3 +543,293 @@ impl UserSignatures {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `UserSignatures::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UserSignaturesGetParams {
+    /// The type of stamps to return. Valid values are:
+    ///
+    /// - `signature`: Returns information about signature images only. This is the default value.
+    /// - `stamp`: Returns information about eHanko and custom stamps only.
+    /// - null.
+    pub stamp_type: Option<String>,
+}
+
+impl UserSignaturesGetParams {
+    pub fn stamp_type<T: ToString>(mut self, stamp_type: T) -> Self {
+        self.stamp_type = Some(stamp_type.to_string());
+        self
+    }
+}
+
+/// The query parameters of `UserSignatures::put_signature_user_signatures_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UserSignaturesPutSignatureUserSignaturesParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub close_existing_signature: Option<String>,
+}
+
+impl UserSignaturesPutSignatureUserSignaturesParams {
+    pub fn close_existing_signature<T: ToString>(mut self, close_existing_signature: T) -> Self {
+        self.close_existing_signature = Some(close_existing_signature.to_string());
+        self
+    }
+}
+
+/// The query parameters of `UserSignatures::get_signature_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UserSignaturesGetSignatureImageParams {
+    /// When **true**, the chrome (or frame containing the added line and identifier) is included with the signature image.
+    pub include_chrome: Option<String>,
+}
+
+impl UserSignaturesGetSignatureImageParams {
+    pub fn include_chrome<T: ToString>(mut self, include_chrome: T) -> Self {
+        self.include_chrome = Some(include_chrome.to_string());
+        self
+    }
+}
+
+/// The query parameters of `UserSignatures::put_signature_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UserSignaturesPutSignatureImageParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub transparent_png: Option<String>,
+}
+
+impl UserSignaturesPutSignatureImageParams {
+    pub fn transparent_png<T: ToString>(mut self, transparent_png: T) -> Self {
+        self.transparent_png = Some(transparent_png.to_string());
+        self
+    }
+}
+
+/// The functions of [`UserSignatures`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUserSignaturesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UserSignaturesApi: Send + Sync {
+    /// See [`UserSignatures::get_with`].
+    async fn get_with(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        params: &UserSignaturesGetParams,
+    ) -> Result<crate::types::UserSignaturesInformation>;
+
+    /// See [`UserSignatures::put_signature`].
+    async fn put_signature(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> Result<crate::types::UserSignaturesInformation>;
+
+    /// See [`UserSignatures::post`].
+    async fn post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> Result<crate::types::UserSignaturesInformation>;
+
+    /// See [`UserSignatures::get_signature`].
+    async fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSignature>;
+
+    /// See [`UserSignatures::put_signature_user_signatures_with`].
+    async fn put_signature_user_signatures_with(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+        params: &UserSignaturesPutSignatureUserSignaturesParams,
+        body: &crate::types::UserSignatureDefinition,
+    ) -> Result<crate::types::UserSignature>;
+
+    /// See [`UserSignatures::delete_signature`].
+    async fn delete_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<()>;
+
+    /// See [`UserSignatures::get_signature_image_with`].
+    async fn get_signature_image_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        params: &UserSignaturesGetSignatureImageParams,
+    ) -> Result<crate::Download>;
+
+    /// See [`UserSignatures::get_signature_image_stream_with`].
+    async fn get_signature_image_stream_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        params: &UserSignaturesGetSignatureImageParams,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`UserSignatures::put_signature_image_with`].
+    async fn put_signature_image_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        params: &UserSignaturesPutSignatureImageParams,
+    ) -> Result<crate::types::UserSignature>;
+
+    /// See [`UserSignatures::delete_signature_image`].
+    async fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSignature>;
+}
+
+#[async_trait::async_trait]
+impl UserSignaturesApi for UserSignatures {
+    async fn get_with(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        params: &UserSignaturesGetParams,
+    ) -> Result<crate::types::UserSignaturesInformation> {
+        UserSignatures::get_with(self, account_id, user_id, params).await
+    }
+
+    async fn put_signature(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> Result<crate::types::UserSignaturesInformation> {
+        UserSignatures::put_signature(self, account_id, user_id, body).await
+    }
+
+    async fn post(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSignaturesInformation,
+    ) -> Result<crate::types::UserSignaturesInformation> {
+        UserSignatures::post(self, account_id, user_id, body).await
+    }
+
+    async fn get_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSignature> {
+        UserSignatures::get_signature(self, account_id, signature_id, user_id).await
+    }
+
+    async fn put_signature_user_signatures_with(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+        params: &UserSignaturesPutSignatureUserSignaturesParams,
+        body: &crate::types::UserSignatureDefinition,
+    ) -> Result<crate::types::UserSignature> {
+        UserSignatures::put_signature_user_signatures_with(
+            self,
+            account_id,
+            signature_id,
+            user_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn delete_signature(
+        &self,
+        account_id: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<()> {
+        UserSignatures::delete_signature(self, account_id, signature_id, user_id).await
+    }
+
+    async fn get_signature_image_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        params: &UserSignaturesGetSignatureImageParams,
+    ) -> Result<crate::Download> {
+        UserSignatures::get_signature_image_with(
+            self,
+            account_id,
+            image_type,
+            signature_id,
+            user_id,
+            params,
+        )
+        .await
+    }
+
+    async fn get_signature_image_stream_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        params: &UserSignaturesGetSignatureImageParams,
+    ) -> Result<crate::DownloadStream> {
+        UserSignatures::get_signature_image_stream_with(
+            self,
+            account_id,
+            image_type,
+            signature_id,
+            user_id,
+            params,
+        )
+        .await
+    }
+
+    async fn put_signature_image_with(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+        params: &UserSignaturesPutSignatureImageParams,
+    ) -> Result<crate::types::UserSignature> {
+        UserSignatures::put_signature_image_with(
+            self,
+            account_id,
+            image_type,
+            signature_id,
+            user_id,
+            params,
+        )
+        .await
+    }
+
+    async fn delete_signature_image(
+        &self,
+        account_id: &str,
+        image_type: &str,
+        signature_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSignature> {
+        UserSignatures::delete_signature_image(self, account_id, image_type, signature_id, user_id)
+            .await
+    }
+}
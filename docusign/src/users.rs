@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Users {
     pub client: Client,
@@ -24,81 +22,48 @@ impl Users {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `additional_info: &str` -- When set to **true**, the custom settings information is returned for each user in the account. If this parameter is omitted, the default behavior is **false**.
-     * * `count: &str` -- The number of records to return. This number must be greater than `0` and less than or equal to `100`. .
-     * * `email: &str` -- Filters results based on the email address associated with the user that you want to return.
-     *   
-     *   **Note**: You can use either this parameter or the `email_substring` parameter, but not both. For older accounts, this parameter might return multiple users who are associated with a single email address.
-     * * `email_substring: &str` -- Filters results based on a fragment of an email address. For example, you could enter `gmail` to return all users who have Gmail addresses.
-     *   
-     *   **Note**: You do not use a wildcard character with this parameter. You can use either this parameter or the `email` parameter, but not both.
-     * * `group_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_usersettings_for_csv: &str` -- When set to **true**, the response includes the `userSettings` object data in CSV format.
-     * * `login_status: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `not_group_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- The position within the total result set from which to start returning values.
-     * * `status: &str` -- Filters results by user account status. Possible values are:
-     *   
-     *   * `ActivationRequired`
-     *   * `ActivationSent`
-     *   * `Active`
-     *   * `Closed`
-     *   * `Disabled`
-     *   .
-     * * `user_name_substring: &str` -- Filters results based on a full or partial user name.
-     *   
-     *   **Note**: When you enter a partial user name, you do not use a wildcard character.
+     * * `params: &UsersGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         account_id: &str,
-        additional_info: &str,
-        count: &str,
-        email: &str,
-        email_substring: &str,
-        group_id: &str,
-        include_usersettings_for_csv: &str,
-        login_status: &str,
-        not_group_id: &str,
-        start_position: &str,
-        status: &str,
-        user_name_substring: &str,
+        params: &UsersGetParams,
     ) -> Result<crate::types::UserInformationList> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !additional_info.is_empty() {
+        if let Some(additional_info) = &params.additional_info {
             query_args.push(("additional_info".to_string(), additional_info.to_string()));
         }
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !email.is_empty() {
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
-        if !email_substring.is_empty() {
+        if let Some(email_substring) = &params.email_substring {
             query_args.push(("email_substring".to_string(), email_substring.to_string()));
         }
-        if !group_id.is_empty() {
+        if let Some(group_id) = &params.group_id {
             query_args.push(("group_id".to_string(), group_id.to_string()));
         }
-        if !include_usersettings_for_csv.is_empty() {
+        if let Some(include_usersettings_for_csv) = &params.include_usersettings_for_csv {
             query_args.push((
                 "include_usersettings_for_csv".to_string(),
                 include_usersettings_for_csv.to_string(),
             ));
         }
-        if !login_status.is_empty() {
+        if let Some(login_status) = &params.login_status {
             query_args.push(("login_status".to_string(), login_status.to_string()));
         }
-        if !not_group_id.is_empty() {
+        if let Some(not_group_id) = &params.not_group_id {
             query_args.push(("not_group_id".to_string(), not_group_id.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !user_name_substring.is_empty() {
+        if let Some(user_name_substring) = &params.user_name_substring {
             query_args.push((
                 "user_name_substring".to_string(),
                 user_name_substring.to_string(),
@@ -114,6 +79,42 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `UsersGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        account_id: &str,
+        additional_info: &str,
+        count: &str,
+        email: &str,
+        email_substring: &str,
+        group_id: &str,
+        include_usersettings_for_csv: &str,
+        login_status: &str,
+        not_group_id: &str,
+        start_position: &str,
+        status: &str,
+        user_name_substring: &str,
+    ) -> Result<crate::types::UserInformationList> {
+        let params = UsersGetParams {
+            additional_info: Some(additional_info.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+            email_substring: Some(email_substring.to_string()).filter(|s| !s.is_empty()),
+            group_id: Some(group_id.to_string()).filter(|s| !s.is_empty()),
+            include_usersettings_for_csv: Some(include_usersettings_for_csv.to_string())
+                .filter(|s| !s.is_empty()),
+            login_status: Some(login_status.to_string()).filter(|s| !s.is_empty()),
+            not_group_id: Some(not_group_id.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            user_name_substring: Some(user_name_substring.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(account_id, &params).await
+    }
+
     /**
      * Changes one or more users in the specified account.
      *
@@ -236,16 +237,16 @@ impl Users {
      * **Parameters:**
      *
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `delete: &str` -- ID of the user to delete. This parameter takes a comma-separated list of values in the format: `Groups,PermissionSet,SigningGroupsEmail`.
+     * * `params: &UsersDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete(
+    pub async fn delete_with(
         &self,
         account_id: &str,
-        delete: &str,
+        params: &UsersDeleteParams,
         body: &crate::types::UserInfoList,
     ) -> Result<crate::types::UsersResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !delete.is_empty() {
+        if let Some(delete) = &params.delete {
             query_args.push(("delete".to_string(), delete.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -260,6 +261,22 @@ impl Users {
             .await
     }
 
+    /**
+     * Deprecated: use `delete_with` and pass the query parameters as a `UsersDeleteParams` instead.
+     */
+    #[deprecated(note = "use `delete_with` instead")]
+    pub async fn delete(
+        &self,
+        account_id: &str,
+        delete: &str,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse> {
+        let params = UsersDeleteParams {
+            delete: Some(delete.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_with(account_id, &params, body).await
+    }
+
     /**
      * Gets the user information for a specified user.
      *
@@ -274,21 +291,19 @@ impl Users {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
      *   .
-     * * `additional_info: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `email: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &UsersGetUsersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_users(
+    pub async fn get_users_with(
         &self,
         account_id: &str,
         user_id: &str,
-        additional_info: &str,
-        email: &str,
+        params: &UsersGetUsersParams,
     ) -> Result<crate::types::UserInformation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !additional_info.is_empty() {
+        if let Some(additional_info) = &params.additional_info {
             query_args.push(("additional_info".to_string(), additional_info.to_string()));
         }
-        if !email.is_empty() {
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -302,6 +317,24 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_users_with` and pass the query parameters as a `UsersGetUsersParams` instead.
+     */
+    #[deprecated(note = "use `get_users_with` instead")]
+    pub async fn get_users(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        additional_info: &str,
+        email: &str,
+    ) -> Result<crate::types::UserInformation> {
+        let params = UsersGetUsersParams {
+            additional_info: Some(additional_info.to_string()).filter(|s| !s.is_empty()),
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_users_with(account_id, user_id, &params).await
+    }
+
     /**
      * Updates user information for the specified user.
      *
@@ -348,16 +381,73 @@ impl Users {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `user_id: &str` -- The ID of the user to access. Generally this is the ID of the current authenticated user, but if the authenticated user is an Administrator on the account, `userId` can represent another user whom the Administrator is accessing.
      *   .
-     * * `encoding: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &UsersProfileImageGetParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn profile_image_get_with(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        params: &UsersProfileImageGetParams,
+    ) -> Result<crate::Download> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(encoding) = &params.encoding {
+            query_args.push(("encoding".to_string(), encoding.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v2.1/accounts/{}/users/{}/profile/image?{}",
+            crate::progenitor_support::encode_path(account_id),
+            crate::progenitor_support::encode_path(user_id),
+            query_
+        );
+
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `profile_image_get_with` and pass the query parameters as a `UsersProfileImageGetParams` instead.
      */
+    #[deprecated(note = "use `profile_image_get_with` instead")]
     pub async fn profile_image_get(
         &self,
         account_id: &str,
         user_id: &str,
         encoding: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
+        let params = UsersProfileImageGetParams {
+            encoding: Some(encoding.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.profile_image_get_with(account_id, user_id, &params)
+            .await
+    }
+
+    /**
+     * Retrieves the user profile image for the specified user.
+     *
+     * This function performs a `GET` to the `/v2.1/accounts/{accountId}/users/{userId}/profile/image` endpoint.
+     *
+     * As opposed to `profile_image_get`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Retrieves the user profile picture for the specified user. The image is returned in the same format as uploaded.
+     *
+     * The userId parameter specified in the endpoint must match the authenticated user's user ID and the user must be a member of the specified account.
+     *
+     * If successful, the response returns a 200 - OK and the user profile image.
+     *
+     * **Parameters:**
+     *
+     * * `params: &UsersProfileImageGetParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn profile_image_get_stream_with(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        params: &UsersProfileImageGetParams,
+    ) -> Result<crate::DownloadStream> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !encoding.is_empty() {
+        if let Some(encoding) = &params.encoding {
             query_args.push(("encoding".to_string(), encoding.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -368,7 +458,26 @@ impl Users {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `profile_image_get_stream_with` and pass the query parameters as a `UsersProfileImageGetParams` instead.
+     */
+    #[deprecated(note = "use `profile_image_get_stream_with` instead")]
+    pub async fn profile_image_get_stream(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        encoding: &str,
+    ) -> Result<crate::DownloadStream> {
+        let params = UsersProfileImageGetParams {
+            encoding: Some(encoding.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.profile_image_get_stream_with(account_id, user_id, &params)
+            .await
     }
 
     /**
@@ -488,3 +597,337 @@ impl Users {
             .await
     }
 }
+
+/// The query parameters of `Users::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersGetParams {
+    /// When set to **true**, the custom settings information is returned for each user in the account. If this parameter is omitted, the default behavior is **false**.
+    pub additional_info: Option<String>,
+    /// The number of records to return. This number must be greater than `0` and less than or equal to `100`. .
+    pub count: Option<String>,
+    /// Filters results based on the email address associated with the user that you want to return.
+    ///
+    /// **Note**: You can use either this parameter or the `email_substring` parameter, but not both. For older accounts, this parameter might return multiple users who are associated with a single email address.
+    pub email: Option<String>,
+    /// Filters results based on a fragment of an email address. For example, you could enter `gmail` to return all users who have Gmail addresses.
+    ///
+    /// **Note**: You do not use a wildcard character with this parameter. You can use either this parameter or the `email` parameter, but not both.
+    pub email_substring: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub group_id: Option<String>,
+    /// When set to **true**, the response includes the `userSettings` object data in CSV format.
+    pub include_usersettings_for_csv: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub login_status: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub not_group_id: Option<String>,
+    /// The position within the total result set from which to start returning values.
+    pub start_position: Option<String>,
+    /// Filters results by user account status. Possible values are:
+    ///
+    /// * `ActivationRequired`
+    /// * `ActivationSent`
+    /// * `Active`
+    /// * `Closed`
+    /// * `Disabled`
+    ///   .
+    pub status: Option<String>,
+    /// Filters results based on a full or partial user name.
+    ///
+    /// **Note**: When you enter a partial user name, you do not use a wildcard character.
+    pub user_name_substring: Option<String>,
+}
+
+impl UsersGetParams {
+    pub fn additional_info<T: ToString>(mut self, additional_info: T) -> Self {
+        self.additional_info = Some(additional_info.to_string());
+        self
+    }
+
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn email<T: ToString>(mut self, email: T) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+
+    pub fn email_substring<T: ToString>(mut self, email_substring: T) -> Self {
+        self.email_substring = Some(email_substring.to_string());
+        self
+    }
+
+    pub fn group_id<T: ToString>(mut self, group_id: T) -> Self {
+        self.group_id = Some(group_id.to_string());
+        self
+    }
+
+    pub fn include_usersettings_for_csv<T: ToString>(
+        mut self,
+        include_usersettings_for_csv: T,
+    ) -> Self {
+        self.include_usersettings_for_csv = Some(include_usersettings_for_csv.to_string());
+        self
+    }
+
+    pub fn login_status<T: ToString>(mut self, login_status: T) -> Self {
+        self.login_status = Some(login_status.to_string());
+        self
+    }
+
+    pub fn not_group_id<T: ToString>(mut self, not_group_id: T) -> Self {
+        self.not_group_id = Some(not_group_id.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn user_name_substring<T: ToString>(mut self, user_name_substring: T) -> Self {
+        self.user_name_substring = Some(user_name_substring.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Users::delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersDeleteParams {
+    /// ID of the user to delete. This parameter takes a comma-separated list of values in the format: `Groups,PermissionSet,SigningGroupsEmail`.
+    pub delete: Option<String>,
+}
+
+impl UsersDeleteParams {
+    pub fn delete<T: ToString>(mut self, delete: T) -> Self {
+        self.delete = Some(delete.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Users::get_users_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersGetUsersParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub additional_info: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub email: Option<String>,
+}
+
+impl UsersGetUsersParams {
+    pub fn additional_info<T: ToString>(mut self, additional_info: T) -> Self {
+        self.additional_info = Some(additional_info.to_string());
+        self
+    }
+
+    pub fn email<T: ToString>(mut self, email: T) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Users::profile_image_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersProfileImageGetParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub encoding: Option<String>,
+}
+
+impl UsersProfileImageGetParams {
+    pub fn encoding<T: ToString>(mut self, encoding: T) -> Self {
+        self.encoding = Some(encoding.to_string());
+        self
+    }
+}
+
+/// The functions of [`Users`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUsersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UsersApi: Send + Sync {
+    /// See [`Users::get_with`].
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &UsersGetParams,
+    ) -> Result<crate::types::UserInformationList>;
+
+    /// See [`Users::put`].
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::UserInformationList,
+    ) -> Result<crate::types::UserInformationList>;
+
+    /// See [`Users::post`].
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::NewUsersDefinition,
+    ) -> Result<crate::types::NewUsersSummary>;
+
+    /// See [`Users::delete_with`].
+    async fn delete_with(
+        &self,
+        account_id: &str,
+        params: &UsersDeleteParams,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse>;
+
+    /// See [`Users::get_users_with`].
+    async fn get_users_with(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        params: &UsersGetUsersParams,
+    ) -> Result<crate::types::UserInformation>;
+
+    /// See [`Users::put_users`].
+    async fn put_users(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserInformation,
+    ) -> Result<crate::types::UserInformation>;
+
+    /// See [`Users::profile_image_get_with`].
+    async fn profile_image_get_with(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        params: &UsersProfileImageGetParams,
+    ) -> Result<crate::Download>;
+
+    /// See [`Users::profile_image_get_stream_with`].
+    async fn profile_image_get_stream_with(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        params: &UsersProfileImageGetParams,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`Users::profile_image_put`].
+    async fn profile_image_put(&self, account_id: &str, user_id: &str) -> Result<()>;
+
+    /// See [`Users::profile_image_delete`].
+    async fn profile_image_delete(&self, account_id: &str, user_id: &str) -> Result<()>;
+
+    /// See [`Users::settings_get`].
+    async fn settings_get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSettingsInformation>;
+
+    /// See [`Users::settings_put`].
+    async fn settings_put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSettingsInformation,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl UsersApi for Users {
+    async fn get_with(
+        &self,
+        account_id: &str,
+        params: &UsersGetParams,
+    ) -> Result<crate::types::UserInformationList> {
+        Users::get_with(self, account_id, params).await
+    }
+
+    async fn put(
+        &self,
+        account_id: &str,
+        body: &crate::types::UserInformationList,
+    ) -> Result<crate::types::UserInformationList> {
+        Users::put(self, account_id, body).await
+    }
+
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::NewUsersDefinition,
+    ) -> Result<crate::types::NewUsersSummary> {
+        Users::post(self, account_id, body).await
+    }
+
+    async fn delete_with(
+        &self,
+        account_id: &str,
+        params: &UsersDeleteParams,
+        body: &crate::types::UserInfoList,
+    ) -> Result<crate::types::UsersResponse> {
+        Users::delete_with(self, account_id, params, body).await
+    }
+
+    async fn get_users_with(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        params: &UsersGetUsersParams,
+    ) -> Result<crate::types::UserInformation> {
+        Users::get_users_with(self, account_id, user_id, params).await
+    }
+
+    async fn put_users(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserInformation,
+    ) -> Result<crate::types::UserInformation> {
+        Users::put_users(self, account_id, user_id, body).await
+    }
+
+    async fn profile_image_get_with(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        params: &UsersProfileImageGetParams,
+    ) -> Result<crate::Download> {
+        Users::profile_image_get_with(self, account_id, user_id, params).await
+    }
+
+    async fn profile_image_get_stream_with(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        params: &UsersProfileImageGetParams,
+    ) -> Result<crate::DownloadStream> {
+        Users::profile_image_get_stream_with(self, account_id, user_id, params).await
+    }
+
+    async fn profile_image_put(&self, account_id: &str, user_id: &str) -> Result<()> {
+        Users::profile_image_put(self, account_id, user_id).await
+    }
+
+    async fn profile_image_delete(&self, account_id: &str, user_id: &str) -> Result<()> {
+        Users::profile_image_delete(self, account_id, user_id).await
+    }
+
+    async fn settings_get(
+        &self,
+        account_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::UserSettingsInformation> {
+        Users::settings_get(self, account_id, user_id).await
+    }
+
+    async fn settings_put(
+        &self,
+        account_id: &str,
+        user_id: &str,
+        body: &crate::types::UserSettingsInformation,
+    ) -> Result<()> {
+        Users::settings_put(self, account_id, user_id, body).await
+    }
+}
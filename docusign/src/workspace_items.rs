@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct WorkspaceItems {
     pub client: Client,
@@ -24,56 +22,44 @@ impl WorkspaceItems {
      * * `account_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `folder_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `workspace_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `include_files: &str` -- When set to **true**, the response includes file information (in addition to folder information). The default is **false**.
-     * * `include_sub_folders: &str` -- When set to **true**, the response includes information about the sub-folders of the current folder. The default is **false**.
-     * * `include_thumbnails: &str` -- When set to **true**, the response returns thumbnails.  The default is **false**.
-     * * `include_user_detail: &str` -- When set to **true**, the response includes extended details about the user. The default is **false**.
-     * * `start_position: &str` -- The position within the total result set from which to start returning values.
-     * * `workspace_user_id: &str` -- If set, the response only includes results associated with the `userId` that you specify.
+     * * `params: &WorkspaceItemsWorkspaceFolderGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn workspace_folder_get(
+    pub async fn workspace_folder_get_with(
         &self,
         account_id: &str,
         folder_id: &str,
         workspace_id: &str,
-        count: &str,
-        include_files: &str,
-        include_sub_folders: &str,
-        include_thumbnails: &str,
-        include_user_detail: &str,
-        start_position: &str,
-        workspace_user_id: &str,
+        params: &WorkspaceItemsWorkspaceFolderGetParams,
     ) -> Result<crate::types::WorkspaceFolderContents> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !include_files.is_empty() {
+        if let Some(include_files) = &params.include_files {
             query_args.push(("include_files".to_string(), include_files.to_string()));
         }
-        if !include_sub_folders.is_empty() {
+        if let Some(include_sub_folders) = &params.include_sub_folders {
             query_args.push((
                 "include_sub_folders".to_string(),
                 include_sub_folders.to_string(),
             ));
         }
-        if !include_thumbnails.is_empty() {
+        if let Some(include_thumbnails) = &params.include_thumbnails {
             query_args.push((
                 "include_thumbnails".to_string(),
                 include_thumbnails.to_string(),
             ));
         }
-        if !include_user_detail.is_empty() {
+        if let Some(include_user_detail) = &params.include_user_detail {
             query_args.push((
                 "include_user_detail".to_string(),
                 include_user_detail.to_string(),
             ));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
-        if !workspace_user_id.is_empty() {
+        if let Some(workspace_user_id) = &params.workspace_user_id {
             query_args.push((
                 "workspace_user_id".to_string(),
                 workspace_user_id.to_string(),
@@ -91,6 +77,36 @@ impl WorkspaceItems {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `workspace_folder_get_with` and pass the query parameters as a `WorkspaceItemsWorkspaceFolderGetParams` instead.
+     */
+    #[deprecated(note = "use `workspace_folder_get_with` instead")]
+    pub async fn workspace_folder_get(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        count: &str,
+        include_files: &str,
+        include_sub_folders: &str,
+        include_thumbnails: &str,
+        include_user_detail: &str,
+        start_position: &str,
+        workspace_user_id: &str,
+    ) -> Result<crate::types::WorkspaceFolderContents> {
+        let params = WorkspaceItemsWorkspaceFolderGetParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            include_files: Some(include_files.to_string()).filter(|s| !s.is_empty()),
+            include_sub_folders: Some(include_sub_folders.to_string()).filter(|s| !s.is_empty()),
+            include_thumbnails: Some(include_thumbnails.to_string()).filter(|s| !s.is_empty()),
+            include_user_detail: Some(include_user_detail.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+            workspace_user_id: Some(workspace_user_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.workspace_folder_get_with(account_id, folder_id, workspace_id, &params)
+            .await
+    }
+
     /**
      * Deletes files or sub-folders from a workspace.
      *
@@ -167,23 +183,21 @@ impl WorkspaceItems {
      * * `file_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `folder_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `workspace_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `is_download: &str` -- When set to **true**, the `Content-Disposition` header is set in the response. The value of the header provides the filename of the file. The default is **false**.
-     * * `pdf_version: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
+     * * `params: &WorkspaceItemsWorkspaceFileGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn workspace_file_get(
+    pub async fn workspace_file_get_with(
         &self,
         account_id: &str,
         file_id: &str,
         folder_id: &str,
         workspace_id: &str,
-        is_download: &str,
-        pdf_version: &str,
+        params: &WorkspaceItemsWorkspaceFileGetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !is_download.is_empty() {
+        if let Some(is_download) = &params.is_download {
             query_args.push(("is_download".to_string(), is_download.to_string()));
         }
-        if !pdf_version.is_empty() {
+        if let Some(pdf_version) = &params.pdf_version {
             query_args.push(("pdf_version".to_string(), pdf_version.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -199,6 +213,27 @@ impl WorkspaceItems {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `workspace_file_get_with` and pass the query parameters as a `WorkspaceItemsWorkspaceFileGetParams` instead.
+     */
+    #[deprecated(note = "use `workspace_file_get_with` instead")]
+    pub async fn workspace_file_get(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        is_download: &str,
+        pdf_version: &str,
+    ) -> Result<()> {
+        let params = WorkspaceItemsWorkspaceFileGetParams {
+            is_download: Some(is_download.to_string()).filter(|s| !s.is_empty()),
+            pdf_version: Some(pdf_version.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.workspace_file_get_with(account_id, file_id, folder_id, workspace_id, &params)
+            .await
+    }
+
     /**
      * Update workspace file or folder metadata.
      *
@@ -244,38 +279,30 @@ impl WorkspaceItems {
      * * `file_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `folder_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
      * * `workspace_id: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `count: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `dpi: &str` -- The number of dots per inch (DPI) for the resulting images. Valid values are 1-310 DPI. The default value is 94.
-     * * `max_height: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `max_width: &str` -- The brand that envelope recipients see when a brand is not explicitly set.
-     * * `start_position: &str` -- The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
+     * * `params: &WorkspaceItemsWorkspaceFilePagesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn workspace_file_pages_get(
+    pub async fn workspace_file_pages_get_with(
         &self,
         account_id: &str,
         file_id: &str,
         folder_id: &str,
         workspace_id: &str,
-        count: &str,
-        dpi: &str,
-        max_height: &str,
-        max_width: &str,
-        start_position: &str,
+        params: &WorkspaceItemsWorkspaceFilePagesGetParams,
     ) -> Result<crate::types::PageImages> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !dpi.is_empty() {
+        if let Some(dpi) = &params.dpi {
             query_args.push(("dpi".to_string(), dpi.to_string()));
         }
-        if !max_height.is_empty() {
+        if let Some(max_height) = &params.max_height {
             query_args.push(("max_height".to_string(), max_height.to_string()));
         }
-        if !max_width.is_empty() {
+        if let Some(max_width) = &params.max_width {
             query_args.push(("max_width".to_string(), max_width.to_string()));
         }
-        if !start_position.is_empty() {
+        if let Some(start_position) = &params.start_position {
             query_args.push(("start_position".to_string(), start_position.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -290,4 +317,299 @@ impl WorkspaceItems {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `workspace_file_pages_get_with` and pass the query parameters as a `WorkspaceItemsWorkspaceFilePagesGetParams` instead.
+     */
+    #[deprecated(note = "use `workspace_file_pages_get_with` instead")]
+    pub async fn workspace_file_pages_get(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        count: &str,
+        dpi: &str,
+        max_height: &str,
+        max_width: &str,
+        start_position: &str,
+    ) -> Result<crate::types::PageImages> {
+        let params = WorkspaceItemsWorkspaceFilePagesGetParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            dpi: Some(dpi.to_string()).filter(|s| !s.is_empty()),
+            max_height: Some(max_height.to_string()).filter(|s| !s.is_empty()),
+            max_width: Some(max_width.to_string()).filter(|s| !s.is_empty()),
+            start_position: Some(start_position.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.workspace_file_pages_get_with(account_id, file_id, folder_id, workspace_id, &params)
+            .await
+    }
+}
+
+/// The query parameters of `WorkspaceItems::workspace_folder_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceItemsWorkspaceFolderGetParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub count: Option<String>,
+    /// When set to **true**, the response includes file information (in addition to folder information). The default is **false**.
+    pub include_files: Option<String>,
+    /// When set to **true**, the response includes information about the sub-folders of the current folder. The default is **false**.
+    pub include_sub_folders: Option<String>,
+    /// When set to **true**, the response returns thumbnails.  The default is **false**.
+    pub include_thumbnails: Option<String>,
+    /// When set to **true**, the response includes extended details about the user. The default is **false**.
+    pub include_user_detail: Option<String>,
+    /// The position within the total result set from which to start returning values.
+    pub start_position: Option<String>,
+    /// If set, the response only includes results associated with the `userId` that you specify.
+    pub workspace_user_id: Option<String>,
+}
+
+impl WorkspaceItemsWorkspaceFolderGetParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn include_files<T: ToString>(mut self, include_files: T) -> Self {
+        self.include_files = Some(include_files.to_string());
+        self
+    }
+
+    pub fn include_sub_folders<T: ToString>(mut self, include_sub_folders: T) -> Self {
+        self.include_sub_folders = Some(include_sub_folders.to_string());
+        self
+    }
+
+    pub fn include_thumbnails<T: ToString>(mut self, include_thumbnails: T) -> Self {
+        self.include_thumbnails = Some(include_thumbnails.to_string());
+        self
+    }
+
+    pub fn include_user_detail<T: ToString>(mut self, include_user_detail: T) -> Self {
+        self.include_user_detail = Some(include_user_detail.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+
+    pub fn workspace_user_id<T: ToString>(mut self, workspace_user_id: T) -> Self {
+        self.workspace_user_id = Some(workspace_user_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `WorkspaceItems::workspace_file_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceItemsWorkspaceFileGetParams {
+    /// When set to **true**, the `Content-Disposition` header is set in the response. The value of the header provides the filename of the file. The default is **false**.
+    pub is_download: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub pdf_version: Option<String>,
+}
+
+impl WorkspaceItemsWorkspaceFileGetParams {
+    pub fn is_download<T: ToString>(mut self, is_download: T) -> Self {
+        self.is_download = Some(is_download.to_string());
+        self
+    }
+
+    pub fn pdf_version<T: ToString>(mut self, pdf_version: T) -> Self {
+        self.pdf_version = Some(pdf_version.to_string());
+        self
+    }
+}
+
+/// The query parameters of `WorkspaceItems::workspace_file_pages_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceItemsWorkspaceFilePagesGetParams {
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub count: Option<String>,
+    /// The number of dots per inch (DPI) for the resulting images. Valid values are 1-310 DPI. The default value is 94.
+    pub dpi: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub max_height: Option<String>,
+    /// The brand that envelope recipients see when a brand is not explicitly set.
+    pub max_width: Option<String>,
+    /// The position within the total result set from which to start returning values. The value **thumbnail** may be used to return the page image.
+    pub start_position: Option<String>,
+}
+
+impl WorkspaceItemsWorkspaceFilePagesGetParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn dpi<T: ToString>(mut self, dpi: T) -> Self {
+        self.dpi = Some(dpi.to_string());
+        self
+    }
+
+    pub fn max_height<T: ToString>(mut self, max_height: T) -> Self {
+        self.max_height = Some(max_height.to_string());
+        self
+    }
+
+    pub fn max_width<T: ToString>(mut self, max_width: T) -> Self {
+        self.max_width = Some(max_width.to_string());
+        self
+    }
+
+    pub fn start_position<T: ToString>(mut self, start_position: T) -> Self {
+        self.start_position = Some(start_position.to_string());
+        self
+    }
+}
+
+/// The functions of [`WorkspaceItems`], for code that takes them as a trait, so that it can be
+/// tested with the `MockWorkspaceItemsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait WorkspaceItemsApi: Send + Sync {
+    /// See [`WorkspaceItems::workspace_folder_get_with`].
+    async fn workspace_folder_get_with(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        params: &WorkspaceItemsWorkspaceFolderGetParams,
+    ) -> Result<crate::types::WorkspaceFolderContents>;
+
+    /// See [`WorkspaceItems::workspace_folder_delete_items`].
+    async fn workspace_folder_delete_items(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        body: &crate::types::WorkspaceItemList,
+    ) -> Result<()>;
+
+    /// See [`WorkspaceItems::workspace_file_post_files`].
+    async fn workspace_file_post_files(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::WorkspaceItem>;
+
+    /// See [`WorkspaceItems::workspace_file_get_with`].
+    async fn workspace_file_get_with(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        params: &WorkspaceItemsWorkspaceFileGetParams,
+    ) -> Result<()>;
+
+    /// See [`WorkspaceItems::workspace_file_put`].
+    async fn workspace_file_put(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::WorkspaceItem>;
+
+    /// See [`WorkspaceItems::workspace_file_pages_get_with`].
+    async fn workspace_file_pages_get_with(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        params: &WorkspaceItemsWorkspaceFilePagesGetParams,
+    ) -> Result<crate::types::PageImages>;
+}
+
+#[async_trait::async_trait]
+impl WorkspaceItemsApi for WorkspaceItems {
+    async fn workspace_folder_get_with(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        params: &WorkspaceItemsWorkspaceFolderGetParams,
+    ) -> Result<crate::types::WorkspaceFolderContents> {
+        WorkspaceItems::workspace_folder_get_with(self, account_id, folder_id, workspace_id, params)
+            .await
+    }
+
+    async fn workspace_folder_delete_items(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        body: &crate::types::WorkspaceItemList,
+    ) -> Result<()> {
+        WorkspaceItems::workspace_folder_delete_items(
+            self,
+            account_id,
+            folder_id,
+            workspace_id,
+            body,
+        )
+        .await
+    }
+
+    async fn workspace_file_post_files(
+        &self,
+        account_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::WorkspaceItem> {
+        WorkspaceItems::workspace_file_post_files(self, account_id, folder_id, workspace_id).await
+    }
+
+    async fn workspace_file_get_with(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        params: &WorkspaceItemsWorkspaceFileGetParams,
+    ) -> Result<()> {
+        WorkspaceItems::workspace_file_get_with(
+            self,
+            account_id,
+            file_id,
+            folder_id,
+            workspace_id,
+            params,
+        )
+        .await
+    }
+
+    async fn workspace_file_put(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::WorkspaceItem> {
+        WorkspaceItems::workspace_file_put(self, account_id, file_id, folder_id, workspace_id).await
+    }
+
+    async fn workspace_file_pages_get_with(
+        &self,
+        account_id: &str,
+        file_id: &str,
+        folder_id: &str,
+        workspace_id: &str,
+        params: &WorkspaceItemsWorkspaceFilePagesGetParams,
+    ) -> Result<crate::types::PageImages> {
+        WorkspaceItems::workspace_file_pages_get_with(
+            self,
+            account_id,
+            file_id,
+            folder_id,
+            workspace_id,
+            params,
+        )
+        .await
+    }
 }
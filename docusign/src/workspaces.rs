@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Workspaces {
     pub client: Client,
@@ -139,3 +137,78 @@ impl Workspaces {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`Workspaces`], for code that takes them as a trait, so that it can be
+/// tested with the `MockWorkspacesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait WorkspacesApi: Send + Sync {
+    /// See [`Workspaces::get`].
+    async fn get(&self, account_id: &str) -> Result<crate::types::WorkspaceList>;
+
+    /// See [`Workspaces::post`].
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::Workspace,
+    ) -> Result<crate::types::Workspace>;
+
+    /// See [`Workspaces::get_workspaces`].
+    async fn get_workspaces(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::Workspace>;
+
+    /// See [`Workspaces::put`].
+    async fn put(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+        body: &crate::types::Workspace,
+    ) -> Result<crate::types::Workspace>;
+
+    /// See [`Workspaces::delete`].
+    async fn delete(&self, account_id: &str, workspace_id: &str)
+        -> Result<crate::types::Workspace>;
+}
+
+#[async_trait::async_trait]
+impl WorkspacesApi for Workspaces {
+    async fn get(&self, account_id: &str) -> Result<crate::types::WorkspaceList> {
+        Workspaces::get(self, account_id).await
+    }
+
+    async fn post(
+        &self,
+        account_id: &str,
+        body: &crate::types::Workspace,
+    ) -> Result<crate::types::Workspace> {
+        Workspaces::post(self, account_id, body).await
+    }
+
+    async fn get_workspaces(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::Workspace> {
+        Workspaces::get_workspaces(self, account_id, workspace_id).await
+    }
+
+    async fn put(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+        body: &crate::types::Workspace,
+    ) -> Result<crate::types::Workspace> {
+        Workspaces::put(self, account_id, workspace_id, body).await
+    }
+
+    async fn delete(
+        &self,
+        account_id: &str,
+        workspace_id: &str,
+    ) -> Result<crate::types::Workspace> {
+        Workspaces::delete(self, account_id, workspace_id).await
+    }
+}
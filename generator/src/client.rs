@@ -1,19 +1,216 @@
 use inflector::cases::snakecase::to_snake_case;
 
+use crate::config::{Auth, Config};
+
 /*
  * Declare the client object:
  */
+pub const DOWNLOAD_TEMPLATE: &str = r#"/// The body of a response that is not JSON, such as a PDF, an image or an archive.
+#[derive(Debug, Clone, Default)]
+pub struct Download {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: bytes::Bytes,
+}
+
+/// The body of a response that is not JSON, returned as a stream of chunks rather
+/// than read into memory all at once.
+pub struct DownloadStream {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: std::pin::Pin<Box<dyn futures::Stream<Item = Result<bytes::Bytes>> + Send>>,
+}
+
+impl std::fmt::Debug for DownloadStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DownloadStream")
+            .field("content_type", &self.content_type)
+            .finish()
+    }
+}"#;
+
+pub const CLIENT_BUILDER_TEMPLATE: &str = r#"/// Configures the HTTP client of a `Client`, with the defaults of `Client::new` unless
+/// told otherwise. Its `build` takes what `Client::new` does.
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    retry_policy: crate::retry::RetryPolicy,
+    max_rate_limit_wait: Option<std::time::Duration>,
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    user_agent: Option<String>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    root_certificates: Vec<reqwest::Certificate>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    identity: Option<reqwest::Identity>,
+    http_client: Option<reqwest_middleware::ClientWithMiddleware>,
+}
+
+impl ClientBuilder {
+    /// Set how failed requests are retried.
+    pub fn retry_policy(mut self, retry_policy: crate::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set how long requests wait for a rate limit to reset, at most,
+    /// `rate_limiter::MAX_WAIT` unless set.
+    pub fn max_rate_limit_wait(mut self, max_wait: std::time::Duration) -> Self {
+        self.max_rate_limit_wait = Some(max_wait);
+        self
+    }
+
+    /// Set how long each attempt at a request can take, from connecting to reading the
+    /// whole response. There is no limit unless set.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set how long connecting to the API can take.
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests through a proxy, like `reqwest::Proxy::https(url)`. Without one,
+    /// the proxies of the `HTTP_PROXY` and `HTTPS_PROXY` environment variables are used.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Set the `User-Agent` of requests.
+    pub fn user_agent<U>(mut self, user_agent: U) -> Self
+    where
+        U: ToString,
+    {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Trust the certificates signed by a certificate authority, along with those of
+    /// the system, like a private one of `reqwest::Certificate::from_pem(pem)`.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Authenticate to the API with a client certificate, for mutual TLS.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn identity(mut self, identity: reqwest::Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Send requests with a client of your own, with its own middleware. The other
+    /// settings of the builder don't apply to it, and it only retries or waits out
+    /// rate limits if it has a `retry::Retry` or a `rate_limiter::RateLimiter` of its
+    /// own.
+    pub fn http_client(mut self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /*
+     * The HTTP client, along with the rate limiter in its middleware.
+     */
+    fn http(
+        self,
+    ) -> Result<(
+        reqwest_middleware::ClientWithMiddleware,
+        crate::rate_limiter::RateLimiter,
+    )> {
+        if let Some(client) = self.http_client {
+            return Ok((client, Default::default()));
+        }
+
+        let mut rate_limiter =
+            crate::rate_limiter::RateLimiter::new().with_retry_policy(self.retry_policy.clone());
+        if let Some(max_wait) = self.max_rate_limit_wait {
+            rate_limiter = rate_limiter.with_max_wait(max_wait);
+        }
+
+        let mut http = reqwest::Client::builder();
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        for proxy in self.proxies {
+            http = http.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(user_agent);
+        }
+        #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+        {
+            for certificate in self.root_certificates {
+                http = http.add_root_certificate(certificate);
+            }
+            if let Some(identity) = self.identity {
+                http = http.identity(identity);
+            }
+        }
+
+        let client = reqwest_middleware::ClientBuilder::new(http.build()?)
+            // Trace HTTP requests. See the tracing crate to make use of these traces.
+            .with(reqwest_tracing::TracingMiddleware::default())
+            // Retry failed requests.
+            .with(crate::retry::Retry::new(self.retry_policy))
+            // Wait out the rate limits of the API, before the retries see them.
+            .with(rate_limiter.clone())
+            .build();
+
+        Ok((client, rate_limiter))
+    }
+}
+
+impl Client {
+    /// Configure the client before creating it.
+    pub fn builder() -> ClientBuilder {
+        Default::default()
+    }
+}"#;
+
 pub const GITHUB_TEMPLATE: &str = r#"/// Entrypoint for interacting with the API client.
 #[derive(Clone)]
 pub struct Client {
     host: String,
     agent: String,
     client: reqwest_middleware::ClientWithMiddleware,
+    rate_limiter: crate::rate_limiter::RateLimiter,
     credentials: Option<crate::auth::Credentials>,
     #[cfg(feature = "httpcache")]
     http_cache: crate::http_cache::BoxedHttpCache,
 }
 
+impl ClientBuilder {
+    /// Create the client, see `Client::new`. Its requests are sent with the `agent` as
+    /// their `User-Agent`, whatever the builder says.
+    pub fn build<A, C>(self, agent: A, credentials: C) -> Result<Client>
+    where
+        A: Into<String>,
+        C: Into<Option<crate::auth::Credentials>>,
+    {
+        let (http, rate_limiter) = self.http()?;
+        #[cfg(feature = "httpcache")]
+        let mut c = Client::custom(
+            DEFAULT_HOST,
+            agent,
+            credentials,
+            http,
+            <dyn crate::http_cache::HttpCache>::noop(),
+        );
+        #[cfg(not(feature = "httpcache"))]
+        let mut c = Client::custom(DEFAULT_HOST, agent, credentials, http);
+        c.rate_limiter = rate_limiter;
+        Ok(c)
+    }
+}
+
 impl Client {
     pub fn new<A, C>(agent: A, credentials: C) -> Result<Self>
     where
@@ -29,33 +226,9 @@ impl Client {
         A: Into<String>,
         C: Into<Option<crate::auth::Credentials>>,
     {
-        let http = reqwest::Client::builder().build()?;
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-                let client = reqwest_middleware::ClientBuilder::new(http)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
-                    .build();
-        #[cfg(feature = "httpcache")]
-        {
-            Ok(Self::custom(
-                host,
-                agent,
-                credentials,
-                client,
-                <dyn crate::http_cache::HttpCache>::noop(),
-            ))
-        }
-        #[cfg(not(feature = "httpcache"))]
-        {
-            Ok(Self::custom(host, agent, credentials, client))
-        }
+        let mut c = Self::builder().build(agent, credentials)?;
+        c.host = host.into();
+        Ok(c)
     }
 
     #[cfg(feature = "httpcache")]
@@ -75,6 +248,8 @@ impl Client {
             host: host.into(),
             agent: agent.into(),
             client: http,
+            // Only the middleware of `http` can wait out the rate limits.
+            rate_limiter: Default::default(),
             credentials: credentials.into(),
             http_cache,
         }
@@ -91,6 +266,8 @@ impl Client {
             host: host.into(),
             agent: agent.into(),
             client: http,
+            // Only the middleware of `http` can wait out the rate limits.
+            rate_limiter: Default::default(),
             credentials: credentials.into(),
         }
     }
@@ -102,6 +279,12 @@ impl Client {
         self.credentials = credentials.into();
     }
 
+    /// The status of the rate limits of the API, as of the last responses. Clients made
+    /// with `custom` don't keep track of it.
+    pub fn rate_limit_status(&self) -> Vec<crate::rate_limiter::RateLimitStatus> {
+        self.rate_limiter.status()
+    }
+
     fn credentials(&self, authentication: crate::auth::AuthenticationConstraint) -> Option<&crate::auth::Credentials> {
         match (authentication, self.credentials.as_ref()) {
             (crate::auth::AuthenticationConstraint::Unconstrained, creds) => creds,
@@ -228,6 +411,7 @@ impl Client {
         let (remaining, reset, etag) = crate::utils::get_header_values(response.headers());
 
         let status = response.status();
+        let headers = response.headers().clone();
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -255,11 +439,11 @@ impl Client {
             }
 
             let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
+                crate::error::decode::<Out>(&response_body)
             };
-            parsed_response.map(|out| (link, out)).map_err(Error::from)
+            parsed_response.map(|out| (link, out))
         } else if status == http::StatusCode::NOT_MODIFIED {
                 // only supported case is when client provides if-none-match
                 // header when cargo builds with --cfg feature="httpcache"
@@ -267,15 +451,18 @@ impl Client {
                 {
                     let body = instance2.http_cache.lookup_body(&uri3).unwrap();
                     let out = serde_json::from_str::<Out>(&body).unwrap();
+                    // failing to look up the cached link isn't fatal, treat it as the last page
                     let link = match link {
-                        Some(link) => Ok(Some(link)),
+                        Some(link) => Some(link),
                         None => instance2.http_cache.lookup_next_link(&uri3)
-                                    .map(|next_link| next_link.map(|next| {
+                                    .ok()
+                                    .flatten()
+                                    .map(|next| {
                                         let next = hyperx::header::LinkValue::new(next).push_rel(hyperx::header::RelationType::Next);
                                         hyperx::header::Link::new(vec![next])
-                                    }))
+                                    })
                     };
-                    link.map(|link| (link, out))
+                    Ok((link, out))
                 }
                 #[cfg(not(feature = "httpcache"))]
                 {
@@ -288,20 +475,117 @@ impl Client {
                         .duration_since(std::time::UNIX_EPOCH)
                         .unwrap()
                         .as_secs();
-                    anyhow!("rate limit exceeded, will reset in {} seconds", u64::from(reset) - now)
-                },
-                _ => {
-                    if response_body.is_empty() {
-                        anyhow!("code: {}, empty response", status)
-                    } else {
-                        anyhow!("code: {}, error: {:?}", status, String::from_utf8_lossy(&response_body),)
+                    crate::Error::RateLimited {
+                        reset: Some(std::time::Duration::from_secs(u64::from(reset).saturating_sub(now))),
                     }
-                }
+                },
+                _ => crate::Error::from_response(status, headers, &response_body),
             };
             Err(error)
         }
     }
 
+    async fn request_raw(
+        &self,
+        method: http::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+        accept: &str,
+        authentication: crate::auth::AuthenticationConstraint,
+    ) -> Result<reqwest::Response> {
+        let (url, auth) = self.url_and_auth(uri, authentication).await?;
+
+        let instance = <&Client>::clone(&self);
+
+        let mut req = instance.client.request(method, url);
+
+        req = req.header(http::header::USER_AGENT, &*instance.agent);
+        req = req.header(
+            http::header::ACCEPT,
+            reqwest::header::HeaderValue::from_str(accept)?,
+        );
+
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+
+        if let Some(body) = body {
+            req = req.body(body);
+        }
+
+        Ok(req.send().await?)
+    }
+
+    async fn request_download(
+        &self,
+        method: http::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::Download> {
+        let response = self.request_raw(
+            method,
+            &(self.host.clone() + uri),
+            body,
+            "*/*",
+            crate::auth::AuthenticationConstraint::Unconstrained,
+        ).await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let content_type = response
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|c| c.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            Ok(crate::Download {
+                content_type,
+                body: response_body,
+            })
+        } else {
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    async fn request_download_stream(
+        &self,
+        method: http::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::DownloadStream> {
+        let response = self.request_raw(
+            method,
+            &(self.host.clone() + uri),
+            body,
+            "*/*",
+            crate::auth::AuthenticationConstraint::Unconstrained,
+        ).await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        if status.is_success() {
+            let content_type = response
+                .headers()
+                .get(http::header::CONTENT_TYPE)
+                .and_then(|c| c.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+
+            Ok(crate::DownloadStream {
+                content_type,
+                body: Box::pin(futures::TryStreamExt::map_err(response.bytes_stream(), Error::from)),
+            })
+        } else {
+            let response_body = response.bytes().await?;
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
     async fn request_entity<D>(
         &self,
         method: http::Method,
@@ -468,7 +752,7 @@ impl Client {
         while !items.is_empty() {
             global_items.append(&mut items);
             // We need to get the next link.
-            if let Some(url) = link.as_ref().and_then(|l| crate::utils::next_link(l)) {
+            if let Some(url) = link.as_ref().and_then(crate::utils::next_link) {
                 let url = reqwest::Url::parse(&url)?;
                 let (new_link, new_items) = self.get_pages_url(&url).await?;
                 link = new_link;
@@ -479,12 +763,8 @@ impl Client {
         Ok(global_items)
     }"#;
 
-pub fn generate_client_generic_token(
-    proper_name: &str,
-    token_endpoint: &str,
-    user_consent_endpoint: &str,
-    add_post_header: &str,
-) -> String {
+pub fn generate_client_generic_token(proper_name: &str, config: &Config) -> String {
+    let add_post_header = config.post_header.as_str();
     let mut new_from_env = basic_new_from_env(proper_name, add_post_header);
     if proper_name.starts_with("Google") {
         new_from_env = GOOGLE_NEW_FROM_ENV_TEMPLATE.to_string();
@@ -524,13 +804,122 @@ pub fn generate_client_generic_token(
         "".to_string()
     };
 
+    let add_post_header_call = if !add_post_header.is_empty() {
+        format!("{},", to_snake_case(add_post_header))
+    } else {
+        "".to_string()
+    };
+
     let consent_pattern = if proper_name.starts_with("Google") {
         "{}?client_id={}&access_type=offline&response_type=code&redirect_uri={}&state={}"
     } else {
         "{}?client_id={}&response_type=code&redirect_uri={}&state={}"
     };
 
-    let token_auth_template = get_token_auth_template(consent_pattern);
+    let mut token_auth_template = get_token_auth_template(consent_pattern);
+    if proper_name == "Shopify" {
+        // Shopify's OAuth endpoints live on the domain of each shop.
+        token_auth_template = format!(
+            "{}\n\n{}",
+            token_auth_template
+                .replace(".post(TOKEN_ENDPOINT)", ".post(self.token_endpoint())")
+                .replace(
+                    "USER_CONSENT_ENDPOINT, self.client_id",
+                    "self.user_consent_endpoint(), self.client_id"
+                ),
+            SHOPIFY_TEMPLATE
+        );
+    } else if proper_name == "MailChimp" {
+        // Each MailChimp account lives in its own datacenter, so once we have a token
+        // we look up the host its requests must be sent to.
+        token_auth_template = format!(
+            "{}\n\n{}",
+            token_auth_template.replace(
+                "        refresh_token: t.refresh_token.clone(),\n        expires_at: Self::compute_expires_at(t.expires_in),\n    };\n\n    self.save_token().await?;\n\n    Ok(t)\n}",
+                r#"        refresh_token: t.refresh_token.clone(),
+        expires_at: Self::compute_expires_at(t.expires_in),
+    };
+
+    self.save_token().await?;
+
+    let metadata = self.metadata().await?;
+    if !metadata.api_endpoint.is_empty() {
+        self.host = format!("{}/3.0", metadata.api_endpoint.trim_end_matches('/'));
+    }
+
+    Ok(t)
+}"#
+            ),
+            MAILCHIMP_TEMPLATE
+        );
+    } else if proper_name == "DocuSign" {
+        // DocuSign's account servers differ between the demo and production environments,
+        // and each account has its own base URI we look up once we have a token.
+        token_auth_template = format!(
+            "{}\n\n{}",
+            token_auth_template
+                .replace(".post(TOKEN_ENDPOINT)", ".post(self.token_endpoint())")
+                .replace(
+                    "USER_CONSENT_ENDPOINT, self.client_id",
+                    "self.user_consent_endpoint(), self.client_id"
+                )
+                .replace(
+                    "async fn request_refreshed_access_token(&self) -> Result<AccessToken> {\n",
+                    r#"async fn request_refreshed_access_token(&self) -> Result<AccessToken> {
+    // The JWT Grant doesn't return refresh tokens, we sign a new assertion instead.
+    if self.jwt_grant.is_some() {
+        let t = self.request_jwt_access_token().await?;
+        let token = crate::token_store::StoredToken::from(&*self.token.read().await);
+        self.token_store.on_refresh(&token).await;
+        return Ok(t);
+    }
+
+"#
+                )
+                .replace(
+                    "        refresh_token: t.refresh_token.clone(),\n        expires_at: Self::compute_expires_at(t.expires_in),\n    };\n\n    self.save_token().await?;\n\n    Ok(t)\n}",
+                    r#"        refresh_token: t.refresh_token.clone(),
+        expires_at: Self::compute_expires_at(t.expires_in),
+    };
+
+    self.save_token().await?;
+
+    self.configure_from_user_info().await?;
+
+    Ok(t)
+}"#
+                ),
+            DOCUSIGN_TEMPLATE
+        );
+    }
+
+    // Clients that support more than the authorization code flow need a few more fields.
+    let (extra_struct, extra_fn, access_token_struct) = if proper_name == "MailChimp" {
+        // MailChimp can also authenticate with an API key instead of an access token.
+        (
+            "api_key: Option<String>,",
+            "api_key: None,",
+            format!(
+                "{}\n{}",
+                ACCESS_TOKEN_STRUCT_TEMPLATE, MAILCHIMP_METADATA_TEMPLATE
+            ),
+        )
+    } else if proper_name == "DocuSign" {
+        (
+            "jwt_grant: Option<JwtGrant>,\n    environment: Environment,\n    account_id: String,",
+            "jwt_grant: None,\n                    environment: Environment::Production,\n                    account_id: String::new(),",
+            format!("{}\n{}", ACCESS_TOKEN_STRUCT_TEMPLATE, DOCUSIGN_TYPES_TEMPLATE),
+        )
+    } else {
+        ("", "", ACCESS_TOKEN_STRUCT_TEMPLATE.to_string())
+    };
+
+    // The client tells the `401`s of invalid access tokens apart from the others.
+    let access_token_struct = format!(
+        "{}\n{}",
+        access_token_struct,
+        get_is_invalid_token(proper_name)
+    );
 
     format!(
         r#"use std::sync::Arc;
@@ -552,8 +941,14 @@ pub struct Client {{
     client_secret: String,
     redirect_uri: String,
     {}
+    {}
     auto_refresh: bool,
+    refresh_on_unauthorized: bool,
+    // The last refresh, which the others wait for while it is in flight.
+    refreshing: Arc<tokio::sync::Mutex<Option<AccessToken>>>,
+    token_store: Arc<dyn crate::token_store::TokenStore>,
     client: reqwest_middleware::ClientWithMiddleware,
+    rate_limiter: crate::rate_limiter::RateLimiter,
 }}
 
 {}
@@ -570,6 +965,74 @@ struct InnerToken {{
     expires_at: Option<Instant>,
 }}
 
+impl From<&InnerToken> for crate::token_store::StoredToken {{
+    fn from(t: &InnerToken) -> Self {{
+        crate::token_store::StoredToken {{
+            access_token: t.access_token.clone(),
+            refresh_token: t.refresh_token.clone(),
+            expires_at: t.expires_at.map(|i| {{
+                let left = i.saturating_duration_since(Instant::now());
+                chrono::Utc::now() + chrono::Duration::from_std(left).unwrap_or_else(|_| chrono::Duration::zero())
+            }}),
+        }}
+    }}
+}}
+
+impl From<crate::token_store::StoredToken> for InnerToken {{
+    fn from(t: crate::token_store::StoredToken) -> Self {{
+        InnerToken {{
+            access_token: t.access_token,
+            refresh_token: t.refresh_token,
+            expires_at: t
+                .expires_at
+                .map(|e| Instant::now() + (e - chrono::Utc::now()).to_std().unwrap_or_default()),
+        }}
+    }}
+}}
+
+impl ClientBuilder {{
+    /// Create the client, see `Client::new`.
+    pub fn build<I, K, R, T, Q{}>(
+        self,
+        client_id: I,
+        client_secret: K,
+        redirect_uri: R,
+        token: T,
+        refresh_token: Q,
+        {}
+    ) -> Result<Client>
+    where
+        I: ToString,
+        K: ToString,
+        R: ToString,
+        T: ToString,
+        Q: ToString,
+        {}
+    {{
+        let (client, rate_limiter) = self.http()?;
+
+        Ok(Client {{
+            host: DEFAULT_HOST.to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            token: Arc::new(RwLock::new(InnerToken {{
+                access_token: token.to_string(),
+                refresh_token: refresh_token.to_string(),
+                expires_at: None
+            }})),
+            {}
+            {}
+            auto_refresh: false,
+            refresh_on_unauthorized: false,
+            refreshing: Default::default(),
+            token_store: Arc::new(crate::token_store::MemoryTokenStore::new()),
+            client,
+            rate_limiter,
+        }})
+    }}
+}}
+
 impl Client {{
     /// Create a new Client struct. It takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -590,38 +1053,15 @@ impl Client {{
         Q: ToString,
         {}
     {{
-        // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest::Client::builder().build();
-        match client {{
-            Ok(c) => {{
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
-                    .build();
-
-                Client {{
-                    host: DEFAULT_HOST.to_string(),
-                    client_id: client_id.to_string(),
-                    client_secret: client_secret.to_string(),
-                    redirect_uri: redirect_uri.to_string(),
-                    token: Arc::new(RwLock::new(InnerToken {{
-                        access_token: token.to_string(),
-                        refresh_token: refresh_token.to_string(),
-                        expires_at: None
-                    }})),
-                    {}
-                    auto_refresh: false,
-                    client,
-                }}
-            }}
+        match Self::builder().build(
+            client_id,
+            client_secret,
+            redirect_uri,
+            token,
+            refresh_token,
+            {}
+        ) {{
+            Ok(c) => c,
             Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
         }}
     }}
@@ -632,6 +1072,14 @@ impl Client {{
         self
     }}
 
+    /// Enables or disables refreshing the access token when a request is rejected with
+    /// `401 Unauthorized` because of it, like when its expiration is unknown, and sending
+    /// the request again with the new one. Requests with a streamed body aren't.
+    pub fn set_refresh_on_unauthorized(&mut self, enabled: bool) -> &mut Self {{
+        self.refresh_on_unauthorized = enabled;
+        self
+    }}
+
     /// Sets a specific `Instant` at which the access token should be considered expired.
     /// The expiration value will only be used when automatic access token refreshing is
     /// also enabled. `None` may be passed in if the expiration is unknown. In this case
@@ -692,6 +1140,28 @@ impl Client {{
         c
     }}
 
+    /// Keep the tokens of the client in a store. The tokens saved there, if any, replace
+    /// those the client was created with, and new tokens are saved to it.
+    pub async fn with_token_store<S>(mut self, store: S) -> Result<Self>
+    where
+        S: crate::token_store::TokenStore + 'static,
+    {{
+        if let Some(t) = store.load().await? {{
+            self.token = Arc::new(RwLock::new(t.into()));
+        }}
+        self.token_store = Arc::new(store);
+        Ok(self)
+    }}
+
+    /*
+     * Save the tokens of the client to its store.
+     */
+    async fn save_token(&self) -> Result<crate::token_store::StoredToken> {{
+        let token = crate::token_store::StoredToken::from(&*self.token.read().await);
+        self.token_store.save(&token).await?;
+        Ok(token)
+    }}
+
     {}
 
 
@@ -699,17 +1169,23 @@ impl Client {{
     {}
 
     {}"#,
-        token_endpoint.trim_start_matches("https://"),
-        user_consent_endpoint.trim_start_matches("https://"),
+        config.token_endpoint.trim_start_matches("https://"),
+        config.user_consent_endpoint.trim_start_matches("https://"),
         add_post_header_struct,
-        ACCESS_TOKEN_STRUCT_TEMPLATE,
+        extra_struct,
+        access_token_struct,
         add_post_header_type,
         add_post_header_args,
         add_post_header_args_where,
         add_post_header_fn,
+        extra_fn,
+        add_post_header_type,
+        add_post_header_args,
+        add_post_header_args_where,
+        add_post_header_call,
         new_from_env,
         token_auth_template,
-        get_shared_functions(proper_name, add_post_header)
+        get_shared_functions(proper_name, config)
     )
 }
 
@@ -792,43 +1268,17 @@ where
     let secret = yup_oauth2::parse_application_secret(decoded_google_key)
         .expect("failed to read from google credential env var");
 
-    let client = reqwest::Client::builder().build();
-    let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-
-    match client {
-        Ok(c) => {
-            let client = reqwest_middleware::ClientBuilder::new(c)
-                // Trace HTTP requests. See the tracing crate to make use of these traces.
-                .with(reqwest_tracing::TracingMiddleware::default())
-                // Retry failed requests.
-                .with(
-                    reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some()
-                    )
-                )
-                .build();
-
-            Client {
-                host: DEFAULT_HOST.to_string(),
-                client_id: secret.client_id.to_string(),
-                client_secret: secret.client_secret.to_string(),
-                redirect_uri: secret.redirect_uris[0].to_string(),
-                token: Arc::new(RwLock::new(InnerToken {
-                    access_token: token.to_string(),
-                    refresh_token: refresh_token.to_string(),
-                    expires_at: None
-                })),
-                auto_refresh: false,
-                client,
-            }
-        },
-        Err(e) => panic!("creating reqwest client failed: {:?}", e),
-    }
+    Client::new(
+        secret.client_id,
+        secret.client_secret,
+        &secret.redirect_uris[0],
+        token,
+        refresh_token,
+    )
 }
 "#;
 
-pub fn generate_client_generic_api_key(proper_name: &str, add_post_header: &str) -> String {
+pub fn generate_client_generic_api_key(proper_name: &str, config: &Config) -> String {
     format!(
         r#"use std::env;
 
@@ -839,6 +1289,28 @@ pub struct Client {{
     token: String,
 
     client: reqwest_middleware::ClientWithMiddleware,
+    rate_limiter: crate::rate_limiter::RateLimiter,
+}}
+
+impl ClientBuilder {{
+    /// Create the client, see `Client::new`.
+    pub fn build<T>(
+        self,
+        token: T,
+    ) -> Result<Client>
+    where
+        T: ToString,
+    {{
+        let (client, rate_limiter) = self.http()?;
+
+        Ok(Client {{
+            host: DEFAULT_HOST.to_string(),
+            token: token.to_string(),
+
+            client,
+            rate_limiter,
+        }})
+    }}
 }}
 
 impl Client {{
@@ -851,29 +1323,8 @@ impl Client {{
     where
         T: ToString,
     {{
-        let client = reqwest::Client::builder().build();
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        match client {{
-            Ok(c) => {{
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
-                    .build();
-
-                Client {{
-                    host: DEFAULT_HOST.to_string(),
-                    token: token.to_string(),
-
-                    client,
-                }}
-            }}
+        match Self::builder().build(token) {{
+            Ok(c) => c,
             Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
         }}
     }}
@@ -906,50 +1357,362 @@ impl Client {{
     {}"#,
         proper_name.to_uppercase().replace('.', ""),
         proper_name.to_uppercase().replace('.', ""),
-        get_shared_functions(proper_name, add_post_header)
+        get_shared_functions(proper_name, config)
     )
 }
 
-fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
-    let post_header_args = if !add_post_header.is_empty() {
-        format!(
-            r#"if method == reqwest::Method::POST {{
-            req = req.header(
-        reqwest::header::HeaderName::from_bytes(b"{}")?,
-        reqwest::header::HeaderValue::from_str(&self.{})?,
-    );
-            }}"#,
-            to_snake_case(add_post_header),
-            to_snake_case(add_post_header)
-        )
-    } else {
-        String::new()
-    };
+pub fn generate_client_okta(config: &Config) -> String {
+    format!(
+        r#"use std::{{
+    convert::TryInto,
+    env,
+    ops::Add,
+    sync::Arc,
+    time::{{Duration, Instant}},
+}};
 
-    let bearer = if proper_name == "Okta" {
-        "SSWS".to_string()
-    } else {
-        "Bearer".to_string()
-    };
+use tokio::sync::RwLock;
 
-    // Add auto refresh functionality to clients that support it
-    let raw_request = if proper_name.starts_with("Google")
-        || proper_name == "DocuSign"
-        || proper_name == "Gusto"
-        || proper_name == "MailChimp"
-        || proper_name == "Ramp"
-        || proper_name == "Shopify"
-        || proper_name == "Slack"
-        || proper_name == "Zoom"
-    {
-        get_shared_raw_functions_with_refresh("Bearer", &post_header_args)
-    } else {
-        get_shared_raw_functions_without_refresh(&bearer, &post_header_args)
-    };
+/// The path of the token endpoint of the org authorization server.
+const TOKEN_PATH: &str = "/oauth2/v1/token";
 
-    format!(
-        r#"
+/// Entrypoint for interacting with the API client.
+#[derive(Clone)]
+pub struct Client {{
+    host: String,
+    token: String,
+    service_app: Option<ServiceApp>,
+    access_token: Arc<RwLock<InnerToken>>,
+
+    client: reqwest_middleware::ClientWithMiddleware,
+    rate_limiter: crate::rate_limiter::RateLimiter,
+}}
+
+/// An OAuth 2.0 service app. It signs a JWT with its private key to get access
+/// tokens for a set of scopes, so the client doesn't need an API token.
+#[derive(Clone)]
+pub struct ServiceApp {{
+    /// The client ID of the app.
+    pub client_id: String,
+    /// The PEM encoded RSA private key registered with the app.
+    pub private_key: String,
+    /// The ID of the key, if the app has more than one.
+    pub key_id: Option<String>,
+    /// The scopes to request, ie. `okta.users.read`.
+    pub scopes: Vec<String>,
+}}
+
+impl std::fmt::Debug for ServiceApp {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        f.debug_struct("ServiceApp")
+            .field("client_id", &self.client_id)
+            .field("key_id", &self.key_id)
+            .field("scopes", &self.scopes)
+            .finish()
+    }}
+}}
+
+{}
+
+/// Time in seconds before the access token expiration point that a refresh should
+/// be performed. This value is subtracted from the `expires_in` value returned by
+/// the provider prior to storing
+const REFRESH_THRESHOLD: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Default)]
+struct InnerToken {{
+    access_token: String,
+    expires_at: Option<Instant>,
+}}
+
+#[derive(Serialize)]
+struct ClientAssertionClaims {{
+    aud: String,
+    iss: String,
+    sub: String,
+    iat: i64,
+    exp: i64,
+    jti: String,
+}}
+
+impl ClientBuilder {{
+    /// Create the client, see `Client::new`.
+    pub fn build<O, T>(
+        self,
+        org_url: O,
+        token: T,
+    ) -> Result<Client>
+    where
+        O: ToString,
+        T: ToString,
+    {{
+        let (client, rate_limiter) = self.http()?;
+
+        Ok(Client {{
+            host: org_url.to_string().trim_end_matches('/').to_string(),
+            token: token.to_string(),
+            service_app: None,
+            access_token: Default::default(),
+
+            client,
+            rate_limiter,
+        }})
+    }}
+}}
+
+impl Client {{
+    /// Create a new Client struct for an Okta org, ie. `https://example.okta.com`,
+    /// that authenticates with an API token. Okta has no default host, each org has
+    /// its own.
+    pub fn new<O, T>(org_url: O, token: T) -> Self
+    where
+        O: ToString,
+        T: ToString,
+    {{
+        match Self::builder().build(org_url, token) {{
+            Ok(c) => c,
+            Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
+        }}
+    }}
+
+    /// Create a new Client struct for an Okta org, the same as `new`.
+    pub fn for_org<O, T>(org_url: O, token: T) -> Self
+    where
+        O: ToString,
+        T: ToString,
+    {{
+        Client::new(org_url, token)
+    }}
+
+    /// Create a new Client struct for an Okta org that authenticates as an OAuth 2.0
+    /// service app. Access tokens are requested, and refreshed before they expire,
+    /// automatically.
+    pub fn for_service_app<O>(org_url: O, service_app: ServiceApp) -> Self
+    where
+        O: ToString,
+    {{
+        let mut c = Client::for_org(org_url, "");
+        c.service_app = Some(service_app);
+        c
+    }}
+
+    /// Send the requests to another host than the org URL.
+    pub fn with_host<H>(&self, host: H) -> Self
+    where
+        H: ToString,
+    {{
+        let mut c = self.clone();
+        c.host = host.to_string();
+        c
+    }}
+
+    /// Create a new Client struct from environment variables. The org is read from
+    /// `OKTA_ORG_URL` and the API token from `OKTA_API_KEY`.
+    ///
+    /// # Panics
+    ///
+    /// If `OKTA_ORG_URL` or `OKTA_API_KEY` is not set.
+    pub fn new_from_env() -> Self {{
+        let org_url = env::var("OKTA_ORG_URL").expect("must set OKTA_ORG_URL");
+        let token = env::var("OKTA_API_KEY").expect("must set OKTA_API_KEY");
+
+        Client::for_org(org_url, token)
+    }}
+
+    /// Create a new Client struct for a service app from environment variables. The
+    /// org is read from `OKTA_ORG_URL`, the app from `OKTA_CLIENT_ID`, `OKTA_PRIVATE_KEY`
+    /// (the PEM encoded key) and `OKTA_KEY_ID` (optional), and the space separated
+    /// scopes from `OKTA_SCOPES`.
+    ///
+    /// # Panics
+    ///
+    /// If `OKTA_ORG_URL`, `OKTA_CLIENT_ID`, `OKTA_PRIVATE_KEY` or `OKTA_SCOPES` is not
+    /// set.
+    pub fn service_app_from_env() -> Self {{
+        let org_url = env::var("OKTA_ORG_URL").expect("must set OKTA_ORG_URL");
+        let client_id = env::var("OKTA_CLIENT_ID").expect("must set OKTA_CLIENT_ID");
+        let private_key = env::var("OKTA_PRIVATE_KEY").expect("must set OKTA_PRIVATE_KEY");
+        let scopes = env::var("OKTA_SCOPES").expect("must set OKTA_SCOPES");
+
+        Client::for_service_app(
+            org_url,
+            ServiceApp {{
+                client_id,
+                private_key,
+                key_id: env::var("OKTA_KEY_ID").ok(),
+                scopes: scopes.split_whitespace().map(|s| s.to_string()).collect(),
+            }},
+        )
+    }}
+
+    /// Get a new access token for the service app, even if the current one has not
+    /// expired yet.
+    pub async fn get_access_token(&self) -> Result<AccessToken> {{
+        let service_app = self
+            .service_app
+            .as_ref()
+            .ok_or_else(|| Error::Auth("the client is not configured as a service app".to_string()))?;
+
+        let token_endpoint = format!("{{}}{{}}", self.host, TOKEN_PATH);
+        let now = chrono::Utc::now().timestamp();
+        let claims = ClientAssertionClaims {{
+            aud: token_endpoint.clone(),
+            iss: service_app.client_id.clone(),
+            sub: service_app.client_id.clone(),
+            iat: now,
+            // Okta rejects assertions that are valid for more than an hour.
+            exp: now + 300,
+            jti: uuid::Uuid::new_v4().to_string(),
+        }};
+        let mut header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256);
+        header.kid = service_app.key_id.clone();
+        let key = jsonwebtoken::EncodingKey::from_rsa_pem(service_app.private_key.as_bytes())
+            .map_err(|e| Error::Auth(format!("invalid private key: {{}}", e)))?;
+        let assertion = jsonwebtoken::encode(&header, &claims, &key)
+            .map_err(|e| Error::Auth(format!("signing the client assertion failed: {{}}", e)))?;
+
+        let scope = service_app.scopes.join(" ");
+        let params = [
+            ("grant_type", "client_credentials"),
+            ("scope", &scope),
+            (
+                "client_assertion_type",
+                "urn:ietf:params:oauth:client-assertion-type:jwt-bearer",
+            ),
+            ("client_assertion", &assertion),
+        ];
+        let client = reqwest::Client::new();
+        let resp = client
+            .post(&token_endpoint)
+            .header(
+                reqwest::header::ACCEPT,
+                reqwest::header::HeaderValue::from_static("application/json"),
+            )
+            .form(&params)
+            .send()
+            .await?;
+
+        let status = resp.status();
+        if !status.is_success() {{
+            let body = resp.text().await?;
+            return Err(Error::Auth(format!("code: {{}}, error: {{:?}}", status, body)));
+        }}
+
+        // Unwrap the response.
+        let t: AccessToken = resp.json().await?;
+
+        *self.access_token.write().await = InnerToken {{
+            access_token: t.access_token.clone(),
+            expires_at: Self::compute_expires_at(t.expires_in),
+        }};
+
+        Ok(t)
+    }}
+
+    fn compute_expires_at(expires_in: i64) -> Option<Instant> {{
+        let seconds_valid = expires_in
+            .try_into()
+            .ok()
+            .map(Duration::from_secs)
+            .and_then(|dur| dur.checked_sub(REFRESH_THRESHOLD))
+            .or_else(|| Some(Duration::from_secs(0)));
+
+        seconds_valid.map(|seconds_valid| Instant::now().add(seconds_valid))
+    }}
+
+    /// Return the value of the `Authorization` header, getting a new access token
+    /// for the service app if the current one expired.
+    async fn authorization(&self) -> Result<String> {{
+        if self.service_app.is_none() {{
+            return Ok(format!("SSWS {{}}", self.token));
+        }}
+
+        {{
+            let token = self.access_token.read().await;
+            if matches!(token.expires_at, Some(expires_at) if expires_at > Instant::now()) {{
+                return Ok(format!("Bearer {{}}", token.access_token));
+            }}
+        }}
+
+        let t = self.get_access_token().await?;
+        Ok(format!("Bearer {{}}", t.access_token))
+    }}
+
+    {}"#,
+        ACCESS_TOKEN_STRUCT_TEMPLATE,
+        get_shared_functions("Okta", config)
+    )
+}
+
+fn get_shared_functions(proper_name: &str, config: &Config) -> String {
+    let add_post_header = config.post_header.as_str();
+    let mut post_header_args = if !add_post_header.is_empty() {
+        format!(
+            r#"if method == reqwest::Method::POST {{
+            req = req.header(
+        reqwest::header::HeaderName::from_bytes(b"{}")?,
+        reqwest::header::HeaderValue::from_str(&self.{})?,
+    );
+            }}"#,
+            to_snake_case(add_post_header),
+            to_snake_case(add_post_header)
+        )
+    } else {
+        String::new()
+    };
+    if let Some(idempotency_key) = &config.idempotency_key {
+        post_header_args.push_str(&format!(
+            r#"
+    if method == reqwest::Method::POST {{
+        if let Some(key) = &self.idempotency_key {{
+            req = req.header("{}", key.as_str());
+        }}
+    }}"#,
+            idempotency_key
+        ));
+    }
+
+    let bearer = config.bearer();
+
+    // Add auto refresh functionality to clients that support it
+    let raw_request = if config.auth == Auth::OAuth2 {
+        let raw_request = get_shared_raw_functions_with_refresh(bearer, &post_header_args);
+        if proper_name == "MailChimp" {
+            // An API key is sent as the password of HTTP basic auth, with any username.
+            raw_request.replace(
+                r#"let auth = format!("Bearer {}", self.token.read().await.access_token);"#,
+                r#"let auth = match &self.api_key {
+        Some(api_key) => format!("Basic {}", base64::encode(format!("anystring:{}", api_key))),
+        None => format!("Bearer {}", self.token.read().await.access_token),
+    };"#,
+            )
+        } else {
+            raw_request
+        }
+    } else if config.auth == Auth::Okta {
+        // Okta authenticates with either an API token or the access token of a service app.
+        get_shared_raw_functions_without_refresh(bearer, &post_header_args).replace(
+            &format!(r#"let auth = format!("{} {{}}", self.token);"#, bearer),
+            "let auth = self.authorization().await?;",
+        )
+    } else {
+        get_shared_raw_functions_without_refresh(bearer, &post_header_args)
+    };
+
+    let idempotency_key = if config.idempotency_key.is_some() {
+        IDEMPOTENCY_KEY_TEMPLATE
+    } else {
+        ""
+    };
+
+    format!(
+        r#"
+{}
 {}
+/// The status of the rate limits of the API, as of the last responses.
+pub fn rate_limit_status(&self) -> Vec<crate::rate_limiter::RateLimitStatus> {{
+    self.rate_limiter.status()
+}}
 
 async fn request<Out>(
     &self,
@@ -963,29 +1726,57 @@ async fn request<Out>(
     let response = self.request_raw(method, uri, body).await?;
 
     let status = response.status();
+    let headers = response.headers().clone();
 
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
         log::debug!("response payload {{}}", String::from_utf8_lossy(&response_body));
-        let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
-            serde_json::from_str("null")
+        if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
+            crate::error::decode(b"null")
         }} else {{
-            serde_json::from_slice::<Out>(&response_body)
-        }};
-        parsed_response.map_err(Error::from)
+            crate::error::decode::<Out>(&response_body)
+        }}
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        Err(crate::Error::from_response(status, headers, &response_body))
+    }}
+}}
+
+/* TODO: make this more DRY */
+#[allow(dead_code)]
+async fn request_form<Out>(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    form: String,
+) -> Result<Out>
+    where
+    Out: serde::de::DeserializeOwned + 'static + Send,
+{{
+    let response = self
+        .request_raw_with_media_types(
+            method,
+            uri,
+            Some(form.into()),
+            "application/json",
+            "application/x-www-form-urlencoded",
+        )
+        .await?;
+
+    let status = response.status();
+    let headers = response.headers().clone();
 
-        Err(error)
+    let response_body = response.bytes().await?;
+
+    if status.is_success() {{
+        log::debug!("response payload {{}}", String::from_utf8_lossy(&response_body));
+        if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
+            crate::error::decode(b"null")
+        }} else {{
+            crate::error::decode::<Out>(&response_body)
+        }}
+    }} else {{
+        Err(crate::Error::from_response(status, headers, &response_body))
     }}
 }}
 
@@ -1001,6 +1792,7 @@ where
     let response = self.request_raw(method, uri, body).await?;
 
     let status = response.status();
+    let headers = response.headers().clone();
     let link = response
         .headers()
         .get(http::header::LINK)
@@ -1013,22 +1805,13 @@ where
         log::debug!("response payload {{}}", String::from_utf8_lossy(&response_body));
 
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
-            serde_json::from_str("null")
+            crate::error::decode(b"null")
         }} else {{
-            serde_json::from_slice::<Out>(&response_body)
+            crate::error::decode::<Out>(&response_body)
         }};
-        parsed_response.map(|out| (link, out)).map_err(Error::from)
+        parsed_response.map(|out| (link, out))
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
-        Err(error)
+        Err(crate::Error::from_response(status, headers, &response_body))
     }}
 }}
 
@@ -1069,32 +1852,22 @@ async fn post_form<Out>(
     let response = req.send().await?;
 
     let status = response.status();
+    let headers = response.headers().clone();
 
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
         log::debug!("response payload {{}}", String::from_utf8_lossy(&response_body));
-        let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
-            serde_json::from_str("null")
+        if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
+            crate::error::decode(b"null")
         }} else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {{
             // Parse the output as a string.
-            serde_json::from_value(serde_json::json!(&String::from_utf8(response_body.to_vec())?))
+            serde_json::from_value(serde_json::json!(String::from_utf8_lossy(&response_body))).map_err(Error::from)
         }} else {{
-            serde_json::from_slice::<Out>(&response_body)
-        }};
-        parsed_response.map_err(Error::from)
+            crate::error::decode::<Out>(&response_body)
+        }}
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
-
-        Err(error)
+        Err(crate::Error::from_response(status, headers, &response_body))
     }}
 }}
 
@@ -1133,32 +1906,22 @@ async fn request_with_accept_mime<Out>(
     let response = req.send().await?;
 
     let status = response.status();
+    let headers = response.headers().clone();
 
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
         log::debug!("response payload {{}}", String::from_utf8_lossy(&response_body));
-        let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
-            serde_json::from_str("null")
+        if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
+            crate::error::decode(b"null")
         }} else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {{
             // Parse the output as a string.
-            serde_json::from_value(serde_json::json!(&String::from_utf8(response_body.to_vec())?))
+            serde_json::from_value(serde_json::json!(String::from_utf8_lossy(&response_body))).map_err(Error::from)
         }} else {{
-            serde_json::from_slice::<Out>(&response_body)
-        }};
-        parsed_response.map_err(Error::from)
+            crate::error::decode::<Out>(&response_body)
+        }}
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
-
-        Err(error)
+        Err(crate::Error::from_response(status, headers, &response_body))
     }}
 }}
 
@@ -1217,29 +1980,79 @@ async fn request_with_mime<Out>(
     let response = req.send().await?;
 
     let status = response.status();
+    let headers = response.headers().clone();
 
     let response_body = response.bytes().await?;
 
     if status.is_success() {{
         log::debug!("response payload {{}}", String::from_utf8_lossy(&response_body));
-        let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
-            serde_json::from_str("null")
+        if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
+            crate::error::decode(b"null")
         }} else {{
-            serde_json::from_slice::<Out>(&response_body)
-        }};
-        parsed_response.map_err(Error::from)
+            crate::error::decode::<Out>(&response_body)
+        }}
     }} else {{
-        let error = if response_body.is_empty() {{
-            anyhow!("code: {{}}, empty response", status)
-        }} else {{
-            anyhow!(
-                "code: {{}}, error: {{:?}}",
-                status,
-                String::from_utf8_lossy(&response_body),
-            )
-        }};
+        Err(crate::Error::from_response(status, headers, &response_body))
+    }}
+}}
+
+#[allow(dead_code)]
+async fn request_download(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+) -> Result<crate::Download> {{
+    let response = self.request_raw_with_media_types(method, uri, body, "*/*", "application/json").await?;
+
+    let status = response.status();
+    let headers = response.headers().clone();
+    let content_type = response
+        .headers()
+        .get(http::header::CONTENT_TYPE)
+        .and_then(|c| c.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+
+    let response_body = response.bytes().await?;
+
+    if status.is_success() {{
+        Ok(crate::Download {{
+            content_type,
+            body: response_body,
+        }})
+    }} else {{
+        Err(crate::Error::from_response(status, headers, &response_body))
+    }}
+}}
+
+#[allow(dead_code)]
+async fn request_download_stream(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+) -> Result<crate::DownloadStream> {{
+    let response = self.request_raw_with_media_types(method, uri, body, "*/*", "application/json").await?;
+
+    let status = response.status();
+    let headers = response.headers().clone();
 
-        Err(error)
+    if status.is_success() {{
+        let content_type = response
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|c| c.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+
+        Ok(crate::DownloadStream {{
+            content_type,
+            body: Box::pin(futures::TryStreamExt::map_err(response.bytes_stream(), Error::from)),
+        }})
+    }} else {{
+        let response_body = response.bytes().await?;
+        Err(crate::Error::from_response(status, headers, &response_body))
     }}
 }}
 
@@ -1376,10 +2189,42 @@ where
         message,
     ).await
 }}"#,
-        raw_request
+        raw_request, idempotency_key
     )
 }
 
+const IDEMPOTENCY_KEY_TEMPLATE: &str = r#"
+/// Send the `POST`s of the returned client with an idempotency key, so the API does
+/// each of them once, however many times it gets them. This makes them safe to retry.
+/// Use a new key, like a UUID, for each operation.
+pub fn with_idempotency_key<K>(&self, key: K) -> Self
+where
+    K: ToString,
+{
+    let mut c = self.clone();
+    c.idempotency_key = Some(key.to_string());
+    c
+}
+"#;
+
+/*
+ * The clients of APIs that take idempotency keys keep the one to send with their
+ * `POST`s, if any.
+ */
+pub fn add_idempotency_key(client: &str) -> String {
+    client
+        .replacen(
+            "    rate_limiter: crate::rate_limiter::RateLimiter,\n}",
+            "    rate_limiter: crate::rate_limiter::RateLimiter,\n    idempotency_key: Option<String>,\n}",
+            1,
+        )
+        .replacen(
+            "            rate_limiter,\n        })",
+            "            rate_limiter,\n            idempotency_key: None,\n        })",
+            1,
+        )
+}
+
 fn get_shared_raw_functions_without_refresh(bearer: &str, post_header_args: &str) -> String {
     format!(
         r#"
@@ -1398,6 +2243,18 @@ async fn request_raw(
     uri: &str,
     body: Option<reqwest::Body>,
 ) -> Result<reqwest::Response>
+{{
+    self.request_raw_with_media_types(method, uri, body, "application/json", "application/json").await
+}}
+
+async fn request_raw_with_media_types(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+    accept: &str,
+    content_type: &str,
+) -> Result<reqwest::Response>
 {{
     let u = if uri.starts_with("https://") {{
         uri.to_string()
@@ -1410,11 +2267,11 @@ async fn request_raw(
     // Set the default headers.
     req = req.header(
         reqwest::header::ACCEPT,
-        reqwest::header::HeaderValue::from_static("application/json"),
+        reqwest::header::HeaderValue::from_str(accept)?,
     );
     req = req.header(
         reqwest::header::CONTENT_TYPE,
-        reqwest::header::HeaderValue::from_static("application/json"),
+        reqwest::header::HeaderValue::from_str(content_type)?,
     );
     {}
     if let Some(auth_str) = auth {{
@@ -1449,6 +2306,8 @@ async fn make_request(
     method: &reqwest::Method,
     uri: &str,
     body: Option<reqwest::Body>,
+    accept: &str,
+    content_type: &str,
 ) -> Result<reqwest::Request> {{
     let u = if uri.starts_with("https://") {{
         uri.to_string()
@@ -1464,11 +2323,11 @@ async fn make_request(
     // Set the default headers.
     req = req.header(
         reqwest::header::ACCEPT,
-        reqwest::header::HeaderValue::from_static("application/json"),
+        reqwest::header::HeaderValue::from_str(accept)?,
     );
     req = req.header(
         reqwest::header::CONTENT_TYPE,
-        reqwest::header::HeaderValue::from_static("application/json"),
+        reqwest::header::HeaderValue::from_str(content_type)?,
     );
     {}
 
@@ -1493,6 +2352,17 @@ async fn request_raw(
     method: reqwest::Method,
     uri: &str,
     body: Option<reqwest::Body>,
+) -> Result<reqwest::Response> {{
+    self.request_raw_with_media_types(method, uri, body, "application/json", "application/json").await
+}}
+
+async fn request_raw_with_media_types(
+    &self,
+    method: reqwest::Method,
+    uri: &str,
+    body: Option<reqwest::Body>,
+    accept: &str,
+    content_type: &str,
 ) -> Result<reqwest::Response> {{
     if self.auto_refresh {{
         let expired = self.is_expired().await;
@@ -1501,7 +2371,8 @@ async fn request_raw(
             // We have a known expired token, we know we need to perform a refresh prior to
             // attempting to make a request
             Some(true) => {{
-                self.refresh_access_token().await?;
+                let stale = self.token.read().await.access_token.clone();
+                self.refresh_access_token_once(&stale).await?;
             }}
 
             // We have a (theoretically) known good token available. We make an optimistic
@@ -1521,15 +2392,104 @@ async fn request_raw(
         }}
     }}
 
-    let req = self.make_request(&method, uri, body).await?;
+    let stale = self.token.read().await.access_token.clone();
+    let req = self.make_request(&method, uri, body, accept, content_type).await?;
+    let replay = if self.refresh_on_unauthorized {{
+        req.try_clone()
+    }} else {{
+        None
+    }};
     let resp = self.client.execute(req).await?;
 
-    Ok(resp)
+    let mut replay = match replay {{
+        Some(replay) if resp.status() == http::StatusCode::UNAUTHORIZED => replay,
+        _ => return Ok(resp),
+    }};
+
+    if !is_invalid_token(resp.headers()) {{
+        // The API may only say so in the body, which we have to read to find out.
+        let code = match INVALID_TOKEN_CODE {{
+            Some(code) => code,
+            None => return Ok(resp),
+        }};
+
+        use reqwest::ResponseBuilderExt;
+        let mut builder = http::Response::builder()
+            .status(resp.status())
+            .version(resp.version())
+            .url(resp.url().clone());
+        if let Some(headers) = builder.headers_mut() {{
+            *headers = resp.headers().clone();
+        }}
+        let body = resp.bytes().await?;
+        if !has_error_code(&body, code) {{
+            // Hand on the response as it was.
+            return builder
+                .body(body)
+                .map(reqwest::Response::from)
+                .map_err(|e| Error::InvalidRequest(e.to_string()));
+        }}
+    }}
+
+    self.refresh_access_token_once(&stale).await?;
+    let (_, auth) = self.url_and_auth(replay.url().as_str()).await?;
+    if let Some(auth_str) = auth {{
+        replay.headers_mut().insert(
+            http::header::AUTHORIZATION,
+            reqwest::header::HeaderValue::from_str(&auth_str)?,
+        );
+    }}
+    Ok(self.client.execute(replay).await?)
 }}"#,
         bearer, post_header_args
     )
 }
 
+/*
+ * Whether a `401 Unauthorized` is because of the access token, as the
+ * `WWW-Authenticate` header of OAuth says, or else the error code of the API in the
+ * body, for the APIs that document one.
+ */
+fn get_is_invalid_token(proper_name: &str) -> String {
+    let code = match proper_name {
+        // {"code": 124, "message": "Invalid access token."}
+        "Zoom" => r#"Some(("/code", "124"))"#,
+        // {"errorCode": "AUTHORIZATION_INVALID_TOKEN", "message": "..."}
+        "DocuSign" => r#"Some(("/errorCode", "AUTHORIZATION_INVALID_TOKEN"))"#,
+        _ => "None",
+    };
+
+    format!(
+        r#"
+/// The error code of the API for an invalid access token, as the JSON pointer of where
+/// it is in the body of a `401 Unauthorized` and its value.
+const INVALID_TOKEN_CODE: Option<(&str, &str)> = {};
+
+/// Whether the `WWW-Authenticate` header of a `401 Unauthorized` says the access token
+/// is invalid, or expired.
+fn is_invalid_token(headers: &reqwest::header::HeaderMap) -> bool {{
+    headers
+        .get_all(http::header::WWW_AUTHENTICATE)
+        .iter()
+        .filter_map(|h| h.to_str().ok())
+        .any(|h| h.contains("invalid_token"))
+}}
+
+fn has_error_code(body: &[u8], (pointer, code): (&str, &str)) -> bool {{
+    match serde_json::from_slice::<serde_json::Value>(body)
+        .ok()
+        .as_ref()
+        .and_then(|v| v.pointer(pointer))
+    {{
+        Some(serde_json::Value::String(s)) => s == code,
+        Some(serde_json::Value::Number(n)) => n.to_string() == code,
+        _ => false,
+    }}
+}}"#,
+        code
+    )
+}
+
 fn get_token_auth_template<S: AsRef<str>>(consent_pattern: S) -> String {
     format!(
         r#"
@@ -1552,13 +2512,37 @@ pub fn user_consent_url(&self, scopes: &[String]) -> String {{
 }}
 
 /// Refresh an access token from a refresh token. Client must have a refresh token
-/// for this to work.
+/// for this to work. The clones of a client refresh once when they do at the same
+/// time, those that wait for another refresh get its token.
 pub async fn refresh_access_token(&self) -> Result<AccessToken> {{
+    let stale = self.token.read().await.access_token.clone();
+    self.refresh_access_token_once(&stale).await
+}}
+
+/*
+ * Refresh the `stale` access token, unless another refresh replaced it while this one
+ * waited for it.
+ */
+async fn refresh_access_token_once(&self, stale: &str) -> Result<AccessToken> {{
+    let mut last = self.refreshing.lock().await;
+    if let Some(t) = last.as_ref() {{
+        let current = &self.token.read().await.access_token;
+        if current != stale && *current == t.access_token {{
+            return Ok(t.clone());
+        }}
+    }}
+
+    let t = self.request_refreshed_access_token().await?;
+    *last = Some(t.clone());
+    Ok(t)
+}}
+
+async fn request_refreshed_access_token(&self) -> Result<AccessToken> {{
     let response = {{
         let refresh_token = &self.token.read().await.refresh_token;
 
         if refresh_token.is_empty() {{
-            anyhow!("refresh token cannot be empty");
+            return Err(Error::Auth("refresh token cannot be empty".to_string()));
         }}
 
         let mut headers = reqwest::header::HeaderMap::new();
@@ -1584,10 +2568,21 @@ pub async fn refresh_access_token(&self) -> Result<AccessToken> {{
             .await?
     }};
 
+    let status = response.status();
+    if !status.is_success() {{
+        let body = response.text().await?;
+        return Err(Error::Auth(format!("code: {{}}, error: {{:?}}", status, body)));
+    }}
+
     // Unwrap the response.
     let t: AccessToken = response.json().await?;
 
-    let refresh_token = self.token.read().await.refresh_token.clone();
+    // Providers that rotate refresh tokens send a new one, the old one no longer works.
+    let refresh_token = if t.refresh_token.is_empty() {{
+        self.token.read().await.refresh_token.clone()
+    }} else {{
+        t.refresh_token.clone()
+    }};
 
     *self.token.write().await = InnerToken {{
         access_token: t.access_token.clone(),
@@ -1595,6 +2590,9 @@ pub async fn refresh_access_token(&self) -> Result<AccessToken> {{
         expires_at: Self::compute_expires_at(t.expires_in),
     }};
 
+    let token = self.save_token().await?;
+    self.token_store.on_refresh(&token).await;
+
     Ok(t)
 }}
 
@@ -1624,6 +2622,12 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> Result<Acce
         .send()
         .await?;
 
+    let status = resp.status();
+    if !status.is_success() {{
+        let body = resp.text().await?;
+        return Err(Error::Auth(format!("code: {{}}, error: {{:?}}", status, body)));
+    }}
+
     // Unwrap the response.
     let t: AccessToken = resp.json().await?;
 
@@ -1633,6 +2637,8 @@ pub async fn get_access_token(&mut self, code: &str, state: &str) -> Result<Acce
         expires_at: Self::compute_expires_at(t.expires_in),
     }};
 
+    self.save_token().await?;
+
     Ok(t)
 }}"#,
         consent_pattern.as_ref()
@@ -1663,6 +2669,12 @@ pub async fn get_access_token(&mut self) -> Result<AccessToken> {
         .send()
         .await?;
 
+    let status = resp.status();
+    if !status.is_success() {
+        let body = resp.text().await?;
+        return Err(Error::Auth(format!("code: {}, error: {:?}", status, body)));
+    }
+
     // Unwrap the response.
     let t: AccessToken = resp.json().await?;
 
@@ -1671,11 +2683,509 @@ pub async fn get_access_token(&mut self) -> Result<AccessToken> {
     Ok(t)
 }"#;
 
-pub fn generate_client_generic_client_credentials(
-    proper_name: &str,
-    token_endpoint: &str,
-    add_post_header: &str,
-) -> String {
+const SHOPIFY_TEMPLATE: &str = r#"
+/// Create a new Client struct for a shop. The shop can be given as its
+/// `myshopify.com` domain (`apple.myshopify.com`) or just its name (`apple`).
+/// The `api_version` is the version of the Admin API to use, ie. `2021-07`.
+pub fn for_shop<S, V, I, K, R, T, Q>(
+    shop_domain: S,
+    api_version: V,
+    client_id: I,
+    client_secret: K,
+    redirect_uri: R,
+    token: T,
+    refresh_token: Q,
+) -> Result<Self>
+where
+    S: ToString,
+    V: ToString,
+    I: ToString,
+    K: ToString,
+    R: ToString,
+    T: ToString,
+    Q: ToString,
+{
+    let shop_domain = Self::normalize_shop_domain(&shop_domain.to_string())?;
+
+    let mut c = Client::new(client_id, client_secret, redirect_uri, token, refresh_token);
+    c.host = format!("https://{}/admin/api/{}", shop_domain, api_version.to_string());
+    Ok(c)
+}
+
+/// Return the `myshopify.com` domain of the shop the client talks to.
+pub fn shop_domain(&self) -> &str {
+    self.host
+        .trim_start_matches("https://")
+        .split('/')
+        .next()
+        .unwrap_or_default()
+}
+
+fn normalize_shop_domain(shop_domain: &str) -> Result<String> {
+    let shop_domain = shop_domain
+        .trim()
+        .trim_start_matches("https://")
+        .trim_end_matches('/')
+        .to_lowercase();
+    let shop_domain = if shop_domain.ends_with(".myshopify.com") {
+        shop_domain
+    } else {
+        format!("{}.myshopify.com", shop_domain)
+    };
+
+    if !Self::is_valid_shop_domain(&shop_domain) {
+        return Err(Error::InvalidRequest(format!("invalid shop domain: {:?}", shop_domain)));
+    }
+
+    Ok(shop_domain)
+}
+
+fn is_valid_shop_domain(shop_domain: &str) -> bool {
+    match shop_domain.strip_suffix(".myshopify.com") {
+        Some(name) => {
+            name.starts_with(|c: char| c.is_ascii_alphanumeric())
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        }
+        None => false,
+    }
+}
+
+fn token_endpoint(&self) -> String {
+    TOKEN_ENDPOINT.replace("{shop}.myshopify.com", self.shop_domain())
+}
+
+fn user_consent_endpoint(&self) -> String {
+    USER_CONSENT_ENDPOINT.replace("{shop}.myshopify.com", self.shop_domain())
+}
+
+/// Verify the query string Shopify sends to the redirect URL at the end of the
+/// OAuth flow, before exchanging the `code` for an access token. The `hmac` must
+/// be signed with the client secret and the `shop` must be the shop of the client.
+pub fn verify_oauth_callback(&self, query: &str) -> Result<()> {
+    use hmac::Mac;
+
+    let params: Vec<(String, String)> = serde_urlencoded::from_str(query.trim_start_matches('?'))
+        .map_err(|e| Error::Auth(format!("invalid callback query: {}", e)))?;
+
+    let mut digest = None;
+    let mut message = Vec::new();
+    for (k, v) in params {
+        if k == "hmac" {
+            digest = Some(v);
+        } else if k != "signature" {
+            message.push((k, v));
+        }
+    }
+    let digest = digest.ok_or_else(|| Error::Auth("callback is missing the hmac parameter".to_string()))?;
+
+    match message.iter().find(|(k, _)| k == "shop") {
+        Some((_, shop)) if Self::is_valid_shop_domain(shop) && shop == self.shop_domain() => {}
+        Some((_, shop)) => return Err(Error::Auth(format!("unexpected shop in callback: {:?}", shop))),
+        None => return Err(Error::Auth("callback is missing the shop parameter".to_string())),
+    }
+
+    // The message is the rest of the query, sorted by key.
+    message.sort();
+    let message = message
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    let signature = (0..digest.len())
+        .step_by(2)
+        .map(|i| digest.get(i..i + 2).and_then(|b| u8::from_str_radix(b, 16).ok()))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or_else(|| Error::Auth("callback hmac is not valid hex".to_string()))?;
+
+    let mut mac = hmac::Hmac::<sha2::Sha256>::new_from_slice(self.client_secret.as_bytes())
+        .map_err(|e| Error::Auth(e.to_string()))?;
+    mac.update(message.as_bytes());
+    mac.verify_slice(&signature)
+        .map_err(|_| Error::Auth("callback hmac does not match".to_string()))
+}"#;
+
+const MAILCHIMP_METADATA_TEMPLATE: &str = r#"
+const METADATA_ENDPOINT: &str = "https://login.mailchimp.com/oauth2/metadata";
+
+/// The account an OAuth access token belongs to, as returned by the metadata endpoint.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct Metadata {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub dc: String,
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub role: String,
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub accountname: String,
+
+    #[serde(default)]
+    pub user_id: i64,
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login_url: String,
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub api_endpoint: String,
+}"#;
+
+const MAILCHIMP_TEMPLATE: &str = r#"
+/// Create a new Client struct that authenticates with an API key, ie.
+/// `0123456789abcdef0123456789abcdef-us19`. The requests are sent to the
+/// datacenter in the suffix of the key.
+pub fn new_with_api_key<K>(api_key: K) -> Result<Self>
+where
+    K: ToString,
+{
+    let api_key = api_key.to_string();
+    let dc = Self::datacenter_from_api_key(&api_key)?;
+
+    let mut c = Client::new("", "", "", "", "");
+    c.host = format!("https://{}.api.mailchimp.com/3.0", dc);
+    c.api_key = Some(api_key);
+    Ok(c)
+}
+
+/// Create a new Client struct that authenticates with the API key in the
+/// `MAILCHIMP_API_KEY` environment variable.
+pub fn new_with_api_key_from_env() -> Result<Self> {
+    let api_key = env::var("MAILCHIMP_API_KEY")
+        .map_err(|_| Error::InvalidRequest("must set MAILCHIMP_API_KEY".to_string()))?;
+
+    Self::new_with_api_key(api_key)
+}
+
+fn datacenter_from_api_key(api_key: &str) -> Result<&str> {
+    match api_key.trim().rsplit_once('-') {
+        Some((_, dc)) if !dc.is_empty() && dc.chars().all(|c| c.is_ascii_alphanumeric()) => Ok(dc),
+        _ => Err(Error::InvalidRequest(
+            "api key does not end with a datacenter, ie. `-us19`".to_string(),
+        )),
+    }
+}
+
+/// Get the metadata of the account the OAuth access token belongs to. The
+/// `api_endpoint` is the datacenter requests for the account must be sent to.
+pub async fn metadata(&self) -> Result<Metadata> {
+    let auth = format!("OAuth {}", self.token.read().await.access_token);
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(METADATA_ENDPOINT)
+        .header(reqwest::header::ACCEPT, "application/json")
+        .header(reqwest::header::AUTHORIZATION, auth)
+        .send()
+        .await?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        let body = resp.text().await?;
+        return Err(Error::Auth(format!("code: {}, error: {:?}", status, body)));
+    }
+
+    Ok(resp.json().await?)
+}"#;
+
+const DOCUSIGN_TYPES_TEMPLATE: &str = r#"
+/// The DocuSign environment whose account server issues the access tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Environment {
+    /// `account.docusign.com`
+    Production,
+    /// `account-d.docusign.com`, for developer accounts.
+    Demo,
+}
+
+impl Environment {
+    /// Return the host of the account server of the environment.
+    pub fn account_server(&self) -> &'static str {
+        match self {
+            Environment::Production => "account.docusign.com",
+            Environment::Demo => "account-d.docusign.com",
+        }
+    }
+}
+
+/// The settings to authenticate with the JWT Grant, where the integration signs
+/// an assertion with its private key to act on behalf of a user that consented
+/// to the `impersonation` scope.
+#[derive(Clone)]
+pub struct JwtGrant {
+    /// The ID of the user to impersonate.
+    pub user_id: String,
+    /// The PEM encoded RSA private key of the integration.
+    pub private_key: String,
+    /// The scopes to request, ie. `signature` and `impersonation`.
+    pub scopes: Vec<String>,
+}
+
+impl std::fmt::Debug for JwtGrant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtGrant")
+            .field("user_id", &self.user_id)
+            .field("scopes", &self.scopes)
+            .finish()
+    }
+}
+
+#[derive(Serialize)]
+struct JwtGrantClaims {
+    iss: String,
+    sub: String,
+    aud: String,
+    iat: i64,
+    exp: i64,
+    scope: String,
+}
+
+/// The user an access token belongs to and the accounts they can access, as
+/// returned by `/oauth/userinfo`.
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct UserInfo {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sub: String,
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accounts: Vec<UserInfoAccount>,
+}
+
+#[derive(Debug, JsonSchema, Clone, Default, Serialize, Deserialize)]
+pub struct UserInfoAccount {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub account_id: String,
+
+    #[serde(default)]
+    pub is_default: bool,
+
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub account_name: String,
+
+    /// The base URI of the account, ie. `https://demo.docusign.net`.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub base_uri: String,
+}"#;
+
+const DOCUSIGN_TEMPLATE: &str = r#"
+/// Create a new Client struct that authenticates with the JWT Grant. Call
+/// `get_jwt_access_token` to get a token and configure the account of the user.
+pub fn for_jwt_grant<I>(client_id: I, jwt_grant: JwtGrant, environment: Environment) -> Self
+where
+    I: ToString,
+{
+    let mut c = Client::new(client_id, "", "", "", "");
+    c.jwt_grant = Some(jwt_grant);
+    c.environment = environment;
+    c
+}
+
+/// Create a new Client struct that authenticates with the JWT Grant from
+/// environment variables. The integration is read from `DOCUSIGN_CLIENT_ID`,
+/// the user from `DOCUSIGN_USER_ID`, the PEM encoded key from
+/// `DOCUSIGN_PRIVATE_KEY`, the space separated scopes from `DOCUSIGN_SCOPES`
+/// (default `signature impersonation`) and the environment from
+/// `DOCUSIGN_ENVIRONMENT`, either `production` (default) or `demo`.
+pub fn jwt_grant_from_env() -> Self {
+    let client_id = env::var("DOCUSIGN_CLIENT_ID").expect("must set DOCUSIGN_CLIENT_ID");
+    let user_id = env::var("DOCUSIGN_USER_ID").expect("must set DOCUSIGN_USER_ID");
+    let private_key = env::var("DOCUSIGN_PRIVATE_KEY").expect("must set DOCUSIGN_PRIVATE_KEY");
+    let scopes = env::var("DOCUSIGN_SCOPES").unwrap_or_else(|_| "signature impersonation".to_string());
+    let environment = match env::var("DOCUSIGN_ENVIRONMENT").unwrap_or_default().as_str() {
+        "demo" => Environment::Demo,
+        _ => Environment::Production,
+    };
+
+    Client::for_jwt_grant(
+        client_id,
+        JwtGrant {
+            user_id,
+            private_key,
+            scopes: scopes.split_whitespace().map(|s| s.to_string()).collect(),
+        },
+        environment,
+    )
+}
+
+/// Use the account server of a different environment.
+pub fn with_environment(&self, environment: Environment) -> Self {
+    let mut c = self.clone();
+    c.environment = environment;
+    c
+}
+
+/// Return the ID of the account the client was configured for by `get_access_token`,
+/// `get_jwt_access_token` or `configure_from_user_info`.
+pub fn account_id(&self) -> &str {
+    &self.account_id
+}
+
+fn token_endpoint(&self) -> String {
+    TOKEN_ENDPOINT.replace("account.docusign.com", self.environment.account_server())
+}
+
+fn user_consent_endpoint(&self) -> String {
+    USER_CONSENT_ENDPOINT.replace("account.docusign.com", self.environment.account_server())
+}
+
+/// Get an access token with the JWT Grant and configure the client for the
+/// default account of the impersonated user.
+pub async fn get_jwt_access_token(&mut self) -> Result<AccessToken> {
+    let t = self.request_jwt_access_token().await?;
+    self.configure_from_user_info().await?;
+
+    Ok(t)
+}
+
+async fn request_jwt_access_token(&self) -> Result<AccessToken> {
+    let jwt_grant = self
+        .jwt_grant
+        .as_ref()
+        .ok_or_else(|| Error::Auth("the client is not configured for the JWT Grant".to_string()))?;
+
+    let now = chrono::Utc::now().timestamp();
+    let claims = JwtGrantClaims {
+        iss: self.client_id.clone(),
+        sub: jwt_grant.user_id.clone(),
+        aud: self.environment.account_server().to_string(),
+        iat: now,
+        // DocuSign rejects assertions that are valid for more than an hour.
+        exp: now + 3600,
+        scope: jwt_grant.scopes.join(" "),
+    };
+    let key = jsonwebtoken::EncodingKey::from_rsa_pem(jwt_grant.private_key.as_bytes())
+        .map_err(|e| Error::Auth(format!("invalid private key: {}", e)))?;
+    let assertion = jsonwebtoken::encode(
+        &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256),
+        &claims,
+        &key,
+    )
+    .map_err(|e| Error::Auth(format!("signing the assertion failed: {}", e)))?;
+
+    let params = [
+        ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+        ("assertion", &assertion),
+    ];
+    let client = reqwest::Client::new();
+    let resp = client
+        .post(self.token_endpoint())
+        .header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        )
+        .form(&params)
+        .send()
+        .await?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        let body = resp.text().await?;
+        return Err(Error::Auth(format!("code: {}, error: {:?}", status, body)));
+    }
+
+    // Unwrap the response.
+    let t: AccessToken = resp.json().await?;
+
+    *self.token.write().await = InnerToken {
+        access_token: t.access_token.clone(),
+        refresh_token: String::new(),
+        expires_at: Self::compute_expires_at(t.expires_in),
+    };
+
+    self.save_token().await?;
+
+    Ok(t)
+}
+
+/// Get the user the access token belongs to and the accounts they can access.
+pub async fn user_info(&self) -> Result<UserInfo> {
+    let auth = format!("Bearer {}", self.token.read().await.access_token);
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(format!("https://{}/oauth/userinfo", self.environment.account_server()))
+        .header(reqwest::header::ACCEPT, "application/json")
+        .header(reqwest::header::AUTHORIZATION, auth)
+        .send()
+        .await?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        let body = resp.text().await?;
+        return Err(Error::Auth(format!("code: {}, error: {:?}", status, body)));
+    }
+
+    Ok(resp.json().await?)
+}
+
+/// Configure the client for the default account of the user the access token
+/// belongs to: requests are sent to the base URI of the account and
+/// `account_id` returns its ID.
+pub async fn configure_from_user_info(&mut self) -> Result<UserInfo> {
+    let user_info = self.user_info().await?;
+
+    let account = user_info
+        .accounts
+        .iter()
+        .find(|a| a.is_default)
+        .or_else(|| user_info.accounts.first())
+        .ok_or_else(|| Error::Auth(format!("user {} has no accounts", user_info.sub)))?;
+    self.host = format!("{}/restapi", account.base_uri.trim_end_matches('/'));
+    self.account_id = account.account_id.clone();
+
+    Ok(user_info)
+}"#;
+
+pub fn generate_client_generic_client_credentials(proper_name: &str, config: &Config) -> String {
     format!(
         r#"use std::env;
 
@@ -1690,10 +3200,38 @@ pub struct Client {{
     client_id: String,
     client_secret: String,
     client: reqwest_middleware::ClientWithMiddleware,
+    rate_limiter: crate::rate_limiter::RateLimiter,
 }}
 
 {}
 
+impl ClientBuilder {{
+    /// Create the client, see `Client::new`.
+    pub fn build<I, K, T>(
+        self,
+        client_id: I,
+        client_secret: K,
+        token: T,
+    ) -> Result<Client>
+    where
+        I: ToString,
+        K: ToString,
+        T: ToString,
+    {{
+        let (client, rate_limiter) = self.http()?;
+
+        Ok(Client {{
+            host: DEFAULT_HOST.to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            token: token.to_string(),
+
+            client,
+            rate_limiter,
+        }})
+    }}
+}}
+
 impl Client {{
     /// Create a new Client struct. It takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -1708,31 +3246,8 @@ impl Client {{
         K: ToString,
         T: ToString,
     {{
-        let client = reqwest::Client::builder().build();
-        let retry_policy = reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        match client {{
-            Ok(c) => {{
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(
-                        reqwest_conditional_middleware::ConditionalMiddleware::new(
-                            reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                            |req: &reqwest::Request| req.try_clone().is_some()
-                        )
-                    )
-                    .build();
-
-                Client {{
-                    host: DEFAULT_HOST.to_string(),
-                    client_id: client_id.to_string(),
-                    client_secret: client_secret.to_string(),
-                    token: token.to_string(),
-
-                    client,
-                }}
-            }}
+        match Self::builder().build(client_id, client_secret, token) {{
+            Ok(c) => c,
             Err(e) => panic!("creating reqwest client failed: {{:?}}", e),
         }}
     }}
@@ -1770,14 +3285,14 @@ impl Client {{
     {}
 
     {}"#,
-        token_endpoint.trim_start_matches("https://"),
+        config.token_endpoint.trim_start_matches("https://"),
         ACCESS_TOKEN_STRUCT_TEMPLATE,
         proper_name.to_uppercase().replace('.', ""),
         proper_name.to_uppercase().replace('.', ""),
         proper_name.to_uppercase().replace('.', ""),
         proper_name.to_uppercase().replace('.', ""),
         CLIENT_AUTH_TEMPLATE,
-        get_shared_functions(proper_name, add_post_header)
+        get_shared_functions(proper_name, config)
     )
 }
 
@@ -0,0 +1,251 @@
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::{Context, Result};
+use inflector::cases::snakecase::to_snake_case;
+use serde::Deserialize;
+
+/*
+ * The settings that differ between the APIs we generate clients for, read from the
+ * `generator.toml` passed with `--config`. Everything is optional, an empty file
+ * gives an OAuth 2.0 client that pages through `Link` headers.
+ */
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// How the client authenticates.
+    pub auth: Auth,
+    /// The scheme of the `Authorization` header, `Bearer` if unset.
+    pub bearer: Option<String>,
+    /// The host and path to get and refresh access tokens at.
+    pub token_endpoint: String,
+    /// The host and path of the page users grant the client access on.
+    pub user_consent_endpoint: String,
+    /// A header the client sends with every `POST`, given when creating the client.
+    pub post_header: String,
+    /// The type of the `error` of `Error::Http`, `serde_json::Value` if unset.
+    pub error_body: Option<String>,
+    /// The property that tells apart the types of a `oneOf` without a `discriminator`
+    /// in the spec. It's only used if each of the types has it with a single value.
+    pub discriminator: Option<String>,
+    /// Skip the tags of the spec that have no operations.
+    pub skip_empty_tags: bool,
+    /// Parameters, in snake case, that the functions don't take.
+    pub hidden_params: Vec<String>,
+    /// Names to give the types of schemas, by schema name.
+    pub type_renames: BTreeMap<String, String>,
+    pub pagination: Pagination,
+    pub decimal: Decimal,
+    /// The headers the API tells its rate limits in.
+    pub rate_limit: RateLimit,
+    /// The header `POST`s can carry an idempotency key in, if the API takes one. Those
+    /// that do are retried like the idempotent methods.
+    pub idempotency_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Auth {
+    /// An OAuth 2.0 access token, refreshed when it expires.
+    #[default]
+    #[serde(rename = "oauth2")]
+    OAuth2,
+    /// An access token from the OAuth 2.0 client credentials grant.
+    ClientCredentials,
+    /// An API key sent as a bearer token.
+    ApiKey,
+    /// GitHub apps, installations and tokens.
+    #[serde(rename = "github")]
+    GitHub,
+    /// Okta API tokens or service app access tokens.
+    Okta,
+}
+
+/*
+ * Every API sends `Retry-After` when it is rate limited, some also tell how much of
+ * the limit is left in their own headers.
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RateLimit {
+    /// Only `Retry-After`.
+    #[default]
+    RetryAfter,
+    /// `X-RateLimit-*` for each resource, like `core` or `search`.
+    #[serde(rename = "github")]
+    GitHub,
+    /// `X-Rate-Limit-*` for each endpoint.
+    Okta,
+    /// `X-RateLimit-*` for each category of operations, like `Light` or `Heavy`.
+    Zoom,
+    /// `Retry-After` for each method, since each has its own tier.
+    Slack,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Pagination {
+    /// How the pages of lists are requested.
+    pub style: PaginationStyle,
+    /// The query parameter to send the cursor of a page in.
+    pub cursor_param: String,
+    /// The response field with the cursor of the next page, `a.b` for nested fields.
+    pub next_cursor_field: String,
+    /// The response field with the number of the current page, for `page-number`.
+    pub current_page_field: String,
+    /// The response field with the number of pages, for `page-number`.
+    pub total_pages_field: String,
+    /// The query parameter to send the number of items per page in, for `page-number`.
+    pub page_size_param: String,
+    /// The number of items to ask for per page, for `page-number`.
+    pub page_size: u32,
+    /// Parameters that only serve pagination, on top of the usual `page`, `limit`, etc.
+    /// The functions that fetch all pages don't take them.
+    pub page_params: Vec<String>,
+}
+
+/*
+ * The strings and numbers that are exact decimals, like amounts of money. With the
+ * `decimal` feature of the crate they are `rust_decimal::Decimal`s, otherwise
+ * `String`s and `f64`s. Those with the `decimal` format always are.
+ */
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Decimal {
+    /// Other formats of exact decimals, like the `float` strings of Gusto.
+    pub formats: Vec<String>,
+    /// Properties, in snake case, that are exact decimals whatever their format.
+    pub properties: Vec<String>,
+}
+
+impl Decimal {
+    pub fn is_decimal(&self, format: &str, property: &str) -> bool {
+        let property = to_snake_case(property);
+        format == "decimal"
+            || self.formats.iter().any(|f| f == format)
+            || self.properties.contains(&property)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PaginationStyle {
+    /// Lists are arrays, the next page is in the `Link` header.
+    #[default]
+    LinkHeader,
+    /// The response has the token of the next page, sent back in `cursor_param`.
+    PageToken,
+    /// The response has the URL of the next page.
+    NextUrl,
+    /// Pages are numbered from 0, the response has the current page and the number of
+    /// pages.
+    PageNumber,
+    /// Stripe lists, with `starting_after` and `ending_before` cursors.
+    Stripe,
+}
+
+impl Config {
+    pub fn load<P>(p: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let p = p.as_ref();
+        let s = std::fs::read_to_string(p)
+            .with_context(|| format!("reading config {}", p.display()))?;
+        toml::from_str(&s).with_context(|| format!("parsing config {}", p.display()))
+    }
+
+    pub fn bearer(&self) -> &str {
+        self.bearer.as_deref().unwrap_or("Bearer")
+    }
+
+    pub fn error_body(&self) -> &str {
+        self.error_body.as_deref().unwrap_or("serde_json::Value")
+    }
+
+    pub fn is_hidden_param(&self, nam: &str) -> bool {
+        self.hidden_params.iter().any(|p| p == nam)
+    }
+
+    pub fn is_page_param(&self, nam: &str) -> bool {
+        nam == "page"
+            || nam == "per_page"
+            || nam == "per"
+            || nam == "page_size"
+            || nam == "size"
+            || nam == "next_page_token"
+            || nam == "page_token"
+            || nam == "max_results"
+            || nam == "page_number"
+            || nam == "start"
+            || nam == "sync_token"
+            || nam == "limit"
+            || nam == "ending_before"
+            || self.pagination.page_params.iter().any(|p| p == nam)
+    }
+
+    /*
+     * The name of the type of a schema.
+     */
+    pub fn type_name<'a>(&'a self, schema: &'a str) -> &'a str {
+        self.type_renames
+            .get(schema)
+            .map(|s| s.as_str())
+            .unwrap_or(schema)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Auth, Config, PaginationStyle, RateLimit};
+
+    #[test]
+    fn test_config() {
+        let config: Config = toml::from_str(
+            r#"
+auth = "oauth2"
+token_endpoint = "zoom.us/oauth/token"
+hidden_params = ["token"]
+rate_limit = "zoom"
+idempotency_key = "Idempotency-Key"
+
+[pagination]
+style = "page-token"
+cursor_param = "next_page_token"
+next_cursor_field = "next_page_token"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.auth, Auth::OAuth2);
+        assert_eq!(config.rate_limit, RateLimit::Zoom);
+        assert_eq!(config.idempotency_key.as_deref(), Some("Idempotency-Key"));
+        assert_eq!(config.bearer(), "Bearer");
+        assert_eq!(config.pagination.style, PaginationStyle::PageToken);
+        assert!(config.is_hidden_param("token"));
+        assert!(config.is_page_param("page_size"));
+        assert!(!config.is_page_param("after"));
+        assert_eq!(config.type_name("Meeting"), "Meeting");
+        assert!(config.decimal.is_decimal("decimal", "rate"));
+        assert!(!config.decimal.is_decimal("float", "rate"));
+
+        let config: Config = toml::from_str(
+            r#"
+[decimal]
+formats = ["float"]
+properties = ["grand_total"]
+"#,
+        )
+        .unwrap();
+        assert!(config.decimal.is_decimal("float", "amount"));
+        assert!(config.decimal.is_decimal("", "grandTotal"));
+        assert!(!config.decimal.is_decimal("", "grandTotals"));
+
+        let config: Config = toml::from_str("").unwrap();
+        assert_eq!(config.auth, Auth::OAuth2);
+        assert_eq!(config.pagination.style, PaginationStyle::LinkHeader);
+        assert_eq!(config.rate_limit, RateLimit::RetryAfter);
+        assert_eq!(config.idempotency_key, None);
+
+        assert!(toml::from_str::<Config>("auht = \"api-key\"").is_err());
+    }
+}
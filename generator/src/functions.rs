@@ -1,24 +1,31 @@
 use std::collections::BTreeMap;
 
 use anyhow::{bail, Result};
-use inflector::cases::snakecase::to_snake_case;
+use inflector::cases::{pascalcase::to_pascal_case, snakecase::to_snake_case};
 
 use crate::{
-    clean_fn_name, clean_name, get_parameter_data, make_plural, oid_to_object_name,
-    path_to_operation_id, struct_name, template::parse, ExtractJsonMediaType, ParameterDataExt,
-    ReferenceOrExt, TypeId, TypeSpace,
+    clean_fn_name, clean_name,
+    config::{Config, PaginationStyle},
+    get_parameter_data, make_plural, oid_to_object_name, path_to_operation_id, struct_name,
+    template::parse,
+    ExtractJsonMediaType, ParameterDataExt, ReferenceOrExt, TypeId, TypeSpace,
 };
 
 /*
- * Generate a function for each Operation.
+ * Generate a function for each Operation. For each tag we return its functions and
+ * the params structs for the query parameters the functions take, followed by the
+ * trait of the functions.
  */
 pub fn generate_files(
     api: &openapiv3::OpenAPI,
     proper_name: &str,
+    config: &Config,
     ts: &mut TypeSpace,
     parameters: &BTreeMap<String, &openapiv3::Parameter>,
-) -> Result<BTreeMap<String, String>> {
+) -> Result<BTreeMap<String, (String, String)>> {
     let mut tag_files: BTreeMap<String, String> = Default::default();
+    let mut tag_params: BTreeMap<String, String> = Default::default();
+    let mut tag_methods: BTreeMap<String, Vec<Method>> = Default::default();
 
     let mut fn_names: Vec<String> = Default::default();
     for (pn, p) in api.paths.iter() {
@@ -78,6 +85,8 @@ pub fn generate_files(
             if let Some(o) = tag_files.get(&tag) {
                 out = o.to_string();
             }
+            let mut params_out = tag_params.get(&tag).cloned().unwrap_or_default();
+            let mut methods = tag_methods.remove(&tag).unwrap_or_default();
 
             let mut a = |s: &str| {
                 out.push_str(s);
@@ -91,7 +100,29 @@ pub fn generate_files(
                                 response_type: &str,
                                 template: &str,
                                 fn_inner: &str,
-                                fn_name: &str| {
+                                fn_name: &str,
+                                params_struct: &str,
+                                query_params: &BTreeMap<String, (String, String)>,
+                                fill_default: bool| {
+                if !params_struct.is_empty() {
+                    print_params_fn(
+                        &mut a,
+                        &mut methods,
+                        docs,
+                        bounds,
+                        fn_params_str,
+                        body_param,
+                        response_type,
+                        template,
+                        fn_inner,
+                        fn_name,
+                        params_struct,
+                        query_params,
+                        fill_default,
+                    );
+                    return;
+                }
+
                 // Print the function docs.
                 a(docs);
 
@@ -124,9 +155,18 @@ pub fn generate_files(
 
                 a("}");
                 a("");
+
+                methods.push(Method::new(
+                    fn_name,
+                    bounds,
+                    fn_params_str.iter(),
+                    body_param,
+                    &format!("Result<{}>", response_type),
+                    true,
+                ));
             };
 
-            let docs = get_fn_docs(o, m, p, parameters, ts)?;
+            let docs = get_fn_docs(o, &od, m, p, parameters, ts)?;
 
             let mut bounds: Vec<String> = Vec::new();
 
@@ -188,15 +228,31 @@ pub fn generate_files(
                             // TODO: fix this later.
                             (None, None)
                         } else if ct == "application/x-www-form-urlencoded" {
-                            println!("got application/x-www-form-urlencoded for {}", oid);
-                            // Skip it for now.
-                            // TODO: fix this later.
-                            (None, None)
+                            if let Some(s) = &mt.schema {
+                                let object_name = format!("{} request", oid_to_object_name(&od));
+                                let id = ts.select(Some(&object_name), s, "")?;
+                                let et = ts.id_to_entry.get(&id).unwrap();
+                                if let crate::TypeDetails::Object(p, _) = &et.details {
+                                    // We want to make sure we actally have properties
+                                    // in our object.
+                                    if p.is_empty() {
+                                        (None, None)
+                                    } else {
+                                        let rt = ts.render_type(&id, false)?;
+                                        (Some(format!("&{}", rt)), Some("form".to_string()))
+                                    }
+                                } else {
+                                    let rt = ts.render_type(&id, false)?;
+                                    (Some(format!("&{}", rt)), Some("form".to_string()))
+                                }
+                            } else {
+                                (None, None)
+                            }
                         } else if let Some(s) = &mt.schema {
                             let tid = ts.select(None, s, "")?;
                             let rt = ts.render_type(&tid, false)?;
-                            bounds.push("T: Into<reqwest::Body>".to_string());
                             if rt == "String" {
+                                bounds.push("T: Into<reqwest::Body>".to_string());
                                 (Some("T".to_string()), Some("body".to_string()))
                             } else {
                                 (Some(rt), Some("body".to_string()))
@@ -221,20 +277,33 @@ pub fn generate_files(
             /*
              * Get the function parameters.
              */
-            let (fn_params_str, query_params) =
-                get_fn_params(ts, o, parameters, false, op.parameters.clone(), proper_name)?;
+            let (fn_params_str, mut query_params) =
+                get_fn_params(ts, o, &od, parameters, false, op.parameters.clone(), config)?;
 
             /*
-             * Generate the URL for the request.
+             * Get the response type.
              */
-            let tmp = parse(p)?;
-            let template = tmp.compile(query_params);
+            let (mut response_type, tid, inner_response_type, pagination_property) =
+                get_response_type(&od, ts, o, proper_name, p)?;
+
+            // Stripe lists also take their filters as `deepObject` query parameters.
+            let stripe_list = config.pagination.style == PaginationStyle::Stripe
+                && !pagination_property.is_empty();
+            if stripe_list {
+                query_params.append(&mut get_stripe_list_params(o));
+            }
 
             /*
-             * Get the response type.
+             * Generate the URL for the request. If the function has query parameters,
+             * they are passed in a params struct.
              */
-            let (mut response_type, tid, inner_response_type, pagination_property) =
-                get_response_type(&od, ts, o)?;
+            let tmp = parse(p)?;
+            let template = if query_params.is_empty() {
+                tmp.compile(query_params.clone())
+            } else {
+                tmp.compile_params(query_params.clone())
+            };
+            let template = encode_str_path_params(template, &fn_params_str);
 
             if proper_name == "GitHub" && response_type == "crate::types::Data" {
                 response_type = "()".to_string();
@@ -249,6 +318,7 @@ pub fn generate_files(
 
             let mut fn_inner = get_fn_inner(
                 proper_name,
+                config,
                 &oid,
                 m,
                 &body_func,
@@ -266,30 +336,6 @@ pub fn generate_files(
                 response_type = "String".to_string();
             }
 
-            if let Some(te) = ts.id_to_entry.get(&tid) {
-                // If we have a one of, we can generate a few different subfunctions to
-                // help as well.
-                if let crate::TypeDetails::OneOf(one_of, _) = &te.details {
-                    for itid in one_of {
-                        let rt = ts.render_type(itid, false)?;
-                        print_fn(
-                            &docs,
-                            &bounds,
-                            &fn_params_str,
-                            &body_param,
-                            &rt,
-                            &template,
-                            &fn_inner,
-                            &to_snake_case(&struct_name(&format!(
-                                "{}_{}",
-                                oid.trim_start_matches(&tag).trim_start_matches('_'),
-                                to_snake_case(&rt.replace("crate::types::", ""))
-                            ))),
-                        );
-                    }
-                }
-            }
-
             // Get the function without the function inners.
             // This is specifically for Ramp.
             // We do this directly before we print the other function.
@@ -332,6 +378,53 @@ pub fn generate_files(
             }
             fn_names.push(fn_name.clone() + &tag);
 
+            // The query parameters are passed in a params struct, named after the function.
+            let params_struct = if query_params.is_empty() {
+                String::new()
+            } else {
+                fn_names.push(format!("{}_with{}", fn_name, tag));
+
+                // Not `struct_name`, it drops repeated words and two functions would get the
+                // same struct.
+                let sn = format!("{}{}Params", struct_name(&tag), to_pascal_case(&fn_name));
+                let (_, field_docs) = split_param_docs(&docs, &[], &sn, &query_params);
+                params_out.push_str(&get_params_struct(
+                    &tag,
+                    &fn_name,
+                    &sn,
+                    &query_params,
+                    &field_docs,
+                ));
+                sn
+            };
+
+            if let Some(te) = ts.id_to_entry.get(&tid) {
+                // If we have a one of, we can generate a few different subfunctions to
+                // help as well.
+                if let crate::TypeDetails::OneOf(one_of, _) = &te.details {
+                    for itid in one_of {
+                        let rt = ts.render_type(itid, false)?;
+                        print_fn(
+                            &docs,
+                            &bounds,
+                            &fn_params_str,
+                            &body_param,
+                            &rt,
+                            &template,
+                            &fn_inner,
+                            &to_snake_case(&struct_name(&format!(
+                                "{}_{}",
+                                oid.trim_start_matches(&tag).trim_start_matches('_'),
+                                to_snake_case(&rt.replace("crate::types::", ""))
+                            ))),
+                            &params_struct,
+                            &query_params,
+                            false,
+                        );
+                    }
+                }
+            }
+
             // Print our standard function.
             print_fn(
                 &docs,
@@ -342,8 +435,46 @@ pub fn generate_files(
                 &template,
                 &fn_inner,
                 &fn_name,
+                &params_struct,
+                &query_params,
+                false,
             );
 
+            // If we are downloading a file, also give the option to stream the body
+            // rather than reading it all into memory.
+            if frt == "crate::Download" {
+                let docs = get_fn_docs_stream(o, m, p, &fn_name)?;
+
+                let fn_inner = get_fn_inner(
+                    proper_name,
+                    config,
+                    &oid,
+                    m,
+                    &body_func,
+                    "crate::DownloadStream",
+                    &inner_response_type,
+                    &pagination_property,
+                    false,
+                )?;
+
+                let fn_name = format!("{}_stream", fn_name);
+                fn_names.push(fn_name.clone() + &tag);
+
+                print_fn(
+                    &docs,
+                    &bounds,
+                    &fn_params_str,
+                    &body_param,
+                    "crate::DownloadStream",
+                    &template,
+                    &fn_inner,
+                    &fn_name,
+                    &params_struct,
+                    &query_params,
+                    false,
+                );
+            }
+
             // If we are returning a list of things and we have page, etc as
             // params, let's get all the pages.
             if frt.starts_with("Vec<") && http::Method::GET == m {
@@ -354,22 +485,51 @@ pub fn generate_files(
                     oid.trim_start_matches(&tag).trim_start_matches('_'),
                 )?;
 
-                let (fn_params_str, query_params) =
-                    get_fn_params(ts, o, parameters, true, op.parameters.clone(), proper_name)?;
+                let (fn_params_str, mut all_query_params) =
+                    get_fn_params(ts, o, &od, parameters, true, op.parameters.clone(), config)?;
+                if stripe_list {
+                    all_query_params.append(&mut get_stripe_list_params(o));
+                }
 
+                // The page parameters of the params struct are ignored, we send our own.
+                let all_params_struct = if all_query_params.is_empty() {
+                    ""
+                } else {
+                    params_struct.as_str()
+                };
                 let tmp = parse(p)?;
-                let template = tmp.compile(query_params);
-
-                let fn_inner = get_fn_inner(
-                    proper_name,
-                    &oid,
-                    m,
-                    &body_func,
-                    &response_type,
-                    &inner_response_type,
-                    &pagination_property,
-                    true,
-                )?;
+                let template = if all_params_struct.is_empty() {
+                    tmp.compile(all_query_params.clone())
+                } else {
+                    tmp.compile_params(all_query_params.clone())
+                };
+                let template = encode_str_path_params(template, &fn_params_str);
+
+                // Stripe lists all page the same way, with the helpers of the
+                // `pagination` module.
+                let item_id = if config.pagination.style == PaginationStyle::Stripe {
+                    get_stripe_item_id(ts, &tid, &pagination_property, &frt)?
+                } else {
+                    String::new()
+                };
+                let fn_inner = if config.pagination.style == PaginationStyle::Stripe {
+                    get_stripe_list_inner(&item_id, &query_params, all_params_struct, false)
+                } else {
+                    get_fn_inner(
+                        proper_name,
+                        config,
+                        &oid,
+                        m,
+                        &body_func,
+                        &response_type,
+                        &inner_response_type,
+                        &pagination_property,
+                        true,
+                    )?
+                };
+
+                // The stream is named after the function that returns a single page.
+                let stream_name = format!("{}_stream", fn_name.trim_end_matches("_page"));
 
                 let mut fn_name = oid
                     .replace("_get_", "_get_all_")
@@ -419,11 +579,58 @@ pub fn generate_files(
                     &template,
                     &fn_inner,
                     &fn_name,
+                    all_params_struct,
+                    &all_query_params,
+                    all_query_params.len() < query_params.len(),
                 );
+
+                // Also give the option to fetch the pages one at a time, as they are needed.
+                if body_param.is_none() && !fn_names.contains(&(stream_name.clone() + &tag)) {
+                    fn_names.push(stream_name.clone() + &tag);
+
+                    let docs = get_fn_docs_pages(o, m, p, &fn_name)?;
+                    let fn_inner = if config.pagination.style == PaginationStyle::Stripe {
+                        get_stripe_list_inner(&item_id, &query_params, all_params_struct, true)
+                    } else {
+                        // The cursor of the params struct, the stream starts there unless
+                        // it is given a `start`.
+                        let start_param = query_params
+                            .iter()
+                            .find(|(_, (_, name))| *name == config.pagination.cursor_param)
+                            .filter(|_| {
+                                !all_params_struct.is_empty()
+                                    && !pagination_property.is_empty()
+                                    && config.pagination.style == PaginationStyle::PageToken
+                            })
+                            .map(|(nam, (typ, _))| (nam.as_str(), typ.as_str()));
+                        get_fn_stream_inner(
+                            proper_name,
+                            config,
+                            &response_type,
+                            &pagination_property,
+                            start_param,
+                        )?
+                    };
+
+                    print_stream_fn(
+                        &mut a,
+                        &mut methods,
+                        &docs,
+                        &fn_params_str,
+                        &frt,
+                        &template,
+                        &fn_inner,
+                        &stream_name,
+                        all_params_struct,
+                        &all_query_params,
+                    );
+                }
             }
 
             // Add this to our map of functions based on the tag name.
-            tag_files.insert(tag, out.to_string());
+            tag_files.insert(tag.to_string(), out.to_string());
+            tag_params.insert(tag.to_string(), params_out);
+            tag_methods.insert(tag, methods);
 
             Ok(())
         };
@@ -438,131 +645,713 @@ pub fn generate_files(
         gen(pn.as_str(), "TRACE", op.trace.as_ref())?;
     }
 
-    Ok(tag_files)
+    Ok(tag_files
+        .into_iter()
+        .map(|(tag, out)| {
+            let mut params = tag_params.remove(&tag).unwrap_or_default();
+            params.push_str(&get_trait(
+                &tag,
+                &tag_methods.remove(&tag).unwrap_or_default(),
+            ));
+            (tag, (out, params))
+        })
+        .collect())
 }
 
-fn get_response_type_from_object(
-    od: &str,
-    ts: &mut TypeSpace,
-    s: Option<&openapiv3::ReferenceOr<openapiv3::Schema>>,
-    r: Option<&openapiv3::ReferenceOr<openapiv3::Response>>,
-) -> Result<(
-    String,        // original response type
-    crate::TypeId, // type id
-    String,        // optional vec response type if this struct paginates
-    String,        // optional name of vec response property if this struct paginates
-)> {
-    let object_name = format!("{} response", oid_to_object_name(od));
-    let mut tid = TypeId(0);
-
-    if let Some(s) = s {
-        tid = ts.select(Some(&clean_name(&object_name)), s, "")?;
-
-        if let openapiv3::ReferenceOr::Reference { reference } = s {
-            tid = ts.select_ref(Some(&clean_name(&object_name)), reference)?;
-        }
-    }
+/*
+ * Print a function that takes its query parameters as a params struct, followed by
+ * the deprecated function that takes them as positional arguments, where an empty
+ * string, `false` or `0` leaves the parameter out.
+ */
+#[allow(clippy::too_many_arguments)]
+fn print_params_fn<F: FnMut(&str)>(
+    a: &mut F,
+    methods: &mut Vec<Method>,
+    docs: &str,
+    bounds: &[String],
+    fn_params_str: &[String],
+    body_param: &Option<String>,
+    response_type: &str,
+    template: &str,
+    fn_inner: &str,
+    fn_name: &str,
+    params_struct: &str,
+    query_params: &BTreeMap<String, (String, String)>,
+    fill_default: bool,
+) {
+    let generics = if bounds.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", bounds.join(", "))
+    };
 
-    if let Some(openapiv3::ReferenceOr::Reference { reference }) = r {
-        tid = ts.select_ref(Some(&clean_name(&object_name)), reference)?;
+    // A positional argument can be prefixed with an underscore if it is unused.
+    let other_params: Vec<&String> = fn_params_str
+        .iter()
+        .filter(|p| {
+            let nam = p.split(':').next().unwrap_or_default().trim();
+            !query_params.contains_key(nam.trim_start_matches('_'))
+        })
+        .collect();
+    let mut args: Vec<String> = other_params
+        .iter()
+        .map(|p| p.split(':').next().unwrap_or_default().trim().to_string())
+        .collect();
+    args.push("&params".to_string());
+    if body_param.is_some() {
+        args.push("body".to_string());
     }
 
-    if tid == TypeId(0) {
-        bail!("should have gotten type id for {}", od);
+    let (docs, _) = split_param_docs(docs, &other_params, params_struct, query_params);
+    a(&docs);
+    a(&format!("pub async fn {}_with{}(", fn_name, generics));
+    a("&self,");
+    for p in &other_params {
+        a(p);
     }
-
-    let og_rt = ts.render_type(&tid, false)?;
-    let mut et = ts.id_to_entry.get(&tid).unwrap();
-
-    if let crate::TypeDetails::NamedType(id, _) = &et.details {
-        et = ts.id_to_entry.get(id).unwrap();
+    a(&format!("params: &{},", params_struct));
+    if let Some(bp) = &body_param {
+        a(&format!("body: {}", bp));
     }
+    a(&format!(") -> Result<{}> {{", response_type));
+    a(template);
+    a(fn_inner);
+    a("}");
+    a("");
+
+    let params_arg = format!("params: &{},", params_struct);
+    methods.push(Method::new(
+        &format!("{}_with", fn_name),
+        bounds,
+        other_params.iter().copied().chain(Some(&params_arg)),
+        body_param,
+        &format!("Result<{}>", response_type),
+        true,
+    ));
 
-    if let crate::TypeDetails::Object(p, _) = &et.details {
-        // For Ramp, the pagination values are passed _in_ the resulting
-        // struct, so we want to ignore them and just get the data.
-        if let Some(pid) = p.get("page") {
-            let rt = ts.render_type(pid, false)?;
-            if rt == "crate::types::Page" || rt.ends_with("Page") {
-                if let Some(did) = p.get("data") {
-                    let rt = ts.render_type(did, false)?;
-                    return Ok((og_rt, did.clone(), rt, "data".to_string()));
-                } else if p.len() == 2 {
-                    // We know for the Ramp API there will only be two fields in
-                    // these structs. This should help prevent errors.
-                    // Let's find the value of the struct that is the vec!
-                    for (n, id) in p {
-                        let rt = ts.render_type(id, false)?;
-                        if rt.starts_with("Vec<") {
-                            return Ok((og_rt, id.clone(), rt, to_snake_case(n)));
-                        }
-                    }
+    // Only the parameters that have a positional argument of the same type can be
+    // set, filters that the positional arguments could not express are left out.
+    // Ranges were sent as a single value, which is the range from it to itself.
+    let fields: Vec<String> = query_params
+        .iter()
+        .filter_map(|(nam, (typ, prop))| {
+            let (arg, arg_typ) = fn_params_str.iter().find_map(|p| {
+                let (arg, arg_typ) = p.split_once(':')?;
+                let arg = arg.trim();
+                let arg_typ = arg_typ.trim().trim_end_matches(',');
+                if arg.trim_start_matches('_') == nam
+                    && (arg_typ == typ
+                        || (arg_typ == "&str" && typ == "crate::pagination::RangeQuery"))
+                {
+                    Some((arg, arg_typ))
+                } else {
+                    None
                 }
+            })?;
+            let value = if arg_typ == typ {
+                get_param_field_value(arg, typ, prop)
+            } else {
+                format!("crate::pagination::RangeQuery::parse({})", arg)
+            };
+            if &value == nam {
+                Some(format!("{},", nam))
+            } else {
+                Some(format!("{}: {},", nam, value))
             }
-        }
+        })
+        .collect();
+
+    // The positional arguments that are not sent any more.
+    let ignored: Vec<&str> = fn_params_str
+        .iter()
+        .filter(|p| !other_params.contains(p))
+        .map(|p| p.split(':').next().unwrap_or_default().trim())
+        .filter(|arg| {
+            !fields
+                .iter()
+                .any(|f| f.split([':', ',']).next() == Some(arg.trim_start_matches('_')))
+        })
+        .collect();
 
-        // For Ramp, the pagination values are passed _in_ the resulting
-        // struct, so we want to ignore them and just get the data.
-        if p.get("has_more").is_some() {
-            if let Some(did) = p.get("data") {
-                let rt = ts.render_type(did, false)?;
-                return Ok((og_rt, did.clone(), rt, "data".to_string()));
-            } else if p.len() == 2 {
-                // We know for the Ramp API there will only be two fields in
-                // these structs. This should help prevent errors.
-                // Let's find the value of the struct that is the vec!
-                for (n, id) in p {
-                    let rt = ts.render_type(id, false)?;
-                    if rt.starts_with("Vec<") {
-                        return Ok((og_rt, id.clone(), rt, to_snake_case(n)));
-                    }
-                }
-            }
+    a(&format!(
+        r#"/**
+* Deprecated: use `{}_with` and pass the query parameters as a `{}` instead.{}
+*/
+#[deprecated(note = "use `{}_with` instead")]"#,
+        fn_name,
+        params_struct,
+        if ignored.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "\n*\n* {} {} not sent.",
+                ignored
+                    .iter()
+                    .map(|arg| format!("`{}`", arg.trim_start_matches('_')))
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                if ignored.len() == 1 { "is" } else { "are" }
+            )
+        },
+        fn_name
+    ));
+    a(&format!("pub async fn {}{}(", fn_name, generics));
+    a("&self,");
+    for p in fn_params_str {
+        let arg = p.split(':').next().unwrap_or_default().trim();
+        if ignored.contains(&arg) && !arg.starts_with('_') {
+            a(&format!("_{}", p));
+        } else {
+            a(p);
         }
+    }
+    if let Some(bp) = &body_param {
+        a(&format!("body: {}", bp));
+    }
+    a(&format!(") -> Result<{}> {{", response_type));
 
-        // For Zoom, the pagination values are passed _in_ the resulting
-        // struct, so we want to ignore them and just get the data.
-        if let Some(pid) = p.get("next_page_token") {
-            let rt = ts.render_type(pid, false)?;
-            if rt == "String" {
-                for (n, id) in p {
-                    // Now we must find the property with the vector for this struct.
-                    let rt = ts.render_type(id, false)?;
-                    if rt.starts_with("Vec<") {
-                        return Ok((og_rt, id.clone(), rt, to_snake_case(n)));
-                    }
-                }
-            }
+    if fields.is_empty() {
+        a(&format!("let params = {}::default();", params_struct));
+    } else {
+        a(&format!("let params = {} {{", params_struct));
+        for f in &fields {
+            a(f);
         }
-
-        // For Google, the pagination values are passed _in_ the resulting
-        // struct, so we want to ignore them and just get the data.
-        if let Some(pid) = p.get("nextPageToken") {
-            let rt = ts.render_type(pid, false)?;
-            if rt == "String" {
-                if let Some(did) = p.get("items") {
-                    let rt = ts.render_type(did, false)?;
-                    return Ok((og_rt, did.clone(), rt, "items".to_string()));
-                } else {
-                    for (n, id) in p {
-                        // Now we must find the property with the vector for this struct.
-                        let rt = ts.render_type(id, false)?;
-                        if rt.starts_with("Vec<") {
-                            return Ok((og_rt, id.clone(), rt, to_snake_case(n)));
-                        }
-                    }
-                }
-            }
+        if fill_default || fields.len() < query_params.len() {
+            a("..Default::default()");
         }
+        a("};");
     }
-
-    Ok((og_rt, tid, "".to_string(), "".to_string()))
+    a(&format!("self.{}_with({}).await", fn_name, args.join(", ")));
+    a("}");
+    a("");
 }
 
-fn get_response_type(
+/*
+ * Print a function that returns a stream of the items of all the pages of a
+ * request, fetching each page only once the items of the previous one are consumed.
+ */
+#[allow(clippy::too_many_arguments)]
+fn print_stream_fn<F: FnMut(&str)>(
+    a: &mut F,
+    methods: &mut Vec<Method>,
+    docs: &str,
+    fn_params_str: &[String],
+    response_type: &str,
+    template: &str,
+    fn_inner: &str,
+    fn_name: &str,
+    params_struct: &str,
+    query_params: &BTreeMap<String, (String, String)>,
+) {
+    let item_type = response_type
+        .trim_start_matches("Vec<")
+        .trim_end_matches('>');
+
+    a(docs);
+    a(&format!("pub fn {}(", fn_name));
+    a("&self,");
+    if params_struct.is_empty() {
+        if !fn_params_str.is_empty() {
+            a(&fn_params_str.join(" "));
+        }
+    } else {
+        for p in fn_params_str {
+            let nam = p.split(':').next().unwrap_or_default().trim();
+            if !query_params.contains_key(nam.trim_start_matches('_')) {
+                a(p);
+            }
+        }
+        a(&format!("params: &{},", params_struct));
+    }
+    a("start: Option<String>,");
+    a("max_items: Option<usize>,");
+    a(&format!(") -> crate::pagination::Pages<{}> {{", item_type));
+    a(template);
+    a(fn_inner);
+    a("}");
+    a("");
+
+    let mut params: Vec<String> = if params_struct.is_empty() {
+        fn_params_str.to_vec()
+    } else {
+        fn_params_str
+            .iter()
+            .filter(|p| {
+                let nam = p.split(':').next().unwrap_or_default().trim();
+                !query_params.contains_key(nam.trim_start_matches('_'))
+            })
+            .cloned()
+            .chain(Some(format!("params: &{},", params_struct)))
+            .collect()
+    };
+    params.push("start: Option<String>,".to_string());
+    params.push("max_items: Option<usize>,".to_string());
+    methods.push(Method::new(
+        fn_name,
+        &[],
+        params.iter(),
+        &None,
+        &format!("crate::pagination::Pages<{}>", item_type),
+        false,
+    ));
+}
+
+/*
+ * A function of a tag, as it is declared in the trait of the tag.
+ */
+struct Method {
+    name: String,
+    bounds: Vec<String>,
+    params: Vec<(String, String)>,
+    response_type: String,
+    is_async: bool,
+}
+
+impl Method {
+    fn new<'a, I>(
+        name: &str,
+        bounds: &[String],
+        params: I,
+        body_param: &Option<String>,
+        response_type: &str,
+        is_async: bool,
+    ) -> Self
+    where
+        I: Iterator<Item = &'a String>,
+    {
+        // The parameters are printed as `name: type,`.
+        let mut params: Vec<(String, String)> = params
+            .filter_map(|p| {
+                let (nam, typ) = p.split_once(':')?;
+                Some((
+                    nam.trim().to_string(),
+                    typ.trim().trim_end_matches(',').to_string(),
+                ))
+            })
+            .collect();
+        if let Some(bp) = body_param {
+            params.push(("body".to_string(), bp.to_string()));
+        }
+
+        Method {
+            name: name.to_string(),
+            bounds: bounds.to_vec(),
+            params,
+            response_type: response_type.to_string(),
+            is_async,
+        }
+    }
+
+    fn signature(&self) -> String {
+        // The futures of the trait are `Send`, and so must be what they hold on to. The
+        // bounds go in a `where`, which `async_trait` adds its own to.
+        let (generics, where_) = if self.bounds.is_empty() {
+            (String::new(), String::new())
+        } else {
+            let names: Vec<&str> = self
+                .bounds
+                .iter()
+                .map(|b| b.split(':').next().unwrap_or_default().trim())
+                .collect();
+            let bounds: Vec<String> = self
+                .bounds
+                .iter()
+                .map(|b| format!("{} + Send + 'static", b))
+                .collect();
+            (
+                format!("<{}>", names.join(", ")),
+                format!(" where {}", bounds.join(", ")),
+            )
+        };
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|(nam, typ)| format!("{}: {},", nam, typ))
+            .collect();
+
+        format!(
+            "{}fn {}{}(&self, {}) -> {}{}",
+            if self.is_async { "async " } else { "" },
+            self.name,
+            generics,
+            params.join(" "),
+            self.response_type,
+            where_
+        )
+    }
+}
+
+/*
+ * Generate the trait of the functions of a tag, implemented by the struct of the tag,
+ * so that code that uses the functions can take the trait instead, and be tested
+ * with the mock of the `mock` feature.
+ */
+fn get_trait(tag: &str, methods: &[Method]) -> String {
+    let sn = struct_name(tag);
+
+    let mut decls = String::new();
+    let mut impls = String::new();
+    for m in methods {
+        let args: Vec<&str> = m.params.iter().map(|(nam, _)| nam.as_str()).collect();
+        decls.push_str(&format!(
+            "/// See [`{}::{}`].\n{};\n\n",
+            sn,
+            m.name,
+            m.signature()
+        ));
+        impls.push_str(&format!(
+            "{} {{\n{}::{}(self, {}){}\n}}\n\n",
+            m.signature(),
+            sn,
+            m.name,
+            args.join(", "),
+            if m.is_async { ".await" } else { "" }
+        ));
+    }
+
+    format!(
+        r#"
+/// The functions of [`{}`], for code that takes them as a trait, so that it can be
+/// tested with the `Mock{}Api` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait {}Api: Send + Sync {{
+{}}}
+
+#[async_trait::async_trait]
+impl {}Api for {} {{
+{}}}
+"#,
+        sn, sn, sn, decls, sn, sn, impls
+    )
+}
+
+/*
+ * Generate the params struct for the query parameters of a function, with a
+ * builder method for each parameter.
+ */
+fn get_params_struct(
+    tag: &str,
+    fn_name: &str,
+    sn: &str,
+    query_params: &BTreeMap<String, (String, String)>,
+    field_docs: &BTreeMap<String, String>,
+) -> String {
+    let mut fields = String::new();
+    let mut setters = String::new();
+    for (nam, (typ, _)) in query_params {
+        let field_type = get_param_field_type(typ);
+        if let Some(d) = field_docs.get(nam) {
+            fields.push_str(&format!("/// {}\n", d.replace('\n', "\n/// ")));
+        }
+        fields.push_str(&format!("pub {}: Option<{}>,\n", nam, field_type));
+        if field_type == "String" {
+            setters.push_str(&format!(
+                r#"
+pub fn {}<T: ToString>(mut self, {}: T) -> Self {{
+    self.{} = Some({}.to_string());
+    self
+}}
+"#,
+                nam, nam, nam, nam
+            ));
+        } else {
+            setters.push_str(&format!(
+                r#"
+pub fn {}(mut self, {}: {}) -> Self {{
+    self.{} = Some({});
+    self
+}}
+"#,
+                nam, nam, field_type, nam, nam
+            ));
+        }
+    }
+
+    format!(
+        r#"
+/// The query parameters of `{}::{}_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct {} {{
+{}}}
+
+impl {} {{
+{}}}
+"#,
+        struct_name(tag),
+        fn_name,
+        sn,
+        fields,
+        sn,
+        setters
+    )
+}
+
+/*
+ * Split the docs of a function into the docs of its `_with` function, which lists its
+ * positional arguments and its params struct, and the docs of the fields of the params
+ * struct, taken from the list of parameters.
+ */
+fn split_param_docs(
+    docs: &str,
+    fn_params_str: &[&String],
+    params_struct: &str,
+    query_params: &BTreeMap<String, (String, String)>,
+) -> (String, BTreeMap<String, String>) {
+    let mut out: Vec<String> = Default::default();
+    let mut field_docs: BTreeMap<String, String> = Default::default();
+    // The parameter the current line is about, and whether to keep it in the list.
+    let mut current: Option<(String, bool)> = None;
+    for line in docs.lines() {
+        if let Some(entry) = line.strip_prefix("* * `") {
+            let nam = entry.split(':').next().unwrap_or_default().trim();
+            let keep = fn_params_str
+                .iter()
+                .any(|p| p.split(':').next().unwrap_or_default().trim() == nam);
+            if query_params.contains_key(nam) {
+                if let Some((_, d)) = entry.split_once(" -- ") {
+                    field_docs.insert(nam.to_string(), d.to_string());
+                }
+            }
+            current = Some((nam.to_string(), keep));
+            if keep {
+                out.push(line.to_string());
+            }
+            continue;
+        }
+
+        // The descriptions go on indented, by two spaces for those of the types.
+        if let (Some((nam, keep)), Some(more)) = (&current, line.strip_prefix("*  ")) {
+            if *keep {
+                out.push(line.to_string());
+            } else if let Some(d) = field_docs.get_mut(nam) {
+                let more = more.trim_start();
+                // Lines that go on after an item of a list are part of the item.
+                let indent = if more.is_empty() || get_list_item_indent(more) > 0 {
+                    0
+                } else {
+                    d.lines()
+                        .last()
+                        .map(get_list_item_indent)
+                        .unwrap_or_default()
+                };
+                d.push('\n');
+                d.push_str(&" ".repeat(indent));
+                d.push_str(more);
+            }
+            continue;
+        }
+
+        current = None;
+        if line == "*/" {
+            // The list of parameters ends the docs, the params struct goes last.
+            if !out.iter().any(|l| l == "* **Parameters:**") {
+                out.push("*".to_string());
+                out.push("* **Parameters:**".to_string());
+                out.push("*".to_string());
+            }
+            out.push(format!(
+                "* * `params: &{}` -- The query parameters, only the ones that are set are sent.",
+                params_struct
+            ));
+        }
+        out.push(line.to_string());
+    }
+
+    (out.join("\n"), field_docs)
+}
+
+/*
+ * How far the text of an item of a list is indented, past its marker, or the
+ * indentation of a line that goes on after an item, `0` for other lines.
+ */
+fn get_list_item_indent(line: &str) -> usize {
+    let text = line.trim_start();
+    let marker = if text.starts_with("- ") || text.starts_with("* ") || text.starts_with("+ ") {
+        2
+    } else {
+        let digits = text.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits > 0 && text[digits..].starts_with(". ") {
+            digits + 2
+        } else {
+            0
+        }
+    };
+
+    if marker > 0 || text.len() < line.len() {
+        line.len() - text.len() + marker
+    } else {
+        0
+    }
+}
+
+/*
+ * The type of the field of a params struct for a query parameter.
+ */
+fn get_param_field_type(typ: &str) -> String {
+    if typ == "&str" {
+        "String".to_string()
+    } else if typ == "&[String]" {
+        "Vec<String>".to_string()
+    } else if let Some(t) = typ
+        .strip_prefix("Option<")
+        .and_then(|t| t.strip_suffix('>'))
+    {
+        t.to_string()
+    } else {
+        typ.to_string()
+    }
+}
+
+/*
+ * Convert a positional argument of a deprecated function into the field of its
+ * params struct, the same way the argument used to be left out of the query.
+ */
+fn get_param_field_value(nam: &str, typ: &str, prop: &str) -> String {
+    if typ.starts_with("Option<") {
+        nam.to_string()
+    } else if typ == "&str" {
+        format!("Some({}.to_string()).filter(|s| !s.is_empty())", nam)
+    } else if typ == "&[String]" {
+        format!("Some({}.to_vec()).filter(|v| !v.is_empty())", nam)
+    } else if typ == "i64" || typ == "i32" {
+        format!("Some({}).filter(|v| *v > 0)", nam)
+    } else if typ == "bool" && prop == "sendNotificationEmail" {
+        format!("Some({})", nam)
+    } else if typ == "bool" {
+        format!("Some({}).filter(|v| *v)", nam)
+    } else if typ == "uuid::Uuid" {
+        format!("Some({}).filter(|u| !u.is_nil())", nam)
+    } else {
+        format!("Some({}).filter(|v| !v.to_string().is_empty())", nam)
+    }
+}
+
+fn get_response_type_from_object(
+    od: &str,
+    ts: &mut TypeSpace,
+    s: Option<&openapiv3::ReferenceOr<openapiv3::Schema>>,
+    r: Option<&openapiv3::ReferenceOr<openapiv3::Response>>,
+) -> Result<(
+    String,        // original response type
+    crate::TypeId, // type id
+    String,        // optional vec response type if this struct paginates
+    String,        // optional name of vec response property if this struct paginates
+)> {
+    let object_name = format!("{} response", oid_to_object_name(od));
+    let mut tid = TypeId(0);
+
+    if let Some(s) = s {
+        tid = ts.select(Some(&clean_name(&object_name)), s, "")?;
+
+        if let openapiv3::ReferenceOr::Reference { reference } = s {
+            tid = ts.select_ref(Some(&clean_name(&object_name)), reference)?;
+        }
+    }
+
+    if let Some(openapiv3::ReferenceOr::Reference { reference }) = r {
+        tid = ts.select_ref(Some(&clean_name(&object_name)), reference)?;
+    }
+
+    if tid == TypeId(0) {
+        bail!("should have gotten type id for {}", od);
+    }
+
+    let og_rt = ts.render_type(&tid, false)?;
+    let mut et = ts.id_to_entry.get(&tid).unwrap();
+
+    if let crate::TypeDetails::NamedType(id, _) = &et.details {
+        et = ts.id_to_entry.get(id).unwrap();
+    }
+
+    if let crate::TypeDetails::Object(p, _) = &et.details {
+        // For Ramp, the pagination values are passed _in_ the resulting
+        // struct, so we want to ignore them and just get the data.
+        if let Some(pid) = p.get("page") {
+            let rt = ts.render_type(pid, false)?;
+            if rt == "crate::types::Page" || rt.ends_with("Page") {
+                if let Some(did) = p.get("data") {
+                    let rt = ts.render_type(did, false)?;
+                    return Ok((og_rt, did.clone(), rt, "data".to_string()));
+                } else if p.len() == 2 {
+                    // We know for the Ramp API there will only be two fields in
+                    // these structs. This should help prevent errors.
+                    // Let's find the value of the struct that is the vec!
+                    for (n, id) in p {
+                        let rt = ts.render_type(id, false)?;
+                        if rt.starts_with("Vec<") {
+                            return Ok((og_rt, id.clone(), rt, to_snake_case(n)));
+                        }
+                    }
+                }
+            }
+        }
+
+        // For Ramp, the pagination values are passed _in_ the resulting
+        // struct, so we want to ignore them and just get the data.
+        if p.get("has_more").is_some() {
+            if let Some(did) = p.get("data") {
+                let rt = ts.render_type(did, false)?;
+                return Ok((og_rt, did.clone(), rt, "data".to_string()));
+            } else if p.len() == 2 {
+                // We know for the Ramp API there will only be two fields in
+                // these structs. This should help prevent errors.
+                // Let's find the value of the struct that is the vec!
+                for (n, id) in p {
+                    let rt = ts.render_type(id, false)?;
+                    if rt.starts_with("Vec<") {
+                        return Ok((og_rt, id.clone(), rt, to_snake_case(n)));
+                    }
+                }
+            }
+        }
+
+        // For Zoom, the pagination values are passed _in_ the resulting
+        // struct, so we want to ignore them and just get the data.
+        if let Some(pid) = p.get("next_page_token") {
+            let rt = ts.render_type(pid, false)?;
+            if rt == "String" {
+                for (n, id) in p {
+                    // Now we must find the property with the vector for this struct.
+                    let rt = ts.render_type(id, false)?;
+                    if rt.starts_with("Vec<") {
+                        return Ok((og_rt, id.clone(), rt, to_snake_case(n)));
+                    }
+                }
+            }
+        }
+
+        // For Google, the pagination values are passed _in_ the resulting
+        // struct, so we want to ignore them and just get the data.
+        if let Some(pid) = p.get("nextPageToken") {
+            let rt = ts.render_type(pid, false)?;
+            if rt == "String" {
+                if let Some(did) = p.get("items") {
+                    let rt = ts.render_type(did, false)?;
+                    return Ok((og_rt, did.clone(), rt, "items".to_string()));
+                } else {
+                    for (n, id) in p {
+                        // Now we must find the property with the vector for this struct.
+                        let rt = ts.render_type(id, false)?;
+                        if rt.starts_with("Vec<") {
+                            return Ok((og_rt, id.clone(), rt, to_snake_case(n)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok((og_rt, tid, "".to_string(), "".to_string()))
+}
+
+fn get_response_type(
     od: &str,
     ts: &mut TypeSpace,
     o: &openapiv3::Operation,
+    proper_name: &str,
+    p: &str,
 ) -> Result<(
     String,        // original response type
     crate::TypeId, // type id
@@ -572,6 +1361,15 @@ fn get_response_type(
     // Get the first response.
     let first = o.responses.responses.first().unwrap();
     if let Ok(i) = first.1.item() {
+        if is_download(proper_name, p, first.0, i) {
+            return Ok((
+                "crate::Download".to_string(),
+                crate::TypeId(0),
+                "".to_string(),
+                "".to_string(),
+            ));
+        }
+
         if i.content.is_empty() {
             // Return empty.
             return Ok((
@@ -654,10 +1452,11 @@ fn get_response_type(
 fn get_fn_params(
     ts: &mut TypeSpace,
     o: &openapiv3::Operation,
+    od: &str,
     parameters: &BTreeMap<String, &openapiv3::Parameter>,
     all_pages: bool,
     global_params: Vec<openapiv3::ReferenceOr<openapiv3::Parameter>>,
-    proper_name: &str,
+    config: &Config,
 ) -> Result<(Vec<String>, BTreeMap<String, (String, String)>)> {
     /*
      * Query parameters are sorted lexicographically to ensure a stable
@@ -666,10 +1465,11 @@ fn get_fn_params(
     let mut fn_params_str: Vec<String> = Default::default();
     let mut fn_params: Vec<String> = Default::default();
     let mut query_params: BTreeMap<String, (String, String)> = Default::default();
+    let global = global_params.len();
     let mut gp = global_params;
     let mut op = o.parameters.clone();
     gp.append(&mut op);
-    for par in gp.iter() {
+    for (i, par) in gp.iter().enumerate() {
         let mut param_name = "".to_string();
         let item = match par {
             openapiv3::ReferenceOr::Reference { reference } => {
@@ -688,7 +1488,20 @@ fn get_fn_params(
         let nam = &to_snake_case(&parameter_data.name);
 
         if !fn_params.contains(nam) && !fn_params.contains(&format!("{}_", nam)) {
-            let typ = parameter_data.render_type(&param_name, ts)?;
+            let typ = if is_enum_param(parameter_data) {
+                // The enums of the parameters are the ones we selected when we generated
+                // them, named after the operation, or the path for those of the path.
+                // Another enum with the same values may be in the module of another tag.
+                let name = if i < global {
+                    None
+                } else {
+                    Some(oid_to_object_name(od))
+                };
+                let pid = ts.select_param(name.as_deref(), par)?;
+                ts.render_type(&pid, false)?
+            } else {
+                parameter_data.render_type(&param_name, ts)?
+            };
             if nam == "ref"
                 || nam == "type"
                 || nam == "foo"
@@ -701,17 +1514,7 @@ fn get_fn_params(
             } else if nam == "i_ds" {
                 fn_params_str.push(format!("ids: {},", typ));
                 fn_params.push("ids".to_string());
-            } else if (!all_pages || !is_page_param(nam, proper_name))
-                && (nam != "authorization" || proper_name == "Stripe")
-                && !nam.starts_with("authorization_bearer")
-                && (!proper_name.starts_with("Google")
-                    || !is_google_unnecessary_param(proper_name, nam))
-                && (proper_name != "SendGrid" || !is_sendgrid_unnecessary_param(nam))
-                && (proper_name != "Slack" || !is_slack_unnecessary_param(nam))
-                && (proper_name != "Okta" || !is_okta_unnecessary_param(nam))
-                && (proper_name != "ShipBob" || !is_shipbob_unnecessary_param(nam))
-                && (proper_name != "Stripe" || !is_stripe_unnecessary_param(nam))
-            {
+            } else if !is_hidden_param(config, item, nam, all_pages) {
                 if typ == "chrono::DateTime<chrono::Utc>" {
                     fn_params_str.push(format!("{}: Option<{}>,", nam, typ));
                     fn_params.push(nam.to_string());
@@ -752,17 +1555,7 @@ fn get_fn_params(
                         "ids".to_string(),
                         (typ.to_string(), parameter_data.name.to_string()),
                     );
-                } else if (!all_pages || !is_page_param(nam, proper_name))
-                    && (nam != "authorization" || proper_name == "Stripe")
-                    && !nam.starts_with("authorization_bearer")
-                    && (!proper_name.starts_with("Google")
-                        || !is_google_unnecessary_param(proper_name, nam))
-                    && (proper_name != "SendGrid" || !is_sendgrid_unnecessary_param(nam))
-                    && (proper_name != "Slack" || !is_slack_unnecessary_param(nam))
-                    && (proper_name != "Okta" || !is_okta_unnecessary_param(nam))
-                    && (proper_name != "ShipBob" || !is_shipbob_unnecessary_param(nam))
-                    && (proper_name != "Stripe" || !is_stripe_unnecessary_param(nam))
-                {
+                } else if !is_hidden_param(config, item, nam, all_pages) {
                     if typ == "chrono::DateTime<chrono::Utc>" {
                         query_params.insert(
                             nam.to_string(),
@@ -782,6 +1575,37 @@ fn get_fn_params(
     Ok((fn_params_str, query_params))
 }
 
+/*
+ * Whether the schema of the parameter is an enum, rather than a reference to one.
+ */
+fn is_enum_param(parameter_data: &openapiv3::ParameterData) -> bool {
+    match &parameter_data.format {
+        openapiv3::ParameterSchemaOrContent::Schema(openapiv3::ReferenceOr::Item(s)) => matches!(
+            &s.schema_kind,
+            openapiv3::SchemaKind::Type(openapiv3::Type::String(st)) if !st.enumeration.is_empty()
+        ),
+        _ => false,
+    }
+}
+
+/*
+ * Encode the path parameters that are already strings as they are, rather than
+ * converting them into strings again.
+ */
+fn encode_str_path_params(template: String, fn_params_str: &[String]) -> String {
+    let mut template = template;
+    for p in fn_params_str {
+        if let Some(nam) = p.strip_suffix(": &str,") {
+            template = template.replace(
+                &format!("encode_path(&{}.to_string())", nam),
+                &format!("encode_path({})", nam),
+            );
+        }
+    }
+
+    template
+}
+
 /*
  * Perform the function.
  */
@@ -789,6 +1613,7 @@ fn get_fn_params(
 #[allow(clippy::too_many_arguments)]
 fn get_fn_inner(
     proper_name: &str,
+    config: &Config,
     oid: &str,
     m: &str,
     body_func: &Option<String>,
@@ -797,6 +1622,17 @@ fn get_fn_inner(
     pagination_property: &str,
     all_pages: bool,
 ) -> Result<String> {
+    if let Some(f) = &body_func {
+        if f == "form" {
+            // Form bodies need their own content type, so we send them directly.
+            return Ok(format!(
+                "self.client.request_form(reqwest::Method::{}, &url, \
+                 crate::utils::encode_form(body)?).await",
+                m
+            ));
+        }
+    }
+
     let body = if let Some(f) = &body_func {
         if f == "json" {
             "Some(reqwest::Body::from(serde_json::to_vec(body)?))"
@@ -807,81 +1643,41 @@ fn get_fn_inner(
         "None"
     };
 
+    if response_type == "crate::Download" {
+        return Ok(format!(
+            "self.client.request_download(reqwest::Method::{}, &url, {}).await",
+            m, body
+        ));
+    } else if response_type == "crate::DownloadStream" {
+        return Ok(format!(
+            "self.client.request_download_stream(reqwest::Method::{}, &url, {}).await",
+            m, body
+        ));
+    }
+
     if all_pages && pagination_property.is_empty() {
         return Ok(format!("self.client.get_all_pages(&url, {}).await", body));
-    } else if all_pages && proper_name.starts_with("Stripe") {
-        // We will do a custom function here.
-        let inner = format!(
-            r#"let mut resp: {} = self.client.{}(&url, {}).await?;
-
-            let mut {} = resp.{};
-            let mut has_more = resp.has_more;
-            let mut page = "".to_string();
-
-            // Paginate if we should.
-            while has_more {{
-                if !{}.is_empty() {{
-                   let last = {}.last().unwrap();
-                   let j = serde_json::json!(last);
-                   if let serde_json::Value::Object(o) = j {{
-                       if let Some(serde_json::Value::String(s)) = o.get("id") {{
-                            page = s.to_string();
-                       }}
-                   }}
-                }}
-
-                if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?startng_after={{}}", url, page), {}).await?;
-                }} else {{
-                    resp = self.client.{}(&format!("{{}}&starting_after={{}}", url, page), {}).await?;
-                }}
-
-
-                {}.append(&mut resp.{});
-
-                has_more = resp.has_more;
-            }}
-
-            // Return our response data.
-            Ok({}.to_vec())"#,
-            response_type,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-            m.to_lowercase(),
-            body,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            pagination_property,
-        );
-
-        return Ok(inner);
-    } else if all_pages && proper_name.starts_with("Google") {
+    } else if all_pages && config.pagination.style == PaginationStyle::PageToken {
         // We will do a custom function here.
         let inner = format!(
             r#"let mut resp: {} = self.client.{}(&url, {}).await?;
 
             let mut {} = resp.{};
-            let mut page = resp.next_page_token;
+            let mut page = resp.{};
 
             // Paginate if we should.
             while !page.is_empty() {{
+                // Check if we already have URL params and need to concat the token.
                 if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?pageToken={{}}", url, page), {}).await?;
+                    resp = self.client.{}(&format!("{{}}?{}={{}}", url, page), {}).await?;
                 }} else {{
-                    resp = self.client.{}(&format!("{{}}&pageToken={{}}", url, page), {}).await?;
+                    resp = self.client.{}(&format!("{{}}&{}={{}}", url, page), {}).await?;
                 }}
 
-
                 {}.append(&mut resp.{});
 
-                if !resp.next_page_token.is_empty() && resp.next_page_token != page {{
-                    page = resp.next_page_token.to_string();
+                if !resp.{}.is_empty() && resp.{} != page {{
+                    page = resp.{}.to_string();
                 }} else {{
                     page = "".to_string();
                 }}
@@ -894,23 +1690,29 @@ fn get_fn_inner(
             body,
             pagination_property,
             pagination_property,
+            config.pagination.next_cursor_field,
             m.to_lowercase(),
+            config.pagination.cursor_param,
             body,
             m.to_lowercase(),
+            config.pagination.cursor_param,
             body,
             pagination_property,
             pagination_property,
+            config.pagination.next_cursor_field,
+            config.pagination.next_cursor_field,
+            config.pagination.next_cursor_field,
             pagination_property,
         );
 
         return Ok(inner);
-    } else if all_pages && proper_name == "Ramp" {
+    } else if all_pages && config.pagination.style == PaginationStyle::NextUrl {
         // We will do a custom function here.
         let inner = format!(
             r#"let resp: {} = self.client.{}(&url, {}).await?;
 
             let mut {} = resp.{};
-            let mut page = resp.page.next.to_string();
+            let mut page = resp.{}.to_string();
 
             // Paginate if we should.
             while !page.is_empty() {{
@@ -918,19 +1720,16 @@ fn get_fn_inner(
                     Ok(mut resp) => {{
                         {}.append(&mut resp.{});
 
-                        page = if resp.page.next != page {{
-                            resp.page.next.to_string()
+                        page = if resp.{} != page {{
+                            resp.{}.to_string()
                         }} else {{
                         "".to_string()
                         }};
                     }},
-                    Err(e) => {{
-                        if e.to_string().contains("404 Not Found") {{
-                            page = "".to_string();
-                        }} else {{
-                            anyhow::bail!(e);
-                        }}
-                    }}
+                    Err(crate::Error::Http {{ status, .. }}) if status == http::StatusCode::NOT_FOUND => {{
+                        page = "".to_string();
+                    }},
+                    Err(e) => return Err(e),
                 }}
             }}
 
@@ -941,99 +1740,78 @@ fn get_fn_inner(
             body,
             pagination_property,
             pagination_property,
+            config.pagination.next_cursor_field,
             m.to_lowercase(),
             response_type,
             body,
             pagination_property,
             pagination_property,
+            config.pagination.next_cursor_field,
+            config.pagination.next_cursor_field,
             pagination_property,
         );
 
         return Ok(inner);
-    } else if all_pages && proper_name == "TripActions" {
+    } else if all_pages && config.pagination.style == PaginationStyle::PageNumber {
         // We will do a custom function here.
+        let first_page = format!(
+            "{}=0&{}={}",
+            config.pagination.cursor_param,
+            config.pagination.page_size_param,
+            config.pagination.page_size
+        );
+        let next_page = format!(
+            "{}={{}}&{}={}",
+            config.pagination.cursor_param,
+            config.pagination.page_size_param,
+            config.pagination.page_size
+        );
         let inner = format!(
             r#"
             let mut resp: {} = if !url.contains('?') {{
-                self.client.{}(&format!("{{}}?page=0&size=100", url), {}).await?
+                self.client.{}(&format!("{{}}?{}", url), {}).await?
             }} else {{
-                self.client.{}(&format!("{{}}&page=0&size=100", url), {}).await?
+                self.client.{}(&format!("{{}}&{}", url), {}).await?
             }};
 
             let mut {} = resp.{};
-            let mut page = resp.page.current_page + 1;
+            let mut page = resp.{} + 1;
 
             // Paginate if we should.
-            while page <= (resp.page.total_pages - 1) {{
+            while page <= (resp.{} - 1) {{
                 if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?page={{}}&size=100", url, page), {}).await?;
+                    resp = self.client.{}(&format!("{{}}?{}", url, page), {}).await?;
                 }} else {{
-                    resp = self.client.{}(&format!("{{}}&page={{}}&size=100", url, page), {}).await?;
+                    resp = self.client.{}(&format!("{{}}&{}", url, page), {}).await?;
                 }}
 
                 {}.append(&mut resp.{});
 
-                page = resp.page.current_page + 1;
+                page = resp.{} + 1;
             }}
 
             // Return our response data.
             Ok({})"#,
             response_type,
             m.to_lowercase(),
+            first_page,
             body,
             m.to_lowercase(),
+            first_page,
             body,
             pagination_property,
             pagination_property,
+            config.pagination.current_page_field,
+            config.pagination.total_pages_field,
             m.to_lowercase(),
+            next_page,
             body,
             m.to_lowercase(),
+            next_page,
             body,
             pagination_property,
             pagination_property,
-            pagination_property,
-        );
-
-        return Ok(inner);
-    } else if all_pages && proper_name == "Zoom" {
-        // We will do a custom function here.
-        let inner = format!(
-            r#"let mut resp: {} = self.client.{}(&url, {}).await?;
-
-            let mut {} = resp.{};
-            let mut page = resp.next_page_token;
-
-            // Paginate if we should.
-            while !page.is_empty() {{
-                // Check if we already have URL params and need to concat the token.
-                if !url.contains('?') {{
-                    resp = self.client.{}(&format!("{{}}?next_page_token={{}}", url, page), {}).await?;
-                }} else {{
-                    resp = self.client.{}(&format!("{{}}&next_page_token={{}}", url, page), {}).await?;
-                }}
-
-                {}.append(&mut resp.{});
-
-                if !resp.next_page_token.is_empty() && resp.next_page_token != page {{
-                    page = resp.next_page_token.to_string();
-                }} else {{
-                    page = "".to_string();
-                }}
-            }}
-
-            // Return our response data.
-            Ok({})"#,
-            response_type,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
-            m.to_lowercase(),
-            body,
-            m.to_lowercase(),
-            body,
-            pagination_property,
-            pagination_property,
+            config.pagination.current_page_field,
             pagination_property,
         );
 
@@ -1094,8 +1872,325 @@ fn get_fn_inner(
         .to_string())
 }
 
+/*
+ * Get the body of a function that streams the pages of a request. The closure
+ * fetches a single page given its cursor, or the first page if it is `None`.
+ */
+fn get_fn_stream_inner(
+    proper_name: &str,
+    config: &Config,
+    response_type: &str,
+    pagination_property: &str,
+    start_param: Option<(&str, &str)>,
+) -> Result<String> {
+    let start = match start_param {
+        Some((nam, typ)) if get_param_field_type(typ) == "String" => {
+            format!("let start = start.or_else(|| params.{}.clone());\n", nam)
+        }
+        Some((nam, _)) => format!(
+            "let start = start.or_else(|| params.{}.as_ref().map(|s| s.to_string()));\n",
+            nam
+        ),
+        None => String::new(),
+    };
+
+    let fetch = if pagination_property.is_empty() {
+        r#"let (link, items) = match page {
+                Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                None => client.get_pages(&url).await?,
+            };
+
+            Ok(crate::pagination::Page::new(
+                items,
+                link.as_ref().and_then(crate::utils::next_link),
+            ))"#
+        .to_string()
+    } else if config.pagination.style == PaginationStyle::PageToken {
+        format!(
+            r#"let url = match page {{
+                Some(page) => crate::pagination::with_query(&url, "{}", &page),
+                None => url,
+            }};
+            let resp: {} = client.get(&url, None).await?;
+
+            Ok(crate::pagination::Page::new(
+                resp.{},
+                Some(resp.{}).filter(|t| !t.is_empty()),
+            ))"#,
+            config.pagination.cursor_param,
+            response_type,
+            pagination_property,
+            config.pagination.next_cursor_field
+        )
+    } else if config.pagination.style == PaginationStyle::NextUrl {
+        format!(
+            r#"// The cursor of the next page is its full URL.
+            let url = match page {{
+                Some(page) => page.trim_start_matches(crate::DEFAULT_HOST).to_string(),
+                None => url,
+            }};
+
+            match client.get::<{}>(&url, None).await {{
+                Ok(resp) => Ok(crate::pagination::Page::new(
+                    resp.{},
+                    Some(resp.{}).filter(|n| !n.is_empty()),
+                )),
+                Err(crate::Error::Http {{ status, .. }}) if status == http::StatusCode::NOT_FOUND => {{
+                    Ok(crate::pagination::Page::new(Vec::new(), None))
+                }},
+                Err(e) => Err(e),
+            }}"#,
+            response_type, pagination_property, config.pagination.next_cursor_field
+        )
+    } else if config.pagination.style == PaginationStyle::PageNumber {
+        format!(
+            r#"// The cursor of the next page is its number.
+            let url = crate::pagination::with_query(&url, "{}", page.as_deref().unwrap_or("0"));
+            let url = crate::pagination::with_query(&url, "{}", "{}");
+            let resp: {} = client.get(&url, None).await?;
+
+            let next = resp.{} + 1;
+            Ok(crate::pagination::Page::new(
+                resp.{},
+                if next < resp.{} {{
+                    Some(next.to_string())
+                }} else {{
+                    None
+                }},
+            ))"#,
+            config.pagination.cursor_param,
+            config.pagination.page_size_param,
+            config.pagination.page_size,
+            response_type,
+            config.pagination.current_page_field,
+            pagination_property,
+            config.pagination.total_pages_field
+        )
+    } else {
+        bail!(
+            "must implement custom pagination function for {} {}",
+            proper_name,
+            pagination_property
+        );
+    };
+
+    Ok(format!(
+        r#"{}let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {{
+            let client = client.clone();
+            let url = url.clone();
+            async move {{
+                {}
+            }}
+        }})"#,
+        start, fetch
+    ))
+}
+
+/*
+ * Get the body of a function that fetches all the pages of a Stripe list at once,
+ * or that streams them, with the helpers of the `pagination` module.
+ */
+fn get_stripe_list_inner(
+    item_id: &str,
+    query_params: &BTreeMap<String, (String, String)>,
+    params_struct: &str,
+    stream: bool,
+) -> String {
+    // The page parameters come from the params struct, when the function takes one.
+    let param = |nam: &str| {
+        if params_struct.is_empty() || !query_params.contains_key(nam) {
+            "None".to_string()
+        } else if nam == "limit" {
+            format!("params.{}", nam)
+        } else {
+            format!("params.{}.as_deref()", nam)
+        }
+    };
+    let list_params = if params_struct.is_empty()
+        || !["ending_before", "limit", "page", "starting_after"]
+            .iter()
+            .any(|p| query_params.contains_key(*p))
+    {
+        "crate::pagination::ListParams::default()".to_string()
+    } else if query_params.contains_key("page") {
+        format!(
+            "crate::pagination::ListParams::search({}, {})",
+            param("page"),
+            param("limit")
+        )
+    } else {
+        format!(
+            "crate::pagination::ListParams::new({}, {}, {})",
+            param("starting_after"),
+            param("ending_before"),
+            param("limit")
+        )
+    };
+
+    if stream {
+        format!(
+            "crate::pagination::list(self.client.clone(), url, {}.resume(start), max_items, {})",
+            list_params, item_id
+        )
+    } else {
+        format!(
+            "crate::pagination::list_all(self.client.clone(), url, {}, {}).await",
+            list_params, item_id
+        )
+    }
+}
+
+/*
+ * Get the closure that returns the ID of an item of a Stripe list, that the page
+ * after it starts after. Items that are one of a few objects get theirs from each
+ * of the objects that has one.
+ */
+fn get_stripe_item_id(
+    ts: &TypeSpace,
+    tid: &crate::TypeId,
+    pagination_property: &str,
+    response_type: &str,
+) -> Result<String> {
+    let item_type = response_type
+        .trim_start_matches("Vec<")
+        .trim_end_matches('>');
+    let item = match get_property_type(ts, tid, pagination_property)
+        .and_then(|items| get_item_type(ts, &items))
+    {
+        Some(item) => item,
+        None => return Ok(format!("|_: &{}| None", item_type)),
+    };
+
+    if has_string_id(ts, &item)? {
+        return Ok(format!("|item: &{}| Some(item.id.clone())", item_type));
+    }
+
+    let variants = match ts.id_to_entry.get(&item).map(|te| &te.details) {
+        Some(crate::TypeDetails::OneOf(v, _)) | Some(crate::TypeDetails::AnyOf(v, _)) => v.clone(),
+        _ => Vec::new(),
+    };
+    let mut arms: Vec<String> = Default::default();
+    for v in &variants {
+        if has_string_id(ts, v)? {
+            let name = struct_name(&ts.render_type(v, true)?);
+            arms.push(format!("{}::{}(o) => Some(o.id.clone()),", item_type, name));
+        }
+    }
+    if arms.is_empty() {
+        return Ok(format!("|_: &{}| None", item_type));
+    }
+    if arms.len() < variants.len() {
+        arms.push("_ => None,".to_string());
+    }
+
+    Ok(format!(
+        "|item: &{}| match item {{ {} }}",
+        item_type,
+        arms.join(" ")
+    ))
+}
+
+fn has_string_id(ts: &TypeSpace, tid: &crate::TypeId) -> Result<bool> {
+    Ok(match get_property_type(ts, tid, "id") {
+        Some(id) => ts.render_type(&id, false)? == "String",
+        None => false,
+    })
+}
+
+fn get_property_type(ts: &TypeSpace, tid: &crate::TypeId, property: &str) -> Option<crate::TypeId> {
+    match &ts.id_to_entry.get(tid)?.details {
+        crate::TypeDetails::Object(properties, _) => properties.get(property).cloned(),
+        crate::TypeDetails::NamedType(t, _) | crate::TypeDetails::Optional(t, _) => {
+            get_property_type(ts, t, property)
+        }
+        _ => None,
+    }
+}
+
+fn get_item_type(ts: &TypeSpace, tid: &crate::TypeId) -> Option<crate::TypeId> {
+    match &ts.id_to_entry.get(tid)?.details {
+        crate::TypeDetails::Array(t, _) => Some(t.clone()),
+        crate::TypeDetails::NamedType(t, _) | crate::TypeDetails::Optional(t, _) => {
+            get_item_type(ts, t)
+        }
+        _ => None,
+    }
+}
+
+/*
+ * Stripe lists take their filters on ranges, like `created[gte]`, and on sets of
+ * values, like `ids[]` or `expand[]`, as `deepObject` query parameters. Get the ones
+ * we know how to send.
+ */
+fn get_stripe_list_params(o: &openapiv3::Operation) -> BTreeMap<String, (String, String)> {
+    let mut query_params: BTreeMap<String, (String, String)> = Default::default();
+    for par in &o.parameters {
+        let parameter_data = match par {
+            openapiv3::ReferenceOr::Item(openapiv3::Parameter::Query {
+                parameter_data,
+                allow_reserved: _,
+                style: openapiv3::QueryStyle::DeepObject,
+                allow_empty_value: _,
+            }) => parameter_data,
+            _ => continue,
+        };
+        let schema = match &parameter_data.format {
+            openapiv3::ParameterSchemaOrContent::Schema(openapiv3::ReferenceOr::Item(s)) => s,
+            _ => continue,
+        };
+
+        let mut nam = to_snake_case(&parameter_data.name);
+        if nam == "i_ds" {
+            nam = "ids".to_string();
+        }
+        match &schema.schema_kind {
+            openapiv3::SchemaKind::Type(openapiv3::Type::Array(at)) if is_string_items(at) => {
+                query_params.insert(
+                    nam,
+                    (
+                        "&[String]".to_string(),
+                        format!("{}[]", parameter_data.name),
+                    ),
+                );
+            }
+            openapiv3::SchemaKind::AnyOf { any_of } if any_of.iter().any(is_range_schema) => {
+                query_params.insert(
+                    nam,
+                    (
+                        "crate::pagination::RangeQuery".to_string(),
+                        parameter_data.name.to_string(),
+                    ),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    query_params
+}
+
+fn is_string_items(at: &openapiv3::ArrayType) -> bool {
+    matches!(
+        &at.items,
+        Some(openapiv3::ReferenceOr::Item(s))
+            if matches!(s.schema_kind, openapiv3::SchemaKind::Type(openapiv3::Type::String(_)))
+    )
+}
+
+fn is_range_schema(s: &openapiv3::ReferenceOr<openapiv3::Schema>) -> bool {
+    if let openapiv3::ReferenceOr::Item(s) = s {
+        if let openapiv3::SchemaKind::Type(openapiv3::Type::Object(o)) = &s.schema_kind {
+            return o.properties.contains_key("gte");
+        }
+    }
+
+    false
+}
+
 fn get_fn_docs(
     o: &openapiv3::Operation,
+    od: &str,
     m: &str,
     p: &str,
     parameters: &BTreeMap<String, &openapiv3::Parameter>,
@@ -1148,7 +2243,9 @@ fn get_fn_docs(
 
         let parameter_data = get_parameter_data(item).unwrap();
 
-        let pid = ts.select_param(None, par)?;
+        // The types of the parameters are named after the operation, as they were
+        // when we generated them.
+        let pid = ts.select_param(Some(&oid_to_object_name(od)), par)?;
         let mut docs = ts.render_docs(&pid);
         if let Some(d) = &parameter_data.description {
             if !d.is_empty() && d.len() > docs.len() {
@@ -1167,7 +2264,11 @@ fn get_fn_docs(
         }
 
         let nam = &to_snake_case(&clean_name(&parameter_data.name));
-        let typ = parameter_data.render_type(&param_name, ts)?;
+        let typ = if is_enum_param(parameter_data) {
+            ts.render_type(&pid, false)?
+        } else {
+            parameter_data.render_type(&param_name, ts)?
+        };
 
         if nam == "ref"
             || nam == "type"
@@ -1221,64 +2322,134 @@ fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) ->
     Ok(out.trim().to_string())
 }
 
-fn is_page_param(s: &str, proper_name: &str) -> bool {
-    s == "page"
-        || s == "per_page"
-        || s == "per"
-        || s == "page_size"
-        || s == "size"
-        || s == "next_page_token"
-        || s == "page_token"
-        || s == "max_results"
-        || s == "page_number"
-        || s == "start"
-        || s == "sync_token"
-        || s == "limit"
-        || s == "ending_before"
-        || (s == "after" && proper_name == "Okta")
-        || (s == "starting_after" && proper_name == "Stripe")
-}
+fn get_fn_docs_pages(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) -> Result<String> {
+    let mut out = String::new();
 
-fn is_google_unnecessary_param(proper_name: &str, s: &str) -> bool {
-    // These are either dumb or depreciated.
-    s == "access_token"
-        || s == "oauth_token"
-        || s == "pretty_print"
-        || s == "xgafv"
-        || s == "custom_field_mask"
-        || s == "sync_token"
-        || s == "user_ip"
-        || s == "quota_user"
-        || s == "key"
-        || s == "fields"
-        || s == "callback"
-        || s == "upload_protocol"
-        || s == "upload_type"
-        || s == "always_include_email"
-        || s == "enforce_single_parent"
-        || s == "corpus"
-        || (s == "alt" && proper_name != "Google Groups Settings")
-}
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    a("/**");
+    if let Some(summary) = &o.summary {
+        a(&format!("* {}.", summary.trim_end_matches('.')));
+        a("*");
+    }
+    a(&format!(
+        "* This function performs a `{}` to the `{}` endpoint.",
+        m, p
+    ));
+    a("*");
+    a(&format!(
+        "* As opposed to `{}`, this function returns a stream of the items that fetches the pages \
+         one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as \
+         `start` to resume it, and `max_items` to stop after that many items.",
+        fn_name
+    ));
+    if let Some(description) = &o.description {
+        a("*");
+        a(&format!("* {}", description.replace('\n', "\n* ")));
+    }
+    if let Some(external_docs) = &o.external_docs {
+        a("*");
+        a(&format!("* FROM: <{}>", external_docs.url));
+    }
+    a("*/");
 
-fn is_slack_unnecessary_param(s: &str) -> bool {
-    s == "token"
+    Ok(out.trim().to_string())
 }
 
-fn is_sendgrid_unnecessary_param(s: &str) -> bool {
-    s == "on_behalf_of" || s == "accept" || s == "x_query_id" || s == "x_cursor"
+fn get_fn_docs_stream(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) -> Result<String> {
+    let mut out = String::new();
+
+    let mut a = |s: &str| {
+        out.push_str(s);
+        out.push('\n');
+    };
+
+    a("/**");
+    if let Some(summary) = &o.summary {
+        a(&format!("* {}.", summary.trim_end_matches('.')));
+        a("*");
+    }
+    a(&format!(
+        "* This function performs a `{}` to the `{}` endpoint.",
+        m, p
+    ));
+    a("*");
+    a(&format!(
+        "* As opposed to `{}`, this function returns the body as a stream of chunks instead of \
+         reading it into memory at once.",
+        fn_name
+    ));
+    if let Some(description) = &o.description {
+        a("*");
+        a(&format!("* {}", description.replace('\n', "\n* ")));
+    }
+    if let Some(external_docs) = &o.external_docs {
+        a("*");
+        a(&format!("* FROM: <{}>", external_docs.url));
+    }
+    a("*/");
+
+    Ok(out.trim().to_string())
 }
 
-fn is_okta_unnecessary_param(s: &str) -> bool {
-    s == "okta_access_gateway_agent"
-        || s == "x_forwarded_for"
-        || s == "user_agent"
-        || s == "accept_language"
+/*
+ * Determine if the response for an operation is a file rather than JSON, ie. a PDF,
+ * an image or an archive. We return the raw bytes for these.
+ */
+fn is_download(
+    proper_name: &str,
+    p: &str,
+    status: &openapiv3::StatusCode,
+    r: &openapiv3::Response,
+) -> bool {
+    if r.content.is_empty() {
+        // GitHub redirects to the archive, the client follows the redirect for us
+        // so what we get back is the file.
+        if let openapiv3::StatusCode::Code(c) = status {
+            if *c == 302 {
+                return true;
+            }
+        }
+
+        // The Google Drive spec does not give a content type for exports, but
+        // the body is the file in the requested format.
+        return proper_name == "Google Drive" && p.ends_with("/export");
+    }
+
+    if r.content.keys().any(|ct| ct.contains("json")) {
+        return false;
+    }
+
+    r.content.keys().any(|ct| is_binary_media_type(ct))
 }
 
-fn is_shipbob_unnecessary_param(s: &str) -> bool {
-    s == "shipbob_channel_id"
+fn is_binary_media_type(ct: &str) -> bool {
+    ct == "application/octet-stream"
+        || ct == "application/pdf"
+        || ct == "application/zip"
+        || ct == "application/gzip"
+        || ct == "application/x-gzip"
+        || ct == "application/x-tar"
+        || ct.starts_with("image/")
+        || ct.starts_with("audio/")
+        || ct.starts_with("video/")
 }
 
-fn is_stripe_unnecessary_param(s: &str) -> bool {
-    s == "expand"
+/*
+ * Parameters the functions don't take: the credentials, which the client sends
+ * itself, the ones the config hides and, when we fetch all pages, the page ones.
+ */
+fn is_hidden_param(
+    config: &Config,
+    item: &openapiv3::Parameter,
+    nam: &str,
+    all_pages: bool,
+) -> bool {
+    (all_pages && config.is_page_param(nam))
+        || (nam == "authorization" && matches!(item, openapiv3::Parameter::Header { .. }))
+        || nam.starts_with("authorization_bearer")
+        || config.is_hidden_param(nam)
 }
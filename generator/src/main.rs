@@ -1,11 +1,17 @@
 mod client;
+mod config;
+mod error;
 mod functions;
+mod pagination;
+mod rate_limiter;
+mod retry;
 mod template;
+mod token_store;
 mod types;
 mod utils;
 
 use std::{
-    collections::{BTreeMap, HashSet},
+    collections::{BTreeMap, BTreeSet, HashSet},
     ffi::OsStr,
     fs::{File, OpenOptions},
     io::Write,
@@ -19,6 +25,8 @@ use inflector::cases::{
 use openapiv3::OpenAPI;
 use serde::Deserialize;
 
+use crate::config::{Auth, Config};
+
 fn save<P>(p: P, data: &str) -> Result<()>
 where
     P: AsRef<Path>,
@@ -534,6 +542,10 @@ pub enum TypeDetails {
     NamedType(TypeId, openapiv3::SchemaData),
     Enum(Vec<String>, openapiv3::SchemaData),
     Array(TypeId, openapiv3::SchemaData),
+    /*
+     * A free-form object, the values of its properties are of this type.
+     */
+    Map(TypeId, openapiv3::SchemaData),
     Optional(TypeId, openapiv3::SchemaData),
     /*
      * Object property names are sorted lexicographically to ensure a stable
@@ -582,19 +594,13 @@ impl TypeDetails {
         false
     }
 
-    pub fn is_named_type(&self) -> bool {
-        if let TypeDetails::NamedType(..) = self {
-            return true;
-        }
-        false
-    }
-
     pub fn description(&self) -> String {
         let desc = match self {
             TypeDetails::Basic(_, d) => d.description.as_ref(),
             TypeDetails::NamedType(_, d) => d.description.as_ref(),
             TypeDetails::Enum(_, d) => d.description.as_ref(),
             TypeDetails::Array(_, d) => d.description.as_ref(),
+            TypeDetails::Map(_, d) => d.description.as_ref(),
             TypeDetails::Optional(_, d) => d.description.as_ref(),
             TypeDetails::Object(_, d) => d.description.as_ref(),
             TypeDetails::OneOf(_, d) => d.description.as_ref(),
@@ -642,6 +648,11 @@ impl PartialEq for TypeDetails {
                     return i == oi;
                 }
             }
+            TypeDetails::Map(i, _d) => {
+                if let TypeDetails::Map(oi, _od) = other {
+                    return i == oi;
+                }
+            }
             TypeDetails::Optional(i, _d) => {
                 if let TypeDetails::Optional(oi, _od) = other {
                     return i == oi;
@@ -712,6 +723,12 @@ impl PartialEq for TypeId {
     }
 }
 
+/*
+ * The key, in the properties of an object, of the map of its additional properties.
+ * Properties can't have an empty name, so it doesn't collide with any of them.
+ */
+pub const ADDITIONAL_PROPERTIES: &str = "";
+
 #[derive(Debug, Clone)]
 pub struct TypeSpace {
     next_id: u64,
@@ -723,6 +740,29 @@ pub struct TypeSpace {
      */
     name_to_id: BTreeMap<String, TypeId>,
     id_to_entry: BTreeMap<TypeId, TypeEntry>,
+    /*
+     * The names of the types of the component schemas. They are kept for those
+     * types, even if we come across a type defined inline with the same name first.
+     */
+    schema_names: BTreeSet<String>,
+    /*
+     * The types of the request bodies of the operations.
+     */
+    request_bodies: BTreeSet<TypeId>,
+    /*
+     * The strings and numbers that are exact decimals.
+     */
+    decimal: crate::config::Decimal,
+    /*
+     * The types that the operations of each tag use, by tag.
+     */
+    tag_types: BTreeMap<String, BTreeSet<TypeId>>,
+    /*
+     * The type of each schema we selected, by the schema and the names we selected it
+     * with. The functions select the types of the operations again, which must give
+     * them the types we generated, not new ones named after the types that exist now.
+     */
+    selected: BTreeMap<String, TypeId>,
 }
 
 impl TypeSpace {
@@ -731,6 +771,11 @@ impl TypeSpace {
             next_id: 1,
             name_to_id: BTreeMap::new(),
             id_to_entry: BTreeMap::new(),
+            schema_names: BTreeSet::new(),
+            request_bodies: BTreeSet::new(),
+            decimal: Default::default(),
+            tag_types: BTreeMap::new(),
+            selected: BTreeMap::new(),
         }
     }
 
@@ -774,6 +819,7 @@ impl TypeSpace {
                      */
                     format!("array of {}", self.describe(itid))
                 }
+                TypeDetails::Map(itid, _) => format!("map of {}", self.describe(itid)),
                 TypeDetails::Optional(itid, _) => {
                     if let Some(ite) = self.id_to_entry.get(itid) {
                         if let Some(n) = &ite.name {
@@ -841,6 +887,7 @@ impl TypeSpace {
                 }
                 TypeDetails::Enum(_, schema_data) => Some(schema_data),
                 TypeDetails::Array(_, schema_data) => Some(schema_data),
+                TypeDetails::Map(_, schema_data) => Some(schema_data),
                 TypeDetails::Optional(id, schema_data) => {
                     let def: openapiv3::SchemaData = Default::default();
                     if def == *schema_data {
@@ -962,6 +1009,10 @@ impl TypeSpace {
                 TypeDetails::Array(itid, _) => {
                     Ok(format!("Vec<{}>", self.render_type(itid, in_mod)?))
                 }
+                TypeDetails::Map(itid, _) => Ok(format!(
+                    "std::collections::BTreeMap<String, {}>",
+                    self.render_type(itid, in_mod)?
+                )),
                 TypeDetails::Optional(itid, _) => {
                     let rt = self.render_type(itid, in_mod)?;
 
@@ -980,6 +1031,7 @@ impl TypeSpace {
                     if rt == "String"
                         || rt.starts_with("Vec<")
                         || rt.starts_with("std::collections::HashMap<")
+                        || rt.starts_with("std::collections::BTreeMap<")
                         || rt == "bool"
                         || rt == "i32"
                         || rt == "i64"
@@ -1022,6 +1074,21 @@ impl TypeSpace {
         }
     }
 
+    /*
+     * Whether we have a type for this ID, rather than only a reference to a type we
+     * never got, like the one of a response without a body.
+     */
+    fn has_type(&self, tid: &TypeId) -> bool {
+        match self.id_to_entry.get(tid) {
+            Some(TypeEntry {
+                details: TypeDetails::NamedType(itid, _),
+                ..
+            }) => self.has_type(itid),
+            Some(_) => true,
+            None => false,
+        }
+    }
+
     fn assign(&mut self) -> TypeId {
         let id = TypeId(self.next_id);
         self.next_id += 1;
@@ -1101,96 +1168,18 @@ impl TypeSpace {
          * We can have types that are references that are never explicitly called
          * but are duplicated all over. Let's ensure that we don't have a type with a different
          * name that is this exact same type.
+         * This is not the case for the types we generate code for: they keep the name
+         * they were given, otherwise which type an operation returns would depend on
+         * which other types happen to have the same fields.
          */
-        if !is_reference {
+        let generated = details.is_object()
+            || details.is_enum()
+            || details.is_one_of()
+            || details.is_any_of()
+            || details.is_all_of();
+        if !is_reference && !generated {
             for (tid, te) in self.id_to_entry.iter() {
                 if te.details == details {
-                    let id = tid.clone();
-
-                    // We have a match! Okay, now we want to keep the shorter
-                    // name of the two structs and ensure that is the one we have
-                    // in our set.
-                    // Only do this if we have an Enum or an Object.
-                    let existing_name = if let Some(n) = &te.name {
-                        n.to_string()
-                    } else {
-                        "".to_string()
-                    };
-                    let new_name = if let Some(n) = &name {
-                        n.to_string()
-                    } else {
-                        "".to_string()
-                    };
-                    if details.is_object()
-                        || details.is_enum()
-                        || details.is_one_of()
-                        || details.is_any_of()
-                        || details.is_all_of()
-                    {
-                        if existing_name == new_name {
-                            // Return early.
-                            return Ok(tid.clone());
-                        }
-
-                        if !new_name.is_empty() && new_name.len() < existing_name.len() {
-                            // Let's make sure we don't already have a type with this name.
-                            if let Some(nidd) = self.name_to_id.get(&new_name) {
-                                let nid = nidd.clone();
-
-                                // Well we already have an id for this type.
-                                // Let's get the details.
-                                let nt = self.id_to_entry.get(&nid).unwrap();
-
-                                // Make sure the type we found is not something
-                                // we care about.
-                                if (nt.details.is_enum()
-                                    || nt.details.is_object()
-                                    || nt.details.is_named_type()
-                                    || nt.details.is_one_of()
-                                    || details.is_any_of()
-                                    || details.is_all_of())
-                                    && nt.details != details
-                                {
-                                    // Return early we definitely don't want to do any funny business.
-                                    return Ok(tid.clone());
-                                }
-                                let ntdetails = nt.details.clone();
-
-                                // We have some other type...
-                                // Let's name it our old name.
-                                self.id_to_entry.insert(
-                                    nid.clone(),
-                                    TypeEntry {
-                                        id: nid.clone(),
-                                        name: Some(existing_name.to_string()),
-                                        details: ntdetails,
-                                    },
-                                );
-                                self.name_to_id.insert(existing_name, nid.clone());
-                            }
-
-                            // Let's keep the new_name instead.
-                            // This ensure's that since we can't always know the order in which we
-                            // parse types, that we will always have the cleaner set of named types.
-
-                            // Update the type entry in our set.
-                            self.id_to_entry.insert(
-                                id.clone(),
-                                TypeEntry {
-                                    id: id.clone(),
-                                    name: Some(new_name.to_string()),
-                                    details,
-                                },
-                            );
-                            self.name_to_id.insert(new_name, id.clone());
-
-                            // Remove the old name from the set.
-                            // TODO: somehow don't remove any of the ones we need to keep.
-                            // self.name_to_id.remove(&existing_name);
-                            return Ok(id);
-                        }
-                    }
-
                     return Ok(tid.clone());
                 }
             }
@@ -1203,69 +1192,74 @@ impl TypeSpace {
             /*
              * If there is already an entry for this type ID, ensure that it
              * matches the entry we have constructed.  If there is not yet an
-             * entry, we can just keep this one.
+             * entry, we can just keep this one, unless the name is that of a
+             * component schema we have not got to yet and this is a type nested in
+             * another.
              */
-            if let Some(et) = self.id_to_entry.get(&id) {
-                if et.details != details {
-                    // We can get here if there are two objects with the same name
-                    // that have properties that are different.
-                    if !parent_name.is_empty() {
-                        // We have a parent name, let's append it to the real name.
-                        let pname = format!("{} {}", parent_name, name);
-                        return self.add_if_not_exists(
-                            Some(clean_name(&pname)),
-                            details,
-                            "",
-                            is_reference,
-                        );
-                    }
-
-                    if !name.contains("data") {
-                        // Let's try to append "data" onto the end and see if that helps.
-                        let new_name = format!("{} data", name);
-                        return self.add_if_not_exists(
-                            Some(clean_name(&new_name)),
-                            details,
-                            "",
-                            is_reference,
-                        );
-                    } else if !name.contains("type") {
-                        // Let's try to append "type" onto the end and see if that helps.
-                        let new_name = format!("{} type", name);
-                        return self.add_if_not_exists(
-                            Some(clean_name(&new_name)),
-                            details,
-                            "",
-                            is_reference,
-                        );
-                    } else if !name.contains("links") {
-                        // Let's try to append "type" onto the end and see if that helps.
-                        let new_name = format!("{} links", name);
-                        return self.add_if_not_exists(
-                            Some(clean_name(&new_name)),
-                            details,
-                            "",
-                            is_reference,
-                        );
-                    } else if !name.contains("object") {
-                        // Let's try to append "type" onto the end and see if that helps.
-                        let new_name = format!("{} object", name);
-                        return self.add_if_not_exists(
-                            Some(clean_name(&new_name)),
-                            details,
-                            "",
-                            is_reference,
-                        );
-                    }
+            let taken = if let Some(et) = self.id_to_entry.get(&id) {
+                et.details != details
+            } else {
+                !parent_name.is_empty() && self.schema_names.contains(name)
+            };
+            if taken {
+                // We can get here if there are two objects with the same name
+                // that have properties that are different.
+                if !parent_name.is_empty() {
+                    // We have a parent name, let's append it to the real name.
+                    let pname = format!("{} {}", parent_name, name);
+                    return self.add_if_not_exists(
+                        Some(clean_name(&pname)),
+                        details,
+                        "",
+                        is_reference,
+                    );
+                }
 
-                    // If we don't have anything to append, let's bail.
-                    // WE ARE RUNNING OUT OF NAMES AND WE TRIED.
-                    bail!(
-                        "we ran out of unique names for this thing {}: {:?}",
-                        name,
+                if !name.contains("data") {
+                    // Let's try to append "data" onto the end and see if that helps.
+                    let new_name = format!("{} data", name);
+                    return self.add_if_not_exists(
+                        Some(clean_name(&new_name)),
+                        details,
+                        "",
+                        is_reference,
+                    );
+                } else if !name.contains("type") {
+                    // Let's try to append "type" onto the end and see if that helps.
+                    let new_name = format!("{} type", name);
+                    return self.add_if_not_exists(
+                        Some(clean_name(&new_name)),
+                        details,
+                        "",
+                        is_reference,
+                    );
+                } else if !name.contains("links") {
+                    // Let's try to append "type" onto the end and see if that helps.
+                    let new_name = format!("{} links", name);
+                    return self.add_if_not_exists(
+                        Some(clean_name(&new_name)),
+                        details,
+                        "",
+                        is_reference,
+                    );
+                } else if !name.contains("object") {
+                    // Let's try to append "type" onto the end and see if that helps.
+                    let new_name = format!("{} object", name);
+                    return self.add_if_not_exists(
+                        Some(clean_name(&new_name)),
                         details,
+                        "",
+                        is_reference,
                     );
                 }
+
+                // If we don't have anything to append, let's bail.
+                // WE ARE RUNNING OUT OF NAMES AND WE TRIED.
+                bail!(
+                    "we ran out of unique names for this thing {}: {:?}",
+                    name,
+                    details,
+                );
             }
 
             // We don't have an entry for this type ID so let's add it!
@@ -1399,10 +1393,79 @@ impl TypeSpace {
         parent_name: &str,
         additional_description: &str,
     ) -> Result<TypeId> {
+        let key = serde_json::to_string(&(name, parent_name, additional_description, s))?;
+        if let Some(id) = self.selected.get(&key) {
+            return Ok(id.clone());
+        }
+
         let (n, details) =
-            self.get_type_name_and_details(name, s, parent_name, additional_description)?;
+            self.get_type_name_and_details(name, s, parent_name, additional_description, false)?;
 
-        self.add_if_not_exists(n, details, parent_name, false)
+        let id = self.add_if_not_exists(n, details, parent_name, false)?;
+        self.selected.insert(key, id.clone());
+        Ok(id)
+    }
+
+    /*
+     * Select the type of a component schema. Unlike other types, which are named
+     * after their title if they have one, it is named after the schema.
+     */
+    fn select_component(
+        &mut self,
+        name: &str,
+        s: &openapiv3::ReferenceOr<openapiv3::Schema>,
+    ) -> Result<TypeId> {
+        match s {
+            openapiv3::ReferenceOr::Reference { reference } => {
+                self.select_ref(Some(name), reference.as_str())
+            }
+            openapiv3::ReferenceOr::Item(s) => {
+                let (n, details) = self.get_type_name_and_details(Some(name), s, "", "", true)?;
+
+                self.add_if_not_exists(n, details, "", false)
+            }
+        }
+    }
+
+    /// The map type of the additional properties of an object, if it allows any.
+    fn select_additional_properties(
+        &mut self,
+        name: &str,
+        o: &openapiv3::ObjectType,
+        schema_data: &openapiv3::SchemaData,
+    ) -> Result<Option<TypeId>> {
+        let vid = match &o.additional_properties {
+            Some(openapiv3::AdditionalProperties::Any(true)) => self.add_if_not_exists(
+                None,
+                TypeDetails::Basic("serde_json::Value".to_string(), Default::default()),
+                "",
+                false,
+            )?,
+            Some(openapiv3::AdditionalProperties::Schema(ad)) => {
+                let desc = if let Some(ref d) = schema_data.description {
+                    d.to_string()
+                } else {
+                    "".to_string()
+                };
+
+                // If this name already exists add additional properties to it.
+                let mut name = name.to_string();
+                if self.name_to_id.get(&clean_name(&name)).is_some() {
+                    name = format!("{} additional properties", name);
+                }
+                // The type may be a reference to a schema we have not got to yet, so
+                // we can't render it until we have all of them.
+                self.select(Some(&name), ad, &desc)?
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(self.add_if_not_exists(
+            None,
+            TypeDetails::Map(vid, Default::default()),
+            "",
+            false,
+        )?))
     }
 
     fn get_type_name_and_details(
@@ -1411,6 +1474,7 @@ impl TypeSpace {
         sc: &openapiv3::Schema,
         parent_name: &str,
         additional_description: &str,
+        keep_name: bool,
     ) -> Result<(Option<String>, TypeDetails)> {
         let nam = if let Some(n) = name {
             n.to_string()
@@ -1476,11 +1540,12 @@ impl TypeSpace {
                 }
                 openapiv3::Type::Object(o) => {
                     // Object types must have a consistent name.
-                    let mut name = clean_name(match (name, s.schema_data.title.as_deref()) {
+                    let name = clean_name(match (name, s.schema_data.title.as_deref()) {
                         (Some(n), None) => n,
                         (Some(n), Some("")) => n,
                         (None, Some(t)) => t,
                         (Some(""), Some(t)) => t,
+                        (Some(n), Some(_)) if keep_name => n,
                         (Some(n), Some(t)) => {
                             // Check if we already have a type with this name.
                             if n == t
@@ -1508,39 +1573,12 @@ impl TypeSpace {
                         }
                     });
 
-                    // TODO: this is a horrible fix just for google!
-                    if name == "user custom properties" {
-                        return Ok((
-                            Some(name.to_string()),
-                            TypeDetails::Basic(
-                                "std::collections::HashMap<String, \
-                                 std::collections::HashMap<String, serde_json::Value>>"
-                                    .to_string(),
-                                s.schema_data.clone(),
-                            ),
-                        ));
-                    }
-
+                    // A free-form object is a map of its additional properties.
+                    let additional_properties =
+                        self.select_additional_properties(&name, o, &s.schema_data)?;
                     if o.properties.is_empty() {
-                        // TODO: make this work for when there is both.
-                        if let Some(openapiv3::AdditionalProperties::Schema(ad)) =
-                            &o.additional_properties
-                        {
-                            let desc = if let Some(ref d) = s.schema_data.description {
-                                d.to_string()
-                            } else {
-                                "".to_string()
-                            };
-
-                            // If this name already exists add additional properties to it.
-                            if self.name_to_id.get(&clean_name(&name)).is_some() {
-                                name = format!("{} additional properties", name);
-                            }
-                            let id = self.select(Some(&name), ad, &desc)?;
-                            return Ok((
-                                Some(name.to_string()),
-                                TypeDetails::NamedType(id, s.schema_data.clone()),
-                            ));
+                        if let Some(mid) = additional_properties {
+                            return Ok((None, TypeDetails::NamedType(mid, s.schema_data.clone())));
                         }
                     }
 
@@ -1606,6 +1644,13 @@ impl TypeSpace {
                             );
                         }
                     }
+
+                    // The additional properties of an object that also has fixed ones
+                    // are flattened into it.
+                    if let Some(mid) = additional_properties {
+                        omap.insert(ADDITIONAL_PROPERTIES.to_string(), mid);
+                    }
+
                     Ok((Some(name), TypeDetails::Object(omap, s.schema_data.clone())))
                 }
                 openapiv3::Type::String(st) => {
@@ -1622,6 +1667,7 @@ impl TypeSpace {
                             (Some(n), Some("")) => n,
                             (None, Some(t)) => t,
                             (Some(""), Some(t)) => t,
+                            (Some(n), Some(_)) if keep_name => n,
                             (Some(n), Some(t)) => {
                                 // Check if we already have a type with this name.
                                 if n == t
@@ -1658,6 +1704,22 @@ impl TypeSpace {
                         }
                     }
 
+                    // Exact decimals, like amounts of money, sent as strings.
+                    let decimal = match &st.format {
+                        Item(_) => false,
+                        Unknown(f) => self.decimal.is_decimal(f, &nam),
+                        Empty => self.decimal.is_decimal("", &nam),
+                    };
+                    if decimal {
+                        return Ok((
+                            Some(uid.to_string()),
+                            TypeDetails::Basic(
+                                "crate::types::DecimalString".to_string(),
+                                s.schema_data.clone(),
+                            ),
+                        ));
+                    }
+
                     match &st.format {
                         // It is far too risky to not make all the DateTime/Dates optional
                         // otherwise you are just risking a panic when the vendor passes back
@@ -1811,10 +1873,22 @@ impl TypeSpace {
                     Some(uid.to_string()),
                     TypeDetails::Basic("bool".to_string(), s.schema_data.clone()),
                 )),
-                openapiv3::Type::Number(_) => Ok((
-                    Some(uid.to_string()),
-                    TypeDetails::Basic("f64".to_string(), s.schema_data.clone()),
-                )),
+                openapiv3::Type::Number(nt) => {
+                    // Exact decimals, like amounts of money, sent as numbers.
+                    let format = match &nt.format {
+                        openapiv3::VariantOrUnknownOrEmpty::Unknown(f) => f.as_str(),
+                        _ => "",
+                    };
+                    let rt = if self.decimal.is_decimal(format, &nam) {
+                        "crate::types::DecimalNumber"
+                    } else {
+                        "f64"
+                    };
+                    Ok((
+                        Some(uid.to_string()),
+                        TypeDetails::Basic(rt.to_string(), s.schema_data.clone()),
+                    ))
+                }
                 openapiv3::Type::Integer(_) => Ok((
                     Some(uid.to_string()),
                     TypeDetails::Basic("i64".to_string(), s.schema_data.clone()),
@@ -1913,10 +1987,13 @@ impl TypeSpace {
                 omap.sort_unstable();
                 omap.dedup();
 
-                Ok((
-                    Some(one_of_name),
-                    TypeDetails::OneOf(omap, Default::default()),
-                ))
+                // The discriminator tells the types apart when we render the enum.
+                let schema_data = openapiv3::SchemaData {
+                    discriminator: s.schema_data.discriminator.clone(),
+                    ..Default::default()
+                };
+
+                Ok((Some(one_of_name), TypeDetails::OneOf(omap, schema_data)))
             }
             openapiv3::SchemaKind::AnyOf { any_of } => {
                 // TODO: This is a stop gap for now, we should figure out a better solution later.
@@ -1963,10 +2040,13 @@ impl TypeSpace {
                 omap.sort_unstable();
                 omap.dedup();
 
-                Ok((
-                    Some(any_of_name),
-                    TypeDetails::AnyOf(omap, Default::default()),
-                ))
+                // The discriminator tells the types apart when we render the enum.
+                let schema_data = openapiv3::SchemaData {
+                    discriminator: s.schema_data.discriminator.clone(),
+                    ..Default::default()
+                };
+
+                Ok((Some(any_of_name), TypeDetails::AnyOf(omap, schema_data)))
             }
             openapiv3::SchemaKind::Any(any) => {
                 // There is at least one occurance where the github api spec gives "items"
@@ -1997,6 +2077,7 @@ impl TypeSpace {
                         (Some(n), Some("")) => n,
                         (None, Some(t)) => t,
                         (Some(""), Some(t)) => t,
+                        (Some(n), Some(_)) if keep_name => n,
                         (Some(n), Some(t)) => {
                             // Check if we already have a type with this name.
                             if n == t
@@ -2137,10 +2218,22 @@ fn get_parameter_data(param: &openapiv3::Parameter) -> Option<&openapiv3::Parame
     }
 }
 
+/*
+ * The name of the variant of an enum value. The name "Unknown" is taken by the variant
+ * that holds any value we don't know about.
+ */
+fn enum_variant_name(e: &str) -> String {
+    let n = struct_name(e);
+    if n == "Unknown" {
+        "UnknownValue".to_string()
+    } else {
+        n
+    }
+}
+
 fn render_param(
     sn: &str,
     en: &[String],
-    required: bool,
     description: &str,
     default: Option<&serde_json::Value>,
 ) -> String {
@@ -2168,6 +2261,10 @@ fn render_param(
         {
             continue;
         }
+        if struct_name(e).is_empty() {
+            // TODO: do something for empty(?)
+            continue;
+        }
         enums.push(e.to_string());
     }
 
@@ -2177,82 +2274,96 @@ fn render_param(
         a("*/");
     }
 
+    // We go through strings so values we don't know about end up in `Unknown` and are
+    // sent back as they came.
     a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
+    a(r#"#[serde(from = "String", into = "String")]"#);
 
     a(&format!("pub enum {} {{", sn));
     for e in &enums {
-        if struct_name(e).is_empty() {
-            // TODO: do something for empty(?)
-            continue;
-        }
         a(&format!(r#"#[serde(rename = "{}")]"#, e));
-        a(&format!("{},", struct_name(e)));
-    }
-    if !required && default.is_none() {
-        a(r#"#[serde(rename = "")]"#);
-        a("Noop,");
+        a(&format!("{},", enum_variant_name(e)));
     }
+    a("/**");
+    a("* A value that is not in the API spec, as it was sent.");
+    a("*/");
+    a("Unknown(String),");
+    a("}");
+    a("");
 
-    // Let's add the wildcard.
-    a("#[serde(other)]");
-    a("FallthroughString");
-
+    a(&format!("impl AsRef<str> for {} {{", sn));
+    a("fn as_ref(&self) -> &str {");
+    a("match self {");
+    for e in &enums {
+        a(&format!(r#"{}::{} => "{}","#, sn, enum_variant_name(e), e));
+    }
+    a(&format!("{}::Unknown(s) => s,", sn));
+    a("}");
+    a("}");
     a("}");
     a("");
 
     a(&format!("impl std::fmt::Display for {} {{", sn));
     a(r#"fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {"#);
-    a(r#"match &*self {"#);
-    for e in &enums {
-        if struct_name(e).is_empty() {
-            // TODO: do something for empty(?)
-            continue;
+    a("self.as_ref().fmt(f)");
+    a("}");
+    a("}");
+    a("");
+
+    a(&format!("impl std::str::FromStr for {} {{", sn));
+    a("type Err = std::convert::Infallible;");
+    a("");
+    a("fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {");
+    if enums.is_empty() {
+        a(&format!("Ok({}::Unknown(s.to_string()))", sn));
+    } else {
+        a("Ok(match s {");
+        for e in &enums {
+            a(&format!(r#""{}" => {}::{},"#, e, sn, enum_variant_name(e)));
         }
-        a(&format!(r#"{}::{} => "{}","#, sn, struct_name(e), e));
-    }
-    if !required && default.is_none() {
-        a(&format!(r#"{}::Noop => "","#, sn));
+        a(&format!("_ => {}::Unknown(s.to_string()),", sn));
+        a("})");
     }
+    a("}");
+    a("}");
+    a("");
 
-    // Let's add the display format for the wildcard.
-    a(&format!(r#"{}::FallthroughString => "*","#, sn));
+    a(&format!("impl std::convert::From<String> for {} {{", sn));
+    a("fn from(s: String) -> Self {");
+    a("match s.parse() {");
+    a("Ok(v) => v,");
+    a("Err(e) => match e {},");
+    a("}");
+    a("}");
+    a("}");
+    a("");
 
+    a(&format!("impl std::convert::From<{}> for String {{", sn));
+    a(&format!("fn from(v: {}) -> Self {{", sn));
+    a("match v {");
+    a(&format!("{}::Unknown(s) => s,", sn));
+    // An enum of only empty values has no other variant.
+    if !enums.is_empty() {
+        a("v => v.as_ref().to_string(),");
+    }
     a("}");
-    a(".fmt(f)");
     a("}");
     a("}");
     a("");
 
-    // Add a default for the enum if it is not required.
-    if !required || default.is_some() {
+    // Use the default that can be passed to the OpenAPI, github is not using that
+    // currently for everything but we might want to in the future.
+    // Fields of enums without a default are options instead.
+    if let Some(d) = default {
         a(&format!("impl Default for {} {{", sn));
         a(&format!("fn default() -> {} {{", sn));
-        if let Some(d) = default {
-            // Use the default that can be passed to the OpenAPI,
-            // github is not using that currently for everything but we might want to
-            // in the future.
-            a(&format!(
-                "{}::{}",
-                sn,
-                struct_name(&d.to_string().replace('"', ""))
-            ));
-        } else {
-            a(&format!("{}::Noop", sn));
-        }
-        a("}");
-        a("}");
-    }
-
-    // Add a method to check if it is empty if it has this Noop state.
-    if !required && default.is_none() {
         a(&format!(
-            r#"impl {} {{
-            pub fn is_noop(&self) -> bool {{
-                matches!(self, {}::Noop)
-            }}
-    }}"#,
-            sn, sn
+            "{}::{}",
+            sn,
+            enum_variant_name(&d.to_string().replace('"', ""))
         ));
+        a("}");
+        a("}");
         a("");
     }
 
@@ -2264,9 +2375,7 @@ fn gen(
     proper_name: &str,
     host: &str,
     tags: Vec<String>,
-    token_endpoint: &str,
-    user_consent_endpoint: &str,
-    add_post_header: &str,
+    config: &Config,
 ) -> Result<String> {
     let mut out = String::new();
 
@@ -2281,11 +2390,13 @@ fn gen(
     a("#![allow(clippy::too_many_arguments)]");
     a("#![allow(clippy::nonstandard_macro_braces)]");
     a("#![allow(clippy::large_enum_variant)]");
+    // The errors keep the status, headers and body of the response.
+    a("#![allow(clippy::result_large_err)]");
     a("#![allow(clippy::tabs_in_doc_comments)]");
     a("#![allow(missing_docs)]"); // TODO: Make this a deny.
     a("#![cfg_attr(docsrs, feature(doc_cfg))]");
     a("");
-    if proper_name == "GitHub" {
+    if config.auth == Auth::GitHub {
         a("pub mod auth;");
         a(r#"#[cfg(feature = "httpcache")]"#);
         a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]"#);
@@ -2296,11 +2407,21 @@ fn gen(
         || proper_name == "SendGrid"
         || proper_name == "Rev.ai"
     {
+        // The traits use the functions of several tags.
+        a(r#"#[cfg(feature = "full")]"#);
+        a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "full")))]"#);
         a("pub mod traits;");
     }
+    a("mod error;");
     a("#[cfg(test)]");
     a("mod tests;");
     // Hopefully there is never a "tag" named after these reserved libs.
+    a("pub mod pagination;");
+    a("pub mod rate_limiter;");
+    a("pub mod retry;");
+    if config.auth == Auth::OAuth2 {
+        a("pub mod token_store;");
+    }
     a("pub mod types;");
     a("#[doc(hidden)]");
     a("pub mod utils;");
@@ -2310,9 +2431,7 @@ fn gen(
      * Tags are how functions are grouped.
      */
     for tag in api.tags.iter() {
-        if !tags.contains(&to_snake_case(&clean_name(&tag.name)))
-            && (proper_name == "Zoom" || proper_name == "DocuSign")
-        {
+        if !tags.contains(&to_snake_case(&clean_name(&tag.name))) && config.skip_empty_tags {
             // Return early do nothing!
             // This fixes Zoom and DocuSign where they list tags that have no associated functions.
             continue;
@@ -2332,31 +2451,43 @@ fn gen(
         if !docs.is_empty() {
             a(&format!("/// {}", docs.replace('\n', "\n///"),));
         }
+        let module = to_snake_case(&clean_name(&tag.name));
+        a(&format!(r#"#[cfg(feature = "{}")]"#, module));
         a(&format!(
-            "pub mod {};",
-            to_snake_case(&clean_name(&tag.name))
+            r#"#[cfg_attr(docsrs, doc(cfg(feature = "{}")))]"#,
+            module
         ));
+        a(&format!("pub mod {};", module));
     }
     if api.tags.is_empty() {
         // If the spec didn't call out tags explicitly, we need to use the
         // ones we found ourselves.
         for tag in tags.iter() {
             if !tag.is_empty() {
-                a(&format!("pub mod {};", to_snake_case(&clean_name(tag))));
+                let module = to_snake_case(&clean_name(tag));
+                a(&format!(r#"#[cfg(feature = "{}")]"#, module));
+                a(&format!(
+                    r#"#[cfg_attr(docsrs, doc(cfg(feature = "{}")))]"#,
+                    module
+                ));
+                a(&format!("pub mod {};", module));
             }
         }
     }
 
     a("");
 
-    a("use anyhow::{anyhow, Error, Result};");
+    a("pub use error::{Error, ErrorBody, Result};");
     a("");
 
-    a(&format!(
-        r#"pub const DEFAULT_HOST: &str = "https://{}";"#,
-        host.trim_start_matches("https://")
-    ));
-    a("");
+    // Okta has no host of its own, each org has one.
+    if config.auth != Auth::Okta {
+        a(&format!(
+            r#"pub const DEFAULT_HOST: &str = "https://{}";"#,
+            host.trim_start_matches("https://")
+        ));
+        a("");
+    }
 
     a("mod progenitor_support {");
     a("    use percent_encoding::{AsciiSet, CONTROLS, utf8_percent_encode};");
@@ -2392,35 +2523,26 @@ fn gen(
     a("}");
     a("");
 
+    a(crate::client::DOWNLOAD_TEMPLATE);
+    a("");
+
+    a(crate::client::CLIENT_BUILDER_TEMPLATE);
     a("");
 
     // Print the client template.
-    if proper_name == "GitHub" {
-        a(crate::client::GITHUB_TEMPLATE);
-    } else if proper_name == "SendGrid"
-        || proper_name == "Giphy"
-        || proper_name == "Rev.ai"
-        || proper_name == "Okta"
-        || proper_name == "ShipBob"
-        || proper_name == "Stripe"
-    {
-        a(&crate::client::generate_client_generic_api_key(
-            proper_name,
-            add_post_header,
-        ));
-    } else if proper_name == "TripActions" {
-        a(&crate::client::generate_client_generic_client_credentials(
-            proper_name,
-            token_endpoint,
-            add_post_header,
-        ));
+    let client = match config.auth {
+        Auth::GitHub => crate::client::GITHUB_TEMPLATE.to_string(),
+        Auth::ApiKey => crate::client::generate_client_generic_api_key(proper_name, config),
+        Auth::Okta => crate::client::generate_client_okta(config),
+        Auth::ClientCredentials => {
+            crate::client::generate_client_generic_client_credentials(proper_name, config)
+        }
+        Auth::OAuth2 => crate::client::generate_client_generic_token(proper_name, config),
+    };
+    if config.idempotency_key.is_some() && config.auth != Auth::GitHub {
+        a(&crate::client::add_idempotency_key(&client));
     } else {
-        a(&crate::client::generate_client_generic_token(
-            proper_name,
-            token_endpoint,
-            user_consent_endpoint,
-            add_post_header,
-        ));
+        a(&client);
     }
 
     a("");
@@ -2430,9 +2552,7 @@ fn gen(
      * Tags are how functions are grouped.
      */
     for tag in api.tags.iter() {
-        if !tags.contains(&to_snake_case(&tag.name))
-            && (proper_name == "Zoom" || proper_name == "DocuSign")
-        {
+        if !tags.contains(&to_snake_case(&tag.name)) && config.skip_empty_tags {
             // Return early do nothing!
             // This fixes Zoom and DocuSign where they list tags that have no associated functions.
             continue;
@@ -2453,12 +2573,16 @@ fn gen(
 
         a(&format!(
             r#"/// {}
+               #[cfg(feature = "{}")]
+               #[cfg_attr(docsrs, doc(cfg(feature = "{}")))]
                pub fn {}(&self) -> {}::{} {{
                     {}::{}::new(self.clone())
                }}"#,
             docs.replace('\n', "\n///"),
             to_snake_case(&clean_name(&tag.name)),
             to_snake_case(&clean_name(&tag.name)),
+            to_snake_case(&clean_name(&tag.name)),
+            to_snake_case(&clean_name(&tag.name)),
             struct_name(&tag.name),
             to_snake_case(&clean_name(&tag.name)),
             struct_name(&tag.name),
@@ -2471,9 +2595,13 @@ fn gen(
         for tag in tags.iter() {
             if !tag.is_empty() {
                 a(&format!(
-                    r#"pub fn {}(&self) -> {}::{} {{
+                    r#"#[cfg(feature = "{}")]
+               #[cfg_attr(docsrs, doc(cfg(feature = "{}")))]
+               pub fn {}(&self) -> {}::{} {{
                     {}::{}::new(self.clone())
                }}"#,
+                    to_snake_case(&clean_name(tag)),
+                    to_snake_case(&clean_name(tag)),
                     to_snake_case(&clean_name(tag)),
                     to_snake_case(&clean_name(tag)),
                     struct_name(tag),
@@ -2490,6 +2618,46 @@ fn gen(
     Ok(out)
 }
 
+/*
+ * The modules of the tags, which are the cargo features of the crate too.
+ */
+fn tag_modules(api: &OpenAPI, tags: &[String], config: &Config) -> Vec<String> {
+    if api.tags.is_empty() {
+        // If the spec didn't call out tags explicitly, we use the ones we found
+        // ourselves.
+        return tags
+            .iter()
+            .filter(|t| !t.is_empty())
+            .map(|t| to_snake_case(&clean_name(t)))
+            .collect();
+    }
+
+    api.tags
+        .iter()
+        .map(|t| to_snake_case(&clean_name(&t.name)))
+        .filter(|t| tags.contains(t) || !config.skip_empty_tags)
+        .collect()
+}
+
+/*
+ * The features of the crates that are not tags.
+ */
+const RESERVED_FEATURES: &[&str] = &[
+    "decimal",
+    "default",
+    "dirs",
+    "full",
+    "httpcache",
+    "mock",
+    "mockall",
+    "native-tls",
+    "openssl",
+    "pem",
+    "ring",
+    "rust_decimal",
+    "rustls-tls",
+];
+
 pub fn make_plural(proper_name: &str, s: &str) -> String {
     // Only fix the ramp names.
     if proper_name != "Ramp" && proper_name != "Okta" {
@@ -2816,6 +2984,17 @@ fn main() -> Result<()> {
         "A header to add to post requests",
         "ADD_POST_HEADER",
     );
+    opts.optopt(
+        "",
+        "config",
+        "Generator settings for the API (TOML)",
+        "CONFIG",
+    );
+    opts.optflag(
+        "",
+        "allow-renames",
+        "Allow the types listed in type-names.json to be renamed",
+    );
     opts.optflag("", "debug", "Print debug output");
 
     let args = match opts.parse(std::env::args().skip(1)) {
@@ -2834,6 +3013,22 @@ fn main() -> Result<()> {
 
     let api = load_api(&args.opt_str("i").unwrap())?;
 
+    let mut config = if let Some(c) = args.opt_str("config") {
+        Config::load(c)?
+    } else {
+        Config::default()
+    };
+    // The flags take precedence over the config file.
+    if let Some(te) = args.opt_str("token-endpoint") {
+        config.token_endpoint = te;
+    }
+    if let Some(uce) = args.opt_str("user-consent-endpoint") {
+        config.user_consent_endpoint = uce;
+    }
+    if let Some(ph) = args.opt_str("add-post-header") {
+        config.post_header = ph;
+    }
+
     let debug = |s: &str| {
         if args.opt_present("debug") {
             println!("{}", s);
@@ -2844,12 +3039,21 @@ fn main() -> Result<()> {
      * Grab all the types defined by schemas and parameters.
      */
     let mut ts = TypeSpace::new();
+    ts.decimal = config.decimal.clone();
     let mut parameters: BTreeMap<String, &openapiv3::Parameter> = BTreeMap::new();
 
+    // The types of the component schemas, the request and response bodies, by where
+    // they are in the spec. Written to the type-names.json of the crate.
+    let mut type_names: BTreeMap<String, TypeId> = BTreeMap::new();
+
     if let Some(components) = &api.components {
+        for sn in components.schemas.keys() {
+            ts.schema_names.insert(clean_name(config.type_name(sn)));
+        }
+
         // Populate a type to describe each entry in the schemas section.
         for (i, (sn, s)) in components.schemas.iter().enumerate() {
-            let name = clean_name(sn);
+            let name = clean_name(config.type_name(sn));
             debug(&format!(
                 "SCHEMA {}/{}: {}",
                 i + 1,
@@ -2857,13 +3061,14 @@ fn main() -> Result<()> {
                 name
             ));
 
-            let id = ts.select(Some(name.as_str()), s, "")?;
+            let id = ts.select_component(&name, s)?;
             debug(&format!("    -> {:?}", id));
             debug("");
 
             // Insert the named type for our reference.
             // DO NOT CLEAN THE NAME HERE.
-            ts.populate_ref(Some(sn.as_str()), Some(id), "schema")?;
+            ts.populate_ref(Some(sn.as_str()), Some(id.clone()), "schema")?;
+            type_names.insert(format!("#/components/schemas/{}", sn), id);
         }
 
         // Populate a type to describe each entry in the parameters section.
@@ -2917,6 +3122,7 @@ fn main() -> Result<()> {
                         // Insert the named type for our reference.
                         // DO NOT CLEAN THE NAME HERE.
                         ts.populate_ref(Some(rn.as_str()), Some(id.clone()), "requestBodies")?;
+                        type_names.insert(format!("#/components/requestBodies/{}", rn), id.clone());
 
                         debug(&format!("    -> {:?}", id));
                         debug("");
@@ -2965,6 +3171,7 @@ fn main() -> Result<()> {
                         // Insert the named type for our reference.
                         // DO NOT CLEAN THE NAME HERE.
                         ts.populate_ref(Some(rn.as_str()), Some(id.clone()), "response")?;
+                        type_names.insert(format!("#/components/responses/{}", rn), id.clone());
 
                         debug(&format!("    -> {:?}", id));
                         debug("");
@@ -2991,15 +3198,16 @@ fn main() -> Result<()> {
         /*
          * Get the request parameters, those might have lingering enums.
          */
+        let mut path_params: Vec<TypeId> = Default::default();
         for par in op.parameters.iter() {
             // The name will be filled in by the parameter data.
-            ts.select_param(None, par)?;
+            path_params.push(ts.select_param(None, par)?);
         }
 
-        let grab = |pn: &str,
-                    m: &str,
-                    o: Option<&openapiv3::Operation>,
-                    ts: &mut TypeSpace|
+        let mut grab = |pn: &str,
+                        m: &str,
+                        o: Option<&openapiv3::Operation>,
+                        ts: &mut TypeSpace|
          -> Result<String> {
             if let Some(o) = o {
                 let op_id = if o.operation_id.is_none() {
@@ -3047,18 +3255,24 @@ fn main() -> Result<()> {
                 debug("");
                 debug(&oid);
 
+                // The types this operation uses, which the feature of its tag needs.
+                let mut used = path_params.clone();
+
                 /*
                  * Get the request body type, if this operation has one.
                  */
                 let mut req: Vec<String> = Default::default();
                 if let Some(openapiv3::ReferenceOr::Item(body)) = &o.request_body {
                     for (ct, mt) in &body.content {
-                        if ct == "application/json" {
+                        if ct == "application/json" || ct == "application/x-www-form-urlencoded" {
                             if let Some(s) = &mt.schema {
                                 let object_name = format!("{} request", oid_to_object_name(&od));
                                 let id = ts.select(Some(&object_name), s, "")?;
                                 let rt = ts.render_type(&id, true)?;
                                 req.push(format!("{} {:?}", rt, id));
+                                used.push(id.clone());
+                                ts.request_bodies.insert(id.clone());
+                                type_names.insert(format!("{} {} request", m, pn), id);
                             }
                         } else {
                             req.push(ct.to_string());
@@ -3069,6 +3283,9 @@ fn main() -> Result<()> {
                 {
                     let id = ts.select_ref(None, reference.as_str())?;
                     req.push(format!("{:?}", id));
+                    used.push(id.clone());
+                    ts.request_bodies.insert(id.clone());
+                    type_names.insert(format!("{} {} request", m, pn), id);
                 }
                 if !req.is_empty() {
                     debug(&format!(
@@ -3084,14 +3301,18 @@ fn main() -> Result<()> {
                  */
                 for par in o.parameters.iter() {
                     // The name will be filled in by the parameter data.
-                    ts.select_param(Some(&oid_to_object_name(&od)), par)?;
+                    used.push(ts.select_param(Some(&oid_to_object_name(&od)), par)?);
                 }
 
                 /*
                  * Get the response body type for each status code:
                  */
                 let mut res: Vec<String> = Default::default();
-                for (_, r) in o.responses.responses.iter() {
+                for (sc, r) in o.responses.responses.iter() {
+                    let key = match sc {
+                        openapiv3::StatusCode::Code(c) => format!("{} {} {}", m, pn, c),
+                        openapiv3::StatusCode::Range(c) => format!("{} {} {}XX", m, pn, c),
+                    };
                     match r {
                         openapiv3::ReferenceOr::Item(ri) => {
                             for (ct, mt) in &ri.content {
@@ -3120,6 +3341,8 @@ fn main() -> Result<()> {
                                             ts.select(Some(&clean_name(&object_name)), s, "")?;
                                         let rt = ts.render_type(&id, false)?;
                                         res.push(format!("{} {:?}", rt, id));
+                                        used.push(id.clone());
+                                        type_names.insert(key.clone(), id);
                                     }
                                 } else {
                                     res.push(ct.to_string());
@@ -3129,6 +3352,8 @@ fn main() -> Result<()> {
                         openapiv3::ReferenceOr::Reference { reference } => {
                             let id = ts.select_ref(None, reference.as_str())?;
                             res.push(format!("{:?}", id));
+                            used.push(id.clone());
+                            type_names.insert(key, id);
                         }
                     }
                 }
@@ -3139,6 +3364,8 @@ fn main() -> Result<()> {
                     res.join(" | ")
                 ));
 
+                ts.tag_types.entry(tag.clone()).or_default().extend(used);
+
                 return Ok(tag);
             }
 
@@ -3161,35 +3388,70 @@ fn main() -> Result<()> {
     let host = args.opt_str("host").unwrap();
     let output_dir = args.opt_str("o").unwrap();
     let spec_link = args.opt_str("spec-link").unwrap();
-    let token_endpoint = if let Some(te) = args.opt_str("token-endpoint") {
-        te
-    } else {
-        String::new()
-    };
-    let user_consent_endpoint = if let Some(uce) = args.opt_str("user-consent-endpoint") {
-        uce
-    } else {
-        String::new()
-    };
+
+    /*
+     * Make sure none of the types we generated last time were renamed, since that
+     * breaks whoever uses them.
+     */
+    let mut rendered_type_names: BTreeMap<String, String> = BTreeMap::new();
+    for (k, id) in type_names.iter() {
+        // The responses without a body have no type.
+        if ts.has_type(id) {
+            rendered_type_names.insert(k.to_string(), ts.render_type(id, true)?);
+        }
+    }
+    let mut type_names_json = PathBuf::from(&output_dir);
+    type_names_json.push("type-names.json");
+    if type_names_json.exists() && !args.opt_present("allow-renames") {
+        let before: BTreeMap<String, String> = load(&type_names_json)?;
+        let renamed = before
+            .iter()
+            .filter_map(|(k, b)| match rendered_type_names.get(k) {
+                Some(a) if a != b => Some(format!("\t{}: {} -> {}", k, b, a)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if !renamed.is_empty() {
+            bail!(
+                "types were renamed, pass --allow-renames if that is intended:\n{}",
+                renamed.join("\n")
+            );
+        }
+    }
 
     // Sort our tags and de-duplicate them.
     tags.sort_unstable();
     tags.dedup();
 
-    let add_post_header = if let Some(ph) = args.opt_str("add-post-header") {
-        ph
-    } else {
-        String::new()
-    };
-    let fail = match gen(
-        &api,
-        &proper_name,
-        &host,
-        tags,
-        &token_endpoint,
-        &user_consent_endpoint,
-        &add_post_header,
-    ) {
+    /*
+     * Each tag is a cargo feature, which needs the features of the tags whose types
+     * it uses.
+     */
+    let modules = tag_modules(&api, &tags, &config);
+    let mut common = vec![config.error_body().trim_start_matches("crate::types::")];
+    if config.auth == Auth::GitHub {
+        // The client creates installation tokens itself.
+        common.push("AppsCreateInstallationAccessTokenRequest");
+    }
+    let type_modules = types::get_type_modules(&ts, &modules, &common);
+    let mut features = "full = [\n".to_string();
+    for m in modules.iter() {
+        features.push_str(&format!("    \"{}\",\n", m));
+    }
+    features.push_str("]\n");
+    for (m, deps) in type_modules.features.iter() {
+        if RESERVED_FEATURES.contains(&m.as_str()) {
+            bail!("the tag {} can't be a feature, it is taken", m);
+        }
+        let deps = deps
+            .iter()
+            .map(|d| format!("\"{}\"", d))
+            .collect::<Vec<_>>()
+            .join(", ");
+        features.push_str(&format!("{} = [{}]\n", m, deps));
+    }
+
+    let fail = match gen(&api, &proper_name, &host, tags, &config) {
         Ok(out) => {
             let description = args.opt_str("d").unwrap();
 
@@ -3204,10 +3466,8 @@ fn main() -> Result<()> {
              */
             let mut uuid_lib = "".to_string();
             let mut yup_oauth2_lib = "".to_string();
-            if proper_name != "GitHub" {
+            if config.auth != Auth::GitHub {
                 uuid_lib = r#"
-bytes = { version = "1", features = ["serde"] }
-async-trait = "^0.1.51"
 uuid = { version = "1.1", features = ["serde", "v4"] }"#
                     .to_string();
             }
@@ -3219,6 +3479,26 @@ yup-oauth2 = "^5""#
                     .to_string();
             }
 
+            let mut auth_lib = "".to_string();
+            if proper_name == "Shopify" {
+                auth_lib = r#"
+hmac = "0.12"
+sha2 = "0.10""#
+                    .to_string();
+            } else if proper_name == "MailChimp" {
+                auth_lib = r#"
+base64 = "^0.13""#
+                    .to_string();
+            }
+
+            /*
+             * Write which type each schema, request and response body has:
+             */
+            save(
+                &type_names_json,
+                &format!("{}\n", serde_json::to_string_pretty(&rendered_type_names)?),
+            )?;
+
             let mut toml = root.clone();
             toml.push("Cargo.toml");
             let tomlout = format!(
@@ -3233,94 +3513,111 @@ edition = "2018"
 license = "MIT"
 
 [features]
-default = ["rustls-tls"]
+default = ["rustls-tls", "full"]
+# exact decimals, like amounts of money, as rust_decimal::Decimal
+decimal = ["rust_decimal", "schemars/rust_decimal"]
 # enable etag-based http_cache functionality
 httpcache = ["dirs"]
-native-tls = ["reqwest/default-tls", "openssl"]
+# mocks of the traits of the tags, for the tests of the code that uses them
+mock = ["mockall"]
+native-tls = ["reqwest/native-tls", "openssl"]
 rustls-tls = ["reqwest/rustls-tls", "ring", "pem"]
-
+# the tags of the API, each needs the tags whose types it uses
+{}
 [dependencies]
 anyhow = "1"
 async-recursion = "^1.0"
+async-trait = "^0.1.51"
+bytes = {{ version = "1", features = ["serde"] }}
 chrono = {{ version = "0.4", features = ["serde"] }}
 dirs = {{ version = "^3.0.2", optional = true }}
+futures = "0.3"
 http = "^0.2.4"
 hyperx = "1"
 jsonwebtoken = "8"
 log = {{ version = "^0.4", features = ["serde"] }}
 mime = "0.3"
+mockall = {{ version = "0.11", optional = true }}
 openssl = {{ version = "0.10", default-features = false, optional = true }}
 pem = {{ version = "1.1.0",  default-features = false, optional = true }}
 percent-encoding = "2.1"
-reqwest = {{ version = "0.11.11", default-features = false, features = ["json", "multipart"] }}
-reqwest-conditional-middleware = "0.1.0"
+reqwest = {{ version = "0.11.11", default-features = false, features = ["json", "multipart", "stream"] }}
 reqwest-middleware = "0.1.5"
-reqwest-retry = "0.1.4"
 reqwest-tracing = {{ version = "0.3.0", features = ["opentelemetry_0_17"] }}
 ring = {{ version = "0.16", default-features = false, optional = true }}
+rust_decimal = {{ version = "1.26", features = ["serde-with-float"], optional = true }}
 schemars = {{ version = "0.8", features = ["bytes", "chrono", "url", "uuid1"] }}
 serde = {{ version = "1", features = ["derive"] }}
 serde_json = "1"
+serde_path_to_error = "0.1"
 serde_urlencoded = "^0.7"
-url = {{ version = "2", features = ["serde"] }}{}{}
+task-local-extensions = "0.1"
+url = {{ version = "2", features = ["serde"] }}{}{}{}
 tokio = {{ version = "1.20.1", features = ["full"] }}
 
 [dev-dependencies]
 base64 = "^0.13"
 dirs = "^3.0.2"
 nom_pem = "4"
+mockall = "0.11"
 
 [package.metadata.docs.rs]
 all-features = true
 rustdoc-args = ["--cfg", "docsrs"]
 "#,
-                name, description, version, name, output_dir, uuid_lib, yup_oauth2_lib
+                name,
+                description,
+                version,
+                name,
+                output_dir,
+                features,
+                uuid_lib,
+                yup_oauth2_lib,
+                auth_lib
             );
             save(&toml, tomlout.as_str())?;
 
             /*
              * Generate our documentation for the library.
              */
-            let docs = if proper_name == "GitHub" {
-                template::generate_docs_github(
+            let docs = match config.auth {
+                Auth::GitHub => template::generate_docs_github(
                     &api,
                     &to_snake_case(&name),
                     &version,
                     &proper_name,
                     host.trim_start_matches("https://"),
                     &spec_link,
-                )
-            } else if proper_name == "SendGrid"
-                || proper_name == "Giphy"
-                || proper_name == "Rev.ai"
-                || proper_name == "Okta"
-                || proper_name == "ShipBob"
-                || proper_name == "Stripe"
-            {
-                template::generate_docs_generic_api_key(
+                ),
+                Auth::Okta => template::generate_docs_okta(
+                    &api,
+                    &to_snake_case(&name),
+                    &version,
+                    &proper_name,
+                    &spec_link,
+                ),
+                Auth::ApiKey => template::generate_docs_generic_api_key(
                     &api,
                     &to_snake_case(&name),
                     &version,
                     &proper_name,
                     &spec_link,
-                )
-            } else if proper_name == "TripActions" {
-                template::generate_docs_generic_client_credentials(
+                ),
+                Auth::ClientCredentials => template::generate_docs_generic_client_credentials(
                     &api,
                     &to_snake_case(&name),
                     &version,
                     &proper_name,
                     &spec_link,
-                )
-            } else {
-                template::generate_docs_generic_token(
+                ),
+                Auth::OAuth2 => template::generate_docs_generic_token(
                     &api,
                     &to_snake_case(&name),
                     &version,
                     &proper_name,
                     &spec_link,
-                    &add_post_header,
-                )
+                    &config.post_header,
+                ),
             };
             let mut readme = root.clone();
             readme.push("README.md");
@@ -3357,28 +3654,84 @@ rustdoc-args = ["--cfg", "docsrs"]
             utilsrs.push("utils.rs");
             save(utilsrs, utils.as_str())?;
 
+            /*
+             * Create the Rust pagination module:
+             */
+            let pagination = pagination::generate_pagination(config.pagination.style);
+            let mut paginationrs = src.clone();
+            paginationrs.push("pagination.rs");
+            save(paginationrs, pagination.as_str())?;
+
+            /*
+             * Create the Rust rate limiter module:
+             */
+            let rate_limiter = rate_limiter::generate_rate_limiter(config.rate_limit);
+            let mut rate_limiterrs = src.clone();
+            rate_limiterrs.push("rate_limiter.rs");
+            save(rate_limiterrs, rate_limiter.as_str())?;
+
+            /*
+             * Create the Rust retry module:
+             */
+            let retry = retry::generate_retry(config.idempotency_key.as_deref());
+            let mut retryrs = src.clone();
+            retryrs.push("retry.rs");
+            save(retryrs, retry.as_str())?;
+
+            /*
+             * Create the Rust token store module:
+             */
+            if config.auth == Auth::OAuth2 {
+                let token_store = token_store::generate_token_store();
+                let mut token_storers = src.clone();
+                token_storers.push("token_store.rs");
+                save(token_storers, token_store.as_str())?;
+            }
+
+            /*
+             * Create the Rust error module:
+             */
+            let error = error::generate_error(config.error_body());
+            let mut errorrs = src.clone();
+            errorrs.push("error.rs");
+            save(errorrs, error.as_str())?;
+
             /*
              * Create the Rust source types file containing the generated types:
              */
-            let types = types::generate_types(&mut ts, &proper_name)?;
+            let (types, tag_types) =
+                types::generate_types(&mut ts, &proper_name, &config, &type_modules)?;
             let mut typesrs = src.clone();
             typesrs.push("types.rs");
             save(typesrs, types.as_str())?;
 
+            let mut typesdir = src.clone();
+            typesdir.push("types");
+            std::fs::create_dir_all(&typesdir)?;
+            for (tag, types) in tag_types {
+                let mut tagrs = typesdir.clone();
+                tagrs.push(format!("{}.rs", tag));
+                save(tagrs, types.as_str())?;
+            }
+
             /*
              * Create the Rust source files for each of the tags functions:
              */
-            let fail = match functions::generate_files(&api, &proper_name, &mut ts, &parameters) {
+            let fail = match functions::generate_files(
+                &api,
+                &proper_name,
+                &config,
+                &mut ts,
+                &parameters,
+            ) {
                 Ok(files) => {
                     // We have a map of our files, let's write to them.
-                    for (f, content) in files {
+                    for (f, (content, params)) in files {
                         let mut tagrs = src.clone();
                         tagrs.push(format!("{}.rs", to_snake_case(&clean_name(&f))));
 
                         let output = format!(
-                            r#"use anyhow::Result;
-
-use crate::Client;
+                            r#"use crate::{{Client, Result}};
 
 pub struct {} {{
     pub client: Client,
@@ -3394,11 +3747,13 @@ impl {} {{
     }}
 
     {}
-}}"#,
+}}
+{}"#,
                             struct_name(&f),
                             struct_name(&f),
                             struct_name(&f),
                             content,
+                            params,
                         );
                         save(tagrs, output.as_str())?;
                     }
@@ -3435,3 +3790,139 @@ impl {} {{
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TypeSpace;
+
+    fn schema(s: &str) -> openapiv3::ReferenceOr<openapiv3::Schema> {
+        serde_json::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn test_types_keep_their_names() {
+        let mut ts = TypeSpace::new();
+        ts.schema_names.insert("owner".to_string());
+
+        // Schemas are named after themselves rather than their title.
+        let user = ts
+            .select_component(
+                "user",
+                &schema(r#"{"type": "object", "title": "Person", "properties": {"id": {"type": "string"}}}"#),
+            )
+            .unwrap();
+        assert_eq!(ts.render_type(&user, true).unwrap(), "User");
+
+        // Types with the same fields are not merged.
+        let member = ts
+            .select(
+                Some("member response"),
+                &schema(r#"{"type": "object", "properties": {"id": {"type": "string"}}}"#),
+                "",
+            )
+            .unwrap();
+        assert_ne!(member, user);
+        assert_eq!(ts.render_type(&member, true).unwrap(), "MemberResponse");
+
+        // Types defined inline don't take the name of a schema we have not got to yet.
+        ts.select_component(
+            "team",
+            &schema(
+                r#"{"type": "object", "properties": {"owner": {"type": "object", "properties": {"name": {"type": "string"}}}}}"#,
+            ),
+        )
+        .unwrap();
+        let owner = ts
+            .select_component(
+                "owner",
+                &schema(r#"{"type": "object", "properties": {"login": {"type": "string"}}}"#),
+            )
+            .unwrap();
+        assert_eq!(ts.render_type(&owner, true).unwrap(), "Owner");
+        assert!(ts.name_to_id.contains_key("team owner"));
+    }
+
+    #[test]
+    fn test_decimal_types() {
+        let mut ts = TypeSpace::new();
+        ts.decimal.properties.push("grand_total".to_string());
+
+        let t = ts
+            .select(
+                Some("rate"),
+                &schema(r#"{"type": "string", "format": "decimal"}"#),
+                "",
+            )
+            .unwrap();
+        assert_eq!(
+            ts.render_type(&t, true).unwrap(),
+            "crate::types::DecimalString"
+        );
+
+        let t = ts
+            .select(Some("grandTotal"), &schema(r#"{"type": "number"}"#), "")
+            .unwrap();
+        assert_eq!(
+            ts.render_type(&t, true).unwrap(),
+            "crate::types::DecimalNumber"
+        );
+
+        let t = ts
+            .select(
+                Some("exchangeRate"),
+                &schema(r#"{"type": "number", "format": "double"}"#),
+                "",
+            )
+            .unwrap();
+        assert_eq!(ts.render_type(&t, true).unwrap(), "f64");
+    }
+
+    #[test]
+    fn test_type_modules() {
+        let mut ts = TypeSpace::new();
+        let object = r#"{"type": "object", "properties": {"id": {"type": "string"}}}"#;
+        let account = ts.select_component("account", &schema(object)).unwrap();
+        let envelope = ts.select_component("envelope", &schema(object)).unwrap();
+        let error = ts.select_component("error", &schema(object)).unwrap();
+
+        ts.tag_types.insert(
+            "accounts".to_string(),
+            vec![account.clone()].into_iter().collect(),
+        );
+        ts.tag_types.insert(
+            "envelopes".to_string(),
+            vec![envelope.clone()].into_iter().collect(),
+        );
+        ts.tag_types.insert(
+            "users".to_string(),
+            vec![account.clone(), envelope.clone()]
+                .into_iter()
+                .collect(),
+        );
+
+        let tags = vec![
+            "accounts".to_string(),
+            "envelopes".to_string(),
+            "users".to_string(),
+        ];
+        let modules = crate::types::get_type_modules(&ts, &tags, &[]);
+        assert_eq!(modules.tag_of[&account], "accounts");
+        assert_eq!(modules.tag_of[&envelope], "envelopes");
+        // Types no operation uses are built whatever the features.
+        assert!(!modules.tag_of.contains_key(&error));
+        assert!(modules.features["accounts"].is_empty());
+        assert!(modules.features["envelopes"].is_empty());
+        assert_eq!(
+            modules.features["users"].iter().collect::<Vec<_>>(),
+            vec!["accounts", "envelopes"]
+        );
+
+        // So are the types the rest of the crate needs.
+        let modules = crate::types::get_type_modules(&ts, &tags, &["Envelope"]);
+        assert!(!modules.tag_of.contains_key(&envelope));
+        assert_eq!(
+            modules.features["users"].iter().collect::<Vec<_>>(),
+            vec!["accounts"]
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use crate::config::PaginationStyle;
+
+const TEMPLATE: &str = r#"// Not every API has paginated endpoints, so parts of this module may go unused.
+#![allow(dead_code)]
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use futures::{
+    stream::{BoxStream, Stream},
+    StreamExt,
+};
+
+use crate::Result;
+
+/// A page of items, along with the cursor of the page after it.
+pub(crate) struct Page<T> {
+    items: Vec<T>,
+    next: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Create a page. If `next` is `None`, this is the last page.
+    pub(crate) fn new(items: Vec<T>, next: Option<String>) -> Self {
+        Page { items, next }
+    }
+}
+
+/// A stream of the items of a paginated endpoint, as returned by the `*_stream` functions.
+///
+/// Pages are fetched one at a time, only once the items of the previous page have been
+/// consumed. The stream ends after the last page, after `max_items` items or after the
+/// first error.
+pub struct Pages<T> {
+    inner: BoxStream<'static, Result<T>>,
+    cursor: Arc<Mutex<Cursor>>,
+}
+
+/// Where a stream of pages is, as returned by `Pages::cursor`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cursor {
+    /// At the page of this cursor, to pass as `start` to resume the stream, or at the
+    /// first page if it is `None`.
+    At(Option<String>),
+    /// Past the last page, there is nothing left to resume.
+    Done,
+}
+
+impl Cursor {
+    fn after(next: &Option<String>) -> Self {
+        match next {
+            Some(next) => Cursor::At(Some(next.clone())),
+            None => Cursor::Done,
+        }
+    }
+}
+
+struct State<T, F> {
+    fetch: F,
+    items: std::vec::IntoIter<T>,
+    next: Option<String>,
+    started: bool,
+    remaining: Option<usize>,
+    cursor: Arc<Mutex<Cursor>>,
+}
+
+impl<T: Send + 'static> Pages<T> {
+    /// Create a stream that calls `fetch` with the cursor of each page, starting at `start`,
+    /// or at the first page if it is `None`.
+    pub(crate) fn new<F, Fut>(start: Option<String>, max_items: Option<usize>, fetch: F) -> Self
+    where
+        F: FnMut(Option<String>) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Page<T>>> + Send + 'static,
+    {
+        let cursor = Arc::new(Mutex::new(Cursor::At(start.clone())));
+        let state = State {
+            fetch,
+            items: Vec::new().into_iter(),
+            next: start,
+            started: false,
+            remaining: max_items,
+            cursor: cursor.clone(),
+        };
+
+        let inner = futures::stream::try_unfold(state, |mut state| async move {
+            loop {
+                if state.remaining == Some(0) {
+                    return Ok(None);
+                }
+
+                if let Some(item) = state.items.next() {
+                    if state.items.as_slice().is_empty() {
+                        // We are done with this page, so resume at the next one.
+                        *state.cursor.lock().unwrap() = Cursor::after(&state.next);
+                    }
+                    state.remaining = state.remaining.map(|r| r - 1);
+                    return Ok(Some((item, state)));
+                }
+
+                if state.started && state.next.is_none() {
+                    return Ok(None);
+                }
+                state.started = true;
+
+                let page = (state.fetch)(state.next.clone()).await?;
+                // Some APIs hand back the cursor we just used on the last page, don't loop
+                // on it forever.
+                state.next = page.next.filter(|n| Some(n) != state.next.as_ref());
+                state.items = page.items.into_iter();
+                if state.items.as_slice().is_empty() {
+                    *state.cursor.lock().unwrap() = Cursor::after(&state.next);
+                }
+            }
+        })
+        .boxed();
+
+        Pages { inner, cursor }
+    }
+}
+
+impl<T> Pages<T> {
+    /// Where the stream stopped: `Cursor::At` the page to pass as `start` to the same
+    /// function to resume it, or `Cursor::Done` once the last page was consumed.
+    ///
+    /// Cursors point at pages, not items, so if the stream stopped in the middle of a page,
+    /// the resumed stream yields the items of that page that were already consumed again.
+    pub fn cursor(&self) -> Cursor {
+        self.cursor.lock().unwrap().clone()
+    }
+}
+
+impl<T> Stream for Pages<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+/// Add a query parameter to a URL that may or may not have a query already.
+pub(crate) fn with_query(url: &str, key: &str, value: &str) -> String {
+    let query = serde_urlencoded::to_string([(key, value)]).unwrap();
+    if url.contains('?') {
+        format!("{}&{}", url, query)
+    } else {
+        format!("{}?{}", url, query)
+    }
+}
+"#;
+
+/*
+ * Stripe lists all page the same way, so rather than generating the pagination for
+ * each of them, they share the helpers below.
+ */
+const STRIPE_TEMPLATE: &str = r#"
+/// A filter on a range of values, such as the `created` timestamp of the objects of a
+/// list. Each bound is only sent if it is `Some`, as `created[gte]=...`, etc.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeQuery {
+    pub gt: Option<i64>,
+    pub gte: Option<i64>,
+    pub lt: Option<i64>,
+    pub lte: Option<i64>,
+}
+
+impl RangeQuery {
+    pub fn gt(mut self, gt: i64) -> Self {
+        self.gt = Some(gt);
+        self
+    }
+
+    pub fn gte(mut self, gte: i64) -> Self {
+        self.gte = Some(gte);
+        self
+    }
+
+    pub fn lt(mut self, lt: i64) -> Self {
+        self.lt = Some(lt);
+        self
+    }
+
+    pub fn lte(mut self, lte: i64) -> Self {
+        self.lte = Some(lte);
+        self
+    }
+
+    /// The range of the single value `s`, as the deprecated functions take it, or `None`
+    /// if it is empty or not a number.
+    pub(crate) fn parse(s: &str) -> Option<Self> {
+        let v = s.trim().parse().ok()?;
+        Some(RangeQuery::default().gte(v).lte(v))
+    }
+
+    /// The query parameters that filter the `name` field on this range.
+    pub(crate) fn to_query(&self, name: &str) -> Vec<(String, String)> {
+        [
+            ("gt", self.gt),
+            ("gte", self.gte),
+            ("lt", self.lt),
+            ("lte", self.lte),
+        ]
+        .iter()
+        .filter_map(|(op, v)| v.map(|v| (format!("{}[{}]", name, op), v.to_string())))
+        .collect()
+    }
+}
+
+/// The fields that the responses of all lists, and of searches, have in common.
+#[derive(serde::Deserialize)]
+#[serde(bound = "T: serde::de::DeserializeOwned")]
+struct List<T> {
+    #[serde(default)]
+    data: Vec<T>,
+    #[serde(default)]
+    has_more: bool,
+    #[serde(default)]
+    next_page: Option<String>,
+}
+
+/// Where to start a list and which way to page through it.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ListParams {
+    start: Option<String>,
+    backwards: bool,
+    search: bool,
+    limit: Option<i64>,
+}
+
+impl ListParams {
+    /// Page forwards from `starting_after`, or backwards from `ending_before`.
+    pub(crate) fn new(
+        starting_after: Option<&str>,
+        ending_before: Option<&str>,
+        limit: Option<i64>,
+    ) -> Self {
+        ListParams {
+            start: starting_after.or(ending_before).map(|s| s.to_string()),
+            backwards: starting_after.is_none() && ending_before.is_some(),
+            search: false,
+            limit,
+        }
+    }
+
+    /// Page through search results from `page`, the `next_page` of a previous search.
+    pub(crate) fn search(page: Option<&str>, limit: Option<i64>) -> Self {
+        ListParams {
+            start: page.map(|s| s.to_string()),
+            backwards: false,
+            search: true,
+            limit,
+        }
+    }
+
+    /// Start from `cursor` instead, if there is one.
+    pub(crate) fn resume(mut self, cursor: Option<String>) -> Self {
+        if cursor.is_some() {
+            self.start = cursor;
+        }
+        self
+    }
+}
+
+/// Stream the items of the list at `url`, `limit` at a time, or 100, the most Stripe
+/// allows. The page after the current one starts after the item that `id` returns the
+/// ID of, so a list of items without an ID ends after its first page.
+pub(crate) fn list<T, F>(
+    client: crate::Client,
+    url: String,
+    params: ListParams,
+    max_items: Option<usize>,
+    id: F,
+) -> Pages<T>
+where
+    T: serde::de::DeserializeOwned + Send + 'static,
+    F: Fn(&T) -> Option<String> + Clone + Send + 'static,
+{
+    let ListParams {
+        start,
+        backwards,
+        search,
+        limit,
+    } = params;
+    let limit = limit.unwrap_or(100).to_string();
+    let key = if search {
+        "page"
+    } else if backwards {
+        "ending_before"
+    } else {
+        "starting_after"
+    };
+
+    Pages::new(start, max_items, move |cursor: Option<String>| {
+        let client = client.clone();
+        let id = id.clone();
+        let mut url = with_query(&url, "limit", &limit);
+        if let Some(cursor) = &cursor {
+            url = with_query(&url, key, cursor);
+        }
+
+        async move {
+            let list: List<T> = client.get(&url, None).await?;
+
+            let next = if !list.has_more {
+                None
+            } else if search {
+                list.next_page.filter(|n| !n.is_empty())
+            } else if backwards {
+                list.data.first().and_then(&id)
+            } else {
+                list.data.last().and_then(&id)
+            };
+
+            Ok(Page::new(list.data, next))
+        }
+    })
+}
+
+/// Fetch all the items of the list at `url` at once.
+pub(crate) async fn list_all<T, F>(
+    client: crate::Client,
+    url: String,
+    params: ListParams,
+    id: F,
+) -> Result<Vec<T>>
+where
+    T: serde::de::DeserializeOwned + Send + 'static,
+    F: Fn(&T) -> Option<String> + Clone + Send + 'static,
+{
+    use futures::TryStreamExt;
+
+    list(client, url, params, None, id).try_collect().await
+}
+"#;
+
+pub fn generate_pagination(style: PaginationStyle) -> String {
+    if style == PaginationStyle::Stripe {
+        format!("{}{}", TEMPLATE, STRIPE_TEMPLATE)
+    } else {
+        TEMPLATE.to_string()
+    }
+}
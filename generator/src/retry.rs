@@ -0,0 +1,163 @@
+const TEMPLATE: &str = r#"//! Retrying requests that failed for reasons that may not last.
+//!
+//! Requests are sent again after timeouts, errors connecting and the `408`, `500`,
+//! `502`, `503` and `504` statuses, waiting longer before each attempt. Only the
+//! requests that are safe to send twice are retried: those with a method of the
+//! `RetryPolicy`, `GET`, `HEAD`, `OPTIONS` and `TRACE` unless it says otherwise, and
+//! the `POST`s with an idempotency key, for the APIs that take one.
+//!
+//! Rate limited requests are left to the [`RateLimiter`](crate::rate_limiter::RateLimiter).
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use reqwest::{Method, Request, Response, StatusCode};
+use reqwest_middleware::{Middleware, Next};
+use task_local_extensions::Extensions;
+
+/// The header of the idempotency key of `POST`s, if the API takes one.
+pub const IDEMPOTENCY_KEY: Option<&str> = {};
+
+/// How failed requests are retried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// How many times a request is sent at most, `1` to never retry.
+    pub max_attempts: u32,
+    /// How long to wait before the first retry. The wait doubles with each retry.
+    pub min_backoff: Duration,
+    /// How long to wait before a retry, at most.
+    pub max_backoff: Duration,
+    /// Wait a random time between half the backoff and the whole of it, so clients
+    /// that failed together don't all retry together.
+    pub jitter: bool,
+    /// The methods of the requests to retry. `PUT` and `DELETE` are idempotent too,
+    /// for the APIs that follow HTTP on that.
+    pub methods: Vec<Method>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 4,
+            min_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            methods: vec![Method::GET, Method::HEAD, Method::OPTIONS, Method::TRACE],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn never() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Whether a request is safe to send again.
+    pub fn is_retryable(&self, req: &Request) -> bool {
+        self.methods.contains(req.method())
+            || (req.method() == Method::POST
+                && IDEMPOTENCY_KEY
+                    .map(|k| req.headers().contains_key(k))
+                    .unwrap_or(false))
+    }
+
+    /// How long to wait before the given retry, counting from 1.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let backoff = self
+            .min_backoff
+            .checked_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff);
+        if !self.jitter {
+            return backoff;
+        }
+
+        // A random number, without a dependency on a crate for it.
+        let random = RandomState::new().build_hasher().finish();
+        let half = backoff / 2;
+        half + Duration::from_nanos(random % (half.as_nanos() as u64 + 1))
+    }
+}
+
+/// The middleware that retries requests as its `RetryPolicy` says.
+#[derive(Debug, Clone, Default)]
+pub struct Retry {
+    policy: RetryPolicy,
+}
+
+impl Retry {
+    pub fn new(policy: RetryPolicy) -> Self {
+        Retry { policy }
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for Retry {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let mut req = req;
+        let mut attempt = 1;
+        loop {
+            // Requests with a streamed body can't be sent again.
+            let again = if self.policy.is_retryable(&req) {
+                req.try_clone()
+            } else {
+                None
+            };
+            let result = next.clone().run(req, extensions).await;
+
+            match again {
+                Some(again) if attempt < self.policy.max_attempts && is_transient(&result) => {
+                    let wait = self.policy.backoff(attempt);
+                    log::debug!("retrying {} {} in {:?}", again.method(), again.url(), wait);
+                    tokio::time::sleep(wait).await;
+                    req = again;
+                    attempt += 1;
+                }
+                _ => return result,
+            }
+        }
+    }
+}
+
+fn is_transient(result: &reqwest_middleware::Result<Response>) -> bool {
+    match result {
+        Ok(response) => matches!(
+            response.status(),
+            StatusCode::REQUEST_TIMEOUT
+                | StatusCode::INTERNAL_SERVER_ERROR
+                | StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+        ),
+        // Not the other errors of sending the request, which may have reached the
+        // server.
+        Err(reqwest_middleware::Error::Reqwest(e)) => e.is_timeout() || e.is_connect(),
+        // Like the requests the rate limiter wouldn't send.
+        Err(reqwest_middleware::Error::Middleware(_)) => false,
+    }
+}
+"#;
+
+/*
+ * The retries are the same for every API, only the header of idempotency keys
+ * differs.
+ */
+pub fn generate_retry(idempotency_key: Option<&str>) -> String {
+    let idempotency_key = match idempotency_key {
+        Some(k) => format!("Some({:?})", k),
+        None => "None".to_string(),
+    };
+    TEMPLATE.replacen("{}", &idempotency_key, 1)
+}
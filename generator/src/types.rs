@@ -1,27 +1,172 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use anyhow::{bail, Result};
 use inflector::cases::snakecase::to_snake_case;
 
-use crate::{render_param, struct_name, TypeDetails, TypeSpace};
+use crate::{config::Config, render_param, struct_name, TypeDetails, TypeSpace};
 
 /*
- * Declare named types we know about:
+ * The `std` types are named with their path, the API may have types of the same name,
+ * like `Result`.
  */
-pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
+const NULLABLE: &str = r#"
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> std::option::Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> std::convert::From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match std::option::Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        std::option::Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        std::option::Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+"#;
+
+const DECIMAL: &str = r#"
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
+
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+"#;
+
+/*
+ * Declare named types we know about. The types of the tags are in modules of their
+ * own, returned by tag, and re-exported from `types.rs` if the feature of the tag is
+ * enabled.
+ */
+pub fn generate_types(
+    ts: &mut TypeSpace,
+    proper_name: &str,
+    config: &Config,
+    modules: &TypeModules,
+) -> Result<(String, BTreeMap<String, String>)> {
     let mut out = String::new();
+    let mut tag_out: BTreeMap<String, String> = BTreeMap::new();
 
-    let mut a = |s: &str| {
-        out.push_str(s);
-        out.push('\n');
-    };
+    out.push_str("//! The data types sent to and returned from the API client.\n");
+    out.push_str("    use schemars::JsonSchema;\n");
+    out.push_str("    use serde::{Serialize, Deserialize};\n");
+    out.push_str(NULLABLE);
+    out.push_str(DECIMAL);
 
-    a("//! The data types sent to and returned from the API client.");
-    a("    use schemars::JsonSchema;");
-    a("    use serde::{Serialize, Deserialize};");
-    a("");
+    // Only the tags that have types of their own have a module.
+    for tag in modules.tag_of.values().collect::<BTreeSet<_>>() {
+        out.push_str(&format!(
+            r#"
+#[cfg(feature = "{}")]
+mod {};
+#[cfg(feature = "{}")]
+pub use {}::*;
+"#,
+            tag, tag, tag, tag
+        ));
+        tag_out.insert(
+            tag.to_string(),
+            format!(
+                r#"//! The data types of the `{}` operations.
+    use schemars::JsonSchema;
+    use serde::{{Serialize, Deserialize}};
+
+    #[allow(unused_imports)]
+    use super::*;
+"#,
+                tag
+            ),
+        );
+    }
+
+    let request_types = get_request_types(ts);
 
     for te in ts.clone().id_to_entry.values() {
+        let mut type_out = String::new();
+        let mut a = |s: &str| {
+            type_out.push_str(s);
+            type_out.push('\n');
+        };
+
         if let Some(sn) = te.name.as_deref() {
             let sn = struct_name(sn);
 
@@ -31,21 +176,20 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                     if let Some(d) = &schema_data.description {
                         desc = d.to_string();
                     }
-                    let p = render_param(
-                        sn.as_str(),
-                        vals,
-                        false,
-                        &desc,
-                        schema_data.default.as_ref(),
-                    );
+                    let p =
+                        render_param(sn.as_str(), vals, &desc, schema_data.default.as_ref());
                     a(&p);
                 }
-                TypeDetails::OneOf(omap, _) => a(&do_one_of_type(ts, omap, sn)),
-                TypeDetails::AnyOf(omap, _) => {
+                TypeDetails::OneOf(omap, schema_data) => {
+                    let tags = get_discriminator(ts, omap, schema_data, config);
+                    a(&do_one_of_type(ts, omap, sn, tags))
+                }
+                TypeDetails::AnyOf(omap, schema_data) => {
                     // When Stripe uses anyof what they really mean is one of.
                     // Because something can not both be a string and an object.
                     if proper_name == "Stripe" {
-                        a(&do_one_of_type(ts, omap, sn));
+                        let tags = get_discriminator(ts, omap, schema_data, config);
+                        a(&do_one_of_type(ts, omap, sn, tags));
                     } else {
                         a(&do_all_of_type(ts, omap, sn));
                     }
@@ -108,7 +252,13 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                         );
                     }
                     a(&format!("pub struct {} {{", sn));
+                    let request = request_types.contains(&te.id);
                     for (name, tid) in omap.iter() {
+                        if name == crate::ADDITIONAL_PROPERTIES {
+                            // We add these last.
+                            continue;
+                        }
+
                         if let Ok(mut rt) = ts.render_type(tid, true) {
                             // Stripe has some really weird recursive types.
                             if rt.ends_with("AnyOf") && proper_name == "Stripe" {
@@ -173,14 +323,54 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
 
                             let te = ts.id_to_entry.get(tid).unwrap();
 
+                            // In request bodies, tell apart a field that is left out from
+                            // one that is `null` or an empty string.
+                            if let TypeDetails::Optional(itid, _) = &te.details {
+                                if request && !rt.starts_with("Box<") {
+                                    let it = ts.render_type(itid, true)?;
+                                    if ts.get_schema_data_for_id(itid).map(|sd| sd.nullable)
+                                        == Some(true)
+                                    {
+                                        let it = it
+                                            .strip_prefix("Option<")
+                                            .and_then(|it| it.strip_suffix('>'))
+                                            .unwrap_or(&it);
+                                        rt = format!("Nullable<{}>", it);
+                                    } else if it == "String" {
+                                        rt = "Option<String>".to_string();
+                                    }
+                                }
+                            }
+
+                            // Decimals that are numbers are sent as numbers, not strings.
+                            if rt == "crate::types::DecimalNumber" {
+                                a(
+                                    r#"#[cfg_attr(feature = "decimal", serde(with = "rust_decimal::serde::float"), schemars(with = "f64"))]"#,
+                                );
+                            } else if rt == "Option<crate::types::DecimalNumber>" {
+                                a(
+                                    r#"#[cfg_attr(feature = "decimal", serde(with = "rust_decimal::serde::float_option"), schemars(with = "Option<f64>"))]"#,
+                                );
+                            }
+
                             // Render the serde string.
-                            if rt == "String"
+                            if rt.starts_with("Nullable<") {
+                                a(
+                                    r#"#[serde(default, skip_serializing_if = "Nullable::is_absent","#,
+                                );
+                            } else if rt == "String"
                                 || rt.starts_with("Vec<")
                                 || rt.starts_with("Option<")
-                                || rt.starts_with("HashMap<")
+                                || rt.starts_with("std::collections::HashMap<")
+                                || rt.starts_with("std::collections::BTreeMap<")
                             {
                                 a(r#"#[serde(default,"#);
-                                if rt == "String" {
+                                if rt == "String" && request {
+                                    // The field is required, so send it even if it is empty.
+                                    a(
+                                        r#"deserialize_with = "crate::utils::deserialize_null_string::deserialize","#,
+                                    );
+                                } else if rt == "String" {
                                     a(r#"skip_serializing_if = "String::is_empty",
                                         deserialize_with = "crate::utils::deserialize_null_string::deserialize","#);
                                 } else if rt.starts_with("Vec<") {
@@ -190,6 +380,9 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                     a(
                                         r#"skip_serializing_if = "std::collections::HashMap::is_empty","#,
                                     );
+                                } else if rt.starts_with("std::collections::BTreeMap<") {
+                                    a(r#"skip_serializing_if = "std::collections::BTreeMap::is_empty",
+                                      deserialize_with = "crate::utils::deserialize_null_map::deserialize","#);
                                 } else if rt.starts_with("Option<url::Url") {
                                     a(r#"skip_serializing_if = "Option::is_none",
                                       deserialize_with = "crate::utils::deserialize_empty_url::deserialize","#);
@@ -247,14 +440,22 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                                     deserialize_with = "crate::utils::deserialize_null_f64::deserialize","#);
                             } else if rt == "u32" || rt == "u64" {
                                 a(r#"#[serde(default,"#);
+                            } else if rt == "crate::types::DecimalString" {
+                                a(r#"#[serde(default,
+                                    deserialize_with = "crate::utils::deserialize_null_decimal::deserialize","#);
+                            } else if rt == "crate::types::DecimalNumber" {
+                                a(r#"#[serde(default,"#);
                             } else if let TypeDetails::Enum(_, sd) = &te.details {
-                                // We for sure have a default for every single enum, even
-                                // if the default is a noop.
                                 a(r#"#[serde(default,"#);
-                                // Figure out if its a no op and skip serializing if it is.
+                                // Only enums with a default in the spec implement Default,
+                                // the others are missing when they are not set.
                                 if sd.default.is_none() {
-                                    a(&format!(r#"skip_serializing_if = "{}::is_noop","#, rt));
+                                    a(r#"skip_serializing_if = "Option::is_none","#);
+                                    rt = format!("Option<{}>", rt);
                                 }
+                            } else if rt.starts_with("Box<Option<") {
+                                // So we can parse the object when the field is missing.
+                                a(r#"#[serde(default,"#);
                             } else {
                                 a(r#"#[serde("#);
                             }
@@ -297,6 +498,16 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                             bail!("rendering type {} {:?} failed", name, tid);
                         }
                     }
+
+                    // Any other properties go in a map.
+                    if let Some(tid) = omap.get(crate::ADDITIONAL_PROPERTIES) {
+                        let rt = ts.render_type(tid, true)?;
+                        a("/**");
+                        a("* The properties of the object that are not listed above.");
+                        a("*/");
+                        a("#[serde(flatten)]");
+                        a(&format!("pub additional_properties: {},", rt));
+                    }
                     a("}");
                     a("");
                 }
@@ -304,15 +515,230 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                 TypeDetails::Unknown => {}
                 TypeDetails::NamedType(..) => {}
                 TypeDetails::Array(..) => {}
+                TypeDetails::Map(..) => {}
                 TypeDetails::Optional(..) => {}
             }
         }
+
+        match modules.tag_of.get(&te.id) {
+            Some(tag) => tag_out.get_mut(tag).unwrap().push_str(&type_out),
+            None => out.push_str(&type_out),
+        }
+    }
+
+    Ok((out, tag_out))
+}
+
+/*
+ * Where the types go. A type that the operations of a tag use, directly or through
+ * other types, is in the module of the first such tag, behind the cargo feature of
+ * that tag. Types that no operation uses, and those that the rest of the crate needs,
+ * like the `error_body`, are built whatever the features.
+ */
+#[derive(Debug, Default)]
+pub struct TypeModules {
+    /// The tag of each type that is in the module of a tag.
+    pub tag_of: BTreeMap<crate::TypeId, String>,
+    /// The tags whose types each tag needs, by tag.
+    pub features: BTreeMap<String, BTreeSet<String>>,
+}
+
+pub fn get_type_modules(ts: &TypeSpace, tags: &[String], common: &[&str]) -> TypeModules {
+    let mut modules = TypeModules::default();
+
+    let mut used: BTreeMap<crate::TypeId, &String> = BTreeMap::new();
+    for tag in tags {
+        modules.features.insert(tag.to_string(), BTreeSet::new());
+        if let Some(tids) = ts.tag_types.get(tag) {
+            for tid in get_reachable_types(ts, tids.iter().cloned()) {
+                used.entry(tid).or_insert(tag);
+            }
+        }
+    }
+
+    // The types that are not in a module can't need those that are.
+    let roots = ts.id_to_entry.values().filter(|te| {
+        !used.contains_key(&te.id)
+            || te
+                .name
+                .as_deref()
+                .map(|n| common.contains(&struct_name(n).as_str()))
+                .unwrap_or(false)
+    });
+    let common = get_reachable_types(ts, roots.map(|te| te.id.clone()));
+
+    for (tid, tag) in used {
+        // Only the types that are declared go in a module, not the `Vec`s, `String`s, etc.
+        let declared = ts
+            .id_to_entry
+            .get(&tid)
+            .map(|te| {
+                te.name.is_some()
+                    && matches!(
+                        te.details,
+                        TypeDetails::Enum(..)
+                            | TypeDetails::Object(..)
+                            | TypeDetails::OneOf(..)
+                            | TypeDetails::AnyOf(..)
+                            | TypeDetails::AllOf(..)
+                    )
+            })
+            .unwrap_or(false);
+        if declared && !common.contains(&tid) {
+            modules.tag_of.insert(tid, tag.to_string());
+        }
+    }
+
+    for tag in tags {
+        if let Some(tids) = ts.tag_types.get(tag) {
+            for tid in get_reachable_types(ts, tids.iter().cloned()) {
+                if let Some(t) = modules.tag_of.get(&tid) {
+                    if t != tag {
+                        modules.features.get_mut(tag).unwrap().insert(t.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    modules
+}
+
+/*
+ * The types of request bodies, and of what they are made of.
+ */
+fn get_request_types(ts: &TypeSpace) -> BTreeSet<crate::TypeId> {
+    get_reachable_types(ts, ts.request_bodies.iter().cloned())
+}
+
+/*
+ * The types given, and those they are made of.
+ */
+fn get_reachable_types<I>(ts: &TypeSpace, tids: I) -> BTreeSet<crate::TypeId>
+where
+    I: IntoIterator<Item = crate::TypeId>,
+{
+    let mut reachable = BTreeSet::new();
+    let mut todo: Vec<crate::TypeId> = tids.into_iter().collect();
+    while let Some(tid) = todo.pop() {
+        if !reachable.insert(tid.clone()) {
+            continue;
+        }
+
+        if let Some(te) = ts.id_to_entry.get(&tid) {
+            match &te.details {
+                TypeDetails::NamedType(itid, _)
+                | TypeDetails::Array(itid, _)
+                | TypeDetails::Map(itid, _)
+                | TypeDetails::Optional(itid, _) => todo.push(itid.clone()),
+                TypeDetails::Object(omap, _) => todo.extend(omap.values().cloned()),
+                TypeDetails::OneOf(omap, _)
+                | TypeDetails::AnyOf(omap, _)
+                | TypeDetails::AllOf(omap, _) => todo.extend(omap.iter().cloned()),
+                TypeDetails::Basic(..) | TypeDetails::Enum(..) | TypeDetails::Unknown => {}
+            }
+        }
     }
 
-    Ok(out.to_string())
+    reachable
 }
 
-fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String {
+/*
+ * The property that tells apart the types of a `oneOf`, and the value of it for
+ * each type. This is the `discriminator` of the spec, or else the one of the
+ * config, if each type has that property with a single possible value, like the
+ * `object` of Stripe. A `String` needs no value, like the IDs Stripe sends in
+ * place of objects that are not expanded. If any other type can't be told
+ * apart, we have none.
+ */
+fn get_discriminator(
+    ts: &TypeSpace,
+    omap: &[crate::TypeId],
+    schema_data: &openapiv3::SchemaData,
+    config: &Config,
+) -> Option<(String, Vec<Option<String>>)> {
+    let (property, mapping) = if let Some(d) = &schema_data.discriminator {
+        (d.property_name.to_string(), Some(&d.mapping))
+    } else if let Some(d) = &config.discriminator {
+        (d.to_string(), None)
+    } else {
+        return None;
+    };
+
+    let mut tags: Vec<Option<String>> = Default::default();
+    for tid in omap {
+        if ts.render_type(tid, true).ok()? == "String" {
+            tags.push(None);
+            continue;
+        }
+
+        let te = ts.id_to_entry.get(tid)?;
+        let reference = te.name.as_deref().unwrap_or_default();
+
+        let tag = if let Some(mapping) = mapping {
+            if let Some((tag, _)) = mapping.iter().find(|(_, r)| *r == reference) {
+                Some(tag.to_string())
+            } else if reference.starts_with("#/components/schemas/") {
+                // Without a mapping, the value is the name of the schema.
+                Some(
+                    reference
+                        .trim_start_matches("#/components/schemas/")
+                        .to_string(),
+                )
+            } else {
+                get_single_value(ts, tid, &property)
+            }
+        } else {
+            get_single_value(ts, tid, &property)
+        };
+
+        match tag {
+            Some(tag) if !tags.contains(&Some(tag.to_string())) => tags.push(Some(tag)),
+            _ => return None,
+        }
+    }
+
+    if tags.iter().all(|t| t.is_none()) {
+        return None;
+    }
+
+    Some((property, tags))
+}
+
+/*
+ * The value of a property of an object, if it is an enum of a single value.
+ */
+fn get_single_value(ts: &TypeSpace, tid: &crate::TypeId, property: &str) -> Option<String> {
+    if let TypeDetails::Object(o, _) = resolve_type(ts, tid)? {
+        if let TypeDetails::Enum(vals, _) = resolve_type(ts, o.get(property)?)? {
+            let vals: Vec<&String> = vals.iter().filter(|v| !v.is_empty()).collect();
+            if vals.len() == 1 {
+                return Some(vals[0].to_string());
+            }
+        }
+    }
+
+    None
+}
+
+/*
+ * The details of a type, past the references and options that wrap it.
+ */
+fn resolve_type<'a>(ts: &'a TypeSpace, mut tid: &'a crate::TypeId) -> Option<&'a TypeDetails> {
+    loop {
+        match &ts.id_to_entry.get(tid)?.details {
+            TypeDetails::NamedType(id, _) | TypeDetails::Optional(id, _) => tid = id,
+            details => return Some(details),
+        }
+    }
+}
+
+fn do_one_of_type(
+    ts: &mut TypeSpace,
+    omap: &[crate::TypeId],
+    sn: String,
+    tags: Option<(String, Vec<Option<String>>)>,
+) -> String {
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -352,17 +778,23 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     description = format!("/// {}", description.replace('\n', "\n/// "));
     a(&description);
 
-    a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
+    if tags.is_some() {
+        // We deserialize these ourselves, below.
+        a("#[derive(Serialize, PartialEq, Debug, Clone, JsonSchema)]");
+    } else {
+        a("#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]");
+    }
     if !flatten {
         a("#[serde(untagged)]");
     }
     a(&format!("pub enum {} {{", sn));
     let mut name_map: BTreeMap<String, String> = Default::default();
+    let mut tag_map: Vec<(Option<String>, String)> = Default::default();
     // Becasue we have so many defaults set on our serde types these enums
     // sometimes parse the wrong value. It's better to instead use the functions we
     // inject that force the value to a specific type.
     let mut fns: Vec<String> = Default::default();
-    for tid in omap.iter() {
+    for (i, tid) in omap.iter().enumerate() {
         let name = ts.render_type(tid, true).unwrap();
 
         let fn_name = if name.starts_with("Vec<") {
@@ -389,12 +821,67 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
 
             a(&format!("{}({}),", fn_name, name));
             name_map.insert(fn_name.to_string(), name.to_string());
+            if let Some((_, values)) = &tags {
+                tag_map.push((values[i].clone(), fn_name.to_string()));
+            }
             fns.push(fn_name);
         }
     }
     a("}");
     a("");
 
+    if let Some((property, _)) = &tags {
+        // Rather than trying each type in turn, the discriminator tells us which one
+        // to deserialize, so its errors are the ones we return.
+        let mut string = String::new();
+        let mut arms: Vec<String> = Default::default();
+        let mut expected: Vec<String> = Default::default();
+        for (tag, fn_name) in &tag_map {
+            if let Some(tag) = tag {
+                arms.push(format!(
+                    "\"{}\" => serde_json::from_value(value).map({}::{}),",
+                    tag, sn, fn_name
+                ));
+                expected.push(format!("\"{}\"", tag));
+            } else {
+                string = format!(
+                    r#"if let serde_json::Value::String(s) = value {{
+                        return Ok({}::{}(s));
+                    }}"#,
+                    sn, fn_name
+                );
+            }
+        }
+        a(&format!(
+            r#"impl<'de> Deserialize<'de> for {} {{
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {{
+                    let value = serde_json::Value::deserialize(deserializer)?;
+                    {}
+                    let tag = match value.get("{}") {{
+                        Some(serde_json::Value::String(tag)) => tag.to_string(),
+                        _ => return Err(serde::de::Error::missing_field("{}")),
+                    }};
+
+                    match tag.as_str() {{
+                        {}
+                        _ => return Err(serde::de::Error::unknown_variant(&tag, &[{}])),
+                    }}
+                    .map_err(serde::de::Error::custom)
+                }}
+            }}"#,
+            sn,
+            string,
+            property,
+            property,
+            arms.join("\n"),
+            expected.join(", ")
+        ));
+        a("");
+    }
+
     // Render the implementation to easily unpack these things for the end user.
     a(&format!("impl {} {{", sn));
     for (fn_name, name) in &name_map {
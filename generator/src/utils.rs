@@ -550,6 +550,20 @@ pub mod deserialize_null_f64 {
     }
 }
 
+pub mod deserialize_null_decimal {
+    use serde::{self, Deserialize, Deserializer};
+
+    // Decimals are `String`s or `rust_decimal::Decimal`s, depending on the `decimal`
+    // feature, `null` is zero or empty either way.
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de> + Default,
+    {
+        Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+    }
+}
+
 pub fn zero_i32(num: &i32) -> bool {
     *num == 0
 }
@@ -566,6 +580,38 @@ pub fn zero_f64(num: &f64) -> bool {
     *num == 0.0
 }
 
+/// Encode a request body as `application/x-www-form-urlencoded`.
+///
+/// Nested objects and arrays are flattened with brackets, the way Stripe expects them,
+/// ie. `metadata[order_id]=6735` and `items[0][price]=price_123`.
+pub fn encode_form<T: serde::Serialize>(body: &T) -> crate::Result<String> {
+    let mut pairs: Vec<(String, String)> = Default::default();
+    flatten_form_value("", &serde_json::to_value(body)?, &mut pairs);
+    Ok(serde_urlencoded::to_string(&pairs)?)
+}
+
+fn flatten_form_value(key: &str, value: &serde_json::Value, pairs: &mut Vec<(String, String)>) {
+    match value {
+        serde_json::Value::Null => {}
+        serde_json::Value::Object(o) => {
+            for (k, v) in o {
+                if key.is_empty() {
+                    flatten_form_value(k, v, pairs);
+                } else {
+                    flatten_form_value(&format!("{}[{}]", key, k), v, pairs);
+                }
+            }
+        }
+        serde_json::Value::Array(a) => {
+            for (i, v) in a.iter().enumerate() {
+                flatten_form_value(&format!("{}[{}]", key, i), v, pairs);
+            }
+        }
+        serde_json::Value::String(s) => pairs.push((key.to_string(), s.to_string())),
+        v => pairs.push((key.to_string(), v.to_string())),
+    }
+}
+
 
 pub mod google_calendar_date_time_format {
     use chrono::{DateTime, Utc};
@@ -643,6 +689,29 @@ pub mod deserialize_null_vector {
         Ok(Default::default())
     }
 }
+
+pub mod deserialize_null_map {
+    use serde::{self, Deserialize, Deserializer};
+
+    // The signature of a deserialize_with function must follow the pattern:
+    //
+    //    fn deserialize<'de, D>(D) -> Result<T, D::Error>
+    //    where
+    //        D: Deserializer<'de>
+    //
+    // although it may also be generic over the output types T.
+    pub fn deserialize<'de, V, D>(
+        deserializer: D,
+    ) -> Result<std::collections::BTreeMap<String, V>, D::Error>
+    where
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let m = Option::deserialize(deserializer)?.unwrap_or_default();
+
+        Ok(m)
+    }
+}
 "#;
 
 const GITHUB_TEMPLATE: &str = r#"//const X_GITHUB_REQUEST_ID: &str = "x-github-request-id";
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Gifs {
     pub client: Client,
@@ -22,11 +20,14 @@ impl Gifs {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Filters results by specified GIF IDs, separated by commas.
+     * * `params: &GifsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(&self, ids: &str) -> Result<crate::types::GetGifsByResponse> {
+    pub async fn get_with(
+        &self,
+        params: &GifsGetParams,
+    ) -> Result<crate::types::GetGifsByResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -35,6 +36,17 @@ impl Gifs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `GifsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(&self, ids: &str) -> Result<crate::types::GetGifsByResponse> {
+        let params = GifsGetParams {
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Random GIF.
      *
@@ -45,15 +57,17 @@ impl Gifs {
      *
      * **Parameters:**
      *
-     * * `tag: &str` -- The unique bit.ly URL for this GIF.
-     * * `rating: &str` -- The unique bit.ly URL for this GIF.
+     * * `params: &GifsRandomParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn random(&self, tag: &str, rating: &str) -> Result<crate::types::RandomGifResponse> {
+    pub async fn random_with(
+        &self,
+        params: &GifsRandomParams,
+    ) -> Result<crate::types::RandomGifResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !rating.is_empty() {
+        if let Some(rating) = &params.rating {
             query_args.push(("rating".to_string(), rating.to_string()));
         }
-        if !tag.is_empty() {
+        if let Some(tag) = &params.tag {
             query_args.push(("tag".to_string(), tag.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -62,6 +76,18 @@ impl Gifs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `random_with` and pass the query parameters as a `GifsRandomParams` instead.
+     */
+    #[deprecated(note = "use `random_with` instead")]
+    pub async fn random(&self, tag: &str, rating: &str) -> Result<crate::types::RandomGifResponse> {
+        let params = GifsRandomParams {
+            rating: Some(rating.to_string()).filter(|s| !s.is_empty()),
+            tag: Some(tag.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.random_with(&params).await
+    }
+
     /**
      * Search GIFs.
      *
@@ -72,34 +98,26 @@ impl Gifs {
      *
      * **Parameters:**
      *
-     * * `q: &str` -- The unique bit.ly URL for this GIF.
-     * * `limit: i64` -- The maximum number of records to return.
-     * * `offset: i64` -- An optional results offset.
-     * * `rating: &str` -- The unique bit.ly URL for this GIF.
-     * * `lang: &str` -- Specify default language for regional content; use a 2-letter ISO 639-1 language code.
+     * * `params: &GifsSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn search(
+    pub async fn search_with(
         &self,
-        q: &str,
-        limit: i64,
-        offset: i64,
-        rating: &str,
-        lang: &str,
-    ) -> Result<crate::types::GetGifsByResponse> {
+        params: &GifsSearchParams,
+    ) -> Result<crate::types::SearchGifsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !lang.is_empty() {
+        if let Some(lang) = &params.lang {
             query_args.push(("lang".to_string(), lang.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !rating.is_empty() {
+        if let Some(rating) = &params.rating {
             query_args.push(("rating".to_string(), rating.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -108,6 +126,28 @@ impl Gifs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `search_with` and pass the query parameters as a `GifsSearchParams` instead.
+     */
+    #[deprecated(note = "use `search_with` instead")]
+    pub async fn search(
+        &self,
+        q: &str,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+        lang: &str,
+    ) -> Result<crate::types::SearchGifsResponse> {
+        let params = GifsSearchParams {
+            lang: Some(lang.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            rating: Some(rating.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.search_with(&params).await
+    }
+
     /**
      * Translate phrase to GIF.
      *
@@ -118,11 +158,14 @@ impl Gifs {
      *
      * **Parameters:**
      *
-     * * `s: &str` -- The unique bit.ly URL for this GIF.
+     * * `params: &GifsTranslateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn translate(&self, s: &str) -> Result<crate::types::RandomGifResponse> {
+    pub async fn translate_with(
+        &self,
+        params: &GifsTranslateParams,
+    ) -> Result<crate::types::TranslateGifResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !s.is_empty() {
+        if let Some(s) = &params.s {
             query_args.push(("s".to_string(), s.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -131,6 +174,17 @@ impl Gifs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `translate_with` and pass the query parameters as a `GifsTranslateParams` instead.
+     */
+    #[deprecated(note = "use `translate_with` instead")]
+    pub async fn translate(&self, s: &str) -> Result<crate::types::TranslateGifResponse> {
+        let params = GifsTranslateParams {
+            s: Some(s.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.translate_with(&params).await
+    }
+
     /**
      * Trending GIFs.
      *
@@ -141,24 +195,20 @@ impl Gifs {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- The maximum number of records to return.
-     * * `offset: i64` -- An optional results offset.
-     * * `rating: &str` -- The unique bit.ly URL for this GIF.
+     * * `params: &GifsTrendingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn trending(
+    pub async fn trending_with(
         &self,
-        limit: i64,
-        offset: i64,
-        rating: &str,
-    ) -> Result<crate::types::GetGifsByResponse> {
+        params: &GifsTrendingParams,
+    ) -> Result<crate::types::TrendingGifsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !rating.is_empty() {
+        if let Some(rating) = &params.rating {
             query_args.push(("rating".to_string(), rating.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -167,6 +217,24 @@ impl Gifs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `trending_with` and pass the query parameters as a `GifsTrendingParams` instead.
+     */
+    #[deprecated(note = "use `trending_with` instead")]
+    pub async fn trending(
+        &self,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+    ) -> Result<crate::types::TrendingGifsResponse> {
+        let params = GifsTrendingParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            rating: Some(rating.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.trending_with(&params).await
+    }
+
     /**
      * Get GIF by Id.
      *
@@ -179,7 +247,7 @@ impl Gifs {
      *
      * * `gif_id: i64` -- Filters results by specified GIF ID.
      */
-    pub async fn get_gifs(&self, gif_id: i64) -> Result<crate::types::RandomGifResponse> {
+    pub async fn get_gifs(&self, gif_id: i64) -> Result<crate::types::GetGifByResponse> {
         let url = format!(
             "/gifs/{}",
             crate::progenitor_support::encode_path(&gif_id.to_string()),
@@ -188,3 +256,197 @@ impl Gifs {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Gifs::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GifsGetParams {
+    /// Filters results by specified GIF IDs, separated by commas.
+    pub ids: Option<String>,
+}
+
+impl GifsGetParams {
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Gifs::random_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GifsRandomParams {
+    /// The unique bit.ly URL for this GIF.
+    pub rating: Option<String>,
+    /// The unique bit.ly URL for this GIF.
+    pub tag: Option<String>,
+}
+
+impl GifsRandomParams {
+    pub fn rating<T: ToString>(mut self, rating: T) -> Self {
+        self.rating = Some(rating.to_string());
+        self
+    }
+
+    pub fn tag<T: ToString>(mut self, tag: T) -> Self {
+        self.tag = Some(tag.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Gifs::search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GifsSearchParams {
+    /// Specify default language for regional content; use a 2-letter ISO 639-1 language code.
+    pub lang: Option<String>,
+    /// The maximum number of records to return.
+    pub limit: Option<i64>,
+    /// An optional results offset.
+    pub offset: Option<i64>,
+    /// The unique bit.ly URL for this GIF.
+    pub q: Option<String>,
+    /// The unique bit.ly URL for this GIF.
+    pub rating: Option<String>,
+}
+
+impl GifsSearchParams {
+    pub fn lang<T: ToString>(mut self, lang: T) -> Self {
+        self.lang = Some(lang.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn rating<T: ToString>(mut self, rating: T) -> Self {
+        self.rating = Some(rating.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Gifs::translate_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GifsTranslateParams {
+    /// The unique bit.ly URL for this GIF.
+    pub s: Option<String>,
+}
+
+impl GifsTranslateParams {
+    pub fn s<T: ToString>(mut self, s: T) -> Self {
+        self.s = Some(s.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Gifs::trending_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GifsTrendingParams {
+    /// The maximum number of records to return.
+    pub limit: Option<i64>,
+    /// An optional results offset.
+    pub offset: Option<i64>,
+    /// The unique bit.ly URL for this GIF.
+    pub rating: Option<String>,
+}
+
+impl GifsTrendingParams {
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn rating<T: ToString>(mut self, rating: T) -> Self {
+        self.rating = Some(rating.to_string());
+        self
+    }
+}
+
+/// The functions of [`Gifs`], for code that takes them as a trait, so that it can be
+/// tested with the `MockGifsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait GifsApi: Send + Sync {
+    /// See [`Gifs::get_with`].
+    async fn get_with(&self, params: &GifsGetParams) -> Result<crate::types::GetGifsByResponse>;
+
+    /// See [`Gifs::random_with`].
+    async fn random_with(
+        &self,
+        params: &GifsRandomParams,
+    ) -> Result<crate::types::RandomGifResponse>;
+
+    /// See [`Gifs::search_with`].
+    async fn search_with(
+        &self,
+        params: &GifsSearchParams,
+    ) -> Result<crate::types::SearchGifsResponse>;
+
+    /// See [`Gifs::translate_with`].
+    async fn translate_with(
+        &self,
+        params: &GifsTranslateParams,
+    ) -> Result<crate::types::TranslateGifResponse>;
+
+    /// See [`Gifs::trending_with`].
+    async fn trending_with(
+        &self,
+        params: &GifsTrendingParams,
+    ) -> Result<crate::types::TrendingGifsResponse>;
+
+    /// See [`Gifs::get_gifs`].
+    async fn get_gifs(&self, gif_id: i64) -> Result<crate::types::GetGifByResponse>;
+}
+
+#[async_trait::async_trait]
+impl GifsApi for Gifs {
+    async fn get_with(&self, params: &GifsGetParams) -> Result<crate::types::GetGifsByResponse> {
+        Gifs::get_with(self, params).await
+    }
+
+    async fn random_with(
+        &self,
+        params: &GifsRandomParams,
+    ) -> Result<crate::types::RandomGifResponse> {
+        Gifs::random_with(self, params).await
+    }
+
+    async fn search_with(
+        &self,
+        params: &GifsSearchParams,
+    ) -> Result<crate::types::SearchGifsResponse> {
+        Gifs::search_with(self, params).await
+    }
+
+    async fn translate_with(
+        &self,
+        params: &GifsTranslateParams,
+    ) -> Result<crate::types::TranslateGifResponse> {
+        Gifs::translate_with(self, params).await
+    }
+
+    async fn trending_with(
+        &self,
+        params: &GifsTrendingParams,
+    ) -> Result<crate::types::TrendingGifsResponse> {
+        Gifs::trending_with(self, params).await
+    }
+
+    async fn get_gifs(&self, gif_id: i64) -> Result<crate::types::GetGifByResponse> {
+        Gifs::get_gifs(self, gif_id).await
+    }
+}
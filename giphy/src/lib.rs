@@ -24,6 +24,30 @@
 //! up to date as features are added. The documentation for the crate is generated
 //! along with the code to make this library easy to use.
 //!
+//! Each tag of the API is a cargo feature of its own, all of them enabled by the
+//! default `full` feature. To build only the tags you use, turn off the default
+//! features and enable those tags, along with `rustls-tls` or `native-tls`.
+//!
+//! The functions of each tag are also a trait, named after the tag, like `UsersApi`
+//! for `users`. Code that takes the trait can be tested with the mocks of the `mock`
+//! feature, like `MockUsersApi`, rather than a server.
+//!
+//! Requests wait out the rate limits of the API. Those that are safe to send twice
+//! are sent again after the `Retry-After` of rate limited responses, and all of them
+//! wait for the limit to reset when none of it is left. `Client::rate_limit_status` tells what the API last said about its
+//! limits.
+//!
+//! Requests that time out, can't connect or get a `5xx` that may not last are
+//! retried, waiting longer before each attempt, but only those that are safe to send
+//! twice: `GET`s, `HEAD`s, `OPTIONS` and `TRACE`s. `Client::builder().retry_policy(...)`
+//! changes how many times, how long to wait and which methods. For the APIs that take
+//! idempotency keys, the `POST`s of `Client::with_idempotency_key` are retried too.
+//!
+//! `Client::builder()` also sets the timeouts, proxies, root certificates, client
+//! certificate and `User-Agent` of the HTTP client, or takes a
+//! `reqwest_middleware::ClientWithMiddleware` of your own. Its `build` takes what
+//! `Client::new` does.
+//!
 //!
 //! To install the library, add the following to your `Cargo.toml` file.
 //!
@@ -58,11 +82,20 @@
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::nonstandard_macro_braces)]
 #![allow(clippy::large_enum_variant)]
+#![allow(clippy::result_large_err)]
 #![allow(clippy::tabs_in_doc_comments)]
 #![allow(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+mod error;
+#[cfg(feature = "gifs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gifs")))]
 pub mod gifs;
+pub mod pagination;
+pub mod rate_limiter;
+pub mod retry;
+#[cfg(feature = "stickers")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stickers")))]
 pub mod stickers;
 #[cfg(test)]
 mod tests;
@@ -70,7 +103,7 @@ pub mod types;
 #[doc(hidden)]
 pub mod utils;
 
-use anyhow::{anyhow, Error, Result};
+pub use error::{Error, ErrorBody, Result};
 
 pub const DEFAULT_HOST: &str = "https://api.giphy.com/v1";
 
@@ -94,6 +127,176 @@ mod progenitor_support {
     }
 }
 
+/// The body of a response that is not JSON, such as a PDF, an image or an archive.
+#[derive(Debug, Clone, Default)]
+pub struct Download {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: bytes::Bytes,
+}
+
+/// The body of a response that is not JSON, returned as a stream of chunks rather
+/// than read into memory all at once.
+pub struct DownloadStream {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: std::pin::Pin<Box<dyn futures::Stream<Item = Result<bytes::Bytes>> + Send>>,
+}
+
+impl std::fmt::Debug for DownloadStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DownloadStream")
+            .field("content_type", &self.content_type)
+            .finish()
+    }
+}
+
+/// Configures the HTTP client of a `Client`, with the defaults of `Client::new` unless
+/// told otherwise. Its `build` takes what `Client::new` does.
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    retry_policy: crate::retry::RetryPolicy,
+    max_rate_limit_wait: Option<std::time::Duration>,
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    user_agent: Option<String>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    root_certificates: Vec<reqwest::Certificate>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    identity: Option<reqwest::Identity>,
+    http_client: Option<reqwest_middleware::ClientWithMiddleware>,
+}
+
+impl ClientBuilder {
+    /// Set how failed requests are retried.
+    pub fn retry_policy(mut self, retry_policy: crate::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set how long requests wait for a rate limit to reset, at most,
+    /// `rate_limiter::MAX_WAIT` unless set.
+    pub fn max_rate_limit_wait(mut self, max_wait: std::time::Duration) -> Self {
+        self.max_rate_limit_wait = Some(max_wait);
+        self
+    }
+
+    /// Set how long each attempt at a request can take, from connecting to reading the
+    /// whole response. There is no limit unless set.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set how long connecting to the API can take.
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests through a proxy, like `reqwest::Proxy::https(url)`. Without one,
+    /// the proxies of the `HTTP_PROXY` and `HTTPS_PROXY` environment variables are used.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Set the `User-Agent` of requests.
+    pub fn user_agent<U>(mut self, user_agent: U) -> Self
+    where
+        U: ToString,
+    {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Trust the certificates signed by a certificate authority, along with those of
+    /// the system, like a private one of `reqwest::Certificate::from_pem(pem)`.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Authenticate to the API with a client certificate, for mutual TLS.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn identity(mut self, identity: reqwest::Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Send requests with a client of your own, with its own middleware. The other
+    /// settings of the builder don't apply to it, and it only retries or waits out
+    /// rate limits if it has a `retry::Retry` or a `rate_limiter::RateLimiter` of its
+    /// own.
+    pub fn http_client(mut self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /*
+     * The HTTP client, along with the rate limiter in its middleware.
+     */
+    fn http(
+        self,
+    ) -> Result<(
+        reqwest_middleware::ClientWithMiddleware,
+        crate::rate_limiter::RateLimiter,
+    )> {
+        if let Some(client) = self.http_client {
+            return Ok((client, Default::default()));
+        }
+
+        let mut rate_limiter =
+            crate::rate_limiter::RateLimiter::new().with_retry_policy(self.retry_policy.clone());
+        if let Some(max_wait) = self.max_rate_limit_wait {
+            rate_limiter = rate_limiter.with_max_wait(max_wait);
+        }
+
+        let mut http = reqwest::Client::builder();
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        for proxy in self.proxies {
+            http = http.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(user_agent);
+        }
+        #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+        {
+            for certificate in self.root_certificates {
+                http = http.add_root_certificate(certificate);
+            }
+            if let Some(identity) = self.identity {
+                http = http.identity(identity);
+            }
+        }
+
+        let client = reqwest_middleware::ClientBuilder::new(http.build()?)
+            // Trace HTTP requests. See the tracing crate to make use of these traces.
+            .with(reqwest_tracing::TracingMiddleware::default())
+            // Retry failed requests.
+            .with(crate::retry::Retry::new(self.retry_policy))
+            // Wait out the rate limits of the API, before the retries see them.
+            .with(rate_limiter.clone())
+            .build();
+
+        Ok((client, rate_limiter))
+    }
+}
+
+impl Client {
+    /// Configure the client before creating it.
+    pub fn builder() -> ClientBuilder {
+        Default::default()
+    }
+}
+
 use std::env;
 
 /// Entrypoint for interacting with the API client.
@@ -103,6 +306,25 @@ pub struct Client {
     token: String,
 
     client: reqwest_middleware::ClientWithMiddleware,
+    rate_limiter: crate::rate_limiter::RateLimiter,
+}
+
+impl ClientBuilder {
+    /// Create the client, see `Client::new`.
+    pub fn build<T>(self, token: T) -> Result<Client>
+    where
+        T: ToString,
+    {
+        let (client, rate_limiter) = self.http()?;
+
+        Ok(Client {
+            host: DEFAULT_HOST.to_string(),
+            token: token.to_string(),
+
+            client,
+            rate_limiter,
+        })
+    }
 }
 
 impl Client {
@@ -113,28 +335,8 @@ impl Client {
     where
         T: ToString,
     {
-        let client = reqwest::Client::builder().build();
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        match client {
-            Ok(c) => {
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
-                    .build();
-
-                Client {
-                    host: DEFAULT_HOST.to_string(),
-                    token: token.to_string(),
-
-                    client,
-                }
-            }
+        match Self::builder().build(token) {
+            Ok(c) => c,
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
         }
     }
@@ -172,6 +374,18 @@ impl Client {
         method: reqwest::Method,
         uri: &str,
         body: Option<reqwest::Body>,
+    ) -> Result<reqwest::Response> {
+        self.request_raw_with_media_types(method, uri, body, "application/json", "application/json")
+            .await
+    }
+
+    async fn request_raw_with_media_types(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+        accept: &str,
+        content_type: &str,
     ) -> Result<reqwest::Response> {
         let u = if uri.starts_with("https://") {
             uri.to_string()
@@ -184,11 +398,11 @@ impl Client {
         // Set the default headers.
         req = req.header(
             reqwest::header::ACCEPT,
-            reqwest::header::HeaderValue::from_static("application/json"),
+            reqwest::header::HeaderValue::from_str(accept)?,
         );
         req = req.header(
             reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
+            reqwest::header::HeaderValue::from_str(content_type)?,
         );
 
         if let Some(auth_str) = auth {
@@ -204,6 +418,11 @@ impl Client {
         Ok(req.send().await?)
     }
 
+    /// The status of the rate limits of the API, as of the last responses.
+    pub fn rate_limit_status(&self) -> Vec<crate::rate_limiter::RateLimitStatus> {
+        self.rate_limiter.status()
+    }
+
     async fn request<Out>(
         &self,
         method: reqwest::Method,
@@ -216,6 +435,7 @@ impl Client {
         let response = self.request_raw(method, uri, body).await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -224,26 +444,58 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn request_form<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        form: String,
+    ) -> Result<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let response = self
+            .request_raw_with_media_types(
+                method,
+                uri,
+                Some(form.into()),
+                "application/json",
+                "application/x-www-form-urlencoded",
+            )
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let response_body = response.bytes().await?;
 
-            Err(error)
+        if status.is_success() {
+            log::debug!(
+                "response payload {}",
+                String::from_utf8_lossy(&response_body)
+            );
+            if status == http::StatusCode::NO_CONTENT
+                || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
+            {
+                crate::error::decode(b"null")
+            } else {
+                crate::error::decode::<Out>(&response_body)
+            }
+        } else {
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -259,6 +511,7 @@ impl Client {
         let response = self.request_raw(method, uri, body).await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -276,22 +529,13 @@ impl Client {
             let parsed_response = if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
+                crate::error::decode::<Out>(&response_body)
             };
-            parsed_response.map(|out| (link, out)).map_err(Error::from)
+            parsed_response.map(|out| (link, out))
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -328,6 +572,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -336,31 +581,19 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {
                 // Parse the output as a string.
-                serde_json::from_value(serde_json::json!(&String::from_utf8(
-                    response_body.to_vec()
-                )?))
+                serde_json::from_value(serde_json::json!(String::from_utf8_lossy(&response_body)))
+                    .map_err(Error::from)
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -399,6 +632,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -407,31 +641,19 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {
                 // Parse the output as a string.
-                serde_json::from_value(serde_json::json!(&String::from_utf8(
-                    response_body.to_vec()
-                )?))
+                serde_json::from_value(serde_json::json!(String::from_utf8_lossy(&response_body)))
+                    .map_err(Error::from)
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -491,6 +713,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -499,26 +722,82 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    #[allow(dead_code)]
+    async fn request_download(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::Download> {
+        let response = self
+            .request_raw_with_media_types(method, uri, body, "*/*", "application/json")
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let content_type = response
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|c| c.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            Ok(crate::Download {
+                content_type,
+                body: response_body,
+            })
+        } else {
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
 
-            Err(error)
+    #[allow(dead_code)]
+    async fn request_download_stream(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::DownloadStream> {
+        let response = self
+            .request_raw_with_media_types(method, uri, body, "*/*", "application/json")
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        if status.is_success() {
+            let content_type = response
+                .headers()
+                .get(http::header::CONTENT_TYPE)
+                .and_then(|c| c.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+
+            Ok(crate::DownloadStream {
+                content_type,
+                body: Box::pin(futures::TryStreamExt::map_err(
+                    response.bytes_stream(),
+                    Error::from,
+                )),
+            })
+        } else {
+            let response_body = response.bytes().await?;
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -638,11 +917,15 @@ impl Client {
     }
 
     /// Return a reference to an interface that provides access to gifs operations.
+    #[cfg(feature = "gifs")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "gifs")))]
     pub fn gifs(&self) -> gifs::Gifs {
         gifs::Gifs::new(self.clone())
     }
 
     /// Return a reference to an interface that provides access to stickers operations.
+    #[cfg(feature = "stickers")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stickers")))]
     pub fn stickers(&self) -> stickers::Stickers {
         stickers::Stickers::new(self.clone())
     }
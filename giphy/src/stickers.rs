@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Stickers {
     pub client: Client,
@@ -22,15 +20,17 @@ impl Stickers {
      *
      * **Parameters:**
      *
-     * * `tag: &str` -- The unique bit.ly URL for this GIF.
-     * * `rating: &str` -- The unique bit.ly URL for this GIF.
+     * * `params: &StickersRandomParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn random(&self, tag: &str, rating: &str) -> Result<crate::types::RandomGifResponse> {
+    pub async fn random_with(
+        &self,
+        params: &StickersRandomParams,
+    ) -> Result<crate::types::RandomStickerResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !rating.is_empty() {
+        if let Some(rating) = &params.rating {
             query_args.push(("rating".to_string(), rating.to_string()));
         }
-        if !tag.is_empty() {
+        if let Some(tag) = &params.tag {
             query_args.push(("tag".to_string(), tag.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -39,6 +39,22 @@ impl Stickers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `random_with` and pass the query parameters as a `StickersRandomParams` instead.
+     */
+    #[deprecated(note = "use `random_with` instead")]
+    pub async fn random(
+        &self,
+        tag: &str,
+        rating: &str,
+    ) -> Result<crate::types::RandomStickerResponse> {
+        let params = StickersRandomParams {
+            rating: Some(rating.to_string()).filter(|s| !s.is_empty()),
+            tag: Some(tag.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.random_with(&params).await
+    }
+
     /**
      * Search Stickers.
      *
@@ -49,34 +65,26 @@ impl Stickers {
      *
      * **Parameters:**
      *
-     * * `q: &str` -- The unique bit.ly URL for this GIF.
-     * * `limit: i64` -- The maximum number of records to return.
-     * * `offset: i64` -- An optional results offset.
-     * * `rating: &str` -- The unique bit.ly URL for this GIF.
-     * * `lang: &str` -- Specify default language for regional content; use a 2-letter ISO 639-1 language code.
+     * * `params: &StickersSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn search(
+    pub async fn search_with(
         &self,
-        q: &str,
-        limit: i64,
-        offset: i64,
-        rating: &str,
-        lang: &str,
-    ) -> Result<crate::types::GetGifsByResponse> {
+        params: &StickersSearchParams,
+    ) -> Result<crate::types::SearchStickersResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !lang.is_empty() {
+        if let Some(lang) = &params.lang {
             query_args.push(("lang".to_string(), lang.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !rating.is_empty() {
+        if let Some(rating) = &params.rating {
             query_args.push(("rating".to_string(), rating.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -85,6 +93,28 @@ impl Stickers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `search_with` and pass the query parameters as a `StickersSearchParams` instead.
+     */
+    #[deprecated(note = "use `search_with` instead")]
+    pub async fn search(
+        &self,
+        q: &str,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+        lang: &str,
+    ) -> Result<crate::types::SearchStickersResponse> {
+        let params = StickersSearchParams {
+            lang: Some(lang.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            rating: Some(rating.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.search_with(&params).await
+    }
+
     /**
      * Translate phrase to Sticker.
      *
@@ -95,11 +125,14 @@ impl Stickers {
      *
      * **Parameters:**
      *
-     * * `s: &str` -- The unique bit.ly URL for this GIF.
+     * * `params: &StickersTranslateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn translate(&self, s: &str) -> Result<crate::types::RandomGifResponse> {
+    pub async fn translate_with(
+        &self,
+        params: &StickersTranslateParams,
+    ) -> Result<crate::types::TranslateStickerResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !s.is_empty() {
+        if let Some(s) = &params.s {
             query_args.push(("s".to_string(), s.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -108,6 +141,17 @@ impl Stickers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `translate_with` and pass the query parameters as a `StickersTranslateParams` instead.
+     */
+    #[deprecated(note = "use `translate_with` instead")]
+    pub async fn translate(&self, s: &str) -> Result<crate::types::TranslateStickerResponse> {
+        let params = StickersTranslateParams {
+            s: Some(s.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.translate_with(&params).await
+    }
+
     /**
      * Trending Stickers.
      *
@@ -118,24 +162,20 @@ impl Stickers {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- The maximum number of records to return.
-     * * `offset: i64` -- An optional results offset.
-     * * `rating: &str` -- The unique bit.ly URL for this GIF.
+     * * `params: &StickersTrendingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn trending(
+    pub async fn trending_with(
         &self,
-        limit: i64,
-        offset: i64,
-        rating: &str,
-    ) -> Result<crate::types::GetGifsByResponse> {
+        params: &StickersTrendingParams,
+    ) -> Result<crate::types::TrendingStickersResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !rating.is_empty() {
+        if let Some(rating) = &params.rating {
             query_args.push(("rating".to_string(), rating.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -143,4 +183,188 @@ impl Stickers {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `trending_with` and pass the query parameters as a `StickersTrendingParams` instead.
+     */
+    #[deprecated(note = "use `trending_with` instead")]
+    pub async fn trending(
+        &self,
+        limit: i64,
+        offset: i64,
+        rating: &str,
+    ) -> Result<crate::types::TrendingStickersResponse> {
+        let params = StickersTrendingParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            rating: Some(rating.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.trending_with(&params).await
+    }
+}
+
+/// The query parameters of `Stickers::random_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StickersRandomParams {
+    /// The unique bit.ly URL for this GIF.
+    pub rating: Option<String>,
+    /// The unique bit.ly URL for this GIF.
+    pub tag: Option<String>,
+}
+
+impl StickersRandomParams {
+    pub fn rating<T: ToString>(mut self, rating: T) -> Self {
+        self.rating = Some(rating.to_string());
+        self
+    }
+
+    pub fn tag<T: ToString>(mut self, tag: T) -> Self {
+        self.tag = Some(tag.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Stickers::search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StickersSearchParams {
+    /// Specify default language for regional content; use a 2-letter ISO 639-1 language code.
+    pub lang: Option<String>,
+    /// The maximum number of records to return.
+    pub limit: Option<i64>,
+    /// An optional results offset.
+    pub offset: Option<i64>,
+    /// The unique bit.ly URL for this GIF.
+    pub q: Option<String>,
+    /// The unique bit.ly URL for this GIF.
+    pub rating: Option<String>,
+}
+
+impl StickersSearchParams {
+    pub fn lang<T: ToString>(mut self, lang: T) -> Self {
+        self.lang = Some(lang.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn rating<T: ToString>(mut self, rating: T) -> Self {
+        self.rating = Some(rating.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Stickers::translate_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StickersTranslateParams {
+    /// The unique bit.ly URL for this GIF.
+    pub s: Option<String>,
+}
+
+impl StickersTranslateParams {
+    pub fn s<T: ToString>(mut self, s: T) -> Self {
+        self.s = Some(s.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Stickers::trending_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StickersTrendingParams {
+    /// The maximum number of records to return.
+    pub limit: Option<i64>,
+    /// An optional results offset.
+    pub offset: Option<i64>,
+    /// The unique bit.ly URL for this GIF.
+    pub rating: Option<String>,
+}
+
+impl StickersTrendingParams {
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn rating<T: ToString>(mut self, rating: T) -> Self {
+        self.rating = Some(rating.to_string());
+        self
+    }
+}
+
+/// The functions of [`Stickers`], for code that takes them as a trait, so that it can be
+/// tested with the `MockStickersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait StickersApi: Send + Sync {
+    /// See [`Stickers::random_with`].
+    async fn random_with(
+        &self,
+        params: &StickersRandomParams,
+    ) -> Result<crate::types::RandomStickerResponse>;
+
+    /// See [`Stickers::search_with`].
+    async fn search_with(
+        &self,
+        params: &StickersSearchParams,
+    ) -> Result<crate::types::SearchStickersResponse>;
+
+    /// See [`Stickers::translate_with`].
+    async fn translate_with(
+        &self,
+        params: &StickersTranslateParams,
+    ) -> Result<crate::types::TranslateStickerResponse>;
+
+    /// See [`Stickers::trending_with`].
+    async fn trending_with(
+        &self,
+        params: &StickersTrendingParams,
+    ) -> Result<crate::types::TrendingStickersResponse>;
+}
+
+#[async_trait::async_trait]
+impl StickersApi for Stickers {
+    async fn random_with(
+        &self,
+        params: &StickersRandomParams,
+    ) -> Result<crate::types::RandomStickerResponse> {
+        Stickers::random_with(self, params).await
+    }
+
+    async fn search_with(
+        &self,
+        params: &StickersSearchParams,
+    ) -> Result<crate::types::SearchStickersResponse> {
+        Stickers::search_with(self, params).await
+    }
+
+    async fn translate_with(
+        &self,
+        params: &StickersTranslateParams,
+    ) -> Result<crate::types::TranslateStickerResponse> {
+        Stickers::translate_with(self, params).await
+    }
+
+    async fn trending_with(
+        &self,
+        params: &StickersTrendingParams,
+    ) -> Result<crate::types::TrendingStickersResponse> {
+        Stickers::trending_with(self, params).await
+    }
 }
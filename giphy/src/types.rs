@@ -2,12 +2,405 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> std::option::Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> std::convert::From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match std::option::Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        std::option::Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        std::option::Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
+
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+
+#[cfg(feature = "gifs")]
+mod gifs;
+#[cfg(feature = "gifs")]
+pub use gifs::*;
+
+#[cfg(feature = "stickers")]
+mod stickers;
+#[cfg(feature = "stickers")]
+pub use stickers::*;
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DownsizedAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DownsizedLargeAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DownsizedMediumAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DownsizedSmallAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DownsizedStillAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FixedHeightAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FixedHeightDownsampledAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FixedHeightSmallAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FixedHeightSmallStillAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FixedHeightStillAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FixedWidthAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FixedWidthDownsampledAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FixedWidthSmallAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FixedWidthSmallStillAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FixedWidthStillAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LoopingAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OriginalAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
 /// All of the following types are flattened into one object:
 ///
 /// - `Image`
 /// - `serde_json::Value`
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LoopingAllOf {
+pub struct OriginalStillAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PreviewAllOf {
+    #[serde(flatten)]
+    pub image: Image,
+    /**
+     * Data surrounding a version of this GIF downsized to be under 2mb.
+     */
+    #[serde(flatten)]
+    pub value: serde_json::Value,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `Image`
+/// - `serde_json::Value`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PreviewGifAllOf {
     #[serde(flatten)]
     pub image: Image,
     /**
@@ -24,77 +417,77 @@ pub struct Images {
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub downsized: Option<LoopingAllOf>,
+    pub downsized: Option<DownsizedAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub downsized_large: Option<LoopingAllOf>,
+    pub downsized_large: Option<DownsizedLargeAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub downsized_medium: Option<LoopingAllOf>,
+    pub downsized_medium: Option<DownsizedMediumAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub downsized_small: Option<LoopingAllOf>,
+    pub downsized_small: Option<DownsizedSmallAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub downsized_still: Option<LoopingAllOf>,
+    pub downsized_still: Option<DownsizedStillAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fixed_height: Option<LoopingAllOf>,
+    pub fixed_height: Option<FixedHeightAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fixed_height_downsampled: Option<LoopingAllOf>,
+    pub fixed_height_downsampled: Option<FixedHeightDownsampledAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fixed_height_small: Option<LoopingAllOf>,
+    pub fixed_height_small: Option<FixedHeightSmallAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fixed_height_small_still: Option<LoopingAllOf>,
+    pub fixed_height_small_still: Option<FixedHeightSmallStillAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fixed_height_still: Option<LoopingAllOf>,
+    pub fixed_height_still: Option<FixedHeightStillAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fixed_width: Option<LoopingAllOf>,
+    pub fixed_width: Option<FixedWidthAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fixed_width_downsampled: Option<LoopingAllOf>,
+    pub fixed_width_downsampled: Option<FixedWidthDownsampledAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fixed_width_small: Option<LoopingAllOf>,
+    pub fixed_width_small: Option<FixedWidthSmallAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fixed_width_small_still: Option<LoopingAllOf>,
+    pub fixed_width_small_still: Option<FixedWidthSmallStillAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fixed_width_still: Option<LoopingAllOf>,
+    pub fixed_width_still: Option<FixedWidthStillAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
@@ -104,42 +497,79 @@ pub struct Images {
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub original: Option<LoopingAllOf>,
+    pub original: Option<OriginalAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub original_still: Option<LoopingAllOf>,
+    pub original_still: Option<OriginalStillAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub preview: Option<LoopingAllOf>,
+    pub preview: Option<PreviewAllOf>,
     /**
      * An object containing data for various available formats and sizes of this GIF.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub preview_gif: Option<LoopingAllOf>,
+    pub preview_gif: Option<PreviewGifAllOf>,
 }
 
 /**
  * Type of the gif. By default, this is almost always gif
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "gif")]
     Gif,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        match self {
+            Type::Gif => "gif",
+            Type::Unknown(s) => s,
+        }
+    }
 }
 
 impl std::fmt::Display for Type {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Type::Gif => "gif",
-            Type::FallthroughString => "*",
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "gif" => Type::Gif,
+            _ => Type::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(v: Type) -> Self {
+        match v {
+            Type::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -149,180 +579,6 @@ impl Default for Type {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Gif {
-    /**
-     * The unique bit.ly URL for this GIF
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub bitly_url: String,
-    /**
-     * The unique bit.ly URL for this GIF
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub content_url: String,
-    /**
-     * The date this GIF was added to the GIPHY database.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub create_datetime: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The unique bit.ly URL for this GIF
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub embded_url: String,
-    /**
-     * An array of featured tags for this GIF (Note: Not available when using the Public Beta Key)
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub featured_tags: Vec<String>,
-    /**
-     * The unique bit.ly URL for this GIF
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * An object containing data for various available formats and sizes of this GIF.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub images: Option<Images>,
-    /**
-     * The date this GIF was added to the GIPHY database.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub import_datetime: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The unique bit.ly URL for this GIF
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub rating: String,
-    /**
-     * The unique bit.ly URL for this GIF
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub slug: String,
-    /**
-     * The unique bit.ly URL for this GIF
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub source: String,
-    /**
-     * The unique bit.ly URL for this GIF
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub source_post_url: String,
-    /**
-     * The unique bit.ly URL for this GIF
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub source_tld: String,
-    /**
-     * An array of featured tags for this GIF (Note: Not available when using the Public Beta Key)
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub tags: Vec<String>,
-    /**
-     * The date this GIF was added to the GIPHY database.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub trending_datetime: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Type of the gif. By default, this is almost always gif
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<Type>,
-    /**
-     * The date this GIF was added to the GIPHY database.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub update_datetime: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The unique bit.ly URL for this GIF
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub url: String,
-    /**
-     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub user: Option<User>,
-    /**
-     * The unique bit.ly URL for this GIF
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-}
-
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Image {
     /**
@@ -479,96 +735,3 @@ pub struct Pagination {
     )]
     pub total_count: i64,
 }
-
-/// The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
-#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct User {
-    /**
-     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub avatar_url: String,
-    /**
-     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub banner_url: String,
-    /**
-     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub display_name: String,
-    /**
-     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub profile_url: String,
-    /**
-     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub twitter: String,
-    /**
-     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetGifsByResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub data: Vec<Gif>,
-    /**
-     * The Meta Object contains basic information regarding the request, whether it was successful, and the response given by the API.  Check `responses` to see a description of types of response codes the API might give you under different cirumstances.
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub meta: Option<Meta>,
-    /**
-     * The Pagination Object contains information relating to the number of total results available as well as the number of results fetched and their relative positions.
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub pagination: Option<Pagination>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct RandomGifResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub data: Option<Gif>,
-    /**
-     * The Meta Object contains basic information regarding the request, whether it was successful, and the response given by the API.  Check `responses` to see a description of types of response codes the API might give you under different cirumstances.
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub meta: Option<Meta>,
-}
@@ -0,0 +1,340 @@
+//! The data types of the `gifs` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Gif {
+    /**
+     * The unique bit.ly URL for this GIF
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub bitly_url: String,
+    /**
+     * The unique bit.ly URL for this GIF
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content_url: String,
+    /**
+     * The date this GIF was added to the GIPHY database.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub create_datetime: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The unique bit.ly URL for this GIF
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub embded_url: String,
+    /**
+     * An array of featured tags for this GIF (Note: Not available when using the Public Beta Key)
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub featured_tags: Vec<String>,
+    /**
+     * The unique bit.ly URL for this GIF
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * An object containing data for various available formats and sizes of this GIF.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub images: Option<Images>,
+    /**
+     * The date this GIF was added to the GIPHY database.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub import_datetime: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The unique bit.ly URL for this GIF
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub rating: String,
+    /**
+     * The unique bit.ly URL for this GIF
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub slug: String,
+    /**
+     * The unique bit.ly URL for this GIF
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub source: String,
+    /**
+     * The unique bit.ly URL for this GIF
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub source_post_url: String,
+    /**
+     * The unique bit.ly URL for this GIF
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub source_tld: String,
+    /**
+     * An array of featured tags for this GIF (Note: Not available when using the Public Beta Key)
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub tags: Vec<String>,
+    /**
+     * The date this GIF was added to the GIPHY database.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub trending_datetime: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Type of the gif. By default, this is almost always gif
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<Type>,
+    /**
+     * The date this GIF was added to the GIPHY database.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub update_datetime: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The unique bit.ly URL for this GIF
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    /**
+     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    /**
+     * The unique bit.ly URL for this GIF
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub username: String,
+}
+
+/// The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct User {
+    /**
+     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub avatar_url: String,
+    /**
+     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub banner_url: String,
+    /**
+     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub display_name: String,
+    /**
+     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub profile_url: String,
+    /**
+     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub twitter: String,
+    /**
+     * The User Object contains information about the user associated with a GIF and URLs to assets such as that user's avatar image, profile, and more.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub username: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetGifsByResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data: Vec<Gif>,
+    /**
+     * The Meta Object contains basic information regarding the request, whether it was successful, and the response given by the API.  Check `responses` to see a description of types of response codes the API might give you under different cirumstances.
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+    /**
+     * The Pagination Object contains information relating to the number of total results available as well as the number of results fetched and their relative positions.
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RandomGifResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Gif>,
+    /**
+     * The Meta Object contains basic information regarding the request, whether it was successful, and the response given by the API.  Check `responses` to see a description of types of response codes the API might give you under different cirumstances.
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SearchGifsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data: Vec<Gif>,
+    /**
+     * The Meta Object contains basic information regarding the request, whether it was successful, and the response given by the API.  Check `responses` to see a description of types of response codes the API might give you under different cirumstances.
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+    /**
+     * The Pagination Object contains information relating to the number of total results available as well as the number of results fetched and their relative positions.
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TranslateGifResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Gif>,
+    /**
+     * The Meta Object contains basic information regarding the request, whether it was successful, and the response given by the API.  Check `responses` to see a description of types of response codes the API might give you under different cirumstances.
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TrendingGifsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data: Vec<Gif>,
+    /**
+     * The Meta Object contains basic information regarding the request, whether it was successful, and the response given by the API.  Check `responses` to see a description of types of response codes the API might give you under different cirumstances.
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+    /**
+     * The Pagination Object contains information relating to the number of total results available as well as the number of results fetched and their relative positions.
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetGifByResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<Gif>,
+    /**
+     * The Meta Object contains basic information regarding the request, whether it was successful, and the response given by the API.  Check `responses` to see a description of types of response codes the API might give you under different cirumstances.
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+}
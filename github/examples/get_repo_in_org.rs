@@ -4,6 +4,7 @@ use std::env;
 use octorust::http_cache::FileBasedCache;
 use octorust::{
     auth::{Credentials, InstallationTokenGenerator, JWTCredentials},
+    retry::{Retry, RetryPolicy},
     Client,
 };
 
@@ -25,15 +26,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let jwt = JWTCredentials::new(app_id, key.data).unwrap();
 
     let http = reqwest::Client::builder().build()?;
-    let retry_policy =
-        reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
     let client = reqwest_middleware::ClientBuilder::new(http)
         // Trace HTTP requests. See the tracing crate to make use of these traces.
         .with(reqwest_tracing::TracingMiddleware::default())
         // Retry failed requests.
-        .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
-            retry_policy,
-        ))
+        .with(Retry::new(RetryPolicy::default()))
         .build();
 
     // Create the HTTP cache.
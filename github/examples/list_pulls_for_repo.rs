@@ -4,7 +4,9 @@ use std::env;
 use octorust::http_cache::FileBasedCache;
 use octorust::{
     auth::{Credentials, InstallationTokenGenerator, JWTCredentials},
-    types::IssuesListState,
+    pulls::PullsListParams,
+    retry::{Retry, RetryPolicy},
+    types::PullsListState,
     Client,
 };
 
@@ -26,15 +28,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let jwt = JWTCredentials::new(app_id, key.data).unwrap();
 
     let http = reqwest::Client::builder().build()?;
-    let retry_policy =
-        reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
     let client = reqwest_middleware::ClientBuilder::new(http)
         // Trace HTTP requests. See the tracing crate to make use of these traces.
         .with(reqwest_tracing::TracingMiddleware::default())
         // Retry failed requests.
-        .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
-            retry_policy,
-        ))
+        .with(Retry::new(RetryPolicy::default()))
         .build();
 
     // Create the HTTP cache.
@@ -67,14 +65,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // List the repos for an org.
     let repos = github
         .pulls()
-        .list_all(
+        .list_all_with(
             "oxidecomputer",
             "rfd",
-            IssuesListState::All,
-            "",                 // head
-            "",                 // base
-            Default::default(), // sort
-            Default::default(), // direction
+            &PullsListParams::default().state(PullsListState::All),
         )
         .await
         .unwrap();
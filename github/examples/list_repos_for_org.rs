@@ -4,7 +4,9 @@ use std::env;
 use octorust::http_cache::FileBasedCache;
 use octorust::{
     auth::{Credentials, InstallationTokenGenerator, JWTCredentials},
-    types::{Order, ReposListOrgSort, ReposListOrgType},
+    repos::ReposListForOrgParams,
+    retry::{Retry, RetryPolicy},
+    types::{Direction, ReposListOrgSort, ReposListOrgType},
     Client,
 };
 
@@ -36,15 +38,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let token_generator = InstallationTokenGenerator::new(app_installation_id, jwt);
 
     let http = reqwest::Client::builder().build()?;
-    let retry_policy =
-        reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
     let client = reqwest_middleware::ClientBuilder::new(http)
         // Trace HTTP requests. See the tracing crate to make use of these traces.
         .with(reqwest_tracing::TracingMiddleware::default())
         // Retry failed requests.
-        .with(reqwest_retry::RetryTransientMiddleware::new_with_policy(
-            retry_policy,
-        ))
+        .with(Retry::new(RetryPolicy::default()))
         .build();
 
     #[cfg(not(feature = "httpcache"))]
@@ -67,11 +65,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // List the repos for an org.
     let repos = github
         .repos()
-        .list_all_for_org(
+        .list_all_for_org_with(
             "oxidecomputer",
-            ReposListOrgType::All,
-            ReposListOrgSort::Created,
-            Order::Desc,
+            &ReposListForOrgParams::default()
+                .type_(ReposListOrgType::All)
+                .sort(ReposListOrgSort::Created)
+                .direction(Direction::Desc),
         )
         .await
         .unwrap();
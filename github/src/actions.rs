@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Actions {
     pub client: Client,
@@ -85,21 +83,19 @@ impl Actions {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_selected_repositories_enabled_github_actions_organization(
+    pub async fn list_selected_repositories_enabled_github_actions_organization_with(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationParams,
     ) -> Result<crate::types::ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse>
     {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -112,6 +108,27 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_selected_repositories_enabled_github_actions_organization_with` and pass the query parameters as a `ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationParams` instead.
+     */
+    #[deprecated(
+        note = "use `list_selected_repositories_enabled_github_actions_organization_with` instead"
+    )]
+    pub async fn list_selected_repositories_enabled_github_actions_organization(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse>
+    {
+        let params = ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_selected_repositories_enabled_github_actions_organization_with(org, &params)
+            .await
+    }
+
     /**
      * Set selected repositories enabled for GitHub Actions in an organization.
      *
@@ -130,7 +147,7 @@ impl Actions {
     pub async fn set_selected_repositories_enabled_github_actions_organization(
         &self,
         org: &str,
-        body: &crate::types::ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest,
+        body: &crate::types::ActionsSetSelectedRepositoriesEnabledGithubOrganizationRequest,
     ) -> Result<()> {
         let url = format!(
             "/orgs/{}/actions/permissions/repositories",
@@ -279,20 +296,18 @@ impl Actions {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListSelfHostedRunnerGroupsForOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_self_hosted_runner_groups_for_org(
+    pub async fn list_self_hosted_runner_groups_for_org_with(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListSelfHostedRunnerGroupsForOrgParams,
     ) -> Result<crate::types::ActionsListSelfHostedRunnerGroupsOrgResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -305,6 +320,24 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_self_hosted_runner_groups_for_org_with` and pass the query parameters as a `ActionsListSelfHostedRunnerGroupsForOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_self_hosted_runner_groups_for_org_with` instead")]
+    pub async fn list_self_hosted_runner_groups_for_org(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActionsListSelfHostedRunnerGroupsOrgResponse> {
+        let params = ActionsListSelfHostedRunnerGroupsForOrgParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_self_hosted_runner_groups_for_org_with(org, &params)
+            .await
+    }
+
     /**
      * Create a self-hosted runner group for an organization.
      *
@@ -453,21 +486,19 @@ impl Actions {
      *
      * * `org: &str`
      * * `runner_group_id: i64` -- Unique identifier of the self-hosted runner group.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
+     * * `params: &ActionsListRepoAccessToSelfHostedRunnerGroupInOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_repo_access_to_self_hosted_runner_group_in_org(
+    pub async fn list_repo_access_to_self_hosted_runner_group_in_org_with(
         &self,
         org: &str,
         runner_group_id: i64,
-        page: i64,
-        per_page: i64,
+        params: &ActionsListRepoAccessToSelfHostedRunnerGroupInOrgParams,
     ) -> Result<crate::types::ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -481,6 +512,25 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_repo_access_to_self_hosted_runner_group_in_org_with` and pass the query parameters as a `ActionsListRepoAccessToSelfHostedRunnerGroupInOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_repo_access_to_self_hosted_runner_group_in_org_with` instead")]
+    pub async fn list_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        page: i64,
+        per_page: i64,
+    ) -> Result<crate::types::ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse> {
+        let params = ActionsListRepoAccessToSelfHostedRunnerGroupInOrgParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_repo_access_to_self_hosted_runner_group_in_org_with(org, runner_group_id, &params)
+            .await
+    }
+
     /**
      * Set repository access for a self-hosted runner group in an organization.
      *
@@ -606,21 +656,19 @@ impl Actions {
      *
      * * `org: &str`
      * * `runner_group_id: i64` -- Unique identifier of the self-hosted runner group.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListSelfHostedRunnersInGroupForOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_self_hosted_runners_in_group_for_org(
+    pub async fn list_self_hosted_runners_in_group_for_org_with(
         &self,
         org: &str,
         runner_group_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListSelfHostedRunnersInGroupForOrgParams,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -634,6 +682,25 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_self_hosted_runners_in_group_for_org_with` and pass the query parameters as a `ActionsListSelfHostedRunnersInGroupForOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_self_hosted_runners_in_group_for_org_with` instead")]
+    pub async fn list_self_hosted_runners_in_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse> {
+        let params = ActionsListSelfHostedRunnersInGroupForOrgParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_self_hosted_runners_in_group_for_org_with(org, runner_group_id, &params)
+            .await
+    }
+
     /**
      * Set self-hosted runners in a group for an organization.
      *
@@ -756,20 +823,18 @@ impl Actions {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListSelfHostedRunnersForOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_self_hosted_runners_for_org(
+    pub async fn list_self_hosted_runners_for_org_with(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListSelfHostedRunnersForOrgParams,
     ) -> Result<crate::types::ActionsListSelfHostedRunnersOrgResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -782,6 +847,24 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_self_hosted_runners_for_org_with` and pass the query parameters as a `ActionsListSelfHostedRunnersForOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_self_hosted_runners_for_org_with` instead")]
+    pub async fn list_self_hosted_runners_for_org(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActionsListSelfHostedRunnersOrgResponse> {
+        let params = ActionsListSelfHostedRunnersForOrgParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_self_hosted_runners_for_org_with(org, &params)
+            .await
+    }
+
     /**
      * List runner applications for an organization.
      *
@@ -834,6 +917,48 @@ impl Actions {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List runner applications for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists binaries for the runner application that you can download and run.
+     *
+     * You must authenticate using an access token with the `admin:org` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-an-organization>
+     */
+    pub fn list_runner_applications_for_org_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RunnerApplication> {
+        let url = format!(
+            "/orgs/{}/actions/runners/downloads",
+            crate::progenitor_support::encode_path(org),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a registration token for an organization.
      *
@@ -977,20 +1102,18 @@ impl Actions {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListOrgSecretsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_org_secrets(
+    pub async fn list_org_secrets_with(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListOrgSecretsParams,
     ) -> Result<crate::types::ActionsListOrgSecretsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1003,6 +1126,23 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_org_secrets_with` and pass the query parameters as a `ActionsListOrgSecretsParams` instead.
+     */
+    #[deprecated(note = "use `list_org_secrets_with` instead")]
+    pub async fn list_org_secrets(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActionsListOrgSecretsResponse> {
+        let params = ActionsListOrgSecretsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_org_secrets_with(org, &params).await
+    }
+
     /**
      * Get an organization public key.
      *
@@ -1146,7 +1286,7 @@ impl Actions {
         org: &str,
         secret_name: &str,
         body: &crate::types::ActionsCreateUpdateOrgSecretRequest,
-    ) -> Result<()> {
+    ) -> Result<crate::types::EmptyObject> {
         let url = format!(
             "/orgs/{}/actions/secrets/{}",
             crate::progenitor_support::encode_path(org),
@@ -1195,21 +1335,19 @@ impl Actions {
      *
      * * `org: &str`
      * * `secret_name: &str` -- secret_name parameter.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
+     * * `params: &ActionsListSelectedReposForOrgSecretParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_selected_repos_for_org_secret(
+    pub async fn list_selected_repos_for_org_secret_with(
         &self,
         org: &str,
         secret_name: &str,
-        page: i64,
-        per_page: i64,
+        params: &ActionsListSelectedReposForOrgSecretParams,
     ) -> Result<crate::types::ActionsListSelectedReposOrgSecretResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1223,6 +1361,25 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_selected_repos_for_org_secret_with` and pass the query parameters as a `ActionsListSelectedReposForOrgSecretParams` instead.
+     */
+    #[deprecated(note = "use `list_selected_repos_for_org_secret_with` instead")]
+    pub async fn list_selected_repos_for_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        page: i64,
+        per_page: i64,
+    ) -> Result<crate::types::ActionsListSelectedReposOrgSecretResponse> {
+        let params = ActionsListSelectedReposForOrgSecretParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_selected_repos_for_org_secret_with(org, secret_name, &params)
+            .await
+    }
+
     /**
      * Set selected repositories for an organization secret.
      *
@@ -1329,21 +1486,19 @@ impl Actions {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListArtifactsForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_artifacts_for_repo(
+    pub async fn list_artifacts_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListArtifactsForRepoParams,
     ) -> Result<crate::types::ActionsListArtifactsRepoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1357,6 +1512,25 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_artifacts_for_repo_with` and pass the query parameters as a `ActionsListArtifactsForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_artifacts_for_repo_with` instead")]
+    pub async fn list_artifacts_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActionsListArtifactsRepoResponse> {
+        let params = ActionsListArtifactsForRepoParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_artifacts_for_repo_with(owner, repo, &params)
+            .await
+    }
+
     /**
      * Get an artifact.
      *
@@ -1439,7 +1613,7 @@ impl Actions {
         repo: &str,
         artifact_id: i64,
         archive_format: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
         let url = format!(
             "/repos/{}/{}/actions/artifacts/{}/{}",
             crate::progenitor_support::encode_path(owner),
@@ -1448,7 +1622,43 @@ impl Actions {
             crate::progenitor_support::encode_path(archive_format),
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Download an artifact.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/artifacts/{artifact_id}/{archive_format}` endpoint.
+     *
+     * As opposed to `download_artifact`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Gets a redirect URL to download an archive for a repository. This URL expires after 1 minute. Look for `Location:` in
+     * the response header to find the URL for the download. The `:archive_format` must be `zip`. Anyone with read access to
+     * the repository can use this endpoint. If the repository is private you must use an access token with the `repo` scope.
+     * GitHub Apps must have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#download-an-artifact>
+     */
+    pub async fn download_artifact_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+        archive_format: &str,
+    ) -> Result<crate::DownloadStream> {
+        let url = format!(
+            "/repos/{}/{}/actions/artifacts/{}/{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&artifact_id.to_string()),
+            crate::progenitor_support::encode_path(archive_format),
+        );
+
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
     }
 
     /**
@@ -1505,7 +1715,7 @@ impl Actions {
         owner: &str,
         repo: &str,
         job_id: i64,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
         let url = format!(
             "/repos/{}/{}/actions/jobs/{}/logs",
             crate::progenitor_support::encode_path(owner),
@@ -1513,7 +1723,41 @@ impl Actions {
             crate::progenitor_support::encode_path(&job_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Download job logs for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/jobs/{job_id}/logs` endpoint.
+     *
+     * As opposed to `download_job_logs_for_workflow_run`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Gets a redirect URL to download a plain text file of logs for a workflow job. This link expires after 1 minute. Look
+     * for `Location:` in the response header to find the URL for the download. Anyone with read access to the repository can
+     * use this endpoint. If the repository is private you must use an access token with the `repo` scope. GitHub Apps must
+     * have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#download-job-logs-for-a-workflow-run>
+     */
+    pub async fn download_job_logs_for_workflow_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> Result<crate::DownloadStream> {
+        let url = format!(
+            "/repos/{}/{}/actions/jobs/{}/logs",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&job_id.to_string()),
+        );
+
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
     }
 
     /**
@@ -1662,21 +1906,19 @@ impl Actions {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListSelfHostedRunnersForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_self_hosted_runners_for_repo(
+    pub async fn list_self_hosted_runners_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
-    ) -> Result<crate::types::ActionsListSelfHostedRunnersOrgResponse> {
+        params: &ActionsListSelfHostedRunnersForRepoParams,
+    ) -> Result<crate::types::ActionsListSelfHostedRunnersRepoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1690,6 +1932,25 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_self_hosted_runners_for_repo_with` and pass the query parameters as a `ActionsListSelfHostedRunnersForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_self_hosted_runners_for_repo_with` instead")]
+    pub async fn list_self_hosted_runners_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActionsListSelfHostedRunnersRepoResponse> {
+        let params = ActionsListSelfHostedRunnersForRepoParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_self_hosted_runners_for_repo_with(owner, repo, &params)
+            .await
+    }
+
     /**
      * List runner applications for a repository.
      *
@@ -1747,6 +2008,50 @@ impl Actions {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List runner applications for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_repo`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists binaries for the runner application that you can download and run.
+     *
+     * You must authenticate using an access token with the `repo` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#list-runner-applications-for-a-repository>
+     */
+    pub fn list_runner_applications_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RunnerApplication> {
+        let url = format!(
+            "/repos/{}/{}/actions/runners/downloads",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a registration token for a repository.
      *
@@ -1904,46 +2209,34 @@ impl Actions {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `actor: &str` -- Returns someone's workflow runs. Use the login for the user who created the `push` associated with the check suite or workflow run.
-     * * `branch: &str` -- Returns workflow runs associated with a branch. Use the name of the branch of the `push`.
-     * * `event: &str` -- Returns workflow run triggered by the event you specify. For example, `push`, `pull_request` or `issue`. For more information, see "[Events that trigger workflows](https://help.github.com/en/actions/automating-your-workflow-with-github-actions/events-that-trigger-workflows).".
-     * * `status: crate::types::WorkflowRunStatus` -- Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run).".
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `created: chrono::DateTime<chrono::Utc>`
+     * * `params: &ActionsListWorkflowRunsForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_workflow_runs_for_repo(
+    pub async fn list_workflow_runs_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        actor: &str,
-        branch: &str,
-        event: &str,
-        status: crate::types::WorkflowRunStatus,
-        per_page: i64,
-        page: i64,
-        created: Option<chrono::DateTime<chrono::Utc>>,
-    ) -> Result<crate::types::ActionsListWorkflowRunsResponse> {
+        params: &ActionsListWorkflowRunsForRepoParams,
+    ) -> Result<crate::types::ActionsListWorkflowRunsRepoResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !actor.is_empty() {
+        if let Some(actor) = &params.actor {
             query_args.push(("actor".to_string(), actor.to_string()));
         }
-        if !branch.is_empty() {
+        if let Some(branch) = &params.branch {
             query_args.push(("branch".to_string(), branch.to_string()));
         }
-        if let Some(date) = created {
-            query_args.push(("created".to_string(), date.to_rfc3339()));
+        if let Some(created) = &params.created {
+            query_args.push(("created".to_string(), created.to_rfc3339()));
         }
-        if !event.is_empty() {
+        if let Some(event) = &params.event {
             query_args.push(("event".to_string(), event.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1958,9 +2251,38 @@ impl Actions {
     }
 
     /**
-     * Get a workflow run.
-     *
-     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}` endpoint.
+     * Deprecated: use `list_workflow_runs_for_repo_with` and pass the query parameters as a `ActionsListWorkflowRunsForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_workflow_runs_for_repo_with` instead")]
+    pub async fn list_workflow_runs_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        actor: &str,
+        branch: &str,
+        event: &str,
+        status: crate::types::WorkflowRunStatus,
+        per_page: i64,
+        page: i64,
+        created: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<crate::types::ActionsListWorkflowRunsRepoResponse> {
+        let params = ActionsListWorkflowRunsForRepoParams {
+            actor: Some(actor.to_string()).filter(|s| !s.is_empty()),
+            branch: Some(branch.to_string()).filter(|s| !s.is_empty()),
+            created,
+            event: Some(event.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_workflow_runs_for_repo_with(owner, repo, &params)
+            .await
+    }
+
+    /**
+     * Get a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}` endpoint.
      *
      * Gets a specific workflow run. Anyone with read access to the repository can use this endpoint. If the repository is private you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint.
      *
@@ -2036,7 +2358,7 @@ impl Actions {
         owner: &str,
         repo: &str,
         run_id: i64,
-    ) -> Result<Vec<crate::types::EnvironmentApproval>> {
+    ) -> Result<Vec<crate::types::EnvironmentApprovals>> {
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/approvals",
             crate::progenitor_support::encode_path(owner),
@@ -2063,7 +2385,7 @@ impl Actions {
         owner: &str,
         repo: &str,
         run_id: i64,
-    ) -> Result<Vec<crate::types::EnvironmentApproval>> {
+    ) -> Result<Vec<crate::types::EnvironmentApprovals>> {
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/approvals",
             crate::progenitor_support::encode_path(owner),
@@ -2074,6 +2396,50 @@ impl Actions {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get the review history for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/approvals` endpoint.
+     *
+     * As opposed to `get_all_reviews_for_run`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Anyone with read access to the repository can use this endpoint. If the repository is private, you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-the-review-history-for-a-workflow-run>
+     */
+    pub fn get_reviews_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::EnvironmentApprovals> {
+        let url = format!(
+            "/repos/{}/{}/actions/runs/{}/approvals",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&run_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Approve a workflow run for a fork pull request.
      *
@@ -2091,7 +2457,12 @@ impl Actions {
      * * `repo: &str`
      * * `run_id: i64` -- The id of the workflow run.
      */
-    pub async fn approve_workflow_run(&self, owner: &str, repo: &str, run_id: i64) -> Result<()> {
+    pub async fn approve_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::types::EmptyObject> {
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/approve",
             crate::progenitor_support::encode_path(owner),
@@ -2116,22 +2487,20 @@ impl Actions {
      * * `owner: &str`
      * * `repo: &str`
      * * `run_id: i64` -- The id of the workflow run.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListWorkflowRunArtifactsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_workflow_run_artifacts(
+    pub async fn list_workflow_run_artifacts_with(
         &self,
         owner: &str,
         repo: &str,
         run_id: i64,
-        per_page: i64,
-        page: i64,
-    ) -> Result<crate::types::ActionsListArtifactsRepoResponse> {
+        params: &ActionsListWorkflowRunArtifactsParams,
+    ) -> Result<crate::types::ActionsListWorkflowRunArtifactsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2146,6 +2515,26 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_workflow_run_artifacts_with` and pass the query parameters as a `ActionsListWorkflowRunArtifactsParams` instead.
+     */
+    #[deprecated(note = "use `list_workflow_run_artifacts_with` instead")]
+    pub async fn list_workflow_run_artifacts(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActionsListWorkflowRunArtifactsResponse> {
+        let params = ActionsListWorkflowRunArtifactsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_workflow_run_artifacts_with(owner, repo, run_id, &params)
+            .await
+    }
+
     /**
      * Cancel a workflow run.
      *
@@ -2161,7 +2550,12 @@ impl Actions {
      * * `repo: &str`
      * * `run_id: i64` -- The id of the workflow run.
      */
-    pub async fn cancel_workflow_run(&self, owner: &str, repo: &str, run_id: i64) -> Result<()> {
+    pub async fn cancel_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::types::ActionsCancelWorkflowRunResponse> {
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/cancel",
             crate::progenitor_support::encode_path(owner),
@@ -2186,29 +2580,23 @@ impl Actions {
      * * `owner: &str`
      * * `repo: &str`
      * * `run_id: i64` -- The id of the workflow run.
-     * * `filter: crate::types::ActionsListJobsWorkflowRunFilter` -- Filters jobs by their `completed_at` timestamp. Can be one of:  
-     *  \\* `latest`: Returns jobs from the most recent execution of the workflow run.  
-     *  \\* `all`: Returns all jobs for a workflow run, including from old executions of the workflow run.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListJobsForWorkflowRunParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_jobs_for_workflow_run(
+    pub async fn list_jobs_for_workflow_run_with(
         &self,
         owner: &str,
         repo: &str,
         run_id: i64,
-        filter: crate::types::ActionsListJobsWorkflowRunFilter,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListJobsForWorkflowRunParams,
     ) -> Result<crate::types::ActionsListJobsWorkflowRunResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.to_string().is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2223,6 +2611,28 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_jobs_for_workflow_run_with` and pass the query parameters as a `ActionsListJobsForWorkflowRunParams` instead.
+     */
+    #[deprecated(note = "use `list_jobs_for_workflow_run_with` instead")]
+    pub async fn list_jobs_for_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        filter: crate::types::ActionsListJobsWorkflowRunFilter,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActionsListJobsWorkflowRunResponse> {
+        let params = ActionsListJobsForWorkflowRunParams {
+            filter: Some(filter).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_jobs_for_workflow_run_with(owner, repo, run_id, &params)
+            .await
+    }
+
     /**
      * Download workflow run logs.
      *
@@ -2246,7 +2656,7 @@ impl Actions {
         owner: &str,
         repo: &str,
         run_id: i64,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/logs",
             crate::progenitor_support::encode_path(owner),
@@ -2254,7 +2664,41 @@ impl Actions {
             crate::progenitor_support::encode_path(&run_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Download workflow run logs.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/logs` endpoint.
+     *
+     * As opposed to `download_workflow_run_logs`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Gets a redirect URL to download an archive of log files for a workflow run. This link expires after 1 minute. Look for
+     * `Location:` in the response header to find the URL for the download. Anyone with read access to the repository can use
+     * this endpoint. If the repository is private you must use an access token with the `repo` scope. GitHub Apps must have
+     * the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#download-workflow-run-logs>
+     */
+    pub async fn download_workflow_run_logs_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::DownloadStream> {
+        let url = format!(
+            "/repos/{}/{}/actions/runs/{}/logs",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&run_id.to_string()),
+        );
+
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
     }
 
     /**
@@ -2350,6 +2794,52 @@ impl Actions {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get pending deployments for a workflow run.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/actions/runs/{run_id}/pending_deployments` endpoint.
+     *
+     * As opposed to `get_all_pending_deployments_for_run`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Get all deployment environments for a workflow run that are waiting for protection rules to pass.
+     *
+     * Anyone with read access to the repository can use this endpoint. If the repository is private, you must use an access token with the `repo` scope. GitHub Apps must have the `actions:read` permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/actions#get-pending-deployments-for-a-workflow-run>
+     */
+    pub fn get_pending_deployments_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PendingDeployment> {
+        let url = format!(
+            "/repos/{}/{}/actions/runs/{}/pending_deployments",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&run_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Review pending deployments for a workflow run.
      *
@@ -2401,7 +2891,12 @@ impl Actions {
      * * `repo: &str`
      * * `run_id: i64` -- The id of the workflow run.
      */
-    pub async fn re_run_workflow(&self, owner: &str, repo: &str, run_id: i64) -> Result<()> {
+    pub async fn re_run_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::types::ActionsReRunWorkflowResponse> {
         let url = format!(
             "/repos/{}/{}/actions/runs/{}/rerun",
             crate::progenitor_support::encode_path(owner),
@@ -2458,21 +2953,19 @@ impl Actions {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListRepoSecretsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_repo_secrets(
+    pub async fn list_repo_secrets_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListRepoSecretsParams,
     ) -> Result<crate::types::ActionsListRepoSecretsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2486,6 +2979,24 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_repo_secrets_with` and pass the query parameters as a `ActionsListRepoSecretsParams` instead.
+     */
+    #[deprecated(note = "use `list_repo_secrets_with` instead")]
+    pub async fn list_repo_secrets(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActionsListRepoSecretsResponse> {
+        let params = ActionsListRepoSecretsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_repo_secrets_with(owner, repo, &params).await
+    }
+
     /**
      * Get a repository public key.
      *
@@ -2640,7 +3151,7 @@ impl Actions {
         repo: &str,
         secret_name: &str,
         body: &crate::types::ActionsCreateUpdateRepoSecretRequest,
-    ) -> Result<()> {
+    ) -> Result<crate::types::ActionsCreateUpdateRepoSecretResponse> {
         let url = format!(
             "/repos/{}/{}/actions/secrets/{}",
             crate::progenitor_support::encode_path(owner),
@@ -2697,21 +3208,19 @@ impl Actions {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListRepoWorkflowsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_repo_workflows(
+    pub async fn list_repo_workflows_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActionsListRepoWorkflowsParams,
     ) -> Result<crate::types::ActionsListRepoWorkflowsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2725,6 +3234,24 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_repo_workflows_with` and pass the query parameters as a `ActionsListRepoWorkflowsParams` instead.
+     */
+    #[deprecated(note = "use `list_repo_workflows_with` instead")]
+    pub async fn list_repo_workflows(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActionsListRepoWorkflowsResponse> {
+        let params = ActionsListRepoWorkflowsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_repo_workflows_with(owner, repo, &params).await
+    }
+
     /**
      * Get a workflow.
      *
@@ -2866,47 +3393,35 @@ impl Actions {
      * * `owner: &str`
      * * `repo: &str`
      * * `workflow_id: &str` -- The ID of the workflow. You can also pass the workflow file name as a string.
-     * * `actor: &str` -- Returns someone's workflow runs. Use the login for the user who created the `push` associated with the check suite or workflow run.
-     * * `branch: &str` -- Returns workflow runs associated with a branch. Use the name of the branch of the `push`.
-     * * `event: &str` -- Returns workflow run triggered by the event you specify. For example, `push`, `pull_request` or `issue`. For more information, see "[Events that trigger workflows](https://help.github.com/en/actions/automating-your-workflow-with-github-actions/events-that-trigger-workflows).".
-     * * `status: crate::types::WorkflowRunStatus` -- Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run).".
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `created: chrono::DateTime<chrono::Utc>`
+     * * `params: &ActionsListWorkflowRunsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_workflow_runs(
+    pub async fn list_workflow_runs_with(
         &self,
         owner: &str,
         repo: &str,
         workflow_id: &str,
-        actor: &str,
-        branch: &str,
-        event: &str,
-        status: crate::types::WorkflowRunStatus,
-        per_page: i64,
-        page: i64,
-        created: Option<chrono::DateTime<chrono::Utc>>,
+        params: &ActionsListWorkflowRunsParams,
     ) -> Result<crate::types::ActionsListWorkflowRunsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !actor.is_empty() {
+        if let Some(actor) = &params.actor {
             query_args.push(("actor".to_string(), actor.to_string()));
         }
-        if !branch.is_empty() {
+        if let Some(branch) = &params.branch {
             query_args.push(("branch".to_string(), branch.to_string()));
         }
-        if let Some(date) = created {
-            query_args.push(("created".to_string(), date.to_rfc3339()));
+        if let Some(created) = &params.created {
+            query_args.push(("created".to_string(), created.to_rfc3339()));
         }
-        if !event.is_empty() {
+        if let Some(event) = &params.event {
             query_args.push(("event".to_string(), event.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2921,6 +3436,36 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_workflow_runs_with` and pass the query parameters as a `ActionsListWorkflowRunsParams` instead.
+     */
+    #[deprecated(note = "use `list_workflow_runs_with` instead")]
+    pub async fn list_workflow_runs(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+        actor: &str,
+        branch: &str,
+        event: &str,
+        status: crate::types::WorkflowRunStatus,
+        per_page: i64,
+        page: i64,
+        created: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<crate::types::ActionsListWorkflowRunsResponse> {
+        let params = ActionsListWorkflowRunsParams {
+            actor: Some(actor.to_string()).filter(|s| !s.is_empty()),
+            branch: Some(branch.to_string()).filter(|s| !s.is_empty()),
+            created,
+            event: Some(event.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_workflow_runs_with(owner, repo, workflow_id, &params)
+            .await
+    }
+
     /**
      * Get workflow usage.
      *
@@ -2967,21 +3512,19 @@ impl Actions {
      *
      * * `repository_id: i64`
      * * `environment_name: &str` -- The name of the environment.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActionsListEnvironmentSecretsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_environment_secrets(
+    pub async fn list_environment_secrets_with(
         &self,
         repository_id: i64,
         environment_name: &str,
-        per_page: i64,
-        page: i64,
-    ) -> Result<crate::types::ActionsListRepoSecretsResponse> {
+        params: &ActionsListEnvironmentSecretsParams,
+    ) -> Result<crate::types::ActionsListEnvironmentSecretsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2995,6 +3538,25 @@ impl Actions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_environment_secrets_with` and pass the query parameters as a `ActionsListEnvironmentSecretsParams` instead.
+     */
+    #[deprecated(note = "use `list_environment_secrets_with` instead")]
+    pub async fn list_environment_secrets(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActionsListEnvironmentSecretsResponse> {
+        let params = ActionsListEnvironmentSecretsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_environment_secrets_with(repository_id, environment_name, &params)
+            .await
+    }
+
     /**
      * Get an environment public key.
      *
@@ -3148,8 +3710,8 @@ impl Actions {
         repository_id: i64,
         environment_name: &str,
         secret_name: &str,
-        body: &crate::types::ActionsCreateUpdateRepoSecretRequest,
-    ) -> Result<()> {
+        body: &crate::types::ActionsCreateUpdateEnvironmentSecretRequest,
+    ) -> Result<crate::types::EmptyObject> {
         let url = format!(
             "/repositories/{}/environments/{}/secrets/{}",
             crate::progenitor_support::encode_path(&repository_id.to_string()),
@@ -3193,3 +3755,1934 @@ impl Actions {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Actions::list_selected_repositories_enabled_github_actions_organization_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_self_hosted_runner_groups_for_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListSelfHostedRunnerGroupsForOrgParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListSelfHostedRunnerGroupsForOrgParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_repo_access_to_self_hosted_runner_group_in_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListRepoAccessToSelfHostedRunnerGroupInOrgParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListRepoAccessToSelfHostedRunnerGroupInOrgParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_self_hosted_runners_in_group_for_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListSelfHostedRunnersInGroupForOrgParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListSelfHostedRunnersInGroupForOrgParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_self_hosted_runners_for_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListSelfHostedRunnersForOrgParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListSelfHostedRunnersForOrgParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_org_secrets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListOrgSecretsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListOrgSecretsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_selected_repos_for_org_secret_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListSelectedReposForOrgSecretParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListSelectedReposForOrgSecretParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_artifacts_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListArtifactsForRepoParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListArtifactsForRepoParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_self_hosted_runners_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListSelfHostedRunnersForRepoParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListSelfHostedRunnersForRepoParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_workflow_runs_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListWorkflowRunsForRepoParams {
+    /// Returns someone's workflow runs. Use the login for the user who created the `push` associated with the check suite or workflow run.
+    pub actor: Option<String>,
+    /// Returns workflow runs associated with a branch. Use the name of the branch of the `push`.
+    pub branch: Option<String>,
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    /// Returns workflow run triggered by the event you specify. For example, `push`, `pull_request` or `issue`. For more information, see "[Events that trigger workflows](https://help.github.com/en/actions/automating-your-workflow-with-github-actions/events-that-trigger-workflows).".
+    pub event: Option<String>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run).".
+    pub status: Option<crate::types::WorkflowRunStatus>,
+}
+
+impl ActionsListWorkflowRunsForRepoParams {
+    pub fn actor<T: ToString>(mut self, actor: T) -> Self {
+        self.actor = Some(actor.to_string());
+        self
+    }
+
+    pub fn branch<T: ToString>(mut self, branch: T) -> Self {
+        self.branch = Some(branch.to_string());
+        self
+    }
+
+    pub fn created(mut self, created: chrono::DateTime<chrono::Utc>) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn event<T: ToString>(mut self, event: T) -> Self {
+        self.event = Some(event.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::WorkflowRunStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_workflow_run_artifacts_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListWorkflowRunArtifactsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListWorkflowRunArtifactsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_jobs_for_workflow_run_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListJobsForWorkflowRunParams {
+    /// Filters jobs by their `completed_at` timestamp. Can be one of:  
+    /// \\* `latest`: Returns jobs from the most recent execution of the workflow run.  
+    /// \\* `all`: Returns all jobs for a workflow run, including from old executions of the workflow run.
+    pub filter: Option<crate::types::ActionsListJobsWorkflowRunFilter>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListJobsForWorkflowRunParams {
+    pub fn filter(mut self, filter: crate::types::ActionsListJobsWorkflowRunFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_repo_secrets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListRepoSecretsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListRepoSecretsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_repo_workflows_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListRepoWorkflowsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListRepoWorkflowsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_workflow_runs_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListWorkflowRunsParams {
+    /// Returns someone's workflow runs. Use the login for the user who created the `push` associated with the check suite or workflow run.
+    pub actor: Option<String>,
+    /// Returns workflow runs associated with a branch. Use the name of the branch of the `push`.
+    pub branch: Option<String>,
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    /// Returns workflow run triggered by the event you specify. For example, `push`, `pull_request` or `issue`. For more information, see "[Events that trigger workflows](https://help.github.com/en/actions/automating-your-workflow-with-github-actions/events-that-trigger-workflows).".
+    pub event: Option<String>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run).".
+    pub status: Option<crate::types::WorkflowRunStatus>,
+}
+
+impl ActionsListWorkflowRunsParams {
+    pub fn actor<T: ToString>(mut self, actor: T) -> Self {
+        self.actor = Some(actor.to_string());
+        self
+    }
+
+    pub fn branch<T: ToString>(mut self, branch: T) -> Self {
+        self.branch = Some(branch.to_string());
+        self
+    }
+
+    pub fn created(mut self, created: chrono::DateTime<chrono::Utc>) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn event<T: ToString>(mut self, event: T) -> Self {
+        self.event = Some(event.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::WorkflowRunStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// The query parameters of `Actions::list_environment_secrets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActionsListEnvironmentSecretsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActionsListEnvironmentSecretsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The functions of [`Actions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockActionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ActionsApi: Send + Sync {
+    /// See [`Actions::get_github_actions_permissions_organization`].
+    async fn get_github_actions_permissions_organization(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::ActionsOrganizationPermissions>;
+
+    /// See [`Actions::set_github_actions_permissions_organization`].
+    async fn set_github_actions_permissions_organization(
+        &self,
+        org: &str,
+        body: &crate::types::ActionsSetGithubPermissionsOrganizationRequest,
+    ) -> Result<()>;
+
+    /// See [`Actions::list_selected_repositories_enabled_github_actions_organization_with`].
+    async fn list_selected_repositories_enabled_github_actions_organization_with(
+        &self,
+        org: &str,
+        params: &ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationParams,
+    ) -> Result<crate::types::ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse>;
+
+    /// See [`Actions::set_selected_repositories_enabled_github_actions_organization`].
+    async fn set_selected_repositories_enabled_github_actions_organization(
+        &self,
+        org: &str,
+        body: &crate::types::ActionsSetSelectedRepositoriesEnabledGithubOrganizationRequest,
+    ) -> Result<()>;
+
+    /// See [`Actions::enable_selected_repository_github_actions_organization`].
+    async fn enable_selected_repository_github_actions_organization(
+        &self,
+        org: &str,
+        repository_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Actions::disable_selected_repository_github_actions_organization`].
+    async fn disable_selected_repository_github_actions_organization(
+        &self,
+        org: &str,
+        repository_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Actions::get_allowed_actions_organization`].
+    async fn get_allowed_actions_organization(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::SelectedActions>;
+
+    /// See [`Actions::set_allowed_actions_organization`].
+    async fn set_allowed_actions_organization(
+        &self,
+        org: &str,
+        body: &crate::types::SelectedActions,
+    ) -> Result<()>;
+
+    /// See [`Actions::list_self_hosted_runner_groups_for_org_with`].
+    async fn list_self_hosted_runner_groups_for_org_with(
+        &self,
+        org: &str,
+        params: &ActionsListSelfHostedRunnerGroupsForOrgParams,
+    ) -> Result<crate::types::ActionsListSelfHostedRunnerGroupsOrgResponse>;
+
+    /// See [`Actions::create_self_hosted_runner_group_for_org`].
+    async fn create_self_hosted_runner_group_for_org(
+        &self,
+        org: &str,
+        body: &crate::types::ActionsCreateSelfHostedRunnerGroupOrgRequest,
+    ) -> Result<crate::types::RunnerGroupsOrg>;
+
+    /// See [`Actions::get_self_hosted_runner_group_for_org`].
+    async fn get_self_hosted_runner_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> Result<crate::types::RunnerGroupsOrg>;
+
+    /// See [`Actions::delete_self_hosted_runner_group_from_org`].
+    async fn delete_self_hosted_runner_group_from_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Actions::update_self_hosted_runner_group_for_org`].
+    async fn update_self_hosted_runner_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsUpdateSelfHostedRunnerGroupOrgRequest,
+    ) -> Result<crate::types::RunnerGroupsOrg>;
+
+    /// See [`Actions::list_repo_access_to_self_hosted_runner_group_in_org_with`].
+    async fn list_repo_access_to_self_hosted_runner_group_in_org_with(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        params: &ActionsListRepoAccessToSelfHostedRunnerGroupInOrgParams,
+    ) -> Result<crate::types::ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse>;
+
+    /// See [`Actions::set_repo_access_to_self_hosted_runner_group_in_org`].
+    async fn set_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest,
+    ) -> Result<()>;
+
+    /// See [`Actions::add_repo_access_to_self_hosted_runner_group_in_org`].
+    async fn add_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        repository_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Actions::remove_repo_access_to_self_hosted_runner_group_in_org`].
+    async fn remove_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        repository_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Actions::list_self_hosted_runners_in_group_for_org_with`].
+    async fn list_self_hosted_runners_in_group_for_org_with(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        params: &ActionsListSelfHostedRunnersInGroupForOrgParams,
+    ) -> Result<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse>;
+
+    /// See [`Actions::set_self_hosted_runners_in_group_for_org`].
+    async fn set_self_hosted_runners_in_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsSetSelfHostedRunnersInGroupOrgRequest,
+    ) -> Result<()>;
+
+    /// See [`Actions::add_self_hosted_runner_to_group_for_org`].
+    async fn add_self_hosted_runner_to_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Actions::remove_self_hosted_runner_from_group_for_org`].
+    async fn remove_self_hosted_runner_from_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Actions::list_self_hosted_runners_for_org_with`].
+    async fn list_self_hosted_runners_for_org_with(
+        &self,
+        org: &str,
+        params: &ActionsListSelfHostedRunnersForOrgParams,
+    ) -> Result<crate::types::ActionsListSelfHostedRunnersOrgResponse>;
+
+    /// See [`Actions::list_runner_applications_for_org`].
+    async fn list_runner_applications_for_org(
+        &self,
+        org: &str,
+    ) -> Result<Vec<crate::types::RunnerApplication>>;
+
+    /// See [`Actions::list_all_runner_applications_for_org`].
+    async fn list_all_runner_applications_for_org(
+        &self,
+        org: &str,
+    ) -> Result<Vec<crate::types::RunnerApplication>>;
+
+    /// See [`Actions::list_runner_applications_for_org_stream`].
+    fn list_runner_applications_for_org_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RunnerApplication>;
+
+    /// See [`Actions::create_registration_token_for_org`].
+    async fn create_registration_token_for_org(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::AuthenticationToken>;
+
+    /// See [`Actions::create_remove_token_for_org`].
+    async fn create_remove_token_for_org(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::AuthenticationToken>;
+
+    /// See [`Actions::get_self_hosted_runner_for_org`].
+    async fn get_self_hosted_runner_for_org(
+        &self,
+        org: &str,
+        runner_id: i64,
+    ) -> Result<crate::types::Runner>;
+
+    /// See [`Actions::delete_self_hosted_runner_from_org`].
+    async fn delete_self_hosted_runner_from_org(&self, org: &str, runner_id: i64) -> Result<()>;
+
+    /// See [`Actions::list_org_secrets_with`].
+    async fn list_org_secrets_with(
+        &self,
+        org: &str,
+        params: &ActionsListOrgSecretsParams,
+    ) -> Result<crate::types::ActionsListOrgSecretsResponse>;
+
+    /// See [`Actions::get_org_public_key`].
+    async fn get_org_public_key(&self, org: &str) -> Result<crate::types::ActionsPublicKey>;
+
+    /// See [`Actions::get_org_secret`].
+    async fn get_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+    ) -> Result<crate::types::OrganizationActionsSecret>;
+
+    /// See [`Actions::create_or_update_org_secret`].
+    async fn create_or_update_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsCreateUpdateOrgSecretRequest,
+    ) -> Result<crate::types::EmptyObject>;
+
+    /// See [`Actions::delete_org_secret`].
+    async fn delete_org_secret(&self, org: &str, secret_name: &str) -> Result<()>;
+
+    /// See [`Actions::list_selected_repos_for_org_secret_with`].
+    async fn list_selected_repos_for_org_secret_with(
+        &self,
+        org: &str,
+        secret_name: &str,
+        params: &ActionsListSelectedReposForOrgSecretParams,
+    ) -> Result<crate::types::ActionsListSelectedReposOrgSecretResponse>;
+
+    /// See [`Actions::set_selected_repos_for_org_secret`].
+    async fn set_selected_repos_for_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsSetSelectedReposOrgSecretRequest,
+    ) -> Result<()>;
+
+    /// See [`Actions::add_selected_repo_to_org_secret`].
+    async fn add_selected_repo_to_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        repository_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Actions::remove_selected_repo_from_org_secret`].
+    async fn remove_selected_repo_from_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        repository_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Actions::list_artifacts_for_repo_with`].
+    async fn list_artifacts_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActionsListArtifactsForRepoParams,
+    ) -> Result<crate::types::ActionsListArtifactsRepoResponse>;
+
+    /// See [`Actions::get_artifact`].
+    async fn get_artifact(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+    ) -> Result<crate::types::Artifact>;
+
+    /// See [`Actions::delete_artifact`].
+    async fn delete_artifact(&self, owner: &str, repo: &str, artifact_id: i64) -> Result<()>;
+
+    /// See [`Actions::download_artifact`].
+    async fn download_artifact(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+        archive_format: &str,
+    ) -> Result<crate::Download>;
+
+    /// See [`Actions::download_artifact_stream`].
+    async fn download_artifact_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+        archive_format: &str,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`Actions::get_job_for_workflow_run`].
+    async fn get_job_for_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> Result<crate::types::Job>;
+
+    /// See [`Actions::download_job_logs_for_workflow_run`].
+    async fn download_job_logs_for_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> Result<crate::Download>;
+
+    /// See [`Actions::download_job_logs_for_workflow_run_stream`].
+    async fn download_job_logs_for_workflow_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`Actions::get_github_actions_permissions_repository`].
+    async fn get_github_actions_permissions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::ActionsRepositoryPermissions>;
+
+    /// See [`Actions::set_github_actions_permissions_repository`].
+    async fn set_github_actions_permissions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ActionsSetGithubPermissionsRepositoryRequest,
+    ) -> Result<()>;
+
+    /// See [`Actions::get_allowed_actions_repository`].
+    async fn get_allowed_actions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::SelectedActions>;
+
+    /// See [`Actions::set_allowed_actions_repository`].
+    async fn set_allowed_actions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::SelectedActions,
+    ) -> Result<()>;
+
+    /// See [`Actions::list_self_hosted_runners_for_repo_with`].
+    async fn list_self_hosted_runners_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActionsListSelfHostedRunnersForRepoParams,
+    ) -> Result<crate::types::ActionsListSelfHostedRunnersRepoResponse>;
+
+    /// See [`Actions::list_runner_applications_for_repo`].
+    async fn list_runner_applications_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::RunnerApplication>>;
+
+    /// See [`Actions::list_all_runner_applications_for_repo`].
+    async fn list_all_runner_applications_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::RunnerApplication>>;
+
+    /// See [`Actions::list_runner_applications_for_repo_stream`].
+    fn list_runner_applications_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RunnerApplication>;
+
+    /// See [`Actions::create_registration_token_for_repo`].
+    async fn create_registration_token_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::AuthenticationToken>;
+
+    /// See [`Actions::create_remove_token_for_repo`].
+    async fn create_remove_token_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::AuthenticationToken>;
+
+    /// See [`Actions::get_self_hosted_runner_for_repo`].
+    async fn get_self_hosted_runner_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        runner_id: i64,
+    ) -> Result<crate::types::Runner>;
+
+    /// See [`Actions::delete_self_hosted_runner_from_repo`].
+    async fn delete_self_hosted_runner_from_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        runner_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Actions::list_workflow_runs_for_repo_with`].
+    async fn list_workflow_runs_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActionsListWorkflowRunsForRepoParams,
+    ) -> Result<crate::types::ActionsListWorkflowRunsRepoResponse>;
+
+    /// See [`Actions::get_workflow_run`].
+    async fn get_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::types::WorkflowRun>;
+
+    /// See [`Actions::delete_workflow_run`].
+    async fn delete_workflow_run(&self, owner: &str, repo: &str, run_id: i64) -> Result<()>;
+
+    /// See [`Actions::get_reviews_for_run`].
+    async fn get_reviews_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<Vec<crate::types::EnvironmentApprovals>>;
+
+    /// See [`Actions::get_all_reviews_for_run`].
+    async fn get_all_reviews_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<Vec<crate::types::EnvironmentApprovals>>;
+
+    /// See [`Actions::get_reviews_for_run_stream`].
+    fn get_reviews_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::EnvironmentApprovals>;
+
+    /// See [`Actions::approve_workflow_run`].
+    async fn approve_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::types::EmptyObject>;
+
+    /// See [`Actions::list_workflow_run_artifacts_with`].
+    async fn list_workflow_run_artifacts_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        params: &ActionsListWorkflowRunArtifactsParams,
+    ) -> Result<crate::types::ActionsListWorkflowRunArtifactsResponse>;
+
+    /// See [`Actions::cancel_workflow_run`].
+    async fn cancel_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::types::ActionsCancelWorkflowRunResponse>;
+
+    /// See [`Actions::list_jobs_for_workflow_run_with`].
+    async fn list_jobs_for_workflow_run_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        params: &ActionsListJobsForWorkflowRunParams,
+    ) -> Result<crate::types::ActionsListJobsWorkflowRunResponse>;
+
+    /// See [`Actions::download_workflow_run_logs`].
+    async fn download_workflow_run_logs(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::Download>;
+
+    /// See [`Actions::download_workflow_run_logs_stream`].
+    async fn download_workflow_run_logs_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`Actions::delete_workflow_run_logs`].
+    async fn delete_workflow_run_logs(&self, owner: &str, repo: &str, run_id: i64) -> Result<()>;
+
+    /// See [`Actions::get_pending_deployments_for_run`].
+    async fn get_pending_deployments_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<Vec<crate::types::PendingDeployment>>;
+
+    /// See [`Actions::get_all_pending_deployments_for_run`].
+    async fn get_all_pending_deployments_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<Vec<crate::types::PendingDeployment>>;
+
+    /// See [`Actions::get_pending_deployments_for_run_stream`].
+    fn get_pending_deployments_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PendingDeployment>;
+
+    /// See [`Actions::review_pending_deployments_for_run`].
+    async fn review_pending_deployments_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        body: &crate::types::ActionsReviewPendingDeploymentsRunRequest,
+    ) -> Result<Vec<crate::types::Deployment>>;
+
+    /// See [`Actions::re_run_workflow`].
+    async fn re_run_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::types::ActionsReRunWorkflowResponse>;
+
+    /// See [`Actions::get_workflow_run_usage`].
+    async fn get_workflow_run_usage(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::types::WorkflowRunUsage>;
+
+    /// See [`Actions::list_repo_secrets_with`].
+    async fn list_repo_secrets_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActionsListRepoSecretsParams,
+    ) -> Result<crate::types::ActionsListRepoSecretsResponse>;
+
+    /// See [`Actions::get_repo_public_key`].
+    async fn get_repo_public_key(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::ActionsPublicKey>;
+
+    /// See [`Actions::get_repo_secret`].
+    async fn get_repo_secret(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+    ) -> Result<crate::types::ActionsSecret>;
+
+    /// See [`Actions::create_or_update_repo_secret`].
+    async fn create_or_update_repo_secret(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsCreateUpdateRepoSecretRequest,
+    ) -> Result<crate::types::ActionsCreateUpdateRepoSecretResponse>;
+
+    /// See [`Actions::delete_repo_secret`].
+    async fn delete_repo_secret(&self, owner: &str, repo: &str, secret_name: &str) -> Result<()>;
+
+    /// See [`Actions::list_repo_workflows_with`].
+    async fn list_repo_workflows_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActionsListRepoWorkflowsParams,
+    ) -> Result<crate::types::ActionsListRepoWorkflowsResponse>;
+
+    /// See [`Actions::get_workflow`].
+    async fn get_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> Result<crate::types::Workflow>;
+
+    /// See [`Actions::disable_workflow`].
+    async fn disable_workflow(&self, owner: &str, repo: &str, workflow_id: &str) -> Result<()>;
+
+    /// See [`Actions::create_workflow_dispatch`].
+    async fn create_workflow_dispatch(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+        body: &crate::types::ActionsCreateWorkflowDispatchRequest,
+    ) -> Result<()>;
+
+    /// See [`Actions::enable_workflow`].
+    async fn enable_workflow(&self, owner: &str, repo: &str, workflow_id: &str) -> Result<()>;
+
+    /// See [`Actions::list_workflow_runs_with`].
+    async fn list_workflow_runs_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+        params: &ActionsListWorkflowRunsParams,
+    ) -> Result<crate::types::ActionsListWorkflowRunsResponse>;
+
+    /// See [`Actions::get_workflow_usage`].
+    async fn get_workflow_usage(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> Result<crate::types::WorkflowUsage>;
+
+    /// See [`Actions::list_environment_secrets_with`].
+    async fn list_environment_secrets_with(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        params: &ActionsListEnvironmentSecretsParams,
+    ) -> Result<crate::types::ActionsListEnvironmentSecretsResponse>;
+
+    /// See [`Actions::get_environment_public_key`].
+    async fn get_environment_public_key(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+    ) -> Result<crate::types::ActionsPublicKey>;
+
+    /// See [`Actions::get_environment_secret`].
+    async fn get_environment_secret(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+    ) -> Result<crate::types::ActionsSecret>;
+
+    /// See [`Actions::create_or_update_environment_secret`].
+    async fn create_or_update_environment_secret(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsCreateUpdateEnvironmentSecretRequest,
+    ) -> Result<crate::types::EmptyObject>;
+
+    /// See [`Actions::delete_environment_secret`].
+    async fn delete_environment_secret(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ActionsApi for Actions {
+    async fn get_github_actions_permissions_organization(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::ActionsOrganizationPermissions> {
+        Actions::get_github_actions_permissions_organization(self, org).await
+    }
+
+    async fn set_github_actions_permissions_organization(
+        &self,
+        org: &str,
+        body: &crate::types::ActionsSetGithubPermissionsOrganizationRequest,
+    ) -> Result<()> {
+        Actions::set_github_actions_permissions_organization(self, org, body).await
+    }
+
+    async fn list_selected_repositories_enabled_github_actions_organization_with(
+        &self,
+        org: &str,
+        params: &ActionsListSelectedRepositoriesEnabledGithubActionsOrganizationParams,
+    ) -> Result<crate::types::ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse>
+    {
+        Actions::list_selected_repositories_enabled_github_actions_organization_with(
+            self, org, params,
+        )
+        .await
+    }
+
+    async fn set_selected_repositories_enabled_github_actions_organization(
+        &self,
+        org: &str,
+        body: &crate::types::ActionsSetSelectedRepositoriesEnabledGithubOrganizationRequest,
+    ) -> Result<()> {
+        Actions::set_selected_repositories_enabled_github_actions_organization(self, org, body)
+            .await
+    }
+
+    async fn enable_selected_repository_github_actions_organization(
+        &self,
+        org: &str,
+        repository_id: i64,
+    ) -> Result<()> {
+        Actions::enable_selected_repository_github_actions_organization(self, org, repository_id)
+            .await
+    }
+
+    async fn disable_selected_repository_github_actions_organization(
+        &self,
+        org: &str,
+        repository_id: i64,
+    ) -> Result<()> {
+        Actions::disable_selected_repository_github_actions_organization(self, org, repository_id)
+            .await
+    }
+
+    async fn get_allowed_actions_organization(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::SelectedActions> {
+        Actions::get_allowed_actions_organization(self, org).await
+    }
+
+    async fn set_allowed_actions_organization(
+        &self,
+        org: &str,
+        body: &crate::types::SelectedActions,
+    ) -> Result<()> {
+        Actions::set_allowed_actions_organization(self, org, body).await
+    }
+
+    async fn list_self_hosted_runner_groups_for_org_with(
+        &self,
+        org: &str,
+        params: &ActionsListSelfHostedRunnerGroupsForOrgParams,
+    ) -> Result<crate::types::ActionsListSelfHostedRunnerGroupsOrgResponse> {
+        Actions::list_self_hosted_runner_groups_for_org_with(self, org, params).await
+    }
+
+    async fn create_self_hosted_runner_group_for_org(
+        &self,
+        org: &str,
+        body: &crate::types::ActionsCreateSelfHostedRunnerGroupOrgRequest,
+    ) -> Result<crate::types::RunnerGroupsOrg> {
+        Actions::create_self_hosted_runner_group_for_org(self, org, body).await
+    }
+
+    async fn get_self_hosted_runner_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> Result<crate::types::RunnerGroupsOrg> {
+        Actions::get_self_hosted_runner_group_for_org(self, org, runner_group_id).await
+    }
+
+    async fn delete_self_hosted_runner_group_from_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+    ) -> Result<()> {
+        Actions::delete_self_hosted_runner_group_from_org(self, org, runner_group_id).await
+    }
+
+    async fn update_self_hosted_runner_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsUpdateSelfHostedRunnerGroupOrgRequest,
+    ) -> Result<crate::types::RunnerGroupsOrg> {
+        Actions::update_self_hosted_runner_group_for_org(self, org, runner_group_id, body).await
+    }
+
+    async fn list_repo_access_to_self_hosted_runner_group_in_org_with(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        params: &ActionsListRepoAccessToSelfHostedRunnerGroupInOrgParams,
+    ) -> Result<crate::types::ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse> {
+        Actions::list_repo_access_to_self_hosted_runner_group_in_org_with(
+            self,
+            org,
+            runner_group_id,
+            params,
+        )
+        .await
+    }
+
+    async fn set_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest,
+    ) -> Result<()> {
+        Actions::set_repo_access_to_self_hosted_runner_group_in_org(
+            self,
+            org,
+            runner_group_id,
+            body,
+        )
+        .await
+    }
+
+    async fn add_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        repository_id: i64,
+    ) -> Result<()> {
+        Actions::add_repo_access_to_self_hosted_runner_group_in_org(
+            self,
+            org,
+            runner_group_id,
+            repository_id,
+        )
+        .await
+    }
+
+    async fn remove_repo_access_to_self_hosted_runner_group_in_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        repository_id: i64,
+    ) -> Result<()> {
+        Actions::remove_repo_access_to_self_hosted_runner_group_in_org(
+            self,
+            org,
+            runner_group_id,
+            repository_id,
+        )
+        .await
+    }
+
+    async fn list_self_hosted_runners_in_group_for_org_with(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        params: &ActionsListSelfHostedRunnersInGroupForOrgParams,
+    ) -> Result<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse> {
+        Actions::list_self_hosted_runners_in_group_for_org_with(self, org, runner_group_id, params)
+            .await
+    }
+
+    async fn set_self_hosted_runners_in_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        body: &crate::types::ActionsSetSelfHostedRunnersInGroupOrgRequest,
+    ) -> Result<()> {
+        Actions::set_self_hosted_runners_in_group_for_org(self, org, runner_group_id, body).await
+    }
+
+    async fn add_self_hosted_runner_to_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> Result<()> {
+        Actions::add_self_hosted_runner_to_group_for_org(self, org, runner_group_id, runner_id)
+            .await
+    }
+
+    async fn remove_self_hosted_runner_from_group_for_org(
+        &self,
+        org: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> Result<()> {
+        Actions::remove_self_hosted_runner_from_group_for_org(self, org, runner_group_id, runner_id)
+            .await
+    }
+
+    async fn list_self_hosted_runners_for_org_with(
+        &self,
+        org: &str,
+        params: &ActionsListSelfHostedRunnersForOrgParams,
+    ) -> Result<crate::types::ActionsListSelfHostedRunnersOrgResponse> {
+        Actions::list_self_hosted_runners_for_org_with(self, org, params).await
+    }
+
+    async fn list_runner_applications_for_org(
+        &self,
+        org: &str,
+    ) -> Result<Vec<crate::types::RunnerApplication>> {
+        Actions::list_runner_applications_for_org(self, org).await
+    }
+
+    async fn list_all_runner_applications_for_org(
+        &self,
+        org: &str,
+    ) -> Result<Vec<crate::types::RunnerApplication>> {
+        Actions::list_all_runner_applications_for_org(self, org).await
+    }
+
+    fn list_runner_applications_for_org_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RunnerApplication> {
+        Actions::list_runner_applications_for_org_stream(self, org, start, max_items)
+    }
+
+    async fn create_registration_token_for_org(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::AuthenticationToken> {
+        Actions::create_registration_token_for_org(self, org).await
+    }
+
+    async fn create_remove_token_for_org(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::AuthenticationToken> {
+        Actions::create_remove_token_for_org(self, org).await
+    }
+
+    async fn get_self_hosted_runner_for_org(
+        &self,
+        org: &str,
+        runner_id: i64,
+    ) -> Result<crate::types::Runner> {
+        Actions::get_self_hosted_runner_for_org(self, org, runner_id).await
+    }
+
+    async fn delete_self_hosted_runner_from_org(&self, org: &str, runner_id: i64) -> Result<()> {
+        Actions::delete_self_hosted_runner_from_org(self, org, runner_id).await
+    }
+
+    async fn list_org_secrets_with(
+        &self,
+        org: &str,
+        params: &ActionsListOrgSecretsParams,
+    ) -> Result<crate::types::ActionsListOrgSecretsResponse> {
+        Actions::list_org_secrets_with(self, org, params).await
+    }
+
+    async fn get_org_public_key(&self, org: &str) -> Result<crate::types::ActionsPublicKey> {
+        Actions::get_org_public_key(self, org).await
+    }
+
+    async fn get_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+    ) -> Result<crate::types::OrganizationActionsSecret> {
+        Actions::get_org_secret(self, org, secret_name).await
+    }
+
+    async fn create_or_update_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsCreateUpdateOrgSecretRequest,
+    ) -> Result<crate::types::EmptyObject> {
+        Actions::create_or_update_org_secret(self, org, secret_name, body).await
+    }
+
+    async fn delete_org_secret(&self, org: &str, secret_name: &str) -> Result<()> {
+        Actions::delete_org_secret(self, org, secret_name).await
+    }
+
+    async fn list_selected_repos_for_org_secret_with(
+        &self,
+        org: &str,
+        secret_name: &str,
+        params: &ActionsListSelectedReposForOrgSecretParams,
+    ) -> Result<crate::types::ActionsListSelectedReposOrgSecretResponse> {
+        Actions::list_selected_repos_for_org_secret_with(self, org, secret_name, params).await
+    }
+
+    async fn set_selected_repos_for_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsSetSelectedReposOrgSecretRequest,
+    ) -> Result<()> {
+        Actions::set_selected_repos_for_org_secret(self, org, secret_name, body).await
+    }
+
+    async fn add_selected_repo_to_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        repository_id: i64,
+    ) -> Result<()> {
+        Actions::add_selected_repo_to_org_secret(self, org, secret_name, repository_id).await
+    }
+
+    async fn remove_selected_repo_from_org_secret(
+        &self,
+        org: &str,
+        secret_name: &str,
+        repository_id: i64,
+    ) -> Result<()> {
+        Actions::remove_selected_repo_from_org_secret(self, org, secret_name, repository_id).await
+    }
+
+    async fn list_artifacts_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActionsListArtifactsForRepoParams,
+    ) -> Result<crate::types::ActionsListArtifactsRepoResponse> {
+        Actions::list_artifacts_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn get_artifact(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+    ) -> Result<crate::types::Artifact> {
+        Actions::get_artifact(self, owner, repo, artifact_id).await
+    }
+
+    async fn delete_artifact(&self, owner: &str, repo: &str, artifact_id: i64) -> Result<()> {
+        Actions::delete_artifact(self, owner, repo, artifact_id).await
+    }
+
+    async fn download_artifact(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+        archive_format: &str,
+    ) -> Result<crate::Download> {
+        Actions::download_artifact(self, owner, repo, artifact_id, archive_format).await
+    }
+
+    async fn download_artifact_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        artifact_id: i64,
+        archive_format: &str,
+    ) -> Result<crate::DownloadStream> {
+        Actions::download_artifact_stream(self, owner, repo, artifact_id, archive_format).await
+    }
+
+    async fn get_job_for_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> Result<crate::types::Job> {
+        Actions::get_job_for_workflow_run(self, owner, repo, job_id).await
+    }
+
+    async fn download_job_logs_for_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> Result<crate::Download> {
+        Actions::download_job_logs_for_workflow_run(self, owner, repo, job_id).await
+    }
+
+    async fn download_job_logs_for_workflow_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: i64,
+    ) -> Result<crate::DownloadStream> {
+        Actions::download_job_logs_for_workflow_run_stream(self, owner, repo, job_id).await
+    }
+
+    async fn get_github_actions_permissions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::ActionsRepositoryPermissions> {
+        Actions::get_github_actions_permissions_repository(self, owner, repo).await
+    }
+
+    async fn set_github_actions_permissions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ActionsSetGithubPermissionsRepositoryRequest,
+    ) -> Result<()> {
+        Actions::set_github_actions_permissions_repository(self, owner, repo, body).await
+    }
+
+    async fn get_allowed_actions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::SelectedActions> {
+        Actions::get_allowed_actions_repository(self, owner, repo).await
+    }
+
+    async fn set_allowed_actions_repository(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::SelectedActions,
+    ) -> Result<()> {
+        Actions::set_allowed_actions_repository(self, owner, repo, body).await
+    }
+
+    async fn list_self_hosted_runners_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActionsListSelfHostedRunnersForRepoParams,
+    ) -> Result<crate::types::ActionsListSelfHostedRunnersRepoResponse> {
+        Actions::list_self_hosted_runners_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn list_runner_applications_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::RunnerApplication>> {
+        Actions::list_runner_applications_for_repo(self, owner, repo).await
+    }
+
+    async fn list_all_runner_applications_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::RunnerApplication>> {
+        Actions::list_all_runner_applications_for_repo(self, owner, repo).await
+    }
+
+    fn list_runner_applications_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RunnerApplication> {
+        Actions::list_runner_applications_for_repo_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn create_registration_token_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::AuthenticationToken> {
+        Actions::create_registration_token_for_repo(self, owner, repo).await
+    }
+
+    async fn create_remove_token_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::AuthenticationToken> {
+        Actions::create_remove_token_for_repo(self, owner, repo).await
+    }
+
+    async fn get_self_hosted_runner_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        runner_id: i64,
+    ) -> Result<crate::types::Runner> {
+        Actions::get_self_hosted_runner_for_repo(self, owner, repo, runner_id).await
+    }
+
+    async fn delete_self_hosted_runner_from_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        runner_id: i64,
+    ) -> Result<()> {
+        Actions::delete_self_hosted_runner_from_repo(self, owner, repo, runner_id).await
+    }
+
+    async fn list_workflow_runs_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActionsListWorkflowRunsForRepoParams,
+    ) -> Result<crate::types::ActionsListWorkflowRunsRepoResponse> {
+        Actions::list_workflow_runs_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn get_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::types::WorkflowRun> {
+        Actions::get_workflow_run(self, owner, repo, run_id).await
+    }
+
+    async fn delete_workflow_run(&self, owner: &str, repo: &str, run_id: i64) -> Result<()> {
+        Actions::delete_workflow_run(self, owner, repo, run_id).await
+    }
+
+    async fn get_reviews_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<Vec<crate::types::EnvironmentApprovals>> {
+        Actions::get_reviews_for_run(self, owner, repo, run_id).await
+    }
+
+    async fn get_all_reviews_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<Vec<crate::types::EnvironmentApprovals>> {
+        Actions::get_all_reviews_for_run(self, owner, repo, run_id).await
+    }
+
+    fn get_reviews_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::EnvironmentApprovals> {
+        Actions::get_reviews_for_run_stream(self, owner, repo, run_id, start, max_items)
+    }
+
+    async fn approve_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::types::EmptyObject> {
+        Actions::approve_workflow_run(self, owner, repo, run_id).await
+    }
+
+    async fn list_workflow_run_artifacts_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        params: &ActionsListWorkflowRunArtifactsParams,
+    ) -> Result<crate::types::ActionsListWorkflowRunArtifactsResponse> {
+        Actions::list_workflow_run_artifacts_with(self, owner, repo, run_id, params).await
+    }
+
+    async fn cancel_workflow_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::types::ActionsCancelWorkflowRunResponse> {
+        Actions::cancel_workflow_run(self, owner, repo, run_id).await
+    }
+
+    async fn list_jobs_for_workflow_run_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        params: &ActionsListJobsForWorkflowRunParams,
+    ) -> Result<crate::types::ActionsListJobsWorkflowRunResponse> {
+        Actions::list_jobs_for_workflow_run_with(self, owner, repo, run_id, params).await
+    }
+
+    async fn download_workflow_run_logs(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::Download> {
+        Actions::download_workflow_run_logs(self, owner, repo, run_id).await
+    }
+
+    async fn download_workflow_run_logs_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::DownloadStream> {
+        Actions::download_workflow_run_logs_stream(self, owner, repo, run_id).await
+    }
+
+    async fn delete_workflow_run_logs(&self, owner: &str, repo: &str, run_id: i64) -> Result<()> {
+        Actions::delete_workflow_run_logs(self, owner, repo, run_id).await
+    }
+
+    async fn get_pending_deployments_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<Vec<crate::types::PendingDeployment>> {
+        Actions::get_pending_deployments_for_run(self, owner, repo, run_id).await
+    }
+
+    async fn get_all_pending_deployments_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<Vec<crate::types::PendingDeployment>> {
+        Actions::get_all_pending_deployments_for_run(self, owner, repo, run_id).await
+    }
+
+    fn get_pending_deployments_for_run_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PendingDeployment> {
+        Actions::get_pending_deployments_for_run_stream(self, owner, repo, run_id, start, max_items)
+    }
+
+    async fn review_pending_deployments_for_run(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+        body: &crate::types::ActionsReviewPendingDeploymentsRunRequest,
+    ) -> Result<Vec<crate::types::Deployment>> {
+        Actions::review_pending_deployments_for_run(self, owner, repo, run_id, body).await
+    }
+
+    async fn re_run_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::types::ActionsReRunWorkflowResponse> {
+        Actions::re_run_workflow(self, owner, repo, run_id).await
+    }
+
+    async fn get_workflow_run_usage(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: i64,
+    ) -> Result<crate::types::WorkflowRunUsage> {
+        Actions::get_workflow_run_usage(self, owner, repo, run_id).await
+    }
+
+    async fn list_repo_secrets_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActionsListRepoSecretsParams,
+    ) -> Result<crate::types::ActionsListRepoSecretsResponse> {
+        Actions::list_repo_secrets_with(self, owner, repo, params).await
+    }
+
+    async fn get_repo_public_key(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::ActionsPublicKey> {
+        Actions::get_repo_public_key(self, owner, repo).await
+    }
+
+    async fn get_repo_secret(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+    ) -> Result<crate::types::ActionsSecret> {
+        Actions::get_repo_secret(self, owner, repo, secret_name).await
+    }
+
+    async fn create_or_update_repo_secret(
+        &self,
+        owner: &str,
+        repo: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsCreateUpdateRepoSecretRequest,
+    ) -> Result<crate::types::ActionsCreateUpdateRepoSecretResponse> {
+        Actions::create_or_update_repo_secret(self, owner, repo, secret_name, body).await
+    }
+
+    async fn delete_repo_secret(&self, owner: &str, repo: &str, secret_name: &str) -> Result<()> {
+        Actions::delete_repo_secret(self, owner, repo, secret_name).await
+    }
+
+    async fn list_repo_workflows_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActionsListRepoWorkflowsParams,
+    ) -> Result<crate::types::ActionsListRepoWorkflowsResponse> {
+        Actions::list_repo_workflows_with(self, owner, repo, params).await
+    }
+
+    async fn get_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> Result<crate::types::Workflow> {
+        Actions::get_workflow(self, owner, repo, workflow_id).await
+    }
+
+    async fn disable_workflow(&self, owner: &str, repo: &str, workflow_id: &str) -> Result<()> {
+        Actions::disable_workflow(self, owner, repo, workflow_id).await
+    }
+
+    async fn create_workflow_dispatch(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+        body: &crate::types::ActionsCreateWorkflowDispatchRequest,
+    ) -> Result<()> {
+        Actions::create_workflow_dispatch(self, owner, repo, workflow_id, body).await
+    }
+
+    async fn enable_workflow(&self, owner: &str, repo: &str, workflow_id: &str) -> Result<()> {
+        Actions::enable_workflow(self, owner, repo, workflow_id).await
+    }
+
+    async fn list_workflow_runs_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+        params: &ActionsListWorkflowRunsParams,
+    ) -> Result<crate::types::ActionsListWorkflowRunsResponse> {
+        Actions::list_workflow_runs_with(self, owner, repo, workflow_id, params).await
+    }
+
+    async fn get_workflow_usage(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: &str,
+    ) -> Result<crate::types::WorkflowUsage> {
+        Actions::get_workflow_usage(self, owner, repo, workflow_id).await
+    }
+
+    async fn list_environment_secrets_with(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        params: &ActionsListEnvironmentSecretsParams,
+    ) -> Result<crate::types::ActionsListEnvironmentSecretsResponse> {
+        Actions::list_environment_secrets_with(self, repository_id, environment_name, params).await
+    }
+
+    async fn get_environment_public_key(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+    ) -> Result<crate::types::ActionsPublicKey> {
+        Actions::get_environment_public_key(self, repository_id, environment_name).await
+    }
+
+    async fn get_environment_secret(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+    ) -> Result<crate::types::ActionsSecret> {
+        Actions::get_environment_secret(self, repository_id, environment_name, secret_name).await
+    }
+
+    async fn create_or_update_environment_secret(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+        body: &crate::types::ActionsCreateUpdateEnvironmentSecretRequest,
+    ) -> Result<crate::types::EmptyObject> {
+        Actions::create_or_update_environment_secret(
+            self,
+            repository_id,
+            environment_name,
+            secret_name,
+            body,
+        )
+        .await
+    }
+
+    async fn delete_environment_secret(
+        &self,
+        repository_id: i64,
+        environment_name: &str,
+        secret_name: &str,
+    ) -> Result<()> {
+        Actions::delete_environment_secret(self, repository_id, environment_name, secret_name).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Activity {
     pub client: Client,
@@ -23,19 +21,17 @@ impl Activity {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListPublicEventsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_public_events(
+    pub async fn list_public_events_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListPublicEventsParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -44,6 +40,22 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_public_events_with` and pass the query parameters as a `ActivityListPublicEventsParams` instead.
+     */
+    #[deprecated(note = "use `list_public_events_with` instead")]
+    pub async fn list_public_events(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Event>> {
+        let params = ActivityListPublicEventsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_public_events_with(&params).await
+    }
+
     /**
      * List public events.
      *
@@ -60,6 +72,41 @@ impl Activity {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List public events.
+     *
+     * This function performs a `GET` to the `/events` endpoint.
+     *
+     * As opposed to `list_all_public_events`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * We delay the public events feed by five minutes, which means the most recent event returned by the public events API actually occurred at least five minutes ago.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events>
+     */
+    pub fn list_public_events_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        let url = "/events".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get feeds.
      *
@@ -97,21 +144,19 @@ impl Activity {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListPublicEventsForRepoNetworkParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_public_events_for_repo_network(
+    pub async fn list_public_events_for_repo_network_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListPublicEventsForRepoNetworkParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -125,6 +170,25 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_public_events_for_repo_network_with` and pass the query parameters as a `ActivityListPublicEventsForRepoNetworkParams` instead.
+     */
+    #[deprecated(note = "use `list_public_events_for_repo_network_with` instead")]
+    pub async fn list_public_events_for_repo_network(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Event>> {
+        let params = ActivityListPublicEventsForRepoNetworkParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_public_events_for_repo_network_with(owner, repo, &params)
+            .await
+    }
+
     /**
      * List public events for a network of repositories.
      *
@@ -150,6 +214,48 @@ impl Activity {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List public events for a network of repositories.
+     *
+     * This function performs a `GET` to the `/networks/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_all_public_events_for_repo_network`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-network-of-repositories>
+     */
+    pub fn list_public_events_for_repo_network_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        let url = format!(
+            "/networks/{}/{}/events",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List notifications for the authenticated user.
      *
@@ -161,40 +267,30 @@ impl Activity {
      *
      * **Parameters:**
      *
-     * * `all: bool` -- If `true`, show notifications marked as read.
-     * * `participating: bool` -- If `true`, only shows notifications in which the user is directly participating or mentioned.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `before: chrono::DateTime<chrono::Utc>` -- Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListNotificationsForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_notifications_for_authenticated_user(
+    pub async fn list_notifications_for_authenticated_user_with(
         &self,
-        all: bool,
-        participating: bool,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        before: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListNotificationsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Thread>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if all {
+        if let Some(all) = &params.all {
             query_args.push(("all".to_string(), all.to_string()));
         }
-        if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+        if let Some(before) = &params.before {
+            query_args.push(("before".to_string(), before.to_rfc3339()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if participating {
+        if let Some(participating) = &params.participating {
             query_args.push(("participating".to_string(), participating.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/notifications?{}", query_);
@@ -202,6 +298,31 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_notifications_for_authenticated_user_with` and pass the query parameters as a `ActivityListNotificationsForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_notifications_for_authenticated_user_with` instead")]
+    pub async fn list_notifications_for_authenticated_user(
+        &self,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Thread>> {
+        let params = ActivityListNotificationsForAuthenticatedUserParams {
+            all: Some(all).filter(|v| *v),
+            before,
+            page: Some(page).filter(|v| *v > 0),
+            participating: Some(participating).filter(|v| *v),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+        };
+        self.list_notifications_for_authenticated_user_with(&params)
+            .await
+    }
+
     /**
      * List notifications for the authenticated user.
      *
@@ -212,7 +333,38 @@ impl Activity {
      * List all notifications for the current user, sorted by most recently updated.
      *
      * FROM: <https://docs.github.com/rest/reference/activity#list-notifications-for-the-authenticated-user>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ActivityListNotificationsForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_notifications_for_authenticated_user_with(
+        &self,
+        params: &ActivityListNotificationsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Thread>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(all) = &params.all {
+            query_args.push(("all".to_string(), all.to_string()));
+        }
+        if let Some(before) = &params.before {
+            query_args.push(("before".to_string(), before.to_rfc3339()));
+        }
+        if let Some(participating) = &params.participating {
+            query_args.push(("participating".to_string(), participating.to_string()));
+        }
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/notifications?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_notifications_for_authenticated_user_with` and pass the query parameters as a `ActivityListNotificationsForAuthenticatedUserParams` instead.
      */
+    #[deprecated(note = "use `list_all_notifications_for_authenticated_user_with` instead")]
     pub async fn list_all_notifications_for_authenticated_user(
         &self,
         all: bool,
@@ -220,23 +372,66 @@ impl Activity {
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Thread>> {
+        let params = ActivityListNotificationsForAuthenticatedUserParams {
+            all: Some(all).filter(|v| *v),
+            before,
+            participating: Some(participating).filter(|v| *v),
+            since,
+            ..Default::default()
+        };
+        self.list_all_notifications_for_authenticated_user_with(&params)
+            .await
+    }
+
+    /**
+     * List notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/notifications` endpoint.
+     *
+     * As opposed to `list_all_notifications_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List all notifications for the current user, sorted by most recently updated.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-notifications-for-the-authenticated-user>
+     */
+    pub fn list_notifications_for_authenticated_user_stream(
+        &self,
+        params: &ActivityListNotificationsForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Thread> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if all {
+        if let Some(all) = &params.all {
             query_args.push(("all".to_string(), all.to_string()));
         }
-        if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+        if let Some(before) = &params.before {
+            query_args.push(("before".to_string(), before.to_rfc3339()));
         }
-        if participating {
+        if let Some(participating) = &params.participating {
             query_args.push(("participating".to_string(), participating.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/notifications?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -251,7 +446,7 @@ impl Activity {
     pub async fn mark_notifications_as_read(
         &self,
         body: &crate::types::ActivityMarkNotificationsAsReadRequest,
-    ) -> Result<crate::types::Error> {
+    ) -> Result<crate::types::ActivityMarkNotificationsAsReadResponse> {
         let url = "/notifications".to_string();
         self.client
             .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -395,20 +590,18 @@ impl Activity {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListPublicOrgEventsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_public_org_events(
+    pub async fn list_public_org_events_with(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListPublicOrgEventsParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -421,6 +614,23 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_public_org_events_with` and pass the query parameters as a `ActivityListPublicOrgEventsParams` instead.
+     */
+    #[deprecated(note = "use `list_public_org_events_with` instead")]
+    pub async fn list_public_org_events(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Event>> {
+        let params = ActivityListPublicOrgEventsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_public_org_events_with(org, &params).await
+    }
+
     /**
      * List public organization events.
      *
@@ -441,6 +651,46 @@ impl Activity {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List public organization events.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/events` endpoint.
+     *
+     * As opposed to `list_all_public_org_events`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-organization-events>
+     */
+    pub fn list_public_org_events_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        let url = format!(
+            "/orgs/{}/events",
+            crate::progenitor_support::encode_path(org),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List repository events.
      *
@@ -454,21 +704,19 @@ impl Activity {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListRepoEventsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_repo_events(
+    pub async fn list_repo_events_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListRepoEventsParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -482,6 +730,24 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_repo_events_with` and pass the query parameters as a `ActivityListRepoEventsParams` instead.
+     */
+    #[deprecated(note = "use `list_repo_events_with` instead")]
+    pub async fn list_repo_events(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Event>> {
+        let params = ActivityListRepoEventsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_repo_events_with(owner, repo, &params).await
+    }
+
     /**
      * List repository events.
      *
@@ -507,6 +773,48 @@ impl Activity {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List repository events.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/events` endpoint.
+     *
+     * As opposed to `list_all_repo_events`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-events>
+     */
+    pub fn list_repo_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        let url = format!(
+            "/repos/{}/{}/events",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List repository notifications for the authenticated user.
      *
@@ -520,42 +828,32 @@ impl Activity {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `all: bool` -- If `true`, show notifications marked as read.
-     * * `participating: bool` -- If `true`, only shows notifications in which the user is directly participating or mentioned.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `before: chrono::DateTime<chrono::Utc>` -- Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListRepoNotificationsForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_repo_notifications_for_authenticated_user(
+    pub async fn list_repo_notifications_for_authenticated_user_with(
         &self,
         owner: &str,
         repo: &str,
-        all: bool,
-        participating: bool,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        before: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListRepoNotificationsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Thread>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if all {
+        if let Some(all) = &params.all {
             query_args.push(("all".to_string(), all.to_string()));
         }
-        if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+        if let Some(before) = &params.before {
+            query_args.push(("before".to_string(), before.to_rfc3339()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if participating {
+        if let Some(participating) = &params.participating {
             query_args.push(("participating".to_string(), participating.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
@@ -568,6 +866,33 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_repo_notifications_for_authenticated_user_with` and pass the query parameters as a `ActivityListRepoNotificationsForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_repo_notifications_for_authenticated_user_with` instead")]
+    pub async fn list_repo_notifications_for_authenticated_user(
+        &self,
+        owner: &str,
+        repo: &str,
+        all: bool,
+        participating: bool,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Thread>> {
+        let params = ActivityListRepoNotificationsForAuthenticatedUserParams {
+            all: Some(all).filter(|v| *v),
+            before,
+            page: Some(page).filter(|v| *v > 0),
+            participating: Some(participating).filter(|v| *v),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+        };
+        self.list_repo_notifications_for_authenticated_user_with(owner, repo, &params)
+            .await
+    }
+
     /**
      * List repository notifications for the authenticated user.
      *
@@ -578,7 +903,45 @@ impl Activity {
      * List all notifications for the current user.
      *
      * FROM: <https://docs.github.com/rest/reference/activity#list-repository-notifications-for-the-authenticated-user>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ActivityListRepoNotificationsForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_repo_notifications_for_authenticated_user_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListRepoNotificationsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Thread>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(all) = &params.all {
+            query_args.push(("all".to_string(), all.to_string()));
+        }
+        if let Some(before) = &params.before {
+            query_args.push(("before".to_string(), before.to_rfc3339()));
+        }
+        if let Some(participating) = &params.participating {
+            query_args.push(("participating".to_string(), participating.to_string()));
+        }
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/notifications?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_repo_notifications_for_authenticated_user_with` and pass the query parameters as a `ActivityListRepoNotificationsForAuthenticatedUserParams` instead.
      */
+    #[deprecated(note = "use `list_all_repo_notifications_for_authenticated_user_with` instead")]
     pub async fn list_all_repo_notifications_for_authenticated_user(
         &self,
         owner: &str,
@@ -588,18 +951,48 @@ impl Activity {
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Thread>> {
+        let params = ActivityListRepoNotificationsForAuthenticatedUserParams {
+            all: Some(all).filter(|v| *v),
+            before,
+            participating: Some(participating).filter(|v| *v),
+            since,
+            ..Default::default()
+        };
+        self.list_all_repo_notifications_for_authenticated_user_with(owner, repo, &params)
+            .await
+    }
+
+    /**
+     * List repository notifications for the authenticated user.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/notifications` endpoint.
+     *
+     * As opposed to `list_all_repo_notifications_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List all notifications for the current user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repository-notifications-for-the-authenticated-user>
+     */
+    pub fn list_repo_notifications_for_authenticated_user_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListRepoNotificationsForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Thread> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if all {
+        if let Some(all) = &params.all {
             query_args.push(("all".to_string(), all.to_string()));
         }
-        if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+        if let Some(before) = &params.before {
+            query_args.push(("before".to_string(), before.to_rfc3339()));
         }
-        if participating {
+        if let Some(participating) = &params.participating {
             query_args.push(("participating".to_string(), participating.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
@@ -609,7 +1002,22 @@ impl Activity {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -631,7 +1039,7 @@ impl Activity {
         owner: &str,
         repo: &str,
         body: &crate::types::ActivityMarkRepoNotificationsAsReadRequest,
-    ) -> Result<crate::types::PullsUpdateBranchResponse> {
+    ) -> Result<crate::types::ActivityMarkRepoNotificationsAsReadResponse> {
         let url = format!(
             "/repos/{}/{}/notifications",
             crate::progenitor_support::encode_path(owner),
@@ -658,21 +1066,19 @@ impl Activity {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListStargazersForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_stargazers_for_repo(
+    pub async fn list_stargazers_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListStargazersForRepoParams,
     ) -> Result<crate::types::ActivityListStargazersRepoResponseAnyOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -686,6 +1092,25 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_stargazers_for_repo_with` and pass the query parameters as a `ActivityListStargazersForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_stargazers_for_repo_with` instead")]
+    pub async fn list_stargazers_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActivityListStargazersRepoResponseAnyOf> {
+        let params = ActivityListStargazersForRepoParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_stargazers_for_repo_with(owner, repo, &params)
+            .await
+    }
+
     /**
      * List watchers.
      *
@@ -699,21 +1124,19 @@ impl Activity {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListWatchersForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_watchers_for_repo(
+    pub async fn list_watchers_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListWatchersForRepoParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -727,6 +1150,24 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_watchers_for_repo_with` and pass the query parameters as a `ActivityListWatchersForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_watchers_for_repo_with` instead")]
+    pub async fn list_watchers_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = ActivityListWatchersForRepoParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_watchers_for_repo_with(owner, repo, &params).await
+    }
+
     /**
      * List watchers.
      *
@@ -752,6 +1193,48 @@ impl Activity {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List watchers.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/subscribers` endpoint.
+     *
+     * As opposed to `list_all_watchers_for_repo`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the people watching the specified repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-watchers>
+     */
+    pub fn list_watchers_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        let url = format!(
+            "/repos/{}/{}/subscribers",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get a repository subscription.
      *
@@ -848,31 +1331,23 @@ impl Activity {
      *
      * **Parameters:**
      *
-     * * `sort: crate::types::Sort` -- One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListReposStarredByAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_repos_starred_by_authenticated_user(
+    pub async fn list_repos_starred_by_authenticated_user_with(
         &self,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListReposStarredByAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Repository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -881,6 +1356,27 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_repos_starred_by_authenticated_user_with` and pass the query parameters as a `ActivityListReposStarredByAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_repos_starred_by_authenticated_user_with` instead")]
+    pub async fn list_repos_starred_by_authenticated_user(
+        &self,
+        sort: crate::types::Sort,
+        direction: crate::types::Direction,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Repository>> {
+        let params = ActivityListReposStarredByAuthenticatedUserParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_repos_starred_by_authenticated_user_with(&params)
+            .await
+    }
+
     /**
      * List repositories starred by the authenticated user.
      *
@@ -893,23 +1389,91 @@ impl Activity {
      * You can also find out _when_ stars were created by passing the following custom [media type](https://docs.github.com/rest/overview/media-types/) via the `Accept` header:
      *
      * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-starred-by-the-authenticated-user>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ActivityListReposStarredByAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_repos_starred_by_authenticated_user_with(
+        &self,
+        params: &ActivityListReposStarredByAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Repository>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/user/starred?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_repos_starred_by_authenticated_user_with` and pass the query parameters as a `ActivityListReposStarredByAuthenticatedUserParams` instead.
      */
+    #[deprecated(note = "use `list_all_repos_starred_by_authenticated_user_with` instead")]
     pub async fn list_all_repos_starred_by_authenticated_user(
         &self,
         sort: crate::types::Sort,
-        direction: crate::types::Order,
+        direction: crate::types::Direction,
     ) -> Result<Vec<crate::types::Repository>> {
+        let params = ActivityListReposStarredByAuthenticatedUserParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_repos_starred_by_authenticated_user_with(&params)
+            .await
+    }
+
+    /**
+     * List repositories starred by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/starred` endpoint.
+     *
+     * As opposed to `list_all_repos_starred_by_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists repositories the authenticated user has starred.
+     *
+     * You can also find out _when_ stars were created by passing the following custom [media type](https://docs.github.com/rest/overview/media-types/) via the `Accept` header:
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-starred-by-the-authenticated-user>
+     */
+    pub fn list_repos_starred_by_authenticated_user_stream(
+        &self,
+        params: &ActivityListReposStarredByAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Repository> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/user/starred?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -999,19 +1563,17 @@ impl Activity {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListWatchedReposForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_watched_repos_for_authenticated_user(
+    pub async fn list_watched_repos_for_authenticated_user_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListWatchedReposForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1020,6 +1582,23 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_watched_repos_for_authenticated_user_with` and pass the query parameters as a `ActivityListWatchedReposForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_watched_repos_for_authenticated_user_with` instead")]
+    pub async fn list_watched_repos_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = ActivityListWatchedReposForAuthenticatedUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_watched_repos_for_authenticated_user_with(&params)
+            .await
+    }
+
     /**
      * List repositories watched by the authenticated user.
      *
@@ -1038,6 +1617,41 @@ impl Activity {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List repositories watched by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/subscriptions` endpoint.
+     *
+     * As opposed to `list_all_watched_repos_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists repositories the authenticated user is watching.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-the-authenticated-user>
+     */
+    pub fn list_watched_repos_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        let url = "/user/subscriptions".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List events for the authenticated user.
      *
@@ -1050,20 +1664,18 @@ impl Activity {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListEventsForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_events_for_authenticated_user(
+    pub async fn list_events_for_authenticated_user_with(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListEventsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1076,6 +1688,24 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_events_for_authenticated_user_with` and pass the query parameters as a `ActivityListEventsForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_events_for_authenticated_user_with` instead")]
+    pub async fn list_events_for_authenticated_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Event>> {
+        let params = ActivityListEventsForAuthenticatedUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_events_for_authenticated_user_with(username, &params)
+            .await
+    }
+
     /**
      * List events for the authenticated user.
      *
@@ -1099,6 +1729,46 @@ impl Activity {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events` endpoint.
+     *
+     * As opposed to `list_all_events_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * If you are authenticated as the given user, you will see your private events. Otherwise, you'll only see public events.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-for-the-authenticated-user>
+     */
+    pub fn list_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        let url = format!(
+            "/users/{}/events",
+            crate::progenitor_support::encode_path(username),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List organization events for the authenticated user.
      *
@@ -1112,21 +1782,19 @@ impl Activity {
      *
      * * `username: &str`
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListOrgEventsForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_org_events_for_authenticated_user(
+    pub async fn list_org_events_for_authenticated_user_with(
         &self,
         username: &str,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListOrgEventsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1140,6 +1808,25 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_org_events_for_authenticated_user_with` and pass the query parameters as a `ActivityListOrgEventsForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_org_events_for_authenticated_user_with` instead")]
+    pub async fn list_org_events_for_authenticated_user(
+        &self,
+        username: &str,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Event>> {
+        let params = ActivityListOrgEventsForAuthenticatedUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_org_events_for_authenticated_user_with(username, org, &params)
+            .await
+    }
+
     /**
      * List organization events for the authenticated user.
      *
@@ -1165,6 +1852,48 @@ impl Activity {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List organization events for the authenticated user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/orgs/{org}` endpoint.
+     *
+     * As opposed to `list_all_org_events_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * This is the user's organization dashboard. You must be authenticated as the user to view this.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-organization-events-for-the-authenticated-user>
+     */
+    pub fn list_org_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        let url = format!(
+            "/users/{}/events/orgs/{}",
+            crate::progenitor_support::encode_path(username),
+            crate::progenitor_support::encode_path(org),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List public events for a user.
      *
@@ -1177,20 +1906,18 @@ impl Activity {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListPublicEventsForUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_public_events_for_user(
+    pub async fn list_public_events_for_user_with(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListPublicEventsForUserParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1203,6 +1930,24 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_public_events_for_user_with` and pass the query parameters as a `ActivityListPublicEventsForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_public_events_for_user_with` instead")]
+    pub async fn list_public_events_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Event>> {
+        let params = ActivityListPublicEventsForUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_public_events_for_user_with(username, &params)
+            .await
+    }
+
     /**
      * List public events for a user.
      *
@@ -1226,6 +1971,46 @@ impl Activity {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List public events for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/events/public` endpoint.
+     *
+     * As opposed to `list_all_public_events_for_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-for-a-user>
+     */
+    pub fn list_public_events_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        let url = format!(
+            "/users/{}/events/public",
+            crate::progenitor_support::encode_path(username),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List events received by the authenticated user.
      *
@@ -1238,20 +2023,18 @@ impl Activity {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListReceivedEventsForUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_received_events_for_user(
+    pub async fn list_received_events_for_user_with(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListReceivedEventsForUserParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1264,6 +2047,24 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_received_events_for_user_with` and pass the query parameters as a `ActivityListReceivedEventsForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_received_events_for_user_with` instead")]
+    pub async fn list_received_events_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Event>> {
+        let params = ActivityListReceivedEventsForUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_received_events_for_user_with(username, &params)
+            .await
+    }
+
     /**
      * List events received by the authenticated user.
      *
@@ -1288,31 +2089,69 @@ impl Activity {
     }
 
     /**
-     * List public events received by a user.
-     *
-     * This function performs a `GET` to the `/users/{username}/received_events/public` endpoint.
-     *
+     * List events received by the authenticated user.
      *
+     * This function performs a `GET` to the `/users/{username}/received_events` endpoint.
      *
-     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-received-by-a-user>
+     * As opposed to `list_all_received_events_for_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
      *
-     * **Parameters:**
+     * These are events that you've received by watching repos and following users. If you are authenticated as the given user, you will see private events. Otherwise, you'll only see public events.
      *
-     * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * FROM: <https://docs.github.com/rest/reference/activity#list-events-received-by-the-authenticated-user>
      */
-    pub async fn list_received_public_events_for_user(
+    pub fn list_received_events_for_user_stream(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
-    ) -> Result<Vec<crate::types::Event>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        let url = format!(
+            "/users/{}/received_events",
+            crate::progenitor_support::encode_path(username),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * List public events received by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events/public` endpoint.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-received-by-a-user>
+     *
+     * **Parameters:**
+     *
+     * * `username: &str`
+     * * `params: &ActivityListReceivedPublicEventsForUserParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_received_public_events_for_user_with(
+        &self,
+        username: &str,
+        params: &ActivityListReceivedPublicEventsForUserParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1325,6 +2164,24 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_received_public_events_for_user_with` and pass the query parameters as a `ActivityListReceivedPublicEventsForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_received_public_events_for_user_with` instead")]
+    pub async fn list_received_public_events_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Event>> {
+        let params = ActivityListReceivedPublicEventsForUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_received_public_events_for_user_with(username, &params)
+            .await
+    }
+
     /**
      * List public events received by a user.
      *
@@ -1348,6 +2205,46 @@ impl Activity {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List public events received by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/received_events/public` endpoint.
+     *
+     * As opposed to `list_all_received_public_events_for_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-public-events-received-by-a-user>
+     */
+    pub fn list_received_public_events_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        let url = format!(
+            "/users/{}/received_events/public",
+            crate::progenitor_support::encode_path(username),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List repositories starred by a user.
      *
@@ -1362,32 +2259,24 @@ impl Activity {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `sort: crate::types::Sort` -- One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListReposStarredByUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_repos_starred_by_user(
+    pub async fn list_repos_starred_by_user_with(
         &self,
         username: &str,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListReposStarredByUserParams,
     ) -> Result<crate::types::ActivityListReposStarredByUserResponseAnyOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1400,6 +2289,28 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_repos_starred_by_user_with` and pass the query parameters as a `ActivityListReposStarredByUserParams` instead.
+     */
+    #[deprecated(note = "use `list_repos_starred_by_user_with` instead")]
+    pub async fn list_repos_starred_by_user(
+        &self,
+        username: &str,
+        sort: crate::types::Sort,
+        direction: crate::types::Direction,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ActivityListReposStarredByUserResponseAnyOf> {
+        let params = ActivityListReposStarredByUserParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_repos_starred_by_user_with(username, &params)
+            .await
+    }
+
     /**
      * List repositories watched by a user.
      *
@@ -1412,20 +2323,18 @@ impl Activity {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ActivityListReposWatchedByUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_repos_watched_by_user(
+    pub async fn list_repos_watched_by_user_with(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &ActivityListReposWatchedByUserParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1438,6 +2347,24 @@ impl Activity {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_repos_watched_by_user_with` and pass the query parameters as a `ActivityListReposWatchedByUserParams` instead.
+     */
+    #[deprecated(note = "use `list_repos_watched_by_user_with` instead")]
+    pub async fn list_repos_watched_by_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = ActivityListReposWatchedByUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_repos_watched_by_user_with(username, &params)
+            .await
+    }
+
     /**
      * List repositories watched by a user.
      *
@@ -1460,4 +2387,1369 @@ impl Activity {
 
         self.client.get_all_pages(&url, None).await
     }
+
+    /**
+     * List repositories watched by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/subscriptions` endpoint.
+     *
+     * As opposed to `list_all_repos_watched_by_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists repositories a user is watching.
+     *
+     * FROM: <https://docs.github.com/rest/reference/activity#list-repositories-watched-by-a-user>
+     */
+    pub fn list_repos_watched_by_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        let url = format!(
+            "/users/{}/subscriptions",
+            crate::progenitor_support::encode_path(username),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Activity::list_public_events_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListPublicEventsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActivityListPublicEventsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_public_events_for_repo_network_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListPublicEventsForRepoNetworkParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActivityListPublicEventsForRepoNetworkParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_notifications_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListNotificationsForAuthenticatedUserParams {
+    /// If `true`, show notifications marked as read.
+    pub all: Option<bool>,
+    /// Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub before: Option<chrono::DateTime<chrono::Utc>>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// If `true`, only shows notifications in which the user is directly participating or mentioned.
+    pub participating: Option<bool>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ActivityListNotificationsForAuthenticatedUserParams {
+    pub fn all(mut self, all: bool) -> Self {
+        self.all = Some(all);
+        self
+    }
+
+    pub fn before(mut self, before: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn participating(mut self, participating: bool) -> Self {
+        self.participating = Some(participating);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_public_org_events_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListPublicOrgEventsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActivityListPublicOrgEventsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_repo_events_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListRepoEventsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActivityListRepoEventsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_repo_notifications_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListRepoNotificationsForAuthenticatedUserParams {
+    /// If `true`, show notifications marked as read.
+    pub all: Option<bool>,
+    /// Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub before: Option<chrono::DateTime<chrono::Utc>>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// If `true`, only shows notifications in which the user is directly participating or mentioned.
+    pub participating: Option<bool>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ActivityListRepoNotificationsForAuthenticatedUserParams {
+    pub fn all(mut self, all: bool) -> Self {
+        self.all = Some(all);
+        self
+    }
+
+    pub fn before(mut self, before: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn participating(mut self, participating: bool) -> Self {
+        self.participating = Some(participating);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_stargazers_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListStargazersForRepoParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActivityListStargazersForRepoParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_watchers_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListWatchersForRepoParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActivityListWatchersForRepoParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_repos_starred_by_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListReposStarredByAuthenticatedUserParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Sorts the results of your query by when the label was `created` or `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
+    pub sort: Option<crate::types::Sort>,
+}
+
+impl ActivityListReposStarredByAuthenticatedUserParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_watched_repos_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListWatchedReposForAuthenticatedUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActivityListWatchedReposForAuthenticatedUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_events_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListEventsForAuthenticatedUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActivityListEventsForAuthenticatedUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_org_events_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListOrgEventsForAuthenticatedUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActivityListOrgEventsForAuthenticatedUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_public_events_for_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListPublicEventsForUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActivityListPublicEventsForUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_received_events_for_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListReceivedEventsForUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActivityListReceivedEventsForUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_received_public_events_for_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListReceivedPublicEventsForUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActivityListReceivedPublicEventsForUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_repos_starred_by_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListReposStarredByUserParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Sorts the results of your query by when the label was `created` or `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
+    pub sort: Option<crate::types::Sort>,
+}
+
+impl ActivityListReposStarredByUserParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The query parameters of `Activity::list_repos_watched_by_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityListReposWatchedByUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ActivityListReposWatchedByUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The functions of [`Activity`], for code that takes them as a trait, so that it can be
+/// tested with the `MockActivityApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ActivityApi: Send + Sync {
+    /// See [`Activity::list_public_events_with`].
+    async fn list_public_events_with(
+        &self,
+        params: &ActivityListPublicEventsParams,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_all_public_events`].
+    async fn list_all_public_events(&self) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_public_events_stream`].
+    fn list_public_events_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event>;
+
+    /// See [`Activity::get_feeds`].
+    async fn get_feeds(&self) -> Result<crate::types::Feed>;
+
+    /// See [`Activity::list_public_events_for_repo_network_with`].
+    async fn list_public_events_for_repo_network_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListPublicEventsForRepoNetworkParams,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_all_public_events_for_repo_network`].
+    async fn list_all_public_events_for_repo_network(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_public_events_for_repo_network_stream`].
+    fn list_public_events_for_repo_network_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event>;
+
+    /// See [`Activity::list_notifications_for_authenticated_user_with`].
+    async fn list_notifications_for_authenticated_user_with(
+        &self,
+        params: &ActivityListNotificationsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Thread>>;
+
+    /// See [`Activity::list_all_notifications_for_authenticated_user_with`].
+    async fn list_all_notifications_for_authenticated_user_with(
+        &self,
+        params: &ActivityListNotificationsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Thread>>;
+
+    /// See [`Activity::list_notifications_for_authenticated_user_stream`].
+    fn list_notifications_for_authenticated_user_stream(
+        &self,
+        params: &ActivityListNotificationsForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Thread>;
+
+    /// See [`Activity::mark_notifications_as_read`].
+    async fn mark_notifications_as_read(
+        &self,
+        body: &crate::types::ActivityMarkNotificationsAsReadRequest,
+    ) -> Result<crate::types::ActivityMarkNotificationsAsReadResponse>;
+
+    /// See [`Activity::get_thread`].
+    async fn get_thread(&self, thread_id: i64) -> Result<crate::types::Thread>;
+
+    /// See [`Activity::mark_thread_as_read`].
+    async fn mark_thread_as_read(&self, thread_id: i64) -> Result<()>;
+
+    /// See [`Activity::get_thread_subscription_for_authenticated_user`].
+    async fn get_thread_subscription_for_authenticated_user(
+        &self,
+        thread_id: i64,
+    ) -> Result<crate::types::ThreadSubscription>;
+
+    /// See [`Activity::set_thread_subscription`].
+    async fn set_thread_subscription(
+        &self,
+        thread_id: i64,
+        body: &crate::types::ActivitySetThreadSubscriptionRequest,
+    ) -> Result<crate::types::ThreadSubscription>;
+
+    /// See [`Activity::delete_thread_subscription`].
+    async fn delete_thread_subscription(&self, thread_id: i64) -> Result<()>;
+
+    /// See [`Activity::list_public_org_events_with`].
+    async fn list_public_org_events_with(
+        &self,
+        org: &str,
+        params: &ActivityListPublicOrgEventsParams,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_all_public_org_events`].
+    async fn list_all_public_org_events(&self, org: &str) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_public_org_events_stream`].
+    fn list_public_org_events_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event>;
+
+    /// See [`Activity::list_repo_events_with`].
+    async fn list_repo_events_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListRepoEventsParams,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_all_repo_events`].
+    async fn list_all_repo_events(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_repo_events_stream`].
+    fn list_repo_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event>;
+
+    /// See [`Activity::list_repo_notifications_for_authenticated_user_with`].
+    async fn list_repo_notifications_for_authenticated_user_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListRepoNotificationsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Thread>>;
+
+    /// See [`Activity::list_all_repo_notifications_for_authenticated_user_with`].
+    async fn list_all_repo_notifications_for_authenticated_user_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListRepoNotificationsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Thread>>;
+
+    /// See [`Activity::list_repo_notifications_for_authenticated_user_stream`].
+    fn list_repo_notifications_for_authenticated_user_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListRepoNotificationsForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Thread>;
+
+    /// See [`Activity::mark_repo_notifications_as_read`].
+    async fn mark_repo_notifications_as_read(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ActivityMarkRepoNotificationsAsReadRequest,
+    ) -> Result<crate::types::ActivityMarkRepoNotificationsAsReadResponse>;
+
+    /// See [`Activity::list_stargazers_for_repo_with`].
+    async fn list_stargazers_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListStargazersForRepoParams,
+    ) -> Result<crate::types::ActivityListStargazersRepoResponseAnyOf>;
+
+    /// See [`Activity::list_watchers_for_repo_with`].
+    async fn list_watchers_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListWatchersForRepoParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Activity::list_all_watchers_for_repo`].
+    async fn list_all_watchers_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Activity::list_watchers_for_repo_stream`].
+    fn list_watchers_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Activity::get_repo_subscription`].
+    async fn get_repo_subscription(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::RepositorySubscription>;
+
+    /// See [`Activity::set_repo_subscription`].
+    async fn set_repo_subscription(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ActivitySetRepoSubscriptionRequest,
+    ) -> Result<crate::types::RepositorySubscription>;
+
+    /// See [`Activity::delete_repo_subscription`].
+    async fn delete_repo_subscription(&self, owner: &str, repo: &str) -> Result<()>;
+
+    /// See [`Activity::list_repos_starred_by_authenticated_user_with`].
+    async fn list_repos_starred_by_authenticated_user_with(
+        &self,
+        params: &ActivityListReposStarredByAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Repository>>;
+
+    /// See [`Activity::list_all_repos_starred_by_authenticated_user_with`].
+    async fn list_all_repos_starred_by_authenticated_user_with(
+        &self,
+        params: &ActivityListReposStarredByAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Repository>>;
+
+    /// See [`Activity::list_repos_starred_by_authenticated_user_stream`].
+    fn list_repos_starred_by_authenticated_user_stream(
+        &self,
+        params: &ActivityListReposStarredByAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Repository>;
+
+    /// See [`Activity::check_repo_is_starred_by_authenticated_user`].
+    async fn check_repo_is_starred_by_authenticated_user(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<()>;
+
+    /// See [`Activity::star_repo_for_authenticated_user`].
+    async fn star_repo_for_authenticated_user(&self, owner: &str, repo: &str) -> Result<()>;
+
+    /// See [`Activity::unstar_repo_for_authenticated_user`].
+    async fn unstar_repo_for_authenticated_user(&self, owner: &str, repo: &str) -> Result<()>;
+
+    /// See [`Activity::list_watched_repos_for_authenticated_user_with`].
+    async fn list_watched_repos_for_authenticated_user_with(
+        &self,
+        params: &ActivityListWatchedReposForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Activity::list_all_watched_repos_for_authenticated_user`].
+    async fn list_all_watched_repos_for_authenticated_user(
+        &self,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Activity::list_watched_repos_for_authenticated_user_stream`].
+    fn list_watched_repos_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository>;
+
+    /// See [`Activity::list_events_for_authenticated_user_with`].
+    async fn list_events_for_authenticated_user_with(
+        &self,
+        username: &str,
+        params: &ActivityListEventsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_all_events_for_authenticated_user`].
+    async fn list_all_events_for_authenticated_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_events_for_authenticated_user_stream`].
+    fn list_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event>;
+
+    /// See [`Activity::list_org_events_for_authenticated_user_with`].
+    async fn list_org_events_for_authenticated_user_with(
+        &self,
+        username: &str,
+        org: &str,
+        params: &ActivityListOrgEventsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_all_org_events_for_authenticated_user`].
+    async fn list_all_org_events_for_authenticated_user(
+        &self,
+        username: &str,
+        org: &str,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_org_events_for_authenticated_user_stream`].
+    fn list_org_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event>;
+
+    /// See [`Activity::list_public_events_for_user_with`].
+    async fn list_public_events_for_user_with(
+        &self,
+        username: &str,
+        params: &ActivityListPublicEventsForUserParams,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_all_public_events_for_user`].
+    async fn list_all_public_events_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_public_events_for_user_stream`].
+    fn list_public_events_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event>;
+
+    /// See [`Activity::list_received_events_for_user_with`].
+    async fn list_received_events_for_user_with(
+        &self,
+        username: &str,
+        params: &ActivityListReceivedEventsForUserParams,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_all_received_events_for_user`].
+    async fn list_all_received_events_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_received_events_for_user_stream`].
+    fn list_received_events_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event>;
+
+    /// See [`Activity::list_received_public_events_for_user_with`].
+    async fn list_received_public_events_for_user_with(
+        &self,
+        username: &str,
+        params: &ActivityListReceivedPublicEventsForUserParams,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_all_received_public_events_for_user`].
+    async fn list_all_received_public_events_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Activity::list_received_public_events_for_user_stream`].
+    fn list_received_public_events_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event>;
+
+    /// See [`Activity::list_repos_starred_by_user_with`].
+    async fn list_repos_starred_by_user_with(
+        &self,
+        username: &str,
+        params: &ActivityListReposStarredByUserParams,
+    ) -> Result<crate::types::ActivityListReposStarredByUserResponseAnyOf>;
+
+    /// See [`Activity::list_repos_watched_by_user_with`].
+    async fn list_repos_watched_by_user_with(
+        &self,
+        username: &str,
+        params: &ActivityListReposWatchedByUserParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Activity::list_all_repos_watched_by_user`].
+    async fn list_all_repos_watched_by_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Activity::list_repos_watched_by_user_stream`].
+    fn list_repos_watched_by_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository>;
+}
+
+#[async_trait::async_trait]
+impl ActivityApi for Activity {
+    async fn list_public_events_with(
+        &self,
+        params: &ActivityListPublicEventsParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_public_events_with(self, params).await
+    }
+
+    async fn list_all_public_events(&self) -> Result<Vec<crate::types::Event>> {
+        Activity::list_all_public_events(self).await
+    }
+
+    fn list_public_events_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        Activity::list_public_events_stream(self, start, max_items)
+    }
+
+    async fn get_feeds(&self) -> Result<crate::types::Feed> {
+        Activity::get_feeds(self).await
+    }
+
+    async fn list_public_events_for_repo_network_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListPublicEventsForRepoNetworkParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_public_events_for_repo_network_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_public_events_for_repo_network(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_all_public_events_for_repo_network(self, owner, repo).await
+    }
+
+    fn list_public_events_for_repo_network_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        Activity::list_public_events_for_repo_network_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn list_notifications_for_authenticated_user_with(
+        &self,
+        params: &ActivityListNotificationsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Thread>> {
+        Activity::list_notifications_for_authenticated_user_with(self, params).await
+    }
+
+    async fn list_all_notifications_for_authenticated_user_with(
+        &self,
+        params: &ActivityListNotificationsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Thread>> {
+        Activity::list_all_notifications_for_authenticated_user_with(self, params).await
+    }
+
+    fn list_notifications_for_authenticated_user_stream(
+        &self,
+        params: &ActivityListNotificationsForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Thread> {
+        Activity::list_notifications_for_authenticated_user_stream(self, params, start, max_items)
+    }
+
+    async fn mark_notifications_as_read(
+        &self,
+        body: &crate::types::ActivityMarkNotificationsAsReadRequest,
+    ) -> Result<crate::types::ActivityMarkNotificationsAsReadResponse> {
+        Activity::mark_notifications_as_read(self, body).await
+    }
+
+    async fn get_thread(&self, thread_id: i64) -> Result<crate::types::Thread> {
+        Activity::get_thread(self, thread_id).await
+    }
+
+    async fn mark_thread_as_read(&self, thread_id: i64) -> Result<()> {
+        Activity::mark_thread_as_read(self, thread_id).await
+    }
+
+    async fn get_thread_subscription_for_authenticated_user(
+        &self,
+        thread_id: i64,
+    ) -> Result<crate::types::ThreadSubscription> {
+        Activity::get_thread_subscription_for_authenticated_user(self, thread_id).await
+    }
+
+    async fn set_thread_subscription(
+        &self,
+        thread_id: i64,
+        body: &crate::types::ActivitySetThreadSubscriptionRequest,
+    ) -> Result<crate::types::ThreadSubscription> {
+        Activity::set_thread_subscription(self, thread_id, body).await
+    }
+
+    async fn delete_thread_subscription(&self, thread_id: i64) -> Result<()> {
+        Activity::delete_thread_subscription(self, thread_id).await
+    }
+
+    async fn list_public_org_events_with(
+        &self,
+        org: &str,
+        params: &ActivityListPublicOrgEventsParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_public_org_events_with(self, org, params).await
+    }
+
+    async fn list_all_public_org_events(&self, org: &str) -> Result<Vec<crate::types::Event>> {
+        Activity::list_all_public_org_events(self, org).await
+    }
+
+    fn list_public_org_events_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        Activity::list_public_org_events_stream(self, org, start, max_items)
+    }
+
+    async fn list_repo_events_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListRepoEventsParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_repo_events_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_repo_events(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_all_repo_events(self, owner, repo).await
+    }
+
+    fn list_repo_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        Activity::list_repo_events_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn list_repo_notifications_for_authenticated_user_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListRepoNotificationsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Thread>> {
+        Activity::list_repo_notifications_for_authenticated_user_with(self, owner, repo, params)
+            .await
+    }
+
+    async fn list_all_repo_notifications_for_authenticated_user_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListRepoNotificationsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Thread>> {
+        Activity::list_all_repo_notifications_for_authenticated_user_with(self, owner, repo, params)
+            .await
+    }
+
+    fn list_repo_notifications_for_authenticated_user_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListRepoNotificationsForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Thread> {
+        Activity::list_repo_notifications_for_authenticated_user_stream(
+            self, owner, repo, params, start, max_items,
+        )
+    }
+
+    async fn mark_repo_notifications_as_read(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ActivityMarkRepoNotificationsAsReadRequest,
+    ) -> Result<crate::types::ActivityMarkRepoNotificationsAsReadResponse> {
+        Activity::mark_repo_notifications_as_read(self, owner, repo, body).await
+    }
+
+    async fn list_stargazers_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListStargazersForRepoParams,
+    ) -> Result<crate::types::ActivityListStargazersRepoResponseAnyOf> {
+        Activity::list_stargazers_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn list_watchers_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ActivityListWatchersForRepoParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Activity::list_watchers_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_watchers_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Activity::list_all_watchers_for_repo(self, owner, repo).await
+    }
+
+    fn list_watchers_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Activity::list_watchers_for_repo_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn get_repo_subscription(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::RepositorySubscription> {
+        Activity::get_repo_subscription(self, owner, repo).await
+    }
+
+    async fn set_repo_subscription(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ActivitySetRepoSubscriptionRequest,
+    ) -> Result<crate::types::RepositorySubscription> {
+        Activity::set_repo_subscription(self, owner, repo, body).await
+    }
+
+    async fn delete_repo_subscription(&self, owner: &str, repo: &str) -> Result<()> {
+        Activity::delete_repo_subscription(self, owner, repo).await
+    }
+
+    async fn list_repos_starred_by_authenticated_user_with(
+        &self,
+        params: &ActivityListReposStarredByAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Repository>> {
+        Activity::list_repos_starred_by_authenticated_user_with(self, params).await
+    }
+
+    async fn list_all_repos_starred_by_authenticated_user_with(
+        &self,
+        params: &ActivityListReposStarredByAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Repository>> {
+        Activity::list_all_repos_starred_by_authenticated_user_with(self, params).await
+    }
+
+    fn list_repos_starred_by_authenticated_user_stream(
+        &self,
+        params: &ActivityListReposStarredByAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Repository> {
+        Activity::list_repos_starred_by_authenticated_user_stream(self, params, start, max_items)
+    }
+
+    async fn check_repo_is_starred_by_authenticated_user(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<()> {
+        Activity::check_repo_is_starred_by_authenticated_user(self, owner, repo).await
+    }
+
+    async fn star_repo_for_authenticated_user(&self, owner: &str, repo: &str) -> Result<()> {
+        Activity::star_repo_for_authenticated_user(self, owner, repo).await
+    }
+
+    async fn unstar_repo_for_authenticated_user(&self, owner: &str, repo: &str) -> Result<()> {
+        Activity::unstar_repo_for_authenticated_user(self, owner, repo).await
+    }
+
+    async fn list_watched_repos_for_authenticated_user_with(
+        &self,
+        params: &ActivityListWatchedReposForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Activity::list_watched_repos_for_authenticated_user_with(self, params).await
+    }
+
+    async fn list_all_watched_repos_for_authenticated_user(
+        &self,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Activity::list_all_watched_repos_for_authenticated_user(self).await
+    }
+
+    fn list_watched_repos_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        Activity::list_watched_repos_for_authenticated_user_stream(self, start, max_items)
+    }
+
+    async fn list_events_for_authenticated_user_with(
+        &self,
+        username: &str,
+        params: &ActivityListEventsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_events_for_authenticated_user_with(self, username, params).await
+    }
+
+    async fn list_all_events_for_authenticated_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_all_events_for_authenticated_user(self, username).await
+    }
+
+    fn list_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        Activity::list_events_for_authenticated_user_stream(self, username, start, max_items)
+    }
+
+    async fn list_org_events_for_authenticated_user_with(
+        &self,
+        username: &str,
+        org: &str,
+        params: &ActivityListOrgEventsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_org_events_for_authenticated_user_with(self, username, org, params).await
+    }
+
+    async fn list_all_org_events_for_authenticated_user(
+        &self,
+        username: &str,
+        org: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_all_org_events_for_authenticated_user(self, username, org).await
+    }
+
+    fn list_org_events_for_authenticated_user_stream(
+        &self,
+        username: &str,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        Activity::list_org_events_for_authenticated_user_stream(
+            self, username, org, start, max_items,
+        )
+    }
+
+    async fn list_public_events_for_user_with(
+        &self,
+        username: &str,
+        params: &ActivityListPublicEventsForUserParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_public_events_for_user_with(self, username, params).await
+    }
+
+    async fn list_all_public_events_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_all_public_events_for_user(self, username).await
+    }
+
+    fn list_public_events_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        Activity::list_public_events_for_user_stream(self, username, start, max_items)
+    }
+
+    async fn list_received_events_for_user_with(
+        &self,
+        username: &str,
+        params: &ActivityListReceivedEventsForUserParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_received_events_for_user_with(self, username, params).await
+    }
+
+    async fn list_all_received_events_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_all_received_events_for_user(self, username).await
+    }
+
+    fn list_received_events_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        Activity::list_received_events_for_user_stream(self, username, start, max_items)
+    }
+
+    async fn list_received_public_events_for_user_with(
+        &self,
+        username: &str,
+        params: &ActivityListReceivedPublicEventsForUserParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_received_public_events_for_user_with(self, username, params).await
+    }
+
+    async fn list_all_received_public_events_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        Activity::list_all_received_public_events_for_user(self, username).await
+    }
+
+    fn list_received_public_events_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        Activity::list_received_public_events_for_user_stream(self, username, start, max_items)
+    }
+
+    async fn list_repos_starred_by_user_with(
+        &self,
+        username: &str,
+        params: &ActivityListReposStarredByUserParams,
+    ) -> Result<crate::types::ActivityListReposStarredByUserResponseAnyOf> {
+        Activity::list_repos_starred_by_user_with(self, username, params).await
+    }
+
+    async fn list_repos_watched_by_user_with(
+        &self,
+        username: &str,
+        params: &ActivityListReposWatchedByUserParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Activity::list_repos_watched_by_user_with(self, username, params).await
+    }
+
+    async fn list_all_repos_watched_by_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Activity::list_all_repos_watched_by_user(self, username).await
+    }
+
+    fn list_repos_watched_by_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        Activity::list_repos_watched_by_user_stream(self, username, start, max_items)
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Apps {
     pub client: Client,
@@ -23,7 +21,7 @@ impl Apps {
      *
      * FROM: <https://docs.github.com/rest/reference/apps#get-the-authenticated-app>
      */
-    pub async fn get_authenticated(&self) -> Result<crate::types::GitHubApp> {
+    pub async fn get_authenticated(&self) -> Result<crate::types::Integration> {
         let url = "/app".to_string();
         self.client.get(&url, None).await
     }
@@ -103,19 +101,17 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `cursor: &str` -- Used for pagination: the starting delivery from which the page of deliveries is fetched. Refer to the `link` header for the next and previous page cursors.
+     * * `params: &AppsListWebhookDeliveriesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_webhook_deliveries(
+    pub async fn list_webhook_deliveries_with(
         &self,
-        per_page: i64,
-        cursor: &str,
+        params: &AppsListWebhookDeliveriesParams,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -124,6 +120,22 @@ impl Apps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_webhook_deliveries_with` and pass the query parameters as a `AppsListWebhookDeliveriesParams` instead.
+     */
+    #[deprecated(note = "use `list_webhook_deliveries_with` instead")]
+    pub async fn list_webhook_deliveries(
+        &self,
+        per_page: i64,
+        cursor: &str,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        let params = AppsListWebhookDeliveriesParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_webhook_deliveries_with(&params).await
+    }
+
     /**
      * List deliveries for an app webhook.
      *
@@ -136,19 +148,82 @@ impl Apps {
      * You must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint.
      *
      * FROM: <https://docs.github.com/rest/reference/apps#list-deliveries-for-an-app-webhook>
+     *
+     * **Parameters:**
+     *
+     * * `params: &AppsListWebhookDeliveriesParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn list_all_webhook_deliveries_with(
+        &self,
+        params: &AppsListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(cursor) = &params.cursor {
+            query_args.push(("cursor".to_string(), cursor.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/app/hook/deliveries?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_webhook_deliveries_with` and pass the query parameters as a `AppsListWebhookDeliveriesParams` instead.
+     */
+    #[deprecated(note = "use `list_all_webhook_deliveries_with` instead")]
     pub async fn list_all_webhook_deliveries(
         &self,
         cursor: &str,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        let params = AppsListWebhookDeliveriesParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_webhook_deliveries_with(&params).await
+    }
+
+    /**
+     * List deliveries for an app webhook.
+     *
+     * This function performs a `GET` to the `/app/hook/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns a list of webhook deliveries for the webhook configured for a GitHub App.
+     *
+     * You must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-deliveries-for-an-app-webhook>
+     */
+    pub fn list_webhook_deliveries_stream(
+        &self,
+        params: &AppsListWebhookDeliveriesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::HookDeliveryItem> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/app/hook/deliveries?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -193,7 +268,10 @@ impl Apps {
      *
      * * `delivery_id: i64`
      */
-    pub async fn redeliver_webhook_delivery(&self, delivery_id: i64) -> Result<()> {
+    pub async fn redeliver_webhook_delivery(
+        &self,
+        delivery_id: i64,
+    ) -> Result<crate::types::AcceptedResponse> {
         let url = format!(
             "/app/hook/deliveries/{}/attempts",
             crate::progenitor_support::encode_path(&delivery_id.to_string()),
@@ -215,30 +293,24 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `outdated: &str`
+     * * `params: &AppsListInstallationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_installations(
+    pub async fn list_installations_with(
         &self,
-        per_page: i64,
-        page: i64,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        outdated: &str,
+        params: &AppsListInstallationsParams,
     ) -> Result<Vec<crate::types::Installation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !outdated.is_empty() {
+        if let Some(outdated) = &params.outdated {
             query_args.push(("outdated".to_string(), outdated.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/app/installations?{}", query_);
@@ -246,6 +318,26 @@ impl Apps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_installations_with` and pass the query parameters as a `AppsListInstallationsParams` instead.
+     */
+    #[deprecated(note = "use `list_installations_with` instead")]
+    pub async fn list_installations(
+        &self,
+        per_page: i64,
+        page: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        outdated: &str,
+    ) -> Result<Vec<crate::types::Installation>> {
+        let params = AppsListInstallationsParams {
+            outdated: Some(outdated.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+        };
+        self.list_installations_with(&params).await
+    }
+
     /**
      * List installations for the authenticated app.
      *
@@ -258,23 +350,90 @@ impl Apps {
      * The permissions the installation has are included under the `permissions` key.
      *
      * FROM: <https://docs.github.com/rest/reference/apps#list-installations-for-the-authenticated-app>
+     *
+     * **Parameters:**
+     *
+     * * `params: &AppsListInstallationsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_installations_with(
+        &self,
+        params: &AppsListInstallationsParams,
+    ) -> Result<Vec<crate::types::Installation>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(outdated) = &params.outdated {
+            query_args.push(("outdated".to_string(), outdated.to_string()));
+        }
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/app/installations?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_installations_with` and pass the query parameters as a `AppsListInstallationsParams` instead.
      */
+    #[deprecated(note = "use `list_all_installations_with` instead")]
     pub async fn list_all_installations(
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
         outdated: &str,
     ) -> Result<Vec<crate::types::Installation>> {
+        let params = AppsListInstallationsParams {
+            outdated: Some(outdated.to_string()).filter(|s| !s.is_empty()),
+            since,
+            ..Default::default()
+        };
+        self.list_all_installations_with(&params).await
+    }
+
+    /**
+     * List installations for the authenticated app.
+     *
+     * This function performs a `GET` to the `/app/installations` endpoint.
+     *
+     * As opposed to `list_all_installations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * You must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint.
+     *
+     * The permissions the installation has are included under the `permissions` key.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-installations-for-the-authenticated-app>
+     */
+    pub fn list_installations_stream(
+        &self,
+        params: &AppsListInstallationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Installation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !outdated.is_empty() {
+        if let Some(outdated) = &params.outdated {
             query_args.push(("outdated".to_string(), outdated.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/app/installations?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -429,7 +588,7 @@ impl Apps {
     pub async fn delete_authorization(
         &self,
         client_id: &str,
-        body: &crate::types::AppsCheckTokenRequest,
+        body: &crate::types::AppsDeleteAuthorizationRequest,
     ) -> Result<()> {
         let url = format!(
             "/applications/{}/grant",
@@ -517,7 +676,7 @@ impl Apps {
     pub async fn delete_token(
         &self,
         client_id: &str,
-        body: &crate::types::AppsCheckTokenRequest,
+        body: &crate::types::AppsDeleteTokenRequest,
     ) -> Result<()> {
         let url = format!(
             "/applications/{}/token",
@@ -545,7 +704,7 @@ impl Apps {
     pub async fn reset_token(
         &self,
         client_id: &str,
-        body: &crate::types::AppsCheckTokenRequest,
+        body: &crate::types::AppsResetTokenRequest,
     ) -> Result<crate::types::Authorization> {
         let url = format!(
             "/applications/{}/token",
@@ -690,7 +849,7 @@ impl Apps {
      *
      * * `app_slug: &str`
      */
-    pub async fn get_by_slug(&self, app_slug: &str) -> Result<crate::types::GitHubApp> {
+    pub async fn get_by_slug(&self, app_slug: &str) -> Result<crate::types::Integration> {
         let url = format!("/apps/{}", crate::progenitor_support::encode_path(app_slug),);
 
         self.client.get(&url, None).await
@@ -709,19 +868,17 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListReposAccessibleToInstallationParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_repos_accessible_to_installation(
+    pub async fn list_repos_accessible_to_installation_with(
         &self,
-        per_page: i64,
-        page: i64,
-    ) -> Result<crate::types::AppsListInstallationReposResponse> {
+        params: &AppsListReposAccessibleToInstallationParams,
+    ) -> Result<crate::types::AppsListReposAccessibleInstallationResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -730,6 +887,23 @@ impl Apps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_repos_accessible_to_installation_with` and pass the query parameters as a `AppsListReposAccessibleToInstallationParams` instead.
+     */
+    #[deprecated(note = "use `list_repos_accessible_to_installation_with` instead")]
+    pub async fn list_repos_accessible_to_installation(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::AppsListReposAccessibleInstallationResponse> {
+        let params = AppsListReposAccessibleToInstallationParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_repos_accessible_to_installation_with(&params)
+            .await
+    }
+
     /**
      * Revoke an installation access token.
      *
@@ -788,19 +962,17 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListPlansParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_plans(
+    pub async fn list_plans_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &AppsListPlansParams,
     ) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -809,6 +981,22 @@ impl Apps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_plans_with` and pass the query parameters as a `AppsListPlansParams` instead.
+     */
+    #[deprecated(note = "use `list_plans_with` instead")]
+    pub async fn list_plans(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
+        let params = AppsListPlansParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_plans_with(&params).await
+    }
+
     /**
      * List plans.
      *
@@ -827,6 +1015,43 @@ impl Apps {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List plans.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans` endpoint.
+     *
+     * As opposed to `list_all_plans`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all plans that are part of your GitHub Marketplace listing.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans>
+     */
+    pub fn list_plans_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MarketplaceListingPlan> {
+        let url = "/marketplace_listing/plans".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List accounts for a plan.
      *
@@ -841,32 +1066,24 @@ impl Apps {
      * **Parameters:**
      *
      * * `plan_id: i64` -- plan_id parameter.
-     * * `sort: crate::types::Sort` -- One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListAccountsForPlanParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_accounts_for_plan(
+    pub async fn list_accounts_for_plan_with(
         &self,
         plan_id: i64,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &AppsListAccountsForPlanParams,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -879,6 +1096,27 @@ impl Apps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_accounts_for_plan_with` and pass the query parameters as a `AppsListAccountsForPlanParams` instead.
+     */
+    #[deprecated(note = "use `list_accounts_for_plan_with` instead")]
+    pub async fn list_accounts_for_plan(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Direction,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
+        let params = AppsListAccountsForPlanParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_accounts_for_plan_with(plan_id, &params).await
+    }
+
     /**
      * List accounts for a plan.
      *
@@ -891,18 +1129,76 @@ impl Apps {
      * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
      *
      * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan>
+     *
+     * **Parameters:**
+     *
+     * * `params: &AppsListAccountsForPlanParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_accounts_for_plan_with(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanParams,
+    ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/marketplace_listing/plans/{}/accounts?{}",
+            crate::progenitor_support::encode_path(&plan_id.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_accounts_for_plan_with` and pass the query parameters as a `AppsListAccountsForPlanParams` instead.
      */
+    #[deprecated(note = "use `list_all_accounts_for_plan_with` instead")]
     pub async fn list_all_accounts_for_plan(
         &self,
         plan_id: i64,
         sort: crate::types::Sort,
-        direction: crate::types::Order,
+        direction: crate::types::Direction,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
+        let params = AppsListAccountsForPlanParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_accounts_for_plan_with(plan_id, &params).await
+    }
+
+    /**
+     * List accounts for a plan.
+     *
+     * This function performs a `GET` to the `/marketplace_listing/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_all_accounts_for_plan`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns user and organization accounts associated with the specified plan, including free plans. For per-seat pricing, you see the list of accounts that have purchased the plan, including the number of seats purchased. When someone submits a plan change that won't be processed until the end of their billing cycle, you will also see the upcoming pending change.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan>
+     */
+    pub fn list_accounts_for_plan_stream(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MarketplacePurchaseData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -912,7 +1208,22 @@ impl Apps {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -955,19 +1266,17 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListPlansStubbedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_plans_stubbed(
+    pub async fn list_plans_stubbed_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &AppsListPlansStubbedParams,
     ) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -976,6 +1285,22 @@ impl Apps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_plans_stubbed_with` and pass the query parameters as a `AppsListPlansStubbedParams` instead.
+     */
+    #[deprecated(note = "use `list_plans_stubbed_with` instead")]
+    pub async fn list_plans_stubbed(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
+        let params = AppsListPlansStubbedParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_plans_stubbed_with(&params).await
+    }
+
     /**
      * List plans (stubbed).
      *
@@ -996,6 +1321,43 @@ impl Apps {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List plans (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans` endpoint.
+     *
+     * As opposed to `list_all_plans_stubbed`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all plans that are part of your GitHub Marketplace listing.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-plans-stubbed>
+     */
+    pub fn list_plans_stubbed_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MarketplaceListingPlan> {
+        let url = "/marketplace_listing/stubbed/plans".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List accounts for a plan (stubbed).
      *
@@ -1010,32 +1372,24 @@ impl Apps {
      * **Parameters:**
      *
      * * `plan_id: i64` -- plan_id parameter.
-     * * `sort: crate::types::Sort` -- One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListAccountsForPlanStubbedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_accounts_for_plan_stubbed(
+    pub async fn list_accounts_for_plan_stubbed_with(
         &self,
         plan_id: i64,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &AppsListAccountsForPlanStubbedParams,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1048,6 +1402,28 @@ impl Apps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_accounts_for_plan_stubbed_with` and pass the query parameters as a `AppsListAccountsForPlanStubbedParams` instead.
+     */
+    #[deprecated(note = "use `list_accounts_for_plan_stubbed_with` instead")]
+    pub async fn list_accounts_for_plan_stubbed(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Direction,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
+        let params = AppsListAccountsForPlanStubbedParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_accounts_for_plan_stubbed_with(plan_id, &params)
+            .await
+    }
+
     /**
      * List accounts for a plan (stubbed).
      *
@@ -1060,18 +1436,21 @@ impl Apps {
      * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
      *
      * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan-stubbed>
+     *
+     * **Parameters:**
+     *
+     * * `params: &AppsListAccountsForPlanStubbedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_accounts_for_plan_stubbed(
+    pub async fn list_all_accounts_for_plan_stubbed_with(
         &self,
         plan_id: i64,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
+        params: &AppsListAccountsForPlanStubbedParams,
     ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1085,7 +1464,78 @@ impl Apps {
     }
 
     /**
-     * Get an organization installation for the authenticated app.
+     * Deprecated: use `list_all_accounts_for_plan_stubbed_with` and pass the query parameters as a `AppsListAccountsForPlanStubbedParams` instead.
+     */
+    #[deprecated(note = "use `list_all_accounts_for_plan_stubbed_with` instead")]
+    pub async fn list_all_accounts_for_plan_stubbed(
+        &self,
+        plan_id: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Direction,
+    ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
+        let params = AppsListAccountsForPlanStubbedParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_accounts_for_plan_stubbed_with(plan_id, &params)
+            .await
+    }
+
+    /**
+     * List accounts for a plan (stubbed).
+     *
+     * This function performs a `GET` to the `/marketplace_listing/stubbed/plans/{plan_id}/accounts` endpoint.
+     *
+     * As opposed to `list_all_accounts_for_plan_stubbed`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns repository and organization accounts associated with the specified plan, including free plans. For per-seat pricing, you see the list of accounts that have purchased the plan, including the number of seats purchased. When someone submits a plan change that won't be processed until the end of their billing cycle, you will also see the upcoming pending change.
+     *
+     * GitHub Apps must use a [JWT](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/#authenticating-as-a-github-app) to access this endpoint. OAuth Apps must use [basic authentication](https://docs.github.com/rest/overview/other-authentication-methods#basic-authentication) with their client ID and client secret to access this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-accounts-for-a-plan-stubbed>
+     */
+    pub fn list_accounts_for_plan_stubbed_stream(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanStubbedParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MarketplacePurchaseData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/marketplace_listing/stubbed/plans/{}/accounts?{}",
+            crate::progenitor_support::encode_path(&plan_id.to_string()),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * Get an organization installation for the authenticated app.
      *
      * This function performs a `GET` to the `/orgs/{org}/installation` endpoint.
      *
@@ -1132,7 +1582,7 @@ impl Apps {
         owner: &str,
         repo: &str,
         content_reference_id: i64,
-        body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
+        body: &crate::types::AppsCreateContentAttachmentRequest,
     ) -> Result<crate::types::ContentReferenceAttachment> {
         let url = format!(
             "/repos/{}/{}/content_references/{}/attachments",
@@ -1193,19 +1643,17 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListInstallationsForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_installations_for_authenticated_user(
+    pub async fn list_installations_for_authenticated_user_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &AppsListInstallationsForAuthenticatedUserParams,
     ) -> Result<crate::types::AppsListInstallationsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1214,6 +1662,23 @@ impl Apps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_installations_for_authenticated_user_with` and pass the query parameters as a `AppsListInstallationsForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_installations_for_authenticated_user_with` instead")]
+    pub async fn list_installations_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::AppsListInstallationsResponse> {
+        let params = AppsListInstallationsForAuthenticatedUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_installations_for_authenticated_user_with(&params)
+            .await
+    }
+
     /**
      * List repositories accessible to the user access token.
      *
@@ -1232,20 +1697,18 @@ impl Apps {
      * **Parameters:**
      *
      * * `installation_id: i64` -- installation_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListInstallationReposForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_installation_repos_for_authenticated_user(
+    pub async fn list_installation_repos_for_authenticated_user_with(
         &self,
         installation_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &AppsListInstallationReposForAuthenticatedUserParams,
     ) -> Result<crate::types::AppsListInstallationReposResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1258,6 +1721,24 @@ impl Apps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_installation_repos_for_authenticated_user_with` and pass the query parameters as a `AppsListInstallationReposForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_installation_repos_for_authenticated_user_with` instead")]
+    pub async fn list_installation_repos_for_authenticated_user(
+        &self,
+        installation_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::AppsListInstallationReposResponse> {
+        let params = AppsListInstallationReposForAuthenticatedUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_installation_repos_for_authenticated_user_with(installation_id, &params)
+            .await
+    }
+
     /**
      * Add a repository to an app installation.
      *
@@ -1329,19 +1810,17 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListSubscriptionsForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_subscriptions_for_authenticated_user(
+    pub async fn list_subscriptions_for_authenticated_user_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &AppsListSubscriptionsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1350,6 +1829,23 @@ impl Apps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_subscriptions_for_authenticated_user_with` and pass the query parameters as a `AppsListSubscriptionsForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_subscriptions_for_authenticated_user_with` instead")]
+    pub async fn list_subscriptions_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
+        let params = AppsListSubscriptionsForAuthenticatedUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_subscriptions_for_authenticated_user_with(&params)
+            .await
+    }
+
     /**
      * List subscriptions for the authenticated user.
      *
@@ -1368,6 +1864,41 @@ impl Apps {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List subscriptions for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases` endpoint.
+     *
+     * As opposed to `list_all_subscriptions_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the active subscriptions for the authenticated user. You must use a [user-to-server OAuth access token](https://docs.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/#identifying-users-on-your-site), created for a user who has authorized your GitHub App, to access this endpoint. . OAuth Apps must authenticate using an [OAuth token](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user>
+     */
+    pub fn list_subscriptions_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserMarketplacePurchase> {
+        let url = "/user/marketplace_purchases".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List subscriptions for the authenticated user (stubbed).
      *
@@ -1379,19 +1910,17 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &AppsListSubscriptionsForAuthenticatedUserStubbedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_subscriptions_for_authenticated_user_stubbed(
+    pub async fn list_subscriptions_for_authenticated_user_stubbed_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &AppsListSubscriptionsForAuthenticatedUserStubbedParams,
     ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1400,6 +1929,23 @@ impl Apps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_subscriptions_for_authenticated_user_stubbed_with` and pass the query parameters as a `AppsListSubscriptionsForAuthenticatedUserStubbedParams` instead.
+     */
+    #[deprecated(note = "use `list_subscriptions_for_authenticated_user_stubbed_with` instead")]
+    pub async fn list_subscriptions_for_authenticated_user_stubbed(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
+        let params = AppsListSubscriptionsForAuthenticatedUserStubbedParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_subscriptions_for_authenticated_user_stubbed_with(&params)
+            .await
+    }
+
     /**
      * List subscriptions for the authenticated user (stubbed).
      *
@@ -1418,6 +1964,41 @@ impl Apps {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List subscriptions for the authenticated user (stubbed).
+     *
+     * This function performs a `GET` to the `/user/marketplace_purchases/stubbed` endpoint.
+     *
+     * As opposed to `list_all_subscriptions_for_authenticated_user_stubbed`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the active subscriptions for the authenticated user. You must use a [user-to-server OAuth access token](https://docs.github.com/apps/building-github-apps/identifying-and-authorizing-users-for-github-apps/#identifying-users-on-your-site), created for a user who has authorized your GitHub App, to access this endpoint. . OAuth Apps must authenticate using an [OAuth token](https://docs.github.com/apps/building-github-apps/authenticating-with-github-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/apps#list-subscriptions-for-the-authenticated-user-stubbed>
+     */
+    pub fn list_subscriptions_for_authenticated_user_stubbed_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserMarketplacePurchase> {
+        let url = "/user/marketplace_purchases/stubbed".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get a user installation for the authenticated app.
      *
@@ -1445,3 +2026,1011 @@ impl Apps {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Apps::list_webhook_deliveries_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsListWebhookDeliveriesParams {
+    /// Used for pagination: the starting delivery from which the page of deliveries is fetched. Refer to the `link` header for the next and previous page cursors.
+    pub cursor: Option<String>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl AppsListWebhookDeliveriesParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Apps::list_installations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsListInstallationsParams {
+    pub outdated: Option<String>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl AppsListInstallationsParams {
+    pub fn outdated<T: ToString>(mut self, outdated: T) -> Self {
+        self.outdated = Some(outdated.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+}
+
+/// The query parameters of `Apps::list_repos_accessible_to_installation_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsListReposAccessibleToInstallationParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl AppsListReposAccessibleToInstallationParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Apps::list_plans_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsListPlansParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl AppsListPlansParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Apps::list_accounts_for_plan_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsListAccountsForPlanParams {
+    /// To return the oldest accounts first, set to `asc`. Can be one of `asc` or `desc`. Ignored without the `sort` parameter.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Sorts the results of your query by when the label was `created` or `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
+    pub sort: Option<crate::types::Sort>,
+}
+
+impl AppsListAccountsForPlanParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The query parameters of `Apps::list_plans_stubbed_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsListPlansStubbedParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl AppsListPlansStubbedParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Apps::list_accounts_for_plan_stubbed_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsListAccountsForPlanStubbedParams {
+    /// To return the oldest accounts first, set to `asc`. Can be one of `asc` or `desc`. Ignored without the `sort` parameter.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Sorts the results of your query by when the label was `created` or `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
+    pub sort: Option<crate::types::Sort>,
+}
+
+impl AppsListAccountsForPlanStubbedParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The query parameters of `Apps::list_installations_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsListInstallationsForAuthenticatedUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl AppsListInstallationsForAuthenticatedUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Apps::list_installation_repos_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsListInstallationReposForAuthenticatedUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl AppsListInstallationReposForAuthenticatedUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Apps::list_subscriptions_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsListSubscriptionsForAuthenticatedUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl AppsListSubscriptionsForAuthenticatedUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Apps::list_subscriptions_for_authenticated_user_stubbed_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsListSubscriptionsForAuthenticatedUserStubbedParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl AppsListSubscriptionsForAuthenticatedUserStubbedParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The functions of [`Apps`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAppsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AppsApi: Send + Sync {
+    /// See [`Apps::get_authenticated`].
+    async fn get_authenticated(&self) -> Result<crate::types::Integration>;
+
+    /// See [`Apps::create_from_manifest`].
+    async fn create_from_manifest(
+        &self,
+        code: &str,
+    ) -> Result<crate::types::AppsCreateFromManifestResponseAllOf>;
+
+    /// See [`Apps::get_webhook_config_for_app`].
+    async fn get_webhook_config_for_app(&self) -> Result<crate::types::WebhookConfig>;
+
+    /// See [`Apps::update_webhook_config_for_app`].
+    async fn update_webhook_config_for_app(
+        &self,
+        body: &crate::types::AppsUpdateWebhookConfigAppRequest,
+    ) -> Result<crate::types::WebhookConfig>;
+
+    /// See [`Apps::list_webhook_deliveries_with`].
+    async fn list_webhook_deliveries_with(
+        &self,
+        params: &AppsListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>>;
+
+    /// See [`Apps::list_all_webhook_deliveries_with`].
+    async fn list_all_webhook_deliveries_with(
+        &self,
+        params: &AppsListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>>;
+
+    /// See [`Apps::list_webhook_deliveries_stream`].
+    fn list_webhook_deliveries_stream(
+        &self,
+        params: &AppsListWebhookDeliveriesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::HookDeliveryItem>;
+
+    /// See [`Apps::get_webhook_delivery`].
+    async fn get_webhook_delivery(&self, delivery_id: i64) -> Result<crate::types::HookDelivery>;
+
+    /// See [`Apps::redeliver_webhook_delivery`].
+    async fn redeliver_webhook_delivery(
+        &self,
+        delivery_id: i64,
+    ) -> Result<crate::types::AcceptedResponse>;
+
+    /// See [`Apps::list_installations_with`].
+    async fn list_installations_with(
+        &self,
+        params: &AppsListInstallationsParams,
+    ) -> Result<Vec<crate::types::Installation>>;
+
+    /// See [`Apps::list_all_installations_with`].
+    async fn list_all_installations_with(
+        &self,
+        params: &AppsListInstallationsParams,
+    ) -> Result<Vec<crate::types::Installation>>;
+
+    /// See [`Apps::list_installations_stream`].
+    fn list_installations_stream(
+        &self,
+        params: &AppsListInstallationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Installation>;
+
+    /// See [`Apps::get_installation`].
+    async fn get_installation(&self, installation_id: i64) -> Result<crate::types::Installation>;
+
+    /// See [`Apps::delete_installation`].
+    async fn delete_installation(&self, installation_id: i64) -> Result<()>;
+
+    /// See [`Apps::create_installation_access_token`].
+    async fn create_installation_access_token(
+        &self,
+        installation_id: i64,
+        body: &crate::types::AppsCreateInstallationAccessTokenRequest,
+    ) -> Result<crate::types::InstallationToken>;
+
+    /// See [`Apps::suspend_installation`].
+    async fn suspend_installation(&self, installation_id: i64) -> Result<()>;
+
+    /// See [`Apps::unsuspend_installation`].
+    async fn unsuspend_installation(&self, installation_id: i64) -> Result<()>;
+
+    /// See [`Apps::delete_authorization`].
+    async fn delete_authorization(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsDeleteAuthorizationRequest,
+    ) -> Result<()>;
+
+    /// See [`Apps::revoke_grant_for_application`].
+    async fn revoke_grant_for_application(&self, client_id: &str, access_token: &str)
+        -> Result<()>;
+
+    /// See [`Apps::check_token`].
+    async fn check_token(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsCheckTokenRequest,
+    ) -> Result<crate::types::Authorization>;
+
+    /// See [`Apps::delete_token`].
+    async fn delete_token(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsDeleteTokenRequest,
+    ) -> Result<()>;
+
+    /// See [`Apps::reset_token`].
+    async fn reset_token(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsResetTokenRequest,
+    ) -> Result<crate::types::Authorization>;
+
+    /// See [`Apps::scope_token`].
+    async fn scope_token(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsScopeTokenRequest,
+    ) -> Result<crate::types::Authorization>;
+
+    /// See [`Apps::check_authorization`].
+    async fn check_authorization(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> Result<crate::types::Authorization>;
+
+    /// See [`Apps::reset_authorization`].
+    async fn reset_authorization(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> Result<crate::types::Authorization>;
+
+    /// See [`Apps::revoke_authorization_for_application`].
+    async fn revoke_authorization_for_application(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> Result<()>;
+
+    /// See [`Apps::get_by_slug`].
+    async fn get_by_slug(&self, app_slug: &str) -> Result<crate::types::Integration>;
+
+    /// See [`Apps::list_repos_accessible_to_installation_with`].
+    async fn list_repos_accessible_to_installation_with(
+        &self,
+        params: &AppsListReposAccessibleToInstallationParams,
+    ) -> Result<crate::types::AppsListReposAccessibleInstallationResponse>;
+
+    /// See [`Apps::revoke_installation_access_token`].
+    async fn revoke_installation_access_token(&self) -> Result<()>;
+
+    /// See [`Apps::get_subscription_plan_for_account`].
+    async fn get_subscription_plan_for_account(
+        &self,
+        account_id: i64,
+    ) -> Result<crate::types::MarketplacePurchaseData>;
+
+    /// See [`Apps::list_plans_with`].
+    async fn list_plans_with(
+        &self,
+        params: &AppsListPlansParams,
+    ) -> Result<Vec<crate::types::MarketplaceListingPlan>>;
+
+    /// See [`Apps::list_all_plans`].
+    async fn list_all_plans(&self) -> Result<Vec<crate::types::MarketplaceListingPlan>>;
+
+    /// See [`Apps::list_plans_stream`].
+    fn list_plans_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MarketplaceListingPlan>;
+
+    /// See [`Apps::list_accounts_for_plan_with`].
+    async fn list_accounts_for_plan_with(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanParams,
+    ) -> Result<Vec<crate::types::MarketplacePurchaseData>>;
+
+    /// See [`Apps::list_all_accounts_for_plan_with`].
+    async fn list_all_accounts_for_plan_with(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanParams,
+    ) -> Result<Vec<crate::types::MarketplacePurchaseData>>;
+
+    /// See [`Apps::list_accounts_for_plan_stream`].
+    fn list_accounts_for_plan_stream(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MarketplacePurchaseData>;
+
+    /// See [`Apps::get_subscription_plan_for_account_stubbed`].
+    async fn get_subscription_plan_for_account_stubbed(
+        &self,
+        account_id: i64,
+    ) -> Result<crate::types::MarketplacePurchaseData>;
+
+    /// See [`Apps::list_plans_stubbed_with`].
+    async fn list_plans_stubbed_with(
+        &self,
+        params: &AppsListPlansStubbedParams,
+    ) -> Result<Vec<crate::types::MarketplaceListingPlan>>;
+
+    /// See [`Apps::list_all_plans_stubbed`].
+    async fn list_all_plans_stubbed(&self) -> Result<Vec<crate::types::MarketplaceListingPlan>>;
+
+    /// See [`Apps::list_plans_stubbed_stream`].
+    fn list_plans_stubbed_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MarketplaceListingPlan>;
+
+    /// See [`Apps::list_accounts_for_plan_stubbed_with`].
+    async fn list_accounts_for_plan_stubbed_with(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanStubbedParams,
+    ) -> Result<Vec<crate::types::MarketplacePurchaseData>>;
+
+    /// See [`Apps::list_all_accounts_for_plan_stubbed_with`].
+    async fn list_all_accounts_for_plan_stubbed_with(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanStubbedParams,
+    ) -> Result<Vec<crate::types::MarketplacePurchaseData>>;
+
+    /// See [`Apps::list_accounts_for_plan_stubbed_stream`].
+    fn list_accounts_for_plan_stubbed_stream(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanStubbedParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MarketplacePurchaseData>;
+
+    /// See [`Apps::get_org_installation`].
+    async fn get_org_installation(&self, org: &str) -> Result<crate::types::Installation>;
+
+    /// See [`Apps::create_content_attachment`].
+    async fn create_content_attachment(
+        &self,
+        owner: &str,
+        repo: &str,
+        content_reference_id: i64,
+        body: &crate::types::AppsCreateContentAttachmentRequest,
+    ) -> Result<crate::types::ContentReferenceAttachment>;
+
+    /// See [`Apps::get_repo_installation`].
+    async fn get_repo_installation(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::Installation>;
+
+    /// See [`Apps::list_installations_for_authenticated_user_with`].
+    async fn list_installations_for_authenticated_user_with(
+        &self,
+        params: &AppsListInstallationsForAuthenticatedUserParams,
+    ) -> Result<crate::types::AppsListInstallationsResponse>;
+
+    /// See [`Apps::list_installation_repos_for_authenticated_user_with`].
+    async fn list_installation_repos_for_authenticated_user_with(
+        &self,
+        installation_id: i64,
+        params: &AppsListInstallationReposForAuthenticatedUserParams,
+    ) -> Result<crate::types::AppsListInstallationReposResponse>;
+
+    /// See [`Apps::add_repo_to_installation`].
+    async fn add_repo_to_installation(
+        &self,
+        installation_id: i64,
+        repository_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Apps::remove_repo_from_installation`].
+    async fn remove_repo_from_installation(
+        &self,
+        installation_id: i64,
+        repository_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Apps::list_subscriptions_for_authenticated_user_with`].
+    async fn list_subscriptions_for_authenticated_user_with(
+        &self,
+        params: &AppsListSubscriptionsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::UserMarketplacePurchase>>;
+
+    /// See [`Apps::list_all_subscriptions_for_authenticated_user`].
+    async fn list_all_subscriptions_for_authenticated_user(
+        &self,
+    ) -> Result<Vec<crate::types::UserMarketplacePurchase>>;
+
+    /// See [`Apps::list_subscriptions_for_authenticated_user_stream`].
+    fn list_subscriptions_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserMarketplacePurchase>;
+
+    /// See [`Apps::list_subscriptions_for_authenticated_user_stubbed_with`].
+    async fn list_subscriptions_for_authenticated_user_stubbed_with(
+        &self,
+        params: &AppsListSubscriptionsForAuthenticatedUserStubbedParams,
+    ) -> Result<Vec<crate::types::UserMarketplacePurchase>>;
+
+    /// See [`Apps::list_all_subscriptions_for_authenticated_user_stubbed`].
+    async fn list_all_subscriptions_for_authenticated_user_stubbed(
+        &self,
+    ) -> Result<Vec<crate::types::UserMarketplacePurchase>>;
+
+    /// See [`Apps::list_subscriptions_for_authenticated_user_stubbed_stream`].
+    fn list_subscriptions_for_authenticated_user_stubbed_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserMarketplacePurchase>;
+
+    /// See [`Apps::get_user_installation`].
+    async fn get_user_installation(&self, username: &str) -> Result<crate::types::Installation>;
+}
+
+#[async_trait::async_trait]
+impl AppsApi for Apps {
+    async fn get_authenticated(&self) -> Result<crate::types::Integration> {
+        Apps::get_authenticated(self).await
+    }
+
+    async fn create_from_manifest(
+        &self,
+        code: &str,
+    ) -> Result<crate::types::AppsCreateFromManifestResponseAllOf> {
+        Apps::create_from_manifest(self, code).await
+    }
+
+    async fn get_webhook_config_for_app(&self) -> Result<crate::types::WebhookConfig> {
+        Apps::get_webhook_config_for_app(self).await
+    }
+
+    async fn update_webhook_config_for_app(
+        &self,
+        body: &crate::types::AppsUpdateWebhookConfigAppRequest,
+    ) -> Result<crate::types::WebhookConfig> {
+        Apps::update_webhook_config_for_app(self, body).await
+    }
+
+    async fn list_webhook_deliveries_with(
+        &self,
+        params: &AppsListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        Apps::list_webhook_deliveries_with(self, params).await
+    }
+
+    async fn list_all_webhook_deliveries_with(
+        &self,
+        params: &AppsListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        Apps::list_all_webhook_deliveries_with(self, params).await
+    }
+
+    fn list_webhook_deliveries_stream(
+        &self,
+        params: &AppsListWebhookDeliveriesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::HookDeliveryItem> {
+        Apps::list_webhook_deliveries_stream(self, params, start, max_items)
+    }
+
+    async fn get_webhook_delivery(&self, delivery_id: i64) -> Result<crate::types::HookDelivery> {
+        Apps::get_webhook_delivery(self, delivery_id).await
+    }
+
+    async fn redeliver_webhook_delivery(
+        &self,
+        delivery_id: i64,
+    ) -> Result<crate::types::AcceptedResponse> {
+        Apps::redeliver_webhook_delivery(self, delivery_id).await
+    }
+
+    async fn list_installations_with(
+        &self,
+        params: &AppsListInstallationsParams,
+    ) -> Result<Vec<crate::types::Installation>> {
+        Apps::list_installations_with(self, params).await
+    }
+
+    async fn list_all_installations_with(
+        &self,
+        params: &AppsListInstallationsParams,
+    ) -> Result<Vec<crate::types::Installation>> {
+        Apps::list_all_installations_with(self, params).await
+    }
+
+    fn list_installations_stream(
+        &self,
+        params: &AppsListInstallationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Installation> {
+        Apps::list_installations_stream(self, params, start, max_items)
+    }
+
+    async fn get_installation(&self, installation_id: i64) -> Result<crate::types::Installation> {
+        Apps::get_installation(self, installation_id).await
+    }
+
+    async fn delete_installation(&self, installation_id: i64) -> Result<()> {
+        Apps::delete_installation(self, installation_id).await
+    }
+
+    async fn create_installation_access_token(
+        &self,
+        installation_id: i64,
+        body: &crate::types::AppsCreateInstallationAccessTokenRequest,
+    ) -> Result<crate::types::InstallationToken> {
+        Apps::create_installation_access_token(self, installation_id, body).await
+    }
+
+    async fn suspend_installation(&self, installation_id: i64) -> Result<()> {
+        Apps::suspend_installation(self, installation_id).await
+    }
+
+    async fn unsuspend_installation(&self, installation_id: i64) -> Result<()> {
+        Apps::unsuspend_installation(self, installation_id).await
+    }
+
+    async fn delete_authorization(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsDeleteAuthorizationRequest,
+    ) -> Result<()> {
+        Apps::delete_authorization(self, client_id, body).await
+    }
+
+    async fn revoke_grant_for_application(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> Result<()> {
+        Apps::revoke_grant_for_application(self, client_id, access_token).await
+    }
+
+    async fn check_token(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsCheckTokenRequest,
+    ) -> Result<crate::types::Authorization> {
+        Apps::check_token(self, client_id, body).await
+    }
+
+    async fn delete_token(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsDeleteTokenRequest,
+    ) -> Result<()> {
+        Apps::delete_token(self, client_id, body).await
+    }
+
+    async fn reset_token(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsResetTokenRequest,
+    ) -> Result<crate::types::Authorization> {
+        Apps::reset_token(self, client_id, body).await
+    }
+
+    async fn scope_token(
+        &self,
+        client_id: &str,
+        body: &crate::types::AppsScopeTokenRequest,
+    ) -> Result<crate::types::Authorization> {
+        Apps::scope_token(self, client_id, body).await
+    }
+
+    async fn check_authorization(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> Result<crate::types::Authorization> {
+        Apps::check_authorization(self, client_id, access_token).await
+    }
+
+    async fn reset_authorization(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> Result<crate::types::Authorization> {
+        Apps::reset_authorization(self, client_id, access_token).await
+    }
+
+    async fn revoke_authorization_for_application(
+        &self,
+        client_id: &str,
+        access_token: &str,
+    ) -> Result<()> {
+        Apps::revoke_authorization_for_application(self, client_id, access_token).await
+    }
+
+    async fn get_by_slug(&self, app_slug: &str) -> Result<crate::types::Integration> {
+        Apps::get_by_slug(self, app_slug).await
+    }
+
+    async fn list_repos_accessible_to_installation_with(
+        &self,
+        params: &AppsListReposAccessibleToInstallationParams,
+    ) -> Result<crate::types::AppsListReposAccessibleInstallationResponse> {
+        Apps::list_repos_accessible_to_installation_with(self, params).await
+    }
+
+    async fn revoke_installation_access_token(&self) -> Result<()> {
+        Apps::revoke_installation_access_token(self).await
+    }
+
+    async fn get_subscription_plan_for_account(
+        &self,
+        account_id: i64,
+    ) -> Result<crate::types::MarketplacePurchaseData> {
+        Apps::get_subscription_plan_for_account(self, account_id).await
+    }
+
+    async fn list_plans_with(
+        &self,
+        params: &AppsListPlansParams,
+    ) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
+        Apps::list_plans_with(self, params).await
+    }
+
+    async fn list_all_plans(&self) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
+        Apps::list_all_plans(self).await
+    }
+
+    fn list_plans_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MarketplaceListingPlan> {
+        Apps::list_plans_stream(self, start, max_items)
+    }
+
+    async fn list_accounts_for_plan_with(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanParams,
+    ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
+        Apps::list_accounts_for_plan_with(self, plan_id, params).await
+    }
+
+    async fn list_all_accounts_for_plan_with(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanParams,
+    ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
+        Apps::list_all_accounts_for_plan_with(self, plan_id, params).await
+    }
+
+    fn list_accounts_for_plan_stream(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MarketplacePurchaseData> {
+        Apps::list_accounts_for_plan_stream(self, plan_id, params, start, max_items)
+    }
+
+    async fn get_subscription_plan_for_account_stubbed(
+        &self,
+        account_id: i64,
+    ) -> Result<crate::types::MarketplacePurchaseData> {
+        Apps::get_subscription_plan_for_account_stubbed(self, account_id).await
+    }
+
+    async fn list_plans_stubbed_with(
+        &self,
+        params: &AppsListPlansStubbedParams,
+    ) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
+        Apps::list_plans_stubbed_with(self, params).await
+    }
+
+    async fn list_all_plans_stubbed(&self) -> Result<Vec<crate::types::MarketplaceListingPlan>> {
+        Apps::list_all_plans_stubbed(self).await
+    }
+
+    fn list_plans_stubbed_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MarketplaceListingPlan> {
+        Apps::list_plans_stubbed_stream(self, start, max_items)
+    }
+
+    async fn list_accounts_for_plan_stubbed_with(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanStubbedParams,
+    ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
+        Apps::list_accounts_for_plan_stubbed_with(self, plan_id, params).await
+    }
+
+    async fn list_all_accounts_for_plan_stubbed_with(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanStubbedParams,
+    ) -> Result<Vec<crate::types::MarketplacePurchaseData>> {
+        Apps::list_all_accounts_for_plan_stubbed_with(self, plan_id, params).await
+    }
+
+    fn list_accounts_for_plan_stubbed_stream(
+        &self,
+        plan_id: i64,
+        params: &AppsListAccountsForPlanStubbedParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MarketplacePurchaseData> {
+        Apps::list_accounts_for_plan_stubbed_stream(self, plan_id, params, start, max_items)
+    }
+
+    async fn get_org_installation(&self, org: &str) -> Result<crate::types::Installation> {
+        Apps::get_org_installation(self, org).await
+    }
+
+    async fn create_content_attachment(
+        &self,
+        owner: &str,
+        repo: &str,
+        content_reference_id: i64,
+        body: &crate::types::AppsCreateContentAttachmentRequest,
+    ) -> Result<crate::types::ContentReferenceAttachment> {
+        Apps::create_content_attachment(self, owner, repo, content_reference_id, body).await
+    }
+
+    async fn get_repo_installation(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::Installation> {
+        Apps::get_repo_installation(self, owner, repo).await
+    }
+
+    async fn list_installations_for_authenticated_user_with(
+        &self,
+        params: &AppsListInstallationsForAuthenticatedUserParams,
+    ) -> Result<crate::types::AppsListInstallationsResponse> {
+        Apps::list_installations_for_authenticated_user_with(self, params).await
+    }
+
+    async fn list_installation_repos_for_authenticated_user_with(
+        &self,
+        installation_id: i64,
+        params: &AppsListInstallationReposForAuthenticatedUserParams,
+    ) -> Result<crate::types::AppsListInstallationReposResponse> {
+        Apps::list_installation_repos_for_authenticated_user_with(self, installation_id, params)
+            .await
+    }
+
+    async fn add_repo_to_installation(
+        &self,
+        installation_id: i64,
+        repository_id: i64,
+    ) -> Result<()> {
+        Apps::add_repo_to_installation(self, installation_id, repository_id).await
+    }
+
+    async fn remove_repo_from_installation(
+        &self,
+        installation_id: i64,
+        repository_id: i64,
+    ) -> Result<()> {
+        Apps::remove_repo_from_installation(self, installation_id, repository_id).await
+    }
+
+    async fn list_subscriptions_for_authenticated_user_with(
+        &self,
+        params: &AppsListSubscriptionsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
+        Apps::list_subscriptions_for_authenticated_user_with(self, params).await
+    }
+
+    async fn list_all_subscriptions_for_authenticated_user(
+        &self,
+    ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
+        Apps::list_all_subscriptions_for_authenticated_user(self).await
+    }
+
+    fn list_subscriptions_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserMarketplacePurchase> {
+        Apps::list_subscriptions_for_authenticated_user_stream(self, start, max_items)
+    }
+
+    async fn list_subscriptions_for_authenticated_user_stubbed_with(
+        &self,
+        params: &AppsListSubscriptionsForAuthenticatedUserStubbedParams,
+    ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
+        Apps::list_subscriptions_for_authenticated_user_stubbed_with(self, params).await
+    }
+
+    async fn list_all_subscriptions_for_authenticated_user_stubbed(
+        &self,
+    ) -> Result<Vec<crate::types::UserMarketplacePurchase>> {
+        Apps::list_all_subscriptions_for_authenticated_user_stubbed(self).await
+    }
+
+    fn list_subscriptions_for_authenticated_user_stubbed_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserMarketplacePurchase> {
+        Apps::list_subscriptions_for_authenticated_user_stubbed_stream(self, start, max_items)
+    }
+
+    async fn get_user_installation(&self, username: &str) -> Result<crate::types::Installation> {
+        Apps::get_user_installation(self, username).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Billing {
     pub client: Client,
@@ -273,3 +271,129 @@ impl Billing {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`Billing`], for code that takes them as a trait, so that it can be
+/// tested with the `MockBillingApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait BillingApi: Send + Sync {
+    /// See [`Billing::get_github_actions_billing_ghe`].
+    async fn get_github_actions_billing_ghe(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::ActionsBillingUsage>;
+
+    /// See [`Billing::get_github_packages_billing_ghe`].
+    async fn get_github_packages_billing_ghe(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::PackagesBillingUsage>;
+
+    /// See [`Billing::get_shared_storage_billing_ghe`].
+    async fn get_shared_storage_billing_ghe(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::CombinedBillingUsage>;
+
+    /// See [`Billing::get_github_actions_billing_org`].
+    async fn get_github_actions_billing_org(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::ActionsBillingUsage>;
+
+    /// See [`Billing::get_github_packages_billing_org`].
+    async fn get_github_packages_billing_org(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::PackagesBillingUsage>;
+
+    /// See [`Billing::get_shared_storage_billing_org`].
+    async fn get_shared_storage_billing_org(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::CombinedBillingUsage>;
+
+    /// See [`Billing::get_github_actions_billing_user`].
+    async fn get_github_actions_billing_user(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::ActionsBillingUsage>;
+
+    /// See [`Billing::get_github_packages_billing_user`].
+    async fn get_github_packages_billing_user(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::PackagesBillingUsage>;
+
+    /// See [`Billing::get_shared_storage_billing_user`].
+    async fn get_shared_storage_billing_user(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::CombinedBillingUsage>;
+}
+
+#[async_trait::async_trait]
+impl BillingApi for Billing {
+    async fn get_github_actions_billing_ghe(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::ActionsBillingUsage> {
+        Billing::get_github_actions_billing_ghe(self, enterprise).await
+    }
+
+    async fn get_github_packages_billing_ghe(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::PackagesBillingUsage> {
+        Billing::get_github_packages_billing_ghe(self, enterprise).await
+    }
+
+    async fn get_shared_storage_billing_ghe(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::CombinedBillingUsage> {
+        Billing::get_shared_storage_billing_ghe(self, enterprise).await
+    }
+
+    async fn get_github_actions_billing_org(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::ActionsBillingUsage> {
+        Billing::get_github_actions_billing_org(self, org).await
+    }
+
+    async fn get_github_packages_billing_org(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::PackagesBillingUsage> {
+        Billing::get_github_packages_billing_org(self, org).await
+    }
+
+    async fn get_shared_storage_billing_org(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::CombinedBillingUsage> {
+        Billing::get_shared_storage_billing_org(self, org).await
+    }
+
+    async fn get_github_actions_billing_user(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::ActionsBillingUsage> {
+        Billing::get_github_actions_billing_user(self, username).await
+    }
+
+    async fn get_github_packages_billing_user(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::PackagesBillingUsage> {
+        Billing::get_github_packages_billing_user(self, username).await
+    }
+
+    async fn get_shared_storage_billing_user(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::CombinedBillingUsage> {
+        Billing::get_shared_storage_billing_user(self, username).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Checks {
     pub client: Client,
@@ -130,22 +128,20 @@ impl Checks {
      * * `owner: &str`
      * * `repo: &str`
      * * `check_run_id: i64` -- check_run_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ChecksListAnnotationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_annotations(
+    pub async fn list_annotations_with(
         &self,
         owner: &str,
         repo: &str,
         check_run_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &ChecksListAnnotationsParams,
     ) -> Result<Vec<crate::types::CheckAnnotation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -160,6 +156,26 @@ impl Checks {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_annotations_with` and pass the query parameters as a `ChecksListAnnotationsParams` instead.
+     */
+    #[deprecated(note = "use `list_annotations_with` instead")]
+    pub async fn list_annotations(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::CheckAnnotation>> {
+        let params = ChecksListAnnotationsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_annotations_with(owner, repo, check_run_id, &params)
+            .await
+    }
+
     /**
      * List check run annotations.
      *
@@ -187,6 +203,50 @@ impl Checks {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List check run annotations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/check-runs/{check_run_id}/annotations` endpoint.
+     *
+     * As opposed to `list_all_annotations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists annotations for a check run using the annotation `id`. GitHub Apps must have the `checks:read` permission on a private repository or pull access to a public repository to get annotations for a check run. OAuth Apps and authenticated users must have the `repo` scope to get annotations for a check run in a private repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/checks#list-check-run-annotations>
+     */
+    pub fn list_annotations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CheckAnnotation> {
+        let url = format!(
+            "/repos/{}/{}/check-runs/{}/annotations",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&check_run_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a check suite.
      *
@@ -208,7 +268,7 @@ impl Checks {
         owner: &str,
         repo: &str,
         body: &crate::types::ChecksCreateSuiteRequest,
-    ) -> Result<crate::types::CheckSuiteData> {
+    ) -> Result<crate::types::CheckSuite> {
         let url = format!(
             "/repos/{}/{}/check-suites",
             crate::progenitor_support::encode_path(owner),
@@ -238,7 +298,7 @@ impl Checks {
         &self,
         owner: &str,
         repo: &str,
-        body: &crate::types::Preferences,
+        body: &crate::types::ChecksSetSuitesPreferencesRequest,
     ) -> Result<crate::types::CheckSuitePreference> {
         let url = format!(
             "/repos/{}/{}/check-suites/preferences",
@@ -273,7 +333,7 @@ impl Checks {
         owner: &str,
         repo: &str,
         check_suite_id: i64,
-    ) -> Result<crate::types::CheckSuiteData> {
+    ) -> Result<crate::types::CheckSuite> {
         let url = format!(
             "/repos/{}/{}/check-suites/{}",
             crate::progenitor_support::encode_path(owner),
@@ -300,39 +360,29 @@ impl Checks {
      * * `owner: &str`
      * * `repo: &str`
      * * `check_suite_id: i64` -- check_suite_id parameter.
-     * * `check_name: &str` -- Returns check runs with the specified `name`.
-     * * `status: crate::types::JobStatus` -- Returns check runs with the specified `status`. Can be one of `queued`, `in_progress`, or `completed`.
-     * * `filter: crate::types::ActionsListJobsWorkflowRunFilter` -- Filters jobs by their `completed_at` timestamp. Can be one of:  
-     *  \\* `latest`: Returns jobs from the most recent execution of the workflow run.  
-     *  \\* `all`: Returns all jobs for a workflow run, including from old executions of the workflow run.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ChecksListForSuiteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_suite(
+    pub async fn list_for_suite_with(
         &self,
         owner: &str,
         repo: &str,
         check_suite_id: i64,
-        check_name: &str,
-        status: crate::types::JobStatus,
-        filter: crate::types::ActionsListJobsWorkflowRunFilter,
-        per_page: i64,
-        page: i64,
-    ) -> Result<crate::types::ChecksListRefResponse> {
+        params: &ChecksListForSuiteParams,
+    ) -> Result<crate::types::ChecksListSuiteResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !check_name.is_empty() {
+        if let Some(check_name) = &params.check_name {
             query_args.push(("check_name".to_string(), check_name.to_string()));
         }
-        if !filter.to_string().is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -347,6 +397,32 @@ impl Checks {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_suite_with` and pass the query parameters as a `ChecksListForSuiteParams` instead.
+     */
+    #[deprecated(note = "use `list_for_suite_with` instead")]
+    pub async fn list_for_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+        check_name: &str,
+        status: crate::types::StatusData,
+        filter: crate::types::ChecksListSuiteFilter,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ChecksListSuiteResponse> {
+        let params = ChecksListForSuiteParams {
+            check_name: Some(check_name.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_for_suite_with(owner, repo, check_suite_id, &params)
+            .await
+    }
+
     /**
      * Rerequest a check suite.
      *
@@ -369,7 +445,7 @@ impl Checks {
         owner: &str,
         repo: &str,
         check_suite_id: i64,
-    ) -> Result<()> {
+    ) -> Result<crate::types::ChecksRerequestSuiteResponse> {
         let url = format!(
             "/repos/{}/{}/check-suites/{}/rerequest",
             crate::progenitor_support::encode_path(owner),
@@ -396,44 +472,32 @@ impl Checks {
      * * `owner: &str`
      * * `repo: &str`
      * * `ref_: &str` -- ref parameter.
-     * * `check_name: &str` -- Returns check runs with the specified `name`.
-     * * `status: crate::types::JobStatus` -- Returns check runs with the specified `status`. Can be one of `queued`, `in_progress`, or `completed`.
-     * * `filter: crate::types::ActionsListJobsWorkflowRunFilter` -- Filters jobs by their `completed_at` timestamp. Can be one of:  
-     *  \\* `latest`: Returns jobs from the most recent execution of the workflow run.  
-     *  \\* `all`: Returns all jobs for a workflow run, including from old executions of the workflow run.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `app_id: i64`
+     * * `params: &ChecksListForRefParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_ref(
+    pub async fn list_for_ref_with(
         &self,
         owner: &str,
         repo: &str,
         ref_: &str,
-        check_name: &str,
-        status: crate::types::JobStatus,
-        filter: crate::types::ActionsListJobsWorkflowRunFilter,
-        per_page: i64,
-        page: i64,
-        app_id: i64,
+        params: &ChecksListForRefParams,
     ) -> Result<crate::types::ChecksListRefResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if app_id > 0 {
+        if let Some(app_id) = &params.app_id {
             query_args.push(("app_id".to_string(), app_id.to_string()));
         }
-        if !check_name.is_empty() {
+        if let Some(check_name) = &params.check_name {
             query_args.push(("check_name".to_string(), check_name.to_string()));
         }
-        if !filter.to_string().is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -448,6 +512,33 @@ impl Checks {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_ref_with` and pass the query parameters as a `ChecksListForRefParams` instead.
+     */
+    #[deprecated(note = "use `list_for_ref_with` instead")]
+    pub async fn list_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        check_name: &str,
+        status: crate::types::StatusData,
+        filter: crate::types::ChecksListRefFilter,
+        per_page: i64,
+        page: i64,
+        app_id: i64,
+    ) -> Result<crate::types::ChecksListRefResponse> {
+        let params = ChecksListForRefParams {
+            app_id: Some(app_id).filter(|v| *v > 0),
+            check_name: Some(check_name.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_for_ref_with(owner, repo, ref_, &params).await
+    }
+
     /**
      * List check suites for a Git reference.
      *
@@ -464,32 +555,26 @@ impl Checks {
      * * `owner: &str`
      * * `repo: &str`
      * * `ref_: &str` -- ref parameter.
-     * * `app_id: i64` -- Filters check suites by GitHub App `id`.
-     * * `check_name: &str` -- Returns check runs with the specified `name`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ChecksListSuitesForRefParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_suites_for_ref(
+    pub async fn list_suites_for_ref_with(
         &self,
         owner: &str,
         repo: &str,
         ref_: &str,
-        app_id: i64,
-        check_name: &str,
-        per_page: i64,
-        page: i64,
+        params: &ChecksListSuitesForRefParams,
     ) -> Result<crate::types::ChecksListSuitesRefResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if app_id > 0 {
+        if let Some(app_id) = &params.app_id {
             query_args.push(("app_id".to_string(), app_id.to_string()));
         }
-        if !check_name.is_empty() {
+        if let Some(check_name) = &params.check_name {
             query_args.push(("check_name".to_string(), check_name.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -503,4 +588,418 @@ impl Checks {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `list_suites_for_ref_with` and pass the query parameters as a `ChecksListSuitesForRefParams` instead.
+     */
+    #[deprecated(note = "use `list_suites_for_ref_with` instead")]
+    pub async fn list_suites_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        app_id: i64,
+        check_name: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::ChecksListSuitesRefResponse> {
+        let params = ChecksListSuitesForRefParams {
+            app_id: Some(app_id).filter(|v| *v > 0),
+            check_name: Some(check_name.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_suites_for_ref_with(owner, repo, ref_, &params)
+            .await
+    }
+}
+
+/// The query parameters of `Checks::list_annotations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChecksListAnnotationsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ChecksListAnnotationsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Checks::list_for_suite_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChecksListForSuiteParams {
+    /// Returns check runs with the specified `name`.
+    pub check_name: Option<String>,
+    /// Filters check runs by their `completed_at` timestamp. Can be one of `latest` (returning the most recent check runs) or `all`.
+    pub filter: Option<crate::types::ChecksListSuiteFilter>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Returns check runs with the specified `status`. Can be one of `queued`, `in_progress`, or `completed`.
+    pub status: Option<crate::types::StatusData>,
+}
+
+impl ChecksListForSuiteParams {
+    pub fn check_name<T: ToString>(mut self, check_name: T) -> Self {
+        self.check_name = Some(check_name.to_string());
+        self
+    }
+
+    pub fn filter(mut self, filter: crate::types::ChecksListSuiteFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::StatusData) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// The query parameters of `Checks::list_for_ref_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChecksListForRefParams {
+    pub app_id: Option<i64>,
+    /// Returns check runs with the specified `name`.
+    pub check_name: Option<String>,
+    /// Filters check runs by their `completed_at` timestamp. Can be one of `latest` (returning the most recent check runs) or `all`.
+    pub filter: Option<crate::types::ChecksListRefFilter>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Returns check runs with the specified `status`. Can be one of `queued`, `in_progress`, or `completed`.
+    pub status: Option<crate::types::StatusData>,
+}
+
+impl ChecksListForRefParams {
+    pub fn app_id(mut self, app_id: i64) -> Self {
+        self.app_id = Some(app_id);
+        self
+    }
+
+    pub fn check_name<T: ToString>(mut self, check_name: T) -> Self {
+        self.check_name = Some(check_name.to_string());
+        self
+    }
+
+    pub fn filter(mut self, filter: crate::types::ChecksListRefFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::StatusData) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// The query parameters of `Checks::list_suites_for_ref_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChecksListSuitesForRefParams {
+    /// Filters check suites by GitHub App `id`.
+    pub app_id: Option<i64>,
+    /// Returns check runs with the specified `name`.
+    pub check_name: Option<String>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ChecksListSuitesForRefParams {
+    pub fn app_id(mut self, app_id: i64) -> Self {
+        self.app_id = Some(app_id);
+        self
+    }
+
+    pub fn check_name<T: ToString>(mut self, check_name: T) -> Self {
+        self.check_name = Some(check_name.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The functions of [`Checks`], for code that takes them as a trait, so that it can be
+/// tested with the `MockChecksApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ChecksApi: Send + Sync {
+    /// See [`Checks::create`].
+    async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ChecksCreateRequest,
+    ) -> Result<crate::types::CheckRun>;
+
+    /// See [`Checks::get`].
+    async fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+    ) -> Result<crate::types::CheckRun>;
+
+    /// See [`Checks::update`].
+    async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        body: &crate::types::ChecksUpdateRequest,
+    ) -> Result<crate::types::CheckRun>;
+
+    /// See [`Checks::list_annotations_with`].
+    async fn list_annotations_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        params: &ChecksListAnnotationsParams,
+    ) -> Result<Vec<crate::types::CheckAnnotation>>;
+
+    /// See [`Checks::list_all_annotations`].
+    async fn list_all_annotations(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+    ) -> Result<Vec<crate::types::CheckAnnotation>>;
+
+    /// See [`Checks::list_annotations_stream`].
+    fn list_annotations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CheckAnnotation>;
+
+    /// See [`Checks::create_suite`].
+    async fn create_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ChecksCreateSuiteRequest,
+    ) -> Result<crate::types::CheckSuite>;
+
+    /// See [`Checks::set_suites_preferences`].
+    async fn set_suites_preferences(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ChecksSetSuitesPreferencesRequest,
+    ) -> Result<crate::types::CheckSuitePreference>;
+
+    /// See [`Checks::get_suite`].
+    async fn get_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+    ) -> Result<crate::types::CheckSuite>;
+
+    /// See [`Checks::list_for_suite_with`].
+    async fn list_for_suite_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+        params: &ChecksListForSuiteParams,
+    ) -> Result<crate::types::ChecksListSuiteResponse>;
+
+    /// See [`Checks::rerequest_suite`].
+    async fn rerequest_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+    ) -> Result<crate::types::ChecksRerequestSuiteResponse>;
+
+    /// See [`Checks::list_for_ref_with`].
+    async fn list_for_ref_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        params: &ChecksListForRefParams,
+    ) -> Result<crate::types::ChecksListRefResponse>;
+
+    /// See [`Checks::list_suites_for_ref_with`].
+    async fn list_suites_for_ref_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        params: &ChecksListSuitesForRefParams,
+    ) -> Result<crate::types::ChecksListSuitesRefResponse>;
+}
+
+#[async_trait::async_trait]
+impl ChecksApi for Checks {
+    async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ChecksCreateRequest,
+    ) -> Result<crate::types::CheckRun> {
+        Checks::create(self, owner, repo, body).await
+    }
+
+    async fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+    ) -> Result<crate::types::CheckRun> {
+        Checks::get(self, owner, repo, check_run_id).await
+    }
+
+    async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        body: &crate::types::ChecksUpdateRequest,
+    ) -> Result<crate::types::CheckRun> {
+        Checks::update(self, owner, repo, check_run_id, body).await
+    }
+
+    async fn list_annotations_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        params: &ChecksListAnnotationsParams,
+    ) -> Result<Vec<crate::types::CheckAnnotation>> {
+        Checks::list_annotations_with(self, owner, repo, check_run_id, params).await
+    }
+
+    async fn list_all_annotations(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+    ) -> Result<Vec<crate::types::CheckAnnotation>> {
+        Checks::list_all_annotations(self, owner, repo, check_run_id).await
+    }
+
+    fn list_annotations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_run_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CheckAnnotation> {
+        Checks::list_annotations_stream(self, owner, repo, check_run_id, start, max_items)
+    }
+
+    async fn create_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ChecksCreateSuiteRequest,
+    ) -> Result<crate::types::CheckSuite> {
+        Checks::create_suite(self, owner, repo, body).await
+    }
+
+    async fn set_suites_preferences(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ChecksSetSuitesPreferencesRequest,
+    ) -> Result<crate::types::CheckSuitePreference> {
+        Checks::set_suites_preferences(self, owner, repo, body).await
+    }
+
+    async fn get_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+    ) -> Result<crate::types::CheckSuite> {
+        Checks::get_suite(self, owner, repo, check_suite_id).await
+    }
+
+    async fn list_for_suite_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+        params: &ChecksListForSuiteParams,
+    ) -> Result<crate::types::ChecksListSuiteResponse> {
+        Checks::list_for_suite_with(self, owner, repo, check_suite_id, params).await
+    }
+
+    async fn rerequest_suite(
+        &self,
+        owner: &str,
+        repo: &str,
+        check_suite_id: i64,
+    ) -> Result<crate::types::ChecksRerequestSuiteResponse> {
+        Checks::rerequest_suite(self, owner, repo, check_suite_id).await
+    }
+
+    async fn list_for_ref_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        params: &ChecksListForRefParams,
+    ) -> Result<crate::types::ChecksListRefResponse> {
+        Checks::list_for_ref_with(self, owner, repo, ref_, params).await
+    }
+
+    async fn list_suites_for_ref_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        params: &ChecksListSuitesForRefParams,
+    ) -> Result<crate::types::ChecksListSuitesRefResponse> {
+        Checks::list_suites_for_ref_with(self, owner, repo, ref_, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CodeScanning {
     pub client: Client,
@@ -33,41 +31,31 @@ impl CodeScanning {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `tool_name: &str` -- The name of a code scanning tool. Only results by this tool will be listed. You can specify the tool by using either `tool_name` or `tool_guid`, but not both.
-     * * `tool_guid: &str` -- The GUID of a code scanning tool. Only results by this tool will be listed. Note that some code scanning tools may not include a GUID in their analysis data. You can specify the tool by using either `tool_guid` or `tool_name`, but not both.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `ref_: &str` -- The Git reference for the results you want to list. The `ref` for a branch can be formatted either as `refs/heads/<branch name>` or simply `<branch name>`. To reference a pull request use `refs/pull/<number>/merge`.
-     * * `state: crate::types::CodeScanningAlertState` -- Set to `open`, `fixed`, or `dismissed` to list code scanning alerts in a specific state.
+     * * `params: &CodeScanningListAlertsForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_alerts_for_repo(
+    pub async fn list_alerts_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        tool_name: &str,
-        tool_guid: &str,
-        page: i64,
-        per_page: i64,
-        ref_: &str,
-        state: crate::types::CodeScanningAlertState,
+        params: &CodeScanningListAlertsForRepoParams,
     ) -> Result<Vec<crate::types::CodeScanningAlertItems>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
-        if !tool_guid.is_empty() {
+        if let Some(tool_guid) = &params.tool_guid {
             query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
         }
-        if !tool_name.is_empty() {
+        if let Some(tool_name) = &params.tool_name {
             query_args.push(("tool_name".to_string(), tool_name.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -81,6 +69,32 @@ impl CodeScanning {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_alerts_for_repo_with` and pass the query parameters as a `CodeScanningListAlertsForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_alerts_for_repo_with` instead")]
+    pub async fn list_alerts_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        page: i64,
+        per_page: i64,
+        ref_: &str,
+        state: crate::types::CodeScanningAlertState,
+    ) -> Result<Vec<crate::types::CodeScanningAlertItems>> {
+        let params = CodeScanningListAlertsForRepoParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            tool_guid: Some(tool_guid.to_string()).filter(|s| !s.is_empty()),
+            tool_name: Some(tool_name.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_alerts_for_repo_with(owner, repo, &params).await
+    }
+
     /**
      * List code scanning alerts for a repository.
      *
@@ -99,7 +113,45 @@ impl CodeScanning {
      * (if you used `ref` in the request).
      *
      * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-alerts-for-a-repository>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CodeScanningListAlertsForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn list_all_alerts_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListAlertsForRepoParams,
+    ) -> Result<Vec<crate::types::CodeScanningAlertItems>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(ref_) = &params.ref_ {
+            query_args.push(("ref".to_string(), ref_.to_string()));
+        }
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        if let Some(tool_guid) = &params.tool_guid {
+            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+        }
+        if let Some(tool_name) = &params.tool_name {
+            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/code-scanning/alerts?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_alerts_for_repo_with` and pass the query parameters as a `CodeScanningListAlertsForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_all_alerts_for_repo_with` instead")]
     pub async fn list_all_alerts_for_repo(
         &self,
         owner: &str,
@@ -109,17 +161,55 @@ impl CodeScanning {
         ref_: &str,
         state: crate::types::CodeScanningAlertState,
     ) -> Result<Vec<crate::types::CodeScanningAlertItems>> {
+        let params = CodeScanningListAlertsForRepoParams {
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            tool_guid: Some(tool_guid.to_string()).filter(|s| !s.is_empty()),
+            tool_name: Some(tool_name.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_alerts_for_repo_with(owner, repo, &params)
+            .await
+    }
+
+    /**
+     * List code scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_all_alerts_for_repo`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all open code scanning alerts for the default branch (usually `main`
+     * or `master`). You must use an access token with the `security_events` scope to use
+     * this endpoint. GitHub Apps must have the `security_events` read permission to use
+     * this endpoint.
+     *
+     * The response includes a `most_recent_instance` object.
+     * This provides details of the most recent instance of this alert
+     * for the default branch or for the specified Git reference
+     * (if you used `ref` in the request).
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-alerts-for-a-repository>
+     */
+    pub fn list_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListAlertsForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CodeScanningAlertItems> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
-        if !tool_guid.is_empty() {
+        if let Some(tool_guid) = &params.tool_guid {
             query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
         }
-        if !tool_name.is_empty() {
+        if let Some(tool_name) = &params.tool_name {
             query_args.push(("tool_name".to_string(), tool_name.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -130,7 +220,22 @@ impl CodeScanning {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -215,27 +320,23 @@ impl CodeScanning {
      * * `owner: &str`
      * * `repo: &str`
      * * `alert_number: i64` -- The number that identifies an alert. You can find this at the end of the URL for a code scanning alert within GitHub, and in the `number` field in the response from the `GET /repos/{owner}/{repo}/code-scanning/alerts` operation.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `ref_: &str` -- The Git reference for the results you want to list. The `ref` for a branch can be formatted either as `refs/heads/<branch name>` or simply `<branch name>`. To reference a pull request use `refs/pull/<number>/merge`.
+     * * `params: &CodeScanningListAlertInstancesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_alert_instances(
+    pub async fn list_alert_instances_with(
         &self,
         owner: &str,
         repo: &str,
         alert_number: i64,
-        page: i64,
-        per_page: i64,
-        ref_: &str,
+        params: &CodeScanningListAlertInstancesParams,
     ) -> Result<Vec<crate::types::CodeScanningAlertInstance>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -250,6 +351,28 @@ impl CodeScanning {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_alert_instances_with` and pass the query parameters as a `CodeScanningListAlertInstancesParams` instead.
+     */
+    #[deprecated(note = "use `list_alert_instances_with` instead")]
+    pub async fn list_alert_instances(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        page: i64,
+        per_page: i64,
+        ref_: &str,
+    ) -> Result<Vec<crate::types::CodeScanningAlertInstance>> {
+        let params = CodeScanningListAlertInstancesParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_alert_instances_with(owner, repo, alert_number, &params)
+            .await
+    }
+
     /**
      * List instances of a code scanning alert.
      *
@@ -260,7 +383,38 @@ impl CodeScanning {
      * Lists all instances of the specified code scanning alert. You must use an access token with the `security_events` scope to use this endpoint. GitHub Apps must have the `security_events` read permission to use this endpoint.
      *
      * FROM: <https://docs.github.com/rest/reference/code-scanning#list-instances-of-a-code-scanning-alert>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CodeScanningListAlertInstancesParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn list_all_alert_instances_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        params: &CodeScanningListAlertInstancesParams,
+    ) -> Result<Vec<crate::types::CodeScanningAlertInstance>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(ref_) = &params.ref_ {
+            query_args.push(("ref".to_string(), ref_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/code-scanning/alerts/{}/instances?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&alert_number.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_alert_instances_with` and pass the query parameters as a `CodeScanningListAlertInstancesParams` instead.
+     */
+    #[deprecated(note = "use `list_all_alert_instances_with` instead")]
     pub async fn list_all_alert_instances(
         &self,
         owner: &str,
@@ -268,8 +422,36 @@ impl CodeScanning {
         alert_number: i64,
         ref_: &str,
     ) -> Result<Vec<crate::types::CodeScanningAlertInstance>> {
+        let params = CodeScanningListAlertInstancesParams {
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_alert_instances_with(owner, repo, alert_number, &params)
+            .await
+    }
+
+    /**
+     * List instances of a code scanning alert.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/alerts/{alert_number}/instances` endpoint.
+     *
+     * As opposed to `list_all_alert_instances`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all instances of the specified code scanning alert. You must use an access token with the `security_events` scope to use this endpoint. GitHub Apps must have the `security_events` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-instances-of-a-code-scanning-alert>
+     */
+    pub fn list_alert_instances_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        params: &CodeScanningListAlertInstancesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CodeScanningAlertInstance> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -281,7 +463,22 @@ impl CodeScanning {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -312,41 +509,31 @@ impl CodeScanning {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `tool_name: &str` -- The name of a code scanning tool. Only results by this tool will be listed. You can specify the tool by using either `tool_name` or `tool_guid`, but not both.
-     * * `tool_guid: &str` -- The GUID of a code scanning tool. Only results by this tool will be listed. Note that some code scanning tools may not include a GUID in their analysis data. You can specify the tool by using either `tool_guid` or `tool_name`, but not both.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `ref_: &str` -- The Git reference for the analyses you want to list. The `ref` for a branch can be formatted either as `refs/heads/<branch name>` or simply `<branch name>`. To reference a pull request use `refs/pull/<number>/merge`.
-     * * `sarif_id: &str` -- Filter analyses belonging to the same SARIF upload.
+     * * `params: &CodeScanningListRecentAnalysesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_recent_analyses(
+    pub async fn list_recent_analyses_with(
         &self,
         owner: &str,
         repo: &str,
-        tool_name: &str,
-        tool_guid: &str,
-        page: i64,
-        per_page: i64,
-        ref_: &str,
-        sarif_id: &str,
+        params: &CodeScanningListRecentAnalysesParams,
     ) -> Result<Vec<crate::types::CodeScanningAnalysis>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
-        if !sarif_id.is_empty() {
+        if let Some(sarif_id) = &params.sarif_id {
             query_args.push(("sarif_id".to_string(), sarif_id.to_string()));
         }
-        if !tool_guid.is_empty() {
+        if let Some(tool_guid) = &params.tool_guid {
             query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
         }
-        if !tool_name.is_empty() {
+        if let Some(tool_name) = &params.tool_name {
             query_args.push(("tool_name".to_string(), tool_name.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -360,6 +547,32 @@ impl CodeScanning {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_recent_analyses_with` and pass the query parameters as a `CodeScanningListRecentAnalysesParams` instead.
+     */
+    #[deprecated(note = "use `list_recent_analyses_with` instead")]
+    pub async fn list_recent_analyses(
+        &self,
+        owner: &str,
+        repo: &str,
+        tool_name: &str,
+        tool_guid: &str,
+        page: i64,
+        per_page: i64,
+        ref_: &str,
+        sarif_id: &str,
+    ) -> Result<Vec<crate::types::CodeScanningAnalysis>> {
+        let params = CodeScanningListRecentAnalysesParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+            sarif_id: Some(sarif_id.to_string()).filter(|s| !s.is_empty()),
+            tool_guid: Some(tool_guid.to_string()).filter(|s| !s.is_empty()),
+            tool_name: Some(tool_name.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_recent_analyses_with(owner, repo, &params).await
+    }
+
     /**
      * List code scanning analyses for a repository.
      *
@@ -385,7 +598,45 @@ impl CodeScanning {
      * The `tool_name` field is deprecated and will, in future, not be included in the response for this endpoint. The example response reflects this change. The tool name can now be found inside the `tool` field.
      *
      * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-analyses-for-a-repository>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CodeScanningListRecentAnalysesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_recent_analyses_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListRecentAnalysesParams,
+    ) -> Result<Vec<crate::types::CodeScanningAnalysis>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(ref_) = &params.ref_ {
+            query_args.push(("ref".to_string(), ref_.to_string()));
+        }
+        if let Some(sarif_id) = &params.sarif_id {
+            query_args.push(("sarif_id".to_string(), sarif_id.to_string()));
+        }
+        if let Some(tool_guid) = &params.tool_guid {
+            query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
+        }
+        if let Some(tool_name) = &params.tool_name {
+            query_args.push(("tool_name".to_string(), tool_name.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/code-scanning/analyses?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_recent_analyses_with` and pass the query parameters as a `CodeScanningListRecentAnalysesParams` instead.
      */
+    #[deprecated(note = "use `list_all_recent_analyses_with` instead")]
     pub async fn list_all_recent_analyses(
         &self,
         owner: &str,
@@ -395,17 +646,62 @@ impl CodeScanning {
         ref_: &str,
         sarif_id: &str,
     ) -> Result<Vec<crate::types::CodeScanningAnalysis>> {
+        let params = CodeScanningListRecentAnalysesParams {
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+            sarif_id: Some(sarif_id.to_string()).filter(|s| !s.is_empty()),
+            tool_guid: Some(tool_guid.to_string()).filter(|s| !s.is_empty()),
+            tool_name: Some(tool_name.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_recent_analyses_with(owner, repo, &params)
+            .await
+    }
+
+    /**
+     * List code scanning analyses for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/code-scanning/analyses` endpoint.
+     *
+     * As opposed to `list_all_recent_analyses`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the details of all code scanning analyses for a repository,
+     * starting with the most recent.
+     * The response is paginated and you can use the `page` and `per_page` parameters
+     * to list the analyses you're interested in.
+     * By default 30 analyses are listed per page.
+     *
+     * The `rules_count` field in the response give the number of rules
+     * that were run in the analysis.
+     * For very old analyses this data is not available,
+     * and `0` is returned in this field.
+     *
+     * You must use an access token with the `security_events` scope to use this endpoint.
+     * GitHub Apps must have the `security_events` read permission to use this endpoint.
+     *
+     * **Deprecation notice**:
+     * The `tool_name` field is deprecated and will, in future, not be included in the response for this endpoint. The example response reflects this change. The tool name can now be found inside the `tool` field.
+     *
+     * FROM: <https://docs.github.com/rest/reference/code-scanning#list-code-scanning-analyses-for-a-repository>
+     */
+    pub fn list_recent_analyses_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListRecentAnalysesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CodeScanningAnalysis> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
-        if !sarif_id.is_empty() {
+        if let Some(sarif_id) = &params.sarif_id {
             query_args.push(("sarif_id".to_string(), sarif_id.to_string()));
         }
-        if !tool_guid.is_empty() {
+        if let Some(tool_guid) = &params.tool_guid {
             query_args.push(("tool_guid".to_string(), tool_guid.to_string()));
         }
-        if !tool_name.is_empty() {
+        if let Some(tool_name) = &params.tool_name {
             query_args.push(("tool_name".to_string(), tool_name.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -416,7 +712,22 @@ impl CodeScanning {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -496,10 +807,6 @@ impl CodeScanning {
      * for each enabled code scanning tool,
      * where a set is determined by a unique combination of analysis values:
      *
-     * * `ref`
-     * * `tool`
-     * * `analysis_key`
-     * * `environment`
      *
      * If you attempt to delete an analysis that is not the most recent in a set,
      * you'll get a 400 response with the message:
@@ -547,17 +854,17 @@ impl CodeScanning {
      * * `owner: &str`
      * * `repo: &str`
      * * `analysis_id: i64` -- The ID of the analysis, as returned from the `GET /repos/{owner}/{repo}/code-scanning/analyses` operation.
-     * * `confirm_delete: &str` -- Allow deletion if the specified analysis is the last in a set. If you attempt to delete the final analysis in a set without setting this parameter to `true`, you'll get a 400 response with the message: `Analysis is last of its type and deletion may result in the loss of historical alert data. Please specify confirm_delete.`.
+     * * `params: &CodeScanningDeleteAnalysisParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_analysis(
+    pub async fn delete_analysis_with(
         &self,
         owner: &str,
         repo: &str,
         analysis_id: i64,
-        confirm_delete: &str,
-    ) -> Result<crate::types::AnalysisDeletion> {
+        params: &CodeScanningDeleteAnalysisParams,
+    ) -> Result<crate::types::CodeScanningAnalysisDeletion> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !confirm_delete.is_empty() {
+        if let Some(confirm_delete) = &params.confirm_delete {
             query_args.push(("confirm_delete".to_string(), confirm_delete.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -572,6 +879,24 @@ impl CodeScanning {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_analysis_with` and pass the query parameters as a `CodeScanningDeleteAnalysisParams` instead.
+     */
+    #[deprecated(note = "use `delete_analysis_with` instead")]
+    pub async fn delete_analysis(
+        &self,
+        owner: &str,
+        repo: &str,
+        analysis_id: i64,
+        confirm_delete: &str,
+    ) -> Result<crate::types::CodeScanningAnalysisDeletion> {
+        let params = CodeScanningDeleteAnalysisParams {
+            confirm_delete: Some(confirm_delete.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_analysis_with(owner, repo, analysis_id, &params)
+            .await
+    }
+
     /**
      * Upload an analysis as SARIF data.
      *
@@ -650,3 +975,437 @@ impl CodeScanning {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `CodeScanning::list_alerts_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CodeScanningListAlertsForRepoParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The Git reference for the results you want to list. The `ref` for a branch can be formatted either as `refs/heads/<branch name>` or simply `<branch name>`. To reference a pull request use `refs/pull/<number>/merge`.
+    pub ref_: Option<String>,
+    /// Set to `open`, `fixed`, or `dismissed` to list code scanning alerts in a specific state.
+    pub state: Option<crate::types::CodeScanningAlertState>,
+    /// The GUID of a code scanning tool. Only results by this tool will be listed. Note that some code scanning tools may not include a GUID in their analysis data. You can specify the tool by using either `tool_guid` or `tool_name`, but not both.
+    pub tool_guid: Option<String>,
+    /// The name of a code scanning tool. Only results by this tool will be listed. You can specify the tool by using either `tool_name` or `tool_guid`, but not both.
+    pub tool_name: Option<String>,
+}
+
+impl CodeScanningListAlertsForRepoParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn ref_<T: ToString>(mut self, ref_: T) -> Self {
+        self.ref_ = Some(ref_.to_string());
+        self
+    }
+
+    pub fn state(mut self, state: crate::types::CodeScanningAlertState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    pub fn tool_guid<T: ToString>(mut self, tool_guid: T) -> Self {
+        self.tool_guid = Some(tool_guid.to_string());
+        self
+    }
+
+    pub fn tool_name<T: ToString>(mut self, tool_name: T) -> Self {
+        self.tool_name = Some(tool_name.to_string());
+        self
+    }
+}
+
+/// The query parameters of `CodeScanning::list_alert_instances_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CodeScanningListAlertInstancesParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The Git reference for the results you want to list. The `ref` for a branch can be formatted either as `refs/heads/<branch name>` or simply `<branch name>`. To reference a pull request use `refs/pull/<number>/merge`.
+    pub ref_: Option<String>,
+}
+
+impl CodeScanningListAlertInstancesParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn ref_<T: ToString>(mut self, ref_: T) -> Self {
+        self.ref_ = Some(ref_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `CodeScanning::list_recent_analyses_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CodeScanningListRecentAnalysesParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The Git reference for the analyses you want to list. The `ref` for a branch can be formatted either as `refs/heads/<branch name>` or simply `<branch name>`. To reference a pull request use `refs/pull/<number>/merge`.
+    pub ref_: Option<String>,
+    /// Filter analyses belonging to the same SARIF upload.
+    pub sarif_id: Option<String>,
+    /// The GUID of a code scanning tool. Only results by this tool will be listed. Note that some code scanning tools may not include a GUID in their analysis data. You can specify the tool by using either `tool_guid` or `tool_name`, but not both.
+    pub tool_guid: Option<String>,
+    /// The name of a code scanning tool. Only results by this tool will be listed. You can specify the tool by using either `tool_name` or `tool_guid`, but not both.
+    pub tool_name: Option<String>,
+}
+
+impl CodeScanningListRecentAnalysesParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn ref_<T: ToString>(mut self, ref_: T) -> Self {
+        self.ref_ = Some(ref_.to_string());
+        self
+    }
+
+    pub fn sarif_id<T: ToString>(mut self, sarif_id: T) -> Self {
+        self.sarif_id = Some(sarif_id.to_string());
+        self
+    }
+
+    pub fn tool_guid<T: ToString>(mut self, tool_guid: T) -> Self {
+        self.tool_guid = Some(tool_guid.to_string());
+        self
+    }
+
+    pub fn tool_name<T: ToString>(mut self, tool_name: T) -> Self {
+        self.tool_name = Some(tool_name.to_string());
+        self
+    }
+}
+
+/// The query parameters of `CodeScanning::delete_analysis_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CodeScanningDeleteAnalysisParams {
+    /// Allow deletion if the specified analysis is the last in a set. If you attempt to delete the final analysis in a set without setting this parameter to `true`, you'll get a 400 response with the message: `Analysis is last of its type and deletion may result in the loss of historical alert data. Please specify confirm_delete.`.
+    pub confirm_delete: Option<String>,
+}
+
+impl CodeScanningDeleteAnalysisParams {
+    pub fn confirm_delete<T: ToString>(mut self, confirm_delete: T) -> Self {
+        self.confirm_delete = Some(confirm_delete.to_string());
+        self
+    }
+}
+
+/// The functions of [`CodeScanning`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCodeScanningApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CodeScanningApi: Send + Sync {
+    /// See [`CodeScanning::list_alerts_for_repo_with`].
+    async fn list_alerts_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListAlertsForRepoParams,
+    ) -> Result<Vec<crate::types::CodeScanningAlertItems>>;
+
+    /// See [`CodeScanning::list_all_alerts_for_repo_with`].
+    async fn list_all_alerts_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListAlertsForRepoParams,
+    ) -> Result<Vec<crate::types::CodeScanningAlertItems>>;
+
+    /// See [`CodeScanning::list_alerts_for_repo_stream`].
+    fn list_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListAlertsForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CodeScanningAlertItems>;
+
+    /// See [`CodeScanning::get_alert`].
+    async fn get_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+    ) -> Result<crate::types::CodeScanningAlert>;
+
+    /// See [`CodeScanning::update_alert`].
+    async fn update_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        body: &crate::types::CodeScanningUpdateAlertRequest,
+    ) -> Result<crate::types::CodeScanningAlert>;
+
+    /// See [`CodeScanning::list_alert_instances_with`].
+    async fn list_alert_instances_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        params: &CodeScanningListAlertInstancesParams,
+    ) -> Result<Vec<crate::types::CodeScanningAlertInstance>>;
+
+    /// See [`CodeScanning::list_all_alert_instances_with`].
+    async fn list_all_alert_instances_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        params: &CodeScanningListAlertInstancesParams,
+    ) -> Result<Vec<crate::types::CodeScanningAlertInstance>>;
+
+    /// See [`CodeScanning::list_alert_instances_stream`].
+    fn list_alert_instances_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        params: &CodeScanningListAlertInstancesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CodeScanningAlertInstance>;
+
+    /// See [`CodeScanning::list_recent_analyses_with`].
+    async fn list_recent_analyses_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListRecentAnalysesParams,
+    ) -> Result<Vec<crate::types::CodeScanningAnalysis>>;
+
+    /// See [`CodeScanning::list_all_recent_analyses_with`].
+    async fn list_all_recent_analyses_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListRecentAnalysesParams,
+    ) -> Result<Vec<crate::types::CodeScanningAnalysis>>;
+
+    /// See [`CodeScanning::list_recent_analyses_stream`].
+    fn list_recent_analyses_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListRecentAnalysesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CodeScanningAnalysis>;
+
+    /// See [`CodeScanning::get_analysis`].
+    async fn get_analysis(
+        &self,
+        owner: &str,
+        repo: &str,
+        analysis_id: i64,
+    ) -> Result<crate::types::CodeScanningAnalysis>;
+
+    /// See [`CodeScanning::delete_analysis_with`].
+    async fn delete_analysis_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        analysis_id: i64,
+        params: &CodeScanningDeleteAnalysisParams,
+    ) -> Result<crate::types::CodeScanningAnalysisDeletion>;
+
+    /// See [`CodeScanning::upload_sarif`].
+    async fn upload_sarif(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::CodeScanningUploadSarifRequest,
+    ) -> Result<crate::types::CodeScanningSarifsReceipt>;
+
+    /// See [`CodeScanning::get_sarif`].
+    async fn get_sarif(
+        &self,
+        owner: &str,
+        repo: &str,
+        sarif_id: &str,
+    ) -> Result<crate::types::CodeScanningSarifsStatus>;
+}
+
+#[async_trait::async_trait]
+impl CodeScanningApi for CodeScanning {
+    async fn list_alerts_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListAlertsForRepoParams,
+    ) -> Result<Vec<crate::types::CodeScanningAlertItems>> {
+        CodeScanning::list_alerts_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_alerts_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListAlertsForRepoParams,
+    ) -> Result<Vec<crate::types::CodeScanningAlertItems>> {
+        CodeScanning::list_all_alerts_for_repo_with(self, owner, repo, params).await
+    }
+
+    fn list_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListAlertsForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CodeScanningAlertItems> {
+        CodeScanning::list_alerts_for_repo_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn get_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+    ) -> Result<crate::types::CodeScanningAlert> {
+        CodeScanning::get_alert(self, owner, repo, alert_number).await
+    }
+
+    async fn update_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        body: &crate::types::CodeScanningUpdateAlertRequest,
+    ) -> Result<crate::types::CodeScanningAlert> {
+        CodeScanning::update_alert(self, owner, repo, alert_number, body).await
+    }
+
+    async fn list_alert_instances_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        params: &CodeScanningListAlertInstancesParams,
+    ) -> Result<Vec<crate::types::CodeScanningAlertInstance>> {
+        CodeScanning::list_alert_instances_with(self, owner, repo, alert_number, params).await
+    }
+
+    async fn list_all_alert_instances_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        params: &CodeScanningListAlertInstancesParams,
+    ) -> Result<Vec<crate::types::CodeScanningAlertInstance>> {
+        CodeScanning::list_all_alert_instances_with(self, owner, repo, alert_number, params).await
+    }
+
+    fn list_alert_instances_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        params: &CodeScanningListAlertInstancesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CodeScanningAlertInstance> {
+        CodeScanning::list_alert_instances_stream(
+            self,
+            owner,
+            repo,
+            alert_number,
+            params,
+            start,
+            max_items,
+        )
+    }
+
+    async fn list_recent_analyses_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListRecentAnalysesParams,
+    ) -> Result<Vec<crate::types::CodeScanningAnalysis>> {
+        CodeScanning::list_recent_analyses_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_recent_analyses_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListRecentAnalysesParams,
+    ) -> Result<Vec<crate::types::CodeScanningAnalysis>> {
+        CodeScanning::list_all_recent_analyses_with(self, owner, repo, params).await
+    }
+
+    fn list_recent_analyses_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &CodeScanningListRecentAnalysesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CodeScanningAnalysis> {
+        CodeScanning::list_recent_analyses_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn get_analysis(
+        &self,
+        owner: &str,
+        repo: &str,
+        analysis_id: i64,
+    ) -> Result<crate::types::CodeScanningAnalysis> {
+        CodeScanning::get_analysis(self, owner, repo, analysis_id).await
+    }
+
+    async fn delete_analysis_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        analysis_id: i64,
+        params: &CodeScanningDeleteAnalysisParams,
+    ) -> Result<crate::types::CodeScanningAnalysisDeletion> {
+        CodeScanning::delete_analysis_with(self, owner, repo, analysis_id, params).await
+    }
+
+    async fn upload_sarif(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::CodeScanningUploadSarifRequest,
+    ) -> Result<crate::types::CodeScanningSarifsReceipt> {
+        CodeScanning::upload_sarif(self, owner, repo, body).await
+    }
+
+    async fn get_sarif(
+        &self,
+        owner: &str,
+        repo: &str,
+        sarif_id: &str,
+    ) -> Result<crate::types::CodeScanningSarifsStatus> {
+        CodeScanning::get_sarif(self, owner, repo, sarif_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CodesOfConduct {
     pub client: Client,
@@ -42,6 +40,41 @@ impl CodesOfConduct {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get all codes of conduct.
+     *
+     * This function performs a `GET` to the `/codes_of_conduct` endpoint.
+     *
+     * As opposed to `get_all_all_codes_of_conduct`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/codes-of-conduct#get-all-codes-of-conduct>
+     */
+    pub fn get_all_codes_of_conduct_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CodeOfConduct> {
+        let url = "/codes_of_conduct".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get a code of conduct.
      *
@@ -94,3 +127,55 @@ impl CodesOfConduct {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`CodesOfConduct`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCodesOfConductApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CodesOfConductApi: Send + Sync {
+    /// See [`CodesOfConduct::get_all_codes_of_conduct`].
+    async fn get_all_codes_of_conduct(&self) -> Result<Vec<crate::types::CodeOfConduct>>;
+
+    /// See [`CodesOfConduct::get_all_all_codes_of_conduct`].
+    async fn get_all_all_codes_of_conduct(&self) -> Result<Vec<crate::types::CodeOfConduct>>;
+
+    /// See [`CodesOfConduct::get_all_codes_of_conduct_stream`].
+    fn get_all_codes_of_conduct_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CodeOfConduct>;
+
+    /// See [`CodesOfConduct::get_conduct_code`].
+    async fn get_conduct_code(&self, key: &str) -> Result<crate::types::CodeOfConduct>;
+
+    /// See [`CodesOfConduct::get_for_repo`].
+    async fn get_for_repo(&self, owner: &str, repo: &str) -> Result<crate::types::CodeOfConduct>;
+}
+
+#[async_trait::async_trait]
+impl CodesOfConductApi for CodesOfConduct {
+    async fn get_all_codes_of_conduct(&self) -> Result<Vec<crate::types::CodeOfConduct>> {
+        CodesOfConduct::get_all_codes_of_conduct(self).await
+    }
+
+    async fn get_all_all_codes_of_conduct(&self) -> Result<Vec<crate::types::CodeOfConduct>> {
+        CodesOfConduct::get_all_all_codes_of_conduct(self).await
+    }
+
+    fn get_all_codes_of_conduct_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CodeOfConduct> {
+        CodesOfConduct::get_all_codes_of_conduct_stream(self, start, max_items)
+    }
+
+    async fn get_conduct_code(&self, key: &str) -> Result<crate::types::CodeOfConduct> {
+        CodesOfConduct::get_conduct_code(self, key).await
+    }
+
+    async fn get_for_repo(&self, owner: &str, repo: &str) -> Result<crate::types::CodeOfConduct> {
+        CodesOfConduct::get_for_repo(self, owner, repo).await
+    }
+}
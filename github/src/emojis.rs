@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Emojis {
     pub client: Client,
@@ -21,8 +19,24 @@ impl Emojis {
      *
      * FROM: <https://docs.github.com/rest/reference/emojis#get-emojis>
      */
-    pub async fn get(&self) -> Result<String> {
+    pub async fn get(&self) -> Result<std::collections::BTreeMap<String, String>> {
         let url = "/emojis".to_string();
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`Emojis`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEmojisApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EmojisApi: Send + Sync {
+    /// See [`Emojis::get`].
+    async fn get(&self) -> Result<std::collections::BTreeMap<String, String>>;
+}
+
+#[async_trait::async_trait]
+impl EmojisApi for Emojis {
+    async fn get(&self) -> Result<std::collections::BTreeMap<String, String>> {
+        Emojis::get(self).await
+    }
+}
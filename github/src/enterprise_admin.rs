@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EnterpriseAdmin {
     pub client: Client,
@@ -83,20 +81,19 @@ impl EnterpriseAdmin {
      * **Parameters:**
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_selected_organizations_enabled_github_actions_enterprise(
+    pub async fn list_selected_organizations_enabled_github_actions_enterprise_with(
         &self,
         enterprise: &str,
-        per_page: i64,
-        page: i64,
-    ) -> Result<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse> {
+        params: &EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseParams,
+    ) -> Result<crate::types::EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsResponse>
+    {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -109,6 +106,27 @@ impl EnterpriseAdmin {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_selected_organizations_enabled_github_actions_enterprise_with` and pass the query parameters as a `EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseParams` instead.
+     */
+    #[deprecated(
+        note = "use `list_selected_organizations_enabled_github_actions_enterprise_with` instead"
+    )]
+    pub async fn list_selected_organizations_enabled_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsResponse>
+    {
+        let params = EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_selected_organizations_enabled_github_actions_enterprise_with(enterprise, &params)
+            .await
+    }
+
     /**
      * Set selected organizations enabled for GitHub Actions in an enterprise.
      *
@@ -127,7 +145,7 @@ impl EnterpriseAdmin {
     pub async fn set_selected_organizations_enabled_github_actions_enterprise(
         &self,
         enterprise: &str,
-        body: &crate::types::EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest,
+        body: &crate::types::EnterpriseAdminSetSelectedOrganizationsEnabledGithubActionsRequest,
     ) -> Result<()> {
         let url = format!(
             "/enterprises/{}/actions/permissions/organizations",
@@ -270,20 +288,18 @@ impl EnterpriseAdmin {
      * **Parameters:**
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_self_hosted_runner_groups_for_enterprise(
+    pub async fn list_self_hosted_runner_groups_for_enterprise_with(
         &self,
         enterprise: &str,
-        per_page: i64,
-        page: i64,
+        params: &EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseParams,
     ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnerGroupsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -296,6 +312,24 @@ impl EnterpriseAdmin {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_self_hosted_runner_groups_for_enterprise_with` and pass the query parameters as a `EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseParams` instead.
+     */
+    #[deprecated(note = "use `list_self_hosted_runner_groups_for_enterprise_with` instead")]
+    pub async fn list_self_hosted_runner_groups_for_enterprise(
+        &self,
+        enterprise: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnerGroupsResponse> {
+        let params = EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_self_hosted_runner_groups_for_enterprise_with(enterprise, &params)
+            .await
+    }
+
     /**
      * Create a self-hosted runner group for an enterprise.
      *
@@ -434,21 +468,19 @@ impl EnterpriseAdmin {
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
      * * `runner_group_id: i64` -- Unique identifier of the self-hosted runner group.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_org_access_to_self_hosted_runner_group_in_enterprise(
+    pub async fn list_org_access_to_self_hosted_runner_group_in_enterprise_with(
         &self,
         enterprise: &str,
         runner_group_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseParams,
     ) -> Result<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -462,6 +494,31 @@ impl EnterpriseAdmin {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_org_access_to_self_hosted_runner_group_in_enterprise_with` and pass the query parameters as a `EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseParams` instead.
+     */
+    #[deprecated(
+        note = "use `list_org_access_to_self_hosted_runner_group_in_enterprise_with` instead"
+    )]
+    pub async fn list_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse> {
+        let params = EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_org_access_to_self_hosted_runner_group_in_enterprise_with(
+            enterprise,
+            runner_group_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Set organization access for a self-hosted runner group in an enterprise.
      *
@@ -576,21 +633,19 @@ impl EnterpriseAdmin {
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
      * * `runner_group_id: i64` -- Unique identifier of the self-hosted runner group.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_self_hosted_runners_in_group_for_enterprise(
+    pub async fn list_self_hosted_runners_in_group_for_enterprise_with(
         &self,
         enterprise: &str,
         runner_group_id: i64,
-        per_page: i64,
-        page: i64,
-    ) -> Result<crate::types::ActionsListSelfHostedRunnersInGroupOrgResponse> {
+        params: &EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseParams,
+    ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnersInGroupResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -604,6 +659,29 @@ impl EnterpriseAdmin {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_self_hosted_runners_in_group_for_enterprise_with` and pass the query parameters as a `EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseParams` instead.
+     */
+    #[deprecated(note = "use `list_self_hosted_runners_in_group_for_enterprise_with` instead")]
+    pub async fn list_self_hosted_runners_in_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnersInGroupResponse> {
+        let params = EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_self_hosted_runners_in_group_for_enterprise_with(
+            enterprise,
+            runner_group_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Set self-hosted runners in a group for an enterprise.
      *
@@ -624,7 +702,7 @@ impl EnterpriseAdmin {
         &self,
         enterprise: &str,
         runner_group_id: i64,
-        body: &crate::types::ActionsSetSelfHostedRunnersInGroupOrgRequest,
+        body: &crate::types::EnterpriseAdminSetSelfHostedRunnersInGroupRequest,
     ) -> Result<()> {
         let url = format!(
             "/enterprises/{}/actions/runner-groups/{}/runners",
@@ -718,20 +796,18 @@ impl EnterpriseAdmin {
      * **Parameters:**
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &EnterpriseAdminListSelfHostedRunnersForEnterpriseParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_self_hosted_runners_for_enterprise(
+    pub async fn list_self_hosted_runners_for_enterprise_with(
         &self,
         enterprise: &str,
-        per_page: i64,
-        page: i64,
+        params: &EnterpriseAdminListSelfHostedRunnersForEnterpriseParams,
     ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnersResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -744,6 +820,24 @@ impl EnterpriseAdmin {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_self_hosted_runners_for_enterprise_with` and pass the query parameters as a `EnterpriseAdminListSelfHostedRunnersForEnterpriseParams` instead.
+     */
+    #[deprecated(note = "use `list_self_hosted_runners_for_enterprise_with` instead")]
+    pub async fn list_self_hosted_runners_for_enterprise(
+        &self,
+        enterprise: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnersResponse> {
+        let params = EnterpriseAdminListSelfHostedRunnersForEnterpriseParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_self_hosted_runners_for_enterprise_with(enterprise, &params)
+            .await
+    }
+
     /**
      * List runner applications for an enterprise.
      *
@@ -796,6 +890,48 @@ impl EnterpriseAdmin {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List runner applications for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/actions/runners/downloads` endpoint.
+     *
+     * As opposed to `list_all_runner_applications_for_enterprise`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists binaries for the runner application that you can download and run.
+     *
+     * You must authenticate using an access token with the `admin:enterprise` scope to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#list-runner-applications-for-an-enterprise>
+     */
+    pub fn list_runner_applications_for_enterprise_stream(
+        &self,
+        enterprise: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RunnerApplication> {
+        let url = format!(
+            "/enterprises/{}/actions/runners/downloads",
+            crate::progenitor_support::encode_path(enterprise),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a registration token for an enterprise.
      *
@@ -939,53 +1075,33 @@ impl EnterpriseAdmin {
      * **Parameters:**
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
-     * * `phrase: &str` -- A search phrase. For more information, see [Searching the audit log](https://docs.github.com/github/setting-up-and-managing-organizations-and-teams/reviewing-the-audit-log-for-your-organization#searching-the-audit-log).
-     * * `include: crate::types::Include` -- The event types to include:
-     *  
-     *  - `web` - returns web (non-Git) events
-     *  - `git` - returns Git events
-     *  - `all` - returns both web and Git events
-     *  
-     *  The default is `web`.
-     * * `after: &str` -- A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events after this cursor.
-     * * `before: &str` -- A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events before this cursor.
-     * * `order: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
+     * * `params: &EnterpriseAdminGetAuditLogParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_audit_log(
+    pub async fn get_audit_log_with(
         &self,
         enterprise: &str,
-        phrase: &str,
-        include: crate::types::Include,
-        after: &str,
-        before: &str,
-        order: crate::types::Order,
-        page: i64,
-        per_page: i64,
+        params: &EnterpriseAdminGetAuditLogParams,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !before.is_empty() {
+        if let Some(before) = &params.before {
             query_args.push(("before".to_string(), before.to_string()));
         }
-        if !include.to_string().is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
-        if !order.to_string().is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !phrase.is_empty() {
+        if let Some(phrase) = &params.phrase {
             query_args.push(("phrase".to_string(), phrase.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -998,6 +1114,33 @@ impl EnterpriseAdmin {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_audit_log_with` and pass the query parameters as a `EnterpriseAdminGetAuditLogParams` instead.
+     */
+    #[deprecated(note = "use `get_audit_log_with` instead")]
+    pub async fn get_audit_log(
+        &self,
+        enterprise: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+        page: i64,
+        per_page: i64,
+    ) -> Result<Vec<crate::types::AuditLogEvent>> {
+        let params = EnterpriseAdminGetAuditLogParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            before: Some(before.to_string()).filter(|s| !s.is_empty()),
+            include: Some(include).filter(|v| !v.to_string().is_empty()),
+            order: Some(order).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            phrase: Some(phrase.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_audit_log_with(enterprise, &params).await
+    }
+
     /**
      * Get the audit log for an enterprise.
      *
@@ -1008,7 +1151,46 @@ impl EnterpriseAdmin {
      * Gets the audit log for an enterprise. To use this endpoint, you must be an enterprise admin, and you must use an access token with the `admin:enterprise` scope.
      *
      * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-the-audit-log-for-an-enterprise>
+     *
+     * **Parameters:**
+     *
+     * * `params: &EnterpriseAdminGetAuditLogParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_audit_log_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminGetAuditLogParams,
+    ) -> Result<Vec<crate::types::AuditLogEvent>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(after) = &params.after {
+            query_args.push(("after".to_string(), after.to_string()));
+        }
+        if let Some(before) = &params.before {
+            query_args.push(("before".to_string(), before.to_string()));
+        }
+        if let Some(include) = &params.include {
+            query_args.push(("include".to_string(), include.to_string()));
+        }
+        if let Some(order) = &params.order {
+            query_args.push(("order".to_string(), order.to_string()));
+        }
+        if let Some(phrase) = &params.phrase {
+            query_args.push(("phrase".to_string(), phrase.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/enterprises/{}/audit-log?{}",
+            crate::progenitor_support::encode_path(enterprise),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_audit_log_with` and pass the query parameters as a `EnterpriseAdminGetAuditLogParams` instead.
      */
+    #[deprecated(note = "use `get_all_audit_log_with` instead")]
     pub async fn get_all_audit_log(
         &self,
         enterprise: &str,
@@ -1018,20 +1200,49 @@ impl EnterpriseAdmin {
         before: &str,
         order: crate::types::Order,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
+        let params = EnterpriseAdminGetAuditLogParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            before: Some(before.to_string()).filter(|s| !s.is_empty()),
+            include: Some(include).filter(|v| !v.to_string().is_empty()),
+            order: Some(order).filter(|v| !v.to_string().is_empty()),
+            phrase: Some(phrase.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_audit_log_with(enterprise, &params).await
+    }
+
+    /**
+     * Get the audit log for an enterprise.
+     *
+     * This function performs a `GET` to the `/enterprises/{enterprise}/audit-log` endpoint.
+     *
+     * As opposed to `get_all_audit_log`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Gets the audit log for an enterprise. To use this endpoint, you must be an enterprise admin, and you must use an access token with the `admin:enterprise` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/enterprise-admin#get-the-audit-log-for-an-enterprise>
+     */
+    pub fn get_audit_log_stream(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminGetAuditLogParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuditLogEvent> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !before.is_empty() {
+        if let Some(before) = &params.before {
             query_args.push(("before".to_string(), before.to_string()));
         }
-        if !include.to_string().is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
-        if !order.to_string().is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !phrase.is_empty() {
+        if let Some(phrase) = &params.phrase {
             query_args.push(("phrase".to_string(), phrase.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1041,7 +1252,22 @@ impl EnterpriseAdmin {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -1056,33 +1282,27 @@ impl EnterpriseAdmin {
      * **Parameters:**
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
-     * * `start_index: i64` -- Used for pagination: the index of the first result to return.
-     * * `count: i64` -- Used for pagination: the number of results to return.
-     * * `filter: &str` -- filter results.
-     * * `excluded_attributes: &str` -- attributes to exclude.
+     * * `params: &EnterpriseAdminListProvisionedGroupsEnterpriseParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_provisioned_groups_enterprise(
+    pub async fn list_provisioned_groups_enterprise_with(
         &self,
         enterprise: &str,
-        start_index: i64,
-        count: i64,
-        filter: &str,
-        excluded_attributes: &str,
+        params: &EnterpriseAdminListProvisionedGroupsEnterpriseParams,
     ) -> Result<crate::types::ScimGroupListEnterprise> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !excluded_attributes.is_empty() {
+        if let Some(excluded_attributes) = &params.excluded_attributes {
             query_args.push((
                 "excludedAttributes".to_string(),
                 excluded_attributes.to_string(),
             ));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if start_index > 0 {
+        if let Some(start_index) = &params.start_index {
             query_args.push(("startIndex".to_string(), start_index.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1095,6 +1315,28 @@ impl EnterpriseAdmin {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_provisioned_groups_enterprise_with` and pass the query parameters as a `EnterpriseAdminListProvisionedGroupsEnterpriseParams` instead.
+     */
+    #[deprecated(note = "use `list_provisioned_groups_enterprise_with` instead")]
+    pub async fn list_provisioned_groups_enterprise(
+        &self,
+        enterprise: &str,
+        start_index: i64,
+        count: i64,
+        filter: &str,
+        excluded_attributes: &str,
+    ) -> Result<crate::types::ScimGroupListEnterprise> {
+        let params = EnterpriseAdminListProvisionedGroupsEnterpriseParams {
+            count: Some(count).filter(|v| *v > 0),
+            excluded_attributes: Some(excluded_attributes.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            start_index: Some(start_index).filter(|v| *v > 0),
+        };
+        self.list_provisioned_groups_enterprise_with(enterprise, &params)
+            .await
+    }
+
     /**
      * Provision a SCIM enterprise group and invite users.
      *
@@ -1138,16 +1380,16 @@ impl EnterpriseAdmin {
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
      * * `scim_group_id: &str` -- Identifier generated by the GitHub SCIM endpoint.
-     * * `excluded_attributes: &str` -- Attributes to exclude.
+     * * `params: &EnterpriseAdminGetProvisioningInformationForEnterpriseGroupParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_provisioning_information_for_enterprise_group(
+    pub async fn get_provisioning_information_for_enterprise_group_with(
         &self,
         enterprise: &str,
         scim_group_id: &str,
-        excluded_attributes: &str,
+        params: &EnterpriseAdminGetProvisioningInformationForEnterpriseGroupParams,
     ) -> Result<crate::types::ScimEnterpriseGroup> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !excluded_attributes.is_empty() {
+        if let Some(excluded_attributes) = &params.excluded_attributes {
             query_args.push((
                 "excludedAttributes".to_string(),
                 excluded_attributes.to_string(),
@@ -1164,6 +1406,27 @@ impl EnterpriseAdmin {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_provisioning_information_for_enterprise_group_with` and pass the query parameters as a `EnterpriseAdminGetProvisioningInformationForEnterpriseGroupParams` instead.
+     */
+    #[deprecated(note = "use `get_provisioning_information_for_enterprise_group_with` instead")]
+    pub async fn get_provisioning_information_for_enterprise_group(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+        excluded_attributes: &str,
+    ) -> Result<crate::types::ScimEnterpriseGroup> {
+        let params = EnterpriseAdminGetProvisioningInformationForEnterpriseGroupParams {
+            excluded_attributes: Some(excluded_attributes.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_provisioning_information_for_enterprise_group_with(
+            enterprise,
+            scim_group_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Set SCIM information for a provisioned enterprise group.
      *
@@ -1184,7 +1447,7 @@ impl EnterpriseAdmin {
         &self,
         enterprise: &str,
         scim_group_id: &str,
-        body: &crate::types::EnterpriseAdminProvisionInviteGroupRequest,
+        body: &crate::types::EnterpriseAdminSetInformationProvisionedGroupRequest,
     ) -> Result<crate::types::ScimEnterpriseGroup> {
         let url = format!(
             "/scim/v2/enterprises/{}/Groups/{}",
@@ -1287,25 +1550,21 @@ impl EnterpriseAdmin {
      * **Parameters:**
      *
      * * `enterprise: &str` -- The slug version of the enterprise name. You can also substitute this value with the enterprise id.
-     * * `start_index: i64` -- Used for pagination: the index of the first result to return.
-     * * `count: i64` -- Used for pagination: the number of results to return.
-     * * `filter: &str` -- filter results.
+     * * `params: &EnterpriseAdminListProvisionedIdentitiesEnterpriseParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_provisioned_identities_enterprise(
+    pub async fn list_provisioned_identities_enterprise_with(
         &self,
         enterprise: &str,
-        start_index: i64,
-        count: i64,
-        filter: &str,
+        params: &EnterpriseAdminListProvisionedIdentitiesEnterpriseParams,
     ) -> Result<crate::types::ScimUserListEnterprise> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if start_index > 0 {
+        if let Some(start_index) = &params.start_index {
             query_args.push(("startIndex".to_string(), start_index.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1318,6 +1577,26 @@ impl EnterpriseAdmin {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_provisioned_identities_enterprise_with` and pass the query parameters as a `EnterpriseAdminListProvisionedIdentitiesEnterpriseParams` instead.
+     */
+    #[deprecated(note = "use `list_provisioned_identities_enterprise_with` instead")]
+    pub async fn list_provisioned_identities_enterprise(
+        &self,
+        enterprise: &str,
+        start_index: i64,
+        count: i64,
+        filter: &str,
+    ) -> Result<crate::types::ScimUserListEnterprise> {
+        let params = EnterpriseAdminListProvisionedIdentitiesEnterpriseParams {
+            count: Some(count).filter(|v| *v > 0),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            start_index: Some(start_index).filter(|v| *v > 0),
+        };
+        self.list_provisioned_identities_enterprise_with(enterprise, &params)
+            .await
+    }
+
     /**
      * Provision and invite a SCIM enterprise user.
      *
@@ -1402,7 +1681,7 @@ impl EnterpriseAdmin {
         &self,
         enterprise: &str,
         scim_user_id: &str,
-        body: &crate::types::EnterpriseAdminProvisionInviteUserRequest,
+        body: &crate::types::EnterpriseAdminSetInformationProvisionedUserRequest,
     ) -> Result<crate::types::ScimEnterpriseUser> {
         let url = format!(
             "/scim/v2/enterprises/{}/Users/{}",
@@ -1491,3 +1770,1050 @@ impl EnterpriseAdmin {
             .await
     }
 }
+
+/// The query parameters of `EnterpriseAdmin::list_selected_organizations_enabled_github_actions_enterprise_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `EnterpriseAdmin::list_self_hosted_runner_groups_for_enterprise_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `EnterpriseAdmin::list_org_access_to_self_hosted_runner_group_in_enterprise_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `EnterpriseAdmin::list_self_hosted_runners_in_group_for_enterprise_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `EnterpriseAdmin::list_self_hosted_runners_for_enterprise_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnterpriseAdminListSelfHostedRunnersForEnterpriseParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl EnterpriseAdminListSelfHostedRunnersForEnterpriseParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `EnterpriseAdmin::get_audit_log_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnterpriseAdminGetAuditLogParams {
+    /// A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events after this cursor.
+    pub after: Option<String>,
+    /// A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events before this cursor.
+    pub before: Option<String>,
+    /// The event types to include:
+    ///
+    /// - `web` - returns web (non-Git) events
+    /// - `git` - returns Git events
+    /// - `all` - returns both web and Git events
+    ///
+    ///   The default is `web`.
+    pub include: Option<crate::types::Include>,
+    /// Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
+    pub order: Option<crate::types::Order>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// A search phrase. For more information, see [Searching the audit log](https://docs.github.com/github/setting-up-and-managing-organizations-and-teams/reviewing-the-audit-log-for-your-organization#searching-the-audit-log).
+    pub phrase: Option<String>,
+}
+
+impl EnterpriseAdminGetAuditLogParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn before<T: ToString>(mut self, before: T) -> Self {
+        self.before = Some(before.to_string());
+        self
+    }
+
+    pub fn include(mut self, include: crate::types::Include) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn phrase<T: ToString>(mut self, phrase: T) -> Self {
+        self.phrase = Some(phrase.to_string());
+        self
+    }
+}
+
+/// The query parameters of `EnterpriseAdmin::list_provisioned_groups_enterprise_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnterpriseAdminListProvisionedGroupsEnterpriseParams {
+    /// Used for pagination: the number of results to return.
+    pub count: Option<i64>,
+    /// attributes to exclude.
+    pub excluded_attributes: Option<String>,
+    /// filter results.
+    pub filter: Option<String>,
+    /// Used for pagination: the index of the first result to return.
+    pub start_index: Option<i64>,
+}
+
+impl EnterpriseAdminListProvisionedGroupsEnterpriseParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn excluded_attributes<T: ToString>(mut self, excluded_attributes: T) -> Self {
+        self.excluded_attributes = Some(excluded_attributes.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn start_index(mut self, start_index: i64) -> Self {
+        self.start_index = Some(start_index);
+        self
+    }
+}
+
+/// The query parameters of `EnterpriseAdmin::get_provisioning_information_for_enterprise_group_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnterpriseAdminGetProvisioningInformationForEnterpriseGroupParams {
+    /// Attributes to exclude.
+    pub excluded_attributes: Option<String>,
+}
+
+impl EnterpriseAdminGetProvisioningInformationForEnterpriseGroupParams {
+    pub fn excluded_attributes<T: ToString>(mut self, excluded_attributes: T) -> Self {
+        self.excluded_attributes = Some(excluded_attributes.to_string());
+        self
+    }
+}
+
+/// The query parameters of `EnterpriseAdmin::list_provisioned_identities_enterprise_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EnterpriseAdminListProvisionedIdentitiesEnterpriseParams {
+    /// Used for pagination: the number of results to return.
+    pub count: Option<i64>,
+    /// filter results.
+    pub filter: Option<String>,
+    /// Used for pagination: the index of the first result to return.
+    pub start_index: Option<i64>,
+}
+
+impl EnterpriseAdminListProvisionedIdentitiesEnterpriseParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn start_index(mut self, start_index: i64) -> Self {
+        self.start_index = Some(start_index);
+        self
+    }
+}
+
+/// The functions of [`EnterpriseAdmin`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEnterpriseAdminApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EnterpriseAdminApi: Send + Sync {
+    /// See [`EnterpriseAdmin::get_github_actions_permissions_enterprise`].
+    async fn get_github_actions_permissions_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::ActionsEnterprisePermissions>;
+
+    /// See [`EnterpriseAdmin::set_github_actions_permissions_enterprise`].
+    async fn set_github_actions_permissions_enterprise(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminSetGithubActionsPermissionsRequest,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::list_selected_organizations_enabled_github_actions_enterprise_with`].
+    async fn list_selected_organizations_enabled_github_actions_enterprise_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseParams,
+    ) -> Result<crate::types::EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsResponse>;
+
+    /// See [`EnterpriseAdmin::set_selected_organizations_enabled_github_actions_enterprise`].
+    async fn set_selected_organizations_enabled_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminSetSelectedOrganizationsEnabledGithubActionsRequest,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::enable_selected_organization_github_actions_enterprise`].
+    async fn enable_selected_organization_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+        org_id: i64,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::disable_selected_organization_github_actions_enterprise`].
+    async fn disable_selected_organization_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+        org_id: i64,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::get_allowed_actions_enterprise`].
+    async fn get_allowed_actions_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::SelectedActions>;
+
+    /// See [`EnterpriseAdmin::set_allowed_actions_enterprise`].
+    async fn set_allowed_actions_enterprise(
+        &self,
+        enterprise: &str,
+        body: &crate::types::SelectedActions,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::list_self_hosted_runner_groups_for_enterprise_with`].
+    async fn list_self_hosted_runner_groups_for_enterprise_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseParams,
+    ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnerGroupsResponse>;
+
+    /// See [`EnterpriseAdmin::create_self_hosted_runner_group_for_enterprise`].
+    async fn create_self_hosted_runner_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminCreateSelfHostedRunnerGroupRequest,
+    ) -> Result<crate::types::RunnerGroupsEnterprise>;
+
+    /// See [`EnterpriseAdmin::get_self_hosted_runner_group_for_enterprise`].
+    async fn get_self_hosted_runner_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> Result<crate::types::RunnerGroupsEnterprise>;
+
+    /// See [`EnterpriseAdmin::delete_self_hosted_runner_group_from_enterprise`].
+    async fn delete_self_hosted_runner_group_from_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::update_self_hosted_runner_group_for_enterprise`].
+    async fn update_self_hosted_runner_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        body: &crate::types::EnterpriseAdminUpdateSelfHostedRunnerGroupRequest,
+    ) -> Result<crate::types::RunnerGroupsEnterprise>;
+
+    /// See [`EnterpriseAdmin::list_org_access_to_self_hosted_runner_group_in_enterprise_with`].
+    async fn list_org_access_to_self_hosted_runner_group_in_enterprise_with(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        params: &EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseParams,
+    ) -> Result<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse>;
+
+    /// See [`EnterpriseAdmin::set_org_access_to_self_hosted_runner_group_in_enterprise`].
+    async fn set_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        body: &crate::types::EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::add_org_access_to_self_hosted_runner_group_in_enterprise`].
+    async fn add_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        org_id: i64,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::remove_org_access_to_self_hosted_runner_group_in_enterprise`].
+    async fn remove_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        org_id: i64,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::list_self_hosted_runners_in_group_for_enterprise_with`].
+    async fn list_self_hosted_runners_in_group_for_enterprise_with(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        params: &EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseParams,
+    ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnersInGroupResponse>;
+
+    /// See [`EnterpriseAdmin::set_self_hosted_runners_in_group_for_enterprise`].
+    async fn set_self_hosted_runners_in_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        body: &crate::types::EnterpriseAdminSetSelfHostedRunnersInGroupRequest,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::add_self_hosted_runner_to_group_for_enterprise`].
+    async fn add_self_hosted_runner_to_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::remove_self_hosted_runner_from_group_for_enterprise`].
+    async fn remove_self_hosted_runner_from_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::list_self_hosted_runners_for_enterprise_with`].
+    async fn list_self_hosted_runners_for_enterprise_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminListSelfHostedRunnersForEnterpriseParams,
+    ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnersResponse>;
+
+    /// See [`EnterpriseAdmin::list_runner_applications_for_enterprise`].
+    async fn list_runner_applications_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> Result<Vec<crate::types::RunnerApplication>>;
+
+    /// See [`EnterpriseAdmin::list_all_runner_applications_for_enterprise`].
+    async fn list_all_runner_applications_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> Result<Vec<crate::types::RunnerApplication>>;
+
+    /// See [`EnterpriseAdmin::list_runner_applications_for_enterprise_stream`].
+    fn list_runner_applications_for_enterprise_stream(
+        &self,
+        enterprise: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RunnerApplication>;
+
+    /// See [`EnterpriseAdmin::create_registration_token_for_enterprise`].
+    async fn create_registration_token_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::AuthenticationToken>;
+
+    /// See [`EnterpriseAdmin::create_remove_token_for_enterprise`].
+    async fn create_remove_token_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::AuthenticationToken>;
+
+    /// See [`EnterpriseAdmin::get_self_hosted_runner_for_enterprise`].
+    async fn get_self_hosted_runner_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_id: i64,
+    ) -> Result<crate::types::Runner>;
+
+    /// See [`EnterpriseAdmin::delete_self_hosted_runner_from_enterprise`].
+    async fn delete_self_hosted_runner_from_enterprise(
+        &self,
+        enterprise: &str,
+        runner_id: i64,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::get_audit_log_with`].
+    async fn get_audit_log_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminGetAuditLogParams,
+    ) -> Result<Vec<crate::types::AuditLogEvent>>;
+
+    /// See [`EnterpriseAdmin::get_all_audit_log_with`].
+    async fn get_all_audit_log_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminGetAuditLogParams,
+    ) -> Result<Vec<crate::types::AuditLogEvent>>;
+
+    /// See [`EnterpriseAdmin::get_audit_log_stream`].
+    fn get_audit_log_stream(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminGetAuditLogParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuditLogEvent>;
+
+    /// See [`EnterpriseAdmin::list_provisioned_groups_enterprise_with`].
+    async fn list_provisioned_groups_enterprise_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminListProvisionedGroupsEnterpriseParams,
+    ) -> Result<crate::types::ScimGroupListEnterprise>;
+
+    /// See [`EnterpriseAdmin::provision_and_invite_enterprise_group`].
+    async fn provision_and_invite_enterprise_group(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminProvisionInviteGroupRequest,
+    ) -> Result<crate::types::ScimEnterpriseGroup>;
+
+    /// See [`EnterpriseAdmin::get_provisioning_information_for_enterprise_group_with`].
+    async fn get_provisioning_information_for_enterprise_group_with(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+        params: &EnterpriseAdminGetProvisioningInformationForEnterpriseGroupParams,
+    ) -> Result<crate::types::ScimEnterpriseGroup>;
+
+    /// See [`EnterpriseAdmin::set_information_for_provisioned_enterprise_group`].
+    async fn set_information_for_provisioned_enterprise_group(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+        body: &crate::types::EnterpriseAdminSetInformationProvisionedGroupRequest,
+    ) -> Result<crate::types::ScimEnterpriseGroup>;
+
+    /// See [`EnterpriseAdmin::delete_scim_group_from_enterprise`].
+    async fn delete_scim_group_from_enterprise(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+    ) -> Result<()>;
+
+    /// See [`EnterpriseAdmin::update_attribute_for_enterprise_group`].
+    async fn update_attribute_for_enterprise_group(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+        body: &crate::types::EnterpriseAdminUpdateAttributeGroupRequest,
+    ) -> Result<crate::types::ScimEnterpriseGroup>;
+
+    /// See [`EnterpriseAdmin::list_provisioned_identities_enterprise_with`].
+    async fn list_provisioned_identities_enterprise_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminListProvisionedIdentitiesEnterpriseParams,
+    ) -> Result<crate::types::ScimUserListEnterprise>;
+
+    /// See [`EnterpriseAdmin::provision_and_invite_enterprise_user`].
+    async fn provision_and_invite_enterprise_user(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminProvisionInviteUserRequest,
+    ) -> Result<crate::types::ScimEnterpriseUser>;
+
+    /// See [`EnterpriseAdmin::get_provisioning_information_for_enterprise_user`].
+    async fn get_provisioning_information_for_enterprise_user(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+    ) -> Result<crate::types::ScimEnterpriseUser>;
+
+    /// See [`EnterpriseAdmin::set_information_for_provisioned_enterprise_user`].
+    async fn set_information_for_provisioned_enterprise_user(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+        body: &crate::types::EnterpriseAdminSetInformationProvisionedUserRequest,
+    ) -> Result<crate::types::ScimEnterpriseUser>;
+
+    /// See [`EnterpriseAdmin::delete_user_from_enterprise`].
+    async fn delete_user_from_enterprise(&self, enterprise: &str, scim_user_id: &str)
+        -> Result<()>;
+
+    /// See [`EnterpriseAdmin::update_attribute_for_enterprise_user`].
+    async fn update_attribute_for_enterprise_user(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+        body: &crate::types::EnterpriseAdminUpdateAttributeUserRequest,
+    ) -> Result<crate::types::ScimEnterpriseUser>;
+}
+
+#[async_trait::async_trait]
+impl EnterpriseAdminApi for EnterpriseAdmin {
+    async fn get_github_actions_permissions_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::ActionsEnterprisePermissions> {
+        EnterpriseAdmin::get_github_actions_permissions_enterprise(self, enterprise).await
+    }
+
+    async fn set_github_actions_permissions_enterprise(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminSetGithubActionsPermissionsRequest,
+    ) -> Result<()> {
+        EnterpriseAdmin::set_github_actions_permissions_enterprise(self, enterprise, body).await
+    }
+
+    async fn list_selected_organizations_enabled_github_actions_enterprise_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsEnterpriseParams,
+    ) -> Result<crate::types::EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsResponse>
+    {
+        EnterpriseAdmin::list_selected_organizations_enabled_github_actions_enterprise_with(
+            self, enterprise, params,
+        )
+        .await
+    }
+
+    async fn set_selected_organizations_enabled_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminSetSelectedOrganizationsEnabledGithubActionsRequest,
+    ) -> Result<()> {
+        EnterpriseAdmin::set_selected_organizations_enabled_github_actions_enterprise(
+            self, enterprise, body,
+        )
+        .await
+    }
+
+    async fn enable_selected_organization_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+        org_id: i64,
+    ) -> Result<()> {
+        EnterpriseAdmin::enable_selected_organization_github_actions_enterprise(
+            self, enterprise, org_id,
+        )
+        .await
+    }
+
+    async fn disable_selected_organization_github_actions_enterprise(
+        &self,
+        enterprise: &str,
+        org_id: i64,
+    ) -> Result<()> {
+        EnterpriseAdmin::disable_selected_organization_github_actions_enterprise(
+            self, enterprise, org_id,
+        )
+        .await
+    }
+
+    async fn get_allowed_actions_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::SelectedActions> {
+        EnterpriseAdmin::get_allowed_actions_enterprise(self, enterprise).await
+    }
+
+    async fn set_allowed_actions_enterprise(
+        &self,
+        enterprise: &str,
+        body: &crate::types::SelectedActions,
+    ) -> Result<()> {
+        EnterpriseAdmin::set_allowed_actions_enterprise(self, enterprise, body).await
+    }
+
+    async fn list_self_hosted_runner_groups_for_enterprise_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminListSelfHostedRunnerGroupsForEnterpriseParams,
+    ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnerGroupsResponse> {
+        EnterpriseAdmin::list_self_hosted_runner_groups_for_enterprise_with(
+            self, enterprise, params,
+        )
+        .await
+    }
+
+    async fn create_self_hosted_runner_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminCreateSelfHostedRunnerGroupRequest,
+    ) -> Result<crate::types::RunnerGroupsEnterprise> {
+        EnterpriseAdmin::create_self_hosted_runner_group_for_enterprise(self, enterprise, body)
+            .await
+    }
+
+    async fn get_self_hosted_runner_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> Result<crate::types::RunnerGroupsEnterprise> {
+        EnterpriseAdmin::get_self_hosted_runner_group_for_enterprise(
+            self,
+            enterprise,
+            runner_group_id,
+        )
+        .await
+    }
+
+    async fn delete_self_hosted_runner_group_from_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+    ) -> Result<()> {
+        EnterpriseAdmin::delete_self_hosted_runner_group_from_enterprise(
+            self,
+            enterprise,
+            runner_group_id,
+        )
+        .await
+    }
+
+    async fn update_self_hosted_runner_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        body: &crate::types::EnterpriseAdminUpdateSelfHostedRunnerGroupRequest,
+    ) -> Result<crate::types::RunnerGroupsEnterprise> {
+        EnterpriseAdmin::update_self_hosted_runner_group_for_enterprise(
+            self,
+            enterprise,
+            runner_group_id,
+            body,
+        )
+        .await
+    }
+
+    async fn list_org_access_to_self_hosted_runner_group_in_enterprise_with(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        params: &EnterpriseAdminListOrgAccessToSelfHostedRunnerGroupInEnterpriseParams,
+    ) -> Result<crate::types::EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse> {
+        EnterpriseAdmin::list_org_access_to_self_hosted_runner_group_in_enterprise_with(
+            self,
+            enterprise,
+            runner_group_id,
+            params,
+        )
+        .await
+    }
+
+    async fn set_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        body: &crate::types::EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest,
+    ) -> Result<()> {
+        EnterpriseAdmin::set_org_access_to_self_hosted_runner_group_in_enterprise(
+            self,
+            enterprise,
+            runner_group_id,
+            body,
+        )
+        .await
+    }
+
+    async fn add_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        org_id: i64,
+    ) -> Result<()> {
+        EnterpriseAdmin::add_org_access_to_self_hosted_runner_group_in_enterprise(
+            self,
+            enterprise,
+            runner_group_id,
+            org_id,
+        )
+        .await
+    }
+
+    async fn remove_org_access_to_self_hosted_runner_group_in_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        org_id: i64,
+    ) -> Result<()> {
+        EnterpriseAdmin::remove_org_access_to_self_hosted_runner_group_in_enterprise(
+            self,
+            enterprise,
+            runner_group_id,
+            org_id,
+        )
+        .await
+    }
+
+    async fn list_self_hosted_runners_in_group_for_enterprise_with(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        params: &EnterpriseAdminListSelfHostedRunnersInGroupForEnterpriseParams,
+    ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnersInGroupResponse> {
+        EnterpriseAdmin::list_self_hosted_runners_in_group_for_enterprise_with(
+            self,
+            enterprise,
+            runner_group_id,
+            params,
+        )
+        .await
+    }
+
+    async fn set_self_hosted_runners_in_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        body: &crate::types::EnterpriseAdminSetSelfHostedRunnersInGroupRequest,
+    ) -> Result<()> {
+        EnterpriseAdmin::set_self_hosted_runners_in_group_for_enterprise(
+            self,
+            enterprise,
+            runner_group_id,
+            body,
+        )
+        .await
+    }
+
+    async fn add_self_hosted_runner_to_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> Result<()> {
+        EnterpriseAdmin::add_self_hosted_runner_to_group_for_enterprise(
+            self,
+            enterprise,
+            runner_group_id,
+            runner_id,
+        )
+        .await
+    }
+
+    async fn remove_self_hosted_runner_from_group_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_group_id: i64,
+        runner_id: i64,
+    ) -> Result<()> {
+        EnterpriseAdmin::remove_self_hosted_runner_from_group_for_enterprise(
+            self,
+            enterprise,
+            runner_group_id,
+            runner_id,
+        )
+        .await
+    }
+
+    async fn list_self_hosted_runners_for_enterprise_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminListSelfHostedRunnersForEnterpriseParams,
+    ) -> Result<crate::types::EnterpriseAdminListSelfHostedRunnersResponse> {
+        EnterpriseAdmin::list_self_hosted_runners_for_enterprise_with(self, enterprise, params)
+            .await
+    }
+
+    async fn list_runner_applications_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> Result<Vec<crate::types::RunnerApplication>> {
+        EnterpriseAdmin::list_runner_applications_for_enterprise(self, enterprise).await
+    }
+
+    async fn list_all_runner_applications_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> Result<Vec<crate::types::RunnerApplication>> {
+        EnterpriseAdmin::list_all_runner_applications_for_enterprise(self, enterprise).await
+    }
+
+    fn list_runner_applications_for_enterprise_stream(
+        &self,
+        enterprise: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RunnerApplication> {
+        EnterpriseAdmin::list_runner_applications_for_enterprise_stream(
+            self, enterprise, start, max_items,
+        )
+    }
+
+    async fn create_registration_token_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::AuthenticationToken> {
+        EnterpriseAdmin::create_registration_token_for_enterprise(self, enterprise).await
+    }
+
+    async fn create_remove_token_for_enterprise(
+        &self,
+        enterprise: &str,
+    ) -> Result<crate::types::AuthenticationToken> {
+        EnterpriseAdmin::create_remove_token_for_enterprise(self, enterprise).await
+    }
+
+    async fn get_self_hosted_runner_for_enterprise(
+        &self,
+        enterprise: &str,
+        runner_id: i64,
+    ) -> Result<crate::types::Runner> {
+        EnterpriseAdmin::get_self_hosted_runner_for_enterprise(self, enterprise, runner_id).await
+    }
+
+    async fn delete_self_hosted_runner_from_enterprise(
+        &self,
+        enterprise: &str,
+        runner_id: i64,
+    ) -> Result<()> {
+        EnterpriseAdmin::delete_self_hosted_runner_from_enterprise(self, enterprise, runner_id)
+            .await
+    }
+
+    async fn get_audit_log_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminGetAuditLogParams,
+    ) -> Result<Vec<crate::types::AuditLogEvent>> {
+        EnterpriseAdmin::get_audit_log_with(self, enterprise, params).await
+    }
+
+    async fn get_all_audit_log_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminGetAuditLogParams,
+    ) -> Result<Vec<crate::types::AuditLogEvent>> {
+        EnterpriseAdmin::get_all_audit_log_with(self, enterprise, params).await
+    }
+
+    fn get_audit_log_stream(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminGetAuditLogParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuditLogEvent> {
+        EnterpriseAdmin::get_audit_log_stream(self, enterprise, params, start, max_items)
+    }
+
+    async fn list_provisioned_groups_enterprise_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminListProvisionedGroupsEnterpriseParams,
+    ) -> Result<crate::types::ScimGroupListEnterprise> {
+        EnterpriseAdmin::list_provisioned_groups_enterprise_with(self, enterprise, params).await
+    }
+
+    async fn provision_and_invite_enterprise_group(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminProvisionInviteGroupRequest,
+    ) -> Result<crate::types::ScimEnterpriseGroup> {
+        EnterpriseAdmin::provision_and_invite_enterprise_group(self, enterprise, body).await
+    }
+
+    async fn get_provisioning_information_for_enterprise_group_with(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+        params: &EnterpriseAdminGetProvisioningInformationForEnterpriseGroupParams,
+    ) -> Result<crate::types::ScimEnterpriseGroup> {
+        EnterpriseAdmin::get_provisioning_information_for_enterprise_group_with(
+            self,
+            enterprise,
+            scim_group_id,
+            params,
+        )
+        .await
+    }
+
+    async fn set_information_for_provisioned_enterprise_group(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+        body: &crate::types::EnterpriseAdminSetInformationProvisionedGroupRequest,
+    ) -> Result<crate::types::ScimEnterpriseGroup> {
+        EnterpriseAdmin::set_information_for_provisioned_enterprise_group(
+            self,
+            enterprise,
+            scim_group_id,
+            body,
+        )
+        .await
+    }
+
+    async fn delete_scim_group_from_enterprise(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+    ) -> Result<()> {
+        EnterpriseAdmin::delete_scim_group_from_enterprise(self, enterprise, scim_group_id).await
+    }
+
+    async fn update_attribute_for_enterprise_group(
+        &self,
+        enterprise: &str,
+        scim_group_id: &str,
+        body: &crate::types::EnterpriseAdminUpdateAttributeGroupRequest,
+    ) -> Result<crate::types::ScimEnterpriseGroup> {
+        EnterpriseAdmin::update_attribute_for_enterprise_group(
+            self,
+            enterprise,
+            scim_group_id,
+            body,
+        )
+        .await
+    }
+
+    async fn list_provisioned_identities_enterprise_with(
+        &self,
+        enterprise: &str,
+        params: &EnterpriseAdminListProvisionedIdentitiesEnterpriseParams,
+    ) -> Result<crate::types::ScimUserListEnterprise> {
+        EnterpriseAdmin::list_provisioned_identities_enterprise_with(self, enterprise, params).await
+    }
+
+    async fn provision_and_invite_enterprise_user(
+        &self,
+        enterprise: &str,
+        body: &crate::types::EnterpriseAdminProvisionInviteUserRequest,
+    ) -> Result<crate::types::ScimEnterpriseUser> {
+        EnterpriseAdmin::provision_and_invite_enterprise_user(self, enterprise, body).await
+    }
+
+    async fn get_provisioning_information_for_enterprise_user(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+    ) -> Result<crate::types::ScimEnterpriseUser> {
+        EnterpriseAdmin::get_provisioning_information_for_enterprise_user(
+            self,
+            enterprise,
+            scim_user_id,
+        )
+        .await
+    }
+
+    async fn set_information_for_provisioned_enterprise_user(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+        body: &crate::types::EnterpriseAdminSetInformationProvisionedUserRequest,
+    ) -> Result<crate::types::ScimEnterpriseUser> {
+        EnterpriseAdmin::set_information_for_provisioned_enterprise_user(
+            self,
+            enterprise,
+            scim_user_id,
+            body,
+        )
+        .await
+    }
+
+    async fn delete_user_from_enterprise(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+    ) -> Result<()> {
+        EnterpriseAdmin::delete_user_from_enterprise(self, enterprise, scim_user_id).await
+    }
+
+    async fn update_attribute_for_enterprise_user(
+        &self,
+        enterprise: &str,
+        scim_user_id: &str,
+        body: &crate::types::EnterpriseAdminUpdateAttributeUserRequest,
+    ) -> Result<crate::types::ScimEnterpriseUser> {
+        EnterpriseAdmin::update_attribute_for_enterprise_user(self, enterprise, scim_user_id, body)
+            .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Gists {
     pub client: Client,
@@ -23,25 +21,18 @@ impl Gists {
      *
      * **Parameters:**
      *
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
-        &self,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
-    ) -> Result<Vec<crate::types::BaseGist>> {
+    pub async fn list_with(&self, params: &GistsListParams) -> Result<Vec<crate::types::BaseGist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/gists?{}", query_);
@@ -49,6 +40,24 @@ impl Gists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `GistsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        let params = GistsListParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * List gists for the authenticated user.
      *
@@ -59,19 +68,80 @@ impl Gists {
      * Lists the authenticated user's gists or if called anonymously, this endpoint returns all public gists:
      *
      * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-the-authenticated-user>
+     *
+     * **Parameters:**
+     *
+     * * `params: &GistsListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(
+        &self,
+        params: &GistsListParams,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/gists?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `GistsListParams` instead.
      */
+    #[deprecated(note = "use `list_all_with` instead")]
     pub async fn list_all(
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::BaseGist>> {
+        let params = GistsListParams {
+            since,
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * List gists for the authenticated user.
+     *
+     * This function performs a `GET` to the `/gists` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the authenticated user's gists or if called anonymously, this endpoint returns all public gists:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-the-authenticated-user>
+     */
+    pub fn list_stream(
+        &self,
+        params: &GistsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BaseGist> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/gists?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -108,25 +178,21 @@ impl Gists {
      *
      * **Parameters:**
      *
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListPublicParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_public(
+    pub async fn list_public_with(
         &self,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &GistsListPublicParams,
     ) -> Result<Vec<crate::types::BaseGist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/gists/public?{}", query_);
@@ -134,6 +200,24 @@ impl Gists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_public_with` and pass the query parameters as a `GistsListPublicParams` instead.
+     */
+    #[deprecated(note = "use `list_public_with` instead")]
+    pub async fn list_public(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        let params = GistsListPublicParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+        };
+        self.list_public_with(&params).await
+    }
+
     /**
      * List public gists.
      *
@@ -146,19 +230,82 @@ impl Gists {
      * Note: With [pagination](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination), you can fetch up to 3000 gists. For example, you can fetch 100 pages with 30 gists per page or 30 pages with 100 gists per page.
      *
      * FROM: <https://docs.github.com/rest/reference/gists#list-public-gists>
+     *
+     * **Parameters:**
+     *
+     * * `params: &GistsListPublicParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn list_all_public_with(
+        &self,
+        params: &GistsListPublicParams,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/gists/public?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_public_with` and pass the query parameters as a `GistsListPublicParams` instead.
+     */
+    #[deprecated(note = "use `list_all_public_with` instead")]
     pub async fn list_all_public(
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::BaseGist>> {
+        let params = GistsListPublicParams {
+            since,
+            ..Default::default()
+        };
+        self.list_all_public_with(&params).await
+    }
+
+    /**
+     * List public gists.
+     *
+     * This function performs a `GET` to the `/gists/public` endpoint.
+     *
+     * As opposed to `list_all_public`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List public gists sorted by most recently updated to least recently updated.
+     *
+     * Note: With [pagination](https://docs.github.com/rest/overview/resources-in-the-rest-api#pagination), you can fetch up to 3000 gists. For example, you can fetch 100 pages with 30 gists per page or 30 pages with 100 gists per page.
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-public-gists>
+     */
+    pub fn list_public_stream(
+        &self,
+        params: &GistsListPublicParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BaseGist> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/gists/public?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -172,25 +319,21 @@ impl Gists {
      *
      * **Parameters:**
      *
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListStarredParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_starred(
+    pub async fn list_starred_with(
         &self,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &GistsListStarredParams,
     ) -> Result<Vec<crate::types::BaseGist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/gists/starred?{}", query_);
@@ -198,6 +341,24 @@ impl Gists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_starred_with` and pass the query parameters as a `GistsListStarredParams` instead.
+     */
+    #[deprecated(note = "use `list_starred_with` instead")]
+    pub async fn list_starred(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        let params = GistsListStarredParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+        };
+        self.list_starred_with(&params).await
+    }
+
     /**
      * List starred gists.
      *
@@ -208,19 +369,80 @@ impl Gists {
      * List the authenticated user's starred gists:
      *
      * FROM: <https://docs.github.com/rest/reference/gists#list-starred-gists>
+     *
+     * **Parameters:**
+     *
+     * * `params: &GistsListStarredParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_starred_with(
+        &self,
+        params: &GistsListStarredParams,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/gists/starred?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_starred_with` and pass the query parameters as a `GistsListStarredParams` instead.
      */
+    #[deprecated(note = "use `list_all_starred_with` instead")]
     pub async fn list_all_starred(
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::BaseGist>> {
+        let params = GistsListStarredParams {
+            since,
+            ..Default::default()
+        };
+        self.list_all_starred_with(&params).await
+    }
+
+    /**
+     * List starred gists.
+     *
+     * This function performs a `GET` to the `/gists/starred` endpoint.
+     *
+     * As opposed to `list_all_starred`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List the authenticated user's starred gists:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-starred-gists>
+     */
+    pub fn list_starred_stream(
+        &self,
+        params: &GistsListStarredParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BaseGist> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/gists/starred?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -298,20 +520,18 @@ impl Gists {
      * **Parameters:**
      *
      * * `gist_id: &str` -- gist_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListCommentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_comments(
+    pub async fn list_comments_with(
         &self,
         gist_id: &str,
-        per_page: i64,
-        page: i64,
+        params: &GistsListCommentsParams,
     ) -> Result<Vec<crate::types::GistComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -324,6 +544,23 @@ impl Gists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_comments_with` and pass the query parameters as a `GistsListCommentsParams` instead.
+     */
+    #[deprecated(note = "use `list_comments_with` instead")]
+    pub async fn list_comments(
+        &self,
+        gist_id: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::GistComment>> {
+        let params = GistsListCommentsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_comments_with(gist_id, &params).await
+    }
+
     /**
      * List gist comments.
      *
@@ -344,6 +581,46 @@ impl Gists {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List gist comments.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-comments>
+     */
+    pub fn list_comments_stream(
+        &self,
+        gist_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GistComment> {
+        let url = format!(
+            "/gists/{}/comments",
+            crate::progenitor_support::encode_path(gist_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a gist comment.
      *
@@ -360,7 +637,7 @@ impl Gists {
     pub async fn create_comment(
         &self,
         gist_id: &str,
-        body: &crate::types::PullsUpdateReviewRequest,
+        body: &crate::types::GistsCreateCommentRequest,
     ) -> Result<crate::types::GistComment> {
         let url = format!(
             "/gists/{}/comments",
@@ -442,7 +719,7 @@ impl Gists {
         &self,
         gist_id: &str,
         comment_id: i64,
-        body: &crate::types::PullsUpdateReviewRequest,
+        body: &crate::types::GistsUpdateCommentRequest,
     ) -> Result<crate::types::GistComment> {
         let url = format!(
             "/gists/{}/comments/{}",
@@ -467,20 +744,18 @@ impl Gists {
      * **Parameters:**
      *
      * * `gist_id: &str` -- gist_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListCommitsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_commits(
+    pub async fn list_commits_with(
         &self,
         gist_id: &str,
-        per_page: i64,
-        page: i64,
+        params: &GistsListCommitsParams,
     ) -> Result<Vec<crate::types::GistCommit>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -493,6 +768,23 @@ impl Gists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_commits_with` and pass the query parameters as a `GistsListCommitsParams` instead.
+     */
+    #[deprecated(note = "use `list_commits_with` instead")]
+    pub async fn list_commits(
+        &self,
+        gist_id: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::GistCommit>> {
+        let params = GistsListCommitsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_commits_with(gist_id, &params).await
+    }
+
     /**
      * List gist commits.
      *
@@ -513,6 +805,46 @@ impl Gists {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List gist commits.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-commits>
+     */
+    pub fn list_commits_stream(
+        &self,
+        gist_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GistCommit> {
+        let url = format!(
+            "/gists/{}/commits",
+            crate::progenitor_support::encode_path(gist_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List gist forks.
      *
@@ -525,20 +857,18 @@ impl Gists {
      * **Parameters:**
      *
      * * `gist_id: &str` -- gist_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListForksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_forks(
+    pub async fn list_forks_with(
         &self,
         gist_id: &str,
-        per_page: i64,
-        page: i64,
+        params: &GistsListForksParams,
     ) -> Result<Vec<crate::types::GistSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -551,6 +881,23 @@ impl Gists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_forks_with` and pass the query parameters as a `GistsListForksParams` instead.
+     */
+    #[deprecated(note = "use `list_forks_with` instead")]
+    pub async fn list_forks(
+        &self,
+        gist_id: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::GistSimple>> {
+        let params = GistsListForksParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_forks_with(gist_id, &params).await
+    }
+
     /**
      * List gist forks.
      *
@@ -571,6 +918,46 @@ impl Gists {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List gist forks.
+     *
+     * This function performs a `GET` to the `/gists/{gist_id}/forks` endpoint.
+     *
+     * As opposed to `list_all_forks`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gist-forks>
+     */
+    pub fn list_forks_stream(
+        &self,
+        gist_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GistSimple> {
+        let url = format!(
+            "/gists/{}/forks",
+            crate::progenitor_support::encode_path(gist_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Fork a gist.
      *
@@ -695,26 +1082,22 @@ impl Gists {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GistsListForUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_user(
+    pub async fn list_for_user_with(
         &self,
         username: &str,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &GistsListForUserParams,
     ) -> Result<Vec<crate::types::BaseGist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
@@ -726,6 +1109,25 @@ impl Gists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_user_with` and pass the query parameters as a `GistsListForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_for_user_with` instead")]
+    pub async fn list_for_user(
+        &self,
+        username: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        let params = GistsListForUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+        };
+        self.list_for_user_with(username, &params).await
+    }
+
     /**
      * List gists for a user.
      *
@@ -736,15 +1138,19 @@ impl Gists {
      * Lists public gists for the specified user:
      *
      * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-a-user>
+     *
+     * **Parameters:**
+     *
+     * * `params: &GistsListForUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_for_user(
+    pub async fn list_all_for_user_with(
         &self,
         username: &str,
-        since: Option<chrono::DateTime<chrono::Utc>>,
+        params: &GistsListForUserParams,
     ) -> Result<Vec<crate::types::BaseGist>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
@@ -755,4 +1161,665 @@ impl Gists {
 
         self.client.get_all_pages(&url, None).await
     }
+
+    /**
+     * Deprecated: use `list_all_for_user_with` and pass the query parameters as a `GistsListForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_all_for_user_with` instead")]
+    pub async fn list_all_for_user(
+        &self,
+        username: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        let params = GistsListForUserParams {
+            since,
+            ..Default::default()
+        };
+        self.list_all_for_user_with(username, &params).await
+    }
+
+    /**
+     * List gists for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gists` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists public gists for the specified user:
+     *
+     * FROM: <https://docs.github.com/rest/reference/gists#list-gists-for-a-user>
+     */
+    pub fn list_for_user_stream(
+        &self,
+        username: &str,
+        params: &GistsListForUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BaseGist> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/users/{}/gists?{}",
+            crate::progenitor_support::encode_path(username),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Gists::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GistsListParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl GistsListParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+}
+
+/// The query parameters of `Gists::list_public_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GistsListPublicParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl GistsListPublicParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+}
+
+/// The query parameters of `Gists::list_starred_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GistsListStarredParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl GistsListStarredParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+}
+
+/// The query parameters of `Gists::list_comments_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GistsListCommentsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl GistsListCommentsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Gists::list_commits_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GistsListCommitsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl GistsListCommitsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Gists::list_forks_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GistsListForksParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl GistsListForksParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Gists::list_for_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GistsListForUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl GistsListForUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+}
+
+/// The functions of [`Gists`], for code that takes them as a trait, so that it can be
+/// tested with the `MockGistsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait GistsApi: Send + Sync {
+    /// See [`Gists::list_with`].
+    async fn list_with(&self, params: &GistsListParams) -> Result<Vec<crate::types::BaseGist>>;
+
+    /// See [`Gists::list_all_with`].
+    async fn list_all_with(&self, params: &GistsListParams) -> Result<Vec<crate::types::BaseGist>>;
+
+    /// See [`Gists::list_stream`].
+    fn list_stream(
+        &self,
+        params: &GistsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BaseGist>;
+
+    /// See [`Gists::create`].
+    async fn create(
+        &self,
+        body: &crate::types::GistsCreateRequest,
+    ) -> Result<crate::types::GistSimple>;
+
+    /// See [`Gists::list_public_with`].
+    async fn list_public_with(
+        &self,
+        params: &GistsListPublicParams,
+    ) -> Result<Vec<crate::types::BaseGist>>;
+
+    /// See [`Gists::list_all_public_with`].
+    async fn list_all_public_with(
+        &self,
+        params: &GistsListPublicParams,
+    ) -> Result<Vec<crate::types::BaseGist>>;
+
+    /// See [`Gists::list_public_stream`].
+    fn list_public_stream(
+        &self,
+        params: &GistsListPublicParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BaseGist>;
+
+    /// See [`Gists::list_starred_with`].
+    async fn list_starred_with(
+        &self,
+        params: &GistsListStarredParams,
+    ) -> Result<Vec<crate::types::BaseGist>>;
+
+    /// See [`Gists::list_all_starred_with`].
+    async fn list_all_starred_with(
+        &self,
+        params: &GistsListStarredParams,
+    ) -> Result<Vec<crate::types::BaseGist>>;
+
+    /// See [`Gists::list_starred_stream`].
+    fn list_starred_stream(
+        &self,
+        params: &GistsListStarredParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BaseGist>;
+
+    /// See [`Gists::get`].
+    async fn get(&self, gist_id: &str) -> Result<crate::types::GistSimple>;
+
+    /// See [`Gists::delete`].
+    async fn delete(&self, gist_id: &str) -> Result<()>;
+
+    /// See [`Gists::update`].
+    async fn update(
+        &self,
+        gist_id: &str,
+        body: &crate::types::GistsUpdateRequest,
+    ) -> Result<crate::types::GistSimple>;
+
+    /// See [`Gists::list_comments_with`].
+    async fn list_comments_with(
+        &self,
+        gist_id: &str,
+        params: &GistsListCommentsParams,
+    ) -> Result<Vec<crate::types::GistComment>>;
+
+    /// See [`Gists::list_all_comments`].
+    async fn list_all_comments(&self, gist_id: &str) -> Result<Vec<crate::types::GistComment>>;
+
+    /// See [`Gists::list_comments_stream`].
+    fn list_comments_stream(
+        &self,
+        gist_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GistComment>;
+
+    /// See [`Gists::create_comment`].
+    async fn create_comment(
+        &self,
+        gist_id: &str,
+        body: &crate::types::GistsCreateCommentRequest,
+    ) -> Result<crate::types::GistComment>;
+
+    /// See [`Gists::get_comment`].
+    async fn get_comment(
+        &self,
+        gist_id: &str,
+        comment_id: i64,
+    ) -> Result<crate::types::GistComment>;
+
+    /// See [`Gists::delete_comment`].
+    async fn delete_comment(&self, gist_id: &str, comment_id: i64) -> Result<()>;
+
+    /// See [`Gists::update_comment`].
+    async fn update_comment(
+        &self,
+        gist_id: &str,
+        comment_id: i64,
+        body: &crate::types::GistsUpdateCommentRequest,
+    ) -> Result<crate::types::GistComment>;
+
+    /// See [`Gists::list_commits_with`].
+    async fn list_commits_with(
+        &self,
+        gist_id: &str,
+        params: &GistsListCommitsParams,
+    ) -> Result<Vec<crate::types::GistCommit>>;
+
+    /// See [`Gists::list_all_commits`].
+    async fn list_all_commits(&self, gist_id: &str) -> Result<Vec<crate::types::GistCommit>>;
+
+    /// See [`Gists::list_commits_stream`].
+    fn list_commits_stream(
+        &self,
+        gist_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GistCommit>;
+
+    /// See [`Gists::list_forks_with`].
+    async fn list_forks_with(
+        &self,
+        gist_id: &str,
+        params: &GistsListForksParams,
+    ) -> Result<Vec<crate::types::GistSimple>>;
+
+    /// See [`Gists::list_all_forks`].
+    async fn list_all_forks(&self, gist_id: &str) -> Result<Vec<crate::types::GistSimple>>;
+
+    /// See [`Gists::list_forks_stream`].
+    fn list_forks_stream(
+        &self,
+        gist_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GistSimple>;
+
+    /// See [`Gists::fork`].
+    async fn fork(&self, gist_id: &str) -> Result<crate::types::BaseGist>;
+
+    /// See [`Gists::check_is_starred`].
+    async fn check_is_starred(&self, gist_id: &str) -> Result<()>;
+
+    /// See [`Gists::star`].
+    async fn star(&self, gist_id: &str) -> Result<()>;
+
+    /// See [`Gists::unstar`].
+    async fn unstar(&self, gist_id: &str) -> Result<()>;
+
+    /// See [`Gists::get_revision`].
+    async fn get_revision(&self, gist_id: &str, sha: &str) -> Result<crate::types::GistSimple>;
+
+    /// See [`Gists::list_for_user_with`].
+    async fn list_for_user_with(
+        &self,
+        username: &str,
+        params: &GistsListForUserParams,
+    ) -> Result<Vec<crate::types::BaseGist>>;
+
+    /// See [`Gists::list_all_for_user_with`].
+    async fn list_all_for_user_with(
+        &self,
+        username: &str,
+        params: &GistsListForUserParams,
+    ) -> Result<Vec<crate::types::BaseGist>>;
+
+    /// See [`Gists::list_for_user_stream`].
+    fn list_for_user_stream(
+        &self,
+        username: &str,
+        params: &GistsListForUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BaseGist>;
+}
+
+#[async_trait::async_trait]
+impl GistsApi for Gists {
+    async fn list_with(&self, params: &GistsListParams) -> Result<Vec<crate::types::BaseGist>> {
+        Gists::list_with(self, params).await
+    }
+
+    async fn list_all_with(&self, params: &GistsListParams) -> Result<Vec<crate::types::BaseGist>> {
+        Gists::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &GistsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BaseGist> {
+        Gists::list_stream(self, params, start, max_items)
+    }
+
+    async fn create(
+        &self,
+        body: &crate::types::GistsCreateRequest,
+    ) -> Result<crate::types::GistSimple> {
+        Gists::create(self, body).await
+    }
+
+    async fn list_public_with(
+        &self,
+        params: &GistsListPublicParams,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        Gists::list_public_with(self, params).await
+    }
+
+    async fn list_all_public_with(
+        &self,
+        params: &GistsListPublicParams,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        Gists::list_all_public_with(self, params).await
+    }
+
+    fn list_public_stream(
+        &self,
+        params: &GistsListPublicParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BaseGist> {
+        Gists::list_public_stream(self, params, start, max_items)
+    }
+
+    async fn list_starred_with(
+        &self,
+        params: &GistsListStarredParams,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        Gists::list_starred_with(self, params).await
+    }
+
+    async fn list_all_starred_with(
+        &self,
+        params: &GistsListStarredParams,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        Gists::list_all_starred_with(self, params).await
+    }
+
+    fn list_starred_stream(
+        &self,
+        params: &GistsListStarredParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BaseGist> {
+        Gists::list_starred_stream(self, params, start, max_items)
+    }
+
+    async fn get(&self, gist_id: &str) -> Result<crate::types::GistSimple> {
+        Gists::get(self, gist_id).await
+    }
+
+    async fn delete(&self, gist_id: &str) -> Result<()> {
+        Gists::delete(self, gist_id).await
+    }
+
+    async fn update(
+        &self,
+        gist_id: &str,
+        body: &crate::types::GistsUpdateRequest,
+    ) -> Result<crate::types::GistSimple> {
+        Gists::update(self, gist_id, body).await
+    }
+
+    async fn list_comments_with(
+        &self,
+        gist_id: &str,
+        params: &GistsListCommentsParams,
+    ) -> Result<Vec<crate::types::GistComment>> {
+        Gists::list_comments_with(self, gist_id, params).await
+    }
+
+    async fn list_all_comments(&self, gist_id: &str) -> Result<Vec<crate::types::GistComment>> {
+        Gists::list_all_comments(self, gist_id).await
+    }
+
+    fn list_comments_stream(
+        &self,
+        gist_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GistComment> {
+        Gists::list_comments_stream(self, gist_id, start, max_items)
+    }
+
+    async fn create_comment(
+        &self,
+        gist_id: &str,
+        body: &crate::types::GistsCreateCommentRequest,
+    ) -> Result<crate::types::GistComment> {
+        Gists::create_comment(self, gist_id, body).await
+    }
+
+    async fn get_comment(
+        &self,
+        gist_id: &str,
+        comment_id: i64,
+    ) -> Result<crate::types::GistComment> {
+        Gists::get_comment(self, gist_id, comment_id).await
+    }
+
+    async fn delete_comment(&self, gist_id: &str, comment_id: i64) -> Result<()> {
+        Gists::delete_comment(self, gist_id, comment_id).await
+    }
+
+    async fn update_comment(
+        &self,
+        gist_id: &str,
+        comment_id: i64,
+        body: &crate::types::GistsUpdateCommentRequest,
+    ) -> Result<crate::types::GistComment> {
+        Gists::update_comment(self, gist_id, comment_id, body).await
+    }
+
+    async fn list_commits_with(
+        &self,
+        gist_id: &str,
+        params: &GistsListCommitsParams,
+    ) -> Result<Vec<crate::types::GistCommit>> {
+        Gists::list_commits_with(self, gist_id, params).await
+    }
+
+    async fn list_all_commits(&self, gist_id: &str) -> Result<Vec<crate::types::GistCommit>> {
+        Gists::list_all_commits(self, gist_id).await
+    }
+
+    fn list_commits_stream(
+        &self,
+        gist_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GistCommit> {
+        Gists::list_commits_stream(self, gist_id, start, max_items)
+    }
+
+    async fn list_forks_with(
+        &self,
+        gist_id: &str,
+        params: &GistsListForksParams,
+    ) -> Result<Vec<crate::types::GistSimple>> {
+        Gists::list_forks_with(self, gist_id, params).await
+    }
+
+    async fn list_all_forks(&self, gist_id: &str) -> Result<Vec<crate::types::GistSimple>> {
+        Gists::list_all_forks(self, gist_id).await
+    }
+
+    fn list_forks_stream(
+        &self,
+        gist_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GistSimple> {
+        Gists::list_forks_stream(self, gist_id, start, max_items)
+    }
+
+    async fn fork(&self, gist_id: &str) -> Result<crate::types::BaseGist> {
+        Gists::fork(self, gist_id).await
+    }
+
+    async fn check_is_starred(&self, gist_id: &str) -> Result<()> {
+        Gists::check_is_starred(self, gist_id).await
+    }
+
+    async fn star(&self, gist_id: &str) -> Result<()> {
+        Gists::star(self, gist_id).await
+    }
+
+    async fn unstar(&self, gist_id: &str) -> Result<()> {
+        Gists::unstar(self, gist_id).await
+    }
+
+    async fn get_revision(&self, gist_id: &str, sha: &str) -> Result<crate::types::GistSimple> {
+        Gists::get_revision(self, gist_id, sha).await
+    }
+
+    async fn list_for_user_with(
+        &self,
+        username: &str,
+        params: &GistsListForUserParams,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        Gists::list_for_user_with(self, username, params).await
+    }
+
+    async fn list_all_for_user_with(
+        &self,
+        username: &str,
+        params: &GistsListForUserParams,
+    ) -> Result<Vec<crate::types::BaseGist>> {
+        Gists::list_all_for_user_with(self, username, params).await
+    }
+
+    fn list_for_user_stream(
+        &self,
+        username: &str,
+        params: &GistsListForUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BaseGist> {
+        Gists::list_for_user_stream(self, username, params, start, max_items)
+    }
 }
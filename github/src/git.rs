@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Git {
     pub client: Client,
@@ -31,7 +29,7 @@ impl Git {
         owner: &str,
         repo: &str,
         body: &crate::types::GitCreateBlobRequest,
-    ) -> Result<crate::types::Tree> {
+    ) -> Result<crate::types::ShortBlob> {
         let url = format!(
             "/repos/{}/{}/git/blobs",
             crate::progenitor_support::encode_path(owner),
@@ -216,22 +214,20 @@ impl Git {
      * * `owner: &str`
      * * `repo: &str`
      * * `ref_: &str` -- ref parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &GitListMatchingRefsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_matching_refs(
+    pub async fn list_matching_refs_with(
         &self,
         owner: &str,
         repo: &str,
         ref_: &str,
-        per_page: i64,
-        page: i64,
+        params: &GitListMatchingRefsParams,
     ) -> Result<Vec<crate::types::GitRef>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -246,6 +242,26 @@ impl Git {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_matching_refs_with` and pass the query parameters as a `GitListMatchingRefsParams` instead.
+     */
+    #[deprecated(note = "use `list_matching_refs_with` instead")]
+    pub async fn list_matching_refs(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::GitRef>> {
+        let params = GitListMatchingRefsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_matching_refs_with(owner, repo, ref_, &params)
+            .await
+    }
+
     /**
      * List matching references.
      *
@@ -279,6 +295,56 @@ impl Git {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List matching references.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/git/matching-refs/{ref}` endpoint.
+     *
+     * As opposed to `list_all_matching_refs`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns an array of references from your Git database that match the supplied name. The `:ref` in the URL must be formatted as `heads/<branch name>` for branches and `tags/<tag name>` for tags. If the `:ref` doesn't exist in the repository, but existing refs start with `:ref`, they will be returned as an array.
+     *
+     * When you use this endpoint without providing a `:ref`, it will return an array of all the references from your Git database, including notes and stashes if they exist on the server. Anything in the namespace is returned, not just `heads` and `tags`.
+     *
+     * **Note:** You need to explicitly [request a pull request](https://docs.github.com/rest/reference/pulls#get-a-pull-request) to trigger a test merge commit, which checks the mergeability of pull requests. For more information, see "[Checking mergeability of pull requests](https://docs.github.com/rest/guides/getting-started-with-the-git-database-api#checking-mergeability-of-pull-requests)".
+     *
+     * If you request matching references for a branch named `feature` but the branch `feature` doesn't exist, the response can still include other matching head refs that start with the word `feature`, such as `featureA` and `featureB`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/git#list-matching-references>
+     */
+    pub fn list_matching_refs_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GitRef> {
+        let url = format!(
+            "/repos/{}/{}/git/matching-refs/{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(ref_),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get a reference.
      *
@@ -570,17 +636,17 @@ impl Git {
      * * `owner: &str`
      * * `repo: &str`
      * * `tree_sha: &str`
-     * * `recursive: &str` -- Setting this parameter to any value returns the objects or subtrees referenced by the tree specified in `:tree_sha`. For example, setting `recursive` to any of the following will enable returning objects or subtrees: `0`, `1`, `"true"`, and `"false"`. Omit this parameter to prevent recursively returning objects or subtrees.
+     * * `params: &GitGetTreeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_tree(
+    pub async fn get_tree_with(
         &self,
         owner: &str,
         repo: &str,
         tree_sha: &str,
-        recursive: &str,
+        params: &GitGetTreeParams,
     ) -> Result<crate::types::GitTreeData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !recursive.is_empty() {
+        if let Some(recursive) = &params.recursive {
             query_args.push(("recursive".to_string(), recursive.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -594,4 +660,302 @@ impl Git {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_tree_with` and pass the query parameters as a `GitGetTreeParams` instead.
+     */
+    #[deprecated(note = "use `get_tree_with` instead")]
+    pub async fn get_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        tree_sha: &str,
+        recursive: &str,
+    ) -> Result<crate::types::GitTreeData> {
+        let params = GitGetTreeParams {
+            recursive: Some(recursive.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_tree_with(owner, repo, tree_sha, &params).await
+    }
+}
+
+/// The query parameters of `Git::list_matching_refs_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GitListMatchingRefsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl GitListMatchingRefsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Git::get_tree_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GitGetTreeParams {
+    /// Setting this parameter to any value returns the objects or subtrees referenced by the tree specified in `:tree_sha`. For example, setting `recursive` to any of the following will enable returning objects or subtrees: `0`, `1`, `"true"`, and `"false"`. Omit this parameter to prevent recursively returning objects or subtrees.
+    pub recursive: Option<String>,
+}
+
+impl GitGetTreeParams {
+    pub fn recursive<T: ToString>(mut self, recursive: T) -> Self {
+        self.recursive = Some(recursive.to_string());
+        self
+    }
+}
+
+/// The functions of [`Git`], for code that takes them as a trait, so that it can be
+/// tested with the `MockGitApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait GitApi: Send + Sync {
+    /// See [`Git::create_blob`].
+    async fn create_blob(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateBlobRequest,
+    ) -> Result<crate::types::ShortBlob>;
+
+    /// See [`Git::get_blob`].
+    async fn get_blob(&self, owner: &str, repo: &str, file_sha: &str)
+        -> Result<crate::types::Blob>;
+
+    /// See [`Git::create_commit`].
+    async fn create_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateCommitRequest,
+    ) -> Result<crate::types::GitCommit>;
+
+    /// See [`Git::get_commit`].
+    async fn get_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> Result<crate::types::GitCommit>;
+
+    /// See [`Git::list_matching_refs_with`].
+    async fn list_matching_refs_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        params: &GitListMatchingRefsParams,
+    ) -> Result<Vec<crate::types::GitRef>>;
+
+    /// See [`Git::list_all_matching_refs`].
+    async fn list_all_matching_refs(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<Vec<crate::types::GitRef>>;
+
+    /// See [`Git::list_matching_refs_stream`].
+    fn list_matching_refs_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GitRef>;
+
+    /// See [`Git::get_ref`].
+    async fn get_ref(&self, owner: &str, repo: &str, ref_: &str) -> Result<crate::types::GitRef>;
+
+    /// See [`Git::create_ref`].
+    async fn create_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateRefRequest,
+    ) -> Result<crate::types::GitRef>;
+
+    /// See [`Git::delete_ref`].
+    async fn delete_ref(&self, owner: &str, repo: &str, ref_: &str) -> Result<()>;
+
+    /// See [`Git::update_ref`].
+    async fn update_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        body: &crate::types::GitUpdateRefRequest,
+    ) -> Result<crate::types::GitRef>;
+
+    /// See [`Git::create_tag`].
+    async fn create_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateTagRequest,
+    ) -> Result<crate::types::GitTag>;
+
+    /// See [`Git::get_tag`].
+    async fn get_tag(&self, owner: &str, repo: &str, tag_sha: &str)
+        -> Result<crate::types::GitTag>;
+
+    /// See [`Git::create_tree`].
+    async fn create_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateTreeRequestData,
+    ) -> Result<crate::types::GitTreeData>;
+
+    /// See [`Git::get_tree_with`].
+    async fn get_tree_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        tree_sha: &str,
+        params: &GitGetTreeParams,
+    ) -> Result<crate::types::GitTreeData>;
+}
+
+#[async_trait::async_trait]
+impl GitApi for Git {
+    async fn create_blob(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateBlobRequest,
+    ) -> Result<crate::types::ShortBlob> {
+        Git::create_blob(self, owner, repo, body).await
+    }
+
+    async fn get_blob(
+        &self,
+        owner: &str,
+        repo: &str,
+        file_sha: &str,
+    ) -> Result<crate::types::Blob> {
+        Git::get_blob(self, owner, repo, file_sha).await
+    }
+
+    async fn create_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateCommitRequest,
+    ) -> Result<crate::types::GitCommit> {
+        Git::create_commit(self, owner, repo, body).await
+    }
+
+    async fn get_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> Result<crate::types::GitCommit> {
+        Git::get_commit(self, owner, repo, commit_sha).await
+    }
+
+    async fn list_matching_refs_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        params: &GitListMatchingRefsParams,
+    ) -> Result<Vec<crate::types::GitRef>> {
+        Git::list_matching_refs_with(self, owner, repo, ref_, params).await
+    }
+
+    async fn list_all_matching_refs(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<Vec<crate::types::GitRef>> {
+        Git::list_all_matching_refs(self, owner, repo, ref_).await
+    }
+
+    fn list_matching_refs_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GitRef> {
+        Git::list_matching_refs_stream(self, owner, repo, ref_, start, max_items)
+    }
+
+    async fn get_ref(&self, owner: &str, repo: &str, ref_: &str) -> Result<crate::types::GitRef> {
+        Git::get_ref(self, owner, repo, ref_).await
+    }
+
+    async fn create_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateRefRequest,
+    ) -> Result<crate::types::GitRef> {
+        Git::create_ref(self, owner, repo, body).await
+    }
+
+    async fn delete_ref(&self, owner: &str, repo: &str, ref_: &str) -> Result<()> {
+        Git::delete_ref(self, owner, repo, ref_).await
+    }
+
+    async fn update_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        body: &crate::types::GitUpdateRefRequest,
+    ) -> Result<crate::types::GitRef> {
+        Git::update_ref(self, owner, repo, ref_, body).await
+    }
+
+    async fn create_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateTagRequest,
+    ) -> Result<crate::types::GitTag> {
+        Git::create_tag(self, owner, repo, body).await
+    }
+
+    async fn get_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag_sha: &str,
+    ) -> Result<crate::types::GitTag> {
+        Git::get_tag(self, owner, repo, tag_sha).await
+    }
+
+    async fn create_tree(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::GitCreateTreeRequestData,
+    ) -> Result<crate::types::GitTreeData> {
+        Git::create_tree(self, owner, repo, body).await
+    }
+
+    async fn get_tree_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        tree_sha: &str,
+        params: &GitGetTreeParams,
+    ) -> Result<crate::types::GitTreeData> {
+        Git::get_tree_with(self, owner, repo, tree_sha, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Gitignore {
     pub client: Client,
@@ -42,6 +40,41 @@ impl Gitignore {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get all gitignore templates.
+     *
+     * This function performs a `GET` to the `/gitignore/templates` endpoint.
+     *
+     * As opposed to `get_all_all_templates`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List all templates available to pass as an option when [creating a repository](https://docs.github.com/rest/reference/repos#create-a-repository-for-the-authenticated-user).
+     *
+     * FROM: <https://docs.github.com/rest/reference/gitignore#get-all-gitignore-templates>
+     */
+    pub fn get_all_templates_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<String> {
+        let url = "/gitignore/templates".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get a gitignore template.
      *
@@ -65,3 +98,48 @@ impl Gitignore {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`Gitignore`], for code that takes them as a trait, so that it can be
+/// tested with the `MockGitignoreApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait GitignoreApi: Send + Sync {
+    /// See [`Gitignore::get_all_templates`].
+    async fn get_all_templates(&self) -> Result<Vec<String>>;
+
+    /// See [`Gitignore::get_all_all_templates`].
+    async fn get_all_all_templates(&self) -> Result<Vec<String>>;
+
+    /// See [`Gitignore::get_all_templates_stream`].
+    fn get_all_templates_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<String>;
+
+    /// See [`Gitignore::get_template`].
+    async fn get_template(&self, name: &str) -> Result<crate::types::GitignoreTemplate>;
+}
+
+#[async_trait::async_trait]
+impl GitignoreApi for Gitignore {
+    async fn get_all_templates(&self) -> Result<Vec<String>> {
+        Gitignore::get_all_templates(self).await
+    }
+
+    async fn get_all_all_templates(&self) -> Result<Vec<String>> {
+        Gitignore::get_all_all_templates(self).await
+    }
+
+    fn get_all_templates_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<String> {
+        Gitignore::get_all_templates_stream(self, start, max_items)
+    }
+
+    async fn get_template(&self, name: &str) -> Result<crate::types::GitignoreTemplate> {
+        Gitignore::get_template(self, name).await
+    }
+}
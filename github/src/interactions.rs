@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Interactions {
     pub client: Client,
@@ -28,7 +26,7 @@ impl Interactions {
     pub async fn get_restrictions_for_org(
         &self,
         org: &str,
-    ) -> Result<crate::types::InteractionsGetRestrictionsResponseAnyOf> {
+    ) -> Result<crate::types::InteractionsGetRestrictionsOrgResponseAnyOf> {
         let url = format!(
             "/orgs/{}/interaction-limits",
             crate::progenitor_support::encode_path(org),
@@ -54,7 +52,7 @@ impl Interactions {
         &self,
         org: &str,
         body: &crate::types::InteractionLimit,
-    ) -> Result<crate::types::InteractionLimits> {
+    ) -> Result<crate::types::InteractionLimitResponse> {
         let url = format!(
             "/orgs/{}/interaction-limits",
             crate::progenitor_support::encode_path(org),
@@ -105,7 +103,7 @@ impl Interactions {
         &self,
         owner: &str,
         repo: &str,
-    ) -> Result<crate::types::InteractionsGetRestrictionsResponseAnyOf> {
+    ) -> Result<crate::types::InteractionsGetRestrictionsRepoResponseAnyOf> {
         let url = format!(
             "/repos/{}/{}/interaction-limits",
             crate::progenitor_support::encode_path(owner),
@@ -134,7 +132,7 @@ impl Interactions {
         owner: &str,
         repo: &str,
         body: &crate::types::InteractionLimit,
-    ) -> Result<crate::types::InteractionLimits> {
+    ) -> Result<crate::types::InteractionLimitResponse> {
         let url = format!(
             "/repos/{}/{}/interaction-limits",
             crate::progenitor_support::encode_path(owner),
@@ -198,7 +196,7 @@ impl Interactions {
     pub async fn set_restrictions_for_authenticated_user(
         &self,
         body: &crate::types::InteractionLimit,
-    ) -> Result<crate::types::InteractionLimits> {
+    ) -> Result<crate::types::InteractionLimitResponse> {
         let url = "/user/interaction-limits".to_string();
         self.client
             .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -219,3 +217,117 @@ impl Interactions {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`Interactions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockInteractionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait InteractionsApi: Send + Sync {
+    /// See [`Interactions::get_restrictions_for_org`].
+    async fn get_restrictions_for_org(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::InteractionsGetRestrictionsOrgResponseAnyOf>;
+
+    /// See [`Interactions::set_restrictions_for_org`].
+    async fn set_restrictions_for_org(
+        &self,
+        org: &str,
+        body: &crate::types::InteractionLimit,
+    ) -> Result<crate::types::InteractionLimitResponse>;
+
+    /// See [`Interactions::remove_restrictions_for_org`].
+    async fn remove_restrictions_for_org(&self, org: &str) -> Result<()>;
+
+    /// See [`Interactions::get_restrictions_for_repo`].
+    async fn get_restrictions_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::InteractionsGetRestrictionsRepoResponseAnyOf>;
+
+    /// See [`Interactions::set_restrictions_for_repo`].
+    async fn set_restrictions_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::InteractionLimit,
+    ) -> Result<crate::types::InteractionLimitResponse>;
+
+    /// See [`Interactions::remove_restrictions_for_repo`].
+    async fn remove_restrictions_for_repo(&self, owner: &str, repo: &str) -> Result<()>;
+
+    /// See [`Interactions::get_restrictions_for_authenticated_user`].
+    async fn get_restrictions_for_authenticated_user(
+        &self,
+    ) -> Result<crate::types::InteractionsGetRestrictionsResponseAnyOf>;
+
+    /// See [`Interactions::set_restrictions_for_authenticated_user`].
+    async fn set_restrictions_for_authenticated_user(
+        &self,
+        body: &crate::types::InteractionLimit,
+    ) -> Result<crate::types::InteractionLimitResponse>;
+
+    /// See [`Interactions::remove_restrictions_for_authenticated_user`].
+    async fn remove_restrictions_for_authenticated_user(&self) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl InteractionsApi for Interactions {
+    async fn get_restrictions_for_org(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::InteractionsGetRestrictionsOrgResponseAnyOf> {
+        Interactions::get_restrictions_for_org(self, org).await
+    }
+
+    async fn set_restrictions_for_org(
+        &self,
+        org: &str,
+        body: &crate::types::InteractionLimit,
+    ) -> Result<crate::types::InteractionLimitResponse> {
+        Interactions::set_restrictions_for_org(self, org, body).await
+    }
+
+    async fn remove_restrictions_for_org(&self, org: &str) -> Result<()> {
+        Interactions::remove_restrictions_for_org(self, org).await
+    }
+
+    async fn get_restrictions_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::InteractionsGetRestrictionsRepoResponseAnyOf> {
+        Interactions::get_restrictions_for_repo(self, owner, repo).await
+    }
+
+    async fn set_restrictions_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::InteractionLimit,
+    ) -> Result<crate::types::InteractionLimitResponse> {
+        Interactions::set_restrictions_for_repo(self, owner, repo, body).await
+    }
+
+    async fn remove_restrictions_for_repo(&self, owner: &str, repo: &str) -> Result<()> {
+        Interactions::remove_restrictions_for_repo(self, owner, repo).await
+    }
+
+    async fn get_restrictions_for_authenticated_user(
+        &self,
+    ) -> Result<crate::types::InteractionsGetRestrictionsResponseAnyOf> {
+        Interactions::get_restrictions_for_authenticated_user(self).await
+    }
+
+    async fn set_restrictions_for_authenticated_user(
+        &self,
+        body: &crate::types::InteractionLimit,
+    ) -> Result<crate::types::InteractionLimitResponse> {
+        Interactions::set_restrictions_for_authenticated_user(self, body).await
+    }
+
+    async fn remove_restrictions_for_authenticated_user(&self) -> Result<()> {
+        Interactions::remove_restrictions_for_authenticated_user(self).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Issues {
     pub client: Client,
@@ -31,76 +29,44 @@ impl Issues {
      *
      * **Parameters:**
      *
-     * * `filter: crate::types::Filter` -- Indicates which sorts of issues to return. Can be one of:  
-     *  \\* `assigned`: Issues assigned to you  
-     *  \\* `created`: Issues created by you  
-     *  \\* `mentioned`: Issues mentioning you  
-     *  \\* `subscribed`: Issues you're subscribed to updates for  
-     *  \\* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation.
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `labels: &str` -- A list of comma separated label names. Example: `bug,ui,@high`.
-     * * `sort: crate::types::IssuesListSort` -- What to sort results by. Can be either `created`, `updated`, `comments`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `collab: bool`
-     * * `orgs: bool`
-     * * `owned: bool`
-     * * `pulls: bool`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
-        &self,
-        filter: crate::types::Filter,
-        state: crate::types::IssuesListState,
-        labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        collab: bool,
-        orgs: bool,
-        owned: bool,
-        pulls: bool,
-        per_page: i64,
-        page: i64,
-    ) -> Result<Vec<crate::types::Issue>> {
+    pub async fn list_with(&self, params: &IssuesListParams) -> Result<Vec<crate::types::Issue>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if collab {
+        if let Some(collab) = &params.collab {
             query_args.push(("collab".to_string(), collab.to_string()));
         }
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !filter.to_string().is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !labels.is_empty() {
+        if let Some(labels) = &params.labels {
             query_args.push(("labels".to_string(), labels.to_string()));
         }
-        if orgs {
+        if let Some(orgs) = &params.orgs {
             query_args.push(("orgs".to_string(), orgs.to_string()));
         }
-        if owned {
+        if let Some(owned) = &params.owned {
             query_args.push(("owned".to_string(), owned.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if pulls {
+        if let Some(pulls) = &params.pulls {
             query_args.push(("pulls".to_string(), pulls.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -109,6 +75,42 @@ impl Issues {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `IssuesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Direction,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        collab: bool,
+        orgs: bool,
+        owned: bool,
+        pulls: bool,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Issue>> {
+        let params = IssuesListParams {
+            collab: Some(collab).filter(|v| *v),
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            filter: Some(filter).filter(|v| !v.to_string().is_empty()),
+            labels: Some(labels.to_string()).filter(|s| !s.is_empty()),
+            orgs: Some(orgs).filter(|v| *v),
+            owned: Some(owned).filter(|v| *v),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            pulls: Some(pulls).filter(|v| *v),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * List issues assigned to the authenticated user.
      *
@@ -127,55 +129,160 @@ impl Issues {
      * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
      *
      * FROM: <https://docs.github.com/rest/reference/issues#list-issues-assigned-to-the-authenticated-user>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IssuesListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(
+        &self,
+        params: &IssuesListParams,
+    ) -> Result<Vec<crate::types::Issue>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(collab) = &params.collab {
+            query_args.push(("collab".to_string(), collab.to_string()));
+        }
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if let Some(labels) = &params.labels {
+            query_args.push(("labels".to_string(), labels.to_string()));
+        }
+        if let Some(orgs) = &params.orgs {
+            query_args.push(("orgs".to_string(), orgs.to_string()));
+        }
+        if let Some(owned) = &params.owned {
+            query_args.push(("owned".to_string(), owned.to_string()));
+        }
+        if let Some(pulls) = &params.pulls {
+            query_args.push(("pulls".to_string(), pulls.to_string()));
+        }
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/issues?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `IssuesListParams` instead.
      */
+    #[deprecated(note = "use `list_all_with` instead")]
     pub async fn list_all(
         &self,
         filter: crate::types::Filter,
         state: crate::types::IssuesListState,
         labels: &str,
         sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
+        direction: crate::types::Direction,
         since: Option<chrono::DateTime<chrono::Utc>>,
         collab: bool,
         orgs: bool,
         owned: bool,
         pulls: bool,
     ) -> Result<Vec<crate::types::Issue>> {
+        let params = IssuesListParams {
+            collab: Some(collab).filter(|v| *v),
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            filter: Some(filter).filter(|v| !v.to_string().is_empty()),
+            labels: Some(labels.to_string()).filter(|s| !s.is_empty()),
+            orgs: Some(orgs).filter(|v| *v),
+            owned: Some(owned).filter(|v| *v),
+            pulls: Some(pulls).filter(|v| *v),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * List issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/issues` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List issues assigned to the authenticated user across all visible repositories including owned repositories, member
+     * repositories, and organization repositories. You can use the `filter` query parameter to fetch issues that are not
+     * necessarily assigned to you.
+     *
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_stream(
+        &self,
+        params: &IssuesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Issue> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if collab {
+        if let Some(collab) = &params.collab {
             query_args.push(("collab".to_string(), collab.to_string()));
         }
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !filter.to_string().is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !labels.is_empty() {
+        if let Some(labels) = &params.labels {
             query_args.push(("labels".to_string(), labels.to_string()));
         }
-        if orgs {
+        if let Some(orgs) = &params.orgs {
             query_args.push(("orgs".to_string(), orgs.to_string()));
         }
-        if owned {
+        if let Some(owned) = &params.owned {
             query_args.push(("owned".to_string(), owned.to_string()));
         }
-        if pulls {
+        if let Some(pulls) = &params.pulls {
             query_args.push(("pulls".to_string(), pulls.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/issues?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -195,57 +302,36 @@ impl Issues {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `filter: crate::types::Filter` -- Indicates which sorts of issues to return. Can be one of:  
-     *  \\* `assigned`: Issues assigned to you  
-     *  \\* `created`: Issues created by you  
-     *  \\* `mentioned`: Issues mentioning you  
-     *  \\* `subscribed`: Issues you're subscribed to updates for  
-     *  \\* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation.
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `labels: &str` -- A list of comma separated label names. Example: `bug,ui,@high`.
-     * * `sort: crate::types::IssuesListSort` -- What to sort results by. Can be either `created`, `updated`, `comments`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListForOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_org(
+    pub async fn list_for_org_with(
         &self,
         org: &str,
-        filter: crate::types::Filter,
-        state: crate::types::IssuesListState,
-        labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListForOrgParams,
     ) -> Result<Vec<crate::types::Issue>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !filter.to_string().is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !labels.is_empty() {
+        if let Some(labels) = &params.labels {
             query_args.push(("labels".to_string(), labels.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -258,6 +344,35 @@ impl Issues {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_org_with` and pass the query parameters as a `IssuesListForOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_for_org_with` instead")]
+    pub async fn list_for_org(
+        &self,
+        org: &str,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListOrgState,
+        labels: &str,
+        sort: crate::types::IssuesListOrgSort,
+        direction: crate::types::Direction,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Issue>> {
+        let params = IssuesListForOrgParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            filter: Some(filter).filter(|v| !v.to_string().is_empty()),
+            labels: Some(labels.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_for_org_with(org, &params).await
+    }
+
     /**
      * List organization issues assigned to the authenticated user.
      *
@@ -273,34 +388,111 @@ impl Issues {
      * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
      *
      * FROM: <https://docs.github.com/rest/reference/issues#list-organization-issues-assigned-to-the-authenticated-user>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IssuesListForOrgParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_org_with(
+        &self,
+        org: &str,
+        params: &IssuesListForOrgParams,
+    ) -> Result<Vec<crate::types::Issue>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if let Some(labels) = &params.labels {
+            query_args.push(("labels".to_string(), labels.to_string()));
+        }
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/issues?{}",
+            crate::progenitor_support::encode_path(org),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_org_with` and pass the query parameters as a `IssuesListForOrgParams` instead.
      */
+    #[deprecated(note = "use `list_all_for_org_with` instead")]
     pub async fn list_all_for_org(
         &self,
         org: &str,
         filter: crate::types::Filter,
-        state: crate::types::IssuesListState,
+        state: crate::types::IssuesListOrgState,
         labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
+        sort: crate::types::IssuesListOrgSort,
+        direction: crate::types::Direction,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Issue>> {
+        let params = IssuesListForOrgParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            filter: Some(filter).filter(|v| !v.to_string().is_empty()),
+            labels: Some(labels.to_string()).filter(|s| !s.is_empty()),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_org_with(org, &params).await
+    }
+
+    /**
+     * List organization issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/issues` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List issues in an organization assigned to the authenticated user.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-organization-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_for_org_stream(
+        &self,
+        org: &str,
+        params: &IssuesListForOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Issue> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !filter.to_string().is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !labels.is_empty() {
+        if let Some(labels) = &params.labels {
             query_args.push(("labels".to_string(), labels.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -310,7 +502,22 @@ impl Issues {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -326,21 +533,19 @@ impl Issues {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListAssigneesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_assignees(
+    pub async fn list_assignees_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListAssigneesParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -354,6 +559,24 @@ impl Issues {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_assignees_with` and pass the query parameters as a `IssuesListAssigneesParams` instead.
+     */
+    #[deprecated(note = "use `list_assignees_with` instead")]
+    pub async fn list_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = IssuesListAssigneesParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_assignees_with(owner, repo, &params).await
+    }
+
     /**
      * List assignees.
      *
@@ -379,6 +602,48 @@ impl Issues {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List assignees.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/assignees` endpoint.
+     *
+     * As opposed to `list_all_assignees`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the [available assignees](https://help.github.com/articles/assigning-issues-and-pull-requests-to-other-github-users/) for issues in a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-assignees>
+     */
+    pub fn list_assignees_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        let url = format!(
+            "/repos/{}/{}/assignees",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Check if a user can be assigned.
      *
@@ -432,68 +697,46 @@ impl Issues {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `milestone: &str` -- If an `integer` is passed, it should refer to a milestone by its `number` field. If the string `*` is passed, issues with any milestone are accepted. If the string `none` is passed, issues without milestones are returned.
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `assignee: &str` -- Can be the name of a user. Pass in `none` for issues with no assigned user, and `*` for issues assigned to any user.
-     * * `creator: &str` -- The user that created the issue.
-     * * `mentioned: &str` -- A user that's mentioned in the issue.
-     * * `labels: &str` -- A list of comma separated label names. Example: `bug,ui,@high`.
-     * * `sort: crate::types::IssuesListSort` -- What to sort results by. Can be either `created`, `updated`, `comments`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_repo(
+    pub async fn list_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        milestone: &str,
-        state: crate::types::IssuesListState,
-        assignee: &str,
-        creator: &str,
-        mentioned: &str,
-        labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListForRepoParams,
     ) -> Result<Vec<crate::types::IssueSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignee.is_empty() {
+        if let Some(assignee) = &params.assignee {
             query_args.push(("assignee".to_string(), assignee.to_string()));
         }
-        if !creator.is_empty() {
+        if let Some(creator) = &params.creator {
             query_args.push(("creator".to_string(), creator.to_string()));
         }
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !labels.is_empty() {
+        if let Some(labels) = &params.labels {
             query_args.push(("labels".to_string(), labels.to_string()));
         }
-        if !mentioned.is_empty() {
+        if let Some(mentioned) = &params.mentioned {
             query_args.push(("mentioned".to_string(), mentioned.to_string()));
         }
-        if !milestone.is_empty() {
+        if let Some(milestone) = &params.milestone {
             query_args.push(("milestone".to_string(), milestone.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -507,6 +750,42 @@ impl Issues {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_repo_with` and pass the query parameters as a `IssuesListForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_for_repo_with` instead")]
+    pub async fn list_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone: &str,
+        state: crate::types::IssuesListRepoState,
+        assignee: &str,
+        creator: &str,
+        mentioned: &str,
+        labels: &str,
+        sort: crate::types::IssuesListRepoSort,
+        direction: crate::types::Direction,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::IssueSimple>> {
+        let params = IssuesListForRepoParams {
+            assignee: Some(assignee.to_string()).filter(|s| !s.is_empty()),
+            creator: Some(creator.to_string()).filter(|s| !s.is_empty()),
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            labels: Some(labels.to_string()).filter(|s| !s.is_empty()),
+            mentioned: Some(mentioned.to_string()).filter(|s| !s.is_empty()),
+            milestone: Some(milestone.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_for_repo_with(owner, repo, &params).await
+    }
+
     /**
      * List repository issues.
      *
@@ -522,47 +801,43 @@ impl Issues {
      * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
      *
      * FROM: <https://docs.github.com/rest/reference/issues#list-repository-issues>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IssuesListForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_for_repo(
+    pub async fn list_all_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        milestone: &str,
-        state: crate::types::IssuesListState,
-        assignee: &str,
-        creator: &str,
-        mentioned: &str,
-        labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
+        params: &IssuesListForRepoParams,
     ) -> Result<Vec<crate::types::IssueSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignee.is_empty() {
+        if let Some(assignee) = &params.assignee {
             query_args.push(("assignee".to_string(), assignee.to_string()));
         }
-        if !creator.is_empty() {
+        if let Some(creator) = &params.creator {
             query_args.push(("creator".to_string(), creator.to_string()));
         }
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !labels.is_empty() {
+        if let Some(labels) = &params.labels {
             query_args.push(("labels".to_string(), labels.to_string()));
         }
-        if !mentioned.is_empty() {
+        if let Some(mentioned) = &params.mentioned {
             query_args.push(("mentioned".to_string(), mentioned.to_string()));
         }
-        if !milestone.is_empty() {
+        if let Some(milestone) = &params.milestone {
             query_args.push(("milestone".to_string(), milestone.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -577,26 +852,137 @@ impl Issues {
     }
 
     /**
-     * Create an issue.
-     *
-     * This function performs a `POST` to the `/repos/{owner}/{repo}/issues` endpoint.
-     *
-     * Any user with pull access to a repository can create an issue. If [issues are disabled in the repository](https://help.github.com/articles/disabling-issues/), the API returns a `410 Gone` status.
-     *
-     * This endpoint triggers [notifications](https://docs.github.com/en/github/managing-subscriptions-and-notifications-on-github/about-notifications). Creating content too quickly using this endpoint may result in secondary rate limiting. See "[Secondary rate limits](https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits)" and "[Dealing with secondary rate limits](https://docs.github.com/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits)" for details.
-     *
-     * FROM: <https://docs.github.com/rest/reference/issues#create-an-issue>
-     *
-     * **Parameters:**
-     *
-     * * `owner: &str`
-     * * `repo: &str`
+     * Deprecated: use `list_all_for_repo_with` and pass the query parameters as a `IssuesListForRepoParams` instead.
      */
-    pub async fn create(
+    #[deprecated(note = "use `list_all_for_repo_with` instead")]
+    pub async fn list_all_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        body: &crate::types::IssuesCreateRequest,
+        milestone: &str,
+        state: crate::types::IssuesListRepoState,
+        assignee: &str,
+        creator: &str,
+        mentioned: &str,
+        labels: &str,
+        sort: crate::types::IssuesListRepoSort,
+        direction: crate::types::Direction,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<crate::types::IssueSimple>> {
+        let params = IssuesListForRepoParams {
+            assignee: Some(assignee.to_string()).filter(|s| !s.is_empty()),
+            creator: Some(creator.to_string()).filter(|s| !s.is_empty()),
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            labels: Some(labels.to_string()).filter(|s| !s.is_empty()),
+            mentioned: Some(mentioned.to_string()).filter(|s| !s.is_empty()),
+            milestone: Some(milestone.to_string()).filter(|s| !s.is_empty()),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_repo_with(owner, repo, &params).await
+    }
+
+    /**
+     * List repository issues.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues` endpoint.
+     *
+     * As opposed to `list_all_for_repo`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List issues in a repository.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-repository-issues>
+     */
+    pub fn list_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueSimple> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(assignee) = &params.assignee {
+            query_args.push(("assignee".to_string(), assignee.to_string()));
+        }
+        if let Some(creator) = &params.creator {
+            query_args.push(("creator".to_string(), creator.to_string()));
+        }
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(labels) = &params.labels {
+            query_args.push(("labels".to_string(), labels.to_string()));
+        }
+        if let Some(mentioned) = &params.mentioned {
+            query_args.push(("mentioned".to_string(), mentioned.to_string()));
+        }
+        if let Some(milestone) = &params.milestone {
+            query_args.push(("milestone".to_string(), milestone.to_string()));
+        }
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/issues?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * Create an issue.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/issues` endpoint.
+     *
+     * Any user with pull access to a repository can create an issue. If [issues are disabled in the repository](https://help.github.com/articles/disabling-issues/), the API returns a `410 Gone` status.
+     *
+     * This endpoint triggers [notifications](https://docs.github.com/en/github/managing-subscriptions-and-notifications-on-github/about-notifications). Creating content too quickly using this endpoint may result in secondary rate limiting. See "[Secondary rate limits](https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits)" and "[Dealing with secondary rate limits](https://docs.github.com/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits)" for details.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#create-an-issue>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     */
+    pub async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::IssuesCreateRequest,
     ) -> Result<crate::types::Issue> {
         let url = format!(
             "/repos/{}/{}/issues",
@@ -622,38 +1008,28 @@ impl Issues {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `sort: crate::types::Sort` -- One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListCommentsForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_comments_for_repo(
+    pub async fn list_comments_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListCommentsForRepoParams,
     ) -> Result<Vec<crate::types::IssueComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -667,6 +1043,30 @@ impl Issues {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_comments_for_repo_with` and pass the query parameters as a `IssuesListCommentsForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_comments_for_repo_with` instead")]
+    pub async fn list_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::Sort,
+        direction: crate::types::Direction,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::IssueComment>> {
+        let params = IssuesListCommentsForRepoParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_comments_for_repo_with(owner, repo, &params).await
+    }
+
     /**
      * List issue comments for a repository.
      *
@@ -677,23 +1077,87 @@ impl Issues {
      * By default, Issue Comments are ordered by ascending ID.
      *
      * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments-for-a-repository>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IssuesListCommentsForRepoParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_comments_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListCommentsForRepoParams,
+    ) -> Result<Vec<crate::types::IssueComment>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/issues/comments?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_comments_for_repo_with` and pass the query parameters as a `IssuesListCommentsForRepoParams` instead.
      */
+    #[deprecated(note = "use `list_all_comments_for_repo_with` instead")]
     pub async fn list_all_comments_for_repo(
         &self,
         owner: &str,
         repo: &str,
         sort: crate::types::Sort,
-        direction: crate::types::Order,
+        direction: crate::types::Direction,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::IssueComment>> {
+        let params = IssuesListCommentsForRepoParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_comments_for_repo_with(owner, repo, &params)
+            .await
+    }
+
+    /**
+     * List issue comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_repo`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * By default, Issue Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments-for-a-repository>
+     */
+    pub fn list_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListCommentsForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueComment> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -704,7 +1168,22 @@ impl Issues {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -784,7 +1263,7 @@ impl Issues {
         owner: &str,
         repo: &str,
         comment_id: i64,
-        body: &crate::types::PullsUpdateReviewRequest,
+        body: &crate::types::IssuesUpdateCommentRequest,
     ) -> Result<crate::types::IssueComment> {
         let url = format!(
             "/repos/{}/{}/issues/comments/{}",
@@ -811,21 +1290,19 @@ impl Issues {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListEventsForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_events_for_repo(
+    pub async fn list_events_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListEventsForRepoParams,
     ) -> Result<Vec<crate::types::IssueEvent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -839,6 +1316,24 @@ impl Issues {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_events_for_repo_with` and pass the query parameters as a `IssuesListEventsForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_events_for_repo_with` instead")]
+    pub async fn list_events_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::IssueEvent>> {
+        let params = IssuesListEventsForRepoParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_events_for_repo_with(owner, repo, &params).await
+    }
+
     /**
      * List issue events for a repository.
      *
@@ -864,6 +1359,48 @@ impl Issues {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List issue events for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/events` endpoint.
+     *
+     * As opposed to `list_all_events_for_repo`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events-for-a-repository>
+     */
+    pub fn list_events_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueEvent> {
+        let url = format!(
+            "/repos/{}/{}/issues/events",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get an issue event.
      *
@@ -1024,7 +1561,7 @@ impl Issues {
         owner: &str,
         repo: &str,
         issue_number: i64,
-        body: &crate::types::IssuesAddAssigneesRequest,
+        body: &crate::types::IssuesRemoveAssigneesRequest,
     ) -> Result<crate::types::IssueSimple> {
         let url = format!(
             "/repos/{}/{}/issues/{}/assignees",
@@ -1052,28 +1589,24 @@ impl Issues {
      * * `owner: &str`
      * * `repo: &str`
      * * `issue_number: i64` -- issue_number parameter.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListCommentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_comments(
+    pub async fn list_comments_with(
         &self,
         owner: &str,
         repo: &str,
         issue_number: i64,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListCommentsParams,
     ) -> Result<Vec<crate::types::IssueComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
@@ -1087,6 +1620,28 @@ impl Issues {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_comments_with` and pass the query parameters as a `IssuesListCommentsParams` instead.
+     */
+    #[deprecated(note = "use `list_comments_with` instead")]
+    pub async fn list_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::IssueComment>> {
+        let params = IssuesListCommentsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+        };
+        self.list_comments_with(owner, repo, issue_number, &params)
+            .await
+    }
+
     /**
      * List issue comments.
      *
@@ -1097,7 +1652,38 @@ impl Issues {
      * Issue Comments are ordered by ascending ID.
      *
      * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IssuesListCommentsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_comments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListCommentsParams,
+    ) -> Result<Vec<crate::types::IssueComment>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/issues/{}/comments?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_comments_with` and pass the query parameters as a `IssuesListCommentsParams` instead.
      */
+    #[deprecated(note = "use `list_all_comments_with` instead")]
     pub async fn list_all_comments(
         &self,
         owner: &str,
@@ -1105,9 +1691,37 @@ impl Issues {
         issue_number: i64,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::IssueComment>> {
+        let params = IssuesListCommentsParams {
+            since,
+            ..Default::default()
+        };
+        self.list_all_comments_with(owner, repo, issue_number, &params)
+            .await
+    }
+
+    /**
+     * List issue comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Issue Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-comments>
+     */
+    pub fn list_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListCommentsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueComment> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
@@ -1118,7 +1732,22 @@ impl Issues {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -1141,7 +1770,7 @@ impl Issues {
         owner: &str,
         repo: &str,
         issue_number: i64,
-        body: &crate::types::PullsUpdateReviewRequest,
+        body: &crate::types::IssuesCreateCommentRequest,
     ) -> Result<crate::types::IssueComment> {
         let url = format!(
             "/repos/{}/{}/issues/{}/comments",
@@ -1169,22 +1798,20 @@ impl Issues {
      * * `owner: &str`
      * * `repo: &str`
      * * `issue_number: i64` -- issue_number parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListEventsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_events(
+    pub async fn list_events_with(
         &self,
         owner: &str,
         repo: &str,
         issue_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListEventsParams,
     ) -> Result<Vec<crate::types::IssueEventAnyOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1199,6 +1826,26 @@ impl Issues {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_events_with` and pass the query parameters as a `IssuesListEventsParams` instead.
+     */
+    #[deprecated(note = "use `list_events_with` instead")]
+    pub async fn list_events(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::IssueEventAnyOf>> {
+        let params = IssuesListEventsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_events_with(owner, repo, issue_number, &params)
+            .await
+    }
+
     /**
      * List issue events.
      *
@@ -1227,9 +1874,53 @@ impl Issues {
     }
 
     /**
-     * List labels for an issue.
+     * List issue events.
      *
-     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels` endpoint.
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/events` endpoint.
+     *
+     * As opposed to `list_all_events`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-issue-events>
+     */
+    pub fn list_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueEventAnyOf> {
+        let url = format!(
+            "/repos/{}/{}/issues/{}/events",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&issue_number.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * List labels for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels` endpoint.
      *
      *
      *
@@ -1240,22 +1931,20 @@ impl Issues {
      * * `owner: &str`
      * * `repo: &str`
      * * `issue_number: i64` -- issue_number parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListLabelsOnIssueParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_labels_on_issue(
+    pub async fn list_labels_on_issue_with(
         &self,
         owner: &str,
         repo: &str,
         issue_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListLabelsOnIssueParams,
     ) -> Result<Vec<crate::types::Label>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1270,6 +1959,26 @@ impl Issues {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_labels_on_issue_with` and pass the query parameters as a `IssuesListLabelsOnIssueParams` instead.
+     */
+    #[deprecated(note = "use `list_labels_on_issue_with` instead")]
+    pub async fn list_labels_on_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Label>> {
+        let params = IssuesListLabelsOnIssueParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_labels_on_issue_with(owner, repo, issue_number, &params)
+            .await
+    }
+
     /**
      * List labels for an issue.
      *
@@ -1297,6 +2006,50 @@ impl Issues {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List labels for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_on_issue`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-an-issue>
+     */
+    pub fn list_labels_on_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Label> {
+        let url = format!(
+            "/repos/{}/{}/issues/{}/labels",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&issue_number.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Set labels for an issue.
      *
@@ -1506,22 +2259,20 @@ impl Issues {
      * * `owner: &str`
      * * `repo: &str`
      * * `issue_number: i64` -- issue_number parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListEventsForTimelineParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_events_for_timeline(
+    pub async fn list_events_for_timeline_with(
         &self,
         owner: &str,
         repo: &str,
         issue_number: i64,
-        per_page: i64,
-        page: i64,
-    ) -> Result<Vec<crate::types::Data>> {
+        params: &IssuesListEventsForTimelineParams,
+    ) -> Result<Vec<crate::types::TimelineIssueEvents>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1536,6 +2287,26 @@ impl Issues {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_events_for_timeline_with` and pass the query parameters as a `IssuesListEventsForTimelineParams` instead.
+     */
+    #[deprecated(note = "use `list_events_for_timeline_with` instead")]
+    pub async fn list_events_for_timeline(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::TimelineIssueEvents>> {
+        let params = IssuesListEventsForTimelineParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_events_for_timeline_with(owner, repo, issue_number, &params)
+            .await
+    }
+
     /**
      * List timeline events for an issue.
      *
@@ -1552,7 +2323,7 @@ impl Issues {
         owner: &str,
         repo: &str,
         issue_number: i64,
-    ) -> Result<Vec<crate::types::Data>> {
+    ) -> Result<Vec<crate::types::TimelineIssueEvents>> {
         let url = format!(
             "/repos/{}/{}/issues/{}/timeline",
             crate::progenitor_support::encode_path(owner),
@@ -1563,6 +2334,50 @@ impl Issues {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List timeline events for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/timeline` endpoint.
+     *
+     * As opposed to `list_all_events_for_timeline`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-timeline-events-for-an-issue>
+     */
+    pub fn list_events_for_timeline_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TimelineIssueEvents> {
+        let url = format!(
+            "/repos/{}/{}/issues/{}/timeline",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&issue_number.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List labels for a repository.
      *
@@ -1576,21 +2391,19 @@ impl Issues {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListLabelsForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_labels_for_repo(
+    pub async fn list_labels_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListLabelsForRepoParams,
     ) -> Result<Vec<crate::types::Label>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1604,6 +2417,24 @@ impl Issues {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_labels_for_repo_with` and pass the query parameters as a `IssuesListLabelsForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_labels_for_repo_with` instead")]
+    pub async fn list_labels_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Label>> {
+        let params = IssuesListLabelsForRepoParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_labels_for_repo_with(owner, repo, &params).await
+    }
+
     /**
      * List labels for a repository.
      *
@@ -1629,6 +2460,48 @@ impl Issues {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List labels for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_for_repo`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-a-repository>
+     */
+    pub fn list_labels_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Label> {
+        let url = format!(
+            "/repos/{}/{}/labels",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a label.
      *
@@ -1764,38 +2637,28 @@ impl Issues {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `sort: crate::types::IssuesListMilestonesSort` -- What to sort results by. Either `due_on` or `completeness`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListMilestonesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_milestones(
+    pub async fn list_milestones_with(
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::IssuesListState,
-        sort: crate::types::IssuesListMilestonesSort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListMilestonesParams,
     ) -> Result<Vec<crate::types::Milestone>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1809,6 +2672,30 @@ impl Issues {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_milestones_with` and pass the query parameters as a `IssuesListMilestonesParams` instead.
+     */
+    #[deprecated(note = "use `list_milestones_with` instead")]
+    pub async fn list_milestones(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::IssuesListMilestonesState,
+        sort: crate::types::IssuesListMilestonesSort,
+        direction: crate::types::Direction,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Milestone>> {
+        let params = IssuesListMilestonesParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_milestones_with(owner, repo, &params).await
+    }
+
     /**
      * List milestones.
      *
@@ -1819,23 +2706,86 @@ impl Issues {
      *
      *
      * FROM: <https://docs.github.com/rest/reference/issues#list-milestones>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IssuesListMilestonesParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn list_all_milestones_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListMilestonesParams,
+    ) -> Result<Vec<crate::types::Milestone>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/milestones?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_milestones_with` and pass the query parameters as a `IssuesListMilestonesParams` instead.
+     */
+    #[deprecated(note = "use `list_all_milestones_with` instead")]
     pub async fn list_all_milestones(
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::IssuesListState,
+        state: crate::types::IssuesListMilestonesState,
         sort: crate::types::IssuesListMilestonesSort,
-        direction: crate::types::Order,
+        direction: crate::types::Direction,
     ) -> Result<Vec<crate::types::Milestone>> {
+        let params = IssuesListMilestonesParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_milestones_with(owner, repo, &params).await
+    }
+
+    /**
+     * List milestones.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones` endpoint.
+     *
+     * As opposed to `list_all_milestones`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-milestones>
+     */
+    pub fn list_milestones_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListMilestonesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Milestone> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1846,7 +2796,22 @@ impl Issues {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -1962,7 +2927,7 @@ impl Issues {
         owner: &str,
         repo: &str,
         milestone_number: i64,
-        body: &crate::types::IssuesCreateMilestoneRequest,
+        body: &crate::types::IssuesUpdateMilestoneRequest,
     ) -> Result<crate::types::Milestone> {
         let url = format!(
             "/repos/{}/{}/milestones/{}",
@@ -1990,22 +2955,20 @@ impl Issues {
      * * `owner: &str`
      * * `repo: &str`
      * * `milestone_number: i64` -- milestone_number parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListLabelsForMilestoneParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_labels_for_milestone(
+    pub async fn list_labels_for_milestone_with(
         &self,
         owner: &str,
         repo: &str,
         milestone_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListLabelsForMilestoneParams,
     ) -> Result<Vec<crate::types::Label>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2020,6 +2983,26 @@ impl Issues {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_labels_for_milestone_with` and pass the query parameters as a `IssuesListLabelsForMilestoneParams` instead.
+     */
+    #[deprecated(note = "use `list_labels_for_milestone_with` instead")]
+    pub async fn list_labels_for_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Label>> {
+        let params = IssuesListLabelsForMilestoneParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_labels_for_milestone_with(owner, repo, milestone_number, &params)
+            .await
+    }
+
     /**
      * List labels for issues in a milestone.
      *
@@ -2047,6 +3030,50 @@ impl Issues {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List labels for issues in a milestone.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/milestones/{milestone_number}/labels` endpoint.
+     *
+     * As opposed to `list_all_labels_for_milestone`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-labels-for-issues-in-a-milestone>
+     */
+    pub fn list_labels_for_milestone_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Label> {
+        let url = format!(
+            "/repos/{}/{}/milestones/{}/labels",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&milestone_number.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List user account issues assigned to the authenticated user.
      *
@@ -2063,56 +3090,35 @@ impl Issues {
      *
      * **Parameters:**
      *
-     * * `filter: crate::types::Filter` -- Indicates which sorts of issues to return. Can be one of:  
-     *  \\* `assigned`: Issues assigned to you  
-     *  \\* `created`: Issues created by you  
-     *  \\* `mentioned`: Issues mentioning you  
-     *  \\* `subscribed`: Issues you're subscribed to updates for  
-     *  \\* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation.
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `labels: &str` -- A list of comma separated label names. Example: `bug,ui,@high`.
-     * * `sort: crate::types::IssuesListSort` -- What to sort results by. Can be either `created`, `updated`, `comments`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &IssuesListForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_authenticated_user(
+    pub async fn list_for_authenticated_user_with(
         &self,
-        filter: crate::types::Filter,
-        state: crate::types::IssuesListState,
-        labels: &str,
-        sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &IssuesListForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Issue>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !filter.to_string().is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !labels.is_empty() {
+        if let Some(labels) = &params.labels {
             query_args.push(("labels".to_string(), labels.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2122,11 +3128,39 @@ impl Issues {
     }
 
     /**
-     * List user account issues assigned to the authenticated user.
-     *
-     * This function performs a `GET` to the `/user/issues` endpoint.
-     *
-     * As opposed to `list_for_authenticated_user`, this function returns all the pages of the request at once.
+     * Deprecated: use `list_for_authenticated_user_with` and pass the query parameters as a `IssuesListForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_for_authenticated_user_with` instead")]
+    pub async fn list_for_authenticated_user(
+        &self,
+        filter: crate::types::Filter,
+        state: crate::types::IssuesListState,
+        labels: &str,
+        sort: crate::types::IssuesListSort,
+        direction: crate::types::Direction,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Issue>> {
+        let params = IssuesListForAuthenticatedUserParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            filter: Some(filter).filter(|v| !v.to_string().is_empty()),
+            labels: Some(labels.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_for_authenticated_user_with(&params).await
+    }
+
+    /**
+     * List user account issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/issues` endpoint.
+     *
+     * As opposed to `list_for_authenticated_user`, this function returns all the pages of the request at once.
      *
      * List issues across owned and member repositories assigned to the authenticated user.
      *
@@ -2136,38 +3170,1818 @@ impl Issues {
      * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
      *
      * FROM: <https://docs.github.com/rest/reference/issues#list-user-account-issues-assigned-to-the-authenticated-user>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IssuesListForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_authenticated_user_with(
+        &self,
+        params: &IssuesListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Issue>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if let Some(labels) = &params.labels {
+            query_args.push(("labels".to_string(), labels.to_string()));
+        }
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/user/issues?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_authenticated_user_with` and pass the query parameters as a `IssuesListForAuthenticatedUserParams` instead.
      */
+    #[deprecated(note = "use `list_all_for_authenticated_user_with` instead")]
     pub async fn list_all_for_authenticated_user(
         &self,
         filter: crate::types::Filter,
         state: crate::types::IssuesListState,
         labels: &str,
         sort: crate::types::IssuesListSort,
-        direction: crate::types::Order,
+        direction: crate::types::Direction,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Issue>> {
+        let params = IssuesListForAuthenticatedUserParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            filter: Some(filter).filter(|v| !v.to_string().is_empty()),
+            labels: Some(labels.to_string()).filter(|s| !s.is_empty()),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_authenticated_user_with(&params).await
+    }
+
+    /**
+     * List user account issues assigned to the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/issues` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List issues across owned and member repositories assigned to the authenticated user.
+     *
+     * **Note**: GitHub's REST API v3 considers every pull request an issue, but not every issue is a pull request. For this
+     * reason, "Issues" endpoints may return both issues and pull requests in the response. You can identify pull requests by
+     * the `pull_request` key. Be aware that the `id` of a pull request returned from "Issues" endpoints will be an _issue id_. To find out the pull
+     * request id, use the "[List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests)" endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/issues#list-user-account-issues-assigned-to-the-authenticated-user>
+     */
+    pub fn list_for_authenticated_user_stream(
+        &self,
+        params: &IssuesListForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Issue> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !filter.to_string().is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !labels.is_empty() {
+        if let Some(labels) = &params.labels {
             query_args.push(("labels".to_string(), labels.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/user/issues?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Issues::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListParams {
+    pub collab: Option<bool>,
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Indicates which sorts of issues to return. Can be one of:  
+    /// \\* `assigned`: Issues assigned to you  
+    /// \\* `created`: Issues created by you  
+    /// \\* `mentioned`: Issues mentioning you  
+    /// \\* `subscribed`: Issues you're subscribed to updates for  
+    /// \\* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation.
+    pub filter: Option<crate::types::Filter>,
+    /// A list of comma separated label names. Example: `bug,ui,@high`.
+    pub labels: Option<String>,
+    pub orgs: Option<bool>,
+    pub owned: Option<bool>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    pub pulls: Option<bool>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// What to sort results by. Can be either `created`, `updated`, `comments`.
+    pub sort: Option<crate::types::IssuesListSort>,
+    /// Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
+    pub state: Option<crate::types::IssuesListState>,
+}
+
+impl IssuesListParams {
+    pub fn collab(mut self, collab: bool) -> Self {
+        self.collab = Some(collab);
+        self
+    }
+
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn filter(mut self, filter: crate::types::Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn labels<T: ToString>(mut self, labels: T) -> Self {
+        self.labels = Some(labels.to_string());
+        self
+    }
+
+    pub fn orgs(mut self, orgs: bool) -> Self {
+        self.orgs = Some(orgs);
+        self
+    }
+
+    pub fn owned(mut self, owned: bool) -> Self {
+        self.owned = Some(owned);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn pulls(mut self, pulls: bool) -> Self {
+        self.pulls = Some(pulls);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::IssuesListSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+/// The query parameters of `Issues::list_for_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListForOrgParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Indicates which sorts of issues to return. Can be one of:  
+    /// \\* `assigned`: Issues assigned to you  
+    /// \\* `created`: Issues created by you  
+    /// \\* `mentioned`: Issues mentioning you  
+    /// \\* `subscribed`: Issues you're subscribed to updates for  
+    /// \\* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation.
+    pub filter: Option<crate::types::Filter>,
+    /// A list of comma separated label names. Example: `bug,ui,@high`.
+    pub labels: Option<String>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// What to sort results by. Can be either `created`, `updated`, `comments`.
+    pub sort: Option<crate::types::IssuesListOrgSort>,
+    /// Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
+    pub state: Option<crate::types::IssuesListOrgState>,
+}
+
+impl IssuesListForOrgParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn filter(mut self, filter: crate::types::Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn labels<T: ToString>(mut self, labels: T) -> Self {
+        self.labels = Some(labels.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::IssuesListOrgSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn state(mut self, state: crate::types::IssuesListOrgState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+/// The query parameters of `Issues::list_assignees_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListAssigneesParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl IssuesListAssigneesParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Issues::list_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListForRepoParams {
+    /// Can be the name of a user. Pass in `none` for issues with no assigned user, and `*` for issues assigned to any user.
+    pub assignee: Option<String>,
+    /// The user that created the issue.
+    pub creator: Option<String>,
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// A list of comma separated label names. Example: `bug,ui,@high`.
+    pub labels: Option<String>,
+    /// A user that's mentioned in the issue.
+    pub mentioned: Option<String>,
+    /// If an `integer` is passed, it should refer to a milestone by its `number` field. If the string `*` is passed, issues with any milestone are accepted. If the string `none` is passed, issues without milestones are returned.
+    pub milestone: Option<String>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// What to sort results by. Can be either `created`, `updated`, `comments`.
+    pub sort: Option<crate::types::IssuesListRepoSort>,
+    /// Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
+    pub state: Option<crate::types::IssuesListRepoState>,
+}
+
+impl IssuesListForRepoParams {
+    pub fn assignee<T: ToString>(mut self, assignee: T) -> Self {
+        self.assignee = Some(assignee.to_string());
+        self
+    }
+
+    pub fn creator<T: ToString>(mut self, creator: T) -> Self {
+        self.creator = Some(creator.to_string());
+        self
+    }
+
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn labels<T: ToString>(mut self, labels: T) -> Self {
+        self.labels = Some(labels.to_string());
+        self
+    }
+
+    pub fn mentioned<T: ToString>(mut self, mentioned: T) -> Self {
+        self.mentioned = Some(mentioned.to_string());
+        self
+    }
+
+    pub fn milestone<T: ToString>(mut self, milestone: T) -> Self {
+        self.milestone = Some(milestone.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::IssuesListRepoSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn state(mut self, state: crate::types::IssuesListRepoState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+/// The query parameters of `Issues::list_comments_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListCommentsForRepoParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// Sorts the results of your query by when the label was `created` or `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
+    pub sort: Option<crate::types::Sort>,
+}
+
+impl IssuesListCommentsForRepoParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The query parameters of `Issues::list_events_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListEventsForRepoParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl IssuesListEventsForRepoParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Issues::list_comments_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListCommentsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl IssuesListCommentsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+}
+
+/// The query parameters of `Issues::list_events_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListEventsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl IssuesListEventsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Issues::list_labels_on_issue_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListLabelsOnIssueParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl IssuesListLabelsOnIssueParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Issues::list_events_for_timeline_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListEventsForTimelineParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl IssuesListEventsForTimelineParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Issues::list_labels_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListLabelsForRepoParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl IssuesListLabelsForRepoParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Issues::list_milestones_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListMilestonesParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// What to sort results by. Either `due_on` or `completeness`.
+    pub sort: Option<crate::types::IssuesListMilestonesSort>,
+    /// The state of the milestone. Either `open`, `closed`, or `all`.
+    pub state: Option<crate::types::IssuesListMilestonesState>,
+}
+
+impl IssuesListMilestonesParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::IssuesListMilestonesSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn state(mut self, state: crate::types::IssuesListMilestonesState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+/// The query parameters of `Issues::list_labels_for_milestone_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListLabelsForMilestoneParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl IssuesListLabelsForMilestoneParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Issues::list_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuesListForAuthenticatedUserParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Indicates which sorts of issues to return. Can be one of:  
+    /// \\* `assigned`: Issues assigned to you  
+    /// \\* `created`: Issues created by you  
+    /// \\* `mentioned`: Issues mentioning you  
+    /// \\* `subscribed`: Issues you're subscribed to updates for  
+    /// \\* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation.
+    pub filter: Option<crate::types::Filter>,
+    /// A list of comma separated label names. Example: `bug,ui,@high`.
+    pub labels: Option<String>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// What to sort results by. Can be either `created`, `updated`, `comments`.
+    pub sort: Option<crate::types::IssuesListSort>,
+    /// Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
+    pub state: Option<crate::types::IssuesListState>,
+}
+
+impl IssuesListForAuthenticatedUserParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn filter(mut self, filter: crate::types::Filter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn labels<T: ToString>(mut self, labels: T) -> Self {
+        self.labels = Some(labels.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::IssuesListSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn state(mut self, state: crate::types::IssuesListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+/// The functions of [`Issues`], for code that takes them as a trait, so that it can be
+/// tested with the `MockIssuesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait IssuesApi: Send + Sync {
+    /// See [`Issues::list_with`].
+    async fn list_with(&self, params: &IssuesListParams) -> Result<Vec<crate::types::Issue>>;
+
+    /// See [`Issues::list_all_with`].
+    async fn list_all_with(&self, params: &IssuesListParams) -> Result<Vec<crate::types::Issue>>;
+
+    /// See [`Issues::list_stream`].
+    fn list_stream(
+        &self,
+        params: &IssuesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Issue>;
+
+    /// See [`Issues::list_for_org_with`].
+    async fn list_for_org_with(
+        &self,
+        org: &str,
+        params: &IssuesListForOrgParams,
+    ) -> Result<Vec<crate::types::Issue>>;
+
+    /// See [`Issues::list_all_for_org_with`].
+    async fn list_all_for_org_with(
+        &self,
+        org: &str,
+        params: &IssuesListForOrgParams,
+    ) -> Result<Vec<crate::types::Issue>>;
+
+    /// See [`Issues::list_for_org_stream`].
+    fn list_for_org_stream(
+        &self,
+        org: &str,
+        params: &IssuesListForOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Issue>;
+
+    /// See [`Issues::list_assignees_with`].
+    async fn list_assignees_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListAssigneesParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Issues::list_all_assignees`].
+    async fn list_all_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Issues::list_assignees_stream`].
+    fn list_assignees_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Issues::check_user_can_be_assigned`].
+    async fn check_user_can_be_assigned(
+        &self,
+        owner: &str,
+        repo: &str,
+        assignee: &str,
+    ) -> Result<()>;
+
+    /// See [`Issues::list_for_repo_with`].
+    async fn list_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListForRepoParams,
+    ) -> Result<Vec<crate::types::IssueSimple>>;
+
+    /// See [`Issues::list_all_for_repo_with`].
+    async fn list_all_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListForRepoParams,
+    ) -> Result<Vec<crate::types::IssueSimple>>;
+
+    /// See [`Issues::list_for_repo_stream`].
+    fn list_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueSimple>;
+
+    /// See [`Issues::create`].
+    async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::IssuesCreateRequest,
+    ) -> Result<crate::types::Issue>;
+
+    /// See [`Issues::list_comments_for_repo_with`].
+    async fn list_comments_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListCommentsForRepoParams,
+    ) -> Result<Vec<crate::types::IssueComment>>;
+
+    /// See [`Issues::list_all_comments_for_repo_with`].
+    async fn list_all_comments_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListCommentsForRepoParams,
+    ) -> Result<Vec<crate::types::IssueComment>>;
+
+    /// See [`Issues::list_comments_for_repo_stream`].
+    fn list_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListCommentsForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueComment>;
+
+    /// See [`Issues::get_comment`].
+    async fn get_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> Result<crate::types::IssueComment>;
+
+    /// See [`Issues::delete_comment`].
+    async fn delete_comment(&self, owner: &str, repo: &str, comment_id: i64) -> Result<()>;
+
+    /// See [`Issues::update_comment`].
+    async fn update_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::IssuesUpdateCommentRequest,
+    ) -> Result<crate::types::IssueComment>;
+
+    /// See [`Issues::list_events_for_repo_with`].
+    async fn list_events_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListEventsForRepoParams,
+    ) -> Result<Vec<crate::types::IssueEvent>>;
+
+    /// See [`Issues::list_all_events_for_repo`].
+    async fn list_all_events_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::IssueEvent>>;
+
+    /// See [`Issues::list_events_for_repo_stream`].
+    fn list_events_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueEvent>;
+
+    /// See [`Issues::get_event`].
+    async fn get_event(
+        &self,
+        owner: &str,
+        repo: &str,
+        event_id: i64,
+    ) -> Result<crate::types::IssueEvent>;
+
+    /// See [`Issues::get`].
+    async fn get(&self, owner: &str, repo: &str, issue_number: i64) -> Result<crate::types::Issue>;
+
+    /// See [`Issues::update`].
+    async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesUpdateRequest,
+    ) -> Result<crate::types::Issue>;
+
+    /// See [`Issues::add_assignees`].
+    async fn add_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesAddAssigneesRequest,
+    ) -> Result<crate::types::IssueSimple>;
+
+    /// See [`Issues::remove_assignees`].
+    async fn remove_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesRemoveAssigneesRequest,
+    ) -> Result<crate::types::IssueSimple>;
+
+    /// See [`Issues::list_comments_with`].
+    async fn list_comments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListCommentsParams,
+    ) -> Result<Vec<crate::types::IssueComment>>;
+
+    /// See [`Issues::list_all_comments_with`].
+    async fn list_all_comments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListCommentsParams,
+    ) -> Result<Vec<crate::types::IssueComment>>;
+
+    /// See [`Issues::list_comments_stream`].
+    fn list_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListCommentsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueComment>;
+
+    /// See [`Issues::create_comment`].
+    async fn create_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesCreateCommentRequest,
+    ) -> Result<crate::types::IssueComment>;
+
+    /// See [`Issues::list_events_with`].
+    async fn list_events_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListEventsParams,
+    ) -> Result<Vec<crate::types::IssueEventAnyOf>>;
+
+    /// See [`Issues::list_all_events`].
+    async fn list_all_events(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> Result<Vec<crate::types::IssueEventAnyOf>>;
+
+    /// See [`Issues::list_events_stream`].
+    fn list_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueEventAnyOf>;
+
+    /// See [`Issues::list_labels_on_issue_with`].
+    async fn list_labels_on_issue_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListLabelsOnIssueParams,
+    ) -> Result<Vec<crate::types::Label>>;
+
+    /// See [`Issues::list_all_labels_on_issue`].
+    async fn list_all_labels_on_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> Result<Vec<crate::types::Label>>;
+
+    /// See [`Issues::list_labels_on_issue_stream`].
+    fn list_labels_on_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Label>;
+
+    /// See [`Issues::set_labels`].
+    async fn set_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesSetLabelsRequestAnyOf,
+    ) -> Result<Vec<crate::types::Label>>;
+
+    /// See [`Issues::add_labels`].
+    async fn add_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesAddLabelsRequestOneOf,
+    ) -> Result<Vec<crate::types::Label>>;
+
+    /// See [`Issues::remove_all_labels`].
+    async fn remove_all_labels(&self, owner: &str, repo: &str, issue_number: i64) -> Result<()>;
+
+    /// See [`Issues::remove_label`].
+    async fn remove_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        name: &str,
+    ) -> Result<Vec<crate::types::Label>>;
+
+    /// See [`Issues::lock`].
+    async fn lock(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesLockRequest,
+    ) -> Result<()>;
+
+    /// See [`Issues::unlock`].
+    async fn unlock(&self, owner: &str, repo: &str, issue_number: i64) -> Result<()>;
+
+    /// See [`Issues::list_events_for_timeline_with`].
+    async fn list_events_for_timeline_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListEventsForTimelineParams,
+    ) -> Result<Vec<crate::types::TimelineIssueEvents>>;
+
+    /// See [`Issues::list_all_events_for_timeline`].
+    async fn list_all_events_for_timeline(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> Result<Vec<crate::types::TimelineIssueEvents>>;
+
+    /// See [`Issues::list_events_for_timeline_stream`].
+    fn list_events_for_timeline_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TimelineIssueEvents>;
+
+    /// See [`Issues::list_labels_for_repo_with`].
+    async fn list_labels_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListLabelsForRepoParams,
+    ) -> Result<Vec<crate::types::Label>>;
+
+    /// See [`Issues::list_all_labels_for_repo`].
+    async fn list_all_labels_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::Label>>;
+
+    /// See [`Issues::list_labels_for_repo_stream`].
+    fn list_labels_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Label>;
+
+    /// See [`Issues::create_label`].
+    async fn create_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::IssuesCreateLabelRequest,
+    ) -> Result<crate::types::Label>;
+
+    /// See [`Issues::get_label`].
+    async fn get_label(&self, owner: &str, repo: &str, name: &str) -> Result<crate::types::Label>;
+
+    /// See [`Issues::delete_label`].
+    async fn delete_label(&self, owner: &str, repo: &str, name: &str) -> Result<()>;
+
+    /// See [`Issues::update_label`].
+    async fn update_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        body: &crate::types::IssuesUpdateLabelRequest,
+    ) -> Result<crate::types::Label>;
+
+    /// See [`Issues::list_milestones_with`].
+    async fn list_milestones_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListMilestonesParams,
+    ) -> Result<Vec<crate::types::Milestone>>;
+
+    /// See [`Issues::list_all_milestones_with`].
+    async fn list_all_milestones_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListMilestonesParams,
+    ) -> Result<Vec<crate::types::Milestone>>;
+
+    /// See [`Issues::list_milestones_stream`].
+    fn list_milestones_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListMilestonesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Milestone>;
+
+    /// See [`Issues::create_milestone`].
+    async fn create_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::IssuesCreateMilestoneRequest,
+    ) -> Result<crate::types::Milestone>;
+
+    /// See [`Issues::get_milestone`].
+    async fn get_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> Result<crate::types::Milestone>;
+
+    /// See [`Issues::delete_milestone`].
+    async fn delete_milestone(&self, owner: &str, repo: &str, milestone_number: i64) -> Result<()>;
+
+    /// See [`Issues::update_milestone`].
+    async fn update_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        body: &crate::types::IssuesUpdateMilestoneRequest,
+    ) -> Result<crate::types::Milestone>;
+
+    /// See [`Issues::list_labels_for_milestone_with`].
+    async fn list_labels_for_milestone_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        params: &IssuesListLabelsForMilestoneParams,
+    ) -> Result<Vec<crate::types::Label>>;
+
+    /// See [`Issues::list_all_labels_for_milestone`].
+    async fn list_all_labels_for_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> Result<Vec<crate::types::Label>>;
+
+    /// See [`Issues::list_labels_for_milestone_stream`].
+    fn list_labels_for_milestone_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Label>;
+
+    /// See [`Issues::list_for_authenticated_user_with`].
+    async fn list_for_authenticated_user_with(
+        &self,
+        params: &IssuesListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Issue>>;
+
+    /// See [`Issues::list_all_for_authenticated_user_with`].
+    async fn list_all_for_authenticated_user_with(
+        &self,
+        params: &IssuesListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Issue>>;
+
+    /// See [`Issues::list_for_authenticated_user_stream`].
+    fn list_for_authenticated_user_stream(
+        &self,
+        params: &IssuesListForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Issue>;
+}
+
+#[async_trait::async_trait]
+impl IssuesApi for Issues {
+    async fn list_with(&self, params: &IssuesListParams) -> Result<Vec<crate::types::Issue>> {
+        Issues::list_with(self, params).await
+    }
+
+    async fn list_all_with(&self, params: &IssuesListParams) -> Result<Vec<crate::types::Issue>> {
+        Issues::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &IssuesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Issue> {
+        Issues::list_stream(self, params, start, max_items)
+    }
+
+    async fn list_for_org_with(
+        &self,
+        org: &str,
+        params: &IssuesListForOrgParams,
+    ) -> Result<Vec<crate::types::Issue>> {
+        Issues::list_for_org_with(self, org, params).await
+    }
+
+    async fn list_all_for_org_with(
+        &self,
+        org: &str,
+        params: &IssuesListForOrgParams,
+    ) -> Result<Vec<crate::types::Issue>> {
+        Issues::list_all_for_org_with(self, org, params).await
+    }
+
+    fn list_for_org_stream(
+        &self,
+        org: &str,
+        params: &IssuesListForOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Issue> {
+        Issues::list_for_org_stream(self, org, params, start, max_items)
+    }
+
+    async fn list_assignees_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListAssigneesParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Issues::list_assignees_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Issues::list_all_assignees(self, owner, repo).await
+    }
+
+    fn list_assignees_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Issues::list_assignees_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn check_user_can_be_assigned(
+        &self,
+        owner: &str,
+        repo: &str,
+        assignee: &str,
+    ) -> Result<()> {
+        Issues::check_user_can_be_assigned(self, owner, repo, assignee).await
+    }
+
+    async fn list_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListForRepoParams,
+    ) -> Result<Vec<crate::types::IssueSimple>> {
+        Issues::list_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListForRepoParams,
+    ) -> Result<Vec<crate::types::IssueSimple>> {
+        Issues::list_all_for_repo_with(self, owner, repo, params).await
+    }
+
+    fn list_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueSimple> {
+        Issues::list_for_repo_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::IssuesCreateRequest,
+    ) -> Result<crate::types::Issue> {
+        Issues::create(self, owner, repo, body).await
+    }
+
+    async fn list_comments_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListCommentsForRepoParams,
+    ) -> Result<Vec<crate::types::IssueComment>> {
+        Issues::list_comments_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_comments_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListCommentsForRepoParams,
+    ) -> Result<Vec<crate::types::IssueComment>> {
+        Issues::list_all_comments_for_repo_with(self, owner, repo, params).await
+    }
+
+    fn list_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListCommentsForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueComment> {
+        Issues::list_comments_for_repo_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn get_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> Result<crate::types::IssueComment> {
+        Issues::get_comment(self, owner, repo, comment_id).await
+    }
+
+    async fn delete_comment(&self, owner: &str, repo: &str, comment_id: i64) -> Result<()> {
+        Issues::delete_comment(self, owner, repo, comment_id).await
+    }
+
+    async fn update_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::IssuesUpdateCommentRequest,
+    ) -> Result<crate::types::IssueComment> {
+        Issues::update_comment(self, owner, repo, comment_id, body).await
+    }
+
+    async fn list_events_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListEventsForRepoParams,
+    ) -> Result<Vec<crate::types::IssueEvent>> {
+        Issues::list_events_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_events_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::IssueEvent>> {
+        Issues::list_all_events_for_repo(self, owner, repo).await
+    }
+
+    fn list_events_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueEvent> {
+        Issues::list_events_for_repo_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn get_event(
+        &self,
+        owner: &str,
+        repo: &str,
+        event_id: i64,
+    ) -> Result<crate::types::IssueEvent> {
+        Issues::get_event(self, owner, repo, event_id).await
+    }
+
+    async fn get(&self, owner: &str, repo: &str, issue_number: i64) -> Result<crate::types::Issue> {
+        Issues::get(self, owner, repo, issue_number).await
+    }
+
+    async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesUpdateRequest,
+    ) -> Result<crate::types::Issue> {
+        Issues::update(self, owner, repo, issue_number, body).await
+    }
+
+    async fn add_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesAddAssigneesRequest,
+    ) -> Result<crate::types::IssueSimple> {
+        Issues::add_assignees(self, owner, repo, issue_number, body).await
+    }
+
+    async fn remove_assignees(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesRemoveAssigneesRequest,
+    ) -> Result<crate::types::IssueSimple> {
+        Issues::remove_assignees(self, owner, repo, issue_number, body).await
+    }
+
+    async fn list_comments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListCommentsParams,
+    ) -> Result<Vec<crate::types::IssueComment>> {
+        Issues::list_comments_with(self, owner, repo, issue_number, params).await
+    }
+
+    async fn list_all_comments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListCommentsParams,
+    ) -> Result<Vec<crate::types::IssueComment>> {
+        Issues::list_all_comments_with(self, owner, repo, issue_number, params).await
+    }
+
+    fn list_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListCommentsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueComment> {
+        Issues::list_comments_stream(self, owner, repo, issue_number, params, start, max_items)
+    }
+
+    async fn create_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesCreateCommentRequest,
+    ) -> Result<crate::types::IssueComment> {
+        Issues::create_comment(self, owner, repo, issue_number, body).await
+    }
+
+    async fn list_events_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListEventsParams,
+    ) -> Result<Vec<crate::types::IssueEventAnyOf>> {
+        Issues::list_events_with(self, owner, repo, issue_number, params).await
+    }
+
+    async fn list_all_events(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> Result<Vec<crate::types::IssueEventAnyOf>> {
+        Issues::list_all_events(self, owner, repo, issue_number).await
+    }
+
+    fn list_events_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssueEventAnyOf> {
+        Issues::list_events_stream(self, owner, repo, issue_number, start, max_items)
+    }
+
+    async fn list_labels_on_issue_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListLabelsOnIssueParams,
+    ) -> Result<Vec<crate::types::Label>> {
+        Issues::list_labels_on_issue_with(self, owner, repo, issue_number, params).await
+    }
+
+    async fn list_all_labels_on_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> Result<Vec<crate::types::Label>> {
+        Issues::list_all_labels_on_issue(self, owner, repo, issue_number).await
+    }
+
+    fn list_labels_on_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Label> {
+        Issues::list_labels_on_issue_stream(self, owner, repo, issue_number, start, max_items)
+    }
+
+    async fn set_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesSetLabelsRequestAnyOf,
+    ) -> Result<Vec<crate::types::Label>> {
+        Issues::set_labels(self, owner, repo, issue_number, body).await
+    }
+
+    async fn add_labels(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesAddLabelsRequestOneOf,
+    ) -> Result<Vec<crate::types::Label>> {
+        Issues::add_labels(self, owner, repo, issue_number, body).await
+    }
+
+    async fn remove_all_labels(&self, owner: &str, repo: &str, issue_number: i64) -> Result<()> {
+        Issues::remove_all_labels(self, owner, repo, issue_number).await
+    }
+
+    async fn remove_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        name: &str,
+    ) -> Result<Vec<crate::types::Label>> {
+        Issues::remove_label(self, owner, repo, issue_number, name).await
+    }
+
+    async fn lock(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::IssuesLockRequest,
+    ) -> Result<()> {
+        Issues::lock(self, owner, repo, issue_number, body).await
+    }
+
+    async fn unlock(&self, owner: &str, repo: &str, issue_number: i64) -> Result<()> {
+        Issues::unlock(self, owner, repo, issue_number).await
+    }
+
+    async fn list_events_for_timeline_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &IssuesListEventsForTimelineParams,
+    ) -> Result<Vec<crate::types::TimelineIssueEvents>> {
+        Issues::list_events_for_timeline_with(self, owner, repo, issue_number, params).await
+    }
+
+    async fn list_all_events_for_timeline(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+    ) -> Result<Vec<crate::types::TimelineIssueEvents>> {
+        Issues::list_all_events_for_timeline(self, owner, repo, issue_number).await
+    }
+
+    fn list_events_for_timeline_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TimelineIssueEvents> {
+        Issues::list_events_for_timeline_stream(self, owner, repo, issue_number, start, max_items)
+    }
+
+    async fn list_labels_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListLabelsForRepoParams,
+    ) -> Result<Vec<crate::types::Label>> {
+        Issues::list_labels_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_labels_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::Label>> {
+        Issues::list_all_labels_for_repo(self, owner, repo).await
+    }
+
+    fn list_labels_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Label> {
+        Issues::list_labels_for_repo_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn create_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::IssuesCreateLabelRequest,
+    ) -> Result<crate::types::Label> {
+        Issues::create_label(self, owner, repo, body).await
+    }
+
+    async fn get_label(&self, owner: &str, repo: &str, name: &str) -> Result<crate::types::Label> {
+        Issues::get_label(self, owner, repo, name).await
+    }
+
+    async fn delete_label(&self, owner: &str, repo: &str, name: &str) -> Result<()> {
+        Issues::delete_label(self, owner, repo, name).await
+    }
+
+    async fn update_label(
+        &self,
+        owner: &str,
+        repo: &str,
+        name: &str,
+        body: &crate::types::IssuesUpdateLabelRequest,
+    ) -> Result<crate::types::Label> {
+        Issues::update_label(self, owner, repo, name, body).await
+    }
+
+    async fn list_milestones_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListMilestonesParams,
+    ) -> Result<Vec<crate::types::Milestone>> {
+        Issues::list_milestones_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_milestones_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListMilestonesParams,
+    ) -> Result<Vec<crate::types::Milestone>> {
+        Issues::list_all_milestones_with(self, owner, repo, params).await
+    }
+
+    fn list_milestones_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &IssuesListMilestonesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Milestone> {
+        Issues::list_milestones_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn create_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::IssuesCreateMilestoneRequest,
+    ) -> Result<crate::types::Milestone> {
+        Issues::create_milestone(self, owner, repo, body).await
+    }
+
+    async fn get_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> Result<crate::types::Milestone> {
+        Issues::get_milestone(self, owner, repo, milestone_number).await
+    }
+
+    async fn delete_milestone(&self, owner: &str, repo: &str, milestone_number: i64) -> Result<()> {
+        Issues::delete_milestone(self, owner, repo, milestone_number).await
+    }
+
+    async fn update_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        body: &crate::types::IssuesUpdateMilestoneRequest,
+    ) -> Result<crate::types::Milestone> {
+        Issues::update_milestone(self, owner, repo, milestone_number, body).await
+    }
+
+    async fn list_labels_for_milestone_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        params: &IssuesListLabelsForMilestoneParams,
+    ) -> Result<Vec<crate::types::Label>> {
+        Issues::list_labels_for_milestone_with(self, owner, repo, milestone_number, params).await
+    }
+
+    async fn list_all_labels_for_milestone(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+    ) -> Result<Vec<crate::types::Label>> {
+        Issues::list_all_labels_for_milestone(self, owner, repo, milestone_number).await
+    }
+
+    fn list_labels_for_milestone_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        milestone_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Label> {
+        Issues::list_labels_for_milestone_stream(
+            self,
+            owner,
+            repo,
+            milestone_number,
+            start,
+            max_items,
+        )
+    }
+
+    async fn list_for_authenticated_user_with(
+        &self,
+        params: &IssuesListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Issue>> {
+        Issues::list_for_authenticated_user_with(self, params).await
+    }
+
+    async fn list_all_for_authenticated_user_with(
+        &self,
+        params: &IssuesListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Issue>> {
+        Issues::list_all_for_authenticated_user_with(self, params).await
+    }
+
+    fn list_for_authenticated_user_stream(
+        &self,
+        params: &IssuesListForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Issue> {
+        Issues::list_for_authenticated_user_stream(self, params, start, max_items)
     }
 }
@@ -30,6 +30,30 @@
 //! up to date as features are added. The documentation for the crate is generated
 //! along with the code to make this library easy to use.
 //!
+//! Each tag of the API is a cargo feature of its own, all of them enabled by the
+//! default `full` feature. To build only the tags you use, turn off the default
+//! features and enable those tags, along with `rustls-tls` or `native-tls`.
+//!
+//! The functions of each tag are also a trait, named after the tag, like `UsersApi`
+//! for `users`. Code that takes the trait can be tested with the mocks of the `mock`
+//! feature, like `MockUsersApi`, rather than a server.
+//!
+//! Requests wait out the rate limits of the API. Those that are safe to send twice
+//! are sent again after the `Retry-After` of rate limited responses, and all of them
+//! wait for the limit to reset when none of it is left. `Client::rate_limit_status` tells what the API last said about its
+//! limits.
+//!
+//! Requests that time out, can't connect or get a `5xx` that may not last are
+//! retried, waiting longer before each attempt, but only those that are safe to send
+//! twice: `GET`s, `HEAD`s, `OPTIONS` and `TRACE`s. `Client::builder().retry_policy(...)`
+//! changes how many times, how long to wait and which methods. For the APIs that take
+//! idempotency keys, the `POST`s of `Client::with_idempotency_key` are retried too.
+//!
+//! `Client::builder()` also sets the timeouts, proxies, root certificates, client
+//! certificate and `User-Agent` of the HTTP client, or takes a
+//! `reqwest_middleware::ClientWithMiddleware` of your own. Its `build` takes what
+//! `Client::new` does.
+//!
 //!
 //! To install the library, add the following to your `Cargo.toml` file.
 //!
@@ -167,83 +191,150 @@
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::nonstandard_macro_braces)]
 #![allow(clippy::large_enum_variant)]
+#![allow(clippy::result_large_err)]
 #![allow(clippy::tabs_in_doc_comments)]
 #![allow(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 /// Endpoints to manage GitHub Actions using the REST API.
+#[cfg(feature = "actions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "actions")))]
 pub mod actions;
 /// Activity APIs provide access to notifications, subscriptions, and timelines.
+#[cfg(feature = "activity")]
+#[cfg_attr(docsrs, doc(cfg(feature = "activity")))]
 pub mod activity;
 /// Information for integrations and installations.
+#[cfg(feature = "apps")]
+#[cfg_attr(docsrs, doc(cfg(feature = "apps")))]
 pub mod apps;
 pub mod auth;
 /// Monitor charges and usage from Actions and Packages.
+#[cfg(feature = "billing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "billing")))]
 pub mod billing;
 /// Rich interactions with checks run by your integrations.
+#[cfg(feature = "checks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "checks")))]
 pub mod checks;
 /// Retrieve code scanning alerts from a repository.
+#[cfg(feature = "code_scanning")]
+#[cfg_attr(docsrs, doc(cfg(feature = "code_scanning")))]
 pub mod code_scanning;
 /// Insight into codes of conduct for your communities.
+#[cfg(feature = "codes_of_conduct")]
+#[cfg_attr(docsrs, doc(cfg(feature = "codes_of_conduct")))]
 pub mod codes_of_conduct;
 /// List emojis available to use on GitHub.
+#[cfg(feature = "emojis")]
+#[cfg_attr(docsrs, doc(cfg(feature = "emojis")))]
 pub mod emojis;
 /// Administer a GitHub enterprise.
+#[cfg(feature = "enterprise_admin")]
+#[cfg_attr(docsrs, doc(cfg(feature = "enterprise_admin")))]
 pub mod enterprise_admin;
+mod error;
 /// View, modify your gists.
+#[cfg(feature = "gists")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gists")))]
 pub mod gists;
 /// Raw Git functionality.
+#[cfg(feature = "git")]
+#[cfg_attr(docsrs, doc(cfg(feature = "git")))]
 pub mod git;
 /// View gitignore templates.
+#[cfg(feature = "gitignore")]
+#[cfg_attr(docsrs, doc(cfg(feature = "gitignore")))]
 pub mod gitignore;
 #[cfg(feature = "httpcache")]
 #[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]
 pub mod http_cache;
 /// Owner or admin management of users interactions.
+#[cfg(feature = "interactions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "interactions")))]
 pub mod interactions;
 /// Interact with GitHub Issues.
+#[cfg(feature = "issues")]
+#[cfg_attr(docsrs, doc(cfg(feature = "issues")))]
 pub mod issues;
 /// View various OSS licenses.
+#[cfg(feature = "licenses")]
+#[cfg_attr(docsrs, doc(cfg(feature = "licenses")))]
 pub mod licenses;
 /// Render Github flavored markdown.
+#[cfg(feature = "markdown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
 pub mod markdown;
 /// Endpoints that give information about the API.
+#[cfg(feature = "meta")]
+#[cfg_attr(docsrs, doc(cfg(feature = "meta")))]
 pub mod meta;
 /// Move projects to or from GitHub.
+#[cfg(feature = "migrations")]
+#[cfg_attr(docsrs, doc(cfg(feature = "migrations")))]
 pub mod migrations;
 /// Manage access of OAuth applications.
+#[cfg(feature = "oauth_authorizations")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oauth_authorizations")))]
 pub mod oauth_authorizations;
 /// Interact with GitHub Orgs.
+#[cfg(feature = "orgs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "orgs")))]
 pub mod orgs;
 /// Manage packages for authenticated users and organizations.
+#[cfg(feature = "packages")]
+#[cfg_attr(docsrs, doc(cfg(feature = "packages")))]
 pub mod packages;
+pub mod pagination;
 /// Interact with GitHub Projects.
+#[cfg(feature = "projects")]
+#[cfg_attr(docsrs, doc(cfg(feature = "projects")))]
 pub mod projects;
 /// Interact with GitHub Pull Requests.
+#[cfg(feature = "pulls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pulls")))]
 pub mod pulls;
 /// Check your current rate limit status.
+#[cfg(feature = "rate_limit")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rate_limit")))]
 pub mod rate_limit;
+pub mod rate_limiter;
 /// Interact with reactions to various GitHub entities.
+#[cfg(feature = "reactions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reactions")))]
 pub mod reactions;
 /// Interact with GitHub Repos.
+#[cfg(feature = "repos")]
+#[cfg_attr(docsrs, doc(cfg(feature = "repos")))]
 pub mod repos;
+pub mod retry;
 /// Provisioning of GitHub organization membership for SCIM-enabled providers.
+#[cfg(feature = "scim")]
+#[cfg_attr(docsrs, doc(cfg(feature = "scim")))]
 pub mod scim;
 /// Look for stuff on GitHub.
+#[cfg(feature = "search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "search")))]
 pub mod search;
 /// Retrieve secret scanning alerts from a repository.
+#[cfg(feature = "secret_scanning")]
+#[cfg_attr(docsrs, doc(cfg(feature = "secret_scanning")))]
 pub mod secret_scanning;
 /// Interact with GitHub Teams.
+#[cfg(feature = "teams")]
+#[cfg_attr(docsrs, doc(cfg(feature = "teams")))]
 pub mod teams;
 #[cfg(test)]
 mod tests;
 pub mod types;
 /// Interact with and view information about users and also current user.
+#[cfg(feature = "users")]
+#[cfg_attr(docsrs, doc(cfg(feature = "users")))]
 pub mod users;
 #[doc(hidden)]
 pub mod utils;
 
-use anyhow::{anyhow, Error, Result};
+pub use error::{Error, ErrorBody, Result};
 
 pub const DEFAULT_HOST: &str = "https://api.github.com";
 
@@ -267,17 +358,212 @@ mod progenitor_support {
     }
 }
 
+/// The body of a response that is not JSON, such as a PDF, an image or an archive.
+#[derive(Debug, Clone, Default)]
+pub struct Download {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: bytes::Bytes,
+}
+
+/// The body of a response that is not JSON, returned as a stream of chunks rather
+/// than read into memory all at once.
+pub struct DownloadStream {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: std::pin::Pin<Box<dyn futures::Stream<Item = Result<bytes::Bytes>> + Send>>,
+}
+
+impl std::fmt::Debug for DownloadStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DownloadStream")
+            .field("content_type", &self.content_type)
+            .finish()
+    }
+}
+
+/// Configures the HTTP client of a `Client`, with the defaults of `Client::new` unless
+/// told otherwise. Its `build` takes what `Client::new` does.
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    retry_policy: crate::retry::RetryPolicy,
+    max_rate_limit_wait: Option<std::time::Duration>,
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    user_agent: Option<String>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    root_certificates: Vec<reqwest::Certificate>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    identity: Option<reqwest::Identity>,
+    http_client: Option<reqwest_middleware::ClientWithMiddleware>,
+}
+
+impl ClientBuilder {
+    /// Set how failed requests are retried.
+    pub fn retry_policy(mut self, retry_policy: crate::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set how long requests wait for a rate limit to reset, at most,
+    /// `rate_limiter::MAX_WAIT` unless set.
+    pub fn max_rate_limit_wait(mut self, max_wait: std::time::Duration) -> Self {
+        self.max_rate_limit_wait = Some(max_wait);
+        self
+    }
+
+    /// Set how long each attempt at a request can take, from connecting to reading the
+    /// whole response. There is no limit unless set.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set how long connecting to the API can take.
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests through a proxy, like `reqwest::Proxy::https(url)`. Without one,
+    /// the proxies of the `HTTP_PROXY` and `HTTPS_PROXY` environment variables are used.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Set the `User-Agent` of requests.
+    pub fn user_agent<U>(mut self, user_agent: U) -> Self
+    where
+        U: ToString,
+    {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Trust the certificates signed by a certificate authority, along with those of
+    /// the system, like a private one of `reqwest::Certificate::from_pem(pem)`.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Authenticate to the API with a client certificate, for mutual TLS.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn identity(mut self, identity: reqwest::Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Send requests with a client of your own, with its own middleware. The other
+    /// settings of the builder don't apply to it, and it only retries or waits out
+    /// rate limits if it has a `retry::Retry` or a `rate_limiter::RateLimiter` of its
+    /// own.
+    pub fn http_client(mut self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /*
+     * The HTTP client, along with the rate limiter in its middleware.
+     */
+    fn http(
+        self,
+    ) -> Result<(
+        reqwest_middleware::ClientWithMiddleware,
+        crate::rate_limiter::RateLimiter,
+    )> {
+        if let Some(client) = self.http_client {
+            return Ok((client, Default::default()));
+        }
+
+        let mut rate_limiter =
+            crate::rate_limiter::RateLimiter::new().with_retry_policy(self.retry_policy.clone());
+        if let Some(max_wait) = self.max_rate_limit_wait {
+            rate_limiter = rate_limiter.with_max_wait(max_wait);
+        }
+
+        let mut http = reqwest::Client::builder();
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        for proxy in self.proxies {
+            http = http.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(user_agent);
+        }
+        #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+        {
+            for certificate in self.root_certificates {
+                http = http.add_root_certificate(certificate);
+            }
+            if let Some(identity) = self.identity {
+                http = http.identity(identity);
+            }
+        }
+
+        let client = reqwest_middleware::ClientBuilder::new(http.build()?)
+            // Trace HTTP requests. See the tracing crate to make use of these traces.
+            .with(reqwest_tracing::TracingMiddleware::default())
+            // Retry failed requests.
+            .with(crate::retry::Retry::new(self.retry_policy))
+            // Wait out the rate limits of the API, before the retries see them.
+            .with(rate_limiter.clone())
+            .build();
+
+        Ok((client, rate_limiter))
+    }
+}
+
+impl Client {
+    /// Configure the client before creating it.
+    pub fn builder() -> ClientBuilder {
+        Default::default()
+    }
+}
+
 /// Entrypoint for interacting with the API client.
 #[derive(Clone)]
 pub struct Client {
     host: String,
     agent: String,
     client: reqwest_middleware::ClientWithMiddleware,
+    rate_limiter: crate::rate_limiter::RateLimiter,
     credentials: Option<crate::auth::Credentials>,
     #[cfg(feature = "httpcache")]
     http_cache: crate::http_cache::BoxedHttpCache,
 }
 
+impl ClientBuilder {
+    /// Create the client, see `Client::new`. Its requests are sent with the `agent` as
+    /// their `User-Agent`, whatever the builder says.
+    pub fn build<A, C>(self, agent: A, credentials: C) -> Result<Client>
+    where
+        A: Into<String>,
+        C: Into<Option<crate::auth::Credentials>>,
+    {
+        let (http, rate_limiter) = self.http()?;
+        #[cfg(feature = "httpcache")]
+        let mut c = Client::custom(
+            DEFAULT_HOST,
+            agent,
+            credentials,
+            http,
+            <dyn crate::http_cache::HttpCache>::noop(),
+        );
+        #[cfg(not(feature = "httpcache"))]
+        let mut c = Client::custom(DEFAULT_HOST, agent, credentials, http);
+        c.rate_limiter = rate_limiter;
+        Ok(c)
+    }
+}
+
 impl Client {
     pub fn new<A, C>(agent: A, credentials: C) -> Result<Self>
     where
@@ -293,32 +579,9 @@ impl Client {
         A: Into<String>,
         C: Into<Option<crate::auth::Credentials>>,
     {
-        let http = reqwest::Client::builder().build()?;
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest_middleware::ClientBuilder::new(http)
-            // Trace HTTP requests. See the tracing crate to make use of these traces.
-            .with(reqwest_tracing::TracingMiddleware::default())
-            // Retry failed requests.
-            .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                |req: &reqwest::Request| req.try_clone().is_some(),
-            ))
-            .build();
-        #[cfg(feature = "httpcache")]
-        {
-            Ok(Self::custom(
-                host,
-                agent,
-                credentials,
-                client,
-                <dyn crate::http_cache::HttpCache>::noop(),
-            ))
-        }
-        #[cfg(not(feature = "httpcache"))]
-        {
-            Ok(Self::custom(host, agent, credentials, client))
-        }
+        let mut c = Self::builder().build(agent, credentials)?;
+        c.host = host.into();
+        Ok(c)
     }
 
     #[cfg(feature = "httpcache")]
@@ -338,6 +601,8 @@ impl Client {
             host: host.into(),
             agent: agent.into(),
             client: http,
+            // Only the middleware of `http` can wait out the rate limits.
+            rate_limiter: Default::default(),
             credentials: credentials.into(),
             http_cache,
         }
@@ -359,6 +624,8 @@ impl Client {
             host: host.into(),
             agent: agent.into(),
             client: http,
+            // Only the middleware of `http` can wait out the rate limits.
+            rate_limiter: Default::default(),
             credentials: credentials.into(),
         }
     }
@@ -370,6 +637,12 @@ impl Client {
         self.credentials = credentials.into();
     }
 
+    /// The status of the rate limits of the API, as of the last responses. Clients made
+    /// with `custom` don't keep track of it.
+    pub fn rate_limit_status(&self) -> Vec<crate::rate_limiter::RateLimitStatus> {
+        self.rate_limiter.status()
+    }
+
     fn credentials(
         &self,
         authentication: crate::auth::AuthenticationConstraint,
@@ -513,6 +786,7 @@ impl Client {
         let (remaining, reset, etag) = crate::utils::get_header_values(response.headers());
 
         let status = response.status();
+        let headers = response.headers().clone();
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -545,11 +819,11 @@ impl Client {
             let parsed_response = if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
+                crate::error::decode::<Out>(&response_body)
             };
-            parsed_response.map(|out| (link, out)).map_err(Error::from)
+            parsed_response.map(|out| (link, out))
         } else if status == http::StatusCode::NOT_MODIFIED {
             // only supported case is when client provides if-none-match
             // header when cargo builds with --cfg feature="httpcache"
@@ -557,20 +831,21 @@ impl Client {
             {
                 let body = instance2.http_cache.lookup_body(&uri3).unwrap();
                 let out = serde_json::from_str::<Out>(&body).unwrap();
+                // failing to look up the cached link isn't fatal, treat it as the last page
                 let link = match link {
-                    Some(link) => Ok(Some(link)),
+                    Some(link) => Some(link),
                     None => instance2
                         .http_cache
                         .lookup_next_link(&uri3)
-                        .map(|next_link| {
-                            next_link.map(|next| {
-                                let next = hyperx::header::LinkValue::new(next)
-                                    .push_rel(hyperx::header::RelationType::Next);
-                                hyperx::header::Link::new(vec![next])
-                            })
+                        .ok()
+                        .flatten()
+                        .map(|next| {
+                            let next = hyperx::header::LinkValue::new(next)
+                                .push_rel(hyperx::header::RelationType::Next);
+                            hyperx::header::Link::new(vec![next])
                         }),
                 };
-                link.map(|link| (link, out))
+                Ok((link, out))
             }
             #[cfg(not(feature = "httpcache"))]
             {
@@ -583,27 +858,126 @@ impl Client {
                         .duration_since(std::time::UNIX_EPOCH)
                         .unwrap()
                         .as_secs();
-                    anyhow!(
-                        "rate limit exceeded, will reset in {} seconds",
-                        u64::from(reset) - now
-                    )
-                }
-                _ => {
-                    if response_body.is_empty() {
-                        anyhow!("code: {}, empty response", status)
-                    } else {
-                        anyhow!(
-                            "code: {}, error: {:?}",
-                            status,
-                            String::from_utf8_lossy(&response_body),
-                        )
+                    crate::Error::RateLimited {
+                        reset: Some(std::time::Duration::from_secs(
+                            u64::from(reset).saturating_sub(now),
+                        )),
                     }
                 }
+                _ => crate::Error::from_response(status, headers, &response_body),
             };
             Err(error)
         }
     }
 
+    async fn request_raw(
+        &self,
+        method: http::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+        accept: &str,
+        authentication: crate::auth::AuthenticationConstraint,
+    ) -> Result<reqwest::Response> {
+        let (url, auth) = self.url_and_auth(uri, authentication).await?;
+
+        let instance = <&Client>::clone(&self);
+
+        let mut req = instance.client.request(method, url);
+
+        req = req.header(http::header::USER_AGENT, &*instance.agent);
+        req = req.header(
+            http::header::ACCEPT,
+            reqwest::header::HeaderValue::from_str(accept)?,
+        );
+
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+
+        if let Some(body) = body {
+            req = req.body(body);
+        }
+
+        Ok(req.send().await?)
+    }
+
+    async fn request_download(
+        &self,
+        method: http::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::Download> {
+        let response = self
+            .request_raw(
+                method,
+                &(self.host.clone() + uri),
+                body,
+                "*/*",
+                crate::auth::AuthenticationConstraint::Unconstrained,
+            )
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let content_type = response
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|c| c.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            Ok(crate::Download {
+                content_type,
+                body: response_body,
+            })
+        } else {
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    async fn request_download_stream(
+        &self,
+        method: http::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::DownloadStream> {
+        let response = self
+            .request_raw(
+                method,
+                &(self.host.clone() + uri),
+                body,
+                "*/*",
+                crate::auth::AuthenticationConstraint::Unconstrained,
+            )
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        if status.is_success() {
+            let content_type = response
+                .headers()
+                .get(http::header::CONTENT_TYPE)
+                .and_then(|c| c.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+
+            Ok(crate::DownloadStream {
+                content_type,
+                body: Box::pin(futures::TryStreamExt::map_err(
+                    response.bytes_stream(),
+                    Error::from,
+                )),
+            })
+        } else {
+            let response_body = response.bytes().await?;
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
     async fn request_entity<D>(
         &self,
         method: http::Method,
@@ -810,156 +1184,218 @@ impl Client {
     }
 
     /// Endpoints to manage GitHub Actions using the REST API.
+    #[cfg(feature = "actions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "actions")))]
     pub fn actions(&self) -> actions::Actions {
         actions::Actions::new(self.clone())
     }
 
     /// Activity APIs provide access to notifications, subscriptions, and timelines.
+    #[cfg(feature = "activity")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "activity")))]
     pub fn activity(&self) -> activity::Activity {
         activity::Activity::new(self.clone())
     }
 
     /// Information for integrations and installations.
+    #[cfg(feature = "apps")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "apps")))]
     pub fn apps(&self) -> apps::Apps {
         apps::Apps::new(self.clone())
     }
 
     /// Monitor charges and usage from Actions and Packages.
+    #[cfg(feature = "billing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "billing")))]
     pub fn billing(&self) -> billing::Billing {
         billing::Billing::new(self.clone())
     }
 
     /// Rich interactions with checks run by your integrations.
+    #[cfg(feature = "checks")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "checks")))]
     pub fn checks(&self) -> checks::Checks {
         checks::Checks::new(self.clone())
     }
 
     /// Retrieve code scanning alerts from a repository.
+    #[cfg(feature = "code_scanning")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "code_scanning")))]
     pub fn code_scanning(&self) -> code_scanning::CodeScanning {
         code_scanning::CodeScanning::new(self.clone())
     }
 
     /// Insight into codes of conduct for your communities.
+    #[cfg(feature = "codes_of_conduct")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "codes_of_conduct")))]
     pub fn codes_of_conduct(&self) -> codes_of_conduct::CodesOfConduct {
         codes_of_conduct::CodesOfConduct::new(self.clone())
     }
 
     /// List emojis available to use on GitHub.
+    #[cfg(feature = "emojis")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "emojis")))]
     pub fn emojis(&self) -> emojis::Emojis {
         emojis::Emojis::new(self.clone())
     }
 
     /// Administer a GitHub enterprise.
+    #[cfg(feature = "enterprise_admin")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "enterprise_admin")))]
     pub fn enterprise_admin(&self) -> enterprise_admin::EnterpriseAdmin {
         enterprise_admin::EnterpriseAdmin::new(self.clone())
     }
 
     /// View, modify your gists.
+    #[cfg(feature = "gists")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "gists")))]
     pub fn gists(&self) -> gists::Gists {
         gists::Gists::new(self.clone())
     }
 
     /// Raw Git functionality.
+    #[cfg(feature = "git")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "git")))]
     pub fn git(&self) -> git::Git {
         git::Git::new(self.clone())
     }
 
     /// View gitignore templates.
+    #[cfg(feature = "gitignore")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "gitignore")))]
     pub fn gitignore(&self) -> gitignore::Gitignore {
         gitignore::Gitignore::new(self.clone())
     }
 
     /// Owner or admin management of users interactions.
+    #[cfg(feature = "interactions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "interactions")))]
     pub fn interactions(&self) -> interactions::Interactions {
         interactions::Interactions::new(self.clone())
     }
 
     /// Interact with GitHub Issues.
+    #[cfg(feature = "issues")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "issues")))]
     pub fn issues(&self) -> issues::Issues {
         issues::Issues::new(self.clone())
     }
 
     /// View various OSS licenses.
+    #[cfg(feature = "licenses")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "licenses")))]
     pub fn licenses(&self) -> licenses::Licenses {
         licenses::Licenses::new(self.clone())
     }
 
     /// Render Github flavored markdown.
+    #[cfg(feature = "markdown")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
     pub fn markdown(&self) -> markdown::Markdown {
         markdown::Markdown::new(self.clone())
     }
 
     /// Endpoints that give information about the API.
+    #[cfg(feature = "meta")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "meta")))]
     pub fn meta(&self) -> meta::Meta {
         meta::Meta::new(self.clone())
     }
 
     /// Move projects to or from GitHub.
+    #[cfg(feature = "migrations")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "migrations")))]
     pub fn migrations(&self) -> migrations::Migrations {
         migrations::Migrations::new(self.clone())
     }
 
     /// Manage access of OAuth applications.
+    #[cfg(feature = "oauth_authorizations")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "oauth_authorizations")))]
     pub fn oauth_authorizations(&self) -> oauth_authorizations::OauthAuthorizations {
         oauth_authorizations::OauthAuthorizations::new(self.clone())
     }
 
     /// Interact with GitHub Orgs.
+    #[cfg(feature = "orgs")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "orgs")))]
     pub fn orgs(&self) -> orgs::Orgs {
         orgs::Orgs::new(self.clone())
     }
 
     /// Manage packages for authenticated users and organizations.
+    #[cfg(feature = "packages")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "packages")))]
     pub fn packages(&self) -> packages::Packages {
         packages::Packages::new(self.clone())
     }
 
     /// Interact with GitHub Projects.
+    #[cfg(feature = "projects")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "projects")))]
     pub fn projects(&self) -> projects::Projects {
         projects::Projects::new(self.clone())
     }
 
     /// Interact with GitHub Pull Requests.
+    #[cfg(feature = "pulls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pulls")))]
     pub fn pulls(&self) -> pulls::Pulls {
         pulls::Pulls::new(self.clone())
     }
 
     /// Check your current rate limit status.
+    #[cfg(feature = "rate_limit")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rate_limit")))]
     pub fn rate_limit(&self) -> rate_limit::RateLimit {
         rate_limit::RateLimit::new(self.clone())
     }
 
     /// Interact with reactions to various GitHub entities.
+    #[cfg(feature = "reactions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "reactions")))]
     pub fn reactions(&self) -> reactions::Reactions {
         reactions::Reactions::new(self.clone())
     }
 
     /// Interact with GitHub Repos.
+    #[cfg(feature = "repos")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "repos")))]
     pub fn repos(&self) -> repos::Repos {
         repos::Repos::new(self.clone())
     }
 
     /// Provisioning of GitHub organization membership for SCIM-enabled providers.
+    #[cfg(feature = "scim")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "scim")))]
     pub fn scim(&self) -> scim::Scim {
         scim::Scim::new(self.clone())
     }
 
     /// Look for stuff on GitHub.
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
     pub fn search(&self) -> search::Search {
         search::Search::new(self.clone())
     }
 
     /// Retrieve secret scanning alerts from a repository.
+    #[cfg(feature = "secret_scanning")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "secret_scanning")))]
     pub fn secret_scanning(&self) -> secret_scanning::SecretScanning {
         secret_scanning::SecretScanning::new(self.clone())
     }
 
     /// Interact with GitHub Teams.
+    #[cfg(feature = "teams")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "teams")))]
     pub fn teams(&self) -> teams::Teams {
         teams::Teams::new(self.clone())
     }
 
     /// Interact with and view information about users and also current user.
+    #[cfg(feature = "users")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "users")))]
     pub fn users(&self) -> users::Users {
         users::Users::new(self.clone())
     }
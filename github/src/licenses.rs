@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Licenses {
     pub client: Client,
@@ -23,24 +21,20 @@ impl Licenses {
      *
      * **Parameters:**
      *
-     * * `featured: bool`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &LicensesGetAllCommonlyUsedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_commonly_used(
+    pub async fn get_all_commonly_used_with(
         &self,
-        featured: bool,
-        per_page: i64,
-        page: i64,
+        params: &LicensesGetAllCommonlyUsedParams,
     ) -> Result<Vec<crate::types::LicenseSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if featured {
+        if let Some(featured) = &params.featured {
             query_args.push(("featured".to_string(), featured.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -49,6 +43,24 @@ impl Licenses {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_all_commonly_used_with` and pass the query parameters as a `LicensesGetAllCommonlyUsedParams` instead.
+     */
+    #[deprecated(note = "use `get_all_commonly_used_with` instead")]
+    pub async fn get_all_commonly_used(
+        &self,
+        featured: bool,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::LicenseSimple>> {
+        let params = LicensesGetAllCommonlyUsedParams {
+            featured: Some(featured).filter(|v| *v),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.get_all_commonly_used_with(&params).await
+    }
+
     /**
      * Get all commonly used licenses.
      *
@@ -59,19 +71,80 @@ impl Licenses {
      *
      *
      * FROM: <https://docs.github.com/rest/reference/licenses#get-all-commonly-used-licenses>
+     *
+     * **Parameters:**
+     *
+     * * `params: &LicensesGetAllCommonlyUsedParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_all_commonly_used_with(
+        &self,
+        params: &LicensesGetAllCommonlyUsedParams,
+    ) -> Result<Vec<crate::types::LicenseSimple>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(featured) = &params.featured {
+            query_args.push(("featured".to_string(), featured.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/licenses?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_all_commonly_used_with` and pass the query parameters as a `LicensesGetAllCommonlyUsedParams` instead.
      */
+    #[deprecated(note = "use `get_all_all_commonly_used_with` instead")]
     pub async fn get_all_all_commonly_used(
         &self,
         featured: bool,
     ) -> Result<Vec<crate::types::LicenseSimple>> {
+        let params = LicensesGetAllCommonlyUsedParams {
+            featured: Some(featured).filter(|v| *v),
+            ..Default::default()
+        };
+        self.get_all_all_commonly_used_with(&params).await
+    }
+
+    /**
+     * Get all commonly used licenses.
+     *
+     * This function performs a `GET` to the `/licenses` endpoint.
+     *
+     * As opposed to `get_all_all_commonly_used`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/licenses#get-all-commonly-used-licenses>
+     */
+    pub fn get_all_commonly_used_stream(
+        &self,
+        params: &LicensesGetAllCommonlyUsedParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LicenseSimple> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if featured {
+        if let Some(featured) = &params.featured {
             query_args.push(("featured".to_string(), featured.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/licenses?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -87,7 +160,7 @@ impl Licenses {
      *
      * * `license: &str`
      */
-    pub async fn get(&self, license: &str) -> Result<crate::types::LicenseData> {
+    pub async fn get(&self, license: &str) -> Result<crate::types::License> {
         let url = format!(
             "/licenses/{}",
             crate::progenitor_support::encode_path(license),
@@ -126,3 +199,96 @@ impl Licenses {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Licenses::get_all_commonly_used_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct LicensesGetAllCommonlyUsedParams {
+    pub featured: Option<bool>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl LicensesGetAllCommonlyUsedParams {
+    pub fn featured(mut self, featured: bool) -> Self {
+        self.featured = Some(featured);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The functions of [`Licenses`], for code that takes them as a trait, so that it can be
+/// tested with the `MockLicensesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait LicensesApi: Send + Sync {
+    /// See [`Licenses::get_all_commonly_used_with`].
+    async fn get_all_commonly_used_with(
+        &self,
+        params: &LicensesGetAllCommonlyUsedParams,
+    ) -> Result<Vec<crate::types::LicenseSimple>>;
+
+    /// See [`Licenses::get_all_all_commonly_used_with`].
+    async fn get_all_all_commonly_used_with(
+        &self,
+        params: &LicensesGetAllCommonlyUsedParams,
+    ) -> Result<Vec<crate::types::LicenseSimple>>;
+
+    /// See [`Licenses::get_all_commonly_used_stream`].
+    fn get_all_commonly_used_stream(
+        &self,
+        params: &LicensesGetAllCommonlyUsedParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LicenseSimple>;
+
+    /// See [`Licenses::get`].
+    async fn get(&self, license: &str) -> Result<crate::types::License>;
+
+    /// See [`Licenses::get_for_repo`].
+    async fn get_for_repo(&self, owner: &str, repo: &str) -> Result<crate::types::LicenseContent>;
+}
+
+#[async_trait::async_trait]
+impl LicensesApi for Licenses {
+    async fn get_all_commonly_used_with(
+        &self,
+        params: &LicensesGetAllCommonlyUsedParams,
+    ) -> Result<Vec<crate::types::LicenseSimple>> {
+        Licenses::get_all_commonly_used_with(self, params).await
+    }
+
+    async fn get_all_all_commonly_used_with(
+        &self,
+        params: &LicensesGetAllCommonlyUsedParams,
+    ) -> Result<Vec<crate::types::LicenseSimple>> {
+        Licenses::get_all_all_commonly_used_with(self, params).await
+    }
+
+    fn get_all_commonly_used_stream(
+        &self,
+        params: &LicensesGetAllCommonlyUsedParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LicenseSimple> {
+        Licenses::get_all_commonly_used_stream(self, params, start, max_items)
+    }
+
+    async fn get(&self, license: &str) -> Result<crate::types::License> {
+        Licenses::get(self, license).await
+    }
+
+    async fn get_for_repo(&self, owner: &str, repo: &str) -> Result<crate::types::LicenseContent> {
+        Licenses::get_for_repo(self, owner, repo).await
+    }
+}
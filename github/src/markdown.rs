@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Markdown {
     pub client: Client,
@@ -42,3 +40,31 @@ impl Markdown {
         self.client.post(&url, Some(body.into())).await
     }
 }
+
+/// The functions of [`Markdown`], for code that takes them as a trait, so that it can be
+/// tested with the `MockMarkdownApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait MarkdownApi: Send + Sync {
+    /// See [`Markdown::render`].
+    async fn render(&self, body: &crate::types::MarkdownRenderRequest) -> Result<String>;
+
+    /// See [`Markdown::render_raw`].
+    async fn render_raw<T>(&self, body: T) -> Result<String>
+    where
+        T: Into<reqwest::Body> + Send + 'static;
+}
+
+#[async_trait::async_trait]
+impl MarkdownApi for Markdown {
+    async fn render(&self, body: &crate::types::MarkdownRenderRequest) -> Result<String> {
+        Markdown::render(self, body).await
+    }
+
+    async fn render_raw<T>(&self, body: T) -> Result<String>
+    where
+        T: Into<reqwest::Body> + Send + 'static,
+    {
+        Markdown::render_raw(self, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Meta {
     pub client: Client,
@@ -54,11 +52,11 @@ impl Meta {
      *
      * **Parameters:**
      *
-     * * `s: &str` -- The words to show in Octocat's speech bubble.
+     * * `params: &MetaGetOctocatParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_octocat(&self, s: &str) -> Result<String> {
+    pub async fn get_octocat_with(&self, params: &MetaGetOctocatParams) -> Result<String> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !s.is_empty() {
+        if let Some(s) = &params.s {
             query_args.push(("s".to_string(), s.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -67,6 +65,17 @@ impl Meta {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_octocat_with` and pass the query parameters as a `MetaGetOctocatParams` instead.
+     */
+    #[deprecated(note = "use `get_octocat_with` instead")]
+    pub async fn get_octocat(&self, s: &str) -> Result<String> {
+        let params = MetaGetOctocatParams {
+            s: Some(s.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_octocat_with(&params).await
+    }
+
     /**
      * Get the Zen of GitHub.
      *
@@ -79,3 +88,54 @@ impl Meta {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Meta::get_octocat_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MetaGetOctocatParams {
+    /// The words to show in Octocat's speech bubble.
+    pub s: Option<String>,
+}
+
+impl MetaGetOctocatParams {
+    pub fn s<T: ToString>(mut self, s: T) -> Self {
+        self.s = Some(s.to_string());
+        self
+    }
+}
+
+/// The functions of [`Meta`], for code that takes them as a trait, so that it can be
+/// tested with the `MockMetaApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait MetaApi: Send + Sync {
+    /// See [`Meta::root`].
+    async fn root(&self) -> Result<crate::types::MetaRootResponse>;
+
+    /// See [`Meta::get`].
+    async fn get(&self) -> Result<crate::types::ApiOverview>;
+
+    /// See [`Meta::get_octocat_with`].
+    async fn get_octocat_with(&self, params: &MetaGetOctocatParams) -> Result<String>;
+
+    /// See [`Meta::get_zen`].
+    async fn get_zen(&self) -> Result<String>;
+}
+
+#[async_trait::async_trait]
+impl MetaApi for Meta {
+    async fn root(&self) -> Result<crate::types::MetaRootResponse> {
+        Meta::root(self).await
+    }
+
+    async fn get(&self) -> Result<crate::types::ApiOverview> {
+        Meta::get(self).await
+    }
+
+    async fn get_octocat_with(&self, params: &MetaGetOctocatParams) -> Result<String> {
+        Meta::get_octocat_with(self, params).await
+    }
+
+    async fn get_zen(&self) -> Result<String> {
+        Meta::get_zen(self).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Migrations {
     pub client: Client,
@@ -24,25 +22,21 @@ impl Migrations {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `exclude: &[String]` -- Exclude attributes from the API response to improve performance.
+     * * `params: &MigrationsListForOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_org(
+    pub async fn list_for_org_with(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
-        exclude: &[String],
+        params: &MigrationsListForOrgParams,
     ) -> Result<Vec<crate::types::Migration>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude.is_empty() {
+        if let Some(exclude) = &params.exclude {
             query_args.push(("exclude".to_string(), exclude.join(" ")));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -55,6 +49,25 @@ impl Migrations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_org_with` and pass the query parameters as a `MigrationsListForOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_for_org_with` instead")]
+    pub async fn list_for_org(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+        exclude: &[String],
+    ) -> Result<Vec<crate::types::Migration>> {
+        let params = MigrationsListForOrgParams {
+            exclude: Some(exclude.to_vec()).filter(|v| !v.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_for_org_with(org, &params).await
+    }
+
     /**
      * List organization migrations.
      *
@@ -65,14 +78,66 @@ impl Migrations {
      * Lists the most recent migrations.
      *
      * FROM: <https://docs.github.com/rest/reference/migrations#list-organization-migrations>
+     *
+     * **Parameters:**
+     *
+     * * `params: &MigrationsListForOrgParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_org_with(
+        &self,
+        org: &str,
+        params: &MigrationsListForOrgParams,
+    ) -> Result<Vec<crate::types::Migration>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(exclude) = &params.exclude {
+            query_args.push(("exclude".to_string(), exclude.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/migrations?{}",
+            crate::progenitor_support::encode_path(org),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_org_with` and pass the query parameters as a `MigrationsListForOrgParams` instead.
      */
+    #[deprecated(note = "use `list_all_for_org_with` instead")]
     pub async fn list_all_for_org(
         &self,
         org: &str,
         exclude: &[String],
     ) -> Result<Vec<crate::types::Migration>> {
+        let params = MigrationsListForOrgParams {
+            exclude: Some(exclude.to_vec()).filter(|v| !v.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_org_with(org, &params).await
+    }
+
+    /**
+     * List organization migrations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the most recent migrations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-organization-migrations>
+     */
+    pub fn list_for_org_stream(
+        &self,
+        org: &str,
+        params: &MigrationsListForOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Migration> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude.is_empty() {
+        if let Some(exclude) = &params.exclude {
             query_args.push(("exclude".to_string(), exclude.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -82,7 +147,22 @@ impl Migrations {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -101,7 +181,7 @@ impl Migrations {
     pub async fn start_for_org(
         &self,
         org: &str,
-        body: &crate::types::MigrationsStartRequest,
+        body: &crate::types::MigrationsStartOrgRequest,
     ) -> Result<crate::types::Migration> {
         let url = format!(
             "/orgs/{}/migrations",
@@ -133,16 +213,16 @@ impl Migrations {
      *
      * * `org: &str`
      * * `migration_id: i64` -- migration_id parameter.
-     * * `exclude: &[String]` -- Exclude attributes from the API response to improve performance.
+     * * `params: &MigrationsGetStatusForOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_status_for_org(
+    pub async fn get_status_for_org_with(
         &self,
         org: &str,
         migration_id: i64,
-        exclude: &[String],
+        params: &MigrationsGetStatusForOrgParams,
     ) -> Result<crate::types::Migration> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude.is_empty() {
+        if let Some(exclude) = &params.exclude {
             query_args.push(("exclude".to_string(), exclude.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -156,6 +236,23 @@ impl Migrations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_status_for_org_with` and pass the query parameters as a `MigrationsGetStatusForOrgParams` instead.
+     */
+    #[deprecated(note = "use `get_status_for_org_with` instead")]
+    pub async fn get_status_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+        exclude: &[String],
+    ) -> Result<crate::types::Migration> {
+        let params = MigrationsGetStatusForOrgParams {
+            exclude: Some(exclude.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_status_for_org_with(org, migration_id, &params)
+            .await
+    }
+
     /**
      * Download an organization migration archive.
      *
@@ -170,14 +267,47 @@ impl Migrations {
      * * `org: &str`
      * * `migration_id: i64` -- migration_id parameter.
      */
-    pub async fn download_archive_for_org(&self, org: &str, migration_id: i64) -> Result<()> {
+    pub async fn download_archive_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> Result<crate::Download> {
         let url = format!(
             "/orgs/{}/migrations/{}/archive",
             crate::progenitor_support::encode_path(org),
             crate::progenitor_support::encode_path(&migration_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Download an organization migration archive.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations/{migration_id}/archive` endpoint.
+     *
+     * As opposed to `download_archive_for_org`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Fetches the URL to a migration archive.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#download-an-organization-migration-archive>
+     */
+    pub async fn download_archive_for_org_stream(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> Result<crate::DownloadStream> {
+        let url = format!(
+            "/orgs/{}/migrations/{}/archive",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(&migration_id.to_string()),
+        );
+
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
     }
 
     /**
@@ -248,21 +378,19 @@ impl Migrations {
      *
      * * `org: &str`
      * * `migration_id: i64` -- migration_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &MigrationsListReposForOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_repos_for_org(
+    pub async fn list_repos_for_org_with(
         &self,
         org: &str,
         migration_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &MigrationsListReposForOrgParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -276,6 +404,25 @@ impl Migrations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_repos_for_org_with` and pass the query parameters as a `MigrationsListReposForOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_repos_for_org_with` instead")]
+    pub async fn list_repos_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = MigrationsListReposForOrgParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_repos_for_org_with(org, migration_id, &params)
+            .await
+    }
+
     /**
      * List repositories in an organization migration.
      *
@@ -301,6 +448,48 @@ impl Migrations {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List repositories in an organization migration.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_all_repos_for_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List all the repositories for this organization migration.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-in-an-organization-migration>
+     */
+    pub fn list_repos_for_org_stream(
+        &self,
+        org: &str,
+        migration_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        let url = format!(
+            "/orgs/{}/migrations/{}/repositories",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(&migration_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get an import status.
      *
@@ -460,16 +649,16 @@ impl Migrations {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `since: i64` -- A user ID. Only return users with an ID greater than this ID.
+     * * `params: &MigrationsGetCommitAuthorsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_commit_authors(
+    pub async fn get_commit_authors_with(
         &self,
         owner: &str,
         repo: &str,
-        since: i64,
+        params: &MigrationsGetCommitAuthorsParams,
     ) -> Result<Vec<crate::types::PorterAuthor>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if since > 0 {
+        if let Some(since) = &params.since {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -483,6 +672,22 @@ impl Migrations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_commit_authors_with` and pass the query parameters as a `MigrationsGetCommitAuthorsParams` instead.
+     */
+    #[deprecated(note = "use `get_commit_authors_with` instead")]
+    pub async fn get_commit_authors(
+        &self,
+        owner: &str,
+        repo: &str,
+        since: i64,
+    ) -> Result<Vec<crate::types::PorterAuthor>> {
+        let params = MigrationsGetCommitAuthorsParams {
+            since: Some(since).filter(|v| *v > 0),
+        };
+        self.get_commit_authors_with(owner, repo, &params).await
+    }
+
     /**
      * Get commit authors.
      *
@@ -495,15 +700,71 @@ impl Migrations {
      * This endpoint and the [Map a commit author](https://docs.github.com/rest/reference/migrations#map-a-commit-author) endpoint allow you to provide correct Git author information.
      *
      * FROM: <https://docs.github.com/rest/reference/migrations#get-commit-authors>
+     *
+     * **Parameters:**
+     *
+     * * `params: &MigrationsGetCommitAuthorsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_commit_authors_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &MigrationsGetCommitAuthorsParams,
+    ) -> Result<Vec<crate::types::PorterAuthor>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/import/authors?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_commit_authors_with` and pass the query parameters as a `MigrationsGetCommitAuthorsParams` instead.
      */
+    #[deprecated(note = "use `get_all_commit_authors_with` instead")]
     pub async fn get_all_commit_authors(
         &self,
         owner: &str,
         repo: &str,
         since: i64,
     ) -> Result<Vec<crate::types::PorterAuthor>> {
+        let params = MigrationsGetCommitAuthorsParams {
+            since: Some(since).filter(|v| *v > 0),
+        };
+        self.get_all_commit_authors_with(owner, repo, &params).await
+    }
+
+    /**
+     * Get commit authors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/authors` endpoint.
+     *
+     * As opposed to `get_all_commit_authors`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Each type of source control system represents authors in a different way. For example, a Git commit author has a display name and an email address, but a Subversion commit author just has a username. The GitHub Importer will make the author information valid, but the author might not be correct. For example, it will change the bare Subversion username `hubot` into something like `hubot <hubot@12341234-abab-fefe-8787-fedcba987654>`.
+     *
+     * This endpoint and the [Map a commit author](https://docs.github.com/rest/reference/migrations#map-a-commit-author) endpoint allow you to provide correct Git author information.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-commit-authors>
+     */
+    pub fn get_commit_authors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &MigrationsGetCommitAuthorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PorterAuthor> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if since > 0 {
+        if let Some(since) = &params.since {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -514,7 +775,22 @@ impl Migrations {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -537,7 +813,7 @@ impl Migrations {
         owner: &str,
         repo: &str,
         author_id: i64,
-        body: &crate::types::Author,
+        body: &crate::types::MigrationsMapCommitAuthorRequest,
     ) -> Result<crate::types::PorterAuthor> {
         let url = format!(
             "/repos/{}/{}/import/authors/{}",
@@ -604,6 +880,48 @@ impl Migrations {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get large files.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/import/large_files` endpoint.
+     *
+     * As opposed to `get_all_large_files`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List files larger than 100MB found during the import
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#get-large-files>
+     */
+    pub fn get_large_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PorterLargeFile> {
+        let url = format!(
+            "/repos/{}/{}/import/large_files",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Update Git LFS preference.
      *
@@ -646,19 +964,17 @@ impl Migrations {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &MigrationsListForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_authenticated_user(
+    pub async fn list_for_authenticated_user_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &MigrationsListForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Migration>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -667,6 +983,22 @@ impl Migrations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_authenticated_user_with` and pass the query parameters as a `MigrationsListForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_for_authenticated_user_with` instead")]
+    pub async fn list_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Migration>> {
+        let params = MigrationsListForAuthenticatedUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_for_authenticated_user_with(&params).await
+    }
+
     /**
      * List user migrations.
      *
@@ -683,6 +1015,41 @@ impl Migrations {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List user migrations.
+     *
+     * This function performs a `GET` to the `/user/migrations` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all migrations a user has started.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-user-migrations>
+     */
+    pub fn list_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Migration> {
+        let url = "/user/migrations".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Start a user migration.
      *
@@ -721,15 +1088,15 @@ impl Migrations {
      * **Parameters:**
      *
      * * `migration_id: i64` -- migration_id parameter.
-     * * `exclude: &[String]` -- The list of events for the GitHub app.
+     * * `params: &MigrationsGetStatusForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_status_for_authenticated_user(
+    pub async fn get_status_for_authenticated_user_with(
         &self,
         migration_id: i64,
-        exclude: &[String],
+        params: &MigrationsGetStatusForAuthenticatedUserParams,
     ) -> Result<crate::types::Migration> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude.is_empty() {
+        if let Some(exclude) = &params.exclude {
             query_args.push(("exclude".to_string(), exclude.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -742,6 +1109,22 @@ impl Migrations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_status_for_authenticated_user_with` and pass the query parameters as a `MigrationsGetStatusForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `get_status_for_authenticated_user_with` instead")]
+    pub async fn get_status_for_authenticated_user(
+        &self,
+        migration_id: i64,
+        exclude: &[String],
+    ) -> Result<crate::types::Migration> {
+        let params = MigrationsGetStatusForAuthenticatedUserParams {
+            exclude: Some(exclude.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_status_for_authenticated_user_with(migration_id, &params)
+            .await
+    }
+
     /**
      * Download a user migration archive.
      *
@@ -775,19 +1158,69 @@ impl Migrations {
      *
      * * `migration_id: i64` -- migration_id parameter.
      */
-    pub async fn get_archive_for_authenticated_user(&self, migration_id: i64) -> Result<()> {
+    pub async fn get_archive_for_authenticated_user(
+        &self,
+        migration_id: i64,
+    ) -> Result<crate::Download> {
         let url = format!(
             "/user/migrations/{}/archive",
             crate::progenitor_support::encode_path(&migration_id.to_string()),
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
     }
 
     /**
-     * Delete a user migration archive.
+     * Download a user migration archive.
      *
-     * This function performs a `DELETE` to the `/user/migrations/{migration_id}/archive` endpoint.
+     * This function performs a `GET` to the `/user/migrations/{migration_id}/archive` endpoint.
+     *
+     * As opposed to `get_archive_for_authenticated_user`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Fetches the URL to download the migration archive as a `tar.gz` file. Depending on the resources your repository uses, the migration archive can contain JSON files with data for these objects:
+     *
+     * *   attachments
+     * *   bases
+     * *   commit\_comments
+     * *   issue\_comments
+     * *   issue\_events
+     * *   issues
+     * *   milestones
+     * *   organizations
+     * *   projects
+     * *   protected\_branches
+     * *   pull\_request\_reviews
+     * *   pull\_requests
+     * *   releases
+     * *   repositories
+     * *   review\_comments
+     * *   schema
+     * *   users
+     *
+     * The archive will also contain an `attachments` directory that includes all attachment files uploaded to GitHub.com and a `repositories` directory that contains the repository's Git data.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#download-a-user-migration-archive>
+     */
+    pub async fn get_archive_for_authenticated_user_stream(
+        &self,
+        migration_id: i64,
+    ) -> Result<crate::DownloadStream> {
+        let url = format!(
+            "/user/migrations/{}/archive",
+            crate::progenitor_support::encode_path(&migration_id.to_string()),
+        );
+
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Delete a user migration archive.
+     *
+     * This function performs a `DELETE` to the `/user/migrations/{migration_id}/archive` endpoint.
      *
      * Deletes a previous migration archive. Downloadable migration archives are automatically deleted after seven days. Migration metadata, which is returned in the [List user migrations](https://docs.github.com/rest/reference/migrations#list-user-migrations) and [Get a user migration status](https://docs.github.com/rest/reference/migrations#get-a-user-migration-status) endpoints, will continue to be available even after an archive is deleted.
      *
@@ -846,20 +1279,18 @@ impl Migrations {
      * **Parameters:**
      *
      * * `migration_id: i64` -- migration_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &MigrationsListReposForUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_repos_for_user(
+    pub async fn list_repos_for_user_with(
         &self,
         migration_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &MigrationsListReposForUserParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -872,6 +1303,23 @@ impl Migrations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_repos_for_user_with` and pass the query parameters as a `MigrationsListReposForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_repos_for_user_with` instead")]
+    pub async fn list_repos_for_user(
+        &self,
+        migration_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = MigrationsListReposForUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_repos_for_user_with(migration_id, &params).await
+    }
+
     /**
      * List repositories for a user migration.
      *
@@ -894,4 +1342,716 @@ impl Migrations {
 
         self.client.get_all_pages(&url, None).await
     }
+
+    /**
+     * List repositories for a user migration.
+     *
+     * This function performs a `GET` to the `/user/migrations/{migration_id}/repositories` endpoint.
+     *
+     * As opposed to `list_all_repos_for_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all the repositories for this user migration.
+     *
+     * FROM: <https://docs.github.com/rest/reference/migrations#list-repositories-for-a-user-migration>
+     */
+    pub fn list_repos_for_user_stream(
+        &self,
+        migration_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        let url = format!(
+            "/user/migrations/{}/repositories",
+            crate::progenitor_support::encode_path(&migration_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Migrations::list_for_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationsListForOrgParams {
+    /// Exclude attributes from the API response to improve performance.
+    pub exclude: Option<Vec<String>>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl MigrationsListForOrgParams {
+    pub fn exclude(mut self, exclude: Vec<String>) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Migrations::get_status_for_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationsGetStatusForOrgParams {
+    /// Exclude attributes from the API response to improve performance.
+    pub exclude: Option<Vec<String>>,
+}
+
+impl MigrationsGetStatusForOrgParams {
+    pub fn exclude(mut self, exclude: Vec<String>) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+}
+
+/// The query parameters of `Migrations::list_repos_for_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationsListReposForOrgParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl MigrationsListReposForOrgParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Migrations::get_commit_authors_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationsGetCommitAuthorsParams {
+    /// A user ID. Only return users with an ID greater than this ID.
+    pub since: Option<i64>,
+}
+
+impl MigrationsGetCommitAuthorsParams {
+    pub fn since(mut self, since: i64) -> Self {
+        self.since = Some(since);
+        self
+    }
+}
+
+/// The query parameters of `Migrations::list_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationsListForAuthenticatedUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl MigrationsListForAuthenticatedUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Migrations::get_status_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationsGetStatusForAuthenticatedUserParams {
+    /// The list of events for the GitHub app.
+    pub exclude: Option<Vec<String>>,
+}
+
+impl MigrationsGetStatusForAuthenticatedUserParams {
+    pub fn exclude(mut self, exclude: Vec<String>) -> Self {
+        self.exclude = Some(exclude);
+        self
+    }
+}
+
+/// The query parameters of `Migrations::list_repos_for_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationsListReposForUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl MigrationsListReposForUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The functions of [`Migrations`], for code that takes them as a trait, so that it can be
+/// tested with the `MockMigrationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait MigrationsApi: Send + Sync {
+    /// See [`Migrations::list_for_org_with`].
+    async fn list_for_org_with(
+        &self,
+        org: &str,
+        params: &MigrationsListForOrgParams,
+    ) -> Result<Vec<crate::types::Migration>>;
+
+    /// See [`Migrations::list_all_for_org_with`].
+    async fn list_all_for_org_with(
+        &self,
+        org: &str,
+        params: &MigrationsListForOrgParams,
+    ) -> Result<Vec<crate::types::Migration>>;
+
+    /// See [`Migrations::list_for_org_stream`].
+    fn list_for_org_stream(
+        &self,
+        org: &str,
+        params: &MigrationsListForOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Migration>;
+
+    /// See [`Migrations::start_for_org`].
+    async fn start_for_org(
+        &self,
+        org: &str,
+        body: &crate::types::MigrationsStartOrgRequest,
+    ) -> Result<crate::types::Migration>;
+
+    /// See [`Migrations::get_status_for_org_with`].
+    async fn get_status_for_org_with(
+        &self,
+        org: &str,
+        migration_id: i64,
+        params: &MigrationsGetStatusForOrgParams,
+    ) -> Result<crate::types::Migration>;
+
+    /// See [`Migrations::download_archive_for_org`].
+    async fn download_archive_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> Result<crate::Download>;
+
+    /// See [`Migrations::download_archive_for_org_stream`].
+    async fn download_archive_for_org_stream(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`Migrations::delete_archive_for_org`].
+    async fn delete_archive_for_org(&self, org: &str, migration_id: i64) -> Result<()>;
+
+    /// See [`Migrations::unlock_repo_for_org`].
+    async fn unlock_repo_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+        repo_name: &str,
+    ) -> Result<()>;
+
+    /// See [`Migrations::list_repos_for_org_with`].
+    async fn list_repos_for_org_with(
+        &self,
+        org: &str,
+        migration_id: i64,
+        params: &MigrationsListReposForOrgParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Migrations::list_all_repos_for_org`].
+    async fn list_all_repos_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Migrations::list_repos_for_org_stream`].
+    fn list_repos_for_org_stream(
+        &self,
+        org: &str,
+        migration_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository>;
+
+    /// See [`Migrations::get_import_status`].
+    async fn get_import_status(&self, owner: &str, repo: &str) -> Result<crate::types::Import>;
+
+    /// See [`Migrations::start_import`].
+    async fn start_import(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::MigrationsStartImportRequest,
+    ) -> Result<crate::types::Import>;
+
+    /// See [`Migrations::cancel_import`].
+    async fn cancel_import(&self, owner: &str, repo: &str) -> Result<()>;
+
+    /// See [`Migrations::update_import`].
+    async fn update_import(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::MigrationsUpdateImportRequest,
+    ) -> Result<crate::types::Import>;
+
+    /// See [`Migrations::get_commit_authors_with`].
+    async fn get_commit_authors_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &MigrationsGetCommitAuthorsParams,
+    ) -> Result<Vec<crate::types::PorterAuthor>>;
+
+    /// See [`Migrations::get_all_commit_authors_with`].
+    async fn get_all_commit_authors_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &MigrationsGetCommitAuthorsParams,
+    ) -> Result<Vec<crate::types::PorterAuthor>>;
+
+    /// See [`Migrations::get_commit_authors_stream`].
+    fn get_commit_authors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &MigrationsGetCommitAuthorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PorterAuthor>;
+
+    /// See [`Migrations::map_commit_author`].
+    async fn map_commit_author(
+        &self,
+        owner: &str,
+        repo: &str,
+        author_id: i64,
+        body: &crate::types::MigrationsMapCommitAuthorRequest,
+    ) -> Result<crate::types::PorterAuthor>;
+
+    /// See [`Migrations::get_large_files`].
+    async fn get_large_files(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::PorterLargeFile>>;
+
+    /// See [`Migrations::get_all_large_files`].
+    async fn get_all_large_files(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::PorterLargeFile>>;
+
+    /// See [`Migrations::get_large_files_stream`].
+    fn get_large_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PorterLargeFile>;
+
+    /// See [`Migrations::set_lfs_preference`].
+    async fn set_lfs_preference(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::MigrationsSetLfsPreferenceRequest,
+    ) -> Result<crate::types::Import>;
+
+    /// See [`Migrations::list_for_authenticated_user_with`].
+    async fn list_for_authenticated_user_with(
+        &self,
+        params: &MigrationsListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Migration>>;
+
+    /// See [`Migrations::list_all_for_authenticated_user`].
+    async fn list_all_for_authenticated_user(&self) -> Result<Vec<crate::types::Migration>>;
+
+    /// See [`Migrations::list_for_authenticated_user_stream`].
+    fn list_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Migration>;
+
+    /// See [`Migrations::start_for_authenticated_user`].
+    async fn start_for_authenticated_user(
+        &self,
+        body: &crate::types::MigrationsStartRequest,
+    ) -> Result<crate::types::Migration>;
+
+    /// See [`Migrations::get_status_for_authenticated_user_with`].
+    async fn get_status_for_authenticated_user_with(
+        &self,
+        migration_id: i64,
+        params: &MigrationsGetStatusForAuthenticatedUserParams,
+    ) -> Result<crate::types::Migration>;
+
+    /// See [`Migrations::get_archive_for_authenticated_user`].
+    async fn get_archive_for_authenticated_user(
+        &self,
+        migration_id: i64,
+    ) -> Result<crate::Download>;
+
+    /// See [`Migrations::get_archive_for_authenticated_user_stream`].
+    async fn get_archive_for_authenticated_user_stream(
+        &self,
+        migration_id: i64,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`Migrations::delete_archive_for_authenticated_user`].
+    async fn delete_archive_for_authenticated_user(&self, migration_id: i64) -> Result<()>;
+
+    /// See [`Migrations::unlock_repo_for_authenticated_user`].
+    async fn unlock_repo_for_authenticated_user(
+        &self,
+        migration_id: i64,
+        repo_name: &str,
+    ) -> Result<()>;
+
+    /// See [`Migrations::list_repos_for_user_with`].
+    async fn list_repos_for_user_with(
+        &self,
+        migration_id: i64,
+        params: &MigrationsListReposForUserParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Migrations::list_all_repos_for_user`].
+    async fn list_all_repos_for_user(
+        &self,
+        migration_id: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Migrations::list_repos_for_user_stream`].
+    fn list_repos_for_user_stream(
+        &self,
+        migration_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository>;
+}
+
+#[async_trait::async_trait]
+impl MigrationsApi for Migrations {
+    async fn list_for_org_with(
+        &self,
+        org: &str,
+        params: &MigrationsListForOrgParams,
+    ) -> Result<Vec<crate::types::Migration>> {
+        Migrations::list_for_org_with(self, org, params).await
+    }
+
+    async fn list_all_for_org_with(
+        &self,
+        org: &str,
+        params: &MigrationsListForOrgParams,
+    ) -> Result<Vec<crate::types::Migration>> {
+        Migrations::list_all_for_org_with(self, org, params).await
+    }
+
+    fn list_for_org_stream(
+        &self,
+        org: &str,
+        params: &MigrationsListForOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Migration> {
+        Migrations::list_for_org_stream(self, org, params, start, max_items)
+    }
+
+    async fn start_for_org(
+        &self,
+        org: &str,
+        body: &crate::types::MigrationsStartOrgRequest,
+    ) -> Result<crate::types::Migration> {
+        Migrations::start_for_org(self, org, body).await
+    }
+
+    async fn get_status_for_org_with(
+        &self,
+        org: &str,
+        migration_id: i64,
+        params: &MigrationsGetStatusForOrgParams,
+    ) -> Result<crate::types::Migration> {
+        Migrations::get_status_for_org_with(self, org, migration_id, params).await
+    }
+
+    async fn download_archive_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> Result<crate::Download> {
+        Migrations::download_archive_for_org(self, org, migration_id).await
+    }
+
+    async fn download_archive_for_org_stream(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> Result<crate::DownloadStream> {
+        Migrations::download_archive_for_org_stream(self, org, migration_id).await
+    }
+
+    async fn delete_archive_for_org(&self, org: &str, migration_id: i64) -> Result<()> {
+        Migrations::delete_archive_for_org(self, org, migration_id).await
+    }
+
+    async fn unlock_repo_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+        repo_name: &str,
+    ) -> Result<()> {
+        Migrations::unlock_repo_for_org(self, org, migration_id, repo_name).await
+    }
+
+    async fn list_repos_for_org_with(
+        &self,
+        org: &str,
+        migration_id: i64,
+        params: &MigrationsListReposForOrgParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Migrations::list_repos_for_org_with(self, org, migration_id, params).await
+    }
+
+    async fn list_all_repos_for_org(
+        &self,
+        org: &str,
+        migration_id: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Migrations::list_all_repos_for_org(self, org, migration_id).await
+    }
+
+    fn list_repos_for_org_stream(
+        &self,
+        org: &str,
+        migration_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        Migrations::list_repos_for_org_stream(self, org, migration_id, start, max_items)
+    }
+
+    async fn get_import_status(&self, owner: &str, repo: &str) -> Result<crate::types::Import> {
+        Migrations::get_import_status(self, owner, repo).await
+    }
+
+    async fn start_import(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::MigrationsStartImportRequest,
+    ) -> Result<crate::types::Import> {
+        Migrations::start_import(self, owner, repo, body).await
+    }
+
+    async fn cancel_import(&self, owner: &str, repo: &str) -> Result<()> {
+        Migrations::cancel_import(self, owner, repo).await
+    }
+
+    async fn update_import(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::MigrationsUpdateImportRequest,
+    ) -> Result<crate::types::Import> {
+        Migrations::update_import(self, owner, repo, body).await
+    }
+
+    async fn get_commit_authors_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &MigrationsGetCommitAuthorsParams,
+    ) -> Result<Vec<crate::types::PorterAuthor>> {
+        Migrations::get_commit_authors_with(self, owner, repo, params).await
+    }
+
+    async fn get_all_commit_authors_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &MigrationsGetCommitAuthorsParams,
+    ) -> Result<Vec<crate::types::PorterAuthor>> {
+        Migrations::get_all_commit_authors_with(self, owner, repo, params).await
+    }
+
+    fn get_commit_authors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &MigrationsGetCommitAuthorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PorterAuthor> {
+        Migrations::get_commit_authors_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn map_commit_author(
+        &self,
+        owner: &str,
+        repo: &str,
+        author_id: i64,
+        body: &crate::types::MigrationsMapCommitAuthorRequest,
+    ) -> Result<crate::types::PorterAuthor> {
+        Migrations::map_commit_author(self, owner, repo, author_id, body).await
+    }
+
+    async fn get_large_files(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::PorterLargeFile>> {
+        Migrations::get_large_files(self, owner, repo).await
+    }
+
+    async fn get_all_large_files(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::PorterLargeFile>> {
+        Migrations::get_all_large_files(self, owner, repo).await
+    }
+
+    fn get_large_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PorterLargeFile> {
+        Migrations::get_large_files_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn set_lfs_preference(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::MigrationsSetLfsPreferenceRequest,
+    ) -> Result<crate::types::Import> {
+        Migrations::set_lfs_preference(self, owner, repo, body).await
+    }
+
+    async fn list_for_authenticated_user_with(
+        &self,
+        params: &MigrationsListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Migration>> {
+        Migrations::list_for_authenticated_user_with(self, params).await
+    }
+
+    async fn list_all_for_authenticated_user(&self) -> Result<Vec<crate::types::Migration>> {
+        Migrations::list_all_for_authenticated_user(self).await
+    }
+
+    fn list_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Migration> {
+        Migrations::list_for_authenticated_user_stream(self, start, max_items)
+    }
+
+    async fn start_for_authenticated_user(
+        &self,
+        body: &crate::types::MigrationsStartRequest,
+    ) -> Result<crate::types::Migration> {
+        Migrations::start_for_authenticated_user(self, body).await
+    }
+
+    async fn get_status_for_authenticated_user_with(
+        &self,
+        migration_id: i64,
+        params: &MigrationsGetStatusForAuthenticatedUserParams,
+    ) -> Result<crate::types::Migration> {
+        Migrations::get_status_for_authenticated_user_with(self, migration_id, params).await
+    }
+
+    async fn get_archive_for_authenticated_user(
+        &self,
+        migration_id: i64,
+    ) -> Result<crate::Download> {
+        Migrations::get_archive_for_authenticated_user(self, migration_id).await
+    }
+
+    async fn get_archive_for_authenticated_user_stream(
+        &self,
+        migration_id: i64,
+    ) -> Result<crate::DownloadStream> {
+        Migrations::get_archive_for_authenticated_user_stream(self, migration_id).await
+    }
+
+    async fn delete_archive_for_authenticated_user(&self, migration_id: i64) -> Result<()> {
+        Migrations::delete_archive_for_authenticated_user(self, migration_id).await
+    }
+
+    async fn unlock_repo_for_authenticated_user(
+        &self,
+        migration_id: i64,
+        repo_name: &str,
+    ) -> Result<()> {
+        Migrations::unlock_repo_for_authenticated_user(self, migration_id, repo_name).await
+    }
+
+    async fn list_repos_for_user_with(
+        &self,
+        migration_id: i64,
+        params: &MigrationsListReposForUserParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Migrations::list_repos_for_user_with(self, migration_id, params).await
+    }
+
+    async fn list_all_repos_for_user(
+        &self,
+        migration_id: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Migrations::list_all_repos_for_user(self, migration_id).await
+    }
+
+    fn list_repos_for_user_stream(
+        &self,
+        migration_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        Migrations::list_repos_for_user_stream(self, migration_id, start, max_items)
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct OauthAuthorizations {
     pub client: Client,
@@ -25,24 +23,20 @@ impl OauthAuthorizations {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `client_id: &str` -- The client ID of your GitHub app.
+     * * `params: &OauthAuthorizationsListGrantsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_grants(
+    pub async fn list_grants_with(
         &self,
-        per_page: i64,
-        page: i64,
-        client_id: &str,
+        params: &OauthAuthorizationsListGrantsParams,
     ) -> Result<Vec<crate::types::ApplicationGrant>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_id.is_empty() {
+        if let Some(client_id) = &params.client_id {
             query_args.push(("client_id".to_string(), client_id.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -51,6 +45,24 @@ impl OauthAuthorizations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_grants_with` and pass the query parameters as a `OauthAuthorizationsListGrantsParams` instead.
+     */
+    #[deprecated(note = "use `list_grants_with` instead")]
+    pub async fn list_grants(
+        &self,
+        per_page: i64,
+        page: i64,
+        client_id: &str,
+    ) -> Result<Vec<crate::types::ApplicationGrant>> {
+        let params = OauthAuthorizationsListGrantsParams {
+            client_id: Some(client_id.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_grants_with(&params).await
+    }
+
     /**
      * List your grants.
      *
@@ -63,19 +75,82 @@ impl OauthAuthorizations {
      * You can use this API to list the set of OAuth applications that have been granted access to your account. Unlike the [list your authorizations](https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations) API, this API does not manage individual tokens. This API will return one entry for each OAuth application that has been granted access to your account, regardless of the number of tokens an application has generated for your user. The list of OAuth applications returned matches what is shown on [the application authorizations settings screen within GitHub](https://github.com/settings/applications#authorized). The `scopes` returned are the union of scopes authorized for the application. For example, if an application has one token with `repo` scope and another token with `user` scope, the grant will return `["repo", "user"]`.
      *
      * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-grants>
+     *
+     * **Parameters:**
+     *
+     * * `params: &OauthAuthorizationsListGrantsParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn list_all_grants_with(
+        &self,
+        params: &OauthAuthorizationsListGrantsParams,
+    ) -> Result<Vec<crate::types::ApplicationGrant>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(client_id) = &params.client_id {
+            query_args.push(("client_id".to_string(), client_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/applications/grants?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_grants_with` and pass the query parameters as a `OauthAuthorizationsListGrantsParams` instead.
+     */
+    #[deprecated(note = "use `list_all_grants_with` instead")]
     pub async fn list_all_grants(
         &self,
         client_id: &str,
     ) -> Result<Vec<crate::types::ApplicationGrant>> {
+        let params = OauthAuthorizationsListGrantsParams {
+            client_id: Some(client_id.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_grants_with(&params).await
+    }
+
+    /**
+     * List your grants.
+     *
+     * This function performs a `GET` to the `/applications/grants` endpoint.
+     *
+     * As opposed to `list_all_grants`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **Deprecation Notice:** GitHub will discontinue the [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations/), which is used by integrations to create personal access tokens and OAuth tokens, and you must now create these tokens using our [web application flow](https://docs.github.com/developers/apps/authorizing-oauth-apps#web-application-flow). The [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations) will be removed on November, 13, 2020. For more information, including scheduled brownouts, see the [blog post](https://developer.github.com/changes/2020-02-14-deprecating-oauth-auth-endpoint/).
+     *
+     * You can use this API to list the set of OAuth applications that have been granted access to your account. Unlike the [list your authorizations](https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations) API, this API does not manage individual tokens. This API will return one entry for each OAuth application that has been granted access to your account, regardless of the number of tokens an application has generated for your user. The list of OAuth applications returned matches what is shown on [the application authorizations settings screen within GitHub](https://github.com/settings/applications#authorized). The `scopes` returned are the union of scopes authorized for the application. For example, if an application has one token with `repo` scope and another token with `user` scope, the grant will return `["repo", "user"]`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-grants>
+     */
+    pub fn list_grants_stream(
+        &self,
+        params: &OauthAuthorizationsListGrantsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ApplicationGrant> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_id.is_empty() {
+        if let Some(client_id) = &params.client_id {
             query_args.push(("client_id".to_string(), client_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/applications/grants?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -135,24 +210,20 @@ impl OauthAuthorizations {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `client_id: &str` -- The client ID of your GitHub app.
+     * * `params: &OauthAuthorizationsListAuthorizationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_authorizations(
+    pub async fn list_authorizations_with(
         &self,
-        per_page: i64,
-        page: i64,
-        client_id: &str,
+        params: &OauthAuthorizationsListAuthorizationsParams,
     ) -> Result<Vec<crate::types::Authorization>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_id.is_empty() {
+        if let Some(client_id) = &params.client_id {
             query_args.push(("client_id".to_string(), client_id.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -161,6 +232,24 @@ impl OauthAuthorizations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_authorizations_with` and pass the query parameters as a `OauthAuthorizationsListAuthorizationsParams` instead.
+     */
+    #[deprecated(note = "use `list_authorizations_with` instead")]
+    pub async fn list_authorizations(
+        &self,
+        per_page: i64,
+        page: i64,
+        client_id: &str,
+    ) -> Result<Vec<crate::types::Authorization>> {
+        let params = OauthAuthorizationsListAuthorizationsParams {
+            client_id: Some(client_id.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_authorizations_with(&params).await
+    }
+
     /**
      * List your authorizations.
      *
@@ -171,19 +260,80 @@ impl OauthAuthorizations {
      * **Deprecation Notice:** GitHub will discontinue the [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations), which is used by integrations to create personal access tokens and OAuth tokens, and you must now create these tokens using our [web application flow](https://docs.github.com/apps/building-oauth-apps/authorizing-oauth-apps/#web-application-flow). The [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations) will be removed on November, 13, 2020. For more information, including scheduled brownouts, see the [blog post](https://developer.github.com/changes/2020-02-14-deprecating-oauth-auth-endpoint/).
      *
      * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations>
+     *
+     * **Parameters:**
+     *
+     * * `params: &OauthAuthorizationsListAuthorizationsParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn list_all_authorizations_with(
+        &self,
+        params: &OauthAuthorizationsListAuthorizationsParams,
+    ) -> Result<Vec<crate::types::Authorization>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(client_id) = &params.client_id {
+            query_args.push(("client_id".to_string(), client_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/authorizations?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_authorizations_with` and pass the query parameters as a `OauthAuthorizationsListAuthorizationsParams` instead.
+     */
+    #[deprecated(note = "use `list_all_authorizations_with` instead")]
     pub async fn list_all_authorizations(
         &self,
         client_id: &str,
     ) -> Result<Vec<crate::types::Authorization>> {
+        let params = OauthAuthorizationsListAuthorizationsParams {
+            client_id: Some(client_id.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_authorizations_with(&params).await
+    }
+
+    /**
+     * List your authorizations.
+     *
+     * This function performs a `GET` to the `/authorizations` endpoint.
+     *
+     * As opposed to `list_all_authorizations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **Deprecation Notice:** GitHub will discontinue the [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations), which is used by integrations to create personal access tokens and OAuth tokens, and you must now create these tokens using our [web application flow](https://docs.github.com/apps/building-oauth-apps/authorizing-oauth-apps/#web-application-flow). The [OAuth Authorizations API](https://docs.github.com/rest/reference/oauth-authorizations) will be removed on November, 13, 2020. For more information, including scheduled brownouts, see the [blog post](https://developer.github.com/changes/2020-02-14-deprecating-oauth-auth-endpoint/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/oauth-authorizations#list-your-authorizations>
+     */
+    pub fn list_authorizations_stream(
+        &self,
+        params: &OauthAuthorizationsListAuthorizationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Authorization> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_id.is_empty() {
+        if let Some(client_id) = &params.client_id {
             query_args.push(("client_id".to_string(), client_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/authorizations?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -367,3 +517,252 @@ impl OauthAuthorizations {
             .await
     }
 }
+
+/// The query parameters of `OauthAuthorizations::list_grants_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OauthAuthorizationsListGrantsParams {
+    /// The client ID of your GitHub app.
+    pub client_id: Option<String>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl OauthAuthorizationsListGrantsParams {
+    pub fn client_id<T: ToString>(mut self, client_id: T) -> Self {
+        self.client_id = Some(client_id.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `OauthAuthorizations::list_authorizations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OauthAuthorizationsListAuthorizationsParams {
+    /// The client ID of your GitHub app.
+    pub client_id: Option<String>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl OauthAuthorizationsListAuthorizationsParams {
+    pub fn client_id<T: ToString>(mut self, client_id: T) -> Self {
+        self.client_id = Some(client_id.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The functions of [`OauthAuthorizations`], for code that takes them as a trait, so that it can be
+/// tested with the `MockOauthAuthorizationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait OauthAuthorizationsApi: Send + Sync {
+    /// See [`OauthAuthorizations::list_grants_with`].
+    async fn list_grants_with(
+        &self,
+        params: &OauthAuthorizationsListGrantsParams,
+    ) -> Result<Vec<crate::types::ApplicationGrant>>;
+
+    /// See [`OauthAuthorizations::list_all_grants_with`].
+    async fn list_all_grants_with(
+        &self,
+        params: &OauthAuthorizationsListGrantsParams,
+    ) -> Result<Vec<crate::types::ApplicationGrant>>;
+
+    /// See [`OauthAuthorizations::list_grants_stream`].
+    fn list_grants_stream(
+        &self,
+        params: &OauthAuthorizationsListGrantsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ApplicationGrant>;
+
+    /// See [`OauthAuthorizations::get_grant`].
+    async fn get_grant(&self, grant_id: i64) -> Result<crate::types::ApplicationGrant>;
+
+    /// See [`OauthAuthorizations::delete_grant`].
+    async fn delete_grant(&self, grant_id: i64) -> Result<()>;
+
+    /// See [`OauthAuthorizations::list_authorizations_with`].
+    async fn list_authorizations_with(
+        &self,
+        params: &OauthAuthorizationsListAuthorizationsParams,
+    ) -> Result<Vec<crate::types::Authorization>>;
+
+    /// See [`OauthAuthorizations::list_all_authorizations_with`].
+    async fn list_all_authorizations_with(
+        &self,
+        params: &OauthAuthorizationsListAuthorizationsParams,
+    ) -> Result<Vec<crate::types::Authorization>>;
+
+    /// See [`OauthAuthorizations::list_authorizations_stream`].
+    fn list_authorizations_stream(
+        &self,
+        params: &OauthAuthorizationsListAuthorizationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Authorization>;
+
+    /// See [`OauthAuthorizations::create_authorization`].
+    async fn create_authorization(
+        &self,
+        body: &crate::types::OauthAuthorizationsCreateAuthorizationRequest,
+    ) -> Result<crate::types::Authorization>;
+
+    /// See [`OauthAuthorizations::get_or_create_authorization_for_app`].
+    async fn get_or_create_authorization_for_app(
+        &self,
+        client_id: &str,
+        body: &crate::types::OauthAuthorizationsGetCreateAuthorizationAppRequest,
+    ) -> Result<crate::types::Authorization>;
+
+    /// See [`OauthAuthorizations::get_or_create_authorization_for_app_and_fingerprint`].
+    async fn get_or_create_authorization_for_app_and_fingerprint(
+        &self,
+        client_id: &str,
+        fingerprint: &str,
+        body: &crate::types::OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest,
+    ) -> Result<crate::types::Authorization>;
+
+    /// See [`OauthAuthorizations::get_authorization`].
+    async fn get_authorization(&self, authorization_id: i64)
+        -> Result<crate::types::Authorization>;
+
+    /// See [`OauthAuthorizations::delete_authorization`].
+    async fn delete_authorization(&self, authorization_id: i64) -> Result<()>;
+
+    /// See [`OauthAuthorizations::update_authorization`].
+    async fn update_authorization(
+        &self,
+        authorization_id: i64,
+        body: &crate::types::OauthAuthorizationsUpdateAuthorizationRequest,
+    ) -> Result<crate::types::Authorization>;
+}
+
+#[async_trait::async_trait]
+impl OauthAuthorizationsApi for OauthAuthorizations {
+    async fn list_grants_with(
+        &self,
+        params: &OauthAuthorizationsListGrantsParams,
+    ) -> Result<Vec<crate::types::ApplicationGrant>> {
+        OauthAuthorizations::list_grants_with(self, params).await
+    }
+
+    async fn list_all_grants_with(
+        &self,
+        params: &OauthAuthorizationsListGrantsParams,
+    ) -> Result<Vec<crate::types::ApplicationGrant>> {
+        OauthAuthorizations::list_all_grants_with(self, params).await
+    }
+
+    fn list_grants_stream(
+        &self,
+        params: &OauthAuthorizationsListGrantsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ApplicationGrant> {
+        OauthAuthorizations::list_grants_stream(self, params, start, max_items)
+    }
+
+    async fn get_grant(&self, grant_id: i64) -> Result<crate::types::ApplicationGrant> {
+        OauthAuthorizations::get_grant(self, grant_id).await
+    }
+
+    async fn delete_grant(&self, grant_id: i64) -> Result<()> {
+        OauthAuthorizations::delete_grant(self, grant_id).await
+    }
+
+    async fn list_authorizations_with(
+        &self,
+        params: &OauthAuthorizationsListAuthorizationsParams,
+    ) -> Result<Vec<crate::types::Authorization>> {
+        OauthAuthorizations::list_authorizations_with(self, params).await
+    }
+
+    async fn list_all_authorizations_with(
+        &self,
+        params: &OauthAuthorizationsListAuthorizationsParams,
+    ) -> Result<Vec<crate::types::Authorization>> {
+        OauthAuthorizations::list_all_authorizations_with(self, params).await
+    }
+
+    fn list_authorizations_stream(
+        &self,
+        params: &OauthAuthorizationsListAuthorizationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Authorization> {
+        OauthAuthorizations::list_authorizations_stream(self, params, start, max_items)
+    }
+
+    async fn create_authorization(
+        &self,
+        body: &crate::types::OauthAuthorizationsCreateAuthorizationRequest,
+    ) -> Result<crate::types::Authorization> {
+        OauthAuthorizations::create_authorization(self, body).await
+    }
+
+    async fn get_or_create_authorization_for_app(
+        &self,
+        client_id: &str,
+        body: &crate::types::OauthAuthorizationsGetCreateAuthorizationAppRequest,
+    ) -> Result<crate::types::Authorization> {
+        OauthAuthorizations::get_or_create_authorization_for_app(self, client_id, body).await
+    }
+
+    async fn get_or_create_authorization_for_app_and_fingerprint(
+        &self,
+        client_id: &str,
+        fingerprint: &str,
+        body: &crate::types::OauthAuthorizationsGetCreateAuthorizationAppFingerprintRequest,
+    ) -> Result<crate::types::Authorization> {
+        OauthAuthorizations::get_or_create_authorization_for_app_and_fingerprint(
+            self,
+            client_id,
+            fingerprint,
+            body,
+        )
+        .await
+    }
+
+    async fn get_authorization(
+        &self,
+        authorization_id: i64,
+    ) -> Result<crate::types::Authorization> {
+        OauthAuthorizations::get_authorization(self, authorization_id).await
+    }
+
+    async fn delete_authorization(&self, authorization_id: i64) -> Result<()> {
+        OauthAuthorizations::delete_authorization(self, authorization_id).await
+    }
+
+    async fn update_authorization(
+        &self,
+        authorization_id: i64,
+        body: &crate::types::OauthAuthorizationsUpdateAuthorizationRequest,
+    ) -> Result<crate::types::Authorization> {
+        OauthAuthorizations::update_authorization(self, authorization_id, body).await
+    }
+}
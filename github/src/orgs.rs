@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Orgs {
     pub client: Client,
@@ -25,19 +23,17 @@ impl Orgs {
      *
      * **Parameters:**
      *
-     * * `since: i64` -- An organization ID. Only return organizations with an ID greater than this ID.
-     * * `per_page: i64` -- Results per page (max 100).
+     * * `params: &OrgsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        since: i64,
-        per_page: i64,
+        params: &OrgsListParams,
     ) -> Result<Vec<crate::types::OrganizationSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if since > 0 {
+        if let Some(since) = &params.since {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -46,6 +42,22 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `OrgsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        since: i64,
+        per_page: i64,
+    ) -> Result<Vec<crate::types::OrganizationSimple>> {
+        let params = OrgsListParams {
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since: Some(since).filter(|v| *v > 0),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * List organizations.
      *
@@ -58,10 +70,17 @@ impl Orgs {
      * **Note:** Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of organizations.
      *
      * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations>
+     *
+     * **Parameters:**
+     *
+     * * `params: &OrgsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(&self, since: i64) -> Result<Vec<crate::types::OrganizationSimple>> {
+    pub async fn list_all_with(
+        &self,
+        params: &OrgsListParams,
+    ) -> Result<Vec<crate::types::OrganizationSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if since > 0 {
+        if let Some(since) = &params.since {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -70,6 +89,62 @@ impl Orgs {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `OrgsListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(&self, since: i64) -> Result<Vec<crate::types::OrganizationSimple>> {
+        let params = OrgsListParams {
+            since: Some(since).filter(|v| *v > 0),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * List organizations.
+     *
+     * This function performs a `GET` to the `/organizations` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all organizations, in the order that they were created on GitHub.
+     *
+     * **Note:** Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of organizations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations>
+     */
+    pub fn list_stream(
+        &self,
+        params: &OrgsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationSimple> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/organizations?{}", query_);
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get an organization.
      *
@@ -132,53 +207,33 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `phrase: &str` -- A search phrase. For more information, see [Searching the audit log](https://docs.github.com/github/setting-up-and-managing-organizations-and-teams/reviewing-the-audit-log-for-your-organization#searching-the-audit-log).
-     * * `include: crate::types::Include` -- The event types to include:
-     *  
-     *  - `web` - returns web (non-Git) events
-     *  - `git` - returns Git events
-     *  - `all` - returns both web and Git events
-     *  
-     *  The default is `web`.
-     * * `after: &str` -- A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events after this cursor.
-     * * `before: &str` -- A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events before this cursor.
-     * * `order: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsGetAuditLogParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_audit_log(
+    pub async fn get_audit_log_with(
         &self,
         org: &str,
-        phrase: &str,
-        include: crate::types::Include,
-        after: &str,
-        before: &str,
-        order: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &OrgsGetAuditLogParams,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !before.is_empty() {
+        if let Some(before) = &params.before {
             query_args.push(("before".to_string(), before.to_string()));
         }
-        if !include.to_string().is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
-        if !order.to_string().is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !phrase.is_empty() {
+        if let Some(phrase) = &params.phrase {
             query_args.push(("phrase".to_string(), phrase.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -191,6 +246,33 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_audit_log_with` and pass the query parameters as a `OrgsGetAuditLogParams` instead.
+     */
+    #[deprecated(note = "use `get_audit_log_with` instead")]
+    pub async fn get_audit_log(
+        &self,
+        org: &str,
+        phrase: &str,
+        include: crate::types::Include,
+        after: &str,
+        before: &str,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::AuditLogEvent>> {
+        let params = OrgsGetAuditLogParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            before: Some(before.to_string()).filter(|s| !s.is_empty()),
+            include: Some(include).filter(|v| !v.to_string().is_empty()),
+            order: Some(order).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            phrase: Some(phrase.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_audit_log_with(org, &params).await
+    }
+
     /**
      * Get the audit log for an organization.
      *
@@ -203,7 +285,46 @@ impl Orgs {
      * To use this endpoint, you must be an organization owner, and you must use an access token with the `admin:org` scope. GitHub Apps must have the `organization_administration` read permission to use this endpoint.
      *
      * FROM: <https://docs.github.com/rest/reference/orgs#get-audit-log>
+     *
+     * **Parameters:**
+     *
+     * * `params: &OrgsGetAuditLogParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_audit_log_with(
+        &self,
+        org: &str,
+        params: &OrgsGetAuditLogParams,
+    ) -> Result<Vec<crate::types::AuditLogEvent>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(after) = &params.after {
+            query_args.push(("after".to_string(), after.to_string()));
+        }
+        if let Some(before) = &params.before {
+            query_args.push(("before".to_string(), before.to_string()));
+        }
+        if let Some(include) = &params.include {
+            query_args.push(("include".to_string(), include.to_string()));
+        }
+        if let Some(order) = &params.order {
+            query_args.push(("order".to_string(), order.to_string()));
+        }
+        if let Some(phrase) = &params.phrase {
+            query_args.push(("phrase".to_string(), phrase.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/audit-log?{}",
+            crate::progenitor_support::encode_path(org),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_audit_log_with` and pass the query parameters as a `OrgsGetAuditLogParams` instead.
      */
+    #[deprecated(note = "use `get_all_audit_log_with` instead")]
     pub async fn get_all_audit_log(
         &self,
         org: &str,
@@ -213,20 +334,51 @@ impl Orgs {
         before: &str,
         order: crate::types::Order,
     ) -> Result<Vec<crate::types::AuditLogEvent>> {
+        let params = OrgsGetAuditLogParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            before: Some(before.to_string()).filter(|s| !s.is_empty()),
+            include: Some(include).filter(|v| !v.to_string().is_empty()),
+            order: Some(order).filter(|v| !v.to_string().is_empty()),
+            phrase: Some(phrase.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_audit_log_with(org, &params).await
+    }
+
+    /**
+     * Get the audit log for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/audit-log` endpoint.
+     *
+     * As opposed to `get_all_audit_log`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Gets the audit log for an organization. For more information, see "[Reviewing the audit log for your organization](https://docs.github.com/github/setting-up-and-managing-organizations-and-teams/reviewing-the-audit-log-for-your-organization)."
+     *
+     * To use this endpoint, you must be an organization owner, and you must use an access token with the `admin:org` scope. GitHub Apps must have the `organization_administration` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-audit-log>
+     */
+    pub fn get_audit_log_stream(
+        &self,
+        org: &str,
+        params: &OrgsGetAuditLogParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuditLogEvent> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !before.is_empty() {
+        if let Some(before) = &params.before {
             query_args.push(("before".to_string(), before.to_string()));
         }
-        if !include.to_string().is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
-        if !order.to_string().is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !phrase.is_empty() {
+        if let Some(phrase) = &params.phrase {
             query_args.push(("phrase".to_string(), phrase.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -236,7 +388,22 @@ impl Orgs {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -281,6 +448,46 @@ impl Orgs {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List users blocked by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/blocks` endpoint.
+     *
+     * As opposed to `list_all_blocked_users`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List the users blocked by an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-users-blocked-by-an-organization>
+     */
+    pub fn list_blocked_users_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        let url = format!(
+            "/orgs/{}/blocks",
+            crate::progenitor_support::encode_path(org),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Check if a user is blocked by an organization.
      *
@@ -405,6 +612,48 @@ impl Orgs {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List SAML SSO authorizations for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/credential-authorizations` endpoint.
+     *
+     * As opposed to `list_all_saml_sso_authorizations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Listing and deleting credential authorizations is available to organizations with GitHub Enterprise Cloud. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products).
+     *
+     * An authenticated organization owner with the `read:org` scope can list all credential authorizations for an organization that uses SAML single sign-on (SSO). The credentials are either personal access tokens or SSH keys that organization members have authorized for the organization. For more information, see [About authentication with SAML single sign-on](https://help.github.com/en/articles/about-authentication-with-saml-single-sign-on).
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-saml-sso-authorizations-for-an-organization>
+     */
+    pub fn list_saml_sso_authorizations_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CredentialAuthorization> {
+        let url = format!(
+            "/orgs/{}/credential-authorizations",
+            crate::progenitor_support::encode_path(org),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Remove a SAML SSO authorization for an organization.
      *
@@ -443,20 +692,18 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListFailedInvitationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_failed_invitations(
+    pub async fn list_failed_invitations_with(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListFailedInvitationsParams,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -469,6 +716,23 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_failed_invitations_with` and pass the query parameters as a `OrgsListFailedInvitationsParams` instead.
+     */
+    #[deprecated(note = "use `list_failed_invitations_with` instead")]
+    pub async fn list_failed_invitations(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        let params = OrgsListFailedInvitationsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_failed_invitations_with(org, &params).await
+    }
+
     /**
      * List failed organization invitations.
      *
@@ -492,6 +756,46 @@ impl Orgs {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List failed organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/failed_invitations` endpoint.
+     *
+     * As opposed to `list_all_failed_invitations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * The return hash contains `failed_at` and `failed_reason` fields which represent the time at which the invitation failed and the reason for the failure.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-failed-organization-invitations>
+     */
+    pub fn list_failed_invitations_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationInvitation> {
+        let url = format!(
+            "/orgs/{}/failed_invitations",
+            crate::progenitor_support::encode_path(org),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List organization webhooks.
      *
@@ -504,20 +808,18 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListWebhooksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_webhooks(
+    pub async fn list_webhooks_with(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListWebhooksParams,
     ) -> Result<Vec<crate::types::OrgHook>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -530,6 +832,23 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_webhooks_with` and pass the query parameters as a `OrgsListWebhooksParams` instead.
+     */
+    #[deprecated(note = "use `list_webhooks_with` instead")]
+    pub async fn list_webhooks(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::OrgHook>> {
+        let params = OrgsListWebhooksParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_webhooks_with(org, &params).await
+    }
+
     /**
      * List organization webhooks.
      *
@@ -550,6 +869,46 @@ impl Orgs {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List organization webhooks.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks` endpoint.
+     *
+     * As opposed to `list_all_webhooks`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-webhooks>
+     */
+    pub fn list_webhooks_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrgHook> {
+        let url = format!(
+            "/orgs/{}/hooks",
+            crate::progenitor_support::encode_path(org),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create an organization webhook.
      *
@@ -707,7 +1066,7 @@ impl Orgs {
         &self,
         org: &str,
         hook_id: i64,
-        body: &crate::types::AppsUpdateWebhookConfigAppRequest,
+        body: &crate::types::OrgsUpdateWebhookConfigOrgRequest,
     ) -> Result<crate::types::WebhookConfig> {
         let url = format!(
             "/orgs/{}/hooks/{}/config",
@@ -733,21 +1092,19 @@ impl Orgs {
      *
      * * `org: &str`
      * * `hook_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `cursor: &str` -- Used for pagination: the starting delivery from which the page of deliveries is fetched. Refer to the `link` header for the next and previous page cursors.
+     * * `params: &OrgsListWebhookDeliveriesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_webhook_deliveries(
+    pub async fn list_webhook_deliveries_with(
         &self,
         org: &str,
         hook_id: i64,
-        per_page: i64,
-        cursor: &str,
+        params: &OrgsListWebhookDeliveriesParams,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -761,6 +1118,25 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_webhook_deliveries_with` and pass the query parameters as a `OrgsListWebhookDeliveriesParams` instead.
+     */
+    #[deprecated(note = "use `list_webhook_deliveries_with` instead")]
+    pub async fn list_webhook_deliveries(
+        &self,
+        org: &str,
+        hook_id: i64,
+        per_page: i64,
+        cursor: &str,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        let params = OrgsListWebhookDeliveriesParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_webhook_deliveries_with(org, hook_id, &params)
+            .await
+    }
+
     /**
      * List deliveries for an organization webhook.
      *
@@ -771,15 +1147,19 @@ impl Orgs {
      * Returns a list of webhook deliveries for a webhook configured in an organization.
      *
      * FROM: <https://docs.github.com/rest/reference/orgs#list-deliveries-for-an-organization-webhook>
+     *
+     * **Parameters:**
+     *
+     * * `params: &OrgsListWebhookDeliveriesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_webhook_deliveries(
+    pub async fn list_all_webhook_deliveries_with(
         &self,
         org: &str,
         hook_id: i64,
-        cursor: &str,
+        params: &OrgsListWebhookDeliveriesParams,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -794,18 +1174,85 @@ impl Orgs {
     }
 
     /**
-     * Get a webhook delivery for an organization webhook.
-     *
-     * This function performs a `GET` to the `/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}` endpoint.
-     *
-     * Returns a delivery for a webhook configured in an organization.
-     *
-     * FROM: <https://docs.github.com/rest/reference/orgs#get-a-webhook-delivery-for-an-organization-webhook>
-     *
-     * **Parameters:**
-     *
-     * * `org: &str`
-     * * `hook_id: i64`
+     * Deprecated: use `list_all_webhook_deliveries_with` and pass the query parameters as a `OrgsListWebhookDeliveriesParams` instead.
+     */
+    #[deprecated(note = "use `list_all_webhook_deliveries_with` instead")]
+    pub async fn list_all_webhook_deliveries(
+        &self,
+        org: &str,
+        hook_id: i64,
+        cursor: &str,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        let params = OrgsListWebhookDeliveriesParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_webhook_deliveries_with(org, hook_id, &params)
+            .await
+    }
+
+    /**
+     * List deliveries for an organization webhook.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns a list of webhook deliveries for a webhook configured in an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-deliveries-for-an-organization-webhook>
+     */
+    pub fn list_webhook_deliveries_stream(
+        &self,
+        org: &str,
+        hook_id: i64,
+        params: &OrgsListWebhookDeliveriesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::HookDeliveryItem> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(cursor) = &params.cursor {
+            query_args.push(("cursor".to_string(), cursor.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/hooks/{}/deliveries?{}",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * Get a webhook delivery for an organization webhook.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/hooks/{hook_id}/deliveries/{delivery_id}` endpoint.
+     *
+     * Returns a delivery for a webhook configured in an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#get-a-webhook-delivery-for-an-organization-webhook>
+     *
+     * **Parameters:**
+     *
+     * * `org: &str`
+     * * `hook_id: i64`
      * * `delivery_id: i64`
      */
     pub async fn get_webhook_delivery(
@@ -844,7 +1291,7 @@ impl Orgs {
         org: &str,
         hook_id: i64,
         delivery_id: i64,
-    ) -> Result<()> {
+    ) -> Result<crate::types::AcceptedResponse> {
         let url = format!(
             "/orgs/{}/hooks/{}/deliveries/{}/attempts",
             crate::progenitor_support::encode_path(org),
@@ -891,20 +1338,18 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListAppInstallationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_app_installations(
+    pub async fn list_app_installations_with(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
-    ) -> Result<crate::types::AppsListInstallationsResponse> {
+        params: &OrgsListAppInstallationsParams,
+    ) -> Result<crate::types::OrgsListAppInstallationsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -917,6 +1362,23 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_app_installations_with` and pass the query parameters as a `OrgsListAppInstallationsParams` instead.
+     */
+    #[deprecated(note = "use `list_app_installations_with` instead")]
+    pub async fn list_app_installations(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::OrgsListAppInstallationsResponse> {
+        let params = OrgsListAppInstallationsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_app_installations_with(org, &params).await
+    }
+
     /**
      * List pending organization invitations.
      *
@@ -929,20 +1391,18 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListPendingInvitationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_pending_invitations(
+    pub async fn list_pending_invitations_with(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListPendingInvitationsParams,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -955,6 +1415,23 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_pending_invitations_with` and pass the query parameters as a `OrgsListPendingInvitationsParams` instead.
+     */
+    #[deprecated(note = "use `list_pending_invitations_with` instead")]
+    pub async fn list_pending_invitations(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        let params = OrgsListPendingInvitationsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_pending_invitations_with(org, &params).await
+    }
+
     /**
      * List pending organization invitations.
      *
@@ -978,6 +1455,46 @@ impl Orgs {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List pending organization invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-pending-organization-invitations>
+     */
+    pub fn list_pending_invitations_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationInvitation> {
+        let url = format!(
+            "/orgs/{}/invitations",
+            crate::progenitor_support::encode_path(org),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create an organization invitation.
      *
@@ -1047,21 +1564,19 @@ impl Orgs {
      *
      * * `org: &str`
      * * `invitation_id: i64` -- invitation_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListInvitationTeamsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_invitation_teams(
+    pub async fn list_invitation_teams_with(
         &self,
         org: &str,
         invitation_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListInvitationTeamsParams,
     ) -> Result<Vec<crate::types::Team>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1075,6 +1590,25 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_invitation_teams_with` and pass the query parameters as a `OrgsListInvitationTeamsParams` instead.
+     */
+    #[deprecated(note = "use `list_invitation_teams_with` instead")]
+    pub async fn list_invitation_teams(
+        &self,
+        org: &str,
+        invitation_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Team>> {
+        let params = OrgsListInvitationTeamsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_invitation_teams_with(org, invitation_id, &params)
+            .await
+    }
+
     /**
      * List organization invitation teams.
      *
@@ -1100,6 +1634,48 @@ impl Orgs {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List organization invitation teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/invitations/{invitation_id}/teams` endpoint.
+     *
+     * As opposed to `list_all_invitation_teams`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List all teams associated with an invitation. In order to see invitations in an organization, the authenticated user must be an organization owner.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-invitation-teams>
+     */
+    pub fn list_invitation_teams_stream(
+        &self,
+        org: &str,
+        invitation_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team> {
+        let url = format!(
+            "/orgs/{}/invitations/{}/teams",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(&invitation_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List organization members.
      *
@@ -1112,35 +1688,24 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `filter: crate::types::OrgsListMembersFilter` -- Filter members returned in the list. Can be one of:  
-     *  \\* `2fa_disabled` - Members without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled. Available for organization owners.  
-     *  \\* `all` - All members the authenticated user can see.
-     * * `role: crate::types::OrgsListMembersRole` -- Filter members returned by their role. Can be one of:  
-     *  \\* `all` - All members of the organization, regardless of role.  
-     *  \\* `admin` - Organization owners.  
-     *  \\* `member` - Non-owner organization members.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListMembersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_members(
+    pub async fn list_members_with(
         &self,
         org: &str,
-        filter: crate::types::OrgsListMembersFilter,
-        role: crate::types::OrgsListMembersRole,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListMembersParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.to_string().is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !role.to_string().is_empty() {
+        if let Some(role) = &params.role {
             query_args.push(("role".to_string(), role.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1153,6 +1718,27 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_members_with` and pass the query parameters as a `OrgsListMembersParams` instead.
+     */
+    #[deprecated(note = "use `list_members_with` instead")]
+    pub async fn list_members(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListMembersFilter,
+        role: crate::types::OrgsListMembersRole,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = OrgsListMembersParams {
+            filter: Some(filter).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            role: Some(role).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_members_with(org, &params).await
+    }
+
     /**
      * List organization members.
      *
@@ -1163,18 +1749,74 @@ impl Orgs {
      * List all users who are members of an organization. If the authenticated user is also a member of this organization then both concealed and public members will be returned.
      *
      * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-members>
+     *
+     * **Parameters:**
+     *
+     * * `params: &OrgsListMembersParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_members_with(
+        &self,
+        org: &str,
+        params: &OrgsListMembersParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if let Some(role) = &params.role {
+            query_args.push(("role".to_string(), role.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/members?{}",
+            crate::progenitor_support::encode_path(org),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_members_with` and pass the query parameters as a `OrgsListMembersParams` instead.
      */
+    #[deprecated(note = "use `list_all_members_with` instead")]
     pub async fn list_all_members(
         &self,
         org: &str,
         filter: crate::types::OrgsListMembersFilter,
         role: crate::types::OrgsListMembersRole,
     ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = OrgsListMembersParams {
+            filter: Some(filter).filter(|v| !v.to_string().is_empty()),
+            role: Some(role).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_members_with(org, &params).await
+    }
+
+    /**
+     * List organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/members` endpoint.
+     *
+     * As opposed to `list_all_members`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List all users who are members of an organization. If the authenticated user is also a member of this organization then both concealed and public members will be returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-members>
+     */
+    pub fn list_members_stream(
+        &self,
+        org: &str,
+        params: &OrgsListMembersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.to_string().is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !role.to_string().is_empty() {
+        if let Some(role) = &params.role {
             query_args.push(("role".to_string(), role.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1184,7 +1826,22 @@ impl Orgs {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -1340,27 +1997,21 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `filter: crate::types::OrgsListMembersFilter` -- Filter members returned in the list. Can be one of:  
-     *  \\* `2fa_disabled` - Members without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled. Available for organization owners.  
-     *  \\* `all` - All members the authenticated user can see.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListOutsideCollaboratorsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_outside_collaborators(
+    pub async fn list_outside_collaborators_with(
         &self,
         org: &str,
-        filter: crate::types::OrgsListMembersFilter,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListOutsideCollaboratorsParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.to_string().is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1373,6 +2024,25 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_outside_collaborators_with` and pass the query parameters as a `OrgsListOutsideCollaboratorsParams` instead.
+     */
+    #[deprecated(note = "use `list_outside_collaborators_with` instead")]
+    pub async fn list_outside_collaborators(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListOutsideCollaboratorsFilter,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = OrgsListOutsideCollaboratorsParams {
+            filter: Some(filter).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_outside_collaborators_with(org, &params).await
+    }
+
     /**
      * List outside collaborators for an organization.
      *
@@ -1383,14 +2053,18 @@ impl Orgs {
      * List all users who are outside collaborators of an organization.
      *
      * FROM: <https://docs.github.com/rest/reference/orgs#list-outside-collaborators-for-an-organization>
+     *
+     * **Parameters:**
+     *
+     * * `params: &OrgsListOutsideCollaboratorsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_outside_collaborators(
+    pub async fn list_all_outside_collaborators_with(
         &self,
         org: &str,
-        filter: crate::types::OrgsListMembersFilter,
+        params: &OrgsListOutsideCollaboratorsParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.to_string().is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1403,6 +2077,69 @@ impl Orgs {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_all_outside_collaborators_with` and pass the query parameters as a `OrgsListOutsideCollaboratorsParams` instead.
+     */
+    #[deprecated(note = "use `list_all_outside_collaborators_with` instead")]
+    pub async fn list_all_outside_collaborators(
+        &self,
+        org: &str,
+        filter: crate::types::OrgsListOutsideCollaboratorsFilter,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = OrgsListOutsideCollaboratorsParams {
+            filter: Some(filter).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_outside_collaborators_with(org, &params).await
+    }
+
+    /**
+     * List outside collaborators for an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/outside_collaborators` endpoint.
+     *
+     * As opposed to `list_all_outside_collaborators`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List all users who are outside collaborators of an organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-outside-collaborators-for-an-organization>
+     */
+    pub fn list_outside_collaborators_stream(
+        &self,
+        org: &str,
+        params: &OrgsListOutsideCollaboratorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/outside_collaborators?{}",
+            crate::progenitor_support::encode_path(org),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Convert an organization member to outside collaborator.
      *
@@ -1421,7 +2158,7 @@ impl Orgs {
         &self,
         org: &str,
         username: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::types::OrgsConvertMemberOutsideCollaboratorResponse> {
         let url = format!(
             "/orgs/{}/outside_collaborators/{}",
             crate::progenitor_support::encode_path(org),
@@ -1467,20 +2204,18 @@ impl Orgs {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListPublicMembersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_public_members(
+    pub async fn list_public_members_with(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListPublicMembersParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1493,6 +2228,23 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_public_members_with` and pass the query parameters as a `OrgsListPublicMembersParams` instead.
+     */
+    #[deprecated(note = "use `list_public_members_with` instead")]
+    pub async fn list_public_members(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = OrgsListPublicMembersParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_public_members_with(org, &params).await
+    }
+
     /**
      * List public organization members.
      *
@@ -1517,7 +2269,47 @@ impl Orgs {
     }
 
     /**
-     * Check public organization membership for a user.
+     * List public organization members.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/public_members` endpoint.
+     *
+     * As opposed to `list_all_public_members`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Members of an organization can choose to have their membership publicized or not.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-public-organization-members>
+     */
+    pub fn list_public_members_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        let url = format!(
+            "/orgs/{}/public_members",
+            crate::progenitor_support::encode_path(org),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * Check public organization membership for a user.
      *
      * This function performs a `GET` to the `/orgs/{org}/public_members/{username}` endpoint.
      *
@@ -1609,24 +2401,20 @@ impl Orgs {
      *
      * **Parameters:**
      *
-     * * `state: crate::types::OrgMembershipState` -- Indicates the state of the memberships to return. Can be either `active` or `pending`. If not specified, the API returns both active and pending memberships.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListMembershipsForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_memberships_for_authenticated_user(
+    pub async fn list_memberships_for_authenticated_user_with(
         &self,
-        state: crate::types::OrgMembershipState,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListMembershipsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::OrgMembership>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1635,6 +2423,25 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_memberships_for_authenticated_user_with` and pass the query parameters as a `OrgsListMembershipsForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_memberships_for_authenticated_user_with` instead")]
+    pub async fn list_memberships_for_authenticated_user(
+        &self,
+        state: crate::types::OrgsListMembershipsState,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::OrgMembership>> {
+        let params = OrgsListMembershipsForAuthenticatedUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_memberships_for_authenticated_user_with(&params)
+            .await
+    }
+
     /**
      * List organization memberships for the authenticated user.
      *
@@ -1645,13 +2452,17 @@ impl Orgs {
      *
      *
      * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-memberships-for-the-authenticated-user>
+     *
+     * **Parameters:**
+     *
+     * * `params: &OrgsListMembershipsForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_memberships_for_authenticated_user(
+    pub async fn list_all_memberships_for_authenticated_user_with(
         &self,
-        state: crate::types::OrgMembershipState,
+        params: &OrgsListMembershipsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::OrgMembership>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1660,6 +2471,64 @@ impl Orgs {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_all_memberships_for_authenticated_user_with` and pass the query parameters as a `OrgsListMembershipsForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_all_memberships_for_authenticated_user_with` instead")]
+    pub async fn list_all_memberships_for_authenticated_user(
+        &self,
+        state: crate::types::OrgsListMembershipsState,
+    ) -> Result<Vec<crate::types::OrgMembership>> {
+        let params = OrgsListMembershipsForAuthenticatedUserParams {
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_memberships_for_authenticated_user_with(&params)
+            .await
+    }
+
+    /**
+     * List organization memberships for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/memberships/orgs` endpoint.
+     *
+     * As opposed to `list_all_memberships_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organization-memberships-for-the-authenticated-user>
+     */
+    pub fn list_memberships_for_authenticated_user_stream(
+        &self,
+        params: &OrgsListMembershipsForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrgMembership> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/user/memberships/orgs?{}", query_);
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get an organization membership for the authenticated user.
      *
@@ -1728,19 +2597,17 @@ impl Orgs {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_authenticated_user(
+    pub async fn list_for_authenticated_user_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::OrganizationSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1749,6 +2616,22 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_authenticated_user_with` and pass the query parameters as a `OrgsListForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_for_authenticated_user_with` instead")]
+    pub async fn list_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::OrganizationSimple>> {
+        let params = OrgsListForAuthenticatedUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_for_authenticated_user_with(&params).await
+    }
+
     /**
      * List organizations for the authenticated user.
      *
@@ -1771,6 +2654,45 @@ impl Orgs {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List organizations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/orgs` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List organizations for the authenticated user.
+     *
+     * **OAuth scope requirements**
+     *
+     * This only lists organizations that your authorization allows you to operate on in some way (e.g., you can list teams with `read:org` scope, you can publicize your organization membership with `user` scope, etc.). Therefore, this API requires at least `user` or `read:org` scope. OAuth requests with insufficient scope receive a `403 Forbidden` response.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-the-authenticated-user>
+     */
+    pub fn list_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationSimple> {
+        let url = "/user/orgs".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List organizations for a user.
      *
@@ -1785,20 +2707,18 @@ impl Orgs {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &OrgsListForUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_user(
+    pub async fn list_for_user_with(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &OrgsListForUserParams,
     ) -> Result<Vec<crate::types::OrganizationSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1811,6 +2731,23 @@ impl Orgs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_user_with` and pass the query parameters as a `OrgsListForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_for_user_with` instead")]
+    pub async fn list_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::OrganizationSimple>> {
+        let params = OrgsListForUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_for_user_with(username, &params).await
+    }
+
     /**
      * List organizations for a user.
      *
@@ -1835,4 +2772,1436 @@ impl Orgs {
 
         self.client.get_all_pages(&url, None).await
     }
+
+    /**
+     * List organizations for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/orgs` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List [public organization memberships](https://help.github.com/articles/publicizing-or-concealing-organization-membership) for the specified user.
+     *
+     * This method only lists _public_ memberships, regardless of authentication. If you need to fetch all of the organization memberships (public and private) for the authenticated user, use the [List organizations for the authenticated user](https://docs.github.com/rest/reference/orgs#list-organizations-for-the-authenticated-user) API instead.
+     *
+     * FROM: <https://docs.github.com/rest/reference/orgs#list-organizations-for-a-user>
+     */
+    pub fn list_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationSimple> {
+        let url = format!(
+            "/users/{}/orgs",
+            crate::progenitor_support::encode_path(username),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Orgs::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsListParams {
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// An organization ID. Only return organizations with an ID greater than this ID.
+    pub since: Option<i64>,
+}
+
+impl OrgsListParams {
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: i64) -> Self {
+        self.since = Some(since);
+        self
+    }
+}
+
+/// The query parameters of `Orgs::get_audit_log_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsGetAuditLogParams {
+    /// A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events after this cursor.
+    pub after: Option<String>,
+    /// A cursor, as given in the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header). If specified, the query only searches for events before this cursor.
+    pub before: Option<String>,
+    /// The event types to include:
+    ///
+    /// - `web` - returns web (non-Git) events
+    /// - `git` - returns Git events
+    /// - `all` - returns both web and Git events
+    ///
+    ///   The default is `web`.
+    pub include: Option<crate::types::Include>,
+    /// Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
+    pub order: Option<crate::types::Order>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// A search phrase. For more information, see [Searching the audit log](https://docs.github.com/github/setting-up-and-managing-organizations-and-teams/reviewing-the-audit-log-for-your-organization#searching-the-audit-log).
+    pub phrase: Option<String>,
+}
+
+impl OrgsGetAuditLogParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn before<T: ToString>(mut self, before: T) -> Self {
+        self.before = Some(before.to_string());
+        self
+    }
+
+    pub fn include(mut self, include: crate::types::Include) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn phrase<T: ToString>(mut self, phrase: T) -> Self {
+        self.phrase = Some(phrase.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orgs::list_failed_invitations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsListFailedInvitationsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl OrgsListFailedInvitationsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Orgs::list_webhooks_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsListWebhooksParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl OrgsListWebhooksParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Orgs::list_webhook_deliveries_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsListWebhookDeliveriesParams {
+    /// Used for pagination: the starting delivery from which the page of deliveries is fetched. Refer to the `link` header for the next and previous page cursors.
+    pub cursor: Option<String>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl OrgsListWebhookDeliveriesParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Orgs::list_app_installations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsListAppInstallationsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl OrgsListAppInstallationsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Orgs::list_pending_invitations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsListPendingInvitationsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl OrgsListPendingInvitationsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Orgs::list_invitation_teams_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsListInvitationTeamsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl OrgsListInvitationTeamsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Orgs::list_members_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsListMembersParams {
+    /// Filter members returned in the list. Can be one of:  
+    /// \\* `2fa_disabled` - Members without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled. Available for organization owners.  
+    /// \\* `all` - All members the authenticated user can see.
+    pub filter: Option<crate::types::OrgsListMembersFilter>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Filter members returned by their role. Can be one of:  
+    /// \\* `all` - All members of the organization, regardless of role.  
+    /// \\* `admin` - Organization owners.  
+    /// \\* `member` - Non-owner organization members.
+    pub role: Option<crate::types::OrgsListMembersRole>,
+}
+
+impl OrgsListMembersParams {
+    pub fn filter(mut self, filter: crate::types::OrgsListMembersFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn role(mut self, role: crate::types::OrgsListMembersRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+}
+
+/// The query parameters of `Orgs::list_outside_collaborators_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsListOutsideCollaboratorsParams {
+    /// Filter the list of outside collaborators. Can be one of:  
+    /// \\* `2fa_disabled`: Outside collaborators without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled.  
+    /// \\* `all`: All outside collaborators.
+    pub filter: Option<crate::types::OrgsListOutsideCollaboratorsFilter>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl OrgsListOutsideCollaboratorsParams {
+    pub fn filter(mut self, filter: crate::types::OrgsListOutsideCollaboratorsFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Orgs::list_public_members_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsListPublicMembersParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl OrgsListPublicMembersParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Orgs::list_memberships_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsListMembershipsForAuthenticatedUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Indicates the state of the memberships to return. Can be either `active` or `pending`. If not specified, the API returns both active and pending memberships.
+    pub state: Option<crate::types::OrgsListMembershipsState>,
+}
+
+impl OrgsListMembershipsForAuthenticatedUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn state(mut self, state: crate::types::OrgsListMembershipsState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+/// The query parameters of `Orgs::list_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsListForAuthenticatedUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl OrgsListForAuthenticatedUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Orgs::list_for_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgsListForUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl OrgsListForUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The functions of [`Orgs`], for code that takes them as a trait, so that it can be
+/// tested with the `MockOrgsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait OrgsApi: Send + Sync {
+    /// See [`Orgs::list_with`].
+    async fn list_with(
+        &self,
+        params: &OrgsListParams,
+    ) -> Result<Vec<crate::types::OrganizationSimple>>;
+
+    /// See [`Orgs::list_all_with`].
+    async fn list_all_with(
+        &self,
+        params: &OrgsListParams,
+    ) -> Result<Vec<crate::types::OrganizationSimple>>;
+
+    /// See [`Orgs::list_stream`].
+    fn list_stream(
+        &self,
+        params: &OrgsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationSimple>;
+
+    /// See [`Orgs::get`].
+    async fn get(&self, org: &str) -> Result<crate::types::OrganizationFull>;
+
+    /// See [`Orgs::update`].
+    async fn update(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsUpdateRequest,
+    ) -> Result<crate::types::OrganizationFull>;
+
+    /// See [`Orgs::get_audit_log_with`].
+    async fn get_audit_log_with(
+        &self,
+        org: &str,
+        params: &OrgsGetAuditLogParams,
+    ) -> Result<Vec<crate::types::AuditLogEvent>>;
+
+    /// See [`Orgs::get_all_audit_log_with`].
+    async fn get_all_audit_log_with(
+        &self,
+        org: &str,
+        params: &OrgsGetAuditLogParams,
+    ) -> Result<Vec<crate::types::AuditLogEvent>>;
+
+    /// See [`Orgs::get_audit_log_stream`].
+    fn get_audit_log_stream(
+        &self,
+        org: &str,
+        params: &OrgsGetAuditLogParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuditLogEvent>;
+
+    /// See [`Orgs::list_blocked_users`].
+    async fn list_blocked_users(&self, org: &str) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Orgs::list_all_blocked_users`].
+    async fn list_all_blocked_users(&self, org: &str) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Orgs::list_blocked_users_stream`].
+    fn list_blocked_users_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Orgs::check_blocked_user`].
+    async fn check_blocked_user(&self, org: &str, username: &str) -> Result<()>;
+
+    /// See [`Orgs::block_user`].
+    async fn block_user(&self, org: &str, username: &str) -> Result<()>;
+
+    /// See [`Orgs::unblock_user`].
+    async fn unblock_user(&self, org: &str, username: &str) -> Result<()>;
+
+    /// See [`Orgs::list_saml_sso_authorizations`].
+    async fn list_saml_sso_authorizations(
+        &self,
+        org: &str,
+    ) -> Result<Vec<crate::types::CredentialAuthorization>>;
+
+    /// See [`Orgs::list_all_saml_sso_authorizations`].
+    async fn list_all_saml_sso_authorizations(
+        &self,
+        org: &str,
+    ) -> Result<Vec<crate::types::CredentialAuthorization>>;
+
+    /// See [`Orgs::list_saml_sso_authorizations_stream`].
+    fn list_saml_sso_authorizations_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CredentialAuthorization>;
+
+    /// See [`Orgs::remove_saml_sso_authorization`].
+    async fn remove_saml_sso_authorization(&self, org: &str, credential_id: i64) -> Result<()>;
+
+    /// See [`Orgs::list_failed_invitations_with`].
+    async fn list_failed_invitations_with(
+        &self,
+        org: &str,
+        params: &OrgsListFailedInvitationsParams,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>>;
+
+    /// See [`Orgs::list_all_failed_invitations`].
+    async fn list_all_failed_invitations(
+        &self,
+        org: &str,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>>;
+
+    /// See [`Orgs::list_failed_invitations_stream`].
+    fn list_failed_invitations_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationInvitation>;
+
+    /// See [`Orgs::list_webhooks_with`].
+    async fn list_webhooks_with(
+        &self,
+        org: &str,
+        params: &OrgsListWebhooksParams,
+    ) -> Result<Vec<crate::types::OrgHook>>;
+
+    /// See [`Orgs::list_all_webhooks`].
+    async fn list_all_webhooks(&self, org: &str) -> Result<Vec<crate::types::OrgHook>>;
+
+    /// See [`Orgs::list_webhooks_stream`].
+    fn list_webhooks_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrgHook>;
+
+    /// See [`Orgs::create_webhook`].
+    async fn create_webhook(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsCreateWebhookRequest,
+    ) -> Result<crate::types::OrgHook>;
+
+    /// See [`Orgs::get_webhook`].
+    async fn get_webhook(&self, org: &str, hook_id: i64) -> Result<crate::types::OrgHook>;
+
+    /// See [`Orgs::delete_webhook`].
+    async fn delete_webhook(&self, org: &str, hook_id: i64) -> Result<()>;
+
+    /// See [`Orgs::update_webhook`].
+    async fn update_webhook(
+        &self,
+        org: &str,
+        hook_id: i64,
+        body: &crate::types::OrgsUpdateWebhookRequest,
+    ) -> Result<crate::types::OrgHook>;
+
+    /// See [`Orgs::get_webhook_config_for_org`].
+    async fn get_webhook_config_for_org(
+        &self,
+        org: &str,
+        hook_id: i64,
+    ) -> Result<crate::types::WebhookConfig>;
+
+    /// See [`Orgs::update_webhook_config_for_org`].
+    async fn update_webhook_config_for_org(
+        &self,
+        org: &str,
+        hook_id: i64,
+        body: &crate::types::OrgsUpdateWebhookConfigOrgRequest,
+    ) -> Result<crate::types::WebhookConfig>;
+
+    /// See [`Orgs::list_webhook_deliveries_with`].
+    async fn list_webhook_deliveries_with(
+        &self,
+        org: &str,
+        hook_id: i64,
+        params: &OrgsListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>>;
+
+    /// See [`Orgs::list_all_webhook_deliveries_with`].
+    async fn list_all_webhook_deliveries_with(
+        &self,
+        org: &str,
+        hook_id: i64,
+        params: &OrgsListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>>;
+
+    /// See [`Orgs::list_webhook_deliveries_stream`].
+    fn list_webhook_deliveries_stream(
+        &self,
+        org: &str,
+        hook_id: i64,
+        params: &OrgsListWebhookDeliveriesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::HookDeliveryItem>;
+
+    /// See [`Orgs::get_webhook_delivery`].
+    async fn get_webhook_delivery(
+        &self,
+        org: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> Result<crate::types::HookDelivery>;
+
+    /// See [`Orgs::redeliver_webhook_delivery`].
+    async fn redeliver_webhook_delivery(
+        &self,
+        org: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> Result<crate::types::AcceptedResponse>;
+
+    /// See [`Orgs::ping_webhook`].
+    async fn ping_webhook(&self, org: &str, hook_id: i64) -> Result<()>;
+
+    /// See [`Orgs::list_app_installations_with`].
+    async fn list_app_installations_with(
+        &self,
+        org: &str,
+        params: &OrgsListAppInstallationsParams,
+    ) -> Result<crate::types::OrgsListAppInstallationsResponse>;
+
+    /// See [`Orgs::list_pending_invitations_with`].
+    async fn list_pending_invitations_with(
+        &self,
+        org: &str,
+        params: &OrgsListPendingInvitationsParams,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>>;
+
+    /// See [`Orgs::list_all_pending_invitations`].
+    async fn list_all_pending_invitations(
+        &self,
+        org: &str,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>>;
+
+    /// See [`Orgs::list_pending_invitations_stream`].
+    fn list_pending_invitations_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationInvitation>;
+
+    /// See [`Orgs::create_invitation`].
+    async fn create_invitation(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsCreateInvitationRequest,
+    ) -> Result<crate::types::OrganizationInvitation>;
+
+    /// See [`Orgs::cancel_invitation`].
+    async fn cancel_invitation(&self, org: &str, invitation_id: i64) -> Result<()>;
+
+    /// See [`Orgs::list_invitation_teams_with`].
+    async fn list_invitation_teams_with(
+        &self,
+        org: &str,
+        invitation_id: i64,
+        params: &OrgsListInvitationTeamsParams,
+    ) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Orgs::list_all_invitation_teams`].
+    async fn list_all_invitation_teams(
+        &self,
+        org: &str,
+        invitation_id: i64,
+    ) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Orgs::list_invitation_teams_stream`].
+    fn list_invitation_teams_stream(
+        &self,
+        org: &str,
+        invitation_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team>;
+
+    /// See [`Orgs::list_members_with`].
+    async fn list_members_with(
+        &self,
+        org: &str,
+        params: &OrgsListMembersParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Orgs::list_all_members_with`].
+    async fn list_all_members_with(
+        &self,
+        org: &str,
+        params: &OrgsListMembersParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Orgs::list_members_stream`].
+    fn list_members_stream(
+        &self,
+        org: &str,
+        params: &OrgsListMembersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Orgs::check_membership_for_user`].
+    async fn check_membership_for_user(&self, org: &str, username: &str) -> Result<()>;
+
+    /// See [`Orgs::remove_member`].
+    async fn remove_member(&self, org: &str, username: &str) -> Result<()>;
+
+    /// See [`Orgs::get_membership_for_user`].
+    async fn get_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> Result<crate::types::OrgMembership>;
+
+    /// See [`Orgs::set_membership_for_user`].
+    async fn set_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+        body: &crate::types::OrgsSetMembershipUserRequest,
+    ) -> Result<crate::types::OrgMembership>;
+
+    /// See [`Orgs::remove_membership_for_user`].
+    async fn remove_membership_for_user(&self, org: &str, username: &str) -> Result<()>;
+
+    /// See [`Orgs::list_outside_collaborators_with`].
+    async fn list_outside_collaborators_with(
+        &self,
+        org: &str,
+        params: &OrgsListOutsideCollaboratorsParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Orgs::list_all_outside_collaborators_with`].
+    async fn list_all_outside_collaborators_with(
+        &self,
+        org: &str,
+        params: &OrgsListOutsideCollaboratorsParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Orgs::list_outside_collaborators_stream`].
+    fn list_outside_collaborators_stream(
+        &self,
+        org: &str,
+        params: &OrgsListOutsideCollaboratorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Orgs::convert_member_to_outside_collaborator`].
+    async fn convert_member_to_outside_collaborator(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> Result<crate::types::OrgsConvertMemberOutsideCollaboratorResponse>;
+
+    /// See [`Orgs::remove_outside_collaborator`].
+    async fn remove_outside_collaborator(&self, org: &str, username: &str) -> Result<()>;
+
+    /// See [`Orgs::list_public_members_with`].
+    async fn list_public_members_with(
+        &self,
+        org: &str,
+        params: &OrgsListPublicMembersParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Orgs::list_all_public_members`].
+    async fn list_all_public_members(&self, org: &str) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Orgs::list_public_members_stream`].
+    fn list_public_members_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Orgs::check_public_membership_for_user`].
+    async fn check_public_membership_for_user(&self, org: &str, username: &str) -> Result<()>;
+
+    /// See [`Orgs::set_public_membership_for_authenticated_user`].
+    async fn set_public_membership_for_authenticated_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> Result<()>;
+
+    /// See [`Orgs::remove_public_membership_for_authenticated_user`].
+    async fn remove_public_membership_for_authenticated_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> Result<()>;
+
+    /// See [`Orgs::list_memberships_for_authenticated_user_with`].
+    async fn list_memberships_for_authenticated_user_with(
+        &self,
+        params: &OrgsListMembershipsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::OrgMembership>>;
+
+    /// See [`Orgs::list_all_memberships_for_authenticated_user_with`].
+    async fn list_all_memberships_for_authenticated_user_with(
+        &self,
+        params: &OrgsListMembershipsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::OrgMembership>>;
+
+    /// See [`Orgs::list_memberships_for_authenticated_user_stream`].
+    fn list_memberships_for_authenticated_user_stream(
+        &self,
+        params: &OrgsListMembershipsForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrgMembership>;
+
+    /// See [`Orgs::get_membership_for_authenticated_user`].
+    async fn get_membership_for_authenticated_user(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::OrgMembership>;
+
+    /// See [`Orgs::update_membership_for_authenticated_user`].
+    async fn update_membership_for_authenticated_user(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsUpdateMembershipRequest,
+    ) -> Result<crate::types::OrgMembership>;
+
+    /// See [`Orgs::list_for_authenticated_user_with`].
+    async fn list_for_authenticated_user_with(
+        &self,
+        params: &OrgsListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::OrganizationSimple>>;
+
+    /// See [`Orgs::list_all_for_authenticated_user`].
+    async fn list_all_for_authenticated_user(
+        &self,
+    ) -> Result<Vec<crate::types::OrganizationSimple>>;
+
+    /// See [`Orgs::list_for_authenticated_user_stream`].
+    fn list_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationSimple>;
+
+    /// See [`Orgs::list_for_user_with`].
+    async fn list_for_user_with(
+        &self,
+        username: &str,
+        params: &OrgsListForUserParams,
+    ) -> Result<Vec<crate::types::OrganizationSimple>>;
+
+    /// See [`Orgs::list_all_for_user`].
+    async fn list_all_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::OrganizationSimple>>;
+
+    /// See [`Orgs::list_for_user_stream`].
+    fn list_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationSimple>;
+}
+
+#[async_trait::async_trait]
+impl OrgsApi for Orgs {
+    async fn list_with(
+        &self,
+        params: &OrgsListParams,
+    ) -> Result<Vec<crate::types::OrganizationSimple>> {
+        Orgs::list_with(self, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        params: &OrgsListParams,
+    ) -> Result<Vec<crate::types::OrganizationSimple>> {
+        Orgs::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &OrgsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationSimple> {
+        Orgs::list_stream(self, params, start, max_items)
+    }
+
+    async fn get(&self, org: &str) -> Result<crate::types::OrganizationFull> {
+        Orgs::get(self, org).await
+    }
+
+    async fn update(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsUpdateRequest,
+    ) -> Result<crate::types::OrganizationFull> {
+        Orgs::update(self, org, body).await
+    }
+
+    async fn get_audit_log_with(
+        &self,
+        org: &str,
+        params: &OrgsGetAuditLogParams,
+    ) -> Result<Vec<crate::types::AuditLogEvent>> {
+        Orgs::get_audit_log_with(self, org, params).await
+    }
+
+    async fn get_all_audit_log_with(
+        &self,
+        org: &str,
+        params: &OrgsGetAuditLogParams,
+    ) -> Result<Vec<crate::types::AuditLogEvent>> {
+        Orgs::get_all_audit_log_with(self, org, params).await
+    }
+
+    fn get_audit_log_stream(
+        &self,
+        org: &str,
+        params: &OrgsGetAuditLogParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuditLogEvent> {
+        Orgs::get_audit_log_stream(self, org, params, start, max_items)
+    }
+
+    async fn list_blocked_users(&self, org: &str) -> Result<Vec<crate::types::SimpleUser>> {
+        Orgs::list_blocked_users(self, org).await
+    }
+
+    async fn list_all_blocked_users(&self, org: &str) -> Result<Vec<crate::types::SimpleUser>> {
+        Orgs::list_all_blocked_users(self, org).await
+    }
+
+    fn list_blocked_users_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Orgs::list_blocked_users_stream(self, org, start, max_items)
+    }
+
+    async fn check_blocked_user(&self, org: &str, username: &str) -> Result<()> {
+        Orgs::check_blocked_user(self, org, username).await
+    }
+
+    async fn block_user(&self, org: &str, username: &str) -> Result<()> {
+        Orgs::block_user(self, org, username).await
+    }
+
+    async fn unblock_user(&self, org: &str, username: &str) -> Result<()> {
+        Orgs::unblock_user(self, org, username).await
+    }
+
+    async fn list_saml_sso_authorizations(
+        &self,
+        org: &str,
+    ) -> Result<Vec<crate::types::CredentialAuthorization>> {
+        Orgs::list_saml_sso_authorizations(self, org).await
+    }
+
+    async fn list_all_saml_sso_authorizations(
+        &self,
+        org: &str,
+    ) -> Result<Vec<crate::types::CredentialAuthorization>> {
+        Orgs::list_all_saml_sso_authorizations(self, org).await
+    }
+
+    fn list_saml_sso_authorizations_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CredentialAuthorization> {
+        Orgs::list_saml_sso_authorizations_stream(self, org, start, max_items)
+    }
+
+    async fn remove_saml_sso_authorization(&self, org: &str, credential_id: i64) -> Result<()> {
+        Orgs::remove_saml_sso_authorization(self, org, credential_id).await
+    }
+
+    async fn list_failed_invitations_with(
+        &self,
+        org: &str,
+        params: &OrgsListFailedInvitationsParams,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        Orgs::list_failed_invitations_with(self, org, params).await
+    }
+
+    async fn list_all_failed_invitations(
+        &self,
+        org: &str,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        Orgs::list_all_failed_invitations(self, org).await
+    }
+
+    fn list_failed_invitations_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationInvitation> {
+        Orgs::list_failed_invitations_stream(self, org, start, max_items)
+    }
+
+    async fn list_webhooks_with(
+        &self,
+        org: &str,
+        params: &OrgsListWebhooksParams,
+    ) -> Result<Vec<crate::types::OrgHook>> {
+        Orgs::list_webhooks_with(self, org, params).await
+    }
+
+    async fn list_all_webhooks(&self, org: &str) -> Result<Vec<crate::types::OrgHook>> {
+        Orgs::list_all_webhooks(self, org).await
+    }
+
+    fn list_webhooks_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrgHook> {
+        Orgs::list_webhooks_stream(self, org, start, max_items)
+    }
+
+    async fn create_webhook(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsCreateWebhookRequest,
+    ) -> Result<crate::types::OrgHook> {
+        Orgs::create_webhook(self, org, body).await
+    }
+
+    async fn get_webhook(&self, org: &str, hook_id: i64) -> Result<crate::types::OrgHook> {
+        Orgs::get_webhook(self, org, hook_id).await
+    }
+
+    async fn delete_webhook(&self, org: &str, hook_id: i64) -> Result<()> {
+        Orgs::delete_webhook(self, org, hook_id).await
+    }
+
+    async fn update_webhook(
+        &self,
+        org: &str,
+        hook_id: i64,
+        body: &crate::types::OrgsUpdateWebhookRequest,
+    ) -> Result<crate::types::OrgHook> {
+        Orgs::update_webhook(self, org, hook_id, body).await
+    }
+
+    async fn get_webhook_config_for_org(
+        &self,
+        org: &str,
+        hook_id: i64,
+    ) -> Result<crate::types::WebhookConfig> {
+        Orgs::get_webhook_config_for_org(self, org, hook_id).await
+    }
+
+    async fn update_webhook_config_for_org(
+        &self,
+        org: &str,
+        hook_id: i64,
+        body: &crate::types::OrgsUpdateWebhookConfigOrgRequest,
+    ) -> Result<crate::types::WebhookConfig> {
+        Orgs::update_webhook_config_for_org(self, org, hook_id, body).await
+    }
+
+    async fn list_webhook_deliveries_with(
+        &self,
+        org: &str,
+        hook_id: i64,
+        params: &OrgsListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        Orgs::list_webhook_deliveries_with(self, org, hook_id, params).await
+    }
+
+    async fn list_all_webhook_deliveries_with(
+        &self,
+        org: &str,
+        hook_id: i64,
+        params: &OrgsListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        Orgs::list_all_webhook_deliveries_with(self, org, hook_id, params).await
+    }
+
+    fn list_webhook_deliveries_stream(
+        &self,
+        org: &str,
+        hook_id: i64,
+        params: &OrgsListWebhookDeliveriesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::HookDeliveryItem> {
+        Orgs::list_webhook_deliveries_stream(self, org, hook_id, params, start, max_items)
+    }
+
+    async fn get_webhook_delivery(
+        &self,
+        org: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> Result<crate::types::HookDelivery> {
+        Orgs::get_webhook_delivery(self, org, hook_id, delivery_id).await
+    }
+
+    async fn redeliver_webhook_delivery(
+        &self,
+        org: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> Result<crate::types::AcceptedResponse> {
+        Orgs::redeliver_webhook_delivery(self, org, hook_id, delivery_id).await
+    }
+
+    async fn ping_webhook(&self, org: &str, hook_id: i64) -> Result<()> {
+        Orgs::ping_webhook(self, org, hook_id).await
+    }
+
+    async fn list_app_installations_with(
+        &self,
+        org: &str,
+        params: &OrgsListAppInstallationsParams,
+    ) -> Result<crate::types::OrgsListAppInstallationsResponse> {
+        Orgs::list_app_installations_with(self, org, params).await
+    }
+
+    async fn list_pending_invitations_with(
+        &self,
+        org: &str,
+        params: &OrgsListPendingInvitationsParams,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        Orgs::list_pending_invitations_with(self, org, params).await
+    }
+
+    async fn list_all_pending_invitations(
+        &self,
+        org: &str,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        Orgs::list_all_pending_invitations(self, org).await
+    }
+
+    fn list_pending_invitations_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationInvitation> {
+        Orgs::list_pending_invitations_stream(self, org, start, max_items)
+    }
+
+    async fn create_invitation(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsCreateInvitationRequest,
+    ) -> Result<crate::types::OrganizationInvitation> {
+        Orgs::create_invitation(self, org, body).await
+    }
+
+    async fn cancel_invitation(&self, org: &str, invitation_id: i64) -> Result<()> {
+        Orgs::cancel_invitation(self, org, invitation_id).await
+    }
+
+    async fn list_invitation_teams_with(
+        &self,
+        org: &str,
+        invitation_id: i64,
+        params: &OrgsListInvitationTeamsParams,
+    ) -> Result<Vec<crate::types::Team>> {
+        Orgs::list_invitation_teams_with(self, org, invitation_id, params).await
+    }
+
+    async fn list_all_invitation_teams(
+        &self,
+        org: &str,
+        invitation_id: i64,
+    ) -> Result<Vec<crate::types::Team>> {
+        Orgs::list_all_invitation_teams(self, org, invitation_id).await
+    }
+
+    fn list_invitation_teams_stream(
+        &self,
+        org: &str,
+        invitation_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team> {
+        Orgs::list_invitation_teams_stream(self, org, invitation_id, start, max_items)
+    }
+
+    async fn list_members_with(
+        &self,
+        org: &str,
+        params: &OrgsListMembersParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Orgs::list_members_with(self, org, params).await
+    }
+
+    async fn list_all_members_with(
+        &self,
+        org: &str,
+        params: &OrgsListMembersParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Orgs::list_all_members_with(self, org, params).await
+    }
+
+    fn list_members_stream(
+        &self,
+        org: &str,
+        params: &OrgsListMembersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Orgs::list_members_stream(self, org, params, start, max_items)
+    }
+
+    async fn check_membership_for_user(&self, org: &str, username: &str) -> Result<()> {
+        Orgs::check_membership_for_user(self, org, username).await
+    }
+
+    async fn remove_member(&self, org: &str, username: &str) -> Result<()> {
+        Orgs::remove_member(self, org, username).await
+    }
+
+    async fn get_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> Result<crate::types::OrgMembership> {
+        Orgs::get_membership_for_user(self, org, username).await
+    }
+
+    async fn set_membership_for_user(
+        &self,
+        org: &str,
+        username: &str,
+        body: &crate::types::OrgsSetMembershipUserRequest,
+    ) -> Result<crate::types::OrgMembership> {
+        Orgs::set_membership_for_user(self, org, username, body).await
+    }
+
+    async fn remove_membership_for_user(&self, org: &str, username: &str) -> Result<()> {
+        Orgs::remove_membership_for_user(self, org, username).await
+    }
+
+    async fn list_outside_collaborators_with(
+        &self,
+        org: &str,
+        params: &OrgsListOutsideCollaboratorsParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Orgs::list_outside_collaborators_with(self, org, params).await
+    }
+
+    async fn list_all_outside_collaborators_with(
+        &self,
+        org: &str,
+        params: &OrgsListOutsideCollaboratorsParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Orgs::list_all_outside_collaborators_with(self, org, params).await
+    }
+
+    fn list_outside_collaborators_stream(
+        &self,
+        org: &str,
+        params: &OrgsListOutsideCollaboratorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Orgs::list_outside_collaborators_stream(self, org, params, start, max_items)
+    }
+
+    async fn convert_member_to_outside_collaborator(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> Result<crate::types::OrgsConvertMemberOutsideCollaboratorResponse> {
+        Orgs::convert_member_to_outside_collaborator(self, org, username).await
+    }
+
+    async fn remove_outside_collaborator(&self, org: &str, username: &str) -> Result<()> {
+        Orgs::remove_outside_collaborator(self, org, username).await
+    }
+
+    async fn list_public_members_with(
+        &self,
+        org: &str,
+        params: &OrgsListPublicMembersParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Orgs::list_public_members_with(self, org, params).await
+    }
+
+    async fn list_all_public_members(&self, org: &str) -> Result<Vec<crate::types::SimpleUser>> {
+        Orgs::list_all_public_members(self, org).await
+    }
+
+    fn list_public_members_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Orgs::list_public_members_stream(self, org, start, max_items)
+    }
+
+    async fn check_public_membership_for_user(&self, org: &str, username: &str) -> Result<()> {
+        Orgs::check_public_membership_for_user(self, org, username).await
+    }
+
+    async fn set_public_membership_for_authenticated_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> Result<()> {
+        Orgs::set_public_membership_for_authenticated_user(self, org, username).await
+    }
+
+    async fn remove_public_membership_for_authenticated_user(
+        &self,
+        org: &str,
+        username: &str,
+    ) -> Result<()> {
+        Orgs::remove_public_membership_for_authenticated_user(self, org, username).await
+    }
+
+    async fn list_memberships_for_authenticated_user_with(
+        &self,
+        params: &OrgsListMembershipsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::OrgMembership>> {
+        Orgs::list_memberships_for_authenticated_user_with(self, params).await
+    }
+
+    async fn list_all_memberships_for_authenticated_user_with(
+        &self,
+        params: &OrgsListMembershipsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::OrgMembership>> {
+        Orgs::list_all_memberships_for_authenticated_user_with(self, params).await
+    }
+
+    fn list_memberships_for_authenticated_user_stream(
+        &self,
+        params: &OrgsListMembershipsForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrgMembership> {
+        Orgs::list_memberships_for_authenticated_user_stream(self, params, start, max_items)
+    }
+
+    async fn get_membership_for_authenticated_user(
+        &self,
+        org: &str,
+    ) -> Result<crate::types::OrgMembership> {
+        Orgs::get_membership_for_authenticated_user(self, org).await
+    }
+
+    async fn update_membership_for_authenticated_user(
+        &self,
+        org: &str,
+        body: &crate::types::OrgsUpdateMembershipRequest,
+    ) -> Result<crate::types::OrgMembership> {
+        Orgs::update_membership_for_authenticated_user(self, org, body).await
+    }
+
+    async fn list_for_authenticated_user_with(
+        &self,
+        params: &OrgsListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::OrganizationSimple>> {
+        Orgs::list_for_authenticated_user_with(self, params).await
+    }
+
+    async fn list_all_for_authenticated_user(
+        &self,
+    ) -> Result<Vec<crate::types::OrganizationSimple>> {
+        Orgs::list_all_for_authenticated_user(self).await
+    }
+
+    fn list_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationSimple> {
+        Orgs::list_for_authenticated_user_stream(self, start, max_items)
+    }
+
+    async fn list_for_user_with(
+        &self,
+        username: &str,
+        params: &OrgsListForUserParams,
+    ) -> Result<Vec<crate::types::OrganizationSimple>> {
+        Orgs::list_for_user_with(self, username, params).await
+    }
+
+    async fn list_all_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::OrganizationSimple>> {
+        Orgs::list_all_for_user(self, username).await
+    }
+
+    fn list_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationSimple> {
+        Orgs::list_for_user_stream(self, username, start, max_items)
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Packages {
     pub client: Client,
@@ -103,17 +101,17 @@ impl Packages {
      * * `package_type: crate::types::PackageType` -- The type of supported package. Can be one of `npm`, `maven`, `rubygems`, `nuget`, `docker`, or `container`. Packages in GitHub's Gradle registry have the type `maven`. Docker images pushed to GitHub's Container registry (`ghcr.io`) have the type `container`. You can use the type `docker` to find images that were pushed to GitHub's Docker registry (`docker.pkg.github.com`), even if these have now been migrated to the Container registry.
      * * `package_name: &str` -- The name of the package.
      * * `org: &str`
-     * * `token: &str` -- package token.
+     * * `params: &PackagesRestorePackageForOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn restore_package_for_org(
+    pub async fn restore_package_for_org_with(
         &self,
         package_type: crate::types::PackageType,
         package_name: &str,
         org: &str,
-        token: &str,
+        params: &PackagesRestorePackageForOrgParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !token.is_empty() {
+        if let Some(token) = &params.token {
             query_args.push(("token".to_string(), token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -128,6 +126,24 @@ impl Packages {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `restore_package_for_org_with` and pass the query parameters as a `PackagesRestorePackageForOrgParams` instead.
+     */
+    #[deprecated(note = "use `restore_package_for_org_with` instead")]
+    pub async fn restore_package_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        token: &str,
+    ) -> Result<()> {
+        let params = PackagesRestorePackageForOrgParams {
+            token: Some(token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.restore_package_for_org_with(package_type, package_name, org, &params)
+            .await
+    }
+
     /**
      * Get all package versions for a package owned by an organization.
      *
@@ -145,27 +161,23 @@ impl Packages {
      * * `package_type: crate::types::PackageType` -- The type of supported package. Can be one of `npm`, `maven`, `rubygems`, `nuget`, `docker`, or `container`. Packages in GitHub's Gradle registry have the type `maven`. Docker images pushed to GitHub's Container registry (`ghcr.io`) have the type `container`. You can use the type `docker` to find images that were pushed to GitHub's Docker registry (`docker.pkg.github.com`), even if these have now been migrated to the Container registry.
      * * `package_name: &str` -- The name of the package.
      * * `org: &str`
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState` -- The state of the package, either active or deleted.
+     * * `params: &PackagesGetAllPackageVersionsForPackageOwnedByOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_package_versions_for_package_owned_by_org(
+    pub async fn get_all_package_versions_for_package_owned_by_org_with(
         &self,
         package_type: crate::types::PackageType,
         package_name: &str,
         org: &str,
-        page: i64,
-        per_page: i64,
-        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByOrgParams,
     ) -> Result<Vec<crate::types::PackageVersion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -180,6 +192,33 @@ impl Packages {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_all_package_versions_for_package_owned_by_org_with` and pass the query parameters as a `PackagesGetAllPackageVersionsForPackageOwnedByOrgParams` instead.
+     */
+    #[deprecated(note = "use `get_all_package_versions_for_package_owned_by_org_with` instead")]
+    pub async fn get_all_package_versions_for_package_owned_by_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        page: i64,
+        per_page: i64,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> Result<Vec<crate::types::PackageVersion>> {
+        let params = PackagesGetAllPackageVersionsForPackageOwnedByOrgParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_all_package_versions_for_package_owned_by_org_with(
+            package_type,
+            package_name,
+            org,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Get all package versions for a package owned by an organization.
      *
@@ -193,7 +232,38 @@ impl Packages {
      * If `package_type` is not `container`, your token must also include the `repo` scope.
      *
      * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-an-organization>
+     *
+     * **Parameters:**
+     *
+     * * `params: &PackagesGetAllPackageVersionsForPackageOwnedByOrgParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_all_package_versions_for_package_owned_by_org_with(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByOrgParams,
+    ) -> Result<Vec<crate::types::PackageVersion>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/packages/{}/{}/versions?{}",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(package_name),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_all_package_versions_for_package_owned_by_org_with` and pass the query parameters as a `PackagesGetAllPackageVersionsForPackageOwnedByOrgParams` instead.
      */
+    #[deprecated(note = "use `get_all_all_package_versions_for_package_owned_by_org_with` instead")]
     pub async fn get_all_all_package_versions_for_package_owned_by_org(
         &self,
         package_type: crate::types::PackageType,
@@ -201,8 +271,44 @@ impl Packages {
         org: &str,
         state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
     ) -> Result<Vec<crate::types::PackageVersion>> {
+        let params = PackagesGetAllPackageVersionsForPackageOwnedByOrgParams {
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_all_package_versions_for_package_owned_by_org_with(
+            package_type,
+            package_name,
+            org,
+            &params,
+        )
+        .await
+    }
+
+    /**
+     * Get all package versions for a package owned by an organization.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns all package versions for a package owned by an organization.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-an-organization>
+     */
+    pub fn get_all_package_versions_for_package_owned_by_org_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PackageVersion> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -214,7 +320,22 @@ impl Packages {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -415,16 +536,16 @@ impl Packages {
      *
      * * `package_type: crate::types::PackageType` -- The type of supported package. Can be one of `npm`, `maven`, `rubygems`, `nuget`, `docker`, or `container`. Packages in GitHub's Gradle registry have the type `maven`. Docker images pushed to GitHub's Container registry (`ghcr.io`) have the type `container`. You can use the type `docker` to find images that were pushed to GitHub's Docker registry (`docker.pkg.github.com`), even if these have now been migrated to the Container registry.
      * * `package_name: &str` -- The name of the package.
-     * * `token: &str` -- package token.
+     * * `params: &PackagesRestorePackageForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn restore_package_for_authenticated_user(
+    pub async fn restore_package_for_authenticated_user_with(
         &self,
         package_type: crate::types::PackageType,
         package_name: &str,
-        token: &str,
+        params: &PackagesRestorePackageForAuthenticatedUserParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !token.is_empty() {
+        if let Some(token) = &params.token {
             query_args.push(("token".to_string(), token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -438,6 +559,23 @@ impl Packages {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `restore_package_for_authenticated_user_with` and pass the query parameters as a `PackagesRestorePackageForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `restore_package_for_authenticated_user_with` instead")]
+    pub async fn restore_package_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        token: &str,
+    ) -> Result<()> {
+        let params = PackagesRestorePackageForAuthenticatedUserParams {
+            token: Some(token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.restore_package_for_authenticated_user_with(package_type, package_name, &params)
+            .await
+    }
+
     /**
      * Get all package versions for a package owned by the authenticated user.
      *
@@ -454,26 +592,22 @@ impl Packages {
      *
      * * `package_type: crate::types::PackageType` -- The type of supported package. Can be one of `npm`, `maven`, `rubygems`, `nuget`, `docker`, or `container`. Packages in GitHub's Gradle registry have the type `maven`. Docker images pushed to GitHub's Container registry (`ghcr.io`) have the type `container`. You can use the type `docker` to find images that were pushed to GitHub's Docker registry (`docker.pkg.github.com`), even if these have now been migrated to the Container registry.
      * * `package_name: &str` -- The name of the package.
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState` -- The state of the package, either active or deleted.
+     * * `params: &PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_package_versions_for_package_owned_by_authenticated_user(
+    pub async fn get_all_package_versions_for_package_owned_by_authenticated_user_with(
         &self,
         package_type: crate::types::PackageType,
         package_name: &str,
-        page: i64,
-        per_page: i64,
-        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::PackageVersion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -487,6 +621,34 @@ impl Packages {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_all_package_versions_for_package_owned_by_authenticated_user_with` and pass the query parameters as a `PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams` instead.
+     */
+    #[deprecated(
+        note = "use `get_all_package_versions_for_package_owned_by_authenticated_user_with` \
+                instead"
+    )]
+    pub async fn get_all_package_versions_for_package_owned_by_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        page: i64,
+        per_page: i64,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByState,
+    ) -> Result<Vec<crate::types::PackageVersion>> {
+        let params = PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_all_package_versions_for_package_owned_by_authenticated_user_with(
+            package_type,
+            package_name,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Get all package versions for a package owned by the authenticated user.
      *
@@ -500,15 +662,19 @@ impl Packages {
      * If `package_type` is not `container`, your token must also include the `repo` scope.
      *
      * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-the-authenticated-user>
+     *
+     * **Parameters:**
+     *
+     * * `params: &PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_all_package_versions_for_package_owned_by_authenticated_user(
+    pub async fn get_all_all_package_versions_for_package_owned_by_authenticated_user_with(
         &self,
         package_type: crate::types::PackageType,
         package_name: &str,
-        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::PackageVersion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -522,6 +688,83 @@ impl Packages {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_all_all_package_versions_for_package_owned_by_authenticated_user_with` and pass the query parameters as a `PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams` instead.
+     */
+    #[deprecated(
+        note = "use `get_all_all_package_versions_for_package_owned_by_authenticated_user_with` \
+                instead"
+    )]
+    pub async fn get_all_all_package_versions_for_package_owned_by_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByState,
+    ) -> Result<Vec<crate::types::PackageVersion>> {
+        let params = PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams {
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_all_package_versions_for_package_owned_by_authenticated_user_with(
+            package_type,
+            package_name,
+            &params,
+        )
+        .await
+    }
+
+    /**
+     * Get all package versions for a package owned by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns all package versions for a package owned by the authenticated user.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-the-authenticated-user>
+     */
+    pub fn get_all_package_versions_for_package_owned_by_authenticated_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PackageVersion> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/user/packages/{}/{}/versions?{}",
+            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(package_name),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get a package version for the authenticated user.
      *
@@ -725,6 +968,53 @@ impl Packages {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get all package versions for a package owned by a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/packages/{package_type}/{package_name}/versions` endpoint.
+     *
+     * As opposed to `get_all_all_package_versions_for_package_owned_by_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns all package versions for a public package owned by a specified user.
+     *
+     * To use this endpoint, you must authenticate using an access token with the `packages:read` scope.
+     * If `package_type` is not `container`, your token must also include the `repo` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/packages#get-all-package-versions-for-a-package-owned-by-a-user>
+     */
+    pub fn get_all_package_versions_for_package_owned_by_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PackageVersion> {
+        let url = format!(
+            "/users/{}/packages/{}/{}/versions",
+            crate::progenitor_support::encode_path(username),
+            crate::progenitor_support::encode_path(&package_type.to_string()),
+            crate::progenitor_support::encode_path(package_name),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get a package version for a user.
      *
@@ -762,3 +1052,626 @@ impl Packages {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Packages::restore_package_for_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PackagesRestorePackageForOrgParams {
+    /// package token.
+    pub token: Option<String>,
+}
+
+impl PackagesRestorePackageForOrgParams {
+    pub fn token<T: ToString>(mut self, token: T) -> Self {
+        self.token = Some(token.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Packages::get_all_package_versions_for_package_owned_by_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PackagesGetAllPackageVersionsForPackageOwnedByOrgParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The state of the package, either active or deleted.
+    pub state: Option<crate::types::PackagesGetAllPackageVersionsOwnedByOrgState>,
+}
+
+impl PackagesGetAllPackageVersionsForPackageOwnedByOrgParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn state(
+        mut self,
+        state: crate::types::PackagesGetAllPackageVersionsOwnedByOrgState,
+    ) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+/// The query parameters of `Packages::restore_package_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PackagesRestorePackageForAuthenticatedUserParams {
+    /// package token.
+    pub token: Option<String>,
+}
+
+impl PackagesRestorePackageForAuthenticatedUserParams {
+    pub fn token<T: ToString>(mut self, token: T) -> Self {
+        self.token = Some(token.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Packages::get_all_package_versions_for_package_owned_by_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The state of the package, either active or deleted.
+    pub state: Option<crate::types::PackagesGetAllPackageVersionsOwnedByState>,
+}
+
+impl PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn state(mut self, state: crate::types::PackagesGetAllPackageVersionsOwnedByState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+/// The functions of [`Packages`], for code that takes them as a trait, so that it can be
+/// tested with the `MockPackagesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait PackagesApi: Send + Sync {
+    /// See [`Packages::get_package_for_organization`].
+    async fn get_package_for_organization(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+    ) -> Result<crate::types::Package>;
+
+    /// See [`Packages::delete_package_for_org`].
+    async fn delete_package_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+    ) -> Result<()>;
+
+    /// See [`Packages::restore_package_for_org_with`].
+    async fn restore_package_for_org_with(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        params: &PackagesRestorePackageForOrgParams,
+    ) -> Result<()>;
+
+    /// See [`Packages::get_all_package_versions_for_package_owned_by_org_with`].
+    async fn get_all_package_versions_for_package_owned_by_org_with(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByOrgParams,
+    ) -> Result<Vec<crate::types::PackageVersion>>;
+
+    /// See [`Packages::get_all_all_package_versions_for_package_owned_by_org_with`].
+    async fn get_all_all_package_versions_for_package_owned_by_org_with(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByOrgParams,
+    ) -> Result<Vec<crate::types::PackageVersion>>;
+
+    /// See [`Packages::get_all_package_versions_for_package_owned_by_org_stream`].
+    fn get_all_package_versions_for_package_owned_by_org_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PackageVersion>;
+
+    /// See [`Packages::get_package_version_for_organization`].
+    async fn get_package_version_for_organization(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> Result<crate::types::PackageVersion>;
+
+    /// See [`Packages::delete_package_version_for_org`].
+    async fn delete_package_version_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Packages::restore_package_version_for_org`].
+    async fn restore_package_version_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Packages::get_package_for_authenticated_user`].
+    async fn get_package_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+    ) -> Result<crate::types::Package>;
+
+    /// See [`Packages::delete_package_for_authenticated_user`].
+    async fn delete_package_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+    ) -> Result<()>;
+
+    /// See [`Packages::restore_package_for_authenticated_user_with`].
+    async fn restore_package_for_authenticated_user_with(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        params: &PackagesRestorePackageForAuthenticatedUserParams,
+    ) -> Result<()>;
+
+    /// See [`Packages::get_all_package_versions_for_package_owned_by_authenticated_user_with`].
+    async fn get_all_package_versions_for_package_owned_by_authenticated_user_with(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::PackageVersion>>;
+
+    /// See [`Packages::get_all_all_package_versions_for_package_owned_by_authenticated_user_with`].
+    async fn get_all_all_package_versions_for_package_owned_by_authenticated_user_with(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::PackageVersion>>;
+
+    /// See [`Packages::get_all_package_versions_for_package_owned_by_authenticated_user_stream`].
+    fn get_all_package_versions_for_package_owned_by_authenticated_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PackageVersion>;
+
+    /// See [`Packages::get_package_version_for_authenticated_user`].
+    async fn get_package_version_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> Result<crate::types::PackageVersion>;
+
+    /// See [`Packages::delete_package_version_for_authenticated_user`].
+    async fn delete_package_version_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Packages::restore_package_version_for_authenticated_user`].
+    async fn restore_package_version_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Packages::get_package_for_user`].
+    async fn get_package_for_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> Result<crate::types::Package>;
+
+    /// See [`Packages::get_all_package_versions_for_package_owned_by_user`].
+    async fn get_all_package_versions_for_package_owned_by_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> Result<Vec<crate::types::PackageVersion>>;
+
+    /// See [`Packages::get_all_all_package_versions_for_package_owned_by_user`].
+    async fn get_all_all_package_versions_for_package_owned_by_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> Result<Vec<crate::types::PackageVersion>>;
+
+    /// See [`Packages::get_all_package_versions_for_package_owned_by_user_stream`].
+    fn get_all_package_versions_for_package_owned_by_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PackageVersion>;
+
+    /// See [`Packages::get_package_version_for_user`].
+    async fn get_package_version_for_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+        username: &str,
+    ) -> Result<crate::types::PackageVersion>;
+}
+
+#[async_trait::async_trait]
+impl PackagesApi for Packages {
+    async fn get_package_for_organization(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+    ) -> Result<crate::types::Package> {
+        Packages::get_package_for_organization(self, package_type, package_name, org).await
+    }
+
+    async fn delete_package_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+    ) -> Result<()> {
+        Packages::delete_package_for_org(self, package_type, package_name, org).await
+    }
+
+    async fn restore_package_for_org_with(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        params: &PackagesRestorePackageForOrgParams,
+    ) -> Result<()> {
+        Packages::restore_package_for_org_with(self, package_type, package_name, org, params).await
+    }
+
+    async fn get_all_package_versions_for_package_owned_by_org_with(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByOrgParams,
+    ) -> Result<Vec<crate::types::PackageVersion>> {
+        Packages::get_all_package_versions_for_package_owned_by_org_with(
+            self,
+            package_type,
+            package_name,
+            org,
+            params,
+        )
+        .await
+    }
+
+    async fn get_all_all_package_versions_for_package_owned_by_org_with(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByOrgParams,
+    ) -> Result<Vec<crate::types::PackageVersion>> {
+        Packages::get_all_all_package_versions_for_package_owned_by_org_with(
+            self,
+            package_type,
+            package_name,
+            org,
+            params,
+        )
+        .await
+    }
+
+    fn get_all_package_versions_for_package_owned_by_org_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PackageVersion> {
+        Packages::get_all_package_versions_for_package_owned_by_org_stream(
+            self,
+            package_type,
+            package_name,
+            org,
+            params,
+            start,
+            max_items,
+        )
+    }
+
+    async fn get_package_version_for_organization(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> Result<crate::types::PackageVersion> {
+        Packages::get_package_version_for_organization(
+            self,
+            package_type,
+            package_name,
+            org,
+            package_version_id,
+        )
+        .await
+    }
+
+    async fn delete_package_version_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> Result<()> {
+        Packages::delete_package_version_for_org(
+            self,
+            package_type,
+            package_name,
+            org,
+            package_version_id,
+        )
+        .await
+    }
+
+    async fn restore_package_version_for_org(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        org: &str,
+        package_version_id: i64,
+    ) -> Result<()> {
+        Packages::restore_package_version_for_org(
+            self,
+            package_type,
+            package_name,
+            org,
+            package_version_id,
+        )
+        .await
+    }
+
+    async fn get_package_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+    ) -> Result<crate::types::Package> {
+        Packages::get_package_for_authenticated_user(self, package_type, package_name).await
+    }
+
+    async fn delete_package_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+    ) -> Result<()> {
+        Packages::delete_package_for_authenticated_user(self, package_type, package_name).await
+    }
+
+    async fn restore_package_for_authenticated_user_with(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        params: &PackagesRestorePackageForAuthenticatedUserParams,
+    ) -> Result<()> {
+        Packages::restore_package_for_authenticated_user_with(
+            self,
+            package_type,
+            package_name,
+            params,
+        )
+        .await
+    }
+
+    async fn get_all_package_versions_for_package_owned_by_authenticated_user_with(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::PackageVersion>> {
+        Packages::get_all_package_versions_for_package_owned_by_authenticated_user_with(
+            self,
+            package_type,
+            package_name,
+            params,
+        )
+        .await
+    }
+
+    async fn get_all_all_package_versions_for_package_owned_by_authenticated_user_with(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::PackageVersion>> {
+        Packages::get_all_all_package_versions_for_package_owned_by_authenticated_user_with(
+            self,
+            package_type,
+            package_name,
+            params,
+        )
+        .await
+    }
+
+    fn get_all_package_versions_for_package_owned_by_authenticated_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        params: &PackagesGetAllPackageVersionsForPackageOwnedByAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PackageVersion> {
+        Packages::get_all_package_versions_for_package_owned_by_authenticated_user_stream(
+            self,
+            package_type,
+            package_name,
+            params,
+            start,
+            max_items,
+        )
+    }
+
+    async fn get_package_version_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> Result<crate::types::PackageVersion> {
+        Packages::get_package_version_for_authenticated_user(
+            self,
+            package_type,
+            package_name,
+            package_version_id,
+        )
+        .await
+    }
+
+    async fn delete_package_version_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> Result<()> {
+        Packages::delete_package_version_for_authenticated_user(
+            self,
+            package_type,
+            package_name,
+            package_version_id,
+        )
+        .await
+    }
+
+    async fn restore_package_version_for_authenticated_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+    ) -> Result<()> {
+        Packages::restore_package_version_for_authenticated_user(
+            self,
+            package_type,
+            package_name,
+            package_version_id,
+        )
+        .await
+    }
+
+    async fn get_package_for_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> Result<crate::types::Package> {
+        Packages::get_package_for_user(self, package_type, package_name, username).await
+    }
+
+    async fn get_all_package_versions_for_package_owned_by_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> Result<Vec<crate::types::PackageVersion>> {
+        Packages::get_all_package_versions_for_package_owned_by_user(
+            self,
+            package_type,
+            package_name,
+            username,
+        )
+        .await
+    }
+
+    async fn get_all_all_package_versions_for_package_owned_by_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+    ) -> Result<Vec<crate::types::PackageVersion>> {
+        Packages::get_all_all_package_versions_for_package_owned_by_user(
+            self,
+            package_type,
+            package_name,
+            username,
+        )
+        .await
+    }
+
+    fn get_all_package_versions_for_package_owned_by_user_stream(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PackageVersion> {
+        Packages::get_all_package_versions_for_package_owned_by_user_stream(
+            self,
+            package_type,
+            package_name,
+            username,
+            start,
+            max_items,
+        )
+    }
+
+    async fn get_package_version_for_user(
+        &self,
+        package_type: crate::types::PackageType,
+        package_name: &str,
+        package_version_id: i64,
+        username: &str,
+    ) -> Result<crate::types::PackageVersion> {
+        Packages::get_package_version_for_user(
+            self,
+            package_type,
+            package_name,
+            package_version_id,
+            username,
+        )
+        .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Projects {
     pub client: Client,
@@ -24,25 +22,21 @@ impl Projects {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ProjectsListForOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_org(
+    pub async fn list_for_org_with(
         &self,
         org: &str,
-        state: crate::types::IssuesListState,
-        per_page: i64,
-        page: i64,
+        params: &ProjectsListForOrgParams,
     ) -> Result<Vec<crate::types::Project>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -55,6 +49,25 @@ impl Projects {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_org_with` and pass the query parameters as a `ProjectsListForOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_for_org_with` instead")]
+    pub async fn list_for_org(
+        &self,
+        org: &str,
+        state: crate::types::ProjectsListOrgState,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Project>> {
+        let params = ProjectsListForOrgParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_for_org_with(org, &params).await
+    }
+
     /**
      * List organization projects.
      *
@@ -65,14 +78,18 @@ impl Projects {
      * Lists the projects in an organization. Returns a `404 Not Found` status if projects are disabled in the organization. If you do not have sufficient privileges to perform this action, a `401 Unauthorized` or `410 Gone` status is returned.
      *
      * FROM: <https://docs.github.com/rest/reference/projects#list-organization-projects>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ProjectsListForOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_for_org(
+    pub async fn list_all_for_org_with(
         &self,
         org: &str,
-        state: crate::types::IssuesListState,
+        params: &ProjectsListForOrgParams,
     ) -> Result<Vec<crate::types::Project>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -85,6 +102,69 @@ impl Projects {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_all_for_org_with` and pass the query parameters as a `ProjectsListForOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_all_for_org_with` instead")]
+    pub async fn list_all_for_org(
+        &self,
+        org: &str,
+        state: crate::types::ProjectsListOrgState,
+    ) -> Result<Vec<crate::types::Project>> {
+        let params = ProjectsListForOrgParams {
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_org_with(org, &params).await
+    }
+
+    /**
+     * List organization projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the projects in an organization. Returns a `404 Not Found` status if projects are disabled in the organization. If you do not have sufficient privileges to perform this action, a `401 Unauthorized` or `410 Gone` status is returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-organization-projects>
+     */
+    pub fn list_for_org_stream(
+        &self,
+        org: &str,
+        params: &ProjectsListForOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Project> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/projects?{}",
+            crate::progenitor_support::encode_path(org),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create an organization project.
      *
@@ -101,7 +181,7 @@ impl Projects {
     pub async fn create_for_org(
         &self,
         org: &str,
-        body: &crate::types::ProjectsCreateRequest,
+        body: &crate::types::ProjectsCreateOrgRequest,
     ) -> Result<crate::types::Project> {
         let url = format!(
             "/orgs/{}/projects",
@@ -202,7 +282,7 @@ impl Projects {
         &self,
         card_id: i64,
         body: &crate::types::ProjectsMoveCardRequest,
-    ) -> Result<()> {
+    ) -> Result<crate::types::ProjectsMoveCardResponse> {
         let url = format!(
             "/projects/columns/cards/{}/moves",
             crate::progenitor_support::encode_path(&card_id.to_string()),
@@ -297,25 +377,21 @@ impl Projects {
      * **Parameters:**
      *
      * * `column_id: i64` -- column_id parameter.
-     * * `archived_state: crate::types::ArchivedState` -- Filters the project cards that are returned by the card's state. Can be one of `all`,`archived`, or `not_archived`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ProjectsListCardsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_cards(
+    pub async fn list_cards_with(
         &self,
         column_id: i64,
-        archived_state: crate::types::ArchivedState,
-        per_page: i64,
-        page: i64,
+        params: &ProjectsListCardsParams,
     ) -> Result<Vec<crate::types::ProjectCard>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !archived_state.to_string().is_empty() {
+        if let Some(archived_state) = &params.archived_state {
             query_args.push(("archived_state".to_string(), archived_state.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -328,6 +404,25 @@ impl Projects {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_cards_with` and pass the query parameters as a `ProjectsListCardsParams` instead.
+     */
+    #[deprecated(note = "use `list_cards_with` instead")]
+    pub async fn list_cards(
+        &self,
+        column_id: i64,
+        archived_state: crate::types::ArchivedState,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::ProjectCard>> {
+        let params = ProjectsListCardsParams {
+            archived_state: Some(archived_state).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_cards_with(column_id, &params).await
+    }
+
     /**
      * List project cards.
      *
@@ -338,14 +433,66 @@ impl Projects {
      *
      *
      * FROM: <https://docs.github.com/rest/reference/projects#list-project-cards>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ProjectsListCardsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_cards_with(
+        &self,
+        column_id: i64,
+        params: &ProjectsListCardsParams,
+    ) -> Result<Vec<crate::types::ProjectCard>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(archived_state) = &params.archived_state {
+            query_args.push(("archived_state".to_string(), archived_state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/projects/columns/{}/cards?{}",
+            crate::progenitor_support::encode_path(&column_id.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_cards_with` and pass the query parameters as a `ProjectsListCardsParams` instead.
      */
+    #[deprecated(note = "use `list_all_cards_with` instead")]
     pub async fn list_all_cards(
         &self,
         column_id: i64,
         archived_state: crate::types::ArchivedState,
     ) -> Result<Vec<crate::types::ProjectCard>> {
+        let params = ProjectsListCardsParams {
+            archived_state: Some(archived_state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_cards_with(column_id, &params).await
+    }
+
+    /**
+     * List project cards.
+     *
+     * This function performs a `GET` to the `/projects/columns/{column_id}/cards` endpoint.
+     *
+     * As opposed to `list_all_cards`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-cards>
+     */
+    pub fn list_cards_stream(
+        &self,
+        column_id: i64,
+        params: &ProjectsListCardsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ProjectCard> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !archived_state.to_string().is_empty() {
+        if let Some(archived_state) = &params.archived_state {
             query_args.push(("archived_state".to_string(), archived_state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -355,7 +502,22 @@ impl Projects {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -403,7 +565,7 @@ impl Projects {
         &self,
         column_id: i64,
         body: &crate::types::ProjectsMoveColumnRequest,
-    ) -> Result<()> {
+    ) -> Result<crate::types::ProjectsMoveColumnResponse> {
         let url = format!(
             "/projects/columns/{}/moves",
             crate::progenitor_support::encode_path(&column_id.to_string()),
@@ -498,28 +660,21 @@ impl Projects {
      * **Parameters:**
      *
      * * `project_id: i64`
-     * * `affiliation: crate::types::Affiliation` -- Filters the collaborators by their affiliation. Can be one of:  
-     *  \\* `outside`: Outside collaborators of a project that are not a member of the project's organization.  
-     *  \\* `direct`: Collaborators with permissions to a project, regardless of organization membership status.  
-     *  \\* `all`: All collaborators the authenticated user can see.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ProjectsListCollaboratorsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_collaborators(
+    pub async fn list_collaborators_with(
         &self,
         project_id: i64,
-        affiliation: crate::types::Affiliation,
-        per_page: i64,
-        page: i64,
+        params: &ProjectsListCollaboratorsParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !affiliation.to_string().is_empty() {
+        if let Some(affiliation) = &params.affiliation {
             query_args.push(("affiliation".to_string(), affiliation.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -532,6 +687,25 @@ impl Projects {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_collaborators_with` and pass the query parameters as a `ProjectsListCollaboratorsParams` instead.
+     */
+    #[deprecated(note = "use `list_collaborators_with` instead")]
+    pub async fn list_collaborators(
+        &self,
+        project_id: i64,
+        affiliation: crate::types::Affiliation,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = ProjectsListCollaboratorsParams {
+            affiliation: Some(affiliation).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_collaborators_with(project_id, &params).await
+    }
+
     /**
      * List project collaborators.
      *
@@ -542,14 +716,66 @@ impl Projects {
      * Lists the collaborators for an organization project. For a project, the list of collaborators includes outside collaborators, organization members that are direct collaborators, organization members with access through team memberships, organization members with access through default organization permissions, and organization owners. You must be an organization owner or a project `admin` to list collaborators.
      *
      * FROM: <https://docs.github.com/rest/reference/projects#list-project-collaborators>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ProjectsListCollaboratorsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_collaborators_with(
+        &self,
+        project_id: i64,
+        params: &ProjectsListCollaboratorsParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(affiliation) = &params.affiliation {
+            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/projects/{}/collaborators?{}",
+            crate::progenitor_support::encode_path(&project_id.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_collaborators_with` and pass the query parameters as a `ProjectsListCollaboratorsParams` instead.
      */
+    #[deprecated(note = "use `list_all_collaborators_with` instead")]
     pub async fn list_all_collaborators(
         &self,
         project_id: i64,
         affiliation: crate::types::Affiliation,
     ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = ProjectsListCollaboratorsParams {
+            affiliation: Some(affiliation).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_collaborators_with(project_id, &params).await
+    }
+
+    /**
+     * List project collaborators.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/collaborators` endpoint.
+     *
+     * As opposed to `list_all_collaborators`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the collaborators for an organization project. For a project, the list of collaborators includes outside collaborators, organization members that are direct collaborators, organization members with access through team memberships, organization members with access through default organization permissions, and organization owners. You must be an organization owner or a project `admin` to list collaborators.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-collaborators>
+     */
+    pub fn list_collaborators_stream(
+        &self,
+        project_id: i64,
+        params: &ProjectsListCollaboratorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !affiliation.to_string().is_empty() {
+        if let Some(affiliation) = &params.affiliation {
             query_args.push(("affiliation".to_string(), affiliation.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -559,7 +785,22 @@ impl Projects {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -657,20 +898,18 @@ impl Projects {
      * **Parameters:**
      *
      * * `project_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ProjectsListColumnsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_columns(
+    pub async fn list_columns_with(
         &self,
         project_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &ProjectsListColumnsParams,
     ) -> Result<Vec<crate::types::ProjectColumn>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -683,6 +922,23 @@ impl Projects {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_columns_with` and pass the query parameters as a `ProjectsListColumnsParams` instead.
+     */
+    #[deprecated(note = "use `list_columns_with` instead")]
+    pub async fn list_columns(
+        &self,
+        project_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::ProjectColumn>> {
+        let params = ProjectsListColumnsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_columns_with(project_id, &params).await
+    }
+
     /**
      * List project columns.
      *
@@ -706,6 +962,46 @@ impl Projects {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List project columns.
+     *
+     * This function performs a `GET` to the `/projects/{project_id}/columns` endpoint.
+     *
+     * As opposed to `list_all_columns`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-project-columns>
+     */
+    pub fn list_columns_stream(
+        &self,
+        project_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ProjectColumn> {
+        let url = format!(
+            "/projects/{}/columns",
+            crate::progenitor_support::encode_path(&project_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a project column.
      *
@@ -722,7 +1018,7 @@ impl Projects {
     pub async fn create_column(
         &self,
         project_id: i64,
-        body: &crate::types::ProjectsUpdateColumnRequest,
+        body: &crate::types::ProjectsCreateColumnRequest,
     ) -> Result<crate::types::ProjectColumn> {
         let url = format!(
             "/projects/{}/columns",
@@ -747,26 +1043,22 @@ impl Projects {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ProjectsListForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_repo(
+    pub async fn list_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::IssuesListState,
-        per_page: i64,
-        page: i64,
+        params: &ProjectsListForRepoParams,
     ) -> Result<Vec<crate::types::Project>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -780,6 +1072,26 @@ impl Projects {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_repo_with` and pass the query parameters as a `ProjectsListForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_for_repo_with` instead")]
+    pub async fn list_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::ProjectsListRepoState,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Project>> {
+        let params = ProjectsListForRepoParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_for_repo_with(owner, repo, &params).await
+    }
+
     /**
      * List repository projects.
      *
@@ -790,15 +1102,19 @@ impl Projects {
      * Lists the projects in a repository. Returns a `404 Not Found` status if projects are disabled in the repository. If you do not have sufficient privileges to perform this action, a `401 Unauthorized` or `410 Gone` status is returned.
      *
      * FROM: <https://docs.github.com/rest/reference/projects#list-repository-projects>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ProjectsListForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_for_repo(
+    pub async fn list_all_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::IssuesListState,
+        params: &ProjectsListForRepoParams,
     ) -> Result<Vec<crate::types::Project>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -812,6 +1128,72 @@ impl Projects {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_all_for_repo_with` and pass the query parameters as a `ProjectsListForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_all_for_repo_with` instead")]
+    pub async fn list_all_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::ProjectsListRepoState,
+    ) -> Result<Vec<crate::types::Project>> {
+        let params = ProjectsListForRepoParams {
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_repo_with(owner, repo, &params).await
+    }
+
+    /**
+     * List repository projects.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_repo`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the projects in a repository. Returns a `404 Not Found` status if projects are disabled in the repository. If you do not have sufficient privileges to perform this action, a `401 Unauthorized` or `410 Gone` status is returned.
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-repository-projects>
+     */
+    pub fn list_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ProjectsListForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Project> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/projects?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a repository project.
      *
@@ -830,7 +1212,7 @@ impl Projects {
         &self,
         owner: &str,
         repo: &str,
-        body: &crate::types::ProjectsCreateRequest,
+        body: &crate::types::ProjectsCreateRepoRequest,
     ) -> Result<crate::types::Project> {
         let url = format!(
             "/repos/{}/{}/projects",
@@ -874,25 +1256,21 @@ impl Projects {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ProjectsListForUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_user(
+    pub async fn list_for_user_with(
         &self,
         username: &str,
-        state: crate::types::IssuesListState,
-        per_page: i64,
-        page: i64,
+        params: &ProjectsListForUserParams,
     ) -> Result<Vec<crate::types::Project>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -905,6 +1283,25 @@ impl Projects {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_user_with` and pass the query parameters as a `ProjectsListForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_for_user_with` instead")]
+    pub async fn list_for_user(
+        &self,
+        username: &str,
+        state: crate::types::ProjectsListUserState,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Project>> {
+        let params = ProjectsListForUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_for_user_with(username, &params).await
+    }
+
     /**
      * List user projects.
      *
@@ -915,14 +1312,18 @@ impl Projects {
      *
      *
      * FROM: <https://docs.github.com/rest/reference/projects#list-user-projects>
-     */
-    pub async fn list_all_for_user(
+     *
+     * **Parameters:**
+     *
+     * * `params: &ProjectsListForUserParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_user_with(
         &self,
         username: &str,
-        state: crate::types::IssuesListState,
+        params: &ProjectsListForUserParams,
     ) -> Result<Vec<crate::types::Project>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -934,4 +1335,761 @@ impl Projects {
 
         self.client.get_all_pages(&url, None).await
     }
+
+    /**
+     * Deprecated: use `list_all_for_user_with` and pass the query parameters as a `ProjectsListForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_all_for_user_with` instead")]
+    pub async fn list_all_for_user(
+        &self,
+        username: &str,
+        state: crate::types::ProjectsListUserState,
+    ) -> Result<Vec<crate::types::Project>> {
+        let params = ProjectsListForUserParams {
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_user_with(username, &params).await
+    }
+
+    /**
+     * List user projects.
+     *
+     * This function performs a `GET` to the `/users/{username}/projects` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/projects#list-user-projects>
+     */
+    pub fn list_for_user_stream(
+        &self,
+        username: &str,
+        params: &ProjectsListForUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Project> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/users/{}/projects?{}",
+            crate::progenitor_support::encode_path(username),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Projects::list_for_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectsListForOrgParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Indicates the state of the projects to return. Can be either `open`, `closed`, or `all`.
+    pub state: Option<crate::types::ProjectsListOrgState>,
+}
+
+impl ProjectsListForOrgParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn state(mut self, state: crate::types::ProjectsListOrgState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+/// The query parameters of `Projects::list_cards_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectsListCardsParams {
+    /// Filters the project cards that are returned by the card's state. Can be one of `all`,`archived`, or `not_archived`.
+    pub archived_state: Option<crate::types::ArchivedState>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ProjectsListCardsParams {
+    pub fn archived_state(mut self, archived_state: crate::types::ArchivedState) -> Self {
+        self.archived_state = Some(archived_state);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Projects::list_collaborators_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectsListCollaboratorsParams {
+    /// Filter collaborators returned by their affiliation. Can be one of:  
+    /// \\* `outside`: All outside collaborators of an organization-owned repository.  
+    /// \\* `direct`: All collaborators with permissions to an organization-owned repository, regardless of organization membership status.  
+    /// \\* `all`: All collaborators the authenticated user can see.
+    pub affiliation: Option<crate::types::Affiliation>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ProjectsListCollaboratorsParams {
+    pub fn affiliation(mut self, affiliation: crate::types::Affiliation) -> Self {
+        self.affiliation = Some(affiliation);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Projects::list_columns_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectsListColumnsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ProjectsListColumnsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Projects::list_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectsListForRepoParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Indicates the state of the projects to return. Can be either `open`, `closed`, or `all`.
+    pub state: Option<crate::types::ProjectsListRepoState>,
+}
+
+impl ProjectsListForRepoParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn state(mut self, state: crate::types::ProjectsListRepoState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+/// The query parameters of `Projects::list_for_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProjectsListForUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Indicates the state of the projects to return. Can be either `open`, `closed`, or `all`.
+    pub state: Option<crate::types::ProjectsListUserState>,
+}
+
+impl ProjectsListForUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn state(mut self, state: crate::types::ProjectsListUserState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+/// The functions of [`Projects`], for code that takes them as a trait, so that it can be
+/// tested with the `MockProjectsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ProjectsApi: Send + Sync {
+    /// See [`Projects::list_for_org_with`].
+    async fn list_for_org_with(
+        &self,
+        org: &str,
+        params: &ProjectsListForOrgParams,
+    ) -> Result<Vec<crate::types::Project>>;
+
+    /// See [`Projects::list_all_for_org_with`].
+    async fn list_all_for_org_with(
+        &self,
+        org: &str,
+        params: &ProjectsListForOrgParams,
+    ) -> Result<Vec<crate::types::Project>>;
+
+    /// See [`Projects::list_for_org_stream`].
+    fn list_for_org_stream(
+        &self,
+        org: &str,
+        params: &ProjectsListForOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Project>;
+
+    /// See [`Projects::create_for_org`].
+    async fn create_for_org(
+        &self,
+        org: &str,
+        body: &crate::types::ProjectsCreateOrgRequest,
+    ) -> Result<crate::types::Project>;
+
+    /// See [`Projects::get_card`].
+    async fn get_card(&self, card_id: i64) -> Result<crate::types::ProjectCard>;
+
+    /// See [`Projects::delete_card`].
+    async fn delete_card(&self, card_id: i64) -> Result<()>;
+
+    /// See [`Projects::update_card`].
+    async fn update_card(
+        &self,
+        card_id: i64,
+        body: &crate::types::ProjectsUpdateCardRequest,
+    ) -> Result<crate::types::ProjectCard>;
+
+    /// See [`Projects::move_card`].
+    async fn move_card(
+        &self,
+        card_id: i64,
+        body: &crate::types::ProjectsMoveCardRequest,
+    ) -> Result<crate::types::ProjectsMoveCardResponse>;
+
+    /// See [`Projects::get_column`].
+    async fn get_column(&self, column_id: i64) -> Result<crate::types::ProjectColumn>;
+
+    /// See [`Projects::delete_column`].
+    async fn delete_column(&self, column_id: i64) -> Result<()>;
+
+    /// See [`Projects::update_column`].
+    async fn update_column(
+        &self,
+        column_id: i64,
+        body: &crate::types::ProjectsUpdateColumnRequest,
+    ) -> Result<crate::types::ProjectColumn>;
+
+    /// See [`Projects::list_cards_with`].
+    async fn list_cards_with(
+        &self,
+        column_id: i64,
+        params: &ProjectsListCardsParams,
+    ) -> Result<Vec<crate::types::ProjectCard>>;
+
+    /// See [`Projects::list_all_cards_with`].
+    async fn list_all_cards_with(
+        &self,
+        column_id: i64,
+        params: &ProjectsListCardsParams,
+    ) -> Result<Vec<crate::types::ProjectCard>>;
+
+    /// See [`Projects::list_cards_stream`].
+    fn list_cards_stream(
+        &self,
+        column_id: i64,
+        params: &ProjectsListCardsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ProjectCard>;
+
+    /// See [`Projects::create_card`].
+    async fn create_card(
+        &self,
+        column_id: i64,
+        body: &crate::types::ProjectsCreateCardRequestOneOf,
+    ) -> Result<crate::types::ProjectCard>;
+
+    /// See [`Projects::move_column`].
+    async fn move_column(
+        &self,
+        column_id: i64,
+        body: &crate::types::ProjectsMoveColumnRequest,
+    ) -> Result<crate::types::ProjectsMoveColumnResponse>;
+
+    /// See [`Projects::get`].
+    async fn get(&self, project_id: i64) -> Result<crate::types::Project>;
+
+    /// See [`Projects::delete`].
+    async fn delete(&self, project_id: i64) -> Result<()>;
+
+    /// See [`Projects::update`].
+    async fn update(
+        &self,
+        project_id: i64,
+        body: &crate::types::ProjectsUpdateRequest,
+    ) -> Result<crate::types::Project>;
+
+    /// See [`Projects::list_collaborators_with`].
+    async fn list_collaborators_with(
+        &self,
+        project_id: i64,
+        params: &ProjectsListCollaboratorsParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Projects::list_all_collaborators_with`].
+    async fn list_all_collaborators_with(
+        &self,
+        project_id: i64,
+        params: &ProjectsListCollaboratorsParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Projects::list_collaborators_stream`].
+    fn list_collaborators_stream(
+        &self,
+        project_id: i64,
+        params: &ProjectsListCollaboratorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Projects::add_collaborator`].
+    async fn add_collaborator(
+        &self,
+        project_id: i64,
+        username: &str,
+        body: &crate::types::ProjectsAddCollaboratorRequest,
+    ) -> Result<()>;
+
+    /// See [`Projects::remove_collaborator`].
+    async fn remove_collaborator(&self, project_id: i64, username: &str) -> Result<()>;
+
+    /// See [`Projects::get_permission_for_user`].
+    async fn get_permission_for_user(
+        &self,
+        project_id: i64,
+        username: &str,
+    ) -> Result<crate::types::RepositoryCollaboratorPermission>;
+
+    /// See [`Projects::list_columns_with`].
+    async fn list_columns_with(
+        &self,
+        project_id: i64,
+        params: &ProjectsListColumnsParams,
+    ) -> Result<Vec<crate::types::ProjectColumn>>;
+
+    /// See [`Projects::list_all_columns`].
+    async fn list_all_columns(&self, project_id: i64) -> Result<Vec<crate::types::ProjectColumn>>;
+
+    /// See [`Projects::list_columns_stream`].
+    fn list_columns_stream(
+        &self,
+        project_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ProjectColumn>;
+
+    /// See [`Projects::create_column`].
+    async fn create_column(
+        &self,
+        project_id: i64,
+        body: &crate::types::ProjectsCreateColumnRequest,
+    ) -> Result<crate::types::ProjectColumn>;
+
+    /// See [`Projects::list_for_repo_with`].
+    async fn list_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ProjectsListForRepoParams,
+    ) -> Result<Vec<crate::types::Project>>;
+
+    /// See [`Projects::list_all_for_repo_with`].
+    async fn list_all_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ProjectsListForRepoParams,
+    ) -> Result<Vec<crate::types::Project>>;
+
+    /// See [`Projects::list_for_repo_stream`].
+    fn list_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ProjectsListForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Project>;
+
+    /// See [`Projects::create_for_repo`].
+    async fn create_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ProjectsCreateRepoRequest,
+    ) -> Result<crate::types::Project>;
+
+    /// See [`Projects::create_for_authenticated_user`].
+    async fn create_for_authenticated_user(
+        &self,
+        body: &crate::types::ProjectsCreateRequest,
+    ) -> Result<crate::types::Project>;
+
+    /// See [`Projects::list_for_user_with`].
+    async fn list_for_user_with(
+        &self,
+        username: &str,
+        params: &ProjectsListForUserParams,
+    ) -> Result<Vec<crate::types::Project>>;
+
+    /// See [`Projects::list_all_for_user_with`].
+    async fn list_all_for_user_with(
+        &self,
+        username: &str,
+        params: &ProjectsListForUserParams,
+    ) -> Result<Vec<crate::types::Project>>;
+
+    /// See [`Projects::list_for_user_stream`].
+    fn list_for_user_stream(
+        &self,
+        username: &str,
+        params: &ProjectsListForUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Project>;
+}
+
+#[async_trait::async_trait]
+impl ProjectsApi for Projects {
+    async fn list_for_org_with(
+        &self,
+        org: &str,
+        params: &ProjectsListForOrgParams,
+    ) -> Result<Vec<crate::types::Project>> {
+        Projects::list_for_org_with(self, org, params).await
+    }
+
+    async fn list_all_for_org_with(
+        &self,
+        org: &str,
+        params: &ProjectsListForOrgParams,
+    ) -> Result<Vec<crate::types::Project>> {
+        Projects::list_all_for_org_with(self, org, params).await
+    }
+
+    fn list_for_org_stream(
+        &self,
+        org: &str,
+        params: &ProjectsListForOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Project> {
+        Projects::list_for_org_stream(self, org, params, start, max_items)
+    }
+
+    async fn create_for_org(
+        &self,
+        org: &str,
+        body: &crate::types::ProjectsCreateOrgRequest,
+    ) -> Result<crate::types::Project> {
+        Projects::create_for_org(self, org, body).await
+    }
+
+    async fn get_card(&self, card_id: i64) -> Result<crate::types::ProjectCard> {
+        Projects::get_card(self, card_id).await
+    }
+
+    async fn delete_card(&self, card_id: i64) -> Result<()> {
+        Projects::delete_card(self, card_id).await
+    }
+
+    async fn update_card(
+        &self,
+        card_id: i64,
+        body: &crate::types::ProjectsUpdateCardRequest,
+    ) -> Result<crate::types::ProjectCard> {
+        Projects::update_card(self, card_id, body).await
+    }
+
+    async fn move_card(
+        &self,
+        card_id: i64,
+        body: &crate::types::ProjectsMoveCardRequest,
+    ) -> Result<crate::types::ProjectsMoveCardResponse> {
+        Projects::move_card(self, card_id, body).await
+    }
+
+    async fn get_column(&self, column_id: i64) -> Result<crate::types::ProjectColumn> {
+        Projects::get_column(self, column_id).await
+    }
+
+    async fn delete_column(&self, column_id: i64) -> Result<()> {
+        Projects::delete_column(self, column_id).await
+    }
+
+    async fn update_column(
+        &self,
+        column_id: i64,
+        body: &crate::types::ProjectsUpdateColumnRequest,
+    ) -> Result<crate::types::ProjectColumn> {
+        Projects::update_column(self, column_id, body).await
+    }
+
+    async fn list_cards_with(
+        &self,
+        column_id: i64,
+        params: &ProjectsListCardsParams,
+    ) -> Result<Vec<crate::types::ProjectCard>> {
+        Projects::list_cards_with(self, column_id, params).await
+    }
+
+    async fn list_all_cards_with(
+        &self,
+        column_id: i64,
+        params: &ProjectsListCardsParams,
+    ) -> Result<Vec<crate::types::ProjectCard>> {
+        Projects::list_all_cards_with(self, column_id, params).await
+    }
+
+    fn list_cards_stream(
+        &self,
+        column_id: i64,
+        params: &ProjectsListCardsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ProjectCard> {
+        Projects::list_cards_stream(self, column_id, params, start, max_items)
+    }
+
+    async fn create_card(
+        &self,
+        column_id: i64,
+        body: &crate::types::ProjectsCreateCardRequestOneOf,
+    ) -> Result<crate::types::ProjectCard> {
+        Projects::create_card(self, column_id, body).await
+    }
+
+    async fn move_column(
+        &self,
+        column_id: i64,
+        body: &crate::types::ProjectsMoveColumnRequest,
+    ) -> Result<crate::types::ProjectsMoveColumnResponse> {
+        Projects::move_column(self, column_id, body).await
+    }
+
+    async fn get(&self, project_id: i64) -> Result<crate::types::Project> {
+        Projects::get(self, project_id).await
+    }
+
+    async fn delete(&self, project_id: i64) -> Result<()> {
+        Projects::delete(self, project_id).await
+    }
+
+    async fn update(
+        &self,
+        project_id: i64,
+        body: &crate::types::ProjectsUpdateRequest,
+    ) -> Result<crate::types::Project> {
+        Projects::update(self, project_id, body).await
+    }
+
+    async fn list_collaborators_with(
+        &self,
+        project_id: i64,
+        params: &ProjectsListCollaboratorsParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Projects::list_collaborators_with(self, project_id, params).await
+    }
+
+    async fn list_all_collaborators_with(
+        &self,
+        project_id: i64,
+        params: &ProjectsListCollaboratorsParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Projects::list_all_collaborators_with(self, project_id, params).await
+    }
+
+    fn list_collaborators_stream(
+        &self,
+        project_id: i64,
+        params: &ProjectsListCollaboratorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Projects::list_collaborators_stream(self, project_id, params, start, max_items)
+    }
+
+    async fn add_collaborator(
+        &self,
+        project_id: i64,
+        username: &str,
+        body: &crate::types::ProjectsAddCollaboratorRequest,
+    ) -> Result<()> {
+        Projects::add_collaborator(self, project_id, username, body).await
+    }
+
+    async fn remove_collaborator(&self, project_id: i64, username: &str) -> Result<()> {
+        Projects::remove_collaborator(self, project_id, username).await
+    }
+
+    async fn get_permission_for_user(
+        &self,
+        project_id: i64,
+        username: &str,
+    ) -> Result<crate::types::RepositoryCollaboratorPermission> {
+        Projects::get_permission_for_user(self, project_id, username).await
+    }
+
+    async fn list_columns_with(
+        &self,
+        project_id: i64,
+        params: &ProjectsListColumnsParams,
+    ) -> Result<Vec<crate::types::ProjectColumn>> {
+        Projects::list_columns_with(self, project_id, params).await
+    }
+
+    async fn list_all_columns(&self, project_id: i64) -> Result<Vec<crate::types::ProjectColumn>> {
+        Projects::list_all_columns(self, project_id).await
+    }
+
+    fn list_columns_stream(
+        &self,
+        project_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ProjectColumn> {
+        Projects::list_columns_stream(self, project_id, start, max_items)
+    }
+
+    async fn create_column(
+        &self,
+        project_id: i64,
+        body: &crate::types::ProjectsCreateColumnRequest,
+    ) -> Result<crate::types::ProjectColumn> {
+        Projects::create_column(self, project_id, body).await
+    }
+
+    async fn list_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ProjectsListForRepoParams,
+    ) -> Result<Vec<crate::types::Project>> {
+        Projects::list_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ProjectsListForRepoParams,
+    ) -> Result<Vec<crate::types::Project>> {
+        Projects::list_all_for_repo_with(self, owner, repo, params).await
+    }
+
+    fn list_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ProjectsListForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Project> {
+        Projects::list_for_repo_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn create_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ProjectsCreateRepoRequest,
+    ) -> Result<crate::types::Project> {
+        Projects::create_for_repo(self, owner, repo, body).await
+    }
+
+    async fn create_for_authenticated_user(
+        &self,
+        body: &crate::types::ProjectsCreateRequest,
+    ) -> Result<crate::types::Project> {
+        Projects::create_for_authenticated_user(self, body).await
+    }
+
+    async fn list_for_user_with(
+        &self,
+        username: &str,
+        params: &ProjectsListForUserParams,
+    ) -> Result<Vec<crate::types::Project>> {
+        Projects::list_for_user_with(self, username, params).await
+    }
+
+    async fn list_all_for_user_with(
+        &self,
+        username: &str,
+        params: &ProjectsListForUserParams,
+    ) -> Result<Vec<crate::types::Project>> {
+        Projects::list_all_for_user_with(self, username, params).await
+    }
+
+    fn list_for_user_stream(
+        &self,
+        username: &str,
+        params: &ProjectsListForUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Project> {
+        Projects::list_for_user_stream(self, username, params, start, max_items)
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Pulls {
     pub client: Client,
@@ -25,48 +23,34 @@ impl Pulls {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `state: crate::types::IssuesListState` -- Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
-     * * `head: &str` -- Filter pulls by head user or head organization and branch name in the format of `user:ref-name` or `organization:ref-name`. For example: `github:new-script-format` or `octocat:test-branch`.
-     * * `base: &str` -- Filter pulls by base branch name. Example: `gh-pages`.
-     * * `sort: crate::types::PullsListSort` -- What to sort results by. Can be either `created`, `updated`, `popularity` (comment count) or `long-running` (age, filtering by pulls updated in the last month).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::IssuesListState,
-        head: &str,
-        base: &str,
-        sort: crate::types::PullsListSort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &PullsListParams,
     ) -> Result<Vec<crate::types::PullRequestSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !base.is_empty() {
+        if let Some(base) = &params.base {
             query_args.push(("base".to_string(), base.to_string()));
         }
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !head.is_empty() {
+        if let Some(head) = &params.head {
             query_args.push(("head".to_string(), head.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -80,6 +64,34 @@ impl Pulls {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `PullsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::PullsListState,
+        head: &str,
+        base: &str,
+        sort: crate::types::PullsListSort,
+        direction: crate::types::Direction,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::PullRequestSimple>> {
+        let params = PullsListParams {
+            base: Some(base.to_string()).filter(|s| !s.is_empty()),
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            head: Some(head.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_with(owner, repo, &params).await
+    }
+
     /**
      * List pull requests.
      *
@@ -90,31 +102,102 @@ impl Pulls {
      * Draft pull requests are available in public repositories with GitHub Free and GitHub Free for organizations, GitHub Pro, and legacy per-repository billing plans, and in public and private repositories with GitHub Team and GitHub Enterprise Cloud. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
      *
      * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests>
+     *
+     * **Parameters:**
+     *
+     * * `params: &PullsListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListParams,
+    ) -> Result<Vec<crate::types::PullRequestSimple>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(base) = &params.base {
+            query_args.push(("base".to_string(), base.to_string()));
+        }
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(head) = &params.head {
+            query_args.push(("head".to_string(), head.to_string()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/pulls?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `PullsListParams` instead.
      */
+    #[deprecated(note = "use `list_all_with` instead")]
     pub async fn list_all(
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::IssuesListState,
+        state: crate::types::PullsListState,
         head: &str,
         base: &str,
         sort: crate::types::PullsListSort,
-        direction: crate::types::Order,
+        direction: crate::types::Direction,
     ) -> Result<Vec<crate::types::PullRequestSimple>> {
+        let params = PullsListParams {
+            base: Some(base.to_string()).filter(|s| !s.is_empty()),
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            head: Some(head.to_string()).filter(|s| !s.is_empty()),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(owner, repo, &params).await
+    }
+
+    /**
+     * List pull requests.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Draft pull requests are available in public repositories with GitHub Free and GitHub Free for organizations, GitHub Pro, and legacy per-repository billing plans, and in public and private repositories with GitHub Team and GitHub Enterprise Cloud. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests>
+     */
+    pub fn list_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestSimple> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !base.is_empty() {
+        if let Some(base) = &params.base {
             query_args.push(("base".to_string(), base.to_string()));
         }
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !head.is_empty() {
+        if let Some(head) = &params.head {
             query_args.push(("head".to_string(), head.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -125,7 +208,22 @@ impl Pulls {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -153,7 +251,7 @@ impl Pulls {
         owner: &str,
         repo: &str,
         body: &crate::types::PullsCreateRequest,
-    ) -> Result<crate::types::PullRequestData> {
+    ) -> Result<crate::types::PullRequest> {
         let url = format!(
             "/repos/{}/{}/pulls",
             crate::progenitor_support::encode_path(owner),
@@ -178,38 +276,28 @@ impl Pulls {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `sort: crate::types::PullsListReviewCommentsRepoSort`
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListReviewCommentsForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_review_comments_for_repo(
+    pub async fn list_review_comments_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        sort: crate::types::PullsListReviewCommentsRepoSort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &PullsListReviewCommentsForRepoParams,
     ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -223,6 +311,31 @@ impl Pulls {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_review_comments_for_repo_with` and pass the query parameters as a `PullsListReviewCommentsForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_review_comments_for_repo_with` instead")]
+    pub async fn list_review_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::PullsListReviewCommentsRepoSort,
+        direction: crate::types::Direction,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
+        let params = PullsListReviewCommentsForRepoParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_review_comments_for_repo_with(owner, repo, &params)
+            .await
+    }
+
     /**
      * List review comments in a repository.
      *
@@ -233,23 +346,87 @@ impl Pulls {
      * Lists review comments for all pull requests in a repository. By default, review comments are in ascending order by ID.
      *
      * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-in-a-repository>
+     *
+     * **Parameters:**
+     *
+     * * `params: &PullsListReviewCommentsForRepoParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_review_comments_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListReviewCommentsForRepoParams,
+    ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/pulls/comments?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_review_comments_for_repo_with` and pass the query parameters as a `PullsListReviewCommentsForRepoParams` instead.
      */
+    #[deprecated(note = "use `list_all_review_comments_for_repo_with` instead")]
     pub async fn list_all_review_comments_for_repo(
         &self,
         owner: &str,
         repo: &str,
         sort: crate::types::PullsListReviewCommentsRepoSort,
-        direction: crate::types::Order,
+        direction: crate::types::Direction,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
+        let params = PullsListReviewCommentsForRepoParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_review_comments_for_repo_with(owner, repo, &params)
+            .await
+    }
+
+    /**
+     * List review comments in a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments` endpoint.
+     *
+     * As opposed to `list_all_review_comments_for_repo`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists review comments for all pull requests in a repository. By default, review comments are in ascending order by ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-in-a-repository>
+     */
+    pub fn list_review_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListReviewCommentsForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestReviewComment> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -260,7 +437,22 @@ impl Pulls {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -345,7 +537,7 @@ impl Pulls {
         owner: &str,
         repo: &str,
         comment_id: i64,
-        body: &crate::types::PullsUpdateReviewRequest,
+        body: &crate::types::PullsUpdateReviewCommentRequest,
     ) -> Result<crate::types::PullRequestReviewComment> {
         let url = format!(
             "/repos/{}/{}/pulls/comments/{}",
@@ -393,7 +585,7 @@ impl Pulls {
         owner: &str,
         repo: &str,
         pull_number: i64,
-    ) -> Result<crate::types::PullRequestData> {
+    ) -> Result<crate::types::PullRequest> {
         let url = format!(
             "/repos/{}/{}/pulls/{}",
             crate::progenitor_support::encode_path(owner),
@@ -427,7 +619,7 @@ impl Pulls {
         repo: &str,
         pull_number: i64,
         body: &crate::types::PullsUpdateRequest,
-    ) -> Result<crate::types::PullRequestData> {
+    ) -> Result<crate::types::PullRequest> {
         let url = format!(
             "/repos/{}/{}/pulls/{}",
             crate::progenitor_support::encode_path(owner),
@@ -454,39 +646,29 @@ impl Pulls {
      * * `owner: &str`
      * * `repo: &str`
      * * `pull_number: i64`
-     * * `sort: crate::types::Sort` -- One of `created` (when the repository was starred) or `updated` (when it was last pushed to).
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListReviewCommentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_review_comments(
+    pub async fn list_review_comments_with(
         &self,
         owner: &str,
         repo: &str,
         pull_number: i64,
-        sort: crate::types::Sort,
-        direction: crate::types::Order,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
+        params: &PullsListReviewCommentsParams,
     ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -501,6 +683,32 @@ impl Pulls {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_review_comments_with` and pass the query parameters as a `PullsListReviewCommentsParams` instead.
+     */
+    #[deprecated(note = "use `list_review_comments_with` instead")]
+    pub async fn list_review_comments(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        sort: crate::types::Sort,
+        direction: crate::types::Direction,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
+        let params = PullsListReviewCommentsParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_review_comments_with(owner, repo, pull_number, &params)
+            .await
+    }
+
     /**
      * List review comments on a pull request.
      *
@@ -511,24 +719,91 @@ impl Pulls {
      * Lists all review comments for a pull request. By default, review comments are in ascending order by ID.
      *
      * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-on-a-pull-request>
+     *
+     * **Parameters:**
+     *
+     * * `params: &PullsListReviewCommentsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_review_comments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListReviewCommentsParams,
+    ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/pulls/{}/comments?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_review_comments_with` and pass the query parameters as a `PullsListReviewCommentsParams` instead.
      */
+    #[deprecated(note = "use `list_all_review_comments_with` instead")]
     pub async fn list_all_review_comments(
         &self,
         owner: &str,
         repo: &str,
         pull_number: i64,
         sort: crate::types::Sort,
-        direction: crate::types::Order,
+        direction: crate::types::Direction,
         since: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
+        let params = PullsListReviewCommentsParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_review_comments_with(owner, repo, pull_number, &params)
+            .await
+    }
+
+    /**
+     * List review comments on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_review_comments`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all review comments for a pull request. By default, review comments are in ascending order by ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-review-comments-on-a-pull-request>
+     */
+    pub fn list_review_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListReviewCommentsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestReviewComment> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -540,7 +815,22 @@ impl Pulls {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -608,7 +898,7 @@ impl Pulls {
         repo: &str,
         pull_number: i64,
         comment_id: i64,
-        body: &crate::types::PullsUpdateReviewRequest,
+        body: &crate::types::PullsCreateReplyReviewCommentRequest,
     ) -> Result<crate::types::PullRequestReviewComment> {
         let url = format!(
             "/repos/{}/{}/pulls/{}/comments/{}/replies",
@@ -637,22 +927,20 @@ impl Pulls {
      * * `owner: &str`
      * * `repo: &str`
      * * `pull_number: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListCommitsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_commits(
+    pub async fn list_commits_with(
         &self,
         owner: &str,
         repo: &str,
         pull_number: i64,
-        per_page: i64,
-        page: i64,
-    ) -> Result<Vec<crate::types::CommitDataType>> {
+        params: &PullsListCommitsParams,
+    ) -> Result<Vec<crate::types::CommitData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -667,6 +955,26 @@ impl Pulls {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_commits_with` and pass the query parameters as a `PullsListCommitsParams` instead.
+     */
+    #[deprecated(note = "use `list_commits_with` instead")]
+    pub async fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::CommitData>> {
+        let params = PullsListCommitsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_commits_with(owner, repo, pull_number, &params)
+            .await
+    }
+
     /**
      * List commits on a pull request.
      *
@@ -683,7 +991,7 @@ impl Pulls {
         owner: &str,
         repo: &str,
         pull_number: i64,
-    ) -> Result<Vec<crate::types::CommitDataType>> {
+    ) -> Result<Vec<crate::types::CommitData>> {
         let url = format!(
             "/repos/{}/{}/pulls/{}/commits",
             crate::progenitor_support::encode_path(owner),
@@ -694,6 +1002,50 @@ impl Pulls {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List commits on a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists a maximum of 250 commits for a pull request. To receive a complete commit list for pull requests with more than 250 commits, use the [List commits](https://docs.github.com/rest/reference/repos#list-commits) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-commits-on-a-pull-request>
+     */
+    pub fn list_commits_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitData> {
+        let url = format!(
+            "/repos/{}/{}/pulls/{}/commits",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&pull_number.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List pull requests files.
      *
@@ -708,22 +1060,20 @@ impl Pulls {
      * * `owner: &str`
      * * `repo: &str`
      * * `pull_number: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListFilesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_files(
+    pub async fn list_files_with(
         &self,
         owner: &str,
         repo: &str,
         pull_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &PullsListFilesParams,
     ) -> Result<Vec<crate::types::DiffEntry>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -738,6 +1088,26 @@ impl Pulls {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_files_with` and pass the query parameters as a `PullsListFilesParams` instead.
+     */
+    #[deprecated(note = "use `list_files_with` instead")]
+    pub async fn list_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::DiffEntry>> {
+        let params = PullsListFilesParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_files_with(owner, repo, pull_number, &params)
+            .await
+    }
+
     /**
      * List pull requests files.
      *
@@ -766,9 +1136,53 @@ impl Pulls {
     }
 
     /**
-     * Check if a pull request has been merged.
+     * List pull requests files.
      *
-     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/merge` endpoint.
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/files` endpoint.
+     *
+     * As opposed to `list_all_files`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **Note:** Responses include a maximum of 3000 files. The paginated response returns 30 files per page by default.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-pull-requests-files>
+     */
+    pub fn list_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DiffEntry> {
+        let url = format!(
+            "/repos/{}/{}/pulls/{}/files",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&pull_number.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * Check if a pull request has been merged.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/merge` endpoint.
      *
      *
      *
@@ -839,22 +1253,20 @@ impl Pulls {
      * * `owner: &str`
      * * `repo: &str`
      * * `pull_number: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListRequestedReviewersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_requested_reviewers(
+    pub async fn list_requested_reviewers_with(
         &self,
         owner: &str,
         repo: &str,
         pull_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &PullsListRequestedReviewersParams,
     ) -> Result<crate::types::PullRequestReview> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -869,6 +1281,26 @@ impl Pulls {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_requested_reviewers_with` and pass the query parameters as a `PullsListRequestedReviewersParams` instead.
+     */
+    #[deprecated(note = "use `list_requested_reviewers_with` instead")]
+    pub async fn list_requested_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::PullRequestReview> {
+        let params = PullsListRequestedReviewersParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_requested_reviewers_with(owner, repo, pull_number, &params)
+            .await
+    }
+
     /**
      * Request reviewers for a pull request.
      *
@@ -951,22 +1383,20 @@ impl Pulls {
      * * `owner: &str`
      * * `repo: &str`
      * * `pull_number: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListReviewsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_reviews(
+    pub async fn list_reviews_with(
         &self,
         owner: &str,
         repo: &str,
         pull_number: i64,
-        per_page: i64,
-        page: i64,
+        params: &PullsListReviewsParams,
     ) -> Result<Vec<crate::types::PullRequestReviewData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -981,6 +1411,26 @@ impl Pulls {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_reviews_with` and pass the query parameters as a `PullsListReviewsParams` instead.
+     */
+    #[deprecated(note = "use `list_reviews_with` instead")]
+    pub async fn list_reviews(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::PullRequestReviewData>> {
+        let params = PullsListReviewsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_reviews_with(owner, repo, pull_number, &params)
+            .await
+    }
+
     /**
      * List reviews for a pull request.
      *
@@ -1008,6 +1458,50 @@ impl Pulls {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List reviews for a pull request.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews` endpoint.
+     *
+     * As opposed to `list_all_reviews`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * The list of reviews returns in chronological order.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-reviews-for-a-pull-request>
+     */
+    pub fn list_reviews_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestReviewData> {
+        let url = format!(
+            "/repos/{}/{}/pulls/{}/reviews",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&pull_number.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a review for a pull request.
      *
@@ -1168,23 +1662,21 @@ impl Pulls {
      * * `repo: &str`
      * * `pull_number: i64`
      * * `review_id: i64` -- review_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &PullsListCommentsForReviewParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_comments_for_review(
+    pub async fn list_comments_for_review_with(
         &self,
         owner: &str,
         repo: &str,
         pull_number: i64,
         review_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &PullsListCommentsForReviewParams,
     ) -> Result<Vec<crate::types::ReviewComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1200,6 +1692,27 @@ impl Pulls {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_comments_for_review_with` and pass the query parameters as a `PullsListCommentsForReviewParams` instead.
+     */
+    #[deprecated(note = "use `list_comments_for_review_with` instead")]
+    pub async fn list_comments_for_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::ReviewComment>> {
+        let params = PullsListCommentsForReviewParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_comments_for_review_with(owner, repo, pull_number, review_id, &params)
+            .await
+    }
+
     /**
      * List comments for a pull request review.
      *
@@ -1229,6 +1742,52 @@ impl Pulls {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List comments for a pull request review.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_review`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List comments for a specific pull request review.
+     *
+     * FROM: <https://docs.github.com/rest/reference/pulls#list-comments-for-a-pull-request-review>
+     */
+    pub fn list_comments_for_review_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReviewComment> {
+        let url = format!(
+            "/repos/{}/{}/pulls/{}/reviews/{}/comments",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&pull_number.to_string()),
+            crate::progenitor_support::encode_path(&review_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Dismiss a review for a pull request.
      *
@@ -1337,3 +1896,1029 @@ impl Pulls {
             .await
     }
 }
+
+/// The query parameters of `Pulls::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PullsListParams {
+    /// Filter pulls by base branch name. Example: `gh-pages`.
+    pub base: Option<String>,
+    /// The direction of the sort. Can be either `asc` or `desc`. Default: `desc` when sort is `created` or sort is not specified, otherwise `asc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Filter pulls by head user or head organization and branch name in the format of `user:ref-name` or `organization:ref-name`. For example: `github:new-script-format` or `octocat:test-branch`.
+    pub head: Option<String>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// What to sort results by. Can be either `created`, `updated`, `popularity` (comment count) or `long-running` (age, filtering by pulls updated in the last month).
+    pub sort: Option<crate::types::PullsListSort>,
+    /// Either `open`, `closed`, or `all` to filter by state.
+    pub state: Option<crate::types::PullsListState>,
+}
+
+impl PullsListParams {
+    pub fn base<T: ToString>(mut self, base: T) -> Self {
+        self.base = Some(base.to_string());
+        self
+    }
+
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn head<T: ToString>(mut self, head: T) -> Self {
+        self.head = Some(head.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::PullsListSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn state(mut self, state: crate::types::PullsListState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+/// The query parameters of `Pulls::list_review_comments_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PullsListReviewCommentsForRepoParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    pub sort: Option<crate::types::PullsListReviewCommentsRepoSort>,
+}
+
+impl PullsListReviewCommentsForRepoParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::PullsListReviewCommentsRepoSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The query parameters of `Pulls::list_review_comments_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PullsListReviewCommentsParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// Sorts the results of your query by when the label was `created` or `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
+    pub sort: Option<crate::types::Sort>,
+}
+
+impl PullsListReviewCommentsParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The query parameters of `Pulls::list_commits_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PullsListCommitsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl PullsListCommitsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Pulls::list_files_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PullsListFilesParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl PullsListFilesParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Pulls::list_requested_reviewers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PullsListRequestedReviewersParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl PullsListRequestedReviewersParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Pulls::list_reviews_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PullsListReviewsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl PullsListReviewsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Pulls::list_comments_for_review_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PullsListCommentsForReviewParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl PullsListCommentsForReviewParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The functions of [`Pulls`], for code that takes them as a trait, so that it can be
+/// tested with the `MockPullsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait PullsApi: Send + Sync {
+    /// See [`Pulls::list_with`].
+    async fn list_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListParams,
+    ) -> Result<Vec<crate::types::PullRequestSimple>>;
+
+    /// See [`Pulls::list_all_with`].
+    async fn list_all_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListParams,
+    ) -> Result<Vec<crate::types::PullRequestSimple>>;
+
+    /// See [`Pulls::list_stream`].
+    fn list_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestSimple>;
+
+    /// See [`Pulls::create`].
+    async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::PullsCreateRequest,
+    ) -> Result<crate::types::PullRequest>;
+
+    /// See [`Pulls::list_review_comments_for_repo_with`].
+    async fn list_review_comments_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListReviewCommentsForRepoParams,
+    ) -> Result<Vec<crate::types::PullRequestReviewComment>>;
+
+    /// See [`Pulls::list_all_review_comments_for_repo_with`].
+    async fn list_all_review_comments_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListReviewCommentsForRepoParams,
+    ) -> Result<Vec<crate::types::PullRequestReviewComment>>;
+
+    /// See [`Pulls::list_review_comments_for_repo_stream`].
+    fn list_review_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListReviewCommentsForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestReviewComment>;
+
+    /// See [`Pulls::get_review_comment`].
+    async fn get_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> Result<crate::types::PullRequestReviewComment>;
+
+    /// See [`Pulls::delete_review_comment`].
+    async fn delete_review_comment(&self, owner: &str, repo: &str, comment_id: i64) -> Result<()>;
+
+    /// See [`Pulls::update_review_comment`].
+    async fn update_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::PullsUpdateReviewCommentRequest,
+    ) -> Result<crate::types::PullRequestReviewComment>;
+
+    /// See [`Pulls::get`].
+    async fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> Result<crate::types::PullRequest>;
+
+    /// See [`Pulls::update`].
+    async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsUpdateRequest,
+    ) -> Result<crate::types::PullRequest>;
+
+    /// See [`Pulls::list_review_comments_with`].
+    async fn list_review_comments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListReviewCommentsParams,
+    ) -> Result<Vec<crate::types::PullRequestReviewComment>>;
+
+    /// See [`Pulls::list_all_review_comments_with`].
+    async fn list_all_review_comments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListReviewCommentsParams,
+    ) -> Result<Vec<crate::types::PullRequestReviewComment>>;
+
+    /// See [`Pulls::list_review_comments_stream`].
+    fn list_review_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListReviewCommentsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestReviewComment>;
+
+    /// See [`Pulls::create_review_comment`].
+    async fn create_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsCreateReviewCommentRequest,
+    ) -> Result<crate::types::PullRequestReviewComment>;
+
+    /// See [`Pulls::create_reply_for_review_comment`].
+    async fn create_reply_for_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        comment_id: i64,
+        body: &crate::types::PullsCreateReplyReviewCommentRequest,
+    ) -> Result<crate::types::PullRequestReviewComment>;
+
+    /// See [`Pulls::list_commits_with`].
+    async fn list_commits_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListCommitsParams,
+    ) -> Result<Vec<crate::types::CommitData>>;
+
+    /// See [`Pulls::list_all_commits`].
+    async fn list_all_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> Result<Vec<crate::types::CommitData>>;
+
+    /// See [`Pulls::list_commits_stream`].
+    fn list_commits_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitData>;
+
+    /// See [`Pulls::list_files_with`].
+    async fn list_files_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListFilesParams,
+    ) -> Result<Vec<crate::types::DiffEntry>>;
+
+    /// See [`Pulls::list_all_files`].
+    async fn list_all_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> Result<Vec<crate::types::DiffEntry>>;
+
+    /// See [`Pulls::list_files_stream`].
+    fn list_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DiffEntry>;
+
+    /// See [`Pulls::check_if_merged`].
+    async fn check_if_merged(&self, owner: &str, repo: &str, pull_number: i64) -> Result<()>;
+
+    /// See [`Pulls::merge`].
+    async fn merge(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsMergeRequest,
+    ) -> Result<crate::types::PullRequestMergeResult>;
+
+    /// See [`Pulls::list_requested_reviewers_with`].
+    async fn list_requested_reviewers_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListRequestedReviewersParams,
+    ) -> Result<crate::types::PullRequestReview>;
+
+    /// See [`Pulls::request_reviewers`].
+    async fn request_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsRequestReviewers,
+    ) -> Result<crate::types::PullRequestSimple>;
+
+    /// See [`Pulls::remove_requested_reviewers`].
+    async fn remove_requested_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsRemoveRequestedReviewersRequest,
+    ) -> Result<crate::types::PullRequestSimple>;
+
+    /// See [`Pulls::list_reviews_with`].
+    async fn list_reviews_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListReviewsParams,
+    ) -> Result<Vec<crate::types::PullRequestReviewData>>;
+
+    /// See [`Pulls::list_all_reviews`].
+    async fn list_all_reviews(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> Result<Vec<crate::types::PullRequestReviewData>>;
+
+    /// See [`Pulls::list_reviews_stream`].
+    fn list_reviews_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestReviewData>;
+
+    /// See [`Pulls::create_review`].
+    async fn create_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsCreateReviewRequest,
+    ) -> Result<crate::types::PullRequestReviewData>;
+
+    /// See [`Pulls::get_review`].
+    async fn get_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> Result<crate::types::PullRequestReviewData>;
+
+    /// See [`Pulls::update_review`].
+    async fn update_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> Result<crate::types::PullRequestReviewData>;
+
+    /// See [`Pulls::delete_pending_review`].
+    async fn delete_pending_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> Result<crate::types::PullRequestReviewData>;
+
+    /// See [`Pulls::list_comments_for_review_with`].
+    async fn list_comments_for_review_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        params: &PullsListCommentsForReviewParams,
+    ) -> Result<Vec<crate::types::ReviewComment>>;
+
+    /// See [`Pulls::list_all_comments_for_review`].
+    async fn list_all_comments_for_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> Result<Vec<crate::types::ReviewComment>>;
+
+    /// See [`Pulls::list_comments_for_review_stream`].
+    fn list_comments_for_review_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReviewComment>;
+
+    /// See [`Pulls::dismiss_review`].
+    async fn dismiss_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        body: &crate::types::PullsDismissReviewRequest,
+    ) -> Result<crate::types::PullRequestReviewData>;
+
+    /// See [`Pulls::submit_review`].
+    async fn submit_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        body: &crate::types::PullsSubmitReviewRequest,
+    ) -> Result<crate::types::PullRequestReviewData>;
+
+    /// See [`Pulls::update_branch`].
+    async fn update_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsUpdateBranchRequest,
+    ) -> Result<crate::types::PullsUpdateBranchResponse>;
+}
+
+#[async_trait::async_trait]
+impl PullsApi for Pulls {
+    async fn list_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListParams,
+    ) -> Result<Vec<crate::types::PullRequestSimple>> {
+        Pulls::list_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListParams,
+    ) -> Result<Vec<crate::types::PullRequestSimple>> {
+        Pulls::list_all_with(self, owner, repo, params).await
+    }
+
+    fn list_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestSimple> {
+        Pulls::list_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn create(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::PullsCreateRequest,
+    ) -> Result<crate::types::PullRequest> {
+        Pulls::create(self, owner, repo, body).await
+    }
+
+    async fn list_review_comments_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListReviewCommentsForRepoParams,
+    ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
+        Pulls::list_review_comments_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_review_comments_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListReviewCommentsForRepoParams,
+    ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
+        Pulls::list_all_review_comments_for_repo_with(self, owner, repo, params).await
+    }
+
+    fn list_review_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &PullsListReviewCommentsForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestReviewComment> {
+        Pulls::list_review_comments_for_repo_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn get_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> Result<crate::types::PullRequestReviewComment> {
+        Pulls::get_review_comment(self, owner, repo, comment_id).await
+    }
+
+    async fn delete_review_comment(&self, owner: &str, repo: &str, comment_id: i64) -> Result<()> {
+        Pulls::delete_review_comment(self, owner, repo, comment_id).await
+    }
+
+    async fn update_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::PullsUpdateReviewCommentRequest,
+    ) -> Result<crate::types::PullRequestReviewComment> {
+        Pulls::update_review_comment(self, owner, repo, comment_id, body).await
+    }
+
+    async fn get(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> Result<crate::types::PullRequest> {
+        Pulls::get(self, owner, repo, pull_number).await
+    }
+
+    async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsUpdateRequest,
+    ) -> Result<crate::types::PullRequest> {
+        Pulls::update(self, owner, repo, pull_number, body).await
+    }
+
+    async fn list_review_comments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListReviewCommentsParams,
+    ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
+        Pulls::list_review_comments_with(self, owner, repo, pull_number, params).await
+    }
+
+    async fn list_all_review_comments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListReviewCommentsParams,
+    ) -> Result<Vec<crate::types::PullRequestReviewComment>> {
+        Pulls::list_all_review_comments_with(self, owner, repo, pull_number, params).await
+    }
+
+    fn list_review_comments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListReviewCommentsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestReviewComment> {
+        Pulls::list_review_comments_stream(self, owner, repo, pull_number, params, start, max_items)
+    }
+
+    async fn create_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsCreateReviewCommentRequest,
+    ) -> Result<crate::types::PullRequestReviewComment> {
+        Pulls::create_review_comment(self, owner, repo, pull_number, body).await
+    }
+
+    async fn create_reply_for_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        comment_id: i64,
+        body: &crate::types::PullsCreateReplyReviewCommentRequest,
+    ) -> Result<crate::types::PullRequestReviewComment> {
+        Pulls::create_reply_for_review_comment(self, owner, repo, pull_number, comment_id, body)
+            .await
+    }
+
+    async fn list_commits_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListCommitsParams,
+    ) -> Result<Vec<crate::types::CommitData>> {
+        Pulls::list_commits_with(self, owner, repo, pull_number, params).await
+    }
+
+    async fn list_all_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> Result<Vec<crate::types::CommitData>> {
+        Pulls::list_all_commits(self, owner, repo, pull_number).await
+    }
+
+    fn list_commits_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitData> {
+        Pulls::list_commits_stream(self, owner, repo, pull_number, start, max_items)
+    }
+
+    async fn list_files_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListFilesParams,
+    ) -> Result<Vec<crate::types::DiffEntry>> {
+        Pulls::list_files_with(self, owner, repo, pull_number, params).await
+    }
+
+    async fn list_all_files(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> Result<Vec<crate::types::DiffEntry>> {
+        Pulls::list_all_files(self, owner, repo, pull_number).await
+    }
+
+    fn list_files_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DiffEntry> {
+        Pulls::list_files_stream(self, owner, repo, pull_number, start, max_items)
+    }
+
+    async fn check_if_merged(&self, owner: &str, repo: &str, pull_number: i64) -> Result<()> {
+        Pulls::check_if_merged(self, owner, repo, pull_number).await
+    }
+
+    async fn merge(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsMergeRequest,
+    ) -> Result<crate::types::PullRequestMergeResult> {
+        Pulls::merge(self, owner, repo, pull_number, body).await
+    }
+
+    async fn list_requested_reviewers_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListRequestedReviewersParams,
+    ) -> Result<crate::types::PullRequestReview> {
+        Pulls::list_requested_reviewers_with(self, owner, repo, pull_number, params).await
+    }
+
+    async fn request_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsRequestReviewers,
+    ) -> Result<crate::types::PullRequestSimple> {
+        Pulls::request_reviewers(self, owner, repo, pull_number, body).await
+    }
+
+    async fn remove_requested_reviewers(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsRemoveRequestedReviewersRequest,
+    ) -> Result<crate::types::PullRequestSimple> {
+        Pulls::remove_requested_reviewers(self, owner, repo, pull_number, body).await
+    }
+
+    async fn list_reviews_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        params: &PullsListReviewsParams,
+    ) -> Result<Vec<crate::types::PullRequestReviewData>> {
+        Pulls::list_reviews_with(self, owner, repo, pull_number, params).await
+    }
+
+    async fn list_all_reviews(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+    ) -> Result<Vec<crate::types::PullRequestReviewData>> {
+        Pulls::list_all_reviews(self, owner, repo, pull_number).await
+    }
+
+    fn list_reviews_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestReviewData> {
+        Pulls::list_reviews_stream(self, owner, repo, pull_number, start, max_items)
+    }
+
+    async fn create_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsCreateReviewRequest,
+    ) -> Result<crate::types::PullRequestReviewData> {
+        Pulls::create_review(self, owner, repo, pull_number, body).await
+    }
+
+    async fn get_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> Result<crate::types::PullRequestReviewData> {
+        Pulls::get_review(self, owner, repo, pull_number, review_id).await
+    }
+
+    async fn update_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        body: &crate::types::PullsUpdateReviewRequest,
+    ) -> Result<crate::types::PullRequestReviewData> {
+        Pulls::update_review(self, owner, repo, pull_number, review_id, body).await
+    }
+
+    async fn delete_pending_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> Result<crate::types::PullRequestReviewData> {
+        Pulls::delete_pending_review(self, owner, repo, pull_number, review_id).await
+    }
+
+    async fn list_comments_for_review_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        params: &PullsListCommentsForReviewParams,
+    ) -> Result<Vec<crate::types::ReviewComment>> {
+        Pulls::list_comments_for_review_with(self, owner, repo, pull_number, review_id, params)
+            .await
+    }
+
+    async fn list_all_comments_for_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+    ) -> Result<Vec<crate::types::ReviewComment>> {
+        Pulls::list_all_comments_for_review(self, owner, repo, pull_number, review_id).await
+    }
+
+    fn list_comments_for_review_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReviewComment> {
+        Pulls::list_comments_for_review_stream(
+            self,
+            owner,
+            repo,
+            pull_number,
+            review_id,
+            start,
+            max_items,
+        )
+    }
+
+    async fn dismiss_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        body: &crate::types::PullsDismissReviewRequest,
+    ) -> Result<crate::types::PullRequestReviewData> {
+        Pulls::dismiss_review(self, owner, repo, pull_number, review_id, body).await
+    }
+
+    async fn submit_review(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        review_id: i64,
+        body: &crate::types::PullsSubmitReviewRequest,
+    ) -> Result<crate::types::PullRequestReviewData> {
+        Pulls::submit_review(self, owner, repo, pull_number, review_id, body).await
+    }
+
+    async fn update_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        pull_number: i64,
+        body: &crate::types::PullsUpdateBranchRequest,
+    ) -> Result<crate::types::PullsUpdateBranchResponse> {
+        Pulls::update_branch(self, owner, repo, pull_number, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct RateLimit {
     pub client: Client,
@@ -28,3 +26,19 @@ impl RateLimit {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`RateLimit`], for code that takes them as a trait, so that it can be
+/// tested with the `MockRateLimitApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait RateLimitApi: Send + Sync {
+    /// See [`RateLimit::get`].
+    async fn get(&self) -> Result<crate::types::RateLimitOverview>;
+}
+
+#[async_trait::async_trait]
+impl RateLimitApi for RateLimit {
+    async fn get(&self) -> Result<crate::types::RateLimitOverview> {
+        RateLimit::get(self).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Reactions {
     pub client: Client,
@@ -29,28 +27,24 @@ impl Reactions {
      * * `team_slug: &str` -- team_slug parameter.
      * * `discussion_number: i64`
      * * `comment_number: i64`
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion comment.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForTeamDiscussionCommentInOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_team_discussion_comment_in_org(
+    pub async fn list_for_team_discussion_comment_in_org_with(
         &self,
         org: &str,
         team_slug: &str,
         discussion_number: i64,
         comment_number: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForTeamDiscussionCommentInOrgParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -66,6 +60,35 @@ impl Reactions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_team_discussion_comment_in_org_with` and pass the query parameters as a `ReactionsListForTeamDiscussionCommentInOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_for_team_discussion_comment_in_org_with` instead")]
+    pub async fn list_for_team_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForTeamDiscussionCommentInOrgParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_for_team_discussion_comment_in_org_with(
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+            &params,
+        )
+        .await
+    }
+
     /**
      * List reactions for a team discussion comment.
      *
@@ -78,7 +101,40 @@ impl Reactions {
      * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/:org_id/team/:team_id/discussions/:discussion_number/comments/:comment_number/reactions`.
      *
      * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReactionsListForTeamDiscussionCommentInOrgParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_team_discussion_comment_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentInOrgParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(content) = &params.content {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions?{}",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(team_slug),
+            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            crate::progenitor_support::encode_path(&comment_number.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_team_discussion_comment_in_org_with` and pass the query parameters as a `ReactionsListForTeamDiscussionCommentInOrgParams` instead.
      */
+    #[deprecated(note = "use `list_all_for_team_discussion_comment_in_org_with` instead")]
     pub async fn list_all_for_team_discussion_comment_in_org(
         &self,
         org: &str,
@@ -87,8 +143,45 @@ impl Reactions {
         comment_number: i64,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForTeamDiscussionCommentInOrgParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_team_discussion_comment_in_org_with(
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+            &params,
+        )
+        .await
+    }
+
+    /**
+     * List reactions for a team discussion comment.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_comment_in_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List the reactions to a [team discussion comment](https://docs.github.com/rest/reference/teams#discussion-comments/). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/:org_id/team/:team_id/discussions/:discussion_number/comments/:comment_number/reactions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment>
+     */
+    pub fn list_for_team_discussion_comment_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -101,7 +194,22 @@ impl Reactions {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -128,7 +236,7 @@ impl Reactions {
         team_slug: &str,
         discussion_number: i64,
         comment_number: i64,
-        body: &crate::types::ReactionsCreateIssueRequest,
+        body: &crate::types::ReactionsCreateTeamDiscussionCommentInOrgRequest,
     ) -> Result<crate::types::Reaction> {
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}/comments/{}/reactions",
@@ -198,27 +306,23 @@ impl Reactions {
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
      * * `discussion_number: i64`
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForTeamDiscussionInOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_team_discussion_in_org(
+    pub async fn list_for_team_discussion_in_org_with(
         &self,
         org: &str,
         team_slug: &str,
         discussion_number: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForTeamDiscussionInOrgParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -233,6 +337,28 @@ impl Reactions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_team_discussion_in_org_with` and pass the query parameters as a `ReactionsListForTeamDiscussionInOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_for_team_discussion_in_org_with` instead")]
+    pub async fn list_for_team_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForTeamDiscussionInOrgParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_for_team_discussion_in_org_with(org, team_slug, discussion_number, &params)
+            .await
+    }
+
     /**
      * List reactions for a team discussion.
      *
@@ -245,7 +371,38 @@ impl Reactions {
      * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/:org_id/team/:team_id/discussions/:discussion_number/reactions`.
      *
      * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReactionsListForTeamDiscussionInOrgParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_team_discussion_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionInOrgParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(content) = &params.content {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/teams/{}/discussions/{}/reactions?{}",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(team_slug),
+            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_team_discussion_in_org_with` and pass the query parameters as a `ReactionsListForTeamDiscussionInOrgParams` instead.
      */
+    #[deprecated(note = "use `list_all_for_team_discussion_in_org_with` instead")]
     pub async fn list_all_for_team_discussion_in_org(
         &self,
         org: &str,
@@ -253,8 +410,38 @@ impl Reactions {
         discussion_number: i64,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForTeamDiscussionInOrgParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_team_discussion_in_org_with(org, team_slug, discussion_number, &params)
+            .await
+    }
+
+    /**
+     * List reactions for a team discussion.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_in_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List the reactions to a [team discussion](https://docs.github.com/rest/reference/teams#discussions). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/:org_id/team/:team_id/discussions/:discussion_number/reactions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion>
+     */
+    pub fn list_for_team_discussion_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -266,7 +453,22 @@ impl Reactions {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -291,7 +493,7 @@ impl Reactions {
         org: &str,
         team_slug: &str,
         discussion_number: i64,
-        body: &crate::types::ReactionsCreateIssueRequest,
+        body: &crate::types::ReactionsCreateTeamDiscussionInOrgRequest,
     ) -> Result<crate::types::Reaction> {
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}/reactions",
@@ -379,27 +581,23 @@ impl Reactions {
      * * `owner: &str`
      * * `repo: &str`
      * * `comment_id: i64` -- comment_id parameter.
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a commit comment.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForCommitCommentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_commit_comment(
+    pub async fn list_for_commit_comment_with(
         &self,
         owner: &str,
         repo: &str,
         comment_id: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForCommitCommentParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -414,6 +612,28 @@ impl Reactions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_commit_comment_with` and pass the query parameters as a `ReactionsListForCommitCommentParams` instead.
+     */
+    #[deprecated(note = "use `list_for_commit_comment_with` instead")]
+    pub async fn list_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForCommitCommentParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_for_commit_comment_with(owner, repo, comment_id, &params)
+            .await
+    }
+
     /**
      * List reactions for a commit comment.
      *
@@ -424,7 +644,38 @@ impl Reactions {
      * List the reactions to a [commit comment](https://docs.github.com/rest/reference/repos#comments).
      *
      * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-commit-comment>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReactionsListForCommitCommentParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_commit_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForCommitCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(content) = &params.content {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/comments/{}/reactions?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_commit_comment_with` and pass the query parameters as a `ReactionsListForCommitCommentParams` instead.
      */
+    #[deprecated(note = "use `list_all_for_commit_comment_with` instead")]
     pub async fn list_all_for_commit_comment(
         &self,
         owner: &str,
@@ -432,8 +683,36 @@ impl Reactions {
         comment_id: i64,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForCommitCommentParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_commit_comment_with(owner, repo, comment_id, &params)
+            .await
+    }
+
+    /**
+     * List reactions for a commit comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_commit_comment`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List the reactions to a [commit comment](https://docs.github.com/rest/reference/repos#comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-commit-comment>
+     */
+    pub fn list_for_commit_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForCommitCommentParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -445,7 +724,22 @@ impl Reactions {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -468,7 +762,7 @@ impl Reactions {
         owner: &str,
         repo: &str,
         comment_id: i64,
-        body: &crate::types::ReactionsCreateIssueRequest,
+        body: &crate::types::ReactionsCreateCommitCommentRequest,
     ) -> Result<crate::types::Reaction> {
         let url = format!(
             "/repos/{}/{}/comments/{}/reactions",
@@ -532,27 +826,23 @@ impl Reactions {
      * * `owner: &str`
      * * `repo: &str`
      * * `comment_id: i64` -- comment_id parameter.
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to an issue comment.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForIssueCommentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_issue_comment(
+    pub async fn list_for_issue_comment_with(
         &self,
         owner: &str,
         repo: &str,
         comment_id: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForIssueCommentParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -567,6 +857,28 @@ impl Reactions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_issue_comment_with` and pass the query parameters as a `ReactionsListForIssueCommentParams` instead.
+     */
+    #[deprecated(note = "use `list_for_issue_comment_with` instead")]
+    pub async fn list_for_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForIssueCommentParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_for_issue_comment_with(owner, repo, comment_id, &params)
+            .await
+    }
+
     /**
      * List reactions for an issue comment.
      *
@@ -577,7 +889,38 @@ impl Reactions {
      * List the reactions to an [issue comment](https://docs.github.com/rest/reference/issues#comments).
      *
      * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue-comment>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReactionsListForIssueCommentParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_issue_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForIssueCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(content) = &params.content {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/issues/comments/{}/reactions?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_issue_comment_with` and pass the query parameters as a `ReactionsListForIssueCommentParams` instead.
      */
+    #[deprecated(note = "use `list_all_for_issue_comment_with` instead")]
     pub async fn list_all_for_issue_comment(
         &self,
         owner: &str,
@@ -585,8 +928,36 @@ impl Reactions {
         comment_id: i64,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForIssueCommentParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_issue_comment_with(owner, repo, comment_id, &params)
+            .await
+    }
+
+    /**
+     * List reactions for an issue comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_issue_comment`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List the reactions to an [issue comment](https://docs.github.com/rest/reference/issues#comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue-comment>
+     */
+    pub fn list_for_issue_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForIssueCommentParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -598,7 +969,22 @@ impl Reactions {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -621,7 +1007,7 @@ impl Reactions {
         owner: &str,
         repo: &str,
         comment_id: i64,
-        body: &crate::types::ReactionsCreateIssueRequest,
+        body: &crate::types::ReactionsCreateIssueCommentRequest,
     ) -> Result<crate::types::Reaction> {
         let url = format!(
             "/repos/{}/{}/issues/comments/{}/reactions",
@@ -685,27 +1071,23 @@ impl Reactions {
      * * `owner: &str`
      * * `repo: &str`
      * * `issue_number: i64` -- issue_number parameter.
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to an issue.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForIssueParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_issue(
+    pub async fn list_for_issue_with(
         &self,
         owner: &str,
         repo: &str,
         issue_number: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForIssueParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -720,6 +1102,28 @@ impl Reactions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_issue_with` and pass the query parameters as a `ReactionsListForIssueParams` instead.
+     */
+    #[deprecated(note = "use `list_for_issue_with` instead")]
+    pub async fn list_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForIssueParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_for_issue_with(owner, repo, issue_number, &params)
+            .await
+    }
+
     /**
      * List reactions for an issue.
      *
@@ -730,7 +1134,38 @@ impl Reactions {
      * List the reactions to an [issue](https://docs.github.com/rest/reference/issues).
      *
      * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReactionsListForIssueParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_issue_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &ReactionsListForIssueParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(content) = &params.content {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/issues/{}/reactions?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&issue_number.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_issue_with` and pass the query parameters as a `ReactionsListForIssueParams` instead.
      */
+    #[deprecated(note = "use `list_all_for_issue_with` instead")]
     pub async fn list_all_for_issue(
         &self,
         owner: &str,
@@ -738,8 +1173,36 @@ impl Reactions {
         issue_number: i64,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForIssueParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_issue_with(owner, repo, issue_number, &params)
+            .await
+    }
+
+    /**
+     * List reactions for an issue.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/issues/{issue_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_issue`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List the reactions to an [issue](https://docs.github.com/rest/reference/issues).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-an-issue>
+     */
+    pub fn list_for_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &ReactionsListForIssueParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -751,7 +1214,22 @@ impl Reactions {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -838,27 +1316,23 @@ impl Reactions {
      * * `owner: &str`
      * * `repo: &str`
      * * `comment_id: i64` -- comment_id parameter.
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a pull request review comment.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForPullRequestReviewCommentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_pull_request_review_comment(
+    pub async fn list_for_pull_request_review_comment_with(
         &self,
         owner: &str,
         repo: &str,
         comment_id: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForPullRequestReviewCommentParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -873,6 +1347,28 @@ impl Reactions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_pull_request_review_comment_with` and pass the query parameters as a `ReactionsListForPullRequestReviewCommentParams` instead.
+     */
+    #[deprecated(note = "use `list_for_pull_request_review_comment_with` instead")]
+    pub async fn list_for_pull_request_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForPullRequestReviewCommentParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_for_pull_request_review_comment_with(owner, repo, comment_id, &params)
+            .await
+    }
+
     /**
      * List reactions for a pull request review comment.
      *
@@ -883,7 +1379,38 @@ impl Reactions {
      * List the reactions to a [pull request review comment](https://docs.github.com/rest/reference/pulls#review-comments).
      *
      * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-pull-request-review-comment>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReactionsListForPullRequestReviewCommentParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_pull_request_review_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForPullRequestReviewCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(content) = &params.content {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/pulls/comments/{}/reactions?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&comment_id.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_pull_request_review_comment_with` and pass the query parameters as a `ReactionsListForPullRequestReviewCommentParams` instead.
      */
+    #[deprecated(note = "use `list_all_for_pull_request_review_comment_with` instead")]
     pub async fn list_all_for_pull_request_review_comment(
         &self,
         owner: &str,
@@ -891,8 +1418,36 @@ impl Reactions {
         comment_id: i64,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForPullRequestReviewCommentParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_pull_request_review_comment_with(owner, repo, comment_id, &params)
+            .await
+    }
+
+    /**
+     * List reactions for a pull request review comment.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pulls/comments/{comment_id}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_pull_request_review_comment`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List the reactions to a [pull request review comment](https://docs.github.com/rest/reference/pulls#review-comments).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions#list-reactions-for-a-pull-request-review-comment>
+     */
+    pub fn list_for_pull_request_review_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForPullRequestReviewCommentParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -904,7 +1459,22 @@ impl Reactions {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -927,7 +1497,7 @@ impl Reactions {
         owner: &str,
         repo: &str,
         comment_id: i64,
-        body: &crate::types::ReactionsCreateIssueRequest,
+        body: &crate::types::ReactionsCreatePullRequestReviewComment,
     ) -> Result<crate::types::Reaction> {
         let url = format!(
             "/repos/{}/{}/pulls/comments/{}/reactions",
@@ -1027,27 +1597,23 @@ impl Reactions {
      * * `team_id: i64`
      * * `discussion_number: i64`
      * * `comment_number: i64`
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion comment.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForTeamDiscussionCommentLegacyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_team_discussion_comment_legacy(
+    pub async fn list_for_team_discussion_comment_legacy_with(
         &self,
         team_id: i64,
         discussion_number: i64,
         comment_number: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForTeamDiscussionCommentLegacyParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1062,6 +1628,33 @@ impl Reactions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_team_discussion_comment_legacy_with` and pass the query parameters as a `ReactionsListForTeamDiscussionCommentLegacyParams` instead.
+     */
+    #[deprecated(note = "use `list_for_team_discussion_comment_legacy_with` instead")]
+    pub async fn list_for_team_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForTeamDiscussionCommentLegacyParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_for_team_discussion_comment_legacy_with(
+            team_id,
+            discussion_number,
+            comment_number,
+            &params,
+        )
+        .await
+    }
+
     /**
      * List reactions for a team discussion comment (Legacy).
      *
@@ -1074,7 +1667,38 @@ impl Reactions {
      * List the reactions to a [team discussion comment](https://docs.github.com/rest/reference/teams#discussion-comments). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
      *
      * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-comment-legacy>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReactionsListForTeamDiscussionCommentLegacyParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_team_discussion_comment_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentLegacyParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(content) = &params.content {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/teams/{}/discussions/{}/comments/{}/reactions?{}",
+            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            crate::progenitor_support::encode_path(&comment_number.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_team_discussion_comment_legacy_with` and pass the query parameters as a `ReactionsListForTeamDiscussionCommentLegacyParams` instead.
      */
+    #[deprecated(note = "use `list_all_for_team_discussion_comment_legacy_with` instead")]
     pub async fn list_all_for_team_discussion_comment_legacy(
         &self,
         team_id: i64,
@@ -1082,8 +1706,43 @@ impl Reactions {
         comment_number: i64,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForTeamDiscussionCommentLegacyParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_team_discussion_comment_legacy_with(
+            team_id,
+            discussion_number,
+            comment_number,
+            &params,
+        )
+        .await
+    }
+
+    /**
+     * List reactions for a team discussion comment (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_comment_legacy`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List reactions for a team discussion comment`](https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion-comment) endpoint.
+     *
+     * List the reactions to a [team discussion comment](https://docs.github.com/rest/reference/teams#discussion-comments). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-comment-legacy>
+     */
+    pub fn list_for_team_discussion_comment_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1095,7 +1754,22 @@ impl Reactions {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -1120,7 +1794,7 @@ impl Reactions {
         team_id: i64,
         discussion_number: i64,
         comment_number: i64,
-        body: &crate::types::ReactionsCreateIssueRequest,
+        body: &crate::types::ReactionsCreateTeamDiscussionCommentLegacyRequest,
     ) -> Result<crate::types::Reaction> {
         let url = format!(
             "/teams/{}/discussions/{}/comments/{}/reactions",
@@ -1149,26 +1823,22 @@ impl Reactions {
      *
      * * `team_id: i64`
      * * `discussion_number: i64`
-     * * `content: crate::types::Content` -- Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReactionsListForTeamDiscussionLegacyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_team_discussion_legacy(
+    pub async fn list_for_team_discussion_legacy_with(
         &self,
         team_id: i64,
         discussion_number: i64,
-        content: crate::types::Content,
-        per_page: i64,
-        page: i64,
+        params: &ReactionsListForTeamDiscussionLegacyParams,
     ) -> Result<Vec<crate::types::Reaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1182,6 +1852,27 @@ impl Reactions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_team_discussion_legacy_with` and pass the query parameters as a `ReactionsListForTeamDiscussionLegacyParams` instead.
+     */
+    #[deprecated(note = "use `list_for_team_discussion_legacy_with` instead")]
+    pub async fn list_for_team_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        content: crate::types::Content,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForTeamDiscussionLegacyParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_for_team_discussion_legacy_with(team_id, discussion_number, &params)
+            .await
+    }
+
     /**
      * List reactions for a team discussion (Legacy).
      *
@@ -1194,15 +1885,73 @@ impl Reactions {
      * List the reactions to a [team discussion](https://docs.github.com/rest/reference/teams#discussions). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
      *
      * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-legacy>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReactionsListForTeamDiscussionLegacyParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_team_discussion_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionLegacyParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(content) = &params.content {
+            query_args.push(("content".to_string(), content.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/teams/{}/discussions/{}/reactions?{}",
+            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_team_discussion_legacy_with` and pass the query parameters as a `ReactionsListForTeamDiscussionLegacyParams` instead.
      */
+    #[deprecated(note = "use `list_all_for_team_discussion_legacy_with` instead")]
     pub async fn list_all_for_team_discussion_legacy(
         &self,
         team_id: i64,
         discussion_number: i64,
         content: crate::types::Content,
     ) -> Result<Vec<crate::types::Reaction>> {
+        let params = ReactionsListForTeamDiscussionLegacyParams {
+            content: Some(content).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_team_discussion_legacy_with(team_id, discussion_number, &params)
+            .await
+    }
+
+    /**
+     * List reactions for a team discussion (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/reactions` endpoint.
+     *
+     * As opposed to `list_all_for_team_discussion_legacy`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List reactions for a team discussion`](https://docs.github.com/rest/reference/reactions#list-reactions-for-a-team-discussion) endpoint.
+     *
+     * List the reactions to a [team discussion](https://docs.github.com/rest/reference/teams#discussions). OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/reactions/#list-reactions-for-a-team-discussion-legacy>
+     */
+    pub fn list_for_team_discussion_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !content.to_string().is_empty() {
+        if let Some(content) = &params.content {
             query_args.push(("content".to_string(), content.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1213,7 +1962,22 @@ impl Reactions {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -1236,7 +2000,7 @@ impl Reactions {
         &self,
         team_id: i64,
         discussion_number: i64,
-        body: &crate::types::ReactionsCreateIssueRequest,
+        body: &crate::types::ReactionsCreateTeamDiscussionLegacyRequest,
     ) -> Result<crate::types::Reaction> {
         let url = format!(
             "/teams/{}/discussions/{}/reactions",
@@ -1249,3 +2013,1136 @@ impl Reactions {
             .await
     }
 }
+
+/// The query parameters of `Reactions::list_for_team_discussion_comment_in_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReactionsListForTeamDiscussionCommentInOrgParams {
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion comment.
+    pub content: Option<crate::types::Content>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReactionsListForTeamDiscussionCommentInOrgParams {
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Reactions::list_for_team_discussion_in_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReactionsListForTeamDiscussionInOrgParams {
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion.
+    pub content: Option<crate::types::Content>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReactionsListForTeamDiscussionInOrgParams {
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Reactions::list_for_commit_comment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReactionsListForCommitCommentParams {
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a commit comment.
+    pub content: Option<crate::types::Content>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReactionsListForCommitCommentParams {
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Reactions::list_for_issue_comment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReactionsListForIssueCommentParams {
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to an issue comment.
+    pub content: Option<crate::types::Content>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReactionsListForIssueCommentParams {
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Reactions::list_for_issue_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReactionsListForIssueParams {
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to an issue.
+    pub content: Option<crate::types::Content>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReactionsListForIssueParams {
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Reactions::list_for_pull_request_review_comment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReactionsListForPullRequestReviewCommentParams {
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a pull request review comment.
+    pub content: Option<crate::types::Content>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReactionsListForPullRequestReviewCommentParams {
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Reactions::list_for_team_discussion_comment_legacy_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReactionsListForTeamDiscussionCommentLegacyParams {
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion comment.
+    pub content: Option<crate::types::Content>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReactionsListForTeamDiscussionCommentLegacyParams {
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Reactions::list_for_team_discussion_legacy_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReactionsListForTeamDiscussionLegacyParams {
+    /// Returns a single [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types). Omit this parameter to list all reactions to a team discussion.
+    pub content: Option<crate::types::Content>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReactionsListForTeamDiscussionLegacyParams {
+    pub fn content(mut self, content: crate::types::Content) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The functions of [`Reactions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockReactionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReactionsApi: Send + Sync {
+    /// See [`Reactions::list_for_team_discussion_comment_in_org_with`].
+    async fn list_for_team_discussion_comment_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentInOrgParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_all_for_team_discussion_comment_in_org_with`].
+    async fn list_all_for_team_discussion_comment_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentInOrgParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_for_team_discussion_comment_in_org_stream`].
+    fn list_for_team_discussion_comment_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction>;
+
+    /// See [`Reactions::create_for_team_discussion_comment_in_org`].
+    async fn create_for_team_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::ReactionsCreateTeamDiscussionCommentInOrgRequest,
+    ) -> Result<crate::types::Reaction>;
+
+    /// See [`Reactions::delete_for_team_discussion_comment`].
+    async fn delete_for_team_discussion_comment(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        reaction_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Reactions::list_for_team_discussion_in_org_with`].
+    async fn list_for_team_discussion_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionInOrgParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_all_for_team_discussion_in_org_with`].
+    async fn list_all_for_team_discussion_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionInOrgParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_for_team_discussion_in_org_stream`].
+    fn list_for_team_discussion_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction>;
+
+    /// See [`Reactions::create_for_team_discussion_in_org`].
+    async fn create_for_team_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        body: &crate::types::ReactionsCreateTeamDiscussionInOrgRequest,
+    ) -> Result<crate::types::Reaction>;
+
+    /// See [`Reactions::delete_for_team_discussion`].
+    async fn delete_for_team_discussion(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        reaction_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Reactions::delete_legacy`].
+    async fn delete_legacy(&self, reaction_id: i64) -> Result<()>;
+
+    /// See [`Reactions::list_for_commit_comment_with`].
+    async fn list_for_commit_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForCommitCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_all_for_commit_comment_with`].
+    async fn list_all_for_commit_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForCommitCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_for_commit_comment_stream`].
+    fn list_for_commit_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForCommitCommentParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction>;
+
+    /// See [`Reactions::create_for_commit_comment`].
+    async fn create_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReactionsCreateCommitCommentRequest,
+    ) -> Result<crate::types::Reaction>;
+
+    /// See [`Reactions::delete_for_commit_comment`].
+    async fn delete_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Reactions::list_for_issue_comment_with`].
+    async fn list_for_issue_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForIssueCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_all_for_issue_comment_with`].
+    async fn list_all_for_issue_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForIssueCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_for_issue_comment_stream`].
+    fn list_for_issue_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForIssueCommentParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction>;
+
+    /// See [`Reactions::create_for_issue_comment`].
+    async fn create_for_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReactionsCreateIssueCommentRequest,
+    ) -> Result<crate::types::Reaction>;
+
+    /// See [`Reactions::delete_for_issue_comment`].
+    async fn delete_for_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Reactions::list_for_issue_with`].
+    async fn list_for_issue_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &ReactionsListForIssueParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_all_for_issue_with`].
+    async fn list_all_for_issue_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &ReactionsListForIssueParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_for_issue_stream`].
+    fn list_for_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &ReactionsListForIssueParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction>;
+
+    /// See [`Reactions::create_for_issue`].
+    async fn create_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> Result<crate::types::Reaction>;
+
+    /// See [`Reactions::delete_for_issue`].
+    async fn delete_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        reaction_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Reactions::list_for_pull_request_review_comment_with`].
+    async fn list_for_pull_request_review_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForPullRequestReviewCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_all_for_pull_request_review_comment_with`].
+    async fn list_all_for_pull_request_review_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForPullRequestReviewCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_for_pull_request_review_comment_stream`].
+    fn list_for_pull_request_review_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForPullRequestReviewCommentParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction>;
+
+    /// See [`Reactions::create_for_pull_request_review_comment`].
+    async fn create_for_pull_request_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReactionsCreatePullRequestReviewComment,
+    ) -> Result<crate::types::Reaction>;
+
+    /// See [`Reactions::delete_for_pull_request_comment`].
+    async fn delete_for_pull_request_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Reactions::create_for_release`].
+    async fn create_for_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        body: &crate::types::ReactionsCreateReleaseRequest,
+    ) -> Result<crate::types::Reaction>;
+
+    /// See [`Reactions::list_for_team_discussion_comment_legacy_with`].
+    async fn list_for_team_discussion_comment_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentLegacyParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_all_for_team_discussion_comment_legacy_with`].
+    async fn list_all_for_team_discussion_comment_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentLegacyParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_for_team_discussion_comment_legacy_stream`].
+    fn list_for_team_discussion_comment_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction>;
+
+    /// See [`Reactions::create_for_team_discussion_comment_legacy`].
+    async fn create_for_team_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::ReactionsCreateTeamDiscussionCommentLegacyRequest,
+    ) -> Result<crate::types::Reaction>;
+
+    /// See [`Reactions::list_for_team_discussion_legacy_with`].
+    async fn list_for_team_discussion_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionLegacyParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_all_for_team_discussion_legacy_with`].
+    async fn list_all_for_team_discussion_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionLegacyParams,
+    ) -> Result<Vec<crate::types::Reaction>>;
+
+    /// See [`Reactions::list_for_team_discussion_legacy_stream`].
+    fn list_for_team_discussion_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction>;
+
+    /// See [`Reactions::create_for_team_discussion_legacy`].
+    async fn create_for_team_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        body: &crate::types::ReactionsCreateTeamDiscussionLegacyRequest,
+    ) -> Result<crate::types::Reaction>;
+}
+
+#[async_trait::async_trait]
+impl ReactionsApi for Reactions {
+    async fn list_for_team_discussion_comment_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentInOrgParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_for_team_discussion_comment_in_org_with(
+            self,
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+            params,
+        )
+        .await
+    }
+
+    async fn list_all_for_team_discussion_comment_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentInOrgParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_all_for_team_discussion_comment_in_org_with(
+            self,
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+            params,
+        )
+        .await
+    }
+
+    fn list_for_team_discussion_comment_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
+        Reactions::list_for_team_discussion_comment_in_org_stream(
+            self,
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+            params,
+            start,
+            max_items,
+        )
+    }
+
+    async fn create_for_team_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::ReactionsCreateTeamDiscussionCommentInOrgRequest,
+    ) -> Result<crate::types::Reaction> {
+        Reactions::create_for_team_discussion_comment_in_org(
+            self,
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+            body,
+        )
+        .await
+    }
+
+    async fn delete_for_team_discussion_comment(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        reaction_id: i64,
+    ) -> Result<()> {
+        Reactions::delete_for_team_discussion_comment(
+            self,
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+            reaction_id,
+        )
+        .await
+    }
+
+    async fn list_for_team_discussion_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionInOrgParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_for_team_discussion_in_org_with(
+            self,
+            org,
+            team_slug,
+            discussion_number,
+            params,
+        )
+        .await
+    }
+
+    async fn list_all_for_team_discussion_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionInOrgParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_all_for_team_discussion_in_org_with(
+            self,
+            org,
+            team_slug,
+            discussion_number,
+            params,
+        )
+        .await
+    }
+
+    fn list_for_team_discussion_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
+        Reactions::list_for_team_discussion_in_org_stream(
+            self,
+            org,
+            team_slug,
+            discussion_number,
+            params,
+            start,
+            max_items,
+        )
+    }
+
+    async fn create_for_team_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        body: &crate::types::ReactionsCreateTeamDiscussionInOrgRequest,
+    ) -> Result<crate::types::Reaction> {
+        Reactions::create_for_team_discussion_in_org(self, org, team_slug, discussion_number, body)
+            .await
+    }
+
+    async fn delete_for_team_discussion(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        reaction_id: i64,
+    ) -> Result<()> {
+        Reactions::delete_for_team_discussion(self, org, team_slug, discussion_number, reaction_id)
+            .await
+    }
+
+    async fn delete_legacy(&self, reaction_id: i64) -> Result<()> {
+        Reactions::delete_legacy(self, reaction_id).await
+    }
+
+    async fn list_for_commit_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForCommitCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_for_commit_comment_with(self, owner, repo, comment_id, params).await
+    }
+
+    async fn list_all_for_commit_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForCommitCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_all_for_commit_comment_with(self, owner, repo, comment_id, params).await
+    }
+
+    fn list_for_commit_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForCommitCommentParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
+        Reactions::list_for_commit_comment_stream(
+            self, owner, repo, comment_id, params, start, max_items,
+        )
+    }
+
+    async fn create_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReactionsCreateCommitCommentRequest,
+    ) -> Result<crate::types::Reaction> {
+        Reactions::create_for_commit_comment(self, owner, repo, comment_id, body).await
+    }
+
+    async fn delete_for_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> Result<()> {
+        Reactions::delete_for_commit_comment(self, owner, repo, comment_id, reaction_id).await
+    }
+
+    async fn list_for_issue_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForIssueCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_for_issue_comment_with(self, owner, repo, comment_id, params).await
+    }
+
+    async fn list_all_for_issue_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForIssueCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_all_for_issue_comment_with(self, owner, repo, comment_id, params).await
+    }
+
+    fn list_for_issue_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForIssueCommentParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
+        Reactions::list_for_issue_comment_stream(
+            self, owner, repo, comment_id, params, start, max_items,
+        )
+    }
+
+    async fn create_for_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReactionsCreateIssueCommentRequest,
+    ) -> Result<crate::types::Reaction> {
+        Reactions::create_for_issue_comment(self, owner, repo, comment_id, body).await
+    }
+
+    async fn delete_for_issue_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> Result<()> {
+        Reactions::delete_for_issue_comment(self, owner, repo, comment_id, reaction_id).await
+    }
+
+    async fn list_for_issue_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &ReactionsListForIssueParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_for_issue_with(self, owner, repo, issue_number, params).await
+    }
+
+    async fn list_all_for_issue_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &ReactionsListForIssueParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_all_for_issue_with(self, owner, repo, issue_number, params).await
+    }
+
+    fn list_for_issue_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        params: &ReactionsListForIssueParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
+        Reactions::list_for_issue_stream(self, owner, repo, issue_number, params, start, max_items)
+    }
+
+    async fn create_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        body: &crate::types::ReactionsCreateIssueRequest,
+    ) -> Result<crate::types::Reaction> {
+        Reactions::create_for_issue(self, owner, repo, issue_number, body).await
+    }
+
+    async fn delete_for_issue(
+        &self,
+        owner: &str,
+        repo: &str,
+        issue_number: i64,
+        reaction_id: i64,
+    ) -> Result<()> {
+        Reactions::delete_for_issue(self, owner, repo, issue_number, reaction_id).await
+    }
+
+    async fn list_for_pull_request_review_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForPullRequestReviewCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_for_pull_request_review_comment_with(self, owner, repo, comment_id, params)
+            .await
+    }
+
+    async fn list_all_for_pull_request_review_comment_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForPullRequestReviewCommentParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_all_for_pull_request_review_comment_with(
+            self, owner, repo, comment_id, params,
+        )
+        .await
+    }
+
+    fn list_for_pull_request_review_comment_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        params: &ReactionsListForPullRequestReviewCommentParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
+        Reactions::list_for_pull_request_review_comment_stream(
+            self, owner, repo, comment_id, params, start, max_items,
+        )
+    }
+
+    async fn create_for_pull_request_review_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReactionsCreatePullRequestReviewComment,
+    ) -> Result<crate::types::Reaction> {
+        Reactions::create_for_pull_request_review_comment(self, owner, repo, comment_id, body).await
+    }
+
+    async fn delete_for_pull_request_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        reaction_id: i64,
+    ) -> Result<()> {
+        Reactions::delete_for_pull_request_comment(self, owner, repo, comment_id, reaction_id).await
+    }
+
+    async fn create_for_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        body: &crate::types::ReactionsCreateReleaseRequest,
+    ) -> Result<crate::types::Reaction> {
+        Reactions::create_for_release(self, owner, repo, release_id, body).await
+    }
+
+    async fn list_for_team_discussion_comment_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentLegacyParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_for_team_discussion_comment_legacy_with(
+            self,
+            team_id,
+            discussion_number,
+            comment_number,
+            params,
+        )
+        .await
+    }
+
+    async fn list_all_for_team_discussion_comment_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentLegacyParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_all_for_team_discussion_comment_legacy_with(
+            self,
+            team_id,
+            discussion_number,
+            comment_number,
+            params,
+        )
+        .await
+    }
+
+    fn list_for_team_discussion_comment_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        params: &ReactionsListForTeamDiscussionCommentLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
+        Reactions::list_for_team_discussion_comment_legacy_stream(
+            self,
+            team_id,
+            discussion_number,
+            comment_number,
+            params,
+            start,
+            max_items,
+        )
+    }
+
+    async fn create_for_team_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::ReactionsCreateTeamDiscussionCommentLegacyRequest,
+    ) -> Result<crate::types::Reaction> {
+        Reactions::create_for_team_discussion_comment_legacy(
+            self,
+            team_id,
+            discussion_number,
+            comment_number,
+            body,
+        )
+        .await
+    }
+
+    async fn list_for_team_discussion_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionLegacyParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_for_team_discussion_legacy_with(self, team_id, discussion_number, params)
+            .await
+    }
+
+    async fn list_all_for_team_discussion_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionLegacyParams,
+    ) -> Result<Vec<crate::types::Reaction>> {
+        Reactions::list_all_for_team_discussion_legacy_with(
+            self,
+            team_id,
+            discussion_number,
+            params,
+        )
+        .await
+    }
+
+    fn list_for_team_discussion_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &ReactionsListForTeamDiscussionLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reaction> {
+        Reactions::list_for_team_discussion_legacy_stream(
+            self,
+            team_id,
+            discussion_number,
+            params,
+            start,
+            max_items,
+        )
+    }
+
+    async fn create_for_team_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        body: &crate::types::ReactionsCreateTeamDiscussionLegacyRequest,
+    ) -> Result<crate::types::Reaction> {
+        Reactions::create_for_team_discussion_legacy(self, team_id, discussion_number, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Repos {
     pub client: Client,
@@ -24,37 +22,27 @@ impl Repos {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `type_: crate::types::ReposListOrgType` -- Specifies the types of repositories you want returned. Can be one of `all`, `public`, `private`, `forks`, `sources`, `member`, `internal`. Note: For GitHub AE, can be one of `all`, `private`, `forks`, `sources`, `member`, `internal`. Default: `all`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `type` can also be `internal`. However, the `internal` value is not yet supported when a GitHub App calls this API with an installation access token.
-     * * `sort: crate::types::ReposListOrgSort` -- Can be one of `created`, `updated`, `pushed`, `full_name`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListForOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_org(
+    pub async fn list_for_org_with(
         &self,
         org: &str,
-        type_: crate::types::ReposListOrgType,
-        sort: crate::types::ReposListOrgSort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &ReposListForOrgParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -67,6 +55,29 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_org_with` and pass the query parameters as a `ReposListForOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_for_org_with` instead")]
+    pub async fn list_for_org(
+        &self,
+        org: &str,
+        type_: crate::types::ReposListOrgType,
+        sort: crate::types::ReposListOrgSort,
+        direction: crate::types::Direction,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = ReposListForOrgParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_for_org_with(org, &params).await
+    }
+
     /**
      * List organization repositories.
      *
@@ -77,22 +88,82 @@ impl Repos {
      * Lists repositories for the specified organization.
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-organization-repositories>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReposListForOrgParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_org_with(
+        &self,
+        org: &str,
+        params: &ReposListForOrgParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/repos?{}",
+            crate::progenitor_support::encode_path(org),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_org_with` and pass the query parameters as a `ReposListForOrgParams` instead.
      */
+    #[deprecated(note = "use `list_all_for_org_with` instead")]
     pub async fn list_all_for_org(
         &self,
         org: &str,
         type_: crate::types::ReposListOrgType,
         sort: crate::types::ReposListOrgSort,
-        direction: crate::types::Order,
+        direction: crate::types::Direction,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = ReposListForOrgParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_org_with(org, &params).await
+    }
+
+    /**
+     * List organization repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/repos` endpoint.
+     *
+     * As opposed to `list_all_for_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists repositories for the specified organization.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-organization-repositories>
+     */
+    pub fn list_for_org_stream(
+        &self,
+        org: &str,
+        params: &ReposListForOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -102,7 +173,22 @@ impl Repos {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -239,16 +325,16 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListAutolinksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_autolinks(
+    pub async fn list_autolinks_with(
         &self,
         owner: &str,
         repo: &str,
-        page: i64,
+        params: &ReposListAutolinksParams,
     ) -> Result<Vec<crate::types::Autolink>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -262,6 +348,22 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_autolinks_with` and pass the query parameters as a `ReposListAutolinksParams` instead.
+     */
+    #[deprecated(note = "use `list_autolinks_with` instead")]
+    pub async fn list_autolinks(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: i64,
+    ) -> Result<Vec<crate::types::Autolink>> {
+        let params = ReposListAutolinksParams {
+            page: Some(page).filter(|v| *v > 0),
+        };
+        self.list_autolinks_with(owner, repo, &params).await
+    }
+
     /**
      * List all autolinks of a repository.
      *
@@ -289,6 +391,50 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List all autolinks of a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/autolinks` endpoint.
+     *
+     * As opposed to `list_all_autolinks`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * This returns a list of autolinks configured for the given repository.
+     *
+     * Information about autolinks are only available to repository administrators.
+     *
+     * FROM: <https://docs.github.com/v3/repos#list-autolinks>
+     */
+    pub fn list_autolinks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Autolink> {
+        let url = format!(
+            "/repos/{}/{}/autolinks",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create an autolink reference for a repository.
      *
@@ -442,26 +588,22 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `protected: bool` -- Setting to `true` returns only protected branches. When set to `false`, only unprotected branches are returned. Omitting this parameter returns all branches.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListBranchesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_branches(
+    pub async fn list_branches_with(
         &self,
         owner: &str,
         repo: &str,
-        protected: bool,
-        per_page: i64,
-        page: i64,
+        params: &ReposListBranchesParams,
     ) -> Result<Vec<crate::types::ShortBranch>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if protected {
+        if let Some(protected) = &params.protected {
             query_args.push(("protected".to_string(), protected.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -475,6 +617,26 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_branches_with` and pass the query parameters as a `ReposListBranchesParams` instead.
+     */
+    #[deprecated(note = "use `list_branches_with` instead")]
+    pub async fn list_branches(
+        &self,
+        owner: &str,
+        repo: &str,
+        protected: bool,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::ShortBranch>> {
+        let params = ReposListBranchesParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            protected: Some(protected).filter(|v| *v),
+        };
+        self.list_branches_with(owner, repo, &params).await
+    }
+
     /**
      * List branches.
      *
@@ -485,15 +647,70 @@ impl Repos {
      *
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-branches>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReposListBranchesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_branches_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListBranchesParams,
+    ) -> Result<Vec<crate::types::ShortBranch>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(protected) = &params.protected {
+            query_args.push(("protected".to_string(), protected.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/branches?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_branches_with` and pass the query parameters as a `ReposListBranchesParams` instead.
      */
+    #[deprecated(note = "use `list_all_branches_with` instead")]
     pub async fn list_all_branches(
         &self,
         owner: &str,
         repo: &str,
         protected: bool,
     ) -> Result<Vec<crate::types::ShortBranch>> {
+        let params = ReposListBranchesParams {
+            protected: Some(protected).filter(|v| *v),
+            ..Default::default()
+        };
+        self.list_all_branches_with(owner, repo, &params).await
+    }
+
+    /**
+     * List branches.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches` endpoint.
+     *
+     * As opposed to `list_all_branches`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches>
+     */
+    pub fn list_branches_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListBranchesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ShortBranch> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if protected {
+        if let Some(protected) = &params.protected {
             query_args.push(("protected".to_string(), protected.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -504,7 +721,22 @@ impl Repos {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -660,7 +892,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         branch: &str,
-    ) -> Result<crate::types::EnforceAdmins> {
+    ) -> Result<crate::types::ProtectedBranchAdminEnforced> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/enforce_admins",
             crate::progenitor_support::encode_path(owner),
@@ -693,7 +925,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         branch: &str,
-    ) -> Result<crate::types::EnforceAdmins> {
+    ) -> Result<crate::types::ProtectedBranchAdminEnforced> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/enforce_admins",
             crate::progenitor_support::encode_path(owner),
@@ -861,7 +1093,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         branch: &str,
-    ) -> Result<crate::types::EnforceAdmins> {
+    ) -> Result<crate::types::ProtectedBranchAdminEnforced> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_signatures",
             crate::progenitor_support::encode_path(owner),
@@ -894,7 +1126,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         branch: &str,
-    ) -> Result<crate::types::EnforceAdmins> {
+    ) -> Result<crate::types::ProtectedBranchAdminEnforced> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_signatures",
             crate::progenitor_support::encode_path(owner),
@@ -1094,6 +1326,50 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get all status check contexts.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/required_status_checks/contexts` endpoint.
+     *
+     * As opposed to `get_all_all_status_check_contexts`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-status-check-contexts>
+     */
+    pub fn get_all_status_check_contexts_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<String> {
+        let url = format!(
+            "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(branch),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Set status check contexts.
      *
@@ -1114,7 +1390,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         branch: &str,
-        body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
+        body: &crate::types::ReposSetStatusCheckContextsRequestOneOf,
     ) -> Result<Vec<String>> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
@@ -1182,7 +1458,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         branch: &str,
-        body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
+        body: &crate::types::ReposRemoveStatusCheckContextsRequestOneOf,
     ) -> Result<Vec<String>> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/required_status_checks/contexts",
@@ -1286,7 +1562,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         branch: &str,
-    ) -> Result<Vec<crate::types::GitHubApp>> {
+    ) -> Result<Vec<crate::types::Integration>> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/apps",
             crate::progenitor_support::encode_path(owner),
@@ -1315,7 +1591,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         branch: &str,
-    ) -> Result<Vec<crate::types::GitHubApp>> {
+    ) -> Result<Vec<crate::types::Integration>> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/apps",
             crate::progenitor_support::encode_path(owner),
@@ -1326,6 +1602,52 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get apps with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/apps` endpoint.
+     *
+     * As opposed to `get_all_apps_with_access_to_protected_branch`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Lists the GitHub Apps that have push access to this branch. Only installed GitHub Apps with `write` access to the `contents` permission can be added as authorized actors on a protected branch.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-apps-with-access-to-the-protected-branch>
+     */
+    pub fn get_apps_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Integration> {
+        let url = format!(
+            "/repos/{}/{}/branches/{}/protection/restrictions/apps",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(branch),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Set app access restrictions.
      *
@@ -1352,8 +1674,8 @@ impl Repos {
         owner: &str,
         repo: &str,
         branch: &str,
-        body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
-    ) -> Result<Vec<crate::types::GitHubApp>> {
+        body: &crate::types::ReposSetAppAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Integration>> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/apps",
             crate::progenitor_support::encode_path(owner),
@@ -1393,7 +1715,7 @@ impl Repos {
         repo: &str,
         branch: &str,
         body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
-    ) -> Result<Vec<crate::types::GitHubApp>> {
+    ) -> Result<Vec<crate::types::Integration>> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/apps",
             crate::progenitor_support::encode_path(owner),
@@ -1432,8 +1754,8 @@ impl Repos {
         owner: &str,
         repo: &str,
         branch: &str,
-        body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
-    ) -> Result<Vec<crate::types::GitHubApp>> {
+        body: &crate::types::ReposRemoveAppAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Integration>> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/apps",
             crate::progenitor_support::encode_path(owner),
@@ -1508,6 +1830,52 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get teams with access to the protected branch.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/teams` endpoint.
+     *
+     * As opposed to `get_all_teams_with_access_to_protected_branch`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Lists the teams who have push access to this branch. The list includes child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-teams-with-access-to-the-protected-branch>
+     */
+    pub fn get_teams_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team> {
+        let url = format!(
+            "/repos/{}/{}/branches/{}/protection/restrictions/teams",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(branch),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Set team access restrictions.
      *
@@ -1534,7 +1902,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         branch: &str,
-        body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
+        body: &crate::types::ReposSetTeamAccessRestrictionsRequestOneOf,
     ) -> Result<Vec<crate::types::Team>> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/teams",
@@ -1614,7 +1982,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         branch: &str,
-        body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
+        body: &crate::types::ReposRemoveTeamAccessRestrictionsRequestOneOf,
     ) -> Result<Vec<crate::types::Team>> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/teams",
@@ -1691,33 +2059,26 @@ impl Repos {
     }
 
     /**
-     * Set user access restrictions.
-     *
-     * This function performs a `PUT` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users` endpoint.
-     *
-     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     * Get users with access to the protected branch.
      *
-     * Replaces the list of people that have push access to this branch. This removes all people that previously had push access and grants push access to the new list of people.
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users` endpoint.
      *
-     * | Type    | Description                                                                                                                   |
-     * | ------- | ----------------------------------------------------------------------------------------------------------------------------- |
-     * | `array` | Usernames for people who can have push access. **Note**: The list of users, apps, and teams in total is limited to 100 items. |
+     * As opposed to `get_all_users_with_access_to_protected_branch`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
      *
-     * FROM: <https://docs.github.com/rest/reference/repos#set-user-access-restrictions>
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
      *
-     * **Parameters:**
+     * Lists the people who have push access to this branch.
      *
-     * * `owner: &str`
-     * * `repo: &str`
-     * * `branch: &str` -- The name of the branch.
+     * FROM: <https://docs.github.com/rest/reference/repos#list-users-with-access-to-the-protected-branch>
      */
-    pub async fn set_user_access_restrictions(
+    pub fn get_users_with_access_to_protected_branch_stream(
         &self,
         owner: &str,
         repo: &str,
         branch: &str,
-        body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
-    ) -> Result<Vec<crate::types::SimpleUser>> {
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/users",
             crate::progenitor_support::encode_path(owner),
@@ -1725,7 +2086,60 @@ impl Repos {
             crate::progenitor_support::encode_path(branch),
         );
 
-        self.client
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * Set user access restrictions.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/branches/{branch}/protection/restrictions/users` endpoint.
+     *
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
+     *
+     * Replaces the list of people that have push access to this branch. This removes all people that previously had push access and grants push access to the new list of people.
+     *
+     * | Type    | Description                                                                                                                   |
+     * | ------- | ----------------------------------------------------------------------------------------------------------------------------- |
+     * | `array` | Usernames for people who can have push access. **Note**: The list of users, apps, and teams in total is limited to 100 items. |
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#set-user-access-restrictions>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `branch: &str` -- The name of the branch.
+     */
+    pub async fn set_user_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposSetUserAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let url = format!(
+            "/repos/{}/{}/branches/{}/protection/restrictions/users",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(branch),
+        );
+
+        self.client
             .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
@@ -1796,7 +2210,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         branch: &str,
-        body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
+        body: &crate::types::ReposRemoveUserAccessRestrictionsRequestOneOf,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let url = format!(
             "/repos/{}/{}/branches/{}/protection/restrictions/users",
@@ -1873,29 +2287,22 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `affiliation: crate::types::Affiliation` -- Filters the collaborators by their affiliation. Can be one of:  
-     *  \\* `outside`: Outside collaborators of a project that are not a member of the project's organization.  
-     *  \\* `direct`: Collaborators with permissions to a project, regardless of organization membership status.  
-     *  \\* `all`: All collaborators the authenticated user can see.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListCollaboratorsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_collaborators(
+    pub async fn list_collaborators_with(
         &self,
         owner: &str,
         repo: &str,
-        affiliation: crate::types::Affiliation,
-        per_page: i64,
-        page: i64,
+        params: &ReposListCollaboratorsParams,
     ) -> Result<Vec<crate::types::Collaborator>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !affiliation.to_string().is_empty() {
+        if let Some(affiliation) = &params.affiliation {
             query_args.push(("affiliation".to_string(), affiliation.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1909,6 +2316,26 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_collaborators_with` and pass the query parameters as a `ReposListCollaboratorsParams` instead.
+     */
+    #[deprecated(note = "use `list_collaborators_with` instead")]
+    pub async fn list_collaborators(
+        &self,
+        owner: &str,
+        repo: &str,
+        affiliation: crate::types::Affiliation,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Collaborator>> {
+        let params = ReposListCollaboratorsParams {
+            affiliation: Some(affiliation).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_collaborators_with(owner, repo, &params).await
+    }
+
     /**
      * List repository collaborators.
      *
@@ -1921,15 +2348,72 @@ impl Repos {
      * Team members will include the members of child teams.
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-repository-collaborators>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReposListCollaboratorsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_collaborators_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCollaboratorsParams,
+    ) -> Result<Vec<crate::types::Collaborator>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(affiliation) = &params.affiliation {
+            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/collaborators?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_collaborators_with` and pass the query parameters as a `ReposListCollaboratorsParams` instead.
      */
+    #[deprecated(note = "use `list_all_collaborators_with` instead")]
     pub async fn list_all_collaborators(
         &self,
         owner: &str,
         repo: &str,
         affiliation: crate::types::Affiliation,
     ) -> Result<Vec<crate::types::Collaborator>> {
+        let params = ReposListCollaboratorsParams {
+            affiliation: Some(affiliation).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_collaborators_with(owner, repo, &params).await
+    }
+
+    /**
+     * List repository collaborators.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/collaborators` endpoint.
+     *
+     * As opposed to `list_all_collaborators`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * For organization-owned repositories, the list of collaborators includes outside collaborators, organization members that are direct collaborators, organization members with access through team memberships, organization members with access through default organization permissions, and organization owners.
+     *
+     * Team members will include the members of child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-collaborators>
+     */
+    pub fn list_collaborators_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCollaboratorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Collaborator> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !affiliation.to_string().is_empty() {
+        if let Some(affiliation) = &params.affiliation {
             query_args.push(("affiliation".to_string(), affiliation.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1940,7 +2424,22 @@ impl Repos {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -2087,21 +2586,19 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListCommitCommentsForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_commit_comments_for_repo(
+    pub async fn list_commit_comments_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListCommitCommentsForRepoParams,
     ) -> Result<Vec<crate::types::CommitComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2115,6 +2612,25 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_commit_comments_for_repo_with` and pass the query parameters as a `ReposListCommitCommentsForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_commit_comments_for_repo_with` instead")]
+    pub async fn list_commit_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::CommitComment>> {
+        let params = ReposListCommitCommentsForRepoParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_commit_comments_for_repo_with(owner, repo, &params)
+            .await
+    }
+
     /**
      * List commit comments for a repository.
      *
@@ -2142,6 +2658,50 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List commit comments for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/comments` endpoint.
+     *
+     * As opposed to `list_all_commit_comments_for_repo`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Commit Comments use [these custom media types](https://docs.github.com/rest/reference/repos#custom-media-types). You can read more about the use of media types in the API [here](https://docs.github.com/rest/overview/media-types/).
+     *
+     * Comments are ordered by ascending ID.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments-for-a-repository>
+     */
+    pub fn list_commit_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitComment> {
+        let url = format!(
+            "/repos/{}/{}/comments",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get a commit comment.
      *
@@ -2224,7 +2784,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         comment_id: i64,
-        body: &crate::types::PullsUpdateReviewRequest,
+        body: &crate::types::ReposUpdateCommitCommentRequest,
     ) -> Result<crate::types::CommitComment> {
         let url = format!(
             "/repos/{}/{}/comments/{}",
@@ -2278,47 +2838,35 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `sha: &str` -- SHA or branch to start listing commits from. Default: the repository’s default branch (usually `master`).
-     * * `path: &str` -- Only commits containing this file path will be returned.
-     * * `author: &str` -- GitHub login or email address by which to filter by commit author.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `until: chrono::DateTime<chrono::Utc>` -- Only commits before this date will be returned. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListCommitsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_commits(
+    pub async fn list_commits_with(
         &self,
         owner: &str,
         repo: &str,
-        sha: &str,
-        path: &str,
-        author: &str,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        until: Option<chrono::DateTime<chrono::Utc>>,
-        per_page: i64,
-        page: i64,
-    ) -> Result<Vec<crate::types::CommitDataType>> {
+        params: &ReposListCommitsParams,
+    ) -> Result<Vec<crate::types::CommitData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
+        if let Some(author) = &params.author {
             query_args.push(("author".to_string(), author.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !sha.is_empty() {
+        if let Some(sha) = &params.sha {
             query_args.push(("sha".to_string(), sha.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if let Some(date) = until {
-            query_args.push(("until".to_string(), date.to_rfc3339()));
+        if let Some(until) = &params.until {
+            query_args.push(("until".to_string(), until.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
@@ -2331,6 +2879,34 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_commits_with` and pass the query parameters as a `ReposListCommitsParams` instead.
+     */
+    #[deprecated(note = "use `list_commits_with` instead")]
+    pub async fn list_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        path: &str,
+        author: &str,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::CommitData>> {
+        let params = ReposListCommitsParams {
+            author: Some(author.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            sha: Some(sha.to_string()).filter(|s| !s.is_empty()),
+            since,
+            until,
+        };
+        self.list_commits_with(owner, repo, &params).await
+    }
+
     /**
      * List commits.
      *
@@ -2368,7 +2944,48 @@ impl Repos {
      * | `valid` | None of the above errors applied, so the signature is considered to be verified. |
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-commits>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReposListCommitsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_commits_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCommitsParams,
+    ) -> Result<Vec<crate::types::CommitData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(author) = &params.author {
+            query_args.push(("author".to_string(), author.to_string()));
+        }
+        if let Some(path) = &params.path {
+            query_args.push(("path".to_string(), path.to_string()));
+        }
+        if let Some(sha) = &params.sha {
+            query_args.push(("sha".to_string(), sha.to_string()));
+        }
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        if let Some(until) = &params.until {
+            query_args.push(("until".to_string(), until.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/commits?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_commits_with` and pass the query parameters as a `ReposListCommitsParams` instead.
      */
+    #[deprecated(note = "use `list_all_commits_with` instead")]
     pub async fn list_all_commits(
         &self,
         owner: &str,
@@ -2378,22 +2995,79 @@ impl Repos {
         author: &str,
         since: Option<chrono::DateTime<chrono::Utc>>,
         until: Option<chrono::DateTime<chrono::Utc>>,
-    ) -> Result<Vec<crate::types::CommitDataType>> {
+    ) -> Result<Vec<crate::types::CommitData>> {
+        let params = ReposListCommitsParams {
+            author: Some(author.to_string()).filter(|s| !s.is_empty()),
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            sha: Some(sha.to_string()).filter(|s| !s.is_empty()),
+            since,
+            until,
+            ..Default::default()
+        };
+        self.list_all_commits_with(owner, repo, &params).await
+    }
+
+    /**
+     * List commits.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits` endpoint.
+     *
+     * As opposed to `list_all_commits`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **Signature verification object**
+     *
+     * The response will include a `verification` object that describes the result of verifying the commit's signature. The following fields are included in the `verification` object:
+     *
+     * | Name | Type | Description |
+     * | ---- | ---- | ----------- |
+     * | `verified` | `boolean` | Indicates whether GitHub considers the signature in this commit to be verified. |
+     * | `reason` | `string` | The reason for verified value. Possible values and their meanings are enumerated in table below. |
+     * | `signature` | `string` | The signature that was extracted from the commit. |
+     * | `payload` | `string` | The value that was signed. |
+     *
+     * These are the possible values for `reason` in the `verification` object:
+     *
+     * | Value | Description |
+     * | ----- | ----------- |
+     * | `expired_key` | The key that made the signature is expired. |
+     * | `not_signing_key` | The "signing" flag is not among the usage flags in the GPG key that made the signature. |
+     * | `gpgverify_error` | There was an error communicating with the signature verification service. |
+     * | `gpgverify_unavailable` | The signature verification service is currently unavailable. |
+     * | `unsigned` | The object does not include a signature. |
+     * | `unknown_signature_type` | A non-PGP signature was found in the commit. |
+     * | `no_user` | No user was associated with the `committer` email address in the commit. |
+     * | `unverified_email` | The `committer` email address in the commit was associated with a user, but the email address is not verified on her/his account. |
+     * | `bad_email` | The `committer` email address in the commit is not included in the identities of the PGP key that made the signature. |
+     * | `unknown_key` | The key that made the signature has not been registered with any user's account. |
+     * | `malformed_signature` | There was an error parsing the signature. |
+     * | `invalid` | The signature could not be cryptographically verified using the key whose key-id was found in the signature. |
+     * | `valid` | None of the above errors applied, so the signature is considered to be verified. |
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commits>
+     */
+    pub fn list_commits_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCommitsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
+        if let Some(author) = &params.author {
             query_args.push(("author".to_string(), author.to_string()));
         }
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !sha.is_empty() {
+        if let Some(sha) = &params.sha {
             query_args.push(("sha".to_string(), sha.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if let Some(date) = until {
-            query_args.push(("until".to_string(), date.to_rfc3339()));
+        if let Some(until) = &params.until {
+            query_args.push(("until".to_string(), until.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
@@ -2403,7 +3077,22 @@ impl Repos {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -2469,40 +3158,84 @@ impl Repos {
     }
 
     /**
-     * List commit comments.
+     * List branches for HEAD commit.
      *
-     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/comments` endpoint.
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/branches-where-head` endpoint.
      *
-     * Use the `:commit_sha` to specify the commit that will have its comments listed.
+     * As opposed to `list_all_branches_for_head_commit`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
      *
-     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments>
+     * Protected branches are available in public repositories with GitHub Free and GitHub Free for organizations, and in public and private repositories with GitHub Pro, GitHub Team, GitHub Enterprise Cloud, and GitHub Enterprise Server. For more information, see [GitHub's products](https://help.github.com/github/getting-started-with-github/githubs-products) in the GitHub Help documentation.
      *
-     * **Parameters:**
+     * Returns all branches where the given commit SHA is the HEAD, or latest commit for the branch.
      *
-     * * `owner: &str`
-     * * `repo: &str`
-     * * `commit_sha: &str` -- commit_sha parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * FROM: <https://docs.github.com/rest/reference/repos#list-branches-for-head-commit>
      */
-    pub async fn list_comments_for_commit(
+    pub fn list_branches_for_head_commit_stream(
         &self,
         owner: &str,
         repo: &str,
         commit_sha: &str,
-        per_page: i64,
-        page: i64,
-    ) -> Result<Vec<crate::types::CommitComment>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
-        }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
-        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BranchShort> {
         let url = format!(
-            "/repos/{}/{}/commits/{}/comments?{}",
+            "/repos/{}/{}/commits/{}/branches-where-head",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(commit_sha),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * List commit comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/comments` endpoint.
+     *
+     * Use the `:commit_sha` to specify the commit that will have its comments listed.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `commit_sha: &str` -- commit_sha parameter.
+     * * `params: &ReposListCommentsForCommitParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_comments_for_commit_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        params: &ReposListCommentsForCommitParams,
+    ) -> Result<Vec<crate::types::CommitComment>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(page) = &params.page {
+            query_args.push(("page".to_string(), page.to_string()));
+        }
+        if let Some(per_page) = &params.per_page {
+            query_args.push(("per_page".to_string(), per_page.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/commits/{}/comments?{}",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
             crate::progenitor_support::encode_path(commit_sha),
@@ -2512,6 +3245,26 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_comments_for_commit_with` and pass the query parameters as a `ReposListCommentsForCommitParams` instead.
+     */
+    #[deprecated(note = "use `list_comments_for_commit_with` instead")]
+    pub async fn list_comments_for_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::CommitComment>> {
+        let params = ReposListCommentsForCommitParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_comments_for_commit_with(owner, repo, commit_sha, &params)
+            .await
+    }
+
     /**
      * List commit comments.
      *
@@ -2539,6 +3292,50 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List commit comments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/comments` endpoint.
+     *
+     * As opposed to `list_all_comments_for_commit`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use the `:commit_sha` to specify the commit that will have its comments listed.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-comments>
+     */
+    pub fn list_comments_for_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitComment> {
+        let url = format!(
+            "/repos/{}/{}/commits/{}/comments",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(commit_sha),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a commit comment.
      *
@@ -2589,22 +3386,20 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `commit_sha: &str` -- commit_sha parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListPullRequestsAssociatedWithCommitParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_pull_requests_associated_with_commit(
+    pub async fn list_pull_requests_associated_with_commit_with(
         &self,
         owner: &str,
         repo: &str,
         commit_sha: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListPullRequestsAssociatedWithCommitParams,
     ) -> Result<Vec<crate::types::PullRequestSimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2619,6 +3414,26 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_pull_requests_associated_with_commit_with` and pass the query parameters as a `ReposListPullRequestsAssociatedWithCommitParams` instead.
+     */
+    #[deprecated(note = "use `list_pull_requests_associated_with_commit_with` instead")]
+    pub async fn list_pull_requests_associated_with_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::PullRequestSimple>> {
+        let params = ReposListPullRequestsAssociatedWithCommitParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_pull_requests_associated_with_commit_with(owner, repo, commit_sha, &params)
+            .await
+    }
+
     /**
      * List pull requests associated with a commit.
      *
@@ -2646,6 +3461,50 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List pull requests associated with a commit.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{commit_sha}/pulls` endpoint.
+     *
+     * As opposed to `list_all_pull_requests_associated_with_commit`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the merged pull request that introduced the commit to the repository. If the commit is not present in the default branch, additionally returns open pull requests associated with the commit. The results may include open and closed pull requests. Additional preview headers may be required to see certain details for associated pull requests, such as whether a pull request is in a draft state. For more information about previews that might affect this endpoint, see the [List pull requests](https://docs.github.com/rest/reference/pulls#list-pull-requests) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-pull-requests-associated-with-a-commit>
+     */
+    pub fn list_pull_requests_associated_with_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestSimple> {
+        let url = format!(
+            "/repos/{}/{}/commits/{}/pulls",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(commit_sha),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get a commit.
      *
@@ -2694,23 +3553,21 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
      * * `ref_: &str` -- ref parameter.
+     * * `params: &ReposGetCommitParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_commit(
+    pub async fn get_commit_with(
         &self,
         owner: &str,
         repo: &str,
-        page: i64,
-        per_page: i64,
         ref_: &str,
-    ) -> Result<crate::types::CommitDataType> {
+        params: &ReposGetCommitParams,
+    ) -> Result<crate::types::CommitData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2725,6 +3582,25 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_commit_with` and pass the query parameters as a `ReposGetCommitParams` instead.
+     */
+    #[deprecated(note = "use `get_commit_with` instead")]
+    pub async fn get_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: i64,
+        per_page: i64,
+        ref_: &str,
+    ) -> Result<crate::types::CommitData> {
+        let params = ReposGetCommitParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.get_commit_with(owner, repo, ref_, &params).await
+    }
+
     /**
      * Get the combined status for a specific reference.
      *
@@ -2747,22 +3623,20 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `ref_: &str` -- ref parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposGetCombinedStatusForRefParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_combined_status_for_ref(
+    pub async fn get_combined_status_for_ref_with(
         &self,
         owner: &str,
         repo: &str,
         ref_: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposGetCombinedStatusForRefParams,
     ) -> Result<crate::types::CombinedCommitStatus> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2777,6 +3651,26 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_combined_status_for_ref_with` and pass the query parameters as a `ReposGetCombinedStatusForRefParams` instead.
+     */
+    #[deprecated(note = "use `get_combined_status_for_ref_with` instead")]
+    pub async fn get_combined_status_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::CombinedCommitStatus> {
+        let params = ReposGetCombinedStatusForRefParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.get_combined_status_for_ref_with(owner, repo, ref_, &params)
+            .await
+    }
+
     /**
      * List commit statuses for a reference.
      *
@@ -2793,22 +3687,20 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `ref_: &str` -- ref parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListCommitStatusesForRefParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_commit_statuses_for_ref(
+    pub async fn list_commit_statuses_for_ref_with(
         &self,
         owner: &str,
         repo: &str,
         ref_: &str,
-        per_page: i64,
-        page: i64,
-    ) -> Result<Vec<crate::types::StatusData>> {
+        params: &ReposListCommitStatusesForRefParams,
+    ) -> Result<Vec<crate::types::Status>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2823,6 +3715,26 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_commit_statuses_for_ref_with` and pass the query parameters as a `ReposListCommitStatusesForRefParams` instead.
+     */
+    #[deprecated(note = "use `list_commit_statuses_for_ref_with` instead")]
+    pub async fn list_commit_statuses_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Status>> {
+        let params = ReposListCommitStatusesForRefParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_commit_statuses_for_ref_with(owner, repo, ref_, &params)
+            .await
+    }
+
     /**
      * List commit statuses for a reference.
      *
@@ -2841,7 +3753,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         ref_: &str,
-    ) -> Result<Vec<crate::types::StatusData>> {
+    ) -> Result<Vec<crate::types::Status>> {
         let url = format!(
             "/repos/{}/{}/commits/{}/statuses",
             crate::progenitor_support::encode_path(owner),
@@ -2852,6 +3764,52 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List commit statuses for a reference.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/commits/{ref}/statuses` endpoint.
+     *
+     * As opposed to `list_all_commit_statuses_for_ref`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Users with pull access in a repository can view commit statuses for a given ref. The ref can be a SHA, a branch name, or a tag name. Statuses are returned in reverse chronological order. The first status in the list will be the latest one.
+     *
+     * This resource is also available via a legacy route: `GET /repos/:owner/:repo/statuses/:ref`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-commit-statuses-for-a-reference>
+     */
+    pub fn list_commit_statuses_for_ref_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Status> {
+        let url = format!(
+            "/repos/{}/{}/commits/{}/statuses",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(ref_),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get community profile metrics.
      *
@@ -2943,23 +3901,21 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
      * * `basehead: &str` -- The base branch and head branch to compare. This parameter expects the format `{base}...{head}`.
+     * * `params: &ReposCompareCommitsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn compare_commits(
+    pub async fn compare_commits_with(
         &self,
         owner: &str,
         repo: &str,
-        page: i64,
-        per_page: i64,
         basehead: &str,
+        params: &ReposCompareCommitsParams,
     ) -> Result<crate::types::CommitComparison> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2974,6 +3930,26 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `compare_commits_with` and pass the query parameters as a `ReposCompareCommitsParams` instead.
+     */
+    #[deprecated(note = "use `compare_commits_with` instead")]
+    pub async fn compare_commits(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: i64,
+        per_page: i64,
+        basehead: &str,
+    ) -> Result<crate::types::CommitComparison> {
+        let params = ReposCompareCommitsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.compare_commits_with(owner, repo, basehead, &params)
+            .await
+    }
+
     /**
      * Get repository content.
      *
@@ -3019,17 +3995,17 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `path: &str` -- path parameter.
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetContentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_content_vec_entries(
+    pub async fn get_content_vec_directory_with(
         &self,
         owner: &str,
         repo: &str,
         path: &str,
-        ref_: &str,
-    ) -> Result<Vec<crate::types::Entries>> {
+        params: &ReposGetContentParams,
+    ) -> Result<Vec<crate::types::ContentDirectory>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3044,6 +4020,24 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_content_vec_directory_with` and pass the query parameters as a `ReposGetContentParams` instead.
+     */
+    #[deprecated(note = "use `get_content_vec_directory_with` instead")]
+    pub async fn get_content_vec_directory(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> Result<Vec<crate::types::ContentDirectory>> {
+        let params = ReposGetContentParams {
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_content_vec_directory_with(owner, repo, path, &params)
+            .await
+    }
+
     /**
      * Get repository content.
      *
@@ -3089,17 +4083,17 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `path: &str` -- path parameter.
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetContentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_content_file(
+    pub async fn get_content_file_with(
         &self,
         owner: &str,
         repo: &str,
         path: &str,
-        ref_: &str,
+        params: &ReposGetContentParams,
     ) -> Result<crate::types::ContentFile> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3114,6 +4108,23 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_content_file_with` and pass the query parameters as a `ReposGetContentParams` instead.
+     */
+    #[deprecated(note = "use `get_content_file_with` instead")]
+    pub async fn get_content_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> Result<crate::types::ContentFile> {
+        let params = ReposGetContentParams {
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_content_file_with(owner, repo, path, &params).await
+    }
+
     /**
      * Get repository content.
      *
@@ -3159,17 +4170,17 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `path: &str` -- path parameter.
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetContentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_content_symlink(
+    pub async fn get_content_symlink_with(
         &self,
         owner: &str,
         repo: &str,
         path: &str,
-        ref_: &str,
-    ) -> Result<crate::types::SymlinkContent> {
+        params: &ReposGetContentParams,
+    ) -> Result<crate::types::ContentSymlink> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3184,6 +4195,24 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_content_symlink_with` and pass the query parameters as a `ReposGetContentParams` instead.
+     */
+    #[deprecated(note = "use `get_content_symlink_with` instead")]
+    pub async fn get_content_symlink(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> Result<crate::types::ContentSymlink> {
+        let params = ReposGetContentParams {
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_content_symlink_with(owner, repo, path, &params)
+            .await
+    }
+
     /**
      * Get repository content.
      *
@@ -3229,17 +4258,17 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `path: &str` -- path parameter.
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetContentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_content_submodule(
+    pub async fn get_content_submodule_with(
         &self,
         owner: &str,
         repo: &str,
         path: &str,
-        ref_: &str,
+        params: &ReposGetContentParams,
     ) -> Result<crate::types::ContentSubmodule> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3254,6 +4283,24 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_content_submodule_with` and pass the query parameters as a `ReposGetContentParams` instead.
+     */
+    #[deprecated(note = "use `get_content_submodule_with` instead")]
+    pub async fn get_content_submodule(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> Result<crate::types::ContentSubmodule> {
+        let params = ReposGetContentParams {
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_content_submodule_with(owner, repo, path, &params)
+            .await
+    }
+
     /**
      * Get repository content.
      *
@@ -3299,17 +4346,17 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `path: &str` -- path parameter.
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetContentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_content(
+    pub async fn get_content_with(
         &self,
         owner: &str,
         repo: &str,
         path: &str,
-        ref_: &str,
+        params: &ReposGetContentParams,
     ) -> Result<crate::types::ReposGetContentResponseOneOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3325,9 +4372,26 @@ impl Repos {
     }
 
     /**
-     * Create or update file contents.
-     *
-     * This function performs a `PUT` to the `/repos/{owner}/{repo}/contents/{path}` endpoint.
+     * Deprecated: use `get_content_with` and pass the query parameters as a `ReposGetContentParams` instead.
+     */
+    #[deprecated(note = "use `get_content_with` instead")]
+    pub async fn get_content(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        ref_: &str,
+    ) -> Result<crate::types::ReposGetContentResponseOneOf> {
+        let params = ReposGetContentParams {
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_content_with(owner, repo, path, &params).await
+    }
+
+    /**
+     * Create or update file contents.
+     *
+     * This function performs a `PUT` to the `/repos/{owner}/{repo}/contents/{path}` endpoint.
      *
      * Creates a new file or replaces an existing file in a repository.
      *
@@ -3413,26 +4477,22 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `anon: &str` -- Set to `1` or `true` to include anonymous contributors in results.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListContributorsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_contributors(
+    pub async fn list_contributors_with(
         &self,
         owner: &str,
         repo: &str,
-        anon: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListContributorsParams,
     ) -> Result<Vec<crate::types::Contributor>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !anon.is_empty() {
+        if let Some(anon) = &params.anon {
             query_args.push(("anon".to_string(), anon.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3446,6 +4506,26 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_contributors_with` and pass the query parameters as a `ReposListContributorsParams` instead.
+     */
+    #[deprecated(note = "use `list_contributors_with` instead")]
+    pub async fn list_contributors(
+        &self,
+        owner: &str,
+        repo: &str,
+        anon: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Contributor>> {
+        let params = ReposListContributorsParams {
+            anon: Some(anon.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_contributors_with(owner, repo, &params).await
+    }
+
     /**
      * List repository contributors.
      *
@@ -3458,15 +4538,72 @@ impl Repos {
      * GitHub identifies contributors by author email address. This endpoint groups contribution counts by GitHub user, which includes all associated email addresses. To improve performance, only the first 500 author email addresses in the repository link to GitHub users. The rest will appear as anonymous contributors without associated GitHub user information.
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-repository-contributors>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReposListContributorsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_contributors_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListContributorsParams,
+    ) -> Result<Vec<crate::types::Contributor>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(anon) = &params.anon {
+            query_args.push(("anon".to_string(), anon.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/contributors?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_contributors_with` and pass the query parameters as a `ReposListContributorsParams` instead.
      */
+    #[deprecated(note = "use `list_all_contributors_with` instead")]
     pub async fn list_all_contributors(
         &self,
         owner: &str,
         repo: &str,
         anon: &str,
     ) -> Result<Vec<crate::types::Contributor>> {
+        let params = ReposListContributorsParams {
+            anon: Some(anon.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_contributors_with(owner, repo, &params).await
+    }
+
+    /**
+     * List repository contributors.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/contributors` endpoint.
+     *
+     * As opposed to `list_all_contributors`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists contributors to the specified repository and sorts them by the number of commits per contributor in descending order. This endpoint may return information that is a few hours old because the GitHub REST API v3 caches contributor data to improve performance.
+     *
+     * GitHub identifies contributors by author email address. This endpoint groups contribution counts by GitHub user, which includes all associated email addresses. To improve performance, only the first 500 author email addresses in the repository link to GitHub users. The rest will appear as anonymous contributors without associated GitHub user information.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-contributors>
+     */
+    pub fn list_contributors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListContributorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Contributor> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !anon.is_empty() {
+        if let Some(anon) = &params.anon {
             query_args.push(("anon".to_string(), anon.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3477,7 +4614,22 @@ impl Repos {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -3493,41 +4645,31 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `sha: &str` -- The SHA recorded at creation time.
-     * * `ref_: &str` -- The name of the ref. This can be a branch, tag, or SHA.
-     * * `task: &str` -- The name of the task for the deployment (e.g., `deploy` or `deploy:migrations`).
-     * * `environment: &str` -- The name of the environment that was deployed to (e.g., `staging` or `production`).
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListDeploymentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_deployments(
+    pub async fn list_deployments_with(
         &self,
         owner: &str,
         repo: &str,
-        sha: &str,
-        ref_: &str,
-        task: &str,
-        environment: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListDeploymentsParams,
     ) -> Result<Vec<crate::types::Deployment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !environment.is_empty() {
+        if let Some(environment) = &params.environment {
             query_args.push(("environment".to_string(), environment.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
-        if !sha.is_empty() {
+        if let Some(sha) = &params.sha {
             query_args.push(("sha".to_string(), sha.to_string()));
         }
-        if !task.is_empty() {
+        if let Some(task) = &params.task {
             query_args.push(("task".to_string(), task.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3541,6 +4683,32 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_deployments_with` and pass the query parameters as a `ReposListDeploymentsParams` instead.
+     */
+    #[deprecated(note = "use `list_deployments_with` instead")]
+    pub async fn list_deployments(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        ref_: &str,
+        task: &str,
+        environment: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Deployment>> {
+        let params = ReposListDeploymentsParams {
+            environment: Some(environment.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+            sha: Some(sha.to_string()).filter(|s| !s.is_empty()),
+            task: Some(task.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_deployments_with(owner, repo, &params).await
+    }
+
     /**
      * List deployments.
      *
@@ -3551,7 +4719,45 @@ impl Repos {
      * Simple filtering of deployments is available via query parameters:
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-deployments>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReposListDeploymentsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_deployments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListDeploymentsParams,
+    ) -> Result<Vec<crate::types::Deployment>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(environment) = &params.environment {
+            query_args.push(("environment".to_string(), environment.to_string()));
+        }
+        if let Some(ref_) = &params.ref_ {
+            query_args.push(("ref".to_string(), ref_.to_string()));
+        }
+        if let Some(sha) = &params.sha {
+            query_args.push(("sha".to_string(), sha.to_string()));
+        }
+        if let Some(task) = &params.task {
+            query_args.push(("task".to_string(), task.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/deployments?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_deployments_with` and pass the query parameters as a `ReposListDeploymentsParams` instead.
      */
+    #[deprecated(note = "use `list_all_deployments_with` instead")]
     pub async fn list_all_deployments(
         &self,
         owner: &str,
@@ -3561,17 +4767,46 @@ impl Repos {
         task: &str,
         environment: &str,
     ) -> Result<Vec<crate::types::Deployment>> {
+        let params = ReposListDeploymentsParams {
+            environment: Some(environment.to_string()).filter(|s| !s.is_empty()),
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+            sha: Some(sha.to_string()).filter(|s| !s.is_empty()),
+            task: Some(task.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_deployments_with(owner, repo, &params).await
+    }
+
+    /**
+     * List deployments.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments` endpoint.
+     *
+     * As opposed to `list_all_deployments`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Simple filtering of deployments is available via query parameters:
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployments>
+     */
+    pub fn list_deployments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListDeploymentsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Deployment> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !environment.is_empty() {
+        if let Some(environment) = &params.environment {
             query_args.push(("environment".to_string(), environment.to_string()));
         }
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
-        if !sha.is_empty() {
+        if let Some(sha) = &params.sha {
             query_args.push(("sha".to_string(), sha.to_string()));
         }
-        if !task.is_empty() {
+        if let Some(task) = &params.task {
             query_args.push(("task".to_string(), task.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3582,7 +4817,22 @@ impl Repos {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -3743,22 +4993,20 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `deployment_id: i64` -- deployment_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListDeploymentStatusesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_deployment_statuses(
+    pub async fn list_deployment_statuses_with(
         &self,
         owner: &str,
         repo: &str,
         deployment_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &ReposListDeploymentStatusesParams,
     ) -> Result<Vec<crate::types::DeploymentStatus>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3773,6 +5021,26 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_deployment_statuses_with` and pass the query parameters as a `ReposListDeploymentStatusesParams` instead.
+     */
+    #[deprecated(note = "use `list_deployment_statuses_with` instead")]
+    pub async fn list_deployment_statuses(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::DeploymentStatus>> {
+        let params = ReposListDeploymentStatusesParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_deployment_statuses_with(owner, repo, deployment_id, &params)
+            .await
+    }
+
     /**
      * List deployment statuses.
      *
@@ -3800,6 +5068,50 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List deployment statuses.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/deployments/{deployment_id}/statuses` endpoint.
+     *
+     * As opposed to `list_all_deployment_statuses`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Users with pull access can view deployment statuses for a deployment:
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deployment-statuses>
+     */
+    pub fn list_deployment_statuses_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DeploymentStatus> {
+        let url = format!(
+            "/repos/{}/{}/deployments/{}/statuses",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&deployment_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a deployment status.
      *
@@ -3960,7 +5272,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         environment_name: &str,
-    ) -> Result<crate::types::EnvironmentData> {
+    ) -> Result<crate::types::Environment> {
         let url = format!(
             "/repos/{}/{}/environments/{}",
             crate::progenitor_support::encode_path(owner),
@@ -3998,7 +5310,7 @@ impl Repos {
         repo: &str,
         environment_name: &str,
         body: &crate::types::ReposCreateUpdateEnvironmentRequest,
-    ) -> Result<crate::types::EnvironmentData> {
+    ) -> Result<crate::types::Environment> {
         let url = format!(
             "/repos/{}/{}/environments/{}",
             crate::progenitor_support::encode_path(owner),
@@ -4055,26 +5367,22 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `sort: crate::types::ReposListForksSort` -- The sort order. Can be either `newest`, `oldest`, or `stargazers`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListForksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_forks(
+    pub async fn list_forks_with(
         &self,
         owner: &str,
         repo: &str,
-        sort: crate::types::ReposListForksSort,
-        per_page: i64,
-        page: i64,
+        params: &ReposListForksParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4088,6 +5396,26 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_forks_with` and pass the query parameters as a `ReposListForksParams` instead.
+     */
+    #[deprecated(note = "use `list_forks_with` instead")]
+    pub async fn list_forks(
+        &self,
+        owner: &str,
+        repo: &str,
+        sort: crate::types::ReposListForksSort,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = ReposListForksParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_forks_with(owner, repo, &params).await
+    }
+
     /**
      * List forks.
      *
@@ -4098,15 +5426,70 @@ impl Repos {
      *
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-forks>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReposListForksParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_forks_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListForksParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/forks?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_forks_with` and pass the query parameters as a `ReposListForksParams` instead.
      */
+    #[deprecated(note = "use `list_all_forks_with` instead")]
     pub async fn list_all_forks(
         &self,
         owner: &str,
         repo: &str,
         sort: crate::types::ReposListForksSort,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = ReposListForksParams {
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_forks_with(owner, repo, &params).await
+    }
+
+    /**
+     * List forks.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/forks` endpoint.
+     *
+     * As opposed to `list_all_forks`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-forks>
+     */
+    pub fn list_forks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListForksParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4117,7 +5500,22 @@ impl Repos {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -4166,21 +5564,19 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListWebhooksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_webhooks(
+    pub async fn list_webhooks_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListWebhooksParams,
     ) -> Result<Vec<crate::types::Hook>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4194,6 +5590,24 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_webhooks_with` and pass the query parameters as a `ReposListWebhooksParams` instead.
+     */
+    #[deprecated(note = "use `list_webhooks_with` instead")]
+    pub async fn list_webhooks(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Hook>> {
+        let params = ReposListWebhooksParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_webhooks_with(owner, repo, &params).await
+    }
+
     /**
      * List repository webhooks.
      *
@@ -4220,12 +5634,54 @@ impl Repos {
     }
 
     /**
-     * Create a repository webhook.
+     * List repository webhooks.
      *
-     * This function performs a `POST` to the `/repos/{owner}/{repo}/hooks` endpoint.
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks` endpoint.
      *
-     * Repositories can have multiple webhooks installed. Each webhook should have a unique `config`. Multiple webhooks can
-     * share the same `config` as long as those webhooks do not have any `events` that overlap.
+     * As opposed to `list_all_webhooks`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-webhooks>
+     */
+    pub fn list_webhooks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Hook> {
+        let url = format!(
+            "/repos/{}/{}/hooks",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * Create a repository webhook.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/hooks` endpoint.
+     *
+     * Repositories can have multiple webhooks installed. Each webhook should have a unique `config`. Multiple webhooks can
+     * share the same `config` as long as those webhooks do not have any `events` that overlap.
      *
      * FROM: <https://docs.github.com/rest/reference/repos#create-a-repository-webhook>
      *
@@ -4397,7 +5853,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         hook_id: i64,
-        body: &crate::types::AppsUpdateWebhookConfigAppRequest,
+        body: &crate::types::ReposUpdateWebhookConfigRepoRequest,
     ) -> Result<crate::types::WebhookConfig> {
         let url = format!(
             "/repos/{}/{}/hooks/{}/config",
@@ -4425,22 +5881,20 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `hook_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `cursor: &str` -- Used for pagination: the starting delivery from which the page of deliveries is fetched. Refer to the `link` header for the next and previous page cursors.
+     * * `params: &ReposListWebhookDeliveriesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_webhook_deliveries(
+    pub async fn list_webhook_deliveries_with(
         &self,
         owner: &str,
         repo: &str,
         hook_id: i64,
-        per_page: i64,
-        cursor: &str,
+        params: &ReposListWebhookDeliveriesParams,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4455,6 +5909,26 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_webhook_deliveries_with` and pass the query parameters as a `ReposListWebhookDeliveriesParams` instead.
+     */
+    #[deprecated(note = "use `list_webhook_deliveries_with` instead")]
+    pub async fn list_webhook_deliveries(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        per_page: i64,
+        cursor: &str,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        let params = ReposListWebhookDeliveriesParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_webhook_deliveries_with(owner, repo, hook_id, &params)
+            .await
+    }
+
     /**
      * List deliveries for a repository webhook.
      *
@@ -4465,7 +5939,38 @@ impl Repos {
      * Returns a list of webhook deliveries for a webhook configured in a repository.
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-deliveries-for-a-repository-webhook>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReposListWebhookDeliveriesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_webhook_deliveries_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        params: &ReposListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(cursor) = &params.cursor {
+            query_args.push(("cursor".to_string(), cursor.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/hooks/{}/deliveries?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&hook_id.to_string()),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_webhook_deliveries_with` and pass the query parameters as a `ReposListWebhookDeliveriesParams` instead.
      */
+    #[deprecated(note = "use `list_all_webhook_deliveries_with` instead")]
     pub async fn list_all_webhook_deliveries(
         &self,
         owner: &str,
@@ -4473,8 +5978,36 @@ impl Repos {
         hook_id: i64,
         cursor: &str,
     ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        let params = ReposListWebhookDeliveriesParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_webhook_deliveries_with(owner, repo, hook_id, &params)
+            .await
+    }
+
+    /**
+     * List deliveries for a repository webhook.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/hooks/{hook_id}/deliveries` endpoint.
+     *
+     * As opposed to `list_all_webhook_deliveries`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns a list of webhook deliveries for a webhook configured in a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deliveries-for-a-repository-webhook>
+     */
+    pub fn list_webhook_deliveries_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        params: &ReposListWebhookDeliveriesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::HookDeliveryItem> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4486,7 +6019,22 @@ impl Repos {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -4545,7 +6093,7 @@ impl Repos {
         repo: &str,
         hook_id: i64,
         delivery_id: i64,
-    ) -> Result<()> {
+    ) -> Result<crate::types::AcceptedResponse> {
         let url = format!(
             "/repos/{}/{}/hooks/{}/deliveries/{}/attempts",
             crate::progenitor_support::encode_path(owner),
@@ -4624,21 +6172,19 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListInvitationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_invitations(
+    pub async fn list_invitations_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListInvitationsParams,
     ) -> Result<Vec<crate::types::RepositoryInvitation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4652,6 +6198,24 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_invitations_with` and pass the query parameters as a `ReposListInvitationsParams` instead.
+     */
+    #[deprecated(note = "use `list_invitations_with` instead")]
+    pub async fn list_invitations(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::RepositoryInvitation>> {
+        let params = ReposListInvitationsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_invitations_with(owner, repo, &params).await
+    }
+
     /**
      * List repository invitations.
      *
@@ -4677,6 +6241,48 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List repository invitations.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/invitations` endpoint.
+     *
+     * As opposed to `list_all_invitations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * When authenticating as a user with admin rights to a repository, this endpoint will list all currently open repository invitations.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations>
+     */
+    pub fn list_invitations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RepositoryInvitation> {
+        let url = format!(
+            "/repos/{}/{}/invitations",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Delete a repository invitation.
      *
@@ -4755,21 +6361,19 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListDeployKeysParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_deploy_keys(
+    pub async fn list_deploy_keys_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListDeployKeysParams,
     ) -> Result<Vec<crate::types::DeployKey>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4783,6 +6387,24 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_deploy_keys_with` and pass the query parameters as a `ReposListDeployKeysParams` instead.
+     */
+    #[deprecated(note = "use `list_deploy_keys_with` instead")]
+    pub async fn list_deploy_keys(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::DeployKey>> {
+        let params = ReposListDeployKeysParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_deploy_keys_with(owner, repo, &params).await
+    }
+
     /**
      * List deploy keys.
      *
@@ -4808,6 +6430,48 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List deploy keys.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/keys` endpoint.
+     *
+     * As opposed to `list_all_deploy_keys`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-deploy-keys>
+     */
+    pub fn list_deploy_keys_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DeployKey> {
+        let url = format!(
+            "/repos/{}/{}/keys",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a deploy key.
      *
@@ -4910,7 +6574,11 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      */
-    pub async fn list_languages(&self, owner: &str, repo: &str) -> Result<i64> {
+    pub async fn list_languages(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<std::collections::BTreeMap<String, i64>> {
         let url = format!(
             "/repos/{}/{}/languages",
             crate::progenitor_support::encode_path(owner),
@@ -4939,7 +6607,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         body: &crate::types::ReposMergeRequest,
-    ) -> Result<crate::types::CommitDataType> {
+    ) -> Result<crate::types::CommitData> {
         let url = format!(
             "/repos/{}/{}/merges",
             crate::progenitor_support::encode_path(owner),
@@ -5074,21 +6742,19 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListPagesBuildsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_pages_builds(
+    pub async fn list_pages_builds_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListPagesBuildsParams,
     ) -> Result<Vec<crate::types::PageBuild>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5102,6 +6768,24 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_pages_builds_with` and pass the query parameters as a `ReposListPagesBuildsParams` instead.
+     */
+    #[deprecated(note = "use `list_pages_builds_with` instead")]
+    pub async fn list_pages_builds(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::PageBuild>> {
+        let params = ReposListPagesBuildsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_pages_builds_with(owner, repo, &params).await
+    }
+
     /**
      * List GitHub Pages builds.
      *
@@ -5127,6 +6811,48 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List GitHub Pages builds.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/pages/builds` endpoint.
+     *
+     * As opposed to `list_all_pages_builds`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-github-pages-builds>
+     */
+    pub fn list_pages_builds_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PageBuild> {
+        let url = format!(
+            "/repos/{}/{}/pages/builds",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Request a GitHub Pages build.
      *
@@ -5263,16 +6989,16 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetReadmeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_readme(
+    pub async fn get_readme_with(
         &self,
         owner: &str,
         repo: &str,
-        ref_: &str,
+        params: &ReposGetReadmeParams,
     ) -> Result<crate::types::ContentFile> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5286,6 +7012,22 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_readme_with` and pass the query parameters as a `ReposGetReadmeParams` instead.
+     */
+    #[deprecated(note = "use `get_readme_with` instead")]
+    pub async fn get_readme(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<crate::types::ContentFile> {
+        let params = ReposGetReadmeParams {
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_readme_with(owner, repo, &params).await
+    }
+
     /**
      * Get a repository README for a directory.
      *
@@ -5302,17 +7044,17 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `dir: &str` -- The alternate path to look for a README file.
-     * * `ref_: &str` -- The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+     * * `params: &ReposGetReadmeInDirectoryParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_readme_in_directory(
+    pub async fn get_readme_in_directory_with(
         &self,
         owner: &str,
         repo: &str,
         dir: &str,
-        ref_: &str,
+        params: &ReposGetReadmeInDirectoryParams,
     ) -> Result<crate::types::ContentFile> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ref_.is_empty() {
+        if let Some(ref_) = &params.ref_ {
             query_args.push(("ref".to_string(), ref_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5327,6 +7069,24 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_readme_in_directory_with` and pass the query parameters as a `ReposGetReadmeInDirectoryParams` instead.
+     */
+    #[deprecated(note = "use `get_readme_in_directory_with` instead")]
+    pub async fn get_readme_in_directory(
+        &self,
+        owner: &str,
+        repo: &str,
+        dir: &str,
+        ref_: &str,
+    ) -> Result<crate::types::ContentFile> {
+        let params = ReposGetReadmeInDirectoryParams {
+            ref_: Some(ref_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_readme_in_directory_with(owner, repo, dir, &params)
+            .await
+    }
+
     /**
      * List releases.
      *
@@ -5342,21 +7102,19 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListReleasesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_releases(
+    pub async fn list_releases_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListReleasesParams,
     ) -> Result<Vec<crate::types::Release>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5370,6 +7128,24 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_releases_with` and pass the query parameters as a `ReposListReleasesParams` instead.
+     */
+    #[deprecated(note = "use `list_releases_with` instead")]
+    pub async fn list_releases(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Release>> {
+        let params = ReposListReleasesParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_releases_with(owner, repo, &params).await
+    }
+
     /**
      * List releases.
      *
@@ -5398,36 +7174,80 @@ impl Repos {
     }
 
     /**
-     * Create a release.
-     *
-     * This function performs a `POST` to the `/repos/{owner}/{repo}/releases` endpoint.
+     * List releases.
      *
-     * Users with push access to the repository can create a release.
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases` endpoint.
      *
-     * This endpoint triggers [notifications](https://docs.github.com/en/github/managing-subscriptions-and-notifications-on-github/about-notifications). Creating content too quickly using this endpoint may result in secondary rate limiting. See "[Secondary rate limits](https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits)" and "[Dealing with secondary rate limits](https://docs.github.com/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits)" for details.
+     * As opposed to `list_all_releases`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
      *
-     * FROM: <https://docs.github.com/rest/reference/repos#create-a-release>
+     * This returns a list of releases, which does not include regular Git tags that have not been associated with a release. To get a list of Git tags, use the [Repository Tags API](https://docs.github.com/rest/reference/repos#list-repository-tags).
      *
-     * **Parameters:**
+     * Information about published releases are available to everyone. Only users with push access will receive listings for draft releases.
      *
-     * * `owner: &str`
-     * * `repo: &str`
+     * FROM: <https://docs.github.com/rest/reference/repos#list-releases>
      */
-    pub async fn create_release(
+    pub fn list_releases_stream(
         &self,
         owner: &str,
         repo: &str,
-        body: &crate::types::ReposCreateReleaseRequest,
-    ) -> Result<crate::types::Release> {
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Release> {
         let url = format!(
             "/repos/{}/{}/releases",
             crate::progenitor_support::encode_path(owner),
             crate::progenitor_support::encode_path(repo),
         );
 
-        self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * Create a release.
+     *
+     * This function performs a `POST` to the `/repos/{owner}/{repo}/releases` endpoint.
+     *
+     * Users with push access to the repository can create a release.
+     *
+     * This endpoint triggers [notifications](https://docs.github.com/en/github/managing-subscriptions-and-notifications-on-github/about-notifications). Creating content too quickly using this endpoint may result in secondary rate limiting. See "[Secondary rate limits](https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits)" and "[Dealing with secondary rate limits](https://docs.github.com/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits)" for details.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#create-a-release>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     */
+    pub async fn create_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateReleaseRequest,
+    ) -> Result<crate::types::Release> {
+        let url = format!(
+            "/repos/{}/{}/releases",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        self.client
+            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .await
     }
 
     /**
@@ -5659,7 +7479,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         release_id: i64,
-        body: &crate::types::ReposCreateReleaseRequest,
+        body: &crate::types::ReposUpdateReleaseRequest,
     ) -> Result<crate::types::Release> {
         let url = format!(
             "/repos/{}/{}/releases/{}",
@@ -5687,22 +7507,20 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `release_id: i64` -- release_id parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListReleaseAssetsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_release_assets(
+    pub async fn list_release_assets_with(
         &self,
         owner: &str,
         repo: &str,
         release_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &ReposListReleaseAssetsParams,
     ) -> Result<Vec<crate::types::ReleaseAsset>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5717,6 +7535,26 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_release_assets_with` and pass the query parameters as a `ReposListReleaseAssetsParams` instead.
+     */
+    #[deprecated(note = "use `list_release_assets_with` instead")]
+    pub async fn list_release_assets(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::ReleaseAsset>> {
+        let params = ReposListReleaseAssetsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_release_assets_with(owner, repo, release_id, &params)
+            .await
+    }
+
     /**
      * List release assets.
      *
@@ -5744,6 +7582,50 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List release assets.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/releases/{release_id}/assets` endpoint.
+     *
+     * As opposed to `list_all_release_assets`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-release-assets>
+     */
+    pub fn list_release_assets_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReleaseAsset> {
+        let url = format!(
+            "/repos/{}/{}/releases/{}/assets",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(&release_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Upload a release asset.
      *
@@ -5775,23 +7657,21 @@ impl Repos {
      * * `owner: &str`
      * * `repo: &str`
      * * `release_id: i64` -- release_id parameter.
-     * * `name: &str`
-     * * `label: &str`
+     * * `params: &ReposUploadReleaseAssetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn upload_release_asset<T: Into<reqwest::Body>>(
+    pub async fn upload_release_asset_with<T: Into<reqwest::Body>>(
         &self,
         owner: &str,
         repo: &str,
         release_id: i64,
-        name: &str,
-        label: &str,
+        params: &ReposUploadReleaseAssetParams,
         body: T,
     ) -> Result<crate::types::ReleaseAsset> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !label.is_empty() {
+        if let Some(label) = &params.label {
             query_args.push(("label".to_string(), label.to_string()));
         }
-        if !name.is_empty() {
+        if let Some(name) = &params.name {
             query_args.push(("name".to_string(), name.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5806,6 +7686,27 @@ impl Repos {
         self.client.post(&url, Some(body.into())).await
     }
 
+    /**
+     * Deprecated: use `upload_release_asset_with` and pass the query parameters as a `ReposUploadReleaseAssetParams` instead.
+     */
+    #[deprecated(note = "use `upload_release_asset_with` instead")]
+    pub async fn upload_release_asset<T: Into<reqwest::Body>>(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        name: &str,
+        label: &str,
+        body: T,
+    ) -> Result<crate::types::ReleaseAsset> {
+        let params = ReposUploadReleaseAssetParams {
+            label: Some(label.to_string()).filter(|s| !s.is_empty()),
+            name: Some(name.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.upload_release_asset_with(owner, repo, release_id, &params, body)
+            .await
+    }
+
     /**
      * Get the weekly commit activity.
      *
@@ -5855,6 +7756,48 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get the weekly commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/code_frequency` endpoint.
+     *
+     * As opposed to `get_all_code_frequency_stats`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns a weekly aggregate of the number of additions and deletions pushed to a repository.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-weekly-commit-activity>
+     */
+    pub fn get_code_frequency_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<i64> {
+        let url = format!(
+            "/repos/{}/{}/stats/code_frequency",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get the last year of commit activity.
      *
@@ -5908,6 +7851,48 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get the last year of commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/commit_activity` endpoint.
+     *
+     * As opposed to `get_all_commit_activity_stats`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns the last year of commit activity grouped by week. The `days` array is a group of commits per day, starting on `Sunday`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-last-year-of-commit-activity>
+     */
+    pub fn get_commit_activity_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitActivity> {
+        let url = format!(
+            "/repos/{}/{}/stats/commit_activity",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get all contributor commit activity.
      *
@@ -5973,6 +7958,54 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get all contributor commit activity.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/contributors` endpoint.
+     *
+     * As opposed to `get_all_contributors_stats`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     * Returns the `total` number of commits authored by the contributor. In addition, the response includes a Weekly Hash (`weeks` array) with the following information:
+     *
+     * *   `w` - Start of the week, given as a [Unix timestamp](http://en.wikipedia.org/wiki/Unix_time).
+     * *   `a` - Number of additions
+     * *   `d` - Number of deletions
+     * *   `c` - Number of commits
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-all-contributor-commit-activity>
+     */
+    pub fn get_contributors_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ContributorActivity> {
+        let url = format!(
+            "/repos/{}/{}/stats/contributors",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get the weekly commit count.
      *
@@ -6060,6 +8093,54 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get the hourly commit count for each day.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/stats/punch_card` endpoint.
+     *
+     * As opposed to `get_all_punch_card_stats`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Each array contains the day number, hour number, and number of commits:
+     *
+     * *   `0-6`: Sunday - Saturday
+     * *   `0-23`: Hour of day
+     * *   Number of commits
+     *
+     * For example, `[2, 14, 25]` indicates that there were 25 total commits, during the 2:00pm hour on Tuesdays. All times are based on the time zone of individual commits.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-the-hourly-commit-count-for-each-day>
+     */
+    pub fn get_punch_card_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<i64> {
+        let url = format!(
+            "/repos/{}/{}/stats/punch_card",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a commit status.
      *
@@ -6083,7 +8164,7 @@ impl Repos {
         repo: &str,
         sha: &str,
         body: &crate::types::ReposCreateCommitStatusRequest,
-    ) -> Result<crate::types::StatusData> {
+    ) -> Result<crate::types::Status> {
         let url = format!(
             "/repos/{}/{}/statuses/{}",
             crate::progenitor_support::encode_path(owner),
@@ -6109,21 +8190,19 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListTagsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_tags(
+    pub async fn list_tags_with(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
+        params: &ReposListTagsParams,
     ) -> Result<Vec<crate::types::Tag>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6137,6 +8216,24 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_tags_with` and pass the query parameters as a `ReposListTagsParams` instead.
+     */
+    #[deprecated(note = "use `list_tags_with` instead")]
+    pub async fn list_tags(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Tag>> {
+        let params = ReposListTagsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_tags_with(owner, repo, &params).await
+    }
+
     /**
      * List repository tags.
      *
@@ -6158,6 +8255,48 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List repository tags.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/tags` endpoint.
+     *
+     * As opposed to `list_all_tags`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-tags>
+     */
+    pub fn list_tags_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Tag> {
+        let url = format!(
+            "/repos/{}/{}/tags",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Download a repository archive (tar).
      *
@@ -6181,7 +8320,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         ref_: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
         let url = format!(
             "/repos/{}/{}/tarball/{}",
             crate::progenitor_support::encode_path(owner),
@@ -6189,39 +8328,71 @@ impl Repos {
             crate::progenitor_support::encode_path(ref_),
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
     }
 
     /**
-     * List repository teams.
-     *
-     * This function performs a `GET` to the `/repos/{owner}/{repo}/teams` endpoint.
-     *
+     * Download a repository archive (tar).
      *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/tarball/{ref}` endpoint.
      *
-     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-teams>
+     * As opposed to `download_tarball_archive`, this function returns the body as a stream of chunks instead of reading it into memory at once.
      *
-     * **Parameters:**
+     * Gets a redirect URL to download a tar archive for a repository. If you omit `:ref`, the repository’s default branch (usually
+     * `master`) will be used. Please make sure your HTTP framework is configured to follow redirects or you will need to use
+     * the `Location` header to make a second `GET` request.
+     * **Note**: For private repositories, these links are temporary and expire after five minutes.
      *
-     * * `owner: &str`
-     * * `repo: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * FROM: <https://docs.github.com/rest/reference/repos#download-a-repository-archive>
      */
-    pub async fn list_teams(
+    pub async fn download_tarball_archive_stream(
         &self,
         owner: &str,
         repo: &str,
-        per_page: i64,
-        page: i64,
-    ) -> Result<Vec<crate::types::Team>> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
-            query_args.push(("page".to_string(), page.to_string()));
-        }
-        if per_page > 0 {
-            query_args.push(("per_page".to_string(), per_page.to_string()));
-        }
+        ref_: &str,
+    ) -> Result<crate::DownloadStream> {
+        let url = format!(
+            "/repos/{}/{}/tarball/{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(ref_),
+        );
+
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * List repository teams.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/teams` endpoint.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-teams>
+     *
+     * **Parameters:**
+     *
+     * * `owner: &str`
+     * * `repo: &str`
+     * * `params: &ReposListTeamsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_teams_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListTeamsParams,
+    ) -> Result<Vec<crate::types::Team>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(page) = &params.page {
+            query_args.push(("page".to_string(), page.to_string()));
+        }
+        if let Some(per_page) = &params.per_page {
+            query_args.push(("per_page".to_string(), per_page.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
             "/repos/{}/{}/teams?{}",
@@ -6233,6 +8404,24 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_teams_with` and pass the query parameters as a `ReposListTeamsParams` instead.
+     */
+    #[deprecated(note = "use `list_teams_with` instead")]
+    pub async fn list_teams(
+        &self,
+        owner: &str,
+        repo: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Team>> {
+        let params = ReposListTeamsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_teams_with(owner, repo, &params).await
+    }
+
     /**
      * List repository teams.
      *
@@ -6254,6 +8443,48 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List repository teams.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/teams` endpoint.
+     *
+     * As opposed to `list_all_teams`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     *
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-teams>
+     */
+    pub fn list_teams_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team> {
+        let url = format!(
+            "/repos/{}/{}/teams",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get all repository topics.
      *
@@ -6267,21 +8498,19 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
+     * * `params: &ReposGetAllTopicsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_topics(
+    pub async fn get_all_topics_with(
         &self,
         owner: &str,
         repo: &str,
-        page: i64,
-        per_page: i64,
+        params: &ReposGetAllTopicsParams,
     ) -> Result<crate::types::Topic> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6295,6 +8524,24 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_all_topics_with` and pass the query parameters as a `ReposGetAllTopicsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_topics_with` instead")]
+    pub async fn get_all_topics(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: i64,
+        per_page: i64,
+    ) -> Result<crate::types::Topic> {
+        let params = ReposGetAllTopicsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.get_all_topics_with(owner, repo, &params).await
+    }
+
     /**
      * Replace all repository topics.
      *
@@ -6313,7 +8560,7 @@ impl Repos {
         &self,
         owner: &str,
         repo: &str,
-        body: &crate::types::Topic,
+        body: &crate::types::ReposReplaceAllTopicsRequest,
     ) -> Result<crate::types::Topic> {
         let url = format!(
             "/repos/{}/{}/topics",
@@ -6339,16 +8586,16 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per: crate::types::Per` -- Must be one of: `day`, `week`.
+     * * `params: &ReposGetClonesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_clones(
+    pub async fn get_clones_with(
         &self,
         owner: &str,
         repo: &str,
-        per: crate::types::Per,
+        params: &ReposGetClonesParams,
     ) -> Result<crate::types::CloneTraffic> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !per.to_string().is_empty() {
+        if let Some(per) = &params.per {
             query_args.push(("per".to_string(), per.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6362,6 +8609,22 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_clones_with` and pass the query parameters as a `ReposGetClonesParams` instead.
+     */
+    #[deprecated(note = "use `get_clones_with` instead")]
+    pub async fn get_clones(
+        &self,
+        owner: &str,
+        repo: &str,
+        per: crate::types::Per,
+    ) -> Result<crate::types::CloneTraffic> {
+        let params = ReposGetClonesParams {
+            per: Some(per).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_clones_with(owner, repo, &params).await
+    }
+
     /**
      * Get top referral paths.
      *
@@ -6415,6 +8678,48 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get top referral paths.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/paths` endpoint.
+     *
+     * As opposed to `get_all_top_paths`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Get the top 10 popular contents over the last 14 days.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-paths>
+     */
+    pub fn get_top_paths_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ContentTraffic> {
+        let url = format!(
+            "/repos/{}/{}/traffic/popular/paths",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get top referral sources.
      *
@@ -6468,6 +8773,48 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get top referral sources.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/traffic/popular/referrers` endpoint.
+     *
+     * As opposed to `get_all_top_referrers`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Get the top 10 referrers over the last 14 days.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#get-top-referral-sources>
+     */
+    pub fn get_top_referrers_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReferrerTraffic> {
+        let url = format!(
+            "/repos/{}/{}/traffic/popular/referrers",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get page views.
      *
@@ -6481,16 +8828,16 @@ impl Repos {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `per: crate::types::Per` -- Must be one of: `day`, `week`.
+     * * `params: &ReposGetViewsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_views(
+    pub async fn get_views_with(
         &self,
         owner: &str,
         repo: &str,
-        per: crate::types::Per,
+        params: &ReposGetViewsParams,
     ) -> Result<crate::types::ViewTraffic> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !per.to_string().is_empty() {
+        if let Some(per) = &params.per {
             query_args.push(("per".to_string(), per.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6504,6 +8851,22 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_views_with` and pass the query parameters as a `ReposGetViewsParams` instead.
+     */
+    #[deprecated(note = "use `get_views_with` instead")]
+    pub async fn get_views(
+        &self,
+        owner: &str,
+        repo: &str,
+        per: crate::types::Per,
+    ) -> Result<crate::types::ViewTraffic> {
+        let params = ReposGetViewsParams {
+            per: Some(per).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_views_with(owner, repo, &params).await
+    }
+
     /**
      * Transfer a repository.
      *
@@ -6630,7 +8993,7 @@ impl Repos {
         owner: &str,
         repo: &str,
         ref_: &str,
-    ) -> Result<()> {
+    ) -> Result<crate::Download> {
         let url = format!(
             "/repos/{}/{}/zipball/{}",
             crate::progenitor_support::encode_path(owner),
@@ -6638,7 +9001,41 @@ impl Repos {
             crate::progenitor_support::encode_path(ref_),
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Download a repository archive (zip).
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/zipball/{ref}` endpoint.
+     *
+     * As opposed to `download_zipball_archive`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Gets a redirect URL to download a zip archive for a repository. If you omit `:ref`, the repository’s default branch (usually
+     * `master`) will be used. Please make sure your HTTP framework is configured to follow redirects or you will need to use
+     * the `Location` header to make a second `GET` request.
+     * **Note**: For private repositories, these links are temporary and expire after five minutes.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#download-a-repository-archive>
+     */
+    pub async fn download_zipball_archive_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<crate::DownloadStream> {
+        let url = format!(
+            "/repos/{}/{}/zipball/{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            crate::progenitor_support::encode_path(ref_),
+        );
+
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
     }
 
     /**
@@ -6694,11 +9091,14 @@ impl Repos {
      *
      * **Parameters:**
      *
-     * * `since: i64` -- A repository ID. Only return repositories with an ID greater than this ID.
+     * * `params: &ReposListPublicParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_public(&self, since: i64) -> Result<Vec<crate::types::MinimalRepository>> {
+    pub async fn list_public_with(
+        &self,
+        params: &ReposListPublicParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if since > 0 {
+        if let Some(since) = &params.since {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6707,6 +9107,17 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_public_with` and pass the query parameters as a `ReposListPublicParams` instead.
+     */
+    #[deprecated(note = "use `list_public_with` instead")]
+    pub async fn list_public(&self, since: i64) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = ReposListPublicParams {
+            since: Some(since).filter(|v| *v > 0),
+        };
+        self.list_public_with(&params).await
+    }
+
     /**
      * List public repositories.
      *
@@ -6721,19 +9132,83 @@ impl Repos {
      * - Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of repositories.
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-public-repositories>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReposListPublicParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_public_with(
+        &self,
+        params: &ReposListPublicParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/repositories?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_public_with` and pass the query parameters as a `ReposListPublicParams` instead.
      */
+    #[deprecated(note = "use `list_all_public_with` instead")]
     pub async fn list_all_public(
         &self,
         since: i64,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = ReposListPublicParams {
+            since: Some(since).filter(|v| *v > 0),
+        };
+        self.list_all_public_with(&params).await
+    }
+
+    /**
+     * List public repositories.
+     *
+     * This function performs a `GET` to the `/repositories` endpoint.
+     *
+     * As opposed to `list_all_public`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all public repositories in the order that they were created.
+     *
+     * Note:
+     * - For GitHub Enterprise Server, this endpoint will only list repositories available to all users on the enterprise.
+     * - Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of repositories.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-public-repositories>
+     */
+    pub fn list_public_stream(
+        &self,
+        params: &ReposListPublicParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if since > 0 {
+        if let Some(since) = &params.since {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/repositories?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -6749,61 +9224,38 @@ impl Repos {
      *
      * **Parameters:**
      *
-     * * `visibility: crate::types::ReposListVisibility` -- Can be one of `all`, `public`, or `private`. Note: For GitHub AE, can be one of `all`, `internal`, or `private`.
-     * * `affiliation: &str` -- Comma-separated list of values. Can include:  
-     *   \* `owner`: Repositories that are owned by the authenticated user.  
-     *   \* `collaborator`: Repositories that the user has been added to as a collaborator.  
-     *   \* `organization_member`: Repositories that the user has access to through being a member of an organization. This includes every repository on every team that the user is on.
-     * * `type_: crate::types::ReposListType` -- Can be one of `all`, `owner`, `public`, `private`, `member`. Note: For GitHub AE, can be one of `all`, `owner`, `internal`, `private`, `member`. Default: `all`  
-     *    
-     *  Will cause a `422` error if used in the same request as \*\*visibility\*\* or \*\*affiliation\*\*. Will cause a `422` error if used in the same request as \*\*visibility\*\* or \*\*affiliation\*\*.
-     * * `sort: crate::types::ReposListOrgSort` -- Can be one of `created`, `updated`, `pushed`, `full_name`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `since: chrono::DateTime<chrono::Utc>` -- Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
-     * * `before: chrono::DateTime<chrono::Utc>` -- Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+     * * `params: &ReposListForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_authenticated_user(
+    pub async fn list_for_authenticated_user_with(
         &self,
-        visibility: crate::types::ReposListVisibility,
-        affiliation: &str,
-        type_: crate::types::ReposListType,
-        sort: crate::types::ReposListOrgSort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        before: Option<chrono::DateTime<chrono::Utc>>,
+        params: &ReposListForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::Repository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !affiliation.is_empty() {
+        if let Some(affiliation) = &params.affiliation {
             query_args.push(("affiliation".to_string(), affiliation.to_string()));
         }
-        if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+        if let Some(before) = &params.before {
+            query_args.push(("before".to_string(), before.to_rfc3339()));
         }
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
-        if !visibility.to_string().is_empty() {
+        if let Some(visibility) = &params.visibility {
             query_args.push(("visibility".to_string(), visibility.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6812,6 +9264,36 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_authenticated_user_with` and pass the query parameters as a `ReposListForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_for_authenticated_user_with` instead")]
+    pub async fn list_for_authenticated_user(
+        &self,
+        visibility: crate::types::ReposListVisibility,
+        affiliation: &str,
+        type_: crate::types::ReposListType,
+        sort: crate::types::ReposListSort,
+        direction: crate::types::Direction,
+        per_page: i64,
+        page: i64,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<crate::types::Repository>> {
+        let params = ReposListForAuthenticatedUserParams {
+            affiliation: Some(affiliation.to_string()).filter(|s| !s.is_empty()),
+            before,
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            visibility: Some(visibility).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_for_authenticated_user_with(&params).await
+    }
+
     /**
      * List repositories for the authenticated user.
      *
@@ -6824,43 +9306,130 @@ impl Repos {
      * The authenticated user has explicit permission to access repositories they own, repositories where they are a collaborator, and repositories that they can access through an organization membership.
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-the-authenticated-user>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReposListForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_for_authenticated_user(
+    pub async fn list_all_for_authenticated_user_with(
         &self,
-        visibility: crate::types::ReposListVisibility,
-        affiliation: &str,
-        type_: crate::types::ReposListType,
-        sort: crate::types::ReposListOrgSort,
-        direction: crate::types::Order,
+        params: &ReposListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Repository>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(affiliation) = &params.affiliation {
+            query_args.push(("affiliation".to_string(), affiliation.to_string()));
+        }
+        if let Some(before) = &params.before {
+            query_args.push(("before".to_string(), before.to_rfc3339()));
+        }
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        if let Some(visibility) = &params.visibility {
+            query_args.push(("visibility".to_string(), visibility.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/user/repos?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_authenticated_user_with` and pass the query parameters as a `ReposListForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_all_for_authenticated_user_with` instead")]
+    pub async fn list_all_for_authenticated_user(
+        &self,
+        visibility: crate::types::ReposListVisibility,
+        affiliation: &str,
+        type_: crate::types::ReposListType,
+        sort: crate::types::ReposListSort,
+        direction: crate::types::Direction,
         since: Option<chrono::DateTime<chrono::Utc>>,
         before: Option<chrono::DateTime<chrono::Utc>>,
     ) -> Result<Vec<crate::types::Repository>> {
+        let params = ReposListForAuthenticatedUserParams {
+            affiliation: Some(affiliation.to_string()).filter(|s| !s.is_empty()),
+            before,
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            since,
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            visibility: Some(visibility).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_authenticated_user_with(&params).await
+    }
+
+    /**
+     * List repositories for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repos` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists repositories that the authenticated user has explicit permission (`:read`, `:write`, or `:admin`) to access.
+     *
+     * The authenticated user has explicit permission to access repositories they own, repositories where they are a collaborator, and repositories that they can access through an organization membership.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-the-authenticated-user>
+     */
+    pub fn list_for_authenticated_user_stream(
+        &self,
+        params: &ReposListForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Repository> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !affiliation.is_empty() {
+        if let Some(affiliation) = &params.affiliation {
             query_args.push(("affiliation".to_string(), affiliation.to_string()));
         }
-        if let Some(date) = before {
-            query_args.push(("before".to_string(), date.to_rfc3339()));
+        if let Some(before) = &params.before {
+            query_args.push(("before".to_string(), before.to_rfc3339()));
         }
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
-        if !visibility.to_string().is_empty() {
+        if let Some(visibility) = &params.visibility {
             query_args.push(("visibility".to_string(), visibility.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/user/repos?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -6900,19 +9469,17 @@ impl Repos {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListInvitationsForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_invitations_for_authenticated_user(
+    pub async fn list_invitations_for_authenticated_user_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &ReposListInvitationsForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::RepositoryInvitation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6921,6 +9488,23 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_invitations_for_authenticated_user_with` and pass the query parameters as a `ReposListInvitationsForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_invitations_for_authenticated_user_with` instead")]
+    pub async fn list_invitations_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::RepositoryInvitation>> {
+        let params = ReposListInvitationsForAuthenticatedUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_invitations_for_authenticated_user_with(&params)
+            .await
+    }
+
     /**
      * List repository invitations for the authenticated user.
      *
@@ -6939,6 +9523,41 @@ impl Repos {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List repository invitations for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/repository_invitations` endpoint.
+     *
+     * As opposed to `list_all_invitations_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * When authenticating as a user, this endpoint will list all currently open repository invitations for that user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repository-invitations-for-the-authenticated-user>
+     */
+    pub fn list_invitations_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RepositoryInvitation> {
+        let url = "/user/repository_invitations".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Decline a repository invitation.
      *
@@ -6995,37 +9614,27 @@ impl Repos {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `type_: crate::types::ReposListUserType` -- Can be one of `all`, `owner`, `member`.
-     * * `sort: crate::types::ReposListOrgSort` -- Can be one of `created`, `updated`, `pushed`, `full_name`.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &ReposListForUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_user(
+    pub async fn list_for_user_with(
         &self,
         username: &str,
-        type_: crate::types::ReposListUserType,
-        sort: crate::types::ReposListOrgSort,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &ReposListForUserParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -7038,6 +9647,29 @@ impl Repos {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_for_user_with` and pass the query parameters as a `ReposListForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_for_user_with` instead")]
+    pub async fn list_for_user(
+        &self,
+        username: &str,
+        type_: crate::types::ReposListUserType,
+        sort: crate::types::ReposListUserSort,
+        direction: crate::types::Direction,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = ReposListForUserParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_for_user_with(username, &params).await
+    }
+
     /**
      * List repositories for a user.
      *
@@ -7048,22 +9680,82 @@ impl Repos {
      * Lists public repositories for the specified user. Note: For GitHub AE, this endpoint will list internal repositories for the specified user.
      *
      * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-a-user>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReposListForUserParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_for_user_with(
+        &self,
+        username: &str,
+        params: &ReposListForUserParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/users/{}/repos?{}",
+            crate::progenitor_support::encode_path(username),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_for_user_with` and pass the query parameters as a `ReposListForUserParams` instead.
      */
+    #[deprecated(note = "use `list_all_for_user_with` instead")]
     pub async fn list_all_for_user(
         &self,
         username: &str,
         type_: crate::types::ReposListUserType,
-        sort: crate::types::ReposListOrgSort,
-        direction: crate::types::Order,
+        sort: crate::types::ReposListUserSort,
+        direction: crate::types::Direction,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = ReposListForUserParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_for_user_with(username, &params).await
+    }
+
+    /**
+     * List repositories for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/repos` endpoint.
+     *
+     * As opposed to `list_all_for_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists public repositories for the specified user. Note: For GitHub AE, this endpoint will list internal repositories for the specified user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/repos#list-repositories-for-a-user>
+     */
+    pub fn list_for_user_stream(
+        &self,
+        username: &str,
+        params: &ReposListForUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -7073,6 +9765,4747 @@ impl Repos {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Repos::list_for_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListForOrgParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Can be one of `created`, `updated`, `pushed`, `full_name`.
+    pub sort: Option<crate::types::ReposListOrgSort>,
+    /// Specifies the types of repositories you want returned. Can be one of `all`, `public`, `private`, `forks`, `sources`, `member`, `internal`. Note: For GitHub AE, can be one of `all`, `private`, `forks`, `sources`, `member`, `internal`. Default: `all`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `type` can also be `internal`. However, the `internal` value is not yet supported when a GitHub App calls this API with an installation access token.
+    pub type_: Option<crate::types::ReposListOrgType>,
+}
+
+impl ReposListForOrgParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::ReposListOrgSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::ReposListOrgType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_autolinks_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListAutolinksParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+}
+
+impl ReposListAutolinksParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_branches_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListBranchesParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Setting to `true` returns only protected branches. When set to `false`, only unprotected branches are returned. Omitting this parameter returns all branches.
+    pub protected: Option<bool>,
+}
+
+impl ReposListBranchesParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn protected(mut self, protected: bool) -> Self {
+        self.protected = Some(protected);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_collaborators_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListCollaboratorsParams {
+    /// Filter collaborators returned by their affiliation. Can be one of:  
+    /// \\* `outside`: All outside collaborators of an organization-owned repository.  
+    /// \\* `direct`: All collaborators with permissions to an organization-owned repository, regardless of organization membership status.  
+    /// \\* `all`: All collaborators the authenticated user can see.
+    pub affiliation: Option<crate::types::Affiliation>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListCollaboratorsParams {
+    pub fn affiliation(mut self, affiliation: crate::types::Affiliation) -> Self {
+        self.affiliation = Some(affiliation);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_commit_comments_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListCommitCommentsForRepoParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListCommitCommentsForRepoParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_commits_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListCommitsParams {
+    /// GitHub login or email address by which to filter by commit author.
+    pub author: Option<String>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Only commits containing this file path will be returned.
+    pub path: Option<String>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// SHA or branch to start listing commits from. Default: the repository’s default branch (usually `master`).
+    pub sha: Option<String>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only commits before this date will be returned. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub until: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ReposListCommitsParams {
+    pub fn author<T: ToString>(mut self, author: T) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn sha<T: ToString>(mut self, sha: T) -> Self {
+        self.sha = Some(sha.to_string());
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn until(mut self, until: chrono::DateTime<chrono::Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_comments_for_commit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListCommentsForCommitParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListCommentsForCommitParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_pull_requests_associated_with_commit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListPullRequestsAssociatedWithCommitParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListPullRequestsAssociatedWithCommitParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::get_commit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposGetCommitParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposGetCommitParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::get_combined_status_for_ref_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposGetCombinedStatusForRefParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposGetCombinedStatusForRefParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_commit_statuses_for_ref_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListCommitStatusesForRefParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListCommitStatusesForRefParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::compare_commits_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposCompareCommitsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposCompareCommitsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::get_content_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposGetContentParams {
+    /// The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+    pub ref_: Option<String>,
+}
+
+impl ReposGetContentParams {
+    pub fn ref_<T: ToString>(mut self, ref_: T) -> Self {
+        self.ref_ = Some(ref_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_contributors_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListContributorsParams {
+    /// Set to `1` or `true` to include anonymous contributors in results.
+    pub anon: Option<String>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListContributorsParams {
+    pub fn anon<T: ToString>(mut self, anon: T) -> Self {
+        self.anon = Some(anon.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_deployments_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListDeploymentsParams {
+    /// The name of the environment that was deployed to (e.g., `staging` or `production`).
+    pub environment: Option<String>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The name of the ref. This can be a branch, tag, or SHA.
+    pub ref_: Option<String>,
+    /// The SHA recorded at creation time.
+    pub sha: Option<String>,
+    /// The name of the task for the deployment (e.g., `deploy` or `deploy:migrations`).
+    pub task: Option<String>,
+}
+
+impl ReposListDeploymentsParams {
+    pub fn environment<T: ToString>(mut self, environment: T) -> Self {
+        self.environment = Some(environment.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn ref_<T: ToString>(mut self, ref_: T) -> Self {
+        self.ref_ = Some(ref_.to_string());
+        self
+    }
+
+    pub fn sha<T: ToString>(mut self, sha: T) -> Self {
+        self.sha = Some(sha.to_string());
+        self
+    }
+
+    pub fn task<T: ToString>(mut self, task: T) -> Self {
+        self.task = Some(task.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_deployment_statuses_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListDeploymentStatusesParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListDeploymentStatusesParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_forks_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListForksParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The sort order. Can be either `newest`, `oldest`, or `stargazers`.
+    pub sort: Option<crate::types::ReposListForksSort>,
+}
+
+impl ReposListForksParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::ReposListForksSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_webhooks_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListWebhooksParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListWebhooksParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_webhook_deliveries_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListWebhookDeliveriesParams {
+    /// Used for pagination: the starting delivery from which the page of deliveries is fetched. Refer to the `link` header for the next and previous page cursors.
+    pub cursor: Option<String>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListWebhookDeliveriesParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_invitations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListInvitationsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListInvitationsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_deploy_keys_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListDeployKeysParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListDeployKeysParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_pages_builds_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListPagesBuildsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListPagesBuildsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::get_readme_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposGetReadmeParams {
+    /// The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+    pub ref_: Option<String>,
+}
+
+impl ReposGetReadmeParams {
+    pub fn ref_<T: ToString>(mut self, ref_: T) -> Self {
+        self.ref_ = Some(ref_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Repos::get_readme_in_directory_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposGetReadmeInDirectoryParams {
+    /// The name of the commit/branch/tag. Default: the repository’s default branch (usually `master`).
+    pub ref_: Option<String>,
+}
+
+impl ReposGetReadmeInDirectoryParams {
+    pub fn ref_<T: ToString>(mut self, ref_: T) -> Self {
+        self.ref_ = Some(ref_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_releases_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListReleasesParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListReleasesParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_release_assets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListReleaseAssetsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListReleaseAssetsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::upload_release_asset_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposUploadReleaseAssetParams {
+    pub label: Option<String>,
+    pub name: Option<String>,
+}
+
+impl ReposUploadReleaseAssetParams {
+    pub fn label<T: ToString>(mut self, label: T) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    pub fn name<T: ToString>(mut self, name: T) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_tags_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListTagsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListTagsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_teams_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListTeamsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListTeamsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::get_all_topics_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposGetAllTopicsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposGetAllTopicsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::get_clones_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposGetClonesParams {
+    /// Must be one of: `day`, `week`.
+    pub per: Option<crate::types::Per>,
+}
+
+impl ReposGetClonesParams {
+    pub fn per(mut self, per: crate::types::Per) -> Self {
+        self.per = Some(per);
+        self
+    }
+}
+
+/// The query parameters of `Repos::get_views_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposGetViewsParams {
+    /// Must be one of: `day`, `week`.
+    pub per: Option<crate::types::Per>,
+}
+
+impl ReposGetViewsParams {
+    pub fn per(mut self, per: crate::types::Per) -> Self {
+        self.per = Some(per);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_public_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListPublicParams {
+    /// A repository ID. Only return repositories with an ID greater than this ID.
+    pub since: Option<i64>,
+}
+
+impl ReposListPublicParams {
+    pub fn since(mut self, since: i64) -> Self {
+        self.since = Some(since);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListForAuthenticatedUserParams {
+    /// Comma-separated list of values. Can include:  
+    /// \* `owner`: Repositories that are owned by the authenticated user.  
+    /// \* `collaborator`: Repositories that the user has been added to as a collaborator.  
+    /// \* `organization_member`: Repositories that the user has access to through being a member of an organization. This includes every repository on every team that the user is on.
+    pub affiliation: Option<String>,
+    /// Only show notifications updated before the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub before: Option<chrono::DateTime<chrono::Utc>>,
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Only show notifications updated after the given time. This is a timestamp in [ISO 8601](https://en.wikipedia.org/wiki/ISO_8601) format: `YYYY-MM-DDTHH:MM:SSZ`.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// Can be one of `created`, `updated`, `pushed`, `full_name`.
+    pub sort: Option<crate::types::ReposListSort>,
+    /// Can be one of `all`, `owner`, `public`, `private`, `member`. Note: For GitHub AE, can be one of `all`, `owner`, `internal`, `private`, `member`. Default: `all`  
+    ///
+    /// Will cause a `422` error if used in the same request as \*\*visibility\*\* or \*\*affiliation\*\*. Will cause a `422` error if used in the same request as \*\*visibility\*\* or \*\*affiliation\*\*.
+    pub type_: Option<crate::types::ReposListType>,
+    /// Can be one of `all`, `public`, or `private`. Note: For GitHub AE, can be one of `all`, `internal`, or `private`.
+    pub visibility: Option<crate::types::ReposListVisibility>,
+}
+
+impl ReposListForAuthenticatedUserParams {
+    pub fn affiliation<T: ToString>(mut self, affiliation: T) -> Self {
+        self.affiliation = Some(affiliation.to_string());
+        self
+    }
+
+    pub fn before(mut self, before: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before = Some(before);
+        self
+    }
+
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::ReposListSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::ReposListType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    pub fn visibility(mut self, visibility: crate::types::ReposListVisibility) -> Self {
+        self.visibility = Some(visibility);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_invitations_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListInvitationsForAuthenticatedUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl ReposListInvitationsForAuthenticatedUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Repos::list_for_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReposListForUserParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Can be one of `created`, `updated`, `pushed`, `full_name`.
+    pub sort: Option<crate::types::ReposListUserSort>,
+    /// Can be one of `all`, `owner`, `member`.
+    pub type_: Option<crate::types::ReposListUserType>,
+}
+
+impl ReposListForUserParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::ReposListUserSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::ReposListUserType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The functions of [`Repos`], for code that takes them as a trait, so that it can be
+/// tested with the `MockReposApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReposApi: Send + Sync {
+    /// See [`Repos::list_for_org_with`].
+    async fn list_for_org_with(
+        &self,
+        org: &str,
+        params: &ReposListForOrgParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Repos::list_all_for_org_with`].
+    async fn list_all_for_org_with(
+        &self,
+        org: &str,
+        params: &ReposListForOrgParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Repos::list_for_org_stream`].
+    fn list_for_org_stream(
+        &self,
+        org: &str,
+        params: &ReposListForOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository>;
+
+    /// See [`Repos::create_in_org`].
+    async fn create_in_org(
+        &self,
+        org: &str,
+        body: &crate::types::ReposCreateInOrgRequest,
+    ) -> Result<crate::types::Repository>;
+
+    /// See [`Repos::get`].
+    async fn get(&self, owner: &str, repo: &str) -> Result<crate::types::FullRepository>;
+
+    /// See [`Repos::delete`].
+    async fn delete(&self, owner: &str, repo: &str) -> Result<()>;
+
+    /// See [`Repos::update`].
+    async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposUpdateRequest,
+    ) -> Result<crate::types::FullRepository>;
+
+    /// See [`Repos::list_autolinks_with`].
+    async fn list_autolinks_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListAutolinksParams,
+    ) -> Result<Vec<crate::types::Autolink>>;
+
+    /// See [`Repos::list_all_autolinks`].
+    async fn list_all_autolinks(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::Autolink>>;
+
+    /// See [`Repos::list_autolinks_stream`].
+    fn list_autolinks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Autolink>;
+
+    /// See [`Repos::create_autolink`].
+    async fn create_autolink(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateAutolinkRequest,
+    ) -> Result<crate::types::Autolink>;
+
+    /// See [`Repos::get_autolink`].
+    async fn get_autolink(
+        &self,
+        owner: &str,
+        repo: &str,
+        autolink_id: i64,
+    ) -> Result<crate::types::Autolink>;
+
+    /// See [`Repos::delete_autolink`].
+    async fn delete_autolink(&self, owner: &str, repo: &str, autolink_id: i64) -> Result<()>;
+
+    /// See [`Repos::enable_automated_security_fixes`].
+    async fn enable_automated_security_fixes(&self, owner: &str, repo: &str) -> Result<()>;
+
+    /// See [`Repos::disable_automated_security_fixes`].
+    async fn disable_automated_security_fixes(&self, owner: &str, repo: &str) -> Result<()>;
+
+    /// See [`Repos::list_branches_with`].
+    async fn list_branches_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListBranchesParams,
+    ) -> Result<Vec<crate::types::ShortBranch>>;
+
+    /// See [`Repos::list_all_branches_with`].
+    async fn list_all_branches_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListBranchesParams,
+    ) -> Result<Vec<crate::types::ShortBranch>>;
+
+    /// See [`Repos::list_branches_stream`].
+    fn list_branches_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListBranchesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ShortBranch>;
+
+    /// See [`Repos::get_branch`].
+    async fn get_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::BranchWithProtection>;
+
+    /// See [`Repos::get_branch_protection`].
+    async fn get_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::BranchProtection>;
+
+    /// See [`Repos::update_branch_protection`].
+    async fn update_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposUpdateBranchProtectionRequest,
+    ) -> Result<crate::types::ProtectedBranch>;
+
+    /// See [`Repos::delete_branch_protection`].
+    async fn delete_branch_protection(&self, owner: &str, repo: &str, branch: &str) -> Result<()>;
+
+    /// See [`Repos::get_admin_branch_protection`].
+    async fn get_admin_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::ProtectedBranchAdminEnforced>;
+
+    /// See [`Repos::set_admin_branch_protection`].
+    async fn set_admin_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::ProtectedBranchAdminEnforced>;
+
+    /// See [`Repos::delete_admin_branch_protection`].
+    async fn delete_admin_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<()>;
+
+    /// See [`Repos::get_pull_request_review_protection`].
+    async fn get_pull_request_review_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::ProtectedBranchPullRequestReview>;
+
+    /// See [`Repos::delete_pull_request_review_protection`].
+    async fn delete_pull_request_review_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<()>;
+
+    /// See [`Repos::update_pull_request_review_protection`].
+    async fn update_pull_request_review_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposUpdatePullRequestReviewProtection,
+    ) -> Result<crate::types::ProtectedBranchPullRequestReview>;
+
+    /// See [`Repos::get_commit_signature_protection`].
+    async fn get_commit_signature_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::ProtectedBranchAdminEnforced>;
+
+    /// See [`Repos::create_commit_signature_protection`].
+    async fn create_commit_signature_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::ProtectedBranchAdminEnforced>;
+
+    /// See [`Repos::delete_commit_signature_protection`].
+    async fn delete_commit_signature_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<()>;
+
+    /// See [`Repos::get_status_checks_protection`].
+    async fn get_status_checks_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::StatusCheckPolicy>;
+
+    /// See [`Repos::remove_status_check_protection`].
+    async fn remove_status_check_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<()>;
+
+    /// See [`Repos::update_status_check_protection`].
+    async fn update_status_check_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposUpdateStatusCheckProtectionRequest,
+    ) -> Result<crate::types::StatusCheckPolicy>;
+
+    /// See [`Repos::get_all_status_check_contexts`].
+    async fn get_all_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<String>>;
+
+    /// See [`Repos::get_all_all_status_check_contexts`].
+    async fn get_all_all_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<String>>;
+
+    /// See [`Repos::get_all_status_check_contexts_stream`].
+    fn get_all_status_check_contexts_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<String>;
+
+    /// See [`Repos::set_status_check_contexts`].
+    async fn set_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposSetStatusCheckContextsRequestOneOf,
+    ) -> Result<Vec<String>>;
+
+    /// See [`Repos::add_status_check_contexts`].
+    async fn add_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
+    ) -> Result<Vec<String>>;
+
+    /// See [`Repos::remove_status_check_contexts`].
+    async fn remove_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposRemoveStatusCheckContextsRequestOneOf,
+    ) -> Result<Vec<String>>;
+
+    /// See [`Repos::get_access_restrictions`].
+    async fn get_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::BranchRestrictionPolicy>;
+
+    /// See [`Repos::delete_access_restrictions`].
+    async fn delete_access_restrictions(&self, owner: &str, repo: &str, branch: &str)
+        -> Result<()>;
+
+    /// See [`Repos::get_apps_with_access_to_protected_branch`].
+    async fn get_apps_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<crate::types::Integration>>;
+
+    /// See [`Repos::get_all_apps_with_access_to_protected_branch`].
+    async fn get_all_apps_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<crate::types::Integration>>;
+
+    /// See [`Repos::get_apps_with_access_to_protected_branch_stream`].
+    fn get_apps_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Integration>;
+
+    /// See [`Repos::set_app_access_restrictions`].
+    async fn set_app_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposSetAppAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Integration>>;
+
+    /// See [`Repos::add_app_access_restrictions`].
+    async fn add_app_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Integration>>;
+
+    /// See [`Repos::remove_app_access_restrictions`].
+    async fn remove_app_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposRemoveAppAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Integration>>;
+
+    /// See [`Repos::get_teams_with_access_to_protected_branch`].
+    async fn get_teams_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Repos::get_all_teams_with_access_to_protected_branch`].
+    async fn get_all_teams_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Repos::get_teams_with_access_to_protected_branch_stream`].
+    fn get_teams_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team>;
+
+    /// See [`Repos::set_team_access_restrictions`].
+    async fn set_team_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposSetTeamAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Repos::add_team_access_restrictions`].
+    async fn add_team_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Repos::remove_team_access_restrictions`].
+    async fn remove_team_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposRemoveTeamAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Repos::get_users_with_access_to_protected_branch`].
+    async fn get_users_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Repos::get_all_users_with_access_to_protected_branch`].
+    async fn get_all_users_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Repos::get_users_with_access_to_protected_branch_stream`].
+    fn get_users_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Repos::set_user_access_restrictions`].
+    async fn set_user_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposSetUserAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Repos::add_user_access_restrictions`].
+    async fn add_user_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Repos::remove_user_access_restrictions`].
+    async fn remove_user_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposRemoveUserAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Repos::rename_branch`].
+    async fn rename_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposRenameBranchRequest,
+    ) -> Result<crate::types::BranchWithProtection>;
+
+    /// See [`Repos::list_collaborators_with`].
+    async fn list_collaborators_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCollaboratorsParams,
+    ) -> Result<Vec<crate::types::Collaborator>>;
+
+    /// See [`Repos::list_all_collaborators_with`].
+    async fn list_all_collaborators_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCollaboratorsParams,
+    ) -> Result<Vec<crate::types::Collaborator>>;
+
+    /// See [`Repos::list_collaborators_stream`].
+    fn list_collaborators_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCollaboratorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Collaborator>;
+
+    /// See [`Repos::check_collaborator`].
+    async fn check_collaborator(&self, owner: &str, repo: &str, username: &str) -> Result<()>;
+
+    /// See [`Repos::add_collaborator`].
+    async fn add_collaborator(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+        body: &crate::types::ReposAddCollaboratorRequest,
+    ) -> Result<crate::types::RepositoryInvitation>;
+
+    /// See [`Repos::remove_collaborator`].
+    async fn remove_collaborator(&self, owner: &str, repo: &str, username: &str) -> Result<()>;
+
+    /// See [`Repos::get_collaborator_permission_level`].
+    async fn get_collaborator_permission_level(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> Result<crate::types::RepositoryCollaboratorPermission>;
+
+    /// See [`Repos::list_commit_comments_for_repo_with`].
+    async fn list_commit_comments_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCommitCommentsForRepoParams,
+    ) -> Result<Vec<crate::types::CommitComment>>;
+
+    /// See [`Repos::list_all_commit_comments_for_repo`].
+    async fn list_all_commit_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::CommitComment>>;
+
+    /// See [`Repos::list_commit_comments_for_repo_stream`].
+    fn list_commit_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitComment>;
+
+    /// See [`Repos::get_commit_comment`].
+    async fn get_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> Result<crate::types::CommitComment>;
+
+    /// See [`Repos::delete_commit_comment`].
+    async fn delete_commit_comment(&self, owner: &str, repo: &str, comment_id: i64) -> Result<()>;
+
+    /// See [`Repos::update_commit_comment`].
+    async fn update_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReposUpdateCommitCommentRequest,
+    ) -> Result<crate::types::CommitComment>;
+
+    /// See [`Repos::list_commits_with`].
+    async fn list_commits_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCommitsParams,
+    ) -> Result<Vec<crate::types::CommitData>>;
+
+    /// See [`Repos::list_all_commits_with`].
+    async fn list_all_commits_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCommitsParams,
+    ) -> Result<Vec<crate::types::CommitData>>;
+
+    /// See [`Repos::list_commits_stream`].
+    fn list_commits_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCommitsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitData>;
+
+    /// See [`Repos::list_branches_for_head_commit`].
+    async fn list_branches_for_head_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> Result<Vec<crate::types::BranchShort>>;
+
+    /// See [`Repos::list_all_branches_for_head_commit`].
+    async fn list_all_branches_for_head_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> Result<Vec<crate::types::BranchShort>>;
+
+    /// See [`Repos::list_branches_for_head_commit_stream`].
+    fn list_branches_for_head_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BranchShort>;
+
+    /// See [`Repos::list_comments_for_commit_with`].
+    async fn list_comments_for_commit_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        params: &ReposListCommentsForCommitParams,
+    ) -> Result<Vec<crate::types::CommitComment>>;
+
+    /// See [`Repos::list_all_comments_for_commit`].
+    async fn list_all_comments_for_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> Result<Vec<crate::types::CommitComment>>;
+
+    /// See [`Repos::list_comments_for_commit_stream`].
+    fn list_comments_for_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitComment>;
+
+    /// See [`Repos::create_commit_comment`].
+    async fn create_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        body: &crate::types::ReposCreateCommitCommentRequest,
+    ) -> Result<crate::types::CommitComment>;
+
+    /// See [`Repos::list_pull_requests_associated_with_commit_with`].
+    async fn list_pull_requests_associated_with_commit_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        params: &ReposListPullRequestsAssociatedWithCommitParams,
+    ) -> Result<Vec<crate::types::PullRequestSimple>>;
+
+    /// See [`Repos::list_all_pull_requests_associated_with_commit`].
+    async fn list_all_pull_requests_associated_with_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> Result<Vec<crate::types::PullRequestSimple>>;
+
+    /// See [`Repos::list_pull_requests_associated_with_commit_stream`].
+    fn list_pull_requests_associated_with_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestSimple>;
+
+    /// See [`Repos::get_commit_with`].
+    async fn get_commit_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        params: &ReposGetCommitParams,
+    ) -> Result<crate::types::CommitData>;
+
+    /// See [`Repos::get_combined_status_for_ref_with`].
+    async fn get_combined_status_for_ref_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        params: &ReposGetCombinedStatusForRefParams,
+    ) -> Result<crate::types::CombinedCommitStatus>;
+
+    /// See [`Repos::list_commit_statuses_for_ref_with`].
+    async fn list_commit_statuses_for_ref_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        params: &ReposListCommitStatusesForRefParams,
+    ) -> Result<Vec<crate::types::Status>>;
+
+    /// See [`Repos::list_all_commit_statuses_for_ref`].
+    async fn list_all_commit_statuses_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<Vec<crate::types::Status>>;
+
+    /// See [`Repos::list_commit_statuses_for_ref_stream`].
+    fn list_commit_statuses_for_ref_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Status>;
+
+    /// See [`Repos::get_community_profile_metrics`].
+    async fn get_community_profile_metrics(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::CommunityProfile>;
+
+    /// See [`Repos::compare_commits_with`].
+    async fn compare_commits_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        basehead: &str,
+        params: &ReposCompareCommitsParams,
+    ) -> Result<crate::types::CommitComparison>;
+
+    /// See [`Repos::get_content_vec_directory_with`].
+    async fn get_content_vec_directory_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        params: &ReposGetContentParams,
+    ) -> Result<Vec<crate::types::ContentDirectory>>;
+
+    /// See [`Repos::get_content_file_with`].
+    async fn get_content_file_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        params: &ReposGetContentParams,
+    ) -> Result<crate::types::ContentFile>;
+
+    /// See [`Repos::get_content_symlink_with`].
+    async fn get_content_symlink_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        params: &ReposGetContentParams,
+    ) -> Result<crate::types::ContentSymlink>;
+
+    /// See [`Repos::get_content_submodule_with`].
+    async fn get_content_submodule_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        params: &ReposGetContentParams,
+    ) -> Result<crate::types::ContentSubmodule>;
+
+    /// See [`Repos::get_content_with`].
+    async fn get_content_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        params: &ReposGetContentParams,
+    ) -> Result<crate::types::ReposGetContentResponseOneOf>;
+
+    /// See [`Repos::create_or_update_file_contents`].
+    async fn create_or_update_file_contents(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        body: &crate::types::ReposCreateUpdateFileContentsRequest,
+    ) -> Result<crate::types::FileCommitData>;
+
+    /// See [`Repos::delete_file`].
+    async fn delete_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        body: &crate::types::ReposDeleteFileRequest,
+    ) -> Result<crate::types::FileCommitData>;
+
+    /// See [`Repos::list_contributors_with`].
+    async fn list_contributors_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListContributorsParams,
+    ) -> Result<Vec<crate::types::Contributor>>;
+
+    /// See [`Repos::list_all_contributors_with`].
+    async fn list_all_contributors_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListContributorsParams,
+    ) -> Result<Vec<crate::types::Contributor>>;
+
+    /// See [`Repos::list_contributors_stream`].
+    fn list_contributors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListContributorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Contributor>;
+
+    /// See [`Repos::list_deployments_with`].
+    async fn list_deployments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListDeploymentsParams,
+    ) -> Result<Vec<crate::types::Deployment>>;
+
+    /// See [`Repos::list_all_deployments_with`].
+    async fn list_all_deployments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListDeploymentsParams,
+    ) -> Result<Vec<crate::types::Deployment>>;
+
+    /// See [`Repos::list_deployments_stream`].
+    fn list_deployments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListDeploymentsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Deployment>;
+
+    /// See [`Repos::create_deployment`].
+    async fn create_deployment(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateDeploymentRequest,
+    ) -> Result<crate::types::Deployment>;
+
+    /// See [`Repos::get_deployment`].
+    async fn get_deployment(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> Result<crate::types::Deployment>;
+
+    /// See [`Repos::delete_deployment`].
+    async fn delete_deployment(&self, owner: &str, repo: &str, deployment_id: i64) -> Result<()>;
+
+    /// See [`Repos::list_deployment_statuses_with`].
+    async fn list_deployment_statuses_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        params: &ReposListDeploymentStatusesParams,
+    ) -> Result<Vec<crate::types::DeploymentStatus>>;
+
+    /// See [`Repos::list_all_deployment_statuses`].
+    async fn list_all_deployment_statuses(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> Result<Vec<crate::types::DeploymentStatus>>;
+
+    /// See [`Repos::list_deployment_statuses_stream`].
+    fn list_deployment_statuses_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DeploymentStatus>;
+
+    /// See [`Repos::create_deployment_status`].
+    async fn create_deployment_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        body: &crate::types::ReposCreateDeploymentStatusRequest,
+    ) -> Result<crate::types::DeploymentStatus>;
+
+    /// See [`Repos::get_deployment_status`].
+    async fn get_deployment_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        status_id: i64,
+    ) -> Result<crate::types::DeploymentStatus>;
+
+    /// See [`Repos::create_dispatch_event`].
+    async fn create_dispatch_event(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateDispatchEventRequest,
+    ) -> Result<()>;
+
+    /// See [`Repos::get_all_environments`].
+    async fn get_all_environments(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::ReposGetAllEnvironmentsResponse>;
+
+    /// See [`Repos::get_environment`].
+    async fn get_environment(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+    ) -> Result<crate::types::Environment>;
+
+    /// See [`Repos::create_or_update_environment`].
+    async fn create_or_update_environment(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+        body: &crate::types::ReposCreateUpdateEnvironmentRequest,
+    ) -> Result<crate::types::Environment>;
+
+    /// See [`Repos::delete_an_environment`].
+    async fn delete_an_environment(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+    ) -> Result<()>;
+
+    /// See [`Repos::list_forks_with`].
+    async fn list_forks_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListForksParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Repos::list_all_forks_with`].
+    async fn list_all_forks_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListForksParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Repos::list_forks_stream`].
+    fn list_forks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListForksParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository>;
+
+    /// See [`Repos::create_fork`].
+    async fn create_fork(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateForkRequest,
+    ) -> Result<crate::types::FullRepository>;
+
+    /// See [`Repos::list_webhooks_with`].
+    async fn list_webhooks_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListWebhooksParams,
+    ) -> Result<Vec<crate::types::Hook>>;
+
+    /// See [`Repos::list_all_webhooks`].
+    async fn list_all_webhooks(&self, owner: &str, repo: &str) -> Result<Vec<crate::types::Hook>>;
+
+    /// See [`Repos::list_webhooks_stream`].
+    fn list_webhooks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Hook>;
+
+    /// See [`Repos::create_webhook`].
+    async fn create_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateWebhookRequest,
+    ) -> Result<crate::types::Hook>;
+
+    /// See [`Repos::get_webhook`].
+    async fn get_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> Result<crate::types::Hook>;
+
+    /// See [`Repos::delete_webhook`].
+    async fn delete_webhook(&self, owner: &str, repo: &str, hook_id: i64) -> Result<()>;
+
+    /// See [`Repos::update_webhook`].
+    async fn update_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        body: &crate::types::ReposUpdateWebhookRequest,
+    ) -> Result<crate::types::Hook>;
+
+    /// See [`Repos::get_webhook_config_for_repo`].
+    async fn get_webhook_config_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> Result<crate::types::WebhookConfig>;
+
+    /// See [`Repos::update_webhook_config_for_repo`].
+    async fn update_webhook_config_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        body: &crate::types::ReposUpdateWebhookConfigRepoRequest,
+    ) -> Result<crate::types::WebhookConfig>;
+
+    /// See [`Repos::list_webhook_deliveries_with`].
+    async fn list_webhook_deliveries_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        params: &ReposListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>>;
+
+    /// See [`Repos::list_all_webhook_deliveries_with`].
+    async fn list_all_webhook_deliveries_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        params: &ReposListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>>;
+
+    /// See [`Repos::list_webhook_deliveries_stream`].
+    fn list_webhook_deliveries_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        params: &ReposListWebhookDeliveriesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::HookDeliveryItem>;
+
+    /// See [`Repos::get_webhook_delivery`].
+    async fn get_webhook_delivery(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> Result<crate::types::HookDelivery>;
+
+    /// See [`Repos::redeliver_webhook_delivery`].
+    async fn redeliver_webhook_delivery(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> Result<crate::types::AcceptedResponse>;
+
+    /// See [`Repos::ping_webhook`].
+    async fn ping_webhook(&self, owner: &str, repo: &str, hook_id: i64) -> Result<()>;
+
+    /// See [`Repos::test_push_webhook`].
+    async fn test_push_webhook(&self, owner: &str, repo: &str, hook_id: i64) -> Result<()>;
+
+    /// See [`Repos::list_invitations_with`].
+    async fn list_invitations_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListInvitationsParams,
+    ) -> Result<Vec<crate::types::RepositoryInvitation>>;
+
+    /// See [`Repos::list_all_invitations`].
+    async fn list_all_invitations(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::RepositoryInvitation>>;
+
+    /// See [`Repos::list_invitations_stream`].
+    fn list_invitations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RepositoryInvitation>;
+
+    /// See [`Repos::delete_invitation`].
+    async fn delete_invitation(&self, owner: &str, repo: &str, invitation_id: i64) -> Result<()>;
+
+    /// See [`Repos::update_invitation`].
+    async fn update_invitation(
+        &self,
+        owner: &str,
+        repo: &str,
+        invitation_id: i64,
+        body: &crate::types::ReposUpdateInvitationRequest,
+    ) -> Result<crate::types::RepositoryInvitation>;
+
+    /// See [`Repos::list_deploy_keys_with`].
+    async fn list_deploy_keys_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListDeployKeysParams,
+    ) -> Result<Vec<crate::types::DeployKey>>;
+
+    /// See [`Repos::list_all_deploy_keys`].
+    async fn list_all_deploy_keys(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::DeployKey>>;
+
+    /// See [`Repos::list_deploy_keys_stream`].
+    fn list_deploy_keys_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DeployKey>;
+
+    /// See [`Repos::create_deploy_key`].
+    async fn create_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateDeployKeyRequest,
+    ) -> Result<crate::types::DeployKey>;
+
+    /// See [`Repos::get_deploy_key`].
+    async fn get_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+        key_id: i64,
+    ) -> Result<crate::types::DeployKey>;
+
+    /// See [`Repos::delete_deploy_key`].
+    async fn delete_deploy_key(&self, owner: &str, repo: &str, key_id: i64) -> Result<()>;
+
+    /// See [`Repos::list_languages`].
+    async fn list_languages(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<std::collections::BTreeMap<String, i64>>;
+
+    /// See [`Repos::merge`].
+    async fn merge(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposMergeRequest,
+    ) -> Result<crate::types::CommitData>;
+
+    /// See [`Repos::get_pages`].
+    async fn get_pages(&self, owner: &str, repo: &str) -> Result<crate::types::Page>;
+
+    /// See [`Repos::update_information_about_pages_site`].
+    async fn update_information_about_pages_site(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposUpdateInformationAboutPagesSiteRequest,
+    ) -> Result<()>;
+
+    /// See [`Repos::create_pages_site`].
+    async fn create_pages_site(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreatePagesSiteRequest,
+    ) -> Result<crate::types::Page>;
+
+    /// See [`Repos::delete_pages_site`].
+    async fn delete_pages_site(&self, owner: &str, repo: &str) -> Result<()>;
+
+    /// See [`Repos::list_pages_builds_with`].
+    async fn list_pages_builds_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListPagesBuildsParams,
+    ) -> Result<Vec<crate::types::PageBuild>>;
+
+    /// See [`Repos::list_all_pages_builds`].
+    async fn list_all_pages_builds(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::PageBuild>>;
+
+    /// See [`Repos::list_pages_builds_stream`].
+    fn list_pages_builds_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PageBuild>;
+
+    /// See [`Repos::request_pages_build`].
+    async fn request_pages_build(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::PageBuildStatus>;
+
+    /// See [`Repos::get_latest_pages_build`].
+    async fn get_latest_pages_build(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::PageBuild>;
+
+    /// See [`Repos::get_pages_build`].
+    async fn get_pages_build(
+        &self,
+        owner: &str,
+        repo: &str,
+        build_id: i64,
+    ) -> Result<crate::types::PageBuild>;
+
+    /// See [`Repos::get_pages_health_check`].
+    async fn get_pages_health_check(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::PagesHealthCheck>;
+
+    /// See [`Repos::get_readme_with`].
+    async fn get_readme_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposGetReadmeParams,
+    ) -> Result<crate::types::ContentFile>;
+
+    /// See [`Repos::get_readme_in_directory_with`].
+    async fn get_readme_in_directory_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        dir: &str,
+        params: &ReposGetReadmeInDirectoryParams,
+    ) -> Result<crate::types::ContentFile>;
+
+    /// See [`Repos::list_releases_with`].
+    async fn list_releases_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListReleasesParams,
+    ) -> Result<Vec<crate::types::Release>>;
+
+    /// See [`Repos::list_all_releases`].
+    async fn list_all_releases(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::Release>>;
+
+    /// See [`Repos::list_releases_stream`].
+    fn list_releases_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Release>;
+
+    /// See [`Repos::create_release`].
+    async fn create_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateReleaseRequest,
+    ) -> Result<crate::types::Release>;
+
+    /// See [`Repos::get_release_asset`].
+    async fn get_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: i64,
+    ) -> Result<crate::types::ReleaseAsset>;
+
+    /// See [`Repos::delete_release_asset`].
+    async fn delete_release_asset(&self, owner: &str, repo: &str, asset_id: i64) -> Result<()>;
+
+    /// See [`Repos::update_release_asset`].
+    async fn update_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: i64,
+        body: &crate::types::ReposUpdateReleaseAssetRequest,
+    ) -> Result<crate::types::ReleaseAsset>;
+
+    /// See [`Repos::get_latest_release`].
+    async fn get_latest_release(&self, owner: &str, repo: &str) -> Result<crate::types::Release>;
+
+    /// See [`Repos::get_release_by_tag`].
+    async fn get_release_by_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+    ) -> Result<crate::types::Release>;
+
+    /// See [`Repos::get_release`].
+    async fn get_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> Result<crate::types::Release>;
+
+    /// See [`Repos::delete_release`].
+    async fn delete_release(&self, owner: &str, repo: &str, release_id: i64) -> Result<()>;
+
+    /// See [`Repos::update_release`].
+    async fn update_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        body: &crate::types::ReposUpdateReleaseRequest,
+    ) -> Result<crate::types::Release>;
+
+    /// See [`Repos::list_release_assets_with`].
+    async fn list_release_assets_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        params: &ReposListReleaseAssetsParams,
+    ) -> Result<Vec<crate::types::ReleaseAsset>>;
+
+    /// See [`Repos::list_all_release_assets`].
+    async fn list_all_release_assets(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> Result<Vec<crate::types::ReleaseAsset>>;
+
+    /// See [`Repos::list_release_assets_stream`].
+    fn list_release_assets_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReleaseAsset>;
+
+    /// See [`Repos::upload_release_asset_with`].
+    async fn upload_release_asset_with<T>(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        params: &ReposUploadReleaseAssetParams,
+        body: T,
+    ) -> Result<crate::types::ReleaseAsset>
+    where
+        T: Into<reqwest::Body> + Send + 'static;
+
+    /// See [`Repos::get_code_frequency_stats`].
+    async fn get_code_frequency_stats(&self, owner: &str, repo: &str) -> Result<Vec<Vec<i64>>>;
+
+    /// See [`Repos::get_all_code_frequency_stats`].
+    async fn get_all_code_frequency_stats(&self, owner: &str, repo: &str) -> Result<Vec<Vec<i64>>>;
+
+    /// See [`Repos::get_code_frequency_stats_stream`].
+    fn get_code_frequency_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<i64>;
+
+    /// See [`Repos::get_commit_activity_stats`].
+    async fn get_commit_activity_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::CommitActivity>>;
+
+    /// See [`Repos::get_all_commit_activity_stats`].
+    async fn get_all_commit_activity_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::CommitActivity>>;
+
+    /// See [`Repos::get_commit_activity_stats_stream`].
+    fn get_commit_activity_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitActivity>;
+
+    /// See [`Repos::get_contributors_stats`].
+    async fn get_contributors_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::ContributorActivity>>;
+
+    /// See [`Repos::get_all_contributors_stats`].
+    async fn get_all_contributors_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::ContributorActivity>>;
+
+    /// See [`Repos::get_contributors_stats_stream`].
+    fn get_contributors_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ContributorActivity>;
+
+    /// See [`Repos::get_participation_stats`].
+    async fn get_participation_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::ParticipationStats>;
+
+    /// See [`Repos::get_punch_card_stats`].
+    async fn get_punch_card_stats(&self, owner: &str, repo: &str) -> Result<Vec<Vec<i64>>>;
+
+    /// See [`Repos::get_all_punch_card_stats`].
+    async fn get_all_punch_card_stats(&self, owner: &str, repo: &str) -> Result<Vec<Vec<i64>>>;
+
+    /// See [`Repos::get_punch_card_stats_stream`].
+    fn get_punch_card_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<i64>;
+
+    /// See [`Repos::create_commit_status`].
+    async fn create_commit_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        body: &crate::types::ReposCreateCommitStatusRequest,
+    ) -> Result<crate::types::Status>;
+
+    /// See [`Repos::list_tags_with`].
+    async fn list_tags_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListTagsParams,
+    ) -> Result<Vec<crate::types::Tag>>;
+
+    /// See [`Repos::list_all_tags`].
+    async fn list_all_tags(&self, owner: &str, repo: &str) -> Result<Vec<crate::types::Tag>>;
+
+    /// See [`Repos::list_tags_stream`].
+    fn list_tags_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Tag>;
+
+    /// See [`Repos::download_tarball_archive`].
+    async fn download_tarball_archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<crate::Download>;
+
+    /// See [`Repos::download_tarball_archive_stream`].
+    async fn download_tarball_archive_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`Repos::list_teams_with`].
+    async fn list_teams_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListTeamsParams,
+    ) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Repos::list_all_teams`].
+    async fn list_all_teams(&self, owner: &str, repo: &str) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Repos::list_teams_stream`].
+    fn list_teams_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team>;
+
+    /// See [`Repos::get_all_topics_with`].
+    async fn get_all_topics_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposGetAllTopicsParams,
+    ) -> Result<crate::types::Topic>;
+
+    /// See [`Repos::replace_all_topics`].
+    async fn replace_all_topics(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposReplaceAllTopicsRequest,
+    ) -> Result<crate::types::Topic>;
+
+    /// See [`Repos::get_clones_with`].
+    async fn get_clones_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposGetClonesParams,
+    ) -> Result<crate::types::CloneTraffic>;
+
+    /// See [`Repos::get_top_paths`].
+    async fn get_top_paths(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::ContentTraffic>>;
+
+    /// See [`Repos::get_all_top_paths`].
+    async fn get_all_top_paths(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::ContentTraffic>>;
+
+    /// See [`Repos::get_top_paths_stream`].
+    fn get_top_paths_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ContentTraffic>;
+
+    /// See [`Repos::get_top_referrers`].
+    async fn get_top_referrers(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::ReferrerTraffic>>;
+
+    /// See [`Repos::get_all_top_referrers`].
+    async fn get_all_top_referrers(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::ReferrerTraffic>>;
+
+    /// See [`Repos::get_top_referrers_stream`].
+    fn get_top_referrers_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReferrerTraffic>;
+
+    /// See [`Repos::get_views_with`].
+    async fn get_views_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposGetViewsParams,
+    ) -> Result<crate::types::ViewTraffic>;
+
+    /// See [`Repos::transfer`].
+    async fn transfer(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposTransferRequest,
+    ) -> Result<crate::types::MinimalRepository>;
+
+    /// See [`Repos::check_vulnerability_alerts`].
+    async fn check_vulnerability_alerts(&self, owner: &str, repo: &str) -> Result<()>;
+
+    /// See [`Repos::enable_vulnerability_alerts`].
+    async fn enable_vulnerability_alerts(&self, owner: &str, repo: &str) -> Result<()>;
+
+    /// See [`Repos::disable_vulnerability_alerts`].
+    async fn disable_vulnerability_alerts(&self, owner: &str, repo: &str) -> Result<()>;
+
+    /// See [`Repos::download_zipball_archive`].
+    async fn download_zipball_archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<crate::Download>;
+
+    /// See [`Repos::download_zipball_archive_stream`].
+    async fn download_zipball_archive_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`Repos::create_using_template`].
+    async fn create_using_template(
+        &self,
+        template_owner: &str,
+        template_repo: &str,
+        body: &crate::types::ReposCreateUsingTemplateRequest,
+    ) -> Result<crate::types::Repository>;
+
+    /// See [`Repos::list_public_with`].
+    async fn list_public_with(
+        &self,
+        params: &ReposListPublicParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Repos::list_all_public_with`].
+    async fn list_all_public_with(
+        &self,
+        params: &ReposListPublicParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Repos::list_public_stream`].
+    fn list_public_stream(
+        &self,
+        params: &ReposListPublicParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository>;
+
+    /// See [`Repos::list_for_authenticated_user_with`].
+    async fn list_for_authenticated_user_with(
+        &self,
+        params: &ReposListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Repository>>;
+
+    /// See [`Repos::list_all_for_authenticated_user_with`].
+    async fn list_all_for_authenticated_user_with(
+        &self,
+        params: &ReposListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Repository>>;
+
+    /// See [`Repos::list_for_authenticated_user_stream`].
+    fn list_for_authenticated_user_stream(
+        &self,
+        params: &ReposListForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Repository>;
+
+    /// See [`Repos::create_for_authenticated_user`].
+    async fn create_for_authenticated_user(
+        &self,
+        body: &crate::types::ReposCreateRequest,
+    ) -> Result<crate::types::Repository>;
+
+    /// See [`Repos::list_invitations_for_authenticated_user_with`].
+    async fn list_invitations_for_authenticated_user_with(
+        &self,
+        params: &ReposListInvitationsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::RepositoryInvitation>>;
+
+    /// See [`Repos::list_all_invitations_for_authenticated_user`].
+    async fn list_all_invitations_for_authenticated_user(
+        &self,
+    ) -> Result<Vec<crate::types::RepositoryInvitation>>;
+
+    /// See [`Repos::list_invitations_for_authenticated_user_stream`].
+    fn list_invitations_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RepositoryInvitation>;
+
+    /// See [`Repos::decline_invitation`].
+    async fn decline_invitation(&self, invitation_id: i64) -> Result<()>;
+
+    /// See [`Repos::accept_invitation`].
+    async fn accept_invitation(&self, invitation_id: i64) -> Result<()>;
+
+    /// See [`Repos::list_for_user_with`].
+    async fn list_for_user_with(
+        &self,
+        username: &str,
+        params: &ReposListForUserParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Repos::list_all_for_user_with`].
+    async fn list_all_for_user_with(
+        &self,
+        username: &str,
+        params: &ReposListForUserParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Repos::list_for_user_stream`].
+    fn list_for_user_stream(
+        &self,
+        username: &str,
+        params: &ReposListForUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository>;
+}
+
+#[async_trait::async_trait]
+impl ReposApi for Repos {
+    async fn list_for_org_with(
+        &self,
+        org: &str,
+        params: &ReposListForOrgParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Repos::list_for_org_with(self, org, params).await
+    }
+
+    async fn list_all_for_org_with(
+        &self,
+        org: &str,
+        params: &ReposListForOrgParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Repos::list_all_for_org_with(self, org, params).await
+    }
+
+    fn list_for_org_stream(
+        &self,
+        org: &str,
+        params: &ReposListForOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        Repos::list_for_org_stream(self, org, params, start, max_items)
+    }
+
+    async fn create_in_org(
+        &self,
+        org: &str,
+        body: &crate::types::ReposCreateInOrgRequest,
+    ) -> Result<crate::types::Repository> {
+        Repos::create_in_org(self, org, body).await
+    }
+
+    async fn get(&self, owner: &str, repo: &str) -> Result<crate::types::FullRepository> {
+        Repos::get(self, owner, repo).await
+    }
+
+    async fn delete(&self, owner: &str, repo: &str) -> Result<()> {
+        Repos::delete(self, owner, repo).await
+    }
+
+    async fn update(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposUpdateRequest,
+    ) -> Result<crate::types::FullRepository> {
+        Repos::update(self, owner, repo, body).await
+    }
+
+    async fn list_autolinks_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListAutolinksParams,
+    ) -> Result<Vec<crate::types::Autolink>> {
+        Repos::list_autolinks_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_autolinks(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::Autolink>> {
+        Repos::list_all_autolinks(self, owner, repo).await
+    }
+
+    fn list_autolinks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Autolink> {
+        Repos::list_autolinks_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn create_autolink(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateAutolinkRequest,
+    ) -> Result<crate::types::Autolink> {
+        Repos::create_autolink(self, owner, repo, body).await
+    }
+
+    async fn get_autolink(
+        &self,
+        owner: &str,
+        repo: &str,
+        autolink_id: i64,
+    ) -> Result<crate::types::Autolink> {
+        Repos::get_autolink(self, owner, repo, autolink_id).await
+    }
+
+    async fn delete_autolink(&self, owner: &str, repo: &str, autolink_id: i64) -> Result<()> {
+        Repos::delete_autolink(self, owner, repo, autolink_id).await
+    }
+
+    async fn enable_automated_security_fixes(&self, owner: &str, repo: &str) -> Result<()> {
+        Repos::enable_automated_security_fixes(self, owner, repo).await
+    }
+
+    async fn disable_automated_security_fixes(&self, owner: &str, repo: &str) -> Result<()> {
+        Repos::disable_automated_security_fixes(self, owner, repo).await
+    }
+
+    async fn list_branches_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListBranchesParams,
+    ) -> Result<Vec<crate::types::ShortBranch>> {
+        Repos::list_branches_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_branches_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListBranchesParams,
+    ) -> Result<Vec<crate::types::ShortBranch>> {
+        Repos::list_all_branches_with(self, owner, repo, params).await
+    }
+
+    fn list_branches_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListBranchesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ShortBranch> {
+        Repos::list_branches_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn get_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::BranchWithProtection> {
+        Repos::get_branch(self, owner, repo, branch).await
+    }
+
+    async fn get_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::BranchProtection> {
+        Repos::get_branch_protection(self, owner, repo, branch).await
+    }
+
+    async fn update_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposUpdateBranchProtectionRequest,
+    ) -> Result<crate::types::ProtectedBranch> {
+        Repos::update_branch_protection(self, owner, repo, branch, body).await
+    }
+
+    async fn delete_branch_protection(&self, owner: &str, repo: &str, branch: &str) -> Result<()> {
+        Repos::delete_branch_protection(self, owner, repo, branch).await
+    }
+
+    async fn get_admin_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::ProtectedBranchAdminEnforced> {
+        Repos::get_admin_branch_protection(self, owner, repo, branch).await
+    }
+
+    async fn set_admin_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::ProtectedBranchAdminEnforced> {
+        Repos::set_admin_branch_protection(self, owner, repo, branch).await
+    }
+
+    async fn delete_admin_branch_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<()> {
+        Repos::delete_admin_branch_protection(self, owner, repo, branch).await
+    }
+
+    async fn get_pull_request_review_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::ProtectedBranchPullRequestReview> {
+        Repos::get_pull_request_review_protection(self, owner, repo, branch).await
+    }
+
+    async fn delete_pull_request_review_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<()> {
+        Repos::delete_pull_request_review_protection(self, owner, repo, branch).await
+    }
+
+    async fn update_pull_request_review_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposUpdatePullRequestReviewProtection,
+    ) -> Result<crate::types::ProtectedBranchPullRequestReview> {
+        Repos::update_pull_request_review_protection(self, owner, repo, branch, body).await
+    }
+
+    async fn get_commit_signature_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::ProtectedBranchAdminEnforced> {
+        Repos::get_commit_signature_protection(self, owner, repo, branch).await
+    }
+
+    async fn create_commit_signature_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::ProtectedBranchAdminEnforced> {
+        Repos::create_commit_signature_protection(self, owner, repo, branch).await
+    }
+
+    async fn delete_commit_signature_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<()> {
+        Repos::delete_commit_signature_protection(self, owner, repo, branch).await
+    }
+
+    async fn get_status_checks_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::StatusCheckPolicy> {
+        Repos::get_status_checks_protection(self, owner, repo, branch).await
+    }
+
+    async fn remove_status_check_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<()> {
+        Repos::remove_status_check_protection(self, owner, repo, branch).await
+    }
+
+    async fn update_status_check_protection(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposUpdateStatusCheckProtectionRequest,
+    ) -> Result<crate::types::StatusCheckPolicy> {
+        Repos::update_status_check_protection(self, owner, repo, branch, body).await
+    }
+
+    async fn get_all_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<String>> {
+        Repos::get_all_status_check_contexts(self, owner, repo, branch).await
+    }
+
+    async fn get_all_all_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<String>> {
+        Repos::get_all_all_status_check_contexts(self, owner, repo, branch).await
+    }
+
+    fn get_all_status_check_contexts_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<String> {
+        Repos::get_all_status_check_contexts_stream(self, owner, repo, branch, start, max_items)
+    }
+
+    async fn set_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposSetStatusCheckContextsRequestOneOf,
+    ) -> Result<Vec<String>> {
+        Repos::set_status_check_contexts(self, owner, repo, branch, body).await
+    }
+
+    async fn add_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddStatusCheckContextsRequestOneOf,
+    ) -> Result<Vec<String>> {
+        Repos::add_status_check_contexts(self, owner, repo, branch, body).await
+    }
+
+    async fn remove_status_check_contexts(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposRemoveStatusCheckContextsRequestOneOf,
+    ) -> Result<Vec<String>> {
+        Repos::remove_status_check_contexts(self, owner, repo, branch, body).await
+    }
+
+    async fn get_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<crate::types::BranchRestrictionPolicy> {
+        Repos::get_access_restrictions(self, owner, repo, branch).await
+    }
+
+    async fn delete_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<()> {
+        Repos::delete_access_restrictions(self, owner, repo, branch).await
+    }
+
+    async fn get_apps_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<crate::types::Integration>> {
+        Repos::get_apps_with_access_to_protected_branch(self, owner, repo, branch).await
+    }
+
+    async fn get_all_apps_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<crate::types::Integration>> {
+        Repos::get_all_apps_with_access_to_protected_branch(self, owner, repo, branch).await
+    }
+
+    fn get_apps_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Integration> {
+        Repos::get_apps_with_access_to_protected_branch_stream(
+            self, owner, repo, branch, start, max_items,
+        )
+    }
+
+    async fn set_app_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposSetAppAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Integration>> {
+        Repos::set_app_access_restrictions(self, owner, repo, branch, body).await
+    }
+
+    async fn add_app_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddAppAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Integration>> {
+        Repos::add_app_access_restrictions(self, owner, repo, branch, body).await
+    }
+
+    async fn remove_app_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposRemoveAppAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Integration>> {
+        Repos::remove_app_access_restrictions(self, owner, repo, branch, body).await
+    }
+
+    async fn get_teams_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<crate::types::Team>> {
+        Repos::get_teams_with_access_to_protected_branch(self, owner, repo, branch).await
+    }
+
+    async fn get_all_teams_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<crate::types::Team>> {
+        Repos::get_all_teams_with_access_to_protected_branch(self, owner, repo, branch).await
+    }
+
+    fn get_teams_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team> {
+        Repos::get_teams_with_access_to_protected_branch_stream(
+            self, owner, repo, branch, start, max_items,
+        )
+    }
+
+    async fn set_team_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposSetTeamAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Team>> {
+        Repos::set_team_access_restrictions(self, owner, repo, branch, body).await
+    }
+
+    async fn add_team_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddTeamAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Team>> {
+        Repos::add_team_access_restrictions(self, owner, repo, branch, body).await
+    }
+
+    async fn remove_team_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposRemoveTeamAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::Team>> {
+        Repos::remove_team_access_restrictions(self, owner, repo, branch, body).await
+    }
+
+    async fn get_users_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Repos::get_users_with_access_to_protected_branch(self, owner, repo, branch).await
+    }
+
+    async fn get_all_users_with_access_to_protected_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Repos::get_all_users_with_access_to_protected_branch(self, owner, repo, branch).await
+    }
+
+    fn get_users_with_access_to_protected_branch_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Repos::get_users_with_access_to_protected_branch_stream(
+            self, owner, repo, branch, start, max_items,
+        )
+    }
+
+    async fn set_user_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposSetUserAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Repos::set_user_access_restrictions(self, owner, repo, branch, body).await
+    }
+
+    async fn add_user_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposAddUserAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Repos::add_user_access_restrictions(self, owner, repo, branch, body).await
+    }
+
+    async fn remove_user_access_restrictions(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposRemoveUserAccessRestrictionsRequestOneOf,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Repos::remove_user_access_restrictions(self, owner, repo, branch, body).await
+    }
+
+    async fn rename_branch(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+        body: &crate::types::ReposRenameBranchRequest,
+    ) -> Result<crate::types::BranchWithProtection> {
+        Repos::rename_branch(self, owner, repo, branch, body).await
+    }
+
+    async fn list_collaborators_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCollaboratorsParams,
+    ) -> Result<Vec<crate::types::Collaborator>> {
+        Repos::list_collaborators_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_collaborators_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCollaboratorsParams,
+    ) -> Result<Vec<crate::types::Collaborator>> {
+        Repos::list_all_collaborators_with(self, owner, repo, params).await
+    }
+
+    fn list_collaborators_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCollaboratorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Collaborator> {
+        Repos::list_collaborators_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn check_collaborator(&self, owner: &str, repo: &str, username: &str) -> Result<()> {
+        Repos::check_collaborator(self, owner, repo, username).await
+    }
+
+    async fn add_collaborator(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+        body: &crate::types::ReposAddCollaboratorRequest,
+    ) -> Result<crate::types::RepositoryInvitation> {
+        Repos::add_collaborator(self, owner, repo, username, body).await
+    }
+
+    async fn remove_collaborator(&self, owner: &str, repo: &str, username: &str) -> Result<()> {
+        Repos::remove_collaborator(self, owner, repo, username).await
+    }
+
+    async fn get_collaborator_permission_level(
+        &self,
+        owner: &str,
+        repo: &str,
+        username: &str,
+    ) -> Result<crate::types::RepositoryCollaboratorPermission> {
+        Repos::get_collaborator_permission_level(self, owner, repo, username).await
+    }
+
+    async fn list_commit_comments_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCommitCommentsForRepoParams,
+    ) -> Result<Vec<crate::types::CommitComment>> {
+        Repos::list_commit_comments_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_commit_comments_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::CommitComment>> {
+        Repos::list_all_commit_comments_for_repo(self, owner, repo).await
+    }
+
+    fn list_commit_comments_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitComment> {
+        Repos::list_commit_comments_for_repo_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn get_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+    ) -> Result<crate::types::CommitComment> {
+        Repos::get_commit_comment(self, owner, repo, comment_id).await
+    }
+
+    async fn delete_commit_comment(&self, owner: &str, repo: &str, comment_id: i64) -> Result<()> {
+        Repos::delete_commit_comment(self, owner, repo, comment_id).await
+    }
+
+    async fn update_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        comment_id: i64,
+        body: &crate::types::ReposUpdateCommitCommentRequest,
+    ) -> Result<crate::types::CommitComment> {
+        Repos::update_commit_comment(self, owner, repo, comment_id, body).await
+    }
+
+    async fn list_commits_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCommitsParams,
+    ) -> Result<Vec<crate::types::CommitData>> {
+        Repos::list_commits_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_commits_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCommitsParams,
+    ) -> Result<Vec<crate::types::CommitData>> {
+        Repos::list_all_commits_with(self, owner, repo, params).await
+    }
+
+    fn list_commits_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListCommitsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitData> {
+        Repos::list_commits_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn list_branches_for_head_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> Result<Vec<crate::types::BranchShort>> {
+        Repos::list_branches_for_head_commit(self, owner, repo, commit_sha).await
+    }
+
+    async fn list_all_branches_for_head_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> Result<Vec<crate::types::BranchShort>> {
+        Repos::list_all_branches_for_head_commit(self, owner, repo, commit_sha).await
+    }
+
+    fn list_branches_for_head_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BranchShort> {
+        Repos::list_branches_for_head_commit_stream(self, owner, repo, commit_sha, start, max_items)
+    }
+
+    async fn list_comments_for_commit_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        params: &ReposListCommentsForCommitParams,
+    ) -> Result<Vec<crate::types::CommitComment>> {
+        Repos::list_comments_for_commit_with(self, owner, repo, commit_sha, params).await
+    }
+
+    async fn list_all_comments_for_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> Result<Vec<crate::types::CommitComment>> {
+        Repos::list_all_comments_for_commit(self, owner, repo, commit_sha).await
+    }
+
+    fn list_comments_for_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitComment> {
+        Repos::list_comments_for_commit_stream(self, owner, repo, commit_sha, start, max_items)
+    }
+
+    async fn create_commit_comment(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        body: &crate::types::ReposCreateCommitCommentRequest,
+    ) -> Result<crate::types::CommitComment> {
+        Repos::create_commit_comment(self, owner, repo, commit_sha, body).await
+    }
+
+    async fn list_pull_requests_associated_with_commit_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        params: &ReposListPullRequestsAssociatedWithCommitParams,
+    ) -> Result<Vec<crate::types::PullRequestSimple>> {
+        Repos::list_pull_requests_associated_with_commit_with(self, owner, repo, commit_sha, params)
+            .await
+    }
+
+    async fn list_all_pull_requests_associated_with_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+    ) -> Result<Vec<crate::types::PullRequestSimple>> {
+        Repos::list_all_pull_requests_associated_with_commit(self, owner, repo, commit_sha).await
+    }
+
+    fn list_pull_requests_associated_with_commit_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit_sha: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PullRequestSimple> {
+        Repos::list_pull_requests_associated_with_commit_stream(
+            self, owner, repo, commit_sha, start, max_items,
+        )
+    }
+
+    async fn get_commit_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        params: &ReposGetCommitParams,
+    ) -> Result<crate::types::CommitData> {
+        Repos::get_commit_with(self, owner, repo, ref_, params).await
+    }
+
+    async fn get_combined_status_for_ref_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        params: &ReposGetCombinedStatusForRefParams,
+    ) -> Result<crate::types::CombinedCommitStatus> {
+        Repos::get_combined_status_for_ref_with(self, owner, repo, ref_, params).await
+    }
+
+    async fn list_commit_statuses_for_ref_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        params: &ReposListCommitStatusesForRefParams,
+    ) -> Result<Vec<crate::types::Status>> {
+        Repos::list_commit_statuses_for_ref_with(self, owner, repo, ref_, params).await
+    }
+
+    async fn list_all_commit_statuses_for_ref(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<Vec<crate::types::Status>> {
+        Repos::list_all_commit_statuses_for_ref(self, owner, repo, ref_).await
+    }
+
+    fn list_commit_statuses_for_ref_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Status> {
+        Repos::list_commit_statuses_for_ref_stream(self, owner, repo, ref_, start, max_items)
+    }
+
+    async fn get_community_profile_metrics(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::CommunityProfile> {
+        Repos::get_community_profile_metrics(self, owner, repo).await
+    }
+
+    async fn compare_commits_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        basehead: &str,
+        params: &ReposCompareCommitsParams,
+    ) -> Result<crate::types::CommitComparison> {
+        Repos::compare_commits_with(self, owner, repo, basehead, params).await
+    }
+
+    async fn get_content_vec_directory_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        params: &ReposGetContentParams,
+    ) -> Result<Vec<crate::types::ContentDirectory>> {
+        Repos::get_content_vec_directory_with(self, owner, repo, path, params).await
+    }
+
+    async fn get_content_file_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        params: &ReposGetContentParams,
+    ) -> Result<crate::types::ContentFile> {
+        Repos::get_content_file_with(self, owner, repo, path, params).await
+    }
+
+    async fn get_content_symlink_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        params: &ReposGetContentParams,
+    ) -> Result<crate::types::ContentSymlink> {
+        Repos::get_content_symlink_with(self, owner, repo, path, params).await
+    }
+
+    async fn get_content_submodule_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        params: &ReposGetContentParams,
+    ) -> Result<crate::types::ContentSubmodule> {
+        Repos::get_content_submodule_with(self, owner, repo, path, params).await
+    }
+
+    async fn get_content_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        params: &ReposGetContentParams,
+    ) -> Result<crate::types::ReposGetContentResponseOneOf> {
+        Repos::get_content_with(self, owner, repo, path, params).await
+    }
+
+    async fn create_or_update_file_contents(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        body: &crate::types::ReposCreateUpdateFileContentsRequest,
+    ) -> Result<crate::types::FileCommitData> {
+        Repos::create_or_update_file_contents(self, owner, repo, path, body).await
+    }
+
+    async fn delete_file(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        body: &crate::types::ReposDeleteFileRequest,
+    ) -> Result<crate::types::FileCommitData> {
+        Repos::delete_file(self, owner, repo, path, body).await
+    }
+
+    async fn list_contributors_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListContributorsParams,
+    ) -> Result<Vec<crate::types::Contributor>> {
+        Repos::list_contributors_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_contributors_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListContributorsParams,
+    ) -> Result<Vec<crate::types::Contributor>> {
+        Repos::list_all_contributors_with(self, owner, repo, params).await
+    }
+
+    fn list_contributors_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListContributorsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Contributor> {
+        Repos::list_contributors_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn list_deployments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListDeploymentsParams,
+    ) -> Result<Vec<crate::types::Deployment>> {
+        Repos::list_deployments_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_deployments_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListDeploymentsParams,
+    ) -> Result<Vec<crate::types::Deployment>> {
+        Repos::list_all_deployments_with(self, owner, repo, params).await
+    }
+
+    fn list_deployments_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListDeploymentsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Deployment> {
+        Repos::list_deployments_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn create_deployment(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateDeploymentRequest,
+    ) -> Result<crate::types::Deployment> {
+        Repos::create_deployment(self, owner, repo, body).await
+    }
+
+    async fn get_deployment(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> Result<crate::types::Deployment> {
+        Repos::get_deployment(self, owner, repo, deployment_id).await
+    }
+
+    async fn delete_deployment(&self, owner: &str, repo: &str, deployment_id: i64) -> Result<()> {
+        Repos::delete_deployment(self, owner, repo, deployment_id).await
+    }
+
+    async fn list_deployment_statuses_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        params: &ReposListDeploymentStatusesParams,
+    ) -> Result<Vec<crate::types::DeploymentStatus>> {
+        Repos::list_deployment_statuses_with(self, owner, repo, deployment_id, params).await
+    }
+
+    async fn list_all_deployment_statuses(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+    ) -> Result<Vec<crate::types::DeploymentStatus>> {
+        Repos::list_all_deployment_statuses(self, owner, repo, deployment_id).await
+    }
+
+    fn list_deployment_statuses_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DeploymentStatus> {
+        Repos::list_deployment_statuses_stream(self, owner, repo, deployment_id, start, max_items)
+    }
+
+    async fn create_deployment_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        body: &crate::types::ReposCreateDeploymentStatusRequest,
+    ) -> Result<crate::types::DeploymentStatus> {
+        Repos::create_deployment_status(self, owner, repo, deployment_id, body).await
+    }
+
+    async fn get_deployment_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        deployment_id: i64,
+        status_id: i64,
+    ) -> Result<crate::types::DeploymentStatus> {
+        Repos::get_deployment_status(self, owner, repo, deployment_id, status_id).await
+    }
+
+    async fn create_dispatch_event(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateDispatchEventRequest,
+    ) -> Result<()> {
+        Repos::create_dispatch_event(self, owner, repo, body).await
+    }
+
+    async fn get_all_environments(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::ReposGetAllEnvironmentsResponse> {
+        Repos::get_all_environments(self, owner, repo).await
+    }
+
+    async fn get_environment(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+    ) -> Result<crate::types::Environment> {
+        Repos::get_environment(self, owner, repo, environment_name).await
+    }
+
+    async fn create_or_update_environment(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+        body: &crate::types::ReposCreateUpdateEnvironmentRequest,
+    ) -> Result<crate::types::Environment> {
+        Repos::create_or_update_environment(self, owner, repo, environment_name, body).await
+    }
+
+    async fn delete_an_environment(
+        &self,
+        owner: &str,
+        repo: &str,
+        environment_name: &str,
+    ) -> Result<()> {
+        Repos::delete_an_environment(self, owner, repo, environment_name).await
+    }
+
+    async fn list_forks_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListForksParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Repos::list_forks_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_forks_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListForksParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Repos::list_all_forks_with(self, owner, repo, params).await
+    }
+
+    fn list_forks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListForksParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        Repos::list_forks_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn create_fork(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateForkRequest,
+    ) -> Result<crate::types::FullRepository> {
+        Repos::create_fork(self, owner, repo, body).await
+    }
+
+    async fn list_webhooks_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListWebhooksParams,
+    ) -> Result<Vec<crate::types::Hook>> {
+        Repos::list_webhooks_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_webhooks(&self, owner: &str, repo: &str) -> Result<Vec<crate::types::Hook>> {
+        Repos::list_all_webhooks(self, owner, repo).await
+    }
+
+    fn list_webhooks_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Hook> {
+        Repos::list_webhooks_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn create_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateWebhookRequest,
+    ) -> Result<crate::types::Hook> {
+        Repos::create_webhook(self, owner, repo, body).await
+    }
+
+    async fn get_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> Result<crate::types::Hook> {
+        Repos::get_webhook(self, owner, repo, hook_id).await
+    }
+
+    async fn delete_webhook(&self, owner: &str, repo: &str, hook_id: i64) -> Result<()> {
+        Repos::delete_webhook(self, owner, repo, hook_id).await
+    }
+
+    async fn update_webhook(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        body: &crate::types::ReposUpdateWebhookRequest,
+    ) -> Result<crate::types::Hook> {
+        Repos::update_webhook(self, owner, repo, hook_id, body).await
+    }
+
+    async fn get_webhook_config_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+    ) -> Result<crate::types::WebhookConfig> {
+        Repos::get_webhook_config_for_repo(self, owner, repo, hook_id).await
+    }
+
+    async fn update_webhook_config_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        body: &crate::types::ReposUpdateWebhookConfigRepoRequest,
+    ) -> Result<crate::types::WebhookConfig> {
+        Repos::update_webhook_config_for_repo(self, owner, repo, hook_id, body).await
+    }
+
+    async fn list_webhook_deliveries_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        params: &ReposListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        Repos::list_webhook_deliveries_with(self, owner, repo, hook_id, params).await
+    }
+
+    async fn list_all_webhook_deliveries_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        params: &ReposListWebhookDeliveriesParams,
+    ) -> Result<Vec<crate::types::HookDeliveryItem>> {
+        Repos::list_all_webhook_deliveries_with(self, owner, repo, hook_id, params).await
+    }
+
+    fn list_webhook_deliveries_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        params: &ReposListWebhookDeliveriesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::HookDeliveryItem> {
+        Repos::list_webhook_deliveries_stream(self, owner, repo, hook_id, params, start, max_items)
+    }
+
+    async fn get_webhook_delivery(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> Result<crate::types::HookDelivery> {
+        Repos::get_webhook_delivery(self, owner, repo, hook_id, delivery_id).await
+    }
+
+    async fn redeliver_webhook_delivery(
+        &self,
+        owner: &str,
+        repo: &str,
+        hook_id: i64,
+        delivery_id: i64,
+    ) -> Result<crate::types::AcceptedResponse> {
+        Repos::redeliver_webhook_delivery(self, owner, repo, hook_id, delivery_id).await
+    }
+
+    async fn ping_webhook(&self, owner: &str, repo: &str, hook_id: i64) -> Result<()> {
+        Repos::ping_webhook(self, owner, repo, hook_id).await
+    }
+
+    async fn test_push_webhook(&self, owner: &str, repo: &str, hook_id: i64) -> Result<()> {
+        Repos::test_push_webhook(self, owner, repo, hook_id).await
+    }
+
+    async fn list_invitations_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListInvitationsParams,
+    ) -> Result<Vec<crate::types::RepositoryInvitation>> {
+        Repos::list_invitations_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_invitations(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::RepositoryInvitation>> {
+        Repos::list_all_invitations(self, owner, repo).await
+    }
+
+    fn list_invitations_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RepositoryInvitation> {
+        Repos::list_invitations_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn delete_invitation(&self, owner: &str, repo: &str, invitation_id: i64) -> Result<()> {
+        Repos::delete_invitation(self, owner, repo, invitation_id).await
+    }
+
+    async fn update_invitation(
+        &self,
+        owner: &str,
+        repo: &str,
+        invitation_id: i64,
+        body: &crate::types::ReposUpdateInvitationRequest,
+    ) -> Result<crate::types::RepositoryInvitation> {
+        Repos::update_invitation(self, owner, repo, invitation_id, body).await
+    }
+
+    async fn list_deploy_keys_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListDeployKeysParams,
+    ) -> Result<Vec<crate::types::DeployKey>> {
+        Repos::list_deploy_keys_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_deploy_keys(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::DeployKey>> {
+        Repos::list_all_deploy_keys(self, owner, repo).await
+    }
+
+    fn list_deploy_keys_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DeployKey> {
+        Repos::list_deploy_keys_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn create_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateDeployKeyRequest,
+    ) -> Result<crate::types::DeployKey> {
+        Repos::create_deploy_key(self, owner, repo, body).await
+    }
+
+    async fn get_deploy_key(
+        &self,
+        owner: &str,
+        repo: &str,
+        key_id: i64,
+    ) -> Result<crate::types::DeployKey> {
+        Repos::get_deploy_key(self, owner, repo, key_id).await
+    }
+
+    async fn delete_deploy_key(&self, owner: &str, repo: &str, key_id: i64) -> Result<()> {
+        Repos::delete_deploy_key(self, owner, repo, key_id).await
+    }
+
+    async fn list_languages(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<std::collections::BTreeMap<String, i64>> {
+        Repos::list_languages(self, owner, repo).await
+    }
+
+    async fn merge(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposMergeRequest,
+    ) -> Result<crate::types::CommitData> {
+        Repos::merge(self, owner, repo, body).await
+    }
+
+    async fn get_pages(&self, owner: &str, repo: &str) -> Result<crate::types::Page> {
+        Repos::get_pages(self, owner, repo).await
+    }
+
+    async fn update_information_about_pages_site(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposUpdateInformationAboutPagesSiteRequest,
+    ) -> Result<()> {
+        Repos::update_information_about_pages_site(self, owner, repo, body).await
+    }
+
+    async fn create_pages_site(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreatePagesSiteRequest,
+    ) -> Result<crate::types::Page> {
+        Repos::create_pages_site(self, owner, repo, body).await
+    }
+
+    async fn delete_pages_site(&self, owner: &str, repo: &str) -> Result<()> {
+        Repos::delete_pages_site(self, owner, repo).await
+    }
+
+    async fn list_pages_builds_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListPagesBuildsParams,
+    ) -> Result<Vec<crate::types::PageBuild>> {
+        Repos::list_pages_builds_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_pages_builds(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::PageBuild>> {
+        Repos::list_all_pages_builds(self, owner, repo).await
+    }
+
+    fn list_pages_builds_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PageBuild> {
+        Repos::list_pages_builds_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn request_pages_build(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::PageBuildStatus> {
+        Repos::request_pages_build(self, owner, repo).await
+    }
+
+    async fn get_latest_pages_build(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::PageBuild> {
+        Repos::get_latest_pages_build(self, owner, repo).await
+    }
+
+    async fn get_pages_build(
+        &self,
+        owner: &str,
+        repo: &str,
+        build_id: i64,
+    ) -> Result<crate::types::PageBuild> {
+        Repos::get_pages_build(self, owner, repo, build_id).await
+    }
+
+    async fn get_pages_health_check(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::PagesHealthCheck> {
+        Repos::get_pages_health_check(self, owner, repo).await
+    }
+
+    async fn get_readme_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposGetReadmeParams,
+    ) -> Result<crate::types::ContentFile> {
+        Repos::get_readme_with(self, owner, repo, params).await
+    }
+
+    async fn get_readme_in_directory_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        dir: &str,
+        params: &ReposGetReadmeInDirectoryParams,
+    ) -> Result<crate::types::ContentFile> {
+        Repos::get_readme_in_directory_with(self, owner, repo, dir, params).await
+    }
+
+    async fn list_releases_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListReleasesParams,
+    ) -> Result<Vec<crate::types::Release>> {
+        Repos::list_releases_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_releases(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::Release>> {
+        Repos::list_all_releases(self, owner, repo).await
+    }
+
+    fn list_releases_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Release> {
+        Repos::list_releases_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn create_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposCreateReleaseRequest,
+    ) -> Result<crate::types::Release> {
+        Repos::create_release(self, owner, repo, body).await
+    }
+
+    async fn get_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: i64,
+    ) -> Result<crate::types::ReleaseAsset> {
+        Repos::get_release_asset(self, owner, repo, asset_id).await
+    }
+
+    async fn delete_release_asset(&self, owner: &str, repo: &str, asset_id: i64) -> Result<()> {
+        Repos::delete_release_asset(self, owner, repo, asset_id).await
+    }
+
+    async fn update_release_asset(
+        &self,
+        owner: &str,
+        repo: &str,
+        asset_id: i64,
+        body: &crate::types::ReposUpdateReleaseAssetRequest,
+    ) -> Result<crate::types::ReleaseAsset> {
+        Repos::update_release_asset(self, owner, repo, asset_id, body).await
+    }
+
+    async fn get_latest_release(&self, owner: &str, repo: &str) -> Result<crate::types::Release> {
+        Repos::get_latest_release(self, owner, repo).await
+    }
+
+    async fn get_release_by_tag(
+        &self,
+        owner: &str,
+        repo: &str,
+        tag: &str,
+    ) -> Result<crate::types::Release> {
+        Repos::get_release_by_tag(self, owner, repo, tag).await
+    }
+
+    async fn get_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> Result<crate::types::Release> {
+        Repos::get_release(self, owner, repo, release_id).await
+    }
+
+    async fn delete_release(&self, owner: &str, repo: &str, release_id: i64) -> Result<()> {
+        Repos::delete_release(self, owner, repo, release_id).await
+    }
+
+    async fn update_release(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        body: &crate::types::ReposUpdateReleaseRequest,
+    ) -> Result<crate::types::Release> {
+        Repos::update_release(self, owner, repo, release_id, body).await
+    }
+
+    async fn list_release_assets_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        params: &ReposListReleaseAssetsParams,
+    ) -> Result<Vec<crate::types::ReleaseAsset>> {
+        Repos::list_release_assets_with(self, owner, repo, release_id, params).await
+    }
+
+    async fn list_all_release_assets(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+    ) -> Result<Vec<crate::types::ReleaseAsset>> {
+        Repos::list_all_release_assets(self, owner, repo, release_id).await
+    }
+
+    fn list_release_assets_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReleaseAsset> {
+        Repos::list_release_assets_stream(self, owner, repo, release_id, start, max_items)
+    }
+
+    async fn upload_release_asset_with<T>(
+        &self,
+        owner: &str,
+        repo: &str,
+        release_id: i64,
+        params: &ReposUploadReleaseAssetParams,
+        body: T,
+    ) -> Result<crate::types::ReleaseAsset>
+    where
+        T: Into<reqwest::Body> + Send + 'static,
+    {
+        Repos::upload_release_asset_with(self, owner, repo, release_id, params, body).await
+    }
+
+    async fn get_code_frequency_stats(&self, owner: &str, repo: &str) -> Result<Vec<Vec<i64>>> {
+        Repos::get_code_frequency_stats(self, owner, repo).await
+    }
+
+    async fn get_all_code_frequency_stats(&self, owner: &str, repo: &str) -> Result<Vec<Vec<i64>>> {
+        Repos::get_all_code_frequency_stats(self, owner, repo).await
+    }
+
+    fn get_code_frequency_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<i64> {
+        Repos::get_code_frequency_stats_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn get_commit_activity_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::CommitActivity>> {
+        Repos::get_commit_activity_stats(self, owner, repo).await
+    }
+
+    async fn get_all_commit_activity_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::CommitActivity>> {
+        Repos::get_all_commit_activity_stats(self, owner, repo).await
+    }
+
+    fn get_commit_activity_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommitActivity> {
+        Repos::get_commit_activity_stats_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn get_contributors_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::ContributorActivity>> {
+        Repos::get_contributors_stats(self, owner, repo).await
+    }
+
+    async fn get_all_contributors_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::ContributorActivity>> {
+        Repos::get_all_contributors_stats(self, owner, repo).await
+    }
+
+    fn get_contributors_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ContributorActivity> {
+        Repos::get_contributors_stats_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn get_participation_stats(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::ParticipationStats> {
+        Repos::get_participation_stats(self, owner, repo).await
+    }
+
+    async fn get_punch_card_stats(&self, owner: &str, repo: &str) -> Result<Vec<Vec<i64>>> {
+        Repos::get_punch_card_stats(self, owner, repo).await
+    }
+
+    async fn get_all_punch_card_stats(&self, owner: &str, repo: &str) -> Result<Vec<Vec<i64>>> {
+        Repos::get_all_punch_card_stats(self, owner, repo).await
+    }
+
+    fn get_punch_card_stats_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<i64> {
+        Repos::get_punch_card_stats_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn create_commit_status(
+        &self,
+        owner: &str,
+        repo: &str,
+        sha: &str,
+        body: &crate::types::ReposCreateCommitStatusRequest,
+    ) -> Result<crate::types::Status> {
+        Repos::create_commit_status(self, owner, repo, sha, body).await
+    }
+
+    async fn list_tags_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListTagsParams,
+    ) -> Result<Vec<crate::types::Tag>> {
+        Repos::list_tags_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_tags(&self, owner: &str, repo: &str) -> Result<Vec<crate::types::Tag>> {
+        Repos::list_all_tags(self, owner, repo).await
+    }
+
+    fn list_tags_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Tag> {
+        Repos::list_tags_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn download_tarball_archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<crate::Download> {
+        Repos::download_tarball_archive(self, owner, repo, ref_).await
+    }
+
+    async fn download_tarball_archive_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<crate::DownloadStream> {
+        Repos::download_tarball_archive_stream(self, owner, repo, ref_).await
+    }
+
+    async fn list_teams_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposListTeamsParams,
+    ) -> Result<Vec<crate::types::Team>> {
+        Repos::list_teams_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_teams(&self, owner: &str, repo: &str) -> Result<Vec<crate::types::Team>> {
+        Repos::list_all_teams(self, owner, repo).await
+    }
+
+    fn list_teams_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team> {
+        Repos::list_teams_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn get_all_topics_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposGetAllTopicsParams,
+    ) -> Result<crate::types::Topic> {
+        Repos::get_all_topics_with(self, owner, repo, params).await
+    }
+
+    async fn replace_all_topics(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposReplaceAllTopicsRequest,
+    ) -> Result<crate::types::Topic> {
+        Repos::replace_all_topics(self, owner, repo, body).await
+    }
+
+    async fn get_clones_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposGetClonesParams,
+    ) -> Result<crate::types::CloneTraffic> {
+        Repos::get_clones_with(self, owner, repo, params).await
+    }
+
+    async fn get_top_paths(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::ContentTraffic>> {
+        Repos::get_top_paths(self, owner, repo).await
+    }
+
+    async fn get_all_top_paths(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::ContentTraffic>> {
+        Repos::get_all_top_paths(self, owner, repo).await
+    }
+
+    fn get_top_paths_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ContentTraffic> {
+        Repos::get_top_paths_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn get_top_referrers(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::ReferrerTraffic>> {
+        Repos::get_top_referrers(self, owner, repo).await
+    }
+
+    async fn get_all_top_referrers(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<crate::types::ReferrerTraffic>> {
+        Repos::get_all_top_referrers(self, owner, repo).await
+    }
+
+    fn get_top_referrers_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReferrerTraffic> {
+        Repos::get_top_referrers_stream(self, owner, repo, start, max_items)
+    }
+
+    async fn get_views_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &ReposGetViewsParams,
+    ) -> Result<crate::types::ViewTraffic> {
+        Repos::get_views_with(self, owner, repo, params).await
+    }
+
+    async fn transfer(
+        &self,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::ReposTransferRequest,
+    ) -> Result<crate::types::MinimalRepository> {
+        Repos::transfer(self, owner, repo, body).await
+    }
+
+    async fn check_vulnerability_alerts(&self, owner: &str, repo: &str) -> Result<()> {
+        Repos::check_vulnerability_alerts(self, owner, repo).await
+    }
+
+    async fn enable_vulnerability_alerts(&self, owner: &str, repo: &str) -> Result<()> {
+        Repos::enable_vulnerability_alerts(self, owner, repo).await
+    }
+
+    async fn disable_vulnerability_alerts(&self, owner: &str, repo: &str) -> Result<()> {
+        Repos::disable_vulnerability_alerts(self, owner, repo).await
+    }
+
+    async fn download_zipball_archive(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<crate::Download> {
+        Repos::download_zipball_archive(self, owner, repo, ref_).await
+    }
+
+    async fn download_zipball_archive_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        ref_: &str,
+    ) -> Result<crate::DownloadStream> {
+        Repos::download_zipball_archive_stream(self, owner, repo, ref_).await
+    }
+
+    async fn create_using_template(
+        &self,
+        template_owner: &str,
+        template_repo: &str,
+        body: &crate::types::ReposCreateUsingTemplateRequest,
+    ) -> Result<crate::types::Repository> {
+        Repos::create_using_template(self, template_owner, template_repo, body).await
+    }
+
+    async fn list_public_with(
+        &self,
+        params: &ReposListPublicParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Repos::list_public_with(self, params).await
+    }
+
+    async fn list_all_public_with(
+        &self,
+        params: &ReposListPublicParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Repos::list_all_public_with(self, params).await
+    }
+
+    fn list_public_stream(
+        &self,
+        params: &ReposListPublicParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        Repos::list_public_stream(self, params, start, max_items)
+    }
+
+    async fn list_for_authenticated_user_with(
+        &self,
+        params: &ReposListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Repository>> {
+        Repos::list_for_authenticated_user_with(self, params).await
+    }
+
+    async fn list_all_for_authenticated_user_with(
+        &self,
+        params: &ReposListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::Repository>> {
+        Repos::list_all_for_authenticated_user_with(self, params).await
+    }
+
+    fn list_for_authenticated_user_stream(
+        &self,
+        params: &ReposListForAuthenticatedUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Repository> {
+        Repos::list_for_authenticated_user_stream(self, params, start, max_items)
+    }
+
+    async fn create_for_authenticated_user(
+        &self,
+        body: &crate::types::ReposCreateRequest,
+    ) -> Result<crate::types::Repository> {
+        Repos::create_for_authenticated_user(self, body).await
+    }
+
+    async fn list_invitations_for_authenticated_user_with(
+        &self,
+        params: &ReposListInvitationsForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::RepositoryInvitation>> {
+        Repos::list_invitations_for_authenticated_user_with(self, params).await
+    }
+
+    async fn list_all_invitations_for_authenticated_user(
+        &self,
+    ) -> Result<Vec<crate::types::RepositoryInvitation>> {
+        Repos::list_all_invitations_for_authenticated_user(self).await
+    }
+
+    fn list_invitations_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RepositoryInvitation> {
+        Repos::list_invitations_for_authenticated_user_stream(self, start, max_items)
+    }
+
+    async fn decline_invitation(&self, invitation_id: i64) -> Result<()> {
+        Repos::decline_invitation(self, invitation_id).await
+    }
+
+    async fn accept_invitation(&self, invitation_id: i64) -> Result<()> {
+        Repos::accept_invitation(self, invitation_id).await
+    }
+
+    async fn list_for_user_with(
+        &self,
+        username: &str,
+        params: &ReposListForUserParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Repos::list_for_user_with(self, username, params).await
+    }
+
+    async fn list_all_for_user_with(
+        &self,
+        username: &str,
+        params: &ReposListForUserParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Repos::list_all_for_user_with(self, username, params).await
+    }
+
+    fn list_for_user_stream(
+        &self,
+        username: &str,
+        params: &ReposListForUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        Repos::list_for_user_stream(self, username, params, start, max_items)
     }
 }
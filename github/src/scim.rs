@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Scim {
     pub client: Client,
@@ -39,31 +37,21 @@ impl Scim {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `start_index: i64` -- Used for pagination: the index of the first result to return.
-     * * `count: i64` -- Used for pagination: the number of results to return.
-     * * `filter: &str` -- Filters results using the equals query parameter operator (`eq`). You can filter results that are equal to `id`, `userName`, `emails`, and `external_id`. For example, to search for an identity with the `userName` Octocat, you would use this query:
-     *   
-     *   `?filter=userName%20eq%20\"Octocat\"`.
-     *   
-     *   To filter results for the identity with the email `octocat@github.com`, you would use this query:
-     *   
-     *   `?filter=emails%20eq%20\"octocat@github.com\"`.
+     * * `params: &ScimListProvisionedIdentitiesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_provisioned_identities(
+    pub async fn list_provisioned_identities_with(
         &self,
         org: &str,
-        start_index: i64,
-        count: i64,
-        filter: &str,
+        params: &ScimListProvisionedIdentitiesParams,
     ) -> Result<crate::types::ScimUserList> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if start_index > 0 {
+        if let Some(start_index) = &params.start_index {
             query_args.push(("startIndex".to_string(), start_index.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -76,6 +64,25 @@ impl Scim {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_provisioned_identities_with` and pass the query parameters as a `ScimListProvisionedIdentitiesParams` instead.
+     */
+    #[deprecated(note = "use `list_provisioned_identities_with` instead")]
+    pub async fn list_provisioned_identities(
+        &self,
+        org: &str,
+        start_index: i64,
+        count: i64,
+        filter: &str,
+    ) -> Result<crate::types::ScimUserList> {
+        let params = ScimListProvisionedIdentitiesParams {
+            count: Some(count).filter(|v| *v > 0),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            start_index: Some(start_index).filter(|v| *v > 0),
+        };
+        self.list_provisioned_identities_with(org, &params).await
+    }
+
     /**
      * Provision and invite a SCIM user.
      *
@@ -154,7 +161,7 @@ impl Scim {
         &self,
         org: &str,
         scim_user_id: &str,
-        body: &crate::types::ScimProvisionInviteUserRequest,
+        body: &crate::types::ScimSetInformationProvisionedUserRequest,
     ) -> Result<crate::types::ScimUser> {
         let url = format!(
             "/scim/v2/organizations/{}/Users/{}",
@@ -237,3 +244,132 @@ impl Scim {
             .await
     }
 }
+
+/// The query parameters of `Scim::list_provisioned_identities_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ScimListProvisionedIdentitiesParams {
+    /// Used for pagination: the number of results to return.
+    pub count: Option<i64>,
+    /// Filters results using the equals query parameter operator (`eq`). You can filter results that are equal to `id`, `userName`, `emails`, and `external_id`. For example, to search for an identity with the `userName` Octocat, you would use this query:
+    ///
+    /// `?filter=userName%20eq%20\"Octocat\"`.
+    ///
+    /// To filter results for the identity with the email `octocat@github.com`, you would use this query:
+    ///
+    /// `?filter=emails%20eq%20\"octocat@github.com\"`.
+    pub filter: Option<String>,
+    /// Used for pagination: the index of the first result to return.
+    pub start_index: Option<i64>,
+}
+
+impl ScimListProvisionedIdentitiesParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn start_index(mut self, start_index: i64) -> Self {
+        self.start_index = Some(start_index);
+        self
+    }
+}
+
+/// The functions of [`Scim`], for code that takes them as a trait, so that it can be
+/// tested with the `MockScimApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ScimApi: Send + Sync {
+    /// See [`Scim::list_provisioned_identities_with`].
+    async fn list_provisioned_identities_with(
+        &self,
+        org: &str,
+        params: &ScimListProvisionedIdentitiesParams,
+    ) -> Result<crate::types::ScimUserList>;
+
+    /// See [`Scim::provision_and_invite_user`].
+    async fn provision_and_invite_user(
+        &self,
+        org: &str,
+        body: &crate::types::ScimProvisionInviteUserRequest,
+    ) -> Result<crate::types::ScimUser>;
+
+    /// See [`Scim::get_provisioning_information_for_user`].
+    async fn get_provisioning_information_for_user(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+    ) -> Result<crate::types::ScimUser>;
+
+    /// See [`Scim::set_information_for_provisioned_user`].
+    async fn set_information_for_provisioned_user(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+        body: &crate::types::ScimSetInformationProvisionedUserRequest,
+    ) -> Result<crate::types::ScimUser>;
+
+    /// See [`Scim::delete_user_from_org`].
+    async fn delete_user_from_org(&self, org: &str, scim_user_id: &str) -> Result<()>;
+
+    /// See [`Scim::update_attribute_for_user`].
+    async fn update_attribute_for_user(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+        body: &crate::types::ScimUpdateAttributeUserRequest,
+    ) -> Result<crate::types::ScimUser>;
+}
+
+#[async_trait::async_trait]
+impl ScimApi for Scim {
+    async fn list_provisioned_identities_with(
+        &self,
+        org: &str,
+        params: &ScimListProvisionedIdentitiesParams,
+    ) -> Result<crate::types::ScimUserList> {
+        Scim::list_provisioned_identities_with(self, org, params).await
+    }
+
+    async fn provision_and_invite_user(
+        &self,
+        org: &str,
+        body: &crate::types::ScimProvisionInviteUserRequest,
+    ) -> Result<crate::types::ScimUser> {
+        Scim::provision_and_invite_user(self, org, body).await
+    }
+
+    async fn get_provisioning_information_for_user(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+    ) -> Result<crate::types::ScimUser> {
+        Scim::get_provisioning_information_for_user(self, org, scim_user_id).await
+    }
+
+    async fn set_information_for_provisioned_user(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+        body: &crate::types::ScimSetInformationProvisionedUserRequest,
+    ) -> Result<crate::types::ScimUser> {
+        Scim::set_information_for_provisioned_user(self, org, scim_user_id, body).await
+    }
+
+    async fn delete_user_from_org(&self, org: &str, scim_user_id: &str) -> Result<()> {
+        Scim::delete_user_from_org(self, org, scim_user_id).await
+    }
+
+    async fn update_attribute_for_user(
+        &self,
+        org: &str,
+        scim_user_id: &str,
+        body: &crate::types::ScimUpdateAttributeUserRequest,
+    ) -> Result<crate::types::ScimUser> {
+        Scim::update_attribute_for_user(self, org, scim_user_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Search {
     pub client: Client,
@@ -40,34 +38,26 @@ impl Search {
      *
      * **Parameters:**
      *
-     * * `q: &str` -- The query contains one or more search keywords and qualifiers. Qualifiers allow you to limit your search to specific areas of GitHub. The REST API supports the same qualifiers as GitHub.com. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query). See "[Searching code](https://help.github.com/articles/searching-code/)" for a detailed list of qualifiers.
-     * * `sort: crate::types::SearchCodeSort` -- Sorts the results of your query. Can only be `indexed`, which indicates how recently a file has been indexed by the GitHub search infrastructure. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
-     * * `order: crate::types::Order` -- Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &SearchCodeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn code(
+    pub async fn code_with(
         &self,
-        q: &str,
-        sort: crate::types::SearchCodeSort,
-        order: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &SearchCodeParams,
     ) -> Result<crate::types::SearchCodeResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !order.to_string().is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -76,6 +66,28 @@ impl Search {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `code_with` and pass the query parameters as a `SearchCodeParams` instead.
+     */
+    #[deprecated(note = "use `code_with` instead")]
+    pub async fn code(
+        &self,
+        q: &str,
+        sort: crate::types::SearchCodeSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::SearchCodeResponse> {
+        let params = SearchCodeParams {
+            order: Some(order).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.code_with(&params).await
+    }
+
     /**
      * Search commits.
      *
@@ -94,34 +106,26 @@ impl Search {
      *
      * **Parameters:**
      *
-     * * `q: &str` -- The query contains one or more search keywords and qualifiers. Qualifiers allow you to limit your search to specific areas of GitHub. The REST API supports the same qualifiers as GitHub.com. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query). See "[Searching commits](https://help.github.com/articles/searching-commits/)" for a detailed list of qualifiers.
-     * * `sort: crate::types::SearchCommitsSort` -- Sorts the results of your query by `author-date` or `committer-date`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
-     * * `order: crate::types::Order` -- Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &SearchCommitsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn commits(
+    pub async fn commits_with(
         &self,
-        q: &str,
-        sort: crate::types::SearchCommitsSort,
-        order: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &SearchCommitsParams,
     ) -> Result<crate::types::SearchCommitsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !order.to_string().is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -130,6 +134,28 @@ impl Search {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `commits_with` and pass the query parameters as a `SearchCommitsParams` instead.
+     */
+    #[deprecated(note = "use `commits_with` instead")]
+    pub async fn commits(
+        &self,
+        q: &str,
+        sort: crate::types::SearchCommitsSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::SearchCommitsResponse> {
+        let params = SearchCommitsParams {
+            order: Some(order).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.commits_with(&params).await
+    }
+
     /**
      * Search issues and pull requests.
      *
@@ -152,34 +178,26 @@ impl Search {
      *
      * **Parameters:**
      *
-     * * `q: &str` -- The query contains one or more search keywords and qualifiers. Qualifiers allow you to limit your search to specific areas of GitHub. The REST API supports the same qualifiers as GitHub.com. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query). See "[Searching issues and pull requests](https://help.github.com/articles/searching-issues-and-pull-requests/)" for a detailed list of qualifiers.
-     * * `sort: crate::types::SearchIssuesPullRequestsSort` -- Sorts the results of your query by the number of `comments`, `reactions`, `reactions-+1`, `reactions--1`, `reactions-smile`, `reactions-thinking_face`, `reactions-heart`, `reactions-tada`, or `interactions`. You can also sort results by how recently the items were `created` or `updated`, Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
-     * * `order: crate::types::Order` -- Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &SearchIssuesAndPullRequestsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn issues_and_pull_requests(
+    pub async fn issues_and_pull_requests_with(
         &self,
-        q: &str,
-        sort: crate::types::SearchIssuesPullRequestsSort,
-        order: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &SearchIssuesAndPullRequestsParams,
     ) -> Result<crate::types::SearchIssuesPullRequestsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !order.to_string().is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -188,6 +206,28 @@ impl Search {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `issues_and_pull_requests_with` and pass the query parameters as a `SearchIssuesAndPullRequestsParams` instead.
+     */
+    #[deprecated(note = "use `issues_and_pull_requests_with` instead")]
+    pub async fn issues_and_pull_requests(
+        &self,
+        q: &str,
+        sort: crate::types::SearchIssuesPullRequestsSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::SearchIssuesPullRequestsResponse> {
+        let params = SearchIssuesAndPullRequestsParams {
+            order: Some(order).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.issues_and_pull_requests_with(&params).await
+    }
+
     /**
      * Search labels.
      *
@@ -207,39 +247,29 @@ impl Search {
      *
      * **Parameters:**
      *
-     * * `repository_id: i64` -- The id of the repository.
-     * * `q: &str` -- The search keywords. This endpoint does not accept qualifiers in the query. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query).
-     * * `sort: crate::types::Sort` -- Sorts the results of your query by when the label was `created` or `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
-     * * `order: crate::types::Order` -- Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &SearchLabelsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn labels(
+    pub async fn labels_with(
         &self,
-        repository_id: i64,
-        q: &str,
-        sort: crate::types::Sort,
-        order: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &SearchLabelsParams,
     ) -> Result<crate::types::SearchLabelsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !order.to_string().is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if repository_id > 0 {
+        if let Some(repository_id) = &params.repository_id {
             query_args.push(("repository_id".to_string(), repository_id.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -248,6 +278,30 @@ impl Search {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `labels_with` and pass the query parameters as a `SearchLabelsParams` instead.
+     */
+    #[deprecated(note = "use `labels_with` instead")]
+    pub async fn labels(
+        &self,
+        repository_id: i64,
+        q: &str,
+        sort: crate::types::Sort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::SearchLabelsResponse> {
+        let params = SearchLabelsParams {
+            order: Some(order).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            repository_id: Some(repository_id).filter(|v| *v > 0),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.labels_with(&params).await
+    }
+
     /**
      * Search repositories.
      *
@@ -271,34 +325,26 @@ impl Search {
      *
      * **Parameters:**
      *
-     * * `q: &str` -- The query contains one or more search keywords and qualifiers. Qualifiers allow you to limit your search to specific areas of GitHub. The REST API supports the same qualifiers as GitHub.com. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query). See "[Searching for repositories](https://help.github.com/articles/searching-for-repositories/)" for a detailed list of qualifiers.
-     * * `sort: crate::types::SearchReposSort` -- Sorts the results of your query by number of `stars`, `forks`, or `help-wanted-issues` or how recently the items were `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
-     * * `order: crate::types::Order` -- Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &SearchReposParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn repos(
+    pub async fn repos_with(
         &self,
-        q: &str,
-        sort: crate::types::SearchReposSort,
-        order: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &SearchReposParams,
     ) -> Result<crate::types::SearchReposResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !order.to_string().is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -307,6 +353,28 @@ impl Search {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `repos_with` and pass the query parameters as a `SearchReposParams` instead.
+     */
+    #[deprecated(note = "use `repos_with` instead")]
+    pub async fn repos(
+        &self,
+        q: &str,
+        sort: crate::types::SearchReposSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::SearchReposResponse> {
+        let params = SearchReposParams {
+            order: Some(order).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.repos_with(&params).await
+    }
+
     /**
      * Search topics.
      *
@@ -326,24 +394,20 @@ impl Search {
      *
      * **Parameters:**
      *
-     * * `q: &str` -- The query contains one or more search keywords and qualifiers. Qualifiers allow you to limit your search to specific areas of GitHub. The REST API supports the same qualifiers as GitHub.com. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query).
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &SearchTopicsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn topics(
+    pub async fn topics_with(
         &self,
-        q: &str,
-        per_page: i64,
-        page: i64,
+        params: &SearchTopicsParams,
     ) -> Result<crate::types::SearchTopicsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -352,6 +416,24 @@ impl Search {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `topics_with` and pass the query parameters as a `SearchTopicsParams` instead.
+     */
+    #[deprecated(note = "use `topics_with` instead")]
+    pub async fn topics(
+        &self,
+        q: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::SearchTopicsResponse> {
+        let params = SearchTopicsParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.topics_with(&params).await
+    }
+
     /**
      * Search users.
      *
@@ -371,34 +453,26 @@ impl Search {
      *
      * **Parameters:**
      *
-     * * `q: &str` -- The query contains one or more search keywords and qualifiers. Qualifiers allow you to limit your search to specific areas of GitHub. The REST API supports the same qualifiers as GitHub.com. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query). See "[Searching users](https://help.github.com/articles/searching-users/)" for a detailed list of qualifiers.
-     * * `sort: crate::types::SearchUsersSort` -- Sorts the results of your query by number of `followers` or `repositories`, or when the person `joined` GitHub. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
-     * * `order: crate::types::Order` -- Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &SearchUsersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn users(
+    pub async fn users_with(
         &self,
-        q: &str,
-        sort: crate::types::SearchUsersSort,
-        order: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &SearchUsersParams,
     ) -> Result<crate::types::SearchUsersResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !order.to_string().is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !sort.to_string().is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -406,4 +480,413 @@ impl Search {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `users_with` and pass the query parameters as a `SearchUsersParams` instead.
+     */
+    #[deprecated(note = "use `users_with` instead")]
+    pub async fn users(
+        &self,
+        q: &str,
+        sort: crate::types::SearchUsersSort,
+        order: crate::types::Order,
+        per_page: i64,
+        page: i64,
+    ) -> Result<crate::types::SearchUsersResponse> {
+        let params = SearchUsersParams {
+            order: Some(order).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            sort: Some(sort).filter(|v| !v.to_string().is_empty()),
+        };
+        self.users_with(&params).await
+    }
+}
+
+/// The query parameters of `Search::code_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SearchCodeParams {
+    /// Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
+    pub order: Option<crate::types::Order>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The query contains one or more search keywords and qualifiers. Qualifiers allow you to limit your search to specific areas of GitHub. The REST API supports the same qualifiers as GitHub.com. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query). See "[Searching code](https://help.github.com/articles/searching-code/)" for a detailed list of qualifiers.
+    pub q: Option<String>,
+    /// Sorts the results of your query. Can only be `indexed`, which indicates how recently a file has been indexed by the GitHub search infrastructure. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
+    pub sort: Option<crate::types::SearchCodeSort>,
+}
+
+impl SearchCodeParams {
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::SearchCodeSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The query parameters of `Search::commits_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SearchCommitsParams {
+    /// Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
+    pub order: Option<crate::types::Order>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The query contains one or more search keywords and qualifiers. Qualifiers allow you to limit your search to specific areas of GitHub. The REST API supports the same qualifiers as GitHub.com. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query). See "[Searching commits](https://help.github.com/articles/searching-commits/)" for a detailed list of qualifiers.
+    pub q: Option<String>,
+    /// Sorts the results of your query by `author-date` or `committer-date`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
+    pub sort: Option<crate::types::SearchCommitsSort>,
+}
+
+impl SearchCommitsParams {
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::SearchCommitsSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The query parameters of `Search::issues_and_pull_requests_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SearchIssuesAndPullRequestsParams {
+    /// Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
+    pub order: Option<crate::types::Order>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The query contains one or more search keywords and qualifiers. Qualifiers allow you to limit your search to specific areas of GitHub. The REST API supports the same qualifiers as GitHub.com. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query). See "[Searching issues and pull requests](https://help.github.com/articles/searching-issues-and-pull-requests/)" for a detailed list of qualifiers.
+    pub q: Option<String>,
+    /// Sorts the results of your query by the number of `comments`, `reactions`, `reactions-+1`, `reactions--1`, `reactions-smile`, `reactions-thinking_face`, `reactions-heart`, `reactions-tada`, or `interactions`. You can also sort results by how recently the items were `created` or `updated`, Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
+    pub sort: Option<crate::types::SearchIssuesPullRequestsSort>,
+}
+
+impl SearchIssuesAndPullRequestsParams {
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::SearchIssuesPullRequestsSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The query parameters of `Search::labels_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SearchLabelsParams {
+    /// Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
+    pub order: Option<crate::types::Order>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The search keywords. This endpoint does not accept qualifiers in the query. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query).
+    pub q: Option<String>,
+    /// The id of the repository.
+    pub repository_id: Option<i64>,
+    /// Sorts the results of your query by when the label was `created` or `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
+    pub sort: Option<crate::types::Sort>,
+}
+
+impl SearchLabelsParams {
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn repository_id(mut self, repository_id: i64) -> Self {
+        self.repository_id = Some(repository_id);
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The query parameters of `Search::repos_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SearchReposParams {
+    /// Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
+    pub order: Option<crate::types::Order>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The query contains one or more search keywords and qualifiers. Qualifiers allow you to limit your search to specific areas of GitHub. The REST API supports the same qualifiers as GitHub.com. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query). See "[Searching for repositories](https://help.github.com/articles/searching-for-repositories/)" for a detailed list of qualifiers.
+    pub q: Option<String>,
+    /// Sorts the results of your query by number of `stars`, `forks`, or `help-wanted-issues` or how recently the items were `updated`. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
+    pub sort: Option<crate::types::SearchReposSort>,
+}
+
+impl SearchReposParams {
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::SearchReposSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The query parameters of `Search::topics_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SearchTopicsParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The query contains one or more search keywords and qualifiers. Qualifiers allow you to limit your search to specific areas of GitHub. The REST API supports the same qualifiers as GitHub.com. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query).
+    pub q: Option<String>,
+}
+
+impl SearchTopicsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Search::users_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SearchUsersParams {
+    /// Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
+    pub order: Option<crate::types::Order>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// The query contains one or more search keywords and qualifiers. Qualifiers allow you to limit your search to specific areas of GitHub. The REST API supports the same qualifiers as GitHub.com. To learn more about the format of the query, see [Constructing a search query](https://docs.github.com/rest/reference/search#constructing-a-search-query). See "[Searching users](https://help.github.com/articles/searching-users/)" for a detailed list of qualifiers.
+    pub q: Option<String>,
+    /// Sorts the results of your query by number of `followers` or `repositories`, or when the person `joined` GitHub. Default: [best match](https://docs.github.com/rest/reference/search#ranking-search-results).
+    pub sort: Option<crate::types::SearchUsersSort>,
+}
+
+impl SearchUsersParams {
+    pub fn order(mut self, order: crate::types::Order) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn sort(mut self, sort: crate::types::SearchUsersSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+}
+
+/// The functions of [`Search`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSearchApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SearchApi: Send + Sync {
+    /// See [`Search::code_with`].
+    async fn code_with(
+        &self,
+        params: &SearchCodeParams,
+    ) -> Result<crate::types::SearchCodeResponse>;
+
+    /// See [`Search::commits_with`].
+    async fn commits_with(
+        &self,
+        params: &SearchCommitsParams,
+    ) -> Result<crate::types::SearchCommitsResponse>;
+
+    /// See [`Search::issues_and_pull_requests_with`].
+    async fn issues_and_pull_requests_with(
+        &self,
+        params: &SearchIssuesAndPullRequestsParams,
+    ) -> Result<crate::types::SearchIssuesPullRequestsResponse>;
+
+    /// See [`Search::labels_with`].
+    async fn labels_with(
+        &self,
+        params: &SearchLabelsParams,
+    ) -> Result<crate::types::SearchLabelsResponse>;
+
+    /// See [`Search::repos_with`].
+    async fn repos_with(
+        &self,
+        params: &SearchReposParams,
+    ) -> Result<crate::types::SearchReposResponse>;
+
+    /// See [`Search::topics_with`].
+    async fn topics_with(
+        &self,
+        params: &SearchTopicsParams,
+    ) -> Result<crate::types::SearchTopicsResponse>;
+
+    /// See [`Search::users_with`].
+    async fn users_with(
+        &self,
+        params: &SearchUsersParams,
+    ) -> Result<crate::types::SearchUsersResponse>;
+}
+
+#[async_trait::async_trait]
+impl SearchApi for Search {
+    async fn code_with(
+        &self,
+        params: &SearchCodeParams,
+    ) -> Result<crate::types::SearchCodeResponse> {
+        Search::code_with(self, params).await
+    }
+
+    async fn commits_with(
+        &self,
+        params: &SearchCommitsParams,
+    ) -> Result<crate::types::SearchCommitsResponse> {
+        Search::commits_with(self, params).await
+    }
+
+    async fn issues_and_pull_requests_with(
+        &self,
+        params: &SearchIssuesAndPullRequestsParams,
+    ) -> Result<crate::types::SearchIssuesPullRequestsResponse> {
+        Search::issues_and_pull_requests_with(self, params).await
+    }
+
+    async fn labels_with(
+        &self,
+        params: &SearchLabelsParams,
+    ) -> Result<crate::types::SearchLabelsResponse> {
+        Search::labels_with(self, params).await
+    }
+
+    async fn repos_with(
+        &self,
+        params: &SearchReposParams,
+    ) -> Result<crate::types::SearchReposResponse> {
+        Search::repos_with(self, params).await
+    }
+
+    async fn topics_with(
+        &self,
+        params: &SearchTopicsParams,
+    ) -> Result<crate::types::SearchTopicsResponse> {
+        Search::topics_with(self, params).await
+    }
+
+    async fn users_with(
+        &self,
+        params: &SearchUsersParams,
+    ) -> Result<crate::types::SearchUsersResponse> {
+        Search::users_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SecretScanning {
     pub client: Client,
@@ -27,31 +25,25 @@ impl SecretScanning {
      *
      * * `owner: &str`
      * * `repo: &str`
-     * * `state: crate::types::SecretScanningAlertState` -- Sets the state of the secret scanning alert. Can be either `open` or `resolved`. You must provide `resolution` when you set the state to `resolved`.
-     * * `secret_type: &str` -- A comma separated list of secret types to return. By default all secret types are returned. See "[About secret scanning for private repositories](https://docs.github.com/code-security/secret-security/about-secret-scanning#about-secret-scanning-for-private-repositories)" for a complete list of secret types (API slug).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `per_page: i64` -- Results per page (max 100).
+     * * `params: &SecretScanningListAlertsForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_alerts_for_repo(
+    pub async fn list_alerts_for_repo_with(
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::SecretScanningAlertState,
-        secret_type: &str,
-        page: i64,
-        per_page: i64,
+        params: &SecretScanningListAlertsForRepoParams,
     ) -> Result<Vec<crate::types::SecretScanningAlert>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !secret_type.is_empty() {
+        if let Some(secret_type) = &params.secret_type {
             query_args.push(("secret_type".to_string(), secret_type.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -65,6 +57,28 @@ impl SecretScanning {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_alerts_for_repo_with` and pass the query parameters as a `SecretScanningListAlertsForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_alerts_for_repo_with` instead")]
+    pub async fn list_alerts_for_repo(
+        &self,
+        owner: &str,
+        repo: &str,
+        state: crate::types::SecretScanningListAlertsRepoState,
+        secret_type: &str,
+        page: i64,
+        per_page: i64,
+    ) -> Result<Vec<crate::types::SecretScanningAlert>> {
+        let params = SecretScanningListAlertsForRepoParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            secret_type: Some(secret_type.to_string()).filter(|s| !s.is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_alerts_for_repo_with(owner, repo, &params).await
+    }
+
     /**
      * List secret scanning alerts for a repository.
      *
@@ -77,19 +91,81 @@ impl SecretScanning {
      * GitHub Apps must have the `secret_scanning_alerts` read permission to use this endpoint.
      *
      * FROM: <https://docs.github.com/rest/reference/secret-scanning#list-secret-scanning-alerts-for-a-repository>
+     *
+     * **Parameters:**
+     *
+     * * `params: &SecretScanningListAlertsForRepoParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn list_all_alerts_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &SecretScanningListAlertsForRepoParams,
+    ) -> Result<Vec<crate::types::SecretScanningAlert>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(secret_type) = &params.secret_type {
+            query_args.push(("secret_type".to_string(), secret_type.to_string()));
+        }
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/repos/{}/{}/secret-scanning/alerts?{}",
+            crate::progenitor_support::encode_path(owner),
+            crate::progenitor_support::encode_path(repo),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_alerts_for_repo_with` and pass the query parameters as a `SecretScanningListAlertsForRepoParams` instead.
+     */
+    #[deprecated(note = "use `list_all_alerts_for_repo_with` instead")]
     pub async fn list_all_alerts_for_repo(
         &self,
         owner: &str,
         repo: &str,
-        state: crate::types::SecretScanningAlertState,
+        state: crate::types::SecretScanningListAlertsRepoState,
         secret_type: &str,
     ) -> Result<Vec<crate::types::SecretScanningAlert>> {
+        let params = SecretScanningListAlertsForRepoParams {
+            secret_type: Some(secret_type.to_string()).filter(|s| !s.is_empty()),
+            state: Some(state).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_alerts_for_repo_with(owner, repo, &params)
+            .await
+    }
+
+    /**
+     * List secret scanning alerts for a repository.
+     *
+     * This function performs a `GET` to the `/repos/{owner}/{repo}/secret-scanning/alerts` endpoint.
+     *
+     * As opposed to `list_all_alerts_for_repo`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all secret scanning alerts for a private repository, from newest to oldest. To use this endpoint, you must be an administrator for the repository or organization, and you must use an access token with the `repo` scope or `security_events` scope.
+     *
+     * GitHub Apps must have the `secret_scanning_alerts` read permission to use this endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/secret-scanning#list-secret-scanning-alerts-for-a-repository>
+     */
+    pub fn list_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &SecretScanningListAlertsForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SecretScanningAlert> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !secret_type.is_empty() {
+        if let Some(secret_type) = &params.secret_type {
             query_args.push(("secret_type".to_string(), secret_type.to_string()));
         }
-        if !state.to_string().is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -100,7 +176,22 @@ impl SecretScanning {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -172,3 +263,138 @@ impl SecretScanning {
             .await
     }
 }
+
+/// The query parameters of `SecretScanning::list_alerts_for_repo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SecretScanningListAlertsForRepoParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// A comma separated list of secret types to return. By default all secret types are returned. See "[About secret scanning for private repositories](https://docs.github.com/code-security/secret-security/about-secret-scanning#about-secret-scanning-for-private-repositories)" for a complete list of secret types (API slug).
+    pub secret_type: Option<String>,
+    /// Set to `open` or `resolved` to only list secret scanning alerts in a specific state.
+    pub state: Option<crate::types::SecretScanningListAlertsRepoState>,
+}
+
+impl SecretScanningListAlertsForRepoParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn secret_type<T: ToString>(mut self, secret_type: T) -> Self {
+        self.secret_type = Some(secret_type.to_string());
+        self
+    }
+
+    pub fn state(mut self, state: crate::types::SecretScanningListAlertsRepoState) -> Self {
+        self.state = Some(state);
+        self
+    }
+}
+
+/// The functions of [`SecretScanning`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSecretScanningApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SecretScanningApi: Send + Sync {
+    /// See [`SecretScanning::list_alerts_for_repo_with`].
+    async fn list_alerts_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &SecretScanningListAlertsForRepoParams,
+    ) -> Result<Vec<crate::types::SecretScanningAlert>>;
+
+    /// See [`SecretScanning::list_all_alerts_for_repo_with`].
+    async fn list_all_alerts_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &SecretScanningListAlertsForRepoParams,
+    ) -> Result<Vec<crate::types::SecretScanningAlert>>;
+
+    /// See [`SecretScanning::list_alerts_for_repo_stream`].
+    fn list_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &SecretScanningListAlertsForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SecretScanningAlert>;
+
+    /// See [`SecretScanning::get_alert`].
+    async fn get_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+    ) -> Result<crate::types::SecretScanningAlert>;
+
+    /// See [`SecretScanning::update_alert`].
+    async fn update_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        body: &crate::types::SecretScanningUpdateAlertRequest,
+    ) -> Result<crate::types::SecretScanningAlert>;
+}
+
+#[async_trait::async_trait]
+impl SecretScanningApi for SecretScanning {
+    async fn list_alerts_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &SecretScanningListAlertsForRepoParams,
+    ) -> Result<Vec<crate::types::SecretScanningAlert>> {
+        SecretScanning::list_alerts_for_repo_with(self, owner, repo, params).await
+    }
+
+    async fn list_all_alerts_for_repo_with(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &SecretScanningListAlertsForRepoParams,
+    ) -> Result<Vec<crate::types::SecretScanningAlert>> {
+        SecretScanning::list_all_alerts_for_repo_with(self, owner, repo, params).await
+    }
+
+    fn list_alerts_for_repo_stream(
+        &self,
+        owner: &str,
+        repo: &str,
+        params: &SecretScanningListAlertsForRepoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SecretScanningAlert> {
+        SecretScanning::list_alerts_for_repo_stream(self, owner, repo, params, start, max_items)
+    }
+
+    async fn get_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+    ) -> Result<crate::types::SecretScanningAlert> {
+        SecretScanning::get_alert(self, owner, repo, alert_number).await
+    }
+
+    async fn update_alert(
+        &self,
+        owner: &str,
+        repo: &str,
+        alert_number: i64,
+        body: &crate::types::SecretScanningUpdateAlertRequest,
+    ) -> Result<crate::types::SecretScanningAlert> {
+        SecretScanning::update_alert(self, owner, repo, alert_number, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Teams {
     pub client: Client,
@@ -28,20 +26,18 @@ impl Teams {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: &str` -- Page token.
+     * * `params: &TeamsListIdpGroupsForOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_idp_groups_for_org(
+    pub async fn list_idp_groups_for_org_with(
         &self,
         org: &str,
-        per_page: i64,
-        page: &str,
+        params: &TeamsListIdpGroupsForOrgParams,
     ) -> Result<crate::types::GroupMapping> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !page.is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -54,6 +50,23 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_idp_groups_for_org_with` and pass the query parameters as a `TeamsListIdpGroupsForOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_idp_groups_for_org_with` instead")]
+    pub async fn list_idp_groups_for_org(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: &str,
+    ) -> Result<crate::types::GroupMapping> {
+        let params = TeamsListIdpGroupsForOrgParams {
+            page: Some(page.to_string()).filter(|s| !s.is_empty()),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_idp_groups_for_org_with(org, &params).await
+    }
+
     /**
      * List teams.
      *
@@ -66,20 +79,18 @@ impl Teams {
      * **Parameters:**
      *
      * * `org: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         org: &str,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListParams,
     ) -> Result<Vec<crate::types::Team>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -92,6 +103,23 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `TeamsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        org: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Team>> {
+        let params = TeamsListParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_with(org, &params).await
+    }
+
     /**
      * List teams.
      *
@@ -112,6 +140,46 @@ impl Teams {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all teams in an organization that are visible to the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams>
+     */
+    pub fn list_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team> {
+        let url = format!(
+            "/orgs/{}/teams",
+            crate::progenitor_support::encode_path(org),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a team.
      *
@@ -131,7 +199,7 @@ impl Teams {
         &self,
         org: &str,
         body: &crate::types::TeamsCreateRequest,
-    ) -> Result<crate::types::FullTeam> {
+    ) -> Result<crate::types::TeamFull> {
         let url = format!(
             "/orgs/{}/teams",
             crate::progenitor_support::encode_path(org),
@@ -158,7 +226,7 @@ impl Teams {
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
      */
-    pub async fn get_by_name(&self, org: &str, team_slug: &str) -> Result<crate::types::FullTeam> {
+    pub async fn get_by_name(&self, org: &str, team_slug: &str) -> Result<crate::types::TeamFull> {
         let url = format!(
             "/orgs/{}/teams/{}",
             crate::progenitor_support::encode_path(org),
@@ -217,7 +285,7 @@ impl Teams {
         org: &str,
         team_slug: &str,
         body: &crate::types::TeamsUpdateInOrgRequest,
-    ) -> Result<crate::types::FullTeam> {
+    ) -> Result<crate::types::TeamFull> {
         let url = format!(
             "/orgs/{}/teams/{}",
             crate::progenitor_support::encode_path(org),
@@ -244,33 +312,25 @@ impl Teams {
      *
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
-     * * `pinned: &str` -- Pinned discussions only filter.
+     * * `params: &TeamsListDiscussionsInOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_discussions_in_org(
+    pub async fn list_discussions_in_org_with(
         &self,
         org: &str,
         team_slug: &str,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
-        pinned: &str,
+        params: &TeamsListDiscussionsInOrgParams,
     ) -> Result<Vec<crate::types::TeamDiscussion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !pinned.is_empty() {
+        if let Some(pinned) = &params.pinned {
             query_args.push(("pinned".to_string(), pinned.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -284,6 +344,29 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_discussions_in_org_with` and pass the query parameters as a `TeamsListDiscussionsInOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_discussions_in_org_with` instead")]
+    pub async fn list_discussions_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        direction: crate::types::Direction,
+        per_page: i64,
+        page: i64,
+        pinned: &str,
+    ) -> Result<Vec<crate::types::TeamDiscussion>> {
+        let params = TeamsListDiscussionsInOrgParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            pinned: Some(pinned.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_discussions_in_org_with(org, team_slug, &params)
+            .await
+    }
+
     /**
      * List discussions.
      *
@@ -296,19 +379,81 @@ impl Teams {
      * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/discussions`.
      *
      * FROM: <https://docs.github.com/rest/reference/teams#list-discussions>
+     *
+     * **Parameters:**
+     *
+     * * `params: &TeamsListDiscussionsInOrgParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_discussions_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListDiscussionsInOrgParams,
+    ) -> Result<Vec<crate::types::TeamDiscussion>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        if let Some(pinned) = &params.pinned {
+            query_args.push(("pinned".to_string(), pinned.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/teams/{}/discussions?{}",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(team_slug),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_discussions_in_org_with` and pass the query parameters as a `TeamsListDiscussionsInOrgParams` instead.
      */
+    #[deprecated(note = "use `list_all_discussions_in_org_with` instead")]
     pub async fn list_all_discussions_in_org(
         &self,
         org: &str,
         team_slug: &str,
-        direction: crate::types::Order,
+        direction: crate::types::Direction,
         pinned: &str,
     ) -> Result<Vec<crate::types::TeamDiscussion>> {
+        let params = TeamsListDiscussionsInOrgParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            pinned: Some(pinned.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_discussions_in_org_with(org, team_slug, &params)
+            .await
+    }
+
+    /**
+     * List discussions.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions` endpoint.
+     *
+     * As opposed to `list_all_discussions_in_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List all discussions on a team's page. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/discussions`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions>
+     */
+    pub fn list_discussions_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListDiscussionsInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDiscussion> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if !pinned.is_empty() {
+        if let Some(pinned) = &params.pinned {
             query_args.push(("pinned".to_string(), pinned.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -319,7 +464,22 @@ impl Teams {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -475,29 +635,23 @@ impl Teams {
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
      * * `discussion_number: i64`
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListDiscussionCommentsInOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_discussion_comments_in_org(
+    pub async fn list_discussion_comments_in_org_with(
         &self,
         org: &str,
         team_slug: &str,
         discussion_number: i64,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListDiscussionCommentsInOrgParams,
     ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -512,6 +666,28 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_discussion_comments_in_org_with` and pass the query parameters as a `TeamsListDiscussionCommentsInOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_discussion_comments_in_org_with` instead")]
+    pub async fn list_discussion_comments_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        direction: crate::types::Direction,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
+        let params = TeamsListDiscussionCommentsInOrgParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_discussion_comments_in_org_with(org, team_slug, discussion_number, &params)
+            .await
+    }
+
     /**
      * List discussion comments.
      *
@@ -524,16 +700,20 @@ impl Teams {
      * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/discussions/{discussion_number}/comments`.
      *
      * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments>
+     *
+     * **Parameters:**
+     *
+     * * `params: &TeamsListDiscussionCommentsInOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_discussion_comments_in_org(
+    pub async fn list_all_discussion_comments_in_org_with(
         &self,
         org: &str,
         team_slug: &str,
         discussion_number: i64,
-        direction: crate::types::Order,
+        params: &TeamsListDiscussionCommentsInOrgParams,
     ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -548,6 +728,78 @@ impl Teams {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_all_discussion_comments_in_org_with` and pass the query parameters as a `TeamsListDiscussionCommentsInOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_all_discussion_comments_in_org_with` instead")]
+    pub async fn list_all_discussion_comments_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        direction: crate::types::Direction,
+    ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
+        let params = TeamsListDiscussionCommentsInOrgParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_discussion_comments_in_org_with(org, team_slug, discussion_number, &params)
+            .await
+    }
+
+    /**
+     * List discussion comments.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_discussion_comments_in_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List all comments on a team discussion. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/discussions/{discussion_number}/comments`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments>
+     */
+    pub fn list_discussion_comments_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDiscussionComment> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/teams/{}/discussions/{}/comments?{}",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(team_slug),
+            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a discussion comment.
      *
@@ -572,7 +824,7 @@ impl Teams {
         org: &str,
         team_slug: &str,
         discussion_number: i64,
-        body: &crate::types::PullsUpdateReviewRequest,
+        body: &crate::types::TeamsCreateDiscussionCommentInOrgRequest,
     ) -> Result<crate::types::TeamDiscussionComment> {
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}/comments",
@@ -682,7 +934,7 @@ impl Teams {
         team_slug: &str,
         discussion_number: i64,
         comment_number: i64,
-        body: &crate::types::PullsUpdateReviewRequest,
+        body: &crate::types::TeamsUpdateDiscussionCommentInOrgRequest,
     ) -> Result<crate::types::TeamDiscussionComment> {
         let url = format!(
             "/orgs/{}/teams/{}/discussions/{}/comments/{}",
@@ -712,21 +964,19 @@ impl Teams {
      *
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListPendingInvitationsInOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_pending_invitations_in_org(
+    pub async fn list_pending_invitations_in_org_with(
         &self,
         org: &str,
         team_slug: &str,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListPendingInvitationsInOrgParams,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -740,6 +990,25 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_pending_invitations_in_org_with` and pass the query parameters as a `TeamsListPendingInvitationsInOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_pending_invitations_in_org_with` instead")]
+    pub async fn list_pending_invitations_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        let params = TeamsListPendingInvitationsInOrgParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_pending_invitations_in_org_with(org, team_slug, &params)
+            .await
+    }
+
     /**
      * List pending team invitations.
      *
@@ -767,6 +1036,50 @@ impl Teams {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List pending team invitations.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations_in_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/invitations`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations>
+     */
+    pub fn list_pending_invitations_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationInvitation> {
+        let url = format!(
+            "/orgs/{}/teams/{}/invitations",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(team_slug),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List team members.
      *
@@ -782,29 +1095,22 @@ impl Teams {
      *
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
-     * * `role: crate::types::TeamsListMembersInOrgRole` -- Filters members returned by their role in the team. Can be one of:  
-     *  \\* `member` - normal members of the team.  
-     *  \\* `maintainer` - team maintainers.  
-     *  \\* `all` - all members of the team.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListMembersInOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_members_in_org(
+    pub async fn list_members_in_org_with(
         &self,
         org: &str,
         team_slug: &str,
-        role: crate::types::TeamsListMembersInOrgRole,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListMembersInOrgParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !role.to_string().is_empty() {
+        if let Some(role) = &params.role {
             query_args.push(("role".to_string(), role.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -818,6 +1124,26 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_members_in_org_with` and pass the query parameters as a `TeamsListMembersInOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_members_in_org_with` instead")]
+    pub async fn list_members_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        role: crate::types::TeamsListMembersInOrgRole,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = TeamsListMembersInOrgParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            role: Some(role).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_members_in_org_with(org, team_slug, &params).await
+    }
+
     /**
      * List team members.
      *
@@ -830,15 +1156,19 @@ impl Teams {
      * To list members in a team, the team must be visible to the authenticated user.
      *
      * FROM: <https://docs.github.com/rest/reference/teams#list-team-members>
+     *
+     * **Parameters:**
+     *
+     * * `params: &TeamsListMembersInOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_members_in_org(
+    pub async fn list_all_members_in_org_with(
         &self,
         org: &str,
         team_slug: &str,
-        role: crate::types::TeamsListMembersInOrgRole,
+        params: &TeamsListMembersInOrgParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !role.to_string().is_empty() {
+        if let Some(role) = &params.role {
             query_args.push(("role".to_string(), role.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -853,24 +1183,93 @@ impl Teams {
     }
 
     /**
-     * Get team membership for a user.
-     *
-     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/memberships/{username}` endpoint.
-     *
-     * Team members will include the members of child teams.
-     *
-     * To get a user's membership with a team, the team must be visible to the authenticated user.
-     *
-     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/memberships/{username}`.
+     * Deprecated: use `list_all_members_in_org_with` and pass the query parameters as a `TeamsListMembersInOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_all_members_in_org_with` instead")]
+    pub async fn list_all_members_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        role: crate::types::TeamsListMembersInOrgRole,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = TeamsListMembersInOrgParams {
+            role: Some(role).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_members_in_org_with(org, team_slug, &params)
+            .await
+    }
+
+    /**
+     * List team members.
      *
-     * **Note:**
-     * The response contains the `state` of the membership and the member's `role`.
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/members` endpoint.
      *
-     * The `role` for organization owners is set to `maintainer`. For more information about `maintainer` roles, see see [Create a team](https://docs.github.com/rest/reference/teams#create-a-team).
+     * As opposed to `list_all_members_in_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
      *
-     * FROM: <https://docs.github.com/rest/reference/teams#get-team-membership-for-a-user>
+     * Team members will include the members of child teams.
      *
-     * **Parameters:**
+     * To list members in a team, the team must be visible to the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members>
+     */
+    pub fn list_members_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListMembersInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(role) = &params.role {
+            query_args.push(("role".to_string(), role.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/orgs/{}/teams/{}/members?{}",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(team_slug),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * Get team membership for a user.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/memberships/{username}` endpoint.
+     *
+     * Team members will include the members of child teams.
+     *
+     * To get a user's membership with a team, the team must be visible to the authenticated user.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/memberships/{username}`.
+     *
+     * **Note:**
+     * The response contains the `state` of the membership and the member's `role`.
+     *
+     * The `role` for organization owners is set to `maintainer`. For more information about `maintainer` roles, see see [Create a team](https://docs.github.com/rest/reference/teams#create-a-team).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#get-team-membership-for-a-user>
+     *
+     * **Parameters:**
      *
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
@@ -988,21 +1387,19 @@ impl Teams {
      *
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListProjectsInOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_projects_in_org(
+    pub async fn list_projects_in_org_with(
         &self,
         org: &str,
         team_slug: &str,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListProjectsInOrgParams,
     ) -> Result<Vec<crate::types::TeamProject>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1016,6 +1413,25 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_projects_in_org_with` and pass the query parameters as a `TeamsListProjectsInOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_projects_in_org_with` instead")]
+    pub async fn list_projects_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::TeamProject>> {
+        let params = TeamsListProjectsInOrgParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_projects_in_org_with(org, team_slug, &params)
+            .await
+    }
+
     /**
      * List team projects.
      *
@@ -1043,6 +1459,50 @@ impl Teams {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List team projects.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/projects` endpoint.
+     *
+     * As opposed to `list_all_projects_in_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the organization projects for a team.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/projects`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-projects>
+     */
+    pub fn list_projects_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamProject> {
+        let url = format!(
+            "/orgs/{}/teams/{}/projects",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(team_slug),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Check team permissions for a project.
      *
@@ -1098,7 +1558,7 @@ impl Teams {
         org: &str,
         team_slug: &str,
         project_id: i64,
-        body: &crate::types::ProjectsAddCollaboratorRequest,
+        body: &crate::types::TeamsAddUpdateProjectPermissionsInOrgRequest,
     ) -> Result<()> {
         let url = format!(
             "/orgs/{}/teams/{}/projects/{}",
@@ -1160,21 +1620,19 @@ impl Teams {
      *
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListReposInOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_repos_in_org(
+    pub async fn list_repos_in_org_with(
         &self,
         org: &str,
         team_slug: &str,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListReposInOrgParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1188,6 +1646,24 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_repos_in_org_with` and pass the query parameters as a `TeamsListReposInOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_repos_in_org_with` instead")]
+    pub async fn list_repos_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = TeamsListReposInOrgParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_repos_in_org_with(org, team_slug, &params).await
+    }
+
     /**
      * List team repositories.
      *
@@ -1215,6 +1691,50 @@ impl Teams {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List team repositories.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/repos` endpoint.
+     *
+     * As opposed to `list_all_repos_in_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists a team's repositories visible to the authenticated user.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/repos`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-repositories>
+     */
+    pub fn list_repos_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        let url = format!(
+            "/orgs/{}/teams/{}/repos",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(team_slug),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Check team permissions for a repository.
      *
@@ -1414,21 +1934,19 @@ impl Teams {
      *
      * * `org: &str`
      * * `team_slug: &str` -- team_slug parameter.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListChildInOrgParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_child_in_org(
+    pub async fn list_child_in_org_with(
         &self,
         org: &str,
         team_slug: &str,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListChildInOrgParams,
     ) -> Result<Vec<crate::types::Team>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1442,6 +1960,24 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_child_in_org_with` and pass the query parameters as a `TeamsListChildInOrgParams` instead.
+     */
+    #[deprecated(note = "use `list_child_in_org_with` instead")]
+    pub async fn list_child_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Team>> {
+        let params = TeamsListChildInOrgParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_child_in_org_with(org, team_slug, &params).await
+    }
+
     /**
      * List child teams.
      *
@@ -1469,6 +2005,50 @@ impl Teams {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List child teams.
+     *
+     * This function performs a `GET` to the `/orgs/{org}/teams/{team_slug}/teams` endpoint.
+     *
+     * As opposed to `list_all_child_in_org`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the child teams of the team specified by `{team_slug}`.
+     *
+     * **Note:** You can also specify a team by `org_id` and `team_id` using the route `GET /organizations/{org_id}/team/{team_id}/teams`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-child-teams>
+     */
+    pub fn list_child_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team> {
+        let url = format!(
+            "/orgs/{}/teams/{}/teams",
+            crate::progenitor_support::encode_path(org),
+            crate::progenitor_support::encode_path(team_slug),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get a team (Legacy).
      *
@@ -1482,7 +2062,7 @@ impl Teams {
      *
      * * `team_id: i64`
      */
-    pub async fn get_legacy(&self, team_id: i64) -> Result<crate::types::FullTeam> {
+    pub async fn get_legacy(&self, team_id: i64) -> Result<crate::types::TeamFull> {
         let url = format!(
             "/teams/{}",
             crate::progenitor_support::encode_path(&team_id.to_string()),
@@ -1537,8 +2117,8 @@ impl Teams {
     pub async fn update_legacy(
         &self,
         team_id: i64,
-        body: &crate::types::TeamsUpdateInOrgRequest,
-    ) -> Result<crate::types::FullTeam> {
+        body: &crate::types::TeamsUpdateLegacyRequest,
+    ) -> Result<crate::types::TeamFull> {
         let url = format!(
             "/teams/{}",
             crate::progenitor_support::encode_path(&team_id.to_string()),
@@ -1563,27 +2143,21 @@ impl Teams {
      * **Parameters:**
      *
      * * `team_id: i64`
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListDiscussionsLegacyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_discussions_legacy(
+    pub async fn list_discussions_legacy_with(
         &self,
         team_id: i64,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListDiscussionsLegacyParams,
     ) -> Result<Vec<crate::types::TeamDiscussion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1596,6 +2170,25 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_discussions_legacy_with` and pass the query parameters as a `TeamsListDiscussionsLegacyParams` instead.
+     */
+    #[deprecated(note = "use `list_discussions_legacy_with` instead")]
+    pub async fn list_discussions_legacy(
+        &self,
+        team_id: i64,
+        direction: crate::types::Direction,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::TeamDiscussion>> {
+        let params = TeamsListDiscussionsLegacyParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_discussions_legacy_with(team_id, &params).await
+    }
+
     /**
      * List discussions (Legacy).
      *
@@ -1608,14 +2201,18 @@ impl Teams {
      * List all discussions on a team's page. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
      *
      * FROM: <https://docs.github.com/rest/reference/teams#list-discussions-legacy>
+     *
+     * **Parameters:**
+     *
+     * * `params: &TeamsListDiscussionsLegacyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_discussions_legacy(
+    pub async fn list_all_discussions_legacy_with(
         &self,
         team_id: i64,
-        direction: crate::types::Order,
+        params: &TeamsListDiscussionsLegacyParams,
     ) -> Result<Vec<crate::types::TeamDiscussion>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1628,6 +2225,72 @@ impl Teams {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_all_discussions_legacy_with` and pass the query parameters as a `TeamsListDiscussionsLegacyParams` instead.
+     */
+    #[deprecated(note = "use `list_all_discussions_legacy_with` instead")]
+    pub async fn list_all_discussions_legacy(
+        &self,
+        team_id: i64,
+        direction: crate::types::Direction,
+    ) -> Result<Vec<crate::types::TeamDiscussion>> {
+        let params = TeamsListDiscussionsLegacyParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_discussions_legacy_with(team_id, &params)
+            .await
+    }
+
+    /**
+     * List discussions (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions` endpoint.
+     *
+     * As opposed to `list_all_discussions_legacy`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List discussions`](https://docs.github.com/rest/reference/teams#list-discussions) endpoint.
+     *
+     * List all discussions on a team's page. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussions-legacy>
+     */
+    pub fn list_discussions_legacy_stream(
+        &self,
+        team_id: i64,
+        params: &TeamsListDiscussionsLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDiscussion> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/teams/{}/discussions?{}",
+            crate::progenitor_support::encode_path(&team_id.to_string()),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a discussion (Legacy).
      *
@@ -1648,7 +2311,7 @@ impl Teams {
     pub async fn create_discussion_legacy(
         &self,
         team_id: i64,
-        body: &crate::types::TeamsCreateDiscussionInOrgRequest,
+        body: &crate::types::TeamsCreateDiscussionLegacyRequest,
     ) -> Result<crate::types::TeamDiscussion> {
         let url = format!(
             "/teams/{}/discussions",
@@ -1740,7 +2403,7 @@ impl Teams {
         &self,
         team_id: i64,
         discussion_number: i64,
-        body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
+        body: &crate::types::TeamsUpdateDiscussionLegacyRequest,
     ) -> Result<crate::types::TeamDiscussion> {
         let url = format!(
             "/teams/{}/discussions/{}",
@@ -1768,28 +2431,22 @@ impl Teams {
      *
      * * `team_id: i64`
      * * `discussion_number: i64`
-     * * `direction: crate::types::Order` -- The order of audit log events. To list newest events first, specify `desc`. To list oldest events first, specify `asc`.
-     *  
-     *  The default is `desc`.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListDiscussionCommentsLegacyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_discussion_comments_legacy(
+    pub async fn list_discussion_comments_legacy_with(
         &self,
         team_id: i64,
         discussion_number: i64,
-        direction: crate::types::Order,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListDiscussionCommentsLegacyParams,
     ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1803,6 +2460,27 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_discussion_comments_legacy_with` and pass the query parameters as a `TeamsListDiscussionCommentsLegacyParams` instead.
+     */
+    #[deprecated(note = "use `list_discussion_comments_legacy_with` instead")]
+    pub async fn list_discussion_comments_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        direction: crate::types::Direction,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
+        let params = TeamsListDiscussionCommentsLegacyParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_discussion_comments_legacy_with(team_id, discussion_number, &params)
+            .await
+    }
+
     /**
      * List discussion comments (Legacy).
      *
@@ -1815,15 +2493,19 @@ impl Teams {
      * List all comments on a team discussion. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
      *
      * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments-legacy>
+     *
+     * **Parameters:**
+     *
+     * * `params: &TeamsListDiscussionCommentsLegacyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_discussion_comments_legacy(
+    pub async fn list_all_discussion_comments_legacy_with(
         &self,
         team_id: i64,
         discussion_number: i64,
-        direction: crate::types::Order,
+        params: &TeamsListDiscussionCommentsLegacyParams,
     ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !direction.to_string().is_empty() {
+        if let Some(direction) = &params.direction {
             query_args.push(("direction".to_string(), direction.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1838,44 +2520,113 @@ impl Teams {
     }
 
     /**
-     * Create a discussion comment (Legacy).
-     *
-     * This function performs a `POST` to the `/teams/{team_id}/discussions/{discussion_number}/comments` endpoint.
-     *
-     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [Create a discussion comment](https://docs.github.com/rest/reference/teams#create-a-discussion-comment) endpoint.
-     *
-     * Creates a new comment on a team discussion. OAuth access tokens require the `write:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
-     *
-     * This endpoint triggers [notifications](https://docs.github.com/en/github/managing-subscriptions-and-notifications-on-github/about-notifications). Creating content too quickly using this endpoint may result in secondary rate limiting. See "[Secondary rate limits](https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits)" and "[Dealing with secondary rate limits](https://docs.github.com/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits)" for details.
-     *
-     * FROM: <https://docs.github.com/rest/reference/teams#create-a-discussion-comment-legacy>
-     *
-     * **Parameters:**
-     *
-     * * `team_id: i64`
-     * * `discussion_number: i64`
+     * Deprecated: use `list_all_discussion_comments_legacy_with` and pass the query parameters as a `TeamsListDiscussionCommentsLegacyParams` instead.
      */
-    pub async fn create_discussion_comment_legacy(
+    #[deprecated(note = "use `list_all_discussion_comments_legacy_with` instead")]
+    pub async fn list_all_discussion_comments_legacy(
         &self,
         team_id: i64,
         discussion_number: i64,
-        body: &crate::types::PullsUpdateReviewRequest,
-    ) -> Result<crate::types::TeamDiscussionComment> {
-        let url = format!(
-            "/teams/{}/discussions/{}/comments",
-            crate::progenitor_support::encode_path(&team_id.to_string()),
-            crate::progenitor_support::encode_path(&discussion_number.to_string()),
-        );
-
-        self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+        direction: crate::types::Direction,
+    ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
+        let params = TeamsListDiscussionCommentsLegacyParams {
+            direction: Some(direction).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_discussion_comments_legacy_with(team_id, discussion_number, &params)
             .await
     }
 
     /**
-     * Get a discussion comment (Legacy).
+     * List discussion comments (Legacy).
      *
-     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}` endpoint.
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * As opposed to `list_all_discussion_comments_legacy`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [List discussion comments](https://docs.github.com/rest/reference/teams#list-discussion-comments) endpoint.
+     *
+     * List all comments on a team discussion. OAuth access tokens require the `read:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-discussion-comments-legacy>
+     */
+    pub fn list_discussion_comments_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDiscussionComment> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(direction) = &params.direction {
+            query_args.push(("direction".to_string(), direction.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/teams/{}/discussions/{}/comments?{}",
+            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * Create a discussion comment (Legacy).
+     *
+     * This function performs a `POST` to the `/teams/{team_id}/discussions/{discussion_number}/comments` endpoint.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [Create a discussion comment](https://docs.github.com/rest/reference/teams#create-a-discussion-comment) endpoint.
+     *
+     * Creates a new comment on a team discussion. OAuth access tokens require the `write:discussion` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * This endpoint triggers [notifications](https://docs.github.com/en/github/managing-subscriptions-and-notifications-on-github/about-notifications). Creating content too quickly using this endpoint may result in secondary rate limiting. See "[Secondary rate limits](https://docs.github.com/rest/overview/resources-in-the-rest-api#secondary-rate-limits)" and "[Dealing with secondary rate limits](https://docs.github.com/rest/guides/best-practices-for-integrators#dealing-with-secondary-rate-limits)" for details.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#create-a-discussion-comment-legacy>
+     *
+     * **Parameters:**
+     *
+     * * `team_id: i64`
+     * * `discussion_number: i64`
+     */
+    pub async fn create_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        body: &crate::types::TeamsCreateDiscussionCommentLegacyRequest,
+    ) -> Result<crate::types::TeamDiscussionComment> {
+        let url = format!(
+            "/teams/{}/discussions/{}/comments",
+            crate::progenitor_support::encode_path(&team_id.to_string()),
+            crate::progenitor_support::encode_path(&discussion_number.to_string()),
+        );
+
+        self.client
+            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .await
+    }
+
+    /**
+     * Get a discussion comment (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/discussions/{discussion_number}/comments/{comment_number}` endpoint.
      *
      * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [Get a discussion comment](https://docs.github.com/rest/reference/teams#get-a-discussion-comment) endpoint.
      *
@@ -1960,7 +2711,7 @@ impl Teams {
         team_id: i64,
         discussion_number: i64,
         comment_number: i64,
-        body: &crate::types::PullsUpdateReviewRequest,
+        body: &crate::types::TeamsUpdateDiscussionCommentLegacyRequest,
     ) -> Result<crate::types::TeamDiscussionComment> {
         let url = format!(
             "/teams/{}/discussions/{}/comments/{}",
@@ -1988,20 +2739,18 @@ impl Teams {
      * **Parameters:**
      *
      * * `team_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListPendingInvitationsLegacyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_pending_invitations_legacy(
+    pub async fn list_pending_invitations_legacy_with(
         &self,
         team_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListPendingInvitationsLegacyParams,
     ) -> Result<Vec<crate::types::OrganizationInvitation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2014,6 +2763,24 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_pending_invitations_legacy_with` and pass the query parameters as a `TeamsListPendingInvitationsLegacyParams` instead.
+     */
+    #[deprecated(note = "use `list_pending_invitations_legacy_with` instead")]
+    pub async fn list_pending_invitations_legacy(
+        &self,
+        team_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        let params = TeamsListPendingInvitationsLegacyParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_pending_invitations_legacy_with(team_id, &params)
+            .await
+    }
+
     /**
      * List pending team invitations (Legacy).
      *
@@ -2039,6 +2806,48 @@ impl Teams {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List pending team invitations (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/invitations` endpoint.
+     *
+     * As opposed to `list_all_pending_invitations_legacy`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List pending team invitations`](https://docs.github.com/rest/reference/teams#list-pending-team-invitations) endpoint.
+     *
+     * The return hash contains a `role` field which refers to the Organization Invitation role and will be one of the following values: `direct_member`, `admin`, `billing_manager`, `hiring_manager`, or `reinstate`. If the invitee is not a GitHub member, the `login` field in the return hash will be `null`.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-pending-team-invitations-legacy>
+     */
+    pub fn list_pending_invitations_legacy_stream(
+        &self,
+        team_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationInvitation> {
+        let url = format!(
+            "/teams/{}/invitations",
+            crate::progenitor_support::encode_path(&team_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List team members (Legacy).
      *
@@ -2053,28 +2862,21 @@ impl Teams {
      * **Parameters:**
      *
      * * `team_id: i64`
-     * * `role: crate::types::TeamsListMembersInOrgRole` -- Filters members returned by their role in the team. Can be one of:  
-     *  \\* `member` - normal members of the team.  
-     *  \\* `maintainer` - team maintainers.  
-     *  \\* `all` - all members of the team.
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListMembersLegacyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_members_legacy(
+    pub async fn list_members_legacy_with(
         &self,
         team_id: i64,
-        role: crate::types::TeamsListMembersInOrgRole,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListMembersLegacyParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if !role.to_string().is_empty() {
+        if let Some(role) = &params.role {
             query_args.push(("role".to_string(), role.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2087,6 +2889,25 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_members_legacy_with` and pass the query parameters as a `TeamsListMembersLegacyParams` instead.
+     */
+    #[deprecated(note = "use `list_members_legacy_with` instead")]
+    pub async fn list_members_legacy(
+        &self,
+        team_id: i64,
+        role: crate::types::TeamsListMembersLegacyRole,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = TeamsListMembersLegacyParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+            role: Some(role).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_members_legacy_with(team_id, &params).await
+    }
+
     /**
      * List team members (Legacy).
      *
@@ -2099,14 +2920,18 @@ impl Teams {
      * Team members will include the members of child teams.
      *
      * FROM: <https://docs.github.com/rest/reference/teams#list-team-members-legacy>
+     *
+     * **Parameters:**
+     *
+     * * `params: &TeamsListMembersLegacyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_members_legacy(
+    pub async fn list_all_members_legacy_with(
         &self,
         team_id: i64,
-        role: crate::types::TeamsListMembersInOrgRole,
+        params: &TeamsListMembersLegacyParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !role.to_string().is_empty() {
+        if let Some(role) = &params.role {
             query_args.push(("role".to_string(), role.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2119,6 +2944,71 @@ impl Teams {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_all_members_legacy_with` and pass the query parameters as a `TeamsListMembersLegacyParams` instead.
+     */
+    #[deprecated(note = "use `list_all_members_legacy_with` instead")]
+    pub async fn list_all_members_legacy(
+        &self,
+        team_id: i64,
+        role: crate::types::TeamsListMembersLegacyRole,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = TeamsListMembersLegacyParams {
+            role: Some(role).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_members_legacy_with(team_id, &params).await
+    }
+
+    /**
+     * List team members (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/members` endpoint.
+     *
+     * As opposed to `list_all_members_legacy`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List team members`](https://docs.github.com/rest/reference/teams#list-team-members) endpoint.
+     *
+     * Team members will include the members of child teams.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-team-members-legacy>
+     */
+    pub fn list_members_legacy_stream(
+        &self,
+        team_id: i64,
+        params: &TeamsListMembersLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(role) = &params.role {
+            query_args.push(("role".to_string(), role.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/teams/{}/members?{}",
+            crate::progenitor_support::encode_path(&team_id.to_string()),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get team member (Legacy).
      *
@@ -2278,7 +3168,7 @@ impl Teams {
         &self,
         team_id: i64,
         username: &str,
-        body: &crate::types::TeamsAddUpdateMembershipUserInOrgRequest,
+        body: &crate::types::TeamsAddUpdateMembershipUserLegacyRequest,
     ) -> Result<crate::types::TeamMembership> {
         let url = format!(
             "/teams/{}/memberships/{}",
@@ -2339,20 +3229,18 @@ impl Teams {
      * **Parameters:**
      *
      * * `team_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListProjectsLegacyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_projects_legacy(
+    pub async fn list_projects_legacy_with(
         &self,
         team_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListProjectsLegacyParams,
     ) -> Result<Vec<crate::types::TeamProject>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2365,6 +3253,23 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_projects_legacy_with` and pass the query parameters as a `TeamsListProjectsLegacyParams` instead.
+     */
+    #[deprecated(note = "use `list_projects_legacy_with` instead")]
+    pub async fn list_projects_legacy(
+        &self,
+        team_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::TeamProject>> {
+        let params = TeamsListProjectsLegacyParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_projects_legacy_with(team_id, &params).await
+    }
+
     /**
      * List team projects (Legacy).
      *
@@ -2390,6 +3295,48 @@ impl Teams {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List team projects (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/projects` endpoint.
+     *
+     * As opposed to `list_all_projects_legacy`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List team projects`](https://docs.github.com/rest/reference/teams#list-team-projects) endpoint.
+     *
+     * Lists the organization projects for a team.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-projects-legacy>
+     */
+    pub fn list_projects_legacy_stream(
+        &self,
+        team_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamProject> {
+        let url = format!(
+            "/teams/{}/projects",
+            crate::progenitor_support::encode_path(&team_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Check team permissions for a project (Legacy).
      *
@@ -2491,20 +3438,18 @@ impl Teams {
      * **Parameters:**
      *
      * * `team_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListReposLegacyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_repos_legacy(
+    pub async fn list_repos_legacy_with(
         &self,
         team_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListReposLegacyParams,
     ) -> Result<Vec<crate::types::MinimalRepository>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2517,6 +3462,23 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_repos_legacy_with` and pass the query parameters as a `TeamsListReposLegacyParams` instead.
+     */
+    #[deprecated(note = "use `list_repos_legacy_with` instead")]
+    pub async fn list_repos_legacy(
+        &self,
+        team_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        let params = TeamsListReposLegacyParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_repos_legacy_with(team_id, &params).await
+    }
+
     /**
      * List team repositories (Legacy).
      *
@@ -2540,6 +3502,46 @@ impl Teams {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List team repositories (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/repos` endpoint.
+     *
+     * As opposed to `list_all_repos_legacy`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [List team repositories](https://docs.github.com/rest/reference/teams#list-team-repositories) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-team-repositories-legacy>
+     */
+    pub fn list_repos_legacy_stream(
+        &self,
+        team_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        let url = format!(
+            "/teams/{}/repos",
+            crate::progenitor_support::encode_path(&team_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Check team permissions for a repository (Legacy).
      *
@@ -2714,20 +3716,18 @@ impl Teams {
      * **Parameters:**
      *
      * * `team_id: i64`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListChildLegacyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_child_legacy(
+    pub async fn list_child_legacy_with(
         &self,
         team_id: i64,
-        per_page: i64,
-        page: i64,
+        params: &TeamsListChildLegacyParams,
     ) -> Result<Vec<crate::types::Team>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2740,6 +3740,23 @@ impl Teams {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_child_legacy_with` and pass the query parameters as a `TeamsListChildLegacyParams` instead.
+     */
+    #[deprecated(note = "use `list_child_legacy_with` instead")]
+    pub async fn list_child_legacy(
+        &self,
+        team_id: i64,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Team>> {
+        let params = TeamsListChildLegacyParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_child_legacy_with(team_id, &params).await
+    }
+
     /**
      * List child teams (Legacy).
      *
@@ -2760,6 +3777,46 @@ impl Teams {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List child teams (Legacy).
+     *
+     * This function performs a `GET` to the `/teams/{team_id}/teams` endpoint.
+     *
+     * As opposed to `list_all_child_legacy`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **Deprecation Notice:** This endpoint route is deprecated and will be removed from the Teams API. We recommend migrating your existing code to use the new [`List child teams`](https://docs.github.com/rest/reference/teams#list-child-teams) endpoint.
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams/#list-child-teams-legacy>
+     */
+    pub fn list_child_legacy_stream(
+        &self,
+        team_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team> {
+        let url = format!(
+            "/teams/{}/teams",
+            crate::progenitor_support::encode_path(&team_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List teams for the authenticated user.
      *
@@ -2771,19 +3828,17 @@ impl Teams {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &TeamsListForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_for_authenticated_user(
+    pub async fn list_for_authenticated_user_with(
         &self,
-        per_page: i64,
-        page: i64,
-    ) -> Result<Vec<crate::types::FullTeam>> {
+        params: &TeamsListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::TeamFull>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2793,18 +3848,2044 @@ impl Teams {
     }
 
     /**
-     * List teams for the authenticated user.
-     *
-     * This function performs a `GET` to the `/user/teams` endpoint.
-     *
+     * Deprecated: use `list_for_authenticated_user_with` and pass the query parameters as a `TeamsListForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_for_authenticated_user_with` instead")]
+    pub async fn list_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::TeamFull>> {
+        let params = TeamsListForAuthenticatedUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_for_authenticated_user_with(&params).await
+    }
+
+    /**
+     * List teams for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/teams` endpoint.
+     *
      * As opposed to `list_for_authenticated_user`, this function returns all the pages of the request at once.
      *
      * List all of the teams across all of the organizations to which the authenticated user belongs. This method requires `user`, `repo`, or `read:org` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/) when authenticating via [OAuth](https://docs.github.com/apps/building-oauth-apps/).
      *
      * FROM: <https://docs.github.com/rest/reference/teams#list-teams-for-the-authenticated-user>
      */
-    pub async fn list_all_for_authenticated_user(&self) -> Result<Vec<crate::types::FullTeam>> {
+    pub async fn list_all_for_authenticated_user(&self) -> Result<Vec<crate::types::TeamFull>> {
         let url = "/user/teams".to_string();
         self.client.get_all_pages(&url, None).await
     }
+
+    /**
+     * List teams for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/teams` endpoint.
+     *
+     * As opposed to `list_all_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List all of the teams across all of the organizations to which the authenticated user belongs. This method requires `user`, `repo`, or `read:org` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/) when authenticating via [OAuth](https://docs.github.com/apps/building-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/teams#list-teams-for-the-authenticated-user>
+     */
+    pub fn list_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamFull> {
+        let url = "/user/teams".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Teams::list_idp_groups_for_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListIdpGroupsForOrgParams {
+    /// Page token.
+    pub page: Option<String>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListIdpGroupsForOrgParams {
+    pub fn page<T: ToString>(mut self, page: T) -> Self {
+        self.page = Some(page.to_string());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_discussions_in_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListDiscussionsInOrgParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Pinned discussions only filter.
+    pub pinned: Option<String>,
+}
+
+impl TeamsListDiscussionsInOrgParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn pinned<T: ToString>(mut self, pinned: T) -> Self {
+        self.pinned = Some(pinned.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_discussion_comments_in_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListDiscussionCommentsInOrgParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListDiscussionCommentsInOrgParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_pending_invitations_in_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListPendingInvitationsInOrgParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListPendingInvitationsInOrgParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_members_in_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListMembersInOrgParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Filters members returned by their role in the team. Can be one of:  
+    /// \\* `member` - normal members of the team.  
+    /// \\* `maintainer` - team maintainers.  
+    /// \\* `all` - all members of the team.
+    pub role: Option<crate::types::TeamsListMembersInOrgRole>,
+}
+
+impl TeamsListMembersInOrgParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn role(mut self, role: crate::types::TeamsListMembersInOrgRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_projects_in_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListProjectsInOrgParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListProjectsInOrgParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_repos_in_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListReposInOrgParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListReposInOrgParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_child_in_org_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListChildInOrgParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListChildInOrgParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_discussions_legacy_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListDiscussionsLegacyParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListDiscussionsLegacyParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_discussion_comments_legacy_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListDiscussionCommentsLegacyParams {
+    /// Can be one of `asc` or `desc`. Default: `asc` when using `full_name`, otherwise `desc`.
+    pub direction: Option<crate::types::Direction>,
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListDiscussionCommentsLegacyParams {
+    pub fn direction(mut self, direction: crate::types::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_pending_invitations_legacy_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListPendingInvitationsLegacyParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListPendingInvitationsLegacyParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_members_legacy_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListMembersLegacyParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// Filters members returned by their role in the team. Can be one of:  
+    /// \\* `member` - normal members of the team.  
+    /// \\* `maintainer` - team maintainers.  
+    /// \\* `all` - all members of the team.
+    pub role: Option<crate::types::TeamsListMembersLegacyRole>,
+}
+
+impl TeamsListMembersLegacyParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn role(mut self, role: crate::types::TeamsListMembersLegacyRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_projects_legacy_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListProjectsLegacyParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListProjectsLegacyParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_repos_legacy_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListReposLegacyParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListReposLegacyParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_child_legacy_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListChildLegacyParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListChildLegacyParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Teams::list_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamsListForAuthenticatedUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl TeamsListForAuthenticatedUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The functions of [`Teams`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTeamsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TeamsApi: Send + Sync {
+    /// See [`Teams::list_idp_groups_for_org_with`].
+    async fn list_idp_groups_for_org_with(
+        &self,
+        org: &str,
+        params: &TeamsListIdpGroupsForOrgParams,
+    ) -> Result<crate::types::GroupMapping>;
+
+    /// See [`Teams::list_with`].
+    async fn list_with(
+        &self,
+        org: &str,
+        params: &TeamsListParams,
+    ) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Teams::list_all`].
+    async fn list_all(&self, org: &str) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Teams::list_stream`].
+    fn list_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team>;
+
+    /// See [`Teams::create`].
+    async fn create(
+        &self,
+        org: &str,
+        body: &crate::types::TeamsCreateRequest,
+    ) -> Result<crate::types::TeamFull>;
+
+    /// See [`Teams::get_by_name`].
+    async fn get_by_name(&self, org: &str, team_slug: &str) -> Result<crate::types::TeamFull>;
+
+    /// See [`Teams::delete_in_org`].
+    async fn delete_in_org(&self, org: &str, team_slug: &str) -> Result<()>;
+
+    /// See [`Teams::update_in_org`].
+    async fn update_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        body: &crate::types::TeamsUpdateInOrgRequest,
+    ) -> Result<crate::types::TeamFull>;
+
+    /// See [`Teams::list_discussions_in_org_with`].
+    async fn list_discussions_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListDiscussionsInOrgParams,
+    ) -> Result<Vec<crate::types::TeamDiscussion>>;
+
+    /// See [`Teams::list_all_discussions_in_org_with`].
+    async fn list_all_discussions_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListDiscussionsInOrgParams,
+    ) -> Result<Vec<crate::types::TeamDiscussion>>;
+
+    /// See [`Teams::list_discussions_in_org_stream`].
+    fn list_discussions_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListDiscussionsInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDiscussion>;
+
+    /// See [`Teams::create_discussion_in_org`].
+    async fn create_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        body: &crate::types::TeamsCreateDiscussionInOrgRequest,
+    ) -> Result<crate::types::TeamDiscussion>;
+
+    /// See [`Teams::get_discussion_in_org`].
+    async fn get_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> Result<crate::types::TeamDiscussion>;
+
+    /// See [`Teams::delete_discussion_in_org`].
+    async fn delete_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> Result<()>;
+
+    /// See [`Teams::update_discussion_in_org`].
+    async fn update_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
+    ) -> Result<crate::types::TeamDiscussion>;
+
+    /// See [`Teams::list_discussion_comments_in_org_with`].
+    async fn list_discussion_comments_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsInOrgParams,
+    ) -> Result<Vec<crate::types::TeamDiscussionComment>>;
+
+    /// See [`Teams::list_all_discussion_comments_in_org_with`].
+    async fn list_all_discussion_comments_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsInOrgParams,
+    ) -> Result<Vec<crate::types::TeamDiscussionComment>>;
+
+    /// See [`Teams::list_discussion_comments_in_org_stream`].
+    fn list_discussion_comments_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDiscussionComment>;
+
+    /// See [`Teams::create_discussion_comment_in_org`].
+    async fn create_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        body: &crate::types::TeamsCreateDiscussionCommentInOrgRequest,
+    ) -> Result<crate::types::TeamDiscussionComment>;
+
+    /// See [`Teams::get_discussion_comment_in_org`].
+    async fn get_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> Result<crate::types::TeamDiscussionComment>;
+
+    /// See [`Teams::delete_discussion_comment_in_org`].
+    async fn delete_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> Result<()>;
+
+    /// See [`Teams::update_discussion_comment_in_org`].
+    async fn update_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::TeamsUpdateDiscussionCommentInOrgRequest,
+    ) -> Result<crate::types::TeamDiscussionComment>;
+
+    /// See [`Teams::list_pending_invitations_in_org_with`].
+    async fn list_pending_invitations_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListPendingInvitationsInOrgParams,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>>;
+
+    /// See [`Teams::list_all_pending_invitations_in_org`].
+    async fn list_all_pending_invitations_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>>;
+
+    /// See [`Teams::list_pending_invitations_in_org_stream`].
+    fn list_pending_invitations_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationInvitation>;
+
+    /// See [`Teams::list_members_in_org_with`].
+    async fn list_members_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListMembersInOrgParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Teams::list_all_members_in_org_with`].
+    async fn list_all_members_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListMembersInOrgParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Teams::list_members_in_org_stream`].
+    fn list_members_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListMembersInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Teams::get_membership_for_user_in_org`].
+    async fn get_membership_for_user_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+    ) -> Result<crate::types::TeamMembership>;
+
+    /// See [`Teams::add_or_update_membership_for_user_in_org`].
+    async fn add_or_update_membership_for_user_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+        body: &crate::types::TeamsAddUpdateMembershipUserInOrgRequest,
+    ) -> Result<crate::types::TeamMembership>;
+
+    /// See [`Teams::remove_membership_for_user_in_org`].
+    async fn remove_membership_for_user_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+    ) -> Result<()>;
+
+    /// See [`Teams::list_projects_in_org_with`].
+    async fn list_projects_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListProjectsInOrgParams,
+    ) -> Result<Vec<crate::types::TeamProject>>;
+
+    /// See [`Teams::list_all_projects_in_org`].
+    async fn list_all_projects_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> Result<Vec<crate::types::TeamProject>>;
+
+    /// See [`Teams::list_projects_in_org_stream`].
+    fn list_projects_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamProject>;
+
+    /// See [`Teams::check_permissions_for_project_in_org`].
+    async fn check_permissions_for_project_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+    ) -> Result<crate::types::TeamProject>;
+
+    /// See [`Teams::add_or_update_project_permissions_in_org`].
+    async fn add_or_update_project_permissions_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+        body: &crate::types::TeamsAddUpdateProjectPermissionsInOrgRequest,
+    ) -> Result<()>;
+
+    /// See [`Teams::remove_project_in_org`].
+    async fn remove_project_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+    ) -> Result<()>;
+
+    /// See [`Teams::list_repos_in_org_with`].
+    async fn list_repos_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListReposInOrgParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Teams::list_all_repos_in_org`].
+    async fn list_all_repos_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Teams::list_repos_in_org_stream`].
+    fn list_repos_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository>;
+
+    /// See [`Teams::check_permissions_for_repo_in_org`].
+    async fn check_permissions_for_repo_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::TeamRepository>;
+
+    /// See [`Teams::add_or_update_repo_permissions_in_org`].
+    async fn add_or_update_repo_permissions_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::TeamsAddUpdateRepoPermissionsInOrgRequest,
+    ) -> Result<()>;
+
+    /// See [`Teams::remove_repo_in_org`].
+    async fn remove_repo_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<()>;
+
+    /// See [`Teams::list_idp_groups_in_org`].
+    async fn list_idp_groups_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> Result<crate::types::GroupMapping>;
+
+    /// See [`Teams::create_or_update_idp_group_connections_in_org`].
+    async fn create_or_update_idp_group_connections_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        body: &crate::types::TeamsCreateUpdateIdpGroupConnectionsInOrgRequest,
+    ) -> Result<crate::types::GroupMapping>;
+
+    /// See [`Teams::list_child_in_org_with`].
+    async fn list_child_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListChildInOrgParams,
+    ) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Teams::list_all_child_in_org`].
+    async fn list_all_child_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Teams::list_child_in_org_stream`].
+    fn list_child_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team>;
+
+    /// See [`Teams::get_legacy`].
+    async fn get_legacy(&self, team_id: i64) -> Result<crate::types::TeamFull>;
+
+    /// See [`Teams::delete_legacy`].
+    async fn delete_legacy(&self, team_id: i64) -> Result<()>;
+
+    /// See [`Teams::update_legacy`].
+    async fn update_legacy(
+        &self,
+        team_id: i64,
+        body: &crate::types::TeamsUpdateLegacyRequest,
+    ) -> Result<crate::types::TeamFull>;
+
+    /// See [`Teams::list_discussions_legacy_with`].
+    async fn list_discussions_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListDiscussionsLegacyParams,
+    ) -> Result<Vec<crate::types::TeamDiscussion>>;
+
+    /// See [`Teams::list_all_discussions_legacy_with`].
+    async fn list_all_discussions_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListDiscussionsLegacyParams,
+    ) -> Result<Vec<crate::types::TeamDiscussion>>;
+
+    /// See [`Teams::list_discussions_legacy_stream`].
+    fn list_discussions_legacy_stream(
+        &self,
+        team_id: i64,
+        params: &TeamsListDiscussionsLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDiscussion>;
+
+    /// See [`Teams::create_discussion_legacy`].
+    async fn create_discussion_legacy(
+        &self,
+        team_id: i64,
+        body: &crate::types::TeamsCreateDiscussionLegacyRequest,
+    ) -> Result<crate::types::TeamDiscussion>;
+
+    /// See [`Teams::get_discussion_legacy`].
+    async fn get_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> Result<crate::types::TeamDiscussion>;
+
+    /// See [`Teams::delete_discussion_legacy`].
+    async fn delete_discussion_legacy(&self, team_id: i64, discussion_number: i64) -> Result<()>;
+
+    /// See [`Teams::update_discussion_legacy`].
+    async fn update_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        body: &crate::types::TeamsUpdateDiscussionLegacyRequest,
+    ) -> Result<crate::types::TeamDiscussion>;
+
+    /// See [`Teams::list_discussion_comments_legacy_with`].
+    async fn list_discussion_comments_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsLegacyParams,
+    ) -> Result<Vec<crate::types::TeamDiscussionComment>>;
+
+    /// See [`Teams::list_all_discussion_comments_legacy_with`].
+    async fn list_all_discussion_comments_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsLegacyParams,
+    ) -> Result<Vec<crate::types::TeamDiscussionComment>>;
+
+    /// See [`Teams::list_discussion_comments_legacy_stream`].
+    fn list_discussion_comments_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDiscussionComment>;
+
+    /// See [`Teams::create_discussion_comment_legacy`].
+    async fn create_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        body: &crate::types::TeamsCreateDiscussionCommentLegacyRequest,
+    ) -> Result<crate::types::TeamDiscussionComment>;
+
+    /// See [`Teams::get_discussion_comment_legacy`].
+    async fn get_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> Result<crate::types::TeamDiscussionComment>;
+
+    /// See [`Teams::delete_discussion_comment_legacy`].
+    async fn delete_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> Result<()>;
+
+    /// See [`Teams::update_discussion_comment_legacy`].
+    async fn update_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::TeamsUpdateDiscussionCommentLegacyRequest,
+    ) -> Result<crate::types::TeamDiscussionComment>;
+
+    /// See [`Teams::list_pending_invitations_legacy_with`].
+    async fn list_pending_invitations_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListPendingInvitationsLegacyParams,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>>;
+
+    /// See [`Teams::list_all_pending_invitations_legacy`].
+    async fn list_all_pending_invitations_legacy(
+        &self,
+        team_id: i64,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>>;
+
+    /// See [`Teams::list_pending_invitations_legacy_stream`].
+    fn list_pending_invitations_legacy_stream(
+        &self,
+        team_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationInvitation>;
+
+    /// See [`Teams::list_members_legacy_with`].
+    async fn list_members_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListMembersLegacyParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Teams::list_all_members_legacy_with`].
+    async fn list_all_members_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListMembersLegacyParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Teams::list_members_legacy_stream`].
+    fn list_members_legacy_stream(
+        &self,
+        team_id: i64,
+        params: &TeamsListMembersLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Teams::get_member_legacy`].
+    async fn get_member_legacy(&self, team_id: i64, username: &str) -> Result<()>;
+
+    /// See [`Teams::add_member_legacy`].
+    async fn add_member_legacy(&self, team_id: i64, username: &str) -> Result<()>;
+
+    /// See [`Teams::remove_member_legacy`].
+    async fn remove_member_legacy(&self, team_id: i64, username: &str) -> Result<()>;
+
+    /// See [`Teams::get_membership_for_user_legacy`].
+    async fn get_membership_for_user_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> Result<crate::types::TeamMembership>;
+
+    /// See [`Teams::add_or_update_membership_for_user_legacy`].
+    async fn add_or_update_membership_for_user_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+        body: &crate::types::TeamsAddUpdateMembershipUserLegacyRequest,
+    ) -> Result<crate::types::TeamMembership>;
+
+    /// See [`Teams::remove_membership_for_user_legacy`].
+    async fn remove_membership_for_user_legacy(&self, team_id: i64, username: &str) -> Result<()>;
+
+    /// See [`Teams::list_projects_legacy_with`].
+    async fn list_projects_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListProjectsLegacyParams,
+    ) -> Result<Vec<crate::types::TeamProject>>;
+
+    /// See [`Teams::list_all_projects_legacy`].
+    async fn list_all_projects_legacy(
+        &self,
+        team_id: i64,
+    ) -> Result<Vec<crate::types::TeamProject>>;
+
+    /// See [`Teams::list_projects_legacy_stream`].
+    fn list_projects_legacy_stream(
+        &self,
+        team_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamProject>;
+
+    /// See [`Teams::check_permissions_for_project_legacy`].
+    async fn check_permissions_for_project_legacy(
+        &self,
+        team_id: i64,
+        project_id: i64,
+    ) -> Result<crate::types::TeamProject>;
+
+    /// See [`Teams::add_or_update_project_permissions_legacy`].
+    async fn add_or_update_project_permissions_legacy(
+        &self,
+        team_id: i64,
+        project_id: i64,
+        body: &crate::types::TeamsAddUpdateProjectPermissionsLegacyRequest,
+    ) -> Result<()>;
+
+    /// See [`Teams::remove_project_legacy`].
+    async fn remove_project_legacy(&self, team_id: i64, project_id: i64) -> Result<()>;
+
+    /// See [`Teams::list_repos_legacy_with`].
+    async fn list_repos_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListReposLegacyParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Teams::list_all_repos_legacy`].
+    async fn list_all_repos_legacy(
+        &self,
+        team_id: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>>;
+
+    /// See [`Teams::list_repos_legacy_stream`].
+    fn list_repos_legacy_stream(
+        &self,
+        team_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository>;
+
+    /// See [`Teams::check_permissions_for_repo_legacy`].
+    async fn check_permissions_for_repo_legacy(
+        &self,
+        team_id: i64,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::TeamRepository>;
+
+    /// See [`Teams::add_or_update_repo_permissions_legacy`].
+    async fn add_or_update_repo_permissions_legacy(
+        &self,
+        team_id: i64,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::TeamsAddUpdateRepoPermissionsLegacyRequest,
+    ) -> Result<()>;
+
+    /// See [`Teams::remove_repo_legacy`].
+    async fn remove_repo_legacy(&self, team_id: i64, owner: &str, repo: &str) -> Result<()>;
+
+    /// See [`Teams::list_idp_groups_for_legacy`].
+    async fn list_idp_groups_for_legacy(&self, team_id: i64) -> Result<crate::types::GroupMapping>;
+
+    /// See [`Teams::create_or_update_idp_group_connections_legacy`].
+    async fn create_or_update_idp_group_connections_legacy(
+        &self,
+        team_id: i64,
+        body: &crate::types::TeamsCreateUpdateIdpGroupConnectionsLegacyRequest,
+    ) -> Result<crate::types::GroupMapping>;
+
+    /// See [`Teams::list_child_legacy_with`].
+    async fn list_child_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListChildLegacyParams,
+    ) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Teams::list_all_child_legacy`].
+    async fn list_all_child_legacy(&self, team_id: i64) -> Result<Vec<crate::types::Team>>;
+
+    /// See [`Teams::list_child_legacy_stream`].
+    fn list_child_legacy_stream(
+        &self,
+        team_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team>;
+
+    /// See [`Teams::list_for_authenticated_user_with`].
+    async fn list_for_authenticated_user_with(
+        &self,
+        params: &TeamsListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::TeamFull>>;
+
+    /// See [`Teams::list_all_for_authenticated_user`].
+    async fn list_all_for_authenticated_user(&self) -> Result<Vec<crate::types::TeamFull>>;
+
+    /// See [`Teams::list_for_authenticated_user_stream`].
+    fn list_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamFull>;
+}
+
+#[async_trait::async_trait]
+impl TeamsApi for Teams {
+    async fn list_idp_groups_for_org_with(
+        &self,
+        org: &str,
+        params: &TeamsListIdpGroupsForOrgParams,
+    ) -> Result<crate::types::GroupMapping> {
+        Teams::list_idp_groups_for_org_with(self, org, params).await
+    }
+
+    async fn list_with(
+        &self,
+        org: &str,
+        params: &TeamsListParams,
+    ) -> Result<Vec<crate::types::Team>> {
+        Teams::list_with(self, org, params).await
+    }
+
+    async fn list_all(&self, org: &str) -> Result<Vec<crate::types::Team>> {
+        Teams::list_all(self, org).await
+    }
+
+    fn list_stream(
+        &self,
+        org: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team> {
+        Teams::list_stream(self, org, start, max_items)
+    }
+
+    async fn create(
+        &self,
+        org: &str,
+        body: &crate::types::TeamsCreateRequest,
+    ) -> Result<crate::types::TeamFull> {
+        Teams::create(self, org, body).await
+    }
+
+    async fn get_by_name(&self, org: &str, team_slug: &str) -> Result<crate::types::TeamFull> {
+        Teams::get_by_name(self, org, team_slug).await
+    }
+
+    async fn delete_in_org(&self, org: &str, team_slug: &str) -> Result<()> {
+        Teams::delete_in_org(self, org, team_slug).await
+    }
+
+    async fn update_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        body: &crate::types::TeamsUpdateInOrgRequest,
+    ) -> Result<crate::types::TeamFull> {
+        Teams::update_in_org(self, org, team_slug, body).await
+    }
+
+    async fn list_discussions_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListDiscussionsInOrgParams,
+    ) -> Result<Vec<crate::types::TeamDiscussion>> {
+        Teams::list_discussions_in_org_with(self, org, team_slug, params).await
+    }
+
+    async fn list_all_discussions_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListDiscussionsInOrgParams,
+    ) -> Result<Vec<crate::types::TeamDiscussion>> {
+        Teams::list_all_discussions_in_org_with(self, org, team_slug, params).await
+    }
+
+    fn list_discussions_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListDiscussionsInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDiscussion> {
+        Teams::list_discussions_in_org_stream(self, org, team_slug, params, start, max_items)
+    }
+
+    async fn create_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        body: &crate::types::TeamsCreateDiscussionInOrgRequest,
+    ) -> Result<crate::types::TeamDiscussion> {
+        Teams::create_discussion_in_org(self, org, team_slug, body).await
+    }
+
+    async fn get_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> Result<crate::types::TeamDiscussion> {
+        Teams::get_discussion_in_org(self, org, team_slug, discussion_number).await
+    }
+
+    async fn delete_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+    ) -> Result<()> {
+        Teams::delete_discussion_in_org(self, org, team_slug, discussion_number).await
+    }
+
+    async fn update_discussion_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        body: &crate::types::TeamsUpdateDiscussionInOrgRequest,
+    ) -> Result<crate::types::TeamDiscussion> {
+        Teams::update_discussion_in_org(self, org, team_slug, discussion_number, body).await
+    }
+
+    async fn list_discussion_comments_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsInOrgParams,
+    ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
+        Teams::list_discussion_comments_in_org_with(self, org, team_slug, discussion_number, params)
+            .await
+    }
+
+    async fn list_all_discussion_comments_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsInOrgParams,
+    ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
+        Teams::list_all_discussion_comments_in_org_with(
+            self,
+            org,
+            team_slug,
+            discussion_number,
+            params,
+        )
+        .await
+    }
+
+    fn list_discussion_comments_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDiscussionComment> {
+        Teams::list_discussion_comments_in_org_stream(
+            self,
+            org,
+            team_slug,
+            discussion_number,
+            params,
+            start,
+            max_items,
+        )
+    }
+
+    async fn create_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        body: &crate::types::TeamsCreateDiscussionCommentInOrgRequest,
+    ) -> Result<crate::types::TeamDiscussionComment> {
+        Teams::create_discussion_comment_in_org(self, org, team_slug, discussion_number, body).await
+    }
+
+    async fn get_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> Result<crate::types::TeamDiscussionComment> {
+        Teams::get_discussion_comment_in_org(
+            self,
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+        )
+        .await
+    }
+
+    async fn delete_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> Result<()> {
+        Teams::delete_discussion_comment_in_org(
+            self,
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+        )
+        .await
+    }
+
+    async fn update_discussion_comment_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::TeamsUpdateDiscussionCommentInOrgRequest,
+    ) -> Result<crate::types::TeamDiscussionComment> {
+        Teams::update_discussion_comment_in_org(
+            self,
+            org,
+            team_slug,
+            discussion_number,
+            comment_number,
+            body,
+        )
+        .await
+    }
+
+    async fn list_pending_invitations_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListPendingInvitationsInOrgParams,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        Teams::list_pending_invitations_in_org_with(self, org, team_slug, params).await
+    }
+
+    async fn list_all_pending_invitations_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        Teams::list_all_pending_invitations_in_org(self, org, team_slug).await
+    }
+
+    fn list_pending_invitations_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationInvitation> {
+        Teams::list_pending_invitations_in_org_stream(self, org, team_slug, start, max_items)
+    }
+
+    async fn list_members_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListMembersInOrgParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Teams::list_members_in_org_with(self, org, team_slug, params).await
+    }
+
+    async fn list_all_members_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListMembersInOrgParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Teams::list_all_members_in_org_with(self, org, team_slug, params).await
+    }
+
+    fn list_members_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListMembersInOrgParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Teams::list_members_in_org_stream(self, org, team_slug, params, start, max_items)
+    }
+
+    async fn get_membership_for_user_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+    ) -> Result<crate::types::TeamMembership> {
+        Teams::get_membership_for_user_in_org(self, org, team_slug, username).await
+    }
+
+    async fn add_or_update_membership_for_user_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+        body: &crate::types::TeamsAddUpdateMembershipUserInOrgRequest,
+    ) -> Result<crate::types::TeamMembership> {
+        Teams::add_or_update_membership_for_user_in_org(self, org, team_slug, username, body).await
+    }
+
+    async fn remove_membership_for_user_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        username: &str,
+    ) -> Result<()> {
+        Teams::remove_membership_for_user_in_org(self, org, team_slug, username).await
+    }
+
+    async fn list_projects_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListProjectsInOrgParams,
+    ) -> Result<Vec<crate::types::TeamProject>> {
+        Teams::list_projects_in_org_with(self, org, team_slug, params).await
+    }
+
+    async fn list_all_projects_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> Result<Vec<crate::types::TeamProject>> {
+        Teams::list_all_projects_in_org(self, org, team_slug).await
+    }
+
+    fn list_projects_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamProject> {
+        Teams::list_projects_in_org_stream(self, org, team_slug, start, max_items)
+    }
+
+    async fn check_permissions_for_project_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+    ) -> Result<crate::types::TeamProject> {
+        Teams::check_permissions_for_project_in_org(self, org, team_slug, project_id).await
+    }
+
+    async fn add_or_update_project_permissions_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+        body: &crate::types::TeamsAddUpdateProjectPermissionsInOrgRequest,
+    ) -> Result<()> {
+        Teams::add_or_update_project_permissions_in_org(self, org, team_slug, project_id, body)
+            .await
+    }
+
+    async fn remove_project_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        project_id: i64,
+    ) -> Result<()> {
+        Teams::remove_project_in_org(self, org, team_slug, project_id).await
+    }
+
+    async fn list_repos_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListReposInOrgParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Teams::list_repos_in_org_with(self, org, team_slug, params).await
+    }
+
+    async fn list_all_repos_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Teams::list_all_repos_in_org(self, org, team_slug).await
+    }
+
+    fn list_repos_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        Teams::list_repos_in_org_stream(self, org, team_slug, start, max_items)
+    }
+
+    async fn check_permissions_for_repo_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::TeamRepository> {
+        Teams::check_permissions_for_repo_in_org(self, org, team_slug, owner, repo).await
+    }
+
+    async fn add_or_update_repo_permissions_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::TeamsAddUpdateRepoPermissionsInOrgRequest,
+    ) -> Result<()> {
+        Teams::add_or_update_repo_permissions_in_org(self, org, team_slug, owner, repo, body).await
+    }
+
+    async fn remove_repo_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        owner: &str,
+        repo: &str,
+    ) -> Result<()> {
+        Teams::remove_repo_in_org(self, org, team_slug, owner, repo).await
+    }
+
+    async fn list_idp_groups_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> Result<crate::types::GroupMapping> {
+        Teams::list_idp_groups_in_org(self, org, team_slug).await
+    }
+
+    async fn create_or_update_idp_group_connections_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+        body: &crate::types::TeamsCreateUpdateIdpGroupConnectionsInOrgRequest,
+    ) -> Result<crate::types::GroupMapping> {
+        Teams::create_or_update_idp_group_connections_in_org(self, org, team_slug, body).await
+    }
+
+    async fn list_child_in_org_with(
+        &self,
+        org: &str,
+        team_slug: &str,
+        params: &TeamsListChildInOrgParams,
+    ) -> Result<Vec<crate::types::Team>> {
+        Teams::list_child_in_org_with(self, org, team_slug, params).await
+    }
+
+    async fn list_all_child_in_org(
+        &self,
+        org: &str,
+        team_slug: &str,
+    ) -> Result<Vec<crate::types::Team>> {
+        Teams::list_all_child_in_org(self, org, team_slug).await
+    }
+
+    fn list_child_in_org_stream(
+        &self,
+        org: &str,
+        team_slug: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team> {
+        Teams::list_child_in_org_stream(self, org, team_slug, start, max_items)
+    }
+
+    async fn get_legacy(&self, team_id: i64) -> Result<crate::types::TeamFull> {
+        Teams::get_legacy(self, team_id).await
+    }
+
+    async fn delete_legacy(&self, team_id: i64) -> Result<()> {
+        Teams::delete_legacy(self, team_id).await
+    }
+
+    async fn update_legacy(
+        &self,
+        team_id: i64,
+        body: &crate::types::TeamsUpdateLegacyRequest,
+    ) -> Result<crate::types::TeamFull> {
+        Teams::update_legacy(self, team_id, body).await
+    }
+
+    async fn list_discussions_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListDiscussionsLegacyParams,
+    ) -> Result<Vec<crate::types::TeamDiscussion>> {
+        Teams::list_discussions_legacy_with(self, team_id, params).await
+    }
+
+    async fn list_all_discussions_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListDiscussionsLegacyParams,
+    ) -> Result<Vec<crate::types::TeamDiscussion>> {
+        Teams::list_all_discussions_legacy_with(self, team_id, params).await
+    }
+
+    fn list_discussions_legacy_stream(
+        &self,
+        team_id: i64,
+        params: &TeamsListDiscussionsLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDiscussion> {
+        Teams::list_discussions_legacy_stream(self, team_id, params, start, max_items)
+    }
+
+    async fn create_discussion_legacy(
+        &self,
+        team_id: i64,
+        body: &crate::types::TeamsCreateDiscussionLegacyRequest,
+    ) -> Result<crate::types::TeamDiscussion> {
+        Teams::create_discussion_legacy(self, team_id, body).await
+    }
+
+    async fn get_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+    ) -> Result<crate::types::TeamDiscussion> {
+        Teams::get_discussion_legacy(self, team_id, discussion_number).await
+    }
+
+    async fn delete_discussion_legacy(&self, team_id: i64, discussion_number: i64) -> Result<()> {
+        Teams::delete_discussion_legacy(self, team_id, discussion_number).await
+    }
+
+    async fn update_discussion_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        body: &crate::types::TeamsUpdateDiscussionLegacyRequest,
+    ) -> Result<crate::types::TeamDiscussion> {
+        Teams::update_discussion_legacy(self, team_id, discussion_number, body).await
+    }
+
+    async fn list_discussion_comments_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsLegacyParams,
+    ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
+        Teams::list_discussion_comments_legacy_with(self, team_id, discussion_number, params).await
+    }
+
+    async fn list_all_discussion_comments_legacy_with(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsLegacyParams,
+    ) -> Result<Vec<crate::types::TeamDiscussionComment>> {
+        Teams::list_all_discussion_comments_legacy_with(self, team_id, discussion_number, params)
+            .await
+    }
+
+    fn list_discussion_comments_legacy_stream(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        params: &TeamsListDiscussionCommentsLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDiscussionComment> {
+        Teams::list_discussion_comments_legacy_stream(
+            self,
+            team_id,
+            discussion_number,
+            params,
+            start,
+            max_items,
+        )
+    }
+
+    async fn create_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        body: &crate::types::TeamsCreateDiscussionCommentLegacyRequest,
+    ) -> Result<crate::types::TeamDiscussionComment> {
+        Teams::create_discussion_comment_legacy(self, team_id, discussion_number, body).await
+    }
+
+    async fn get_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> Result<crate::types::TeamDiscussionComment> {
+        Teams::get_discussion_comment_legacy(self, team_id, discussion_number, comment_number).await
+    }
+
+    async fn delete_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+    ) -> Result<()> {
+        Teams::delete_discussion_comment_legacy(self, team_id, discussion_number, comment_number)
+            .await
+    }
+
+    async fn update_discussion_comment_legacy(
+        &self,
+        team_id: i64,
+        discussion_number: i64,
+        comment_number: i64,
+        body: &crate::types::TeamsUpdateDiscussionCommentLegacyRequest,
+    ) -> Result<crate::types::TeamDiscussionComment> {
+        Teams::update_discussion_comment_legacy(
+            self,
+            team_id,
+            discussion_number,
+            comment_number,
+            body,
+        )
+        .await
+    }
+
+    async fn list_pending_invitations_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListPendingInvitationsLegacyParams,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        Teams::list_pending_invitations_legacy_with(self, team_id, params).await
+    }
+
+    async fn list_all_pending_invitations_legacy(
+        &self,
+        team_id: i64,
+    ) -> Result<Vec<crate::types::OrganizationInvitation>> {
+        Teams::list_all_pending_invitations_legacy(self, team_id).await
+    }
+
+    fn list_pending_invitations_legacy_stream(
+        &self,
+        team_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrganizationInvitation> {
+        Teams::list_pending_invitations_legacy_stream(self, team_id, start, max_items)
+    }
+
+    async fn list_members_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListMembersLegacyParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Teams::list_members_legacy_with(self, team_id, params).await
+    }
+
+    async fn list_all_members_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListMembersLegacyParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Teams::list_all_members_legacy_with(self, team_id, params).await
+    }
+
+    fn list_members_legacy_stream(
+        &self,
+        team_id: i64,
+        params: &TeamsListMembersLegacyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Teams::list_members_legacy_stream(self, team_id, params, start, max_items)
+    }
+
+    async fn get_member_legacy(&self, team_id: i64, username: &str) -> Result<()> {
+        Teams::get_member_legacy(self, team_id, username).await
+    }
+
+    async fn add_member_legacy(&self, team_id: i64, username: &str) -> Result<()> {
+        Teams::add_member_legacy(self, team_id, username).await
+    }
+
+    async fn remove_member_legacy(&self, team_id: i64, username: &str) -> Result<()> {
+        Teams::remove_member_legacy(self, team_id, username).await
+    }
+
+    async fn get_membership_for_user_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+    ) -> Result<crate::types::TeamMembership> {
+        Teams::get_membership_for_user_legacy(self, team_id, username).await
+    }
+
+    async fn add_or_update_membership_for_user_legacy(
+        &self,
+        team_id: i64,
+        username: &str,
+        body: &crate::types::TeamsAddUpdateMembershipUserLegacyRequest,
+    ) -> Result<crate::types::TeamMembership> {
+        Teams::add_or_update_membership_for_user_legacy(self, team_id, username, body).await
+    }
+
+    async fn remove_membership_for_user_legacy(&self, team_id: i64, username: &str) -> Result<()> {
+        Teams::remove_membership_for_user_legacy(self, team_id, username).await
+    }
+
+    async fn list_projects_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListProjectsLegacyParams,
+    ) -> Result<Vec<crate::types::TeamProject>> {
+        Teams::list_projects_legacy_with(self, team_id, params).await
+    }
+
+    async fn list_all_projects_legacy(
+        &self,
+        team_id: i64,
+    ) -> Result<Vec<crate::types::TeamProject>> {
+        Teams::list_all_projects_legacy(self, team_id).await
+    }
+
+    fn list_projects_legacy_stream(
+        &self,
+        team_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamProject> {
+        Teams::list_projects_legacy_stream(self, team_id, start, max_items)
+    }
+
+    async fn check_permissions_for_project_legacy(
+        &self,
+        team_id: i64,
+        project_id: i64,
+    ) -> Result<crate::types::TeamProject> {
+        Teams::check_permissions_for_project_legacy(self, team_id, project_id).await
+    }
+
+    async fn add_or_update_project_permissions_legacy(
+        &self,
+        team_id: i64,
+        project_id: i64,
+        body: &crate::types::TeamsAddUpdateProjectPermissionsLegacyRequest,
+    ) -> Result<()> {
+        Teams::add_or_update_project_permissions_legacy(self, team_id, project_id, body).await
+    }
+
+    async fn remove_project_legacy(&self, team_id: i64, project_id: i64) -> Result<()> {
+        Teams::remove_project_legacy(self, team_id, project_id).await
+    }
+
+    async fn list_repos_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListReposLegacyParams,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Teams::list_repos_legacy_with(self, team_id, params).await
+    }
+
+    async fn list_all_repos_legacy(
+        &self,
+        team_id: i64,
+    ) -> Result<Vec<crate::types::MinimalRepository>> {
+        Teams::list_all_repos_legacy(self, team_id).await
+    }
+
+    fn list_repos_legacy_stream(
+        &self,
+        team_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MinimalRepository> {
+        Teams::list_repos_legacy_stream(self, team_id, start, max_items)
+    }
+
+    async fn check_permissions_for_repo_legacy(
+        &self,
+        team_id: i64,
+        owner: &str,
+        repo: &str,
+    ) -> Result<crate::types::TeamRepository> {
+        Teams::check_permissions_for_repo_legacy(self, team_id, owner, repo).await
+    }
+
+    async fn add_or_update_repo_permissions_legacy(
+        &self,
+        team_id: i64,
+        owner: &str,
+        repo: &str,
+        body: &crate::types::TeamsAddUpdateRepoPermissionsLegacyRequest,
+    ) -> Result<()> {
+        Teams::add_or_update_repo_permissions_legacy(self, team_id, owner, repo, body).await
+    }
+
+    async fn remove_repo_legacy(&self, team_id: i64, owner: &str, repo: &str) -> Result<()> {
+        Teams::remove_repo_legacy(self, team_id, owner, repo).await
+    }
+
+    async fn list_idp_groups_for_legacy(&self, team_id: i64) -> Result<crate::types::GroupMapping> {
+        Teams::list_idp_groups_for_legacy(self, team_id).await
+    }
+
+    async fn create_or_update_idp_group_connections_legacy(
+        &self,
+        team_id: i64,
+        body: &crate::types::TeamsCreateUpdateIdpGroupConnectionsLegacyRequest,
+    ) -> Result<crate::types::GroupMapping> {
+        Teams::create_or_update_idp_group_connections_legacy(self, team_id, body).await
+    }
+
+    async fn list_child_legacy_with(
+        &self,
+        team_id: i64,
+        params: &TeamsListChildLegacyParams,
+    ) -> Result<Vec<crate::types::Team>> {
+        Teams::list_child_legacy_with(self, team_id, params).await
+    }
+
+    async fn list_all_child_legacy(&self, team_id: i64) -> Result<Vec<crate::types::Team>> {
+        Teams::list_all_child_legacy(self, team_id).await
+    }
+
+    fn list_child_legacy_stream(
+        &self,
+        team_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Team> {
+        Teams::list_child_legacy_stream(self, team_id, start, max_items)
+    }
+
+    async fn list_for_authenticated_user_with(
+        &self,
+        params: &TeamsListForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::TeamFull>> {
+        Teams::list_for_authenticated_user_with(self, params).await
+    }
+
+    async fn list_all_for_authenticated_user(&self) -> Result<Vec<crate::types::TeamFull>> {
+        Teams::list_all_for_authenticated_user(self).await
+    }
+
+    fn list_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamFull> {
+        Teams::list_for_authenticated_user_stream(self, start, max_items)
+    }
 }
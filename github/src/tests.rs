@@ -1 +1,31 @@
+#[test]
+fn test_nullable() {
+    use crate::types::Nullable;
 
+    #[derive(serde::Serialize, serde::Deserialize, Debug, Default, PartialEq)]
+    struct Body {
+        #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+        homepage: Nullable<String>,
+    }
+
+    // Fields that are left out are not sent, `null` clears them.
+    assert_eq!(serde_json::to_string(&Body::default()).unwrap(), "{}");
+    let body = Body {
+        homepage: Nullable::Null,
+    };
+    assert_eq!(
+        serde_json::to_string(&body).unwrap(),
+        r#"{"homepage":null}"#
+    );
+    let body = Body {
+        homepage: "".to_string().into(),
+    };
+    assert_eq!(serde_json::to_string(&body).unwrap(), r#"{"homepage":""}"#);
+
+    let body: Body = serde_json::from_str(r#"{"homepage":null}"#).unwrap();
+    assert_eq!(body.homepage, Nullable::Null);
+    let body: Body = serde_json::from_str("{}").unwrap();
+    assert!(body.homepage.is_absent());
+    let body: Body = serde_json::from_str(r#"{"homepage":"https://github.com"}"#).unwrap();
+    assert_eq!(body.homepage.value().unwrap(), "https://github.com");
+}
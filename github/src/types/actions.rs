@@ -0,0 +1,2300 @@
+//! The data types of the `actions` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SelectedActions {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub github_owned_allowed: bool,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub patterns_allowed: Vec<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub verified_allowed: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Labels {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * The type of label. Read-only labels are applied automatically when the runner is configured.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<Type>,
+}
+
+/// A self hosted runner
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Runner {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub busy: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub labels: Vec<Labels>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub os: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status: String,
+}
+
+/// Runner Application
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RunnerApplication {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub architecture: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub download_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub filename: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub os: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "sha256_checksum"
+    )]
+    pub sha_256_checksum: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub temp_download_token: String,
+}
+
+/// Authentication Token
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthenticationToken {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Authentication Token
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<AuthenticationTokenPermissions>,
+    /**
+     * Authentication Token
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<Repository>,
+    /**
+     * Authentication Token
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository_selection: Option<RepositorySelection>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub single_file: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Repo {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/**
+ * The policy that controls the repositories in the organization that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum EnabledRepositories {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "selected")]
+    Selected,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for EnabledRepositories {
+    fn as_ref(&self) -> &str {
+        match self {
+            EnabledRepositories::All => "all",
+            EnabledRepositories::None => "none",
+            EnabledRepositories::Selected => "selected",
+            EnabledRepositories::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for EnabledRepositories {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EnabledRepositories {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => EnabledRepositories::All,
+            "none" => EnabledRepositories::None,
+            "selected" => EnabledRepositories::Selected,
+            _ => EnabledRepositories::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for EnabledRepositories {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<EnabledRepositories> for String {
+    fn from(v: EnabledRepositories) -> Self {
+        match v {
+            EnabledRepositories::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsOrganizationPermissions {
+    /**
+     * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    /**
+     * The policy that controls the repositories in the organization that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
+     */
+    #[serde()]
+    pub enabled_repositories: EnabledRepositories,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_actions_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_repositories_url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RunnerGroupsOrg {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub allows_public_repositories: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub default: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub id: f64,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub inherited: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub inherited_allows_public_repositories: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub runners_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_repositories_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub visibility: String,
+}
+
+/// Secrets for GitHub Actions for an organization.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrganizationActionsSecret {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_repositories_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Configures the access that repositories have to the organization secret. Can be one of:  
+     *  \- `all` - All repositories in an organization can access the secret.  
+     *  \- `private` - Private repositories in an organization can access the secret.  
+     *  \- `selected` - Only specific repositories can access the secret.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+/// The public key used for setting Actions Secrets.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsPublicKey {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    /**
+     * The public key used for setting Actions Secrets.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// An object without any properties.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EmptyObject {}
+
+/// An artifact
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Artifact {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub archive_download_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub expired: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size_in_bytes: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/**
+ * The phase of the lifecycle that the job is currently in.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum JobStatus {
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "in_progress")]
+    InProgress,
+    #[serde(rename = "queued")]
+    Queued,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for JobStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            JobStatus::Completed => "completed",
+            JobStatus::InProgress => "in_progress",
+            JobStatus::Queued => "queued",
+            JobStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for JobStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "completed" => JobStatus::Completed,
+            "in_progress" => JobStatus::InProgress,
+            "queued" => JobStatus::Queued,
+            _ => JobStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for JobStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<JobStatus> for String {
+    fn from(v: JobStatus) -> Self {
+        match v {
+            JobStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// Information of a job execution in a workflow run
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Job {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub check_run_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub conclusion: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub head_sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub run_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub run_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The phase of the lifecycle that the job is currently in.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<JobStatus>,
+    /**
+     * Information of a job execution in a workflow run
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub steps: Vec<Steps>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsRepositoryPermissions {
+    /**
+     * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enabled: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_actions_url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Head {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ref"
+    )]
+    pub ref_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<Repo>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Base {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ref"
+    )]
+    pub ref_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repo: Option<Repo>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PullRequestMinimal {
+    #[serde()]
+    pub base: Base,
+    #[serde()]
+    pub head: Head,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// An invocation of a workflow
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkflowRun {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub artifacts_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub cancel_url: String,
+    /**
+     * An invocation of a workflow
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub check_suite_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub check_suite_node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub check_suite_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub conclusion: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub head_branch: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub head_commit: Option<SimpleCommit>,
+    /**
+     * Minimal Repository
+     */
+    #[serde()]
+    pub head_repository: MinimalRepository,
+    /**
+     * An invocation of a workflow
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub head_repository_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub head_sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub jobs_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub logs_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub pull_requests: Vec<PullRequestMinimal>,
+    /**
+     * Minimal Repository
+     */
+    #[serde()]
+    pub repository: MinimalRepository,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub rerun_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub run_number: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub workflow_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub workflow_url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnvironmentApprovalsEnvironments {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/**
+ * Whether deployment to the environment(s) was approved or rejected
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum EnvironmentApprovalsState {
+    #[serde(rename = "approved")]
+    Approved,
+    #[serde(rename = "rejected")]
+    Rejected,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for EnvironmentApprovalsState {
+    fn as_ref(&self) -> &str {
+        match self {
+            EnvironmentApprovalsState::Approved => "approved",
+            EnvironmentApprovalsState::Rejected => "rejected",
+            EnvironmentApprovalsState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for EnvironmentApprovalsState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EnvironmentApprovalsState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approved" => EnvironmentApprovalsState::Approved,
+            "rejected" => EnvironmentApprovalsState::Rejected,
+            _ => EnvironmentApprovalsState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for EnvironmentApprovalsState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<EnvironmentApprovalsState> for String {
+    fn from(v: EnvironmentApprovalsState) -> Self {
+        match v {
+            EnvironmentApprovalsState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// An entry in the reviews log for environment deployments
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnvironmentApprovals {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub comment: String,
+    /**
+     * The list of environments that were approved or rejected
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub environments: Vec<EnvironmentApprovalsEnvironments>,
+    /**
+     * Whether deployment to the environment(s) was approved or rejected
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<EnvironmentApprovalsState>,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<SimpleUser>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PendingDeploymentEnvironment {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Details of a deployment that is waiting for protection rules to pass
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PendingDeployment {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub current_user_can_approve: bool,
+    #[serde()]
+    pub environment: PendingDeploymentEnvironment,
+    /**
+     * The people or teams that may approve jobs that reference the environment. You can list up to six users or teams as reviewers. The reviewers must have at least read access to the repository. Only one of the required reviewers needs to approve the job for it to proceed.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub reviewers: Vec<Reviewers>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub wait_timer: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub wait_timer_started_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// All of the following types:
+///
+/// - `String`
+/// - `std::collections::BTreeMap<String, serde_json::Value>`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum PayloadOneOf {
+    String(String),
+    /**
+     * The request headers sent with the webhook delivery.
+     */
+    StdCollectionsBTreeMapStringSerdeJsonValue(
+        std::collections::BTreeMap<String, serde_json::Value>,
+    ),
+}
+
+impl PayloadOneOf {
+    pub fn std_collections_b_tree_map_string_serde_json_value(
+        &self,
+    ) -> Option<&std::collections::BTreeMap<String, serde_json::Value>> {
+        if let PayloadOneOf::StdCollectionsBTreeMapStringSerdeJsonValue(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn string(&self) -> Option<&String> {
+        if let PayloadOneOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<String> for PayloadOneOf {
+    fn from(f: String) -> Self {
+        PayloadOneOf::String(f)
+    }
+}
+
+impl std::convert::From<PayloadOneOf> for String {
+    fn from(f: PayloadOneOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+/// A request for a specific ref(branch,sha,tag) to be deployed
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Deployment {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub environment: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub original_environment: String,
+    #[serde()]
+    pub payload: PayloadOneOf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    /**
+     * A request for a specific ref(branch,sha,tag) to be deployed
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub production_environment: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ref"
+    )]
+    pub ref_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repository_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub statuses_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub task: String,
+    /**
+     * A request for a specific ref(branch,sha,tag) to be deployed
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub transient_environment: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Ubuntu {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub jobs: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_ms: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Macos {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub jobs: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_ms: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Windows {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub jobs: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_ms: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Billable {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "MACOS")]
+    pub macos: Option<Macos>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "UBUNTU")]
+    pub ubuntu: Option<Ubuntu>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "WINDOWS")]
+    pub windows: Option<Windows>,
+}
+
+/// Workflow Run Usage
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkflowRunUsage {
+    #[serde()]
+    pub billable: Billable,
+    /**
+     * Workflow Run Usage
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub run_duration_ms: i64,
+}
+
+/// Set secrets for GitHub Actions.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsSecret {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum WorkflowState {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "deleted")]
+    Deleted,
+    #[serde(rename = "disabled_fork")]
+    DisabledFork,
+    #[serde(rename = "disabled_inactivity")]
+    DisabledInactivity,
+    #[serde(rename = "disabled_manually")]
+    DisabledManually,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for WorkflowState {
+    fn as_ref(&self) -> &str {
+        match self {
+            WorkflowState::Active => "active",
+            WorkflowState::Deleted => "deleted",
+            WorkflowState::DisabledFork => "disabled_fork",
+            WorkflowState::DisabledInactivity => "disabled_inactivity",
+            WorkflowState::DisabledManually => "disabled_manually",
+            WorkflowState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for WorkflowState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for WorkflowState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => WorkflowState::Active,
+            "deleted" => WorkflowState::Deleted,
+            "disabled_fork" => WorkflowState::DisabledFork,
+            "disabled_inactivity" => WorkflowState::DisabledInactivity,
+            "disabled_manually" => WorkflowState::DisabledManually,
+            _ => WorkflowState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for WorkflowState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<WorkflowState> for String {
+    fn from(v: WorkflowState) -> Self {
+        match v {
+            WorkflowState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// A GitHub Actions workflow
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Workflow {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub badge_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * A GitHub Actions workflow
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<WorkflowState>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkflowUsageBillableUbuntu {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_ms: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkflowUsageBillableMacos {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_ms: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkflowUsageBillableWindows {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_ms: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkflowUsageBillable {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "MACOS")]
+    pub macos: Option<WorkflowUsageBillableMacos>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "UBUNTU")]
+    pub ubuntu: Option<WorkflowUsageBillableUbuntu>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "WINDOWS")]
+    pub windows: Option<WorkflowUsageBillableWindows>,
+}
+
+/// Workflow Usage
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WorkflowUsage {
+    #[serde()]
+    pub billable: WorkflowUsageBillable,
+}
+
+/**
+ * Returns workflow runs with the check run `status` or `conclusion` that you specify. For example, a conclusion can be `success` or a status can be `in_progress`. Only GitHub can set a status of `waiting` or `requested`. For a list of the possible `status` and `conclusion` options, see "[Create a check run](https://docs.github.com/rest/reference/checks#create-a-check-run)."
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum WorkflowRunStatus {
+    #[serde(rename = "action_required")]
+    ActionRequired,
+    #[serde(rename = "cancelled")]
+    Cancelled,
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "failure")]
+    Failure,
+    #[serde(rename = "in_progress")]
+    InProgress,
+    #[serde(rename = "neutral")]
+    Neutral,
+    #[serde(rename = "queued")]
+    Queued,
+    #[serde(rename = "requested")]
+    Requested,
+    #[serde(rename = "skipped")]
+    Skipped,
+    #[serde(rename = "stale")]
+    Stale,
+    #[serde(rename = "success")]
+    Success,
+    #[serde(rename = "timed_out")]
+    TimedOut,
+    #[serde(rename = "waiting")]
+    Waiting,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for WorkflowRunStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            WorkflowRunStatus::ActionRequired => "action_required",
+            WorkflowRunStatus::Cancelled => "cancelled",
+            WorkflowRunStatus::Completed => "completed",
+            WorkflowRunStatus::Failure => "failure",
+            WorkflowRunStatus::InProgress => "in_progress",
+            WorkflowRunStatus::Neutral => "neutral",
+            WorkflowRunStatus::Queued => "queued",
+            WorkflowRunStatus::Requested => "requested",
+            WorkflowRunStatus::Skipped => "skipped",
+            WorkflowRunStatus::Stale => "stale",
+            WorkflowRunStatus::Success => "success",
+            WorkflowRunStatus::TimedOut => "timed_out",
+            WorkflowRunStatus::Waiting => "waiting",
+            WorkflowRunStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for WorkflowRunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for WorkflowRunStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "action_required" => WorkflowRunStatus::ActionRequired,
+            "cancelled" => WorkflowRunStatus::Cancelled,
+            "completed" => WorkflowRunStatus::Completed,
+            "failure" => WorkflowRunStatus::Failure,
+            "in_progress" => WorkflowRunStatus::InProgress,
+            "neutral" => WorkflowRunStatus::Neutral,
+            "queued" => WorkflowRunStatus::Queued,
+            "requested" => WorkflowRunStatus::Requested,
+            "skipped" => WorkflowRunStatus::Skipped,
+            "stale" => WorkflowRunStatus::Stale,
+            "success" => WorkflowRunStatus::Success,
+            "timed_out" => WorkflowRunStatus::TimedOut,
+            "waiting" => WorkflowRunStatus::Waiting,
+            _ => WorkflowRunStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for WorkflowRunStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<WorkflowRunStatus> for String {
+    fn from(v: WorkflowRunStatus) -> Self {
+        match v {
+            WorkflowRunStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// All of the following types:
+///
+/// - `String`
+/// - `i64`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum WorkflowOneOf {
+    String(String),
+    I64(i64),
+}
+
+impl WorkflowOneOf {
+    pub fn i64(&self) -> Option<&i64> {
+        if let WorkflowOneOf::I64(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn string(&self) -> Option<&String> {
+        if let WorkflowOneOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<i64> for WorkflowOneOf {
+    fn from(f: i64) -> Self {
+        WorkflowOneOf::I64(f)
+    }
+}
+
+impl std::convert::From<String> for WorkflowOneOf {
+    fn from(f: String) -> Self {
+        WorkflowOneOf::String(f)
+    }
+}
+
+impl std::convert::From<WorkflowOneOf> for i64 {
+    fn from(f: WorkflowOneOf) -> Self {
+        *f.i64().unwrap()
+    }
+}
+
+impl std::convert::From<WorkflowOneOf> for String {
+    fn from(f: WorkflowOneOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsSetGithubPermissionsOrganizationRequest {
+    /**
+     * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    /**
+     * The policy that controls the repositories in the organization that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
+     */
+    #[serde()]
+    pub enabled_repositories: EnabledRepositories,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListSelectedRepositoriesEnabledGithubOrganizationResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<Repository>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsSetSelectedRepositoriesEnabledGithubOrganizationRequest {
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_repository_ids: Vec<i64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListSelfHostedRunnerGroupsOrgResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runner_groups: Vec<RunnerGroupsOrg>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsCreateSelfHostedRunnerGroupOrgRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runners: Vec<i64>,
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_repository_ids: Vec<i64>,
+    /**
+     * Configures the access that repositories have to the organization secret. Can be one of:  
+     *  \- `all` - All repositories in an organization can access the secret.  
+     *  \- `private` - Private repositories in an organization can access the secret.  
+     *  \- `selected` - Only specific repositories can access the secret.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsUpdateSelfHostedRunnerGroupOrgRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    /**
+     * Configures the access that repositories have to the organization secret. Can be one of:  
+     *  \- `all` - All repositories in an organization can access the secret.  
+     *  \- `private` - Private repositories in an organization can access the secret.  
+     *  \- `selected` - Only specific repositories can access the secret.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListRepoAccessSelfHostedRunnerGroupInOrgResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<MinimalRepository>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsSetRepoAccessSelfHostedRunnerGroupInOrgRequest {
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_repository_ids: Vec<i64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListSelfHostedRunnersInGroupOrgResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runners: Vec<Runner>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsSetSelfHostedRunnersInGroupOrgRequest {
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runners: Vec<i64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListSelfHostedRunnersOrgResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runners: Vec<Runner>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListOrgSecretsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub secrets: Vec<OrganizationActionsSecret>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsCreateUpdateOrgSecretRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub encrypted_value: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub key_id: Nullable<String>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_repository_ids: Vec<String>,
+    /**
+     * Configures the access that repositories have to the organization secret. Can be one of:  
+     *  \- `all` - All repositories in an organization can access the secret.  
+     *  \- `private` - Private repositories in an organization can access the secret.  
+     *  \- `selected` - Only specific repositories can access the secret.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListSelectedReposOrgSecretResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub repositories: Vec<MinimalRepository>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsSetSelectedReposOrgSecretRequest {
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_repository_ids: Vec<i64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListArtifactsRepoResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub artifacts: Vec<Artifact>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsSetGithubPermissionsRepositoryRequest {
+    /**
+     * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListSelfHostedRunnersRepoResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runners: Vec<Runner>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListWorkflowRunsRepoResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub workflow_runs: Vec<WorkflowRun>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListWorkflowRunArtifactsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub artifacts: Vec<Artifact>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsCancelWorkflowRunResponse {}
+
+/**
+ * Filters jobs by their `completed_at` timestamp. Can be one of:  
+ *   \* `latest`: Returns jobs from the most recent execution of the workflow run.  
+ *   \* `all`: Returns all jobs for a workflow run, including from old executions of the workflow run.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ActionsListJobsWorkflowRunFilter {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "latest")]
+    Latest,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ActionsListJobsWorkflowRunFilter {
+    fn as_ref(&self) -> &str {
+        match self {
+            ActionsListJobsWorkflowRunFilter::All => "all",
+            ActionsListJobsWorkflowRunFilter::Latest => "latest",
+            ActionsListJobsWorkflowRunFilter::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ActionsListJobsWorkflowRunFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ActionsListJobsWorkflowRunFilter {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ActionsListJobsWorkflowRunFilter::All,
+            "latest" => ActionsListJobsWorkflowRunFilter::Latest,
+            _ => ActionsListJobsWorkflowRunFilter::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ActionsListJobsWorkflowRunFilter {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ActionsListJobsWorkflowRunFilter> for String {
+    fn from(v: ActionsListJobsWorkflowRunFilter) -> Self {
+        match v {
+            ActionsListJobsWorkflowRunFilter::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for ActionsListJobsWorkflowRunFilter {
+    fn default() -> ActionsListJobsWorkflowRunFilter {
+        ActionsListJobsWorkflowRunFilter::Latest
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListJobsWorkflowRunResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub jobs: Vec<Job>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
+
+/**
+ * Whether to approve or reject deployment to the specified environments. Must be one of: `approved` or `rejected`
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ActionsReviewPendingDeploymentsRunRequestState {
+    #[serde(rename = "approved")]
+    Approved,
+    #[serde(rename = "rejected")]
+    Rejected,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ActionsReviewPendingDeploymentsRunRequestState {
+    fn as_ref(&self) -> &str {
+        match self {
+            ActionsReviewPendingDeploymentsRunRequestState::Approved => "approved",
+            ActionsReviewPendingDeploymentsRunRequestState::Rejected => "rejected",
+            ActionsReviewPendingDeploymentsRunRequestState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ActionsReviewPendingDeploymentsRunRequestState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ActionsReviewPendingDeploymentsRunRequestState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approved" => ActionsReviewPendingDeploymentsRunRequestState::Approved,
+            "rejected" => ActionsReviewPendingDeploymentsRunRequestState::Rejected,
+            _ => ActionsReviewPendingDeploymentsRunRequestState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ActionsReviewPendingDeploymentsRunRequestState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ActionsReviewPendingDeploymentsRunRequestState> for String {
+    fn from(v: ActionsReviewPendingDeploymentsRunRequestState) -> Self {
+        match v {
+            ActionsReviewPendingDeploymentsRunRequestState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsReviewPendingDeploymentsRunRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub comment: Nullable<String>,
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub environment_ids: Vec<i64>,
+    /**
+     * Whether to approve or reject deployment to the specified environments. Must be one of: `approved` or `rejected`
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<ActionsReviewPendingDeploymentsRunRequestState>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsReRunWorkflowResponse {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListRepoSecretsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub secrets: Vec<ActionsSecret>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsCreateUpdateRepoSecretRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub encrypted_value: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub key_id: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsCreateUpdateRepoSecretResponse {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListRepoWorkflowsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub workflows: Vec<Workflow>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsCreateWorkflowDispatchRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub inputs: std::collections::BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent", rename = "ref")]
+    pub ref_: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListWorkflowRunsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub workflow_runs: Vec<WorkflowRun>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsListEnvironmentSecretsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub secrets: Vec<ActionsSecret>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsCreateUpdateEnvironmentSecretRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub encrypted_value: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub key_id: Nullable<String>,
+}
@@ -0,0 +1,906 @@
+//! The data types of the `checks` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/**
+ * The phase of the lifecycle that the check is currently in.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum CheckRunStatus {
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "in_progress")]
+    InProgress,
+    #[serde(rename = "queued")]
+    Queued,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for CheckRunStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            CheckRunStatus::Completed => "completed",
+            CheckRunStatus::InProgress => "in_progress",
+            CheckRunStatus::Queued => "queued",
+            CheckRunStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for CheckRunStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CheckRunStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "completed" => CheckRunStatus::Completed,
+            "in_progress" => CheckRunStatus::InProgress,
+            "queued" => CheckRunStatus::Queued,
+            _ => CheckRunStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CheckRunStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<CheckRunStatus> for String {
+    fn from(v: CheckRunStatus) -> Self {
+        match v {
+            CheckRunStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Conclusion {
+    #[serde(rename = "action_required")]
+    ActionRequired,
+    #[serde(rename = "cancelled")]
+    Cancelled,
+    #[serde(rename = "failure")]
+    Failure,
+    #[serde(rename = "neutral")]
+    Neutral,
+    #[serde(rename = "skipped")]
+    Skipped,
+    #[serde(rename = "success")]
+    Success,
+    #[serde(rename = "timed_out")]
+    TimedOut,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Conclusion {
+    fn as_ref(&self) -> &str {
+        match self {
+            Conclusion::ActionRequired => "action_required",
+            Conclusion::Cancelled => "cancelled",
+            Conclusion::Failure => "failure",
+            Conclusion::Neutral => "neutral",
+            Conclusion::Skipped => "skipped",
+            Conclusion::Success => "success",
+            Conclusion::TimedOut => "timed_out",
+            Conclusion::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Conclusion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Conclusion {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "action_required" => Conclusion::ActionRequired,
+            "cancelled" => Conclusion::Cancelled,
+            "failure" => Conclusion::Failure,
+            "neutral" => Conclusion::Neutral,
+            "skipped" => Conclusion::Skipped,
+            "success" => Conclusion::Success,
+            "timed_out" => Conclusion::TimedOut,
+            _ => Conclusion::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Conclusion {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Conclusion> for String {
+    fn from(v: Conclusion) -> Self {
+        match v {
+            Conclusion::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Output {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub annotations_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub annotations_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub summary: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+}
+
+/// A suite of checks performed on the code of a given code change
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CheckSuite {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub after: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<Integration>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub before: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub check_runs_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<Conclusion>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub head_branch: String,
+    /**
+     * Simple Commit
+     */
+    #[serde()]
+    pub head_commit: SimpleCommit,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub head_sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub latest_check_runs_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub pull_requests: Vec<PullRequestMinimal>,
+    /**
+     * Minimal Repository
+     */
+    #[serde()]
+    pub repository: MinimalRepository,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<CheckSuiteStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CheckRunSuite {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+}
+
+/// A check performed on the code of a given code change
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CheckRun {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<Integration>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub check_suite: Option<CheckRunSuite>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<Conclusion>,
+    /**
+     * A check performed on the code of a given code change
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deployment: Option<DeploymentSimple>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub details_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub external_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub head_sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde()]
+    pub output: Output,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub pull_requests: Vec<PullRequestMinimal>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The phase of the lifecycle that the check is currently in.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<CheckRunStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Check Annotation
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CheckAnnotation {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub annotation_level: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub blob_href: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub end_column: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub end_line: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub raw_details: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub start_column: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub start_line: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum CheckSuiteStatus {
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "in_progress")]
+    InProgress,
+    #[serde(rename = "queued")]
+    Queued,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for CheckSuiteStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            CheckSuiteStatus::Completed => "completed",
+            CheckSuiteStatus::InProgress => "in_progress",
+            CheckSuiteStatus::Queued => "queued",
+            CheckSuiteStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for CheckSuiteStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CheckSuiteStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "completed" => CheckSuiteStatus::Completed,
+            "in_progress" => CheckSuiteStatus::InProgress,
+            "queued" => CheckSuiteStatus::Queued,
+            _ => CheckSuiteStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CheckSuiteStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<CheckSuiteStatus> for String {
+    fn from(v: CheckSuiteStatus) -> Self {
+        match v {
+            CheckSuiteStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AutoTriggerChecks {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub app_id: i64,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub setting: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Preferences {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub auto_trigger_checks: Vec<AutoTriggerChecks>,
+}
+
+/// Check suite configuration preferences for a repository.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CheckSuitePreference {
+    #[serde()]
+    pub preferences: Preferences,
+    /**
+     * Minimal Repository
+     */
+    #[serde()]
+    pub repository: MinimalRepository,
+}
+
+/**
+ * Returns check runs with the specified `status`. Can be one of `queued`, `in_progress`, or `completed`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum StatusData {
+    #[serde(rename = "completed")]
+    Completed,
+    #[serde(rename = "in_progress")]
+    InProgress,
+    #[serde(rename = "queued")]
+    Queued,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for StatusData {
+    fn as_ref(&self) -> &str {
+        match self {
+            StatusData::Completed => "completed",
+            StatusData::InProgress => "in_progress",
+            StatusData::Queued => "queued",
+            StatusData::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for StatusData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for StatusData {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "completed" => StatusData::Completed,
+            "in_progress" => StatusData::InProgress,
+            "queued" => StatusData::Queued,
+            _ => StatusData::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for StatusData {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<StatusData> for String {
+    fn from(v: StatusData) -> Self {
+        match v {
+            StatusData::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChecksCreateRequest {
+    /**
+     * Displays a button on GitHub that can be clicked to alert your app to do additional tasks. For example, a code linting app can display a button that automatically fixes detected errors. The button created in this object is displayed after the check run completes. When a user clicks the button, GitHub sends the [`check_run.requested_action` webhook](https://docs.github.com/webhooks/event-payloads/#check_run) to your app. Each action includes a `label`, `identifier` and `description`. A maximum of three actions are accepted. See the [`actions` object](https://docs.github.com/rest/reference/checks#actions-object) description. To learn more about check runs and requested actions, see "[Check runs and requested actions](https://docs.github.com/rest/reference/checks#check-runs-and-requested-actions)." To learn more about check runs and requested actions, see "[Check runs and requested actions](https://docs.github.com/rest/reference/checks#check-runs-and-requested-actions)."
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub actions: Vec<ChecksCreateRequestActions>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * \*\*Required if you provide `completed_at` or a `status` of `completed`\*\*. The final conclusion of the check. Can be one of `action_required`, `cancelled`, `failure`, `neutral`, `success`, `skipped`, `stale`, or `timed_out`. When the conclusion is `action_required`, additional details should be provided on the site specified by `details_url`.  
+     *  \*\*Note:\*\* Providing `conclusion` will automatically set the `status` parameter to `completed`. You cannot change a check run conclusion to `stale`, only GitHub can set this.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<ChecksCreateRequestConclusion>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub details_url: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub external_id: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub head_sha: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    /**
+     * Check runs can accept a variety of data in the `output` object, including a `title` and `summary` and can optionally provide descriptive details about the run. See the [`output` object](https://docs.github.com/rest/reference/checks#output-object) description.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<ChecksCreateRequestOutput>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The current status. Can be one of `queued`, `in_progress`, or `completed`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<ChecksCreateRequestStatus>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChecksUpdateRequest {
+    /**
+     * Possible further actions the integrator can perform, which a user may trigger. Each action includes a `label`, `identifier` and `description`. A maximum of three actions are accepted. See the [`actions` object](https://docs.github.com/rest/reference/checks#actions-object) description. To learn more about check runs and requested actions, see "[Check runs and requested actions](https://docs.github.com/rest/reference/checks#check-runs-and-requested-actions)."
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub actions: Vec<ChecksUpdateRequestActions>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * \*\*Required if you provide `completed_at` or a `status` of `completed`\*\*. The final conclusion of the check. Can be one of `action_required`, `cancelled`, `failure`, `neutral`, `success`, `skipped`, `stale`, or `timed_out`.  
+     *  \*\*Note:\*\* Providing `conclusion` will automatically set the `status` parameter to `completed`. You cannot change a check run conclusion to `stale`, only GitHub can set this.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<ChecksUpdateRequestConclusion>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub details_url: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub external_id: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    /**
+     * Check runs can accept a variety of data in the `output` object, including a `title` and `summary` and can optionally provide descriptive details about the run. See the [`output` object](https://docs.github.com/rest/reference/checks#output-object-1) description.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<ChecksUpdateRequestOutput>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The current status. Can be one of `queued`, `in_progress`, or `completed`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<ChecksUpdateRequestStatus>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChecksCreateSuiteRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub head_sha: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChecksSetSuitesPreferencesRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub auto_trigger_checks: Vec<AutoTriggerChecks>,
+}
+
+/**
+ * Filters check runs by their `completed_at` timestamp. Can be one of `latest` (returning the most recent check runs) or `all`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ChecksListSuiteFilter {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "latest")]
+    Latest,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ChecksListSuiteFilter {
+    fn as_ref(&self) -> &str {
+        match self {
+            ChecksListSuiteFilter::All => "all",
+            ChecksListSuiteFilter::Latest => "latest",
+            ChecksListSuiteFilter::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ChecksListSuiteFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ChecksListSuiteFilter {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ChecksListSuiteFilter::All,
+            "latest" => ChecksListSuiteFilter::Latest,
+            _ => ChecksListSuiteFilter::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ChecksListSuiteFilter {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ChecksListSuiteFilter> for String {
+    fn from(v: ChecksListSuiteFilter) -> Self {
+        match v {
+            ChecksListSuiteFilter::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for ChecksListSuiteFilter {
+    fn default() -> ChecksListSuiteFilter {
+        ChecksListSuiteFilter::Latest
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChecksListSuiteResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub check_runs: Vec<CheckRun>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChecksRerequestSuiteResponse {}
+
+/**
+ * Filters check runs by their `completed_at` timestamp. Can be one of `latest` (returning the most recent check runs) or `all`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ChecksListRefFilter {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "latest")]
+    Latest,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ChecksListRefFilter {
+    fn as_ref(&self) -> &str {
+        match self {
+            ChecksListRefFilter::All => "all",
+            ChecksListRefFilter::Latest => "latest",
+            ChecksListRefFilter::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ChecksListRefFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ChecksListRefFilter {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ChecksListRefFilter::All,
+            "latest" => ChecksListRefFilter::Latest,
+            _ => ChecksListRefFilter::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ChecksListRefFilter {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ChecksListRefFilter> for String {
+    fn from(v: ChecksListRefFilter) -> Self {
+        match v {
+            ChecksListRefFilter::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for ChecksListRefFilter {
+    fn default() -> ChecksListRefFilter {
+        ChecksListRefFilter::Latest
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChecksListRefResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub check_runs: Vec<CheckRun>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChecksListSuitesRefResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub check_suites: Vec<CheckSuite>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
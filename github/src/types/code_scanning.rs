@@ -0,0 +1,744 @@
+//! The data types of the `code_scanning` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/**
+ * **Required when the state is dismissed.** The reason for dismissing or closing the alert. Can be one of: `false positive`, `won't fix`, and `used in tests`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum CodeScanningAlertDismissedReason {
+    #[serde(rename = "false positive")]
+    FalsePositive,
+    #[serde(rename = "used in tests")]
+    UsedInTests,
+    #[serde(rename = "won't fix")]
+    WonTFix,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for CodeScanningAlertDismissedReason {
+    fn as_ref(&self) -> &str {
+        match self {
+            CodeScanningAlertDismissedReason::FalsePositive => "false positive",
+            CodeScanningAlertDismissedReason::UsedInTests => "used in tests",
+            CodeScanningAlertDismissedReason::WonTFix => "won't fix",
+            CodeScanningAlertDismissedReason::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for CodeScanningAlertDismissedReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CodeScanningAlertDismissedReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "false positive" => CodeScanningAlertDismissedReason::FalsePositive,
+            "used in tests" => CodeScanningAlertDismissedReason::UsedInTests,
+            "won't fix" => CodeScanningAlertDismissedReason::WonTFix,
+            _ => CodeScanningAlertDismissedReason::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CodeScanningAlertDismissedReason {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<CodeScanningAlertDismissedReason> for String {
+    fn from(v: CodeScanningAlertDismissedReason) -> Self {
+        match v {
+            CodeScanningAlertDismissedReason::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * The severity of the alert.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Severity {
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "note")]
+    Note,
+    #[serde(rename = "warning")]
+    Warning,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Severity {
+    fn as_ref(&self) -> &str {
+        match self {
+            Severity::Error => "error",
+            Severity::None => "none",
+            Severity::Note => "note",
+            Severity::Warning => "warning",
+            Severity::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "error" => Severity::Error,
+            "none" => Severity::None,
+            "note" => Severity::Note,
+            "warning" => Severity::Warning,
+            _ => Severity::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Severity {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Severity> for String {
+    fn from(v: Severity) -> Self {
+        match v {
+            Severity::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAlertRuleSummary {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * The severity of the alert.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAnalysisTool {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub guid: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Message {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub text: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAlertInstance {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub analysis_key: String,
+    /**
+     * Classifications that have been applied to the file that triggered the alert.
+     *  For example identifying it as documentation, or a generated file.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub classifications: Vec<CodeScanningAlertClassification>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub environment: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    /**
+     * Describe a region within a file for the alert.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<CodeScanningAlertLocation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ref"
+    )]
+    pub ref_: String,
+    /**
+     * State of a code scanning alert.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<CodeScanningAlertState>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAlertItems {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub dismissed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_by: Option<SimpleUser>,
+    /**
+     * \*\*Required when the state is dismissed.\*\* The reason for dismissing or closing the alert. Can be one of: `false positive`, `won't fix`, and `used in tests`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_reason: Option<CodeScanningAlertDismissedReason>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub instances_url: String,
+    #[serde()]
+    pub most_recent_instance: CodeScanningAlertInstance,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    #[serde()]
+    pub rule: CodeScanningAlertRuleSummary,
+    /**
+     * State of a code scanning alert.
+     */
+    #[serde()]
+    pub state: CodeScanningAlertState,
+    #[serde()]
+    pub tool: CodeScanningAnalysisTool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/**
+ * The security severity of the alert.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SecuritySeverityLevel {
+    #[serde(rename = "critical")]
+    Critical,
+    #[serde(rename = "high")]
+    High,
+    #[serde(rename = "low")]
+    Low,
+    #[serde(rename = "medium")]
+    Medium,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SecuritySeverityLevel {
+    fn as_ref(&self) -> &str {
+        match self {
+            SecuritySeverityLevel::Critical => "critical",
+            SecuritySeverityLevel::High => "high",
+            SecuritySeverityLevel::Low => "low",
+            SecuritySeverityLevel::Medium => "medium",
+            SecuritySeverityLevel::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SecuritySeverityLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SecuritySeverityLevel {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "critical" => SecuritySeverityLevel::Critical,
+            "high" => SecuritySeverityLevel::High,
+            "low" => SecuritySeverityLevel::Low,
+            "medium" => SecuritySeverityLevel::Medium,
+            _ => SecuritySeverityLevel::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SecuritySeverityLevel {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SecuritySeverityLevel> for String {
+    fn from(v: SecuritySeverityLevel) -> Self {
+        match v {
+            SecuritySeverityLevel::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAlertRule {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub full_description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub help: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * The security severity of the alert.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_severity_level: Option<SecuritySeverityLevel>,
+    /**
+     * The severity of the alert.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub tags: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAlert {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub dismissed_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_by: Option<SimpleUser>,
+    /**
+     * \*\*Required when the state is dismissed.\*\* The reason for dismissing or closing the alert. Can be one of: `false positive`, `won't fix`, and `used in tests`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_reason: Option<CodeScanningAlertDismissedReason>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instances: Option<serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub instances_url: String,
+    #[serde()]
+    pub most_recent_instance: CodeScanningAlertInstance,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    #[serde()]
+    pub rule: CodeScanningAlertRule,
+    /**
+     * State of a code scanning alert.
+     */
+    #[serde()]
+    pub state: CodeScanningAlertState,
+    #[serde()]
+    pub tool: CodeScanningAnalysisTool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/**
+ * Sets the state of the code scanning alert. Can be one of `open` or `dismissed`. You must provide `dismissed_reason` when you set the state to `dismissed`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum CodeScanningAlertSetState {
+    #[serde(rename = "dismissed")]
+    Dismissed,
+    #[serde(rename = "open")]
+    Open,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for CodeScanningAlertSetState {
+    fn as_ref(&self) -> &str {
+        match self {
+            CodeScanningAlertSetState::Dismissed => "dismissed",
+            CodeScanningAlertSetState::Open => "open",
+            CodeScanningAlertSetState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for CodeScanningAlertSetState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CodeScanningAlertSetState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "dismissed" => CodeScanningAlertSetState::Dismissed,
+            "open" => CodeScanningAlertSetState::Open,
+            _ => CodeScanningAlertSetState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CodeScanningAlertSetState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<CodeScanningAlertSetState> for String {
+    fn from(v: CodeScanningAlertSetState) -> Self {
+        match v {
+            CodeScanningAlertSetState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAnalysis {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub analysis_key: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub category: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub deletable: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub environment: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub error: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ref"
+    )]
+    pub ref_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub results_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub rules_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sarif_id: String,
+    #[serde()]
+    pub tool: CodeScanningAnalysisTool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tool_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub warning: String,
+}
+
+/// Successful deletion of a code scanning analysis
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningAnalysisDeletion {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub confirm_delete_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub next_analysis_url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningSarifsReceipt {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningSarifsStatus {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub analyses_url: String,
+    /**
+     * `pending` files have not yet been processed, while `complete` means all results in the SARIF have been stored.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processing_status: Option<ProcessingStatus>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningUpdateAlertRequest {
+    /**
+     * \*\*Required when the state is dismissed.\*\* The reason for dismissing or closing the alert. Can be one of: `false positive`, `won't fix`, and `used in tests`.
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub dismissed_reason: Nullable<CodeScanningAlertDismissedReason>,
+    /**
+     * Sets the state of the code scanning alert. Can be one of `open` or `dismissed`. You must provide `dismissed_reason` when you set the state to `dismissed`.
+     */
+    #[serde()]
+    pub state: CodeScanningAlertSetState,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeScanningUploadSarifRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub checkout_uri: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub commit_sha: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent", rename = "ref")]
+    pub ref_: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub sarif: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub tool_name: Nullable<String>,
+}
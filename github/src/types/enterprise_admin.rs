@@ -0,0 +1,1549 @@
+//! The data types of the `enterprise_admin` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/**
+ * The policy that controls the organizations in the enterprise that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum EnabledOrganizations {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "selected")]
+    Selected,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for EnabledOrganizations {
+    fn as_ref(&self) -> &str {
+        match self {
+            EnabledOrganizations::All => "all",
+            EnabledOrganizations::None => "none",
+            EnabledOrganizations::Selected => "selected",
+            EnabledOrganizations::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for EnabledOrganizations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EnabledOrganizations {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => EnabledOrganizations::All,
+            "none" => EnabledOrganizations::None,
+            "selected" => EnabledOrganizations::Selected,
+            _ => EnabledOrganizations::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for EnabledOrganizations {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<EnabledOrganizations> for String {
+    fn from(v: EnabledOrganizations) -> Self {
+        match v {
+            EnabledOrganizations::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActionsEnterprisePermissions {
+    /**
+     * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    /**
+     * The policy that controls the organizations in the enterprise that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
+     */
+    #[serde()]
+    pub enabled_organizations: EnabledOrganizations,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_actions_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_organizations_url: String,
+}
+
+/// Organization Simple
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrganizationSimple {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub avatar_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub events_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub hooks_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issues_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub members_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub public_members_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repos_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RunnerGroupsEnterprise {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub allows_public_repositories: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub default: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub id: f64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub runners_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub selected_organizations_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub visibility: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ActorLocation {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub country_name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuditLogEvent {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "@timestamp"
+    )]
+    pub timestamp: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "_document_id"
+    )]
+    pub document_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub active: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub active_was: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub actor: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub actor_id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor_location: Option<ActorLocation>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub blocked_user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub business: String,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub config: Vec<String>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub config_was: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub created_at: i64,
+    /**
+     * The request headers sent with the webhook delivery.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub data: std::collections::BTreeMap<String, serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub deploy_key_fingerprint: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub emoji: String,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events_were: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub explanation: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub fingerprint: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub hook_id: i64,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub limited_availability: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub old_user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub openssh_public_key: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub org: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub org_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub previous_visibility: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub read_only: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repo: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repository: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub repository_public: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target_login: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub transport_protocol: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub transport_protocol_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub user: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub visibility: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimGroupListEnterpriseResourcesMembers {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "$ref"
+    )]
+    pub ref_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub display: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Meta {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "lastModified"
+    )]
+    pub last_modified: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub location: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "resourceType"
+    )]
+    pub resource_type: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimGroupListEnterpriseResources {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "displayName"
+    )]
+    pub display_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "externalId"
+    )]
+    pub external_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub members: Vec<ScimGroupListEnterpriseResourcesMembers>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimGroupListEnterprise {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "Resources"
+    )]
+    pub resources: Vec<ScimGroupListEnterpriseResources>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
+        rename = "itemsPerPage"
+    )]
+    pub items_per_page: f64,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
+        rename = "startIndex"
+    )]
+    pub start_index: f64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
+        rename = "totalResults"
+    )]
+    pub total_results: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimEnterpriseGroupMembers {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "$ref"
+    )]
+    pub ref_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub display: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimEnterpriseGroup {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "displayName"
+    )]
+    pub display_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "externalId"
+    )]
+    pub external_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub members: Vec<ScimEnterpriseGroupMembers>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Name {
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "familyName"
+    )]
+    pub family_name: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "givenName"
+    )]
+    pub given_name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Emails {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub primary: bool,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent", rename = "type")]
+    pub type_: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub value: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimUserListEnterpriseResourcesGroups {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimUserListEnterpriseResources {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub active: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<Emails>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "externalId"
+    )]
+    pub external_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub groups: Vec<ScimUserListEnterpriseResourcesGroups>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Name>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "userName"
+    )]
+    pub user_name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimUserListEnterprise {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "Resources"
+    )]
+    pub resources: Vec<ScimUserListEnterpriseResources>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
+        rename = "itemsPerPage"
+    )]
+    pub items_per_page: f64,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
+        rename = "startIndex"
+    )]
+    pub start_index: f64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
+        rename = "totalResults"
+    )]
+    pub total_results: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimEnterpriseUserGroups {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimEnterpriseUser {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub active: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<Emails>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "externalId"
+    )]
+    pub external_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub groups: Vec<ScimEnterpriseUserGroups>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Meta>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<Name>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "userName"
+    )]
+    pub user_name: String,
+}
+
+/**
+ * The event types to include:
+ *   
+ *   - `web` - returns web (non-Git) events
+ *   - `git` - returns Git events
+ *   - `all` - returns both web and Git events
+ *   
+ *   The default is `web`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Include {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "git")]
+    Git,
+    #[serde(rename = "web")]
+    Web,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Include {
+    fn as_ref(&self) -> &str {
+        match self {
+            Include::All => "all",
+            Include::Git => "git",
+            Include::Web => "web",
+            Include::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Include {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Include {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => Include::All,
+            "git" => Include::Git,
+            "web" => Include::Web,
+            _ => Include::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Include {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Include> for String {
+    fn from(v: Include) -> Self {
+        match v {
+            Include::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Determines whether the first search result returned is the highest number of matches (`desc`) or lowest number of matches (`asc`). This parameter is ignored unless you provide `sort`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Order {
+    #[serde(rename = "asc")]
+    Asc,
+    #[serde(rename = "desc")]
+    Desc,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Order {
+    fn as_ref(&self) -> &str {
+        match self {
+            Order::Asc => "asc",
+            Order::Desc => "desc",
+            Order::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Order {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Order {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "asc" => Order::Asc,
+            "desc" => Order::Desc,
+            _ => Order::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Order {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Order> for String {
+    fn from(v: Order) -> Self {
+        match v {
+            Order::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for Order {
+    fn default() -> Order {
+        Order::Desc
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminSetGithubActionsPermissionsRequest {
+    /**
+     * The permissions policy that controls the actions that are allowed to run. Can be one of: `all`, `local_only`, or `selected`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_actions: Option<AllowedActions>,
+    /**
+     * The policy that controls the organizations in the enterprise that are allowed to run GitHub Actions. Can be one of: `all`, `none`, or `selected`.
+     */
+    #[serde()]
+    pub enabled_organizations: EnabledOrganizations,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminListSelectedOrganizationsEnabledGithubActionsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub organizations: Vec<OrganizationSimple>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminSetSelectedOrganizationsEnabledGithubActionsRequest {
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_organization_ids: Vec<i64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminListSelfHostedRunnerGroupsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runner_groups: Vec<RunnerGroupsEnterprise>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminCreateSelfHostedRunnerGroupRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runners: Vec<i64>,
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_organization_ids: Vec<i64>,
+    /**
+     * Visibility of a runner group. You can select all organizations or select individual organization. Can be one of: `all` or `selected`
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<EnterpriseAdminCreateSelfHostedRunnerGroupRequestVisibility>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminUpdateSelfHostedRunnerGroupRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    /**
+     * Visibility of a runner group. You can select all organizations or select individual organizations. Can be one of: `all` or `selected`
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<EnterpriseAdminUpdateSelfHostedRunnerGroupRequestVisibility>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminListOrgAccessSelfHostedRunnerGroupInResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub organizations: Vec<OrganizationSimple>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminSetOrgAccessSelfHostedRunnerGroupInRequest {
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub selected_organization_ids: Vec<i64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminListSelfHostedRunnersInGroupResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runners: Vec<Runner>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminSetSelfHostedRunnersInGroupRequest {
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runners: Vec<i64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminListSelfHostedRunnersResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub runners: Vec<Runner>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub total_count: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminProvisionInviteGroupRequestMembers {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub value: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminProvisionInviteGroupRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "displayName"
+    )]
+    pub display_name: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub members: Vec<EnterpriseAdminProvisionInviteGroupRequestMembers>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminSetInformationProvisionedGroupRequestMembers {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub value: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminSetInformationProvisionedGroupRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "displayName"
+    )]
+    pub display_name: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub members: Vec<EnterpriseAdminSetInformationProvisionedGroupRequestMembers>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    #[serde(rename = "Add")]
+    Add,
+    #[serde(rename = "Remove")]
+    Remove,
+    #[serde(rename = "Replace")]
+    Replace,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    fn as_ref(&self) -> &str {
+        match self {
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Add => "Add",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Remove => "Remove",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Replace => "Replace",
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Add" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Add,
+            "Remove" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Remove,
+            "Replace" => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Replace,
+            _ => EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for EnterpriseAdminUpdateAttributeGroupRequestOperationsOp {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<EnterpriseAdminUpdateAttributeGroupRequestOperationsOp> for String {
+    fn from(v: EnterpriseAdminUpdateAttributeGroupRequestOperationsOp) -> Self {
+        match v {
+            EnterpriseAdminUpdateAttributeGroupRequestOperationsOp::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// All of the following types:
+///
+/// - `String`
+/// - `Vec<String>`
+/// - `Value`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf {
+    String(String),
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    Value(Value),
+}
+
+impl EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf {
+    pub fn string(&self) -> Option<&String> {
+        if let EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf::StringVector(ref_) =
+            self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn value(&self) -> Option<&Value> {
+        if let EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf::Value(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<String> for EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf {
+    fn from(f: String) -> Self {
+        EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf::String(f)
+    }
+}
+
+impl std::convert::From<Vec<String>>
+    for EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf
+{
+    fn from(f: Vec<String>) -> Self {
+        EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf> for String {
+    fn from(f: EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+impl std::convert::From<EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf>
+    for Vec<String>
+{
+    fn from(f: EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminUpdateAttributeGroupRequestOperations {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<EnterpriseAdminUpdateAttributeGroupRequestOperationsOp>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub path: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<EnterpriseAdminUpdateAttributeGroupRequestOperationsValueOneOf>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminUpdateAttributeGroupRequest {
+    /**
+     * Array of [SCIM operations](https://tools.ietf.org/html/rfc7644#section-3.5.2).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "Operations"
+    )]
+    pub operations: Vec<EnterpriseAdminUpdateAttributeGroupRequestOperations>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminProvisionInviteUserRequestEmails {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub primary: bool,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent", rename = "type")]
+    pub type_: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub value: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminProvisionInviteUserRequest {
+    /**
+     * List of user emails.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<EnterpriseAdminProvisionInviteUserRequestEmails>,
+    /**
+     * List of SCIM group IDs the user is a member of.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub groups: Vec<EnterpriseAdminProvisionInviteUserRequestGroups>,
+    #[serde()]
+    pub name: Name,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "userName"
+    )]
+    pub user_name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminSetInformationProvisionedUserRequestEmails {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub primary: bool,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent", rename = "type")]
+    pub type_: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub value: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminSetInformationProvisionedUserRequest {
+    /**
+     * List of user emails.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<EnterpriseAdminSetInformationProvisionedUserRequestEmails>,
+    /**
+     * List of SCIM group IDs the user is a member of.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub groups: Vec<EnterpriseAdminSetInformationProvisionedUserRequestGroups>,
+    #[serde()]
+    pub name: Name,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "userName"
+    )]
+    pub user_name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminUpdateAttributeUserRequestOperations {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EnterpriseAdminUpdateAttributeUserRequest {
+    /**
+     * Array of [SCIM operations](https://tools.ietf.org/html/rfc7644#section-3.5.2).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "Operations"
+    )]
+    pub operations: Vec<EnterpriseAdminUpdateAttributeUserRequestOperations>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+}
@@ -0,0 +1,162 @@
+//! The data types of the `interactions` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/**
+ * The type of GitHub user that can comment, open issues, or create pull requests while the interaction limit is in effect. Can be one of: `existing_users`, `contributors_only`, `collaborators_only`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum InteractionGroup {
+    #[serde(rename = "collaborators_only")]
+    CollaboratorsOnly,
+    #[serde(rename = "contributors_only")]
+    ContributorsOnly,
+    #[serde(rename = "existing_users")]
+    ExistingUsers,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for InteractionGroup {
+    fn as_ref(&self) -> &str {
+        match self {
+            InteractionGroup::CollaboratorsOnly => "collaborators_only",
+            InteractionGroup::ContributorsOnly => "contributors_only",
+            InteractionGroup::ExistingUsers => "existing_users",
+            InteractionGroup::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for InteractionGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for InteractionGroup {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "collaborators_only" => InteractionGroup::CollaboratorsOnly,
+            "contributors_only" => InteractionGroup::ContributorsOnly,
+            "existing_users" => InteractionGroup::ExistingUsers,
+            _ => InteractionGroup::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for InteractionGroup {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<InteractionGroup> for String {
+    fn from(v: InteractionGroup) -> Self {
+        match v {
+            InteractionGroup::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// Interaction limit settings.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InteractionLimitResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The type of GitHub user that can comment, open issues, or create pull requests while the interaction limit is in effect. Can be one of: `existing_users`, `contributors_only`, `collaborators_only`.
+     */
+    #[serde()]
+    pub limit: InteractionGroup,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub origin: String,
+}
+
+/// Limit interactions to a specific type of user for a specified duration
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InteractionLimit {
+    /**
+     * Limit interactions to a specific type of user for a specified duration
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiry: Option<InteractionExpiry>,
+    /**
+     * The type of GitHub user that can comment, open issues, or create pull requests while the interaction limit is in effect. Can be one of: `existing_users`, `contributors_only`, `collaborators_only`.
+     */
+    #[serde()]
+    pub limit: InteractionGroup,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InteractionsGetRestrictionsOrgResponse {}
+
+/// All of the following types are flattened into one object:
+///
+/// - `InteractionLimitResponse`
+/// - `InteractionsGetRestrictionsOrgResponse`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InteractionsGetRestrictionsOrgResponseAnyOf {
+    /**
+     * Interaction limit settings.
+     */
+    #[serde(flatten)]
+    pub interaction_limit_response: InteractionLimitResponse,
+    #[serde(flatten)]
+    pub interactions_get_restrictions_org_response: InteractionsGetRestrictionsOrgResponse,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InteractionsGetRestrictionsRepoResponse {}
+
+/// All of the following types are flattened into one object:
+///
+/// - `InteractionLimitResponse`
+/// - `InteractionsGetRestrictionsRepoResponse`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InteractionsGetRestrictionsRepoResponseAnyOf {
+    /**
+     * Interaction limit settings.
+     */
+    #[serde(flatten)]
+    pub interaction_limit_response: InteractionLimitResponse,
+    #[serde(flatten)]
+    pub interactions_get_restrictions_repo_response: InteractionsGetRestrictionsRepoResponse,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InteractionsGetRestrictionsResponse {}
+
+/// All of the following types are flattened into one object:
+///
+/// - `InteractionLimitResponse`
+/// - `InteractionsGetRestrictionsResponse`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InteractionsGetRestrictionsResponseAnyOf {
+    /**
+     * Interaction limit settings.
+     */
+    #[serde(flatten)]
+    pub interaction_limit_response: InteractionLimitResponse,
+    #[serde(flatten)]
+    pub interactions_get_restrictions_response: InteractionsGetRestrictionsResponse,
+}
@@ -0,0 +1,2621 @@
+//! The data types of the `issues` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LabelsData {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub color: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub default: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// All of the following types:
+///
+/// - `String`
+/// - `LabelsData`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum LabelsOneOf {
+    String(String),
+    LabelsData(LabelsData),
+}
+
+impl LabelsOneOf {
+    pub fn labels_data(&self) -> Option<&LabelsData> {
+        if let LabelsOneOf::LabelsData(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn string(&self) -> Option<&String> {
+        if let LabelsOneOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<String> for LabelsOneOf {
+    fn from(f: String) -> Self {
+        LabelsOneOf::String(f)
+    }
+}
+
+impl std::convert::From<LabelsOneOf> for String {
+    fn from(f: LabelsOneOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+/// Issues are a great way to keep track of tasks, enhancements, and bugs for your projects.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Issue {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub active_lock_reason: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub assignees: Vec<SimpleUser>,
+    /**
+     * How the author is associated with the repository.
+     */
+    #[serde()]
+    pub author_association: AuthorAssociation,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body_html: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body_text: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub closed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub closed_by: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub comments: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub comments_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub events_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Labels to associate with this issue; pass one or more label names to replace the set of labels on this issue; send an empty array to clear all labels from the issue; note that the labels are silently dropped for users without push access to the repository
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub labels: Vec<LabelsOneOf>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub labels_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub locked: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<Milestone>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    /**
+     * Issues are a great way to keep track of tasks, enhancements, and bugs for your projects.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_request: Option<IssuePullRequest>,
+    /**
+     * Issues are a great way to keep track of tasks, enhancements, and bugs for your projects.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<ReactionRollup>,
+    /**
+     * Issues are a great way to keep track of tasks, enhancements, and bugs for your projects.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<Repository>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repository_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub timeline_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<SimpleUser>,
+}
+
+/// Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssueEvent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigner: Option<SimpleUser>,
+    /**
+     * Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author_association: Option<AuthorAssociation>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissed_review: Option<IssueEventDismissedReview>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue: Option<IssueSimple>,
+    /**
+     * Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<IssueEventLabel>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub lock_reason: String,
+    /**
+     * Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<IssueEventMilestone>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    /**
+     * Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_card: Option<IssueEventProjectCard>,
+    /**
+     * Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rename: Option<IssueEventRename>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_reviewer: Option<SimpleUser>,
+    /**
+     * Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_team: Option<Team>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_requester: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LabeledIssueEventLabel {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub color: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+/// Labeled Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LabeledIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde()]
+    pub label: LabeledIssueEventLabel,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UnlabeledIssueEventLabel {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub color: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+/// Unlabeled Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UnlabeledIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde()]
+    pub label: UnlabeledIssueEventLabel,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Assigned Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AssignedIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<SimpleUser>,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigner: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Unassigned Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UnassignedIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assignee: Option<SimpleUser>,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigner: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MilestonedIssueEventMilestone {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+}
+
+/// Milestoned Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MilestonedIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde()]
+    pub milestone: MilestonedIssueEventMilestone,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DemilestonedIssueEventMilestone {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+}
+
+/// Demilestoned Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DemilestonedIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde()]
+    pub milestone: DemilestonedIssueEventMilestone,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Rename {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub from: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub to: String,
+}
+
+/// Renamed Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RenamedIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    #[serde()]
+    pub rename: Rename,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Review Requested Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReviewRequestedIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_reviewer: Option<SimpleUser>,
+    /**
+     * Review Requested Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_team: Option<Team>,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_requester: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Review Request Removed Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReviewRequestRemovedIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_reviewer: Option<SimpleUser>,
+    /**
+     * Review Request Removed Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_team: Option<Team>,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_requester: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DismissedReview {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub dismissal_commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub dismissal_message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub review_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+}
+
+/// Review Dismissed Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReviewDismissedIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde()]
+    pub dismissed_review: DismissedReview,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Locked Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LockedIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub lock_reason: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Added to Project Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AddedProjectIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    /**
+     * Added to Project Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_card: Option<AddedProjectIssueEventCard>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Moved Column in Project Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MovedColumnInProjectIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    /**
+     * Moved Column in Project Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_card: Option<MovedColumnInProjectIssueEventCard>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Removed from Project Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RemovedFromProjectIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    /**
+     * Removed from Project Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_card: Option<RemovedFromProjectIssueEventCard>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Converted Note to Issue Issue Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConvertedNoteIssueEvent {
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub event: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * GitHub apps are a new way to extend GitHub. They can be installed directly on organizations and user accounts and granted access to specific repositories. They come with granular permissions and built-in webhooks. GitHub apps are first class actors within GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    /**
+     * Converted Note to Issue Issue Event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_card: Option<ConvertedNoteIssueEventProjectCard>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `LabeledIssueEvent`
+/// - `UnlabeledIssueEvent`
+/// - `AssignedIssueEvent`
+/// - `UnassignedIssueEvent`
+/// - `MilestonedIssueEvent`
+/// - `DemilestonedIssueEvent`
+/// - `RenamedIssueEvent`
+/// - `ReviewRequestedIssueEvent`
+/// - `ReviewRequestRemovedIssueEvent`
+/// - `ReviewDismissedIssueEvent`
+/// - `LockedIssueEvent`
+/// - `AddedProjectIssueEvent`
+/// - `MovedColumnInProjectIssueEvent`
+/// - `RemovedFromProjectIssueEvent`
+/// - `ConvertedNoteIssueEvent`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssueEventAnyOf {
+    /**
+     * Labeled Issue Event
+     */
+    #[serde(flatten)]
+    pub labeled_issue_event: LabeledIssueEvent,
+    /**
+     * Unlabeled Issue Event
+     */
+    #[serde(flatten)]
+    pub unlabeled_issue_event: UnlabeledIssueEvent,
+    /**
+     * Assigned Issue Event
+     */
+    #[serde(flatten)]
+    pub assigned_issue_event: AssignedIssueEvent,
+    /**
+     * Unassigned Issue Event
+     */
+    #[serde(flatten)]
+    pub unassigned_issue_event: UnassignedIssueEvent,
+    /**
+     * Milestoned Issue Event
+     */
+    #[serde(flatten)]
+    pub milestoned_issue_event: MilestonedIssueEvent,
+    /**
+     * Demilestoned Issue Event
+     */
+    #[serde(flatten)]
+    pub demilestoned_issue_event: DemilestonedIssueEvent,
+    /**
+     * Renamed Issue Event
+     */
+    #[serde(flatten)]
+    pub renamed_issue_event: RenamedIssueEvent,
+    /**
+     * Review Requested Issue Event
+     */
+    #[serde(flatten)]
+    pub review_requested_issue_event: ReviewRequestedIssueEvent,
+    /**
+     * Review Request Removed Issue Event
+     */
+    #[serde(flatten)]
+    pub review_request_removed_issue_event: ReviewRequestRemovedIssueEvent,
+    /**
+     * Review Dismissed Issue Event
+     */
+    #[serde(flatten)]
+    pub review_dismissed_issue_event: ReviewDismissedIssueEvent,
+    /**
+     * Locked Issue Event
+     */
+    #[serde(flatten)]
+    pub locked_issue_event: LockedIssueEvent,
+    /**
+     * Added to Project Issue Event
+     */
+    #[serde(flatten)]
+    pub added_project_issue_event: AddedProjectIssueEvent,
+    /**
+     * Moved Column in Project Issue Event
+     */
+    #[serde(flatten)]
+    pub moved_column_in_project_issue_event: MovedColumnInProjectIssueEvent,
+    /**
+     * Removed from Project Issue Event
+     */
+    #[serde(flatten)]
+    pub removed_from_project_issue_event: RemovedFromProjectIssueEvent,
+    /**
+     * Converted Note to Issue Issue Event
+     */
+    #[serde(flatten)]
+    pub converted_note_issue_event: ConvertedNoteIssueEvent,
+}
+
+/// Timeline Event
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TimelineIssueEvents {}
+
+/**
+ * Indicates which sorts of issues to return. Can be one of:  
+ *   \* `assigned`: Issues assigned to you  
+ *   \* `created`: Issues created by you  
+ *   \* `mentioned`: Issues mentioning you  
+ *   \* `subscribed`: Issues you're subscribed to updates for  
+ *   \* `all` or `repos`: All issues the authenticated user can see, regardless of participation or creation
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Filter {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "assigned")]
+    Assigned,
+    #[serde(rename = "created")]
+    Created,
+    #[serde(rename = "mentioned")]
+    Mentioned,
+    #[serde(rename = "repos")]
+    Repos,
+    #[serde(rename = "subscribed")]
+    Subscribed,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Filter {
+    fn as_ref(&self) -> &str {
+        match self {
+            Filter::All => "all",
+            Filter::Assigned => "assigned",
+            Filter::Created => "created",
+            Filter::Mentioned => "mentioned",
+            Filter::Repos => "repos",
+            Filter::Subscribed => "subscribed",
+            Filter::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Filter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Filter {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => Filter::All,
+            "assigned" => Filter::Assigned,
+            "created" => Filter::Created,
+            "mentioned" => Filter::Mentioned,
+            "repos" => Filter::Repos,
+            "subscribed" => Filter::Subscribed,
+            _ => Filter::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Filter {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Filter> for String {
+    fn from(v: Filter) -> Self {
+        match v {
+            Filter::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Filter {
+        Filter::Assigned
+    }
+}
+
+/**
+ * Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum IssuesListState {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "closed")]
+    Closed,
+    #[serde(rename = "open")]
+    Open,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for IssuesListState {
+    fn as_ref(&self) -> &str {
+        match self {
+            IssuesListState::All => "all",
+            IssuesListState::Closed => "closed",
+            IssuesListState::Open => "open",
+            IssuesListState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for IssuesListState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for IssuesListState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => IssuesListState::All,
+            "closed" => IssuesListState::Closed,
+            "open" => IssuesListState::Open,
+            _ => IssuesListState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for IssuesListState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<IssuesListState> for String {
+    fn from(v: IssuesListState) -> Self {
+        match v {
+            IssuesListState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for IssuesListState {
+    fn default() -> IssuesListState {
+        IssuesListState::Open
+    }
+}
+
+/**
+ * What to sort results by. Can be either `created`, `updated`, `comments`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum IssuesListSort {
+    #[serde(rename = "comments")]
+    Comments,
+    #[serde(rename = "created")]
+    Created,
+    #[serde(rename = "updated")]
+    Updated,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for IssuesListSort {
+    fn as_ref(&self) -> &str {
+        match self {
+            IssuesListSort::Comments => "comments",
+            IssuesListSort::Created => "created",
+            IssuesListSort::Updated => "updated",
+            IssuesListSort::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for IssuesListSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for IssuesListSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "comments" => IssuesListSort::Comments,
+            "created" => IssuesListSort::Created,
+            "updated" => IssuesListSort::Updated,
+            _ => IssuesListSort::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for IssuesListSort {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<IssuesListSort> for String {
+    fn from(v: IssuesListSort) -> Self {
+        match v {
+            IssuesListSort::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for IssuesListSort {
+    fn default() -> IssuesListSort {
+        IssuesListSort::Created
+    }
+}
+
+/**
+ * Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum IssuesListOrgState {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "closed")]
+    Closed,
+    #[serde(rename = "open")]
+    Open,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for IssuesListOrgState {
+    fn as_ref(&self) -> &str {
+        match self {
+            IssuesListOrgState::All => "all",
+            IssuesListOrgState::Closed => "closed",
+            IssuesListOrgState::Open => "open",
+            IssuesListOrgState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for IssuesListOrgState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for IssuesListOrgState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => IssuesListOrgState::All,
+            "closed" => IssuesListOrgState::Closed,
+            "open" => IssuesListOrgState::Open,
+            _ => IssuesListOrgState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for IssuesListOrgState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<IssuesListOrgState> for String {
+    fn from(v: IssuesListOrgState) -> Self {
+        match v {
+            IssuesListOrgState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for IssuesListOrgState {
+    fn default() -> IssuesListOrgState {
+        IssuesListOrgState::Open
+    }
+}
+
+/**
+ * What to sort results by. Can be either `created`, `updated`, `comments`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum IssuesListOrgSort {
+    #[serde(rename = "comments")]
+    Comments,
+    #[serde(rename = "created")]
+    Created,
+    #[serde(rename = "updated")]
+    Updated,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for IssuesListOrgSort {
+    fn as_ref(&self) -> &str {
+        match self {
+            IssuesListOrgSort::Comments => "comments",
+            IssuesListOrgSort::Created => "created",
+            IssuesListOrgSort::Updated => "updated",
+            IssuesListOrgSort::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for IssuesListOrgSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for IssuesListOrgSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "comments" => IssuesListOrgSort::Comments,
+            "created" => IssuesListOrgSort::Created,
+            "updated" => IssuesListOrgSort::Updated,
+            _ => IssuesListOrgSort::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for IssuesListOrgSort {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<IssuesListOrgSort> for String {
+    fn from(v: IssuesListOrgSort) -> Self {
+        match v {
+            IssuesListOrgSort::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for IssuesListOrgSort {
+    fn default() -> IssuesListOrgSort {
+        IssuesListOrgSort::Created
+    }
+}
+
+/**
+ * Indicates the state of the issues to return. Can be either `open`, `closed`, or `all`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum IssuesListRepoState {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "closed")]
+    Closed,
+    #[serde(rename = "open")]
+    Open,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for IssuesListRepoState {
+    fn as_ref(&self) -> &str {
+        match self {
+            IssuesListRepoState::All => "all",
+            IssuesListRepoState::Closed => "closed",
+            IssuesListRepoState::Open => "open",
+            IssuesListRepoState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for IssuesListRepoState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for IssuesListRepoState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => IssuesListRepoState::All,
+            "closed" => IssuesListRepoState::Closed,
+            "open" => IssuesListRepoState::Open,
+            _ => IssuesListRepoState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for IssuesListRepoState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<IssuesListRepoState> for String {
+    fn from(v: IssuesListRepoState) -> Self {
+        match v {
+            IssuesListRepoState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for IssuesListRepoState {
+    fn default() -> IssuesListRepoState {
+        IssuesListRepoState::Open
+    }
+}
+
+/**
+ * What to sort results by. Can be either `created`, `updated`, `comments`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum IssuesListRepoSort {
+    #[serde(rename = "comments")]
+    Comments,
+    #[serde(rename = "created")]
+    Created,
+    #[serde(rename = "updated")]
+    Updated,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for IssuesListRepoSort {
+    fn as_ref(&self) -> &str {
+        match self {
+            IssuesListRepoSort::Comments => "comments",
+            IssuesListRepoSort::Created => "created",
+            IssuesListRepoSort::Updated => "updated",
+            IssuesListRepoSort::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for IssuesListRepoSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for IssuesListRepoSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "comments" => IssuesListRepoSort::Comments,
+            "created" => IssuesListRepoSort::Created,
+            "updated" => IssuesListRepoSort::Updated,
+            _ => IssuesListRepoSort::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for IssuesListRepoSort {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<IssuesListRepoSort> for String {
+    fn from(v: IssuesListRepoSort) -> Self {
+        match v {
+            IssuesListRepoSort::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for IssuesListRepoSort {
+    fn default() -> IssuesListRepoSort {
+        IssuesListRepoSort::Created
+    }
+}
+
+/// All of the following types:
+///
+/// - `String`
+/// - `i64`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum TitleOneOf {
+    String(String),
+    I64(i64),
+}
+
+impl TitleOneOf {
+    pub fn i64(&self) -> Option<&i64> {
+        if let TitleOneOf::I64(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn string(&self) -> Option<&String> {
+        if let TitleOneOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<i64> for TitleOneOf {
+    fn from(f: i64) -> Self {
+        TitleOneOf::I64(f)
+    }
+}
+
+impl std::convert::From<String> for TitleOneOf {
+    fn from(f: String) -> Self {
+        TitleOneOf::String(f)
+    }
+}
+
+impl std::convert::From<TitleOneOf> for i64 {
+    fn from(f: TitleOneOf) -> Self {
+        *f.i64().unwrap()
+    }
+}
+
+impl std::convert::From<TitleOneOf> for String {
+    fn from(f: TitleOneOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+/// All of the following types:
+///
+/// - `String`
+/// - `i64`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum MilestoneOneOf {
+    String(String),
+    I64(i64),
+}
+
+impl MilestoneOneOf {
+    pub fn i64(&self) -> Option<&i64> {
+        if let MilestoneOneOf::I64(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn string(&self) -> Option<&String> {
+        if let MilestoneOneOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<i64> for MilestoneOneOf {
+    fn from(f: i64) -> Self {
+        MilestoneOneOf::I64(f)
+    }
+}
+
+impl std::convert::From<String> for MilestoneOneOf {
+    fn from(f: String) -> Self {
+        MilestoneOneOf::String(f)
+    }
+}
+
+impl std::convert::From<MilestoneOneOf> for i64 {
+    fn from(f: MilestoneOneOf) -> Self {
+        *f.i64().unwrap()
+    }
+}
+
+impl std::convert::From<MilestoneOneOf> for String {
+    fn from(f: MilestoneOneOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesCreateRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub assignee: Nullable<String>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub assignees: Vec<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub body: Nullable<String>,
+    /**
+     * Labels to associate with this issue. _NOTE: Only users with push access can set labels for new issues. Labels are silently dropped otherwise._
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub labels: Vec<IssuesCreateRequestLabelsOneOf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<MilestoneOneOf>,
+    #[serde()]
+    pub title: TitleOneOf,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesUpdateCommentRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub body: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesUpdateRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub assignee: Nullable<String>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub assignees: Vec<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub body: Nullable<String>,
+    /**
+     * Labels to associate with this issue. Pass one or more Labels to _replace_ the set of Labels on this Issue. Send an empty array (`[]`) to clear all Labels from the Issue. _NOTE: Only users with push access can set labels for issues. Labels are silently dropped otherwise._
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub labels: Vec<IssuesUpdateRequestLabelsOneOf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<MilestoneOneOf>,
+    /**
+     * State of this Pull Request. Either `open` or `closed`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<TitleOneOf>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesAddAssigneesRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub assignees: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesRemoveAssigneesRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub assignees: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesCreateCommentRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub body: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesAddLabelsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub labels: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesAddLabelsRequestData {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesAddLabelsRequestDataType {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub labels: Vec<IssuesAddLabelsRequestData>,
+}
+
+/// All of the following types:
+///
+/// - `String`
+/// - `Vec<String>`
+/// - `IssuesAddLabelsRequest`
+/// - `Vec<IssuesAddLabelsRequestData>`
+/// - `IssuesAddLabelsRequestDataType`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum IssuesAddLabelsRequestOneOf {
+    String(String),
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    IssuesAddLabelsRequest(IssuesAddLabelsRequest),
+    IssuesAddLabelsRequestDataVector(Vec<IssuesAddLabelsRequestData>),
+    IssuesAddLabelsRequestDataType(IssuesAddLabelsRequestDataType),
+}
+
+impl IssuesAddLabelsRequestOneOf {
+    pub fn issues_add_labels_request(&self) -> Option<&IssuesAddLabelsRequest> {
+        if let IssuesAddLabelsRequestOneOf::IssuesAddLabelsRequest(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn issues_add_labels_request_data_type(&self) -> Option<&IssuesAddLabelsRequestDataType> {
+        if let IssuesAddLabelsRequestOneOf::IssuesAddLabelsRequestDataType(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_issues_add_labels_request_data(&self) -> Option<&Vec<IssuesAddLabelsRequestData>> {
+        if let IssuesAddLabelsRequestOneOf::IssuesAddLabelsRequestDataVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn string(&self) -> Option<&String> {
+        if let IssuesAddLabelsRequestOneOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let IssuesAddLabelsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<IssuesAddLabelsRequestData>> for IssuesAddLabelsRequestOneOf {
+    fn from(f: Vec<IssuesAddLabelsRequestData>) -> Self {
+        IssuesAddLabelsRequestOneOf::IssuesAddLabelsRequestDataVector(f)
+    }
+}
+
+impl std::convert::From<String> for IssuesAddLabelsRequestOneOf {
+    fn from(f: String) -> Self {
+        IssuesAddLabelsRequestOneOf::String(f)
+    }
+}
+
+impl std::convert::From<Vec<String>> for IssuesAddLabelsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        IssuesAddLabelsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<IssuesAddLabelsRequestOneOf> for Vec<IssuesAddLabelsRequestData> {
+    fn from(f: IssuesAddLabelsRequestOneOf) -> Self {
+        f.vec_issues_add_labels_request_data().unwrap().clone()
+    }
+}
+
+impl std::convert::From<IssuesAddLabelsRequestOneOf> for String {
+    fn from(f: IssuesAddLabelsRequestOneOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+impl std::convert::From<IssuesAddLabelsRequestOneOf> for Vec<String> {
+    fn from(f: IssuesAddLabelsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesSetLabelsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub labels: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesSetLabelsRequestData {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesSetLabelsRequestDataType {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub labels: Vec<IssuesSetLabelsRequestData>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `String`
+/// - `Vec<String>`
+/// - `IssuesSetLabelsRequest`
+/// - `Vec<IssuesSetLabelsRequestData>`
+/// - `IssuesSetLabelsRequestDataType`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesSetLabelsRequestAnyOf {
+    #[serde(flatten)]
+    pub string: String,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(flatten)]
+    pub string_vector: Vec<String>,
+    #[serde(flatten)]
+    pub issues_set_labels_request: IssuesSetLabelsRequest,
+    #[serde(flatten)]
+    pub issues_set_labels_request_data_vector: Vec<IssuesSetLabelsRequestData>,
+    #[serde(flatten)]
+    pub issues_set_labels_request_data_type: IssuesSetLabelsRequestDataType,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesLockRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lock_reason: Option<LockReason>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesCreateLabelRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub color: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesUpdateLabelRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub color: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub new_name: Nullable<String>,
+}
+
+/**
+ * The state of the milestone. Either `open`, `closed`, or `all`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum IssuesListMilestonesState {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "closed")]
+    Closed,
+    #[serde(rename = "open")]
+    Open,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for IssuesListMilestonesState {
+    fn as_ref(&self) -> &str {
+        match self {
+            IssuesListMilestonesState::All => "all",
+            IssuesListMilestonesState::Closed => "closed",
+            IssuesListMilestonesState::Open => "open",
+            IssuesListMilestonesState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for IssuesListMilestonesState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for IssuesListMilestonesState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => IssuesListMilestonesState::All,
+            "closed" => IssuesListMilestonesState::Closed,
+            "open" => IssuesListMilestonesState::Open,
+            _ => IssuesListMilestonesState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for IssuesListMilestonesState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<IssuesListMilestonesState> for String {
+    fn from(v: IssuesListMilestonesState) -> Self {
+        match v {
+            IssuesListMilestonesState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for IssuesListMilestonesState {
+    fn default() -> IssuesListMilestonesState {
+        IssuesListMilestonesState::Open
+    }
+}
+
+/**
+ * What to sort results by. Either `due_on` or `completeness`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum IssuesListMilestonesSort {
+    #[serde(rename = "completeness")]
+    Completeness,
+    #[serde(rename = "due_on")]
+    DueOn,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for IssuesListMilestonesSort {
+    fn as_ref(&self) -> &str {
+        match self {
+            IssuesListMilestonesSort::Completeness => "completeness",
+            IssuesListMilestonesSort::DueOn => "due_on",
+            IssuesListMilestonesSort::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for IssuesListMilestonesSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for IssuesListMilestonesSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "completeness" => IssuesListMilestonesSort::Completeness,
+            "due_on" => IssuesListMilestonesSort::DueOn,
+            _ => IssuesListMilestonesSort::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for IssuesListMilestonesSort {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<IssuesListMilestonesSort> for String {
+    fn from(v: IssuesListMilestonesSort) -> Self {
+        match v {
+            IssuesListMilestonesSort::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for IssuesListMilestonesSort {
+    fn default() -> IssuesListMilestonesSort {
+        IssuesListMilestonesSort::DueOn
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesCreateMilestoneRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub due_on: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * State of this Pull Request. Either `open` or `closed`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub title: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IssuesUpdateMilestoneRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub due_on: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * State of this Pull Request. Either `open` or `closed`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub title: Nullable<String>,
+}
@@ -0,0 +1,18 @@
+//! The data types of the `markdown` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MarkdownRenderRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub context: Nullable<String>,
+    /**
+     * The rendering mode.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<Mode>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub text: Nullable<String>,
+}
@@ -0,0 +1,1318 @@
+//! The data types of the `orgs` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Organization Full
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrganizationFull {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub avatar_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub billing_email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub blog: String,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub collaborators: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub company: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub default_repository_permission: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub disk_usage: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub events_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub followers: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub following: i64,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_organization_projects: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_repository_projects: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub hooks_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_verified: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issues_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub location: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub members_allowed_repository_creation_type: String,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub members_can_create_internal_repositories: bool,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub members_can_create_pages: bool,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub members_can_create_private_pages: bool,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub members_can_create_private_repositories: bool,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub members_can_create_public_pages: bool,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub members_can_create_public_repositories: bool,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub members_can_create_repositories: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub members_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub owned_private_repos: i64,
+    /**
+     * Organization Full
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan: Option<OrganizationFullPlan>,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub private_gists: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub public_gists: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub public_members_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub public_repos: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repos_url: String,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_private_repos: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub twitter_username: String,
+    /**
+     * Organization Full
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub two_factor_requirement_enabled: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Credential Authorization
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CredentialAuthorization {
+    /**
+     * Credential Authorization
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub authorized_credential_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub authorized_credential_note: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub authorized_credential_title: String,
+    /**
+     * Credential Authorization
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub credential_accessed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub credential_authorized_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub credential_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub credential_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub fingerprint: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login: String,
+    /**
+     * Credential Authorization
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token_last_eight: String,
+}
+
+/// Organization Invitation
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrganizationInvitation {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub failed_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub failed_reason: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub invitation_teams_url: String,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inviter: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub role: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub team_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Config {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub insecure_ssl: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Org Hook
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgHook {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub active: bool,
+    #[serde()]
+    pub config: Config,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub deliveries_url: String,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ping_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/**
+ * The state of the member in the organization. The `pending` state indicates the user has not yet accepted an invitation.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OrgMembershipState {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "pending")]
+    Pending,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OrgMembershipState {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrgMembershipState::Active => "active",
+            OrgMembershipState::Pending => "pending",
+            OrgMembershipState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OrgMembershipState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OrgMembershipState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => OrgMembershipState::Active,
+            "pending" => OrgMembershipState::Pending,
+            _ => OrgMembershipState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OrgMembershipState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OrgMembershipState> for String {
+    fn from(v: OrgMembershipState) -> Self {
+        match v {
+            OrgMembershipState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * The user's membership type in the organization.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Role {
+    #[serde(rename = "admin")]
+    Admin,
+    #[serde(rename = "billing_manager")]
+    BillingManager,
+    #[serde(rename = "member")]
+    Member,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Role {
+    fn as_ref(&self) -> &str {
+        match self {
+            Role::Admin => "admin",
+            Role::BillingManager => "billing_manager",
+            Role::Member => "member",
+            Role::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Role {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => Role::Admin,
+            "billing_manager" => Role::BillingManager,
+            "member" => Role::Member,
+            _ => Role::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Role {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Role> for String {
+    fn from(v: Role) -> Self {
+        match v {
+            Role::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// Org Membership
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgMembership {
+    /**
+     * Organization Simple
+     */
+    #[serde()]
+    pub organization: OrganizationSimple,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub organization_url: String,
+    /**
+     * Org Membership
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<OrgMembershipPermissions>,
+    /**
+     * The user's membership type in the organization.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<Role>,
+    /**
+     * The state of the member in the organization. The `pending` state indicates the user has not yet accepted an invitation.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<OrgMembershipState>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<SimpleUser>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsUpdateRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub billing_email: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub blog: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub company: Nullable<String>,
+    /**
+     * Default permission level members have for organization repositories:  
+     *  \\* `read` - can pull, but not push to or administer this repository.  
+     *  \\* `write` - can pull and push, but not administer this repository.  
+     *  \\* `admin` - can pull, push, and administer this repository.  
+     *  \\* `none` - no permissions granted by default.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_repository_permission: Option<DefaultRepositoryPermission>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub email: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_organization_projects: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_repository_projects: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub location: Nullable<String>,
+    /**
+     * Specifies which types of repositories non-admin organization members can create. Can be one of:  
+     *  \\* `all` - all organization members can create public and private repositories.  
+     *  \\* `private` - members can create private repositories. This option is only available to repositories that are part of an organization on GitHub Enterprise Cloud.  
+     *  \\* `none` - only admin members can create repositories.  
+     *  \*\*Note:\*\* This parameter is deprecated and will be removed in the future. Its return value ignores internal repositories. Using this parameter overrides values set in `members_can_create_repositories`. See the parameter deprecation notice in the operation description for details.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_allowed_repository_creation_type: Option<MembersAllowedRepositoryCreationType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_internal_repositories: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_pages: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_private_pages: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_private_repositories: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_public_pages: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_public_repositories: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members_can_create_repositories: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub twitter_username: Nullable<String>,
+}
+
+/// All of the following types:
+///
+/// - `ValidationErrorSimple`
+/// - `ValidationError`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OrgsUpdateResponseOneOf {
+    /**
+     * Validation Error Simple
+     */
+    ValidationErrorSimple(ValidationErrorSimple),
+    /**
+     * Validation Error
+     */
+    ValidationError(ValidationError),
+}
+
+impl OrgsUpdateResponseOneOf {
+    pub fn validation_error(&self) -> Option<&ValidationError> {
+        if let OrgsUpdateResponseOneOf::ValidationError(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn validation_error_simple(&self) -> Option<&ValidationErrorSimple> {
+        if let OrgsUpdateResponseOneOf::ValidationErrorSimple(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+/// Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#create-hook-config-params).
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsCreateWebhookRequestConfig {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub content_type: Nullable<String>,
+    /**
+     * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#create-hook-config-params).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub password: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub secret: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub url: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub username: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsCreateWebhookRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /**
+     * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#create-hook-config-params).
+     */
+    #[serde()]
+    pub config: OrgsCreateWebhookRequestConfig,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsUpdateWebhookRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /**
+     * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/orgs#update-hook-config-params).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<OrgsUpdateWebhookRequestConfig>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsUpdateWebhookConfigOrgRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub content_type: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub secret: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub url: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsListAppInstallationsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub installations: Vec<Installation>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsCreateInvitationRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub email: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub invitee_id: i64,
+    /**
+     * Specify role for new member. Can be one of:  
+     *  \\* `admin` - Organization owners with full administrative rights to the organization and complete access to all repositories and teams.  
+     *  \\* `direct_member` - Non-owner organization members with ability to see other members and join teams by invitation.  
+     *  \\* `billing_manager` - Non-owner organization members with ability to manage the billing settings of your organization.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<OrgsCreateInvitationRequestRole>,
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub team_ids: Vec<i64>,
+}
+
+/**
+ * Filter members returned in the list. Can be one of:  
+ *   \* `2fa_disabled` - Members without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled. Available for organization owners.  
+ *   \* `all` - All members the authenticated user can see.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OrgsListMembersFilter {
+    #[serde(rename = "2fa_disabled")]
+    TwoFaDisabled,
+    #[serde(rename = "all")]
+    All,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OrgsListMembersFilter {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrgsListMembersFilter::TwoFaDisabled => "2fa_disabled",
+            OrgsListMembersFilter::All => "all",
+            OrgsListMembersFilter::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OrgsListMembersFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OrgsListMembersFilter {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "2fa_disabled" => OrgsListMembersFilter::TwoFaDisabled,
+            "all" => OrgsListMembersFilter::All,
+            _ => OrgsListMembersFilter::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OrgsListMembersFilter {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OrgsListMembersFilter> for String {
+    fn from(v: OrgsListMembersFilter) -> Self {
+        match v {
+            OrgsListMembersFilter::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for OrgsListMembersFilter {
+    fn default() -> OrgsListMembersFilter {
+        OrgsListMembersFilter::All
+    }
+}
+
+/**
+ * Filter members returned by their role. Can be one of:  
+ *   \* `all` - All members of the organization, regardless of role.  
+ *   \* `admin` - Organization owners.  
+ *   \* `member` - Non-owner organization members.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OrgsListMembersRole {
+    #[serde(rename = "admin")]
+    Admin,
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "member")]
+    Member,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OrgsListMembersRole {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrgsListMembersRole::Admin => "admin",
+            OrgsListMembersRole::All => "all",
+            OrgsListMembersRole::Member => "member",
+            OrgsListMembersRole::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OrgsListMembersRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OrgsListMembersRole {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => OrgsListMembersRole::Admin,
+            "all" => OrgsListMembersRole::All,
+            "member" => OrgsListMembersRole::Member,
+            _ => OrgsListMembersRole::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OrgsListMembersRole {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OrgsListMembersRole> for String {
+    fn from(v: OrgsListMembersRole) -> Self {
+        match v {
+            OrgsListMembersRole::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for OrgsListMembersRole {
+    fn default() -> OrgsListMembersRole {
+        OrgsListMembersRole::All
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsSetMembershipUserRequest {
+    /**
+     * The role to give the user in the organization. Can be one of:  
+     *  \\* `admin` - The user will become an owner of the organization.  
+     *  \\* `member` - The user will become a non-owner member of the organization.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<OrgsSetMembershipUserRequestRole>,
+}
+
+/**
+ * Filter the list of outside collaborators. Can be one of:  
+ *   \* `2fa_disabled`: Outside collaborators without [two-factor authentication](https://github.com/blog/1614-two-factor-authentication) enabled.  
+ *   \* `all`: All outside collaborators.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OrgsListOutsideCollaboratorsFilter {
+    #[serde(rename = "2fa_disabled")]
+    TwoFaDisabled,
+    #[serde(rename = "all")]
+    All,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OrgsListOutsideCollaboratorsFilter {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrgsListOutsideCollaboratorsFilter::TwoFaDisabled => "2fa_disabled",
+            OrgsListOutsideCollaboratorsFilter::All => "all",
+            OrgsListOutsideCollaboratorsFilter::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OrgsListOutsideCollaboratorsFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OrgsListOutsideCollaboratorsFilter {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "2fa_disabled" => OrgsListOutsideCollaboratorsFilter::TwoFaDisabled,
+            "all" => OrgsListOutsideCollaboratorsFilter::All,
+            _ => OrgsListOutsideCollaboratorsFilter::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OrgsListOutsideCollaboratorsFilter {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OrgsListOutsideCollaboratorsFilter> for String {
+    fn from(v: OrgsListOutsideCollaboratorsFilter) -> Self {
+        match v {
+            OrgsListOutsideCollaboratorsFilter::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for OrgsListOutsideCollaboratorsFilter {
+    fn default() -> OrgsListOutsideCollaboratorsFilter {
+        OrgsListOutsideCollaboratorsFilter::All
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsConvertMemberOutsideCollaboratorResponse {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsRemoveOutsideCollaboratorResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub documentation_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+}
+
+/**
+ * Indicates the state of the memberships to return. Can be either `active` or `pending`. If not specified, the API returns both active and pending memberships.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OrgsListMembershipsState {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "pending")]
+    Pending,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OrgsListMembershipsState {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrgsListMembershipsState::Active => "active",
+            OrgsListMembershipsState::Pending => "pending",
+            OrgsListMembershipsState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OrgsListMembershipsState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OrgsListMembershipsState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => OrgsListMembershipsState::Active,
+            "pending" => OrgsListMembershipsState::Pending,
+            _ => OrgsListMembershipsState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OrgsListMembershipsState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OrgsListMembershipsState> for String {
+    fn from(v: OrgsListMembershipsState) -> Self {
+        match v {
+            OrgsListMembershipsState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * The state that the membership should be in. Only `"active"` will be accepted.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OrgsUpdateMembershipRequestState {
+    #[serde(rename = "active")]
+    Active,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OrgsUpdateMembershipRequestState {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrgsUpdateMembershipRequestState::Active => "active",
+            OrgsUpdateMembershipRequestState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OrgsUpdateMembershipRequestState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OrgsUpdateMembershipRequestState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => OrgsUpdateMembershipRequestState::Active,
+            _ => OrgsUpdateMembershipRequestState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OrgsUpdateMembershipRequestState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OrgsUpdateMembershipRequestState> for String {
+    fn from(v: OrgsUpdateMembershipRequestState) -> Self {
+        match v {
+            OrgsUpdateMembershipRequestState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgsUpdateMembershipRequest {
+    /**
+     * The state that the membership should be in. Only `"active"` will be accepted.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<OrgsUpdateMembershipRequestState>,
+}
@@ -0,0 +1,331 @@
+//! The data types of the `packages` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PackageVisibility {
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "public")]
+    Public,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PackageVisibility {
+    fn as_ref(&self) -> &str {
+        match self {
+            PackageVisibility::Private => "private",
+            PackageVisibility::Public => "public",
+            PackageVisibility::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PackageVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PackageVisibility {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "private" => PackageVisibility::Private,
+            "public" => PackageVisibility::Public,
+            _ => PackageVisibility::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PackageVisibility {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PackageVisibility> for String {
+    fn from(v: PackageVisibility) -> Self {
+        match v {
+            PackageVisibility::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// A software package
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Package {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<SimpleUser>,
+    /**
+     * The type of supported package. Can be one of `npm`, `maven`, `rubygems`, `nuget`, `docker`, or `container`. Packages in GitHub's Gradle registry have the type `maven`. Docker images pushed to GitHub's Container registry (`ghcr.io`) have the type `container`. You can use the type `docker` to find images that were pushed to GitHub's Docker registry (`docker.pkg.github.com`), even if these have now been migrated to the Container registry.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package_type: Option<PackageType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repository: Option<MinimalRepository>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub version_count: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<PackageVisibility>,
+}
+
+/// A version of a software package
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PackageVersion {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * A version of a software package
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub license: String,
+    /**
+     * A version of a software package
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<PackageVersionMetadata>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub package_html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/**
+ * The state of the package, either active or deleted.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PackagesGetAllPackageVersionsOwnedByOrgState {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "deleted")]
+    Deleted,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PackagesGetAllPackageVersionsOwnedByOrgState {
+    fn as_ref(&self) -> &str {
+        match self {
+            PackagesGetAllPackageVersionsOwnedByOrgState::Active => "active",
+            PackagesGetAllPackageVersionsOwnedByOrgState::Deleted => "deleted",
+            PackagesGetAllPackageVersionsOwnedByOrgState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PackagesGetAllPackageVersionsOwnedByOrgState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PackagesGetAllPackageVersionsOwnedByOrgState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => PackagesGetAllPackageVersionsOwnedByOrgState::Active,
+            "deleted" => PackagesGetAllPackageVersionsOwnedByOrgState::Deleted,
+            _ => PackagesGetAllPackageVersionsOwnedByOrgState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PackagesGetAllPackageVersionsOwnedByOrgState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PackagesGetAllPackageVersionsOwnedByOrgState> for String {
+    fn from(v: PackagesGetAllPackageVersionsOwnedByOrgState) -> Self {
+        match v {
+            PackagesGetAllPackageVersionsOwnedByOrgState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for PackagesGetAllPackageVersionsOwnedByOrgState {
+    fn default() -> PackagesGetAllPackageVersionsOwnedByOrgState {
+        PackagesGetAllPackageVersionsOwnedByOrgState::Active
+    }
+}
+
+/**
+ * The state of the package, either active or deleted.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PackagesGetAllPackageVersionsOwnedByState {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "deleted")]
+    Deleted,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PackagesGetAllPackageVersionsOwnedByState {
+    fn as_ref(&self) -> &str {
+        match self {
+            PackagesGetAllPackageVersionsOwnedByState::Active => "active",
+            PackagesGetAllPackageVersionsOwnedByState::Deleted => "deleted",
+            PackagesGetAllPackageVersionsOwnedByState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PackagesGetAllPackageVersionsOwnedByState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PackagesGetAllPackageVersionsOwnedByState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => PackagesGetAllPackageVersionsOwnedByState::Active,
+            "deleted" => PackagesGetAllPackageVersionsOwnedByState::Deleted,
+            _ => PackagesGetAllPackageVersionsOwnedByState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PackagesGetAllPackageVersionsOwnedByState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PackagesGetAllPackageVersionsOwnedByState> for String {
+    fn from(v: PackagesGetAllPackageVersionsOwnedByState) -> Self {
+        match v {
+            PackagesGetAllPackageVersionsOwnedByState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for PackagesGetAllPackageVersionsOwnedByState {
+    fn default() -> PackagesGetAllPackageVersionsOwnedByState {
+        PackagesGetAllPackageVersionsOwnedByState::Active
+    }
+}
@@ -0,0 +1,30 @@
+//! The data types of the `rate_limit` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Resources {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_scanning_upload: Option<RateLimit>,
+    #[serde()]
+    pub core: RateLimit,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub graphql: Option<RateLimit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integration_manifest: Option<RateLimit>,
+    #[serde()]
+    pub search: RateLimit,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_import: Option<RateLimit>,
+}
+
+/// Rate Limit Overview
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RateLimitOverview {
+    #[serde()]
+    pub rate: RateLimit,
+    #[serde()]
+    pub resources: Resources,
+}
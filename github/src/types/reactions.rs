@@ -0,0 +1,280 @@
+//! The data types of the `reactions` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/**
+ * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the team discussion.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Content {
+    #[serde(rename = "+1")]
+    PlusOne,
+    #[serde(rename = "-1")]
+    MinusOne,
+    #[serde(rename = "confused")]
+    Confused,
+    #[serde(rename = "eyes")]
+    Eyes,
+    #[serde(rename = "heart")]
+    Heart,
+    #[serde(rename = "hooray")]
+    Hooray,
+    #[serde(rename = "laugh")]
+    Laugh,
+    #[serde(rename = "rocket")]
+    Rocket,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Content {
+    fn as_ref(&self) -> &str {
+        match self {
+            Content::PlusOne => "+1",
+            Content::MinusOne => "-1",
+            Content::Confused => "confused",
+            Content::Eyes => "eyes",
+            Content::Heart => "heart",
+            Content::Hooray => "hooray",
+            Content::Laugh => "laugh",
+            Content::Rocket => "rocket",
+            Content::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Content {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Content {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "+1" => Content::PlusOne,
+            "-1" => Content::MinusOne,
+            "confused" => Content::Confused,
+            "eyes" => Content::Eyes,
+            "heart" => Content::Heart,
+            "hooray" => Content::Hooray,
+            "laugh" => Content::Laugh,
+            "rocket" => Content::Rocket,
+            _ => Content::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Content {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Content> for String {
+    fn from(v: Content) -> Self {
+        match v {
+            Content::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// Reactions to conversations provide a way to help people express their feelings more simply and effectively.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Reaction {
+    /**
+     * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the team discussion.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<SimpleUser>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsCreateTeamDiscussionCommentInOrgRequest {
+    /**
+     * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the team discussion.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsCreateTeamDiscussionInOrgRequest {
+    /**
+     * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the team discussion.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsCreateCommitCommentRequest {
+    /**
+     * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the team discussion.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsCreateIssueCommentRequest {
+    /**
+     * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the team discussion.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsCreateIssueRequest {
+    /**
+     * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the team discussion.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsCreatePullRequestReviewComment {
+    /**
+     * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the team discussion.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+}
+
+/**
+ * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the release.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReactionsCreateReleaseRequestContent {
+    #[serde(rename = "+1")]
+    PlusOne,
+    #[serde(rename = "eyes")]
+    Eyes,
+    #[serde(rename = "heart")]
+    Heart,
+    #[serde(rename = "hooray")]
+    Hooray,
+    #[serde(rename = "laugh")]
+    Laugh,
+    #[serde(rename = "rocket")]
+    Rocket,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReactionsCreateReleaseRequestContent {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReactionsCreateReleaseRequestContent::PlusOne => "+1",
+            ReactionsCreateReleaseRequestContent::Eyes => "eyes",
+            ReactionsCreateReleaseRequestContent::Heart => "heart",
+            ReactionsCreateReleaseRequestContent::Hooray => "hooray",
+            ReactionsCreateReleaseRequestContent::Laugh => "laugh",
+            ReactionsCreateReleaseRequestContent::Rocket => "rocket",
+            ReactionsCreateReleaseRequestContent::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReactionsCreateReleaseRequestContent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReactionsCreateReleaseRequestContent {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "+1" => ReactionsCreateReleaseRequestContent::PlusOne,
+            "eyes" => ReactionsCreateReleaseRequestContent::Eyes,
+            "heart" => ReactionsCreateReleaseRequestContent::Heart,
+            "hooray" => ReactionsCreateReleaseRequestContent::Hooray,
+            "laugh" => ReactionsCreateReleaseRequestContent::Laugh,
+            "rocket" => ReactionsCreateReleaseRequestContent::Rocket,
+            _ => ReactionsCreateReleaseRequestContent::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReactionsCreateReleaseRequestContent {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReactionsCreateReleaseRequestContent> for String {
+    fn from(v: ReactionsCreateReleaseRequestContent) -> Self {
+        match v {
+            ReactionsCreateReleaseRequestContent::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsCreateReleaseRequest {
+    /**
+     * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the release.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<ReactionsCreateReleaseRequestContent>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsCreateTeamDiscussionCommentLegacyRequest {
+    /**
+     * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the team discussion.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReactionsCreateTeamDiscussionLegacyRequest {
+    /**
+     * The [reaction type](https://docs.github.com/rest/reference/reactions#reaction-types) to add to the team discussion.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<Content>,
+}
@@ -0,0 +1,6192 @@
+//! The data types of the `repos` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Code of Conduct Simple
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CodeOfConductSimple {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FullRepositoryTemplate {}
+
+/// The status of a commit.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Status {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub avatar_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub context: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub updated_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SecurityAnalysis {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub advanced_security: Option<AdvancedSecurity>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_scanning: Option<SecretScanning>,
+}
+
+/// Full Repository
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FullRepository {
+    /**
+     * Full Repository
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub allow_auto_merge: bool,
+    /**
+     * Full Repository
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub allow_merge_commit: bool,
+    /**
+     * Full Repository
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub allow_rebase_merge: bool,
+    /**
+     * Full Repository
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub allow_squash_merge: bool,
+    /**
+     * Full Repository
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub anonymous_access_enabled: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub archive_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub archived: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub assignees_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub blobs_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub branches_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub clone_url: String,
+    /**
+     * Full Repository
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_of_conduct: Option<CodeOfConductSimple>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub collaborators_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub comments_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commits_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub compare_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub contents_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub contributors_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub default_branch: String,
+    /**
+     * Full Repository
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub delete_branch_on_merge: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub deployments_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub disabled: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub downloads_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub events_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub fork: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub forks: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub forks_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub forks_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub full_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_commits_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_refs_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_tags_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_downloads: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_issues: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_pages: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_projects: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_wiki: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub homepage: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub hooks_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Full Repository
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_template: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issue_comment_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issue_events_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issues_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub keys_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub labels_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub language: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub languages_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<LicenseSimple>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub master_branch: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub merges_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub milestones_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub mirror_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub network_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub notifications_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub open_issues: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub open_issues_count: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<SimpleUser>,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<SimpleUser>,
+    /**
+     * Full Repository
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Repository>,
+    /**
+     * Full Repository
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<FullRepositoryPermissions>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub private: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub pulls_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub pushed_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub releases_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_and_analysis: Option<SecurityAnalysis>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    /**
+     * Full Repository
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Repository>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ssh_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub stargazers_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub stargazers_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub statuses_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub subscribers_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subscribers_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subscription_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub svn_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tags_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub teams_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub temp_clone_token: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template_repository: Option<FullRepositoryTemplate>,
+    /**
+     * Full Repository
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub topics: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub trees_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub visibility: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub watchers: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub watchers_count: i64,
+}
+
+/// Details of a deployment environment
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Environment {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The type of deployment branch policy for this environment. To allow all branches to deploy, set to `null`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deployment_branch_policy: Option<DeploymentBranchPolicy>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * Details of a deployment environment
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub protection_rules: Vec<ProtectionRulesAnyOf>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// An autolink reference.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Autolink {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key_prefix: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url_template: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ShortBranchCommit {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Short Branch
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ShortBranch {
+    #[serde()]
+    pub commit: ShortBranchCommit,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub protected: bool,
+    /**
+     * Short Branch
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protection: Option<BranchProtection>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub protection_url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BranchWithProtectionLinks {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "self"
+    )]
+    pub self_: String,
+}
+
+/// Branch With Protection
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BranchWithProtection {
+    #[serde(rename = "_links")]
+    pub links: BranchWithProtectionLinks,
+    /**
+     * Commit
+     */
+    #[serde()]
+    pub commit: CommitData,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub pattern: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub protected: bool,
+    /**
+     * Branch Protection
+     */
+    #[serde()]
+    pub protection: BranchProtection,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub protection_url: String,
+    /**
+     * Branch With Protection
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub required_approving_review_count: i64,
+}
+
+/// Branch protections protect branches
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtectedBranch {
+    /**
+     * Branch protections protect branches
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_deletions: Option<ProtectedBranchAllowDeletions>,
+    /**
+     * Branch protections protect branches
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_force_pushes: Option<ProtectedBranchAllowForcePushes>,
+    /**
+     * Branch protections protect branches
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enforce_admins: Option<EnforceAdmins>,
+    /**
+     * Branch protections protect branches
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_conversation_resolution: Option<RequiredConversationResolution>,
+    /**
+     * Branch protections protect branches
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_linear_history: Option<ProtectedBranchRequiredLinearHistory>,
+    /**
+     * Branch protections protect branches
+     */
+    #[serde(default)]
+    pub required_pull_request_reviews: Option<RequiredPullRequestReviews>,
+    /**
+     * Branch protections protect branches
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_signatures: Option<RequiredSignatures>,
+    /**
+     * Branch protections protect branches
+     */
+    #[serde(default)]
+    pub required_status_checks: Option<StatusCheckPolicy>,
+    /**
+     * Branch protections protect branches
+     */
+    #[serde(default)]
+    pub restrictions: Option<BranchRestrictionPolicy>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Collaborator
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Collaborator {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub avatar_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub events_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub followers_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub following_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub gists_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub gravatar_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub organizations_url: String,
+    /**
+     * Collaborator
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<CollaboratorPermissions>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub received_events_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repos_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub site_admin: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub starred_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subscriptions_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/**
+ * The permission associated with the invitation.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum RepositoryInvitationPermissions {
+    #[serde(rename = "admin")]
+    Admin,
+    #[serde(rename = "maintain")]
+    Maintain,
+    #[serde(rename = "read")]
+    Read,
+    #[serde(rename = "triage")]
+    Triage,
+    #[serde(rename = "write")]
+    Write,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for RepositoryInvitationPermissions {
+    fn as_ref(&self) -> &str {
+        match self {
+            RepositoryInvitationPermissions::Admin => "admin",
+            RepositoryInvitationPermissions::Maintain => "maintain",
+            RepositoryInvitationPermissions::Read => "read",
+            RepositoryInvitationPermissions::Triage => "triage",
+            RepositoryInvitationPermissions::Write => "write",
+            RepositoryInvitationPermissions::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for RepositoryInvitationPermissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for RepositoryInvitationPermissions {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => RepositoryInvitationPermissions::Admin,
+            "maintain" => RepositoryInvitationPermissions::Maintain,
+            "read" => RepositoryInvitationPermissions::Read,
+            "triage" => RepositoryInvitationPermissions::Triage,
+            "write" => RepositoryInvitationPermissions::Write,
+            _ => RepositoryInvitationPermissions::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RepositoryInvitationPermissions {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<RepositoryInvitationPermissions> for String {
+    fn from(v: RepositoryInvitationPermissions) -> Self {
+        match v {
+            RepositoryInvitationPermissions::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// Repository invitations let you manage who you collaborate with.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RepositoryInvitation {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Repository invitations let you manage who you collaborate with.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub expired: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invitee: Option<SimpleUser>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inviter: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    /**
+     * The permission associated with the invitation.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<RepositoryInvitationPermissions>,
+    /**
+     * Minimal Repository
+     */
+    #[serde()]
+    pub repository: MinimalRepository,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BranchShortCommit {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Branch Short
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BranchShort {
+    #[serde()]
+    pub commit: BranchShortCommit,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub protected: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SimpleCommitStatus {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub avatar_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub context: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub required: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Combined Commit Status
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CombinedCommitStatus {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit_url: String,
+    /**
+     * Minimal Repository
+     */
+    #[serde()]
+    pub repository: MinimalRepository,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub statuses: Vec<SimpleCommitStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CommunityProfileFiles {
+    /**
+     * Code of Conduct Simple
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_of_conduct: Option<CodeOfConductSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code_of_conduct_file: Option<CommunityHealthFile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contributing: Option<CommunityHealthFile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issue_template: Option<CommunityHealthFile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub license: Option<LicenseSimple>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pull_request_template: Option<CommunityHealthFile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub readme: Option<CommunityHealthFile>,
+}
+
+/// Community Profile
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CommunityProfile {
+    /**
+     * Community Profile
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub content_reports_enabled: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub documentation: String,
+    #[serde()]
+    pub files: CommunityProfileFiles,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub health_percentage: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum CommitComparisonStatus {
+    #[serde(rename = "ahead")]
+    Ahead,
+    #[serde(rename = "behind")]
+    Behind,
+    #[serde(rename = "diverged")]
+    Diverged,
+    #[serde(rename = "identical")]
+    Identical,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for CommitComparisonStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            CommitComparisonStatus::Ahead => "ahead",
+            CommitComparisonStatus::Behind => "behind",
+            CommitComparisonStatus::Diverged => "diverged",
+            CommitComparisonStatus::Identical => "identical",
+            CommitComparisonStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for CommitComparisonStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CommitComparisonStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ahead" => CommitComparisonStatus::Ahead,
+            "behind" => CommitComparisonStatus::Behind,
+            "diverged" => CommitComparisonStatus::Diverged,
+            "identical" => CommitComparisonStatus::Identical,
+            _ => CommitComparisonStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CommitComparisonStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<CommitComparisonStatus> for String {
+    fn from(v: CommitComparisonStatus) -> Self {
+        match v {
+            CommitComparisonStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// Commit Comparison
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CommitComparison {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub ahead_by: i64,
+    /**
+     * Commit
+     */
+    #[serde()]
+    pub base_commit: CommitData,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub behind_by: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub commits: Vec<CommitData>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub diff_url: String,
+    /**
+     * Commit Comparison
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub files: Vec<DiffEntry>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    /**
+     * Commit
+     */
+    #[serde()]
+    pub merge_base_commit: CommitData,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub patch_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub permalink_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<CommitComparisonStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_commits: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContentDirectoryLinks {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "self"
+    )]
+    pub self_: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContentDirectory {
+    #[serde(rename = "_links")]
+    pub links: ContentDirectoryLinks,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub download_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContentFileLinks {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "self"
+    )]
+    pub self_: String,
+}
+
+/// Content File
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContentFile {
+    #[serde(rename = "_links")]
+    pub links: ContentFileLinks,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub download_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub encoding: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub submodule_git_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContentSymlinkLinks {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "self"
+    )]
+    pub self_: String,
+}
+
+/// An object describing a symlink
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContentSymlink {
+    #[serde(rename = "_links")]
+    pub links: ContentSymlinkLinks,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub download_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContentSubmoduleLinks {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "self"
+    )]
+    pub self_: String,
+}
+
+/// An object describing a symlink
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContentSubmodule {
+    #[serde(rename = "_links")]
+    pub links: ContentSubmoduleLinks,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub download_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub submodule_git_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FileCommitContent {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
+    pub links: Option<FileCommitContentLinks>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub download_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub git_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FileCommitAuthor {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub date: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FileCommitCommitter {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub date: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FileCommitVerification {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub payload: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub signature: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub verified: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FileCommit {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<FileCommitAuthor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committer: Option<FileCommitCommitter>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub parents: Vec<Parents>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tree: Option<Tree>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<FileCommitVerification>,
+}
+
+/// File Commit
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FileCommitData {
+    #[serde()]
+    pub commit: FileCommit,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<FileCommitContent>,
+}
+
+/// Contributor
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Contributor {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub avatar_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub contributions: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub events_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub followers_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub following_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub gists_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub gravatar_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    /**
+     * Contributor
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub organizations_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub received_events_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repos_url: String,
+    /**
+     * Contributor
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub site_admin: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub starred_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subscriptions_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/**
+ * The state of the status.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum DeploymentStatusState {
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "failure")]
+    Failure,
+    #[serde(rename = "in_progress")]
+    InProgress,
+    #[serde(rename = "inactive")]
+    Inactive,
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "queued")]
+    Queued,
+    #[serde(rename = "success")]
+    Success,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for DeploymentStatusState {
+    fn as_ref(&self) -> &str {
+        match self {
+            DeploymentStatusState::Error => "error",
+            DeploymentStatusState::Failure => "failure",
+            DeploymentStatusState::InProgress => "in_progress",
+            DeploymentStatusState::Inactive => "inactive",
+            DeploymentStatusState::Pending => "pending",
+            DeploymentStatusState::Queued => "queued",
+            DeploymentStatusState::Success => "success",
+            DeploymentStatusState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DeploymentStatusState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DeploymentStatusState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "error" => DeploymentStatusState::Error,
+            "failure" => DeploymentStatusState::Failure,
+            "in_progress" => DeploymentStatusState::InProgress,
+            "inactive" => DeploymentStatusState::Inactive,
+            "pending" => DeploymentStatusState::Pending,
+            "queued" => DeploymentStatusState::Queued,
+            "success" => DeploymentStatusState::Success,
+            _ => DeploymentStatusState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DeploymentStatusState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DeploymentStatusState> for String {
+    fn from(v: DeploymentStatusState) -> Self {
+        match v {
+            DeploymentStatusState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// The status of a deployment.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeploymentStatus {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub deployment_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub environment: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub environment_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub log_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub performed_via_github_app: Option<Integration>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repository_url: String,
+    /**
+     * The state of the status.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<DeploymentStatusState>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// The type of deployment branch policy for this environment. To allow all branches to deploy, set to `null`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeploymentBranchPolicy {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub custom_branch_policies: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub protected_branches: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct HookResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub code: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct HookConfig {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub digest: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub password: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub room: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subdomain: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Webhooks for repositories.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Hook {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub active: bool,
+    #[serde()]
+    pub config: HookConfig,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub deliveries_url: String,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde()]
+    pub last_response: HookResponse,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ping_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub test_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// An SSH key granting access to a single repository.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeployKey {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub read_only: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub verified: bool,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PagesSourceHash {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub branch: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PagesHttpsCertificateState {
+    #[serde(rename = "approved")]
+    Approved,
+    #[serde(rename = "authorization_created")]
+    AuthorizationCreated,
+    #[serde(rename = "authorization_pending")]
+    AuthorizationPending,
+    #[serde(rename = "authorization_revoked")]
+    AuthorizationRevoked,
+    #[serde(rename = "authorized")]
+    Authorized,
+    #[serde(rename = "bad_authz")]
+    BadAuthz,
+    #[serde(rename = "destroy_pending")]
+    DestroyPending,
+    #[serde(rename = "dns_changed")]
+    DnsChanged,
+    #[serde(rename = "errored")]
+    Errored,
+    #[serde(rename = "issued")]
+    Issued,
+    #[serde(rename = "new")]
+    New,
+    #[serde(rename = "uploaded")]
+    Uploaded,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PagesHttpsCertificateState {
+    fn as_ref(&self) -> &str {
+        match self {
+            PagesHttpsCertificateState::Approved => "approved",
+            PagesHttpsCertificateState::AuthorizationCreated => "authorization_created",
+            PagesHttpsCertificateState::AuthorizationPending => "authorization_pending",
+            PagesHttpsCertificateState::AuthorizationRevoked => "authorization_revoked",
+            PagesHttpsCertificateState::Authorized => "authorized",
+            PagesHttpsCertificateState::BadAuthz => "bad_authz",
+            PagesHttpsCertificateState::DestroyPending => "destroy_pending",
+            PagesHttpsCertificateState::DnsChanged => "dns_changed",
+            PagesHttpsCertificateState::Errored => "errored",
+            PagesHttpsCertificateState::Issued => "issued",
+            PagesHttpsCertificateState::New => "new",
+            PagesHttpsCertificateState::Uploaded => "uploaded",
+            PagesHttpsCertificateState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PagesHttpsCertificateState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PagesHttpsCertificateState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approved" => PagesHttpsCertificateState::Approved,
+            "authorization_created" => PagesHttpsCertificateState::AuthorizationCreated,
+            "authorization_pending" => PagesHttpsCertificateState::AuthorizationPending,
+            "authorization_revoked" => PagesHttpsCertificateState::AuthorizationRevoked,
+            "authorized" => PagesHttpsCertificateState::Authorized,
+            "bad_authz" => PagesHttpsCertificateState::BadAuthz,
+            "destroy_pending" => PagesHttpsCertificateState::DestroyPending,
+            "dns_changed" => PagesHttpsCertificateState::DnsChanged,
+            "errored" => PagesHttpsCertificateState::Errored,
+            "issued" => PagesHttpsCertificateState::Issued,
+            "new" => PagesHttpsCertificateState::New,
+            "uploaded" => PagesHttpsCertificateState::Uploaded,
+            _ => PagesHttpsCertificateState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PagesHttpsCertificateState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PagesHttpsCertificateState> for String {
+    fn from(v: PagesHttpsCertificateState) -> Self {
+        match v {
+            PagesHttpsCertificateState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PagesHttpsCertificate {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub domains: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub expires_at: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<PagesHttpsCertificateState>,
+}
+
+/**
+ * The status of the most recent build of the Page.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PageStatus {
+    #[serde(rename = "building")]
+    Building,
+    #[serde(rename = "built")]
+    Built,
+    #[serde(rename = "errored")]
+    Errored,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PageStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            PageStatus::Building => "building",
+            PageStatus::Built => "built",
+            PageStatus::Errored => "errored",
+            PageStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PageStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PageStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "building" => PageStatus::Building,
+            "built" => PageStatus::Built,
+            "errored" => PageStatus::Errored,
+            _ => PageStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PageStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PageStatus> for String {
+    fn from(v: PageStatus) -> Self {
+        match v {
+            PageStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// The configuration for GitHub Pages for a repository.
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Page {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub cname: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub custom_404: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde()]
+    pub https_certificate: PagesHttpsCertificate,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub https_enforced: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub public: bool,
+    #[serde()]
+    pub source: PagesSourceHash,
+    /**
+     * The status of the most recent build of the Page.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<PageStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Error {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+}
+
+/// Page Build
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PageBuild {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub commit: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub duration: i64,
+    #[serde()]
+    pub error: Error,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pusher: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Page Build Status
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PageBuildStatus {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub status: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AltDomain {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub caa_error: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub dns_resolves: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enforces_https: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_cname_record: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub has_mx_records_present: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub host: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub https_error: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_a_record: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_apex_domain: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_cloudflare_ip: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_cname_to_fastly: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_cname_to_github_user_domain: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_cname_to_pages_dot_github_dot_com: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_fastly_ip: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_https_eligible: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_non_github_pages_ip_present: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_old_ip_address: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_pages_domain: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_pointed_to_github_pages_ip: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_proxied: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_served_by_pages: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_valid: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_valid_domain: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub nameservers: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub responds_to_https: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub should_be_a_record: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uri: String,
+}
+
+/// Pages Health Check Status
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PagesHealthCheck {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alt_domain: Option<AltDomain>,
+    /**
+     * Pages Health Check Status
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<Domain>,
+}
+
+/**
+ * State of the release asset.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReleaseAssetState {
+    #[serde(rename = "open")]
+    Open,
+    #[serde(rename = "uploaded")]
+    Uploaded,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReleaseAssetState {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReleaseAssetState::Open => "open",
+            ReleaseAssetState::Uploaded => "uploaded",
+            ReleaseAssetState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReleaseAssetState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReleaseAssetState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "open" => ReleaseAssetState::Open,
+            "uploaded" => ReleaseAssetState::Uploaded,
+            _ => ReleaseAssetState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReleaseAssetState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReleaseAssetState> for String {
+    fn from(v: ReleaseAssetState) -> Self {
+        match v {
+            ReleaseAssetState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// Data related to a release.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReleaseAsset {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub browser_download_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub content_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub download_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub label: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub size: i64,
+    /**
+     * State of the release asset.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<ReleaseAssetState>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uploader: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// A release.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Release {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub assets: Vec<ReleaseAsset>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub assets_url: String,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body_html: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub body_text: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub discussion_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub draft: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * A release.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub mentions_count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub prerelease: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub published_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * A release.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<ReactionRollup>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tag_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tarball_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub target_commitish: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub upload_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub zipball_url: String,
+}
+
+/// Commit Activity
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CommitActivity {
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub days: Vec<i64>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub week: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Weeks {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub a: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub c: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub d: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub w: i64,
+}
+
+/// Contributor Activity
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContributorActivity {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub weeks: Vec<Weeks>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ParticipationStats {
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub all: Vec<i64>,
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub owner: Vec<i64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TagCommit {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sha: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Tag
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Tag {
+    #[serde()]
+    pub commit: TagCommit,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tarball_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub zipball_url: String,
+}
+
+/// A topic aggregates entities that are related to a subject.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Topic {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub names: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Traffic {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub uniques: i64,
+}
+
+/// Clone Traffic
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CloneTraffic {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub clones: Vec<Traffic>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub uniques: i64,
+}
+
+/// Content Traffic
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContentTraffic {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub path: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub uniques: i64,
+}
+
+/// Referrer Traffic
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReferrerTraffic {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub referrer: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub uniques: i64,
+}
+
+/// View Traffic
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ViewTraffic {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub count: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub uniques: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub views: Vec<Traffic>,
+}
+
+/**
+ * Must be one of: `day`, `week`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Per {
+    #[serde(rename = "day")]
+    Day,
+    #[serde(rename = "week")]
+    Week,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Per {
+    fn as_ref(&self) -> &str {
+        match self {
+            Per::Day => "day",
+            Per::Week => "week",
+            Per::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Per {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Per {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "day" => Per::Day,
+            "week" => Per::Week,
+            _ => Per::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Per {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Per> for String {
+    fn from(v: Per) -> Self {
+        match v {
+            Per::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for Per {
+    fn default() -> Per {
+        Per::Day
+    }
+}
+
+/**
+ * Specifies the types of repositories you want returned. Can be one of `all`, `public`, `private`, `forks`, `sources`, `member`, `internal`. Note: For GitHub AE, can be one of `all`, `private`, `forks`, `sources`, `member`, `internal`. Default: `all`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `type` can also be `internal`. However, the `internal` value is not yet supported when a GitHub App calls this API with an installation access token.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReposListOrgType {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "forks")]
+    Forks,
+    #[serde(rename = "internal")]
+    Internal,
+    #[serde(rename = "member")]
+    Member,
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "public")]
+    Public,
+    #[serde(rename = "sources")]
+    Sources,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReposListOrgType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReposListOrgType::All => "all",
+            ReposListOrgType::Forks => "forks",
+            ReposListOrgType::Internal => "internal",
+            ReposListOrgType::Member => "member",
+            ReposListOrgType::Private => "private",
+            ReposListOrgType::Public => "public",
+            ReposListOrgType::Sources => "sources",
+            ReposListOrgType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReposListOrgType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReposListOrgType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ReposListOrgType::All,
+            "forks" => ReposListOrgType::Forks,
+            "internal" => ReposListOrgType::Internal,
+            "member" => ReposListOrgType::Member,
+            "private" => ReposListOrgType::Private,
+            "public" => ReposListOrgType::Public,
+            "sources" => ReposListOrgType::Sources,
+            _ => ReposListOrgType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReposListOrgType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReposListOrgType> for String {
+    fn from(v: ReposListOrgType) -> Self {
+        match v {
+            ReposListOrgType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Can be one of `created`, `updated`, `pushed`, `full_name`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReposListOrgSort {
+    #[serde(rename = "created")]
+    Created,
+    #[serde(rename = "full_name")]
+    FullName,
+    #[serde(rename = "pushed")]
+    Pushed,
+    #[serde(rename = "updated")]
+    Updated,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReposListOrgSort {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReposListOrgSort::Created => "created",
+            ReposListOrgSort::FullName => "full_name",
+            ReposListOrgSort::Pushed => "pushed",
+            ReposListOrgSort::Updated => "updated",
+            ReposListOrgSort::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReposListOrgSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReposListOrgSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "created" => ReposListOrgSort::Created,
+            "full_name" => ReposListOrgSort::FullName,
+            "pushed" => ReposListOrgSort::Pushed,
+            "updated" => ReposListOrgSort::Updated,
+            _ => ReposListOrgSort::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReposListOrgSort {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReposListOrgSort> for String {
+    fn from(v: ReposListOrgSort) -> Self {
+        match v {
+            ReposListOrgSort::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for ReposListOrgSort {
+    fn default() -> ReposListOrgSort {
+        ReposListOrgSort::Created
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateInOrgRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_auto_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_merge_commit: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_rebase_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_squash_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_init: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_branch_on_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub gitignore_template: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_projects: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub homepage: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_template: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub license_template: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub team_id: i64,
+    /**
+     * Can be `public` or `private`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `visibility` can also be `internal`. Note: For GitHub Enterprise Server and GitHub AE, this endpoint will only list repositories available to all users on the enterprise. For more information, see "[Creating an internal repository](https://help.github.com/en/github/creating-cloning-and-archiving-repositories/about-repository-visibility#about-internal-repositories)" in the GitHub Help documentation.  
+     *  The `visibility` parameter overrides the `private` parameter when you use both parameters with the `nebula-preview` preview header.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<ReposCreateInOrgRequestVisibility>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposDeleteResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub documentation_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+}
+
+/// Specify which security and analysis features to enable or disable. For example, to enable GitHub Advanced Security, use this data in the body of the PATCH request: `{"security_and_analysis": {"advanced_security": {"status": "enabled"}}}`. If you have admin permissions for a private repository covered by an Advanced Security license, you can check which security and analysis features are currently enabled by using a `GET /repos/{owner}/{repo}` request.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateRequestSecurityAnalysis {
+    /**
+     * Specify which security and analysis features to enable or disable. For example, to enable GitHub Advanced Security, use this data in the body of the PATCH request: `{"security_and_analysis": {"advanced_security": {"status": "enabled"}}}`. If you have admin permissions for a private repository covered by an Advanced Security license, you can check which security and analysis features are currently enabled by using a `GET /repos/{owner}/{repo}` request.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub advanced_security: Option<ReposUpdateRequestSecurityAnalysisAdvanced>,
+    /**
+     * Specify which security and analysis features to enable or disable. For example, to enable GitHub Advanced Security, use this data in the body of the PATCH request: `{"security_and_analysis": {"advanced_security": {"status": "enabled"}}}`. If you have admin permissions for a private repository covered by an Advanced Security license, you can check which security and analysis features are currently enabled by using a `GET /repos/{owner}/{repo}` request.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_scanning: Option<ReposUpdateRequestSecurityAnalysisSecretScanning>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_auto_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_merge_commit: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_rebase_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_squash_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub archived: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub default_branch: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_branch_on_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_projects: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub homepage: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_template: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    /**
+     * Specify which security and analysis features to enable or disable. For example, to enable GitHub Advanced Security, use this data in the body of the PATCH request: `{"security_and_analysis": {"advanced_security": {"status": "enabled"}}}`. If you have admin permissions for a private repository covered by an Advanced Security license, you can check which security and analysis features are currently enabled by using a `GET /repos/{owner}/{repo}` request.
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub security_and_analysis: Nullable<ReposUpdateRequestSecurityAnalysis>,
+    /**
+     * Can be `public` or `private`. If your organization is associated with an enterprise account using GitHub Enterprise Cloud or GitHub Enterprise Server 2.20+, `visibility` can also be `internal`. The `visibility` parameter overrides the `private` parameter when you use both along with the `nebula-preview` preview header.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<ReposUpdateRequestVisibility>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateAutolinkRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub key_prefix: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub url_template: Nullable<String>,
+}
+
+/// Require status checks to pass before merging. Set to `null` to disable.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateBranchProtectionRequestRequiredStatusChecks {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub contexts: Vec<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub strict: bool,
+}
+
+/// Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateBranchProtectionRequestRequiredPullReviews {
+    /**
+     * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub dismiss_stale_reviews: bool,
+    /**
+     * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissal_restrictions:
+        Option<ReposUpdateBranchProtectionRequestRequiredPullReviewsDismissalRestrictions>,
+    /**
+     * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub require_code_owner_reviews: bool,
+    /**
+     * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub required_approving_review_count: i64,
+}
+
+/// Restrict who can push to the protected branch. User, app, and team `restrictions` are only available for organization-owned repositories. Set to `null` to disable.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Restrictions {
+    /**
+     * Restrict who can push to the protected branch. User, app, and team `restrictions` are only available for organization-owned repositories. Set to `null` to disable.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub apps: Vec<String>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub teams: Vec<String>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub users: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateBranchProtectionRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_deletions: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_force_pushes: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enforce_admins: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_conversation_resolution: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_linear_history: Option<bool>,
+    /**
+     * Require at least one approving review on a pull request, before merging. Set to `null` to disable.
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub required_pull_request_reviews:
+        Nullable<ReposUpdateBranchProtectionRequestRequiredPullReviews>,
+    /**
+     * Require status checks to pass before merging. Set to `null` to disable.
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub required_status_checks: Nullable<ReposUpdateBranchProtectionRequestRequiredStatusChecks>,
+    /**
+     * Restrict who can push to the protected branch. User, app, and team `restrictions` are only available for organization-owned repositories. Set to `null` to disable.
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub restrictions: Nullable<Restrictions>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdatePullRequestReviewProtection {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub dismiss_stale_reviews: bool,
+    /**
+     * Specify which users and teams can dismiss pull request reviews. Pass an empty `dismissal_restrictions` object to disable. User and team `dismissal_restrictions` are only available for organization-owned repositories. Omit this parameter for personal repositories.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dismissal_restrictions: Option<ReposUpdatePullRequestReviewProtectionDismissalRestrictions>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub require_code_owner_reviews: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub required_approving_review_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateStatusCheckProtectionRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub contexts: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposAddStatusCheckContextsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub contexts: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposAddStatusCheckContextsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposAddStatusCheckContextsRequestOneOf {
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    ReposAddStatusCheckContextsRequest(ReposAddStatusCheckContextsRequest),
+}
+
+impl ReposAddStatusCheckContextsRequestOneOf {
+    pub fn repos_add_status_check_contexts_request(
+        &self,
+    ) -> Option<&ReposAddStatusCheckContextsRequest> {
+        if let ReposAddStatusCheckContextsRequestOneOf::ReposAddStatusCheckContextsRequest(ref_) =
+            self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposAddStatusCheckContextsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposAddStatusCheckContextsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposAddStatusCheckContextsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposAddStatusCheckContextsRequestOneOf> for Vec<String> {
+    fn from(f: ReposAddStatusCheckContextsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposSetStatusCheckContextsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub contexts: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposSetStatusCheckContextsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposSetStatusCheckContextsRequestOneOf {
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    ReposSetStatusCheckContextsRequest(ReposSetStatusCheckContextsRequest),
+}
+
+impl ReposSetStatusCheckContextsRequestOneOf {
+    pub fn repos_set_status_check_contexts_request(
+        &self,
+    ) -> Option<&ReposSetStatusCheckContextsRequest> {
+        if let ReposSetStatusCheckContextsRequestOneOf::ReposSetStatusCheckContextsRequest(ref_) =
+            self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposSetStatusCheckContextsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposSetStatusCheckContextsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposSetStatusCheckContextsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposSetStatusCheckContextsRequestOneOf> for Vec<String> {
+    fn from(f: ReposSetStatusCheckContextsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposRemoveStatusCheckContextsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub contexts: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposRemoveStatusCheckContextsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposRemoveStatusCheckContextsRequestOneOf {
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    ReposRemoveStatusCheckContextsRequest(ReposRemoveStatusCheckContextsRequest),
+}
+
+impl ReposRemoveStatusCheckContextsRequestOneOf {
+    pub fn repos_remove_status_check_contexts_request(
+        &self,
+    ) -> Option<&ReposRemoveStatusCheckContextsRequest> {
+        if let ReposRemoveStatusCheckContextsRequestOneOf::ReposRemoveStatusCheckContextsRequest(
+            ref_,
+        ) = self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposRemoveStatusCheckContextsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposRemoveStatusCheckContextsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposRemoveStatusCheckContextsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposRemoveStatusCheckContextsRequestOneOf> for Vec<String> {
+    fn from(f: ReposRemoveStatusCheckContextsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposAddAppAccessRestrictionsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub apps: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposAddAppAccessRestrictionsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposAddAppAccessRestrictionsRequestOneOf {
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    ReposAddAppAccessRestrictionsRequest(ReposAddAppAccessRestrictionsRequest),
+}
+
+impl ReposAddAppAccessRestrictionsRequestOneOf {
+    pub fn repos_add_app_access_restrictions_request(
+        &self,
+    ) -> Option<&ReposAddAppAccessRestrictionsRequest> {
+        if let ReposAddAppAccessRestrictionsRequestOneOf::ReposAddAppAccessRestrictionsRequest(
+            ref_,
+        ) = self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposAddAppAccessRestrictionsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposAddAppAccessRestrictionsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposAddAppAccessRestrictionsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposAddAppAccessRestrictionsRequestOneOf> for Vec<String> {
+    fn from(f: ReposAddAppAccessRestrictionsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposSetAppAccessRestrictionsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub apps: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposSetAppAccessRestrictionsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposSetAppAccessRestrictionsRequestOneOf {
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    ReposSetAppAccessRestrictionsRequest(ReposSetAppAccessRestrictionsRequest),
+}
+
+impl ReposSetAppAccessRestrictionsRequestOneOf {
+    pub fn repos_set_app_access_restrictions_request(
+        &self,
+    ) -> Option<&ReposSetAppAccessRestrictionsRequest> {
+        if let ReposSetAppAccessRestrictionsRequestOneOf::ReposSetAppAccessRestrictionsRequest(
+            ref_,
+        ) = self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposSetAppAccessRestrictionsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposSetAppAccessRestrictionsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposSetAppAccessRestrictionsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposSetAppAccessRestrictionsRequestOneOf> for Vec<String> {
+    fn from(f: ReposSetAppAccessRestrictionsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposRemoveAppAccessRestrictionsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub apps: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposRemoveAppAccessRestrictionsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposRemoveAppAccessRestrictionsRequestOneOf {
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    ReposRemoveAppAccessRestrictionsRequest(ReposRemoveAppAccessRestrictionsRequest),
+}
+
+impl ReposRemoveAppAccessRestrictionsRequestOneOf {
+    pub fn repos_remove_app_access_restrictions_request(
+        &self,
+    ) -> Option<&ReposRemoveAppAccessRestrictionsRequest> {
+        if let ReposRemoveAppAccessRestrictionsRequestOneOf::ReposRemoveAppAccessRestrictionsRequest(ref_) = self {
+                                return Some(ref_);
+                            }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposRemoveAppAccessRestrictionsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposRemoveAppAccessRestrictionsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposRemoveAppAccessRestrictionsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposRemoveAppAccessRestrictionsRequestOneOf> for Vec<String> {
+    fn from(f: ReposRemoveAppAccessRestrictionsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposAddTeamAccessRestrictionsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub teams: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposAddTeamAccessRestrictionsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposAddTeamAccessRestrictionsRequestOneOf {
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    ReposAddTeamAccessRestrictionsRequest(ReposAddTeamAccessRestrictionsRequest),
+}
+
+impl ReposAddTeamAccessRestrictionsRequestOneOf {
+    pub fn repos_add_team_access_restrictions_request(
+        &self,
+    ) -> Option<&ReposAddTeamAccessRestrictionsRequest> {
+        if let ReposAddTeamAccessRestrictionsRequestOneOf::ReposAddTeamAccessRestrictionsRequest(
+            ref_,
+        ) = self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposAddTeamAccessRestrictionsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposAddTeamAccessRestrictionsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposAddTeamAccessRestrictionsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposAddTeamAccessRestrictionsRequestOneOf> for Vec<String> {
+    fn from(f: ReposAddTeamAccessRestrictionsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposSetTeamAccessRestrictionsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub teams: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposSetTeamAccessRestrictionsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposSetTeamAccessRestrictionsRequestOneOf {
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    ReposSetTeamAccessRestrictionsRequest(ReposSetTeamAccessRestrictionsRequest),
+}
+
+impl ReposSetTeamAccessRestrictionsRequestOneOf {
+    pub fn repos_set_team_access_restrictions_request(
+        &self,
+    ) -> Option<&ReposSetTeamAccessRestrictionsRequest> {
+        if let ReposSetTeamAccessRestrictionsRequestOneOf::ReposSetTeamAccessRestrictionsRequest(
+            ref_,
+        ) = self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposSetTeamAccessRestrictionsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposSetTeamAccessRestrictionsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposSetTeamAccessRestrictionsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposSetTeamAccessRestrictionsRequestOneOf> for Vec<String> {
+    fn from(f: ReposSetTeamAccessRestrictionsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposRemoveTeamAccessRestrictionsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub teams: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposRemoveTeamAccessRestrictionsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposRemoveTeamAccessRestrictionsRequestOneOf {
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    ReposRemoveTeamAccessRestrictionsRequest(ReposRemoveTeamAccessRestrictionsRequest),
+}
+
+impl ReposRemoveTeamAccessRestrictionsRequestOneOf {
+    pub fn repos_remove_team_access_restrictions_request(
+        &self,
+    ) -> Option<&ReposRemoveTeamAccessRestrictionsRequest> {
+        if let ReposRemoveTeamAccessRestrictionsRequestOneOf::ReposRemoveTeamAccessRestrictionsRequest(ref_) = self {
+                                return Some(ref_);
+                            }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposRemoveTeamAccessRestrictionsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposRemoveTeamAccessRestrictionsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposRemoveTeamAccessRestrictionsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposRemoveTeamAccessRestrictionsRequestOneOf> for Vec<String> {
+    fn from(f: ReposRemoveTeamAccessRestrictionsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposAddUserAccessRestrictionsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub users: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposAddUserAccessRestrictionsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposAddUserAccessRestrictionsRequestOneOf {
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    ReposAddUserAccessRestrictionsRequest(ReposAddUserAccessRestrictionsRequest),
+}
+
+impl ReposAddUserAccessRestrictionsRequestOneOf {
+    pub fn repos_add_user_access_restrictions_request(
+        &self,
+    ) -> Option<&ReposAddUserAccessRestrictionsRequest> {
+        if let ReposAddUserAccessRestrictionsRequestOneOf::ReposAddUserAccessRestrictionsRequest(
+            ref_,
+        ) = self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposAddUserAccessRestrictionsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposAddUserAccessRestrictionsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposAddUserAccessRestrictionsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposAddUserAccessRestrictionsRequestOneOf> for Vec<String> {
+    fn from(f: ReposAddUserAccessRestrictionsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposSetUserAccessRestrictionsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub users: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposSetUserAccessRestrictionsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposSetUserAccessRestrictionsRequestOneOf {
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    ReposSetUserAccessRestrictionsRequest(ReposSetUserAccessRestrictionsRequest),
+}
+
+impl ReposSetUserAccessRestrictionsRequestOneOf {
+    pub fn repos_set_user_access_restrictions_request(
+        &self,
+    ) -> Option<&ReposSetUserAccessRestrictionsRequest> {
+        if let ReposSetUserAccessRestrictionsRequestOneOf::ReposSetUserAccessRestrictionsRequest(
+            ref_,
+        ) = self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposSetUserAccessRestrictionsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposSetUserAccessRestrictionsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposSetUserAccessRestrictionsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposSetUserAccessRestrictionsRequestOneOf> for Vec<String> {
+    fn from(f: ReposSetUserAccessRestrictionsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposRemoveUserAccessRestrictionsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub users: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `Vec<String>`
+/// - `ReposRemoveUserAccessRestrictionsRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposRemoveUserAccessRestrictionsRequestOneOf {
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    ReposRemoveUserAccessRestrictionsRequest(ReposRemoveUserAccessRestrictionsRequest),
+}
+
+impl ReposRemoveUserAccessRestrictionsRequestOneOf {
+    pub fn repos_remove_user_access_restrictions_request(
+        &self,
+    ) -> Option<&ReposRemoveUserAccessRestrictionsRequest> {
+        if let ReposRemoveUserAccessRestrictionsRequestOneOf::ReposRemoveUserAccessRestrictionsRequest(ref_) = self {
+                                return Some(ref_);
+                            }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let ReposRemoveUserAccessRestrictionsRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<String>> for ReposRemoveUserAccessRestrictionsRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        ReposRemoveUserAccessRestrictionsRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<ReposRemoveUserAccessRestrictionsRequestOneOf> for Vec<String> {
+    fn from(f: ReposRemoveUserAccessRestrictionsRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposRenameBranchRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub new_name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposAddCollaboratorRequest {
+    /**
+     * The permission to grant the collaborator. \*\*Only valid on organization-owned repositories.\*\* Can be one of:  
+     *  \\* `pull` - can pull, but not push to or administer this repository.  
+     *  \\* `push` - can pull and push, but not administer this repository.  
+     *  \\* `admin` - can pull, push and administer this repository.  
+     *  \\* `maintain` - Recommended for project managers who need to manage the repository without access to sensitive or destructive actions.  
+     *  \\* `triage` - Recommended for contributors who need to proactively manage issues and pull requests without write access.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permission: Option<ReposAddCollaboratorRequestPermission>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub permissions: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateCommitCommentRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub body: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateCommitCommentRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub body: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub line: i64,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub path: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub position: i64,
+}
+
+/// All of the following types:
+///
+/// - `Vec<ContentDirectory>`
+/// - `ContentFile`
+/// - `ContentSymlink`
+/// - `ContentSubmodule`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ReposGetContentResponseOneOf {
+    /**
+     * A list of directory items
+     */
+    ContentDirectoryVector(Vec<ContentDirectory>),
+    /**
+     * Content File
+     */
+    ContentFile(ContentFile),
+    /**
+     * An object describing a symlink
+     */
+    ContentSymlink(ContentSymlink),
+    /**
+     * An object describing a symlink
+     */
+    ContentSubmodule(ContentSubmodule),
+}
+
+impl ReposGetContentResponseOneOf {
+    pub fn vec_content_directory(&self) -> Option<&Vec<ContentDirectory>> {
+        if let ReposGetContentResponseOneOf::ContentDirectoryVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn content_file(&self) -> Option<&ContentFile> {
+        if let ReposGetContentResponseOneOf::ContentFile(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn content_submodule(&self) -> Option<&ContentSubmodule> {
+        if let ReposGetContentResponseOneOf::ContentSubmodule(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn content_symlink(&self) -> Option<&ContentSymlink> {
+        if let ReposGetContentResponseOneOf::ContentSymlink(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<Vec<ContentDirectory>> for ReposGetContentResponseOneOf {
+    fn from(f: Vec<ContentDirectory>) -> Self {
+        ReposGetContentResponseOneOf::ContentDirectoryVector(f)
+    }
+}
+
+impl std::convert::From<ReposGetContentResponseOneOf> for Vec<ContentDirectory> {
+    fn from(f: ReposGetContentResponseOneOf) -> Self {
+        f.vec_content_directory().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateUpdateFileContentsRequest {
+    /**
+     * The author of the file. Default: The `committer` or the authenticated user if you omit `committer`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<ReposCreateUpdateFileContentsRequestAuthor>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub branch: Nullable<String>,
+    /**
+     * The person that committed the file. Default: the authenticated user.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committer: Option<ReposCreateUpdateFileContentsRequestCommitter>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub content: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub message: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub sha: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposDeleteFileRequest {
+    /**
+     * object containing information about the author.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<Author>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub branch: Nullable<String>,
+    /**
+     * object containing information about the committer.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub committer: Option<Committer>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub message: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub sha: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateDeploymentRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub environment: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<PayloadOneOf>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub production_environment: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent", rename = "ref")]
+    pub ref_: Nullable<String>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub required_contexts: Vec<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub task: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transient_environment: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateDeploymentResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+}
+
+/**
+ * The state of the status. Can be one of `error`, `failure`, `inactive`, `in_progress`, `queued` `pending`, or `success`. **Note:** To use the `inactive` state, you must provide the [`application/vnd.github.ant-man-preview+json`](https://docs.github.com/rest/overview/api-previews#enhanced-deployments) custom media type. To use the `in_progress` and `queued` states, you must provide the [`application/vnd.github.flash-preview+json`](https://docs.github.com/rest/overview/api-previews#deployment-statuses) custom media type. When you set a transient deployment to `inactive`, the deployment will be shown as `destroyed` in GitHub.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReposCreateDeploymentStatusRequestState {
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "failure")]
+    Failure,
+    #[serde(rename = "in_progress")]
+    InProgress,
+    #[serde(rename = "inactive")]
+    Inactive,
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "queued")]
+    Queued,
+    #[serde(rename = "success")]
+    Success,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReposCreateDeploymentStatusRequestState {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReposCreateDeploymentStatusRequestState::Error => "error",
+            ReposCreateDeploymentStatusRequestState::Failure => "failure",
+            ReposCreateDeploymentStatusRequestState::InProgress => "in_progress",
+            ReposCreateDeploymentStatusRequestState::Inactive => "inactive",
+            ReposCreateDeploymentStatusRequestState::Pending => "pending",
+            ReposCreateDeploymentStatusRequestState::Queued => "queued",
+            ReposCreateDeploymentStatusRequestState::Success => "success",
+            ReposCreateDeploymentStatusRequestState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReposCreateDeploymentStatusRequestState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReposCreateDeploymentStatusRequestState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "error" => ReposCreateDeploymentStatusRequestState::Error,
+            "failure" => ReposCreateDeploymentStatusRequestState::Failure,
+            "in_progress" => ReposCreateDeploymentStatusRequestState::InProgress,
+            "inactive" => ReposCreateDeploymentStatusRequestState::Inactive,
+            "pending" => ReposCreateDeploymentStatusRequestState::Pending,
+            "queued" => ReposCreateDeploymentStatusRequestState::Queued,
+            "success" => ReposCreateDeploymentStatusRequestState::Success,
+            _ => ReposCreateDeploymentStatusRequestState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReposCreateDeploymentStatusRequestState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReposCreateDeploymentStatusRequestState> for String {
+    fn from(v: ReposCreateDeploymentStatusRequestState) -> Self {
+        match v {
+            ReposCreateDeploymentStatusRequestState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateDeploymentStatusRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_inactive: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
+    /**
+     * Name for the target deployment environment, which can be changed when setting a deploy status. For example, `production`, `staging`, or `qa`. \*\*Note:\*\* This parameter requires you to use the [`application/vnd.github.flash-preview+json`](https://docs.github.com/rest/overview/api-previews#deployment-statuses) custom media type.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<ReposCreateDeploymentStatusRequestEnvironment>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub environment_url: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub log_url: Nullable<String>,
+    /**
+     * The state of the status. Can be one of `error`, `failure`, `inactive`, `in_progress`, `queued` `pending`, or `success`. \*\*Note:\*\* To use the `inactive` state, you must provide the [`application/vnd.github.ant-man-preview+json`](https://docs.github.com/rest/overview/api-previews#enhanced-deployments) custom media type. To use the `in_progress` and `queued` states, you must provide the [`application/vnd.github.flash-preview+json`](https://docs.github.com/rest/overview/api-previews#deployment-statuses) custom media type. When you set a transient deployment to `inactive`, the deployment will be shown as `destroyed` in GitHub.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<ReposCreateDeploymentStatusRequestState>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub target_url: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateDispatchEventRequest {
+    /**
+     * The request headers sent with the webhook delivery.
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub client_payload: Nullable<std::collections::BTreeMap<String, serde_json::Value>>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub event_type: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposGetAllEnvironmentsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub environments: Vec<Environment>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateUpdateEnvironmentRequestReviewers {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * The type of reviewer. Must be one of: `User` or `Team`
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<DeploymentReviewerType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateUpdateEnvironmentRequest {
+    /**
+     * The type of deployment branch policy for this environment. To allow all branches to deploy, set to `null`.
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub deployment_branch_policy: Nullable<DeploymentBranchPolicy>,
+    /**
+     * The people or teams that may review jobs that reference the environment. You can list up to six users or teams as reviewers. The reviewers must have at least read access to the repository. Only one of the required reviewers needs to approve the job for it to proceed.
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub reviewers: Nullable<Vec<ReposCreateUpdateEnvironmentRequestReviewers>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub wait_timer: i64,
+}
+
+/**
+ * The sort order. Can be either `newest`, `oldest`, or `stargazers`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReposListForksSort {
+    #[serde(rename = "newest")]
+    Newest,
+    #[serde(rename = "oldest")]
+    Oldest,
+    #[serde(rename = "stargazers")]
+    Stargazers,
+    #[serde(rename = "watchers")]
+    Watchers,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReposListForksSort {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReposListForksSort::Newest => "newest",
+            ReposListForksSort::Oldest => "oldest",
+            ReposListForksSort::Stargazers => "stargazers",
+            ReposListForksSort::Watchers => "watchers",
+            ReposListForksSort::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReposListForksSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReposListForksSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "newest" => ReposListForksSort::Newest,
+            "oldest" => ReposListForksSort::Oldest,
+            "stargazers" => ReposListForksSort::Stargazers,
+            "watchers" => ReposListForksSort::Watchers,
+            _ => ReposListForksSort::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReposListForksSort {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReposListForksSort> for String {
+    fn from(v: ReposListForksSort) -> Self {
+        match v {
+            ReposListForksSort::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for ReposListForksSort {
+    fn default() -> ReposListForksSort {
+        ReposListForksSort::Newest
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateForkRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub organization: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateWebhookRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<ReposCreateWebhookRequestConfig>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateWebhookRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub add_events: Vec<String>,
+    /**
+     * Key/value pairs to provide settings for this webhook. [These are defined below](https://docs.github.com/rest/reference/repos#create-hook-config-params).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<ReposUpdateWebhookRequestConfig>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub events: Vec<String>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub remove_events: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateWebhookConfigRepoRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub content_type: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub insecure_ssl: Option<WebhookConfigInsecureSslOneOf>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub secret: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub url: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateInvitationRequest {
+    /**
+     * The permissions that the associated user will have on the repository. Valid values are `read`, `write`, `maintain`, `triage`, and `admin`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<ReposUpdateInvitationRequestPermissions>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateDeployKeyRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub key: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub title: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposMergeRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub base: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub commit_message: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub head: Nullable<String>,
+}
+
+/// The source branch and directory used to publish your Pages site.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreatePagesSiteRequestSource {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub branch: Nullable<String>,
+    /**
+     * The source branch and directory used to publish your Pages site.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<Path>,
+}
+
+/// The source branch and directory used to publish your Pages site.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreatePagesSiteRequest {
+    /**
+     * The source branch and directory used to publish your Pages site.
+     */
+    #[serde()]
+    pub source: ReposCreatePagesSiteRequestSource,
+}
+
+/**
+ * Update the source for the repository. Must include the branch name, and may optionally specify the subdirectory `/docs`. Possible values are `"gh-pages"`, `"master"`, and `"master /docs"`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SourceData {
+    #[serde(rename = "gh-pages")]
+    GhPages,
+    #[serde(rename = "master")]
+    Master,
+    #[serde(rename = "master /docs")]
+    MasterDocs,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SourceData {
+    fn as_ref(&self) -> &str {
+        match self {
+            SourceData::GhPages => "gh-pages",
+            SourceData::Master => "master",
+            SourceData::MasterDocs => "master /docs",
+            SourceData::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SourceData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SourceData {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "gh-pages" => SourceData::GhPages,
+            "master" => SourceData::Master,
+            "master /docs" => SourceData::MasterDocs,
+            _ => SourceData::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SourceData {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SourceData> for String {
+    fn from(v: SourceData) -> Self {
+        match v {
+            SourceData::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// Update the source for the repository. Must include the branch name and path.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SourceDataType {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub branch: Nullable<String>,
+    /**
+     * The repository directory that includes the source files for the Pages site. Allowed paths are `/` or `/docs`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<Path>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `SourceData`
+/// - `SourceDataType`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SourceAnyOf {
+    /**
+     * Update the source for the repository. Must include the branch name, and may optionally specify the subdirectory `/docs`. Possible values are `"gh-pages"`, `"master"`, and `"master /docs"`.
+     */
+    #[serde(flatten)]
+    pub source_data: SourceData,
+    /**
+     * Update the source for the repository. Must include the branch name and path.
+     */
+    #[serde(flatten)]
+    pub source_data_type: SourceDataType,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateInformationAboutPagesSiteRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub cname: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub https_enforced: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<SourceAnyOf>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateReleaseRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub body: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub discussion_category_name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub draft: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prerelease: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub tag_name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub target_commitish: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateReleaseAssetRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub label: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub state: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposUpdateReleaseRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub body: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub discussion_category_name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub draft: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prerelease: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub tag_name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub target_commitish: Nullable<String>,
+}
+
+/**
+ * The state of the status. Can be one of `error`, `failure`, `pending`, or `success`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReposCreateCommitStatusRequestState {
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "failure")]
+    Failure,
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "success")]
+    Success,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReposCreateCommitStatusRequestState {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReposCreateCommitStatusRequestState::Error => "error",
+            ReposCreateCommitStatusRequestState::Failure => "failure",
+            ReposCreateCommitStatusRequestState::Pending => "pending",
+            ReposCreateCommitStatusRequestState::Success => "success",
+            ReposCreateCommitStatusRequestState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReposCreateCommitStatusRequestState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReposCreateCommitStatusRequestState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "error" => ReposCreateCommitStatusRequestState::Error,
+            "failure" => ReposCreateCommitStatusRequestState::Failure,
+            "pending" => ReposCreateCommitStatusRequestState::Pending,
+            "success" => ReposCreateCommitStatusRequestState::Success,
+            _ => ReposCreateCommitStatusRequestState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReposCreateCommitStatusRequestState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReposCreateCommitStatusRequestState> for String {
+    fn from(v: ReposCreateCommitStatusRequestState) -> Self {
+        match v {
+            ReposCreateCommitStatusRequestState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateCommitStatusRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub context: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
+    /**
+     * The state of the status. Can be one of `error`, `failure`, `pending`, or `success`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<ReposCreateCommitStatusRequestState>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub target_url: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposReplaceAllTopicsRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub names: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposTransferRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub new_owner: Nullable<String>,
+    /**
+     * Code Frequency Stat
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub team_ids: Vec<i64>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateUsingTemplateRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_all_branches: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub owner: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+}
+
+/**
+ * Can be one of `all`, `public`, or `private`. Note: For GitHub AE, can be one of `all`, `internal`, or `private`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReposListVisibility {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "public")]
+    Public,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReposListVisibility {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReposListVisibility::All => "all",
+            ReposListVisibility::Private => "private",
+            ReposListVisibility::Public => "public",
+            ReposListVisibility::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReposListVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReposListVisibility {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ReposListVisibility::All,
+            "private" => ReposListVisibility::Private,
+            "public" => ReposListVisibility::Public,
+            _ => ReposListVisibility::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReposListVisibility {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReposListVisibility> for String {
+    fn from(v: ReposListVisibility) -> Self {
+        match v {
+            ReposListVisibility::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for ReposListVisibility {
+    fn default() -> ReposListVisibility {
+        ReposListVisibility::All
+    }
+}
+
+/**
+ * Can be one of `all`, `owner`, `public`, `private`, `member`. Note: For GitHub AE, can be one of `all`, `owner`, `internal`, `private`, `member`. Default: `all`  
+ *     
+ *   Will cause a `422` error if used in the same request as **visibility** or **affiliation**. Will cause a `422` error if used in the same request as **visibility** or **affiliation**.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReposListType {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "member")]
+    Member,
+    #[serde(rename = "owner")]
+    Owner,
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "public")]
+    Public,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReposListType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReposListType::All => "all",
+            ReposListType::Member => "member",
+            ReposListType::Owner => "owner",
+            ReposListType::Private => "private",
+            ReposListType::Public => "public",
+            ReposListType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReposListType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReposListType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ReposListType::All,
+            "member" => ReposListType::Member,
+            "owner" => ReposListType::Owner,
+            "private" => ReposListType::Private,
+            "public" => ReposListType::Public,
+            _ => ReposListType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReposListType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReposListType> for String {
+    fn from(v: ReposListType) -> Self {
+        match v {
+            ReposListType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for ReposListType {
+    fn default() -> ReposListType {
+        ReposListType::All
+    }
+}
+
+/**
+ * Can be one of `created`, `updated`, `pushed`, `full_name`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReposListSort {
+    #[serde(rename = "created")]
+    Created,
+    #[serde(rename = "full_name")]
+    FullName,
+    #[serde(rename = "pushed")]
+    Pushed,
+    #[serde(rename = "updated")]
+    Updated,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReposListSort {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReposListSort::Created => "created",
+            ReposListSort::FullName => "full_name",
+            ReposListSort::Pushed => "pushed",
+            ReposListSort::Updated => "updated",
+            ReposListSort::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReposListSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReposListSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "created" => ReposListSort::Created,
+            "full_name" => ReposListSort::FullName,
+            "pushed" => ReposListSort::Pushed,
+            "updated" => ReposListSort::Updated,
+            _ => ReposListSort::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReposListSort {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReposListSort> for String {
+    fn from(v: ReposListSort) -> Self {
+        match v {
+            ReposListSort::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for ReposListSort {
+    fn default() -> ReposListSort {
+        ReposListSort::FullName
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReposCreateRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_auto_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_merge_commit: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_rebase_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_squash_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_init: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_branch_on_merge: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub description: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub gitignore_template: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_downloads: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_issues: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_projects: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_wiki: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub homepage: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_template: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub license_template: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub team_id: i64,
+}
+
+/**
+ * Can be one of `all`, `owner`, `member`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReposListUserType {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "member")]
+    Member,
+    #[serde(rename = "owner")]
+    Owner,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReposListUserType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReposListUserType::All => "all",
+            ReposListUserType::Member => "member",
+            ReposListUserType::Owner => "owner",
+            ReposListUserType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReposListUserType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReposListUserType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ReposListUserType::All,
+            "member" => ReposListUserType::Member,
+            "owner" => ReposListUserType::Owner,
+            _ => ReposListUserType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReposListUserType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReposListUserType> for String {
+    fn from(v: ReposListUserType) -> Self {
+        match v {
+            ReposListUserType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for ReposListUserType {
+    fn default() -> ReposListUserType {
+        ReposListUserType::Owner
+    }
+}
+
+/**
+ * Can be one of `created`, `updated`, `pushed`, `full_name`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReposListUserSort {
+    #[serde(rename = "created")]
+    Created,
+    #[serde(rename = "full_name")]
+    FullName,
+    #[serde(rename = "pushed")]
+    Pushed,
+    #[serde(rename = "updated")]
+    Updated,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReposListUserSort {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReposListUserSort::Created => "created",
+            ReposListUserSort::FullName => "full_name",
+            ReposListUserSort::Pushed => "pushed",
+            ReposListUserSort::Updated => "updated",
+            ReposListUserSort::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReposListUserSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReposListUserSort {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "created" => ReposListUserSort::Created,
+            "full_name" => ReposListUserSort::FullName,
+            "pushed" => ReposListUserSort::Pushed,
+            "updated" => ReposListUserSort::Updated,
+            _ => ReposListUserSort::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReposListUserSort {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReposListUserSort> for String {
+    fn from(v: ReposListUserSort) -> Self {
+        match v {
+            ReposListUserSort::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for ReposListUserSort {
+    fn default() -> ReposListUserSort {
+        ReposListUserSort::FullName
+    }
+}
@@ -0,0 +1,329 @@
+//! The data types of the `scim` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Scim Error
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimError {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub detail: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub documentation_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    /**
+     * Scim Error
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "scimType"
+    )]
+    pub scim_type: String,
+    /**
+     * Scim Error
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub status: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimProvisionInviteUserRequestName {
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "familyName"
+    )]
+    pub family_name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub formatted: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "givenName"
+    )]
+    pub given_name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimProvisionInviteUserRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "displayName"
+    )]
+    pub display_name: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<Emails>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "externalId"
+    )]
+    pub external_id: Nullable<String>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub groups: Vec<String>,
+    #[serde()]
+    pub name: ScimProvisionInviteUserRequestName,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "userName"
+    )]
+    pub user_name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimSetInformationProvisionedUserRequestName {
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "familyName"
+    )]
+    pub family_name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub formatted: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "givenName"
+    )]
+    pub given_name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimSetInformationProvisionedUserRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "displayName"
+    )]
+    pub display_name: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<Emails>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "externalId"
+    )]
+    pub external_id: Nullable<String>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub groups: Vec<String>,
+    #[serde()]
+    pub name: ScimSetInformationProvisionedUserRequestName,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "userName"
+    )]
+    pub user_name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ValueData {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub active: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "externalId"
+    )]
+    pub external_id: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "familyName"
+    )]
+    pub family_name: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "givenName"
+    )]
+    pub given_name: Nullable<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "userName"
+    )]
+    pub user_name: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ValueDataType {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub primary: bool,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub value: Nullable<String>,
+}
+
+/// All of the following types:
+///
+/// - `String`
+/// - `ValueData`
+/// - `Vec<ValueDataType>`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum ScimUpdateAttributeUserRequestOperationsValueOneOf {
+    String(String),
+    ValueData(ValueData),
+    ValueDataTypeVector(Vec<ValueDataType>),
+}
+
+impl ScimUpdateAttributeUserRequestOperationsValueOneOf {
+    pub fn string(&self) -> Option<&String> {
+        if let ScimUpdateAttributeUserRequestOperationsValueOneOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn value_data(&self) -> Option<&ValueData> {
+        if let ScimUpdateAttributeUserRequestOperationsValueOneOf::ValueData(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_value_data_type(&self) -> Option<&Vec<ValueDataType>> {
+        if let ScimUpdateAttributeUserRequestOperationsValueOneOf::ValueDataTypeVector(ref_) = self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<String> for ScimUpdateAttributeUserRequestOperationsValueOneOf {
+    fn from(f: String) -> Self {
+        ScimUpdateAttributeUserRequestOperationsValueOneOf::String(f)
+    }
+}
+
+impl std::convert::From<Vec<ValueDataType>> for ScimUpdateAttributeUserRequestOperationsValueOneOf {
+    fn from(f: Vec<ValueDataType>) -> Self {
+        ScimUpdateAttributeUserRequestOperationsValueOneOf::ValueDataTypeVector(f)
+    }
+}
+
+impl std::convert::From<ScimUpdateAttributeUserRequestOperationsValueOneOf> for String {
+    fn from(f: ScimUpdateAttributeUserRequestOperationsValueOneOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+impl std::convert::From<ScimUpdateAttributeUserRequestOperationsValueOneOf> for Vec<ValueDataType> {
+    fn from(f: ScimUpdateAttributeUserRequestOperationsValueOneOf) -> Self {
+        f.vec_value_data_type().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimUpdateAttributeUserRequestOperations {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<Op>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub path: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<ScimUpdateAttributeUserRequestOperationsValueOneOf>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScimUpdateAttributeUserRequest {
+    /**
+     * Set of operations to be performed
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "Operations"
+    )]
+    pub operations: Vec<ScimUpdateAttributeUserRequestOperations>,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub schemas: Vec<String>,
+}
@@ -0,0 +1,212 @@
+//! The data types of the `secret_scanning` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/**
+ * **Required when the `state` is `resolved`.** The reason for resolving the alert. Can be one of `false_positive`, `wont_fix`, `revoked`, or `used_in_tests`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SecretScanningAlertResolution {
+    #[serde(rename = "false_positive")]
+    FalsePositive,
+    #[serde(rename = "revoked")]
+    Revoked,
+    #[serde(rename = "used_in_tests")]
+    UsedInTests,
+    #[serde(rename = "wont_fix")]
+    WontFix,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SecretScanningAlertResolution {
+    fn as_ref(&self) -> &str {
+        match self {
+            SecretScanningAlertResolution::FalsePositive => "false_positive",
+            SecretScanningAlertResolution::Revoked => "revoked",
+            SecretScanningAlertResolution::UsedInTests => "used_in_tests",
+            SecretScanningAlertResolution::WontFix => "wont_fix",
+            SecretScanningAlertResolution::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SecretScanningAlertResolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SecretScanningAlertResolution {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "false_positive" => SecretScanningAlertResolution::FalsePositive,
+            "revoked" => SecretScanningAlertResolution::Revoked,
+            "used_in_tests" => SecretScanningAlertResolution::UsedInTests,
+            "wont_fix" => SecretScanningAlertResolution::WontFix,
+            _ => SecretScanningAlertResolution::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SecretScanningAlertResolution {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SecretScanningAlertResolution> for String {
+    fn from(v: SecretScanningAlertResolution) -> Self {
+        match v {
+            SecretScanningAlertResolution::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SecretScanningAlert {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    /**
+     * \*\*Required when the `state` is `resolved`.\*\* The reason for resolving the alert. Can be one of `false_positive`, `wont_fix`, `revoked`, or `used_in_tests`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<SecretScanningAlertResolution>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub resolved_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Simple User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_by: Option<SimpleUser>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub secret: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub secret_type: String,
+    /**
+     * Sets the state of the secret scanning alert. Can be either `open` or `resolved`. You must provide `resolution` when you set the state to `resolved`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<SecretScanningAlertState>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/**
+ * Set to `open` or `resolved` to only list secret scanning alerts in a specific state.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SecretScanningListAlertsRepoState {
+    #[serde(rename = "open")]
+    Open,
+    #[serde(rename = "resolved")]
+    Resolved,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SecretScanningListAlertsRepoState {
+    fn as_ref(&self) -> &str {
+        match self {
+            SecretScanningListAlertsRepoState::Open => "open",
+            SecretScanningListAlertsRepoState::Resolved => "resolved",
+            SecretScanningListAlertsRepoState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SecretScanningListAlertsRepoState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SecretScanningListAlertsRepoState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "open" => SecretScanningListAlertsRepoState::Open,
+            "resolved" => SecretScanningListAlertsRepoState::Resolved,
+            _ => SecretScanningListAlertsRepoState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SecretScanningListAlertsRepoState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SecretScanningListAlertsRepoState> for String {
+    fn from(v: SecretScanningListAlertsRepoState) -> Self {
+        match v {
+            SecretScanningListAlertsRepoState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SecretScanningUpdateAlertRequest {
+    /**
+     * \*\*Required when the `state` is `resolved`.\*\* The reason for resolving the alert. Can be one of `false_positive`, `wont_fix`, `revoked`, or `used_in_tests`.
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub resolution: Nullable<SecretScanningAlertResolution>,
+    /**
+     * Sets the state of the secret scanning alert. Can be either `open` or `resolved`. You must provide `resolution` when you set the state to `resolved`.
+     */
+    #[serde()]
+    pub state: SecretScanningAlertState,
+}
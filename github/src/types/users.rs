@@ -0,0 +1,972 @@
+//! The data types of the `users` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Private User
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PrivateUser {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub avatar_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub bio: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub blog: String,
+    /**
+     * Private User
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub business_plus: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub collaborators: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub company: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub disk_usage: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub events_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub followers: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub followers_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub following: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub following_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub gists_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub gravatar_id: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub hireable: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ldap_dn: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub location: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub node_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub organizations_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub owned_private_repos: i64,
+    /**
+     * Private User
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plan: Option<Plan>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub private_gists: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub public_gists: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub public_repos: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub received_events_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub repos_url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub site_admin: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub starred_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subscriptions_url: String,
+    /**
+     * Private User
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub suspended_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_private_repos: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub twitter_username: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub two_factor_authentication: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+}
+
+/// Email
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Email {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub primary: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub verified: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub visibility: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GpgKeyEmails {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub verified: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Subkeys {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub can_certify: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub can_encrypt_comms: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub can_encrypt_storage: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub can_sign: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_at: String,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub expires_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub primary_key_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub public_key: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub raw_key: String,
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub subkeys: Vec<String>,
+}
+
+/// A unique encryption key
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GpgKey {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub can_certify: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub can_encrypt_comms: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub can_encrypt_storage: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub can_sign: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<GpgKeyEmails>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub primary_key_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub public_key: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub raw_key: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub subkeys: Vec<Subkeys>,
+}
+
+/// Key
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Key {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub read_only: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub url: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub verified: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Contexts {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub octicon: String,
+}
+
+/// Hovercard
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Hovercard {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub contexts: Vec<Contexts>,
+}
+
+/// Key Simple
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct KeySimple {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub key: String,
+}
+
+/// All of the following types:
+///
+/// - `PublicUser`
+/// - `PrivateUser`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum UsersGetAuthenticatedResponseOneOf {
+    /**
+     * Public User
+     */
+    PublicUser(PublicUser),
+    /**
+     * Private User
+     */
+    PrivateUser(PrivateUser),
+}
+
+impl UsersGetAuthenticatedResponseOneOf {
+    pub fn private_user(&self) -> Option<&PrivateUser> {
+        if let UsersGetAuthenticatedResponseOneOf::PrivateUser(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn public_user(&self) -> Option<&PublicUser> {
+        if let UsersGetAuthenticatedResponseOneOf::PublicUser(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersUpdateAuthenticatedRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub bio: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub blog: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub company: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub email: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hireable: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub location: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub twitter_username: Nullable<String>,
+}
+
+/**
+ * Denotes whether an email is publicly visible.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum UsersSetPrimaryEmailVisibilityAuthenticatedRequest {
+    #[serde(rename = "private")]
+    Private,
+    #[serde(rename = "public")]
+    Public,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for UsersSetPrimaryEmailVisibilityAuthenticatedRequest {
+    fn as_ref(&self) -> &str {
+        match self {
+            UsersSetPrimaryEmailVisibilityAuthenticatedRequest::Private => "private",
+            UsersSetPrimaryEmailVisibilityAuthenticatedRequest::Public => "public",
+            UsersSetPrimaryEmailVisibilityAuthenticatedRequest::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for UsersSetPrimaryEmailVisibilityAuthenticatedRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UsersSetPrimaryEmailVisibilityAuthenticatedRequest {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "private" => UsersSetPrimaryEmailVisibilityAuthenticatedRequest::Private,
+            "public" => UsersSetPrimaryEmailVisibilityAuthenticatedRequest::Public,
+            _ => UsersSetPrimaryEmailVisibilityAuthenticatedRequest::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UsersSetPrimaryEmailVisibilityAuthenticatedRequest {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UsersSetPrimaryEmailVisibilityAuthenticatedRequest> for String {
+    fn from(v: UsersSetPrimaryEmailVisibilityAuthenticatedRequest) -> Self {
+        match v {
+            UsersSetPrimaryEmailVisibilityAuthenticatedRequest::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersSetPrimaryEmailVisibilityAuthenticatedRequestData {
+    /**
+     * Denotes whether an email is publicly visible.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<UsersSetPrimaryEmailVisibilityAuthenticatedRequest>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersAddEmailAuthenticatedRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `String`
+/// - `Vec<String>`
+/// - `UsersAddEmailAuthenticatedRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum UsersAddEmailAuthenticatedRequestOneOf {
+    String(String),
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    UsersAddEmailAuthenticatedRequest(UsersAddEmailAuthenticatedRequest),
+}
+
+impl UsersAddEmailAuthenticatedRequestOneOf {
+    pub fn string(&self) -> Option<&String> {
+        if let UsersAddEmailAuthenticatedRequestOneOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let UsersAddEmailAuthenticatedRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn users_add_email_authenticated_request(
+        &self,
+    ) -> Option<&UsersAddEmailAuthenticatedRequest> {
+        if let UsersAddEmailAuthenticatedRequestOneOf::UsersAddEmailAuthenticatedRequest(ref_) =
+            self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<String> for UsersAddEmailAuthenticatedRequestOneOf {
+    fn from(f: String) -> Self {
+        UsersAddEmailAuthenticatedRequestOneOf::String(f)
+    }
+}
+
+impl std::convert::From<Vec<String>> for UsersAddEmailAuthenticatedRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        UsersAddEmailAuthenticatedRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<UsersAddEmailAuthenticatedRequestOneOf> for String {
+    fn from(f: UsersAddEmailAuthenticatedRequestOneOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+impl std::convert::From<UsersAddEmailAuthenticatedRequestOneOf> for Vec<String> {
+    fn from(f: UsersAddEmailAuthenticatedRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+/// Deletes one or more email addresses from your GitHub account. Must contain at least one email address. **Note:** Alternatively, you can pass a single email address or an `array` of emails addresses directly, but we recommend that you pass an object using the `emails` key.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersDeleteEmailAuthenticatedRequest {
+    /**
+     * The list of events for the GitHub app
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub emails: Vec<String>,
+}
+
+/// All of the following types:
+///
+/// - `String`
+/// - `Vec<String>`
+/// - `UsersDeleteEmailAuthenticatedRequest`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum UsersDeleteEmailAuthenticatedRequestOneOf {
+    String(String),
+    /**
+     * The list of events for the GitHub app
+     */
+    StringVector(Vec<String>),
+    /**
+     * Deletes one or more email addresses from your GitHub account. Must contain at least one email address. \*\*Note:\*\* Alternatively, you can pass a single email address or an `array` of emails addresses directly, but we recommend that you pass an object using the `emails` key.
+     */
+    UsersDeleteEmailAuthenticatedRequest(UsersDeleteEmailAuthenticatedRequest),
+}
+
+impl UsersDeleteEmailAuthenticatedRequestOneOf {
+    pub fn string(&self) -> Option<&String> {
+        if let UsersDeleteEmailAuthenticatedRequestOneOf::String(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn vec_string(&self) -> Option<&Vec<String>> {
+        if let UsersDeleteEmailAuthenticatedRequestOneOf::StringVector(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn users_delete_email_authenticated_request(
+        &self,
+    ) -> Option<&UsersDeleteEmailAuthenticatedRequest> {
+        if let UsersDeleteEmailAuthenticatedRequestOneOf::UsersDeleteEmailAuthenticatedRequest(
+            ref_,
+        ) = self
+        {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+impl std::convert::From<String> for UsersDeleteEmailAuthenticatedRequestOneOf {
+    fn from(f: String) -> Self {
+        UsersDeleteEmailAuthenticatedRequestOneOf::String(f)
+    }
+}
+
+impl std::convert::From<Vec<String>> for UsersDeleteEmailAuthenticatedRequestOneOf {
+    fn from(f: Vec<String>) -> Self {
+        UsersDeleteEmailAuthenticatedRequestOneOf::StringVector(f)
+    }
+}
+
+impl std::convert::From<UsersDeleteEmailAuthenticatedRequestOneOf> for String {
+    fn from(f: UsersDeleteEmailAuthenticatedRequestOneOf) -> Self {
+        f.string().unwrap().clone()
+    }
+}
+
+impl std::convert::From<UsersDeleteEmailAuthenticatedRequestOneOf> for Vec<String> {
+    fn from(f: UsersDeleteEmailAuthenticatedRequestOneOf) -> Self {
+        f.vec_string().unwrap().clone()
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersCreateGpgKeyAuthenticatedRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub armored_public_key: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UsersCreatePublicSshKeyAuthenticatedRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub key: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub title: Nullable<String>,
+}
+
+/// All of the following types:
+///
+/// - `PublicUser`
+/// - `PrivateUser`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum UsersGetByUsernameResponseOneOf {
+    /**
+     * Public User
+     */
+    PublicUser(PublicUser),
+    /**
+     * Private User
+     */
+    PrivateUser(PrivateUser),
+}
+
+impl UsersGetByUsernameResponseOneOf {
+    pub fn private_user(&self) -> Option<&PrivateUser> {
+        if let UsersGetByUsernameResponseOneOf::PrivateUser(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn public_user(&self) -> Option<&PublicUser> {
+        if let UsersGetByUsernameResponseOneOf::PublicUser(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+/**
+ * Identifies which additional information you'd like to receive about the person's hovercard. Can be `organization`, `repository`, `issue`, `pull_request`. **Required** when using `subject_id`.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SubjectType {
+    #[serde(rename = "issue")]
+    Issue,
+    #[serde(rename = "organization")]
+    Organization,
+    #[serde(rename = "pull_request")]
+    PullRequest,
+    #[serde(rename = "repository")]
+    Repository,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SubjectType {
+    fn as_ref(&self) -> &str {
+        match self {
+            SubjectType::Issue => "issue",
+            SubjectType::Organization => "organization",
+            SubjectType::PullRequest => "pull_request",
+            SubjectType::Repository => "repository",
+            SubjectType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SubjectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SubjectType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "issue" => SubjectType::Issue,
+            "organization" => SubjectType::Organization,
+            "pull_request" => SubjectType::PullRequest,
+            "repository" => SubjectType::Repository,
+            _ => SubjectType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SubjectType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SubjectType> for String {
+    fn from(v: SubjectType) -> Self {
+        match v {
+            SubjectType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Users {
     pub client: Client,
@@ -55,7 +53,9 @@ impl Users {
      *
      * FROM: <https://docs.github.com/rest/reference/users#get-the-authenticated-user>
      */
-    pub async fn get_authenticated(&self) -> Result<crate::types::UsersGetByUsernameResponseOneOf> {
+    pub async fn get_authenticated(
+        &self,
+    ) -> Result<crate::types::UsersGetAuthenticatedResponseOneOf> {
         let url = "/user".to_string();
         self.client.get(&url, None).await
     }
@@ -109,6 +109,41 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List users blocked by the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/blocks` endpoint.
+     *
+     * As opposed to `list_all_blocked_by_authenticated`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List the users you've blocked on your personal account.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-users-blocked-by-the-authenticated-user>
+     */
+    pub fn list_blocked_by_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        let url = "/user/blocks".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Check if a user is blocked by the authenticated user.
      *
@@ -186,7 +221,7 @@ impl Users {
      */
     pub async fn set_primary_email_visibility_for_authenticated(
         &self,
-        body: &crate::types::UsersSetPrimaryEmailVisibilityAuthenticatedRequest,
+        body: &crate::types::UsersSetPrimaryEmailVisibilityAuthenticatedRequestData,
     ) -> Result<Vec<crate::types::Email>> {
         let url = "/user/email/visibility".to_string();
         self.client
@@ -205,19 +240,17 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &UsersListEmailsForAuthenticatedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_emails_for_authenticated(
+    pub async fn list_emails_for_authenticated_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &UsersListEmailsForAuthenticatedParams,
     ) -> Result<Vec<crate::types::Email>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -226,6 +259,22 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_emails_for_authenticated_with` and pass the query parameters as a `UsersListEmailsForAuthenticatedParams` instead.
+     */
+    #[deprecated(note = "use `list_emails_for_authenticated_with` instead")]
+    pub async fn list_emails_for_authenticated(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Email>> {
+        let params = UsersListEmailsForAuthenticatedParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_emails_for_authenticated_with(&params).await
+    }
+
     /**
      * List email addresses for the authenticated user.
      *
@@ -242,6 +291,41 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/emails` endpoint.
+     *
+     * As opposed to `list_all_emails_for_authenticated`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all of your email addresses, and specifies which one is visible to the public. This endpoint is accessible with the `user:email` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-email-addresses-for-the-authenticated-user>
+     */
+    pub fn list_emails_for_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Email> {
+        let url = "/user/emails".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Add an email address for the authenticated user.
      *
@@ -272,7 +356,7 @@ impl Users {
      */
     pub async fn delete_email_for_authenticated(
         &self,
-        body: &crate::types::UsersAddEmailAuthenticatedRequestOneOf,
+        body: &crate::types::UsersDeleteEmailAuthenticatedRequestOneOf,
     ) -> Result<()> {
         let url = "/user/emails".to_string();
         self.client
@@ -291,19 +375,17 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &UsersListFollowersForAuthenticatedUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_followers_for_authenticated_user(
+    pub async fn list_followers_for_authenticated_user_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &UsersListFollowersForAuthenticatedUserParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -312,6 +394,23 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_followers_for_authenticated_user_with` and pass the query parameters as a `UsersListFollowersForAuthenticatedUserParams` instead.
+     */
+    #[deprecated(note = "use `list_followers_for_authenticated_user_with` instead")]
+    pub async fn list_followers_for_authenticated_user(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = UsersListFollowersForAuthenticatedUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_followers_for_authenticated_user_with(&params)
+            .await
+    }
+
     /**
      * List followers of the authenticated user.
      *
@@ -330,6 +429,41 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List followers of the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/followers` endpoint.
+     *
+     * As opposed to `list_all_followers_for_authenticated_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the people following the authenticated user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-the-authenticated-user>
+     */
+    pub fn list_followers_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        let url = "/user/followers".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List the people the authenticated user follows.
      *
@@ -341,19 +475,17 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &UsersListFollowedByAuthenticatedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_followed_by_authenticated(
+    pub async fn list_followed_by_authenticated_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &UsersListFollowedByAuthenticatedParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -362,6 +494,22 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_followed_by_authenticated_with` and pass the query parameters as a `UsersListFollowedByAuthenticatedParams` instead.
+     */
+    #[deprecated(note = "use `list_followed_by_authenticated_with` instead")]
+    pub async fn list_followed_by_authenticated(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = UsersListFollowedByAuthenticatedParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_followed_by_authenticated_with(&params).await
+    }
+
     /**
      * List the people the authenticated user follows.
      *
@@ -380,6 +528,41 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List the people the authenticated user follows.
+     *
+     * This function performs a `GET` to the `/user/following` endpoint.
+     *
+     * As opposed to `list_all_followed_by_authenticated`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the people who the authenticated user follows.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-the-authenticated-user-follows>
+     */
+    pub fn list_followed_by_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        let url = "/user/following".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Check if a person is followed by the authenticated user.
      *
@@ -459,19 +642,17 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &UsersListGpgKeysForAuthenticatedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_gpg_keys_for_authenticated(
+    pub async fn list_gpg_keys_for_authenticated_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &UsersListGpgKeysForAuthenticatedParams,
     ) -> Result<Vec<crate::types::GpgKey>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -480,6 +661,22 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_gpg_keys_for_authenticated_with` and pass the query parameters as a `UsersListGpgKeysForAuthenticatedParams` instead.
+     */
+    #[deprecated(note = "use `list_gpg_keys_for_authenticated_with` instead")]
+    pub async fn list_gpg_keys_for_authenticated(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::GpgKey>> {
+        let params = UsersListGpgKeysForAuthenticatedParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_gpg_keys_for_authenticated_with(&params).await
+    }
+
     /**
      * List GPG keys for the authenticated user.
      *
@@ -496,6 +693,41 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List GPG keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/gpg_keys` endpoint.
+     *
+     * As opposed to `list_all_gpg_keys_for_authenticated`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the current user's GPG keys. Requires that you are authenticated via Basic Auth or via OAuth with at least `read:gpg_key` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-the-authenticated-user>
+     */
+    pub fn list_gpg_keys_for_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GpgKey> {
+        let url = "/user/gpg_keys".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a GPG key for the authenticated user.
      *
@@ -573,19 +805,17 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &UsersListPublicSshKeysForAuthenticatedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_public_ssh_keys_for_authenticated(
+    pub async fn list_public_ssh_keys_for_authenticated_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &UsersListPublicSshKeysForAuthenticatedParams,
     ) -> Result<Vec<crate::types::Key>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -594,6 +824,23 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_public_ssh_keys_for_authenticated_with` and pass the query parameters as a `UsersListPublicSshKeysForAuthenticatedParams` instead.
+     */
+    #[deprecated(note = "use `list_public_ssh_keys_for_authenticated_with` instead")]
+    pub async fn list_public_ssh_keys_for_authenticated(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Key>> {
+        let params = UsersListPublicSshKeysForAuthenticatedParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_public_ssh_keys_for_authenticated_with(&params)
+            .await
+    }
+
     /**
      * List public SSH keys for the authenticated user.
      *
@@ -612,6 +859,41 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List public SSH keys for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/keys` endpoint.
+     *
+     * As opposed to `list_all_public_ssh_keys_for_authenticated`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the public SSH keys for the authenticated user's GitHub account. Requires that you are authenticated via Basic Auth or via OAuth with at least `read:public_key` [scope](https://docs.github.com/apps/building-oauth-apps/understanding-scopes-for-oauth-apps/).
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-ssh-keys-for-the-authenticated-user>
+     */
+    pub fn list_public_ssh_keys_for_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Key> {
+        let url = "/user/keys".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a public SSH key for the authenticated user.
      *
@@ -689,19 +971,17 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &UsersListPublicEmailsForAuthenticatedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_public_emails_for_authenticated(
+    pub async fn list_public_emails_for_authenticated_with(
         &self,
-        per_page: i64,
-        page: i64,
+        params: &UsersListPublicEmailsForAuthenticatedParams,
     ) -> Result<Vec<crate::types::Email>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -710,6 +990,23 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_public_emails_for_authenticated_with` and pass the query parameters as a `UsersListPublicEmailsForAuthenticatedParams` instead.
+     */
+    #[deprecated(note = "use `list_public_emails_for_authenticated_with` instead")]
+    pub async fn list_public_emails_for_authenticated(
+        &self,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::Email>> {
+        let params = UsersListPublicEmailsForAuthenticatedParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_public_emails_for_authenticated_with(&params)
+            .await
+    }
+
     /**
      * List public email addresses for the authenticated user.
      *
@@ -728,6 +1025,41 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List public email addresses for the authenticated user.
+     *
+     * This function performs a `GET` to the `/user/public_emails` endpoint.
+     *
+     * As opposed to `list_all_public_emails_for_authenticated`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists your publicly visible email address, which you can set with the [Set primary email visibility for the authenticated user](https://docs.github.com/rest/reference/users#set-primary-email-visibility-for-the-authenticated-user) endpoint. This endpoint is accessible with the `user:email` scope.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-email-addresses-for-the-authenticated-user>
+     */
+    pub fn list_public_emails_for_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Email> {
+        let url = "/user/public_emails".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List users.
      *
@@ -741,15 +1073,17 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `since: i64` -- A user ID. Only return users with an ID greater than this ID.
-     * * `per_page: i64` -- Results per page (max 100).
+     * * `params: &UsersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(&self, since: i64, per_page: i64) -> Result<Vec<crate::types::SimpleUser>> {
+    pub async fn list_with(
+        &self,
+        params: &UsersListParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
-        if since > 0 {
+        if let Some(since) = &params.since {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -758,6 +1092,18 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `UsersListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(&self, since: i64, per_page: i64) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = UsersListParams {
+            per_page: Some(per_page).filter(|v| *v > 0),
+            since: Some(since).filter(|v| *v > 0),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * List users.
      *
@@ -770,10 +1116,17 @@ impl Users {
      * Note: Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of users.
      *
      * FROM: <https://docs.github.com/rest/reference/users#list-users>
+     *
+     * **Parameters:**
+     *
+     * * `params: &UsersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(&self, since: i64) -> Result<Vec<crate::types::SimpleUser>> {
+    pub async fn list_all_with(
+        &self,
+        params: &UsersListParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if since > 0 {
+        if let Some(since) = &params.since {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -782,6 +1135,62 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `UsersListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(&self, since: i64) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = UsersListParams {
+            since: Some(since).filter(|v| *v > 0),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all users, in the order that they signed up on GitHub. This list includes personal user accounts and organization accounts.
+     *
+     * Note: Pagination is powered exclusively by the `since` parameter. Use the [Link header](https://docs.github.com/rest/overview/resources-in-the-rest-api#link-header) to get the URL for the next page of users.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-users>
+     */
+    pub fn list_stream(
+        &self,
+        params: &UsersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/users?{}", query_);
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get a user.
      *
@@ -887,20 +1296,18 @@ impl Users {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &UsersListFollowersForUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_followers_for_user(
+    pub async fn list_followers_for_user_with(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &UsersListFollowersForUserParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -913,6 +1320,23 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_followers_for_user_with` and pass the query parameters as a `UsersListFollowersForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_followers_for_user_with` instead")]
+    pub async fn list_followers_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = UsersListFollowersForUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_followers_for_user_with(username, &params).await
+    }
+
     /**
      * List followers of a user.
      *
@@ -936,6 +1360,46 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List followers of a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/followers` endpoint.
+     *
+     * As opposed to `list_all_followers_for_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the people following the specified user.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-followers-of-a-user>
+     */
+    pub fn list_followers_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        let url = format!(
+            "/users/{}/followers",
+            crate::progenitor_support::encode_path(username),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * List the people a user follows.
      *
@@ -948,20 +1412,18 @@ impl Users {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &UsersListFollowingForUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_following_for_user(
+    pub async fn list_following_for_user_with(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &UsersListFollowingForUserParams,
     ) -> Result<Vec<crate::types::SimpleUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -975,11 +1437,28 @@ impl Users {
     }
 
     /**
-     * List the people a user follows.
-     *
-     * This function performs a `GET` to the `/users/{username}/following` endpoint.
-     *
-     * As opposed to `list_following_for_user`, this function returns all the pages of the request at once.
+     * Deprecated: use `list_following_for_user_with` and pass the query parameters as a `UsersListFollowingForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_following_for_user_with` instead")]
+    pub async fn list_following_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        let params = UsersListFollowingForUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_following_for_user_with(username, &params).await
+    }
+
+    /**
+     * List the people a user follows.
+     *
+     * This function performs a `GET` to the `/users/{username}/following` endpoint.
+     *
+     * As opposed to `list_following_for_user`, this function returns all the pages of the request at once.
      *
      * Lists the people who the specified user follows.
      *
@@ -997,6 +1476,46 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List the people a user follows.
+     *
+     * This function performs a `GET` to the `/users/{username}/following` endpoint.
+     *
+     * As opposed to `list_all_following_for_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the people who the specified user follows.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-the-people-a-user-follows>
+     */
+    pub fn list_following_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        let url = format!(
+            "/users/{}/following",
+            crate::progenitor_support::encode_path(username),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Check if a user follows another user.
      *
@@ -1033,20 +1552,18 @@ impl Users {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &UsersListGpgKeysForUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_gpg_keys_for_user(
+    pub async fn list_gpg_keys_for_user_with(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &UsersListGpgKeysForUserParams,
     ) -> Result<Vec<crate::types::GpgKey>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1059,6 +1576,23 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_gpg_keys_for_user_with` and pass the query parameters as a `UsersListGpgKeysForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_gpg_keys_for_user_with` instead")]
+    pub async fn list_gpg_keys_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::GpgKey>> {
+        let params = UsersListGpgKeysForUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_gpg_keys_for_user_with(username, &params).await
+    }
+
     /**
      * List GPG keys for a user.
      *
@@ -1082,6 +1616,46 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List GPG keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/gpg_keys` endpoint.
+     *
+     * As opposed to `list_all_gpg_keys_for_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the GPG keys for a user. This information is accessible by anyone.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-gpg-keys-for-a-user>
+     */
+    pub fn list_gpg_keys_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GpgKey> {
+        let url = format!(
+            "/users/{}/gpg_keys",
+            crate::progenitor_support::encode_path(username),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get contextual information for a user.
      *
@@ -1101,20 +1675,18 @@ impl Users {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `subject_type: crate::types::SubjectType` -- Identifies which additional information you'd like to receive about the person's hovercard. Can be `organization`, `repository`, `issue`, `pull_request`. \*\*Required\*\* when using `subject_id`.
-     * * `subject_id: &str` -- Uses the ID for the `subject_type` you specified. **Required** when using `subject_type`.
+     * * `params: &UsersGetContextForUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_context_for_user(
+    pub async fn get_context_for_user_with(
         &self,
         username: &str,
-        subject_type: crate::types::SubjectType,
-        subject_id: &str,
+        params: &UsersGetContextForUserParams,
     ) -> Result<crate::types::Hovercard> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !subject_id.is_empty() {
+        if let Some(subject_id) = &params.subject_id {
             query_args.push(("subject_id".to_string(), subject_id.to_string()));
         }
-        if !subject_type.to_string().is_empty() {
+        if let Some(subject_type) = &params.subject_type {
             query_args.push(("subject_type".to_string(), subject_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1127,6 +1699,23 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_context_for_user_with` and pass the query parameters as a `UsersGetContextForUserParams` instead.
+     */
+    #[deprecated(note = "use `get_context_for_user_with` instead")]
+    pub async fn get_context_for_user(
+        &self,
+        username: &str,
+        subject_type: crate::types::SubjectType,
+        subject_id: &str,
+    ) -> Result<crate::types::Hovercard> {
+        let params = UsersGetContextForUserParams {
+            subject_id: Some(subject_id.to_string()).filter(|s| !s.is_empty()),
+            subject_type: Some(subject_type).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_context_for_user_with(username, &params).await
+    }
+
     /**
      * List public keys for a user.
      *
@@ -1139,20 +1728,18 @@ impl Users {
      * **Parameters:**
      *
      * * `username: &str`
-     * * `per_page: i64` -- Results per page (max 100).
-     * * `page: i64` -- Page number of the results to fetch.
+     * * `params: &UsersListPublicKeysForUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_public_keys_for_user(
+    pub async fn list_public_keys_for_user_with(
         &self,
         username: &str,
-        per_page: i64,
-        page: i64,
+        params: &UsersListPublicKeysForUserParams,
     ) -> Result<Vec<crate::types::KeySimple>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if per_page > 0 {
+        if let Some(per_page) = &params.per_page {
             query_args.push(("per_page".to_string(), per_page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1165,6 +1752,23 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_public_keys_for_user_with` and pass the query parameters as a `UsersListPublicKeysForUserParams` instead.
+     */
+    #[deprecated(note = "use `list_public_keys_for_user_with` instead")]
+    pub async fn list_public_keys_for_user(
+        &self,
+        username: &str,
+        per_page: i64,
+        page: i64,
+    ) -> Result<Vec<crate::types::KeySimple>> {
+        let params = UsersListPublicKeysForUserParams {
+            page: Some(page).filter(|v| *v > 0),
+            per_page: Some(per_page).filter(|v| *v > 0),
+        };
+        self.list_public_keys_for_user_with(username, &params).await
+    }
+
     /**
      * List public keys for a user.
      *
@@ -1187,4 +1791,991 @@ impl Users {
 
         self.client.get_all_pages(&url, None).await
     }
+
+    /**
+     * List public keys for a user.
+     *
+     * This function performs a `GET` to the `/users/{username}/keys` endpoint.
+     *
+     * As opposed to `list_all_public_keys_for_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the _verified_ public SSH keys for a user. This is accessible by anyone.
+     *
+     * FROM: <https://docs.github.com/rest/reference/users#list-public-keys-for-a-user>
+     */
+    pub fn list_public_keys_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::KeySimple> {
+        let url = format!(
+            "/users/{}/keys",
+            crate::progenitor_support::encode_path(username),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Users::list_emails_for_authenticated_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListEmailsForAuthenticatedParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl UsersListEmailsForAuthenticatedParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_followers_for_authenticated_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListFollowersForAuthenticatedUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl UsersListFollowersForAuthenticatedUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_followed_by_authenticated_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListFollowedByAuthenticatedParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl UsersListFollowedByAuthenticatedParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_gpg_keys_for_authenticated_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListGpgKeysForAuthenticatedParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl UsersListGpgKeysForAuthenticatedParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_public_ssh_keys_for_authenticated_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListPublicSshKeysForAuthenticatedParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl UsersListPublicSshKeysForAuthenticatedParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_public_emails_for_authenticated_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListPublicEmailsForAuthenticatedParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl UsersListPublicEmailsForAuthenticatedParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListParams {
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+    /// A user ID. Only return users with an ID greater than this ID.
+    pub since: Option<i64>,
+}
+
+impl UsersListParams {
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+
+    pub fn since(mut self, since: i64) -> Self {
+        self.since = Some(since);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_followers_for_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListFollowersForUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl UsersListFollowersForUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_following_for_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListFollowingForUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl UsersListFollowingForUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_gpg_keys_for_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListGpgKeysForUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl UsersListGpgKeysForUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The query parameters of `Users::get_context_for_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersGetContextForUserParams {
+    /// Uses the ID for the `subject_type` you specified. **Required** when using `subject_type`.
+    pub subject_id: Option<String>,
+    /// Identifies which additional information you'd like to receive about the person's hovercard. Can be `organization`, `repository`, `issue`, `pull_request`. \*\*Required\*\* when using `subject_id`.
+    pub subject_type: Option<crate::types::SubjectType>,
+}
+
+impl UsersGetContextForUserParams {
+    pub fn subject_id<T: ToString>(mut self, subject_id: T) -> Self {
+        self.subject_id = Some(subject_id.to_string());
+        self
+    }
+
+    pub fn subject_type(mut self, subject_type: crate::types::SubjectType) -> Self {
+        self.subject_type = Some(subject_type);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_public_keys_for_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListPublicKeysForUserParams {
+    /// Page number of the results to fetch.
+    pub page: Option<i64>,
+    /// Results per page (max 100).
+    pub per_page: Option<i64>,
+}
+
+impl UsersListPublicKeysForUserParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: i64) -> Self {
+        self.per_page = Some(per_page);
+        self
+    }
+}
+
+/// The functions of [`Users`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUsersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UsersApi: Send + Sync {
+    /// See [`Users::get_authenticated_public_user`].
+    async fn get_authenticated_public_user(&self) -> Result<crate::types::PublicUser>;
+
+    /// See [`Users::get_authenticated_private_user`].
+    async fn get_authenticated_private_user(&self) -> Result<crate::types::PrivateUser>;
+
+    /// See [`Users::get_authenticated`].
+    async fn get_authenticated(&self) -> Result<crate::types::UsersGetAuthenticatedResponseOneOf>;
+
+    /// See [`Users::update_authenticated`].
+    async fn update_authenticated(
+        &self,
+        body: &crate::types::UsersUpdateAuthenticatedRequest,
+    ) -> Result<crate::types::PrivateUser>;
+
+    /// See [`Users::list_blocked_by_authenticated`].
+    async fn list_blocked_by_authenticated(&self) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Users::list_all_blocked_by_authenticated`].
+    async fn list_all_blocked_by_authenticated(&self) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Users::list_blocked_by_authenticated_stream`].
+    fn list_blocked_by_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Users::check_blocked`].
+    async fn check_blocked(&self, username: &str) -> Result<()>;
+
+    /// See [`Users::block`].
+    async fn block(&self, username: &str) -> Result<()>;
+
+    /// See [`Users::unblock`].
+    async fn unblock(&self, username: &str) -> Result<()>;
+
+    /// See [`Users::set_primary_email_visibility_for_authenticated`].
+    async fn set_primary_email_visibility_for_authenticated(
+        &self,
+        body: &crate::types::UsersSetPrimaryEmailVisibilityAuthenticatedRequestData,
+    ) -> Result<Vec<crate::types::Email>>;
+
+    /// See [`Users::list_emails_for_authenticated_with`].
+    async fn list_emails_for_authenticated_with(
+        &self,
+        params: &UsersListEmailsForAuthenticatedParams,
+    ) -> Result<Vec<crate::types::Email>>;
+
+    /// See [`Users::list_all_emails_for_authenticated`].
+    async fn list_all_emails_for_authenticated(&self) -> Result<Vec<crate::types::Email>>;
+
+    /// See [`Users::list_emails_for_authenticated_stream`].
+    fn list_emails_for_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Email>;
+
+    /// See [`Users::add_email_for_authenticated`].
+    async fn add_email_for_authenticated(
+        &self,
+        body: &crate::types::UsersAddEmailAuthenticatedRequestOneOf,
+    ) -> Result<Vec<crate::types::Email>>;
+
+    /// See [`Users::delete_email_for_authenticated`].
+    async fn delete_email_for_authenticated(
+        &self,
+        body: &crate::types::UsersDeleteEmailAuthenticatedRequestOneOf,
+    ) -> Result<()>;
+
+    /// See [`Users::list_followers_for_authenticated_user_with`].
+    async fn list_followers_for_authenticated_user_with(
+        &self,
+        params: &UsersListFollowersForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Users::list_all_followers_for_authenticated_user`].
+    async fn list_all_followers_for_authenticated_user(
+        &self,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Users::list_followers_for_authenticated_user_stream`].
+    fn list_followers_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Users::list_followed_by_authenticated_with`].
+    async fn list_followed_by_authenticated_with(
+        &self,
+        params: &UsersListFollowedByAuthenticatedParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Users::list_all_followed_by_authenticated`].
+    async fn list_all_followed_by_authenticated(&self) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Users::list_followed_by_authenticated_stream`].
+    fn list_followed_by_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Users::check_person_is_followed_by_authenticated`].
+    async fn check_person_is_followed_by_authenticated(&self, username: &str) -> Result<()>;
+
+    /// See [`Users::follow`].
+    async fn follow(&self, username: &str) -> Result<()>;
+
+    /// See [`Users::unfollow`].
+    async fn unfollow(&self, username: &str) -> Result<()>;
+
+    /// See [`Users::list_gpg_keys_for_authenticated_with`].
+    async fn list_gpg_keys_for_authenticated_with(
+        &self,
+        params: &UsersListGpgKeysForAuthenticatedParams,
+    ) -> Result<Vec<crate::types::GpgKey>>;
+
+    /// See [`Users::list_all_gpg_keys_for_authenticated`].
+    async fn list_all_gpg_keys_for_authenticated(&self) -> Result<Vec<crate::types::GpgKey>>;
+
+    /// See [`Users::list_gpg_keys_for_authenticated_stream`].
+    fn list_gpg_keys_for_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GpgKey>;
+
+    /// See [`Users::create_gpg_key_for_authenticated`].
+    async fn create_gpg_key_for_authenticated(
+        &self,
+        body: &crate::types::UsersCreateGpgKeyAuthenticatedRequest,
+    ) -> Result<crate::types::GpgKey>;
+
+    /// See [`Users::get_gpg_key_for_authenticated`].
+    async fn get_gpg_key_for_authenticated(&self, gpg_key_id: i64) -> Result<crate::types::GpgKey>;
+
+    /// See [`Users::delete_gpg_key_for_authenticated`].
+    async fn delete_gpg_key_for_authenticated(&self, gpg_key_id: i64) -> Result<()>;
+
+    /// See [`Users::list_public_ssh_keys_for_authenticated_with`].
+    async fn list_public_ssh_keys_for_authenticated_with(
+        &self,
+        params: &UsersListPublicSshKeysForAuthenticatedParams,
+    ) -> Result<Vec<crate::types::Key>>;
+
+    /// See [`Users::list_all_public_ssh_keys_for_authenticated`].
+    async fn list_all_public_ssh_keys_for_authenticated(&self) -> Result<Vec<crate::types::Key>>;
+
+    /// See [`Users::list_public_ssh_keys_for_authenticated_stream`].
+    fn list_public_ssh_keys_for_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Key>;
+
+    /// See [`Users::create_public_ssh_key_for_authenticated`].
+    async fn create_public_ssh_key_for_authenticated(
+        &self,
+        body: &crate::types::UsersCreatePublicSshKeyAuthenticatedRequest,
+    ) -> Result<crate::types::Key>;
+
+    /// See [`Users::get_public_ssh_key_for_authenticated`].
+    async fn get_public_ssh_key_for_authenticated(&self, key_id: i64) -> Result<crate::types::Key>;
+
+    /// See [`Users::delete_public_ssh_key_for_authenticated`].
+    async fn delete_public_ssh_key_for_authenticated(&self, key_id: i64) -> Result<()>;
+
+    /// See [`Users::list_public_emails_for_authenticated_with`].
+    async fn list_public_emails_for_authenticated_with(
+        &self,
+        params: &UsersListPublicEmailsForAuthenticatedParams,
+    ) -> Result<Vec<crate::types::Email>>;
+
+    /// See [`Users::list_all_public_emails_for_authenticated`].
+    async fn list_all_public_emails_for_authenticated(&self) -> Result<Vec<crate::types::Email>>;
+
+    /// See [`Users::list_public_emails_for_authenticated_stream`].
+    fn list_public_emails_for_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Email>;
+
+    /// See [`Users::list_with`].
+    async fn list_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Users::list_all_with`].
+    async fn list_all_with(
+        &self,
+        params: &UsersListParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Users::list_stream`].
+    fn list_stream(
+        &self,
+        params: &UsersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Users::get_by_username_public_user`].
+    async fn get_by_username_public_user(&self, username: &str)
+        -> Result<crate::types::PublicUser>;
+
+    /// See [`Users::get_by_username_private_user`].
+    async fn get_by_username_private_user(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::PrivateUser>;
+
+    /// See [`Users::get_by_username`].
+    async fn get_by_username(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::UsersGetByUsernameResponseOneOf>;
+
+    /// See [`Users::list_followers_for_user_with`].
+    async fn list_followers_for_user_with(
+        &self,
+        username: &str,
+        params: &UsersListFollowersForUserParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Users::list_all_followers_for_user`].
+    async fn list_all_followers_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Users::list_followers_for_user_stream`].
+    fn list_followers_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Users::list_following_for_user_with`].
+    async fn list_following_for_user_with(
+        &self,
+        username: &str,
+        params: &UsersListFollowingForUserParams,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Users::list_all_following_for_user`].
+    async fn list_all_following_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::SimpleUser>>;
+
+    /// See [`Users::list_following_for_user_stream`].
+    fn list_following_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser>;
+
+    /// See [`Users::check_following_for_user`].
+    async fn check_following_for_user(&self, username: &str, target_user: &str) -> Result<()>;
+
+    /// See [`Users::list_gpg_keys_for_user_with`].
+    async fn list_gpg_keys_for_user_with(
+        &self,
+        username: &str,
+        params: &UsersListGpgKeysForUserParams,
+    ) -> Result<Vec<crate::types::GpgKey>>;
+
+    /// See [`Users::list_all_gpg_keys_for_user`].
+    async fn list_all_gpg_keys_for_user(&self, username: &str)
+        -> Result<Vec<crate::types::GpgKey>>;
+
+    /// See [`Users::list_gpg_keys_for_user_stream`].
+    fn list_gpg_keys_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GpgKey>;
+
+    /// See [`Users::get_context_for_user_with`].
+    async fn get_context_for_user_with(
+        &self,
+        username: &str,
+        params: &UsersGetContextForUserParams,
+    ) -> Result<crate::types::Hovercard>;
+
+    /// See [`Users::list_public_keys_for_user_with`].
+    async fn list_public_keys_for_user_with(
+        &self,
+        username: &str,
+        params: &UsersListPublicKeysForUserParams,
+    ) -> Result<Vec<crate::types::KeySimple>>;
+
+    /// See [`Users::list_all_public_keys_for_user`].
+    async fn list_all_public_keys_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::KeySimple>>;
+
+    /// See [`Users::list_public_keys_for_user_stream`].
+    fn list_public_keys_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::KeySimple>;
+}
+
+#[async_trait::async_trait]
+impl UsersApi for Users {
+    async fn get_authenticated_public_user(&self) -> Result<crate::types::PublicUser> {
+        Users::get_authenticated_public_user(self).await
+    }
+
+    async fn get_authenticated_private_user(&self) -> Result<crate::types::PrivateUser> {
+        Users::get_authenticated_private_user(self).await
+    }
+
+    async fn get_authenticated(&self) -> Result<crate::types::UsersGetAuthenticatedResponseOneOf> {
+        Users::get_authenticated(self).await
+    }
+
+    async fn update_authenticated(
+        &self,
+        body: &crate::types::UsersUpdateAuthenticatedRequest,
+    ) -> Result<crate::types::PrivateUser> {
+        Users::update_authenticated(self, body).await
+    }
+
+    async fn list_blocked_by_authenticated(&self) -> Result<Vec<crate::types::SimpleUser>> {
+        Users::list_blocked_by_authenticated(self).await
+    }
+
+    async fn list_all_blocked_by_authenticated(&self) -> Result<Vec<crate::types::SimpleUser>> {
+        Users::list_all_blocked_by_authenticated(self).await
+    }
+
+    fn list_blocked_by_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Users::list_blocked_by_authenticated_stream(self, start, max_items)
+    }
+
+    async fn check_blocked(&self, username: &str) -> Result<()> {
+        Users::check_blocked(self, username).await
+    }
+
+    async fn block(&self, username: &str) -> Result<()> {
+        Users::block(self, username).await
+    }
+
+    async fn unblock(&self, username: &str) -> Result<()> {
+        Users::unblock(self, username).await
+    }
+
+    async fn set_primary_email_visibility_for_authenticated(
+        &self,
+        body: &crate::types::UsersSetPrimaryEmailVisibilityAuthenticatedRequestData,
+    ) -> Result<Vec<crate::types::Email>> {
+        Users::set_primary_email_visibility_for_authenticated(self, body).await
+    }
+
+    async fn list_emails_for_authenticated_with(
+        &self,
+        params: &UsersListEmailsForAuthenticatedParams,
+    ) -> Result<Vec<crate::types::Email>> {
+        Users::list_emails_for_authenticated_with(self, params).await
+    }
+
+    async fn list_all_emails_for_authenticated(&self) -> Result<Vec<crate::types::Email>> {
+        Users::list_all_emails_for_authenticated(self).await
+    }
+
+    fn list_emails_for_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Email> {
+        Users::list_emails_for_authenticated_stream(self, start, max_items)
+    }
+
+    async fn add_email_for_authenticated(
+        &self,
+        body: &crate::types::UsersAddEmailAuthenticatedRequestOneOf,
+    ) -> Result<Vec<crate::types::Email>> {
+        Users::add_email_for_authenticated(self, body).await
+    }
+
+    async fn delete_email_for_authenticated(
+        &self,
+        body: &crate::types::UsersDeleteEmailAuthenticatedRequestOneOf,
+    ) -> Result<()> {
+        Users::delete_email_for_authenticated(self, body).await
+    }
+
+    async fn list_followers_for_authenticated_user_with(
+        &self,
+        params: &UsersListFollowersForAuthenticatedUserParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Users::list_followers_for_authenticated_user_with(self, params).await
+    }
+
+    async fn list_all_followers_for_authenticated_user(
+        &self,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Users::list_all_followers_for_authenticated_user(self).await
+    }
+
+    fn list_followers_for_authenticated_user_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Users::list_followers_for_authenticated_user_stream(self, start, max_items)
+    }
+
+    async fn list_followed_by_authenticated_with(
+        &self,
+        params: &UsersListFollowedByAuthenticatedParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Users::list_followed_by_authenticated_with(self, params).await
+    }
+
+    async fn list_all_followed_by_authenticated(&self) -> Result<Vec<crate::types::SimpleUser>> {
+        Users::list_all_followed_by_authenticated(self).await
+    }
+
+    fn list_followed_by_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Users::list_followed_by_authenticated_stream(self, start, max_items)
+    }
+
+    async fn check_person_is_followed_by_authenticated(&self, username: &str) -> Result<()> {
+        Users::check_person_is_followed_by_authenticated(self, username).await
+    }
+
+    async fn follow(&self, username: &str) -> Result<()> {
+        Users::follow(self, username).await
+    }
+
+    async fn unfollow(&self, username: &str) -> Result<()> {
+        Users::unfollow(self, username).await
+    }
+
+    async fn list_gpg_keys_for_authenticated_with(
+        &self,
+        params: &UsersListGpgKeysForAuthenticatedParams,
+    ) -> Result<Vec<crate::types::GpgKey>> {
+        Users::list_gpg_keys_for_authenticated_with(self, params).await
+    }
+
+    async fn list_all_gpg_keys_for_authenticated(&self) -> Result<Vec<crate::types::GpgKey>> {
+        Users::list_all_gpg_keys_for_authenticated(self).await
+    }
+
+    fn list_gpg_keys_for_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GpgKey> {
+        Users::list_gpg_keys_for_authenticated_stream(self, start, max_items)
+    }
+
+    async fn create_gpg_key_for_authenticated(
+        &self,
+        body: &crate::types::UsersCreateGpgKeyAuthenticatedRequest,
+    ) -> Result<crate::types::GpgKey> {
+        Users::create_gpg_key_for_authenticated(self, body).await
+    }
+
+    async fn get_gpg_key_for_authenticated(&self, gpg_key_id: i64) -> Result<crate::types::GpgKey> {
+        Users::get_gpg_key_for_authenticated(self, gpg_key_id).await
+    }
+
+    async fn delete_gpg_key_for_authenticated(&self, gpg_key_id: i64) -> Result<()> {
+        Users::delete_gpg_key_for_authenticated(self, gpg_key_id).await
+    }
+
+    async fn list_public_ssh_keys_for_authenticated_with(
+        &self,
+        params: &UsersListPublicSshKeysForAuthenticatedParams,
+    ) -> Result<Vec<crate::types::Key>> {
+        Users::list_public_ssh_keys_for_authenticated_with(self, params).await
+    }
+
+    async fn list_all_public_ssh_keys_for_authenticated(&self) -> Result<Vec<crate::types::Key>> {
+        Users::list_all_public_ssh_keys_for_authenticated(self).await
+    }
+
+    fn list_public_ssh_keys_for_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Key> {
+        Users::list_public_ssh_keys_for_authenticated_stream(self, start, max_items)
+    }
+
+    async fn create_public_ssh_key_for_authenticated(
+        &self,
+        body: &crate::types::UsersCreatePublicSshKeyAuthenticatedRequest,
+    ) -> Result<crate::types::Key> {
+        Users::create_public_ssh_key_for_authenticated(self, body).await
+    }
+
+    async fn get_public_ssh_key_for_authenticated(&self, key_id: i64) -> Result<crate::types::Key> {
+        Users::get_public_ssh_key_for_authenticated(self, key_id).await
+    }
+
+    async fn delete_public_ssh_key_for_authenticated(&self, key_id: i64) -> Result<()> {
+        Users::delete_public_ssh_key_for_authenticated(self, key_id).await
+    }
+
+    async fn list_public_emails_for_authenticated_with(
+        &self,
+        params: &UsersListPublicEmailsForAuthenticatedParams,
+    ) -> Result<Vec<crate::types::Email>> {
+        Users::list_public_emails_for_authenticated_with(self, params).await
+    }
+
+    async fn list_all_public_emails_for_authenticated(&self) -> Result<Vec<crate::types::Email>> {
+        Users::list_all_public_emails_for_authenticated(self).await
+    }
+
+    fn list_public_emails_for_authenticated_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Email> {
+        Users::list_public_emails_for_authenticated_stream(self, start, max_items)
+    }
+
+    async fn list_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::SimpleUser>> {
+        Users::list_with(self, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        params: &UsersListParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Users::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &UsersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Users::list_stream(self, params, start, max_items)
+    }
+
+    async fn get_by_username_public_user(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::PublicUser> {
+        Users::get_by_username_public_user(self, username).await
+    }
+
+    async fn get_by_username_private_user(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::PrivateUser> {
+        Users::get_by_username_private_user(self, username).await
+    }
+
+    async fn get_by_username(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::UsersGetByUsernameResponseOneOf> {
+        Users::get_by_username(self, username).await
+    }
+
+    async fn list_followers_for_user_with(
+        &self,
+        username: &str,
+        params: &UsersListFollowersForUserParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Users::list_followers_for_user_with(self, username, params).await
+    }
+
+    async fn list_all_followers_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Users::list_all_followers_for_user(self, username).await
+    }
+
+    fn list_followers_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Users::list_followers_for_user_stream(self, username, start, max_items)
+    }
+
+    async fn list_following_for_user_with(
+        &self,
+        username: &str,
+        params: &UsersListFollowingForUserParams,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Users::list_following_for_user_with(self, username, params).await
+    }
+
+    async fn list_all_following_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::SimpleUser>> {
+        Users::list_all_following_for_user(self, username).await
+    }
+
+    fn list_following_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SimpleUser> {
+        Users::list_following_for_user_stream(self, username, start, max_items)
+    }
+
+    async fn check_following_for_user(&self, username: &str, target_user: &str) -> Result<()> {
+        Users::check_following_for_user(self, username, target_user).await
+    }
+
+    async fn list_gpg_keys_for_user_with(
+        &self,
+        username: &str,
+        params: &UsersListGpgKeysForUserParams,
+    ) -> Result<Vec<crate::types::GpgKey>> {
+        Users::list_gpg_keys_for_user_with(self, username, params).await
+    }
+
+    async fn list_all_gpg_keys_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::GpgKey>> {
+        Users::list_all_gpg_keys_for_user(self, username).await
+    }
+
+    fn list_gpg_keys_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GpgKey> {
+        Users::list_gpg_keys_for_user_stream(self, username, start, max_items)
+    }
+
+    async fn get_context_for_user_with(
+        &self,
+        username: &str,
+        params: &UsersGetContextForUserParams,
+    ) -> Result<crate::types::Hovercard> {
+        Users::get_context_for_user_with(self, username, params).await
+    }
+
+    async fn list_public_keys_for_user_with(
+        &self,
+        username: &str,
+        params: &UsersListPublicKeysForUserParams,
+    ) -> Result<Vec<crate::types::KeySimple>> {
+        Users::list_public_keys_for_user_with(self, username, params).await
+    }
+
+    async fn list_all_public_keys_for_user(
+        &self,
+        username: &str,
+    ) -> Result<Vec<crate::types::KeySimple>> {
+        Users::list_all_public_keys_for_user(self, username).await
+    }
+
+    fn list_public_keys_for_user_stream(
+        &self,
+        username: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::KeySimple> {
+        Users::list_public_keys_for_user_stream(self, username, start, max_items)
+    }
 }
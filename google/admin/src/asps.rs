@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Asps {
     pub client: Client,
@@ -70,3 +68,33 @@ impl Asps {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`Asps`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAspsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AspsApi: Send + Sync {
+    /// See [`Asps::list`].
+    async fn list(&self, user_key: &str) -> Result<crate::types::Asps>;
+
+    /// See [`Asps::get`].
+    async fn get(&self, user_key: &str, code_id: i64) -> Result<crate::types::Asp>;
+
+    /// See [`Asps::delete`].
+    async fn delete(&self, user_key: &str, code_id: i64) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl AspsApi for Asps {
+    async fn list(&self, user_key: &str) -> Result<crate::types::Asps> {
+        Asps::list(self, user_key).await
+    }
+
+    async fn get(&self, user_key: &str, code_id: i64) -> Result<crate::types::Asp> {
+        Asps::get(self, user_key, code_id).await
+    }
+
+    async fn delete(&self, user_key: &str, code_id: i64) -> Result<()> {
+        Asps::delete(self, user_key, code_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Chromeosdevices {
     pub client: Client,
@@ -20,45 +18,33 @@ impl Chromeosdevices {
      * **Parameters:**
      *
      * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
-     * * `max_results: i64` -- Maximum number of results to return.
-     * * `order_by: crate::types::OrderBy` -- Device property to use for sorting results.
-     * * `org_unit_path: &str` -- The full path of the organizational unit or its unique ID.
-     * * `page_token: &str` -- The `pageToken` query parameter is used to request the next page of query results. The follow-on request's `pageToken` query parameter is the `nextPageToken` from your previous response.
-     * * `projection: crate::types::Projection` -- Restrict information returned to a set of selected fields.
-     * * `query: &str` -- Search string in the format given at http://support.google.com/chromeos/a/bin/answer.py?answer=1698333.
-     * * `sort_order: crate::types::SortOrder` -- Whether to return results in ascending or descending order. Must be used with the `orderBy` parameter.
+     * * `params: &ChromeosdevicesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         customer_id: &str,
-        max_results: i64,
-        order_by: crate::types::OrderBy,
-        org_unit_path: &str,
-        page_token: &str,
-        projection: crate::types::Projection,
-        query: &str,
-        sort_order: crate::types::SortOrder,
+        params: &ChromeosdevicesListParams,
     ) -> Result<Vec<crate::types::ChromeOsDevice>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !order_by.to_string().is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !org_unit_path.is_empty() {
+        if let Some(org_unit_path) = &params.org_unit_path {
             query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !projection.to_string().is_empty() {
+        if let Some(projection) = &params.projection {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
-        if !sort_order.to_string().is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sortOrder".to_string(), sort_order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -75,35 +61,62 @@ impl Chromeosdevices {
     }
 
     /**
-     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos` endpoint.
-     *
-     * As opposed to `list`, this function returns all the pages of the request at once.
-     *
-     * Retrieves a paginated list of Chrome OS devices within an account.
+     * Deprecated: use `list_with` and pass the query parameters as a `ChromeosdevicesListParams` instead.
      */
-    pub async fn list_all(
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
         &self,
         customer_id: &str,
+        max_results: i64,
         order_by: crate::types::OrderBy,
         org_unit_path: &str,
+        page_token: &str,
         projection: crate::types::Projection,
         query: &str,
         sort_order: crate::types::SortOrder,
+    ) -> Result<Vec<crate::types::ChromeOsDevice>> {
+        let params = ChromeosdevicesListParams {
+            max_results: Some(max_results).filter(|v| *v > 0),
+            order_by: Some(order_by).filter(|v| !v.to_string().is_empty()),
+            org_unit_path: Some(org_unit_path.to_string()).filter(|s| !s.is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            projection: Some(projection).filter(|v| !v.to_string().is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_with(customer_id, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Retrieves a paginated list of Chrome OS devices within an account.
+     *
+     * **Parameters:**
+     *
+     * * `params: &ChromeosdevicesListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(
+        &self,
+        customer_id: &str,
+        params: &ChromeosdevicesListParams,
     ) -> Result<Vec<crate::types::ChromeOsDevice>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !order_by.to_string().is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !org_unit_path.is_empty() {
+        if let Some(org_unit_path) = &params.org_unit_path {
             query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
         }
-        if !projection.to_string().is_empty() {
+        if let Some(projection) = &params.projection {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
-        if !sort_order.to_string().is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sortOrder".to_string(), sort_order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -120,6 +133,7 @@ impl Chromeosdevices {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -145,6 +159,87 @@ impl Chromeosdevices {
         Ok(chromeosdevices)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `ChromeosdevicesListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        customer_id: &str,
+        order_by: crate::types::OrderBy,
+        org_unit_path: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> Result<Vec<crate::types::ChromeOsDevice>> {
+        let params = ChromeosdevicesListParams {
+            order_by: Some(order_by).filter(|v| !v.to_string().is_empty()),
+            org_unit_path: Some(org_unit_path.to_string()).filter(|s| !s.is_empty()),
+            projection: Some(projection).filter(|v| !v.to_string().is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(customer_id, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieves a paginated list of Chrome OS devices within an account.
+     */
+    pub fn list_stream(
+        &self,
+        customer_id: &str,
+        params: &ChromeosdevicesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ChromeOsDevice> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(order_by) = &params.order_by {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if let Some(org_unit_path) = &params.org_unit_path {
+            query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
+        }
+        if let Some(projection) = &params.projection {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        if let Some(query) = &params.query {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if let Some(sort_order) = &params.sort_order {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/devices/chromeos?{}",
+            crate::progenitor_support::encode_path(customer_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::ChromeOsDevices = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.chromeosdevices,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/moveDevicesToOu` endpoint.
      *
@@ -153,16 +248,16 @@ impl Chromeosdevices {
      * **Parameters:**
      *
      * * `customer_id: &str` -- Immutable ID of the Google Workspace account.
-     * * `org_unit_path: &str` -- Full path of the target organizational unit or its ID.
+     * * `params: &ChromeosdevicesMoveDevicesOuParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn move_devices_ou(
+    pub async fn move_devices_ou_with(
         &self,
         customer_id: &str,
-        org_unit_path: &str,
+        params: &ChromeosdevicesMoveDevicesOuParams,
         body: &crate::types::ChromeOsMoveDevicesOu,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !org_unit_path.is_empty() {
+        if let Some(org_unit_path) = &params.org_unit_path {
             query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -177,6 +272,22 @@ impl Chromeosdevices {
             .await
     }
 
+    /**
+     * Deprecated: use `move_devices_ou_with` and pass the query parameters as a `ChromeosdevicesMoveDevicesOuParams` instead.
+     */
+    #[deprecated(note = "use `move_devices_ou_with` instead")]
+    pub async fn move_devices_ou(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::ChromeOsMoveDevicesOu,
+    ) -> Result<()> {
+        let params = ChromeosdevicesMoveDevicesOuParams {
+            org_unit_path: Some(org_unit_path.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.move_devices_ou_with(customer_id, &params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
      *
@@ -186,16 +297,16 @@ impl Chromeosdevices {
      *
      * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
      * * `device_id: &str` -- The unique ID of the device. The `deviceId`s are returned in the response from the [chromeosdevices.list](/admin-sdk/directory/v1/reference/chromeosdevices/list) method.
-     * * `projection: crate::types::Projection` -- Determines whether the response contains the full list of properties or only a subset.
+     * * `params: &ChromeosdevicesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         customer_id: &str,
         device_id: &str,
-        projection: crate::types::Projection,
+        params: &ChromeosdevicesGetParams,
     ) -> Result<crate::types::ChromeOsDevice> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !projection.to_string().is_empty() {
+        if let Some(projection) = &params.projection {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -209,6 +320,22 @@ impl Chromeosdevices {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `ChromeosdevicesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+    ) -> Result<crate::types::ChromeOsDevice> {
+        let params = ChromeosdevicesGetParams {
+            projection: Some(projection).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_with(customer_id, device_id, &params).await
+    }
+
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
      *
@@ -218,17 +345,17 @@ impl Chromeosdevices {
      *
      * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
      * * `device_id: &str` -- The unique ID of the device. The `deviceId`s are returned in the response from the [chromeosdevices.list](/admin-sdk/v1/reference/chromeosdevices/list) method.
-     * * `projection: crate::types::Projection` -- Restrict information returned to a set of selected fields.
+     * * `params: &ChromeosdevicesUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update(
+    pub async fn update_with(
         &self,
         customer_id: &str,
         device_id: &str,
-        projection: crate::types::Projection,
+        params: &ChromeosdevicesUpdateParams,
         body: &crate::types::ChromeOsDevice,
     ) -> Result<crate::types::ChromeOsDevice> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !projection.to_string().is_empty() {
+        if let Some(projection) = &params.projection {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -244,6 +371,24 @@ impl Chromeosdevices {
             .await
     }
 
+    /**
+     * Deprecated: use `update_with` and pass the query parameters as a `ChromeosdevicesUpdateParams` instead.
+     */
+    #[deprecated(note = "use `update_with` instead")]
+    pub async fn update(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+        body: &crate::types::ChromeOsDevice,
+    ) -> Result<crate::types::ChromeOsDevice> {
+        let params = ChromeosdevicesUpdateParams {
+            projection: Some(projection).filter(|v| !v.to_string().is_empty()),
+        };
+        self.update_with(customer_id, device_id, &params, body)
+            .await
+    }
+
     /**
      * This function performs a `PATCH` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{deviceId}` endpoint.
      *
@@ -253,17 +398,17 @@ impl Chromeosdevices {
      *
      * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
      * * `device_id: &str` -- The unique ID of the device. The `deviceId`s are returned in the response from the [chromeosdevices.list](/admin-sdk/v1/reference/chromeosdevices/list) method.
-     * * `projection: crate::types::Projection` -- Restrict information returned to a set of selected fields.
+     * * `params: &ChromeosdevicesPatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn patch(
+    pub async fn patch_with(
         &self,
         customer_id: &str,
         device_id: &str,
-        projection: crate::types::Projection,
+        params: &ChromeosdevicesPatchParams,
         body: &crate::types::ChromeOsDevice,
     ) -> Result<crate::types::ChromeOsDevice> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !projection.to_string().is_empty() {
+        if let Some(projection) = &params.projection {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -279,6 +424,23 @@ impl Chromeosdevices {
             .await
     }
 
+    /**
+     * Deprecated: use `patch_with` and pass the query parameters as a `ChromeosdevicesPatchParams` instead.
+     */
+    #[deprecated(note = "use `patch_with` instead")]
+    pub async fn patch(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        projection: crate::types::Projection,
+        body: &crate::types::ChromeOsDevice,
+    ) -> Result<crate::types::ChromeOsDevice> {
+        let params = ChromeosdevicesPatchParams {
+            projection: Some(projection).filter(|v| !v.to_string().is_empty()),
+        };
+        self.patch_with(customer_id, device_id, &params, body).await
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/devices/chromeos/{resourceId}/action` endpoint.
      *
@@ -306,3 +468,262 @@ impl Chromeosdevices {
             .await
     }
 }
+
+/// The query parameters of `Chromeosdevices::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChromeosdevicesListParams {
+    /// Maximum number of results to return.
+    pub max_results: Option<i64>,
+    /// Device property to use for sorting results.
+    pub order_by: Option<crate::types::OrderBy>,
+    /// The full path of the organizational unit or its unique ID.
+    pub org_unit_path: Option<String>,
+    /// The `pageToken` query parameter is used to request the next page of query results. The follow-on request's `pageToken` query parameter is the `nextPageToken` from your previous response.
+    pub page_token: Option<String>,
+    /// Restrict information returned to a set of selected fields.
+    pub projection: Option<crate::types::Projection>,
+    /// Search string in the format given at http://support.google.com/chromeos/a/bin/answer.py?answer=1698333.
+    pub query: Option<String>,
+    /// Whether to return results in ascending or descending order. Must be used with the `orderBy` parameter.
+    pub sort_order: Option<crate::types::SortOrder>,
+}
+
+impl ChromeosdevicesListParams {
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn order_by(mut self, order_by: crate::types::OrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    pub fn org_unit_path<T: ToString>(mut self, org_unit_path: T) -> Self {
+        self.org_unit_path = Some(org_unit_path.to_string());
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+}
+
+/// The query parameters of `Chromeosdevices::move_devices_ou_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChromeosdevicesMoveDevicesOuParams {
+    /// Full path of the target organizational unit or its ID.
+    pub org_unit_path: Option<String>,
+}
+
+impl ChromeosdevicesMoveDevicesOuParams {
+    pub fn org_unit_path<T: ToString>(mut self, org_unit_path: T) -> Self {
+        self.org_unit_path = Some(org_unit_path.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Chromeosdevices::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChromeosdevicesGetParams {
+    /// Determines whether the response contains the full list of properties or only a subset.
+    pub projection: Option<crate::types::Projection>,
+}
+
+impl ChromeosdevicesGetParams {
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+}
+
+/// The query parameters of `Chromeosdevices::update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChromeosdevicesUpdateParams {
+    /// Restrict information returned to a set of selected fields.
+    pub projection: Option<crate::types::Projection>,
+}
+
+impl ChromeosdevicesUpdateParams {
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+}
+
+/// The query parameters of `Chromeosdevices::patch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChromeosdevicesPatchParams {
+    /// Restrict information returned to a set of selected fields.
+    pub projection: Option<crate::types::Projection>,
+}
+
+impl ChromeosdevicesPatchParams {
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+}
+
+/// The functions of [`Chromeosdevices`], for code that takes them as a trait, so that it can be
+/// tested with the `MockChromeosdevicesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ChromeosdevicesApi: Send + Sync {
+    /// See [`Chromeosdevices::list_with`].
+    async fn list_with(
+        &self,
+        customer_id: &str,
+        params: &ChromeosdevicesListParams,
+    ) -> Result<Vec<crate::types::ChromeOsDevice>>;
+
+    /// See [`Chromeosdevices::list_all_with`].
+    async fn list_all_with(
+        &self,
+        customer_id: &str,
+        params: &ChromeosdevicesListParams,
+    ) -> Result<Vec<crate::types::ChromeOsDevice>>;
+
+    /// See [`Chromeosdevices::list_stream`].
+    fn list_stream(
+        &self,
+        customer_id: &str,
+        params: &ChromeosdevicesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ChromeOsDevice>;
+
+    /// See [`Chromeosdevices::move_devices_ou_with`].
+    async fn move_devices_ou_with(
+        &self,
+        customer_id: &str,
+        params: &ChromeosdevicesMoveDevicesOuParams,
+        body: &crate::types::ChromeOsMoveDevicesOu,
+    ) -> Result<()>;
+
+    /// See [`Chromeosdevices::get_with`].
+    async fn get_with(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        params: &ChromeosdevicesGetParams,
+    ) -> Result<crate::types::ChromeOsDevice>;
+
+    /// See [`Chromeosdevices::update_with`].
+    async fn update_with(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        params: &ChromeosdevicesUpdateParams,
+        body: &crate::types::ChromeOsDevice,
+    ) -> Result<crate::types::ChromeOsDevice>;
+
+    /// See [`Chromeosdevices::patch_with`].
+    async fn patch_with(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        params: &ChromeosdevicesPatchParams,
+        body: &crate::types::ChromeOsDevice,
+    ) -> Result<crate::types::ChromeOsDevice>;
+
+    /// See [`Chromeosdevices::action`].
+    async fn action(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::ChromeOsDeviceAction,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ChromeosdevicesApi for Chromeosdevices {
+    async fn list_with(
+        &self,
+        customer_id: &str,
+        params: &ChromeosdevicesListParams,
+    ) -> Result<Vec<crate::types::ChromeOsDevice>> {
+        Chromeosdevices::list_with(self, customer_id, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        customer_id: &str,
+        params: &ChromeosdevicesListParams,
+    ) -> Result<Vec<crate::types::ChromeOsDevice>> {
+        Chromeosdevices::list_all_with(self, customer_id, params).await
+    }
+
+    fn list_stream(
+        &self,
+        customer_id: &str,
+        params: &ChromeosdevicesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ChromeOsDevice> {
+        Chromeosdevices::list_stream(self, customer_id, params, start, max_items)
+    }
+
+    async fn move_devices_ou_with(
+        &self,
+        customer_id: &str,
+        params: &ChromeosdevicesMoveDevicesOuParams,
+        body: &crate::types::ChromeOsMoveDevicesOu,
+    ) -> Result<()> {
+        Chromeosdevices::move_devices_ou_with(self, customer_id, params, body).await
+    }
+
+    async fn get_with(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        params: &ChromeosdevicesGetParams,
+    ) -> Result<crate::types::ChromeOsDevice> {
+        Chromeosdevices::get_with(self, customer_id, device_id, params).await
+    }
+
+    async fn update_with(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        params: &ChromeosdevicesUpdateParams,
+        body: &crate::types::ChromeOsDevice,
+    ) -> Result<crate::types::ChromeOsDevice> {
+        Chromeosdevices::update_with(self, customer_id, device_id, params, body).await
+    }
+
+    async fn patch_with(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        params: &ChromeosdevicesPatchParams,
+        body: &crate::types::ChromeOsDevice,
+    ) -> Result<crate::types::ChromeOsDevice> {
+        Chromeosdevices::patch_with(self, customer_id, device_id, params, body).await
+    }
+
+    async fn action(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::ChromeOsDeviceAction,
+    ) -> Result<()> {
+        Chromeosdevices::action(self, customer_id, resource_id, body).await
+    }
+}
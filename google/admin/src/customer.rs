@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Customer {
     pub client: Client,
@@ -66,3 +64,47 @@ impl Customer {
             .await
     }
 }
+
+/// The functions of [`Customer`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCustomerApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CustomerApi: Send + Sync {
+    /// See [`Customer::admin_devices_chromeos_commands_get`].
+    async fn admin_devices_chromeos_commands_get(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        command_id: &str,
+    ) -> Result<crate::types::DirectoryChromeosdevicesCommand>;
+
+    /// See [`Customer::admin_devices_chromeos_issue_command`].
+    async fn admin_devices_chromeos_issue_command(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        body: &crate::types::DirectoryChromeosdevicesIssueCommandRequest,
+    ) -> Result<crate::types::DirectoryChromeosdevicesIssueCommandResponse>;
+}
+
+#[async_trait::async_trait]
+impl CustomerApi for Customer {
+    async fn admin_devices_chromeos_commands_get(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        command_id: &str,
+    ) -> Result<crate::types::DirectoryChromeosdevicesCommand> {
+        Customer::admin_devices_chromeos_commands_get(self, customer_id, device_id, command_id)
+            .await
+    }
+
+    async fn admin_devices_chromeos_issue_command(
+        &self,
+        customer_id: &str,
+        device_id: &str,
+        body: &crate::types::DirectoryChromeosdevicesIssueCommandRequest,
+    ) -> Result<crate::types::DirectoryChromeosdevicesIssueCommandResponse> {
+        Customer::admin_devices_chromeos_issue_command(self, customer_id, device_id, body).await
+    }
+}
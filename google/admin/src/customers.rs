@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Customers {
     pub client: Client,
@@ -122,21 +120,19 @@ impl Customers {
      * **Parameters:**
      *
      * * `name: &str` -- The resource name of the Printer object, in the format customers/{customer-id}/printers/{printer-id} (During printer creation leave empty).
-     * * `clear_mask: &str` -- The list of fields to be cleared. Note, some of the fields are read only and cannot be updated. Values for not specified fields will be patched.
-     * * `update_mask: &str` -- The list of fields to be updated. Note, some of the fields are read only and cannot be updated. Values for not specified fields will be patched.
+     * * `params: &CustomersAdminChromePrintersPatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn admin_chrome_printers_patch(
+    pub async fn admin_chrome_printers_patch_with(
         &self,
         name: &str,
-        clear_mask: &str,
-        update_mask: &str,
+        params: &CustomersAdminChromePrintersPatchParams,
         body: &crate::types::Printer,
     ) -> Result<crate::types::Printer> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !clear_mask.is_empty() {
+        if let Some(clear_mask) = &params.clear_mask {
             query_args.push(("clearMask".to_string(), clear_mask.to_string()));
         }
-        if !update_mask.is_empty() {
+        if let Some(update_mask) = &params.update_mask {
             query_args.push(("updateMask".to_string(), update_mask.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -151,6 +147,25 @@ impl Customers {
             .await
     }
 
+    /**
+     * Deprecated: use `admin_chrome_printers_patch_with` and pass the query parameters as a `CustomersAdminChromePrintersPatchParams` instead.
+     */
+    #[deprecated(note = "use `admin_chrome_printers_patch_with` instead")]
+    pub async fn admin_chrome_printers_patch(
+        &self,
+        name: &str,
+        clear_mask: &str,
+        update_mask: &str,
+        body: &crate::types::Printer,
+    ) -> Result<crate::types::Printer> {
+        let params = CustomersAdminChromePrintersPatchParams {
+            clear_mask: Some(clear_mask.to_string()).filter(|s| !s.is_empty()),
+            update_mask: Some(update_mask.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.admin_chrome_printers_patch_with(name, &params, body)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
      *
@@ -159,30 +174,24 @@ impl Customers {
      * **Parameters:**
      *
      * * `parent: &str` -- Required. The name of the customer who owns this collection of printers. Format: customers/{customer_id}.
-     * * `filter: &str` -- Search query. Search syntax is shared between this api and Admin Console printers pages.
-     * * `org_unit_id: &str` -- Organization Unit that we want to list the printers for. When org_unit is not present in the request then all printers of the customer are returned (or filtered). When org_unit is present in the request then only printers available to this OU will be returned (owned or inherited). You may see if printer is owned or inherited for this OU by looking at Printer.org_unit_id.
-     * * `page_size: i64` -- The maximum number of objects to return. The service may return fewer than this value.
-     * * `page_token: &str` -- A page token, received from a previous call.
+     * * `params: &CustomersAdminChromePrintersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn admin_chrome_printers_list(
+    pub async fn admin_chrome_printers_list_with(
         &self,
         parent: &str,
-        filter: &str,
-        org_unit_id: &str,
-        page_size: i64,
-        page_token: &str,
+        params: &CustomersAdminChromePrintersListParams,
     ) -> Result<Vec<crate::types::Printer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !org_unit_id.is_empty() {
+        if let Some(org_unit_id) = &params.org_unit_id {
             query_args.push(("orgUnitId".to_string(), org_unit_id.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -198,24 +207,48 @@ impl Customers {
         Ok(resp.printers.to_vec())
     }
 
+    /**
+     * Deprecated: use `admin_chrome_printers_list_with` and pass the query parameters as a `CustomersAdminChromePrintersListParams` instead.
+     */
+    #[deprecated(note = "use `admin_chrome_printers_list_with` instead")]
+    pub async fn admin_chrome_printers_list(
+        &self,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Printer>> {
+        let params = CustomersAdminChromePrintersListParams {
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            org_unit_id: Some(org_unit_id.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.admin_chrome_printers_list_with(parent, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
      *
      * As opposed to `admin_chrome_printers_list`, this function returns all the pages of the request at once.
      *
      * List printers configs.
+     *
+     * **Parameters:**
+     *
+     * * `params: &CustomersAdminChromePrintersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn admin_chrome_printers_list_all(
+    pub async fn admin_chrome_printers_list_all_with(
         &self,
         parent: &str,
-        filter: &str,
-        org_unit_id: &str,
+        params: &CustomersAdminChromePrintersListParams,
     ) -> Result<Vec<crate::types::Printer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !org_unit_id.is_empty() {
+        if let Some(org_unit_id) = &params.org_unit_id {
             query_args.push(("orgUnitId".to_string(), org_unit_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -232,6 +265,7 @@ impl Customers {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -257,6 +291,73 @@ impl Customers {
         Ok(printers)
     }
 
+    /**
+     * Deprecated: use `admin_chrome_printers_list_all_with` and pass the query parameters as a `CustomersAdminChromePrintersListParams` instead.
+     */
+    #[deprecated(note = "use `admin_chrome_printers_list_all_with` instead")]
+    pub async fn admin_chrome_printers_list_all(
+        &self,
+        parent: &str,
+        filter: &str,
+        org_unit_id: &str,
+    ) -> Result<Vec<crate::types::Printer>> {
+        let params = CustomersAdminChromePrintersListParams {
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            org_unit_id: Some(org_unit_id.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.admin_chrome_printers_list_all_with(parent, &params)
+            .await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List printers configs.
+     */
+    pub fn admin_chrome_printers_list_stream(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Printer> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if let Some(org_unit_id) = &params.org_unit_id {
+            query_args.push(("orgUnitId".to_string(), org_unit_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/{}/chrome/printers?{}",
+            crate::progenitor_support::encode_path(parent),
+            query_
+        );
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListPrintersResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.printers,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/{parent}/chrome/printers` endpoint.
      *
@@ -337,25 +438,21 @@ impl Customers {
      * **Parameters:**
      *
      * * `parent: &str` -- Required. The name of the customer who owns this collection of printers. Format: customers/{customer_id}.
-     * * `filter: &str` -- Filer to list only models by a given manufacturer in format: "manufacturer:Brother". Search syntax is shared between this api and Admin Console printers pages.
-     * * `page_size: i64` -- The maximum number of objects to return. The service may return fewer than this value.
-     * * `page_token: &str` -- A page token, received from a previous call.
+     * * `params: &CustomersAdminChromePrintersListPrinterModelsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn admin_chrome_printers_list_printer_models(
+    pub async fn admin_chrome_printers_list_printer_models_with(
         &self,
         parent: &str,
-        filter: &str,
-        page_size: i64,
-        page_token: &str,
+        params: &CustomersAdminChromePrintersListPrinterModelsParams,
     ) -> Result<Vec<crate::types::PrinterModel>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -371,20 +468,44 @@ impl Customers {
         Ok(resp.printer_models.to_vec())
     }
 
+    /**
+     * Deprecated: use `admin_chrome_printers_list_printer_models_with` and pass the query parameters as a `CustomersAdminChromePrintersListPrinterModelsParams` instead.
+     */
+    #[deprecated(note = "use `admin_chrome_printers_list_printer_models_with` instead")]
+    pub async fn admin_chrome_printers_list_printer_models(
+        &self,
+        parent: &str,
+        filter: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::PrinterModel>> {
+        let params = CustomersAdminChromePrintersListPrinterModelsParams {
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.admin_chrome_printers_list_printer_models_with(parent, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers:listPrinterModels` endpoint.
      *
      * As opposed to `admin_chrome_printers_list_printer_models`, this function returns all the pages of the request at once.
      *
      * Lists the supported printer models.
+     *
+     * **Parameters:**
+     *
+     * * `params: &CustomersAdminChromePrintersListPrinterModelsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn admin_chrome_printers_list_all_printer_models(
+    pub async fn admin_chrome_printers_list_all_printer_models_with(
         &self,
         parent: &str,
-        filter: &str,
+        params: &CustomersAdminChromePrintersListPrinterModelsParams,
     ) -> Result<Vec<crate::types::PrinterModel>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -401,6 +522,7 @@ impl Customers {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -425,4 +547,372 @@ impl Customers {
         // Return our response data.
         Ok(printer_models)
     }
+
+    /**
+     * Deprecated: use `admin_chrome_printers_list_all_printer_models_with` and pass the query parameters as a `CustomersAdminChromePrintersListPrinterModelsParams` instead.
+     */
+    #[deprecated(note = "use `admin_chrome_printers_list_all_printer_models_with` instead")]
+    pub async fn admin_chrome_printers_list_all_printer_models(
+        &self,
+        parent: &str,
+        filter: &str,
+    ) -> Result<Vec<crate::types::PrinterModel>> {
+        let params = CustomersAdminChromePrintersListPrinterModelsParams {
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.admin_chrome_printers_list_all_printer_models_with(parent, &params)
+            .await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/{parent}/chrome/printers:listPrinterModels` endpoint.
+     *
+     * As opposed to `admin_chrome_printers_list_all_printer_models`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the supported printer models.
+     */
+    pub fn admin_chrome_printers_list_printer_models_stream(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListPrinterModelsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PrinterModel> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/{}/chrome/printers:listPrinterModels?{}",
+            crate::progenitor_support::encode_path(parent),
+            query_
+        );
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListPrinterModelsResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.printer_models,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Customers::admin_chrome_printers_patch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersAdminChromePrintersPatchParams {
+    /// The list of fields to be cleared. Note, some of the fields are read only and cannot be updated. Values for not specified fields will be patched.
+    pub clear_mask: Option<String>,
+    /// The list of fields to be updated. Note, some of the fields are read only and cannot be updated. Values for not specified fields will be patched.
+    pub update_mask: Option<String>,
+}
+
+impl CustomersAdminChromePrintersPatchParams {
+    pub fn clear_mask<T: ToString>(mut self, clear_mask: T) -> Self {
+        self.clear_mask = Some(clear_mask.to_string());
+        self
+    }
+
+    pub fn update_mask<T: ToString>(mut self, update_mask: T) -> Self {
+        self.update_mask = Some(update_mask.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::admin_chrome_printers_list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersAdminChromePrintersListParams {
+    /// Search query. Search syntax is shared between this api and Admin Console printers pages.
+    pub filter: Option<String>,
+    /// Organization Unit that we want to list the printers for. When org_unit is not present in the request then all printers of the customer are returned (or filtered). When org_unit is present in the request then only printers available to this OU will be returned (owned or inherited). You may see if printer is owned or inherited for this OU by looking at Printer.org_unit_id.
+    pub org_unit_id: Option<String>,
+    /// The maximum number of objects to return. The service may return fewer than this value.
+    pub page_size: Option<i64>,
+    /// A page token, received from a previous call.
+    pub page_token: Option<String>,
+}
+
+impl CustomersAdminChromePrintersListParams {
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn org_unit_id<T: ToString>(mut self, org_unit_id: T) -> Self {
+        self.org_unit_id = Some(org_unit_id.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::admin_chrome_printers_list_printer_models_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersAdminChromePrintersListPrinterModelsParams {
+    /// Filer to list only models by a given manufacturer in format: "manufacturer:Brother". Search syntax is shared between this api and Admin Console printers pages.
+    pub filter: Option<String>,
+    /// The maximum number of objects to return. The service may return fewer than this value.
+    pub page_size: Option<i64>,
+    /// A page token, received from a previous call.
+    pub page_token: Option<String>,
+}
+
+impl CustomersAdminChromePrintersListPrinterModelsParams {
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The functions of [`Customers`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCustomersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CustomersApi: Send + Sync {
+    /// See [`Customers::get`].
+    async fn get(&self, customer_key: &str) -> Result<crate::types::Customer>;
+
+    /// See [`Customers::update`].
+    async fn update(
+        &self,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> Result<crate::types::Customer>;
+
+    /// See [`Customers::patch`].
+    async fn patch(
+        &self,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> Result<crate::types::Customer>;
+
+    /// See [`Customers::admin_chrome_printers_get`].
+    async fn admin_chrome_printers_get(&self, name: &str) -> Result<crate::types::Printer>;
+
+    /// See [`Customers::admin_chrome_printers_delete`].
+    async fn admin_chrome_printers_delete(&self, name: &str) -> Result<crate::types::Empty>;
+
+    /// See [`Customers::admin_chrome_printers_patch_with`].
+    async fn admin_chrome_printers_patch_with(
+        &self,
+        name: &str,
+        params: &CustomersAdminChromePrintersPatchParams,
+        body: &crate::types::Printer,
+    ) -> Result<crate::types::Printer>;
+
+    /// See [`Customers::admin_chrome_printers_list_with`].
+    async fn admin_chrome_printers_list_with(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListParams,
+    ) -> Result<Vec<crate::types::Printer>>;
+
+    /// See [`Customers::admin_chrome_printers_list_all_with`].
+    async fn admin_chrome_printers_list_all_with(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListParams,
+    ) -> Result<Vec<crate::types::Printer>>;
+
+    /// See [`Customers::admin_chrome_printers_list_stream`].
+    fn admin_chrome_printers_list_stream(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Printer>;
+
+    /// See [`Customers::admin_chrome_printers_create`].
+    async fn admin_chrome_printers_create(
+        &self,
+        parent: &str,
+        body: &crate::types::Printer,
+    ) -> Result<crate::types::Printer>;
+
+    /// See [`Customers::admin_chrome_printers_batch_create`].
+    async fn admin_chrome_printers_batch_create(
+        &self,
+        parent: &str,
+        body: &crate::types::BatchCreatePrintersRequest,
+    ) -> Result<crate::types::BatchCreatePrintersResponse>;
+
+    /// See [`Customers::admin_chrome_printers_batch_delete`].
+    async fn admin_chrome_printers_batch_delete(
+        &self,
+        parent: &str,
+        body: &crate::types::BatchDeletePrintersRequest,
+    ) -> Result<crate::types::BatchDeletePrintersResponse>;
+
+    /// See [`Customers::admin_chrome_printers_list_printer_models_with`].
+    async fn admin_chrome_printers_list_printer_models_with(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListPrinterModelsParams,
+    ) -> Result<Vec<crate::types::PrinterModel>>;
+
+    /// See [`Customers::admin_chrome_printers_list_all_printer_models_with`].
+    async fn admin_chrome_printers_list_all_printer_models_with(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListPrinterModelsParams,
+    ) -> Result<Vec<crate::types::PrinterModel>>;
+
+    /// See [`Customers::admin_chrome_printers_list_printer_models_stream`].
+    fn admin_chrome_printers_list_printer_models_stream(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListPrinterModelsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PrinterModel>;
+}
+
+#[async_trait::async_trait]
+impl CustomersApi for Customers {
+    async fn get(&self, customer_key: &str) -> Result<crate::types::Customer> {
+        Customers::get(self, customer_key).await
+    }
+
+    async fn update(
+        &self,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> Result<crate::types::Customer> {
+        Customers::update(self, customer_key, body).await
+    }
+
+    async fn patch(
+        &self,
+        customer_key: &str,
+        body: &crate::types::Customer,
+    ) -> Result<crate::types::Customer> {
+        Customers::patch(self, customer_key, body).await
+    }
+
+    async fn admin_chrome_printers_get(&self, name: &str) -> Result<crate::types::Printer> {
+        Customers::admin_chrome_printers_get(self, name).await
+    }
+
+    async fn admin_chrome_printers_delete(&self, name: &str) -> Result<crate::types::Empty> {
+        Customers::admin_chrome_printers_delete(self, name).await
+    }
+
+    async fn admin_chrome_printers_patch_with(
+        &self,
+        name: &str,
+        params: &CustomersAdminChromePrintersPatchParams,
+        body: &crate::types::Printer,
+    ) -> Result<crate::types::Printer> {
+        Customers::admin_chrome_printers_patch_with(self, name, params, body).await
+    }
+
+    async fn admin_chrome_printers_list_with(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListParams,
+    ) -> Result<Vec<crate::types::Printer>> {
+        Customers::admin_chrome_printers_list_with(self, parent, params).await
+    }
+
+    async fn admin_chrome_printers_list_all_with(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListParams,
+    ) -> Result<Vec<crate::types::Printer>> {
+        Customers::admin_chrome_printers_list_all_with(self, parent, params).await
+    }
+
+    fn admin_chrome_printers_list_stream(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Printer> {
+        Customers::admin_chrome_printers_list_stream(self, parent, params, start, max_items)
+    }
+
+    async fn admin_chrome_printers_create(
+        &self,
+        parent: &str,
+        body: &crate::types::Printer,
+    ) -> Result<crate::types::Printer> {
+        Customers::admin_chrome_printers_create(self, parent, body).await
+    }
+
+    async fn admin_chrome_printers_batch_create(
+        &self,
+        parent: &str,
+        body: &crate::types::BatchCreatePrintersRequest,
+    ) -> Result<crate::types::BatchCreatePrintersResponse> {
+        Customers::admin_chrome_printers_batch_create(self, parent, body).await
+    }
+
+    async fn admin_chrome_printers_batch_delete(
+        &self,
+        parent: &str,
+        body: &crate::types::BatchDeletePrintersRequest,
+    ) -> Result<crate::types::BatchDeletePrintersResponse> {
+        Customers::admin_chrome_printers_batch_delete(self, parent, body).await
+    }
+
+    async fn admin_chrome_printers_list_printer_models_with(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListPrinterModelsParams,
+    ) -> Result<Vec<crate::types::PrinterModel>> {
+        Customers::admin_chrome_printers_list_printer_models_with(self, parent, params).await
+    }
+
+    async fn admin_chrome_printers_list_all_printer_models_with(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListPrinterModelsParams,
+    ) -> Result<Vec<crate::types::PrinterModel>> {
+        Customers::admin_chrome_printers_list_all_printer_models_with(self, parent, params).await
+    }
+
+    fn admin_chrome_printers_list_printer_models_stream(
+        &self,
+        parent: &str,
+        params: &CustomersAdminChromePrintersListPrinterModelsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PrinterModel> {
+        Customers::admin_chrome_printers_list_printer_models_stream(
+            self, parent, params, start, max_items,
+        )
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct DomainAliases {
     pub client: Client,
@@ -20,15 +18,15 @@ impl DomainAliases {
      * **Parameters:**
      *
      * * `customer: &str` -- Immutable ID of the Google Workspace account.
-     * * `parent_domain_name: &str` -- Name of the parent domain for which domain aliases are to be fetched.
+     * * `params: &DomainAliasesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         customer: &str,
-        parent_domain_name: &str,
+        params: &DomainAliasesListParams,
     ) -> Result<crate::types::DomainAliases> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !parent_domain_name.is_empty() {
+        if let Some(parent_domain_name) = &params.parent_domain_name {
             query_args.push((
                 "parentDomainName".to_string(),
                 parent_domain_name.to_string(),
@@ -44,6 +42,21 @@ impl DomainAliases {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `DomainAliasesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        customer: &str,
+        parent_domain_name: &str,
+    ) -> Result<crate::types::DomainAliases> {
+        let params = DomainAliasesListParams {
+            parent_domain_name: Some(parent_domain_name.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(customer, &params).await
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/domainaliases` endpoint.
      *
@@ -112,3 +125,78 @@ impl DomainAliases {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `DomainAliases::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DomainAliasesListParams {
+    /// Name of the parent domain for which domain aliases are to be fetched.
+    pub parent_domain_name: Option<String>,
+}
+
+impl DomainAliasesListParams {
+    pub fn parent_domain_name<T: ToString>(mut self, parent_domain_name: T) -> Self {
+        self.parent_domain_name = Some(parent_domain_name.to_string());
+        self
+    }
+}
+
+/// The functions of [`DomainAliases`], for code that takes them as a trait, so that it can be
+/// tested with the `MockDomainAliasesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait DomainAliasesApi: Send + Sync {
+    /// See [`DomainAliases::list_with`].
+    async fn list_with(
+        &self,
+        customer: &str,
+        params: &DomainAliasesListParams,
+    ) -> Result<crate::types::DomainAliases>;
+
+    /// See [`DomainAliases::insert`].
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::DomainAlias,
+    ) -> Result<crate::types::DomainAlias>;
+
+    /// See [`DomainAliases::get`].
+    async fn get(
+        &self,
+        customer: &str,
+        domain_alias_name: &str,
+    ) -> Result<crate::types::DomainAlias>;
+
+    /// See [`DomainAliases::delete`].
+    async fn delete(&self, customer: &str, domain_alias_name: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl DomainAliasesApi for DomainAliases {
+    async fn list_with(
+        &self,
+        customer: &str,
+        params: &DomainAliasesListParams,
+    ) -> Result<crate::types::DomainAliases> {
+        DomainAliases::list_with(self, customer, params).await
+    }
+
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::DomainAlias,
+    ) -> Result<crate::types::DomainAlias> {
+        DomainAliases::insert(self, customer, body).await
+    }
+
+    async fn get(
+        &self,
+        customer: &str,
+        domain_alias_name: &str,
+    ) -> Result<crate::types::DomainAlias> {
+        DomainAliases::get(self, customer, domain_alias_name).await
+    }
+
+    async fn delete(&self, customer: &str, domain_alias_name: &str) -> Result<()> {
+        DomainAliases::delete(self, customer, domain_alias_name).await
+    }
+}
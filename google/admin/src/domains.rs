@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Domains {
     pub client: Client,
@@ -94,3 +92,48 @@ impl Domains {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`Domains`], for code that takes them as a trait, so that it can be
+/// tested with the `MockDomainsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait DomainsApi: Send + Sync {
+    /// See [`Domains::list`].
+    async fn list(&self, customer: &str) -> Result<crate::types::Domains2>;
+
+    /// See [`Domains::insert`].
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::Domains,
+    ) -> Result<crate::types::Domains>;
+
+    /// See [`Domains::get`].
+    async fn get(&self, customer: &str, domain_name: &str) -> Result<crate::types::Domains>;
+
+    /// See [`Domains::delete`].
+    async fn delete(&self, customer: &str, domain_name: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl DomainsApi for Domains {
+    async fn list(&self, customer: &str) -> Result<crate::types::Domains2> {
+        Domains::list(self, customer).await
+    }
+
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::Domains,
+    ) -> Result<crate::types::Domains> {
+        Domains::insert(self, customer, body).await
+    }
+
+    async fn get(&self, customer: &str, domain_name: &str) -> Result<crate::types::Domains> {
+        Domains::get(self, customer, domain_name).await
+    }
+
+    async fn delete(&self, customer: &str, domain_name: &str) -> Result<()> {
+        Domains::delete(self, customer, domain_name).await
+    }
+}
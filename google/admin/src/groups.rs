@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Groups {
     pub client: Client,
@@ -19,49 +17,32 @@ impl Groups {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. In case of a multi-domain account, to fetch all groups for a customer, fill this field instead of domain. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users](/admin-sdk/directory/v1/reference/users).
-     * * `domain: &str` -- The domain name. Use this field to get fields from only one domain. To return all domains for a customer account, use the `customer` query parameter instead.
-     * * `max_results: i64` -- Maximum number of results to return. Max allowed value is 200.
-     * * `order_by: crate::types::DirectoryGroupsListOrderBy` -- Column to use for sorting results.
-     * * `page_token: &str` -- Token to specify next page in the list.
-     * * `query: &str` -- Query string search. Should be of the form "". Complete documentation is at https: //developers.google.com/admin-sdk/directory/v1/guides/search-groups.
-     * * `sort_order: crate::types::SortOrder` -- Whether to return results in ascending or descending order. Must be used with the `orderBy` parameter.
-     * * `user_key: &str` -- Email or immutable ID of the user if only those groups are to be listed, the given user is a member of. If it's an ID, it should match with the ID of the user object.
+     * * `params: &GroupsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
-        &self,
-        customer: &str,
-        domain: &str,
-        max_results: i64,
-        order_by: crate::types::DirectoryGroupsListOrderBy,
-        page_token: &str,
-        query: &str,
-        sort_order: crate::types::SortOrder,
-        user_key: &str,
-    ) -> Result<Vec<crate::types::Group>> {
+    pub async fn list_with(&self, params: &GroupsListParams) -> Result<Vec<crate::types::Group>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !domain.is_empty() {
+        if let Some(domain) = &params.domain {
             query_args.push(("domain".to_string(), domain.to_string()));
         }
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !order_by.to_string().is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
-        if !sort_order.to_string().is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sortOrder".to_string(), sort_order.to_string()));
         }
-        if !user_key.is_empty() {
+        if let Some(user_key) = &params.user_key {
             query_args.push(("userKey".to_string(), user_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -74,38 +55,65 @@ impl Groups {
     }
 
     /**
-     * This function performs a `GET` to the `/admin/directory/v1/groups` endpoint.
-     *
-     * As opposed to `list`, this function returns all the pages of the request at once.
-     *
-     * Retrieves all groups of a domain or of a user given a userKey (paginated).
+     * Deprecated: use `list_with` and pass the query parameters as a `GroupsListParams` instead.
      */
-    pub async fn list_all(
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
         &self,
         customer: &str,
         domain: &str,
+        max_results: i64,
         order_by: crate::types::DirectoryGroupsListOrderBy,
+        page_token: &str,
         query: &str,
         sort_order: crate::types::SortOrder,
         user_key: &str,
+    ) -> Result<Vec<crate::types::Group>> {
+        let params = GroupsListParams {
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            domain: Some(domain.to_string()).filter(|s| !s.is_empty()),
+            max_results: Some(max_results).filter(|v| *v > 0),
+            order_by: Some(order_by).filter(|v| !v.to_string().is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order).filter(|v| !v.to_string().is_empty()),
+            user_key: Some(user_key.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Retrieves all groups of a domain or of a user given a userKey (paginated).
+     *
+     * **Parameters:**
+     *
+     * * `params: &GroupsListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(
+        &self,
+        params: &GroupsListParams,
     ) -> Result<Vec<crate::types::Group>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !domain.is_empty() {
+        if let Some(domain) = &params.domain {
             query_args.push(("domain".to_string(), domain.to_string()));
         }
-        if !order_by.to_string().is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
-        if !sort_order.to_string().is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sortOrder".to_string(), sort_order.to_string()));
         }
-        if !user_key.is_empty() {
+        if let Some(user_key) = &params.user_key {
             query_args.push(("userKey".to_string(), user_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -118,6 +126,7 @@ impl Groups {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -143,6 +152,86 @@ impl Groups {
         Ok(groups)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `GroupsListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        customer: &str,
+        domain: &str,
+        order_by: crate::types::DirectoryGroupsListOrderBy,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+        user_key: &str,
+    ) -> Result<Vec<crate::types::Group>> {
+        let params = GroupsListParams {
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            domain: Some(domain.to_string()).filter(|s| !s.is_empty()),
+            order_by: Some(order_by).filter(|v| !v.to_string().is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order).filter(|v| !v.to_string().is_empty()),
+            user_key: Some(user_key.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieves all groups of a domain or of a user given a userKey (paginated).
+     */
+    pub fn list_stream(
+        &self,
+        params: &GroupsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(customer) = &params.customer {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if let Some(domain) = &params.domain {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        if let Some(order_by) = &params.order_by {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if let Some(query) = &params.query {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if let Some(sort_order) = &params.sort_order {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        if let Some(user_key) = &params.user_key {
+            query_args.push(("userKey".to_string(), user_key.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/admin/directory/v1/groups?{}", query_);
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::Groups = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.groups,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/groups` endpoint.
      *
@@ -301,3 +390,186 @@ impl Groups {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Groups::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsListParams {
+    /// The unique ID for the customer's Google Workspace account. In case of a multi-domain account, to fetch all groups for a customer, fill this field instead of domain. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users](/admin-sdk/directory/v1/reference/users).
+    pub customer: Option<String>,
+    /// The domain name. Use this field to get fields from only one domain. To return all domains for a customer account, use the `customer` query parameter instead.
+    pub domain: Option<String>,
+    /// Maximum number of results to return. Max allowed value is 200.
+    pub max_results: Option<i64>,
+    /// Column to use for sorting results.
+    pub order_by: Option<crate::types::DirectoryGroupsListOrderBy>,
+    /// Token to specify next page in the list.
+    pub page_token: Option<String>,
+    /// Query string search. Should be of the form "". Complete documentation is at https: //developers.google.com/admin-sdk/directory/v1/guides/search-groups.
+    pub query: Option<String>,
+    /// Whether to return results in ascending or descending order. Only of use when orderBy is also used.
+    pub sort_order: Option<crate::types::SortOrder>,
+    /// Email or immutable ID of the user if only those groups are to be listed, the given user is a member of. If it's an ID, it should match with the ID of the user object.
+    pub user_key: Option<String>,
+}
+
+impl GroupsListParams {
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn domain<T: ToString>(mut self, domain: T) -> Self {
+        self.domain = Some(domain.to_string());
+        self
+    }
+
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn order_by(mut self, order_by: crate::types::DirectoryGroupsListOrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    pub fn user_key<T: ToString>(mut self, user_key: T) -> Self {
+        self.user_key = Some(user_key.to_string());
+        self
+    }
+}
+
+/// The functions of [`Groups`], for code that takes them as a trait, so that it can be
+/// tested with the `MockGroupsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait GroupsApi: Send + Sync {
+    /// See [`Groups::list_with`].
+    async fn list_with(&self, params: &GroupsListParams) -> Result<Vec<crate::types::Group>>;
+
+    /// See [`Groups::list_all_with`].
+    async fn list_all_with(&self, params: &GroupsListParams) -> Result<Vec<crate::types::Group>>;
+
+    /// See [`Groups::list_stream`].
+    fn list_stream(
+        &self,
+        params: &GroupsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group>;
+
+    /// See [`Groups::insert`].
+    async fn insert(&self, body: &crate::types::Group) -> Result<crate::types::Group>;
+
+    /// See [`Groups::get`].
+    async fn get(&self, group_key: &str) -> Result<crate::types::Group>;
+
+    /// See [`Groups::update`].
+    async fn update(
+        &self,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> Result<crate::types::Group>;
+
+    /// See [`Groups::delete`].
+    async fn delete(&self, group_key: &str) -> Result<()>;
+
+    /// See [`Groups::patch`].
+    async fn patch(
+        &self,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> Result<crate::types::Group>;
+
+    /// See [`Groups::aliases_list`].
+    async fn aliases_list(&self, group_key: &str) -> Result<crate::types::Aliases>;
+
+    /// See [`Groups::aliases_insert`].
+    async fn aliases_insert(
+        &self,
+        group_key: &str,
+        body: &crate::types::Alias,
+    ) -> Result<crate::types::Alias>;
+
+    /// See [`Groups::aliases_delete`].
+    async fn aliases_delete(&self, group_key: &str, alias: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl GroupsApi for Groups {
+    async fn list_with(&self, params: &GroupsListParams) -> Result<Vec<crate::types::Group>> {
+        Groups::list_with(self, params).await
+    }
+
+    async fn list_all_with(&self, params: &GroupsListParams) -> Result<Vec<crate::types::Group>> {
+        Groups::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &GroupsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group> {
+        Groups::list_stream(self, params, start, max_items)
+    }
+
+    async fn insert(&self, body: &crate::types::Group) -> Result<crate::types::Group> {
+        Groups::insert(self, body).await
+    }
+
+    async fn get(&self, group_key: &str) -> Result<crate::types::Group> {
+        Groups::get(self, group_key).await
+    }
+
+    async fn update(
+        &self,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> Result<crate::types::Group> {
+        Groups::update(self, group_key, body).await
+    }
+
+    async fn delete(&self, group_key: &str) -> Result<()> {
+        Groups::delete(self, group_key).await
+    }
+
+    async fn patch(
+        &self,
+        group_key: &str,
+        body: &crate::types::Group,
+    ) -> Result<crate::types::Group> {
+        Groups::patch(self, group_key, body).await
+    }
+
+    async fn aliases_list(&self, group_key: &str) -> Result<crate::types::Aliases> {
+        Groups::aliases_list(self, group_key).await
+    }
+
+    async fn aliases_insert(
+        &self,
+        group_key: &str,
+        body: &crate::types::Alias,
+    ) -> Result<crate::types::Alias> {
+        Groups::aliases_insert(self, group_key, body).await
+    }
+
+    async fn aliases_delete(&self, group_key: &str, alias: &str) -> Result<()> {
+        Groups::aliases_delete(self, group_key, alias).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Members {
     pub client: Client,
@@ -44,33 +42,27 @@ impl Members {
      * **Parameters:**
      *
      * * `group_key: &str` -- Identifies the group in the API request. The value can be the group's email address, group alias, or the unique group ID.
-     * * `include_derived_membership: bool` -- A Boolean value to indicate whether payload is wanted. Optional.
-     * * `max_results: i64` -- Maximum number of results to return. Max allowed value is 200.
-     * * `page_token: &str` -- Token to specify next page in the list.
-     * * `roles: &str` -- The `roles` query parameter allows you to retrieve group members by role. Allowed values are `OWNER`, `MANAGER`, and `MEMBER`.
+     * * `params: &MembersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         group_key: &str,
-        include_derived_membership: bool,
-        max_results: i64,
-        page_token: &str,
-        roles: &str,
+        params: &MembersListParams,
     ) -> Result<Vec<crate::types::Member>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_derived_membership {
+        if let Some(include_derived_membership) = &params.include_derived_membership {
             query_args.push((
                 "includeDerivedMembership".to_string(),
                 include_derived_membership.to_string(),
             ));
         }
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !roles.is_empty() {
+        if let Some(roles) = &params.roles {
             query_args.push(("roles".to_string(), roles.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -86,27 +78,51 @@ impl Members {
         Ok(resp.members.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `MembersListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        group_key: &str,
+        include_derived_membership: bool,
+        max_results: i64,
+        page_token: &str,
+        roles: &str,
+    ) -> Result<Vec<crate::types::Member>> {
+        let params = MembersListParams {
+            include_derived_membership: Some(include_derived_membership).filter(|v| *v),
+            max_results: Some(max_results).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            roles: Some(roles.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(group_key, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Retrieves a paginated list of all members in a group.
+     *
+     * **Parameters:**
+     *
+     * * `params: &MembersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(
+    pub async fn list_all_with(
         &self,
         group_key: &str,
-        include_derived_membership: bool,
-        roles: &str,
+        params: &MembersListParams,
     ) -> Result<Vec<crate::types::Member>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_derived_membership {
+        if let Some(include_derived_membership) = &params.include_derived_membership {
             query_args.push((
                 "includeDerivedMembership".to_string(),
                 include_derived_membership.to_string(),
             ));
         }
-        if !roles.is_empty() {
+        if let Some(roles) = &params.roles {
             query_args.push(("roles".to_string(), roles.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -123,6 +139,7 @@ impl Members {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -148,6 +165,75 @@ impl Members {
         Ok(members)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `MembersListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        group_key: &str,
+        include_derived_membership: bool,
+        roles: &str,
+    ) -> Result<Vec<crate::types::Member>> {
+        let params = MembersListParams {
+            include_derived_membership: Some(include_derived_membership).filter(|v| *v),
+            roles: Some(roles.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(group_key, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieves a paginated list of all members in a group.
+     */
+    pub fn list_stream(
+        &self,
+        group_key: &str,
+        params: &MembersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Member> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(include_derived_membership) = &params.include_derived_membership {
+            query_args.push((
+                "includeDerivedMembership".to_string(),
+                include_derived_membership.to_string(),
+            ));
+        }
+        if let Some(roles) = &params.roles {
+            query_args.push(("roles".to_string(), roles.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/groups/{}/members?{}",
+            crate::progenitor_support::encode_path(group_key),
+            query_
+        );
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::Members = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.members,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/groups/{groupKey}/members` endpoint.
      *
@@ -266,3 +352,174 @@ impl Members {
             .await
     }
 }
+
+/// The query parameters of `Members::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MembersListParams {
+    /// A Boolean value to indicate whether payload is wanted. Optional.
+    pub include_derived_membership: Option<bool>,
+    /// Maximum number of results to return. Max allowed value is 200.
+    pub max_results: Option<i64>,
+    /// Token to specify next page in the list.
+    pub page_token: Option<String>,
+    /// The `roles` query parameter allows you to retrieve group members by role. Allowed values are `OWNER`, `MANAGER`, and `MEMBER`.
+    pub roles: Option<String>,
+}
+
+impl MembersListParams {
+    pub fn include_derived_membership(mut self, include_derived_membership: bool) -> Self {
+        self.include_derived_membership = Some(include_derived_membership);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn roles<T: ToString>(mut self, roles: T) -> Self {
+        self.roles = Some(roles.to_string());
+        self
+    }
+}
+
+/// The functions of [`Members`], for code that takes them as a trait, so that it can be
+/// tested with the `MockMembersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait MembersApi: Send + Sync {
+    /// See [`Members::has`].
+    async fn has(
+        &self,
+        group_key: &str,
+        member_key: &str,
+    ) -> Result<crate::types::MembersHasMember>;
+
+    /// See [`Members::list_with`].
+    async fn list_with(
+        &self,
+        group_key: &str,
+        params: &MembersListParams,
+    ) -> Result<Vec<crate::types::Member>>;
+
+    /// See [`Members::list_all_with`].
+    async fn list_all_with(
+        &self,
+        group_key: &str,
+        params: &MembersListParams,
+    ) -> Result<Vec<crate::types::Member>>;
+
+    /// See [`Members::list_stream`].
+    fn list_stream(
+        &self,
+        group_key: &str,
+        params: &MembersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Member>;
+
+    /// See [`Members::insert`].
+    async fn insert(
+        &self,
+        group_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::types::Member>;
+
+    /// See [`Members::get`].
+    async fn get(&self, group_key: &str, member_key: &str) -> Result<crate::types::Member>;
+
+    /// See [`Members::update`].
+    async fn update(
+        &self,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::types::Member>;
+
+    /// See [`Members::delete`].
+    async fn delete(&self, group_key: &str, member_key: &str) -> Result<()>;
+
+    /// See [`Members::patch`].
+    async fn patch(
+        &self,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::types::Member>;
+}
+
+#[async_trait::async_trait]
+impl MembersApi for Members {
+    async fn has(
+        &self,
+        group_key: &str,
+        member_key: &str,
+    ) -> Result<crate::types::MembersHasMember> {
+        Members::has(self, group_key, member_key).await
+    }
+
+    async fn list_with(
+        &self,
+        group_key: &str,
+        params: &MembersListParams,
+    ) -> Result<Vec<crate::types::Member>> {
+        Members::list_with(self, group_key, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        group_key: &str,
+        params: &MembersListParams,
+    ) -> Result<Vec<crate::types::Member>> {
+        Members::list_all_with(self, group_key, params).await
+    }
+
+    fn list_stream(
+        &self,
+        group_key: &str,
+        params: &MembersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Member> {
+        Members::list_stream(self, group_key, params, start, max_items)
+    }
+
+    async fn insert(
+        &self,
+        group_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::types::Member> {
+        Members::insert(self, group_key, body).await
+    }
+
+    async fn get(&self, group_key: &str, member_key: &str) -> Result<crate::types::Member> {
+        Members::get(self, group_key, member_key).await
+    }
+
+    async fn update(
+        &self,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::types::Member> {
+        Members::update(self, group_key, member_key, body).await
+    }
+
+    async fn delete(&self, group_key: &str, member_key: &str) -> Result<()> {
+        Members::delete(self, group_key, member_key).await
+    }
+
+    async fn patch(
+        &self,
+        group_key: &str,
+        member_key: &str,
+        body: &crate::types::Member,
+    ) -> Result<crate::types::Member> {
+        Members::patch(self, group_key, member_key, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Mobiledevices {
     pub client: Client,
@@ -20,40 +18,30 @@ impl Mobiledevices {
      * **Parameters:**
      *
      * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
-     * * `max_results: i64` -- Maximum number of results to return. Max allowed value is 100.
-     * * `order_by: crate::types::DirectoryMobiledevicesListOrderBy` -- Device property to use for sorting results.
-     * * `page_token: &str` -- Token to specify next page in the list.
-     * * `projection: crate::types::Projection` -- Restrict information returned to a set of selected fields.
-     * * `query: &str` -- Search string in the format given at https://developers.google.com/admin-sdk/directory/v1/search-operators.
-     * * `sort_order: crate::types::SortOrder` -- Whether to return results in ascending or descending order. Must be used with the `orderBy` parameter.
+     * * `params: &MobiledevicesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         customer_id: &str,
-        max_results: i64,
-        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
-        page_token: &str,
-        projection: crate::types::Projection,
-        query: &str,
-        sort_order: crate::types::SortOrder,
+        params: &MobiledevicesListParams,
     ) -> Result<Vec<crate::types::MobileDevice>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !order_by.to_string().is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !projection.to_string().is_empty() {
+        if let Some(projection) = &params.projection {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
-        if !sort_order.to_string().is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sortOrder".to_string(), sort_order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -69,32 +57,58 @@ impl Mobiledevices {
         Ok(resp.mobiledevices.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `MobiledevicesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        customer_id: &str,
+        max_results: i64,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        page_token: &str,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> Result<Vec<crate::types::MobileDevice>> {
+        let params = MobiledevicesListParams {
+            max_results: Some(max_results).filter(|v| *v > 0),
+            order_by: Some(order_by).filter(|v| !v.to_string().is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            projection: Some(projection).filter(|v| !v.to_string().is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_with(customer_id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Retrieves a paginated list of all mobile devices for an account.
+     *
+     * **Parameters:**
+     *
+     * * `params: &MobiledevicesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(
+    pub async fn list_all_with(
         &self,
         customer_id: &str,
-        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
-        projection: crate::types::Projection,
-        query: &str,
-        sort_order: crate::types::SortOrder,
+        params: &MobiledevicesListParams,
     ) -> Result<Vec<crate::types::MobileDevice>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !order_by.to_string().is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !projection.to_string().is_empty() {
+        if let Some(projection) = &params.projection {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
-        if !sort_order.to_string().is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sortOrder".to_string(), sort_order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -111,6 +125,7 @@ impl Mobiledevices {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -136,6 +151,82 @@ impl Mobiledevices {
         Ok(mobiledevices)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `MobiledevicesListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        customer_id: &str,
+        order_by: crate::types::DirectoryMobiledevicesListOrderBy,
+        projection: crate::types::Projection,
+        query: &str,
+        sort_order: crate::types::SortOrder,
+    ) -> Result<Vec<crate::types::MobileDevice>> {
+        let params = MobiledevicesListParams {
+            order_by: Some(order_by).filter(|v| !v.to_string().is_empty()),
+            projection: Some(projection).filter(|v| !v.to_string().is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(customer_id, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieves a paginated list of all mobile devices for an account.
+     */
+    pub fn list_stream(
+        &self,
+        customer_id: &str,
+        params: &MobiledevicesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MobileDevice> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(order_by) = &params.order_by {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if let Some(projection) = &params.projection {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        if let Some(query) = &params.query {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if let Some(sort_order) = &params.sort_order {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/devices/mobile?{}",
+            crate::progenitor_support::encode_path(customer_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::MobileDevices = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.mobiledevices,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
      *
@@ -145,16 +236,16 @@ impl Mobiledevices {
      *
      * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
      * * `resource_id: &str` -- The unique ID the API service uses to identify the mobile device.
-     * * `projection: crate::types::Projection` -- Restrict information returned to a set of selected fields.
+     * * `params: &MobiledevicesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         customer_id: &str,
         resource_id: &str,
-        projection: crate::types::Projection,
+        params: &MobiledevicesGetParams,
     ) -> Result<crate::types::MobileDevice> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !projection.to_string().is_empty() {
+        if let Some(projection) = &params.projection {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -168,6 +259,22 @@ impl Mobiledevices {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `MobiledevicesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        projection: crate::types::Projection,
+    ) -> Result<crate::types::MobileDevice> {
+        let params = MobiledevicesGetParams {
+            projection: Some(projection).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_with(customer_id, resource_id, &params).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customerId}/devices/mobile/{resourceId}` endpoint.
      *
@@ -215,3 +322,165 @@ impl Mobiledevices {
             .await
     }
 }
+
+/// The query parameters of `Mobiledevices::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MobiledevicesListParams {
+    /// Maximum number of results to return. Max allowed value is 100.
+    pub max_results: Option<i64>,
+    /// Device property to use for sorting results.
+    pub order_by: Option<crate::types::DirectoryMobiledevicesListOrderBy>,
+    /// Token to specify next page in the list.
+    pub page_token: Option<String>,
+    /// Restrict information returned to a set of selected fields.
+    pub projection: Option<crate::types::Projection>,
+    /// Search string in the format given at https://developers.google.com/admin-sdk/directory/v1/search-operators.
+    pub query: Option<String>,
+    /// Whether to return results in ascending or descending order. Must be used with the `orderBy` parameter.
+    pub sort_order: Option<crate::types::SortOrder>,
+}
+
+impl MobiledevicesListParams {
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn order_by(mut self, order_by: crate::types::DirectoryMobiledevicesListOrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+}
+
+/// The query parameters of `Mobiledevices::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MobiledevicesGetParams {
+    /// Restrict information returned to a set of selected fields.
+    pub projection: Option<crate::types::Projection>,
+}
+
+impl MobiledevicesGetParams {
+    pub fn projection(mut self, projection: crate::types::Projection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+}
+
+/// The functions of [`Mobiledevices`], for code that takes them as a trait, so that it can be
+/// tested with the `MockMobiledevicesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait MobiledevicesApi: Send + Sync {
+    /// See [`Mobiledevices::list_with`].
+    async fn list_with(
+        &self,
+        customer_id: &str,
+        params: &MobiledevicesListParams,
+    ) -> Result<Vec<crate::types::MobileDevice>>;
+
+    /// See [`Mobiledevices::list_all_with`].
+    async fn list_all_with(
+        &self,
+        customer_id: &str,
+        params: &MobiledevicesListParams,
+    ) -> Result<Vec<crate::types::MobileDevice>>;
+
+    /// See [`Mobiledevices::list_stream`].
+    fn list_stream(
+        &self,
+        customer_id: &str,
+        params: &MobiledevicesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MobileDevice>;
+
+    /// See [`Mobiledevices::get_with`].
+    async fn get_with(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        params: &MobiledevicesGetParams,
+    ) -> Result<crate::types::MobileDevice>;
+
+    /// See [`Mobiledevices::delete`].
+    async fn delete(&self, customer_id: &str, resource_id: &str) -> Result<()>;
+
+    /// See [`Mobiledevices::action`].
+    async fn action(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::MobileDeviceAction,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl MobiledevicesApi for Mobiledevices {
+    async fn list_with(
+        &self,
+        customer_id: &str,
+        params: &MobiledevicesListParams,
+    ) -> Result<Vec<crate::types::MobileDevice>> {
+        Mobiledevices::list_with(self, customer_id, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        customer_id: &str,
+        params: &MobiledevicesListParams,
+    ) -> Result<Vec<crate::types::MobileDevice>> {
+        Mobiledevices::list_all_with(self, customer_id, params).await
+    }
+
+    fn list_stream(
+        &self,
+        customer_id: &str,
+        params: &MobiledevicesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::MobileDevice> {
+        Mobiledevices::list_stream(self, customer_id, params, start, max_items)
+    }
+
+    async fn get_with(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        params: &MobiledevicesGetParams,
+    ) -> Result<crate::types::MobileDevice> {
+        Mobiledevices::get_with(self, customer_id, resource_id, params).await
+    }
+
+    async fn delete(&self, customer_id: &str, resource_id: &str) -> Result<()> {
+        Mobiledevices::delete(self, customer_id, resource_id).await
+    }
+
+    async fn action(
+        &self,
+        customer_id: &str,
+        resource_id: &str,
+        body: &crate::types::MobileDeviceAction,
+    ) -> Result<()> {
+        Mobiledevices::action(self, customer_id, resource_id, body).await
+    }
+}
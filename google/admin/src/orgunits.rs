@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Orgunits {
     pub client: Client,
@@ -20,20 +18,18 @@ impl Orgunits {
      * **Parameters:**
      *
      * * `customer_id: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users).
-     * * `org_unit_path: &str` -- The full path to the organizational unit or its unique ID. Returns the children of the specified organizational unit.
-     * * `type_: crate::types::DirectoryOrgunitsListType` -- Whether to return all sub-organizations or just immediate children.
+     * * `params: &OrgunitsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         customer_id: &str,
-        org_unit_path: &str,
-        type_: crate::types::DirectoryOrgunitsListType,
+        params: &OrgunitsListParams,
     ) -> Result<crate::types::OrgUnits> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !org_unit_path.is_empty() {
+        if let Some(org_unit_path) = &params.org_unit_path {
             query_args.push(("orgUnitPath".to_string(), org_unit_path.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -46,6 +42,23 @@ impl Orgunits {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `OrgunitsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        type_: crate::types::DirectoryOrgunitsListType,
+    ) -> Result<crate::types::OrgUnits> {
+        let params = OrgunitsListParams {
+            org_unit_path: Some(org_unit_path.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_with(customer_id, &params).await
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customerId}/orgunits` endpoint.
      *
@@ -168,3 +181,111 @@ impl Orgunits {
             .await
     }
 }
+
+/// The query parameters of `Orgunits::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrgunitsListParams {
+    /// The full path to the organizational unit or its unique ID. Returns the children of the specified organizational unit.
+    pub org_unit_path: Option<String>,
+    /// Whether to return all sub-organizations or just immediate children.
+    pub type_: Option<crate::types::DirectoryOrgunitsListType>,
+}
+
+impl OrgunitsListParams {
+    pub fn org_unit_path<T: ToString>(mut self, org_unit_path: T) -> Self {
+        self.org_unit_path = Some(org_unit_path.to_string());
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::DirectoryOrgunitsListType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The functions of [`Orgunits`], for code that takes them as a trait, so that it can be
+/// tested with the `MockOrgunitsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait OrgunitsApi: Send + Sync {
+    /// See [`Orgunits::list_with`].
+    async fn list_with(
+        &self,
+        customer_id: &str,
+        params: &OrgunitsListParams,
+    ) -> Result<crate::types::OrgUnits>;
+
+    /// See [`Orgunits::insert`].
+    async fn insert(
+        &self,
+        customer_id: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::types::OrgUnit>;
+
+    /// See [`Orgunits::get`].
+    async fn get(&self, customer_id: &str, org_unit_path: &str) -> Result<crate::types::OrgUnit>;
+
+    /// See [`Orgunits::update`].
+    async fn update(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::types::OrgUnit>;
+
+    /// See [`Orgunits::delete`].
+    async fn delete(&self, customer_id: &str, org_unit_path: &str) -> Result<()>;
+
+    /// See [`Orgunits::patch`].
+    async fn patch(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::types::OrgUnit>;
+}
+
+#[async_trait::async_trait]
+impl OrgunitsApi for Orgunits {
+    async fn list_with(
+        &self,
+        customer_id: &str,
+        params: &OrgunitsListParams,
+    ) -> Result<crate::types::OrgUnits> {
+        Orgunits::list_with(self, customer_id, params).await
+    }
+
+    async fn insert(
+        &self,
+        customer_id: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::types::OrgUnit> {
+        Orgunits::insert(self, customer_id, body).await
+    }
+
+    async fn get(&self, customer_id: &str, org_unit_path: &str) -> Result<crate::types::OrgUnit> {
+        Orgunits::get(self, customer_id, org_unit_path).await
+    }
+
+    async fn update(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::types::OrgUnit> {
+        Orgunits::update(self, customer_id, org_unit_path, body).await
+    }
+
+    async fn delete(&self, customer_id: &str, org_unit_path: &str) -> Result<()> {
+        Orgunits::delete(self, customer_id, org_unit_path).await
+    }
+
+    async fn patch(
+        &self,
+        customer_id: &str,
+        org_unit_path: &str,
+        body: &crate::types::OrgUnit,
+    ) -> Result<crate::types::OrgUnit> {
+        Orgunits::patch(self, customer_id, org_unit_path, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Privileges {
     pub client: Client,
@@ -30,3 +28,19 @@ impl Privileges {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`Privileges`], for code that takes them as a trait, so that it can be
+/// tested with the `MockPrivilegesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait PrivilegesApi: Send + Sync {
+    /// See [`Privileges::list`].
+    async fn list(&self, customer: &str) -> Result<crate::types::Privileges>;
+}
+
+#[async_trait::async_trait]
+impl PrivilegesApi for Privileges {
+    async fn list(&self, customer: &str) -> Result<crate::types::Privileges> {
+        Privileges::list(self, customer).await
+    }
+}
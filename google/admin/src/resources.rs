@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Resources {
     pub client: Client,
@@ -20,20 +18,18 @@ impl Resources {
      * **Parameters:**
      *
      * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
-     * * `max_results: i64` -- Maximum number of results to return.
-     * * `page_token: &str` -- Token to specify the next page in the list.
+     * * `params: &ResourcesBuildingsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn buildings_list(
+    pub async fn buildings_list_with(
         &self,
         customer: &str,
-        max_results: i64,
-        page_token: &str,
+        params: &ResourcesBuildingsListParams,
     ) -> Result<Vec<crate::types::Building>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -49,6 +45,23 @@ impl Resources {
         Ok(resp.buildings.to_vec())
     }
 
+    /**
+     * Deprecated: use `buildings_list_with` and pass the query parameters as a `ResourcesBuildingsListParams` instead.
+     */
+    #[deprecated(note = "use `buildings_list_with` instead")]
+    pub async fn buildings_list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Building>> {
+        let params = ResourcesBuildingsListParams {
+            max_results: Some(max_results).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.buildings_list_with(customer, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
      *
@@ -69,6 +82,7 @@ impl Resources {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -94,6 +108,43 @@ impl Resources {
         Ok(buildings)
     }
 
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
+     *
+     * As opposed to `buildings_list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieves a list of buildings for an account.
+     */
+    pub fn buildings_list_stream(
+        &self,
+        customer: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Building> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/buildings",
+            crate::progenitor_support::encode_path(customer),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::Buildings = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.buildings,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/buildings` endpoint.
      *
@@ -102,16 +153,16 @@ impl Resources {
      * **Parameters:**
      *
      * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
-     * * `coordinates_source: crate::types::CoordinatesSource` -- Source from which Building.coordinates are derived.
+     * * `params: &ResourcesBuildingsInsertParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn buildings_insert(
+    pub async fn buildings_insert_with(
         &self,
         customer: &str,
-        coordinates_source: crate::types::CoordinatesSource,
+        params: &ResourcesBuildingsInsertParams,
         body: &crate::types::Building,
     ) -> Result<crate::types::Building> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !coordinates_source.to_string().is_empty() {
+        if let Some(coordinates_source) = &params.coordinates_source {
             query_args.push((
                 "coordinatesSource".to_string(),
                 coordinates_source.to_string(),
@@ -129,6 +180,22 @@ impl Resources {
             .await
     }
 
+    /**
+     * Deprecated: use `buildings_insert_with` and pass the query parameters as a `ResourcesBuildingsInsertParams` instead.
+     */
+    #[deprecated(note = "use `buildings_insert_with` instead")]
+    pub async fn buildings_insert(
+        &self,
+        customer: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building> {
+        let params = ResourcesBuildingsInsertParams {
+            coordinates_source: Some(coordinates_source).filter(|v| !v.to_string().is_empty()),
+        };
+        self.buildings_insert_with(customer, &params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
      *
@@ -162,17 +229,17 @@ impl Resources {
      *
      * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
      * * `building_id: &str` -- The id of the building to update.
-     * * `coordinates_source: crate::types::CoordinatesSource` -- Source from which Building.coordinates are derived.
+     * * `params: &ResourcesBuildingsUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn buildings_update(
+    pub async fn buildings_update_with(
         &self,
         customer: &str,
         building_id: &str,
-        coordinates_source: crate::types::CoordinatesSource,
+        params: &ResourcesBuildingsUpdateParams,
         body: &crate::types::Building,
     ) -> Result<crate::types::Building> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !coordinates_source.to_string().is_empty() {
+        if let Some(coordinates_source) = &params.coordinates_source {
             query_args.push((
                 "coordinatesSource".to_string(),
                 coordinates_source.to_string(),
@@ -191,6 +258,24 @@ impl Resources {
             .await
     }
 
+    /**
+     * Deprecated: use `buildings_update_with` and pass the query parameters as a `ResourcesBuildingsUpdateParams` instead.
+     */
+    #[deprecated(note = "use `buildings_update_with` instead")]
+    pub async fn buildings_update(
+        &self,
+        customer: &str,
+        building_id: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building> {
+        let params = ResourcesBuildingsUpdateParams {
+            coordinates_source: Some(coordinates_source).filter(|v| !v.to_string().is_empty()),
+        };
+        self.buildings_update_with(customer, building_id, &params, body)
+            .await
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/customer/{customer}/resources/buildings/{buildingId}` endpoint.
      *
@@ -220,17 +305,17 @@ impl Resources {
      *
      * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
      * * `building_id: &str` -- The id of the building to update.
-     * * `coordinates_source: crate::types::CoordinatesSource` -- Source from which Building.coordinates are derived.
+     * * `params: &ResourcesBuildingsPatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn buildings_patch(
+    pub async fn buildings_patch_with(
         &self,
         customer: &str,
         building_id: &str,
-        coordinates_source: crate::types::CoordinatesSource,
+        params: &ResourcesBuildingsPatchParams,
         body: &crate::types::Building,
     ) -> Result<crate::types::Building> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !coordinates_source.to_string().is_empty() {
+        if let Some(coordinates_source) = &params.coordinates_source {
             query_args.push((
                 "coordinatesSource".to_string(),
                 coordinates_source.to_string(),
@@ -249,6 +334,24 @@ impl Resources {
             .await
     }
 
+    /**
+     * Deprecated: use `buildings_patch_with` and pass the query parameters as a `ResourcesBuildingsPatchParams` instead.
+     */
+    #[deprecated(note = "use `buildings_patch_with` instead")]
+    pub async fn buildings_patch(
+        &self,
+        customer: &str,
+        building_id: &str,
+        coordinates_source: crate::types::CoordinatesSource,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building> {
+        let params = ResourcesBuildingsPatchParams {
+            coordinates_source: Some(coordinates_source).filter(|v| !v.to_string().is_empty()),
+        };
+        self.buildings_patch_with(customer, building_id, &params, body)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
      *
@@ -257,30 +360,24 @@ impl Resources {
      * **Parameters:**
      *
      * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
-     * * `max_results: i64` -- Maximum number of results to return.
-     * * `order_by: &str` -- Field(s) to sort results by in either ascending or descending order. Supported fields include `resourceId`, `resourceName`, `capacity`, `buildingId`, and `floorName`. If no order is specified, defaults to ascending. Should be of the form "field [asc|desc], field [asc|desc], ...". For example `buildingId, capacity desc` would return results sorted first by `buildingId` in ascending order then by `capacity` in descending order.
-     * * `page_token: &str` -- Token to specify the next page in the list.
-     * * `query: &str` -- String query used to filter results. Should be of the form "field operator value" where field can be any of supported fields and operators can be any of supported operations. Operators include '=' for exact match, '!=' for mismatch and ':' for prefix match or HAS match where applicable. For prefix match, the value should always be followed by a *. Logical operators NOT and AND are supported (in this order of precedence). Supported fields include `generatedResourceName`, `name`, `buildingId`, `floor_name`, `capacity`, `featureInstances.feature.name`, `resourceEmail`, `resourceCategory`. For example `buildingId=US-NYC-9TH AND featureInstances.feature.name:Phone`.
+     * * `params: &ResourcesCalendarsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn calendars_list(
+    pub async fn calendars_list_with(
         &self,
         customer: &str,
-        max_results: i64,
-        order_by: &str,
-        page_token: &str,
-        query: &str,
+        params: &ResourcesCalendarsListParams,
     ) -> Result<Vec<crate::types::CalendarResource>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !order_by.is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -296,24 +393,48 @@ impl Resources {
         Ok(resp.items.to_vec())
     }
 
+    /**
+     * Deprecated: use `calendars_list_with` and pass the query parameters as a `ResourcesCalendarsListParams` instead.
+     */
+    #[deprecated(note = "use `calendars_list_with` instead")]
+    pub async fn calendars_list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        order_by: &str,
+        page_token: &str,
+        query: &str,
+    ) -> Result<Vec<crate::types::CalendarResource>> {
+        let params = ResourcesCalendarsListParams {
+            max_results: Some(max_results).filter(|v| *v > 0),
+            order_by: Some(order_by.to_string()).filter(|s| !s.is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.calendars_list_with(customer, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
      *
      * As opposed to `calendars_list`, this function returns all the pages of the request at once.
      *
      * Retrieves a list of calendar resources for an account.
+     *
+     * **Parameters:**
+     *
+     * * `params: &ResourcesCalendarsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn calendars_list_all(
+    pub async fn calendars_list_all_with(
         &self,
         customer: &str,
-        order_by: &str,
-        query: &str,
+        params: &ResourcesCalendarsListParams,
     ) -> Result<Vec<crate::types::CalendarResource>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !order_by.is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -330,6 +451,7 @@ impl Resources {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -355,6 +477,72 @@ impl Resources {
         Ok(items)
     }
 
+    /**
+     * Deprecated: use `calendars_list_all_with` and pass the query parameters as a `ResourcesCalendarsListParams` instead.
+     */
+    #[deprecated(note = "use `calendars_list_all_with` instead")]
+    pub async fn calendars_list_all(
+        &self,
+        customer: &str,
+        order_by: &str,
+        query: &str,
+    ) -> Result<Vec<crate::types::CalendarResource>> {
+        let params = ResourcesCalendarsListParams {
+            order_by: Some(order_by.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.calendars_list_all_with(customer, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
+     *
+     * As opposed to `calendars_list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieves a list of calendar resources for an account.
+     */
+    pub fn calendars_list_stream(
+        &self,
+        customer: &str,
+        params: &ResourcesCalendarsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CalendarResource> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(order_by) = &params.order_by {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if let Some(query) = &params.query {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/calendars?{}",
+            crate::progenitor_support::encode_path(customer),
+            query_
+        );
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::CalendarResources = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.items,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/calendars` endpoint.
      *
@@ -485,20 +673,18 @@ impl Resources {
      * **Parameters:**
      *
      * * `customer: &str` -- The unique ID for the customer's Google Workspace account. As an account administrator, you can also use the `my_customer` alias to represent your account's customer ID.
-     * * `max_results: i64` -- Maximum number of results to return.
-     * * `page_token: &str` -- Token to specify the next page in the list.
+     * * `params: &ResourcesFeaturesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn features_list(
+    pub async fn features_list_with(
         &self,
         customer: &str,
-        max_results: i64,
-        page_token: &str,
+        params: &ResourcesFeaturesListParams,
     ) -> Result<Vec<crate::types::Feature>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -514,6 +700,23 @@ impl Resources {
         Ok(resp.features.to_vec())
     }
 
+    /**
+     * Deprecated: use `features_list_with` and pass the query parameters as a `ResourcesFeaturesListParams` instead.
+     */
+    #[deprecated(note = "use `features_list_with` instead")]
+    pub async fn features_list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Feature>> {
+        let params = ResourcesFeaturesListParams {
+            max_results: Some(max_results).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.features_list_with(customer, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
      *
@@ -534,6 +737,7 @@ impl Resources {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -559,6 +763,43 @@ impl Resources {
         Ok(features)
     }
 
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
+     *
+     * As opposed to `features_list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieves a list of features for an account.
+     */
+    pub fn features_list_stream(
+        &self,
+        customer: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Feature> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/resources/features",
+            crate::progenitor_support::encode_path(customer),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::Features = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.features,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/resources/features` endpoint.
      *
@@ -708,3 +949,503 @@ impl Resources {
             .await
     }
 }
+
+/// The query parameters of `Resources::buildings_list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ResourcesBuildingsListParams {
+    /// Maximum number of results to return.
+    pub max_results: Option<i64>,
+    /// Token to specify the next page in the list.
+    pub page_token: Option<String>,
+}
+
+impl ResourcesBuildingsListParams {
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Resources::buildings_insert_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ResourcesBuildingsInsertParams {
+    /// Source from which Building.coordinates are derived.
+    pub coordinates_source: Option<crate::types::CoordinatesSource>,
+}
+
+impl ResourcesBuildingsInsertParams {
+    pub fn coordinates_source(
+        mut self,
+        coordinates_source: crate::types::CoordinatesSource,
+    ) -> Self {
+        self.coordinates_source = Some(coordinates_source);
+        self
+    }
+}
+
+/// The query parameters of `Resources::buildings_update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ResourcesBuildingsUpdateParams {
+    /// Source from which Building.coordinates are derived.
+    pub coordinates_source: Option<crate::types::CoordinatesSource>,
+}
+
+impl ResourcesBuildingsUpdateParams {
+    pub fn coordinates_source(
+        mut self,
+        coordinates_source: crate::types::CoordinatesSource,
+    ) -> Self {
+        self.coordinates_source = Some(coordinates_source);
+        self
+    }
+}
+
+/// The query parameters of `Resources::buildings_patch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ResourcesBuildingsPatchParams {
+    /// Source from which Building.coordinates are derived.
+    pub coordinates_source: Option<crate::types::CoordinatesSource>,
+}
+
+impl ResourcesBuildingsPatchParams {
+    pub fn coordinates_source(
+        mut self,
+        coordinates_source: crate::types::CoordinatesSource,
+    ) -> Self {
+        self.coordinates_source = Some(coordinates_source);
+        self
+    }
+}
+
+/// The query parameters of `Resources::calendars_list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ResourcesCalendarsListParams {
+    /// Maximum number of results to return.
+    pub max_results: Option<i64>,
+    /// Field(s) to sort results by in either ascending or descending order. Supported fields include `resourceId`, `resourceName`, `capacity`, `buildingId`, and `floorName`. If no order is specified, defaults to ascending. Should be of the form "field [asc|desc], field [asc|desc], ...". For example `buildingId, capacity desc` would return results sorted first by `buildingId` in ascending order then by `capacity` in descending order.
+    pub order_by: Option<String>,
+    /// Token to specify the next page in the list.
+    pub page_token: Option<String>,
+    /// String query used to filter results. Should be of the form "field operator value" where field can be any of supported fields and operators can be any of supported operations. Operators include '=' for exact match, '!=' for mismatch and ':' for prefix match or HAS match where applicable. For prefix match, the value should always be followed by a *. Logical operators NOT and AND are supported (in this order of precedence). Supported fields include `generatedResourceName`, `name`, `buildingId`, `floor_name`, `capacity`, `featureInstances.feature.name`, `resourceEmail`, `resourceCategory`. For example `buildingId=US-NYC-9TH AND featureInstances.feature.name:Phone`.
+    pub query: Option<String>,
+}
+
+impl ResourcesCalendarsListParams {
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn order_by<T: ToString>(mut self, order_by: T) -> Self {
+        self.order_by = Some(order_by.to_string());
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Resources::features_list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ResourcesFeaturesListParams {
+    /// Maximum number of results to return.
+    pub max_results: Option<i64>,
+    /// Token to specify the next page in the list.
+    pub page_token: Option<String>,
+}
+
+impl ResourcesFeaturesListParams {
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The functions of [`Resources`], for code that takes them as a trait, so that it can be
+/// tested with the `MockResourcesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ResourcesApi: Send + Sync {
+    /// See [`Resources::buildings_list_with`].
+    async fn buildings_list_with(
+        &self,
+        customer: &str,
+        params: &ResourcesBuildingsListParams,
+    ) -> Result<Vec<crate::types::Building>>;
+
+    /// See [`Resources::buildings_list_all`].
+    async fn buildings_list_all(&self, customer: &str) -> Result<Vec<crate::types::Building>>;
+
+    /// See [`Resources::buildings_list_stream`].
+    fn buildings_list_stream(
+        &self,
+        customer: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Building>;
+
+    /// See [`Resources::buildings_insert_with`].
+    async fn buildings_insert_with(
+        &self,
+        customer: &str,
+        params: &ResourcesBuildingsInsertParams,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building>;
+
+    /// See [`Resources::buildings_get`].
+    async fn buildings_get(
+        &self,
+        customer: &str,
+        building_id: &str,
+    ) -> Result<crate::types::Building>;
+
+    /// See [`Resources::buildings_update_with`].
+    async fn buildings_update_with(
+        &self,
+        customer: &str,
+        building_id: &str,
+        params: &ResourcesBuildingsUpdateParams,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building>;
+
+    /// See [`Resources::buildings_delete`].
+    async fn buildings_delete(&self, customer: &str, building_id: &str) -> Result<()>;
+
+    /// See [`Resources::buildings_patch_with`].
+    async fn buildings_patch_with(
+        &self,
+        customer: &str,
+        building_id: &str,
+        params: &ResourcesBuildingsPatchParams,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building>;
+
+    /// See [`Resources::calendars_list_with`].
+    async fn calendars_list_with(
+        &self,
+        customer: &str,
+        params: &ResourcesCalendarsListParams,
+    ) -> Result<Vec<crate::types::CalendarResource>>;
+
+    /// See [`Resources::calendars_list_all_with`].
+    async fn calendars_list_all_with(
+        &self,
+        customer: &str,
+        params: &ResourcesCalendarsListParams,
+    ) -> Result<Vec<crate::types::CalendarResource>>;
+
+    /// See [`Resources::calendars_list_stream`].
+    fn calendars_list_stream(
+        &self,
+        customer: &str,
+        params: &ResourcesCalendarsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CalendarResource>;
+
+    /// See [`Resources::calendars_insert`].
+    async fn calendars_insert(
+        &self,
+        customer: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::types::CalendarResource>;
+
+    /// See [`Resources::calendars_get`].
+    async fn calendars_get(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> Result<crate::types::CalendarResource>;
+
+    /// See [`Resources::calendars_update`].
+    async fn calendars_update(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::types::CalendarResource>;
+
+    /// See [`Resources::calendars_delete`].
+    async fn calendars_delete(&self, customer: &str, calendar_resource_id: &str) -> Result<()>;
+
+    /// See [`Resources::calendars_patch`].
+    async fn calendars_patch(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::types::CalendarResource>;
+
+    /// See [`Resources::features_list_with`].
+    async fn features_list_with(
+        &self,
+        customer: &str,
+        params: &ResourcesFeaturesListParams,
+    ) -> Result<Vec<crate::types::Feature>>;
+
+    /// See [`Resources::features_list_all`].
+    async fn features_list_all(&self, customer: &str) -> Result<Vec<crate::types::Feature>>;
+
+    /// See [`Resources::features_list_stream`].
+    fn features_list_stream(
+        &self,
+        customer: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Feature>;
+
+    /// See [`Resources::features_insert`].
+    async fn features_insert(
+        &self,
+        customer: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::types::Feature>;
+
+    /// See [`Resources::features_get`].
+    async fn features_get(
+        &self,
+        customer: &str,
+        feature_key: &str,
+    ) -> Result<crate::types::Feature>;
+
+    /// See [`Resources::features_update`].
+    async fn features_update(
+        &self,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::types::Feature>;
+
+    /// See [`Resources::features_delete`].
+    async fn features_delete(&self, customer: &str, feature_key: &str) -> Result<()>;
+
+    /// See [`Resources::features_patch`].
+    async fn features_patch(
+        &self,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::types::Feature>;
+
+    /// See [`Resources::features_rename`].
+    async fn features_rename(
+        &self,
+        customer: &str,
+        old_name: &str,
+        body: &crate::types::FeatureRename,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ResourcesApi for Resources {
+    async fn buildings_list_with(
+        &self,
+        customer: &str,
+        params: &ResourcesBuildingsListParams,
+    ) -> Result<Vec<crate::types::Building>> {
+        Resources::buildings_list_with(self, customer, params).await
+    }
+
+    async fn buildings_list_all(&self, customer: &str) -> Result<Vec<crate::types::Building>> {
+        Resources::buildings_list_all(self, customer).await
+    }
+
+    fn buildings_list_stream(
+        &self,
+        customer: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Building> {
+        Resources::buildings_list_stream(self, customer, start, max_items)
+    }
+
+    async fn buildings_insert_with(
+        &self,
+        customer: &str,
+        params: &ResourcesBuildingsInsertParams,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building> {
+        Resources::buildings_insert_with(self, customer, params, body).await
+    }
+
+    async fn buildings_get(
+        &self,
+        customer: &str,
+        building_id: &str,
+    ) -> Result<crate::types::Building> {
+        Resources::buildings_get(self, customer, building_id).await
+    }
+
+    async fn buildings_update_with(
+        &self,
+        customer: &str,
+        building_id: &str,
+        params: &ResourcesBuildingsUpdateParams,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building> {
+        Resources::buildings_update_with(self, customer, building_id, params, body).await
+    }
+
+    async fn buildings_delete(&self, customer: &str, building_id: &str) -> Result<()> {
+        Resources::buildings_delete(self, customer, building_id).await
+    }
+
+    async fn buildings_patch_with(
+        &self,
+        customer: &str,
+        building_id: &str,
+        params: &ResourcesBuildingsPatchParams,
+        body: &crate::types::Building,
+    ) -> Result<crate::types::Building> {
+        Resources::buildings_patch_with(self, customer, building_id, params, body).await
+    }
+
+    async fn calendars_list_with(
+        &self,
+        customer: &str,
+        params: &ResourcesCalendarsListParams,
+    ) -> Result<Vec<crate::types::CalendarResource>> {
+        Resources::calendars_list_with(self, customer, params).await
+    }
+
+    async fn calendars_list_all_with(
+        &self,
+        customer: &str,
+        params: &ResourcesCalendarsListParams,
+    ) -> Result<Vec<crate::types::CalendarResource>> {
+        Resources::calendars_list_all_with(self, customer, params).await
+    }
+
+    fn calendars_list_stream(
+        &self,
+        customer: &str,
+        params: &ResourcesCalendarsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CalendarResource> {
+        Resources::calendars_list_stream(self, customer, params, start, max_items)
+    }
+
+    async fn calendars_insert(
+        &self,
+        customer: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::types::CalendarResource> {
+        Resources::calendars_insert(self, customer, body).await
+    }
+
+    async fn calendars_get(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+    ) -> Result<crate::types::CalendarResource> {
+        Resources::calendars_get(self, customer, calendar_resource_id).await
+    }
+
+    async fn calendars_update(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::types::CalendarResource> {
+        Resources::calendars_update(self, customer, calendar_resource_id, body).await
+    }
+
+    async fn calendars_delete(&self, customer: &str, calendar_resource_id: &str) -> Result<()> {
+        Resources::calendars_delete(self, customer, calendar_resource_id).await
+    }
+
+    async fn calendars_patch(
+        &self,
+        customer: &str,
+        calendar_resource_id: &str,
+        body: &crate::types::CalendarResource,
+    ) -> Result<crate::types::CalendarResource> {
+        Resources::calendars_patch(self, customer, calendar_resource_id, body).await
+    }
+
+    async fn features_list_with(
+        &self,
+        customer: &str,
+        params: &ResourcesFeaturesListParams,
+    ) -> Result<Vec<crate::types::Feature>> {
+        Resources::features_list_with(self, customer, params).await
+    }
+
+    async fn features_list_all(&self, customer: &str) -> Result<Vec<crate::types::Feature>> {
+        Resources::features_list_all(self, customer).await
+    }
+
+    fn features_list_stream(
+        &self,
+        customer: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Feature> {
+        Resources::features_list_stream(self, customer, start, max_items)
+    }
+
+    async fn features_insert(
+        &self,
+        customer: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::types::Feature> {
+        Resources::features_insert(self, customer, body).await
+    }
+
+    async fn features_get(
+        &self,
+        customer: &str,
+        feature_key: &str,
+    ) -> Result<crate::types::Feature> {
+        Resources::features_get(self, customer, feature_key).await
+    }
+
+    async fn features_update(
+        &self,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::types::Feature> {
+        Resources::features_update(self, customer, feature_key, body).await
+    }
+
+    async fn features_delete(&self, customer: &str, feature_key: &str) -> Result<()> {
+        Resources::features_delete(self, customer, feature_key).await
+    }
+
+    async fn features_patch(
+        &self,
+        customer: &str,
+        feature_key: &str,
+        body: &crate::types::Feature,
+    ) -> Result<crate::types::Feature> {
+        Resources::features_patch(self, customer, feature_key, body).await
+    }
+
+    async fn features_rename(
+        &self,
+        customer: &str,
+        old_name: &str,
+        body: &crate::types::FeatureRename,
+    ) -> Result<()> {
+        Resources::features_rename(self, customer, old_name, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct RoleAssignments {
     pub client: Client,
@@ -20,30 +18,24 @@ impl RoleAssignments {
      * **Parameters:**
      *
      * * `customer: &str` -- Immutable ID of the Google Workspace account.
-     * * `max_results: i64` -- Maximum number of results to return.
-     * * `page_token: &str` -- Token to specify the next page in the list.
-     * * `role_id: &str` -- Immutable ID of a role. If included in the request, returns only role assignments containing this role ID.
-     * * `user_key: &str` -- The user's primary email address, alias email address, or unique user ID. If included in the request, returns role assignments only for this user.
+     * * `params: &RoleAssignmentsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         customer: &str,
-        max_results: i64,
-        page_token: &str,
-        role_id: &str,
-        user_key: &str,
+        params: &RoleAssignmentsListParams,
     ) -> Result<Vec<crate::types::RoleAssignment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !role_id.is_empty() {
+        if let Some(role_id) = &params.role_id {
             query_args.push(("roleId".to_string(), role_id.to_string()));
         }
-        if !user_key.is_empty() {
+        if let Some(user_key) = &params.user_key {
             query_args.push(("userKey".to_string(), user_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -59,24 +51,48 @@ impl RoleAssignments {
         Ok(resp.items.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `RoleAssignmentsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+        role_id: &str,
+        user_key: &str,
+    ) -> Result<Vec<crate::types::RoleAssignment>> {
+        let params = RoleAssignmentsListParams {
+            max_results: Some(max_results).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            role_id: Some(role_id.to_string()).filter(|s| !s.is_empty()),
+            user_key: Some(user_key.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(customer, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Retrieves a paginated list of all roleAssignments.
+     *
+     * **Parameters:**
+     *
+     * * `params: &RoleAssignmentsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(
+    pub async fn list_all_with(
         &self,
         customer: &str,
-        role_id: &str,
-        user_key: &str,
+        params: &RoleAssignmentsListParams,
     ) -> Result<Vec<crate::types::RoleAssignment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !role_id.is_empty() {
+        if let Some(role_id) = &params.role_id {
             query_args.push(("roleId".to_string(), role_id.to_string()));
         }
-        if !user_key.is_empty() {
+        if let Some(user_key) = &params.user_key {
             query_args.push(("userKey".to_string(), user_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -93,6 +109,7 @@ impl RoleAssignments {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -118,6 +135,72 @@ impl RoleAssignments {
         Ok(items)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `RoleAssignmentsListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        customer: &str,
+        role_id: &str,
+        user_key: &str,
+    ) -> Result<Vec<crate::types::RoleAssignment>> {
+        let params = RoleAssignmentsListParams {
+            role_id: Some(role_id.to_string()).filter(|s| !s.is_empty()),
+            user_key: Some(user_key.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(customer, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieves a paginated list of all roleAssignments.
+     */
+    pub fn list_stream(
+        &self,
+        customer: &str,
+        params: &RoleAssignmentsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RoleAssignment> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(role_id) = &params.role_id {
+            query_args.push(("roleId".to_string(), role_id.to_string()));
+        }
+        if let Some(user_key) = &params.user_key {
+            query_args.push(("userKey".to_string(), user_key.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/admin/directory/v1/customer/{}/roleassignments?{}",
+            crate::progenitor_support::encode_path(customer),
+            query_
+        );
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::RoleAssignments = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.items,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/roleassignments` endpoint.
      *
@@ -186,3 +269,133 @@ impl RoleAssignments {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `RoleAssignments::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RoleAssignmentsListParams {
+    /// Maximum number of results to return.
+    pub max_results: Option<i64>,
+    /// Token to specify the next page in the list.
+    pub page_token: Option<String>,
+    /// Immutable ID of a role. If included in the request, returns only role assignments containing this role ID.
+    pub role_id: Option<String>,
+    /// The user's primary email address, alias email address, or unique user ID. If included in the request, returns role assignments only for this user.
+    pub user_key: Option<String>,
+}
+
+impl RoleAssignmentsListParams {
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn role_id<T: ToString>(mut self, role_id: T) -> Self {
+        self.role_id = Some(role_id.to_string());
+        self
+    }
+
+    pub fn user_key<T: ToString>(mut self, user_key: T) -> Self {
+        self.user_key = Some(user_key.to_string());
+        self
+    }
+}
+
+/// The functions of [`RoleAssignments`], for code that takes them as a trait, so that it can be
+/// tested with the `MockRoleAssignmentsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait RoleAssignmentsApi: Send + Sync {
+    /// See [`RoleAssignments::list_with`].
+    async fn list_with(
+        &self,
+        customer: &str,
+        params: &RoleAssignmentsListParams,
+    ) -> Result<Vec<crate::types::RoleAssignment>>;
+
+    /// See [`RoleAssignments::list_all_with`].
+    async fn list_all_with(
+        &self,
+        customer: &str,
+        params: &RoleAssignmentsListParams,
+    ) -> Result<Vec<crate::types::RoleAssignment>>;
+
+    /// See [`RoleAssignments::list_stream`].
+    fn list_stream(
+        &self,
+        customer: &str,
+        params: &RoleAssignmentsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RoleAssignment>;
+
+    /// See [`RoleAssignments::insert`].
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::RoleAssignment,
+    ) -> Result<crate::types::RoleAssignment>;
+
+    /// See [`RoleAssignments::get`].
+    async fn get(
+        &self,
+        customer: &str,
+        role_assignment_id: &str,
+    ) -> Result<crate::types::RoleAssignment>;
+
+    /// See [`RoleAssignments::delete`].
+    async fn delete(&self, customer: &str, role_assignment_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl RoleAssignmentsApi for RoleAssignments {
+    async fn list_with(
+        &self,
+        customer: &str,
+        params: &RoleAssignmentsListParams,
+    ) -> Result<Vec<crate::types::RoleAssignment>> {
+        RoleAssignments::list_with(self, customer, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        customer: &str,
+        params: &RoleAssignmentsListParams,
+    ) -> Result<Vec<crate::types::RoleAssignment>> {
+        RoleAssignments::list_all_with(self, customer, params).await
+    }
+
+    fn list_stream(
+        &self,
+        customer: &str,
+        params: &RoleAssignmentsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RoleAssignment> {
+        RoleAssignments::list_stream(self, customer, params, start, max_items)
+    }
+
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::RoleAssignment,
+    ) -> Result<crate::types::RoleAssignment> {
+        RoleAssignments::insert(self, customer, body).await
+    }
+
+    async fn get(
+        &self,
+        customer: &str,
+        role_assignment_id: &str,
+    ) -> Result<crate::types::RoleAssignment> {
+        RoleAssignments::get(self, customer, role_assignment_id).await
+    }
+
+    async fn delete(&self, customer: &str, role_assignment_id: &str) -> Result<()> {
+        RoleAssignments::delete(self, customer, role_assignment_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Roles {
     pub client: Client,
@@ -20,20 +18,18 @@ impl Roles {
      * **Parameters:**
      *
      * * `customer: &str` -- Immutable ID of the Google Workspace account.
-     * * `max_results: i64` -- Maximum number of results to return.
-     * * `page_token: &str` -- Token to specify the next page in the list.
+     * * `params: &RolesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         customer: &str,
-        max_results: i64,
-        page_token: &str,
+        params: &RolesListParams,
     ) -> Result<Vec<crate::types::Role>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -49,6 +45,23 @@ impl Roles {
         Ok(resp.items.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `RolesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        customer: &str,
+        max_results: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Role>> {
+        let params = RolesListParams {
+            max_results: Some(max_results).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(customer, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roles` endpoint.
      *
@@ -69,6 +82,7 @@ impl Roles {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -94,6 +108,43 @@ impl Roles {
         Ok(items)
     }
 
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/customer/{customer}/roles` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieves a paginated list of all the roles in a domain.
+     */
+    pub fn list_stream(
+        &self,
+        customer: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Role> {
+        let url = format!(
+            "/admin/directory/v1/customer/{}/roles",
+            crate::progenitor_support::encode_path(customer),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::Roles = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.items,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/customer/{customer}/roles` endpoint.
      *
@@ -212,3 +263,132 @@ impl Roles {
             .await
     }
 }
+
+/// The query parameters of `Roles::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RolesListParams {
+    /// Maximum number of results to return.
+    pub max_results: Option<i64>,
+    /// Token to specify the next page in the list.
+    pub page_token: Option<String>,
+}
+
+impl RolesListParams {
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The functions of [`Roles`], for code that takes them as a trait, so that it can be
+/// tested with the `MockRolesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait RolesApi: Send + Sync {
+    /// See [`Roles::list_with`].
+    async fn list_with(
+        &self,
+        customer: &str,
+        params: &RolesListParams,
+    ) -> Result<Vec<crate::types::Role>>;
+
+    /// See [`Roles::list_all`].
+    async fn list_all(&self, customer: &str) -> Result<Vec<crate::types::Role>>;
+
+    /// See [`Roles::list_stream`].
+    fn list_stream(
+        &self,
+        customer: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Role>;
+
+    /// See [`Roles::insert`].
+    async fn insert(&self, customer: &str, body: &crate::types::Role)
+        -> Result<crate::types::Role>;
+
+    /// See [`Roles::get`].
+    async fn get(&self, customer: &str, role_id: &str) -> Result<crate::types::Role>;
+
+    /// See [`Roles::update`].
+    async fn update(
+        &self,
+        customer: &str,
+        role_id: &str,
+        body: &crate::types::Role,
+    ) -> Result<crate::types::Role>;
+
+    /// See [`Roles::delete`].
+    async fn delete(&self, customer: &str, role_id: &str) -> Result<()>;
+
+    /// See [`Roles::patch`].
+    async fn patch(
+        &self,
+        customer: &str,
+        role_id: &str,
+        body: &crate::types::Role,
+    ) -> Result<crate::types::Role>;
+}
+
+#[async_trait::async_trait]
+impl RolesApi for Roles {
+    async fn list_with(
+        &self,
+        customer: &str,
+        params: &RolesListParams,
+    ) -> Result<Vec<crate::types::Role>> {
+        Roles::list_with(self, customer, params).await
+    }
+
+    async fn list_all(&self, customer: &str) -> Result<Vec<crate::types::Role>> {
+        Roles::list_all(self, customer).await
+    }
+
+    fn list_stream(
+        &self,
+        customer: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Role> {
+        Roles::list_stream(self, customer, start, max_items)
+    }
+
+    async fn insert(
+        &self,
+        customer: &str,
+        body: &crate::types::Role,
+    ) -> Result<crate::types::Role> {
+        Roles::insert(self, customer, body).await
+    }
+
+    async fn get(&self, customer: &str, role_id: &str) -> Result<crate::types::Role> {
+        Roles::get(self, customer, role_id).await
+    }
+
+    async fn update(
+        &self,
+        customer: &str,
+        role_id: &str,
+        body: &crate::types::Role,
+    ) -> Result<crate::types::Role> {
+        Roles::update(self, customer, role_id, body).await
+    }
+
+    async fn delete(&self, customer: &str, role_id: &str) -> Result<()> {
+        Roles::delete(self, customer, role_id).await
+    }
+
+    async fn patch(
+        &self,
+        customer: &str,
+        role_id: &str,
+        body: &crate::types::Role,
+    ) -> Result<crate::types::Role> {
+        Roles::patch(self, customer, role_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Schemas {
     pub client: Client,
@@ -148,3 +146,82 @@ impl Schemas {
             .await
     }
 }
+
+/// The functions of [`Schemas`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSchemasApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SchemasApi: Send + Sync {
+    /// See [`Schemas::list`].
+    async fn list(&self, customer_id: &str) -> Result<crate::types::Schemas>;
+
+    /// See [`Schemas::insert`].
+    async fn insert(
+        &self,
+        customer_id: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::types::Schema>;
+
+    /// See [`Schemas::get`].
+    async fn get(&self, customer_id: &str, schema_key: &str) -> Result<crate::types::Schema>;
+
+    /// See [`Schemas::update`].
+    async fn update(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::types::Schema>;
+
+    /// See [`Schemas::delete`].
+    async fn delete(&self, customer_id: &str, schema_key: &str) -> Result<()>;
+
+    /// See [`Schemas::patch`].
+    async fn patch(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::types::Schema>;
+}
+
+#[async_trait::async_trait]
+impl SchemasApi for Schemas {
+    async fn list(&self, customer_id: &str) -> Result<crate::types::Schemas> {
+        Schemas::list(self, customer_id).await
+    }
+
+    async fn insert(
+        &self,
+        customer_id: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::types::Schema> {
+        Schemas::insert(self, customer_id, body).await
+    }
+
+    async fn get(&self, customer_id: &str, schema_key: &str) -> Result<crate::types::Schema> {
+        Schemas::get(self, customer_id, schema_key).await
+    }
+
+    async fn update(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::types::Schema> {
+        Schemas::update(self, customer_id, schema_key, body).await
+    }
+
+    async fn delete(&self, customer_id: &str, schema_key: &str) -> Result<()> {
+        Schemas::delete(self, customer_id, schema_key).await
+    }
+
+    async fn patch(
+        &self,
+        customer_id: &str,
+        schema_key: &str,
+        body: &crate::types::Schema,
+    ) -> Result<crate::types::Schema> {
+        Schemas::patch(self, customer_id, schema_key, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Tokens {
     pub client: Client,
@@ -70,3 +68,33 @@ impl Tokens {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`Tokens`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTokensApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TokensApi: Send + Sync {
+    /// See [`Tokens::list`].
+    async fn list(&self, user_key: &str) -> Result<crate::types::Tokens>;
+
+    /// See [`Tokens::get`].
+    async fn get(&self, user_key: &str, client_id: &str) -> Result<crate::types::Token>;
+
+    /// See [`Tokens::delete`].
+    async fn delete(&self, user_key: &str, client_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl TokensApi for Tokens {
+    async fn list(&self, user_key: &str) -> Result<crate::types::Tokens> {
+        Tokens::list(self, user_key).await
+    }
+
+    async fn get(&self, user_key: &str, client_id: &str) -> Result<crate::types::Token> {
+        Tokens::get(self, user_key, client_id).await
+    }
+
+    async fn delete(&self, user_key: &str, client_id: &str) -> Result<()> {
+        Tokens::delete(self, user_key, client_id).await
+    }
+}
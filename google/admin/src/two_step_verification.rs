@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TwoStepVerification {
     pub client: Client,
@@ -30,3 +28,19 @@ impl TwoStepVerification {
         self.client.post(&url, None).await
     }
 }
+
+/// The functions of [`TwoStepVerification`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTwoStepVerificationApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TwoStepVerificationApi: Send + Sync {
+    /// See [`TwoStepVerification::turn_off`].
+    async fn turn_off(&self, user_key: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl TwoStepVerificationApi for TwoStepVerification {
+    async fn turn_off(&self, user_key: &str) -> Result<()> {
+        TwoStepVerification::turn_off(self, user_key).await
+    }
+}
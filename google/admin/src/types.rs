@@ -2,89 +2,199 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-/// JSON template for Alias object in Directory API.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Alias {
-    /**
-     * JSON template for Alias object in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub alias: String,
-    /**
-     * JSON template for Alias object in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * JSON template for Alias object in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * JSON template for Alias object in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * JSON template for Alias object in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "primaryEmail"
-    )]
-    pub primary_email: String,
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> std::option::Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
 }
 
-/// JSON response template to list aliases in Directory API.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Aliases {
-    /**
-     * JSON response template to list aliases in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub aliases: Vec<String>,
-    /**
-     * JSON response template to list aliases in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * JSON response template to list aliases in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+impl<T> std::convert::From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match std::option::Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        std::option::Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        std::option::Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
 }
 
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
+
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+
+#[cfg(feature = "asps")]
+mod asps;
+#[cfg(feature = "asps")]
+pub use asps::*;
+
+#[cfg(feature = "channels")]
+mod channels;
+#[cfg(feature = "channels")]
+pub use channels::*;
+
+#[cfg(feature = "chromeosdevices")]
+mod chromeosdevices;
+#[cfg(feature = "chromeosdevices")]
+pub use chromeosdevices::*;
+
+#[cfg(feature = "customer")]
+mod customer;
+#[cfg(feature = "customer")]
+pub use customer::*;
+
+#[cfg(feature = "customers")]
+mod customers;
+#[cfg(feature = "customers")]
+pub use customers::*;
+
+#[cfg(feature = "domain_aliases")]
+mod domain_aliases;
+#[cfg(feature = "domain_aliases")]
+pub use domain_aliases::*;
+
+#[cfg(feature = "domains")]
+mod domains;
+#[cfg(feature = "domains")]
+pub use domains::*;
+
+#[cfg(feature = "groups")]
+mod groups;
+#[cfg(feature = "groups")]
+pub use groups::*;
+
+#[cfg(feature = "members")]
+mod members;
+#[cfg(feature = "members")]
+pub use members::*;
+
+#[cfg(feature = "mobiledevices")]
+mod mobiledevices;
+#[cfg(feature = "mobiledevices")]
+pub use mobiledevices::*;
+
+#[cfg(feature = "orgunits")]
+mod orgunits;
+#[cfg(feature = "orgunits")]
+pub use orgunits::*;
+
+#[cfg(feature = "privileges")]
+mod privileges;
+#[cfg(feature = "privileges")]
+pub use privileges::*;
+
+#[cfg(feature = "resources")]
+mod resources;
+#[cfg(feature = "resources")]
+pub use resources::*;
+
+#[cfg(feature = "role_assignments")]
+mod role_assignments;
+#[cfg(feature = "role_assignments")]
+pub use role_assignments::*;
+
+#[cfg(feature = "roles")]
+mod roles;
+#[cfg(feature = "roles")]
+pub use roles::*;
+
+#[cfg(feature = "schemas")]
+mod schemas;
+#[cfg(feature = "schemas")]
+pub use schemas::*;
+
+#[cfg(feature = "tokens")]
+mod tokens;
+#[cfg(feature = "tokens")]
+pub use tokens::*;
+
+#[cfg(feature = "users")]
+mod users;
+#[cfg(feature = "users")]
+pub use users::*;
+
+#[cfg(feature = "verification_codes")]
+mod verification_codes;
+#[cfg(feature = "verification_codes")]
+pub use verification_codes::*;
 /// An application-specific password (ASP) is used with applications that do not accept a verification code when logging into the application on certain devices. The ASP access code is used instead of the login and password you commonly use when accessing an application through a browser. For more information about ASPs and how to create one, see the [help center](https://support.google.com/a/answer/2537800#asp).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Asp {
@@ -157,35 +267,11 @@ pub struct Asp {
     pub user_key: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Asps {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * A list of ASP resources.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub items: Vec<Asp>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-}
-
 /**
  * Message severity
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Severity {
     #[serde(rename = "SEVERITY_ERROR")]
     SeverityError,
@@ -195,34 +281,59 @@ pub enum Severity {
     SeverityUnspecified,
     #[serde(rename = "SEVERITY_WARNING")]
     SeverityWarning,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Severity {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Severity {
+    fn as_ref(&self) -> &str {
+        match self {
             Severity::SeverityError => "SEVERITY_ERROR",
             Severity::SeverityInfo => "SEVERITY_INFO",
             Severity::SeverityUnspecified => "SEVERITY_UNSPECIFIED",
             Severity::SeverityWarning => "SEVERITY_WARNING",
-            Severity::Noop => "",
-            Severity::FallthroughString => "*",
+            Severity::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Severity {
-    fn default() -> Severity {
-        Severity::Noop
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Severity {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SEVERITY_ERROR" => Severity::SeverityError,
+            "SEVERITY_INFO" => Severity::SeverityInfo,
+            "SEVERITY_UNSPECIFIED" => Severity::SeverityUnspecified,
+            "SEVERITY_WARNING" => Severity::SeverityWarning,
+            _ => Severity::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Severity {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Severity {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Severity::Noop)
+
+impl std::convert::From<Severity> for String {
+    fn from(v: Severity) -> Self {
+        match v {
+            Severity::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -234,21 +345,15 @@ pub struct AuxiliaryMessage {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "auxiliaryMessage"
     )]
-    pub auxiliary_message: String,
+    pub auxiliary_message: Option<String>,
     /**
      * Auxiliary message about issues with printers or settings. Example: {message_type:AUXILIARY_MESSAGE_WARNING, field_mask:make_and_model, message:"Given printer is invalid or no longer supported."}
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fieldMask"
-    )]
-    pub field_mask: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "fieldMask")]
+    pub field_mask: Option<String>,
     /**
      * Auxiliary message about issues with printers or settings. Example: {message_type:AUXILIARY_MESSAGE_WARNING, field_mask:make_and_model, message:"Given printer is invalid or no longer supported."}
      */
@@ -256,83 +361,6 @@ pub struct AuxiliaryMessage {
     pub severity: Option<Severity>,
 }
 
-/// Request for adding new printers in batch.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchCreatePrintersRequest {
-    /**
-     * Request for adding new printers in batch.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub requests: Vec<CreatePrinterRequest>,
-}
-
-/// Response for adding new printers in batch.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchCreatePrintersResponse {
-    /**
-     * Response for adding new printers in batch.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub failures: Vec<FailureInfo>,
-    /**
-     * Response for adding new printers in batch.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub printers: Vec<Printer>,
-}
-
-/// Request for deleting existing printers in batch.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchDeletePrintersRequest {
-    /**
-     * Request for deleting existing printers in batch.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "printerIds"
-    )]
-    pub printer_ids: Vec<String>,
-}
-
-/// Response for deleting existing printers in batch.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchDeletePrintersResponse {
-    /**
-     * Response for deleting existing printers in batch.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "failedPrinters"
-    )]
-    pub failed_printers: Vec<FailureInfo>,
-    /**
-     * Response for deleting existing printers in batch.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "printerIds"
-    )]
-    pub printer_ids: Vec<String>,
-}
-
 /// Public API: Resources.buildings
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Building {
@@ -346,21 +374,19 @@ pub struct Building {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "buildingId"
     )]
-    pub building_id: String,
+    pub building_id: Option<String>,
     /**
      * Public API: Resources.buildings
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "buildingName"
     )]
-    pub building_name: String,
+    pub building_name: Option<String>,
     /**
      * Public API: Resources.buildings
      */
@@ -369,21 +395,13 @@ pub struct Building {
     /**
      * Public API: Resources.buildings
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /**
      * Public API: Resources.buildings
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etags: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etags: Option<String>,
     /**
      * Public API: Resources.buildings
      */
@@ -397,12 +415,8 @@ pub struct Building {
     /**
      * Public API: Resources.buildings
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
 }
 
 /// Public API: Resources.buildings
@@ -423,59 +437,47 @@ pub struct BuildingAddress {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "administrativeArea"
     )]
-    pub administrative_area: String,
+    pub administrative_area: Option<String>,
     /**
      * Public API: Resources.buildings
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "languageCode"
     )]
-    pub language_code: String,
+    pub language_code: Option<String>,
     /**
      * Public API: Resources.buildings
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub locality: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locality: Option<String>,
     /**
      * Public API: Resources.buildings
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "postalCode"
     )]
-    pub postal_code: String,
+    pub postal_code: Option<String>,
     /**
      * Public API: Resources.buildings
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "regionCode"
     )]
-    pub region_code: String,
+    pub region_code: Option<String>,
     /**
      * Public API: Resources.buildings
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub sublocality: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sublocality: Option<String>,
 }
 
 /// Public API: Resources.buildings
@@ -501,79 +503,32 @@ pub struct BuildingCoordinates {
     pub longitude: f64,
 }
 
-/// Public API: Resources.buildings
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Buildings {
+/// Public API: Resources.calendars
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CalendarResource {
     /**
-     * Public API: Resources.buildings
+     * Public API: Resources.calendars
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "buildingId"
     )]
-    pub buildings: Vec<Building>,
+    pub building_id: Option<String>,
     /**
-     * Public API: Resources.buildings
+     * Public API: Resources.calendars
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub etag: String,
+    pub capacity: i64,
     /**
-     * Public API: Resources.buildings
+     * Public API: Resources.calendars
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * Public API: Resources.buildings
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-}
-
-/// Public API: Resources.calendars
-#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CalendarResource {
-    /**
-     * Public API: Resources.calendars
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "buildingId"
-    )]
-    pub building_id: String,
-    /**
-     * Public API: Resources.calendars
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub capacity: i64,
-    /**
-     * Public API: Resources.calendars
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etags: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etags: Option<String>,
     /**
      * Public API: Resources.calendars
      */
@@ -586,251 +541,94 @@ pub struct CalendarResource {
     /**
      * Public API: Resources.calendars
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "floorName"
-    )]
-    pub floor_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "floorName")]
+    pub floor_name: Option<String>,
     /**
      * Public API: Resources.calendars
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "floorSection"
     )]
-    pub floor_section: String,
+    pub floor_section: Option<String>,
     /**
      * Public API: Resources.calendars
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "generatedResourceName"
     )]
-    pub generated_resource_name: String,
+    pub generated_resource_name: Option<String>,
     /**
      * Public API: Resources.calendars
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * Public API: Resources.calendars
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "resourceCategory"
     )]
-    pub resource_category: String,
+    pub resource_category: Option<String>,
     /**
      * Public API: Resources.calendars
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "resourceDescription"
     )]
-    pub resource_description: String,
+    pub resource_description: Option<String>,
     /**
      * Public API: Resources.calendars
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "resourceEmail"
     )]
-    pub resource_email: String,
+    pub resource_email: Option<String>,
     /**
      * Public API: Resources.calendars
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "resourceId"
     )]
-    pub resource_id: String,
+    pub resource_id: Option<String>,
     /**
      * Public API: Resources.calendars
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "resourceName"
     )]
-    pub resource_name: String,
+    pub resource_name: Option<String>,
     /**
      * Public API: Resources.calendars
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "resourceType"
     )]
-    pub resource_type: String,
+    pub resource_type: Option<String>,
     /**
      * Public API: Resources.calendars
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "userVisibleDescription"
     )]
-    pub user_visible_description: String,
-}
-
-/// Public API: Resources.calendars
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CalendarResources {
-    /**
-     * Public API: Resources.calendars
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * Public API: Resources.calendars
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub items: Vec<CalendarResource>,
-    /**
-     * Public API: Resources.calendars
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * Public API: Resources.calendars
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-}
-
-/// An notification channel used to watch for resource changes.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Channel {
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub expiration: i64,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub params: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub payload: bool,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceId"
-    )]
-    pub resource_id: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceUri"
-    )]
-    pub resource_uri: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub token: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    pub user_visible_description: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -858,12 +656,8 @@ pub struct ActiveTimeRanges {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CpuTemperatureInfo {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
     /**
      * The unique ID of the ASP.
      */
@@ -920,24 +714,14 @@ pub struct DeviceFiles {
     pub create_time: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "downloadUrl"
     )]
-    pub download_url: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    pub download_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -962,13 +746,8 @@ pub struct VolumeInfo {
         rename = "storageTotal"
     )]
     pub storage_total: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "volumeId"
-    )]
-    pub volume_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "volumeId")]
+    pub volume_id: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -991,23 +770,17 @@ pub struct LastKnownNetwork {
     /**
      * Information for an ip address.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "ipAddress"
-    )]
-    pub ip_address: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "ipAddress")]
+    pub ip_address: Option<String>,
     /**
      * Information for an ip address.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "wanIpAddress"
     )]
-    pub wan_ip_address: String,
+    pub wan_ip_address: Option<String>,
 }
 
 /// List of recent device users, in descending order, by last login time.
@@ -1016,26 +789,17 @@ pub struct RecentUsers {
     /**
      * List of recent device users, in descending order, by last login time.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
     /**
      * List of recent device users, in descending order, by last login time.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SystemRamFreeReports {
+pub struct ScreenshotFiles {
     /**
      * Date and time the report was received.
      */
@@ -1043,9 +807,33 @@ pub struct SystemRamFreeReports {
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "reportTime"
+        rename = "createTime"
     )]
-    pub report_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "downloadUrl"
+    )]
+    pub download_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SystemRamFreeReports {
+    /**
+     * Date and time the report was received.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "reportTime"
+    )]
+    pub report_time: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -1061,61 +849,41 @@ pub struct TpmVersionInfo {
     /**
      * Trusted Platform Module (TPM) (Read-only)
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub family: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub family: Option<String>,
     /**
      * Trusted Platform Module (TPM) (Read-only)
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "firmwareVersion"
     )]
-    pub firmware_version: String,
+    pub firmware_version: Option<String>,
     /**
      * Trusted Platform Module (TPM) (Read-only)
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub manufacturer: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manufacturer: Option<String>,
     /**
      * Trusted Platform Module (TPM) (Read-only)
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "specLevel"
-    )]
-    pub spec_level: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "specLevel")]
+    pub spec_level: Option<String>,
     /**
      * Trusted Platform Module (TPM) (Read-only)
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tpmModel"
-    )]
-    pub tpm_model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "tpmModel")]
+    pub tpm_model: Option<String>,
     /**
      * Trusted Platform Module (TPM) (Read-only)
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "vendorSpecific"
     )]
-    pub vendor_specific: String,
+    pub vendor_specific: Option<String>,
 }
 
 /// Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
@@ -1136,31 +904,28 @@ pub struct ChromeOsDevice {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "annotatedAssetId"
     )]
-    pub annotated_asset_id: String,
+    pub annotated_asset_id: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "annotatedLocation"
     )]
-    pub annotated_location: String,
+    pub annotated_location: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "annotatedUser"
     )]
-    pub annotated_user: String,
+    pub annotated_user: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
@@ -1174,13 +939,8 @@ pub struct ChromeOsDevice {
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "bootMode"
-    )]
-    pub boot_mode: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "bootMode")]
+    pub boot_mode: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
@@ -1204,13 +964,8 @@ pub struct ChromeOsDevice {
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "deviceId"
-    )]
-    pub device_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "deviceId")]
+    pub device_id: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
@@ -1226,59 +981,47 @@ pub struct ChromeOsDevice {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "dockMacAddress"
     )]
-    pub dock_mac_address: String,
+    pub dock_mac_address: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "ethernetMacAddress"
     )]
-    pub ethernet_mac_address: String,
+    pub ethernet_mac_address: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "ethernetMacAddress0"
     )]
-    pub ethernet_mac_address_0: String,
+    pub ethernet_mac_address_0: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "firmwareVersion"
     )]
-    pub firmware_version: String,
+    pub firmware_version: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
@@ -1314,11 +1057,10 @@ pub struct ChromeOsDevice {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "macAddress"
     )]
-    pub mac_address: String,
+    pub mac_address: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
@@ -1332,70 +1074,50 @@ pub struct ChromeOsDevice {
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub meid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meid: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub model: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub notes: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "orderNumber"
     )]
-    pub order_number: String,
+    pub order_number: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "orgUnitPath"
     )]
-    pub org_unit_path: String,
+    pub org_unit_path: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "osVersion"
-    )]
-    pub os_version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "osVersion")]
+    pub os_version: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "platformVersion"
     )]
-    pub platform_version: String,
+    pub platform_version: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
@@ -1415,26 +1137,21 @@ pub struct ChromeOsDevice {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
         rename = "screenshotFiles"
     )]
-    pub screenshot_files: Vec<DeviceFiles>,
+    pub screenshot_files: Vec<ScreenshotFiles>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "serialNumber"
     )]
-    pub serial_number: String,
+    pub serial_number: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
     /**
      * Google Chrome devices run on the [Chrome OS](https://support.google.com/chromeos). For more information about common API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-chrome-devices).
      */
@@ -1485,78 +1202,14 @@ pub struct ChromeOsDevice {
     pub will_auto_renew: bool,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChromeOsDeviceAction {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub action: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "deprovisionReason"
-    )]
-    pub deprovision_reason: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChromeOsDevices {
-    /**
-     * List of Chrome OS Device objects.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub chromeosdevices: Vec<ChromeOsDevice>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChromeOsMoveDevicesOu {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "deviceIds"
-    )]
-    pub device_ids: Vec<String>,
-}
-
 /// Request for adding a new printer.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreatePrinterRequest {
     /**
      * Request for adding a new printer.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub parent: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
     /**
      * Request for adding a new printer.
      */
@@ -1564,164 +1217,35 @@ pub struct CreatePrinterRequest {
     pub printer: Option<Printer>,
 }
 
+/**
+ * Indicates the command state.
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Customer {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "alternateEmail"
-    )]
-    pub alternate_email: String,
+#[serde(from = "String", into = "String")]
+pub enum State {
+    #[serde(rename = "ACKED_BY_CLIENT")]
+    AckedByClient,
+    #[serde(rename = "CANCELLED")]
+    Cancelled,
+    #[serde(rename = "EXECUTED_BY_CLIENT")]
+    ExecutedByClient,
+    #[serde(rename = "EXPIRED")]
+    Expired,
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "SENT_TO_CLIENT")]
+    SentToClient,
+    #[serde(rename = "STATE_UNSPECIFIED")]
+    StateUnspecified,
     /**
-     * Date and time the report was received.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "customerCreationTime"
-    )]
-    pub customer_creation_time: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "customerDomain"
-    )]
-    pub customer_domain: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub language: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "phoneNumber"
-    )]
-    pub phone_number: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "postalAddress"
-    )]
-    pub postal_address: Option<CustomerPostalAddress>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CustomerPostalAddress {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "addressLine1"
-    )]
-    pub address_line_1: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "addressLine2"
-    )]
-    pub address_line_2: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "addressLine3"
-    )]
-    pub address_line_3: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "contactName"
-    )]
-    pub contact_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "countryCode"
-    )]
-    pub country_code: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub locality: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "organizationName"
-    )]
-    pub organization_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "postalCode"
-    )]
-    pub postal_code: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub region: String,
-}
-
-/**
- * Indicates the command state.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum State {
-    #[serde(rename = "ACKED_BY_CLIENT")]
-    AckedByClient,
-    #[serde(rename = "CANCELLED")]
-    Cancelled,
-    #[serde(rename = "EXECUTED_BY_CLIENT")]
-    ExecutedByClient,
-    #[serde(rename = "EXPIRED")]
-    Expired,
-    #[serde(rename = "PENDING")]
-    Pending,
-    #[serde(rename = "SENT_TO_CLIENT")]
-    SentToClient,
-    #[serde(rename = "STATE_UNSPECIFIED")]
-    StateUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    Unknown(String),
 }
 
-impl std::fmt::Display for State {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for State {
+    fn as_ref(&self) -> &str {
+        match self {
             State::AckedByClient => "ACKED_BY_CLIENT",
             State::Cancelled => "CANCELLED",
             State::ExecutedByClient => "EXECUTED_BY_CLIENT",
@@ -1729,21 +1253,49 @@ impl std::fmt::Display for State {
             State::Pending => "PENDING",
             State::SentToClient => "SENT_TO_CLIENT",
             State::StateUnspecified => "STATE_UNSPECIFIED",
-            State::Noop => "",
-            State::FallthroughString => "*",
+            State::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for State {
-    fn default() -> State {
-        State::Noop
+impl std::fmt::Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for State {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACKED_BY_CLIENT" => State::AckedByClient,
+            "CANCELLED" => State::Cancelled,
+            "EXECUTED_BY_CLIENT" => State::ExecutedByClient,
+            "EXPIRED" => State::Expired,
+            "PENDING" => State::Pending,
+            "SENT_TO_CLIENT" => State::SentToClient,
+            "STATE_UNSPECIFIED" => State::StateUnspecified,
+            _ => State::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for State {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl State {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, State::Noop)
+
+impl std::convert::From<State> for String {
+    fn from(v: State) -> Self {
+        match v {
+            State::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1751,6 +1303,7 @@ impl State {
  * The type of the command.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "COMMAND_TYPE_UNSPECIFIED")]
     CommandTypeUnspecified,
@@ -1764,106 +1317,71 @@ pub enum Type {
     TakeAScreenshot,
     #[serde(rename = "WIPE_USERS")]
     WipeUsers,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Type {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        match self {
             Type::CommandTypeUnspecified => "COMMAND_TYPE_UNSPECIFIED",
             Type::Reboot => "REBOOT",
             Type::RemotePowerwash => "REMOTE_POWERWASH",
             Type::SetVolume => "SET_VOLUME",
             Type::TakeAScreenshot => "TAKE_A_SCREENSHOT",
             Type::WipeUsers => "WIPE_USERS",
-            Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Type {
-    fn default() -> Type {
-        Type::Noop
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Type {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Type::Noop)
+
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COMMAND_TYPE_UNSPECIFIED" => Type::CommandTypeUnspecified,
+            "REBOOT" => Type::Reboot,
+            "REMOTE_POWERWASH" => Type::RemotePowerwash,
+            "SET_VOLUME" => Type::SetVolume,
+            "TAKE_A_SCREENSHOT" => Type::TakeAScreenshot,
+            "WIPE_USERS" => Type::WipeUsers,
+            _ => Type::Unknown(s.to_string()),
+        })
     }
 }
 
-/// Information regarding a command that was issued to a device.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DirectoryChromeosdevicesCommand {
-    /**
-     * Information regarding a command that was issued to a device.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "commandExpireTime"
-    )]
-    pub command_expire_time: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Information regarding a command that was issued to a device.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "commandId"
-    )]
-    pub command_id: i64,
-    /**
-     * Information regarding a command that was issued to a device.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "commandResult"
-    )]
-    pub command_result: Option<DirectoryChromeosdevicesCommandResult>,
-    /**
-     * Information regarding a command that was issued to a device.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "issueTime"
-    )]
-    pub issue_time: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Information regarding a command that was issued to a device.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub payload: String,
-    /**
-     * Information regarding a command that was issued to a device.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub state: Option<State>,
-    /**
-     * Information regarding a command that was issued to a device.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<Type>,
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(v: Type) -> Self {
+        match v {
+            Type::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 /**
  * The result of the command.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Result {
     #[serde(rename = "COMMAND_RESULT_TYPE_UNSPECIFIED")]
     CommandResultTypeUnspecified,
@@ -1873,103 +1391,138 @@ pub enum Result {
     Ignored,
     #[serde(rename = "SUCCESS")]
     Success,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Result {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Result {
+    fn as_ref(&self) -> &str {
+        match self {
             Result::CommandResultTypeUnspecified => "COMMAND_RESULT_TYPE_UNSPECIFIED",
             Result::Failure => "FAILURE",
             Result::Ignored => "IGNORED",
             Result::Success => "SUCCESS",
-            Result::Noop => "",
-            Result::FallthroughString => "*",
+            Result::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Result {
-    fn default() -> Result {
-        Result::Noop
+impl std::fmt::Display for Result {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Result {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Result::Noop)
+
+impl std::str::FromStr for Result {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COMMAND_RESULT_TYPE_UNSPECIFIED" => Result::CommandResultTypeUnspecified,
+            "FAILURE" => Result::Failure,
+            "IGNORED" => Result::Ignored,
+            "SUCCESS" => Result::Success,
+            _ => Result::Unknown(s.to_string()),
+        })
     }
 }
 
-/// The result of executing a command.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DirectoryChromeosdevicesCommandResult {
-    /**
-     * The result of executing a command.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "errorMessage"
-    )]
-    pub error_message: String,
-    /**
-     * The result of executing a command.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "executeTime"
-    )]
-    pub execute_time: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The result of executing a command.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub result: Option<Result>,
+impl std::convert::From<String> for Result {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-/// A request for issuing a command.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DirectoryChromeosdevicesIssueCommandRequest {
-    /**
-     * A request for issuing a command.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "commandType"
-    )]
-    pub command_type: Option<Type>,
-    /**
-     * A request for issuing a command.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub payload: String,
+impl std::convert::From<Result> for String {
+    fn from(v: Result) -> Self {
+        match v {
+            Result::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
-/// A response for issuing a command.
+/**
+ * The type of command.
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DirectoryChromeosdevicesIssueCommandResponse {
+#[serde(from = "String", into = "String")]
+pub enum CommandType {
+    #[serde(rename = "COMMAND_TYPE_UNSPECIFIED")]
+    CommandTypeUnspecified,
+    #[serde(rename = "REBOOT")]
+    Reboot,
+    #[serde(rename = "REMOTE_POWERWASH")]
+    RemotePowerwash,
+    #[serde(rename = "SET_VOLUME")]
+    SetVolume,
+    #[serde(rename = "TAKE_A_SCREENSHOT")]
+    TakeAScreenshot,
+    #[serde(rename = "WIPE_USERS")]
+    WipeUsers,
     /**
-     * A response for issuing a command.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "commandId"
-    )]
-    pub command_id: i64,
+    Unknown(String),
+}
+
+impl AsRef<str> for CommandType {
+    fn as_ref(&self) -> &str {
+        match self {
+            CommandType::CommandTypeUnspecified => "COMMAND_TYPE_UNSPECIFIED",
+            CommandType::Reboot => "REBOOT",
+            CommandType::RemotePowerwash => "REMOTE_POWERWASH",
+            CommandType::SetVolume => "SET_VOLUME",
+            CommandType::TakeAScreenshot => "TAKE_A_SCREENSHOT",
+            CommandType::WipeUsers => "WIPE_USERS",
+            CommandType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for CommandType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CommandType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COMMAND_TYPE_UNSPECIFIED" => CommandType::CommandTypeUnspecified,
+            "REBOOT" => CommandType::Reboot,
+            "REMOTE_POWERWASH" => CommandType::RemotePowerwash,
+            "SET_VOLUME" => CommandType::SetVolume,
+            "TAKE_A_SCREENSHOT" => CommandType::TakeAScreenshot,
+            "WIPE_USERS" => CommandType::WipeUsers,
+            _ => CommandType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CommandType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<CommandType> for String {
+    fn from(v: CommandType) -> Self {
+        match v {
+            CommandType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1986,30 +1539,20 @@ pub struct DomainAlias {
     pub creation_time: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "domainAliasName"
     )]
-    pub domain_alias_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    pub domain_alias_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "parentDomainName"
     )]
-    pub parent_domain_name: String,
+    pub parent_domain_name: Option<String>,
     /**
      * A Boolean value to indicate whether payload is wanted. Optional.
      */
@@ -2021,35 +1564,9 @@ pub struct DomainAlias {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DomainAliases {
+pub struct Domains {
     /**
-     * List of domain alias objects.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "domainAliases"
-    )]
-    pub domain_aliases: Vec<DomainAlias>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Domains {
-    /**
-     * The unique ID of the ASP.
+     * The unique ID of the ASP.
      */
     #[serde(
         default,
@@ -2070,17 +1587,12 @@ pub struct Domains {
     pub domain_aliases: Vec<DomainAlias>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "domainName"
     )]
-    pub domain_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
+    pub domain_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
     /**
      * A Boolean value to indicate whether payload is wanted. Optional.
      */
@@ -2090,12 +1602,8 @@ pub struct Domains {
         rename = "isPrimary"
     )]
     pub is_primary: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * A Boolean value to indicate whether payload is wanted. Optional.
      */
@@ -2106,39 +1614,11 @@ pub struct Domains {
     pub verified: bool,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Domains2 {
-    /**
-     * List of domain objects.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub domains: Vec<Domains>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-}
-
-/// A generic empty message that you can re-use to avoid defining duplicated empty messages in your APIs. A typical example is to use it as the request or the response type of an API method. For instance: service Foo { rpc Bar(google.protobuf.Empty) returns (google.protobuf.Empty); } The JSON representation for `Empty` is empty JSON object `{}`.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Empty {}
-
 /**
  * Canonical code for why the update failed to apply.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ErrorCode {
     #[serde(rename = "ABORTED")]
     Aborted,
@@ -2173,16 +1653,16 @@ pub enum ErrorCode {
     #[serde(rename = "UNIMPLEMENTED")]
     Unimplemented,
     #[serde(rename = "UNKNOWN")]
-    Unknown,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    UnknownValue,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ErrorCode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        match self {
             ErrorCode::Aborted => "ABORTED",
             ErrorCode::AlreadyExists => "ALREADY_EXISTS",
             ErrorCode::Cancelled => "CANCELLED",
@@ -2199,22 +1679,60 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::Unauthenticated => "UNAUTHENTICATED",
             ErrorCode::Unavailable => "UNAVAILABLE",
             ErrorCode::Unimplemented => "UNIMPLEMENTED",
-            ErrorCode::Unknown => "UNKNOWN",
-            ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::UnknownValue => "UNKNOWN",
+            ErrorCode::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ErrorCode {
-    fn default() -> ErrorCode {
-        ErrorCode::Noop
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ErrorCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ABORTED" => ErrorCode::Aborted,
+            "ALREADY_EXISTS" => ErrorCode::AlreadyExists,
+            "CANCELLED" => ErrorCode::Cancelled,
+            "DATA_LOSS" => ErrorCode::DataLoss,
+            "DEADLINE_EXCEEDED" => ErrorCode::DeadlineExceeded,
+            "FAILED_PRECONDITION" => ErrorCode::FailedPrecondition,
+            "INTERNAL" => ErrorCode::Internal,
+            "INVALID_ARGUMENT" => ErrorCode::InvalidArgument,
+            "NOT_FOUND" => ErrorCode::NotFound,
+            "OK" => ErrorCode::Ok,
+            "OUT_OF_RANGE" => ErrorCode::OutOfRange,
+            "PERMISSION_DENIED" => ErrorCode::PermissionDenied,
+            "RESOURCE_EXHAUSTED" => ErrorCode::ResourceExhausted,
+            "UNAUTHENTICATED" => ErrorCode::Unauthenticated,
+            "UNAVAILABLE" => ErrorCode::Unavailable,
+            "UNIMPLEMENTED" => ErrorCode::Unimplemented,
+            "UNKNOWN" => ErrorCode::UnknownValue,
+            _ => ErrorCode::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ErrorCode {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ErrorCode {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ErrorCode::Noop)
+
+impl std::convert::From<ErrorCode> for String {
+    fn from(v: ErrorCode) -> Self {
+        match v {
+            ErrorCode::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2259,30 +1777,18 @@ pub struct Feature {
     /**
      * JSON template for Feature object in Directory API.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etags: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etags: Option<String>,
     /**
      * JSON template for Feature object in Directory API.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * JSON template for Feature object in Directory API.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 /// JSON template for a feature instance.
@@ -2295,59 +1801,6 @@ pub struct FeatureInstance {
     pub feature: Option<Feature>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FeatureRename {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "newName"
-    )]
-    pub new_name: String,
-}
-
-/// Public API: Resources.features
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Features {
-    /**
-     * Public API: Resources.features
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * Public API: Resources.features
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub features: Vec<Feature>,
-    /**
-     * Public API: Resources.features
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * Public API: Resources.features
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-}
-
 /// Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
 #[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Group {
@@ -2372,12 +1825,8 @@ pub struct Group {
     /**
      * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /**
      * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
      */
@@ -2391,48 +1840,28 @@ pub struct Group {
     /**
      * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
     /**
      * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
     /**
      * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /**
      * Google Groups provide your users the ability to send messages to groups of people using the group's email address. For more information about common tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-groups).
      */
@@ -2445,266 +1874,106 @@ pub struct Group {
     pub non_editable_aliases: Vec<String>,
 }
 
+/// A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Member {
+    /**
+     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivery_settings: Option<String>,
+    /**
+     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /**
+     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    /**
+     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /**
+     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /**
+     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    /**
+     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /**
+     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Groups {
+pub struct Applications {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "displayName"
     )]
-    pub etag: String,
-    /**
-     * List of group objects.
-     */
+    pub display_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "packageName"
+    )]
+    pub package_name: String,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub groups: Vec<Group>,
+    pub permission: Vec<String>,
+    /**
+     * The unique ID of the ASP.
+     */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "versionCode"
     )]
-    pub kind: String,
+    pub version_code: i64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        rename = "versionName"
     )]
-    pub next_page_token: String,
+    pub version_name: String,
 }
 
-/// Response for listing allowed printer models.
+/// Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ListPrinterModelsResponse {
+pub struct MobileDevice {
     /**
-     * Response for listing allowed printer models.
+     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "adbStatus"
     )]
-    pub next_page_token: String,
+    pub adb_status: bool,
     /**
-     * Response for listing allowed printer models.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "printerModels"
-    )]
-    pub printer_models: Vec<PrinterModel>,
-}
-
-/// Response for listing printers.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ListPrintersResponse {
-    /**
-     * Response for listing printers.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-    /**
-     * Response for listing printers.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub printers: Vec<Printer>,
-}
-
-/// A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Member {
-    /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub delivery_settings: String,
-    /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub role: String,
-    /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub status: String,
-    /**
-     * A Google Groups member can be a user or another group. This member can be inside or outside of your account's domains. For more information about common group member tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-group-members).
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Members {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * List of member objects.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub members: Vec<Member>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-}
-
-/// JSON template for Has Member response in Directory API.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MembersHasMember {
-    /**
-     * JSON template for Has Member response in Directory API.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "isMember"
-    )]
-    pub is_member: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Applications {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
-    )]
-    pub display_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "packageName"
-    )]
-    pub package_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub permission: Vec<String>,
-    /**
-     * The unique ID of the ASP.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "versionCode"
-    )]
-    pub version_code: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "versionName"
-    )]
-    pub version_name: String,
-}
-
-/// Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MobileDevice {
-    /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "adbStatus"
-    )]
-    pub adb_status: bool,
-    /**
-     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
+     * Google Workspace Mobile Management includes Android, [Google Sync](https://support.google.com/a/answer/135937), and iOS devices. For more information about common group mobile device API tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-mobile-devices.html).
      */
     #[serde(
         default,
@@ -3077,48 +2346,6 @@ pub struct MobileDevice {
     pub wifi_mac_address: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MobileDeviceAction {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub action: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MobileDevices {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * List of Mobile Device objects.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub mobiledevices: Vec<MobileDevice>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-}
-
 /// Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct OrgUnit {
@@ -3134,105 +2361,55 @@ pub struct OrgUnit {
     /**
      * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /**
      * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
     /**
      * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /**
+     * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "orgUnitId")]
+    pub org_unit_id: Option<String>,
     /**
      * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "orgUnitId"
-    )]
-    pub org_unit_id: String,
-    /**
-     * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "orgUnitPath"
     )]
-    pub org_unit_path: String,
+    pub org_unit_path: Option<String>,
     /**
      * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "parentOrgUnitId"
     )]
-    pub parent_org_unit_id: String,
+    pub parent_org_unit_id: Option<String>,
     /**
      * Managing your account's organizational units allows you to configure your users' access to services and custom settings. For more information about common organizational unit tasks, see the [Developer's Guide](/admin-sdk/directory/v1/guides/manage-org-units.html).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "parentOrgUnitPath"
     )]
-    pub parent_org_unit_path: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrgUnits {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * List of organizational unit objects.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "organizationUnits"
-    )]
-    pub organization_units: Vec<OrgUnit>,
+    pub parent_org_unit_path: Option<String>,
 }
 
 /// Printer configuration.
@@ -3261,69 +2438,46 @@ pub struct Printer {
     /**
      * Printer configuration.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /**
      * Printer configuration.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "displayName"
     )]
-    pub display_name: String,
+    pub display_name: Option<String>,
     /**
      * Printer configuration.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * Printer configuration.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "makeAndModel"
     )]
-    pub make_and_model: String,
+    pub make_and_model: Option<String>,
     /**
      * Printer configuration.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /**
      * Printer configuration.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "orgUnitId"
-    )]
-    pub org_unit_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "orgUnitId")]
+    pub org_unit_id: Option<String>,
     /**
      * Printer configuration.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
     /**
      * Printer configuration.
      */
@@ -3425,57 +2579,22 @@ pub struct Privilege {
     pub service_name: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Privileges {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * A list of child privileges. Privileges for a service form a tree. Each privilege can have a list of child privileges; this list is empty for a leaf privilege.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub items: Vec<Privilege>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-}
-
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RolePrivileges {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "privilegeName"
     )]
-    pub privilege_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "serviceId"
-    )]
-    pub service_id: String,
+    pub privilege_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "serviceId")]
+    pub service_id: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Role {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
     /**
      * A Boolean value to indicate whether payload is wanted. Optional.
      */
@@ -3494,19 +2613,14 @@ pub struct Role {
         rename = "isSystemRole"
     )]
     pub is_system_role: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "roleDescription"
     )]
-    pub role_description: String,
+    pub role_description: Option<String>,
     /**
      * The unique ID of the ASP.
      */
@@ -3517,13 +2631,8 @@ pub struct Role {
         rename = "roleId"
     )]
     pub role_id: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "roleName"
-    )]
-    pub role_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "roleName")]
+    pub role_name: Option<String>,
     /**
      * The set of privileges that are granted to this role.
      */
@@ -3544,39 +2653,25 @@ pub struct RoleAssignment {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "assignedTo"
     )]
-    pub assigned_to: String,
+    pub assigned_to: Option<String>,
     /**
      * Defines an assignment of a role.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
     /**
      * Defines an assignment of a role.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * Defines an assignment of a role.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "orgUnitId"
-    )]
-    pub org_unit_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "orgUnitId")]
+    pub org_unit_id: Option<String>,
     /**
      * Defines an assignment of a role.
      */
@@ -3600,77 +2695,8 @@ pub struct RoleAssignment {
     /**
      * Defines an assignment of a role.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "scopeType"
-    )]
-    pub scope_type: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct RoleAssignments {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * A list of RoleAssignment resources.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub items: Vec<RoleAssignment>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Roles {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * A list of Role resources.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub items: Vec<Role>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "scopeType")]
+    pub scope_type: Option<String>,
 }
 
 /// The type of API resource. For Schema resources, this is always `admin#directory#schema`.
@@ -3681,20 +2707,15 @@ pub struct Schema {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "displayName"
     )]
-    pub display_name: String,
+    pub display_name: Option<String>,
     /**
      * The type of API resource. For Schema resources, this is always `admin#directory#schema`.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
     /**
      * The type of API resource. For Schema resources, this is always `admin#directory#schema`.
      */
@@ -3707,32 +2728,22 @@ pub struct Schema {
     /**
      * The type of API resource. For Schema resources, this is always `admin#directory#schema`.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * The type of API resource. For Schema resources, this is always `admin#directory#schema`.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "schemaId"
-    )]
-    pub schema_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "schemaId")]
+    pub schema_id: Option<String>,
     /**
      * The type of API resource. For Schema resources, this is always `admin#directory#schema`.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "schemaName"
     )]
-    pub schema_name: String,
+    pub schema_name: Option<String>,
 }
 
 /// Indexing spec for a numeric field. By default, only exact match queries will be supported for numeric fields. Setting the `numericIndexingSpec` allows range queries to be supported.
@@ -3768,50 +2779,30 @@ pub struct SchemaFieldSpec {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "displayName"
     )]
-    pub display_name: String,
+    pub display_name: Option<String>,
     /**
      * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
     /**
      * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fieldId"
-    )]
-    pub field_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "fieldId")]
+    pub field_id: Option<String>,
     /**
      * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fieldName"
-    )]
-    pub field_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "fieldName")]
+    pub field_name: Option<String>,
     /**
      * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fieldType"
-    )]
-    pub field_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "fieldType")]
+    pub field_type: Option<String>,
     /**
      * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
      */
@@ -3823,12 +2814,8 @@ pub struct SchemaFieldSpec {
     /**
      * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * You can use schemas to add custom fields to user profiles. You can use these fields to store information such as the projects your users work on, their physical locations, their hire dates, or whatever else fits your business needs. For more information, see [Custom User Fields](/admin-sdk/directory/v1/guides/manage-schemas).
      */
@@ -3852,43 +2839,10 @@ pub struct SchemaFieldSpec {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "readAccessType"
     )]
-    pub read_access_type: String,
-}
-
-/// JSON response template for List Schema operation in Directory API.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Schemas {
-    /**
-     * JSON response template for List Schema operation in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * JSON response template for List Schema operation in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * JSON response template for List Schema operation in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub schemas: Vec<Schema>,
+    pub read_access_type: Option<String>,
 }
 
 /// JSON template for token resource in Directory API.
@@ -3970,60 +2924,22 @@ pub struct Token {
     pub user_key: String,
 }
 
-/// JSON response template for List tokens operation in Directory API.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Tokens {
-    /**
-     * JSON response template for List tokens operation in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * JSON response template for List tokens operation in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub items: Vec<Token>,
-    /**
-     * JSON response template for List tokens operation in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-}
-
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Ims {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "customProtocol"
     )]
-    pub custom_protocol: String,
+    pub custom_protocol: Option<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "customType"
     )]
-    pub custom_type: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub im: String,
+    pub custom_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub im: Option<String>,
     /**
      * A Boolean value to indicate whether payload is wanted. Optional.
      */
@@ -4032,19 +2948,10 @@ pub struct Ims {
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
     pub primary: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub protocol: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
 }
 
 /// The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
@@ -4107,19 +3014,23 @@ pub struct User {
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
-    #[serde(rename = "customSchemas")]
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "customSchemas"
+    )]
     pub custom_schemas:
-        std::collections::HashMap<String, std::collections::HashMap<String, serde_json::Value>>,
+        std::collections::BTreeMap<String, std::collections::BTreeMap<String, serde_json::Value>>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "customerId"
     )]
-    pub customer_id: String,
+    pub customer_id: Option<String>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
@@ -4142,12 +3053,8 @@ pub struct User {
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
@@ -4167,20 +3074,15 @@ pub struct User {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "hashFunction"
     )]
-    pub hash_function: String,
+    pub hash_function: Option<String>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
@@ -4261,12 +3163,8 @@ pub struct User {
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
@@ -4316,11 +3214,10 @@ pub struct User {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "orgUnitPath"
     )]
-    pub org_unit_path: String,
+    pub org_unit_path: Option<String>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
@@ -4329,12 +3226,8 @@ pub struct User {
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub password: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
@@ -4358,31 +3251,28 @@ pub struct User {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "primaryEmail"
     )]
-    pub primary_email: String,
+    pub primary_email: Option<String>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "recoveryEmail"
     )]
-    pub recovery_email: String,
+    pub recovery_email: Option<String>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "recoveryPhone"
     )]
-    pub recovery_phone: String,
+    pub recovery_phone: Option<String>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
@@ -4411,31 +3301,28 @@ pub struct User {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "suspensionReason"
     )]
-    pub suspension_reason: String,
+    pub suspension_reason: Option<String>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "thumbnailPhotoEtag"
     )]
-    pub thumbnail_photo_etag: String,
+    pub thumbnail_photo_etag: Option<String>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "thumbnailPhotoUrl"
     )]
-    pub thumbnail_photo_url: String,
+    pub thumbnail_photo_url: Option<String>,
     /**
      * The Directory API allows you to create and manage your account's users, user aliases, and user Gmail chat profile photos. For more information about common tasks, see the [User Accounts Developer's Guide](/admin-sdk/directory/v1/guides/manage-users.html) and the [User Aliases Developer's Guide](/admin-sdk/directory/v1/guides/manage-user-aliases.html).
      */
@@ -4473,80 +3360,59 @@ pub struct UserAddress {
     /**
      * JSON template for address.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
     /**
      * JSON template for address.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "countryCode"
     )]
-    pub country_code: String,
+    pub country_code: Option<String>,
     /**
      * JSON template for address.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "customType"
     )]
-    pub custom_type: String,
+    pub custom_type: Option<String>,
     /**
      * JSON template for address.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "extendedAddress"
     )]
-    pub extended_address: String,
+    pub extended_address: Option<String>,
     /**
      * JSON template for address.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub formatted: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formatted: Option<String>,
     /**
      * JSON template for address.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub locality: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locality: Option<String>,
     /**
      * JSON template for address.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "poBox"
-    )]
-    pub po_box: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "poBox")]
+    pub po_box: Option<String>,
     /**
      * JSON template for address.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "postalCode"
     )]
-    pub postal_code: String,
+    pub postal_code: Option<String>,
     /**
      * JSON template for address.
      */
@@ -4558,12 +3424,8 @@ pub struct UserAddress {
     /**
      * JSON template for address.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub region: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
     /**
      * JSON template for address.
      */
@@ -4578,21 +3440,15 @@ pub struct UserAddress {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "streetAddress"
     )]
-    pub street_address: String,
+    pub street_address: Option<String>,
     /**
      * JSON template for address.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
 }
 
 /// JSON template for an email.
@@ -4601,22 +3457,17 @@ pub struct UserEmail {
     /**
      * JSON template for an email.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
     /**
      * JSON template for an email.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "customType"
     )]
-    pub custom_type: String,
+    pub custom_type: Option<String>,
     /**
      * JSON template for an email.
      */
@@ -4628,13 +3479,8 @@ pub struct UserEmail {
     /**
      * JSON template for an email.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
 }
 
 /// JSON template for an externalId entry.
@@ -4675,25 +3521,18 @@ pub struct UserExternalId {
 pub struct UserGender {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "addressMeAs"
     )]
-    pub address_me_as: String,
+    pub address_me_as: Option<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "customGender"
     )]
-    pub custom_gender: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    pub custom_gender: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
 }
 
 /// JSON template for instant messenger of an user.
@@ -4822,109 +3661,64 @@ pub struct UserLocation {
     /**
      * JSON template for a location entry.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub area: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub area: Option<String>,
     /**
      * JSON template for a location entry.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "buildingId"
     )]
-    pub building_id: String,
+    pub building_id: Option<String>,
     /**
      * JSON template for a location entry.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "customType"
     )]
-    pub custom_type: String,
+    pub custom_type: Option<String>,
     /**
      * JSON template for a location entry.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "deskCode"
-    )]
-    pub desk_code: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "deskCode")]
+    pub desk_code: Option<String>,
     /**
      * JSON template for a location entry.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "floorName"
-    )]
-    pub floor_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "floorName")]
+    pub floor_name: Option<String>,
     /**
      * JSON template for a location entry.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "floorSection"
     )]
-    pub floor_section: String,
+    pub floor_section: Option<String>,
     /**
      * JSON template for a location entry.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserMakeAdmin {
-    /**
-     * A Boolean value to indicate whether payload is wanted. Optional.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub status: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserName {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "familyName"
     )]
-    pub family_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fullName"
-    )]
-    pub full_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "givenName"
-    )]
-    pub given_name: String,
+    pub family_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "fullName")]
+    pub full_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "givenName")]
+    pub given_name: Option<String>,
 }
 
 /// JSON template for an organization entry.
@@ -5051,11 +3845,10 @@ pub struct UserPhone {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "customType"
     )]
-    pub custom_type: String,
+    pub custom_type: Option<String>,
     /**
      * JSON template for a phone entry.
      */
@@ -5067,81 +3860,13 @@ pub struct UserPhone {
     /**
      * JSON template for a phone entry.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
     /**
      * JSON template for a phone entry.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserPhoto {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * The unique ID of the ASP.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub height: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mimeType"
-    )]
-    pub mime_type: String,
-    /**
-     * The user photo's upload data in [web-safe Base64](https://en.wikipedia.org/wiki/Base64#URL_applications) format in bytes. This means: \* The slash (/) character is replaced with the underscore (_) character. \* The plus sign (+) character is replaced with the hyphen (-) character. \* The equals sign (=) character is replaced with the asterisk (\*). \* For padding, the period (.) character is used instead of the RFC-4648 baseURL definition which uses the equals sign (=) for padding. This is done to simplify URL-parsing. \* Whatever the size of the photo being uploaded, the API downsizes it to 96x96 pixels.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "photoData")]
-    pub photo_data: Option<bytes::Bytes>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "primaryEmail"
-    )]
-    pub primary_email: String,
-    /**
-     * The unique ID of the ASP.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub width: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
 }
 
 /// JSON template for a POSIX account entry.
@@ -5284,32 +4009,13 @@ pub struct UserSshPublicKey {
     /**
      * JSON template for a POSIX account entry.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fingerprint: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
     /**
      * JSON template for a POSIX account entry.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub key: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserUndelete {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "orgUnitPath"
-    )]
-    pub org_unit_path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
 }
 
 /// JSON template for a website entry.
@@ -5354,44 +4060,6 @@ pub struct UserWebsite {
     pub value: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Users {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub trigger_event: String,
-    /**
-     * List of user objects.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub users: Vec<User>,
-}
-
 /// The Directory API allows you to view, generate, and invalidate backup verification codes for a user.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct VerificationCode {
@@ -5434,618 +4102,3 @@ pub struct VerificationCode {
     )]
     pub verification_code: String,
 }
-
-/// JSON response template for List verification codes operation in Directory API.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct VerificationCodes {
-    /**
-     * JSON response template for List verification codes operation in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * JSON response template for List verification codes operation in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub items: Vec<VerificationCode>,
-    /**
-     * JSON response template for List verification codes operation in Directory API.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-}
-
-/**
- * V1 error format.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Xgafv {
-    #[serde(rename = "1")]
-    One,
-    #[serde(rename = "2")]
-    Two,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Xgafv {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Xgafv::One => "1",
-            Xgafv::Two => "2",
-            Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Xgafv {
-    fn default() -> Xgafv {
-        Xgafv::Noop
-    }
-}
-impl Xgafv {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Xgafv::Noop)
-    }
-}
-
-/**
- * Data format for response.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Alt {
-    #[serde(rename = "json")]
-    Json,
-    #[serde(rename = "media")]
-    Media,
-    #[serde(rename = "proto")]
-    Proto,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Alt {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Alt::Json => "json",
-            Alt::Media => "media",
-            Alt::Proto => "proto",
-            Alt::Noop => "",
-            Alt::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Alt {
-    fn default() -> Alt {
-        Alt::Noop
-    }
-}
-impl Alt {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Alt::Noop)
-    }
-}
-
-/**
- * Device property to use for sorting results.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OrderBy {
-    #[serde(rename = "annotatedLocation")]
-    AnnotatedLocation,
-    #[serde(rename = "annotatedUser")]
-    AnnotatedUser,
-    #[serde(rename = "lastSync")]
-    LastSync,
-    #[serde(rename = "notes")]
-    Notes,
-    #[serde(rename = "serialNumber")]
-    SerialNumber,
-    #[serde(rename = "status")]
-    Status,
-    #[serde(rename = "supportEndDate")]
-    SupportEndDate,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for OrderBy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OrderBy::AnnotatedLocation => "annotatedLocation",
-            OrderBy::AnnotatedUser => "annotatedUser",
-            OrderBy::LastSync => "lastSync",
-            OrderBy::Notes => "notes",
-            OrderBy::SerialNumber => "serialNumber",
-            OrderBy::Status => "status",
-            OrderBy::SupportEndDate => "supportEndDate",
-            OrderBy::Noop => "",
-            OrderBy::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for OrderBy {
-    fn default() -> OrderBy {
-        OrderBy::Noop
-    }
-}
-impl OrderBy {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OrderBy::Noop)
-    }
-}
-
-/**
- * Restrict information returned to a set of selected fields.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Projection {
-    #[serde(rename = "BASIC")]
-    Basic,
-    #[serde(rename = "FULL")]
-    Full,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Projection {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Projection::Basic => "BASIC",
-            Projection::Full => "FULL",
-            Projection::Noop => "",
-            Projection::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Projection {
-    fn default() -> Projection {
-        Projection::Noop
-    }
-}
-impl Projection {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Projection::Noop)
-    }
-}
-
-/**
- * Whether to return results in ascending or descending order. Must be used with the `orderBy` parameter.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum SortOrder {
-    #[serde(rename = "ASCENDING")]
-    Ascending,
-    #[serde(rename = "DESCENDING")]
-    Descending,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for SortOrder {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            SortOrder::Ascending => "ASCENDING",
-            SortOrder::Descending => "DESCENDING",
-            SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for SortOrder {
-    fn default() -> SortOrder {
-        SortOrder::Noop
-    }
-}
-impl SortOrder {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SortOrder::Noop)
-    }
-}
-
-/**
- * Device property to use for sorting results.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum DirectoryMobiledevicesListOrderBy {
-    #[serde(rename = "deviceId")]
-    DeviceId,
-    #[serde(rename = "email")]
-    Email,
-    #[serde(rename = "lastSync")]
-    LastSync,
-    #[serde(rename = "model")]
-    Model,
-    #[serde(rename = "name")]
-    Name,
-    #[serde(rename = "os")]
-    Os,
-    #[serde(rename = "status")]
-    Status,
-    #[serde(rename = "type")]
-    Type,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for DirectoryMobiledevicesListOrderBy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            DirectoryMobiledevicesListOrderBy::DeviceId => "deviceId",
-            DirectoryMobiledevicesListOrderBy::Email => "email",
-            DirectoryMobiledevicesListOrderBy::LastSync => "lastSync",
-            DirectoryMobiledevicesListOrderBy::Model => "model",
-            DirectoryMobiledevicesListOrderBy::Name => "name",
-            DirectoryMobiledevicesListOrderBy::Os => "os",
-            DirectoryMobiledevicesListOrderBy::Status => "status",
-            DirectoryMobiledevicesListOrderBy::Type => "type",
-            DirectoryMobiledevicesListOrderBy::Noop => "",
-            DirectoryMobiledevicesListOrderBy::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for DirectoryMobiledevicesListOrderBy {
-    fn default() -> DirectoryMobiledevicesListOrderBy {
-        DirectoryMobiledevicesListOrderBy::Noop
-    }
-}
-impl DirectoryMobiledevicesListOrderBy {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DirectoryMobiledevicesListOrderBy::Noop)
-    }
-}
-
-/**
- * Whether to return all sub-organizations or just immediate children.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum DirectoryOrgunitsListType {
-    #[serde(rename = "all")]
-    All,
-    #[serde(rename = "children")]
-    Children,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for DirectoryOrgunitsListType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            DirectoryOrgunitsListType::All => "all",
-            DirectoryOrgunitsListType::Children => "children",
-            DirectoryOrgunitsListType::Noop => "",
-            DirectoryOrgunitsListType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for DirectoryOrgunitsListType {
-    fn default() -> DirectoryOrgunitsListType {
-        DirectoryOrgunitsListType::Noop
-    }
-}
-impl DirectoryOrgunitsListType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DirectoryOrgunitsListType::Noop)
-    }
-}
-
-/**
- * Source from which Building.coordinates are derived.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum CoordinatesSource {
-    #[serde(rename = "CLIENT_SPECIFIED")]
-    ClientSpecified,
-    #[serde(rename = "RESOLVED_FROM_ADDRESS")]
-    ResolvedFromAddress,
-    #[serde(rename = "SOURCE_UNSPECIFIED")]
-    SourceUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for CoordinatesSource {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            CoordinatesSource::ClientSpecified => "CLIENT_SPECIFIED",
-            CoordinatesSource::ResolvedFromAddress => "RESOLVED_FROM_ADDRESS",
-            CoordinatesSource::SourceUnspecified => "SOURCE_UNSPECIFIED",
-            CoordinatesSource::Noop => "",
-            CoordinatesSource::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for CoordinatesSource {
-    fn default() -> CoordinatesSource {
-        CoordinatesSource::Noop
-    }
-}
-impl CoordinatesSource {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, CoordinatesSource::Noop)
-    }
-}
-
-/**
- * Column to use for sorting results
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum DirectoryGroupsListOrderBy {
-    #[serde(rename = "email")]
-    Email,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for DirectoryGroupsListOrderBy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            DirectoryGroupsListOrderBy::Email => "email",
-            DirectoryGroupsListOrderBy::Noop => "",
-            DirectoryGroupsListOrderBy::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for DirectoryGroupsListOrderBy {
-    fn default() -> DirectoryGroupsListOrderBy {
-        DirectoryGroupsListOrderBy::Noop
-    }
-}
-impl DirectoryGroupsListOrderBy {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DirectoryGroupsListOrderBy::Noop)
-    }
-}
-
-/**
- * Event on which subscription is intended (if subscribing)
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Event {
-    #[serde(rename = "add")]
-    Add,
-    #[serde(rename = "delete")]
-    Delete,
-    #[serde(rename = "makeAdmin")]
-    MakeAdmin,
-    #[serde(rename = "undelete")]
-    Undelete,
-    #[serde(rename = "update")]
-    Update,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Event {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Event::Add => "add",
-            Event::Delete => "delete",
-            Event::MakeAdmin => "makeAdmin",
-            Event::Undelete => "undelete",
-            Event::Update => "update",
-            Event::Noop => "",
-            Event::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Event {
-    fn default() -> Event {
-        Event::Noop
-    }
-}
-impl Event {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Event::Noop)
-    }
-}
-
-/**
- * Property to use for sorting results.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum DirectoryUsersListOrderBy {
-    #[serde(rename = "email")]
-    Email,
-    #[serde(rename = "familyName")]
-    FamilyName,
-    #[serde(rename = "givenName")]
-    GivenName,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for DirectoryUsersListOrderBy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            DirectoryUsersListOrderBy::Email => "email",
-            DirectoryUsersListOrderBy::FamilyName => "familyName",
-            DirectoryUsersListOrderBy::GivenName => "givenName",
-            DirectoryUsersListOrderBy::Noop => "",
-            DirectoryUsersListOrderBy::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for DirectoryUsersListOrderBy {
-    fn default() -> DirectoryUsersListOrderBy {
-        DirectoryUsersListOrderBy::Noop
-    }
-}
-impl DirectoryUsersListOrderBy {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DirectoryUsersListOrderBy::Noop)
-    }
-}
-
-/**
- * What subset of fields to fetch for this user.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum DirectoryUsersListProjection {
-    #[serde(rename = "basic")]
-    Basic,
-    #[serde(rename = "custom")]
-    Custom,
-    #[serde(rename = "full")]
-    Full,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for DirectoryUsersListProjection {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            DirectoryUsersListProjection::Basic => "basic",
-            DirectoryUsersListProjection::Custom => "custom",
-            DirectoryUsersListProjection::Full => "full",
-            DirectoryUsersListProjection::Noop => "",
-            DirectoryUsersListProjection::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for DirectoryUsersListProjection {
-    fn default() -> DirectoryUsersListProjection {
-        DirectoryUsersListProjection::Noop
-    }
-}
-impl DirectoryUsersListProjection {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DirectoryUsersListProjection::Noop)
-    }
-}
-
-/**
- * Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ViewType {
-    #[serde(rename = "admin_view")]
-    AdminView,
-    #[serde(rename = "domain_public")]
-    DomainPublic,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for ViewType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ViewType::AdminView => "admin_view",
-            ViewType::DomainPublic => "domain_public",
-            ViewType::Noop => "",
-            ViewType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for ViewType {
-    fn default() -> ViewType {
-        ViewType::Noop
-    }
-}
-impl ViewType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ViewType::Noop)
-    }
-}
-
-/**
- * Events to watch for.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum DirectoryUsersAliasesListEvent {
-    #[serde(rename = "add")]
-    Add,
-    #[serde(rename = "delete")]
-    Delete,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for DirectoryUsersAliasesListEvent {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            DirectoryUsersAliasesListEvent::Add => "add",
-            DirectoryUsersAliasesListEvent::Delete => "delete",
-            DirectoryUsersAliasesListEvent::Noop => "",
-            DirectoryUsersAliasesListEvent::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for DirectoryUsersAliasesListEvent {
-    fn default() -> DirectoryUsersAliasesListEvent {
-        DirectoryUsersAliasesListEvent::Noop
-    }
-}
-impl DirectoryUsersAliasesListEvent {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DirectoryUsersAliasesListEvent::Noop)
-    }
-}
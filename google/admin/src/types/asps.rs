@@ -0,0 +1,158 @@
+//! The data types of the `asps` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Asps {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub etag: String,
+    /**
+     * A list of ASP resources.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub items: Vec<Asp>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+}
+
+/**
+ * V1 error format.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Xgafv {
+    #[serde(rename = "1")]
+    One,
+    #[serde(rename = "2")]
+    Two,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Xgafv {
+    fn as_ref(&self) -> &str {
+        match self {
+            Xgafv::One => "1",
+            Xgafv::Two => "2",
+            Xgafv::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Xgafv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Xgafv {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "1" => Xgafv::One,
+            "2" => Xgafv::Two,
+            _ => Xgafv::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Xgafv {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Xgafv> for String {
+    fn from(v: Xgafv) -> Self {
+        match v {
+            Xgafv::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Data format for response.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Alt {
+    #[serde(rename = "json")]
+    Json,
+    #[serde(rename = "media")]
+    Media,
+    #[serde(rename = "proto")]
+    Proto,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Alt {
+    fn as_ref(&self) -> &str {
+        match self {
+            Alt::Json => "json",
+            Alt::Media => "media",
+            Alt::Proto => "proto",
+            Alt::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Alt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Alt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "json" => Alt::Json,
+            "media" => Alt::Media,
+            "proto" => Alt::Proto,
+            _ => Alt::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Alt {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Alt> for String {
+    fn from(v: Alt) -> Self {
+        match v {
+            Alt::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+//! The data types of the `channels` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// An notification channel used to watch for resource changes.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Channel {
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub expiration: i64,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub params: std::collections::BTreeMap<String, String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub payload: bool,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "resourceId"
+    )]
+    pub resource_id: Option<String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "resourceUri"
+    )]
+    pub resource_uri: Option<String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+}
@@ -0,0 +1,266 @@
+//! The data types of the `chromeosdevices` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChromeOsDeviceAction {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "deprovisionReason"
+    )]
+    pub deprovision_reason: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChromeOsDevices {
+    /**
+     * List of Chrome OS Device objects.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub chromeosdevices: Vec<ChromeOsDevice>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub etag: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChromeOsMoveDevicesOu {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "deviceIds"
+    )]
+    pub device_ids: Vec<String>,
+}
+
+/**
+ * Device property to use for sorting results.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OrderBy {
+    #[serde(rename = "annotatedLocation")]
+    AnnotatedLocation,
+    #[serde(rename = "annotatedUser")]
+    AnnotatedUser,
+    #[serde(rename = "lastSync")]
+    LastSync,
+    #[serde(rename = "notes")]
+    Notes,
+    #[serde(rename = "serialNumber")]
+    SerialNumber,
+    #[serde(rename = "status")]
+    Status,
+    #[serde(rename = "supportEndDate")]
+    SupportEndDate,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OrderBy {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrderBy::AnnotatedLocation => "annotatedLocation",
+            OrderBy::AnnotatedUser => "annotatedUser",
+            OrderBy::LastSync => "lastSync",
+            OrderBy::Notes => "notes",
+            OrderBy::SerialNumber => "serialNumber",
+            OrderBy::Status => "status",
+            OrderBy::SupportEndDate => "supportEndDate",
+            OrderBy::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OrderBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "annotatedLocation" => OrderBy::AnnotatedLocation,
+            "annotatedUser" => OrderBy::AnnotatedUser,
+            "lastSync" => OrderBy::LastSync,
+            "notes" => OrderBy::Notes,
+            "serialNumber" => OrderBy::SerialNumber,
+            "status" => OrderBy::Status,
+            "supportEndDate" => OrderBy::SupportEndDate,
+            _ => OrderBy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OrderBy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OrderBy> for String {
+    fn from(v: OrderBy) -> Self {
+        match v {
+            OrderBy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Restrict information returned to a set of selected fields.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Projection {
+    #[serde(rename = "BASIC")]
+    Basic,
+    #[serde(rename = "FULL")]
+    Full,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Projection {
+    fn as_ref(&self) -> &str {
+        match self {
+            Projection::Basic => "BASIC",
+            Projection::Full => "FULL",
+            Projection::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Projection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Projection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BASIC" => Projection::Basic,
+            "FULL" => Projection::Full,
+            _ => Projection::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Projection {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Projection> for String {
+    fn from(v: Projection) -> Self {
+        match v {
+            Projection::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Whether to return results in ascending or descending order.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SortOrder {
+    #[serde(rename = "ASCENDING")]
+    Ascending,
+    #[serde(rename = "DESCENDING")]
+    Descending,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SortOrder {
+    fn as_ref(&self) -> &str {
+        match self {
+            SortOrder::Ascending => "ASCENDING",
+            SortOrder::Descending => "DESCENDING",
+            SortOrder::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SortOrder {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ASCENDING" => SortOrder::Ascending,
+            "DESCENDING" => SortOrder::Descending,
+            _ => SortOrder::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SortOrder {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SortOrder> for String {
+    fn from(v: SortOrder) -> Self {
+        match v {
+            SortOrder::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
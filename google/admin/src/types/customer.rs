@@ -0,0 +1,132 @@
+//! The data types of the `customer` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Information regarding a command that was issued to a device.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DirectoryChromeosdevicesCommand {
+    /**
+     * Information regarding a command that was issued to a device.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "commandExpireTime"
+    )]
+    pub command_expire_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Information regarding a command that was issued to a device.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "commandId"
+    )]
+    pub command_id: i64,
+    /**
+     * Information regarding a command that was issued to a device.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "commandResult"
+    )]
+    pub command_result: Option<DirectoryChromeosdevicesCommandResult>,
+    /**
+     * Information regarding a command that was issued to a device.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "issueTime"
+    )]
+    pub issue_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Information regarding a command that was issued to a device.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub payload: String,
+    /**
+     * Information regarding a command that was issued to a device.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<State>,
+    /**
+     * Information regarding a command that was issued to a device.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<Type>,
+}
+
+/// The result of executing a command.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DirectoryChromeosdevicesCommandResult {
+    /**
+     * The result of executing a command.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "errorMessage"
+    )]
+    pub error_message: String,
+    /**
+     * The result of executing a command.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "executeTime"
+    )]
+    pub execute_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The result of executing a command.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<Result>,
+}
+
+/// A request for issuing a command.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DirectoryChromeosdevicesIssueCommandRequest {
+    /**
+     * A request for issuing a command.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "commandType"
+    )]
+    pub command_type: Option<CommandType>,
+    /**
+     * A request for issuing a command.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
+}
+
+/// A response for issuing a command.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DirectoryChromeosdevicesIssueCommandResponse {
+    /**
+     * A response for issuing a command.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "commandId"
+    )]
+    pub command_id: i64,
+}
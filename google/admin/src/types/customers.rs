@@ -0,0 +1,231 @@
+//! The data types of the `customers` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Request for adding new printers in batch.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchCreatePrintersRequest {
+    /**
+     * Request for adding new printers in batch.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub requests: Vec<CreatePrinterRequest>,
+}
+
+/// Response for adding new printers in batch.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchCreatePrintersResponse {
+    /**
+     * Response for adding new printers in batch.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub failures: Vec<FailureInfo>,
+    /**
+     * Response for adding new printers in batch.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub printers: Vec<Printer>,
+}
+
+/// Request for deleting existing printers in batch.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchDeletePrintersRequest {
+    /**
+     * Request for deleting existing printers in batch.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "printerIds"
+    )]
+    pub printer_ids: Vec<String>,
+}
+
+/// Response for deleting existing printers in batch.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchDeletePrintersResponse {
+    /**
+     * Response for deleting existing printers in batch.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "failedPrinters"
+    )]
+    pub failed_printers: Vec<FailureInfo>,
+    /**
+     * Response for deleting existing printers in batch.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "printerIds"
+    )]
+    pub printer_ids: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Customer {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "alternateEmail"
+    )]
+    pub alternate_email: Option<String>,
+    /**
+     * Date and time the report was received.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "customerCreationTime"
+    )]
+    pub customer_creation_time: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "customerDomain"
+    )]
+    pub customer_domain: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "phoneNumber"
+    )]
+    pub phone_number: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "postalAddress"
+    )]
+    pub postal_address: Option<CustomerPostalAddress>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CustomerPostalAddress {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "addressLine1"
+    )]
+    pub address_line_1: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "addressLine2"
+    )]
+    pub address_line_2: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "addressLine3"
+    )]
+    pub address_line_3: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "contactName"
+    )]
+    pub contact_name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "countryCode"
+    )]
+    pub country_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locality: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "organizationName"
+    )]
+    pub organization_name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "postalCode"
+    )]
+    pub postal_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+}
+
+/// A generic empty message that you can re-use to avoid defining duplicated empty messages in your APIs. A typical example is to use it as the request or the response type of an API method. For instance: service Foo { rpc Bar(google.protobuf.Empty) returns (google.protobuf.Empty); } The JSON representation for `Empty` is empty JSON object `{}`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Empty {}
+
+/// Response for listing allowed printer models.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ListPrinterModelsResponse {
+    /**
+     * Response for listing allowed printer models.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+    /**
+     * Response for listing allowed printer models.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "printerModels"
+    )]
+    pub printer_models: Vec<PrinterModel>,
+}
+
+/// Response for listing printers.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ListPrintersResponse {
+    /**
+     * Response for listing printers.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+    /**
+     * Response for listing printers.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub printers: Vec<Printer>,
+}
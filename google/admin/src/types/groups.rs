@@ -0,0 +1,161 @@
+//! The data types of the `groups` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// JSON template for Alias object in Directory API.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Alias {
+    /**
+     * JSON template for Alias object in Directory API.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    /**
+     * JSON template for Alias object in Directory API.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    /**
+     * JSON template for Alias object in Directory API.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /**
+     * JSON template for Alias object in Directory API.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /**
+     * JSON template for Alias object in Directory API.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "primaryEmail"
+    )]
+    pub primary_email: Option<String>,
+}
+
+/// JSON response template to list aliases in Directory API.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Aliases {
+    /**
+     * JSON response template to list aliases in Directory API.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub aliases: Vec<String>,
+    /**
+     * JSON response template to list aliases in Directory API.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub etag: String,
+    /**
+     * JSON response template to list aliases in Directory API.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Groups {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub etag: String,
+    /**
+     * List of group objects.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub groups: Vec<Group>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+}
+
+/**
+ * Column to use for sorting results
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum DirectoryGroupsListOrderBy {
+    #[serde(rename = "email")]
+    Email,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for DirectoryGroupsListOrderBy {
+    fn as_ref(&self) -> &str {
+        match self {
+            DirectoryGroupsListOrderBy::Email => "email",
+            DirectoryGroupsListOrderBy::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DirectoryGroupsListOrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DirectoryGroupsListOrderBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "email" => DirectoryGroupsListOrderBy::Email,
+            _ => DirectoryGroupsListOrderBy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DirectoryGroupsListOrderBy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DirectoryGroupsListOrderBy> for String {
+    fn from(v: DirectoryGroupsListOrderBy) -> Self {
+        match v {
+            DirectoryGroupsListOrderBy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! The data types of the `mobiledevices` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MobileDeviceAction {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MobileDevices {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub etag: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * List of Mobile Device objects.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub mobiledevices: Vec<MobileDevice>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+}
+
+/**
+ * Device property to use for sorting results.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum DirectoryMobiledevicesListOrderBy {
+    #[serde(rename = "deviceId")]
+    DeviceId,
+    #[serde(rename = "email")]
+    Email,
+    #[serde(rename = "lastSync")]
+    LastSync,
+    #[serde(rename = "model")]
+    Model,
+    #[serde(rename = "name")]
+    Name,
+    #[serde(rename = "os")]
+    Os,
+    #[serde(rename = "status")]
+    Status,
+    #[serde(rename = "type")]
+    Type,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for DirectoryMobiledevicesListOrderBy {
+    fn as_ref(&self) -> &str {
+        match self {
+            DirectoryMobiledevicesListOrderBy::DeviceId => "deviceId",
+            DirectoryMobiledevicesListOrderBy::Email => "email",
+            DirectoryMobiledevicesListOrderBy::LastSync => "lastSync",
+            DirectoryMobiledevicesListOrderBy::Model => "model",
+            DirectoryMobiledevicesListOrderBy::Name => "name",
+            DirectoryMobiledevicesListOrderBy::Os => "os",
+            DirectoryMobiledevicesListOrderBy::Status => "status",
+            DirectoryMobiledevicesListOrderBy::Type => "type",
+            DirectoryMobiledevicesListOrderBy::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DirectoryMobiledevicesListOrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DirectoryMobiledevicesListOrderBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "deviceId" => DirectoryMobiledevicesListOrderBy::DeviceId,
+            "email" => DirectoryMobiledevicesListOrderBy::Email,
+            "lastSync" => DirectoryMobiledevicesListOrderBy::LastSync,
+            "model" => DirectoryMobiledevicesListOrderBy::Model,
+            "name" => DirectoryMobiledevicesListOrderBy::Name,
+            "os" => DirectoryMobiledevicesListOrderBy::Os,
+            "status" => DirectoryMobiledevicesListOrderBy::Status,
+            "type" => DirectoryMobiledevicesListOrderBy::Type,
+            _ => DirectoryMobiledevicesListOrderBy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DirectoryMobiledevicesListOrderBy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DirectoryMobiledevicesListOrderBy> for String {
+    fn from(v: DirectoryMobiledevicesListOrderBy) -> Self {
+        match v {
+            DirectoryMobiledevicesListOrderBy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
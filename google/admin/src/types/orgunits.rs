@@ -0,0 +1,93 @@
+//! The data types of the `orgunits` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrgUnits {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub etag: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * List of organizational unit objects.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "organizationUnits"
+    )]
+    pub organization_units: Vec<OrgUnit>,
+}
+
+/**
+ * Whether to return all sub-organizations or just immediate children.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum DirectoryOrgunitsListType {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "children")]
+    Children,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for DirectoryOrgunitsListType {
+    fn as_ref(&self) -> &str {
+        match self {
+            DirectoryOrgunitsListType::All => "all",
+            DirectoryOrgunitsListType::Children => "children",
+            DirectoryOrgunitsListType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DirectoryOrgunitsListType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DirectoryOrgunitsListType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => DirectoryOrgunitsListType::All,
+            "children" => DirectoryOrgunitsListType::Children,
+            _ => DirectoryOrgunitsListType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DirectoryOrgunitsListType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DirectoryOrgunitsListType> for String {
+    fn from(v: DirectoryOrgunitsListType) -> Self {
+        match v {
+            DirectoryOrgunitsListType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
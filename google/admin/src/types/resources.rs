@@ -0,0 +1,203 @@
+//! The data types of the `resources` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Public API: Resources.buildings
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Buildings {
+    /**
+     * Public API: Resources.buildings
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub buildings: Vec<Building>,
+    /**
+     * Public API: Resources.buildings
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub etag: String,
+    /**
+     * Public API: Resources.buildings
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * Public API: Resources.buildings
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+}
+
+/// Public API: Resources.calendars
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CalendarResources {
+    /**
+     * Public API: Resources.calendars
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub etag: String,
+    /**
+     * Public API: Resources.calendars
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub items: Vec<CalendarResource>,
+    /**
+     * Public API: Resources.calendars
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * Public API: Resources.calendars
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FeatureRename {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "newName")]
+    pub new_name: Option<String>,
+}
+
+/// Public API: Resources.features
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Features {
+    /**
+     * Public API: Resources.features
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub etag: String,
+    /**
+     * Public API: Resources.features
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub features: Vec<Feature>,
+    /**
+     * Public API: Resources.features
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * Public API: Resources.features
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+}
+
+/**
+ * Source from which Building.coordinates are derived.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum CoordinatesSource {
+    #[serde(rename = "CLIENT_SPECIFIED")]
+    ClientSpecified,
+    #[serde(rename = "RESOLVED_FROM_ADDRESS")]
+    ResolvedFromAddress,
+    #[serde(rename = "SOURCE_UNSPECIFIED")]
+    SourceUnspecified,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for CoordinatesSource {
+    fn as_ref(&self) -> &str {
+        match self {
+            CoordinatesSource::ClientSpecified => "CLIENT_SPECIFIED",
+            CoordinatesSource::ResolvedFromAddress => "RESOLVED_FROM_ADDRESS",
+            CoordinatesSource::SourceUnspecified => "SOURCE_UNSPECIFIED",
+            CoordinatesSource::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for CoordinatesSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CoordinatesSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CLIENT_SPECIFIED" => CoordinatesSource::ClientSpecified,
+            "RESOLVED_FROM_ADDRESS" => CoordinatesSource::ResolvedFromAddress,
+            "SOURCE_UNSPECIFIED" => CoordinatesSource::SourceUnspecified,
+            _ => CoordinatesSource::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CoordinatesSource {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<CoordinatesSource> for String {
+    fn from(v: CoordinatesSource) -> Self {
+        match v {
+            CoordinatesSource::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
@@ -0,0 +1,696 @@
+//! The data types of the `users` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserMakeAdmin {
+    /**
+     * A Boolean value to indicate whether payload is wanted. Optional.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub status: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserPhoto {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    /**
+     * The unique ID of the ASP.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub height: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "mimeType")]
+    pub mime_type: Option<String>,
+    /**
+     * The user photo's upload data in [web-safe Base64](https://en.wikipedia.org/wiki/Base64#URL_applications) format in bytes. This means: \* The slash (/) character is replaced with the underscore (_) character. \* The plus sign (+) character is replaced with the hyphen (-) character. \* The equals sign (=) character is replaced with the asterisk (\*). \* For padding, the period (.) character is used instead of the RFC-4648 baseURL definition which uses the equals sign (=) for padding. This is done to simplify URL-parsing. \* Whatever the size of the photo being uploaded, the API downsizes it to 96x96 pixels.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "photoData")]
+    pub photo_data: Option<bytes::Bytes>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "primaryEmail"
+    )]
+    pub primary_email: Option<String>,
+    /**
+     * The unique ID of the ASP.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub width: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserUndelete {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "orgUnitPath"
+    )]
+    pub org_unit_path: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Users {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub etag: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub trigger_event: String,
+    /**
+     * List of user objects.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub users: Vec<User>,
+}
+
+/**
+ * Events to watch for.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Event {
+    #[serde(rename = "add")]
+    Add,
+    #[serde(rename = "delete")]
+    Delete,
+    #[serde(rename = "makeAdmin")]
+    MakeAdmin,
+    #[serde(rename = "undelete")]
+    Undelete,
+    #[serde(rename = "update")]
+    Update,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Event {
+    fn as_ref(&self) -> &str {
+        match self {
+            Event::Add => "add",
+            Event::Delete => "delete",
+            Event::MakeAdmin => "makeAdmin",
+            Event::Undelete => "undelete",
+            Event::Update => "update",
+            Event::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Event {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Event {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "add" => Event::Add,
+            "delete" => Event::Delete,
+            "makeAdmin" => Event::MakeAdmin,
+            "undelete" => Event::Undelete,
+            "update" => Event::Update,
+            _ => Event::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Event {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Event> for String {
+    fn from(v: Event) -> Self {
+        match v {
+            Event::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Property to use for sorting results.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum DirectoryUsersListOrderBy {
+    #[serde(rename = "email")]
+    Email,
+    #[serde(rename = "familyName")]
+    FamilyName,
+    #[serde(rename = "givenName")]
+    GivenName,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for DirectoryUsersListOrderBy {
+    fn as_ref(&self) -> &str {
+        match self {
+            DirectoryUsersListOrderBy::Email => "email",
+            DirectoryUsersListOrderBy::FamilyName => "familyName",
+            DirectoryUsersListOrderBy::GivenName => "givenName",
+            DirectoryUsersListOrderBy::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DirectoryUsersListOrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DirectoryUsersListOrderBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "email" => DirectoryUsersListOrderBy::Email,
+            "familyName" => DirectoryUsersListOrderBy::FamilyName,
+            "givenName" => DirectoryUsersListOrderBy::GivenName,
+            _ => DirectoryUsersListOrderBy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DirectoryUsersListOrderBy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DirectoryUsersListOrderBy> for String {
+    fn from(v: DirectoryUsersListOrderBy) -> Self {
+        match v {
+            DirectoryUsersListOrderBy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * What subset of fields to fetch for this user.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum DirectoryUsersListProjection {
+    #[serde(rename = "basic")]
+    Basic,
+    #[serde(rename = "custom")]
+    Custom,
+    #[serde(rename = "full")]
+    Full,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for DirectoryUsersListProjection {
+    fn as_ref(&self) -> &str {
+        match self {
+            DirectoryUsersListProjection::Basic => "basic",
+            DirectoryUsersListProjection::Custom => "custom",
+            DirectoryUsersListProjection::Full => "full",
+            DirectoryUsersListProjection::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DirectoryUsersListProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DirectoryUsersListProjection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "basic" => DirectoryUsersListProjection::Basic,
+            "custom" => DirectoryUsersListProjection::Custom,
+            "full" => DirectoryUsersListProjection::Full,
+            _ => DirectoryUsersListProjection::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DirectoryUsersListProjection {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DirectoryUsersListProjection> for String {
+    fn from(v: DirectoryUsersListProjection) -> Self {
+        match v {
+            DirectoryUsersListProjection::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ViewType {
+    #[serde(rename = "admin_view")]
+    AdminView,
+    #[serde(rename = "domain_public")]
+    DomainPublic,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ViewType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ViewType::AdminView => "admin_view",
+            ViewType::DomainPublic => "domain_public",
+            ViewType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ViewType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ViewType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin_view" => ViewType::AdminView,
+            "domain_public" => ViewType::DomainPublic,
+            _ => ViewType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ViewType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ViewType> for String {
+    fn from(v: ViewType) -> Self {
+        match v {
+            ViewType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Column to use for sorting results
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum DirectoryUsersWatchOrderBy {
+    #[serde(rename = "email")]
+    Email,
+    #[serde(rename = "familyName")]
+    FamilyName,
+    #[serde(rename = "givenName")]
+    GivenName,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for DirectoryUsersWatchOrderBy {
+    fn as_ref(&self) -> &str {
+        match self {
+            DirectoryUsersWatchOrderBy::Email => "email",
+            DirectoryUsersWatchOrderBy::FamilyName => "familyName",
+            DirectoryUsersWatchOrderBy::GivenName => "givenName",
+            DirectoryUsersWatchOrderBy::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DirectoryUsersWatchOrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DirectoryUsersWatchOrderBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "email" => DirectoryUsersWatchOrderBy::Email,
+            "familyName" => DirectoryUsersWatchOrderBy::FamilyName,
+            "givenName" => DirectoryUsersWatchOrderBy::GivenName,
+            _ => DirectoryUsersWatchOrderBy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DirectoryUsersWatchOrderBy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DirectoryUsersWatchOrderBy> for String {
+    fn from(v: DirectoryUsersWatchOrderBy) -> Self {
+        match v {
+            DirectoryUsersWatchOrderBy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * What subset of fields to fetch for this user.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum DirectoryUsersWatchProjection {
+    #[serde(rename = "basic")]
+    Basic,
+    #[serde(rename = "custom")]
+    Custom,
+    #[serde(rename = "full")]
+    Full,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for DirectoryUsersWatchProjection {
+    fn as_ref(&self) -> &str {
+        match self {
+            DirectoryUsersWatchProjection::Basic => "basic",
+            DirectoryUsersWatchProjection::Custom => "custom",
+            DirectoryUsersWatchProjection::Full => "full",
+            DirectoryUsersWatchProjection::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DirectoryUsersWatchProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DirectoryUsersWatchProjection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "basic" => DirectoryUsersWatchProjection::Basic,
+            "custom" => DirectoryUsersWatchProjection::Custom,
+            "full" => DirectoryUsersWatchProjection::Full,
+            _ => DirectoryUsersWatchProjection::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DirectoryUsersWatchProjection {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DirectoryUsersWatchProjection> for String {
+    fn from(v: DirectoryUsersWatchProjection) -> Self {
+        match v {
+            DirectoryUsersWatchProjection::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * What subset of fields to fetch for this user.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum DirectoryUsersGetProjection {
+    #[serde(rename = "basic")]
+    Basic,
+    #[serde(rename = "custom")]
+    Custom,
+    #[serde(rename = "full")]
+    Full,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for DirectoryUsersGetProjection {
+    fn as_ref(&self) -> &str {
+        match self {
+            DirectoryUsersGetProjection::Basic => "basic",
+            DirectoryUsersGetProjection::Custom => "custom",
+            DirectoryUsersGetProjection::Full => "full",
+            DirectoryUsersGetProjection::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DirectoryUsersGetProjection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DirectoryUsersGetProjection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "basic" => DirectoryUsersGetProjection::Basic,
+            "custom" => DirectoryUsersGetProjection::Custom,
+            "full" => DirectoryUsersGetProjection::Full,
+            _ => DirectoryUsersGetProjection::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DirectoryUsersGetProjection {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DirectoryUsersGetProjection> for String {
+    fn from(v: DirectoryUsersGetProjection) -> Self {
+        match v {
+            DirectoryUsersGetProjection::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Events to watch for.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum DirectoryUsersAliasesListEvent {
+    #[serde(rename = "add")]
+    Add,
+    #[serde(rename = "delete")]
+    Delete,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for DirectoryUsersAliasesListEvent {
+    fn as_ref(&self) -> &str {
+        match self {
+            DirectoryUsersAliasesListEvent::Add => "add",
+            DirectoryUsersAliasesListEvent::Delete => "delete",
+            DirectoryUsersAliasesListEvent::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DirectoryUsersAliasesListEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DirectoryUsersAliasesListEvent {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "add" => DirectoryUsersAliasesListEvent::Add,
+            "delete" => DirectoryUsersAliasesListEvent::Delete,
+            _ => DirectoryUsersAliasesListEvent::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DirectoryUsersAliasesListEvent {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DirectoryUsersAliasesListEvent> for String {
+    fn from(v: DirectoryUsersAliasesListEvent) -> Self {
+        match v {
+            DirectoryUsersAliasesListEvent::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Events to watch for.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum DirectoryUsersAliasesWatchEvent {
+    #[serde(rename = "add")]
+    Add,
+    #[serde(rename = "delete")]
+    Delete,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for DirectoryUsersAliasesWatchEvent {
+    fn as_ref(&self) -> &str {
+        match self {
+            DirectoryUsersAliasesWatchEvent::Add => "add",
+            DirectoryUsersAliasesWatchEvent::Delete => "delete",
+            DirectoryUsersAliasesWatchEvent::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DirectoryUsersAliasesWatchEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DirectoryUsersAliasesWatchEvent {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "add" => DirectoryUsersAliasesWatchEvent::Add,
+            "delete" => DirectoryUsersAliasesWatchEvent::Delete,
+            _ => DirectoryUsersAliasesWatchEvent::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DirectoryUsersAliasesWatchEvent {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DirectoryUsersAliasesWatchEvent> for String {
+    fn from(v: DirectoryUsersAliasesWatchEvent) -> Self {
+        match v {
+            DirectoryUsersAliasesWatchEvent::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Users {
     pub client: Client,
@@ -19,65 +17,41 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `custom_field_mask: &str` -- A comma-separated list of schema names. All fields from these schemas are fetched. This should only be set when `projection=custom`.
-     * * `customer: &str` -- The unique ID for the customer's Google Workspace account. In case of a multi-domain account, to fetch all groups for a customer, fill this field instead of domain. You can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users). Either the `customer` or the `domain` parameter must be provided.
-     * * `domain: &str` -- The domain name. Use this field to get fields from only one domain. To return all domains for a customer account, use the `customer` query parameter instead. Either the `customer` or the `domain` parameter must be provided.
-     * * `event: crate::types::Event` -- Event on which subscription is intended (if subscribing).
-     * * `max_results: i64` -- Maximum number of results to return.
-     * * `order_by: crate::types::DirectoryUsersListOrderBy` -- Property to use for sorting results.
-     * * `page_token: &str` -- Token to specify next page in the list.
-     * * `projection: crate::types::DirectoryUsersListProjection` -- What subset of fields to fetch for this user.
-     * * `query: &str` -- Query string for searching user fields. For more information on constructing user queries, see [Search for Users](/admin-sdk/directory/v1/guides/search-users).
-     * * `show_deleted: &str` -- If set to `true`, retrieves the list of deleted users. (Default: `false`).
-     * * `sort_order: crate::types::SortOrder` -- Whether to return results in ascending or descending order. Must be used with the `orderBy` parameter.
-     * * `view_type: crate::types::ViewType` -- Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
+     * * `params: &UsersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
-        &self,
-        customer: &str,
-        domain: &str,
-        event: crate::types::Event,
-        max_results: i64,
-        order_by: crate::types::DirectoryUsersListOrderBy,
-        page_token: &str,
-        projection: crate::types::DirectoryUsersListProjection,
-        query: &str,
-        show_deleted: &str,
-        sort_order: crate::types::SortOrder,
-        view_type: crate::types::ViewType,
-    ) -> Result<Vec<crate::types::User>> {
+    pub async fn list_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::User>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !domain.is_empty() {
+        if let Some(domain) = &params.domain {
             query_args.push(("domain".to_string(), domain.to_string()));
         }
-        if !event.to_string().is_empty() {
+        if let Some(event) = &params.event {
             query_args.push(("event".to_string(), event.to_string()));
         }
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !order_by.to_string().is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !projection.to_string().is_empty() {
+        if let Some(projection) = &params.projection {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
-        if !show_deleted.is_empty() {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
-        if !sort_order.to_string().is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sortOrder".to_string(), sort_order.to_string()));
         }
-        if !view_type.to_string().is_empty() {
+        if let Some(view_type) = &params.view_type {
             query_args.push(("viewType".to_string(), view_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -90,50 +64,77 @@ impl Users {
     }
 
     /**
-     * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
-     *
-     * As opposed to `list`, this function returns all the pages of the request at once.
-     *
-     * Retrieves a paginated list of either deleted users or all users in a domain.
+     * Deprecated: use `list_with` and pass the query parameters as a `UsersListParams` instead.
      */
-    pub async fn list_all(
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
         &self,
         customer: &str,
         domain: &str,
         event: crate::types::Event,
+        max_results: i64,
         order_by: crate::types::DirectoryUsersListOrderBy,
+        page_token: &str,
         projection: crate::types::DirectoryUsersListProjection,
         query: &str,
         show_deleted: &str,
         sort_order: crate::types::SortOrder,
         view_type: crate::types::ViewType,
     ) -> Result<Vec<crate::types::User>> {
+        let params = UsersListParams {
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            domain: Some(domain.to_string()).filter(|s| !s.is_empty()),
+            event: Some(event).filter(|v| !v.to_string().is_empty()),
+            max_results: Some(max_results).filter(|v| *v > 0),
+            order_by: Some(order_by).filter(|v| !v.to_string().is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            projection: Some(projection).filter(|v| !v.to_string().is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            show_deleted: Some(show_deleted.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order).filter(|v| !v.to_string().is_empty()),
+            view_type: Some(view_type).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Retrieves a paginated list of either deleted users or all users in a domain.
+     *
+     * **Parameters:**
+     *
+     * * `params: &UsersListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::User>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !domain.is_empty() {
+        if let Some(domain) = &params.domain {
             query_args.push(("domain".to_string(), domain.to_string()));
         }
-        if !event.to_string().is_empty() {
+        if let Some(event) = &params.event {
             query_args.push(("event".to_string(), event.to_string()));
         }
-        if !order_by.to_string().is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !projection.to_string().is_empty() {
+        if let Some(projection) = &params.projection {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
-        if !show_deleted.is_empty() {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
-        if !sort_order.to_string().is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sortOrder".to_string(), sort_order.to_string()));
         }
-        if !view_type.to_string().is_empty() {
+        if let Some(view_type) = &params.view_type {
             query_args.push(("viewType".to_string(), view_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -146,6 +147,7 @@ impl Users {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -171,6 +173,101 @@ impl Users {
         Ok(users)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `UsersListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        order_by: crate::types::DirectoryUsersListOrderBy,
+        projection: crate::types::DirectoryUsersListProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+    ) -> Result<Vec<crate::types::User>> {
+        let params = UsersListParams {
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            domain: Some(domain.to_string()).filter(|s| !s.is_empty()),
+            event: Some(event).filter(|v| !v.to_string().is_empty()),
+            order_by: Some(order_by).filter(|v| !v.to_string().is_empty()),
+            projection: Some(projection).filter(|v| !v.to_string().is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            show_deleted: Some(show_deleted.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order).filter(|v| !v.to_string().is_empty()),
+            view_type: Some(view_type).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin/directory/v1/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieves a paginated list of either deleted users or all users in a domain.
+     */
+    pub fn list_stream(
+        &self,
+        params: &UsersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::User> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(customer) = &params.customer {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if let Some(domain) = &params.domain {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        if let Some(event) = &params.event {
+            query_args.push(("event".to_string(), event.to_string()));
+        }
+        if let Some(order_by) = &params.order_by {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if let Some(projection) = &params.projection {
+            query_args.push(("projection".to_string(), projection.to_string()));
+        }
+        if let Some(query) = &params.query {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if let Some(show_deleted) = &params.show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if let Some(sort_order) = &params.sort_order {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        if let Some(view_type) = &params.view_type {
+            query_args.push(("viewType".to_string(), view_type.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/admin/directory/v1/users?{}", query_);
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::Users = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.users,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users` endpoint.
      *
@@ -190,66 +287,45 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `custom_field_mask: &str` -- Comma-separated list of schema names. All fields from these schemas are fetched. This should only be set when projection=custom.
-     * * `customer: &str` -- Immutable ID of the Google Workspace account. In case of multi-domain, to fetch all users for a customer, fill this field instead of domain.
-     * * `domain: &str` -- Name of the domain. Fill this field to get users from only this domain. To return all users in a multi-domain fill customer field instead.".
-     * * `event: crate::types::Event` -- Event on which subscription is intended (if subscribing).
-     * * `max_results: i64` -- Maximum number of results to return.
-     * * `order_by: crate::types::DirectoryUsersListOrderBy` -- Property to use for sorting results.
-     * * `page_token: &str` -- Token to specify next page in the list.
-     * * `projection: crate::types::DirectoryUsersListProjection` -- What subset of fields to fetch for this user.
-     * * `query: &str` -- Query string search. Should be of the form "". Complete documentation is at https: //developers.google.com/admin-sdk/directory/v1/guides/search-users.
-     * * `show_deleted: &str` -- If set to true, retrieves the list of deleted users. (Default: false).
-     * * `sort_order: crate::types::SortOrder` -- Whether to return results in ascending or descending order. Must be used with the `orderBy` parameter.
-     * * `view_type: crate::types::ViewType` -- Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
+     * * `params: &UsersWatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn watch(
+    pub async fn watch_with(
         &self,
-        customer: &str,
-        domain: &str,
-        event: crate::types::Event,
-        max_results: i64,
-        order_by: crate::types::DirectoryUsersListOrderBy,
-        page_token: &str,
-        projection: crate::types::DirectoryUsersListProjection,
-        query: &str,
-        show_deleted: &str,
-        sort_order: crate::types::SortOrder,
-        view_type: crate::types::ViewType,
+        params: &UsersWatchParams,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !domain.is_empty() {
+        if let Some(domain) = &params.domain {
             query_args.push(("domain".to_string(), domain.to_string()));
         }
-        if !event.to_string().is_empty() {
+        if let Some(event) = &params.event {
             query_args.push(("event".to_string(), event.to_string()));
         }
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !order_by.to_string().is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !projection.to_string().is_empty() {
+        if let Some(projection) = &params.projection {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
-        if !show_deleted.is_empty() {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
-        if !sort_order.to_string().is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sortOrder".to_string(), sort_order.to_string()));
         }
-        if !view_type.to_string().is_empty() {
+        if let Some(view_type) = &params.view_type {
             query_args.push(("viewType".to_string(), view_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -260,6 +336,41 @@ impl Users {
             .await
     }
 
+    /**
+     * Deprecated: use `watch_with` and pass the query parameters as a `UsersWatchParams` instead.
+     */
+    #[deprecated(note = "use `watch_with` instead")]
+    pub async fn watch(
+        &self,
+        customer: &str,
+        domain: &str,
+        event: crate::types::Event,
+        max_results: i64,
+        order_by: crate::types::DirectoryUsersWatchOrderBy,
+        page_token: &str,
+        projection: crate::types::DirectoryUsersWatchProjection,
+        query: &str,
+        show_deleted: &str,
+        sort_order: crate::types::SortOrder,
+        view_type: crate::types::ViewType,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        let params = UsersWatchParams {
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            domain: Some(domain.to_string()).filter(|s| !s.is_empty()),
+            event: Some(event).filter(|v| !v.to_string().is_empty()),
+            max_results: Some(max_results).filter(|v| *v > 0),
+            order_by: Some(order_by).filter(|v| !v.to_string().is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            projection: Some(projection).filter(|v| !v.to_string().is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            show_deleted: Some(show_deleted.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order).filter(|v| !v.to_string().is_empty()),
+            view_type: Some(view_type).filter(|v| !v.to_string().is_empty()),
+        };
+        self.watch_with(&params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/admin/directory/v1/users/{userKey}` endpoint.
      *
@@ -268,21 +379,18 @@ impl Users {
      * **Parameters:**
      *
      * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
-     * * `custom_field_mask: &str` -- A comma-separated list of schema names. All fields from these schemas are fetched. This should only be set when `projection=custom`.
-     * * `projection: crate::types::DirectoryUsersListProjection` -- What subset of fields to fetch for this user.
-     * * `view_type: crate::types::ViewType` -- Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
+     * * `params: &UsersGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         user_key: &str,
-        projection: crate::types::DirectoryUsersListProjection,
-        view_type: crate::types::ViewType,
+        params: &UsersGetParams,
     ) -> Result<crate::types::User> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !projection.to_string().is_empty() {
+        if let Some(projection) = &params.projection {
             query_args.push(("projection".to_string(), projection.to_string()));
         }
-        if !view_type.to_string().is_empty() {
+        if let Some(view_type) = &params.view_type {
             query_args.push(("viewType".to_string(), view_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -295,6 +403,23 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `UsersGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        user_key: &str,
+        projection: crate::types::DirectoryUsersGetProjection,
+        view_type: crate::types::ViewType,
+    ) -> Result<crate::types::User> {
+        let params = UsersGetParams {
+            projection: Some(projection).filter(|v| !v.to_string().is_empty()),
+            view_type: Some(view_type).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_with(user_key, &params).await
+    }
+
     /**
      * This function performs a `PUT` to the `/admin/directory/v1/users/{userKey}` endpoint.
      *
@@ -369,15 +494,15 @@ impl Users {
      * **Parameters:**
      *
      * * `user_key: &str` -- Identifies the user in the API request. The value can be the user's primary email address, alias email address, or unique user ID.
-     * * `event: crate::types::DirectoryUsersAliasesListEvent` -- Events to watch for.
+     * * `params: &UsersAliasesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn aliases_list(
+    pub async fn aliases_list_with(
         &self,
         user_key: &str,
-        event: crate::types::DirectoryUsersAliasesListEvent,
+        params: &UsersAliasesListParams,
     ) -> Result<crate::types::Aliases> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !event.to_string().is_empty() {
+        if let Some(event) = &params.event {
             query_args.push(("event".to_string(), event.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -390,6 +515,21 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `aliases_list_with` and pass the query parameters as a `UsersAliasesListParams` instead.
+     */
+    #[deprecated(note = "use `aliases_list_with` instead")]
+    pub async fn aliases_list(
+        &self,
+        user_key: &str,
+        event: crate::types::DirectoryUsersAliasesListEvent,
+    ) -> Result<crate::types::Aliases> {
+        let params = UsersAliasesListParams {
+            event: Some(event).filter(|v| !v.to_string().is_empty()),
+        };
+        self.aliases_list_with(user_key, &params).await
+    }
+
     /**
      * This function performs a `POST` to the `/admin/directory/v1/users/{userKey}/aliases` endpoint.
      *
@@ -422,16 +562,16 @@ impl Users {
      * **Parameters:**
      *
      * * `user_key: &str` -- Email or immutable ID of the user.
-     * * `event: crate::types::DirectoryUsersAliasesListEvent` -- Events to watch for.
+     * * `params: &UsersAliasesWatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn aliases_watch(
+    pub async fn aliases_watch_with(
         &self,
         user_key: &str,
-        event: crate::types::DirectoryUsersAliasesListEvent,
+        params: &UsersAliasesWatchParams,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !event.to_string().is_empty() {
+        if let Some(event) = &params.event {
             query_args.push(("event".to_string(), event.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -446,6 +586,22 @@ impl Users {
             .await
     }
 
+    /**
+     * Deprecated: use `aliases_watch_with` and pass the query parameters as a `UsersAliasesWatchParams` instead.
+     */
+    #[deprecated(note = "use `aliases_watch_with` instead")]
+    pub async fn aliases_watch(
+        &self,
+        user_key: &str,
+        event: crate::types::DirectoryUsersAliasesWatchEvent,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        let params = UsersAliasesWatchParams {
+            event: Some(event).filter(|v| !v.to_string().is_empty()),
+        };
+        self.aliases_watch_with(user_key, &params, body).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/admin/directory/v1/users/{userKey}/aliases/{alias}` endpoint.
      *
@@ -612,3 +768,439 @@ impl Users {
             .await
     }
 }
+
+/// The query parameters of `Users::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListParams {
+    /// The unique ID for the customer's Google Workspace account. In case of a multi-domain account, to fetch all groups for a customer, fill this field instead of domain. You can also use the `my_customer` alias to represent your account's `customerId`. The `customerId` is also returned as part of the [Users resource](/admin-sdk/directory/v1/reference/users). Either the `customer` or the `domain` parameter must be provided.
+    pub customer: Option<String>,
+    /// The domain name. Use this field to get fields from only one domain. To return all domains for a customer account, use the `customer` query parameter instead. Either the `customer` or the `domain` parameter must be provided.
+    pub domain: Option<String>,
+    /// Event on which subscription is intended (if subscribing).
+    pub event: Option<crate::types::Event>,
+    /// Maximum number of results to return.
+    pub max_results: Option<i64>,
+    /// Property to use for sorting results.
+    pub order_by: Option<crate::types::DirectoryUsersListOrderBy>,
+    /// Token to specify next page in the list.
+    pub page_token: Option<String>,
+    /// What subset of fields to fetch for this user.
+    pub projection: Option<crate::types::DirectoryUsersListProjection>,
+    /// Query string for searching user fields. For more information on constructing user queries, see [Search for Users](/admin-sdk/directory/v1/guides/search-users).
+    pub query: Option<String>,
+    /// If set to `true`, retrieves the list of deleted users. (Default: `false`).
+    pub show_deleted: Option<String>,
+    /// Whether to return results in ascending or descending order.
+    pub sort_order: Option<crate::types::SortOrder>,
+    /// Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
+    pub view_type: Option<crate::types::ViewType>,
+}
+
+impl UsersListParams {
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn domain<T: ToString>(mut self, domain: T) -> Self {
+        self.domain = Some(domain.to_string());
+        self
+    }
+
+    pub fn event(mut self, event: crate::types::Event) -> Self {
+        self.event = Some(event);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn order_by(mut self, order_by: crate::types::DirectoryUsersListOrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn projection(mut self, projection: crate::types::DirectoryUsersListProjection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+
+    pub fn show_deleted<T: ToString>(mut self, show_deleted: T) -> Self {
+        self.show_deleted = Some(show_deleted.to_string());
+        self
+    }
+
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    pub fn view_type(mut self, view_type: crate::types::ViewType) -> Self {
+        self.view_type = Some(view_type);
+        self
+    }
+}
+
+/// The query parameters of `Users::watch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersWatchParams {
+    /// Immutable ID of the Google Workspace account. In case of multi-domain, to fetch all users for a customer, fill this field instead of domain.
+    pub customer: Option<String>,
+    /// Name of the domain. Fill this field to get users from only this domain. To return all users in a multi-domain fill customer field instead.".
+    pub domain: Option<String>,
+    /// Events to watch for.
+    pub event: Option<crate::types::Event>,
+    /// Maximum number of results to return.
+    pub max_results: Option<i64>,
+    /// Column to use for sorting results.
+    pub order_by: Option<crate::types::DirectoryUsersWatchOrderBy>,
+    /// Token to specify next page in the list.
+    pub page_token: Option<String>,
+    /// What subset of fields to fetch for this user.
+    pub projection: Option<crate::types::DirectoryUsersWatchProjection>,
+    /// Query string search. Should be of the form "". Complete documentation is at https: //developers.google.com/admin-sdk/directory/v1/guides/search-users.
+    pub query: Option<String>,
+    /// If set to true, retrieves the list of deleted users. (Default: false).
+    pub show_deleted: Option<String>,
+    /// Whether to return results in ascending or descending order.
+    pub sort_order: Option<crate::types::SortOrder>,
+    /// Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
+    pub view_type: Option<crate::types::ViewType>,
+}
+
+impl UsersWatchParams {
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn domain<T: ToString>(mut self, domain: T) -> Self {
+        self.domain = Some(domain.to_string());
+        self
+    }
+
+    pub fn event(mut self, event: crate::types::Event) -> Self {
+        self.event = Some(event);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn order_by(mut self, order_by: crate::types::DirectoryUsersWatchOrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn projection(mut self, projection: crate::types::DirectoryUsersWatchProjection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+
+    pub fn show_deleted<T: ToString>(mut self, show_deleted: T) -> Self {
+        self.show_deleted = Some(show_deleted.to_string());
+        self
+    }
+
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    pub fn view_type(mut self, view_type: crate::types::ViewType) -> Self {
+        self.view_type = Some(view_type);
+        self
+    }
+}
+
+/// The query parameters of `Users::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersGetParams {
+    /// What subset of fields to fetch for this user.
+    pub projection: Option<crate::types::DirectoryUsersGetProjection>,
+    /// Whether to fetch the administrator-only or domain-wide public view of the user. For more information, see [Retrieve a user as a non-administrator](/admin-sdk/directory/v1/guides/manage-users#retrieve_users_non_admin).
+    pub view_type: Option<crate::types::ViewType>,
+}
+
+impl UsersGetParams {
+    pub fn projection(mut self, projection: crate::types::DirectoryUsersGetProjection) -> Self {
+        self.projection = Some(projection);
+        self
+    }
+
+    pub fn view_type(mut self, view_type: crate::types::ViewType) -> Self {
+        self.view_type = Some(view_type);
+        self
+    }
+}
+
+/// The query parameters of `Users::aliases_list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersAliasesListParams {
+    /// Events to watch for.
+    pub event: Option<crate::types::DirectoryUsersAliasesListEvent>,
+}
+
+impl UsersAliasesListParams {
+    pub fn event(mut self, event: crate::types::DirectoryUsersAliasesListEvent) -> Self {
+        self.event = Some(event);
+        self
+    }
+}
+
+/// The query parameters of `Users::aliases_watch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersAliasesWatchParams {
+    /// Events to watch for.
+    pub event: Option<crate::types::DirectoryUsersAliasesWatchEvent>,
+}
+
+impl UsersAliasesWatchParams {
+    pub fn event(mut self, event: crate::types::DirectoryUsersAliasesWatchEvent) -> Self {
+        self.event = Some(event);
+        self
+    }
+}
+
+/// The functions of [`Users`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUsersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UsersApi: Send + Sync {
+    /// See [`Users::list_with`].
+    async fn list_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::User>>;
+
+    /// See [`Users::list_all_with`].
+    async fn list_all_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::User>>;
+
+    /// See [`Users::list_stream`].
+    fn list_stream(
+        &self,
+        params: &UsersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::User>;
+
+    /// See [`Users::insert`].
+    async fn insert(&self, body: &crate::types::User) -> Result<crate::types::User>;
+
+    /// See [`Users::watch_with`].
+    async fn watch_with(
+        &self,
+        params: &UsersWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+
+    /// See [`Users::get_with`].
+    async fn get_with(&self, user_key: &str, params: &UsersGetParams)
+        -> Result<crate::types::User>;
+
+    /// See [`Users::update`].
+    async fn update(&self, user_key: &str, body: &crate::types::User)
+        -> Result<crate::types::User>;
+
+    /// See [`Users::delete`].
+    async fn delete(&self, user_key: &str) -> Result<()>;
+
+    /// See [`Users::patch`].
+    async fn patch(&self, user_key: &str, body: &crate::types::User) -> Result<crate::types::User>;
+
+    /// See [`Users::aliases_list_with`].
+    async fn aliases_list_with(
+        &self,
+        user_key: &str,
+        params: &UsersAliasesListParams,
+    ) -> Result<crate::types::Aliases>;
+
+    /// See [`Users::aliases_insert`].
+    async fn aliases_insert(
+        &self,
+        user_key: &str,
+        body: &crate::types::Alias,
+    ) -> Result<crate::types::Alias>;
+
+    /// See [`Users::aliases_watch_with`].
+    async fn aliases_watch_with(
+        &self,
+        user_key: &str,
+        params: &UsersAliasesWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+
+    /// See [`Users::aliases_delete`].
+    async fn aliases_delete(&self, user_key: &str, alias: &str) -> Result<()>;
+
+    /// See [`Users::make_admin`].
+    async fn make_admin(&self, user_key: &str, body: &crate::types::UserMakeAdmin) -> Result<()>;
+
+    /// See [`Users::photos_get`].
+    async fn photos_get(&self, user_key: &str) -> Result<crate::types::UserPhoto>;
+
+    /// See [`Users::photos_update`].
+    async fn photos_update(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> Result<crate::types::UserPhoto>;
+
+    /// See [`Users::photos_delete`].
+    async fn photos_delete(&self, user_key: &str) -> Result<()>;
+
+    /// See [`Users::photos_patch`].
+    async fn photos_patch(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> Result<crate::types::UserPhoto>;
+
+    /// See [`Users::sign_out`].
+    async fn sign_out(&self, user_key: &str) -> Result<()>;
+
+    /// See [`Users::undelete`].
+    async fn undelete(&self, user_key: &str, body: &crate::types::UserUndelete) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl UsersApi for Users {
+    async fn list_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::User>> {
+        Users::list_with(self, params).await
+    }
+
+    async fn list_all_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::User>> {
+        Users::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &UsersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::User> {
+        Users::list_stream(self, params, start, max_items)
+    }
+
+    async fn insert(&self, body: &crate::types::User) -> Result<crate::types::User> {
+        Users::insert(self, body).await
+    }
+
+    async fn watch_with(
+        &self,
+        params: &UsersWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Users::watch_with(self, params, body).await
+    }
+
+    async fn get_with(
+        &self,
+        user_key: &str,
+        params: &UsersGetParams,
+    ) -> Result<crate::types::User> {
+        Users::get_with(self, user_key, params).await
+    }
+
+    async fn update(
+        &self,
+        user_key: &str,
+        body: &crate::types::User,
+    ) -> Result<crate::types::User> {
+        Users::update(self, user_key, body).await
+    }
+
+    async fn delete(&self, user_key: &str) -> Result<()> {
+        Users::delete(self, user_key).await
+    }
+
+    async fn patch(&self, user_key: &str, body: &crate::types::User) -> Result<crate::types::User> {
+        Users::patch(self, user_key, body).await
+    }
+
+    async fn aliases_list_with(
+        &self,
+        user_key: &str,
+        params: &UsersAliasesListParams,
+    ) -> Result<crate::types::Aliases> {
+        Users::aliases_list_with(self, user_key, params).await
+    }
+
+    async fn aliases_insert(
+        &self,
+        user_key: &str,
+        body: &crate::types::Alias,
+    ) -> Result<crate::types::Alias> {
+        Users::aliases_insert(self, user_key, body).await
+    }
+
+    async fn aliases_watch_with(
+        &self,
+        user_key: &str,
+        params: &UsersAliasesWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Users::aliases_watch_with(self, user_key, params, body).await
+    }
+
+    async fn aliases_delete(&self, user_key: &str, alias: &str) -> Result<()> {
+        Users::aliases_delete(self, user_key, alias).await
+    }
+
+    async fn make_admin(&self, user_key: &str, body: &crate::types::UserMakeAdmin) -> Result<()> {
+        Users::make_admin(self, user_key, body).await
+    }
+
+    async fn photos_get(&self, user_key: &str) -> Result<crate::types::UserPhoto> {
+        Users::photos_get(self, user_key).await
+    }
+
+    async fn photos_update(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> Result<crate::types::UserPhoto> {
+        Users::photos_update(self, user_key, body).await
+    }
+
+    async fn photos_delete(&self, user_key: &str) -> Result<()> {
+        Users::photos_delete(self, user_key).await
+    }
+
+    async fn photos_patch(
+        &self,
+        user_key: &str,
+        body: &crate::types::UserPhoto,
+    ) -> Result<crate::types::UserPhoto> {
+        Users::photos_patch(self, user_key, body).await
+    }
+
+    async fn sign_out(&self, user_key: &str) -> Result<()> {
+        Users::sign_out(self, user_key).await
+    }
+
+    async fn undelete(&self, user_key: &str, body: &crate::types::UserUndelete) -> Result<()> {
+        Users::undelete(self, user_key, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct VerificationCodes {
     pub client: Client,
@@ -66,3 +64,33 @@ impl VerificationCodes {
         self.client.post(&url, None).await
     }
 }
+
+/// The functions of [`VerificationCodes`], for code that takes them as a trait, so that it can be
+/// tested with the `MockVerificationCodesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait VerificationCodesApi: Send + Sync {
+    /// See [`VerificationCodes::list`].
+    async fn list(&self, user_key: &str) -> Result<crate::types::VerificationCodes>;
+
+    /// See [`VerificationCodes::generate`].
+    async fn generate(&self, user_key: &str) -> Result<()>;
+
+    /// See [`VerificationCodes::invalidate`].
+    async fn invalidate(&self, user_key: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl VerificationCodesApi for VerificationCodes {
+    async fn list(&self, user_key: &str) -> Result<crate::types::VerificationCodes> {
+        VerificationCodes::list(self, user_key).await
+    }
+
+    async fn generate(&self, user_key: &str) -> Result<()> {
+        VerificationCodes::generate(self, user_key).await
+    }
+
+    async fn invalidate(&self, user_key: &str) -> Result<()> {
+        VerificationCodes::invalidate(self, user_key).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Acl {
     pub client: Client,
@@ -20,29 +18,21 @@ impl Acl {
      * **Parameters:**
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
-     * * `max_results: i64` -- Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
-     * * `page_token: &str` -- Token specifying which result page to return. Optional.
-     * * `show_deleted: bool` -- Whether to include deleted ACLs in the result. Deleted ACLs are represented by role equal to "none". Deleted ACLs will always be included if syncToken is provided. Optional. The default is False.
-     * * `sync_token: &str` -- Token obtained from the nextSyncToken field returned on the last page of results from the previous list request. It makes the result of this list request contain only entries that have changed since then. All entries deleted since the previous list request will always be in the result set and it is not allowed to set showDeleted to False.
-     *   If the syncToken expires, the server will respond with a 410 GONE response code and the client should clear its storage and perform a full synchronization without any syncToken.
-     *   Learn more about incremental synchronization.
-     *   Optional. The default is to return all entries.
+     * * `params: &AclListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         calendar_id: &str,
-        max_results: i64,
-        page_token: &str,
-        show_deleted: bool,
+        params: &AclListParams,
     ) -> Result<Vec<crate::types::AclRule>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if show_deleted {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -58,20 +48,43 @@ impl Acl {
         Ok(resp.items.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `AclListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        calendar_id: &str,
+        max_results: i64,
+        page_token: &str,
+        show_deleted: bool,
+    ) -> Result<Vec<crate::types::AclRule>> {
+        let params = AclListParams {
+            max_results: Some(max_results).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            show_deleted: Some(show_deleted).filter(|v| *v),
+        };
+        self.list_with(calendar_id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/acl` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Returns the rules in the access control list for the calendar.
+     *
+     * **Parameters:**
+     *
+     * * `params: &AclListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(
+    pub async fn list_all_with(
         &self,
         calendar_id: &str,
-        show_deleted: bool,
+        params: &AclListParams,
     ) -> Result<Vec<crate::types::AclRule>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if show_deleted {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -88,6 +101,7 @@ impl Acl {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -113,6 +127,67 @@ impl Acl {
         Ok(items)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `AclListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        calendar_id: &str,
+        show_deleted: bool,
+    ) -> Result<Vec<crate::types::AclRule>> {
+        let params = AclListParams {
+            show_deleted: Some(show_deleted).filter(|v| *v),
+            ..Default::default()
+        };
+        self.list_all_with(calendar_id, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/acl` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns the rules in the access control list for the calendar.
+     */
+    pub fn list_stream(
+        &self,
+        calendar_id: &str,
+        params: &AclListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AclRule> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(show_deleted) = &params.show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/calendars/{}/acl?{}",
+            crate::progenitor_support::encode_path(calendar_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::Acl = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.items,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/acl` endpoint.
      *
@@ -121,16 +196,16 @@ impl Acl {
      * **Parameters:**
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
-     * * `send_notifications: bool` -- Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * * `params: &AclInsertParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn insert(
+    pub async fn insert_with(
         &self,
         calendar_id: &str,
-        send_notifications: bool,
+        params: &AclInsertParams,
         body: &crate::types::AclRule,
     ) -> Result<crate::types::AclRule> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if send_notifications {
+        if let Some(send_notifications) = &params.send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
                 send_notifications.to_string(),
@@ -148,6 +223,22 @@ impl Acl {
             .await
     }
 
+    /**
+     * Deprecated: use `insert_with` and pass the query parameters as a `AclInsertParams` instead.
+     */
+    #[deprecated(note = "use `insert_with` instead")]
+    pub async fn insert(
+        &self,
+        calendar_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule> {
+        let params = AclInsertParams {
+            send_notifications: Some(send_notifications).filter(|v| *v),
+        };
+        self.insert_with(calendar_id, &params, body).await
+    }
+
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/acl/watch` endpoint.
      *
@@ -156,30 +247,22 @@ impl Acl {
      * **Parameters:**
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
-     * * `max_results: i64` -- Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
-     * * `page_token: &str` -- Token specifying which result page to return. Optional.
-     * * `show_deleted: bool` -- Whether to include deleted ACLs in the result. Deleted ACLs are represented by role equal to "none". Deleted ACLs will always be included if syncToken is provided. Optional. The default is False.
-     * * `sync_token: &str` -- Token obtained from the nextSyncToken field returned on the last page of results from the previous list request. It makes the result of this list request contain only entries that have changed since then. All entries deleted since the previous list request will always be in the result set and it is not allowed to set showDeleted to False.
-     *   If the syncToken expires, the server will respond with a 410 GONE response code and the client should clear its storage and perform a full synchronization without any syncToken.
-     *   Learn more about incremental synchronization.
-     *   Optional. The default is to return all entries.
+     * * `params: &AclWatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn watch(
+    pub async fn watch_with(
         &self,
         calendar_id: &str,
-        max_results: i64,
-        page_token: &str,
-        show_deleted: bool,
+        params: &AclWatchParams,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if show_deleted {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -194,6 +277,26 @@ impl Acl {
             .await
     }
 
+    /**
+     * Deprecated: use `watch_with` and pass the query parameters as a `AclWatchParams` instead.
+     */
+    #[deprecated(note = "use `watch_with` instead")]
+    pub async fn watch(
+        &self,
+        calendar_id: &str,
+        max_results: i64,
+        page_token: &str,
+        show_deleted: bool,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        let params = AclWatchParams {
+            max_results: Some(max_results).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            show_deleted: Some(show_deleted).filter(|v| *v),
+        };
+        self.watch_with(calendar_id, &params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/acl/{ruleId}` endpoint.
      *
@@ -223,17 +326,17 @@ impl Acl {
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      * * `rule_id: &str` -- ETag of the collection.
-     * * `send_notifications: bool` -- Whether to send notifications about the calendar sharing change. Note that there are no notifications on access removal. Optional. The default is True.
+     * * `params: &AclUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update(
+    pub async fn update_with(
         &self,
         calendar_id: &str,
         rule_id: &str,
-        send_notifications: bool,
+        params: &AclUpdateParams,
         body: &crate::types::AclRule,
     ) -> Result<crate::types::AclRule> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if send_notifications {
+        if let Some(send_notifications) = &params.send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
                 send_notifications.to_string(),
@@ -252,6 +355,23 @@ impl Acl {
             .await
     }
 
+    /**
+     * Deprecated: use `update_with` and pass the query parameters as a `AclUpdateParams` instead.
+     */
+    #[deprecated(note = "use `update_with` instead")]
+    pub async fn update(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule> {
+        let params = AclUpdateParams {
+            send_notifications: Some(send_notifications).filter(|v| *v),
+        };
+        self.update_with(calendar_id, rule_id, &params, body).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/calendars/{calendarId}/acl/{ruleId}` endpoint.
      *
@@ -281,17 +401,17 @@ impl Acl {
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      * * `rule_id: &str` -- ETag of the collection.
-     * * `send_notifications: bool` -- Whether to send notifications about the calendar sharing change. Note that there are no notifications on access removal. Optional. The default is True.
+     * * `params: &AclPatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn patch(
+    pub async fn patch_with(
         &self,
         calendar_id: &str,
         rule_id: &str,
-        send_notifications: bool,
+        params: &AclPatchParams,
         body: &crate::types::AclRule,
     ) -> Result<crate::types::AclRule> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if send_notifications {
+        if let Some(send_notifications) = &params.send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
                 send_notifications.to_string(),
@@ -309,4 +429,263 @@ impl Acl {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `patch_with` and pass the query parameters as a `AclPatchParams` instead.
+     */
+    #[deprecated(note = "use `patch_with` instead")]
+    pub async fn patch(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+        send_notifications: bool,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule> {
+        let params = AclPatchParams {
+            send_notifications: Some(send_notifications).filter(|v| *v),
+        };
+        self.patch_with(calendar_id, rule_id, &params, body).await
+    }
+}
+
+/// The query parameters of `Acl::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AclListParams {
+    /// Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
+    pub max_results: Option<i64>,
+    /// Token specifying which result page to return. Optional.
+    pub page_token: Option<String>,
+    /// Whether to include deleted ACLs in the result. Deleted ACLs are represented by role equal to "none". Deleted ACLs will always be included if syncToken is provided. Optional. The default is False.
+    pub show_deleted: Option<bool>,
+}
+
+impl AclListParams {
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+}
+
+/// The query parameters of `Acl::insert_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AclInsertParams {
+    /// Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+    pub send_notifications: Option<bool>,
+}
+
+impl AclInsertParams {
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+}
+
+/// The query parameters of `Acl::watch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AclWatchParams {
+    /// Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
+    pub max_results: Option<i64>,
+    /// Token specifying which result page to return. Optional.
+    pub page_token: Option<String>,
+    /// Whether to include deleted ACLs in the result. Deleted ACLs are represented by role equal to "none". Deleted ACLs will always be included if syncToken is provided. Optional. The default is False.
+    pub show_deleted: Option<bool>,
+}
+
+impl AclWatchParams {
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+}
+
+/// The query parameters of `Acl::update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AclUpdateParams {
+    /// Whether to send notifications about the calendar sharing change. Note that there are no notifications on access removal. Optional. The default is True.
+    pub send_notifications: Option<bool>,
+}
+
+impl AclUpdateParams {
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+}
+
+/// The query parameters of `Acl::patch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AclPatchParams {
+    /// Whether to send notifications about the calendar sharing change. Note that there are no notifications on access removal. Optional. The default is True.
+    pub send_notifications: Option<bool>,
+}
+
+impl AclPatchParams {
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+}
+
+/// The functions of [`Acl`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAclApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AclApi: Send + Sync {
+    /// See [`Acl::list_with`].
+    async fn list_with(
+        &self,
+        calendar_id: &str,
+        params: &AclListParams,
+    ) -> Result<Vec<crate::types::AclRule>>;
+
+    /// See [`Acl::list_all_with`].
+    async fn list_all_with(
+        &self,
+        calendar_id: &str,
+        params: &AclListParams,
+    ) -> Result<Vec<crate::types::AclRule>>;
+
+    /// See [`Acl::list_stream`].
+    fn list_stream(
+        &self,
+        calendar_id: &str,
+        params: &AclListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AclRule>;
+
+    /// See [`Acl::insert_with`].
+    async fn insert_with(
+        &self,
+        calendar_id: &str,
+        params: &AclInsertParams,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule>;
+
+    /// See [`Acl::watch_with`].
+    async fn watch_with(
+        &self,
+        calendar_id: &str,
+        params: &AclWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+
+    /// See [`Acl::get`].
+    async fn get(&self, calendar_id: &str, rule_id: &str) -> Result<crate::types::AclRule>;
+
+    /// See [`Acl::update_with`].
+    async fn update_with(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+        params: &AclUpdateParams,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule>;
+
+    /// See [`Acl::delete`].
+    async fn delete(&self, calendar_id: &str, rule_id: &str) -> Result<()>;
+
+    /// See [`Acl::patch_with`].
+    async fn patch_with(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+        params: &AclPatchParams,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule>;
+}
+
+#[async_trait::async_trait]
+impl AclApi for Acl {
+    async fn list_with(
+        &self,
+        calendar_id: &str,
+        params: &AclListParams,
+    ) -> Result<Vec<crate::types::AclRule>> {
+        Acl::list_with(self, calendar_id, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        calendar_id: &str,
+        params: &AclListParams,
+    ) -> Result<Vec<crate::types::AclRule>> {
+        Acl::list_all_with(self, calendar_id, params).await
+    }
+
+    fn list_stream(
+        &self,
+        calendar_id: &str,
+        params: &AclListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AclRule> {
+        Acl::list_stream(self, calendar_id, params, start, max_items)
+    }
+
+    async fn insert_with(
+        &self,
+        calendar_id: &str,
+        params: &AclInsertParams,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule> {
+        Acl::insert_with(self, calendar_id, params, body).await
+    }
+
+    async fn watch_with(
+        &self,
+        calendar_id: &str,
+        params: &AclWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Acl::watch_with(self, calendar_id, params, body).await
+    }
+
+    async fn get(&self, calendar_id: &str, rule_id: &str) -> Result<crate::types::AclRule> {
+        Acl::get(self, calendar_id, rule_id).await
+    }
+
+    async fn update_with(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+        params: &AclUpdateParams,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule> {
+        Acl::update_with(self, calendar_id, rule_id, params, body).await
+    }
+
+    async fn delete(&self, calendar_id: &str, rule_id: &str) -> Result<()> {
+        Acl::delete(self, calendar_id, rule_id).await
+    }
+
+    async fn patch_with(
+        &self,
+        calendar_id: &str,
+        rule_id: &str,
+        params: &AclPatchParams,
+        body: &crate::types::AclRule,
+    ) -> Result<crate::types::AclRule> {
+        Acl::patch_with(self, calendar_id, rule_id, params, body).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CalendarList {
     pub client: Client,
@@ -19,39 +17,26 @@ impl CalendarList {
      *
      * **Parameters:**
      *
-     * * `max_results: i64` -- Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
-     * * `min_access_role: crate::types::MinAccessRole` -- The minimum access role for the user in the returned entries. Optional. The default is no restriction.
-     * * `page_token: &str` -- Token specifying which result page to return. Optional.
-     * * `show_deleted: bool` -- Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
-     * * `show_hidden: bool` -- Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
-     * * `sync_token: &str` -- Token obtained from the nextSyncToken field returned on the last page of results from the previous list request. It makes the result of this list request contain only entries that have changed since then. If only read-only fields such as calendar properties or ACLs have changed, the entry won't be returned. All entries deleted and hidden since the previous list request will always be in the result set and it is not allowed to set showDeleted neither showHidden to False.
-     *   To ensure client state consistency minAccessRole query parameter cannot be specified together with nextSyncToken.
-     *   If the syncToken expires, the server will respond with a 410 GONE response code and the client should clear its storage and perform a full synchronization without any syncToken.
-     *   Learn more about incremental synchronization.
-     *   Optional. The default is to return all entries.
+     * * `params: &CalendarListListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        max_results: i64,
-        min_access_role: crate::types::MinAccessRole,
-        page_token: &str,
-        show_deleted: bool,
-        show_hidden: bool,
+        params: &CalendarListListParams,
     ) -> Result<Vec<crate::types::CalendarListEntry>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !min_access_role.to_string().is_empty() {
+        if let Some(min_access_role) = &params.min_access_role {
             query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if show_deleted {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
-        if show_hidden {
+        if let Some(show_hidden) = &params.show_hidden {
             query_args.push(("showHidden".to_string(), show_hidden.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -63,27 +48,51 @@ impl CalendarList {
         Ok(resp.items.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `CalendarListListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        max_results: i64,
+        min_access_role: crate::types::MinAccessRole,
+        page_token: &str,
+        show_deleted: bool,
+        show_hidden: bool,
+    ) -> Result<Vec<crate::types::CalendarListEntry>> {
+        let params = CalendarListListParams {
+            max_results: Some(max_results).filter(|v| *v > 0),
+            min_access_role: Some(min_access_role).filter(|v| !v.to_string().is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            show_deleted: Some(show_deleted).filter(|v| *v),
+            show_hidden: Some(show_hidden).filter(|v| *v),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/users/me/calendarList` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Returns the calendars on the user's calendar list.
+     *
+     * **Parameters:**
+     *
+     * * `params: &CalendarListListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(
+    pub async fn list_all_with(
         &self,
-        min_access_role: crate::types::MinAccessRole,
-        show_deleted: bool,
-        show_hidden: bool,
+        params: &CalendarListListParams,
     ) -> Result<Vec<crate::types::CalendarListEntry>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !min_access_role.to_string().is_empty() {
+        if let Some(min_access_role) = &params.min_access_role {
             query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
         }
-        if show_deleted {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
-        if show_hidden {
+        if let Some(show_hidden) = &params.show_hidden {
             query_args.push(("showHidden".to_string(), show_hidden.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -96,6 +105,7 @@ impl CalendarList {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -121,6 +131,71 @@ impl CalendarList {
         Ok(items)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `CalendarListListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        min_access_role: crate::types::MinAccessRole,
+        show_deleted: bool,
+        show_hidden: bool,
+    ) -> Result<Vec<crate::types::CalendarListEntry>> {
+        let params = CalendarListListParams {
+            min_access_role: Some(min_access_role).filter(|v| !v.to_string().is_empty()),
+            show_deleted: Some(show_deleted).filter(|v| *v),
+            show_hidden: Some(show_hidden).filter(|v| *v),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/users/me/calendarList` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns the calendars on the user's calendar list.
+     */
+    pub fn list_stream(
+        &self,
+        params: &CalendarListListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CalendarListEntry> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(min_access_role) = &params.min_access_role {
+            query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
+        }
+        if let Some(show_deleted) = &params.show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if let Some(show_hidden) = &params.show_hidden {
+            query_args.push(("showHidden".to_string(), show_hidden.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/users/me/calendarList?{}", query_);
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::CalendarList = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.items,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/users/me/calendarList` endpoint.
      *
@@ -128,15 +203,15 @@ impl CalendarList {
      *
      * **Parameters:**
      *
-     * * `color_rgb_format: bool` -- Whether to use the foregroundColor and backgroundColor fields to write the calendar colors (RGB). If this feature is used, the index-based colorId field will be set to the best matching option automatically. Optional. The default is False.
+     * * `params: &CalendarListListInsertParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_insert(
+    pub async fn list_insert_with(
         &self,
-        color_rgb_format: bool,
+        params: &CalendarListListInsertParams,
         body: &crate::types::CalendarListEntry,
     ) -> Result<crate::types::CalendarListEntry> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if color_rgb_format {
+        if let Some(color_rgb_format) = &params.color_rgb_format {
             query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -147,6 +222,21 @@ impl CalendarList {
             .await
     }
 
+    /**
+     * Deprecated: use `list_insert_with` and pass the query parameters as a `CalendarListListInsertParams` instead.
+     */
+    #[deprecated(note = "use `list_insert_with` instead")]
+    pub async fn list_insert(
+        &self,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry> {
+        let params = CalendarListListInsertParams {
+            color_rgb_format: Some(color_rgb_format).filter(|v| *v),
+        };
+        self.list_insert_with(&params, body).await
+    }
+
     /**
      * This function performs a `POST` to the `/users/me/calendarList/watch` endpoint.
      *
@@ -154,40 +244,27 @@ impl CalendarList {
      *
      * **Parameters:**
      *
-     * * `max_results: i64` -- Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
-     * * `min_access_role: crate::types::MinAccessRole` -- The minimum access role for the user in the returned entries. Optional. The default is no restriction.
-     * * `page_token: &str` -- Token specifying which result page to return. Optional.
-     * * `show_deleted: bool` -- Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
-     * * `show_hidden: bool` -- Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
-     * * `sync_token: &str` -- Token obtained from the nextSyncToken field returned on the last page of results from the previous list request. It makes the result of this list request contain only entries that have changed since then. If only read-only fields such as calendar properties or ACLs have changed, the entry won't be returned. All entries deleted and hidden since the previous list request will always be in the result set and it is not allowed to set showDeleted neither showHidden to False.
-     *   To ensure client state consistency minAccessRole query parameter cannot be specified together with nextSyncToken.
-     *   If the syncToken expires, the server will respond with a 410 GONE response code and the client should clear its storage and perform a full synchronization without any syncToken.
-     *   Learn more about incremental synchronization.
-     *   Optional. The default is to return all entries.
+     * * `params: &CalendarListListWatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_watch(
+    pub async fn list_watch_with(
         &self,
-        max_results: i64,
-        min_access_role: crate::types::MinAccessRole,
-        page_token: &str,
-        show_deleted: bool,
-        show_hidden: bool,
+        params: &CalendarListListWatchParams,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !min_access_role.to_string().is_empty() {
+        if let Some(min_access_role) = &params.min_access_role {
             query_args.push(("minAccessRole".to_string(), min_access_role.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if show_deleted {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
-        if show_hidden {
+        if let Some(show_hidden) = &params.show_hidden {
             query_args.push(("showHidden".to_string(), show_hidden.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -198,6 +275,29 @@ impl CalendarList {
             .await
     }
 
+    /**
+     * Deprecated: use `list_watch_with` and pass the query parameters as a `CalendarListListWatchParams` instead.
+     */
+    #[deprecated(note = "use `list_watch_with` instead")]
+    pub async fn list_watch(
+        &self,
+        max_results: i64,
+        min_access_role: crate::types::MinAccessRole,
+        page_token: &str,
+        show_deleted: bool,
+        show_hidden: bool,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        let params = CalendarListListWatchParams {
+            max_results: Some(max_results).filter(|v| *v > 0),
+            min_access_role: Some(min_access_role).filter(|v| !v.to_string().is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            show_deleted: Some(show_deleted).filter(|v| *v),
+            show_hidden: Some(show_hidden).filter(|v| *v),
+        };
+        self.list_watch_with(&params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/users/me/calendarList/{calendarId}` endpoint.
      *
@@ -224,16 +324,16 @@ impl CalendarList {
      * **Parameters:**
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
-     * * `color_rgb_format: bool` -- Whether to use the foregroundColor and backgroundColor fields to write the calendar colors (RGB). If this feature is used, the index-based colorId field will be set to the best matching option automatically. Optional. The default is False.
+     * * `params: &CalendarListListUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_update(
+    pub async fn list_update_with(
         &self,
         calendar_id: &str,
-        color_rgb_format: bool,
+        params: &CalendarListListUpdateParams,
         body: &crate::types::CalendarListEntry,
     ) -> Result<crate::types::CalendarListEntry> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if color_rgb_format {
+        if let Some(color_rgb_format) = &params.color_rgb_format {
             query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -248,6 +348,22 @@ impl CalendarList {
             .await
     }
 
+    /**
+     * Deprecated: use `list_update_with` and pass the query parameters as a `CalendarListListUpdateParams` instead.
+     */
+    #[deprecated(note = "use `list_update_with` instead")]
+    pub async fn list_update(
+        &self,
+        calendar_id: &str,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry> {
+        let params = CalendarListListUpdateParams {
+            color_rgb_format: Some(color_rgb_format).filter(|v| *v),
+        };
+        self.list_update_with(calendar_id, &params, body).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/users/me/calendarList/{calendarId}` endpoint.
      *
@@ -274,16 +390,16 @@ impl CalendarList {
      * **Parameters:**
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
-     * * `color_rgb_format: bool` -- Whether to use the foregroundColor and backgroundColor fields to write the calendar colors (RGB). If this feature is used, the index-based colorId field will be set to the best matching option automatically. Optional. The default is False.
+     * * `params: &CalendarListListPatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_patch(
+    pub async fn list_patch_with(
         &self,
         calendar_id: &str,
-        color_rgb_format: bool,
+        params: &CalendarListListPatchParams,
         body: &crate::types::CalendarListEntry,
     ) -> Result<crate::types::CalendarListEntry> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if color_rgb_format {
+        if let Some(color_rgb_format) = &params.color_rgb_format {
             query_args.push(("colorRgbFormat".to_string(), color_rgb_format.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -297,4 +413,276 @@ impl CalendarList {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `list_patch_with` and pass the query parameters as a `CalendarListListPatchParams` instead.
+     */
+    #[deprecated(note = "use `list_patch_with` instead")]
+    pub async fn list_patch(
+        &self,
+        calendar_id: &str,
+        color_rgb_format: bool,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry> {
+        let params = CalendarListListPatchParams {
+            color_rgb_format: Some(color_rgb_format).filter(|v| *v),
+        };
+        self.list_patch_with(calendar_id, &params, body).await
+    }
+}
+
+/// The query parameters of `CalendarList::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CalendarListListParams {
+    /// Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
+    pub max_results: Option<i64>,
+    /// The minimum access role for the user in the returned entries. Optional. The default is no restriction.
+    pub min_access_role: Option<crate::types::MinAccessRole>,
+    /// Token specifying which result page to return. Optional.
+    pub page_token: Option<String>,
+    /// Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+    pub show_deleted: Option<bool>,
+    /// Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+    pub show_hidden: Option<bool>,
+}
+
+impl CalendarListListParams {
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn min_access_role(mut self, min_access_role: crate::types::MinAccessRole) -> Self {
+        self.min_access_role = Some(min_access_role);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = Some(show_hidden);
+        self
+    }
+}
+
+/// The query parameters of `CalendarList::list_insert_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CalendarListListInsertParams {
+    /// Whether to use the foregroundColor and backgroundColor fields to write the calendar colors (RGB). If this feature is used, the index-based colorId field will be set to the best matching option automatically. Optional. The default is False.
+    pub color_rgb_format: Option<bool>,
+}
+
+impl CalendarListListInsertParams {
+    pub fn color_rgb_format(mut self, color_rgb_format: bool) -> Self {
+        self.color_rgb_format = Some(color_rgb_format);
+        self
+    }
+}
+
+/// The query parameters of `CalendarList::list_watch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CalendarListListWatchParams {
+    /// Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
+    pub max_results: Option<i64>,
+    /// The minimum access role for the user in the returned entries. Optional. The default is no restriction.
+    pub min_access_role: Option<crate::types::MinAccessRole>,
+    /// Token specifying which result page to return. Optional.
+    pub page_token: Option<String>,
+    /// Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+    pub show_deleted: Option<bool>,
+    /// Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+    pub show_hidden: Option<bool>,
+}
+
+impl CalendarListListWatchParams {
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn min_access_role(mut self, min_access_role: crate::types::MinAccessRole) -> Self {
+        self.min_access_role = Some(min_access_role);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = Some(show_hidden);
+        self
+    }
+}
+
+/// The query parameters of `CalendarList::list_update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CalendarListListUpdateParams {
+    /// Whether to use the foregroundColor and backgroundColor fields to write the calendar colors (RGB). If this feature is used, the index-based colorId field will be set to the best matching option automatically. Optional. The default is False.
+    pub color_rgb_format: Option<bool>,
+}
+
+impl CalendarListListUpdateParams {
+    pub fn color_rgb_format(mut self, color_rgb_format: bool) -> Self {
+        self.color_rgb_format = Some(color_rgb_format);
+        self
+    }
+}
+
+/// The query parameters of `CalendarList::list_patch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CalendarListListPatchParams {
+    /// Whether to use the foregroundColor and backgroundColor fields to write the calendar colors (RGB). If this feature is used, the index-based colorId field will be set to the best matching option automatically. Optional. The default is False.
+    pub color_rgb_format: Option<bool>,
+}
+
+impl CalendarListListPatchParams {
+    pub fn color_rgb_format(mut self, color_rgb_format: bool) -> Self {
+        self.color_rgb_format = Some(color_rgb_format);
+        self
+    }
+}
+
+/// The functions of [`CalendarList`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCalendarListApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CalendarListApi: Send + Sync {
+    /// See [`CalendarList::list_with`].
+    async fn list_with(
+        &self,
+        params: &CalendarListListParams,
+    ) -> Result<Vec<crate::types::CalendarListEntry>>;
+
+    /// See [`CalendarList::list_all_with`].
+    async fn list_all_with(
+        &self,
+        params: &CalendarListListParams,
+    ) -> Result<Vec<crate::types::CalendarListEntry>>;
+
+    /// See [`CalendarList::list_stream`].
+    fn list_stream(
+        &self,
+        params: &CalendarListListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CalendarListEntry>;
+
+    /// See [`CalendarList::list_insert_with`].
+    async fn list_insert_with(
+        &self,
+        params: &CalendarListListInsertParams,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry>;
+
+    /// See [`CalendarList::list_watch_with`].
+    async fn list_watch_with(
+        &self,
+        params: &CalendarListListWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+
+    /// See [`CalendarList::list_get`].
+    async fn list_get(&self, calendar_id: &str) -> Result<crate::types::CalendarListEntry>;
+
+    /// See [`CalendarList::list_update_with`].
+    async fn list_update_with(
+        &self,
+        calendar_id: &str,
+        params: &CalendarListListUpdateParams,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry>;
+
+    /// See [`CalendarList::list_delete`].
+    async fn list_delete(&self, calendar_id: &str) -> Result<()>;
+
+    /// See [`CalendarList::list_patch_with`].
+    async fn list_patch_with(
+        &self,
+        calendar_id: &str,
+        params: &CalendarListListPatchParams,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry>;
+}
+
+#[async_trait::async_trait]
+impl CalendarListApi for CalendarList {
+    async fn list_with(
+        &self,
+        params: &CalendarListListParams,
+    ) -> Result<Vec<crate::types::CalendarListEntry>> {
+        CalendarList::list_with(self, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        params: &CalendarListListParams,
+    ) -> Result<Vec<crate::types::CalendarListEntry>> {
+        CalendarList::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &CalendarListListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CalendarListEntry> {
+        CalendarList::list_stream(self, params, start, max_items)
+    }
+
+    async fn list_insert_with(
+        &self,
+        params: &CalendarListListInsertParams,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry> {
+        CalendarList::list_insert_with(self, params, body).await
+    }
+
+    async fn list_watch_with(
+        &self,
+        params: &CalendarListListWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        CalendarList::list_watch_with(self, params, body).await
+    }
+
+    async fn list_get(&self, calendar_id: &str) -> Result<crate::types::CalendarListEntry> {
+        CalendarList::list_get(self, calendar_id).await
+    }
+
+    async fn list_update_with(
+        &self,
+        calendar_id: &str,
+        params: &CalendarListListUpdateParams,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry> {
+        CalendarList::list_update_with(self, calendar_id, params, body).await
+    }
+
+    async fn list_delete(&self, calendar_id: &str) -> Result<()> {
+        CalendarList::list_delete(self, calendar_id).await
+    }
+
+    async fn list_patch_with(
+        &self,
+        calendar_id: &str,
+        params: &CalendarListListPatchParams,
+        body: &crate::types::CalendarListEntry,
+    ) -> Result<crate::types::CalendarListEntry> {
+        CalendarList::list_patch_with(self, calendar_id, params, body).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Calendars {
     pub client: Client,
@@ -126,3 +124,70 @@ impl Calendars {
         self.client.post(&url, None).await
     }
 }
+
+/// The functions of [`Calendars`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCalendarsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CalendarsApi: Send + Sync {
+    /// See [`Calendars::insert`].
+    async fn insert(&self, body: &crate::types::Calendar) -> Result<crate::types::Calendar>;
+
+    /// See [`Calendars::get`].
+    async fn get(&self, calendar_id: &str) -> Result<crate::types::Calendar>;
+
+    /// See [`Calendars::update`].
+    async fn update(
+        &self,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> Result<crate::types::Calendar>;
+
+    /// See [`Calendars::delete`].
+    async fn delete(&self, calendar_id: &str) -> Result<()>;
+
+    /// See [`Calendars::patch`].
+    async fn patch(
+        &self,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> Result<crate::types::Calendar>;
+
+    /// See [`Calendars::clear`].
+    async fn clear(&self, calendar_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl CalendarsApi for Calendars {
+    async fn insert(&self, body: &crate::types::Calendar) -> Result<crate::types::Calendar> {
+        Calendars::insert(self, body).await
+    }
+
+    async fn get(&self, calendar_id: &str) -> Result<crate::types::Calendar> {
+        Calendars::get(self, calendar_id).await
+    }
+
+    async fn update(
+        &self,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> Result<crate::types::Calendar> {
+        Calendars::update(self, calendar_id, body).await
+    }
+
+    async fn delete(&self, calendar_id: &str) -> Result<()> {
+        Calendars::delete(self, calendar_id).await
+    }
+
+    async fn patch(
+        &self,
+        calendar_id: &str,
+        body: &crate::types::Calendar,
+    ) -> Result<crate::types::Calendar> {
+        Calendars::patch(self, calendar_id, body).await
+    }
+
+    async fn clear(&self, calendar_id: &str) -> Result<()> {
+        Calendars::clear(self, calendar_id).await
+    }
+}
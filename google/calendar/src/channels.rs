@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Channels {
     pub client: Client,
@@ -24,3 +22,19 @@ impl Channels {
             .await
     }
 }
+
+/// The functions of [`Channels`], for code that takes them as a trait, so that it can be
+/// tested with the `MockChannelsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ChannelsApi: Send + Sync {
+    /// See [`Channels::stop`].
+    async fn stop(&self, body: &crate::types::Channel) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ChannelsApi for Channels {
+    async fn stop(&self, body: &crate::types::Channel) -> Result<()> {
+        Channels::stop(self, body).await
+    }
+}
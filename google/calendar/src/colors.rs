@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Colors {
     pub client: Client,
@@ -22,3 +20,19 @@ impl Colors {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`Colors`], for code that takes them as a trait, so that it can be
+/// tested with the `MockColorsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ColorsApi: Send + Sync {
+    /// See [`Colors::get`].
+    async fn get(&self) -> Result<crate::types::Colors>;
+}
+
+#[async_trait::async_trait]
+impl ColorsApi for Colors {
+    async fn get(&self) -> Result<crate::types::Colors> {
+        Colors::get(self).await
+    }
+}
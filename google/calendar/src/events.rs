@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Events {
     pub client: Client,
@@ -20,109 +18,66 @@ impl Events {
      * **Parameters:**
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
-     * * `always_include_email: bool` -- Deprecated and ignored. A value will always be returned in the email field for the organizer, creator and attendees, even if no real email address is available (i.e. a generated, non-working value will be provided).
-     * * `i_cal_uid: &str` -- Specifies event ID in the iCalendar format to be included in the response. Optional.
-     * * `max_attendees: i64` -- The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
-     * * `max_results: i64` -- Maximum number of events returned on one result page. The number of events in the resulting page may be less than this value, or none at all, even if there are more events matching the query. Incomplete pages can be detected by a non-empty nextPageToken field in the response. By default the value is 250 events. The page size can never be larger than 2500 events. Optional.
-     * * `order_by: crate::types::OrderBy` -- The order of the events returned in the result. Optional. The default is an unspecified, stable order.
-     * * `page_token: &str` -- Token specifying which result page to return. Optional.
-     * * `private_extended_property: &[String]` -- Extended properties constraint specified as propertyName=value. Matches only private properties. This parameter might be repeated multiple times to return events that match all given constraints.
-     * * `q: &str` -- Free text search terms to find events that match these terms in any field, except for extended properties. Optional.
-     * * `shared_extended_property: &[String]` -- Extended properties constraint specified as propertyName=value. Matches only shared properties. This parameter might be repeated multiple times to return events that match all given constraints.
-     * * `show_deleted: bool` -- Whether to include deleted events (with status equals "cancelled") in the result. Cancelled instances of recurring events (but not the underlying recurring event) will still be included if showDeleted and singleEvents are both False. If showDeleted and singleEvents are both True, only single instances of deleted events (but not the underlying recurring events) are returned. Optional. The default is False.
-     * * `show_hidden_invitations: bool` -- Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
-     * * `single_events: bool` -- Whether to expand recurring events into instances and only return single one-off events and instances of recurring events, but not the underlying recurring events themselves. Optional. The default is False.
-     * * `sync_token: &str` -- Token obtained from the nextSyncToken field returned on the last page of results from the previous list request. It makes the result of this list request contain only entries that have changed since then. All events deleted since the previous list request will always be in the result set and it is not allowed to set showDeleted to False.
-     *   There are several query parameters that cannot be specified together with nextSyncToken to ensure consistency of the client state.
-     *   
-     *   These are:
-     *   - iCalUID
-     *   - orderBy
-     *   - privateExtendedProperty
-     *   - q
-     *   - sharedExtendedProperty
-     *   - timeMin
-     *   - timeMax
-     *   - updatedMin If the syncToken expires, the server will respond with a 410 GONE response code and the client should clear its storage and perform a full synchronization without any syncToken.
-     *   Learn more about incremental synchronization.
-     *   Optional. The default is to return all entries.
-     * * `time_max: &str` -- Upper bound (exclusive) for an event's start time to filter by. Optional. The default is not to filter by start time. Must be an RFC3339 timestamp with mandatory time zone offset, for example, 2011-06-03T10:00:00-07:00, 2011-06-03T10:00:00Z. Milliseconds may be provided but are ignored. If timeMin is set, timeMax must be greater than timeMin.
-     * * `time_min: &str` -- Lower bound (exclusive) for an event's end time to filter by. Optional. The default is not to filter by end time. Must be an RFC3339 timestamp with mandatory time zone offset, for example, 2011-06-03T10:00:00-07:00, 2011-06-03T10:00:00Z. Milliseconds may be provided but are ignored. If timeMax is set, timeMin must be smaller than timeMax.
-     * * `time_zone: &str` -- Time zone used in the response. Optional. The default is the time zone of the calendar.
-     * * `updated_min: &str` -- Lower bound for an event's last modification time (as a RFC3339 timestamp) to filter by. When specified, entries deleted since this time will always be included regardless of showDeleted. Optional. The default is not to filter by last modification time.
+     * * `params: &EventsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         calendar_id: &str,
-        i_cal_uid: &str,
-        max_attendees: i64,
-        max_results: i64,
-        order_by: crate::types::OrderBy,
-        page_token: &str,
-        private_extended_property: &[String],
-        q: &str,
-        shared_extended_property: &[String],
-        show_deleted: bool,
-        show_hidden_invitations: bool,
-        single_events: bool,
-        time_max: &str,
-        time_min: &str,
-        time_zone: &str,
-        updated_min: &str,
+        params: &EventsListParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !i_cal_uid.is_empty() {
+        if let Some(i_cal_uid) = &params.i_cal_uid {
             query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
         }
-        if max_attendees > 0 {
+        if let Some(max_attendees) = &params.max_attendees {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !order_by.to_string().is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !private_extended_property.is_empty() {
+        if let Some(private_extended_property) = &params.private_extended_property {
             query_args.push((
                 "privateExtendedProperty".to_string(),
                 private_extended_property.join(" "),
             ));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !shared_extended_property.is_empty() {
+        if let Some(shared_extended_property) = &params.shared_extended_property {
             query_args.push((
                 "sharedExtendedProperty".to_string(),
                 shared_extended_property.join(" "),
             ));
         }
-        if show_deleted {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
-        if show_hidden_invitations {
+        if let Some(show_hidden_invitations) = &params.show_hidden_invitations {
             query_args.push((
                 "showHiddenInvitations".to_string(),
                 show_hidden_invitations.to_string(),
             ));
         }
-        if single_events {
+        if let Some(single_events) = &params.single_events {
             query_args.push(("singleEvents".to_string(), single_events.to_string()));
         }
-        if !time_max.is_empty() {
+        if let Some(time_max) = &params.time_max {
             query_args.push(("timeMax".to_string(), time_max.to_string()));
         }
-        if !time_min.is_empty() {
+        if let Some(time_min) = &params.time_min {
             query_args.push(("timeMin".to_string(), time_min.to_string()));
         }
-        if !time_zone.is_empty() {
+        if let Some(time_zone) = &params.time_zone {
             query_args.push(("timeZone".to_string(), time_zone.to_string()));
         }
-        if !updated_min.is_empty() {
+        if let Some(updated_min) = &params.updated_min {
             query_args.push(("updatedMin".to_string(), updated_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -139,18 +94,17 @@ impl Events {
     }
 
     /**
-     * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
-     *
-     * As opposed to `list`, this function returns all the pages of the request at once.
-     *
-     * Returns events on the specified calendar.
+     * Deprecated: use `list_with` and pass the query parameters as a `EventsListParams` instead.
      */
-    pub async fn list_all(
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
         &self,
         calendar_id: &str,
         i_cal_uid: &str,
         max_attendees: i64,
+        max_results: i64,
         order_by: crate::types::OrderBy,
+        page_token: &str,
         private_extended_property: &[String],
         q: &str,
         shared_extended_property: &[String],
@@ -161,54 +115,92 @@ impl Events {
         time_min: &str,
         time_zone: &str,
         updated_min: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        let params = EventsListParams {
+            i_cal_uid: Some(i_cal_uid.to_string()).filter(|s| !s.is_empty()),
+            max_attendees: Some(max_attendees).filter(|v| *v > 0),
+            max_results: Some(max_results).filter(|v| *v > 0),
+            order_by: Some(order_by).filter(|v| !v.to_string().is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            private_extended_property: Some(private_extended_property.to_vec())
+                .filter(|v| !v.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            shared_extended_property: Some(shared_extended_property.to_vec())
+                .filter(|v| !v.is_empty()),
+            show_deleted: Some(show_deleted).filter(|v| *v),
+            show_hidden_invitations: Some(show_hidden_invitations).filter(|v| *v),
+            single_events: Some(single_events).filter(|v| *v),
+            time_max: Some(time_max.to_string()).filter(|s| !s.is_empty()),
+            time_min: Some(time_min.to_string()).filter(|s| !s.is_empty()),
+            time_zone: Some(time_zone.to_string()).filter(|s| !s.is_empty()),
+            updated_min: Some(updated_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(calendar_id, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Returns events on the specified calendar.
+     *
+     * **Parameters:**
+     *
+     * * `params: &EventsListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(
+        &self,
+        calendar_id: &str,
+        params: &EventsListParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !i_cal_uid.is_empty() {
+        if let Some(i_cal_uid) = &params.i_cal_uid {
             query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
         }
-        if max_attendees > 0 {
+        if let Some(max_attendees) = &params.max_attendees {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
-        if !order_by.to_string().is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !private_extended_property.is_empty() {
+        if let Some(private_extended_property) = &params.private_extended_property {
             query_args.push((
                 "privateExtendedProperty".to_string(),
                 private_extended_property.join(" "),
             ));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !shared_extended_property.is_empty() {
+        if let Some(shared_extended_property) = &params.shared_extended_property {
             query_args.push((
                 "sharedExtendedProperty".to_string(),
                 shared_extended_property.join(" "),
             ));
         }
-        if show_deleted {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
-        if show_hidden_invitations {
+        if let Some(show_hidden_invitations) = &params.show_hidden_invitations {
             query_args.push((
                 "showHiddenInvitations".to_string(),
                 show_hidden_invitations.to_string(),
             ));
         }
-        if single_events {
+        if let Some(single_events) = &params.single_events {
             query_args.push(("singleEvents".to_string(), single_events.to_string()));
         }
-        if !time_max.is_empty() {
+        if let Some(time_max) = &params.time_max {
             query_args.push(("timeMax".to_string(), time_max.to_string()));
         }
-        if !time_min.is_empty() {
+        if let Some(time_min) = &params.time_min {
             query_args.push(("timeMin".to_string(), time_min.to_string()));
         }
-        if !time_zone.is_empty() {
+        if let Some(time_zone) = &params.time_zone {
             query_args.push(("timeZone".to_string(), time_zone.to_string()));
         }
-        if !updated_min.is_empty() {
+        if let Some(updated_min) = &params.updated_min {
             query_args.push(("updatedMin".to_string(), updated_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -225,6 +217,7 @@ impl Events {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -250,6 +243,138 @@ impl Events {
         Ok(items)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `EventsListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        order_by: crate::types::OrderBy,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        let params = EventsListParams {
+            i_cal_uid: Some(i_cal_uid.to_string()).filter(|s| !s.is_empty()),
+            max_attendees: Some(max_attendees).filter(|v| *v > 0),
+            order_by: Some(order_by).filter(|v| !v.to_string().is_empty()),
+            private_extended_property: Some(private_extended_property.to_vec())
+                .filter(|v| !v.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            shared_extended_property: Some(shared_extended_property.to_vec())
+                .filter(|v| !v.is_empty()),
+            show_deleted: Some(show_deleted).filter(|v| *v),
+            show_hidden_invitations: Some(show_hidden_invitations).filter(|v| *v),
+            single_events: Some(single_events).filter(|v| *v),
+            time_max: Some(time_max.to_string()).filter(|s| !s.is_empty()),
+            time_min: Some(time_min.to_string()).filter(|s| !s.is_empty()),
+            time_zone: Some(time_zone.to_string()).filter(|s| !s.is_empty()),
+            updated_min: Some(updated_min.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(calendar_id, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns events on the specified calendar.
+     */
+    pub fn list_stream(
+        &self,
+        calendar_id: &str,
+        params: &EventsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(i_cal_uid) = &params.i_cal_uid {
+            query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
+        }
+        if let Some(max_attendees) = &params.max_attendees {
+            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+        }
+        if let Some(order_by) = &params.order_by {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if let Some(private_extended_property) = &params.private_extended_property {
+            query_args.push((
+                "privateExtendedProperty".to_string(),
+                private_extended_property.join(" "),
+            ));
+        }
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(shared_extended_property) = &params.shared_extended_property {
+            query_args.push((
+                "sharedExtendedProperty".to_string(),
+                shared_extended_property.join(" "),
+            ));
+        }
+        if let Some(show_deleted) = &params.show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if let Some(show_hidden_invitations) = &params.show_hidden_invitations {
+            query_args.push((
+                "showHiddenInvitations".to_string(),
+                show_hidden_invitations.to_string(),
+            ));
+        }
+        if let Some(single_events) = &params.single_events {
+            query_args.push(("singleEvents".to_string(), single_events.to_string()));
+        }
+        if let Some(time_max) = &params.time_max {
+            query_args.push(("timeMax".to_string(), time_max.to_string()));
+        }
+        if let Some(time_min) = &params.time_min {
+            query_args.push(("timeMin".to_string(), time_min.to_string()));
+        }
+        if let Some(time_zone) = &params.time_zone {
+            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+        }
+        if let Some(updated_min) = &params.updated_min {
+            query_args.push(("updatedMin".to_string(), updated_min.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/calendars/{}/events?{}",
+            crate::progenitor_support::encode_path(calendar_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::Events = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.items,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events` endpoint.
      *
@@ -258,44 +383,34 @@ impl Events {
      * **Parameters:**
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
-     * * `conference_data_version: u64` -- Version number of conference data supported by the API client. Version 0 assumes no conference data support and ignores conference data in the event's body. Version 1 enables support for copying of ConferenceData as well as for creating new conferences using the createRequest field of conferenceData. The default is 0.
-     * * `max_attendees: i64` -- The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
-     * * `send_notifications: bool` -- Deprecated. Please use sendUpdates instead.
-     *   
-     *   Whether to send notifications about the creation of the new event. Note that some emails might still be sent even if you set the value to false. The default is false.
-     * * `send_updates: crate::types::SendUpdates` -- Whether to send notifications about the creation of the new event. Note that some emails might still be sent. The default is false.
-     * * `supports_attachments: bool` -- Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * * `params: &EventsInsertParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn insert(
+    pub async fn insert_with(
         &self,
         calendar_id: &str,
-        conference_data_version: u64,
-        max_attendees: i64,
-        send_notifications: bool,
-        send_updates: crate::types::SendUpdates,
-        supports_attachments: bool,
+        params: &EventsInsertParams,
         body: &crate::types::Event,
     ) -> Result<crate::types::Event> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !conference_data_version.to_string().is_empty() {
+        if let Some(conference_data_version) = &params.conference_data_version {
             query_args.push((
                 "conferenceDataVersion".to_string(),
                 conference_data_version.to_string(),
             ));
         }
-        if max_attendees > 0 {
+        if let Some(max_attendees) = &params.max_attendees {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
-        if send_notifications {
+        if let Some(send_notifications) = &params.send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
                 send_notifications.to_string(),
             ));
         }
-        if !send_updates.to_string().is_empty() {
+        if let Some(send_updates) = &params.send_updates {
             query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
         }
-        if supports_attachments {
+        if let Some(supports_attachments) = &params.supports_attachments {
             query_args.push((
                 "supportsAttachments".to_string(),
                 supports_attachments.to_string(),
@@ -313,6 +428,31 @@ impl Events {
             .await
     }
 
+    /**
+     * Deprecated: use `insert_with` and pass the query parameters as a `EventsInsertParams` instead.
+     */
+    #[deprecated(note = "use `insert_with` instead")]
+    pub async fn insert(
+        &self,
+        calendar_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event> {
+        let params = EventsInsertParams {
+            conference_data_version: Some(conference_data_version)
+                .filter(|v| !v.to_string().is_empty()),
+            max_attendees: Some(max_attendees).filter(|v| *v > 0),
+            send_notifications: Some(send_notifications).filter(|v| *v),
+            send_updates: Some(send_updates).filter(|v| !v.to_string().is_empty()),
+            supports_attachments: Some(supports_attachments).filter(|v| *v),
+        };
+        self.insert_with(calendar_id, &params, body).await
+    }
+
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events/import` endpoint.
      *
@@ -321,24 +461,22 @@ impl Events {
      * **Parameters:**
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
-     * * `conference_data_version: u64` -- Version number of conference data supported by the API client. Version 0 assumes no conference data support and ignores conference data in the event's body. Version 1 enables support for copying of ConferenceData as well as for creating new conferences using the createRequest field of conferenceData. The default is 0.
-     * * `supports_attachments: bool` -- Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * * `params: &EventsImportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn import(
+    pub async fn import_with(
         &self,
         calendar_id: &str,
-        conference_data_version: u64,
-        supports_attachments: bool,
+        params: &EventsImportParams,
         body: &crate::types::Event,
     ) -> Result<crate::types::Event> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !conference_data_version.to_string().is_empty() {
+        if let Some(conference_data_version) = &params.conference_data_version {
             query_args.push((
                 "conferenceDataVersion".to_string(),
                 conference_data_version.to_string(),
             ));
         }
-        if supports_attachments {
+        if let Some(supports_attachments) = &params.supports_attachments {
             query_args.push((
                 "supportsAttachments".to_string(),
                 supports_attachments.to_string(),
@@ -356,6 +494,25 @@ impl Events {
             .await
     }
 
+    /**
+     * Deprecated: use `import_with` and pass the query parameters as a `EventsImportParams` instead.
+     */
+    #[deprecated(note = "use `import_with` instead")]
+    pub async fn import(
+        &self,
+        calendar_id: &str,
+        conference_data_version: u64,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event> {
+        let params = EventsImportParams {
+            conference_data_version: Some(conference_data_version)
+                .filter(|v| !v.to_string().is_empty()),
+            supports_attachments: Some(supports_attachments).filter(|v| *v),
+        };
+        self.import_with(calendar_id, &params, body).await
+    }
+
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events/quickAdd` endpoint.
      *
@@ -364,30 +521,24 @@ impl Events {
      * **Parameters:**
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
-     * * `text: &str` -- The text describing the event to be created.
-     * * `send_notifications: bool` -- Deprecated. Please use sendUpdates instead.
-     *   
-     *   Whether to send notifications about the creation of the event. Note that some emails might still be sent even if you set the value to false. The default is false.
-     * * `send_updates: crate::types::SendUpdates` -- Whether to send notifications about the creation of the new event. Note that some emails might still be sent. The default is false.
+     * * `params: &EventsQuickAddParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn quick_add(
+    pub async fn quick_add_with(
         &self,
         calendar_id: &str,
-        text: &str,
-        send_notifications: bool,
-        send_updates: crate::types::SendUpdates,
+        params: &EventsQuickAddParams,
     ) -> Result<crate::types::Event> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if send_notifications {
+        if let Some(send_notifications) = &params.send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
                 send_notifications.to_string(),
             ));
         }
-        if !send_updates.to_string().is_empty() {
+        if let Some(send_updates) = &params.send_updates {
             query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
         }
-        if !text.is_empty() {
+        if let Some(text) = &params.text {
             query_args.push(("text".to_string(), text.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -400,6 +551,25 @@ impl Events {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `quick_add_with` and pass the query parameters as a `EventsQuickAddParams` instead.
+     */
+    #[deprecated(note = "use `quick_add_with` instead")]
+    pub async fn quick_add(
+        &self,
+        calendar_id: &str,
+        text: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> Result<crate::types::Event> {
+        let params = EventsQuickAddParams {
+            send_notifications: Some(send_notifications).filter(|v| *v),
+            send_updates: Some(send_updates).filter(|v| !v.to_string().is_empty()),
+            text: Some(text.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.quick_add_with(calendar_id, &params).await
+    }
+
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events/watch` endpoint.
      *
@@ -408,110 +578,67 @@ impl Events {
      * **Parameters:**
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
-     * * `always_include_email: bool` -- Deprecated and ignored. A value will always be returned in the email field for the organizer, creator and attendees, even if no real email address is available (i.e. a generated, non-working value will be provided).
-     * * `i_cal_uid: &str` -- Specifies event ID in the iCalendar format to be included in the response. Optional.
-     * * `max_attendees: i64` -- The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
-     * * `max_results: i64` -- Maximum number of events returned on one result page. The number of events in the resulting page may be less than this value, or none at all, even if there are more events matching the query. Incomplete pages can be detected by a non-empty nextPageToken field in the response. By default the value is 250 events. The page size can never be larger than 2500 events. Optional.
-     * * `order_by: crate::types::OrderBy` -- The order of the events returned in the result. Optional. The default is an unspecified, stable order.
-     * * `page_token: &str` -- Token specifying which result page to return. Optional.
-     * * `private_extended_property: &[String]` -- Extended properties constraint specified as propertyName=value. Matches only private properties. This parameter might be repeated multiple times to return events that match all given constraints.
-     * * `q: &str` -- Free text search terms to find events that match these terms in any field, except for extended properties. Optional.
-     * * `shared_extended_property: &[String]` -- Extended properties constraint specified as propertyName=value. Matches only shared properties. This parameter might be repeated multiple times to return events that match all given constraints.
-     * * `show_deleted: bool` -- Whether to include deleted events (with status equals "cancelled") in the result. Cancelled instances of recurring events (but not the underlying recurring event) will still be included if showDeleted and singleEvents are both False. If showDeleted and singleEvents are both True, only single instances of deleted events (but not the underlying recurring events) are returned. Optional. The default is False.
-     * * `show_hidden_invitations: bool` -- Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
-     * * `single_events: bool` -- Whether to expand recurring events into instances and only return single one-off events and instances of recurring events, but not the underlying recurring events themselves. Optional. The default is False.
-     * * `sync_token: &str` -- Token obtained from the nextSyncToken field returned on the last page of results from the previous list request. It makes the result of this list request contain only entries that have changed since then. All events deleted since the previous list request will always be in the result set and it is not allowed to set showDeleted to False.
-     *   There are several query parameters that cannot be specified together with nextSyncToken to ensure consistency of the client state.
-     *   
-     *   These are:
-     *   - iCalUID
-     *   - orderBy
-     *   - privateExtendedProperty
-     *   - q
-     *   - sharedExtendedProperty
-     *   - timeMin
-     *   - timeMax
-     *   - updatedMin If the syncToken expires, the server will respond with a 410 GONE response code and the client should clear its storage and perform a full synchronization without any syncToken.
-     *   Learn more about incremental synchronization.
-     *   Optional. The default is to return all entries.
-     * * `time_max: &str` -- Upper bound (exclusive) for an event's start time to filter by. Optional. The default is not to filter by start time. Must be an RFC3339 timestamp with mandatory time zone offset, for example, 2011-06-03T10:00:00-07:00, 2011-06-03T10:00:00Z. Milliseconds may be provided but are ignored. If timeMin is set, timeMax must be greater than timeMin.
-     * * `time_min: &str` -- Lower bound (exclusive) for an event's end time to filter by. Optional. The default is not to filter by end time. Must be an RFC3339 timestamp with mandatory time zone offset, for example, 2011-06-03T10:00:00-07:00, 2011-06-03T10:00:00Z. Milliseconds may be provided but are ignored. If timeMax is set, timeMin must be smaller than timeMax.
-     * * `time_zone: &str` -- Time zone used in the response. Optional. The default is the time zone of the calendar.
-     * * `updated_min: &str` -- Lower bound for an event's last modification time (as a RFC3339 timestamp) to filter by. When specified, entries deleted since this time will always be included regardless of showDeleted. Optional. The default is not to filter by last modification time.
+     * * `params: &EventsWatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn watch(
+    pub async fn watch_with(
         &self,
         calendar_id: &str,
-        i_cal_uid: &str,
-        max_attendees: i64,
-        max_results: i64,
-        order_by: crate::types::OrderBy,
-        page_token: &str,
-        private_extended_property: &[String],
-        q: &str,
-        shared_extended_property: &[String],
-        show_deleted: bool,
-        show_hidden_invitations: bool,
-        single_events: bool,
-        time_max: &str,
-        time_min: &str,
-        time_zone: &str,
-        updated_min: &str,
+        params: &EventsWatchParams,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !i_cal_uid.is_empty() {
+        if let Some(i_cal_uid) = &params.i_cal_uid {
             query_args.push(("iCalUID".to_string(), i_cal_uid.to_string()));
         }
-        if max_attendees > 0 {
+        if let Some(max_attendees) = &params.max_attendees {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !order_by.to_string().is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !private_extended_property.is_empty() {
+        if let Some(private_extended_property) = &params.private_extended_property {
             query_args.push((
                 "privateExtendedProperty".to_string(),
                 private_extended_property.join(" "),
             ));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !shared_extended_property.is_empty() {
+        if let Some(shared_extended_property) = &params.shared_extended_property {
             query_args.push((
                 "sharedExtendedProperty".to_string(),
                 shared_extended_property.join(" "),
             ));
         }
-        if show_deleted {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
-        if show_hidden_invitations {
+        if let Some(show_hidden_invitations) = &params.show_hidden_invitations {
             query_args.push((
                 "showHiddenInvitations".to_string(),
                 show_hidden_invitations.to_string(),
             ));
         }
-        if single_events {
+        if let Some(single_events) = &params.single_events {
             query_args.push(("singleEvents".to_string(), single_events.to_string()));
         }
-        if !time_max.is_empty() {
+        if let Some(time_max) = &params.time_max {
             query_args.push(("timeMax".to_string(), time_max.to_string()));
         }
-        if !time_min.is_empty() {
+        if let Some(time_min) = &params.time_min {
             query_args.push(("timeMin".to_string(), time_min.to_string()));
         }
-        if !time_zone.is_empty() {
+        if let Some(time_zone) = &params.time_zone {
             query_args.push(("timeZone".to_string(), time_zone.to_string()));
         }
-        if !updated_min.is_empty() {
+        if let Some(updated_min) = &params.updated_min {
             query_args.push(("updatedMin".to_string(), updated_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -526,6 +653,52 @@ impl Events {
             .await
     }
 
+    /**
+     * Deprecated: use `watch_with` and pass the query parameters as a `EventsWatchParams` instead.
+     */
+    #[deprecated(note = "use `watch_with` instead")]
+    pub async fn watch(
+        &self,
+        calendar_id: &str,
+        i_cal_uid: &str,
+        max_attendees: i64,
+        max_results: i64,
+        order_by: crate::types::OrderBy,
+        page_token: &str,
+        private_extended_property: &[String],
+        q: &str,
+        shared_extended_property: &[String],
+        show_deleted: bool,
+        show_hidden_invitations: bool,
+        single_events: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+        updated_min: &str,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        let params = EventsWatchParams {
+            i_cal_uid: Some(i_cal_uid.to_string()).filter(|s| !s.is_empty()),
+            max_attendees: Some(max_attendees).filter(|v| *v > 0),
+            max_results: Some(max_results).filter(|v| *v > 0),
+            order_by: Some(order_by).filter(|v| !v.to_string().is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            private_extended_property: Some(private_extended_property.to_vec())
+                .filter(|v| !v.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            shared_extended_property: Some(shared_extended_property.to_vec())
+                .filter(|v| !v.is_empty()),
+            show_deleted: Some(show_deleted).filter(|v| *v),
+            show_hidden_invitations: Some(show_hidden_invitations).filter(|v| *v),
+            single_events: Some(single_events).filter(|v| *v),
+            time_max: Some(time_max.to_string()).filter(|s| !s.is_empty()),
+            time_min: Some(time_min.to_string()).filter(|s| !s.is_empty()),
+            time_zone: Some(time_zone.to_string()).filter(|s| !s.is_empty()),
+            updated_min: Some(updated_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.watch_with(calendar_id, &params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
      *
@@ -535,22 +708,19 @@ impl Events {
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      * * `event_id: &str` -- ETag of the collection.
-     * * `always_include_email: bool` -- Deprecated and ignored. A value will always be returned in the email field for the organizer, creator and attendees, even if no real email address is available (i.e. a generated, non-working value will be provided).
-     * * `max_attendees: i64` -- The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
-     * * `time_zone: &str` -- Time zone used in the response. Optional. The default is the time zone of the calendar.
+     * * `params: &EventsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         calendar_id: &str,
         event_id: &str,
-        max_attendees: i64,
-        time_zone: &str,
+        params: &EventsGetParams,
     ) -> Result<crate::types::Event> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_attendees > 0 {
+        if let Some(max_attendees) = &params.max_attendees {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
-        if !time_zone.is_empty() {
+        if let Some(time_zone) = &params.time_zone {
             query_args.push(("timeZone".to_string(), time_zone.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -564,6 +734,24 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `EventsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        time_zone: &str,
+    ) -> Result<crate::types::Event> {
+        let params = EventsGetParams {
+            max_attendees: Some(max_attendees).filter(|v| *v > 0),
+            time_zone: Some(time_zone.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(calendar_id, event_id, &params).await
+    }
+
     /**
      * This function performs a `PUT` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
      *
@@ -573,46 +761,35 @@ impl Events {
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      * * `event_id: &str` -- ETag of the collection.
-     * * `always_include_email: bool` -- Deprecated and ignored. A value will always be returned in the email field for the organizer, creator and attendees, even if no real email address is available (i.e. a generated, non-working value will be provided).
-     * * `conference_data_version: u64` -- Version number of conference data supported by the API client. Version 0 assumes no conference data support and ignores conference data in the event's body. Version 1 enables support for copying of ConferenceData as well as for creating new conferences using the createRequest field of conferenceData. The default is 0.
-     * * `max_attendees: i64` -- The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
-     * * `send_notifications: bool` -- Deprecated. Please use sendUpdates instead.
-     *   
-     *   Whether to send notifications about the event update (for example, description changes, etc.). Note that some emails might still be sent even if you set the value to false. The default is false.
-     * * `send_updates: crate::types::SendUpdates` -- Whether to send notifications about the creation of the new event. Note that some emails might still be sent. The default is false.
-     * * `supports_attachments: bool` -- Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * * `params: &EventsUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update(
+    pub async fn update_with(
         &self,
         calendar_id: &str,
         event_id: &str,
-        conference_data_version: u64,
-        max_attendees: i64,
-        send_notifications: bool,
-        send_updates: crate::types::SendUpdates,
-        supports_attachments: bool,
+        params: &EventsUpdateParams,
         body: &crate::types::Event,
     ) -> Result<crate::types::Event> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !conference_data_version.to_string().is_empty() {
+        if let Some(conference_data_version) = &params.conference_data_version {
             query_args.push((
                 "conferenceDataVersion".to_string(),
                 conference_data_version.to_string(),
             ));
         }
-        if max_attendees > 0 {
+        if let Some(max_attendees) = &params.max_attendees {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
-        if send_notifications {
+        if let Some(send_notifications) = &params.send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
                 send_notifications.to_string(),
             ));
         }
-        if !send_updates.to_string().is_empty() {
+        if let Some(send_updates) = &params.send_updates {
             query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
         }
-        if supports_attachments {
+        if let Some(supports_attachments) = &params.supports_attachments {
             query_args.push((
                 "supportsAttachments".to_string(),
                 supports_attachments.to_string(),
@@ -631,6 +808,32 @@ impl Events {
             .await
     }
 
+    /**
+     * Deprecated: use `update_with` and pass the query parameters as a `EventsUpdateParams` instead.
+     */
+    #[deprecated(note = "use `update_with` instead")]
+    pub async fn update(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event> {
+        let params = EventsUpdateParams {
+            conference_data_version: Some(conference_data_version)
+                .filter(|v| !v.to_string().is_empty()),
+            max_attendees: Some(max_attendees).filter(|v| *v > 0),
+            send_notifications: Some(send_notifications).filter(|v| *v),
+            send_updates: Some(send_updates).filter(|v| !v.to_string().is_empty()),
+            supports_attachments: Some(supports_attachments).filter(|v| *v),
+        };
+        self.update_with(calendar_id, event_id, &params, body).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
      *
@@ -640,26 +843,22 @@ impl Events {
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      * * `event_id: &str` -- ETag of the collection.
-     * * `send_notifications: bool` -- Deprecated. Please use sendUpdates instead.
-     *   
-     *   Whether to send notifications about the deletion of the event. Note that some emails might still be sent even if you set the value to false. The default is false.
-     * * `send_updates: crate::types::SendUpdates` -- Whether to send notifications about the creation of the new event. Note that some emails might still be sent. The default is false.
+     * * `params: &EventsDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete(
+    pub async fn delete_with(
         &self,
         calendar_id: &str,
         event_id: &str,
-        send_notifications: bool,
-        send_updates: crate::types::SendUpdates,
+        params: &EventsDeleteParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if send_notifications {
+        if let Some(send_notifications) = &params.send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
                 send_notifications.to_string(),
             ));
         }
-        if !send_updates.to_string().is_empty() {
+        if let Some(send_updates) = &params.send_updates {
             query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -673,6 +872,24 @@ impl Events {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_with` and pass the query parameters as a `EventsDeleteParams` instead.
+     */
+    #[deprecated(note = "use `delete_with` instead")]
+    pub async fn delete(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> Result<()> {
+        let params = EventsDeleteParams {
+            send_notifications: Some(send_notifications).filter(|v| *v),
+            send_updates: Some(send_updates).filter(|v| !v.to_string().is_empty()),
+        };
+        self.delete_with(calendar_id, event_id, &params).await
+    }
+
     /**
      * This function performs a `PATCH` to the `/calendars/{calendarId}/events/{eventId}` endpoint.
      *
@@ -682,46 +899,35 @@ impl Events {
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      * * `event_id: &str` -- ETag of the collection.
-     * * `always_include_email: bool` -- Deprecated and ignored. A value will always be returned in the email field for the organizer, creator and attendees, even if no real email address is available (i.e. a generated, non-working value will be provided).
-     * * `conference_data_version: u64` -- Version number of conference data supported by the API client. Version 0 assumes no conference data support and ignores conference data in the event's body. Version 1 enables support for copying of ConferenceData as well as for creating new conferences using the createRequest field of conferenceData. The default is 0.
-     * * `max_attendees: i64` -- The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
-     * * `send_notifications: bool` -- Deprecated. Please use sendUpdates instead.
-     *   
-     *   Whether to send notifications about the event update (for example, description changes, etc.). Note that some emails might still be sent even if you set the value to false. The default is false.
-     * * `send_updates: crate::types::SendUpdates` -- Whether to send notifications about the creation of the new event. Note that some emails might still be sent. The default is false.
-     * * `supports_attachments: bool` -- Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * * `params: &EventsPatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn patch(
+    pub async fn patch_with(
         &self,
         calendar_id: &str,
         event_id: &str,
-        conference_data_version: u64,
-        max_attendees: i64,
-        send_notifications: bool,
-        send_updates: crate::types::SendUpdates,
-        supports_attachments: bool,
+        params: &EventsPatchParams,
         body: &crate::types::Event,
     ) -> Result<crate::types::Event> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !conference_data_version.to_string().is_empty() {
+        if let Some(conference_data_version) = &params.conference_data_version {
             query_args.push((
                 "conferenceDataVersion".to_string(),
                 conference_data_version.to_string(),
             ));
         }
-        if max_attendees > 0 {
+        if let Some(max_attendees) = &params.max_attendees {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
-        if send_notifications {
+        if let Some(send_notifications) = &params.send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
                 send_notifications.to_string(),
             ));
         }
-        if !send_updates.to_string().is_empty() {
+        if let Some(send_updates) = &params.send_updates {
             query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
         }
-        if supports_attachments {
+        if let Some(supports_attachments) = &params.supports_attachments {
             query_args.push((
                 "supportsAttachments".to_string(),
                 supports_attachments.to_string(),
@@ -740,6 +946,32 @@ impl Events {
             .await
     }
 
+    /**
+     * Deprecated: use `patch_with` and pass the query parameters as a `EventsPatchParams` instead.
+     */
+    #[deprecated(note = "use `patch_with` instead")]
+    pub async fn patch(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        conference_data_version: u64,
+        max_attendees: i64,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+        supports_attachments: bool,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event> {
+        let params = EventsPatchParams {
+            conference_data_version: Some(conference_data_version)
+                .filter(|v| !v.to_string().is_empty()),
+            max_attendees: Some(max_attendees).filter(|v| *v > 0),
+            send_notifications: Some(send_notifications).filter(|v| *v),
+            send_updates: Some(send_updates).filter(|v| !v.to_string().is_empty()),
+            supports_attachments: Some(supports_attachments).filter(|v| *v),
+        };
+        self.patch_with(calendar_id, event_id, &params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
      *
@@ -749,52 +981,37 @@ impl Events {
      *
      * * `calendar_id: &str` -- Calendar identifier. To retrieve calendar IDs call the calendarList.list method. If you want to access the primary calendar of the currently logged in user, use the "primary" keyword.
      * * `event_id: &str` -- Recurring event identifier.
-     * * `always_include_email: bool` -- Deprecated and ignored. A value will always be returned in the email field for the organizer, creator and attendees, even if no real email address is available (i.e. a generated, non-working value will be provided).
-     * * `max_attendees: i64` -- The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
-     * * `max_results: i64` -- Maximum number of events returned on one result page. By default the value is 250 events. The page size can never be larger than 2500 events. Optional.
-     * * `original_start: &str` -- The original start time of the instance in the result. Optional.
-     * * `page_token: &str` -- Token specifying which result page to return. Optional.
-     * * `show_deleted: bool` -- Whether to include deleted events (with status equals "cancelled") in the result. Cancelled instances of recurring events will still be included if singleEvents is False. Optional. The default is False.
-     * * `time_max: &str` -- Upper bound (exclusive) for an event's start time to filter by. Optional. The default is not to filter by start time. Must be an RFC3339 timestamp with mandatory time zone offset.
-     * * `time_min: &str` -- Lower bound (inclusive) for an event's end time to filter by. Optional. The default is not to filter by end time. Must be an RFC3339 timestamp with mandatory time zone offset.
-     * * `time_zone: &str` -- Time zone used in the response. Optional. The default is the time zone of the calendar.
+     * * `params: &EventsInstancesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn instances(
+    pub async fn instances_with(
         &self,
         calendar_id: &str,
         event_id: &str,
-        max_attendees: i64,
-        max_results: i64,
-        original_start: &str,
-        page_token: &str,
-        show_deleted: bool,
-        time_max: &str,
-        time_min: &str,
-        time_zone: &str,
+        params: &EventsInstancesParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_attendees > 0 {
+        if let Some(max_attendees) = &params.max_attendees {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !original_start.is_empty() {
+        if let Some(original_start) = &params.original_start {
             query_args.push(("originalStart".to_string(), original_start.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if show_deleted {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
-        if !time_max.is_empty() {
+        if let Some(time_max) = &params.time_max {
             query_args.push(("timeMax".to_string(), time_max.to_string()));
         }
-        if !time_min.is_empty() {
+        if let Some(time_min) = &params.time_min {
             query_args.push(("timeMin".to_string(), time_min.to_string()));
         }
-        if !time_zone.is_empty() {
+        if let Some(time_zone) = &params.time_zone {
             query_args.push(("timeZone".to_string(), time_zone.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -812,40 +1029,69 @@ impl Events {
     }
 
     /**
-     * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
-     *
-     * As opposed to `instances`, this function returns all the pages of the request at once.
-     *
-     * Returns instances of the specified recurring event.
+     * Deprecated: use `instances_with` and pass the query parameters as a `EventsInstancesParams` instead.
      */
-    pub async fn get_all_instances(
+    #[deprecated(note = "use `instances_with` instead")]
+    pub async fn instances(
         &self,
         calendar_id: &str,
         event_id: &str,
         max_attendees: i64,
+        max_results: i64,
         original_start: &str,
+        page_token: &str,
         show_deleted: bool,
         time_max: &str,
         time_min: &str,
         time_zone: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        let params = EventsInstancesParams {
+            max_attendees: Some(max_attendees).filter(|v| *v > 0),
+            max_results: Some(max_results).filter(|v| *v > 0),
+            original_start: Some(original_start.to_string()).filter(|s| !s.is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            show_deleted: Some(show_deleted).filter(|v| *v),
+            time_max: Some(time_max.to_string()).filter(|s| !s.is_empty()),
+            time_min: Some(time_min.to_string()).filter(|s| !s.is_empty()),
+            time_zone: Some(time_zone.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.instances_with(calendar_id, event_id, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
+     *
+     * As opposed to `instances`, this function returns all the pages of the request at once.
+     *
+     * Returns instances of the specified recurring event.
+     *
+     * **Parameters:**
+     *
+     * * `params: &EventsInstancesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_instances_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsInstancesParams,
     ) -> Result<Vec<crate::types::Event>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_attendees > 0 {
+        if let Some(max_attendees) = &params.max_attendees {
             query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
         }
-        if !original_start.is_empty() {
+        if let Some(original_start) = &params.original_start {
             query_args.push(("originalStart".to_string(), original_start.to_string()));
         }
-        if show_deleted {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
-        if !time_max.is_empty() {
+        if let Some(time_max) = &params.time_max {
             query_args.push(("timeMax".to_string(), time_max.to_string()));
         }
-        if !time_min.is_empty() {
+        if let Some(time_min) = &params.time_min {
             query_args.push(("timeMin".to_string(), time_min.to_string()));
         }
-        if !time_zone.is_empty() {
+        if let Some(time_zone) = &params.time_zone {
             query_args.push(("timeZone".to_string(), time_zone.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -863,6 +1109,7 @@ impl Events {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -888,6 +1135,96 @@ impl Events {
         Ok(items)
     }
 
+    /**
+     * Deprecated: use `get_all_instances_with` and pass the query parameters as a `EventsInstancesParams` instead.
+     */
+    #[deprecated(note = "use `get_all_instances_with` instead")]
+    pub async fn get_all_instances(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        max_attendees: i64,
+        original_start: &str,
+        show_deleted: bool,
+        time_max: &str,
+        time_min: &str,
+        time_zone: &str,
+    ) -> Result<Vec<crate::types::Event>> {
+        let params = EventsInstancesParams {
+            max_attendees: Some(max_attendees).filter(|v| *v > 0),
+            original_start: Some(original_start.to_string()).filter(|s| !s.is_empty()),
+            show_deleted: Some(show_deleted).filter(|v| *v),
+            time_max: Some(time_max.to_string()).filter(|s| !s.is_empty()),
+            time_min: Some(time_min.to_string()).filter(|s| !s.is_empty()),
+            time_zone: Some(time_zone.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_instances_with(calendar_id, event_id, &params)
+            .await
+    }
+
+    /**
+     * This function performs a `GET` to the `/calendars/{calendarId}/events/{eventId}/instances` endpoint.
+     *
+     * As opposed to `get_all_instances`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns instances of the specified recurring event.
+     */
+    pub fn instances_stream(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsInstancesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(max_attendees) = &params.max_attendees {
+            query_args.push(("maxAttendees".to_string(), max_attendees.to_string()));
+        }
+        if let Some(original_start) = &params.original_start {
+            query_args.push(("originalStart".to_string(), original_start.to_string()));
+        }
+        if let Some(show_deleted) = &params.show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        if let Some(time_max) = &params.time_max {
+            query_args.push(("timeMax".to_string(), time_max.to_string()));
+        }
+        if let Some(time_min) = &params.time_min {
+            query_args.push(("timeMin".to_string(), time_min.to_string()));
+        }
+        if let Some(time_zone) = &params.time_zone {
+            query_args.push(("timeZone".to_string(), time_zone.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/calendars/{}/events/{}/instances?{}",
+            crate::progenitor_support::encode_path(calendar_id),
+            crate::progenitor_support::encode_path(event_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::Events = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.items,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/calendars/{calendarId}/events/{eventId}/move` endpoint.
      *
@@ -897,31 +1234,25 @@ impl Events {
      *
      * * `calendar_id: &str` -- Calendar identifier of the source calendar where the event currently is on.
      * * `event_id: &str` -- ETag of the collection.
-     * * `destination: &str` -- Calendar identifier of the target calendar where the event is to be moved to.
-     * * `send_notifications: bool` -- Deprecated. Please use sendUpdates instead.
-     *   
-     *   Whether to send notifications about the change of the event's organizer. Note that some emails might still be sent even if you set the value to false. The default is false.
-     * * `send_updates: crate::types::SendUpdates` -- Whether to send notifications about the creation of the new event. Note that some emails might still be sent. The default is false.
+     * * `params: &EventsMvParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn mv(
+    pub async fn mv_with(
         &self,
         calendar_id: &str,
         event_id: &str,
-        destination: &str,
-        send_notifications: bool,
-        send_updates: crate::types::SendUpdates,
+        params: &EventsMvParams,
     ) -> Result<crate::types::Event> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !destination.is_empty() {
+        if let Some(destination) = &params.destination {
             query_args.push(("destination".to_string(), destination.to_string()));
         }
-        if send_notifications {
+        if let Some(send_notifications) = &params.send_notifications {
             query_args.push((
                 "sendNotifications".to_string(),
                 send_notifications.to_string(),
             ));
         }
-        if !send_updates.to_string().is_empty() {
+        if let Some(send_updates) = &params.send_updates {
             query_args.push(("sendUpdates".to_string(), send_updates.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -934,4 +1265,836 @@ impl Events {
 
         self.client.post(&url, None).await
     }
+
+    /**
+     * Deprecated: use `mv_with` and pass the query parameters as a `EventsMvParams` instead.
+     */
+    #[deprecated(note = "use `mv_with` instead")]
+    pub async fn mv(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        destination: &str,
+        send_notifications: bool,
+        send_updates: crate::types::SendUpdates,
+    ) -> Result<crate::types::Event> {
+        let params = EventsMvParams {
+            destination: Some(destination.to_string()).filter(|s| !s.is_empty()),
+            send_notifications: Some(send_notifications).filter(|v| *v),
+            send_updates: Some(send_updates).filter(|v| !v.to_string().is_empty()),
+        };
+        self.mv_with(calendar_id, event_id, &params).await
+    }
+}
+
+/// The query parameters of `Events::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsListParams {
+    /// Specifies event ID in the iCalendar format to be included in the response. Optional.
+    pub i_cal_uid: Option<String>,
+    /// The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
+    pub max_attendees: Option<i64>,
+    /// Maximum number of events returned on one result page. The number of events in the resulting page may be less than this value, or none at all, even if there are more events matching the query. Incomplete pages can be detected by a non-empty nextPageToken field in the response. By default the value is 250 events. The page size can never be larger than 2500 events. Optional.
+    pub max_results: Option<i64>,
+    /// The order of the events returned in the result. Optional. The default is an unspecified, stable order.
+    pub order_by: Option<crate::types::OrderBy>,
+    /// Token specifying which result page to return. Optional.
+    pub page_token: Option<String>,
+    /// Extended properties constraint specified as propertyName=value. Matches only private properties. This parameter might be repeated multiple times to return events that match all given constraints.
+    pub private_extended_property: Option<Vec<String>>,
+    /// Free text search terms to find events that match these terms in any field, except for extended properties. Optional.
+    pub q: Option<String>,
+    /// Extended properties constraint specified as propertyName=value. Matches only shared properties. This parameter might be repeated multiple times to return events that match all given constraints.
+    pub shared_extended_property: Option<Vec<String>>,
+    /// Whether to include deleted events (with status equals "cancelled") in the result. Cancelled instances of recurring events (but not the underlying recurring event) will still be included if showDeleted and singleEvents are both False. If showDeleted and singleEvents are both True, only single instances of deleted events (but not the underlying recurring events) are returned. Optional. The default is False.
+    pub show_deleted: Option<bool>,
+    /// Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+    pub show_hidden_invitations: Option<bool>,
+    /// Whether to expand recurring events into instances and only return single one-off events and instances of recurring events, but not the underlying recurring events themselves. Optional. The default is False.
+    pub single_events: Option<bool>,
+    /// Upper bound (exclusive) for an event's start time to filter by. Optional. The default is not to filter by start time. Must be an RFC3339 timestamp with mandatory time zone offset, for example, 2011-06-03T10:00:00-07:00, 2011-06-03T10:00:00Z. Milliseconds may be provided but are ignored. If timeMin is set, timeMax must be greater than timeMin.
+    pub time_max: Option<String>,
+    /// Lower bound (exclusive) for an event's end time to filter by. Optional. The default is not to filter by end time. Must be an RFC3339 timestamp with mandatory time zone offset, for example, 2011-06-03T10:00:00-07:00, 2011-06-03T10:00:00Z. Milliseconds may be provided but are ignored. If timeMax is set, timeMin must be smaller than timeMax.
+    pub time_min: Option<String>,
+    /// Time zone used in the response. Optional. The default is the time zone of the calendar.
+    pub time_zone: Option<String>,
+    /// Lower bound for an event's last modification time (as a RFC3339 timestamp) to filter by. When specified, entries deleted since this time will always be included regardless of showDeleted. Optional. The default is not to filter by last modification time.
+    pub updated_min: Option<String>,
+}
+
+impl EventsListParams {
+    pub fn i_cal_uid<T: ToString>(mut self, i_cal_uid: T) -> Self {
+        self.i_cal_uid = Some(i_cal_uid.to_string());
+        self
+    }
+
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn order_by(mut self, order_by: crate::types::OrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn private_extended_property(mut self, private_extended_property: Vec<String>) -> Self {
+        self.private_extended_property = Some(private_extended_property);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn shared_extended_property(mut self, shared_extended_property: Vec<String>) -> Self {
+        self.shared_extended_property = Some(shared_extended_property);
+        self
+    }
+
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    pub fn show_hidden_invitations(mut self, show_hidden_invitations: bool) -> Self {
+        self.show_hidden_invitations = Some(show_hidden_invitations);
+        self
+    }
+
+    pub fn single_events(mut self, single_events: bool) -> Self {
+        self.single_events = Some(single_events);
+        self
+    }
+
+    pub fn time_max<T: ToString>(mut self, time_max: T) -> Self {
+        self.time_max = Some(time_max.to_string());
+        self
+    }
+
+    pub fn time_min<T: ToString>(mut self, time_min: T) -> Self {
+        self.time_min = Some(time_min.to_string());
+        self
+    }
+
+    pub fn time_zone<T: ToString>(mut self, time_zone: T) -> Self {
+        self.time_zone = Some(time_zone.to_string());
+        self
+    }
+
+    pub fn updated_min<T: ToString>(mut self, updated_min: T) -> Self {
+        self.updated_min = Some(updated_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::insert_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsInsertParams {
+    /// Version number of conference data supported by the API client. Version 0 assumes no conference data support and ignores conference data in the event's body. Version 1 enables support for copying of ConferenceData as well as for creating new conferences using the createRequest field of conferenceData. The default is 0.
+    pub conference_data_version: Option<u64>,
+    /// The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
+    pub max_attendees: Option<i64>,
+    /// Deprecated. Please use sendUpdates instead.
+    ///
+    /// Whether to send notifications about the creation of the new event. Note that some emails might still be sent even if you set the value to false. The default is false.
+    pub send_notifications: Option<bool>,
+    /// Whether to send notifications about the creation of the new event. Note that some emails might still be sent. The default is false.
+    pub send_updates: Option<crate::types::SendUpdates>,
+    /// Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+    pub supports_attachments: Option<bool>,
+}
+
+impl EventsInsertParams {
+    pub fn conference_data_version(mut self, conference_data_version: u64) -> Self {
+        self.conference_data_version = Some(conference_data_version);
+        self
+    }
+
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.send_updates = Some(send_updates);
+        self
+    }
+
+    pub fn supports_attachments(mut self, supports_attachments: bool) -> Self {
+        self.supports_attachments = Some(supports_attachments);
+        self
+    }
+}
+
+/// The query parameters of `Events::import_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsImportParams {
+    /// Version number of conference data supported by the API client. Version 0 assumes no conference data support and ignores conference data in the event's body. Version 1 enables support for copying of ConferenceData as well as for creating new conferences using the createRequest field of conferenceData. The default is 0.
+    pub conference_data_version: Option<u64>,
+    /// Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+    pub supports_attachments: Option<bool>,
+}
+
+impl EventsImportParams {
+    pub fn conference_data_version(mut self, conference_data_version: u64) -> Self {
+        self.conference_data_version = Some(conference_data_version);
+        self
+    }
+
+    pub fn supports_attachments(mut self, supports_attachments: bool) -> Self {
+        self.supports_attachments = Some(supports_attachments);
+        self
+    }
+}
+
+/// The query parameters of `Events::quick_add_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsQuickAddParams {
+    /// Deprecated. Please use sendUpdates instead.
+    ///
+    /// Whether to send notifications about the creation of the event. Note that some emails might still be sent even if you set the value to false. The default is false.
+    pub send_notifications: Option<bool>,
+    /// Guests who should receive notifications about the change of the event's organizer.
+    pub send_updates: Option<crate::types::SendUpdates>,
+    /// The text describing the event to be created.
+    pub text: Option<String>,
+}
+
+impl EventsQuickAddParams {
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.send_updates = Some(send_updates);
+        self
+    }
+
+    pub fn text<T: ToString>(mut self, text: T) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::watch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsWatchParams {
+    /// Specifies event ID in the iCalendar format to be included in the response. Optional.
+    pub i_cal_uid: Option<String>,
+    /// The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
+    pub max_attendees: Option<i64>,
+    /// Maximum number of events returned on one result page. The number of events in the resulting page may be less than this value, or none at all, even if there are more events matching the query. Incomplete pages can be detected by a non-empty nextPageToken field in the response. By default the value is 250 events. The page size can never be larger than 2500 events. Optional.
+    pub max_results: Option<i64>,
+    /// The order of the events returned in the result. Optional. The default is an unspecified, stable order.
+    pub order_by: Option<crate::types::OrderBy>,
+    /// Token specifying which result page to return. Optional.
+    pub page_token: Option<String>,
+    /// Extended properties constraint specified as propertyName=value. Matches only private properties. This parameter might be repeated multiple times to return events that match all given constraints.
+    pub private_extended_property: Option<Vec<String>>,
+    /// Free text search terms to find events that match these terms in any field, except for extended properties. Optional.
+    pub q: Option<String>,
+    /// Extended properties constraint specified as propertyName=value. Matches only shared properties. This parameter might be repeated multiple times to return events that match all given constraints.
+    pub shared_extended_property: Option<Vec<String>>,
+    /// Whether to include deleted events (with status equals "cancelled") in the result. Cancelled instances of recurring events (but not the underlying recurring event) will still be included if showDeleted and singleEvents are both False. If showDeleted and singleEvents are both True, only single instances of deleted events (but not the underlying recurring events) are returned. Optional. The default is False.
+    pub show_deleted: Option<bool>,
+    /// Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+    pub show_hidden_invitations: Option<bool>,
+    /// Whether to expand recurring events into instances and only return single one-off events and instances of recurring events, but not the underlying recurring events themselves. Optional. The default is False.
+    pub single_events: Option<bool>,
+    /// Upper bound (exclusive) for an event's start time to filter by. Optional. The default is not to filter by start time. Must be an RFC3339 timestamp with mandatory time zone offset, for example, 2011-06-03T10:00:00-07:00, 2011-06-03T10:00:00Z. Milliseconds may be provided but are ignored. If timeMin is set, timeMax must be greater than timeMin.
+    pub time_max: Option<String>,
+    /// Lower bound (exclusive) for an event's end time to filter by. Optional. The default is not to filter by end time. Must be an RFC3339 timestamp with mandatory time zone offset, for example, 2011-06-03T10:00:00-07:00, 2011-06-03T10:00:00Z. Milliseconds may be provided but are ignored. If timeMax is set, timeMin must be smaller than timeMax.
+    pub time_min: Option<String>,
+    /// Time zone used in the response. Optional. The default is the time zone of the calendar.
+    pub time_zone: Option<String>,
+    /// Lower bound for an event's last modification time (as a RFC3339 timestamp) to filter by. When specified, entries deleted since this time will always be included regardless of showDeleted. Optional. The default is not to filter by last modification time.
+    pub updated_min: Option<String>,
+}
+
+impl EventsWatchParams {
+    pub fn i_cal_uid<T: ToString>(mut self, i_cal_uid: T) -> Self {
+        self.i_cal_uid = Some(i_cal_uid.to_string());
+        self
+    }
+
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn order_by(mut self, order_by: crate::types::OrderBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn private_extended_property(mut self, private_extended_property: Vec<String>) -> Self {
+        self.private_extended_property = Some(private_extended_property);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn shared_extended_property(mut self, shared_extended_property: Vec<String>) -> Self {
+        self.shared_extended_property = Some(shared_extended_property);
+        self
+    }
+
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    pub fn show_hidden_invitations(mut self, show_hidden_invitations: bool) -> Self {
+        self.show_hidden_invitations = Some(show_hidden_invitations);
+        self
+    }
+
+    pub fn single_events(mut self, single_events: bool) -> Self {
+        self.single_events = Some(single_events);
+        self
+    }
+
+    pub fn time_max<T: ToString>(mut self, time_max: T) -> Self {
+        self.time_max = Some(time_max.to_string());
+        self
+    }
+
+    pub fn time_min<T: ToString>(mut self, time_min: T) -> Self {
+        self.time_min = Some(time_min.to_string());
+        self
+    }
+
+    pub fn time_zone<T: ToString>(mut self, time_zone: T) -> Self {
+        self.time_zone = Some(time_zone.to_string());
+        self
+    }
+
+    pub fn updated_min<T: ToString>(mut self, updated_min: T) -> Self {
+        self.updated_min = Some(updated_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsGetParams {
+    /// The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
+    pub max_attendees: Option<i64>,
+    /// Time zone used in the response. Optional. The default is the time zone of the calendar.
+    pub time_zone: Option<String>,
+}
+
+impl EventsGetParams {
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    pub fn time_zone<T: ToString>(mut self, time_zone: T) -> Self {
+        self.time_zone = Some(time_zone.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsUpdateParams {
+    /// Version number of conference data supported by the API client. Version 0 assumes no conference data support and ignores conference data in the event's body. Version 1 enables support for copying of ConferenceData as well as for creating new conferences using the createRequest field of conferenceData. The default is 0.
+    pub conference_data_version: Option<u64>,
+    /// The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
+    pub max_attendees: Option<i64>,
+    /// Deprecated. Please use sendUpdates instead.
+    ///
+    /// Whether to send notifications about the event update (for example, description changes, etc.). Note that some emails might still be sent even if you set the value to false. The default is false.
+    pub send_notifications: Option<bool>,
+    /// Guests who should receive notifications about the event update (for example, title changes, etc.).
+    pub send_updates: Option<crate::types::SendUpdates>,
+    /// Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+    pub supports_attachments: Option<bool>,
+}
+
+impl EventsUpdateParams {
+    pub fn conference_data_version(mut self, conference_data_version: u64) -> Self {
+        self.conference_data_version = Some(conference_data_version);
+        self
+    }
+
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.send_updates = Some(send_updates);
+        self
+    }
+
+    pub fn supports_attachments(mut self, supports_attachments: bool) -> Self {
+        self.supports_attachments = Some(supports_attachments);
+        self
+    }
+}
+
+/// The query parameters of `Events::delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeleteParams {
+    /// Deprecated. Please use sendUpdates instead.
+    ///
+    /// Whether to send notifications about the deletion of the event. Note that some emails might still be sent even if you set the value to false. The default is false.
+    pub send_notifications: Option<bool>,
+    /// Guests who should receive notifications about the change of the event's organizer.
+    pub send_updates: Option<crate::types::SendUpdates>,
+}
+
+impl EventsDeleteParams {
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.send_updates = Some(send_updates);
+        self
+    }
+}
+
+/// The query parameters of `Events::patch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsPatchParams {
+    /// Version number of conference data supported by the API client. Version 0 assumes no conference data support and ignores conference data in the event's body. Version 1 enables support for copying of ConferenceData as well as for creating new conferences using the createRequest field of conferenceData. The default is 0.
+    pub conference_data_version: Option<u64>,
+    /// The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
+    pub max_attendees: Option<i64>,
+    /// Deprecated. Please use sendUpdates instead.
+    ///
+    /// Whether to send notifications about the event update (for example, description changes, etc.). Note that some emails might still be sent even if you set the value to false. The default is false.
+    pub send_notifications: Option<bool>,
+    /// Guests who should receive notifications about the event update (for example, title changes, etc.).
+    pub send_updates: Option<crate::types::SendUpdates>,
+    /// Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+    pub supports_attachments: Option<bool>,
+}
+
+impl EventsPatchParams {
+    pub fn conference_data_version(mut self, conference_data_version: u64) -> Self {
+        self.conference_data_version = Some(conference_data_version);
+        self
+    }
+
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.send_updates = Some(send_updates);
+        self
+    }
+
+    pub fn supports_attachments(mut self, supports_attachments: bool) -> Self {
+        self.supports_attachments = Some(supports_attachments);
+        self
+    }
+}
+
+/// The query parameters of `Events::instances_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsInstancesParams {
+    /// The maximum number of attendees to include in the response. If there are more than the specified number of attendees, only the participant is returned. Optional.
+    pub max_attendees: Option<i64>,
+    /// Maximum number of events returned on one result page. By default the value is 250 events. The page size can never be larger than 2500 events. Optional.
+    pub max_results: Option<i64>,
+    /// The original start time of the instance in the result. Optional.
+    pub original_start: Option<String>,
+    /// Token specifying which result page to return. Optional.
+    pub page_token: Option<String>,
+    /// Whether to include deleted events (with status equals "cancelled") in the result. Cancelled instances of recurring events will still be included if singleEvents is False. Optional. The default is False.
+    pub show_deleted: Option<bool>,
+    /// Upper bound (exclusive) for an event's start time to filter by. Optional. The default is not to filter by start time. Must be an RFC3339 timestamp with mandatory time zone offset.
+    pub time_max: Option<String>,
+    /// Lower bound (inclusive) for an event's end time to filter by. Optional. The default is not to filter by end time. Must be an RFC3339 timestamp with mandatory time zone offset.
+    pub time_min: Option<String>,
+    /// Time zone used in the response. Optional. The default is the time zone of the calendar.
+    pub time_zone: Option<String>,
+}
+
+impl EventsInstancesParams {
+    pub fn max_attendees(mut self, max_attendees: i64) -> Self {
+        self.max_attendees = Some(max_attendees);
+        self
+    }
+
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn original_start<T: ToString>(mut self, original_start: T) -> Self {
+        self.original_start = Some(original_start.to_string());
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+
+    pub fn time_max<T: ToString>(mut self, time_max: T) -> Self {
+        self.time_max = Some(time_max.to_string());
+        self
+    }
+
+    pub fn time_min<T: ToString>(mut self, time_min: T) -> Self {
+        self.time_min = Some(time_min.to_string());
+        self
+    }
+
+    pub fn time_zone<T: ToString>(mut self, time_zone: T) -> Self {
+        self.time_zone = Some(time_zone.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::mv_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsMvParams {
+    /// Calendar identifier of the target calendar where the event is to be moved to.
+    pub destination: Option<String>,
+    /// Deprecated. Please use sendUpdates instead.
+    ///
+    /// Whether to send notifications about the change of the event's organizer. Note that some emails might still be sent even if you set the value to false. The default is false.
+    pub send_notifications: Option<bool>,
+    /// Guests who should receive notifications about the change of the event's organizer.
+    pub send_updates: Option<crate::types::SendUpdates>,
+}
+
+impl EventsMvParams {
+    pub fn destination<T: ToString>(mut self, destination: T) -> Self {
+        self.destination = Some(destination.to_string());
+        self
+    }
+
+    pub fn send_notifications(mut self, send_notifications: bool) -> Self {
+        self.send_notifications = Some(send_notifications);
+        self
+    }
+
+    pub fn send_updates(mut self, send_updates: crate::types::SendUpdates) -> Self {
+        self.send_updates = Some(send_updates);
+        self
+    }
+}
+
+/// The functions of [`Events`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEventsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EventsApi: Send + Sync {
+    /// See [`Events::list_with`].
+    async fn list_with(
+        &self,
+        calendar_id: &str,
+        params: &EventsListParams,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Events::list_all_with`].
+    async fn list_all_with(
+        &self,
+        calendar_id: &str,
+        params: &EventsListParams,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Events::list_stream`].
+    fn list_stream(
+        &self,
+        calendar_id: &str,
+        params: &EventsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event>;
+
+    /// See [`Events::insert_with`].
+    async fn insert_with(
+        &self,
+        calendar_id: &str,
+        params: &EventsInsertParams,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event>;
+
+    /// See [`Events::import_with`].
+    async fn import_with(
+        &self,
+        calendar_id: &str,
+        params: &EventsImportParams,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event>;
+
+    /// See [`Events::quick_add_with`].
+    async fn quick_add_with(
+        &self,
+        calendar_id: &str,
+        params: &EventsQuickAddParams,
+    ) -> Result<crate::types::Event>;
+
+    /// See [`Events::watch_with`].
+    async fn watch_with(
+        &self,
+        calendar_id: &str,
+        params: &EventsWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+
+    /// See [`Events::get_with`].
+    async fn get_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsGetParams,
+    ) -> Result<crate::types::Event>;
+
+    /// See [`Events::update_with`].
+    async fn update_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsUpdateParams,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event>;
+
+    /// See [`Events::delete_with`].
+    async fn delete_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsDeleteParams,
+    ) -> Result<()>;
+
+    /// See [`Events::patch_with`].
+    async fn patch_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsPatchParams,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event>;
+
+    /// See [`Events::instances_with`].
+    async fn instances_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsInstancesParams,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Events::get_all_instances_with`].
+    async fn get_all_instances_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsInstancesParams,
+    ) -> Result<Vec<crate::types::Event>>;
+
+    /// See [`Events::instances_stream`].
+    fn instances_stream(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsInstancesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event>;
+
+    /// See [`Events::mv_with`].
+    async fn mv_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsMvParams,
+    ) -> Result<crate::types::Event>;
+}
+
+#[async_trait::async_trait]
+impl EventsApi for Events {
+    async fn list_with(
+        &self,
+        calendar_id: &str,
+        params: &EventsListParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        Events::list_with(self, calendar_id, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        calendar_id: &str,
+        params: &EventsListParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        Events::list_all_with(self, calendar_id, params).await
+    }
+
+    fn list_stream(
+        &self,
+        calendar_id: &str,
+        params: &EventsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        Events::list_stream(self, calendar_id, params, start, max_items)
+    }
+
+    async fn insert_with(
+        &self,
+        calendar_id: &str,
+        params: &EventsInsertParams,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event> {
+        Events::insert_with(self, calendar_id, params, body).await
+    }
+
+    async fn import_with(
+        &self,
+        calendar_id: &str,
+        params: &EventsImportParams,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event> {
+        Events::import_with(self, calendar_id, params, body).await
+    }
+
+    async fn quick_add_with(
+        &self,
+        calendar_id: &str,
+        params: &EventsQuickAddParams,
+    ) -> Result<crate::types::Event> {
+        Events::quick_add_with(self, calendar_id, params).await
+    }
+
+    async fn watch_with(
+        &self,
+        calendar_id: &str,
+        params: &EventsWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Events::watch_with(self, calendar_id, params, body).await
+    }
+
+    async fn get_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsGetParams,
+    ) -> Result<crate::types::Event> {
+        Events::get_with(self, calendar_id, event_id, params).await
+    }
+
+    async fn update_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsUpdateParams,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event> {
+        Events::update_with(self, calendar_id, event_id, params, body).await
+    }
+
+    async fn delete_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsDeleteParams,
+    ) -> Result<()> {
+        Events::delete_with(self, calendar_id, event_id, params).await
+    }
+
+    async fn patch_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsPatchParams,
+        body: &crate::types::Event,
+    ) -> Result<crate::types::Event> {
+        Events::patch_with(self, calendar_id, event_id, params, body).await
+    }
+
+    async fn instances_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsInstancesParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        Events::instances_with(self, calendar_id, event_id, params).await
+    }
+
+    async fn get_all_instances_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsInstancesParams,
+    ) -> Result<Vec<crate::types::Event>> {
+        Events::get_all_instances_with(self, calendar_id, event_id, params).await
+    }
+
+    fn instances_stream(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsInstancesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Event> {
+        Events::instances_stream(self, calendar_id, event_id, params, start, max_items)
+    }
+
+    async fn mv_with(
+        &self,
+        calendar_id: &str,
+        event_id: &str,
+        params: &EventsMvParams,
+    ) -> Result<crate::types::Event> {
+        Events::mv_with(self, calendar_id, event_id, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Freebusy {
     pub client: Client,
@@ -27,3 +25,25 @@ impl Freebusy {
             .await
     }
 }
+
+/// The functions of [`Freebusy`], for code that takes them as a trait, so that it can be
+/// tested with the `MockFreebusyApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait FreebusyApi: Send + Sync {
+    /// See [`Freebusy::query`].
+    async fn query(
+        &self,
+        body: &crate::types::FreeBusyRequest,
+    ) -> Result<crate::types::FreeBusyResponse>;
+}
+
+#[async_trait::async_trait]
+impl FreebusyApi for Freebusy {
+    async fn query(
+        &self,
+        body: &crate::types::FreeBusyRequest,
+    ) -> Result<crate::types::FreeBusyResponse> {
+        Freebusy::query(self, body).await
+    }
+}
@@ -30,6 +30,30 @@
 //! up to date as features are added. The documentation for the crate is generated
 //! along with the code to make this library easy to use.
 //!
+//! Each tag of the API is a cargo feature of its own, all of them enabled by the
+//! default `full` feature. To build only the tags you use, turn off the default
+//! features and enable those tags, along with `rustls-tls` or `native-tls`.
+//!
+//! The functions of each tag are also a trait, named after the tag, like `UsersApi`
+//! for `users`. Code that takes the trait can be tested with the mocks of the `mock`
+//! feature, like `MockUsersApi`, rather than a server.
+//!
+//! Requests wait out the rate limits of the API. Those that are safe to send twice
+//! are sent again after the `Retry-After` of rate limited responses, and all of them
+//! wait for the limit to reset when none of it is left. `Client::rate_limit_status` tells what the API last said about its
+//! limits.
+//!
+//! Requests that time out, can't connect or get a `5xx` that may not last are
+//! retried, waiting longer before each attempt, but only those that are safe to send
+//! twice: `GET`s, `HEAD`s, `OPTIONS` and `TRACE`s. `Client::builder().retry_policy(...)`
+//! changes how many times, how long to wait and which methods. For the APIs that take
+//! idempotency keys, the `POST`s of `Client::with_idempotency_key` are retried too.
+//!
+//! `Client::builder()` also sets the timeouts, proxies, root certificates, client
+//! certificate and `User-Agent` of the HTTP client, or takes a
+//! `reqwest_middleware::ClientWithMiddleware` of your own. Its `build` takes what
+//! `Client::new` does.
+//!
 //!
 //! To install the library, add the following to your `Cargo.toml` file.
 //!
@@ -100,28 +124,80 @@
 //!     access_token = google calendar.refresh_access_token().await.unwrap();
 //! }
 //! ```
+//!
+//! With `set_auto_access_token_refresh(true)`, the client refreshes the access token
+//! before requests once it expires. With `set_refresh_on_unauthorized(true)`, it also
+//! does when a request is rejected with `401 Unauthorized` because of the access
+//! token, and sends the request again. The clones of a client that need a refresh at
+//! the same time wait for a single one.
+//!
+//! ## Keeping tokens
+//!
+//! The client only has its tokens in memory, and some providers give a new refresh
+//! token with each refresh, which makes the old one useless. To keep the tokens across
+//! restarts, give the client a `TokenStore`, like a file. It starts with the tokens
+//! saved there, if any, and saves the new ones it gets.
+//!
+//! ```
+//! use google_calendar::{token_store::FileTokenStore, Client};
+//!
+//! async fn do_call() {
+//!     let google calendar = Client::new(
+//!         String::from("client-id"),
+//!         String::from("client-secret"),
+//!         String::from("redirect-uri"),
+//!         String::from("token"),
+//!         String::from("refresh-token")
+//!     )
+//!     .with_token_store(FileTokenStore::new("tokens.json"))
+//!     .await
+//!     .unwrap();
+//! }
+//! ```
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::nonstandard_macro_braces)]
 #![allow(clippy::large_enum_variant)]
+#![allow(clippy::result_large_err)]
 #![allow(clippy::tabs_in_doc_comments)]
 #![allow(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "acl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "acl")))]
 pub mod acl;
+#[cfg(feature = "calendar_list")]
+#[cfg_attr(docsrs, doc(cfg(feature = "calendar_list")))]
 pub mod calendar_list;
+#[cfg(feature = "calendars")]
+#[cfg_attr(docsrs, doc(cfg(feature = "calendars")))]
 pub mod calendars;
+#[cfg(feature = "channels")]
+#[cfg_attr(docsrs, doc(cfg(feature = "channels")))]
 pub mod channels;
+#[cfg(feature = "colors")]
+#[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
 pub mod colors;
+mod error;
+#[cfg(feature = "events")]
+#[cfg_attr(docsrs, doc(cfg(feature = "events")))]
 pub mod events;
+#[cfg(feature = "freebusy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "freebusy")))]
 pub mod freebusy;
+pub mod pagination;
+pub mod rate_limiter;
+pub mod retry;
+#[cfg(feature = "settings")]
+#[cfg_attr(docsrs, doc(cfg(feature = "settings")))]
 pub mod settings;
 #[cfg(test)]
 mod tests;
+pub mod token_store;
 pub mod types;
 #[doc(hidden)]
 pub mod utils;
 
-use anyhow::{anyhow, Error, Result};
+pub use error::{Error, ErrorBody, Result};
 
 pub const DEFAULT_HOST: &str = "https://www.googleapis.com/calendar/v3";
 
@@ -145,6 +221,176 @@ mod progenitor_support {
     }
 }
 
+/// The body of a response that is not JSON, such as a PDF, an image or an archive.
+#[derive(Debug, Clone, Default)]
+pub struct Download {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: bytes::Bytes,
+}
+
+/// The body of a response that is not JSON, returned as a stream of chunks rather
+/// than read into memory all at once.
+pub struct DownloadStream {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: std::pin::Pin<Box<dyn futures::Stream<Item = Result<bytes::Bytes>> + Send>>,
+}
+
+impl std::fmt::Debug for DownloadStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DownloadStream")
+            .field("content_type", &self.content_type)
+            .finish()
+    }
+}
+
+/// Configures the HTTP client of a `Client`, with the defaults of `Client::new` unless
+/// told otherwise. Its `build` takes what `Client::new` does.
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    retry_policy: crate::retry::RetryPolicy,
+    max_rate_limit_wait: Option<std::time::Duration>,
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    user_agent: Option<String>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    root_certificates: Vec<reqwest::Certificate>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    identity: Option<reqwest::Identity>,
+    http_client: Option<reqwest_middleware::ClientWithMiddleware>,
+}
+
+impl ClientBuilder {
+    /// Set how failed requests are retried.
+    pub fn retry_policy(mut self, retry_policy: crate::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set how long requests wait for a rate limit to reset, at most,
+    /// `rate_limiter::MAX_WAIT` unless set.
+    pub fn max_rate_limit_wait(mut self, max_wait: std::time::Duration) -> Self {
+        self.max_rate_limit_wait = Some(max_wait);
+        self
+    }
+
+    /// Set how long each attempt at a request can take, from connecting to reading the
+    /// whole response. There is no limit unless set.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set how long connecting to the API can take.
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests through a proxy, like `reqwest::Proxy::https(url)`. Without one,
+    /// the proxies of the `HTTP_PROXY` and `HTTPS_PROXY` environment variables are used.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Set the `User-Agent` of requests.
+    pub fn user_agent<U>(mut self, user_agent: U) -> Self
+    where
+        U: ToString,
+    {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Trust the certificates signed by a certificate authority, along with those of
+    /// the system, like a private one of `reqwest::Certificate::from_pem(pem)`.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Authenticate to the API with a client certificate, for mutual TLS.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn identity(mut self, identity: reqwest::Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Send requests with a client of your own, with its own middleware. The other
+    /// settings of the builder don't apply to it, and it only retries or waits out
+    /// rate limits if it has a `retry::Retry` or a `rate_limiter::RateLimiter` of its
+    /// own.
+    pub fn http_client(mut self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /*
+     * The HTTP client, along with the rate limiter in its middleware.
+     */
+    fn http(
+        self,
+    ) -> Result<(
+        reqwest_middleware::ClientWithMiddleware,
+        crate::rate_limiter::RateLimiter,
+    )> {
+        if let Some(client) = self.http_client {
+            return Ok((client, Default::default()));
+        }
+
+        let mut rate_limiter =
+            crate::rate_limiter::RateLimiter::new().with_retry_policy(self.retry_policy.clone());
+        if let Some(max_wait) = self.max_rate_limit_wait {
+            rate_limiter = rate_limiter.with_max_wait(max_wait);
+        }
+
+        let mut http = reqwest::Client::builder();
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        for proxy in self.proxies {
+            http = http.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(user_agent);
+        }
+        #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+        {
+            for certificate in self.root_certificates {
+                http = http.add_root_certificate(certificate);
+            }
+            if let Some(identity) = self.identity {
+                http = http.identity(identity);
+            }
+        }
+
+        let client = reqwest_middleware::ClientBuilder::new(http.build()?)
+            // Trace HTTP requests. See the tracing crate to make use of these traces.
+            .with(reqwest_tracing::TracingMiddleware::default())
+            // Retry failed requests.
+            .with(crate::retry::Retry::new(self.retry_policy))
+            // Wait out the rate limits of the API, before the retries see them.
+            .with(rate_limiter.clone())
+            .build();
+
+        Ok((client, rate_limiter))
+    }
+}
+
+impl Client {
+    /// Configure the client before creating it.
+    pub fn builder() -> ClientBuilder {
+        Default::default()
+    }
+}
+
 use std::{
     convert::TryInto,
     env,
@@ -168,7 +414,12 @@ pub struct Client {
     redirect_uri: String,
 
     auto_refresh: bool,
+    refresh_on_unauthorized: bool,
+    // The last refresh, which the others wait for while it is in flight.
+    refreshing: Arc<tokio::sync::Mutex<Option<AccessToken>>>,
+    token_store: Arc<dyn crate::token_store::TokenStore>,
     client: reqwest_middleware::ClientWithMiddleware,
+    rate_limiter: crate::rate_limiter::RateLimiter,
 }
 
 use schemars::JsonSchema;
@@ -209,6 +460,32 @@ pub struct AccessToken {
     pub scope: String,
 }
 
+/// The error code of the API for an invalid access token, as the JSON pointer of where
+/// it is in the body of a `401 Unauthorized` and its value.
+const INVALID_TOKEN_CODE: Option<(&str, &str)> = None;
+
+/// Whether the `WWW-Authenticate` header of a `401 Unauthorized` says the access token
+/// is invalid, or expired.
+fn is_invalid_token(headers: &reqwest::header::HeaderMap) -> bool {
+    headers
+        .get_all(http::header::WWW_AUTHENTICATE)
+        .iter()
+        .filter_map(|h| h.to_str().ok())
+        .any(|h| h.contains("invalid_token"))
+}
+
+fn has_error_code(body: &[u8], (pointer, code): (&str, &str)) -> bool {
+    match serde_json::from_slice::<serde_json::Value>(body)
+        .ok()
+        .as_ref()
+        .and_then(|v| v.pointer(pointer))
+    {
+        Some(serde_json::Value::String(s)) => s == code,
+        Some(serde_json::Value::Number(n)) => n.to_string() == code,
+        _ => false,
+    }
+}
+
 /// Time in seconds before the access token expiration point that a refresh should
 /// be performed. This value is subtracted from the `expires_in` value returned by
 /// the provider prior to storing
@@ -221,6 +498,72 @@ struct InnerToken {
     expires_at: Option<Instant>,
 }
 
+impl From<&InnerToken> for crate::token_store::StoredToken {
+    fn from(t: &InnerToken) -> Self {
+        crate::token_store::StoredToken {
+            access_token: t.access_token.clone(),
+            refresh_token: t.refresh_token.clone(),
+            expires_at: t.expires_at.map(|i| {
+                let left = i.saturating_duration_since(Instant::now());
+                chrono::Utc::now()
+                    + chrono::Duration::from_std(left).unwrap_or_else(|_| chrono::Duration::zero())
+            }),
+        }
+    }
+}
+
+impl From<crate::token_store::StoredToken> for InnerToken {
+    fn from(t: crate::token_store::StoredToken) -> Self {
+        InnerToken {
+            access_token: t.access_token,
+            refresh_token: t.refresh_token,
+            expires_at: t
+                .expires_at
+                .map(|e| Instant::now() + (e - chrono::Utc::now()).to_std().unwrap_or_default()),
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Create the client, see `Client::new`.
+    pub fn build<I, K, R, T, Q>(
+        self,
+        client_id: I,
+        client_secret: K,
+        redirect_uri: R,
+        token: T,
+        refresh_token: Q,
+    ) -> Result<Client>
+    where
+        I: ToString,
+        K: ToString,
+        R: ToString,
+        T: ToString,
+        Q: ToString,
+    {
+        let (client, rate_limiter) = self.http()?;
+
+        Ok(Client {
+            host: DEFAULT_HOST.to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            token: Arc::new(RwLock::new(InnerToken {
+                access_token: token.to_string(),
+                refresh_token: refresh_token.to_string(),
+                expires_at: None,
+            })),
+
+            auto_refresh: false,
+            refresh_on_unauthorized: false,
+            refreshing: Default::default(),
+            token_store: Arc::new(crate::token_store::MemoryTokenStore::new()),
+            client,
+            rate_limiter,
+        })
+    }
+}
+
 impl Client {
     /// Create a new Client struct. It takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -239,37 +582,8 @@ impl Client {
         T: ToString,
         Q: ToString,
     {
-        // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest::Client::builder().build();
-        match client {
-            Ok(c) => {
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
-                    .build();
-
-                Client {
-                    host: DEFAULT_HOST.to_string(),
-                    client_id: client_id.to_string(),
-                    client_secret: client_secret.to_string(),
-                    redirect_uri: redirect_uri.to_string(),
-                    token: Arc::new(RwLock::new(InnerToken {
-                        access_token: token.to_string(),
-                        refresh_token: refresh_token.to_string(),
-                        expires_at: None,
-                    })),
-
-                    auto_refresh: false,
-                    client,
-                }
-            }
+        match Self::builder().build(client_id, client_secret, redirect_uri, token, refresh_token) {
+            Ok(c) => c,
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
         }
     }
@@ -280,6 +594,14 @@ impl Client {
         self
     }
 
+    /// Enables or disables refreshing the access token when a request is rejected with
+    /// `401 Unauthorized` because of it, like when its expiration is unknown, and sending
+    /// the request again with the new one. Requests with a streamed body aren't.
+    pub fn set_refresh_on_unauthorized(&mut self, enabled: bool) -> &mut Self {
+        self.refresh_on_unauthorized = enabled;
+        self
+    }
+
     /// Sets a specific `Instant` at which the access token should be considered expired.
     /// The expiration value will only be used when automatic access token refreshing is
     /// also enabled. `None` may be passed in if the expiration is unknown. In this case
@@ -344,6 +666,28 @@ impl Client {
         c
     }
 
+    /// Keep the tokens of the client in a store. The tokens saved there, if any, replace
+    /// those the client was created with, and new tokens are saved to it.
+    pub async fn with_token_store<S>(mut self, store: S) -> Result<Self>
+    where
+        S: crate::token_store::TokenStore + 'static,
+    {
+        if let Some(t) = store.load().await? {
+            self.token = Arc::new(RwLock::new(t.into()));
+        }
+        self.token_store = Arc::new(store);
+        Ok(self)
+    }
+
+    /*
+     * Save the tokens of the client to its store.
+     */
+    async fn save_token(&self) -> Result<crate::token_store::StoredToken> {
+        let token = crate::token_store::StoredToken::from(&*self.token.read().await);
+        self.token_store.save(&token).await?;
+        Ok(token)
+    }
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -360,38 +704,13 @@ impl Client {
         let secret = yup_oauth2::parse_application_secret(decoded_google_key)
             .expect("failed to read from google credential env var");
 
-        let client = reqwest::Client::builder().build();
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-
-        match client {
-            Ok(c) => {
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
-                    .build();
-
-                Client {
-                    host: DEFAULT_HOST.to_string(),
-                    client_id: secret.client_id.to_string(),
-                    client_secret: secret.client_secret.to_string(),
-                    redirect_uri: secret.redirect_uris[0].to_string(),
-                    token: Arc::new(RwLock::new(InnerToken {
-                        access_token: token.to_string(),
-                        refresh_token: refresh_token.to_string(),
-                        expires_at: None,
-                    })),
-                    auto_refresh: false,
-                    client,
-                }
-            }
-            Err(e) => panic!("creating reqwest client failed: {:?}", e),
-        }
+        Client::new(
+            secret.client_id,
+            secret.client_secret,
+            &secret.redirect_uris[0],
+            token,
+            refresh_token,
+        )
     }
 
     /// Return a user consent url with an optional set of scopes.
@@ -413,13 +732,37 @@ impl Client {
     }
 
     /// Refresh an access token from a refresh token. Client must have a refresh token
-    /// for this to work.
+    /// for this to work. The clones of a client refresh once when they do at the same
+    /// time, those that wait for another refresh get its token.
     pub async fn refresh_access_token(&self) -> Result<AccessToken> {
+        let stale = self.token.read().await.access_token.clone();
+        self.refresh_access_token_once(&stale).await
+    }
+
+    /*
+     * Refresh the `stale` access token, unless another refresh replaced it while this one
+     * waited for it.
+     */
+    async fn refresh_access_token_once(&self, stale: &str) -> Result<AccessToken> {
+        let mut last = self.refreshing.lock().await;
+        if let Some(t) = last.as_ref() {
+            let current = &self.token.read().await.access_token;
+            if current != stale && *current == t.access_token {
+                return Ok(t.clone());
+            }
+        }
+
+        let t = self.request_refreshed_access_token().await?;
+        *last = Some(t.clone());
+        Ok(t)
+    }
+
+    async fn request_refreshed_access_token(&self) -> Result<AccessToken> {
         let response = {
             let refresh_token = &self.token.read().await.refresh_token;
 
             if refresh_token.is_empty() {
-                anyhow!("refresh token cannot be empty");
+                return Err(Error::Auth("refresh token cannot be empty".to_string()));
             }
 
             let mut headers = reqwest::header::HeaderMap::new();
@@ -445,10 +788,21 @@ impl Client {
                 .await?
         };
 
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await?;
+            return Err(Error::Auth(format!("code: {}, error: {:?}", status, body)));
+        }
+
         // Unwrap the response.
         let t: AccessToken = response.json().await?;
 
-        let refresh_token = self.token.read().await.refresh_token.clone();
+        // Providers that rotate refresh tokens send a new one, the old one no longer works.
+        let refresh_token = if t.refresh_token.is_empty() {
+            self.token.read().await.refresh_token.clone()
+        } else {
+            t.refresh_token.clone()
+        };
 
         *self.token.write().await = InnerToken {
             access_token: t.access_token.clone(),
@@ -456,6 +810,9 @@ impl Client {
             expires_at: Self::compute_expires_at(t.expires_in),
         };
 
+        let token = self.save_token().await?;
+        self.token_store.on_refresh(&token).await;
+
         Ok(t)
     }
 
@@ -485,6 +842,12 @@ impl Client {
             .send()
             .await?;
 
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await?;
+            return Err(Error::Auth(format!("code: {}, error: {:?}", status, body)));
+        }
+
         // Unwrap the response.
         let t: AccessToken = resp.json().await?;
 
@@ -494,6 +857,8 @@ impl Client {
             expires_at: Self::compute_expires_at(t.expires_in),
         };
 
+        self.save_token().await?;
+
         Ok(t)
     }
 
@@ -509,6 +874,8 @@ impl Client {
         method: &reqwest::Method,
         uri: &str,
         body: Option<reqwest::Body>,
+        accept: &str,
+        content_type: &str,
     ) -> Result<reqwest::Request> {
         let u = if uri.starts_with("https://") {
             uri.to_string()
@@ -524,11 +891,11 @@ impl Client {
         // Set the default headers.
         req = req.header(
             reqwest::header::ACCEPT,
-            reqwest::header::HeaderValue::from_static("application/json"),
+            reqwest::header::HeaderValue::from_str(accept)?,
         );
         req = req.header(
             reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
+            reqwest::header::HeaderValue::from_str(content_type)?,
         );
 
         if let Some(auth_str) = auth {
@@ -552,6 +919,18 @@ impl Client {
         method: reqwest::Method,
         uri: &str,
         body: Option<reqwest::Body>,
+    ) -> Result<reqwest::Response> {
+        self.request_raw_with_media_types(method, uri, body, "application/json", "application/json")
+            .await
+    }
+
+    async fn request_raw_with_media_types(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+        accept: &str,
+        content_type: &str,
     ) -> Result<reqwest::Response> {
         if self.auto_refresh {
             let expired = self.is_expired().await;
@@ -560,7 +939,8 @@ impl Client {
                 // We have a known expired token, we know we need to perform a refresh prior to
                 // attempting to make a request
                 Some(true) => {
-                    self.refresh_access_token().await?;
+                    let stale = self.token.read().await.access_token.clone();
+                    self.refresh_access_token_once(&stale).await?;
                 }
 
                 // We have a (theoretically) known good token available. We make an optimistic
@@ -580,10 +960,61 @@ impl Client {
             }
         }
 
-        let req = self.make_request(&method, uri, body).await?;
+        let stale = self.token.read().await.access_token.clone();
+        let req = self
+            .make_request(&method, uri, body, accept, content_type)
+            .await?;
+        let replay = if self.refresh_on_unauthorized {
+            req.try_clone()
+        } else {
+            None
+        };
         let resp = self.client.execute(req).await?;
 
-        Ok(resp)
+        let mut replay = match replay {
+            Some(replay) if resp.status() == http::StatusCode::UNAUTHORIZED => replay,
+            _ => return Ok(resp),
+        };
+
+        if !is_invalid_token(resp.headers()) {
+            // The API may only say so in the body, which we have to read to find out.
+            let code = match INVALID_TOKEN_CODE {
+                Some(code) => code,
+                None => return Ok(resp),
+            };
+
+            use reqwest::ResponseBuilderExt;
+            let mut builder = http::Response::builder()
+                .status(resp.status())
+                .version(resp.version())
+                .url(resp.url().clone());
+            if let Some(headers) = builder.headers_mut() {
+                *headers = resp.headers().clone();
+            }
+            let body = resp.bytes().await?;
+            if !has_error_code(&body, code) {
+                // Hand on the response as it was.
+                return builder
+                    .body(body)
+                    .map(reqwest::Response::from)
+                    .map_err(|e| Error::InvalidRequest(e.to_string()));
+            }
+        }
+
+        self.refresh_access_token_once(&stale).await?;
+        let (_, auth) = self.url_and_auth(replay.url().as_str()).await?;
+        if let Some(auth_str) = auth {
+            replay.headers_mut().insert(
+                http::header::AUTHORIZATION,
+                reqwest::header::HeaderValue::from_str(&auth_str)?,
+            );
+        }
+        Ok(self.client.execute(replay).await?)
+    }
+
+    /// The status of the rate limits of the API, as of the last responses.
+    pub fn rate_limit_status(&self) -> Vec<crate::rate_limiter::RateLimitStatus> {
+        self.rate_limiter.status()
     }
 
     async fn request<Out>(
@@ -598,6 +1029,7 @@ impl Client {
         let response = self.request_raw(method, uri, body).await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -606,26 +1038,58 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn request_form<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        form: String,
+    ) -> Result<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let response = self
+            .request_raw_with_media_types(
+                method,
+                uri,
+                Some(form.into()),
+                "application/json",
+                "application/x-www-form-urlencoded",
+            )
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
 
-            Err(error)
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            log::debug!(
+                "response payload {}",
+                String::from_utf8_lossy(&response_body)
+            );
+            if status == http::StatusCode::NO_CONTENT
+                || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
+            {
+                crate::error::decode(b"null")
+            } else {
+                crate::error::decode::<Out>(&response_body)
+            }
+        } else {
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -641,6 +1105,7 @@ impl Client {
         let response = self.request_raw(method, uri, body).await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -658,22 +1123,13 @@ impl Client {
             let parsed_response = if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
+                crate::error::decode::<Out>(&response_body)
             };
-            parsed_response.map(|out| (link, out)).map_err(Error::from)
+            parsed_response.map(|out| (link, out))
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -710,6 +1166,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -718,31 +1175,19 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {
                 // Parse the output as a string.
-                serde_json::from_value(serde_json::json!(&String::from_utf8(
-                    response_body.to_vec()
-                )?))
+                serde_json::from_value(serde_json::json!(String::from_utf8_lossy(&response_body)))
+                    .map_err(Error::from)
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -781,6 +1226,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -789,31 +1235,19 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {
                 // Parse the output as a string.
-                serde_json::from_value(serde_json::json!(&String::from_utf8(
-                    response_body.to_vec()
-                )?))
+                serde_json::from_value(serde_json::json!(String::from_utf8_lossy(&response_body)))
+                    .map_err(Error::from)
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -873,6 +1307,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -881,26 +1316,82 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    #[allow(dead_code)]
+    async fn request_download(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::Download> {
+        let response = self
+            .request_raw_with_media_types(method, uri, body, "*/*", "application/json")
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let content_type = response
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|c| c.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            Ok(crate::Download {
+                content_type,
+                body: response_body,
+            })
+        } else {
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
 
-            Err(error)
+    #[allow(dead_code)]
+    async fn request_download_stream(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::DownloadStream> {
+        let response = self
+            .request_raw_with_media_types(method, uri, body, "*/*", "application/json")
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        if status.is_success() {
+            let content_type = response
+                .headers()
+                .get(http::header::CONTENT_TYPE)
+                .and_then(|c| c.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+
+            Ok(crate::DownloadStream {
+                content_type,
+                body: Box::pin(futures::TryStreamExt::map_err(
+                    response.bytes_stream(),
+                    Error::from,
+                )),
+            })
+        } else {
+            let response_body = response.bytes().await?;
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -1020,41 +1511,57 @@ impl Client {
     }
 
     /// Return a reference to an interface that provides access to acl operations.
+    #[cfg(feature = "acl")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "acl")))]
     pub fn acl(&self) -> acl::Acl {
         acl::Acl::new(self.clone())
     }
 
     /// Return a reference to an interface that provides access to calendarList operations.
+    #[cfg(feature = "calendar_list")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "calendar_list")))]
     pub fn calendar_list(&self) -> calendar_list::CalendarList {
         calendar_list::CalendarList::new(self.clone())
     }
 
     /// Return a reference to an interface that provides access to calendars operations.
+    #[cfg(feature = "calendars")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "calendars")))]
     pub fn calendars(&self) -> calendars::Calendars {
         calendars::Calendars::new(self.clone())
     }
 
     /// Return a reference to an interface that provides access to channels operations.
+    #[cfg(feature = "channels")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "channels")))]
     pub fn channels(&self) -> channels::Channels {
         channels::Channels::new(self.clone())
     }
 
     /// Return a reference to an interface that provides access to colors operations.
+    #[cfg(feature = "colors")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "colors")))]
     pub fn colors(&self) -> colors::Colors {
         colors::Colors::new(self.clone())
     }
 
     /// Return a reference to an interface that provides access to events operations.
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
     pub fn events(&self) -> events::Events {
         events::Events::new(self.clone())
     }
 
     /// Return a reference to an interface that provides access to freebusy operations.
+    #[cfg(feature = "freebusy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "freebusy")))]
     pub fn freebusy(&self) -> freebusy::Freebusy {
         freebusy::Freebusy::new(self.clone())
     }
 
     /// Return a reference to an interface that provides access to settings operations.
+    #[cfg(feature = "settings")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "settings")))]
     pub fn settings(&self) -> settings::Settings {
         settings::Settings::new(self.clone())
     }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Settings {
     pub client: Client,
@@ -19,23 +17,17 @@ impl Settings {
      *
      * **Parameters:**
      *
-     * * `max_results: i64` -- Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
-     * * `page_token: &str` -- Token specifying which result page to return. Optional.
-     * * `sync_token: &str` -- Token obtained from the nextSyncToken field returned on the last page of results from the previous list request. It makes the result of this list request contain only entries that have changed since then.
-     *   If the syncToken expires, the server will respond with a 410 GONE response code and the client should clear its storage and perform a full synchronization without any syncToken.
-     *   Learn more about incremental synchronization.
-     *   Optional. The default is to return all entries.
+     * * `params: &SettingsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        max_results: i64,
-        page_token: &str,
+        params: &SettingsListParams,
     ) -> Result<Vec<crate::types::Setting>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -47,6 +39,22 @@ impl Settings {
         Ok(resp.items.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `SettingsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        max_results: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Setting>> {
+        let params = SettingsListParams {
+            max_results: Some(max_results).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/users/me/settings` endpoint.
      *
@@ -63,6 +71,7 @@ impl Settings {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -88,6 +97,38 @@ impl Settings {
         Ok(items)
     }
 
+    /**
+     * This function performs a `GET` to the `/users/me/settings` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns all user settings for the authenticated user.
+     */
+    pub fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Setting> {
+        let url = "/users/me/settings".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::Settings = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.items,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/users/me/settings/watch` endpoint.
      *
@@ -95,24 +136,18 @@ impl Settings {
      *
      * **Parameters:**
      *
-     * * `max_results: i64` -- Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
-     * * `page_token: &str` -- Token specifying which result page to return. Optional.
-     * * `sync_token: &str` -- Token obtained from the nextSyncToken field returned on the last page of results from the previous list request. It makes the result of this list request contain only entries that have changed since then.
-     *   If the syncToken expires, the server will respond with a 410 GONE response code and the client should clear its storage and perform a full synchronization without any syncToken.
-     *   Learn more about incremental synchronization.
-     *   Optional. The default is to return all entries.
+     * * `params: &SettingsWatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn watch(
+    pub async fn watch_with(
         &self,
-        max_results: i64,
-        page_token: &str,
+        params: &SettingsWatchParams,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if max_results > 0 {
+        if let Some(max_results) = &params.max_results {
             query_args.push(("maxResults".to_string(), max_results.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -123,6 +158,23 @@ impl Settings {
             .await
     }
 
+    /**
+     * Deprecated: use `watch_with` and pass the query parameters as a `SettingsWatchParams` instead.
+     */
+    #[deprecated(note = "use `watch_with` instead")]
+    pub async fn watch(
+        &self,
+        max_results: i64,
+        page_token: &str,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        let params = SettingsWatchParams {
+            max_results: Some(max_results).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.watch_with(&params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/users/me/settings/{setting}` endpoint.
      *
@@ -141,3 +193,105 @@ impl Settings {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Settings::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SettingsListParams {
+    /// Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
+    pub max_results: Option<i64>,
+    /// Token specifying which result page to return. Optional.
+    pub page_token: Option<String>,
+}
+
+impl SettingsListParams {
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Settings::watch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SettingsWatchParams {
+    /// Maximum number of entries returned on one result page. By default the value is 100 entries. The page size can never be larger than 250 entries. Optional.
+    pub max_results: Option<i64>,
+    /// Token specifying which result page to return. Optional.
+    pub page_token: Option<String>,
+}
+
+impl SettingsWatchParams {
+    pub fn max_results(mut self, max_results: i64) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The functions of [`Settings`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSettingsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SettingsApi: Send + Sync {
+    /// See [`Settings::list_with`].
+    async fn list_with(&self, params: &SettingsListParams) -> Result<Vec<crate::types::Setting>>;
+
+    /// See [`Settings::list_all`].
+    async fn list_all(&self) -> Result<Vec<crate::types::Setting>>;
+
+    /// See [`Settings::list_stream`].
+    fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Setting>;
+
+    /// See [`Settings::watch_with`].
+    async fn watch_with(
+        &self,
+        params: &SettingsWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+
+    /// See [`Settings::get`].
+    async fn get(&self, setting: &str) -> Result<crate::types::Setting>;
+}
+
+#[async_trait::async_trait]
+impl SettingsApi for Settings {
+    async fn list_with(&self, params: &SettingsListParams) -> Result<Vec<crate::types::Setting>> {
+        Settings::list_with(self, params).await
+    }
+
+    async fn list_all(&self) -> Result<Vec<crate::types::Setting>> {
+        Settings::list_all(self).await
+    }
+
+    fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Setting> {
+        Settings::list_stream(self, start, max_items)
+    }
+
+    async fn watch_with(
+        &self,
+        params: &SettingsWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Settings::watch_with(self, params, body).await
+    }
+
+    async fn get(&self, setting: &str) -> Result<crate::types::Setting> {
+        Settings::get(self, setting).await
+    }
+}
@@ -2,1318 +2,592 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> std::option::Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> std::convert::From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match std::option::Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        std::option::Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        std::option::Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
+
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+
+#[cfg(feature = "acl")]
+mod acl;
+#[cfg(feature = "acl")]
+pub use acl::*;
+
+#[cfg(feature = "calendar_list")]
+mod calendar_list;
+#[cfg(feature = "calendar_list")]
+pub use calendar_list::*;
+
+#[cfg(feature = "calendars")]
+mod calendars;
+#[cfg(feature = "calendars")]
+pub use calendars::*;
+
+#[cfg(feature = "colors")]
+mod colors;
+#[cfg(feature = "colors")]
+pub use colors::*;
+
+#[cfg(feature = "events")]
+mod events;
+#[cfg(feature = "events")]
+pub use events::*;
+
+#[cfg(feature = "freebusy")]
+mod freebusy;
+#[cfg(feature = "freebusy")]
+pub use freebusy::*;
+
+#[cfg(feature = "settings")]
+mod settings;
+#[cfg(feature = "settings")]
+pub use settings::*;
+/// The extent to which calendar access is granted by this ACL rule.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Acl {
+pub struct Scope {
     /**
-     * ETag of the collection.
+     * The extent to which calendar access is granted by this ACL rule.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
     /**
-     * List of rules on the access control list.
+     * The extent to which calendar access is granted by this ACL rule.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub items: Vec<AclRule>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AclRule {
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextSyncToken"
-    )]
-    pub next_sync_token: String,
-}
-
-/// The extent to which calendar access is granted by this ACL rule.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Scope {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
-     * The extent to which calendar access is granted by this ACL rule.
+     * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
     /**
      * The extent to which calendar access is granted by this ACL rule.
      */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<Scope>,
+}
+
+/// The notifications that the authenticated user is receiving for this calendar.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct NotificationSettings {
+    /**
+     * The notifications that the authenticated user is receiving for this calendar.
+     */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub value: String,
+    pub notifications: Vec<CalendarNotification>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AclRule {
+pub struct CalendarListEntry {
     /**
      * ETag of the collection.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "accessRole"
     )]
-    pub etag: String,
+    pub access_role: Option<String>,
     /**
      * ETag of the collection.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "backgroundColor"
     )]
-    pub id: String,
+    pub background_color: Option<String>,
     /**
      * ETag of the collection.
      */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "colorId")]
+    pub color_id: Option<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "conferenceProperties"
     )]
-    pub kind: String,
+    pub conference_properties: Option<ConferenceProperties>,
     /**
-     * ETag of the collection.
+     * The default reminders that the authenticated user has for this calendar.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "defaultReminders"
     )]
-    pub role: String,
+    pub default_reminders: Vec<EventReminder>,
     /**
-     * The extent to which calendar access is granted by this ACL rule.
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub scope: Option<Scope>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Calendar {
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "conferenceProperties"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub conference_properties: Option<ConferenceProperties>,
+    pub deleted: bool,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
     /**
      * ETag of the collection.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "foregroundColor"
     )]
-    pub id: String,
+    pub foreground_color: Option<String>,
     /**
-     * ETag of the collection.
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub kind: String,
+    pub hidden: bool,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub location: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub summary: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * ETag of the collection.
      */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /**
+     * The notifications that the authenticated user is receiving for this calendar.
+     */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "timeZone"
+        skip_serializing_if = "Option::is_none",
+        rename = "notificationSettings"
     )]
-    pub time_zone: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CalendarList {
+    pub notification_settings: Option<NotificationSettings>,
     /**
-     * ETag of the collection.
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub etag: String,
+    pub primary: bool,
     /**
-     * Calendars that are present on the user's calendar list.
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub items: Vec<CalendarListEntry>,
+    pub selected: bool,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
     /**
      * ETag of the collection.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        skip_serializing_if = "Option::is_none",
+        rename = "summaryOverride"
     )]
-    pub next_page_token: String,
+    pub summary_override: Option<String>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextSyncToken"
-    )]
-    pub next_sync_token: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "timeZone")]
+    pub time_zone: Option<String>,
 }
 
-/// The notifications that the authenticated user is receiving for this calendar.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct NotificationSettings {
+pub struct CalendarNotification {
     /**
-     * The notifications that the authenticated user is receiving for this calendar.
+     * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub notifications: Vec<CalendarNotification>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CalendarListEntry {
+pub struct ColorDefinition {
     /**
      * ETag of the collection.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "accessRole"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub access_role: String,
+    pub background: String,
     /**
      * ETag of the collection.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "backgroundColor"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub background_color: String,
+    pub foreground: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConferenceData {
     /**
      * ETag of the collection.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "colorId"
+        skip_serializing_if = "Option::is_none",
+        rename = "conferenceId"
     )]
-    pub color_id: String,
+    pub conference_id: Option<String>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        rename = "conferenceProperties"
+        rename = "conferenceSolution"
     )]
-    pub conference_properties: Option<ConferenceProperties>,
+    pub conference_solution: Option<ConferenceSolution>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "createRequest"
+    )]
+    pub create_request: Option<CreateConferenceRequest>,
     /**
-     * The default reminders that the authenticated user has for this calendar.
+     * Information about individual conference entry points, such as URLs or phone numbers.
+     *  All of them must belong to the same conference.
+     *  Either conferenceSolution and at least one entryPoint, or createRequest is required.
      */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "defaultReminders"
-    )]
-    pub default_reminders: Vec<EventReminder>,
-    /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub deleted: bool,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub etag: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "foregroundColor"
-    )]
-    pub foreground_color: String,
-    /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub hidden: bool,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub location: String,
-    /**
-     * The notifications that the authenticated user is receiving for this calendar.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "notificationSettings"
-    )]
-    pub notification_settings: Option<NotificationSettings>,
-    /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub primary: bool,
-    /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub selected: bool,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub summary: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "summaryOverride"
-    )]
-    pub summary_override: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "timeZone"
-    )]
-    pub time_zone: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CalendarNotification {
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub method: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Channel {
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address: String,
-    /**
-     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub expiration: i64,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * Additional parameters controlling delivery channel behavior. Optional.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub params: String,
-    /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub payload: bool,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceId"
-    )]
-    pub resource_id: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceUri"
-    )]
-    pub resource_uri: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub token: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ColorDefinition {
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub background: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub foreground: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Colors {
-    /**
-     * A global palette of calendar colors, mapping from the color ID to its definition. A calendarListEntry resource refers to one of these color IDs in its colorId field. Read-only.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub calendar: Option<ColorDefinition>,
-    /**
-     * A global palette of calendar colors, mapping from the color ID to its definition. A calendarListEntry resource refers to one of these color IDs in its colorId field. Read-only.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub event: Option<ColorDefinition>,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
-    )]
-    pub updated: Option<chrono::DateTime<chrono::Utc>>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConferenceData {
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "conferenceId"
-    )]
-    pub conference_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "conferenceSolution"
-    )]
-    pub conference_solution: Option<ConferenceSolution>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "createRequest"
-    )]
-    pub create_request: Option<CreateConferenceRequest>,
-    /**
-     * Information about individual conference entry points, such as URLs or phone numbers.
-     *  All of them must belong to the same conference.
-     *  Either conferenceSolution and at least one entryPoint, or createRequest is required.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "entryPoints"
-    )]
-    pub entry_points: Vec<EntryPoint>,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub notes: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<ConferenceParameters>,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub signature: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConferenceParameters {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "addOnParameters"
-    )]
-    pub add_on_parameters: Option<ConferenceParametersAddOn>,
-}
-
-/// Properties that are shared between copies of the event on other attendees' calendars.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Shared {}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConferenceParametersAddOn {
-    /**
-     * Properties that are shared between copies of the event on other attendees' calendars.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub parameters: Option<Shared>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConferenceProperties {
-    /**
-     * The types of conference solutions that are supported for this calendar.
-     *  The possible values are:
-     *  - "eventHangout"
-     *  - "eventNamedHangout"
-     *  - "hangoutsMeet"  Optional.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "allowedConferenceSolutionTypes"
-    )]
-    pub allowed_conference_solution_types: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConferenceRequestStatus {
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "statusCode"
-    )]
-    pub status_code: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConferenceSolution {
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "iconUri"
-    )]
-    pub icon_uri: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub key: Option<ConferenceSolutionKey>,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ConferenceSolutionKey {
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CreateConferenceRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "conferenceSolutionKey"
-    )]
-    pub conference_solution_key: Option<ConferenceSolutionKey>,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "requestId"
-    )]
-    pub request_id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<ConferenceRequestStatus>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EntryPoint {
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "accessCode"
-    )]
-    pub access_code: String,
-    /**
-     * The types of conference solutions that are supported for this calendar.
-     *  The possible values are:
-     *  - "eventHangout"
-     *  - "eventNamedHangout"
-     *  - "hangoutsMeet"  Optional.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "entryPointFeatures"
-    )]
-    pub entry_point_features: Vec<String>,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "entryPointType"
-    )]
-    pub entry_point_type: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub label: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "meetingCode"
-    )]
-    pub meeting_code: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub passcode: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub password: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub pin: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "regionCode"
-    )]
-    pub region_code: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub uri: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Error {
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub domain: String,
-    /**
-     * ETag of the collection.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
-}
-
-/// The creator of the event. Read-only.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Creator {
-    /**
-     * The creator of the event. Read-only.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
-    )]
-    pub display_name: String,
-    /**
-     * The creator of the event. Read-only.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The creator of the event. Read-only.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * The creator of the event. Read-only.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "self"
+        rename = "entryPoints"
     )]
-    pub self_: bool,
-}
-
-/// Extended properties of the event.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ExtendedProperties {
+    pub entry_points: Vec<EntryPoint>,
     /**
-     * Extended properties of the event.
+     * ETag of the collection.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub private: Option<Shared>,
+    pub notes: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<ConferenceParameters>,
     /**
-     * Extended properties of the event.
+     * ETag of the collection.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub shared: Option<Shared>,
+    pub signature: Option<String>,
 }
 
-/// A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Gadget {
-    /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub display: String,
-    /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub height: i64,
-    /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "iconLink"
-    )]
-    pub icon_link: String,
-    /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub link: String,
-    /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub preferences: String,
-    /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
-    /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-    /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
-     */
+pub struct ConferenceParameters {
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "addOnParameters"
     )]
-    pub width: i64,
+    pub add_on_parameters: Option<ConferenceParametersAddOn>,
 }
 
-/// The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Organizer {
-    /**
-     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
-    )]
-    pub display_name: String,
-    /**
-     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "self"
-    )]
-    pub self_: bool,
-}
+pub struct Parameters {}
 
-/// Information about the event's reminders for the authenticated user.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Reminders {
-    /**
-     * Information about the event's reminders for the authenticated user.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub overrides: Vec<EventReminder>,
-    /**
-     * Information about the event's reminders for the authenticated user.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "useDefault"
-    )]
-    pub use_default: bool,
+pub struct ConferenceParametersAddOn {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Parameters>,
 }
 
-/// Source from which the event was created. For example, a web page, an email message or any document identifiable by an URL with HTTP or HTTPS scheme. Can only be seen or modified by the creator of the event.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Source {
-    /**
-     * Source from which the event was created. For example, a web page, an email message or any document identifiable by an URL with HTTP or HTTPS scheme. Can only be seen or modified by the creator of the event.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
+pub struct ConferenceProperties {
     /**
-     * Source from which the event was created. For example, a web page, an email message or any document identifiable by an URL with HTTP or HTTPS scheme. Can only be seen or modified by the creator of the event.
+     * The types of conference solutions that are supported for this calendar.
+     *  The possible values are:
+     *  - "eventHangout"
+     *  - "eventNamedHangout"
+     *  - "hangoutsMeet"  Optional.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "allowedConferenceSolutionTypes"
     )]
-    pub url: String,
+    pub allowed_conference_solution_types: Vec<String>,
 }
 
-#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Event {
-    /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "anyoneCanAddSelf"
-    )]
-    pub anyone_can_add_self: bool,
-    /**
-     * File attachments for the event. Currently only Google Drive attachments are supported.
-     *  In order to modify attachments the supportsAttachments request parameter should be set to true.
-     *  There can be at most 25 attachments per event,
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub attachments: Vec<EventAttachment>,
-    /**
-     * The attendees of the event. See the Events with attendees guide for more information on scheduling events with other calendar users. Service accounts need to use domain-wide delegation of authority to populate the attendee list.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub attendees: Vec<EventAttendee>,
-    /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "attendeesOmitted"
-    )]
-    pub attendees_omitted: bool,
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConferenceRequestStatus {
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "colorId"
-    )]
-    pub color_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "conferenceData"
-    )]
-    pub conference_data: Option<ConferenceData>,
-    /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
-     */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
+        rename = "statusCode"
     )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    pub status_code: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConferenceSolution {
     /**
-     * The creator of the event. Read-only.
+     * ETag of the collection.
      */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "iconUri")]
+    pub icon_uri: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub creator: Option<Creator>,
+    pub key: Option<ConferenceSolutionKey>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub end: Option<EventDateTime>,
+    pub name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ConferenceSolutionKey {
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * ETag of the collection.
      */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CreateConferenceRequest {
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "endTimeUnspecified"
+        skip_serializing_if = "Option::is_none",
+        rename = "conferenceSolutionKey"
     )]
-    pub end_time_unspecified: bool,
+    pub conference_solution_key: Option<ConferenceSolutionKey>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "requestId")]
+    pub request_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<ConferenceRequestStatus>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EntryPoint {
     /**
      * ETag of the collection.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "accessCode"
     )]
-    pub etag: String,
+    pub access_code: Option<String>,
     /**
-     * ETag of the collection.
+     * The types of conference solutions that are supported for this calendar.
+     *  The possible values are:
+     *  - "eventHangout"
+     *  - "eventNamedHangout"
+     *  - "hangoutsMeet"  Optional.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "eventType"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "entryPointFeatures"
     )]
-    pub event_type: String,
+    pub entry_point_features: Vec<String>,
     /**
-     * Extended properties of the event.
+     * ETag of the collection.
      */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        rename = "extendedProperties"
+        rename = "entryPointType"
     )]
-    pub extended_properties: Option<ExtendedProperties>,
+    pub entry_point_type: Option<String>,
     /**
-     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
+     * ETag of the collection.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub gadget: Option<Gadget>,
+    pub label: Option<String>,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * ETag of the collection.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "guestsCanInviteOthers"
+        skip_serializing_if = "Option::is_none",
+        rename = "meetingCode"
     )]
-    pub guests_can_invite_others: bool,
+    pub meeting_code: Option<String>,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * ETag of the collection.
      */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "guestsCanModify"
-    )]
-    pub guests_can_modify: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passcode: Option<String>,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * ETag of the collection.
      */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "guestsCanSeeOtherGuests"
-    )]
-    pub guests_can_see_other_guests: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "hangoutLink"
-    )]
-    pub hangout_link: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pin: Option<String>,
     /**
      * ETag of the collection.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "htmlLink"
+        skip_serializing_if = "Option::is_none",
+        rename = "regionCode"
     )]
-    pub html_link: String,
+    pub region_code: Option<String>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "iCalUID"
-    )]
-    pub i_cal_uid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Error {
     /**
      * ETag of the collection.
      */
@@ -1322,7 +596,7 @@ pub struct Event {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub domain: String,
     /**
      * ETag of the collection.
      */
@@ -1331,436 +605,446 @@ pub struct Event {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub kind: String,
+    pub reason: String,
+}
+
+/// The creator of the event. Read-only.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Creator {
     /**
-     * ETag of the collection.
+     * The creator of the event. Read-only.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "displayName"
     )]
-    pub location: String,
+    pub display_name: Option<String>,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * The creator of the event. Read-only.
      */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub locked: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
     /**
-     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
+     * The creator of the event. Read-only.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub organizer: Option<Organizer>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "originalStartTime"
-    )]
-    pub original_start_time: Option<EventDateTime>,
+    pub id: Option<String>,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * The creator of the event. Read-only.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "privateCopy"
+        rename = "self"
     )]
-    pub private_copy: bool,
+    pub self_: bool,
+}
+
+/// Properties that are private to the copy of the event that appears on this calendar.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Private {}
+
+/// Properties that are shared between copies of the event on other attendees' calendars.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Shared {}
+
+/// Extended properties of the event.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ExtendedProperties {
     /**
-     * The types of conference solutions that are supported for this calendar.
-     *  The possible values are:
-     *  - "eventHangout"
-     *  - "eventNamedHangout"
-     *  - "hangoutsMeet"  Optional.
+     * Extended properties of the event.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub recurrence: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub private: Option<Private>,
     /**
-     * ETag of the collection.
+     * Extended properties of the event.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "recurringEventId"
-    )]
-    pub recurring_event_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared: Option<Shared>,
+}
+
+/// A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Gadget {
     /**
-     * Information about the event's reminders for the authenticated user.
+     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub reminders: Option<Reminders>,
+    pub display: Option<String>,
     /**
-     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
+     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
      */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub sequence: i64,
+    pub height: i64,
     /**
-     * Source from which the event was created. For example, a web page, an email message or any document identifiable by an URL with HTTP or HTTPS scheme. Can only be seen or modified by the creator of the event.
+     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "iconLink")]
+    pub icon_link: Option<String>,
+    /**
+     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub source: Option<Source>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub start: Option<EventDateTime>,
+    pub link: Option<String>,
     /**
-     * ETag of the collection.
+     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub status: String,
+    pub preferences: std::collections::BTreeMap<String, String>,
     /**
-     * ETag of the collection.
+     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /**
+     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+    /**
+     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub summary: String,
+    pub width: i64,
+}
+
+/// The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Organizer {
     /**
-     * ETag of the collection.
+     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "displayName"
     )]
-    pub transparency: String,
+    pub display_name: Option<String>,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /**
+     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /**
+     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
      */
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "self"
+    )]
+    pub self_: bool,
+}
+
+/// Information about the event's reminders for the authenticated user.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Reminders {
+    /**
+     * Information about the event's reminders for the authenticated user.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub updated: Option<chrono::DateTime<chrono::Utc>>,
+    pub overrides: Vec<EventReminder>,
     /**
-     * ETag of the collection.
+     * Information about the event's reminders for the authenticated user.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "useDefault"
     )]
-    pub visibility: String,
+    pub use_default: bool,
 }
 
+/// Source from which the event was created. For example, a web page, an email message or any document identifiable by an URL with HTTP or HTTPS scheme. Can only be seen or modified by the creator of the event.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EventAttachment {
+pub struct Source {
     /**
-     * ETag of the collection.
+     * Source from which the event was created. For example, a web page, an email message or any document identifiable by an URL with HTTP or HTTPS scheme. Can only be seen or modified by the creator of the event.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /**
+     * Source from which the event was created. For example, a web page, an email message or any document identifiable by an URL with HTTP or HTTPS scheme. Can only be seen or modified by the creator of the event.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Event {
+    /**
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fileId"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "anyoneCanAddSelf"
     )]
-    pub file_id: String,
+    pub anyone_can_add_self: bool,
     /**
-     * ETag of the collection.
+     * File attachments for the event. Currently only Google Drive attachments are supported.
+     *  In order to modify attachments the supportsAttachments request parameter should be set to true.
+     *  There can be at most 25 attachments per event,
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fileUrl"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub file_url: String,
+    pub attachments: Vec<EventAttachment>,
     /**
-     * ETag of the collection.
+     * The attendees of the event. See the Events with attendees guide for more information on scheduling events with other calendar users. Service accounts need to use domain-wide delegation of authority to populate the attendee list.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "iconLink"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub icon_link: String,
+    pub attendees: Vec<EventAttendee>,
     /**
-     * ETag of the collection.
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mimeType"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "attendeesOmitted"
     )]
-    pub mime_type: String,
+    pub attendees_omitted: bool,
     /**
      * ETag of the collection.
      */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "colorId")]
+    pub color_id: Option<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "conferenceData"
     )]
-    pub title: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EventAttendee {
+    pub conference_data: Option<ConferenceData>,
     /**
-     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
+     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "additionalGuests"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
     )]
-    pub additional_guests: i64,
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * ETag of the collection.
+     * The creator of the event. Read-only.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub comment: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Creator>,
     /**
      * ETag of the collection.
      */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<EventDateTime>,
+    /**
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "endTimeUnspecified"
     )]
-    pub display_name: String,
+    pub end_time_unspecified: bool,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "eventType")]
+    pub event_type: Option<String>,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * Extended properties of the event.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "extendedProperties"
     )]
-    pub optional: bool,
+    pub extended_properties: Option<ExtendedProperties>,
     /**
-     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     * A gadget that extends this event. Gadgets are deprecated; this structure is instead only used for returning birthday calendar metadata.
      */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub organizer: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gadget: Option<Gadget>,
     /**
      * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "guestsCanInviteOthers"
     )]
-    pub resource: bool,
+    pub guests_can_invite_others: bool,
     /**
-     * ETag of the collection.
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "responseStatus"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "guestsCanModify"
     )]
-    pub response_status: String,
+    pub guests_can_modify: bool,
     /**
      * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "self"
+        rename = "guestsCanSeeOtherGuests"
     )]
-    pub self_: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EventDateTime {
+    pub guests_can_see_other_guests: bool,
     /**
-     * The date, in the format "yyyy-mm-dd", if this is an all-day event.
+     * ETag of the collection.
      */
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
+        rename = "hangoutLink"
     )]
-    pub date: Option<chrono::NaiveDate>,
+    pub hangout_link: Option<String>,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
-        rename = "dateTime"
-    )]
-    pub date_time: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "htmlLink")]
+    pub html_link: Option<String>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "timeZone"
-    )]
-    pub time_zone: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EventReminder {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "iCalUID")]
+    pub i_cal_uid: Option<String>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub method: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
-     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
+     * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub minutes: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Events {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "accessRole"
-    )]
-    pub access_role: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
     /**
-     * The default reminders that the authenticated user has for this calendar.
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "defaultReminders"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub default_reminders: Vec<EventReminder>,
+    pub locked: bool,
     /**
-     * ETag of the collection.
+     * The organizer of the event. If the organizer is also an attendee, this is indicated with a separate entry in attendees with the organizer field set to True. To change the organizer, use the move operation. Read-only, except when importing an event.
      */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organizer: Option<Organizer>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "originalStartTime"
     )]
-    pub description: String,
+    pub original_start_time: Option<EventDateTime>,
     /**
-     * ETag of the collection.
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "privateCopy"
     )]
-    pub etag: String,
+    pub private_copy: bool,
     /**
-     * List of events on the calendar.
+     * The types of conference solutions that are supported for this calendar.
+     *  The possible values are:
+     *  - "eventHangout"
+     *  - "eventNamedHangout"
+     *  - "hangoutsMeet"  Optional.
      */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub items: Vec<Event>,
+    pub recurrence: Vec<String>,
     /**
      * ETag of the collection.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "recurringEventId"
     )]
-    pub kind: String,
+    pub recurring_event_id: Option<String>,
     /**
-     * ETag of the collection.
+     * Information about the event's reminders for the authenticated user.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reminders: Option<Reminders>,
     /**
-     * ETag of the collection.
+     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextSyncToken"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub next_sync_token: String,
+    pub sequence: i64,
+    /**
+     * Source from which the event was created. For example, a web page, an email message or any document identifiable by an URL with HTTP or HTTPS scheme. Can only be seen or modified by the creator of the event.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<EventDateTime>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub summary: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
     /**
      * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "timeZone"
-    )]
-    pub time_zone: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transparency: Option<String>,
     /**
      * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
      */
@@ -1771,58 +1055,44 @@ pub struct Events {
         serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
     )]
     pub updated: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FreeBusyCalendar {
+pub struct EventAttachment {
     /**
-     * List of time ranges during which this calendar should be regarded as busy.
+     * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub busy: Vec<TimePeriod>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "fileId")]
+    pub file_id: Option<String>,
     /**
-     * Optional error(s) (if computation for the calendar failed).
+     * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<Error>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FreeBusyGroup {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "fileUrl")]
+    pub file_url: Option<String>,
     /**
-     * The types of conference solutions that are supported for this calendar.
-     *  The possible values are:
-     *  - "eventHangout"
-     *  - "eventNamedHangout"
-     *  - "hangoutsMeet"  Optional.
+     * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub calendars: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "iconLink")]
+    pub icon_link: Option<String>,
     /**
-     * Optional error(s) (if computation for the calendar failed).
+     * ETag of the collection.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<Error>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "mimeType")]
+    pub mime_type: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FreeBusyRequest {
+pub struct EventAttendee {
     /**
      * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
      */
@@ -1830,96 +1100,88 @@ pub struct FreeBusyRequest {
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "calendarExpansionMax"
+        rename = "additionalGuests"
     )]
-    pub calendar_expansion_max: i64,
+    pub additional_guests: i64,
     /**
-     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /**
+     * ETag of the collection.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "groupExpansionMax"
+        skip_serializing_if = "Option::is_none",
+        rename = "displayName"
     )]
-    pub group_expansion_max: i64,
+    pub display_name: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
-     * List of calendars and/or groups to query.
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub items: Vec<FreeBusyRequestItem>,
+    pub optional: bool,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
-        rename = "timeMax"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub time_max: Option<chrono::DateTime<chrono::Utc>>,
+    pub organizer: bool,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
-        rename = "timeMin"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub time_min: Option<chrono::DateTime<chrono::Utc>>,
+    pub resource: bool,
     /**
      * ETag of the collection.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "timeZone"
+        skip_serializing_if = "Option::is_none",
+        rename = "responseStatus"
     )]
-    pub time_zone: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FreeBusyRequestItem {
+    pub response_status: Option<String>,
     /**
-     * ETag of the collection.
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "self"
     )]
-    pub id: String,
+    pub self_: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FreeBusyResponse {
-    /**
-     * List of free/busy information for calendars.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub calendars: Option<FreeBusyCalendar>,
-    /**
-     * Expansion of groups.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub groups: Option<FreeBusyGroup>,
+pub struct EventDateTime {
     /**
-     * ETag of the collection.
+     * The date, in the format "yyyy-mm-dd", if this is an all-day event.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
     )]
-    pub kind: String,
+    pub date: Option<chrono::NaiveDate>,
     /**
      * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
      */
@@ -1928,64 +1190,93 @@ pub struct FreeBusyResponse {
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize",
         serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
-        rename = "timeMax"
+        rename = "dateTime"
     )]
-    pub time_max: Option<chrono::DateTime<chrono::Utc>>,
+    pub date_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "timeZone")]
+    pub time_zone: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EventReminder {
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /**
+     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
      */
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
-        rename = "timeMin"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub time_min: Option<chrono::DateTime<chrono::Utc>>,
+    pub minutes: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Setting {
+pub struct FreeBusyCalendar {
     /**
-     * ETag of the collection.
+     * List of time ranges during which this calendar should be regarded as busy.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub etag: String,
+    pub busy: Vec<TimePeriod>,
     /**
-     * ETag of the collection.
+     * Optional error(s) (if computation for the calendar failed).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub id: String,
+    pub errors: Vec<Error>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FreeBusyGroup {
     /**
-     * ETag of the collection.
+     * The types of conference solutions that are supported for this calendar.
+     *  The possible values are:
+     *  - "eventHangout"
+     *  - "eventNamedHangout"
+     *  - "hangoutsMeet"  Optional.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub kind: String,
+    pub calendars: Vec<String>,
     /**
-     * ETag of the collection.
+     * Optional error(s) (if computation for the calendar failed).
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub value: String,
+    pub errors: Vec<Error>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FreeBusyRequestItem {
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Settings {
+pub struct Setting {
     /**
      * ETag of the collection.
      */
@@ -1995,15 +1286,6 @@ pub struct Settings {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub etag: String,
-    /**
-     * List of user settings.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub items: Vec<Setting>,
     /**
      * ETag of the collection.
      */
@@ -2012,27 +1294,25 @@ pub struct Settings {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub kind: String,
+    pub id: String,
     /**
      * ETag of the collection.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub next_page_token: String,
+    pub kind: String,
     /**
      * ETag of the collection.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextSyncToken"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub next_sync_token: String,
+    pub value: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2058,161 +1338,3 @@ pub struct TimePeriod {
     )]
     pub start: Option<chrono::DateTime<chrono::Utc>>,
 }
-
-/**
- * Data format for the response.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Alt {
-    #[serde(rename = "json")]
-    Json,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Alt {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Alt::Json => "json",
-            Alt::Noop => "",
-            Alt::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Alt {
-    fn default() -> Alt {
-        Alt::Noop
-    }
-}
-impl Alt {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Alt::Noop)
-    }
-}
-
-/**
- * The order of the events returned in the result. Optional. The default is an unspecified, stable order.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OrderBy {
-    #[serde(rename = "startTime")]
-    StartTime,
-    #[serde(rename = "updated")]
-    Updated,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for OrderBy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OrderBy::StartTime => "startTime",
-            OrderBy::Updated => "updated",
-            OrderBy::Noop => "",
-            OrderBy::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for OrderBy {
-    fn default() -> OrderBy {
-        OrderBy::Noop
-    }
-}
-impl OrderBy {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OrderBy::Noop)
-    }
-}
-
-/**
- * Whether to send notifications about the creation of the new event. Note that some emails might still be sent. The default is false.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum SendUpdates {
-    #[serde(rename = "all")]
-    All,
-    #[serde(rename = "externalOnly")]
-    ExternalOnly,
-    #[serde(rename = "none")]
-    None,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for SendUpdates {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            SendUpdates::All => "all",
-            SendUpdates::ExternalOnly => "externalOnly",
-            SendUpdates::None => "none",
-            SendUpdates::Noop => "",
-            SendUpdates::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for SendUpdates {
-    fn default() -> SendUpdates {
-        SendUpdates::Noop
-    }
-}
-impl SendUpdates {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SendUpdates::Noop)
-    }
-}
-
-/**
- * The minimum access role for the user in the returned entries. Optional. The default is no restriction.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum MinAccessRole {
-    #[serde(rename = "freeBusyReader")]
-    FreeBusyReader,
-    #[serde(rename = "owner")]
-    Owner,
-    #[serde(rename = "reader")]
-    Reader,
-    #[serde(rename = "writer")]
-    Writer,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for MinAccessRole {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            MinAccessRole::FreeBusyReader => "freeBusyReader",
-            MinAccessRole::Owner => "owner",
-            MinAccessRole::Reader => "reader",
-            MinAccessRole::Writer => "writer",
-            MinAccessRole::Noop => "",
-            MinAccessRole::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for MinAccessRole {
-    fn default() -> MinAccessRole {
-        MinAccessRole::Noop
-    }
-}
-impl MinAccessRole {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MinAccessRole::Noop)
-    }
-}
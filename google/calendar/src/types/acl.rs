@@ -0,0 +1,187 @@
+//! The data types of the `acl` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Acl {
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub etag: String,
+    /**
+     * List of rules on the access control list.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub items: Vec<AclRule>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextSyncToken"
+    )]
+    pub next_sync_token: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Channel {
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /**
+     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub expiration: i64,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /**
+     * Additional parameters controlling delivery channel behavior. Optional.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub params: std::collections::BTreeMap<String, String>,
+    /**
+     * Whether this calendar list entry has been deleted from the calendar list. Read-only. Optional. The default is False.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub payload: bool,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "resourceId"
+    )]
+    pub resource_id: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "resourceUri"
+    )]
+    pub resource_uri: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+}
+
+/**
+ * Data format for the response.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Alt {
+    #[serde(rename = "json")]
+    Json,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Alt {
+    fn as_ref(&self) -> &str {
+        match self {
+            Alt::Json => "json",
+            Alt::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Alt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Alt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "json" => Alt::Json,
+            _ => Alt::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Alt {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Alt> for String {
+    fn from(v: Alt) -> Self {
+        match v {
+            Alt::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
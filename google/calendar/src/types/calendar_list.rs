@@ -0,0 +1,126 @@
+//! The data types of the `calendar_list` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CalendarList {
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub etag: String,
+    /**
+     * Calendars that are present on the user's calendar list.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub items: Vec<CalendarListEntry>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextSyncToken"
+    )]
+    pub next_sync_token: String,
+}
+
+/**
+ * The minimum access role for the user in the returned entries. Optional. The default is no restriction.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum MinAccessRole {
+    #[serde(rename = "freeBusyReader")]
+    FreeBusyReader,
+    #[serde(rename = "owner")]
+    Owner,
+    #[serde(rename = "reader")]
+    Reader,
+    #[serde(rename = "writer")]
+    Writer,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for MinAccessRole {
+    fn as_ref(&self) -> &str {
+        match self {
+            MinAccessRole::FreeBusyReader => "freeBusyReader",
+            MinAccessRole::Owner => "owner",
+            MinAccessRole::Reader => "reader",
+            MinAccessRole::Writer => "writer",
+            MinAccessRole::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for MinAccessRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for MinAccessRole {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "freeBusyReader" => MinAccessRole::FreeBusyReader,
+            "owner" => MinAccessRole::Owner,
+            "reader" => MinAccessRole::Reader,
+            "writer" => MinAccessRole::Writer,
+            _ => MinAccessRole::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for MinAccessRole {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<MinAccessRole> for String {
+    fn from(v: MinAccessRole) -> Self {
+        match v {
+            MinAccessRole::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
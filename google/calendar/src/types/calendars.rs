@@ -0,0 +1,50 @@
+//! The data types of the `calendars` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Calendar {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "conferenceProperties"
+    )]
+    pub conference_properties: Option<ConferenceProperties>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "timeZone")]
+    pub time_zone: Option<String>,
+}
@@ -0,0 +1,242 @@
+//! The data types of the `events` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Events {
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "accessRole"
+    )]
+    pub access_role: String,
+    /**
+     * The default reminders that the authenticated user has for this calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "defaultReminders"
+    )]
+    pub default_reminders: Vec<EventReminder>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub etag: String,
+    /**
+     * List of events on the calendar.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub items: Vec<Event>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextSyncToken"
+    )]
+    pub next_sync_token: String,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub summary: String,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "timeZone"
+    )]
+    pub time_zone: String,
+    /**
+     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        serialize_with = "crate::utils::google_calendar_date_time_format::serialize"
+    )]
+    pub updated: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/**
+ * The order of the events returned in the result. Optional. The default is an unspecified, stable order.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OrderBy {
+    #[serde(rename = "startTime")]
+    StartTime,
+    #[serde(rename = "updated")]
+    Updated,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OrderBy {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrderBy::StartTime => "startTime",
+            OrderBy::Updated => "updated",
+            OrderBy::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OrderBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OrderBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "startTime" => OrderBy::StartTime,
+            "updated" => OrderBy::Updated,
+            _ => OrderBy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OrderBy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OrderBy> for String {
+    fn from(v: OrderBy) -> Self {
+        match v {
+            OrderBy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Guests who should receive notifications about the change of the event's organizer.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SendUpdates {
+    #[serde(rename = "all")]
+    All,
+    #[serde(rename = "externalOnly")]
+    ExternalOnly,
+    #[serde(rename = "none")]
+    None,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SendUpdates {
+    fn as_ref(&self) -> &str {
+        match self {
+            SendUpdates::All => "all",
+            SendUpdates::ExternalOnly => "externalOnly",
+            SendUpdates::None => "none",
+            SendUpdates::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SendUpdates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SendUpdates {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => SendUpdates::All,
+            "externalOnly" => SendUpdates::ExternalOnly,
+            "none" => SendUpdates::None,
+            _ => SendUpdates::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SendUpdates {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SendUpdates> for String {
+    fn from(v: SendUpdates) -> Self {
+        match v {
+            SendUpdates::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
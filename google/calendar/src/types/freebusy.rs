@@ -0,0 +1,118 @@
+//! The data types of the `freebusy` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FreeBusyRequest {
+    /**
+     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "calendarExpansionMax"
+    )]
+    pub calendar_expansion_max: i64,
+    /**
+     * Date and time of notification channel expiration, expressed as a Unix timestamp, in milliseconds. Optional.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "groupExpansionMax"
+    )]
+    pub group_expansion_max: i64,
+    /**
+     * List of calendars and/or groups to query.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub items: Vec<FreeBusyRequestItem>,
+    /**
+     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
+        rename = "timeMax"
+    )]
+    pub time_max: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
+        rename = "timeMin"
+    )]
+    pub time_min: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "timeZone")]
+    pub time_zone: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FreeBusyResponse {
+    /**
+     * List of free/busy information for calendars.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub calendars: std::collections::BTreeMap<String, FreeBusyCalendar>,
+    /**
+     * Expansion of groups.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub groups: std::collections::BTreeMap<String, FreeBusyGroup>,
+    /**
+     * ETag of the collection.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
+        rename = "timeMax"
+    )]
+    pub time_max: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Last modification time of the color palette (as a RFC3339 timestamp). Read-only.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        serialize_with = "crate::utils::google_calendar_date_time_format::serialize",
+        rename = "timeMin"
+    )]
+    pub time_min: Option<chrono::DateTime<chrono::Utc>>,
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Folders {
     pub client: Client,
@@ -19,29 +17,20 @@ impl Folders {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- Optional. The policy format version to be returned. Valid values are 0, 1, and 3. Requests specifying an invalid value will be rejected. Requests for policies with any conditional bindings must specify version 3. Policies without any conditional bindings may specify any valid value or leave the field unset. To learn which resources support conditions in their IAM policies, see the [IAM documentation](https://cloud.google.com/iam/help/conditions/resource-policies).
-     * * `page_token: &str` -- Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
-     * * `parent: &str` -- Required. The resource name of the Organization or Folder whose Folders are being listed. Must be of the form `folders/{folder_id}` or `organizations/{org_id}`. Access to this method is controlled by checking the `resourcemanager.folders.list` permission on the `parent`.
-     * * `show_deleted: bool` -- True if the project can be retrieved using `GetProject`. No other operations on the project are guaranteed to work until the project creation is complete.
+     * * `params: &FoldersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
-        &self,
-        page_size: i64,
-        page_token: &str,
-        parent: &str,
-        show_deleted: bool,
-    ) -> Result<Vec<crate::types::Folder>> {
+    pub async fn list_with(&self, params: &FoldersListParams) -> Result<Vec<crate::types::Folder>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !parent.is_empty() {
+        if let Some(parent) = &params.parent {
             query_args.push(("parent".to_string(), parent.to_string()));
         }
-        if show_deleted {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -53,23 +42,46 @@ impl Folders {
         Ok(resp.folders.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `FoldersListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        parent: &str,
+        show_deleted: bool,
+    ) -> Result<Vec<crate::types::Folder>> {
+        let params = FoldersListParams {
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            parent: Some(parent.to_string()).filter(|s| !s.is_empty()),
+            show_deleted: Some(show_deleted).filter(|v| *v),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v2/folders` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Lists the Folders that are direct descendants of supplied parent resource. List provides a strongly consistent view of the Folders underneath the specified parent resource. List returns Folders sorted based upon the (ascending) lexical ordering of their display_name. The caller must have `resourcemanager.folders.list` permission on the identified parent.
+     *
+     * **Parameters:**
+     *
+     * * `params: &FoldersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(
+    pub async fn list_all_with(
         &self,
-        parent: &str,
-        show_deleted: bool,
+        params: &FoldersListParams,
     ) -> Result<Vec<crate::types::Folder>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !parent.is_empty() {
+        if let Some(parent) = &params.parent {
             query_args.push(("parent".to_string(), parent.to_string()));
         }
-        if show_deleted {
+        if let Some(show_deleted) = &params.show_deleted {
             query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -82,6 +94,7 @@ impl Folders {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -107,6 +120,66 @@ impl Folders {
         Ok(folders)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `FoldersListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        parent: &str,
+        show_deleted: bool,
+    ) -> Result<Vec<crate::types::Folder>> {
+        let params = FoldersListParams {
+            parent: Some(parent.to_string()).filter(|s| !s.is_empty()),
+            show_deleted: Some(show_deleted).filter(|v| *v),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/v2/folders` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the Folders that are direct descendants of supplied parent resource. List provides a strongly consistent view of the Folders underneath the specified parent resource. List returns Folders sorted based upon the (ascending) lexical ordering of their display_name. The caller must have `resourcemanager.folders.list` permission on the identified parent.
+     */
+    pub fn list_stream(
+        &self,
+        params: &FoldersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Folder> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(parent) = &params.parent {
+            query_args.push(("parent".to_string(), parent.to_string()));
+        }
+        if let Some(show_deleted) = &params.show_deleted {
+            query_args.push(("showDeleted".to_string(), show_deleted.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v2/folders?{}", query_);
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListFoldersResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.folders,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/v2/folders` endpoint.
      *
@@ -114,15 +187,15 @@ impl Folders {
      *
      * **Parameters:**
      *
-     * * `parent: &str` -- Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
+     * * `params: &FoldersCreateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create(
+    pub async fn create_with(
         &self,
-        parent: &str,
+        params: &FoldersCreateParams,
         body: &crate::types::Folder,
     ) -> Result<crate::types::Operation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !parent.is_empty() {
+        if let Some(parent) = &params.parent {
             query_args.push(("parent".to_string(), parent.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -133,6 +206,21 @@ impl Folders {
             .await
     }
 
+    /**
+     * Deprecated: use `create_with` and pass the query parameters as a `FoldersCreateParams` instead.
+     */
+    #[deprecated(note = "use `create_with` instead")]
+    pub async fn create(
+        &self,
+        parent: &str,
+        body: &crate::types::Folder,
+    ) -> Result<crate::types::Operation> {
+        let params = FoldersCreateParams {
+            parent: Some(parent.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_with(&params, body).await
+    }
+
     /**
      * This function performs a `POST` to the `/v2/folders:search` endpoint.
      *
@@ -190,16 +278,16 @@ impl Folders {
      * **Parameters:**
      *
      * * `name: &str` -- Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
-     * * `update_mask: &str` -- Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
+     * * `params: &FoldersPatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn patch(
+    pub async fn patch_with(
         &self,
         name: &str,
-        update_mask: &str,
+        params: &FoldersPatchParams,
         body: &crate::types::Folder,
     ) -> Result<crate::types::Folder> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !update_mask.is_empty() {
+        if let Some(update_mask) = &params.update_mask {
             query_args.push(("updateMask".to_string(), update_mask.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -214,6 +302,22 @@ impl Folders {
             .await
     }
 
+    /**
+     * Deprecated: use `patch_with` and pass the query parameters as a `FoldersPatchParams` instead.
+     */
+    #[deprecated(note = "use `patch_with` instead")]
+    pub async fn patch(
+        &self,
+        name: &str,
+        update_mask: &str,
+        body: &crate::types::Folder,
+    ) -> Result<crate::types::Folder> {
+        let params = FoldersPatchParams {
+            update_mask: Some(update_mask.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.patch_with(name, &params, body).await
+    }
+
     /**
      * This function performs a `POST` to the `/v2/{name}:move` endpoint.
      *
@@ -247,7 +351,7 @@ impl Folders {
     pub async fn undelete(
         &self,
         name: &str,
-        body: &crate::types::MoveProjectMetadata,
+        body: &crate::types::UndeleteFolderRequest,
     ) -> Result<crate::types::Folder> {
         let url = format!(
             "/v2/{}/undelete",
@@ -331,3 +435,240 @@ impl Folders {
             .await
     }
 }
+
+/// The query parameters of `Folders::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FoldersListParams {
+    /// Optional. The policy format version to be returned. Valid values are 0, 1, and 3. Requests specifying an invalid value will be rejected. Requests for policies with any conditional bindings must specify version 3. Policies without any conditional bindings may specify any valid value or leave the field unset. To learn which resources support conditions in their IAM policies, see the [IAM documentation](https://cloud.google.com/iam/help/conditions/resource-policies).
+    pub page_size: Option<i64>,
+    /// Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
+    pub page_token: Option<String>,
+    /// Required. The resource name of the Organization or Folder whose Folders are being listed. Must be of the form `folders/{folder_id}` or `organizations/{org_id}`. Access to this method is controlled by checking the `resourcemanager.folders.list` permission on the `parent`.
+    pub parent: Option<String>,
+    /// True if the project can be retrieved using `GetProject`. No other operations on the project are guaranteed to work until the project creation is complete.
+    pub show_deleted: Option<bool>,
+}
+
+impl FoldersListParams {
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn parent<T: ToString>(mut self, parent: T) -> Self {
+        self.parent = Some(parent.to_string());
+        self
+    }
+
+    pub fn show_deleted(mut self, show_deleted: bool) -> Self {
+        self.show_deleted = Some(show_deleted);
+        self
+    }
+}
+
+/// The query parameters of `Folders::create_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FoldersCreateParams {
+    /// Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
+    pub parent: Option<String>,
+}
+
+impl FoldersCreateParams {
+    pub fn parent<T: ToString>(mut self, parent: T) -> Self {
+        self.parent = Some(parent.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Folders::patch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FoldersPatchParams {
+    /// Specifies a service that will be enabled for audit logging. For example, `storage.googleapis.com`, `cloudsql.googleapis.com`. `allServices` is a special value that covers all services.
+    pub update_mask: Option<String>,
+}
+
+impl FoldersPatchParams {
+    pub fn update_mask<T: ToString>(mut self, update_mask: T) -> Self {
+        self.update_mask = Some(update_mask.to_string());
+        self
+    }
+}
+
+/// The functions of [`Folders`], for code that takes them as a trait, so that it can be
+/// tested with the `MockFoldersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait FoldersApi: Send + Sync {
+    /// See [`Folders::list_with`].
+    async fn list_with(&self, params: &FoldersListParams) -> Result<Vec<crate::types::Folder>>;
+
+    /// See [`Folders::list_all_with`].
+    async fn list_all_with(&self, params: &FoldersListParams) -> Result<Vec<crate::types::Folder>>;
+
+    /// See [`Folders::list_stream`].
+    fn list_stream(
+        &self,
+        params: &FoldersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Folder>;
+
+    /// See [`Folders::create_with`].
+    async fn create_with(
+        &self,
+        params: &FoldersCreateParams,
+        body: &crate::types::Folder,
+    ) -> Result<crate::types::Operation>;
+
+    /// See [`Folders::search`].
+    async fn search(
+        &self,
+        body: &crate::types::SearchFoldersRequest,
+    ) -> Result<Vec<crate::types::Folder>>;
+
+    /// See [`Folders::get`].
+    async fn get(&self, name: &str) -> Result<crate::types::Folder>;
+
+    /// See [`Folders::delete`].
+    async fn delete(&self, name: &str) -> Result<crate::types::Folder>;
+
+    /// See [`Folders::patch_with`].
+    async fn patch_with(
+        &self,
+        name: &str,
+        params: &FoldersPatchParams,
+        body: &crate::types::Folder,
+    ) -> Result<crate::types::Folder>;
+
+    /// See [`Folders::mv`].
+    async fn mv(
+        &self,
+        name: &str,
+        body: &crate::types::MoveFolderRequest,
+    ) -> Result<crate::types::Operation>;
+
+    /// See [`Folders::undelete`].
+    async fn undelete(
+        &self,
+        name: &str,
+        body: &crate::types::UndeleteFolderRequest,
+    ) -> Result<crate::types::Folder>;
+
+    /// See [`Folders::get_iam_policy`].
+    async fn get_iam_policy(
+        &self,
+        resource: &str,
+        body: &crate::types::GetIamPolicyRequest,
+    ) -> Result<crate::types::Policy>;
+
+    /// See [`Folders::set_iam_policy`].
+    async fn set_iam_policy(
+        &self,
+        resource: &str,
+        body: &crate::types::SetIamPolicyRequest,
+    ) -> Result<crate::types::Policy>;
+
+    /// See [`Folders::test_iam_permissions`].
+    async fn test_iam_permissions(
+        &self,
+        resource: &str,
+        body: &crate::types::TestIamPermissionsRequest,
+    ) -> Result<crate::types::TestIamPermissionsResponse>;
+}
+
+#[async_trait::async_trait]
+impl FoldersApi for Folders {
+    async fn list_with(&self, params: &FoldersListParams) -> Result<Vec<crate::types::Folder>> {
+        Folders::list_with(self, params).await
+    }
+
+    async fn list_all_with(&self, params: &FoldersListParams) -> Result<Vec<crate::types::Folder>> {
+        Folders::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &FoldersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Folder> {
+        Folders::list_stream(self, params, start, max_items)
+    }
+
+    async fn create_with(
+        &self,
+        params: &FoldersCreateParams,
+        body: &crate::types::Folder,
+    ) -> Result<crate::types::Operation> {
+        Folders::create_with(self, params, body).await
+    }
+
+    async fn search(
+        &self,
+        body: &crate::types::SearchFoldersRequest,
+    ) -> Result<Vec<crate::types::Folder>> {
+        Folders::search(self, body).await
+    }
+
+    async fn get(&self, name: &str) -> Result<crate::types::Folder> {
+        Folders::get(self, name).await
+    }
+
+    async fn delete(&self, name: &str) -> Result<crate::types::Folder> {
+        Folders::delete(self, name).await
+    }
+
+    async fn patch_with(
+        &self,
+        name: &str,
+        params: &FoldersPatchParams,
+        body: &crate::types::Folder,
+    ) -> Result<crate::types::Folder> {
+        Folders::patch_with(self, name, params, body).await
+    }
+
+    async fn mv(
+        &self,
+        name: &str,
+        body: &crate::types::MoveFolderRequest,
+    ) -> Result<crate::types::Operation> {
+        Folders::mv(self, name, body).await
+    }
+
+    async fn undelete(
+        &self,
+        name: &str,
+        body: &crate::types::UndeleteFolderRequest,
+    ) -> Result<crate::types::Folder> {
+        Folders::undelete(self, name, body).await
+    }
+
+    async fn get_iam_policy(
+        &self,
+        resource: &str,
+        body: &crate::types::GetIamPolicyRequest,
+    ) -> Result<crate::types::Policy> {
+        Folders::get_iam_policy(self, resource, body).await
+    }
+
+    async fn set_iam_policy(
+        &self,
+        resource: &str,
+        body: &crate::types::SetIamPolicyRequest,
+    ) -> Result<crate::types::Policy> {
+        Folders::set_iam_policy(self, resource, body).await
+    }
+
+    async fn test_iam_permissions(
+        &self,
+        resource: &str,
+        body: &crate::types::TestIamPermissionsRequest,
+    ) -> Result<crate::types::TestIamPermissionsResponse> {
+        Folders::test_iam_permissions(self, resource, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Operations {
     pub client: Client,
@@ -27,3 +25,19 @@ impl Operations {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`Operations`], for code that takes them as a trait, so that it can be
+/// tested with the `MockOperationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait OperationsApi: Send + Sync {
+    /// See [`Operations::get`].
+    async fn get(&self, name: &str) -> Result<crate::types::Operation>;
+}
+
+#[async_trait::async_trait]
+impl OperationsApi for Operations {
+    async fn get(&self, name: &str) -> Result<crate::types::Operation> {
+        Operations::get(self, name).await
+    }
+}
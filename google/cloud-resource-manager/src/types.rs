@@ -2,6 +2,109 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> std::option::Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> std::convert::From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match std::option::Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        std::option::Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        std::option::Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
+
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+
+#[cfg(feature = "folders")]
+mod folders;
+#[cfg(feature = "folders")]
+pub use folders::*;
 /// Specifies the audit configuration for a service. The configuration determines which permission types are logged, and what identities, if any, are exempted from logging. An AuditConfig must have one or more AuditLogConfigs. If there are AuditConfigs for both `allServices` and a specific service, the union of the two AuditConfigs is used for that service: the log_types specified in each AuditConfig are enabled, and the exempted_members in each AuditLogConfig are exempted. Example Policy with multiple AuditConfigs: { "audit_configs": [ { "service": "allServices", "audit_log_configs": [ { "log_type": "DATA_READ", "exempted_members": [ "user:jose@example.com" ] }, { "log_type": "DATA_WRITE" }, { "log_type": "ADMIN_READ" } ] }, { "service": "sampleservice.googleapis.com", "audit_log_configs": [ { "log_type": "DATA_READ" }, { "log_type": "DATA_WRITE", "exempted_members": [ "user:aliya@example.com" ] } ] } ] } For sampleservice, this policy enables DATA_READ, DATA_WRITE and ADMIN_READ logging. It also exempts jose@example.com from DATA_READ logging, and aliya@example.com from DATA_WRITE logging.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AuditConfig {
@@ -18,18 +121,15 @@ pub struct AuditConfig {
     /**
      * Specifies the audit configuration for a service. The configuration determines which permission types are logged, and what identities, if any, are exempted from logging. An AuditConfig must have one or more AuditLogConfigs. If there are AuditConfigs for both `allServices` and a specific service, the union of the two AuditConfigs is used for that service: the log_types specified in each AuditConfig are enabled, and the exempted_members in each AuditLogConfig are exempted. Example Policy with multiple AuditConfigs: { "audit_configs": [ { "service": "allServices", "audit_log_configs": [ { "log_type": "DATA_READ", "exempted_members": [ "user:jose@example.com" ] }, { "log_type": "DATA_WRITE" }, { "log_type": "ADMIN_READ" } ] }, { "service": "sampleservice.googleapis.com", "audit_log_configs": [ { "log_type": "DATA_READ" }, { "log_type": "DATA_WRITE", "exempted_members": [ "user:aliya@example.com" ] } ] } ] } For sampleservice, this policy enables DATA_READ, DATA_WRITE and ADMIN_READ logging. It also exempts jose@example.com from DATA_READ logging, and aliya@example.com from DATA_WRITE logging.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub service: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
 }
 
 /**
  * The log type that this config enables.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LogType {
     #[serde(rename = "ADMIN_READ")]
     AdminRead,
@@ -39,34 +139,59 @@ pub enum LogType {
     DataWrite,
     #[serde(rename = "LOG_TYPE_UNSPECIFIED")]
     LogTypeUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LogType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LogType {
+    fn as_ref(&self) -> &str {
+        match self {
             LogType::AdminRead => "ADMIN_READ",
             LogType::DataRead => "DATA_READ",
             LogType::DataWrite => "DATA_WRITE",
             LogType::LogTypeUnspecified => "LOG_TYPE_UNSPECIFIED",
-            LogType::Noop => "",
-            LogType::FallthroughString => "*",
+            LogType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LogType {
-    fn default() -> LogType {
-        LogType::Noop
+impl std::fmt::Display for LogType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LogType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ADMIN_READ" => LogType::AdminRead,
+            "DATA_READ" => LogType::DataRead,
+            "DATA_WRITE" => LogType::DataWrite,
+            "LOG_TYPE_UNSPECIFIED" => LogType::LogTypeUnspecified,
+            _ => LogType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LogType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LogType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LogType::Noop)
+
+impl std::convert::From<LogType> for String {
+    fn from(v: LogType) -> Self {
+        match v {
+            LogType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -110,18 +235,15 @@ pub struct Binding {
     /**
      * Associates `members` with a `role`.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub role: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
 }
 
 /**
  * The type of this operation.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OperationType {
     #[serde(rename = "CREATE")]
     Create,
@@ -129,39 +251,107 @@ pub enum OperationType {
     Move,
     #[serde(rename = "OPERATION_TYPE_UNSPECIFIED")]
     OperationTypeUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for OperationType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for OperationType {
+    fn as_ref(&self) -> &str {
+        match self {
             OperationType::Create => "CREATE",
             OperationType::Move => "MOVE",
             OperationType::OperationTypeUnspecified => "OPERATION_TYPE_UNSPECIFIED",
-            OperationType::Noop => "",
-            OperationType::FallthroughString => "*",
+            OperationType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for OperationType {
-    fn default() -> OperationType {
-        OperationType::Noop
+impl std::fmt::Display for OperationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OperationType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CREATE" => OperationType::Create,
+            "MOVE" => OperationType::Move,
+            "OPERATION_TYPE_UNSPECIFIED" => OperationType::OperationTypeUnspecified,
+            _ => OperationType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OperationType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl OperationType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OperationType::Noop)
+
+impl std::convert::From<OperationType> for String {
+    fn from(v: OperationType) -> Self {
+        match v {
+            OperationType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 /// Metadata describing a long running folder operation
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FolderOperation {
+pub struct CloudresourcemanagerGoogleCloudResourcemanagerV2Alpha1FolderOperation {
+    /**
+     * Metadata describing a long running folder operation
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "destinationParent"
+    )]
+    pub destination_parent: String,
+    /**
+     * Metadata describing a long running folder operation
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "displayName"
+    )]
+    pub display_name: String,
+    /**
+     * Metadata describing a long running folder operation
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "operationType"
+    )]
+    pub operation_type: Option<OperationType>,
+    /**
+     * Metadata describing a long running folder operation
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "sourceParent"
+    )]
+    pub source_parent: String,
+}
+
+/// Metadata describing a long running folder operation
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CloudresourcemanagerGoogleCloudResourcemanagerV2Beta1FolderOperation {
     /**
      * Metadata describing a long running folder operation
      */
@@ -258,9 +448,41 @@ pub struct CreateProjectMetadata {
     pub ready: bool,
 }
 
-/// A status object which is used as the `metadata` field for the Operation returned by MoveProject.
+/// Runtime operation information for creating a TagValue.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MoveProjectMetadata {}
+pub struct CreateTagBindingMetadata {}
+
+/// Runtime operation information for creating a TagKey.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CreateTagKeyMetadata {}
+
+/// Runtime operation information for creating a TagValue.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CreateTagValueMetadata {}
+
+/// A status object which is used as the `metadata` field for the `Operation` returned by `DeleteFolder`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeleteFolderMetadata {}
+
+/// A status object which is used as the `metadata` field for the operation returned by DeleteOrganization.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeleteOrganizationMetadata {}
+
+/// A status object which is used as the `metadata` field for the Operation returned by `DeleteProject`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeleteProjectMetadata {}
+
+/// Runtime operation information for deleting a TagBinding.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeleteTagBindingMetadata {}
+
+/// Runtime operation information for deleting a TagKey.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeleteTagKeyMetadata {}
+
+/// Runtime operation information for deleting a TagValue.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeleteTagValueMetadata {}
 
 /// Represents a textual expression in the Common Expression Language (CEL) syntax. CEL is a C-like expression language. The syntax and semantics of CEL are documented at https://github.com/google/cel-spec. Example (Comparison): title: "Summary size limit" description: "Determines if a summary is less than 100 chars" expression: "document.summary.size() < 100" Example (Equality): title: "Requestor is owner" description: "Determines if requestor is the document owner" expression: "document.owner == request.auth.claims.email" Example (Logic): title: "Public documents" description: "Determine whether the document should be publicly visible" expression: "document.type != 'private' && document.type != 'internal'" Example (Data Manipulation): title: "Notification string" description: "Create a notification string with a timestamp." expression: "'New message received at ' + string(document.create_time)" The exact variables and functions that may be referenced within an expression are determined by the service that evaluates it. See the service documentation for additional information.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -268,45 +490,30 @@ pub struct Expr {
     /**
      * Represents a textual expression in the Common Expression Language (CEL) syntax. CEL is a C-like expression language. The syntax and semantics of CEL are documented at https://github.com/google/cel-spec. Example (Comparison): title: "Summary size limit" description: "Determines if a summary is less than 100 chars" expression: "document.summary.size() < 100" Example (Equality): title: "Requestor is owner" description: "Determines if requestor is the document owner" expression: "document.owner == request.auth.claims.email" Example (Logic): title: "Public documents" description: "Determine whether the document should be publicly visible" expression: "document.type != 'private' && document.type != 'internal'" Example (Data Manipulation): title: "Notification string" description: "Create a notification string with a timestamp." expression: "'New message received at ' + string(document.create_time)" The exact variables and functions that may be referenced within an expression are determined by the service that evaluates it. See the service documentation for additional information.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /**
      * Represents a textual expression in the Common Expression Language (CEL) syntax. CEL is a C-like expression language. The syntax and semantics of CEL are documented at https://github.com/google/cel-spec. Example (Comparison): title: "Summary size limit" description: "Determines if a summary is less than 100 chars" expression: "document.summary.size() < 100" Example (Equality): title: "Requestor is owner" description: "Determines if requestor is the document owner" expression: "document.owner == request.auth.claims.email" Example (Logic): title: "Public documents" description: "Determine whether the document should be publicly visible" expression: "document.type != 'private' && document.type != 'internal'" Example (Data Manipulation): title: "Notification string" description: "Create a notification string with a timestamp." expression: "'New message received at ' + string(document.create_time)" The exact variables and functions that may be referenced within an expression are determined by the service that evaluates it. See the service documentation for additional information.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub expression: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expression: Option<String>,
     /**
      * Represents a textual expression in the Common Expression Language (CEL) syntax. CEL is a C-like expression language. The syntax and semantics of CEL are documented at https://github.com/google/cel-spec. Example (Comparison): title: "Summary size limit" description: "Determines if a summary is less than 100 chars" expression: "document.summary.size() < 100" Example (Equality): title: "Requestor is owner" description: "Determines if requestor is the document owner" expression: "document.owner == request.auth.claims.email" Example (Logic): title: "Public documents" description: "Determine whether the document should be publicly visible" expression: "document.type != 'private' && document.type != 'internal'" Example (Data Manipulation): title: "Notification string" description: "Create a notification string with a timestamp." expression: "'New message received at ' + string(document.create_time)" The exact variables and functions that may be referenced within an expression are determined by the service that evaluates it. See the service documentation for additional information.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub location: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
     /**
      * Represents a textual expression in the Common Expression Language (CEL) syntax. CEL is a C-like expression language. The syntax and semantics of CEL are documented at https://github.com/google/cel-spec. Example (Comparison): title: "Summary size limit" description: "Determines if a summary is less than 100 chars" expression: "document.summary.size() < 100" Example (Equality): title: "Requestor is owner" description: "Determines if requestor is the document owner" expression: "document.owner == request.auth.claims.email" Example (Logic): title: "Public documents" description: "Determine whether the document should be publicly visible" expression: "document.type != 'private' && document.type != 'internal'" Example (Data Manipulation): title: "Notification string" description: "Create a notification string with a timestamp." expression: "'New message received at ' + string(document.create_time)" The exact variables and functions that may be referenced within an expression are determined by the service that evaluates it. See the service documentation for additional information.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
 }
 
 /**
  * Output only. The lifecycle state of the folder. Updates to the lifecycle_state must be performed via DeleteFolder and UndeleteFolder.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LifecycleState {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -314,33 +521,57 @@ pub enum LifecycleState {
     DeleteRequested,
     #[serde(rename = "LIFECYCLE_STATE_UNSPECIFIED")]
     LifecycleStateUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LifecycleState {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LifecycleState {
+    fn as_ref(&self) -> &str {
+        match self {
             LifecycleState::Active => "ACTIVE",
             LifecycleState::DeleteRequested => "DELETE_REQUESTED",
             LifecycleState::LifecycleStateUnspecified => "LIFECYCLE_STATE_UNSPECIFIED",
-            LifecycleState::Noop => "",
-            LifecycleState::FallthroughString => "*",
+            LifecycleState::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LifecycleState {
-    fn default() -> LifecycleState {
-        LifecycleState::Noop
+impl std::fmt::Display for LifecycleState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LifecycleState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => LifecycleState::Active,
+            "DELETE_REQUESTED" => LifecycleState::DeleteRequested,
+            "LIFECYCLE_STATE_UNSPECIFIED" => LifecycleState::LifecycleStateUnspecified,
+            _ => LifecycleState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LifecycleState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LifecycleState {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LifecycleState::Noop)
+
+impl std::convert::From<LifecycleState> for String {
+    fn from(v: LifecycleState) -> Self {
+        match v {
+            LifecycleState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -362,11 +593,10 @@ pub struct Folder {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "displayName"
     )]
-    pub display_name: String,
+    pub display_name: Option<String>,
     /**
      * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
      */
@@ -379,27 +609,64 @@ pub struct Folder {
     /**
      * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
      */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /**
+     * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+}
+
+/// Metadata describing a long running folder operation
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FolderOperation {
+    /**
+     * Metadata describing a long running folder operation
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "destinationParent"
     )]
-    pub name: String,
+    pub destination_parent: String,
     /**
-     * A Folder in an Organization's resource hierarchy, used to organize that Organization's resources.
+     * Metadata describing a long running folder operation
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "displayName"
     )]
-    pub parent: String,
+    pub display_name: String,
+    /**
+     * Metadata describing a long running folder operation
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "operationType"
+    )]
+    pub operation_type: Option<OperationType>,
+    /**
+     * Metadata describing a long running folder operation
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "sourceParent"
+    )]
+    pub source_parent: String,
 }
 
 /**
  * The type of operation error experienced.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ErrorMessageId {
     #[serde(rename = "ACTIVE_FOLDER_HEIGHT_VIOLATION")]
     ActiveFolderHeightViolation,
@@ -421,15 +688,15 @@ pub enum ErrorMessageId {
     ParentDeletedViolation,
     #[serde(rename = "RESOURCE_DELETED_VIOLATION")]
     ResourceDeletedViolation,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ErrorMessageId {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ErrorMessageId {
+    fn as_ref(&self) -> &str {
+        match self {
             ErrorMessageId::ActiveFolderHeightViolation => "ACTIVE_FOLDER_HEIGHT_VIOLATION",
             ErrorMessageId::CycleIntroducedViolation => "CYCLE_INTRODUCED_VIOLATION",
             ErrorMessageId::DeletedFolderHeightViolation => "DELETED_FOLDER_HEIGHT_VIOLATION",
@@ -442,21 +709,54 @@ impl std::fmt::Display for ErrorMessageId {
             ErrorMessageId::MaxChildFoldersViolation => "MAX_CHILD_FOLDERS_VIOLATION",
             ErrorMessageId::ParentDeletedViolation => "PARENT_DELETED_VIOLATION",
             ErrorMessageId::ResourceDeletedViolation => "RESOURCE_DELETED_VIOLATION",
-            ErrorMessageId::Noop => "",
-            ErrorMessageId::FallthroughString => "*",
+            ErrorMessageId::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ErrorMessageId {
-    fn default() -> ErrorMessageId {
-        ErrorMessageId::Noop
+impl std::fmt::Display for ErrorMessageId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl ErrorMessageId {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ErrorMessageId::Noop)
+
+impl std::str::FromStr for ErrorMessageId {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE_FOLDER_HEIGHT_VIOLATION" => ErrorMessageId::ActiveFolderHeightViolation,
+            "CYCLE_INTRODUCED_VIOLATION" => ErrorMessageId::CycleIntroducedViolation,
+            "DELETED_FOLDER_HEIGHT_VIOLATION" => ErrorMessageId::DeletedFolderHeightViolation,
+            "ERROR_TYPE_UNSPECIFIED" => ErrorMessageId::ErrorTypeUnspecified,
+            "FOLDER_BEING_MOVED_VIOLATION" => ErrorMessageId::FolderBeingMovedViolation,
+            "FOLDER_NAME_UNIQUENESS_VIOLATION" => ErrorMessageId::FolderNameUniquenessViolation,
+            "FOLDER_TO_DELETE_NON_EMPTY_VIOLATION" => {
+                ErrorMessageId::FolderToDeleteNonEmptyViolation
+            }
+            "MAX_CHILD_FOLDERS_VIOLATION" => ErrorMessageId::MaxChildFoldersViolation,
+            "PARENT_DELETED_VIOLATION" => ErrorMessageId::ParentDeletedViolation,
+            "RESOURCE_DELETED_VIOLATION" => ErrorMessageId::ResourceDeletedViolation,
+            _ => ErrorMessageId::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ErrorMessageId {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ErrorMessageId> for String {
+    fn from(v: ErrorMessageId) -> Self {
+        match v {
+            ErrorMessageId::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -474,55 +774,6 @@ pub struct FolderOperationError {
     pub error_message_id: Option<ErrorMessageId>,
 }
 
-/// Request message for `GetIamPolicy` method.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetIamPolicyRequest {
-    /**
-     * Request message for `GetIamPolicy` method.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub options: Option<GetPolicyOptions>,
-}
-
-/// Encapsulates settings provided to GetIamPolicy.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetPolicyOptions {
-    /**
-     * Encapsulates settings provided to GetIamPolicy.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "requestedPolicyVersion"
-    )]
-    pub requested_policy_version: i64,
-}
-
-/// The ListFolders response message.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ListFoldersResponse {
-    /**
-     * The ListFolders response message.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub folders: Vec<Folder>,
-    /**
-     * The ListFolders response message.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-}
-
 /// Metadata pertaining to the folder move process.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MoveFolderMetadata {
@@ -558,57 +809,9 @@ pub struct MoveFolderMetadata {
     pub source_parent: String,
 }
 
-/// The MoveFolder request message.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MoveFolderRequest {
-    /**
-     * The MoveFolder request message.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "destinationParent"
-    )]
-    pub destination_parent: String,
-}
-
-/// This resource represents a long-running operation that is the result of a network API call.
+/// A status object which is used as the `metadata` field for the Operation returned by MoveProject.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Operation {
-    /**
-     * This resource represents a long-running operation that is the result of a network API call.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub done: bool,
-    /**
-     * This resource represents a long-running operation that is the result of a network API call.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub error: Option<Status>,
-    /**
-     * This resource represents a long-running operation that is the result of a network API call.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<serde_json::Value>,
-    /**
-     * This resource represents a long-running operation that is the result of a network API call.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * This resource represents a long-running operation that is the result of a network API call.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub response: Option<serde_json::Value>,
-}
+pub struct MoveProjectMetadata {}
 
 /// An Identity and Access Management (IAM) policy, which specifies access controls for Google Cloud resources. A `Policy` is a collection of `bindings`. A `binding` binds one or more `members` to a single `role`. Members can be user accounts, service accounts, Google groups, and domains (such as G Suite). A `role` is a named list of permissions; each `role` can be an IAM predefined role or a user-created custom role. For some types of Google Cloud resources, a `binding` can also specify a `condition`, which is a logical expression that allows access to a resource only if the expression evaluates to `true`. A condition can add constraints based on attributes of the request, the resource, or both. To learn which resources support conditions in their IAM policies, see the [IAM documentation](https://cloud.google.com/iam/help/conditions/resource-policies). **JSON example:** { "bindings": [ { "role": "roles/resourcemanager.organizationAdmin", "members": [ "user:mike@example.com", "group:admins@example.com", "domain:google.com", "serviceAccount:my-project-id@appspot.gserviceaccount.com" ] }, { "role": "roles/resourcemanager.organizationViewer", "members": [ "user:eve@example.com" ], "condition": { "title": "expirable access", "description": "Does not grant access after Sep 2020", "expression": "request.time < timestamp('2020-10-01T00:00:00.000Z')", } } ], "etag": "BwWWja0YfJA=", "version": 3 } **YAML example:** bindings: - members: - user:mike@example.com - group:admins@example.com - domain:google.com - serviceAccount:my-project-id@appspot.gserviceaccount.com role: roles/resourcemanager.organizationAdmin - members: - user:eve@example.com role: roles/resourcemanager.organizationViewer condition: title: expirable access description: Does not grant access after Sep 2020 expression: request.time < timestamp('2020-10-01T00:00:00.000Z') etag: BwWWja0YfJA= version: 3 For a description of IAM and its features, see the [IAM documentation](https://cloud.google.com/iam/docs/).
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -648,219 +851,61 @@ pub struct Policy {
     pub version: i64,
 }
 
-/// The request message for searching folders.
+/// A status object which is used as the `metadata` field for the Operation returned by CreateProject. It provides insight for when significant phases of Project creation have completed.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SearchFoldersRequest {
+pub struct ProjectCreationStatus {
     /**
-     * The request message for searching folders.
+     * A status object which is used as the `metadata` field for the Operation returned by CreateProject. It provides insight for when significant phases of Project creation have completed.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "pageSize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "createTime"
     )]
-    pub page_size: i64,
+    pub create_time: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The request message for searching folders.
+     * A status object which is used as the `metadata` field for the Operation returned by CreateProject. It provides insight for when significant phases of Project creation have completed.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "pageToken"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub page_token: String,
+    pub gettable: bool,
     /**
-     * The request message for searching folders.
+     * A status object which is used as the `metadata` field for the Operation returned by CreateProject. It provides insight for when significant phases of Project creation have completed.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub query: String,
+    pub ready: bool,
 }
 
-/// The response message for searching folders.
+/// A status object which is used as the `metadata` field for the `Operation` returned by `UndeleteFolder`.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SearchFoldersResponse {
-    /**
-     * The response message for searching folders.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub folders: Vec<Folder>,
-    /**
-     * The response message for searching folders.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-}
+pub struct UndeleteFolderMetadata {}
 
-/// Request message for `SetIamPolicy` method.
+/// A status object which is used as the `metadata` field for the Operation returned by UndeleteOrganization.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SetIamPolicyRequest {
-    /**
-     * Request message for `SetIamPolicy` method.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub policy: Option<Policy>,
-    /**
-     * Request message for `SetIamPolicy` method.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "updateMask"
-    )]
-    pub update_mask: String,
-}
+pub struct UndeleteOrganizationMetadata {}
 
-/// The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
+/// A status object which is used as the `metadata` field for the Operation returned by `UndeleteProject`.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Status {
-    /**
-     * The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub code: i64,
-    /**
-     * The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub details: Vec<serde_json::Value>,
-    /**
-     * The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
+pub struct UndeleteProjectMetadata {}
 
-/// Request message for `TestIamPermissions` method.
+/// A status object which is used as the `metadata` field for the Operation returned by UpdateFolder.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TestIamPermissionsRequest {
-    /**
-     * Request message for `TestIamPermissions` method.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub permissions: Vec<String>,
-}
+pub struct UpdateFolderMetadata {}
 
-/// Response message for `TestIamPermissions` method.
+/// A status object which is used as the `metadata` field for the Operation returned by UpdateProject.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TestIamPermissionsResponse {
-    /**
-     * Response message for `TestIamPermissions` method.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub permissions: Vec<String>,
-}
+pub struct UpdateProjectMetadata {}
 
-/**
- * V1 error format.
- */
+/// Runtime operation information for updating a TagKey.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Xgafv {
-    #[serde(rename = "1")]
-    One,
-    #[serde(rename = "2")]
-    Two,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Xgafv {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Xgafv::One => "1",
-            Xgafv::Two => "2",
-            Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
+pub struct UpdateTagKeyMetadata {}
 
-impl Default for Xgafv {
-    fn default() -> Xgafv {
-        Xgafv::Noop
-    }
-}
-impl Xgafv {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Xgafv::Noop)
-    }
-}
-
-/**
- * Data format for response.
- */
+/// Runtime operation information for updating a TagValue.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Alt {
-    #[serde(rename = "json")]
-    Json,
-    #[serde(rename = "media")]
-    Media,
-    #[serde(rename = "proto")]
-    Proto,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Alt {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Alt::Json => "json",
-            Alt::Media => "media",
-            Alt::Proto => "proto",
-            Alt::Noop => "",
-            Alt::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Alt {
-    fn default() -> Alt {
-        Alt::Noop
-    }
-}
-impl Alt {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Alt::Noop)
-    }
-}
+pub struct UpdateTagValueMetadata {}
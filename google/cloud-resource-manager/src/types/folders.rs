@@ -0,0 +1,373 @@
+//! The data types of the `folders` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Request message for `GetIamPolicy` method.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetIamPolicyRequest {
+    /**
+     * Request message for `GetIamPolicy` method.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<GetPolicyOptions>,
+}
+
+/// Encapsulates settings provided to GetIamPolicy.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetPolicyOptions {
+    /**
+     * Encapsulates settings provided to GetIamPolicy.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "requestedPolicyVersion"
+    )]
+    pub requested_policy_version: i64,
+}
+
+/// The ListFolders response message.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ListFoldersResponse {
+    /**
+     * The ListFolders response message.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub folders: Vec<Folder>,
+    /**
+     * The ListFolders response message.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+}
+
+/// The MoveFolder request message.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MoveFolderRequest {
+    /**
+     * The MoveFolder request message.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "destinationParent"
+    )]
+    pub destination_parent: Option<String>,
+}
+
+/// This resource represents a long-running operation that is the result of a network API call.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Operation {
+    /**
+     * This resource represents a long-running operation that is the result of a network API call.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub done: bool,
+    /**
+     * This resource represents a long-running operation that is the result of a network API call.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Status>,
+    /**
+     * This resource represents a long-running operation that is the result of a network API call.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::BTreeMap<String, serde_json::Value>,
+    /**
+     * This resource represents a long-running operation that is the result of a network API call.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * This resource represents a long-running operation that is the result of a network API call.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub response: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+/// The request message for searching folders.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SearchFoldersRequest {
+    /**
+     * The request message for searching folders.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "pageSize"
+    )]
+    pub page_size: i64,
+    /**
+     * The request message for searching folders.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "pageToken")]
+    pub page_token: Option<String>,
+    /**
+     * The request message for searching folders.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+}
+
+/// The response message for searching folders.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SearchFoldersResponse {
+    /**
+     * The response message for searching folders.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub folders: Vec<Folder>,
+    /**
+     * The response message for searching folders.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+}
+
+/// Request message for `SetIamPolicy` method.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SetIamPolicyRequest {
+    /**
+     * Request message for `SetIamPolicy` method.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Policy>,
+    /**
+     * Request message for `SetIamPolicy` method.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "updateMask"
+    )]
+    pub update_mask: Option<String>,
+}
+
+/// The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Status {
+    /**
+     * The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub code: i64,
+    /**
+     * The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub details: Vec<std::collections::BTreeMap<String, serde_json::Value>>,
+    /**
+     * The `Status` type defines a logical error model that is suitable for different programming environments, including REST APIs and RPC APIs. It is used by [gRPC](https://github.com/grpc). Each `Status` message contains three pieces of data: error code, error message, and error details. You can find out more about this error model and how to work with it in the [API Design Guide](https://cloud.google.com/apis/design/errors).
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+}
+
+/// Request message for `TestIamPermissions` method.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TestIamPermissionsRequest {
+    /**
+     * Request message for `TestIamPermissions` method.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub permissions: Vec<String>,
+}
+
+/// Response message for `TestIamPermissions` method.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TestIamPermissionsResponse {
+    /**
+     * Response message for `TestIamPermissions` method.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub permissions: Vec<String>,
+}
+
+/// The UndeleteFolder request message.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UndeleteFolderRequest {}
+
+/**
+ * V1 error format.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Xgafv {
+    #[serde(rename = "1")]
+    One,
+    #[serde(rename = "2")]
+    Two,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Xgafv {
+    fn as_ref(&self) -> &str {
+        match self {
+            Xgafv::One => "1",
+            Xgafv::Two => "2",
+            Xgafv::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Xgafv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Xgafv {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "1" => Xgafv::One,
+            "2" => Xgafv::Two,
+            _ => Xgafv::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Xgafv {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Xgafv> for String {
+    fn from(v: Xgafv) -> Self {
+        match v {
+            Xgafv::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Data format for response.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Alt {
+    #[serde(rename = "json")]
+    Json,
+    #[serde(rename = "media")]
+    Media,
+    #[serde(rename = "proto")]
+    Proto,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Alt {
+    fn as_ref(&self) -> &str {
+        match self {
+            Alt::Json => "json",
+            Alt::Media => "media",
+            Alt::Proto => "proto",
+            Alt::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Alt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Alt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "json" => Alt::Json,
+            "media" => Alt::Media,
+            "proto" => Alt::Proto,
+            _ => Alt::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Alt {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Alt> for String {
+    fn from(v: Alt) -> Self {
+        match v {
+            Alt::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct About {
     pub client: Client,
@@ -22,3 +20,19 @@ impl About {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`About`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAboutApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AboutApi: Send + Sync {
+    /// See [`About::get`].
+    async fn get(&self) -> Result<crate::types::About>;
+}
+
+#[async_trait::async_trait]
+impl AboutApi for About {
+    async fn get(&self) -> Result<crate::types::About> {
+        About::get(self).await
+    }
+}
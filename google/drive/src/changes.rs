@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Changes {
     pub client: Client,
@@ -19,95 +17,68 @@ impl Changes {
      *
      * **Parameters:**
      *
-     * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response or to the response from the getStartPageToken method.
-     * * `drive_id: &str` -- The shared drive from which changes are returned. If specified the change IDs will be reflective of the shared drive; use the combined drive ID and change ID as an identifier.
-     * * `include_corpus_removals: bool` -- Whether changes should include the file resource if the file is still accessible by the user at the time of the request, even when a file was removed from the list of changes and there will be no further change entries for this file.
-     * * `include_items_from_all_drives: bool` -- Whether both My Drive and shared drive items should be included in results.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `include_removed: bool` -- Whether to include changes indicating that items have been removed from the list of changes, for example by deletion or loss of access.
-     * * `include_team_drive_items: bool` -- Whether the user has installed the requesting app.
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `restrict_to_my_drive: bool` -- Whether to restrict the results to changes inside the My Drive hierarchy. This omits changes to files such as those in the Application Data folder or shared files which have not been added to My Drive.
-     * * `spaces: &str` -- A comma-separated list of spaces to query within the user corpus. Supported values are 'drive', 'appDataFolder' and 'photos'.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `team_drive_id: &str` -- A link to this theme's background image.
+     * * `params: &ChangesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
-        &self,
-        page_token: &str,
-        drive_id: &str,
-        include_corpus_removals: bool,
-        include_items_from_all_drives: bool,
-        include_permissions_for_view: &str,
-        include_removed: bool,
-        include_team_drive_items: bool,
-        page_size: i64,
-        restrict_to_my_drive: bool,
-        spaces: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        team_drive_id: &str,
-    ) -> Result<Vec<crate::types::Change>> {
+    pub async fn list_with(&self, params: &ChangesListParams) -> Result<Vec<crate::types::Change>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !drive_id.is_empty() {
+        if let Some(drive_id) = &params.drive_id {
             query_args.push(("driveId".to_string(), drive_id.to_string()));
         }
-        if include_corpus_removals {
+        if let Some(include_corpus_removals) = &params.include_corpus_removals {
             query_args.push((
                 "includeCorpusRemovals".to_string(),
                 include_corpus_removals.to_string(),
             ));
         }
-        if include_items_from_all_drives {
+        if let Some(include_items_from_all_drives) = &params.include_items_from_all_drives {
             query_args.push((
                 "includeItemsFromAllDrives".to_string(),
                 include_items_from_all_drives.to_string(),
             ));
         }
-        if !include_permissions_for_view.is_empty() {
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
             query_args.push((
                 "includePermissionsForView".to_string(),
                 include_permissions_for_view.to_string(),
             ));
         }
-        if include_removed {
+        if let Some(include_removed) = &params.include_removed {
             query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
         }
-        if include_team_drive_items {
+        if let Some(include_team_drive_items) = &params.include_team_drive_items {
             query_args.push((
                 "includeTeamDriveItems".to_string(),
                 include_team_drive_items.to_string(),
             ));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if restrict_to_my_drive {
+        if let Some(restrict_to_my_drive) = &params.restrict_to_my_drive {
             query_args.push((
                 "restrictToMyDrive".to_string(),
                 restrict_to_my_drive.to_string(),
             ));
         }
-        if !spaces.is_empty() {
+        if let Some(spaces) = &params.spaces {
             query_args.push(("spaces".to_string(), spaces.to_string()));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if !team_drive_id.is_empty() {
+        if let Some(team_drive_id) = &params.team_drive_id {
             query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -120,79 +91,112 @@ impl Changes {
     }
 
     /**
-     * This function performs a `GET` to the `/changes` endpoint.
-     *
-     * As opposed to `list`, this function returns all the pages of the request at once.
-     *
-     * Lists the changes for a user or shared drive.
+     * Deprecated: use `list_with` and pass the query parameters as a `ChangesListParams` instead.
      */
-    pub async fn list_all(
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
         &self,
+        page_token: &str,
         drive_id: &str,
         include_corpus_removals: bool,
         include_items_from_all_drives: bool,
         include_permissions_for_view: &str,
         include_removed: bool,
         include_team_drive_items: bool,
+        page_size: i64,
         restrict_to_my_drive: bool,
         spaces: &str,
         supports_all_drives: bool,
         supports_team_drives: bool,
         team_drive_id: &str,
+    ) -> Result<Vec<crate::types::Change>> {
+        let params = ChangesListParams {
+            drive_id: Some(drive_id.to_string()).filter(|s| !s.is_empty()),
+            include_corpus_removals: Some(include_corpus_removals).filter(|v| *v),
+            include_items_from_all_drives: Some(include_items_from_all_drives).filter(|v| *v),
+            include_permissions_for_view: Some(include_permissions_for_view.to_string())
+                .filter(|s| !s.is_empty()),
+            include_removed: Some(include_removed).filter(|v| *v),
+            include_team_drive_items: Some(include_team_drive_items).filter(|v| *v),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            restrict_to_my_drive: Some(restrict_to_my_drive).filter(|v| *v),
+            spaces: Some(spaces.to_string()).filter(|s| !s.is_empty()),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            team_drive_id: Some(team_drive_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/changes` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Lists the changes for a user or shared drive.
+     *
+     * **Parameters:**
+     *
+     * * `params: &ChangesListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(
+        &self,
+        params: &ChangesListParams,
     ) -> Result<Vec<crate::types::Change>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !drive_id.is_empty() {
+        if let Some(drive_id) = &params.drive_id {
             query_args.push(("driveId".to_string(), drive_id.to_string()));
         }
-        if include_corpus_removals {
+        if let Some(include_corpus_removals) = &params.include_corpus_removals {
             query_args.push((
                 "includeCorpusRemovals".to_string(),
                 include_corpus_removals.to_string(),
             ));
         }
-        if include_items_from_all_drives {
+        if let Some(include_items_from_all_drives) = &params.include_items_from_all_drives {
             query_args.push((
                 "includeItemsFromAllDrives".to_string(),
                 include_items_from_all_drives.to_string(),
             ));
         }
-        if !include_permissions_for_view.is_empty() {
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
             query_args.push((
                 "includePermissionsForView".to_string(),
                 include_permissions_for_view.to_string(),
             ));
         }
-        if include_removed {
+        if let Some(include_removed) = &params.include_removed {
             query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
         }
-        if include_team_drive_items {
+        if let Some(include_team_drive_items) = &params.include_team_drive_items {
             query_args.push((
                 "includeTeamDriveItems".to_string(),
                 include_team_drive_items.to_string(),
             ));
         }
-        if restrict_to_my_drive {
+        if let Some(restrict_to_my_drive) = &params.restrict_to_my_drive {
             query_args.push((
                 "restrictToMyDrive".to_string(),
                 restrict_to_my_drive.to_string(),
             ));
         }
-        if !spaces.is_empty() {
+        if let Some(spaces) = &params.spaces {
             query_args.push(("spaces".to_string(), spaces.to_string()));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if !team_drive_id.is_empty() {
+        if let Some(team_drive_id) = &params.team_drive_id {
             query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -205,6 +209,7 @@ impl Changes {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -230,6 +235,133 @@ impl Changes {
         Ok(changes)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `ChangesListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<Vec<crate::types::Change>> {
+        let params = ChangesListParams {
+            drive_id: Some(drive_id.to_string()).filter(|s| !s.is_empty()),
+            include_corpus_removals: Some(include_corpus_removals).filter(|v| *v),
+            include_items_from_all_drives: Some(include_items_from_all_drives).filter(|v| *v),
+            include_permissions_for_view: Some(include_permissions_for_view.to_string())
+                .filter(|s| !s.is_empty()),
+            include_removed: Some(include_removed).filter(|v| *v),
+            include_team_drive_items: Some(include_team_drive_items).filter(|v| *v),
+            restrict_to_my_drive: Some(restrict_to_my_drive).filter(|v| *v),
+            spaces: Some(spaces.to_string()).filter(|s| !s.is_empty()),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            team_drive_id: Some(team_drive_id.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/changes` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the changes for a user or shared drive.
+     */
+    pub fn list_stream(
+        &self,
+        params: &ChangesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Change> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(drive_id) = &params.drive_id {
+            query_args.push(("driveId".to_string(), drive_id.to_string()));
+        }
+        if let Some(include_corpus_removals) = &params.include_corpus_removals {
+            query_args.push((
+                "includeCorpusRemovals".to_string(),
+                include_corpus_removals.to_string(),
+            ));
+        }
+        if let Some(include_items_from_all_drives) = &params.include_items_from_all_drives {
+            query_args.push((
+                "includeItemsFromAllDrives".to_string(),
+                include_items_from_all_drives.to_string(),
+            ));
+        }
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if let Some(include_removed) = &params.include_removed {
+            query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
+        }
+        if let Some(include_team_drive_items) = &params.include_team_drive_items {
+            query_args.push((
+                "includeTeamDriveItems".to_string(),
+                include_team_drive_items.to_string(),
+            ));
+        }
+        if let Some(restrict_to_my_drive) = &params.restrict_to_my_drive {
+            query_args.push((
+                "restrictToMyDrive".to_string(),
+                restrict_to_my_drive.to_string(),
+            ));
+        }
+        if let Some(spaces) = &params.spaces {
+            query_args.push(("spaces".to_string(), spaces.to_string()));
+        }
+        if let Some(supports_all_drives) = &params.supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if let Some(supports_team_drives) = &params.supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if let Some(team_drive_id) = &params.team_drive_id {
+            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/changes?{}", query_);
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::ChangeList = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.changes,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `GET` to the `/changes/startPageToken` endpoint.
      *
@@ -237,35 +369,29 @@ impl Changes {
      *
      * **Parameters:**
      *
-     * * `drive_id: &str` -- The ID of the shared drive for which the starting pageToken for listing future changes from that shared drive is returned.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `team_drive_id: &str` -- A link to this theme's background image.
+     * * `params: &ChangesGetStartPageTokenParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_start_page_token(
+    pub async fn get_start_page_token_with(
         &self,
-        drive_id: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        team_drive_id: &str,
+        params: &ChangesGetStartPageTokenParams,
     ) -> Result<crate::types::StartPageToken> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !drive_id.is_empty() {
+        if let Some(drive_id) = &params.drive_id {
             query_args.push(("driveId".to_string(), drive_id.to_string()));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if !team_drive_id.is_empty() {
+        if let Some(team_drive_id) = &params.team_drive_id {
             query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -274,6 +400,26 @@ impl Changes {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_start_page_token_with` and pass the query parameters as a `ChangesGetStartPageTokenParams` instead.
+     */
+    #[deprecated(note = "use `get_start_page_token_with` instead")]
+    pub async fn get_start_page_token(
+        &self,
+        drive_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<crate::types::StartPageToken> {
+        let params = ChangesGetStartPageTokenParams {
+            drive_id: Some(drive_id.to_string()).filter(|s| !s.is_empty()),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            team_drive_id: Some(team_drive_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_start_page_token_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/changes/watch` endpoint.
      *
@@ -281,96 +427,72 @@ impl Changes {
      *
      * **Parameters:**
      *
-     * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response or to the response from the getStartPageToken method.
-     * * `drive_id: &str` -- The shared drive from which changes are returned. If specified the change IDs will be reflective of the shared drive; use the combined drive ID and change ID as an identifier.
-     * * `include_corpus_removals: bool` -- Whether changes should include the file resource if the file is still accessible by the user at the time of the request, even when a file was removed from the list of changes and there will be no further change entries for this file.
-     * * `include_items_from_all_drives: bool` -- Whether both My Drive and shared drive items should be included in results.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `include_removed: bool` -- Whether to include changes indicating that items have been removed from the list of changes, for example by deletion or loss of access.
-     * * `include_team_drive_items: bool` -- Whether the user has installed the requesting app.
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `restrict_to_my_drive: bool` -- Whether to restrict the results to changes inside the My Drive hierarchy. This omits changes to files such as those in the Application Data folder or shared files which have not been added to My Drive.
-     * * `spaces: &str` -- A comma-separated list of spaces to query within the user corpus. Supported values are 'drive', 'appDataFolder' and 'photos'.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `team_drive_id: &str` -- A link to this theme's background image.
+     * * `params: &ChangesWatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn watch(
+    pub async fn watch_with(
         &self,
-        page_token: &str,
-        drive_id: &str,
-        include_corpus_removals: bool,
-        include_items_from_all_drives: bool,
-        include_permissions_for_view: &str,
-        include_removed: bool,
-        include_team_drive_items: bool,
-        page_size: i64,
-        restrict_to_my_drive: bool,
-        spaces: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        team_drive_id: &str,
+        params: &ChangesWatchParams,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !drive_id.is_empty() {
+        if let Some(drive_id) = &params.drive_id {
             query_args.push(("driveId".to_string(), drive_id.to_string()));
         }
-        if include_corpus_removals {
+        if let Some(include_corpus_removals) = &params.include_corpus_removals {
             query_args.push((
                 "includeCorpusRemovals".to_string(),
                 include_corpus_removals.to_string(),
             ));
         }
-        if include_items_from_all_drives {
+        if let Some(include_items_from_all_drives) = &params.include_items_from_all_drives {
             query_args.push((
                 "includeItemsFromAllDrives".to_string(),
                 include_items_from_all_drives.to_string(),
             ));
         }
-        if !include_permissions_for_view.is_empty() {
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
             query_args.push((
                 "includePermissionsForView".to_string(),
                 include_permissions_for_view.to_string(),
             ));
         }
-        if include_removed {
+        if let Some(include_removed) = &params.include_removed {
             query_args.push(("includeRemoved".to_string(), include_removed.to_string()));
         }
-        if include_team_drive_items {
+        if let Some(include_team_drive_items) = &params.include_team_drive_items {
             query_args.push((
                 "includeTeamDriveItems".to_string(),
                 include_team_drive_items.to_string(),
             ));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if restrict_to_my_drive {
+        if let Some(restrict_to_my_drive) = &params.restrict_to_my_drive {
             query_args.push((
                 "restrictToMyDrive".to_string(),
                 restrict_to_my_drive.to_string(),
             ));
         }
-        if !spaces.is_empty() {
+        if let Some(spaces) = &params.spaces {
             query_args.push(("spaces".to_string(), spaces.to_string()));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if !team_drive_id.is_empty() {
+        if let Some(team_drive_id) = &params.team_drive_id {
             query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -380,4 +502,349 @@ impl Changes {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `watch_with` and pass the query parameters as a `ChangesWatchParams` instead.
+     */
+    #[deprecated(note = "use `watch_with` instead")]
+    pub async fn watch(
+        &self,
+        page_token: &str,
+        drive_id: &str,
+        include_corpus_removals: bool,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_removed: bool,
+        include_team_drive_items: bool,
+        page_size: i64,
+        restrict_to_my_drive: bool,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        let params = ChangesWatchParams {
+            drive_id: Some(drive_id.to_string()).filter(|s| !s.is_empty()),
+            include_corpus_removals: Some(include_corpus_removals).filter(|v| *v),
+            include_items_from_all_drives: Some(include_items_from_all_drives).filter(|v| *v),
+            include_permissions_for_view: Some(include_permissions_for_view.to_string())
+                .filter(|s| !s.is_empty()),
+            include_removed: Some(include_removed).filter(|v| *v),
+            include_team_drive_items: Some(include_team_drive_items).filter(|v| *v),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            restrict_to_my_drive: Some(restrict_to_my_drive).filter(|v| *v),
+            spaces: Some(spaces.to_string()).filter(|s| !s.is_empty()),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            team_drive_id: Some(team_drive_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.watch_with(&params, body).await
+    }
+}
+
+/// The query parameters of `Changes::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChangesListParams {
+    /// The shared drive from which changes are returned. If specified the change IDs will be reflective of the shared drive; use the combined drive ID and change ID as an identifier.
+    pub drive_id: Option<String>,
+    /// Whether changes should include the file resource if the file is still accessible by the user at the time of the request, even when a file was removed from the list of changes and there will be no further change entries for this file.
+    pub include_corpus_removals: Option<bool>,
+    /// Whether both My Drive and shared drive items should be included in results.
+    pub include_items_from_all_drives: Option<bool>,
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub include_permissions_for_view: Option<String>,
+    /// Whether to include changes indicating that items have been removed from the list of changes, for example by deletion or loss of access.
+    pub include_removed: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub include_team_drive_items: Option<bool>,
+    /// A map of maximum import sizes by MIME type, in bytes.
+    pub page_size: Option<i64>,
+    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response or to the response from the getStartPageToken method.
+    pub page_token: Option<String>,
+    /// Whether to restrict the results to changes inside the My Drive hierarchy. This omits changes to files such as those in the Application Data folder or shared files which have not been added to My Drive.
+    pub restrict_to_my_drive: Option<bool>,
+    /// A comma-separated list of spaces to query within the user corpus. Supported values are 'drive', 'appDataFolder' and 'photos'.
+    pub spaces: Option<String>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+    /// A link to this theme's background image.
+    pub team_drive_id: Option<String>,
+}
+
+impl ChangesListParams {
+    pub fn drive_id<T: ToString>(mut self, drive_id: T) -> Self {
+        self.drive_id = Some(drive_id.to_string());
+        self
+    }
+
+    pub fn include_corpus_removals(mut self, include_corpus_removals: bool) -> Self {
+        self.include_corpus_removals = Some(include_corpus_removals);
+        self
+    }
+
+    pub fn include_items_from_all_drives(mut self, include_items_from_all_drives: bool) -> Self {
+        self.include_items_from_all_drives = Some(include_items_from_all_drives);
+        self
+    }
+
+    pub fn include_permissions_for_view<T: ToString>(
+        mut self,
+        include_permissions_for_view: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view.to_string());
+        self
+    }
+
+    pub fn include_removed(mut self, include_removed: bool) -> Self {
+        self.include_removed = Some(include_removed);
+        self
+    }
+
+    pub fn include_team_drive_items(mut self, include_team_drive_items: bool) -> Self {
+        self.include_team_drive_items = Some(include_team_drive_items);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn restrict_to_my_drive(mut self, restrict_to_my_drive: bool) -> Self {
+        self.restrict_to_my_drive = Some(restrict_to_my_drive);
+        self
+    }
+
+    pub fn spaces<T: ToString>(mut self, spaces: T) -> Self {
+        self.spaces = Some(spaces.to_string());
+        self
+    }
+
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    pub fn team_drive_id<T: ToString>(mut self, team_drive_id: T) -> Self {
+        self.team_drive_id = Some(team_drive_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Changes::get_start_page_token_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChangesGetStartPageTokenParams {
+    /// The ID of the shared drive for which the starting pageToken for listing future changes from that shared drive is returned.
+    pub drive_id: Option<String>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+    /// A link to this theme's background image.
+    pub team_drive_id: Option<String>,
+}
+
+impl ChangesGetStartPageTokenParams {
+    pub fn drive_id<T: ToString>(mut self, drive_id: T) -> Self {
+        self.drive_id = Some(drive_id.to_string());
+        self
+    }
+
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    pub fn team_drive_id<T: ToString>(mut self, team_drive_id: T) -> Self {
+        self.team_drive_id = Some(team_drive_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Changes::watch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChangesWatchParams {
+    /// The shared drive from which changes are returned. If specified the change IDs will be reflective of the shared drive; use the combined drive ID and change ID as an identifier.
+    pub drive_id: Option<String>,
+    /// Whether changes should include the file resource if the file is still accessible by the user at the time of the request, even when a file was removed from the list of changes and there will be no further change entries for this file.
+    pub include_corpus_removals: Option<bool>,
+    /// Whether both My Drive and shared drive items should be included in results.
+    pub include_items_from_all_drives: Option<bool>,
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub include_permissions_for_view: Option<String>,
+    /// Whether to include changes indicating that items have been removed from the list of changes, for example by deletion or loss of access.
+    pub include_removed: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub include_team_drive_items: Option<bool>,
+    /// A map of maximum import sizes by MIME type, in bytes.
+    pub page_size: Option<i64>,
+    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response or to the response from the getStartPageToken method.
+    pub page_token: Option<String>,
+    /// Whether to restrict the results to changes inside the My Drive hierarchy. This omits changes to files such as those in the Application Data folder or shared files which have not been added to My Drive.
+    pub restrict_to_my_drive: Option<bool>,
+    /// A comma-separated list of spaces to query within the user corpus. Supported values are 'drive', 'appDataFolder' and 'photos'.
+    pub spaces: Option<String>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+    /// A link to this theme's background image.
+    pub team_drive_id: Option<String>,
+}
+
+impl ChangesWatchParams {
+    pub fn drive_id<T: ToString>(mut self, drive_id: T) -> Self {
+        self.drive_id = Some(drive_id.to_string());
+        self
+    }
+
+    pub fn include_corpus_removals(mut self, include_corpus_removals: bool) -> Self {
+        self.include_corpus_removals = Some(include_corpus_removals);
+        self
+    }
+
+    pub fn include_items_from_all_drives(mut self, include_items_from_all_drives: bool) -> Self {
+        self.include_items_from_all_drives = Some(include_items_from_all_drives);
+        self
+    }
+
+    pub fn include_permissions_for_view<T: ToString>(
+        mut self,
+        include_permissions_for_view: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view.to_string());
+        self
+    }
+
+    pub fn include_removed(mut self, include_removed: bool) -> Self {
+        self.include_removed = Some(include_removed);
+        self
+    }
+
+    pub fn include_team_drive_items(mut self, include_team_drive_items: bool) -> Self {
+        self.include_team_drive_items = Some(include_team_drive_items);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn restrict_to_my_drive(mut self, restrict_to_my_drive: bool) -> Self {
+        self.restrict_to_my_drive = Some(restrict_to_my_drive);
+        self
+    }
+
+    pub fn spaces<T: ToString>(mut self, spaces: T) -> Self {
+        self.spaces = Some(spaces.to_string());
+        self
+    }
+
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    pub fn team_drive_id<T: ToString>(mut self, team_drive_id: T) -> Self {
+        self.team_drive_id = Some(team_drive_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`Changes`], for code that takes them as a trait, so that it can be
+/// tested with the `MockChangesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ChangesApi: Send + Sync {
+    /// See [`Changes::list_with`].
+    async fn list_with(&self, params: &ChangesListParams) -> Result<Vec<crate::types::Change>>;
+
+    /// See [`Changes::list_all_with`].
+    async fn list_all_with(&self, params: &ChangesListParams) -> Result<Vec<crate::types::Change>>;
+
+    /// See [`Changes::list_stream`].
+    fn list_stream(
+        &self,
+        params: &ChangesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Change>;
+
+    /// See [`Changes::get_start_page_token_with`].
+    async fn get_start_page_token_with(
+        &self,
+        params: &ChangesGetStartPageTokenParams,
+    ) -> Result<crate::types::StartPageToken>;
+
+    /// See [`Changes::watch_with`].
+    async fn watch_with(
+        &self,
+        params: &ChangesWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+}
+
+#[async_trait::async_trait]
+impl ChangesApi for Changes {
+    async fn list_with(&self, params: &ChangesListParams) -> Result<Vec<crate::types::Change>> {
+        Changes::list_with(self, params).await
+    }
+
+    async fn list_all_with(&self, params: &ChangesListParams) -> Result<Vec<crate::types::Change>> {
+        Changes::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &ChangesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Change> {
+        Changes::list_stream(self, params, start, max_items)
+    }
+
+    async fn get_start_page_token_with(
+        &self,
+        params: &ChangesGetStartPageTokenParams,
+    ) -> Result<crate::types::StartPageToken> {
+        Changes::get_start_page_token_with(self, params).await
+    }
+
+    async fn watch_with(
+        &self,
+        params: &ChangesWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Changes::watch_with(self, params, body).await
+    }
 }
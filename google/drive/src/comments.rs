@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Comments {
     pub client: Client,
@@ -20,30 +18,24 @@ impl Comments {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `include_deleted: bool` -- Whether to include deleted comments. Deleted comments will not include their original content.
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
-     * * `start_modified_time: &str` -- The minimum value of 'modifiedTime' for the result comments (RFC 3339 date-time).
+     * * `params: &CommentsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         file_id: &str,
-        include_deleted: bool,
-        page_size: i64,
-        page_token: &str,
-        start_modified_time: &str,
+        params: &CommentsListParams,
     ) -> Result<Vec<crate::types::Comment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_deleted {
+        if let Some(include_deleted) = &params.include_deleted {
             query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !start_modified_time.is_empty() {
+        if let Some(start_modified_time) = &params.start_modified_time {
             query_args.push((
                 "startModifiedTime".to_string(),
                 start_modified_time.to_string(),
@@ -62,24 +54,48 @@ impl Comments {
         Ok(resp.comments.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `CommentsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        file_id: &str,
+        include_deleted: bool,
+        page_size: i64,
+        page_token: &str,
+        start_modified_time: &str,
+    ) -> Result<Vec<crate::types::Comment>> {
+        let params = CommentsListParams {
+            include_deleted: Some(include_deleted).filter(|v| *v),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            start_modified_time: Some(start_modified_time.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(file_id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/comments` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Lists a file's comments.
+     *
+     * **Parameters:**
+     *
+     * * `params: &CommentsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(
+    pub async fn list_all_with(
         &self,
         file_id: &str,
-        include_deleted: bool,
-        start_modified_time: &str,
+        params: &CommentsListParams,
     ) -> Result<Vec<crate::types::Comment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_deleted {
+        if let Some(include_deleted) = &params.include_deleted {
             query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
         }
-        if !start_modified_time.is_empty() {
+        if let Some(start_modified_time) = &params.start_modified_time {
             query_args.push((
                 "startModifiedTime".to_string(),
                 start_modified_time.to_string(),
@@ -99,6 +115,7 @@ impl Comments {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -124,6 +141,75 @@ impl Comments {
         Ok(comments)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `CommentsListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        file_id: &str,
+        include_deleted: bool,
+        start_modified_time: &str,
+    ) -> Result<Vec<crate::types::Comment>> {
+        let params = CommentsListParams {
+            include_deleted: Some(include_deleted).filter(|v| *v),
+            start_modified_time: Some(start_modified_time.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(file_id, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists a file's comments.
+     */
+    pub fn list_stream(
+        &self,
+        file_id: &str,
+        params: &CommentsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Comment> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(include_deleted) = &params.include_deleted {
+            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+        }
+        if let Some(start_modified_time) = &params.start_modified_time {
+            query_args.push((
+                "startModifiedTime".to_string(),
+                start_modified_time.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/comments?{}",
+            crate::progenitor_support::encode_path(file_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::CommentList = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.comments,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/files/{fileId}/comments` endpoint.
      *
@@ -157,16 +243,16 @@ impl Comments {
      *
      * * `file_id: &str` -- A link to this theme's background image.
      * * `comment_id: &str` -- A link to this theme's background image.
-     * * `include_deleted: bool` -- Whether to return deleted comments. Deleted comments will not include their original content.
+     * * `params: &CommentsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         file_id: &str,
         comment_id: &str,
-        include_deleted: bool,
+        params: &CommentsGetParams,
     ) -> Result<crate::types::Comment> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_deleted {
+        if let Some(include_deleted) = &params.include_deleted {
             query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -180,6 +266,22 @@ impl Comments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `CommentsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+    ) -> Result<crate::types::Comment> {
+        let params = CommentsGetParams {
+            include_deleted: Some(include_deleted).filter(|v| *v),
+        };
+        self.get_with(file_id, comment_id, &params).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/files/{fileId}/comments/{commentId}` endpoint.
      *
@@ -227,3 +329,166 @@ impl Comments {
             .await
     }
 }
+
+/// The query parameters of `Comments::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CommentsListParams {
+    /// Whether to include deleted comments. Deleted comments will not include their original content.
+    pub include_deleted: Option<bool>,
+    /// A map of maximum import sizes by MIME type, in bytes.
+    pub page_size: Option<i64>,
+    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+    pub page_token: Option<String>,
+    /// The minimum value of 'modifiedTime' for the result comments (RFC 3339 date-time).
+    pub start_modified_time: Option<String>,
+}
+
+impl CommentsListParams {
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.include_deleted = Some(include_deleted);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn start_modified_time<T: ToString>(mut self, start_modified_time: T) -> Self {
+        self.start_modified_time = Some(start_modified_time.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Comments::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CommentsGetParams {
+    /// Whether to return deleted comments. Deleted comments will not include their original content.
+    pub include_deleted: Option<bool>,
+}
+
+impl CommentsGetParams {
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.include_deleted = Some(include_deleted);
+        self
+    }
+}
+
+/// The functions of [`Comments`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCommentsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CommentsApi: Send + Sync {
+    /// See [`Comments::list_with`].
+    async fn list_with(
+        &self,
+        file_id: &str,
+        params: &CommentsListParams,
+    ) -> Result<Vec<crate::types::Comment>>;
+
+    /// See [`Comments::list_all_with`].
+    async fn list_all_with(
+        &self,
+        file_id: &str,
+        params: &CommentsListParams,
+    ) -> Result<Vec<crate::types::Comment>>;
+
+    /// See [`Comments::list_stream`].
+    fn list_stream(
+        &self,
+        file_id: &str,
+        params: &CommentsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Comment>;
+
+    /// See [`Comments::create`].
+    async fn create(
+        &self,
+        file_id: &str,
+        body: &crate::types::Comment,
+    ) -> Result<crate::types::Comment>;
+
+    /// See [`Comments::get_with`].
+    async fn get_with(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        params: &CommentsGetParams,
+    ) -> Result<crate::types::Comment>;
+
+    /// See [`Comments::delete`].
+    async fn delete(&self, file_id: &str, comment_id: &str) -> Result<()>;
+
+    /// See [`Comments::update`].
+    async fn update(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        body: &crate::types::Comment,
+    ) -> Result<crate::types::Comment>;
+}
+
+#[async_trait::async_trait]
+impl CommentsApi for Comments {
+    async fn list_with(
+        &self,
+        file_id: &str,
+        params: &CommentsListParams,
+    ) -> Result<Vec<crate::types::Comment>> {
+        Comments::list_with(self, file_id, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        file_id: &str,
+        params: &CommentsListParams,
+    ) -> Result<Vec<crate::types::Comment>> {
+        Comments::list_all_with(self, file_id, params).await
+    }
+
+    fn list_stream(
+        &self,
+        file_id: &str,
+        params: &CommentsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Comment> {
+        Comments::list_stream(self, file_id, params, start, max_items)
+    }
+
+    async fn create(
+        &self,
+        file_id: &str,
+        body: &crate::types::Comment,
+    ) -> Result<crate::types::Comment> {
+        Comments::create(self, file_id, body).await
+    }
+
+    async fn get_with(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        params: &CommentsGetParams,
+    ) -> Result<crate::types::Comment> {
+        Comments::get_with(self, file_id, comment_id, params).await
+    }
+
+    async fn delete(&self, file_id: &str, comment_id: &str) -> Result<()> {
+        Comments::delete(self, file_id, comment_id).await
+    }
+
+    async fn update(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        body: &crate::types::Comment,
+    ) -> Result<crate::types::Comment> {
+        Comments::update(self, file_id, comment_id, body).await
+    }
+}
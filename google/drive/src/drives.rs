@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Drives {
     pub client: Client,
@@ -19,29 +17,20 @@ impl Drives {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `page_token: &str` -- A link to this theme's background image.
-     * * `q: &str` -- A link to this theme's background image.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then all shared drives of the domain in which the requester is an administrator are returned.
+     * * `params: &DrivesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
-        &self,
-        page_size: i64,
-        page_token: &str,
-        q: &str,
-        use_domain_admin_access: bool,
-    ) -> Result<Vec<crate::types::Drive>> {
+    pub async fn list_with(&self, params: &DrivesListParams) -> Result<Vec<crate::types::Drive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -56,23 +45,46 @@ impl Drives {
         Ok(resp.drives.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `DrivesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::Drive>> {
+        let params = DrivesListParams {
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/drives` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Lists the user's shared drives.
+     *
+     * **Parameters:**
+     *
+     * * `params: &DrivesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(
+    pub async fn list_all_with(
         &self,
-        q: &str,
-        use_domain_admin_access: bool,
+        params: &DrivesListParams,
     ) -> Result<Vec<crate::types::Drive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -88,6 +100,7 @@ impl Drives {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -113,6 +126,69 @@ impl Drives {
         Ok(drives)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `DrivesListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::Drive>> {
+        let params = DrivesListParams {
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/drives` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the user's shared drives.
+     */
+    pub fn list_stream(
+        &self,
+        params: &DrivesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Drive> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/drives?{}", query_);
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::DriveList = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.drives,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/drives` endpoint.
      *
@@ -120,15 +196,15 @@ impl Drives {
      *
      * **Parameters:**
      *
-     * * `request_id: &str` -- An ID, such as a random UUID, which uniquely identifies this user's request for idempotent creation of a shared drive. A repeated request by the same user and with the same request ID will avoid creating duplicates by attempting to create the same shared drive. If the shared drive already exists a 409 error will be returned.
+     * * `params: &DrivesCreateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create(
+    pub async fn create_with(
         &self,
-        request_id: &str,
+        params: &DrivesCreateParams,
         body: &crate::types::Drive,
     ) -> Result<crate::types::Drive> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !request_id.is_empty() {
+        if let Some(request_id) = &params.request_id {
             query_args.push(("requestId".to_string(), request_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -139,6 +215,21 @@ impl Drives {
             .await
     }
 
+    /**
+     * Deprecated: use `create_with` and pass the query parameters as a `DrivesCreateParams` instead.
+     */
+    #[deprecated(note = "use `create_with` instead")]
+    pub async fn create(
+        &self,
+        request_id: &str,
+        body: &crate::types::Drive,
+    ) -> Result<crate::types::Drive> {
+        let params = DrivesCreateParams {
+            request_id: Some(request_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_with(&params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/drives/{driveId}` endpoint.
      *
@@ -147,15 +238,15 @@ impl Drives {
      * **Parameters:**
      *
      * * `drive_id: &str` -- A link to this theme's background image.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the shared drive belongs.
+     * * `params: &DrivesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         drive_id: &str,
-        use_domain_admin_access: bool,
+        params: &DrivesGetParams,
     ) -> Result<crate::types::Drive> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -171,6 +262,21 @@ impl Drives {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `DrivesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        drive_id: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<crate::types::Drive> {
+        let params = DrivesGetParams {
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+        };
+        self.get_with(drive_id, &params).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/drives/{driveId}` endpoint.
      *
@@ -197,16 +303,16 @@ impl Drives {
      * **Parameters:**
      *
      * * `drive_id: &str` -- A link to this theme's background image.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the shared drive belongs.
+     * * `params: &DrivesUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update(
+    pub async fn update_with(
         &self,
         drive_id: &str,
-        use_domain_admin_access: bool,
+        params: &DrivesUpdateParams,
         body: &crate::types::Drive,
     ) -> Result<crate::types::Drive> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -224,6 +330,22 @@ impl Drives {
             .await
     }
 
+    /**
+     * Deprecated: use `update_with` and pass the query parameters as a `DrivesUpdateParams` instead.
+     */
+    #[deprecated(note = "use `update_with` instead")]
+    pub async fn update(
+        &self,
+        drive_id: &str,
+        use_domain_admin_access: bool,
+        body: &crate::types::Drive,
+    ) -> Result<crate::types::Drive> {
+        let params = DrivesUpdateParams {
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+        };
+        self.update_with(drive_id, &params, body).await
+    }
+
     /**
      * This function performs a `POST` to the `/drives/{driveId}/hide` endpoint.
      *
@@ -260,3 +382,188 @@ impl Drives {
         self.client.post(&url, None).await
     }
 }
+
+/// The query parameters of `Drives::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DrivesListParams {
+    /// A map of maximum import sizes by MIME type, in bytes.
+    pub page_size: Option<i64>,
+    /// A link to this theme's background image.
+    pub page_token: Option<String>,
+    /// A link to this theme's background image.
+    pub q: Option<String>,
+    /// Issue the request as a domain administrator; if set to true, then all shared drives of the domain in which the requester is an administrator are returned.
+    pub use_domain_admin_access: Option<bool>,
+}
+
+impl DrivesListParams {
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+}
+
+/// The query parameters of `Drives::create_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DrivesCreateParams {
+    /// An ID, such as a random UUID, which uniquely identifies this user's request for idempotent creation of a shared drive. A repeated request by the same user and with the same request ID will avoid creating duplicates by attempting to create the same shared drive. If the shared drive already exists a 409 error will be returned.
+    pub request_id: Option<String>,
+}
+
+impl DrivesCreateParams {
+    pub fn request_id<T: ToString>(mut self, request_id: T) -> Self {
+        self.request_id = Some(request_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Drives::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DrivesGetParams {
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the shared drive belongs.
+    pub use_domain_admin_access: Option<bool>,
+}
+
+impl DrivesGetParams {
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+}
+
+/// The query parameters of `Drives::update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DrivesUpdateParams {
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the shared drive belongs.
+    pub use_domain_admin_access: Option<bool>,
+}
+
+impl DrivesUpdateParams {
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+}
+
+/// The functions of [`Drives`], for code that takes them as a trait, so that it can be
+/// tested with the `MockDrivesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait DrivesApi: Send + Sync {
+    /// See [`Drives::list_with`].
+    async fn list_with(&self, params: &DrivesListParams) -> Result<Vec<crate::types::Drive>>;
+
+    /// See [`Drives::list_all_with`].
+    async fn list_all_with(&self, params: &DrivesListParams) -> Result<Vec<crate::types::Drive>>;
+
+    /// See [`Drives::list_stream`].
+    fn list_stream(
+        &self,
+        params: &DrivesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Drive>;
+
+    /// See [`Drives::create_with`].
+    async fn create_with(
+        &self,
+        params: &DrivesCreateParams,
+        body: &crate::types::Drive,
+    ) -> Result<crate::types::Drive>;
+
+    /// See [`Drives::get_with`].
+    async fn get_with(
+        &self,
+        drive_id: &str,
+        params: &DrivesGetParams,
+    ) -> Result<crate::types::Drive>;
+
+    /// See [`Drives::delete`].
+    async fn delete(&self, drive_id: &str) -> Result<()>;
+
+    /// See [`Drives::update_with`].
+    async fn update_with(
+        &self,
+        drive_id: &str,
+        params: &DrivesUpdateParams,
+        body: &crate::types::Drive,
+    ) -> Result<crate::types::Drive>;
+
+    /// See [`Drives::hide`].
+    async fn hide(&self, drive_id: &str) -> Result<crate::types::Drive>;
+
+    /// See [`Drives::unhide`].
+    async fn unhide(&self, drive_id: &str) -> Result<crate::types::Drive>;
+}
+
+#[async_trait::async_trait]
+impl DrivesApi for Drives {
+    async fn list_with(&self, params: &DrivesListParams) -> Result<Vec<crate::types::Drive>> {
+        Drives::list_with(self, params).await
+    }
+
+    async fn list_all_with(&self, params: &DrivesListParams) -> Result<Vec<crate::types::Drive>> {
+        Drives::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &DrivesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Drive> {
+        Drives::list_stream(self, params, start, max_items)
+    }
+
+    async fn create_with(
+        &self,
+        params: &DrivesCreateParams,
+        body: &crate::types::Drive,
+    ) -> Result<crate::types::Drive> {
+        Drives::create_with(self, params, body).await
+    }
+
+    async fn get_with(
+        &self,
+        drive_id: &str,
+        params: &DrivesGetParams,
+    ) -> Result<crate::types::Drive> {
+        Drives::get_with(self, drive_id, params).await
+    }
+
+    async fn delete(&self, drive_id: &str) -> Result<()> {
+        Drives::delete(self, drive_id).await
+    }
+
+    async fn update_with(
+        &self,
+        drive_id: &str,
+        params: &DrivesUpdateParams,
+        body: &crate::types::Drive,
+    ) -> Result<crate::types::Drive> {
+        Drives::update_with(self, drive_id, params, body).await
+    }
+
+    async fn hide(&self, drive_id: &str) -> Result<crate::types::Drive> {
+        Drives::hide(self, drive_id).await
+    }
+
+    async fn unhide(&self, drive_id: &str) -> Result<crate::types::Drive> {
+        Drives::unhide(self, drive_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Files {
     pub client: Client,
@@ -19,90 +17,62 @@ impl Files {
      *
      * **Parameters:**
      *
-     * * `corpora: &str` -- Groupings of files to which the query applies. Supported groupings are: 'user' (files created by, opened by, or shared directly with the user), 'drive' (files in the specified shared drive as indicated by the 'driveId'), 'domain' (files shared to the user's domain), and 'allDrives' (A combination of 'user' and 'drive' for all drives where the user is a member). When able, use 'user' or 'drive', instead of 'allDrives', for efficiency.
-     * * `corpus: crate::types::Corpus` -- The source of files to list. Deprecated: use 'corpora' instead.
-     * * `drive_id: &str` -- A link to this theme's background image.
-     * * `include_items_from_all_drives: bool` -- Whether both My Drive and shared drive items should be included in results.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `include_team_drive_items: bool` -- Whether the user has installed the requesting app.
-     * * `order_by: &str` -- A comma-separated list of sort keys. Valid keys are 'createdTime', 'folder', 'modifiedByMeTime', 'modifiedTime', 'name', 'name_natural', 'quotaBytesUsed', 'recency', 'sharedWithMeTime', 'starred', and 'viewedByMeTime'. Each key sorts ascending by default, but may be reversed with the 'desc' modifier. Example usage: ?orderBy=folder,modifiedTime desc,name. Please note that there is a current limitation for users with approximately one million files in which the requested sort order is ignored.
-     * * `page_size: i64` -- The maximum number of files to return per page. Partial or empty result pages are possible even before the end of the files list has been reached.
-     * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
-     * * `q: &str` -- A query for filtering the file results. See the "Search for Files" guide for supported syntax.
-     * * `spaces: &str` -- A comma-separated list of spaces to query within the corpus. Supported values are 'drive' and 'appDataFolder'.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `team_drive_id: &str` -- A link to this theme's background image.
+     * * `params: &FilesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
-        &self,
-        corpora: &str,
-        drive_id: &str,
-        include_items_from_all_drives: bool,
-        include_permissions_for_view: &str,
-        include_team_drive_items: bool,
-        order_by: &str,
-        page_size: i64,
-        page_token: &str,
-        q: &str,
-        spaces: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        team_drive_id: &str,
-    ) -> Result<Vec<crate::types::File>> {
+    pub async fn list_with(&self, params: &FilesListParams) -> Result<Vec<crate::types::File>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !corpora.is_empty() {
+        if let Some(corpora) = &params.corpora {
             query_args.push(("corpora".to_string(), corpora.to_string()));
         }
-        if !drive_id.is_empty() {
+        if let Some(drive_id) = &params.drive_id {
             query_args.push(("driveId".to_string(), drive_id.to_string()));
         }
-        if include_items_from_all_drives {
+        if let Some(include_items_from_all_drives) = &params.include_items_from_all_drives {
             query_args.push((
                 "includeItemsFromAllDrives".to_string(),
                 include_items_from_all_drives.to_string(),
             ));
         }
-        if !include_permissions_for_view.is_empty() {
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
             query_args.push((
                 "includePermissionsForView".to_string(),
                 include_permissions_for_view.to_string(),
             ));
         }
-        if include_team_drive_items {
+        if let Some(include_team_drive_items) = &params.include_team_drive_items {
             query_args.push((
                 "includeTeamDriveItems".to_string(),
                 include_team_drive_items.to_string(),
             ));
         }
-        if !order_by.is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !spaces.is_empty() {
+        if let Some(spaces) = &params.spaces {
             query_args.push(("spaces".to_string(), spaces.to_string()));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if !team_drive_id.is_empty() {
+        if let Some(team_drive_id) = &params.team_drive_id {
             query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -115,13 +85,10 @@ impl Files {
     }
 
     /**
-     * This function performs a `GET` to the `/files` endpoint.
-     *
-     * As opposed to `list`, this function returns all the pages of the request at once.
-     *
-     * Lists or searches files.
+     * Deprecated: use `list_with` and pass the query parameters as a `FilesListParams` instead.
      */
-    pub async fn list_all(
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
         &self,
         corpora: &str,
         drive_id: &str,
@@ -129,59 +96,92 @@ impl Files {
         include_permissions_for_view: &str,
         include_team_drive_items: bool,
         order_by: &str,
+        page_size: i64,
+        page_token: &str,
         q: &str,
         spaces: &str,
         supports_all_drives: bool,
         supports_team_drives: bool,
         team_drive_id: &str,
     ) -> Result<Vec<crate::types::File>> {
+        let params = FilesListParams {
+            corpora: Some(corpora.to_string()).filter(|s| !s.is_empty()),
+            drive_id: Some(drive_id.to_string()).filter(|s| !s.is_empty()),
+            include_items_from_all_drives: Some(include_items_from_all_drives).filter(|v| *v),
+            include_permissions_for_view: Some(include_permissions_for_view.to_string())
+                .filter(|s| !s.is_empty()),
+            include_team_drive_items: Some(include_team_drive_items).filter(|v| *v),
+            order_by: Some(order_by.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            spaces: Some(spaces.to_string()).filter(|s| !s.is_empty()),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            team_drive_id: Some(team_drive_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/files` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Lists or searches files.
+     *
+     * **Parameters:**
+     *
+     * * `params: &FilesListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(&self, params: &FilesListParams) -> Result<Vec<crate::types::File>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !corpora.is_empty() {
+        if let Some(corpora) = &params.corpora {
             query_args.push(("corpora".to_string(), corpora.to_string()));
         }
-        if !drive_id.is_empty() {
+        if let Some(drive_id) = &params.drive_id {
             query_args.push(("driveId".to_string(), drive_id.to_string()));
         }
-        if include_items_from_all_drives {
+        if let Some(include_items_from_all_drives) = &params.include_items_from_all_drives {
             query_args.push((
                 "includeItemsFromAllDrives".to_string(),
                 include_items_from_all_drives.to_string(),
             ));
         }
-        if !include_permissions_for_view.is_empty() {
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
             query_args.push((
                 "includePermissionsForView".to_string(),
                 include_permissions_for_view.to_string(),
             ));
         }
-        if include_team_drive_items {
+        if let Some(include_team_drive_items) = &params.include_team_drive_items {
             query_args.push((
                 "includeTeamDriveItems".to_string(),
                 include_team_drive_items.to_string(),
             ));
         }
-        if !order_by.is_empty() {
+        if let Some(order_by) = &params.order_by {
             query_args.push(("orderBy".to_string(), order_by.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !spaces.is_empty() {
+        if let Some(spaces) = &params.spaces {
             query_args.push(("spaces".to_string(), spaces.to_string()));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if !team_drive_id.is_empty() {
+        if let Some(team_drive_id) = &params.team_drive_id {
             query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -194,6 +194,7 @@ impl Files {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -219,6 +220,127 @@ impl Files {
         Ok(files)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `FilesListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        corpora: &str,
+        drive_id: &str,
+        include_items_from_all_drives: bool,
+        include_permissions_for_view: &str,
+        include_team_drive_items: bool,
+        order_by: &str,
+        q: &str,
+        spaces: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        team_drive_id: &str,
+    ) -> Result<Vec<crate::types::File>> {
+        let params = FilesListParams {
+            corpora: Some(corpora.to_string()).filter(|s| !s.is_empty()),
+            drive_id: Some(drive_id.to_string()).filter(|s| !s.is_empty()),
+            include_items_from_all_drives: Some(include_items_from_all_drives).filter(|v| *v),
+            include_permissions_for_view: Some(include_permissions_for_view.to_string())
+                .filter(|s| !s.is_empty()),
+            include_team_drive_items: Some(include_team_drive_items).filter(|v| *v),
+            order_by: Some(order_by.to_string()).filter(|s| !s.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            spaces: Some(spaces.to_string()).filter(|s| !s.is_empty()),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            team_drive_id: Some(team_drive_id.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/files` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists or searches files.
+     */
+    pub fn list_stream(
+        &self,
+        params: &FilesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::File> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(corpora) = &params.corpora {
+            query_args.push(("corpora".to_string(), corpora.to_string()));
+        }
+        if let Some(drive_id) = &params.drive_id {
+            query_args.push(("driveId".to_string(), drive_id.to_string()));
+        }
+        if let Some(include_items_from_all_drives) = &params.include_items_from_all_drives {
+            query_args.push((
+                "includeItemsFromAllDrives".to_string(),
+                include_items_from_all_drives.to_string(),
+            ));
+        }
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if let Some(include_team_drive_items) = &params.include_team_drive_items {
+            query_args.push((
+                "includeTeamDriveItems".to_string(),
+                include_team_drive_items.to_string(),
+            ));
+        }
+        if let Some(order_by) = &params.order_by {
+            query_args.push(("orderBy".to_string(), order_by.to_string()));
+        }
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(spaces) = &params.spaces {
+            query_args.push(("spaces".to_string(), spaces.to_string()));
+        }
+        if let Some(supports_all_drives) = &params.supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if let Some(supports_team_drives) = &params.supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if let Some(team_drive_id) = &params.team_drive_id {
+            query_args.push(("teamDriveId".to_string(), team_drive_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/files?{}", query_);
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::FileList = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.files,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/files` endpoint.
      *
@@ -226,61 +348,48 @@ impl Files {
      *
      * **Parameters:**
      *
-     * * `enforce_single_parent: bool` -- Deprecated. Creating files in multiple folders is no longer supported.
-     * * `ignore_default_visibility: bool` -- Whether to ignore the domain's default visibility settings for the created file. Domain administrators can choose to make all uploaded files visible to the domain by default; this parameter bypasses that behavior for the request. Permissions are still inherited from parent folders.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `keep_revision_forever: bool` -- Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
-     * * `ocr_language: &str` -- A language hint for OCR processing during image import (ISO 639-1 code).
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `use_content_as_indexable_text: bool` -- Whether to use the uploaded content as indexable text.
+     * * `params: &FilesCreateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create(
+    pub async fn create_with(
         &self,
-        ignore_default_visibility: bool,
-        include_permissions_for_view: &str,
-        keep_revision_forever: bool,
-        ocr_language: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        use_content_as_indexable_text: bool,
+        params: &FilesCreateParams,
         body: &crate::types::File,
     ) -> Result<crate::types::File> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if ignore_default_visibility {
+        if let Some(ignore_default_visibility) = &params.ignore_default_visibility {
             query_args.push((
                 "ignoreDefaultVisibility".to_string(),
                 ignore_default_visibility.to_string(),
             ));
         }
-        if !include_permissions_for_view.is_empty() {
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
             query_args.push((
                 "includePermissionsForView".to_string(),
                 include_permissions_for_view.to_string(),
             ));
         }
-        if keep_revision_forever {
+        if let Some(keep_revision_forever) = &params.keep_revision_forever {
             query_args.push((
                 "keepRevisionForever".to_string(),
                 keep_revision_forever.to_string(),
             ));
         }
-        if !ocr_language.is_empty() {
+        if let Some(ocr_language) = &params.ocr_language {
             query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if use_content_as_indexable_text {
+        if let Some(use_content_as_indexable_text) = &params.use_content_as_indexable_text {
             query_args.push((
                 "useContentAsIndexableText".to_string(),
                 use_content_as_indexable_text.to_string(),
@@ -294,6 +403,34 @@ impl Files {
             .await
     }
 
+    /**
+     * Deprecated: use `create_with` and pass the query parameters as a `FilesCreateParams` instead.
+     */
+    #[deprecated(note = "use `create_with` instead")]
+    pub async fn create(
+        &self,
+        ignore_default_visibility: bool,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_content_as_indexable_text: bool,
+        body: &crate::types::File,
+    ) -> Result<crate::types::File> {
+        let params = FilesCreateParams {
+            ignore_default_visibility: Some(ignore_default_visibility).filter(|v| *v),
+            include_permissions_for_view: Some(include_permissions_for_view.to_string())
+                .filter(|s| !s.is_empty()),
+            keep_revision_forever: Some(keep_revision_forever).filter(|v| *v),
+            ocr_language: Some(ocr_language.to_string()).filter(|s| !s.is_empty()),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            use_content_as_indexable_text: Some(use_content_as_indexable_text).filter(|v| *v),
+        };
+        self.create_with(&params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/files/generateIds` endpoint.
      *
@@ -301,24 +438,20 @@ impl Files {
      *
      * **Parameters:**
      *
-     * * `count: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `space: &str` -- The space in which the IDs can be used to create new files. Supported values are 'drive' and 'appDataFolder'. (Default: 'drive').
-     * * `type_: &str` -- The type of items which the IDs can be used for. Supported values are 'files' and 'shortcuts'. Note that 'shortcuts' are only supported in the drive 'space'. (Default: 'files').
+     * * `params: &FilesGenerateIdParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn generate_id(
+    pub async fn generate_id_with(
         &self,
-        count: i64,
-        space: &str,
-        type_: &str,
+        params: &FilesGenerateIdParams,
     ) -> Result<crate::types::GeneratedIds> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !space.is_empty() {
+        if let Some(space) = &params.space {
             query_args.push(("space".to_string(), space.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -327,6 +460,24 @@ impl Files {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `generate_id_with` and pass the query parameters as a `FilesGenerateIdParams` instead.
+     */
+    #[deprecated(note = "use `generate_id_with` instead")]
+    pub async fn generate_id(
+        &self,
+        count: i64,
+        space: &str,
+        type_: &str,
+    ) -> Result<crate::types::GeneratedIds> {
+        let params = FilesGenerateIdParams {
+            count: Some(count).filter(|v| *v > 0),
+            space: Some(space.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.generate_id_with(&params).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/files/trash` endpoint.
      *
@@ -349,39 +500,33 @@ impl Files {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `acknowledge_abuse: bool` -- Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `params: &FilesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         file_id: &str,
-        acknowledge_abuse: bool,
-        include_permissions_for_view: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
+        params: &FilesGetParams,
     ) -> Result<crate::types::File> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if acknowledge_abuse {
+        if let Some(acknowledge_abuse) = &params.acknowledge_abuse {
             query_args.push((
                 "acknowledgeAbuse".to_string(),
                 acknowledge_abuse.to_string(),
             ));
         }
-        if !include_permissions_for_view.is_empty() {
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
             query_args.push((
                 "includePermissionsForView".to_string(),
                 include_permissions_for_view.to_string(),
             ));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
@@ -397,6 +542,28 @@ impl Files {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `FilesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> Result<crate::types::File> {
+        let params = FilesGetParams {
+            acknowledge_abuse: Some(acknowledge_abuse).filter(|v| *v),
+            include_permissions_for_view: Some(include_permissions_for_view.to_string())
+                .filter(|s| !s.is_empty()),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+        };
+        self.get_with(file_id, &params).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/files/{fileId}` endpoint.
      *
@@ -405,24 +572,17 @@ impl Files {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `enforce_single_parent: bool` -- Deprecated. If an item is not in a shared drive and its last parent is deleted but the item itself is not, the item will be placed under its owner's root.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `params: &FilesDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete(
-        &self,
-        file_id: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-    ) -> Result<()> {
+    pub async fn delete_with(&self, file_id: &str, params: &FilesDeleteParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
@@ -438,6 +598,23 @@ impl Files {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_with` and pass the query parameters as a `FilesDeleteParams` instead.
+     */
+    #[deprecated(note = "use `delete_with` instead")]
+    pub async fn delete(
+        &self,
+        file_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+    ) -> Result<()> {
+        let params = FilesDeleteParams {
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+        };
+        self.delete_with(file_id, &params).await
+    }
+
     /**
      * This function performs a `PATCH` to the `/files/{fileId}` endpoint.
      *
@@ -446,64 +623,49 @@ impl Files {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `add_parents: &str` -- A comma-separated list of parent IDs to add.
-     * * `enforce_single_parent: bool` -- Deprecated. Adding files to multiple folders is no longer supported. Use shortcuts instead.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `keep_revision_forever: bool` -- Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
-     * * `ocr_language: &str` -- A language hint for OCR processing during image import (ISO 639-1 code).
-     * * `remove_parents: &str` -- A comma-separated list of parent IDs to remove.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `use_content_as_indexable_text: bool` -- Whether to use the uploaded content as indexable text.
+     * * `params: &FilesUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update(
+    pub async fn update_with(
         &self,
         file_id: &str,
-        add_parents: &str,
-        include_permissions_for_view: &str,
-        keep_revision_forever: bool,
-        ocr_language: &str,
-        remove_parents: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        use_content_as_indexable_text: bool,
+        params: &FilesUpdateParams,
         body: &crate::types::File,
     ) -> Result<crate::types::File> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !add_parents.is_empty() {
+        if let Some(add_parents) = &params.add_parents {
             query_args.push(("addParents".to_string(), add_parents.to_string()));
         }
-        if !include_permissions_for_view.is_empty() {
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
             query_args.push((
                 "includePermissionsForView".to_string(),
                 include_permissions_for_view.to_string(),
             ));
         }
-        if keep_revision_forever {
+        if let Some(keep_revision_forever) = &params.keep_revision_forever {
             query_args.push((
                 "keepRevisionForever".to_string(),
                 keep_revision_forever.to_string(),
             ));
         }
-        if !ocr_language.is_empty() {
+        if let Some(ocr_language) = &params.ocr_language {
             query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
         }
-        if !remove_parents.is_empty() {
+        if let Some(remove_parents) = &params.remove_parents {
             query_args.push(("removeParents".to_string(), remove_parents.to_string()));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if use_content_as_indexable_text {
+        if let Some(use_content_as_indexable_text) = &params.use_content_as_indexable_text {
             query_args.push((
                 "useContentAsIndexableText".to_string(),
                 use_content_as_indexable_text.to_string(),
@@ -521,6 +683,37 @@ impl Files {
             .await
     }
 
+    /**
+     * Deprecated: use `update_with` and pass the query parameters as a `FilesUpdateParams` instead.
+     */
+    #[deprecated(note = "use `update_with` instead")]
+    pub async fn update(
+        &self,
+        file_id: &str,
+        add_parents: &str,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        remove_parents: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_content_as_indexable_text: bool,
+        body: &crate::types::File,
+    ) -> Result<crate::types::File> {
+        let params = FilesUpdateParams {
+            add_parents: Some(add_parents.to_string()).filter(|s| !s.is_empty()),
+            include_permissions_for_view: Some(include_permissions_for_view.to_string())
+                .filter(|s| !s.is_empty()),
+            keep_revision_forever: Some(keep_revision_forever).filter(|v| *v),
+            ocr_language: Some(ocr_language.to_string()).filter(|s| !s.is_empty()),
+            remove_parents: Some(remove_parents.to_string()).filter(|s| !s.is_empty()),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            use_content_as_indexable_text: Some(use_content_as_indexable_text).filter(|v| *v),
+        };
+        self.update_with(file_id, &params, body).await
+    }
+
     /**
      * This function performs a `POST` to the `/files/{fileId}/copy` endpoint.
      *
@@ -529,54 +722,43 @@ impl Files {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `enforce_single_parent: bool` -- Deprecated. Copying files into multiple folders is no longer supported. Use shortcuts instead.
-     * * `ignore_default_visibility: bool` -- Whether to ignore the domain's default visibility settings for the created file. Domain administrators can choose to make all uploaded files visible to the domain by default; this parameter bypasses that behavior for the request. Permissions are still inherited from parent folders.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `keep_revision_forever: bool` -- Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
-     * * `ocr_language: &str` -- A language hint for OCR processing during image import (ISO 639-1 code).
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `params: &FilesCopyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn copy(
+    pub async fn copy_with(
         &self,
         file_id: &str,
-        ignore_default_visibility: bool,
-        include_permissions_for_view: &str,
-        keep_revision_forever: bool,
-        ocr_language: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
+        params: &FilesCopyParams,
         body: &crate::types::File,
     ) -> Result<crate::types::File> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if ignore_default_visibility {
+        if let Some(ignore_default_visibility) = &params.ignore_default_visibility {
             query_args.push((
                 "ignoreDefaultVisibility".to_string(),
                 ignore_default_visibility.to_string(),
             ));
         }
-        if !include_permissions_for_view.is_empty() {
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
             query_args.push((
                 "includePermissionsForView".to_string(),
                 include_permissions_for_view.to_string(),
             ));
         }
-        if keep_revision_forever {
+        if let Some(keep_revision_forever) = &params.keep_revision_forever {
             query_args.push((
                 "keepRevisionForever".to_string(),
                 keep_revision_forever.to_string(),
             ));
         }
-        if !ocr_language.is_empty() {
+        if let Some(ocr_language) = &params.ocr_language {
             query_args.push(("ocrLanguage".to_string(), ocr_language.to_string()));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
@@ -594,6 +776,33 @@ impl Files {
             .await
     }
 
+    /**
+     * Deprecated: use `copy_with` and pass the query parameters as a `FilesCopyParams` instead.
+     */
+    #[deprecated(note = "use `copy_with` instead")]
+    pub async fn copy(
+        &self,
+        file_id: &str,
+        ignore_default_visibility: bool,
+        include_permissions_for_view: &str,
+        keep_revision_forever: bool,
+        ocr_language: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        body: &crate::types::File,
+    ) -> Result<crate::types::File> {
+        let params = FilesCopyParams {
+            ignore_default_visibility: Some(ignore_default_visibility).filter(|v| *v),
+            include_permissions_for_view: Some(include_permissions_for_view.to_string())
+                .filter(|s| !s.is_empty()),
+            keep_revision_forever: Some(keep_revision_forever).filter(|v| *v),
+            ocr_language: Some(ocr_language.to_string()).filter(|s| !s.is_empty()),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+        };
+        self.copy_with(file_id, &params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/export` endpoint.
      *
@@ -602,11 +811,15 @@ impl Files {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `mime_type: &str` -- The MIME type of the format requested for this export.
+     * * `params: &FilesExportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn export(&self, file_id: &str, mime_type: &str) -> Result<()> {
+    pub async fn export_with(
+        &self,
+        file_id: &str,
+        params: &FilesExportParams,
+    ) -> Result<crate::Download> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !mime_type.is_empty() {
+        if let Some(mime_type) = &params.mime_type {
             query_args.push(("mimeType".to_string(), mime_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -616,7 +829,67 @@ impl Files {
             query_
         );
 
-        self.client.get(&url, None).await
+        self.client
+            .request_download(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `export_with` and pass the query parameters as a `FilesExportParams` instead.
+     */
+    #[deprecated(note = "use `export_with` instead")]
+    pub async fn export(&self, file_id: &str, mime_type: &str) -> Result<crate::Download> {
+        let params = FilesExportParams {
+            mime_type: Some(mime_type.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.export_with(file_id, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/export` endpoint.
+     *
+     * As opposed to `export`, this function returns the body as a stream of chunks instead of reading it into memory at once.
+     *
+     * Exports a Google Doc to the requested MIME type and returns the exported content. Please note that the exported content is limited to 10MB.
+     *
+     * **Parameters:**
+     *
+     * * `params: &FilesExportParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn export_stream_with(
+        &self,
+        file_id: &str,
+        params: &FilesExportParams,
+    ) -> Result<crate::DownloadStream> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(mime_type) = &params.mime_type {
+            query_args.push(("mimeType".to_string(), mime_type.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/export?{}",
+            crate::progenitor_support::encode_path(file_id),
+            query_
+        );
+
+        self.client
+            .request_download_stream(reqwest::Method::GET, &url, None)
+            .await
+    }
+
+    /**
+     * Deprecated: use `export_stream_with` and pass the query parameters as a `FilesExportParams` instead.
+     */
+    #[deprecated(note = "use `export_stream_with` instead")]
+    pub async fn export_stream(
+        &self,
+        file_id: &str,
+        mime_type: &str,
+    ) -> Result<crate::DownloadStream> {
+        let params = FilesExportParams {
+            mime_type: Some(mime_type.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.export_stream_with(file_id, &params).await
     }
 
     /**
@@ -627,40 +900,34 @@ impl Files {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `acknowledge_abuse: bool` -- Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
+     * * `params: &FilesWatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn watch(
+    pub async fn watch_with(
         &self,
         file_id: &str,
-        acknowledge_abuse: bool,
-        include_permissions_for_view: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
+        params: &FilesWatchParams,
         body: &crate::types::Channel,
     ) -> Result<crate::types::Channel> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if acknowledge_abuse {
+        if let Some(acknowledge_abuse) = &params.acknowledge_abuse {
             query_args.push((
                 "acknowledgeAbuse".to_string(),
                 acknowledge_abuse.to_string(),
             ));
         }
-        if !include_permissions_for_view.is_empty() {
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
             query_args.push((
                 "includePermissionsForView".to_string(),
                 include_permissions_for_view.to_string(),
             ));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
@@ -677,4 +944,614 @@ impl Files {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `watch_with` and pass the query parameters as a `FilesWatchParams` instead.
+     */
+    #[deprecated(note = "use `watch_with` instead")]
+    pub async fn watch(
+        &self,
+        file_id: &str,
+        acknowledge_abuse: bool,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        let params = FilesWatchParams {
+            acknowledge_abuse: Some(acknowledge_abuse).filter(|v| *v),
+            include_permissions_for_view: Some(include_permissions_for_view.to_string())
+                .filter(|s| !s.is_empty()),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+        };
+        self.watch_with(file_id, &params, body).await
+    }
+}
+
+/// The query parameters of `Files::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesListParams {
+    /// Groupings of files to which the query applies. Supported groupings are: 'user' (files created by, opened by, or shared directly with the user), 'drive' (files in the specified shared drive as indicated by the 'driveId'), 'domain' (files shared to the user's domain), and 'allDrives' (A combination of 'user' and 'drive' for all drives where the user is a member). When able, use 'user' or 'drive', instead of 'allDrives', for efficiency.
+    pub corpora: Option<String>,
+    /// A link to this theme's background image.
+    pub drive_id: Option<String>,
+    /// Whether both My Drive and shared drive items should be included in results.
+    pub include_items_from_all_drives: Option<bool>,
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub include_permissions_for_view: Option<String>,
+    /// Whether the user has installed the requesting app.
+    pub include_team_drive_items: Option<bool>,
+    /// A comma-separated list of sort keys. Valid keys are 'createdTime', 'folder', 'modifiedByMeTime', 'modifiedTime', 'name', 'name_natural', 'quotaBytesUsed', 'recency', 'sharedWithMeTime', 'starred', and 'viewedByMeTime'. Each key sorts ascending by default, but may be reversed with the 'desc' modifier. Example usage: ?orderBy=folder,modifiedTime desc,name. Please note that there is a current limitation for users with approximately one million files in which the requested sort order is ignored.
+    pub order_by: Option<String>,
+    /// The maximum number of files to return per page. Partial or empty result pages are possible even before the end of the files list has been reached.
+    pub page_size: Option<i64>,
+    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+    pub page_token: Option<String>,
+    /// A query for filtering the file results. See the "Search for Files" guide for supported syntax.
+    pub q: Option<String>,
+    /// A comma-separated list of spaces to query within the corpus. Supported values are 'drive' and 'appDataFolder'.
+    pub spaces: Option<String>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+    /// A link to this theme's background image.
+    pub team_drive_id: Option<String>,
+}
+
+impl FilesListParams {
+    pub fn corpora<T: ToString>(mut self, corpora: T) -> Self {
+        self.corpora = Some(corpora.to_string());
+        self
+    }
+
+    pub fn drive_id<T: ToString>(mut self, drive_id: T) -> Self {
+        self.drive_id = Some(drive_id.to_string());
+        self
+    }
+
+    pub fn include_items_from_all_drives(mut self, include_items_from_all_drives: bool) -> Self {
+        self.include_items_from_all_drives = Some(include_items_from_all_drives);
+        self
+    }
+
+    pub fn include_permissions_for_view<T: ToString>(
+        mut self,
+        include_permissions_for_view: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view.to_string());
+        self
+    }
+
+    pub fn include_team_drive_items(mut self, include_team_drive_items: bool) -> Self {
+        self.include_team_drive_items = Some(include_team_drive_items);
+        self
+    }
+
+    pub fn order_by<T: ToString>(mut self, order_by: T) -> Self {
+        self.order_by = Some(order_by.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn spaces<T: ToString>(mut self, spaces: T) -> Self {
+        self.spaces = Some(spaces.to_string());
+        self
+    }
+
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    pub fn team_drive_id<T: ToString>(mut self, team_drive_id: T) -> Self {
+        self.team_drive_id = Some(team_drive_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Files::create_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesCreateParams {
+    /// Whether to ignore the domain's default visibility settings for the created file. Domain administrators can choose to make all uploaded files visible to the domain by default; this parameter bypasses that behavior for the request. Permissions are still inherited from parent folders.
+    pub ignore_default_visibility: Option<bool>,
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub include_permissions_for_view: Option<String>,
+    /// Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
+    pub keep_revision_forever: Option<bool>,
+    /// A language hint for OCR processing during image import (ISO 639-1 code).
+    pub ocr_language: Option<String>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+    /// Whether to use the uploaded content as indexable text.
+    pub use_content_as_indexable_text: Option<bool>,
+}
+
+impl FilesCreateParams {
+    pub fn ignore_default_visibility(mut self, ignore_default_visibility: bool) -> Self {
+        self.ignore_default_visibility = Some(ignore_default_visibility);
+        self
+    }
+
+    pub fn include_permissions_for_view<T: ToString>(
+        mut self,
+        include_permissions_for_view: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view.to_string());
+        self
+    }
+
+    pub fn keep_revision_forever(mut self, keep_revision_forever: bool) -> Self {
+        self.keep_revision_forever = Some(keep_revision_forever);
+        self
+    }
+
+    pub fn ocr_language<T: ToString>(mut self, ocr_language: T) -> Self {
+        self.ocr_language = Some(ocr_language.to_string());
+        self
+    }
+
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    pub fn use_content_as_indexable_text(mut self, use_content_as_indexable_text: bool) -> Self {
+        self.use_content_as_indexable_text = Some(use_content_as_indexable_text);
+        self
+    }
+}
+
+/// The query parameters of `Files::generate_id_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesGenerateIdParams {
+    /// A map of maximum import sizes by MIME type, in bytes.
+    pub count: Option<i64>,
+    /// The space in which the IDs can be used to create new files. Supported values are 'drive' and 'appDataFolder'. (Default: 'drive').
+    pub space: Option<String>,
+    /// The type of items which the IDs can be used for. Supported values are 'files' and 'shortcuts'. Note that 'shortcuts' are only supported in the drive 'space'. (Default: 'files').
+    pub type_: Option<String>,
+}
+
+impl FilesGenerateIdParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn space<T: ToString>(mut self, space: T) -> Self {
+        self.space = Some(space.to_string());
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Files::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesGetParams {
+    /// Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
+    pub acknowledge_abuse: Option<bool>,
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub include_permissions_for_view: Option<String>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+}
+
+impl FilesGetParams {
+    pub fn acknowledge_abuse(mut self, acknowledge_abuse: bool) -> Self {
+        self.acknowledge_abuse = Some(acknowledge_abuse);
+        self
+    }
+
+    pub fn include_permissions_for_view<T: ToString>(
+        mut self,
+        include_permissions_for_view: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view.to_string());
+        self
+    }
+
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+}
+
+/// The query parameters of `Files::delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesDeleteParams {
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+}
+
+impl FilesDeleteParams {
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+}
+
+/// The query parameters of `Files::update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesUpdateParams {
+    /// A comma-separated list of parent IDs to add.
+    pub add_parents: Option<String>,
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub include_permissions_for_view: Option<String>,
+    /// Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
+    pub keep_revision_forever: Option<bool>,
+    /// A language hint for OCR processing during image import (ISO 639-1 code).
+    pub ocr_language: Option<String>,
+    /// A comma-separated list of parent IDs to remove.
+    pub remove_parents: Option<String>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+    /// Whether to use the uploaded content as indexable text.
+    pub use_content_as_indexable_text: Option<bool>,
+}
+
+impl FilesUpdateParams {
+    pub fn add_parents<T: ToString>(mut self, add_parents: T) -> Self {
+        self.add_parents = Some(add_parents.to_string());
+        self
+    }
+
+    pub fn include_permissions_for_view<T: ToString>(
+        mut self,
+        include_permissions_for_view: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view.to_string());
+        self
+    }
+
+    pub fn keep_revision_forever(mut self, keep_revision_forever: bool) -> Self {
+        self.keep_revision_forever = Some(keep_revision_forever);
+        self
+    }
+
+    pub fn ocr_language<T: ToString>(mut self, ocr_language: T) -> Self {
+        self.ocr_language = Some(ocr_language.to_string());
+        self
+    }
+
+    pub fn remove_parents<T: ToString>(mut self, remove_parents: T) -> Self {
+        self.remove_parents = Some(remove_parents.to_string());
+        self
+    }
+
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    pub fn use_content_as_indexable_text(mut self, use_content_as_indexable_text: bool) -> Self {
+        self.use_content_as_indexable_text = Some(use_content_as_indexable_text);
+        self
+    }
+}
+
+/// The query parameters of `Files::copy_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesCopyParams {
+    /// Whether to ignore the domain's default visibility settings for the created file. Domain administrators can choose to make all uploaded files visible to the domain by default; this parameter bypasses that behavior for the request. Permissions are still inherited from parent folders.
+    pub ignore_default_visibility: Option<bool>,
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub include_permissions_for_view: Option<String>,
+    /// Whether to set the 'keepForever' field in the new head revision. This is only applicable to files with binary content in Google Drive. Only 200 revisions for the file can be kept forever. If the limit is reached, try deleting pinned revisions.
+    pub keep_revision_forever: Option<bool>,
+    /// A language hint for OCR processing during image import (ISO 639-1 code).
+    pub ocr_language: Option<String>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+}
+
+impl FilesCopyParams {
+    pub fn ignore_default_visibility(mut self, ignore_default_visibility: bool) -> Self {
+        self.ignore_default_visibility = Some(ignore_default_visibility);
+        self
+    }
+
+    pub fn include_permissions_for_view<T: ToString>(
+        mut self,
+        include_permissions_for_view: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view.to_string());
+        self
+    }
+
+    pub fn keep_revision_forever(mut self, keep_revision_forever: bool) -> Self {
+        self.keep_revision_forever = Some(keep_revision_forever);
+        self
+    }
+
+    pub fn ocr_language<T: ToString>(mut self, ocr_language: T) -> Self {
+        self.ocr_language = Some(ocr_language.to_string());
+        self
+    }
+
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+}
+
+/// The query parameters of `Files::export_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesExportParams {
+    /// The MIME type of the format requested for this export.
+    pub mime_type: Option<String>,
+}
+
+impl FilesExportParams {
+    pub fn mime_type<T: ToString>(mut self, mime_type: T) -> Self {
+        self.mime_type = Some(mime_type.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Files::watch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesWatchParams {
+    /// Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
+    pub acknowledge_abuse: Option<bool>,
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub include_permissions_for_view: Option<String>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+}
+
+impl FilesWatchParams {
+    pub fn acknowledge_abuse(mut self, acknowledge_abuse: bool) -> Self {
+        self.acknowledge_abuse = Some(acknowledge_abuse);
+        self
+    }
+
+    pub fn include_permissions_for_view<T: ToString>(
+        mut self,
+        include_permissions_for_view: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view.to_string());
+        self
+    }
+
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+}
+
+/// The functions of [`Files`], for code that takes them as a trait, so that it can be
+/// tested with the `MockFilesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait FilesApi: Send + Sync {
+    /// See [`Files::list_with`].
+    async fn list_with(&self, params: &FilesListParams) -> Result<Vec<crate::types::File>>;
+
+    /// See [`Files::list_all_with`].
+    async fn list_all_with(&self, params: &FilesListParams) -> Result<Vec<crate::types::File>>;
+
+    /// See [`Files::list_stream`].
+    fn list_stream(
+        &self,
+        params: &FilesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::File>;
+
+    /// See [`Files::create_with`].
+    async fn create_with(
+        &self,
+        params: &FilesCreateParams,
+        body: &crate::types::File,
+    ) -> Result<crate::types::File>;
+
+    /// See [`Files::generate_id_with`].
+    async fn generate_id_with(
+        &self,
+        params: &FilesGenerateIdParams,
+    ) -> Result<crate::types::GeneratedIds>;
+
+    /// See [`Files::empty_trash`].
+    async fn empty_trash(&self) -> Result<()>;
+
+    /// See [`Files::get_with`].
+    async fn get_with(&self, file_id: &str, params: &FilesGetParams) -> Result<crate::types::File>;
+
+    /// See [`Files::delete_with`].
+    async fn delete_with(&self, file_id: &str, params: &FilesDeleteParams) -> Result<()>;
+
+    /// See [`Files::update_with`].
+    async fn update_with(
+        &self,
+        file_id: &str,
+        params: &FilesUpdateParams,
+        body: &crate::types::File,
+    ) -> Result<crate::types::File>;
+
+    /// See [`Files::copy_with`].
+    async fn copy_with(
+        &self,
+        file_id: &str,
+        params: &FilesCopyParams,
+        body: &crate::types::File,
+    ) -> Result<crate::types::File>;
+
+    /// See [`Files::export_with`].
+    async fn export_with(
+        &self,
+        file_id: &str,
+        params: &FilesExportParams,
+    ) -> Result<crate::Download>;
+
+    /// See [`Files::export_stream_with`].
+    async fn export_stream_with(
+        &self,
+        file_id: &str,
+        params: &FilesExportParams,
+    ) -> Result<crate::DownloadStream>;
+
+    /// See [`Files::watch_with`].
+    async fn watch_with(
+        &self,
+        file_id: &str,
+        params: &FilesWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel>;
+}
+
+#[async_trait::async_trait]
+impl FilesApi for Files {
+    async fn list_with(&self, params: &FilesListParams) -> Result<Vec<crate::types::File>> {
+        Files::list_with(self, params).await
+    }
+
+    async fn list_all_with(&self, params: &FilesListParams) -> Result<Vec<crate::types::File>> {
+        Files::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &FilesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::File> {
+        Files::list_stream(self, params, start, max_items)
+    }
+
+    async fn create_with(
+        &self,
+        params: &FilesCreateParams,
+        body: &crate::types::File,
+    ) -> Result<crate::types::File> {
+        Files::create_with(self, params, body).await
+    }
+
+    async fn generate_id_with(
+        &self,
+        params: &FilesGenerateIdParams,
+    ) -> Result<crate::types::GeneratedIds> {
+        Files::generate_id_with(self, params).await
+    }
+
+    async fn empty_trash(&self) -> Result<()> {
+        Files::empty_trash(self).await
+    }
+
+    async fn get_with(&self, file_id: &str, params: &FilesGetParams) -> Result<crate::types::File> {
+        Files::get_with(self, file_id, params).await
+    }
+
+    async fn delete_with(&self, file_id: &str, params: &FilesDeleteParams) -> Result<()> {
+        Files::delete_with(self, file_id, params).await
+    }
+
+    async fn update_with(
+        &self,
+        file_id: &str,
+        params: &FilesUpdateParams,
+        body: &crate::types::File,
+    ) -> Result<crate::types::File> {
+        Files::update_with(self, file_id, params, body).await
+    }
+
+    async fn copy_with(
+        &self,
+        file_id: &str,
+        params: &FilesCopyParams,
+        body: &crate::types::File,
+    ) -> Result<crate::types::File> {
+        Files::copy_with(self, file_id, params, body).await
+    }
+
+    async fn export_with(
+        &self,
+        file_id: &str,
+        params: &FilesExportParams,
+    ) -> Result<crate::Download> {
+        Files::export_with(self, file_id, params).await
+    }
+
+    async fn export_stream_with(
+        &self,
+        file_id: &str,
+        params: &FilesExportParams,
+    ) -> Result<crate::DownloadStream> {
+        Files::export_stream_with(self, file_id, params).await
+    }
+
+    async fn watch_with(
+        &self,
+        file_id: &str,
+        params: &FilesWatchParams,
+        body: &crate::types::Channel,
+    ) -> Result<crate::types::Channel> {
+        Files::watch_with(self, file_id, params, body).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Permissions {
     pub client: Client,
@@ -20,49 +18,39 @@ impl Permissions {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `include_permissions_for_view: &str` -- Specifies which additional view's permissions to include in the response. Only 'published' is supported.
-     * * `page_size: i64` -- The maximum number of permissions to return per page. When not set for files in a shared drive, at most 100 results will be returned. When not set for files that are not in a shared drive, the entire list will be returned.
-     * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     * * `params: &PermissionsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         file_id: &str,
-        include_permissions_for_view: &str,
-        page_size: i64,
-        page_token: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        use_domain_admin_access: bool,
+        params: &PermissionsListParams,
     ) -> Result<Vec<crate::types::Permission>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_permissions_for_view.is_empty() {
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
             query_args.push((
                 "includePermissionsForView".to_string(),
                 include_permissions_for_view.to_string(),
             ));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -81,41 +69,68 @@ impl Permissions {
         Ok(resp.permissions.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `PermissionsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        file_id: &str,
+        include_permissions_for_view: &str,
+        page_size: i64,
+        page_token: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::Permission>> {
+        let params = PermissionsListParams {
+            include_permissions_for_view: Some(include_permissions_for_view.to_string())
+                .filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+        };
+        self.list_with(file_id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/permissions` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Lists a file's or shared drive's permissions.
+     *
+     * **Parameters:**
+     *
+     * * `params: &PermissionsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(
+    pub async fn list_all_with(
         &self,
         file_id: &str,
-        include_permissions_for_view: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        use_domain_admin_access: bool,
+        params: &PermissionsListParams,
     ) -> Result<Vec<crate::types::Permission>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_permissions_for_view.is_empty() {
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
             query_args.push((
                 "includePermissionsForView".to_string(),
                 include_permissions_for_view.to_string(),
             ));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -135,6 +150,7 @@ impl Permissions {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -160,6 +176,95 @@ impl Permissions {
         Ok(permissions)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `PermissionsListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        file_id: &str,
+        include_permissions_for_view: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::Permission>> {
+        let params = PermissionsListParams {
+            include_permissions_for_view: Some(include_permissions_for_view.to_string())
+                .filter(|s| !s.is_empty()),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+            ..Default::default()
+        };
+        self.list_all_with(file_id, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/permissions` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists a file's or shared drive's permissions.
+     */
+    pub fn list_stream(
+        &self,
+        file_id: &str,
+        params: &PermissionsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Permission> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(include_permissions_for_view) = &params.include_permissions_for_view {
+            query_args.push((
+                "includePermissionsForView".to_string(),
+                include_permissions_for_view.to_string(),
+            ));
+        }
+        if let Some(supports_all_drives) = &params.supports_all_drives {
+            query_args.push((
+                "supportsAllDrives".to_string(),
+                supports_all_drives.to_string(),
+            ));
+        }
+        if let Some(supports_team_drives) = &params.supports_team_drives {
+            query_args.push((
+                "supportsTeamDrives".to_string(),
+                supports_team_drives.to_string(),
+            ));
+        }
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/permissions?{}",
+            crate::progenitor_support::encode_path(file_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::PermissionList = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.permissions,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/files/{fileId}/permissions` endpoint.
      *
@@ -168,60 +273,49 @@ impl Permissions {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `email_message: &str` -- A plain text custom message to include in the notification email.
-     * * `enforce_single_parent: bool` -- Whether the user has installed the requesting app.
-     * * `move_to_new_owners_root: bool` -- This parameter will only take effect if the item is not in a shared drive and the request is attempting to transfer the ownership of the item. If set to true, the item will be moved to the new owner's My Drive root folder and all prior parents removed. If set to false, parents are not changed.
-     * * `send_notification_email: bool` -- Whether to send a notification email when sharing to users or groups. This defaults to true for users and groups, and is not allowed for other requests. It must not be disabled for ownership transfers.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `transfer_ownership: bool` -- Whether to transfer ownership to the specified user and downgrade the current owner to a writer. This parameter is required as an acknowledgement of the side effect. File owners can only transfer ownership of files existing on My Drive. Files existing in a shared drive are owned by the organization that owns that shared drive. Ownership transfers are not supported for files and folders in shared drives. Organizers of a shared drive can move items from that shared drive into their My Drive which transfers the ownership to them.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     * * `params: &PermissionsCreateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create(
+    pub async fn create_with(
         &self,
         file_id: &str,
-        email_message: &str,
-        move_to_new_owners_root: bool,
-        send_notification_email: bool,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        transfer_ownership: bool,
-        use_domain_admin_access: bool,
+        params: &PermissionsCreateParams,
         body: &crate::types::Permission,
     ) -> Result<crate::types::Permission> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !email_message.is_empty() {
+        if let Some(email_message) = &params.email_message {
             query_args.push(("emailMessage".to_string(), email_message.to_string()));
         }
-        if move_to_new_owners_root {
+        if let Some(move_to_new_owners_root) = &params.move_to_new_owners_root {
             query_args.push((
                 "moveToNewOwnersRoot".to_string(),
                 move_to_new_owners_root.to_string(),
             ));
         }
-        query_args.push((
-            "sendNotificationEmail".to_string(),
-            send_notification_email.to_string(),
-        ));
-        if supports_all_drives {
+        if let Some(send_notification_email) = &params.send_notification_email {
+            query_args.push((
+                "sendNotificationEmail".to_string(),
+                send_notification_email.to_string(),
+            ));
+        }
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if transfer_ownership {
+        if let Some(transfer_ownership) = &params.transfer_ownership {
             query_args.push((
                 "transferOwnership".to_string(),
                 transfer_ownership.to_string(),
             ));
         }
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -239,6 +333,34 @@ impl Permissions {
             .await
     }
 
+    /**
+     * Deprecated: use `create_with` and pass the query parameters as a `PermissionsCreateParams` instead.
+     */
+    #[deprecated(note = "use `create_with` instead")]
+    pub async fn create(
+        &self,
+        file_id: &str,
+        email_message: &str,
+        move_to_new_owners_root: bool,
+        send_notification_email: bool,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        transfer_ownership: bool,
+        use_domain_admin_access: bool,
+        body: &crate::types::Permission,
+    ) -> Result<crate::types::Permission> {
+        let params = PermissionsCreateParams {
+            email_message: Some(email_message.to_string()).filter(|s| !s.is_empty()),
+            move_to_new_owners_root: Some(move_to_new_owners_root).filter(|v| *v),
+            send_notification_email: Some(send_notification_email),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            transfer_ownership: Some(transfer_ownership).filter(|v| *v),
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+        };
+        self.create_with(file_id, &params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
      *
@@ -248,32 +370,28 @@ impl Permissions {
      *
      * * `file_id: &str` -- A link to this theme's background image.
      * * `permission_id: &str` -- A link to this theme's background image.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     * * `params: &PermissionsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         file_id: &str,
         permission_id: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        use_domain_admin_access: bool,
+        params: &PermissionsGetParams,
     ) -> Result<crate::types::Permission> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -290,6 +408,26 @@ impl Permissions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `PermissionsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<crate::types::Permission> {
+        let params = PermissionsGetParams {
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+        };
+        self.get_with(file_id, permission_id, &params).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
      *
@@ -299,32 +437,28 @@ impl Permissions {
      *
      * * `file_id: &str` -- A link to this theme's background image.
      * * `permission_id: &str` -- A link to this theme's background image.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     * * `params: &PermissionsDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete(
+    pub async fn delete_with(
         &self,
         file_id: &str,
         permission_id: &str,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        use_domain_admin_access: bool,
+        params: &PermissionsDeleteParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -341,6 +475,26 @@ impl Permissions {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_with` and pass the query parameters as a `PermissionsDeleteParams` instead.
+     */
+    #[deprecated(note = "use `delete_with` instead")]
+    pub async fn delete(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        use_domain_admin_access: bool,
+    ) -> Result<()> {
+        let params = PermissionsDeleteParams {
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+        };
+        self.delete_with(file_id, permission_id, &params).await
+    }
+
     /**
      * This function performs a `PATCH` to the `/files/{fileId}/permissions/{permissionId}` endpoint.
      *
@@ -350,49 +504,41 @@ impl Permissions {
      *
      * * `file_id: &str` -- A link to this theme's background image.
      * * `permission_id: &str` -- A link to this theme's background image.
-     * * `remove_expiration: bool` -- Whether the user has installed the requesting app.
-     * * `supports_all_drives: bool` -- Whether the requesting application supports both My Drives and shared drives.
-     * * `supports_team_drives: bool` -- Whether the user has installed the requesting app.
-     * * `transfer_ownership: bool` -- Whether to transfer ownership to the specified user and downgrade the current owner to a writer. This parameter is required as an acknowledgement of the side effect. File owners can only transfer ownership of files existing on My Drive. Files existing in a shared drive are owned by the organization that owns that shared drive. Ownership transfers are not supported for files and folders in shared drives. Organizers of a shared drive can move items from that shared drive into their My Drive which transfers the ownership to them.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+     * * `params: &PermissionsUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update(
+    pub async fn update_with(
         &self,
         file_id: &str,
         permission_id: &str,
-        remove_expiration: bool,
-        supports_all_drives: bool,
-        supports_team_drives: bool,
-        transfer_ownership: bool,
-        use_domain_admin_access: bool,
+        params: &PermissionsUpdateParams,
         body: &crate::types::Permission,
     ) -> Result<crate::types::Permission> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if remove_expiration {
+        if let Some(remove_expiration) = &params.remove_expiration {
             query_args.push((
                 "removeExpiration".to_string(),
                 remove_expiration.to_string(),
             ));
         }
-        if supports_all_drives {
+        if let Some(supports_all_drives) = &params.supports_all_drives {
             query_args.push((
                 "supportsAllDrives".to_string(),
                 supports_all_drives.to_string(),
             ));
         }
-        if supports_team_drives {
+        if let Some(supports_team_drives) = &params.supports_team_drives {
             query_args.push((
                 "supportsTeamDrives".to_string(),
                 supports_team_drives.to_string(),
             ));
         }
-        if transfer_ownership {
+        if let Some(transfer_ownership) = &params.transfer_ownership {
             query_args.push((
                 "transferOwnership".to_string(),
                 transfer_ownership.to_string(),
             ));
         }
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -410,4 +556,364 @@ impl Permissions {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `update_with` and pass the query parameters as a `PermissionsUpdateParams` instead.
+     */
+    #[deprecated(note = "use `update_with` instead")]
+    pub async fn update(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        remove_expiration: bool,
+        supports_all_drives: bool,
+        supports_team_drives: bool,
+        transfer_ownership: bool,
+        use_domain_admin_access: bool,
+        body: &crate::types::Permission,
+    ) -> Result<crate::types::Permission> {
+        let params = PermissionsUpdateParams {
+            remove_expiration: Some(remove_expiration).filter(|v| *v),
+            supports_all_drives: Some(supports_all_drives).filter(|v| *v),
+            supports_team_drives: Some(supports_team_drives).filter(|v| *v),
+            transfer_ownership: Some(transfer_ownership).filter(|v| *v),
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+        };
+        self.update_with(file_id, permission_id, &params, body)
+            .await
+    }
+}
+
+/// The query parameters of `Permissions::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PermissionsListParams {
+    /// Specifies which additional view's permissions to include in the response. Only 'published' is supported.
+    pub include_permissions_for_view: Option<String>,
+    /// The maximum number of permissions to return per page. When not set for files in a shared drive, at most 100 results will be returned. When not set for files that are not in a shared drive, the entire list will be returned.
+    pub page_size: Option<i64>,
+    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+    pub page_token: Option<String>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+    pub use_domain_admin_access: Option<bool>,
+}
+
+impl PermissionsListParams {
+    pub fn include_permissions_for_view<T: ToString>(
+        mut self,
+        include_permissions_for_view: T,
+    ) -> Self {
+        self.include_permissions_for_view = Some(include_permissions_for_view.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+}
+
+/// The query parameters of `Permissions::create_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PermissionsCreateParams {
+    /// A plain text custom message to include in the notification email.
+    pub email_message: Option<String>,
+    /// This parameter will only take effect if the item is not in a shared drive and the request is attempting to transfer the ownership of the item. If set to true, the item will be moved to the new owner's My Drive root folder and all prior parents removed. If set to false, parents are not changed.
+    pub move_to_new_owners_root: Option<bool>,
+    /// Whether to send a notification email when sharing to users or groups. This defaults to true for users and groups, and is not allowed for other requests. It must not be disabled for ownership transfers.
+    pub send_notification_email: Option<bool>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+    /// Whether to transfer ownership to the specified user and downgrade the current owner to a writer. This parameter is required as an acknowledgement of the side effect. File owners can only transfer ownership of files existing on My Drive. Files existing in a shared drive are owned by the organization that owns that shared drive. Ownership transfers are not supported for files and folders in shared drives. Organizers of a shared drive can move items from that shared drive into their My Drive which transfers the ownership to them.
+    pub transfer_ownership: Option<bool>,
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+    pub use_domain_admin_access: Option<bool>,
+}
+
+impl PermissionsCreateParams {
+    pub fn email_message<T: ToString>(mut self, email_message: T) -> Self {
+        self.email_message = Some(email_message.to_string());
+        self
+    }
+
+    pub fn move_to_new_owners_root(mut self, move_to_new_owners_root: bool) -> Self {
+        self.move_to_new_owners_root = Some(move_to_new_owners_root);
+        self
+    }
+
+    pub fn send_notification_email(mut self, send_notification_email: bool) -> Self {
+        self.send_notification_email = Some(send_notification_email);
+        self
+    }
+
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    pub fn transfer_ownership(mut self, transfer_ownership: bool) -> Self {
+        self.transfer_ownership = Some(transfer_ownership);
+        self
+    }
+
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+}
+
+/// The query parameters of `Permissions::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PermissionsGetParams {
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+    pub use_domain_admin_access: Option<bool>,
+}
+
+impl PermissionsGetParams {
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+}
+
+/// The query parameters of `Permissions::delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PermissionsDeleteParams {
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+    pub use_domain_admin_access: Option<bool>,
+}
+
+impl PermissionsDeleteParams {
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+}
+
+/// The query parameters of `Permissions::update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PermissionsUpdateParams {
+    /// Whether the user has installed the requesting app.
+    pub remove_expiration: Option<bool>,
+    /// Whether the requesting application supports both My Drives and shared drives.
+    pub supports_all_drives: Option<bool>,
+    /// Whether the user has installed the requesting app.
+    pub supports_team_drives: Option<bool>,
+    /// Whether to transfer ownership to the specified user and downgrade the current owner to a writer. This parameter is required as an acknowledgement of the side effect. File owners can only transfer ownership of files existing on My Drive. Files existing in a shared drive are owned by the organization that owns that shared drive. Ownership transfers are not supported for files and folders in shared drives. Organizers of a shared drive can move items from that shared drive into their My Drive which transfers the ownership to them.
+    pub transfer_ownership: Option<bool>,
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if the file ID parameter refers to a shared drive and the requester is an administrator of the domain to which the shared drive belongs.
+    pub use_domain_admin_access: Option<bool>,
+}
+
+impl PermissionsUpdateParams {
+    pub fn remove_expiration(mut self, remove_expiration: bool) -> Self {
+        self.remove_expiration = Some(remove_expiration);
+        self
+    }
+
+    pub fn supports_all_drives(mut self, supports_all_drives: bool) -> Self {
+        self.supports_all_drives = Some(supports_all_drives);
+        self
+    }
+
+    pub fn supports_team_drives(mut self, supports_team_drives: bool) -> Self {
+        self.supports_team_drives = Some(supports_team_drives);
+        self
+    }
+
+    pub fn transfer_ownership(mut self, transfer_ownership: bool) -> Self {
+        self.transfer_ownership = Some(transfer_ownership);
+        self
+    }
+
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+}
+
+/// The functions of [`Permissions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockPermissionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait PermissionsApi: Send + Sync {
+    /// See [`Permissions::list_with`].
+    async fn list_with(
+        &self,
+        file_id: &str,
+        params: &PermissionsListParams,
+    ) -> Result<Vec<crate::types::Permission>>;
+
+    /// See [`Permissions::list_all_with`].
+    async fn list_all_with(
+        &self,
+        file_id: &str,
+        params: &PermissionsListParams,
+    ) -> Result<Vec<crate::types::Permission>>;
+
+    /// See [`Permissions::list_stream`].
+    fn list_stream(
+        &self,
+        file_id: &str,
+        params: &PermissionsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Permission>;
+
+    /// See [`Permissions::create_with`].
+    async fn create_with(
+        &self,
+        file_id: &str,
+        params: &PermissionsCreateParams,
+        body: &crate::types::Permission,
+    ) -> Result<crate::types::Permission>;
+
+    /// See [`Permissions::get_with`].
+    async fn get_with(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        params: &PermissionsGetParams,
+    ) -> Result<crate::types::Permission>;
+
+    /// See [`Permissions::delete_with`].
+    async fn delete_with(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        params: &PermissionsDeleteParams,
+    ) -> Result<()>;
+
+    /// See [`Permissions::update_with`].
+    async fn update_with(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        params: &PermissionsUpdateParams,
+        body: &crate::types::Permission,
+    ) -> Result<crate::types::Permission>;
+}
+
+#[async_trait::async_trait]
+impl PermissionsApi for Permissions {
+    async fn list_with(
+        &self,
+        file_id: &str,
+        params: &PermissionsListParams,
+    ) -> Result<Vec<crate::types::Permission>> {
+        Permissions::list_with(self, file_id, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        file_id: &str,
+        params: &PermissionsListParams,
+    ) -> Result<Vec<crate::types::Permission>> {
+        Permissions::list_all_with(self, file_id, params).await
+    }
+
+    fn list_stream(
+        &self,
+        file_id: &str,
+        params: &PermissionsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Permission> {
+        Permissions::list_stream(self, file_id, params, start, max_items)
+    }
+
+    async fn create_with(
+        &self,
+        file_id: &str,
+        params: &PermissionsCreateParams,
+        body: &crate::types::Permission,
+    ) -> Result<crate::types::Permission> {
+        Permissions::create_with(self, file_id, params, body).await
+    }
+
+    async fn get_with(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        params: &PermissionsGetParams,
+    ) -> Result<crate::types::Permission> {
+        Permissions::get_with(self, file_id, permission_id, params).await
+    }
+
+    async fn delete_with(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        params: &PermissionsDeleteParams,
+    ) -> Result<()> {
+        Permissions::delete_with(self, file_id, permission_id, params).await
+    }
+
+    async fn update_with(
+        &self,
+        file_id: &str,
+        permission_id: &str,
+        params: &PermissionsUpdateParams,
+        body: &crate::types::Permission,
+    ) -> Result<crate::types::Permission> {
+        Permissions::update_with(self, file_id, permission_id, params, body).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Replies {
     pub client: Client,
@@ -21,26 +19,22 @@ impl Replies {
      *
      * * `file_id: &str` -- A link to this theme's background image.
      * * `comment_id: &str` -- A link to this theme's background image.
-     * * `include_deleted: bool` -- Whether to include deleted replies. Deleted replies will not include their original content.
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+     * * `params: &RepliesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         file_id: &str,
         comment_id: &str,
-        include_deleted: bool,
-        page_size: i64,
-        page_token: &str,
+        params: &RepliesListParams,
     ) -> Result<Vec<crate::types::Reply>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_deleted {
+        if let Some(include_deleted) = &params.include_deleted {
             query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -57,21 +51,45 @@ impl Replies {
         Ok(resp.replies.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `RepliesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Reply>> {
+        let params = RepliesListParams {
+            include_deleted: Some(include_deleted).filter(|v| *v),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(file_id, comment_id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Lists a comment's replies.
+     *
+     * **Parameters:**
+     *
+     * * `params: &RepliesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(
+    pub async fn list_all_with(
         &self,
         file_id: &str,
         comment_id: &str,
-        include_deleted: bool,
+        params: &RepliesListParams,
     ) -> Result<Vec<crate::types::Reply>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_deleted {
+        if let Some(include_deleted) = &params.include_deleted {
             query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -89,6 +107,7 @@ impl Replies {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -114,6 +133,70 @@ impl Replies {
         Ok(replies)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `RepliesListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        include_deleted: bool,
+    ) -> Result<Vec<crate::types::Reply>> {
+        let params = RepliesListParams {
+            include_deleted: Some(include_deleted).filter(|v| *v),
+            ..Default::default()
+        };
+        self.list_all_with(file_id, comment_id, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists a comment's replies.
+     */
+    pub fn list_stream(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        params: &RepliesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reply> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(include_deleted) = &params.include_deleted {
+            query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/files/{}/comments/{}/replies?{}",
+            crate::progenitor_support::encode_path(file_id),
+            crate::progenitor_support::encode_path(comment_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::ReplyList = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.replies,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/files/{fileId}/comments/{commentId}/replies` endpoint.
      *
@@ -151,17 +234,17 @@ impl Replies {
      * * `file_id: &str` -- A link to this theme's background image.
      * * `comment_id: &str` -- A link to this theme's background image.
      * * `reply_id: &str` -- A link to this theme's background image.
-     * * `include_deleted: bool` -- Whether to return deleted replies. Deleted replies will not include their original content.
+     * * `params: &RepliesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         file_id: &str,
         comment_id: &str,
         reply_id: &str,
-        include_deleted: bool,
+        params: &RepliesGetParams,
     ) -> Result<crate::types::Reply> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_deleted {
+        if let Some(include_deleted) = &params.include_deleted {
             query_args.push(("includeDeleted".to_string(), include_deleted.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -176,6 +259,23 @@ impl Replies {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `RepliesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        include_deleted: bool,
+    ) -> Result<crate::types::Reply> {
+        let params = RepliesGetParams {
+            include_deleted: Some(include_deleted).filter(|v| *v),
+        };
+        self.get_with(file_id, comment_id, reply_id, &params).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/files/{fileId}/comments/{commentId}/replies/{replyId}` endpoint.
      *
@@ -228,3 +328,171 @@ impl Replies {
             .await
     }
 }
+
+/// The query parameters of `Replies::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RepliesListParams {
+    /// Whether to include deleted replies. Deleted replies will not include their original content.
+    pub include_deleted: Option<bool>,
+    /// A map of maximum import sizes by MIME type, in bytes.
+    pub page_size: Option<i64>,
+    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+    pub page_token: Option<String>,
+}
+
+impl RepliesListParams {
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.include_deleted = Some(include_deleted);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Replies::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RepliesGetParams {
+    /// Whether to return deleted replies. Deleted replies will not include their original content.
+    pub include_deleted: Option<bool>,
+}
+
+impl RepliesGetParams {
+    pub fn include_deleted(mut self, include_deleted: bool) -> Self {
+        self.include_deleted = Some(include_deleted);
+        self
+    }
+}
+
+/// The functions of [`Replies`], for code that takes them as a trait, so that it can be
+/// tested with the `MockRepliesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait RepliesApi: Send + Sync {
+    /// See [`Replies::list_with`].
+    async fn list_with(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        params: &RepliesListParams,
+    ) -> Result<Vec<crate::types::Reply>>;
+
+    /// See [`Replies::list_all_with`].
+    async fn list_all_with(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        params: &RepliesListParams,
+    ) -> Result<Vec<crate::types::Reply>>;
+
+    /// See [`Replies::list_stream`].
+    fn list_stream(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        params: &RepliesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reply>;
+
+    /// See [`Replies::create`].
+    async fn create(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        body: &crate::types::Reply,
+    ) -> Result<crate::types::Reply>;
+
+    /// See [`Replies::get_with`].
+    async fn get_with(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        params: &RepliesGetParams,
+    ) -> Result<crate::types::Reply>;
+
+    /// See [`Replies::delete`].
+    async fn delete(&self, file_id: &str, comment_id: &str, reply_id: &str) -> Result<()>;
+
+    /// See [`Replies::update`].
+    async fn update(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        body: &crate::types::Reply,
+    ) -> Result<crate::types::Reply>;
+}
+
+#[async_trait::async_trait]
+impl RepliesApi for Replies {
+    async fn list_with(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        params: &RepliesListParams,
+    ) -> Result<Vec<crate::types::Reply>> {
+        Replies::list_with(self, file_id, comment_id, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        params: &RepliesListParams,
+    ) -> Result<Vec<crate::types::Reply>> {
+        Replies::list_all_with(self, file_id, comment_id, params).await
+    }
+
+    fn list_stream(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        params: &RepliesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reply> {
+        Replies::list_stream(self, file_id, comment_id, params, start, max_items)
+    }
+
+    async fn create(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        body: &crate::types::Reply,
+    ) -> Result<crate::types::Reply> {
+        Replies::create(self, file_id, comment_id, body).await
+    }
+
+    async fn get_with(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        params: &RepliesGetParams,
+    ) -> Result<crate::types::Reply> {
+        Replies::get_with(self, file_id, comment_id, reply_id, params).await
+    }
+
+    async fn delete(&self, file_id: &str, comment_id: &str, reply_id: &str) -> Result<()> {
+        Replies::delete(self, file_id, comment_id, reply_id).await
+    }
+
+    async fn update(
+        &self,
+        file_id: &str,
+        comment_id: &str,
+        reply_id: &str,
+        body: &crate::types::Reply,
+    ) -> Result<crate::types::Reply> {
+        Replies::update(self, file_id, comment_id, reply_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Revisions {
     pub client: Client,
@@ -20,20 +18,18 @@ impl Revisions {
      * **Parameters:**
      *
      * * `file_id: &str` -- A link to this theme's background image.
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `page_token: &str` -- The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+     * * `params: &RevisionsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
         file_id: &str,
-        page_size: i64,
-        page_token: &str,
+        params: &RevisionsListParams,
     ) -> Result<Vec<crate::types::Revision>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -49,6 +45,23 @@ impl Revisions {
         Ok(resp.revisions.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `RevisionsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        file_id: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<Vec<crate::types::Revision>> {
+        let params = RevisionsListParams {
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(file_id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/revisions` endpoint.
      *
@@ -69,6 +82,7 @@ impl Revisions {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -94,6 +108,43 @@ impl Revisions {
         Ok(revisions)
     }
 
+    /**
+     * This function performs a `GET` to the `/files/{fileId}/revisions` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists a file's revisions.
+     */
+    pub fn list_stream(
+        &self,
+        file_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Revision> {
+        let url = format!(
+            "/files/{}/revisions",
+            crate::progenitor_support::encode_path(file_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::RevisionList = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.revisions,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `GET` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
      *
@@ -103,16 +154,16 @@ impl Revisions {
      *
      * * `file_id: &str` -- A link to this theme's background image.
      * * `revision_id: &str` -- A link to this theme's background image.
-     * * `acknowledge_abuse: bool` -- Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
+     * * `params: &RevisionsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         file_id: &str,
         revision_id: &str,
-        acknowledge_abuse: bool,
+        params: &RevisionsGetParams,
     ) -> Result<crate::types::Revision> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if acknowledge_abuse {
+        if let Some(acknowledge_abuse) = &params.acknowledge_abuse {
             query_args.push((
                 "acknowledgeAbuse".to_string(),
                 acknowledge_abuse.to_string(),
@@ -129,6 +180,22 @@ impl Revisions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `RevisionsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        acknowledge_abuse: bool,
+    ) -> Result<crate::types::Revision> {
+        let params = RevisionsGetParams {
+            acknowledge_abuse: Some(acknowledge_abuse).filter(|v| *v),
+        };
+        self.get_with(file_id, revision_id, &params).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/files/{fileId}/revisions/{revisionId}` endpoint.
      *
@@ -176,3 +243,127 @@ impl Revisions {
             .await
     }
 }
+
+/// The query parameters of `Revisions::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RevisionsListParams {
+    /// A map of maximum import sizes by MIME type, in bytes.
+    pub page_size: Option<i64>,
+    /// The token for continuing a previous list request on the next page. This should be set to the value of 'nextPageToken' from the previous response.
+    pub page_token: Option<String>,
+}
+
+impl RevisionsListParams {
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Revisions::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RevisionsGetParams {
+    /// Whether the user is acknowledging the risk of downloading known malware or other abusive files. This is only applicable when alt=media.
+    pub acknowledge_abuse: Option<bool>,
+}
+
+impl RevisionsGetParams {
+    pub fn acknowledge_abuse(mut self, acknowledge_abuse: bool) -> Self {
+        self.acknowledge_abuse = Some(acknowledge_abuse);
+        self
+    }
+}
+
+/// The functions of [`Revisions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockRevisionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait RevisionsApi: Send + Sync {
+    /// See [`Revisions::list_with`].
+    async fn list_with(
+        &self,
+        file_id: &str,
+        params: &RevisionsListParams,
+    ) -> Result<Vec<crate::types::Revision>>;
+
+    /// See [`Revisions::list_all`].
+    async fn list_all(&self, file_id: &str) -> Result<Vec<crate::types::Revision>>;
+
+    /// See [`Revisions::list_stream`].
+    fn list_stream(
+        &self,
+        file_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Revision>;
+
+    /// See [`Revisions::get_with`].
+    async fn get_with(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        params: &RevisionsGetParams,
+    ) -> Result<crate::types::Revision>;
+
+    /// See [`Revisions::delete`].
+    async fn delete(&self, file_id: &str, revision_id: &str) -> Result<()>;
+
+    /// See [`Revisions::update`].
+    async fn update(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        body: &crate::types::Revision,
+    ) -> Result<crate::types::Revision>;
+}
+
+#[async_trait::async_trait]
+impl RevisionsApi for Revisions {
+    async fn list_with(
+        &self,
+        file_id: &str,
+        params: &RevisionsListParams,
+    ) -> Result<Vec<crate::types::Revision>> {
+        Revisions::list_with(self, file_id, params).await
+    }
+
+    async fn list_all(&self, file_id: &str) -> Result<Vec<crate::types::Revision>> {
+        Revisions::list_all(self, file_id).await
+    }
+
+    fn list_stream(
+        &self,
+        file_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Revision> {
+        Revisions::list_stream(self, file_id, start, max_items)
+    }
+
+    async fn get_with(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        params: &RevisionsGetParams,
+    ) -> Result<crate::types::Revision> {
+        Revisions::get_with(self, file_id, revision_id, params).await
+    }
+
+    async fn delete(&self, file_id: &str, revision_id: &str) -> Result<()> {
+        Revisions::delete(self, file_id, revision_id).await
+    }
+
+    async fn update(
+        &self,
+        file_id: &str,
+        revision_id: &str,
+        body: &crate::types::Revision,
+    ) -> Result<crate::types::Revision> {
+        Revisions::update(self, file_id, revision_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Teamdrives {
     pub client: Client,
@@ -19,29 +17,23 @@ impl Teamdrives {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- A map of maximum import sizes by MIME type, in bytes.
-     * * `page_token: &str` -- A link to this theme's background image.
-     * * `q: &str` -- A link to this theme's background image.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then all Team Drives of the domain in which the requester is an administrator are returned.
+     * * `params: &TeamdrivesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        page_size: i64,
-        page_token: &str,
-        q: &str,
-        use_domain_admin_access: bool,
+        params: &TeamdrivesListParams,
     ) -> Result<Vec<crate::types::TeamDrive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("pageSize".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("pageToken".to_string(), page_token.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -56,23 +48,46 @@ impl Teamdrives {
         Ok(resp.team_drives.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `TeamdrivesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::TeamDrive>> {
+        let params = TeamdrivesListParams {
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/teamdrives` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Deprecated use drives.list instead.
+     *
+     * **Parameters:**
+     *
+     * * `params: &TeamdrivesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(
+    pub async fn list_all_with(
         &self,
-        q: &str,
-        use_domain_admin_access: bool,
+        params: &TeamdrivesListParams,
     ) -> Result<Vec<crate::types::TeamDrive>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -88,6 +103,7 @@ impl Teamdrives {
 
         // Paginate if we should.
         while !page.is_empty() {
+            // Check if we already have URL params and need to concat the token.
             if !url.contains('?') {
                 resp = self
                     .client
@@ -113,6 +129,69 @@ impl Teamdrives {
         Ok(team_drives)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `TeamdrivesListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        q: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<Vec<crate::types::TeamDrive>> {
+        let params = TeamdrivesListParams {
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/teamdrives` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Deprecated use drives.list instead.
+     */
+    pub fn list_stream(
+        &self,
+        params: &TeamdrivesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDrive> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
+            query_args.push((
+                "useDomainAdminAccess".to_string(),
+                use_domain_admin_access.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/teamdrives?{}", query_);
+
+        let start = start.or_else(|| params.page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "pageToken", &page),
+                    None => url,
+                };
+                let resp: crate::types::TeamDriveList = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.team_drives,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/teamdrives` endpoint.
      *
@@ -120,15 +199,15 @@ impl Teamdrives {
      *
      * **Parameters:**
      *
-     * * `request_id: &str` -- An ID, such as a random UUID, which uniquely identifies this user's request for idempotent creation of a Team Drive. A repeated request by the same user and with the same request ID will avoid creating duplicates by attempting to create the same Team Drive. If the Team Drive already exists a 409 error will be returned.
+     * * `params: &TeamdrivesCreateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create(
+    pub async fn create_with(
         &self,
-        request_id: &str,
+        params: &TeamdrivesCreateParams,
         body: &crate::types::TeamDrive,
     ) -> Result<crate::types::TeamDrive> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !request_id.is_empty() {
+        if let Some(request_id) = &params.request_id {
             query_args.push(("requestId".to_string(), request_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -139,6 +218,21 @@ impl Teamdrives {
             .await
     }
 
+    /**
+     * Deprecated: use `create_with` and pass the query parameters as a `TeamdrivesCreateParams` instead.
+     */
+    #[deprecated(note = "use `create_with` instead")]
+    pub async fn create(
+        &self,
+        request_id: &str,
+        body: &crate::types::TeamDrive,
+    ) -> Result<crate::types::TeamDrive> {
+        let params = TeamdrivesCreateParams {
+            request_id: Some(request_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_with(&params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/teamdrives/{teamDriveId}` endpoint.
      *
@@ -147,15 +241,15 @@ impl Teamdrives {
      * **Parameters:**
      *
      * * `team_drive_id: &str` -- A link to this theme's background image.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the Team Drive belongs.
+     * * `params: &TeamdrivesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         team_drive_id: &str,
-        use_domain_admin_access: bool,
+        params: &TeamdrivesGetParams,
     ) -> Result<crate::types::TeamDrive> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -171,6 +265,21 @@ impl Teamdrives {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `TeamdrivesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        team_drive_id: &str,
+        use_domain_admin_access: bool,
+    ) -> Result<crate::types::TeamDrive> {
+        let params = TeamdrivesGetParams {
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+        };
+        self.get_with(team_drive_id, &params).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/teamdrives/{teamDriveId}` endpoint.
      *
@@ -197,16 +306,16 @@ impl Teamdrives {
      * **Parameters:**
      *
      * * `team_drive_id: &str` -- A link to this theme's background image.
-     * * `use_domain_admin_access: bool` -- Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the Team Drive belongs.
+     * * `params: &TeamdrivesUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update(
+    pub async fn update_with(
         &self,
         team_drive_id: &str,
-        use_domain_admin_access: bool,
+        params: &TeamdrivesUpdateParams,
         body: &crate::types::TeamDrive,
     ) -> Result<crate::types::TeamDrive> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if use_domain_admin_access {
+        if let Some(use_domain_admin_access) = &params.use_domain_admin_access {
             query_args.push((
                 "useDomainAdminAccess".to_string(),
                 use_domain_admin_access.to_string(),
@@ -223,4 +332,203 @@ impl Teamdrives {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `update_with` and pass the query parameters as a `TeamdrivesUpdateParams` instead.
+     */
+    #[deprecated(note = "use `update_with` instead")]
+    pub async fn update(
+        &self,
+        team_drive_id: &str,
+        use_domain_admin_access: bool,
+        body: &crate::types::TeamDrive,
+    ) -> Result<crate::types::TeamDrive> {
+        let params = TeamdrivesUpdateParams {
+            use_domain_admin_access: Some(use_domain_admin_access).filter(|v| *v),
+        };
+        self.update_with(team_drive_id, &params, body).await
+    }
+}
+
+/// The query parameters of `Teamdrives::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamdrivesListParams {
+    /// A map of maximum import sizes by MIME type, in bytes.
+    pub page_size: Option<i64>,
+    /// A link to this theme's background image.
+    pub page_token: Option<String>,
+    /// A link to this theme's background image.
+    pub q: Option<String>,
+    /// Issue the request as a domain administrator; if set to true, then all Team Drives of the domain in which the requester is an administrator are returned.
+    pub use_domain_admin_access: Option<bool>,
+}
+
+impl TeamdrivesListParams {
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+}
+
+/// The query parameters of `Teamdrives::create_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamdrivesCreateParams {
+    /// An ID, such as a random UUID, which uniquely identifies this user's request for idempotent creation of a Team Drive. A repeated request by the same user and with the same request ID will avoid creating duplicates by attempting to create the same Team Drive. If the Team Drive already exists a 409 error will be returned.
+    pub request_id: Option<String>,
+}
+
+impl TeamdrivesCreateParams {
+    pub fn request_id<T: ToString>(mut self, request_id: T) -> Self {
+        self.request_id = Some(request_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Teamdrives::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamdrivesGetParams {
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the Team Drive belongs.
+    pub use_domain_admin_access: Option<bool>,
+}
+
+impl TeamdrivesGetParams {
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+}
+
+/// The query parameters of `Teamdrives::update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamdrivesUpdateParams {
+    /// Issue the request as a domain administrator; if set to true, then the requester will be granted access if they are an administrator of the domain to which the Team Drive belongs.
+    pub use_domain_admin_access: Option<bool>,
+}
+
+impl TeamdrivesUpdateParams {
+    pub fn use_domain_admin_access(mut self, use_domain_admin_access: bool) -> Self {
+        self.use_domain_admin_access = Some(use_domain_admin_access);
+        self
+    }
+}
+
+/// The functions of [`Teamdrives`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTeamdrivesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TeamdrivesApi: Send + Sync {
+    /// See [`Teamdrives::list_with`].
+    async fn list_with(
+        &self,
+        params: &TeamdrivesListParams,
+    ) -> Result<Vec<crate::types::TeamDrive>>;
+
+    /// See [`Teamdrives::list_all_with`].
+    async fn list_all_with(
+        &self,
+        params: &TeamdrivesListParams,
+    ) -> Result<Vec<crate::types::TeamDrive>>;
+
+    /// See [`Teamdrives::list_stream`].
+    fn list_stream(
+        &self,
+        params: &TeamdrivesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDrive>;
+
+    /// See [`Teamdrives::create_with`].
+    async fn create_with(
+        &self,
+        params: &TeamdrivesCreateParams,
+        body: &crate::types::TeamDrive,
+    ) -> Result<crate::types::TeamDrive>;
+
+    /// See [`Teamdrives::get_with`].
+    async fn get_with(
+        &self,
+        team_drive_id: &str,
+        params: &TeamdrivesGetParams,
+    ) -> Result<crate::types::TeamDrive>;
+
+    /// See [`Teamdrives::delete`].
+    async fn delete(&self, team_drive_id: &str) -> Result<()>;
+
+    /// See [`Teamdrives::update_with`].
+    async fn update_with(
+        &self,
+        team_drive_id: &str,
+        params: &TeamdrivesUpdateParams,
+        body: &crate::types::TeamDrive,
+    ) -> Result<crate::types::TeamDrive>;
+}
+
+#[async_trait::async_trait]
+impl TeamdrivesApi for Teamdrives {
+    async fn list_with(
+        &self,
+        params: &TeamdrivesListParams,
+    ) -> Result<Vec<crate::types::TeamDrive>> {
+        Teamdrives::list_with(self, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        params: &TeamdrivesListParams,
+    ) -> Result<Vec<crate::types::TeamDrive>> {
+        Teamdrives::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &TeamdrivesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TeamDrive> {
+        Teamdrives::list_stream(self, params, start, max_items)
+    }
+
+    async fn create_with(
+        &self,
+        params: &TeamdrivesCreateParams,
+        body: &crate::types::TeamDrive,
+    ) -> Result<crate::types::TeamDrive> {
+        Teamdrives::create_with(self, params, body).await
+    }
+
+    async fn get_with(
+        &self,
+        team_drive_id: &str,
+        params: &TeamdrivesGetParams,
+    ) -> Result<crate::types::TeamDrive> {
+        Teamdrives::get_with(self, team_drive_id, params).await
+    }
+
+    async fn delete(&self, team_drive_id: &str) -> Result<()> {
+        Teamdrives::delete(self, team_drive_id).await
+    }
+
+    async fn update_with(
+        &self,
+        team_drive_id: &str,
+        params: &TeamdrivesUpdateParams,
+        body: &crate::types::TeamDrive,
+    ) -> Result<crate::types::TeamDrive> {
+        Teamdrives::update_with(self, team_drive_id, params, body).await
+    }
 }
@@ -1 +1,51 @@
+use futures::StreamExt;
 
+use crate::files::FilesApi;
+
+fn file(name: &str) -> crate::types::File {
+    crate::types::File {
+        name: Some(name.to_string()),
+        ..Default::default()
+    }
+}
+
+// Code that takes the functions of a tag as a trait, so its tests need no server.
+async fn file_names<F: FilesApi>(files: &F, q: &str) -> Vec<String> {
+    let params = crate::files::FilesListParams::default().q(q);
+    files
+        .list_stream(&params, None, None)
+        .map(|f| f.unwrap().name.unwrap_or_default())
+        .collect()
+        .await
+}
+
+#[tokio::test]
+async fn test_mock_files_api() {
+    let mut files = crate::files::MockFilesApi::new();
+    files
+        .expect_list_stream()
+        .withf(|params, start, max_items| {
+            params.q.as_deref() == Some("trashed = false") && start.is_none() && max_items.is_none()
+        })
+        .returning(|_, _, _| {
+            crate::pagination::Pages::new(None, None, |_| async {
+                Ok(crate::pagination::Page::new(
+                    vec![file("a.txt"), file("b.txt")],
+                    None,
+                ))
+            })
+        });
+    files
+        .expect_get_with()
+        .withf(|file_id, params| file_id == "a" && params.supports_all_drives == Some(true))
+        .returning(|_, _| Ok(file("a.txt")));
+
+    assert_eq!(
+        file_names(&files, "trashed = false").await,
+        vec!["a.txt".to_string(), "b.txt".to_string()]
+    );
+
+    let params = crate::files::FilesGetParams::default().supports_all_drives(true);
+    let f = files.get_with("a", &params).await.unwrap();
+    assert_eq!(f.name.as_deref(), Some("a.txt"));
+}
@@ -62,18 +62,23 @@ impl PermissionOps for crate::permissions::Permissions {
         // First let's check if the permission already exists.
         // List all the permissions for a file.
         let perms = self
-            .list_all(
+            .list_all_with(
                 file_id,
-                "",   // include_permissions_for_view
-                true, // supports_all_drives
-                true, // supports_team_drives
-                use_domain_admin_access,
+                &crate::permissions::PermissionsListParams {
+                    supports_all_drives: Some(true),
+                    supports_team_drives: Some(true),
+                    use_domain_admin_access: Some(use_domain_admin_access),
+                    ..Default::default()
+                },
             )
             .await?;
 
         // Iterate over our permissions and see if we have ours.
         for perm in perms {
-            if perm.email_address == email_address && perm.role == role && perm.type_ == type_ {
+            if perm.email_address.as_deref() == Some(email_address)
+                && perm.role.as_deref() == Some(role)
+                && perm.type_.as_deref() == Some(type_)
+            {
                 // We found the permission, return it.
                 return Ok(perm);
             }
@@ -83,33 +88,35 @@ impl PermissionOps for crate::permissions::Permissions {
         let perm = crate::types::Permission {
             allow_file_discovery: None,
             deleted: None,
-            display_name: String::new(),
-            domain: String::new(),
-            email_address: email_address.to_string(),
+            display_name: None,
+            domain: None,
+            email_address: Some(email_address.to_string()),
             expiration_time: None,
-            id: String::new(),
-            kind: String::new(),
+            id: None,
+            kind: None,
             permission_details: Default::default(),
-            photo_link: String::new(),
-            role: role.to_string(),
+            photo_link: None,
+            role: Some(role.to_string()),
             team_drive_permission_details: Default::default(),
-            type_: type_.to_string(),
-            view: String::new(),
+            type_: Some(type_.to_string()),
+            view: None,
         };
 
         // Create the permission and return it.
-        self.create(
-            file_id,
-            email_message,
-            false, // move_to_new_owners_root
-            send_notification_email,
-            true,  // supports_all_drives
-            true,  // supports_team_drives
-            false, // transfer_ownership
-            use_domain_admin_access,
-            &perm,
-        )
-        .await
+        Ok(self
+            .create_with(
+                file_id,
+                &crate::permissions::PermissionsCreateParams {
+                    email_message: Some(email_message.to_string()).filter(|s| !s.is_empty()),
+                    send_notification_email: Some(send_notification_email),
+                    supports_all_drives: Some(true),
+                    supports_team_drives: Some(true),
+                    use_domain_admin_access: Some(use_domain_admin_access),
+                    ..Default::default()
+                },
+                &perm,
+            )
+            .await?)
     }
 }
 
@@ -162,20 +169,16 @@ impl FileOps for crate::files::Files {
             query = format!("{} and '{}' in parents", query, parent_id);
         }
 
-        self.list_all(
-            "drive",  // corpora
-            drive_id, // drive id
-            true,     // include_items_from_all_drives
-            "",       // include_permissions_for_view
-            false,    // include_team_drive_items
-            "",       // order_by
-            &query,   // query
-            "",       // spaces
-            true,     // supports_all_drives
-            false,    // supports_team_drives
-            "",       // team_drive_id
-        )
-        .await
+        Ok(self
+            .list_all_with(&crate::files::FilesListParams {
+                corpora: Some("drive".to_string()),
+                drive_id: Some(drive_id.to_string()).filter(|s| !s.is_empty()),
+                include_items_from_all_drives: Some(true),
+                q: Some(query),
+                supports_all_drives: Some(true),
+                ..Default::default()
+            })
+            .await?)
     }
 
     /// Create or update a file in a drive.
@@ -201,8 +204,8 @@ impl FileOps for crate::files::Files {
             .unwrap_or_default();
         if files.is_empty() {
             // Set the name,
-            f.name = name.to_string();
-            f.mime_type = mime_type.to_string();
+            f.name = Some(name.to_string());
+            f.mime_type = Some(mime_type.to_string());
             if !parent_id.is_empty() {
                 f.parents = vec![parent_id.to_string()];
             } else {
@@ -217,17 +220,17 @@ impl FileOps for crate::files::Files {
             let mut f = f.clone();
             uri += &format!(
                 "/{}?uploadType=resumable&supportsAllDrives=true&includeItemsFromAllDrives=true",
-                f.id
+                f.id.as_deref().unwrap_or_default()
             );
 
-            f.id = "".to_string();
-            f.drive_id = "".to_string();
-            f.kind = "".to_string();
-            f.original_filename = f.name.to_string();
+            f.id = None;
+            f.drive_id = None;
+            f.kind = None;
+            f.original_filename = f.name.clone();
         } else {
             // Set the name,
-            f.name = name.to_string();
-            f.mime_type = mime_type.to_string();
+            f.name = Some(name.to_string());
+            f.mime_type = Some(mime_type.to_string());
             if !parent_id.is_empty() {
                 f.parents = vec![parent_id.to_string()];
             } else {
@@ -256,9 +259,10 @@ impl FileOps for crate::files::Files {
         };
 
         // Now upload the file to that location.
-        self.client
+        Ok(self
+            .client
             .request_with_mime(reqwest::Method::PUT, location, contents, mime_type)
-            .await
+            .await?)
     }
 
     /// Download a file by it's ID.
@@ -280,8 +284,8 @@ impl FileOps for crate::files::Files {
         let folder_mime_type = "application/vnd.google-apps.folder";
         let mut file: crate::types::File = Default::default();
         // Set the name,
-        file.name = name.to_string();
-        file.mime_type = folder_mime_type.to_string();
+        file.name = Some(name.to_string());
+        file.mime_type = Some(folder_mime_type.to_string());
         if !parent_id.is_empty() {
             file.parents = vec![parent_id.to_string()];
         } else {
@@ -298,24 +302,19 @@ impl FileOps for crate::files::Files {
 
         // Check if the folder exists.
         let folders = self
-            .list_all(
-                "drive",  // corpora
-                drive_id, // drive id
-                true,     // include_items_from_all_drives
-                "",       // include_permissions_for_view
-                false,    // include_team_drive_items
-                "",       // order_by
-                &query,   // query
-                "",       // spaces
-                true,     // supports_all_drives
-                false,    // supports_team_drives
-                "",       // team_drive_id
-            )
+            .list_all_with(&crate::files::FilesListParams {
+                corpora: Some("drive".to_string()),
+                drive_id: Some(drive_id.to_string()).filter(|s| !s.is_empty()),
+                include_items_from_all_drives: Some(true),
+                q: Some(query),
+                supports_all_drives: Some(true),
+                ..Default::default()
+            })
             .await
             .unwrap_or_default();
         if !folders.is_empty() {
             let f = folders.get(0).unwrap().clone();
-            return Ok(f.id);
+            return Ok(f.id.unwrap_or_default());
         }
 
         // Make the request and return the ID.
@@ -327,7 +326,7 @@ impl FileOps for crate::files::Files {
             )
             .await?;
 
-        Ok(folder.id)
+        Ok(folder.id.unwrap_or_default())
     }
 
     /// Get a file's contents by it's ID. Only works for Google Docs.
@@ -367,12 +366,15 @@ impl FileOps for crate::files::Files {
         }
 
         // Delete the file.
-        self.delete(
-            &files.get(0).unwrap().id,
-            true, // supports all drives
-            true, // supports team drives
-        )
-        .await
+        Ok(self
+            .delete_with(
+                files[0].id.as_deref().unwrap_or_default(),
+                &crate::files::FilesDeleteParams {
+                    supports_all_drives: Some(true),
+                    supports_team_drives: Some(true),
+                },
+            )
+            .await?)
     }
 }
 
@@ -387,14 +389,14 @@ impl DriveOps for crate::drives::Drives {
     /// Get a drive by it's name.
     async fn get_by_name(&self, name: &str) -> Result<crate::types::Drive> {
         let drives = self
-            .list_all(
-                //&format!("name = '{}'", name), // query
-                "", true, // use domain admin access
-            )
+            .list_all_with(&crate::drives::DrivesListParams {
+                use_domain_admin_access: Some(true),
+                ..Default::default()
+            })
             .await?;
 
         for drive in drives {
-            if drive.name == name {
+            if drive.name.as_deref() == Some(name) {
                 return Ok(drive);
             }
         }
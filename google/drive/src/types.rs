@@ -2,6 +2,149 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> std::option::Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> std::convert::From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match std::option::Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        std::option::Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        std::option::Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
+
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+
+#[cfg(feature = "about")]
+mod about;
+#[cfg(feature = "about")]
+pub use about::*;
+
+#[cfg(feature = "changes")]
+mod changes;
+#[cfg(feature = "changes")]
+pub use changes::*;
+
+#[cfg(feature = "comments")]
+mod comments;
+#[cfg(feature = "comments")]
+pub use comments::*;
+
+#[cfg(feature = "drives")]
+mod drives;
+#[cfg(feature = "drives")]
+pub use drives::*;
+
+#[cfg(feature = "files")]
+mod files;
+#[cfg(feature = "files")]
+pub use files::*;
+
+#[cfg(feature = "permissions")]
+mod permissions;
+#[cfg(feature = "permissions")]
+pub use permissions::*;
+
+#[cfg(feature = "replies")]
+mod replies;
+#[cfg(feature = "replies")]
+pub use replies::*;
+
+#[cfg(feature = "revisions")]
+mod revisions;
+#[cfg(feature = "revisions")]
+pub use revisions::*;
+
+#[cfg(feature = "teamdrives")]
+mod teamdrives;
+#[cfg(feature = "teamdrives")]
+pub use teamdrives::*;
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DriveThemes {
     /**
@@ -78,129 +221,37 @@ pub struct StorageQuota {
     pub usage_in_drive_trash: i64,
 }
 
-/// Information about the user, the user's Drive, and system capabilities.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct About {
-    /**
-     * Information about the user, the user's Drive, and system capabilities.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "appInstalled"
-    )]
-    pub app_installed: Option<bool>,
-    /**
-     * Information about the user, the user's Drive, and system capabilities.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canCreateDrives"
-    )]
-    pub can_create_drives: Option<bool>,
-    /**
-     * Information about the user, the user's Drive, and system capabilities.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "canCreateTeamDrives"
-    )]
-    pub can_create_team_drives: Option<bool>,
+pub struct TeamDriveThemes {
     /**
-     * Information about the user, the user's Drive, and system capabilities.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "driveThemes"
-    )]
-    pub drive_themes: Vec<DriveThemes>,
-    /**
-     * Information about the user, the user's Drive, and system capabilities.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "exportFormats"
-    )]
-    pub export_formats: Vec<String>,
-    /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * A link to this theme's background image.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "folderColorPalette"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "backgroundImageLink"
     )]
-    pub folder_color_palette: Vec<String>,
+    pub background_image_link: String,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * A link to this theme's background image.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "importFormats"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "colorRgb"
     )]
-    pub import_formats: Vec<String>,
+    pub color_rgb: String,
     /**
-     * Information about the user, the user's Drive, and system capabilities.
+     * A link to this theme's background image.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub kind: String,
-    /**
-     * Information about the user, the user's Drive, and system capabilities.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxImportSizes"
-    )]
-    pub max_import_sizes: i64,
-    /**
-     * Information about the user, the user's Drive, and system capabilities.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxUploadSize"
-    )]
-    pub max_upload_size: i64,
-    /**
-     * Information about the user, the user's Drive, and system capabilities.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "storageQuota"
-    )]
-    pub storage_quota: Option<StorageQuota>,
-    /**
-     * Information about the user, the user's Drive, and system capabilities.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "teamDriveThemes"
-    )]
-    pub team_drive_themes: Vec<DriveThemes>,
-    /**
-     * Information about the user, the user's Drive, and system capabilities.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub user: Option<User>,
+    pub id: String,
 }
 
 /// A change to a file or shared drive.
@@ -296,165 +347,19 @@ pub struct Change {
     pub type_: String,
 }
 
-/// A list of changes for a user.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChangeList {
-    /**
-     * A list of changes for a user.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub changes: Vec<Change>,
-    /**
-     * A list of changes for a user.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * A list of changes for a user.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "newStartPageToken"
-    )]
-    pub new_start_page_token: String,
-    /**
-     * A list of changes for a user.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-}
-
-/// An notification channel used to watch for resource changes.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Channel {
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub expiration: i64,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub params: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub payload: Option<bool>,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceId"
-    )]
-    pub resource_id: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resourceUri"
-    )]
-    pub resource_uri: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub token: String,
-    /**
-     * An notification channel used to watch for resource changes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
 /// The file content to which the comment refers, typically within the anchor region. For a text file, for example, this would be the text at the location of the comment.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct QuotedFileContent {
     /**
      * The file content to which the comment refers, typically within the anchor region. For a text file, for example, this would be the text at the location of the comment.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mimeType"
-    )]
-    pub mime_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "mimeType")]
+    pub mime_type: Option<String>,
     /**
      * The file content to which the comment refers, typically within the anchor region. For a text file, for example, this would be the text at the location of the comment.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
 }
 
 /// A comment on a file.
@@ -463,12 +368,8 @@ pub struct Comment {
     /**
      * A comment on a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub anchor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
     /**
      * A comment on a file.
      */
@@ -477,12 +378,8 @@ pub struct Comment {
     /**
      * A comment on a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
     /**
      * A comment on a file.
      */
@@ -503,29 +400,20 @@ pub struct Comment {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "htmlContent"
     )]
-    pub html_content: String,
+    pub html_content: Option<String>,
     /**
      * A comment on a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * A comment on a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * A comment on a file.
      */
@@ -561,39 +449,6 @@ pub struct Comment {
     pub resolved: Option<bool>,
 }
 
-/// A list of comments on a file.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CommentList {
-    /**
-     * A list of comments on a file.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub comments: Vec<Comment>,
-    /**
-     * A list of comments on a file.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * A list of comments on a file.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-}
-
 /// A restriction for accessing the content of the file.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ContentRestriction {
@@ -605,12 +460,8 @@ pub struct ContentRestriction {
     /**
      * A restriction for accessing the content of the file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
     /**
      * A restriction for accessing the content of the file.
      */
@@ -633,13 +484,8 @@ pub struct ContentRestriction {
     /**
      * A restriction for accessing the content of the file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
 }
 
 /// An image file and cropping parameters from which a background image for this shared drive is set. This is a write only field; it can only be set on drive.drives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
@@ -648,12 +494,8 @@ pub struct BackgroundImageFile {
     /**
      * An image file and cropping parameters from which a background image for this shared drive is set. This is a write only field; it can only be set on drive.drives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * An image file and cropping parameters from which a background image for this shared drive is set. This is a write only field; it can only be set on drive.drives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
      */
@@ -894,11 +736,10 @@ pub struct Drive {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "backgroundImageLink"
     )]
-    pub background_image_link: String,
+    pub background_image_link: Option<String>,
     /**
      * Representation of a shared drive.
      */
@@ -907,13 +748,8 @@ pub struct Drive {
     /**
      * Representation of a shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "colorRgb"
-    )]
-    pub color_rgb: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "colorRgb")]
+    pub color_rgb: Option<String>,
     /**
      * Representation of a shared drive.
      */
@@ -932,30 +768,18 @@ pub struct Drive {
     /**
      * Representation of a shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * Representation of a shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * Representation of a shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /**
      * Representation of a shared drive.
      */
@@ -964,46 +788,8 @@ pub struct Drive {
     /**
      * Representation of a shared drive.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "themeId"
-    )]
-    pub theme_id: String,
-}
-
-/// A list of shared drives.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DriveList {
-    /**
-     * A list of shared drives.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub drives: Vec<Drive>,
-    /**
-     * A list of shared drives.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * A list of shared drives.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "themeId")]
+    pub theme_id: Option<String>,
 }
 
 /// Capabilities the current user has on this file. Each capability corresponds to a fine-grained action that a user may take.
@@ -1313,13 +1099,8 @@ pub struct Thumbnail {
     /**
      * A thumbnail for the file. This will only be used if Google Drive cannot generate a standard thumbnail.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mimeType"
-    )]
-    pub mime_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "mimeType")]
+    pub mime_type: Option<String>,
 }
 
 /// Additional information about the content of the file. These fields are never populated in responses.
@@ -1330,11 +1111,10 @@ pub struct ContentHints {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "indexableText"
     )]
-    pub indexable_text: String,
+    pub indexable_text: Option<String>,
     /**
      * Additional information about the content of the file. These fields are never populated in responses.
      */
@@ -1391,31 +1171,28 @@ pub struct ImageMediaMetadata {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "cameraMake"
     )]
-    pub camera_make: String,
+    pub camera_make: Option<String>,
     /**
      * Additional metadata about image media, if available.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "cameraModel"
     )]
-    pub camera_model: String,
+    pub camera_model: Option<String>,
     /**
      * Additional metadata about image media, if available.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "colorSpace"
     )]
-    pub color_space: String,
+    pub color_space: Option<String>,
     /**
      * Additional metadata about image media, if available.
      */
@@ -1431,11 +1208,10 @@ pub struct ImageMediaMetadata {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "exposureMode"
     )]
-    pub exposure_mode: String,
+    pub exposure_mode: Option<String>,
     /**
      * Additional metadata about image media, if available.
      */
@@ -1483,12 +1259,8 @@ pub struct ImageMediaMetadata {
     /**
      * Additional metadata about image media, if available.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub lens: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lens: Option<String>,
     /**
      * Additional metadata about image media, if available.
      */
@@ -1509,11 +1281,10 @@ pub struct ImageMediaMetadata {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "meteringMode"
     )]
-    pub metering_mode: String,
+    pub metering_mode: Option<String>,
     /**
      * Additional metadata about image media, if available.
      */
@@ -1526,12 +1297,8 @@ pub struct ImageMediaMetadata {
     /**
      * Additional metadata about image media, if available.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub sensor: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sensor: Option<String>,
     /**
      * Additional metadata about image media, if available.
      */
@@ -1545,22 +1312,17 @@ pub struct ImageMediaMetadata {
     /**
      * Additional metadata about image media, if available.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub time: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
     /**
      * Additional metadata about image media, if available.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "whiteBalance"
     )]
-    pub white_balance: String,
+    pub white_balance: Option<String>,
     /**
      * Additional metadata about image media, if available.
      */
@@ -1597,37 +1359,30 @@ pub struct LinkShareMetadata {
 
 /// Shortcut file details. Only populated for shortcut files, which have the mimeType field set to application/vnd.google-apps.shortcut.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ShortcutDetails {
-    /**
-     * Shortcut file details. Only populated for shortcut files, which have the mimeType field set to application/vnd.google-apps.shortcut.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "targetId"
-    )]
-    pub target_id: String,
+pub struct ShortcutDetails {
+    /**
+     * Shortcut file details. Only populated for shortcut files, which have the mimeType field set to application/vnd.google-apps.shortcut.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "targetId")]
+    pub target_id: Option<String>,
     /**
      * Shortcut file details. Only populated for shortcut files, which have the mimeType field set to application/vnd.google-apps.shortcut.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "targetMimeType"
     )]
-    pub target_mime_type: String,
+    pub target_mime_type: Option<String>,
     /**
      * Shortcut file details. Only populated for shortcut files, which have the mimeType field set to application/vnd.google-apps.shortcut.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "targetResourceKey"
     )]
-    pub target_resource_key: String,
+    pub target_resource_key: Option<String>,
 }
 
 /// Additional metadata about video media. This may not be available immediately upon upload.
@@ -1671,11 +1426,11 @@ pub struct File {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "appProperties"
     )]
-    pub app_properties: String,
+    pub app_properties: std::collections::BTreeMap<String, String>,
     /**
      * The metadata for a file.
      */
@@ -1722,22 +1477,13 @@ pub struct File {
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "driveId"
-    )]
-    pub drive_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "driveId")]
+    pub drive_id: Option<String>,
     /**
      * The metadata for a file.
      */
@@ -1752,41 +1498,38 @@ pub struct File {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "exportLinks"
     )]
-    pub export_links: String,
+    pub export_links: std::collections::BTreeMap<String, String>,
     /**
      * The metadata for a file.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "fileExtension"
     )]
-    pub file_extension: String,
+    pub file_extension: Option<String>,
     /**
      * The metadata for a file.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "folderColorRgb"
     )]
-    pub folder_color_rgb: String,
+    pub folder_color_rgb: Option<String>,
     /**
      * The metadata for a file.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "fullFileExtension"
     )]
-    pub full_file_extension: String,
+    pub full_file_extension: Option<String>,
     /**
      * The metadata for a file.
      */
@@ -1810,30 +1553,20 @@ pub struct File {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "headRevisionId"
     )]
-    pub head_revision_id: String,
+    pub head_revision_id: Option<String>,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "iconLink"
-    )]
-    pub icon_link: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "iconLink")]
+    pub icon_link: Option<String>,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * The metadata for a file.
      */
@@ -1855,12 +1588,8 @@ pub struct File {
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * The metadata for a file.
      */
@@ -1884,21 +1613,15 @@ pub struct File {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "md5Checksum"
     )]
-    pub md_5_checksum: String,
+    pub md_5_checksum: Option<String>,
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mimeType"
-    )]
-    pub mime_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "mimeType")]
+    pub mime_type: Option<String>,
     /**
      * The metadata for a file.
      */
@@ -1931,22 +1654,17 @@ pub struct File {
     /**
      * The metadata for a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /**
      * The metadata for a file.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "originalFilename"
     )]
-    pub original_filename: String,
+    pub original_filename: Option<String>,
     /**
      * The metadata for a file.
      */
@@ -1994,10 +1712,10 @@ pub struct File {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
     )]
-    pub properties: String,
+    pub properties: std::collections::BTreeMap<String, String>,
     /**
      * The metadata for a file.
      */
@@ -2013,11 +1731,10 @@ pub struct File {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "resourceKey"
     )]
-    pub resource_key: String,
+    pub resource_key: Option<String>,
     /**
      * The metadata for a file.
      */
@@ -2079,21 +1796,19 @@ pub struct File {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "teamDriveId"
     )]
-    pub team_drive_id: String,
+    pub team_drive_id: Option<String>,
     /**
      * The metadata for a file.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "thumbnailLink"
     )]
-    pub thumbnail_link: String,
+    pub thumbnail_link: Option<String>,
     /**
      * The metadata for a file.
      */
@@ -2179,21 +1894,19 @@ pub struct File {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "webContentLink"
     )]
-    pub web_content_link: String,
+    pub web_content_link: Option<String>,
     /**
      * The metadata for a file.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "webViewLink"
     )]
-    pub web_view_link: String,
+    pub web_view_link: Option<String>,
     /**
      * The metadata for a file.
      */
@@ -2205,80 +1918,6 @@ pub struct File {
     pub writers_can_share: Option<bool>,
 }
 
-/// A list of files.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FileList {
-    /**
-     * A list of files.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub files: Vec<File>,
-    /**
-     * A list of files.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "incompleteSearch"
-    )]
-    pub incomplete_search: Option<bool>,
-    /**
-     * A list of files.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * A list of files.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-}
-
-/// A list of generated file IDs which can be provided in create requests.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GeneratedIds {
-    /**
-     * A list of generated file IDs which can be provided in create requests.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ids: Vec<String>,
-    /**
-     * A list of generated file IDs which can be provided in create requests.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * A list of generated file IDs which can be provided in create requests.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub space: String,
-}
-
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PermissionDetails {
     /**
@@ -2291,30 +1930,24 @@ pub struct PermissionDetails {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "inheritedFrom"
     )]
-    pub inherited_from: String,
+    pub inherited_from: Option<String>,
     /**
      * A link to this theme's background image.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "permissionType"
     )]
-    pub permission_type: String,
+    pub permission_type: Option<String>,
     /**
      * A link to this theme's background image.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub role: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2329,30 +1962,24 @@ pub struct TeamDrivePermissionDetails {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "inheritedFrom"
     )]
-    pub inherited_from: String,
+    pub inherited_from: Option<String>,
     /**
      * A link to this theme's background image.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub role: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
     /**
      * A link to this theme's background image.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "teamDrivePermissionType"
     )]
-    pub team_drive_permission_type: String,
+    pub team_drive_permission_type: Option<String>,
 }
 
 /// A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
@@ -2377,30 +2004,24 @@ pub struct Permission {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "displayName"
     )]
-    pub display_name: String,
+    pub display_name: Option<String>,
     /**
      * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub domain: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
     /**
      * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "emailAddress"
     )]
-    pub email_address: String,
+    pub email_address: Option<String>,
     /**
      * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
      */
@@ -2414,21 +2035,13 @@ pub struct Permission {
     /**
      * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
      */
@@ -2442,84 +2055,33 @@ pub struct Permission {
     /**
      * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "photoLink"
-    )]
-    pub photo_link: String,
-    /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub role: String,
-    /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "teamDrivePermissionDetails"
-    )]
-    pub team_drive_permission_details: Vec<TeamDrivePermissionDetails>,
-    /**
-     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "photoLink")]
+    pub photo_link: Option<String>,
     /**
      * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub view: String,
-}
-
-/// A list of permissions for a file.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PermissionList {
-    /**
-     * A list of permissions for a file.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * A list of permissions for a file.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
     /**
-     * A list of permissions for a file.
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
      */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "teamDrivePermissionDetails"
     )]
-    pub permissions: Vec<Permission>,
+    pub team_drive_permission_details: Vec<TeamDrivePermissionDetails>,
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+    /**
+     * A permission for a file. A permission grants a user, group, domain or the world access to a file or a folder hierarchy.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub view: Option<String>,
 }
 
 /// A reply to a comment on a file.
@@ -2528,12 +2090,8 @@ pub struct Reply {
     /**
      * A reply to a comment on a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub action: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
     /**
      * A reply to a comment on a file.
      */
@@ -2542,12 +2100,8 @@ pub struct Reply {
     /**
      * A reply to a comment on a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
     /**
      * A reply to a comment on a file.
      */
@@ -2568,29 +2122,20 @@ pub struct Reply {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "htmlContent"
     )]
-    pub html_content: String,
+    pub html_content: Option<String>,
     /**
      * A reply to a comment on a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * A reply to a comment on a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * A reply to a comment on a file.
      */
@@ -2603,39 +2148,6 @@ pub struct Reply {
     pub modified_time: Option<chrono::DateTime<chrono::Utc>>,
 }
 
-/// A list of replies to a comment on a file.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReplyList {
-    /**
-     * A list of replies to a comment on a file.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * A list of replies to a comment on a file.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-    /**
-     * A list of replies to a comment on a file.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub replies: Vec<Reply>,
-}
-
 /// The metadata for a revision to a file.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Revision {
@@ -2644,20 +2156,16 @@ pub struct Revision {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
         rename = "exportLinks"
     )]
-    pub export_links: String,
+    pub export_links: std::collections::BTreeMap<String, String>,
     /**
      * The metadata for a revision to a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * The metadata for a revision to a file.
      */
@@ -2670,12 +2178,8 @@ pub struct Revision {
     /**
      * The metadata for a revision to a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * The metadata for a revision to a file.
      */
@@ -2690,21 +2194,15 @@ pub struct Revision {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "md5Checksum"
     )]
-    pub md_5_checksum: String,
+    pub md_5_checksum: Option<String>,
     /**
      * The metadata for a revision to a file.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mimeType"
-    )]
-    pub mime_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "mimeType")]
+    pub mime_type: Option<String>,
     /**
      * The metadata for a revision to a file.
      */
@@ -2720,11 +2218,10 @@ pub struct Revision {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "originalFilename"
     )]
-    pub original_filename: String,
+    pub original_filename: Option<String>,
     /**
      * The metadata for a revision to a file.
      */
@@ -2744,11 +2241,10 @@ pub struct Revision {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "publishedLink"
     )]
-    pub published_link: String,
+    pub published_link: Option<String>,
     /**
      * The metadata for a revision to a file.
      */
@@ -2769,74 +2265,14 @@ pub struct Revision {
     pub size: i64,
 }
 
-/// A list of revisions of a file.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct RevisionList {
-    /**
-     * A list of revisions of a file.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * A list of revisions of a file.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-    /**
-     * A list of revisions of a file.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub revisions: Vec<Revision>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct StartPageToken {
-    /**
-     * A link to this theme's background image.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * A link to this theme's background image.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "startPageToken"
-    )]
-    pub start_page_token: String,
-}
-
 /// An image file and cropping parameters from which a background image for this Team Drive is set. This is a write only field; it can only be set on drive.teamdrives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct TeamDriveBackgroundImageFile {
     /**
      * An image file and cropping parameters from which a background image for this Team Drive is set. This is a write only field; it can only be set on drive.teamdrives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * An image file and cropping parameters from which a background image for this Team Drive is set. This is a write only field; it can only be set on drive.teamdrives.update requests that don't set themeId. When specified, all fields of the backgroundImageFile must be set.
      */
@@ -3086,11 +2522,10 @@ pub struct TeamDrive {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "backgroundImageLink"
     )]
-    pub background_image_link: String,
+    pub background_image_link: Option<String>,
     /**
      * Deprecated: use the drive collection instead.
      */
@@ -3099,13 +2534,8 @@ pub struct TeamDrive {
     /**
      * Deprecated: use the drive collection instead.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "colorRgb"
-    )]
-    pub color_rgb: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "colorRgb")]
+    pub color_rgb: Option<String>,
     /**
      * Deprecated: use the drive collection instead.
      */
@@ -3119,30 +2549,18 @@ pub struct TeamDrive {
     /**
      * Deprecated: use the drive collection instead.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     /**
      * Deprecated: use the drive collection instead.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * Deprecated: use the drive collection instead.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /**
      * Deprecated: use the drive collection instead.
      */
@@ -3151,47 +2569,8 @@ pub struct TeamDrive {
     /**
      * Deprecated: use the drive collection instead.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "themeId"
-    )]
-    pub theme_id: String,
-}
-
-/// A list of Team Drives.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TeamDriveList {
-    /**
-     * A list of Team Drives.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
-    /**
-     * A list of Team Drives.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPageToken"
-    )]
-    pub next_page_token: String,
-    /**
-     * A list of Team Drives.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "teamDrives"
-    )]
-    pub team_drives: Vec<TeamDrive>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "themeId")]
+    pub theme_id: Option<String>,
 }
 
 /// Information about a Drive user.
@@ -3202,30 +2581,24 @@ pub struct User {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "displayName"
     )]
-    pub display_name: String,
+    pub display_name: Option<String>,
     /**
      * Information about a Drive user.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "emailAddress"
     )]
-    pub email_address: String,
+    pub email_address: Option<String>,
     /**
      * Information about a Drive user.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
     /**
      * Information about a Drive user.
      */
@@ -3236,92 +2609,13 @@ pub struct User {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "permissionId"
     )]
-    pub permission_id: String,
+    pub permission_id: Option<String>,
     /**
      * Information about a Drive user.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "photoLink"
-    )]
-    pub photo_link: String,
-}
-
-/**
- * Data format for the response.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Alt {
-    #[serde(rename = "json")]
-    Json,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Alt {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Alt::Json => "json",
-            Alt::Noop => "",
-            Alt::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Alt {
-    fn default() -> Alt {
-        Alt::Noop
-    }
-}
-impl Alt {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Alt::Noop)
-    }
-}
-
-/**
- * The source of files to list. Deprecated: use 'corpora' instead.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Corpus {
-    #[serde(rename = "domain")]
-    Domain,
-    #[serde(rename = "user")]
-    User,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Corpus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Corpus::Domain => "domain",
-            Corpus::User => "user",
-            Corpus::Noop => "",
-            Corpus::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Corpus {
-    fn default() -> Corpus {
-        Corpus::Noop
-    }
-}
-impl Corpus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Corpus::Noop)
-    }
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "photoLink")]
+    pub photo_link: Option<String>,
 }
@@ -0,0 +1,188 @@
+//! The data types of the `about` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Information about the user, the user's Drive, and system capabilities.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct About {
+    /**
+     * Information about the user, the user's Drive, and system capabilities.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "appInstalled"
+    )]
+    pub app_installed: Option<bool>,
+    /**
+     * Information about the user, the user's Drive, and system capabilities.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "canCreateDrives"
+    )]
+    pub can_create_drives: Option<bool>,
+    /**
+     * Information about the user, the user's Drive, and system capabilities.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "canCreateTeamDrives"
+    )]
+    pub can_create_team_drives: Option<bool>,
+    /**
+     * Information about the user, the user's Drive, and system capabilities.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "driveThemes"
+    )]
+    pub drive_themes: Vec<DriveThemes>,
+    /**
+     * Information about the user, the user's Drive, and system capabilities.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "exportFormats"
+    )]
+    pub export_formats: std::collections::BTreeMap<String, Vec<String>>,
+    /**
+     * Information about the user, the user's Drive, and system capabilities.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "folderColorPalette"
+    )]
+    pub folder_color_palette: Vec<String>,
+    /**
+     * Information about the user, the user's Drive, and system capabilities.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "importFormats"
+    )]
+    pub import_formats: std::collections::BTreeMap<String, Vec<String>>,
+    /**
+     * Information about the user, the user's Drive, and system capabilities.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * Information about the user, the user's Drive, and system capabilities.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "maxImportSizes"
+    )]
+    pub max_import_sizes: std::collections::BTreeMap<String, i64>,
+    /**
+     * Information about the user, the user's Drive, and system capabilities.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "maxUploadSize"
+    )]
+    pub max_upload_size: i64,
+    /**
+     * Information about the user, the user's Drive, and system capabilities.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "storageQuota"
+    )]
+    pub storage_quota: Option<StorageQuota>,
+    /**
+     * Information about the user, the user's Drive, and system capabilities.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "teamDriveThemes"
+    )]
+    pub team_drive_themes: Vec<TeamDriveThemes>,
+    /**
+     * Information about the user, the user's Drive, and system capabilities.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+
+/**
+ * Data format for the response.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Alt {
+    #[serde(rename = "json")]
+    Json,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Alt {
+    fn as_ref(&self) -> &str {
+        match self {
+            Alt::Json => "json",
+            Alt::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Alt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Alt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "json" => Alt::Json,
+            _ => Alt::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Alt {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Alt> for String {
+    fn from(v: Alt) -> Self {
+        match v {
+            Alt::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
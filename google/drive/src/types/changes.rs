@@ -0,0 +1,142 @@
+//! The data types of the `changes` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// A list of changes for a user.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ChangeList {
+    /**
+     * A list of changes for a user.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub changes: Vec<Change>,
+    /**
+     * A list of changes for a user.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * A list of changes for a user.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "newStartPageToken"
+    )]
+    pub new_start_page_token: String,
+    /**
+     * A list of changes for a user.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+}
+
+/// An notification channel used to watch for resource changes.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Channel {
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub expiration: i64,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub params: std::collections::BTreeMap<String, String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload: Option<bool>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "resourceId"
+    )]
+    pub resource_id: Option<String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "resourceUri"
+    )]
+    pub resource_uri: Option<String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /**
+     * An notification channel used to watch for resource changes.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct StartPageToken {
+    /**
+     * A link to this theme's background image.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * A link to this theme's background image.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "startPageToken"
+    )]
+    pub start_page_token: String,
+}
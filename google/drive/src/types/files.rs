@@ -0,0 +1,141 @@
+//! The data types of the `files` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// A list of files.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FileList {
+    /**
+     * A list of files.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub files: Vec<File>,
+    /**
+     * A list of files.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "incompleteSearch"
+    )]
+    pub incomplete_search: Option<bool>,
+    /**
+     * A list of files.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * A list of files.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nextPageToken"
+    )]
+    pub next_page_token: String,
+}
+
+/// A list of generated file IDs which can be provided in create requests.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GeneratedIds {
+    /**
+     * A list of generated file IDs which can be provided in create requests.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub ids: Vec<String>,
+    /**
+     * A list of generated file IDs which can be provided in create requests.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kind: String,
+    /**
+     * A list of generated file IDs which can be provided in create requests.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub space: String,
+}
+
+/**
+ * The source of files to list. Deprecated: use 'corpora' instead.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Corpus {
+    #[serde(rename = "domain")]
+    Domain,
+    #[serde(rename = "user")]
+    User,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Corpus {
+    fn as_ref(&self) -> &str {
+        match self {
+            Corpus::Domain => "domain",
+            Corpus::User => "user",
+            Corpus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Corpus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Corpus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "domain" => Corpus::Domain,
+            "user" => Corpus::User,
+            _ => Corpus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Corpus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Corpus> for String {
+    fn from(v: Corpus) -> Self {
+        match v {
+            Corpus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
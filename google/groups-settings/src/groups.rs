@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Groups {
     pub client: Client,
@@ -22,14 +20,15 @@ impl Groups {
      * * `group_unique_id: &str` -- Identifies whether members external to your organization can join the group. Possible values are:  
      *  - true: G Suite users external to your organization can become members of this group.
      *  - false: Users not belonging to the organization are not allowed to become members of this group.
+     * * `params: &GroupsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        alt: crate::types::Alt,
         group_unique_id: &str,
+        params: &GroupsGetParams,
     ) -> Result<crate::types::Groups> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !alt.to_string().is_empty() {
+        if let Some(alt) = &params.alt {
             query_args.push(("alt".to_string(), alt.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -42,6 +41,21 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `GroupsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+    ) -> Result<crate::types::Groups> {
+        let params = GroupsGetParams {
+            alt: Some(alt).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_with(group_unique_id, &params).await
+    }
+
     /**
      * This function performs a `PUT` to the `/{groupUniqueId}` endpoint.
      *
@@ -52,15 +66,16 @@ impl Groups {
      * * `group_unique_id: &str` -- Identifies whether members external to your organization can join the group. Possible values are:  
      *  - true: G Suite users external to your organization can become members of this group.
      *  - false: Users not belonging to the organization are not allowed to become members of this group.
+     * * `params: &GroupsUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update(
+    pub async fn update_with(
         &self,
-        alt: crate::types::Alt,
         group_unique_id: &str,
+        params: &GroupsUpdateParams,
         body: &crate::types::Groups,
     ) -> Result<crate::types::Groups> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !alt.to_string().is_empty() {
+        if let Some(alt) = &params.alt {
             query_args.push(("alt".to_string(), alt.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -75,6 +90,22 @@ impl Groups {
             .await
     }
 
+    /**
+     * Deprecated: use `update_with` and pass the query parameters as a `GroupsUpdateParams` instead.
+     */
+    #[deprecated(note = "use `update_with` instead")]
+    pub async fn update(
+        &self,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+        body: &crate::types::Groups,
+    ) -> Result<crate::types::Groups> {
+        let params = GroupsUpdateParams {
+            alt: Some(alt).filter(|v| !v.to_string().is_empty()),
+        };
+        self.update_with(group_unique_id, &params, body).await
+    }
+
     /**
      * This function performs a `PATCH` to the `/{groupUniqueId}` endpoint.
      *
@@ -85,15 +116,16 @@ impl Groups {
      * * `group_unique_id: &str` -- Identifies whether members external to your organization can join the group. Possible values are:  
      *  - true: G Suite users external to your organization can become members of this group.
      *  - false: Users not belonging to the organization are not allowed to become members of this group.
+     * * `params: &GroupsPatchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn patch(
+    pub async fn patch_with(
         &self,
-        alt: crate::types::Alt,
         group_unique_id: &str,
+        params: &GroupsPatchParams,
         body: &crate::types::Groups,
     ) -> Result<crate::types::Groups> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !alt.to_string().is_empty() {
+        if let Some(alt) = &params.alt {
             query_args.push(("alt".to_string(), alt.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -107,4 +139,117 @@ impl Groups {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `patch_with` and pass the query parameters as a `GroupsPatchParams` instead.
+     */
+    #[deprecated(note = "use `patch_with` instead")]
+    pub async fn patch(
+        &self,
+        alt: crate::types::Alt,
+        group_unique_id: &str,
+        body: &crate::types::Groups,
+    ) -> Result<crate::types::Groups> {
+        let params = GroupsPatchParams {
+            alt: Some(alt).filter(|v| !v.to_string().is_empty()),
+        };
+        self.patch_with(group_unique_id, &params, body).await
+    }
+}
+
+/// The query parameters of `Groups::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsGetParams {
+    pub alt: Option<crate::types::Alt>,
+}
+
+impl GroupsGetParams {
+    pub fn alt(mut self, alt: crate::types::Alt) -> Self {
+        self.alt = Some(alt);
+        self
+    }
+}
+
+/// The query parameters of `Groups::update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsUpdateParams {
+    pub alt: Option<crate::types::Alt>,
+}
+
+impl GroupsUpdateParams {
+    pub fn alt(mut self, alt: crate::types::Alt) -> Self {
+        self.alt = Some(alt);
+        self
+    }
+}
+
+/// The query parameters of `Groups::patch_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsPatchParams {
+    pub alt: Option<crate::types::Alt>,
+}
+
+impl GroupsPatchParams {
+    pub fn alt(mut self, alt: crate::types::Alt) -> Self {
+        self.alt = Some(alt);
+        self
+    }
+}
+
+/// The functions of [`Groups`], for code that takes them as a trait, so that it can be
+/// tested with the `MockGroupsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait GroupsApi: Send + Sync {
+    /// See [`Groups::get_with`].
+    async fn get_with(
+        &self,
+        group_unique_id: &str,
+        params: &GroupsGetParams,
+    ) -> Result<crate::types::Groups>;
+
+    /// See [`Groups::update_with`].
+    async fn update_with(
+        &self,
+        group_unique_id: &str,
+        params: &GroupsUpdateParams,
+        body: &crate::types::Groups,
+    ) -> Result<crate::types::Groups>;
+
+    /// See [`Groups::patch_with`].
+    async fn patch_with(
+        &self,
+        group_unique_id: &str,
+        params: &GroupsPatchParams,
+        body: &crate::types::Groups,
+    ) -> Result<crate::types::Groups>;
+}
+
+#[async_trait::async_trait]
+impl GroupsApi for Groups {
+    async fn get_with(
+        &self,
+        group_unique_id: &str,
+        params: &GroupsGetParams,
+    ) -> Result<crate::types::Groups> {
+        Groups::get_with(self, group_unique_id, params).await
+    }
+
+    async fn update_with(
+        &self,
+        group_unique_id: &str,
+        params: &GroupsUpdateParams,
+        body: &crate::types::Groups,
+    ) -> Result<crate::types::Groups> {
+        Groups::update_with(self, group_unique_id, params, body).await
+    }
+
+    async fn patch_with(
+        &self,
+        group_unique_id: &str,
+        params: &GroupsPatchParams,
+        body: &crate::types::Groups,
+    ) -> Result<crate::types::Groups> {
+        Groups::patch_with(self, group_unique_id, params, body).await
+    }
 }
@@ -0,0 +1,603 @@
+//! The data types of the `groups` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// JSON template for Group resource
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Groups {
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "allowExternalMembers"
+    )]
+    pub allow_external_members: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "allowGoogleCommunication"
+    )]
+    pub allow_google_communication: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "allowWebPosting"
+    )]
+    pub allow_web_posting: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "archiveOnly"
+    )]
+    pub archive_only: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "customFooterText"
+    )]
+    pub custom_footer_text: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "customReplyTo"
+    )]
+    pub custom_reply_to: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "customRolesEnabledForSettingsToBeMerged"
+    )]
+    pub custom_roles_enabled_for_settings_to_be_merged: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "defaultMessageDenyNotificationText"
+    )]
+    pub default_message_deny_notification_text: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_sender: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "enableCollaborativeInbox"
+    )]
+    pub enable_collaborative_inbox: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "favoriteRepliesOnTop"
+    )]
+    pub favorite_replies_on_top: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeCustomFooter"
+    )]
+    pub include_custom_footer: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeInGlobalAddressList"
+    )]
+    pub include_in_global_address_list: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "isArchived"
+    )]
+    pub is_archived: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "maxMessageBytes"
+    )]
+    pub max_message_bytes: i64,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "membersCanPostAsTheGroup"
+    )]
+    pub members_can_post_as_the_group: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "messageDisplayFont"
+    )]
+    pub message_display_font: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "messageModerationLevel"
+    )]
+    pub message_moderation_level: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "primaryLanguage"
+    )]
+    pub primary_language: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "replyTo")]
+    pub reply_to: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "sendMessageDenyNotification"
+    )]
+    pub send_message_deny_notification: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "showInGroupDirectory"
+    )]
+    pub show_in_group_directory: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "spamModerationLevel"
+    )]
+    pub spam_moderation_level: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "whoCanAdd")]
+    pub who_can_add: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanAddReferences"
+    )]
+    pub who_can_add_references: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanApproveMembers"
+    )]
+    pub who_can_approve_members: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanApproveMessages"
+    )]
+    pub who_can_approve_messages: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanAssignTopics"
+    )]
+    pub who_can_assign_topics: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanAssistContent"
+    )]
+    pub who_can_assist_content: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanBanUsers"
+    )]
+    pub who_can_ban_users: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanContactOwner"
+    )]
+    pub who_can_contact_owner: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanDeleteAnyPost"
+    )]
+    pub who_can_delete_any_post: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanDeleteTopics"
+    )]
+    pub who_can_delete_topics: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanDiscoverGroup"
+    )]
+    pub who_can_discover_group: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanEnterFreeFormTags"
+    )]
+    pub who_can_enter_free_form_tags: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanHideAbuse"
+    )]
+    pub who_can_hide_abuse: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanInvite"
+    )]
+    pub who_can_invite: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanJoin"
+    )]
+    pub who_can_join: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanLeaveGroup"
+    )]
+    pub who_can_leave_group: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanLockTopics"
+    )]
+    pub who_can_lock_topics: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanMakeTopicsSticky"
+    )]
+    pub who_can_make_topics_sticky: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanMarkDuplicate"
+    )]
+    pub who_can_mark_duplicate: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanMarkFavoriteReplyOnAnyTopic"
+    )]
+    pub who_can_mark_favorite_reply_on_any_topic: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanMarkFavoriteReplyOnOwnTopic"
+    )]
+    pub who_can_mark_favorite_reply_on_own_topic: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanMarkNoResponseNeeded"
+    )]
+    pub who_can_mark_no_response_needed: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanModerateContent"
+    )]
+    pub who_can_moderate_content: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanModerateMembers"
+    )]
+    pub who_can_moderate_members: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanModifyMembers"
+    )]
+    pub who_can_modify_members: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanModifyTagsAndCategories"
+    )]
+    pub who_can_modify_tags_and_categories: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanMoveTopicsIn"
+    )]
+    pub who_can_move_topics_in: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanMoveTopicsOut"
+    )]
+    pub who_can_move_topics_out: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanPostAnnouncements"
+    )]
+    pub who_can_post_announcements: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanPostMessage"
+    )]
+    pub who_can_post_message: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanTakeTopics"
+    )]
+    pub who_can_take_topics: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanUnassignTopic"
+    )]
+    pub who_can_unassign_topic: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanUnmarkFavoriteReplyOnAnyTopic"
+    )]
+    pub who_can_unmark_favorite_reply_on_any_topic: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanViewGroup"
+    )]
+    pub who_can_view_group: Option<String>,
+    /**
+     * JSON template for Group resource
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "whoCanViewMembership"
+    )]
+    pub who_can_view_membership: Option<String>,
+}
+
+/**
+ * Data format for the response.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Alt {
+    #[serde(rename = "atom")]
+    Atom,
+    #[serde(rename = "json")]
+    Json,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Alt {
+    fn as_ref(&self) -> &str {
+        match self {
+            Alt::Atom => "atom",
+            Alt::Json => "json",
+            Alt::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Alt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Alt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "atom" => Alt::Atom,
+            "json" => Alt::Json,
+            _ => Alt::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Alt {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Alt> for String {
+    fn from(v: Alt) -> Self {
+        match v {
+            Alt::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Spreadsheets {
     pub client: Client,
@@ -35,20 +33,18 @@ impl Spreadsheets {
      * **Parameters:**
      *
      * * `spreadsheet_id: &str` -- The fields of CellData that should be updated. At least one field must be specified. The root is the CellData; 'row.values.' should not be specified. A single `"\*"` can be used as short-hand for listing every field.
-     * * `include_grid_data: bool` -- True if we should generate data with the "alternate" series. This differs based on the type and amount of source data.
-     * * `ranges: &[String]` -- The ranges that were cleared, in A1 notation. If the requests are for an unbounded range or a ranger larger than the bounds of the sheet, this is the actual ranges that were cleared, bounded to the sheet's limits.
+     * * `params: &SpreadsheetsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         spreadsheet_id: &str,
-        include_grid_data: bool,
-        ranges: &[String],
+        params: &SpreadsheetsGetParams,
     ) -> Result<crate::types::Spreadsheet> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_grid_data {
+        if let Some(include_grid_data) = &params.include_grid_data {
             query_args.push(("includeGridData".to_string(), include_grid_data.to_string()));
         }
-        if !ranges.is_empty() {
+        if let Some(ranges) = &params.ranges {
             query_args.push(("ranges".to_string(), ranges.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -61,6 +57,23 @@ impl Spreadsheets {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `SpreadsheetsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        spreadsheet_id: &str,
+        include_grid_data: bool,
+        ranges: &[String],
+    ) -> Result<crate::types::Spreadsheet> {
+        let params = SpreadsheetsGetParams {
+            include_grid_data: Some(include_grid_data).filter(|v| *v),
+            ranges: Some(ranges.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_with(spreadsheet_id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v4/spreadsheets/{spreadsheetId}/developerMetadata/{metadataId}` endpoint.
      *
@@ -145,29 +158,25 @@ impl Spreadsheets {
      *
      * * `spreadsheet_id: &str` -- The fields of CellData that should be updated. At least one field must be specified. The root is the CellData; 'row.values.' should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      * * `range: &str` -- The fields of CellData that should be updated. At least one field must be specified. The root is the CellData; 'row.values.' should not be specified. A single `"\*"` can be used as short-hand for listing every field.
-     * * `date_time_render_option: crate::types::DateTimeRenderOption` -- How dates, times, and durations should be represented in the output. This is ignored if value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
-     * * `major_dimension: crate::types::Dimension` -- The major dimension that results should use. For example, if the spreadsheet data is: `A1=1,B1=2,A2=3,B2=4`, then requesting `range=A1:B2,majorDimension=ROWS` returns `[[1,2],[3,4]]`, whereas requesting `range=A1:B2,majorDimension=COLUMNS` returns `[[1,3],[2,4]]`.
-     * * `value_render_option: crate::types::ValueRenderOption` -- How values should be represented in the output. The default render option is FORMATTED_VALUE.
+     * * `params: &SpreadsheetsValuesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn values_get(
+    pub async fn values_get_with(
         &self,
         spreadsheet_id: &str,
         range: &str,
-        date_time_render_option: crate::types::DateTimeRenderOption,
-        major_dimension: crate::types::Dimension,
-        value_render_option: crate::types::ValueRenderOption,
+        params: &SpreadsheetsValuesGetParams,
     ) -> Result<crate::types::ValueRange> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date_time_render_option.to_string().is_empty() {
+        if let Some(date_time_render_option) = &params.date_time_render_option {
             query_args.push((
                 "dateTimeRenderOption".to_string(),
                 date_time_render_option.to_string(),
             ));
         }
-        if !major_dimension.to_string().is_empty() {
+        if let Some(major_dimension) = &params.major_dimension {
             query_args.push(("majorDimension".to_string(), major_dimension.to_string()));
         }
-        if !value_render_option.to_string().is_empty() {
+        if let Some(value_render_option) = &params.value_render_option {
             query_args.push((
                 "valueRenderOption".to_string(),
                 value_render_option.to_string(),
@@ -184,6 +193,27 @@ impl Spreadsheets {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `values_get_with` and pass the query parameters as a `SpreadsheetsValuesGetParams` instead.
+     */
+    #[deprecated(note = "use `values_get_with` instead")]
+    pub async fn values_get(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+        major_dimension: crate::types::MajorDimension,
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> Result<crate::types::ValueRange> {
+        let params = SpreadsheetsValuesGetParams {
+            date_time_render_option: Some(date_time_render_option)
+                .filter(|v| !v.to_string().is_empty()),
+            major_dimension: Some(major_dimension).filter(|v| !v.to_string().is_empty()),
+            value_render_option: Some(value_render_option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.values_get_with(spreadsheet_id, range, &params).await
+    }
+
     /**
      * This function performs a `PUT` to the `/v4/spreadsheets/{spreadsheetId}/values/{range}` endpoint.
      *
@@ -193,41 +223,35 @@ impl Spreadsheets {
      *
      * * `spreadsheet_id: &str` -- The fields of CellData that should be updated. At least one field must be specified. The root is the CellData; 'row.values.' should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      * * `range: &str` -- The fields of CellData that should be updated. At least one field must be specified. The root is the CellData; 'row.values.' should not be specified. A single `"\*"` can be used as short-hand for listing every field.
-     * * `include_values_in_response: bool` -- Determines if the update response should include the values of the cells that were updated. By default, responses do not include the updated values. If the range to write was larger than the range actually written, the response includes all values in the requested range (excluding trailing empty rows and columns).
-     * * `response_date_time_render_option: crate::types::DateTimeRenderOption` -- Determines how dates, times, and durations in the response should be rendered. This is ignored if response_value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
-     * * `response_value_render_option: crate::types::ValueRenderOption` -- Determines how values in the response should be rendered. The default render option is FORMATTED_VALUE.
-     * * `value_input_option: crate::types::ValueInputOption` -- How the input data should be interpreted.
+     * * `params: &SpreadsheetsValuesUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn values_update(
+    pub async fn values_update_with(
         &self,
         spreadsheet_id: &str,
         range: &str,
-        include_values_in_response: bool,
-        response_date_time_render_option: crate::types::DateTimeRenderOption,
-        response_value_render_option: crate::types::ValueRenderOption,
-        value_input_option: crate::types::ValueInputOption,
+        params: &SpreadsheetsValuesUpdateParams,
         body: &crate::types::ValueRange,
     ) -> Result<crate::types::UpdateValuesResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_values_in_response {
+        if let Some(include_values_in_response) = &params.include_values_in_response {
             query_args.push((
                 "includeValuesInResponse".to_string(),
                 include_values_in_response.to_string(),
             ));
         }
-        if !response_date_time_render_option.to_string().is_empty() {
+        if let Some(response_date_time_render_option) = &params.response_date_time_render_option {
             query_args.push((
                 "responseDateTimeRenderOption".to_string(),
                 response_date_time_render_option.to_string(),
             ));
         }
-        if !response_value_render_option.to_string().is_empty() {
+        if let Some(response_value_render_option) = &params.response_value_render_option {
             query_args.push((
                 "responseValueRenderOption".to_string(),
                 response_value_render_option.to_string(),
             ));
         }
-        if !value_input_option.to_string().is_empty() {
+        if let Some(value_input_option) = &params.value_input_option {
             query_args.push((
                 "valueInputOption".to_string(),
                 value_input_option.to_string(),
@@ -246,6 +270,32 @@ impl Spreadsheets {
             .await
     }
 
+    /**
+     * Deprecated: use `values_update_with` and pass the query parameters as a `SpreadsheetsValuesUpdateParams` instead.
+     */
+    #[deprecated(note = "use `values_update_with` instead")]
+    pub async fn values_update(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        include_values_in_response: bool,
+        response_date_time_render_option: crate::types::ResponseDateTimeRenderOption,
+        response_value_render_option: crate::types::ResponseValueRenderOption,
+        value_input_option: crate::types::ValueInputOption,
+        body: &crate::types::ValueRange,
+    ) -> Result<crate::types::UpdateValuesResponse> {
+        let params = SpreadsheetsValuesUpdateParams {
+            include_values_in_response: Some(include_values_in_response).filter(|v| *v),
+            response_date_time_render_option: Some(response_date_time_render_option)
+                .filter(|v| !v.to_string().is_empty()),
+            response_value_render_option: Some(response_value_render_option)
+                .filter(|v| !v.to_string().is_empty()),
+            value_input_option: Some(value_input_option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.values_update_with(spreadsheet_id, range, &params, body)
+            .await
+    }
+
     /**
      * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values/{range}:append` endpoint.
      *
@@ -255,49 +305,41 @@ impl Spreadsheets {
      *
      * * `spreadsheet_id: &str` -- The fields of CellData that should be updated. At least one field must be specified. The root is the CellData; 'row.values.' should not be specified. A single `"\*"` can be used as short-hand for listing every field.
      * * `range: &str` -- The fields of CellData that should be updated. At least one field must be specified. The root is the CellData; 'row.values.' should not be specified. A single `"\*"` can be used as short-hand for listing every field.
-     * * `include_values_in_response: bool` -- Determines if the update response should include the values of the cells that were appended. By default, responses do not include the updated values.
-     * * `insert_data_option: crate::types::InsertDataOption` -- How the input data should be inserted.
-     * * `response_date_time_render_option: crate::types::DateTimeRenderOption` -- Determines how dates, times, and durations in the response should be rendered. This is ignored if response_value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
-     * * `response_value_render_option: crate::types::ValueRenderOption` -- Determines how values in the response should be rendered. The default render option is FORMATTED_VALUE.
-     * * `value_input_option: crate::types::ValueInputOption` -- How the input data should be interpreted.
+     * * `params: &SpreadsheetsValuesAppendParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn values_append(
+    pub async fn values_append_with(
         &self,
         spreadsheet_id: &str,
         range: &str,
-        include_values_in_response: bool,
-        insert_data_option: crate::types::InsertDataOption,
-        response_date_time_render_option: crate::types::DateTimeRenderOption,
-        response_value_render_option: crate::types::ValueRenderOption,
-        value_input_option: crate::types::ValueInputOption,
+        params: &SpreadsheetsValuesAppendParams,
         body: &crate::types::ValueRange,
     ) -> Result<crate::types::AppendValuesResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_values_in_response {
+        if let Some(include_values_in_response) = &params.include_values_in_response {
             query_args.push((
                 "includeValuesInResponse".to_string(),
                 include_values_in_response.to_string(),
             ));
         }
-        if !insert_data_option.to_string().is_empty() {
+        if let Some(insert_data_option) = &params.insert_data_option {
             query_args.push((
                 "insertDataOption".to_string(),
                 insert_data_option.to_string(),
             ));
         }
-        if !response_date_time_render_option.to_string().is_empty() {
+        if let Some(response_date_time_render_option) = &params.response_date_time_render_option {
             query_args.push((
                 "responseDateTimeRenderOption".to_string(),
                 response_date_time_render_option.to_string(),
             ));
         }
-        if !response_value_render_option.to_string().is_empty() {
+        if let Some(response_value_render_option) = &params.response_value_render_option {
             query_args.push((
                 "responseValueRenderOption".to_string(),
                 response_value_render_option.to_string(),
             ));
         }
-        if !value_input_option.to_string().is_empty() {
+        if let Some(value_input_option) = &params.value_input_option {
             query_args.push((
                 "valueInputOption".to_string(),
                 value_input_option.to_string(),
@@ -316,6 +358,34 @@ impl Spreadsheets {
             .await
     }
 
+    /**
+     * Deprecated: use `values_append_with` and pass the query parameters as a `SpreadsheetsValuesAppendParams` instead.
+     */
+    #[deprecated(note = "use `values_append_with` instead")]
+    pub async fn values_append(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        include_values_in_response: bool,
+        insert_data_option: crate::types::InsertDataOption,
+        response_date_time_render_option: crate::types::ResponseDateTimeRenderOption,
+        response_value_render_option: crate::types::ResponseValueRenderOption,
+        value_input_option: crate::types::ValueInputOption,
+        body: &crate::types::ValueRange,
+    ) -> Result<crate::types::AppendValuesResponse> {
+        let params = SpreadsheetsValuesAppendParams {
+            include_values_in_response: Some(include_values_in_response).filter(|v| *v),
+            insert_data_option: Some(insert_data_option).filter(|v| !v.to_string().is_empty()),
+            response_date_time_render_option: Some(response_date_time_render_option)
+                .filter(|v| !v.to_string().is_empty()),
+            response_value_render_option: Some(response_value_render_option)
+                .filter(|v| !v.to_string().is_empty()),
+            value_input_option: Some(value_input_option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.values_append_with(spreadsheet_id, range, &params, body)
+            .await
+    }
+
     /**
      * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values/{range}:clear` endpoint.
      *
@@ -399,33 +469,27 @@ impl Spreadsheets {
      * **Parameters:**
      *
      * * `spreadsheet_id: &str` -- The fields of CellData that should be updated. At least one field must be specified. The root is the CellData; 'row.values.' should not be specified. A single `"\*"` can be used as short-hand for listing every field.
-     * * `date_time_render_option: crate::types::DateTimeRenderOption` -- How dates, times, and durations should be represented in the output. This is ignored if value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
-     * * `major_dimension: crate::types::Dimension` -- The major dimension that results should use. For example, if the spreadsheet data is: `A1=1,B1=2,A2=3,B2=4`, then requesting `range=A1:B2,majorDimension=ROWS` returns `[[1,2],[3,4]]`, whereas requesting `range=A1:B2,majorDimension=COLUMNS` returns `[[1,3],[2,4]]`.
-     * * `ranges: &[String]` -- The ranges that were cleared, in A1 notation. If the requests are for an unbounded range or a ranger larger than the bounds of the sheet, this is the actual ranges that were cleared, bounded to the sheet's limits.
-     * * `value_render_option: crate::types::ValueRenderOption` -- How values should be represented in the output. The default render option is ValueRenderOption.FORMATTED_VALUE.
+     * * `params: &SpreadsheetsValuesBatchGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn values_batch_get(
+    pub async fn values_batch_get_with(
         &self,
         spreadsheet_id: &str,
-        date_time_render_option: crate::types::DateTimeRenderOption,
-        major_dimension: crate::types::Dimension,
-        ranges: &[String],
-        value_render_option: crate::types::ValueRenderOption,
+        params: &SpreadsheetsValuesBatchGetParams,
     ) -> Result<crate::types::BatchGetValuesResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date_time_render_option.to_string().is_empty() {
+        if let Some(date_time_render_option) = &params.date_time_render_option {
             query_args.push((
                 "dateTimeRenderOption".to_string(),
                 date_time_render_option.to_string(),
             ));
         }
-        if !major_dimension.to_string().is_empty() {
+        if let Some(major_dimension) = &params.major_dimension {
             query_args.push(("majorDimension".to_string(), major_dimension.to_string()));
         }
-        if !ranges.is_empty() {
+        if let Some(ranges) = &params.ranges {
             query_args.push(("ranges".to_string(), ranges.join(" ")));
         }
-        if !value_render_option.to_string().is_empty() {
+        if let Some(value_render_option) = &params.value_render_option {
             query_args.push((
                 "valueRenderOption".to_string(),
                 value_render_option.to_string(),
@@ -441,6 +505,28 @@ impl Spreadsheets {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `values_batch_get_with` and pass the query parameters as a `SpreadsheetsValuesBatchGetParams` instead.
+     */
+    #[deprecated(note = "use `values_batch_get_with` instead")]
+    pub async fn values_batch_get(
+        &self,
+        spreadsheet_id: &str,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+        major_dimension: crate::types::MajorDimension,
+        ranges: &[String],
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> Result<crate::types::BatchGetValuesResponse> {
+        let params = SpreadsheetsValuesBatchGetParams {
+            date_time_render_option: Some(date_time_render_option)
+                .filter(|v| !v.to_string().is_empty()),
+            major_dimension: Some(major_dimension).filter(|v| !v.to_string().is_empty()),
+            ranges: Some(ranges.to_vec()).filter(|v| !v.is_empty()),
+            value_render_option: Some(value_render_option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.values_batch_get_with(spreadsheet_id, &params).await
+    }
+
     /**
      * This function performs a `POST` to the `/v4/spreadsheets/{spreadsheetId}/values:batchGetByDataFilter` endpoint.
      *
@@ -561,3 +647,467 @@ impl Spreadsheets {
             .await
     }
 }
+
+/// The query parameters of `Spreadsheets::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SpreadsheetsGetParams {
+    /// True if we should generate data with the "alternate" series. This differs based on the type and amount of source data.
+    pub include_grid_data: Option<bool>,
+    /// The ranges that were cleared, in A1 notation. If the requests are for an unbounded range or a ranger larger than the bounds of the sheet, this is the actual ranges that were cleared, bounded to the sheet's limits.
+    pub ranges: Option<Vec<String>>,
+}
+
+impl SpreadsheetsGetParams {
+    pub fn include_grid_data(mut self, include_grid_data: bool) -> Self {
+        self.include_grid_data = Some(include_grid_data);
+        self
+    }
+
+    pub fn ranges(mut self, ranges: Vec<String>) -> Self {
+        self.ranges = Some(ranges);
+        self
+    }
+}
+
+/// The query parameters of `Spreadsheets::values_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SpreadsheetsValuesGetParams {
+    /// How dates, times, and durations should be represented in the output. This is ignored if value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
+    pub date_time_render_option: Option<crate::types::DateTimeRenderOption>,
+    /// The major dimension that results should use. For example, if the spreadsheet data is: `A1=1,B1=2,A2=3,B2=4`, then requesting `range=A1:B2,majorDimension=ROWS` returns `[[1,2],[3,4]]`, whereas requesting `range=A1:B2,majorDimension=COLUMNS` returns `[[1,3],[2,4]]`.
+    pub major_dimension: Option<crate::types::MajorDimension>,
+    /// How values should be represented in the output. The default render option is ValueRenderOption.FORMATTED_VALUE.
+    pub value_render_option: Option<crate::types::ValueRenderOption>,
+}
+
+impl SpreadsheetsValuesGetParams {
+    pub fn date_time_render_option(
+        mut self,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+    ) -> Self {
+        self.date_time_render_option = Some(date_time_render_option);
+        self
+    }
+
+    pub fn major_dimension(mut self, major_dimension: crate::types::MajorDimension) -> Self {
+        self.major_dimension = Some(major_dimension);
+        self
+    }
+
+    pub fn value_render_option(
+        mut self,
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> Self {
+        self.value_render_option = Some(value_render_option);
+        self
+    }
+}
+
+/// The query parameters of `Spreadsheets::values_update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SpreadsheetsValuesUpdateParams {
+    /// Determines if the update response should include the values of the cells that were updated. By default, responses do not include the updated values. If the range to write was larger than the range actually written, the response includes all values in the requested range (excluding trailing empty rows and columns).
+    pub include_values_in_response: Option<bool>,
+    /// Determines how dates, times, and durations in the response should be rendered. This is ignored if response_value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
+    pub response_date_time_render_option: Option<crate::types::ResponseDateTimeRenderOption>,
+    /// Determines how values in the response should be rendered. The default render option is FORMATTED_VALUE.
+    pub response_value_render_option: Option<crate::types::ResponseValueRenderOption>,
+    /// How the input data should be interpreted.
+    pub value_input_option: Option<crate::types::ValueInputOption>,
+}
+
+impl SpreadsheetsValuesUpdateParams {
+    pub fn include_values_in_response(mut self, include_values_in_response: bool) -> Self {
+        self.include_values_in_response = Some(include_values_in_response);
+        self
+    }
+
+    pub fn response_date_time_render_option(
+        mut self,
+        response_date_time_render_option: crate::types::ResponseDateTimeRenderOption,
+    ) -> Self {
+        self.response_date_time_render_option = Some(response_date_time_render_option);
+        self
+    }
+
+    pub fn response_value_render_option(
+        mut self,
+        response_value_render_option: crate::types::ResponseValueRenderOption,
+    ) -> Self {
+        self.response_value_render_option = Some(response_value_render_option);
+        self
+    }
+
+    pub fn value_input_option(
+        mut self,
+        value_input_option: crate::types::ValueInputOption,
+    ) -> Self {
+        self.value_input_option = Some(value_input_option);
+        self
+    }
+}
+
+/// The query parameters of `Spreadsheets::values_append_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SpreadsheetsValuesAppendParams {
+    /// Determines if the update response should include the values of the cells that were appended. By default, responses do not include the updated values.
+    pub include_values_in_response: Option<bool>,
+    /// How the input data should be inserted.
+    pub insert_data_option: Option<crate::types::InsertDataOption>,
+    /// Determines how dates, times, and durations in the response should be rendered. This is ignored if response_value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
+    pub response_date_time_render_option: Option<crate::types::ResponseDateTimeRenderOption>,
+    /// Determines how values in the response should be rendered. The default render option is FORMATTED_VALUE.
+    pub response_value_render_option: Option<crate::types::ResponseValueRenderOption>,
+    /// How the input data should be interpreted.
+    pub value_input_option: Option<crate::types::ValueInputOption>,
+}
+
+impl SpreadsheetsValuesAppendParams {
+    pub fn include_values_in_response(mut self, include_values_in_response: bool) -> Self {
+        self.include_values_in_response = Some(include_values_in_response);
+        self
+    }
+
+    pub fn insert_data_option(
+        mut self,
+        insert_data_option: crate::types::InsertDataOption,
+    ) -> Self {
+        self.insert_data_option = Some(insert_data_option);
+        self
+    }
+
+    pub fn response_date_time_render_option(
+        mut self,
+        response_date_time_render_option: crate::types::ResponseDateTimeRenderOption,
+    ) -> Self {
+        self.response_date_time_render_option = Some(response_date_time_render_option);
+        self
+    }
+
+    pub fn response_value_render_option(
+        mut self,
+        response_value_render_option: crate::types::ResponseValueRenderOption,
+    ) -> Self {
+        self.response_value_render_option = Some(response_value_render_option);
+        self
+    }
+
+    pub fn value_input_option(
+        mut self,
+        value_input_option: crate::types::ValueInputOption,
+    ) -> Self {
+        self.value_input_option = Some(value_input_option);
+        self
+    }
+}
+
+/// The query parameters of `Spreadsheets::values_batch_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SpreadsheetsValuesBatchGetParams {
+    /// How dates, times, and durations should be represented in the output. This is ignored if value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
+    pub date_time_render_option: Option<crate::types::DateTimeRenderOption>,
+    /// The major dimension that results should use. For example, if the spreadsheet data is: `A1=1,B1=2,A2=3,B2=4`, then requesting `range=A1:B2,majorDimension=ROWS` returns `[[1,2],[3,4]]`, whereas requesting `range=A1:B2,majorDimension=COLUMNS` returns `[[1,3],[2,4]]`.
+    pub major_dimension: Option<crate::types::MajorDimension>,
+    /// The ranges that were cleared, in A1 notation. If the requests are for an unbounded range or a ranger larger than the bounds of the sheet, this is the actual ranges that were cleared, bounded to the sheet's limits.
+    pub ranges: Option<Vec<String>>,
+    /// How values should be represented in the output. The default render option is ValueRenderOption.FORMATTED_VALUE.
+    pub value_render_option: Option<crate::types::ValueRenderOption>,
+}
+
+impl SpreadsheetsValuesBatchGetParams {
+    pub fn date_time_render_option(
+        mut self,
+        date_time_render_option: crate::types::DateTimeRenderOption,
+    ) -> Self {
+        self.date_time_render_option = Some(date_time_render_option);
+        self
+    }
+
+    pub fn major_dimension(mut self, major_dimension: crate::types::MajorDimension) -> Self {
+        self.major_dimension = Some(major_dimension);
+        self
+    }
+
+    pub fn ranges(mut self, ranges: Vec<String>) -> Self {
+        self.ranges = Some(ranges);
+        self
+    }
+
+    pub fn value_render_option(
+        mut self,
+        value_render_option: crate::types::ValueRenderOption,
+    ) -> Self {
+        self.value_render_option = Some(value_render_option);
+        self
+    }
+}
+
+/// The functions of [`Spreadsheets`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSpreadsheetsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SpreadsheetsApi: Send + Sync {
+    /// See [`Spreadsheets::create`].
+    async fn create(&self, body: &crate::types::Spreadsheet) -> Result<crate::types::Spreadsheet>;
+
+    /// See [`Spreadsheets::get_with`].
+    async fn get_with(
+        &self,
+        spreadsheet_id: &str,
+        params: &SpreadsheetsGetParams,
+    ) -> Result<crate::types::Spreadsheet>;
+
+    /// See [`Spreadsheets::developer_metadata_get`].
+    async fn developer_metadata_get(
+        &self,
+        spreadsheet_id: &str,
+        metadata_id: i64,
+    ) -> Result<crate::types::DeveloperMetadata>;
+
+    /// See [`Spreadsheets::developer_metadata_search`].
+    async fn developer_metadata_search(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::SearchDeveloperMetadataRequest,
+    ) -> Result<crate::types::SearchDeveloperMetadataResponse>;
+
+    /// See [`Spreadsheets::sheets_copy`].
+    async fn sheets_copy(
+        &self,
+        spreadsheet_id: &str,
+        sheet_id: i64,
+        body: &crate::types::CopySheetAnotherSpreadsheetRequest,
+    ) -> Result<crate::types::SheetProperties>;
+
+    /// See [`Spreadsheets::values_get_with`].
+    async fn values_get_with(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        params: &SpreadsheetsValuesGetParams,
+    ) -> Result<crate::types::ValueRange>;
+
+    /// See [`Spreadsheets::values_update_with`].
+    async fn values_update_with(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        params: &SpreadsheetsValuesUpdateParams,
+        body: &crate::types::ValueRange,
+    ) -> Result<crate::types::UpdateValuesResponse>;
+
+    /// See [`Spreadsheets::values_append_with`].
+    async fn values_append_with(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        params: &SpreadsheetsValuesAppendParams,
+        body: &crate::types::ValueRange,
+    ) -> Result<crate::types::AppendValuesResponse>;
+
+    /// See [`Spreadsheets::values_clear`].
+    async fn values_clear(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        body: &crate::types::ClearValuesRequest,
+    ) -> Result<crate::types::ClearValuesResponse>;
+
+    /// See [`Spreadsheets::values_batch_clear`].
+    async fn values_batch_clear(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchClearValuesRequest,
+    ) -> Result<crate::types::BatchClearValuesResponse>;
+
+    /// See [`Spreadsheets::values_batch_clear_data_filter`].
+    async fn values_batch_clear_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchClearValuesByDataFilterRequest,
+    ) -> Result<crate::types::BatchClearValuesByDataFilterResponse>;
+
+    /// See [`Spreadsheets::values_batch_get_with`].
+    async fn values_batch_get_with(
+        &self,
+        spreadsheet_id: &str,
+        params: &SpreadsheetsValuesBatchGetParams,
+    ) -> Result<crate::types::BatchGetValuesResponse>;
+
+    /// See [`Spreadsheets::values_batch_get_data_filter`].
+    async fn values_batch_get_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchGetValuesByDataFilterRequest,
+    ) -> Result<crate::types::BatchGetValuesByDataFilterResponse>;
+
+    /// See [`Spreadsheets::values_batch_update`].
+    async fn values_batch_update(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateValuesRequest,
+    ) -> Result<crate::types::BatchUpdateValuesResponse>;
+
+    /// See [`Spreadsheets::values_batch_update_data_filter`].
+    async fn values_batch_update_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateValuesByDataFilterRequest,
+    ) -> Result<crate::types::BatchUpdateValuesByDataFilterResponse>;
+
+    /// See [`Spreadsheets::batch_update`].
+    async fn batch_update(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateSpreadsheetRequest,
+    ) -> Result<crate::types::BatchUpdateSpreadsheetResponse>;
+
+    /// See [`Spreadsheets::get_data_filter`].
+    async fn get_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::GetSpreadsheetByDataFilterRequest,
+    ) -> Result<crate::types::Spreadsheet>;
+}
+
+#[async_trait::async_trait]
+impl SpreadsheetsApi for Spreadsheets {
+    async fn create(&self, body: &crate::types::Spreadsheet) -> Result<crate::types::Spreadsheet> {
+        Spreadsheets::create(self, body).await
+    }
+
+    async fn get_with(
+        &self,
+        spreadsheet_id: &str,
+        params: &SpreadsheetsGetParams,
+    ) -> Result<crate::types::Spreadsheet> {
+        Spreadsheets::get_with(self, spreadsheet_id, params).await
+    }
+
+    async fn developer_metadata_get(
+        &self,
+        spreadsheet_id: &str,
+        metadata_id: i64,
+    ) -> Result<crate::types::DeveloperMetadata> {
+        Spreadsheets::developer_metadata_get(self, spreadsheet_id, metadata_id).await
+    }
+
+    async fn developer_metadata_search(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::SearchDeveloperMetadataRequest,
+    ) -> Result<crate::types::SearchDeveloperMetadataResponse> {
+        Spreadsheets::developer_metadata_search(self, spreadsheet_id, body).await
+    }
+
+    async fn sheets_copy(
+        &self,
+        spreadsheet_id: &str,
+        sheet_id: i64,
+        body: &crate::types::CopySheetAnotherSpreadsheetRequest,
+    ) -> Result<crate::types::SheetProperties> {
+        Spreadsheets::sheets_copy(self, spreadsheet_id, sheet_id, body).await
+    }
+
+    async fn values_get_with(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        params: &SpreadsheetsValuesGetParams,
+    ) -> Result<crate::types::ValueRange> {
+        Spreadsheets::values_get_with(self, spreadsheet_id, range, params).await
+    }
+
+    async fn values_update_with(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        params: &SpreadsheetsValuesUpdateParams,
+        body: &crate::types::ValueRange,
+    ) -> Result<crate::types::UpdateValuesResponse> {
+        Spreadsheets::values_update_with(self, spreadsheet_id, range, params, body).await
+    }
+
+    async fn values_append_with(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        params: &SpreadsheetsValuesAppendParams,
+        body: &crate::types::ValueRange,
+    ) -> Result<crate::types::AppendValuesResponse> {
+        Spreadsheets::values_append_with(self, spreadsheet_id, range, params, body).await
+    }
+
+    async fn values_clear(
+        &self,
+        spreadsheet_id: &str,
+        range: &str,
+        body: &crate::types::ClearValuesRequest,
+    ) -> Result<crate::types::ClearValuesResponse> {
+        Spreadsheets::values_clear(self, spreadsheet_id, range, body).await
+    }
+
+    async fn values_batch_clear(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchClearValuesRequest,
+    ) -> Result<crate::types::BatchClearValuesResponse> {
+        Spreadsheets::values_batch_clear(self, spreadsheet_id, body).await
+    }
+
+    async fn values_batch_clear_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchClearValuesByDataFilterRequest,
+    ) -> Result<crate::types::BatchClearValuesByDataFilterResponse> {
+        Spreadsheets::values_batch_clear_data_filter(self, spreadsheet_id, body).await
+    }
+
+    async fn values_batch_get_with(
+        &self,
+        spreadsheet_id: &str,
+        params: &SpreadsheetsValuesBatchGetParams,
+    ) -> Result<crate::types::BatchGetValuesResponse> {
+        Spreadsheets::values_batch_get_with(self, spreadsheet_id, params).await
+    }
+
+    async fn values_batch_get_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchGetValuesByDataFilterRequest,
+    ) -> Result<crate::types::BatchGetValuesByDataFilterResponse> {
+        Spreadsheets::values_batch_get_data_filter(self, spreadsheet_id, body).await
+    }
+
+    async fn values_batch_update(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateValuesRequest,
+    ) -> Result<crate::types::BatchUpdateValuesResponse> {
+        Spreadsheets::values_batch_update(self, spreadsheet_id, body).await
+    }
+
+    async fn values_batch_update_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateValuesByDataFilterRequest,
+    ) -> Result<crate::types::BatchUpdateValuesByDataFilterResponse> {
+        Spreadsheets::values_batch_update_data_filter(self, spreadsheet_id, body).await
+    }
+
+    async fn batch_update(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::BatchUpdateSpreadsheetRequest,
+    ) -> Result<crate::types::BatchUpdateSpreadsheetResponse> {
+        Spreadsheets::batch_update(self, spreadsheet_id, body).await
+    }
+
+    async fn get_data_filter(
+        &self,
+        spreadsheet_id: &str,
+        body: &crate::types::GetSpreadsheetByDataFilterRequest,
+    ) -> Result<crate::types::Spreadsheet> {
+        Spreadsheets::get_data_filter(self, spreadsheet_id, body).await
+    }
+}
@@ -1 +1,14 @@
+#[test]
+fn test_enum_unknown_value() {
+    let d: crate::types::Dimension = serde_json::from_str(r#""ROWS""#).unwrap();
+    assert_eq!(d, crate::types::Dimension::Rows);
+    assert_eq!(d.to_string(), "ROWS");
 
+    // Values that are not in the spec are kept as they came, and sent back unchanged.
+    let d: crate::types::Dimension = serde_json::from_str(r#""DIAGONALS""#).unwrap();
+    assert_eq!(d, crate::types::Dimension::Unknown("DIAGONALS".to_string()));
+    assert_eq!(serde_json::to_string(&d).unwrap(), r#""DIAGONALS""#);
+
+    let d: crate::types::Dimension = "COLUMNS".parse().unwrap();
+    assert_eq!(d.as_ref(), "COLUMNS");
+}
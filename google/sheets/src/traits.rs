@@ -15,12 +15,16 @@ impl SpreadsheetOps for crate::spreadsheets::Spreadsheets {
     /// the cell's value.
     async fn cell_get(&self, sheet_id: &str, cell_name: &str) -> Result<String> {
         let values = self
-            .values_get(
+            .values_get_with(
                 sheet_id,
                 cell_name,
-                crate::types::DateTimeRenderOption::FormattedString,
-                crate::types::Dimension::Rows,
-                crate::types::ValueRenderOption::FormattedValue,
+                &crate::spreadsheets::SpreadsheetsValuesGetParams {
+                    date_time_render_option: Some(
+                        crate::types::DateTimeRenderOption::FormattedString,
+                    ),
+                    major_dimension: Some(crate::types::MajorDimension::Rows),
+                    value_render_option: Some(crate::types::ValueRenderOption::FormattedValue),
+                },
             )
             .await
             .unwrap();
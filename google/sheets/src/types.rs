@@ -2,6 +2,109 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> std::option::Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> std::convert::From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match std::option::Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        std::option::Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        std::option::Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
+
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+
+#[cfg(feature = "spreadsheets")]
+mod spreadsheets;
+#[cfg(feature = "spreadsheets")]
+pub use spreadsheets::*;
 /// Adds a new banded range to the spreadsheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AddBandingRequest {
@@ -253,12 +356,8 @@ pub struct AppendCellsRequest {
     /**
      * Adds new cells after the last row with data in a sheet, inserting new rows into the sheet if necessary.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
     /**
      * Adds new cells after the last row with data in a sheet, inserting new rows into the sheet if necessary.
      */
@@ -281,9 +380,10 @@ pub struct AppendCellsRequest {
 }
 
 /**
- * Whether rows or columns should be appended.
+ * The dimension that data should be filled into.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Dimension {
     #[serde(rename = "COLUMNS")]
     Columns,
@@ -291,33 +391,57 @@ pub enum Dimension {
     DimensionUnspecified,
     #[serde(rename = "ROWS")]
     Rows,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Dimension {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Dimension {
+    fn as_ref(&self) -> &str {
+        match self {
             Dimension::Columns => "COLUMNS",
             Dimension::DimensionUnspecified => "DIMENSION_UNSPECIFIED",
             Dimension::Rows => "ROWS",
-            Dimension::Noop => "",
-            Dimension::FallthroughString => "*",
+            Dimension::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Dimension {
-    fn default() -> Dimension {
-        Dimension::Noop
+impl std::fmt::Display for Dimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Dimension {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COLUMNS" => Dimension::Columns,
+            "DIMENSION_UNSPECIFIED" => Dimension::DimensionUnspecified,
+            "ROWS" => Dimension::Rows,
+            _ => Dimension::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Dimension {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Dimension {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Dimension::Noop)
+
+impl std::convert::From<Dimension> for String {
+    fn from(v: Dimension) -> Self {
+        match v {
+            Dimension::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -350,36 +474,6 @@ pub struct AppendDimensionRequest {
     pub sheet_id: i64,
 }
 
-/// The response when updating a range of values in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AppendValuesResponse {
-    /**
-     * The response when updating a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
-    )]
-    pub spreadsheet_id: String,
-    /**
-     * The response when updating a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tableRange"
-    )]
-    pub table_range: String,
-    /**
-     * The response when updating a range of values in a spreadsheet.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub updates: Option<UpdateValuesResponse>,
-}
-
 /// Fills in more data based on existing data.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AutoFillRequest {
@@ -546,6 +640,7 @@ pub struct BandingProperties {
  * The comparison type of key value with baseline value.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ComparisonType {
     #[serde(rename = "ABSOLUTE_DIFFERENCE")]
     AbsoluteDifference,
@@ -553,33 +648,57 @@ pub enum ComparisonType {
     ComparisonTypeUndefined,
     #[serde(rename = "PERCENTAGE_DIFFERENCE")]
     PercentageDifference,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ComparisonType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ComparisonType {
+    fn as_ref(&self) -> &str {
+        match self {
             ComparisonType::AbsoluteDifference => "ABSOLUTE_DIFFERENCE",
             ComparisonType::ComparisonTypeUndefined => "COMPARISON_TYPE_UNDEFINED",
             ComparisonType::PercentageDifference => "PERCENTAGE_DIFFERENCE",
-            ComparisonType::Noop => "",
-            ComparisonType::FallthroughString => "*",
+            ComparisonType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ComparisonType {
-    fn default() -> ComparisonType {
-        ComparisonType::Noop
+impl std::fmt::Display for ComparisonType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ComparisonType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ABSOLUTE_DIFFERENCE" => ComparisonType::AbsoluteDifference,
+            "COMPARISON_TYPE_UNDEFINED" => ComparisonType::ComparisonTypeUndefined,
+            "PERCENTAGE_DIFFERENCE" => ComparisonType::PercentageDifference,
+            _ => ComparisonType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ComparisonType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ComparisonType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ComparisonType::Noop)
+
+impl std::convert::From<ComparisonType> for String {
+    fn from(v: ComparisonType) -> Self {
+        match v {
+            ComparisonType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -598,12 +717,8 @@ pub struct BaselineValueFormat {
     /**
      * Formatting options for baseline value.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /**
      * Formatting options for baseline value.
      */
@@ -660,6 +775,7 @@ pub struct BaselineValueFormat {
  * The position of this axis.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Position {
     #[serde(rename = "BASIC_CHART_AXIS_POSITION_UNSPECIFIED")]
     BasicChartAxisPositionUnspecified,
@@ -669,34 +785,59 @@ pub enum Position {
     LeftAxis,
     #[serde(rename = "RIGHT_AXIS")]
     RightAxis,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Position {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Position {
+    fn as_ref(&self) -> &str {
+        match self {
             Position::BasicChartAxisPositionUnspecified => "BASIC_CHART_AXIS_POSITION_UNSPECIFIED",
             Position::BottomAxis => "BOTTOM_AXIS",
             Position::LeftAxis => "LEFT_AXIS",
             Position::RightAxis => "RIGHT_AXIS",
-            Position::Noop => "",
-            Position::FallthroughString => "*",
+            Position::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Position {
-    fn default() -> Position {
-        Position::Noop
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Position {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BASIC_CHART_AXIS_POSITION_UNSPECIFIED" => Position::BasicChartAxisPositionUnspecified,
+            "BOTTOM_AXIS" => Position::BottomAxis,
+            "LEFT_AXIS" => Position::LeftAxis,
+            "RIGHT_AXIS" => Position::RightAxis,
+            _ => Position::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Position {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Position {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Position::Noop)
+
+impl std::convert::From<Position> for String {
+    fn from(v: Position) -> Self {
+        match v {
+            Position::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -716,12 +857,8 @@ pub struct BasicChartAxis {
     /**
      * An axis of the chart. A chart may not have more than one axis per axis position.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /**
      * An axis of the chart. A chart may not have more than one axis per axis position.
      */
@@ -760,10 +897,85 @@ pub struct BasicChartDomain {
     pub reversed: bool,
 }
 
+/**
+ * The minor axis that will specify the range of values for this series. For example, if charting stocks over time, the "Volume" series may want to be pinned to the right with the prices pinned to the left, because the scale of trading volume is different than the scale of prices. It is an error to specify an axis that isn't a valid minor axis for the chart's type.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum TargetAxis {
+    #[serde(rename = "BASIC_CHART_AXIS_POSITION_UNSPECIFIED")]
+    BasicChartAxisPositionUnspecified,
+    #[serde(rename = "BOTTOM_AXIS")]
+    BottomAxis,
+    #[serde(rename = "LEFT_AXIS")]
+    LeftAxis,
+    #[serde(rename = "RIGHT_AXIS")]
+    RightAxis,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for TargetAxis {
+    fn as_ref(&self) -> &str {
+        match self {
+            TargetAxis::BasicChartAxisPositionUnspecified => {
+                "BASIC_CHART_AXIS_POSITION_UNSPECIFIED"
+            }
+            TargetAxis::BottomAxis => "BOTTOM_AXIS",
+            TargetAxis::LeftAxis => "LEFT_AXIS",
+            TargetAxis::RightAxis => "RIGHT_AXIS",
+            TargetAxis::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for TargetAxis {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for TargetAxis {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BASIC_CHART_AXIS_POSITION_UNSPECIFIED" => {
+                TargetAxis::BasicChartAxisPositionUnspecified
+            }
+            "BOTTOM_AXIS" => TargetAxis::BottomAxis,
+            "LEFT_AXIS" => TargetAxis::LeftAxis,
+            "RIGHT_AXIS" => TargetAxis::RightAxis,
+            _ => TargetAxis::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for TargetAxis {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<TargetAxis> for String {
+    fn from(v: TargetAxis) -> Self {
+        match v {
+            TargetAxis::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
 /**
  * The type of this series. Valid only if the chartType is COMBO. Different types will change the way the series is visualized. Only LINE, AREA, and COLUMN are supported.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "AREA")]
     Area,
@@ -781,15 +993,15 @@ pub enum Type {
     Scatter,
     #[serde(rename = "STEPPED_AREA")]
     SteppedArea,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Type {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        match self {
             Type::Area => "AREA",
             Type::Bar => "BAR",
             Type::BasicChartTypeUnspecified => "BASIC_CHART_TYPE_UNSPECIFIED",
@@ -798,21 +1010,50 @@ impl std::fmt::Display for Type {
             Type::Line => "LINE",
             Type::Scatter => "SCATTER",
             Type::SteppedArea => "STEPPED_AREA",
-            Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Type {
-    fn default() -> Type {
-        Type::Noop
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AREA" => Type::Area,
+            "BAR" => Type::Bar,
+            "BASIC_CHART_TYPE_UNSPECIFIED" => Type::BasicChartTypeUnspecified,
+            "COLUMN" => Type::Column,
+            "COMBO" => Type::Combo,
+            "LINE" => Type::Line,
+            "SCATTER" => Type::Scatter,
+            "STEPPED_AREA" => Type::SteppedArea,
+            _ => Type::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Type {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Type::Noop)
+
+impl std::convert::From<Type> for String {
+    fn from(v: Type) -> Self {
+        match v {
+            Type::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -875,7 +1116,7 @@ pub struct BasicChartSeries {
         skip_serializing_if = "Option::is_none",
         rename = "targetAxis"
     )]
-    pub target_axis: Option<Position>,
+    pub target_axis: Option<TargetAxis>,
     /**
      * A single series of data in a chart. For example, if charting stock prices over time, multiple series may exist, one for the "Open Price", "High Price", "Low Price" and "Close Price".
      */
@@ -883,10 +1124,97 @@ pub struct BasicChartSeries {
     pub type_: Option<Type>,
 }
 
+/**
+ * The type of the chart.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ChartType {
+    #[serde(rename = "AREA")]
+    Area,
+    #[serde(rename = "BAR")]
+    Bar,
+    #[serde(rename = "BASIC_CHART_TYPE_UNSPECIFIED")]
+    BasicChartTypeUnspecified,
+    #[serde(rename = "COLUMN")]
+    Column,
+    #[serde(rename = "COMBO")]
+    Combo,
+    #[serde(rename = "LINE")]
+    Line,
+    #[serde(rename = "SCATTER")]
+    Scatter,
+    #[serde(rename = "STEPPED_AREA")]
+    SteppedArea,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ChartType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ChartType::Area => "AREA",
+            ChartType::Bar => "BAR",
+            ChartType::BasicChartTypeUnspecified => "BASIC_CHART_TYPE_UNSPECIFIED",
+            ChartType::Column => "COLUMN",
+            ChartType::Combo => "COMBO",
+            ChartType::Line => "LINE",
+            ChartType::Scatter => "SCATTER",
+            ChartType::SteppedArea => "STEPPED_AREA",
+            ChartType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ChartType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ChartType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AREA" => ChartType::Area,
+            "BAR" => ChartType::Bar,
+            "BASIC_CHART_TYPE_UNSPECIFIED" => ChartType::BasicChartTypeUnspecified,
+            "COLUMN" => ChartType::Column,
+            "COMBO" => ChartType::Combo,
+            "LINE" => ChartType::Line,
+            "SCATTER" => ChartType::Scatter,
+            "STEPPED_AREA" => ChartType::SteppedArea,
+            _ => ChartType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ChartType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ChartType> for String {
+    fn from(v: ChartType) -> Self {
+        match v {
+            ChartType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
 /**
  * The behavior of tooltips and data highlighting when hovering on data and chart area.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CompareMode {
     #[serde(rename = "BASIC_CHART_COMPARE_MODE_UNSPECIFIED")]
     BasicChartCompareModeUnspecified,
@@ -894,33 +1222,57 @@ pub enum CompareMode {
     Category,
     #[serde(rename = "DATUM")]
     Datum,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for CompareMode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for CompareMode {
+    fn as_ref(&self) -> &str {
+        match self {
             CompareMode::BasicChartCompareModeUnspecified => "BASIC_CHART_COMPARE_MODE_UNSPECIFIED",
             CompareMode::Category => "CATEGORY",
             CompareMode::Datum => "DATUM",
-            CompareMode::Noop => "",
-            CompareMode::FallthroughString => "*",
+            CompareMode::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for CompareMode {
-    fn default() -> CompareMode {
-        CompareMode::Noop
+impl std::fmt::Display for CompareMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CompareMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BASIC_CHART_COMPARE_MODE_UNSPECIFIED" => CompareMode::BasicChartCompareModeUnspecified,
+            "CATEGORY" => CompareMode::Category,
+            "DATUM" => CompareMode::Datum,
+            _ => CompareMode::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CompareMode {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl CompareMode {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, CompareMode::Noop)
+
+impl std::convert::From<CompareMode> for String {
+    fn from(v: CompareMode) -> Self {
+        match v {
+            CompareMode::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -928,6 +1280,7 @@ impl CompareMode {
  * The position of the chart legend.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LegendPosition {
     #[serde(rename = "BASIC_CHART_LEGEND_POSITION_UNSPECIFIED")]
     BasicChartLegendPositionUnspecified,
@@ -941,15 +1294,15 @@ pub enum LegendPosition {
     RightLegend,
     #[serde(rename = "TOP_LEGEND")]
     TopLegend,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LegendPosition {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LegendPosition {
+    fn as_ref(&self) -> &str {
+        match self {
             LegendPosition::BasicChartLegendPositionUnspecified => {
                 "BASIC_CHART_LEGEND_POSITION_UNSPECIFIED"
             }
@@ -958,21 +1311,50 @@ impl std::fmt::Display for LegendPosition {
             LegendPosition::NoLegend => "NO_LEGEND",
             LegendPosition::RightLegend => "RIGHT_LEGEND",
             LegendPosition::TopLegend => "TOP_LEGEND",
-            LegendPosition::Noop => "",
-            LegendPosition::FallthroughString => "*",
+            LegendPosition::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LegendPosition {
-    fn default() -> LegendPosition {
-        LegendPosition::Noop
+impl std::fmt::Display for LegendPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LegendPosition {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BASIC_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                LegendPosition::BasicChartLegendPositionUnspecified
+            }
+            "BOTTOM_LEGEND" => LegendPosition::BottomLegend,
+            "LEFT_LEGEND" => LegendPosition::LeftLegend,
+            "NO_LEGEND" => LegendPosition::NoLegend,
+            "RIGHT_LEGEND" => LegendPosition::RightLegend,
+            "TOP_LEGEND" => LegendPosition::TopLegend,
+            _ => LegendPosition::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LegendPosition {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LegendPosition {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LegendPosition::Noop)
+
+impl std::convert::From<LegendPosition> for String {
+    fn from(v: LegendPosition) -> Self {
+        match v {
+            LegendPosition::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -980,6 +1362,7 @@ impl LegendPosition {
  * The stacked type for charts that support vertical stacking. Applies to Area, Bar, Column, Combo, and Stepped Area charts.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum StackedType {
     #[serde(rename = "BASIC_CHART_STACKED_TYPE_UNSPECIFIED")]
     BasicChartStackedTypeUnspecified,
@@ -989,44 +1372,69 @@ pub enum StackedType {
     PercentStacked,
     #[serde(rename = "STACKED")]
     Stacked,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for StackedType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for StackedType {
+    fn as_ref(&self) -> &str {
+        match self {
             StackedType::BasicChartStackedTypeUnspecified => "BASIC_CHART_STACKED_TYPE_UNSPECIFIED",
             StackedType::NotStacked => "NOT_STACKED",
             StackedType::PercentStacked => "PERCENT_STACKED",
             StackedType::Stacked => "STACKED",
-            StackedType::Noop => "",
-            StackedType::FallthroughString => "*",
+            StackedType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for StackedType {
-    fn default() -> StackedType {
-        StackedType::Noop
+impl std::fmt::Display for StackedType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl StackedType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, StackedType::Noop)
+
+impl std::str::FromStr for StackedType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BASIC_CHART_STACKED_TYPE_UNSPECIFIED" => StackedType::BasicChartStackedTypeUnspecified,
+            "NOT_STACKED" => StackedType::NotStacked,
+            "PERCENT_STACKED" => StackedType::PercentStacked,
+            "STACKED" => StackedType::Stacked,
+            _ => StackedType::Unknown(s.to_string()),
+        })
     }
 }
 
-/// The specification for a basic chart. See BasicChartType for the list of charts this supports.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BasicChartSpec {
-    /**
-     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
-     */
-    #[serde(
+impl std::convert::From<String> for StackedType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<StackedType> for String {
+    fn from(v: StackedType) -> Self {
+        match v {
+            StackedType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// The specification for a basic chart. See BasicChartType for the list of charts this supports.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BasicChartSpec {
+    /**
+     * The specification for a basic chart. See BasicChartType for the list of charts this supports.
+     */
+    #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
@@ -1036,7 +1444,7 @@ pub struct BasicChartSpec {
      * The specification for a basic chart. See BasicChartType for the list of charts this supports.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "chartType")]
-    pub chart_type: Option<Type>,
+    pub chart_type: Option<ChartType>,
     /**
      * The specification for a basic chart. See BasicChartType for the list of charts this supports.
      */
@@ -1136,8 +1544,12 @@ pub struct BasicFilter {
     /**
      * The default filter associated with a sheet.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub criteria: Option<FilterCriteria>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub criteria: std::collections::BTreeMap<String, FilterCriteria>,
     /**
      * The default filter associated with a sheet.
      */
@@ -1202,598 +1614,392 @@ pub struct BasicSeriesDataPointStyleOverride {
     pub point_style: Option<PointStyle>,
 }
 
-/// The request for clearing more than one range selected by a DataFilter in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchClearValuesByDataFilterRequest {
-    /**
-     * The request for clearing more than one range selected by a DataFilter in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataFilters"
-    )]
-    pub data_filters: Vec<DataFilter>,
-}
-
-/// The response when clearing a range of values selected with DataFilters in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchClearValuesByDataFilterResponse {
-    /**
-     * The response when clearing a range of values selected with DataFilters in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "clearedRanges"
-    )]
-    pub cleared_ranges: Vec<String>,
-    /**
-     * The response when clearing a range of values selected with DataFilters in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
-    )]
-    pub spreadsheet_id: String,
-}
-
-/// The request for clearing more than one range of values in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchClearValuesRequest {
-    /**
-     * The request for clearing more than one range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ranges: Vec<String>,
-}
-
-/// The response when clearing a range of values in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchClearValuesResponse {
-    /**
-     * The response when clearing a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "clearedRanges"
-    )]
-    pub cleared_ranges: Vec<String>,
-    /**
-     * The response when clearing a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
-    )]
-    pub spreadsheet_id: String,
-}
-
 /**
  * How dates, times, and durations should be represented in the output. This is ignored if value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateTimeRenderOption {
     #[serde(rename = "FORMATTED_STRING")]
     FormattedString,
     #[serde(rename = "SERIAL_NUMBER")]
     SerialNumber,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DateTimeRenderOption {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DateTimeRenderOption {
+    fn as_ref(&self) -> &str {
+        match self {
             DateTimeRenderOption::FormattedString => "FORMATTED_STRING",
             DateTimeRenderOption::SerialNumber => "SERIAL_NUMBER",
-            DateTimeRenderOption::Noop => "",
-            DateTimeRenderOption::FallthroughString => "*",
+            DateTimeRenderOption::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DateTimeRenderOption {
-    fn default() -> DateTimeRenderOption {
-        DateTimeRenderOption::Noop
-    }
-}
-impl DateTimeRenderOption {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DateTimeRenderOption::Noop)
+impl std::fmt::Display for DateTimeRenderOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-/**
- * How values should be represented in the output. The default render option is FORMATTED_VALUE.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ValueRenderOption {
-    #[serde(rename = "FORMATTED_VALUE")]
-    FormattedValue,
-    #[serde(rename = "FORMULA")]
-    Formula,
-    #[serde(rename = "UNFORMATTED_VALUE")]
-    UnformattedValue,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
+impl std::str::FromStr for DateTimeRenderOption {
+    type Err = std::convert::Infallible;
 
-impl std::fmt::Display for ValueRenderOption {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ValueRenderOption::FormattedValue => "FORMATTED_VALUE",
-            ValueRenderOption::Formula => "FORMULA",
-            ValueRenderOption::UnformattedValue => "UNFORMATTED_VALUE",
-            ValueRenderOption::Noop => "",
-            ValueRenderOption::FallthroughString => "*",
-        }
-        .fmt(f)
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "FORMATTED_STRING" => DateTimeRenderOption::FormattedString,
+            "SERIAL_NUMBER" => DateTimeRenderOption::SerialNumber,
+            _ => DateTimeRenderOption::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for ValueRenderOption {
-    fn default() -> ValueRenderOption {
-        ValueRenderOption::Noop
+impl std::convert::From<String> for DateTimeRenderOption {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ValueRenderOption {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ValueRenderOption::Noop)
+
+impl std::convert::From<DateTimeRenderOption> for String {
+    fn from(v: DateTimeRenderOption) -> Self {
+        match v {
+            DateTimeRenderOption::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
-/// The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
+/**
+ * The major dimension that results should use. For example, if the spreadsheet data is: `A1=1,B1=2,A2=3,B2=4`, then requesting `range=A1:B2,majorDimension=ROWS` returns `[[1,2],[3,4]]`, whereas requesting `range=A1:B2,majorDimension=COLUMNS` returns `[[1,3],[2,4]]`.
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchGetValuesByDataFilterRequest {
-    /**
-     * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataFilters"
-    )]
-    pub data_filters: Vec<DataFilter>,
-    /**
-     * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "dateTimeRenderOption"
-    )]
-    pub date_time_render_option: Option<DateTimeRenderOption>,
-    /**
-     * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "majorDimension"
-    )]
-    pub major_dimension: Option<Dimension>,
+#[serde(from = "String", into = "String")]
+pub enum MajorDimension {
+    #[serde(rename = "COLUMNS")]
+    Columns,
+    #[serde(rename = "DIMENSION_UNSPECIFIED")]
+    DimensionUnspecified,
+    #[serde(rename = "ROWS")]
+    Rows,
     /**
-     * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "valueRenderOption"
-    )]
-    pub value_render_option: Option<ValueRenderOption>,
+    Unknown(String),
 }
 
-/// The response when retrieving more than one range of values in a spreadsheet selected by DataFilters.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchGetValuesByDataFilterResponse {
-    /**
-     * The response when retrieving more than one range of values in a spreadsheet selected by DataFilters.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
-    )]
-    pub spreadsheet_id: String,
-    /**
-     * The response when retrieving more than one range of values in a spreadsheet selected by DataFilters.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "valueRanges"
-    )]
-    pub value_ranges: Vec<MatchedValueRange>,
+impl AsRef<str> for MajorDimension {
+    fn as_ref(&self) -> &str {
+        match self {
+            MajorDimension::Columns => "COLUMNS",
+            MajorDimension::DimensionUnspecified => "DIMENSION_UNSPECIFIED",
+            MajorDimension::Rows => "ROWS",
+            MajorDimension::Unknown(s) => s,
+        }
+    }
 }
 
-/// The response when retrieving more than one range of values in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchGetValuesResponse {
-    /**
-     * The response when retrieving more than one range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
-    )]
-    pub spreadsheet_id: String,
-    /**
-     * The response when retrieving more than one range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "valueRanges"
-    )]
-    pub value_ranges: Vec<ValueRange>,
+impl std::fmt::Display for MajorDimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-/// The request for updating any aspect of a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchUpdateSpreadsheetRequest {
-    /**
-     * The request for updating any aspect of a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "includeSpreadsheetInResponse"
-    )]
-    pub include_spreadsheet_in_response: Option<bool>,
-    /**
-     * The request for updating any aspect of a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub requests: Vec<Request>,
-    /**
-     * The request for updating any aspect of a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "responseIncludeGridData"
-    )]
-    pub response_include_grid_data: Option<bool>,
-    /**
-     * The request for updating any aspect of a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "responseRanges"
-    )]
-    pub response_ranges: Vec<String>,
+impl std::str::FromStr for MajorDimension {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COLUMNS" => MajorDimension::Columns,
+            "DIMENSION_UNSPECIFIED" => MajorDimension::DimensionUnspecified,
+            "ROWS" => MajorDimension::Rows,
+            _ => MajorDimension::Unknown(s.to_string()),
+        })
+    }
 }
 
-/// The reply for batch updating a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchUpdateSpreadsheetResponse {
-    /**
-     * The reply for batch updating a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub replies: Vec<Response>,
-    /**
-     * The reply for batch updating a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
-    )]
-    pub spreadsheet_id: String,
-    /**
-     * The reply for batch updating a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "updatedSpreadsheet"
-    )]
-    pub updated_spreadsheet: Option<Spreadsheet>,
+impl std::convert::From<String> for MajorDimension {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<MajorDimension> for String {
+    fn from(v: MajorDimension) -> Self {
+        match v {
+            MajorDimension::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 /**
- * How the input data should be interpreted.
+ * How values should be represented in the output. The default render option is ValueRenderOption.FORMATTED_VALUE.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ValueInputOption {
-    #[serde(rename = "INPUT_VALUE_OPTION_UNSPECIFIED")]
-    InputValueOptionUnspecified,
-    #[serde(rename = "RAW")]
-    Raw,
-    #[serde(rename = "USER_ENTERED")]
-    UserEntered,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum ValueRenderOption {
+    #[serde(rename = "FORMATTED_VALUE")]
+    FormattedValue,
+    #[serde(rename = "FORMULA")]
+    Formula,
+    #[serde(rename = "UNFORMATTED_VALUE")]
+    UnformattedValue,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ValueInputOption {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ValueInputOption::InputValueOptionUnspecified => "INPUT_VALUE_OPTION_UNSPECIFIED",
-            ValueInputOption::Raw => "RAW",
-            ValueInputOption::UserEntered => "USER_ENTERED",
-            ValueInputOption::Noop => "",
-            ValueInputOption::FallthroughString => "*",
+impl AsRef<str> for ValueRenderOption {
+    fn as_ref(&self) -> &str {
+        match self {
+            ValueRenderOption::FormattedValue => "FORMATTED_VALUE",
+            ValueRenderOption::Formula => "FORMULA",
+            ValueRenderOption::UnformattedValue => "UNFORMATTED_VALUE",
+            ValueRenderOption::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ValueInputOption {
-    fn default() -> ValueInputOption {
-        ValueInputOption::Noop
+impl std::fmt::Display for ValueRenderOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl ValueInputOption {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ValueInputOption::Noop)
+
+impl std::str::FromStr for ValueRenderOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "FORMATTED_VALUE" => ValueRenderOption::FormattedValue,
+            "FORMULA" => ValueRenderOption::Formula,
+            "UNFORMATTED_VALUE" => ValueRenderOption::UnformattedValue,
+            _ => ValueRenderOption::Unknown(s.to_string()),
+        })
     }
 }
 
-/// The request for updating more than one range of values in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchUpdateValuesByDataFilterRequest {
-    /**
-     * The request for updating more than one range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub data: Vec<DataFilterValueRange>,
-    /**
-     * The request for updating more than one range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "includeValuesInResponse"
-    )]
-    pub include_values_in_response: Option<bool>,
-    /**
-     * The request for updating more than one range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "responseDateTimeRenderOption"
-    )]
-    pub response_date_time_render_option: Option<DateTimeRenderOption>,
-    /**
-     * The request for updating more than one range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "responseValueRenderOption"
-    )]
-    pub response_value_render_option: Option<ValueRenderOption>,
-    /**
-     * The request for updating more than one range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "valueInputOption"
-    )]
-    pub value_input_option: Option<ValueInputOption>,
+impl std::convert::From<String> for ValueRenderOption {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-/// The response when updating a range of values in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchUpdateValuesByDataFilterResponse {
-    /**
-     * The response when updating a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub responses: Vec<UpdateValuesByDataFilterResponse>,
-    /**
-     * The response when updating a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
-    )]
-    pub spreadsheet_id: String,
-    /**
-     * The response when updating a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedCells"
-    )]
-    pub total_updated_cells: i64,
-    /**
-     * The response when updating a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedColumns"
-    )]
-    pub total_updated_columns: i64,
-    /**
-     * The response when updating a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedRows"
-    )]
-    pub total_updated_rows: i64,
-    /**
-     * The response when updating a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedSheets"
-    )]
-    pub total_updated_sheets: i64,
+impl std::convert::From<ValueRenderOption> for String {
+    fn from(v: ValueRenderOption) -> Self {
+        match v {
+            ValueRenderOption::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
-/// The request for updating more than one range of values in a spreadsheet.
+/**
+ * Determines how dates, times, and durations in the response should be rendered. This is ignored if response_value_render_option is FORMATTED_VALUE. The default dateTime render option is SERIAL_NUMBER.
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchUpdateValuesRequest {
-    /**
-     * The request for updating more than one range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub data: Vec<ValueRange>,
-    /**
-     * The request for updating more than one range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "includeValuesInResponse"
-    )]
-    pub include_values_in_response: Option<bool>,
-    /**
-     * The request for updating more than one range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "responseDateTimeRenderOption"
-    )]
-    pub response_date_time_render_option: Option<DateTimeRenderOption>,
-    /**
-     * The request for updating more than one range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "responseValueRenderOption"
-    )]
-    pub response_value_render_option: Option<ValueRenderOption>,
+#[serde(from = "String", into = "String")]
+pub enum ResponseDateTimeRenderOption {
+    #[serde(rename = "FORMATTED_STRING")]
+    FormattedString,
+    #[serde(rename = "SERIAL_NUMBER")]
+    SerialNumber,
     /**
-     * The request for updating more than one range of values in a spreadsheet.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "valueInputOption"
-    )]
-    pub value_input_option: Option<ValueInputOption>,
+    Unknown(String),
 }
 
-/// The response when updating a range of values in a spreadsheet.
+impl AsRef<str> for ResponseDateTimeRenderOption {
+    fn as_ref(&self) -> &str {
+        match self {
+            ResponseDateTimeRenderOption::FormattedString => "FORMATTED_STRING",
+            ResponseDateTimeRenderOption::SerialNumber => "SERIAL_NUMBER",
+            ResponseDateTimeRenderOption::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ResponseDateTimeRenderOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ResponseDateTimeRenderOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "FORMATTED_STRING" => ResponseDateTimeRenderOption::FormattedString,
+            "SERIAL_NUMBER" => ResponseDateTimeRenderOption::SerialNumber,
+            _ => ResponseDateTimeRenderOption::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ResponseDateTimeRenderOption {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ResponseDateTimeRenderOption> for String {
+    fn from(v: ResponseDateTimeRenderOption) -> Self {
+        match v {
+            ResponseDateTimeRenderOption::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Determines how values in the response should be rendered. The default render option is FORMATTED_VALUE.
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BatchUpdateValuesResponse {
-    /**
-     * The response when updating a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub responses: Vec<UpdateValuesResponse>,
-    /**
-     * The response when updating a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
-    )]
-    pub spreadsheet_id: String,
-    /**
-     * The response when updating a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedCells"
-    )]
-    pub total_updated_cells: i64,
-    /**
-     * The response when updating a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedColumns"
-    )]
-    pub total_updated_columns: i64,
+#[serde(from = "String", into = "String")]
+pub enum ResponseValueRenderOption {
+    #[serde(rename = "FORMATTED_VALUE")]
+    FormattedValue,
+    #[serde(rename = "FORMULA")]
+    Formula,
+    #[serde(rename = "UNFORMATTED_VALUE")]
+    UnformattedValue,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedRows"
-    )]
-    pub total_updated_rows: i64,
+    Unknown(String),
+}
+
+impl AsRef<str> for ResponseValueRenderOption {
+    fn as_ref(&self) -> &str {
+        match self {
+            ResponseValueRenderOption::FormattedValue => "FORMATTED_VALUE",
+            ResponseValueRenderOption::Formula => "FORMULA",
+            ResponseValueRenderOption::UnformattedValue => "UNFORMATTED_VALUE",
+            ResponseValueRenderOption::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ResponseValueRenderOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ResponseValueRenderOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "FORMATTED_VALUE" => ResponseValueRenderOption::FormattedValue,
+            "FORMULA" => ResponseValueRenderOption::Formula,
+            "UNFORMATTED_VALUE" => ResponseValueRenderOption::UnformattedValue,
+            _ => ResponseValueRenderOption::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ResponseValueRenderOption {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ResponseValueRenderOption> for String {
+    fn from(v: ResponseValueRenderOption) -> Self {
+        match v {
+            ResponseValueRenderOption::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * How the input data should be interpreted.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ValueInputOption {
+    #[serde(rename = "INPUT_VALUE_OPTION_UNSPECIFIED")]
+    InputValueOptionUnspecified,
+    #[serde(rename = "RAW")]
+    Raw,
+    #[serde(rename = "USER_ENTERED")]
+    UserEntered,
     /**
-     * The response when updating a range of values in a spreadsheet.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "totalUpdatedSheets"
-    )]
-    pub total_updated_sheets: i64,
+    Unknown(String),
+}
+
+impl AsRef<str> for ValueInputOption {
+    fn as_ref(&self) -> &str {
+        match self {
+            ValueInputOption::InputValueOptionUnspecified => "INPUT_VALUE_OPTION_UNSPECIFIED",
+            ValueInputOption::Raw => "RAW",
+            ValueInputOption::UserEntered => "USER_ENTERED",
+            ValueInputOption::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ValueInputOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ValueInputOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "INPUT_VALUE_OPTION_UNSPECIFIED" => ValueInputOption::InputValueOptionUnspecified,
+            "RAW" => ValueInputOption::Raw,
+            "USER_ENTERED" => ValueInputOption::UserEntered,
+            _ => ValueInputOption::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ValueInputOption {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ValueInputOption> for String {
+    fn from(v: ValueInputOption) -> Self {
+        match v {
+            ValueInputOption::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 /// The specification of a BigQuery data source that's connected to a sheet.
@@ -1802,13 +2008,8 @@ pub struct BigQueryDataSourceSpec {
     /**
      * The specification of a BigQuery data source that's connected to a sheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "projectId"
-    )]
-    pub project_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "projectId")]
+    pub project_id: Option<String>,
     /**
      * The specification of a BigQuery data source that's connected to a sheet.
      */
@@ -1827,13 +2028,8 @@ pub struct BigQuerySpec {
     /**
      * Specifies a custom BigQuery query.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "rawQuery"
-    )]
-    pub raw_query: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "rawQuery")]
+    pub raw_query: Option<String>,
 }
 
 /// Specifies a BigQuery table definition. Only [native tables](https://cloud.google.com/bigquery/docs/tables-intro) is allowed.
@@ -1842,39 +2038,29 @@ pub struct BigQueryTableSpec {
     /**
      * Specifies a BigQuery table definition. Only [native tables](https://cloud.google.com/bigquery/docs/tables-intro) is allowed.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "datasetId"
-    )]
-    pub dataset_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "datasetId")]
+    pub dataset_id: Option<String>,
     /**
      * Specifies a BigQuery table definition. Only [native tables](https://cloud.google.com/bigquery/docs/tables-intro) is allowed.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tableId"
-    )]
-    pub table_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "tableId")]
+    pub table_id: Option<String>,
     /**
      * Specifies a BigQuery table definition. Only [native tables](https://cloud.google.com/bigquery/docs/tables-intro) is allowed.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "tableProjectId"
     )]
-    pub table_project_id: String,
+    pub table_project_id: Option<String>,
 }
 
 /**
  * The type of condition.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BooleanConditionType {
     #[serde(rename = "BLANK")]
     Blank,
@@ -1940,15 +2126,15 @@ pub enum BooleanConditionType {
     TextNotEq,
     #[serde(rename = "TEXT_STARTS_WITH")]
     TextStartsWith,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for BooleanConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for BooleanConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             BooleanConditionType::Blank => "BLANK",
             BooleanConditionType::Boolean => "BOOLEAN",
             BooleanConditionType::ConditionTypeUnspecified => "CONDITION_TYPE_UNSPECIFIED",
@@ -1981,21 +2167,74 @@ impl std::fmt::Display for BooleanConditionType {
             BooleanConditionType::TextNotContains => "TEXT_NOT_CONTAINS",
             BooleanConditionType::TextNotEq => "TEXT_NOT_EQ",
             BooleanConditionType::TextStartsWith => "TEXT_STARTS_WITH",
-            BooleanConditionType::Noop => "",
-            BooleanConditionType::FallthroughString => "*",
+            BooleanConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for BooleanConditionType {
-    fn default() -> BooleanConditionType {
-        BooleanConditionType::Noop
+impl std::fmt::Display for BooleanConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for BooleanConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BLANK" => BooleanConditionType::Blank,
+            "BOOLEAN" => BooleanConditionType::Boolean,
+            "CONDITION_TYPE_UNSPECIFIED" => BooleanConditionType::ConditionTypeUnspecified,
+            "CUSTOM_FORMULA" => BooleanConditionType::CustomFormula,
+            "DATE_AFTER" => BooleanConditionType::DateAfter,
+            "DATE_BEFORE" => BooleanConditionType::DateBefore,
+            "DATE_BETWEEN" => BooleanConditionType::DateBetween,
+            "DATE_EQ" => BooleanConditionType::DateEq,
+            "DATE_IS_VALID" => BooleanConditionType::DateIsValid,
+            "DATE_NOT_BETWEEN" => BooleanConditionType::DateNotBetween,
+            "DATE_NOT_EQ" => BooleanConditionType::DateNotEq,
+            "DATE_ON_OR_AFTER" => BooleanConditionType::DateOnOrAfter,
+            "DATE_ON_OR_BEFORE" => BooleanConditionType::DateOnOrBefore,
+            "NOT_BLANK" => BooleanConditionType::NotBlank,
+            "NUMBER_BETWEEN" => BooleanConditionType::NumberBetween,
+            "NUMBER_EQ" => BooleanConditionType::NumberEq,
+            "NUMBER_GREATER" => BooleanConditionType::NumberGreater,
+            "NUMBER_GREATER_THAN_EQ" => BooleanConditionType::NumberGreaterThanEq,
+            "NUMBER_LESS" => BooleanConditionType::NumberLess,
+            "NUMBER_LESS_THAN_EQ" => BooleanConditionType::NumberLessThanEq,
+            "NUMBER_NOT_BETWEEN" => BooleanConditionType::NumberNotBetween,
+            "NUMBER_NOT_EQ" => BooleanConditionType::NumberNotEq,
+            "ONE_OF_LIST" => BooleanConditionType::OneOfList,
+            "ONE_OF_RANGE" => BooleanConditionType::OneOfRange,
+            "TEXT_CONTAINS" => BooleanConditionType::TextContains,
+            "TEXT_ENDS_WITH" => BooleanConditionType::TextEndsWith,
+            "TEXT_EQ" => BooleanConditionType::TextEq,
+            "TEXT_IS_EMAIL" => BooleanConditionType::TextIsEmail,
+            "TEXT_IS_URL" => BooleanConditionType::TextIsUrl,
+            "TEXT_NOT_CONTAINS" => BooleanConditionType::TextNotContains,
+            "TEXT_NOT_EQ" => BooleanConditionType::TextNotEq,
+            "TEXT_STARTS_WITH" => BooleanConditionType::TextStartsWith,
+            _ => BooleanConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for BooleanConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl BooleanConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, BooleanConditionType::Noop)
+
+impl std::convert::From<BooleanConditionType> for String {
+    fn from(v: BooleanConditionType) -> Self {
+        match v {
+            BooleanConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2037,6 +2276,7 @@ pub struct BooleanRule {
  * The style of the border.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Style {
     #[serde(rename = "DASHED")]
     Dashed,
@@ -2054,15 +2294,15 @@ pub enum Style {
     SolidThick,
     #[serde(rename = "STYLE_UNSPECIFIED")]
     StyleUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Style {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Style {
+    fn as_ref(&self) -> &str {
+        match self {
             Style::Dashed => "DASHED",
             Style::Dotted => "DOTTED",
             Style::Double => "DOUBLE",
@@ -2071,21 +2311,50 @@ impl std::fmt::Display for Style {
             Style::SolidMedium => "SOLID_MEDIUM",
             Style::SolidThick => "SOLID_THICK",
             Style::StyleUnspecified => "STYLE_UNSPECIFIED",
-            Style::Noop => "",
-            Style::FallthroughString => "*",
+            Style::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Style {
-    fn default() -> Style {
-        Style::Noop
+impl std::fmt::Display for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Style {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DASHED" => Style::Dashed,
+            "DOTTED" => Style::Dotted,
+            "DOUBLE" => Style::Double,
+            "NONE" => Style::None,
+            "SOLID" => Style::Solid,
+            "SOLID_MEDIUM" => Style::SolidMedium,
+            "SOLID_THICK" => Style::SolidThick,
+            "STYLE_UNSPECIFIED" => Style::StyleUnspecified,
+            _ => Style::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Style {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Style {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Style::Noop)
+
+impl std::convert::From<Style> for String {
+    fn from(v: Style) -> Self {
+        match v {
+            Style::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2151,6 +2420,7 @@ pub struct Borders {
  * Where the legend of the chart should be drawn.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BubbleChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -2166,15 +2436,15 @@ pub enum BubbleChartSpecLegendPosition {
     RightLegend,
     #[serde(rename = "TOP_LEGEND")]
     TopLegend,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for BubbleChartSpecLegendPosition {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for BubbleChartSpecLegendPosition {
+    fn as_ref(&self) -> &str {
+        match self {
             BubbleChartSpecLegendPosition::BottomLegend => "BOTTOM_LEGEND",
             BubbleChartSpecLegendPosition::BubbleChartLegendPositionUnspecified => {
                 "BUBBLE_CHART_LEGEND_POSITION_UNSPECIFIED"
@@ -2184,21 +2454,51 @@ impl std::fmt::Display for BubbleChartSpecLegendPosition {
             BubbleChartSpecLegendPosition::NoLegend => "NO_LEGEND",
             BubbleChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             BubbleChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
-            BubbleChartSpecLegendPosition::Noop => "",
-            BubbleChartSpecLegendPosition::FallthroughString => "*",
+            BubbleChartSpecLegendPosition::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for BubbleChartSpecLegendPosition {
-    fn default() -> BubbleChartSpecLegendPosition {
-        BubbleChartSpecLegendPosition::Noop
+impl std::fmt::Display for BubbleChartSpecLegendPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for BubbleChartSpecLegendPosition {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BOTTOM_LEGEND" => BubbleChartSpecLegendPosition::BottomLegend,
+            "BUBBLE_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                BubbleChartSpecLegendPosition::BubbleChartLegendPositionUnspecified
+            }
+            "INSIDE_LEGEND" => BubbleChartSpecLegendPosition::InsideLegend,
+            "LEFT_LEGEND" => BubbleChartSpecLegendPosition::LeftLegend,
+            "NO_LEGEND" => BubbleChartSpecLegendPosition::NoLegend,
+            "RIGHT_LEGEND" => BubbleChartSpecLegendPosition::RightLegend,
+            "TOP_LEGEND" => BubbleChartSpecLegendPosition::TopLegend,
+            _ => BubbleChartSpecLegendPosition::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for BubbleChartSpecLegendPosition {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl BubbleChartSpecLegendPosition {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, BubbleChartSpecLegendPosition::Noop)
+
+impl std::convert::From<BubbleChartSpecLegendPosition> for String {
+    fn from(v: BubbleChartSpecLegendPosition) -> Self {
+        match v {
+            BubbleChartSpecLegendPosition::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2443,29 +2743,20 @@ pub struct CellData {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "formattedValue"
     )]
-    pub formatted_value: String,
+    pub formatted_value: Option<String>,
     /**
      * Data about a specific cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub hyperlink: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hyperlink: Option<String>,
     /**
      * Data about a specific cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub note: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
     /**
      * Data about a specific cell.
      */
@@ -2506,9 +2797,10 @@ pub struct CellData {
 }
 
 /**
- * The horizontal alignment of the value in the cell.
+ * Horizontal alignment setting for the piece of text.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum HorizontalAlignment {
     #[serde(rename = "CENTER")]
     Center,
@@ -2518,34 +2810,59 @@ pub enum HorizontalAlignment {
     Left,
     #[serde(rename = "RIGHT")]
     Right,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for HorizontalAlignment {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for HorizontalAlignment {
+    fn as_ref(&self) -> &str {
+        match self {
             HorizontalAlignment::Center => "CENTER",
             HorizontalAlignment::HorizontalAlignUnspecified => "HORIZONTAL_ALIGN_UNSPECIFIED",
             HorizontalAlignment::Left => "LEFT",
             HorizontalAlignment::Right => "RIGHT",
-            HorizontalAlignment::Noop => "",
-            HorizontalAlignment::FallthroughString => "*",
+            HorizontalAlignment::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for HorizontalAlignment {
-    fn default() -> HorizontalAlignment {
-        HorizontalAlignment::Noop
+impl std::fmt::Display for HorizontalAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for HorizontalAlignment {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CENTER" => HorizontalAlignment::Center,
+            "HORIZONTAL_ALIGN_UNSPECIFIED" => HorizontalAlignment::HorizontalAlignUnspecified,
+            "LEFT" => HorizontalAlignment::Left,
+            "RIGHT" => HorizontalAlignment::Right,
+            _ => HorizontalAlignment::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for HorizontalAlignment {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl HorizontalAlignment {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, HorizontalAlignment::Noop)
+
+impl std::convert::From<HorizontalAlignment> for String {
+    fn from(v: HorizontalAlignment) -> Self {
+        match v {
+            HorizontalAlignment::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2553,6 +2870,7 @@ impl HorizontalAlignment {
  * How a hyperlink, if it exists, should be displayed in the cell.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum HyperlinkDisplayType {
     #[serde(rename = "HYPERLINK_DISPLAY_TYPE_UNSPECIFIED")]
     HyperlinkDisplayTypeUnspecified,
@@ -2560,35 +2878,61 @@ pub enum HyperlinkDisplayType {
     Linked,
     #[serde(rename = "PLAIN_TEXT")]
     PlainText,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for HyperlinkDisplayType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for HyperlinkDisplayType {
+    fn as_ref(&self) -> &str {
+        match self {
             HyperlinkDisplayType::HyperlinkDisplayTypeUnspecified => {
                 "HYPERLINK_DISPLAY_TYPE_UNSPECIFIED"
             }
             HyperlinkDisplayType::Linked => "LINKED",
             HyperlinkDisplayType::PlainText => "PLAIN_TEXT",
-            HyperlinkDisplayType::Noop => "",
-            HyperlinkDisplayType::FallthroughString => "*",
+            HyperlinkDisplayType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for HyperlinkDisplayType {
-    fn default() -> HyperlinkDisplayType {
-        HyperlinkDisplayType::Noop
+impl std::fmt::Display for HyperlinkDisplayType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for HyperlinkDisplayType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "HYPERLINK_DISPLAY_TYPE_UNSPECIFIED" => {
+                HyperlinkDisplayType::HyperlinkDisplayTypeUnspecified
+            }
+            "LINKED" => HyperlinkDisplayType::Linked,
+            "PLAIN_TEXT" => HyperlinkDisplayType::PlainText,
+            _ => HyperlinkDisplayType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for HyperlinkDisplayType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl HyperlinkDisplayType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, HyperlinkDisplayType::Noop)
+
+impl std::convert::From<HyperlinkDisplayType> for String {
+    fn from(v: HyperlinkDisplayType) -> Self {
+        match v {
+            HyperlinkDisplayType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2596,6 +2940,7 @@ impl HyperlinkDisplayType {
  * The direction of the text in the cell.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TextDirection {
     #[serde(rename = "LEFT_TO_RIGHT")]
     LeftToRight,
@@ -2603,33 +2948,57 @@ pub enum TextDirection {
     RightToLeft,
     #[serde(rename = "TEXT_DIRECTION_UNSPECIFIED")]
     TextDirectionUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for TextDirection {
+    fn as_ref(&self) -> &str {
+        match self {
+            TextDirection::LeftToRight => "LEFT_TO_RIGHT",
+            TextDirection::RightToLeft => "RIGHT_TO_LEFT",
+            TextDirection::TextDirectionUnspecified => "TEXT_DIRECTION_UNSPECIFIED",
+            TextDirection::Unknown(s) => s,
+        }
+    }
 }
 
 impl std::fmt::Display for TextDirection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            TextDirection::LeftToRight => "LEFT_TO_RIGHT",
-            TextDirection::RightToLeft => "RIGHT_TO_LEFT",
-            TextDirection::TextDirectionUnspecified => "TEXT_DIRECTION_UNSPECIFIED",
-            TextDirection::Noop => "",
-            TextDirection::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for TextDirection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "LEFT_TO_RIGHT" => TextDirection::LeftToRight,
+            "RIGHT_TO_LEFT" => TextDirection::RightToLeft,
+            "TEXT_DIRECTION_UNSPECIFIED" => TextDirection::TextDirectionUnspecified,
+            _ => TextDirection::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for TextDirection {
-    fn default() -> TextDirection {
-        TextDirection::Noop
+impl std::convert::From<String> for TextDirection {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl TextDirection {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, TextDirection::Noop)
+
+impl std::convert::From<TextDirection> for String {
+    fn from(v: TextDirection) -> Self {
+        match v {
+            TextDirection::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2637,6 +3006,7 @@ impl TextDirection {
  * The vertical alignment of the value in the cell.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum VerticalAlignment {
     #[serde(rename = "BOTTOM")]
     Bottom,
@@ -2646,34 +3016,59 @@ pub enum VerticalAlignment {
     Top,
     #[serde(rename = "VERTICAL_ALIGN_UNSPECIFIED")]
     VerticalAlignUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for VerticalAlignment {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for VerticalAlignment {
+    fn as_ref(&self) -> &str {
+        match self {
             VerticalAlignment::Bottom => "BOTTOM",
             VerticalAlignment::Middle => "MIDDLE",
             VerticalAlignment::Top => "TOP",
             VerticalAlignment::VerticalAlignUnspecified => "VERTICAL_ALIGN_UNSPECIFIED",
-            VerticalAlignment::Noop => "",
-            VerticalAlignment::FallthroughString => "*",
+            VerticalAlignment::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for VerticalAlignment {
-    fn default() -> VerticalAlignment {
-        VerticalAlignment::Noop
+impl std::fmt::Display for VerticalAlignment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for VerticalAlignment {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BOTTOM" => VerticalAlignment::Bottom,
+            "MIDDLE" => VerticalAlignment::Middle,
+            "TOP" => VerticalAlignment::Top,
+            "VERTICAL_ALIGN_UNSPECIFIED" => VerticalAlignment::VerticalAlignUnspecified,
+            _ => VerticalAlignment::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for VerticalAlignment {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl VerticalAlignment {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, VerticalAlignment::Noop)
+
+impl std::convert::From<VerticalAlignment> for String {
+    fn from(v: VerticalAlignment) -> Self {
+        match v {
+            VerticalAlignment::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2681,6 +3076,7 @@ impl VerticalAlignment {
  * The wrap strategy for the value in the cell.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WrapStrategy {
     #[serde(rename = "CLIP")]
     Clip,
@@ -2692,35 +3088,61 @@ pub enum WrapStrategy {
     Wrap,
     #[serde(rename = "WRAP_STRATEGY_UNSPECIFIED")]
     WrapStrategyUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for WrapStrategy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for WrapStrategy {
+    fn as_ref(&self) -> &str {
+        match self {
             WrapStrategy::Clip => "CLIP",
             WrapStrategy::LegacyWrap => "LEGACY_WRAP",
             WrapStrategy::OverflowCell => "OVERFLOW_CELL",
             WrapStrategy::Wrap => "WRAP",
             WrapStrategy::WrapStrategyUnspecified => "WRAP_STRATEGY_UNSPECIFIED",
-            WrapStrategy::Noop => "",
-            WrapStrategy::FallthroughString => "*",
+            WrapStrategy::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for WrapStrategy {
-    fn default() -> WrapStrategy {
-        WrapStrategy::Noop
+impl std::fmt::Display for WrapStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for WrapStrategy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CLIP" => WrapStrategy::Clip,
+            "LEGACY_WRAP" => WrapStrategy::LegacyWrap,
+            "OVERFLOW_CELL" => WrapStrategy::OverflowCell,
+            "WRAP" => WrapStrategy::Wrap,
+            "WRAP_STRATEGY_UNSPECIFIED" => WrapStrategy::WrapStrategyUnspecified,
+            _ => WrapStrategy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for WrapStrategy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl WrapStrategy {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, WrapStrategy::Noop)
+
+impl std::convert::From<WrapStrategy> for String {
+    fn from(v: WrapStrategy) -> Self {
+        match v {
+            WrapStrategy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2833,6 +3255,7 @@ pub struct CellFormat {
  * The view window's mode.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ViewWindowMode {
     #[serde(rename = "DEFAULT_VIEW_WINDOW_MODE")]
     DefaultViewWindowMode,
@@ -2842,34 +3265,59 @@ pub enum ViewWindowMode {
     Pretty,
     #[serde(rename = "VIEW_WINDOW_MODE_UNSUPPORTED")]
     ViewWindowModeUnsupported,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ViewWindowMode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ViewWindowMode {
+    fn as_ref(&self) -> &str {
+        match self {
             ViewWindowMode::DefaultViewWindowMode => "DEFAULT_VIEW_WINDOW_MODE",
             ViewWindowMode::Explicit => "EXPLICIT",
             ViewWindowMode::Pretty => "PRETTY",
             ViewWindowMode::ViewWindowModeUnsupported => "VIEW_WINDOW_MODE_UNSUPPORTED",
-            ViewWindowMode::Noop => "",
-            ViewWindowMode::FallthroughString => "*",
+            ViewWindowMode::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ViewWindowMode {
-    fn default() -> ViewWindowMode {
-        ViewWindowMode::Noop
+impl std::fmt::Display for ViewWindowMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ViewWindowMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DEFAULT_VIEW_WINDOW_MODE" => ViewWindowMode::DefaultViewWindowMode,
+            "EXPLICIT" => ViewWindowMode::Explicit,
+            "PRETTY" => ViewWindowMode::Pretty,
+            "VIEW_WINDOW_MODE_UNSUPPORTED" => ViewWindowMode::ViewWindowModeUnsupported,
+            _ => ViewWindowMode::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ViewWindowMode {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ViewWindowMode {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ViewWindowMode::Noop)
+
+impl std::convert::From<ViewWindowMode> for String {
+    fn from(v: ViewWindowMode) -> Self {
+        match v {
+            ViewWindowMode::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2913,27 +3361,20 @@ pub struct ChartCustomNumberFormatOptions {
     /**
      * Custom number formatting options for chart attributes.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub prefix: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
     /**
      * Custom number formatting options for chart attributes.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub suffix: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
 }
 
 /**
- * The aggregation type for the series of a data source chart. Only supported for data source charts.
+ * The aggregation type for key and baseline chart data in scorecard chart. This field is not supported for data source charts. Use the ChartData.aggregateType field of the key_value_data or baseline_value_data instead for data source charts. This field is optional.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AggregateType {
     #[serde(rename = "AVERAGE")]
     Average,
@@ -2949,15 +3390,15 @@ pub enum AggregateType {
     Min,
     #[serde(rename = "SUM")]
     Sum,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AggregateType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AggregateType {
+    fn as_ref(&self) -> &str {
+        match self {
             AggregateType::Average => "AVERAGE",
             AggregateType::ChartAggregateTypeUnspecified => "CHART_AGGREGATE_TYPE_UNSPECIFIED",
             AggregateType::Count => "COUNT",
@@ -2965,21 +3406,49 @@ impl std::fmt::Display for AggregateType {
             AggregateType::Median => "MEDIAN",
             AggregateType::Min => "MIN",
             AggregateType::Sum => "SUM",
-            AggregateType::Noop => "",
-            AggregateType::FallthroughString => "*",
+            AggregateType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AggregateType {
-    fn default() -> AggregateType {
-        AggregateType::Noop
+impl std::fmt::Display for AggregateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AggregateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AVERAGE" => AggregateType::Average,
+            "CHART_AGGREGATE_TYPE_UNSPECIFIED" => AggregateType::ChartAggregateTypeUnspecified,
+            "COUNT" => AggregateType::Count,
+            "MAX" => AggregateType::Max,
+            "MEDIAN" => AggregateType::Median,
+            "MIN" => AggregateType::Min,
+            "SUM" => AggregateType::Sum,
+            _ => AggregateType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AggregateType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AggregateType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AggregateType::Noop)
+
+impl std::convert::From<AggregateType> for String {
+    fn from(v: AggregateType) -> Self {
+        match v {
+            AggregateType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3024,6 +3493,7 @@ pub struct ChartData {
  * The type of date-time grouping to apply.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ChartDateTimeRuleType {
     #[serde(rename = "CHART_DATE_TIME_RULE_TYPE_UNSPECIFIED")]
     ChartDateTimeRuleTypeUnspecified,
@@ -3057,15 +3527,15 @@ pub enum ChartDateTimeRuleType {
     YearMonthDay,
     #[serde(rename = "YEAR_QUARTER")]
     YearQuarter,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ChartDateTimeRuleType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ChartDateTimeRuleType {
+    fn as_ref(&self) -> &str {
+        match self {
             ChartDateTimeRuleType::ChartDateTimeRuleTypeUnspecified => {
                 "CHART_DATE_TIME_RULE_TYPE_UNSPECIFIED"
             }
@@ -3084,21 +3554,60 @@ impl std::fmt::Display for ChartDateTimeRuleType {
             ChartDateTimeRuleType::YearMonth => "YEAR_MONTH",
             ChartDateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
             ChartDateTimeRuleType::YearQuarter => "YEAR_QUARTER",
-            ChartDateTimeRuleType::Noop => "",
-            ChartDateTimeRuleType::FallthroughString => "*",
+            ChartDateTimeRuleType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ChartDateTimeRuleType {
-    fn default() -> ChartDateTimeRuleType {
-        ChartDateTimeRuleType::Noop
+impl std::fmt::Display for ChartDateTimeRuleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ChartDateTimeRuleType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CHART_DATE_TIME_RULE_TYPE_UNSPECIFIED" => {
+                ChartDateTimeRuleType::ChartDateTimeRuleTypeUnspecified
+            }
+            "DAY_MONTH" => ChartDateTimeRuleType::DayMonth,
+            "DAY_OF_MONTH" => ChartDateTimeRuleType::DayOfMonth,
+            "DAY_OF_WEEK" => ChartDateTimeRuleType::DayOfWeek,
+            "DAY_OF_YEAR" => ChartDateTimeRuleType::DayOfYear,
+            "HOUR" => ChartDateTimeRuleType::Hour,
+            "HOUR_MINUTE" => ChartDateTimeRuleType::HourMinute,
+            "HOUR_MINUTE_AMPM" => ChartDateTimeRuleType::HourMinuteAmpm,
+            "MINUTE" => ChartDateTimeRuleType::Minute,
+            "MONTH" => ChartDateTimeRuleType::Month,
+            "QUARTER" => ChartDateTimeRuleType::Quarter,
+            "SECOND" => ChartDateTimeRuleType::Second,
+            "YEAR" => ChartDateTimeRuleType::Year,
+            "YEAR_MONTH" => ChartDateTimeRuleType::YearMonth,
+            "YEAR_MONTH_DAY" => ChartDateTimeRuleType::YearMonthDay,
+            "YEAR_QUARTER" => ChartDateTimeRuleType::YearQuarter,
+            _ => ChartDateTimeRuleType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ChartDateTimeRuleType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ChartDateTimeRuleType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ChartDateTimeRuleType::Noop)
+
+impl std::convert::From<ChartDateTimeRuleType> for String {
+    fn from(v: ChartDateTimeRuleType) -> Self {
+        match v {
+            ChartDateTimeRuleType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3188,6 +3697,7 @@ pub struct ChartSourceRange {
  * Determines how the charts will use hidden rows or columns.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum HiddenDimensionStrategy {
     #[serde(rename = "CHART_HIDDEN_DIMENSION_STRATEGY_UNSPECIFIED")]
     ChartHiddenDimensionStrategyUnspecified,
@@ -3199,15 +3709,15 @@ pub enum HiddenDimensionStrategy {
     SkipHiddenRows,
     #[serde(rename = "SKIP_HIDDEN_ROWS_AND_COLUMNS")]
     SkipHiddenRowsAndColumns,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for HiddenDimensionStrategy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for HiddenDimensionStrategy {
+    fn as_ref(&self) -> &str {
+        match self {
             HiddenDimensionStrategy::ChartHiddenDimensionStrategyUnspecified => {
                 "CHART_HIDDEN_DIMENSION_STRATEGY_UNSPECIFIED"
             }
@@ -3215,21 +3725,49 @@ impl std::fmt::Display for HiddenDimensionStrategy {
             HiddenDimensionStrategy::SkipHiddenColumns => "SKIP_HIDDEN_COLUMNS",
             HiddenDimensionStrategy::SkipHiddenRows => "SKIP_HIDDEN_ROWS",
             HiddenDimensionStrategy::SkipHiddenRowsAndColumns => "SKIP_HIDDEN_ROWS_AND_COLUMNS",
-            HiddenDimensionStrategy::Noop => "",
-            HiddenDimensionStrategy::FallthroughString => "*",
+            HiddenDimensionStrategy::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for HiddenDimensionStrategy {
-    fn default() -> HiddenDimensionStrategy {
-        HiddenDimensionStrategy::Noop
+impl std::fmt::Display for HiddenDimensionStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for HiddenDimensionStrategy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CHART_HIDDEN_DIMENSION_STRATEGY_UNSPECIFIED" => {
+                HiddenDimensionStrategy::ChartHiddenDimensionStrategyUnspecified
+            }
+            "SHOW_ALL" => HiddenDimensionStrategy::ShowAll,
+            "SKIP_HIDDEN_COLUMNS" => HiddenDimensionStrategy::SkipHiddenColumns,
+            "SKIP_HIDDEN_ROWS" => HiddenDimensionStrategy::SkipHiddenRows,
+            "SKIP_HIDDEN_ROWS_AND_COLUMNS" => HiddenDimensionStrategy::SkipHiddenRowsAndColumns,
+            _ => HiddenDimensionStrategy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for HiddenDimensionStrategy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl HiddenDimensionStrategy {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, HiddenDimensionStrategy::Noop)
+
+impl std::convert::From<HiddenDimensionStrategy> for String {
+    fn from(v: HiddenDimensionStrategy) -> Self {
+        match v {
+            HiddenDimensionStrategy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3239,13 +3777,8 @@ pub struct ChartSpec {
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "altText"
-    )]
-    pub alt_text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "altText")]
+    pub alt_text: Option<String>,
     /**
      * The specifications of a chart.
      */
@@ -3313,13 +3846,8 @@ pub struct ChartSpec {
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "fontName"
-    )]
-    pub font_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "fontName")]
+    pub font_name: Option<String>,
     /**
      * The specifications of a chart.
      */
@@ -3378,12 +3906,8 @@ pub struct ChartSpec {
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subtitle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
     /**
      * The specifications of a chart.
      */
@@ -3405,12 +3929,8 @@ pub struct ChartSpec {
     /**
      * The specifications of a chart.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     /**
      * The specifications of a chart.
      */
@@ -3464,35 +3984,6 @@ pub struct ClearBasicFilterRequest {
     pub sheet_id: i64,
 }
 
-/// The request for clearing a range of values in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ClearValuesRequest {}
-
-/// The response when clearing a range of values in a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ClearValuesResponse {
-    /**
-     * The response when clearing a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "clearedRange"
-    )]
-    pub cleared_range: String,
-    /**
-     * The response when clearing a range of values in a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
-    )]
-    pub spreadsheet_id: String,
-}
-
 /// Represents a color in the RGBA color space. This representation is designed for simplicity of conversion to/from color representations in various languages over compactness. For example, the fields of this representation can be trivially provided to the constructor of `java.awt.Color` in Java; it can also be trivially provided to UIColor's `+colorWithRed:green:blue:alpha` method in iOS; and, with just a little work, it can be easily formatted into a CSS `rgba()` string in JavaScript. This reference page doesn't carry information about the absolute color space that should be used to interpret the RGB value (e.g. sRGB, Adobe RGB, DCI-P3, BT.2020, etc.). By default, applications should assume the sRGB color space. When color equality needs to be decided, implementations, unless documented otherwise, treat two colors as equal if all their red, green, blue, and alpha values each differ by at most 1e-5. Example (Java): import com.google.type.Color; // ... public static java.awt.Color fromProto(Color protocolor) { float alpha = protocolor.hasAlpha() ? protocolor.getAlpha().getValue() : 1.0; return new java.awt.Color( protocolor.getRed(), protocolor.getGreen(), protocolor.getBlue(), alpha); } public static Color toProto(java.awt.Color color) { float red = (float) color.getRed(); float green = (float) color.getGreen(); float blue = (float) color.getBlue(); float denominator = 255.0; Color.Builder resultBuilder = Color .newBuilder() .setRed(red / denominator) .setGreen(green / denominator) .setBlue(blue / denominator); int alpha = color.getAlpha(); if (alpha != 255) { result.setAlpha( FloatValue .newBuilder() .setValue(((float) alpha) / denominator) .build()); } return resultBuilder.build(); } // ... Example (iOS / Obj-C): // ... static UIColor* fromProto(Color* protocolor) { float red = [protocolor red]; float green = [protocolor green]; float blue = [protocolor blue]; FloatValue* alpha_wrapper = [protocolor alpha]; float alpha = 1.0; if (alpha_wrapper != nil) { alpha = [alpha_wrapper value]; } return [UIColor colorWithRed:red green:green blue:blue alpha:alpha]; } static Color* toProto(UIColor* color) { CGFloat red, green, blue, alpha; if (![color getRed:&red green:&green blue:&blue alpha:&alpha]) { return nil; } Color* result = [[Color alloc] init]; [result setRed:red]; [result setGreen:green]; [result setBlue:blue]; if (alpha <= 0.9999) { [result setAlpha:floatWrapperWithValue(alpha)]; } [result autorelease]; return result; } // ... Example (JavaScript): // ... var protoToCssColor = function(rgb_color) { var redFrac = rgb_color.red || 0.0; var greenFrac = rgb_color.green || 0.0; var blueFrac = rgb_color.blue || 0.0; var red = Math.floor(redFrac * 255); var green = Math.floor(greenFrac * 255); var blue = Math.floor(blueFrac * 255); if (!('alpha' in rgb_color)) { return rgbToCssColor(red, green, blue); } var alphaFrac = rgb_color.alpha.value || 0.0; var rgbParams = [red, green, blue].join(','); return ['rgba(', rgbParams, ',', alphaFrac, ')'].join(''); }; var rgbToCssColor = function(red, green, blue) { var rgbNumber = new Number((red << 16) | (green << 8) | blue); var hexString = rgbNumber.toString(16); var missingZeros = 6 - hexString.length; var resultBuilder = ['#']; for (var i = 0; i < missingZeros; i++) { resultBuilder.push('0'); } resultBuilder.push(hexString); return resultBuilder.join(''); }; // ...
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Color {
@@ -3535,10 +4026,11 @@ pub struct Color {
 }
 
 /**
- * The type of the spreadsheet theme color.
+ * Theme color.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ColorType {
+#[serde(from = "String", into = "String")]
+pub enum ThemeColor {
     #[serde(rename = "ACCENT1")]
     Accent1,
     #[serde(rename = "ACCENT2")]
@@ -3559,40 +4051,71 @@ pub enum ColorType {
     Text,
     #[serde(rename = "THEME_COLOR_TYPE_UNSPECIFIED")]
     ThemeColorTypeUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ThemeColor {
+    fn as_ref(&self) -> &str {
+        match self {
+            ThemeColor::Accent1 => "ACCENT1",
+            ThemeColor::Accent2 => "ACCENT2",
+            ThemeColor::Accent3 => "ACCENT3",
+            ThemeColor::Accent4 => "ACCENT4",
+            ThemeColor::Accent5 => "ACCENT5",
+            ThemeColor::Accent6 => "ACCENT6",
+            ThemeColor::Background => "BACKGROUND",
+            ThemeColor::Link => "LINK",
+            ThemeColor::Text => "TEXT",
+            ThemeColor::ThemeColorTypeUnspecified => "THEME_COLOR_TYPE_UNSPECIFIED",
+            ThemeColor::Unknown(s) => s,
+        }
+    }
 }
 
-impl std::fmt::Display for ColorType {
+impl std::fmt::Display for ThemeColor {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ColorType::Accent1 => "ACCENT1",
-            ColorType::Accent2 => "ACCENT2",
-            ColorType::Accent3 => "ACCENT3",
-            ColorType::Accent4 => "ACCENT4",
-            ColorType::Accent5 => "ACCENT5",
-            ColorType::Accent6 => "ACCENT6",
-            ColorType::Background => "BACKGROUND",
-            ColorType::Link => "LINK",
-            ColorType::Text => "TEXT",
-            ColorType::ThemeColorTypeUnspecified => "THEME_COLOR_TYPE_UNSPECIFIED",
-            ColorType::Noop => "",
-            ColorType::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ThemeColor {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACCENT1" => ThemeColor::Accent1,
+            "ACCENT2" => ThemeColor::Accent2,
+            "ACCENT3" => ThemeColor::Accent3,
+            "ACCENT4" => ThemeColor::Accent4,
+            "ACCENT5" => ThemeColor::Accent5,
+            "ACCENT6" => ThemeColor::Accent6,
+            "BACKGROUND" => ThemeColor::Background,
+            "LINK" => ThemeColor::Link,
+            "TEXT" => ThemeColor::Text,
+            "THEME_COLOR_TYPE_UNSPECIFIED" => ThemeColor::ThemeColorTypeUnspecified,
+            _ => ThemeColor::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for ColorType {
-    fn default() -> ColorType {
-        ColorType::Noop
+impl std::convert::From<String> for ThemeColor {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ColorType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ColorType::Noop)
+
+impl std::convert::From<ThemeColor> for String {
+    fn from(v: ThemeColor) -> Self {
+        match v {
+            ThemeColor::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3612,13 +4135,14 @@ pub struct ColorStyle {
         skip_serializing_if = "Option::is_none",
         rename = "themeColor"
     )]
-    pub theme_color: Option<ColorType>,
+    pub theme_color: Option<ThemeColor>,
 }
 
 /**
  * A relative date (based on the current date). Valid only if the type is DATE_BEFORE, DATE_AFTER, DATE_ON_OR_BEFORE or DATE_ON_OR_AFTER. Relative dates are not supported in data validation. They are supported only in conditional formatting and conditional filters.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RelativeDate {
     #[serde(rename = "PAST_MONTH")]
     PastMonth,
@@ -3634,15 +4158,15 @@ pub enum RelativeDate {
     Tomorrow,
     #[serde(rename = "YESTERDAY")]
     Yesterday,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for RelativeDate {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for RelativeDate {
+    fn as_ref(&self) -> &str {
+        match self {
             RelativeDate::PastMonth => "PAST_MONTH",
             RelativeDate::PastWeek => "PAST_WEEK",
             RelativeDate::PastYear => "PAST_YEAR",
@@ -3650,21 +4174,49 @@ impl std::fmt::Display for RelativeDate {
             RelativeDate::Today => "TODAY",
             RelativeDate::Tomorrow => "TOMORROW",
             RelativeDate::Yesterday => "YESTERDAY",
-            RelativeDate::Noop => "",
-            RelativeDate::FallthroughString => "*",
+            RelativeDate::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for RelativeDate {
-    fn default() -> RelativeDate {
-        RelativeDate::Noop
+impl std::fmt::Display for RelativeDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for RelativeDate {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "PAST_MONTH" => RelativeDate::PastMonth,
+            "PAST_WEEK" => RelativeDate::PastWeek,
+            "PAST_YEAR" => RelativeDate::PastYear,
+            "RELATIVE_DATE_UNSPECIFIED" => RelativeDate::RelativeDateUnspecified,
+            "TODAY" => RelativeDate::Today,
+            "TOMORROW" => RelativeDate::Tomorrow,
+            "YESTERDAY" => RelativeDate::Yesterday,
+            _ => RelativeDate::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RelativeDate {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl RelativeDate {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RelativeDate::Noop)
+
+impl std::convert::From<RelativeDate> for String {
+    fn from(v: RelativeDate) -> Self {
+        match v {
+            RelativeDate::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3685,11 +4237,10 @@ pub struct ConditionValue {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "userEnteredValue"
     )]
-    pub user_entered_value: String,
+    pub user_entered_value: Option<String>,
 }
 
 /// A rule describing a conditional format.
@@ -3728,44 +4279,69 @@ pub struct ConditionalFormatRule {
  * How that data should be oriented when pasting.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PasteOrientation {
     #[serde(rename = "NORMAL")]
     Normal,
     #[serde(rename = "TRANSPOSE")]
     Transpose,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PasteOrientation {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PasteOrientation {
+    fn as_ref(&self) -> &str {
+        match self {
             PasteOrientation::Normal => "NORMAL",
             PasteOrientation::Transpose => "TRANSPOSE",
-            PasteOrientation::Noop => "",
-            PasteOrientation::FallthroughString => "*",
+            PasteOrientation::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PasteOrientation {
-    fn default() -> PasteOrientation {
-        PasteOrientation::Noop
+impl std::fmt::Display for PasteOrientation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PasteOrientation {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "NORMAL" => PasteOrientation::Normal,
+            "TRANSPOSE" => PasteOrientation::Transpose,
+            _ => PasteOrientation::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PasteOrientation {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PasteOrientation {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PasteOrientation::Noop)
+
+impl std::convert::From<PasteOrientation> for String {
+    fn from(v: PasteOrientation) -> Self {
+        match v {
+            PasteOrientation::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 /**
- * What kind of data to paste.
+ * What kind of data to paste. All the source data will be cut, regardless of what is pasted.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PasteType {
     #[serde(rename = "PASTE_CONDITIONAL_FORMATTING")]
     PasteConditionalFormatting,
@@ -3781,15 +4357,15 @@ pub enum PasteType {
     PasteNoBorders,
     #[serde(rename = "PASTE_VALUES")]
     PasteValues,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PasteType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PasteType {
+    fn as_ref(&self) -> &str {
+        match self {
             PasteType::PasteConditionalFormatting => "PASTE_CONDITIONAL_FORMATTING",
             PasteType::PasteDataValidation => "PASTE_DATA_VALIDATION",
             PasteType::PasteFormat => "PASTE_FORMAT",
@@ -3797,21 +4373,49 @@ impl std::fmt::Display for PasteType {
             PasteType::PasteNormal => "PASTE_NORMAL",
             PasteType::PasteNoBorders => "PASTE_NO_BORDERS",
             PasteType::PasteValues => "PASTE_VALUES",
-            PasteType::Noop => "",
-            PasteType::FallthroughString => "*",
+            PasteType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PasteType {
-    fn default() -> PasteType {
-        PasteType::Noop
+impl std::fmt::Display for PasteType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PasteType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "PASTE_CONDITIONAL_FORMATTING" => PasteType::PasteConditionalFormatting,
+            "PASTE_DATA_VALIDATION" => PasteType::PasteDataValidation,
+            "PASTE_FORMAT" => PasteType::PasteFormat,
+            "PASTE_FORMULA" => PasteType::PasteFormula,
+            "PASTE_NORMAL" => PasteType::PasteNormal,
+            "PASTE_NO_BORDERS" => PasteType::PasteNoBorders,
+            "PASTE_VALUES" => PasteType::PasteValues,
+            _ => PasteType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PasteType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PasteType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PasteType::Noop)
+
+impl std::convert::From<PasteType> for String {
+    fn from(v: PasteType) -> Self {
+        match v {
+            PasteType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3844,21 +4448,6 @@ pub struct CopyPasteRequest {
     pub source: Option<GridRange>,
 }
 
-/// The request to copy a sheet across spreadsheets.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CopySheetAnotherSpreadsheetRequest {
-    /**
-     * The request to copy a sheet across spreadsheets.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "destinationSpreadsheetId"
-    )]
-    pub destination_spreadsheet_id: String,
-}
-
 /// A request to create developer metadata.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct CreateDeveloperMetadataRequest {
@@ -3911,6 +4500,7 @@ pub struct CutPasteRequest {
  * The error code.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ErrorCode {
     #[serde(rename = "CONCURRENT_QUERY")]
     ConcurrentQuery,
@@ -3948,15 +4538,15 @@ pub enum ErrorCode {
     TooManyRows,
     #[serde(rename = "UNSUPPORTED_DATA_TYPE")]
     UnsupportedDataType,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ErrorCode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        match self {
             ErrorCode::ConcurrentQuery => "CONCURRENT_QUERY",
             ErrorCode::DataExecutionErrorCodeUnspecified => "DATA_EXECUTION_ERROR_CODE_UNSPECIFIED",
             ErrorCode::DataNotFound => "DATA_NOT_FOUND",
@@ -3975,21 +4565,60 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::TooManyCharsPerCell => "TOO_MANY_CHARS_PER_CELL",
             ErrorCode::TooManyRows => "TOO_MANY_ROWS",
             ErrorCode::UnsupportedDataType => "UNSUPPORTED_DATA_TYPE",
-            ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ErrorCode {
-    fn default() -> ErrorCode {
-        ErrorCode::Noop
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ErrorCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CONCURRENT_QUERY" => ErrorCode::ConcurrentQuery,
+            "DATA_EXECUTION_ERROR_CODE_UNSPECIFIED" => ErrorCode::DataExecutionErrorCodeUnspecified,
+            "DATA_NOT_FOUND" => ErrorCode::DataNotFound,
+            "DUPLICATE_COLUMN_NAMES" => ErrorCode::DuplicateColumnNames,
+            "ENGINE" => ErrorCode::Engine,
+            "INTERRUPTED" => ErrorCode::Interrupted,
+            "MISSING_COLUMN_ALIAS" => ErrorCode::MissingColumnAlias,
+            "OBJECT_IN_ERROR_STATE" => ErrorCode::ObjectInErrorState,
+            "OBJECT_NOT_FOUND" => ErrorCode::ObjectNotFound,
+            "OBJECT_SPEC_INVALID" => ErrorCode::ObjectSpecInvalid,
+            "OTHER" => ErrorCode::Other,
+            "PARAMETER_INVALID" => ErrorCode::ParameterInvalid,
+            "PERMISSION_DENIED" => ErrorCode::PermissionDenied,
+            "TIMED_OUT" => ErrorCode::TimedOut,
+            "TOO_MANY_CELLS" => ErrorCode::TooManyCells,
+            "TOO_MANY_CHARS_PER_CELL" => ErrorCode::TooManyCharsPerCell,
+            "TOO_MANY_ROWS" => ErrorCode::TooManyRows,
+            "UNSUPPORTED_DATA_TYPE" => ErrorCode::UnsupportedDataType,
+            _ => ErrorCode::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ErrorCode {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ErrorCode {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ErrorCode::Noop)
+
+impl std::convert::From<ErrorCode> for String {
+    fn from(v: ErrorCode) -> Self {
+        match v {
+            ErrorCode::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3997,6 +4626,7 @@ impl ErrorCode {
  * The state of the data execution.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum State {
     #[serde(rename = "DATA_EXECUTION_STATE_UNSPECIFIED")]
     DataExecutionStateUnspecified,
@@ -4008,35 +4638,61 @@ pub enum State {
     Running,
     #[serde(rename = "SUCCEEDED")]
     Succeeded,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
-
-impl std::fmt::Display for State {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+
+impl AsRef<str> for State {
+    fn as_ref(&self) -> &str {
+        match self {
             State::DataExecutionStateUnspecified => "DATA_EXECUTION_STATE_UNSPECIFIED",
             State::Failed => "FAILED",
             State::NotStarted => "NOT_STARTED",
             State::Running => "RUNNING",
             State::Succeeded => "SUCCEEDED",
-            State::Noop => "",
-            State::FallthroughString => "*",
+            State::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for State {
-    fn default() -> State {
-        State::Noop
+impl std::fmt::Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for State {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DATA_EXECUTION_STATE_UNSPECIFIED" => State::DataExecutionStateUnspecified,
+            "FAILED" => State::Failed,
+            "NOT_STARTED" => State::NotStarted,
+            "RUNNING" => State::Running,
+            "SUCCEEDED" => State::Succeeded,
+            _ => State::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for State {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl State {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, State::Noop)
+
+impl std::convert::From<State> for String {
+    fn from(v: State) -> Self {
+        match v {
+            State::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4053,11 +4709,10 @@ pub struct DataExecutionStatus {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "errorMessage"
     )]
-    pub error_message: String,
+    pub error_message: Option<String>,
     /**
      * The data execution status. A data execution is created to sync a data source object with the latest data from a DataSource. It is usually scheduled to run at background, you can check its state to tell if an execution completes There are several scenarios where a data execution is triggered to run: \* Adding a data source creates an associated data source sheet as well as a data execution to sync the data from the data source to the sheet. \* Updating a data source creates a data execution to refresh the associated data source sheet similarly. \* You can send refresh request to explicitly refresh one or multiple data source objects.
      */
@@ -4081,13 +4736,8 @@ pub struct DataFilter {
     /**
      * Filter that describes what data should be selected or returned from a request.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "a1Range"
-    )]
-    pub a_1_range: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "a1Range")]
+    pub a_1_range: Option<String>,
     /**
      * Filter that describes what data should be selected or returned from a request.
      */
@@ -4124,7 +4774,7 @@ pub struct DataFilterValueRange {
         skip_serializing_if = "Option::is_none",
         rename = "majorDimension"
     )]
-    pub major_dimension: Option<Dimension>,
+    pub major_dimension: Option<MajorDimension>,
     /**
      * A range of values whose location is specified by a DataFilter.
      */
@@ -4140,6 +4790,7 @@ pub struct DataFilterValueRange {
  * The placement of the data label relative to the labeled data.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Placement {
     #[serde(rename = "ABOVE")]
     Above,
@@ -4159,15 +4810,15 @@ pub enum Placement {
     OutsideEnd,
     #[serde(rename = "RIGHT")]
     Right,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Placement {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Placement {
+    fn as_ref(&self) -> &str {
+        match self {
             Placement::Above => "ABOVE",
             Placement::Below => "BELOW",
             Placement::Center => "CENTER",
@@ -4177,21 +4828,51 @@ impl std::fmt::Display for Placement {
             Placement::Left => "LEFT",
             Placement::OutsideEnd => "OUTSIDE_END",
             Placement::Right => "RIGHT",
-            Placement::Noop => "",
-            Placement::FallthroughString => "*",
+            Placement::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Placement {
-    fn default() -> Placement {
-        Placement::Noop
+impl std::fmt::Display for Placement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Placement {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ABOVE" => Placement::Above,
+            "BELOW" => Placement::Below,
+            "CENTER" => Placement::Center,
+            "DATA_LABEL_PLACEMENT_UNSPECIFIED" => Placement::DataLabelPlacementUnspecified,
+            "INSIDE_BASE" => Placement::InsideBase,
+            "INSIDE_END" => Placement::InsideEnd,
+            "LEFT" => Placement::Left,
+            "OUTSIDE_END" => Placement::OutsideEnd,
+            "RIGHT" => Placement::Right,
+            _ => Placement::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Placement {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Placement {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Placement::Noop)
+
+impl std::convert::From<Placement> for String {
+    fn from(v: Placement) -> Self {
+        match v {
+            Placement::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4199,6 +4880,7 @@ impl Placement {
  * The type of the data label.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DataLabelType {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -4208,34 +4890,59 @@ pub enum DataLabelType {
     DataLabelTypeUnspecified,
     #[serde(rename = "NONE")]
     None,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DataLabelType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DataLabelType {
+    fn as_ref(&self) -> &str {
+        match self {
             DataLabelType::Custom => "CUSTOM",
             DataLabelType::Data => "DATA",
             DataLabelType::DataLabelTypeUnspecified => "DATA_LABEL_TYPE_UNSPECIFIED",
             DataLabelType::None => "NONE",
-            DataLabelType::Noop => "",
-            DataLabelType::FallthroughString => "*",
+            DataLabelType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DataLabelType {
-    fn default() -> DataLabelType {
-        DataLabelType::Noop
+impl std::fmt::Display for DataLabelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DataLabelType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CUSTOM" => DataLabelType::Custom,
+            "DATA" => DataLabelType::Data,
+            "DATA_LABEL_TYPE_UNSPECIFIED" => DataLabelType::DataLabelTypeUnspecified,
+            "NONE" => DataLabelType::None,
+            _ => DataLabelType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DataLabelType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DataLabelType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DataLabelType::Noop)
+
+impl std::convert::From<DataLabelType> for String {
+    fn from(v: DataLabelType) -> Self {
+        match v {
+            DataLabelType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4290,11 +4997,10 @@ pub struct DataSource {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "dataSourceId"
     )]
-    pub data_source_id: String,
+    pub data_source_id: Option<String>,
     /**
      * Information about an external data source in the spreadsheet.
      */
@@ -4329,11 +5035,10 @@ pub struct DataSourceChartProperties {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "dataSourceId"
     )]
-    pub data_source_id: String,
+    pub data_source_id: Option<String>,
 }
 
 /// A column in a data source.
@@ -4342,12 +5047,8 @@ pub struct DataSourceColumn {
     /**
      * A column in a data source.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub formula: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formula: Option<String>,
     /**
      * A column in a data source.
      */
@@ -4361,12 +5062,8 @@ pub struct DataSourceColumnReference {
     /**
      * An unique identifier that references a data source column.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 /// A data source formula.
@@ -4386,11 +5083,10 @@ pub struct DataSourceFormula {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "dataSourceId"
     )]
-    pub data_source_id: String,
+    pub data_source_id: Option<String>,
 }
 
 /// Reference to a data source object.
@@ -4436,13 +5132,8 @@ pub struct DataSourceObjectReference {
     /**
      * Reference to a data source object.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "sheetId"
-    )]
-    pub sheet_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "sheetId")]
+    pub sheet_id: Option<String>,
 }
 
 /// A list of references to data source objects.
@@ -4465,22 +5156,17 @@ pub struct DataSourceParameter {
     /**
      * A parameter in a data source's query. The parameter allows the user to pass in values from the spreadsheet into a query.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /**
      * A parameter in a data source's query. The parameter allows the user to pass in values from the spreadsheet into a query.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "namedRangeId"
     )]
-    pub named_range_id: String,
+    pub named_range_id: Option<String>,
     /**
      * A parameter in a data source's query. The parameter allows the user to pass in values from the spreadsheet into a query.
      */
@@ -4522,39 +5208,65 @@ pub struct DataSourceRefreshMonthlySchedule {
  * The scope of the refresh. Must be ALL_DATA_SOURCES.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RefreshScope {
     #[serde(rename = "ALL_DATA_SOURCES")]
     AllDataSources,
     #[serde(rename = "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED")]
     DataSourceRefreshScopeUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for RefreshScope {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for RefreshScope {
+    fn as_ref(&self) -> &str {
+        match self {
             RefreshScope::AllDataSources => "ALL_DATA_SOURCES",
             RefreshScope::DataSourceRefreshScopeUnspecified => {
                 "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED"
             }
-            RefreshScope::Noop => "",
-            RefreshScope::FallthroughString => "*",
+            RefreshScope::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for RefreshScope {
-    fn default() -> RefreshScope {
-        RefreshScope::Noop
+impl std::fmt::Display for RefreshScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for RefreshScope {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ALL_DATA_SOURCES" => RefreshScope::AllDataSources,
+            "DATA_SOURCE_REFRESH_SCOPE_UNSPECIFIED" => {
+                RefreshScope::DataSourceRefreshScopeUnspecified
+            }
+            _ => RefreshScope::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RefreshScope {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl RefreshScope {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RefreshScope::Noop)
+
+impl std::convert::From<RefreshScope> for String {
+    fn from(v: RefreshScope) -> Self {
+        match v {
+            RefreshScope::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4613,6 +5325,7 @@ pub struct DataSourceRefreshSchedule {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DaysOfWeek {
     #[serde(rename = "DAY_OF_WEEK_UNSPECIFIED")]
     DayOfWeekUnspecified,
@@ -4630,15 +5343,15 @@ pub enum DaysOfWeek {
     Tuesday,
     #[serde(rename = "WEDNESDAY")]
     Wednesday,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DaysOfWeek {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DaysOfWeek {
+    fn as_ref(&self) -> &str {
+        match self {
             DaysOfWeek::DayOfWeekUnspecified => "DAY_OF_WEEK_UNSPECIFIED",
             DaysOfWeek::Friday => "FRIDAY",
             DaysOfWeek::Monday => "MONDAY",
@@ -4647,21 +5360,50 @@ impl std::fmt::Display for DaysOfWeek {
             DaysOfWeek::Thursday => "THURSDAY",
             DaysOfWeek::Tuesday => "TUESDAY",
             DaysOfWeek::Wednesday => "WEDNESDAY",
-            DaysOfWeek::Noop => "",
-            DaysOfWeek::FallthroughString => "*",
+            DaysOfWeek::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DaysOfWeek {
-    fn default() -> DaysOfWeek {
-        DaysOfWeek::Noop
+impl std::fmt::Display for DaysOfWeek {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DaysOfWeek {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DAY_OF_WEEK_UNSPECIFIED" => DaysOfWeek::DayOfWeekUnspecified,
+            "FRIDAY" => DaysOfWeek::Friday,
+            "MONDAY" => DaysOfWeek::Monday,
+            "SATURDAY" => DaysOfWeek::Saturday,
+            "SUNDAY" => DaysOfWeek::Sunday,
+            "THURSDAY" => DaysOfWeek::Thursday,
+            "TUESDAY" => DaysOfWeek::Tuesday,
+            "WEDNESDAY" => DaysOfWeek::Wednesday,
+            _ => DaysOfWeek::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DaysOfWeek {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DaysOfWeek {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DaysOfWeek::Noop)
+
+impl std::convert::From<DaysOfWeek> for String {
+    fn from(v: DaysOfWeek) -> Self {
+        match v {
+            DaysOfWeek::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4736,11 +5478,10 @@ pub struct DataSourceSheetProperties {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "dataSourceId"
     )]
-    pub data_source_id: String,
+    pub data_source_id: Option<String>,
 }
 
 /// This specifies the details of the data source. For example, for BigQuery, this specifies information about the BigQuery source.
@@ -4766,6 +5507,7 @@ pub struct DataSourceSpec {
  * The type to select columns for the data source table. Defaults to SELECTED.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ColumnSelectionType {
     #[serde(rename = "DATA_SOURCE_TABLE_COLUMN_SELECTION_TYPE_UNSPECIFIED")]
     DataSourceTableColumnSelectionTypeUnspecified,
@@ -4773,35 +5515,61 @@ pub enum ColumnSelectionType {
     Selected,
     #[serde(rename = "SYNC_ALL")]
     SyncAll,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ColumnSelectionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ColumnSelectionType {
+    fn as_ref(&self) -> &str {
+        match self {
             ColumnSelectionType::DataSourceTableColumnSelectionTypeUnspecified => {
                 "DATA_SOURCE_TABLE_COLUMN_SELECTION_TYPE_UNSPECIFIED"
             }
             ColumnSelectionType::Selected => "SELECTED",
             ColumnSelectionType::SyncAll => "SYNC_ALL",
-            ColumnSelectionType::Noop => "",
-            ColumnSelectionType::FallthroughString => "*",
+            ColumnSelectionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ColumnSelectionType {
-    fn default() -> ColumnSelectionType {
-        ColumnSelectionType::Noop
+impl std::fmt::Display for ColumnSelectionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ColumnSelectionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DATA_SOURCE_TABLE_COLUMN_SELECTION_TYPE_UNSPECIFIED" => {
+                ColumnSelectionType::DataSourceTableColumnSelectionTypeUnspecified
+            }
+            "SELECTED" => ColumnSelectionType::Selected,
+            "SYNC_ALL" => ColumnSelectionType::SyncAll,
+            _ => ColumnSelectionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ColumnSelectionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ColumnSelectionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ColumnSelectionType::Noop)
+
+impl std::convert::From<ColumnSelectionType> for String {
+    fn from(v: ColumnSelectionType) -> Self {
+        match v {
+            ColumnSelectionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4840,11 +5608,10 @@ pub struct DataSourceTable {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "dataSourceId"
     )]
-    pub data_source_id: String,
+    pub data_source_id: Option<String>,
     /**
      * A data source table, which allows the user to import a static table of data from the DataSource into Sheets. This is also known as "Extract" in the Sheets editor.
      */
@@ -4890,11 +5657,10 @@ pub struct DataValidationRule {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "inputMessage"
     )]
-    pub input_message: String,
+    pub input_message: Option<String>,
     /**
      * A data validation rule.
      */
@@ -4918,6 +5684,7 @@ pub struct DataValidationRule {
  * The type of date-time grouping to apply.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateTimeRuleType {
     #[serde(rename = "DATE_TIME_RULE_TYPE_UNSPECIFIED")]
     DateTimeRuleTypeUnspecified,
@@ -4951,15 +5718,15 @@ pub enum DateTimeRuleType {
     YearMonthDay,
     #[serde(rename = "YEAR_QUARTER")]
     YearQuarter,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DateTimeRuleType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DateTimeRuleType {
+    fn as_ref(&self) -> &str {
+        match self {
             DateTimeRuleType::DateTimeRuleTypeUnspecified => "DATE_TIME_RULE_TYPE_UNSPECIFIED",
             DateTimeRuleType::DayMonth => "DAY_MONTH",
             DateTimeRuleType::DayOfMonth => "DAY_OF_MONTH",
@@ -4976,21 +5743,58 @@ impl std::fmt::Display for DateTimeRuleType {
             DateTimeRuleType::YearMonth => "YEAR_MONTH",
             DateTimeRuleType::YearMonthDay => "YEAR_MONTH_DAY",
             DateTimeRuleType::YearQuarter => "YEAR_QUARTER",
-            DateTimeRuleType::Noop => "",
-            DateTimeRuleType::FallthroughString => "*",
+            DateTimeRuleType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DateTimeRuleType {
-    fn default() -> DateTimeRuleType {
-        DateTimeRuleType::Noop
+impl std::fmt::Display for DateTimeRuleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DateTimeRuleType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DATE_TIME_RULE_TYPE_UNSPECIFIED" => DateTimeRuleType::DateTimeRuleTypeUnspecified,
+            "DAY_MONTH" => DateTimeRuleType::DayMonth,
+            "DAY_OF_MONTH" => DateTimeRuleType::DayOfMonth,
+            "DAY_OF_WEEK" => DateTimeRuleType::DayOfWeek,
+            "DAY_OF_YEAR" => DateTimeRuleType::DayOfYear,
+            "HOUR" => DateTimeRuleType::Hour,
+            "HOUR_MINUTE" => DateTimeRuleType::HourMinute,
+            "HOUR_MINUTE_AMPM" => DateTimeRuleType::HourMinuteAmpm,
+            "MINUTE" => DateTimeRuleType::Minute,
+            "MONTH" => DateTimeRuleType::Month,
+            "QUARTER" => DateTimeRuleType::Quarter,
+            "SECOND" => DateTimeRuleType::Second,
+            "YEAR" => DateTimeRuleType::Year,
+            "YEAR_MONTH" => DateTimeRuleType::YearMonth,
+            "YEAR_MONTH_DAY" => DateTimeRuleType::YearMonthDay,
+            "YEAR_QUARTER" => DateTimeRuleType::YearQuarter,
+            _ => DateTimeRuleType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DateTimeRuleType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DateTimeRuleType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DateTimeRuleType::Noop)
+
+impl std::convert::From<DateTimeRuleType> for String {
+    fn from(v: DateTimeRuleType) -> Self {
+        match v {
+            DateTimeRuleType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5061,11 +5865,10 @@ pub struct DeleteDataSourceRequest {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "dataSourceId"
     )]
-    pub data_source_id: String,
+    pub data_source_id: Option<String>,
 }
 
 /// A request to delete developer metadata.
@@ -5205,11 +6008,10 @@ pub struct DeleteNamedRangeRequest {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "namedRangeId"
     )]
-    pub named_range_id: String,
+    pub named_range_id: Option<String>,
 }
 
 /// Deletes the protected range with the given ID.
@@ -5227,6 +6029,72 @@ pub struct DeleteProtectedRangeRequest {
     pub protected_range_id: i64,
 }
 
+/**
+ * The dimension which will be shifted when inserting cells. If ROWS, existing cells will be shifted down. If COLUMNS, existing cells will be shifted right.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ShiftDimension {
+    #[serde(rename = "COLUMNS")]
+    Columns,
+    #[serde(rename = "DIMENSION_UNSPECIFIED")]
+    DimensionUnspecified,
+    #[serde(rename = "ROWS")]
+    Rows,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ShiftDimension {
+    fn as_ref(&self) -> &str {
+        match self {
+            ShiftDimension::Columns => "COLUMNS",
+            ShiftDimension::DimensionUnspecified => "DIMENSION_UNSPECIFIED",
+            ShiftDimension::Rows => "ROWS",
+            ShiftDimension::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ShiftDimension {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ShiftDimension {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COLUMNS" => ShiftDimension::Columns,
+            "DIMENSION_UNSPECIFIED" => ShiftDimension::DimensionUnspecified,
+            "ROWS" => ShiftDimension::Rows,
+            _ => ShiftDimension::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ShiftDimension {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ShiftDimension> for String {
+    fn from(v: ShiftDimension) -> Self {
+        match v {
+            ShiftDimension::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
 /// Deletes a range of cells, shifting other cells into the deleted area.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DeleteRangeRequest {
@@ -5243,7 +6111,7 @@ pub struct DeleteRangeRequest {
         skip_serializing_if = "Option::is_none",
         rename = "shiftDimension"
     )]
-    pub shift_dimension: Option<Dimension>,
+    pub shift_dimension: Option<ShiftDimension>,
 }
 
 /// Deletes the requested sheet.
@@ -5262,9 +6130,10 @@ pub struct DeleteSheetRequest {
 }
 
 /**
- * The metadata visibility. Developer metadata must always have a visibility specified.
+ * Limits the selected developer metadata to that which has a matching DeveloperMetadata.visibility. If left unspecified, all developer metadata visibile to the requesting project is considered.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Visibility {
     #[serde(rename = "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED")]
     DeveloperMetadataVisibilityUnspecified,
@@ -5272,35 +6141,61 @@ pub enum Visibility {
     Document,
     #[serde(rename = "PROJECT")]
     Project,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Visibility {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Visibility {
+    fn as_ref(&self) -> &str {
+        match self {
             Visibility::DeveloperMetadataVisibilityUnspecified => {
                 "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED"
             }
             Visibility::Document => "DOCUMENT",
             Visibility::Project => "PROJECT",
-            Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Visibility {
-    fn default() -> Visibility {
-        Visibility::Noop
+impl std::fmt::Display for Visibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Visibility {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DEVELOPER_METADATA_VISIBILITY_UNSPECIFIED" => {
+                Visibility::DeveloperMetadataVisibilityUnspecified
+            }
+            "DOCUMENT" => Visibility::Document,
+            "PROJECT" => Visibility::Project,
+            _ => Visibility::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Visibility {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Visibility {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Visibility::Noop)
+
+impl std::convert::From<Visibility> for String {
+    fn from(v: Visibility) -> Self {
+        match v {
+            Visibility::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5327,21 +6222,19 @@ pub struct DeveloperMetadata {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "metadataKey"
     )]
-    pub metadata_key: String,
+    pub metadata_key: Option<String>,
     /**
      * Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "metadataValue"
     )]
-    pub metadata_value: String,
+    pub metadata_value: Option<String>,
     /**
      * Developer metadata associated with a location or object in a spreadsheet. Developer metadata may be used to associate arbitrary data with various parts of a spreadsheet and will remain associated at those locations as they move around and the spreadsheet is edited. For example, if developer metadata is associated with row 5 and another row is then subsequently inserted above row 5, that original metadata will still be associated with the row it was first associated with (what is now row 6). If the associated object is deleted its metadata is deleted too.
      */
@@ -5350,9 +6243,10 @@ pub struct DeveloperMetadata {
 }
 
 /**
- * The type of location this object represents. This field is read-only.
+ * Limits the selected developer metadata to those entries which are associated with locations of the specified type. For example, when this field is specified as ROW this lookup only considers developer metadata associated on rows. If the field is left unspecified, all location types are considered. This field cannot be specified as SPREADSHEET when the locationMatchingStrategy is specified as INTERSECTING or when the metadataLocation is specified as a non-spreadsheet location: spreadsheet metadata cannot intersect any other developer metadata location. This field also must be left unspecified when the locationMatchingStrategy is specified as EXACT.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LocationType {
     #[serde(rename = "COLUMN")]
     Column,
@@ -5364,15 +6258,15 @@ pub enum LocationType {
     Sheet,
     #[serde(rename = "SPREADSHEET")]
     Spreadsheet,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LocationType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LocationType {
+    fn as_ref(&self) -> &str {
+        match self {
             LocationType::Column => "COLUMN",
             LocationType::DeveloperMetadataLocationTypeUnspecified => {
                 "DEVELOPER_METADATA_LOCATION_TYPE_UNSPECIFIED"
@@ -5380,21 +6274,49 @@ impl std::fmt::Display for LocationType {
             LocationType::Row => "ROW",
             LocationType::Sheet => "SHEET",
             LocationType::Spreadsheet => "SPREADSHEET",
-            LocationType::Noop => "",
-            LocationType::FallthroughString => "*",
+            LocationType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LocationType {
-    fn default() -> LocationType {
-        LocationType::Noop
+impl std::fmt::Display for LocationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LocationType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COLUMN" => LocationType::Column,
+            "DEVELOPER_METADATA_LOCATION_TYPE_UNSPECIFIED" => {
+                LocationType::DeveloperMetadataLocationTypeUnspecified
+            }
+            "ROW" => LocationType::Row,
+            "SHEET" => LocationType::Sheet,
+            "SPREADSHEET" => LocationType::Spreadsheet,
+            _ => LocationType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LocationType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LocationType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LocationType::Noop)
+
+impl std::convert::From<LocationType> for String {
+    fn from(v: LocationType) -> Self {
+        match v {
+            LocationType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5443,6 +6365,7 @@ pub struct DeveloperMetadataLocation {
  * Determines how this lookup matches the location. If this field is specified as EXACT, only developer metadata associated on the exact location specified is matched. If this field is specified to INTERSECTING, developer metadata associated on intersecting locations is also matched. If left unspecified, this field assumes a default value of INTERSECTING. If this field is specified, a metadataLocation must also be specified.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LocationMatchingStrategy {
     #[serde(rename = "DEVELOPER_METADATA_LOCATION_MATCHING_STRATEGY_UNSPECIFIED")]
     DeveloperMetadataLocationMatchingStrategyUnspecified,
@@ -5450,35 +6373,61 @@ pub enum LocationMatchingStrategy {
     ExactLocation,
     #[serde(rename = "INTERSECTING_LOCATION")]
     IntersectingLocation,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LocationMatchingStrategy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LocationMatchingStrategy {
+    fn as_ref(&self) -> &str {
+        match self {
             LocationMatchingStrategy::DeveloperMetadataLocationMatchingStrategyUnspecified => {
                 "DEVELOPER_METADATA_LOCATION_MATCHING_STRATEGY_UNSPECIFIED"
             }
             LocationMatchingStrategy::ExactLocation => "EXACT_LOCATION",
             LocationMatchingStrategy::IntersectingLocation => "INTERSECTING_LOCATION",
-            LocationMatchingStrategy::Noop => "",
-            LocationMatchingStrategy::FallthroughString => "*",
+            LocationMatchingStrategy::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LocationMatchingStrategy {
-    fn default() -> LocationMatchingStrategy {
-        LocationMatchingStrategy::Noop
+impl std::fmt::Display for LocationMatchingStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LocationMatchingStrategy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DEVELOPER_METADATA_LOCATION_MATCHING_STRATEGY_UNSPECIFIED" => {
+                LocationMatchingStrategy::DeveloperMetadataLocationMatchingStrategyUnspecified
+            }
+            "EXACT_LOCATION" => LocationMatchingStrategy::ExactLocation,
+            "INTERSECTING_LOCATION" => LocationMatchingStrategy::IntersectingLocation,
+            _ => LocationMatchingStrategy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LocationMatchingStrategy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LocationMatchingStrategy {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LocationMatchingStrategy::Noop)
+
+impl std::convert::From<LocationMatchingStrategy> for String {
+    fn from(v: LocationMatchingStrategy) -> Self {
+        match v {
+            LocationMatchingStrategy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5518,11 +6467,10 @@ pub struct DeveloperMetadataLookup {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "metadataKey"
     )]
-    pub metadata_key: String,
+    pub metadata_key: Option<String>,
     /**
      * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
      */
@@ -5536,12 +6484,11 @@ pub struct DeveloperMetadataLookup {
      * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
      */
     #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        default,
+        skip_serializing_if = "Option::is_none",
         rename = "metadataValue"
     )]
-    pub metadata_value: String,
+    pub metadata_value: Option<String>,
     /**
      * Selects DeveloperMetadata that matches all of the specified fields. For example, if only a metadata ID is specified this considers the DeveloperMetadata with that particular unique ID. If a metadata key is specified, this considers all developer metadata with that key. If a key, visibility, and location type are all specified, this considers all developer metadata with that key and visibility that are associated with a location of that type. In general, this selects all DeveloperMetadata that matches the intersection of all the specified fields; any field or combination of fields may be specified.
      */
@@ -5721,11 +6668,10 @@ pub struct DuplicateSheetRequest {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "newSheetName"
     )]
-    pub new_sheet_name: String,
+    pub new_sheet_name: Option<String>,
     /**
      * Duplicates the contents of a sheet.
      */
@@ -5866,6 +6812,7 @@ pub struct EmbeddedObjectPosition {
  * The type of error.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ErrorValueType {
     #[serde(rename = "DIVIDE_BY_ZERO")]
     DivideByZero,
@@ -5887,15 +6834,15 @@ pub enum ErrorValueType {
     Ref,
     #[serde(rename = "VALUE")]
     Value,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ErrorValueType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ErrorValueType {
+    fn as_ref(&self) -> &str {
+        match self {
             ErrorValueType::DivideByZero => "DIVIDE_BY_ZERO",
             ErrorValueType::Error => "ERROR",
             ErrorValueType::ErrorTypeUnspecified => "ERROR_TYPE_UNSPECIFIED",
@@ -5906,21 +6853,52 @@ impl std::fmt::Display for ErrorValueType {
             ErrorValueType::NA => "N_A",
             ErrorValueType::Ref => "REF",
             ErrorValueType::Value => "VALUE",
-            ErrorValueType::Noop => "",
-            ErrorValueType::FallthroughString => "*",
+            ErrorValueType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ErrorValueType {
-    fn default() -> ErrorValueType {
-        ErrorValueType::Noop
+impl std::fmt::Display for ErrorValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ErrorValueType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DIVIDE_BY_ZERO" => ErrorValueType::DivideByZero,
+            "ERROR" => ErrorValueType::Error,
+            "ERROR_TYPE_UNSPECIFIED" => ErrorValueType::ErrorTypeUnspecified,
+            "LOADING" => ErrorValueType::Loading,
+            "NAME" => ErrorValueType::Name,
+            "NULL_VALUE" => ErrorValueType::NullValue,
+            "NUM" => ErrorValueType::Num,
+            "N_A" => ErrorValueType::NA,
+            "REF" => ErrorValueType::Ref,
+            "VALUE" => ErrorValueType::Value,
+            _ => ErrorValueType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ErrorValueType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ErrorValueType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ErrorValueType::Noop)
+
+impl std::convert::From<ErrorValueType> for String {
+    fn from(v: ErrorValueType) -> Self {
+        match v {
+            ErrorValueType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5930,12 +6908,8 @@ pub struct ErrorValue {
     /**
      * An error in a cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
     /**
      * An error in a cell.
      */
@@ -5969,11 +6943,10 @@ pub struct ExtendedValue {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "formulaValue"
     )]
-    pub formula_value: String,
+    pub formula_value: Option<String>,
     /**
      * The kinds of value that a cell in a spreadsheet can have.
      */
@@ -5989,11 +6962,10 @@ pub struct ExtendedValue {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "stringValue"
     )]
-    pub string_value: String,
+    pub string_value: Option<String>,
 }
 
 /// Criteria for showing/hiding rows in a filter or filter view.
@@ -6091,8 +7063,12 @@ pub struct FilterView {
     /**
      * A filter view.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub criteria: Option<FilterCriteria>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub criteria: std::collections::BTreeMap<String, FilterCriteria>,
     /**
      * A filter view.
      */
@@ -6118,11 +7094,10 @@ pub struct FilterView {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "namedRangeId"
     )]
-    pub named_range_id: String,
+    pub named_range_id: Option<String>,
     /**
      * A filter view.
      */
@@ -6141,12 +7116,8 @@ pub struct FilterView {
     /**
      * A filter view.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
 }
 
 /// Finds and replaces data in cells over a range, sheet, or all sheets.
@@ -6160,12 +7131,8 @@ pub struct FindReplaceRequest {
     /**
      * Finds and replaces data in cells over a range, sheet, or all sheets.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub find: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub find: Option<String>,
     /**
      * Finds and replaces data in cells over a range, sheet, or all sheets.
      */
@@ -6197,12 +7164,8 @@ pub struct FindReplaceRequest {
     /**
      * Finds and replaces data in cells over a range, sheet, or all sheets.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub replacement: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replacement: Option<String>,
     /**
      * Finds and replaces data in cells over a range, sheet, or all sheets.
      */
@@ -6279,30 +7242,6 @@ pub struct FindReplaceResponse {
     pub values_changed: i64,
 }
 
-/// The request for retrieving a Spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetSpreadsheetByDataFilterRequest {
-    /**
-     * The request for retrieving a Spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataFilters"
-    )]
-    pub data_filters: Vec<DataFilter>,
-    /**
-     * The request for retrieving a Spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "includeGridData"
-    )]
-    pub include_grid_data: Option<bool>,
-}
-
 /// A rule that applies a gradient color scale format, based on the interpolation points listed. The format of a cell will vary based on its contents as compared to the values of the interpolation points.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GradientRule {
@@ -6544,6 +7483,7 @@ pub struct GridRange {
  * The position of the chart legend.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum HistogramChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -6559,15 +7499,15 @@ pub enum HistogramChartSpecLegendPosition {
     RightLegend,
     #[serde(rename = "TOP_LEGEND")]
     TopLegend,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for HistogramChartSpecLegendPosition {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for HistogramChartSpecLegendPosition {
+    fn as_ref(&self) -> &str {
+        match self {
             HistogramChartSpecLegendPosition::BottomLegend => "BOTTOM_LEGEND",
             HistogramChartSpecLegendPosition::HistogramChartLegendPositionUnspecified => {
                 "HISTOGRAM_CHART_LEGEND_POSITION_UNSPECIFIED"
@@ -6577,21 +7517,51 @@ impl std::fmt::Display for HistogramChartSpecLegendPosition {
             HistogramChartSpecLegendPosition::NoLegend => "NO_LEGEND",
             HistogramChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             HistogramChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
-            HistogramChartSpecLegendPosition::Noop => "",
-            HistogramChartSpecLegendPosition::FallthroughString => "*",
+            HistogramChartSpecLegendPosition::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for HistogramChartSpecLegendPosition {
-    fn default() -> HistogramChartSpecLegendPosition {
-        HistogramChartSpecLegendPosition::Noop
+impl std::fmt::Display for HistogramChartSpecLegendPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for HistogramChartSpecLegendPosition {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BOTTOM_LEGEND" => HistogramChartSpecLegendPosition::BottomLegend,
+            "HISTOGRAM_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                HistogramChartSpecLegendPosition::HistogramChartLegendPositionUnspecified
+            }
+            "INSIDE_LEGEND" => HistogramChartSpecLegendPosition::InsideLegend,
+            "LEFT_LEGEND" => HistogramChartSpecLegendPosition::LeftLegend,
+            "NO_LEGEND" => HistogramChartSpecLegendPosition::NoLegend,
+            "RIGHT_LEGEND" => HistogramChartSpecLegendPosition::RightLegend,
+            "TOP_LEGEND" => HistogramChartSpecLegendPosition::TopLegend,
+            _ => HistogramChartSpecLegendPosition::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for HistogramChartSpecLegendPosition {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl HistogramChartSpecLegendPosition {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, HistogramChartSpecLegendPosition::Noop)
+
+impl std::convert::From<HistogramChartSpecLegendPosition> for String {
+    fn from(v: HistogramChartSpecLegendPosition) -> Self {
+        match v {
+            HistogramChartSpecLegendPosition::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -6738,13 +7708,14 @@ pub struct InsertRangeRequest {
         skip_serializing_if = "Option::is_none",
         rename = "shiftDimension"
     )]
-    pub shift_dimension: Option<Dimension>,
+    pub shift_dimension: Option<ShiftDimension>,
 }
 
 /**
  * How the value should be interpreted.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum InterpolationPointType {
     #[serde(rename = "INTERPOLATION_POINT_TYPE_UNSPECIFIED")]
     InterpolationPointTypeUnspecified,
@@ -6758,15 +7729,15 @@ pub enum InterpolationPointType {
     Percent,
     #[serde(rename = "PERCENTILE")]
     Percentile,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for InterpolationPointType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for InterpolationPointType {
+    fn as_ref(&self) -> &str {
+        match self {
             InterpolationPointType::InterpolationPointTypeUnspecified => {
                 "INTERPOLATION_POINT_TYPE_UNSPECIFIED"
             }
@@ -6775,21 +7746,50 @@ impl std::fmt::Display for InterpolationPointType {
             InterpolationPointType::Number => "NUMBER",
             InterpolationPointType::Percent => "PERCENT",
             InterpolationPointType::Percentile => "PERCENTILE",
-            InterpolationPointType::Noop => "",
-            InterpolationPointType::FallthroughString => "*",
+            InterpolationPointType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for InterpolationPointType {
-    fn default() -> InterpolationPointType {
-        InterpolationPointType::Noop
+impl std::fmt::Display for InterpolationPointType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for InterpolationPointType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "INTERPOLATION_POINT_TYPE_UNSPECIFIED" => {
+                InterpolationPointType::InterpolationPointTypeUnspecified
+            }
+            "MAX" => InterpolationPointType::Max,
+            "MIN" => InterpolationPointType::Min,
+            "NUMBER" => InterpolationPointType::Number,
+            "PERCENT" => InterpolationPointType::Percent,
+            "PERCENTILE" => InterpolationPointType::Percentile,
+            _ => InterpolationPointType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for InterpolationPointType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl InterpolationPointType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, InterpolationPointType::Noop)
+
+impl std::convert::From<InterpolationPointType> for String {
+    fn from(v: InterpolationPointType) -> Self {
+        match v {
+            InterpolationPointType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -6818,12 +7818,8 @@ pub struct InterpolationPoint {
     /**
      * A single interpolation point on a gradient conditional format. These pin the gradient color scale according to the color, type and value chosen.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
 }
 
 /// Represents a time interval, encoded as a Timestamp start (inclusive) and a Timestamp end (exclusive). The start must be less than or equal to the end. When the start equals the end, the interval is empty (matches no time). When both start and end are unspecified, the interval matches any time.
@@ -6899,6 +7895,7 @@ pub struct KeyValueFormat {
  * The dash type of the line.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LineStyleType {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -6918,15 +7915,15 @@ pub enum LineStyleType {
     MediumDashedDotted,
     #[serde(rename = "SOLID")]
     Solid,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LineStyleType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LineStyleType {
+    fn as_ref(&self) -> &str {
+        match self {
             LineStyleType::Custom => "CUSTOM",
             LineStyleType::Dotted => "DOTTED",
             LineStyleType::Invisible => "INVISIBLE",
@@ -6936,21 +7933,51 @@ impl std::fmt::Display for LineStyleType {
             LineStyleType::MediumDashed => "MEDIUM_DASHED",
             LineStyleType::MediumDashedDotted => "MEDIUM_DASHED_DOTTED",
             LineStyleType::Solid => "SOLID",
-            LineStyleType::Noop => "",
-            LineStyleType::FallthroughString => "*",
+            LineStyleType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LineStyleType {
-    fn default() -> LineStyleType {
-        LineStyleType::Noop
+impl std::fmt::Display for LineStyleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LineStyleType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CUSTOM" => LineStyleType::Custom,
+            "DOTTED" => LineStyleType::Dotted,
+            "INVISIBLE" => LineStyleType::Invisible,
+            "LINE_DASH_TYPE_UNSPECIFIED" => LineStyleType::LineDashTypeUnspecified,
+            "LONG_DASHED" => LineStyleType::LongDashed,
+            "LONG_DASHED_DOTTED" => LineStyleType::LongDashedDotted,
+            "MEDIUM_DASHED" => LineStyleType::MediumDashed,
+            "MEDIUM_DASHED_DOTTED" => LineStyleType::MediumDashedDotted,
+            "SOLID" => LineStyleType::Solid,
+            _ => LineStyleType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LineStyleType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LineStyleType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LineStyleType::Noop)
+
+impl std::convert::From<LineStyleType> for String {
+    fn from(v: LineStyleType) -> Self {
+        match v {
+            LineStyleType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -6979,12 +8006,8 @@ pub struct Link {
     /**
      * An external or local reference.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
 }
 
 /// Allows you to manually organize the values in a source data column into buckets with names of your choosing. For example, a pivot table that aggregates population by state: +-------+-------------------+ | State | SUM of Population | +-------+-------------------+ | AK | 0.7 | | AL | 4.8 | | AR | 2.9 | ... +-------+-------------------+ could be turned into a pivot table that aggregates population by time zone by providing a list of groups (for example, groupName = 'Central', items = ['AL', 'AR', 'IA', ...]) to a manual group rule. Note that a similar effect could be achieved by adding a time zone column to the source data and adjusting the pivot table. +-----------+-------------------+ | Time Zone | SUM of Population | +-----------+-------------------+ | Central | 106.3 | | Eastern | 151.9 | | Mountain | 17.4 | ... +-----------+-------------------+
@@ -7072,6 +8095,7 @@ pub struct MatchedValueRange {
  * How the cells should be merged.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MergeType {
     #[serde(rename = "MERGE_ALL")]
     MergeAll,
@@ -7079,33 +8103,57 @@ pub enum MergeType {
     MergeColumns,
     #[serde(rename = "MERGE_ROWS")]
     MergeRows,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for MergeType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for MergeType {
+    fn as_ref(&self) -> &str {
+        match self {
             MergeType::MergeAll => "MERGE_ALL",
             MergeType::MergeColumns => "MERGE_COLUMNS",
             MergeType::MergeRows => "MERGE_ROWS",
-            MergeType::Noop => "",
-            MergeType::FallthroughString => "*",
+            MergeType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for MergeType {
-    fn default() -> MergeType {
-        MergeType::Noop
+impl std::fmt::Display for MergeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for MergeType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "MERGE_ALL" => MergeType::MergeAll,
+            "MERGE_COLUMNS" => MergeType::MergeColumns,
+            "MERGE_ROWS" => MergeType::MergeRows,
+            _ => MergeType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for MergeType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl MergeType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MergeType::Noop)
+
+impl std::convert::From<MergeType> for String {
+    fn from(v: MergeType) -> Self {
+        match v {
+            MergeType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -7150,22 +8198,17 @@ pub struct NamedRange {
     /**
      * A named range.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /**
      * A named range.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "namedRangeId"
     )]
-    pub named_range_id: String,
+    pub named_range_id: Option<String>,
     /**
      * A named range.
      */
@@ -7177,6 +8220,7 @@ pub struct NamedRange {
  * The type of the number format. When writing, this field must be set.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NumberFormatType {
     #[serde(rename = "CURRENCY")]
     Currency,
@@ -7196,15 +8240,15 @@ pub enum NumberFormatType {
     Text,
     #[serde(rename = "TIME")]
     Time,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for NumberFormatType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for NumberFormatType {
+    fn as_ref(&self) -> &str {
+        match self {
             NumberFormatType::Currency => "CURRENCY",
             NumberFormatType::Date => "DATE",
             NumberFormatType::DateTime => "DATE_TIME",
@@ -7214,21 +8258,51 @@ impl std::fmt::Display for NumberFormatType {
             NumberFormatType::Scientific => "SCIENTIFIC",
             NumberFormatType::Text => "TEXT",
             NumberFormatType::Time => "TIME",
-            NumberFormatType::Noop => "",
-            NumberFormatType::FallthroughString => "*",
+            NumberFormatType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for NumberFormatType {
-    fn default() -> NumberFormatType {
-        NumberFormatType::Noop
+impl std::fmt::Display for NumberFormatType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for NumberFormatType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CURRENCY" => NumberFormatType::Currency,
+            "DATE" => NumberFormatType::Date,
+            "DATE_TIME" => NumberFormatType::DateTime,
+            "NUMBER" => NumberFormatType::Number,
+            "NUMBER_FORMAT_TYPE_UNSPECIFIED" => NumberFormatType::NumberFormatTypeUnspecified,
+            "PERCENT" => NumberFormatType::Percent,
+            "SCIENTIFIC" => NumberFormatType::Scientific,
+            "TEXT" => NumberFormatType::Text,
+            "TIME" => NumberFormatType::Time,
+            _ => NumberFormatType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for NumberFormatType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl NumberFormatType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, NumberFormatType::Noop)
+
+impl std::convert::From<NumberFormatType> for String {
+    fn from(v: NumberFormatType) -> Self {
+        match v {
+            NumberFormatType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -7238,12 +8312,8 @@ pub struct NumberFormat {
     /**
      * The number format of a cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub pattern: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
     /**
      * The number format of a cell.
      */
@@ -7255,6 +8325,7 @@ pub struct NumberFormat {
  * The size of the org chart nodes.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NodeSize {
     #[serde(rename = "LARGE")]
     Large,
@@ -7264,34 +8335,59 @@ pub enum NodeSize {
     OrgChartLabelSizeUnspecified,
     #[serde(rename = "SMALL")]
     Small,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for NodeSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for NodeSize {
+    fn as_ref(&self) -> &str {
+        match self {
             NodeSize::Large => "LARGE",
             NodeSize::Medium => "MEDIUM",
             NodeSize::OrgChartLabelSizeUnspecified => "ORG_CHART_LABEL_SIZE_UNSPECIFIED",
             NodeSize::Small => "SMALL",
-            NodeSize::Noop => "",
-            NodeSize::FallthroughString => "*",
+            NodeSize::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for NodeSize {
-    fn default() -> NodeSize {
-        NodeSize::Noop
+impl std::fmt::Display for NodeSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for NodeSize {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "LARGE" => NodeSize::Large,
+            "MEDIUM" => NodeSize::Medium,
+            "ORG_CHART_LABEL_SIZE_UNSPECIFIED" => NodeSize::OrgChartLabelSizeUnspecified,
+            "SMALL" => NodeSize::Small,
+            _ => NodeSize::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for NodeSize {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl NodeSize {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, NodeSize::Noop)
+
+impl std::convert::From<NodeSize> for String {
+    fn from(v: NodeSize) -> Self {
+        match v {
+            NodeSize::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -7451,6 +8547,88 @@ pub struct Padding {
     pub top: i64,
 }
 
+/**
+ * How the data should be pasted.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PasteDataRequestType {
+    #[serde(rename = "PASTE_CONDITIONAL_FORMATTING")]
+    PasteConditionalFormatting,
+    #[serde(rename = "PASTE_DATA_VALIDATION")]
+    PasteDataValidation,
+    #[serde(rename = "PASTE_FORMAT")]
+    PasteFormat,
+    #[serde(rename = "PASTE_FORMULA")]
+    PasteFormula,
+    #[serde(rename = "PASTE_NORMAL")]
+    PasteNormal,
+    #[serde(rename = "PASTE_NO_BORDERS")]
+    PasteNoBorders,
+    #[serde(rename = "PASTE_VALUES")]
+    PasteValues,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PasteDataRequestType {
+    fn as_ref(&self) -> &str {
+        match self {
+            PasteDataRequestType::PasteConditionalFormatting => "PASTE_CONDITIONAL_FORMATTING",
+            PasteDataRequestType::PasteDataValidation => "PASTE_DATA_VALIDATION",
+            PasteDataRequestType::PasteFormat => "PASTE_FORMAT",
+            PasteDataRequestType::PasteFormula => "PASTE_FORMULA",
+            PasteDataRequestType::PasteNormal => "PASTE_NORMAL",
+            PasteDataRequestType::PasteNoBorders => "PASTE_NO_BORDERS",
+            PasteDataRequestType::PasteValues => "PASTE_VALUES",
+            PasteDataRequestType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PasteDataRequestType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PasteDataRequestType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "PASTE_CONDITIONAL_FORMATTING" => PasteDataRequestType::PasteConditionalFormatting,
+            "PASTE_DATA_VALIDATION" => PasteDataRequestType::PasteDataValidation,
+            "PASTE_FORMAT" => PasteDataRequestType::PasteFormat,
+            "PASTE_FORMULA" => PasteDataRequestType::PasteFormula,
+            "PASTE_NORMAL" => PasteDataRequestType::PasteNormal,
+            "PASTE_NO_BORDERS" => PasteDataRequestType::PasteNoBorders,
+            "PASTE_VALUES" => PasteDataRequestType::PasteValues,
+            _ => PasteDataRequestType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PasteDataRequestType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PasteDataRequestType> for String {
+    fn from(v: PasteDataRequestType) -> Self {
+        match v {
+            PasteDataRequestType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
 /// Inserts data into the spreadsheet starting at the specified coordinate.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PasteDataRequest {
@@ -7462,21 +8640,13 @@ pub struct PasteDataRequest {
     /**
      * Inserts data into the spreadsheet starting at the specified coordinate.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub data: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
     /**
      * Inserts data into the spreadsheet starting at the specified coordinate.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub delimiter: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<String>,
     /**
      * Inserts data into the spreadsheet starting at the specified coordinate.
      */
@@ -7486,13 +8656,14 @@ pub struct PasteDataRequest {
      * Inserts data into the spreadsheet starting at the specified coordinate.
      */
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<PasteType>,
+    pub type_: Option<PasteDataRequestType>,
 }
 
 /**
  * Where the legend of the pie chart should be drawn.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PieChartSpecLegendPosition {
     #[serde(rename = "BOTTOM_LEGEND")]
     BottomLegend,
@@ -7508,15 +8679,15 @@ pub enum PieChartSpecLegendPosition {
     RightLegend,
     #[serde(rename = "TOP_LEGEND")]
     TopLegend,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PieChartSpecLegendPosition {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PieChartSpecLegendPosition {
+    fn as_ref(&self) -> &str {
+        match self {
             PieChartSpecLegendPosition::BottomLegend => "BOTTOM_LEGEND",
             PieChartSpecLegendPosition::LabeledLegend => "LABELED_LEGEND",
             PieChartSpecLegendPosition::LeftLegend => "LEFT_LEGEND",
@@ -7526,21 +8697,51 @@ impl std::fmt::Display for PieChartSpecLegendPosition {
             }
             PieChartSpecLegendPosition::RightLegend => "RIGHT_LEGEND",
             PieChartSpecLegendPosition::TopLegend => "TOP_LEGEND",
-            PieChartSpecLegendPosition::Noop => "",
-            PieChartSpecLegendPosition::FallthroughString => "*",
+            PieChartSpecLegendPosition::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PieChartSpecLegendPosition {
-    fn default() -> PieChartSpecLegendPosition {
-        PieChartSpecLegendPosition::Noop
+impl std::fmt::Display for PieChartSpecLegendPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PieChartSpecLegendPosition {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BOTTOM_LEGEND" => PieChartSpecLegendPosition::BottomLegend,
+            "LABELED_LEGEND" => PieChartSpecLegendPosition::LabeledLegend,
+            "LEFT_LEGEND" => PieChartSpecLegendPosition::LeftLegend,
+            "NO_LEGEND" => PieChartSpecLegendPosition::NoLegend,
+            "PIE_CHART_LEGEND_POSITION_UNSPECIFIED" => {
+                PieChartSpecLegendPosition::PieChartLegendPositionUnspecified
+            }
+            "RIGHT_LEGEND" => PieChartSpecLegendPosition::RightLegend,
+            "TOP_LEGEND" => PieChartSpecLegendPosition::TopLegend,
+            _ => PieChartSpecLegendPosition::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PieChartSpecLegendPosition {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PieChartSpecLegendPosition {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PieChartSpecLegendPosition::Noop)
+
+impl std::convert::From<PieChartSpecLegendPosition> for String {
+    fn from(v: PieChartSpecLegendPosition) -> Self {
+        match v {
+            PieChartSpecLegendPosition::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -7650,9 +8851,10 @@ pub struct PivotFilterSpec {
 }
 
 /**
- * The order the values in this group should be sorted.
+ * The order data should be sorted.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SortOrder {
     #[serde(rename = "ASCENDING")]
     Ascending,
@@ -7660,33 +8862,57 @@ pub enum SortOrder {
     Descending,
     #[serde(rename = "SORT_ORDER_UNSPECIFIED")]
     SortOrderUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SortOrder {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SortOrder {
+    fn as_ref(&self) -> &str {
+        match self {
             SortOrder::Ascending => "ASCENDING",
             SortOrder::Descending => "DESCENDING",
             SortOrder::SortOrderUnspecified => "SORT_ORDER_UNSPECIFIED",
-            SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+            SortOrder::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SortOrder {
-    fn default() -> SortOrder {
-        SortOrder::Noop
+impl std::fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SortOrder {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ASCENDING" => SortOrder::Ascending,
+            "DESCENDING" => SortOrder::Descending,
+            "SORT_ORDER_UNSPECIFIED" => SortOrder::SortOrderUnspecified,
+            _ => SortOrder::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SortOrder {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SortOrder {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SortOrder::Noop)
+
+impl std::convert::From<SortOrder> for String {
+    fn from(v: SortOrder) -> Self {
+        match v {
+            SortOrder::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -7719,12 +8945,8 @@ pub struct PivotGroup {
     /**
      * A single grouping (either row or column) in a pivot table.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
     /**
      * A single grouping (either row or column) in a pivot table.
      */
@@ -7882,37 +9104,61 @@ pub struct PivotGroupValueMetadata {
  * Whether values should be listed horizontally (as columns) or vertically (as rows).
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ValueLayout {
     #[serde(rename = "HORIZONTAL")]
     Horizontal,
     #[serde(rename = "VERTICAL")]
     Vertical,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ValueLayout {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ValueLayout {
+    fn as_ref(&self) -> &str {
+        match self {
             ValueLayout::Horizontal => "HORIZONTAL",
             ValueLayout::Vertical => "VERTICAL",
-            ValueLayout::Noop => "",
-            ValueLayout::FallthroughString => "*",
+            ValueLayout::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ValueLayout {
-    fn default() -> ValueLayout {
-        ValueLayout::Noop
+impl std::fmt::Display for ValueLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ValueLayout {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "HORIZONTAL" => ValueLayout::Horizontal,
+            "VERTICAL" => ValueLayout::Vertical,
+            _ => ValueLayout::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ValueLayout {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ValueLayout {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ValueLayout::Noop)
+
+impl std::convert::From<ValueLayout> for String {
+    fn from(v: ValueLayout) -> Self {
+        match v {
+            ValueLayout::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -7931,8 +9177,12 @@ pub struct PivotTable {
     /**
      * A pivot table.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub criteria: Option<PivotFilterCriteria>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub criteria: std::collections::BTreeMap<String, PivotFilterCriteria>,
     /**
      * A pivot table.
      */
@@ -7947,11 +9197,10 @@ pub struct PivotTable {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "dataSourceId"
     )]
-    pub data_source_id: String,
+    pub data_source_id: Option<String>,
     /**
      * A pivot table.
      */
@@ -8000,6 +9249,7 @@ pub struct PivotTable {
  * If specified, indicates that pivot values should be displayed as the result of a calculation with another pivot value. For example, if calculated_display_type is specified as PERCENT_OF_GRAND_TOTAL, all the pivot values are displayed as the percentage of the grand total. In the Sheets editor, this is referred to as "Show As" in the value section of a pivot table.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CalculatedDisplayType {
     #[serde(rename = "PERCENT_OF_COLUMN_TOTAL")]
     PercentOfColumnTotal,
@@ -8009,36 +9259,63 @@ pub enum CalculatedDisplayType {
     PercentOfRowTotal,
     #[serde(rename = "PIVOT_VALUE_CALCULATED_DISPLAY_TYPE_UNSPECIFIED")]
     PivotValueCalculatedDisplayTypeUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for CalculatedDisplayType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for CalculatedDisplayType {
+    fn as_ref(&self) -> &str {
+        match self {
             CalculatedDisplayType::PercentOfColumnTotal => "PERCENT_OF_COLUMN_TOTAL",
             CalculatedDisplayType::PercentOfGrandTotal => "PERCENT_OF_GRAND_TOTAL",
             CalculatedDisplayType::PercentOfRowTotal => "PERCENT_OF_ROW_TOTAL",
             CalculatedDisplayType::PivotValueCalculatedDisplayTypeUnspecified => {
                 "PIVOT_VALUE_CALCULATED_DISPLAY_TYPE_UNSPECIFIED"
             }
-            CalculatedDisplayType::Noop => "",
-            CalculatedDisplayType::FallthroughString => "*",
+            CalculatedDisplayType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for CalculatedDisplayType {
-    fn default() -> CalculatedDisplayType {
-        CalculatedDisplayType::Noop
+impl std::fmt::Display for CalculatedDisplayType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CalculatedDisplayType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "PERCENT_OF_COLUMN_TOTAL" => CalculatedDisplayType::PercentOfColumnTotal,
+            "PERCENT_OF_GRAND_TOTAL" => CalculatedDisplayType::PercentOfGrandTotal,
+            "PERCENT_OF_ROW_TOTAL" => CalculatedDisplayType::PercentOfRowTotal,
+            "PIVOT_VALUE_CALCULATED_DISPLAY_TYPE_UNSPECIFIED" => {
+                CalculatedDisplayType::PivotValueCalculatedDisplayTypeUnspecified
+            }
+            _ => CalculatedDisplayType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CalculatedDisplayType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl CalculatedDisplayType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, CalculatedDisplayType::Noop)
+
+impl std::convert::From<CalculatedDisplayType> for String {
+    fn from(v: CalculatedDisplayType) -> Self {
+        match v {
+            CalculatedDisplayType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -8046,6 +9323,7 @@ impl CalculatedDisplayType {
  * A function to summarize the value. If formula is set, the only supported values are SUM and CUSTOM. If sourceColumnOffset is set, then `CUSTOM` is not supported.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SummarizeFunction {
     #[serde(rename = "AVERAGE")]
     Average,
@@ -8077,15 +9355,15 @@ pub enum SummarizeFunction {
     Var,
     #[serde(rename = "VARP")]
     Varp,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SummarizeFunction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SummarizeFunction {
+    fn as_ref(&self) -> &str {
+        match self {
             SummarizeFunction::Average => "AVERAGE",
             SummarizeFunction::Count => "COUNT",
             SummarizeFunction::Counta => "COUNTA",
@@ -8103,21 +9381,59 @@ impl std::fmt::Display for SummarizeFunction {
             SummarizeFunction::Sum => "SUM",
             SummarizeFunction::Var => "VAR",
             SummarizeFunction::Varp => "VARP",
-            SummarizeFunction::Noop => "",
-            SummarizeFunction::FallthroughString => "*",
+            SummarizeFunction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SummarizeFunction {
-    fn default() -> SummarizeFunction {
-        SummarizeFunction::Noop
+impl std::fmt::Display for SummarizeFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SummarizeFunction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AVERAGE" => SummarizeFunction::Average,
+            "COUNT" => SummarizeFunction::Count,
+            "COUNTA" => SummarizeFunction::Counta,
+            "COUNTUNIQUE" => SummarizeFunction::Countunique,
+            "CUSTOM" => SummarizeFunction::Custom,
+            "MAX" => SummarizeFunction::Max,
+            "MEDIAN" => SummarizeFunction::Median,
+            "MIN" => SummarizeFunction::Min,
+            "PIVOT_STANDARD_VALUE_FUNCTION_UNSPECIFIED" => {
+                SummarizeFunction::PivotStandardValueFunctionUnspecified
+            }
+            "PRODUCT" => SummarizeFunction::Product,
+            "STDEV" => SummarizeFunction::Stdev,
+            "STDEVP" => SummarizeFunction::Stdevp,
+            "SUM" => SummarizeFunction::Sum,
+            "VAR" => SummarizeFunction::Var,
+            "VARP" => SummarizeFunction::Varp,
+            _ => SummarizeFunction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SummarizeFunction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SummarizeFunction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SummarizeFunction::Noop)
+
+impl std::convert::From<SummarizeFunction> for String {
+    fn from(v: SummarizeFunction) -> Self {
+        match v {
+            SummarizeFunction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -8145,21 +9461,13 @@ pub struct PivotValue {
     /**
      * The definition of how a value in a pivot table should be calculated.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub formula: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formula: Option<String>,
     /**
      * The definition of how a value in a pivot table should be calculated.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
     /**
      * The definition of how a value in a pivot table should be calculated.
      */
@@ -8185,6 +9493,7 @@ pub struct PivotValue {
  * The point shape. If empty or unspecified, a default shape is used.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Shape {
     #[serde(rename = "CIRCLE")]
     Circle,
@@ -8204,15 +9513,15 @@ pub enum Shape {
     Triangle,
     #[serde(rename = "X_MARK")]
     XMark,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Shape {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Shape {
+    fn as_ref(&self) -> &str {
+        match self {
             Shape::Circle => "CIRCLE",
             Shape::Diamond => "DIAMOND",
             Shape::Hexagon => "HEXAGON",
@@ -8222,21 +9531,51 @@ impl std::fmt::Display for Shape {
             Shape::Star => "STAR",
             Shape::Triangle => "TRIANGLE",
             Shape::XMark => "X_MARK",
-            Shape::Noop => "",
-            Shape::FallthroughString => "*",
+            Shape::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Shape {
-    fn default() -> Shape {
-        Shape::Noop
+impl std::fmt::Display for Shape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Shape {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CIRCLE" => Shape::Circle,
+            "DIAMOND" => Shape::Diamond,
+            "HEXAGON" => Shape::Hexagon,
+            "PENTAGON" => Shape::Pentagon,
+            "POINT_SHAPE_UNSPECIFIED" => Shape::PointShapeUnspecified,
+            "SQUARE" => Shape::Square,
+            "STAR" => Shape::Star,
+            "TRIANGLE" => Shape::Triangle,
+            "X_MARK" => Shape::XMark,
+            _ => Shape::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Shape {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Shape {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Shape::Noop)
+
+impl std::convert::From<Shape> for String {
+    fn from(v: Shape) -> Self {
+        match v {
+            Shape::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -8265,12 +9604,8 @@ pub struct ProtectedRange {
     /**
      * A protected range.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     /**
      * A protected range.
      */
@@ -8281,11 +9616,10 @@ pub struct ProtectedRange {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "namedRangeId"
     )]
-    pub named_range_id: String,
+    pub named_range_id: Option<String>,
     /**
      * A protected range.
      */
@@ -8368,11 +9702,10 @@ pub struct RefreshDataSourceRequest {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "dataSourceId"
     )]
-    pub data_source_id: String,
+    pub data_source_id: Option<String>,
     /**
      * Refreshes one or multiple data source objects in the spreadsheet by the specified references. The request requires an additional `bigquery.readonly` OAuth scope. If there are multiple refresh requests referencing the same data source objects in one batch, only the last refresh request is processed, and all those requests will have the same response accordingly.
      */
@@ -8415,12 +9748,8 @@ pub struct RepeatCellRequest {
     /**
      * Updates all cells in the range to the values in the given Cell object. Only the fields listed in the fields field are updated; others are unchanged. If writing a cell with a formula, the formula's ranges will automatically increment for each field in the range. For example, if writing a cell with formula `=A1` into range B2:C4, B2 would be `=A1`, B3 would be `=A2`, B4 would be `=A3`, C2 would be `=B1`, C3 would be `=B2`, C4 would be `=B3`. To keep the formula's ranges static, use the `$` indicator. For example, use the formula `=$A$1` to prevent both the row and the column from incrementing.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
     /**
      * Updates all cells in the range to the values in the given Cell object. Only the fields listed in the fields field are updated; others are unchanged. If writing a cell with a formula, the formula's ranges will automatically increment for each field in the range. For example, if writing a cell with formula `=A1` into range B2:C4, B2 would be `=A1`, B3 would be `=A2`, B4 would be `=A3`, C2 would be `=B1`, C3 would be `=B2`, C4 would be `=B3`. To keep the formula's ranges static, use the `$` indicator. For example, use the formula `=$A$1` to prevent both the row and the column from incrementing.
      */
@@ -9204,6 +10533,7 @@ pub struct RowData {
  * The number format source used in the scorecard chart. This field is optional.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NumberFormatSource {
     #[serde(rename = "CHART_NUMBER_FORMAT_SOURCE_UNDEFINED")]
     ChartNumberFormatSourceUndefined,
@@ -9211,35 +10541,61 @@ pub enum NumberFormatSource {
     Custom,
     #[serde(rename = "FROM_DATA")]
     FromData,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for NumberFormatSource {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for NumberFormatSource {
+    fn as_ref(&self) -> &str {
+        match self {
             NumberFormatSource::ChartNumberFormatSourceUndefined => {
                 "CHART_NUMBER_FORMAT_SOURCE_UNDEFINED"
             }
             NumberFormatSource::Custom => "CUSTOM",
             NumberFormatSource::FromData => "FROM_DATA",
-            NumberFormatSource::Noop => "",
-            NumberFormatSource::FallthroughString => "*",
+            NumberFormatSource::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for NumberFormatSource {
-    fn default() -> NumberFormatSource {
-        NumberFormatSource::Noop
+impl std::fmt::Display for NumberFormatSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for NumberFormatSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CHART_NUMBER_FORMAT_SOURCE_UNDEFINED" => {
+                NumberFormatSource::ChartNumberFormatSourceUndefined
+            }
+            "CUSTOM" => NumberFormatSource::Custom,
+            "FROM_DATA" => NumberFormatSource::FromData,
+            _ => NumberFormatSource::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for NumberFormatSource {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl NumberFormatSource {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, NumberFormatSource::Noop)
+
+impl std::convert::From<NumberFormatSource> for String {
+    fn from(v: NumberFormatSource) -> Self {
+        match v {
+            NumberFormatSource::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -9321,36 +10677,6 @@ pub struct ScorecardChartSpec {
     pub scale_factor: f64,
 }
 
-/// A request to retrieve all developer metadata matching the set of specified criteria.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SearchDeveloperMetadataRequest {
-    /**
-     * A request to retrieve all developer metadata matching the set of specified criteria.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataFilters"
-    )]
-    pub data_filters: Vec<DataFilter>,
-}
-
-/// A reply to a developer metadata search request.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SearchDeveloperMetadataResponse {
-    /**
-     * A reply to a developer metadata search request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "matchedDeveloperMetadata"
-    )]
-    pub matched_developer_metadata: Vec<MatchedDeveloperMetadata>,
-}
-
 /// Sets the basic filter associated with a sheet.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SetBasicFilterRequest {
@@ -9505,6 +10831,7 @@ pub struct Sheet {
  * The type of sheet. Defaults to GRID. This field cannot be changed once set.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SheetType {
     #[serde(rename = "DATA_SOURCE")]
     DataSource,
@@ -9514,34 +10841,59 @@ pub enum SheetType {
     Object,
     #[serde(rename = "SHEET_TYPE_UNSPECIFIED")]
     SheetTypeUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SheetType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SheetType {
+    fn as_ref(&self) -> &str {
+        match self {
             SheetType::DataSource => "DATA_SOURCE",
             SheetType::Grid => "GRID",
             SheetType::Object => "OBJECT",
             SheetType::SheetTypeUnspecified => "SHEET_TYPE_UNSPECIFIED",
-            SheetType::Noop => "",
-            SheetType::FallthroughString => "*",
+            SheetType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SheetType {
-    fn default() -> SheetType {
-        SheetType::Noop
+impl std::fmt::Display for SheetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SheetType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DATA_SOURCE" => SheetType::DataSource,
+            "GRID" => SheetType::Grid,
+            "OBJECT" => SheetType::Object,
+            "SHEET_TYPE_UNSPECIFIED" => SheetType::SheetTypeUnspecified,
+            _ => SheetType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SheetType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SheetType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SheetType::Noop)
+
+impl std::convert::From<SheetType> for String {
+    fn from(v: SheetType) -> Self {
+        match v {
+            SheetType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -9624,12 +10976,8 @@ pub struct SheetProperties {
     /**
      * Properties of a sheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
 }
 
 /// A slicer in a sheet.
@@ -9732,12 +11080,8 @@ pub struct SlicerSpec {
     /**
      * The specifications of a slicer.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
 }
 
 /// Sorts data in rows based on a sort order per column.
@@ -9850,89 +11194,11 @@ pub struct SourceDestination {
     pub source: Option<GridRange>,
 }
 
-/// Resource that represents a spreadsheet.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Spreadsheet {
-    /**
-     * Resource that represents a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataSourceSchedules"
-    )]
-    pub data_source_schedules: Vec<DataSourceRefreshSchedule>,
-    /**
-     * Resource that represents a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dataSources"
-    )]
-    pub data_sources: Vec<DataSource>,
-    /**
-     * Resource that represents a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "developerMetadata"
-    )]
-    pub developer_metadata: Vec<DeveloperMetadata>,
-    /**
-     * Resource that represents a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "namedRanges"
-    )]
-    pub named_ranges: Vec<NamedRange>,
-    /**
-     * Resource that represents a spreadsheet.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<SpreadsheetProperties>,
-    /**
-     * Resource that represents a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub sheets: Vec<Sheet>,
-    /**
-     * Resource that represents a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetId"
-    )]
-    pub spreadsheet_id: String,
-    /**
-     * Resource that represents a spreadsheet.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spreadsheetUrl"
-    )]
-    pub spreadsheet_url: String,
-}
-
 /**
  * The amount of time to wait before volatile functions are recalculated.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AutoRecalc {
     #[serde(rename = "HOUR")]
     Hour,
@@ -9942,34 +11208,59 @@ pub enum AutoRecalc {
     OnChange,
     #[serde(rename = "RECALCULATION_INTERVAL_UNSPECIFIED")]
     RecalculationIntervalUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AutoRecalc {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AutoRecalc {
+    fn as_ref(&self) -> &str {
+        match self {
             AutoRecalc::Hour => "HOUR",
             AutoRecalc::Minute => "MINUTE",
             AutoRecalc::OnChange => "ON_CHANGE",
             AutoRecalc::RecalculationIntervalUnspecified => "RECALCULATION_INTERVAL_UNSPECIFIED",
-            AutoRecalc::Noop => "",
-            AutoRecalc::FallthroughString => "*",
+            AutoRecalc::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AutoRecalc {
-    fn default() -> AutoRecalc {
-        AutoRecalc::Noop
+impl std::fmt::Display for AutoRecalc {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AutoRecalc {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "HOUR" => AutoRecalc::Hour,
+            "MINUTE" => AutoRecalc::Minute,
+            "ON_CHANGE" => AutoRecalc::OnChange,
+            "RECALCULATION_INTERVAL_UNSPECIFIED" => AutoRecalc::RecalculationIntervalUnspecified,
+            _ => AutoRecalc::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AutoRecalc {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AutoRecalc {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AutoRecalc::Noop)
+
+impl std::convert::From<AutoRecalc> for String {
+    fn from(v: AutoRecalc) -> Self {
+        match v {
+            AutoRecalc::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -10006,12 +11297,8 @@ pub struct SpreadsheetProperties {
     /**
      * Properties of a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub locale: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
     /**
      * Properties of a spreadsheet.
      */
@@ -10024,22 +11311,13 @@ pub struct SpreadsheetProperties {
     /**
      * Properties of a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "timeZone"
-    )]
-    pub time_zone: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "timeZone")]
+    pub time_zone: Option<String>,
     /**
      * Properties of a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
 }
 
 /// Represents spreadsheet theme
@@ -10050,11 +11328,10 @@ pub struct SpreadsheetTheme {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "primaryFontFamily"
     )]
-    pub primary_font_family: String,
+    pub primary_font_family: Option<String>,
     /**
      * Represents spreadsheet theme
      */
@@ -10083,11 +11360,10 @@ pub struct TextFormat {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "fontFamily"
     )]
-    pub font_family: String,
+    pub font_family: Option<String>,
     /**
      * The format of a run of text in a cell. Absent values indicate that the field isn't specified.
      */
@@ -10207,6 +11483,7 @@ pub struct TextRotation {
  * The delimiter type to use.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DelimiterType {
     #[serde(rename = "AUTODETECT")]
     Autodetect,
@@ -10222,15 +11499,15 @@ pub enum DelimiterType {
     Semicolon,
     #[serde(rename = "SPACE")]
     Space,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DelimiterType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DelimiterType {
+    fn as_ref(&self) -> &str {
+        match self {
             DelimiterType::Autodetect => "AUTODETECT",
             DelimiterType::Comma => "COMMA",
             DelimiterType::Custom => "CUSTOM",
@@ -10238,21 +11515,49 @@ impl std::fmt::Display for DelimiterType {
             DelimiterType::Period => "PERIOD",
             DelimiterType::Semicolon => "SEMICOLON",
             DelimiterType::Space => "SPACE",
-            DelimiterType::Noop => "",
-            DelimiterType::FallthroughString => "*",
+            DelimiterType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DelimiterType {
-    fn default() -> DelimiterType {
-        DelimiterType::Noop
+impl std::fmt::Display for DelimiterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DelimiterType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AUTODETECT" => DelimiterType::Autodetect,
+            "COMMA" => DelimiterType::Comma,
+            "CUSTOM" => DelimiterType::Custom,
+            "DELIMITER_TYPE_UNSPECIFIED" => DelimiterType::DelimiterTypeUnspecified,
+            "PERIOD" => DelimiterType::Period,
+            "SEMICOLON" => DelimiterType::Semicolon,
+            "SPACE" => DelimiterType::Space,
+            _ => DelimiterType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DelimiterType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DelimiterType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DelimiterType::Noop)
+
+impl std::convert::From<DelimiterType> for String {
+    fn from(v: DelimiterType) -> Self {
+        match v {
+            DelimiterType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -10262,12 +11567,8 @@ pub struct TextColumnsRequest {
     /**
      * Splits a column of text into multiple columns, based on a delimiter in each cell.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub delimiter: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delimiter: Option<String>,
     /**
      * Splits a column of text into multiple columns, based on a delimiter in each cell.
      */
@@ -10284,6 +11585,100 @@ pub struct TextColumnsRequest {
     pub source: Option<GridRange>,
 }
 
+/**
+ * The type of the spreadsheet theme color.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ColorType {
+    #[serde(rename = "ACCENT1")]
+    Accent1,
+    #[serde(rename = "ACCENT2")]
+    Accent2,
+    #[serde(rename = "ACCENT3")]
+    Accent3,
+    #[serde(rename = "ACCENT4")]
+    Accent4,
+    #[serde(rename = "ACCENT5")]
+    Accent5,
+    #[serde(rename = "ACCENT6")]
+    Accent6,
+    #[serde(rename = "BACKGROUND")]
+    Background,
+    #[serde(rename = "LINK")]
+    Link,
+    #[serde(rename = "TEXT")]
+    Text,
+    #[serde(rename = "THEME_COLOR_TYPE_UNSPECIFIED")]
+    ThemeColorTypeUnspecified,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ColorType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ColorType::Accent1 => "ACCENT1",
+            ColorType::Accent2 => "ACCENT2",
+            ColorType::Accent3 => "ACCENT3",
+            ColorType::Accent4 => "ACCENT4",
+            ColorType::Accent5 => "ACCENT5",
+            ColorType::Accent6 => "ACCENT6",
+            ColorType::Background => "BACKGROUND",
+            ColorType::Link => "LINK",
+            ColorType::Text => "TEXT",
+            ColorType::ThemeColorTypeUnspecified => "THEME_COLOR_TYPE_UNSPECIFIED",
+            ColorType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ColorType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ColorType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACCENT1" => ColorType::Accent1,
+            "ACCENT2" => ColorType::Accent2,
+            "ACCENT3" => ColorType::Accent3,
+            "ACCENT4" => ColorType::Accent4,
+            "ACCENT5" => ColorType::Accent5,
+            "ACCENT6" => ColorType::Accent6,
+            "BACKGROUND" => ColorType::Background,
+            "LINK" => ColorType::Link,
+            "TEXT" => ColorType::Text,
+            "THEME_COLOR_TYPE_UNSPECIFIED" => ColorType::ThemeColorTypeUnspecified,
+            _ => ColorType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ColorType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ColorType> for String {
+    fn from(v: ColorType) -> Self {
+        match v {
+            ColorType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
 /// A pair mapping a spreadsheet theme color type to the concrete color it represents.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ThemeColorPair {
@@ -10580,12 +11975,8 @@ pub struct UpdateBandingRequest {
     /**
      * Updates properties of the supplied banded range.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
 }
 
 /// Updates the borders of a range. If a field is not set in the request, that means the border remains as-is. For example, with two subsequent UpdateBordersRequest: 1. range: A1:A5 `{ top: RED, bottom: WHITE }` 2. range: A1:A5 `{ left: BLUE }` That would result in A1:A5 having a borders of `{ top: RED, bottom: WHITE, left: BLUE }`. If you want to clear a border, explicitly set the style to NONE.
@@ -10642,12 +12033,8 @@ pub struct UpdateCellsRequest {
     /**
      * Updates all cells in a range with new data.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
     /**
      * Updates all cells in a range with new data.
      */
@@ -10778,12 +12165,8 @@ pub struct UpdateDataSourceRequest {
     /**
      * Updates a data source. After the data source is updated successfully, an execution is triggered to refresh the associated DATA_SOURCE sheet to read data from the updated data source. The request requires an additional `bigquery.readonly` OAuth scope.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
 }
 
 /// The response from updating data source.
@@ -10834,12 +12217,8 @@ pub struct UpdateDeveloperMetadataRequest {
     /**
      * A request to update properties of developer metadata. Updates the properties of the developer metadata selected by the filters to the values provided in the DeveloperMetadata resource. Callers must specify the properties they wish to update in the fields parameter, as well as specify at least one DataFilter matching the metadata they wish to update.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
 }
 
 /// The response from updating developer metadata.
@@ -10872,12 +12251,8 @@ pub struct UpdateDimensionGroupRequest {
     /**
      * Updates the state of the specified group.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
 }
 
 /// Updates properties of dimensions within the specified range.
@@ -10895,12 +12270,8 @@ pub struct UpdateDimensionPropertiesRequest {
     /**
      * Updates properties of dimensions within the specified range.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
     /**
      * Updates properties of dimensions within the specified range.
      */
@@ -10924,12 +12295,8 @@ pub struct UpdateEmbeddedObjectBorderRequest {
     /**
      * Updates an embedded object's border property.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
     /**
      * Updates an embedded object's border property.
      */
@@ -10948,12 +12315,8 @@ pub struct UpdateEmbeddedObjectPositionRequest {
     /**
      * Update an embedded object's position (such as a moving or resizing a chart or image).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
     /**
      * Update an embedded object's position (such as a moving or resizing a chart or image).
      */
@@ -10991,12 +12354,8 @@ pub struct UpdateFilterViewRequest {
     /**
      * Updates properties of the filter view.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
     /**
      * Updates properties of the filter view.
      */
@@ -11010,12 +12369,8 @@ pub struct UpdateNamedRangeRequest {
     /**
      * Updates properties of the named range with the specified namedRangeId.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
     /**
      * Updates properties of the named range with the specified namedRangeId.
      */
@@ -11033,12 +12388,8 @@ pub struct UpdateProtectedRangeRequest {
     /**
      * Updates an existing protected range with the specified protectedRangeId.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
     /**
      * Updates an existing protected range with the specified protectedRangeId.
      */
@@ -11056,12 +12407,8 @@ pub struct UpdateSheetPropertiesRequest {
     /**
      * Updates properties of the sheet with the specified sheetId.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
     /**
      * Updates properties of the sheet with the specified sheetId.
      */
@@ -11075,12 +12422,8 @@ pub struct UpdateSlicerSpecRequest {
     /**
      * Updates a slicer's specifications. (This does not move or resize a slicer. To move or resize a slicer use UpdateEmbeddedObjectPositionRequest.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
     /**
      * Updates a slicer's specifications. (This does not move or resize a slicer. To move or resize a slicer use UpdateEmbeddedObjectPositionRequest.
      */
@@ -11104,12 +12447,8 @@ pub struct UpdateSpreadsheetPropertiesRequest {
     /**
      * Updates properties of a spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fields: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
     /**
      * Updates properties of a spreadsheet.
      */
@@ -11255,16 +12594,12 @@ pub struct ValueRange {
         skip_serializing_if = "Option::is_none",
         rename = "majorDimension"
     )]
-    pub major_dimension: Option<Dimension>,
+    pub major_dimension: Option<MajorDimension>,
     /**
      * Data within a range of the spreadsheet.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub range: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub range: Option<String>,
     /**
      * Data within a range of the spreadsheet.
      */
@@ -11296,12 +12631,8 @@ pub struct WaterfallChartColumnStyle {
     /**
      * Styles for a waterfall chart column.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
 }
 
 /// A custom subtotal column for a waterfall chart series.
@@ -11319,12 +12650,8 @@ pub struct WaterfallChartCustomSubtotal {
     /**
      * A custom subtotal column for a waterfall chart series.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
     /**
      * A custom subtotal column for a waterfall chart series.
      */
@@ -11420,6 +12747,7 @@ pub struct WaterfallChartSeries {
  * The stacked type.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WaterfallChartSpecStackedType {
     #[serde(rename = "SEQUENTIAL")]
     Sequential,
@@ -11427,35 +12755,61 @@ pub enum WaterfallChartSpecStackedType {
     Stacked,
     #[serde(rename = "WATERFALL_STACKED_TYPE_UNSPECIFIED")]
     WaterfallStackedTypeUnspecified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for WaterfallChartSpecStackedType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for WaterfallChartSpecStackedType {
+    fn as_ref(&self) -> &str {
+        match self {
             WaterfallChartSpecStackedType::Sequential => "SEQUENTIAL",
             WaterfallChartSpecStackedType::Stacked => "STACKED",
             WaterfallChartSpecStackedType::WaterfallStackedTypeUnspecified => {
                 "WATERFALL_STACKED_TYPE_UNSPECIFIED"
             }
-            WaterfallChartSpecStackedType::Noop => "",
-            WaterfallChartSpecStackedType::FallthroughString => "*",
+            WaterfallChartSpecStackedType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for WaterfallChartSpecStackedType {
-    fn default() -> WaterfallChartSpecStackedType {
-        WaterfallChartSpecStackedType::Noop
+impl std::fmt::Display for WaterfallChartSpecStackedType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for WaterfallChartSpecStackedType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SEQUENTIAL" => WaterfallChartSpecStackedType::Sequential,
+            "STACKED" => WaterfallChartSpecStackedType::Stacked,
+            "WATERFALL_STACKED_TYPE_UNSPECIFIED" => {
+                WaterfallChartSpecStackedType::WaterfallStackedTypeUnspecified
+            }
+            _ => WaterfallChartSpecStackedType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for WaterfallChartSpecStackedType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl WaterfallChartSpecStackedType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, WaterfallChartSpecStackedType::Noop)
+
+impl std::convert::From<WaterfallChartSpecStackedType> for String {
+    fn from(v: WaterfallChartSpecStackedType) -> Self {
+        match v {
+            WaterfallChartSpecStackedType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -11522,120 +12876,3 @@ pub struct WaterfallChartSpec {
     )]
     pub total_data_label: Option<DataLabel>,
 }
-
-/**
- * V1 error format.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Xgafv {
-    #[serde(rename = "1")]
-    One,
-    #[serde(rename = "2")]
-    Two,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Xgafv {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Xgafv::One => "1",
-            Xgafv::Two => "2",
-            Xgafv::Noop => "",
-            Xgafv::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Xgafv {
-    fn default() -> Xgafv {
-        Xgafv::Noop
-    }
-}
-impl Xgafv {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Xgafv::Noop)
-    }
-}
-
-/**
- * Data format for response.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Alt {
-    #[serde(rename = "json")]
-    Json,
-    #[serde(rename = "media")]
-    Media,
-    #[serde(rename = "proto")]
-    Proto,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Alt {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Alt::Json => "json",
-            Alt::Media => "media",
-            Alt::Proto => "proto",
-            Alt::Noop => "",
-            Alt::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Alt {
-    fn default() -> Alt {
-        Alt::Noop
-    }
-}
-impl Alt {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Alt::Noop)
-    }
-}
-
-/**
- * How the input data should be inserted.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum InsertDataOption {
-    #[serde(rename = "INSERT_ROWS")]
-    InsertRows,
-    #[serde(rename = "OVERWRITE")]
-    Overwrite,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for InsertDataOption {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            InsertDataOption::InsertRows => "INSERT_ROWS",
-            InsertDataOption::Overwrite => "OVERWRITE",
-            InsertDataOption::Noop => "",
-            InsertDataOption::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for InsertDataOption {
-    fn default() -> InsertDataOption {
-        InsertDataOption::Noop
-    }
-}
-impl InsertDataOption {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, InsertDataOption::Noop)
-    }
-}
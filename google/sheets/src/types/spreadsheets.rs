@@ -0,0 +1,873 @@
+//! The data types of the `spreadsheets` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// The response when updating a range of values in a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppendValuesResponse {
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "spreadsheetId"
+    )]
+    pub spreadsheet_id: String,
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "tableRange"
+    )]
+    pub table_range: String,
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updates: Option<UpdateValuesResponse>,
+}
+
+/// The request for clearing more than one range selected by a DataFilter in a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchClearValuesByDataFilterRequest {
+    /**
+     * The request for clearing more than one range selected by a DataFilter in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "dataFilters"
+    )]
+    pub data_filters: Vec<DataFilter>,
+}
+
+/// The response when clearing a range of values selected with DataFilters in a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchClearValuesByDataFilterResponse {
+    /**
+     * The response when clearing a range of values selected with DataFilters in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "clearedRanges"
+    )]
+    pub cleared_ranges: Vec<String>,
+    /**
+     * The response when clearing a range of values selected with DataFilters in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "spreadsheetId"
+    )]
+    pub spreadsheet_id: String,
+}
+
+/// The request for clearing more than one range of values in a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchClearValuesRequest {
+    /**
+     * The request for clearing more than one range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub ranges: Vec<String>,
+}
+
+/// The response when clearing a range of values in a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchClearValuesResponse {
+    /**
+     * The response when clearing a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "clearedRanges"
+    )]
+    pub cleared_ranges: Vec<String>,
+    /**
+     * The response when clearing a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "spreadsheetId"
+    )]
+    pub spreadsheet_id: String,
+}
+
+/// The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchGetValuesByDataFilterRequest {
+    /**
+     * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "dataFilters"
+    )]
+    pub data_filters: Vec<DataFilter>,
+    /**
+     * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "dateTimeRenderOption"
+    )]
+    pub date_time_render_option: Option<DateTimeRenderOption>,
+    /**
+     * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "majorDimension"
+    )]
+    pub major_dimension: Option<MajorDimension>,
+    /**
+     * The request for retrieving a range of values in a spreadsheet selected by a set of DataFilters.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "valueRenderOption"
+    )]
+    pub value_render_option: Option<ValueRenderOption>,
+}
+
+/// The response when retrieving more than one range of values in a spreadsheet selected by DataFilters.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchGetValuesByDataFilterResponse {
+    /**
+     * The response when retrieving more than one range of values in a spreadsheet selected by DataFilters.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "spreadsheetId"
+    )]
+    pub spreadsheet_id: String,
+    /**
+     * The response when retrieving more than one range of values in a spreadsheet selected by DataFilters.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "valueRanges"
+    )]
+    pub value_ranges: Vec<MatchedValueRange>,
+}
+
+/// The response when retrieving more than one range of values in a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchGetValuesResponse {
+    /**
+     * The response when retrieving more than one range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "spreadsheetId"
+    )]
+    pub spreadsheet_id: String,
+    /**
+     * The response when retrieving more than one range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "valueRanges"
+    )]
+    pub value_ranges: Vec<ValueRange>,
+}
+
+/// The request for updating any aspect of a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchUpdateSpreadsheetRequest {
+    /**
+     * The request for updating any aspect of a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeSpreadsheetInResponse"
+    )]
+    pub include_spreadsheet_in_response: Option<bool>,
+    /**
+     * The request for updating any aspect of a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub requests: Vec<Request>,
+    /**
+     * The request for updating any aspect of a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "responseIncludeGridData"
+    )]
+    pub response_include_grid_data: Option<bool>,
+    /**
+     * The request for updating any aspect of a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "responseRanges"
+    )]
+    pub response_ranges: Vec<String>,
+}
+
+/// The reply for batch updating a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchUpdateSpreadsheetResponse {
+    /**
+     * The reply for batch updating a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub replies: Vec<Response>,
+    /**
+     * The reply for batch updating a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "spreadsheetId"
+    )]
+    pub spreadsheet_id: String,
+    /**
+     * The reply for batch updating a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "updatedSpreadsheet"
+    )]
+    pub updated_spreadsheet: Option<Spreadsheet>,
+}
+
+/// The request for updating more than one range of values in a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchUpdateValuesByDataFilterRequest {
+    /**
+     * The request for updating more than one range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data: Vec<DataFilterValueRange>,
+    /**
+     * The request for updating more than one range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeValuesInResponse"
+    )]
+    pub include_values_in_response: Option<bool>,
+    /**
+     * The request for updating more than one range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "responseDateTimeRenderOption"
+    )]
+    pub response_date_time_render_option: Option<ResponseDateTimeRenderOption>,
+    /**
+     * The request for updating more than one range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "responseValueRenderOption"
+    )]
+    pub response_value_render_option: Option<ResponseValueRenderOption>,
+    /**
+     * The request for updating more than one range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "valueInputOption"
+    )]
+    pub value_input_option: Option<ValueInputOption>,
+}
+
+/// The response when updating a range of values in a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchUpdateValuesByDataFilterResponse {
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub responses: Vec<UpdateValuesByDataFilterResponse>,
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "spreadsheetId"
+    )]
+    pub spreadsheet_id: String,
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "totalUpdatedCells"
+    )]
+    pub total_updated_cells: i64,
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "totalUpdatedColumns"
+    )]
+    pub total_updated_columns: i64,
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "totalUpdatedRows"
+    )]
+    pub total_updated_rows: i64,
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "totalUpdatedSheets"
+    )]
+    pub total_updated_sheets: i64,
+}
+
+/// The request for updating more than one range of values in a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchUpdateValuesRequest {
+    /**
+     * The request for updating more than one range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data: Vec<ValueRange>,
+    /**
+     * The request for updating more than one range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeValuesInResponse"
+    )]
+    pub include_values_in_response: Option<bool>,
+    /**
+     * The request for updating more than one range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "responseDateTimeRenderOption"
+    )]
+    pub response_date_time_render_option: Option<ResponseDateTimeRenderOption>,
+    /**
+     * The request for updating more than one range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "responseValueRenderOption"
+    )]
+    pub response_value_render_option: Option<ResponseValueRenderOption>,
+    /**
+     * The request for updating more than one range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "valueInputOption"
+    )]
+    pub value_input_option: Option<ValueInputOption>,
+}
+
+/// The response when updating a range of values in a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchUpdateValuesResponse {
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub responses: Vec<UpdateValuesResponse>,
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "spreadsheetId"
+    )]
+    pub spreadsheet_id: String,
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "totalUpdatedCells"
+    )]
+    pub total_updated_cells: i64,
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "totalUpdatedColumns"
+    )]
+    pub total_updated_columns: i64,
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "totalUpdatedRows"
+    )]
+    pub total_updated_rows: i64,
+    /**
+     * The response when updating a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "totalUpdatedSheets"
+    )]
+    pub total_updated_sheets: i64,
+}
+
+/// The request for clearing a range of values in a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ClearValuesRequest {}
+
+/// The response when clearing a range of values in a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ClearValuesResponse {
+    /**
+     * The response when clearing a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "clearedRange"
+    )]
+    pub cleared_range: String,
+    /**
+     * The response when clearing a range of values in a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "spreadsheetId"
+    )]
+    pub spreadsheet_id: String,
+}
+
+/// The request to copy a sheet across spreadsheets.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CopySheetAnotherSpreadsheetRequest {
+    /**
+     * The request to copy a sheet across spreadsheets.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "destinationSpreadsheetId"
+    )]
+    pub destination_spreadsheet_id: Option<String>,
+}
+
+/// The request for retrieving a Spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetSpreadsheetByDataFilterRequest {
+    /**
+     * The request for retrieving a Spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "dataFilters"
+    )]
+    pub data_filters: Vec<DataFilter>,
+    /**
+     * The request for retrieving a Spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "includeGridData"
+    )]
+    pub include_grid_data: Option<bool>,
+}
+
+/// A request to retrieve all developer metadata matching the set of specified criteria.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SearchDeveloperMetadataRequest {
+    /**
+     * A request to retrieve all developer metadata matching the set of specified criteria.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "dataFilters"
+    )]
+    pub data_filters: Vec<DataFilter>,
+}
+
+/// A reply to a developer metadata search request.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SearchDeveloperMetadataResponse {
+    /**
+     * A reply to a developer metadata search request.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "matchedDeveloperMetadata"
+    )]
+    pub matched_developer_metadata: Vec<MatchedDeveloperMetadata>,
+}
+
+/// Resource that represents a spreadsheet.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Spreadsheet {
+    /**
+     * Resource that represents a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "dataSourceSchedules"
+    )]
+    pub data_source_schedules: Vec<DataSourceRefreshSchedule>,
+    /**
+     * Resource that represents a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "dataSources"
+    )]
+    pub data_sources: Vec<DataSource>,
+    /**
+     * Resource that represents a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "developerMetadata"
+    )]
+    pub developer_metadata: Vec<DeveloperMetadata>,
+    /**
+     * Resource that represents a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "namedRanges"
+    )]
+    pub named_ranges: Vec<NamedRange>,
+    /**
+     * Resource that represents a spreadsheet.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<SpreadsheetProperties>,
+    /**
+     * Resource that represents a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub sheets: Vec<Sheet>,
+    /**
+     * Resource that represents a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "spreadsheetId"
+    )]
+    pub spreadsheet_id: Option<String>,
+    /**
+     * Resource that represents a spreadsheet.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "spreadsheetUrl"
+    )]
+    pub spreadsheet_url: Option<String>,
+}
+
+/**
+ * V1 error format.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Xgafv {
+    #[serde(rename = "1")]
+    One,
+    #[serde(rename = "2")]
+    Two,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Xgafv {
+    fn as_ref(&self) -> &str {
+        match self {
+            Xgafv::One => "1",
+            Xgafv::Two => "2",
+            Xgafv::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Xgafv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Xgafv {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "1" => Xgafv::One,
+            "2" => Xgafv::Two,
+            _ => Xgafv::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Xgafv {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Xgafv> for String {
+    fn from(v: Xgafv) -> Self {
+        match v {
+            Xgafv::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Data format for response.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Alt {
+    #[serde(rename = "json")]
+    Json,
+    #[serde(rename = "media")]
+    Media,
+    #[serde(rename = "proto")]
+    Proto,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Alt {
+    fn as_ref(&self) -> &str {
+        match self {
+            Alt::Json => "json",
+            Alt::Media => "media",
+            Alt::Proto => "proto",
+            Alt::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Alt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Alt {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "json" => Alt::Json,
+            "media" => Alt::Media,
+            "proto" => Alt::Proto,
+            _ => Alt::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Alt {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Alt> for String {
+    fn from(v: Alt) -> Self {
+        match v {
+            Alt::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * How the input data should be inserted.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum InsertDataOption {
+    #[serde(rename = "INSERT_ROWS")]
+    InsertRows,
+    #[serde(rename = "OVERWRITE")]
+    Overwrite,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for InsertDataOption {
+    fn as_ref(&self) -> &str {
+        match self {
+            InsertDataOption::InsertRows => "INSERT_ROWS",
+            InsertDataOption::Overwrite => "OVERWRITE",
+            InsertDataOption::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for InsertDataOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for InsertDataOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "INSERT_ROWS" => InsertDataOption::InsertRows,
+            "OVERWRITE" => InsertDataOption::Overwrite,
+            _ => InsertDataOption::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for InsertDataOption {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<InsertDataOption> for String {
+    fn from(v: InsertDataOption) -> Self {
+        match v {
+            InsertDataOption::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminsBeta {
     pub client: Client,
@@ -53,6 +51,46 @@ impl AdminsBeta {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get all the admins at a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/admins` endpoint.
+     *
+     * As opposed to `get_all_company_admins`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * This endpoint is in beta and intended for **[Gusto Embedded Payroll](https://gusto.com/embedded-payroll)** customers. Please [apply for early access](https://gusto-embedded-payroll.typeform.com/to/iomAQIj3?utm_source=docs) if you’d like to learn more and use it for production. Note, this endpoint will require you to enter a different agreement with Gusto.
+     *
+     * Returns a list of all the admins at a company
+     */
+    pub fn get_company_admins_stream(
+        &self,
+        company_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Admin> {
+        let url = format!(
+            "/v1/companies/{}/admins",
+            crate::progenitor_support::encode_path(company_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create an admin for the company.
      *
@@ -77,3 +115,58 @@ impl AdminsBeta {
             .await
     }
 }
+
+/// The functions of [`AdminsBeta`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminsBetaApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminsBetaApi: Send + Sync {
+    /// See [`AdminsBeta::get_company_admins`].
+    async fn get_company_admins(&self, company_id: &str) -> Result<Vec<crate::types::Admin>>;
+
+    /// See [`AdminsBeta::get_all_company_admins`].
+    async fn get_all_company_admins(&self, company_id: &str) -> Result<Vec<crate::types::Admin>>;
+
+    /// See [`AdminsBeta::get_company_admins_stream`].
+    fn get_company_admins_stream(
+        &self,
+        company_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Admin>;
+
+    /// See [`AdminsBeta::post_company_admin`].
+    async fn post_company_admin(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyAdminsRequest,
+    ) -> Result<crate::types::Admin>;
+}
+
+#[async_trait::async_trait]
+impl AdminsBetaApi for AdminsBeta {
+    async fn get_company_admins(&self, company_id: &str) -> Result<Vec<crate::types::Admin>> {
+        AdminsBeta::get_company_admins(self, company_id).await
+    }
+
+    async fn get_all_company_admins(&self, company_id: &str) -> Result<Vec<crate::types::Admin>> {
+        AdminsBeta::get_all_company_admins(self, company_id).await
+    }
+
+    fn get_company_admins_stream(
+        &self,
+        company_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Admin> {
+        AdminsBeta::get_company_admins_stream(self, company_id, start, max_items)
+    }
+
+    async fn post_company_admin(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyAdminsRequest,
+    ) -> Result<crate::types::Admin> {
+        AdminsBeta::post_company_admin(self, company_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Benefits {
     pub client: Client,
@@ -42,6 +40,41 @@ impl Benefits {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get all benefits supported by Gusto.
+     *
+     * This function performs a `GET` to the `/v1/benefits` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns all benefits supported by Gusto.
+     *
+     * The benefit object in Gusto contains high level information about a particular benefit type and its tax considerations. When companies choose to offer a benefit, they are creating a Company Benefit object associated with a particular benefit.
+     */
+    pub fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SupportedBenefit> {
+        let url = "/v1/benefits".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get a supported benefit by ID.
      *
@@ -101,6 +134,46 @@ impl Benefits {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get benefits for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/company_benefits` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Company benefits represent the benefits that a company is offering to employees. This ties together a particular supported benefit with the company-specific information for the offering of that benefit.
+     *
+     * Note that company benefits can be deactivated only when no employees are enrolled.
+     */
+    pub fn get_company_stream(
+        &self,
+        company_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CompanyBenefit> {
+        let url = format!(
+            "/v1/companies/{}/company_benefits",
+            crate::progenitor_support::encode_path(company_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a company benefit.
      *
@@ -214,6 +287,46 @@ impl Benefits {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get an employee's benefits.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/employee_benefits` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Employee benefits represent an employee enrolled in a particular company benefit. It includes information specific to that employee’s enrollment.
+     *
+     * Returns an array of all employee benefits for this employee
+     */
+    pub fn get_employee_stream(
+        &self,
+        employee_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::EmployeeBenefit> {
+        let url = format!(
+            "/v1/employees/{}/employee_benefits",
+            crate::progenitor_support::encode_path(employee_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create an employee benefit.
      *
@@ -315,3 +428,224 @@ impl Benefits {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`Benefits`], for code that takes them as a trait, so that it can be
+/// tested with the `MockBenefitsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait BenefitsApi: Send + Sync {
+    /// See [`Benefits::get_page`].
+    async fn get_page(&self) -> Result<Vec<crate::types::SupportedBenefit>>;
+
+    /// See [`Benefits::get_all`].
+    async fn get_all(&self) -> Result<Vec<crate::types::SupportedBenefit>>;
+
+    /// See [`Benefits::get_stream`].
+    fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SupportedBenefit>;
+
+    /// See [`Benefits::get`].
+    async fn get(&self, benefit_id: &str) -> Result<crate::types::SupportedBenefit>;
+
+    /// See [`Benefits::get_company`].
+    async fn get_company(&self, company_id: &str) -> Result<Vec<crate::types::CompanyBenefit>>;
+
+    /// See [`Benefits::get_all_company`].
+    async fn get_all_company(&self, company_id: &str) -> Result<Vec<crate::types::CompanyBenefit>>;
+
+    /// See [`Benefits::get_company_stream`].
+    fn get_company_stream(
+        &self,
+        company_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CompanyBenefit>;
+
+    /// See [`Benefits::post_company`].
+    async fn post_company(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyBenefitsRequest,
+    ) -> Result<crate::types::CompanyBenefit>;
+
+    /// See [`Benefits::get_company_benefits`].
+    async fn get_company_benefits(
+        &self,
+        company_benefit_id: &str,
+    ) -> Result<crate::types::CompanyBenefit>;
+
+    /// See [`Benefits::put_company`].
+    async fn put_company(
+        &self,
+        company_benefit_id: &str,
+        body: &crate::types::PutCompanyBenefitRequest,
+    ) -> Result<crate::types::CompanyBenefit>;
+
+    /// See [`Benefits::get_employee`].
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::EmployeeBenefit>>;
+
+    /// See [`Benefits::get_all_employee`].
+    async fn get_all_employee(
+        &self,
+        employee_id: &str,
+    ) -> Result<Vec<crate::types::EmployeeBenefit>>;
+
+    /// See [`Benefits::get_employee_stream`].
+    fn get_employee_stream(
+        &self,
+        employee_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::EmployeeBenefit>;
+
+    /// See [`Benefits::post_employee`].
+    async fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeBenefitsRequest,
+    ) -> Result<crate::types::EmployeeBenefit>;
+
+    /// See [`Benefits::post_employee_ytd_amounts_from_different_company`].
+    async fn post_employee_ytd_amounts_from_different_company(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest,
+    ) -> Result<()>;
+
+    /// See [`Benefits::get_employee_benefits`].
+    async fn get_employee_benefits(
+        &self,
+        employee_benefit_id: &str,
+    ) -> Result<crate::types::EmployeeBenefit>;
+
+    /// See [`Benefits::put_employee`].
+    async fn put_employee(
+        &self,
+        employee_benefit_id: &str,
+        body: &crate::types::PutEmployeeBenefitRequest,
+    ) -> Result<crate::types::EmployeeBenefit>;
+
+    /// See [`Benefits::delete_employee`].
+    async fn delete_employee(&self, employee_benefit_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl BenefitsApi for Benefits {
+    async fn get_page(&self) -> Result<Vec<crate::types::SupportedBenefit>> {
+        Benefits::get_page(self).await
+    }
+
+    async fn get_all(&self) -> Result<Vec<crate::types::SupportedBenefit>> {
+        Benefits::get_all(self).await
+    }
+
+    fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SupportedBenefit> {
+        Benefits::get_stream(self, start, max_items)
+    }
+
+    async fn get(&self, benefit_id: &str) -> Result<crate::types::SupportedBenefit> {
+        Benefits::get(self, benefit_id).await
+    }
+
+    async fn get_company(&self, company_id: &str) -> Result<Vec<crate::types::CompanyBenefit>> {
+        Benefits::get_company(self, company_id).await
+    }
+
+    async fn get_all_company(&self, company_id: &str) -> Result<Vec<crate::types::CompanyBenefit>> {
+        Benefits::get_all_company(self, company_id).await
+    }
+
+    fn get_company_stream(
+        &self,
+        company_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CompanyBenefit> {
+        Benefits::get_company_stream(self, company_id, start, max_items)
+    }
+
+    async fn post_company(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyBenefitsRequest,
+    ) -> Result<crate::types::CompanyBenefit> {
+        Benefits::post_company(self, company_id, body).await
+    }
+
+    async fn get_company_benefits(
+        &self,
+        company_benefit_id: &str,
+    ) -> Result<crate::types::CompanyBenefit> {
+        Benefits::get_company_benefits(self, company_benefit_id).await
+    }
+
+    async fn put_company(
+        &self,
+        company_benefit_id: &str,
+        body: &crate::types::PutCompanyBenefitRequest,
+    ) -> Result<crate::types::CompanyBenefit> {
+        Benefits::put_company(self, company_benefit_id, body).await
+    }
+
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::EmployeeBenefit>> {
+        Benefits::get_employee(self, employee_id).await
+    }
+
+    async fn get_all_employee(
+        &self,
+        employee_id: &str,
+    ) -> Result<Vec<crate::types::EmployeeBenefit>> {
+        Benefits::get_all_employee(self, employee_id).await
+    }
+
+    fn get_employee_stream(
+        &self,
+        employee_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::EmployeeBenefit> {
+        Benefits::get_employee_stream(self, employee_id, start, max_items)
+    }
+
+    async fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeBenefitsRequest,
+    ) -> Result<crate::types::EmployeeBenefit> {
+        Benefits::post_employee(self, employee_id, body).await
+    }
+
+    async fn post_employee_ytd_amounts_from_different_company(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest,
+    ) -> Result<()> {
+        Benefits::post_employee_ytd_amounts_from_different_company(self, employee_id, body).await
+    }
+
+    async fn get_employee_benefits(
+        &self,
+        employee_benefit_id: &str,
+    ) -> Result<crate::types::EmployeeBenefit> {
+        Benefits::get_employee_benefits(self, employee_benefit_id).await
+    }
+
+    async fn put_employee(
+        &self,
+        employee_benefit_id: &str,
+        body: &crate::types::PutEmployeeBenefitRequest,
+    ) -> Result<crate::types::EmployeeBenefit> {
+        Benefits::put_employee(self, employee_benefit_id, body).await
+    }
+
+    async fn delete_employee(&self, employee_benefit_id: &str) -> Result<()> {
+        Benefits::delete_employee(self, employee_benefit_id).await
+    }
+}
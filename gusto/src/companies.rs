@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Companies {
     pub client: Client,
@@ -104,3 +102,45 @@ impl Companies {
             .await
     }
 }
+
+/// The functions of [`Companies`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCompaniesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CompaniesApi: Send + Sync {
+    /// See [`Companies::get`].
+    async fn get(&self, company_id_or_uuid: &str) -> Result<crate::types::Company>;
+
+    /// See [`Companies::post_partner_managed`].
+    async fn post_partner_managed(
+        &self,
+        body: &crate::types::PostPartnerManagedCompaniesRequest,
+    ) -> Result<crate::types::PostPartnerManagedCompaniesResponse>;
+
+    /// See [`Companies::post_provision`].
+    async fn post_provision(
+        &self,
+        body: &crate::types::PostProvisionRequest,
+    ) -> Result<crate::types::PostProvisionResponse>;
+}
+
+#[async_trait::async_trait]
+impl CompaniesApi for Companies {
+    async fn get(&self, company_id_or_uuid: &str) -> Result<crate::types::Company> {
+        Companies::get(self, company_id_or_uuid).await
+    }
+
+    async fn post_partner_managed(
+        &self,
+        body: &crate::types::PostPartnerManagedCompaniesRequest,
+    ) -> Result<crate::types::PostPartnerManagedCompaniesResponse> {
+        Companies::post_partner_managed(self, body).await
+    }
+
+    async fn post_provision(
+        &self,
+        body: &crate::types::PostProvisionRequest,
+    ) -> Result<crate::types::PostProvisionResponse> {
+        Companies::post_provision(self, body).await
+    }
+}
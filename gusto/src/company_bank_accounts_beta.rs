@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CompanyBankAccountsBeta {
     pub client: Client,
@@ -56,6 +54,46 @@ impl CompanyBankAccountsBeta {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get all company bank accounts.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/bank_accounts` endpoint.
+     *
+     * As opposed to `get_all_company_bank_accounts`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * This endpoint is in beta and intended for **[Gusto Embedded Payroll](https://gusto.com/embedded-payroll)** customers. Please [apply for early access](https://gusto-embedded-payroll.typeform.com/to/iomAQIj3?utm_source=docs) if you’d like to learn more and use it for production. Note, this endpoint will require you to enter a different agreement with Gusto.
+     *
+     * Returns all company bank accounts
+     */
+    pub fn get_company_bank_accounts_stream(
+        &self,
+        company_id_or_uuid: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CompanyBankAccount> {
+        let url = format!(
+            "/v1/companies/{}/bank_accounts",
+            crate::progenitor_support::encode_path(company_id_or_uuid),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a company bank account.
      *
@@ -106,3 +144,98 @@ impl CompanyBankAccountsBeta {
             .await
     }
 }
+
+/// The functions of [`CompanyBankAccountsBeta`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCompanyBankAccountsBetaApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CompanyBankAccountsBetaApi: Send + Sync {
+    /// See [`CompanyBankAccountsBeta::get_company_bank_accounts`].
+    async fn get_company_bank_accounts(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::CompanyBankAccount>>;
+
+    /// See [`CompanyBankAccountsBeta::get_all_company_bank_accounts`].
+    async fn get_all_company_bank_accounts(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::CompanyBankAccount>>;
+
+    /// See [`CompanyBankAccountsBeta::get_company_bank_accounts_stream`].
+    fn get_company_bank_accounts_stream(
+        &self,
+        company_id_or_uuid: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CompanyBankAccount>;
+
+    /// See [`CompanyBankAccountsBeta::post_company_bank_account`].
+    async fn post_company_bank_account(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyBankAccountsRequest,
+    ) -> Result<crate::types::CompanyBankAccount>;
+
+    /// See [`CompanyBankAccountsBeta::put_company_bank_accounts_verify`].
+    async fn put_company_bank_accounts_verify(
+        &self,
+        company_id_or_uuid: &str,
+        bank_account_uuid: &str,
+        body: &crate::types::PutCompanyBankAccountsVerifyRequest,
+    ) -> Result<crate::types::CompanyBankAccount>;
+}
+
+#[async_trait::async_trait]
+impl CompanyBankAccountsBetaApi for CompanyBankAccountsBeta {
+    async fn get_company_bank_accounts(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::CompanyBankAccount>> {
+        CompanyBankAccountsBeta::get_company_bank_accounts(self, company_id_or_uuid).await
+    }
+
+    async fn get_all_company_bank_accounts(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::CompanyBankAccount>> {
+        CompanyBankAccountsBeta::get_all_company_bank_accounts(self, company_id_or_uuid).await
+    }
+
+    fn get_company_bank_accounts_stream(
+        &self,
+        company_id_or_uuid: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CompanyBankAccount> {
+        CompanyBankAccountsBeta::get_company_bank_accounts_stream(
+            self,
+            company_id_or_uuid,
+            start,
+            max_items,
+        )
+    }
+
+    async fn post_company_bank_account(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyBankAccountsRequest,
+    ) -> Result<crate::types::CompanyBankAccount> {
+        CompanyBankAccountsBeta::post_company_bank_account(self, company_id_or_uuid, body).await
+    }
+
+    async fn put_company_bank_accounts_verify(
+        &self,
+        company_id_or_uuid: &str,
+        bank_account_uuid: &str,
+        body: &crate::types::PutCompanyBankAccountsVerifyRequest,
+    ) -> Result<crate::types::CompanyBankAccount> {
+        CompanyBankAccountsBeta::put_company_bank_accounts_verify(
+            self,
+            company_id_or_uuid,
+            bank_account_uuid,
+            body,
+        )
+        .await
+    }
+}
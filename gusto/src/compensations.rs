@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Compensations {
     pub client: Client,
@@ -96,4 +94,108 @@ impl Compensations {
 
         self.client.get_all_pages(&url, None).await
     }
+
+    /**
+     * Get compensations for a job.
+     *
+     * This function performs a `GET` to the `/v1/jobs/{job_id}/compensations` endpoint.
+     *
+     * As opposed to `get_all_job`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Compensations contain information on how much is paid out for a job. Jobs may have many compensations, but only one that is active. The current compensation is the one with the most recent `effective_date`.
+     *
+     * Note: Currently, jobs are arbitrarily limited to a single compensation as multiple compensations per job are not yet available in Gusto. The API is architected as if multiple compensations may exist, so integrations should integrate under the same assumption. The only exception is that creating a compensation with the same `job_id` as another will fail with a relevant error.
+     *
+     * Use the `flsa_status` to determine if an employee is elibgle for overtime.
+     */
+    pub fn get_job_stream(
+        &self,
+        job_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Compensation> {
+        let url = format!(
+            "/v1/jobs/{}/compensations",
+            crate::progenitor_support::encode_path(job_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The functions of [`Compensations`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCompensationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CompensationsApi: Send + Sync {
+    /// See [`Compensations::get`].
+    async fn get(&self, compensation_id: &str) -> Result<crate::types::Compensation>;
+
+    /// See [`Compensations::put`].
+    async fn put(
+        &self,
+        compensation_id: &str,
+        body: &crate::types::PutCompensationRequest,
+    ) -> Result<crate::types::Compensation>;
+
+    /// See [`Compensations::get_job`].
+    async fn get_job(&self, job_id: &str) -> Result<Vec<crate::types::Compensation>>;
+
+    /// See [`Compensations::get_all_job`].
+    async fn get_all_job(&self, job_id: &str) -> Result<Vec<crate::types::Compensation>>;
+
+    /// See [`Compensations::get_job_stream`].
+    fn get_job_stream(
+        &self,
+        job_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Compensation>;
+}
+
+#[async_trait::async_trait]
+impl CompensationsApi for Compensations {
+    async fn get(&self, compensation_id: &str) -> Result<crate::types::Compensation> {
+        Compensations::get(self, compensation_id).await
+    }
+
+    async fn put(
+        &self,
+        compensation_id: &str,
+        body: &crate::types::PutCompensationRequest,
+    ) -> Result<crate::types::Compensation> {
+        Compensations::put(self, compensation_id, body).await
+    }
+
+    async fn get_job(&self, job_id: &str) -> Result<Vec<crate::types::Compensation>> {
+        Compensations::get_job(self, job_id).await
+    }
+
+    async fn get_all_job(&self, job_id: &str) -> Result<Vec<crate::types::Compensation>> {
+        Compensations::get_all_job(self, job_id).await
+    }
+
+    fn get_job_stream(
+        &self,
+        job_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Compensation> {
+        Compensations::get_job_stream(self, job_id, start, max_items)
+    }
 }
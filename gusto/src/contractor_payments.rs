@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ContractorPayments {
     pub client: Client,
@@ -21,20 +19,18 @@ impl ContractorPayments {
      *
      * **Parameters:**
      *
-     * * `start_date: &str` -- The time period for which to retrieve contractor payments.
-     * * `end_date: &str` -- The time period for which to retrieve contractor payments.
+     * * `params: &ContractorPaymentsGetCompanyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_company(
+    pub async fn get_company_with(
         &self,
         company_id: &str,
-        start_date: &str,
-        end_date: &str,
+        params: &ContractorPaymentsGetCompanyParams,
     ) -> Result<crate::types::ContractorPaymentSummary> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -47,6 +43,23 @@ impl ContractorPayments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_company_with` and pass the query parameters as a `ContractorPaymentsGetCompanyParams` instead.
+     */
+    #[deprecated(note = "use `get_company_with` instead")]
+    pub async fn get_company(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<crate::types::ContractorPaymentSummary> {
+        let params = ContractorPaymentsGetCompanyParams {
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_company_with(company_id, &params).await
+    }
+
     /**
      * Create a contractor payment (Beta).
      *
@@ -58,40 +71,30 @@ impl ContractorPayments {
      *
      * **Parameters:**
      *
-     * * `date: &str` -- A unique identifier of the employee in Gusto.
-     * * `contractor_id: f64` -- The contractor receiving the payment.
-     * * `wage: f64` -- If the contractor is on a fixed wage, this is the fixed wage payment for the contractor, regardless of hours worked.
-     * * `hours: f64` -- If the contractor is on an hourly wage, this is the number of hours that the contractor worked for the payment.
-     * * `bonus: f64` -- If the contractor is on an hourly wage, this is the bonus the contractor earned.
-     * * `reimbursement: f64` -- Reimbursed wages for the contractor .
+     * * `params: &ContractorPaymentsPostCompanyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn post_company(
+    pub async fn post_company_with(
         &self,
         company_id: &str,
-        date: &str,
-        contractor_id: f64,
-        wage: f64,
-        hours: f64,
-        bonus: f64,
-        reimbursement: f64,
+        params: &ContractorPaymentsPostCompanyParams,
     ) -> Result<crate::types::ContractorPayment> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !bonus.to_string().is_empty() {
+        if let Some(bonus) = &params.bonus {
             query_args.push(("bonus".to_string(), bonus.to_string()));
         }
-        if !contractor_id.to_string().is_empty() {
+        if let Some(contractor_id) = &params.contractor_id {
             query_args.push(("contractor_id".to_string(), contractor_id.to_string()));
         }
-        if !date.is_empty() {
+        if let Some(date) = &params.date {
             query_args.push(("date".to_string(), date.to_string()));
         }
-        if !hours.to_string().is_empty() {
+        if let Some(hours) = &params.hours {
             query_args.push(("hours".to_string(), hours.to_string()));
         }
-        if !reimbursement.to_string().is_empty() {
+        if let Some(reimbursement) = &params.reimbursement {
             query_args.push(("reimbursement".to_string(), reimbursement.to_string()));
         }
-        if !wage.to_string().is_empty() {
+        if let Some(wage) = &params.wage {
             query_args.push(("wage".to_string(), wage.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -104,6 +107,31 @@ impl ContractorPayments {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `post_company_with` and pass the query parameters as a `ContractorPaymentsPostCompanyParams` instead.
+     */
+    #[deprecated(note = "use `post_company_with` instead")]
+    pub async fn post_company(
+        &self,
+        company_id: &str,
+        date: &str,
+        contractor_id: f64,
+        wage: f64,
+        hours: f64,
+        bonus: f64,
+        reimbursement: f64,
+    ) -> Result<crate::types::ContractorPayment> {
+        let params = ContractorPaymentsPostCompanyParams {
+            bonus: Some(bonus).filter(|v| !v.to_string().is_empty()),
+            contractor_id: Some(contractor_id).filter(|v| !v.to_string().is_empty()),
+            date: Some(date.to_string()).filter(|s| !s.is_empty()),
+            hours: Some(hours).filter(|v| !v.to_string().is_empty()),
+            reimbursement: Some(reimbursement).filter(|v| !v.to_string().is_empty()),
+            wage: Some(wage).filter(|v| !v.to_string().is_empty()),
+        };
+        self.post_company_with(company_id, &params).await
+    }
+
     /**
      * Get a single contractor payment.
      *
@@ -148,3 +176,147 @@ impl ContractorPayments {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `ContractorPayments::get_company_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ContractorPaymentsGetCompanyParams {
+    /// The time period for which to retrieve contractor payments.
+    pub end_date: Option<String>,
+    /// The time period for which to retrieve contractor payments.
+    pub start_date: Option<String>,
+}
+
+impl ContractorPaymentsGetCompanyParams {
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ContractorPayments::post_company_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ContractorPaymentsPostCompanyParams {
+    /// If the contractor is on an hourly wage, this is the bonus the contractor earned.
+    pub bonus: Option<f64>,
+    /// The contractor receiving the payment.
+    pub contractor_id: Option<f64>,
+    /// A unique identifier of the employee in Gusto.
+    pub date: Option<String>,
+    /// If the contractor is on an hourly wage, this is the number of hours that the contractor worked for the payment.
+    pub hours: Option<f64>,
+    /// Reimbursed wages for the contractor .
+    pub reimbursement: Option<f64>,
+    /// If the contractor is on a fixed wage, this is the fixed wage payment for the contractor, regardless of hours worked.
+    pub wage: Option<f64>,
+}
+
+impl ContractorPaymentsPostCompanyParams {
+    pub fn bonus(mut self, bonus: f64) -> Self {
+        self.bonus = Some(bonus);
+        self
+    }
+
+    pub fn contractor_id(mut self, contractor_id: f64) -> Self {
+        self.contractor_id = Some(contractor_id);
+        self
+    }
+
+    pub fn date<T: ToString>(mut self, date: T) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+
+    pub fn hours(mut self, hours: f64) -> Self {
+        self.hours = Some(hours);
+        self
+    }
+
+    pub fn reimbursement(mut self, reimbursement: f64) -> Self {
+        self.reimbursement = Some(reimbursement);
+        self
+    }
+
+    pub fn wage(mut self, wage: f64) -> Self {
+        self.wage = Some(wage);
+        self
+    }
+}
+
+/// The functions of [`ContractorPayments`], for code that takes them as a trait, so that it can be
+/// tested with the `MockContractorPaymentsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ContractorPaymentsApi: Send + Sync {
+    /// See [`ContractorPayments::get_company_with`].
+    async fn get_company_with(
+        &self,
+        company_id: &str,
+        params: &ContractorPaymentsGetCompanyParams,
+    ) -> Result<crate::types::ContractorPaymentSummary>;
+
+    /// See [`ContractorPayments::post_company_with`].
+    async fn post_company_with(
+        &self,
+        company_id: &str,
+        params: &ContractorPaymentsPostCompanyParams,
+    ) -> Result<crate::types::ContractorPayment>;
+
+    /// See [`ContractorPayments::get_company_contractor_payments`].
+    async fn get_company_contractor_payments(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> Result<crate::types::ContractorPayment>;
+
+    /// See [`ContractorPayments::delete_company`].
+    async fn delete_company(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ContractorPaymentsApi for ContractorPayments {
+    async fn get_company_with(
+        &self,
+        company_id: &str,
+        params: &ContractorPaymentsGetCompanyParams,
+    ) -> Result<crate::types::ContractorPaymentSummary> {
+        ContractorPayments::get_company_with(self, company_id, params).await
+    }
+
+    async fn post_company_with(
+        &self,
+        company_id: &str,
+        params: &ContractorPaymentsPostCompanyParams,
+    ) -> Result<crate::types::ContractorPayment> {
+        ContractorPayments::post_company_with(self, company_id, params).await
+    }
+
+    async fn get_company_contractor_payments(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> Result<crate::types::ContractorPayment> {
+        ContractorPayments::get_company_contractor_payments(
+            self,
+            company_id,
+            contractor_payment_id_or_uuid,
+        )
+        .await
+    }
+
+    async fn delete_company(
+        &self,
+        company_id: &str,
+        contractor_payment_id_or_uuid: &str,
+    ) -> Result<()> {
+        ContractorPayments::delete_company(self, company_id, contractor_payment_id_or_uuid).await
+    }
+}
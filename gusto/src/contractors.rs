@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Contractors {
     pub client: Client,
@@ -90,6 +88,44 @@ impl Contractors {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get contractors of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/contractors` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Get all contractors, active and inactive, individual and business, for a company.
+     */
+    pub fn get_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Contractor> {
+        let url = format!(
+            "/v1/companies/{}/contractors",
+            crate::progenitor_support::encode_path(company_id_or_uuid),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a contractor.
      *
@@ -112,3 +148,86 @@ impl Contractors {
             .await
     }
 }
+
+/// The functions of [`Contractors`], for code that takes them as a trait, so that it can be
+/// tested with the `MockContractorsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ContractorsApi: Send + Sync {
+    /// See [`Contractors::get`].
+    async fn get(&self, contractor_id_or_uuid: &str) -> Result<crate::types::Contractor>;
+
+    /// See [`Contractors::put`].
+    async fn put(
+        &self,
+        contractor_id_or_uuid: &str,
+        body: &crate::types::PutComntractorRequest,
+    ) -> Result<crate::types::Contractor>;
+
+    /// See [`Contractors::get_company`].
+    async fn get_company(&self, company_id_or_uuid: &str) -> Result<Vec<crate::types::Contractor>>;
+
+    /// See [`Contractors::get_all_company`].
+    async fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::Contractor>>;
+
+    /// See [`Contractors::get_company_stream`].
+    fn get_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Contractor>;
+
+    /// See [`Contractors::post_company`].
+    async fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyContractorsRequest,
+    ) -> Result<crate::types::Contractor>;
+}
+
+#[async_trait::async_trait]
+impl ContractorsApi for Contractors {
+    async fn get(&self, contractor_id_or_uuid: &str) -> Result<crate::types::Contractor> {
+        Contractors::get(self, contractor_id_or_uuid).await
+    }
+
+    async fn put(
+        &self,
+        contractor_id_or_uuid: &str,
+        body: &crate::types::PutComntractorRequest,
+    ) -> Result<crate::types::Contractor> {
+        Contractors::put(self, contractor_id_or_uuid, body).await
+    }
+
+    async fn get_company(&self, company_id_or_uuid: &str) -> Result<Vec<crate::types::Contractor>> {
+        Contractors::get_company(self, company_id_or_uuid).await
+    }
+
+    async fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::Contractor>> {
+        Contractors::get_all_company(self, company_id_or_uuid).await
+    }
+
+    fn get_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Contractor> {
+        Contractors::get_company_stream(self, company_id_or_uuid, start, max_items)
+    }
+
+    async fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyContractorsRequest,
+    ) -> Result<crate::types::Contractor> {
+        Contractors::post_company(self, company_id_or_uuid, body).await
+    }
+}
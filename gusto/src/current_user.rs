@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CurrentUser {
     pub client: Client,
@@ -24,3 +22,19 @@ impl CurrentUser {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`CurrentUser`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCurrentUserApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CurrentUserApi: Send + Sync {
+    /// See [`CurrentUser::get_me`].
+    async fn get_me(&self) -> Result<crate::types::CurrentUser>;
+}
+
+#[async_trait::async_trait]
+impl CurrentUserApi for CurrentUser {
+    async fn get_me(&self) -> Result<crate::types::CurrentUser> {
+        CurrentUser::get_me(self).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CustomFields {
     pub client: Client,
@@ -50,3 +48,38 @@ impl CustomFields {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`CustomFields`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCustomFieldsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CustomFieldsApi: Send + Sync {
+    /// See [`CustomFields::get_employee`].
+    async fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> Result<crate::types::GetEmployeeCustomFieldsResponse>;
+
+    /// See [`CustomFields::get_company`].
+    async fn get_company(
+        &self,
+        company_id: &str,
+    ) -> Result<crate::types::GetCompanyCustomFieldsResponse>;
+}
+
+#[async_trait::async_trait]
+impl CustomFieldsApi for CustomFields {
+    async fn get_employee(
+        &self,
+        employee_id: &str,
+    ) -> Result<crate::types::GetEmployeeCustomFieldsResponse> {
+        CustomFields::get_employee(self, employee_id).await
+    }
+
+    async fn get_company(
+        &self,
+        company_id: &str,
+    ) -> Result<crate::types::GetCompanyCustomFieldsResponse> {
+        CustomFields::get_company(self, company_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EarningType {
     pub client: Client,
@@ -106,3 +104,58 @@ impl EarningType {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`EarningType`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEarningTypeApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EarningTypeApi: Send + Sync {
+    /// See [`EarningType::get_company`].
+    async fn get_company(&self, company_id: &str) -> Result<crate::types::EarningTypeListResponse>;
+
+    /// See [`EarningType::post_company`].
+    async fn post_company(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyEarningTypesRequest,
+    ) -> Result<crate::types::EarningType>;
+
+    /// See [`EarningType::put_company_type`].
+    async fn put_company_type(
+        &self,
+        company_id: &str,
+        earning_type_uuid: &str,
+        body: &crate::types::PutCompanyEarningTypeRequest,
+    ) -> Result<crate::types::EarningType>;
+
+    /// See [`EarningType::delete_company_type`].
+    async fn delete_company_type(&self, company_id: &str, earning_type_uuid: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl EarningTypeApi for EarningType {
+    async fn get_company(&self, company_id: &str) -> Result<crate::types::EarningTypeListResponse> {
+        EarningType::get_company(self, company_id).await
+    }
+
+    async fn post_company(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyEarningTypesRequest,
+    ) -> Result<crate::types::EarningType> {
+        EarningType::post_company(self, company_id, body).await
+    }
+
+    async fn put_company_type(
+        &self,
+        company_id: &str,
+        earning_type_uuid: &str,
+        body: &crate::types::PutCompanyEarningTypeRequest,
+    ) -> Result<crate::types::EarningType> {
+        EarningType::put_company_type(self, company_id, earning_type_uuid, body).await
+    }
+
+    async fn delete_company_type(&self, company_id: &str, earning_type_uuid: &str) -> Result<()> {
+        EarningType::delete_company_type(self, company_id, earning_type_uuid).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Employees {
     pub client: Client,
@@ -21,15 +19,15 @@ impl Employees {
      *
      * **Parameters:**
      *
-     * * `include: &[String]` -- Include the requested attribute(s) in each employee response.
+     * * `params: &EmployeesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         employee_id_or_uuid: &str,
-        include: &[String],
+        params: &EmployeesGetParams,
     ) -> Result<crate::types::Employee> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -42,6 +40,21 @@ impl Employees {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `EmployeesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        employee_id_or_uuid: &str,
+        include: &[String],
+    ) -> Result<crate::types::Employee> {
+        let params = EmployeesGetParams {
+            include: Some(include.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_with(employee_id_or_uuid, &params).await
+    }
+
     /**
      * Update an employee.
      *
@@ -73,30 +86,24 @@ impl Employees {
      *
      * **Parameters:**
      *
-     * * `terminated: bool` -- Filters employees by the provided boolean.
-     * * `page: f64` -- The page that is requested. When unspecified, will load all employees.
-     * * `per: f64` -- Number of employees per page. When unspecified, will default to 25.
-     * * `include: &[String]` -- Include the requested attribute(s) in each employee response.
+     * * `params: &EmployeesGetCompanyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_company(
+    pub async fn get_company_with(
         &self,
         company_id_or_uuid: &str,
-        terminated: bool,
-        page: f64,
-        per: f64,
-        include: &[String],
+        params: &EmployeesGetCompanyParams,
     ) -> Result<Vec<crate::types::Employee>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.join(" ")));
         }
-        if !page.to_string().is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if !per.to_string().is_empty() {
+        if let Some(per) = &params.per {
             query_args.push(("per".to_string(), per.to_string()));
         }
-        if terminated {
+        if let Some(terminated) = &params.terminated {
             query_args.push(("terminated".to_string(), terminated.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -109,6 +116,27 @@ impl Employees {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_company_with` and pass the query parameters as a `EmployeesGetCompanyParams` instead.
+     */
+    #[deprecated(note = "use `get_company_with` instead")]
+    pub async fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+        terminated: bool,
+        page: f64,
+        per: f64,
+        include: &[String],
+    ) -> Result<Vec<crate::types::Employee>> {
+        let params = EmployeesGetCompanyParams {
+            include: Some(include.to_vec()).filter(|v| !v.is_empty()),
+            page: Some(page).filter(|v| !v.to_string().is_empty()),
+            per: Some(per).filter(|v| !v.to_string().is_empty()),
+            terminated: Some(terminated).filter(|v| *v),
+        };
+        self.get_company_with(company_id_or_uuid, &params).await
+    }
+
     /**
      * Get employees of a company.
      *
@@ -117,18 +145,72 @@ impl Employees {
      * As opposed to `get_company`, this function returns all the pages of the request at once.
      *
      * Get all of the employees, onboarding, active and terminated, for a given company.
+     *
+     * **Parameters:**
+     *
+     * * `params: &EmployeesGetCompanyParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_company_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &EmployeesGetCompanyParams,
+    ) -> Result<Vec<crate::types::Employee>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(include) = &params.include {
+            query_args.push(("include".to_string(), include.join(" ")));
+        }
+        if let Some(terminated) = &params.terminated {
+            query_args.push(("terminated".to_string(), terminated.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/companies/{}/employees?{}",
+            crate::progenitor_support::encode_path(company_id_or_uuid),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_company_with` and pass the query parameters as a `EmployeesGetCompanyParams` instead.
      */
+    #[deprecated(note = "use `get_all_company_with` instead")]
     pub async fn get_all_company(
         &self,
         company_id_or_uuid: &str,
         terminated: bool,
         include: &[String],
     ) -> Result<Vec<crate::types::Employee>> {
+        let params = EmployeesGetCompanyParams {
+            include: Some(include.to_vec()).filter(|v| !v.is_empty()),
+            terminated: Some(terminated).filter(|v| *v),
+            ..Default::default()
+        };
+        self.get_all_company_with(company_id_or_uuid, &params).await
+    }
+
+    /**
+     * Get employees of a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/employees` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Get all of the employees, onboarding, active and terminated, for a given company.
+     */
+    pub fn get_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        params: &EmployeesGetCompanyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Employee> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.join(" ")));
         }
-        if terminated {
+        if let Some(terminated) = &params.terminated {
             query_args.push(("terminated".to_string(), terminated.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -138,7 +220,22 @@ impl Employees {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -201,3 +298,177 @@ impl Employees {
             .await
     }
 }
+
+/// The query parameters of `Employees::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EmployeesGetParams {
+    /// Include the requested attribute(s) in each employee response.
+    pub include: Option<Vec<String>>,
+}
+
+impl EmployeesGetParams {
+    pub fn include(mut self, include: Vec<String>) -> Self {
+        self.include = Some(include);
+        self
+    }
+}
+
+/// The query parameters of `Employees::get_company_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EmployeesGetCompanyParams {
+    /// Include the requested attribute(s) in each employee response.
+    pub include: Option<Vec<String>>,
+    /// The page that is requested. When unspecified, will load all employees.
+    pub page: Option<f64>,
+    /// Number of employees per page. When unspecified, will default to 25.
+    pub per: Option<f64>,
+    /// Filters employees by the provided boolean.
+    pub terminated: Option<bool>,
+}
+
+impl EmployeesGetCompanyParams {
+    pub fn include(mut self, include: Vec<String>) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    pub fn page(mut self, page: f64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn per(mut self, per: f64) -> Self {
+        self.per = Some(per);
+        self
+    }
+
+    pub fn terminated(mut self, terminated: bool) -> Self {
+        self.terminated = Some(terminated);
+        self
+    }
+}
+
+/// The functions of [`Employees`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEmployeesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EmployeesApi: Send + Sync {
+    /// See [`Employees::get_with`].
+    async fn get_with(
+        &self,
+        employee_id_or_uuid: &str,
+        params: &EmployeesGetParams,
+    ) -> Result<crate::types::Employee>;
+
+    /// See [`Employees::put`].
+    async fn put(
+        &self,
+        employee_id_or_uuid: &str,
+        body: &crate::types::PutEmployeesRequest,
+    ) -> Result<crate::types::Employee>;
+
+    /// See [`Employees::get_company_with`].
+    async fn get_company_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &EmployeesGetCompanyParams,
+    ) -> Result<Vec<crate::types::Employee>>;
+
+    /// See [`Employees::get_all_company_with`].
+    async fn get_all_company_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &EmployeesGetCompanyParams,
+    ) -> Result<Vec<crate::types::Employee>>;
+
+    /// See [`Employees::get_company_stream`].
+    fn get_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        params: &EmployeesGetCompanyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Employee>;
+
+    /// See [`Employees::post`].
+    async fn post(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostEmployeesRequest,
+    ) -> Result<crate::types::Employee>;
+
+    /// See [`Employees::get_home_address`].
+    async fn get_home_address(&self, employee_id: &str) -> Result<crate::types::Location>;
+
+    /// See [`Employees::put_home_address`].
+    async fn put_home_address(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PutEmployeeHomeAddressRequest,
+    ) -> Result<crate::types::Location>;
+}
+
+#[async_trait::async_trait]
+impl EmployeesApi for Employees {
+    async fn get_with(
+        &self,
+        employee_id_or_uuid: &str,
+        params: &EmployeesGetParams,
+    ) -> Result<crate::types::Employee> {
+        Employees::get_with(self, employee_id_or_uuid, params).await
+    }
+
+    async fn put(
+        &self,
+        employee_id_or_uuid: &str,
+        body: &crate::types::PutEmployeesRequest,
+    ) -> Result<crate::types::Employee> {
+        Employees::put(self, employee_id_or_uuid, body).await
+    }
+
+    async fn get_company_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &EmployeesGetCompanyParams,
+    ) -> Result<Vec<crate::types::Employee>> {
+        Employees::get_company_with(self, company_id_or_uuid, params).await
+    }
+
+    async fn get_all_company_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &EmployeesGetCompanyParams,
+    ) -> Result<Vec<crate::types::Employee>> {
+        Employees::get_all_company_with(self, company_id_or_uuid, params).await
+    }
+
+    fn get_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        params: &EmployeesGetCompanyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Employee> {
+        Employees::get_company_stream(self, company_id_or_uuid, params, start, max_items)
+    }
+
+    async fn post(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostEmployeesRequest,
+    ) -> Result<crate::types::Employee> {
+        Employees::post(self, company_id_or_uuid, body).await
+    }
+
+    async fn get_home_address(&self, employee_id: &str) -> Result<crate::types::Location> {
+        Employees::get_home_address(self, employee_id).await
+    }
+
+    async fn put_home_address(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PutEmployeeHomeAddressRequest,
+    ) -> Result<crate::types::Location> {
+        Employees::put_home_address(self, employee_id, body).await
+    }
+}
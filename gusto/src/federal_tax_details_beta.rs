@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct FederalTaxDetailsBeta {
     pub client: Client,
@@ -30,7 +28,7 @@ company_id_or_uuid: &str,
 ) -> Result<crate::types::GetCompanyFederalTaxDetailsResponse> {
 let url =
 format!("/v1/companies/{}/federal_tax_details",
-crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),);
+crate::progenitor_support::encode_path(company_id_or_uuid),);
 
 self.client.get(&url, None).await
 }
@@ -48,13 +46,38 @@ pub async fn put_company_or_federal_tax_details(
 &self,
 company_id_or_uuid: &str,
 body: &crate::types::PutCompanyFederalTaxDetailsRequest
-) -> Result<crate::types::GetCompanyFederalTaxDetailsResponse> {
+) -> Result<crate::types::PutCompanyFederalTaxDetailsResponse> {
 let url =
 format!("/v1/companies/{}/federal_tax_details",
-crate::progenitor_support::encode_path(&company_id_or_uuid.to_string()),);
+crate::progenitor_support::encode_path(company_id_or_uuid),);
 
 self.client.put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?))).await
 }
 
 
-}
\ No newline at end of file
+}
+
+/// The functions of [`FederalTaxDetailsBeta`], for code that takes them as a trait, so that it can be
+/// tested with the `MockFederalTaxDetailsBetaApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait FederalTaxDetailsBetaApi: Send + Sync {
+/// See [`FederalTaxDetailsBeta::get_company_or_federal_tax_details`].
+async fn get_company_or_federal_tax_details(&self, company_id_or_uuid: &str,) -> Result<crate::types::GetCompanyFederalTaxDetailsResponse>;
+
+/// See [`FederalTaxDetailsBeta::put_company_or_federal_tax_details`].
+async fn put_company_or_federal_tax_details(&self, company_id_or_uuid: &str, body: &crate::types::PutCompanyFederalTaxDetailsRequest,) -> Result<crate::types::PutCompanyFederalTaxDetailsResponse>;
+
+}
+
+#[async_trait::async_trait]
+impl FederalTaxDetailsBetaApi for FederalTaxDetailsBeta {
+async fn get_company_or_federal_tax_details(&self, company_id_or_uuid: &str,) -> Result<crate::types::GetCompanyFederalTaxDetailsResponse> {
+FederalTaxDetailsBeta::get_company_or_federal_tax_details(self, company_id_or_uuid).await
+}
+
+async fn put_company_or_federal_tax_details(&self, company_id_or_uuid: &str, body: &crate::types::PutCompanyFederalTaxDetailsRequest,) -> Result<crate::types::PutCompanyFederalTaxDetailsResponse> {
+FederalTaxDetailsBeta::put_company_or_federal_tax_details(self, company_id_or_uuid, body).await
+}
+
+}
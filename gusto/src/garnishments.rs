@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Garnishments {
     pub client: Client,
@@ -49,6 +47,44 @@ impl Garnishments {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get garnishments for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/garnishments` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
+     */
+    pub fn get_employee_stream(
+        &self,
+        employee_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Garnishment> {
+        let url = format!(
+            "/v1/employees/{}/garnishments",
+            crate::progenitor_support::encode_path(employee_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a garnishment.
      *
@@ -109,3 +145,80 @@ impl Garnishments {
             .await
     }
 }
+
+/// The functions of [`Garnishments`], for code that takes them as a trait, so that it can be
+/// tested with the `MockGarnishmentsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait GarnishmentsApi: Send + Sync {
+    /// See [`Garnishments::get_employee`].
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Garnishment>>;
+
+    /// See [`Garnishments::get_all_employee`].
+    async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Garnishment>>;
+
+    /// See [`Garnishments::get_employee_stream`].
+    fn get_employee_stream(
+        &self,
+        employee_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Garnishment>;
+
+    /// See [`Garnishments::post_employee`].
+    async fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeGarnishmentsRequest,
+    ) -> Result<crate::types::Garnishment>;
+
+    /// See [`Garnishments::get`].
+    async fn get(&self, garnishment_id: &str) -> Result<crate::types::Garnishment>;
+
+    /// See [`Garnishments::put`].
+    async fn put(
+        &self,
+        garnishment_id: &str,
+        body: &crate::types::PutGarnishmentRequest,
+    ) -> Result<crate::types::Garnishment>;
+}
+
+#[async_trait::async_trait]
+impl GarnishmentsApi for Garnishments {
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Garnishment>> {
+        Garnishments::get_employee(self, employee_id).await
+    }
+
+    async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Garnishment>> {
+        Garnishments::get_all_employee(self, employee_id).await
+    }
+
+    fn get_employee_stream(
+        &self,
+        employee_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Garnishment> {
+        Garnishments::get_employee_stream(self, employee_id, start, max_items)
+    }
+
+    async fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeGarnishmentsRequest,
+    ) -> Result<crate::types::Garnishment> {
+        Garnishments::post_employee(self, employee_id, body).await
+    }
+
+    async fn get(&self, garnishment_id: &str) -> Result<crate::types::Garnishment> {
+        Garnishments::get(self, garnishment_id).await
+    }
+
+    async fn put(
+        &self,
+        garnishment_id: &str,
+        body: &crate::types::PutGarnishmentRequest,
+    ) -> Result<crate::types::Garnishment> {
+        Garnishments::put(self, garnishment_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct JobApplicantsBeta {
     pub client: Client,
@@ -56,6 +54,46 @@ impl JobApplicantsBeta {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get all job applicants for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/job_applicants` endpoint.
+     *
+     * As opposed to `get_all_company_job_applicants`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * *This endpoint is in beta - we will be making breaking changes based on developer feedback.
+     *
+     * Returns all job applicants for a company.
+     */
+    pub fn get_company_job_applicants_stream(
+        &self,
+        company_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JobApplicant> {
+        let url = format!(
+            "/v1/companies/{}/job_applicants",
+            crate::progenitor_support::encode_path(company_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a job applicant.
      *
@@ -152,3 +190,133 @@ impl JobApplicantsBeta {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`JobApplicantsBeta`], for code that takes them as a trait, so that it can be
+/// tested with the `MockJobApplicantsBetaApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait JobApplicantsBetaApi: Send + Sync {
+    /// See [`JobApplicantsBeta::get_company_job_applicants`].
+    async fn get_company_job_applicants(
+        &self,
+        company_id: &str,
+    ) -> Result<Vec<crate::types::JobApplicant>>;
+
+    /// See [`JobApplicantsBeta::get_all_company_job_applicants`].
+    async fn get_all_company_job_applicants(
+        &self,
+        company_id: &str,
+    ) -> Result<Vec<crate::types::JobApplicant>>;
+
+    /// See [`JobApplicantsBeta::get_company_job_applicants_stream`].
+    fn get_company_job_applicants_stream(
+        &self,
+        company_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JobApplicant>;
+
+    /// See [`JobApplicantsBeta::post_company_job_applicant`].
+    async fn post_company_job_applicant(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyJobApplicantsRequest,
+    ) -> Result<crate::types::JobApplicant>;
+
+    /// See [`JobApplicantsBeta::get_company_job_applicants_applicant`].
+    async fn get_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> Result<crate::types::JobApplicant>;
+
+    /// See [`JobApplicantsBeta::put_company_job_applicants_applicant`].
+    async fn put_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+        body: &crate::types::PutCompanyJobApplicantRequest,
+    ) -> Result<crate::types::JobApplicant>;
+
+    /// See [`JobApplicantsBeta::delete_company_job_applicants_applicant`].
+    async fn delete_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl JobApplicantsBetaApi for JobApplicantsBeta {
+    async fn get_company_job_applicants(
+        &self,
+        company_id: &str,
+    ) -> Result<Vec<crate::types::JobApplicant>> {
+        JobApplicantsBeta::get_company_job_applicants(self, company_id).await
+    }
+
+    async fn get_all_company_job_applicants(
+        &self,
+        company_id: &str,
+    ) -> Result<Vec<crate::types::JobApplicant>> {
+        JobApplicantsBeta::get_all_company_job_applicants(self, company_id).await
+    }
+
+    fn get_company_job_applicants_stream(
+        &self,
+        company_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JobApplicant> {
+        JobApplicantsBeta::get_company_job_applicants_stream(self, company_id, start, max_items)
+    }
+
+    async fn post_company_job_applicant(
+        &self,
+        company_id: &str,
+        body: &crate::types::PostCompanyJobApplicantsRequest,
+    ) -> Result<crate::types::JobApplicant> {
+        JobApplicantsBeta::post_company_job_applicant(self, company_id, body).await
+    }
+
+    async fn get_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> Result<crate::types::JobApplicant> {
+        JobApplicantsBeta::get_company_job_applicants_applicant(
+            self,
+            company_id,
+            job_applicant_uuid,
+        )
+        .await
+    }
+
+    async fn put_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+        body: &crate::types::PutCompanyJobApplicantRequest,
+    ) -> Result<crate::types::JobApplicant> {
+        JobApplicantsBeta::put_company_job_applicants_applicant(
+            self,
+            company_id,
+            job_applicant_uuid,
+            body,
+        )
+        .await
+    }
+
+    async fn delete_company_job_applicants_applicant(
+        &self,
+        company_id: &str,
+        job_applicant_uuid: &str,
+    ) -> Result<()> {
+        JobApplicantsBeta::delete_company_job_applicants_applicant(
+            self,
+            company_id,
+            job_applicant_uuid,
+        )
+        .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Jobs {
     pub client: Client,
@@ -100,6 +98,44 @@ impl Jobs {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get jobs for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/jobs` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Get all of the jobs that an employee holds.
+     */
+    pub fn get_employee_stream(
+        &self,
+        employee_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Job> {
+        let url = format!(
+            "/v1/employees/{}/jobs",
+            crate::progenitor_support::encode_path(employee_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a job.
      *
@@ -146,3 +182,102 @@ impl Jobs {
             .await
     }
 }
+
+/// The functions of [`Jobs`], for code that takes them as a trait, so that it can be
+/// tested with the `MockJobsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait JobsApi: Send + Sync {
+    /// See [`Jobs::get`].
+    async fn get(&self, job_id: &str) -> Result<crate::types::Job>;
+
+    /// See [`Jobs::put`].
+    async fn put(
+        &self,
+        job_id: &str,
+        body: &crate::types::PutJobRequest,
+    ) -> Result<crate::types::Job>;
+
+    /// See [`Jobs::delete`].
+    async fn delete(&self, job_id: &str) -> Result<()>;
+
+    /// See [`Jobs::get_employee`].
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Job>>;
+
+    /// See [`Jobs::get_all_employee`].
+    async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Job>>;
+
+    /// See [`Jobs::get_employee_stream`].
+    fn get_employee_stream(
+        &self,
+        employee_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Job>;
+
+    /// See [`Jobs::post`].
+    async fn post(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostJobRequest,
+    ) -> Result<crate::types::Job>;
+
+    /// See [`Jobs::post_compensation`].
+    async fn post_compensation(
+        &self,
+        job_id: &str,
+        body: &crate::types::PostJobCompensationsRequest,
+    ) -> Result<crate::types::Compensation>;
+}
+
+#[async_trait::async_trait]
+impl JobsApi for Jobs {
+    async fn get(&self, job_id: &str) -> Result<crate::types::Job> {
+        Jobs::get(self, job_id).await
+    }
+
+    async fn put(
+        &self,
+        job_id: &str,
+        body: &crate::types::PutJobRequest,
+    ) -> Result<crate::types::Job> {
+        Jobs::put(self, job_id, body).await
+    }
+
+    async fn delete(&self, job_id: &str) -> Result<()> {
+        Jobs::delete(self, job_id).await
+    }
+
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Job>> {
+        Jobs::get_employee(self, employee_id).await
+    }
+
+    async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Job>> {
+        Jobs::get_all_employee(self, employee_id).await
+    }
+
+    fn get_employee_stream(
+        &self,
+        employee_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Job> {
+        Jobs::get_employee_stream(self, employee_id, start, max_items)
+    }
+
+    async fn post(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostJobRequest,
+    ) -> Result<crate::types::Job> {
+        Jobs::post(self, employee_id, body).await
+    }
+
+    async fn post_compensation(
+        &self,
+        job_id: &str,
+        body: &crate::types::PostJobCompensationsRequest,
+    ) -> Result<crate::types::Compensation> {
+        Jobs::post_compensation(self, job_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Locations {
     pub client: Client,
@@ -56,6 +54,46 @@ impl Locations {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get company locations.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/locations` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Company locations represent all addresses associated with a company. These can be filing addesses, mailing addresses, and/or work locations; one address may serve multiple, or all, purposes.
+     *
+     * Since all company locations are subsets of locations, retrieving or updating an individual record should be done via the locations endpoints.
+     */
+    pub fn get_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Location> {
+        let url = format!(
+            "/v1/companies/{}/locations",
+            crate::progenitor_support::encode_path(company_id_or_uuid),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a company location.
      *
@@ -118,3 +156,86 @@ impl Locations {
             .await
     }
 }
+
+/// The functions of [`Locations`], for code that takes them as a trait, so that it can be
+/// tested with the `MockLocationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait LocationsApi: Send + Sync {
+    /// See [`Locations::get_company`].
+    async fn get_company(&self, company_id_or_uuid: &str) -> Result<Vec<crate::types::Location>>;
+
+    /// See [`Locations::get_all_company`].
+    async fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::Location>>;
+
+    /// See [`Locations::get_company_stream`].
+    fn get_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Location>;
+
+    /// See [`Locations::post_company`].
+    async fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyLocationsRequest,
+    ) -> Result<crate::types::Location>;
+
+    /// See [`Locations::get`].
+    async fn get(&self, location_id: &str) -> Result<crate::types::Location>;
+
+    /// See [`Locations::put`].
+    async fn put(
+        &self,
+        location_id: &str,
+        body: &crate::types::PutLocationRequest,
+    ) -> Result<crate::types::Location>;
+}
+
+#[async_trait::async_trait]
+impl LocationsApi for Locations {
+    async fn get_company(&self, company_id_or_uuid: &str) -> Result<Vec<crate::types::Location>> {
+        Locations::get_company(self, company_id_or_uuid).await
+    }
+
+    async fn get_all_company(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<Vec<crate::types::Location>> {
+        Locations::get_all_company(self, company_id_or_uuid).await
+    }
+
+    fn get_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Location> {
+        Locations::get_company_stream(self, company_id_or_uuid, start, max_items)
+    }
+
+    async fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyLocationsRequest,
+    ) -> Result<crate::types::Location> {
+        Locations::post_company(self, company_id_or_uuid, body).await
+    }
+
+    async fn get(&self, location_id: &str) -> Result<crate::types::Location> {
+        Locations::get(self, location_id).await
+    }
+
+    async fn put(
+        &self,
+        location_id: &str,
+        body: &crate::types::PutLocationRequest,
+    ) -> Result<crate::types::Location> {
+        Locations::put(self, location_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct PaySchedules {
     pub client: Client,
@@ -49,6 +47,44 @@ impl PaySchedules {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get the pay schedules for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/pay_schedules` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * The pay schedule object in Gusto captures the details of when employees work and when they should be paid. A company can have multiple pay schedules.
+     */
+    pub fn get_company_stream(
+        &self,
+        company_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PaySchedule> {
+        let url = format!(
+            "/v1/companies/{}/pay_schedules",
+            crate::progenitor_support::encode_path(company_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get a pay schedule.
      *
@@ -96,3 +132,76 @@ impl PaySchedules {
             .await
     }
 }
+
+/// The functions of [`PaySchedules`], for code that takes them as a trait, so that it can be
+/// tested with the `MockPaySchedulesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait PaySchedulesApi: Send + Sync {
+    /// See [`PaySchedules::get_company`].
+    async fn get_company(&self, company_id: &str) -> Result<Vec<crate::types::PaySchedule>>;
+
+    /// See [`PaySchedules::get_all_company`].
+    async fn get_all_company(&self, company_id: &str) -> Result<Vec<crate::types::PaySchedule>>;
+
+    /// See [`PaySchedules::get_company_stream`].
+    fn get_company_stream(
+        &self,
+        company_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PaySchedule>;
+
+    /// See [`PaySchedules::get_company_schedule`].
+    async fn get_company_schedule(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+    ) -> Result<crate::types::PaySchedule>;
+
+    /// See [`PaySchedules::put_company_schedule`].
+    async fn put_company_schedule(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+        body: &crate::types::PutCompanyPaySchedulesScheduleRequest,
+    ) -> Result<crate::types::PaySchedule>;
+}
+
+#[async_trait::async_trait]
+impl PaySchedulesApi for PaySchedules {
+    async fn get_company(&self, company_id: &str) -> Result<Vec<crate::types::PaySchedule>> {
+        PaySchedules::get_company(self, company_id).await
+    }
+
+    async fn get_all_company(&self, company_id: &str) -> Result<Vec<crate::types::PaySchedule>> {
+        PaySchedules::get_all_company(self, company_id).await
+    }
+
+    fn get_company_stream(
+        &self,
+        company_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PaySchedule> {
+        PaySchedules::get_company_stream(self, company_id, start, max_items)
+    }
+
+    async fn get_company_schedule(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+    ) -> Result<crate::types::PaySchedule> {
+        PaySchedules::get_company_schedule(self, company_id_or_uuid, pay_schedule_id_or_uuid).await
+    }
+
+    async fn put_company_schedule(
+        &self,
+        company_id_or_uuid: &str,
+        pay_schedule_id_or_uuid: &str,
+        body: &crate::types::PutCompanyPaySchedulesScheduleRequest,
+    ) -> Result<crate::types::PaySchedule> {
+        PaySchedules::put_company_schedule(self, company_id_or_uuid, pay_schedule_id_or_uuid, body)
+            .await
+    }
+}
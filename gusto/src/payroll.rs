@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Payroll {
     pub client: Client,
@@ -24,20 +22,18 @@ impl Payroll {
      *
      * **Parameters:**
      *
-     * * `start_date: &str`
-     * * `end_date: &str`
+     * * `params: &PayrollGetCompanyPayPeriodsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_company_pay_periods(
+    pub async fn get_company_pay_periods_with(
         &self,
         company_id_or_uuid: &str,
-        start_date: &str,
-        end_date: &str,
+        params: &PayrollGetCompanyPayPeriodsParams,
     ) -> Result<Vec<crate::types::PayPeriod>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -50,6 +46,24 @@ impl Payroll {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_company_pay_periods_with` and pass the query parameters as a `PayrollGetCompanyPayPeriodsParams` instead.
+     */
+    #[deprecated(note = "use `get_company_pay_periods_with` instead")]
+    pub async fn get_company_pay_periods(
+        &self,
+        company_id_or_uuid: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::PayPeriod>> {
+        let params = PayrollGetCompanyPayPeriodsParams {
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_company_pay_periods_with(company_id_or_uuid, &params)
+            .await
+    }
+
     /**
      * Get pay periods for a company.
      *
@@ -61,18 +75,75 @@ impl Payroll {
      *
      *
      * By default, this endpoint returns every current and past pay period for a company. Since companies can process payroll as often as every week, there can be up to 53 pay periods a year. If a company has been running payroll with Gusto for five years, this endpoint could return up to 265 pay periods. Use the `start_date` and `end_date` parameters to reduce the scope of the response.
+     *
+     * **Parameters:**
+     *
+     * * `params: &PayrollGetCompanyPayPeriodsParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_company_pay_periods_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyPayPeriodsParams,
+    ) -> Result<Vec<crate::types::PayPeriod>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/companies/{}/pay_periods?{}",
+            crate::progenitor_support::encode_path(company_id_or_uuid),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_company_pay_periods_with` and pass the query parameters as a `PayrollGetCompanyPayPeriodsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_company_pay_periods_with` instead")]
     pub async fn get_all_company_pay_periods(
         &self,
         company_id_or_uuid: &str,
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::PayPeriod>> {
+        let params = PayrollGetCompanyPayPeriodsParams {
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_all_company_pay_periods_with(company_id_or_uuid, &params)
+            .await
+    }
+
+    /**
+     * Get pay periods for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/pay_periods` endpoint.
+     *
+     * As opposed to `get_all_company_pay_periods`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Pay periods are the foundation of payroll. Compensation, time & attendance, taxes, and expense reports all rely on when they happened. To begin submitting information for a given payroll, we need to agree on the time period.
+     *
+     *
+     * By default, this endpoint returns every current and past pay period for a company. Since companies can process payroll as often as every week, there can be up to 53 pay periods a year. If a company has been running payroll with Gusto for five years, this endpoint could return up to 265 pay periods. Use the `start_date` and `end_date` parameters to reduce the scope of the response.
+     */
+    pub fn get_company_pay_periods_stream(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyPayPeriodsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PayPeriod> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -82,7 +153,22 @@ impl Payroll {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -99,38 +185,30 @@ impl Payroll {
      *
      * **Parameters:**
      *
-     * * `processed: bool` -- Whether to return processed or unprocessed payrolls.
-     * * `include_off_cycle: bool` -- Whether to include off cycle payrolls in the response.
-     * * `include: &[String]` -- Include the requested attribute in the employee_compensations attribute in the response.
-     * * `start_date: &str` -- Return payrolls whose pay period is after the start date.
-     * * `end_date: &str` -- Return payrolls whose pay period is before the end date.
+     * * `params: &PayrollGetCompanyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_company(
+    pub async fn get_company_with(
         &self,
         company_id_or_uuid: &str,
-        processed: bool,
-        include_off_cycle: bool,
-        include: &[String],
-        start_date: &str,
-        end_date: &str,
-    ) -> Result<Vec<crate::types::PayrollData>> {
+        params: &PayrollGetCompanyParams,
+    ) -> Result<Vec<crate::types::Payroll>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.join(" ")));
         }
-        if include_off_cycle {
+        if let Some(include_off_cycle) = &params.include_off_cycle {
             query_args.push((
                 "include_off_cycle".to_string(),
                 include_off_cycle.to_string(),
             ));
         }
-        if processed {
+        if let Some(processed) = &params.processed {
             query_args.push(("processed".to_string(), processed.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -143,6 +221,29 @@ impl Payroll {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_company_with` and pass the query parameters as a `PayrollGetCompanyParams` instead.
+     */
+    #[deprecated(note = "use `get_company_with` instead")]
+    pub async fn get_company(
+        &self,
+        company_id_or_uuid: &str,
+        processed: bool,
+        include_off_cycle: bool,
+        include: &[String],
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::Payroll>> {
+        let params = PayrollGetCompanyParams {
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            include: Some(include.to_vec()).filter(|v| !v.is_empty()),
+            include_off_cycle: Some(include_off_cycle).filter(|v| *v),
+            processed: Some(processed).filter(|v| *v),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_company_with(company_id_or_uuid, &params).await
+    }
+
     /**
      * Get all payrolls for a company.
      *
@@ -156,7 +257,49 @@ impl Payroll {
      * * Hour and dollar amounts are returned as string representations of numeric decimals.
      * * Hours are represented to the thousands place; dollar amounts are represented to the cent.
      * * Every eligible compensation is returned for each employee. If no data has yet be inserted for a given field, it defaults to “0.00” (for fixed amounts) or “0.000” (for hours ).
+     *
+     * **Parameters:**
+     *
+     * * `params: &PayrollGetCompanyParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_company_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyParams,
+    ) -> Result<Vec<crate::types::Payroll>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if let Some(include) = &params.include {
+            query_args.push(("include".to_string(), include.join(" ")));
+        }
+        if let Some(include_off_cycle) = &params.include_off_cycle {
+            query_args.push((
+                "include_off_cycle".to_string(),
+                include_off_cycle.to_string(),
+            ));
+        }
+        if let Some(processed) = &params.processed {
+            query_args.push(("processed".to_string(), processed.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/companies/{}/payrolls?{}",
+            crate::progenitor_support::encode_path(company_id_or_uuid),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_company_with` and pass the query parameters as a `PayrollGetCompanyParams` instead.
      */
+    #[deprecated(note = "use `get_all_company_with` instead")]
     pub async fn get_all_company(
         &self,
         company_id_or_uuid: &str,
@@ -165,24 +308,55 @@ impl Payroll {
         include: &[String],
         start_date: &str,
         end_date: &str,
-    ) -> Result<Vec<crate::types::PayrollData>> {
+    ) -> Result<Vec<crate::types::Payroll>> {
+        let params = PayrollGetCompanyParams {
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            include: Some(include.to_vec()).filter(|v| !v.is_empty()),
+            include_off_cycle: Some(include_off_cycle).filter(|v| *v),
+            processed: Some(processed).filter(|v| *v),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_all_company_with(company_id_or_uuid, &params).await
+    }
+
+    /**
+     * Get all payrolls for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id_or_uuid}/payrolls` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns all payrolls, current and past for a company.
+     *
+     * Notes:
+     * * Hour and dollar amounts are returned as string representations of numeric decimals.
+     * * Hours are represented to the thousands place; dollar amounts are represented to the cent.
+     * * Every eligible compensation is returned for each employee. If no data has yet be inserted for a given field, it defaults to “0.00” (for fixed amounts) or “0.000” (for hours ).
+     */
+    pub fn get_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Payroll> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !include.is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.join(" ")));
         }
-        if include_off_cycle {
+        if let Some(include_off_cycle) = &params.include_off_cycle {
             query_args.push((
                 "include_off_cycle".to_string(),
                 include_off_cycle.to_string(),
             ));
         }
-        if processed {
+        if let Some(processed) = &params.processed {
             query_args.push(("processed".to_string(), processed.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -192,7 +366,22 @@ impl Payroll {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -208,7 +397,7 @@ impl Payroll {
         &self,
         company_id_or_uuid: &str,
         body: &crate::types::PostCompanyPayrollsRequest,
-    ) -> Result<crate::types::PayrollData> {
+    ) -> Result<crate::types::Payroll> {
         let url = format!(
             "/v1/companies/{}/payrolls",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -233,21 +422,19 @@ impl Payroll {
      *
      * **Parameters:**
      *
-     * * `include: crate::types::GetCompanyPayrollsInclude` -- Include the requested attribute in the employee_compensations attribute in the response.
-     * * `show_calculation: &str` -- with `include`, shows the tax, and/or benefit, and/or deduction details for a calculated, unprocessed payroll. .
+     * * `params: &PayrollGetCompanyPayrollParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_company_payroll(
+    pub async fn get_company_payroll_with(
         &self,
         company_id_or_uuid: &str,
         payroll_id_or_uuid: &str,
-        include: crate::types::GetCompanyPayrollsInclude,
-        show_calculation: &str,
-    ) -> Result<crate::types::PayrollData> {
+        params: &PayrollGetCompanyPayrollParams,
+    ) -> Result<crate::types::Payroll> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include.to_string().is_empty() {
+        if let Some(include) = &params.include {
             query_args.push(("include".to_string(), include.to_string()));
         }
-        if !show_calculation.is_empty() {
+        if let Some(show_calculation) = &params.show_calculation {
             query_args.push(("show_calculation".to_string(), show_calculation.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -261,6 +448,25 @@ impl Payroll {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_company_payroll_with` and pass the query parameters as a `PayrollGetCompanyPayrollParams` instead.
+     */
+    #[deprecated(note = "use `get_company_payroll_with` instead")]
+    pub async fn get_company_payroll(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+        include: crate::types::GetCompanyPayrollInclude,
+        show_calculation: &str,
+    ) -> Result<crate::types::Payroll> {
+        let params = PayrollGetCompanyPayrollParams {
+            include: Some(include).filter(|v| !v.to_string().is_empty()),
+            show_calculation: Some(show_calculation.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_company_payroll_with(company_id_or_uuid, payroll_id_or_uuid, &params)
+            .await
+    }
+
     /**
      * Update a payroll by ID.
      *
@@ -273,7 +479,7 @@ impl Payroll {
         company_id_or_uuid: &str,
         payroll_id_or_uuid: &str,
         body: &crate::types::PutCompanyPayrollsRequest,
-    ) -> Result<crate::types::PayrollData> {
+    ) -> Result<crate::types::Payroll> {
         let url = format!(
             "/v1/companies/{}/payrolls/{}",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -299,8 +505,8 @@ impl Payroll {
         company_id_or_uuid: &str,
         pay_period_start_date: &str,
         pay_period_end_date: &str,
-        body: &crate::types::PutCompanyPayrollsRequest,
-    ) -> Result<crate::types::PayrollData> {
+        body: &crate::types::PutCompanyPayrollsPayPeriodStartDateEndRequest,
+    ) -> Result<crate::types::Payroll> {
         let url = format!(
             "/v1/companies/{}/payrolls/{}/{}",
             crate::progenitor_support::encode_path(company_id_or_uuid),
@@ -367,7 +573,7 @@ impl Payroll {
         &self,
         company_id: &str,
         payroll_id: &str,
-    ) -> Result<crate::types::PayrollData> {
+    ) -> Result<crate::types::Payroll> {
         let url = format!(
             "/v1/companies/{}/payrolls/{}/cancel",
             crate::progenitor_support::encode_path(company_id),
@@ -396,3 +602,310 @@ impl Payroll {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Payroll::get_company_pay_periods_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PayrollGetCompanyPayPeriodsParams {
+    pub end_date: Option<String>,
+    pub start_date: Option<String>,
+}
+
+impl PayrollGetCompanyPayPeriodsParams {
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Payroll::get_company_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PayrollGetCompanyParams {
+    /// Return payrolls whose pay period is before the end date.
+    pub end_date: Option<String>,
+    /// Include the requested attribute in the employee_compensations attribute in the response.
+    pub include: Option<Vec<String>>,
+    /// Whether to include off cycle payrolls in the response.
+    pub include_off_cycle: Option<bool>,
+    /// Whether to return processed or unprocessed payrolls.
+    pub processed: Option<bool>,
+    /// Return payrolls whose pay period is after the start date.
+    pub start_date: Option<String>,
+}
+
+impl PayrollGetCompanyParams {
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn include(mut self, include: Vec<String>) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    pub fn include_off_cycle(mut self, include_off_cycle: bool) -> Self {
+        self.include_off_cycle = Some(include_off_cycle);
+        self
+    }
+
+    pub fn processed(mut self, processed: bool) -> Self {
+        self.processed = Some(processed);
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Payroll::get_company_payroll_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PayrollGetCompanyPayrollParams {
+    /// Include the requested attribute in the employee_compensations attribute in the response.
+    pub include: Option<crate::types::GetCompanyPayrollInclude>,
+    /// with `include`, shows the tax, and/or benefit, and/or deduction details for a calculated, unprocessed payroll. .
+    pub show_calculation: Option<String>,
+}
+
+impl PayrollGetCompanyPayrollParams {
+    pub fn include(mut self, include: crate::types::GetCompanyPayrollInclude) -> Self {
+        self.include = Some(include);
+        self
+    }
+
+    pub fn show_calculation<T: ToString>(mut self, show_calculation: T) -> Self {
+        self.show_calculation = Some(show_calculation.to_string());
+        self
+    }
+}
+
+/// The functions of [`Payroll`], for code that takes them as a trait, so that it can be
+/// tested with the `MockPayrollApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait PayrollApi: Send + Sync {
+    /// See [`Payroll::get_company_pay_periods_with`].
+    async fn get_company_pay_periods_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyPayPeriodsParams,
+    ) -> Result<Vec<crate::types::PayPeriod>>;
+
+    /// See [`Payroll::get_all_company_pay_periods_with`].
+    async fn get_all_company_pay_periods_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyPayPeriodsParams,
+    ) -> Result<Vec<crate::types::PayPeriod>>;
+
+    /// See [`Payroll::get_company_pay_periods_stream`].
+    fn get_company_pay_periods_stream(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyPayPeriodsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PayPeriod>;
+
+    /// See [`Payroll::get_company_with`].
+    async fn get_company_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyParams,
+    ) -> Result<Vec<crate::types::Payroll>>;
+
+    /// See [`Payroll::get_all_company_with`].
+    async fn get_all_company_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyParams,
+    ) -> Result<Vec<crate::types::Payroll>>;
+
+    /// See [`Payroll::get_company_stream`].
+    fn get_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Payroll>;
+
+    /// See [`Payroll::post_company`].
+    async fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyPayrollsRequest,
+    ) -> Result<crate::types::Payroll>;
+
+    /// See [`Payroll::get_company_payroll_with`].
+    async fn get_company_payroll_with(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+        params: &PayrollGetCompanyPayrollParams,
+    ) -> Result<crate::types::Payroll>;
+
+    /// See [`Payroll::put_company`].
+    async fn put_company(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+        body: &crate::types::PutCompanyPayrollsRequest,
+    ) -> Result<crate::types::Payroll>;
+
+    /// See [`Payroll::put_company_pay_period_start_date_end`].
+    async fn put_company_pay_period_start_date_end(
+        &self,
+        company_id_or_uuid: &str,
+        pay_period_start_date: &str,
+        pay_period_end_date: &str,
+        body: &crate::types::PutCompanyPayrollsPayPeriodStartDateEndRequest,
+    ) -> Result<crate::types::Payroll>;
+
+    /// See [`Payroll::put_company_calculate`].
+    async fn put_company_calculate(&self, company_id: &str, payroll_id: &str) -> Result<()>;
+
+    /// See [`Payroll::put_company_submit`].
+    async fn put_company_submit(&self, company_id: &str, payroll_id: &str) -> Result<()>;
+
+    /// See [`Payroll::put_company_cancel`].
+    async fn put_company_cancel(
+        &self,
+        company_id: &str,
+        payroll_id: &str,
+    ) -> Result<crate::types::Payroll>;
+
+    /// See [`Payroll::get_company_or_reversals`].
+    async fn get_company_or_reversals(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<crate::types::GetCompanyPayrollReversalsResponse>;
+}
+
+#[async_trait::async_trait]
+impl PayrollApi for Payroll {
+    async fn get_company_pay_periods_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyPayPeriodsParams,
+    ) -> Result<Vec<crate::types::PayPeriod>> {
+        Payroll::get_company_pay_periods_with(self, company_id_or_uuid, params).await
+    }
+
+    async fn get_all_company_pay_periods_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyPayPeriodsParams,
+    ) -> Result<Vec<crate::types::PayPeriod>> {
+        Payroll::get_all_company_pay_periods_with(self, company_id_or_uuid, params).await
+    }
+
+    fn get_company_pay_periods_stream(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyPayPeriodsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PayPeriod> {
+        Payroll::get_company_pay_periods_stream(self, company_id_or_uuid, params, start, max_items)
+    }
+
+    async fn get_company_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyParams,
+    ) -> Result<Vec<crate::types::Payroll>> {
+        Payroll::get_company_with(self, company_id_or_uuid, params).await
+    }
+
+    async fn get_all_company_with(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyParams,
+    ) -> Result<Vec<crate::types::Payroll>> {
+        Payroll::get_all_company_with(self, company_id_or_uuid, params).await
+    }
+
+    fn get_company_stream(
+        &self,
+        company_id_or_uuid: &str,
+        params: &PayrollGetCompanyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Payroll> {
+        Payroll::get_company_stream(self, company_id_or_uuid, params, start, max_items)
+    }
+
+    async fn post_company(
+        &self,
+        company_id_or_uuid: &str,
+        body: &crate::types::PostCompanyPayrollsRequest,
+    ) -> Result<crate::types::Payroll> {
+        Payroll::post_company(self, company_id_or_uuid, body).await
+    }
+
+    async fn get_company_payroll_with(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+        params: &PayrollGetCompanyPayrollParams,
+    ) -> Result<crate::types::Payroll> {
+        Payroll::get_company_payroll_with(self, company_id_or_uuid, payroll_id_or_uuid, params)
+            .await
+    }
+
+    async fn put_company(
+        &self,
+        company_id_or_uuid: &str,
+        payroll_id_or_uuid: &str,
+        body: &crate::types::PutCompanyPayrollsRequest,
+    ) -> Result<crate::types::Payroll> {
+        Payroll::put_company(self, company_id_or_uuid, payroll_id_or_uuid, body).await
+    }
+
+    async fn put_company_pay_period_start_date_end(
+        &self,
+        company_id_or_uuid: &str,
+        pay_period_start_date: &str,
+        pay_period_end_date: &str,
+        body: &crate::types::PutCompanyPayrollsPayPeriodStartDateEndRequest,
+    ) -> Result<crate::types::Payroll> {
+        Payroll::put_company_pay_period_start_date_end(
+            self,
+            company_id_or_uuid,
+            pay_period_start_date,
+            pay_period_end_date,
+            body,
+        )
+        .await
+    }
+
+    async fn put_company_calculate(&self, company_id: &str, payroll_id: &str) -> Result<()> {
+        Payroll::put_company_calculate(self, company_id, payroll_id).await
+    }
+
+    async fn put_company_submit(&self, company_id: &str, payroll_id: &str) -> Result<()> {
+        Payroll::put_company_submit(self, company_id, payroll_id).await
+    }
+
+    async fn put_company_cancel(
+        &self,
+        company_id: &str,
+        payroll_id: &str,
+    ) -> Result<crate::types::Payroll> {
+        Payroll::put_company_cancel(self, company_id, payroll_id).await
+    }
+
+    async fn get_company_or_reversals(
+        &self,
+        company_id_or_uuid: &str,
+    ) -> Result<crate::types::GetCompanyPayrollReversalsResponse> {
+        Payroll::get_company_or_reversals(self, company_id_or_uuid).await
+    }
+}
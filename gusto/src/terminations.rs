@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Terminations {
     pub client: Client,
@@ -53,6 +51,46 @@ impl Terminations {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get terminations for an employee.
+     *
+     * This function performs a `GET` to the `/v1/employees/{employee_id}/terminations` endpoint.
+     *
+     * As opposed to `get_all_employee`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Terminations are created whenever an employee is scheduled to leave the company. The only things required are an effective date (their last day of work) and whether they should receive their wages in a one-off termination payroll or with the rest of the company.
+     *
+     * Note that some states require employees to receive their final wages within 24 hours (unless they consent otherwise,) in which case running a one-off payroll may be the only option.
+     */
+    pub fn get_employee_stream(
+        &self,
+        employee_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Termination> {
+        let url = format!(
+            "/v1/employees/{}/terminations",
+            crate::progenitor_support::encode_path(employee_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create an employee termination.
      *
@@ -77,3 +115,58 @@ impl Terminations {
             .await
     }
 }
+
+/// The functions of [`Terminations`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTerminationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TerminationsApi: Send + Sync {
+    /// See [`Terminations::get_employee`].
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Termination>>;
+
+    /// See [`Terminations::get_all_employee`].
+    async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Termination>>;
+
+    /// See [`Terminations::get_employee_stream`].
+    fn get_employee_stream(
+        &self,
+        employee_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Termination>;
+
+    /// See [`Terminations::post_employee`].
+    async fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeTerminationsRequest,
+    ) -> Result<crate::types::Termination>;
+}
+
+#[async_trait::async_trait]
+impl TerminationsApi for Terminations {
+    async fn get_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Termination>> {
+        Terminations::get_employee(self, employee_id).await
+    }
+
+    async fn get_all_employee(&self, employee_id: &str) -> Result<Vec<crate::types::Termination>> {
+        Terminations::get_all_employee(self, employee_id).await
+    }
+
+    fn get_employee_stream(
+        &self,
+        employee_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Termination> {
+        Terminations::get_employee_stream(self, employee_id, start, max_items)
+    }
+
+    async fn post_employee(
+        &self,
+        employee_id: &str,
+        body: &crate::types::PostEmployeeTerminationsRequest,
+    ) -> Result<crate::types::Termination> {
+        Terminations::post_employee(self, employee_id, body).await
+    }
+}
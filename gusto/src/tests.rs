@@ -135,12 +135,26 @@ fn test_deserialize_employee() {
             .unwrap()
             .clone()
             .to_string(),
-        // TODO: fix this better.
-        //"Commission Only Exempt".to_string()
-        "*".to_string()
+        "Commission Only Exempt".to_string()
     );
     assert_eq!(
         first_compensation.payment_unit.as_ref().unwrap().clone(),
         crate::types::PaymentUnit::Year
     );
 }
+
+#[test]
+fn test_decimal_rate() {
+    let deserialized: crate::types::Employee = serde_json::from_str(EMPLOYEE).unwrap();
+    let first_compensation = deserialized.jobs[0].compensations.first().unwrap();
+
+    // The rate is sent back as it came, zeros included.
+    let serialized = serde_json::to_value(first_compensation).unwrap();
+    assert_eq!(serialized["rate"], "70000.00");
+
+    #[cfg(feature = "decimal")]
+    assert_eq!(
+        first_compensation.rate,
+        rust_decimal::Decimal::new(7000000, 2)
+    );
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TimeOffRequests {
     pub client: Client,
@@ -38,20 +36,18 @@ impl TimeOffRequests {
      *
      * **Parameters:**
      *
-     * * `start_date: &str` -- Filter time off requests where the request start date is equal to or after this parameter.
-     * * `end_date: &str` -- Filter time off requests where the request end date is equal to or after this parameter.
+     * * `params: &TimeOffRequestsGetCompanyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_company(
+    pub async fn get_company_with(
         &self,
         company_id: &str,
-        start_date: &str,
-        end_date: &str,
+        params: &TimeOffRequestsGetCompanyParams,
     ) -> Result<Vec<crate::types::TimeOffRequest>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -64,6 +60,23 @@ impl TimeOffRequests {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_company_with` and pass the query parameters as a `TimeOffRequestsGetCompanyParams` instead.
+     */
+    #[deprecated(note = "use `get_company_with` instead")]
+    pub async fn get_company(
+        &self,
+        company_id: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::TimeOffRequest>> {
+        let params = TimeOffRequestsGetCompanyParams {
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_company_with(company_id, &params).await
+    }
+
     /**
      * Get time off requests for a company.
      *
@@ -89,18 +102,88 @@ impl TimeOffRequests {
      *
      * Returns all time off requests where the request start date is equal to or after May 1, 2019 and the request end date is equal to or before August 31, 2019.
      *
+     *
+     * **Parameters:**
+     *
+     * * `params: &TimeOffRequestsGetCompanyParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_company_with(
+        &self,
+        company_id: &str,
+        params: &TimeOffRequestsGetCompanyParams,
+    ) -> Result<Vec<crate::types::TimeOffRequest>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/companies/{}/time_off_requests?{}",
+            crate::progenitor_support::encode_path(company_id),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_company_with` and pass the query parameters as a `TimeOffRequestsGetCompanyParams` instead.
+     */
+    #[deprecated(note = "use `get_all_company_with` instead")]
     pub async fn get_all_company(
         &self,
         company_id: &str,
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::TimeOffRequest>> {
+        let params = TimeOffRequestsGetCompanyParams {
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_all_company_with(company_id, &params).await
+    }
+
+    /**
+     * Get time off requests for a company.
+     *
+     * This function performs a `GET` to the `/v1/companies/{company_id}/time_off_requests` endpoint.
+     *
+     * As opposed to `get_all_company`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Get all time off requests, past and present, for a company.
+     *
+     * In order to reduce the number of time off requests returned in a single response, or to retrieve time off requests from a time period of interest, you may use the `start_date` and `end_date` parameters.
+     *
+     * You may provide both or either parameters to scope the returned data. For example:
+     *
+     * `?start_date='2019-01-01'`
+     *
+     * Returns all time off requests where the request start date is equal to or after January 1, 2019.
+     *
+     * `?end_date='2019-01-01'`
+     *
+     * Returns all time off requests where the request end date is equal to or before January 1, 2019.
+     *
+     * `?start_date='2019-05-01'&end_date='2019-08-31'`
+     *
+     * Returns all time off requests where the request start date is equal to or after May 1, 2019 and the request end date is equal to or before August 31, 2019.
+     *
+     */
+    pub fn get_company_stream(
+        &self,
+        company_id: &str,
+        params: &TimeOffRequestsGetCompanyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TimeOffRequest> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -110,7 +193,22 @@ impl TimeOffRequests {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -134,3 +232,97 @@ impl TimeOffRequests {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `TimeOffRequests::get_company_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TimeOffRequestsGetCompanyParams {
+    /// Filter time off requests where the request end date is equal to or after this parameter.
+    pub end_date: Option<String>,
+    /// Filter time off requests where the request start date is equal to or after this parameter.
+    pub start_date: Option<String>,
+}
+
+impl TimeOffRequestsGetCompanyParams {
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The functions of [`TimeOffRequests`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTimeOffRequestsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TimeOffRequestsApi: Send + Sync {
+    /// See [`TimeOffRequests::get_company_with`].
+    async fn get_company_with(
+        &self,
+        company_id: &str,
+        params: &TimeOffRequestsGetCompanyParams,
+    ) -> Result<Vec<crate::types::TimeOffRequest>>;
+
+    /// See [`TimeOffRequests::get_all_company_with`].
+    async fn get_all_company_with(
+        &self,
+        company_id: &str,
+        params: &TimeOffRequestsGetCompanyParams,
+    ) -> Result<Vec<crate::types::TimeOffRequest>>;
+
+    /// See [`TimeOffRequests::get_company_stream`].
+    fn get_company_stream(
+        &self,
+        company_id: &str,
+        params: &TimeOffRequestsGetCompanyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TimeOffRequest>;
+
+    /// See [`TimeOffRequests::get_company_request`].
+    async fn get_company_request(
+        &self,
+        company_id: &str,
+        time_off_request_id: &str,
+    ) -> Result<crate::types::TimeOffRequest>;
+}
+
+#[async_trait::async_trait]
+impl TimeOffRequestsApi for TimeOffRequests {
+    async fn get_company_with(
+        &self,
+        company_id: &str,
+        params: &TimeOffRequestsGetCompanyParams,
+    ) -> Result<Vec<crate::types::TimeOffRequest>> {
+        TimeOffRequests::get_company_with(self, company_id, params).await
+    }
+
+    async fn get_all_company_with(
+        &self,
+        company_id: &str,
+        params: &TimeOffRequestsGetCompanyParams,
+    ) -> Result<Vec<crate::types::TimeOffRequest>> {
+        TimeOffRequests::get_all_company_with(self, company_id, params).await
+    }
+
+    fn get_company_stream(
+        &self,
+        company_id: &str,
+        params: &TimeOffRequestsGetCompanyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TimeOffRequest> {
+        TimeOffRequests::get_company_stream(self, company_id, params, start, max_items)
+    }
+
+    async fn get_company_request(
+        &self,
+        company_id: &str,
+        time_off_request_id: &str,
+    ) -> Result<crate::types::TimeOffRequest> {
+        TimeOffRequests::get_company_request(self, company_id, time_off_request_id).await
+    }
+}
@@ -2,234 +2,199 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-/// The representation of an employee in Gusto.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Employee {
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub company_id: f64,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company_uuid: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub custom_fields: Vec<EmployeeCustomField>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub date_of_birth: Option<chrono::NaiveDate>,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub department: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub eligible_paid_time_off: Vec<PaidTimeOff>,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub garnishments: Vec<Garnishment>,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub has_ssn: bool,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub home_address: Option<Location>,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub id: f64,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub jobs: Vec<Job>,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub manager_id: f64,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub middle_initial: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub onboarded: bool,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub preferred_first_name: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ssn: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub terminated: bool,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub terminations: Vec<Termination>,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub two_percent_shareholder: bool,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub uuid: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-    /**
-     * The representation of an employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub work_email: String,
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> std::option::Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> std::convert::From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match std::option::Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        std::option::Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        std::option::Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
 }
 
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
+
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+
+#[cfg(feature = "admins_beta")]
+mod admins_beta;
+#[cfg(feature = "admins_beta")]
+pub use admins_beta::*;
+
+#[cfg(feature = "benefits")]
+mod benefits;
+#[cfg(feature = "benefits")]
+pub use benefits::*;
+
+#[cfg(feature = "companies")]
+mod companies;
+#[cfg(feature = "companies")]
+pub use companies::*;
+
+#[cfg(feature = "company_bank_accounts_beta")]
+mod company_bank_accounts_beta;
+#[cfg(feature = "company_bank_accounts_beta")]
+pub use company_bank_accounts_beta::*;
+
+#[cfg(feature = "compensations")]
+mod compensations;
+#[cfg(feature = "compensations")]
+pub use compensations::*;
+
+#[cfg(feature = "contractor_payments")]
+mod contractor_payments;
+#[cfg(feature = "contractor_payments")]
+pub use contractor_payments::*;
+
+#[cfg(feature = "contractors")]
+mod contractors;
+#[cfg(feature = "contractors")]
+pub use contractors::*;
+
+#[cfg(feature = "current_user")]
+mod current_user;
+#[cfg(feature = "current_user")]
+pub use current_user::*;
+
+#[cfg(feature = "custom_fields")]
+mod custom_fields;
+#[cfg(feature = "custom_fields")]
+pub use custom_fields::*;
+
+#[cfg(feature = "earning_type")]
+mod earning_type;
+#[cfg(feature = "earning_type")]
+pub use earning_type::*;
+
+#[cfg(feature = "employees")]
+mod employees;
+#[cfg(feature = "employees")]
+pub use employees::*;
+
+#[cfg(feature = "garnishments")]
+mod garnishments;
+#[cfg(feature = "garnishments")]
+pub use garnishments::*;
+
+#[cfg(feature = "job_applicants_beta")]
+mod job_applicants_beta;
+#[cfg(feature = "job_applicants_beta")]
+pub use job_applicants_beta::*;
+
+#[cfg(feature = "jobs")]
+mod jobs;
+#[cfg(feature = "jobs")]
+pub use jobs::*;
+
+#[cfg(feature = "locations")]
+mod locations;
+#[cfg(feature = "locations")]
+pub use locations::*;
+
+#[cfg(feature = "pay_schedules")]
+mod pay_schedules;
+#[cfg(feature = "pay_schedules")]
+pub use pay_schedules::*;
+
+#[cfg(feature = "payroll")]
+mod payroll;
+#[cfg(feature = "payroll")]
+pub use payroll::*;
+
+#[cfg(feature = "terminations")]
+mod terminations;
+#[cfg(feature = "terminations")]
+pub use terminations::*;
+
+#[cfg(feature = "time_off_requests")]
+mod time_off_requests;
+#[cfg(feature = "time_off_requests")]
+pub use time_off_requests::*;
 /// The representation of an address in Gusto.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Location {
@@ -439,21 +404,13 @@ pub struct Garnishment {
     /**
      * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub amount: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<crate::types::DecimalString>,
     /**
      * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub annual_maximum: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annual_maximum: Option<crate::types::DecimalString>,
     /**
      * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
      */
@@ -500,12 +457,8 @@ pub struct Garnishment {
     /**
      * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub pay_period_maximum: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pay_period_maximum: Option<crate::types::DecimalString>,
     /**
      * Garnishments, or employee deductions, are fixed amounts or percentages deducted from an employee’s pay. They can be deducted a specific number of times or on a recurring basis. Garnishments can also have maximum deductions on a yearly or per-pay-period bases. Common uses for garnishments are court-ordered payments for child support or back taxes. Some companies provide loans to their employees that are repaid via garnishments.
      */
@@ -592,6 +545,7 @@ pub struct Termination {
  * The unit accompanying the compensation rate. If the employee is an owner, rate should be 'Paycheck'.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PaymentUnit {
     #[serde(rename = "Hour")]
     Hour,
@@ -603,35 +557,61 @@ pub enum PaymentUnit {
     Week,
     #[serde(rename = "Year")]
     Year,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PaymentUnit {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PaymentUnit {
+    fn as_ref(&self) -> &str {
+        match self {
             PaymentUnit::Hour => "Hour",
             PaymentUnit::Month => "Month",
             PaymentUnit::Paycheck => "Paycheck",
             PaymentUnit::Week => "Week",
             PaymentUnit::Year => "Year",
-            PaymentUnit::Noop => "",
-            PaymentUnit::FallthroughString => "*",
+            PaymentUnit::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PaymentUnit {
-    fn default() -> PaymentUnit {
-        PaymentUnit::Noop
+impl std::fmt::Display for PaymentUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PaymentUnit {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Hour" => PaymentUnit::Hour,
+            "Month" => PaymentUnit::Month,
+            "Paycheck" => PaymentUnit::Paycheck,
+            "Week" => PaymentUnit::Week,
+            "Year" => PaymentUnit::Year,
+            _ => PaymentUnit::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PaymentUnit {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PaymentUnit {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PaymentUnit::Noop)
+
+impl std::convert::From<PaymentUnit> for String {
+    fn from(v: PaymentUnit) -> Self {
+        match v {
+            PaymentUnit::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -639,6 +619,7 @@ impl PaymentUnit {
  * The FLSA status for this compensation. Salaried ('Exempt') employees are paid a fixed salary every pay period. Salaried with overtime ('Salaried Nonexempt') employees are paid a fixed salary every pay period, and receive overtime pay when applicable. Hourly ('Nonexempt') employees are paid for the hours they work, and receive overtime pay when applicable. Owners ('Owner') are employees that own at least twenty percent of the company.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FlsaStatus {
     #[serde(rename = "Exempt")]
     Exempt,
@@ -648,43 +629,68 @@ pub enum FlsaStatus {
     Owner,
     #[serde(rename = "Salaried Nonexempt")]
     SalariedNonexempt,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for FlsaStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for FlsaStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             FlsaStatus::Exempt => "Exempt",
             FlsaStatus::Nonexempt => "Nonexempt",
             FlsaStatus::Owner => "Owner",
             FlsaStatus::SalariedNonexempt => "Salaried Nonexempt",
-            FlsaStatus::Noop => "",
-            FlsaStatus::FallthroughString => "*",
+            FlsaStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for FlsaStatus {
-    fn default() -> FlsaStatus {
-        FlsaStatus::Noop
+impl std::fmt::Display for FlsaStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl FlsaStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FlsaStatus::Noop)
+
+impl std::str::FromStr for FlsaStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Exempt" => FlsaStatus::Exempt,
+            "Nonexempt" => FlsaStatus::Nonexempt,
+            "Owner" => FlsaStatus::Owner,
+            "Salaried Nonexempt" => FlsaStatus::SalariedNonexempt,
+            _ => FlsaStatus::Unknown(s.to_string()),
+        })
     }
 }
 
-/// The representation of compensation in Gusto.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Compensation {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
+impl std::convert::From<String> for FlsaStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<FlsaStatus> for String {
+    fn from(v: FlsaStatus) -> Self {
+        match v {
+            FlsaStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// The representation of compensation in Gusto.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Compensation {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_format::deserialize"
     )]
     pub effective_date: Option<chrono::NaiveDate>,
@@ -719,12 +725,8 @@ pub struct Compensation {
     /**
      * The representation of compensation in Gusto.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub rate: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate: Option<crate::types::DecimalString>,
     /**
      * The representation of compensation in Gusto.
      */
@@ -890,12 +892,8 @@ pub struct Job {
     /**
      * The representation of a job in Gusto.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub rate: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate: Option<crate::types::DecimalString>,
     /**
      * The representation of a job in Gusto.
      */
@@ -916,42 +914,11 @@ pub struct Job {
     pub version: String,
 }
 
-/// The representation of an admin user in Gusto.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Admin {
-    /**
-     * The representation of an admin user in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The representation of an admin user in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     * The representation of an admin user in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-}
-
 /**
  * The tax payer type of the company.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EntityType {
     #[serde(rename = "Association")]
     Association,
@@ -977,15 +944,15 @@ pub enum EntityType {
     SoleProprietor,
     #[serde(rename = "Trusteeship")]
     Trusteeship,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EntityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EntityType {
+    fn as_ref(&self) -> &str {
+        match self {
             EntityType::Association => "Association",
             EntityType::CCorporation => "C-Corporation",
             EntityType::CoOwnership => "Co-ownership",
@@ -998,68 +965,54 @@ impl std::fmt::Display for EntityType {
             EntityType::SCorporation => "S-Corporation",
             EntityType::SoleProprietor => "Sole proprietor",
             EntityType::Trusteeship => "Trusteeship",
-            EntityType::Noop => "",
-            EntityType::FallthroughString => "*",
+            EntityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EntityType {
-    fn default() -> EntityType {
-        EntityType::Noop
+impl std::fmt::Display for EntityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl EntityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EntityType::Noop)
+
+impl std::str::FromStr for EntityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Association" => EntityType::Association,
+            "C-Corporation" => EntityType::CCorporation,
+            "Co-ownership" => EntityType::CoOwnership,
+            "General partnership" => EntityType::GeneralPartnership,
+            "Joint venture" => EntityType::JointVenture,
+            "LLC" => EntityType::Llc,
+            "LLP" => EntityType::Llp,
+            "Limited partnership" => EntityType::LimitedPartnership,
+            "Non-Profit" => EntityType::NonProfit,
+            "S-Corporation" => EntityType::SCorporation,
+            "Sole proprietor" => EntityType::SoleProprietor,
+            "Trusteeship" => EntityType::Trusteeship,
+            _ => EntityType::Unknown(s.to_string()),
+        })
     }
 }
 
-/**
- * The Gusto product tier of the company.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Tier {
-    #[serde(rename = "basic")]
-    Basic,
-    #[serde(rename = "complete")]
-    Complete,
-    #[serde(rename = "concierge")]
-    Concierge,
-    #[serde(rename = "contractor_only")]
-    ContractorOnly,
-    #[serde(rename = "core")]
-    Core,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Tier {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Tier::Basic => "basic",
-            Tier::Complete => "complete",
-            Tier::Concierge => "concierge",
-            Tier::ContractorOnly => "contractor_only",
-            Tier::Core => "core",
-            Tier::Noop => "",
-            Tier::FallthroughString => "*",
+impl std::convert::From<String> for EntityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
         }
-        .fmt(f)
     }
 }
 
-impl Default for Tier {
-    fn default() -> Tier {
-        Tier::Noop
-    }
-}
-impl Tier {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Tier::Noop)
+impl std::convert::From<EntityType> for String {
+    fn from(v: EntityType) -> Self {
+        match v {
+            EntityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1067,6 +1020,7 @@ impl Tier {
  * The status of the company in Gusto. "Approved" companies may run payroll with Gusto. "Not Approved" companies may not yet run payroll with Gusto. In order to run payroll, the company may need to complete onboarding or contact support. "Suspended" companies may not run payroll with Gusto. In order to unsuspend their account, the company must contact support.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CompanyStatus {
     #[serde(rename = "Approved")]
     Approved,
@@ -1074,33 +1028,57 @@ pub enum CompanyStatus {
     NotApproved,
     #[serde(rename = "Suspended")]
     Suspended,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for CompanyStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for CompanyStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             CompanyStatus::Approved => "Approved",
             CompanyStatus::NotApproved => "Not Approved",
             CompanyStatus::Suspended => "Suspended",
-            CompanyStatus::Noop => "",
-            CompanyStatus::FallthroughString => "*",
+            CompanyStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for CompanyStatus {
-    fn default() -> CompanyStatus {
-        CompanyStatus::Noop
+impl std::fmt::Display for CompanyStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CompanyStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Approved" => CompanyStatus::Approved,
+            "Not Approved" => CompanyStatus::NotApproved,
+            "Suspended" => CompanyStatus::Suspended,
+            _ => CompanyStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CompanyStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl CompanyStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, CompanyStatus::Noop)
+
+impl std::convert::From<CompanyStatus> for String {
+    fn from(v: CompanyStatus) -> Self {
+        match v {
+            CompanyStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1130,6 +1108,16 @@ pub struct Fixed {
     pub name: String,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CompanyCompensationsPaidTimeOff {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
 /// The available company-wide compensation rates for the company.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Compensations {
@@ -1159,7 +1147,7 @@ pub struct Compensations {
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub paid_time_off: Vec<Fixed>,
+    pub paid_time_off: Vec<CompanyCompensationsPaidTimeOff>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1298,176 +1286,129 @@ pub struct PrimaryPayrollAdmin {
     pub phone: String,
 }
 
-/// The representation of a company in Gusto.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Company {
-    /**
-     * The representation of a company in Gusto.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub company_status: Option<CompanyStatus>,
-    /**
-     * The representation of a company in Gusto.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub compensations: Option<Compensations>,
-    /**
-     * The representation of a company in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ein: String,
-    /**
-     * The representation of a company in Gusto.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub entity_type: Option<EntityType>,
-    /**
-     * The representation of a company in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub id: f64,
-    /**
-     * The representation of a company in Gusto.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_suspended: bool,
-    /**
-     * The representation of a company in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub locations: Vec<Location>,
-    /**
-     * The representation of a company in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * The representation of a company in Gusto.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub primary_payroll_admin: Option<PrimaryPayrollAdmin>,
-    /**
-     * The representation of a company in Gusto.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub primary_signatory: Option<PrimarySignatory>,
-    /**
-     * The Gusto product tier of the company.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub tier: Option<Tier>,
-    /**
-     * The representation of a company in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub trade_name: String,
-    /**
-     * The representation of a company in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub uuid: String,
-}
-
 /**
- * The contractor's wage type, either "Fixed" or "Hourly".
+ * The contractor’s wage type, either “Fixed” or “Hourly”.
+ *   
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WageType {
     #[serde(rename = "Fixed")]
     Fixed,
     #[serde(rename = "Hourly")]
     Hourly,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for WageType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for WageType {
+    fn as_ref(&self) -> &str {
+        match self {
             WageType::Fixed => "Fixed",
             WageType::Hourly => "Hourly",
-            WageType::Noop => "",
-            WageType::FallthroughString => "*",
+            WageType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for WageType {
-    fn default() -> WageType {
-        WageType::Noop
+impl std::fmt::Display for WageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for WageType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Fixed" => WageType::Fixed,
+            "Hourly" => WageType::Hourly,
+            _ => WageType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for WageType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl WageType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, WageType::Noop)
+
+impl std::convert::From<WageType> for String {
+    fn from(v: WageType) -> Self {
+        match v {
+            WageType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 /**
- * The contractor's type, either "Individual" or "Business".
+ * The contractor type, either an “Individual” or a “Business”.
+ *   
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "Business")]
     Business,
     #[serde(rename = "Individual")]
     Individual,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Type {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        match self {
             Type::Business => "Business",
             Type::Individual => "Individual",
-            Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Type {
-    fn default() -> Type {
-        Type::Noop
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Business" => Type::Business,
+            "Individual" => Type::Individual,
+            _ => Type::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Type {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Type::Noop)
+
+impl std::convert::From<Type> for String {
+    fn from(v: Type) -> Self {
+        match v {
+            Type::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1530,209 +1471,113 @@ pub struct Address {
     pub zip: String,
 }
 
-/// The representation of a contractor (individual or business) in Gusto.
+/**
+ * The payment method.
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Contractor {
+#[serde(from = "String", into = "String")]
+pub enum PaymentMethod {
+    #[serde(rename = "Check")]
+    Check,
+    #[serde(rename = "Correction Payment")]
+    CorrectionPayment,
+    #[serde(rename = "Direct Deposit")]
+    DirectDeposit,
+    #[serde(rename = "Historical Payment")]
+    HistoricalPayment,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub address: Option<Address>,
+    Unknown(String),
+}
+
+impl AsRef<str> for PaymentMethod {
+    fn as_ref(&self) -> &str {
+        match self {
+            PaymentMethod::Check => "Check",
+            PaymentMethod::CorrectionPayment => "Correction Payment",
+            PaymentMethod::DirectDeposit => "Direct Deposit",
+            PaymentMethod::HistoricalPayment => "Historical Payment",
+            PaymentMethod::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PaymentMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PaymentMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Check" => PaymentMethod::Check,
+            "Correction Payment" => PaymentMethod::CorrectionPayment,
+            "Direct Deposit" => PaymentMethod::DirectDeposit,
+            "Historical Payment" => PaymentMethod::HistoricalPayment,
+            _ => PaymentMethod::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PaymentMethod {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PaymentMethod> for String {
+    fn from(v: PaymentMethod) -> Self {
+        match v {
+            PaymentMethod::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// The representation of a single contractor payment.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContractorPayment {
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The representation of a single contractor payment.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub business_name: String,
+    pub bonus: String,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The representation of a single contractor payment.
      */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub company_id: f64,
+    pub contractor_id: f64,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
+     * The representation of a single contractor payment.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ein: String,
+    pub date: String,
+    /**
+     * The representation of a single contractor payment.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hourly_rate: Option<crate::types::DecimalString>,
     /**
-     * The representation of a contractor (individual or business) in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The representation of a contractor (individual or business) in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     * The representation of a contractor (individual or business) in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub hourly_rate: String,
-    /**
-     * The representation of a contractor (individual or business) in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub id: f64,
-    /**
-     * The representation of a contractor (individual or business) in Gusto.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_active: bool,
-    /**
-     * The representation of a contractor (individual or business) in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     * The representation of a contractor (individual or business) in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub middle_initial: String,
-    /**
-     * The representation of a contractor (individual or business) in Gusto.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<Type>,
-    /**
-     * The representation of a contractor (individual or business) in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-    /**
-     * The representation of a contractor (individual or business) in Gusto.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub wage_type: Option<WageType>,
-}
-
-/**
- * The payment method.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PaymentMethod {
-    #[serde(rename = "Check")]
-    Check,
-    #[serde(rename = "Correction Payment")]
-    CorrectionPayment,
-    #[serde(rename = "Direct Deposit")]
-    DirectDeposit,
-    #[serde(rename = "Historical Payment")]
-    HistoricalPayment,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for PaymentMethod {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PaymentMethod::Check => "Check",
-            PaymentMethod::CorrectionPayment => "Correction Payment",
-            PaymentMethod::DirectDeposit => "Direct Deposit",
-            PaymentMethod::HistoricalPayment => "Historical Payment",
-            PaymentMethod::Noop => "",
-            PaymentMethod::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for PaymentMethod {
-    fn default() -> PaymentMethod {
-        PaymentMethod::Noop
-    }
-}
-impl PaymentMethod {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PaymentMethod::Noop)
-    }
-}
-
-/// The representation of a single contractor payment.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContractorPayment {
-    /**
-     * The representation of a single contractor payment.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub bonus: String,
-    /**
-     * The representation of a single contractor payment.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub contractor_id: f64,
-    /**
-     * The representation of a single contractor payment.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub date: String,
-    /**
-     * The representation of a single contractor payment.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub hourly_rate: String,
-    /**
-     * The representation of a single contractor payment.
+     * The representation of a single contractor payment.
      */
     #[serde(
         default,
@@ -1766,21 +1611,13 @@ pub struct ContractorPayment {
     /**
      * The representation of a single contractor payment.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub wage: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wage: Option<crate::types::DecimalString>,
     /**
      * The representation of a single contractor payment.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub wage_total: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wage_total: Option<crate::types::DecimalString>,
     /**
      * The representation of a single contractor payment.
      */
@@ -1803,12 +1640,8 @@ pub struct Total {
     /**
      * The wage and reimbursement totals for all contractor payments within a given time period.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub wages: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wages: Option<crate::types::DecimalString>,
 }
 
 ///
@@ -1835,46 +1668,20 @@ pub struct ContractorPayments {
     /**
      *
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reimbursement_total: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reimbursement_total: Option<crate::types::DecimalString>,
     /**
      *
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub wage_total: String,
-}
-
-/// The representation of the summary of contractor payments for a given company in a given time period.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContractorPaymentSummary {
-    /**
-     * The representation of the summary of contractor payments for a given company in a given time period.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub contractor_payments: Vec<ContractorPayments>,
-    /**
-     * The representation of the summary of contractor payments for a given company in a given time period.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub total: Option<Total>,
+    pub wage_total: Option<crate::types::DecimalString>,
 }
 
 /**
  * The status of the time off request.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Status {
     #[serde(rename = "approved")]
     Approved,
@@ -1882,33 +1689,57 @@ pub enum Status {
     Denied,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Status {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        match self {
             Status::Approved => "approved",
             Status::Denied => "denied",
             Status::Pending => "pending",
-            Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Status {
-    fn default() -> Status {
-        Status::Noop
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approved" => Status::Approved,
+            "denied" => Status::Denied,
+            "pending" => Status::Pending,
+            _ => Status::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Status {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Status::Noop)
+
+impl std::convert::From<Status> for String {
+    fn from(v: Status) -> Self {
+        match v {
+            Status::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1916,37 +1747,61 @@ impl Status {
  * The type of time off request.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RequestType {
     #[serde(rename = "sick")]
     Sick,
     #[serde(rename = "vacation")]
     Vacation,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for RequestType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for RequestType {
+    fn as_ref(&self) -> &str {
+        match self {
             RequestType::Sick => "sick",
             RequestType::Vacation => "vacation",
-            RequestType::Noop => "",
-            RequestType::FallthroughString => "*",
+            RequestType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for RequestType {
-    fn default() -> RequestType {
-        RequestType::Noop
+impl std::fmt::Display for RequestType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for RequestType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "sick" => RequestType::Sick,
+            "vacation" => RequestType::Vacation,
+            _ => RequestType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RequestType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl RequestType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RequestType::Noop)
+
+impl std::convert::From<RequestType> for String {
+    fn from(v: RequestType) -> Self {
+        match v {
+            RequestType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1977,191 +1832,56 @@ pub struct TimeOffRequestEmployee {
     pub id: String,
 }
 
-///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Initiator {
-    /**
-     *
-     */
+pub struct Companies {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub full_name: String,
-    /**
-     *
-     */
+    pub id: f64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub id: String,
-}
-
-/// This value will be null if the request has not been approved.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Approver {
-    /**
-     * This value will be null if the request has not been approved.
-     */
+    pub locations: Vec<Location>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub full_name: String,
-    /**
-     * This value will be null if the request has not been approved.
-     */
+    pub name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub trade_name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PayrollAdmin {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub companies: Vec<Companies>,
 }
 
-/// The representation of a time off request.
+/// An object containing each of the user's permissions.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TimeOffRequest {
+pub struct Roles {
     /**
-     * This value will be null if the request has not been approved.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub approver: Option<Approver>,
-    /**
-     * The representation of a time off request.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub days: Option<Days>,
-    /**
-     * The representation of a time off request.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub employee: Option<TimeOffRequestEmployee>,
-    /**
-     * The representation of a time off request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employee_note: String,
-    /**
-     * The representation of a time off request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employer_note: String,
-    /**
-     * The representation of a time off request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub initiator: Option<Initiator>,
-    /**
-     * The representation of a time off request.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub request_type: Option<RequestType>,
-    /**
-     * The representation of a time off request.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<Status>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Companies {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub id: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub locations: Vec<Location>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub trade_name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PayrollAdmin {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub companies: Vec<Companies>,
-}
-
-/// An object containing each of the user's permissions.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Roles {
-    /**
-     * An object containing each of the user's permissions.
+     * An object containing each of the user's permissions.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub payroll_admin: Option<PayrollAdmin>,
 }
 
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CurrentUser {
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub id: f64,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub roles: Option<Roles>,
-}
-
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Frequency {
     #[serde(rename = "Every other week")]
     EveryOtherWeek,
@@ -2171,141 +1891,121 @@ pub enum Frequency {
     Monthly,
     #[serde(rename = "Twice per month")]
     TwicePerMonth,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Frequency {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Frequency {
+    fn as_ref(&self) -> &str {
+        match self {
             Frequency::EveryOtherWeek => "Every other week",
             Frequency::EveryWeek => "Every week",
             Frequency::Monthly => "Monthly",
             Frequency::TwicePerMonth => "Twice per month",
-            Frequency::Noop => "",
-            Frequency::FallthroughString => "*",
+            Frequency::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Frequency {
-    fn default() -> Frequency {
-        Frequency::Noop
+impl std::fmt::Display for Frequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Frequency {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Frequency::Noop)
+
+impl std::str::FromStr for Frequency {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Every other week" => Frequency::EveryOtherWeek,
+            "Every week" => Frequency::EveryWeek,
+            "Monthly" => Frequency::Monthly,
+            "Twice per month" => Frequency::TwicePerMonth,
+            _ => Frequency::Unknown(s.to_string()),
+        })
     }
 }
 
-/// The representation of a pay schedule.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PaySchedule {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub anchor_pay_date: Option<chrono::NaiveDate>,
-    /**
-     * The representation of a pay schedule.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub auto_pilot: bool,
-    /**
-     * The representation of a pay schedule.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub day_1: i64,
-    /**
-     * The representation of a pay schedule.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub day_2: i64,
-    /**
-     * The representation of a pay schedule.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<Frequency>,
-    /**
-     * The representation of a pay schedule.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * The representation of a pay schedule.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * The representation of a pay schedule.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub uuid: String,
+impl std::convert::From<String> for Frequency {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Frequency> for String {
+    fn from(v: Frequency) -> Self {
+        match v {
+            Frequency::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 /**
- * Bank account type
+ * The bank account type
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AccountType {
     #[serde(rename = "Checking")]
     Checking,
     #[serde(rename = "Savings")]
     Savings,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AccountType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AccountType {
+    fn as_ref(&self) -> &str {
+        match self {
             AccountType::Checking => "Checking",
             AccountType::Savings => "Savings",
-            AccountType::Noop => "",
-            AccountType::FallthroughString => "*",
+            AccountType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AccountType {
-    fn default() -> AccountType {
-        AccountType::Noop
+impl std::fmt::Display for AccountType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AccountType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Checking" => AccountType::Checking,
+            "Savings" => AccountType::Savings,
+            _ => AccountType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AccountType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AccountType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AccountType::Noop)
+
+impl std::convert::From<AccountType> for String {
+    fn from(v: AccountType) -> Self {
+        match v {
+            AccountType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2317,6 +2017,7 @@ impl AccountType {
  *   'verified' means the bank account is verified.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum VerificationStatus {
     #[serde(rename = "awaiting_deposits")]
     AwaitingDeposits,
@@ -2324,3044 +2025,376 @@ pub enum VerificationStatus {
     ReadyForVerification,
     #[serde(rename = "verified")]
     Verified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for VerificationStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for VerificationStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             VerificationStatus::AwaitingDeposits => "awaiting_deposits",
             VerificationStatus::ReadyForVerification => "ready_for_verification",
             VerificationStatus::Verified => "verified",
-            VerificationStatus::Noop => "",
-            VerificationStatus::FallthroughString => "*",
+            VerificationStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for VerificationStatus {
-    fn default() -> VerificationStatus {
-        VerificationStatus::Noop
+impl std::fmt::Display for VerificationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for VerificationStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "awaiting_deposits" => VerificationStatus::AwaitingDeposits,
+            "ready_for_verification" => VerificationStatus::ReadyForVerification,
+            "verified" => VerificationStatus::Verified,
+            _ => VerificationStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for VerificationStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl VerificationStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, VerificationStatus::Noop)
+
+impl std::convert::From<VerificationStatus> for String {
+    fn from(v: VerificationStatus) -> Self {
+        match v {
+            VerificationStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
-/// The company bank account
+///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CompanyBankAccount {
-    /**
-     * The company bank account
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub account_type: Option<AccountType>,
+pub struct EarningType {
     /**
-     * The company bank account
+     *
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub company_uuid: String,
+    pub name: String,
     /**
-     * The company bank account
+     *
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub hidden_account_number: String,
-    /**
-     * The company bank account
-     */
+    pub uuid: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EligibleEmployees {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub routing_number: String,
-    /**
-     * The company bank account
-     */
+    pub id: f64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub uuid: String,
-    /**
-     * The company bank account
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub verification_status: Option<VerificationStatus>,
+    pub job_ids: Vec<f64>,
 }
 
-///
+/// Information about the payroll for the pay period.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SupportedBenefit {
+pub struct PayPeriodPayroll {
     /**
-     *
+     * Information about the payroll for the pay period.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub description: String,
+    pub payroll_deadline: String,
     /**
-     *
+     * Information about the payroll for the pay period.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub healthcare: bool,
-    /**
-     *
-     */
+    pub processed: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PayrollPayPeriod {
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub id: f64,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub imputed: bool,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub posttax: bool,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub pretax: bool,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub retirement: bool,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub yearly_limit: bool,
-}
-
-/// The representation of a company benefit.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CompanyBenefit {
-    /**
-     * The representation of a company benefit.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub active: bool,
-    /**
-     * The representation of a company benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub benefit_id: f64,
-    /**
-     * The representation of a company benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub company_id: f64,
-    /**
-     * The representation of a company benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    /**
-     * The representation of a company benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub id: f64,
-    /**
-     * The representation of a company benefit.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "responsible_for_employee_w2"
-    )]
-    pub responsible_for_employee_w_2: bool,
-    /**
-     * The representation of a company benefit.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub responsible_for_employer_taxes: bool,
-    /**
-     * The representation of a company benefit.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub supports_percentage_amounts: bool,
-    /**
-     * The representation of a company benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EarningType {
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub uuid: String,
-}
-
-/**
- * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum DeductionReducesTaxableIncome {
-    #[serde(rename = "does_not_reduce_taxable_income")]
-    DoesNotReduceTaxableIncome,
-    #[serde(rename = "reduces_taxable_income")]
-    ReducesTaxableIncome,
-    #[serde(rename = "unset")]
-    Unset,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for DeductionReducesTaxableIncome {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            DeductionReducesTaxableIncome::DoesNotReduceTaxableIncome => {
-                "does_not_reduce_taxable_income"
-            }
-            DeductionReducesTaxableIncome::ReducesTaxableIncome => "reduces_taxable_income",
-            DeductionReducesTaxableIncome::Unset => "unset",
-            DeductionReducesTaxableIncome::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for DeductionReducesTaxableIncome {
-    fn default() -> DeductionReducesTaxableIncome {
-        DeductionReducesTaxableIncome::Unset
-    }
-}
-
-/// The representation of an employee benefit.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EmployeeBenefit {
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub active: bool,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub catch_up: bool,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub company_benefit_id: f64,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company_contribution: String,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company_contribution_annual_maximum: String,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub contribute_as_percentage: bool,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub coverage_amount: String,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub coverage_salary_multiplier: String,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub deduct_as_percentage: bool,
-    /**
-     * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deduction_reduces_taxable_income: Option<DeductionReducesTaxableIncome>,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employee_deduction: String,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employee_deduction_annual_maximum: String,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub employee_id: f64,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub id: f64,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub limit_option: String,
-    /**
-     * The representation of an employee benefit.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EligibleEmployees {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub id: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub job_ids: Vec<f64>,
-}
-
-/// Information about the payroll for the pay period.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Payroll {
-    /**
-     * Information about the payroll for the pay period.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub payroll_deadline: String,
-    /**
-     * Information about the payroll for the pay period.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub processed: bool,
-}
-
-/// The representation of a pay period.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PayPeriod {
-    /**
-     * The representation of a pay period.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub eligible_employees: Vec<EligibleEmployees>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub end_date: Option<chrono::NaiveDate>,
-    /**
-     * The representation of a pay period.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub pay_schedule_id: f64,
-    /**
-     * The representation of a pay period.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub pay_schedule_uuid: String,
-    /**
-     * The representation of a pay period.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub payroll: Option<Payroll>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub start_date: Option<chrono::NaiveDate>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PayrollPayPeriod {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub end_date: Option<chrono::NaiveDate>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub pay_schedule_id: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub pay_schedule_uuid: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub start_date: Option<chrono::NaiveDate>,
-}
-
-/// The subtotals for the payroll.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Totals {
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub additional_earnings: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub benefits: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub check_amount: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub child_support_debit: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company_debit: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub deferred_payroll_taxes: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employee_benefits_deductions: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employee_bonuses: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employee_cash_tips: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employee_commissions: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employee_paycheck_tips: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employee_taxes: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employer_taxes: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub gross_pay: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub net_pay: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub net_pay_debit: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub owners_draw: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reimbursement_debit: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reimbursements: String,
-    /**
-     * The subtotals for the payroll.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub tax_debit: String,
-}
-
-/**
- * The employee's compensation payment method. This value is only available for processed payrolls.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PayrollEmployeeCompensationsPaymentMethod {
-    #[serde(rename = "Check")]
-    Check,
-    #[serde(rename = "Direct Deposit")]
-    DirectDeposit,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for PayrollEmployeeCompensationsPaymentMethod {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PayrollEmployeeCompensationsPaymentMethod::Check => "Check",
-            PayrollEmployeeCompensationsPaymentMethod::DirectDeposit => "Direct Deposit",
-            PayrollEmployeeCompensationsPaymentMethod::Noop => "",
-            PayrollEmployeeCompensationsPaymentMethod::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for PayrollEmployeeCompensationsPaymentMethod {
-    fn default() -> PayrollEmployeeCompensationsPaymentMethod {
-        PayrollEmployeeCompensationsPaymentMethod::Noop
-    }
-}
-impl PayrollEmployeeCompensationsPaymentMethod {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PayrollEmployeeCompensationsPaymentMethod::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FixedCompensations {
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub amount: String,
-    /**
-     * The ID of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub job_id: f64,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct HourlyCompensations {
-    /**
-     * The ID of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub compensation_multiplier: f64,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub hours: String,
-    /**
-     * The ID of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub job_id: f64,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PayrollEmployeeCompensationsPaidTimeOff {
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub hours: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Benefits {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company_contribution: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub employee_deduction: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub imputed: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Deductions {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub amount: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Taxes {
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub amount: String,
-    /**
-     * Whether the employee is terminated.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub employer: bool,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EmployeeCompensations {
-    /**
-     * An array of employee benefits for the pay period. Benefits are only included for processed payroll when the include parameter is present.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub benefits: Vec<Benefits>,
-    /**
-     * An array of employee deductions for the pay period. Deductions are only included for processed payroll when the include parameter is present.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub deductions: Vec<Deductions>,
-    /**
-     * The ID of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub employee_id: f64,
-    /**
-     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements. If this payroll has been procesed, only fixed compensations with a value greater than 0.00 are returned. For an unprocess payroll, all active fixed compensations are returned.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub fixed_compensations: Vec<FixedCompensations>,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub gross_pay: String,
-    /**
-     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours. If this payroll has been procesed, only hourly compensations with a value greater than 0.00 are returned. For an unprocess payroll, all active hourly compensations are returned.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub hourly_compensations: Vec<HourlyCompensations>,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub net_pay: String,
-    /**
-     * An array of all paid time off the employee is eligible for this pay period.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub paid_time_off: Vec<PayrollEmployeeCompensationsPaidTimeOff>,
-    /**
-     * The employee's compensation payment method. This value is only available for processed payrolls.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub payment_method: Option<PayrollEmployeeCompensationsPaymentMethod>,
-    /**
-     * An array of employer and employee taxes for the pay period. Taxes are only included for processed payroll when the include parameter is present.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub taxes: Vec<Taxes>,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PayrollData {
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub calculated_at: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub check_date: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub company_id: f64,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company_uuid: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub employee_compensations: Vec<EmployeeCompensations>,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub pay_period: Option<PayrollPayPeriod>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub payroll_deadline: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub payroll_id: f64,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub payroll_uuid: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub processed: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub processed_date: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub totals: Option<Totals>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum CustomFieldType {
-    #[serde(rename = "currency")]
-    Currency,
-    #[serde(rename = "date")]
-    Date,
-    #[serde(rename = "number")]
-    Number,
-    #[serde(rename = "radio")]
-    Radio,
-    #[serde(rename = "text")]
-    Text,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for CustomFieldType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            CustomFieldType::Currency => "currency",
-            CustomFieldType::Date => "date",
-            CustomFieldType::Number => "number",
-            CustomFieldType::Radio => "radio",
-            CustomFieldType::Text => "text",
-            CustomFieldType::Noop => "",
-            CustomFieldType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for CustomFieldType {
-    fn default() -> CustomFieldType {
-        CustomFieldType::Noop
-    }
-}
-impl CustomFieldType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, CustomFieldType::Noop)
-    }
-}
-
-/// A custom field of an employee
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EmployeeCustomField {
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company_custom_field_id: String,
-    /**
-     * A custom field of an employee
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * A custom field of an employee
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub selection_options: Vec<String>,
-    #[serde(rename = "type")]
-    pub type_: CustomFieldType,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
-}
-
-/// A custom field on a company
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CompanyCustomField {
-    /**
-     * A custom field on a company
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * A custom field on a company
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub selection_options: Vec<String>,
-    #[serde(rename = "type")]
-    pub type_: CustomFieldType,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum GustoPersonType {
-    #[serde(rename = "Candidate")]
-    Candidate,
-    #[serde(rename = "Contractor")]
-    Contractor,
-    #[serde(rename = "Employee")]
-    Employee,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for GustoPersonType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            GustoPersonType::Candidate => "Candidate",
-            GustoPersonType::Contractor => "Contractor",
-            GustoPersonType::Employee => "Employee",
-            GustoPersonType::Noop => "",
-            GustoPersonType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for GustoPersonType {
-    fn default() -> GustoPersonType {
-        GustoPersonType::Noop
-    }
-}
-impl GustoPersonType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GustoPersonType::Noop)
-    }
-}
-
-/// The representation of a job applicant in Gusto.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct JobApplicant {
-    /**
-     * The representation of a job applicant in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub company_id: f64,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company_uuid: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub date_of_birth: Option<chrono::NaiveDate>,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub gusto_person_id: f64,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub gusto_person_type: Option<GustoPersonType>,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub gusto_person_uuid: String,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub job_title: String,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub start_date: Option<chrono::NaiveDate>,
-    /**
-     * The representation of a job applicant in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub uuid: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FederalTaxDetails {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ein: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub ein_verified: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub filing_form: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub legal_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub tax_payer_type: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub taxable_as_scorp: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest {
-    /**
-     * The ID of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub benefit_id: f64,
-    /**
-     * The ID of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub tax_year: f64,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ytd_company_contribution_amount: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ytd_employee_deduction_amount: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EarningTypeListResponse {
-    /**
-     * The default earning types for the company.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub custom: Vec<EarningType>,
-    /**
-     * The default earning types for the company.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub default: Vec<EarningType>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Include {
-    #[serde(rename = "custom_fields")]
-    CustomFields,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Include {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Include::CustomFields => "custom_fields",
-            Include::Noop => "",
-            Include::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Include {
-    fn default() -> Include {
-        Include::Noop
-    }
-}
-impl Include {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Include::Noop)
-    }
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutEmployeesRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub date_of_birth: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub middle_initial: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ssn: String,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub two_percent_shareholder: Option<bool>,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostEmployeesRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub date_of_birth: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub middle_initial: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ssn: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutJobRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub hire_date: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub location_id: f64,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostJobRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub hire_date: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub location_id: f64,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostCompanyLocationsRequest {
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub filing_address: Option<bool>,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub mailing_address: Option<bool>,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone_number: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub street_1: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub street_2: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zip: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutLocationRequest {
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub filing_address: Option<bool>,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub mailing_address: Option<bool>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone_number: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub street_1: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub street_2: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zip: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutComntractorRequest {
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub business_name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ein: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub hourly_rate: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub middle_initial: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub start_date: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub wage_type: Option<WageType>,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostCompanyContractorsRequest {
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub business_name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ein: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub middle_initial: String,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub self_onboarding: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub start_date: Option<chrono::NaiveDate>,
-    /**
-     * The contractor's type, either "Individual" or "Business".
-     */
-    #[serde(default, skip_serializing_if = "Type::is_noop", rename = "type")]
-    pub type_: Type,
-    /**
-     * The contractor's wage type, either "Fixed" or "Hourly".
-     */
-    #[serde(default, skip_serializing_if = "WageType::is_noop")]
-    pub wage_type: WageType,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutCompensationRequest {
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub flsa_status: Option<FlsaStatus>,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub payment_unit: Option<PaymentUnit>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub rate: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostJobCompensationsRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub effective_date: Option<chrono::NaiveDate>,
-    /**
-     * The FLSA status for this compensation. Salaried ('Exempt') employees are paid a fixed salary every pay period. Salaried with overtime ('Salaried Nonexempt') employees are paid a fixed salary every pay period, and receive overtime pay when applicable. Hourly ('Nonexempt') employees are paid for the hours they work, and receive overtime pay when applicable. Owners ('Owner') are employees that own at least twenty percent of the company.
-     */
-    #[serde(default, skip_serializing_if = "FlsaStatus::is_noop")]
-    pub flsa_status: FlsaStatus,
-    /**
-     * The unit accompanying the compensation rate. If the employee is an owner, rate should be 'Paycheck'.
-     */
-    #[serde(default, skip_serializing_if = "PaymentUnit::is_noop")]
-    pub payment_unit: PaymentUnit,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub rate: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostEmployeeGarnishmentsRequest {
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub active: Option<bool>,
-    /**
-     * The ID of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub amount: f64,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub annual_maximum: f64,
-    /**
-     * Whether the employee is terminated.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub court_ordered: Option<bool>,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deduct_as_percentage: Option<bool>,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub pay_period_maximum: f64,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub recurring: Option<bool>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub times: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutGarnishmentRequest {
-    /**
-     * Whether the employee is terminated.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub active: Option<bool>,
-    /**
-     * The ID of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub amount: f64,
-    /**
-     * The ID of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub annual_maximum: f64,
-    /**
-     * Whether the employee is terminated.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub court_ordered: Option<bool>,
-    /**
-     * Whether the employee is terminated.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deduct_as_percentage: Option<bool>,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    /**
-     * The ID of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub pay_period_maximum: f64,
-    /**
-     * Whether the employee is terminated.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub recurring: Option<bool>,
-    /**
-     * The unique identifier of the location in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub times: i64,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostEmployeeTerminationsRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub effective_date: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub run_termination_payroll: Option<bool>,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutEmployeeHomeAddressRequest {
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub street_1: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub street_2: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zip: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutCompanyPaySchedulesScheduleRequest {
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub auto_pilot: Option<bool>,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostCompanyBankAccountsRequest {
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub account_number: String,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub account_type: Option<AccountType>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub routing_number: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutCompanyBankAccountsVerifyRequest {
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub deposit_1: f64,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub deposit_2: f64,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostCompanyBenefitsRequest {
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub active: Option<bool>,
-    /**
-     * The ID of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub benefit_id: f64,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "responsible_for_employee_w2"
-    )]
-    pub responsible_for_employee_w_2: Option<bool>,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub responsible_for_employer_taxes: Option<bool>,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutCompanyBenefitRequest {
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub active: Option<bool>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostCompanyEarningTypesRequest {
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutCompanyEarningTypeRequest {
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostEmployeeBenefitsRequest {
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub active: Option<bool>,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catch_up: Option<bool>,
-    /**
-     * The ID of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub company_benefit_id: f64,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company_contribution: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company_contribution_annual_maximum: String,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub contribute_as_percentage: Option<bool>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub coverage_amount: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
     )]
-    pub coverage_salary_multiplier: String,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deduct_as_percentage: Option<bool>,
-    /**
-     * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deduction_reduces_taxable_income: Option<DeductionReducesTaxableIncome>,
-    /**
-     *
-     */
+    pub end_date: Option<chrono::NaiveDate>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub employee_deduction: String,
-    /**
-     *
-     */
+    pub pay_schedule_id: f64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub employee_deduction_annual_maximum: String,
-    /**
-     *
-     */
+    pub pay_schedule_uuid: String,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
     )]
-    pub limit_option: String,
+    pub start_date: Option<chrono::NaiveDate>,
 }
 
+/// The subtotals for the payroll.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutEmployeeBenefitRequest {
-    /**
-     * Whether the employee is terminated.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub active: Option<bool>,
-    /**
-     * Whether the employee is terminated.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub catch_up: Option<bool>,
+pub struct Totals {
     /**
-     * A unique identifier of the employee in Gusto.
+     * The subtotals for the payroll.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub company_contribution: String,
+    pub additional_earnings: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The subtotals for the payroll.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub company_contribution_annual_maximum: String,
+    pub benefits: String,
     /**
-     * Whether the employee is terminated.
+     * The subtotals for the payroll.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub contribute_as_percentage: Option<bool>,
+    pub check_amount: Option<crate::types::DecimalString>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The subtotals for the payroll.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub coverage_amount: String,
+    pub child_support_debit: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The subtotals for the payroll.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub coverage_salary_multiplier: String,
+    pub company_debit: String,
     /**
-     * Whether the employee is terminated.
+     * The subtotals for the payroll.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deduct_as_percentage: Option<bool>,
+    pub deferred_payroll_taxes: Option<crate::types::DecimalString>,
     /**
-     * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
+     * The subtotals for the payroll.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deduction_reduces_taxable_income: Option<DeductionReducesTaxableIncome>,
+    pub employee_benefits_deductions: Option<crate::types::DecimalString>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The subtotals for the payroll.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub employee_deduction: String,
+    pub employee_bonuses: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The subtotals for the payroll.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub employee_deduction_annual_maximum: String,
+    pub employee_cash_tips: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The subtotals for the payroll.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub limit_option: String,
+    pub employee_commissions: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The subtotals for the payroll.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub version: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum GetCompanyPayrollsInclude {
-    #[serde(rename = "benefits")]
-    Benefits,
-    #[serde(rename = "deductions")]
-    Deductions,
-    #[serde(rename = "taxes")]
-    Taxes,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for GetCompanyPayrollsInclude {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            GetCompanyPayrollsInclude::Benefits => "benefits",
-            GetCompanyPayrollsInclude::Deductions => "deductions",
-            GetCompanyPayrollsInclude::Taxes => "taxes",
-            GetCompanyPayrollsInclude::Noop => "",
-            GetCompanyPayrollsInclude::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for GetCompanyPayrollsInclude {
-    fn default() -> GetCompanyPayrollsInclude {
-        GetCompanyPayrollsInclude::Noop
-    }
-}
-impl GetCompanyPayrollsInclude {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetCompanyPayrollsInclude::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OffCycleReason {
-    #[serde(rename = "Bonus")]
-    Bonus,
-    #[serde(rename = "Correction")]
-    Correction,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for OffCycleReason {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OffCycleReason::Bonus => "Bonus",
-            OffCycleReason::Correction => "Correction",
-            OffCycleReason::Noop => "",
-            OffCycleReason::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for OffCycleReason {
-    fn default() -> OffCycleReason {
-        OffCycleReason::Noop
-    }
-}
-impl OffCycleReason {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OffCycleReason::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostCompanyPayrollsRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub check_date: Option<chrono::NaiveDate>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub employee_ids: Vec<i64>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub end_date: Option<chrono::NaiveDate>,
+    pub employee_paycheck_tips: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The subtotals for the payroll.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub off_cycle: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub off_cycle_reason: Option<OffCycleReason>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub start_date: Option<chrono::NaiveDate>,
-}
-
-/// An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutCompanyPayrollsRequestEmployeeCompensationsFixed {
-    /**
-     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub amount: String,
-    /**
-     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub job_id: i64,
-    /**
-     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-/// An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutCompanyPayrollsRequestEmployeeCompensationsHourly {
-    /**
-     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub hours: String,
-    /**
-     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub job_id: i64,
-    /**
-     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutCompanyPayrollsRequestEmployeeCompensations {
-    /**
-     * The unique identifier of the location in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub employee_id: i64,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub fixed_compensations: Vec<PutCompanyPayrollsRequestEmployeeCompensationsFixed>,
+    pub employee_taxes: Option<crate::types::DecimalString>,
     /**
-     *
+     * The subtotals for the payroll.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub hourly_compensations: Vec<PutCompanyPayrollsRequestEmployeeCompensationsHourly>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub employer_taxes: Option<crate::types::DecimalString>,
     /**
-     *
+     * The subtotals for the payroll.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub paid_time_off: Vec<PayrollEmployeeCompensationsPaidTimeOff>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutCompanyPayrollsRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub employee_compensations: Vec<PutCompanyPayrollsRequestEmployeeCompensations>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gross_pay: Option<crate::types::DecimalString>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The subtotals for the payroll.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-}
-
-/// Information for the user who will be the primary payroll administrator for the new company.
-#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct User {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub net_pay: Option<crate::types::DecimalString>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The subtotals for the payroll.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub net_pay_debit: Option<crate::types::DecimalString>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The subtotals for the payroll.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub first_name: String,
+    pub owners_draw: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The subtotals for the payroll.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_name: String,
+    pub reimbursement_debit: String,
     /**
-     * Information for the user who will be the primary payroll administrator for the new company.
+     * The subtotals for the payroll.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub phone: String,
+    pub reimbursements: String,
+    /**
+     * The subtotals for the payroll.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_debit: Option<crate::types::DecimalString>,
 }
 
+/**
+ * The employee's compensation payment method. This value is only available for processed payrolls.
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostPartnerManagedCompaniesRequestCompany {
+#[serde(from = "String", into = "String")]
+pub enum PayrollEmployeeCompensationsPaymentMethod {
+    #[serde(rename = "Check")]
+    Check,
+    #[serde(rename = "Direct Deposit")]
+    DirectDeposit,
     /**
-     * A unique identifier of the employee in Gusto.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ein: String,
+    Unknown(String),
+}
+
+impl AsRef<str> for PayrollEmployeeCompensationsPaymentMethod {
+    fn as_ref(&self) -> &str {
+        match self {
+            PayrollEmployeeCompensationsPaymentMethod::Check => "Check",
+            PayrollEmployeeCompensationsPaymentMethod::DirectDeposit => "Direct Deposit",
+            PayrollEmployeeCompensationsPaymentMethod::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PayrollEmployeeCompensationsPaymentMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PayrollEmployeeCompensationsPaymentMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Check" => PayrollEmployeeCompensationsPaymentMethod::Check,
+            "Direct Deposit" => PayrollEmployeeCompensationsPaymentMethod::DirectDeposit,
+            _ => PayrollEmployeeCompensationsPaymentMethod::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PayrollEmployeeCompensationsPaymentMethod {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PayrollEmployeeCompensationsPaymentMethod> for String {
+    fn from(v: PayrollEmployeeCompensationsPaymentMethod) -> Self {
+        match v {
+            PayrollEmployeeCompensationsPaymentMethod::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FixedCompensations {
     /**
-     * A unique identifier of the employee in Gusto.
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<crate::types::DecimalString>,
+    /**
+     * The ID of the employee in Gusto.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub name: String,
+    pub job_id: f64,
     /**
      * A unique identifier of the employee in Gusto.
      */
@@ -5370,55 +2403,51 @@ pub struct PostPartnerManagedCompaniesRequestCompany {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub trade_name: String,
+    pub name: String,
 }
 
-///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostPartnerManagedCompaniesRequest {
-    #[serde()]
-    pub company: PostPartnerManagedCompaniesRequestCompany,
+pub struct HourlyCompensations {
     /**
-     * Information for the user who will be the primary payroll administrator for the new company.
+     * The ID of the employee in Gusto.
      */
-    #[serde()]
-    pub user: User,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostPartnerManagedCompaniesResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub compensation_multiplier: f64,
     /**
-     *
+     * A unique identifier of the employee in Gusto.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub access_token: String,
+    pub hours: String,
     /**
-     *
+     * The ID of the employee in Gusto.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub company_uuid: String,
+    pub job_id: f64,
     /**
-     *
+     * A unique identifier of the employee in Gusto.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub refresh_token: String,
+    pub name: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Addresses {
+pub struct PayrollEmployeeCompensationsPaidTimeOff {
     /**
      * A unique identifier of the employee in Gusto.
      */
@@ -5427,7 +2456,7 @@ pub struct Addresses {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub city: String,
+    pub hours: String,
     /**
      * A unique identifier of the employee in Gusto.
      */
@@ -5436,43 +2465,62 @@ pub struct Addresses {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub is_primary: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Benefits {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub phone: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
+    pub company_contribution: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub employee_deduction: Option<crate::types::DecimalString>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub imputed: bool,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub state: String,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Deductions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<crate::types::DecimalString>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub street_1: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Taxes {
     /**
-     * A unique identifier of the employee in Gusto.
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_decimal::deserialize"
     )]
-    pub street_2: String,
+    pub amount: crate::types::DecimalString,
+    /**
+     * Whether the employee is terminated.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub employer: bool,
     /**
      * A unique identifier of the employee in Gusto.
      */
@@ -5481,38 +2529,29 @@ pub struct Addresses {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub zip: String,
+    pub name: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostProvisionRequestCompany {
+pub struct EmployeeCompensations {
     /**
-     * The locations for the company. This includes mailing, work, and filing addresses.
+     * An array of employee benefits for the pay period. Benefits are only included for processed payroll when the include parameter is present.
      */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub addresses: Vec<Addresses>,
-    /**
-     * A unique identifier of the employee in Gusto.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ein: String,
+    pub benefits: Vec<Benefits>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * An array of employee deductions for the pay period. Deductions are only included for processed payroll when the include parameter is present.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub name: String,
+    pub deductions: Vec<Deductions>,
     /**
      * The ID of the employee in Gusto.
      */
@@ -5521,123 +2560,134 @@ pub struct PostProvisionRequestCompany {
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub number_employees: f64,
+    pub employee_id: f64,
     /**
-     * An array of options for fields of type radio. Otherwise, null.
+     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements. If this payroll has been procesed, only fixed compensations with a value greater than 0.00 are returned. For an unprocess payroll, all active fixed compensations are returned.
      */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub states: Vec<String>,
+    pub fixed_compensations: Vec<FixedCompensations>,
     /**
-     * A unique identifier of the employee in Gusto.
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gross_pay: Option<crate::types::DecimalString>,
+    /**
+     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours. If this payroll has been procesed, only hourly compensations with a value greater than 0.00 are returned. For an unprocess payroll, all active hourly compensations are returned.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub trade_name: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostProvisionRequest {
-    #[serde()]
-    pub company: PostProvisionRequestCompany,
+    pub hourly_compensations: Vec<HourlyCompensations>,
     /**
-     * Information for the user who will be the primary payroll administrator for the new company.
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
      */
-    #[serde()]
-    pub user: User,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostProvisionResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub net_pay: Option<crate::types::DecimalString>,
     /**
-     *
+     * An array of all paid time off the employee is eligible for this pay period.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub account_claim_url: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetEmployeeCustomFieldsResponse {
+    pub paid_time_off: Vec<PayrollEmployeeCompensationsPaidTimeOff>,
+    /**
+     * The employee's compensation payment method. This value is only available for processed payrolls.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<PayrollEmployeeCompensationsPaymentMethod>,
     /**
-     * Custom fields are only included for the employee if the include param has the custom_fields value set
+     * An array of employer and employee taxes for the pay period. Taxes are only included for processed payroll when the include parameter is present.
      */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub custom_fields: Vec<EmployeeCustomField>,
+    pub taxes: Vec<Taxes>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetCompanyCustomFieldsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub custom_fields: Vec<CompanyCustomField>,
+#[serde(from = "String", into = "String")]
+pub enum CustomFieldType {
+    #[serde(rename = "currency")]
+    Currency,
+    #[serde(rename = "date")]
+    Date,
+    #[serde(rename = "number")]
+    Number,
+    #[serde(rename = "radio")]
+    Radio,
+    #[serde(rename = "text")]
+    Text,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-/**
- * Must be "Employee" if send_offer is set to true.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OnboardingPersonType {
-    #[serde(rename = "Contractor")]
-    Contractor,
-    #[serde(rename = "Employee")]
-    Employee,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for OnboardingPersonType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OnboardingPersonType::Contractor => "Contractor",
-            OnboardingPersonType::Employee => "Employee",
-            OnboardingPersonType::Noop => "",
-            OnboardingPersonType::FallthroughString => "*",
+impl AsRef<str> for CustomFieldType {
+    fn as_ref(&self) -> &str {
+        match self {
+            CustomFieldType::Currency => "currency",
+            CustomFieldType::Date => "date",
+            CustomFieldType::Number => "number",
+            CustomFieldType::Radio => "radio",
+            CustomFieldType::Text => "text",
+            CustomFieldType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for OnboardingPersonType {
-    fn default() -> OnboardingPersonType {
-        OnboardingPersonType::Noop
+impl std::fmt::Display for CustomFieldType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CustomFieldType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "currency" => CustomFieldType::Currency,
+            "date" => CustomFieldType::Date,
+            "number" => CustomFieldType::Number,
+            "radio" => CustomFieldType::Radio,
+            "text" => CustomFieldType::Text,
+            _ => CustomFieldType::Unknown(s.to_string()),
+        })
     }
 }
-impl OnboardingPersonType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OnboardingPersonType::Noop)
+
+impl std::convert::From<String> for CustomFieldType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
 
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostCompanyJobApplicantsRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub date_of_birth: Option<chrono::NaiveDate>,
+impl std::convert::From<CustomFieldType> for String {
+    fn from(v: CustomFieldType) -> Self {
+        match v {
+            CustomFieldType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// A custom field of an employee
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EmployeeCustomField {
     /**
      * A unique identifier of the employee in Gusto.
      */
@@ -5646,25 +2696,25 @@ pub struct PostCompanyJobApplicantsRequest {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub email: String,
+    pub company_custom_field_id: String,
     /**
-     * A unique identifier of the employee in Gusto.
+     * A custom field of an employee
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub first_name: String,
+    pub description: String,
     /**
-     *
+     * A unique identifier of the employee in Gusto.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub job_title: String,
+    pub id: String,
     /**
      * A unique identifier of the employee in Gusto.
      */
@@ -5673,191 +2723,235 @@ pub struct PostCompanyJobApplicantsRequest {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_name: String,
-    /**
-     * Must be "Employee" if send_offer is set to true.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub onboarding_person_type: Option<OnboardingPersonType>,
+    pub name: String,
     /**
-     *
+     * A custom field of an employee
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub phone: String,
+    pub selection_options: Vec<String>,
+    #[serde(rename = "type")]
+    pub type_: CustomFieldType,
     /**
-     *
+     * A unique identifier of the employee in Gusto.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub send_offer: Option<bool>,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub start_date: Option<chrono::NaiveDate>,
+    pub value: String,
 }
 
-///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutCompanyJobApplicantRequest {
+pub struct FederalTaxDetails {
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub date_of_birth: Option<chrono::NaiveDate>,
-    /**
-     *
-     */
+    pub ein: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ein_verified: bool,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub email: String,
-    /**
-     *
-     */
+    pub filing_form: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub first_name: String,
-    /**
-     *
-     */
+    pub legal_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub job_title: String,
-    /**
-     *
-     */
+    pub tax_payer_type: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_name: String,
-    /**
-     * Must be "Employee" if send_offer is set to true.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub onboarding_person_type: Option<OnboardingPersonType>,
-    /**
-     *
-     */
+    pub taxable_as_scorp: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub phone: String,
+    pub version: String,
+}
+
+/// An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutCompanyPayrollsRequestEmployeeCompensationsFixed {
     /**
-     *
+     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub send_offer: Option<bool>,
+    pub amount: Option<crate::types::DecimalString>,
+    /**
+     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
+     */
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub start_date: Option<chrono::NaiveDate>,
+    pub job_id: i64,
+    /**
+     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
+/// An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetCompanyPayrollReversalsResponse {
+pub struct PutCompanyPayrollsRequestEmployeeCompensationsHourly {
     /**
-     * A unique identifier of the employee in Gusto.
+     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hours: Option<String>,
+    /**
+     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub approved_at: String,
+    pub job_id: i64,
+    /**
+     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutCompanyPayrollsRequestEmployeeCompensationsPaidTimeOff {
     /**
      * A unique identifier of the employee in Gusto.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub category: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hours: Option<String>,
     /**
      * A unique identifier of the employee in Gusto.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutCompanyPayrollsPayPeriodStartDateEndRequestEmployeeCompensationsFixed {
+    /**
+     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<crate::types::DecimalString>,
     /**
-     * The unique identifier of the location in Gusto.
+     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
      */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub reversal_payroll_id: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub reversed_employee_ids: Vec<i64>,
+    pub job_id: i64,
+    /**
+     * An array of fixed compensations for the employee. Fixed compensations include tips, bonuses, and one time reimbursements.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutCompanyPayrollsPayPeriodStartDateEndRequestEmployeeCompensationsHourly {
+    /**
+     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hours: Option<String>,
     /**
-     * The unique identifier of the location in Gusto.
+     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
      */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub reversed_payroll_id: i64,
+    pub job_id: i64,
+    /**
+     * An array of hourly compensations for the employee. Hourly compensations include regular, overtime, and double overtime hours.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
-///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostCompanyAdminsRequest {
+pub struct PutCompanyPayrollsPayPeriodStartDateEndRequestEmployeeCompensationsPaidTimeOff {
     /**
      * A unique identifier of the employee in Gusto.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hours: Option<String>,
     /**
      * A unique identifier of the employee in Gusto.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Addresses {
     /**
      * A unique identifier of the employee in Gusto.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_primary: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub street_1: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub street_2: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -5927,6 +3021,43 @@ pub struct GetCompanyFederalTaxDetailsResponse {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct PutCompanyFederalTaxDetailsRequest {
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ein: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filing_form: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legal_name: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax_payer_type: Option<String>,
+    /**
+     * Whether the employee is terminated.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub taxable_as_scorp: Option<bool>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutCompanyFederalTaxDetailsResponse {
     /**
      * A unique identifier of the employee in Gusto.
      */
@@ -5936,6 +3067,14 @@ pub struct PutCompanyFederalTaxDetailsRequest {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub ein: String,
+    /**
+     * Whether the employee is terminated.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ein_verified: bool,
     /**
      * A unique identifier of the employee in Gusto.
      */
@@ -5966,8 +3105,11 @@ pub struct PutCompanyFederalTaxDetailsRequest {
     /**
      * Whether the employee is terminated.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub taxable_as_scorp: Option<bool>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub taxable_as_scorp: bool,
     /**
      * A unique identifier of the employee in Gusto.
      */
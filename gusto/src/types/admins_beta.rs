@@ -0,0 +1,66 @@
+//! The data types of the `admins_beta` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// The representation of an admin user in Gusto.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Admin {
+    /**
+     * The representation of an admin user in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    /**
+     * The representation of an admin user in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub first_name: String,
+    /**
+     * The representation of an admin user in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub last_name: String,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostCompanyAdminsRequest {
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub first_name: String,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub last_name: String,
+}
@@ -0,0 +1,621 @@
+//! The data types of the `benefits` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SupportedBenefit {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub healthcare: bool,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub id: f64,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub imputed: bool,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub posttax: bool,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub pretax: bool,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub retirement: bool,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub yearly_limit: bool,
+}
+
+/// The representation of a company benefit.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CompanyBenefit {
+    /**
+     * The representation of a company benefit.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub active: bool,
+    /**
+     * The representation of a company benefit.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub benefit_id: f64,
+    /**
+     * The representation of a company benefit.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub company_id: f64,
+    /**
+     * The representation of a company benefit.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     * The representation of a company benefit.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub id: f64,
+    /**
+     * The representation of a company benefit.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "responsible_for_employee_w2"
+    )]
+    pub responsible_for_employee_w_2: bool,
+    /**
+     * The representation of a company benefit.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub responsible_for_employer_taxes: bool,
+    /**
+     * The representation of a company benefit.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub supports_percentage_amounts: bool,
+    /**
+     * The representation of a company benefit.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+}
+
+/**
+ * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum DeductionReducesTaxableIncome {
+    #[serde(rename = "does_not_reduce_taxable_income")]
+    DoesNotReduceTaxableIncome,
+    #[serde(rename = "reduces_taxable_income")]
+    ReducesTaxableIncome,
+    #[serde(rename = "unset")]
+    Unset,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for DeductionReducesTaxableIncome {
+    fn as_ref(&self) -> &str {
+        match self {
+            DeductionReducesTaxableIncome::DoesNotReduceTaxableIncome => {
+                "does_not_reduce_taxable_income"
+            }
+            DeductionReducesTaxableIncome::ReducesTaxableIncome => "reduces_taxable_income",
+            DeductionReducesTaxableIncome::Unset => "unset",
+            DeductionReducesTaxableIncome::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DeductionReducesTaxableIncome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DeductionReducesTaxableIncome {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "does_not_reduce_taxable_income" => {
+                DeductionReducesTaxableIncome::DoesNotReduceTaxableIncome
+            }
+            "reduces_taxable_income" => DeductionReducesTaxableIncome::ReducesTaxableIncome,
+            "unset" => DeductionReducesTaxableIncome::Unset,
+            _ => DeductionReducesTaxableIncome::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DeductionReducesTaxableIncome {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DeductionReducesTaxableIncome> for String {
+    fn from(v: DeductionReducesTaxableIncome) -> Self {
+        match v {
+            DeductionReducesTaxableIncome::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for DeductionReducesTaxableIncome {
+    fn default() -> DeductionReducesTaxableIncome {
+        DeductionReducesTaxableIncome::Unset
+    }
+}
+
+/// The representation of an employee benefit.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EmployeeBenefit {
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub active: bool,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub catch_up: bool,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub company_benefit_id: f64,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub company_contribution: String,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub company_contribution_annual_maximum: String,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub contribute_as_percentage: bool,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coverage_amount: Option<crate::types::DecimalString>,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub coverage_salary_multiplier: String,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub deduct_as_percentage: bool,
+    /**
+     * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deduction_reduces_taxable_income: Option<DeductionReducesTaxableIncome>,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub employee_deduction: Option<crate::types::DecimalString>,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub employee_deduction_annual_maximum: Option<crate::types::DecimalString>,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub employee_id: f64,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub id: f64,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub limit_option: String,
+    /**
+     * The representation of an employee benefit.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostEmployeeYtdBenefitAmountsFromDifferentCompanyRequest {
+    /**
+     * The ID of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub benefit_id: f64,
+    /**
+     * The ID of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub tax_year: f64,
+    /**
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_decimal::deserialize"
+    )]
+    pub ytd_company_contribution_amount: crate::types::DecimalString,
+    /**
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_decimal::deserialize"
+    )]
+    pub ytd_employee_deduction_amount: crate::types::DecimalString,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostCompanyBenefitsRequest {
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /**
+     * The ID of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub benefit_id: f64,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "responsible_for_employee_w2"
+    )]
+    pub responsible_for_employee_w_2: Option<bool>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub responsible_for_employer_taxes: Option<bool>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutCompanyBenefitRequest {
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostEmployeeBenefitsRequest {
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catch_up: Option<bool>,
+    /**
+     * The ID of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub company_benefit_id: f64,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_contribution: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_contribution_annual_maximum: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contribute_as_percentage: Option<bool>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coverage_amount: Option<crate::types::DecimalString>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coverage_salary_multiplier: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deduct_as_percentage: Option<bool>,
+    /**
+     * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub deduction_reduces_taxable_income: Nullable<DeductionReducesTaxableIncome>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub employee_deduction: Option<crate::types::DecimalString>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub employee_deduction_annual_maximum: Option<crate::types::DecimalString>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit_option: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutEmployeeBenefitRequest {
+    /**
+     * Whether the employee is terminated.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /**
+     * Whether the employee is terminated.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catch_up: Option<bool>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_contribution: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_contribution_annual_maximum: Option<String>,
+    /**
+     * Whether the employee is terminated.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contribute_as_percentage: Option<bool>,
+    /**
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coverage_amount: Option<crate::types::DecimalString>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coverage_salary_multiplier: Option<String>,
+    /**
+     * Whether the employee is terminated.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deduct_as_percentage: Option<bool>,
+    /**
+     * Whether the employee deduction reduces taxable income or not. Only valid for Group Term Life benefits. Note: when the value is not "unset", coverage amount and coverage salary multiplier are ignored.
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub deduction_reduces_taxable_income: Nullable<DeductionReducesTaxableIncome>,
+    /**
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub employee_deduction: Option<crate::types::DecimalString>,
+    /**
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub employee_deduction_annual_maximum: Option<crate::types::DecimalString>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit_option: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+}
@@ -0,0 +1,351 @@
+//! The data types of the `companies` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/**
+ * The Gusto product tier of the company.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Tier {
+    #[serde(rename = "basic")]
+    Basic,
+    #[serde(rename = "complete")]
+    Complete,
+    #[serde(rename = "concierge")]
+    Concierge,
+    #[serde(rename = "contractor_only")]
+    ContractorOnly,
+    #[serde(rename = "core")]
+    Core,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Tier {
+    fn as_ref(&self) -> &str {
+        match self {
+            Tier::Basic => "basic",
+            Tier::Complete => "complete",
+            Tier::Concierge => "concierge",
+            Tier::ContractorOnly => "contractor_only",
+            Tier::Core => "core",
+            Tier::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Tier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Tier {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "basic" => Tier::Basic,
+            "complete" => Tier::Complete,
+            "concierge" => Tier::Concierge,
+            "contractor_only" => Tier::ContractorOnly,
+            "core" => Tier::Core,
+            _ => Tier::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Tier {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Tier> for String {
+    fn from(v: Tier) -> Self {
+        match v {
+            Tier::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// The representation of a company in Gusto.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Company {
+    /**
+     * The representation of a company in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company_status: Option<CompanyStatus>,
+    /**
+     * The representation of a company in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compensations: Option<Compensations>,
+    /**
+     * The representation of a company in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ein: String,
+    /**
+     * The representation of a company in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entity_type: Option<EntityType>,
+    /**
+     * The representation of a company in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub id: f64,
+    /**
+     * The representation of a company in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_suspended: bool,
+    /**
+     * The representation of a company in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub locations: Vec<Location>,
+    /**
+     * The representation of a company in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * The representation of a company in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_payroll_admin: Option<PrimaryPayrollAdmin>,
+    /**
+     * The representation of a company in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_signatory: Option<PrimarySignatory>,
+    /**
+     * The Gusto product tier of the company.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier: Option<Tier>,
+    /**
+     * The representation of a company in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub trade_name: String,
+    /**
+     * The representation of a company in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uuid: String,
+}
+
+/// Information for the user who will be the primary payroll administrator for the new company.
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct User {
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub first_name: String,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub last_name: String,
+    /**
+     * Information for the user who will be the primary payroll administrator for the new company.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostPartnerManagedCompaniesRequestCompany {
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ein: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_name: Option<String>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostPartnerManagedCompaniesRequest {
+    #[serde()]
+    pub company: PostPartnerManagedCompaniesRequestCompany,
+    /**
+     * Information for the user who will be the primary payroll administrator for the new company.
+     */
+    #[serde()]
+    pub user: User,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostPartnerManagedCompaniesResponse {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub access_token: String,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub company_uuid: String,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub refresh_token: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostProvisionRequestCompany {
+    /**
+     * The locations for the company. This includes mailing, work, and filing addresses.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub addresses: Vec<Addresses>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ein: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * The ID of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub number_employees: f64,
+    /**
+     * An array of options for fields of type radio. Otherwise, null.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub states: Vec<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trade_name: Option<String>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostProvisionRequest {
+    #[serde()]
+    pub company: PostProvisionRequestCompany,
+    /**
+     * Information for the user who will be the primary payroll administrator for the new company.
+     */
+    #[serde()]
+    pub user: User,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostProvisionResponse {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub account_claim_url: String,
+}
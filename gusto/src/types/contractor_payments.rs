@@ -0,0 +1,24 @@
+//! The data types of the `contractor_payments` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// The representation of the summary of contractor payments for a given company in a given time period.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContractorPaymentSummary {
+    /**
+     * The representation of the summary of contractor payments for a given company in a given time period.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub contractor_payments: Vec<ContractorPayments>,
+    /**
+     * The representation of the summary of contractor payments for a given company in a given time period.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<Total>,
+}
@@ -0,0 +1,220 @@
+//! The data types of the `contractors` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// The representation of a contractor (individual or business) in Gusto.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Contractor {
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub business_name: String,
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub company_id: f64,
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub ein: String,
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub first_name: String,
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hourly_rate: Option<crate::types::DecimalString>,
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub id: f64,
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_active: bool,
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub last_name: String,
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub middle_initial: String,
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<Type>,
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+    /**
+     * The representation of a contractor (individual or business) in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wage_type: Option<WageType>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutComntractorRequest {
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub business_name: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ein: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hourly_rate: Option<crate::types::DecimalString>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub middle_initial: Option<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub start_date: Nullable<chrono::NaiveDate>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wage_type: Option<WageType>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostCompanyContractorsRequest {
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub business_name: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ein: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub middle_initial: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub self_onboarding: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub start_date: Nullable<chrono::NaiveDate>,
+    /**
+     * The contractor type, either an “Individual” or a “Business”.
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<Type>,
+    /**
+     * The contractor’s wage type, either “Fixed” or “Hourly”.
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wage_type: Option<WageType>,
+}
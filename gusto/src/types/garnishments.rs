@@ -0,0 +1,126 @@
+//! The data types of the `garnishments` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostEmployeeGarnishmentsRequest {
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /**
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_decimal::deserialize"
+    )]
+    pub amount: crate::types::DecimalString,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annual_maximum: Option<crate::types::DecimalString>,
+    /**
+     * Whether the employee is terminated.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub court_ordered: Option<bool>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deduct_as_percentage: Option<bool>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pay_period_maximum: Option<crate::types::DecimalString>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<bool>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub times: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutGarnishmentRequest {
+    /**
+     * Whether the employee is terminated.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    /**
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<crate::types::DecimalString>,
+    /**
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub annual_maximum: Option<crate::types::DecimalString>,
+    /**
+     * Whether the employee is terminated.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub court_ordered: Option<bool>,
+    /**
+     * Whether the employee is terminated.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deduct_as_percentage: Option<bool>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /**
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pay_period_maximum: Option<crate::types::DecimalString>,
+    /**
+     * Whether the employee is terminated.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recurring: Option<bool>,
+    /**
+     * The unique identifier of the location in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub times: i64,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+}
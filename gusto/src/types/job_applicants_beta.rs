@@ -0,0 +1,336 @@
+//! The data types of the `job_applicants_beta` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum GustoPersonType {
+    #[serde(rename = "Candidate")]
+    Candidate,
+    #[serde(rename = "Contractor")]
+    Contractor,
+    #[serde(rename = "Employee")]
+    Employee,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for GustoPersonType {
+    fn as_ref(&self) -> &str {
+        match self {
+            GustoPersonType::Candidate => "Candidate",
+            GustoPersonType::Contractor => "Contractor",
+            GustoPersonType::Employee => "Employee",
+            GustoPersonType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for GustoPersonType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GustoPersonType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Candidate" => GustoPersonType::Candidate,
+            "Contractor" => GustoPersonType::Contractor,
+            "Employee" => GustoPersonType::Employee,
+            _ => GustoPersonType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GustoPersonType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GustoPersonType> for String {
+    fn from(v: GustoPersonType) -> Self {
+        match v {
+            GustoPersonType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// The representation of a job applicant in Gusto.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct JobApplicant {
+    /**
+     * The representation of a job applicant in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub company_id: f64,
+    /**
+     * The representation of a job applicant in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub company_uuid: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub date_of_birth: Option<chrono::NaiveDate>,
+    /**
+     * The representation of a job applicant in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    /**
+     * The representation of a job applicant in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub first_name: String,
+    /**
+     * The representation of a job applicant in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub gusto_person_id: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gusto_person_type: Option<GustoPersonType>,
+    /**
+     * The representation of a job applicant in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub gusto_person_uuid: String,
+    /**
+     * The representation of a job applicant in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub job_title: String,
+    /**
+     * The representation of a job applicant in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub last_name: String,
+    /**
+     * The representation of a job applicant in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub phone: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub start_date: Option<chrono::NaiveDate>,
+    /**
+     * The representation of a job applicant in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uuid: String,
+}
+
+/**
+ * Must be "Employee" if send_offer is set to true.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OnboardingPersonType {
+    #[serde(rename = "Contractor")]
+    Contractor,
+    #[serde(rename = "Employee")]
+    Employee,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OnboardingPersonType {
+    fn as_ref(&self) -> &str {
+        match self {
+            OnboardingPersonType::Contractor => "Contractor",
+            OnboardingPersonType::Employee => "Employee",
+            OnboardingPersonType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OnboardingPersonType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OnboardingPersonType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Contractor" => OnboardingPersonType::Contractor,
+            "Employee" => OnboardingPersonType::Employee,
+            _ => OnboardingPersonType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OnboardingPersonType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OnboardingPersonType> for String {
+    fn from(v: OnboardingPersonType) -> Self {
+        match v {
+            OnboardingPersonType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostCompanyJobApplicantsRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub date_of_birth: Nullable<chrono::NaiveDate>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub first_name: String,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub last_name: String,
+    /**
+     * Must be "Employee" if send_offer is set to true.
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub onboarding_person_type: Nullable<OnboardingPersonType>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_offer: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub start_date: Nullable<chrono::NaiveDate>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutCompanyJobApplicantRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub date_of_birth: Nullable<chrono::NaiveDate>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub job_title: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
+    /**
+     * Must be "Employee" if send_offer is set to true.
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub onboarding_person_type: Nullable<OnboardingPersonType>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_offer: Option<bool>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub start_date: Nullable<chrono::NaiveDate>,
+}
@@ -0,0 +1,80 @@
+//! The data types of the `jobs` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutJobRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub hire_date: Nullable<chrono::NaiveDate>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub location_id: f64,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostJobRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub hire_date: Nullable<chrono::NaiveDate>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub location_id: f64,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostJobCompensationsRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub effective_date: Nullable<chrono::NaiveDate>,
+    /**
+     * The FLSA status for this compensation. Salaried ('Exempt') employees are paid a fixed salary every pay period. Salaried with overtime ('Salaried Nonexempt') employees are paid a fixed salary every pay period, and receive overtime pay when applicable. Hourly ('Nonexempt') employees are paid for the hours they work, and receive overtime pay when applicable. Owners ('Owner') are employees that own at least twenty percent of the company.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flsa_status: Option<FlsaStatus>,
+    /**
+     * The unit accompanying the compensation rate. If the employee is an owner, rate should be 'Paycheck'.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_unit: Option<PaymentUnit>,
+    /**
+     * The amount of the garnishment. Either a percentage or a fixed dollar amount. Represented as a float, e.g. "8.00".
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_decimal::deserialize"
+    )]
+    pub rate: crate::types::DecimalString,
+}
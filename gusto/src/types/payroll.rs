@@ -0,0 +1,552 @@
+//! The data types of the `payroll` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Payroll {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub calculated_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub check_date: Option<chrono::NaiveDate>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub company_id: f64,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub company_uuid: String,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub employee_compensations: Vec<EmployeeCompensations>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pay_period: Option<PayrollPayPeriod>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub payroll_deadline: String,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub payroll_id: f64,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub payroll_uuid: String,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub processed: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub processed_date: Option<chrono::NaiveDate>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub totals: Option<Totals>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+}
+
+/// The representation of a pay period.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PayPeriod {
+    /**
+     * The representation of a pay period.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub eligible_employees: Vec<EligibleEmployees>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub end_date: Option<chrono::NaiveDate>,
+    /**
+     * The representation of a pay period.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub pay_schedule_id: f64,
+    /**
+     * The representation of a pay period.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub pay_schedule_uuid: String,
+    /**
+     * The representation of a pay period.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payroll: Option<PayPeriodPayroll>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub start_date: Option<chrono::NaiveDate>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum GetCompanyPayrollsInclude {
+    #[serde(rename = "benefits")]
+    Benefits,
+    #[serde(rename = "deductions")]
+    Deductions,
+    #[serde(rename = "taxes")]
+    Taxes,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for GetCompanyPayrollsInclude {
+    fn as_ref(&self) -> &str {
+        match self {
+            GetCompanyPayrollsInclude::Benefits => "benefits",
+            GetCompanyPayrollsInclude::Deductions => "deductions",
+            GetCompanyPayrollsInclude::Taxes => "taxes",
+            GetCompanyPayrollsInclude::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for GetCompanyPayrollsInclude {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetCompanyPayrollsInclude {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "benefits" => GetCompanyPayrollsInclude::Benefits,
+            "deductions" => GetCompanyPayrollsInclude::Deductions,
+            "taxes" => GetCompanyPayrollsInclude::Taxes,
+            _ => GetCompanyPayrollsInclude::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetCompanyPayrollsInclude {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetCompanyPayrollsInclude> for String {
+    fn from(v: GetCompanyPayrollsInclude) -> Self {
+        match v {
+            GetCompanyPayrollsInclude::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OffCycleReason {
+    #[serde(rename = "Bonus")]
+    Bonus,
+    #[serde(rename = "Correction")]
+    Correction,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OffCycleReason {
+    fn as_ref(&self) -> &str {
+        match self {
+            OffCycleReason::Bonus => "Bonus",
+            OffCycleReason::Correction => "Correction",
+            OffCycleReason::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OffCycleReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OffCycleReason {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Bonus" => OffCycleReason::Bonus,
+            "Correction" => OffCycleReason::Correction,
+            _ => OffCycleReason::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OffCycleReason {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OffCycleReason> for String {
+    fn from(v: OffCycleReason) -> Self {
+        match v {
+            OffCycleReason::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostCompanyPayrollsRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub check_date: Nullable<chrono::NaiveDate>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub employee_ids: Vec<i64>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub end_date: Nullable<chrono::NaiveDate>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub off_cycle: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub off_cycle_reason: Option<OffCycleReason>,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub start_date: Nullable<chrono::NaiveDate>,
+}
+
+/**
+ * Include the requested attribute in the employee_compensations attribute in the response
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum GetCompanyPayrollInclude {
+    #[serde(rename = "benefits")]
+    Benefits,
+    #[serde(rename = "deductions")]
+    Deductions,
+    #[serde(rename = "taxes")]
+    Taxes,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for GetCompanyPayrollInclude {
+    fn as_ref(&self) -> &str {
+        match self {
+            GetCompanyPayrollInclude::Benefits => "benefits",
+            GetCompanyPayrollInclude::Deductions => "deductions",
+            GetCompanyPayrollInclude::Taxes => "taxes",
+            GetCompanyPayrollInclude::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for GetCompanyPayrollInclude {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetCompanyPayrollInclude {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "benefits" => GetCompanyPayrollInclude::Benefits,
+            "deductions" => GetCompanyPayrollInclude::Deductions,
+            "taxes" => GetCompanyPayrollInclude::Taxes,
+            _ => GetCompanyPayrollInclude::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetCompanyPayrollInclude {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetCompanyPayrollInclude> for String {
+    fn from(v: GetCompanyPayrollInclude) -> Self {
+        match v {
+            GetCompanyPayrollInclude::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutCompanyPayrollsRequestEmployeeCompensations {
+    /**
+     * The unique identifier of the location in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub employee_id: i64,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub fixed_compensations: Vec<PutCompanyPayrollsRequestEmployeeCompensationsFixed>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub hourly_compensations: Vec<PutCompanyPayrollsRequestEmployeeCompensationsHourly>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub paid_time_off: Vec<PutCompanyPayrollsRequestEmployeeCompensationsPaidTimeOff>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutCompanyPayrollsRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub employee_compensations: Vec<PutCompanyPayrollsRequestEmployeeCompensations>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutCompanyPayrollsPayPeriodStartDateEndRequestEmployeeCompensations {
+    /**
+     * The unique identifier of the location in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub employee_id: i64,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub fixed_compensations:
+        Vec<PutCompanyPayrollsPayPeriodStartDateEndRequestEmployeeCompensationsFixed>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub hourly_compensations:
+        Vec<PutCompanyPayrollsPayPeriodStartDateEndRequestEmployeeCompensationsHourly>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub paid_time_off:
+        Vec<PutCompanyPayrollsPayPeriodStartDateEndRequestEmployeeCompensationsPaidTimeOff>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutCompanyPayrollsPayPeriodStartDateEndRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub employee_compensations:
+        Vec<PutCompanyPayrollsPayPeriodStartDateEndRequestEmployeeCompensations>,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetCompanyPayrollReversalsResponse {
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub approved_at: String,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub category: String,
+    /**
+     * A unique identifier of the employee in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    /**
+     * The unique identifier of the location in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub reversal_payroll_id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub reversed_employee_ids: Vec<i64>,
+    /**
+     * The unique identifier of the location in Gusto.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub reversed_payroll_id: i64,
+}
@@ -0,0 +1,17 @@
+//! The data types of the `terminations` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostEmployeeTerminationsRequest {
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub effective_date: Nullable<chrono::NaiveDate>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_termination_payroll: Option<bool>,
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ActivityFeed {
     pub client: Client,
@@ -21,19 +19,17 @@ impl ActivityFeed {
      *
      * **Parameters:**
      *
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `params: &ActivityFeedGetChimpChatterParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_chimp_chatter(
+    pub async fn get_chimp_chatter_with(
         &self,
-        count: i64,
-        offset: i64,
+        params: &ActivityFeedGetChimpChatterParams,
     ) -> Result<crate::types::GetActivityFeedChimpChatterResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -41,4 +37,41 @@ impl ActivityFeed {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_chimp_chatter_with` and pass the query parameters as a `ActivityFeedGetChimpChatterParams` instead.
+     */
+    #[deprecated(note = "use `get_chimp_chatter_with` instead")]
+    pub async fn get_chimp_chatter(
+        &self,
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetActivityFeedChimpChatterResponse> {
+        let params = ActivityFeedGetChimpChatterParams {
+            count: Some(count).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_chimp_chatter_with(&params).await
+    }
+}
+
+/// The query parameters of `ActivityFeed::get_chimp_chatter_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityFeedGetChimpChatterParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ActivityFeedGetChimpChatterParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AuthorizedApps {
     pub client: Client,
@@ -21,29 +19,23 @@ impl AuthorizedApps {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `params: &AuthorizedAppsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
+        params: &AuthorizedAppsGetParams,
     ) -> Result<crate::types::GetAuthorizedAppsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -52,6 +44,26 @@ impl AuthorizedApps {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `AuthorizedAppsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetAuthorizedAppsResponse> {
+        let params = AuthorizedAppsGetParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Get authorized app info.
      *
@@ -61,21 +73,19 @@ impl AuthorizedApps {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `app_id: &str` -- The unique id for the connected authorized application.
+     * * `params: &AuthorizedAppsGetAuthorizedAppsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_authorized_apps(
+    pub async fn get_authorized_apps_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         app_id: &str,
+        params: &AuthorizedAppsGetAuthorizedAppsParams,
     ) -> Result<crate::types::Apps> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -87,4 +97,77 @@ impl AuthorizedApps {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_authorized_apps_with` and pass the query parameters as a `AuthorizedAppsGetAuthorizedAppsParams` instead.
+     */
+    #[deprecated(note = "use `get_authorized_apps_with` instead")]
+    pub async fn get_authorized_apps(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        app_id: &str,
+    ) -> Result<crate::types::Apps> {
+        let params = AuthorizedAppsGetAuthorizedAppsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_authorized_apps_with(app_id, &params).await
+    }
+}
+
+/// The query parameters of `AuthorizedApps::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AuthorizedAppsGetParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl AuthorizedAppsGetParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `AuthorizedApps::get_authorized_apps_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AuthorizedAppsGetAuthorizedAppsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl AuthorizedAppsGetAuthorizedAppsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
 }
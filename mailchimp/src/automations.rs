@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Automations {
     pub client: Client,
@@ -21,54 +19,50 @@ impl Automations {
      *
      * **Parameters:**
      *
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `before_create_time: chrono::DateTime<chrono::Utc>` -- Restrict the response to automations created before this time. Uses the ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `since_create_time: chrono::DateTime<chrono::Utc>` -- Restrict the response to automations created after this time. Uses the ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `before_start_time: chrono::DateTime<chrono::Utc>` -- Restrict the response to automations started before this time. Uses the ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `since_start_time: chrono::DateTime<chrono::Utc>` -- Restrict the response to automations started after this time. Uses the ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `status: crate::types::Status` -- Restrict the results to automations with the specified status.
+     * * `params: &AutomationsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        count: i64,
-        offset: i64,
-        fields: &[String],
-        exclude_fields: &[String],
-        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
-        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
-        before_start_time: Option<chrono::DateTime<chrono::Utc>>,
-        since_start_time: Option<chrono::DateTime<chrono::Utc>>,
-        status: crate::types::Status,
+        params: &AutomationsGetParams,
     ) -> Result<crate::types::GetAutomationsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = before_create_time {
-            query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
+        if let Some(before_create_time) = &params.before_create_time {
+            query_args.push((
+                "before_create_time".to_string(),
+                before_create_time.to_rfc3339(),
+            ));
         }
-        if let Some(date) = before_start_time {
-            query_args.push(("before_start_time".to_string(), date.to_rfc3339()));
+        if let Some(before_start_time) = &params.before_start_time {
+            query_args.push((
+                "before_start_time".to_string(),
+                before_start_time.to_rfc3339(),
+            ));
         }
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if let Some(date) = since_create_time {
-            query_args.push(("since_create_time".to_string(), date.to_rfc3339()));
+        if let Some(since_create_time) = &params.since_create_time {
+            query_args.push((
+                "since_create_time".to_string(),
+                since_create_time.to_rfc3339(),
+            ));
         }
-        if let Some(date) = since_start_time {
-            query_args.push(("since_start_time".to_string(), date.to_rfc3339()));
+        if let Some(since_start_time) = &params.since_start_time {
+            query_args.push((
+                "since_start_time".to_string(),
+                since_start_time.to_rfc3339(),
+            ));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -77,6 +71,36 @@ impl Automations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `AutomationsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        count: i64,
+        offset: i64,
+        fields: &[String],
+        exclude_fields: &[String],
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        status: crate::types::Status,
+    ) -> Result<crate::types::GetAutomationsResponse> {
+        let params = AutomationsGetParams {
+            before_create_time,
+            before_start_time,
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            since_create_time,
+            since_start_time,
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Add automation.
      *
@@ -103,21 +127,19 @@ impl Automations {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `workflow_id: &str` -- The unique id for the Automation workflow.
+     * * `params: &AutomationsGetAutomationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_automations(
+    pub async fn get_automations_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         workflow_id: &str,
+        params: &AutomationsGetAutomationsParams,
     ) -> Result<crate::types::Automations> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -130,6 +152,23 @@ impl Automations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_automations_with` and pass the query parameters as a `AutomationsGetAutomationsParams` instead.
+     */
+    #[deprecated(note = "use `get_automations_with` instead")]
+    pub async fn get_automations(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        workflow_id: &str,
+    ) -> Result<crate::types::Automations> {
+        let params = AutomationsGetAutomationsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_automations_with(workflow_id, &params).await
+    }
+
     /**
      * Pause automation emails.
      *
@@ -498,3 +537,94 @@ impl Automations {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Automations::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AutomationsGetParams {
+    /// Restrict the response to automations created before this time. Uses the ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Restrict the response to automations started before this time. Uses the ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_start_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Restrict the response to automations created after this time. Uses the ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Restrict the response to automations started after this time. Uses the ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_start_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Restrict the results to automations with the specified status.
+    pub status: Option<crate::types::Status>,
+}
+
+impl AutomationsGetParams {
+    pub fn before_create_time(mut self, before_create_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before_create_time = Some(before_create_time);
+        self
+    }
+
+    pub fn before_start_time(mut self, before_start_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before_start_time = Some(before_start_time);
+        self
+    }
+
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn since_create_time(mut self, since_create_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since_create_time = Some(since_create_time);
+        self
+    }
+
+    pub fn since_start_time(mut self, since_start_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since_start_time = Some(since_start_time);
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::Status) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// The query parameters of `Automations::get_automations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AutomationsGetAutomationsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl AutomationsGetAutomationsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
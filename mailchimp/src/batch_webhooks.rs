@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct BatchWebhooks {
     pub client: Client,
@@ -21,29 +19,23 @@ impl BatchWebhooks {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `params: &BatchWebhooksGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
+        params: &BatchWebhooksGetParams,
     ) -> Result<crate::types::BatchWebhooks> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -52,6 +44,26 @@ impl BatchWebhooks {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `BatchWebhooksGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::BatchWebhooks> {
+        let params = BatchWebhooksGetParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Add batch webhook.
      *
@@ -75,21 +87,19 @@ impl BatchWebhooks {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `batch_webhook_id: &str` -- The unique id for the batch webhook.
+     * * `params: &BatchWebhooksGetBatchWebhooksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_batch_webhooks(
+    pub async fn get_batch_webhooks_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         batch_webhook_id: &str,
+        params: &BatchWebhooksGetBatchWebhooksParams,
     ) -> Result<crate::types::Webhooks> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -102,6 +112,24 @@ impl BatchWebhooks {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_batch_webhooks_with` and pass the query parameters as a `BatchWebhooksGetBatchWebhooksParams` instead.
+     */
+    #[deprecated(note = "use `get_batch_webhooks_with` instead")]
+    pub async fn get_batch_webhooks(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        batch_webhook_id: &str,
+    ) -> Result<crate::types::Webhooks> {
+        let params = BatchWebhooksGetBatchWebhooksParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_batch_webhooks_with(batch_webhook_id, &params)
+            .await
+    }
+
     /**
      * Delete batch webhook.
      *
@@ -148,3 +176,59 @@ impl BatchWebhooks {
             .await
     }
 }
+
+/// The query parameters of `BatchWebhooks::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BatchWebhooksGetParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl BatchWebhooksGetParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `BatchWebhooks::get_batch_webhooks_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BatchWebhooksGetBatchWebhooksParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl BatchWebhooksGetBatchWebhooksParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Batches {
     pub client: Client,
@@ -21,29 +19,23 @@ impl Batches {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `params: &BatchesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
+        params: &BatchesGetParams,
     ) -> Result<crate::types::BatchOperations> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -52,6 +44,26 @@ impl Batches {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `BatchesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::BatchOperations> {
+        let params = BatchesGetParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Start batch operation.
      *
@@ -78,21 +90,19 @@ impl Batches {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `batch_id: &str` -- The unique id for the batch operation.
+     * * `params: &BatchesGetBatchesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_batches(
+    pub async fn get_batches_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         batch_id: &str,
+        params: &BatchesGetBatchesParams,
     ) -> Result<crate::types::Batch> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -105,6 +115,23 @@ impl Batches {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_batches_with` and pass the query parameters as a `BatchesGetBatchesParams` instead.
+     */
+    #[deprecated(note = "use `get_batches_with` instead")]
+    pub async fn get_batches(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        batch_id: &str,
+    ) -> Result<crate::types::Batch> {
+        let params = BatchesGetBatchesParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_batches_with(batch_id, &params).await
+    }
+
     /**
      * Delete batch request.
      *
@@ -125,3 +152,59 @@ impl Batches {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Batches::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BatchesGetParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl BatchesGetParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Batches::get_batches_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BatchesGetBatchesParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl BatchesGetBatchesParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CampaignFolders {
     pub client: Client,
@@ -21,29 +19,23 @@ impl CampaignFolders {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `params: &CampaignFoldersGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
+        params: &CampaignFoldersGetParams,
     ) -> Result<crate::types::CampaignFolders> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -52,6 +44,26 @@ impl CampaignFolders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `CampaignFoldersGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::CampaignFolders> {
+        let params = CampaignFoldersGetParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Add campaign folder.
      *
@@ -78,21 +90,19 @@ impl CampaignFolders {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `folder_id: &str` -- The unique id for the campaign folder.
+     * * `params: &CampaignFoldersGetCampaignFoldersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_campaign_folders(
+    pub async fn get_campaign_folders_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         folder_id: &str,
+        params: &CampaignFoldersGetCampaignFoldersParams,
     ) -> Result<crate::types::CampaignFolder> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -105,6 +115,23 @@ impl CampaignFolders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_campaign_folders_with` and pass the query parameters as a `CampaignFoldersGetCampaignFoldersParams` instead.
+     */
+    #[deprecated(note = "use `get_campaign_folders_with` instead")]
+    pub async fn get_campaign_folders(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        folder_id: &str,
+    ) -> Result<crate::types::CampaignFolder> {
+        let params = CampaignFoldersGetCampaignFoldersParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_campaign_folders_with(folder_id, &params).await
+    }
+
     /**
      * Delete campaign folder.
      *
@@ -151,3 +178,59 @@ impl CampaignFolders {
             .await
     }
 }
+
+/// The query parameters of `CampaignFolders::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CampaignFoldersGetParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl CampaignFoldersGetParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `CampaignFolders::get_campaign_folders_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CampaignFoldersGetCampaignFoldersParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl CampaignFoldersGetCampaignFoldersParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
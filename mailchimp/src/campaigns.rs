@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Campaigns {
     pub client: Client,
@@ -21,84 +19,65 @@ impl Campaigns {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `type_: crate::types::CampaignType` -- There are four types of [campaigns](https://mailchimp.com/help/getting-started-with-campaigns/) you can create in Mailchimp. A/B Split campaigns have been deprecated and variate campaigns should be used instead.
-     * * `status: crate::types::GetCampaignsStatus` -- The status of the campaign.
-     * * `before_send_time: chrono::DateTime<chrono::Utc>` -- Restrict the response to campaigns sent before the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `since_send_time: chrono::DateTime<chrono::Utc>` -- Restrict the response to campaigns sent after the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `before_create_time: chrono::DateTime<chrono::Utc>` -- Restrict the response to campaigns created before the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `since_create_time: chrono::DateTime<chrono::Utc>` -- Restrict the response to campaigns created after the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `list_id: &str` -- The unique id for the list.
-     * * `folder_id: &str` -- The name of the folder.
-     * * `member_id: &str` -- Retrieve campaigns sent to a particular list member. Member ID is The MD5 hash of the lowercase version of the list member’s email address.
-     * * `sort_field: crate::types::SortField` -- Returns files sorted by the specified field.
-     * * `sort_dir: crate::types::SortDir` -- Determines the order direction for sorted results.
+     * * `params: &CampaignsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        type_: crate::types::CampaignType,
-        status: crate::types::GetCampaignsStatus,
-        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
-        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
-        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
-        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
-        list_id: &str,
-        folder_id: &str,
-        member_id: &str,
-        sort_field: crate::types::SortField,
-        sort_dir: crate::types::SortDir,
+        params: &CampaignsGetParams,
     ) -> Result<crate::types::GetCampaignsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = before_create_time {
-            query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
+        if let Some(before_create_time) = &params.before_create_time {
+            query_args.push((
+                "before_create_time".to_string(),
+                before_create_time.to_rfc3339(),
+            ));
         }
-        if let Some(date) = before_send_time {
-            query_args.push(("before_send_time".to_string(), date.to_rfc3339()));
+        if let Some(before_send_time) = &params.before_send_time {
+            query_args.push((
+                "before_send_time".to_string(),
+                before_send_time.to_rfc3339(),
+            ));
         }
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if !folder_id.is_empty() {
+        if let Some(folder_id) = &params.folder_id {
             query_args.push(("folder_id".to_string(), folder_id.to_string()));
         }
-        if !list_id.is_empty() {
+        if let Some(list_id) = &params.list_id {
             query_args.push(("list_id".to_string(), list_id.to_string()));
         }
-        if !member_id.is_empty() {
+        if let Some(member_id) = &params.member_id {
             query_args.push(("member_id".to_string(), member_id.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if let Some(date) = since_create_time {
-            query_args.push(("since_create_time".to_string(), date.to_rfc3339()));
+        if let Some(since_create_time) = &params.since_create_time {
+            query_args.push((
+                "since_create_time".to_string(),
+                since_create_time.to_rfc3339(),
+            ));
         }
-        if let Some(date) = since_send_time {
-            query_args.push(("since_send_time".to_string(), date.to_rfc3339()));
+        if let Some(since_send_time) = &params.since_send_time {
+            query_args.push(("since_send_time".to_string(), since_send_time.to_rfc3339()));
         }
-        if !sort_dir.to_string().is_empty() {
+        if let Some(sort_dir) = &params.sort_dir {
             query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
         }
-        if !sort_field.to_string().is_empty() {
+        if let Some(sort_field) = &params.sort_field {
             query_args.push(("sort_field".to_string(), sort_field.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -107,6 +86,48 @@ impl Campaigns {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `CampaignsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: crate::types::CampaignType,
+        status: crate::types::GetCampaignsStatus,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        list_id: &str,
+        folder_id: &str,
+        member_id: &str,
+        sort_field: crate::types::SortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::GetCampaignsResponse> {
+        let params = CampaignsGetParams {
+            before_create_time,
+            before_send_time,
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            folder_id: Some(folder_id.to_string()).filter(|s| !s.is_empty()),
+            list_id: Some(list_id.to_string()).filter(|s| !s.is_empty()),
+            member_id: Some(member_id.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            since_create_time,
+            since_send_time,
+            sort_dir: Some(sort_dir).filter(|v| !v.to_string().is_empty()),
+            sort_field: Some(sort_field).filter(|v| !v.to_string().is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Add campaign.
      *
@@ -133,21 +154,19 @@ impl Campaigns {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &CampaignsGetCampaignsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_campaigns(
+    pub async fn get_campaigns_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
+        params: &CampaignsGetCampaignsParams,
     ) -> Result<crate::types::Campaign> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -160,6 +179,23 @@ impl Campaigns {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_campaigns_with` and pass the query parameters as a `CampaignsGetCampaignsParams` instead.
+     */
+    #[deprecated(note = "use `get_campaigns_with` instead")]
+    pub async fn get_campaigns(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::Campaign> {
+        let params = CampaignsGetCampaignsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_campaigns_with(campaign_id, &params).await
+    }
+
     /**
      * Delete campaign.
      *
@@ -413,21 +449,19 @@ impl Campaigns {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &CampaignsGetContentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_content(
+    pub async fn get_content_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
+        params: &CampaignsGetContentParams,
     ) -> Result<crate::types::CampaignContent> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -440,6 +474,23 @@ impl Campaigns {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_content_with` and pass the query parameters as a `CampaignsGetContentParams` instead.
+     */
+    #[deprecated(note = "use `get_content_with` instead")]
+    pub async fn get_content(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignContent> {
+        let params = CampaignsGetContentParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_content_with(campaign_id, &params).await
+    }
+
     /**
      * Set campaign content.
      *
@@ -475,21 +526,19 @@ impl Campaigns {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &CampaignsGetFeedbackParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_feedback(
+    pub async fn get_feedback_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
+        params: &CampaignsGetFeedbackParams,
     ) -> Result<crate::types::CampaignReports> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -502,6 +551,23 @@ impl Campaigns {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_feedback_with` and pass the query parameters as a `CampaignsGetFeedbackParams` instead.
+     */
+    #[deprecated(note = "use `get_feedback_with` instead")]
+    pub async fn get_feedback(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignReports> {
+        let params = CampaignsGetFeedbackParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_feedback_with(campaign_id, &params).await
+    }
+
     /**
      * Add campaign feedback.
      *
@@ -537,23 +603,21 @@ impl Campaigns {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
      * * `feedback_id: &str` -- The unique id for the feedback message.
+     * * `params: &CampaignsGetFeedbackCampaignsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_feedback_campaigns(
+    pub async fn get_feedback_campaigns_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
         feedback_id: &str,
+        params: &CampaignsGetFeedbackCampaignsParams,
     ) -> Result<crate::types::CampaignFeedbackData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -567,6 +631,25 @@ impl Campaigns {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_feedback_campaigns_with` and pass the query parameters as a `CampaignsGetFeedbackCampaignsParams` instead.
+     */
+    #[deprecated(note = "use `get_feedback_campaigns_with` instead")]
+    pub async fn get_feedback_campaigns(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        feedback_id: &str,
+    ) -> Result<crate::types::CampaignFeedbackData> {
+        let params = CampaignsGetFeedbackCampaignsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_feedback_campaigns_with(campaign_id, feedback_id, &params)
+            .await
+    }
+
     /**
      * Delete campaign feedback message.
      *
@@ -627,21 +710,19 @@ impl Campaigns {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &CampaignsGetSendChecklistParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_send_checklist(
+    pub async fn get_send_checklist_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
+        params: &CampaignsGetSendChecklistParams,
     ) -> Result<crate::types::SendChecklist> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -653,4 +734,238 @@ impl Campaigns {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_send_checklist_with` and pass the query parameters as a `CampaignsGetSendChecklistParams` instead.
+     */
+    #[deprecated(note = "use `get_send_checklist_with` instead")]
+    pub async fn get_send_checklist(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::SendChecklist> {
+        let params = CampaignsGetSendChecklistParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_send_checklist_with(campaign_id, &params).await
+    }
+}
+
+/// The query parameters of `Campaigns::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CampaignsGetParams {
+    /// Restrict the response to campaigns created before the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Restrict the response to campaigns sent before the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// The name of the folder.
+    pub folder_id: Option<String>,
+    /// The unique id for the list.
+    pub list_id: Option<String>,
+    /// Retrieve campaigns sent to a particular list member. Member ID is The MD5 hash of the lowercase version of the list member’s email address.
+    pub member_id: Option<String>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Restrict the response to campaigns created after the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Restrict the response to campaigns sent after the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// Determines the order direction for sorted results.
+    pub sort_dir: Option<crate::types::SortDir>,
+    /// Returns files sorted by the specified field.
+    pub sort_field: Option<crate::types::SortField>,
+    /// The status of the campaign.
+    pub status: Option<crate::types::GetCampaignsStatus>,
+    /// There are four types of [campaigns](https://mailchimp.com/help/getting-started-with-campaigns/) you can create in Mailchimp. A/B Split campaigns have been deprecated and variate campaigns should be used instead.
+    pub type_: Option<crate::types::CampaignType>,
+}
+
+impl CampaignsGetParams {
+    pub fn before_create_time(mut self, before_create_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before_create_time = Some(before_create_time);
+        self
+    }
+
+    pub fn before_send_time(mut self, before_send_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before_send_time = Some(before_send_time);
+        self
+    }
+
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn folder_id<T: ToString>(mut self, folder_id: T) -> Self {
+        self.folder_id = Some(folder_id.to_string());
+        self
+    }
+
+    pub fn list_id<T: ToString>(mut self, list_id: T) -> Self {
+        self.list_id = Some(list_id.to_string());
+        self
+    }
+
+    pub fn member_id<T: ToString>(mut self, member_id: T) -> Self {
+        self.member_id = Some(member_id.to_string());
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn since_create_time(mut self, since_create_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since_create_time = Some(since_create_time);
+        self
+    }
+
+    pub fn since_send_time(mut self, since_send_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since_send_time = Some(since_send_time);
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn sort_field(mut self, sort_field: crate::types::SortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::GetCampaignsStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::CampaignType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Campaigns::get_campaigns_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CampaignsGetCampaignsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl CampaignsGetCampaignsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Campaigns::get_content_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CampaignsGetContentParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl CampaignsGetContentParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Campaigns::get_feedback_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CampaignsGetFeedbackParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl CampaignsGetFeedbackParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Campaigns::get_feedback_campaigns_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CampaignsGetFeedbackCampaignsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl CampaignsGetFeedbackCampaignsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Campaigns::get_send_checklist_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CampaignsGetSendChecklistParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl CampaignsGetSendChecklistParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
 }
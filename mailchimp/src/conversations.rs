@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Conversations {
     pub client: Client,
@@ -21,47 +19,35 @@ impl Conversations {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `has_unread_messages: crate::types::IsRead` -- Whether a conversation message has been marked as read.
-     * * `list_id: &str` -- The unique id for the list.
-     * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &ConversationsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        has_unread_messages: crate::types::IsRead,
-        list_id: &str,
-        campaign_id: &str,
+        params: &ConversationsGetParams,
     ) -> Result<crate::types::TrackedConversations> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !campaign_id.is_empty() {
+        if let Some(campaign_id) = &params.campaign_id {
             query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
         }
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if !has_unread_messages.to_string().is_empty() {
+        if let Some(has_unread_messages) = &params.has_unread_messages {
             query_args.push((
                 "has_unread_messages".to_string(),
                 has_unread_messages.to_string(),
             ));
         }
-        if !list_id.is_empty() {
+        if let Some(list_id) = &params.list_id {
             query_args.push(("list_id".to_string(), list_id.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -70,6 +56,32 @@ impl Conversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `ConversationsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        has_unread_messages: crate::types::IsRead,
+        list_id: &str,
+        campaign_id: &str,
+    ) -> Result<crate::types::TrackedConversations> {
+        let params = ConversationsGetParams {
+            campaign_id: Some(campaign_id.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            has_unread_messages: Some(has_unread_messages).filter(|v| !v.to_string().is_empty()),
+            list_id: Some(list_id.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Get conversation.
      *
@@ -79,21 +91,19 @@ impl Conversations {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `conversation_id: &str` -- The unique id for the conversation.
+     * * `params: &ConversationsGetConversationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_conversations(
+    pub async fn get_conversations_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         conversation_id: &str,
+        params: &ConversationsGetConversationsParams,
     ) -> Result<crate::types::Conversation> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -106,6 +116,23 @@ impl Conversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_conversations_with` and pass the query parameters as a `ConversationsGetConversationsParams` instead.
+     */
+    #[deprecated(note = "use `get_conversations_with` instead")]
+    pub async fn get_conversations(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+    ) -> Result<crate::types::Conversation> {
+        let params = ConversationsGetConversationsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_conversations_with(conversation_id, &params).await
+    }
+
     /**
      * List messages.
      *
@@ -115,37 +142,32 @@ impl Conversations {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `conversation_id: &str` -- The unique id for the conversation.
-     * * `is_read: crate::types::IsRead` -- Whether a conversation message has been marked as read.
-     * * `before_timestamp: chrono::DateTime<chrono::Utc>` -- Restrict the response to messages created before the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `since_timestamp: chrono::DateTime<chrono::Utc>` -- Restrict the response to messages created after the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * * `params: &ConversationsGetMessageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_message(
+    pub async fn get_message_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         conversation_id: &str,
-        is_read: crate::types::IsRead,
-        before_timestamp: Option<chrono::DateTime<chrono::Utc>>,
-        since_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+        params: &ConversationsGetMessageParams,
     ) -> Result<crate::types::CollectionOfConversationMessages> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = before_timestamp {
-            query_args.push(("before_timestamp".to_string(), date.to_rfc3339()));
+        if let Some(before_timestamp) = &params.before_timestamp {
+            query_args.push((
+                "before_timestamp".to_string(),
+                before_timestamp.to_rfc3339(),
+            ));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if !is_read.to_string().is_empty() {
+        if let Some(is_read) = &params.is_read {
             query_args.push(("is_read".to_string(), is_read.to_string()));
         }
-        if let Some(date) = since_timestamp {
-            query_args.push(("since_timestamp".to_string(), date.to_rfc3339()));
+        if let Some(since_timestamp) = &params.since_timestamp {
+            query_args.push(("since_timestamp".to_string(), since_timestamp.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
@@ -157,6 +179,29 @@ impl Conversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_message_with` and pass the query parameters as a `ConversationsGetMessageParams` instead.
+     */
+    #[deprecated(note = "use `get_message_with` instead")]
+    pub async fn get_message(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+        is_read: crate::types::IsRead,
+        before_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+        since_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<crate::types::CollectionOfConversationMessages> {
+        let params = ConversationsGetMessageParams {
+            before_timestamp,
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            is_read: Some(is_read).filter(|v| !v.to_string().is_empty()),
+            since_timestamp,
+        };
+        self.get_message_with(conversation_id, &params).await
+    }
+
     /**
      * Get message.
      *
@@ -166,23 +211,21 @@ impl Conversations {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `conversation_id: &str` -- The unique id for the conversation.
      * * `message_id: &str` -- The unique id for the conversation message.
+     * * `params: &ConversationsGetMessageConversationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_message_conversations(
+    pub async fn get_message_conversations_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         conversation_id: &str,
         message_id: &str,
+        params: &ConversationsGetMessageConversationsParams,
     ) -> Result<crate::types::ConversationMessage> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -195,4 +238,163 @@ impl Conversations {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_message_conversations_with` and pass the query parameters as a `ConversationsGetMessageConversationsParams` instead.
+     */
+    #[deprecated(note = "use `get_message_conversations_with` instead")]
+    pub async fn get_message_conversations(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        conversation_id: &str,
+        message_id: &str,
+    ) -> Result<crate::types::ConversationMessage> {
+        let params = ConversationsGetMessageConversationsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_message_conversations_with(conversation_id, message_id, &params)
+            .await
+    }
+}
+
+/// The query parameters of `Conversations::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ConversationsGetParams {
+    /// The unique id for the campaign.
+    pub campaign_id: Option<String>,
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Whether a conversation message has been marked as read.
+    pub has_unread_messages: Option<crate::types::IsRead>,
+    /// The unique id for the list.
+    pub list_id: Option<String>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ConversationsGetParams {
+    pub fn campaign_id<T: ToString>(mut self, campaign_id: T) -> Self {
+        self.campaign_id = Some(campaign_id.to_string());
+        self
+    }
+
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn has_unread_messages(mut self, has_unread_messages: crate::types::IsRead) -> Self {
+        self.has_unread_messages = Some(has_unread_messages);
+        self
+    }
+
+    pub fn list_id<T: ToString>(mut self, list_id: T) -> Self {
+        self.list_id = Some(list_id.to_string());
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Conversations::get_conversations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ConversationsGetConversationsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ConversationsGetConversationsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Conversations::get_message_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ConversationsGetMessageParams {
+    /// Restrict the response to messages created before the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Whether a conversation message has been marked as read.
+    pub is_read: Option<crate::types::IsRead>,
+    /// Restrict the response to messages created after the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ConversationsGetMessageParams {
+    pub fn before_timestamp(mut self, before_timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before_timestamp = Some(before_timestamp);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn is_read(mut self, is_read: crate::types::IsRead) -> Self {
+        self.is_read = Some(is_read);
+        self
+    }
+
+    pub fn since_timestamp(mut self, since_timestamp: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since_timestamp = Some(since_timestamp);
+        self
+    }
+}
+
+/// The query parameters of `Conversations::get_message_conversations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ConversationsGetMessageConversationsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ConversationsGetMessageConversationsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Ecommerce {
     pub client: Client,
@@ -21,49 +19,35 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `campaign_id: &str` -- Restrict results to orders with a specific `campaign_id` value.
-     * * `outreach_id: &str` -- Restrict results to orders with a specific `outreach_id` value.
-     * * `customer_id: &str` -- Restrict results to orders made by a specific customer.
-     * * `has_outreach: bool` -- Restrict results to orders that have an outreach attached. For example, an email campaign or Facebook ad.
+     * * `params: &EcommerceGetOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_order(
+    pub async fn get_order_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        campaign_id: &str,
-        outreach_id: &str,
-        customer_id: &str,
-        has_outreach: bool,
+        params: &EcommerceGetOrderParams,
     ) -> Result<crate::types::OrdersData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !campaign_id.is_empty() {
+        if let Some(campaign_id) = &params.campaign_id {
             query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
         }
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !customer_id.is_empty() {
+        if let Some(customer_id) = &params.customer_id {
             query_args.push(("customer_id".to_string(), customer_id.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if has_outreach {
+        if let Some(has_outreach) = &params.has_outreach {
             query_args.push(("has_outreach".to_string(), has_outreach.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !outreach_id.is_empty() {
+        if let Some(outreach_id) = &params.outreach_id {
             query_args.push(("outreach_id".to_string(), outreach_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -72,6 +56,34 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_order_with` and pass the query parameters as a `EcommerceGetOrderParams` instead.
+     */
+    #[deprecated(note = "use `get_order_with` instead")]
+    pub async fn get_order(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        outreach_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+    ) -> Result<crate::types::OrdersData> {
+        let params = EcommerceGetOrderParams {
+            campaign_id: Some(campaign_id.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count).filter(|v| *v > 0),
+            customer_id: Some(customer_id.to_string()).filter(|s| !s.is_empty()),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            has_outreach: Some(has_outreach).filter(|v| *v),
+            offset: Some(offset).filter(|v| *v > 0),
+            outreach_id: Some(outreach_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_order_with(&params).await
+    }
+
     /**
      * List stores.
      *
@@ -81,29 +93,23 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `params: &EcommerceGetStoreParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_store(
+    pub async fn get_store_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
+        params: &EcommerceGetStoreParams,
     ) -> Result<crate::types::ECommerceStores> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -112,6 +118,26 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_store_with` and pass the query parameters as a `EcommerceGetStoreParams` instead.
+     */
+    #[deprecated(note = "use `get_store_with` instead")]
+    pub async fn get_store(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::ECommerceStores> {
+        let params = EcommerceGetStoreParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_store_with(&params).await
+    }
+
     /**
      * Add store.
      *
@@ -138,21 +164,19 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `store_id: &str` -- The name of the folder.
+     * * `params: &EcommerceGetStoreEcommerceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_store_ecommerce(
+    pub async fn get_store_ecommerce_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         store_id: &str,
+        params: &EcommerceGetStoreEcommerceParams,
     ) -> Result<crate::types::Stores> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -165,6 +189,23 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_store_ecommerce_with` and pass the query parameters as a `EcommerceGetStoreEcommerceParams` instead.
+     */
+    #[deprecated(note = "use `get_store_ecommerce_with` instead")]
+    pub async fn get_store_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+    ) -> Result<crate::types::Stores> {
+        let params = EcommerceGetStoreEcommerceParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_store_ecommerce_with(store_id, &params).await
+    }
+
     /**
      * Delete store.
      *
@@ -220,31 +261,25 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `store_id: &str` -- The name of the folder.
+     * * `params: &EcommerceGetStoresCartParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_cart(
+    pub async fn get_stores_cart_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         store_id: &str,
+        params: &EcommerceGetStoresCartParams,
     ) -> Result<crate::types::CartsData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -257,6 +292,27 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_cart_with` and pass the query parameters as a `EcommerceGetStoresCartParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_cart_with` instead")]
+    pub async fn get_stores_cart(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> Result<crate::types::CartsData> {
+        let params = EcommerceGetStoresCartParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_stores_cart_with(store_id, &params).await
+    }
+
     /**
      * Add cart.
      *
@@ -292,23 +348,21 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `store_id: &str` -- The name of the folder.
      * * `cart_id: &str` -- The name of the folder.
+     * * `params: &EcommerceGetStoresCartEcommerceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_cart_ecommerce(
+    pub async fn get_stores_cart_ecommerce_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         store_id: &str,
         cart_id: &str,
+        params: &EcommerceGetStoresCartEcommerceParams,
     ) -> Result<crate::types::Carts> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -322,6 +376,25 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_cart_ecommerce_with` and pass the query parameters as a `EcommerceGetStoresCartEcommerceParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_cart_ecommerce_with` instead")]
+    pub async fn get_stores_cart_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        cart_id: &str,
+    ) -> Result<crate::types::Carts> {
+        let params = EcommerceGetStoresCartEcommerceParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_stores_cart_ecommerce_with(store_id, cart_id, &params)
+            .await
+    }
+
     /**
      * Delete cart.
      *
@@ -382,33 +455,27 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `store_id: &str` -- The name of the folder.
      * * `cart_id: &str` -- The name of the folder.
+     * * `params: &EcommerceGetStoresCartsLineParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_carts_line(
+    pub async fn get_stores_carts_line_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         store_id: &str,
         cart_id: &str,
+        params: &EcommerceGetStoresCartsLineParams,
     ) -> Result<crate::types::CartLines> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -422,6 +489,29 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_carts_line_with` and pass the query parameters as a `EcommerceGetStoresCartsLineParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_carts_line_with` instead")]
+    pub async fn get_stores_carts_line(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        cart_id: &str,
+    ) -> Result<crate::types::CartLines> {
+        let params = EcommerceGetStoresCartsLineParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_stores_carts_line_with(store_id, cart_id, &params)
+            .await
+    }
+
     /**
      * Add cart line item.
      *
@@ -460,25 +550,23 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `store_id: &str` -- The name of the folder.
      * * `cart_id: &str` -- The name of the folder.
      * * `line_id: &str` -- The id for the line item of a cart.
+     * * `params: &EcommerceGetStoresCartsLineEcommerceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_carts_line_ecommerce(
+    pub async fn get_stores_carts_line_ecommerce_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         store_id: &str,
         cart_id: &str,
         line_id: &str,
+        params: &EcommerceGetStoresCartsLineEcommerceParams,
     ) -> Result<crate::types::ECommerceCartLineItem> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -493,6 +581,26 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_carts_line_ecommerce_with` and pass the query parameters as a `EcommerceGetStoresCartsLineEcommerceParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_carts_line_ecommerce_with` instead")]
+    pub async fn get_stores_carts_line_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        cart_id: &str,
+        line_id: &str,
+    ) -> Result<crate::types::ECommerceCartLineItem> {
+        let params = EcommerceGetStoresCartsLineEcommerceParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_stores_carts_line_ecommerce_with(store_id, cart_id, line_id, &params)
+            .await
+    }
+
     /**
      * Delete cart line item.
      *
@@ -563,36 +671,28 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `store_id: &str` -- The name of the folder.
-     * * `email_address: &str` -- Restrict the response to customers with the email address.
+     * * `params: &EcommerceGetStoresCustomerParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_customer(
+    pub async fn get_stores_customer_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         store_id: &str,
-        email_address: &str,
+        params: &EcommerceGetStoresCustomerParams,
     ) -> Result<crate::types::Customers> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !email_address.is_empty() {
+        if let Some(email_address) = &params.email_address {
             query_args.push(("email_address".to_string(), email_address.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -605,6 +705,29 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_customer_with` and pass the query parameters as a `EcommerceGetStoresCustomerParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_customer_with` instead")]
+    pub async fn get_stores_customer(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        email_address: &str,
+    ) -> Result<crate::types::Customers> {
+        let params = EcommerceGetStoresCustomerParams {
+            count: Some(count).filter(|v| *v > 0),
+            email_address: Some(email_address.to_string()).filter(|s| !s.is_empty()),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_stores_customer_with(store_id, &params).await
+    }
+
     /**
      * Add customer.
      *
@@ -640,23 +763,21 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `store_id: &str` -- The name of the folder.
      * * `customer_id: &str` -- The id for the customer of a store.
+     * * `params: &EcommerceGetStoresCustomerEcommerceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_customer_ecommerce(
+    pub async fn get_stores_customer_ecommerce_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         store_id: &str,
         customer_id: &str,
+        params: &EcommerceGetStoresCustomerEcommerceParams,
     ) -> Result<crate::types::Customer> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -670,6 +791,25 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_customer_ecommerce_with` and pass the query parameters as a `EcommerceGetStoresCustomerEcommerceParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_customer_ecommerce_with` instead")]
+    pub async fn get_stores_customer_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        customer_id: &str,
+    ) -> Result<crate::types::Customer> {
+        let params = EcommerceGetStoresCustomerEcommerceParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_stores_customer_ecommerce_with(store_id, customer_id, &params)
+            .await
+    }
+
     /**
      * Add or update customer.
      *
@@ -759,31 +899,25 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `store_id: &str` -- The name of the folder.
+     * * `params: &EcommerceGetStoresPromoruleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_promorule(
+    pub async fn get_stores_promorule_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         store_id: &str,
+        params: &EcommerceGetStoresPromoruleParams,
     ) -> Result<crate::types::PromoRulesData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -796,6 +930,27 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_promorule_with` and pass the query parameters as a `EcommerceGetStoresPromoruleParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_promorule_with` instead")]
+    pub async fn get_stores_promorule(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> Result<crate::types::PromoRulesData> {
+        let params = EcommerceGetStoresPromoruleParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_stores_promorule_with(store_id, &params).await
+    }
+
     /**
      * Add promo rule.
      *
@@ -831,23 +986,21 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `store_id: &str` -- The name of the folder.
      * * `promo_rule_id: &str` -- The id for the promo rule of a store.
+     * * `params: &EcommerceGetStoresPromoruleEcommerceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_promorule_ecommerce(
+    pub async fn get_stores_promorule_ecommerce_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         store_id: &str,
         promo_rule_id: &str,
+        params: &EcommerceGetStoresPromoruleEcommerceParams,
     ) -> Result<crate::types::PromoRules> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -861,6 +1014,25 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_promorule_ecommerce_with` and pass the query parameters as a `EcommerceGetStoresPromoruleEcommerceParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_promorule_ecommerce_with` instead")]
+    pub async fn get_stores_promorule_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        promo_rule_id: &str,
+    ) -> Result<crate::types::PromoRules> {
+        let params = EcommerceGetStoresPromoruleEcommerceParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_stores_promorule_ecommerce_with(store_id, promo_rule_id, &params)
+            .await
+    }
+
     /**
      * Delete promo rule.
      *
@@ -925,33 +1097,27 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `promo_rule_id: &str` -- The id for the promo rule of a store.
      * * `store_id: &str` -- The name of the folder.
+     * * `params: &EcommerceGetStoresPromocodeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_promocode(
+    pub async fn get_stores_promocode_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         promo_rule_id: &str,
         store_id: &str,
+        params: &EcommerceGetStoresPromocodeParams,
     ) -> Result<crate::types::PromoCodesData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -965,6 +1131,29 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_promocode_with` and pass the query parameters as a `EcommerceGetStoresPromocodeParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_promocode_with` instead")]
+    pub async fn get_stores_promocode(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        promo_rule_id: &str,
+        store_id: &str,
+    ) -> Result<crate::types::PromoCodesData> {
+        let params = EcommerceGetStoresPromocodeParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_stores_promocode_with(promo_rule_id, store_id, &params)
+            .await
+    }
+
     /**
      * Add promo code.
      *
@@ -1003,25 +1192,23 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `store_id: &str` -- The name of the folder.
      * * `promo_rule_id: &str` -- The id for the promo rule of a store.
      * * `promo_code_id: &str` -- The id for the promo code of a store.
+     * * `params: &EcommerceGetStoresPromocodeEcommerceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_promocode_ecommerce(
+    pub async fn get_stores_promocode_ecommerce_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         store_id: &str,
         promo_rule_id: &str,
         promo_code_id: &str,
+        params: &EcommerceGetStoresPromocodeEcommerceParams,
     ) -> Result<crate::types::PromoCodes> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1036,6 +1223,26 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_promocode_ecommerce_with` and pass the query parameters as a `EcommerceGetStoresPromocodeEcommerceParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_promocode_ecommerce_with` instead")]
+    pub async fn get_stores_promocode_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        promo_rule_id: &str,
+        promo_code_id: &str,
+    ) -> Result<crate::types::PromoCodes> {
+        let params = EcommerceGetStoresPromocodeEcommerceParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_stores_promocode_ecommerce_with(store_id, promo_rule_id, promo_code_id, &params)
+            .await
+    }
+
     /**
      * Delete promo code.
      *
@@ -1106,51 +1313,37 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `store_id: &str` -- The name of the folder.
-     * * `customer_id: &str` -- Restrict results to orders made by a specific customer.
-     * * `has_outreach: bool` -- Restrict results to orders that have an outreach attached. For example, an email campaign or Facebook ad.
-     * * `campaign_id: &str` -- Restrict results to orders with a specific `campaign_id` value.
-     * * `outreach_id: &str` -- Restrict results to orders with a specific `outreach_id` value.
+     * * `params: &EcommerceGetStoresOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_order(
+    pub async fn get_stores_order_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         store_id: &str,
-        customer_id: &str,
-        has_outreach: bool,
-        campaign_id: &str,
-        outreach_id: &str,
+        params: &EcommerceGetStoresOrderParams,
     ) -> Result<crate::types::OrdersDataType> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !campaign_id.is_empty() {
+        if let Some(campaign_id) = &params.campaign_id {
             query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
         }
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !customer_id.is_empty() {
+        if let Some(customer_id) = &params.customer_id {
             query_args.push(("customer_id".to_string(), customer_id.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if has_outreach {
+        if let Some(has_outreach) = &params.has_outreach {
             query_args.push(("has_outreach".to_string(), has_outreach.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !outreach_id.is_empty() {
+        if let Some(outreach_id) = &params.outreach_id {
             query_args.push(("outreach_id".to_string(), outreach_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1163,6 +1356,35 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_order_with` and pass the query parameters as a `EcommerceGetStoresOrderParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_order_with` instead")]
+    pub async fn get_stores_order(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+        campaign_id: &str,
+        outreach_id: &str,
+    ) -> Result<crate::types::OrdersDataType> {
+        let params = EcommerceGetStoresOrderParams {
+            campaign_id: Some(campaign_id.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count).filter(|v| *v > 0),
+            customer_id: Some(customer_id.to_string()).filter(|s| !s.is_empty()),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            has_outreach: Some(has_outreach).filter(|v| *v),
+            offset: Some(offset).filter(|v| *v > 0),
+            outreach_id: Some(outreach_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_stores_order_with(store_id, &params).await
+    }
+
     /**
      * Add order.
      *
@@ -1198,23 +1420,21 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `store_id: &str` -- The name of the folder.
      * * `order_id: &str` -- The id for the order in a store.
+     * * `params: &EcommerceGetStoresOrderEcommerceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_order_ecommerce(
+    pub async fn get_stores_order_ecommerce_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         store_id: &str,
         order_id: &str,
+        params: &EcommerceGetStoresOrderEcommerceParams,
     ) -> Result<crate::types::Orders> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1229,12 +1449,31 @@ impl Ecommerce {
     }
 
     /**
-     * Delete order.
-     *
-     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/orders/{order_id}` endpoint.
-     *
-     * Delete an order.
-     *
+     * Deprecated: use `get_stores_order_ecommerce_with` and pass the query parameters as a `EcommerceGetStoresOrderEcommerceParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_order_ecommerce_with` instead")]
+    pub async fn get_stores_order_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        order_id: &str,
+    ) -> Result<crate::types::Orders> {
+        let params = EcommerceGetStoresOrderEcommerceParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_stores_order_ecommerce_with(store_id, order_id, &params)
+            .await
+    }
+
+    /**
+     * Delete order.
+     *
+     * This function performs a `DELETE` to the `/ecommerce/stores/{store_id}/orders/{order_id}` endpoint.
+     *
+     * Delete an order.
+     *
      * **Parameters:**
      *
      * * `store_id: &str` -- The name of the folder.
@@ -1288,33 +1527,27 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `store_id: &str` -- The name of the folder.
      * * `order_id: &str` -- The id for the order in a store.
+     * * `params: &EcommerceGetStoresOrdersLineParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_orders_line(
+    pub async fn get_stores_orders_line_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         store_id: &str,
         order_id: &str,
+        params: &EcommerceGetStoresOrdersLineParams,
     ) -> Result<crate::types::OrderLines> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1328,6 +1561,29 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_orders_line_with` and pass the query parameters as a `EcommerceGetStoresOrdersLineParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_orders_line_with` instead")]
+    pub async fn get_stores_orders_line(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        order_id: &str,
+    ) -> Result<crate::types::OrderLines> {
+        let params = EcommerceGetStoresOrdersLineParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_stores_orders_line_with(store_id, order_id, &params)
+            .await
+    }
+
     /**
      * Add order line item.
      *
@@ -1366,25 +1622,23 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `store_id: &str` -- The name of the folder.
      * * `order_id: &str` -- The id for the order in a store.
      * * `line_id: &str` -- The id for the line item of an order.
+     * * `params: &EcommerceGetStoresOrdersLineEcommerceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_orders_line_ecommerce(
+    pub async fn get_stores_orders_line_ecommerce_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         store_id: &str,
         order_id: &str,
         line_id: &str,
+        params: &EcommerceGetStoresOrdersLineEcommerceParams,
     ) -> Result<crate::types::Lines> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1399,6 +1653,26 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_orders_line_ecommerce_with` and pass the query parameters as a `EcommerceGetStoresOrdersLineEcommerceParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_orders_line_ecommerce_with` instead")]
+    pub async fn get_stores_orders_line_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        order_id: &str,
+        line_id: &str,
+    ) -> Result<crate::types::Lines> {
+        let params = EcommerceGetStoresOrdersLineEcommerceParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_stores_orders_line_ecommerce_with(store_id, order_id, line_id, &params)
+            .await
+    }
+
     /**
      * Delete order line item.
      *
@@ -1469,31 +1743,25 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `store_id: &str` -- The name of the folder.
+     * * `params: &EcommerceGetStoresProductParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_product(
+    pub async fn get_stores_product_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         store_id: &str,
+        params: &EcommerceGetStoresProductParams,
     ) -> Result<crate::types::ProductsData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1506,6 +1774,27 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_product_with` and pass the query parameters as a `EcommerceGetStoresProductParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_product_with` instead")]
+    pub async fn get_stores_product(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+    ) -> Result<crate::types::ProductsData> {
+        let params = EcommerceGetStoresProductParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_stores_product_with(store_id, &params).await
+    }
+
     /**
      * Add product.
      *
@@ -1541,23 +1830,21 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `store_id: &str` -- The name of the folder.
      * * `product_id: &str` -- The id for the product of a store.
+     * * `params: &EcommerceGetStoresProductEcommerceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_product_ecommerce(
+    pub async fn get_stores_product_ecommerce_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         store_id: &str,
         product_id: &str,
+        params: &EcommerceGetStoresProductEcommerceParams,
     ) -> Result<crate::types::ECommerceProduct> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1571,6 +1858,25 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_product_ecommerce_with` and pass the query parameters as a `EcommerceGetStoresProductEcommerceParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_product_ecommerce_with` instead")]
+    pub async fn get_stores_product_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<crate::types::ECommerceProduct> {
+        let params = EcommerceGetStoresProductEcommerceParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_stores_product_ecommerce_with(store_id, product_id, &params)
+            .await
+    }
+
     /**
      * Delete product.
      *
@@ -1631,33 +1937,27 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `store_id: &str` -- The name of the folder.
      * * `product_id: &str` -- The id for the product of a store.
+     * * `params: &EcommerceGetStoresProductsVariantParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_products_variant(
+    pub async fn get_stores_products_variant_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         store_id: &str,
         product_id: &str,
+        params: &EcommerceGetStoresProductsVariantParams,
     ) -> Result<crate::types::EcommerceProductVariants> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1671,6 +1971,29 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_products_variant_with` and pass the query parameters as a `EcommerceGetStoresProductsVariantParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_products_variant_with` instead")]
+    pub async fn get_stores_products_variant(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<crate::types::EcommerceProductVariants> {
+        let params = EcommerceGetStoresProductsVariantParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_stores_products_variant_with(store_id, product_id, &params)
+            .await
+    }
+
     /**
      * Add product variant.
      *
@@ -1709,25 +2032,23 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `store_id: &str` -- The name of the folder.
      * * `product_id: &str` -- The id for the product of a store.
      * * `variant_id: &str` -- The id for the product variant.
+     * * `params: &EcommerceGetStoresProductsVariantEcommerceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_products_variant_ecommerce(
+    pub async fn get_stores_products_variant_ecommerce_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         store_id: &str,
         product_id: &str,
         variant_id: &str,
+        params: &EcommerceGetStoresProductsVariantEcommerceParams,
     ) -> Result<crate::types::Variants> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1742,6 +2063,26 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_products_variant_ecommerce_with` and pass the query parameters as a `EcommerceGetStoresProductsVariantEcommerceParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_products_variant_ecommerce_with` instead")]
+    pub async fn get_stores_products_variant_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+        variant_id: &str,
+    ) -> Result<crate::types::Variants> {
+        let params = EcommerceGetStoresProductsVariantEcommerceParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_stores_products_variant_ecommerce_with(store_id, product_id, variant_id, &params)
+            .await
+    }
+
     /**
      * Add or update product variant.
      *
@@ -1844,33 +2185,27 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `store_id: &str` -- The name of the folder.
      * * `product_id: &str` -- The id for the product of a store.
+     * * `params: &EcommerceGetStoresProductsImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_products_image(
+    pub async fn get_stores_products_image_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         store_id: &str,
         product_id: &str,
+        params: &EcommerceGetStoresProductsImageParams,
     ) -> Result<crate::types::EcommerceProductImages> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1884,6 +2219,29 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_products_image_with` and pass the query parameters as a `EcommerceGetStoresProductsImageParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_products_image_with` instead")]
+    pub async fn get_stores_products_image(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<crate::types::EcommerceProductImages> {
+        let params = EcommerceGetStoresProductsImageParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_stores_products_image_with(store_id, product_id, &params)
+            .await
+    }
+
     /**
      * Add product image.
      *
@@ -1922,25 +2280,23 @@ impl Ecommerce {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `store_id: &str` -- The name of the folder.
      * * `product_id: &str` -- The id for the product of a store.
      * * `image_id: &str` -- The id for the product image.
+     * * `params: &EcommerceGetStoresProductsImageEcommerceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stores_products_image_ecommerce(
+    pub async fn get_stores_products_image_ecommerce_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         store_id: &str,
         product_id: &str,
         image_id: &str,
+        params: &EcommerceGetStoresProductsImageEcommerceParams,
     ) -> Result<crate::types::Images> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1955,6 +2311,26 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stores_products_image_ecommerce_with` and pass the query parameters as a `EcommerceGetStoresProductsImageEcommerceParams` instead.
+     */
+    #[deprecated(note = "use `get_stores_products_image_ecommerce_with` instead")]
+    pub async fn get_stores_products_image_ecommerce(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<crate::types::Images> {
+        let params = EcommerceGetStoresProductsImageEcommerceParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_stores_products_image_ecommerce_with(store_id, product_id, image_id, &params)
+            .await
+    }
+
     /**
      * Delete product image.
      *
@@ -2016,3 +2392,717 @@ impl Ecommerce {
             .await
     }
 }
+
+/// The query parameters of `Ecommerce::get_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetOrderParams {
+    /// Restrict results to orders with a specific `campaign_id` value.
+    pub campaign_id: Option<String>,
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// Restrict results to orders made by a specific customer.
+    pub customer_id: Option<String>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Restrict results to orders that have an outreach attached. For example, an email campaign or Facebook ad.
+    pub has_outreach: Option<bool>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Restrict results to orders with a specific `outreach_id` value.
+    pub outreach_id: Option<String>,
+}
+
+impl EcommerceGetOrderParams {
+    pub fn campaign_id<T: ToString>(mut self, campaign_id: T) -> Self {
+        self.campaign_id = Some(campaign_id.to_string());
+        self
+    }
+
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn customer_id<T: ToString>(mut self, customer_id: T) -> Self {
+        self.customer_id = Some(customer_id.to_string());
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn has_outreach(mut self, has_outreach: bool) -> Self {
+        self.has_outreach = Some(has_outreach);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn outreach_id<T: ToString>(mut self, outreach_id: T) -> Self {
+        self.outreach_id = Some(outreach_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_store_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoreParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl EcommerceGetStoreParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_store_ecommerce_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoreEcommerceParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl EcommerceGetStoreEcommerceParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_cart_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresCartParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl EcommerceGetStoresCartParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_cart_ecommerce_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresCartEcommerceParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl EcommerceGetStoresCartEcommerceParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_carts_line_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresCartsLineParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl EcommerceGetStoresCartsLineParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_carts_line_ecommerce_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresCartsLineEcommerceParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl EcommerceGetStoresCartsLineEcommerceParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_customer_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresCustomerParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// Restrict the response to customers with the email address.
+    pub email_address: Option<String>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl EcommerceGetStoresCustomerParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn email_address<T: ToString>(mut self, email_address: T) -> Self {
+        self.email_address = Some(email_address.to_string());
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_customer_ecommerce_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresCustomerEcommerceParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl EcommerceGetStoresCustomerEcommerceParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_promorule_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresPromoruleParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl EcommerceGetStoresPromoruleParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_promorule_ecommerce_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresPromoruleEcommerceParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl EcommerceGetStoresPromoruleEcommerceParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_promocode_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresPromocodeParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl EcommerceGetStoresPromocodeParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_promocode_ecommerce_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresPromocodeEcommerceParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl EcommerceGetStoresPromocodeEcommerceParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresOrderParams {
+    /// Restrict results to orders with a specific `campaign_id` value.
+    pub campaign_id: Option<String>,
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// Restrict results to orders made by a specific customer.
+    pub customer_id: Option<String>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Restrict results to orders that have an outreach attached. For example, an email campaign or Facebook ad.
+    pub has_outreach: Option<bool>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Restrict results to orders with a specific `outreach_id` value.
+    pub outreach_id: Option<String>,
+}
+
+impl EcommerceGetStoresOrderParams {
+    pub fn campaign_id<T: ToString>(mut self, campaign_id: T) -> Self {
+        self.campaign_id = Some(campaign_id.to_string());
+        self
+    }
+
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn customer_id<T: ToString>(mut self, customer_id: T) -> Self {
+        self.customer_id = Some(customer_id.to_string());
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn has_outreach(mut self, has_outreach: bool) -> Self {
+        self.has_outreach = Some(has_outreach);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn outreach_id<T: ToString>(mut self, outreach_id: T) -> Self {
+        self.outreach_id = Some(outreach_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_order_ecommerce_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresOrderEcommerceParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl EcommerceGetStoresOrderEcommerceParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_orders_line_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresOrdersLineParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl EcommerceGetStoresOrdersLineParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_orders_line_ecommerce_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresOrdersLineEcommerceParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl EcommerceGetStoresOrdersLineEcommerceParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_product_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresProductParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl EcommerceGetStoresProductParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_product_ecommerce_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresProductEcommerceParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl EcommerceGetStoresProductEcommerceParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_products_variant_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresProductsVariantParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl EcommerceGetStoresProductsVariantParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_products_variant_ecommerce_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresProductsVariantEcommerceParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl EcommerceGetStoresProductsVariantEcommerceParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_products_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresProductsImageParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl EcommerceGetStoresProductsImageParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Ecommerce::get_stores_products_image_ecommerce_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EcommerceGetStoresProductsImageEcommerceParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl EcommerceGetStoresProductsImageEcommerceParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct FacebookAds {
     pub client: Client,
@@ -21,39 +19,29 @@ impl FacebookAds {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `sort_field: crate::types::GetAllFacebookAdsSortField` -- Returns files sorted by the specified field.
-     * * `sort_dir: crate::types::SortDir` -- Determines the order direction for sorted results.
+     * * `params: &FacebookAdsGetAllParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        sort_field: crate::types::GetAllFacebookAdsSortField,
-        sort_dir: crate::types::SortDir,
+        params: &FacebookAdsGetAllParams,
     ) -> Result<crate::types::GetAllFacebookAdsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !sort_dir.to_string().is_empty() {
+        if let Some(sort_dir) = &params.sort_dir {
             query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
         }
-        if !sort_field.to_string().is_empty() {
+        if let Some(sort_field) = &params.sort_field {
             query_args.push(("sort_field".to_string(), sort_field.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -62,6 +50,30 @@ impl FacebookAds {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `FacebookAdsGetAllParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::GetAllFacebookAdsResponse> {
+        let params = FacebookAdsGetAllParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            sort_dir: Some(sort_dir).filter(|v| !v.to_string().is_empty()),
+            sort_field: Some(sort_field).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_all_with(&params).await
+    }
+
     /**
      * Get facebook ad info.
      *
@@ -71,21 +83,19 @@ impl FacebookAds {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `outreach_id: &str` -- The name of the folder.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `params: &FacebookAdsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        fields: &[String],
         outreach_id: &str,
-        exclude_fields: &[String],
+        params: &FacebookAdsGetParams,
     ) -> Result<crate::types::FacebookAdsAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -97,4 +107,91 @@ impl FacebookAds {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `FacebookAdsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> Result<crate::types::FacebookAdsAllOf> {
+        let params = FacebookAdsGetParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_with(outreach_id, &params).await
+    }
+}
+
+/// The query parameters of `FacebookAds::get_all_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FacebookAdsGetAllParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Determines the order direction for sorted results.
+    pub sort_dir: Option<crate::types::SortDir>,
+    /// Returns files sorted by the specified field.
+    pub sort_field: Option<crate::types::GetAllFacebookAdsSortField>,
+}
+
+impl FacebookAdsGetAllParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn sort_field(mut self, sort_field: crate::types::GetAllFacebookAdsSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+}
+
+/// The query parameters of `FacebookAds::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FacebookAdsGetParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl FacebookAdsGetParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct FileManager {
     pub client: Client,
@@ -21,62 +19,44 @@ impl FileManager {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `type_: &str` -- The file type for the File Manager file.
-     * * `created_by: &str` -- The Mailchimp account user who created the File Manager file.
-     * * `before_created_at: &str` -- Restrict the response to files created before the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `since_created_at: &str` -- Restrict the response to files created after the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `sort_field: crate::types::GetFileManagerFilesSortField` -- Returns files sorted by the specified field.
-     * * `sort_dir: crate::types::SortDir` -- Determines the order direction for sorted results.
+     * * `params: &FileManagerGetFileParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_file(
+    pub async fn get_file_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        type_: &str,
-        created_by: &str,
-        before_created_at: &str,
-        since_created_at: &str,
-        sort_field: crate::types::GetFileManagerFilesSortField,
-        sort_dir: crate::types::SortDir,
+        params: &FileManagerGetFileParams,
     ) -> Result<crate::types::FileManager> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !before_created_at.is_empty() {
+        if let Some(before_created_at) = &params.before_created_at {
             query_args.push((
                 "before_created_at".to_string(),
                 before_created_at.to_string(),
             ));
         }
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !created_by.is_empty() {
+        if let Some(created_by) = &params.created_by {
             query_args.push(("created_by".to_string(), created_by.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !since_created_at.is_empty() {
+        if let Some(since_created_at) = &params.since_created_at {
             query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
         }
-        if !sort_dir.to_string().is_empty() {
+        if let Some(sort_dir) = &params.sort_dir {
             query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
         }
-        if !sort_field.to_string().is_empty() {
+        if let Some(sort_field) = &params.sort_field {
             query_args.push(("sort_field".to_string(), sort_field.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -85,6 +65,38 @@ impl FileManager {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_file_with` and pass the query parameters as a `FileManagerGetFileParams` instead.
+     */
+    #[deprecated(note = "use `get_file_with` instead")]
+    pub async fn get_file(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+        sort_field: crate::types::GetFileManagerFilesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::FileManager> {
+        let params = FileManagerGetFileParams {
+            before_created_at: Some(before_created_at.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count).filter(|v| *v > 0),
+            created_by: Some(created_by.to_string()).filter(|s| !s.is_empty()),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            since_created_at: Some(since_created_at.to_string()).filter(|s| !s.is_empty()),
+            sort_dir: Some(sort_dir).filter(|v| !v.to_string().is_empty()),
+            sort_field: Some(sort_field).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_file_with(&params).await
+    }
+
     /**
      * Add file.
      *
@@ -108,21 +120,19 @@ impl FileManager {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `file_id: &str` -- The unique id for the File Manager file.
+     * * `params: &FileManagerGetFileFileManagerParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_file_file_manager(
+    pub async fn get_file_file_manager_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         file_id: &str,
+        params: &FileManagerGetFileFileManagerParams,
     ) -> Result<crate::types::Files> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -135,6 +145,23 @@ impl FileManager {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_file_file_manager_with` and pass the query parameters as a `FileManagerGetFileFileManagerParams` instead.
+     */
+    #[deprecated(note = "use `get_file_file_manager_with` instead")]
+    pub async fn get_file_file_manager(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        file_id: &str,
+    ) -> Result<crate::types::Files> {
+        let params = FileManagerGetFileFileManagerParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_file_file_manager_with(file_id, &params).await
+    }
+
     /**
      * Delete file.
      *
@@ -190,47 +217,35 @@ impl FileManager {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `created_by: &str` -- The Mailchimp account user who created the File Manager file.
-     * * `before_created_at: &str` -- Restrict the response to files created before the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `since_created_at: &str` -- Restrict the response to files created after the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * * `params: &FileManagerGetFolderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_folder(
+    pub async fn get_folder_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        created_by: &str,
-        before_created_at: &str,
-        since_created_at: &str,
+        params: &FileManagerGetFolderParams,
     ) -> Result<crate::types::FileManagerFolders> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !before_created_at.is_empty() {
+        if let Some(before_created_at) = &params.before_created_at {
             query_args.push((
                 "before_created_at".to_string(),
                 before_created_at.to_string(),
             ));
         }
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !created_by.is_empty() {
+        if let Some(created_by) = &params.created_by {
             query_args.push(("created_by".to_string(), created_by.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !since_created_at.is_empty() {
+        if let Some(since_created_at) = &params.since_created_at {
             query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -239,6 +254,32 @@ impl FileManager {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_folder_with` and pass the query parameters as a `FileManagerGetFolderParams` instead.
+     */
+    #[deprecated(note = "use `get_folder_with` instead")]
+    pub async fn get_folder(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+    ) -> Result<crate::types::FileManagerFolders> {
+        let params = FileManagerGetFolderParams {
+            before_created_at: Some(before_created_at.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count).filter(|v| *v > 0),
+            created_by: Some(created_by.to_string()).filter(|s| !s.is_empty()),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            since_created_at: Some(since_created_at.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_folder_with(&params).await
+    }
+
     /**
      * Add folder.
      *
@@ -265,21 +306,19 @@ impl FileManager {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `folder_id: &str` -- The unique id for the File Manager folder.
+     * * `params: &FileManagerGetFolderFileManagerParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_folder_file_manager(
+    pub async fn get_folder_file_manager_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         folder_id: &str,
+        params: &FileManagerGetFolderFileManagerParams,
     ) -> Result<crate::types::FileManagerFoldersGalleryFolder> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -292,6 +331,23 @@ impl FileManager {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_folder_file_manager_with` and pass the query parameters as a `FileManagerGetFolderFileManagerParams` instead.
+     */
+    #[deprecated(note = "use `get_folder_file_manager_with` instead")]
+    pub async fn get_folder_file_manager(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        folder_id: &str,
+    ) -> Result<crate::types::FileManagerFoldersGalleryFolder> {
+        let params = FileManagerGetFolderFileManagerParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_folder_file_manager_with(folder_id, &params).await
+    }
+
     /**
      * Delete folder.
      *
@@ -338,3 +394,178 @@ impl FileManager {
             .await
     }
 }
+
+/// The query parameters of `FileManager::get_file_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FileManagerGetFileParams {
+    /// Restrict the response to files created before the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_created_at: Option<String>,
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// The Mailchimp account user who created the File Manager file.
+    pub created_by: Option<String>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Restrict the response to files created after the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_created_at: Option<String>,
+    /// Determines the order direction for sorted results.
+    pub sort_dir: Option<crate::types::SortDir>,
+    /// Returns files sorted by the specified field.
+    pub sort_field: Option<crate::types::GetFileManagerFilesSortField>,
+    /// The file type for the File Manager file.
+    pub type_: Option<String>,
+}
+
+impl FileManagerGetFileParams {
+    pub fn before_created_at<T: ToString>(mut self, before_created_at: T) -> Self {
+        self.before_created_at = Some(before_created_at.to_string());
+        self
+    }
+
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn created_by<T: ToString>(mut self, created_by: T) -> Self {
+        self.created_by = Some(created_by.to_string());
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn since_created_at<T: ToString>(mut self, since_created_at: T) -> Self {
+        self.since_created_at = Some(since_created_at.to_string());
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn sort_field(mut self, sort_field: crate::types::GetFileManagerFilesSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `FileManager::get_file_file_manager_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FileManagerGetFileFileManagerParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl FileManagerGetFileFileManagerParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `FileManager::get_folder_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FileManagerGetFolderParams {
+    /// Restrict the response to files created before the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_created_at: Option<String>,
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// The Mailchimp account user who created the File Manager file.
+    pub created_by: Option<String>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Restrict the response to files created after the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_created_at: Option<String>,
+}
+
+impl FileManagerGetFolderParams {
+    pub fn before_created_at<T: ToString>(mut self, before_created_at: T) -> Self {
+        self.before_created_at = Some(before_created_at.to_string());
+        self
+    }
+
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn created_by<T: ToString>(mut self, created_by: T) -> Self {
+        self.created_by = Some(created_by.to_string());
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn since_created_at<T: ToString>(mut self, since_created_at: T) -> Self {
+        self.since_created_at = Some(since_created_at.to_string());
+        self
+    }
+}
+
+/// The query parameters of `FileManager::get_folder_file_manager_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FileManagerGetFolderFileManagerParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl FileManagerGetFolderFileManagerParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
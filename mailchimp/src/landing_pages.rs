@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct LandingPages {
     pub client: Client,
@@ -21,34 +19,26 @@ impl LandingPages {
      *
      * **Parameters:**
      *
-     * * `sort_dir: crate::types::SortDir` -- Determines the order direction for sorted results.
-     * * `sort_field: crate::types::GetAllLandingPagesSortField` -- Returns files sorted by the specified field.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
+     * * `params: &LandingPagesGetAllParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        sort_dir: crate::types::SortDir,
-        sort_field: crate::types::GetAllLandingPagesSortField,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
+        params: &LandingPagesGetAllParams,
     ) -> Result<crate::types::GetAllLandingPagesResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if !sort_dir.to_string().is_empty() {
+        if let Some(sort_dir) = &params.sort_dir {
             query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
         }
-        if !sort_field.to_string().is_empty() {
+        if let Some(sort_field) = &params.sort_field {
             query_args.push(("sort_field".to_string(), sort_field.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -57,6 +47,28 @@ impl LandingPages {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `LandingPagesGetAllParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        sort_dir: crate::types::SortDir,
+        sort_field: crate::types::GetAllLandingPagesSortField,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+    ) -> Result<crate::types::GetAllLandingPagesResponse> {
+        let params = LandingPagesGetAllParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            sort_dir: Some(sort_dir).filter(|v| !v.to_string().is_empty()),
+            sort_field: Some(sort_field).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_all_with(&params).await
+    }
+
     /**
      * Add landing page.
      *
@@ -66,15 +78,15 @@ impl LandingPages {
      *
      * **Parameters:**
      *
-     * * `use_default_list: bool` -- Will create the Landing Page using the account's Default List instead of requiring a list_id.
+     * * `params: &LandingPagesPostAllParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn post_all(
+    pub async fn post_all_with(
         &self,
-        use_default_list: bool,
+        params: &LandingPagesPostAllParams,
         body: &crate::types::LandingPageData,
     ) -> Result<crate::types::LandingPage> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if use_default_list {
+        if let Some(use_default_list) = &params.use_default_list {
             query_args.push(("use_default_list".to_string(), use_default_list.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -85,6 +97,21 @@ impl LandingPages {
             .await
     }
 
+    /**
+     * Deprecated: use `post_all_with` and pass the query parameters as a `LandingPagesPostAllParams` instead.
+     */
+    #[deprecated(note = "use `post_all_with` instead")]
+    pub async fn post_all(
+        &self,
+        use_default_list: bool,
+        body: &crate::types::LandingPageData,
+    ) -> Result<crate::types::LandingPage> {
+        let params = LandingPagesPostAllParams {
+            use_default_list: Some(use_default_list).filter(|v| *v),
+        };
+        self.post_all_with(&params, body).await
+    }
+
     /**
      * Get landing page info.
      *
@@ -94,21 +121,19 @@ impl LandingPages {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `page_id: &str` -- The unique id for the page.
+     * * `params: &LandingPagesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         page_id: &str,
+        params: &LandingPagesGetParams,
     ) -> Result<crate::types::LandingPage> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -121,6 +146,23 @@ impl LandingPages {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `LandingPagesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        page_id: &str,
+    ) -> Result<crate::types::LandingPage> {
+        let params = LandingPagesGetParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_with(page_id, &params).await
+    }
+
     /**
      * Delete landing page.
      *
@@ -216,21 +258,19 @@ impl LandingPages {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `page_id: &str` -- The unique id for the page.
+     * * `params: &LandingPagesGetContentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_content(
+    pub async fn get_content_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         page_id: &str,
+        params: &LandingPagesGetContentParams,
     ) -> Result<crate::types::LandingPageContent> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -242,4 +282,119 @@ impl LandingPages {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_content_with` and pass the query parameters as a `LandingPagesGetContentParams` instead.
+     */
+    #[deprecated(note = "use `get_content_with` instead")]
+    pub async fn get_content(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        page_id: &str,
+    ) -> Result<crate::types::LandingPageContent> {
+        let params = LandingPagesGetContentParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_content_with(page_id, &params).await
+    }
+}
+
+/// The query parameters of `LandingPages::get_all_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct LandingPagesGetAllParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Determines the order direction for sorted results.
+    pub sort_dir: Option<crate::types::SortDir>,
+    /// Returns files sorted by the specified field.
+    pub sort_field: Option<crate::types::GetAllLandingPagesSortField>,
+}
+
+impl LandingPagesGetAllParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn sort_field(mut self, sort_field: crate::types::GetAllLandingPagesSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+}
+
+/// The query parameters of `LandingPages::post_all_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct LandingPagesPostAllParams {
+    /// Will create the Landing Page using the account's Default List instead of requiring a list_id.
+    pub use_default_list: Option<bool>,
+}
+
+impl LandingPagesPostAllParams {
+    pub fn use_default_list(mut self, use_default_list: bool) -> Self {
+        self.use_default_list = Some(use_default_list);
+        self
+    }
+}
+
+/// The query parameters of `LandingPages::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct LandingPagesGetParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl LandingPagesGetParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `LandingPages::get_content_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct LandingPagesGetContentParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl LandingPagesGetContentParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Lists {
     pub client: Client,
@@ -21,92 +19,65 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `before_date_created: &str` -- Restrict response to lists created before the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `since_date_created: &str` -- Restrict results to lists created after the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `before_campaign_last_sent: &str` -- Restrict results to lists created before the last campaign send date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `since_campaign_last_sent: &str` -- Restrict results to lists created after the last campaign send date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `email: &str` -- Restrict results to lists that include a specific subscriber's email address.
-     * * `sort_field: crate::types::GetListsSortField` -- Returns files sorted by the specified field.
-     * * `sort_dir: crate::types::SortDir` -- Determines the order direction for sorted results.
-     * * `has_ecommerce_store: bool` -- Restrict results to lists that contain an active, connected, undeleted ecommerce store.
-     * * `include_total_contacts: bool` -- Return the total_contacts field in the stats response, which contains an approximate count of all contacts in any state.
+     * * `params: &ListsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
-        &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        before_date_created: &str,
-        since_date_created: &str,
-        before_campaign_last_sent: &str,
-        since_campaign_last_sent: &str,
-        email: &str,
-        sort_field: crate::types::GetListsSortField,
-        sort_dir: crate::types::SortDir,
-        has_ecommerce_store: bool,
-        include_total_contacts: bool,
-    ) -> Result<crate::types::SubscriberLists> {
+    pub async fn get_with(&self, params: &ListsGetParams) -> Result<crate::types::SubscriberLists> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !before_campaign_last_sent.is_empty() {
+        if let Some(before_campaign_last_sent) = &params.before_campaign_last_sent {
             query_args.push((
                 "before_campaign_last_sent".to_string(),
                 before_campaign_last_sent.to_string(),
             ));
         }
-        if !before_date_created.is_empty() {
+        if let Some(before_date_created) = &params.before_date_created {
             query_args.push((
                 "before_date_created".to_string(),
                 before_date_created.to_string(),
             ));
         }
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !email.is_empty() {
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if has_ecommerce_store {
+        if let Some(has_ecommerce_store) = &params.has_ecommerce_store {
             query_args.push((
                 "has_ecommerce_store".to_string(),
                 has_ecommerce_store.to_string(),
             ));
         }
-        if include_total_contacts {
+        if let Some(include_total_contacts) = &params.include_total_contacts {
             query_args.push((
                 "include_total_contacts".to_string(),
                 include_total_contacts.to_string(),
             ));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !since_campaign_last_sent.is_empty() {
+        if let Some(since_campaign_last_sent) = &params.since_campaign_last_sent {
             query_args.push((
                 "since_campaign_last_sent".to_string(),
                 since_campaign_last_sent.to_string(),
             ));
         }
-        if !since_date_created.is_empty() {
+        if let Some(since_date_created) = &params.since_date_created {
             query_args.push((
                 "since_date_created".to_string(),
                 since_date_created.to_string(),
             ));
         }
-        if !sort_dir.to_string().is_empty() {
+        if let Some(sort_dir) = &params.sort_dir {
             query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
         }
-        if !sort_field.to_string().is_empty() {
+        if let Some(sort_field) = &params.sort_field {
             query_args.push(("sort_field".to_string(), sort_field.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -115,6 +86,46 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `ListsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        before_date_created: &str,
+        since_date_created: &str,
+        before_campaign_last_sent: &str,
+        since_campaign_last_sent: &str,
+        email: &str,
+        sort_field: crate::types::GetListsSortField,
+        sort_dir: crate::types::SortDir,
+        has_ecommerce_store: bool,
+        include_total_contacts: bool,
+    ) -> Result<crate::types::SubscriberLists> {
+        let params = ListsGetParams {
+            before_campaign_last_sent: Some(before_campaign_last_sent.to_string())
+                .filter(|s| !s.is_empty()),
+            before_date_created: Some(before_date_created.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count).filter(|v| *v > 0),
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            has_ecommerce_store: Some(has_ecommerce_store).filter(|v| *v),
+            include_total_contacts: Some(include_total_contacts).filter(|v| *v),
+            offset: Some(offset).filter(|v| *v > 0),
+            since_campaign_last_sent: Some(since_campaign_last_sent.to_string())
+                .filter(|s| !s.is_empty()),
+            since_date_created: Some(since_date_created.to_string()).filter(|s| !s.is_empty()),
+            sort_dir: Some(sort_dir).filter(|v| !v.to_string().is_empty()),
+            sort_field: Some(sort_field).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Add list.
      *
@@ -138,26 +149,22 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `list_id: &str` -- The unique ID for the list.
-     * * `include_total_contacts: bool` -- Return the total_contacts field in the stats response, which contains an approximate count of all contacts in any state.
+     * * `params: &ListsGetListsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_lists(
+    pub async fn get_lists_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         list_id: &str,
-        include_total_contacts: bool,
+        params: &ListsGetListsParams,
     ) -> Result<crate::types::Lists> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if include_total_contacts {
+        if let Some(include_total_contacts) = &params.include_total_contacts {
             query_args.push((
                 "include_total_contacts".to_string(),
                 include_total_contacts.to_string(),
@@ -173,6 +180,25 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_lists_with` and pass the query parameters as a `ListsGetListsParams` instead.
+     */
+    #[deprecated(note = "use `get_lists_with` instead")]
+    pub async fn get_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        include_total_contacts: bool,
+    ) -> Result<crate::types::Lists> {
+        let params = ListsGetListsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            include_total_contacts: Some(include_total_contacts).filter(|v| *v),
+        };
+        self.get_lists_with(list_id, &params).await
+    }
+
     /**
      * Batch subscribe or unsubscribe.
      *
@@ -183,24 +209,22 @@ impl Lists {
      * **Parameters:**
      *
      * * `list_id: &str` -- The unique ID for the list.
-     * * `skip_merge_validation: bool` -- If skip_merge_validation is true, member data will be accepted without merge field values, even if the merge field is usually required. This defaults to false.
-     * * `skip_duplicate_check: bool` -- If skip_duplicate_check is true, we will ignore duplicates sent in the request when using the batch sub/unsub on the lists endpoint. The status of the first appearance in the request will be saved. This defaults to false.
+     * * `params: &ListsPostListsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn post_lists(
+    pub async fn post_lists_with(
         &self,
         list_id: &str,
-        skip_merge_validation: bool,
-        skip_duplicate_check: bool,
+        params: &ListsPostListsParams,
         body: &crate::types::MembersSubscribeUnsubscribeFromAListInBatch,
     ) -> Result<crate::types::BatchUpdateListMembers> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if skip_duplicate_check {
+        if let Some(skip_duplicate_check) = &params.skip_duplicate_check {
             query_args.push((
                 "skip_duplicate_check".to_string(),
                 skip_duplicate_check.to_string(),
             ));
         }
-        if skip_merge_validation {
+        if let Some(skip_merge_validation) = &params.skip_merge_validation {
             query_args.push((
                 "skip_merge_validation".to_string(),
                 skip_merge_validation.to_string(),
@@ -218,6 +242,24 @@ impl Lists {
             .await
     }
 
+    /**
+     * Deprecated: use `post_lists_with` and pass the query parameters as a `ListsPostListsParams` instead.
+     */
+    #[deprecated(note = "use `post_lists_with` instead")]
+    pub async fn post_lists(
+        &self,
+        list_id: &str,
+        skip_merge_validation: bool,
+        skip_duplicate_check: bool,
+        body: &crate::types::MembersSubscribeUnsubscribeFromAListInBatch,
+    ) -> Result<crate::types::BatchUpdateListMembers> {
+        let params = ListsPostListsParams {
+            skip_duplicate_check: Some(skip_duplicate_check).filter(|v| *v),
+            skip_merge_validation: Some(skip_merge_validation).filter(|v| *v),
+        };
+        self.post_lists_with(list_id, &params, body).await
+    }
+
     /**
      * Delete list.
      *
@@ -267,31 +309,25 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `list_id: &str` -- The unique ID for the list.
+     * * `params: &ListsGetAbuseReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_abuse_report(
+    pub async fn get_abuse_report_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         list_id: &str,
+        params: &ListsGetAbuseReportParams,
     ) -> Result<crate::types::AbuseComplaints> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -304,6 +340,27 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_abuse_report_with` and pass the query parameters as a `ListsGetAbuseReportParams` instead.
+     */
+    #[deprecated(note = "use `get_abuse_report_with` instead")]
+    pub async fn get_abuse_report(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+    ) -> Result<crate::types::AbuseComplaints> {
+        let params = ListsGetAbuseReportParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_abuse_report_with(list_id, &params).await
+    }
+
     /**
      * Get abuse report.
      *
@@ -313,33 +370,27 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `list_id: &str` -- The unique ID for the list.
      * * `report_id: &str` -- The id for the abuse report.
+     * * `params: &ListsGetAbuseReportListsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_abuse_report_lists(
+    pub async fn get_abuse_report_lists_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         list_id: &str,
         report_id: &str,
+        params: &ListsGetAbuseReportListsParams,
     ) -> Result<crate::types::AbuseReports> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -353,6 +404,29 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_abuse_report_lists_with` and pass the query parameters as a `ListsGetAbuseReportListsParams` instead.
+     */
+    #[deprecated(note = "use `get_abuse_report_lists_with` instead")]
+    pub async fn get_abuse_report_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        report_id: &str,
+    ) -> Result<crate::types::AbuseReports> {
+        let params = ListsGetAbuseReportListsParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_abuse_report_lists_with(list_id, report_id, &params)
+            .await
+    }
+
     /**
      * List recent activity.
      *
@@ -362,21 +436,19 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `list_id: &str` -- The unique ID for the list.
+     * * `params: &ListsGetActivityParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_activity(
+    pub async fn get_activity_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         list_id: &str,
+        params: &ListsGetActivityParams,
     ) -> Result<crate::types::ListActivity> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -389,6 +461,23 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_activity_with` and pass the query parameters as a `ListsGetActivityParams` instead.
+     */
+    #[deprecated(note = "use `get_activity_with` instead")]
+    pub async fn get_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> Result<crate::types::ListActivity> {
+        let params = ListsGetActivityParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_activity_with(list_id, &params).await
+    }
+
     /**
      * List top email clients.
      *
@@ -398,21 +487,19 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `list_id: &str` -- The unique ID for the list.
+     * * `params: &ListsGetClientParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_client(
+    pub async fn get_client_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         list_id: &str,
+        params: &ListsGetClientParams,
     ) -> Result<crate::types::EmailClients> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -425,6 +512,23 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_client_with` and pass the query parameters as a `ListsGetClientParams` instead.
+     */
+    #[deprecated(note = "use `get_client_with` instead")]
+    pub async fn get_client(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> Result<crate::types::EmailClients> {
+        let params = ListsGetClientParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_client_with(list_id, &params).await
+    }
+
     /**
      * List growth history data.
      *
@@ -434,41 +538,31 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `list_id: &str` -- The unique ID for the list.
-     * * `sort_field: crate::types::GetListsGrowthHistorySortField` -- Returns files sorted by the specified field.
-     * * `sort_dir: crate::types::SortDir` -- Determines the order direction for sorted results.
+     * * `params: &ListsGetGrowthHistoryParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_growth_history(
+    pub async fn get_growth_history_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         list_id: &str,
-        sort_field: crate::types::GetListsGrowthHistorySortField,
-        sort_dir: crate::types::SortDir,
+        params: &ListsGetGrowthHistoryParams,
     ) -> Result<crate::types::GrowthHistory> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !sort_dir.to_string().is_empty() {
+        if let Some(sort_dir) = &params.sort_dir {
             query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
         }
-        if !sort_field.to_string().is_empty() {
+        if let Some(sort_field) = &params.sort_field {
             query_args.push(("sort_field".to_string(), sort_field.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -481,6 +575,31 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_growth_history_with` and pass the query parameters as a `ListsGetGrowthHistoryParams` instead.
+     */
+    #[deprecated(note = "use `get_growth_history_with` instead")]
+    pub async fn get_growth_history(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        sort_field: crate::types::GetListsGrowthHistorySortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::GrowthHistory> {
+        let params = ListsGetGrowthHistoryParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            sort_dir: Some(sort_dir).filter(|v| !v.to_string().is_empty()),
+            sort_field: Some(sort_field).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_growth_history_with(list_id, &params).await
+    }
+
     /**
      * Get growth history by month.
      *
@@ -490,23 +609,21 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `list_id: &str` -- The unique ID for the list.
      * * `month: &str` -- A specific month of list growth history.
+     * * `params: &ListsGetGrowthHistoryListsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_growth_history_lists(
+    pub async fn get_growth_history_lists_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         list_id: &str,
         month: &str,
+        params: &ListsGetGrowthHistoryListsParams,
     ) -> Result<crate::types::History> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -520,6 +637,25 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_growth_history_lists_with` and pass the query parameters as a `ListsGetGrowthHistoryListsParams` instead.
+     */
+    #[deprecated(note = "use `get_growth_history_lists_with` instead")]
+    pub async fn get_growth_history_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        month: &str,
+    ) -> Result<crate::types::History> {
+        let params = ListsGetGrowthHistoryListsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_growth_history_lists_with(list_id, month, &params)
+            .await
+    }
+
     /**
      * List interest categories.
      *
@@ -530,35 +666,27 @@ impl Lists {
      * **Parameters:**
      *
      * * `list_id: &str` -- The unique ID for the list.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `type_: &str` -- Restrict results a type of interest group.
+     * * `params: &ListsGetInterestCategorieParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_interest_categorie(
+    pub async fn get_interest_categorie_with(
         &self,
         list_id: &str,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        type_: &str,
+        params: &ListsGetInterestCategorieParams,
     ) -> Result<crate::types::InterestGroupings> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -571,6 +699,29 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_interest_categorie_with` and pass the query parameters as a `ListsGetInterestCategorieParams` instead.
+     */
+    #[deprecated(note = "use `get_interest_categorie_with` instead")]
+    pub async fn get_interest_categorie(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+    ) -> Result<crate::types::InterestGroupings> {
+        let params = ListsGetInterestCategorieParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_interest_categorie_with(list_id, &params).await
+    }
+
     /**
      * Add interest category.
      *
@@ -608,21 +759,19 @@ impl Lists {
      *
      * * `list_id: &str` -- The unique ID for the list.
      * * `interest_category_id: &str` -- The unique ID for the interest category.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `params: &ListsGetInterestCategorieListsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_interest_categorie_lists(
+    pub async fn get_interest_categorie_lists_with(
         &self,
         list_id: &str,
         interest_category_id: &str,
-        fields: &[String],
-        exclude_fields: &[String],
+        params: &ListsGetInterestCategorieListsParams,
     ) -> Result<crate::types::Categories> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -636,6 +785,25 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_interest_categorie_lists_with` and pass the query parameters as a `ListsGetInterestCategorieListsParams` instead.
+     */
+    #[deprecated(note = "use `get_interest_categorie_lists_with` instead")]
+    pub async fn get_interest_categorie_lists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::Categories> {
+        let params = ListsGetInterestCategorieListsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_interest_categorie_lists_with(list_id, interest_category_id, &params)
+            .await
+    }
+
     /**
      * Delete interest category.
      *
@@ -702,31 +870,25 @@ impl Lists {
      *
      * * `list_id: &str` -- The unique ID for the list.
      * * `interest_category_id: &str` -- The unique ID for the interest category.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `params: &ListsGetInterestCategoriesInterestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_interest_categories_interest(
+    pub async fn get_interest_categories_interest_with(
         &self,
         list_id: &str,
         interest_category_id: &str,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
+        params: &ListsGetInterestCategoriesInterestParams,
     ) -> Result<crate::types::InterestsData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -740,6 +902,29 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_interest_categories_interest_with` and pass the query parameters as a `ListsGetInterestCategoriesInterestParams` instead.
+     */
+    #[deprecated(note = "use `get_interest_categories_interest_with` instead")]
+    pub async fn get_interest_categories_interest(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::InterestsData> {
+        let params = ListsGetInterestCategoriesInterestParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_interest_categories_interest_with(list_id, interest_category_id, &params)
+            .await
+    }
+
     /**
      * Add interest in category.
      *
@@ -781,22 +966,20 @@ impl Lists {
      * * `list_id: &str` -- The unique ID for the list.
      * * `interest_category_id: &str` -- The unique ID for the interest category.
      * * `interest_id: &str` -- The specific interest or 'group name'.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `params: &ListsGetInterestCategoriesInterestListsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_interest_categories_interest_lists(
+    pub async fn get_interest_categories_interest_lists_with(
         &self,
         list_id: &str,
         interest_category_id: &str,
         interest_id: &str,
-        fields: &[String],
-        exclude_fields: &[String],
+        params: &ListsGetInterestCategoriesInterestListsParams,
     ) -> Result<crate::types::InterestsInterest> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -811,6 +994,31 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_interest_categories_interest_lists_with` and pass the query parameters as a `ListsGetInterestCategoriesInterestListsParams` instead.
+     */
+    #[deprecated(note = "use `get_interest_categories_interest_lists_with` instead")]
+    pub async fn get_interest_categories_interest_lists(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        interest_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::InterestsInterest> {
+        let params = ListsGetInterestCategoriesInterestListsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_interest_categories_interest_lists_with(
+            list_id,
+            interest_category_id,
+            interest_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Delete interest in category.
      *
@@ -881,83 +1089,61 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `list_id: &str` -- The unique ID for the list.
-     * * `type_: &str` -- Limit results based on segment type.
-     * * `since_created_at: &str` -- Restrict results to segments created after the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `before_created_at: &str` -- Restrict results to segments created before the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `include_cleaned: bool` -- Whether the webhook is triggered when a list subscriber is added.
-     * * `include_transactional: bool` -- Whether the webhook is triggered when a list subscriber is added.
-     * * `include_unsubscribed: bool` -- Whether the webhook is triggered when a list subscriber is added.
-     * * `since_updated_at: &str` -- Restrict results to segments update after the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `before_updated_at: &str` -- Restrict results to segments update before the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * * `params: &ListsPreviewSegmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn preview_segment(
+    pub async fn preview_segment_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         list_id: &str,
-        type_: &str,
-        since_created_at: &str,
-        before_created_at: &str,
-        include_cleaned: bool,
-        include_transactional: bool,
-        include_unsubscribed: bool,
-        since_updated_at: &str,
-        before_updated_at: &str,
+        params: &ListsPreviewSegmentParams,
     ) -> Result<crate::types::CollectionOfSegments> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !before_created_at.is_empty() {
+        if let Some(before_created_at) = &params.before_created_at {
             query_args.push((
                 "before_created_at".to_string(),
                 before_created_at.to_string(),
             ));
         }
-        if !before_updated_at.is_empty() {
+        if let Some(before_updated_at) = &params.before_updated_at {
             query_args.push((
                 "before_updated_at".to_string(),
                 before_updated_at.to_string(),
             ));
         }
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if include_cleaned {
+        if let Some(include_cleaned) = &params.include_cleaned {
             query_args.push(("include_cleaned".to_string(), include_cleaned.to_string()));
         }
-        if include_transactional {
+        if let Some(include_transactional) = &params.include_transactional {
             query_args.push((
                 "include_transactional".to_string(),
                 include_transactional.to_string(),
             ));
         }
-        if include_unsubscribed {
+        if let Some(include_unsubscribed) = &params.include_unsubscribed {
             query_args.push((
                 "include_unsubscribed".to_string(),
                 include_unsubscribed.to_string(),
             ));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !since_created_at.is_empty() {
+        if let Some(since_created_at) = &params.since_created_at {
             query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
         }
-        if !since_updated_at.is_empty() {
+        if let Some(since_updated_at) = &params.since_updated_at {
             query_args.push(("since_updated_at".to_string(), since_updated_at.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -971,17 +1157,54 @@ impl Lists {
     }
 
     /**
-     * Add segment.
-     *
-     * This function performs a `POST` to the `/lists/{list_id}/segments` endpoint.
-     *
-     * Create a new segment in a specific list.
-     *
-     * **Parameters:**
-     *
-     * * `list_id: &str` -- The unique ID for the list.
+     * Deprecated: use `preview_segment_with` and pass the query parameters as a `ListsPreviewSegmentParams` instead.
      */
-    pub async fn post_segment(
+    #[deprecated(note = "use `preview_segment_with` instead")]
+    pub async fn preview_segment(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        type_: &str,
+        since_created_at: &str,
+        before_created_at: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+        since_updated_at: &str,
+        before_updated_at: &str,
+    ) -> Result<crate::types::CollectionOfSegments> {
+        let params = ListsPreviewSegmentParams {
+            before_created_at: Some(before_created_at.to_string()).filter(|s| !s.is_empty()),
+            before_updated_at: Some(before_updated_at.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            include_cleaned: Some(include_cleaned).filter(|v| *v),
+            include_transactional: Some(include_transactional).filter(|v| *v),
+            include_unsubscribed: Some(include_unsubscribed).filter(|v| *v),
+            offset: Some(offset).filter(|v| *v > 0),
+            since_created_at: Some(since_created_at.to_string()).filter(|s| !s.is_empty()),
+            since_updated_at: Some(since_updated_at.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.preview_segment_with(list_id, &params).await
+    }
+
+    /**
+     * Add segment.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/segments` endpoint.
+     *
+     * Create a new segment in a specific list.
+     *
+     * **Parameters:**
+     *
+     * * `list_id: &str` -- The unique ID for the list.
+     */
+    pub async fn post_segment(
         &self,
         list_id: &str,
         body: &crate::types::ListData,
@@ -1005,41 +1228,33 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `list_id: &str` -- The unique ID for the list.
      * * `segment_id: &str` -- The unique id for the segment.
-     * * `include_cleaned: bool` -- Whether the webhook is triggered when a list subscriber is added.
-     * * `include_transactional: bool` -- Whether the webhook is triggered when a list subscriber is added.
-     * * `include_unsubscribed: bool` -- Whether the webhook is triggered when a list subscriber is added.
+     * * `params: &ListsGetSegmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_segment(
+    pub async fn get_segment_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         list_id: &str,
         segment_id: &str,
-        include_cleaned: bool,
-        include_transactional: bool,
-        include_unsubscribed: bool,
+        params: &ListsGetSegmentParams,
     ) -> Result<crate::types::Segments> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if include_cleaned {
+        if let Some(include_cleaned) = &params.include_cleaned {
             query_args.push(("include_cleaned".to_string(), include_cleaned.to_string()));
         }
-        if include_transactional {
+        if let Some(include_transactional) = &params.include_transactional {
             query_args.push((
                 "include_transactional".to_string(),
                 include_transactional.to_string(),
             ));
         }
-        if include_unsubscribed {
+        if let Some(include_unsubscribed) = &params.include_unsubscribed {
             query_args.push((
                 "include_unsubscribed".to_string(),
                 include_unsubscribed.to_string(),
@@ -1056,6 +1271,30 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_segment_with` and pass the query parameters as a `ListsGetSegmentParams` instead.
+     */
+    #[deprecated(note = "use `get_segment_with` instead")]
+    pub async fn get_segment(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        segment_id: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+    ) -> Result<crate::types::Segments> {
+        let params = ListsGetSegmentParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            include_cleaned: Some(include_cleaned).filter(|v| *v),
+            include_transactional: Some(include_transactional).filter(|v| *v),
+            include_unsubscribed: Some(include_unsubscribed).filter(|v| *v),
+        };
+        self.get_segment_with(list_id, segment_id, &params).await
+    }
+
     /**
      * Batch add or remove members.
      *
@@ -1145,54 +1384,42 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `list_id: &str` -- The unique ID for the list.
      * * `segment_id: &str` -- The unique id for the segment.
-     * * `include_cleaned: bool` -- Whether the webhook is triggered when a list subscriber is added.
-     * * `include_transactional: bool` -- Whether the webhook is triggered when a list subscriber is added.
-     * * `include_unsubscribed: bool` -- Whether the webhook is triggered when a list subscriber is added.
+     * * `params: &ListsGetSegmentsMemberParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_segments_member(
+    pub async fn get_segments_member_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         list_id: &str,
         segment_id: &str,
-        include_cleaned: bool,
-        include_transactional: bool,
-        include_unsubscribed: bool,
+        params: &ListsGetSegmentsMemberParams,
     ) -> Result<crate::types::SegmentMembers> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if include_cleaned {
+        if let Some(include_cleaned) = &params.include_cleaned {
             query_args.push(("include_cleaned".to_string(), include_cleaned.to_string()));
         }
-        if include_transactional {
+        if let Some(include_transactional) = &params.include_transactional {
             query_args.push((
                 "include_transactional".to_string(),
                 include_transactional.to_string(),
             ));
         }
-        if include_unsubscribed {
+        if let Some(include_unsubscribed) = &params.include_unsubscribed {
             query_args.push((
                 "include_unsubscribed".to_string(),
                 include_unsubscribed.to_string(),
             ));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1206,6 +1433,35 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_segments_member_with` and pass the query parameters as a `ListsGetSegmentsMemberParams` instead.
+     */
+    #[deprecated(note = "use `get_segments_member_with` instead")]
+    pub async fn get_segments_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        segment_id: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+    ) -> Result<crate::types::SegmentMembers> {
+        let params = ListsGetSegmentsMemberParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            include_cleaned: Some(include_cleaned).filter(|v| *v),
+            include_transactional: Some(include_transactional).filter(|v| *v),
+            include_unsubscribed: Some(include_unsubscribed).filter(|v| *v),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_segments_member_with(list_id, segment_id, &params)
+            .await
+    }
+
     /**
      * Add member to segment.
      *
@@ -1274,15 +1530,15 @@ impl Lists {
      * **Parameters:**
      *
      * * `list_id: &str` -- The unique ID for the list.
-     * * `name: &str` -- The search query used to filter tags.  The search query will be compared to each tag as a prefix, so all tags that have a name starting with this field will be returned.
+     * * `params: &ListsSearchTagsNameParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn search_tags_name(
+    pub async fn search_tags_name_with(
         &self,
         list_id: &str,
-        name: &str,
+        params: &ListsSearchTagsNameParams,
     ) -> Result<crate::types::TagSearchResults> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !name.is_empty() {
+        if let Some(name) = &params.name {
             query_args.push(("name".to_string(), name.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1295,6 +1551,21 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `search_tags_name_with` and pass the query parameters as a `ListsSearchTagsNameParams` instead.
+     */
+    #[deprecated(note = "use `search_tags_name_with` instead")]
+    pub async fn search_tags_name(
+        &self,
+        list_id: &str,
+        name: &str,
+    ) -> Result<crate::types::TagSearchResults> {
+        let params = ListsSearchTagsNameParams {
+            name: Some(name.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.search_tags_name_with(list_id, &params).await
+    }
+
     /**
      * List members info.
      *
@@ -1304,127 +1575,91 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `list_id: &str` -- The unique ID for the list.
-     * * `email_type: &str` -- The name of the folder.
-     * * `status: crate::types::GetListsMembersStatus` -- The subscriber's status.
-     * * `since_timestamp_opt: &str` -- Restrict results to subscribers who opted-in after the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `before_timestamp_opt: &str` -- Restrict results to subscribers who opted-in before the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `since_last_changed: &str` -- Restrict results to subscribers whose information changed after the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `before_last_changed: &str` -- Restrict results to subscribers whose information changed before the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `unique_email_id: &str` -- A unique identifier for the email address across all Mailchimp lists.
-     * * `vip_only: bool` -- A filter to return only the list's VIP members. Passing `true` will restrict results to VIP list members, passing `false` will return all list members.
-     * * `interest_category_id: &str` -- The unique id for the interest category.
-     * * `interest_ids: &str` -- Used to filter list members by interests. Must be accompanied by interest_category_id and interest_match. The value must be a comma separated list of interest ids present for any supplied interest categories.
-     * * `interest_match: crate::types::InterestMatch` -- Used to filter list members by interests. Must be accompanied by interest_category_id and interest_ids. "any" will match a member with any of the interest supplied, "all" will only match members with every interest supplied, and "none" will match members without any of the interest supplied.
-     * * `sort_field: crate::types::GetListsMembersSortField` -- Returns files sorted by the specified field.
-     * * `sort_dir: crate::types::SortDir` -- Determines the order direction for sorted results.
-     * * `since_last_campaign: bool` -- Filter subscribers by those subscribed/unsubscribed/pending/cleaned since last email campaign send. Member status is required to use this filter.
-     * * `unsubscribed_since: &str` -- Filter subscribers by those unsubscribed since a specific date. Using any status other than unsubscribed with this filter will result in an error.
+     * * `params: &ListsGetMemberParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_member(
+    pub async fn get_member_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         list_id: &str,
-        email_type: &str,
-        status: crate::types::GetListsMembersStatus,
-        since_timestamp_opt: &str,
-        before_timestamp_opt: &str,
-        since_last_changed: &str,
-        before_last_changed: &str,
-        unique_email_id: &str,
-        vip_only: bool,
-        interest_category_id: &str,
-        interest_ids: &str,
-        interest_match: crate::types::InterestMatch,
-        sort_field: crate::types::GetListsMembersSortField,
-        sort_dir: crate::types::SortDir,
-        since_last_campaign: bool,
-        unsubscribed_since: &str,
+        params: &ListsGetMemberParams,
     ) -> Result<crate::types::ListMembersDataType> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !before_last_changed.is_empty() {
+        if let Some(before_last_changed) = &params.before_last_changed {
             query_args.push((
                 "before_last_changed".to_string(),
                 before_last_changed.to_string(),
             ));
         }
-        if !before_timestamp_opt.is_empty() {
+        if let Some(before_timestamp_opt) = &params.before_timestamp_opt {
             query_args.push((
                 "before_timestamp_opt".to_string(),
                 before_timestamp_opt.to_string(),
             ));
         }
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !email_type.is_empty() {
+        if let Some(email_type) = &params.email_type {
             query_args.push(("email_type".to_string(), email_type.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if !interest_category_id.is_empty() {
+        if let Some(interest_category_id) = &params.interest_category_id {
             query_args.push((
                 "interest_category_id".to_string(),
                 interest_category_id.to_string(),
             ));
         }
-        if !interest_ids.is_empty() {
+        if let Some(interest_ids) = &params.interest_ids {
             query_args.push(("interest_ids".to_string(), interest_ids.to_string()));
         }
-        if !interest_match.to_string().is_empty() {
+        if let Some(interest_match) = &params.interest_match {
             query_args.push(("interest_match".to_string(), interest_match.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if since_last_campaign {
+        if let Some(since_last_campaign) = &params.since_last_campaign {
             query_args.push((
                 "since_last_campaign".to_string(),
                 since_last_campaign.to_string(),
             ));
         }
-        if !since_last_changed.is_empty() {
+        if let Some(since_last_changed) = &params.since_last_changed {
             query_args.push((
                 "since_last_changed".to_string(),
                 since_last_changed.to_string(),
             ));
         }
-        if !since_timestamp_opt.is_empty() {
+        if let Some(since_timestamp_opt) = &params.since_timestamp_opt {
             query_args.push((
                 "since_timestamp_opt".to_string(),
                 since_timestamp_opt.to_string(),
             ));
         }
-        if !sort_dir.to_string().is_empty() {
+        if let Some(sort_dir) = &params.sort_dir {
             query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
         }
-        if !sort_field.to_string().is_empty() {
+        if let Some(sort_field) = &params.sort_field {
             query_args.push(("sort_field".to_string(), sort_field.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !unique_email_id.is_empty() {
+        if let Some(unique_email_id) = &params.unique_email_id {
             query_args.push(("unique_email_id".to_string(), unique_email_id.to_string()));
         }
-        if !unsubscribed_since.is_empty() {
+        if let Some(unsubscribed_since) = &params.unsubscribed_since {
             query_args.push((
                 "unsubscribed_since".to_string(),
                 unsubscribed_since.to_string(),
             ));
         }
-        if vip_only {
+        if let Some(vip_only) = &params.vip_only {
             query_args.push(("vip_only".to_string(), vip_only.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1437,6 +1672,57 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_member_with` and pass the query parameters as a `ListsGetMemberParams` instead.
+     */
+    #[deprecated(note = "use `get_member_with` instead")]
+    pub async fn get_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        email_type: &str,
+        status: crate::types::GetListsMembersStatus,
+        since_timestamp_opt: &str,
+        before_timestamp_opt: &str,
+        since_last_changed: &str,
+        before_last_changed: &str,
+        unique_email_id: &str,
+        vip_only: bool,
+        interest_category_id: &str,
+        interest_ids: &str,
+        interest_match: crate::types::InterestMatch,
+        sort_field: crate::types::GetListsMembersSortField,
+        sort_dir: crate::types::SortDir,
+        since_last_campaign: bool,
+        unsubscribed_since: &str,
+    ) -> Result<crate::types::ListMembersDataType> {
+        let params = ListsGetMemberParams {
+            before_last_changed: Some(before_last_changed.to_string()).filter(|s| !s.is_empty()),
+            before_timestamp_opt: Some(before_timestamp_opt.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count).filter(|v| *v > 0),
+            email_type: Some(email_type.to_string()).filter(|s| !s.is_empty()),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            interest_category_id: Some(interest_category_id.to_string()).filter(|s| !s.is_empty()),
+            interest_ids: Some(interest_ids.to_string()).filter(|s| !s.is_empty()),
+            interest_match: Some(interest_match).filter(|v| !v.to_string().is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            since_last_campaign: Some(since_last_campaign).filter(|v| *v),
+            since_last_changed: Some(since_last_changed.to_string()).filter(|s| !s.is_empty()),
+            since_timestamp_opt: Some(since_timestamp_opt.to_string()).filter(|s| !s.is_empty()),
+            sort_dir: Some(sort_dir).filter(|v| !v.to_string().is_empty()),
+            sort_field: Some(sort_field).filter(|v| !v.to_string().is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            unique_email_id: Some(unique_email_id.to_string()).filter(|s| !s.is_empty()),
+            unsubscribed_since: Some(unsubscribed_since.to_string()).filter(|s| !s.is_empty()),
+            vip_only: Some(vip_only).filter(|v| *v),
+        };
+        self.get_member_with(list_id, &params).await
+    }
+
     /**
      * Add member to list.
      *
@@ -1447,16 +1733,16 @@ impl Lists {
      * **Parameters:**
      *
      * * `list_id: &str` -- The unique ID for the list.
-     * * `skip_merge_validation: bool` -- If skip_merge_validation is true, member data will be accepted without merge field values, even if the merge field is usually required. This defaults to false.
+     * * `params: &ListsPostMemberParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn post_member(
+    pub async fn post_member_with(
         &self,
         list_id: &str,
-        skip_merge_validation: bool,
+        params: &ListsPostMemberParams,
         body: &crate::types::AddListMembers,
     ) -> Result<crate::types::ListMembersData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if skip_merge_validation {
+        if let Some(skip_merge_validation) = &params.skip_merge_validation {
             query_args.push((
                 "skip_merge_validation".to_string(),
                 skip_merge_validation.to_string(),
@@ -1474,6 +1760,22 @@ impl Lists {
             .await
     }
 
+    /**
+     * Deprecated: use `post_member_with` and pass the query parameters as a `ListsPostMemberParams` instead.
+     */
+    #[deprecated(note = "use `post_member_with` instead")]
+    pub async fn post_member(
+        &self,
+        list_id: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembers,
+    ) -> Result<crate::types::ListMembersData> {
+        let params = ListsPostMemberParams {
+            skip_merge_validation: Some(skip_merge_validation).filter(|v| *v),
+        };
+        self.post_member_with(list_id, &params, body).await
+    }
+
     /**
      * Get member info.
      *
@@ -1483,23 +1785,21 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `list_id: &str` -- The unique ID for the list.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address. This endpoint also accepts email addresses.
+     * * `params: &ListsGetMemberListsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_member_lists(
+    pub async fn get_member_lists_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         list_id: &str,
         subscriber_hash: &str,
+        params: &ListsGetMemberListsParams,
     ) -> Result<crate::types::ListMembersData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1513,6 +1813,25 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_member_lists_with` and pass the query parameters as a `ListsGetMemberListsParams` instead.
+     */
+    #[deprecated(note = "use `get_member_lists_with` instead")]
+    pub async fn get_member_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::ListMembersData> {
+        let params = ListsGetMemberListsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_member_lists_with(list_id, subscriber_hash, &params)
+            .await
+    }
+
     /**
      * Add or update list member.
      *
@@ -1524,17 +1843,17 @@ impl Lists {
      *
      * * `list_id: &str` -- The unique ID for the list.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
-     * * `skip_merge_validation: bool` -- If skip_merge_validation is true, member data will be accepted without merge field values, even if the merge field is usually required. This defaults to false.
+     * * `params: &ListsPutMembersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn put_members(
+    pub async fn put_members_with(
         &self,
         list_id: &str,
         subscriber_hash: &str,
-        skip_merge_validation: bool,
+        params: &ListsPutMembersParams,
         body: &crate::types::AddListMembersData,
     ) -> Result<crate::types::ListMembersData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if skip_merge_validation {
+        if let Some(skip_merge_validation) = &params.skip_merge_validation {
             query_args.push((
                 "skip_merge_validation".to_string(),
                 skip_merge_validation.to_string(),
@@ -1553,6 +1872,24 @@ impl Lists {
             .await
     }
 
+    /**
+     * Deprecated: use `put_members_with` and pass the query parameters as a `ListsPutMembersParams` instead.
+     */
+    #[deprecated(note = "use `put_members_with` instead")]
+    pub async fn put_members(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembersData,
+    ) -> Result<crate::types::ListMembersData> {
+        let params = ListsPutMembersParams {
+            skip_merge_validation: Some(skip_merge_validation).filter(|v| *v),
+        };
+        self.put_members_with(list_id, subscriber_hash, &params, body)
+            .await
+    }
+
     /**
      * Archive list member.
      *
@@ -1586,17 +1923,17 @@ impl Lists {
      *
      * * `list_id: &str` -- The unique ID for the list.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
-     * * `skip_merge_validation: bool` -- If skip_merge_validation is true, member data will be accepted without merge field values, even if the merge field is usually required. This defaults to false.
+     * * `params: &ListsPatchMembersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn patch_members(
+    pub async fn patch_members_with(
         &self,
         list_id: &str,
         subscriber_hash: &str,
-        skip_merge_validation: bool,
+        params: &ListsPatchMembersParams,
         body: &crate::types::AddListMembersDataType,
     ) -> Result<crate::types::ListMembersData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if skip_merge_validation {
+        if let Some(skip_merge_validation) = &params.skip_merge_validation {
             query_args.push((
                 "skip_merge_validation".to_string(),
                 skip_merge_validation.to_string(),
@@ -1615,6 +1952,24 @@ impl Lists {
             .await
     }
 
+    /**
+     * Deprecated: use `patch_members_with` and pass the query parameters as a `ListsPatchMembersParams` instead.
+     */
+    #[deprecated(note = "use `patch_members_with` instead")]
+    pub async fn patch_members(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        skip_merge_validation: bool,
+        body: &crate::types::AddListMembersDataType,
+    ) -> Result<crate::types::ListMembersData> {
+        let params = ListsPatchMembersParams {
+            skip_merge_validation: Some(skip_merge_validation).filter(|v| *v),
+        };
+        self.patch_members_with(list_id, subscriber_hash, &params, body)
+            .await
+    }
+
     /**
      * View recent activity 50.
      *
@@ -1626,26 +1981,22 @@ impl Lists {
      *
      * * `list_id: &str` -- The unique ID for the list.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `action: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `params: &ListsGetMembersActivityParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_members_activity(
+    pub async fn get_members_activity_with(
         &self,
         list_id: &str,
         subscriber_hash: &str,
-        fields: &[String],
-        exclude_fields: &[String],
-        action: &[String],
+        params: &ListsGetMembersActivityParams,
     ) -> Result<crate::types::MemberActivityEvents> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !action.is_empty() {
+        if let Some(action) = &params.action {
             query_args.push(("action".to_string(), action.join(" ")));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1659,6 +2010,27 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_members_activity_with` and pass the query parameters as a `ListsGetMembersActivityParams` instead.
+     */
+    #[deprecated(note = "use `get_members_activity_with` instead")]
+    pub async fn get_members_activity(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        action: &[String],
+    ) -> Result<crate::types::MemberActivityEvents> {
+        let params = ListsGetMembersActivityParams {
+            action: Some(action.to_vec()).filter(|v| !v.is_empty()),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_members_activity_with(list_id, subscriber_hash, &params)
+            .await
+    }
+
     /**
      * View recent activity.
      *
@@ -1670,36 +2042,28 @@ impl Lists {
      *
      * * `list_id: &str` -- The unique ID for the list.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `activity_filters: &[String]` -- A comma-separated list of activity filters that correspond to a set of activity types, e.g "?activity_filters=open,bounce,click".
+     * * `params: &ListsGetMembersActivityFeedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_members_activity_feed(
+    pub async fn get_members_activity_feed_with(
         &self,
         list_id: &str,
         subscriber_hash: &str,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        activity_filters: &[String],
+        params: &ListsGetMembersActivityFeedParams,
     ) -> Result<crate::types::MemberActivityEventsData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !activity_filters.is_empty() {
+        if let Some(activity_filters) = &params.activity_filters {
             query_args.push(("activity_filters".to_string(), activity_filters.join(" ")));
         }
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1713,6 +2077,31 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_members_activity_feed_with` and pass the query parameters as a `ListsGetMembersActivityFeedParams` instead.
+     */
+    #[deprecated(note = "use `get_members_activity_feed_with` instead")]
+    pub async fn get_members_activity_feed(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        activity_filters: &[String],
+    ) -> Result<crate::types::MemberActivityEventsData> {
+        let params = ListsGetMembersActivityFeedParams {
+            activity_filters: Some(activity_filters.to_vec()).filter(|v| !v.is_empty()),
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_members_activity_feed_with(list_id, subscriber_hash, &params)
+            .await
+    }
+
     /**
      * List member tags.
      *
@@ -1724,31 +2113,25 @@ impl Lists {
      *
      * * `list_id: &str` -- The unique ID for the list.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `params: &ListsGetMemberTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_member_tag(
+    pub async fn get_member_tag_with(
         &self,
         list_id: &str,
         subscriber_hash: &str,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
+        params: &ListsGetMemberTagParams,
     ) -> Result<crate::types::CollectionOfTags> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1762,6 +2145,29 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_member_tag_with` and pass the query parameters as a `ListsGetMemberTagParams` instead.
+     */
+    #[deprecated(note = "use `get_member_tag_with` instead")]
+    pub async fn get_member_tag(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::CollectionOfTags> {
+        let params = ListsGetMemberTagParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_member_tag_with(list_id, subscriber_hash, &params)
+            .await
+    }
+
     /**
      * Add or remove member tags.
      *
@@ -1802,31 +2208,25 @@ impl Lists {
      *
      * * `list_id: &str` -- The unique ID for the list.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address. This endpoint also accepts email addresses.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `params: &ListsGetMembersEventParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_members_event(
+    pub async fn get_members_event_with(
         &self,
         list_id: &str,
         subscriber_hash: &str,
-        count: i64,
-        offset: i64,
-        fields: &[String],
-        exclude_fields: &[String],
+        params: &ListsGetMembersEventParams,
     ) -> Result<crate::types::CollectionOfEvents> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1841,13 +2241,36 @@ impl Lists {
     }
 
     /**
-     * Add event.
-     *
-     * This function performs a `POST` to the `/lists/{list_id}/members/{subscriber_hash}/events` endpoint.
-     *
-     * Add an event for a list member.
-     *
-     * **Parameters:**
+     * Deprecated: use `get_members_event_with` and pass the query parameters as a `ListsGetMembersEventParams` instead.
+     */
+    #[deprecated(note = "use `get_members_event_with` instead")]
+    pub async fn get_members_event(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        count: i64,
+        offset: i64,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::CollectionOfEvents> {
+        let params = ListsGetMembersEventParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_members_event_with(list_id, subscriber_hash, &params)
+            .await
+    }
+
+    /**
+     * Add event.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/members/{subscriber_hash}/events` endpoint.
+     *
+     * Add an event for a list member.
+     *
+     * **Parameters:**
      *
      * * `list_id: &str` -- The unique ID for the list.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address. This endpoint also accepts email addresses.
@@ -1880,21 +2303,19 @@ impl Lists {
      *
      * * `list_id: &str` -- The unique ID for the list.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `params: &ListsGetMembersGoalParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_members_goal(
+    pub async fn get_members_goal_with(
         &self,
         list_id: &str,
         subscriber_hash: &str,
-        fields: &[String],
-        exclude_fields: &[String],
+        params: &ListsGetMembersGoalParams,
     ) -> Result<crate::types::CollectionOfMemberActivityEvents> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1908,6 +2329,25 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_members_goal_with` and pass the query parameters as a `ListsGetMembersGoalParams` instead.
+     */
+    #[deprecated(note = "use `get_members_goal_with` instead")]
+    pub async fn get_members_goal(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::CollectionOfMemberActivityEvents> {
+        let params = ListsGetMembersGoalParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_members_goal_with(list_id, subscriber_hash, &params)
+            .await
+    }
+
     /**
      * List recent member notes.
      *
@@ -1919,41 +2359,31 @@ impl Lists {
      *
      * * `list_id: &str` -- The unique ID for the list.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
-     * * `sort_field: crate::types::GetListsMembersNotesSortField` -- Returns notes sorted by the specified field.
-     * * `sort_dir: crate::types::SortDir` -- Determines the order direction for sorted results.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `params: &ListsGetMembersNoteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_members_note(
+    pub async fn get_members_note_with(
         &self,
         list_id: &str,
         subscriber_hash: &str,
-        sort_field: crate::types::GetListsMembersNotesSortField,
-        sort_dir: crate::types::SortDir,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
+        params: &ListsGetMembersNoteParams,
     ) -> Result<crate::types::CollectionOfNotes> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !sort_dir.to_string().is_empty() {
+        if let Some(sort_dir) = &params.sort_dir {
             query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
         }
-        if !sort_field.to_string().is_empty() {
+        if let Some(sort_field) = &params.sort_field {
             query_args.push(("sort_field".to_string(), sort_field.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1967,6 +2397,33 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_members_note_with` and pass the query parameters as a `ListsGetMembersNoteParams` instead.
+     */
+    #[deprecated(note = "use `get_members_note_with` instead")]
+    pub async fn get_members_note(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        sort_field: crate::types::GetListsMembersNotesSortField,
+        sort_dir: crate::types::SortDir,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::CollectionOfNotes> {
+        let params = ListsGetMembersNoteParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            sort_dir: Some(sort_dir).filter(|v| !v.to_string().is_empty()),
+            sort_field: Some(sort_field).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_members_note_with(list_id, subscriber_hash, &params)
+            .await
+    }
+
     /**
      * Add member note.
      *
@@ -2008,22 +2465,20 @@ impl Lists {
      * * `list_id: &str` -- The unique ID for the list.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
      * * `note_id: &str` -- The name of the folder.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `params: &ListsGetMembersNoteListsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_members_note_lists(
+    pub async fn get_members_note_lists_with(
         &self,
         list_id: &str,
         subscriber_hash: &str,
         note_id: &str,
-        fields: &[String],
-        exclude_fields: &[String],
+        params: &ListsGetMembersNoteListsParams,
     ) -> Result<crate::types::CollectionOfNotesMember> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2038,6 +2493,26 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_members_note_lists_with` and pass the query parameters as a `ListsGetMembersNoteListsParams` instead.
+     */
+    #[deprecated(note = "use `get_members_note_lists_with` instead")]
+    pub async fn get_members_note_lists(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        note_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<crate::types::CollectionOfNotesMember> {
+        let params = ListsGetMembersNoteListsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_members_note_lists_with(list_id, subscriber_hash, note_id, &params)
+            .await
+    }
+
     /**
      * Delete note.
      *
@@ -2135,40 +2610,30 @@ impl Lists {
      * **Parameters:**
      *
      * * `list_id: &str` -- The unique ID for the list.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `type_: &str` -- The name of the folder.
-     * * `required: bool` -- Whether the webhook is triggered when a list subscriber is added.
+     * * `params: &ListsGetMergeFieldParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_merge_field(
+    pub async fn get_merge_field_with(
         &self,
         list_id: &str,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        type_: &str,
-        required: bool,
+        params: &ListsGetMergeFieldParams,
     ) -> Result<crate::types::CollectionOfMergeFields> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if required {
+        if let Some(required) = &params.required {
             query_args.push(("required".to_string(), required.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2181,6 +2646,31 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_merge_field_with` and pass the query parameters as a `ListsGetMergeFieldParams` instead.
+     */
+    #[deprecated(note = "use `get_merge_field_with` instead")]
+    pub async fn get_merge_field(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: &str,
+        required: bool,
+    ) -> Result<crate::types::CollectionOfMergeFields> {
+        let params = ListsGetMergeFieldParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            required: Some(required).filter(|v| *v),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_merge_field_with(list_id, &params).await
+    }
+
     /**
      * Add merge field.
      *
@@ -2218,21 +2708,19 @@ impl Lists {
      *
      * * `list_id: &str` -- The unique ID for the list.
      * * `merge_id: &str` -- The id for the merge field.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `params: &ListsGetMergeFieldListsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_merge_field_lists(
+    pub async fn get_merge_field_lists_with(
         &self,
         list_id: &str,
         merge_id: &str,
-        exclude_fields: &[String],
-        fields: &[String],
+        params: &ListsGetMergeFieldListsParams,
     ) -> Result<crate::types::MergeField> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2246,6 +2734,25 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_merge_field_lists_with` and pass the query parameters as a `ListsGetMergeFieldListsParams` instead.
+     */
+    #[deprecated(note = "use `get_merge_field_lists_with` instead")]
+    pub async fn get_merge_field_lists(
+        &self,
+        list_id: &str,
+        merge_id: &str,
+        exclude_fields: &[String],
+        fields: &[String],
+    ) -> Result<crate::types::MergeField> {
+        let params = ListsGetMergeFieldListsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_merge_field_lists_with(list_id, merge_id, &params)
+            .await
+    }
+
     /**
      * Delete merge field.
      *
@@ -2475,21 +2982,19 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `list_id: &str` -- The unique ID for the list.
+     * * `params: &ListsGetLocationParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_location(
+    pub async fn get_location_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         list_id: &str,
+        params: &ListsGetLocationParams,
     ) -> Result<crate::types::ListLocations> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2501,4 +3006,1197 @@ impl Lists {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_location_with` and pass the query parameters as a `ListsGetLocationParams` instead.
+     */
+    #[deprecated(note = "use `get_location_with` instead")]
+    pub async fn get_location(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> Result<crate::types::ListLocations> {
+        let params = ListsGetLocationParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_location_with(list_id, &params).await
+    }
+}
+
+/// The query parameters of `Lists::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetParams {
+    /// Restrict results to lists created before the last campaign send date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_campaign_last_sent: Option<String>,
+    /// Restrict response to lists created before the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_date_created: Option<String>,
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// Restrict results to lists that include a specific subscriber's email address.
+    pub email: Option<String>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Restrict results to lists that contain an active, connected, undeleted ecommerce store.
+    pub has_ecommerce_store: Option<bool>,
+    /// Return the total_contacts field in the stats response, which contains an approximate count of all contacts in any state.
+    pub include_total_contacts: Option<bool>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Restrict results to lists created after the last campaign send date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_campaign_last_sent: Option<String>,
+    /// Restrict results to lists created after the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_date_created: Option<String>,
+    /// Determines the order direction for sorted results.
+    pub sort_dir: Option<crate::types::SortDir>,
+    /// Returns files sorted by the specified field.
+    pub sort_field: Option<crate::types::GetListsSortField>,
+}
+
+impl ListsGetParams {
+    pub fn before_campaign_last_sent<T: ToString>(mut self, before_campaign_last_sent: T) -> Self {
+        self.before_campaign_last_sent = Some(before_campaign_last_sent.to_string());
+        self
+    }
+
+    pub fn before_date_created<T: ToString>(mut self, before_date_created: T) -> Self {
+        self.before_date_created = Some(before_date_created.to_string());
+        self
+    }
+
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn email<T: ToString>(mut self, email: T) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn has_ecommerce_store(mut self, has_ecommerce_store: bool) -> Self {
+        self.has_ecommerce_store = Some(has_ecommerce_store);
+        self
+    }
+
+    pub fn include_total_contacts(mut self, include_total_contacts: bool) -> Self {
+        self.include_total_contacts = Some(include_total_contacts);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn since_campaign_last_sent<T: ToString>(mut self, since_campaign_last_sent: T) -> Self {
+        self.since_campaign_last_sent = Some(since_campaign_last_sent.to_string());
+        self
+    }
+
+    pub fn since_date_created<T: ToString>(mut self, since_date_created: T) -> Self {
+        self.since_date_created = Some(since_date_created.to_string());
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn sort_field(mut self, sort_field: crate::types::GetListsSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_lists_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetListsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Return the total_contacts field in the stats response, which contains an approximate count of all contacts in any state.
+    pub include_total_contacts: Option<bool>,
+}
+
+impl ListsGetListsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn include_total_contacts(mut self, include_total_contacts: bool) -> Self {
+        self.include_total_contacts = Some(include_total_contacts);
+        self
+    }
+}
+
+/// The query parameters of `Lists::post_lists_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsPostListsParams {
+    /// If skip_duplicate_check is true, we will ignore duplicates sent in the request when using the batch sub/unsub on the lists endpoint. The status of the first appearance in the request will be saved. This defaults to false.
+    pub skip_duplicate_check: Option<bool>,
+    /// If skip_merge_validation is true, member data will be accepted without merge field values, even if the merge field is usually required. This defaults to false.
+    pub skip_merge_validation: Option<bool>,
+}
+
+impl ListsPostListsParams {
+    pub fn skip_duplicate_check(mut self, skip_duplicate_check: bool) -> Self {
+        self.skip_duplicate_check = Some(skip_duplicate_check);
+        self
+    }
+
+    pub fn skip_merge_validation(mut self, skip_merge_validation: bool) -> Self {
+        self.skip_merge_validation = Some(skip_merge_validation);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_abuse_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetAbuseReportParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ListsGetAbuseReportParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_abuse_report_lists_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetAbuseReportListsParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ListsGetAbuseReportListsParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_activity_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetActivityParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ListsGetActivityParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_client_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetClientParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ListsGetClientParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_growth_history_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetGrowthHistoryParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Determines the order direction for sorted results.
+    pub sort_dir: Option<crate::types::SortDir>,
+    /// Returns files sorted by the specified field.
+    pub sort_field: Option<crate::types::GetListsGrowthHistorySortField>,
+}
+
+impl ListsGetGrowthHistoryParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn sort_field(mut self, sort_field: crate::types::GetListsGrowthHistorySortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_growth_history_lists_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetGrowthHistoryListsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ListsGetGrowthHistoryListsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_interest_categorie_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetInterestCategorieParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Restrict results a type of interest group.
+    pub type_: Option<String>,
+}
+
+impl ListsGetInterestCategorieParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_interest_categorie_lists_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetInterestCategorieListsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ListsGetInterestCategorieListsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_interest_categories_interest_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetInterestCategoriesInterestParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ListsGetInterestCategoriesInterestParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_interest_categories_interest_lists_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetInterestCategoriesInterestListsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ListsGetInterestCategoriesInterestListsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Lists::preview_segment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsPreviewSegmentParams {
+    /// Restrict results to segments created before the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_created_at: Option<String>,
+    /// Restrict results to segments update before the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_updated_at: Option<String>,
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Whether the webhook is triggered when a list subscriber is added.
+    pub include_cleaned: Option<bool>,
+    /// Whether the webhook is triggered when a list subscriber is added.
+    pub include_transactional: Option<bool>,
+    /// Whether the webhook is triggered when a list subscriber is added.
+    pub include_unsubscribed: Option<bool>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Restrict results to segments created after the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_created_at: Option<String>,
+    /// Restrict results to segments update after the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_updated_at: Option<String>,
+    /// Limit results based on segment type.
+    pub type_: Option<String>,
+}
+
+impl ListsPreviewSegmentParams {
+    pub fn before_created_at<T: ToString>(mut self, before_created_at: T) -> Self {
+        self.before_created_at = Some(before_created_at.to_string());
+        self
+    }
+
+    pub fn before_updated_at<T: ToString>(mut self, before_updated_at: T) -> Self {
+        self.before_updated_at = Some(before_updated_at.to_string());
+        self
+    }
+
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn include_cleaned(mut self, include_cleaned: bool) -> Self {
+        self.include_cleaned = Some(include_cleaned);
+        self
+    }
+
+    pub fn include_transactional(mut self, include_transactional: bool) -> Self {
+        self.include_transactional = Some(include_transactional);
+        self
+    }
+
+    pub fn include_unsubscribed(mut self, include_unsubscribed: bool) -> Self {
+        self.include_unsubscribed = Some(include_unsubscribed);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn since_created_at<T: ToString>(mut self, since_created_at: T) -> Self {
+        self.since_created_at = Some(since_created_at.to_string());
+        self
+    }
+
+    pub fn since_updated_at<T: ToString>(mut self, since_updated_at: T) -> Self {
+        self.since_updated_at = Some(since_updated_at.to_string());
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_segment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetSegmentParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Whether the webhook is triggered when a list subscriber is added.
+    pub include_cleaned: Option<bool>,
+    /// Whether the webhook is triggered when a list subscriber is added.
+    pub include_transactional: Option<bool>,
+    /// Whether the webhook is triggered when a list subscriber is added.
+    pub include_unsubscribed: Option<bool>,
+}
+
+impl ListsGetSegmentParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn include_cleaned(mut self, include_cleaned: bool) -> Self {
+        self.include_cleaned = Some(include_cleaned);
+        self
+    }
+
+    pub fn include_transactional(mut self, include_transactional: bool) -> Self {
+        self.include_transactional = Some(include_transactional);
+        self
+    }
+
+    pub fn include_unsubscribed(mut self, include_unsubscribed: bool) -> Self {
+        self.include_unsubscribed = Some(include_unsubscribed);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_segments_member_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetSegmentsMemberParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Whether the webhook is triggered when a list subscriber is added.
+    pub include_cleaned: Option<bool>,
+    /// Whether the webhook is triggered when a list subscriber is added.
+    pub include_transactional: Option<bool>,
+    /// Whether the webhook is triggered when a list subscriber is added.
+    pub include_unsubscribed: Option<bool>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ListsGetSegmentsMemberParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn include_cleaned(mut self, include_cleaned: bool) -> Self {
+        self.include_cleaned = Some(include_cleaned);
+        self
+    }
+
+    pub fn include_transactional(mut self, include_transactional: bool) -> Self {
+        self.include_transactional = Some(include_transactional);
+        self
+    }
+
+    pub fn include_unsubscribed(mut self, include_unsubscribed: bool) -> Self {
+        self.include_unsubscribed = Some(include_unsubscribed);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Lists::search_tags_name_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsSearchTagsNameParams {
+    /// The search query used to filter tags.  The search query will be compared to each tag as a prefix, so all tags that have a name starting with this field will be returned.
+    pub name: Option<String>,
+}
+
+impl ListsSearchTagsNameParams {
+    pub fn name<T: ToString>(mut self, name: T) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_member_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetMemberParams {
+    /// Restrict results to subscribers whose information changed before the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_last_changed: Option<String>,
+    /// Restrict results to subscribers who opted-in before the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_timestamp_opt: Option<String>,
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// The name of the folder.
+    pub email_type: Option<String>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// The unique id for the interest category.
+    pub interest_category_id: Option<String>,
+    /// Used to filter list members by interests. Must be accompanied by interest_category_id and interest_match. The value must be a comma separated list of interest ids present for any supplied interest categories.
+    pub interest_ids: Option<String>,
+    /// Used to filter list members by interests. Must be accompanied by interest_category_id and interest_ids. "any" will match a member with any of the interest supplied, "all" will only match members with every interest supplied, and "none" will match members without any of the interest supplied.
+    pub interest_match: Option<crate::types::InterestMatch>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Filter subscribers by those subscribed/unsubscribed/pending/cleaned since last email campaign send. Member status is required to use this filter.
+    pub since_last_campaign: Option<bool>,
+    /// Restrict results to subscribers whose information changed after the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_last_changed: Option<String>,
+    /// Restrict results to subscribers who opted-in after the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_timestamp_opt: Option<String>,
+    /// Determines the order direction for sorted results.
+    pub sort_dir: Option<crate::types::SortDir>,
+    /// Returns files sorted by the specified field.
+    pub sort_field: Option<crate::types::GetListsMembersSortField>,
+    /// The subscriber's status.
+    pub status: Option<crate::types::GetListsMembersStatus>,
+    /// A unique identifier for the email address across all Mailchimp lists.
+    pub unique_email_id: Option<String>,
+    /// Filter subscribers by those unsubscribed since a specific date. Using any status other than unsubscribed with this filter will result in an error.
+    pub unsubscribed_since: Option<String>,
+    /// A filter to return only the list's VIP members. Passing `true` will restrict results to VIP list members, passing `false` will return all list members.
+    pub vip_only: Option<bool>,
+}
+
+impl ListsGetMemberParams {
+    pub fn before_last_changed<T: ToString>(mut self, before_last_changed: T) -> Self {
+        self.before_last_changed = Some(before_last_changed.to_string());
+        self
+    }
+
+    pub fn before_timestamp_opt<T: ToString>(mut self, before_timestamp_opt: T) -> Self {
+        self.before_timestamp_opt = Some(before_timestamp_opt.to_string());
+        self
+    }
+
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn email_type<T: ToString>(mut self, email_type: T) -> Self {
+        self.email_type = Some(email_type.to_string());
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn interest_category_id<T: ToString>(mut self, interest_category_id: T) -> Self {
+        self.interest_category_id = Some(interest_category_id.to_string());
+        self
+    }
+
+    pub fn interest_ids<T: ToString>(mut self, interest_ids: T) -> Self {
+        self.interest_ids = Some(interest_ids.to_string());
+        self
+    }
+
+    pub fn interest_match(mut self, interest_match: crate::types::InterestMatch) -> Self {
+        self.interest_match = Some(interest_match);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn since_last_campaign(mut self, since_last_campaign: bool) -> Self {
+        self.since_last_campaign = Some(since_last_campaign);
+        self
+    }
+
+    pub fn since_last_changed<T: ToString>(mut self, since_last_changed: T) -> Self {
+        self.since_last_changed = Some(since_last_changed.to_string());
+        self
+    }
+
+    pub fn since_timestamp_opt<T: ToString>(mut self, since_timestamp_opt: T) -> Self {
+        self.since_timestamp_opt = Some(since_timestamp_opt.to_string());
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn sort_field(mut self, sort_field: crate::types::GetListsMembersSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::GetListsMembersStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn unique_email_id<T: ToString>(mut self, unique_email_id: T) -> Self {
+        self.unique_email_id = Some(unique_email_id.to_string());
+        self
+    }
+
+    pub fn unsubscribed_since<T: ToString>(mut self, unsubscribed_since: T) -> Self {
+        self.unsubscribed_since = Some(unsubscribed_since.to_string());
+        self
+    }
+
+    pub fn vip_only(mut self, vip_only: bool) -> Self {
+        self.vip_only = Some(vip_only);
+        self
+    }
+}
+
+/// The query parameters of `Lists::post_member_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsPostMemberParams {
+    /// If skip_merge_validation is true, member data will be accepted without merge field values, even if the merge field is usually required. This defaults to false.
+    pub skip_merge_validation: Option<bool>,
+}
+
+impl ListsPostMemberParams {
+    pub fn skip_merge_validation(mut self, skip_merge_validation: bool) -> Self {
+        self.skip_merge_validation = Some(skip_merge_validation);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_member_lists_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetMemberListsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ListsGetMemberListsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Lists::put_members_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsPutMembersParams {
+    /// If skip_merge_validation is true, member data will be accepted without merge field values, even if the merge field is usually required. This defaults to false.
+    pub skip_merge_validation: Option<bool>,
+}
+
+impl ListsPutMembersParams {
+    pub fn skip_merge_validation(mut self, skip_merge_validation: bool) -> Self {
+        self.skip_merge_validation = Some(skip_merge_validation);
+        self
+    }
+}
+
+/// The query parameters of `Lists::patch_members_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsPatchMembersParams {
+    /// If skip_merge_validation is true, member data will be accepted without merge field values, even if the merge field is usually required. This defaults to false.
+    pub skip_merge_validation: Option<bool>,
+}
+
+impl ListsPatchMembersParams {
+    pub fn skip_merge_validation(mut self, skip_merge_validation: bool) -> Self {
+        self.skip_merge_validation = Some(skip_merge_validation);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_members_activity_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetMembersActivityParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub action: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ListsGetMembersActivityParams {
+    pub fn action(mut self, action: Vec<String>) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_members_activity_feed_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetMembersActivityFeedParams {
+    /// A comma-separated list of activity filters that correspond to a set of activity types, e.g "?activity_filters=open,bounce,click".
+    pub activity_filters: Option<Vec<String>>,
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ListsGetMembersActivityFeedParams {
+    pub fn activity_filters(mut self, activity_filters: Vec<String>) -> Self {
+        self.activity_filters = Some(activity_filters);
+        self
+    }
+
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_member_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetMemberTagParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ListsGetMemberTagParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_members_event_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetMembersEventParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ListsGetMembersEventParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_members_goal_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetMembersGoalParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ListsGetMembersGoalParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_members_note_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetMembersNoteParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Determines the order direction for sorted results.
+    pub sort_dir: Option<crate::types::SortDir>,
+    /// Returns notes sorted by the specified field.
+    pub sort_field: Option<crate::types::GetListsMembersNotesSortField>,
+}
+
+impl ListsGetMembersNoteParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn sort_field(mut self, sort_field: crate::types::GetListsMembersNotesSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_members_note_lists_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetMembersNoteListsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ListsGetMembersNoteListsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_merge_field_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetMergeFieldParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Whether the webhook is triggered when a list subscriber is added.
+    pub required: Option<bool>,
+    /// The name of the folder.
+    pub type_: Option<String>,
+}
+
+impl ListsGetMergeFieldParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = Some(required);
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_merge_field_lists_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetMergeFieldListsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ListsGetMergeFieldListsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_location_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetLocationParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ListsGetLocationParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
 }
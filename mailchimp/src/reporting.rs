@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Reporting {
     pub client: Client,
@@ -21,39 +19,29 @@ impl Reporting {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `sort_field: crate::types::GetAllFacebookAdsSortField` -- Returns files sorted by the specified field.
-     * * `sort_dir: crate::types::SortDir` -- Determines the order direction for sorted results.
+     * * `params: &ReportingGetFacebookAdsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_facebook_ads(
+    pub async fn get_facebook_ads_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        sort_field: crate::types::GetAllFacebookAdsSortField,
-        sort_dir: crate::types::SortDir,
+        params: &ReportingGetFacebookAdsParams,
     ) -> Result<crate::types::GetReportingFacebookAdsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !sort_dir.to_string().is_empty() {
+        if let Some(sort_dir) = &params.sort_dir {
             query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
         }
-        if !sort_field.to_string().is_empty() {
+        if let Some(sort_field) = &params.sort_field {
             query_args.push(("sort_field".to_string(), sort_field.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -62,6 +50,30 @@ impl Reporting {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_facebook_ads_with` and pass the query parameters as a `ReportingGetFacebookAdsParams` instead.
+     */
+    #[deprecated(note = "use `get_facebook_ads_with` instead")]
+    pub async fn get_facebook_ads(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::GetReportingFacebookAdsResponse> {
+        let params = ReportingGetFacebookAdsParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            sort_dir: Some(sort_dir).filter(|v| !v.to_string().is_empty()),
+            sort_field: Some(sort_field).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_facebook_ads_with(&params).await
+    }
+
     /**
      * Get facebook ad report.
      *
@@ -71,21 +83,19 @@ impl Reporting {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `outreach_id: &str` -- The name of the folder.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `params: &ReportingGetFacebookAdParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_facebook_ad(
+    pub async fn get_facebook_ad_with(
         &self,
-        fields: &[String],
         outreach_id: &str,
-        exclude_fields: &[String],
+        params: &ReportingGetFacebookAdParams,
     ) -> Result<crate::types::GetReportingFacebookAdsResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -98,6 +108,23 @@ impl Reporting {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_facebook_ad_with` and pass the query parameters as a `ReportingGetFacebookAdParams` instead.
+     */
+    #[deprecated(note = "use `get_facebook_ad_with` instead")]
+    pub async fn get_facebook_ad(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> Result<crate::types::GetReportingFacebookAdsResponseAllOf> {
+        let params = ReportingGetFacebookAdParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_facebook_ad_with(outreach_id, &params).await
+    }
+
     /**
      * List facebook ecommerce report.
      *
@@ -107,36 +134,28 @@ impl Reporting {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `outreach_id: &str` -- The name of the folder.
-     * * `sort_field: crate::types::GetReportsEcommerceProductActivitySortField` -- Returns files sorted by the specified field.
+     * * `params: &ReportingGetFacebookAdsEcommerceProductActivityParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_facebook_ads_ecommerce_product_activity(
+    pub async fn get_facebook_ads_ecommerce_product_activity_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         outreach_id: &str,
-        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+        params: &ReportingGetFacebookAdsEcommerceProductActivityParams,
     ) -> Result<crate::types::GetReportsEcommerceProductActivityResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !sort_field.to_string().is_empty() {
+        if let Some(sort_field) = &params.sort_field {
             query_args.push(("sort_field".to_string(), sort_field.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -149,6 +168,30 @@ impl Reporting {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_facebook_ads_ecommerce_product_activity_with` and pass the query parameters as a `ReportingGetFacebookAdsEcommerceProductActivityParams` instead.
+     */
+    #[deprecated(note = "use `get_facebook_ads_ecommerce_product_activity_with` instead")]
+    pub async fn get_facebook_ads_ecommerce_product_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        outreach_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Result<crate::types::GetReportsEcommerceProductActivityResponse> {
+        let params = ReportingGetFacebookAdsEcommerceProductActivityParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            sort_field: Some(sort_field).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_facebook_ads_ecommerce_product_activity_with(outreach_id, &params)
+            .await
+    }
+
     /**
      * Get landing page report.
      *
@@ -158,21 +201,19 @@ impl Reporting {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `outreach_id: &str` -- The name of the folder.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `params: &ReportingGetLandingPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_landing_page(
+    pub async fn get_landing_page_with(
         &self,
-        fields: &[String],
         outreach_id: &str,
-        exclude_fields: &[String],
+        params: &ReportingGetLandingPageParams,
     ) -> Result<crate::types::LandingPages> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -185,6 +226,23 @@ impl Reporting {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_landing_page_with` and pass the query parameters as a `ReportingGetLandingPageParams` instead.
+     */
+    #[deprecated(note = "use `get_landing_page_with` instead")]
+    pub async fn get_landing_page(
+        &self,
+        fields: &[String],
+        outreach_id: &str,
+        exclude_fields: &[String],
+    ) -> Result<crate::types::LandingPages> {
+        let params = ReportingGetLandingPageParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_landing_page_with(outreach_id, &params).await
+    }
+
     /**
      * List landing pages reports.
      *
@@ -194,29 +252,23 @@ impl Reporting {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `params: &ReportingGetLandingPagesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_landing_pages(
+    pub async fn get_landing_pages_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
+        params: &ReportingGetLandingPagesParams,
     ) -> Result<crate::types::GetReportingLandingPagesResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -224,4 +276,195 @@ impl Reporting {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_landing_pages_with` and pass the query parameters as a `ReportingGetLandingPagesParams` instead.
+     */
+    #[deprecated(note = "use `get_landing_pages_with` instead")]
+    pub async fn get_landing_pages(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetReportingLandingPagesResponse> {
+        let params = ReportingGetLandingPagesParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_landing_pages_with(&params).await
+    }
+}
+
+/// The query parameters of `Reporting::get_facebook_ads_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportingGetFacebookAdsParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Determines the order direction for sorted results.
+    pub sort_dir: Option<crate::types::SortDir>,
+    /// Returns files sorted by the specified field.
+    pub sort_field: Option<crate::types::GetAllFacebookAdsSortField>,
+}
+
+impl ReportingGetFacebookAdsParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn sort_field(mut self, sort_field: crate::types::GetAllFacebookAdsSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+}
+
+/// The query parameters of `Reporting::get_facebook_ad_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportingGetFacebookAdParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportingGetFacebookAdParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reporting::get_facebook_ads_ecommerce_product_activity_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportingGetFacebookAdsEcommerceProductActivityParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Returns files sorted by the specified field.
+    pub sort_field: Option<crate::types::GetReportsEcommerceProductActivitySortField>,
+}
+
+impl ReportingGetFacebookAdsEcommerceProductActivityParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn sort_field(
+        mut self,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+}
+
+/// The query parameters of `Reporting::get_landing_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportingGetLandingPageParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportingGetLandingPageParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reporting::get_landing_pages_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportingGetLandingPagesParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ReportingGetLandingPagesParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
 }
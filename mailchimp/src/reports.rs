@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Reports {
     pub client: Client,
@@ -21,44 +19,35 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `type_: crate::types::CampaignType` -- There are four types of [campaigns](https://mailchimp.com/help/getting-started-with-campaigns/) you can create in Mailchimp. A/B Split campaigns have been deprecated and variate campaigns should be used instead.
-     * * `before_send_time: chrono::DateTime<chrono::Utc>` -- Restrict the response to campaigns sent before the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `since_send_time: chrono::DateTime<chrono::Utc>` -- Restrict the response to campaigns sent after the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * * `params: &ReportsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        type_: crate::types::CampaignType,
-        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
-        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        params: &ReportsGetParams,
     ) -> Result<crate::types::CampaignReportsData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = before_send_time {
-            query_args.push(("before_send_time".to_string(), date.to_rfc3339()));
+        if let Some(before_send_time) = &params.before_send_time {
+            query_args.push((
+                "before_send_time".to_string(),
+                before_send_time.to_rfc3339(),
+            ));
         }
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if let Some(date) = since_send_time {
-            query_args.push(("since_send_time".to_string(), date.to_rfc3339()));
+        if let Some(since_send_time) = &params.since_send_time {
+            query_args.push(("since_send_time".to_string(), since_send_time.to_rfc3339()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -67,6 +56,32 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `ReportsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        type_: crate::types::CampaignType,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<crate::types::CampaignReportsData> {
+        let params = ReportsGetParams {
+            before_send_time,
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            since_send_time,
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Get campaign report.
      *
@@ -76,21 +91,19 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &ReportsGetReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_reports(
+    pub async fn get_reports_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
+        params: &ReportsGetReportsParams,
     ) -> Result<crate::types::Reports> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -103,6 +116,23 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_reports_with` and pass the query parameters as a `ReportsGetReportsParams` instead.
+     */
+    #[deprecated(note = "use `get_reports_with` instead")]
+    pub async fn get_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::Reports> {
+        let params = ReportsGetReportsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_reports_with(campaign_id, &params).await
+    }
+
     /**
      * List abuse reports.
      *
@@ -112,21 +142,19 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &ReportsGetAbuseParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_abuse(
+    pub async fn get_abuse_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
+        params: &ReportsGetAbuseParams,
     ) -> Result<crate::types::AbuseComplaintsData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -139,6 +167,23 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_abuse_with` and pass the query parameters as a `ReportsGetAbuseParams` instead.
+     */
+    #[deprecated(note = "use `get_abuse_with` instead")]
+    pub async fn get_abuse(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::AbuseComplaintsData> {
+        let params = ReportsGetAbuseParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_abuse_with(campaign_id, &params).await
+    }
+
     /**
      * Get abuse report.
      *
@@ -148,23 +193,21 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
      * * `report_id: &str` -- The id for the abuse report.
+     * * `params: &ReportsGetAbuseReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_abuse_reports(
+    pub async fn get_abuse_reports_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
         report_id: &str,
+        params: &ReportsGetAbuseReportsParams,
     ) -> Result<crate::types::AbuseComplaint> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -178,6 +221,25 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_abuse_reports_with` and pass the query parameters as a `ReportsGetAbuseReportsParams` instead.
+     */
+    #[deprecated(note = "use `get_abuse_reports_with` instead")]
+    pub async fn get_abuse_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        report_id: &str,
+    ) -> Result<crate::types::AbuseComplaint> {
+        let params = ReportsGetAbuseReportsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_abuse_reports_with(campaign_id, report_id, &params)
+            .await
+    }
+
     /**
      * List campaign feedback.
      *
@@ -187,21 +249,19 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &ReportsGetAdviceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_advice(
+    pub async fn get_advice_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
+        params: &ReportsGetAdviceParams,
     ) -> Result<crate::types::CampaignAdviceReport> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -214,6 +274,23 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_advice_with` and pass the query parameters as a `ReportsGetAdviceParams` instead.
+     */
+    #[deprecated(note = "use `get_advice_with` instead")]
+    pub async fn get_advice(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignAdviceReport> {
+        let params = ReportsGetAdviceParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_advice_with(campaign_id, &params).await
+    }
+
     /**
      * List campaign details.
      *
@@ -223,31 +300,25 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &ReportsGetClickDetailParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_click_detail(
+    pub async fn get_click_detail_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         campaign_id: &str,
+        params: &ReportsGetClickDetailParams,
     ) -> Result<crate::types::ClickDetailReport> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -260,6 +331,27 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_click_detail_with` and pass the query parameters as a `ReportsGetClickDetailParams` instead.
+     */
+    #[deprecated(note = "use `get_click_detail_with` instead")]
+    pub async fn get_click_detail(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> Result<crate::types::ClickDetailReport> {
+        let params = ReportsGetClickDetailParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_click_detail_with(campaign_id, &params).await
+    }
+
     /**
      * Get campaign link details.
      *
@@ -269,23 +361,21 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
      * * `link_id: &str` -- The name of the folder.
+     * * `params: &ReportsGetClickDetailReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_click_detail_reports(
+    pub async fn get_click_detail_reports_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
         link_id: &str,
+        params: &ReportsGetClickDetailReportsParams,
     ) -> Result<crate::types::UrlsClicked> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -299,6 +389,25 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_click_detail_reports_with` and pass the query parameters as a `ReportsGetClickDetailReportsParams` instead.
+     */
+    #[deprecated(note = "use `get_click_detail_reports_with` instead")]
+    pub async fn get_click_detail_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        link_id: &str,
+    ) -> Result<crate::types::UrlsClicked> {
+        let params = ReportsGetClickDetailReportsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_click_detail_reports_with(campaign_id, link_id, &params)
+            .await
+    }
+
     /**
      * List clicked link subscribers.
      *
@@ -308,33 +417,27 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `campaign_id: &str` -- The unique id for the campaign.
      * * `link_id: &str` -- The name of the folder.
+     * * `params: &ReportsGetClickDetailsMemberParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_click_details_member(
+    pub async fn get_click_details_member_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         campaign_id: &str,
         link_id: &str,
+        params: &ReportsGetClickDetailsMemberParams,
     ) -> Result<crate::types::ClickDetailMembers> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -348,6 +451,29 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_click_details_member_with` and pass the query parameters as a `ReportsGetClickDetailsMemberParams` instead.
+     */
+    #[deprecated(note = "use `get_click_details_member_with` instead")]
+    pub async fn get_click_details_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        link_id: &str,
+    ) -> Result<crate::types::ClickDetailMembers> {
+        let params = ReportsGetClickDetailsMemberParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_click_details_member_with(campaign_id, link_id, &params)
+            .await
+    }
+
     /**
      * Get clicked link subscriber.
      *
@@ -357,25 +483,23 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
      * * `link_id: &str` -- The name of the folder.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
+     * * `params: &ReportsGetClickDetailsMemberReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_click_details_member_reports(
+    pub async fn get_click_details_member_reports_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
         link_id: &str,
         subscriber_hash: &str,
+        params: &ReportsGetClickDetailsMemberReportsParams,
     ) -> Result<crate::types::ClickDetailMember> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -390,6 +514,26 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_click_details_member_reports_with` and pass the query parameters as a `ReportsGetClickDetailsMemberReportsParams` instead.
+     */
+    #[deprecated(note = "use `get_click_details_member_reports_with` instead")]
+    pub async fn get_click_details_member_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        link_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::ClickDetailMember> {
+        let params = ReportsGetClickDetailsMemberReportsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_click_details_member_reports_with(campaign_id, link_id, subscriber_hash, &params)
+            .await
+    }
+
     /**
      * List campaign open details.
      *
@@ -399,36 +543,28 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `campaign_id: &str` -- The unique id for the campaign.
-     * * `since: &str` -- Restrict results to campaign open events that occur after a specific time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * * `params: &ReportsGetOpenDetailParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_open_detail(
+    pub async fn get_open_detail_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         campaign_id: &str,
-        since: &str,
+        params: &ReportsGetOpenDetailParams,
     ) -> Result<crate::types::OpenDetailReport> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !since.is_empty() {
+        if let Some(since) = &params.since {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -441,6 +577,29 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_open_detail_with` and pass the query parameters as a `ReportsGetOpenDetailParams` instead.
+     */
+    #[deprecated(note = "use `get_open_detail_with` instead")]
+    pub async fn get_open_detail(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        since: &str,
+    ) -> Result<crate::types::OpenDetailReport> {
+        let params = ReportsGetOpenDetailParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            since: Some(since.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_open_detail_with(campaign_id, &params).await
+    }
+
     /**
      * Get opened campaign subscriber.
      *
@@ -450,23 +609,21 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
+     * * `params: &ReportsGetOpenDetailsMemberParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_open_details_member(
+    pub async fn get_open_details_member_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
         subscriber_hash: &str,
+        params: &ReportsGetOpenDetailsMemberParams,
     ) -> Result<crate::types::OpenActivity> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -480,6 +637,25 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_open_details_member_with` and pass the query parameters as a `ReportsGetOpenDetailsMemberParams` instead.
+     */
+    #[deprecated(note = "use `get_open_details_member_with` instead")]
+    pub async fn get_open_details_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::OpenActivity> {
+        let params = ReportsGetOpenDetailsMemberParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_open_details_member_with(campaign_id, subscriber_hash, &params)
+            .await
+    }
+
     /**
      * List domain performance stats.
      *
@@ -489,21 +665,19 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &ReportsGetDomainPerformanceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_domain_performance(
+    pub async fn get_domain_performance_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
+        params: &ReportsGetDomainPerformanceParams,
     ) -> Result<crate::types::DomainPerformance> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -516,6 +690,23 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_domain_performance_with` and pass the query parameters as a `ReportsGetDomainPerformanceParams` instead.
+     */
+    #[deprecated(note = "use `get_domain_performance_with` instead")]
+    pub async fn get_domain_performance(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::DomainPerformance> {
+        let params = ReportsGetDomainPerformanceParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_domain_performance_with(campaign_id, &params).await
+    }
+
     /**
      * List EepURL activity.
      *
@@ -525,21 +716,19 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &ReportsGetEepurlParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_eepurl(
+    pub async fn get_eepurl_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
+        params: &ReportsGetEepurlParams,
     ) -> Result<crate::types::EepurlActivity> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -552,6 +741,23 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_eepurl_with` and pass the query parameters as a `ReportsGetEepurlParams` instead.
+     */
+    #[deprecated(note = "use `get_eepurl_with` instead")]
+    pub async fn get_eepurl(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::EepurlActivity> {
+        let params = ReportsGetEepurlParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_eepurl_with(campaign_id, &params).await
+    }
+
     /**
      * List email activity.
      *
@@ -561,36 +767,28 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `campaign_id: &str` -- The unique id for the campaign.
-     * * `since: &str` -- Restrict results to email activity events that occur after a specific time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * * `params: &ReportsGetEmailActivityParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_email_activity(
+    pub async fn get_email_activity_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         campaign_id: &str,
-        since: &str,
+        params: &ReportsGetEmailActivityParams,
     ) -> Result<crate::types::EmailActivityData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !since.is_empty() {
+        if let Some(since) = &params.since {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -603,6 +801,29 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_email_activity_with` and pass the query parameters as a `ReportsGetEmailActivityParams` instead.
+     */
+    #[deprecated(note = "use `get_email_activity_with` instead")]
+    pub async fn get_email_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        since: &str,
+    ) -> Result<crate::types::EmailActivityData> {
+        let params = ReportsGetEmailActivityParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            since: Some(since.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_email_activity_with(campaign_id, &params).await
+    }
+
     /**
      * Get subscriber email activity.
      *
@@ -612,28 +833,24 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
-     * * `since: &str` -- Restrict results to email activity events that occur after a specific time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * * `params: &ReportsGetEmailActivityReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_email_activity_reports(
+    pub async fn get_email_activity_reports_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
         subscriber_hash: &str,
-        since: &str,
+        params: &ReportsGetEmailActivityReportsParams,
     ) -> Result<crate::types::EmailActivity> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if !since.is_empty() {
+        if let Some(since) = &params.since {
             query_args.push(("since".to_string(), since.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -647,6 +864,27 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_email_activity_reports_with` and pass the query parameters as a `ReportsGetEmailActivityReportsParams` instead.
+     */
+    #[deprecated(note = "use `get_email_activity_reports_with` instead")]
+    pub async fn get_email_activity_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+        since: &str,
+    ) -> Result<crate::types::EmailActivity> {
+        let params = ReportsGetEmailActivityReportsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            since: Some(since.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_email_activity_reports_with(campaign_id, subscriber_hash, &params)
+            .await
+    }
+
     /**
      * List top open activities.
      *
@@ -656,31 +894,25 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `params: &ReportsGetLocationParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_location(
+    pub async fn get_location_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
-        count: i64,
-        offset: i64,
+        params: &ReportsGetLocationParams,
     ) -> Result<crate::types::OpenLocationsData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -693,6 +925,27 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_location_with` and pass the query parameters as a `ReportsGetLocationParams` instead.
+     */
+    #[deprecated(note = "use `get_location_with` instead")]
+    pub async fn get_location(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::OpenLocationsData> {
+        let params = ReportsGetLocationParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_location_with(campaign_id, &params).await
+    }
+
     /**
      * List campaign recipients.
      *
@@ -702,31 +955,25 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &ReportsGetSentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_sent(
+    pub async fn get_sent_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         campaign_id: &str,
+        params: &ReportsGetSentParams,
     ) -> Result<crate::types::SentData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -739,6 +986,27 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_sent_with` and pass the query parameters as a `ReportsGetSentParams` instead.
+     */
+    #[deprecated(note = "use `get_sent_with` instead")]
+    pub async fn get_sent(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> Result<crate::types::SentData> {
+        let params = ReportsGetSentParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_sent_with(campaign_id, &params).await
+    }
+
     /**
      * Get campaign recipient info.
      *
@@ -748,23 +1016,21 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
+     * * `params: &ReportsGetSentReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_sent_reports(
+    pub async fn get_sent_reports_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
         subscriber_hash: &str,
+        params: &ReportsGetSentReportsParams,
     ) -> Result<crate::types::SentTo> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -778,6 +1044,25 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_sent_reports_with` and pass the query parameters as a `ReportsGetSentReportsParams` instead.
+     */
+    #[deprecated(note = "use `get_sent_reports_with` instead")]
+    pub async fn get_sent_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::SentTo> {
+        let params = ReportsGetSentReportsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_sent_reports_with(campaign_id, subscriber_hash, &params)
+            .await
+    }
+
     /**
      * List child campaign reports.
      *
@@ -787,21 +1072,19 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &ReportsGetSubParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_sub(
+    pub async fn get_sub_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
+        params: &ReportsGetSubParams,
     ) -> Result<crate::types::CampaignSubReports> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -814,6 +1097,23 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_sub_with` and pass the query parameters as a `ReportsGetSubParams` instead.
+     */
+    #[deprecated(note = "use `get_sub_with` instead")]
+    pub async fn get_sub(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::CampaignSubReports> {
+        let params = ReportsGetSubParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_sub_with(campaign_id, &params).await
+    }
+
     /**
      * List unsubscribed members.
      *
@@ -823,31 +1123,25 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `params: &ReportsGetUnsubscribedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_unsubscribed(
+    pub async fn get_unsubscribed_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         campaign_id: &str,
+        params: &ReportsGetUnsubscribedParams,
     ) -> Result<crate::types::UnsubscribesData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -860,6 +1154,27 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_unsubscribed_with` and pass the query parameters as a `ReportsGetUnsubscribedParams` instead.
+     */
+    #[deprecated(note = "use `get_unsubscribed_with` instead")]
+    pub async fn get_unsubscribed(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+    ) -> Result<crate::types::UnsubscribesData> {
+        let params = ReportsGetUnsubscribedParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_unsubscribed_with(campaign_id, &params).await
+    }
+
     /**
      * Get unsubscribed member.
      *
@@ -869,23 +1184,21 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
      * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
+     * * `params: &ReportsGetUnsubscribedReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_unsubscribed_reports(
+    pub async fn get_unsubscribed_reports_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         campaign_id: &str,
         subscriber_hash: &str,
+        params: &ReportsGetUnsubscribedReportsParams,
     ) -> Result<crate::types::Unsubscribes> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -899,6 +1212,25 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_unsubscribed_reports_with` and pass the query parameters as a `ReportsGetUnsubscribedReportsParams` instead.
+     */
+    #[deprecated(note = "use `get_unsubscribed_reports_with` instead")]
+    pub async fn get_unsubscribed_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::Unsubscribes> {
+        let params = ReportsGetUnsubscribedReportsParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_unsubscribed_reports_with(campaign_id, subscriber_hash, &params)
+            .await
+    }
+
     /**
      * List campaign product activity.
      *
@@ -908,36 +1240,28 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `campaign_id: &str` -- The unique id for the campaign.
-     * * `sort_field: crate::types::GetReportsEcommerceProductActivitySortField` -- Returns files sorted by the specified field.
+     * * `params: &ReportsGetEcommerceProductActivityParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_ecommerce_product_activity(
+    pub async fn get_ecommerce_product_activity_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         campaign_id: &str,
-        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+        params: &ReportsGetEcommerceProductActivityParams,
     ) -> Result<crate::types::GetReportsEcommerceProductActivityResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !sort_field.to_string().is_empty() {
+        if let Some(sort_field) = &params.sort_field {
             query_args.push(("sort_field".to_string(), sort_field.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -949,4 +1273,668 @@ impl Reports {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_ecommerce_product_activity_with` and pass the query parameters as a `ReportsGetEcommerceProductActivityParams` instead.
+     */
+    #[deprecated(note = "use `get_ecommerce_product_activity_with` instead")]
+    pub async fn get_ecommerce_product_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Result<crate::types::GetReportsEcommerceProductActivityResponse> {
+        let params = ReportsGetEcommerceProductActivityParams {
+            count: Some(count).filter(|v| *v > 0),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            sort_field: Some(sort_field).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_ecommerce_product_activity_with(campaign_id, &params)
+            .await
+    }
+}
+
+/// The query parameters of `Reports::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetParams {
+    /// Restrict the response to campaigns sent before the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Restrict the response to campaigns sent after the set time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    /// There are four types of [campaigns](https://mailchimp.com/help/getting-started-with-campaigns/) you can create in Mailchimp. A/B Split campaigns have been deprecated and variate campaigns should be used instead.
+    pub type_: Option<crate::types::CampaignType>,
+}
+
+impl ReportsGetParams {
+    pub fn before_send_time(mut self, before_send_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.before_send_time = Some(before_send_time);
+        self
+    }
+
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn since_send_time(mut self, since_send_time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since_send_time = Some(since_send_time);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::CampaignType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetReportsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportsGetReportsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_abuse_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetAbuseParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportsGetAbuseParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_abuse_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetAbuseReportsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportsGetAbuseReportsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_advice_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetAdviceParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportsGetAdviceParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_click_detail_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetClickDetailParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ReportsGetClickDetailParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_click_detail_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetClickDetailReportsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportsGetClickDetailReportsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_click_details_member_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetClickDetailsMemberParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ReportsGetClickDetailsMemberParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_click_details_member_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetClickDetailsMemberReportsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportsGetClickDetailsMemberReportsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_open_detail_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetOpenDetailParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Restrict results to campaign open events that occur after a specific time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since: Option<String>,
+}
+
+impl ReportsGetOpenDetailParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn since<T: ToString>(mut self, since: T) -> Self {
+        self.since = Some(since.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_open_details_member_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetOpenDetailsMemberParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportsGetOpenDetailsMemberParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_domain_performance_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetDomainPerformanceParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportsGetDomainPerformanceParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_eepurl_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetEepurlParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportsGetEepurlParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_email_activity_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetEmailActivityParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Restrict results to email activity events that occur after a specific time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since: Option<String>,
+}
+
+impl ReportsGetEmailActivityParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn since<T: ToString>(mut self, since: T) -> Self {
+        self.since = Some(since.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_email_activity_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetEmailActivityReportsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Restrict results to email activity events that occur after a specific time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since: Option<String>,
+}
+
+impl ReportsGetEmailActivityReportsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn since<T: ToString>(mut self, since: T) -> Self {
+        self.since = Some(since.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_location_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetLocationParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ReportsGetLocationParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_sent_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetSentParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ReportsGetSentParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_sent_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetSentReportsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportsGetSentReportsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_sub_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetSubParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportsGetSubParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_unsubscribed_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetUnsubscribedParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+}
+
+impl ReportsGetUnsubscribedParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_unsubscribed_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetUnsubscribedReportsParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl ReportsGetUnsubscribedReportsParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_ecommerce_product_activity_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetEcommerceProductActivityParams {
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Returns files sorted by the specified field.
+    pub sort_field: Option<crate::types::GetReportsEcommerceProductActivitySortField>,
+}
+
+impl ReportsGetEcommerceProductActivityParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn sort_field(
+        mut self,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
 }
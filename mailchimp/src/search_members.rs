@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SearchMembers {
     pub client: Client,
@@ -21,29 +19,23 @@ impl SearchMembers {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `query: &str` -- The search query used to filter results. Query should be a valid email, or a string representing a contact's first or last name.
-     * * `list_id: &str` -- The unique id for the list.
+     * * `params: &SearchMembersGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        query: &str,
-        list_id: &str,
+        params: &SearchMembersGetParams,
     ) -> Result<crate::types::MembersData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if !list_id.is_empty() {
+        if let Some(list_id) = &params.list_id {
             query_args.push(("list_id".to_string(), list_id.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -51,4 +43,59 @@ impl SearchMembers {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `SearchMembersGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        query: &str,
+        list_id: &str,
+    ) -> Result<crate::types::MembersData> {
+        let params = SearchMembersGetParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            list_id: Some(list_id.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(&params).await
+    }
+}
+
+/// The query parameters of `SearchMembers::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SearchMembersGetParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// The unique id for the list.
+    pub list_id: Option<String>,
+    /// The search query used to filter results. Query should be a valid email, or a string representing a contact's first or last name.
+    pub query: Option<String>,
+}
+
+impl SearchMembersGetParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn list_id<T: ToString>(mut self, list_id: T) -> Self {
+        self.list_id = Some(list_id.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
 }
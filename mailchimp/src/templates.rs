@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Templates {
     pub client: Client,
@@ -21,75 +19,53 @@ impl Templates {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `created_by: &str` -- The Mailchimp account user who created the template.
-     * * `since_date_created: &str` -- Restrict the response to templates created after the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `before_date_created: &str` -- Restrict the response to templates created before the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `type_: &str` -- Limit results based on template type.
-     * * `category: &str` -- Limit results based on category.
-     * * `folder_id: &str` -- The name of the folder.
-     * * `sort_field: crate::types::GetTemplatesSortField` -- Returns user templates sorted by the specified field.
-     * * `sort_dir: crate::types::SortDir` -- Determines the order direction for sorted results.
+     * * `params: &TemplatesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        created_by: &str,
-        since_date_created: &str,
-        before_date_created: &str,
-        type_: &str,
-        category: &str,
-        folder_id: &str,
-        sort_field: crate::types::GetTemplatesSortField,
-        sort_dir: crate::types::SortDir,
+        params: &TemplatesGetParams,
     ) -> Result<crate::types::TemplatesData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !before_date_created.is_empty() {
+        if let Some(before_date_created) = &params.before_date_created {
             query_args.push((
                 "before_date_created".to_string(),
                 before_date_created.to_string(),
             ));
         }
-        if !category.is_empty() {
+        if let Some(category) = &params.category {
             query_args.push(("category".to_string(), category.to_string()));
         }
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !created_by.is_empty() {
+        if let Some(created_by) = &params.created_by {
             query_args.push(("created_by".to_string(), created_by.to_string()));
         }
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if !folder_id.is_empty() {
+        if let Some(folder_id) = &params.folder_id {
             query_args.push(("folder_id".to_string(), folder_id.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !since_date_created.is_empty() {
+        if let Some(since_date_created) = &params.since_date_created {
             query_args.push((
                 "since_date_created".to_string(),
                 since_date_created.to_string(),
             ));
         }
-        if !sort_dir.to_string().is_empty() {
+        if let Some(sort_dir) = &params.sort_dir {
             query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
         }
-        if !sort_field.to_string().is_empty() {
+        if let Some(sort_field) = &params.sort_field {
             query_args.push(("sort_field".to_string(), sort_field.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -98,6 +74,42 @@ impl Templates {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `TemplatesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        created_by: &str,
+        since_date_created: &str,
+        before_date_created: &str,
+        type_: &str,
+        category: &str,
+        folder_id: &str,
+        sort_field: crate::types::GetTemplatesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<crate::types::TemplatesData> {
+        let params = TemplatesGetParams {
+            before_date_created: Some(before_date_created.to_string()).filter(|s| !s.is_empty()),
+            category: Some(category.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count).filter(|v| *v > 0),
+            created_by: Some(created_by.to_string()).filter(|s| !s.is_empty()),
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+            folder_id: Some(folder_id.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            since_date_created: Some(since_date_created.to_string()).filter(|s| !s.is_empty()),
+            sort_dir: Some(sort_dir).filter(|v| !v.to_string().is_empty()),
+            sort_field: Some(sort_field).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Add template.
      *
@@ -124,21 +136,19 @@ impl Templates {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `template_id: &str` -- The unique id for the template.
+     * * `params: &TemplatesGetTemplatesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_templates(
+    pub async fn get_templates_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         template_id: &str,
+        params: &TemplatesGetTemplatesParams,
     ) -> Result<crate::types::Templates> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -151,6 +161,23 @@ impl Templates {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_templates_with` and pass the query parameters as a `TemplatesGetTemplatesParams` instead.
+     */
+    #[deprecated(note = "use `get_templates_with` instead")]
+    pub async fn get_templates(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        template_id: &str,
+    ) -> Result<crate::types::Templates> {
+        let params = TemplatesGetTemplatesParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_templates_with(template_id, &params).await
+    }
+
     /**
      * Delete template.
      *
@@ -206,21 +233,19 @@ impl Templates {
      *
      * **Parameters:**
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `template_id: &str` -- The unique id for the template.
+     * * `params: &TemplatesGetDefaultContentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_default_content(
+    pub async fn get_default_content_with(
         &self,
-        fields: &[String],
-        exclude_fields: &[String],
         template_id: &str,
+        params: &TemplatesGetDefaultContentParams,
     ) -> Result<crate::types::TemplateDefaultContent> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !exclude_fields.is_empty() {
+        if let Some(exclude_fields) = &params.exclude_fields {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -232,4 +257,154 @@ impl Templates {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_default_content_with` and pass the query parameters as a `TemplatesGetDefaultContentParams` instead.
+     */
+    #[deprecated(note = "use `get_default_content_with` instead")]
+    pub async fn get_default_content(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        template_id: &str,
+    ) -> Result<crate::types::TemplateDefaultContent> {
+        let params = TemplatesGetDefaultContentParams {
+            exclude_fields: Some(exclude_fields.to_vec()).filter(|v| !v.is_empty()),
+            fields: Some(fields.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_default_content_with(template_id, &params).await
+    }
+}
+
+/// The query parameters of `Templates::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplatesGetParams {
+    /// Restrict the response to templates created before the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub before_date_created: Option<String>,
+    /// Limit results based on category.
+    pub category: Option<String>,
+    /// The number of records to return. Default value is 10. Maximum value is 1000.
+    pub count: Option<i64>,
+    /// The Mailchimp account user who created the template.
+    pub created_by: Option<String>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+    /// The name of the folder.
+    pub folder_id: Option<String>,
+    /// Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+    pub offset: Option<i64>,
+    /// Restrict the response to templates created after the set date. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+    pub since_date_created: Option<String>,
+    /// Determines the order direction for sorted results.
+    pub sort_dir: Option<crate::types::SortDir>,
+    /// Returns user templates sorted by the specified field.
+    pub sort_field: Option<crate::types::GetTemplatesSortField>,
+    /// Limit results based on template type.
+    pub type_: Option<String>,
+}
+
+impl TemplatesGetParams {
+    pub fn before_date_created<T: ToString>(mut self, before_date_created: T) -> Self {
+        self.before_date_created = Some(before_date_created.to_string());
+        self
+    }
+
+    pub fn category<T: ToString>(mut self, category: T) -> Self {
+        self.category = Some(category.to_string());
+        self
+    }
+
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn created_by<T: ToString>(mut self, created_by: T) -> Self {
+        self.created_by = Some(created_by.to_string());
+        self
+    }
+
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn folder_id<T: ToString>(mut self, folder_id: T) -> Self {
+        self.folder_id = Some(folder_id.to_string());
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn since_date_created<T: ToString>(mut self, since_date_created: T) -> Self {
+        self.since_date_created = Some(since_date_created.to_string());
+        self
+    }
+
+    pub fn sort_dir(mut self, sort_dir: crate::types::SortDir) -> Self {
+        self.sort_dir = Some(sort_dir);
+        self
+    }
+
+    pub fn sort_field(mut self, sort_field: crate::types::GetTemplatesSortField) -> Self {
+        self.sort_field = Some(sort_field);
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Templates::get_templates_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplatesGetTemplatesParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl TemplatesGetTemplatesParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+}
+
+/// The query parameters of `Templates::get_default_content_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplatesGetDefaultContentParams {
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub exclude_fields: Option<Vec<String>>,
+    /// A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+    pub fields: Option<Vec<String>>,
+}
+
+impl TemplatesGetDefaultContentParams {
+    pub fn exclude_fields(mut self, exclude_fields: Vec<String>) -> Self {
+        self.exclude_fields = Some(exclude_fields);
+        self
+    }
+
+    pub fn fields(mut self, fields: Vec<String>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
 }
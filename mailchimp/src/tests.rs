@@ -1 +1,12 @@
+#[test]
+fn test_new_with_api_key() {
+    let client = crate::Client::new_with_api_key("0123456789abcdef0123456789abcdef-us19").unwrap();
+    assert_eq!(client.host, "https://us19.api.mailchimp.com/3.0");
+    assert_eq!(
+        client.api_key.as_deref(),
+        Some("0123456789abcdef0123456789abcdef-us19")
+    );
 
+    assert!(crate::Client::new_with_api_key("0123456789abcdef0123456789abcdef").is_err());
+    assert!(crate::Client::new_with_api_key("0123456789abcdef-").is_err());
+}
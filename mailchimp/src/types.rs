@@ -2,6 +2,104 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
+
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+
 /// An individual folder listed in the File Manager.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct GalleryFolder {
@@ -89,6 +187,7 @@ pub struct Interest {
  * Determines how this category’s interests appear on signup forms.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "checkboxes")]
     Checkboxes,
@@ -98,34 +197,59 @@ pub enum Type {
     Hidden,
     #[serde(rename = "radio")]
     Radio,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Type {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        match self {
             Type::Checkboxes => "checkboxes",
             Type::Dropdown => "dropdown",
             Type::Hidden => "hidden",
             Type::Radio => "radio",
-            Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Type {
-    fn default() -> Type {
-        Type::Noop
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "checkboxes" => Type::Checkboxes,
+            "dropdown" => Type::Dropdown,
+            "hidden" => Type::Hidden,
+            "radio" => Type::Radio,
+            _ => Type::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Type {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Type {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Type::Noop)
+
+impl From<Type> for String {
+    fn from(v: Type) -> Self {
+        match v {
+            Type::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -153,8 +277,8 @@ pub struct InterestCategory {
     /**
      * Determines how this category’s interests appear on signup forms.
      */
-    #[serde(default, skip_serializing_if = "Type::is_noop", rename = "type")]
-    pub type_: Type,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<Type>,
 }
 
 /// The events that can trigger the webhook and whether they are enabled.
@@ -367,6 +491,7 @@ pub struct ECommerceProductVariant {
  * The type of pricing plan the account is on.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PricingPlanType {
     #[serde(rename = "forever_free")]
     ForeverFree,
@@ -374,33 +499,57 @@ pub enum PricingPlanType {
     Monthly,
     #[serde(rename = "pay_as_you_go")]
     PayAsYouGo,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PricingPlanType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PricingPlanType {
+    fn as_ref(&self) -> &str {
+        match self {
             PricingPlanType::ForeverFree => "forever_free",
             PricingPlanType::Monthly => "monthly",
             PricingPlanType::PayAsYouGo => "pay_as_you_go",
-            PricingPlanType::Noop => "",
-            PricingPlanType::FallthroughString => "*",
+            PricingPlanType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PricingPlanType {
-    fn default() -> PricingPlanType {
-        PricingPlanType::Noop
+impl std::fmt::Display for PricingPlanType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PricingPlanType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "forever_free" => PricingPlanType::ForeverFree,
+            "monthly" => PricingPlanType::Monthly,
+            "pay_as_you_go" => PricingPlanType::PayAsYouGo,
+            _ => PricingPlanType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for PricingPlanType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PricingPlanType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PricingPlanType::Noop)
+
+impl From<PricingPlanType> for String {
+    fn from(v: PricingPlanType) -> Self {
+        match v {
+            PricingPlanType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -510,6 +659,7 @@ pub struct IndustryStats {
  * The HTTP method that should be used when accessing the URL defined in 'href'.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Method {
     #[serde(rename = "DELETE")]
     Delete,
@@ -525,15 +675,15 @@ pub enum Method {
     Post,
     #[serde(rename = "PUT")]
     Put,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Method {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Method {
+    fn as_ref(&self) -> &str {
+        match self {
             Method::Delete => "DELETE",
             Method::Get => "GET",
             Method::Head => "HEAD",
@@ -541,21 +691,49 @@ impl std::fmt::Display for Method {
             Method::Patch => "PATCH",
             Method::Post => "POST",
             Method::Put => "PUT",
-            Method::Noop => "",
-            Method::FallthroughString => "*",
+            Method::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Method {
-    fn default() -> Method {
-        Method::Noop
+impl std::fmt::Display for Method {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Method {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "DELETE" => Method::Delete,
+            "GET" => Method::Get,
+            "HEAD" => Method::Head,
+            "OPTIONS" => Method::Options,
+            "PATCH" => Method::Patch,
+            "POST" => Method::Post,
+            "PUT" => Method::Put,
+            _ => Method::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Method {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Method {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Method::Noop)
+
+impl From<Method> for String {
+    fn from(v: Method) -> Self {
+        match v {
+            Method::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -783,6 +961,7 @@ pub struct ApiRoot {
  * The type of activity
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetActivityFeedChimpChatterResponseType {
     #[serde(rename = "campaigns:facebook-likes")]
     CampaignsFacebookLikes,
@@ -796,15 +975,15 @@ pub enum GetActivityFeedChimpChatterResponseType {
     ListsProfileUpdates,
     #[serde(rename = "lists:unsubscribes")]
     ListsUnsubscribes,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetActivityFeedChimpChatterResponseType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetActivityFeedChimpChatterResponseType {
+    fn as_ref(&self) -> &str {
+        match self {
             GetActivityFeedChimpChatterResponseType::CampaignsFacebookLikes => {
                 "campaigns:facebook-likes"
             }
@@ -815,21 +994,52 @@ impl std::fmt::Display for GetActivityFeedChimpChatterResponseType {
             GetActivityFeedChimpChatterResponseType::ListsNewSubscriber => "lists:new-subscriber",
             GetActivityFeedChimpChatterResponseType::ListsProfileUpdates => "lists:profile-updates",
             GetActivityFeedChimpChatterResponseType::ListsUnsubscribes => "lists:unsubscribes",
-            GetActivityFeedChimpChatterResponseType::Noop => "",
-            GetActivityFeedChimpChatterResponseType::FallthroughString => "*",
+            GetActivityFeedChimpChatterResponseType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetActivityFeedChimpChatterResponseType {
-    fn default() -> GetActivityFeedChimpChatterResponseType {
-        GetActivityFeedChimpChatterResponseType::Noop
+impl std::fmt::Display for GetActivityFeedChimpChatterResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetActivityFeedChimpChatterResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "campaigns:facebook-likes" => {
+                GetActivityFeedChimpChatterResponseType::CampaignsFacebookLikes
+            }
+            "campaigns:forward-to-friend" => {
+                GetActivityFeedChimpChatterResponseType::CampaignsForwardToFriend
+            }
+            "lists:imports" => GetActivityFeedChimpChatterResponseType::ListsImports,
+            "lists:new-subscriber" => GetActivityFeedChimpChatterResponseType::ListsNewSubscriber,
+            "lists:profile-updates" => GetActivityFeedChimpChatterResponseType::ListsProfileUpdates,
+            "lists:unsubscribes" => GetActivityFeedChimpChatterResponseType::ListsUnsubscribes,
+            _ => GetActivityFeedChimpChatterResponseType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GetActivityFeedChimpChatterResponseType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetActivityFeedChimpChatterResponseType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetActivityFeedChimpChatterResponseType::Noop)
+
+impl From<GetActivityFeedChimpChatterResponseType> for String {
+    fn from(v: GetActivityFeedChimpChatterResponseType) -> Self {
+        match v {
+            GetActivityFeedChimpChatterResponseType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1018,6 +1228,7 @@ pub struct GetAuthorizedAppsResponse {
  * Restrict the results to automations with the specified status.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Status {
     #[serde(rename = "paused")]
     Paused,
@@ -1025,33 +1236,57 @@ pub enum Status {
     Save,
     #[serde(rename = "sending")]
     Sending,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Status {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        match self {
             Status::Paused => "paused",
             Status::Save => "save",
             Status::Sending => "sending",
-            Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Status {
-    fn default() -> Status {
-        Status::Noop
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "paused" => Status::Paused,
+            "save" => Status::Save,
+            "sending" => Status::Sending,
+            _ => Status::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Status {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Status {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Status::Noop)
+
+impl From<Status> for String {
+    fn from(v: Status) -> Self {
+        match v {
+            Status::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1059,69 +1294,116 @@ impl Status {
  * Segment match type.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Match {
     #[serde(rename = "all")]
     All,
     #[serde(rename = "any")]
     Any,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Match {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Match {
+    fn as_ref(&self) -> &str {
+        match self {
             Match::All => "all",
             Match::Any => "any",
-            Match::Noop => "",
-            Match::FallthroughString => "*",
+            Match::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Match {
-    fn default() -> Match {
-        Match::Noop
+impl std::fmt::Display for Match {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Match {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => Match::All,
+            "any" => Match::Any,
+            _ => Match::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Match {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Match {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Match::Noop)
+
+impl From<Match> for String {
+    fn from(v: Match) -> Self {
+        match v {
+            Match::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ConditionType {
     #[serde(rename = "Aim")]
     Aim,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             ConditionType::Aim => "Aim",
-            ConditionType::Noop => "",
-            ConditionType::FallthroughString => "*",
+            ConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ConditionType {
-    fn default() -> ConditionType {
-        ConditionType::Noop
+impl std::fmt::Display for ConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Aim" => ConditionType::Aim,
+            _ => ConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for ConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ConditionType::Noop)
+
+impl From<ConditionType> for String {
+    fn from(v: ConditionType) -> Self {
+        match v {
+            ConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1129,34 +1411,57 @@ impl ConditionType {
  * Segment by interaction with a specific campaign.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Field {
     #[serde(rename = "aim")]
     Aim,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Field {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Field {
+    fn as_ref(&self) -> &str {
+        match self {
             Field::Aim => "aim",
-            Field::Noop => "",
-            Field::FallthroughString => "*",
+            Field::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Field {
-    fn default() -> Field {
-        Field::Noop
+impl std::fmt::Display for Field {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Field {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "aim" => Field::Aim,
+            _ => Field::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Field {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Field {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Field::Noop)
+
+impl From<Field> for String {
+    fn from(v: Field) -> Self {
+        match v {
+            Field::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1164,6 +1469,7 @@ impl Field {
  * The status of the member with regard to their campaign interaction. One of the following: opened, clicked, was sent, didn't open, didn't click, or was not sent.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Op {
     #[serde(rename = "click")]
     Click,
@@ -1177,36 +1483,63 @@ pub enum Op {
     Open,
     #[serde(rename = "sent")]
     Sent,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Op {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Op {
+    fn as_ref(&self) -> &str {
+        match self {
             Op::Click => "click",
             Op::Noclick => "noclick",
             Op::Noopen => "noopen",
             Op::Nosent => "nosent",
             Op::Open => "open",
             Op::Sent => "sent",
-            Op::Noop => "",
-            Op::FallthroughString => "*",
+            Op::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Op {
-    fn default() -> Op {
-        Op::Noop
+impl std::fmt::Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Op {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "click" => Op::Click,
+            "noclick" => Op::Noclick,
+            "noopen" => Op::Noopen,
+            "nosent" => Op::Nosent,
+            "open" => Op::Open,
+            "sent" => Op::Sent,
+            _ => Op::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Op {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Op {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Op::Noop)
+
+impl From<Op> for String {
+    fn from(v: Op) -> Self {
+        match v {
+            Op::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1240,34 +1573,57 @@ pub struct Conditions {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AutomationSegmentConditionType {
     #[serde(rename = "Automation")]
     Automation,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AutomationSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AutomationSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             AutomationSegmentConditionType::Automation => "Automation",
-            AutomationSegmentConditionType::Noop => "",
-            AutomationSegmentConditionType::FallthroughString => "*",
+            AutomationSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AutomationSegmentConditionType {
-    fn default() -> AutomationSegmentConditionType {
-        AutomationSegmentConditionType::Noop
+impl std::fmt::Display for AutomationSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AutomationSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Automation" => AutomationSegmentConditionType::Automation,
+            _ => AutomationSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for AutomationSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AutomationSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AutomationSegmentConditionType::Noop)
+
+impl From<AutomationSegmentConditionType> for String {
+    fn from(v: AutomationSegmentConditionType) -> Self {
+        match v {
+            AutomationSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1275,34 +1631,57 @@ impl AutomationSegmentConditionType {
  * Segment by interaction with an Automation workflow.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SegmentField {
     #[serde(rename = "automation")]
     Automation,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             SegmentField::Automation => "automation",
-            SegmentField::Noop => "",
-            SegmentField::FallthroughString => "*",
+            SegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SegmentField {
-    fn default() -> SegmentField {
-        SegmentField::Noop
+impl std::fmt::Display for SegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "automation" => SegmentField::Automation,
+            _ => SegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SegmentField::Noop)
+
+impl From<SegmentField> for String {
+    fn from(v: SegmentField) -> Self {
+        match v {
+            SegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1310,6 +1689,7 @@ impl SegmentField {
  * The status of the member with regard to the automation workflow. One of the following: has started the workflow, has completed the workflow, has not started the workflow, or has not completed the workflow.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SegmentOperator {
     #[serde(rename = "completed")]
     Completed,
@@ -1319,34 +1699,59 @@ pub enum SegmentOperator {
     NotStarted,
     #[serde(rename = "started")]
     Started,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             SegmentOperator::Completed => "completed",
             SegmentOperator::NotCompleted => "not_completed",
             SegmentOperator::NotStarted => "not_started",
             SegmentOperator::Started => "started",
-            SegmentOperator::Noop => "",
-            SegmentOperator::FallthroughString => "*",
+            SegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SegmentOperator {
-    fn default() -> SegmentOperator {
-        SegmentOperator::Noop
+impl std::fmt::Display for SegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "completed" => SegmentOperator::Completed,
+            "not_completed" => SegmentOperator::NotCompleted,
+            "not_started" => SegmentOperator::NotStarted,
+            "started" => SegmentOperator::Started,
+            _ => SegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SegmentOperator::Noop)
+
+impl From<SegmentOperator> for String {
+    fn from(v: SegmentOperator) -> Self {
+        match v {
+            SegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1361,13 +1766,13 @@ pub struct AutomationSegment {
     /**
      * Segment by interaction with an Automation workflow.
      */
-    #[serde(default, skip_serializing_if = "SegmentField::is_noop")]
-    pub field: SegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<SegmentField>,
     /**
      * The status of the member with regard to the automation workflow. One of the following: has started the workflow, has completed the workflow, has not started the workflow, or has not completed the workflow.
      */
-    #[serde(default, skip_serializing_if = "SegmentOperator::is_noop")]
-    pub op: SegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<SegmentOperator>,
     /**
      * The name of the folder.
      */
@@ -1380,34 +1785,57 @@ pub struct AutomationSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PollActivitySegmentConditionType {
     #[serde(rename = "CampaignPoll")]
     CampaignPoll,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PollActivitySegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PollActivitySegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             PollActivitySegmentConditionType::CampaignPoll => "CampaignPoll",
-            PollActivitySegmentConditionType::Noop => "",
-            PollActivitySegmentConditionType::FallthroughString => "*",
+            PollActivitySegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PollActivitySegmentConditionType {
-    fn default() -> PollActivitySegmentConditionType {
-        PollActivitySegmentConditionType::Noop
+impl std::fmt::Display for PollActivitySegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PollActivitySegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "CampaignPoll" => PollActivitySegmentConditionType::CampaignPoll,
+            _ => PollActivitySegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for PollActivitySegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PollActivitySegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PollActivitySegmentConditionType::Noop)
+
+impl From<PollActivitySegmentConditionType> for String {
+    fn from(v: PollActivitySegmentConditionType) -> Self {
+        match v {
+            PollActivitySegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1415,72 +1843,119 @@ impl PollActivitySegmentConditionType {
  * Segment by poll activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PollActivitySegmentField {
     #[serde(rename = "poll")]
     Poll,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PollActivitySegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PollActivitySegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             PollActivitySegmentField::Poll => "poll",
-            PollActivitySegmentField::Noop => "",
-            PollActivitySegmentField::FallthroughString => "*",
+            PollActivitySegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PollActivitySegmentField {
-    fn default() -> PollActivitySegmentField {
-        PollActivitySegmentField::Noop
+impl std::fmt::Display for PollActivitySegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl PollActivitySegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PollActivitySegmentField::Noop)
+
+impl std::str::FromStr for PollActivitySegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "poll" => PollActivitySegmentField::Poll,
+            _ => PollActivitySegmentField::Unknown(s.to_string()),
+        })
     }
 }
 
-/**
- * Members have/have not interacted with a specific poll in a Mailchimp email.
+impl From<String> for PollActivitySegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<PollActivitySegmentField> for String {
+    fn from(v: PollActivitySegmentField) -> Self {
+        match v {
+            PollActivitySegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Members have/have not interacted with a specific poll in a Mailchimp email.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PollActivitySegmentOperator {
     #[serde(rename = "member")]
     Member,
     #[serde(rename = "notmember")]
     Notmember,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PollActivitySegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PollActivitySegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             PollActivitySegmentOperator::Member => "member",
             PollActivitySegmentOperator::Notmember => "notmember",
-            PollActivitySegmentOperator::Noop => "",
-            PollActivitySegmentOperator::FallthroughString => "*",
+            PollActivitySegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PollActivitySegmentOperator {
-    fn default() -> PollActivitySegmentOperator {
-        PollActivitySegmentOperator::Noop
+impl std::fmt::Display for PollActivitySegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PollActivitySegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "member" => PollActivitySegmentOperator::Member,
+            "notmember" => PollActivitySegmentOperator::Notmember,
+            _ => PollActivitySegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for PollActivitySegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PollActivitySegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PollActivitySegmentOperator::Noop)
+
+impl From<PollActivitySegmentOperator> for String {
+    fn from(v: PollActivitySegmentOperator) -> Self {
+        match v {
+            PollActivitySegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1495,13 +1970,13 @@ pub struct PollActivitySegment {
     /**
      * Segment by poll activity.
      */
-    #[serde(default, skip_serializing_if = "PollActivitySegmentField::is_noop")]
-    pub field: PollActivitySegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<PollActivitySegmentField>,
     /**
      * Members have/have not interacted with a specific poll in a Mailchimp email.
      */
-    #[serde(default, skip_serializing_if = "PollActivitySegmentOperator::is_noop")]
-    pub op: PollActivitySegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<PollActivitySegmentOperator>,
     /**
      * The price of a product variant.
      */
@@ -1514,34 +1989,57 @@ pub struct PollActivitySegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ConversationSegmentConditionType {
     #[serde(rename = "Conversation")]
     Conversation,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ConversationSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ConversationSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             ConversationSegmentConditionType::Conversation => "Conversation",
-            ConversationSegmentConditionType::Noop => "",
-            ConversationSegmentConditionType::FallthroughString => "*",
+            ConversationSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ConversationSegmentConditionType {
-    fn default() -> ConversationSegmentConditionType {
-        ConversationSegmentConditionType::Noop
+impl std::fmt::Display for ConversationSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ConversationSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Conversation" => ConversationSegmentConditionType::Conversation,
+            _ => ConversationSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for ConversationSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ConversationSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ConversationSegmentConditionType::Noop)
+
+impl From<ConversationSegmentConditionType> for String {
+    fn from(v: ConversationSegmentConditionType) -> Self {
+        match v {
+            ConversationSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1549,34 +2047,57 @@ impl ConversationSegmentConditionType {
  * Segment by interaction with a campaign via Conversations.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ConversationSegmentField {
     #[serde(rename = "conversation")]
     Conversation,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ConversationSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ConversationSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             ConversationSegmentField::Conversation => "conversation",
-            ConversationSegmentField::Noop => "",
-            ConversationSegmentField::FallthroughString => "*",
+            ConversationSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ConversationSegmentField {
-    fn default() -> ConversationSegmentField {
-        ConversationSegmentField::Noop
+impl std::fmt::Display for ConversationSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ConversationSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "conversation" => ConversationSegmentField::Conversation,
+            _ => ConversationSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for ConversationSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ConversationSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ConversationSegmentField::Noop)
+
+impl From<ConversationSegmentField> for String {
+    fn from(v: ConversationSegmentField) -> Self {
+        match v {
+            ConversationSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1591,13 +2112,13 @@ pub struct ConversationSegment {
     /**
      * Segment by interaction with a campaign via Conversations.
      */
-    #[serde(default, skip_serializing_if = "ConversationSegmentField::is_noop")]
-    pub field: ConversationSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<ConversationSegmentField>,
     /**
      * Members have/have not interacted with a specific poll in a Mailchimp email.
      */
-    #[serde(default, skip_serializing_if = "PollActivitySegmentOperator::is_noop")]
-    pub op: PollActivitySegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<PollActivitySegmentOperator>,
     /**
      * The name of the folder.
      */
@@ -1610,34 +2131,57 @@ pub struct ConversationSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateSegmentConditionType {
     #[serde(rename = "Date")]
     Date,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DateSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DateSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             DateSegmentConditionType::Date => "Date",
-            DateSegmentConditionType::Noop => "",
-            DateSegmentConditionType::FallthroughString => "*",
+            DateSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DateSegmentConditionType {
-    fn default() -> DateSegmentConditionType {
-        DateSegmentConditionType::Noop
+impl std::fmt::Display for DateSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DateSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Date" => DateSegmentConditionType::Date,
+            _ => DateSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for DateSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DateSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DateSegmentConditionType::Noop)
+
+impl From<DateSegmentConditionType> for String {
+    fn from(v: DateSegmentConditionType) -> Self {
+        match v {
+            DateSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1645,6 +2189,7 @@ impl DateSegmentConditionType {
  * The type of date field to segment on: The opt-in time for a signup, the date the subscriber was last updated, or the date of their last ecomm purchase.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateSegmentField {
     #[serde(rename = "ecomm_date")]
     EcommDate,
@@ -1652,33 +2197,57 @@ pub enum DateSegmentField {
     InfoChanged,
     #[serde(rename = "timestamp_opt")]
     TimestampOpt,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DateSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DateSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             DateSegmentField::EcommDate => "ecomm_date",
             DateSegmentField::InfoChanged => "info_changed",
             DateSegmentField::TimestampOpt => "timestamp_opt",
-            DateSegmentField::Noop => "",
-            DateSegmentField::FallthroughString => "*",
+            DateSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DateSegmentField {
-    fn default() -> DateSegmentField {
-        DateSegmentField::Noop
+impl std::fmt::Display for DateSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DateSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_date" => DateSegmentField::EcommDate,
+            "info_changed" => DateSegmentField::InfoChanged,
+            "timestamp_opt" => DateSegmentField::TimestampOpt,
+            _ => DateSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for DateSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DateSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DateSegmentField::Noop)
+
+impl From<DateSegmentField> for String {
+    fn from(v: DateSegmentField) -> Self {
+        match v {
+            DateSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1686,6 +2255,7 @@ impl DateSegmentField {
  * When the event took place:  Before, after, is a specific date, is not a specific date, is blank, or is not blank.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -1703,15 +2273,15 @@ pub enum DateSegmentOperator {
     Notwithin,
     #[serde(rename = "within")]
     Within,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DateSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DateSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             DateSegmentOperator::Blank => "blank",
             DateSegmentOperator::BlankNot => "blank_not",
             DateSegmentOperator::Greater => "greater",
@@ -1720,21 +2290,50 @@ impl std::fmt::Display for DateSegmentOperator {
             DateSegmentOperator::Not => "not",
             DateSegmentOperator::Notwithin => "notwithin",
             DateSegmentOperator::Within => "within",
-            DateSegmentOperator::Noop => "",
-            DateSegmentOperator::FallthroughString => "*",
+            DateSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DateSegmentOperator {
-    fn default() -> DateSegmentOperator {
-        DateSegmentOperator::Noop
+impl std::fmt::Display for DateSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DateSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "blank" => DateSegmentOperator::Blank,
+            "blank_not" => DateSegmentOperator::BlankNot,
+            "greater" => DateSegmentOperator::Greater,
+            "is" => DateSegmentOperator::Is,
+            "less" => DateSegmentOperator::Less,
+            "not" => DateSegmentOperator::Not,
+            "notwithin" => DateSegmentOperator::Notwithin,
+            "within" => DateSegmentOperator::Within,
+            _ => DateSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for DateSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DateSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DateSegmentOperator::Noop)
+
+impl From<DateSegmentOperator> for String {
+    fn from(v: DateSegmentOperator) -> Self {
+        match v {
+            DateSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1758,13 +2357,13 @@ pub struct DateSegment {
     /**
      * The type of date field to segment on: The opt-in time for a signup, the date the subscriber was last updated, or the date of their last ecomm purchase.
      */
-    #[serde(default, skip_serializing_if = "DateSegmentField::is_noop")]
-    pub field: DateSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<DateSegmentField>,
     /**
      * When the event took place:  Before, after, is a specific date, is not a specific date, is blank, or is not blank.
      */
-    #[serde(default, skip_serializing_if = "DateSegmentOperator::is_noop")]
-    pub op: DateSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<DateSegmentOperator>,
     /**
      * The name of the folder.
      */
@@ -1777,34 +2376,57 @@ pub struct DateSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailClientSegmentConditionType {
     #[serde(rename = "EmailClient")]
     EmailClient,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EmailClientSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EmailClientSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             EmailClientSegmentConditionType::EmailClient => "EmailClient",
-            EmailClientSegmentConditionType::Noop => "",
-            EmailClientSegmentConditionType::FallthroughString => "*",
+            EmailClientSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EmailClientSegmentConditionType {
-    fn default() -> EmailClientSegmentConditionType {
-        EmailClientSegmentConditionType::Noop
+impl std::fmt::Display for EmailClientSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EmailClientSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "EmailClient" => EmailClientSegmentConditionType::EmailClient,
+            _ => EmailClientSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EmailClientSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EmailClientSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EmailClientSegmentConditionType::Noop)
+
+impl From<EmailClientSegmentConditionType> for String {
+    fn from(v: EmailClientSegmentConditionType) -> Self {
+        match v {
+            EmailClientSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1812,34 +2434,57 @@ impl EmailClientSegmentConditionType {
  * Segment by use of a particular email client.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailClientSegmentField {
     #[serde(rename = "email_client")]
     EmailClient,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EmailClientSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EmailClientSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             EmailClientSegmentField::EmailClient => "email_client",
-            EmailClientSegmentField::Noop => "",
-            EmailClientSegmentField::FallthroughString => "*",
+            EmailClientSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EmailClientSegmentField {
-    fn default() -> EmailClientSegmentField {
-        EmailClientSegmentField::Noop
+impl std::fmt::Display for EmailClientSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EmailClientSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "email_client" => EmailClientSegmentField::EmailClient,
+            _ => EmailClientSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EmailClientSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EmailClientSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EmailClientSegmentField::Noop)
+
+impl From<EmailClientSegmentField> for String {
+    fn from(v: EmailClientSegmentField) -> Self {
+        match v {
+            EmailClientSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1847,37 +2492,61 @@ impl EmailClientSegmentField {
  * The operation to determine whether we select clients that match the value, or clients that do not match the value.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailClientSegmentOperator {
     #[serde(rename = "client_is")]
     ClientIs,
     #[serde(rename = "client_not")]
     ClientNot,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EmailClientSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EmailClientSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             EmailClientSegmentOperator::ClientIs => "client_is",
             EmailClientSegmentOperator::ClientNot => "client_not",
-            EmailClientSegmentOperator::Noop => "",
-            EmailClientSegmentOperator::FallthroughString => "*",
+            EmailClientSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EmailClientSegmentOperator {
-    fn default() -> EmailClientSegmentOperator {
-        EmailClientSegmentOperator::Noop
+impl std::fmt::Display for EmailClientSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EmailClientSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "client_is" => EmailClientSegmentOperator::ClientIs,
+            "client_not" => EmailClientSegmentOperator::ClientNot,
+            _ => EmailClientSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EmailClientSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EmailClientSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EmailClientSegmentOperator::Noop)
+
+impl From<EmailClientSegmentOperator> for String {
+    fn from(v: EmailClientSegmentOperator) -> Self {
+        match v {
+            EmailClientSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1892,13 +2561,13 @@ pub struct EmailClientSegment {
     /**
      * Segment by use of a particular email client.
      */
-    #[serde(default, skip_serializing_if = "EmailClientSegmentField::is_noop")]
-    pub field: EmailClientSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<EmailClientSegmentField>,
     /**
      * The operation to determine whether we select clients that match the value, or clients that do not match the value.
      */
-    #[serde(default, skip_serializing_if = "EmailClientSegmentOperator::is_noop")]
-    pub op: EmailClientSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<EmailClientSegmentOperator>,
     /**
      * The name of the folder.
      */
@@ -1911,34 +2580,57 @@ pub struct EmailClientSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LanguageSegmentConditionType {
     #[serde(rename = "Language")]
     Language,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LanguageSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LanguageSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             LanguageSegmentConditionType::Language => "Language",
-            LanguageSegmentConditionType::Noop => "",
-            LanguageSegmentConditionType::FallthroughString => "*",
+            LanguageSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LanguageSegmentConditionType {
-    fn default() -> LanguageSegmentConditionType {
-        LanguageSegmentConditionType::Noop
+impl std::fmt::Display for LanguageSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LanguageSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Language" => LanguageSegmentConditionType::Language,
+            _ => LanguageSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for LanguageSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LanguageSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LanguageSegmentConditionType::Noop)
+
+impl From<LanguageSegmentConditionType> for String {
+    fn from(v: LanguageSegmentConditionType) -> Self {
+        match v {
+            LanguageSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1946,34 +2638,57 @@ impl LanguageSegmentConditionType {
  * Segmenting based off of a subscriber's language.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LanguageSegmentField {
     #[serde(rename = "language")]
     Language,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LanguageSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LanguageSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             LanguageSegmentField::Language => "language",
-            LanguageSegmentField::Noop => "",
-            LanguageSegmentField::FallthroughString => "*",
+            LanguageSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LanguageSegmentField {
-    fn default() -> LanguageSegmentField {
-        LanguageSegmentField::Noop
+impl std::fmt::Display for LanguageSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LanguageSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "language" => LanguageSegmentField::Language,
+            _ => LanguageSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for LanguageSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LanguageSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LanguageSegmentField::Noop)
+
+impl From<LanguageSegmentField> for String {
+    fn from(v: LanguageSegmentField) -> Self {
+        match v {
+            LanguageSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1981,37 +2696,61 @@ impl LanguageSegmentField {
  * Whether the member's language is or is not set to a specific language.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LanguageSegmentOperator {
     #[serde(rename = "is")]
     Is,
     #[serde(rename = "not")]
     Not,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LanguageSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LanguageSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             LanguageSegmentOperator::Is => "is",
             LanguageSegmentOperator::Not => "not",
-            LanguageSegmentOperator::Noop => "",
-            LanguageSegmentOperator::FallthroughString => "*",
+            LanguageSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LanguageSegmentOperator {
-    fn default() -> LanguageSegmentOperator {
-        LanguageSegmentOperator::Noop
+impl std::fmt::Display for LanguageSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LanguageSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "is" => LanguageSegmentOperator::Is,
+            "not" => LanguageSegmentOperator::Not,
+            _ => LanguageSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for LanguageSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LanguageSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LanguageSegmentOperator::Noop)
+
+impl From<LanguageSegmentOperator> for String {
+    fn from(v: LanguageSegmentOperator) -> Self {
+        match v {
+            LanguageSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2026,13 +2765,13 @@ pub struct LanguageSegment {
     /**
      * Segmenting based off of a subscriber's language.
      */
-    #[serde(default, skip_serializing_if = "LanguageSegmentField::is_noop")]
-    pub field: LanguageSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<LanguageSegmentField>,
     /**
      * Whether the member's language is or is not set to a specific language.
      */
-    #[serde(default, skip_serializing_if = "LanguageSegmentOperator::is_noop")]
-    pub op: LanguageSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<LanguageSegmentOperator>,
     /**
      * The name of the folder.
      */
@@ -2045,34 +2784,57 @@ pub struct LanguageSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MemberRatingSegmentConditionType {
     #[serde(rename = "MemberRating")]
     MemberRating,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for MemberRatingSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for MemberRatingSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             MemberRatingSegmentConditionType::MemberRating => "MemberRating",
-            MemberRatingSegmentConditionType::Noop => "",
-            MemberRatingSegmentConditionType::FallthroughString => "*",
+            MemberRatingSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for MemberRatingSegmentConditionType {
-    fn default() -> MemberRatingSegmentConditionType {
-        MemberRatingSegmentConditionType::Noop
+impl std::fmt::Display for MemberRatingSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for MemberRatingSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "MemberRating" => MemberRatingSegmentConditionType::MemberRating,
+            _ => MemberRatingSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for MemberRatingSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl MemberRatingSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MemberRatingSegmentConditionType::Noop)
+
+impl From<MemberRatingSegmentConditionType> for String {
+    fn from(v: MemberRatingSegmentConditionType) -> Self {
+        match v {
+            MemberRatingSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2080,34 +2842,57 @@ impl MemberRatingSegmentConditionType {
  * Segment by member rating.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MemberRatingSegmentField {
     #[serde(rename = "rating")]
     Rating,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for MemberRatingSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for MemberRatingSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             MemberRatingSegmentField::Rating => "rating",
-            MemberRatingSegmentField::Noop => "",
-            MemberRatingSegmentField::FallthroughString => "*",
+            MemberRatingSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for MemberRatingSegmentField {
-    fn default() -> MemberRatingSegmentField {
-        MemberRatingSegmentField::Noop
+impl std::fmt::Display for MemberRatingSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for MemberRatingSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "rating" => MemberRatingSegmentField::Rating,
+            _ => MemberRatingSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for MemberRatingSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl MemberRatingSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MemberRatingSegmentField::Noop)
+
+impl From<MemberRatingSegmentField> for String {
+    fn from(v: MemberRatingSegmentField) -> Self {
+        match v {
+            MemberRatingSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2115,6 +2900,7 @@ impl MemberRatingSegmentField {
  * Members who have have a rating that is/not exactly a given number or members who have a rating greater/less than a given number.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MemberRatingSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -2124,38 +2910,63 @@ pub enum MemberRatingSegmentOperator {
     Less,
     #[serde(rename = "not")]
     Not,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for MemberRatingSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for MemberRatingSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             MemberRatingSegmentOperator::Greater => "greater",
             MemberRatingSegmentOperator::Is => "is",
             MemberRatingSegmentOperator::Less => "less",
             MemberRatingSegmentOperator::Not => "not",
-            MemberRatingSegmentOperator::Noop => "",
-            MemberRatingSegmentOperator::FallthroughString => "*",
+            MemberRatingSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for MemberRatingSegmentOperator {
-    fn default() -> MemberRatingSegmentOperator {
-        MemberRatingSegmentOperator::Noop
+impl std::fmt::Display for MemberRatingSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl MemberRatingSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MemberRatingSegmentOperator::Noop)
+
+impl std::str::FromStr for MemberRatingSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "greater" => MemberRatingSegmentOperator::Greater,
+            "is" => MemberRatingSegmentOperator::Is,
+            "less" => MemberRatingSegmentOperator::Less,
+            "not" => MemberRatingSegmentOperator::Not,
+            _ => MemberRatingSegmentOperator::Unknown(s.to_string()),
+        })
     }
 }
 
-/// Segment by member rating.
+impl From<String> for MemberRatingSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<MemberRatingSegmentOperator> for String {
+    fn from(v: MemberRatingSegmentOperator) -> Self {
+        match v {
+            MemberRatingSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// Segment by member rating.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MemberRatingSegment {
     /**
@@ -2166,13 +2977,13 @@ pub struct MemberRatingSegment {
     /**
      * Segment by member rating.
      */
-    #[serde(default, skip_serializing_if = "MemberRatingSegmentField::is_noop")]
-    pub field: MemberRatingSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<MemberRatingSegmentField>,
     /**
      * Members who have have a rating that is/not exactly a given number or members who have a rating greater/less than a given number.
      */
-    #[serde(default, skip_serializing_if = "MemberRatingSegmentOperator::is_noop")]
-    pub op: MemberRatingSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<MemberRatingSegmentOperator>,
     /**
      * The price of a product variant.
      */
@@ -2185,66 +2996,112 @@ pub struct MemberRatingSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SignupSourceSegmentType {
     #[serde(rename = "SignupSource")]
     SignupSource,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SignupSourceSegmentType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SignupSourceSegmentType {
+    fn as_ref(&self) -> &str {
+        match self {
             SignupSourceSegmentType::SignupSource => "SignupSource",
-            SignupSourceSegmentType::Noop => "",
-            SignupSourceSegmentType::FallthroughString => "*",
+            SignupSourceSegmentType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SignupSourceSegmentType {
-    fn default() -> SignupSourceSegmentType {
-        SignupSourceSegmentType::Noop
+impl std::fmt::Display for SignupSourceSegmentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SignupSourceSegmentType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "SignupSource" => SignupSourceSegmentType::SignupSource,
+            _ => SignupSourceSegmentType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SignupSourceSegmentType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SignupSourceSegmentType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SignupSourceSegmentType::Noop)
+
+impl From<SignupSourceSegmentType> for String {
+    fn from(v: SignupSourceSegmentType) -> Self {
+        match v {
+            SignupSourceSegmentType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SignupSourceSegmentField {
     #[serde(rename = "source")]
     Source,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SignupSourceSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SignupSourceSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             SignupSourceSegmentField::Source => "source",
-            SignupSourceSegmentField::Noop => "",
-            SignupSourceSegmentField::FallthroughString => "*",
+            SignupSourceSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SignupSourceSegmentField {
-    fn default() -> SignupSourceSegmentField {
-        SignupSourceSegmentField::Noop
+impl std::fmt::Display for SignupSourceSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SignupSourceSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "source" => SignupSourceSegmentField::Source,
+            _ => SignupSourceSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SignupSourceSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SignupSourceSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SignupSourceSegmentField::Noop)
+
+impl From<SignupSourceSegmentField> for String {
+    fn from(v: SignupSourceSegmentField) -> Self {
+        match v {
+            SignupSourceSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2252,52 +3109,76 @@ impl SignupSourceSegmentField {
  * Whether the member's signup source was/was not a particular value.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SignupSourceSegmentOperator {
     #[serde(rename = "source_is")]
     SourceIs,
     #[serde(rename = "source_not")]
     SourceNot,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SignupSourceSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SignupSourceSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             SignupSourceSegmentOperator::SourceIs => "source_is",
             SignupSourceSegmentOperator::SourceNot => "source_not",
-            SignupSourceSegmentOperator::Noop => "",
-            SignupSourceSegmentOperator::FallthroughString => "*",
+            SignupSourceSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SignupSourceSegmentOperator {
-    fn default() -> SignupSourceSegmentOperator {
-        SignupSourceSegmentOperator::Noop
+impl std::fmt::Display for SignupSourceSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SignupSourceSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "source_is" => SignupSourceSegmentOperator::SourceIs,
+            "source_not" => SignupSourceSegmentOperator::SourceNot,
+            _ => SignupSourceSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SignupSourceSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SignupSourceSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SignupSourceSegmentOperator::Noop)
+
+impl From<SignupSourceSegmentOperator> for String {
+    fn from(v: SignupSourceSegmentOperator) -> Self {
+        match v {
+            SignupSourceSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 /// Segment by signup source.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SignupSourceSegment {
-    #[serde(default, skip_serializing_if = "SignupSourceSegmentType::is_noop")]
-    pub condition_type: SignupSourceSegmentType,
-    #[serde(default, skip_serializing_if = "SignupSourceSegmentField::is_noop")]
-    pub field: SignupSourceSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition_type: Option<SignupSourceSegmentType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<SignupSourceSegmentField>,
     /**
      * Whether the member's signup source was/was not a particular value.
      */
-    #[serde(default, skip_serializing_if = "SignupSourceSegmentOperator::is_noop")]
-    pub op: SignupSourceSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<SignupSourceSegmentOperator>,
     /**
      * Segment by signup source.
      */
@@ -2310,34 +3191,57 @@ pub struct SignupSourceSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SurveyMonkeySegmentConditionType {
     #[serde(rename = "SurveyMonkey")]
     SurveyMonkey,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SurveyMonkeySegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SurveyMonkeySegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             SurveyMonkeySegmentConditionType::SurveyMonkey => "SurveyMonkey",
-            SurveyMonkeySegmentConditionType::Noop => "",
-            SurveyMonkeySegmentConditionType::FallthroughString => "*",
+            SurveyMonkeySegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SurveyMonkeySegmentConditionType {
-    fn default() -> SurveyMonkeySegmentConditionType {
-        SurveyMonkeySegmentConditionType::Noop
+impl std::fmt::Display for SurveyMonkeySegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SurveyMonkeySegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "SurveyMonkey" => SurveyMonkeySegmentConditionType::SurveyMonkey,
+            _ => SurveyMonkeySegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SurveyMonkeySegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SurveyMonkeySegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SurveyMonkeySegmentConditionType::Noop)
+
+impl From<SurveyMonkeySegmentConditionType> for String {
+    fn from(v: SurveyMonkeySegmentConditionType) -> Self {
+        match v {
+            SurveyMonkeySegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2345,34 +3249,57 @@ impl SurveyMonkeySegmentConditionType {
  * Segment by interaction with a SurveyMonkey survey.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SurveyMonkeySegmentField {
     #[serde(rename = "survey_monkey")]
     SurveyMonkey,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SurveyMonkeySegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SurveyMonkeySegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             SurveyMonkeySegmentField::SurveyMonkey => "survey_monkey",
-            SurveyMonkeySegmentField::Noop => "",
-            SurveyMonkeySegmentField::FallthroughString => "*",
+            SurveyMonkeySegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SurveyMonkeySegmentField {
-    fn default() -> SurveyMonkeySegmentField {
-        SurveyMonkeySegmentField::Noop
+impl std::fmt::Display for SurveyMonkeySegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SurveyMonkeySegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "survey_monkey" => SurveyMonkeySegmentField::SurveyMonkey,
+            _ => SurveyMonkeySegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SurveyMonkeySegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SurveyMonkeySegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SurveyMonkeySegmentField::Noop)
+
+impl From<SurveyMonkeySegmentField> for String {
+    fn from(v: SurveyMonkeySegmentField) -> Self {
+        match v {
+            SurveyMonkeySegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2387,13 +3314,13 @@ pub struct SurveyMonkeySegment {
     /**
      * Segment by interaction with a SurveyMonkey survey.
      */
-    #[serde(default, skip_serializing_if = "SurveyMonkeySegmentField::is_noop")]
-    pub field: SurveyMonkeySegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<SurveyMonkeySegmentField>,
     /**
      * The status of the member with regard to the automation workflow. One of the following: has started the workflow, has completed the workflow, has not started the workflow, or has not completed the workflow.
      */
-    #[serde(default, skip_serializing_if = "SegmentOperator::is_noop")]
-    pub op: SegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<SegmentOperator>,
     /**
      * The name of the folder.
      */
@@ -2406,34 +3333,57 @@ pub struct SurveyMonkeySegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum VipSegmentConditionType {
     #[serde(rename = "VIP")]
     Vip,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for VipSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for VipSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             VipSegmentConditionType::Vip => "VIP",
-            VipSegmentConditionType::Noop => "",
-            VipSegmentConditionType::FallthroughString => "*",
+            VipSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for VipSegmentConditionType {
-    fn default() -> VipSegmentConditionType {
-        VipSegmentConditionType::Noop
+impl std::fmt::Display for VipSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for VipSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "VIP" => VipSegmentConditionType::Vip,
+            _ => VipSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for VipSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl VipSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, VipSegmentConditionType::Noop)
+
+impl From<VipSegmentConditionType> for String {
+    fn from(v: VipSegmentConditionType) -> Self {
+        match v {
+            VipSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2441,34 +3391,57 @@ impl VipSegmentConditionType {
  * Segment by VIP status.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum VipSegmentField {
     #[serde(rename = "gmonkey")]
     Gmonkey,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for VipSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for VipSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             VipSegmentField::Gmonkey => "gmonkey",
-            VipSegmentField::Noop => "",
-            VipSegmentField::FallthroughString => "*",
+            VipSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for VipSegmentField {
-    fn default() -> VipSegmentField {
-        VipSegmentField::Noop
+impl std::fmt::Display for VipSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for VipSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "gmonkey" => VipSegmentField::Gmonkey,
+            _ => VipSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for VipSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl VipSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, VipSegmentField::Noop)
+
+impl From<VipSegmentField> for String {
+    fn from(v: VipSegmentField) -> Self {
+        match v {
+            VipSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2483,44 +3456,67 @@ pub struct VipSegment {
     /**
      * Segment by VIP status.
      */
-    #[serde(default, skip_serializing_if = "VipSegmentField::is_noop")]
-    pub field: VipSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<VipSegmentField>,
     /**
      * Members have/have not interacted with a specific poll in a Mailchimp email.
      */
-    #[serde(default, skip_serializing_if = "PollActivitySegmentOperator::is_noop")]
-    pub op: PollActivitySegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<PollActivitySegmentOperator>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum InterestsSegmentConditionType {
     #[serde(rename = "Interests")]
     Interests,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for InterestsSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for InterestsSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             InterestsSegmentConditionType::Interests => "Interests",
-            InterestsSegmentConditionType::Noop => "",
-            InterestsSegmentConditionType::FallthroughString => "*",
+            InterestsSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for InterestsSegmentConditionType {
-    fn default() -> InterestsSegmentConditionType {
-        InterestsSegmentConditionType::Noop
+impl std::fmt::Display for InterestsSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for InterestsSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Interests" => InterestsSegmentConditionType::Interests,
+            _ => InterestsSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for InterestsSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl InterestsSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, InterestsSegmentConditionType::Noop)
+
+impl From<InterestsSegmentConditionType> for String {
+    fn from(v: InterestsSegmentConditionType) -> Self {
+        match v {
+            InterestsSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2528,6 +3524,7 @@ impl InterestsSegmentConditionType {
  * Whether the member is a part of one, all, or none of the groups.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum InterestsSegmentOperator {
     #[serde(rename = "interestcontains")]
     Interestcontains,
@@ -2535,33 +3532,57 @@ pub enum InterestsSegmentOperator {
     Interestcontainsall,
     #[serde(rename = "interestnotcontains")]
     Interestnotcontains,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for InterestsSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for InterestsSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             InterestsSegmentOperator::Interestcontains => "interestcontains",
             InterestsSegmentOperator::Interestcontainsall => "interestcontainsall",
             InterestsSegmentOperator::Interestnotcontains => "interestnotcontains",
-            InterestsSegmentOperator::Noop => "",
-            InterestsSegmentOperator::FallthroughString => "*",
+            InterestsSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for InterestsSegmentOperator {
-    fn default() -> InterestsSegmentOperator {
-        InterestsSegmentOperator::Noop
+impl std::fmt::Display for InterestsSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for InterestsSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "interestcontains" => InterestsSegmentOperator::Interestcontains,
+            "interestcontainsall" => InterestsSegmentOperator::Interestcontainsall,
+            "interestnotcontains" => InterestsSegmentOperator::Interestnotcontains,
+            _ => InterestsSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for InterestsSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl InterestsSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, InterestsSegmentOperator::Noop)
+
+impl From<InterestsSegmentOperator> for String {
+    fn from(v: InterestsSegmentOperator) -> Self {
+        match v {
+            InterestsSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2599,34 +3620,57 @@ pub struct InterestsSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceCategorySegmentConditionType {
     #[serde(rename = "EcommCategory")]
     EcommCategory,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommerceCategorySegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommerceCategorySegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommerceCategorySegmentConditionType::EcommCategory => "EcommCategory",
-            EcommerceCategorySegmentConditionType::Noop => "",
-            EcommerceCategorySegmentConditionType::FallthroughString => "*",
+            EcommerceCategorySegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommerceCategorySegmentConditionType {
-    fn default() -> EcommerceCategorySegmentConditionType {
-        EcommerceCategorySegmentConditionType::Noop
+impl std::fmt::Display for EcommerceCategorySegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EcommerceCategorySegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "EcommCategory" => EcommerceCategorySegmentConditionType::EcommCategory,
+            _ => EcommerceCategorySegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EcommerceCategorySegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EcommerceCategorySegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommerceCategorySegmentConditionType::Noop)
+
+impl From<EcommerceCategorySegmentConditionType> for String {
+    fn from(v: EcommerceCategorySegmentConditionType) -> Self {
+        match v {
+            EcommerceCategorySegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2634,37 +3678,61 @@ impl EcommerceCategorySegmentConditionType {
  * Segment by purchases in specific items or categories.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceCategorySegmentField {
     #[serde(rename = "ecomm_cat")]
     EcommCat,
     #[serde(rename = "ecomm_prod")]
     EcommProd,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommerceCategorySegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommerceCategorySegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommerceCategorySegmentField::EcommCat => "ecomm_cat",
             EcommerceCategorySegmentField::EcommProd => "ecomm_prod",
-            EcommerceCategorySegmentField::Noop => "",
-            EcommerceCategorySegmentField::FallthroughString => "*",
+            EcommerceCategorySegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommerceCategorySegmentField {
-    fn default() -> EcommerceCategorySegmentField {
-        EcommerceCategorySegmentField::Noop
+impl std::fmt::Display for EcommerceCategorySegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EcommerceCategorySegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_cat" => EcommerceCategorySegmentField::EcommCat,
+            "ecomm_prod" => EcommerceCategorySegmentField::EcommProd,
+            _ => EcommerceCategorySegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EcommerceCategorySegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EcommerceCategorySegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommerceCategorySegmentField::Noop)
+
+impl From<EcommerceCategorySegmentField> for String {
+    fn from(v: EcommerceCategorySegmentField) -> Self {
+        match v {
+            EcommerceCategorySegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2672,6 +3740,7 @@ impl EcommerceCategorySegmentField {
  * A member who has purchased from a category/specific item that is/is not a specific name, where the category/item name contains/doesn't contain a specific phrase or string, or a category/item name that starts/ends with a string.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceCategorySegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -2685,36 +3754,63 @@ pub enum EcommerceCategorySegmentOperator {
     Notcontain,
     #[serde(rename = "starts")]
     Starts,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommerceCategorySegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommerceCategorySegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommerceCategorySegmentOperator::Contains => "contains",
             EcommerceCategorySegmentOperator::Ends => "ends",
             EcommerceCategorySegmentOperator::Is => "is",
             EcommerceCategorySegmentOperator::Not => "not",
             EcommerceCategorySegmentOperator::Notcontain => "notcontain",
             EcommerceCategorySegmentOperator::Starts => "starts",
-            EcommerceCategorySegmentOperator::Noop => "",
-            EcommerceCategorySegmentOperator::FallthroughString => "*",
+            EcommerceCategorySegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommerceCategorySegmentOperator {
-    fn default() -> EcommerceCategorySegmentOperator {
-        EcommerceCategorySegmentOperator::Noop
+impl std::fmt::Display for EcommerceCategorySegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EcommerceCategorySegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "contains" => EcommerceCategorySegmentOperator::Contains,
+            "ends" => EcommerceCategorySegmentOperator::Ends,
+            "is" => EcommerceCategorySegmentOperator::Is,
+            "not" => EcommerceCategorySegmentOperator::Not,
+            "notcontain" => EcommerceCategorySegmentOperator::Notcontain,
+            "starts" => EcommerceCategorySegmentOperator::Starts,
+            _ => EcommerceCategorySegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EcommerceCategorySegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EcommerceCategorySegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommerceCategorySegmentOperator::Noop)
+
+impl From<EcommerceCategorySegmentOperator> for String {
+    fn from(v: EcommerceCategorySegmentOperator) -> Self {
+        match v {
+            EcommerceCategorySegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2748,34 +3844,57 @@ pub struct EcommerceCategorySegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceNumberSegmentConditionType {
     #[serde(rename = "EcommNumber")]
     EcommNumber,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommerceNumberSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommerceNumberSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommerceNumberSegmentConditionType::EcommNumber => "EcommNumber",
-            EcommerceNumberSegmentConditionType::Noop => "",
-            EcommerceNumberSegmentConditionType::FallthroughString => "*",
+            EcommerceNumberSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommerceNumberSegmentConditionType {
-    fn default() -> EcommerceNumberSegmentConditionType {
-        EcommerceNumberSegmentConditionType::Noop
+impl std::fmt::Display for EcommerceNumberSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EcommerceNumberSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "EcommNumber" => EcommerceNumberSegmentConditionType::EcommNumber,
+            _ => EcommerceNumberSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EcommerceNumberSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EcommerceNumberSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommerceNumberSegmentConditionType::Noop)
+
+impl From<EcommerceNumberSegmentConditionType> for String {
+    fn from(v: EcommerceNumberSegmentConditionType) -> Self {
+        match v {
+            EcommerceNumberSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2783,6 +3902,7 @@ impl EcommerceNumberSegmentConditionType {
  * Segment by average spent total, number of orders, total number of products purchased, or average number of products per order.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceNumberSegmentField {
     #[serde(rename = "ecomm_avg_ord")]
     EcommAvgOrd,
@@ -2792,34 +3912,59 @@ pub enum EcommerceNumberSegmentField {
     EcommProdAll,
     #[serde(rename = "ecomm_spent_avg")]
     EcommSpentAvg,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommerceNumberSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommerceNumberSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommerceNumberSegmentField::EcommAvgOrd => "ecomm_avg_ord",
             EcommerceNumberSegmentField::EcommOrders => "ecomm_orders",
             EcommerceNumberSegmentField::EcommProdAll => "ecomm_prod_all",
             EcommerceNumberSegmentField::EcommSpentAvg => "ecomm_spent_avg",
-            EcommerceNumberSegmentField::Noop => "",
-            EcommerceNumberSegmentField::FallthroughString => "*",
+            EcommerceNumberSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommerceNumberSegmentField {
-    fn default() -> EcommerceNumberSegmentField {
-        EcommerceNumberSegmentField::Noop
+impl std::fmt::Display for EcommerceNumberSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EcommerceNumberSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_avg_ord" => EcommerceNumberSegmentField::EcommAvgOrd,
+            "ecomm_orders" => EcommerceNumberSegmentField::EcommOrders,
+            "ecomm_prod_all" => EcommerceNumberSegmentField::EcommProdAll,
+            "ecomm_spent_avg" => EcommerceNumberSegmentField::EcommSpentAvg,
+            _ => EcommerceNumberSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EcommerceNumberSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EcommerceNumberSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommerceNumberSegmentField::Noop)
+
+impl From<EcommerceNumberSegmentField> for String {
+    fn from(v: EcommerceNumberSegmentField) -> Self {
+        match v {
+            EcommerceNumberSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2834,13 +3979,13 @@ pub struct EcommerceNumberSegment {
     /**
      * Segment by average spent total, number of orders, total number of products purchased, or average number of products per order.
      */
-    #[serde(default, skip_serializing_if = "EcommerceNumberSegmentField::is_noop")]
-    pub field: EcommerceNumberSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<EcommerceNumberSegmentField>,
     /**
      * Members who have have a rating that is/not exactly a given number or members who have a rating greater/less than a given number.
      */
-    #[serde(default, skip_serializing_if = "MemberRatingSegmentOperator::is_noop")]
-    pub op: MemberRatingSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<MemberRatingSegmentOperator>,
     /**
      * The price of a product variant.
      */
@@ -2853,69 +3998,115 @@ pub struct EcommerceNumberSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommercePurchasedSegmentConditionType {
     #[serde(rename = "EcommPurchased")]
     EcommPurchased,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommercePurchasedSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommercePurchasedSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommercePurchasedSegmentConditionType::EcommPurchased => "EcommPurchased",
-            EcommercePurchasedSegmentConditionType::Noop => "",
-            EcommercePurchasedSegmentConditionType::FallthroughString => "*",
+            EcommercePurchasedSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommercePurchasedSegmentConditionType {
-    fn default() -> EcommercePurchasedSegmentConditionType {
-        EcommercePurchasedSegmentConditionType::Noop
+impl std::fmt::Display for EcommercePurchasedSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl EcommercePurchasedSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommercePurchasedSegmentConditionType::Noop)
+
+impl std::str::FromStr for EcommercePurchasedSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "EcommPurchased" => EcommercePurchasedSegmentConditionType::EcommPurchased,
+            _ => EcommercePurchasedSegmentConditionType::Unknown(s.to_string()),
+        })
     }
 }
 
-/**
- * Segment by whether someone has purchased anything.
- */
+impl From<String> for EcommercePurchasedSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<EcommercePurchasedSegmentConditionType> for String {
+    fn from(v: EcommercePurchasedSegmentConditionType) -> Self {
+        match v {
+            EcommercePurchasedSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Segment by whether someone has purchased anything.
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommercePurchasedSegmentField {
     #[serde(rename = "ecomm_purchased")]
     EcommPurchased,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommercePurchasedSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommercePurchasedSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommercePurchasedSegmentField::EcommPurchased => "ecomm_purchased",
-            EcommercePurchasedSegmentField::Noop => "",
-            EcommercePurchasedSegmentField::FallthroughString => "*",
+            EcommercePurchasedSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommercePurchasedSegmentField {
-    fn default() -> EcommercePurchasedSegmentField {
-        EcommercePurchasedSegmentField::Noop
+impl std::fmt::Display for EcommercePurchasedSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EcommercePurchasedSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_purchased" => EcommercePurchasedSegmentField::EcommPurchased,
+            _ => EcommercePurchasedSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EcommercePurchasedSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EcommercePurchasedSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommercePurchasedSegmentField::Noop)
+
+impl From<EcommercePurchasedSegmentField> for String {
+    fn from(v: EcommercePurchasedSegmentField) -> Self {
+        match v {
+            EcommercePurchasedSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2940,34 +4131,57 @@ pub struct EcommercePurchasedSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceSpentSegmentConditionType {
     #[serde(rename = "EcommSpent")]
     EcommSpent,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommerceSpentSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommerceSpentSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommerceSpentSegmentConditionType::EcommSpent => "EcommSpent",
-            EcommerceSpentSegmentConditionType::Noop => "",
-            EcommerceSpentSegmentConditionType::FallthroughString => "*",
+            EcommerceSpentSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommerceSpentSegmentConditionType {
-    fn default() -> EcommerceSpentSegmentConditionType {
-        EcommerceSpentSegmentConditionType::Noop
+impl std::fmt::Display for EcommerceSpentSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EcommerceSpentSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "EcommSpent" => EcommerceSpentSegmentConditionType::EcommSpent,
+            _ => EcommerceSpentSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EcommerceSpentSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EcommerceSpentSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommerceSpentSegmentConditionType::Noop)
+
+impl From<EcommerceSpentSegmentConditionType> for String {
+    fn from(v: EcommerceSpentSegmentConditionType) -> Self {
+        match v {
+            EcommerceSpentSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2975,37 +4189,61 @@ impl EcommerceSpentSegmentConditionType {
  * Segment by amount spent on a single order or across all orders.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceSpentSegmentField {
     #[serde(rename = "ecomm_spent_all")]
     EcommSpentAll,
     #[serde(rename = "ecomm_spent_one")]
     EcommSpentOne,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommerceSpentSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommerceSpentSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommerceSpentSegmentField::EcommSpentAll => "ecomm_spent_all",
             EcommerceSpentSegmentField::EcommSpentOne => "ecomm_spent_one",
-            EcommerceSpentSegmentField::Noop => "",
-            EcommerceSpentSegmentField::FallthroughString => "*",
+            EcommerceSpentSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommerceSpentSegmentField {
-    fn default() -> EcommerceSpentSegmentField {
-        EcommerceSpentSegmentField::Noop
+impl std::fmt::Display for EcommerceSpentSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EcommerceSpentSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_spent_all" => EcommerceSpentSegmentField::EcommSpentAll,
+            "ecomm_spent_one" => EcommerceSpentSegmentField::EcommSpentOne,
+            _ => EcommerceSpentSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EcommerceSpentSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EcommerceSpentSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommerceSpentSegmentField::Noop)
+
+impl From<EcommerceSpentSegmentField> for String {
+    fn from(v: EcommerceSpentSegmentField) -> Self {
+        match v {
+            EcommerceSpentSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3013,37 +4251,61 @@ impl EcommerceSpentSegmentField {
  * Members who have spent 'more' or 'less' than then specified value.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceSpentSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
     #[serde(rename = "less")]
     Less,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommerceSpentSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommerceSpentSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommerceSpentSegmentOperator::Greater => "greater",
             EcommerceSpentSegmentOperator::Less => "less",
-            EcommerceSpentSegmentOperator::Noop => "",
-            EcommerceSpentSegmentOperator::FallthroughString => "*",
+            EcommerceSpentSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommerceSpentSegmentOperator {
-    fn default() -> EcommerceSpentSegmentOperator {
-        EcommerceSpentSegmentOperator::Noop
+impl std::fmt::Display for EcommerceSpentSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EcommerceSpentSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "greater" => EcommerceSpentSegmentOperator::Greater,
+            "less" => EcommerceSpentSegmentOperator::Less,
+            _ => EcommerceSpentSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EcommerceSpentSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EcommerceSpentSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommerceSpentSegmentOperator::Noop)
+
+impl From<EcommerceSpentSegmentOperator> for String {
+    fn from(v: EcommerceSpentSegmentOperator) -> Self {
+        match v {
+            EcommerceSpentSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3077,34 +4339,57 @@ pub struct EcommerceSpentSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommercePurchasedStoreSegmentConditionType {
     #[serde(rename = "EcommStore")]
     EcommStore,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommercePurchasedStoreSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommercePurchasedStoreSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommercePurchasedStoreSegmentConditionType::EcommStore => "EcommStore",
-            EcommercePurchasedStoreSegmentConditionType::Noop => "",
-            EcommercePurchasedStoreSegmentConditionType::FallthroughString => "*",
+            EcommercePurchasedStoreSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommercePurchasedStoreSegmentConditionType {
-    fn default() -> EcommercePurchasedStoreSegmentConditionType {
-        EcommercePurchasedStoreSegmentConditionType::Noop
+impl std::fmt::Display for EcommercePurchasedStoreSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EcommercePurchasedStoreSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "EcommStore" => EcommercePurchasedStoreSegmentConditionType::EcommStore,
+            _ => EcommercePurchasedStoreSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EcommercePurchasedStoreSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EcommercePurchasedStoreSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommercePurchasedStoreSegmentConditionType::Noop)
+
+impl From<EcommercePurchasedStoreSegmentConditionType> for String {
+    fn from(v: EcommercePurchasedStoreSegmentConditionType) -> Self {
+        match v {
+            EcommercePurchasedStoreSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3112,34 +4397,57 @@ impl EcommercePurchasedStoreSegmentConditionType {
  * Segment by purchases from a specific store.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommercePurchasedStoreSegmentField {
     #[serde(rename = "ecomm_store")]
     EcommStore,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommercePurchasedStoreSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommercePurchasedStoreSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommercePurchasedStoreSegmentField::EcommStore => "ecomm_store",
-            EcommercePurchasedStoreSegmentField::Noop => "",
-            EcommercePurchasedStoreSegmentField::FallthroughString => "*",
+            EcommercePurchasedStoreSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommercePurchasedStoreSegmentField {
-    fn default() -> EcommercePurchasedStoreSegmentField {
-        EcommercePurchasedStoreSegmentField::Noop
+impl std::fmt::Display for EcommercePurchasedStoreSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EcommercePurchasedStoreSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_store" => EcommercePurchasedStoreSegmentField::EcommStore,
+            _ => EcommercePurchasedStoreSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EcommercePurchasedStoreSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EcommercePurchasedStoreSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommercePurchasedStoreSegmentField::Noop)
+
+impl From<EcommercePurchasedStoreSegmentField> for String {
+    fn from(v: EcommercePurchasedStoreSegmentField) -> Self {
+        match v {
+            EcommercePurchasedStoreSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3173,34 +4481,57 @@ pub struct EcommercePurchasedStoreSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GoalActivitySegmentConditionType {
     #[serde(rename = "GoalActivity")]
     GoalActivity,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GoalActivitySegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GoalActivitySegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             GoalActivitySegmentConditionType::GoalActivity => "GoalActivity",
-            GoalActivitySegmentConditionType::Noop => "",
-            GoalActivitySegmentConditionType::FallthroughString => "*",
+            GoalActivitySegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GoalActivitySegmentConditionType {
-    fn default() -> GoalActivitySegmentConditionType {
-        GoalActivitySegmentConditionType::Noop
+impl std::fmt::Display for GoalActivitySegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GoalActivitySegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "GoalActivity" => GoalActivitySegmentConditionType::GoalActivity,
+            _ => GoalActivitySegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GoalActivitySegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GoalActivitySegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GoalActivitySegmentConditionType::Noop)
+
+impl From<GoalActivitySegmentConditionType> for String {
+    fn from(v: GoalActivitySegmentConditionType) -> Self {
+        match v {
+            GoalActivitySegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3208,34 +4539,57 @@ impl GoalActivitySegmentConditionType {
  * Segment by Goal activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GoalActivitySegmentField {
     #[serde(rename = "goal")]
     Goal,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GoalActivitySegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GoalActivitySegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             GoalActivitySegmentField::Goal => "goal",
-            GoalActivitySegmentField::Noop => "",
-            GoalActivitySegmentField::FallthroughString => "*",
+            GoalActivitySegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GoalActivitySegmentField {
-    fn default() -> GoalActivitySegmentField {
-        GoalActivitySegmentField::Noop
+impl std::fmt::Display for GoalActivitySegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GoalActivitySegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "goal" => GoalActivitySegmentField::Goal,
+            _ => GoalActivitySegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GoalActivitySegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GoalActivitySegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GoalActivitySegmentField::Noop)
+
+impl From<GoalActivitySegmentField> for String {
+    fn from(v: GoalActivitySegmentField) -> Self {
+        match v {
+            GoalActivitySegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3243,6 +4597,7 @@ impl GoalActivitySegmentField {
  * Whether the website URL is/not exactly, contains/doesn't contain, starts with/ends with a string.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GoalActivitySegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -3256,36 +4611,63 @@ pub enum GoalActivitySegmentOperator {
     Is,
     #[serde(rename = "starts")]
     Starts,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GoalActivitySegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GoalActivitySegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             GoalActivitySegmentOperator::Contains => "contains",
             GoalActivitySegmentOperator::Ends => "ends",
             GoalActivitySegmentOperator::GoalNot => "goal_not",
             GoalActivitySegmentOperator::GoalNotcontain => "goal_notcontain",
             GoalActivitySegmentOperator::Is => "is",
             GoalActivitySegmentOperator::Starts => "starts",
-            GoalActivitySegmentOperator::Noop => "",
-            GoalActivitySegmentOperator::FallthroughString => "*",
+            GoalActivitySegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GoalActivitySegmentOperator {
-    fn default() -> GoalActivitySegmentOperator {
-        GoalActivitySegmentOperator::Noop
+impl std::fmt::Display for GoalActivitySegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GoalActivitySegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "contains" => GoalActivitySegmentOperator::Contains,
+            "ends" => GoalActivitySegmentOperator::Ends,
+            "goal_not" => GoalActivitySegmentOperator::GoalNot,
+            "goal_notcontain" => GoalActivitySegmentOperator::GoalNotcontain,
+            "is" => GoalActivitySegmentOperator::Is,
+            "starts" => GoalActivitySegmentOperator::Starts,
+            _ => GoalActivitySegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GoalActivitySegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GoalActivitySegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GoalActivitySegmentOperator::Noop)
+
+impl From<GoalActivitySegmentOperator> for String {
+    fn from(v: GoalActivitySegmentOperator) -> Self {
+        match v {
+            GoalActivitySegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3300,13 +4682,13 @@ pub struct GoalActivitySegment {
     /**
      * Segment by Goal activity.
      */
-    #[serde(default, skip_serializing_if = "GoalActivitySegmentField::is_noop")]
-    pub field: GoalActivitySegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<GoalActivitySegmentField>,
     /**
      * Whether the website URL is/not exactly, contains/doesn't contain, starts with/ends with a string.
      */
-    #[serde(default, skip_serializing_if = "GoalActivitySegmentOperator::is_noop")]
-    pub op: GoalActivitySegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<GoalActivitySegmentOperator>,
     /**
      * The name of the folder.
      */
@@ -3319,34 +4701,57 @@ pub struct GoalActivitySegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GoalTimestampSegmentConditionType {
     #[serde(rename = "GoalTimestamp")]
     GoalTimestamp,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GoalTimestampSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GoalTimestampSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             GoalTimestampSegmentConditionType::GoalTimestamp => "GoalTimestamp",
-            GoalTimestampSegmentConditionType::Noop => "",
-            GoalTimestampSegmentConditionType::FallthroughString => "*",
+            GoalTimestampSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GoalTimestampSegmentConditionType {
-    fn default() -> GoalTimestampSegmentConditionType {
-        GoalTimestampSegmentConditionType::Noop
+impl std::fmt::Display for GoalTimestampSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GoalTimestampSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "GoalTimestamp" => GoalTimestampSegmentConditionType::GoalTimestamp,
+            _ => GoalTimestampSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GoalTimestampSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GoalTimestampSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GoalTimestampSegmentConditionType::Noop)
+
+impl From<GoalTimestampSegmentConditionType> for String {
+    fn from(v: GoalTimestampSegmentConditionType) -> Self {
+        match v {
+            GoalTimestampSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3354,34 +4759,57 @@ impl GoalTimestampSegmentConditionType {
  * Segment by most recent interaction with a website.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GoalTimestampSegmentField {
     #[serde(rename = "goal_last_visited")]
     GoalLastVisited,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GoalTimestampSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GoalTimestampSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             GoalTimestampSegmentField::GoalLastVisited => "goal_last_visited",
-            GoalTimestampSegmentField::Noop => "",
-            GoalTimestampSegmentField::FallthroughString => "*",
+            GoalTimestampSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GoalTimestampSegmentField {
-    fn default() -> GoalTimestampSegmentField {
-        GoalTimestampSegmentField::Noop
+impl std::fmt::Display for GoalTimestampSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GoalTimestampSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "goal_last_visited" => GoalTimestampSegmentField::GoalLastVisited,
+            _ => GoalTimestampSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GoalTimestampSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GoalTimestampSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GoalTimestampSegmentField::Noop)
+
+impl From<GoalTimestampSegmentField> for String {
+    fn from(v: GoalTimestampSegmentField) -> Self {
+        match v {
+            GoalTimestampSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3389,6 +4817,7 @@ impl GoalTimestampSegmentField {
  * Whether the website activity happened after, before, or at a given timestamp.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GoalTimestampSegmentOperator {
     #[serde(rename = "greater")]
     Greater,
@@ -3396,33 +4825,57 @@ pub enum GoalTimestampSegmentOperator {
     Is,
     #[serde(rename = "less")]
     Less,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GoalTimestampSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GoalTimestampSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             GoalTimestampSegmentOperator::Greater => "greater",
             GoalTimestampSegmentOperator::Is => "is",
             GoalTimestampSegmentOperator::Less => "less",
-            GoalTimestampSegmentOperator::Noop => "",
-            GoalTimestampSegmentOperator::FallthroughString => "*",
+            GoalTimestampSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GoalTimestampSegmentOperator {
-    fn default() -> GoalTimestampSegmentOperator {
-        GoalTimestampSegmentOperator::Noop
+impl std::fmt::Display for GoalTimestampSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GoalTimestampSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "greater" => GoalTimestampSegmentOperator::Greater,
+            "is" => GoalTimestampSegmentOperator::Is,
+            "less" => GoalTimestampSegmentOperator::Less,
+            _ => GoalTimestampSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GoalTimestampSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GoalTimestampSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GoalTimestampSegmentOperator::Noop)
+
+impl From<GoalTimestampSegmentOperator> for String {
+    fn from(v: GoalTimestampSegmentOperator) -> Self {
+        match v {
+            GoalTimestampSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3437,13 +4890,13 @@ pub struct GoalTimestampSegment {
     /**
      * Segment by most recent interaction with a website.
      */
-    #[serde(default, skip_serializing_if = "GoalTimestampSegmentField::is_noop")]
-    pub field: GoalTimestampSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<GoalTimestampSegmentField>,
     /**
      * Whether the website activity happened after, before, or at a given timestamp.
      */
-    #[serde(default, skip_serializing_if = "GoalTimestampSegmentOperator::is_noop")]
-    pub op: GoalTimestampSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<GoalTimestampSegmentOperator>,
     /**
      * The name of the folder.
      */
@@ -3456,34 +4909,57 @@ pub struct GoalTimestampSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SimilarSubscribersSegmentMemberConditionType {
     #[serde(rename = "FuzzySegment")]
     FuzzySegment,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SimilarSubscribersSegmentMemberConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SimilarSubscribersSegmentMemberConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             SimilarSubscribersSegmentMemberConditionType::FuzzySegment => "FuzzySegment",
-            SimilarSubscribersSegmentMemberConditionType::Noop => "",
-            SimilarSubscribersSegmentMemberConditionType::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SimilarSubscribersSegmentMemberConditionType {
-    fn default() -> SimilarSubscribersSegmentMemberConditionType {
-        SimilarSubscribersSegmentMemberConditionType::Noop
+impl std::fmt::Display for SimilarSubscribersSegmentMemberConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SimilarSubscribersSegmentMemberConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "FuzzySegment" => SimilarSubscribersSegmentMemberConditionType::FuzzySegment,
+            _ => SimilarSubscribersSegmentMemberConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SimilarSubscribersSegmentMemberConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SimilarSubscribersSegmentMemberConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SimilarSubscribersSegmentMemberConditionType::Noop)
+
+impl From<SimilarSubscribersSegmentMemberConditionType> for String {
+    fn from(v: SimilarSubscribersSegmentMemberConditionType) -> Self {
+        match v {
+            SimilarSubscribersSegmentMemberConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3491,34 +4967,57 @@ impl SimilarSubscribersSegmentMemberConditionType {
  * Segment by similar subscribers.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SimilarSubscribersSegmentMemberField {
     #[serde(rename = "fuzzy_segment")]
     FuzzySegment,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SimilarSubscribersSegmentMemberField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SimilarSubscribersSegmentMemberField {
+    fn as_ref(&self) -> &str {
+        match self {
             SimilarSubscribersSegmentMemberField::FuzzySegment => "fuzzy_segment",
-            SimilarSubscribersSegmentMemberField::Noop => "",
-            SimilarSubscribersSegmentMemberField::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SimilarSubscribersSegmentMemberField {
-    fn default() -> SimilarSubscribersSegmentMemberField {
-        SimilarSubscribersSegmentMemberField::Noop
+impl std::fmt::Display for SimilarSubscribersSegmentMemberField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SimilarSubscribersSegmentMemberField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "fuzzy_segment" => SimilarSubscribersSegmentMemberField::FuzzySegment,
+            _ => SimilarSubscribersSegmentMemberField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SimilarSubscribersSegmentMemberField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SimilarSubscribersSegmentMemberField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SimilarSubscribersSegmentMemberField::Noop)
+
+impl From<SimilarSubscribersSegmentMemberField> for String {
+    fn from(v: SimilarSubscribersSegmentMemberField) -> Self {
+        match v {
+            SimilarSubscribersSegmentMemberField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3526,37 +5025,61 @@ impl SimilarSubscribersSegmentMemberField {
  * Members who are/are not apart of a 'similar subscribers' segment.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SimilarSubscribersSegmentMemberOperator {
     #[serde(rename = "fuzzy_is")]
     FuzzyIs,
     #[serde(rename = "fuzzy_not")]
     FuzzyNot,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SimilarSubscribersSegmentMemberOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SimilarSubscribersSegmentMemberOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             SimilarSubscribersSegmentMemberOperator::FuzzyIs => "fuzzy_is",
             SimilarSubscribersSegmentMemberOperator::FuzzyNot => "fuzzy_not",
-            SimilarSubscribersSegmentMemberOperator::Noop => "",
-            SimilarSubscribersSegmentMemberOperator::FallthroughString => "*",
+            SimilarSubscribersSegmentMemberOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SimilarSubscribersSegmentMemberOperator {
-    fn default() -> SimilarSubscribersSegmentMemberOperator {
-        SimilarSubscribersSegmentMemberOperator::Noop
+impl std::fmt::Display for SimilarSubscribersSegmentMemberOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SimilarSubscribersSegmentMemberOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "fuzzy_is" => SimilarSubscribersSegmentMemberOperator::FuzzyIs,
+            "fuzzy_not" => SimilarSubscribersSegmentMemberOperator::FuzzyNot,
+            _ => SimilarSubscribersSegmentMemberOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SimilarSubscribersSegmentMemberOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SimilarSubscribersSegmentMemberOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SimilarSubscribersSegmentMemberOperator::Noop)
+
+impl From<SimilarSubscribersSegmentMemberOperator> for String {
+    fn from(v: SimilarSubscribersSegmentMemberOperator) -> Self {
+        match v {
+            SimilarSubscribersSegmentMemberOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3571,19 +5094,13 @@ pub struct SimilarSubscribersSegmentMember {
     /**
      * Segment by similar subscribers.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "SimilarSubscribersSegmentMemberField::is_noop"
-    )]
-    pub field: SimilarSubscribersSegmentMemberField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<SimilarSubscribersSegmentMemberField>,
     /**
      * Members who are/are not apart of a 'similar subscribers' segment.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "SimilarSubscribersSegmentMemberOperator::is_noop"
-    )]
-    pub op: SimilarSubscribersSegmentMemberOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<SimilarSubscribersSegmentMemberOperator>,
     /**
      * The price of a product variant.
      */
@@ -3596,34 +5113,57 @@ pub struct SimilarSubscribersSegmentMember {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum StaticSegmentMemberConditionType {
     #[serde(rename = "StaticSegment")]
     StaticSegment,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for StaticSegmentMemberConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for StaticSegmentMemberConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             StaticSegmentMemberConditionType::StaticSegment => "StaticSegment",
-            StaticSegmentMemberConditionType::Noop => "",
-            StaticSegmentMemberConditionType::FallthroughString => "*",
+            StaticSegmentMemberConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for StaticSegmentMemberConditionType {
-    fn default() -> StaticSegmentMemberConditionType {
-        StaticSegmentMemberConditionType::Noop
+impl std::fmt::Display for StaticSegmentMemberConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl StaticSegmentMemberConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, StaticSegmentMemberConditionType::Noop)
+
+impl std::str::FromStr for StaticSegmentMemberConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "StaticSegment" => StaticSegmentMemberConditionType::StaticSegment,
+            _ => StaticSegmentMemberConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for StaticSegmentMemberConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<StaticSegmentMemberConditionType> for String {
+    fn from(v: StaticSegmentMemberConditionType) -> Self {
+        match v {
+            StaticSegmentMemberConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3631,34 +5171,57 @@ impl StaticSegmentMemberConditionType {
  * Segment by a given static segment.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum StaticSegmentMemberField {
     #[serde(rename = "static_segment")]
     StaticSegment,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for StaticSegmentMemberField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for StaticSegmentMemberField {
+    fn as_ref(&self) -> &str {
+        match self {
             StaticSegmentMemberField::StaticSegment => "static_segment",
-            StaticSegmentMemberField::Noop => "",
-            StaticSegmentMemberField::FallthroughString => "*",
+            StaticSegmentMemberField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for StaticSegmentMemberField {
-    fn default() -> StaticSegmentMemberField {
-        StaticSegmentMemberField::Noop
+impl std::fmt::Display for StaticSegmentMemberField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl StaticSegmentMemberField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, StaticSegmentMemberField::Noop)
+
+impl std::str::FromStr for StaticSegmentMemberField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "static_segment" => StaticSegmentMemberField::StaticSegment,
+            _ => StaticSegmentMemberField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for StaticSegmentMemberField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<StaticSegmentMemberField> for String {
+    fn from(v: StaticSegmentMemberField) -> Self {
+        match v {
+            StaticSegmentMemberField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3666,37 +5229,61 @@ impl StaticSegmentMemberField {
  * Members who are/are not apart of a static segment.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum StaticSegmentMemberOperator {
     #[serde(rename = "static_is")]
     StaticIs,
     #[serde(rename = "static_not")]
     StaticNot,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for StaticSegmentMemberOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for StaticSegmentMemberOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             StaticSegmentMemberOperator::StaticIs => "static_is",
             StaticSegmentMemberOperator::StaticNot => "static_not",
-            StaticSegmentMemberOperator::Noop => "",
-            StaticSegmentMemberOperator::FallthroughString => "*",
+            StaticSegmentMemberOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for StaticSegmentMemberOperator {
-    fn default() -> StaticSegmentMemberOperator {
-        StaticSegmentMemberOperator::Noop
+impl std::fmt::Display for StaticSegmentMemberOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for StaticSegmentMemberOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "static_is" => StaticSegmentMemberOperator::StaticIs,
+            "static_not" => StaticSegmentMemberOperator::StaticNot,
+            _ => StaticSegmentMemberOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for StaticSegmentMemberOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl StaticSegmentMemberOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, StaticSegmentMemberOperator::Noop)
+
+impl From<StaticSegmentMemberOperator> for String {
+    fn from(v: StaticSegmentMemberOperator) -> Self {
+        match v {
+            StaticSegmentMemberOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3711,13 +5298,13 @@ pub struct StaticSegmentMember {
     /**
      * Segment by a given static segment.
      */
-    #[serde(default, skip_serializing_if = "StaticSegmentMemberField::is_noop")]
-    pub field: StaticSegmentMemberField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<StaticSegmentMemberField>,
     /**
      * Members who are/are not apart of a static segment.
      */
-    #[serde(default, skip_serializing_if = "StaticSegmentMemberOperator::is_noop")]
-    pub op: StaticSegmentMemberOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<StaticSegmentMemberOperator>,
     /**
      * The price of a product variant.
      */
@@ -3730,34 +5317,57 @@ pub struct StaticSegmentMember {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoCountryState")]
     IpGeoCountryState,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LocationBasedSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LocationBasedSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             LocationBasedSegmentConditionType::IpGeoCountryState => "IPGeoCountryState",
-            LocationBasedSegmentConditionType::Noop => "",
-            LocationBasedSegmentConditionType::FallthroughString => "*",
+            LocationBasedSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LocationBasedSegmentConditionType {
-    fn default() -> LocationBasedSegmentConditionType {
-        LocationBasedSegmentConditionType::Noop
+impl std::fmt::Display for LocationBasedSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl LocationBasedSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LocationBasedSegmentConditionType::Noop)
+
+impl std::str::FromStr for LocationBasedSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "IPGeoCountryState" => LocationBasedSegmentConditionType::IpGeoCountryState,
+            _ => LocationBasedSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for LocationBasedSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<LocationBasedSegmentConditionType> for String {
+    fn from(v: LocationBasedSegmentConditionType) -> Self {
+        match v {
+            LocationBasedSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3765,34 +5375,57 @@ impl LocationBasedSegmentConditionType {
  * Segmenting subscribers who are within a specific location.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LocationBasedSegmentField {
     #[serde(rename = "ipgeo")]
     Ipgeo,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LocationBasedSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LocationBasedSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             LocationBasedSegmentField::Ipgeo => "ipgeo",
-            LocationBasedSegmentField::Noop => "",
-            LocationBasedSegmentField::FallthroughString => "*",
+            LocationBasedSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LocationBasedSegmentField {
-    fn default() -> LocationBasedSegmentField {
-        LocationBasedSegmentField::Noop
+impl std::fmt::Display for LocationBasedSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LocationBasedSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ipgeo" => LocationBasedSegmentField::Ipgeo,
+            _ => LocationBasedSegmentField::Unknown(s.to_string()),
+        })
     }
 }
-impl LocationBasedSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LocationBasedSegmentField::Noop)
+
+impl From<String> for LocationBasedSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<LocationBasedSegmentField> for String {
+    fn from(v: LocationBasedSegmentField) -> Self {
+        match v {
+            LocationBasedSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3800,6 +5433,7 @@ impl LocationBasedSegmentField {
  * Segment members who are within a specific country or US state.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LocationBasedSegmentOperator {
     #[serde(rename = "ipgeocountry")]
     Ipgeocountry,
@@ -3809,34 +5443,59 @@ pub enum LocationBasedSegmentOperator {
     Ipgeonotstate,
     #[serde(rename = "ipgeostate")]
     Ipgeostate,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LocationBasedSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LocationBasedSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             LocationBasedSegmentOperator::Ipgeocountry => "ipgeocountry",
             LocationBasedSegmentOperator::Ipgeonotcountry => "ipgeonotcountry",
             LocationBasedSegmentOperator::Ipgeonotstate => "ipgeonotstate",
             LocationBasedSegmentOperator::Ipgeostate => "ipgeostate",
-            LocationBasedSegmentOperator::Noop => "",
-            LocationBasedSegmentOperator::FallthroughString => "*",
+            LocationBasedSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LocationBasedSegmentOperator {
-    fn default() -> LocationBasedSegmentOperator {
-        LocationBasedSegmentOperator::Noop
+impl std::fmt::Display for LocationBasedSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LocationBasedSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ipgeocountry" => LocationBasedSegmentOperator::Ipgeocountry,
+            "ipgeonotcountry" => LocationBasedSegmentOperator::Ipgeonotcountry,
+            "ipgeonotstate" => LocationBasedSegmentOperator::Ipgeonotstate,
+            "ipgeostate" => LocationBasedSegmentOperator::Ipgeostate,
+            _ => LocationBasedSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for LocationBasedSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LocationBasedSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LocationBasedSegmentOperator::Noop)
+
+impl From<LocationBasedSegmentOperator> for String {
+    fn from(v: LocationBasedSegmentOperator) -> Self {
+        match v {
+            LocationBasedSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3851,13 +5510,13 @@ pub struct LocationBasedSegment {
     /**
      * Segmenting subscribers who are within a specific location.
      */
-    #[serde(default, skip_serializing_if = "LocationBasedSegmentField::is_noop")]
-    pub field: LocationBasedSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<LocationBasedSegmentField>,
     /**
      * Segment members who are within a specific country or US state.
      */
-    #[serde(default, skip_serializing_if = "LocationBasedSegmentOperator::is_noop")]
-    pub op: LocationBasedSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<LocationBasedSegmentOperator>,
     /**
      * The name of the folder.
      */
@@ -3870,34 +5529,57 @@ pub struct LocationBasedSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GeolocationSegmentConditionType {
     #[serde(rename = "IPGeoIn")]
     IpGeoIn,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GeolocationSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GeolocationSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             GeolocationSegmentConditionType::IpGeoIn => "IPGeoIn",
-            GeolocationSegmentConditionType::Noop => "",
-            GeolocationSegmentConditionType::FallthroughString => "*",
+            GeolocationSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GeolocationSegmentConditionType {
-    fn default() -> GeolocationSegmentConditionType {
-        GeolocationSegmentConditionType::Noop
+impl std::fmt::Display for GeolocationSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl GeolocationSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GeolocationSegmentConditionType::Noop)
+
+impl std::str::FromStr for GeolocationSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "IPGeoIn" => GeolocationSegmentConditionType::IpGeoIn,
+            _ => GeolocationSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GeolocationSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<GeolocationSegmentConditionType> for String {
+    fn from(v: GeolocationSegmentConditionType) -> Self {
+        match v {
+            GeolocationSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3905,37 +5587,61 @@ impl GeolocationSegmentConditionType {
  * Segment members who are within a specific geographic region.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GeolocationSegmentOperator {
     #[serde(rename = "ipgeoin")]
     Ipgeoin,
     #[serde(rename = "ipgeonotin")]
     Ipgeonotin,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GeolocationSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GeolocationSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             GeolocationSegmentOperator::Ipgeoin => "ipgeoin",
             GeolocationSegmentOperator::Ipgeonotin => "ipgeonotin",
-            GeolocationSegmentOperator::Noop => "",
-            GeolocationSegmentOperator::FallthroughString => "*",
+            GeolocationSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GeolocationSegmentOperator {
-    fn default() -> GeolocationSegmentOperator {
-        GeolocationSegmentOperator::Noop
+impl std::fmt::Display for GeolocationSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl GeolocationSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GeolocationSegmentOperator::Noop)
+
+impl std::str::FromStr for GeolocationSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ipgeoin" => GeolocationSegmentOperator::Ipgeoin,
+            "ipgeonotin" => GeolocationSegmentOperator::Ipgeonotin,
+            _ => GeolocationSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GeolocationSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<GeolocationSegmentOperator> for String {
+    fn from(v: GeolocationSegmentOperator) -> Self {
+        match v {
+            GeolocationSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3959,8 +5665,8 @@ pub struct GeolocationSegment {
     /**
      * Segmenting subscribers who are within a specific location.
      */
-    #[serde(default, skip_serializing_if = "LocationBasedSegmentField::is_noop")]
-    pub field: LocationBasedSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<LocationBasedSegmentField>,
     /**
      * The name of the folder.
      */
@@ -3982,8 +5688,8 @@ pub struct GeolocationSegment {
     /**
      * Segment members who are within a specific geographic region.
      */
-    #[serde(default, skip_serializing_if = "GeolocationSegmentOperator::is_noop")]
-    pub op: GeolocationSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<GeolocationSegmentOperator>,
     /**
      * The display order for interests.
      */
@@ -3996,34 +5702,57 @@ pub struct GeolocationSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UsZipCodeSegmentConditionType {
     #[serde(rename = "IPGeoInZip")]
     IpGeoInZip,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UsZipCodeSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UsZipCodeSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             UsZipCodeSegmentConditionType::IpGeoInZip => "IPGeoInZip",
-            UsZipCodeSegmentConditionType::Noop => "",
-            UsZipCodeSegmentConditionType::FallthroughString => "*",
+            UsZipCodeSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for UsZipCodeSegmentConditionType {
-    fn default() -> UsZipCodeSegmentConditionType {
-        UsZipCodeSegmentConditionType::Noop
+impl std::fmt::Display for UsZipCodeSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UsZipCodeSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "IPGeoInZip" => UsZipCodeSegmentConditionType::IpGeoInZip,
+            _ => UsZipCodeSegmentConditionType::Unknown(s.to_string()),
+        })
     }
 }
-impl UsZipCodeSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UsZipCodeSegmentConditionType::Noop)
+
+impl From<String> for UsZipCodeSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<UsZipCodeSegmentConditionType> for String {
+    fn from(v: UsZipCodeSegmentConditionType) -> Self {
+        match v {
+            UsZipCodeSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4031,34 +5760,57 @@ impl UsZipCodeSegmentConditionType {
  * Segment members who are within a specific US zip code.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UsZipCodeSegmentOperator {
     #[serde(rename = "ipgeoinzip")]
     Ipgeoinzip,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UsZipCodeSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UsZipCodeSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             UsZipCodeSegmentOperator::Ipgeoinzip => "ipgeoinzip",
-            UsZipCodeSegmentOperator::Noop => "",
-            UsZipCodeSegmentOperator::FallthroughString => "*",
+            UsZipCodeSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for UsZipCodeSegmentOperator {
-    fn default() -> UsZipCodeSegmentOperator {
-        UsZipCodeSegmentOperator::Noop
+impl std::fmt::Display for UsZipCodeSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UsZipCodeSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ipgeoinzip" => UsZipCodeSegmentOperator::Ipgeoinzip,
+            _ => UsZipCodeSegmentOperator::Unknown(s.to_string()),
+        })
     }
 }
-impl UsZipCodeSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UsZipCodeSegmentOperator::Noop)
+
+impl From<String> for UsZipCodeSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<UsZipCodeSegmentOperator> for String {
+    fn from(v: UsZipCodeSegmentOperator) -> Self {
+        match v {
+            UsZipCodeSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4082,13 +5834,13 @@ pub struct UsZipCodeSegment {
     /**
      * Segmenting subscribers who are within a specific location.
      */
-    #[serde(default, skip_serializing_if = "LocationBasedSegmentField::is_noop")]
-    pub field: LocationBasedSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<LocationBasedSegmentField>,
     /**
      * Segment members who are within a specific US zip code.
      */
-    #[serde(default, skip_serializing_if = "UsZipCodeSegmentOperator::is_noop")]
-    pub op: UsZipCodeSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<UsZipCodeSegmentOperator>,
     /**
      * The display order for interests.
      */
@@ -4101,34 +5853,57 @@ pub struct UsZipCodeSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UnknownLocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoUnknown")]
     IpGeoUnknown,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UnknownLocationBasedSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UnknownLocationBasedSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             UnknownLocationBasedSegmentConditionType::IpGeoUnknown => "IPGeoUnknown",
-            UnknownLocationBasedSegmentConditionType::Noop => "",
-            UnknownLocationBasedSegmentConditionType::FallthroughString => "*",
+            UnknownLocationBasedSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for UnknownLocationBasedSegmentConditionType {
-    fn default() -> UnknownLocationBasedSegmentConditionType {
-        UnknownLocationBasedSegmentConditionType::Noop
+impl std::fmt::Display for UnknownLocationBasedSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UnknownLocationBasedSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "IPGeoUnknown" => UnknownLocationBasedSegmentConditionType::IpGeoUnknown,
+            _ => UnknownLocationBasedSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for UnknownLocationBasedSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl UnknownLocationBasedSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UnknownLocationBasedSegmentConditionType::Noop)
+
+impl From<UnknownLocationBasedSegmentConditionType> for String {
+    fn from(v: UnknownLocationBasedSegmentConditionType) -> Self {
+        match v {
+            UnknownLocationBasedSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4136,34 +5911,57 @@ impl UnknownLocationBasedSegmentConditionType {
  * Segment members for which location information is unknown.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UnknownLocationBasedSegmentOperator {
     #[serde(rename = "ipgeounknown")]
     Ipgeounknown,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UnknownLocationBasedSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UnknownLocationBasedSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             UnknownLocationBasedSegmentOperator::Ipgeounknown => "ipgeounknown",
-            UnknownLocationBasedSegmentOperator::Noop => "",
-            UnknownLocationBasedSegmentOperator::FallthroughString => "*",
+            UnknownLocationBasedSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for UnknownLocationBasedSegmentOperator {
-    fn default() -> UnknownLocationBasedSegmentOperator {
-        UnknownLocationBasedSegmentOperator::Noop
+impl std::fmt::Display for UnknownLocationBasedSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UnknownLocationBasedSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ipgeounknown" => UnknownLocationBasedSegmentOperator::Ipgeounknown,
+            _ => UnknownLocationBasedSegmentOperator::Unknown(s.to_string()),
+        })
     }
 }
-impl UnknownLocationBasedSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UnknownLocationBasedSegmentOperator::Noop)
+
+impl From<String> for UnknownLocationBasedSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<UnknownLocationBasedSegmentOperator> for String {
+    fn from(v: UnknownLocationBasedSegmentOperator) -> Self {
+        match v {
+            UnknownLocationBasedSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4178,47 +5976,67 @@ pub struct UnknownLocationBasedSegment {
     /**
      * Segmenting subscribers who are within a specific location.
      */
-    #[serde(default, skip_serializing_if = "LocationBasedSegmentField::is_noop")]
-    pub field: LocationBasedSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<LocationBasedSegmentField>,
     /**
      * Segment members for which location information is unknown.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "UnknownLocationBasedSegmentOperator::is_noop"
-    )]
-    pub op: UnknownLocationBasedSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<UnknownLocationBasedSegmentOperator>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ZipCodeLocationBasedSegmentConditionType {
     #[serde(rename = "IPGeoZip")]
     IpGeoZip,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ZipCodeLocationBasedSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ZipCodeLocationBasedSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             ZipCodeLocationBasedSegmentConditionType::IpGeoZip => "IPGeoZip",
-            ZipCodeLocationBasedSegmentConditionType::Noop => "",
-            ZipCodeLocationBasedSegmentConditionType::FallthroughString => "*",
+            ZipCodeLocationBasedSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ZipCodeLocationBasedSegmentConditionType {
-    fn default() -> ZipCodeLocationBasedSegmentConditionType {
-        ZipCodeLocationBasedSegmentConditionType::Noop
+impl std::fmt::Display for ZipCodeLocationBasedSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ZipCodeLocationBasedSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "IPGeoZip" => ZipCodeLocationBasedSegmentConditionType::IpGeoZip,
+            _ => ZipCodeLocationBasedSegmentConditionType::Unknown(s.to_string()),
+        })
     }
 }
-impl ZipCodeLocationBasedSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ZipCodeLocationBasedSegmentConditionType::Noop)
+
+impl From<String> for ZipCodeLocationBasedSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<ZipCodeLocationBasedSegmentConditionType> for String {
+    fn from(v: ZipCodeLocationBasedSegmentConditionType) -> Self {
+        match v {
+            ZipCodeLocationBasedSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4226,37 +6044,61 @@ impl ZipCodeLocationBasedSegmentConditionType {
  * Segment members who are/are not within a specific US zip code.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ZipCodeLocationBasedSegmentOperator {
     #[serde(rename = "ipgeoiszip")]
     Ipgeoiszip,
     #[serde(rename = "ipgeonotzip")]
     Ipgeonotzip,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ZipCodeLocationBasedSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ZipCodeLocationBasedSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             ZipCodeLocationBasedSegmentOperator::Ipgeoiszip => "ipgeoiszip",
             ZipCodeLocationBasedSegmentOperator::Ipgeonotzip => "ipgeonotzip",
-            ZipCodeLocationBasedSegmentOperator::Noop => "",
-            ZipCodeLocationBasedSegmentOperator::FallthroughString => "*",
+            ZipCodeLocationBasedSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ZipCodeLocationBasedSegmentOperator {
-    fn default() -> ZipCodeLocationBasedSegmentOperator {
-        ZipCodeLocationBasedSegmentOperator::Noop
+impl std::fmt::Display for ZipCodeLocationBasedSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl ZipCodeLocationBasedSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ZipCodeLocationBasedSegmentOperator::Noop)
+
+impl std::str::FromStr for ZipCodeLocationBasedSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ipgeoiszip" => ZipCodeLocationBasedSegmentOperator::Ipgeoiszip,
+            "ipgeonotzip" => ZipCodeLocationBasedSegmentOperator::Ipgeonotzip,
+            _ => ZipCodeLocationBasedSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for ZipCodeLocationBasedSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<ZipCodeLocationBasedSegmentOperator> for String {
+    fn from(v: ZipCodeLocationBasedSegmentOperator) -> Self {
+        match v {
+            ZipCodeLocationBasedSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4271,16 +6113,13 @@ pub struct ZipCodeLocationBasedSegment {
     /**
      * Segmenting subscribers who are within a specific location.
      */
-    #[serde(default, skip_serializing_if = "LocationBasedSegmentField::is_noop")]
-    pub field: LocationBasedSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<LocationBasedSegmentField>,
     /**
      * Segment members who are/are not within a specific US zip code.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "ZipCodeLocationBasedSegmentOperator::is_noop"
-    )]
-    pub op: ZipCodeLocationBasedSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<ZipCodeLocationBasedSegmentOperator>,
     /**
      * The display order for interests.
      */
@@ -4293,34 +6132,57 @@ pub struct ZipCodeLocationBasedSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesAgeSegmentConditionType {
     #[serde(rename = "SocialAge")]
     SocialAge,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SocialProfilesAgeSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SocialProfilesAgeSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             SocialProfilesAgeSegmentConditionType::SocialAge => "SocialAge",
-            SocialProfilesAgeSegmentConditionType::Noop => "",
-            SocialProfilesAgeSegmentConditionType::FallthroughString => "*",
+            SocialProfilesAgeSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SocialProfilesAgeSegmentConditionType {
-    fn default() -> SocialProfilesAgeSegmentConditionType {
-        SocialProfilesAgeSegmentConditionType::Noop
+impl std::fmt::Display for SocialProfilesAgeSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl SocialProfilesAgeSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SocialProfilesAgeSegmentConditionType::Noop)
+
+impl std::str::FromStr for SocialProfilesAgeSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "SocialAge" => SocialProfilesAgeSegmentConditionType::SocialAge,
+            _ => SocialProfilesAgeSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SocialProfilesAgeSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<SocialProfilesAgeSegmentConditionType> for String {
+    fn from(v: SocialProfilesAgeSegmentConditionType) -> Self {
+        match v {
+            SocialProfilesAgeSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4328,34 +6190,57 @@ impl SocialProfilesAgeSegmentConditionType {
  * Segment by age ranges in Social Profiles data.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesAgeSegmentField {
     #[serde(rename = "social_age")]
     SocialAge,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SocialProfilesAgeSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SocialProfilesAgeSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             SocialProfilesAgeSegmentField::SocialAge => "social_age",
-            SocialProfilesAgeSegmentField::Noop => "",
-            SocialProfilesAgeSegmentField::FallthroughString => "*",
+            SocialProfilesAgeSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SocialProfilesAgeSegmentField {
-    fn default() -> SocialProfilesAgeSegmentField {
-        SocialProfilesAgeSegmentField::Noop
+impl std::fmt::Display for SocialProfilesAgeSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SocialProfilesAgeSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "social_age" => SocialProfilesAgeSegmentField::SocialAge,
+            _ => SocialProfilesAgeSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SocialProfilesAgeSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SocialProfilesAgeSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SocialProfilesAgeSegmentField::Noop)
+
+impl From<SocialProfilesAgeSegmentField> for String {
+    fn from(v: SocialProfilesAgeSegmentField) -> Self {
+        match v {
+            SocialProfilesAgeSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4363,6 +6248,7 @@ impl SocialProfilesAgeSegmentField {
  * The age range to segment.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Value {
     #[serde(rename = "18-24")]
     OneThousandEightHundredAndTwentyFour,
@@ -4372,34 +6258,59 @@ pub enum Value {
     ThreeThousandFiveHundredAndFiftyFour,
     #[serde(rename = "55+")]
     FiftyFive,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Value {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Value {
+    fn as_ref(&self) -> &str {
+        match self {
             Value::OneThousandEightHundredAndTwentyFour => "18-24",
             Value::TwoThousandFiveHundredAndThirtyFour => "25-34",
             Value::ThreeThousandFiveHundredAndFiftyFour => "35-54",
             Value::FiftyFive => "55+",
-            Value::Noop => "",
-            Value::FallthroughString => "*",
+            Value::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Value {
-    fn default() -> Value {
-        Value::Noop
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Value {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "18-24" => Value::OneThousandEightHundredAndTwentyFour,
+            "25-34" => Value::TwoThousandFiveHundredAndThirtyFour,
+            "35-54" => Value::ThreeThousandFiveHundredAndFiftyFour,
+            "55+" => Value::FiftyFive,
+            _ => Value::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Value {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Value::Noop)
+
+impl From<Value> for String {
+    fn from(v: Value) -> Self {
+        match v {
+            Value::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4414,52 +6325,72 @@ pub struct SocialProfilesAgeSegment {
     /**
      * Segment by age ranges in Social Profiles data.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "SocialProfilesAgeSegmentField::is_noop"
-    )]
-    pub field: SocialProfilesAgeSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<SocialProfilesAgeSegmentField>,
     /**
      * Whether the member's language is or is not set to a specific language.
      */
-    #[serde(default, skip_serializing_if = "LanguageSegmentOperator::is_noop")]
-    pub op: LanguageSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<LanguageSegmentOperator>,
     /**
      * The age range to segment.
      */
-    #[serde(default, skip_serializing_if = "Value::is_noop")]
-    pub value: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesGenderSegmentConditionType {
     #[serde(rename = "SocialGender")]
     SocialGender,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SocialProfilesGenderSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SocialProfilesGenderSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             SocialProfilesGenderSegmentConditionType::SocialGender => "SocialGender",
-            SocialProfilesGenderSegmentConditionType::Noop => "",
-            SocialProfilesGenderSegmentConditionType::FallthroughString => "*",
+            SocialProfilesGenderSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SocialProfilesGenderSegmentConditionType {
-    fn default() -> SocialProfilesGenderSegmentConditionType {
-        SocialProfilesGenderSegmentConditionType::Noop
+impl std::fmt::Display for SocialProfilesGenderSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SocialProfilesGenderSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "SocialGender" => SocialProfilesGenderSegmentConditionType::SocialGender,
+            _ => SocialProfilesGenderSegmentConditionType::Unknown(s.to_string()),
+        })
     }
 }
-impl SocialProfilesGenderSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SocialProfilesGenderSegmentConditionType::Noop)
+
+impl From<String> for SocialProfilesGenderSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<SocialProfilesGenderSegmentConditionType> for String {
+    fn from(v: SocialProfilesGenderSegmentConditionType) -> Self {
+        match v {
+            SocialProfilesGenderSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4467,34 +6398,57 @@ impl SocialProfilesGenderSegmentConditionType {
  * Segment by listed gender in Social Profiles data.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesGenderSegmentField {
     #[serde(rename = "social_gender")]
     SocialGender,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SocialProfilesGenderSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SocialProfilesGenderSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             SocialProfilesGenderSegmentField::SocialGender => "social_gender",
-            SocialProfilesGenderSegmentField::Noop => "",
-            SocialProfilesGenderSegmentField::FallthroughString => "*",
+            SocialProfilesGenderSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SocialProfilesGenderSegmentField {
-    fn default() -> SocialProfilesGenderSegmentField {
-        SocialProfilesGenderSegmentField::Noop
+impl std::fmt::Display for SocialProfilesGenderSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SocialProfilesGenderSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "social_gender" => SocialProfilesGenderSegmentField::SocialGender,
+            _ => SocialProfilesGenderSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SocialProfilesGenderSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SocialProfilesGenderSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SocialProfilesGenderSegmentField::Noop)
+
+impl From<SocialProfilesGenderSegmentField> for String {
+    fn from(v: SocialProfilesGenderSegmentField) -> Self {
+        match v {
+            SocialProfilesGenderSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4502,37 +6456,61 @@ impl SocialProfilesGenderSegmentField {
  * The Social Profiles gender to segment.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesGenderSegmentOperator {
     #[serde(rename = "female")]
     Female,
     #[serde(rename = "male")]
     Male,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SocialProfilesGenderSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SocialProfilesGenderSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             SocialProfilesGenderSegmentOperator::Female => "female",
             SocialProfilesGenderSegmentOperator::Male => "male",
-            SocialProfilesGenderSegmentOperator::Noop => "",
-            SocialProfilesGenderSegmentOperator::FallthroughString => "*",
+            SocialProfilesGenderSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SocialProfilesGenderSegmentOperator {
-    fn default() -> SocialProfilesGenderSegmentOperator {
-        SocialProfilesGenderSegmentOperator::Noop
+impl std::fmt::Display for SocialProfilesGenderSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SocialProfilesGenderSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "female" => SocialProfilesGenderSegmentOperator::Female,
+            "male" => SocialProfilesGenderSegmentOperator::Male,
+            _ => SocialProfilesGenderSegmentOperator::Unknown(s.to_string()),
+        })
     }
 }
-impl SocialProfilesGenderSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SocialProfilesGenderSegmentOperator::Noop)
+
+impl From<String> for SocialProfilesGenderSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<SocialProfilesGenderSegmentOperator> for String {
+    fn from(v: SocialProfilesGenderSegmentOperator) -> Self {
+        match v {
+            SocialProfilesGenderSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4547,55 +6525,72 @@ pub struct SocialProfilesGenderSegment {
     /**
      * Segment by listed gender in Social Profiles data.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "SocialProfilesGenderSegmentField::is_noop"
-    )]
-    pub field: SocialProfilesGenderSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<SocialProfilesGenderSegmentField>,
     /**
      * Whether the member's language is or is not set to a specific language.
      */
-    #[serde(default, skip_serializing_if = "LanguageSegmentOperator::is_noop")]
-    pub op: LanguageSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<LanguageSegmentOperator>,
     /**
      * The Social Profiles gender to segment.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "SocialProfilesGenderSegmentOperator::is_noop"
-    )]
-    pub value: SocialProfilesGenderSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<SocialProfilesGenderSegmentOperator>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesInfluenceSegmentConditionType {
     #[serde(rename = "SocialInfluence")]
     SocialInfluence,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SocialProfilesInfluenceSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SocialProfilesInfluenceSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             SocialProfilesInfluenceSegmentConditionType::SocialInfluence => "SocialInfluence",
-            SocialProfilesInfluenceSegmentConditionType::Noop => "",
-            SocialProfilesInfluenceSegmentConditionType::FallthroughString => "*",
+            SocialProfilesInfluenceSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SocialProfilesInfluenceSegmentConditionType {
-    fn default() -> SocialProfilesInfluenceSegmentConditionType {
-        SocialProfilesInfluenceSegmentConditionType::Noop
+impl std::fmt::Display for SocialProfilesInfluenceSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl SocialProfilesInfluenceSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SocialProfilesInfluenceSegmentConditionType::Noop)
+
+impl std::str::FromStr for SocialProfilesInfluenceSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "SocialInfluence" => SocialProfilesInfluenceSegmentConditionType::SocialInfluence,
+            _ => SocialProfilesInfluenceSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SocialProfilesInfluenceSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<SocialProfilesInfluenceSegmentConditionType> for String {
+    fn from(v: SocialProfilesInfluenceSegmentConditionType) -> Self {
+        match v {
+            SocialProfilesInfluenceSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4603,34 +6598,57 @@ impl SocialProfilesInfluenceSegmentConditionType {
  * Segment by influence rating in Social Profiles data.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesInfluenceSegmentField {
     #[serde(rename = "social_influence")]
     SocialInfluence,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SocialProfilesInfluenceSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SocialProfilesInfluenceSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             SocialProfilesInfluenceSegmentField::SocialInfluence => "social_influence",
-            SocialProfilesInfluenceSegmentField::Noop => "",
-            SocialProfilesInfluenceSegmentField::FallthroughString => "*",
+            SocialProfilesInfluenceSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SocialProfilesInfluenceSegmentField {
-    fn default() -> SocialProfilesInfluenceSegmentField {
-        SocialProfilesInfluenceSegmentField::Noop
+impl std::fmt::Display for SocialProfilesInfluenceSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SocialProfilesInfluenceSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "social_influence" => SocialProfilesInfluenceSegmentField::SocialInfluence,
+            _ => SocialProfilesInfluenceSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SocialProfilesInfluenceSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SocialProfilesInfluenceSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SocialProfilesInfluenceSegmentField::Noop)
+
+impl From<SocialProfilesInfluenceSegmentField> for String {
+    fn from(v: SocialProfilesInfluenceSegmentField) -> Self {
+        match v {
+            SocialProfilesInfluenceSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4645,16 +6663,13 @@ pub struct SocialProfilesInfluenceSegment {
     /**
      * Segment by influence rating in Social Profiles data.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "SocialProfilesInfluenceSegmentField::is_noop"
-    )]
-    pub field: SocialProfilesInfluenceSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<SocialProfilesInfluenceSegmentField>,
     /**
      * Members who have have a rating that is/not exactly a given number or members who have a rating greater/less than a given number.
      */
-    #[serde(default, skip_serializing_if = "MemberRatingSegmentOperator::is_noop")]
-    pub op: MemberRatingSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<MemberRatingSegmentOperator>,
     /**
      * The price of a product variant.
      */
@@ -4667,34 +6682,57 @@ pub struct SocialProfilesInfluenceSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesNetworkSegmentConditionType {
     #[serde(rename = "SocialNetworkMember")]
     SocialNetworkMember,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SocialProfilesNetworkSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SocialProfilesNetworkSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             SocialProfilesNetworkSegmentConditionType::SocialNetworkMember => "SocialNetworkMember",
-            SocialProfilesNetworkSegmentConditionType::Noop => "",
-            SocialProfilesNetworkSegmentConditionType::FallthroughString => "*",
+            SocialProfilesNetworkSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SocialProfilesNetworkSegmentConditionType {
-    fn default() -> SocialProfilesNetworkSegmentConditionType {
-        SocialProfilesNetworkSegmentConditionType::Noop
+impl std::fmt::Display for SocialProfilesNetworkSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SocialProfilesNetworkSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "SocialNetworkMember" => SocialProfilesNetworkSegmentConditionType::SocialNetworkMember,
+            _ => SocialProfilesNetworkSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SocialProfilesNetworkSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SocialProfilesNetworkSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SocialProfilesNetworkSegmentConditionType::Noop)
+
+impl From<SocialProfilesNetworkSegmentConditionType> for String {
+    fn from(v: SocialProfilesNetworkSegmentConditionType) -> Self {
+        match v {
+            SocialProfilesNetworkSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4702,34 +6740,57 @@ impl SocialProfilesNetworkSegmentConditionType {
  * Segment by social network in Social Profiles data.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesNetworkSegmentField {
     #[serde(rename = "social_network")]
     SocialNetwork,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SocialProfilesNetworkSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SocialProfilesNetworkSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             SocialProfilesNetworkSegmentField::SocialNetwork => "social_network",
-            SocialProfilesNetworkSegmentField::Noop => "",
-            SocialProfilesNetworkSegmentField::FallthroughString => "*",
+            SocialProfilesNetworkSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SocialProfilesNetworkSegmentField {
-    fn default() -> SocialProfilesNetworkSegmentField {
-        SocialProfilesNetworkSegmentField::Noop
+impl std::fmt::Display for SocialProfilesNetworkSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SocialProfilesNetworkSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "social_network" => SocialProfilesNetworkSegmentField::SocialNetwork,
+            _ => SocialProfilesNetworkSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SocialProfilesNetworkSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SocialProfilesNetworkSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SocialProfilesNetworkSegmentField::Noop)
+
+impl From<SocialProfilesNetworkSegmentField> for String {
+    fn from(v: SocialProfilesNetworkSegmentField) -> Self {
+        match v {
+            SocialProfilesNetworkSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4737,6 +6798,7 @@ impl SocialProfilesNetworkSegmentField {
  * The social network to segment against.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesNetworkSegmentOperator {
     #[serde(rename = "facebook")]
     Facebook,
@@ -4760,15 +6822,15 @@ pub enum SocialProfilesNetworkSegmentOperator {
     Yelp,
     #[serde(rename = "youtube")]
     Youtube,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SocialProfilesNetworkSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SocialProfilesNetworkSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             SocialProfilesNetworkSegmentOperator::Facebook => "facebook",
             SocialProfilesNetworkSegmentOperator::Flickr => "flickr",
             SocialProfilesNetworkSegmentOperator::Foursquare => "foursquare",
@@ -4780,21 +6842,53 @@ impl std::fmt::Display for SocialProfilesNetworkSegmentOperator {
             SocialProfilesNetworkSegmentOperator::Vimeo => "vimeo",
             SocialProfilesNetworkSegmentOperator::Yelp => "yelp",
             SocialProfilesNetworkSegmentOperator::Youtube => "youtube",
-            SocialProfilesNetworkSegmentOperator::Noop => "",
-            SocialProfilesNetworkSegmentOperator::FallthroughString => "*",
+            SocialProfilesNetworkSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SocialProfilesNetworkSegmentOperator {
-    fn default() -> SocialProfilesNetworkSegmentOperator {
-        SocialProfilesNetworkSegmentOperator::Noop
+impl std::fmt::Display for SocialProfilesNetworkSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SocialProfilesNetworkSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "facebook" => SocialProfilesNetworkSegmentOperator::Facebook,
+            "flickr" => SocialProfilesNetworkSegmentOperator::Flickr,
+            "foursquare" => SocialProfilesNetworkSegmentOperator::Foursquare,
+            "lastfm" => SocialProfilesNetworkSegmentOperator::Lastfm,
+            "linkedin" => SocialProfilesNetworkSegmentOperator::Linkedin,
+            "myspace" => SocialProfilesNetworkSegmentOperator::Myspace,
+            "quora" => SocialProfilesNetworkSegmentOperator::Quora,
+            "twitter" => SocialProfilesNetworkSegmentOperator::Twitter,
+            "vimeo" => SocialProfilesNetworkSegmentOperator::Vimeo,
+            "yelp" => SocialProfilesNetworkSegmentOperator::Yelp,
+            "youtube" => SocialProfilesNetworkSegmentOperator::Youtube,
+            _ => SocialProfilesNetworkSegmentOperator::Unknown(s.to_string()),
+        })
     }
 }
-impl SocialProfilesNetworkSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SocialProfilesNetworkSegmentOperator::Noop)
+
+impl From<String> for SocialProfilesNetworkSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl From<SocialProfilesNetworkSegmentOperator> for String {
+    fn from(v: SocialProfilesNetworkSegmentOperator) -> Self {
+        match v {
+            SocialProfilesNetworkSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4809,57 +6903,76 @@ pub struct SocialProfilesNetworkSegment {
     /**
      * Segment by social network in Social Profiles data.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "SocialProfilesNetworkSegmentField::is_noop"
-    )]
-    pub field: SocialProfilesNetworkSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<SocialProfilesNetworkSegmentField>,
     /**
      * Members have/have not interacted with a specific poll in a Mailchimp email.
      */
-    #[serde(default, skip_serializing_if = "PollActivitySegmentOperator::is_noop")]
-    pub op: PollActivitySegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<PollActivitySegmentOperator>,
     /**
      * The social network to segment against.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "SocialProfilesNetworkSegmentOperator::is_noop"
-    )]
-    pub value: SocialProfilesNetworkSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<SocialProfilesNetworkSegmentOperator>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesNetworkFollowSegmentConditionType {
     #[serde(rename = "SocialNetworkFollow")]
     SocialNetworkFollow,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SocialProfilesNetworkFollowSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SocialProfilesNetworkFollowSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             SocialProfilesNetworkFollowSegmentConditionType::SocialNetworkFollow => {
                 "SocialNetworkFollow"
             }
-            SocialProfilesNetworkFollowSegmentConditionType::Noop => "",
-            SocialProfilesNetworkFollowSegmentConditionType::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SocialProfilesNetworkFollowSegmentConditionType {
-    fn default() -> SocialProfilesNetworkFollowSegmentConditionType {
-        SocialProfilesNetworkFollowSegmentConditionType::Noop
+impl std::fmt::Display for SocialProfilesNetworkFollowSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SocialProfilesNetworkFollowSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "SocialNetworkFollow" => {
+                SocialProfilesNetworkFollowSegmentConditionType::SocialNetworkFollow
+            }
+            _ => SocialProfilesNetworkFollowSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SocialProfilesNetworkFollowSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SocialProfilesNetworkFollowSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SocialProfilesNetworkFollowSegmentConditionType::Noop)
+
+impl From<SocialProfilesNetworkFollowSegmentConditionType> for String {
+    fn from(v: SocialProfilesNetworkFollowSegmentConditionType) -> Self {
+        match v {
+            SocialProfilesNetworkFollowSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4867,37 +6980,61 @@ impl SocialProfilesNetworkFollowSegmentConditionType {
  * Members who are/not following a linked account on a given social network.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesNetworkFollowSegmentOperator {
     #[serde(rename = "follow")]
     Follow,
     #[serde(rename = "notfollow")]
     Notfollow,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SocialProfilesNetworkFollowSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             SocialProfilesNetworkFollowSegmentOperator::Follow => "follow",
             SocialProfilesNetworkFollowSegmentOperator::Notfollow => "notfollow",
-            SocialProfilesNetworkFollowSegmentOperator::Noop => "",
-            SocialProfilesNetworkFollowSegmentOperator::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SocialProfilesNetworkFollowSegmentOperator {
-    fn default() -> SocialProfilesNetworkFollowSegmentOperator {
-        SocialProfilesNetworkFollowSegmentOperator::Noop
+impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SocialProfilesNetworkFollowSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "follow" => SocialProfilesNetworkFollowSegmentOperator::Follow,
+            "notfollow" => SocialProfilesNetworkFollowSegmentOperator::Notfollow,
+            _ => SocialProfilesNetworkFollowSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SocialProfilesNetworkFollowSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SocialProfilesNetworkFollowSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SocialProfilesNetworkFollowSegmentOperator::Noop)
+
+impl From<SocialProfilesNetworkFollowSegmentOperator> for String {
+    fn from(v: SocialProfilesNetworkFollowSegmentOperator) -> Self {
+        match v {
+            SocialProfilesNetworkFollowSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4905,34 +7042,57 @@ impl SocialProfilesNetworkFollowSegmentOperator {
  * The social network to segment against.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SocialProfilesNetworkFollowSegmentOperatorData {
     #[serde(rename = "twitter_follow")]
     TwitterFollow,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperatorData {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SocialProfilesNetworkFollowSegmentOperatorData {
+    fn as_ref(&self) -> &str {
+        match self {
             SocialProfilesNetworkFollowSegmentOperatorData::TwitterFollow => "twitter_follow",
-            SocialProfilesNetworkFollowSegmentOperatorData::Noop => "",
-            SocialProfilesNetworkFollowSegmentOperatorData::FallthroughString => "*",
+            SocialProfilesNetworkFollowSegmentOperatorData::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SocialProfilesNetworkFollowSegmentOperatorData {
-    fn default() -> SocialProfilesNetworkFollowSegmentOperatorData {
-        SocialProfilesNetworkFollowSegmentOperatorData::Noop
+impl std::fmt::Display for SocialProfilesNetworkFollowSegmentOperatorData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SocialProfilesNetworkFollowSegmentOperatorData {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "twitter_follow" => SocialProfilesNetworkFollowSegmentOperatorData::TwitterFollow,
+            _ => SocialProfilesNetworkFollowSegmentOperatorData::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SocialProfilesNetworkFollowSegmentOperatorData {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SocialProfilesNetworkFollowSegmentOperatorData {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SocialProfilesNetworkFollowSegmentOperatorData::Noop)
+
+impl From<SocialProfilesNetworkFollowSegmentOperatorData> for String {
+    fn from(v: SocialProfilesNetworkFollowSegmentOperatorData) -> Self {
+        match v {
+            SocialProfilesNetworkFollowSegmentOperatorData::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4947,58 +7107,72 @@ pub struct SocialProfilesNetworkFollowSegment {
     /**
      * Segment by social network in Social Profiles data.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "SocialProfilesNetworkSegmentField::is_noop"
-    )]
-    pub field: SocialProfilesNetworkSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<SocialProfilesNetworkSegmentField>,
     /**
      * Members who are/not following a linked account on a given social network.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "SocialProfilesNetworkFollowSegmentOperator::is_noop"
-    )]
-    pub op: SocialProfilesNetworkFollowSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<SocialProfilesNetworkFollowSegmentOperator>,
     /**
      * The social network to segment against.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "SocialProfilesNetworkFollowSegmentOperatorData::is_noop"
-    )]
-    pub value: SocialProfilesNetworkFollowSegmentOperatorData,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<SocialProfilesNetworkFollowSegmentOperatorData>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AddressMergeFieldSegmentConditionType {
     #[serde(rename = "AddressMerge")]
     AddressMerge,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AddressMergeFieldSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AddressMergeFieldSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             AddressMergeFieldSegmentConditionType::AddressMerge => "AddressMerge",
-            AddressMergeFieldSegmentConditionType::Noop => "",
-            AddressMergeFieldSegmentConditionType::FallthroughString => "*",
+            AddressMergeFieldSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AddressMergeFieldSegmentConditionType {
-    fn default() -> AddressMergeFieldSegmentConditionType {
-        AddressMergeFieldSegmentConditionType::Noop
+impl std::fmt::Display for AddressMergeFieldSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AddressMergeFieldSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "AddressMerge" => AddressMergeFieldSegmentConditionType::AddressMerge,
+            _ => AddressMergeFieldSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for AddressMergeFieldSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AddressMergeFieldSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AddressMergeFieldSegmentConditionType::Noop)
+
+impl From<AddressMergeFieldSegmentConditionType> for String {
+    fn from(v: AddressMergeFieldSegmentConditionType) -> Self {
+        match v {
+            AddressMergeFieldSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5006,6 +7180,7 @@ impl AddressMergeFieldSegmentConditionType {
  * Whether the member's address merge field contains/does not contain a value or is/is not blank.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AddressMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5015,34 +7190,59 @@ pub enum AddressMergeFieldSegmentOperator {
     Contains,
     #[serde(rename = "notcontain")]
     Notcontain,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AddressMergeFieldSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AddressMergeFieldSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             AddressMergeFieldSegmentOperator::Blank => "blank",
             AddressMergeFieldSegmentOperator::BlankNot => "blank_not",
             AddressMergeFieldSegmentOperator::Contains => "contains",
             AddressMergeFieldSegmentOperator::Notcontain => "notcontain",
-            AddressMergeFieldSegmentOperator::Noop => "",
-            AddressMergeFieldSegmentOperator::FallthroughString => "*",
+            AddressMergeFieldSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AddressMergeFieldSegmentOperator {
-    fn default() -> AddressMergeFieldSegmentOperator {
-        AddressMergeFieldSegmentOperator::Noop
+impl std::fmt::Display for AddressMergeFieldSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AddressMergeFieldSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "blank" => AddressMergeFieldSegmentOperator::Blank,
+            "blank_not" => AddressMergeFieldSegmentOperator::BlankNot,
+            "contains" => AddressMergeFieldSegmentOperator::Contains,
+            "notcontain" => AddressMergeFieldSegmentOperator::Notcontain,
+            _ => AddressMergeFieldSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for AddressMergeFieldSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AddressMergeFieldSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AddressMergeFieldSegmentOperator::Noop)
+
+impl From<AddressMergeFieldSegmentOperator> for String {
+    fn from(v: AddressMergeFieldSegmentOperator) -> Self {
+        match v {
+            AddressMergeFieldSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5066,11 +7266,8 @@ pub struct AddressMergeFieldSegment {
     /**
      * Whether the member's address merge field contains/does not contain a value or is/is not blank.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "AddressMergeFieldSegmentOperator::is_noop"
-    )]
-    pub op: AddressMergeFieldSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<AddressMergeFieldSegmentOperator>,
     /**
      * Segment by an address-type merge field.
      */
@@ -5083,34 +7280,57 @@ pub struct AddressMergeFieldSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AddressZipMergeFieldSegmentConditionType {
     #[serde(rename = "ZipMerge")]
     ZipMerge,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AddressZipMergeFieldSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AddressZipMergeFieldSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             AddressZipMergeFieldSegmentConditionType::ZipMerge => "ZipMerge",
-            AddressZipMergeFieldSegmentConditionType::Noop => "",
-            AddressZipMergeFieldSegmentConditionType::FallthroughString => "*",
+            AddressZipMergeFieldSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AddressZipMergeFieldSegmentConditionType {
-    fn default() -> AddressZipMergeFieldSegmentConditionType {
-        AddressZipMergeFieldSegmentConditionType::Noop
+impl std::fmt::Display for AddressZipMergeFieldSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AddressZipMergeFieldSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ZipMerge" => AddressZipMergeFieldSegmentConditionType::ZipMerge,
+            _ => AddressZipMergeFieldSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for AddressZipMergeFieldSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AddressZipMergeFieldSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AddressZipMergeFieldSegmentConditionType::Noop)
+
+impl From<AddressZipMergeFieldSegmentConditionType> for String {
+    fn from(v: AddressZipMergeFieldSegmentConditionType) -> Self {
+        match v {
+            AddressZipMergeFieldSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5118,34 +7338,57 @@ impl AddressZipMergeFieldSegmentConditionType {
  * Whether the member's address merge field is within a given distance from a city or zip.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AddressZipMergeFieldSegmentOperator {
     #[serde(rename = "geoin")]
     Geoin,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AddressZipMergeFieldSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AddressZipMergeFieldSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             AddressZipMergeFieldSegmentOperator::Geoin => "geoin",
-            AddressZipMergeFieldSegmentOperator::Noop => "",
-            AddressZipMergeFieldSegmentOperator::FallthroughString => "*",
+            AddressZipMergeFieldSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AddressZipMergeFieldSegmentOperator {
-    fn default() -> AddressZipMergeFieldSegmentOperator {
-        AddressZipMergeFieldSegmentOperator::Noop
+impl std::fmt::Display for AddressZipMergeFieldSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AddressZipMergeFieldSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "geoin" => AddressZipMergeFieldSegmentOperator::Geoin,
+            _ => AddressZipMergeFieldSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for AddressZipMergeFieldSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AddressZipMergeFieldSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AddressZipMergeFieldSegmentOperator::Noop)
+
+impl From<AddressZipMergeFieldSegmentOperator> for String {
+    fn from(v: AddressZipMergeFieldSegmentOperator) -> Self {
+        match v {
+            AddressZipMergeFieldSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5178,11 +7421,8 @@ pub struct AddressZipMergeFieldSegment {
     /**
      * Whether the member's address merge field is within a given distance from a city or zip.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "AddressZipMergeFieldSegmentOperator::is_noop"
-    )]
-    pub op: AddressZipMergeFieldSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<AddressZipMergeFieldSegmentOperator>,
     /**
      * The name of the folder.
      */
@@ -5195,34 +7435,57 @@ pub struct AddressZipMergeFieldSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BirthdayMergeFieldSegmentConditionType {
     #[serde(rename = "BirthdayMerge")]
     BirthdayMerge,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for BirthdayMergeFieldSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for BirthdayMergeFieldSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             BirthdayMergeFieldSegmentConditionType::BirthdayMerge => "BirthdayMerge",
-            BirthdayMergeFieldSegmentConditionType::Noop => "",
-            BirthdayMergeFieldSegmentConditionType::FallthroughString => "*",
+            BirthdayMergeFieldSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for BirthdayMergeFieldSegmentConditionType {
-    fn default() -> BirthdayMergeFieldSegmentConditionType {
-        BirthdayMergeFieldSegmentConditionType::Noop
+impl std::fmt::Display for BirthdayMergeFieldSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for BirthdayMergeFieldSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "BirthdayMerge" => BirthdayMergeFieldSegmentConditionType::BirthdayMerge,
+            _ => BirthdayMergeFieldSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for BirthdayMergeFieldSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl BirthdayMergeFieldSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, BirthdayMergeFieldSegmentConditionType::Noop)
+
+impl From<BirthdayMergeFieldSegmentConditionType> for String {
+    fn from(v: BirthdayMergeFieldSegmentConditionType) -> Self {
+        match v {
+            BirthdayMergeFieldSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5230,6 +7493,7 @@ impl BirthdayMergeFieldSegmentConditionType {
  * Whether the member's birthday merge information is/is not a certain date or is/is not blank.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BirthdayMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5239,34 +7503,59 @@ pub enum BirthdayMergeFieldSegmentOperator {
     Is,
     #[serde(rename = "not")]
     Not,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for BirthdayMergeFieldSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for BirthdayMergeFieldSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             BirthdayMergeFieldSegmentOperator::Blank => "blank",
             BirthdayMergeFieldSegmentOperator::BlankNot => "blank_not",
             BirthdayMergeFieldSegmentOperator::Is => "is",
             BirthdayMergeFieldSegmentOperator::Not => "not",
-            BirthdayMergeFieldSegmentOperator::Noop => "",
-            BirthdayMergeFieldSegmentOperator::FallthroughString => "*",
+            BirthdayMergeFieldSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for BirthdayMergeFieldSegmentOperator {
-    fn default() -> BirthdayMergeFieldSegmentOperator {
-        BirthdayMergeFieldSegmentOperator::Noop
+impl std::fmt::Display for BirthdayMergeFieldSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for BirthdayMergeFieldSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "blank" => BirthdayMergeFieldSegmentOperator::Blank,
+            "blank_not" => BirthdayMergeFieldSegmentOperator::BlankNot,
+            "is" => BirthdayMergeFieldSegmentOperator::Is,
+            "not" => BirthdayMergeFieldSegmentOperator::Not,
+            _ => BirthdayMergeFieldSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for BirthdayMergeFieldSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl BirthdayMergeFieldSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, BirthdayMergeFieldSegmentOperator::Noop)
+
+impl From<BirthdayMergeFieldSegmentOperator> for String {
+    fn from(v: BirthdayMergeFieldSegmentOperator) -> Self {
+        match v {
+            BirthdayMergeFieldSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5290,11 +7579,8 @@ pub struct BirthdayMergeFieldSegment {
     /**
      * Whether the member's birthday merge information is/is not a certain date or is/is not blank.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "BirthdayMergeFieldSegmentOperator::is_noop"
-    )]
-    pub op: BirthdayMergeFieldSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<BirthdayMergeFieldSegmentOperator>,
     /**
      * Segment by a contact's birthday.
      */
@@ -5307,34 +7593,57 @@ pub struct BirthdayMergeFieldSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateMergeFieldSegmentConditionType {
     #[serde(rename = "DateMerge")]
     DateMerge,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DateMergeFieldSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DateMergeFieldSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             DateMergeFieldSegmentConditionType::DateMerge => "DateMerge",
-            DateMergeFieldSegmentConditionType::Noop => "",
-            DateMergeFieldSegmentConditionType::FallthroughString => "*",
+            DateMergeFieldSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DateMergeFieldSegmentConditionType {
-    fn default() -> DateMergeFieldSegmentConditionType {
-        DateMergeFieldSegmentConditionType::Noop
+impl std::fmt::Display for DateMergeFieldSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DateMergeFieldSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "DateMerge" => DateMergeFieldSegmentConditionType::DateMerge,
+            _ => DateMergeFieldSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for DateMergeFieldSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DateMergeFieldSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DateMergeFieldSegmentConditionType::Noop)
+
+impl From<DateMergeFieldSegmentConditionType> for String {
+    fn from(v: DateMergeFieldSegmentConditionType) -> Self {
+        match v {
+            DateMergeFieldSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5342,6 +7651,7 @@ impl DateMergeFieldSegmentConditionType {
  * Whether the member's merge information is/is not, is greater/less than a value or is/is not blank.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DateMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5355,36 +7665,63 @@ pub enum DateMergeFieldSegmentOperator {
     Less,
     #[serde(rename = "not")]
     Not,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DateMergeFieldSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DateMergeFieldSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             DateMergeFieldSegmentOperator::Blank => "blank",
             DateMergeFieldSegmentOperator::BlankNot => "blank_not",
             DateMergeFieldSegmentOperator::Greater => "greater",
             DateMergeFieldSegmentOperator::Is => "is",
             DateMergeFieldSegmentOperator::Less => "less",
             DateMergeFieldSegmentOperator::Not => "not",
-            DateMergeFieldSegmentOperator::Noop => "",
-            DateMergeFieldSegmentOperator::FallthroughString => "*",
+            DateMergeFieldSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DateMergeFieldSegmentOperator {
-    fn default() -> DateMergeFieldSegmentOperator {
-        DateMergeFieldSegmentOperator::Noop
+impl std::fmt::Display for DateMergeFieldSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DateMergeFieldSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "blank" => DateMergeFieldSegmentOperator::Blank,
+            "blank_not" => DateMergeFieldSegmentOperator::BlankNot,
+            "greater" => DateMergeFieldSegmentOperator::Greater,
+            "is" => DateMergeFieldSegmentOperator::Is,
+            "less" => DateMergeFieldSegmentOperator::Less,
+            "not" => DateMergeFieldSegmentOperator::Not,
+            _ => DateMergeFieldSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for DateMergeFieldSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DateMergeFieldSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DateMergeFieldSegmentOperator::Noop)
+
+impl From<DateMergeFieldSegmentOperator> for String {
+    fn from(v: DateMergeFieldSegmentOperator) -> Self {
+        match v {
+            DateMergeFieldSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5408,11 +7745,8 @@ pub struct DateMergeFieldSegment {
     /**
      * Whether the member's merge information is/is not, is greater/less than a value or is/is not blank.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "DateMergeFieldSegmentOperator::is_noop"
-    )]
-    pub op: DateMergeFieldSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<DateMergeFieldSegmentOperator>,
     /**
      * Segment by a given date merge field.
      */
@@ -5425,34 +7759,57 @@ pub struct DateMergeFieldSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DropdownRadioMergeFieldSegmentConditionType {
     #[serde(rename = "SelectMerge")]
     SelectMerge,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DropdownRadioMergeFieldSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DropdownRadioMergeFieldSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             DropdownRadioMergeFieldSegmentConditionType::SelectMerge => "SelectMerge",
-            DropdownRadioMergeFieldSegmentConditionType::Noop => "",
-            DropdownRadioMergeFieldSegmentConditionType::FallthroughString => "*",
+            DropdownRadioMergeFieldSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DropdownRadioMergeFieldSegmentConditionType {
-    fn default() -> DropdownRadioMergeFieldSegmentConditionType {
-        DropdownRadioMergeFieldSegmentConditionType::Noop
+impl std::fmt::Display for DropdownRadioMergeFieldSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DropdownRadioMergeFieldSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "SelectMerge" => DropdownRadioMergeFieldSegmentConditionType::SelectMerge,
+            _ => DropdownRadioMergeFieldSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for DropdownRadioMergeFieldSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DropdownRadioMergeFieldSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DropdownRadioMergeFieldSegmentConditionType::Noop)
+
+impl From<DropdownRadioMergeFieldSegmentConditionType> for String {
+    fn from(v: DropdownRadioMergeFieldSegmentConditionType) -> Self {
+        match v {
+            DropdownRadioMergeFieldSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5460,6 +7817,7 @@ impl DropdownRadioMergeFieldSegmentConditionType {
  * Whether the member's merge information is/is not a value or is/is not blank.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DropdownRadioMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5473,36 +7831,63 @@ pub enum DropdownRadioMergeFieldSegmentOperator {
     Not,
     #[serde(rename = "notcontain")]
     Notcontain,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DropdownRadioMergeFieldSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DropdownRadioMergeFieldSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             DropdownRadioMergeFieldSegmentOperator::Blank => "blank",
             DropdownRadioMergeFieldSegmentOperator::BlankNot => "blank_not",
             DropdownRadioMergeFieldSegmentOperator::Contains => "contains",
             DropdownRadioMergeFieldSegmentOperator::Is => "is",
             DropdownRadioMergeFieldSegmentOperator::Not => "not",
             DropdownRadioMergeFieldSegmentOperator::Notcontain => "notcontain",
-            DropdownRadioMergeFieldSegmentOperator::Noop => "",
-            DropdownRadioMergeFieldSegmentOperator::FallthroughString => "*",
+            DropdownRadioMergeFieldSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DropdownRadioMergeFieldSegmentOperator {
-    fn default() -> DropdownRadioMergeFieldSegmentOperator {
-        DropdownRadioMergeFieldSegmentOperator::Noop
+impl std::fmt::Display for DropdownRadioMergeFieldSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DropdownRadioMergeFieldSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "blank" => DropdownRadioMergeFieldSegmentOperator::Blank,
+            "blank_not" => DropdownRadioMergeFieldSegmentOperator::BlankNot,
+            "contains" => DropdownRadioMergeFieldSegmentOperator::Contains,
+            "is" => DropdownRadioMergeFieldSegmentOperator::Is,
+            "not" => DropdownRadioMergeFieldSegmentOperator::Not,
+            "notcontain" => DropdownRadioMergeFieldSegmentOperator::Notcontain,
+            _ => DropdownRadioMergeFieldSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for DropdownRadioMergeFieldSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DropdownRadioMergeFieldSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DropdownRadioMergeFieldSegmentOperator::Noop)
+
+impl From<DropdownRadioMergeFieldSegmentOperator> for String {
+    fn from(v: DropdownRadioMergeFieldSegmentOperator) -> Self {
+        match v {
+            DropdownRadioMergeFieldSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5526,11 +7911,8 @@ pub struct DropdownRadioMergeFieldSegment {
     /**
      * Whether the member's merge information is/is not a value or is/is not blank.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "DropdownRadioMergeFieldSegmentOperator::is_noop"
-    )]
-    pub op: DropdownRadioMergeFieldSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<DropdownRadioMergeFieldSegmentOperator>,
     /**
      * An individual segment condition
      */
@@ -5543,34 +7925,57 @@ pub struct DropdownRadioMergeFieldSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TextNumberMergeFieldSegmentConditionType {
     #[serde(rename = "TextMerge")]
     TextMerge,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for TextNumberMergeFieldSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for TextNumberMergeFieldSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             TextNumberMergeFieldSegmentConditionType::TextMerge => "TextMerge",
-            TextNumberMergeFieldSegmentConditionType::Noop => "",
-            TextNumberMergeFieldSegmentConditionType::FallthroughString => "*",
+            TextNumberMergeFieldSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for TextNumberMergeFieldSegmentConditionType {
-    fn default() -> TextNumberMergeFieldSegmentConditionType {
-        TextNumberMergeFieldSegmentConditionType::Noop
+impl std::fmt::Display for TextNumberMergeFieldSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for TextNumberMergeFieldSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "TextMerge" => TextNumberMergeFieldSegmentConditionType::TextMerge,
+            _ => TextNumberMergeFieldSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for TextNumberMergeFieldSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl TextNumberMergeFieldSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, TextNumberMergeFieldSegmentConditionType::Noop)
+
+impl From<TextNumberMergeFieldSegmentConditionType> for String {
+    fn from(v: TextNumberMergeFieldSegmentConditionType) -> Self {
+        match v {
+            TextNumberMergeFieldSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5578,6 +7983,7 @@ impl TextNumberMergeFieldSegmentConditionType {
  * Whether the member's merge information is/is not, contains/does not contain, starts/ends with, or is greater/less than a value
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TextNumberMergeFieldSegmentOperator {
     #[serde(rename = "blank")]
     Blank,
@@ -5599,15 +8005,15 @@ pub enum TextNumberMergeFieldSegmentOperator {
     Notcontain,
     #[serde(rename = "starts")]
     Starts,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for TextNumberMergeFieldSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for TextNumberMergeFieldSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             TextNumberMergeFieldSegmentOperator::Blank => "blank",
             TextNumberMergeFieldSegmentOperator::BlankNot => "blank_not",
             TextNumberMergeFieldSegmentOperator::Contains => "contains",
@@ -5618,21 +8024,52 @@ impl std::fmt::Display for TextNumberMergeFieldSegmentOperator {
             TextNumberMergeFieldSegmentOperator::Not => "not",
             TextNumberMergeFieldSegmentOperator::Notcontain => "notcontain",
             TextNumberMergeFieldSegmentOperator::Starts => "starts",
-            TextNumberMergeFieldSegmentOperator::Noop => "",
-            TextNumberMergeFieldSegmentOperator::FallthroughString => "*",
+            TextNumberMergeFieldSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for TextNumberMergeFieldSegmentOperator {
-    fn default() -> TextNumberMergeFieldSegmentOperator {
-        TextNumberMergeFieldSegmentOperator::Noop
+impl std::fmt::Display for TextNumberMergeFieldSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for TextNumberMergeFieldSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "blank" => TextNumberMergeFieldSegmentOperator::Blank,
+            "blank_not" => TextNumberMergeFieldSegmentOperator::BlankNot,
+            "contains" => TextNumberMergeFieldSegmentOperator::Contains,
+            "ends" => TextNumberMergeFieldSegmentOperator::Ends,
+            "greater" => TextNumberMergeFieldSegmentOperator::Greater,
+            "is" => TextNumberMergeFieldSegmentOperator::Is,
+            "less" => TextNumberMergeFieldSegmentOperator::Less,
+            "not" => TextNumberMergeFieldSegmentOperator::Not,
+            "notcontain" => TextNumberMergeFieldSegmentOperator::Notcontain,
+            "starts" => TextNumberMergeFieldSegmentOperator::Starts,
+            _ => TextNumberMergeFieldSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for TextNumberMergeFieldSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl TextNumberMergeFieldSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, TextNumberMergeFieldSegmentOperator::Noop)
+
+impl From<TextNumberMergeFieldSegmentOperator> for String {
+    fn from(v: TextNumberMergeFieldSegmentOperator) -> Self {
+        match v {
+            TextNumberMergeFieldSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5656,11 +8093,8 @@ pub struct TextNumberMergeFieldSegment {
     /**
      * Whether the member's merge information is/is not, contains/does not contain, starts/ends with, or is greater/less than a value
      */
-    #[serde(
-        default,
-        skip_serializing_if = "TextNumberMergeFieldSegmentOperator::is_noop"
-    )]
-    pub op: TextNumberMergeFieldSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<TextNumberMergeFieldSegmentOperator>,
     /**
      * Segment by a given text or number merge field.
      */
@@ -5673,34 +8107,57 @@ pub struct TextNumberMergeFieldSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailSegmentConditionType {
     #[serde(rename = "EmailAddress")]
     EmailAddress,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EmailSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EmailSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             EmailSegmentConditionType::EmailAddress => "EmailAddress",
-            EmailSegmentConditionType::Noop => "",
-            EmailSegmentConditionType::FallthroughString => "*",
+            EmailSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EmailSegmentConditionType {
-    fn default() -> EmailSegmentConditionType {
-        EmailSegmentConditionType::Noop
+impl std::fmt::Display for EmailSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EmailSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "EmailAddress" => EmailSegmentConditionType::EmailAddress,
+            _ => EmailSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EmailSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EmailSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EmailSegmentConditionType::Noop)
+
+impl From<EmailSegmentConditionType> for String {
+    fn from(v: EmailSegmentConditionType) -> Self {
+        match v {
+            EmailSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5708,37 +8165,61 @@ impl EmailSegmentConditionType {
  * Segmenting based off of a subscriber's email address.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailSegmentField {
     #[serde(rename = "EMAIL")]
     Email,
     #[serde(rename = "merge0")]
     Merge0,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EmailSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EmailSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             EmailSegmentField::Email => "EMAIL",
             EmailSegmentField::Merge0 => "merge0",
-            EmailSegmentField::Noop => "",
-            EmailSegmentField::FallthroughString => "*",
+            EmailSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EmailSegmentField {
-    fn default() -> EmailSegmentField {
-        EmailSegmentField::Noop
+impl std::fmt::Display for EmailSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EmailSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "EMAIL" => EmailSegmentField::Email,
+            "merge0" => EmailSegmentField::Merge0,
+            _ => EmailSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EmailSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EmailSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EmailSegmentField::Noop)
+
+impl From<EmailSegmentField> for String {
+    fn from(v: EmailSegmentField) -> Self {
+        match v {
+            EmailSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5746,6 +8227,7 @@ impl EmailSegmentField {
  * Whether the email address is/not exactly, contains/doesn't contain, starts/ends with a string.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailSegmentOperator {
     #[serde(rename = "contains")]
     Contains,
@@ -5763,15 +8245,15 @@ pub enum EmailSegmentOperator {
     Notcontain,
     #[serde(rename = "starts")]
     Starts,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EmailSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EmailSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             EmailSegmentOperator::Contains => "contains",
             EmailSegmentOperator::Ends => "ends",
             EmailSegmentOperator::Greater => "greater",
@@ -5780,21 +8262,50 @@ impl std::fmt::Display for EmailSegmentOperator {
             EmailSegmentOperator::Not => "not",
             EmailSegmentOperator::Notcontain => "notcontain",
             EmailSegmentOperator::Starts => "starts",
-            EmailSegmentOperator::Noop => "",
-            EmailSegmentOperator::FallthroughString => "*",
+            EmailSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EmailSegmentOperator {
-    fn default() -> EmailSegmentOperator {
-        EmailSegmentOperator::Noop
+impl std::fmt::Display for EmailSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EmailSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "contains" => EmailSegmentOperator::Contains,
+            "ends" => EmailSegmentOperator::Ends,
+            "greater" => EmailSegmentOperator::Greater,
+            "is" => EmailSegmentOperator::Is,
+            "less" => EmailSegmentOperator::Less,
+            "not" => EmailSegmentOperator::Not,
+            "notcontain" => EmailSegmentOperator::Notcontain,
+            "starts" => EmailSegmentOperator::Starts,
+            _ => EmailSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EmailSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EmailSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EmailSegmentOperator::Noop)
+
+impl From<EmailSegmentOperator> for String {
+    fn from(v: EmailSegmentOperator) -> Self {
+        match v {
+            EmailSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5809,13 +8320,13 @@ pub struct EmailSegment {
     /**
      * Segmenting based off of a subscriber's email address.
      */
-    #[serde(default, skip_serializing_if = "EmailSegmentField::is_noop")]
-    pub field: EmailSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<EmailSegmentField>,
     /**
      * Whether the email address is/not exactly, contains/doesn't contain, starts/ends with a string.
      */
-    #[serde(default, skip_serializing_if = "EmailSegmentOperator::is_noop")]
-    pub op: EmailSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<EmailSegmentOperator>,
     /**
      * Segment by email address.
      */
@@ -5828,34 +8339,57 @@ pub struct EmailSegment {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PredictedGenderSegmentConditionType {
     #[serde(rename = "PredictedGender")]
     PredictedGender,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PredictedGenderSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PredictedGenderSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             PredictedGenderSegmentConditionType::PredictedGender => "PredictedGender",
-            PredictedGenderSegmentConditionType::Noop => "",
-            PredictedGenderSegmentConditionType::FallthroughString => "*",
+            PredictedGenderSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PredictedGenderSegmentConditionType {
-    fn default() -> PredictedGenderSegmentConditionType {
-        PredictedGenderSegmentConditionType::Noop
+impl std::fmt::Display for PredictedGenderSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PredictedGenderSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "PredictedGender" => PredictedGenderSegmentConditionType::PredictedGender,
+            _ => PredictedGenderSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for PredictedGenderSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PredictedGenderSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PredictedGenderSegmentConditionType::Noop)
+
+impl From<PredictedGenderSegmentConditionType> for String {
+    fn from(v: PredictedGenderSegmentConditionType) -> Self {
+        match v {
+            PredictedGenderSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5863,34 +8397,57 @@ impl PredictedGenderSegmentConditionType {
  * Segment by predicted gender.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PredictedGenderSegmentField {
     #[serde(rename = "predicted_gender")]
     PredictedGender,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PredictedGenderSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PredictedGenderSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             PredictedGenderSegmentField::PredictedGender => "predicted_gender",
-            PredictedGenderSegmentField::Noop => "",
-            PredictedGenderSegmentField::FallthroughString => "*",
+            PredictedGenderSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PredictedGenderSegmentField {
-    fn default() -> PredictedGenderSegmentField {
-        PredictedGenderSegmentField::Noop
+impl std::fmt::Display for PredictedGenderSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PredictedGenderSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "predicted_gender" => PredictedGenderSegmentField::PredictedGender,
+            _ => PredictedGenderSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for PredictedGenderSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PredictedGenderSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PredictedGenderSegmentField::Noop)
+
+impl From<PredictedGenderSegmentField> for String {
+    fn from(v: PredictedGenderSegmentField) -> Self {
+        match v {
+            PredictedGenderSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5905,52 +8462,72 @@ pub struct PredictedGenderSegment {
     /**
      * Segment by predicted gender.
      */
-    #[serde(default, skip_serializing_if = "PredictedGenderSegmentField::is_noop")]
-    pub field: PredictedGenderSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<PredictedGenderSegmentField>,
     /**
      * Whether the member's language is or is not set to a specific language.
      */
-    #[serde(default, skip_serializing_if = "LanguageSegmentOperator::is_noop")]
-    pub op: LanguageSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<LanguageSegmentOperator>,
     /**
      * The Social Profiles gender to segment.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "SocialProfilesGenderSegmentOperator::is_noop"
-    )]
-    pub value: SocialProfilesGenderSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<SocialProfilesGenderSegmentOperator>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PredictedAgeSegmentConditionType {
     #[serde(rename = "PredictedAge")]
     PredictedAge,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PredictedAgeSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PredictedAgeSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             PredictedAgeSegmentConditionType::PredictedAge => "PredictedAge",
-            PredictedAgeSegmentConditionType::Noop => "",
-            PredictedAgeSegmentConditionType::FallthroughString => "*",
+            PredictedAgeSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PredictedAgeSegmentConditionType {
-    fn default() -> PredictedAgeSegmentConditionType {
-        PredictedAgeSegmentConditionType::Noop
+impl std::fmt::Display for PredictedAgeSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PredictedAgeSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "PredictedAge" => PredictedAgeSegmentConditionType::PredictedAge,
+            _ => PredictedAgeSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for PredictedAgeSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PredictedAgeSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PredictedAgeSegmentConditionType::Noop)
+
+impl From<PredictedAgeSegmentConditionType> for String {
+    fn from(v: PredictedAgeSegmentConditionType) -> Self {
+        match v {
+            PredictedAgeSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5958,34 +8535,57 @@ impl PredictedAgeSegmentConditionType {
  * Segment by predicted age.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PredictedAgeSegmentField {
     #[serde(rename = "predicted_age_range")]
     PredictedAgeRange,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PredictedAgeSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PredictedAgeSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             PredictedAgeSegmentField::PredictedAgeRange => "predicted_age_range",
-            PredictedAgeSegmentField::Noop => "",
-            PredictedAgeSegmentField::FallthroughString => "*",
+            PredictedAgeSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PredictedAgeSegmentField {
-    fn default() -> PredictedAgeSegmentField {
-        PredictedAgeSegmentField::Noop
+impl std::fmt::Display for PredictedAgeSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PredictedAgeSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "predicted_age_range" => PredictedAgeSegmentField::PredictedAgeRange,
+            _ => PredictedAgeSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for PredictedAgeSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PredictedAgeSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PredictedAgeSegmentField::Noop)
+
+impl From<PredictedAgeSegmentField> for String {
+    fn from(v: PredictedAgeSegmentField) -> Self {
+        match v {
+            PredictedAgeSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5993,34 +8593,57 @@ impl PredictedAgeSegmentField {
  * Members who are/not the exact criteria listed.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PredictedAgeSegmentOperator {
     #[serde(rename = "is")]
     Is,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PredictedAgeSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PredictedAgeSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             PredictedAgeSegmentOperator::Is => "is",
-            PredictedAgeSegmentOperator::Noop => "",
-            PredictedAgeSegmentOperator::FallthroughString => "*",
+            PredictedAgeSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PredictedAgeSegmentOperator {
-    fn default() -> PredictedAgeSegmentOperator {
-        PredictedAgeSegmentOperator::Noop
+impl std::fmt::Display for PredictedAgeSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PredictedAgeSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "is" => PredictedAgeSegmentOperator::Is,
+            _ => PredictedAgeSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for PredictedAgeSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PredictedAgeSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PredictedAgeSegmentOperator::Noop)
+
+impl From<PredictedAgeSegmentOperator> for String {
+    fn from(v: PredictedAgeSegmentOperator) -> Self {
+        match v {
+            PredictedAgeSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -6028,6 +8651,7 @@ impl PredictedAgeSegmentOperator {
  * The predicted age to segment.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PredictedAgeSegmentOperatorData {
     #[serde(rename = "18-24")]
     OneThousandEightHundredAndTwentyFour,
@@ -6041,36 +8665,63 @@ pub enum PredictedAgeSegmentOperatorData {
     FiveThousandFiveHundredAndSixtyFour,
     #[serde(rename = "65+")]
     SixtyFive,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PredictedAgeSegmentOperatorData {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PredictedAgeSegmentOperatorData {
+    fn as_ref(&self) -> &str {
+        match self {
             PredictedAgeSegmentOperatorData::OneThousandEightHundredAndTwentyFour => "18-24",
             PredictedAgeSegmentOperatorData::TwoThousandFiveHundredAndThirtyFour => "25-34",
             PredictedAgeSegmentOperatorData::ThreeThousandFiveHundredAndFortyFour => "35-44",
             PredictedAgeSegmentOperatorData::FourThousandFiveHundredAndFiftyFour => "45-54",
             PredictedAgeSegmentOperatorData::FiveThousandFiveHundredAndSixtyFour => "55-64",
             PredictedAgeSegmentOperatorData::SixtyFive => "65+",
-            PredictedAgeSegmentOperatorData::Noop => "",
-            PredictedAgeSegmentOperatorData::FallthroughString => "*",
+            PredictedAgeSegmentOperatorData::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PredictedAgeSegmentOperatorData {
-    fn default() -> PredictedAgeSegmentOperatorData {
-        PredictedAgeSegmentOperatorData::Noop
+impl std::fmt::Display for PredictedAgeSegmentOperatorData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PredictedAgeSegmentOperatorData {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "18-24" => PredictedAgeSegmentOperatorData::OneThousandEightHundredAndTwentyFour,
+            "25-34" => PredictedAgeSegmentOperatorData::TwoThousandFiveHundredAndThirtyFour,
+            "35-44" => PredictedAgeSegmentOperatorData::ThreeThousandFiveHundredAndFortyFour,
+            "45-54" => PredictedAgeSegmentOperatorData::FourThousandFiveHundredAndFiftyFour,
+            "55-64" => PredictedAgeSegmentOperatorData::FiveThousandFiveHundredAndSixtyFour,
+            "65+" => PredictedAgeSegmentOperatorData::SixtyFive,
+            _ => PredictedAgeSegmentOperatorData::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for PredictedAgeSegmentOperatorData {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PredictedAgeSegmentOperatorData {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PredictedAgeSegmentOperatorData::Noop)
+
+impl From<PredictedAgeSegmentOperatorData> for String {
+    fn from(v: PredictedAgeSegmentOperatorData) -> Self {
+        match v {
+            PredictedAgeSegmentOperatorData::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -6085,52 +8736,72 @@ pub struct PredictedAgeSegment {
     /**
      * Segment by predicted age.
      */
-    #[serde(default, skip_serializing_if = "PredictedAgeSegmentField::is_noop")]
-    pub field: PredictedAgeSegmentField,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<PredictedAgeSegmentField>,
     /**
      * Members who are/not the exact criteria listed.
      */
-    #[serde(default, skip_serializing_if = "PredictedAgeSegmentOperator::is_noop")]
-    pub op: PredictedAgeSegmentOperator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub op: Option<PredictedAgeSegmentOperator>,
     /**
      * The predicted age to segment.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "PredictedAgeSegmentOperatorData::is_noop"
-    )]
-    pub value: PredictedAgeSegmentOperatorData,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<PredictedAgeSegmentOperatorData>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NewSubscribersPrebuiltSegmentConditionType {
     #[serde(rename = "NewSubscribers")]
     NewSubscribers,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for NewSubscribersPrebuiltSegmentConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for NewSubscribersPrebuiltSegmentConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
             NewSubscribersPrebuiltSegmentConditionType::NewSubscribers => "NewSubscribers",
-            NewSubscribersPrebuiltSegmentConditionType::Noop => "",
-            NewSubscribersPrebuiltSegmentConditionType::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentConditionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for NewSubscribersPrebuiltSegmentConditionType {
-    fn default() -> NewSubscribersPrebuiltSegmentConditionType {
-        NewSubscribersPrebuiltSegmentConditionType::Noop
+impl std::fmt::Display for NewSubscribersPrebuiltSegmentConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for NewSubscribersPrebuiltSegmentConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "NewSubscribers" => NewSubscribersPrebuiltSegmentConditionType::NewSubscribers,
+            _ => NewSubscribersPrebuiltSegmentConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for NewSubscribersPrebuiltSegmentConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl NewSubscribersPrebuiltSegmentConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, NewSubscribersPrebuiltSegmentConditionType::Noop)
+
+impl From<NewSubscribersPrebuiltSegmentConditionType> for String {
+    fn from(v: NewSubscribersPrebuiltSegmentConditionType) -> Self {
+        match v {
+            NewSubscribersPrebuiltSegmentConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -6138,34 +8809,57 @@ impl NewSubscribersPrebuiltSegmentConditionType {
  * Segment by when people subscribed.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NewSubscribersPrebuiltSegmentField {
     #[serde(rename = "timestamp_opt")]
     TimestampOpt,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for NewSubscribersPrebuiltSegmentField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for NewSubscribersPrebuiltSegmentField {
+    fn as_ref(&self) -> &str {
+        match self {
             NewSubscribersPrebuiltSegmentField::TimestampOpt => "timestamp_opt",
-            NewSubscribersPrebuiltSegmentField::Noop => "",
-            NewSubscribersPrebuiltSegmentField::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for NewSubscribersPrebuiltSegmentField {
-    fn default() -> NewSubscribersPrebuiltSegmentField {
-        NewSubscribersPrebuiltSegmentField::Noop
+impl std::fmt::Display for NewSubscribersPrebuiltSegmentField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for NewSubscribersPrebuiltSegmentField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "timestamp_opt" => NewSubscribersPrebuiltSegmentField::TimestampOpt,
+            _ => NewSubscribersPrebuiltSegmentField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for NewSubscribersPrebuiltSegmentField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl NewSubscribersPrebuiltSegmentField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, NewSubscribersPrebuiltSegmentField::Noop)
+
+impl From<NewSubscribersPrebuiltSegmentField> for String {
+    fn from(v: NewSubscribersPrebuiltSegmentField) -> Self {
+        match v {
+            NewSubscribersPrebuiltSegmentField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -6173,34 +8867,57 @@ impl NewSubscribersPrebuiltSegmentField {
  * Whe the event took place, namely within a time frame.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NewSubscribersPrebuiltSegmentOperator {
     #[serde(rename = "date_within")]
     DateWithin,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for NewSubscribersPrebuiltSegmentOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for NewSubscribersPrebuiltSegmentOperator {
+    fn as_ref(&self) -> &str {
+        match self {
             NewSubscribersPrebuiltSegmentOperator::DateWithin => "date_within",
-            NewSubscribersPrebuiltSegmentOperator::Noop => "",
-            NewSubscribersPrebuiltSegmentOperator::FallthroughString => "*",
+            NewSubscribersPrebuiltSegmentOperator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for NewSubscribersPrebuiltSegmentOperator {
-    fn default() -> NewSubscribersPrebuiltSegmentOperator {
-        NewSubscribersPrebuiltSegmentOperator::Noop
+impl std::fmt::Display for NewSubscribersPrebuiltSegmentOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for NewSubscribersPrebuiltSegmentOperator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "date_within" => NewSubscribersPrebuiltSegmentOperator::DateWithin,
+            _ => NewSubscribersPrebuiltSegmentOperator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for NewSubscribersPrebuiltSegmentOperator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl NewSubscribersPrebuiltSegmentOperator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, NewSubscribersPrebuiltSegmentOperator::Noop)
+
+impl From<NewSubscribersPrebuiltSegmentOperator> for String {
+    fn from(v: NewSubscribersPrebuiltSegmentOperator) -> Self {
+        match v {
+            NewSubscribersPrebuiltSegmentOperator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -6996,6 +9713,7 @@ pub struct Tracking {
  * The type of Automation workflow.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WorkflowType {
     #[serde(rename = "abandonedBrowse")]
     AbandonedBrowse,
@@ -7031,15 +9749,15 @@ pub enum WorkflowType {
     VisitUrl,
     #[serde(rename = "welcomeSeries")]
     WelcomeSeries,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for WorkflowType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for WorkflowType {
+    fn as_ref(&self) -> &str {
+        match self {
             WorkflowType::AbandonedBrowse => "abandonedBrowse",
             WorkflowType::AbandonedCart => "abandonedCart",
             WorkflowType::Api => "api",
@@ -7057,25 +9775,64 @@ impl std::fmt::Display for WorkflowType {
             WorkflowType::SpecialEvent => "specialEvent",
             WorkflowType::VisitUrl => "visitUrl",
             WorkflowType::WelcomeSeries => "welcomeSeries",
-            WorkflowType::Noop => "",
-            WorkflowType::FallthroughString => "*",
+            WorkflowType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for WorkflowType {
-    fn default() -> WorkflowType {
-        WorkflowType::Noop
+impl std::fmt::Display for WorkflowType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for WorkflowType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "abandonedBrowse" => WorkflowType::AbandonedBrowse,
+            "abandonedCart" => WorkflowType::AbandonedCart,
+            "api" => WorkflowType::Api,
+            "bestCustomers" => WorkflowType::BestCustomers,
+            "categoryFollowup" => WorkflowType::CategoryFollowup,
+            "dateAdded" => WorkflowType::DateAdded,
+            "emailFollowup" => WorkflowType::EmailFollowup,
+            "emailSeries" => WorkflowType::EmailSeries,
+            "groupAdd" => WorkflowType::GroupAdd,
+            "groupRemove" => WorkflowType::GroupRemove,
+            "mandrill" => WorkflowType::Mandrill,
+            "productFollowup" => WorkflowType::ProductFollowup,
+            "purchaseFollowup" => WorkflowType::PurchaseFollowup,
+            "recurringEvent" => WorkflowType::RecurringEvent,
+            "specialEvent" => WorkflowType::SpecialEvent,
+            "visitUrl" => WorkflowType::VisitUrl,
+            "welcomeSeries" => WorkflowType::WelcomeSeries,
+            _ => WorkflowType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for WorkflowType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl WorkflowType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, WorkflowType::Noop)
+
+impl From<WorkflowType> for String {
+    fn from(v: WorkflowType) -> Self {
+        match v {
+            WorkflowType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Days {
     #[serde(rename = "friday")]
     Friday,
@@ -7091,15 +9848,15 @@ pub enum Days {
     Tuesday,
     #[serde(rename = "wednesday")]
     Wednesday,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Days {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Days {
+    fn as_ref(&self) -> &str {
+        match self {
             Days::Friday => "friday",
             Days::Monday => "monday",
             Days::Saturday => "saturday",
@@ -7107,21 +9864,49 @@ impl std::fmt::Display for Days {
             Days::Thursday => "thursday",
             Days::Tuesday => "tuesday",
             Days::Wednesday => "wednesday",
-            Days::Noop => "",
-            Days::FallthroughString => "*",
+            Days::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Days {
-    fn default() -> Days {
-        Days::Noop
+impl std::fmt::Display for Days {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Days {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "friday" => Days::Friday,
+            "monday" => Days::Monday,
+            "saturday" => Days::Saturday,
+            "sunday" => Days::Sunday,
+            "thursday" => Days::Thursday,
+            "tuesday" => Days::Tuesday,
+            "wednesday" => Days::Wednesday,
+            _ => Days::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Days {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Days {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Days::Noop)
+
+impl From<Days> for String {
+    fn from(v: Days) -> Self {
+        match v {
+            Days::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -7129,6 +9914,7 @@ impl Days {
  * When to send the Automation email.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailSendTimeSettings {
     #[serde(rename = "send_asap")]
     SendAsap,
@@ -7136,33 +9922,57 @@ pub enum EmailSendTimeSettings {
     SendAt,
     #[serde(rename = "send_between")]
     SendBetween,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EmailSendTimeSettings {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EmailSendTimeSettings {
+    fn as_ref(&self) -> &str {
+        match self {
             EmailSendTimeSettings::SendAsap => "send_asap",
             EmailSendTimeSettings::SendAt => "send_at",
             EmailSendTimeSettings::SendBetween => "send_between",
-            EmailSendTimeSettings::Noop => "",
-            EmailSendTimeSettings::FallthroughString => "*",
+            EmailSendTimeSettings::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EmailSendTimeSettings {
-    fn default() -> EmailSendTimeSettings {
-        EmailSendTimeSettings::Noop
+impl std::fmt::Display for EmailSendTimeSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EmailSendTimeSettings {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "send_asap" => EmailSendTimeSettings::SendAsap,
+            "send_at" => EmailSendTimeSettings::SendAt,
+            "send_between" => EmailSendTimeSettings::SendBetween,
+            _ => EmailSendTimeSettings::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EmailSendTimeSettings {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EmailSendTimeSettings {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EmailSendTimeSettings::Noop)
+
+impl From<EmailSendTimeSettings> for String {
+    fn from(v: EmailSendTimeSettings) -> Self {
+        match v {
+            EmailSendTimeSettings::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -7172,12 +9982,8 @@ pub struct Hours {
     /**
      * When to send the Automation email.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "EmailSendTimeSettings::is_noop",
-        rename = "type"
-    )]
-    pub type_: EmailSendTimeSettings,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<EmailSendTimeSettings>,
 }
 
 /// A workflow's runtime settings for an Automation.
@@ -7228,8 +10034,8 @@ pub struct TriggerSettings {
     /**
      * The type of Automation workflow.
      */
-    #[serde(default, skip_serializing_if = "WorkflowType::is_noop")]
-    pub workflow_type: WorkflowType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow_type: Option<WorkflowType>,
 }
 
 /// A summary of opens and clicks for sent campaigns.
@@ -7489,6 +10295,7 @@ pub struct AutomationWorkflow {
  * The type of delay for an Automation email.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DelayType {
     #[serde(rename = "day")]
     Day,
@@ -7498,34 +10305,59 @@ pub enum DelayType {
     Now,
     #[serde(rename = "week")]
     Week,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DelayType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DelayType {
+    fn as_ref(&self) -> &str {
+        match self {
             DelayType::Day => "day",
             DelayType::Hour => "hour",
             DelayType::Now => "now",
             DelayType::Week => "week",
-            DelayType::Noop => "",
-            DelayType::FallthroughString => "*",
+            DelayType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DelayType {
-    fn default() -> DelayType {
-        DelayType::Noop
+impl std::fmt::Display for DelayType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DelayType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "day" => DelayType::Day,
+            "hour" => DelayType::Hour,
+            "now" => DelayType::Now,
+            "week" => DelayType::Week,
+            _ => DelayType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for DelayType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DelayType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DelayType::Noop)
+
+impl From<DelayType> for String {
+    fn from(v: DelayType) -> Self {
+        match v {
+            DelayType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -7533,37 +10365,61 @@ impl DelayType {
  * Whether the delay settings describe before or after the delay action of an Automation email.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Direction {
     #[serde(rename = "after")]
     After,
     #[serde(rename = "before")]
     Before,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Direction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Direction {
+    fn as_ref(&self) -> &str {
+        match self {
             Direction::After => "after",
             Direction::Before => "before",
-            Direction::Noop => "",
-            Direction::FallthroughString => "*",
+            Direction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Direction {
-    fn default() -> Direction {
-        Direction::Noop
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Direction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "after" => Direction::After,
+            "before" => Direction::Before,
+            _ => Direction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Direction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Direction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Direction::Noop)
+
+impl From<Direction> for String {
+    fn from(v: Direction) -> Self {
+        match v {
+            Direction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -7571,6 +10427,7 @@ impl Direction {
  * The action that triggers the delay of an Automation email.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Action {
     #[serde(rename = "annual")]
     Annual,
@@ -7638,15 +10495,15 @@ pub enum Action {
     Signup,
     #[serde(rename = "tag_add")]
     TagAdd,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Action {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Action {
+    fn as_ref(&self) -> &str {
+        match self {
             Action::Annual => "annual",
             Action::Api => "api",
             Action::Birthday => "birthday",
@@ -7680,21 +10537,75 @@ impl std::fmt::Display for Action {
             Action::PreviousCampaignSpecificClicked => "previous_campaign_specific_clicked",
             Action::Signup => "signup",
             Action::TagAdd => "tag_add",
-            Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Action {
-    fn default() -> Action {
-        Action::Noop
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Action {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "annual" => Action::Annual,
+            "api" => Action::Api,
+            "birthday" => Action::Birthday,
+            "campaign_sent" => Action::CampaignSent,
+            "campaign_specific_clicked" => Action::CampaignSpecificClicked,
+            "clicked_email" => Action::ClickedEmail,
+            "date" => Action::Date,
+            "date_added" => Action::DateAdded,
+            "ecomm_abandoned_cart" => Action::EcommAbandonedCart,
+            "ecomm_bought_any" => Action::EcommBoughtAny,
+            "ecomm_bought_category" => Action::EcommBoughtCategory,
+            "ecomm_bought_product" => Action::EcommBoughtProduct,
+            "ecomm_not_bought_any" => Action::EcommNotBoughtAny,
+            "goal" => Action::Goal,
+            "group_add" => Action::GroupAdd,
+            "group_remove" => Action::GroupRemove,
+            "mandrill_any" => Action::MandrillAny,
+            "mandrill_clicked" => Action::MandrillClicked,
+            "mandrill_opened" => Action::MandrillOpened,
+            "mandrill_sent" => Action::MandrillSent,
+            "manual" => Action::Manual,
+            "merge_changed" => Action::MergeChanged,
+            "not_clicked_email" => Action::NotClickedEmail,
+            "not_opened_email" => Action::NotOpenedEmail,
+            "opened_email" => Action::OpenedEmail,
+            "previous_campaign_clicked_any" => Action::PreviousCampaignClickedAny,
+            "previous_campaign_not_clicked_any" => Action::PreviousCampaignNotClickedAny,
+            "previous_campaign_not_opened" => Action::PreviousCampaignNotOpened,
+            "previous_campaign_opened" => Action::PreviousCampaignOpened,
+            "previous_campaign_sent" => Action::PreviousCampaignSent,
+            "previous_campaign_specific_clicked" => Action::PreviousCampaignSpecificClicked,
+            "signup" => Action::Signup,
+            "tag_add" => Action::TagAdd,
+            _ => Action::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Action {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Action {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Action::Noop)
+
+impl From<Action> for String {
+    fn from(v: Action) -> Self {
+        match v {
+            Action::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -8365,34 +11276,57 @@ pub struct UpdateInformationAboutASpecificWorkflowEmailCampaignSettings {
  * Whether the delay settings describe before or after the delay action of an automation email.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DelayDirection {
     #[serde(rename = "after")]
     After,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DelayDirection {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DelayDirection {
+    fn as_ref(&self) -> &str {
+        match self {
             DelayDirection::After => "after",
-            DelayDirection::Noop => "",
-            DelayDirection::FallthroughString => "*",
+            DelayDirection::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DelayDirection {
-    fn default() -> DelayDirection {
-        DelayDirection::Noop
+impl std::fmt::Display for DelayDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DelayDirection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "after" => DelayDirection::After,
+            _ => DelayDirection::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for DelayDirection {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DelayDirection {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DelayDirection::Noop)
+
+impl From<DelayDirection> for String {
+    fn from(v: DelayDirection) -> Self {
+        match v {
+            DelayDirection::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -8400,6 +11334,7 @@ impl DelayDirection {
  * The action that triggers the delay of an automation emails.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DelayAction {
     #[serde(rename = "ecomm_abandoned_browse")]
     EcommAbandonedBrowse,
@@ -8407,33 +11342,57 @@ pub enum DelayAction {
     EcommAbandonedCart,
     #[serde(rename = "signup")]
     Signup,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DelayAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DelayAction {
+    fn as_ref(&self) -> &str {
+        match self {
             DelayAction::EcommAbandonedBrowse => "ecomm_abandoned_browse",
             DelayAction::EcommAbandonedCart => "ecomm_abandoned_cart",
             DelayAction::Signup => "signup",
-            DelayAction::Noop => "",
-            DelayAction::FallthroughString => "*",
+            DelayAction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DelayAction {
-    fn default() -> DelayAction {
-        DelayAction::Noop
+impl std::fmt::Display for DelayAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DelayAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ecomm_abandoned_browse" => DelayAction::EcommAbandonedBrowse,
+            "ecomm_abandoned_cart" => DelayAction::EcommAbandonedCart,
+            "signup" => DelayAction::Signup,
+            _ => DelayAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for DelayAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DelayAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DelayAction::Noop)
+
+impl From<DelayAction> for String {
+    fn from(v: DelayAction) -> Self {
+        match v {
+            DelayAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -8443,8 +11402,8 @@ pub struct AutomationDelay {
     /**
      * The action that triggers the delay of an automation emails.
      */
-    #[serde(default, skip_serializing_if = "DelayAction::is_noop")]
-    pub action: DelayAction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<DelayAction>,
     /**
      * The delay settings for an automation email.
      */
@@ -8775,6 +11734,7 @@ pub struct RemovedSubscribers {
  * The status of the batch call. [Learn more](https://mailchimp.com/developer/marketing/guides/run-async-requests-batch-endpoint/#check-the-status-of-a-batch-operation) about the batch operation status.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BatchOperationsStatus {
     #[serde(rename = "finalizing")]
     Finalizing,
@@ -8786,35 +11746,61 @@ pub enum BatchOperationsStatus {
     Preprocessing,
     #[serde(rename = "started")]
     Started,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for BatchOperationsStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for BatchOperationsStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             BatchOperationsStatus::Finalizing => "finalizing",
             BatchOperationsStatus::Finished => "finished",
             BatchOperationsStatus::Pending => "pending",
             BatchOperationsStatus::Preprocessing => "preprocessing",
             BatchOperationsStatus::Started => "started",
-            BatchOperationsStatus::Noop => "",
-            BatchOperationsStatus::FallthroughString => "*",
+            BatchOperationsStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for BatchOperationsStatus {
-    fn default() -> BatchOperationsStatus {
-        BatchOperationsStatus::Noop
+impl std::fmt::Display for BatchOperationsStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for BatchOperationsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "finalizing" => BatchOperationsStatus::Finalizing,
+            "finished" => BatchOperationsStatus::Finished,
+            "pending" => BatchOperationsStatus::Pending,
+            "preprocessing" => BatchOperationsStatus::Preprocessing,
+            "started" => BatchOperationsStatus::Started,
+            _ => BatchOperationsStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for BatchOperationsStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl BatchOperationsStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, BatchOperationsStatus::Noop)
+
+impl From<BatchOperationsStatus> for String {
+    fn from(v: BatchOperationsStatus) -> Self {
+        match v {
+            BatchOperationsStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -8938,6 +11924,7 @@ pub struct BatchOperations {
  * The HTTP method to use for the operation.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum HttpMethod {
     #[serde(rename = "DELETE")]
     Delete,
@@ -8949,35 +11936,61 @@ pub enum HttpMethod {
     Post,
     #[serde(rename = "PUT")]
     Put,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for HttpMethod {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for HttpMethod {
+    fn as_ref(&self) -> &str {
+        match self {
             HttpMethod::Delete => "DELETE",
             HttpMethod::Get => "GET",
             HttpMethod::Patch => "PATCH",
             HttpMethod::Post => "POST",
             HttpMethod::Put => "PUT",
-            HttpMethod::Noop => "",
-            HttpMethod::FallthroughString => "*",
+            HttpMethod::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for HttpMethod {
-    fn default() -> HttpMethod {
-        HttpMethod::Noop
+impl std::fmt::Display for HttpMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for HttpMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "DELETE" => HttpMethod::Delete,
+            "GET" => HttpMethod::Get,
+            "PATCH" => HttpMethod::Patch,
+            "POST" => HttpMethod::Post,
+            "PUT" => HttpMethod::Put,
+            _ => HttpMethod::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for HttpMethod {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl HttpMethod {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, HttpMethod::Noop)
+
+impl From<HttpMethod> for String {
+    fn from(v: HttpMethod) -> Self {
+        match v {
+            HttpMethod::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -8996,8 +12009,8 @@ pub struct Operations {
     /**
      * The HTTP method to use for the operation.
      */
-    #[serde(default, skip_serializing_if = "HttpMethod::is_noop")]
-    pub method: HttpMethod,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<HttpMethod>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -9264,6 +12277,7 @@ pub struct CampaignFolders {
  * There are four types of [campaigns](https://mailchimp.com/help/getting-started-with-campaigns/) you can create in Mailchimp. A/B Split campaigns have been deprecated and variate campaigns should be used instead.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CampaignType {
     #[serde(rename = "absplit")]
     Absplit,
@@ -9275,35 +12289,61 @@ pub enum CampaignType {
     Rss,
     #[serde(rename = "variate")]
     Variate,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for CampaignType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for CampaignType {
+    fn as_ref(&self) -> &str {
+        match self {
             CampaignType::Absplit => "absplit",
             CampaignType::Plaintext => "plaintext",
             CampaignType::Regular => "regular",
             CampaignType::Rss => "rss",
             CampaignType::Variate => "variate",
-            CampaignType::Noop => "",
-            CampaignType::FallthroughString => "*",
+            CampaignType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for CampaignType {
-    fn default() -> CampaignType {
-        CampaignType::Noop
+impl std::fmt::Display for CampaignType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CampaignType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "absplit" => CampaignType::Absplit,
+            "plaintext" => CampaignType::Plaintext,
+            "regular" => CampaignType::Regular,
+            "rss" => CampaignType::Rss,
+            "variate" => CampaignType::Variate,
+            _ => CampaignType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for CampaignType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl CampaignType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, CampaignType::Noop)
+
+impl From<CampaignType> for String {
+    fn from(v: CampaignType) -> Self {
+        match v {
+            CampaignType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -9311,6 +12351,7 @@ impl CampaignType {
  * The status of the campaign.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetCampaignsStatus {
     #[serde(rename = "paused")]
     Paused,
@@ -9322,35 +12363,61 @@ pub enum GetCampaignsStatus {
     Sending,
     #[serde(rename = "sent")]
     Sent,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetCampaignsStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetCampaignsStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             GetCampaignsStatus::Paused => "paused",
             GetCampaignsStatus::Save => "save",
             GetCampaignsStatus::Schedule => "schedule",
             GetCampaignsStatus::Sending => "sending",
             GetCampaignsStatus::Sent => "sent",
-            GetCampaignsStatus::Noop => "",
-            GetCampaignsStatus::FallthroughString => "*",
+            GetCampaignsStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetCampaignsStatus {
-    fn default() -> GetCampaignsStatus {
-        GetCampaignsStatus::Noop
+impl std::fmt::Display for GetCampaignsStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetCampaignsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "paused" => GetCampaignsStatus::Paused,
+            "save" => GetCampaignsStatus::Save,
+            "schedule" => GetCampaignsStatus::Schedule,
+            "sending" => GetCampaignsStatus::Sending,
+            "sent" => GetCampaignsStatus::Sent,
+            _ => GetCampaignsStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GetCampaignsStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetCampaignsStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetCampaignsStatus::Noop)
+
+impl From<GetCampaignsStatus> for String {
+    fn from(v: GetCampaignsStatus) -> Self {
+        match v {
+            GetCampaignsStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -9358,37 +12425,61 @@ impl GetCampaignsStatus {
  * Returns files sorted by the specified field.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SortField {
     #[serde(rename = "create_time")]
     CreateTime,
     #[serde(rename = "send_time")]
     SendTime,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SortField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SortField {
+    fn as_ref(&self) -> &str {
+        match self {
             SortField::CreateTime => "create_time",
             SortField::SendTime => "send_time",
-            SortField::Noop => "",
-            SortField::FallthroughString => "*",
+            SortField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SortField {
-    fn default() -> SortField {
-        SortField::Noop
+impl std::fmt::Display for SortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "create_time" => SortField::CreateTime,
+            "send_time" => SortField::SendTime,
+            _ => SortField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SortField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SortField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SortField::Noop)
+
+impl From<SortField> for String {
+    fn from(v: SortField) -> Self {
+        match v {
+            SortField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -9396,37 +12487,61 @@ impl SortField {
  * Determines the order direction for sorted results.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SortDir {
     #[serde(rename = "ASC")]
     Asc,
     #[serde(rename = "DESC")]
     Desc,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SortDir {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SortDir {
+    fn as_ref(&self) -> &str {
+        match self {
             SortDir::Asc => "ASC",
             SortDir::Desc => "DESC",
-            SortDir::Noop => "",
-            SortDir::FallthroughString => "*",
+            SortDir::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SortDir {
-    fn default() -> SortDir {
-        SortDir::Noop
+impl std::fmt::Display for SortDir {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SortDir {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ASC" => SortDir::Asc,
+            "DESC" => SortDir::Desc,
+            _ => SortDir::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SortDir {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SortDir {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SortDir::Noop)
+
+impl From<SortDir> for String {
+    fn from(v: SortDir) -> Self {
+        match v {
+            SortDir::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -9434,6 +12549,7 @@ impl SortDir {
  * The current status of the campaign.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CampaignStatus {
     #[serde(rename = "archived")]
     Archived,
@@ -9451,15 +12567,15 @@ pub enum CampaignStatus {
     Sending,
     #[serde(rename = "sent")]
     Sent,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for CampaignStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for CampaignStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             CampaignStatus::Archived => "archived",
             CampaignStatus::Canceled => "canceled",
             CampaignStatus::Canceling => "canceling",
@@ -9468,21 +12584,50 @@ impl std::fmt::Display for CampaignStatus {
             CampaignStatus::Schedule => "schedule",
             CampaignStatus::Sending => "sending",
             CampaignStatus::Sent => "sent",
-            CampaignStatus::Noop => "",
-            CampaignStatus::FallthroughString => "*",
+            CampaignStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for CampaignStatus {
-    fn default() -> CampaignStatus {
-        CampaignStatus::Noop
+impl std::fmt::Display for CampaignStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CampaignStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "archived" => CampaignStatus::Archived,
+            "canceled" => CampaignStatus::Canceled,
+            "canceling" => CampaignStatus::Canceling,
+            "paused" => CampaignStatus::Paused,
+            "save" => CampaignStatus::Save,
+            "schedule" => CampaignStatus::Schedule,
+            "sending" => CampaignStatus::Sending,
+            "sent" => CampaignStatus::Sent,
+            _ => CampaignStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for CampaignStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl CampaignStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, CampaignStatus::Noop)
+
+impl From<CampaignStatus> for String {
+    fn from(v: CampaignStatus) -> Self {
+        match v {
+            CampaignStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -9490,6 +12635,7 @@ impl CampaignStatus {
  * How the campaign's content is put together.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ContentType {
     #[serde(rename = "html")]
     Html,
@@ -9499,34 +12645,59 @@ pub enum ContentType {
     Template,
     #[serde(rename = "url")]
     Url,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ContentType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ContentType {
+    fn as_ref(&self) -> &str {
+        match self {
             ContentType::Html => "html",
             ContentType::Multichannel => "multichannel",
             ContentType::Template => "template",
             ContentType::Url => "url",
-            ContentType::Noop => "",
-            ContentType::FallthroughString => "*",
+            ContentType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ContentType {
-    fn default() -> ContentType {
-        ContentType::Noop
+impl std::fmt::Display for ContentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ContentType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "html" => ContentType::Html,
+            "multichannel" => ContentType::Multichannel,
+            "template" => ContentType::Template,
+            "url" => ContentType::Url,
+            _ => ContentType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for ContentType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ContentType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ContentType::Noop)
+
+impl From<ContentType> for String {
+    fn from(v: ContentType) -> Self {
+        match v {
+            ContentType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -9684,6 +12855,7 @@ pub struct GetCampaignsResponseCampaignSettings {
  * The combination that performs the best. This may be determined automatically by click rate, open rate, or total revenue -- or you may choose manually based on the reporting data you find the most valuable. For Multivariate Campaigns testing send_time, winner_criteria is ignored. For Multivariate Campaigns with 'manual' as the winner_criteria, the winner must be chosen in the Mailchimp web application.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WinnerCriteria {
     #[serde(rename = "clicks")]
     Clicks,
@@ -9693,34 +12865,59 @@ pub enum WinnerCriteria {
     Opens,
     #[serde(rename = "total_revenue")]
     TotalRevenue,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for WinnerCriteria {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for WinnerCriteria {
+    fn as_ref(&self) -> &str {
+        match self {
             WinnerCriteria::Clicks => "clicks",
             WinnerCriteria::Manual => "manual",
             WinnerCriteria::Opens => "opens",
             WinnerCriteria::TotalRevenue => "total_revenue",
-            WinnerCriteria::Noop => "",
-            WinnerCriteria::FallthroughString => "*",
+            WinnerCriteria::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for WinnerCriteria {
-    fn default() -> WinnerCriteria {
-        WinnerCriteria::Noop
+impl std::fmt::Display for WinnerCriteria {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for WinnerCriteria {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "clicks" => WinnerCriteria::Clicks,
+            "manual" => WinnerCriteria::Manual,
+            "opens" => WinnerCriteria::Opens,
+            "total_revenue" => WinnerCriteria::TotalRevenue,
+            _ => WinnerCriteria::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for WinnerCriteria {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl WinnerCriteria {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, WinnerCriteria::Noop)
+
+impl From<WinnerCriteria> for String {
+    fn from(v: WinnerCriteria) -> Self {
+        match v {
+            WinnerCriteria::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -9895,6 +13092,7 @@ pub struct ABTestOptions {
  * The frequency of the RSS Campaign.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Frequency {
     #[serde(rename = "daily")]
     Daily,
@@ -9902,33 +13100,57 @@ pub enum Frequency {
     Monthly,
     #[serde(rename = "weekly")]
     Weekly,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Frequency {
+    fn as_ref(&self) -> &str {
+        match self {
+            Frequency::Daily => "daily",
+            Frequency::Monthly => "monthly",
+            Frequency::Weekly => "weekly",
+            Frequency::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Frequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-impl std::fmt::Display for Frequency {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Frequency::Daily => "daily",
-            Frequency::Monthly => "monthly",
-            Frequency::Weekly => "weekly",
-            Frequency::Noop => "",
-            Frequency::FallthroughString => "*",
-        }
-        .fmt(f)
+impl std::str::FromStr for Frequency {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "daily" => Frequency::Daily,
+            "monthly" => Frequency::Monthly,
+            "weekly" => Frequency::Weekly,
+            _ => Frequency::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for Frequency {
-    fn default() -> Frequency {
-        Frequency::Noop
+impl From<String> for Frequency {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Frequency {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Frequency::Noop)
+
+impl From<Frequency> for String {
+    fn from(v: Frequency) -> Self {
+        match v {
+            Frequency::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -10071,6 +13293,7 @@ pub struct RssOpts {
  * The type of AB split to run.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SplitTest {
     #[serde(rename = "from_name")]
     FromName,
@@ -10078,33 +13301,57 @@ pub enum SplitTest {
     Schedule,
     #[serde(rename = "subject")]
     Subject,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SplitTest {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SplitTest {
+    fn as_ref(&self) -> &str {
+        match self {
             SplitTest::FromName => "from_name",
             SplitTest::Schedule => "schedule",
             SplitTest::Subject => "subject",
-            SplitTest::Noop => "",
-            SplitTest::FallthroughString => "*",
+            SplitTest::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SplitTest {
-    fn default() -> SplitTest {
-        SplitTest::Noop
+impl std::fmt::Display for SplitTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SplitTest {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "from_name" => SplitTest::FromName,
+            "schedule" => SplitTest::Schedule,
+            "subject" => SplitTest::Subject,
+            _ => SplitTest::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SplitTest {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SplitTest {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SplitTest::Noop)
+
+impl From<SplitTest> for String {
+    fn from(v: SplitTest) -> Self {
+        match v {
+            SplitTest::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -10112,6 +13359,7 @@ impl SplitTest {
  * How we should evaluate a winner. Based on 'opens', 'clicks', or 'manual'.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PickWinner {
     #[serde(rename = "clicks")]
     Clicks,
@@ -10119,33 +13367,57 @@ pub enum PickWinner {
     Manual,
     #[serde(rename = "opens")]
     Opens,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PickWinner {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PickWinner {
+    fn as_ref(&self) -> &str {
+        match self {
             PickWinner::Clicks => "clicks",
             PickWinner::Manual => "manual",
             PickWinner::Opens => "opens",
-            PickWinner::Noop => "",
-            PickWinner::FallthroughString => "*",
+            PickWinner::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PickWinner {
-    fn default() -> PickWinner {
-        PickWinner::Noop
+impl std::fmt::Display for PickWinner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PickWinner {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "clicks" => PickWinner::Clicks,
+            "manual" => PickWinner::Manual,
+            "opens" => PickWinner::Opens,
+            _ => PickWinner::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for PickWinner {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PickWinner {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PickWinner::Noop)
+
+impl From<PickWinner> for String {
+    fn from(v: PickWinner) -> Self {
+        match v {
+            PickWinner::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -10153,37 +13425,61 @@ impl PickWinner {
  * How unit of time for measuring the winner ('hours' or 'days'). This cannot be changed after a campaign is sent.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WaitTime {
     #[serde(rename = "days")]
     Days,
     #[serde(rename = "hours")]
     Hours,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for WaitTime {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for WaitTime {
+    fn as_ref(&self) -> &str {
+        match self {
             WaitTime::Days => "days",
             WaitTime::Hours => "hours",
-            WaitTime::Noop => "",
-            WaitTime::FallthroughString => "*",
+            WaitTime::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for WaitTime {
-    fn default() -> WaitTime {
-        WaitTime::Noop
+impl std::fmt::Display for WaitTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for WaitTime {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "days" => WaitTime::Days,
+            "hours" => WaitTime::Hours,
+            _ => WaitTime::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for WaitTime {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl WaitTime {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, WaitTime::Noop)
+
+impl From<WaitTime> for String {
+    fn from(v: WaitTime) -> Self {
+        match v {
+            WaitTime::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -10406,6 +13702,7 @@ pub struct GetCampaignsResponseCampaignReportSummary {
  * The current state of a campaign delivery.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CampaignDeliveryStatus {
     #[serde(rename = "canceled")]
     Canceled,
@@ -10415,34 +13712,59 @@ pub enum CampaignDeliveryStatus {
     Delivered,
     #[serde(rename = "delivering")]
     Delivering,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for CampaignDeliveryStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for CampaignDeliveryStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             CampaignDeliveryStatus::Canceled => "canceled",
             CampaignDeliveryStatus::Canceling => "canceling",
             CampaignDeliveryStatus::Delivered => "delivered",
             CampaignDeliveryStatus::Delivering => "delivering",
-            CampaignDeliveryStatus::Noop => "",
-            CampaignDeliveryStatus::FallthroughString => "*",
+            CampaignDeliveryStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for CampaignDeliveryStatus {
-    fn default() -> CampaignDeliveryStatus {
-        CampaignDeliveryStatus::Noop
+impl std::fmt::Display for CampaignDeliveryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CampaignDeliveryStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "canceled" => CampaignDeliveryStatus::Canceled,
+            "canceling" => CampaignDeliveryStatus::Canceling,
+            "delivered" => CampaignDeliveryStatus::Delivered,
+            "delivering" => CampaignDeliveryStatus::Delivering,
+            _ => CampaignDeliveryStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for CampaignDeliveryStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl CampaignDeliveryStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, CampaignDeliveryStatus::Noop)
+
+impl From<CampaignDeliveryStatus> for String {
+    fn from(v: CampaignDeliveryStatus) -> Self {
+        match v {
+            CampaignDeliveryStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -10927,8 +14249,8 @@ pub struct VariateSettings {
     /**
      * The combination that performs the best. This may be determined automatically by click rate, open rate, or total revenue -- or you may choose manually based on the reporting data you find the most valuable. For Multivariate Campaigns testing send_time, winner_criteria is ignored. For Multivariate Campaigns with 'manual' as the winner_criteria, the winner must be chosen in the Mailchimp web application.
      */
-    #[serde(default, skip_serializing_if = "WinnerCriteria::is_noop")]
-    pub winner_criteria: WinnerCriteria,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub winner_criteria: Option<WinnerCriteria>,
 }
 
 /// [RSS](https://mailchimp.com/help/share-your-blog-posts-with-mailchimp/) options, specific to an RSS campaign.
@@ -10954,8 +14276,8 @@ pub struct RssOptions {
     /**
      * The frequency of the RSS Campaign.
      */
-    #[serde(default, skip_serializing_if = "Frequency::is_noop")]
-    pub frequency: Frequency,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<Frequency>,
     /**
      * [RSS](https://mailchimp.com/help/share-your-blog-posts-with-mailchimp/) options, specific to an RSS campaign.
      */
@@ -10967,37 +14289,61 @@ pub struct RssOptions {
  * How the campaign's content is put together. The old drag and drop editor uses 'template' while the new editor uses 'multichannel'. Defaults to template.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CreatedCampaignContentType {
     #[serde(rename = "multichannel")]
     Multichannel,
     #[serde(rename = "template")]
     Template,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for CreatedCampaignContentType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for CreatedCampaignContentType {
+    fn as_ref(&self) -> &str {
+        match self {
             CreatedCampaignContentType::Multichannel => "multichannel",
             CreatedCampaignContentType::Template => "template",
-            CreatedCampaignContentType::Noop => "",
-            CreatedCampaignContentType::FallthroughString => "*",
+            CreatedCampaignContentType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for CreatedCampaignContentType {
-    fn default() -> CreatedCampaignContentType {
-        CreatedCampaignContentType::Noop
+impl std::fmt::Display for CreatedCampaignContentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CreatedCampaignContentType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "multichannel" => CreatedCampaignContentType::Multichannel,
+            "template" => CreatedCampaignContentType::Template,
+            _ => CreatedCampaignContentType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for CreatedCampaignContentType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl CreatedCampaignContentType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, CreatedCampaignContentType::Noop)
+
+impl From<CreatedCampaignContentType> for String {
+    fn from(v: CreatedCampaignContentType) -> Self {
+        match v {
+            CreatedCampaignContentType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -11037,12 +14383,8 @@ pub struct CreatedCampaign {
     /**
      * There are four types of [campaigns](https://mailchimp.com/help/getting-started-with-campaigns/) you can create in Mailchimp. A/B Split campaigns have been deprecated and variate campaigns should be used instead.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "CampaignType::is_noop",
-        rename = "type"
-    )]
-    pub type_: CampaignType,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<CampaignType>,
     /**
      * A summary of an individual campaign's settings and content.
      */
@@ -11226,8 +14568,8 @@ pub struct CampaignRssOptions {
     /**
      * The frequency of the RSS Campaign.
      */
-    #[serde(default, skip_serializing_if = "Frequency::is_noop")]
-    pub frequency: Frequency,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<Frequency>,
     /**
      * [RSS](https://mailchimp.com/help/share-your-blog-posts-with-mailchimp/) options for a campaign.
      */
@@ -11394,8 +14736,8 @@ pub struct CampaignABTestOptions {
     /**
      * The combination that performs the best. This may be determined automatically by click rate, open rate, or total revenue -- or you may choose manually based on the reporting data you find the most valuable. For Multivariate Campaigns testing send_time, winner_criteria is ignored. For Multivariate Campaigns with 'manual' as the winner_criteria, the winner must be chosen in the Mailchimp web application.
      */
-    #[serde(default, skip_serializing_if = "WinnerCriteria::is_noop")]
-    pub winner_criteria: WinnerCriteria,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub winner_criteria: Option<WinnerCriteria>,
     /**
      * The settings specific to A/B test campaigns.
      */
@@ -11439,8 +14781,8 @@ pub struct CampaignRssOptionsData {
     /**
      * The frequency of the RSS Campaign.
      */
-    #[serde(default, skip_serializing_if = "Frequency::is_noop")]
-    pub frequency: Frequency,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<Frequency>,
     /**
      * [RSS](https://mailchimp.com/help/share-your-blog-posts-with-mailchimp/) options for a campaign.
      */
@@ -11670,12 +15012,8 @@ pub struct CampaignDataType {
     /**
      * There are four types of [campaigns](https://mailchimp.com/help/getting-started-with-campaigns/) you can create in Mailchimp. A/B Split campaigns have been deprecated and variate campaigns should be used instead.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "CampaignType::is_noop",
-        rename = "type"
-    )]
-    pub type_: CampaignType,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<CampaignType>,
     /**
      * A summary of an individual campaign's settings and content.
      */
@@ -11742,37 +15080,61 @@ pub struct PostCampaignsActionsScheduleRequest {
  * Choose the type of test email to send.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SendType {
     #[serde(rename = "html")]
     Html,
     #[serde(rename = "plaintext")]
     Plaintext,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SendType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SendType {
+    fn as_ref(&self) -> &str {
+        match self {
             SendType::Html => "html",
             SendType::Plaintext => "plaintext",
-            SendType::Noop => "",
-            SendType::FallthroughString => "*",
+            SendType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SendType {
-    fn default() -> SendType {
-        SendType::Noop
+impl std::fmt::Display for SendType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SendType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "html" => SendType::Html,
+            "plaintext" => SendType::Plaintext,
+            _ => SendType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SendType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SendType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SendType::Noop)
+
+impl From<SendType> for String {
+    fn from(v: SendType) -> Self {
+        match v {
+            SendType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -11781,8 +15143,8 @@ pub struct PostCampaignsActionsTestRequest {
     /**
      * Choose the type of test email to send.
      */
-    #[serde(default, skip_serializing_if = "SendType::is_noop")]
-    pub send_type: SendType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub send_type: Option<SendType>,
     /**
      * A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      */
@@ -11899,6 +15261,7 @@ pub struct Template {
  * The type of encoded file. Defaults to zip.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ArchiveType {
     #[serde(rename = "tar")]
     Tar,
@@ -11912,36 +15275,63 @@ pub enum ArchiveType {
     Tgz,
     #[serde(rename = "zip")]
     Zip,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ArchiveType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ArchiveType {
+    fn as_ref(&self) -> &str {
+        match self {
             ArchiveType::Tar => "tar",
             ArchiveType::TarBz2 => "tar.bz2",
             ArchiveType::TarGz => "tar.gz",
             ArchiveType::Tbz => "tbz",
             ArchiveType::Tgz => "tgz",
             ArchiveType::Zip => "zip",
-            ArchiveType::Noop => "",
-            ArchiveType::FallthroughString => "*",
+            ArchiveType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ArchiveType {
-    fn default() -> ArchiveType {
-        ArchiveType::Noop
+impl std::fmt::Display for ArchiveType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ArchiveType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "tar" => ArchiveType::Tar,
+            "tar.bz2" => ArchiveType::TarBz2,
+            "tar.gz" => ArchiveType::TarGz,
+            "tbz" => ArchiveType::Tbz,
+            "tgz" => ArchiveType::Tgz,
+            "zip" => ArchiveType::Zip,
+            _ => ArchiveType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for ArchiveType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ArchiveType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ArchiveType::Noop)
+
+impl From<ArchiveType> for String {
+    fn from(v: ArchiveType) -> Self {
+        match v {
+            ArchiveType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -12088,6 +15478,7 @@ pub struct CampaignContentData {
  * The source of the feedback.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Source {
     #[serde(rename = "android")]
     Android,
@@ -12101,36 +15492,63 @@ pub enum Source {
     Sms,
     #[serde(rename = "web")]
     Web,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Source {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Source {
+    fn as_ref(&self) -> &str {
+        match self {
             Source::Android => "android",
             Source::Api => "api",
             Source::Email => "email",
             Source::Ios => "ios",
             Source::Sms => "sms",
             Source::Web => "web",
-            Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Source {
-    fn default() -> Source {
-        Source::Noop
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Source {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "android" => Source::Android,
+            "api" => Source::Api,
+            "email" => Source::Email,
+            "ios" => Source::Ios,
+            "sms" => Source::Sms,
+            "web" => Source::Web,
+            _ => Source::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Source {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Source {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Source::Noop)
+
+impl From<Source> for String {
+    fn from(v: Source) -> Self {
+        match v {
+            Source::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -12442,6 +15860,7 @@ pub struct CampaignFeedbackDataType {
  * The item type.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SendChecklistItemsType {
     #[serde(rename = "error")]
     Error,
@@ -12449,33 +15868,57 @@ pub enum SendChecklistItemsType {
     Success,
     #[serde(rename = "warning")]
     Warning,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SendChecklistItemsType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SendChecklistItemsType {
+    fn as_ref(&self) -> &str {
+        match self {
             SendChecklistItemsType::Error => "error",
             SendChecklistItemsType::Success => "success",
             SendChecklistItemsType::Warning => "warning",
-            SendChecklistItemsType::Noop => "",
-            SendChecklistItemsType::FallthroughString => "*",
+            SendChecklistItemsType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SendChecklistItemsType {
-    fn default() -> SendChecklistItemsType {
-        SendChecklistItemsType::Noop
+impl std::fmt::Display for SendChecklistItemsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SendChecklistItemsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "error" => SendChecklistItemsType::Error,
+            "success" => SendChecklistItemsType::Success,
+            "warning" => SendChecklistItemsType::Warning,
+            _ => SendChecklistItemsType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SendChecklistItemsType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SendChecklistItemsType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SendChecklistItemsType::Noop)
+
+impl From<SendChecklistItemsType> for String {
+    fn from(v: SendChecklistItemsType) -> Self {
+        match v {
+            SendChecklistItemsType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -12704,37 +16147,61 @@ pub struct ConnectedSite {
  * Whether a conversation message has been marked as read.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum IsRead {
     #[serde(rename = "false")]
     False,
     #[serde(rename = "true")]
     True,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for IsRead {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for IsRead {
+    fn as_ref(&self) -> &str {
+        match self {
             IsRead::False => "false",
             IsRead::True => "true",
-            IsRead::Noop => "",
-            IsRead::FallthroughString => "*",
+            IsRead::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for IsRead {
-    fn default() -> IsRead {
-        IsRead::Noop
+impl std::fmt::Display for IsRead {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for IsRead {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "false" => IsRead::False,
+            "true" => IsRead::True,
+            _ => IsRead::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for IsRead {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl IsRead {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, IsRead::Noop)
+
+impl From<IsRead> for String {
+    fn from(v: IsRead) -> Self {
+        match v {
+            IsRead::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -13062,34 +16529,57 @@ pub struct CollectionOfConversationMessages {
  * Returns files sorted by the specified field.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetFileManagerFilesSortField {
     #[serde(rename = "added_date")]
     AddedDate,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetFileManagerFilesSortField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetFileManagerFilesSortField {
+    fn as_ref(&self) -> &str {
+        match self {
             GetFileManagerFilesSortField::AddedDate => "added_date",
-            GetFileManagerFilesSortField::Noop => "",
-            GetFileManagerFilesSortField::FallthroughString => "*",
+            GetFileManagerFilesSortField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetFileManagerFilesSortField {
-    fn default() -> GetFileManagerFilesSortField {
-        GetFileManagerFilesSortField::Noop
+impl std::fmt::Display for GetFileManagerFilesSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetFileManagerFilesSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "added_date" => GetFileManagerFilesSortField::AddedDate,
+            _ => GetFileManagerFilesSortField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GetFileManagerFilesSortField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetFileManagerFilesSortField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetFileManagerFilesSortField::Noop)
+
+impl From<GetFileManagerFilesSortField> for String {
+    fn from(v: GetFileManagerFilesSortField) -> Self {
+        match v {
+            GetFileManagerFilesSortField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -13097,37 +16587,61 @@ impl GetFileManagerFilesSortField {
  * The type of file in the File Manager.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FileType {
     #[serde(rename = "file")]
     File,
     #[serde(rename = "image")]
     Image,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for FileType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for FileType {
+    fn as_ref(&self) -> &str {
+        match self {
             FileType::File => "file",
             FileType::Image => "image",
-            FileType::Noop => "",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for FileType {
-    fn default() -> FileType {
-        FileType::Noop
+impl std::fmt::Display for FileType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for FileType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "file" => FileType::File,
+            "image" => FileType::Image,
+            _ => FileType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for FileType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl FileType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FileType::Noop)
+
+impl From<FileType> for String {
+    fn from(v: FileType) -> Self {
+        match v {
+            FileType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -13435,34 +16949,57 @@ pub struct FileManagerFolders {
  * Returns files sorted by the specified field.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetListsSortField {
     #[serde(rename = "date_created")]
     DateCreated,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetListsSortField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetListsSortField {
+    fn as_ref(&self) -> &str {
+        match self {
             GetListsSortField::DateCreated => "date_created",
-            GetListsSortField::Noop => "",
-            GetListsSortField::FallthroughString => "*",
+            GetListsSortField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetListsSortField {
-    fn default() -> GetListsSortField {
-        GetListsSortField::Noop
+impl std::fmt::Display for GetListsSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetListsSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "date_created" => GetListsSortField::DateCreated,
+            _ => GetListsSortField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GetListsSortField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetListsSortField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetListsSortField::Noop)
+
+impl From<GetListsSortField> for String {
+    fn from(v: GetListsSortField) -> Self {
+        match v {
+            GetListsSortField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -13590,37 +17127,61 @@ pub struct CampaignDefaults {
  * Legacy - visibility settings are no longer used
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Visibility {
     #[serde(rename = "prv")]
     Prv,
     #[serde(rename = "pub")]
     Pub,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Visibility {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Visibility {
+    fn as_ref(&self) -> &str {
+        match self {
             Visibility::Prv => "prv",
             Visibility::Pub => "pub",
-            Visibility::Noop => "",
-            Visibility::FallthroughString => "*",
+            Visibility::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Visibility {
-    fn default() -> Visibility {
-        Visibility::Noop
+impl std::fmt::Display for Visibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Visibility {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "prv" => Visibility::Prv,
+            "pub" => Visibility::Pub,
+            _ => Visibility::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Visibility {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Visibility {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Visibility::Noop)
+
+impl From<Visibility> for String {
+    fn from(v: Visibility) -> Self {
+        match v {
+            Visibility::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -14241,6 +17802,7 @@ pub struct SubscriberList {
  * Subscriber's current status.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MembersSubscribeUnsubscribeFromAListInBatchStatus {
     #[serde(rename = "cleaned")]
     Cleaned,
@@ -14250,37 +17812,59 @@ pub enum MembersSubscribeUnsubscribeFromAListInBatchStatus {
     Subscribed,
     #[serde(rename = "unsubscribed")]
     Unsubscribed,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for MembersSubscribeUnsubscribeFromAListInBatchStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for MembersSubscribeUnsubscribeFromAListInBatchStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Cleaned => "cleaned",
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Pending => "pending",
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Subscribed => "subscribed",
             MembersSubscribeUnsubscribeFromAListInBatchStatus::Unsubscribed => "unsubscribed",
-            MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop => "",
-            MembersSubscribeUnsubscribeFromAListInBatchStatus::FallthroughString => "*",
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for MembersSubscribeUnsubscribeFromAListInBatchStatus {
-    fn default() -> MembersSubscribeUnsubscribeFromAListInBatchStatus {
-        MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop
+impl std::fmt::Display for MembersSubscribeUnsubscribeFromAListInBatchStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for MembersSubscribeUnsubscribeFromAListInBatchStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "cleaned" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Cleaned,
+            "pending" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Pending,
+            "subscribed" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Subscribed,
+            "unsubscribed" => MembersSubscribeUnsubscribeFromAListInBatchStatus::Unsubscribed,
+            _ => MembersSubscribeUnsubscribeFromAListInBatchStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for MembersSubscribeUnsubscribeFromAListInBatchStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl MembersSubscribeUnsubscribeFromAListInBatchStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(
-            self,
-            MembersSubscribeUnsubscribeFromAListInBatchStatus::Noop
-        )
+
+impl From<MembersSubscribeUnsubscribeFromAListInBatchStatus> for String {
+    fn from(v: MembersSubscribeUnsubscribeFromAListInBatchStatus) -> Self {
+        match v {
+            MembersSubscribeUnsubscribeFromAListInBatchStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -14432,6 +18016,7 @@ pub struct MembersSubscribeUnsubscribeFromAListInBatch {
  * Subscriber's status. This value is required only if the email address is not already present on the list.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum StatusIfNew {
     #[serde(rename = "cleaned")]
     Cleaned,
@@ -14443,35 +18028,61 @@ pub enum StatusIfNew {
     Transactional,
     #[serde(rename = "unsubscribed")]
     Unsubscribed,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for StatusIfNew {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for StatusIfNew {
+    fn as_ref(&self) -> &str {
+        match self {
             StatusIfNew::Cleaned => "cleaned",
             StatusIfNew::Pending => "pending",
             StatusIfNew::Subscribed => "subscribed",
             StatusIfNew::Transactional => "transactional",
             StatusIfNew::Unsubscribed => "unsubscribed",
-            StatusIfNew::Noop => "",
-            StatusIfNew::FallthroughString => "*",
+            StatusIfNew::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for StatusIfNew {
-    fn default() -> StatusIfNew {
-        StatusIfNew::Noop
+impl std::fmt::Display for StatusIfNew {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for StatusIfNew {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "cleaned" => StatusIfNew::Cleaned,
+            "pending" => StatusIfNew::Pending,
+            "subscribed" => StatusIfNew::Subscribed,
+            "transactional" => StatusIfNew::Transactional,
+            "unsubscribed" => StatusIfNew::Unsubscribed,
+            _ => StatusIfNew::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for StatusIfNew {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl StatusIfNew {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, StatusIfNew::Noop)
+
+impl From<StatusIfNew> for String {
+    fn from(v: StatusIfNew) -> Self {
+        match v {
+            StatusIfNew::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -14815,37 +18426,61 @@ pub struct NewMembers {
  * A unique code that identifies this specifc error.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ErrorCode {
     #[serde(rename = "ERROR_CONTACT_EXISTS")]
     ErrorContactExists,
     #[serde(rename = "ERROR_GENERIC")]
     ErrorGeneric,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ErrorCode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ErrorCode {
+    fn as_ref(&self) -> &str {
+        match self {
             ErrorCode::ErrorContactExists => "ERROR_CONTACT_EXISTS",
             ErrorCode::ErrorGeneric => "ERROR_GENERIC",
-            ErrorCode::Noop => "",
-            ErrorCode::FallthroughString => "*",
+            ErrorCode::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ErrorCode {
-    fn default() -> ErrorCode {
-        ErrorCode::Noop
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ErrorCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ERROR_CONTACT_EXISTS" => ErrorCode::ErrorContactExists,
+            "ERROR_GENERIC" => ErrorCode::ErrorGeneric,
+            _ => ErrorCode::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for ErrorCode {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ErrorCode {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ErrorCode::Noop)
+
+impl From<ErrorCode> for String {
+    fn from(v: ErrorCode) -> Self {
+        match v {
+            ErrorCode::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -15447,34 +19082,57 @@ pub struct EmailClients {
  * Returns files sorted by the specified field.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetListsGrowthHistorySortField {
     #[serde(rename = "month")]
     Month,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetListsGrowthHistorySortField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetListsGrowthHistorySortField {
+    fn as_ref(&self) -> &str {
+        match self {
             GetListsGrowthHistorySortField::Month => "month",
-            GetListsGrowthHistorySortField::Noop => "",
-            GetListsGrowthHistorySortField::FallthroughString => "*",
+            GetListsGrowthHistorySortField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetListsGrowthHistorySortField {
-    fn default() -> GetListsGrowthHistorySortField {
-        GetListsGrowthHistorySortField::Noop
+impl std::fmt::Display for GetListsGrowthHistorySortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetListsGrowthHistorySortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "month" => GetListsGrowthHistorySortField::Month,
+            _ => GetListsGrowthHistorySortField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GetListsGrowthHistorySortField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetListsGrowthHistorySortField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetListsGrowthHistorySortField::Noop)
+
+impl From<GetListsGrowthHistorySortField> for String {
+    fn from(v: GetListsGrowthHistorySortField) -> Self {
+        match v {
+            GetListsGrowthHistorySortField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -15865,6 +19523,7 @@ pub struct InterestsData {
  * The type of segment. Static segments are now known as tags. Learn more about [tags](https://mailchimp.com/help/getting-started-tags?utm_source=mc-api&utm_medium=docs&utm_campaign=apidocs).
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CollectionOfSegmentsType {
     #[serde(rename = "fuzzy")]
     Fuzzy,
@@ -15872,33 +19531,57 @@ pub enum CollectionOfSegmentsType {
     Saved,
     #[serde(rename = "static")]
     Static,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for CollectionOfSegmentsType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for CollectionOfSegmentsType {
+    fn as_ref(&self) -> &str {
+        match self {
             CollectionOfSegmentsType::Fuzzy => "fuzzy",
             CollectionOfSegmentsType::Saved => "saved",
             CollectionOfSegmentsType::Static => "static",
-            CollectionOfSegmentsType::Noop => "",
-            CollectionOfSegmentsType::FallthroughString => "*",
+            CollectionOfSegmentsType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for CollectionOfSegmentsType {
-    fn default() -> CollectionOfSegmentsType {
-        CollectionOfSegmentsType::Noop
+impl std::fmt::Display for CollectionOfSegmentsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CollectionOfSegmentsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "fuzzy" => CollectionOfSegmentsType::Fuzzy,
+            "saved" => CollectionOfSegmentsType::Saved,
+            "static" => CollectionOfSegmentsType::Static,
+            _ => CollectionOfSegmentsType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for CollectionOfSegmentsType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl CollectionOfSegmentsType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, CollectionOfSegmentsType::Noop)
+
+impl From<CollectionOfSegmentsType> for String {
+    fn from(v: CollectionOfSegmentsType) -> Self {
+        match v {
+            CollectionOfSegmentsType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -16483,6 +20166,7 @@ pub struct TagSearchResults {
  * The subscriber's status.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetListsMembersStatus {
     #[serde(rename = "archived")]
     Archived,
@@ -16496,36 +20180,63 @@ pub enum GetListsMembersStatus {
     Transactional,
     #[serde(rename = "unsubscribed")]
     Unsubscribed,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetListsMembersStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetListsMembersStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             GetListsMembersStatus::Archived => "archived",
             GetListsMembersStatus::Cleaned => "cleaned",
             GetListsMembersStatus::Pending => "pending",
             GetListsMembersStatus::Subscribed => "subscribed",
             GetListsMembersStatus::Transactional => "transactional",
             GetListsMembersStatus::Unsubscribed => "unsubscribed",
-            GetListsMembersStatus::Noop => "",
-            GetListsMembersStatus::FallthroughString => "*",
+            GetListsMembersStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetListsMembersStatus {
-    fn default() -> GetListsMembersStatus {
-        GetListsMembersStatus::Noop
+impl std::fmt::Display for GetListsMembersStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetListsMembersStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "archived" => GetListsMembersStatus::Archived,
+            "cleaned" => GetListsMembersStatus::Cleaned,
+            "pending" => GetListsMembersStatus::Pending,
+            "subscribed" => GetListsMembersStatus::Subscribed,
+            "transactional" => GetListsMembersStatus::Transactional,
+            "unsubscribed" => GetListsMembersStatus::Unsubscribed,
+            _ => GetListsMembersStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GetListsMembersStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetListsMembersStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetListsMembersStatus::Noop)
+
+impl From<GetListsMembersStatus> for String {
+    fn from(v: GetListsMembersStatus) -> Self {
+        match v {
+            GetListsMembersStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -16533,6 +20244,7 @@ impl GetListsMembersStatus {
  * Used to filter list members by interests. Must be accompanied by interest_category_id and interest_ids. "any" will match a member with any of the interest supplied, "all" will only match members with every interest supplied, and "none" will match members without any of the interest supplied.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum InterestMatch {
     #[serde(rename = "all")]
     All,
@@ -16540,33 +20252,57 @@ pub enum InterestMatch {
     Any,
     #[serde(rename = "none")]
     None,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for InterestMatch {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for InterestMatch {
+    fn as_ref(&self) -> &str {
+        match self {
             InterestMatch::All => "all",
             InterestMatch::Any => "any",
             InterestMatch::None => "none",
-            InterestMatch::Noop => "",
-            InterestMatch::FallthroughString => "*",
+            InterestMatch::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for InterestMatch {
-    fn default() -> InterestMatch {
-        InterestMatch::Noop
+impl std::fmt::Display for InterestMatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for InterestMatch {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => InterestMatch::All,
+            "any" => InterestMatch::Any,
+            "none" => InterestMatch::None,
+            _ => InterestMatch::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for InterestMatch {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl InterestMatch {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, InterestMatch::Noop)
+
+impl From<InterestMatch> for String {
+    fn from(v: InterestMatch) -> Self {
+        match v {
+            InterestMatch::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -16574,6 +20310,7 @@ impl InterestMatch {
  * Returns files sorted by the specified field.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetListsMembersSortField {
     #[serde(rename = "last_changed")]
     LastChanged,
@@ -16581,33 +20318,57 @@ pub enum GetListsMembersSortField {
     TimestampOpt,
     #[serde(rename = "timestamp_signup")]
     TimestampSignup,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetListsMembersSortField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetListsMembersSortField {
+    fn as_ref(&self) -> &str {
+        match self {
             GetListsMembersSortField::LastChanged => "last_changed",
             GetListsMembersSortField::TimestampOpt => "timestamp_opt",
             GetListsMembersSortField::TimestampSignup => "timestamp_signup",
-            GetListsMembersSortField::Noop => "",
-            GetListsMembersSortField::FallthroughString => "*",
+            GetListsMembersSortField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetListsMembersSortField {
-    fn default() -> GetListsMembersSortField {
-        GetListsMembersSortField::Noop
+impl std::fmt::Display for GetListsMembersSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetListsMembersSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "last_changed" => GetListsMembersSortField::LastChanged,
+            "timestamp_opt" => GetListsMembersSortField::TimestampOpt,
+            "timestamp_signup" => GetListsMembersSortField::TimestampSignup,
+            _ => GetListsMembersSortField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GetListsMembersSortField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetListsMembersSortField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetListsMembersSortField::Noop)
+
+impl From<GetListsMembersSortField> for String {
+    fn from(v: GetListsMembersSortField) -> Self {
+        match v {
+            GetListsMembersSortField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -17080,8 +20841,8 @@ pub struct AddListMembers {
     /**
      * Subscriber's status. This value is required only if the email address is not already present on the list.
      */
-    #[serde(default, skip_serializing_if = "StatusIfNew::is_noop")]
-    pub status: StatusIfNew,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<StatusIfNew>,
     /**
      * Individuals who are currently or have been previously subscribed to this list, including members who have bounced or unsubscribed.
      */
@@ -17202,8 +20963,8 @@ pub struct AddListMembersData {
     /**
      * Subscriber's status. This value is required only if the email address is not already present on the list.
      */
-    #[serde(default, skip_serializing_if = "StatusIfNew::is_noop")]
-    pub status_if_new: StatusIfNew,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_if_new: Option<StatusIfNew>,
     /**
      * Individuals who are currently or have been previously subscribed to this list, including members who have bounced or unsubscribed.
      */
@@ -17464,34 +21225,57 @@ pub struct MemberActivityEvents {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ActivityType {
     #[serde(rename = "open")]
     Open,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             ActivityType::Open => "open",
-            ActivityType::Noop => "",
-            ActivityType::FallthroughString => "*",
+            ActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ActivityType {
-    fn default() -> ActivityType {
-        ActivityType::Noop
+impl std::fmt::Display for ActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "open" => ActivityType::Open,
+            _ => ActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for ActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ActivityType::Noop)
+
+impl From<ActivityType> for String {
+    fn from(v: ActivityType) -> Self {
+        match v {
+            ActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -17536,34 +21320,57 @@ pub struct EmailOpens {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailClicksActivityType {
     #[serde(rename = "click")]
     Click,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EmailClicksActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EmailClicksActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             EmailClicksActivityType::Click => "click",
-            EmailClicksActivityType::Noop => "",
-            EmailClicksActivityType::FallthroughString => "*",
+            EmailClicksActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EmailClicksActivityType {
-    fn default() -> EmailClicksActivityType {
-        EmailClicksActivityType::Noop
+impl std::fmt::Display for EmailClicksActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EmailClicksActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "click" => EmailClicksActivityType::Click,
+            _ => EmailClicksActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EmailClicksActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EmailClicksActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EmailClicksActivityType::Noop)
+
+impl From<EmailClicksActivityType> for String {
+    fn from(v: EmailClicksActivityType) -> Self {
+        match v {
+            EmailClicksActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -17617,34 +21424,57 @@ pub struct EmailClicks {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailBouncedActivityType {
     #[serde(rename = "bounce")]
     Bounce,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EmailBouncedActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EmailBouncedActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             EmailBouncedActivityType::Bounce => "bounce",
-            EmailBouncedActivityType::Noop => "",
-            EmailBouncedActivityType::FallthroughString => "*",
+            EmailBouncedActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EmailBouncedActivityType {
-    fn default() -> EmailBouncedActivityType {
-        EmailBouncedActivityType::Noop
+impl std::fmt::Display for EmailBouncedActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EmailBouncedActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "bounce" => EmailBouncedActivityType::Bounce,
+            _ => EmailBouncedActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EmailBouncedActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EmailBouncedActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EmailBouncedActivityType::Noop)
+
+impl From<EmailBouncedActivityType> for String {
+    fn from(v: EmailBouncedActivityType) -> Self {
+        match v {
+            EmailBouncedActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -17652,37 +21482,61 @@ impl EmailBouncedActivityType {
  * The type of bounce.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum BounceType {
     #[serde(rename = "hard")]
     Hard,
     #[serde(rename = "soft")]
     Soft,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for BounceType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for BounceType {
+    fn as_ref(&self) -> &str {
+        match self {
             BounceType::Hard => "hard",
             BounceType::Soft => "soft",
-            BounceType::Noop => "",
-            BounceType::FallthroughString => "*",
+            BounceType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for BounceType {
-    fn default() -> BounceType {
-        BounceType::Noop
+impl std::fmt::Display for BounceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for BounceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "hard" => BounceType::Hard,
+            "soft" => BounceType::Soft,
+            _ => BounceType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for BounceType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl BounceType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, BounceType::Noop)
+
+impl From<BounceType> for String {
+    fn from(v: BounceType) -> Self {
+        match v {
+            BounceType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -17740,34 +21594,57 @@ pub struct EmailBounced {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListUnsubscribedActivityType {
     #[serde(rename = "unsub")]
     Unsub,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ListUnsubscribedActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ListUnsubscribedActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             ListUnsubscribedActivityType::Unsub => "unsub",
-            ListUnsubscribedActivityType::Noop => "",
-            ListUnsubscribedActivityType::FallthroughString => "*",
+            ListUnsubscribedActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ListUnsubscribedActivityType {
-    fn default() -> ListUnsubscribedActivityType {
-        ListUnsubscribedActivityType::Noop
+impl std::fmt::Display for ListUnsubscribedActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ListUnsubscribedActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "unsub" => ListUnsubscribedActivityType::Unsub,
+            _ => ListUnsubscribedActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for ListUnsubscribedActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ListUnsubscribedActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ListUnsubscribedActivityType::Noop)
+
+impl From<ListUnsubscribedActivityType> for String {
+    fn from(v: ListUnsubscribedActivityType) -> Self {
+        match v {
+            ListUnsubscribedActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -17829,34 +21706,57 @@ pub struct ListUnsubscribed {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EmailSentActivityType {
     #[serde(rename = "sent")]
     Sent,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EmailSentActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EmailSentActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             EmailSentActivityType::Sent => "sent",
-            EmailSentActivityType::Noop => "",
-            EmailSentActivityType::FallthroughString => "*",
+            EmailSentActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EmailSentActivityType {
-    fn default() -> EmailSentActivityType {
-        EmailSentActivityType::Noop
+impl std::fmt::Display for EmailSentActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EmailSentActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "sent" => EmailSentActivityType::Sent,
+            _ => EmailSentActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EmailSentActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EmailSentActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EmailSentActivityType::Noop)
+
+impl From<EmailSentActivityType> for String {
+    fn from(v: EmailSentActivityType) -> Self {
+        match v {
+            EmailSentActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -17999,34 +21899,57 @@ pub struct EmailConversation {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NoteActivityType {
     #[serde(rename = "note")]
     Note,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for NoteActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for NoteActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             NoteActivityType::Note => "note",
-            NoteActivityType::Noop => "",
-            NoteActivityType::FallthroughString => "*",
+            NoteActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for NoteActivityType {
-    fn default() -> NoteActivityType {
-        NoteActivityType::Noop
+impl std::fmt::Display for NoteActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for NoteActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "note" => NoteActivityType::Note,
+            _ => NoteActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for NoteActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl NoteActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, NoteActivityType::Noop)
+
+impl From<NoteActivityType> for String {
+    fn from(v: NoteActivityType) -> Self {
+        match v {
+            NoteActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -18098,34 +22021,57 @@ pub struct Note {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MarketingPermissionActivityType {
     #[serde(rename = "marketing_permission")]
     MarketingPermission,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for MarketingPermissionActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
+            MarketingPermissionActivityType::MarketingPermission => "marketing_permission",
+            MarketingPermissionActivityType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for MarketingPermissionActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-impl std::fmt::Display for MarketingPermissionActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            MarketingPermissionActivityType::MarketingPermission => "marketing_permission",
-            MarketingPermissionActivityType::Noop => "",
-            MarketingPermissionActivityType::FallthroughString => "*",
-        }
-        .fmt(f)
+impl std::str::FromStr for MarketingPermissionActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "marketing_permission" => MarketingPermissionActivityType::MarketingPermission,
+            _ => MarketingPermissionActivityType::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for MarketingPermissionActivityType {
-    fn default() -> MarketingPermissionActivityType {
-        MarketingPermissionActivityType::Noop
+impl From<String> for MarketingPermissionActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl MarketingPermissionActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MarketingPermissionActivityType::Noop)
+
+impl From<MarketingPermissionActivityType> for String {
+    fn from(v: MarketingPermissionActivityType) -> Self {
+        match v {
+            MarketingPermissionActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -18178,34 +22124,57 @@ pub struct MarketingPermissionData {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PostcardSentActivityType {
     #[serde(rename = "postcard_sent")]
     PostcardSent,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PostcardSentActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PostcardSentActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             PostcardSentActivityType::PostcardSent => "postcard_sent",
-            PostcardSentActivityType::Noop => "",
-            PostcardSentActivityType::FallthroughString => "*",
+            PostcardSentActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PostcardSentActivityType {
-    fn default() -> PostcardSentActivityType {
-        PostcardSentActivityType::Noop
+impl std::fmt::Display for PostcardSentActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PostcardSentActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "postcard_sent" => PostcardSentActivityType::PostcardSent,
+            _ => PostcardSentActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for PostcardSentActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PostcardSentActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PostcardSentActivityType::Noop)
+
+impl From<PostcardSentActivityType> for String {
+    fn from(v: PostcardSentActivityType) -> Self {
+        match v {
+            PostcardSentActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -18250,34 +22219,57 @@ pub struct PostcardSent {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SquatterSignupActivityType {
     #[serde(rename = "squatter_signup")]
     SquatterSignup,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SquatterSignupActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SquatterSignupActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             SquatterSignupActivityType::SquatterSignup => "squatter_signup",
-            SquatterSignupActivityType::Noop => "",
-            SquatterSignupActivityType::FallthroughString => "*",
+            SquatterSignupActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SquatterSignupActivityType {
-    fn default() -> SquatterSignupActivityType {
-        SquatterSignupActivityType::Noop
+impl std::fmt::Display for SquatterSignupActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SquatterSignupActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "squatter_signup" => SquatterSignupActivityType::SquatterSignup,
+            _ => SquatterSignupActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SquatterSignupActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SquatterSignupActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SquatterSignupActivityType::Noop)
+
+impl From<SquatterSignupActivityType> for String {
+    fn from(v: SquatterSignupActivityType) -> Self {
+        match v {
+            SquatterSignupActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -18322,34 +22314,57 @@ pub struct SquatterSignup {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WebsiteSignupActivityType {
     #[serde(rename = "website_signup")]
     WebsiteSignup,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for WebsiteSignupActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for WebsiteSignupActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             WebsiteSignupActivityType::WebsiteSignup => "website_signup",
-            WebsiteSignupActivityType::Noop => "",
-            WebsiteSignupActivityType::FallthroughString => "*",
+            WebsiteSignupActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for WebsiteSignupActivityType {
-    fn default() -> WebsiteSignupActivityType {
-        WebsiteSignupActivityType::Noop
+impl std::fmt::Display for WebsiteSignupActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for WebsiteSignupActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "website_signup" => WebsiteSignupActivityType::WebsiteSignup,
+            _ => WebsiteSignupActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for WebsiteSignupActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl WebsiteSignupActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, WebsiteSignupActivityType::Noop)
+
+impl From<WebsiteSignupActivityType> for String {
+    fn from(v: WebsiteSignupActivityType) -> Self {
+        match v {
+            WebsiteSignupActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -18394,34 +22409,57 @@ pub struct WebsiteSignup {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LandingPageSignupActivityType {
     #[serde(rename = "landing_page_signup")]
     LandingPageSignup,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LandingPageSignupActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LandingPageSignupActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             LandingPageSignupActivityType::LandingPageSignup => "landing_page_signup",
-            LandingPageSignupActivityType::Noop => "",
-            LandingPageSignupActivityType::FallthroughString => "*",
+            LandingPageSignupActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LandingPageSignupActivityType {
-    fn default() -> LandingPageSignupActivityType {
-        LandingPageSignupActivityType::Noop
+impl std::fmt::Display for LandingPageSignupActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LandingPageSignupActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "landing_page_signup" => LandingPageSignupActivityType::LandingPageSignup,
+            _ => LandingPageSignupActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for LandingPageSignupActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LandingPageSignupActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LandingPageSignupActivityType::Noop)
+
+impl From<LandingPageSignupActivityType> for String {
+    fn from(v: LandingPageSignupActivityType) -> Self {
+        match v {
+            LandingPageSignupActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -18466,34 +22504,57 @@ pub struct LandingPageSignup {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceSignupActivityType {
     #[serde(rename = "ecommerce_signup")]
     EcommerceSignup,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommerceSignupActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommerceSignupActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommerceSignupActivityType::EcommerceSignup => "ecommerce_signup",
-            EcommerceSignupActivityType::Noop => "",
-            EcommerceSignupActivityType::FallthroughString => "*",
+            EcommerceSignupActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommerceSignupActivityType {
-    fn default() -> EcommerceSignupActivityType {
-        EcommerceSignupActivityType::Noop
+impl std::fmt::Display for EcommerceSignupActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EcommerceSignupActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "ecommerce_signup" => EcommerceSignupActivityType::EcommerceSignup,
+            _ => EcommerceSignupActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EcommerceSignupActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EcommerceSignupActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommerceSignupActivityType::Noop)
+
+impl From<EcommerceSignupActivityType> for String {
+    fn from(v: EcommerceSignupActivityType) -> Self {
+        match v {
+            EcommerceSignupActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -18529,34 +22590,57 @@ pub struct EcommerceSignup {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GenericSignupActivityType {
     #[serde(rename = "generic_signup")]
     GenericSignup,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GenericSignupActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GenericSignupActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             GenericSignupActivityType::GenericSignup => "generic_signup",
-            GenericSignupActivityType::Noop => "",
-            GenericSignupActivityType::FallthroughString => "*",
+            GenericSignupActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GenericSignupActivityType {
-    fn default() -> GenericSignupActivityType {
-        GenericSignupActivityType::Noop
+impl std::fmt::Display for GenericSignupActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GenericSignupActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "generic_signup" => GenericSignupActivityType::GenericSignup,
+            _ => GenericSignupActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GenericSignupActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GenericSignupActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GenericSignupActivityType::Noop)
+
+impl From<GenericSignupActivityType> for String {
+    fn from(v: GenericSignupActivityType) -> Self {
+        match v {
+            GenericSignupActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -18592,34 +22676,57 @@ pub struct GenericSignup {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EcommerceOrderActivityType {
     #[serde(rename = "order")]
     Order,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EcommerceOrderActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EcommerceOrderActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             EcommerceOrderActivityType::Order => "order",
-            EcommerceOrderActivityType::Noop => "",
-            EcommerceOrderActivityType::FallthroughString => "*",
+            EcommerceOrderActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EcommerceOrderActivityType {
-    fn default() -> EcommerceOrderActivityType {
-        EcommerceOrderActivityType::Noop
+impl std::fmt::Display for EcommerceOrderActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EcommerceOrderActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "order" => EcommerceOrderActivityType::Order,
+            _ => EcommerceOrderActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for EcommerceOrderActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EcommerceOrderActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EcommerceOrderActivityType::Noop)
+
+impl From<EcommerceOrderActivityType> for String {
+    fn from(v: EcommerceOrderActivityType) -> Self {
+        match v {
+            EcommerceOrderActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -18814,34 +22921,57 @@ pub struct EcommerceOrder {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ContactActivityEventType {
     #[serde(rename = "event")]
     Event,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ContactActivityEventType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ContactActivityEventType {
+    fn as_ref(&self) -> &str {
+        match self {
             ContactActivityEventType::Event => "event",
-            ContactActivityEventType::Noop => "",
-            ContactActivityEventType::FallthroughString => "*",
+            ContactActivityEventType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ContactActivityEventType {
-    fn default() -> ContactActivityEventType {
-        ContactActivityEventType::Noop
+impl std::fmt::Display for ContactActivityEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ContactActivityEventType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "event" => ContactActivityEventType::Event,
+            _ => ContactActivityEventType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for ContactActivityEventType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ContactActivityEventType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ContactActivityEventType::Noop)
+
+impl From<ContactActivityEventType> for String {
+    fn from(v: ContactActivityEventType) -> Self {
+        match v {
+            ContactActivityEventType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -18886,34 +23016,57 @@ pub struct ContactActivityEvent {
  * The type of event activity.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SurveyResponseActivityType {
     #[serde(rename = "survey_response")]
     SurveyResponse,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SurveyResponseActivityType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SurveyResponseActivityType {
+    fn as_ref(&self) -> &str {
+        match self {
             SurveyResponseActivityType::SurveyResponse => "survey_response",
-            SurveyResponseActivityType::Noop => "",
-            SurveyResponseActivityType::FallthroughString => "*",
+            SurveyResponseActivityType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SurveyResponseActivityType {
-    fn default() -> SurveyResponseActivityType {
-        SurveyResponseActivityType::Noop
+impl std::fmt::Display for SurveyResponseActivityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SurveyResponseActivityType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "survey_response" => SurveyResponseActivityType::SurveyResponse,
+            _ => SurveyResponseActivityType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SurveyResponseActivityType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SurveyResponseActivityType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SurveyResponseActivityType::Noop)
+
+impl From<SurveyResponseActivityType> for String {
+    fn from(v: SurveyResponseActivityType) -> Self {
+        match v {
+            SurveyResponseActivityType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -19248,37 +23401,61 @@ pub struct CollectionOfTags {
  * The status for the tag on the member, pass in active to add a tag or inactive to remove it.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MemberTagsTagStatus {
     #[serde(rename = "active")]
     Active,
     #[serde(rename = "inactive")]
     Inactive,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for MemberTagsTagStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for MemberTagsTagStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             MemberTagsTagStatus::Active => "active",
             MemberTagsTagStatus::Inactive => "inactive",
-            MemberTagsTagStatus::Noop => "",
-            MemberTagsTagStatus::FallthroughString => "*",
+            MemberTagsTagStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for MemberTagsTagStatus {
-    fn default() -> MemberTagsTagStatus {
-        MemberTagsTagStatus::Noop
+impl std::fmt::Display for MemberTagsTagStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for MemberTagsTagStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => MemberTagsTagStatus::Active,
+            "inactive" => MemberTagsTagStatus::Inactive,
+            _ => MemberTagsTagStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for MemberTagsTagStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl MemberTagsTagStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MemberTagsTagStatus::Noop)
+
+impl From<MemberTagsTagStatus> for String {
+    fn from(v: MemberTagsTagStatus) -> Self {
+        match v {
+            MemberTagsTagStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -19297,8 +23474,8 @@ pub struct MemberTag {
     /**
      * The status for the tag on the member, pass in active to add a tag or inactive to remove it.
      */
-    #[serde(default, skip_serializing_if = "MemberTagsTagStatus::is_noop")]
-    pub status: MemberTagsTagStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<MemberTagsTagStatus>,
 }
 
 /// A list of tags assigned to a list member.
@@ -19524,6 +23701,7 @@ pub struct CollectionOfMemberActivityEvents {
  * Returns notes sorted by the specified field.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetListsMembersNotesSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -19531,33 +23709,57 @@ pub enum GetListsMembersNotesSortField {
     NoteId,
     #[serde(rename = "updated_at")]
     UpdatedAt,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetListsMembersNotesSortField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetListsMembersNotesSortField {
+    fn as_ref(&self) -> &str {
+        match self {
             GetListsMembersNotesSortField::CreatedAt => "created_at",
             GetListsMembersNotesSortField::NoteId => "note_id",
             GetListsMembersNotesSortField::UpdatedAt => "updated_at",
-            GetListsMembersNotesSortField::Noop => "",
-            GetListsMembersNotesSortField::FallthroughString => "*",
+            GetListsMembersNotesSortField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetListsMembersNotesSortField {
-    fn default() -> GetListsMembersNotesSortField {
-        GetListsMembersNotesSortField::Noop
+impl std::fmt::Display for GetListsMembersNotesSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetListsMembersNotesSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "created_at" => GetListsMembersNotesSortField::CreatedAt,
+            "note_id" => GetListsMembersNotesSortField::NoteId,
+            "updated_at" => GetListsMembersNotesSortField::UpdatedAt,
+            _ => GetListsMembersNotesSortField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GetListsMembersNotesSortField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetListsMembersNotesSortField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetListsMembersNotesSortField::Noop)
+
+impl From<GetListsMembersNotesSortField> for String {
+    fn from(v: GetListsMembersNotesSortField) -> Self {
+        match v {
+            GetListsMembersNotesSortField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -19694,6 +23896,7 @@ pub struct CollectionOfNotes {
  * The [type](https://mailchimp.com/help/manage-audience-signup-form-fields/#Audience_field_types) for the merge field.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MergeFieldType {
     #[serde(rename = "address")]
     Address,
@@ -19717,15 +23920,15 @@ pub enum MergeFieldType {
     Url,
     #[serde(rename = "zip")]
     Zip,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for MergeFieldType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for MergeFieldType {
+    fn as_ref(&self) -> &str {
+        match self {
             MergeFieldType::Address => "address",
             MergeFieldType::Birthday => "birthday",
             MergeFieldType::Date => "date",
@@ -19737,21 +23940,53 @@ impl std::fmt::Display for MergeFieldType {
             MergeFieldType::Text => "text",
             MergeFieldType::Url => "url",
             MergeFieldType::Zip => "zip",
-            MergeFieldType::Noop => "",
-            MergeFieldType::FallthroughString => "*",
+            MergeFieldType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for MergeFieldType {
-    fn default() -> MergeFieldType {
-        MergeFieldType::Noop
+impl std::fmt::Display for MergeFieldType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for MergeFieldType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "address" => MergeFieldType::Address,
+            "birthday" => MergeFieldType::Birthday,
+            "date" => MergeFieldType::Date,
+            "dropdown" => MergeFieldType::Dropdown,
+            "imageurl" => MergeFieldType::Imageurl,
+            "number" => MergeFieldType::Number,
+            "phone" => MergeFieldType::Phone,
+            "radio" => MergeFieldType::Radio,
+            "text" => MergeFieldType::Text,
+            "url" => MergeFieldType::Url,
+            "zip" => MergeFieldType::Zip,
+            _ => MergeFieldType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for MergeFieldType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl MergeFieldType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MergeFieldType::Noop)
+
+impl From<MergeFieldType> for String {
+    fn from(v: MergeFieldType) -> Self {
+        match v {
+            MergeFieldType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -20023,12 +24258,8 @@ pub struct MergeFieldData {
     /**
      * The [type](https://mailchimp.com/help/manage-audience-signup-form-fields/#Audience_field_types) for the merge field.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "MergeFieldType::is_noop",
-        rename = "type"
-    )]
-    pub type_: MergeFieldType,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<MergeFieldType>,
 }
 
 /// Extra options for some merge field types.
@@ -20323,6 +24554,7 @@ pub struct ListWebhooksData {
  * Image alignment.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ImageAlign {
     #[serde(rename = "center")]
     Center,
@@ -20332,34 +24564,59 @@ pub enum ImageAlign {
     None,
     #[serde(rename = "right")]
     Right,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ImageAlign {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ImageAlign {
+    fn as_ref(&self) -> &str {
+        match self {
             ImageAlign::Center => "center",
             ImageAlign::Left => "left",
             ImageAlign::None => "none",
             ImageAlign::Right => "right",
-            ImageAlign::Noop => "",
-            ImageAlign::FallthroughString => "*",
+            ImageAlign::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ImageAlign {
-    fn default() -> ImageAlign {
-        ImageAlign::Noop
+impl std::fmt::Display for ImageAlign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ImageAlign {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "center" => ImageAlign::Center,
+            "left" => ImageAlign::Left,
+            "none" => ImageAlign::None,
+            "right" => ImageAlign::Right,
+            _ => ImageAlign::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for ImageAlign {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ImageAlign {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ImageAlign::Noop)
+
+impl From<ImageAlign> for String {
+    fn from(v: ImageAlign) -> Self {
+        match v {
+            ImageAlign::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -20367,6 +24624,7 @@ impl ImageAlign {
  * Image border style.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ImageBorderStyle {
     #[serde(rename = "dashed")]
     Dashed,
@@ -20386,15 +24644,15 @@ pub enum ImageBorderStyle {
     Ridge,
     #[serde(rename = "solid")]
     Solid,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ImageBorderStyle {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ImageBorderStyle {
+    fn as_ref(&self) -> &str {
+        match self {
             ImageBorderStyle::Dashed => "dashed",
             ImageBorderStyle::Dotted => "dotted",
             ImageBorderStyle::Double => "double",
@@ -20404,21 +24662,51 @@ impl std::fmt::Display for ImageBorderStyle {
             ImageBorderStyle::Outset => "outset",
             ImageBorderStyle::Ridge => "ridge",
             ImageBorderStyle::Solid => "solid",
-            ImageBorderStyle::Noop => "",
-            ImageBorderStyle::FallthroughString => "*",
+            ImageBorderStyle::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ImageBorderStyle {
-    fn default() -> ImageBorderStyle {
-        ImageBorderStyle::Noop
+impl std::fmt::Display for ImageBorderStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ImageBorderStyle {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "dashed" => ImageBorderStyle::Dashed,
+            "dotted" => ImageBorderStyle::Dotted,
+            "double" => ImageBorderStyle::Double,
+            "groove" => ImageBorderStyle::Groove,
+            "inset" => ImageBorderStyle::Inset,
+            "none" => ImageBorderStyle::None,
+            "outset" => ImageBorderStyle::Outset,
+            "ridge" => ImageBorderStyle::Ridge,
+            "solid" => ImageBorderStyle::Solid,
+            _ => ImageBorderStyle::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for ImageBorderStyle {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ImageBorderStyle {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ImageBorderStyle::Noop)
+
+impl From<ImageBorderStyle> for String {
+    fn from(v: ImageBorderStyle) -> Self {
+        match v {
+            ImageBorderStyle::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -20426,37 +24714,61 @@ impl ImageBorderStyle {
  * Image link target.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ImageTarget {
     #[serde(rename = "_blank")]
     Blank,
     #[serde(rename = "null")]
     Null,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ImageTarget {
+    fn as_ref(&self) -> &str {
+        match self {
+            ImageTarget::Blank => "_blank",
+            ImageTarget::Null => "null",
+            ImageTarget::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ImageTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ImageTarget {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "_blank" => ImageTarget::Blank,
+            "null" => ImageTarget::Null,
+            _ => ImageTarget::Unknown(s.to_string()),
+        })
+    }
 }
 
-impl std::fmt::Display for ImageTarget {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ImageTarget::Blank => "_blank",
-            ImageTarget::Null => "null",
-            ImageTarget::Noop => "",
-            ImageTarget::FallthroughString => "*",
+impl From<String> for ImageTarget {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
         }
-        .fmt(f)
     }
 }
 
-impl Default for ImageTarget {
-    fn default() -> ImageTarget {
-        ImageTarget::Noop
-    }
-}
-impl ImageTarget {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ImageTarget::Noop)
+impl From<ImageTarget> for String {
+    fn from(v: ImageTarget) -> Self {
+        match v {
+            ImageTarget::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -20556,6 +24868,7 @@ pub struct Header {
  * The content section name.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Section {
     #[serde(rename = "signup_message")]
     SignupMessage,
@@ -20563,33 +24876,57 @@ pub enum Section {
     SignupThankYouTitle,
     #[serde(rename = "unsub_message")]
     UnsubMessage,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Section {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Section {
+    fn as_ref(&self) -> &str {
+        match self {
             Section::SignupMessage => "signup_message",
             Section::SignupThankYouTitle => "signup_thank_you_title",
             Section::UnsubMessage => "unsub_message",
-            Section::Noop => "",
-            Section::FallthroughString => "*",
+            Section::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Section {
-    fn default() -> Section {
-        Section::Noop
+impl std::fmt::Display for Section {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Section {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "signup_message" => Section::SignupMessage,
+            "signup_thank_you_title" => Section::SignupThankYouTitle,
+            "unsub_message" => Section::UnsubMessage,
+            _ => Section::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Section {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Section {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Section::Noop)
+
+impl From<Section> for String {
+    fn from(v: Section) -> Self {
+        match v {
+            Section::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -20616,6 +24953,7 @@ pub struct Contents {
  * A string that identifies the element selector.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Selector {
     #[serde(rename = "body_background")]
     BodyBackground,
@@ -20645,15 +24983,15 @@ pub enum Selector {
     PageHeader,
     #[serde(rename = "page_outer_wrapper")]
     PageOuterWrapper,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Selector {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Selector {
+    fn as_ref(&self) -> &str {
+        match self {
             Selector::BodyBackground => "body_background",
             Selector::BodyLinkStyle => "body_link_style",
             Selector::FormsButtons => "forms_buttons",
@@ -20668,21 +25006,56 @@ impl std::fmt::Display for Selector {
             Selector::PageBackground => "page_background",
             Selector::PageHeader => "page_header",
             Selector::PageOuterWrapper => "page_outer_wrapper",
-            Selector::Noop => "",
-            Selector::FallthroughString => "*",
+            Selector::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Selector {
-    fn default() -> Selector {
-        Selector::Noop
+impl std::fmt::Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Selector {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "body_background" => Selector::BodyBackground,
+            "body_link_style" => Selector::BodyLinkStyle,
+            "forms_buttons" => Selector::FormsButtons,
+            "forms_buttons_hovered" => Selector::FormsButtonsHovered,
+            "forms_errors" => Selector::FormsErrors,
+            "forms_field_label" => Selector::FormsFieldLabel,
+            "forms_field_text" => Selector::FormsFieldText,
+            "forms_help_text" => Selector::FormsHelpText,
+            "forms_required" => Selector::FormsRequired,
+            "forms_required_legend" => Selector::FormsRequiredLegend,
+            "monkey_rewards_badge" => Selector::MonkeyRewardsBadge,
+            "page_background" => Selector::PageBackground,
+            "page_header" => Selector::PageHeader,
+            "page_outer_wrapper" => Selector::PageOuterWrapper,
+            _ => Selector::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Selector {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Selector {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Selector::Noop)
+
+impl From<Selector> for String {
+    fn from(v: Selector) -> Self {
+        match v {
+            Selector::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -20928,37 +25301,61 @@ pub struct ListLocations {
  * Returns files sorted by the specified field.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetAllLandingPagesSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
     #[serde(rename = "updated_at")]
     UpdatedAt,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetAllLandingPagesSortField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetAllLandingPagesSortField {
+    fn as_ref(&self) -> &str {
+        match self {
             GetAllLandingPagesSortField::CreatedAt => "created_at",
             GetAllLandingPagesSortField::UpdatedAt => "updated_at",
-            GetAllLandingPagesSortField::Noop => "",
-            GetAllLandingPagesSortField::FallthroughString => "*",
+            GetAllLandingPagesSortField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetAllLandingPagesSortField {
-    fn default() -> GetAllLandingPagesSortField {
-        GetAllLandingPagesSortField::Noop
+impl std::fmt::Display for GetAllLandingPagesSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetAllLandingPagesSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "created_at" => GetAllLandingPagesSortField::CreatedAt,
+            "updated_at" => GetAllLandingPagesSortField::UpdatedAt,
+            _ => GetAllLandingPagesSortField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GetAllLandingPagesSortField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetAllLandingPagesSortField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetAllLandingPagesSortField::Noop)
+
+impl From<GetAllLandingPagesSortField> for String {
+    fn from(v: GetAllLandingPagesSortField) -> Self {
+        match v {
+            GetAllLandingPagesSortField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -20966,6 +25363,7 @@ impl GetAllLandingPagesSortField {
  * The status of this landing page.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LandingPageStatus {
     #[serde(rename = "draft")]
     Draft,
@@ -20973,33 +25371,57 @@ pub enum LandingPageStatus {
     Published,
     #[serde(rename = "unpublished")]
     Unpublished,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LandingPageStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LandingPageStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             LandingPageStatus::Draft => "draft",
             LandingPageStatus::Published => "published",
             LandingPageStatus::Unpublished => "unpublished",
-            LandingPageStatus::Noop => "",
-            LandingPageStatus::FallthroughString => "*",
+            LandingPageStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LandingPageStatus {
-    fn default() -> LandingPageStatus {
-        LandingPageStatus::Noop
+impl std::fmt::Display for LandingPageStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LandingPageStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "draft" => LandingPageStatus::Draft,
+            "published" => LandingPageStatus::Published,
+            "unpublished" => LandingPageStatus::Unpublished,
+            _ => LandingPageStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for LandingPageStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LandingPageStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LandingPageStatus::Noop)
+
+impl From<LandingPageStatus> for String {
+    fn from(v: LandingPageStatus) -> Self {
+        match v {
+            LandingPageStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -21212,37 +25634,61 @@ pub struct GetAllLandingPagesResponse {
  * The type of template the landing page has.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LandingPageTemplateType {
     #[serde(rename = "product")]
     Product,
     #[serde(rename = "signup")]
     Signup,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LandingPageTemplateType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LandingPageTemplateType {
+    fn as_ref(&self) -> &str {
+        match self {
             LandingPageTemplateType::Product => "product",
             LandingPageTemplateType::Signup => "signup",
-            LandingPageTemplateType::Noop => "",
-            LandingPageTemplateType::FallthroughString => "*",
+            LandingPageTemplateType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LandingPageTemplateType {
-    fn default() -> LandingPageTemplateType {
-        LandingPageTemplateType::Noop
+impl std::fmt::Display for LandingPageTemplateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LandingPageTemplateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "product" => LandingPageTemplateType::Product,
+            "signup" => LandingPageTemplateType::Signup,
+            _ => LandingPageTemplateType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for LandingPageTemplateType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LandingPageTemplateType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LandingPageTemplateType::Noop)
+
+impl From<LandingPageTemplateType> for String {
+    fn from(v: LandingPageTemplateType) -> Self {
+        match v {
+            LandingPageTemplateType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -22432,6 +26878,7 @@ pub struct AbuseComplaintsData {
  * The sentiment type for a feedback message.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AdviceType {
     #[serde(rename = "negative")]
     Negative,
@@ -22439,33 +26886,57 @@ pub enum AdviceType {
     Neutral,
     #[serde(rename = "positive")]
     Positive,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AdviceType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AdviceType {
+    fn as_ref(&self) -> &str {
+        match self {
             AdviceType::Negative => "negative",
             AdviceType::Neutral => "neutral",
             AdviceType::Positive => "positive",
-            AdviceType::Noop => "",
-            AdviceType::FallthroughString => "*",
+            AdviceType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AdviceType {
-    fn default() -> AdviceType {
-        AdviceType::Noop
+impl std::fmt::Display for AdviceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AdviceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "negative" => AdviceType::Negative,
+            "neutral" => AdviceType::Neutral,
+            "positive" => AdviceType::Positive,
+            _ => AdviceType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for AdviceType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AdviceType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AdviceType::Noop)
+
+impl From<AdviceType> for String {
+    fn from(v: AdviceType) -> Self {
+        match v {
+            AdviceType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -24085,6 +28556,7 @@ pub struct UnsubscribesData {
  * Returns files sorted by the specified field.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetReportsEcommerceProductActivitySortField {
     #[serde(rename = "title")]
     Title,
@@ -24092,33 +28564,57 @@ pub enum GetReportsEcommerceProductActivitySortField {
     TotalPurchased,
     #[serde(rename = "total_revenue")]
     TotalRevenue,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetReportsEcommerceProductActivitySortField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetReportsEcommerceProductActivitySortField {
+    fn as_ref(&self) -> &str {
+        match self {
             GetReportsEcommerceProductActivitySortField::Title => "title",
             GetReportsEcommerceProductActivitySortField::TotalPurchased => "total_purchased",
             GetReportsEcommerceProductActivitySortField::TotalRevenue => "total_revenue",
-            GetReportsEcommerceProductActivitySortField::Noop => "",
-            GetReportsEcommerceProductActivitySortField::FallthroughString => "*",
+            GetReportsEcommerceProductActivitySortField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetReportsEcommerceProductActivitySortField {
-    fn default() -> GetReportsEcommerceProductActivitySortField {
-        GetReportsEcommerceProductActivitySortField::Noop
+impl std::fmt::Display for GetReportsEcommerceProductActivitySortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetReportsEcommerceProductActivitySortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "title" => GetReportsEcommerceProductActivitySortField::Title,
+            "total_purchased" => GetReportsEcommerceProductActivitySortField::TotalPurchased,
+            "total_revenue" => GetReportsEcommerceProductActivitySortField::TotalRevenue,
+            _ => GetReportsEcommerceProductActivitySortField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GetReportsEcommerceProductActivitySortField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetReportsEcommerceProductActivitySortField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetReportsEcommerceProductActivitySortField::Noop)
+
+impl From<GetReportsEcommerceProductActivitySortField> for String {
+    fn from(v: GetReportsEcommerceProductActivitySortField) -> Self {
+        match v {
+            GetReportsEcommerceProductActivitySortField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -24235,6 +28731,7 @@ pub struct GetReportsEcommerceProductActivityResponse {
  * Returns user templates sorted by the specified field.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetTemplatesSortField {
     #[serde(rename = "date_created")]
     DateCreated,
@@ -24242,33 +28739,57 @@ pub enum GetTemplatesSortField {
     DateEdited,
     #[serde(rename = "name")]
     Name,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetTemplatesSortField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetTemplatesSortField {
+    fn as_ref(&self) -> &str {
+        match self {
             GetTemplatesSortField::DateCreated => "date_created",
             GetTemplatesSortField::DateEdited => "date_edited",
             GetTemplatesSortField::Name => "name",
-            GetTemplatesSortField::Noop => "",
-            GetTemplatesSortField::FallthroughString => "*",
+            GetTemplatesSortField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetTemplatesSortField {
-    fn default() -> GetTemplatesSortField {
-        GetTemplatesSortField::Noop
+impl std::fmt::Display for GetTemplatesSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetTemplatesSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "date_created" => GetTemplatesSortField::DateCreated,
+            "date_edited" => GetTemplatesSortField::DateEdited,
+            "name" => GetTemplatesSortField::Name,
+            _ => GetTemplatesSortField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GetTemplatesSortField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetTemplatesSortField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetTemplatesSortField::Noop)
+
+impl From<GetTemplatesSortField> for String {
+    fn from(v: GetTemplatesSortField) -> Self {
+        match v {
+            GetTemplatesSortField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -24656,34 +29177,57 @@ pub struct Customer {
  * The Mailchimp tracking code for the order. Uses the 'mc_tc' parameter in E-Commerce tracking URLs.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TrackingCode {
     #[serde(rename = "prec")]
     Prec,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for TrackingCode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for TrackingCode {
+    fn as_ref(&self) -> &str {
+        match self {
             TrackingCode::Prec => "prec",
-            TrackingCode::Noop => "",
-            TrackingCode::FallthroughString => "*",
+            TrackingCode::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for TrackingCode {
-    fn default() -> TrackingCode {
-        TrackingCode::Noop
+impl std::fmt::Display for TrackingCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for TrackingCode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "prec" => TrackingCode::Prec,
+            _ => TrackingCode::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for TrackingCode {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl TrackingCode {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, TrackingCode::Noop)
+
+impl From<TrackingCode> for String {
+    fn from(v: TrackingCode) -> Self {
+        match v {
+            TrackingCode::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -24939,37 +29483,61 @@ pub struct BillingAddress {
  * Type of discount. For free shipping set type to fixed
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OrdersPromosType {
     #[serde(rename = "fixed")]
     Fixed,
     #[serde(rename = "percentage")]
     Percentage,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for OrdersPromosType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for OrdersPromosType {
+    fn as_ref(&self) -> &str {
+        match self {
             OrdersPromosType::Fixed => "fixed",
             OrdersPromosType::Percentage => "percentage",
-            OrdersPromosType::Noop => "",
-            OrdersPromosType::FallthroughString => "*",
+            OrdersPromosType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for OrdersPromosType {
-    fn default() -> OrdersPromosType {
-        OrdersPromosType::Noop
+impl std::fmt::Display for OrdersPromosType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OrdersPromosType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "fixed" => OrdersPromosType::Fixed,
+            "percentage" => OrdersPromosType::Percentage,
+            _ => OrdersPromosType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for OrdersPromosType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl OrdersPromosType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OrdersPromosType::Noop)
+
+impl From<OrdersPromosType> for String {
+    fn from(v: OrdersPromosType) -> Self {
+        match v {
+            OrdersPromosType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -26645,6 +31213,7 @@ pub struct ECommerceCustomerDataType {
  * The target that the discount applies to.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Target {
     #[serde(rename = "per_item")]
     PerItem,
@@ -26652,33 +31221,57 @@ pub enum Target {
     Shipping,
     #[serde(rename = "total")]
     Total,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Target {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Target {
+    fn as_ref(&self) -> &str {
+        match self {
             Target::PerItem => "per_item",
             Target::Shipping => "shipping",
             Target::Total => "total",
-            Target::Noop => "",
-            Target::FallthroughString => "*",
+            Target::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Target {
-    fn default() -> Target {
-        Target::Noop
+impl std::fmt::Display for Target {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Target {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "per_item" => Target::PerItem,
+            "shipping" => Target::Shipping,
+            "total" => Target::Total,
+            _ => Target::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for Target {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Target {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Target::Noop)
+
+impl From<Target> for String {
+    fn from(v: Target) -> Self {
+        match v {
+            Target::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -26897,8 +31490,8 @@ pub struct ECommercePromoRule {
     /**
      * The target that the discount applies to.
      */
-    #[serde(default, skip_serializing_if = "Target::is_noop")]
-    pub target: Target,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<Target>,
     /**
      * Information about an Ecommerce Store's specific Promo Rule.
      */
@@ -26911,12 +31504,8 @@ pub struct ECommercePromoRule {
     /**
      * Type of discount. For free shipping set type to fixed
      */
-    #[serde(
-        default,
-        skip_serializing_if = "OrdersPromosType::is_noop",
-        rename = "type"
-    )]
-    pub type_: OrdersPromosType,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<OrdersPromosType>,
     /**
      * Information about an Ecommerce Store's specific Promo Rule.
      */
@@ -27323,12 +31912,8 @@ pub struct ECommerceOrderPromos {
     /**
      * Type of discount. For free shipping set type to fixed
      */
-    #[serde(
-        default,
-        skip_serializing_if = "OrdersPromosType::is_noop",
-        rename = "type"
-    )]
-    pub type_: OrdersPromosType,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<OrdersPromosType>,
 }
 
 /// Information about a specific order line.
@@ -28750,6 +33335,7 @@ pub struct ApiHealthStatus {
  * Returns files sorted by the specified field.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetAllFacebookAdsSortField {
     #[serde(rename = "created_at")]
     CreatedAt,
@@ -28757,33 +33343,57 @@ pub enum GetAllFacebookAdsSortField {
     EndTime,
     #[serde(rename = "updated_at")]
     UpdatedAt,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetAllFacebookAdsSortField {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetAllFacebookAdsSortField {
+    fn as_ref(&self) -> &str {
+        match self {
             GetAllFacebookAdsSortField::CreatedAt => "created_at",
             GetAllFacebookAdsSortField::EndTime => "end_time",
             GetAllFacebookAdsSortField::UpdatedAt => "updated_at",
-            GetAllFacebookAdsSortField::Noop => "",
-            GetAllFacebookAdsSortField::FallthroughString => "*",
+            GetAllFacebookAdsSortField::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetAllFacebookAdsSortField {
-    fn default() -> GetAllFacebookAdsSortField {
-        GetAllFacebookAdsSortField::Noop
+impl std::fmt::Display for GetAllFacebookAdsSortField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetAllFacebookAdsSortField {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "created_at" => GetAllFacebookAdsSortField::CreatedAt,
+            "end_time" => GetAllFacebookAdsSortField::EndTime,
+            "updated_at" => GetAllFacebookAdsSortField::UpdatedAt,
+            _ => GetAllFacebookAdsSortField::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for GetAllFacebookAdsSortField {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetAllFacebookAdsSortField {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetAllFacebookAdsSortField::Noop)
+
+impl From<GetAllFacebookAdsSortField> for String {
+    fn from(v: GetAllFacebookAdsSortField) -> Self {
+        match v {
+            GetAllFacebookAdsSortField::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -28791,6 +33401,7 @@ impl GetAllFacebookAdsSortField {
  * Supported Campaign, Ad, Page type
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OutreachType {
     #[serde(rename = "absplit")]
     Absplit,
@@ -28820,15 +33431,15 @@ pub enum OutreachType {
     Variate,
     #[serde(rename = "website")]
     Website,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for OutreachType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for OutreachType {
+    fn as_ref(&self) -> &str {
+        match self {
             OutreachType::Absplit => "absplit",
             OutreachType::Automation => "automation",
             OutreachType::Autoresponder => "autoresponder",
@@ -28843,21 +33454,56 @@ impl std::fmt::Display for OutreachType {
             OutreachType::Transactional => "transactional",
             OutreachType::Variate => "variate",
             OutreachType::Website => "website",
-            OutreachType::Noop => "",
-            OutreachType::FallthroughString => "*",
+            OutreachType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for OutreachType {
-    fn default() -> OutreachType {
-        OutreachType::Noop
+impl std::fmt::Display for OutreachType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OutreachType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "absplit" => OutreachType::Absplit,
+            "automation" => OutreachType::Automation,
+            "autoresponder" => OutreachType::Autoresponder,
+            "facebook" => OutreachType::Facebook,
+            "google" => OutreachType::Google,
+            "page" => OutreachType::Page,
+            "plaintext" => OutreachType::Plaintext,
+            "reconfirm" => OutreachType::Reconfirm,
+            "regular" => OutreachType::Regular,
+            "rss" => OutreachType::Rss,
+            "survey" => OutreachType::Survey,
+            "transactional" => OutreachType::Transactional,
+            "variate" => OutreachType::Variate,
+            "website" => OutreachType::Website,
+            _ => OutreachType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for OutreachType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl OutreachType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OutreachType::Noop)
+
+impl From<OutreachType> for String {
+    fn from(v: OutreachType) -> Self {
+        match v {
+            OutreachType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -28865,6 +33511,7 @@ impl OutreachType {
  * Campaign, Ad, or Page status
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OutreachStatus {
     #[serde(rename = "active")]
     Active,
@@ -28900,15 +33547,15 @@ pub enum OutreachStatus {
     Somepaused,
     #[serde(rename = "unpublished")]
     Unpublished,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for OutreachStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for OutreachStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             OutreachStatus::Active => "active",
             OutreachStatus::Canceled => "canceled",
             OutreachStatus::Canceling => "canceling",
@@ -28926,21 +33573,59 @@ impl std::fmt::Display for OutreachStatus {
             OutreachStatus::Sent => "sent",
             OutreachStatus::Somepaused => "somepaused",
             OutreachStatus::Unpublished => "unpublished",
-            OutreachStatus::Noop => "",
-            OutreachStatus::FallthroughString => "*",
+            OutreachStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for OutreachStatus {
-    fn default() -> OutreachStatus {
-        OutreachStatus::Noop
+impl std::fmt::Display for OutreachStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OutreachStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => OutreachStatus::Active,
+            "canceled" => OutreachStatus::Canceled,
+            "canceling" => OutreachStatus::Canceling,
+            "completed" => OutreachStatus::Completed,
+            "disconnected" => OutreachStatus::Disconnected,
+            "draft" => OutreachStatus::Draft,
+            "partialRejected" => OutreachStatus::PartialRejected,
+            "paused" => OutreachStatus::Paused,
+            "pending" => OutreachStatus::Pending,
+            "published" => OutreachStatus::Published,
+            "rejected" => OutreachStatus::Rejected,
+            "save" => OutreachStatus::Save,
+            "schedule" => OutreachStatus::Schedule,
+            "sending" => OutreachStatus::Sending,
+            "sent" => OutreachStatus::Sent,
+            "somepaused" => OutreachStatus::Somepaused,
+            "unpublished" => OutreachStatus::Unpublished,
+            _ => OutreachStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for OutreachStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl OutreachStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OutreachStatus::Noop)
+
+impl From<OutreachStatus> for String {
+    fn from(v: OutreachStatus) -> Self {
+        match v {
+            OutreachStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -29432,6 +34117,7 @@ pub struct Site {
  * Type of the audience
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FacebookAdsAudienceType {
     #[serde(rename = "Custom Audience")]
     CustomAudience,
@@ -29439,33 +34125,57 @@ pub enum FacebookAdsAudienceType {
     InterestBasedAudience,
     #[serde(rename = "Lookalike Audience")]
     LookalikeAudience,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for FacebookAdsAudienceType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for FacebookAdsAudienceType {
+    fn as_ref(&self) -> &str {
+        match self {
             FacebookAdsAudienceType::CustomAudience => "Custom Audience",
             FacebookAdsAudienceType::InterestBasedAudience => "Interest-based Audience",
             FacebookAdsAudienceType::LookalikeAudience => "Lookalike Audience",
-            FacebookAdsAudienceType::Noop => "",
-            FacebookAdsAudienceType::FallthroughString => "*",
+            FacebookAdsAudienceType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for FacebookAdsAudienceType {
-    fn default() -> FacebookAdsAudienceType {
-        FacebookAdsAudienceType::Noop
+impl std::fmt::Display for FacebookAdsAudienceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for FacebookAdsAudienceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "Custom Audience" => FacebookAdsAudienceType::CustomAudience,
+            "Interest-based Audience" => FacebookAdsAudienceType::InterestBasedAudience,
+            "Lookalike Audience" => FacebookAdsAudienceType::LookalikeAudience,
+            _ => FacebookAdsAudienceType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for FacebookAdsAudienceType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl FacebookAdsAudienceType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FacebookAdsAudienceType::Noop)
+
+impl From<FacebookAdsAudienceType> for String {
+    fn from(v: FacebookAdsAudienceType) -> Self {
+        match v {
+            FacebookAdsAudienceType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -29473,37 +34183,61 @@ impl FacebookAdsAudienceType {
  * List or Facebook based audience
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SourceType {
     #[serde(rename = "facebook")]
     Facebook,
     #[serde(rename = "list")]
     List,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SourceType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SourceType {
+    fn as_ref(&self) -> &str {
+        match self {
             SourceType::Facebook => "facebook",
             SourceType::List => "list",
-            SourceType::Noop => "",
-            SourceType::FallthroughString => "*",
+            SourceType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SourceType {
-    fn default() -> SourceType {
-        SourceType::Noop
+impl std::fmt::Display for SourceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SourceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "facebook" => SourceType::Facebook,
+            "list" => SourceType::List,
+            _ => SourceType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl From<String> for SourceType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SourceType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SourceType::Noop)
+
+impl From<SourceType> for String {
+    fn from(v: SourceType) -> Self {
+        match v {
+            SourceType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
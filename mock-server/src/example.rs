@@ -0,0 +1,222 @@
+use serde_json::{json, Map, Value};
+
+use crate::resolve;
+
+/*
+ * How deep made up values go, so schemas that refer to themselves end.
+ */
+const MAX_DEPTH: usize = 8;
+
+/*
+ * The properties that point to the next page of a list.
+ */
+const NEXT_PAGE: &[&str] = &[
+    "cursor",
+    "next",
+    "next_cursor",
+    "next_page",
+    "next_page_token",
+    "next_page_url",
+    "nextCursor",
+    "nextLink",
+    "nextPageToken",
+];
+
+/*
+ * The body of a response: the example of the spec if it has one, otherwise one made
+ * up from the schema.
+ */
+pub(crate) fn media(doc: &Value, media: &Value) -> Value {
+    let mut body = if let Some(example) = media.get("example") {
+        example.clone()
+    } else if let Some(example) = media
+        .get("examples")
+        .and_then(|e| e.as_object())
+        .and_then(|e| e.values().next())
+    {
+        resolve(doc, example)
+            .get("value")
+            .cloned()
+            .unwrap_or(Value::Null)
+    } else if let Some(schema) = media.get("schema") {
+        from_schema(doc, schema, 0)
+    } else {
+        Value::Null
+    };
+
+    last_page(&mut body);
+    body
+}
+
+/*
+ * A value that matches a schema.
+ */
+pub(crate) fn from_schema(doc: &Value, schema: &Value, depth: usize) -> Value {
+    let schema = resolve(doc, schema);
+
+    if let Some(v) = schema.get("example").or_else(|| schema.get("default")) {
+        return v.clone();
+    }
+    if let Some(v) = schema
+        .get("enum")
+        .and_then(|e| e.as_array())
+        .and_then(|e| e.first())
+    {
+        return v.clone();
+    }
+
+    if let Some(all) = schema.get("allOf").and_then(|a| a.as_array()) {
+        let mut merged = Map::new();
+        for s in all {
+            match from_schema(doc, s, depth) {
+                Value::Object(o) => merged.extend(o),
+                v if all.len() == 1 => return v,
+                _ => {}
+            }
+        }
+        return Value::Object(merged);
+    }
+    if let Some(first) = schema
+        .get("oneOf")
+        .or_else(|| schema.get("anyOf"))
+        .and_then(|a| a.as_array())
+        .and_then(|a| a.first())
+    {
+        return from_schema(doc, first, depth);
+    }
+
+    let ty = match schema.get("type") {
+        Some(Value::String(t)) => t.as_str(),
+        // OpenAPI 3.1 lists the types, with `null` for nullable ones.
+        Some(Value::Array(t)) => t
+            .iter()
+            .filter_map(|t| t.as_str())
+            .find(|t| *t != "null")
+            .unwrap_or("null"),
+        _ if schema.get("properties").is_some() => "object",
+        _ if schema.get("items").is_some() => "array",
+        _ => "",
+    };
+
+    match ty {
+        "object" => {
+            let mut o = Map::new();
+            if depth < MAX_DEPTH {
+                if let Some(props) = schema.get("properties").and_then(|p| p.as_object()) {
+                    for (name, s) in props {
+                        o.insert(name.to_string(), from_schema(doc, s, depth + 1));
+                    }
+                }
+            }
+            Value::Object(o)
+        }
+        "array" => match schema.get("items") {
+            Some(items) if depth < MAX_DEPTH => json!([from_schema(doc, items, depth + 1)]),
+            _ => json!([]),
+        },
+        "string" => json!(match schema.get("format").and_then(|f| f.as_str()) {
+            Some("date-time") => "1970-01-01T00:00:00Z",
+            Some("date") => "1970-01-01",
+            Some("time") => "00:00:00",
+            Some("uuid") => "00000000-0000-0000-0000-000000000000",
+            Some("email") => "user@example.com",
+            Some("uri") | Some("url") => "https://example.com",
+            Some("ipv4") | Some("ip") => "127.0.0.1",
+            Some("ipv6") => "::1",
+            Some("decimal") | Some("float") | Some("double") | Some("number") => "0.00",
+            Some("int32") | Some("int64") | Some("integer") => "0",
+            _ => "string",
+        }),
+        "integer" => json!(0),
+        "number" => json!(0.0),
+        "boolean" => json!(false),
+        _ => Value::Null,
+    }
+}
+
+/*
+ * Make a page of a list the last one, so the clients that fetch all the pages stop.
+ */
+fn last_page(v: &mut Value) {
+    match v {
+        Value::Object(o) => {
+            for (k, v) in o.iter_mut() {
+                if NEXT_PAGE.contains(&k.as_str()) && (v.is_string() || v.is_number()) {
+                    *v = Value::Null;
+                } else if k == "has_more" || k == "hasMore" {
+                    if v.is_boolean() {
+                        *v = Value::Bool(false);
+                    }
+                } else {
+                    last_page(v);
+                }
+            }
+        }
+        Value::Array(a) => a.iter_mut().for_each(last_page),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    #[test]
+    fn test_from_schema() {
+        let doc = json!({
+            "components": {
+                "schemas": {
+                    // A schema that refers to itself.
+                    "Folder": {
+                        "type": "object",
+                        "properties": {
+                            "name": {"type": "string"},
+                            "parent": {"$ref": "#/components/schemas/Folder"}
+                        }
+                    },
+                    "Amount": {
+                        "allOf": [
+                            {"properties": {"value": {"type": "string", "format": "decimal"}}},
+                            {"properties": {"currency": {"type": "string", "default": "USD"}}}
+                        ]
+                    }
+                }
+            }
+        });
+
+        let folder = super::from_schema(&doc, &json!({"$ref": "#/components/schemas/Folder"}), 0);
+        assert_eq!(folder["name"], json!("string"));
+        assert_eq!(
+            folder.pointer("/parent/parent/name"),
+            Some(&json!("string"))
+        );
+
+        assert_eq!(
+            super::from_schema(&doc, &json!({"$ref": "#/components/schemas/Amount"}), 0),
+            json!({"value": "0.00", "currency": "USD"})
+        );
+        assert_eq!(
+            super::from_schema(&doc, &json!({"type": ["null", "integer"]}), 0),
+            json!(0)
+        );
+    }
+
+    #[test]
+    fn test_media_last_page() {
+        let media = json!({
+            "example": {
+                "has_more": true,
+                "data": [{"id": 1}],
+                "meta": {"nextPageToken": "abc"}
+            }
+        });
+        assert_eq!(
+            super::media(&json!({}), &media),
+            json!({
+                "has_more": false,
+                "data": [{"id": 1}],
+                "meta": {"nextPageToken": null}
+            })
+        );
+    }
+}
@@ -0,0 +1,635 @@
+//! A mock server for the APIs of the generated clients.
+//!
+//! It serves every operation of an OpenAPI spec, the same one the generator read, on
+//! localhost. Requests are checked against the spec, and those that don't match it get
+//! a `400` that says what is wrong. The others get the `example` of the response in the
+//! spec, or one made up from the schema of the response.
+//!
+//! To test a client against it, start it and point the client at it with
+//! `Client::with_host`:
+//!
+//! ```no_run
+//! # async fn run() -> anyhow::Result<()> {
+//! use mock_server::{MockServer, Spec};
+//!
+//! let server = MockServer::start(Spec::load("specs/giphy/giphy.yaml")?).await?;
+//! let host = server.url();
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Operations answer with their first `2XX` response. The `x-mock-status` header of
+//! a request picks another one, like `x-mock-status: 404`, to test how errors are
+//! handled.
+//!
+//! The pages of lists are made up too, so the cursors of the next page, like
+//! `next_page_token`, are always `null` and `has_more` is always `false`. Otherwise
+//! the clients that fetch all the pages would never stop.
+
+use std::{
+    collections::BTreeMap, convert::Infallible, ffi::OsStr, fs::File, net::SocketAddr, path::Path,
+    sync::Arc,
+};
+
+use anyhow::{bail, Result};
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, HeaderMap, Request, Response,
+};
+use serde_json::{json, Value};
+
+mod example;
+mod validate;
+
+/// The header of a request that picks the status of the response.
+pub const STATUS_HEADER: &str = "x-mock-status";
+
+const METHODS: &[&str] = &[
+    "get", "put", "post", "delete", "options", "head", "patch", "trace",
+];
+
+/// An OpenAPI spec to serve.
+pub struct Spec {
+    doc: Value,
+    base_path: String,
+    routes: Vec<Route>,
+}
+
+/*
+ * A path of the spec, split into segments, like `/users/{userId}` into `users` and
+ * `{userId}`.
+ */
+struct Route {
+    path: String,
+    segments: Vec<String>,
+}
+
+impl Spec {
+    /// Load a spec from a JSON or YAML file.
+    pub fn load<P>(p: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let p = p.as_ref();
+        let f = File::open(p)?;
+        if let Some(ext) = p.extension() {
+            if ext == OsStr::new("yaml") || ext == OsStr::new("yml") {
+                return Self::from_value(serde_yaml::from_reader(f)?);
+            }
+        }
+        Self::from_value(serde_json::from_reader(f)?)
+    }
+
+    pub fn from_value(doc: Value) -> Result<Self> {
+        let paths = match doc.get("paths").and_then(|p| p.as_object()) {
+            Some(paths) => paths,
+            None => bail!("the spec has no paths"),
+        };
+
+        let routes = paths
+            .keys()
+            .map(|path| Route {
+                path: path.to_string(),
+                segments: split_path(path),
+            })
+            .collect();
+
+        // The generated clients may or may not have the path of the server in their
+        // host, like the `/v2` of `https://api.zoom.us/v2`, so we take both.
+        let base_path = doc
+            .pointer("/servers/0/url")
+            .and_then(|u| u.as_str())
+            .map(|u| {
+                let u = u.split("://").nth(1).unwrap_or(u);
+                u.find('/')
+                    .map(|i| u[i..].trim_end_matches('/').to_string())
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default();
+
+        Ok(Spec {
+            doc,
+            base_path,
+            routes,
+        })
+    }
+
+    /// The response of the mock server to a request.
+    pub fn respond(
+        &self,
+        method: &str,
+        path: &str,
+        query: Option<&str>,
+        headers: &HeaderMap,
+        body: &[u8],
+    ) -> MockResponse {
+        let path = match path.strip_prefix(&self.base_path) {
+            Some(p) if !self.base_path.is_empty() && (p.is_empty() || p.starts_with('/')) => p,
+            _ => path,
+        };
+        let segments = split_path(path);
+
+        let (route, path_params) = match self.route(&segments) {
+            Some(r) => r,
+            None => {
+                return MockResponse::error(404, &format!("no path of the spec matches {}", path))
+            }
+        };
+
+        let method = method.to_lowercase();
+        let path_item = resolve(&self.doc, &self.doc["paths"][&route.path]);
+        let op = match path_item.get(&method) {
+            Some(op) if METHODS.contains(&method.as_str()) => resolve(&self.doc, op),
+            _ => {
+                return MockResponse::error(
+                    405,
+                    &format!("{} has no {} operation", route.path, method.to_uppercase()),
+                )
+            }
+        };
+
+        // The parameters of the operation take precedence over those of the path.
+        let mut params: BTreeMap<(String, String), &Value> = BTreeMap::new();
+        for p in [path_item.get("parameters"), op.get("parameters")]
+            .iter()
+            .flatten()
+            .filter_map(|p| p.as_array())
+            .flatten()
+        {
+            let p = resolve(&self.doc, p);
+            let name = p["name"].as_str().unwrap_or_default().to_string();
+            let location = p["in"].as_str().unwrap_or_default().to_string();
+            params.insert((location, name), p);
+        }
+
+        let query: Vec<(String, String)> =
+            serde_urlencoded::from_str(query.unwrap_or_default()).unwrap_or_default();
+
+        let errors = validate::request(
+            &self.doc,
+            op,
+            &params.values().copied().collect::<Vec<_>>(),
+            &path_params,
+            &query,
+            headers,
+            body,
+        );
+        if !errors.is_empty() {
+            return MockResponse::json(
+                400,
+                &json!({
+                    "message": "the request does not match the spec",
+                    "errors": errors,
+                }),
+            );
+        }
+
+        let status = headers
+            .get(STATUS_HEADER)
+            .and_then(|s| s.to_str().ok())
+            .and_then(|s| s.parse::<u16>().ok());
+        self.response(op, status)
+    }
+
+    /*
+     * The path of the spec that matches the segments of a request, along with the
+     * values of the parameters in it. If several do, like `/users/me` and
+     * `/users/{userId}`, the one with the most fixed segments wins.
+     */
+    fn route(&self, segments: &[String]) -> Option<(&Route, BTreeMap<String, String>)> {
+        let mut best: Option<(usize, &Route, BTreeMap<String, String>)> = None;
+        for route in &self.routes {
+            if route.segments.len() != segments.len() {
+                continue;
+            }
+
+            let mut fixed = 0;
+            let mut params = BTreeMap::new();
+            let matches = route.segments.iter().zip(segments).all(|(r, s)| {
+                match (r.find('{'), r.rfind('}')) {
+                    // Parameters may only be part of a segment, like `{order_id}.json`.
+                    (Some(start), Some(end)) if start < end => {
+                        let (prefix, suffix) = (&r[..start], &r[end + 1..]);
+                        if s.len() > prefix.len() + suffix.len()
+                            && s.starts_with(prefix)
+                            && s.ends_with(suffix)
+                        {
+                            params.insert(
+                                r[start + 1..end].to_string(),
+                                s[prefix.len()..s.len() - suffix.len()].to_string(),
+                            );
+                            true
+                        } else {
+                            false
+                        }
+                    }
+                    _ => {
+                        fixed += 1;
+                        r == s
+                    }
+                }
+            });
+
+            if matches && best.as_ref().map(|(f, _, _)| fixed > *f).unwrap_or(true) {
+                best = Some((fixed, route, params));
+            }
+        }
+
+        best.map(|(_, route, params)| (route, params))
+    }
+
+    /*
+     * The response of an operation with the given status, or its first `2XX` one.
+     */
+    fn response(&self, op: &Value, status: Option<u16>) -> MockResponse {
+        let responses = match op.get("responses").and_then(|r| r.as_object()) {
+            Some(r) => r,
+            None => return MockResponse::empty(status.unwrap_or(200)),
+        };
+
+        let (code, response) = match status {
+            Some(status) => {
+                let range = format!("{}XX", status / 100);
+                match responses
+                    .get(&status.to_string())
+                    .or_else(|| responses.get(&range))
+                    .or_else(|| responses.get("default"))
+                {
+                    Some(r) => (status, r),
+                    // Any status can be asked for, even one the spec does not have.
+                    None => return MockResponse::empty(status),
+                }
+            }
+            None => {
+                let mut codes: Vec<(&String, &Value)> = responses
+                    .iter()
+                    .filter(|(c, _)| c.starts_with('2'))
+                    .collect();
+                codes.sort_by_key(|(c, _)| c.as_str());
+                match codes.first() {
+                    Some((c, r)) => (c.replace("XX", "00").parse().unwrap_or(200), *r),
+                    None => match responses.get("default") {
+                        Some(r) => (200, r),
+                        None => return MockResponse::empty(200),
+                    },
+                }
+            }
+        };
+
+        let response = resolve(&self.doc, response);
+        let content = match response.get("content").and_then(|c| c.as_object()) {
+            Some(c) if !c.is_empty() && code != 204 => c,
+            _ => return MockResponse::empty(code),
+        };
+
+        // JSON if the operation has it, otherwise whatever it has.
+        let (content_type, media) = content
+            .iter()
+            .find(|(ct, _)| ct.contains("json"))
+            .unwrap_or_else(|| content.iter().next().unwrap());
+        let body = example::media(&self.doc, media);
+
+        if content_type.contains("json") {
+            MockResponse {
+                status: code,
+                content_type: Some(content_type.to_string()),
+                body: serde_json::to_vec(&body).unwrap(),
+            }
+        } else {
+            MockResponse {
+                status: code,
+                content_type: Some(content_type.to_string()),
+                body: body.as_str().unwrap_or_default().as_bytes().to_vec(),
+            }
+        }
+    }
+}
+
+/// What the mock server answers a request with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockResponse {
+    pub status: u16,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+impl MockResponse {
+    fn empty(status: u16) -> Self {
+        MockResponse {
+            status,
+            content_type: None,
+            body: Vec::new(),
+        }
+    }
+
+    fn json(status: u16, body: &Value) -> Self {
+        MockResponse {
+            status,
+            content_type: Some("application/json".to_string()),
+            body: serde_json::to_vec(body).unwrap(),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self::json(status, &json!({ "message": message }))
+    }
+
+    /// The body, parsed as JSON.
+    pub fn json_body(&self) -> Result<Value> {
+        Ok(serde_json::from_slice(&self.body)?)
+    }
+}
+
+/// A mock server running in the background, until it is dropped.
+pub struct MockServer {
+    addr: SocketAddr,
+    shutdown: Option<tokio::sync::oneshot::Sender<()>>,
+}
+
+impl MockServer {
+    /// Serve a spec on a free port of localhost.
+    pub async fn start(spec: Spec) -> Result<Self> {
+        Self::bind(spec, ([127, 0, 0, 1], 0).into()).await
+    }
+
+    /// Serve a spec at the given address.
+    pub async fn bind(spec: Spec, addr: SocketAddr) -> Result<Self> {
+        let spec = Arc::new(spec);
+        let make_service = make_service_fn(move |_| {
+            let spec = spec.clone();
+            async move { Ok::<_, Infallible>(service_fn(move |req| handle(spec.clone(), req))) }
+        });
+
+        let server = hyper::Server::try_bind(&addr)?.serve(make_service);
+        let addr = server.local_addr();
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        tokio::spawn(server.with_graceful_shutdown(async {
+            rx.await.ok();
+        }));
+
+        Ok(MockServer {
+            addr,
+            shutdown: Some(tx),
+        })
+    }
+
+    /// The address the server listens on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// The URL of the server, to pass to `Client::with_host`.
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(tx) = self.shutdown.take() {
+            tx.send(()).ok();
+        }
+    }
+}
+
+async fn handle(spec: Arc<Spec>, req: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let (parts, body) = req.into_parts();
+    let body = hyper::body::to_bytes(body).await.unwrap_or_default();
+
+    let r = spec.respond(
+        parts.method.as_str(),
+        parts.uri.path(),
+        parts.uri.query(),
+        &parts.headers,
+        &body,
+    );
+
+    let mut response = Response::builder().status(r.status);
+    if let Some(ct) = &r.content_type {
+        response = response.header(hyper::header::CONTENT_TYPE, ct.as_str());
+    }
+    Ok(response.body(Body::from(r.body)).unwrap())
+}
+
+fn split_path(path: &str) -> Vec<String> {
+    path.split('/')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            percent_encoding::percent_decode_str(s)
+                .decode_utf8_lossy()
+                .to_string()
+        })
+        .collect()
+}
+
+/*
+ * Follow the `$ref`s of the spec, like `#/components/schemas/User`.
+ */
+pub(crate) fn resolve<'a>(doc: &'a Value, mut v: &'a Value) -> &'a Value {
+    // Give up on references that go round in circles.
+    for _ in 0..16 {
+        match v.get("$ref").and_then(|r| r.as_str()) {
+            Some(r) => match r.strip_prefix('#').and_then(|p| doc.pointer(p)) {
+                Some(target) => v = target,
+                None => return &Value::Null,
+            },
+            None => return v,
+        }
+    }
+    v
+}
+
+#[cfg(test)]
+mod tests {
+    use hyper::HeaderMap;
+    use serde_json::json;
+
+    use super::{MockServer, Spec};
+
+    fn spec() -> Spec {
+        Spec::from_value(json!({
+            "openapi": "3.0.3",
+            "servers": [{"url": "https://api.example.com/v2"}],
+            "paths": {
+                "/users/{userId}": {
+                    "parameters": [
+                        {"name": "userId", "in": "path", "required": true, "schema": {"type": "integer"}}
+                    ],
+                    "get": {
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {"schema": {"$ref": "#/components/schemas/User"}}
+                                }
+                            },
+                            "404": {
+                                "content": {
+                                    "application/json": {"example": {"message": "not found"}}
+                                }
+                            }
+                        }
+                    },
+                    "patch": {
+                        "requestBody": {
+                            "required": true,
+                            "content": {
+                                "application/json": {"schema": {"$ref": "#/components/schemas/User"}}
+                            }
+                        },
+                        "responses": {"204": {"description": "updated"}}
+                    }
+                },
+                "/users/me": {
+                    "get": {
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {"example": {"id": 1, "name": "me"}}
+                                }
+                            }
+                        }
+                    }
+                },
+                "/users": {
+                    "get": {
+                        "parameters": [
+                            {"name": "page_size", "in": "query", "required": true, "schema": {"type": "integer"}}
+                        ],
+                        "responses": {
+                            "200": {
+                                "content": {
+                                    "application/json": {
+                                        "schema": {
+                                            "type": "object",
+                                            "properties": {
+                                                "next_page_token": {"type": "string", "example": "abc"},
+                                                "users": {"type": "array", "items": {"$ref": "#/components/schemas/User"}}
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "components": {
+                "schemas": {
+                    "User": {
+                        "type": "object",
+                        "required": ["name"],
+                        "properties": {
+                            "id": {"type": "integer"},
+                            "name": {"type": "string"},
+                            "created_at": {"type": "string", "format": "date-time"},
+                            "role": {"type": "string", "enum": ["admin", "member"]}
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_respond() {
+        let spec = spec();
+        let headers = HeaderMap::new();
+
+        // The response is made up from the schema, with or without the base path.
+        for path in &["/users/7", "/v2/users/7"] {
+            let r = spec.respond("GET", path, None, &headers, &[]);
+            assert_eq!(r.status, 200);
+            assert_eq!(r.content_type.as_deref(), Some("application/json"));
+            assert_eq!(
+                r.json_body().unwrap(),
+                json!({
+                    "id": 0,
+                    "name": "string",
+                    "created_at": "1970-01-01T00:00:00Z",
+                    "role": "admin",
+                })
+            );
+        }
+
+        // Fixed segments win over parameters.
+        let r = spec.respond("GET", "/users/me", None, &headers, &[]);
+        assert_eq!(r.json_body().unwrap(), json!({"id": 1, "name": "me"}));
+
+        // Other responses can be asked for.
+        let mut not_found = HeaderMap::new();
+        not_found.insert(super::STATUS_HEADER, "404".parse().unwrap());
+        let r = spec.respond("GET", "/users/7", None, &not_found, &[]);
+        assert_eq!(r.status, 404);
+        assert_eq!(r.json_body().unwrap(), json!({"message": "not found"}));
+
+        // There is no next page.
+        let r = spec.respond("GET", "/users", Some("page_size=10"), &headers, &[]);
+        assert_eq!(r.json_body().unwrap()["next_page_token"], json!(null));
+
+        let r = spec.respond("PATCH", "/users/7", None, &headers, br#"{"name": "x"}"#);
+        assert_eq!(r.status, 204);
+        assert!(r.body.is_empty());
+
+        assert_eq!(
+            spec.respond("GET", "/groups", None, &headers, &[]).status,
+            404
+        );
+        assert_eq!(
+            spec.respond("DELETE", "/users/7", None, &headers, &[])
+                .status,
+            405
+        );
+    }
+
+    #[test]
+    fn test_respond_invalid_requests() {
+        let spec = spec();
+        let headers = HeaderMap::new();
+
+        let errors = |r: super::MockResponse| {
+            assert_eq!(r.status, 400);
+            r.json_body().unwrap()["errors"].clone()
+        };
+
+        assert_eq!(
+            errors(spec.respond("GET", "/users/seven", None, &headers, &[])),
+            json!(["path parameter `userId`: `seven` is not an integer"])
+        );
+        assert_eq!(
+            errors(spec.respond("GET", "/users", None, &headers, &[])),
+            json!(["query parameter `page_size` is missing"])
+        );
+        assert_eq!(
+            errors(spec.respond("PATCH", "/users/7", None, &headers, &[])),
+            json!(["the request body is missing"])
+        );
+        assert_eq!(
+            errors(spec.respond(
+                "PATCH",
+                "/users/7",
+                None,
+                &headers,
+                br#"{"id": "7", "role": "owner"}"#
+            )),
+            json!([
+                "body.id: expected an integer, got \"7\"",
+                "body.name is missing",
+                "body.role: \"owner\" is not one of [\"admin\",\"member\"]",
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mock_server() {
+        let server = MockServer::start(spec()).await.unwrap();
+
+        let client = hyper::Client::new();
+        let uri = format!("{}/users/me", server.url()).parse().unwrap();
+        let response = client.get(uri).await.unwrap();
+        assert_eq!(response.status(), 200);
+
+        let body = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        assert_eq!(&body[..], br#"{"id":1,"name":"me"}"#);
+    }
+}
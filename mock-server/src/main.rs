@@ -0,0 +1,59 @@
+use std::{env, net::IpAddr};
+
+use anyhow::{bail, Result};
+use mock_server::{MockServer, Spec};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let mut opts = getopts::Options::new();
+    opts.parsing_style(getopts::ParsingStyle::StopAtFirstFree);
+    opts.reqopt(
+        "i",
+        "",
+        "OpenAPI definition document (JSON or YAML)",
+        "INPUT",
+    );
+    opts.optopt(
+        "",
+        "host",
+        "address to listen on (default 127.0.0.1)",
+        "HOST",
+    );
+    opts.optopt(
+        "p",
+        "port",
+        "port to listen on (default: any free one)",
+        "PORT",
+    );
+
+    let args = match opts.parse(env::args().skip(1)) {
+        Ok(args) => {
+            if !args.free.is_empty() {
+                eprintln!("{}", opts.usage("mock-server"));
+                bail!("unexpected positional arguments");
+            }
+            args
+        }
+        Err(e) => {
+            eprintln!("{}", opts.usage("mock-server"));
+            bail!(e);
+        }
+    };
+
+    let host: IpAddr = args
+        .opt_str("host")
+        .as_deref()
+        .unwrap_or("127.0.0.1")
+        .parse()?;
+    let port: u16 = match args.opt_str("p") {
+        Some(p) => p.parse()?,
+        None => 0,
+    };
+
+    let spec = Spec::load(args.opt_str("i").unwrap())?;
+    let server = MockServer::bind(spec, (host, port).into()).await?;
+    println!("serving {} at {}", args.opt_str("i").unwrap(), server.url());
+
+    tokio::signal::ctrl_c().await?;
+    Ok(())
+}
@@ -0,0 +1,354 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use hyper::HeaderMap;
+use serde_json::Value;
+
+use crate::resolve;
+
+/*
+ * What is wrong with a request to an operation, if anything.
+ */
+pub(crate) fn request(
+    doc: &Value,
+    op: &Value,
+    params: &[&Value],
+    path_params: &BTreeMap<String, String>,
+    query: &[(String, String)],
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    for p in params {
+        let name = p["name"].as_str().unwrap_or_default();
+        let location = p["in"].as_str().unwrap_or_default();
+        let required = p["required"].as_bool().unwrap_or(location == "path");
+        let schema = p.get("schema").unwrap_or(&Value::Null);
+        let at = format!("{} parameter `{}`", location, name);
+
+        let values: Vec<&str> = match location {
+            "path" => path_params
+                .get(name)
+                .map(|v| v.as_str())
+                .into_iter()
+                .collect(),
+            "query" => {
+                let array = format!("{}[]", name);
+                let values: Vec<&str> = query
+                    .iter()
+                    .filter(|(k, _)| k == name || *k == array)
+                    .map(|(_, v)| v.as_str())
+                    .collect();
+                // Objects are sent in several parameters, like `created[gte]`.
+                let object = format!("{}[", name);
+                if values.is_empty() && query.iter().any(|(k, _)| k.starts_with(&object)) {
+                    continue;
+                }
+                values
+            }
+            // The spec has no say on these, even when it lists them.
+            "header"
+                if name.eq_ignore_ascii_case("accept")
+                    || name.eq_ignore_ascii_case("authorization")
+                    || name.eq_ignore_ascii_case("content-type") =>
+            {
+                continue
+            }
+            "header" => headers
+                .get_all(name)
+                .iter()
+                .filter_map(|v| v.to_str().ok())
+                .collect(),
+            _ => continue,
+        };
+
+        if values.is_empty() {
+            if required {
+                errors.push(format!("{} is missing", at));
+            }
+            continue;
+        }
+
+        for v in values {
+            match param(doc, schema, v) {
+                Ok(v) => value(doc, schema, &v, &at, &mut errors),
+                Err(e) => errors.push(format!("{}: `{}` {}", at, v, e)),
+            }
+        }
+    }
+
+    if let Some(request_body) = op.get("requestBody") {
+        let request_body = resolve(doc, request_body);
+        if body.is_empty() {
+            if request_body["required"].as_bool().unwrap_or(false) {
+                errors.push("the request body is missing".to_string());
+            }
+        } else if let Some(content) = request_body["content"].as_object() {
+            let content_type = headers
+                .get(hyper::header::CONTENT_TYPE)
+                .and_then(|ct| ct.to_str().ok())
+                .and_then(|ct| ct.split(';').next())
+                .map(|ct| ct.trim().to_lowercase())
+                .unwrap_or_default();
+
+            let media = content.iter().find(|(ct, _)| {
+                if content_type.is_empty() {
+                    ct.contains("json")
+                } else {
+                    let ct = ct.to_lowercase();
+                    ct == content_type
+                        || ct == "*/*"
+                        || (ct.ends_with("/*") && content_type.starts_with(&ct[..ct.len() - 1]))
+                }
+            });
+
+            match media {
+                Some((ct, media)) => {
+                    let schema = media.get("schema").unwrap_or(&Value::Null);
+                    if ct.contains("json") {
+                        match serde_json::from_slice::<Value>(body) {
+                            Ok(v) => value(doc, schema, &v, "body", &mut errors),
+                            Err(e) => errors.push(format!("the request body is not JSON: {}", e)),
+                        }
+                    } else if ct == "application/x-www-form-urlencoded" {
+                        form(doc, schema, body, &mut errors);
+                    }
+                }
+                None if !content_type.is_empty() => errors.push(format!(
+                    "the content type `{}` is not one of {:?}",
+                    content_type,
+                    content.keys().collect::<Vec<_>>()
+                )),
+                None => {}
+            }
+        }
+    }
+
+    errors.sort();
+    errors.dedup();
+    errors
+}
+
+/*
+ * A parameter, which is always a string, as the type of its schema.
+ */
+fn param(doc: &Value, schema: &Value, s: &str) -> Result<Value, &'static str> {
+    let schema = resolve(doc, schema);
+    match schema["type"].as_str().unwrap_or_default() {
+        "integer" => s
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| "is not an integer"),
+        "number" => s
+            .parse::<f64>()
+            .map(Value::from)
+            .map_err(|_| "is not a number"),
+        "boolean" => match s {
+            "true" => Ok(Value::Bool(true)),
+            "false" => Ok(Value::Bool(false)),
+            _ => Err("is not a boolean"),
+        },
+        "array" => s
+            .split(',')
+            .map(|s| param(doc, schema.get("items").unwrap_or(&Value::Null), s))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        _ => Ok(Value::String(s.to_string())),
+    }
+}
+
+/*
+ * Check a value against a schema, adding what is wrong with it to `errors`. `at` is
+ * where the value is, like `body.user.name`.
+ */
+pub(crate) fn value(doc: &Value, schema: &Value, v: &Value, at: &str, errors: &mut Vec<String>) {
+    let schema = resolve(doc, schema);
+
+    let types: Vec<&str> = match schema.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(t)) => t.iter().filter_map(|t| t.as_str()).collect(),
+        _ => vec![],
+    };
+
+    if v.is_null() {
+        if !types.is_empty()
+            && !types.contains(&"null")
+            && !schema["nullable"].as_bool().unwrap_or(false)
+        {
+            errors.push(format!("{}: expected {}, got null", at, a(types[0])));
+        }
+        return;
+    }
+
+    if let Some(all) = schema.get("allOf").and_then(|a| a.as_array()) {
+        for s in all {
+            value(doc, s, v, at, errors);
+        }
+    }
+    for key in &["oneOf", "anyOf"] {
+        if let Some(any) = schema.get(*key).and_then(|a| a.as_array()) {
+            let matches = any.iter().any(|s| {
+                let mut e = Vec::new();
+                value(doc, s, v, at, &mut e);
+                e.is_empty()
+            });
+            if !matches {
+                errors.push(format!(
+                    "{}: {} matches none of the schemas of {}",
+                    at, v, key
+                ));
+            }
+        }
+    }
+
+    if let Some(values) = schema.get("enum").and_then(|e| e.as_array()) {
+        if !values.contains(v) {
+            errors.push(format!(
+                "{}: {} is not one of {}",
+                at,
+                v,
+                Value::from(values.clone())
+            ));
+            return;
+        }
+    }
+
+    if !types.is_empty() && !types.iter().any(|t| is(t, v)) {
+        errors.push(format!("{}: expected {}, got {}", at, a(types[0]), v));
+        return;
+    }
+
+    match v {
+        Value::Object(o) => {
+            let props = schema.get("properties").and_then(|p| p.as_object());
+
+            for name in schema["required"].as_array().into_iter().flatten() {
+                let name = name.as_str().unwrap_or_default();
+                // The server fills in the read only properties.
+                let read_only = props
+                    .and_then(|p| p.get(name))
+                    .map(|p| resolve(doc, p)["readOnly"].as_bool().unwrap_or(false))
+                    .unwrap_or(false);
+                if !read_only && !o.contains_key(name) {
+                    errors.push(format!("{}.{} is missing", at, name));
+                }
+            }
+
+            for (k, v) in o {
+                let at = format!("{}.{}", at, k);
+                match (
+                    props.and_then(|p| p.get(k)),
+                    schema.get("additionalProperties"),
+                ) {
+                    (Some(s), _) => value(doc, s, v, &at, errors),
+                    (None, Some(Value::Bool(false))) => {
+                        errors.push(format!("{} is not a property", at))
+                    }
+                    (None, Some(s)) if s.is_object() => value(doc, s, v, &at, errors),
+                    _ => {}
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(s) = schema.get("items") {
+                for (i, v) in items.iter().enumerate() {
+                    value(doc, s, v, &format!("{}[{}]", at, i), errors);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/*
+ * Forms only get their required fields checked, since everything in them is a string.
+ */
+fn form(doc: &Value, schema: &Value, body: &[u8], errors: &mut Vec<String>) {
+    let fields: Vec<(String, String)> = match serde_urlencoded::from_bytes(body) {
+        Ok(f) => f,
+        Err(e) => {
+            errors.push(format!("the request body is not a form: {}", e));
+            return;
+        }
+    };
+    // Objects and arrays are sent in several fields, like `metadata[key]`.
+    let names: BTreeSet<&str> = fields
+        .iter()
+        .map(|(k, _)| k.split('[').next().unwrap_or_default())
+        .collect();
+
+    let schema = resolve(doc, schema);
+    for name in schema["required"].as_array().into_iter().flatten() {
+        let name = name.as_str().unwrap_or_default();
+        if !names.contains(name) {
+            errors.push(format!("body.{} is missing", name));
+        }
+    }
+}
+
+fn is(ty: &str, v: &Value) -> bool {
+    match ty {
+        "object" => v.is_object(),
+        "array" => v.is_array(),
+        "string" => v.is_string(),
+        "integer" => v.is_i64() || v.is_u64(),
+        "number" => v.is_number(),
+        "boolean" => v.is_boolean(),
+        "null" => v.is_null(),
+        _ => true,
+    }
+}
+
+fn a(ty: &str) -> String {
+    match ty {
+        "object" | "array" | "integer" => format!("an {}", ty),
+        _ => format!("a {}", ty),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    #[test]
+    fn test_value() {
+        let doc = json!({});
+        let schema = json!({
+            "type": "object",
+            "additionalProperties": false,
+            "properties": {
+                "id": {"type": "integer", "readOnly": true},
+                "tags": {"type": "array", "items": {"type": "string"}},
+                "note": {"type": "string", "nullable": true},
+                "amount": {"oneOf": [{"type": "string"}, {"type": "number"}]}
+            },
+            "required": ["id", "tags"]
+        });
+
+        let mut errors = Vec::new();
+        super::value(
+            &doc,
+            &schema,
+            &json!({"tags": ["a"], "note": null, "amount": 1.5}),
+            "body",
+            &mut errors,
+        );
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        super::value(
+            &doc,
+            &schema,
+            &json!({"tags": ["a", 2], "amount": true, "extra": 1}),
+            "body",
+            &mut errors,
+        );
+        assert_eq!(
+            errors,
+            vec![
+                "body.amount: true matches none of the schemas of oneOf",
+                "body.extra is not a property",
+                "body.tags[1]: expected a string, got 2",
+            ]
+        );
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Applications {
     pub client: Client,
@@ -21,42 +19,32 @@ impl Applications {
      *
      * **Parameters:**
      *
-     * * `q: &str`
-     * * `after: &str` -- Specifies the pagination cursor for the next page of apps.
-     * * `limit: i64` -- Specifies the number of results for a page.
-     * * `filter: &str` -- Filters apps by status, user.id, group.id or credentials.signing.kid expression.
-     * * `expand: &str` -- Traverses users link relationship and optionally embeds Application User resource.
-     * * `include_non_deleted: bool`
+     * * `params: &ApplicationsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        q: &str,
-        after: &str,
-        limit: i64,
-        filter: &str,
-        expand: &str,
-        include_non_deleted: bool,
+        params: &ApplicationsListParams,
     ) -> Result<Vec<crate::types::Application>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if include_non_deleted {
+        if let Some(include_non_deleted) = &params.include_non_deleted {
             query_args.push((
                 "includeNonDeleted".to_string(),
                 include_non_deleted.to_string(),
             ));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -65,6 +53,30 @@ impl Applications {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `ApplicationsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        expand: &str,
+        include_non_deleted: bool,
+    ) -> Result<Vec<crate::types::Application>> {
+        let params = ApplicationsListParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            include_non_deleted: Some(include_non_deleted).filter(|v| *v),
+            limit: Some(limit).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * List Applications.
      *
@@ -73,7 +85,41 @@ impl Applications {
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Enumerates apps added to your organization with pagination. A subset of apps can be returned that match a supported filter expression or query.
+     *
+     * **Parameters:**
+     *
+     * * `params: &ApplicationsListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(
+        &self,
+        params: &ApplicationsListParams,
+    ) -> Result<Vec<crate::types::Application>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if let Some(include_non_deleted) = &params.include_non_deleted {
+            query_args.push((
+                "includeNonDeleted".to_string(),
+                include_non_deleted.to_string(),
+            ));
+        }
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/api/v1/apps?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `ApplicationsListParams` instead.
      */
+    #[deprecated(note = "use `list_all_with` instead")]
     pub async fn list_all(
         &self,
         q: &str,
@@ -81,26 +127,66 @@ impl Applications {
         expand: &str,
         include_non_deleted: bool,
     ) -> Result<Vec<crate::types::Application>> {
+        let params = ApplicationsListParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            include_non_deleted: Some(include_non_deleted).filter(|v| *v),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * List Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/apps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates apps added to your organization with pagination. A subset of apps can be returned that match a supported filter expression or query.
+     */
+    pub fn list_stream(
+        &self,
+        params: &ApplicationsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Application> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if include_non_deleted {
+        if let Some(include_non_deleted) = &params.include_non_deleted {
             query_args.push((
                 "includeNonDeleted".to_string(),
                 include_non_deleted.to_string(),
             ));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/api/v1/apps?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -112,16 +198,15 @@ impl Applications {
      *
      * **Parameters:**
      *
-     * * `activate: bool` -- Executes activation lifecycle operation when creating the app.
-     * * `okta_access_gateway_agent: &str`
+     * * `params: &ApplicationsCreateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create(
+    pub async fn create_with(
         &self,
-        activate: bool,
+        params: &ApplicationsCreateParams,
         body: &crate::types::Application,
     ) -> Result<crate::types::Application> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if activate {
+        if let Some(activate) = &params.activate {
             query_args.push(("activate".to_string(), activate.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -132,6 +217,21 @@ impl Applications {
             .await
     }
 
+    /**
+     * Deprecated: use `create_with` and pass the query parameters as a `ApplicationsCreateParams` instead.
+     */
+    #[deprecated(note = "use `create_with` instead")]
+    pub async fn create(
+        &self,
+        activate: bool,
+        body: &crate::types::Application,
+    ) -> Result<crate::types::Application> {
+        let params = ApplicationsCreateParams {
+            activate: Some(activate).filter(|v| *v),
+        };
+        self.create_with(&params, body).await
+    }
+
     /**
      * Get Application.
      *
@@ -142,11 +242,15 @@ impl Applications {
      * **Parameters:**
      *
      * * `app_id: &str`
-     * * `expand: &str`
+     * * `params: &ApplicationsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(&self, app_id: &str, expand: &str) -> Result<crate::types::Application> {
+    pub async fn get_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsGetParams,
+    ) -> Result<crate::types::Application> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -159,6 +263,17 @@ impl Applications {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `ApplicationsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(&self, app_id: &str, expand: &str) -> Result<crate::types::Application> {
+        let params = ApplicationsGetParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(app_id, &params).await
+    }
+
     /**
      * Update Application.
      *
@@ -243,6 +358,44 @@ impl Applications {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List Certificate Signing Requests for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates Certificate Signing Requests for an application
+     */
+    pub fn list_csrs_fors_stream(
+        &self,
+        app_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Csr> {
+        let url = format!(
+            "/api/v1/apps/{}/credentials/csrs",
+            crate::progenitor_support::encode_path(app_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Generate Certificate Signing Request for Application.
      *
@@ -365,6 +518,44 @@ impl Applications {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List Key Credentials for Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates key credentials for an application
+     */
+    pub fn list_keys_stream(
+        &self,
+        app_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JsonWebKey> {
+        let url = format!(
+            "/api/v1/apps/{}/credentials/keys",
+            crate::progenitor_support::encode_path(app_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/api/v1/apps/{appId}/credentials/keys/generate` endpoint.
      *
@@ -373,15 +564,15 @@ impl Applications {
      * **Parameters:**
      *
      * * `app_id: &str`
-     * * `validity_years: i64`
+     * * `params: &ApplicationsGenerateKeyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn generate_key(
+    pub async fn generate_key_with(
         &self,
         app_id: &str,
-        validity_years: i64,
+        params: &ApplicationsGenerateKeyParams,
     ) -> Result<crate::types::JsonWebKey> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if validity_years > 0 {
+        if let Some(validity_years) = &params.validity_years {
             query_args.push(("validityYears".to_string(), validity_years.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -394,6 +585,21 @@ impl Applications {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `generate_key_with` and pass the query parameters as a `ApplicationsGenerateKeyParams` instead.
+     */
+    #[deprecated(note = "use `generate_key_with` instead")]
+    pub async fn generate_key(
+        &self,
+        app_id: &str,
+        validity_years: i64,
+    ) -> Result<crate::types::JsonWebKey> {
+        let params = ApplicationsGenerateKeyParams {
+            validity_years: Some(validity_years).filter(|v| *v > 0),
+        };
+        self.generate_key_with(app_id, &params).await
+    }
+
     /**
      * Get Key Credential for Application.
      *
@@ -427,16 +633,16 @@ impl Applications {
      *
      * * `app_id: &str`
      * * `key_id: &str`
-     * * `target_aid: &str` -- Unique key of the target Application.
+     * * `params: &ApplicationsCloneKeyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn clone_key(
+    pub async fn clone_key_with(
         &self,
         app_id: &str,
         key_id: &str,
-        target_aid: &str,
+        params: &ApplicationsCloneKeyParams,
     ) -> Result<crate::types::JsonWebKey> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !target_aid.is_empty() {
+        if let Some(target_aid) = &params.target_aid {
             query_args.push(("targetAid".to_string(), target_aid.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -450,6 +656,22 @@ impl Applications {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `clone_key_with` and pass the query parameters as a `ApplicationsCloneKeyParams` instead.
+     */
+    #[deprecated(note = "use `clone_key_with` instead")]
+    pub async fn clone_key(
+        &self,
+        app_id: &str,
+        key_id: &str,
+        target_aid: &str,
+    ) -> Result<crate::types::JsonWebKey> {
+        let params = ApplicationsCloneKeyParams {
+            target_aid: Some(target_aid.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.clone_key_with(app_id, key_id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/apps/{appId}/grants` endpoint.
      *
@@ -458,15 +680,15 @@ impl Applications {
      * **Parameters:**
      *
      * * `app_id: &str`
-     * * `expand: &str`
+     * * `params: &ApplicationsListScopeConsentGrantsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_scope_consent_grants(
+    pub async fn list_scope_consent_grants_with(
         &self,
         app_id: &str,
-        expand: &str,
+        params: &ApplicationsListScopeConsentGrantsParams,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -479,20 +701,83 @@ impl Applications {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_scope_consent_grants_with` and pass the query parameters as a `ApplicationsListScopeConsentGrantsParams` instead.
+     */
+    #[deprecated(note = "use `list_scope_consent_grants_with` instead")]
+    pub async fn list_scope_consent_grants(
+        &self,
+        app_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        let params = ApplicationsListScopeConsentGrantsParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_scope_consent_grants_with(app_id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/apps/{appId}/grants` endpoint.
      *
      * As opposed to `list_scope_consent_grants`, this function returns all the pages of the request at once.
      *
      * Lists all scope consent grants for the application
+     *
+     * **Parameters:**
+     *
+     * * `params: &ApplicationsListScopeConsentGrantsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_scope_consent_grants_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListScopeConsentGrantsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/api/v1/apps/{}/grants?{}",
+            crate::progenitor_support::encode_path(app_id),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_scope_consent_grants_with` and pass the query parameters as a `ApplicationsListScopeConsentGrantsParams` instead.
      */
+    #[deprecated(note = "use `list_all_scope_consent_grants_with` instead")]
     pub async fn list_all_scope_consent_grants(
         &self,
         app_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        let params = ApplicationsListScopeConsentGrantsParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_all_scope_consent_grants_with(app_id, &params)
+            .await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/grants` endpoint.
+     *
+     * As opposed to `list_all_scope_consent_grants`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all scope consent grants for the application
+     */
+    pub fn list_scope_consent_grants_stream(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListScopeConsentGrantsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2ScopeConsentGrant> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -502,7 +787,22 @@ impl Applications {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -538,16 +838,16 @@ impl Applications {
      *
      * * `app_id: &str`
      * * `grant_id: &str`
-     * * `expand: &str`
+     * * `params: &ApplicationsGetScopeConsentGrantParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_scope_consent_grant(
+    pub async fn get_scope_consent_grant_with(
         &self,
         app_id: &str,
         grant_id: &str,
-        expand: &str,
+        params: &ApplicationsGetScopeConsentGrantParams,
     ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -561,6 +861,23 @@ impl Applications {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_scope_consent_grant_with` and pass the query parameters as a `ApplicationsGetScopeConsentGrantParams` instead.
+     */
+    #[deprecated(note = "use `get_scope_consent_grant_with` instead")]
+    pub async fn get_scope_consent_grant(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
+        let params = ApplicationsGetScopeConsentGrantParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_scope_consent_grant_with(app_id, grant_id, &params)
+            .await
+    }
+
     /**
      * This function performs a `DELETE` to the `/api/v1/apps/{appId}/grants/{grantId}` endpoint.
      *
@@ -591,30 +908,24 @@ impl Applications {
      * **Parameters:**
      *
      * * `app_id: &str`
-     * * `q: &str`
-     * * `after: &str` -- Specifies the pagination cursor for the next page of assignments.
-     * * `limit: i64` -- Specifies the number of results for a page.
-     * * `expand: &str`
+     * * `params: &ApplicationsListGroupAssignmentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_group_assignments(
+    pub async fn list_group_assignments_with(
         &self,
         app_id: &str,
-        q: &str,
-        after: &str,
-        limit: i64,
-        expand: &str,
+        params: &ApplicationsListGroupAssignmentsParams,
     ) -> Result<Vec<crate::types::ApplicationGroupAssignment>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -627,6 +938,27 @@ impl Applications {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_group_assignments_with` and pass the query parameters as a `ApplicationsListGroupAssignmentsParams` instead.
+     */
+    #[deprecated(note = "use `list_group_assignments_with` instead")]
+    pub async fn list_group_assignments(
+        &self,
+        app_id: &str,
+        q: &str,
+        after: &str,
+        limit: i64,
+        expand: &str,
+    ) -> Result<Vec<crate::types::ApplicationGroupAssignment>> {
+        let params = ApplicationsListGroupAssignmentsParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_group_assignments_with(app_id, &params).await
+    }
+
     /**
      * List Groups Assigned to Application.
      *
@@ -635,18 +967,72 @@ impl Applications {
      * As opposed to `list_group_assignments`, this function returns all the pages of the request at once.
      *
      * Enumerates group assignments for an application.
+     *
+     * **Parameters:**
+     *
+     * * `params: &ApplicationsListGroupAssignmentsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_group_assignments_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListGroupAssignmentsParams,
+    ) -> Result<Vec<crate::types::ApplicationGroupAssignment>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/api/v1/apps/{}/groups?{}",
+            crate::progenitor_support::encode_path(app_id),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_group_assignments_with` and pass the query parameters as a `ApplicationsListGroupAssignmentsParams` instead.
      */
+    #[deprecated(note = "use `list_all_group_assignments_with` instead")]
     pub async fn list_all_group_assignments(
         &self,
         app_id: &str,
         q: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::ApplicationGroupAssignment>> {
+        let params = ApplicationsListGroupAssignmentsParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_group_assignments_with(app_id, &params).await
+    }
+
+    /**
+     * List Groups Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/groups` endpoint.
+     *
+     * As opposed to `list_all_group_assignments`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates group assignments for an application.
+     */
+    pub fn list_group_assignments_stream(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListGroupAssignmentsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ApplicationGroupAssignment> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -656,7 +1042,22 @@ impl Applications {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -670,16 +1071,16 @@ impl Applications {
      *
      * * `app_id: &str`
      * * `group_id: &str`
-     * * `expand: &str`
+     * * `params: &ApplicationsGetGroupAssignmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_group_assignment(
+    pub async fn get_group_assignment_with(
         &self,
         app_id: &str,
         group_id: &str,
-        expand: &str,
+        params: &ApplicationsGetGroupAssignmentParams,
     ) -> Result<crate::types::ApplicationGroupAssignment> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -694,15 +1095,32 @@ impl Applications {
     }
 
     /**
-     * Assign Group to Application.
-     *
-     * This function performs a `PUT` to the `/api/v1/apps/{appId}/groups/{groupId}` endpoint.
-     *
-     * Assigns a group to an application
-     *
-     * **Parameters:**
-     *
-     * * `app_id: &str`
+     * Deprecated: use `get_group_assignment_with` and pass the query parameters as a `ApplicationsGetGroupAssignmentParams` instead.
+     */
+    #[deprecated(note = "use `get_group_assignment_with` instead")]
+    pub async fn get_group_assignment(
+        &self,
+        app_id: &str,
+        group_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::ApplicationGroupAssignment> {
+        let params = ApplicationsGetGroupAssignmentParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_group_assignment_with(app_id, group_id, &params)
+            .await
+    }
+
+    /**
+     * Assign Group to Application.
+     *
+     * This function performs a `PUT` to the `/api/v1/apps/{appId}/groups/{groupId}` endpoint.
+     *
+     * Assigns a group to an application
+     *
+     * **Parameters:**
+     *
+     * * `app_id: &str`
      * * `group_id: &str`
      */
     pub async fn create_group_assignment(
@@ -792,25 +1210,21 @@ impl Applications {
      * **Parameters:**
      *
      * * `app_id: &str`
-     * * `expand: &str`
-     * * `after: &str`
-     * * `limit: i64`
+     * * `params: &ApplicationsListOAuth2TokensForsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_o_auth_2_tokens_fors(
+    pub async fn list_o_auth_2_tokens_fors_with(
         &self,
         app_id: &str,
-        expand: &str,
-        after: &str,
-        limit: i64,
+        params: &ApplicationsListOAuth2TokensForsParams,
     ) -> Result<Vec<crate::types::OAuth2Token>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -823,20 +1237,88 @@ impl Applications {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_o_auth_2_tokens_fors_with` and pass the query parameters as a `ApplicationsListOAuth2TokensForsParams` instead.
+     */
+    #[deprecated(note = "use `list_o_auth_2_tokens_fors_with` instead")]
+    pub async fn list_o_auth_2_tokens_fors(
+        &self,
+        app_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2Token>> {
+        let params = ApplicationsListOAuth2TokensForsParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_o_auth_2_tokens_fors_with(app_id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/apps/{appId}/tokens` endpoint.
      *
      * As opposed to `list_o_auth_2_tokens_for`, this function returns all the pages of the request at once.
      *
      * Lists all tokens for the application
+     *
+     * **Parameters:**
+     *
+     * * `params: &ApplicationsListOAuth2TokensForsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_o_auth_2_tokens_fors_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListOAuth2TokensForsParams,
+    ) -> Result<Vec<crate::types::OAuth2Token>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/api/v1/apps/{}/tokens?{}",
+            crate::progenitor_support::encode_path(app_id),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_o_auth_2_tokens_fors_with` and pass the query parameters as a `ApplicationsListOAuth2TokensForsParams` instead.
      */
+    #[deprecated(note = "use `list_all_o_auth_2_tokens_fors_with` instead")]
     pub async fn list_all_o_auth_2_tokens_fors(
         &self,
         app_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2Token>> {
+        let params = ApplicationsListOAuth2TokensForsParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_o_auth_2_tokens_fors_with(app_id, &params)
+            .await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_tokens_fors`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all tokens for the application
+     */
+    pub fn list_o_auth_2_tokens_fors_stream(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListOAuth2TokensForsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Token> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -846,7 +1328,22 @@ impl Applications {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -876,16 +1373,16 @@ impl Applications {
      *
      * * `app_id: &str`
      * * `token_id: &str`
-     * * `expand: &str`
+     * * `params: &ApplicationsGetOAuth2TokenForParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_o_auth_2_token_for(
+    pub async fn get_o_auth_2_token_for_with(
         &self,
         app_id: &str,
         token_id: &str,
-        expand: &str,
+        params: &ApplicationsGetOAuth2TokenForParams,
     ) -> Result<crate::types::OAuth2Token> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -899,6 +1396,23 @@ impl Applications {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_o_auth_2_token_for_with` and pass the query parameters as a `ApplicationsGetOAuth2TokenForParams` instead.
+     */
+    #[deprecated(note = "use `get_o_auth_2_token_for_with` instead")]
+    pub async fn get_o_auth_2_token_for(
+        &self,
+        app_id: &str,
+        token_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::OAuth2Token> {
+        let params = ApplicationsGetOAuth2TokenForParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_o_auth_2_token_for_with(app_id, token_id, &params)
+            .await
+    }
+
     /**
      * This function performs a `DELETE` to the `/api/v1/apps/{appId}/tokens/{tokenId}` endpoint.
      *
@@ -929,40 +1443,30 @@ impl Applications {
      * **Parameters:**
      *
      * * `app_id: &str`
-     * * `q: &str`
-     * * `query_scope: &str`
-     * * `after: &str` -- specifies the pagination cursor for the next page of assignments.
-     * * `limit: i64` -- specifies the number of results for a page.
-     * * `filter: &str`
-     * * `expand: &str`
+     * * `params: &ApplicationsListUsersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_users(
+    pub async fn list_users_with(
         &self,
         app_id: &str,
-        q: &str,
-        query_scope: &str,
-        after: &str,
-        limit: i64,
-        filter: &str,
-        expand: &str,
+        params: &ApplicationsListUsersParams,
     ) -> Result<Vec<crate::types::AppUser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !query_scope.is_empty() {
+        if let Some(query_scope) = &params.query_scope {
             query_args.push(("query_scope".to_string(), query_scope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -975,6 +1479,31 @@ impl Applications {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_users_with` and pass the query parameters as a `ApplicationsListUsersParams` instead.
+     */
+    #[deprecated(note = "use `list_users_with` instead")]
+    pub async fn list_users(
+        &self,
+        app_id: &str,
+        q: &str,
+        query_scope: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::AppUser>> {
+        let params = ApplicationsListUsersParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            query_scope: Some(query_scope.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_users_with(app_id, &params).await
+    }
+
     /**
      * List Users Assigned to Application.
      *
@@ -983,7 +1512,43 @@ impl Applications {
      * As opposed to `list_users`, this function returns all the pages of the request at once.
      *
      * Enumerates all assigned [application users](#application-user-model) for an application.
+     *
+     * **Parameters:**
+     *
+     * * `params: &ApplicationsListUsersParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_users_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListUsersParams,
+    ) -> Result<Vec<crate::types::AppUser>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(query_scope) = &params.query_scope {
+            query_args.push(("query_scope".to_string(), query_scope.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/api/v1/apps/{}/users?{}",
+            crate::progenitor_support::encode_path(app_id),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_users_with` and pass the query parameters as a `ApplicationsListUsersParams` instead.
      */
+    #[deprecated(note = "use `list_all_users_with` instead")]
     pub async fn list_all_users(
         &self,
         app_id: &str,
@@ -992,17 +1557,43 @@ impl Applications {
         filter: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::AppUser>> {
+        let params = ApplicationsListUsersParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            query_scope: Some(query_scope.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_users_with(app_id, &params).await
+    }
+
+    /**
+     * List Users Assigned to Application.
+     *
+     * This function performs a `GET` to the `/api/v1/apps/{appId}/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates all assigned [application users](#application-user-model) for an application.
+     */
+    pub fn list_users_stream(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListUsersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AppUser> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !query_scope.is_empty() {
+        if let Some(query_scope) = &params.query_scope {
             query_args.push(("query_scope".to_string(), query_scope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1012,7 +1603,22 @@ impl Applications {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -1052,16 +1658,16 @@ impl Applications {
      *
      * * `app_id: &str`
      * * `user_id: &str`
-     * * `expand: &str`
+     * * `params: &ApplicationsGetUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_user(
+    pub async fn get_user_with(
         &self,
         app_id: &str,
         user_id: &str,
-        expand: &str,
+        params: &ApplicationsGetUserParams,
     ) -> Result<crate::types::AppUser> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1075,6 +1681,22 @@ impl Applications {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_user_with` and pass the query parameters as a `ApplicationsGetUserParams` instead.
+     */
+    #[deprecated(note = "use `get_user_with` instead")]
+    pub async fn get_user(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::AppUser> {
+        let params = ApplicationsGetUserParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_user_with(app_id, user_id, &params).await
+    }
+
     /**
      * Update Application Profile for Assigned User.
      *
@@ -1115,11 +1737,16 @@ impl Applications {
      *
      * * `app_id: &str`
      * * `user_id: &str`
-     * * `send_email: bool`
+     * * `params: &ApplicationsDeleteUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_user(&self, app_id: &str, user_id: &str, send_email: bool) -> Result<()> {
+    pub async fn delete_user_with(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        params: &ApplicationsDeleteUserParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if send_email {
+        if let Some(send_email) = &params.send_email {
             query_args.push(("sendEmail".to_string(), send_email.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1132,4 +1759,933 @@ impl Applications {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Deprecated: use `delete_user_with` and pass the query parameters as a `ApplicationsDeleteUserParams` instead.
+     */
+    #[deprecated(note = "use `delete_user_with` instead")]
+    pub async fn delete_user(&self, app_id: &str, user_id: &str, send_email: bool) -> Result<()> {
+        let params = ApplicationsDeleteUserParams {
+            send_email: Some(send_email).filter(|v| *v),
+        };
+        self.delete_user_with(app_id, user_id, &params).await
+    }
+}
+
+/// The query parameters of `Applications::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsListParams {
+    /// Specifies the pagination cursor for the next page of apps.
+    pub after: Option<String>,
+    /// Traverses users link relationship and optionally embeds Application User resource.
+    pub expand: Option<String>,
+    /// Filters apps by status, user.id, group.id or credentials.signing.kid expression.
+    pub filter: Option<String>,
+    pub include_non_deleted: Option<bool>,
+    /// Specifies the number of results for a page.
+    pub limit: Option<i64>,
+    pub q: Option<String>,
+}
+
+impl ApplicationsListParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn include_non_deleted(mut self, include_non_deleted: bool) -> Self {
+        self.include_non_deleted = Some(include_non_deleted);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Applications::create_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsCreateParams {
+    /// Executes activation lifecycle operation when creating the app.
+    pub activate: Option<bool>,
+}
+
+impl ApplicationsCreateParams {
+    pub fn activate(mut self, activate: bool) -> Self {
+        self.activate = Some(activate);
+        self
+    }
+}
+
+/// The query parameters of `Applications::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsGetParams {
+    pub expand: Option<String>,
+}
+
+impl ApplicationsGetParams {
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Applications::generate_key_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsGenerateKeyParams {
+    pub validity_years: Option<i64>,
+}
+
+impl ApplicationsGenerateKeyParams {
+    pub fn validity_years(mut self, validity_years: i64) -> Self {
+        self.validity_years = Some(validity_years);
+        self
+    }
+}
+
+/// The query parameters of `Applications::clone_key_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsCloneKeyParams {
+    /// Unique key of the target Application.
+    pub target_aid: Option<String>,
+}
+
+impl ApplicationsCloneKeyParams {
+    pub fn target_aid<T: ToString>(mut self, target_aid: T) -> Self {
+        self.target_aid = Some(target_aid.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Applications::list_scope_consent_grants_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsListScopeConsentGrantsParams {
+    pub expand: Option<String>,
+}
+
+impl ApplicationsListScopeConsentGrantsParams {
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Applications::get_scope_consent_grant_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsGetScopeConsentGrantParams {
+    pub expand: Option<String>,
+}
+
+impl ApplicationsGetScopeConsentGrantParams {
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Applications::list_group_assignments_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsListGroupAssignmentsParams {
+    /// Specifies the pagination cursor for the next page of assignments.
+    pub after: Option<String>,
+    pub expand: Option<String>,
+    /// Specifies the number of results for a page.
+    pub limit: Option<i64>,
+    pub q: Option<String>,
+}
+
+impl ApplicationsListGroupAssignmentsParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Applications::get_group_assignment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsGetGroupAssignmentParams {
+    pub expand: Option<String>,
+}
+
+impl ApplicationsGetGroupAssignmentParams {
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Applications::list_o_auth_2_tokens_fors_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsListOAuth2TokensForsParams {
+    pub after: Option<String>,
+    pub expand: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl ApplicationsListOAuth2TokensForsParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `Applications::get_o_auth_2_token_for_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsGetOAuth2TokenForParams {
+    pub expand: Option<String>,
+}
+
+impl ApplicationsGetOAuth2TokenForParams {
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Applications::list_users_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsListUsersParams {
+    /// specifies the pagination cursor for the next page of assignments.
+    pub after: Option<String>,
+    pub expand: Option<String>,
+    pub filter: Option<String>,
+    /// specifies the number of results for a page.
+    pub limit: Option<i64>,
+    pub q: Option<String>,
+    pub query_scope: Option<String>,
+}
+
+impl ApplicationsListUsersParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn query_scope<T: ToString>(mut self, query_scope: T) -> Self {
+        self.query_scope = Some(query_scope.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Applications::get_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsGetUserParams {
+    pub expand: Option<String>,
+}
+
+impl ApplicationsGetUserParams {
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Applications::delete_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationsDeleteUserParams {
+    pub send_email: Option<bool>,
+}
+
+impl ApplicationsDeleteUserParams {
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.send_email = Some(send_email);
+        self
+    }
+}
+
+/// The functions of [`Applications`], for code that takes them as a trait, so that it can be
+/// tested with the `MockApplicationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ApplicationsApi: Send + Sync {
+    /// See [`Applications::list_with`].
+    async fn list_with(
+        &self,
+        params: &ApplicationsListParams,
+    ) -> Result<Vec<crate::types::Application>>;
+
+    /// See [`Applications::list_all_with`].
+    async fn list_all_with(
+        &self,
+        params: &ApplicationsListParams,
+    ) -> Result<Vec<crate::types::Application>>;
+
+    /// See [`Applications::list_stream`].
+    fn list_stream(
+        &self,
+        params: &ApplicationsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Application>;
+
+    /// See [`Applications::create_with`].
+    async fn create_with(
+        &self,
+        params: &ApplicationsCreateParams,
+        body: &crate::types::Application,
+    ) -> Result<crate::types::Application>;
+
+    /// See [`Applications::get_with`].
+    async fn get_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsGetParams,
+    ) -> Result<crate::types::Application>;
+
+    /// See [`Applications::update`].
+    async fn update(
+        &self,
+        app_id: &str,
+        body: &crate::types::Application,
+    ) -> Result<crate::types::Application>;
+
+    /// See [`Applications::delete`].
+    async fn delete(&self, app_id: &str) -> Result<()>;
+
+    /// See [`Applications::list_csrs_fors`].
+    async fn list_csrs_fors(&self, app_id: &str) -> Result<Vec<crate::types::Csr>>;
+
+    /// See [`Applications::list_all_csrs_fors`].
+    async fn list_all_csrs_fors(&self, app_id: &str) -> Result<Vec<crate::types::Csr>>;
+
+    /// See [`Applications::list_csrs_fors_stream`].
+    fn list_csrs_fors_stream(
+        &self,
+        app_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Csr>;
+
+    /// See [`Applications::generate_csr_for`].
+    async fn generate_csr_for(
+        &self,
+        app_id: &str,
+        body: &crate::types::CsrMetadata,
+    ) -> Result<crate::types::Csr>;
+
+    /// See [`Applications::get_csr_for`].
+    async fn get_csr_for(&self, app_id: &str, csr_id: &str) -> Result<crate::types::Csr>;
+
+    /// See [`Applications::revoke_csr_from`].
+    async fn revoke_csr_from(&self, app_id: &str, csr_id: &str) -> Result<()>;
+
+    /// See [`Applications::post_app_credentials_csr_lifecycle_publish`].
+    async fn post_app_credentials_csr_lifecycle_publish(
+        &self,
+        app_id: &str,
+        csr_id: &str,
+    ) -> Result<crate::types::JsonWebKey>;
+
+    /// See [`Applications::list_keys`].
+    async fn list_keys(&self, app_id: &str) -> Result<Vec<crate::types::JsonWebKey>>;
+
+    /// See [`Applications::list_all_keys`].
+    async fn list_all_keys(&self, app_id: &str) -> Result<Vec<crate::types::JsonWebKey>>;
+
+    /// See [`Applications::list_keys_stream`].
+    fn list_keys_stream(
+        &self,
+        app_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JsonWebKey>;
+
+    /// See [`Applications::generate_key_with`].
+    async fn generate_key_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsGenerateKeyParams,
+    ) -> Result<crate::types::JsonWebKey>;
+
+    /// See [`Applications::get_key`].
+    async fn get_key(&self, app_id: &str, key_id: &str) -> Result<crate::types::JsonWebKey>;
+
+    /// See [`Applications::clone_key_with`].
+    async fn clone_key_with(
+        &self,
+        app_id: &str,
+        key_id: &str,
+        params: &ApplicationsCloneKeyParams,
+    ) -> Result<crate::types::JsonWebKey>;
+
+    /// See [`Applications::list_scope_consent_grants_with`].
+    async fn list_scope_consent_grants_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListScopeConsentGrantsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>>;
+
+    /// See [`Applications::list_all_scope_consent_grants_with`].
+    async fn list_all_scope_consent_grants_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListScopeConsentGrantsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>>;
+
+    /// See [`Applications::list_scope_consent_grants_stream`].
+    fn list_scope_consent_grants_stream(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListScopeConsentGrantsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2ScopeConsentGrant>;
+
+    /// See [`Applications::grant_consent_scope`].
+    async fn grant_consent_scope(
+        &self,
+        app_id: &str,
+        body: &crate::types::OAuth2ScopeConsentGrant,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant>;
+
+    /// See [`Applications::get_scope_consent_grant_with`].
+    async fn get_scope_consent_grant_with(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+        params: &ApplicationsGetScopeConsentGrantParams,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant>;
+
+    /// See [`Applications::revoke_scope_consent_grant`].
+    async fn revoke_scope_consent_grant(&self, app_id: &str, grant_id: &str) -> Result<()>;
+
+    /// See [`Applications::list_group_assignments_with`].
+    async fn list_group_assignments_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListGroupAssignmentsParams,
+    ) -> Result<Vec<crate::types::ApplicationGroupAssignment>>;
+
+    /// See [`Applications::list_all_group_assignments_with`].
+    async fn list_all_group_assignments_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListGroupAssignmentsParams,
+    ) -> Result<Vec<crate::types::ApplicationGroupAssignment>>;
+
+    /// See [`Applications::list_group_assignments_stream`].
+    fn list_group_assignments_stream(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListGroupAssignmentsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ApplicationGroupAssignment>;
+
+    /// See [`Applications::get_group_assignment_with`].
+    async fn get_group_assignment_with(
+        &self,
+        app_id: &str,
+        group_id: &str,
+        params: &ApplicationsGetGroupAssignmentParams,
+    ) -> Result<crate::types::ApplicationGroupAssignment>;
+
+    /// See [`Applications::create_group_assignment`].
+    async fn create_group_assignment(
+        &self,
+        app_id: &str,
+        group_id: &str,
+        body: &crate::types::ApplicationGroupAssignment,
+    ) -> Result<crate::types::ApplicationGroupAssignment>;
+
+    /// See [`Applications::delete_group_assignment`].
+    async fn delete_group_assignment(&self, app_id: &str, group_id: &str) -> Result<()>;
+
+    /// See [`Applications::activate`].
+    async fn activate(&self, app_id: &str) -> Result<()>;
+
+    /// See [`Applications::deactivate`].
+    async fn deactivate(&self, app_id: &str) -> Result<()>;
+
+    /// See [`Applications::list_o_auth_2_tokens_fors_with`].
+    async fn list_o_auth_2_tokens_fors_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListOAuth2TokensForsParams,
+    ) -> Result<Vec<crate::types::OAuth2Token>>;
+
+    /// See [`Applications::list_all_o_auth_2_tokens_fors_with`].
+    async fn list_all_o_auth_2_tokens_fors_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListOAuth2TokensForsParams,
+    ) -> Result<Vec<crate::types::OAuth2Token>>;
+
+    /// See [`Applications::list_o_auth_2_tokens_fors_stream`].
+    fn list_o_auth_2_tokens_fors_stream(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListOAuth2TokensForsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Token>;
+
+    /// See [`Applications::revoke_o_auth_2_tokens_for`].
+    async fn revoke_o_auth_2_tokens_for(&self, app_id: &str) -> Result<()>;
+
+    /// See [`Applications::get_o_auth_2_token_for_with`].
+    async fn get_o_auth_2_token_for_with(
+        &self,
+        app_id: &str,
+        token_id: &str,
+        params: &ApplicationsGetOAuth2TokenForParams,
+    ) -> Result<crate::types::OAuth2Token>;
+
+    /// See [`Applications::revoke_o_auth_2_token_for`].
+    async fn revoke_o_auth_2_token_for(&self, app_id: &str, token_id: &str) -> Result<()>;
+
+    /// See [`Applications::list_users_with`].
+    async fn list_users_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListUsersParams,
+    ) -> Result<Vec<crate::types::AppUser>>;
+
+    /// See [`Applications::list_all_users_with`].
+    async fn list_all_users_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListUsersParams,
+    ) -> Result<Vec<crate::types::AppUser>>;
+
+    /// See [`Applications::list_users_stream`].
+    fn list_users_stream(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListUsersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AppUser>;
+
+    /// See [`Applications::assign_user`].
+    async fn assign_user(
+        &self,
+        app_id: &str,
+        body: &crate::types::AppUser,
+    ) -> Result<crate::types::AppUser>;
+
+    /// See [`Applications::get_user_with`].
+    async fn get_user_with(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        params: &ApplicationsGetUserParams,
+    ) -> Result<crate::types::AppUser>;
+
+    /// See [`Applications::update_user`].
+    async fn update_user(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        body: &crate::types::AppUser,
+    ) -> Result<crate::types::AppUser>;
+
+    /// See [`Applications::delete_user_with`].
+    async fn delete_user_with(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        params: &ApplicationsDeleteUserParams,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ApplicationsApi for Applications {
+    async fn list_with(
+        &self,
+        params: &ApplicationsListParams,
+    ) -> Result<Vec<crate::types::Application>> {
+        Applications::list_with(self, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        params: &ApplicationsListParams,
+    ) -> Result<Vec<crate::types::Application>> {
+        Applications::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &ApplicationsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Application> {
+        Applications::list_stream(self, params, start, max_items)
+    }
+
+    async fn create_with(
+        &self,
+        params: &ApplicationsCreateParams,
+        body: &crate::types::Application,
+    ) -> Result<crate::types::Application> {
+        Applications::create_with(self, params, body).await
+    }
+
+    async fn get_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsGetParams,
+    ) -> Result<crate::types::Application> {
+        Applications::get_with(self, app_id, params).await
+    }
+
+    async fn update(
+        &self,
+        app_id: &str,
+        body: &crate::types::Application,
+    ) -> Result<crate::types::Application> {
+        Applications::update(self, app_id, body).await
+    }
+
+    async fn delete(&self, app_id: &str) -> Result<()> {
+        Applications::delete(self, app_id).await
+    }
+
+    async fn list_csrs_fors(&self, app_id: &str) -> Result<Vec<crate::types::Csr>> {
+        Applications::list_csrs_fors(self, app_id).await
+    }
+
+    async fn list_all_csrs_fors(&self, app_id: &str) -> Result<Vec<crate::types::Csr>> {
+        Applications::list_all_csrs_fors(self, app_id).await
+    }
+
+    fn list_csrs_fors_stream(
+        &self,
+        app_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Csr> {
+        Applications::list_csrs_fors_stream(self, app_id, start, max_items)
+    }
+
+    async fn generate_csr_for(
+        &self,
+        app_id: &str,
+        body: &crate::types::CsrMetadata,
+    ) -> Result<crate::types::Csr> {
+        Applications::generate_csr_for(self, app_id, body).await
+    }
+
+    async fn get_csr_for(&self, app_id: &str, csr_id: &str) -> Result<crate::types::Csr> {
+        Applications::get_csr_for(self, app_id, csr_id).await
+    }
+
+    async fn revoke_csr_from(&self, app_id: &str, csr_id: &str) -> Result<()> {
+        Applications::revoke_csr_from(self, app_id, csr_id).await
+    }
+
+    async fn post_app_credentials_csr_lifecycle_publish(
+        &self,
+        app_id: &str,
+        csr_id: &str,
+    ) -> Result<crate::types::JsonWebKey> {
+        Applications::post_app_credentials_csr_lifecycle_publish(self, app_id, csr_id).await
+    }
+
+    async fn list_keys(&self, app_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+        Applications::list_keys(self, app_id).await
+    }
+
+    async fn list_all_keys(&self, app_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+        Applications::list_all_keys(self, app_id).await
+    }
+
+    fn list_keys_stream(
+        &self,
+        app_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JsonWebKey> {
+        Applications::list_keys_stream(self, app_id, start, max_items)
+    }
+
+    async fn generate_key_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsGenerateKeyParams,
+    ) -> Result<crate::types::JsonWebKey> {
+        Applications::generate_key_with(self, app_id, params).await
+    }
+
+    async fn get_key(&self, app_id: &str, key_id: &str) -> Result<crate::types::JsonWebKey> {
+        Applications::get_key(self, app_id, key_id).await
+    }
+
+    async fn clone_key_with(
+        &self,
+        app_id: &str,
+        key_id: &str,
+        params: &ApplicationsCloneKeyParams,
+    ) -> Result<crate::types::JsonWebKey> {
+        Applications::clone_key_with(self, app_id, key_id, params).await
+    }
+
+    async fn list_scope_consent_grants_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListScopeConsentGrantsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Applications::list_scope_consent_grants_with(self, app_id, params).await
+    }
+
+    async fn list_all_scope_consent_grants_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListScopeConsentGrantsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Applications::list_all_scope_consent_grants_with(self, app_id, params).await
+    }
+
+    fn list_scope_consent_grants_stream(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListScopeConsentGrantsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2ScopeConsentGrant> {
+        Applications::list_scope_consent_grants_stream(self, app_id, params, start, max_items)
+    }
+
+    async fn grant_consent_scope(
+        &self,
+        app_id: &str,
+        body: &crate::types::OAuth2ScopeConsentGrant,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
+        Applications::grant_consent_scope(self, app_id, body).await
+    }
+
+    async fn get_scope_consent_grant_with(
+        &self,
+        app_id: &str,
+        grant_id: &str,
+        params: &ApplicationsGetScopeConsentGrantParams,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
+        Applications::get_scope_consent_grant_with(self, app_id, grant_id, params).await
+    }
+
+    async fn revoke_scope_consent_grant(&self, app_id: &str, grant_id: &str) -> Result<()> {
+        Applications::revoke_scope_consent_grant(self, app_id, grant_id).await
+    }
+
+    async fn list_group_assignments_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListGroupAssignmentsParams,
+    ) -> Result<Vec<crate::types::ApplicationGroupAssignment>> {
+        Applications::list_group_assignments_with(self, app_id, params).await
+    }
+
+    async fn list_all_group_assignments_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListGroupAssignmentsParams,
+    ) -> Result<Vec<crate::types::ApplicationGroupAssignment>> {
+        Applications::list_all_group_assignments_with(self, app_id, params).await
+    }
+
+    fn list_group_assignments_stream(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListGroupAssignmentsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ApplicationGroupAssignment> {
+        Applications::list_group_assignments_stream(self, app_id, params, start, max_items)
+    }
+
+    async fn get_group_assignment_with(
+        &self,
+        app_id: &str,
+        group_id: &str,
+        params: &ApplicationsGetGroupAssignmentParams,
+    ) -> Result<crate::types::ApplicationGroupAssignment> {
+        Applications::get_group_assignment_with(self, app_id, group_id, params).await
+    }
+
+    async fn create_group_assignment(
+        &self,
+        app_id: &str,
+        group_id: &str,
+        body: &crate::types::ApplicationGroupAssignment,
+    ) -> Result<crate::types::ApplicationGroupAssignment> {
+        Applications::create_group_assignment(self, app_id, group_id, body).await
+    }
+
+    async fn delete_group_assignment(&self, app_id: &str, group_id: &str) -> Result<()> {
+        Applications::delete_group_assignment(self, app_id, group_id).await
+    }
+
+    async fn activate(&self, app_id: &str) -> Result<()> {
+        Applications::activate(self, app_id).await
+    }
+
+    async fn deactivate(&self, app_id: &str) -> Result<()> {
+        Applications::deactivate(self, app_id).await
+    }
+
+    async fn list_o_auth_2_tokens_fors_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListOAuth2TokensForsParams,
+    ) -> Result<Vec<crate::types::OAuth2Token>> {
+        Applications::list_o_auth_2_tokens_fors_with(self, app_id, params).await
+    }
+
+    async fn list_all_o_auth_2_tokens_fors_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListOAuth2TokensForsParams,
+    ) -> Result<Vec<crate::types::OAuth2Token>> {
+        Applications::list_all_o_auth_2_tokens_fors_with(self, app_id, params).await
+    }
+
+    fn list_o_auth_2_tokens_fors_stream(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListOAuth2TokensForsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Token> {
+        Applications::list_o_auth_2_tokens_fors_stream(self, app_id, params, start, max_items)
+    }
+
+    async fn revoke_o_auth_2_tokens_for(&self, app_id: &str) -> Result<()> {
+        Applications::revoke_o_auth_2_tokens_for(self, app_id).await
+    }
+
+    async fn get_o_auth_2_token_for_with(
+        &self,
+        app_id: &str,
+        token_id: &str,
+        params: &ApplicationsGetOAuth2TokenForParams,
+    ) -> Result<crate::types::OAuth2Token> {
+        Applications::get_o_auth_2_token_for_with(self, app_id, token_id, params).await
+    }
+
+    async fn revoke_o_auth_2_token_for(&self, app_id: &str, token_id: &str) -> Result<()> {
+        Applications::revoke_o_auth_2_token_for(self, app_id, token_id).await
+    }
+
+    async fn list_users_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListUsersParams,
+    ) -> Result<Vec<crate::types::AppUser>> {
+        Applications::list_users_with(self, app_id, params).await
+    }
+
+    async fn list_all_users_with(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListUsersParams,
+    ) -> Result<Vec<crate::types::AppUser>> {
+        Applications::list_all_users_with(self, app_id, params).await
+    }
+
+    fn list_users_stream(
+        &self,
+        app_id: &str,
+        params: &ApplicationsListUsersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AppUser> {
+        Applications::list_users_stream(self, app_id, params, start, max_items)
+    }
+
+    async fn assign_user(
+        &self,
+        app_id: &str,
+        body: &crate::types::AppUser,
+    ) -> Result<crate::types::AppUser> {
+        Applications::assign_user(self, app_id, body).await
+    }
+
+    async fn get_user_with(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        params: &ApplicationsGetUserParams,
+    ) -> Result<crate::types::AppUser> {
+        Applications::get_user_with(self, app_id, user_id, params).await
+    }
+
+    async fn update_user(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        body: &crate::types::AppUser,
+    ) -> Result<crate::types::AppUser> {
+        Applications::update_user(self, app_id, user_id, body).await
+    }
+
+    async fn delete_user_with(
+        &self,
+        app_id: &str,
+        user_id: &str,
+        params: &ApplicationsDeleteUserParams,
+    ) -> Result<()> {
+        Applications::delete_user_with(self, app_id, user_id, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AuthorizationServers {
     pub client: Client,
@@ -19,24 +17,20 @@ impl AuthorizationServers {
      *
      * **Parameters:**
      *
-     * * `q: &str`
-     * * `limit: &str`
-     * * `after: &str`
+     * * `params: &AuthorizationServersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        q: &str,
-        limit: &str,
-        after: &str,
+        params: &AuthorizationServersListParams,
     ) -> Result<Vec<crate::types::AuthorizationServer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -45,16 +39,41 @@ impl AuthorizationServers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `AuthorizationServersListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        q: &str,
+        limit: &str,
+        after: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServer>> {
+        let params = AuthorizationServersListParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/authorizationServers` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Success
+     *
+     * **Parameters:**
+     *
+     * * `params: &AuthorizationServersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(&self, q: &str) -> Result<Vec<crate::types::AuthorizationServer>> {
+    pub async fn list_all_with(
+        &self,
+        params: &AuthorizationServersListParams,
+    ) -> Result<Vec<crate::types::AuthorizationServer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -63,6 +82,56 @@ impl AuthorizationServers {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `AuthorizationServersListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(&self, q: &str) -> Result<Vec<crate::types::AuthorizationServer>> {
+        let params = AuthorizationServersListParams {
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_stream(
+        &self,
+        params: &AuthorizationServersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuthorizationServer> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/api/v1/authorizationServers?{}", query_);
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers` endpoint.
      *
@@ -178,6 +247,42 @@ impl AuthorizationServers {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/claims` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_claims`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_o_auth_2_claims_stream(
+        &self,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Claim> {
+        let url = format!(
+            "/api/v1/authorizationServers/{}/claims",
+            crate::progenitor_support::encode_path(auth_server_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/claims` endpoint.
      *
@@ -313,6 +418,42 @@ impl AuthorizationServers {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_clients_fors`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_o_auth_2_clients_fors_stream(
+        &self,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Client> {
+        let url = format!(
+            "/api/v1/authorizationServers/{}/clients",
+            crate::progenitor_support::encode_path(auth_server_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
      *
@@ -322,26 +463,22 @@ impl AuthorizationServers {
      *
      * * `auth_server_id: &str`
      * * `client_id: &str`
-     * * `expand: &str`
-     * * `after: &str`
-     * * `limit: i64`
+     * * `params: &AuthorizationServersListRefreshTokensForAndClientsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_refresh_tokens_for_and_clients(
+    pub async fn list_refresh_tokens_for_and_clients_with(
         &self,
         auth_server_id: &str,
         client_id: &str,
-        expand: &str,
-        after: &str,
-        limit: i64,
+        params: &AuthorizationServersListRefreshTokensForAndClientsParams,
     ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -355,21 +492,94 @@ impl AuthorizationServers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_refresh_tokens_for_and_clients_with` and pass the query parameters as a `AuthorizationServersListRefreshTokensForAndClientsParams` instead.
+     */
+    #[deprecated(note = "use `list_refresh_tokens_for_and_clients_with` instead")]
+    pub async fn list_refresh_tokens_for_and_clients(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        let params = AuthorizationServersListRefreshTokensForAndClientsParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_refresh_tokens_for_and_clients_with(auth_server_id, client_id, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
      *
      * As opposed to `list_refresh_tokens_for_and_client`, this function returns all the pages of the request at once.
      *
      * Success
+     *
+     * **Parameters:**
+     *
+     * * `params: &AuthorizationServersListRefreshTokensForAndClientsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_refresh_tokens_for_and_clients_with(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        params: &AuthorizationServersListRefreshTokensForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/api/v1/authorizationServers/{}/clients/{}/tokens?{}",
+            crate::progenitor_support::encode_path(auth_server_id),
+            crate::progenitor_support::encode_path(client_id),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_refresh_tokens_for_and_clients_with` and pass the query parameters as a `AuthorizationServersListRefreshTokensForAndClientsParams` instead.
      */
+    #[deprecated(note = "use `list_all_refresh_tokens_for_and_clients_with` instead")]
     pub async fn list_all_refresh_tokens_for_and_clients(
         &self,
         auth_server_id: &str,
         client_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        let params = AuthorizationServersListRefreshTokensForAndClientsParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_refresh_tokens_for_and_clients_with(auth_server_id, client_id, &params)
+            .await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_refresh_tokens_for_and_clients`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_refresh_tokens_for_and_clients_stream(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        params: &AuthorizationServersListRefreshTokensForAndClientsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2RefreshToken> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -380,7 +590,22 @@ impl AuthorizationServers {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -417,17 +642,17 @@ impl AuthorizationServers {
      * * `auth_server_id: &str`
      * * `client_id: &str`
      * * `token_id: &str`
-     * * `expand: &str`
+     * * `params: &AuthorizationServersGetRefreshTokenForAndClientParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_refresh_token_for_and_client(
+    pub async fn get_refresh_token_for_and_client_with(
         &self,
         auth_server_id: &str,
         client_id: &str,
         token_id: &str,
-        expand: &str,
+        params: &AuthorizationServersGetRefreshTokenForAndClientParams,
     ) -> Result<crate::types::OAuth2RefreshToken> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -442,6 +667,24 @@ impl AuthorizationServers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_refresh_token_for_and_client_with` and pass the query parameters as a `AuthorizationServersGetRefreshTokenForAndClientParams` instead.
+     */
+    #[deprecated(note = "use `get_refresh_token_for_and_client_with` instead")]
+    pub async fn get_refresh_token_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::OAuth2RefreshToken> {
+        let params = AuthorizationServersGetRefreshTokenForAndClientParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_refresh_token_for_and_client_with(auth_server_id, client_id, token_id, &params)
+            .await
+    }
+
     /**
      * This function performs a `DELETE` to the `/api/v1/authorizationServers/{authServerId}/clients/{clientId}/tokens/{tokenId}` endpoint.
      *
@@ -506,6 +749,42 @@ impl AuthorizationServers {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_keys_stream(
+        &self,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JsonWebKey> {
+        let url = format!(
+            "/api/v1/authorizationServers/{}/credentials/keys",
+            crate::progenitor_support::encode_path(auth_server_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/credentials/lifecycle/keyRotate` endpoint.
      *
@@ -606,6 +885,42 @@ impl AuthorizationServers {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies` endpoint.
+     *
+     * As opposed to `list_all_policies`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_policies_stream(
+        &self,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuthorizationServerPolicy> {
+        let url = format!(
+            "/api/v1/authorizationServers/{}/policies",
+            crate::progenitor_support::encode_path(auth_server_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies` endpoint.
      *
@@ -786,6 +1101,44 @@ impl AuthorizationServers {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_all_policy_rules`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates all policy rules for the specified Custom Authorization Server and Policy.
+     */
+    pub fn list_policy_rules_stream(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuthorizationServerPolicyRule> {
+        let url = format!(
+            "/api/v1/authorizationServers/{}/policies/{}/rules",
+            crate::progenitor_support::encode_path(auth_server_id),
+            crate::progenitor_support::encode_path(policy_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/api/v1/authorizationServers/{authServerId}/policies/{policyId}/rules` endpoint.
      *
@@ -959,30 +1312,24 @@ impl AuthorizationServers {
      * **Parameters:**
      *
      * * `auth_server_id: &str`
-     * * `q: &str`
-     * * `filter: &str`
-     * * `cursor: &str`
-     * * `limit: i64`
+     * * `params: &AuthorizationServersListOAuth2ScopesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_o_auth_2_scopes(
+    pub async fn list_o_auth_2_scopes_with(
         &self,
         auth_server_id: &str,
-        q: &str,
-        filter: &str,
-        cursor: &str,
-        limit: i64,
+        params: &AuthorizationServersListOAuth2ScopesParams,
     ) -> Result<Vec<crate::types::OAuth2Scope>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -995,13 +1342,68 @@ impl AuthorizationServers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_o_auth_2_scopes_with` and pass the query parameters as a `AuthorizationServersListOAuth2ScopesParams` instead.
+     */
+    #[deprecated(note = "use `list_o_auth_2_scopes_with` instead")]
+    pub async fn list_o_auth_2_scopes(
+        &self,
+        auth_server_id: &str,
+        q: &str,
+        filter: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2Scope>> {
+        let params = AuthorizationServersListOAuth2ScopesParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_o_auth_2_scopes_with(auth_server_id, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
      *
      * As opposed to `list_o_auth_2_scopes`, this function returns all the pages of the request at once.
      *
      * Success
+     *
+     * **Parameters:**
+     *
+     * * `params: &AuthorizationServersListOAuth2ScopesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_o_auth_2_scopes_with(
+        &self,
+        auth_server_id: &str,
+        params: &AuthorizationServersListOAuth2ScopesParams,
+    ) -> Result<Vec<crate::types::OAuth2Scope>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(cursor) = &params.cursor {
+            query_args.push(("cursor".to_string(), cursor.to_string()));
+        }
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/api/v1/authorizationServers/{}/scopes?{}",
+            crate::progenitor_support::encode_path(auth_server_id),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_o_auth_2_scopes_with` and pass the query parameters as a `AuthorizationServersListOAuth2ScopesParams` instead.
      */
+    #[deprecated(note = "use `list_all_o_auth_2_scopes_with` instead")]
     pub async fn list_all_o_auth_2_scopes(
         &self,
         auth_server_id: &str,
@@ -1009,14 +1411,38 @@ impl AuthorizationServers {
         filter: &str,
         cursor: &str,
     ) -> Result<Vec<crate::types::OAuth2Scope>> {
+        let params = AuthorizationServersListOAuth2ScopesParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_o_auth_2_scopes_with(auth_server_id, &params)
+            .await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/authorizationServers/{authServerId}/scopes` endpoint.
+     *
+     * As opposed to `list_all_o_auth_2_scopes`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_o_auth_2_scopes_stream(
+        &self,
+        auth_server_id: &str,
+        params: &AuthorizationServersListOAuth2ScopesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Scope> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1026,7 +1452,22 @@ impl AuthorizationServers {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -1124,3 +1565,915 @@ impl AuthorizationServers {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `AuthorizationServers::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AuthorizationServersListParams {
+    pub after: Option<String>,
+    pub limit: Option<String>,
+    pub q: Option<String>,
+}
+
+impl AuthorizationServersListParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AuthorizationServers::list_refresh_tokens_for_and_clients_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AuthorizationServersListRefreshTokensForAndClientsParams {
+    pub after: Option<String>,
+    pub expand: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl AuthorizationServersListRefreshTokensForAndClientsParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `AuthorizationServers::get_refresh_token_for_and_client_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AuthorizationServersGetRefreshTokenForAndClientParams {
+    pub expand: Option<String>,
+}
+
+impl AuthorizationServersGetRefreshTokenForAndClientParams {
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AuthorizationServers::list_o_auth_2_scopes_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AuthorizationServersListOAuth2ScopesParams {
+    pub cursor: Option<String>,
+    pub filter: Option<String>,
+    pub limit: Option<i64>,
+    pub q: Option<String>,
+}
+
+impl AuthorizationServersListOAuth2ScopesParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+}
+
+/// The functions of [`AuthorizationServers`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAuthorizationServersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AuthorizationServersApi: Send + Sync {
+    /// See [`AuthorizationServers::list_with`].
+    async fn list_with(
+        &self,
+        params: &AuthorizationServersListParams,
+    ) -> Result<Vec<crate::types::AuthorizationServer>>;
+
+    /// See [`AuthorizationServers::list_all_with`].
+    async fn list_all_with(
+        &self,
+        params: &AuthorizationServersListParams,
+    ) -> Result<Vec<crate::types::AuthorizationServer>>;
+
+    /// See [`AuthorizationServers::list_stream`].
+    fn list_stream(
+        &self,
+        params: &AuthorizationServersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuthorizationServer>;
+
+    /// See [`AuthorizationServers::create`].
+    async fn create(
+        &self,
+        body: &crate::types::AuthorizationServer,
+    ) -> Result<crate::types::AuthorizationServer>;
+
+    /// See [`AuthorizationServers::get`].
+    async fn get(&self, auth_server_id: &str) -> Result<crate::types::AuthorizationServer>;
+
+    /// See [`AuthorizationServers::update`].
+    async fn update(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServer,
+    ) -> Result<crate::types::AuthorizationServer>;
+
+    /// See [`AuthorizationServers::delete`].
+    async fn delete(&self, auth_server_id: &str) -> Result<()>;
+
+    /// See [`AuthorizationServers::list_o_auth_2_claims`].
+    async fn list_o_auth_2_claims(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Claim>>;
+
+    /// See [`AuthorizationServers::list_all_o_auth_2_claims`].
+    async fn list_all_o_auth_2_claims(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Claim>>;
+
+    /// See [`AuthorizationServers::list_o_auth_2_claims_stream`].
+    fn list_o_auth_2_claims_stream(
+        &self,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Claim>;
+
+    /// See [`AuthorizationServers::create_o_auth_2_claim`].
+    async fn create_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::OAuth2Claim,
+    ) -> Result<crate::types::OAuth2Claim>;
+
+    /// See [`AuthorizationServers::get_o_auth_2_claim`].
+    async fn get_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> Result<crate::types::OAuth2Claim>;
+
+    /// See [`AuthorizationServers::update_o_auth_2_claim`].
+    async fn update_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+        body: &crate::types::OAuth2Claim,
+    ) -> Result<crate::types::OAuth2Claim>;
+
+    /// See [`AuthorizationServers::delete_o_auth_2_claim`].
+    async fn delete_o_auth_2_claim(&self, auth_server_id: &str, claim_id: &str) -> Result<()>;
+
+    /// See [`AuthorizationServers::list_o_auth_2_clients_fors`].
+    async fn list_o_auth_2_clients_fors(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Client>>;
+
+    /// See [`AuthorizationServers::list_all_o_auth_2_clients_fors`].
+    async fn list_all_o_auth_2_clients_fors(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Client>>;
+
+    /// See [`AuthorizationServers::list_o_auth_2_clients_fors_stream`].
+    fn list_o_auth_2_clients_fors_stream(
+        &self,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Client>;
+
+    /// See [`AuthorizationServers::list_refresh_tokens_for_and_clients_with`].
+    async fn list_refresh_tokens_for_and_clients_with(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        params: &AuthorizationServersListRefreshTokensForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>>;
+
+    /// See [`AuthorizationServers::list_all_refresh_tokens_for_and_clients_with`].
+    async fn list_all_refresh_tokens_for_and_clients_with(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        params: &AuthorizationServersListRefreshTokensForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>>;
+
+    /// See [`AuthorizationServers::list_refresh_tokens_for_and_clients_stream`].
+    fn list_refresh_tokens_for_and_clients_stream(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        params: &AuthorizationServersListRefreshTokensForAndClientsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2RefreshToken>;
+
+    /// See [`AuthorizationServers::revoke_refresh_tokens_for_and_client`].
+    async fn revoke_refresh_tokens_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+    ) -> Result<()>;
+
+    /// See [`AuthorizationServers::get_refresh_token_for_and_client_with`].
+    async fn get_refresh_token_for_and_client_with(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+        params: &AuthorizationServersGetRefreshTokenForAndClientParams,
+    ) -> Result<crate::types::OAuth2RefreshToken>;
+
+    /// See [`AuthorizationServers::revoke_refresh_token_for_and_client`].
+    async fn revoke_refresh_token_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<()>;
+
+    /// See [`AuthorizationServers::list_keys`].
+    async fn list_keys(&self, auth_server_id: &str) -> Result<Vec<crate::types::JsonWebKey>>;
+
+    /// See [`AuthorizationServers::list_all_keys`].
+    async fn list_all_keys(&self, auth_server_id: &str) -> Result<Vec<crate::types::JsonWebKey>>;
+
+    /// See [`AuthorizationServers::list_keys_stream`].
+    fn list_keys_stream(
+        &self,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JsonWebKey>;
+
+    /// See [`AuthorizationServers::rotate_keys`].
+    async fn rotate_keys(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::JwkUse,
+    ) -> Result<Vec<crate::types::JsonWebKey>>;
+
+    /// See [`AuthorizationServers::activate`].
+    async fn activate(&self, auth_server_id: &str) -> Result<()>;
+
+    /// See [`AuthorizationServers::deactivate`].
+    async fn deactivate(&self, auth_server_id: &str) -> Result<()>;
+
+    /// See [`AuthorizationServers::list_policies`].
+    async fn list_policies(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServerPolicy>>;
+
+    /// See [`AuthorizationServers::list_all_policies`].
+    async fn list_all_policies(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServerPolicy>>;
+
+    /// See [`AuthorizationServers::list_policies_stream`].
+    fn list_policies_stream(
+        &self,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuthorizationServerPolicy>;
+
+    /// See [`AuthorizationServers::create_policy`].
+    async fn create_policy(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServerPolicy,
+    ) -> Result<crate::types::AuthorizationServerPolicy>;
+
+    /// See [`AuthorizationServers::get_policy`].
+    async fn get_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> Result<crate::types::AuthorizationServerPolicy>;
+
+    /// See [`AuthorizationServers::update_policy`].
+    async fn update_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        body: &crate::types::AuthorizationServerPolicy,
+    ) -> Result<crate::types::AuthorizationServerPolicy>;
+
+    /// See [`AuthorizationServers::delete_policy`].
+    async fn delete_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()>;
+
+    /// See [`AuthorizationServers::activate_policy`].
+    async fn activate_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()>;
+
+    /// See [`AuthorizationServers::deactivate_policy`].
+    async fn deactivate_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()>;
+
+    /// See [`AuthorizationServers::list_policy_rules`].
+    async fn list_policy_rules(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServerPolicyRule>>;
+
+    /// See [`AuthorizationServers::list_all_policy_rules`].
+    async fn list_all_policy_rules(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServerPolicyRule>>;
+
+    /// See [`AuthorizationServers::list_policy_rules_stream`].
+    fn list_policy_rules_stream(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuthorizationServerPolicyRule>;
+
+    /// See [`AuthorizationServers::create_policy_rule`].
+    async fn create_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServerPolicyRule,
+    ) -> Result<crate::types::AuthorizationServerPolicyRule>;
+
+    /// See [`AuthorizationServers::get_policy_rule`].
+    async fn get_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> Result<crate::types::AuthorizationServerPolicyRule>;
+
+    /// See [`AuthorizationServers::update_policy_rule`].
+    async fn update_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+        body: &crate::types::AuthorizationServerPolicyRule,
+    ) -> Result<crate::types::AuthorizationServerPolicyRule>;
+
+    /// See [`AuthorizationServers::delete_policy_rule`].
+    async fn delete_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> Result<()>;
+
+    /// See [`AuthorizationServers::activate_policy_rule`].
+    async fn activate_policy_rule(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> Result<()>;
+
+    /// See [`AuthorizationServers::deactivate_policy_rule`].
+    async fn deactivate_policy_rule(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> Result<()>;
+
+    /// See [`AuthorizationServers::list_o_auth_2_scopes_with`].
+    async fn list_o_auth_2_scopes_with(
+        &self,
+        auth_server_id: &str,
+        params: &AuthorizationServersListOAuth2ScopesParams,
+    ) -> Result<Vec<crate::types::OAuth2Scope>>;
+
+    /// See [`AuthorizationServers::list_all_o_auth_2_scopes_with`].
+    async fn list_all_o_auth_2_scopes_with(
+        &self,
+        auth_server_id: &str,
+        params: &AuthorizationServersListOAuth2ScopesParams,
+    ) -> Result<Vec<crate::types::OAuth2Scope>>;
+
+    /// See [`AuthorizationServers::list_o_auth_2_scopes_stream`].
+    fn list_o_auth_2_scopes_stream(
+        &self,
+        auth_server_id: &str,
+        params: &AuthorizationServersListOAuth2ScopesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Scope>;
+
+    /// See [`AuthorizationServers::create_o_auth_2_scope`].
+    async fn create_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::OAuth2Scope,
+    ) -> Result<crate::types::OAuth2Scope>;
+
+    /// See [`AuthorizationServers::get_o_auth_2_scope`].
+    async fn get_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> Result<crate::types::OAuth2Scope>;
+
+    /// See [`AuthorizationServers::update_o_auth_2_scope`].
+    async fn update_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+        body: &crate::types::OAuth2Scope,
+    ) -> Result<crate::types::OAuth2Scope>;
+
+    /// See [`AuthorizationServers::delete_o_auth_2_scope`].
+    async fn delete_o_auth_2_scope(&self, auth_server_id: &str, scope_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl AuthorizationServersApi for AuthorizationServers {
+    async fn list_with(
+        &self,
+        params: &AuthorizationServersListParams,
+    ) -> Result<Vec<crate::types::AuthorizationServer>> {
+        AuthorizationServers::list_with(self, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        params: &AuthorizationServersListParams,
+    ) -> Result<Vec<crate::types::AuthorizationServer>> {
+        AuthorizationServers::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &AuthorizationServersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuthorizationServer> {
+        AuthorizationServers::list_stream(self, params, start, max_items)
+    }
+
+    async fn create(
+        &self,
+        body: &crate::types::AuthorizationServer,
+    ) -> Result<crate::types::AuthorizationServer> {
+        AuthorizationServers::create(self, body).await
+    }
+
+    async fn get(&self, auth_server_id: &str) -> Result<crate::types::AuthorizationServer> {
+        AuthorizationServers::get(self, auth_server_id).await
+    }
+
+    async fn update(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServer,
+    ) -> Result<crate::types::AuthorizationServer> {
+        AuthorizationServers::update(self, auth_server_id, body).await
+    }
+
+    async fn delete(&self, auth_server_id: &str) -> Result<()> {
+        AuthorizationServers::delete(self, auth_server_id).await
+    }
+
+    async fn list_o_auth_2_claims(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Claim>> {
+        AuthorizationServers::list_o_auth_2_claims(self, auth_server_id).await
+    }
+
+    async fn list_all_o_auth_2_claims(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Claim>> {
+        AuthorizationServers::list_all_o_auth_2_claims(self, auth_server_id).await
+    }
+
+    fn list_o_auth_2_claims_stream(
+        &self,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Claim> {
+        AuthorizationServers::list_o_auth_2_claims_stream(self, auth_server_id, start, max_items)
+    }
+
+    async fn create_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::OAuth2Claim,
+    ) -> Result<crate::types::OAuth2Claim> {
+        AuthorizationServers::create_o_auth_2_claim(self, auth_server_id, body).await
+    }
+
+    async fn get_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+    ) -> Result<crate::types::OAuth2Claim> {
+        AuthorizationServers::get_o_auth_2_claim(self, auth_server_id, claim_id).await
+    }
+
+    async fn update_o_auth_2_claim(
+        &self,
+        auth_server_id: &str,
+        claim_id: &str,
+        body: &crate::types::OAuth2Claim,
+    ) -> Result<crate::types::OAuth2Claim> {
+        AuthorizationServers::update_o_auth_2_claim(self, auth_server_id, claim_id, body).await
+    }
+
+    async fn delete_o_auth_2_claim(&self, auth_server_id: &str, claim_id: &str) -> Result<()> {
+        AuthorizationServers::delete_o_auth_2_claim(self, auth_server_id, claim_id).await
+    }
+
+    async fn list_o_auth_2_clients_fors(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Client>> {
+        AuthorizationServers::list_o_auth_2_clients_fors(self, auth_server_id).await
+    }
+
+    async fn list_all_o_auth_2_clients_fors(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::OAuth2Client>> {
+        AuthorizationServers::list_all_o_auth_2_clients_fors(self, auth_server_id).await
+    }
+
+    fn list_o_auth_2_clients_fors_stream(
+        &self,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Client> {
+        AuthorizationServers::list_o_auth_2_clients_fors_stream(
+            self,
+            auth_server_id,
+            start,
+            max_items,
+        )
+    }
+
+    async fn list_refresh_tokens_for_and_clients_with(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        params: &AuthorizationServersListRefreshTokensForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        AuthorizationServers::list_refresh_tokens_for_and_clients_with(
+            self,
+            auth_server_id,
+            client_id,
+            params,
+        )
+        .await
+    }
+
+    async fn list_all_refresh_tokens_for_and_clients_with(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        params: &AuthorizationServersListRefreshTokensForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        AuthorizationServers::list_all_refresh_tokens_for_and_clients_with(
+            self,
+            auth_server_id,
+            client_id,
+            params,
+        )
+        .await
+    }
+
+    fn list_refresh_tokens_for_and_clients_stream(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        params: &AuthorizationServersListRefreshTokensForAndClientsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2RefreshToken> {
+        AuthorizationServers::list_refresh_tokens_for_and_clients_stream(
+            self,
+            auth_server_id,
+            client_id,
+            params,
+            start,
+            max_items,
+        )
+    }
+
+    async fn revoke_refresh_tokens_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+    ) -> Result<()> {
+        AuthorizationServers::revoke_refresh_tokens_for_and_client(self, auth_server_id, client_id)
+            .await
+    }
+
+    async fn get_refresh_token_for_and_client_with(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+        params: &AuthorizationServersGetRefreshTokenForAndClientParams,
+    ) -> Result<crate::types::OAuth2RefreshToken> {
+        AuthorizationServers::get_refresh_token_for_and_client_with(
+            self,
+            auth_server_id,
+            client_id,
+            token_id,
+            params,
+        )
+        .await
+    }
+
+    async fn revoke_refresh_token_for_and_client(
+        &self,
+        auth_server_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<()> {
+        AuthorizationServers::revoke_refresh_token_for_and_client(
+            self,
+            auth_server_id,
+            client_id,
+            token_id,
+        )
+        .await
+    }
+
+    async fn list_keys(&self, auth_server_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+        AuthorizationServers::list_keys(self, auth_server_id).await
+    }
+
+    async fn list_all_keys(&self, auth_server_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+        AuthorizationServers::list_all_keys(self, auth_server_id).await
+    }
+
+    fn list_keys_stream(
+        &self,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JsonWebKey> {
+        AuthorizationServers::list_keys_stream(self, auth_server_id, start, max_items)
+    }
+
+    async fn rotate_keys(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::JwkUse,
+    ) -> Result<Vec<crate::types::JsonWebKey>> {
+        AuthorizationServers::rotate_keys(self, auth_server_id, body).await
+    }
+
+    async fn activate(&self, auth_server_id: &str) -> Result<()> {
+        AuthorizationServers::activate(self, auth_server_id).await
+    }
+
+    async fn deactivate(&self, auth_server_id: &str) -> Result<()> {
+        AuthorizationServers::deactivate(self, auth_server_id).await
+    }
+
+    async fn list_policies(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServerPolicy>> {
+        AuthorizationServers::list_policies(self, auth_server_id).await
+    }
+
+    async fn list_all_policies(
+        &self,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServerPolicy>> {
+        AuthorizationServers::list_all_policies(self, auth_server_id).await
+    }
+
+    fn list_policies_stream(
+        &self,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuthorizationServerPolicy> {
+        AuthorizationServers::list_policies_stream(self, auth_server_id, start, max_items)
+    }
+
+    async fn create_policy(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServerPolicy,
+    ) -> Result<crate::types::AuthorizationServerPolicy> {
+        AuthorizationServers::create_policy(self, auth_server_id, body).await
+    }
+
+    async fn get_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+    ) -> Result<crate::types::AuthorizationServerPolicy> {
+        AuthorizationServers::get_policy(self, auth_server_id, policy_id).await
+    }
+
+    async fn update_policy(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        body: &crate::types::AuthorizationServerPolicy,
+    ) -> Result<crate::types::AuthorizationServerPolicy> {
+        AuthorizationServers::update_policy(self, auth_server_id, policy_id, body).await
+    }
+
+    async fn delete_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()> {
+        AuthorizationServers::delete_policy(self, auth_server_id, policy_id).await
+    }
+
+    async fn activate_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()> {
+        AuthorizationServers::activate_policy(self, auth_server_id, policy_id).await
+    }
+
+    async fn deactivate_policy(&self, auth_server_id: &str, policy_id: &str) -> Result<()> {
+        AuthorizationServers::deactivate_policy(self, auth_server_id, policy_id).await
+    }
+
+    async fn list_policy_rules(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServerPolicyRule>> {
+        AuthorizationServers::list_policy_rules(self, policy_id, auth_server_id).await
+    }
+
+    async fn list_all_policy_rules(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+    ) -> Result<Vec<crate::types::AuthorizationServerPolicyRule>> {
+        AuthorizationServers::list_all_policy_rules(self, policy_id, auth_server_id).await
+    }
+
+    fn list_policy_rules_stream(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AuthorizationServerPolicyRule> {
+        AuthorizationServers::list_policy_rules_stream(
+            self,
+            policy_id,
+            auth_server_id,
+            start,
+            max_items,
+        )
+    }
+
+    async fn create_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        body: &crate::types::AuthorizationServerPolicyRule,
+    ) -> Result<crate::types::AuthorizationServerPolicyRule> {
+        AuthorizationServers::create_policy_rule(self, policy_id, auth_server_id, body).await
+    }
+
+    async fn get_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> Result<crate::types::AuthorizationServerPolicyRule> {
+        AuthorizationServers::get_policy_rule(self, policy_id, auth_server_id, rule_id).await
+    }
+
+    async fn update_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+        body: &crate::types::AuthorizationServerPolicyRule,
+    ) -> Result<crate::types::AuthorizationServerPolicyRule> {
+        AuthorizationServers::update_policy_rule(self, policy_id, auth_server_id, rule_id, body)
+            .await
+    }
+
+    async fn delete_policy_rule(
+        &self,
+        policy_id: &str,
+        auth_server_id: &str,
+        rule_id: &str,
+    ) -> Result<()> {
+        AuthorizationServers::delete_policy_rule(self, policy_id, auth_server_id, rule_id).await
+    }
+
+    async fn activate_policy_rule(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> Result<()> {
+        AuthorizationServers::activate_policy_rule(self, auth_server_id, policy_id, rule_id).await
+    }
+
+    async fn deactivate_policy_rule(
+        &self,
+        auth_server_id: &str,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> Result<()> {
+        AuthorizationServers::deactivate_policy_rule(self, auth_server_id, policy_id, rule_id).await
+    }
+
+    async fn list_o_auth_2_scopes_with(
+        &self,
+        auth_server_id: &str,
+        params: &AuthorizationServersListOAuth2ScopesParams,
+    ) -> Result<Vec<crate::types::OAuth2Scope>> {
+        AuthorizationServers::list_o_auth_2_scopes_with(self, auth_server_id, params).await
+    }
+
+    async fn list_all_o_auth_2_scopes_with(
+        &self,
+        auth_server_id: &str,
+        params: &AuthorizationServersListOAuth2ScopesParams,
+    ) -> Result<Vec<crate::types::OAuth2Scope>> {
+        AuthorizationServers::list_all_o_auth_2_scopes_with(self, auth_server_id, params).await
+    }
+
+    fn list_o_auth_2_scopes_stream(
+        &self,
+        auth_server_id: &str,
+        params: &AuthorizationServersListOAuth2ScopesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Scope> {
+        AuthorizationServers::list_o_auth_2_scopes_stream(
+            self,
+            auth_server_id,
+            params,
+            start,
+            max_items,
+        )
+    }
+
+    async fn create_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        body: &crate::types::OAuth2Scope,
+    ) -> Result<crate::types::OAuth2Scope> {
+        AuthorizationServers::create_o_auth_2_scope(self, auth_server_id, body).await
+    }
+
+    async fn get_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+    ) -> Result<crate::types::OAuth2Scope> {
+        AuthorizationServers::get_o_auth_2_scope(self, auth_server_id, scope_id).await
+    }
+
+    async fn update_o_auth_2_scope(
+        &self,
+        auth_server_id: &str,
+        scope_id: &str,
+        body: &crate::types::OAuth2Scope,
+    ) -> Result<crate::types::OAuth2Scope> {
+        AuthorizationServers::update_o_auth_2_scope(self, auth_server_id, scope_id, body).await
+    }
+
+    async fn delete_o_auth_2_scope(&self, auth_server_id: &str, scope_id: &str) -> Result<()> {
+        AuthorizationServers::delete_o_auth_2_scope(self, auth_server_id, scope_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Domains {
     pub client: Client,
@@ -124,3 +122,62 @@ impl Domains {
         self.client.post(&url, None).await
     }
 }
+
+/// The functions of [`Domains`], for code that takes them as a trait, so that it can be
+/// tested with the `MockDomainsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait DomainsApi: Send + Sync {
+    /// See [`Domains::list`].
+    async fn list(&self) -> Result<crate::types::DomainListResponse>;
+
+    /// See [`Domains::create`].
+    async fn create(&self, body: &crate::types::Domain) -> Result<crate::types::Domain>;
+
+    /// See [`Domains::get`].
+    async fn get(&self, domain_id: &str) -> Result<crate::types::Domain>;
+
+    /// See [`Domains::delete`].
+    async fn delete(&self, domain_id: &str) -> Result<()>;
+
+    /// See [`Domains::create_certificate`].
+    async fn create_certificate(
+        &self,
+        domain_id: &str,
+        body: &crate::types::DomainCertificate,
+    ) -> Result<()>;
+
+    /// See [`Domains::verify`].
+    async fn verify(&self, domain_id: &str) -> Result<crate::types::Domain>;
+}
+
+#[async_trait::async_trait]
+impl DomainsApi for Domains {
+    async fn list(&self) -> Result<crate::types::DomainListResponse> {
+        Domains::list(self).await
+    }
+
+    async fn create(&self, body: &crate::types::Domain) -> Result<crate::types::Domain> {
+        Domains::create(self, body).await
+    }
+
+    async fn get(&self, domain_id: &str) -> Result<crate::types::Domain> {
+        Domains::get(self, domain_id).await
+    }
+
+    async fn delete(&self, domain_id: &str) -> Result<()> {
+        Domains::delete(self, domain_id).await
+    }
+
+    async fn create_certificate(
+        &self,
+        domain_id: &str,
+        body: &crate::types::DomainCertificate,
+    ) -> Result<()> {
+        Domains::create_certificate(self, domain_id, body).await
+    }
+
+    async fn verify(&self, domain_id: &str) -> Result<crate::types::Domain> {
+        Domains::verify(self, domain_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EventHooks {
     pub client: Client,
@@ -34,6 +32,37 @@ impl EventHooks {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/eventHooks` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::EventHook> {
+        let url = "/api/v1/eventHooks".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/api/v1/eventHooks` endpoint.
      *
@@ -160,3 +189,98 @@ impl EventHooks {
         self.client.post(&url, None).await
     }
 }
+
+/// The functions of [`EventHooks`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEventHooksApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EventHooksApi: Send + Sync {
+    /// See [`EventHooks::list`].
+    async fn list(&self) -> Result<Vec<crate::types::EventHook>>;
+
+    /// See [`EventHooks::list_all`].
+    async fn list_all(&self) -> Result<Vec<crate::types::EventHook>>;
+
+    /// See [`EventHooks::list_stream`].
+    fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::EventHook>;
+
+    /// See [`EventHooks::create`].
+    async fn create(&self, body: &crate::types::EventHook) -> Result<crate::types::EventHook>;
+
+    /// See [`EventHooks::get`].
+    async fn get(&self, event_hook_id: &str) -> Result<crate::types::EventHook>;
+
+    /// See [`EventHooks::update`].
+    async fn update(
+        &self,
+        event_hook_id: &str,
+        body: &crate::types::EventHook,
+    ) -> Result<crate::types::EventHook>;
+
+    /// See [`EventHooks::delete`].
+    async fn delete(&self, event_hook_id: &str) -> Result<()>;
+
+    /// See [`EventHooks::activate`].
+    async fn activate(&self, event_hook_id: &str) -> Result<crate::types::EventHook>;
+
+    /// See [`EventHooks::deactivate`].
+    async fn deactivate(&self, event_hook_id: &str) -> Result<crate::types::EventHook>;
+
+    /// See [`EventHooks::verify`].
+    async fn verify(&self, event_hook_id: &str) -> Result<crate::types::EventHook>;
+}
+
+#[async_trait::async_trait]
+impl EventHooksApi for EventHooks {
+    async fn list(&self) -> Result<Vec<crate::types::EventHook>> {
+        EventHooks::list(self).await
+    }
+
+    async fn list_all(&self) -> Result<Vec<crate::types::EventHook>> {
+        EventHooks::list_all(self).await
+    }
+
+    fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::EventHook> {
+        EventHooks::list_stream(self, start, max_items)
+    }
+
+    async fn create(&self, body: &crate::types::EventHook) -> Result<crate::types::EventHook> {
+        EventHooks::create(self, body).await
+    }
+
+    async fn get(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
+        EventHooks::get(self, event_hook_id).await
+    }
+
+    async fn update(
+        &self,
+        event_hook_id: &str,
+        body: &crate::types::EventHook,
+    ) -> Result<crate::types::EventHook> {
+        EventHooks::update(self, event_hook_id, body).await
+    }
+
+    async fn delete(&self, event_hook_id: &str) -> Result<()> {
+        EventHooks::delete(self, event_hook_id).await
+    }
+
+    async fn activate(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
+        EventHooks::activate(self, event_hook_id).await
+    }
+
+    async fn deactivate(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
+        EventHooks::deactivate(self, event_hook_id).await
+    }
+
+    async fn verify(&self, event_hook_id: &str) -> Result<crate::types::EventHook> {
+        EventHooks::verify(self, event_hook_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Features {
     pub client: Client,
@@ -34,6 +32,37 @@ impl Features {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/features` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Feature> {
+        let url = "/api/v1/features".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/features/{featureId}` endpoint.
      *
@@ -89,6 +118,42 @@ impl Features {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependencies` endpoint.
+     *
+     * As opposed to `list_all_dependencies`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_dependencies_stream(
+        &self,
+        feature_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Feature> {
+        let url = format!(
+            "/api/v1/features/{}/dependencies",
+            crate::progenitor_support::encode_path(feature_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/features/{featureId}/dependents` endpoint.
      *
@@ -126,6 +191,42 @@ impl Features {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/features/{featureId}/dependents` endpoint.
+     *
+     * As opposed to `list_all_dependents`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_dependents_stream(
+        &self,
+        feature_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Feature> {
+        let url = format!(
+            "/api/v1/features/{}/dependents",
+            crate::progenitor_support::encode_path(feature_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/api/v1/features/{featureId}/{lifecycle}` endpoint.
      *
@@ -135,16 +236,16 @@ impl Features {
      *
      * * `feature_id: &str`
      * * `lifecycle: &str`
-     * * `mode: &str`
+     * * `params: &FeaturesUpdateLifecycleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update_lifecycle(
+    pub async fn update_lifecycle_with(
         &self,
         feature_id: &str,
         lifecycle: &str,
-        mode: &str,
+        params: &FeaturesUpdateLifecycleParams,
     ) -> Result<crate::types::Feature> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !mode.is_empty() {
+        if let Some(mode) = &params.mode {
             query_args.push(("mode".to_string(), mode.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -157,4 +258,158 @@ impl Features {
 
         self.client.post(&url, None).await
     }
+
+    /**
+     * Deprecated: use `update_lifecycle_with` and pass the query parameters as a `FeaturesUpdateLifecycleParams` instead.
+     */
+    #[deprecated(note = "use `update_lifecycle_with` instead")]
+    pub async fn update_lifecycle(
+        &self,
+        feature_id: &str,
+        lifecycle: &str,
+        mode: &str,
+    ) -> Result<crate::types::Feature> {
+        let params = FeaturesUpdateLifecycleParams {
+            mode: Some(mode.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.update_lifecycle_with(feature_id, lifecycle, &params)
+            .await
+    }
+}
+
+/// The query parameters of `Features::update_lifecycle_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FeaturesUpdateLifecycleParams {
+    pub mode: Option<String>,
+}
+
+impl FeaturesUpdateLifecycleParams {
+    pub fn mode<T: ToString>(mut self, mode: T) -> Self {
+        self.mode = Some(mode.to_string());
+        self
+    }
+}
+
+/// The functions of [`Features`], for code that takes them as a trait, so that it can be
+/// tested with the `MockFeaturesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait FeaturesApi: Send + Sync {
+    /// See [`Features::list`].
+    async fn list(&self) -> Result<Vec<crate::types::Feature>>;
+
+    /// See [`Features::list_all`].
+    async fn list_all(&self) -> Result<Vec<crate::types::Feature>>;
+
+    /// See [`Features::list_stream`].
+    fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Feature>;
+
+    /// See [`Features::get`].
+    async fn get(&self, feature_id: &str) -> Result<crate::types::Feature>;
+
+    /// See [`Features::list_dependencies`].
+    async fn list_dependencies(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>>;
+
+    /// See [`Features::list_all_dependencies`].
+    async fn list_all_dependencies(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>>;
+
+    /// See [`Features::list_dependencies_stream`].
+    fn list_dependencies_stream(
+        &self,
+        feature_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Feature>;
+
+    /// See [`Features::list_dependents`].
+    async fn list_dependents(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>>;
+
+    /// See [`Features::list_all_dependents`].
+    async fn list_all_dependents(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>>;
+
+    /// See [`Features::list_dependents_stream`].
+    fn list_dependents_stream(
+        &self,
+        feature_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Feature>;
+
+    /// See [`Features::update_lifecycle_with`].
+    async fn update_lifecycle_with(
+        &self,
+        feature_id: &str,
+        lifecycle: &str,
+        params: &FeaturesUpdateLifecycleParams,
+    ) -> Result<crate::types::Feature>;
+}
+
+#[async_trait::async_trait]
+impl FeaturesApi for Features {
+    async fn list(&self) -> Result<Vec<crate::types::Feature>> {
+        Features::list(self).await
+    }
+
+    async fn list_all(&self) -> Result<Vec<crate::types::Feature>> {
+        Features::list_all(self).await
+    }
+
+    fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Feature> {
+        Features::list_stream(self, start, max_items)
+    }
+
+    async fn get(&self, feature_id: &str) -> Result<crate::types::Feature> {
+        Features::get(self, feature_id).await
+    }
+
+    async fn list_dependencies(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>> {
+        Features::list_dependencies(self, feature_id).await
+    }
+
+    async fn list_all_dependencies(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>> {
+        Features::list_all_dependencies(self, feature_id).await
+    }
+
+    fn list_dependencies_stream(
+        &self,
+        feature_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Feature> {
+        Features::list_dependencies_stream(self, feature_id, start, max_items)
+    }
+
+    async fn list_dependents(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>> {
+        Features::list_dependents(self, feature_id).await
+    }
+
+    async fn list_all_dependents(&self, feature_id: &str) -> Result<Vec<crate::types::Feature>> {
+        Features::list_all_dependents(self, feature_id).await
+    }
+
+    fn list_dependents_stream(
+        &self,
+        feature_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Feature> {
+        Features::list_dependents_stream(self, feature_id, start, max_items)
+    }
+
+    async fn update_lifecycle_with(
+        &self,
+        feature_id: &str,
+        lifecycle: &str,
+        params: &FeaturesUpdateLifecycleParams,
+    ) -> Result<crate::types::Feature> {
+        Features::update_lifecycle_with(self, feature_id, lifecycle, params).await
+    }
 }
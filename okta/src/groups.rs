@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Groups {
     pub client: Client,
@@ -21,34 +19,23 @@ impl Groups {
      *
      * **Parameters:**
      *
-     * * `q: &str` -- Searches the name property of groups for matching value.
-     * * `search: &str` -- Filter expression for groups.
-     * * `after: &str` -- Specifies the pagination cursor for the next page of groups.
-     * * `limit: i64` -- Specifies the number of group results in a page.
-     * * `expand: &str` -- If specified, it causes additional metadata to be included in the response.
+     * * `params: &GroupsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
-        &self,
-        q: &str,
-        search: &str,
-        after: &str,
-        limit: i64,
-        expand: &str,
-    ) -> Result<Vec<crate::types::Group>> {
+    pub async fn list_with(&self, params: &GroupsListParams) -> Result<Vec<crate::types::Group>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !search.is_empty() {
+        if let Some(search) = &params.search {
             query_args.push(("search".to_string(), search.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -57,6 +44,28 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `GroupsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        q: &str,
+        search: &str,
+        after: &str,
+        limit: i64,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Group>> {
+        let params = GroupsListParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            search: Some(search.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * List Groups.
      *
@@ -65,27 +74,94 @@ impl Groups {
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Enumerates groups in your organization with pagination. A subset of groups can be returned that match a supported filter expression or query.
+     *
+     * **Parameters:**
+     *
+     * * `params: &GroupsListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(
+        &self,
+        params: &GroupsListParams,
+    ) -> Result<Vec<crate::types::Group>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(search) = &params.search {
+            query_args.push(("search".to_string(), search.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/api/v1/groups?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `GroupsListParams` instead.
      */
+    #[deprecated(note = "use `list_all_with` instead")]
     pub async fn list_all(
         &self,
         q: &str,
         search: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Group>> {
+        let params = GroupsListParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            search: Some(search.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * List Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/groups` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates groups in your organization with pagination. A subset of groups can be returned that match a supported filter expression or query.
+     */
+    pub fn list_stream(
+        &self,
+        params: &GroupsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !search.is_empty() {
+        if let Some(search) = &params.search {
             query_args.push(("search".to_string(), search.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/api/v1/groups?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -111,29 +187,23 @@ impl Groups {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- Specifies the number of rule results in a page.
-     * * `after: &str` -- Specifies the pagination cursor for the next page of rules.
-     * * `search: &str` -- Specifies the keyword to search fules for.
-     * * `expand: &str` -- If specified as `groupIdToGroupNameMap`, then show group names.
+     * * `params: &GroupsListRulesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_rules(
+    pub async fn list_rules_with(
         &self,
-        limit: i64,
-        after: &str,
-        search: &str,
-        expand: &str,
+        params: &GroupsListRulesParams,
     ) -> Result<Vec<crate::types::GroupRule>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !search.is_empty() {
+        if let Some(search) = &params.search {
             query_args.push(("search".to_string(), search.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -142,6 +212,26 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_rules_with` and pass the query parameters as a `GroupsListRulesParams` instead.
+     */
+    #[deprecated(note = "use `list_rules_with` instead")]
+    pub async fn list_rules(
+        &self,
+        limit: i64,
+        after: &str,
+        search: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::GroupRule>> {
+        let params = GroupsListRulesParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            search: Some(search.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_rules_with(&params).await
+    }
+
     /**
      * List Group Rules.
      *
@@ -150,23 +240,86 @@ impl Groups {
      * As opposed to `list_rules`, this function returns all the pages of the request at once.
      *
      * Lists all group rules for your organization.
+     *
+     * **Parameters:**
+     *
+     * * `params: &GroupsListRulesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_rules_with(
+        &self,
+        params: &GroupsListRulesParams,
+    ) -> Result<Vec<crate::types::GroupRule>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if let Some(search) = &params.search {
+            query_args.push(("search".to_string(), search.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/api/v1/groups/rules?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_rules_with` and pass the query parameters as a `GroupsListRulesParams` instead.
      */
+    #[deprecated(note = "use `list_all_rules_with` instead")]
     pub async fn list_all_rules(
         &self,
         search: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::GroupRule>> {
+        let params = GroupsListRulesParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            search: Some(search.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_rules_with(&params).await
+    }
+
+    /**
+     * List Group Rules.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/rules` endpoint.
+     *
+     * As opposed to `list_all_rules`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all group rules for your organization.
+     */
+    pub fn list_rules_stream(
+        &self,
+        params: &GroupsListRulesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GroupRule> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if !search.is_empty() {
+        if let Some(search) = &params.search {
             query_args.push(("search".to_string(), search.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/api/v1/groups/rules?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -196,11 +349,15 @@ impl Groups {
      * **Parameters:**
      *
      * * `rule_id: &str`
-     * * `expand: &str`
+     * * `params: &GroupsGetRuleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_rule(&self, rule_id: &str, expand: &str) -> Result<crate::types::GroupRule> {
+    pub async fn get_rule_with(
+        &self,
+        rule_id: &str,
+        params: &GroupsGetRuleParams,
+    ) -> Result<crate::types::GroupRule> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -213,6 +370,17 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_rule_with` and pass the query parameters as a `GroupsGetRuleParams` instead.
+     */
+    #[deprecated(note = "use `get_rule_with` instead")]
+    pub async fn get_rule(&self, rule_id: &str, expand: &str) -> Result<crate::types::GroupRule> {
+        let params = GroupsGetRuleParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_rule_with(rule_id, &params).await
+    }
+
     /**
      * This function performs a `PUT` to the `/api/v1/groups/rules/{ruleId}` endpoint.
      *
@@ -247,11 +415,15 @@ impl Groups {
      * **Parameters:**
      *
      * * `rule_id: &str`
-     * * `remove_users: bool` -- Indicates whether to keep or remove users from groups assigned by this rule.
+     * * `params: &GroupsDeleteRuleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_rule(&self, rule_id: &str, remove_users: bool) -> Result<()> {
+    pub async fn delete_rule_with(
+        &self,
+        rule_id: &str,
+        params: &GroupsDeleteRuleParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if remove_users {
+        if let Some(remove_users) = &params.remove_users {
             query_args.push(("removeUsers".to_string(), remove_users.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -264,6 +436,17 @@ impl Groups {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_rule_with` and pass the query parameters as a `GroupsDeleteRuleParams` instead.
+     */
+    #[deprecated(note = "use `delete_rule_with` instead")]
+    pub async fn delete_rule(&self, rule_id: &str, remove_users: bool) -> Result<()> {
+        let params = GroupsDeleteRuleParams {
+            remove_users: Some(remove_users).filter(|v| *v),
+        };
+        self.delete_rule_with(rule_id, &params).await
+    }
+
     /**
      * Activate a group Rule.
      *
@@ -380,20 +563,18 @@ impl Groups {
      * **Parameters:**
      *
      * * `group_id: &str`
-     * * `after: &str` -- Specifies the pagination cursor for the next page of apps.
-     * * `limit: i64` -- Specifies the number of app results for a page.
+     * * `params: &GroupsListAssignedApplicationsForsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_assigned_applications_fors(
+    pub async fn list_assigned_applications_fors_with(
         &self,
         group_id: &str,
-        after: &str,
-        limit: i64,
+        params: &GroupsListAssignedApplicationsForsParams,
     ) -> Result<Vec<crate::types::Application>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -406,6 +587,24 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_assigned_applications_fors_with` and pass the query parameters as a `GroupsListAssignedApplicationsForsParams` instead.
+     */
+    #[deprecated(note = "use `list_assigned_applications_fors_with` instead")]
+    pub async fn list_assigned_applications_fors(
+        &self,
+        group_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Application>> {
+        let params = GroupsListAssignedApplicationsForsParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_assigned_applications_fors_with(group_id, &params)
+            .await
+    }
+
     /**
      * List Assigned Applications.
      *
@@ -427,6 +626,44 @@ impl Groups {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List Assigned Applications.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/apps` endpoint.
+     *
+     * As opposed to `list_all_assigned_applications_fors`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates all applications that are assigned to a group.
+     */
+    pub fn list_assigned_applications_fors_stream(
+        &self,
+        group_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Application> {
+        let url = format!(
+            "/api/v1/groups/{}/apps",
+            crate::progenitor_support::encode_path(group_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
      *
@@ -435,15 +672,15 @@ impl Groups {
      * **Parameters:**
      *
      * * `group_id: &str`
-     * * `expand: &str`
+     * * `params: &GroupsListAssignedRolesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_assigned_roles(
+    pub async fn list_assigned_roles_with(
         &self,
         group_id: &str,
-        expand: &str,
+        params: &GroupsListAssignedRolesParams,
     ) -> Result<Vec<crate::types::Role>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -456,20 +693,82 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_assigned_roles_with` and pass the query parameters as a `GroupsListAssignedRolesParams` instead.
+     */
+    #[deprecated(note = "use `list_assigned_roles_with` instead")]
+    pub async fn list_assigned_roles(
+        &self,
+        group_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Role>> {
+        let params = GroupsListAssignedRolesParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_assigned_roles_with(group_id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
      *
      * As opposed to `list_assigned_roles`, this function returns all the pages of the request at once.
      *
      * Success
+     *
+     * **Parameters:**
+     *
+     * * `params: &GroupsListAssignedRolesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_assigned_roles_with(
+        &self,
+        group_id: &str,
+        params: &GroupsListAssignedRolesParams,
+    ) -> Result<Vec<crate::types::Role>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/api/v1/groups/{}/roles?{}",
+            crate::progenitor_support::encode_path(group_id),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_assigned_roles_with` and pass the query parameters as a `GroupsListAssignedRolesParams` instead.
      */
+    #[deprecated(note = "use `list_all_assigned_roles_with` instead")]
     pub async fn list_all_assigned_roles(
         &self,
         group_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Role>> {
+        let params = GroupsListAssignedRolesParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_all_assigned_roles_with(group_id, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles` endpoint.
+     *
+     * As opposed to `list_all_assigned_roles`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_assigned_roles_stream(
+        &self,
+        group_id: &str,
+        params: &GroupsListAssignedRolesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Role> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -479,7 +778,22 @@ impl Groups {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -490,16 +804,16 @@ impl Groups {
      * **Parameters:**
      *
      * * `group_id: &str`
-     * * `disable_notifications: &str`
+     * * `params: &GroupsAssignRoleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn assign_role(
+    pub async fn assign_role_with(
         &self,
         group_id: &str,
-        disable_notifications: &str,
+        params: &GroupsAssignRoleParams,
         body: &crate::types::AssignRoleRequest,
     ) -> Result<crate::types::Role> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !disable_notifications.is_empty() {
+        if let Some(disable_notifications) = &params.disable_notifications {
             query_args.push((
                 "disableNotifications".to_string(),
                 disable_notifications.to_string(),
@@ -517,6 +831,23 @@ impl Groups {
             .await
     }
 
+    /**
+     * Deprecated: use `assign_role_with` and pass the query parameters as a `GroupsAssignRoleParams` instead.
+     */
+    #[deprecated(note = "use `assign_role_with` instead")]
+    pub async fn assign_role(
+        &self,
+        group_id: &str,
+        disable_notifications: &str,
+        body: &crate::types::AssignRoleRequest,
+    ) -> Result<crate::types::Role> {
+        let params = GroupsAssignRoleParams {
+            disable_notifications: Some(disable_notifications.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.assign_role_with(group_id, &params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}` endpoint.
      *
@@ -566,21 +897,19 @@ impl Groups {
      *
      * * `group_id: &str`
      * * `role_id: &str`
-     * * `after: &str`
-     * * `limit: i64`
+     * * `params: &GroupsListApplicationTargetsForAdministratorRolesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_application_targets_for_administrator_roles(
+    pub async fn list_application_targets_for_administrator_roles_with(
         &self,
         group_id: &str,
         role_id: &str,
-        after: &str,
-        limit: i64,
+        params: &GroupsListApplicationTargetsForAdministratorRolesParams,
     ) -> Result<Vec<crate::types::CatalogApplication>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -594,6 +923,25 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_application_targets_for_administrator_roles_with` and pass the query parameters as a `GroupsListApplicationTargetsForAdministratorRolesParams` instead.
+     */
+    #[deprecated(note = "use `list_application_targets_for_administrator_roles_with` instead")]
+    pub async fn list_application_targets_for_administrator_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::CatalogApplication>> {
+        let params = GroupsListApplicationTargetsForAdministratorRolesParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_application_targets_for_administrator_roles_with(group_id, role_id, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps` endpoint.
      *
@@ -615,6 +963,44 @@ impl Groups {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_all_application_targets_for_administrator_roles`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all App targets for an `APP_ADMIN` Role assigned to a Group. This methods return list may include full Applications or Instances. The response for an instance will have an `ID` value, while Application will not have an ID.
+     */
+    pub fn list_application_targets_for_administrator_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CatalogApplication> {
+        let url = format!(
+            "/api/v1/groups/{}/roles/{}/targets/catalog/apps",
+            crate::progenitor_support::encode_path(group_id),
+            crate::progenitor_support::encode_path(role_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/catalog/apps/{appName}` endpoint.
      *
@@ -742,21 +1128,19 @@ impl Groups {
      *
      * * `group_id: &str`
      * * `role_id: &str`
-     * * `after: &str`
-     * * `limit: i64`
+     * * `params: &GroupsListTargetsForRolesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_targets_for_roles(
+    pub async fn list_targets_for_roles_with(
         &self,
         group_id: &str,
         role_id: &str,
-        after: &str,
-        limit: i64,
+        params: &GroupsListTargetsForRolesParams,
     ) -> Result<Vec<crate::types::Group>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -770,6 +1154,25 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_targets_for_roles_with` and pass the query parameters as a `GroupsListTargetsForRolesParams` instead.
+     */
+    #[deprecated(note = "use `list_targets_for_roles_with` instead")]
+    pub async fn list_targets_for_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Group>> {
+        let params = GroupsListTargetsForRolesParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_targets_for_roles_with(group_id, role_id, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups` endpoint.
      *
@@ -792,13 +1195,51 @@ impl Groups {
     }
 
     /**
-     * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}` endpoint.
-     *
-     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `list_all_targets_for_roles`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
      *
-     * * `group_id: &str`
+     * Success
+     */
+    pub fn list_targets_for_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group> {
+        let url = format!(
+            "/api/v1/groups/{}/roles/{}/targets/groups",
+            crate::progenitor_support::encode_path(group_id),
+            crate::progenitor_support::encode_path(role_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * This function performs a `PUT` to the `/api/v1/groups/{groupId}/roles/{roleId}/targets/groups/{targetGroupId}` endpoint.
+     *
+     *
+     *
+     * **Parameters:**
+     *
+     * * `group_id: &str`
      * * `role_id: &str`
      * * `target_group_id: &str`
      */
@@ -855,20 +1296,18 @@ impl Groups {
      * **Parameters:**
      *
      * * `group_id: &str`
-     * * `after: &str` -- Specifies the pagination cursor for the next page of users.
-     * * `limit: i64` -- Specifies the number of user results in a page.
+     * * `params: &GroupsListUsersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_users(
+    pub async fn list_users_with(
         &self,
         group_id: &str,
-        after: &str,
-        limit: i64,
+        params: &GroupsListUsersParams,
     ) -> Result<Vec<crate::types::User>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -881,6 +1320,23 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_users_with` and pass the query parameters as a `GroupsListUsersParams` instead.
+     */
+    #[deprecated(note = "use `list_users_with` instead")]
+    pub async fn list_users(
+        &self,
+        group_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::User>> {
+        let params = GroupsListUsersParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_users_with(group_id, &params).await
+    }
+
     /**
      * List Group Members.
      *
@@ -899,6 +1355,44 @@ impl Groups {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List Group Members.
+     *
+     * This function performs a `GET` to the `/api/v1/groups/{groupId}/users` endpoint.
+     *
+     * As opposed to `list_all_users`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates all users that are a member of a group.
+     */
+    pub fn list_users_stream(
+        &self,
+        group_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::User> {
+        let url = format!(
+            "/api/v1/groups/{}/users",
+            crate::progenitor_support::encode_path(group_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Add User to Group.
      *
@@ -943,3 +1437,804 @@ impl Groups {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Groups::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsListParams {
+    /// Specifies the pagination cursor for the next page of groups.
+    pub after: Option<String>,
+    /// If specified, it causes additional metadata to be included in the response.
+    pub expand: Option<String>,
+    /// Specifies the number of group results in a page.
+    pub limit: Option<i64>,
+    /// Searches the name property of groups for matching value.
+    pub q: Option<String>,
+    /// Filter expression for groups.
+    pub search: Option<String>,
+}
+
+impl GroupsListParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn search<T: ToString>(mut self, search: T) -> Self {
+        self.search = Some(search.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Groups::list_rules_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsListRulesParams {
+    /// Specifies the pagination cursor for the next page of rules.
+    pub after: Option<String>,
+    /// If specified as `groupIdToGroupNameMap`, then show group names.
+    pub expand: Option<String>,
+    /// Specifies the number of rule results in a page.
+    pub limit: Option<i64>,
+    /// Specifies the keyword to search fules for.
+    pub search: Option<String>,
+}
+
+impl GroupsListRulesParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn search<T: ToString>(mut self, search: T) -> Self {
+        self.search = Some(search.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Groups::get_rule_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsGetRuleParams {
+    pub expand: Option<String>,
+}
+
+impl GroupsGetRuleParams {
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Groups::delete_rule_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsDeleteRuleParams {
+    /// Indicates whether to keep or remove users from groups assigned by this rule.
+    pub remove_users: Option<bool>,
+}
+
+impl GroupsDeleteRuleParams {
+    pub fn remove_users(mut self, remove_users: bool) -> Self {
+        self.remove_users = Some(remove_users);
+        self
+    }
+}
+
+/// The query parameters of `Groups::list_assigned_applications_fors_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsListAssignedApplicationsForsParams {
+    /// Specifies the pagination cursor for the next page of apps.
+    pub after: Option<String>,
+    /// Specifies the number of app results for a page.
+    pub limit: Option<i64>,
+}
+
+impl GroupsListAssignedApplicationsForsParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `Groups::list_assigned_roles_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsListAssignedRolesParams {
+    pub expand: Option<String>,
+}
+
+impl GroupsListAssignedRolesParams {
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Groups::assign_role_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsAssignRoleParams {
+    pub disable_notifications: Option<String>,
+}
+
+impl GroupsAssignRoleParams {
+    pub fn disable_notifications<T: ToString>(mut self, disable_notifications: T) -> Self {
+        self.disable_notifications = Some(disable_notifications.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Groups::list_application_targets_for_administrator_roles_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsListApplicationTargetsForAdministratorRolesParams {
+    pub after: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GroupsListApplicationTargetsForAdministratorRolesParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `Groups::list_targets_for_roles_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsListTargetsForRolesParams {
+    pub after: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl GroupsListTargetsForRolesParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `Groups::list_users_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsListUsersParams {
+    /// Specifies the pagination cursor for the next page of users.
+    pub after: Option<String>,
+    /// Specifies the number of user results in a page.
+    pub limit: Option<i64>,
+}
+
+impl GroupsListUsersParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The functions of [`Groups`], for code that takes them as a trait, so that it can be
+/// tested with the `MockGroupsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait GroupsApi: Send + Sync {
+    /// See [`Groups::list_with`].
+    async fn list_with(&self, params: &GroupsListParams) -> Result<Vec<crate::types::Group>>;
+
+    /// See [`Groups::list_all_with`].
+    async fn list_all_with(&self, params: &GroupsListParams) -> Result<Vec<crate::types::Group>>;
+
+    /// See [`Groups::list_stream`].
+    fn list_stream(
+        &self,
+        params: &GroupsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group>;
+
+    /// See [`Groups::create`].
+    async fn create(&self, body: &crate::types::Group) -> Result<crate::types::Group>;
+
+    /// See [`Groups::list_rules_with`].
+    async fn list_rules_with(
+        &self,
+        params: &GroupsListRulesParams,
+    ) -> Result<Vec<crate::types::GroupRule>>;
+
+    /// See [`Groups::list_all_rules_with`].
+    async fn list_all_rules_with(
+        &self,
+        params: &GroupsListRulesParams,
+    ) -> Result<Vec<crate::types::GroupRule>>;
+
+    /// See [`Groups::list_rules_stream`].
+    fn list_rules_stream(
+        &self,
+        params: &GroupsListRulesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GroupRule>;
+
+    /// See [`Groups::create_rule`].
+    async fn create_rule(&self, body: &crate::types::GroupRule) -> Result<crate::types::GroupRule>;
+
+    /// See [`Groups::get_rule_with`].
+    async fn get_rule_with(
+        &self,
+        rule_id: &str,
+        params: &GroupsGetRuleParams,
+    ) -> Result<crate::types::GroupRule>;
+
+    /// See [`Groups::update_rule`].
+    async fn update_rule(
+        &self,
+        rule_id: &str,
+        body: &crate::types::GroupRule,
+    ) -> Result<crate::types::GroupRule>;
+
+    /// See [`Groups::delete_rule_with`].
+    async fn delete_rule_with(&self, rule_id: &str, params: &GroupsDeleteRuleParams) -> Result<()>;
+
+    /// See [`Groups::activate_rule`].
+    async fn activate_rule(&self, rule_id: &str) -> Result<()>;
+
+    /// See [`Groups::deactivate_rule`].
+    async fn deactivate_rule(&self, rule_id: &str) -> Result<()>;
+
+    /// See [`Groups::get`].
+    async fn get(&self, group_id: &str) -> Result<crate::types::Group>;
+
+    /// See [`Groups::update`].
+    async fn update(
+        &self,
+        group_id: &str,
+        body: &crate::types::Group,
+    ) -> Result<crate::types::Group>;
+
+    /// See [`Groups::delete`].
+    async fn delete(&self, group_id: &str) -> Result<()>;
+
+    /// See [`Groups::list_assigned_applications_fors_with`].
+    async fn list_assigned_applications_fors_with(
+        &self,
+        group_id: &str,
+        params: &GroupsListAssignedApplicationsForsParams,
+    ) -> Result<Vec<crate::types::Application>>;
+
+    /// See [`Groups::list_all_assigned_applications_fors`].
+    async fn list_all_assigned_applications_fors(
+        &self,
+        group_id: &str,
+    ) -> Result<Vec<crate::types::Application>>;
+
+    /// See [`Groups::list_assigned_applications_fors_stream`].
+    fn list_assigned_applications_fors_stream(
+        &self,
+        group_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Application>;
+
+    /// See [`Groups::list_assigned_roles_with`].
+    async fn list_assigned_roles_with(
+        &self,
+        group_id: &str,
+        params: &GroupsListAssignedRolesParams,
+    ) -> Result<Vec<crate::types::Role>>;
+
+    /// See [`Groups::list_all_assigned_roles_with`].
+    async fn list_all_assigned_roles_with(
+        &self,
+        group_id: &str,
+        params: &GroupsListAssignedRolesParams,
+    ) -> Result<Vec<crate::types::Role>>;
+
+    /// See [`Groups::list_assigned_roles_stream`].
+    fn list_assigned_roles_stream(
+        &self,
+        group_id: &str,
+        params: &GroupsListAssignedRolesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Role>;
+
+    /// See [`Groups::assign_role_with`].
+    async fn assign_role_with(
+        &self,
+        group_id: &str,
+        params: &GroupsAssignRoleParams,
+        body: &crate::types::AssignRoleRequest,
+    ) -> Result<crate::types::Role>;
+
+    /// See [`Groups::get_role`].
+    async fn get_role(&self, group_id: &str, role_id: &str) -> Result<crate::types::Role>;
+
+    /// See [`Groups::remove_role_from`].
+    async fn remove_role_from(&self, group_id: &str, role_id: &str) -> Result<()>;
+
+    /// See [`Groups::list_application_targets_for_administrator_roles_with`].
+    async fn list_application_targets_for_administrator_roles_with(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        params: &GroupsListApplicationTargetsForAdministratorRolesParams,
+    ) -> Result<Vec<crate::types::CatalogApplication>>;
+
+    /// See [`Groups::list_all_application_targets_for_administrator_roles`].
+    async fn list_all_application_targets_for_administrator_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::CatalogApplication>>;
+
+    /// See [`Groups::list_application_targets_for_administrator_roles_stream`].
+    fn list_application_targets_for_administrator_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CatalogApplication>;
+
+    /// See [`Groups::add_application_target_admin_role_given`].
+    async fn add_application_target_admin_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()>;
+
+    /// See [`Groups::remove_application_target_from_administrator_role_given`].
+    async fn remove_application_target_from_administrator_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()>;
+
+    /// See [`Groups::add_application_instance_target_app_admin_role_given`].
+    async fn add_application_instance_target_app_admin_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Groups::remove_application_target_from_administrator_role_given_groups`].
+    async fn remove_application_target_from_administrator_role_given_groups(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Groups::list_targets_for_roles_with`].
+    async fn list_targets_for_roles_with(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        params: &GroupsListTargetsForRolesParams,
+    ) -> Result<Vec<crate::types::Group>>;
+
+    /// See [`Groups::list_all_targets_for_roles`].
+    async fn list_all_targets_for_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::Group>>;
+
+    /// See [`Groups::list_targets_for_roles_stream`].
+    fn list_targets_for_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group>;
+
+    /// See [`Groups::add_target_administrator_role_for`].
+    async fn add_target_administrator_role_for(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Groups::remove_target_from_administrator_role_given`].
+    async fn remove_target_from_administrator_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Groups::list_users_with`].
+    async fn list_users_with(
+        &self,
+        group_id: &str,
+        params: &GroupsListUsersParams,
+    ) -> Result<Vec<crate::types::User>>;
+
+    /// See [`Groups::list_all_users`].
+    async fn list_all_users(&self, group_id: &str) -> Result<Vec<crate::types::User>>;
+
+    /// See [`Groups::list_users_stream`].
+    fn list_users_stream(
+        &self,
+        group_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::User>;
+
+    /// See [`Groups::add_user`].
+    async fn add_user(&self, group_id: &str, user_id: &str) -> Result<()>;
+
+    /// See [`Groups::remove_user_from`].
+    async fn remove_user_from(&self, group_id: &str, user_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl GroupsApi for Groups {
+    async fn list_with(&self, params: &GroupsListParams) -> Result<Vec<crate::types::Group>> {
+        Groups::list_with(self, params).await
+    }
+
+    async fn list_all_with(&self, params: &GroupsListParams) -> Result<Vec<crate::types::Group>> {
+        Groups::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &GroupsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group> {
+        Groups::list_stream(self, params, start, max_items)
+    }
+
+    async fn create(&self, body: &crate::types::Group) -> Result<crate::types::Group> {
+        Groups::create(self, body).await
+    }
+
+    async fn list_rules_with(
+        &self,
+        params: &GroupsListRulesParams,
+    ) -> Result<Vec<crate::types::GroupRule>> {
+        Groups::list_rules_with(self, params).await
+    }
+
+    async fn list_all_rules_with(
+        &self,
+        params: &GroupsListRulesParams,
+    ) -> Result<Vec<crate::types::GroupRule>> {
+        Groups::list_all_rules_with(self, params).await
+    }
+
+    fn list_rules_stream(
+        &self,
+        params: &GroupsListRulesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GroupRule> {
+        Groups::list_rules_stream(self, params, start, max_items)
+    }
+
+    async fn create_rule(&self, body: &crate::types::GroupRule) -> Result<crate::types::GroupRule> {
+        Groups::create_rule(self, body).await
+    }
+
+    async fn get_rule_with(
+        &self,
+        rule_id: &str,
+        params: &GroupsGetRuleParams,
+    ) -> Result<crate::types::GroupRule> {
+        Groups::get_rule_with(self, rule_id, params).await
+    }
+
+    async fn update_rule(
+        &self,
+        rule_id: &str,
+        body: &crate::types::GroupRule,
+    ) -> Result<crate::types::GroupRule> {
+        Groups::update_rule(self, rule_id, body).await
+    }
+
+    async fn delete_rule_with(&self, rule_id: &str, params: &GroupsDeleteRuleParams) -> Result<()> {
+        Groups::delete_rule_with(self, rule_id, params).await
+    }
+
+    async fn activate_rule(&self, rule_id: &str) -> Result<()> {
+        Groups::activate_rule(self, rule_id).await
+    }
+
+    async fn deactivate_rule(&self, rule_id: &str) -> Result<()> {
+        Groups::deactivate_rule(self, rule_id).await
+    }
+
+    async fn get(&self, group_id: &str) -> Result<crate::types::Group> {
+        Groups::get(self, group_id).await
+    }
+
+    async fn update(
+        &self,
+        group_id: &str,
+        body: &crate::types::Group,
+    ) -> Result<crate::types::Group> {
+        Groups::update(self, group_id, body).await
+    }
+
+    async fn delete(&self, group_id: &str) -> Result<()> {
+        Groups::delete(self, group_id).await
+    }
+
+    async fn list_assigned_applications_fors_with(
+        &self,
+        group_id: &str,
+        params: &GroupsListAssignedApplicationsForsParams,
+    ) -> Result<Vec<crate::types::Application>> {
+        Groups::list_assigned_applications_fors_with(self, group_id, params).await
+    }
+
+    async fn list_all_assigned_applications_fors(
+        &self,
+        group_id: &str,
+    ) -> Result<Vec<crate::types::Application>> {
+        Groups::list_all_assigned_applications_fors(self, group_id).await
+    }
+
+    fn list_assigned_applications_fors_stream(
+        &self,
+        group_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Application> {
+        Groups::list_assigned_applications_fors_stream(self, group_id, start, max_items)
+    }
+
+    async fn list_assigned_roles_with(
+        &self,
+        group_id: &str,
+        params: &GroupsListAssignedRolesParams,
+    ) -> Result<Vec<crate::types::Role>> {
+        Groups::list_assigned_roles_with(self, group_id, params).await
+    }
+
+    async fn list_all_assigned_roles_with(
+        &self,
+        group_id: &str,
+        params: &GroupsListAssignedRolesParams,
+    ) -> Result<Vec<crate::types::Role>> {
+        Groups::list_all_assigned_roles_with(self, group_id, params).await
+    }
+
+    fn list_assigned_roles_stream(
+        &self,
+        group_id: &str,
+        params: &GroupsListAssignedRolesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Role> {
+        Groups::list_assigned_roles_stream(self, group_id, params, start, max_items)
+    }
+
+    async fn assign_role_with(
+        &self,
+        group_id: &str,
+        params: &GroupsAssignRoleParams,
+        body: &crate::types::AssignRoleRequest,
+    ) -> Result<crate::types::Role> {
+        Groups::assign_role_with(self, group_id, params, body).await
+    }
+
+    async fn get_role(&self, group_id: &str, role_id: &str) -> Result<crate::types::Role> {
+        Groups::get_role(self, group_id, role_id).await
+    }
+
+    async fn remove_role_from(&self, group_id: &str, role_id: &str) -> Result<()> {
+        Groups::remove_role_from(self, group_id, role_id).await
+    }
+
+    async fn list_application_targets_for_administrator_roles_with(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        params: &GroupsListApplicationTargetsForAdministratorRolesParams,
+    ) -> Result<Vec<crate::types::CatalogApplication>> {
+        Groups::list_application_targets_for_administrator_roles_with(
+            self, group_id, role_id, params,
+        )
+        .await
+    }
+
+    async fn list_all_application_targets_for_administrator_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::CatalogApplication>> {
+        Groups::list_all_application_targets_for_administrator_roles(self, group_id, role_id).await
+    }
+
+    fn list_application_targets_for_administrator_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CatalogApplication> {
+        Groups::list_application_targets_for_administrator_roles_stream(
+            self, group_id, role_id, start, max_items,
+        )
+    }
+
+    async fn add_application_target_admin_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()> {
+        Groups::add_application_target_admin_role_given(self, group_id, role_id, app_name).await
+    }
+
+    async fn remove_application_target_from_administrator_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()> {
+        Groups::remove_application_target_from_administrator_role_given(
+            self, group_id, role_id, app_name,
+        )
+        .await
+    }
+
+    async fn add_application_instance_target_app_admin_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()> {
+        Groups::add_application_instance_target_app_admin_role_given(
+            self,
+            group_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await
+    }
+
+    async fn remove_application_target_from_administrator_role_given_groups(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()> {
+        Groups::remove_application_target_from_administrator_role_given_groups(
+            self,
+            group_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await
+    }
+
+    async fn list_targets_for_roles_with(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        params: &GroupsListTargetsForRolesParams,
+    ) -> Result<Vec<crate::types::Group>> {
+        Groups::list_targets_for_roles_with(self, group_id, role_id, params).await
+    }
+
+    async fn list_all_targets_for_roles(
+        &self,
+        group_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::Group>> {
+        Groups::list_all_targets_for_roles(self, group_id, role_id).await
+    }
+
+    fn list_targets_for_roles_stream(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group> {
+        Groups::list_targets_for_roles_stream(self, group_id, role_id, start, max_items)
+    }
+
+    async fn add_target_administrator_role_for(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> Result<()> {
+        Groups::add_target_administrator_role_for(self, group_id, role_id, target_group_id).await
+    }
+
+    async fn remove_target_from_administrator_role_given(
+        &self,
+        group_id: &str,
+        role_id: &str,
+        target_group_id: &str,
+    ) -> Result<()> {
+        Groups::remove_target_from_administrator_role_given(
+            self,
+            group_id,
+            role_id,
+            target_group_id,
+        )
+        .await
+    }
+
+    async fn list_users_with(
+        &self,
+        group_id: &str,
+        params: &GroupsListUsersParams,
+    ) -> Result<Vec<crate::types::User>> {
+        Groups::list_users_with(self, group_id, params).await
+    }
+
+    async fn list_all_users(&self, group_id: &str) -> Result<Vec<crate::types::User>> {
+        Groups::list_all_users(self, group_id).await
+    }
+
+    fn list_users_stream(
+        &self,
+        group_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::User> {
+        Groups::list_users_stream(self, group_id, start, max_items)
+    }
+
+    async fn add_user(&self, group_id: &str, user_id: &str) -> Result<()> {
+        Groups::add_user(self, group_id, user_id).await
+    }
+
+    async fn remove_user_from(&self, group_id: &str, user_id: &str) -> Result<()> {
+        Groups::remove_user_from(self, group_id, user_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct IdentityProviders {
     pub client: Client,
@@ -21,29 +19,23 @@ impl IdentityProviders {
      *
      * **Parameters:**
      *
-     * * `q: &str` -- Searches the name property of IdPs for matching value.
-     * * `after: &str` -- Specifies the pagination cursor for the next page of IdPs.
-     * * `limit: i64` -- Specifies the number of IdP results in a page.
-     * * `type_: &str` -- Filters IdPs by type.
+     * * `params: &IdentityProvidersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        q: &str,
-        after: &str,
-        limit: i64,
-        type_: &str,
+        params: &IdentityProvidersListParams,
     ) -> Result<Vec<crate::types::IdentityProvider>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -52,6 +44,26 @@ impl IdentityProviders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `IdentityProvidersListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        type_: &str,
+    ) -> Result<Vec<crate::types::IdentityProvider>> {
+        let params = IdentityProvidersListParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * List Identity Providers.
      *
@@ -60,23 +72,86 @@ impl IdentityProviders {
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Enumerates IdPs in your organization with pagination. A subset of IdPs can be returned that match a supported filter expression or query.
+     *
+     * **Parameters:**
+     *
+     * * `params: &IdentityProvidersListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(
+        &self,
+        params: &IdentityProvidersListParams,
+    ) -> Result<Vec<crate::types::IdentityProvider>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/api/v1/idps?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `IdentityProvidersListParams` instead.
      */
+    #[deprecated(note = "use `list_all_with` instead")]
     pub async fn list_all(
         &self,
         q: &str,
         type_: &str,
     ) -> Result<Vec<crate::types::IdentityProvider>> {
+        let params = IdentityProvidersListParams {
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * List Identity Providers.
+     *
+     * This function performs a `GET` to the `/api/v1/idps` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates IdPs in your organization with pagination. A subset of IdPs can be returned that match a supported filter expression or query.
+     */
+    pub fn list_stream(
+        &self,
+        params: &IdentityProvidersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IdentityProvider> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/api/v1/idps?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -105,19 +180,17 @@ impl IdentityProviders {
      *
      * **Parameters:**
      *
-     * * `after: &str` -- Specifies the pagination cursor for the next page of keys.
-     * * `limit: i64` -- Specifies the number of key results in a page.
+     * * `params: &IdentityProvidersListKeysParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_keys(
+    pub async fn list_keys_with(
         &self,
-        after: &str,
-        limit: i64,
+        params: &IdentityProvidersListKeysParams,
     ) -> Result<Vec<crate::types::JsonWebKey>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -126,6 +199,22 @@ impl IdentityProviders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_keys_with` and pass the query parameters as a `IdentityProvidersListKeysParams` instead.
+     */
+    #[deprecated(note = "use `list_keys_with` instead")]
+    pub async fn list_keys(
+        &self,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::JsonWebKey>> {
+        let params = IdentityProvidersListKeysParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_keys_with(&params).await
+    }
+
     /**
      * List Keys.
      *
@@ -140,6 +229,39 @@ impl IdentityProviders {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List Keys.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_keys`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates IdP key credentials.
+     */
+    pub fn list_keys_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JsonWebKey> {
+        let url = "/api/v1/idps/credentials/keys".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Add X.509 Certificate Public Key.
      *
@@ -301,6 +423,44 @@ impl IdentityProviders {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List Certificate Signing Requests for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/csrs` endpoint.
+     *
+     * As opposed to `list_all_csrs_fors`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates Certificate Signing Requests for an IdP
+     */
+    pub fn list_csrs_fors_stream(
+        &self,
+        idp_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Csr> {
+        let url = format!(
+            "/api/v1/idps/{}/credentials/csrs",
+            crate::progenitor_support::encode_path(idp_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Generate Certificate Signing Request for IdP.
      *
@@ -432,6 +592,44 @@ impl IdentityProviders {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * List Signing Key Credentials for IdP.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/credentials/keys` endpoint.
+     *
+     * As opposed to `list_all_signing_keys`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates signing key credentials for an IdP
+     */
+    pub fn list_signing_keys_stream(
+        &self,
+        idp_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JsonWebKey> {
+        let url = format!(
+            "/api/v1/idps/{}/credentials/keys",
+            crate::progenitor_support::encode_path(idp_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Generate New IdP Signing Key Credential.
      *
@@ -442,15 +640,15 @@ impl IdentityProviders {
      * **Parameters:**
      *
      * * `idp_id: &str`
-     * * `validity_years: i64` -- expiry of the IdP Key Credential.
+     * * `params: &IdentityProvidersGenerateSigningKeyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn generate_signing_key(
+    pub async fn generate_signing_key_with(
         &self,
         idp_id: &str,
-        validity_years: i64,
+        params: &IdentityProvidersGenerateSigningKeyParams,
     ) -> Result<crate::types::JsonWebKey> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if validity_years > 0 {
+        if let Some(validity_years) = &params.validity_years {
             query_args.push(("validityYears".to_string(), validity_years.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -463,6 +661,21 @@ impl IdentityProviders {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `generate_signing_key_with` and pass the query parameters as a `IdentityProvidersGenerateSigningKeyParams` instead.
+     */
+    #[deprecated(note = "use `generate_signing_key_with` instead")]
+    pub async fn generate_signing_key(
+        &self,
+        idp_id: &str,
+        validity_years: i64,
+    ) -> Result<crate::types::JsonWebKey> {
+        let params = IdentityProvidersGenerateSigningKeyParams {
+            validity_years: Some(validity_years).filter(|v| *v > 0),
+        };
+        self.generate_signing_key_with(idp_id, &params).await
+    }
+
     /**
      * Get Signing Key Credential for IdP.
      *
@@ -500,16 +713,16 @@ impl IdentityProviders {
      *
      * * `idp_id: &str`
      * * `key_id: &str`
-     * * `target_idp_id: &str`
+     * * `params: &IdentityProvidersCloneKeyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn clone_key(
+    pub async fn clone_key_with(
         &self,
         idp_id: &str,
         key_id: &str,
-        target_idp_id: &str,
+        params: &IdentityProvidersCloneKeyParams,
     ) -> Result<crate::types::JsonWebKey> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !target_idp_id.is_empty() {
+        if let Some(target_idp_id) = &params.target_idp_id {
             query_args.push(("targetIdpId".to_string(), target_idp_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -523,6 +736,22 @@ impl IdentityProviders {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `clone_key_with` and pass the query parameters as a `IdentityProvidersCloneKeyParams` instead.
+     */
+    #[deprecated(note = "use `clone_key_with` instead")]
+    pub async fn clone_key(
+        &self,
+        idp_id: &str,
+        key_id: &str,
+        target_idp_id: &str,
+    ) -> Result<crate::types::JsonWebKey> {
+        let params = IdentityProvidersCloneKeyParams {
+            target_idp_id: Some(target_idp_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.clone_key_with(idp_id, key_id, &params).await
+    }
+
     /**
      * Activate Identity Provider.
      *
@@ -607,6 +836,44 @@ impl IdentityProviders {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Find Users.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users` endpoint.
+     *
+     * As opposed to `list_all_application_users`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Find all the users linked to an identity provider
+     */
+    pub fn list_application_users_stream(
+        &self,
+        idp_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IdentityProviderApplicationUser> {
+        let url = format!(
+            "/api/v1/idps/{}/users",
+            crate::progenitor_support::encode_path(idp_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}` endpoint.
      *
@@ -730,4 +997,576 @@ impl IdentityProviders {
 
         self.client.get_all_pages(&url, None).await
     }
+
+    /**
+     * Social Authentication Token Operation.
+     *
+     * This function performs a `GET` to the `/api/v1/idps/{idpId}/users/{userId}/credentials/tokens` endpoint.
+     *
+     * As opposed to `list_all_social_auth_tokens`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Fetches the tokens minted by the Social Authentication Provider when the user authenticates with Okta via Social Auth.
+     */
+    pub fn list_social_auth_tokens_stream(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SocialAuthToken> {
+        let url = format!(
+            "/api/v1/idps/{}/users/{}/credentials/tokens",
+            crate::progenitor_support::encode_path(idp_id),
+            crate::progenitor_support::encode_path(user_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `IdentityProviders::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IdentityProvidersListParams {
+    /// Specifies the pagination cursor for the next page of IdPs.
+    pub after: Option<String>,
+    /// Specifies the number of IdP results in a page.
+    pub limit: Option<i64>,
+    /// Searches the name property of IdPs for matching value.
+    pub q: Option<String>,
+    /// Filters IdPs by type.
+    pub type_: Option<String>,
+}
+
+impl IdentityProvidersListParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `IdentityProviders::list_keys_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IdentityProvidersListKeysParams {
+    /// Specifies the pagination cursor for the next page of keys.
+    pub after: Option<String>,
+    /// Specifies the number of key results in a page.
+    pub limit: Option<i64>,
+}
+
+impl IdentityProvidersListKeysParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `IdentityProviders::generate_signing_key_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IdentityProvidersGenerateSigningKeyParams {
+    /// expiry of the IdP Key Credential.
+    pub validity_years: Option<i64>,
+}
+
+impl IdentityProvidersGenerateSigningKeyParams {
+    pub fn validity_years(mut self, validity_years: i64) -> Self {
+        self.validity_years = Some(validity_years);
+        self
+    }
+}
+
+/// The query parameters of `IdentityProviders::clone_key_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IdentityProvidersCloneKeyParams {
+    pub target_idp_id: Option<String>,
+}
+
+impl IdentityProvidersCloneKeyParams {
+    pub fn target_idp_id<T: ToString>(mut self, target_idp_id: T) -> Self {
+        self.target_idp_id = Some(target_idp_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`IdentityProviders`], for code that takes them as a trait, so that it can be
+/// tested with the `MockIdentityProvidersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait IdentityProvidersApi: Send + Sync {
+    /// See [`IdentityProviders::list_with`].
+    async fn list_with(
+        &self,
+        params: &IdentityProvidersListParams,
+    ) -> Result<Vec<crate::types::IdentityProvider>>;
+
+    /// See [`IdentityProviders::list_all_with`].
+    async fn list_all_with(
+        &self,
+        params: &IdentityProvidersListParams,
+    ) -> Result<Vec<crate::types::IdentityProvider>>;
+
+    /// See [`IdentityProviders::list_stream`].
+    fn list_stream(
+        &self,
+        params: &IdentityProvidersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IdentityProvider>;
+
+    /// See [`IdentityProviders::create`].
+    async fn create(
+        &self,
+        body: &crate::types::IdentityProvider,
+    ) -> Result<crate::types::IdentityProvider>;
+
+    /// See [`IdentityProviders::list_keys_with`].
+    async fn list_keys_with(
+        &self,
+        params: &IdentityProvidersListKeysParams,
+    ) -> Result<Vec<crate::types::JsonWebKey>>;
+
+    /// See [`IdentityProviders::list_all_keys`].
+    async fn list_all_keys(&self) -> Result<Vec<crate::types::JsonWebKey>>;
+
+    /// See [`IdentityProviders::list_keys_stream`].
+    fn list_keys_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JsonWebKey>;
+
+    /// See [`IdentityProviders::create_key`].
+    async fn create_key(&self, body: &crate::types::JsonWebKey)
+        -> Result<crate::types::JsonWebKey>;
+
+    /// See [`IdentityProviders::get_key`].
+    async fn get_key(&self, key_id: &str) -> Result<crate::types::JsonWebKey>;
+
+    /// See [`IdentityProviders::delete_key`].
+    async fn delete_key(&self, key_id: &str) -> Result<()>;
+
+    /// See [`IdentityProviders::get`].
+    async fn get(&self, idp_id: &str) -> Result<crate::types::IdentityProvider>;
+
+    /// See [`IdentityProviders::update`].
+    async fn update(
+        &self,
+        idp_id: &str,
+        body: &crate::types::IdentityProvider,
+    ) -> Result<crate::types::IdentityProvider>;
+
+    /// See [`IdentityProviders::delete`].
+    async fn delete(&self, idp_id: &str) -> Result<()>;
+
+    /// See [`IdentityProviders::list_csrs_fors`].
+    async fn list_csrs_fors(&self, idp_id: &str) -> Result<Vec<crate::types::Csr>>;
+
+    /// See [`IdentityProviders::list_all_csrs_fors`].
+    async fn list_all_csrs_fors(&self, idp_id: &str) -> Result<Vec<crate::types::Csr>>;
+
+    /// See [`IdentityProviders::list_csrs_fors_stream`].
+    fn list_csrs_fors_stream(
+        &self,
+        idp_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Csr>;
+
+    /// See [`IdentityProviders::generate_csr_for`].
+    async fn generate_csr_for(
+        &self,
+        idp_id: &str,
+        body: &crate::types::CsrMetadata,
+    ) -> Result<crate::types::Csr>;
+
+    /// See [`IdentityProviders::get_csr_for`].
+    async fn get_csr_for(&self, idp_id: &str, csr_id: &str) -> Result<crate::types::Csr>;
+
+    /// See [`IdentityProviders::revoke_csr_for`].
+    async fn revoke_csr_for(&self, idp_id: &str, csr_id: &str) -> Result<()>;
+
+    /// See [`IdentityProviders::post_idp_credentials_csr_lifecycle_publish`].
+    async fn post_idp_credentials_csr_lifecycle_publish(
+        &self,
+        idp_id: &str,
+        csr_id: &str,
+    ) -> Result<crate::types::JsonWebKey>;
+
+    /// See [`IdentityProviders::list_signing_keys`].
+    async fn list_signing_keys(&self, idp_id: &str) -> Result<Vec<crate::types::JsonWebKey>>;
+
+    /// See [`IdentityProviders::list_all_signing_keys`].
+    async fn list_all_signing_keys(&self, idp_id: &str) -> Result<Vec<crate::types::JsonWebKey>>;
+
+    /// See [`IdentityProviders::list_signing_keys_stream`].
+    fn list_signing_keys_stream(
+        &self,
+        idp_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JsonWebKey>;
+
+    /// See [`IdentityProviders::generate_signing_key_with`].
+    async fn generate_signing_key_with(
+        &self,
+        idp_id: &str,
+        params: &IdentityProvidersGenerateSigningKeyParams,
+    ) -> Result<crate::types::JsonWebKey>;
+
+    /// See [`IdentityProviders::get_signing_key`].
+    async fn get_signing_key(&self, idp_id: &str, key_id: &str)
+        -> Result<crate::types::JsonWebKey>;
+
+    /// See [`IdentityProviders::clone_key_with`].
+    async fn clone_key_with(
+        &self,
+        idp_id: &str,
+        key_id: &str,
+        params: &IdentityProvidersCloneKeyParams,
+    ) -> Result<crate::types::JsonWebKey>;
+
+    /// See [`IdentityProviders::activate`].
+    async fn activate(&self, idp_id: &str) -> Result<crate::types::IdentityProvider>;
+
+    /// See [`IdentityProviders::deactivate`].
+    async fn deactivate(&self, idp_id: &str) -> Result<crate::types::IdentityProvider>;
+
+    /// See [`IdentityProviders::list_application_users`].
+    async fn list_application_users(
+        &self,
+        idp_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProviderApplicationUser>>;
+
+    /// See [`IdentityProviders::list_all_application_users`].
+    async fn list_all_application_users(
+        &self,
+        idp_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProviderApplicationUser>>;
+
+    /// See [`IdentityProviders::list_application_users_stream`].
+    fn list_application_users_stream(
+        &self,
+        idp_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IdentityProviderApplicationUser>;
+
+    /// See [`IdentityProviders::get_application_user`].
+    async fn get_application_user(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::IdentityProviderApplicationUser>;
+
+    /// See [`IdentityProviders::link_user`].
+    async fn link_user(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+        body: &crate::types::UserIdentityProviderLinkRequest,
+    ) -> Result<crate::types::IdentityProviderApplicationUser>;
+
+    /// See [`IdentityProviders::unlink_user_from`].
+    async fn unlink_user_from(&self, idp_id: &str, user_id: &str) -> Result<()>;
+
+    /// See [`IdentityProviders::list_social_auth_tokens`].
+    async fn list_social_auth_tokens(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SocialAuthToken>>;
+
+    /// See [`IdentityProviders::list_all_social_auth_tokens`].
+    async fn list_all_social_auth_tokens(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SocialAuthToken>>;
+
+    /// See [`IdentityProviders::list_social_auth_tokens_stream`].
+    fn list_social_auth_tokens_stream(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SocialAuthToken>;
+}
+
+#[async_trait::async_trait]
+impl IdentityProvidersApi for IdentityProviders {
+    async fn list_with(
+        &self,
+        params: &IdentityProvidersListParams,
+    ) -> Result<Vec<crate::types::IdentityProvider>> {
+        IdentityProviders::list_with(self, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        params: &IdentityProvidersListParams,
+    ) -> Result<Vec<crate::types::IdentityProvider>> {
+        IdentityProviders::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &IdentityProvidersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IdentityProvider> {
+        IdentityProviders::list_stream(self, params, start, max_items)
+    }
+
+    async fn create(
+        &self,
+        body: &crate::types::IdentityProvider,
+    ) -> Result<crate::types::IdentityProvider> {
+        IdentityProviders::create(self, body).await
+    }
+
+    async fn list_keys_with(
+        &self,
+        params: &IdentityProvidersListKeysParams,
+    ) -> Result<Vec<crate::types::JsonWebKey>> {
+        IdentityProviders::list_keys_with(self, params).await
+    }
+
+    async fn list_all_keys(&self) -> Result<Vec<crate::types::JsonWebKey>> {
+        IdentityProviders::list_all_keys(self).await
+    }
+
+    fn list_keys_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JsonWebKey> {
+        IdentityProviders::list_keys_stream(self, start, max_items)
+    }
+
+    async fn create_key(
+        &self,
+        body: &crate::types::JsonWebKey,
+    ) -> Result<crate::types::JsonWebKey> {
+        IdentityProviders::create_key(self, body).await
+    }
+
+    async fn get_key(&self, key_id: &str) -> Result<crate::types::JsonWebKey> {
+        IdentityProviders::get_key(self, key_id).await
+    }
+
+    async fn delete_key(&self, key_id: &str) -> Result<()> {
+        IdentityProviders::delete_key(self, key_id).await
+    }
+
+    async fn get(&self, idp_id: &str) -> Result<crate::types::IdentityProvider> {
+        IdentityProviders::get(self, idp_id).await
+    }
+
+    async fn update(
+        &self,
+        idp_id: &str,
+        body: &crate::types::IdentityProvider,
+    ) -> Result<crate::types::IdentityProvider> {
+        IdentityProviders::update(self, idp_id, body).await
+    }
+
+    async fn delete(&self, idp_id: &str) -> Result<()> {
+        IdentityProviders::delete(self, idp_id).await
+    }
+
+    async fn list_csrs_fors(&self, idp_id: &str) -> Result<Vec<crate::types::Csr>> {
+        IdentityProviders::list_csrs_fors(self, idp_id).await
+    }
+
+    async fn list_all_csrs_fors(&self, idp_id: &str) -> Result<Vec<crate::types::Csr>> {
+        IdentityProviders::list_all_csrs_fors(self, idp_id).await
+    }
+
+    fn list_csrs_fors_stream(
+        &self,
+        idp_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Csr> {
+        IdentityProviders::list_csrs_fors_stream(self, idp_id, start, max_items)
+    }
+
+    async fn generate_csr_for(
+        &self,
+        idp_id: &str,
+        body: &crate::types::CsrMetadata,
+    ) -> Result<crate::types::Csr> {
+        IdentityProviders::generate_csr_for(self, idp_id, body).await
+    }
+
+    async fn get_csr_for(&self, idp_id: &str, csr_id: &str) -> Result<crate::types::Csr> {
+        IdentityProviders::get_csr_for(self, idp_id, csr_id).await
+    }
+
+    async fn revoke_csr_for(&self, idp_id: &str, csr_id: &str) -> Result<()> {
+        IdentityProviders::revoke_csr_for(self, idp_id, csr_id).await
+    }
+
+    async fn post_idp_credentials_csr_lifecycle_publish(
+        &self,
+        idp_id: &str,
+        csr_id: &str,
+    ) -> Result<crate::types::JsonWebKey> {
+        IdentityProviders::post_idp_credentials_csr_lifecycle_publish(self, idp_id, csr_id).await
+    }
+
+    async fn list_signing_keys(&self, idp_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+        IdentityProviders::list_signing_keys(self, idp_id).await
+    }
+
+    async fn list_all_signing_keys(&self, idp_id: &str) -> Result<Vec<crate::types::JsonWebKey>> {
+        IdentityProviders::list_all_signing_keys(self, idp_id).await
+    }
+
+    fn list_signing_keys_stream(
+        &self,
+        idp_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JsonWebKey> {
+        IdentityProviders::list_signing_keys_stream(self, idp_id, start, max_items)
+    }
+
+    async fn generate_signing_key_with(
+        &self,
+        idp_id: &str,
+        params: &IdentityProvidersGenerateSigningKeyParams,
+    ) -> Result<crate::types::JsonWebKey> {
+        IdentityProviders::generate_signing_key_with(self, idp_id, params).await
+    }
+
+    async fn get_signing_key(
+        &self,
+        idp_id: &str,
+        key_id: &str,
+    ) -> Result<crate::types::JsonWebKey> {
+        IdentityProviders::get_signing_key(self, idp_id, key_id).await
+    }
+
+    async fn clone_key_with(
+        &self,
+        idp_id: &str,
+        key_id: &str,
+        params: &IdentityProvidersCloneKeyParams,
+    ) -> Result<crate::types::JsonWebKey> {
+        IdentityProviders::clone_key_with(self, idp_id, key_id, params).await
+    }
+
+    async fn activate(&self, idp_id: &str) -> Result<crate::types::IdentityProvider> {
+        IdentityProviders::activate(self, idp_id).await
+    }
+
+    async fn deactivate(&self, idp_id: &str) -> Result<crate::types::IdentityProvider> {
+        IdentityProviders::deactivate(self, idp_id).await
+    }
+
+    async fn list_application_users(
+        &self,
+        idp_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProviderApplicationUser>> {
+        IdentityProviders::list_application_users(self, idp_id).await
+    }
+
+    async fn list_all_application_users(
+        &self,
+        idp_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProviderApplicationUser>> {
+        IdentityProviders::list_all_application_users(self, idp_id).await
+    }
+
+    fn list_application_users_stream(
+        &self,
+        idp_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IdentityProviderApplicationUser> {
+        IdentityProviders::list_application_users_stream(self, idp_id, start, max_items)
+    }
+
+    async fn get_application_user(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> Result<crate::types::IdentityProviderApplicationUser> {
+        IdentityProviders::get_application_user(self, idp_id, user_id).await
+    }
+
+    async fn link_user(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+        body: &crate::types::UserIdentityProviderLinkRequest,
+    ) -> Result<crate::types::IdentityProviderApplicationUser> {
+        IdentityProviders::link_user(self, idp_id, user_id, body).await
+    }
+
+    async fn unlink_user_from(&self, idp_id: &str, user_id: &str) -> Result<()> {
+        IdentityProviders::unlink_user_from(self, idp_id, user_id).await
+    }
+
+    async fn list_social_auth_tokens(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SocialAuthToken>> {
+        IdentityProviders::list_social_auth_tokens(self, idp_id, user_id).await
+    }
+
+    async fn list_all_social_auth_tokens(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SocialAuthToken>> {
+        IdentityProviders::list_all_social_auth_tokens(self, idp_id, user_id).await
+    }
+
+    fn list_social_auth_tokens_stream(
+        &self,
+        idp_id: &str,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SocialAuthToken> {
+        IdentityProviders::list_social_auth_tokens_stream(self, idp_id, user_id, start, max_items)
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct InlineHooks {
     pub client: Client,
@@ -19,11 +17,14 @@ impl InlineHooks {
      *
      * **Parameters:**
      *
-     * * `type_: &str`
+     * * `params: &InlineHooksListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(&self, type_: &str) -> Result<Vec<crate::types::InlineHook>> {
+    pub async fn list_with(
+        &self,
+        params: &InlineHooksListParams,
+    ) -> Result<Vec<crate::types::InlineHook>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -32,16 +33,34 @@ impl InlineHooks {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `InlineHooksListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(&self, type_: &str) -> Result<Vec<crate::types::InlineHook>> {
+        let params = InlineHooksListParams {
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/inlineHooks` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Success
+     *
+     * **Parameters:**
+     *
+     * * `params: &InlineHooksListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(&self, type_: &str) -> Result<Vec<crate::types::InlineHook>> {
+    pub async fn list_all_with(
+        &self,
+        params: &InlineHooksListParams,
+    ) -> Result<Vec<crate::types::InlineHook>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -50,6 +69,55 @@ impl InlineHooks {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `InlineHooksListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(&self, type_: &str) -> Result<Vec<crate::types::InlineHook>> {
+        let params = InlineHooksListParams {
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/inlineHooks` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_stream(
+        &self,
+        params: &InlineHooksListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::InlineHook> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/api/v1/inlineHooks?{}", query_);
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/api/v1/inlineHooks` endpoint.
      *
@@ -137,7 +205,7 @@ impl InlineHooks {
     pub async fn execute(
         &self,
         inline_hook_id: &str,
-        body: &crate::types::Links,
+        body: &crate::types::InlineHookPayload,
     ) -> Result<crate::types::InlineHookResponse> {
         let url = format!(
             "/api/v1/inlineHooks/{}/execute",
@@ -185,3 +253,133 @@ impl InlineHooks {
         self.client.post(&url, None).await
     }
 }
+
+/// The query parameters of `InlineHooks::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InlineHooksListParams {
+    pub type_: Option<String>,
+}
+
+impl InlineHooksListParams {
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
+
+/// The functions of [`InlineHooks`], for code that takes them as a trait, so that it can be
+/// tested with the `MockInlineHooksApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait InlineHooksApi: Send + Sync {
+    /// See [`InlineHooks::list_with`].
+    async fn list_with(
+        &self,
+        params: &InlineHooksListParams,
+    ) -> Result<Vec<crate::types::InlineHook>>;
+
+    /// See [`InlineHooks::list_all_with`].
+    async fn list_all_with(
+        &self,
+        params: &InlineHooksListParams,
+    ) -> Result<Vec<crate::types::InlineHook>>;
+
+    /// See [`InlineHooks::list_stream`].
+    fn list_stream(
+        &self,
+        params: &InlineHooksListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::InlineHook>;
+
+    /// See [`InlineHooks::create`].
+    async fn create(&self, body: &crate::types::InlineHook) -> Result<crate::types::InlineHook>;
+
+    /// See [`InlineHooks::get`].
+    async fn get(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook>;
+
+    /// See [`InlineHooks::update`].
+    async fn update(
+        &self,
+        inline_hook_id: &str,
+        body: &crate::types::InlineHook,
+    ) -> Result<crate::types::InlineHook>;
+
+    /// See [`InlineHooks::delete`].
+    async fn delete(&self, inline_hook_id: &str) -> Result<()>;
+
+    /// See [`InlineHooks::execute`].
+    async fn execute(
+        &self,
+        inline_hook_id: &str,
+        body: &crate::types::InlineHookPayload,
+    ) -> Result<crate::types::InlineHookResponse>;
+
+    /// See [`InlineHooks::activate`].
+    async fn activate(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook>;
+
+    /// See [`InlineHooks::deactivate`].
+    async fn deactivate(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook>;
+}
+
+#[async_trait::async_trait]
+impl InlineHooksApi for InlineHooks {
+    async fn list_with(
+        &self,
+        params: &InlineHooksListParams,
+    ) -> Result<Vec<crate::types::InlineHook>> {
+        InlineHooks::list_with(self, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        params: &InlineHooksListParams,
+    ) -> Result<Vec<crate::types::InlineHook>> {
+        InlineHooks::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &InlineHooksListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::InlineHook> {
+        InlineHooks::list_stream(self, params, start, max_items)
+    }
+
+    async fn create(&self, body: &crate::types::InlineHook) -> Result<crate::types::InlineHook> {
+        InlineHooks::create(self, body).await
+    }
+
+    async fn get(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook> {
+        InlineHooks::get(self, inline_hook_id).await
+    }
+
+    async fn update(
+        &self,
+        inline_hook_id: &str,
+        body: &crate::types::InlineHook,
+    ) -> Result<crate::types::InlineHook> {
+        InlineHooks::update(self, inline_hook_id, body).await
+    }
+
+    async fn delete(&self, inline_hook_id: &str) -> Result<()> {
+        InlineHooks::delete(self, inline_hook_id).await
+    }
+
+    async fn execute(
+        &self,
+        inline_hook_id: &str,
+        body: &crate::types::InlineHookPayload,
+    ) -> Result<crate::types::InlineHookResponse> {
+        InlineHooks::execute(self, inline_hook_id, body).await
+    }
+
+    async fn activate(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook> {
+        InlineHooks::activate(self, inline_hook_id).await
+    }
+
+    async fn deactivate(&self, inline_hook_id: &str) -> Result<crate::types::InlineHook> {
+        InlineHooks::deactivate(self, inline_hook_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct LinkedObjects {
     pub client: Client,
@@ -34,6 +32,37 @@ impl LinkedObjects {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
+     *
+     * As opposed to `list_all_definitions`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_definitions_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LinkedObject> {
+        let url = "/api/v1/meta/schemas/user/linkedObjects".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/api/v1/meta/schemas/user/linkedObjects` endpoint.
      *
@@ -88,3 +117,68 @@ impl LinkedObjects {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`LinkedObjects`], for code that takes them as a trait, so that it can be
+/// tested with the `MockLinkedObjectsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait LinkedObjectsApi: Send + Sync {
+    /// See [`LinkedObjects::list_definitions`].
+    async fn list_definitions(&self) -> Result<Vec<crate::types::LinkedObject>>;
+
+    /// See [`LinkedObjects::list_all_definitions`].
+    async fn list_all_definitions(&self) -> Result<Vec<crate::types::LinkedObject>>;
+
+    /// See [`LinkedObjects::list_definitions_stream`].
+    fn list_definitions_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LinkedObject>;
+
+    /// See [`LinkedObjects::add_definition`].
+    async fn add_definition(
+        &self,
+        body: &crate::types::LinkedObject,
+    ) -> Result<crate::types::LinkedObject>;
+
+    /// See [`LinkedObjects::get_definition`].
+    async fn get_definition(&self, linked_object_name: &str) -> Result<crate::types::LinkedObject>;
+
+    /// See [`LinkedObjects::delete_definition`].
+    async fn delete_definition(&self, linked_object_name: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl LinkedObjectsApi for LinkedObjects {
+    async fn list_definitions(&self) -> Result<Vec<crate::types::LinkedObject>> {
+        LinkedObjects::list_definitions(self).await
+    }
+
+    async fn list_all_definitions(&self) -> Result<Vec<crate::types::LinkedObject>> {
+        LinkedObjects::list_all_definitions(self).await
+    }
+
+    fn list_definitions_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LinkedObject> {
+        LinkedObjects::list_definitions_stream(self, start, max_items)
+    }
+
+    async fn add_definition(
+        &self,
+        body: &crate::types::LinkedObject,
+    ) -> Result<crate::types::LinkedObject> {
+        LinkedObjects::add_definition(self, body).await
+    }
+
+    async fn get_definition(&self, linked_object_name: &str) -> Result<crate::types::LinkedObject> {
+        LinkedObjects::get_definition(self, linked_object_name).await
+    }
+
+    async fn delete_definition(&self, linked_object_name: &str) -> Result<()> {
+        LinkedObjects::delete_definition(self, linked_object_name).await
+    }
+}
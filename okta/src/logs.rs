@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Logs {
     pub client: Client,
@@ -21,45 +19,33 @@ impl Logs {
      *
      * **Parameters:**
      *
-     * * `since: chrono::DateTime<chrono::Utc>`
-     * * `until: chrono::DateTime<chrono::Utc>`
-     * * `filter: &str`
-     * * `q: &str`
-     * * `limit: i64`
-     * * `sort_order: &str`
-     * * `after: &str`
+     * * `params: &LogsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        since: Option<chrono::DateTime<chrono::Utc>>,
-        until: Option<chrono::DateTime<chrono::Utc>>,
-        filter: &str,
-        q: &str,
-        limit: i64,
-        sort_order: &str,
-        after: &str,
+        params: &LogsGetPageParams,
     ) -> Result<Vec<crate::types::LogEvent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort_order.is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sortOrder".to_string(), sort_order.to_string()));
         }
-        if let Some(date) = until {
-            query_args.push(("until".to_string(), date.to_rfc3339()));
+        if let Some(until) = &params.until {
+            query_args.push(("until".to_string(), until.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/api/v1/logs?{}", query_);
@@ -67,6 +53,32 @@ impl Logs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `LogsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        until: Option<chrono::DateTime<chrono::Utc>>,
+        filter: &str,
+        q: &str,
+        limit: i64,
+        sort_order: &str,
+        after: &str,
+    ) -> Result<Vec<crate::types::LogEvent>> {
+        let params = LogsGetPageParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            since,
+            sort_order: Some(sort_order.to_string()).filter(|s| !s.is_empty()),
+            until,
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * Fetch a list of events from your Okta organization system log.
      *
@@ -75,7 +87,41 @@ impl Logs {
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * The Okta System Log API provides read access to your organization’s system log. This API provides more functionality than the Events API
+     *
+     * **Parameters:**
+     *
+     * * `params: &LogsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_with(
+        &self,
+        params: &LogsGetPageParams,
+    ) -> Result<Vec<crate::types::LogEvent>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
+        }
+        if let Some(sort_order) = &params.sort_order {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        if let Some(until) = &params.until {
+            query_args.push(("until".to_string(), until.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/api/v1/logs?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `LogsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
     pub async fn get_all(
         &self,
         since: Option<chrono::DateTime<chrono::Utc>>,
@@ -84,25 +130,165 @@ impl Logs {
         q: &str,
         sort_order: &str,
     ) -> Result<Vec<crate::types::LogEvent>> {
+        let params = LogsGetPageParams {
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            since,
+            sort_order: Some(sort_order.to_string()).filter(|s| !s.is_empty()),
+            until,
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * Fetch a list of events from your Okta organization system log.
+     *
+     * This function performs a `GET` to the `/api/v1/logs` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * The Okta System Log API provides read access to your organization’s system log. This API provides more functionality than the Events API
+     */
+    pub fn get_stream(
+        &self,
+        params: &LogsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LogEvent> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if let Some(date) = since {
-            query_args.push(("since".to_string(), date.to_rfc3339()));
+        if let Some(since) = &params.since {
+            query_args.push(("since".to_string(), since.to_rfc3339()));
         }
-        if !sort_order.is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sortOrder".to_string(), sort_order.to_string()));
         }
-        if let Some(date) = until {
-            query_args.push(("until".to_string(), date.to_rfc3339()));
+        if let Some(until) = &params.until {
+            query_args.push(("until".to_string(), until.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/api/v1/logs?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Logs::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct LogsGetPageParams {
+    pub after: Option<String>,
+    pub filter: Option<String>,
+    pub limit: Option<i64>,
+    pub q: Option<String>,
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    pub sort_order: Option<String>,
+    pub until: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl LogsGetPageParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn since(mut self, since: chrono::DateTime<chrono::Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn sort_order<T: ToString>(mut self, sort_order: T) -> Self {
+        self.sort_order = Some(sort_order.to_string());
+        self
+    }
+
+    pub fn until(mut self, until: chrono::DateTime<chrono::Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+}
+
+/// The functions of [`Logs`], for code that takes them as a trait, so that it can be
+/// tested with the `MockLogsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait LogsApi: Send + Sync {
+    /// See [`Logs::get_page_with`].
+    async fn get_page_with(
+        &self,
+        params: &LogsGetPageParams,
+    ) -> Result<Vec<crate::types::LogEvent>>;
+
+    /// See [`Logs::get_all_with`].
+    async fn get_all_with(&self, params: &LogsGetPageParams)
+        -> Result<Vec<crate::types::LogEvent>>;
+
+    /// See [`Logs::get_stream`].
+    fn get_stream(
+        &self,
+        params: &LogsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LogEvent>;
+}
+
+#[async_trait::async_trait]
+impl LogsApi for Logs {
+    async fn get_page_with(
+        &self,
+        params: &LogsGetPageParams,
+    ) -> Result<Vec<crate::types::LogEvent>> {
+        Logs::get_page_with(self, params).await
+    }
+
+    async fn get_all_with(
+        &self,
+        params: &LogsGetPageParams,
+    ) -> Result<Vec<crate::types::LogEvent>> {
+        Logs::get_all_with(self, params).await
+    }
+
+    fn get_stream(
+        &self,
+        params: &LogsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LogEvent> {
+        Logs::get_stream(self, params, start, max_items)
     }
 }
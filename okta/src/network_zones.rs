@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct NetworkZones {
     pub client: Client,
@@ -21,24 +19,20 @@ impl NetworkZones {
      *
      * **Parameters:**
      *
-     * * `after: &str` -- Specifies the pagination cursor for the next page of network zones.
-     * * `limit: i64` -- Specifies the number of results for a page.
-     * * `filter: &str` -- Filters zones by usage or id expression.
+     * * `params: &NetworkZonesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        after: &str,
-        limit: i64,
-        filter: &str,
+        params: &NetworkZonesListParams,
     ) -> Result<Vec<crate::types::NetworkZone>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -47,6 +41,24 @@ impl NetworkZones {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `NetworkZonesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        after: &str,
+        limit: i64,
+        filter: &str,
+    ) -> Result<Vec<crate::types::NetworkZone>> {
+        let params = NetworkZonesListParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * List Network Zones.
      *
@@ -55,10 +67,17 @@ impl NetworkZones {
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Enumerates network zones added to your organization with pagination. A subset of zones can be returned that match a supported filter expression or query.
+     *
+     * **Parameters:**
+     *
+     * * `params: &NetworkZonesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(&self, filter: &str) -> Result<Vec<crate::types::NetworkZone>> {
+    pub async fn list_all_with(
+        &self,
+        params: &NetworkZonesListParams,
+    ) -> Result<Vec<crate::types::NetworkZone>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -67,6 +86,58 @@ impl NetworkZones {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `NetworkZonesListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(&self, filter: &str) -> Result<Vec<crate::types::NetworkZone>> {
+        let params = NetworkZonesListParams {
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * List Network Zones.
+     *
+     * This function performs a `GET` to the `/api/v1/zones` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates network zones added to your organization with pagination. A subset of zones can be returned that match a supported filter expression or query.
+     */
+    pub fn list_stream(
+        &self,
+        params: &NetworkZonesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::NetworkZone> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/api/v1/zones?{}", query_);
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Add Network Zone.
      *
@@ -190,3 +261,133 @@ impl NetworkZones {
         self.client.post(&url, None).await
     }
 }
+
+/// The query parameters of `NetworkZones::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkZonesListParams {
+    /// Specifies the pagination cursor for the next page of network zones.
+    pub after: Option<String>,
+    /// Filters zones by usage or id expression.
+    pub filter: Option<String>,
+    /// Specifies the number of results for a page.
+    pub limit: Option<i64>,
+}
+
+impl NetworkZonesListParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The functions of [`NetworkZones`], for code that takes them as a trait, so that it can be
+/// tested with the `MockNetworkZonesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait NetworkZonesApi: Send + Sync {
+    /// See [`NetworkZones::list_with`].
+    async fn list_with(
+        &self,
+        params: &NetworkZonesListParams,
+    ) -> Result<Vec<crate::types::NetworkZone>>;
+
+    /// See [`NetworkZones::list_all_with`].
+    async fn list_all_with(
+        &self,
+        params: &NetworkZonesListParams,
+    ) -> Result<Vec<crate::types::NetworkZone>>;
+
+    /// See [`NetworkZones::list_stream`].
+    fn list_stream(
+        &self,
+        params: &NetworkZonesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::NetworkZone>;
+
+    /// See [`NetworkZones::create`].
+    async fn create(&self, body: &crate::types::NetworkZone) -> Result<crate::types::NetworkZone>;
+
+    /// See [`NetworkZones::get`].
+    async fn get(&self, zone_id: &str) -> Result<crate::types::NetworkZone>;
+
+    /// See [`NetworkZones::update`].
+    async fn update(
+        &self,
+        zone_id: &str,
+        body: &crate::types::NetworkZone,
+    ) -> Result<crate::types::NetworkZone>;
+
+    /// See [`NetworkZones::delete`].
+    async fn delete(&self, zone_id: &str) -> Result<()>;
+
+    /// See [`NetworkZones::activate`].
+    async fn activate(&self, zone_id: &str) -> Result<crate::types::NetworkZone>;
+
+    /// See [`NetworkZones::deactivate`].
+    async fn deactivate(&self, zone_id: &str) -> Result<crate::types::NetworkZone>;
+}
+
+#[async_trait::async_trait]
+impl NetworkZonesApi for NetworkZones {
+    async fn list_with(
+        &self,
+        params: &NetworkZonesListParams,
+    ) -> Result<Vec<crate::types::NetworkZone>> {
+        NetworkZones::list_with(self, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        params: &NetworkZonesListParams,
+    ) -> Result<Vec<crate::types::NetworkZone>> {
+        NetworkZones::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &NetworkZonesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::NetworkZone> {
+        NetworkZones::list_stream(self, params, start, max_items)
+    }
+
+    async fn create(&self, body: &crate::types::NetworkZone) -> Result<crate::types::NetworkZone> {
+        NetworkZones::create(self, body).await
+    }
+
+    async fn get(&self, zone_id: &str) -> Result<crate::types::NetworkZone> {
+        NetworkZones::get(self, zone_id).await
+    }
+
+    async fn update(
+        &self,
+        zone_id: &str,
+        body: &crate::types::NetworkZone,
+    ) -> Result<crate::types::NetworkZone> {
+        NetworkZones::update(self, zone_id, body).await
+    }
+
+    async fn delete(&self, zone_id: &str) -> Result<()> {
+        NetworkZones::delete(self, zone_id).await
+    }
+
+    async fn activate(&self, zone_id: &str) -> Result<crate::types::NetworkZone> {
+        NetworkZones::activate(self, zone_id).await
+    }
+
+    async fn deactivate(&self, zone_id: &str) -> Result<crate::types::NetworkZone> {
+        NetworkZones::deactivate(self, zone_id).await
+    }
+}
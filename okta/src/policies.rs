@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Policies {
     pub client: Client,
@@ -19,24 +17,20 @@ impl Policies {
      *
      * **Parameters:**
      *
-     * * `type_: &str`
-     * * `status: &str`
-     * * `expand: &str`
+     * * `params: &PoliciesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        type_: &str,
-        status: &str,
-        expand: &str,
+        params: &PoliciesListParams,
     ) -> Result<Vec<crate::types::Policy>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -45,33 +39,115 @@ impl Policies {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `PoliciesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        type_: &str,
+        status: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Policy>> {
+        let params = PoliciesListParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/policies` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Gets all policies with the specified type.
+     *
+     * **Parameters:**
+     *
+     * * `params: &PoliciesListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(
+        &self,
+        params: &PoliciesListParams,
+    ) -> Result<Vec<crate::types::Policy>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if let Some(status) = &params.status {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/api/v1/policies?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `PoliciesListParams` instead.
      */
+    #[deprecated(note = "use `list_all_with` instead")]
     pub async fn list_all(
         &self,
         type_: &str,
         status: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Policy>> {
+        let params = PoliciesListParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/policies` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Gets all policies with the specified type.
+     */
+    pub fn list_stream(
+        &self,
+        params: &PoliciesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Policy> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/api/v1/policies?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -81,15 +157,15 @@ impl Policies {
      *
      * **Parameters:**
      *
-     * * `activate: bool`
+     * * `params: &PoliciesCreatePolicyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create_policy(
+    pub async fn create_policy_with(
         &self,
-        activate: bool,
+        params: &PoliciesCreatePolicyParams,
         body: &crate::types::Policy,
     ) -> Result<crate::types::Policy> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if activate {
+        if let Some(activate) = &params.activate {
             query_args.push(("activate".to_string(), activate.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -100,6 +176,21 @@ impl Policies {
             .await
     }
 
+    /**
+     * Deprecated: use `create_policy_with` and pass the query parameters as a `PoliciesCreatePolicyParams` instead.
+     */
+    #[deprecated(note = "use `create_policy_with` instead")]
+    pub async fn create_policy(
+        &self,
+        activate: bool,
+        body: &crate::types::Policy,
+    ) -> Result<crate::types::Policy> {
+        let params = PoliciesCreatePolicyParams {
+            activate: Some(activate).filter(|v| *v),
+        };
+        self.create_policy_with(&params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/policies/{policyId}` endpoint.
      *
@@ -108,11 +199,15 @@ impl Policies {
      * **Parameters:**
      *
      * * `policy_id: &str`
-     * * `expand: &str`
+     * * `params: &PoliciesGetPolicyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_policy(&self, policy_id: &str, expand: &str) -> Result<crate::types::Policy> {
+    pub async fn get_policy_with(
+        &self,
+        policy_id: &str,
+        params: &PoliciesGetPolicyParams,
+    ) -> Result<crate::types::Policy> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -125,6 +220,17 @@ impl Policies {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_policy_with` and pass the query parameters as a `PoliciesGetPolicyParams` instead.
+     */
+    #[deprecated(note = "use `get_policy_with` instead")]
+    pub async fn get_policy(&self, policy_id: &str, expand: &str) -> Result<crate::types::Policy> {
+        let params = PoliciesGetPolicyParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_policy_with(policy_id, &params).await
+    }
+
     /**
      * This function performs a `PUT` to the `/api/v1/policies/{policyId}` endpoint.
      *
@@ -243,6 +349,42 @@ impl Policies {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/policies/{policyId}/rules` endpoint.
+     *
+     * As opposed to `list_all_policy_rules`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates all policy rules.
+     */
+    pub fn list_policy_rules_stream(
+        &self,
+        policy_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PolicyRule> {
+        let url = format!(
+            "/api/v1/policies/{}/rules",
+            crate::progenitor_support::encode_path(policy_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/api/v1/policies/{policyId}/rules` endpoint.
      *
@@ -378,3 +520,267 @@ impl Policies {
         self.client.post(&url, None).await
     }
 }
+
+/// The query parameters of `Policies::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PoliciesListParams {
+    pub expand: Option<String>,
+    pub status: Option<String>,
+    pub type_: Option<String>,
+}
+
+impl PoliciesListParams {
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Policies::create_policy_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PoliciesCreatePolicyParams {
+    pub activate: Option<bool>,
+}
+
+impl PoliciesCreatePolicyParams {
+    pub fn activate(mut self, activate: bool) -> Self {
+        self.activate = Some(activate);
+        self
+    }
+}
+
+/// The query parameters of `Policies::get_policy_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PoliciesGetPolicyParams {
+    pub expand: Option<String>,
+}
+
+impl PoliciesGetPolicyParams {
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+}
+
+/// The functions of [`Policies`], for code that takes them as a trait, so that it can be
+/// tested with the `MockPoliciesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait PoliciesApi: Send + Sync {
+    /// See [`Policies::list_with`].
+    async fn list_with(&self, params: &PoliciesListParams) -> Result<Vec<crate::types::Policy>>;
+
+    /// See [`Policies::list_all_with`].
+    async fn list_all_with(&self, params: &PoliciesListParams)
+        -> Result<Vec<crate::types::Policy>>;
+
+    /// See [`Policies::list_stream`].
+    fn list_stream(
+        &self,
+        params: &PoliciesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Policy>;
+
+    /// See [`Policies::create_policy_with`].
+    async fn create_policy_with(
+        &self,
+        params: &PoliciesCreatePolicyParams,
+        body: &crate::types::Policy,
+    ) -> Result<crate::types::Policy>;
+
+    /// See [`Policies::get_policy_with`].
+    async fn get_policy_with(
+        &self,
+        policy_id: &str,
+        params: &PoliciesGetPolicyParams,
+    ) -> Result<crate::types::Policy>;
+
+    /// See [`Policies::update_policy`].
+    async fn update_policy(
+        &self,
+        policy_id: &str,
+        body: &crate::types::Policy,
+    ) -> Result<crate::types::Policy>;
+
+    /// See [`Policies::delete_policy`].
+    async fn delete_policy(&self, policy_id: &str) -> Result<()>;
+
+    /// See [`Policies::activate_policy`].
+    async fn activate_policy(&self, policy_id: &str) -> Result<()>;
+
+    /// See [`Policies::deactivate_policy`].
+    async fn deactivate_policy(&self, policy_id: &str) -> Result<()>;
+
+    /// See [`Policies::list_policy_rules`].
+    async fn list_policy_rules(&self, policy_id: &str) -> Result<Vec<crate::types::PolicyRule>>;
+
+    /// See [`Policies::list_all_policy_rules`].
+    async fn list_all_policy_rules(&self, policy_id: &str)
+        -> Result<Vec<crate::types::PolicyRule>>;
+
+    /// See [`Policies::list_policy_rules_stream`].
+    fn list_policy_rules_stream(
+        &self,
+        policy_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PolicyRule>;
+
+    /// See [`Policies::create_policy_rule`].
+    async fn create_policy_rule(
+        &self,
+        policy_id: &str,
+        body: &crate::types::PolicyRule,
+    ) -> Result<crate::types::PolicyRule>;
+
+    /// See [`Policies::get_policy_rule`].
+    async fn get_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> Result<crate::types::PolicyRule>;
+
+    /// See [`Policies::update_policy_rule`].
+    async fn update_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+        body: &crate::types::PolicyRule,
+    ) -> Result<crate::types::PolicyRule>;
+
+    /// See [`Policies::delete_policy_rule`].
+    async fn delete_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()>;
+
+    /// See [`Policies::activate_policy_rule`].
+    async fn activate_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()>;
+
+    /// See [`Policies::deactivate_policy_rule`].
+    async fn deactivate_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl PoliciesApi for Policies {
+    async fn list_with(&self, params: &PoliciesListParams) -> Result<Vec<crate::types::Policy>> {
+        Policies::list_with(self, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        params: &PoliciesListParams,
+    ) -> Result<Vec<crate::types::Policy>> {
+        Policies::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &PoliciesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Policy> {
+        Policies::list_stream(self, params, start, max_items)
+    }
+
+    async fn create_policy_with(
+        &self,
+        params: &PoliciesCreatePolicyParams,
+        body: &crate::types::Policy,
+    ) -> Result<crate::types::Policy> {
+        Policies::create_policy_with(self, params, body).await
+    }
+
+    async fn get_policy_with(
+        &self,
+        policy_id: &str,
+        params: &PoliciesGetPolicyParams,
+    ) -> Result<crate::types::Policy> {
+        Policies::get_policy_with(self, policy_id, params).await
+    }
+
+    async fn update_policy(
+        &self,
+        policy_id: &str,
+        body: &crate::types::Policy,
+    ) -> Result<crate::types::Policy> {
+        Policies::update_policy(self, policy_id, body).await
+    }
+
+    async fn delete_policy(&self, policy_id: &str) -> Result<()> {
+        Policies::delete_policy(self, policy_id).await
+    }
+
+    async fn activate_policy(&self, policy_id: &str) -> Result<()> {
+        Policies::activate_policy(self, policy_id).await
+    }
+
+    async fn deactivate_policy(&self, policy_id: &str) -> Result<()> {
+        Policies::deactivate_policy(self, policy_id).await
+    }
+
+    async fn list_policy_rules(&self, policy_id: &str) -> Result<Vec<crate::types::PolicyRule>> {
+        Policies::list_policy_rules(self, policy_id).await
+    }
+
+    async fn list_all_policy_rules(
+        &self,
+        policy_id: &str,
+    ) -> Result<Vec<crate::types::PolicyRule>> {
+        Policies::list_all_policy_rules(self, policy_id).await
+    }
+
+    fn list_policy_rules_stream(
+        &self,
+        policy_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PolicyRule> {
+        Policies::list_policy_rules_stream(self, policy_id, start, max_items)
+    }
+
+    async fn create_policy_rule(
+        &self,
+        policy_id: &str,
+        body: &crate::types::PolicyRule,
+    ) -> Result<crate::types::PolicyRule> {
+        Policies::create_policy_rule(self, policy_id, body).await
+    }
+
+    async fn get_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+    ) -> Result<crate::types::PolicyRule> {
+        Policies::get_policy_rule(self, policy_id, rule_id).await
+    }
+
+    async fn update_policy_rule(
+        &self,
+        policy_id: &str,
+        rule_id: &str,
+        body: &crate::types::PolicyRule,
+    ) -> Result<crate::types::PolicyRule> {
+        Policies::update_policy_rule(self, policy_id, rule_id, body).await
+    }
+
+    async fn delete_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()> {
+        Policies::delete_policy_rule(self, policy_id, rule_id).await
+    }
+
+    async fn activate_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()> {
+        Policies::activate_policy_rule(self, policy_id, rule_id).await
+    }
+
+    async fn deactivate_policy_rule(&self, policy_id: &str, rule_id: &str) -> Result<()> {
+        Policies::deactivate_policy_rule(self, policy_id, rule_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ProfileMappings {
     pub client: Client,
@@ -19,29 +17,23 @@ impl ProfileMappings {
      *
      * **Parameters:**
      *
-     * * `after: &str`
-     * * `limit: i64`
-     * * `source_id: &str`
-     * * `target_id: &str`
+     * * `params: &ProfileMappingsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        after: &str,
-        limit: i64,
-        source_id: &str,
-        target_id: &str,
+        params: &ProfileMappingsListParams,
     ) -> Result<Vec<crate::types::ProfileMapping>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !source_id.is_empty() {
+        if let Some(source_id) = &params.source_id {
             query_args.push(("sourceId".to_string(), source_id.to_string()));
         }
-        if !target_id.is_empty() {
+        if let Some(target_id) = &params.target_id {
             query_args.push(("targetId".to_string(), target_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -50,29 +42,110 @@ impl ProfileMappings {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `ProfileMappingsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        after: &str,
+        limit: i64,
+        source_id: &str,
+        target_id: &str,
+    ) -> Result<Vec<crate::types::ProfileMapping>> {
+        let params = ProfileMappingsListParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            source_id: Some(source_id.to_string()).filter(|s| !s.is_empty()),
+            target_id: Some(target_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/mappings` endpoint.
      *
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Enumerates Profile Mappings in your organization with pagination.
+     *
+     * **Parameters:**
+     *
+     * * `params: &ProfileMappingsListParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn list_all_with(
+        &self,
+        params: &ProfileMappingsListParams,
+    ) -> Result<Vec<crate::types::ProfileMapping>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(source_id) = &params.source_id {
+            query_args.push(("sourceId".to_string(), source_id.to_string()));
+        }
+        if let Some(target_id) = &params.target_id {
+            query_args.push(("targetId".to_string(), target_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/api/v1/mappings?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `ProfileMappingsListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
     pub async fn list_all(
         &self,
         source_id: &str,
         target_id: &str,
     ) -> Result<Vec<crate::types::ProfileMapping>> {
+        let params = ProfileMappingsListParams {
+            source_id: Some(source_id.to_string()).filter(|s| !s.is_empty()),
+            target_id: Some(target_id.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/mappings` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates Profile Mappings in your organization with pagination.
+     */
+    pub fn list_stream(
+        &self,
+        params: &ProfileMappingsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ProfileMapping> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !source_id.is_empty() {
+        if let Some(source_id) = &params.source_id {
             query_args.push(("sourceId".to_string(), source_id.to_string()));
         }
-        if !target_id.is_empty() {
+        if let Some(target_id) = &params.target_id {
             query_args.push(("targetId".to_string(), target_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/api/v1/mappings?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -121,3 +194,108 @@ impl ProfileMappings {
             .await
     }
 }
+
+/// The query parameters of `ProfileMappings::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProfileMappingsListParams {
+    pub after: Option<String>,
+    pub limit: Option<i64>,
+    pub source_id: Option<String>,
+    pub target_id: Option<String>,
+}
+
+impl ProfileMappingsListParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn source_id<T: ToString>(mut self, source_id: T) -> Self {
+        self.source_id = Some(source_id.to_string());
+        self
+    }
+
+    pub fn target_id<T: ToString>(mut self, target_id: T) -> Self {
+        self.target_id = Some(target_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`ProfileMappings`], for code that takes them as a trait, so that it can be
+/// tested with the `MockProfileMappingsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ProfileMappingsApi: Send + Sync {
+    /// See [`ProfileMappings::list_with`].
+    async fn list_with(
+        &self,
+        params: &ProfileMappingsListParams,
+    ) -> Result<Vec<crate::types::ProfileMapping>>;
+
+    /// See [`ProfileMappings::list_all_with`].
+    async fn list_all_with(
+        &self,
+        params: &ProfileMappingsListParams,
+    ) -> Result<Vec<crate::types::ProfileMapping>>;
+
+    /// See [`ProfileMappings::list_stream`].
+    fn list_stream(
+        &self,
+        params: &ProfileMappingsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ProfileMapping>;
+
+    /// See [`ProfileMappings::get`].
+    async fn get(&self, mapping_id: &str) -> Result<crate::types::ProfileMapping>;
+
+    /// See [`ProfileMappings::update`].
+    async fn update(
+        &self,
+        mapping_id: &str,
+        body: &crate::types::ProfileMapping,
+    ) -> Result<crate::types::ProfileMapping>;
+}
+
+#[async_trait::async_trait]
+impl ProfileMappingsApi for ProfileMappings {
+    async fn list_with(
+        &self,
+        params: &ProfileMappingsListParams,
+    ) -> Result<Vec<crate::types::ProfileMapping>> {
+        ProfileMappings::list_with(self, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        params: &ProfileMappingsListParams,
+    ) -> Result<Vec<crate::types::ProfileMapping>> {
+        ProfileMappings::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &ProfileMappingsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ProfileMapping> {
+        ProfileMappings::list_stream(self, params, start, max_items)
+    }
+
+    async fn get(&self, mapping_id: &str) -> Result<crate::types::ProfileMapping> {
+        ProfileMappings::get(self, mapping_id).await
+    }
+
+    async fn update(
+        &self,
+        mapping_id: &str,
+        body: &crate::types::ProfileMapping,
+    ) -> Result<crate::types::ProfileMapping> {
+        ProfileMappings::update(self, mapping_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Sessions {
     pub client: Client,
@@ -87,3 +85,46 @@ impl Sessions {
         self.client.post(&url, None).await
     }
 }
+
+/// The functions of [`Sessions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSessionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SessionsApi: Send + Sync {
+    /// See [`Sessions::create`].
+    async fn create(
+        &self,
+        body: &crate::types::CreateSessionRequest,
+    ) -> Result<crate::types::Session>;
+
+    /// See [`Sessions::get`].
+    async fn get(&self, session_id: &str) -> Result<crate::types::Session>;
+
+    /// See [`Sessions::end`].
+    async fn end(&self, session_id: &str) -> Result<()>;
+
+    /// See [`Sessions::refresh`].
+    async fn refresh(&self, session_id: &str) -> Result<crate::types::Session>;
+}
+
+#[async_trait::async_trait]
+impl SessionsApi for Sessions {
+    async fn create(
+        &self,
+        body: &crate::types::CreateSessionRequest,
+    ) -> Result<crate::types::Session> {
+        Sessions::create(self, body).await
+    }
+
+    async fn get(&self, session_id: &str) -> Result<crate::types::Session> {
+        Sessions::get(self, session_id).await
+    }
+
+    async fn end(&self, session_id: &str) -> Result<()> {
+        Sessions::end(self, session_id).await
+    }
+
+    async fn refresh(&self, session_id: &str) -> Result<crate::types::Session> {
+        Sessions::refresh(self, session_id).await
+    }
+}
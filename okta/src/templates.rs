@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Templates {
     pub client: Client,
@@ -21,11 +19,14 @@ impl Templates {
      *
      * **Parameters:**
      *
-     * * `template_type: &str`
+     * * `params: &TemplatesListSmsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_sms(&self, template_type: &str) -> Result<Vec<crate::types::SmsTemplate>> {
+    pub async fn list_sms_with(
+        &self,
+        params: &TemplatesListSmsParams,
+    ) -> Result<Vec<crate::types::SmsTemplate>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !template_type.is_empty() {
+        if let Some(template_type) = &params.template_type {
             query_args.push(("templateType".to_string(), template_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -34,6 +35,17 @@ impl Templates {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_sms_with` and pass the query parameters as a `TemplatesListSmsParams` instead.
+     */
+    #[deprecated(note = "use `list_sms_with` instead")]
+    pub async fn list_sms(&self, template_type: &str) -> Result<Vec<crate::types::SmsTemplate>> {
+        let params = TemplatesListSmsParams {
+            template_type: Some(template_type.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_sms_with(&params).await
+    }
+
     /**
      * List SMS Templates.
      *
@@ -42,19 +54,77 @@ impl Templates {
      * As opposed to `list_sms`, this function returns all the pages of the request at once.
      *
      * Enumerates custom SMS templates in your organization. A subset of templates can be returned that match a template type.
+     *
+     * **Parameters:**
+     *
+     * * `params: &TemplatesListSmsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_sms_with(
+        &self,
+        params: &TemplatesListSmsParams,
+    ) -> Result<Vec<crate::types::SmsTemplate>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(template_type) = &params.template_type {
+            query_args.push(("templateType".to_string(), template_type.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/api/v1/templates/sms?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_sms_with` and pass the query parameters as a `TemplatesListSmsParams` instead.
      */
+    #[deprecated(note = "use `list_all_sms_with` instead")]
     pub async fn list_all_sms(
         &self,
         template_type: &str,
     ) -> Result<Vec<crate::types::SmsTemplate>> {
+        let params = TemplatesListSmsParams {
+            template_type: Some(template_type.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_all_sms_with(&params).await
+    }
+
+    /**
+     * List SMS Templates.
+     *
+     * This function performs a `GET` to the `/api/v1/templates/sms` endpoint.
+     *
+     * As opposed to `list_all_sms`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates custom SMS templates in your organization. A subset of templates can be returned that match a template type.
+     */
+    pub fn list_sms_stream(
+        &self,
+        params: &TemplatesListSmsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SmsTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !template_type.is_empty() {
+        if let Some(template_type) = &params.template_type {
             query_args.push(("templateType".to_string(), template_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/api/v1/templates/sms?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -166,3 +236,125 @@ impl Templates {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Templates::list_sms_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TemplatesListSmsParams {
+    pub template_type: Option<String>,
+}
+
+impl TemplatesListSmsParams {
+    pub fn template_type<T: ToString>(mut self, template_type: T) -> Self {
+        self.template_type = Some(template_type.to_string());
+        self
+    }
+}
+
+/// The functions of [`Templates`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTemplatesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TemplatesApi: Send + Sync {
+    /// See [`Templates::list_sms_with`].
+    async fn list_sms_with(
+        &self,
+        params: &TemplatesListSmsParams,
+    ) -> Result<Vec<crate::types::SmsTemplate>>;
+
+    /// See [`Templates::list_all_sms_with`].
+    async fn list_all_sms_with(
+        &self,
+        params: &TemplatesListSmsParams,
+    ) -> Result<Vec<crate::types::SmsTemplate>>;
+
+    /// See [`Templates::list_sms_stream`].
+    fn list_sms_stream(
+        &self,
+        params: &TemplatesListSmsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SmsTemplate>;
+
+    /// See [`Templates::create_sms`].
+    async fn create_sms(
+        &self,
+        body: &crate::types::SmsTemplate,
+    ) -> Result<crate::types::SmsTemplate>;
+
+    /// See [`Templates::get_sm`].
+    async fn get_sm(&self, template_id: &str) -> Result<crate::types::SmsTemplate>;
+
+    /// See [`Templates::update_sms`].
+    async fn update_sms(
+        &self,
+        template_id: &str,
+        body: &crate::types::SmsTemplate,
+    ) -> Result<crate::types::SmsTemplate>;
+
+    /// See [`Templates::partial_update_sms`].
+    async fn partial_update_sms(
+        &self,
+        template_id: &str,
+        body: &crate::types::SmsTemplate,
+    ) -> Result<crate::types::SmsTemplate>;
+
+    /// See [`Templates::delete_sms`].
+    async fn delete_sms(&self, template_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl TemplatesApi for Templates {
+    async fn list_sms_with(
+        &self,
+        params: &TemplatesListSmsParams,
+    ) -> Result<Vec<crate::types::SmsTemplate>> {
+        Templates::list_sms_with(self, params).await
+    }
+
+    async fn list_all_sms_with(
+        &self,
+        params: &TemplatesListSmsParams,
+    ) -> Result<Vec<crate::types::SmsTemplate>> {
+        Templates::list_all_sms_with(self, params).await
+    }
+
+    fn list_sms_stream(
+        &self,
+        params: &TemplatesListSmsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SmsTemplate> {
+        Templates::list_sms_stream(self, params, start, max_items)
+    }
+
+    async fn create_sms(
+        &self,
+        body: &crate::types::SmsTemplate,
+    ) -> Result<crate::types::SmsTemplate> {
+        Templates::create_sms(self, body).await
+    }
+
+    async fn get_sm(&self, template_id: &str) -> Result<crate::types::SmsTemplate> {
+        Templates::get_sm(self, template_id).await
+    }
+
+    async fn update_sms(
+        &self,
+        template_id: &str,
+        body: &crate::types::SmsTemplate,
+    ) -> Result<crate::types::SmsTemplate> {
+        Templates::update_sms(self, template_id, body).await
+    }
+
+    async fn partial_update_sms(
+        &self,
+        template_id: &str,
+        body: &crate::types::SmsTemplate,
+    ) -> Result<crate::types::SmsTemplate> {
+        Templates::partial_update_sms(self, template_id, body).await
+    }
+
+    async fn delete_sms(&self, template_id: &str) -> Result<()> {
+        Templates::delete_sms(self, template_id).await
+    }
+}
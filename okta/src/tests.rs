@@ -1 +1,24 @@
+#[test]
+fn test_for_org() {
+    let okta = crate::Client::for_org("https://example.okta.com/", "api-token");
+    assert_eq!(okta.host, "https://example.okta.com");
+    assert!(okta.service_app.is_none());
+}
 
+#[tokio::test]
+async fn test_service_app_invalid_private_key() {
+    let okta = crate::Client::for_service_app(
+        "https://example.okta.com",
+        crate::ServiceApp {
+            client_id: "client-id".to_string(),
+            private_key: "not a key".to_string(),
+            key_id: None,
+            scopes: vec!["okta.users.read".to_string()],
+        },
+    );
+
+    match okta.get_access_token().await {
+        Err(crate::Error::Auth(e)) => assert!(e.starts_with("invalid private key")),
+        r => panic!("expected an auth error, got {:?}", r.map(|t| t.token_type)),
+    }
+}
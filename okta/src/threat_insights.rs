@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ThreatInsights {
     pub client: Client,
@@ -39,3 +37,32 @@ impl ThreatInsights {
             .await
     }
 }
+
+/// The functions of [`ThreatInsights`], for code that takes them as a trait, so that it can be
+/// tested with the `MockThreatInsightsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ThreatInsightsApi: Send + Sync {
+    /// See [`ThreatInsights::get_current_configuration`].
+    async fn get_current_configuration(&self) -> Result<crate::types::ThreatInsightConfiguration>;
+
+    /// See [`ThreatInsights::update_configuration`].
+    async fn update_configuration(
+        &self,
+        body: &crate::types::ThreatInsightConfiguration,
+    ) -> Result<crate::types::ThreatInsightConfiguration>;
+}
+
+#[async_trait::async_trait]
+impl ThreatInsightsApi for ThreatInsights {
+    async fn get_current_configuration(&self) -> Result<crate::types::ThreatInsightConfiguration> {
+        ThreatInsights::get_current_configuration(self).await
+    }
+
+    async fn update_configuration(
+        &self,
+        body: &crate::types::ThreatInsightConfiguration,
+    ) -> Result<crate::types::ThreatInsightConfiguration> {
+        ThreatInsights::update_configuration(self, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TrustedOrigins {
     pub client: Client,
@@ -19,29 +17,23 @@ impl TrustedOrigins {
      *
      * **Parameters:**
      *
-     * * `q: &str`
-     * * `filter: &str`
-     * * `after: &str`
-     * * `limit: i64`
+     * * `params: &TrustedOriginsListOriginsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_origins(
+    pub async fn list_origins_with(
         &self,
-        q: &str,
-        filter: &str,
-        after: &str,
-        limit: i64,
+        params: &TrustedOriginsListOriginsParams,
     ) -> Result<Vec<crate::types::TrustedOrigin>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -50,29 +42,110 @@ impl TrustedOrigins {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_origins_with` and pass the query parameters as a `TrustedOriginsListOriginsParams` instead.
+     */
+    #[deprecated(note = "use `list_origins_with` instead")]
+    pub async fn list_origins(
+        &self,
+        q: &str,
+        filter: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::TrustedOrigin>> {
+        let params = TrustedOriginsListOriginsParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_origins_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/trustedOrigins` endpoint.
      *
      * As opposed to `list_origins`, this function returns all the pages of the request at once.
      *
      * Success
+     *
+     * **Parameters:**
+     *
+     * * `params: &TrustedOriginsListOriginsParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn list_all_origins_with(
+        &self,
+        params: &TrustedOriginsListOriginsParams,
+    ) -> Result<Vec<crate::types::TrustedOrigin>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/api/v1/trustedOrigins?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_origins_with` and pass the query parameters as a `TrustedOriginsListOriginsParams` instead.
+     */
+    #[deprecated(note = "use `list_all_origins_with` instead")]
     pub async fn list_all_origins(
         &self,
         q: &str,
         filter: &str,
     ) -> Result<Vec<crate::types::TrustedOrigin>> {
+        let params = TrustedOriginsListOriginsParams {
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_origins_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/trustedOrigins` endpoint.
+     *
+     * As opposed to `list_all_origins`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_origins_stream(
+        &self,
+        params: &TrustedOriginsListOriginsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TrustedOrigin> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/api/v1/trustedOrigins?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -192,3 +265,152 @@ impl TrustedOrigins {
         self.client.post(&url, None).await
     }
 }
+
+/// The query parameters of `TrustedOrigins::list_origins_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedOriginsListOriginsParams {
+    pub after: Option<String>,
+    pub filter: Option<String>,
+    pub limit: Option<i64>,
+    pub q: Option<String>,
+}
+
+impl TrustedOriginsListOriginsParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+}
+
+/// The functions of [`TrustedOrigins`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTrustedOriginsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TrustedOriginsApi: Send + Sync {
+    /// See [`TrustedOrigins::list_origins_with`].
+    async fn list_origins_with(
+        &self,
+        params: &TrustedOriginsListOriginsParams,
+    ) -> Result<Vec<crate::types::TrustedOrigin>>;
+
+    /// See [`TrustedOrigins::list_all_origins_with`].
+    async fn list_all_origins_with(
+        &self,
+        params: &TrustedOriginsListOriginsParams,
+    ) -> Result<Vec<crate::types::TrustedOrigin>>;
+
+    /// See [`TrustedOrigins::list_origins_stream`].
+    fn list_origins_stream(
+        &self,
+        params: &TrustedOriginsListOriginsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TrustedOrigin>;
+
+    /// See [`TrustedOrigins::create_origin`].
+    async fn create_origin(
+        &self,
+        body: &crate::types::TrustedOrigin,
+    ) -> Result<crate::types::TrustedOrigin>;
+
+    /// See [`TrustedOrigins::get_origin`].
+    async fn get_origin(&self, trusted_origin_id: &str) -> Result<crate::types::TrustedOrigin>;
+
+    /// See [`TrustedOrigins::update_origin`].
+    async fn update_origin(
+        &self,
+        trusted_origin_id: &str,
+        body: &crate::types::TrustedOrigin,
+    ) -> Result<crate::types::TrustedOrigin>;
+
+    /// See [`TrustedOrigins::delete_origin`].
+    async fn delete_origin(&self, trusted_origin_id: &str) -> Result<()>;
+
+    /// See [`TrustedOrigins::activate_origin`].
+    async fn activate_origin(&self, trusted_origin_id: &str)
+        -> Result<crate::types::TrustedOrigin>;
+
+    /// See [`TrustedOrigins::deactivate_origin`].
+    async fn deactivate_origin(
+        &self,
+        trusted_origin_id: &str,
+    ) -> Result<crate::types::TrustedOrigin>;
+}
+
+#[async_trait::async_trait]
+impl TrustedOriginsApi for TrustedOrigins {
+    async fn list_origins_with(
+        &self,
+        params: &TrustedOriginsListOriginsParams,
+    ) -> Result<Vec<crate::types::TrustedOrigin>> {
+        TrustedOrigins::list_origins_with(self, params).await
+    }
+
+    async fn list_all_origins_with(
+        &self,
+        params: &TrustedOriginsListOriginsParams,
+    ) -> Result<Vec<crate::types::TrustedOrigin>> {
+        TrustedOrigins::list_all_origins_with(self, params).await
+    }
+
+    fn list_origins_stream(
+        &self,
+        params: &TrustedOriginsListOriginsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TrustedOrigin> {
+        TrustedOrigins::list_origins_stream(self, params, start, max_items)
+    }
+
+    async fn create_origin(
+        &self,
+        body: &crate::types::TrustedOrigin,
+    ) -> Result<crate::types::TrustedOrigin> {
+        TrustedOrigins::create_origin(self, body).await
+    }
+
+    async fn get_origin(&self, trusted_origin_id: &str) -> Result<crate::types::TrustedOrigin> {
+        TrustedOrigins::get_origin(self, trusted_origin_id).await
+    }
+
+    async fn update_origin(
+        &self,
+        trusted_origin_id: &str,
+        body: &crate::types::TrustedOrigin,
+    ) -> Result<crate::types::TrustedOrigin> {
+        TrustedOrigins::update_origin(self, trusted_origin_id, body).await
+    }
+
+    async fn delete_origin(&self, trusted_origin_id: &str) -> Result<()> {
+        TrustedOrigins::delete_origin(self, trusted_origin_id).await
+    }
+
+    async fn activate_origin(
+        &self,
+        trusted_origin_id: &str,
+    ) -> Result<crate::types::TrustedOrigin> {
+        TrustedOrigins::activate_origin(self, trusted_origin_id).await
+    }
+
+    async fn deactivate_origin(
+        &self,
+        trusted_origin_id: &str,
+    ) -> Result<crate::types::TrustedOrigin> {
+        TrustedOrigins::deactivate_origin(self, trusted_origin_id).await
+    }
+}
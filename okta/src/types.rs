@@ -2,6 +2,204 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> std::option::Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> std::convert::From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match std::option::Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        std::option::Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        std::option::Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
+
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+
+#[cfg(feature = "applications")]
+mod applications;
+#[cfg(feature = "applications")]
+pub use applications::*;
+
+#[cfg(feature = "authorization_servers")]
+mod authorization_servers;
+#[cfg(feature = "authorization_servers")]
+pub use authorization_servers::*;
+
+#[cfg(feature = "domains")]
+mod domains;
+#[cfg(feature = "domains")]
+pub use domains::*;
+
+#[cfg(feature = "event_hooks")]
+mod event_hooks;
+#[cfg(feature = "event_hooks")]
+pub use event_hooks::*;
+
+#[cfg(feature = "features")]
+mod features;
+#[cfg(feature = "features")]
+pub use features::*;
+
+#[cfg(feature = "groups")]
+mod groups;
+#[cfg(feature = "groups")]
+pub use groups::*;
+
+#[cfg(feature = "identity_providers")]
+mod identity_providers;
+#[cfg(feature = "identity_providers")]
+pub use identity_providers::*;
+
+#[cfg(feature = "inline_hooks")]
+mod inline_hooks;
+#[cfg(feature = "inline_hooks")]
+pub use inline_hooks::*;
+
+#[cfg(feature = "linked_objects")]
+mod linked_objects;
+#[cfg(feature = "linked_objects")]
+pub use linked_objects::*;
+
+#[cfg(feature = "logs")]
+mod logs;
+#[cfg(feature = "logs")]
+pub use logs::*;
+
+#[cfg(feature = "network_zones")]
+mod network_zones;
+#[cfg(feature = "network_zones")]
+pub use network_zones::*;
+
+#[cfg(feature = "policies")]
+mod policies;
+#[cfg(feature = "policies")]
+pub use policies::*;
+
+#[cfg(feature = "profile_mappings")]
+mod profile_mappings;
+#[cfg(feature = "profile_mappings")]
+pub use profile_mappings::*;
+
+#[cfg(feature = "sessions")]
+mod sessions;
+#[cfg(feature = "sessions")]
+pub use sessions::*;
+
+#[cfg(feature = "templates")]
+mod templates;
+#[cfg(feature = "templates")]
+pub use templates::*;
+
+#[cfg(feature = "threat_insights")]
+mod threat_insights;
+#[cfg(feature = "threat_insights")]
+pub use threat_insights::*;
+
+#[cfg(feature = "trusted_origins")]
+mod trusted_origins;
+#[cfg(feature = "trusted_origins")]
+pub use trusted_origins::*;
+
+#[cfg(feature = "user_factors")]
+mod user_factors;
+#[cfg(feature = "user_factors")]
+pub use user_factors::*;
+
+#[cfg(feature = "user_schemas")]
+mod user_schemas;
+#[cfg(feature = "user_schemas")]
+pub use user_schemas::*;
+
+#[cfg(feature = "users")]
+mod users;
+#[cfg(feature = "users")]
+pub use users::*;
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct AcsEndpoint {
     #[serde(
@@ -19,302 +217,94 @@ pub struct AcsEndpoint {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ActivateFactorRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub attestation: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "clientData"
-    )]
-    pub client_data: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "passCode"
-    )]
-    pub pass_code: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "registrationData"
-    )]
-    pub registration_data: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "stateToken"
-    )]
-    pub state_token: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "APP")]
     App,
     #[serde(rename = "APP_TYPE")]
     AppType,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Type {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        match self {
             Type::App => "APP",
             Type::AppType => "APP_TYPE",
-            Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Type {
-    fn default() -> Type {
-        Type::Noop
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Type {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Type::Noop)
+
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "APP" => Type::App,
+            "APP_TYPE" => Type::AppType,
+            _ => Type::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AppInstanceConditionEvaluatorOr {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<Type>,
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AppInstancePolicyRuleCondition {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub exclude: Vec<AppInstanceConditionEvaluatorOr>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub include: Vec<AppInstanceConditionEvaluatorOr>,
+impl std::convert::From<Type> for String {
+    fn from(v: Type) -> Self {
+        match v {
+            Type::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserCondition {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub exclude: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub include: Vec<String>,
+pub struct AppInstanceConditionEvaluatorOr {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<Type>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AppLink {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "appAssignmentId"
-    )]
-    pub app_assignment_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "appInstanceId"
-    )]
-    pub app_instance_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "appName"
-    )]
-    pub app_name: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "credentialsSetup"
-    )]
-    pub credentials_setup: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub hidden: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub label: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "linkUrl"
-    )]
-    pub link_url: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "logoUrl"
-    )]
-    pub logo_url: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "sortOrder"
-    )]
-    pub sort_order: i64,
-}
+pub struct Embedded {}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Links {}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AppUser {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub credentials: Option<AppUserCredentials>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "externalId"
-    )]
-    pub external_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastSync"
-    )]
-    pub last_sync: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "passwordChanged"
-    )]
-    pub password_changed: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub scope: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub status: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "statusChanged"
-    )]
-    pub status_changed: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "syncState"
-    )]
-    pub sync_state: String,
-}
+pub struct Profile {}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AppUserCredentials {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub password: Option<AppUserPasswordCredential>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userName"
-    )]
-    pub user_name: String,
-}
+pub struct EmbeddedAdditionalProperties {}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AppUserPasswordCredential {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
-}
+pub struct LinksAdditionalProperties {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProfileAdditionalProperties {}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Status {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -322,151 +312,74 @@ pub enum Status {
     Deleted,
     #[serde(rename = "INACTIVE")]
     Inactive,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Status {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        match self {
             Status::Active => "ACTIVE",
             Status::Deleted => "DELETED",
             Status::Inactive => "INACTIVE",
-            Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Status {
-    fn default() -> Status {
-        Status::Noop
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => Status::Active,
+            "DELETED" => Status::Deleted,
+            "INACTIVE" => Status::Inactive,
+            _ => Status::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Status {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Status::Noop)
+
+impl std::convert::From<Status> for String {
+    fn from(v: Status) -> Self {
+        match v {
+            Status::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Application {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub accessibility: Option<ApplicationAccessibility>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub credentials: Option<ApplicationCredentials>,
+pub struct ApplicationCredentialsOAuthClient {
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "autoKeyRotation"
     )]
-    pub features: Vec<String>,
+    pub auto_key_rotation: bool,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub label: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub licensing: Option<ApplicationLicensing>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub settings: Option<ApplicationSettings>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "signOnMode"
-    )]
-    pub sign_on_mode: Option<ApplicationSignOnMode>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<Status>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub visibility: Option<ApplicationVisibility>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApplicationAccessibility {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "errorRedirectUrl"
-    )]
-    pub error_redirect_url: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "loginRedirectUrl"
-    )]
-    pub login_redirect_url: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "selfService"
-    )]
-    pub self_service: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApplicationCredentials {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub signing: Option<ApplicationCredentialsSigning>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "userNameTemplate"
-    )]
-    pub user_name_template: Option<ApplicationCredentialsUsernameTemplate>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApplicationCredentialsOAuthClient {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "autoKeyRotation"
-    )]
-    pub auto_key_rotation: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub client_id: String,
+    pub client_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -478,6 +391,7 @@ pub struct ApplicationCredentialsOAuthClient {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ApplicationCredentialsScheme {
     #[serde(rename = "ADMIN_SETS_CREDENTIALS")]
     AdminSetsCredentials,
@@ -489,15 +403,15 @@ pub enum ApplicationCredentialsScheme {
     ExternalPasswordSync,
     #[serde(rename = "SHARED_USERNAME_AND_PASSWORD")]
     SharedUsernameAndPassword,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ApplicationCredentialsScheme {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ApplicationCredentialsScheme {
+    fn as_ref(&self) -> &str {
+        match self {
             ApplicationCredentialsScheme::AdminSetsCredentials => "ADMIN_SETS_CREDENTIALS",
             ApplicationCredentialsScheme::EditPasswordOnly => "EDIT_PASSWORD_ONLY",
             ApplicationCredentialsScheme::EditUsernameAndPassword => "EDIT_USERNAME_AND_PASSWORD",
@@ -505,32 +419,56 @@ impl std::fmt::Display for ApplicationCredentialsScheme {
             ApplicationCredentialsScheme::SharedUsernameAndPassword => {
                 "SHARED_USERNAME_AND_PASSWORD"
             }
-            ApplicationCredentialsScheme::Noop => "",
-            ApplicationCredentialsScheme::FallthroughString => "*",
+            ApplicationCredentialsScheme::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ApplicationCredentialsScheme {
-    fn default() -> ApplicationCredentialsScheme {
-        ApplicationCredentialsScheme::Noop
+impl std::fmt::Display for ApplicationCredentialsScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ApplicationCredentialsScheme {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ADMIN_SETS_CREDENTIALS" => ApplicationCredentialsScheme::AdminSetsCredentials,
+            "EDIT_PASSWORD_ONLY" => ApplicationCredentialsScheme::EditPasswordOnly,
+            "EDIT_USERNAME_AND_PASSWORD" => ApplicationCredentialsScheme::EditUsernameAndPassword,
+            "EXTERNAL_PASSWORD_SYNC" => ApplicationCredentialsScheme::ExternalPasswordSync,
+            "SHARED_USERNAME_AND_PASSWORD" => {
+                ApplicationCredentialsScheme::SharedUsernameAndPassword
+            }
+            _ => ApplicationCredentialsScheme::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ApplicationCredentialsScheme {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ApplicationCredentialsScheme {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ApplicationCredentialsScheme::Noop)
+
+impl std::convert::From<ApplicationCredentialsScheme> for String {
+    fn from(v: ApplicationCredentialsScheme) -> Self {
+        match v {
+            ApplicationCredentialsScheme::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct ApplicationCredentialsSigning {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kid: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -547,690 +485,364 @@ pub struct ApplicationCredentialsSigning {
     pub next_rotation: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "rotationMode"
     )]
-    pub rotation_mode: String,
+    pub rotation_mode: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "use")]
-    pub use_: Option<Use>,
+    pub use_: Option<ApplicationCredentialsSigningUse>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Use {
+#[serde(from = "String", into = "String")]
+pub enum ApplicationCredentialsSigningUse {
     #[serde(rename = "sig")]
     Sig,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Use {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Use::Sig => "sig",
-            Use::Noop => "",
-            Use::FallthroughString => "*",
+impl AsRef<str> for ApplicationCredentialsSigningUse {
+    fn as_ref(&self) -> &str {
+        match self {
+            ApplicationCredentialsSigningUse::Sig => "sig",
+            ApplicationCredentialsSigningUse::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Use {
-    fn default() -> Use {
-        Use::Noop
-    }
-}
-impl Use {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Use::Noop)
+impl std::fmt::Display for ApplicationCredentialsSigningUse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApplicationCredentialsUsernameTemplate {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub suffix: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub template: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApplicationGroupAssignment {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub priority: i64,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<Links>,
-}
+impl std::str::FromStr for ApplicationCredentialsSigningUse {
+    type Err = std::convert::Infallible;
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApplicationLicensing {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "seatCount"
-    )]
-    pub seat_count: i64,
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "sig" => ApplicationCredentialsSigningUse::Sig,
+            _ => ApplicationCredentialsSigningUse::Unknown(s.to_string()),
+        })
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApplicationSettings {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub app: Option<serde_json::Value>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "implicitAssignment"
-    )]
-    pub implicit_assignment: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "inlineHookId"
-    )]
-    pub inline_hook_id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub notifications: Option<ApplicationSettingsNotifications>,
+impl std::convert::From<String> for ApplicationCredentialsSigningUse {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApplicationSettingsNotifications {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub vpn: Option<ApplicationSettingsNotificationsVpn>,
+impl std::convert::From<ApplicationCredentialsSigningUse> for String {
+    fn from(v: ApplicationCredentialsSigningUse) -> Self {
+        match v {
+            ApplicationCredentialsSigningUse::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApplicationSettingsNotificationsVpn {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "helpUrl"
-    )]
-    pub help_url: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub network: Option<ApplicationSettingsNotificationsVpnNetwork>,
+#[serde(from = "String", into = "String")]
+pub enum IssuerMode {
+    #[serde(rename = "CUSTOM_URL")]
+    CustomUrl,
+    #[serde(rename = "ORG_URL")]
+    OrgUrl,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApplicationSettingsNotificationsVpnNetwork {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub connection: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub exclude: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub include: Vec<String>,
+impl AsRef<str> for IssuerMode {
+    fn as_ref(&self) -> &str {
+        match self {
+            IssuerMode::CustomUrl => "CUSTOM_URL",
+            IssuerMode::OrgUrl => "ORG_URL",
+            IssuerMode::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ApplicationSignOnMode {
-    #[serde(rename = "AUTO_LOGIN")]
-    AutoLogin,
-    #[serde(rename = "BASIC_AUTH")]
-    BasicAuth,
-    #[serde(rename = "BOOKMARK")]
-    Bookmark,
-    #[serde(rename = "BROWSER_PLUGIN")]
-    BrowserPlugin,
-    #[serde(rename = "OPENID_CONNECT")]
-    OpenidConnect,
-    #[serde(rename = "SAML_1_1")]
-    Saml1,
-    #[serde(rename = "SAML_2_0")]
-    Saml20,
-    #[serde(rename = "SECURE_PASSWORD_STORE")]
-    SecurePasswordStore,
-    #[serde(rename = "WS_FEDERATION")]
-    WsFederation,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for ApplicationSignOnMode {
+impl std::fmt::Display for IssuerMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ApplicationSignOnMode::AutoLogin => "AUTO_LOGIN",
-            ApplicationSignOnMode::BasicAuth => "BASIC_AUTH",
-            ApplicationSignOnMode::Bookmark => "BOOKMARK",
-            ApplicationSignOnMode::BrowserPlugin => "BROWSER_PLUGIN",
-            ApplicationSignOnMode::OpenidConnect => "OPENID_CONNECT",
-            ApplicationSignOnMode::Saml1 => "SAML_1_1",
-            ApplicationSignOnMode::Saml20 => "SAML_2_0",
-            ApplicationSignOnMode::SecurePasswordStore => "SECURE_PASSWORD_STORE",
-            ApplicationSignOnMode::WsFederation => "WS_FEDERATION",
-            ApplicationSignOnMode::Noop => "",
-            ApplicationSignOnMode::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
     }
 }
 
-impl Default for ApplicationSignOnMode {
-    fn default() -> ApplicationSignOnMode {
-        ApplicationSignOnMode::Noop
+impl std::str::FromStr for IssuerMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CUSTOM_URL" => IssuerMode::CustomUrl,
+            "ORG_URL" => IssuerMode::OrgUrl,
+            _ => IssuerMode::Unknown(s.to_string()),
+        })
     }
 }
-impl ApplicationSignOnMode {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ApplicationSignOnMode::Noop)
+
+impl std::convert::From<String> for IssuerMode {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApplicationVisibility {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "appLinks"
-    )]
-    pub app_links: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "autoSubmitToolbar"
-    )]
-    pub auto_submit_toolbar: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub hide: Option<ApplicationVisibilityHide>,
+impl std::convert::From<IssuerMode> for String {
+    fn from(v: IssuerMode) -> Self {
+        match v {
+            IssuerMode::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApplicationVisibilityHide {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "iOS"
-    )]
-    pub i_os: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub web: bool,
+#[serde(from = "String", into = "String")]
+pub enum AuthorizationServerStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for AuthorizationServerStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            AuthorizationServerStatus::Active => "ACTIVE",
+            AuthorizationServerStatus::Inactive => "INACTIVE",
+            AuthorizationServerStatus::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AssignRoleRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<RoleType>,
+impl std::fmt::Display for AuthorizationServerStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthenticationProvider {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<AuthenticationProviderType>,
-}
+impl std::str::FromStr for AuthorizationServerStatus {
+    type Err = std::convert::Infallible;
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum AuthenticationProviderType {
-    #[serde(rename = "ACTIVE_DIRECTORY")]
-    ActiveDirectory,
-    #[serde(rename = "FEDERATION")]
-    Federation,
-    #[serde(rename = "IMPORT")]
-    Import,
-    #[serde(rename = "LDAP")]
-    Ldap,
-    #[serde(rename = "OKTA")]
-    Okta,
-    #[serde(rename = "SOCIAL")]
-    Social,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => AuthorizationServerStatus::Active,
+            "INACTIVE" => AuthorizationServerStatus::Inactive,
+            _ => AuthorizationServerStatus::Unknown(s.to_string()),
+        })
+    }
 }
 
-impl std::fmt::Display for AuthenticationProviderType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            AuthenticationProviderType::ActiveDirectory => "ACTIVE_DIRECTORY",
-            AuthenticationProviderType::Federation => "FEDERATION",
-            AuthenticationProviderType::Import => "IMPORT",
-            AuthenticationProviderType::Ldap => "LDAP",
-            AuthenticationProviderType::Okta => "OKTA",
-            AuthenticationProviderType::Social => "SOCIAL",
-            AuthenticationProviderType::Noop => "",
-            AuthenticationProviderType::FallthroughString => "*",
+impl std::convert::From<String> for AuthorizationServerStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
         }
-        .fmt(f)
     }
 }
 
-impl Default for AuthenticationProviderType {
-    fn default() -> AuthenticationProviderType {
-        AuthenticationProviderType::Noop
-    }
-}
-impl AuthenticationProviderType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AuthenticationProviderType::Noop)
+impl std::convert::From<AuthorizationServerStatus> for String {
+    fn from(v: AuthorizationServerStatus) -> Self {
+        match v {
+            AuthorizationServerStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum IssuerMode {
-    #[serde(rename = "CUSTOM_URL")]
-    CustomUrl,
-    #[serde(rename = "ORG_URL")]
-    OrgUrl,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum AuthorizationServerPolicyStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for AuthorizationServerPolicyStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            AuthorizationServerPolicyStatus::Active => "ACTIVE",
+            AuthorizationServerPolicyStatus::Inactive => "INACTIVE",
+            AuthorizationServerPolicyStatus::Unknown(s) => s,
+        }
+    }
 }
 
-impl std::fmt::Display for IssuerMode {
+impl std::fmt::Display for AuthorizationServerPolicyStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            IssuerMode::CustomUrl => "CUSTOM_URL",
-            IssuerMode::OrgUrl => "ORG_URL",
-            IssuerMode::Noop => "",
-            IssuerMode::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AuthorizationServerPolicyStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => AuthorizationServerPolicyStatus::Active,
+            "INACTIVE" => AuthorizationServerPolicyStatus::Inactive,
+            _ => AuthorizationServerPolicyStatus::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for IssuerMode {
-    fn default() -> IssuerMode {
-        IssuerMode::Noop
+impl std::convert::From<String> for AuthorizationServerPolicyStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl IssuerMode {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, IssuerMode::Noop)
+
+impl std::convert::From<AuthorizationServerPolicyStatus> for String {
+    fn from(v: AuthorizationServerPolicyStatus) -> Self {
+        match v {
+            AuthorizationServerPolicyStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum RoleStatus {
+#[serde(from = "String", into = "String")]
+pub enum AuthorizationServerPolicyRuleStatus {
     #[serde(rename = "ACTIVE")]
     Active,
     #[serde(rename = "INACTIVE")]
     Inactive,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for RoleStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            RoleStatus::Active => "ACTIVE",
-            RoleStatus::Inactive => "INACTIVE",
-            RoleStatus::Noop => "",
-            RoleStatus::FallthroughString => "*",
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for AuthorizationServerPolicyRuleStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            AuthorizationServerPolicyRuleStatus::Active => "ACTIVE",
+            AuthorizationServerPolicyRuleStatus::Inactive => "INACTIVE",
+            AuthorizationServerPolicyRuleStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for RoleStatus {
-    fn default() -> RoleStatus {
-        RoleStatus::Noop
-    }
-}
-impl RoleStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RoleStatus::Noop)
+impl std::fmt::Display for AuthorizationServerPolicyRuleStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthorizationServer {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub audiences: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub credentials: Option<AuthorizationServerCredentials>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub issuer: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "issuerMode"
-    )]
-    pub issuer_mode: Option<IssuerMode>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthorizationServerCredentials {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub signing: Option<AuthorizationServerCredentialsSigningConfig>,
-}
+impl std::str::FromStr for AuthorizationServerPolicyRuleStatus {
+    type Err = std::convert::Infallible;
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum AuthorizationServerCredentialsRotationMode {
-    #[serde(rename = "AUTO")]
-    Auto,
-    #[serde(rename = "MANUAL")]
-    Manual,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => AuthorizationServerPolicyRuleStatus::Active,
+            "INACTIVE" => AuthorizationServerPolicyRuleStatus::Inactive,
+            _ => AuthorizationServerPolicyRuleStatus::Unknown(s.to_string()),
+        })
+    }
 }
 
-impl std::fmt::Display for AuthorizationServerCredentialsRotationMode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            AuthorizationServerCredentialsRotationMode::Auto => "AUTO",
-            AuthorizationServerCredentialsRotationMode::Manual => "MANUAL",
-            AuthorizationServerCredentialsRotationMode::Noop => "",
-            AuthorizationServerCredentialsRotationMode::FallthroughString => "*",
+impl std::convert::From<String> for AuthorizationServerPolicyRuleStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
         }
-        .fmt(f)
     }
 }
 
-impl Default for AuthorizationServerCredentialsRotationMode {
-    fn default() -> AuthorizationServerCredentialsRotationMode {
-        AuthorizationServerCredentialsRotationMode::Noop
-    }
-}
-impl AuthorizationServerCredentialsRotationMode {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AuthorizationServerCredentialsRotationMode::Noop)
+impl std::convert::From<AuthorizationServerPolicyRuleStatus> for String {
+    fn from(v: AuthorizationServerPolicyRuleStatus) -> Self {
+        match v {
+            AuthorizationServerPolicyRuleStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthorizationServerCredentialsSigningConfig {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kid: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastRotated"
-    )]
-    pub last_rotated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "nextRotation"
-    )]
-    pub next_rotation: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "rotationMode"
-    )]
-    pub rotation_mode: Option<AuthorizationServerCredentialsRotationMode>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "use")]
-    pub use_: Option<Use>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthorizationServerPolicy {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub conditions: Option<PolicyRuleConditions>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub priority: i64,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub system: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<PolicyType>,
+impl Default for AuthorizationServerPolicyRuleStatus {
+    fn default() -> AuthorizationServerPolicyRuleStatus {
+        AuthorizationServerPolicyRuleStatus::Active
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AuthorizationServerPolicyRuleType {
     #[serde(rename = "RESOURCE_ACCESS")]
     ResourceAccess,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AuthorizationServerPolicyRuleType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AuthorizationServerPolicyRuleType {
+    fn as_ref(&self) -> &str {
+        match self {
             AuthorizationServerPolicyRuleType::ResourceAccess => "RESOURCE_ACCESS",
-            AuthorizationServerPolicyRuleType::Noop => "",
-            AuthorizationServerPolicyRuleType::FallthroughString => "*",
+            AuthorizationServerPolicyRuleType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AuthorizationServerPolicyRuleType {
-    fn default() -> AuthorizationServerPolicyRuleType {
-        AuthorizationServerPolicyRuleType::Noop
-    }
-}
-impl AuthorizationServerPolicyRuleType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AuthorizationServerPolicyRuleType::Noop)
+impl std::fmt::Display for AuthorizationServerPolicyRuleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthorizationServerPolicyRule {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub actions: Option<AuthorizationServerPolicyRuleActions>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub conditions: Option<AuthorizationServerPolicyRuleConditions>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub priority: i64,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub system: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<AuthorizationServerPolicyRuleType>,
+impl std::str::FromStr for AuthorizationServerPolicyRuleType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "RESOURCE_ACCESS" => AuthorizationServerPolicyRuleType::ResourceAccess,
+            _ => AuthorizationServerPolicyRuleType::Unknown(s.to_string()),
+        })
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthorizationServerPolicyRuleActions {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub token: Option<TokenAuthorizationServerPolicyRuleAction>,
+impl std::convert::From<String> for AuthorizationServerPolicyRuleType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthorizationServerPolicyRuleConditions {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub clients: Option<ClientPolicyCondition>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "grantTypes"
-    )]
-    pub grant_types: Option<ClientPolicyCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub people: Option<PolicyPeopleCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub scopes: Option<OAuth2ScopesMediationPolicyRuleCondition>,
+impl std::convert::From<AuthorizationServerPolicyRuleType> for String {
+    fn from(v: AuthorizationServerPolicyRuleType) -> Self {
+        match v {
+            AuthorizationServerPolicyRuleType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1298,18 +910,6 @@ pub struct BasicAuthApplication {
     pub settings: Option<BasicApplicationSettings>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BeforeScheduledActionPolicyRuleCondition {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub duration: Option<Duration>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "lifecycleAction"
-    )]
-    pub lifecycle_action: Option<ScheduledUserLifecycleAction>,
-}
-
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct BookmarkApplication {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1371,276 +971,35 @@ pub struct CallUserFactorProfile {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CatalogApplication {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+pub struct CustomHotpUserFactor {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "factorProfileId"
     )]
-    pub category: String,
+    pub factor_profile_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<CustomHotpUserFactorProfile>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CustomHotpUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "sharedSecret"
     )]
-    pub description: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
-    )]
-    pub display_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub features: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "signOnModes"
-    )]
-    pub sign_on_modes: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "verificationStatus"
-    )]
-    pub verification_status: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub website: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ChangePasswordRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "newPassword"
-    )]
-    pub new_password: Option<PasswordCredential>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "oldPassword"
-    )]
-    pub old_password: Option<PasswordCredential>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ClientPolicyCondition {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub include: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContextPolicyRuleCondition {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub expression: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CreateSessionRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "sessionToken"
-    )]
-    pub session_token: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CreateUserRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub credentials: Option<UserCredentials>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "groupIds"
-    )]
-    pub group_ids: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<UserProfile>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<UserType>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Csr {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub csr: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kty: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CsrMetadata {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub subject: Option<CsrMetadataSubject>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "subjectAltNames"
-    )]
-    pub subject_alt_names: Option<CsrMetadataSubjectAltNames>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CsrMetadataSubject {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "commonName"
-    )]
-    pub common_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "countryName"
-    )]
-    pub country_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "localityName"
-    )]
-    pub locality_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "organizationName"
-    )]
-    pub organization_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "organizationalUnitName"
-    )]
-    pub organizational_unit_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "stateOrProvinceName"
-    )]
-    pub state_or_province_name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CsrMetadataSubjectAltNames {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "dnsNames"
-    )]
-    pub dns_names: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CustomHotpUserFactor {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "factorProfileId"
-    )]
-    pub factor_profile_id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<CustomHotpUserFactorProfile>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CustomHotpUserFactorProfile {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "sharedSecret"
-    )]
-    pub shared_secret: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DnsRecord {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub expiration: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fqdn: String,
+    pub shared_secret: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DnsRecord {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fqdn: Option<String>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -1656,98 +1015,125 @@ pub struct DnsRecord {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DnsRecordType {
     #[serde(rename = "CNAME")]
     Cname,
     #[serde(rename = "TXT")]
     Txt,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DnsRecordType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DnsRecordType {
+    fn as_ref(&self) -> &str {
+        match self {
             DnsRecordType::Cname => "CNAME",
             DnsRecordType::Txt => "TXT",
-            DnsRecordType::Noop => "",
-            DnsRecordType::FallthroughString => "*",
+            DnsRecordType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DnsRecordType {
-    fn default() -> DnsRecordType {
-        DnsRecordType::Noop
+impl std::fmt::Display for DnsRecordType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DnsRecordType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CNAME" => DnsRecordType::Cname,
+            "TXT" => DnsRecordType::Txt,
+            _ => DnsRecordType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DnsRecordType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DnsRecordType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DnsRecordType::Noop)
+
+impl std::convert::From<DnsRecordType> for String {
+    fn from(v: DnsRecordType) -> Self {
+        match v {
+            DnsRecordType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TrustLevel {
     #[serde(rename = "ANY")]
     Any,
     #[serde(rename = "TRUSTED")]
     Trusted,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for TrustLevel {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for TrustLevel {
+    fn as_ref(&self) -> &str {
+        match self {
             TrustLevel::Any => "ANY",
             TrustLevel::Trusted => "TRUSTED",
-            TrustLevel::Noop => "",
-            TrustLevel::FallthroughString => "*",
+            TrustLevel::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for TrustLevel {
-    fn default() -> TrustLevel {
-        TrustLevel::Noop
+impl std::fmt::Display for TrustLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for TrustLevel {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANY" => TrustLevel::Any,
+            "TRUSTED" => TrustLevel::Trusted,
+            _ => TrustLevel::Unknown(s.to_string()),
+        })
     }
 }
-impl TrustLevel {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, TrustLevel::Noop)
+
+impl std::convert::From<String> for TrustLevel {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DevicePolicyRuleCondition {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub migrated: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub platform: Option<DevicePolicyRuleConditionPlatform>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub rooted: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "trustLevel"
-    )]
-    pub trust_level: Option<TrustLevel>,
+impl std::convert::From<TrustLevel> for String {
+    fn from(v: TrustLevel) -> Self {
+        match v {
+            TrustLevel::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SupportedMdmFrameworks {
     #[serde(rename = "AFW")]
     Afw,
@@ -1755,92 +1141,125 @@ pub enum SupportedMdmFrameworks {
     Native,
     #[serde(rename = "SAFE")]
     Safe,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SupportedMdmFrameworks {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SupportedMdmFrameworks {
+    fn as_ref(&self) -> &str {
+        match self {
             SupportedMdmFrameworks::Afw => "AFW",
             SupportedMdmFrameworks::Native => "NATIVE",
             SupportedMdmFrameworks::Safe => "SAFE",
-            SupportedMdmFrameworks::Noop => "",
-            SupportedMdmFrameworks::FallthroughString => "*",
+            SupportedMdmFrameworks::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SupportedMdmFrameworks {
-    fn default() -> SupportedMdmFrameworks {
-        SupportedMdmFrameworks::Noop
+impl std::fmt::Display for SupportedMdmFrameworks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl SupportedMdmFrameworks {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SupportedMdmFrameworks::Noop)
+
+impl std::str::FromStr for SupportedMdmFrameworks {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AFW" => SupportedMdmFrameworks::Afw,
+            "NATIVE" => SupportedMdmFrameworks::Native,
+            "SAFE" => SupportedMdmFrameworks::Safe,
+            _ => SupportedMdmFrameworks::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Types {
-    #[serde(rename = "ANDROID")]
-    Android,
-    #[serde(rename = "IOS")]
-    Ios,
+impl std::convert::From<String> for SupportedMdmFrameworks {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SupportedMdmFrameworks> for String {
+    fn from(v: SupportedMdmFrameworks) -> Self {
+        match v {
+            SupportedMdmFrameworks::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Types {
+    #[serde(rename = "ANDROID")]
+    Android,
+    #[serde(rename = "IOS")]
+    Ios,
     #[serde(rename = "OSX")]
     Osx,
     #[serde(rename = "WINDOWS")]
     Windows,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Types {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Types {
+    fn as_ref(&self) -> &str {
+        match self {
             Types::Android => "ANDROID",
             Types::Ios => "IOS",
             Types::Osx => "OSX",
             Types::Windows => "WINDOWS",
-            Types::Noop => "",
-            Types::FallthroughString => "*",
+            Types::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Types {
-    fn default() -> Types {
-        Types::Noop
+impl std::fmt::Display for Types {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Types {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Types::Noop)
+
+impl std::str::FromStr for Types {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANDROID" => Types::Android,
+            "IOS" => Types::Ios,
+            "OSX" => Types::Osx,
+            "WINDOWS" => Types::Windows,
+            _ => Types::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DevicePolicyRuleConditionPlatform {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "supportedMDMFrameworks"
-    )]
-    pub supported_mdm_frameworks: Vec<SupportedMdmFrameworks>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub types: Vec<Types>,
+impl std::convert::From<String> for Types {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Types> for String {
+    fn from(v: Types) -> Self {
+        match v {
+            Types::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -1858,18 +1277,10 @@ pub struct Domain {
         rename = "dnsRecords"
     )]
     pub dns_records: Vec<DnsRecord>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub domain: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
@@ -1884,129 +1295,73 @@ pub struct Domain {
     pub validation_status: Option<DomainValidationStatus>,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DomainCertificate {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub certificate: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "certificateChain"
-    )]
-    pub certificate_chain: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "privateKey"
-    )]
-    pub private_key: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<DomainCertificateType>,
-}
-
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct DomainCertificateMetadata {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub expiration: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub fingerprint: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subject: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DomainCertificateSourceType {
     #[serde(rename = "MANUAL")]
     Manual,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DomainCertificateSourceType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DomainCertificateSourceType {
+    fn as_ref(&self) -> &str {
+        match self {
             DomainCertificateSourceType::Manual => "MANUAL",
-            DomainCertificateSourceType::Noop => "",
-            DomainCertificateSourceType::FallthroughString => "*",
+            DomainCertificateSourceType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DomainCertificateSourceType {
-    fn default() -> DomainCertificateSourceType {
-        DomainCertificateSourceType::Noop
-    }
-}
-impl DomainCertificateSourceType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DomainCertificateSourceType::Noop)
+impl std::fmt::Display for DomainCertificateSourceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum DomainCertificateType {
-    #[serde(rename = "PEM")]
-    Pem,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
+impl std::str::FromStr for DomainCertificateSourceType {
+    type Err = std::convert::Infallible;
 
-impl std::fmt::Display for DomainCertificateType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            DomainCertificateType::Pem => "PEM",
-            DomainCertificateType::Noop => "",
-            DomainCertificateType::FallthroughString => "*",
-        }
-        .fmt(f)
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "MANUAL" => DomainCertificateSourceType::Manual,
+            _ => DomainCertificateSourceType::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for DomainCertificateType {
-    fn default() -> DomainCertificateType {
-        DomainCertificateType::Noop
-    }
-}
-impl DomainCertificateType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DomainCertificateType::Noop)
+impl std::convert::From<String> for DomainCertificateSourceType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DomainListResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub domains: Vec<Domain>,
+impl std::convert::From<DomainCertificateSourceType> for String {
+    fn from(v: DomainCertificateSourceType) -> Self {
+        match v {
+            DomainCertificateSourceType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DomainValidationStatus {
     #[serde(rename = "COMPLETED")]
     Completed,
@@ -2016,51 +1371,60 @@ pub enum DomainValidationStatus {
     NotStarted,
     #[serde(rename = "VERIFIED")]
     Verified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DomainValidationStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DomainValidationStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             DomainValidationStatus::Completed => "COMPLETED",
             DomainValidationStatus::InProgress => "IN_PROGRESS",
             DomainValidationStatus::NotStarted => "NOT_STARTED",
             DomainValidationStatus::Verified => "VERIFIED",
-            DomainValidationStatus::Noop => "",
-            DomainValidationStatus::FallthroughString => "*",
+            DomainValidationStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DomainValidationStatus {
-    fn default() -> DomainValidationStatus {
-        DomainValidationStatus::Noop
+impl std::fmt::Display for DomainValidationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl DomainValidationStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DomainValidationStatus::Noop)
+
+impl std::str::FromStr for DomainValidationStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "COMPLETED" => DomainValidationStatus::Completed,
+            "IN_PROGRESS" => DomainValidationStatus::InProgress,
+            "NOT_STARTED" => DomainValidationStatus::NotStarted,
+            "VERIFIED" => DomainValidationStatus::Verified,
+            _ => DomainValidationStatus::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Duration {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub number: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub unit: String,
+impl std::convert::From<String> for DomainValidationStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DomainValidationStatus> for String {
+    fn from(v: DomainValidationStatus) -> Self {
+        match v {
+            DomainValidationStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -2080,307 +1444,306 @@ pub struct EmailUserFactorProfile {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EnabledStatus {
     #[serde(rename = "DISABLED")]
     Disabled,
     #[serde(rename = "ENABLED")]
     Enabled,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EnabledStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EnabledStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             EnabledStatus::Disabled => "DISABLED",
             EnabledStatus::Enabled => "ENABLED",
-            EnabledStatus::Noop => "",
-            EnabledStatus::FallthroughString => "*",
+            EnabledStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for EnabledStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EnabledStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DISABLED" => EnabledStatus::Disabled,
+            "ENABLED" => EnabledStatus::Enabled,
+            _ => EnabledStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for EnabledStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<EnabledStatus> for String {
+    fn from(v: EnabledStatus) -> Self {
+        match v {
+            EnabledStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum EventHookStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for EventHookStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            EventHookStatus::Active => "ACTIVE",
+            EventHookStatus::Inactive => "INACTIVE",
+            EventHookStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EnabledStatus {
-    fn default() -> EnabledStatus {
-        EnabledStatus::Noop
+impl std::fmt::Display for EventHookStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EventHookStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => EventHookStatus::Active,
+            "INACTIVE" => EventHookStatus::Inactive,
+            _ => EventHookStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for EventHookStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl EnabledStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EnabledStatus::Noop)
+
+impl std::convert::From<EventHookStatus> for String {
+    fn from(v: EventHookStatus) -> Self {
+        match v {
+            EventHookStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum VerificationStatus {
     #[serde(rename = "UNVERIFIED")]
     Unverified,
     #[serde(rename = "VERIFIED")]
     Verified,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for VerificationStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for VerificationStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             VerificationStatus::Unverified => "UNVERIFIED",
             VerificationStatus::Verified => "VERIFIED",
-            VerificationStatus::Noop => "",
-            VerificationStatus::FallthroughString => "*",
+            VerificationStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for VerificationStatus {
-    fn default() -> VerificationStatus {
-        VerificationStatus::Noop
+impl std::fmt::Display for VerificationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl VerificationStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, VerificationStatus::Noop)
+
+impl std::str::FromStr for VerificationStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "UNVERIFIED" => VerificationStatus::Unverified,
+            "VERIFIED" => VerificationStatus::Verified,
+            _ => VerificationStatus::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EventHook {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub channel: Option<EventHookChannel>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "createdBy"
-    )]
-    pub created_by: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub events: Option<EventSubscriptions>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "verificationStatus"
-    )]
-    pub verification_status: Option<VerificationStatus>,
+impl std::convert::From<String> for VerificationStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<VerificationStatus> for String {
+    fn from(v: VerificationStatus) -> Self {
+        match v {
+            VerificationStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EventHookChannelType {
     #[serde(rename = "HTTP")]
     Http,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EventHookChannelType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EventHookChannelType {
+    fn as_ref(&self) -> &str {
+        match self {
             EventHookChannelType::Http => "HTTP",
-            EventHookChannelType::Noop => "",
-            EventHookChannelType::FallthroughString => "*",
+            EventHookChannelType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EventHookChannelType {
-    fn default() -> EventHookChannelType {
-        EventHookChannelType::Noop
+impl std::fmt::Display for EventHookChannelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl EventHookChannelType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EventHookChannelType::Noop)
+
+impl std::str::FromStr for EventHookChannelType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "HTTP" => EventHookChannelType::Http,
+            _ => EventHookChannelType::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EventHookChannel {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub config: Option<EventHookChannelConfig>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<EventHookChannelType>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
+impl std::convert::From<String> for EventHookChannelType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EventHookChannelConfig {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authScheme"
-    )]
-    pub auth_scheme: Option<EventHookChannelConfigAuthScheme>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub headers: Vec<EventHookChannelConfigHeader>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub uri: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EventHookChannelConfigAuthScheme {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub key: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<EventHookChannelConfigAuthSchemeType>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum EventHookChannelConfigAuthSchemeType {
-    #[serde(rename = "HEADER")]
-    Header,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for EventHookChannelConfigAuthSchemeType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            EventHookChannelConfigAuthSchemeType::Header => "HEADER",
-            EventHookChannelConfigAuthSchemeType::Noop => "",
-            EventHookChannelConfigAuthSchemeType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for EventHookChannelConfigAuthSchemeType {
-    fn default() -> EventHookChannelConfigAuthSchemeType {
-        EventHookChannelConfigAuthSchemeType::Noop
-    }
-}
-impl EventHookChannelConfigAuthSchemeType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EventHookChannelConfigAuthSchemeType::Noop)
-    }
+impl std::convert::From<EventHookChannelType> for String {
+    fn from(v: EventHookChannelType) -> Self {
+        match v {
+            EventHookChannelType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct EventHookChannelConfigHeader {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub key: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EventSubscriptionsType {
     #[serde(rename = "EVENT_TYPE")]
     EventType,
     #[serde(rename = "FLOW_EVENT")]
     FlowEvent,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EventSubscriptionsType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EventSubscriptionsType {
+    fn as_ref(&self) -> &str {
+        match self {
             EventSubscriptionsType::EventType => "EVENT_TYPE",
             EventSubscriptionsType::FlowEvent => "FLOW_EVENT",
-            EventSubscriptionsType::Noop => "",
-            EventSubscriptionsType::FallthroughString => "*",
+            EventSubscriptionsType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EventSubscriptionsType {
-    fn default() -> EventSubscriptionsType {
-        EventSubscriptionsType::Noop
+impl std::fmt::Display for EventSubscriptionsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl EventSubscriptionsType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EventSubscriptionsType::Noop)
+
+impl std::str::FromStr for EventSubscriptionsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EVENT_TYPE" => EventSubscriptionsType::EventType,
+            "FLOW_EVENT" => EventSubscriptionsType::FlowEvent,
+            _ => EventSubscriptionsType::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EventSubscriptions {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub items: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<EventSubscriptionsType>,
+impl std::convert::From<String> for EventSubscriptionsType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<EventSubscriptionsType> for String {
+    fn from(v: EventSubscriptionsType) -> Self {
+        match v {
+            EventSubscriptionsType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FactorProvider {
     #[serde(rename = "CUSTOM")]
     Custom,
@@ -2398,15 +1761,15 @@ pub enum FactorProvider {
     Symantec,
     #[serde(rename = "YUBICO")]
     Yubico,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for FactorProvider {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for FactorProvider {
+    fn as_ref(&self) -> &str {
+        match self {
             FactorProvider::Custom => "CUSTOM",
             FactorProvider::Duo => "DUO",
             FactorProvider::Fido => "FIDO",
@@ -2415,25 +1778,55 @@ impl std::fmt::Display for FactorProvider {
             FactorProvider::Rsa => "RSA",
             FactorProvider::Symantec => "SYMANTEC",
             FactorProvider::Yubico => "YUBICO",
-            FactorProvider::Noop => "",
-            FactorProvider::FallthroughString => "*",
+            FactorProvider::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for FactorProvider {
-    fn default() -> FactorProvider {
-        FactorProvider::Noop
+impl std::fmt::Display for FactorProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for FactorProvider {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CUSTOM" => FactorProvider::Custom,
+            "DUO" => FactorProvider::Duo,
+            "FIDO" => FactorProvider::Fido,
+            "GOOGLE" => FactorProvider::Google,
+            "OKTA" => FactorProvider::Okta,
+            "RSA" => FactorProvider::Rsa,
+            "SYMANTEC" => FactorProvider::Symantec,
+            "YUBICO" => FactorProvider::Yubico,
+            _ => FactorProvider::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for FactorProvider {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl FactorProvider {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FactorProvider::Noop)
+
+impl std::convert::From<FactorProvider> for String {
+    fn from(v: FactorProvider) -> Self {
+        match v {
+            FactorProvider::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FactorResultType {
     #[serde(rename = "CANCELLED")]
     Cancelled,
@@ -2455,15 +1848,15 @@ pub enum FactorResultType {
     TimeWindowExceeded,
     #[serde(rename = "WAITING")]
     Waiting,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for FactorResultType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for FactorResultType {
+    fn as_ref(&self) -> &str {
+        match self {
             FactorResultType::Cancelled => "CANCELLED",
             FactorResultType::Challenge => "CHALLENGE",
             FactorResultType::Error => "ERROR",
@@ -2474,25 +1867,57 @@ impl std::fmt::Display for FactorResultType {
             FactorResultType::Timeout => "TIMEOUT",
             FactorResultType::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
             FactorResultType::Waiting => "WAITING",
-            FactorResultType::Noop => "",
-            FactorResultType::FallthroughString => "*",
+            FactorResultType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for FactorResultType {
-    fn default() -> FactorResultType {
-        FactorResultType::Noop
+impl std::fmt::Display for FactorResultType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for FactorResultType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CANCELLED" => FactorResultType::Cancelled,
+            "CHALLENGE" => FactorResultType::Challenge,
+            "ERROR" => FactorResultType::Error,
+            "FAILED" => FactorResultType::Failed,
+            "PASSCODE_REPLAYED" => FactorResultType::PasscodeReplayed,
+            "REJECTED" => FactorResultType::Rejected,
+            "SUCCESS" => FactorResultType::Success,
+            "TIMEOUT" => FactorResultType::Timeout,
+            "TIME_WINDOW_EXCEEDED" => FactorResultType::TimeWindowExceeded,
+            "WAITING" => FactorResultType::Waiting,
+            _ => FactorResultType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for FactorResultType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl FactorResultType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FactorResultType::Noop)
+
+impl std::convert::From<FactorResultType> for String {
+    fn from(v: FactorResultType) -> Self {
+        match v {
+            FactorResultType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FactorStatus {
     #[serde(rename = "ACTIVE")]
     Active,
@@ -2508,15 +1933,15 @@ pub enum FactorStatus {
     NotSetup,
     #[serde(rename = "PENDING_ACTIVATION")]
     PendingActivation,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for FactorStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for FactorStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             FactorStatus::Active => "ACTIVE",
             FactorStatus::Disabled => "DISABLED",
             FactorStatus::Enrolled => "ENROLLED",
@@ -2524,25 +1949,54 @@ impl std::fmt::Display for FactorStatus {
             FactorStatus::Inactive => "INACTIVE",
             FactorStatus::NotSetup => "NOT_SETUP",
             FactorStatus::PendingActivation => "PENDING_ACTIVATION",
-            FactorStatus::Noop => "",
-            FactorStatus::FallthroughString => "*",
+            FactorStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for FactorStatus {
-    fn default() -> FactorStatus {
-        FactorStatus::Noop
+impl std::fmt::Display for FactorStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for FactorStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => FactorStatus::Active,
+            "DISABLED" => FactorStatus::Disabled,
+            "ENROLLED" => FactorStatus::Enrolled,
+            "EXPIRED" => FactorStatus::Expired,
+            "INACTIVE" => FactorStatus::Inactive,
+            "NOT_SETUP" => FactorStatus::NotSetup,
+            "PENDING_ACTIVATION" => FactorStatus::PendingActivation,
+            _ => FactorStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for FactorStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl FactorStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FactorStatus::Noop)
+
+impl std::convert::From<FactorStatus> for String {
+    fn from(v: FactorStatus) -> Self {
+        match v {
+            FactorStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FactorType {
     #[serde(rename = "call")]
     Call,
@@ -2570,15 +2024,15 @@ pub enum FactorType {
     Web,
     #[serde(rename = "webauthn")]
     Webauthn,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for FactorType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for FactorType {
+    fn as_ref(&self) -> &str {
+        match self {
             FactorType::Call => "call",
             FactorType::Email => "email",
             FactorType::Hotp => "hotp",
@@ -2592,3768 +2046,1750 @@ impl std::fmt::Display for FactorType {
             FactorType::U2F => "u2f",
             FactorType::Web => "web",
             FactorType::Webauthn => "webauthn",
-            FactorType::Noop => "",
-            FactorType::FallthroughString => "*",
+            FactorType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for FactorType {
-    fn default() -> FactorType {
-        FactorType::Noop
+impl std::fmt::Display for FactorType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for FactorType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "call" => FactorType::Call,
+            "email" => FactorType::Email,
+            "hotp" => FactorType::Hotp,
+            "push" => FactorType::Push,
+            "question" => FactorType::Question,
+            "sms" => FactorType::Sms,
+            "token" => FactorType::Token,
+            "token:hardware" => FactorType::TokenHardware,
+            "token:hotp" => FactorType::TokenHotp,
+            "token:software:totp" => FactorType::TokenSoftwareTotp,
+            "u2f" => FactorType::U2F,
+            "web" => FactorType::Web,
+            "webauthn" => FactorType::Webauthn,
+            _ => FactorType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for FactorType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl FactorType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FactorType::Noop)
+
+impl std::convert::From<FactorType> for String {
+    fn from(v: FactorType) -> Self {
+        match v {
+            FactorType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Feature {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+pub struct GroupCondition {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub description: String,
+    pub exclude: Vec<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub id: String,
+    pub include: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct HardwareUserFactor {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<HardwareUserFactorProfile>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct HardwareUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "credentialId"
     )]
-    pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub stage: Option<FeatureStage>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<EnabledStatus>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<FeatureType>,
+    pub credential_id: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FeatureStage {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub state: Option<FeatureStageState>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub value: Option<FeatureStageValue>,
+#[serde(from = "String", into = "String")]
+pub enum IdentityProviderIssuerMode {
+    #[serde(rename = "CUSTOM_URL_DOMAIN")]
+    CustomUrlDomain,
+    #[serde(rename = "ORG_URL")]
+    OrgUrl,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum FeatureStageState {
-    #[serde(rename = "CLOSED")]
-    Closed,
-    #[serde(rename = "OPEN")]
-    Open,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+impl AsRef<str> for IdentityProviderIssuerMode {
+    fn as_ref(&self) -> &str {
+        match self {
+            IdentityProviderIssuerMode::CustomUrlDomain => "CUSTOM_URL_DOMAIN",
+            IdentityProviderIssuerMode::OrgUrl => "ORG_URL",
+            IdentityProviderIssuerMode::Unknown(s) => s,
+        }
+    }
 }
 
-impl std::fmt::Display for FeatureStageState {
+impl std::fmt::Display for IdentityProviderIssuerMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            FeatureStageState::Closed => "CLOSED",
-            FeatureStageState::Open => "OPEN",
-            FeatureStageState::Noop => "",
-            FeatureStageState::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for IdentityProviderIssuerMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CUSTOM_URL_DOMAIN" => IdentityProviderIssuerMode::CustomUrlDomain,
+            "ORG_URL" => IdentityProviderIssuerMode::OrgUrl,
+            _ => IdentityProviderIssuerMode::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for FeatureStageState {
-    fn default() -> FeatureStageState {
-        FeatureStageState::Noop
+impl std::convert::From<String> for IdentityProviderIssuerMode {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl FeatureStageState {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FeatureStageState::Noop)
+
+impl std::convert::From<IdentityProviderIssuerMode> for String {
+    fn from(v: IdentityProviderIssuerMode) -> Self {
+        match v {
+            IdentityProviderIssuerMode::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum FeatureStageValue {
-    #[serde(rename = "BETA")]
-    Beta,
-    #[serde(rename = "EA")]
-    Ea,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum IdentityProviderStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for IdentityProviderStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            IdentityProviderStatus::Active => "ACTIVE",
+            IdentityProviderStatus::Inactive => "INACTIVE",
+            IdentityProviderStatus::Unknown(s) => s,
+        }
+    }
 }
 
-impl std::fmt::Display for FeatureStageValue {
+impl std::fmt::Display for IdentityProviderStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            FeatureStageValue::Beta => "BETA",
-            FeatureStageValue::Ea => "EA",
-            FeatureStageValue::Noop => "",
-            FeatureStageValue::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for IdentityProviderStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => IdentityProviderStatus::Active,
+            "INACTIVE" => IdentityProviderStatus::Inactive,
+            _ => IdentityProviderStatus::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for FeatureStageValue {
-    fn default() -> FeatureStageValue {
-        FeatureStageValue::Noop
+impl std::convert::From<String> for IdentityProviderStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl FeatureStageValue {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FeatureStageValue::Noop)
+
+impl std::convert::From<IdentityProviderStatus> for String {
+    fn from(v: IdentityProviderStatus) -> Self {
+        match v {
+            IdentityProviderStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum FeatureType {
-    #[serde(rename = "self-service")]
-    SelfService,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum IdentityProviderType {
+    #[serde(rename = "AgentlessDSSO")]
+    AgentlessDsso,
+    #[serde(rename = "FACEBOOK")]
+    Facebook,
+    #[serde(rename = "GOOGLE")]
+    Google,
+    #[serde(rename = "IWA")]
+    Iwa,
+    #[serde(rename = "LINKEDIN")]
+    Linkedin,
+    #[serde(rename = "MICROSOFT")]
+    Microsoft,
+    #[serde(rename = "OIDC")]
+    Oidc,
+    #[serde(rename = "OKTA")]
+    Okta,
+    #[serde(rename = "SAML2")]
+    Saml2,
+    #[serde(rename = "X509")]
+    X509,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for FeatureType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            FeatureType::SelfService => "self-service",
-            FeatureType::Noop => "",
-            FeatureType::FallthroughString => "*",
+impl AsRef<str> for IdentityProviderType {
+    fn as_ref(&self) -> &str {
+        match self {
+            IdentityProviderType::AgentlessDsso => "AgentlessDSSO",
+            IdentityProviderType::Facebook => "FACEBOOK",
+            IdentityProviderType::Google => "GOOGLE",
+            IdentityProviderType::Iwa => "IWA",
+            IdentityProviderType::Linkedin => "LINKEDIN",
+            IdentityProviderType::Microsoft => "MICROSOFT",
+            IdentityProviderType::Oidc => "OIDC",
+            IdentityProviderType::Okta => "OKTA",
+            IdentityProviderType::Saml2 => "SAML2",
+            IdentityProviderType::X509 => "X509",
+            IdentityProviderType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for FeatureType {
-    fn default() -> FeatureType {
-        FeatureType::Noop
+impl std::fmt::Display for IdentityProviderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl FeatureType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FeatureType::Noop)
+
+impl std::str::FromStr for IdentityProviderType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AgentlessDSSO" => IdentityProviderType::AgentlessDsso,
+            "FACEBOOK" => IdentityProviderType::Facebook,
+            "GOOGLE" => IdentityProviderType::Google,
+            "IWA" => IdentityProviderType::Iwa,
+            "LINKEDIN" => IdentityProviderType::Linkedin,
+            "MICROSOFT" => IdentityProviderType::Microsoft,
+            "OIDC" => IdentityProviderType::Oidc,
+            "OKTA" => IdentityProviderType::Okta,
+            "SAML2" => IdentityProviderType::Saml2,
+            "X509" => IdentityProviderType::X509,
+            _ => IdentityProviderType::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ResetPasswordToken {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "resetPasswordUrl"
-    )]
-    pub reset_password_url: String,
+impl std::convert::From<String> for IdentityProviderType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Group {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastMembershipUpdated"
-    )]
-    pub last_membership_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "objectClass"
-    )]
-    pub object_class: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<GroupProfile>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<GroupType>,
+impl std::convert::From<IdentityProviderType> for String {
+    fn from(v: IdentityProviderType) -> Self {
+        match v {
+            IdentityProviderType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GroupProfile {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GroupRule {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub actions: Option<GroupRuleAction>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub conditions: Option<GroupRuleConditions>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<GroupRuleStatus>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GroupRuleAction {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "assignUserToGroups"
-    )]
-    pub assign_user_to_groups: Option<GroupRuleAssignment>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GroupRuleConditions {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub expression: Option<GroupRuleExpression>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub people: Option<GroupRulePeopleCondition>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GroupRuleExpression {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
+#[serde(from = "String", into = "String")]
+pub enum Revocation {
+    #[serde(rename = "CRL")]
+    Crl,
+    #[serde(rename = "DELTA_CRL")]
+    DeltaCrl,
+    #[serde(rename = "OCSP")]
+    Ocsp,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GroupRuleAssignment {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "groupIds"
-    )]
-    pub group_ids: Vec<String>,
+impl AsRef<str> for Revocation {
+    fn as_ref(&self) -> &str {
+        match self {
+            Revocation::Crl => "CRL",
+            Revocation::DeltaCrl => "DELTA_CRL",
+            Revocation::Ocsp => "OCSP",
+            Revocation::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GroupRuleCondition {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub exclude: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub include: Vec<String>,
+impl std::fmt::Display for Revocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GroupRulePeopleCondition {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub groups: Option<GroupRuleCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub users: Option<GroupRuleCondition>,
-}
+impl std::str::FromStr for Revocation {
+    type Err = std::convert::Infallible;
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum GroupRuleStatus {
-    #[serde(rename = "ACTIVE")]
-    Active,
-    #[serde(rename = "INACTIVE")]
-    Inactive,
-    #[serde(rename = "INVALID")]
-    Invalid,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CRL" => Revocation::Crl,
+            "DELTA_CRL" => Revocation::DeltaCrl,
+            "OCSP" => Revocation::Ocsp,
+            _ => Revocation::Unknown(s.to_string()),
+        })
+    }
 }
 
-impl std::fmt::Display for GroupRuleStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            GroupRuleStatus::Active => "ACTIVE",
-            GroupRuleStatus::Inactive => "INACTIVE",
-            GroupRuleStatus::Invalid => "INVALID",
-            GroupRuleStatus::Noop => "",
-            GroupRuleStatus::FallthroughString => "*",
+impl std::convert::From<String> for Revocation {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
         }
-        .fmt(f)
     }
 }
 
-impl Default for GroupRuleStatus {
-    fn default() -> GroupRuleStatus {
-        GroupRuleStatus::Noop
-    }
-}
-impl GroupRuleStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GroupRuleStatus::Noop)
+impl std::convert::From<Revocation> for String {
+    fn from(v: Revocation) -> Self {
+        match v {
+            Revocation::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum GroupType {
-    #[serde(rename = "APP_GROUP")]
-    AppGroup,
-    #[serde(rename = "BUILT_IN")]
-    BuiltIn,
-    #[serde(rename = "OKTA_GROUP")]
-    OktaGroup,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum Provider {
+    #[serde(rename = "ANY")]
+    Any,
+    #[serde(rename = "OKTA")]
+    Okta,
+    #[serde(rename = "SPECIFIC_IDP")]
+    SpecificIdp,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GroupType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            GroupType::AppGroup => "APP_GROUP",
-            GroupType::BuiltIn => "BUILT_IN",
-            GroupType::OktaGroup => "OKTA_GROUP",
-            GroupType::Noop => "",
-            GroupType::FallthroughString => "*",
+impl AsRef<str> for Provider {
+    fn as_ref(&self) -> &str {
+        match self {
+            Provider::Any => "ANY",
+            Provider::Okta => "OKTA",
+            Provider::SpecificIdp => "SPECIFIC_IDP",
+            Provider::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GroupType {
-    fn default() -> GroupType {
-        GroupType::Noop
+impl std::fmt::Display for Provider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl GroupType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GroupType::Noop)
+
+impl std::str::FromStr for Provider {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANY" => Provider::Any,
+            "OKTA" => Provider::Okta,
+            "SPECIFIC_IDP" => Provider::SpecificIdp,
+            _ => Provider::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct HardwareUserFactor {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<WebUserFactorProfile>,
+impl std::convert::From<String> for Provider {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct WebUserFactorProfile {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "credentialId"
-    )]
-    pub credential_id: String,
+impl std::convert::From<Provider> for String {
+    fn from(v: Provider) -> Self {
+        match v {
+            Provider::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum IdentityProviderIssuerMode {
-    #[serde(rename = "CUSTOM_URL_DOMAIN")]
-    CustomUrlDomain,
-    #[serde(rename = "ORG_URL")]
-    OrgUrl,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum InlineHookChannelType {
+    #[serde(rename = "HTTP")]
+    Http,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for IdentityProviderIssuerMode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            IdentityProviderIssuerMode::CustomUrlDomain => "CUSTOM_URL_DOMAIN",
-            IdentityProviderIssuerMode::OrgUrl => "ORG_URL",
-            IdentityProviderIssuerMode::Noop => "",
-            IdentityProviderIssuerMode::FallthroughString => "*",
+impl AsRef<str> for InlineHookChannelType {
+    fn as_ref(&self) -> &str {
+        match self {
+            InlineHookChannelType::Http => "HTTP",
+            InlineHookChannelType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for IdentityProviderIssuerMode {
-    fn default() -> IdentityProviderIssuerMode {
-        IdentityProviderIssuerMode::Noop
-    }
-}
-impl IdentityProviderIssuerMode {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, IdentityProviderIssuerMode::Noop)
+impl std::fmt::Display for InlineHookChannelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum IdentityProviderType {
-    #[serde(rename = "AgentlessDSSO")]
-    AgentlessDsso,
-    #[serde(rename = "FACEBOOK")]
-    Facebook,
-    #[serde(rename = "GOOGLE")]
-    Google,
-    #[serde(rename = "IWA")]
-    Iwa,
-    #[serde(rename = "LINKEDIN")]
-    Linkedin,
-    #[serde(rename = "MICROSOFT")]
-    Microsoft,
-    #[serde(rename = "OIDC")]
-    Oidc,
-    #[serde(rename = "OKTA")]
-    Okta,
-    #[serde(rename = "SAML2")]
-    Saml2,
-    #[serde(rename = "X509")]
-    X509,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+impl std::str::FromStr for InlineHookChannelType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "HTTP" => InlineHookChannelType::Http,
+            _ => InlineHookChannelType::Unknown(s.to_string()),
+        })
+    }
 }
 
-impl std::fmt::Display for IdentityProviderType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            IdentityProviderType::AgentlessDsso => "AgentlessDSSO",
-            IdentityProviderType::Facebook => "FACEBOOK",
-            IdentityProviderType::Google => "GOOGLE",
-            IdentityProviderType::Iwa => "IWA",
-            IdentityProviderType::Linkedin => "LINKEDIN",
-            IdentityProviderType::Microsoft => "MICROSOFT",
-            IdentityProviderType::Oidc => "OIDC",
-            IdentityProviderType::Okta => "OKTA",
-            IdentityProviderType::Saml2 => "SAML2",
-            IdentityProviderType::X509 => "X509",
-            IdentityProviderType::Noop => "",
-            IdentityProviderType::FallthroughString => "*",
+impl std::convert::From<String> for InlineHookChannelType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
         }
-        .fmt(f)
     }
 }
 
-impl Default for IdentityProviderType {
-    fn default() -> IdentityProviderType {
-        IdentityProviderType::Noop
+impl std::convert::From<InlineHookChannelType> for String {
+    fn from(v: InlineHookChannelType) -> Self {
+        match v {
+            InlineHookChannelType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
-impl IdentityProviderType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, IdentityProviderType::Noop)
-    }
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InlineHookChannelConfigHeaders {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IdentityProvider {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+pub struct InlineHookResponseCommandValue {
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    pub op: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub path: String,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "issuerMode"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub issuer_mode: Option<IdentityProviderIssuerMode>,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InlineHookResponseCommands {
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
     )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    pub type_: String,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub policy: Option<IdentityProviderPolicy>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<Protocol>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<IdentityProviderType>,
+    pub value: Vec<InlineHookResponseCommandValue>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IdentityProviderApplicationUser {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+pub struct Value {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IonField {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub form: Option<IonForm>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub created: String,
+    pub label: String,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "externalId"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub external_id: String,
+    pub mutable: bool,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub name: String,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "lastUpdated"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub last_updated: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<Links>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IdentityProviderCredentials {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub client: Option<IdentityProviderCredentialsClient>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub signing: Option<IdentityProviderCredentialsSigning>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub trust: Option<IdentityProviderCredentialsTrust>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IdentityProviderCredentialsClient {
+    pub required: bool,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub client_id: String,
+    pub secret: bool,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
     )]
-    pub client_secret: String,
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub value: std::collections::BTreeMap<String, Value>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub visible: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IdentityProviderCredentialsSigning {
+pub struct IonForm {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub kid: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Revocation {
-    #[serde(rename = "CRL")]
-    Crl,
-    #[serde(rename = "DELTA_CRL")]
-    DeltaCrl,
-    #[serde(rename = "OCSP")]
-    Ocsp,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Revocation {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Revocation::Crl => "CRL",
-            Revocation::DeltaCrl => "DELTA_CRL",
-            Revocation::Ocsp => "OCSP",
-            Revocation::Noop => "",
-            Revocation::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Revocation {
-    fn default() -> Revocation {
-        Revocation::Noop
-    }
-}
-impl Revocation {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Revocation::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IdentityProviderCredentialsTrust {
+    pub accepts: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub audience: String,
+    pub href: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub issuer: String,
+    pub method: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub kid: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub revocation: Option<Revocation>,
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub produces: String,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "revocationCacheLifetime"
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub refresh: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub rel: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "relatesTo"
+    )]
+    pub relates_to: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub revocation_cache_lifetime: i64,
+    pub value: Vec<IonField>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IdentityProviderPolicy {
+pub struct JsonWebKey {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        rename = "accountLink"
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub account_link: Option<PolicyAccountLink>,
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxClockSkew"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "expiresAt"
+    )]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub key_ops: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kty: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
     )]
-    pub max_clock_skew: i64,
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub provisioning: Option<Provisioning>,
+    pub n: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub subject: Option<PolicySubject>,
+    pub status: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "use")]
+    pub use_: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "x5c"
+    )]
+    pub x_5c: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "x5t")]
+    pub x_5t: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "x5t#S256")]
+    pub x_5t_s256: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "x5u")]
+    pub x_5u: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Provider {
-    #[serde(rename = "ANY")]
-    Any,
-    #[serde(rename = "OKTA")]
-    Okta,
-    #[serde(rename = "SPECIFIC_IDP")]
-    SpecificIdp,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum Use {
+    #[serde(rename = "sig")]
+    Sig,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Provider {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Provider::Any => "ANY",
-            Provider::Okta => "OKTA",
-            Provider::SpecificIdp => "SPECIFIC_IDP",
-            Provider::Noop => "",
-            Provider::FallthroughString => "*",
+impl AsRef<str> for Use {
+    fn as_ref(&self) -> &str {
+        match self {
+            Use::Sig => "sig",
+            Use::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Provider {
-    fn default() -> Provider {
-        Provider::Noop
+impl std::fmt::Display for Use {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Provider {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Provider::Noop)
+
+impl std::str::FromStr for Use {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "sig" => Use::Sig,
+            _ => Use::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IdentityProviderPolicyRuleCondition {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "idpIds"
-    )]
-    pub idp_ids: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub provider: Option<Provider>,
+impl std::convert::From<String> for Use {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Use> for String {
+    fn from(v: Use) -> Self {
+        match v {
+            Use::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct InlineHook {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub channel: Option<InlineHookChannel>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
+pub struct Detail {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DebugData {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LogGeographicalContext {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub city: String,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    pub country: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub geolocation: Option<LogGeolocation>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "postalCode"
     )]
-    pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<InlineHookType>,
+    pub postal_code: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub version: String,
+    pub state: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct InlineHookChannel {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub config: Option<InlineHookChannelConfig>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<EventHookChannelType>,
+pub struct LogGeolocation {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub version: String,
+    pub lat: f64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub lon: f64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct InlineHookChannelConfig {
+pub struct LogIpAddress {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        rename = "authScheme"
+        rename = "geographicalContext"
     )]
-    pub auth_scheme: Option<InlineHookChannelConfigAuthScheme>,
+    pub geographical_context: Option<LogGeographicalContext>,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub headers: Vec<InlineHookChannelConfigHeaders>,
+    pub ip: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub method: String,
+    pub source: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub uri: String,
+    pub version: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct InlineHookChannelConfigAuthScheme {
+pub struct DetailEntry {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LogTarget {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "alternateId"
     )]
-    pub key: String,
+    pub alternate_id: String,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "detailEntry"
     )]
-    pub type_: String,
+    pub detail_entry: std::collections::BTreeMap<String, DetailEntry>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "displayName"
     )]
-    pub value: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct InlineHookChannelConfigHeaders {
+    pub display_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub key: String,
+    pub id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
     )]
-    pub value: String,
+    pub type_: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct InlineHookResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub commands: Vec<InlineHookResponseCommands>,
-}
+pub struct DetailAdditionalProperties {}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct InlineHookResponseCommandValue {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub op: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub path: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct InlineHookResponseCommands {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub value: Vec<InlineHookResponseCommandValue>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum InlineHookType {
-    #[serde(rename = "com.okta.import.transform")]
-    ComOktaImportTransform,
-    #[serde(rename = "com.okta.oauth2.tokens.transform")]
-    ComOktaOauth2TokensTransform,
-    #[serde(rename = "com.okta.saml.tokens.transform")]
-    ComOktaSamlTokensTransform,
-    #[serde(rename = "com.okta.user.credential.password.import")]
-    ComOktaUserCredentialPasswordImport,
-    #[serde(rename = "com.okta.user.pre-registration")]
-    ComOktaUserPreRegistration,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for InlineHookType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            InlineHookType::ComOktaImportTransform => "com.okta.import.transform",
-            InlineHookType::ComOktaOauth2TokensTransform => "com.okta.oauth2.tokens.transform",
-            InlineHookType::ComOktaSamlTokensTransform => "com.okta.saml.tokens.transform",
-            InlineHookType::ComOktaUserCredentialPasswordImport => {
-                "com.okta.user.credential.password.import"
-            }
-            InlineHookType::ComOktaUserPreRegistration => "com.okta.user.pre-registration",
-            InlineHookType::Noop => "",
-            InlineHookType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for InlineHookType {
-    fn default() -> InlineHookType {
-        InlineHookType::Noop
-    }
-}
-impl InlineHookType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, InlineHookType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IonField {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub form: Option<IonForm>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub label: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub mutable: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub required: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub secret: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub value: Option<Links>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub visible: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IonForm {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub accepts: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub href: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub method: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub produces: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub refresh: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub rel: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "relatesTo"
-    )]
-    pub relates_to: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub value: Vec<IonField>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct JsonWebKey {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub alg: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub e: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "expiresAt"
-    )]
-    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub key_ops: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kid: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub kty: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub n: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub status: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "use"
-    )]
-    pub use_: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "x5c"
-    )]
-    pub x_5c: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "x5t"
-    )]
-    pub x_5t: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "x5t#S256"
-    )]
-    pub x_5t_s256: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "x5u"
-    )]
-    pub x_5u: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct JwkUse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "use")]
-    pub use_: Option<Use>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LifecycleExpirationPolicyRuleCondition {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "lifecycleStatus"
-    )]
-    pub lifecycle_status: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub number: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub unit: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LinkedObject {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub associated: Option<LinkedObjectDetails>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub primary: Option<LinkedObjectDetails>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LinkedObjectDetails {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<LinkedObjectDetailsType>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum LinkedObjectDetailsType {
-    #[serde(rename = "USER")]
-    User,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for LinkedObjectDetailsType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            LinkedObjectDetailsType::User => "USER",
-            LinkedObjectDetailsType::Noop => "",
-            LinkedObjectDetailsType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for LinkedObjectDetailsType {
-    fn default() -> LinkedObjectDetailsType {
-        LinkedObjectDetailsType::Noop
-    }
-}
-impl LinkedObjectDetailsType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LinkedObjectDetailsType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogActor {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "alternateId"
-    )]
-    pub alternate_id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub detail: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
-    )]
-    pub display_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogAuthenticationContext {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authenticationProvider"
-    )]
-    pub authentication_provider: Option<LogAuthenticationProvider>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "authenticationStep"
-    )]
-    pub authentication_step: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "credentialProvider"
-    )]
-    pub credential_provider: Option<LogCredentialProvider>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "credentialType"
-    )]
-    pub credential_type: Option<LogCredentialType>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "externalSessionId"
-    )]
-    pub external_session_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub interface: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub issuer: Option<LogIssuer>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum LogAuthenticationProvider {
-    #[serde(rename = "ACTIVE_DIRECTORY")]
-    ActiveDirectory,
-    #[serde(rename = "FACTOR_PROVIDER")]
-    FactorProvider,
-    #[serde(rename = "FEDERATION")]
-    Federation,
-    #[serde(rename = "LDAP")]
-    Ldap,
-    #[serde(rename = "OKTA_AUTHENTICATION_PROVIDER")]
-    OktaAuthenticationProvider,
-    #[serde(rename = "SOCIAL")]
-    Social,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for LogAuthenticationProvider {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            LogAuthenticationProvider::ActiveDirectory => "ACTIVE_DIRECTORY",
-            LogAuthenticationProvider::FactorProvider => "FACTOR_PROVIDER",
-            LogAuthenticationProvider::Federation => "FEDERATION",
-            LogAuthenticationProvider::Ldap => "LDAP",
-            LogAuthenticationProvider::OktaAuthenticationProvider => "OKTA_AUTHENTICATION_PROVIDER",
-            LogAuthenticationProvider::Social => "SOCIAL",
-            LogAuthenticationProvider::Noop => "",
-            LogAuthenticationProvider::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for LogAuthenticationProvider {
-    fn default() -> LogAuthenticationProvider {
-        LogAuthenticationProvider::Noop
-    }
-}
-impl LogAuthenticationProvider {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LogAuthenticationProvider::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogClient {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub device: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "geographicalContext"
-    )]
-    pub geographical_context: Option<LogGeographicalContext>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "ipAddress"
-    )]
-    pub ip_address: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userAgent")]
-    pub user_agent: Option<LogUserAgent>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zone: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum LogCredentialProvider {
-    #[serde(rename = "DUO")]
-    Duo,
-    #[serde(rename = "GOOGLE")]
-    Google,
-    #[serde(rename = "OKTA_AUTHENTICATION_PROVIDER")]
-    OktaAuthenticationProvider,
-    #[serde(rename = "OKTA_CREDENTIAL_PROVIDER")]
-    OktaCredentialProvider,
-    #[serde(rename = "RSA")]
-    Rsa,
-    #[serde(rename = "SYMANTEC")]
-    Symantec,
-    #[serde(rename = "YUBIKEY")]
-    Yubikey,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for LogCredentialProvider {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            LogCredentialProvider::Duo => "DUO",
-            LogCredentialProvider::Google => "GOOGLE",
-            LogCredentialProvider::OktaAuthenticationProvider => "OKTA_AUTHENTICATION_PROVIDER",
-            LogCredentialProvider::OktaCredentialProvider => "OKTA_CREDENTIAL_PROVIDER",
-            LogCredentialProvider::Rsa => "RSA",
-            LogCredentialProvider::Symantec => "SYMANTEC",
-            LogCredentialProvider::Yubikey => "YUBIKEY",
-            LogCredentialProvider::Noop => "",
-            LogCredentialProvider::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for LogCredentialProvider {
-    fn default() -> LogCredentialProvider {
-        LogCredentialProvider::Noop
-    }
-}
-impl LogCredentialProvider {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LogCredentialProvider::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum LogCredentialType {
-    #[serde(rename = "ASSERTION")]
-    Assertion,
-    #[serde(rename = "EMAIL")]
-    Email,
-    #[serde(rename = "IWA")]
-    Iwa,
-    #[serde(rename = "JWT")]
-    Jwt,
-    #[serde(rename = "OAUTH2")]
-    Oauth2,
-    #[serde(rename = "OTP")]
-    Otp,
-    #[serde(rename = "PASSWORD")]
-    Password,
-    #[serde(rename = "SMS")]
-    Sms,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for LogCredentialType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            LogCredentialType::Assertion => "ASSERTION",
-            LogCredentialType::Email => "EMAIL",
-            LogCredentialType::Iwa => "IWA",
-            LogCredentialType::Jwt => "JWT",
-            LogCredentialType::Oauth2 => "OAUTH2",
-            LogCredentialType::Otp => "OTP",
-            LogCredentialType::Password => "PASSWORD",
-            LogCredentialType::Sms => "SMS",
-            LogCredentialType::Noop => "",
-            LogCredentialType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for LogCredentialType {
-    fn default() -> LogCredentialType {
-        LogCredentialType::Noop
-    }
-}
-impl LogCredentialType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LogCredentialType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogDebugContext {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "debugData")]
-    pub debug_data: Option<Links>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogEvent {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub actor: Option<LogActor>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authenticationContext"
-    )]
-    pub authentication_context: Option<LogAuthenticationContext>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub client: Option<LogClient>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "debugContext"
-    )]
-    pub debug_context: Option<LogDebugContext>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayMessage"
-    )]
-    pub display_message: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "eventType"
-    )]
-    pub event_type: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "legacyEventType"
-    )]
-    pub legacy_event_type: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub outcome: Option<LogOutcome>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub published: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub request: Option<LogRequest>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "securityContext"
-    )]
-    pub security_context: Option<LogSecurityContext>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub severity: Option<LogSeverity>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub target: Vec<LogTarget>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub transaction: Option<LogTransaction>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub uuid: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogGeographicalContext {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub geolocation: Option<LogGeolocation>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "postalCode"
-    )]
-    pub postal_code: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogGeolocation {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub lat: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub lon: f64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogIpAddress {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "geographicalContext"
-    )]
-    pub geographical_context: Option<LogGeographicalContext>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub source: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogIssuer {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogOutcome {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub result: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "ipChain"
-    )]
-    pub ip_chain: Vec<LogIpAddress>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogSecurityContext {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "asNumber"
-    )]
-    pub as_number: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "asOrg"
-    )]
-    pub as_org: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub domain: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "isProxy"
-    )]
-    pub is_proxy: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub isp: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum LogSeverity {
-    #[serde(rename = "DEBUG")]
-    Debug,
-    #[serde(rename = "ERROR")]
-    Error,
-    #[serde(rename = "INFO")]
-    Info,
-    #[serde(rename = "WARN")]
-    Warn,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for LogSeverity {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            LogSeverity::Debug => "DEBUG",
-            LogSeverity::Error => "ERROR",
-            LogSeverity::Info => "INFO",
-            LogSeverity::Warn => "WARN",
-            LogSeverity::Noop => "",
-            LogSeverity::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for LogSeverity {
-    fn default() -> LogSeverity {
-        LogSeverity::Noop
-    }
-}
-impl LogSeverity {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LogSeverity::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogTarget {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "alternateId"
-    )]
-    pub alternate_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "detailEntry"
-    )]
-    pub detail_entry: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
-    )]
-    pub display_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogTransaction {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub detail: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LogUserAgent {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub browser: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub os: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "rawUserAgent"
-    )]
-    pub raw_user_agent: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Enrollment {
-    #[serde(rename = "ANY_OR_NONE")]
-    AnyOrNone,
-    #[serde(rename = "OMM")]
-    Omm,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Enrollment {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Enrollment::AnyOrNone => "ANY_OR_NONE",
-            Enrollment::Omm => "OMM",
-            Enrollment::Noop => "",
-            Enrollment::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Enrollment {
-    fn default() -> Enrollment {
-        Enrollment::Noop
-    }
-}
-impl Enrollment {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Enrollment::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MdmEnrollmentPolicyRuleCondition {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "blockNonSafeAndroid"
-    )]
-    pub block_non_safe_android: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub enrollment: Option<Enrollment>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct NetworkZone {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub asns: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub gateways: Vec<NetworkZoneAddress>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub locations: Vec<NetworkZoneLocation>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub proxies: Vec<NetworkZoneAddress>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "proxyType"
-    )]
-    pub proxy_type: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub system: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<NetworkZoneType>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub usage: Option<NetworkZoneUsage>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct NetworkZoneAddress {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<NetworkZoneAddressType>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum NetworkZoneAddressType {
-    #[serde(rename = "CIDR")]
-    Cidr,
-    #[serde(rename = "RANGE")]
-    Range,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for NetworkZoneAddressType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            NetworkZoneAddressType::Cidr => "CIDR",
-            NetworkZoneAddressType::Range => "RANGE",
-            NetworkZoneAddressType::Noop => "",
-            NetworkZoneAddressType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for NetworkZoneAddressType {
-    fn default() -> NetworkZoneAddressType {
-        NetworkZoneAddressType::Noop
-    }
-}
-impl NetworkZoneAddressType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, NetworkZoneAddressType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct NetworkZoneLocation {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub region: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum NetworkZoneType {
-    #[serde(rename = "DYNAMIC")]
-    Dynamic,
-    #[serde(rename = "IP")]
-    Ip,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for NetworkZoneType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            NetworkZoneType::Dynamic => "DYNAMIC",
-            NetworkZoneType::Ip => "IP",
-            NetworkZoneType::Noop => "",
-            NetworkZoneType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for NetworkZoneType {
-    fn default() -> NetworkZoneType {
-        NetworkZoneType::Noop
-    }
-}
-impl NetworkZoneType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, NetworkZoneType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum NetworkZoneUsage {
-    #[serde(rename = "BLOCKLIST")]
-    Blocklist,
-    #[serde(rename = "POLICY")]
-    Policy,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for NetworkZoneUsage {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            NetworkZoneUsage::Blocklist => "BLOCKLIST",
-            NetworkZoneUsage::Policy => "POLICY",
-            NetworkZoneUsage::Noop => "",
-            NetworkZoneUsage::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for NetworkZoneUsage {
-    fn default() -> NetworkZoneUsage {
-        NetworkZoneUsage::Noop
-    }
-}
-impl NetworkZoneUsage {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, NetworkZoneUsage::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OAuth2Actor {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ClaimType {
-    #[serde(rename = "IDENTITY")]
-    Identity,
-    #[serde(rename = "RESOURCE")]
-    Resource,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for ClaimType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ClaimType::Identity => "IDENTITY",
-            ClaimType::Resource => "RESOURCE",
-            ClaimType::Noop => "",
-            ClaimType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for ClaimType {
-    fn default() -> ClaimType {
-        ClaimType::Noop
-    }
-}
-impl ClaimType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ClaimType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum GroupFilterType {
-    #[serde(rename = "CONTAINS")]
-    Contains,
-    #[serde(rename = "EQUALS")]
-    Equals,
-    #[serde(rename = "REGEX")]
-    Regex,
-    #[serde(rename = "STARTS_WITH")]
-    StartsWith,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for GroupFilterType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            GroupFilterType::Contains => "CONTAINS",
-            GroupFilterType::Equals => "EQUALS",
-            GroupFilterType::Regex => "REGEX",
-            GroupFilterType::StartsWith => "STARTS_WITH",
-            GroupFilterType::Noop => "",
-            GroupFilterType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for GroupFilterType {
-    fn default() -> GroupFilterType {
-        GroupFilterType::Noop
-    }
-}
-impl GroupFilterType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GroupFilterType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ValueType {
-    #[serde(rename = "EXPRESSION")]
-    Expression,
-    #[serde(rename = "GROUPS")]
-    Groups,
-    #[serde(rename = "SYSTEM")]
-    System,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for ValueType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ValueType::Expression => "EXPRESSION",
-            ValueType::Groups => "GROUPS",
-            ValueType::System => "SYSTEM",
-            ValueType::Noop => "",
-            ValueType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for ValueType {
-    fn default() -> ValueType {
-        ValueType::Noop
-    }
-}
-impl ValueType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ValueType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OAuth2Claim {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "alwaysIncludeInToken"
-    )]
-    pub always_include_in_token: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "claimType")]
-    pub claim_type: Option<ClaimType>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub conditions: Option<OAuth2ClaimConditions>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub group_filter_type: Option<GroupFilterType>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub system: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "valueType")]
-    pub value_type: Option<ValueType>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OAuth2ClaimConditions {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OAuth2Client {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub client_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub client_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub client_uri: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub logo_uri: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OAuth2RefreshTokenStatus {
-    #[serde(rename = "ACTIVE")]
-    Active,
-    #[serde(rename = "REVOKED")]
-    Revoked,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for OAuth2RefreshTokenStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OAuth2RefreshTokenStatus::Active => "ACTIVE",
-            OAuth2RefreshTokenStatus::Revoked => "REVOKED",
-            OAuth2RefreshTokenStatus::Noop => "",
-            OAuth2RefreshTokenStatus::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for OAuth2RefreshTokenStatus {
-    fn default() -> OAuth2RefreshTokenStatus {
-        OAuth2RefreshTokenStatus::Noop
-    }
-}
-impl OAuth2RefreshTokenStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OAuth2RefreshTokenStatus::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OAuth2RefreshToken {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "clientId"
-    )]
-    pub client_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "createdBy")]
-    pub created_by: Option<OAuth2Actor>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "expiresAt"
-    )]
-    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub issuer: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<OAuth2RefreshTokenStatus>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userId"
-    )]
-    pub user_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Consent {
-    #[serde(rename = "ADMIN")]
-    Admin,
-    #[serde(rename = "IMPLICIT")]
-    Implicit,
-    #[serde(rename = "REQUIRED")]
-    Required,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Consent {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Consent::Admin => "ADMIN",
-            Consent::Implicit => "IMPLICIT",
-            Consent::Required => "REQUIRED",
-            Consent::Noop => "",
-            Consent::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Consent {
-    fn default() -> Consent {
-        Consent::Noop
-    }
-}
-impl Consent {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Consent::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum MetadataPublish {
-    #[serde(rename = "ALL_CLIENTS")]
-    AllClients,
-    #[serde(rename = "NO_CLIENTS")]
-    NoClients,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for MetadataPublish {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            MetadataPublish::AllClients => "ALL_CLIENTS",
-            MetadataPublish::NoClients => "NO_CLIENTS",
-            MetadataPublish::Noop => "",
-            MetadataPublish::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for MetadataPublish {
-    fn default() -> MetadataPublish {
-        MetadataPublish::Noop
-    }
-}
-impl MetadataPublish {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MetadataPublish::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OAuth2Scope {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub consent: Option<Consent>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub default: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
-    )]
-    pub display_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "metadataPublish"
-    )]
-    pub metadata_publish: Option<MetadataPublish>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub system: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OAuth2ScopeConsentGrant {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "clientId"
-    )]
-    pub client_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "createdBy")]
-    pub created_by: Option<OAuth2Actor>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub issuer: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "scopeId"
-    )]
-    pub scope_id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub source: Option<OAuth2ScopeConsentGrantSource>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<OAuth2RefreshTokenStatus>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userId"
-    )]
-    pub user_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OAuth2ScopeConsentGrantSource {
-    #[serde(rename = "ADMIN")]
-    Admin,
-    #[serde(rename = "END_USER")]
-    EndUser,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum Enrollment {
+    #[serde(rename = "ANY_OR_NONE")]
+    AnyOrNone,
+    #[serde(rename = "OMM")]
+    Omm,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for OAuth2ScopeConsentGrantSource {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OAuth2ScopeConsentGrantSource::Admin => "ADMIN",
-            OAuth2ScopeConsentGrantSource::EndUser => "END_USER",
-            OAuth2ScopeConsentGrantSource::Noop => "",
-            OAuth2ScopeConsentGrantSource::FallthroughString => "*",
+impl AsRef<str> for Enrollment {
+    fn as_ref(&self) -> &str {
+        match self {
+            Enrollment::AnyOrNone => "ANY_OR_NONE",
+            Enrollment::Omm => "OMM",
+            Enrollment::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for OAuth2ScopeConsentGrantSource {
-    fn default() -> OAuth2ScopeConsentGrantSource {
-        OAuth2ScopeConsentGrantSource::Noop
-    }
-}
-impl OAuth2ScopeConsentGrantSource {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OAuth2ScopeConsentGrantSource::Noop)
+impl std::fmt::Display for Enrollment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OAuth2ScopesMediationPolicyRuleCondition {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub include: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OAuth2Token {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "clientId"
-    )]
-    pub client_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "expiresAt"
-    )]
-    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub issuer: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<OAuth2RefreshTokenStatus>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userId"
-    )]
-    pub user_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OAuthApplicationCredentials {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "oauthClient"
-    )]
-    pub oauth_client: Option<ApplicationCredentialsOAuthClient>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OAuthEndpointAuthenticationMethod {
-    #[serde(rename = "client_secret_basic")]
-    ClientSecretBasic,
-    #[serde(rename = "client_secret_jwt")]
-    ClientSecretJwt,
-    #[serde(rename = "client_secret_post")]
-    ClientSecretPost,
-    #[serde(rename = "none")]
-    None,
-    #[serde(rename = "private_key_jwt")]
-    PrivateKeyJwt,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
+impl std::str::FromStr for Enrollment {
+    type Err = std::convert::Infallible;
 
-impl std::fmt::Display for OAuthEndpointAuthenticationMethod {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OAuthEndpointAuthenticationMethod::ClientSecretBasic => "client_secret_basic",
-            OAuthEndpointAuthenticationMethod::ClientSecretJwt => "client_secret_jwt",
-            OAuthEndpointAuthenticationMethod::ClientSecretPost => "client_secret_post",
-            OAuthEndpointAuthenticationMethod::None => "none",
-            OAuthEndpointAuthenticationMethod::PrivateKeyJwt => "private_key_jwt",
-            OAuthEndpointAuthenticationMethod::Noop => "",
-            OAuthEndpointAuthenticationMethod::FallthroughString => "*",
-        }
-        .fmt(f)
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANY_OR_NONE" => Enrollment::AnyOrNone,
+            "OMM" => Enrollment::Omm,
+            _ => Enrollment::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for OAuthEndpointAuthenticationMethod {
-    fn default() -> OAuthEndpointAuthenticationMethod {
-        OAuthEndpointAuthenticationMethod::Noop
-    }
-}
-impl OAuthEndpointAuthenticationMethod {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OAuthEndpointAuthenticationMethod::Noop)
+impl std::convert::From<String> for Enrollment {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OAuthGrantType {
-    #[serde(rename = "authorization_code")]
-    AuthorizationCode,
-    #[serde(rename = "client_credentials")]
-    ClientCredentials,
-    #[serde(rename = "implicit")]
-    Implicit,
-    #[serde(rename = "password")]
-    Password,
-    #[serde(rename = "refresh_token")]
-    RefreshToken,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for OAuthGrantType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OAuthGrantType::AuthorizationCode => "authorization_code",
-            OAuthGrantType::ClientCredentials => "client_credentials",
-            OAuthGrantType::Implicit => "implicit",
-            OAuthGrantType::Password => "password",
-            OAuthGrantType::RefreshToken => "refresh_token",
-            OAuthGrantType::Noop => "",
-            OAuthGrantType::FallthroughString => "*",
+impl std::convert::From<Enrollment> for String {
+    fn from(v: Enrollment) -> Self {
+        match v {
+            Enrollment::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
-impl Default for OAuthGrantType {
-    fn default() -> OAuthGrantType {
-        OAuthGrantType::Noop
-    }
-}
-impl OAuthGrantType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OAuthGrantType::Noop)
-    }
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct NetworkZoneAddress {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<NetworkZoneAddressType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OAuthResponseType {
-    #[serde(rename = "code")]
-    Code,
-    #[serde(rename = "id_token")]
-    IdToken,
-    #[serde(rename = "token")]
-    Token,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum NetworkZoneAddressType {
+    #[serde(rename = "CIDR")]
+    Cidr,
+    #[serde(rename = "RANGE")]
+    Range,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for OAuthResponseType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OAuthResponseType::Code => "code",
-            OAuthResponseType::IdToken => "id_token",
-            OAuthResponseType::Token => "token",
-            OAuthResponseType::Noop => "",
-            OAuthResponseType::FallthroughString => "*",
+impl AsRef<str> for NetworkZoneAddressType {
+    fn as_ref(&self) -> &str {
+        match self {
+            NetworkZoneAddressType::Cidr => "CIDR",
+            NetworkZoneAddressType::Range => "RANGE",
+            NetworkZoneAddressType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for OAuthResponseType {
-    fn default() -> OAuthResponseType {
-        OAuthResponseType::Noop
-    }
-}
-impl OAuthResponseType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OAuthResponseType::Noop)
+impl std::fmt::Display for NetworkZoneAddressType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OktaSignOnPolicy {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub conditions: Option<OktaSignOnPolicyConditions>,
-}
+impl std::str::FromStr for NetworkZoneAddressType {
+    type Err = std::convert::Infallible;
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OktaSignOnPolicyConditions {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub people: Option<PolicyPeopleCondition>,
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CIDR" => NetworkZoneAddressType::Cidr,
+            "RANGE" => NetworkZoneAddressType::Range,
+            _ => NetworkZoneAddressType::Unknown(s.to_string()),
+        })
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OktaSignOnPolicyRule {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub actions: Option<OktaSignOnPolicyRuleActions>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub conditions: Option<OktaSignOnPolicyRuleConditions>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+impl std::convert::From<String> for NetworkZoneAddressType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OktaSignOnPolicyRuleActions {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub signon: Option<OktaSignOnPolicyRuleSignonActions>,
+impl std::convert::From<NetworkZoneAddressType> for String {
+    fn from(v: NetworkZoneAddressType) -> Self {
+        match v {
+            NetworkZoneAddressType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OktaSignOnPolicyRuleConditions {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authContext"
-    )]
-    pub auth_context: Option<PolicyRuleAuthContextCondition>,
+pub struct NetworkZoneLocation {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub network: Option<PolicyNetworkCondition>,
+    pub country: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub people: Option<PolicyPeopleCondition>,
+    pub region: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Access {
-    #[serde(rename = "ALLOW")]
-    Allow,
-    #[serde(rename = "DENY")]
-    Deny,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum ClaimType {
+    #[serde(rename = "IDENTITY")]
+    Identity,
+    #[serde(rename = "RESOURCE")]
+    Resource,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Access {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Access::Allow => "ALLOW",
-            Access::Deny => "DENY",
-            Access::Noop => "",
-            Access::FallthroughString => "*",
+impl AsRef<str> for ClaimType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ClaimType::Identity => "IDENTITY",
+            ClaimType::Resource => "RESOURCE",
+            ClaimType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Access {
-    fn default() -> Access {
-        Access::Noop
+impl std::fmt::Display for ClaimType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Access {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Access::Noop)
+
+impl std::str::FromStr for ClaimType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "IDENTITY" => ClaimType::Identity,
+            "RESOURCE" => ClaimType::Resource,
+            _ => ClaimType::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum FactorPromptMode {
-    #[serde(rename = "ALWAYS")]
-    Always,
-    #[serde(rename = "DEVICE")]
-    Device,
-    #[serde(rename = "SESSION")]
-    Session,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+impl std::convert::From<String> for ClaimType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-impl std::fmt::Display for FactorPromptMode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            FactorPromptMode::Always => "ALWAYS",
-            FactorPromptMode::Device => "DEVICE",
-            FactorPromptMode::Session => "SESSION",
-            FactorPromptMode::Noop => "",
-            FactorPromptMode::FallthroughString => "*",
+impl std::convert::From<ClaimType> for String {
+    fn from(v: ClaimType) -> Self {
+        match v {
+            ClaimType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
-impl Default for FactorPromptMode {
-    fn default() -> FactorPromptMode {
-        FactorPromptMode::Noop
-    }
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum GroupFilterType {
+    #[serde(rename = "CONTAINS")]
+    Contains,
+    #[serde(rename = "EQUALS")]
+    Equals,
+    #[serde(rename = "REGEX")]
+    Regex,
+    #[serde(rename = "STARTS_WITH")]
+    StartsWith,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
-impl FactorPromptMode {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FactorPromptMode::Noop)
+
+impl AsRef<str> for GroupFilterType {
+    fn as_ref(&self) -> &str {
+        match self {
+            GroupFilterType::Contains => "CONTAINS",
+            GroupFilterType::Equals => "EQUALS",
+            GroupFilterType::Regex => "REGEX",
+            GroupFilterType::StartsWith => "STARTS_WITH",
+            GroupFilterType::Unknown(s) => s,
+        }
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OktaSignOnPolicyRuleSignonActions {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub access: Option<Access>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "factorLifetime"
-    )]
-    pub factor_lifetime: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "factorPromptMode"
-    )]
-    pub factor_prompt_mode: Option<FactorPromptMode>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "rememberDeviceByDefault"
-    )]
-    pub remember_device_by_default: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "requireFactor"
-    )]
-    pub require_factor: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub session: Option<OktaSignOnPolicyRuleSignonSessionActions>,
+impl std::fmt::Display for GroupFilterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OktaSignOnPolicyRuleSignonSessionActions {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxSessionIdleMinutes"
-    )]
-    pub max_session_idle_minutes: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxSessionLifetimeMinutes"
-    )]
-    pub max_session_lifetime_minutes: i64,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "usePersistentCookie"
-    )]
-    pub use_persistent_cookie: bool,
+impl std::str::FromStr for GroupFilterType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CONTAINS" => GroupFilterType::Contains,
+            "EQUALS" => GroupFilterType::Equals,
+            "REGEX" => GroupFilterType::Regex,
+            "STARTS_WITH" => GroupFilterType::StartsWith,
+            _ => GroupFilterType::Unknown(s.to_string()),
+        })
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OpenConnectApplication {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub credentials: Option<OAuthApplicationCredentials>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub name: Option<serde_json::Value>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub settings: Option<OpenConnectApplicationSettings>,
+impl std::convert::From<String> for GroupFilterType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OpenConnectApplicationConsentMethod {
-    #[serde(rename = "REQUIRED")]
-    Required,
-    #[serde(rename = "TRUSTED")]
-    Trusted,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+impl std::convert::From<GroupFilterType> for String {
+    fn from(v: GroupFilterType) -> Self {
+        match v {
+            GroupFilterType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
-impl std::fmt::Display for OpenConnectApplicationConsentMethod {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OpenConnectApplicationConsentMethod::Required => "REQUIRED",
-            OpenConnectApplicationConsentMethod::Trusted => "TRUSTED",
-            OpenConnectApplicationConsentMethod::Noop => "",
-            OpenConnectApplicationConsentMethod::FallthroughString => "*",
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OAuth2ClaimStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OAuth2ClaimStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            OAuth2ClaimStatus::Active => "ACTIVE",
+            OAuth2ClaimStatus::Inactive => "INACTIVE",
+            OAuth2ClaimStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for OpenConnectApplicationConsentMethod {
-    fn default() -> OpenConnectApplicationConsentMethod {
-        OpenConnectApplicationConsentMethod::Noop
+impl std::fmt::Display for OAuth2ClaimStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl OpenConnectApplicationConsentMethod {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OpenConnectApplicationConsentMethod::Noop)
+
+impl std::str::FromStr for OAuth2ClaimStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => OAuth2ClaimStatus::Active,
+            "INACTIVE" => OAuth2ClaimStatus::Inactive,
+            _ => OAuth2ClaimStatus::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OpenConnectApplicationIdpInitiatedLogin {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub default_scope: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub mode: String,
+impl std::convert::From<String> for OAuth2ClaimStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OpenConnectApplicationSettings {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "oauthClient"
-    )]
-    pub oauth_client: Option<OpenConnectApplicationSettingsClient>,
+impl std::convert::From<OAuth2ClaimStatus> for String {
+    fn from(v: OAuth2ClaimStatus) -> Self {
+        match v {
+            OAuth2ClaimStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OpenConnectApplicationSettingsClient {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub application_type: Option<OpenConnectApplicationType>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub client_uri: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub consent_method: Option<OpenConnectApplicationConsentMethod>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub grant_types: Vec<OAuthGrantType>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub idp_initiated_login: Option<OpenConnectApplicationIdpInitiatedLogin>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub initiate_login_uri: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub issuer_mode: Option<IssuerMode>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub jwks: Option<OpenConnectApplicationSettingsClientKeys>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub logo_uri: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub policy_uri: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub post_logout_redirect_uris: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub redirect_uris: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub refresh_token: Option<OpenConnectApplicationSettingsRefreshToken>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub response_types: Vec<OAuthResponseType>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub tos_uri: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub wildcard_redirect: String,
+#[serde(from = "String", into = "String")]
+pub enum ValueType {
+    #[serde(rename = "EXPRESSION")]
+    Expression,
+    #[serde(rename = "GROUPS")]
+    Groups,
+    #[serde(rename = "SYSTEM")]
+    System,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OpenConnectApplicationSettingsClientKeys {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub keys: Vec<JsonWebKey>,
+impl AsRef<str> for ValueType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ValueType::Expression => "EXPRESSION",
+            ValueType::Groups => "GROUPS",
+            ValueType::System => "SYSTEM",
+            ValueType::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OpenConnectApplicationSettingsRefreshToken {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub leeway: i64,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub rotation_type: Option<OpenConnectRefreshTokenRotationType>,
+impl std::fmt::Display for ValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ValueType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EXPRESSION" => ValueType::Expression,
+            "GROUPS" => ValueType::Groups,
+            "SYSTEM" => ValueType::System,
+            _ => ValueType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ValueType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ValueType> for String {
+    fn from(v: ValueType) -> Self {
+        match v {
+            ValueType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OpenConnectApplicationType {
-    #[serde(rename = "browser")]
-    Browser,
-    #[serde(rename = "native")]
-    Native,
-    #[serde(rename = "service")]
-    Service,
-    #[serde(rename = "web")]
-    Web,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum OAuth2RefreshTokenStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "REVOKED")]
+    Revoked,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for OpenConnectApplicationType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OpenConnectApplicationType::Browser => "browser",
-            OpenConnectApplicationType::Native => "native",
-            OpenConnectApplicationType::Service => "service",
-            OpenConnectApplicationType::Web => "web",
-            OpenConnectApplicationType::Noop => "",
-            OpenConnectApplicationType::FallthroughString => "*",
+impl AsRef<str> for OAuth2RefreshTokenStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            OAuth2RefreshTokenStatus::Active => "ACTIVE",
+            OAuth2RefreshTokenStatus::Revoked => "REVOKED",
+            OAuth2RefreshTokenStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for OpenConnectApplicationType {
-    fn default() -> OpenConnectApplicationType {
-        OpenConnectApplicationType::Noop
+impl std::fmt::Display for OAuth2RefreshTokenStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OAuth2RefreshTokenStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => OAuth2RefreshTokenStatus::Active,
+            "REVOKED" => OAuth2RefreshTokenStatus::Revoked,
+            _ => OAuth2RefreshTokenStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OAuth2RefreshTokenStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl OpenConnectApplicationType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OpenConnectApplicationType::Noop)
+
+impl std::convert::From<OAuth2RefreshTokenStatus> for String {
+    fn from(v: OAuth2RefreshTokenStatus) -> Self {
+        match v {
+            OAuth2RefreshTokenStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OpenConnectRefreshTokenRotationType {
-    #[serde(rename = "rotate")]
-    Rotate,
-    #[serde(rename = "static")]
-    Static,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum Consent {
+    #[serde(rename = "ADMIN")]
+    Admin,
+    #[serde(rename = "IMPLICIT")]
+    Implicit,
+    #[serde(rename = "REQUIRED")]
+    Required,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for OpenConnectRefreshTokenRotationType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OpenConnectRefreshTokenRotationType::Rotate => "rotate",
-            OpenConnectRefreshTokenRotationType::Static => "static",
-            OpenConnectRefreshTokenRotationType::Noop => "",
-            OpenConnectRefreshTokenRotationType::FallthroughString => "*",
+impl AsRef<str> for Consent {
+    fn as_ref(&self) -> &str {
+        match self {
+            Consent::Admin => "ADMIN",
+            Consent::Implicit => "IMPLICIT",
+            Consent::Required => "REQUIRED",
+            Consent::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for OpenConnectRefreshTokenRotationType {
-    fn default() -> OpenConnectRefreshTokenRotationType {
-        OpenConnectRefreshTokenRotationType::Noop
+impl std::fmt::Display for Consent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl OpenConnectRefreshTokenRotationType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OpenConnectRefreshTokenRotationType::Noop)
+
+impl std::str::FromStr for Consent {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ADMIN" => Consent::Admin,
+            "IMPLICIT" => Consent::Implicit,
+            "REQUIRED" => Consent::Required,
+            _ => Consent::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordCredential {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub hash: Option<PasswordCredentialHash>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub hook: Option<PasswordCredentialHook>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
+impl std::convert::From<String> for Consent {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordCredentialHash {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub algorithm: Option<PasswordCredentialHashAlgorithm>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub salt: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "saltOrder"
-    )]
-    pub salt_order: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "workFactor"
-    )]
-    pub work_factor: i64,
+impl std::convert::From<Consent> for String {
+    fn from(v: Consent) -> Self {
+        match v {
+            Consent::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PasswordCredentialHashAlgorithm {
-    #[serde(rename = "BCRYPT")]
-    Bcrypt,
-    #[serde(rename = "MD5")]
-    Md5,
-    #[serde(rename = "SHA-1")]
-    ShAminusOne,
-    #[serde(rename = "SHA-256")]
-    Sha256,
-    #[serde(rename = "SHA-512")]
-    Sha512,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum MetadataPublish {
+    #[serde(rename = "ALL_CLIENTS")]
+    AllClients,
+    #[serde(rename = "NO_CLIENTS")]
+    NoClients,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PasswordCredentialHashAlgorithm {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PasswordCredentialHashAlgorithm::Bcrypt => "BCRYPT",
-            PasswordCredentialHashAlgorithm::Md5 => "MD5",
-            PasswordCredentialHashAlgorithm::ShAminusOne => "SHA-1",
-            PasswordCredentialHashAlgorithm::Sha256 => "SHA-256",
-            PasswordCredentialHashAlgorithm::Sha512 => "SHA-512",
-            PasswordCredentialHashAlgorithm::Noop => "",
-            PasswordCredentialHashAlgorithm::FallthroughString => "*",
+impl AsRef<str> for MetadataPublish {
+    fn as_ref(&self) -> &str {
+        match self {
+            MetadataPublish::AllClients => "ALL_CLIENTS",
+            MetadataPublish::NoClients => "NO_CLIENTS",
+            MetadataPublish::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PasswordCredentialHashAlgorithm {
-    fn default() -> PasswordCredentialHashAlgorithm {
-        PasswordCredentialHashAlgorithm::Noop
+impl std::fmt::Display for MetadataPublish {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for MetadataPublish {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ALL_CLIENTS" => MetadataPublish::AllClients,
+            "NO_CLIENTS" => MetadataPublish::NoClients,
+            _ => MetadataPublish::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for MetadataPublish {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PasswordCredentialHashAlgorithm {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PasswordCredentialHashAlgorithm::Noop)
+
+impl std::convert::From<MetadataPublish> for String {
+    fn from(v: MetadataPublish) -> Self {
+        match v {
+            MetadataPublish::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordCredentialHook {
+pub struct OAuth2ScopesMediationPolicyRuleCondition {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub type_: String,
+    pub include: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordDictionary {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub common: Option<PasswordDictionaryCommon>,
+#[serde(from = "String", into = "String")]
+pub enum OAuth2TokenStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "REVOKED")]
+    Revoked,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OAuth2TokenStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            OAuth2TokenStatus::Active => "ACTIVE",
+            OAuth2TokenStatus::Revoked => "REVOKED",
+            OAuth2TokenStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OAuth2TokenStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OAuth2TokenStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => OAuth2TokenStatus::Active,
+            "REVOKED" => OAuth2TokenStatus::Revoked,
+            _ => OAuth2TokenStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OAuth2TokenStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OAuth2TokenStatus> for String {
+    fn from(v: OAuth2TokenStatus) -> Self {
+        match v {
+            OAuth2TokenStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordDictionaryCommon {
+pub struct OAuthApplicationCredentials {
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "oauthClient"
     )]
-    pub exclude: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicy {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub conditions: Option<PasswordPolicyConditions>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub settings: Option<PasswordPolicySettingsData>,
+    pub oauth_client: Option<ApplicationCredentialsOAuthClient>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PasswordPolicyAuthenticationProviderCondition {
-    #[serde(rename = "ACTIVE_DIRECTORY")]
-    ActiveDirectory,
-    #[serde(rename = "ANY")]
-    Any,
-    #[serde(rename = "LDAP")]
-    Ldap,
-    #[serde(rename = "OKTA")]
-    Okta,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum OAuthEndpointAuthenticationMethod {
+    #[serde(rename = "client_secret_basic")]
+    ClientSecretBasic,
+    #[serde(rename = "client_secret_jwt")]
+    ClientSecretJwt,
+    #[serde(rename = "client_secret_post")]
+    ClientSecretPost,
+    #[serde(rename = "none")]
+    None,
+    #[serde(rename = "private_key_jwt")]
+    PrivateKeyJwt,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PasswordPolicyAuthenticationProviderCondition {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PasswordPolicyAuthenticationProviderCondition::ActiveDirectory => "ACTIVE_DIRECTORY",
-            PasswordPolicyAuthenticationProviderCondition::Any => "ANY",
-            PasswordPolicyAuthenticationProviderCondition::Ldap => "LDAP",
-            PasswordPolicyAuthenticationProviderCondition::Okta => "OKTA",
-            PasswordPolicyAuthenticationProviderCondition::Noop => "",
-            PasswordPolicyAuthenticationProviderCondition::FallthroughString => "*",
+impl AsRef<str> for OAuthEndpointAuthenticationMethod {
+    fn as_ref(&self) -> &str {
+        match self {
+            OAuthEndpointAuthenticationMethod::ClientSecretBasic => "client_secret_basic",
+            OAuthEndpointAuthenticationMethod::ClientSecretJwt => "client_secret_jwt",
+            OAuthEndpointAuthenticationMethod::ClientSecretPost => "client_secret_post",
+            OAuthEndpointAuthenticationMethod::None => "none",
+            OAuthEndpointAuthenticationMethod::PrivateKeyJwt => "private_key_jwt",
+            OAuthEndpointAuthenticationMethod::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PasswordPolicyAuthenticationProviderCondition {
-    fn default() -> PasswordPolicyAuthenticationProviderCondition {
-        PasswordPolicyAuthenticationProviderCondition::Noop
+impl std::fmt::Display for OAuthEndpointAuthenticationMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl PasswordPolicyAuthenticationProviderCondition {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PasswordPolicyAuthenticationProviderCondition::Noop)
+
+impl std::str::FromStr for OAuthEndpointAuthenticationMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "client_secret_basic" => OAuthEndpointAuthenticationMethod::ClientSecretBasic,
+            "client_secret_jwt" => OAuthEndpointAuthenticationMethod::ClientSecretJwt,
+            "client_secret_post" => OAuthEndpointAuthenticationMethod::ClientSecretPost,
+            "none" => OAuthEndpointAuthenticationMethod::None,
+            "private_key_jwt" => OAuthEndpointAuthenticationMethod::PrivateKeyJwt,
+            _ => OAuthEndpointAuthenticationMethod::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyAuthenticationProviderConditionData {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub include: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub provider: Option<PasswordPolicyAuthenticationProviderCondition>,
+impl std::convert::From<String> for OAuthEndpointAuthenticationMethod {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyConditions {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authProvider"
-    )]
-    pub auth_provider: Option<PasswordPolicyAuthenticationProviderConditionData>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub people: Option<PolicyPeopleCondition>,
+impl std::convert::From<OAuthEndpointAuthenticationMethod> for String {
+    fn from(v: OAuthEndpointAuthenticationMethod) -> Self {
+        match v {
+            OAuthEndpointAuthenticationMethod::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyDelegationSettings {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub options: Option<PasswordPolicyDelegationSettingsOptions>,
+#[serde(from = "String", into = "String")]
+pub enum OAuthGrantType {
+    #[serde(rename = "authorization_code")]
+    AuthorizationCode,
+    #[serde(rename = "client_credentials")]
+    ClientCredentials,
+    #[serde(rename = "implicit")]
+    Implicit,
+    #[serde(rename = "password")]
+    Password,
+    #[serde(rename = "refresh_token")]
+    RefreshToken,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyDelegationSettingsOptions {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "skipUnlock"
-    )]
-    pub skip_unlock: bool,
+impl AsRef<str> for OAuthGrantType {
+    fn as_ref(&self) -> &str {
+        match self {
+            OAuthGrantType::AuthorizationCode => "authorization_code",
+            OAuthGrantType::ClientCredentials => "client_credentials",
+            OAuthGrantType::Implicit => "implicit",
+            OAuthGrantType::Password => "password",
+            OAuthGrantType::RefreshToken => "refresh_token",
+            OAuthGrantType::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicySettings {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub age: Option<PasswordPolicySettingsAge>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub complexity: Option<PasswordPolicySettingsComplexity>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub lockout: Option<PasswordPolicySettingsLockout>,
+impl std::fmt::Display for OAuthGrantType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicySettingsAge {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "expireWarnDays"
-    )]
-    pub expire_warn_days: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "historyCount"
-    )]
-    pub history_count: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxAgeDays"
-    )]
-    pub max_age_days: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minAgeMinutes"
-    )]
-    pub min_age_minutes: i64,
-}
+impl std::str::FromStr for OAuthGrantType {
+    type Err = std::convert::Infallible;
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicySettingsComplexity {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub dictionary: Option<PasswordDictionary>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "excludeAttributes"
-    )]
-    pub exclude_attributes: Vec<String>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "excludeUsername"
-    )]
-    pub exclude_username: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minLength"
-    )]
-    pub min_length: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minLowerCase"
-    )]
-    pub min_lower_case: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minNumber"
-    )]
-    pub min_number: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minSymbol"
-    )]
-    pub min_symbol: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minUpperCase"
-    )]
-    pub min_upper_case: i64,
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "authorization_code" => OAuthGrantType::AuthorizationCode,
+            "client_credentials" => OAuthGrantType::ClientCredentials,
+            "implicit" => OAuthGrantType::Implicit,
+            "password" => OAuthGrantType::Password,
+            "refresh_token" => OAuthGrantType::RefreshToken,
+            _ => OAuthGrantType::Unknown(s.to_string()),
+        })
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicySettingsLockout {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "autoUnlockMinutes"
-    )]
-    pub auto_unlock_minutes: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "maxAttempts"
-    )]
-    pub max_attempts: i64,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "showLockoutFailures"
-    )]
-    pub show_lockout_failures: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "userLockoutNotificationChannels"
-    )]
-    pub user_lockout_notification_channels: Vec<String>,
+impl std::convert::From<String> for OAuthGrantType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyRecoveryEmail {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<PasswordPolicyRecoveryEmailProperties>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
+impl std::convert::From<OAuthGrantType> for String {
+    fn from(v: OAuthGrantType) -> Self {
+        match v {
+            OAuthGrantType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyRecoveryEmailProperties {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "recoveryToken"
-    )]
-    pub recovery_token: Option<PasswordPolicyRecoveryEmailToken>,
+#[serde(from = "String", into = "String")]
+pub enum OAuthResponseType {
+    #[serde(rename = "code")]
+    Code,
+    #[serde(rename = "id_token")]
+    IdToken,
+    #[serde(rename = "token")]
+    Token,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyRecoveryEmailToken {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "tokenLifetimeMinutes"
-    )]
-    pub token_lifetime_minutes: i64,
+impl AsRef<str> for OAuthResponseType {
+    fn as_ref(&self) -> &str {
+        match self {
+            OAuthResponseType::Code => "code",
+            OAuthResponseType::IdToken => "id_token",
+            OAuthResponseType::Token => "token",
+            OAuthResponseType::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyRecoveryFactorSettings {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
+impl std::fmt::Display for OAuthResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyRecoveryFactors {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub okta_call: Option<PasswordPolicyRecoveryFactorSettings>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub okta_email: Option<PasswordPolicyRecoveryEmail>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub okta_sms: Option<PasswordPolicyRecoveryFactorSettings>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub recovery_question: Option<PasswordPolicyRecoveryQuestion>,
-}
+impl std::str::FromStr for OAuthResponseType {
+    type Err = std::convert::Infallible;
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyRecoveryQuestion {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<PasswordPolicyRecoveryQuestionProperties>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "code" => OAuthResponseType::Code,
+            "id_token" => OAuthResponseType::IdToken,
+            "token" => OAuthResponseType::Token,
+            _ => OAuthResponseType::Unknown(s.to_string()),
+        })
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyRecoveryQuestionComplexity {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "minLength"
-    )]
-    pub min_length: i64,
+impl std::convert::From<String> for OAuthResponseType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OAuthResponseType> for String {
+    fn from(v: OAuthResponseType) -> Self {
+        match v {
+            OAuthResponseType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyRecoveryQuestionProperties {
+pub struct OktaSignOnPolicy {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub complexity: Option<PasswordPolicyRecoveryQuestionComplexity>,
+    pub conditions: Option<OktaSignOnPolicyConditions>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyRecoverySettings {
+pub struct OktaSignOnPolicyConditions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub factors: Option<PasswordPolicyRecoveryFactors>,
+    pub people: Option<PolicyPeopleCondition>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyRule {
+pub struct OktaSignOnPolicyRule {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub actions: Option<PasswordPolicyRuleActions>,
+    pub actions: Option<OktaSignOnPolicyRuleActions>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub conditions: Option<PasswordPolicyRuleConditions>,
+    pub conditions: Option<OktaSignOnPolicyRuleConditions>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -6363,2733 +3799,2458 @@ pub struct PasswordPolicyRule {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyRuleAction {
+pub struct OktaSignOnPolicyRuleActions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub access: Option<Access>,
+    pub signon: Option<OktaSignOnPolicyRuleSignonActions>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyRuleActions {
+pub struct OktaSignOnPolicyRuleConditions {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        rename = "passwordChange"
+        rename = "authContext"
     )]
-    pub password_change: Option<PasswordPolicyRuleAction>,
+    pub auth_context: Option<PolicyRuleAuthContextCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<PolicyNetworkCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub people: Option<PolicyPeopleCondition>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Access {
+    #[serde(rename = "ALLOW")]
+    Allow,
+    #[serde(rename = "DENY")]
+    Deny,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Access {
+    fn as_ref(&self) -> &str {
+        match self {
+            Access::Allow => "ALLOW",
+            Access::Deny => "DENY",
+            Access::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Access {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Access {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ALLOW" => Access::Allow,
+            "DENY" => Access::Deny,
+            _ => Access::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Access {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Access> for String {
+    fn from(v: Access) -> Self {
+        match v {
+            Access::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum FactorPromptMode {
+    #[serde(rename = "ALWAYS")]
+    Always,
+    #[serde(rename = "DEVICE")]
+    Device,
+    #[serde(rename = "SESSION")]
+    Session,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for FactorPromptMode {
+    fn as_ref(&self) -> &str {
+        match self {
+            FactorPromptMode::Always => "ALWAYS",
+            FactorPromptMode::Device => "DEVICE",
+            FactorPromptMode::Session => "SESSION",
+            FactorPromptMode::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for FactorPromptMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for FactorPromptMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ALWAYS" => FactorPromptMode::Always,
+            "DEVICE" => FactorPromptMode::Device,
+            "SESSION" => FactorPromptMode::Session,
+            _ => FactorPromptMode::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for FactorPromptMode {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<FactorPromptMode> for String {
+    fn from(v: FactorPromptMode) -> Self {
+        match v {
+            FactorPromptMode::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OktaSignOnPolicyRuleSignonActions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access: Option<Access>,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "selfServicePasswordReset"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "factorLifetime"
     )]
-    pub self_service_password_reset: Option<PasswordPolicyRuleAction>,
+    pub factor_lifetime: i64,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        rename = "selfServiceUnlock"
+        rename = "factorPromptMode"
     )]
-    pub self_service_unlock: Option<PasswordPolicyRuleAction>,
+    pub factor_prompt_mode: Option<FactorPromptMode>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "rememberDeviceByDefault"
+    )]
+    pub remember_device_by_default: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "requireFactor"
+    )]
+    pub require_factor: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session: Option<OktaSignOnPolicyRuleSignonSessionActions>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicyRuleConditions {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub network: Option<PolicyNetworkCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub people: Option<PolicyPeopleCondition>,
+pub struct OktaSignOnPolicyRuleSignonSessionActions {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "maxSessionIdleMinutes"
+    )]
+    pub max_session_idle_minutes: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "maxSessionLifetimeMinutes"
+    )]
+    pub max_session_lifetime_minutes: i64,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "usePersistentCookie"
+    )]
+    pub use_persistent_cookie: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PasswordPolicySettingsData {
+pub struct OpenConnectApplication {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub delegation: Option<PasswordPolicyDelegationSettings>,
+    pub credentials: Option<OAuthApplicationCredentials>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub password: Option<PasswordPolicySettings>,
+    pub name: Option<serde_json::Value>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub recovery: Option<PasswordPolicyRecoverySettings>,
+    pub settings: Option<OpenConnectApplicationSettings>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PlatformConditionEvaluatorType {
-    #[serde(rename = "ANY")]
-    Any,
-    #[serde(rename = "DESKTOP")]
-    Desktop,
-    #[serde(rename = "MOBILE")]
-    Mobile,
-    #[serde(rename = "OTHER")]
-    Other,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum OpenConnectApplicationConsentMethod {
+    #[serde(rename = "REQUIRED")]
+    Required,
+    #[serde(rename = "TRUSTED")]
+    Trusted,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PlatformConditionEvaluatorType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PlatformConditionEvaluatorType::Any => "ANY",
-            PlatformConditionEvaluatorType::Desktop => "DESKTOP",
-            PlatformConditionEvaluatorType::Mobile => "MOBILE",
-            PlatformConditionEvaluatorType::Other => "OTHER",
-            PlatformConditionEvaluatorType::Noop => "",
-            PlatformConditionEvaluatorType::FallthroughString => "*",
+impl AsRef<str> for OpenConnectApplicationConsentMethod {
+    fn as_ref(&self) -> &str {
+        match self {
+            OpenConnectApplicationConsentMethod::Required => "REQUIRED",
+            OpenConnectApplicationConsentMethod::Trusted => "TRUSTED",
+            OpenConnectApplicationConsentMethod::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PlatformConditionEvaluatorType {
-    fn default() -> PlatformConditionEvaluatorType {
-        PlatformConditionEvaluatorType::Noop
-    }
-}
-impl PlatformConditionEvaluatorType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PlatformConditionEvaluatorType::Noop)
+impl std::fmt::Display for OpenConnectApplicationConsentMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PlatformConditionEvaluator {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub os: Option<PlatformConditionEvaluatorOperatingSystem>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<PlatformConditionEvaluatorType>,
-}
+impl std::str::FromStr for OpenConnectApplicationConsentMethod {
+    type Err = std::convert::Infallible;
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PlatformConditionEvaluatorOperatingSystemType {
-    #[serde(rename = "ANDROID")]
-    Android,
-    #[serde(rename = "ANY")]
-    Any,
-    #[serde(rename = "IOS")]
-    Ios,
-    #[serde(rename = "OSX")]
-    Osx,
-    #[serde(rename = "OTHER")]
-    Other,
-    #[serde(rename = "WINDOWS")]
-    Windows,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "REQUIRED" => OpenConnectApplicationConsentMethod::Required,
+            "TRUSTED" => OpenConnectApplicationConsentMethod::Trusted,
+            _ => OpenConnectApplicationConsentMethod::Unknown(s.to_string()),
+        })
+    }
 }
 
-impl std::fmt::Display for PlatformConditionEvaluatorOperatingSystemType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PlatformConditionEvaluatorOperatingSystemType::Android => "ANDROID",
-            PlatformConditionEvaluatorOperatingSystemType::Any => "ANY",
-            PlatformConditionEvaluatorOperatingSystemType::Ios => "IOS",
-            PlatformConditionEvaluatorOperatingSystemType::Osx => "OSX",
-            PlatformConditionEvaluatorOperatingSystemType::Other => "OTHER",
-            PlatformConditionEvaluatorOperatingSystemType::Windows => "WINDOWS",
-            PlatformConditionEvaluatorOperatingSystemType::Noop => "",
-            PlatformConditionEvaluatorOperatingSystemType::FallthroughString => "*",
+impl std::convert::From<String> for OpenConnectApplicationConsentMethod {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
         }
-        .fmt(f)
     }
 }
 
-impl Default for PlatformConditionEvaluatorOperatingSystemType {
-    fn default() -> PlatformConditionEvaluatorOperatingSystemType {
-        PlatformConditionEvaluatorOperatingSystemType::Noop
-    }
-}
-impl PlatformConditionEvaluatorOperatingSystemType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PlatformConditionEvaluatorOperatingSystemType::Noop)
+impl std::convert::From<OpenConnectApplicationConsentMethod> for String {
+    fn from(v: OpenConnectApplicationConsentMethod) -> Self {
+        match v {
+            OpenConnectApplicationConsentMethod::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PlatformConditionEvaluatorOperatingSystem {
+pub struct OpenConnectApplicationIdpInitiatedLogin {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub default_scope: Vec<String>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub expression: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<PlatformConditionEvaluatorOperatingSystemType>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub version: Option<PlatformConditionEvaluatorOperatingSystemVersion>,
+    pub mode: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum MatchType {
-    #[serde(rename = "EXPRESSION")]
-    Expression,
-    #[serde(rename = "SEMVER")]
-    Semver,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum OpenConnectApplicationIssuerMode {
+    #[serde(rename = "CUSTOM_URL")]
+    CustomUrl,
+    #[serde(rename = "ORG_URL")]
+    OrgUrl,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OpenConnectApplicationIssuerMode {
+    fn as_ref(&self) -> &str {
+        match self {
+            OpenConnectApplicationIssuerMode::CustomUrl => "CUSTOM_URL",
+            OpenConnectApplicationIssuerMode::OrgUrl => "ORG_URL",
+            OpenConnectApplicationIssuerMode::Unknown(s) => s,
+        }
+    }
 }
 
-impl std::fmt::Display for MatchType {
+impl std::fmt::Display for OpenConnectApplicationIssuerMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            MatchType::Expression => "EXPRESSION",
-            MatchType::Semver => "SEMVER",
-            MatchType::Noop => "",
-            MatchType::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OpenConnectApplicationIssuerMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CUSTOM_URL" => OpenConnectApplicationIssuerMode::CustomUrl,
+            "ORG_URL" => OpenConnectApplicationIssuerMode::OrgUrl,
+            _ => OpenConnectApplicationIssuerMode::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for MatchType {
-    fn default() -> MatchType {
-        MatchType::Noop
+impl std::convert::From<String> for OpenConnectApplicationIssuerMode {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl MatchType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MatchType::Noop)
+
+impl std::convert::From<OpenConnectApplicationIssuerMode> for String {
+    fn from(v: OpenConnectApplicationIssuerMode) -> Self {
+        match v {
+            OpenConnectApplicationIssuerMode::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PlatformConditionEvaluatorOperatingSystemVersion {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "matchType")]
-    pub match_type: Option<MatchType>,
+pub struct OpenConnectApplicationSettings {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "oauthClient"
     )]
-    pub value: String,
+    pub oauth_client: Option<OpenConnectApplicationSettingsClient>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PlatformPolicyRuleCondition {
+pub struct OpenConnectApplicationSettingsClient {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub application_type: Option<OpenConnectApplicationType>,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub exclude: Vec<PlatformConditionEvaluator>,
+    pub client_uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consent_method: Option<OpenConnectApplicationConsentMethod>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub include: Vec<PlatformConditionEvaluator>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Policy {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    pub grant_types: Vec<OAuthGrantType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub conditions: Option<PolicyRuleConditions>,
+    pub idp_initiated_login: Option<OpenConnectApplicationIdpInitiatedLogin>,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    pub initiate_login_uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_mode: Option<OpenConnectApplicationIssuerMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jwks: Option<OpenConnectApplicationSettingsClientKeys>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub description: String,
+    pub logo_uri: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub policy_uri: String,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    pub post_logout_redirect_uris: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub redirect_uris: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<OpenConnectApplicationSettingsRefreshToken>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub response_types: Vec<OAuthResponseType>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub tos_uri: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub priority: i64,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
+    pub wildcard_redirect: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OpenConnectApplicationSettingsClientKeys {
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub system: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<PolicyType>,
+    pub keys: Vec<JsonWebKey>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Action {
-    #[serde(rename = "AUTO")]
-    Auto,
-    #[serde(rename = "DISABLED")]
-    Disabled,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+pub struct OpenConnectApplicationSettingsRefreshToken {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub leeway: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rotation_type: Option<OpenConnectRefreshTokenRotationType>,
 }
 
-impl std::fmt::Display for Action {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Action::Auto => "AUTO",
-            Action::Disabled => "DISABLED",
-            Action::Noop => "",
-            Action::FallthroughString => "*",
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OpenConnectApplicationType {
+    #[serde(rename = "browser")]
+    Browser,
+    #[serde(rename = "native")]
+    Native,
+    #[serde(rename = "service")]
+    Service,
+    #[serde(rename = "web")]
+    Web,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OpenConnectApplicationType {
+    fn as_ref(&self) -> &str {
+        match self {
+            OpenConnectApplicationType::Browser => "browser",
+            OpenConnectApplicationType::Native => "native",
+            OpenConnectApplicationType::Service => "service",
+            OpenConnectApplicationType::Web => "web",
+            OpenConnectApplicationType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Action {
-    fn default() -> Action {
-        Action::Noop
+impl std::fmt::Display for OpenConnectApplicationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Action {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Action::Noop)
+
+impl std::str::FromStr for OpenConnectApplicationType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "browser" => OpenConnectApplicationType::Browser,
+            "native" => OpenConnectApplicationType::Native,
+            "service" => OpenConnectApplicationType::Service,
+            "web" => OpenConnectApplicationType::Web,
+            _ => OpenConnectApplicationType::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PolicyAccountLink {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub action: Option<Action>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub filter: Option<PolicyAccountLinkFilter>,
+impl std::convert::From<String> for OpenConnectApplicationType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PolicyAccountLinkFilter {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub groups: Option<ClientPolicyCondition>,
+impl std::convert::From<OpenConnectApplicationType> for String {
+    fn from(v: OpenConnectApplicationType) -> Self {
+        match v {
+            OpenConnectApplicationType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Connection {
-    #[serde(rename = "ANYWHERE")]
-    Anywhere,
-    #[serde(rename = "ZONE")]
-    Zone,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum OpenConnectRefreshTokenRotationType {
+    #[serde(rename = "rotate")]
+    Rotate,
+    #[serde(rename = "static")]
+    Static,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Connection {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Connection::Anywhere => "ANYWHERE",
-            Connection::Zone => "ZONE",
-            Connection::Noop => "",
-            Connection::FallthroughString => "*",
+impl AsRef<str> for OpenConnectRefreshTokenRotationType {
+    fn as_ref(&self) -> &str {
+        match self {
+            OpenConnectRefreshTokenRotationType::Rotate => "rotate",
+            OpenConnectRefreshTokenRotationType::Static => "static",
+            OpenConnectRefreshTokenRotationType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Connection {
-    fn default() -> Connection {
-        Connection::Noop
+impl std::fmt::Display for OpenConnectRefreshTokenRotationType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OpenConnectRefreshTokenRotationType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "rotate" => OpenConnectRefreshTokenRotationType::Rotate,
+            "static" => OpenConnectRefreshTokenRotationType::Static,
+            _ => OpenConnectRefreshTokenRotationType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OpenConnectRefreshTokenRotationType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Connection {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Connection::Noop)
+
+impl std::convert::From<OpenConnectRefreshTokenRotationType> for String {
+    fn from(v: OpenConnectRefreshTokenRotationType) -> Self {
+        match v {
+            OpenConnectRefreshTokenRotationType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PolicyNetworkCondition {
+pub struct PasswordCredential {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub connection: Option<Connection>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub exclude: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub include: Vec<String>,
+    pub hash: Option<PasswordCredentialHash>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hook: Option<PasswordCredentialHook>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PolicyPeopleCondition {
+pub struct PasswordCredentialHash {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<PasswordCredentialHashAlgorithm>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub groups: Option<UserCondition>,
+    pub salt: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "saltOrder")]
+    pub salt_order: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub users: Option<UserCondition>,
+    pub value: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "workFactor"
+    )]
+    pub work_factor: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PolicyRuleType {
-    #[serde(rename = "PASSWORD")]
-    Password,
-    #[serde(rename = "SIGN_ON")]
-    SignOn,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum PasswordCredentialHashAlgorithm {
+    #[serde(rename = "BCRYPT")]
+    Bcrypt,
+    #[serde(rename = "MD5")]
+    Md5,
+    #[serde(rename = "SHA-1")]
+    ShAminusOne,
+    #[serde(rename = "SHA-256")]
+    Sha256,
+    #[serde(rename = "SHA-512")]
+    Sha512,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PolicyRuleType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PolicyRuleType::Password => "PASSWORD",
-            PolicyRuleType::SignOn => "SIGN_ON",
-            PolicyRuleType::Noop => "",
-            PolicyRuleType::FallthroughString => "*",
+impl AsRef<str> for PasswordCredentialHashAlgorithm {
+    fn as_ref(&self) -> &str {
+        match self {
+            PasswordCredentialHashAlgorithm::Bcrypt => "BCRYPT",
+            PasswordCredentialHashAlgorithm::Md5 => "MD5",
+            PasswordCredentialHashAlgorithm::ShAminusOne => "SHA-1",
+            PasswordCredentialHashAlgorithm::Sha256 => "SHA-256",
+            PasswordCredentialHashAlgorithm::Sha512 => "SHA-512",
+            PasswordCredentialHashAlgorithm::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PolicyRuleType {
-    fn default() -> PolicyRuleType {
-        PolicyRuleType::Noop
+impl std::fmt::Display for PasswordCredentialHashAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl PolicyRuleType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PolicyRuleType::Noop)
+
+impl std::str::FromStr for PasswordCredentialHashAlgorithm {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BCRYPT" => PasswordCredentialHashAlgorithm::Bcrypt,
+            "MD5" => PasswordCredentialHashAlgorithm::Md5,
+            "SHA-1" => PasswordCredentialHashAlgorithm::ShAminusOne,
+            "SHA-256" => PasswordCredentialHashAlgorithm::Sha256,
+            "SHA-512" => PasswordCredentialHashAlgorithm::Sha512,
+            _ => PasswordCredentialHashAlgorithm::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PolicyRule {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub actions: Option<PolicyRuleActions>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub conditions: Option<PolicyRuleConditions>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub priority: i64,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub system: bool,
+impl std::convert::From<String> for PasswordCredentialHashAlgorithm {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PasswordCredentialHashAlgorithm> for String {
+    fn from(v: PasswordCredentialHashAlgorithm) -> Self {
+        match v {
+            PasswordCredentialHashAlgorithm::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordCredentialHook {
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<PolicyRuleType>,
+    pub type_: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PolicyRuleActions {
+pub struct PasswordDictionary {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub enroll: Option<PolicyRuleActionsEnroll>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "passwordChange"
-    )]
-    pub password_change: Option<PasswordPolicyRuleAction>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "selfServicePasswordReset"
-    )]
-    pub self_service_password_reset: Option<PasswordPolicyRuleAction>,
+    pub common: Option<PasswordDictionaryCommon>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordDictionaryCommon {
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "selfServiceUnlock"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub self_service_unlock: Option<PasswordPolicyRuleAction>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub signon: Option<OktaSignOnPolicyRuleSignonActions>,
+    pub exclude: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PolicyRuleActionsEnroll {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "self")]
-    pub self_: Option<PolicyRuleActionsEnrollSelf>,
+pub struct PasswordPolicy {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<PasswordPolicyConditions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<PasswordPolicySettingsData>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PolicyRuleActionsEnrollSelf {
-    #[serde(rename = "CHALLENGE")]
-    Challenge,
-    #[serde(rename = "LOGIN")]
-    Login,
-    #[serde(rename = "NEVER")]
-    Never,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum PasswordPolicyAuthenticationProviderCondition {
+    #[serde(rename = "ACTIVE_DIRECTORY")]
+    ActiveDirectory,
+    #[serde(rename = "ANY")]
+    Any,
+    #[serde(rename = "LDAP")]
+    Ldap,
+    #[serde(rename = "OKTA")]
+    Okta,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PolicyRuleActionsEnrollSelf {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PolicyRuleActionsEnrollSelf::Challenge => "CHALLENGE",
-            PolicyRuleActionsEnrollSelf::Login => "LOGIN",
-            PolicyRuleActionsEnrollSelf::Never => "NEVER",
-            PolicyRuleActionsEnrollSelf::Noop => "",
-            PolicyRuleActionsEnrollSelf::FallthroughString => "*",
+impl AsRef<str> for PasswordPolicyAuthenticationProviderCondition {
+    fn as_ref(&self) -> &str {
+        match self {
+            PasswordPolicyAuthenticationProviderCondition::ActiveDirectory => "ACTIVE_DIRECTORY",
+            PasswordPolicyAuthenticationProviderCondition::Any => "ANY",
+            PasswordPolicyAuthenticationProviderCondition::Ldap => "LDAP",
+            PasswordPolicyAuthenticationProviderCondition::Okta => "OKTA",
+            PasswordPolicyAuthenticationProviderCondition::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PolicyRuleActionsEnrollSelf {
-    fn default() -> PolicyRuleActionsEnrollSelf {
-        PolicyRuleActionsEnrollSelf::Noop
-    }
-}
-impl PolicyRuleActionsEnrollSelf {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PolicyRuleActionsEnrollSelf::Noop)
+impl std::fmt::Display for PasswordPolicyAuthenticationProviderCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum AuthType {
-    #[serde(rename = "ANY")]
-    Any,
-    #[serde(rename = "RADIUS")]
-    Radius,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
+impl std::str::FromStr for PasswordPolicyAuthenticationProviderCondition {
+    type Err = std::convert::Infallible;
 
-impl std::fmt::Display for AuthType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            AuthType::Any => "ANY",
-            AuthType::Radius => "RADIUS",
-            AuthType::Noop => "",
-            AuthType::FallthroughString => "*",
-        }
-        .fmt(f)
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE_DIRECTORY" => PasswordPolicyAuthenticationProviderCondition::ActiveDirectory,
+            "ANY" => PasswordPolicyAuthenticationProviderCondition::Any,
+            "LDAP" => PasswordPolicyAuthenticationProviderCondition::Ldap,
+            "OKTA" => PasswordPolicyAuthenticationProviderCondition::Okta,
+            _ => PasswordPolicyAuthenticationProviderCondition::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for AuthType {
-    fn default() -> AuthType {
-        AuthType::Noop
-    }
-}
-impl AuthType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AuthType::Noop)
+impl std::convert::From<String> for PasswordPolicyAuthenticationProviderCondition {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PolicyRuleAuthContextCondition {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "authType")]
-    pub auth_type: Option<AuthType>,
+impl std::convert::From<PasswordPolicyAuthenticationProviderCondition> for String {
+    fn from(v: PasswordPolicyAuthenticationProviderCondition) -> Self {
+        match v {
+            PasswordPolicyAuthenticationProviderCondition::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PolicyRuleConditions {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub app: Option<AppInstancePolicyRuleCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub apps: Option<UserCondition>,
+pub struct PasswordPolicyAuthenticationProviderConditionData {
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "authContext"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub auth_context: Option<PolicyRuleAuthContextCondition>,
+    pub include: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<PasswordPolicyAuthenticationProviderCondition>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordPolicyConditions {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         rename = "authProvider"
     )]
     pub auth_provider: Option<PasswordPolicyAuthenticationProviderConditionData>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "beforeScheduledAction"
-    )]
-    pub before_scheduled_action: Option<BeforeScheduledActionPolicyRuleCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub clients: Option<ClientPolicyCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub context: Option<ContextPolicyRuleCondition>,
+    pub people: Option<PolicyPeopleCondition>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordPolicyDelegationSettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub device: Option<DevicePolicyRuleCondition>,
+    pub options: Option<PasswordPolicyDelegationSettingsOptions>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordPolicyDelegationSettingsOptions {
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "grantTypes"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "skipUnlock"
     )]
-    pub grant_types: Option<ClientPolicyCondition>,
+    pub skip_unlock: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordPolicySettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age: Option<PasswordPolicySettingsAge>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complexity: Option<PasswordPolicySettingsComplexity>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub groups: Option<UserCondition>,
+    pub lockout: Option<PasswordPolicySettingsLockout>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordPolicySettingsAge {
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "identityProvider"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "expireWarnDays"
     )]
-    pub identity_provider: Option<IdentityProviderPolicyRuleCondition>,
+    pub expire_warn_days: i64,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "mdmEnrollment"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "historyCount"
     )]
-    pub mdm_enrollment: Option<MdmEnrollmentPolicyRuleCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub network: Option<PolicyNetworkCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub people: Option<PolicyPeopleCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub platform: Option<PlatformPolicyRuleCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub risk: Option<RiskPolicyRuleCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "riskScore")]
-    pub risk_score: Option<RiskScorePolicyRuleCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub scopes: Option<OAuth2ScopesMediationPolicyRuleCondition>,
+    pub history_count: i64,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "userIdentifier"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "maxAgeDays"
     )]
-    pub user_identifier: Option<UserIdentifierPolicyRuleCondition>,
+    pub max_age_days: i64,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "userStatus"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "minAgeMinutes"
     )]
-    pub user_status: Option<UserStatusPolicyRuleCondition>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub users: Option<UserPolicyRuleCondition>,
+    pub min_age_minutes: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PolicySubject {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub filter: String,
+pub struct PasswordPolicySettingsComplexity {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dictionary: Option<PasswordDictionary>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "excludeAttributes"
     )]
-    pub format: Vec<String>,
+    pub exclude_attributes: Vec<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "matchAttribute"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "excludeUsername"
     )]
-    pub match_attribute: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "matchType")]
-    pub match_type: Option<PolicySubjectMatchType>,
+    pub exclude_username: bool,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "userNameTemplate"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "minLength"
     )]
-    pub user_name_template: Option<PolicyUserNameTemplate>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PolicySubjectMatchType {
-    #[serde(rename = "CUSTOM_ATTRIBUTE")]
-    CustomAttribute,
-    #[serde(rename = "EMAIL")]
-    Email,
-    #[serde(rename = "USERNAME")]
-    Username,
-    #[serde(rename = "USERNAME_OR_EMAIL")]
-    UsernameOrEmail,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for PolicySubjectMatchType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PolicySubjectMatchType::CustomAttribute => "CUSTOM_ATTRIBUTE",
-            PolicySubjectMatchType::Email => "EMAIL",
-            PolicySubjectMatchType::Username => "USERNAME",
-            PolicySubjectMatchType::UsernameOrEmail => "USERNAME_OR_EMAIL",
-            PolicySubjectMatchType::Noop => "",
-            PolicySubjectMatchType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for PolicySubjectMatchType {
-    fn default() -> PolicySubjectMatchType {
-        PolicySubjectMatchType::Noop
-    }
-}
-impl PolicySubjectMatchType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PolicySubjectMatchType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PolicyType {
-    #[serde(rename = "IDP_DISCOVERY")]
-    IdpDiscovery,
-    #[serde(rename = "OAUTH_AUTHORIZATION_POLICY")]
-    OauthAuthorizationPolicy,
-    #[serde(rename = "OKTA_SIGN_ON")]
-    OktaSignOn,
-    #[serde(rename = "PASSWORD")]
-    Password,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for PolicyType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PolicyType::IdpDiscovery => "IDP_DISCOVERY",
-            PolicyType::OauthAuthorizationPolicy => "OAUTH_AUTHORIZATION_POLICY",
-            PolicyType::OktaSignOn => "OKTA_SIGN_ON",
-            PolicyType::Password => "PASSWORD",
-            PolicyType::Noop => "",
-            PolicyType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for PolicyType {
-    fn default() -> PolicyType {
-        PolicyType::Noop
-    }
-}
-impl PolicyType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PolicyType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PolicyUserNameTemplate {
+    pub min_length: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "minLowerCase"
     )]
-    pub template: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProfileMapping {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+    pub min_lower_case: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "minNumber"
     )]
-    pub id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<ProfileMappingProperty>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub source: Option<ProfileMappingSource>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub target: Option<ProfileMappingSource>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProfileMappingProperty {
+    pub min_number: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "minSymbol"
     )]
-    pub expression: String,
+    pub min_symbol: i64,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "pushStatus"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "minUpperCase"
     )]
-    pub push_status: Option<Links>,
+    pub min_upper_case: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProfileMappingSource {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
+pub struct PasswordPolicySettingsLockout {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "autoUnlockMinutes"
     )]
-    pub id: String,
+    pub auto_unlock_minutes: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "maxAttempts"
+    )]
+    pub max_attempts: i64,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "showLockoutFailures"
     )]
-    pub name: String,
+    pub show_lockout_failures: bool,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "userLockoutNotificationChannels"
     )]
-    pub type_: String,
+    pub user_lockout_notification_channels: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ProtocolType {
-    #[serde(rename = "MTLS")]
-    Mtls,
-    #[serde(rename = "OAUTH2")]
-    Oauth2,
-    #[serde(rename = "OIDC")]
-    Oidc,
-    #[serde(rename = "SAML2")]
-    Saml2,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum PasswordPolicyRecoveryEmailStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PasswordPolicyRecoveryEmailStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            PasswordPolicyRecoveryEmailStatus::Active => "ACTIVE",
+            PasswordPolicyRecoveryEmailStatus::Inactive => "INACTIVE",
+            PasswordPolicyRecoveryEmailStatus::Unknown(s) => s,
+        }
+    }
 }
 
-impl std::fmt::Display for ProtocolType {
+impl std::fmt::Display for PasswordPolicyRecoveryEmailStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ProtocolType::Mtls => "MTLS",
-            ProtocolType::Oauth2 => "OAUTH2",
-            ProtocolType::Oidc => "OIDC",
-            ProtocolType::Saml2 => "SAML2",
-            ProtocolType::Noop => "",
-            ProtocolType::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
     }
 }
 
-impl Default for ProtocolType {
-    fn default() -> ProtocolType {
-        ProtocolType::Noop
+impl std::str::FromStr for PasswordPolicyRecoveryEmailStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => PasswordPolicyRecoveryEmailStatus::Active,
+            "INACTIVE" => PasswordPolicyRecoveryEmailStatus::Inactive,
+            _ => PasswordPolicyRecoveryEmailStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PasswordPolicyRecoveryEmailStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ProtocolType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ProtocolType::Noop)
+
+impl std::convert::From<PasswordPolicyRecoveryEmailStatus> for String {
+    fn from(v: PasswordPolicyRecoveryEmailStatus) -> Self {
+        match v {
+            PasswordPolicyRecoveryEmailStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Protocol {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub algorithms: Option<ProtocolAlgorithms>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub credentials: Option<IdentityProviderCredentials>,
+pub struct PasswordPolicyRecoveryEmail {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub endpoints: Option<ProtocolEndpoints>,
+    pub properties: Option<PasswordPolicyRecoveryEmailProperties>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub issuer: Option<ProtocolEndpoint>,
+    pub status: Option<PasswordPolicyRecoveryEmailStatus>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordPolicyRecoveryEmailProperties {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        rename = "relayState"
+        rename = "recoveryToken"
     )]
-    pub relay_state: Option<ProtocolRelayState>,
+    pub recovery_token: Option<PasswordPolicyRecoveryEmailToken>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordPolicyRecoveryEmailToken {
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "tokenLifetimeMinutes"
     )]
-    pub scopes: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub settings: Option<ProtocolSettings>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<ProtocolType>,
+    pub token_lifetime_minutes: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProtocolAlgorithmType {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub signature: Option<ProtocolAlgorithmTypeSignature>,
+#[serde(from = "String", into = "String")]
+pub enum PasswordPolicyRecoveryFactorSettingsStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PasswordPolicyRecoveryFactorSettingsStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            PasswordPolicyRecoveryFactorSettingsStatus::Active => "ACTIVE",
+            PasswordPolicyRecoveryFactorSettingsStatus::Inactive => "INACTIVE",
+            PasswordPolicyRecoveryFactorSettingsStatus::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Scope {
-    #[serde(rename = "ANY")]
-    Any,
-    #[serde(rename = "NONE")]
-    None,
-    #[serde(rename = "REQUEST")]
-    Request,
-    #[serde(rename = "RESPONSE")]
-    Response,
-    #[serde(rename = "TOKEN")]
-    Token,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+impl std::fmt::Display for PasswordPolicyRecoveryFactorSettingsStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-impl std::fmt::Display for Scope {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Scope::Any => "ANY",
-            Scope::None => "NONE",
-            Scope::Request => "REQUEST",
-            Scope::Response => "RESPONSE",
-            Scope::Token => "TOKEN",
-            Scope::Noop => "",
-            Scope::FallthroughString => "*",
+impl std::str::FromStr for PasswordPolicyRecoveryFactorSettingsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => PasswordPolicyRecoveryFactorSettingsStatus::Active,
+            "INACTIVE" => PasswordPolicyRecoveryFactorSettingsStatus::Inactive,
+            _ => PasswordPolicyRecoveryFactorSettingsStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PasswordPolicyRecoveryFactorSettingsStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
         }
-        .fmt(f)
     }
 }
 
-impl Default for Scope {
-    fn default() -> Scope {
-        Scope::Noop
+impl std::convert::From<PasswordPolicyRecoveryFactorSettingsStatus> for String {
+    fn from(v: PasswordPolicyRecoveryFactorSettingsStatus) -> Self {
+        match v {
+            PasswordPolicyRecoveryFactorSettingsStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
-impl Scope {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Scope::Noop)
+
+impl Default for PasswordPolicyRecoveryFactorSettingsStatus {
+    fn default() -> PasswordPolicyRecoveryFactorSettingsStatus {
+        PasswordPolicyRecoveryFactorSettingsStatus::Inactive
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProtocolAlgorithmTypeSignature {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub algorithm: String,
+pub struct PasswordPolicyRecoveryFactorSettings {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub scope: Option<Scope>,
+    pub status: Option<PasswordPolicyRecoveryFactorSettingsStatus>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProtocolAlgorithms {
+pub struct PasswordPolicyRecoveryFactors {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub okta_call: Option<PasswordPolicyRecoveryFactorSettings>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub okta_email: Option<PasswordPolicyRecoveryEmail>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub request: Option<ProtocolAlgorithmType>,
+    pub okta_sms: Option<PasswordPolicyRecoveryFactorSettings>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub response: Option<ProtocolAlgorithmType>,
+    pub recovery_question: Option<PasswordPolicyRecoveryQuestion>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Binding {
-    #[serde(rename = "HTTP-POST")]
-    HttpPost,
-    #[serde(rename = "HTTP-REDIRECT")]
-    HttpRedirect,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum PasswordPolicyRecoveryQuestionStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PasswordPolicyRecoveryQuestionStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            PasswordPolicyRecoveryQuestionStatus::Active => "ACTIVE",
+            PasswordPolicyRecoveryQuestionStatus::Inactive => "INACTIVE",
+            PasswordPolicyRecoveryQuestionStatus::Unknown(s) => s,
+        }
+    }
 }
 
-impl std::fmt::Display for Binding {
+impl std::fmt::Display for PasswordPolicyRecoveryQuestionStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Binding::HttpPost => "HTTP-POST",
-            Binding::HttpRedirect => "HTTP-REDIRECT",
-            Binding::Noop => "",
-            Binding::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PasswordPolicyRecoveryQuestionStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => PasswordPolicyRecoveryQuestionStatus::Active,
+            "INACTIVE" => PasswordPolicyRecoveryQuestionStatus::Inactive,
+            _ => PasswordPolicyRecoveryQuestionStatus::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for Binding {
-    fn default() -> Binding {
-        Binding::Noop
+impl std::convert::From<String> for PasswordPolicyRecoveryQuestionStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Binding {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Binding::Noop)
+
+impl std::convert::From<PasswordPolicyRecoveryQuestionStatus> for String {
+    fn from(v: PasswordPolicyRecoveryQuestionStatus) -> Self {
+        match v {
+            PasswordPolicyRecoveryQuestionStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ProtocolEndpointType {
-    #[serde(rename = "INSTANCE")]
-    Instance,
-    #[serde(rename = "ORG")]
-    Org,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+pub struct PasswordPolicyRecoveryQuestion {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<PasswordPolicyRecoveryQuestionProperties>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<PasswordPolicyRecoveryQuestionStatus>,
 }
 
-impl std::fmt::Display for ProtocolEndpointType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ProtocolEndpointType::Instance => "INSTANCE",
-            ProtocolEndpointType::Org => "ORG",
-            ProtocolEndpointType::Noop => "",
-            ProtocolEndpointType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordPolicyRecoveryQuestionComplexity {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "minLength"
+    )]
+    pub min_length: i64,
 }
 
-impl Default for ProtocolEndpointType {
-    fn default() -> ProtocolEndpointType {
-        ProtocolEndpointType::Noop
-    }
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordPolicyRecoveryQuestionProperties {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complexity: Option<PasswordPolicyRecoveryQuestionComplexity>,
 }
-impl ProtocolEndpointType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ProtocolEndpointType::Noop)
-    }
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordPolicyRecoverySettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub factors: Option<PasswordPolicyRecoveryFactors>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProtocolEndpoint {
+pub struct PasswordPolicyRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<PasswordPolicyRuleActions>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub binding: Option<Binding>,
+    pub conditions: Option<PasswordPolicyRuleConditions>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub destination: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<ProtocolEndpointType>,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordPolicyRuleAction {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub access: Option<Access>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordPolicyRuleActions {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "passwordChange"
     )]
-    pub url: String,
+    pub password_change: Option<PasswordPolicyRuleAction>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "selfServicePasswordReset"
+    )]
+    pub self_service_password_reset: Option<PasswordPolicyRuleAction>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "selfServiceUnlock"
+    )]
+    pub self_service_unlock: Option<PasswordPolicyRuleAction>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProtocolEndpoints {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub acs: Option<ProtocolEndpoint>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub authorization: Option<ProtocolEndpoint>,
+pub struct PasswordPolicyRuleConditions {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub jwks: Option<ProtocolEndpoint>,
+    pub network: Option<PolicyNetworkCondition>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<ProtocolEndpoint>,
+    pub people: Option<PolicyPeopleCondition>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordPolicySettingsData {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub slo: Option<ProtocolEndpoint>,
+    pub delegation: Option<PasswordPolicyDelegationSettings>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub sso: Option<ProtocolEndpoint>,
+    pub password: Option<PasswordPolicySettings>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub token: Option<ProtocolEndpoint>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userInfo")]
-    pub user_info: Option<ProtocolEndpoint>,
+    pub recovery: Option<PasswordPolicyRecoverySettings>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProtocolRelayState {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub format: Option<ProtocolRelayStateFormat>,
+#[serde(from = "String", into = "String")]
+pub enum PlatformConditionEvaluatorType {
+    #[serde(rename = "ANY")]
+    Any,
+    #[serde(rename = "DESKTOP")]
+    Desktop,
+    #[serde(rename = "MOBILE")]
+    Mobile,
+    #[serde(rename = "OTHER")]
+    Other,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ProtocolRelayStateFormat {
-    #[serde(rename = "FROM_URL")]
-    FromUrl,
-    #[serde(rename = "OPAQUE")]
-    Opaque,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+impl AsRef<str> for PlatformConditionEvaluatorType {
+    fn as_ref(&self) -> &str {
+        match self {
+            PlatformConditionEvaluatorType::Any => "ANY",
+            PlatformConditionEvaluatorType::Desktop => "DESKTOP",
+            PlatformConditionEvaluatorType::Mobile => "MOBILE",
+            PlatformConditionEvaluatorType::Other => "OTHER",
+            PlatformConditionEvaluatorType::Unknown(s) => s,
+        }
+    }
 }
 
-impl std::fmt::Display for ProtocolRelayStateFormat {
+impl std::fmt::Display for PlatformConditionEvaluatorType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ProtocolRelayStateFormat::FromUrl => "FROM_URL",
-            ProtocolRelayStateFormat::Opaque => "OPAQUE",
-            ProtocolRelayStateFormat::Noop => "",
-            ProtocolRelayStateFormat::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
     }
 }
 
-impl Default for ProtocolRelayStateFormat {
-    fn default() -> ProtocolRelayStateFormat {
-        ProtocolRelayStateFormat::Noop
+impl std::str::FromStr for PlatformConditionEvaluatorType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANY" => PlatformConditionEvaluatorType::Any,
+            "DESKTOP" => PlatformConditionEvaluatorType::Desktop,
+            "MOBILE" => PlatformConditionEvaluatorType::Mobile,
+            "OTHER" => PlatformConditionEvaluatorType::Other,
+            _ => PlatformConditionEvaluatorType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PlatformConditionEvaluatorType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ProtocolRelayStateFormat {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ProtocolRelayStateFormat::Noop)
+
+impl std::convert::From<PlatformConditionEvaluatorType> for String {
+    fn from(v: PlatformConditionEvaluatorType) -> Self {
+        match v {
+            PlatformConditionEvaluatorType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProtocolSettings {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nameFormat"
-    )]
-    pub name_format: String,
+pub struct PlatformConditionEvaluator {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub os: Option<PlatformConditionEvaluatorOperatingSystem>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<PlatformConditionEvaluatorType>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ProvisioningAction {
-    #[serde(rename = "AUTO")]
-    Auto,
-    #[serde(rename = "CALLOUT")]
-    Callout,
-    #[serde(rename = "DISABLED")]
-    Disabled,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum PlatformConditionEvaluatorOperatingSystemType {
+    #[serde(rename = "ANDROID")]
+    Android,
+    #[serde(rename = "ANY")]
+    Any,
+    #[serde(rename = "IOS")]
+    Ios,
+    #[serde(rename = "OSX")]
+    Osx,
+    #[serde(rename = "OTHER")]
+    Other,
+    #[serde(rename = "WINDOWS")]
+    Windows,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ProvisioningAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ProvisioningAction::Auto => "AUTO",
-            ProvisioningAction::Callout => "CALLOUT",
-            ProvisioningAction::Disabled => "DISABLED",
-            ProvisioningAction::Noop => "",
-            ProvisioningAction::FallthroughString => "*",
+impl AsRef<str> for PlatformConditionEvaluatorOperatingSystemType {
+    fn as_ref(&self) -> &str {
+        match self {
+            PlatformConditionEvaluatorOperatingSystemType::Android => "ANDROID",
+            PlatformConditionEvaluatorOperatingSystemType::Any => "ANY",
+            PlatformConditionEvaluatorOperatingSystemType::Ios => "IOS",
+            PlatformConditionEvaluatorOperatingSystemType::Osx => "OSX",
+            PlatformConditionEvaluatorOperatingSystemType::Other => "OTHER",
+            PlatformConditionEvaluatorOperatingSystemType::Windows => "WINDOWS",
+            PlatformConditionEvaluatorOperatingSystemType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ProvisioningAction {
-    fn default() -> ProvisioningAction {
-        ProvisioningAction::Noop
+impl std::fmt::Display for PlatformConditionEvaluatorOperatingSystemType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl ProvisioningAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ProvisioningAction::Noop)
+
+impl std::str::FromStr for PlatformConditionEvaluatorOperatingSystemType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANDROID" => PlatformConditionEvaluatorOperatingSystemType::Android,
+            "ANY" => PlatformConditionEvaluatorOperatingSystemType::Any,
+            "IOS" => PlatformConditionEvaluatorOperatingSystemType::Ios,
+            "OSX" => PlatformConditionEvaluatorOperatingSystemType::Osx,
+            "OTHER" => PlatformConditionEvaluatorOperatingSystemType::Other,
+            "WINDOWS" => PlatformConditionEvaluatorOperatingSystemType::Windows,
+            _ => PlatformConditionEvaluatorOperatingSystemType::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Provisioning {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub action: Option<ProvisioningAction>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub conditions: Option<ProvisioningConditions>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub groups: Option<ProvisioningGroups>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "profileMaster"
-    )]
-    pub profile_master: bool,
+impl std::convert::From<String> for PlatformConditionEvaluatorOperatingSystemType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PlatformConditionEvaluatorOperatingSystemType> for String {
+    fn from(v: PlatformConditionEvaluatorOperatingSystemType) -> Self {
+        match v {
+            PlatformConditionEvaluatorOperatingSystemType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProvisioningConditions {
+pub struct PlatformConditionEvaluatorOperatingSystem {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deprovisioned: Option<ProvisioningDeprovisionedCondition>,
+    pub expression: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<PlatformConditionEvaluatorOperatingSystemType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub suspended: Option<ProvisioningSuspendedCondition>,
+    pub version: Option<PlatformConditionEvaluatorOperatingSystemVersion>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ProvisioningDeprovisionedConditionAction {
-    #[serde(rename = "NONE")]
-    None,
-    #[serde(rename = "REACTIVATE")]
-    Reactivate,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum MatchType {
+    #[serde(rename = "EXPRESSION")]
+    Expression,
+    #[serde(rename = "SEMVER")]
+    Semver,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ProvisioningDeprovisionedConditionAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ProvisioningDeprovisionedConditionAction::None => "NONE",
-            ProvisioningDeprovisionedConditionAction::Reactivate => "REACTIVATE",
-            ProvisioningDeprovisionedConditionAction::Noop => "",
-            ProvisioningDeprovisionedConditionAction::FallthroughString => "*",
+impl AsRef<str> for MatchType {
+    fn as_ref(&self) -> &str {
+        match self {
+            MatchType::Expression => "EXPRESSION",
+            MatchType::Semver => "SEMVER",
+            MatchType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ProvisioningDeprovisionedConditionAction {
-    fn default() -> ProvisioningDeprovisionedConditionAction {
-        ProvisioningDeprovisionedConditionAction::Noop
-    }
-}
-impl ProvisioningDeprovisionedConditionAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ProvisioningDeprovisionedConditionAction::Noop)
+impl std::fmt::Display for MatchType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProvisioningDeprovisionedCondition {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub action: Option<ProvisioningDeprovisionedConditionAction>,
-}
+impl std::str::FromStr for MatchType {
+    type Err = std::convert::Infallible;
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ProvisioningGroupsAction {
-    #[serde(rename = "APPEND")]
-    Append,
-    #[serde(rename = "ASSIGN")]
-    Assign,
-    #[serde(rename = "NONE")]
-    None,
-    #[serde(rename = "SYNC")]
-    Sync,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EXPRESSION" => MatchType::Expression,
+            "SEMVER" => MatchType::Semver,
+            _ => MatchType::Unknown(s.to_string()),
+        })
+    }
 }
 
-impl std::fmt::Display for ProvisioningGroupsAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ProvisioningGroupsAction::Append => "APPEND",
-            ProvisioningGroupsAction::Assign => "ASSIGN",
-            ProvisioningGroupsAction::None => "NONE",
-            ProvisioningGroupsAction::Sync => "SYNC",
-            ProvisioningGroupsAction::Noop => "",
-            ProvisioningGroupsAction::FallthroughString => "*",
+impl std::convert::From<String> for MatchType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
         }
-        .fmt(f)
     }
 }
 
-impl Default for ProvisioningGroupsAction {
-    fn default() -> ProvisioningGroupsAction {
-        ProvisioningGroupsAction::Noop
-    }
-}
-impl ProvisioningGroupsAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ProvisioningGroupsAction::Noop)
+impl std::convert::From<MatchType> for String {
+    fn from(v: MatchType) -> Self {
+        match v {
+            MatchType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProvisioningGroups {
+pub struct PlatformConditionEvaluatorOperatingSystemVersion {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "matchType")]
+    pub match_type: Option<MatchType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub action: Option<ProvisioningGroupsAction>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub assignments: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub filter: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "sourceAttributeName"
-    )]
-    pub source_attribute_name: String,
+    pub value: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ProvisioningSuspendedConditionAction {
-    #[serde(rename = "NONE")]
-    None,
-    #[serde(rename = "UNSUSPEND")]
-    Unsuspend,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for ProvisioningSuspendedConditionAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ProvisioningSuspendedConditionAction::None => "NONE",
-            ProvisioningSuspendedConditionAction::Unsuspend => "UNSUSPEND",
-            ProvisioningSuspendedConditionAction::Noop => "",
-            ProvisioningSuspendedConditionAction::FallthroughString => "*",
+#[serde(from = "String", into = "String")]
+pub enum PolicyStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PolicyStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            PolicyStatus::Active => "ACTIVE",
+            PolicyStatus::Inactive => "INACTIVE",
+            PolicyStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ProvisioningSuspendedConditionAction {
-    fn default() -> ProvisioningSuspendedConditionAction {
-        ProvisioningSuspendedConditionAction::Noop
-    }
-}
-impl ProvisioningSuspendedConditionAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ProvisioningSuspendedConditionAction::Noop)
+impl std::fmt::Display for PolicyStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProvisioningSuspendedCondition {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub action: Option<ProvisioningSuspendedConditionAction>,
-}
+impl std::str::FromStr for PolicyStatus {
+    type Err = std::convert::Infallible;
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PushUserFactor {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "expiresAt"
-    )]
-    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "factorResult"
-    )]
-    pub factor_result: Option<FactorResultType>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<PushUserFactorProfile>,
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => PolicyStatus::Active,
+            "INACTIVE" => PolicyStatus::Inactive,
+            _ => PolicyStatus::Unknown(s.to_string()),
+        })
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PushUserFactorProfile {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "credentialId"
-    )]
-    pub credential_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "deviceToken"
-    )]
-    pub device_token: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "deviceType"
-    )]
-    pub device_type: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub platform: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version: String,
+impl std::convert::From<String> for PolicyStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct RecoveryQuestionCredential {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub answer: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub question: String,
+impl std::convert::From<PolicyStatus> for String {
+    fn from(v: PolicyStatus) -> Self {
+        match v {
+            PolicyStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct RiskPolicyRuleCondition {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub behaviors: Vec<String>,
+#[serde(from = "String", into = "String")]
+pub enum Action {
+    #[serde(rename = "AUTO")]
+    Auto,
+    #[serde(rename = "DISABLED")]
+    Disabled,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct RiskScorePolicyRuleCondition {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub level: String,
+impl AsRef<str> for Action {
+    fn as_ref(&self) -> &str {
+        match self {
+            Action::Auto => "AUTO",
+            Action::Disabled => "DISABLED",
+            Action::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Role {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "assignmentType"
-    )]
-    pub assignment_type: Option<RoleAssignmentType>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub label: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<RoleStatus>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<RoleType>,
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum RoleAssignmentType {
-    #[serde(rename = "GROUP")]
-    Group,
-    #[serde(rename = "USER")]
-    User,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
+impl std::str::FromStr for Action {
+    type Err = std::convert::Infallible;
 
-impl std::fmt::Display for RoleAssignmentType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            RoleAssignmentType::Group => "GROUP",
-            RoleAssignmentType::User => "USER",
-            RoleAssignmentType::Noop => "",
-            RoleAssignmentType::FallthroughString => "*",
-        }
-        .fmt(f)
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AUTO" => Action::Auto,
+            "DISABLED" => Action::Disabled,
+            _ => Action::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for RoleAssignmentType {
-    fn default() -> RoleAssignmentType {
-        RoleAssignmentType::Noop
+impl std::convert::From<String> for Action {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl RoleAssignmentType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RoleAssignmentType::Noop)
+
+impl std::convert::From<Action> for String {
+    fn from(v: Action) -> Self {
+        match v {
+            Action::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum RoleType {
-    #[serde(rename = "API_ACCESS_MANAGEMENT_ADMIN")]
-    ApiAccessManagementAdmin,
-    #[serde(rename = "APP_ADMIN")]
-    AppAdmin,
-    #[serde(rename = "HELP_DESK_ADMIN")]
-    HelpDeskAdmin,
-    #[serde(rename = "MOBILE_ADMIN")]
-    MobileAdmin,
-    #[serde(rename = "ORG_ADMIN")]
-    OrgAdmin,
-    #[serde(rename = "READ_ONLY_ADMIN")]
-    ReadOnlyAdmin,
-    #[serde(rename = "REPORT_ADMIN")]
-    ReportAdmin,
-    #[serde(rename = "SUPER_ADMIN")]
-    SuperAdmin,
-    #[serde(rename = "USER_ADMIN")]
-    UserAdmin,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum Connection {
+    #[serde(rename = "ANYWHERE")]
+    Anywhere,
+    #[serde(rename = "ZONE")]
+    Zone,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for RoleType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            RoleType::ApiAccessManagementAdmin => "API_ACCESS_MANAGEMENT_ADMIN",
-            RoleType::AppAdmin => "APP_ADMIN",
-            RoleType::HelpDeskAdmin => "HELP_DESK_ADMIN",
-            RoleType::MobileAdmin => "MOBILE_ADMIN",
-            RoleType::OrgAdmin => "ORG_ADMIN",
-            RoleType::ReadOnlyAdmin => "READ_ONLY_ADMIN",
-            RoleType::ReportAdmin => "REPORT_ADMIN",
-            RoleType::SuperAdmin => "SUPER_ADMIN",
-            RoleType::UserAdmin => "USER_ADMIN",
-            RoleType::Noop => "",
-            RoleType::FallthroughString => "*",
+impl AsRef<str> for Connection {
+    fn as_ref(&self) -> &str {
+        match self {
+            Connection::Anywhere => "ANYWHERE",
+            Connection::Zone => "ZONE",
+            Connection::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for RoleType {
-    fn default() -> RoleType {
-        RoleType::Noop
+impl std::fmt::Display for Connection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl RoleType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RoleType::Noop)
+
+impl std::str::FromStr for Connection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANYWHERE" => Connection::Anywhere,
+            "ZONE" => Connection::Zone,
+            _ => Connection::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SamlApplication {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub settings: Option<SamlApplicationSettings>,
+impl std::convert::From<String> for Connection {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SamlApplicationSettings {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "signOn")]
-    pub sign_on: Option<SamlApplicationSettingsSignOn>,
+impl std::convert::From<Connection> for String {
+    fn from(v: Connection) -> Self {
+        match v {
+            Connection::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SamlApplicationSettingsSignOn {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "acsEndpoints"
-    )]
-    pub acs_endpoints: Vec<AcsEndpoint>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "allowMultipleAcsEndpoints"
-    )]
-    pub allow_multiple_acs_endpoints: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "assertionSigned"
-    )]
-    pub assertion_signed: bool,
+pub struct PolicyNetworkCondition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection: Option<Connection>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "attributeStatements"
-    )]
-    pub attribute_statements: Vec<SamlAttributeStatement>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub audience: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "audienceOverride"
-    )]
-    pub audience_override: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "authnContextClassRef"
-    )]
-    pub authn_context_class_ref: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "defaultRelayState"
-    )]
-    pub default_relay_state: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub destination: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "destinationOverride"
-    )]
-    pub destination_override: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "digestAlgorithm"
-    )]
-    pub digest_algorithm: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "honorForceAuthn"
-    )]
-    pub honor_force_authn: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "idpIssuer"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub idp_issuer: String,
+    pub exclude: Vec<String>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "inlineHooks"
-    )]
-    pub inline_hooks: Vec<SignOnInlineHook>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub recipient: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "recipientOverride"
-    )]
-    pub recipient_override: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "requestCompressed"
-    )]
-    pub request_compressed: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "responseSigned"
-    )]
-    pub response_signed: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "signatureAlgorithm"
-    )]
-    pub signature_algorithm: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub slo: Option<SingleLogout>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "spCertificate"
-    )]
-    pub sp_certificate: Option<SpCertificate>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "spIssuer"
-    )]
-    pub sp_issuer: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "ssoAcsUrl"
-    )]
-    pub sso_acs_url: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "ssoAcsUrlOverride"
-    )]
-    pub sso_acs_url_override: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "subjectNameIdFormat"
-    )]
-    pub subject_name_id_format: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "subjectNameIdTemplate"
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub subject_name_id_template: String,
+    pub include: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SamlAttributeStatement {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "filterType"
-    )]
-    pub filter_type: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "filterValue"
-    )]
-    pub filter_value: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub namespace: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub values: Vec<String>,
+pub struct PolicyPeopleCondition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub groups: Option<GroupCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub users: Option<UserCondition>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Value {
-    #[serde(rename = "ACTIVATING")]
-    Activating,
+#[serde(from = "String", into = "String")]
+pub enum PolicyRuleStatus {
     #[serde(rename = "ACTIVE")]
     Active,
-    #[serde(rename = "DELETED")]
-    Deleted,
-    #[serde(rename = "DELETING")]
-    Deleting,
-    #[serde(rename = "EXPIRED_PASSWORD")]
-    ExpiredPassword,
     #[serde(rename = "INACTIVE")]
-    Inactive,
-    #[serde(rename = "PENDING")]
-    Pending,
-    #[serde(rename = "SUSPENDED")]
-    Suspended,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PolicyRuleStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            PolicyRuleStatus::Active => "ACTIVE",
+            PolicyRuleStatus::Inactive => "INACTIVE",
+            PolicyRuleStatus::Unknown(s) => s,
+        }
+    }
 }
 
-impl std::fmt::Display for Value {
+impl std::fmt::Display for PolicyRuleStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Value::Activating => "ACTIVATING",
-            Value::Active => "ACTIVE",
-            Value::Deleted => "DELETED",
-            Value::Deleting => "DELETING",
-            Value::ExpiredPassword => "EXPIRED_PASSWORD",
-            Value::Inactive => "INACTIVE",
-            Value::Pending => "PENDING",
-            Value::Suspended => "SUSPENDED",
-            Value::Noop => "",
-            Value::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
     }
 }
 
-impl Default for Value {
-    fn default() -> Value {
-        Value::Noop
+impl std::str::FromStr for PolicyRuleStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => PolicyRuleStatus::Active,
+            "INACTIVE" => PolicyRuleStatus::Inactive,
+            _ => PolicyRuleStatus::Unknown(s.to_string()),
+        })
     }
 }
-impl Value {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Value::Noop)
+
+impl std::convert::From<String> for PolicyRuleStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ScheduledUserLifecycleAction {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<Value>,
+impl std::convert::From<PolicyRuleStatus> for String {
+    fn from(v: PolicyRuleStatus) -> Self {
+        match v {
+            PolicyRuleStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SchemeApplicationCredentials {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub password: Option<PasswordCredential>,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
-        rename = "revealPassword"
-    )]
-    pub reveal_password: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub scheme: Option<ApplicationCredentialsScheme>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub signing: Option<ApplicationCredentialsSigning>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userName"
-    )]
-    pub user_name: String,
+impl Default for PolicyRuleStatus {
+    fn default() -> PolicyRuleStatus {
+        PolicyRuleStatus::Active
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ScopeData {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "stringValue"
-    )]
-    pub string_value: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<ScopeType>,
+#[serde(from = "String", into = "String")]
+pub enum PolicyRuleType {
+    #[serde(rename = "PASSWORD")]
+    Password,
+    #[serde(rename = "SIGN_ON")]
+    SignOn,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ScopeType {
-    #[serde(rename = "CORS")]
-    Cors,
-    #[serde(rename = "REDIRECT")]
-    Redirect,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+impl AsRef<str> for PolicyRuleType {
+    fn as_ref(&self) -> &str {
+        match self {
+            PolicyRuleType::Password => "PASSWORD",
+            PolicyRuleType::SignOn => "SIGN_ON",
+            PolicyRuleType::Unknown(s) => s,
+        }
+    }
 }
 
-impl std::fmt::Display for ScopeType {
+impl std::fmt::Display for PolicyRuleType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ScopeType::Cors => "CORS",
-            ScopeType::Redirect => "REDIRECT",
-            ScopeType::Noop => "",
-            ScopeType::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
     }
 }
 
-impl Default for ScopeType {
-    fn default() -> ScopeType {
-        ScopeType::Noop
+impl std::str::FromStr for PolicyRuleType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "PASSWORD" => PolicyRuleType::Password,
+            "SIGN_ON" => PolicyRuleType::SignOn,
+            _ => PolicyRuleType::Unknown(s.to_string()),
+        })
     }
 }
-impl ScopeType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ScopeType::Noop)
+
+impl std::convert::From<String> for PolicyRuleType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SecurePasswordStoreApplication {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub credentials: Option<SchemeApplicationCredentials>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub name: Option<serde_json::Value>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub settings: Option<SecurePasswordStoreApplicationSettings>,
+impl std::convert::From<PolicyRuleType> for String {
+    fn from(v: PolicyRuleType) -> Self {
+        match v {
+            PolicyRuleType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SecurePasswordStoreApplicationSettings {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub app: Option<SecurePasswordStoreApplicationSettingsData>,
+#[serde(from = "String", into = "String")]
+pub enum AuthType {
+    #[serde(rename = "ANY")]
+    Any,
+    #[serde(rename = "RADIUS")]
+    Radius,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SecurePasswordStoreApplicationSettingsData {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "optionalField1"
-    )]
-    pub optional_field_1: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "optionalField1Value"
-    )]
-    pub optional_field_1_value: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "optionalField2"
-    )]
-    pub optional_field_2: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "optionalField2Value"
-    )]
-    pub optional_field_2_value: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "optionalField3"
-    )]
-    pub optional_field_3: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "optionalField3Value"
-    )]
-    pub optional_field_3_value: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "passwordField"
-    )]
-    pub password_field: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub url: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "usernameField"
-    )]
-    pub username_field: String,
+impl AsRef<str> for AuthType {
+    fn as_ref(&self) -> &str {
+        match self {
+            AuthType::Any => "ANY",
+            AuthType::Radius => "RADIUS",
+            AuthType::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SecurityQuestion {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub answer: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub question: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "questionText"
-    )]
-    pub question_text: String,
+impl std::fmt::Display for AuthType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AuthType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANY" => AuthType::Any,
+            "RADIUS" => AuthType::Radius,
+            _ => AuthType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AuthType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<AuthType> for String {
+    fn from(v: AuthType) -> Self {
+        match v {
+            AuthType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SecurityQuestionUserFactor {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<SecurityQuestion>,
+pub struct PolicyRuleAuthContextCondition {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "authType")]
+    pub auth_type: Option<AuthType>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Session {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub amr: Vec<SessionAuthenticationMethod>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "createdAt"
-    )]
-    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "expiresAt"
-    )]
-    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+pub struct ProfileMappingProperty {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub idp: Option<SessionIdentityProvider>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastFactorVerification"
-    )]
-    pub last_factor_verification: Option<chrono::DateTime<chrono::Utc>>,
+    pub expression: Option<String>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastPasswordVerification"
-    )]
-    pub last_password_verification: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub login: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<SessionStatus>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userId"
+        rename = "pushStatus"
     )]
-    pub user_id: String,
+    pub push_status: Option<ProfileMappingPropertyPushStatus>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum SessionAuthenticationMethod {
-    #[serde(rename = "fpt")]
-    Fpt,
-    #[serde(rename = "geo")]
-    Geo,
-    #[serde(rename = "hwk")]
-    Hwk,
-    #[serde(rename = "kba")]
-    Kba,
-    #[serde(rename = "mfa")]
-    Mfa,
-    #[serde(rename = "otp")]
-    Otp,
-    #[serde(rename = "pwd")]
-    Pwd,
-    #[serde(rename = "sms")]
-    Sms,
-    #[serde(rename = "swk")]
-    Swk,
-    #[serde(rename = "tel")]
-    Tel,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+pub struct ProfileMappingPropertyPushStatus {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ProtocolType {
+    #[serde(rename = "MTLS")]
+    Mtls,
+    #[serde(rename = "OAUTH2")]
+    Oauth2,
+    #[serde(rename = "OIDC")]
+    Oidc,
+    #[serde(rename = "SAML2")]
+    Saml2,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SessionAuthenticationMethod {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            SessionAuthenticationMethod::Fpt => "fpt",
-            SessionAuthenticationMethod::Geo => "geo",
-            SessionAuthenticationMethod::Hwk => "hwk",
-            SessionAuthenticationMethod::Kba => "kba",
-            SessionAuthenticationMethod::Mfa => "mfa",
-            SessionAuthenticationMethod::Otp => "otp",
-            SessionAuthenticationMethod::Pwd => "pwd",
-            SessionAuthenticationMethod::Sms => "sms",
-            SessionAuthenticationMethod::Swk => "swk",
-            SessionAuthenticationMethod::Tel => "tel",
-            SessionAuthenticationMethod::Noop => "",
-            SessionAuthenticationMethod::FallthroughString => "*",
+impl AsRef<str> for ProtocolType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ProtocolType::Mtls => "MTLS",
+            ProtocolType::Oauth2 => "OAUTH2",
+            ProtocolType::Oidc => "OIDC",
+            ProtocolType::Saml2 => "SAML2",
+            ProtocolType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SessionAuthenticationMethod {
-    fn default() -> SessionAuthenticationMethod {
-        SessionAuthenticationMethod::Noop
+impl std::fmt::Display for ProtocolType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ProtocolType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "MTLS" => ProtocolType::Mtls,
+            "OAUTH2" => ProtocolType::Oauth2,
+            "OIDC" => ProtocolType::Oidc,
+            "SAML2" => ProtocolType::Saml2,
+            _ => ProtocolType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ProtocolType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SessionAuthenticationMethod {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SessionAuthenticationMethod::Noop)
+
+impl std::convert::From<ProtocolType> for String {
+    fn from(v: ProtocolType) -> Self {
+        match v {
+            ProtocolType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SessionIdentityProvider {
+pub struct Scope {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        rename = "stringValue"
     )]
-    pub id: String,
+    pub string_value: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<SessionIdentityProviderType>,
+    pub type_: Option<ScopeType>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum SessionIdentityProviderType {
-    #[serde(rename = "ACTIVE_DIRECTORY")]
-    ActiveDirectory,
-    #[serde(rename = "FEDERATION")]
-    Federation,
-    #[serde(rename = "LDAP")]
-    Ldap,
-    #[serde(rename = "OKTA")]
-    Okta,
-    #[serde(rename = "SOCIAL")]
-    Social,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum ProtocolAlgorithmTypeSignatureScope {
+    #[serde(rename = "ANY")]
+    Any,
+    #[serde(rename = "NONE")]
+    None,
+    #[serde(rename = "REQUEST")]
+    Request,
+    #[serde(rename = "RESPONSE")]
+    Response,
+    #[serde(rename = "TOKEN")]
+    Token,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ProtocolAlgorithmTypeSignatureScope {
+    fn as_ref(&self) -> &str {
+        match self {
+            ProtocolAlgorithmTypeSignatureScope::Any => "ANY",
+            ProtocolAlgorithmTypeSignatureScope::None => "NONE",
+            ProtocolAlgorithmTypeSignatureScope::Request => "REQUEST",
+            ProtocolAlgorithmTypeSignatureScope::Response => "RESPONSE",
+            ProtocolAlgorithmTypeSignatureScope::Token => "TOKEN",
+            ProtocolAlgorithmTypeSignatureScope::Unknown(s) => s,
+        }
+    }
 }
 
-impl std::fmt::Display for SessionIdentityProviderType {
+impl std::fmt::Display for ProtocolAlgorithmTypeSignatureScope {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            SessionIdentityProviderType::ActiveDirectory => "ACTIVE_DIRECTORY",
-            SessionIdentityProviderType::Federation => "FEDERATION",
-            SessionIdentityProviderType::Ldap => "LDAP",
-            SessionIdentityProviderType::Okta => "OKTA",
-            SessionIdentityProviderType::Social => "SOCIAL",
-            SessionIdentityProviderType::Noop => "",
-            SessionIdentityProviderType::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ProtocolAlgorithmTypeSignatureScope {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ANY" => ProtocolAlgorithmTypeSignatureScope::Any,
+            "NONE" => ProtocolAlgorithmTypeSignatureScope::None,
+            "REQUEST" => ProtocolAlgorithmTypeSignatureScope::Request,
+            "RESPONSE" => ProtocolAlgorithmTypeSignatureScope::Response,
+            "TOKEN" => ProtocolAlgorithmTypeSignatureScope::Token,
+            _ => ProtocolAlgorithmTypeSignatureScope::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for SessionIdentityProviderType {
-    fn default() -> SessionIdentityProviderType {
-        SessionIdentityProviderType::Noop
+impl std::convert::From<String> for ProtocolAlgorithmTypeSignatureScope {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SessionIdentityProviderType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SessionIdentityProviderType::Noop)
+
+impl std::convert::From<ProtocolAlgorithmTypeSignatureScope> for String {
+    fn from(v: ProtocolAlgorithmTypeSignatureScope) -> Self {
+        match v {
+            ProtocolAlgorithmTypeSignatureScope::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum SessionStatus {
-    #[serde(rename = "ACTIVE")]
-    Active,
-    #[serde(rename = "MFA_ENROLL")]
-    MfaEnroll,
-    #[serde(rename = "MFA_REQUIRED")]
-    MfaRequired,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum Binding {
+    #[serde(rename = "HTTP-POST")]
+    HttpPost,
+    #[serde(rename = "HTTP-REDIRECT")]
+    HttpRedirect,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SessionStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            SessionStatus::Active => "ACTIVE",
-            SessionStatus::MfaEnroll => "MFA_ENROLL",
-            SessionStatus::MfaRequired => "MFA_REQUIRED",
-            SessionStatus::Noop => "",
-            SessionStatus::FallthroughString => "*",
+impl AsRef<str> for Binding {
+    fn as_ref(&self) -> &str {
+        match self {
+            Binding::HttpPost => "HTTP-POST",
+            Binding::HttpRedirect => "HTTP-REDIRECT",
+            Binding::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SessionStatus {
-    fn default() -> SessionStatus {
-        SessionStatus::Noop
+impl std::fmt::Display for Binding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl SessionStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SessionStatus::Noop)
+
+impl std::str::FromStr for Binding {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "HTTP-POST" => Binding::HttpPost,
+            "HTTP-REDIRECT" => Binding::HttpRedirect,
+            _ => Binding::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SignOnInlineHook {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+impl std::convert::From<String> for Binding {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SingleLogout {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enabled: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub issuer: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "logoutUrl"
-    )]
-    pub logout_url: String,
+impl std::convert::From<Binding> for String {
+    fn from(v: Binding) -> Self {
+        match v {
+            Binding::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SmsTemplate {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub template: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub translations: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<SmsTemplateType>,
+#[serde(from = "String", into = "String")]
+pub enum ProtocolEndpointType {
+    #[serde(rename = "INSTANCE")]
+    Instance,
+    #[serde(rename = "ORG")]
+    Org,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum SmsTemplateType {
-    #[serde(rename = "SMS_VERIFY_CODE")]
-    SmsVerifyCode,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+impl AsRef<str> for ProtocolEndpointType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ProtocolEndpointType::Instance => "INSTANCE",
+            ProtocolEndpointType::Org => "ORG",
+            ProtocolEndpointType::Unknown(s) => s,
+        }
+    }
 }
 
-impl std::fmt::Display for SmsTemplateType {
+impl std::fmt::Display for ProtocolEndpointType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            SmsTemplateType::SmsVerifyCode => "SMS_VERIFY_CODE",
-            SmsTemplateType::Noop => "",
-            SmsTemplateType::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ProtocolEndpointType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "INSTANCE" => ProtocolEndpointType::Instance,
+            "ORG" => ProtocolEndpointType::Org,
+            _ => ProtocolEndpointType::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for SmsTemplateType {
-    fn default() -> SmsTemplateType {
-        SmsTemplateType::Noop
+impl std::convert::From<String> for ProtocolEndpointType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SmsTemplateType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SmsTemplateType::Noop)
+
+impl std::convert::From<ProtocolEndpointType> for String {
+    fn from(v: ProtocolEndpointType) -> Self {
+        match v {
+            ProtocolEndpointType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SmsUserFactor {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<SmsUserFactorProfile>,
+#[serde(from = "String", into = "String")]
+pub enum ProvisioningAction {
+    #[serde(rename = "AUTO")]
+    Auto,
+    #[serde(rename = "CALLOUT")]
+    Callout,
+    #[serde(rename = "DISABLED")]
+    Disabled,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SmsUserFactorProfile {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "phoneNumber"
-    )]
-    pub phone_number: String,
+impl AsRef<str> for ProvisioningAction {
+    fn as_ref(&self) -> &str {
+        match self {
+            ProvisioningAction::Auto => "AUTO",
+            ProvisioningAction::Callout => "CALLOUT",
+            ProvisioningAction::Disabled => "DISABLED",
+            ProvisioningAction::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ProvisioningAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ProvisioningAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AUTO" => ProvisioningAction::Auto,
+            "CALLOUT" => ProvisioningAction::Callout,
+            "DISABLED" => ProvisioningAction::Disabled,
+            _ => ProvisioningAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ProvisioningAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ProvisioningAction> for String {
+    fn from(v: ProvisioningAction) -> Self {
+        match v {
+            ProvisioningAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum TokenType {
-    #[serde(rename = "ACCESS")]
-    Access,
-    #[serde(rename = "REFRESH")]
-    Refresh,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum ProvisioningDeprovisionedConditionAction {
+    #[serde(rename = "NONE")]
+    None,
+    #[serde(rename = "REACTIVATE")]
+    Reactivate,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for TokenType {
+impl AsRef<str> for ProvisioningDeprovisionedConditionAction {
+    fn as_ref(&self) -> &str {
+        match self {
+            ProvisioningDeprovisionedConditionAction::None => "NONE",
+            ProvisioningDeprovisionedConditionAction::Reactivate => "REACTIVATE",
+            ProvisioningDeprovisionedConditionAction::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ProvisioningDeprovisionedConditionAction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            TokenType::Access => "ACCESS",
-            TokenType::Refresh => "REFRESH",
-            TokenType::Noop => "",
-            TokenType::FallthroughString => "*",
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ProvisioningDeprovisionedConditionAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "NONE" => ProvisioningDeprovisionedConditionAction::None,
+            "REACTIVATE" => ProvisioningDeprovisionedConditionAction::Reactivate,
+            _ => ProvisioningDeprovisionedConditionAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ProvisioningDeprovisionedConditionAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ProvisioningDeprovisionedConditionAction> for String {
+    fn from(v: ProvisioningDeprovisionedConditionAction) -> Self {
+        match v {
+            ProvisioningDeprovisionedConditionAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ProvisioningGroupsAction {
+    #[serde(rename = "APPEND")]
+    Append,
+    #[serde(rename = "ASSIGN")]
+    Assign,
+    #[serde(rename = "NONE")]
+    None,
+    #[serde(rename = "SYNC")]
+    Sync,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ProvisioningGroupsAction {
+    fn as_ref(&self) -> &str {
+        match self {
+            ProvisioningGroupsAction::Append => "APPEND",
+            ProvisioningGroupsAction::Assign => "ASSIGN",
+            ProvisioningGroupsAction::None => "NONE",
+            ProvisioningGroupsAction::Sync => "SYNC",
+            ProvisioningGroupsAction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for TokenType {
-    fn default() -> TokenType {
-        TokenType::Noop
+impl std::fmt::Display for ProvisioningGroupsAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl TokenType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, TokenType::Noop)
+
+impl std::str::FromStr for ProvisioningGroupsAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "APPEND" => ProvisioningGroupsAction::Append,
+            "ASSIGN" => ProvisioningGroupsAction::Assign,
+            "NONE" => ProvisioningGroupsAction::None,
+            "SYNC" => ProvisioningGroupsAction::Sync,
+            _ => ProvisioningGroupsAction::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SocialAuthToken {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "expiresAt"
-    )]
-    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub token: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tokenAuthScheme"
-    )]
-    pub token_auth_scheme: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "tokenType")]
-    pub token_type: Option<TokenType>,
+impl std::convert::From<String> for ProvisioningGroupsAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SpCertificate {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "x5c"
-    )]
-    pub x_5c: Vec<String>,
+impl std::convert::From<ProvisioningGroupsAction> for String {
+    fn from(v: ProvisioningGroupsAction) -> Self {
+        match v {
+            ProvisioningGroupsAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SwaApplication {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub name: Option<serde_json::Value>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub settings: Option<SwaApplicationSettings>,
+#[serde(from = "String", into = "String")]
+pub enum ProvisioningSuspendedConditionAction {
+    #[serde(rename = "NONE")]
+    None,
+    #[serde(rename = "UNSUSPEND")]
+    Unsuspend,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SwaApplicationSettings {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub app: Option<SwaApplicationSettingsData>,
+impl AsRef<str> for ProvisioningSuspendedConditionAction {
+    fn as_ref(&self) -> &str {
+        match self {
+            ProvisioningSuspendedConditionAction::None => "NONE",
+            ProvisioningSuspendedConditionAction::Unsuspend => "UNSUSPEND",
+            ProvisioningSuspendedConditionAction::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SwaApplicationSettingsData {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "buttonField"
-    )]
-    pub button_field: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "loginUrlRegex"
-    )]
-    pub login_url_regex: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "passwordField"
-    )]
-    pub password_field: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub url: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "usernameField"
-    )]
-    pub username_field: String,
+impl std::fmt::Display for ProvisioningSuspendedConditionAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SwaThreeFieldApplication {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub name: Option<serde_json::Value>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub settings: Option<SwaThreeFieldApplicationSettings>,
-}
+impl std::str::FromStr for ProvisioningSuspendedConditionAction {
+    type Err = std::convert::Infallible;
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SwaThreeFieldApplicationSettings {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub app: Option<SwaThreeFieldApplicationSettingsData>,
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "NONE" => ProvisioningSuspendedConditionAction::None,
+            "UNSUSPEND" => ProvisioningSuspendedConditionAction::Unsuspend,
+            _ => ProvisioningSuspendedConditionAction::Unknown(s.to_string()),
+        })
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SwaThreeFieldApplicationSettingsData {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "buttonSelector"
-    )]
-    pub button_selector: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "extraFieldSelector"
-    )]
-    pub extra_field_selector: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "extraFieldValue"
-    )]
-    pub extra_field_value: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "loginUrlRegex"
-    )]
-    pub login_url_regex: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "passwordSelector"
-    )]
-    pub password_selector: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "targetURL"
-    )]
-    pub target_url: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userNameSelector"
-    )]
-    pub user_name_selector: String,
+impl std::convert::From<String> for ProvisioningSuspendedConditionAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TempPassword {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "tempPassword"
-    )]
-    pub temp_password: String,
+impl std::convert::From<ProvisioningSuspendedConditionAction> for String {
+    fn from(v: ProvisioningSuspendedConditionAction) -> Self {
+        match v {
+            ProvisioningSuspendedConditionAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ThreatInsightConfiguration {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub action: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "excludeZones"
-    )]
-    pub exclude_zones: Vec<String>,
+pub struct PushUserFactor {
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TokenAuthorizationServerPolicyRuleAction {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "accessTokenLifetimeMinutes"
+        rename = "expiresAt"
     )]
-    pub access_token_lifetime_minutes: i64,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        rename = "inlineHook"
-    )]
-    pub inline_hook: Option<TokenAuthorizationServerPolicyRuleActionInlineHook>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "refreshTokenLifetimeMinutes"
-    )]
-    pub refresh_token_lifetime_minutes: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
-        rename = "refreshTokenWindowMinutes"
-    )]
-    pub refresh_token_window_minutes: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TokenAuthorizationServerPolicyRuleActionInlineHook {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        rename = "factorResult"
     )]
-    pub id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TokenUserFactor {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<WebUserFactorProfile>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TotpUserFactor {
+    pub factor_result: Option<FactorResultType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<WebUserFactorProfile>,
+    pub profile: Option<PushUserFactorProfile>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TrustedOrigin {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
+pub struct PushUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "createdBy"
+        rename = "credentialId"
     )]
-    pub created_by: String,
+    pub credential_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "deviceToken"
     )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    pub device_token: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "lastUpdatedBy"
+        rename = "deviceType"
     )]
-    pub last_updated_by: String,
+    pub device_type: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -9101,724 +6262,1111 @@ pub struct TrustedOrigin {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub origin: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<ScopeData>,
+    pub platform: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub status: String,
+    pub version: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct U2FUserFactor {
+pub struct SamlApplication {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<WebUserFactorProfile>,
+    pub settings: Option<SamlApplicationSettings>,
 }
 
-#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct User {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub activated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub credentials: Option<UserCredentials>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SamlApplicationSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "signOn")]
+    pub sign_on: Option<SamlApplicationSettingsSignOn>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SamlApplicationSettingsSignOn {
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastLogin"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "acsEndpoints"
     )]
-    pub last_login: Option<chrono::DateTime<chrono::Utc>>,
+    pub acs_endpoints: Vec<AcsEndpoint>,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "allowMultipleAcsEndpoints"
     )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    pub allow_multiple_acs_endpoints: bool,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "passwordChanged"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "assertionSigned"
     )]
-    pub password_changed: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<UserProfile>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<UserStatus>,
+    pub assertion_signed: bool,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "statusChanged"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "attributeStatements"
     )]
-    pub status_changed: Option<chrono::DateTime<chrono::Utc>>,
+    pub attribute_statements: Vec<SamlAttributeStatement>,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "transitioningToStatus"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub transitioning_to_status: Option<UserStatus>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<UserType>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserActivationToken {
+    pub audience: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "activationToken"
+        rename = "audienceOverride"
     )]
-    pub activation_token: String,
+    pub audience_override: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "activationUrl"
-    )]
-    pub activation_url: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserCredentials {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub password: Option<PasswordCredential>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub provider: Option<AuthenticationProvider>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub recovery_question: Option<RecoveryQuestionCredential>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserFactor {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
+        rename = "authnContextClassRef"
     )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    pub authn_context_class_ref: String,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "factorType"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "defaultRelayState"
     )]
-    pub factor_type: Option<FactorType>,
+    pub default_relay_state: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub provider: Option<FactorProvider>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<FactorStatus>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub verify: Option<VerifyFactorRequest>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum UserIdentifierConditionEvaluatorPatternMatchType {
-    #[serde(rename = "CONTAINS")]
-    Contains,
-    #[serde(rename = "EQUALS")]
-    Equals,
-    #[serde(rename = "EXPRESSION")]
-    Expression,
-    #[serde(rename = "STARTS_WITH")]
-    StartsWith,
-    #[serde(rename = "SUFFIX")]
-    Suffix,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for UserIdentifierConditionEvaluatorPatternMatchType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            UserIdentifierConditionEvaluatorPatternMatchType::Contains => "CONTAINS",
-            UserIdentifierConditionEvaluatorPatternMatchType::Equals => "EQUALS",
-            UserIdentifierConditionEvaluatorPatternMatchType::Expression => "EXPRESSION",
-            UserIdentifierConditionEvaluatorPatternMatchType::StartsWith => "STARTS_WITH",
-            UserIdentifierConditionEvaluatorPatternMatchType::Suffix => "SUFFIX",
-            UserIdentifierConditionEvaluatorPatternMatchType::Noop => "",
-            UserIdentifierConditionEvaluatorPatternMatchType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for UserIdentifierConditionEvaluatorPatternMatchType {
-    fn default() -> UserIdentifierConditionEvaluatorPatternMatchType {
-        UserIdentifierConditionEvaluatorPatternMatchType::Noop
-    }
-}
-impl UserIdentifierConditionEvaluatorPatternMatchType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UserIdentifierConditionEvaluatorPatternMatchType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserIdentifierConditionEvaluatorPattern {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "matchType")]
-    pub match_type: Option<UserIdentifierConditionEvaluatorPatternMatchType>,
+    pub destination: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "destinationOverride"
     )]
-    pub value: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum UserIdentifierPolicyRuleConditionType {
-    #[serde(rename = "ATTRIBUTE")]
-    Attribute,
-    #[serde(rename = "IDENTIFIER")]
-    Identifier,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for UserIdentifierPolicyRuleConditionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            UserIdentifierPolicyRuleConditionType::Attribute => "ATTRIBUTE",
-            UserIdentifierPolicyRuleConditionType::Identifier => "IDENTIFIER",
-            UserIdentifierPolicyRuleConditionType::Noop => "",
-            UserIdentifierPolicyRuleConditionType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for UserIdentifierPolicyRuleConditionType {
-    fn default() -> UserIdentifierPolicyRuleConditionType {
-        UserIdentifierPolicyRuleConditionType::Noop
-    }
-}
-impl UserIdentifierPolicyRuleConditionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UserIdentifierPolicyRuleConditionType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserIdentifierPolicyRuleCondition {
+    pub destination_override: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "digestAlgorithm"
     )]
-    pub attribute: String,
+    pub digest_algorithm: String,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "honorForceAuthn"
     )]
-    pub patterns: Vec<UserIdentifierConditionEvaluatorPattern>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<UserIdentifierPolicyRuleConditionType>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserIdentityProviderLinkRequest {
+    pub honor_force_authn: bool,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "externalId"
+        rename = "idpIssuer"
     )]
-    pub external_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserLifecycleAttributePolicyRuleCondition {
+    pub idp_issuer: String,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "attributeName"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "inlineHooks"
     )]
-    pub attribute_name: String,
+    pub inline_hooks: Vec<SignOnInlineHook>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "matchingValue"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub matching_value: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum UserNextLogin {
-    #[serde(rename = "changePassword")]
-    ChangePassword,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for UserNextLogin {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            UserNextLogin::ChangePassword => "changePassword",
-            UserNextLogin::Noop => "",
-            UserNextLogin::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for UserNextLogin {
-    fn default() -> UserNextLogin {
-        UserNextLogin::Noop
-    }
-}
-impl UserNextLogin {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UserNextLogin::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserPolicyRuleCondition {
+    pub recipient: String,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "recipientOverride"
     )]
-    pub exclude: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub inactivity: Option<Duration>,
+    pub recipient_override: String,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "requestCompressed"
     )]
-    pub include: Vec<String>,
+    pub request_compressed: bool,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "lifecycleExpiration"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "responseSigned"
     )]
-    pub lifecycle_expiration: Option<LifecycleExpirationPolicyRuleCondition>,
+    pub response_signed: bool,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        rename = "passwordExpiration"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "signatureAlgorithm"
     )]
-    pub password_expiration: Option<Duration>,
+    pub signature_algorithm: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slo: Option<SingleLogout>,
     #[serde(
         default,
         skip_serializing_if = "Option::is_none",
-        rename = "userLifecycleAttribute"
-    )]
-    pub user_lifecycle_attribute: Option<UserLifecycleAttributePolicyRuleCondition>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserProfile {
+        rename = "spCertificate"
+    )]
+    pub sp_certificate: Option<SpCertificate>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "awsRole"
+        rename = "spIssuer"
     )]
-    pub aws_role: String,
+    pub sp_issuer: String,
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ssoAcsUrl"
     )]
-    pub birthday: Option<chrono::NaiveDate>,
+    pub sso_acs_url: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ssoAcsUrlOverride"
     )]
-    pub city: String,
+    pub sso_acs_url_override: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "costCenter"
+        rename = "subjectNameIdFormat"
     )]
-    pub cost_center: String,
+    pub subject_name_id_format: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "countryCode"
+        rename = "subjectNameIdTemplate"
     )]
-    pub country_code: String,
+    pub subject_name_id_template: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SamlAttributeStatement {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "filterType"
     )]
-    pub department: String,
+    pub filter_type: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
+        rename = "filterValue"
     )]
-    pub display_name: String,
+    pub filter_value: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub division: String,
+    pub name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "emailAliases"
-    )]
-    pub email_aliases: Vec<String>,
+    pub namespace: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "employeeNumber"
+        rename = "type"
     )]
-    pub employee_number: String,
+    pub type_: String,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "firstName"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub first_name: String,
+    pub values: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ScheduledUserLifecycleActionStatus {
+    #[serde(rename = "ACTIVATING")]
+    Activating,
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "DELETED")]
+    Deleted,
+    #[serde(rename = "DELETING")]
+    Deleting,
+    #[serde(rename = "EXPIRED_PASSWORD")]
+    ExpiredPassword,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    #[serde(rename = "PENDING")]
+    Pending,
+    #[serde(rename = "SUSPENDED")]
+    Suspended,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ScheduledUserLifecycleActionStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            ScheduledUserLifecycleActionStatus::Activating => "ACTIVATING",
+            ScheduledUserLifecycleActionStatus::Active => "ACTIVE",
+            ScheduledUserLifecycleActionStatus::Deleted => "DELETED",
+            ScheduledUserLifecycleActionStatus::Deleting => "DELETING",
+            ScheduledUserLifecycleActionStatus::ExpiredPassword => "EXPIRED_PASSWORD",
+            ScheduledUserLifecycleActionStatus::Inactive => "INACTIVE",
+            ScheduledUserLifecycleActionStatus::Pending => "PENDING",
+            ScheduledUserLifecycleActionStatus::Suspended => "SUSPENDED",
+            ScheduledUserLifecycleActionStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ScheduledUserLifecycleActionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ScheduledUserLifecycleActionStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVATING" => ScheduledUserLifecycleActionStatus::Activating,
+            "ACTIVE" => ScheduledUserLifecycleActionStatus::Active,
+            "DELETED" => ScheduledUserLifecycleActionStatus::Deleted,
+            "DELETING" => ScheduledUserLifecycleActionStatus::Deleting,
+            "EXPIRED_PASSWORD" => ScheduledUserLifecycleActionStatus::ExpiredPassword,
+            "INACTIVE" => ScheduledUserLifecycleActionStatus::Inactive,
+            "PENDING" => ScheduledUserLifecycleActionStatus::Pending,
+            "SUSPENDED" => ScheduledUserLifecycleActionStatus::Suspended,
+            _ => ScheduledUserLifecycleActionStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ScheduledUserLifecycleActionStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ScheduledUserLifecycleActionStatus> for String {
+    fn from(v: ScheduledUserLifecycleActionStatus) -> Self {
+        match v {
+            ScheduledUserLifecycleActionStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SchemeApplicationCredentials {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<PasswordCredential>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "githubUsername"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "revealPassword"
     )]
-    pub github_username: String,
+    pub reveal_password: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scheme: Option<ApplicationCredentialsScheme>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing: Option<ApplicationCredentialsSigning>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "honorificPrefix"
+        rename = "userName"
     )]
-    pub honorific_prefix: String,
+    pub user_name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ScopeType {
+    #[serde(rename = "CORS")]
+    Cors,
+    #[serde(rename = "REDIRECT")]
+    Redirect,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ScopeType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ScopeType::Cors => "CORS",
+            ScopeType::Redirect => "REDIRECT",
+            ScopeType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ScopeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ScopeType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CORS" => ScopeType::Cors,
+            "REDIRECT" => ScopeType::Redirect,
+            _ => ScopeType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ScopeType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ScopeType> for String {
+    fn from(v: ScopeType) -> Self {
+        match v {
+            ScopeType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SecurePasswordStoreApplication {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<SchemeApplicationCredentials>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<SecurePasswordStoreApplicationSettings>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SecurePasswordStoreApplicationSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<SecurePasswordStoreApplicationSettingsData>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SecurePasswordStoreApplicationSettingsData {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "honorificSuffix"
+        rename = "optionalField1"
     )]
-    pub honorific_suffix: String,
+    pub optional_field_1: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "lastName"
+        rename = "optionalField1Value"
     )]
-    pub last_name: String,
+    pub optional_field_1_value: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "optionalField2"
     )]
-    pub locale: String,
+    pub optional_field_2: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "optionalField2Value"
     )]
-    pub login: String,
+    pub optional_field_2_value: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "optionalField3"
     )]
-    pub manager: String,
+    pub optional_field_3: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "managerId"
+        rename = "optionalField3Value"
     )]
-    pub manager_id: String,
+    pub optional_field_3_value: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "matrixUsername"
+        rename = "passwordField"
     )]
-    pub matrix_username: String,
+    pub password_field: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "middleName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub middle_name: String,
+    pub url: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "mobilePhone"
+        rename = "usernameField"
     )]
-    pub mobile_phone: String,
+    pub username_field: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SecurityQuestionUserFactor {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<SecurityQuestionUserFactorProfile>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SecurityQuestionUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nickName"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub nick_name: String,
+    pub answer: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub organization: String,
+    pub question: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "postalAddress"
+        rename = "questionText"
+    )]
+    pub question_text: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SessionAuthenticationMethod {
+    #[serde(rename = "fpt")]
+    Fpt,
+    #[serde(rename = "geo")]
+    Geo,
+    #[serde(rename = "hwk")]
+    Hwk,
+    #[serde(rename = "kba")]
+    Kba,
+    #[serde(rename = "mfa")]
+    Mfa,
+    #[serde(rename = "otp")]
+    Otp,
+    #[serde(rename = "pwd")]
+    Pwd,
+    #[serde(rename = "sms")]
+    Sms,
+    #[serde(rename = "swk")]
+    Swk,
+    #[serde(rename = "tel")]
+    Tel,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SessionAuthenticationMethod {
+    fn as_ref(&self) -> &str {
+        match self {
+            SessionAuthenticationMethod::Fpt => "fpt",
+            SessionAuthenticationMethod::Geo => "geo",
+            SessionAuthenticationMethod::Hwk => "hwk",
+            SessionAuthenticationMethod::Kba => "kba",
+            SessionAuthenticationMethod::Mfa => "mfa",
+            SessionAuthenticationMethod::Otp => "otp",
+            SessionAuthenticationMethod::Pwd => "pwd",
+            SessionAuthenticationMethod::Sms => "sms",
+            SessionAuthenticationMethod::Swk => "swk",
+            SessionAuthenticationMethod::Tel => "tel",
+            SessionAuthenticationMethod::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SessionAuthenticationMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SessionAuthenticationMethod {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "fpt" => SessionAuthenticationMethod::Fpt,
+            "geo" => SessionAuthenticationMethod::Geo,
+            "hwk" => SessionAuthenticationMethod::Hwk,
+            "kba" => SessionAuthenticationMethod::Kba,
+            "mfa" => SessionAuthenticationMethod::Mfa,
+            "otp" => SessionAuthenticationMethod::Otp,
+            "pwd" => SessionAuthenticationMethod::Pwd,
+            "sms" => SessionAuthenticationMethod::Sms,
+            "swk" => SessionAuthenticationMethod::Swk,
+            "tel" => SessionAuthenticationMethod::Tel,
+            _ => SessionAuthenticationMethod::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SessionAuthenticationMethod {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SessionAuthenticationMethod> for String {
+    fn from(v: SessionAuthenticationMethod) -> Self {
+        match v {
+            SessionAuthenticationMethod::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SignOnInlineHook {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub postal_address: String,
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SingleLogout {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "preferredLanguage"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub preferred_language: String,
+    pub enabled: bool,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "primaryPhone"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub primary_phone: String,
+    pub issuer: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "profileUrl"
+        rename = "logoutUrl"
     )]
-    pub profile_url: String,
+    pub logout_url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SmsUserFactor {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<SmsUserFactorProfile>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SmsUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "secondEmail"
+        rename = "phoneNumber"
     )]
-    pub second_email: String,
+    pub phone_number: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum TokenType {
+    #[serde(rename = "ACCESS")]
+    Access,
+    #[serde(rename = "REFRESH")]
+    Refresh,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for TokenType {
+    fn as_ref(&self) -> &str {
+        match self {
+            TokenType::Access => "ACCESS",
+            TokenType::Refresh => "REFRESH",
+            TokenType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for TokenType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for TokenType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACCESS" => TokenType::Access,
+            "REFRESH" => TokenType::Refresh,
+            _ => TokenType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for TokenType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<TokenType> for String {
+    fn from(v: TokenType) -> Self {
+        match v {
+            TokenType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SpCertificate {
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize",
-        rename = "startDate"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "x5c"
     )]
-    pub start_date: Option<chrono::NaiveDate>,
+    pub x_5c: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SwaApplication {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<SwaApplicationSettings>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SwaApplicationSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<SwaApplicationSettingsData>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SwaApplicationSettingsData {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "buttonField"
     )]
-    pub state: String,
+    pub button_field: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "streetAddress"
+        rename = "loginUrlRegex"
     )]
-    pub street_address: String,
+    pub login_url_regex: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "passwordField"
     )]
-    pub timezone: String,
+    pub password_field: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub title: String,
+    pub url: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "userType"
+        rename = "usernameField"
     )]
-    pub user_type: String,
+    pub username_field: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SwaThreeFieldApplication {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<SwaThreeFieldApplicationSettings>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SwaThreeFieldApplicationSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<SwaThreeFieldApplicationSettingsData>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SwaThreeFieldApplicationSettingsData {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "workCity"
+        rename = "buttonSelector"
     )]
-    pub work_city: String,
+    pub button_selector: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "workCountryCode"
+        rename = "extraFieldSelector"
     )]
-    pub work_country_code: String,
+    pub extra_field_selector: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "workPostalAddress"
+        rename = "extraFieldValue"
     )]
-    pub work_postal_address: String,
+    pub extra_field_value: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "workState"
+        rename = "loginUrlRegex"
     )]
-    pub work_state: String,
+    pub login_url_regex: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "workStreetAddress"
+        rename = "passwordSelector"
     )]
-    pub work_street_address: String,
+    pub password_selector: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "workZipCode"
+        rename = "targetURL"
     )]
-    pub work_zip_code: String,
+    pub target_url: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "zipCode"
+        rename = "userNameSelector"
     )]
-    pub zip_code: String,
+    pub user_name_selector: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserSchema {
+pub struct TokenUserFactor {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<TokenUserFactorProfile>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TokenUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "$schema"
-    )]
-    pub schema: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        rename = "credentialId"
     )]
-    pub created: String,
+    pub credential_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TotpUserFactor {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub definitions: Option<UserSchemaDefinitions>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+    pub profile: Option<TotpUserFactorProfile>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TotpUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "lastUpdated"
+        rename = "credentialId"
     )]
-    pub last_updated: String,
+    pub credential_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct U2FUserFactor {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<U2FUserFactorProfile>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct U2FUserFactorProfile {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "credentialId"
     )]
-    pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<UserSchemaProperties>,
+    pub credential_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserCondition {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub title: String,
+    pub exclude: Vec<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub type_: String,
+    pub include: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum UserIdentifierConditionEvaluatorPatternMatchType {
+    #[serde(rename = "CONTAINS")]
+    Contains,
+    #[serde(rename = "EQUALS")]
+    Equals,
+    #[serde(rename = "EXPRESSION")]
+    Expression,
+    #[serde(rename = "STARTS_WITH")]
+    StartsWith,
+    #[serde(rename = "SUFFIX")]
+    Suffix,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for UserIdentifierConditionEvaluatorPatternMatchType {
+    fn as_ref(&self) -> &str {
+        match self {
+            UserIdentifierConditionEvaluatorPatternMatchType::Contains => "CONTAINS",
+            UserIdentifierConditionEvaluatorPatternMatchType::Equals => "EQUALS",
+            UserIdentifierConditionEvaluatorPatternMatchType::Expression => "EXPRESSION",
+            UserIdentifierConditionEvaluatorPatternMatchType::StartsWith => "STARTS_WITH",
+            UserIdentifierConditionEvaluatorPatternMatchType::Suffix => "SUFFIX",
+            UserIdentifierConditionEvaluatorPatternMatchType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for UserIdentifierConditionEvaluatorPatternMatchType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UserIdentifierConditionEvaluatorPatternMatchType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CONTAINS" => UserIdentifierConditionEvaluatorPatternMatchType::Contains,
+            "EQUALS" => UserIdentifierConditionEvaluatorPatternMatchType::Equals,
+            "EXPRESSION" => UserIdentifierConditionEvaluatorPatternMatchType::Expression,
+            "STARTS_WITH" => UserIdentifierConditionEvaluatorPatternMatchType::StartsWith,
+            "SUFFIX" => UserIdentifierConditionEvaluatorPatternMatchType::Suffix,
+            _ => UserIdentifierConditionEvaluatorPatternMatchType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UserIdentifierConditionEvaluatorPatternMatchType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UserIdentifierConditionEvaluatorPatternMatchType> for String {
+    fn from(v: UserIdentifierConditionEvaluatorPatternMatchType) -> Self {
+        match v {
+            UserIdentifierConditionEvaluatorPatternMatchType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserIdentifierConditionEvaluatorPattern {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "matchType")]
+    pub match_type: Option<UserIdentifierConditionEvaluatorPatternMatchType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum UserIdentifierPolicyRuleConditionType {
+    #[serde(rename = "ATTRIBUTE")]
+    Attribute,
+    #[serde(rename = "IDENTIFIER")]
+    Identifier,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for UserIdentifierPolicyRuleConditionType {
+    fn as_ref(&self) -> &str {
+        match self {
+            UserIdentifierPolicyRuleConditionType::Attribute => "ATTRIBUTE",
+            UserIdentifierPolicyRuleConditionType::Identifier => "IDENTIFIER",
+            UserIdentifierPolicyRuleConditionType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for UserIdentifierPolicyRuleConditionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UserIdentifierPolicyRuleConditionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ATTRIBUTE" => UserIdentifierPolicyRuleConditionType::Attribute,
+            "IDENTIFIER" => UserIdentifierPolicyRuleConditionType::Identifier,
+            _ => UserIdentifierPolicyRuleConditionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UserIdentifierPolicyRuleConditionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UserIdentifierPolicyRuleConditionType> for String {
+    fn from(v: UserIdentifierPolicyRuleConditionType) -> Self {
+        match v {
+            UserIdentifierPolicyRuleConditionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum UserNextLogin {
+    #[serde(rename = "changePassword")]
+    ChangePassword,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for UserNextLogin {
+    fn as_ref(&self) -> &str {
+        match self {
+            UserNextLogin::ChangePassword => "changePassword",
+            UserNextLogin::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for UserNextLogin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UserNextLogin {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "changePassword" => UserNextLogin::ChangePassword,
+            _ => UserNextLogin::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UserNextLogin {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UserNextLogin> for String {
+    fn from(v: UserNextLogin) -> Self {
+        match v {
+            UserNextLogin::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserSchemaAttribute {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -9828,18 +7376,16 @@ pub struct UserSchemaAttribute {
     pub enum_: Vec<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "externalName"
     )]
-    pub external_name: String,
+    pub external_name: Option<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        skip_serializing_if = "Option::is_none",
         rename = "externalNamespace"
     )]
-    pub external_namespace: String,
+    pub external_namespace: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub items: Option<UserSchemaAttributeItems>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -9858,12 +7404,8 @@ pub struct UserSchemaAttribute {
         rename = "minLength"
     )]
     pub min_length: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub mutability: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mutability: Option<String>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -9871,12 +7413,8 @@ pub struct UserSchemaAttribute {
         rename = "oneOf"
     )]
     pub one_of: Vec<UserSchemaAttributeEnum>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub pattern: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
@@ -9889,40 +7427,23 @@ pub struct UserSchemaAttribute {
     )]
     pub required: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub scope: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
+    pub scope: Option<UserSchemaAttributeScope>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
     pub type_: Option<UserSchemaAttributeType>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub union: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub unique: String,
+    pub union: Option<UserSchemaAttributeUnion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unique: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserSchemaAttributeEnum {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "const"
-    )]
-    pub const_: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "const")]
+    pub const_: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9941,13 +7462,8 @@ pub struct UserSchemaAttributeItems {
         rename = "oneOf"
     )]
     pub one_of: Vec<UserSchemaAttributeEnum>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -9964,22 +7480,14 @@ pub struct UserSchemaAttributeMaster {
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct UserSchemaAttributeMasterPriority {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserSchemaAttributeMasterType {
     #[serde(rename = "OKTA")]
     Okta,
@@ -9987,485 +7495,236 @@ pub enum UserSchemaAttributeMasterType {
     Override,
     #[serde(rename = "PROFILE_MASTER")]
     ProfileMaster,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for UserSchemaAttributeMasterType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            UserSchemaAttributeMasterType::Okta => "OKTA",
-            UserSchemaAttributeMasterType::Override => "OVERRIDE",
-            UserSchemaAttributeMasterType::ProfileMaster => "PROFILE_MASTER",
-            UserSchemaAttributeMasterType::Noop => "",
-            UserSchemaAttributeMasterType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for UserSchemaAttributeMasterType {
-    fn default() -> UserSchemaAttributeMasterType {
-        UserSchemaAttributeMasterType::Noop
-    }
-}
-impl UserSchemaAttributeMasterType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UserSchemaAttributeMasterType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserSchemaAttributePermission {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub action: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub principal: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum UserSchemaAttributeType {
-    #[serde(rename = "array")]
-    Array,
-    #[serde(rename = "boolean")]
-    Boolean,
-    #[serde(rename = "integer")]
-    Integer,
-    #[serde(rename = "number")]
-    Number,
-    #[serde(rename = "string")]
-    String,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for UserSchemaAttributeType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            UserSchemaAttributeType::Array => "array",
-            UserSchemaAttributeType::Boolean => "boolean",
-            UserSchemaAttributeType::Integer => "integer",
-            UserSchemaAttributeType::Number => "number",
-            UserSchemaAttributeType::String => "string",
-            UserSchemaAttributeType::Noop => "",
-            UserSchemaAttributeType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for UserSchemaAttributeType {
-    fn default() -> UserSchemaAttributeType {
-        UserSchemaAttributeType::Noop
-    }
-}
-impl UserSchemaAttributeType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UserSchemaAttributeType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserSchemaBase {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<UserSchemaBaseProperties>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub required: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserSchemaBaseProperties {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub city: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "costCenter"
-    )]
-    pub cost_center: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "countryCode"
-    )]
-    pub country_code: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub department: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "displayName"
-    )]
-    pub display_name: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub division: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub email: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "employeeNumber"
-    )]
-    pub employee_number: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "firstName")]
-    pub first_name: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "honorificPrefix"
-    )]
-    pub honorific_prefix: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "honorificSuffix"
-    )]
-    pub honorific_suffix: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "lastName")]
-    pub last_name: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub locale: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub login: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub manager: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "managerId")]
-    pub manager_id: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "middleName"
-    )]
-    pub middle_name: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "mobilePhone"
-    )]
-    pub mobile_phone: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "nickName")]
-    pub nick_name: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub organization: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "postalAddress"
-    )]
-    pub postal_address: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "preferredLanguage"
-    )]
-    pub preferred_language: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "primaryPhone"
-    )]
-    pub primary_phone: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "profileUrl"
-    )]
-    pub profile_url: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "secondEmail"
-    )]
-    pub second_email: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub state: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "streetAddress"
-    )]
-    pub street_address: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub timezone: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub title: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userType")]
-    pub user_type: Option<UserSchemaAttribute>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "zipCode")]
-    pub zip_code: Option<UserSchemaAttribute>,
+
+impl AsRef<str> for UserSchemaAttributeMasterType {
+    fn as_ref(&self) -> &str {
+        match self {
+            UserSchemaAttributeMasterType::Okta => "OKTA",
+            UserSchemaAttributeMasterType::Override => "OVERRIDE",
+            UserSchemaAttributeMasterType::ProfileMaster => "PROFILE_MASTER",
+            UserSchemaAttributeMasterType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for UserSchemaAttributeMasterType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UserSchemaAttributeMasterType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "OKTA" => UserSchemaAttributeMasterType::Okta,
+            "OVERRIDE" => UserSchemaAttributeMasterType::Override,
+            "PROFILE_MASTER" => UserSchemaAttributeMasterType::ProfileMaster,
+            _ => UserSchemaAttributeMasterType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UserSchemaAttributeMasterType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UserSchemaAttributeMasterType> for String {
+    fn from(v: UserSchemaAttributeMasterType) -> Self {
+        match v {
+            UserSchemaAttributeMasterType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserSchemaDefinitions {
+pub struct UserSchemaAttributePermission {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub base: Option<UserSchemaBase>,
+    pub action: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub custom: Option<UserSchemaPublic>,
+    pub principal: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserSchemaProperties {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub profile: Option<UserSchemaPropertiesProfile>,
-}
+pub struct UserSchemaAttributeScope {}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserSchemaPropertiesProfile {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "allOf"
-    )]
-    pub all_of: Vec<UserSchemaPropertiesProfileItem>,
+#[serde(from = "String", into = "String")]
+pub enum UserSchemaAttributeType {
+    #[serde(rename = "array")]
+    Array,
+    #[serde(rename = "boolean")]
+    Boolean,
+    #[serde(rename = "integer")]
+    Integer,
+    #[serde(rename = "number")]
+    Number,
+    #[serde(rename = "string")]
+    String,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserSchemaPropertiesProfileItem {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "$ref"
-    )]
-    pub ref_: String,
+impl AsRef<str> for UserSchemaAttributeType {
+    fn as_ref(&self) -> &str {
+        match self {
+            UserSchemaAttributeType::Array => "array",
+            UserSchemaAttributeType::Boolean => "boolean",
+            UserSchemaAttributeType::Integer => "integer",
+            UserSchemaAttributeType::Number => "number",
+            UserSchemaAttributeType::String => "string",
+            UserSchemaAttributeType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for UserSchemaAttributeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UserSchemaAttributeType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "array" => UserSchemaAttributeType::Array,
+            "boolean" => UserSchemaAttributeType::Boolean,
+            "integer" => UserSchemaAttributeType::Integer,
+            "number" => UserSchemaAttributeType::Number,
+            "string" => UserSchemaAttributeType::String,
+            _ => UserSchemaAttributeType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UserSchemaAttributeType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UserSchemaAttributeType> for String {
+    fn from(v: UserSchemaAttributeType) -> Self {
+        match v {
+            UserSchemaAttributeType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserSchemaPublic {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub properties: Option<UserSchemaAttribute>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub required: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+pub struct UserSchemaAttributeUnion {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserSchemaPropertiesProfileItem {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "$ref")]
+    pub ref_: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum UserStatus {
+#[serde(from = "String", into = "String")]
+pub enum UserStatusPolicyRuleConditionValue {
+    #[serde(rename = "ACTIVATING")]
+    Activating,
     #[serde(rename = "ACTIVE")]
     Active,
-    #[serde(rename = "DEPROVISIONED")]
-    Deprovisioned,
-    #[serde(rename = "LOCKED_OUT")]
-    LockedOut,
-    #[serde(rename = "PASSWORD_EXPIRED")]
-    PasswordExpired,
-    #[serde(rename = "PROVISIONED")]
-    Provisioned,
-    #[serde(rename = "RECOVERY")]
-    Recovery,
-    #[serde(rename = "STAGED")]
-    Staged,
+    #[serde(rename = "DELETED")]
+    Deleted,
+    #[serde(rename = "DELETING")]
+    Deleting,
+    #[serde(rename = "EXPIRED_PASSWORD")]
+    ExpiredPassword,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    #[serde(rename = "PENDING")]
+    Pending,
     #[serde(rename = "SUSPENDED")]
     Suspended,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for UserStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            UserStatus::Active => "ACTIVE",
-            UserStatus::Deprovisioned => "DEPROVISIONED",
-            UserStatus::LockedOut => "LOCKED_OUT",
-            UserStatus::PasswordExpired => "PASSWORD_EXPIRED",
-            UserStatus::Provisioned => "PROVISIONED",
-            UserStatus::Recovery => "RECOVERY",
-            UserStatus::Staged => "STAGED",
-            UserStatus::Suspended => "SUSPENDED",
-            UserStatus::Noop => "",
-            UserStatus::FallthroughString => "*",
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for UserStatusPolicyRuleConditionValue {
+    fn as_ref(&self) -> &str {
+        match self {
+            UserStatusPolicyRuleConditionValue::Activating => "ACTIVATING",
+            UserStatusPolicyRuleConditionValue::Active => "ACTIVE",
+            UserStatusPolicyRuleConditionValue::Deleted => "DELETED",
+            UserStatusPolicyRuleConditionValue::Deleting => "DELETING",
+            UserStatusPolicyRuleConditionValue::ExpiredPassword => "EXPIRED_PASSWORD",
+            UserStatusPolicyRuleConditionValue::Inactive => "INACTIVE",
+            UserStatusPolicyRuleConditionValue::Pending => "PENDING",
+            UserStatusPolicyRuleConditionValue::Suspended => "SUSPENDED",
+            UserStatusPolicyRuleConditionValue::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for UserStatus {
-    fn default() -> UserStatus {
-        UserStatus::Noop
-    }
-}
-impl UserStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UserStatus::Noop)
+impl std::fmt::Display for UserStatusPolicyRuleConditionValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserStatusPolicyRuleCondition {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub value: Option<Value>,
+impl std::str::FromStr for UserStatusPolicyRuleConditionValue {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVATING" => UserStatusPolicyRuleConditionValue::Activating,
+            "ACTIVE" => UserStatusPolicyRuleConditionValue::Active,
+            "DELETED" => UserStatusPolicyRuleConditionValue::Deleted,
+            "DELETING" => UserStatusPolicyRuleConditionValue::Deleting,
+            "EXPIRED_PASSWORD" => UserStatusPolicyRuleConditionValue::ExpiredPassword,
+            "INACTIVE" => UserStatusPolicyRuleConditionValue::Inactive,
+            "PENDING" => UserStatusPolicyRuleConditionValue::Pending,
+            "SUSPENDED" => UserStatusPolicyRuleConditionValue::Suspended,
+            _ => UserStatusPolicyRuleConditionValue::Unknown(s.to_string()),
+        })
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserType {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "createdBy"
-    )]
-    pub created_by: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub default: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "displayName"
-    )]
-    pub display_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "lastUpdated"
-    )]
-    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "lastUpdatedBy"
-    )]
-    pub last_updated_by: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+impl std::convert::From<String> for UserStatusPolicyRuleConditionValue {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct VerifyFactorRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "activationToken"
-    )]
-    pub activation_token: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub answer: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub attestation: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "clientData"
-    )]
-    pub client_data: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "nextPassCode"
-    )]
-    pub next_pass_code: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "passCode"
-    )]
-    pub pass_code: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "registrationData"
-    )]
-    pub registration_data: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "stateToken"
-    )]
-    pub state_token: String,
+impl std::convert::From<UserStatusPolicyRuleConditionValue> for String {
+    fn from(v: UserStatusPolicyRuleConditionValue) -> Self {
+        match v {
+            UserStatusPolicyRuleConditionValue::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FactorResult {
     #[serde(rename = "CHALLENGE")]
     Challenge,
@@ -10487,15 +7746,15 @@ pub enum FactorResult {
     TimeWindowExceeded,
     #[serde(rename = "WAITING")]
     Waiting,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for FactorResult {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for FactorResult {
+    fn as_ref(&self) -> &str {
+        match self {
             FactorResult::Challenge => "CHALLENGE",
             FactorResult::Error => "ERROR",
             FactorResult::Expired => "EXPIRED",
@@ -10506,50 +7765,53 @@ impl std::fmt::Display for FactorResult {
             FactorResult::Timeout => "TIMEOUT",
             FactorResult::TimeWindowExceeded => "TIME_WINDOW_EXCEEDED",
             FactorResult::Waiting => "WAITING",
-            FactorResult::Noop => "",
-            FactorResult::FallthroughString => "*",
+            FactorResult::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for FactorResult {
-    fn default() -> FactorResult {
-        FactorResult::Noop
+impl std::fmt::Display for FactorResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl FactorResult {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FactorResult::Noop)
+
+impl std::str::FromStr for FactorResult {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CHALLENGE" => FactorResult::Challenge,
+            "ERROR" => FactorResult::Error,
+            "EXPIRED" => FactorResult::Expired,
+            "FAILED" => FactorResult::Failed,
+            "PASSCODE_REPLAYED" => FactorResult::PasscodeReplayed,
+            "REJECTED" => FactorResult::Rejected,
+            "SUCCESS" => FactorResult::Success,
+            "TIMEOUT" => FactorResult::Timeout,
+            "TIME_WINDOW_EXCEEDED" => FactorResult::TimeWindowExceeded,
+            "WAITING" => FactorResult::Waiting,
+            _ => FactorResult::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct VerifyUserFactorResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_embedded")]
-    pub embedded: Option<Links>,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_links")]
-    pub links: Option<Links>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize",
-        rename = "expiresAt"
-    )]
-    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        rename = "factorResult"
-    )]
-    pub factor_result: Option<FactorResult>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "factorResultMessage"
-    )]
-    pub factor_result_message: String,
+impl std::convert::From<String> for FactorResult {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<FactorResult> for String {
+    fn from(v: FactorResult) -> Self {
+        match v {
+            FactorResult::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -10582,6 +7844,17 @@ pub struct WebUserFactor {
     pub profile: Option<WebUserFactorProfile>,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WebUserFactorProfile {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "credentialId"
+    )]
+    pub credential_id: String,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct WsFederationApplication {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,765 @@
+//! The data types of the `applications` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppUser {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: std::collections::BTreeMap<String, Embedded>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, Links>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<AppUserCredentials>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "externalId"
+    )]
+    pub external_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastSync"
+    )]
+    pub last_sync: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "passwordChanged"
+    )]
+    pub password_changed: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub profile: std::collections::BTreeMap<String, Profile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "statusChanged"
+    )]
+    pub status_changed: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "syncState")]
+    pub sync_state: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppUserCredentials {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<AppUserPasswordCredential>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userName")]
+    pub user_name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppUserPasswordCredential {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Application {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: std::collections::BTreeMap<String, EmbeddedAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accessibility: Option<ApplicationAccessibility>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<ApplicationCredentials>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub features: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub licensing: Option<ApplicationLicensing>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub profile: std::collections::BTreeMap<String, ProfileAdditionalProperties>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<ApplicationSettings>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "signOnMode"
+    )]
+    pub sign_on_mode: Option<ApplicationSignOnMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<ApplicationVisibility>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApplicationAccessibility {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "errorRedirectUrl"
+    )]
+    pub error_redirect_url: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "loginRedirectUrl"
+    )]
+    pub login_redirect_url: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "selfService"
+    )]
+    pub self_service: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApplicationCredentials {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing: Option<ApplicationCredentialsSigning>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "userNameTemplate"
+    )]
+    pub user_name_template: Option<ApplicationCredentialsUsernameTemplate>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApplicationCredentialsUsernameTemplate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suffix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApplicationGroupAssignment {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: std::collections::BTreeMap<String, EmbeddedAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub priority: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub profile: std::collections::BTreeMap<String, ProfileAdditionalProperties>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApplicationLicensing {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "seatCount"
+    )]
+    pub seat_count: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApplicationSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<serde_json::Value>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "implicitAssignment"
+    )]
+    pub implicit_assignment: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "inlineHookId"
+    )]
+    pub inline_hook_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notifications: Option<ApplicationSettingsNotifications>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApplicationSettingsNotifications {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vpn: Option<ApplicationSettingsNotificationsVpn>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApplicationSettingsNotificationsVpn {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "helpUrl")]
+    pub help_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<ApplicationSettingsNotificationsVpnNetwork>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApplicationSettingsNotificationsVpnNetwork {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub exclude: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub include: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ApplicationSignOnMode {
+    #[serde(rename = "AUTO_LOGIN")]
+    AutoLogin,
+    #[serde(rename = "BASIC_AUTH")]
+    BasicAuth,
+    #[serde(rename = "BOOKMARK")]
+    Bookmark,
+    #[serde(rename = "BROWSER_PLUGIN")]
+    BrowserPlugin,
+    #[serde(rename = "OPENID_CONNECT")]
+    OpenidConnect,
+    #[serde(rename = "SAML_1_1")]
+    Saml1,
+    #[serde(rename = "SAML_2_0")]
+    Saml20,
+    #[serde(rename = "SECURE_PASSWORD_STORE")]
+    SecurePasswordStore,
+    #[serde(rename = "WS_FEDERATION")]
+    WsFederation,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ApplicationSignOnMode {
+    fn as_ref(&self) -> &str {
+        match self {
+            ApplicationSignOnMode::AutoLogin => "AUTO_LOGIN",
+            ApplicationSignOnMode::BasicAuth => "BASIC_AUTH",
+            ApplicationSignOnMode::Bookmark => "BOOKMARK",
+            ApplicationSignOnMode::BrowserPlugin => "BROWSER_PLUGIN",
+            ApplicationSignOnMode::OpenidConnect => "OPENID_CONNECT",
+            ApplicationSignOnMode::Saml1 => "SAML_1_1",
+            ApplicationSignOnMode::Saml20 => "SAML_2_0",
+            ApplicationSignOnMode::SecurePasswordStore => "SECURE_PASSWORD_STORE",
+            ApplicationSignOnMode::WsFederation => "WS_FEDERATION",
+            ApplicationSignOnMode::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ApplicationSignOnMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ApplicationSignOnMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AUTO_LOGIN" => ApplicationSignOnMode::AutoLogin,
+            "BASIC_AUTH" => ApplicationSignOnMode::BasicAuth,
+            "BOOKMARK" => ApplicationSignOnMode::Bookmark,
+            "BROWSER_PLUGIN" => ApplicationSignOnMode::BrowserPlugin,
+            "OPENID_CONNECT" => ApplicationSignOnMode::OpenidConnect,
+            "SAML_1_1" => ApplicationSignOnMode::Saml1,
+            "SAML_2_0" => ApplicationSignOnMode::Saml20,
+            "SECURE_PASSWORD_STORE" => ApplicationSignOnMode::SecurePasswordStore,
+            "WS_FEDERATION" => ApplicationSignOnMode::WsFederation,
+            _ => ApplicationSignOnMode::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ApplicationSignOnMode {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ApplicationSignOnMode> for String {
+    fn from(v: ApplicationSignOnMode) -> Self {
+        match v {
+            ApplicationSignOnMode::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApplicationVisibility {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "appLinks"
+    )]
+    pub app_links: std::collections::BTreeMap<String, bool>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "autoSubmitToolbar"
+    )]
+    pub auto_submit_toolbar: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide: Option<ApplicationVisibilityHide>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApplicationVisibilityHide {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "iOS"
+    )]
+    pub i_os: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub web: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Csr {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub csr: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub kty: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CsrMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<CsrMetadataSubject>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "subjectAltNames"
+    )]
+    pub subject_alt_names: Option<CsrMetadataSubjectAltNames>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CsrMetadataSubject {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "commonName"
+    )]
+    pub common_name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "countryName"
+    )]
+    pub country_name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "localityName"
+    )]
+    pub locality_name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "organizationName"
+    )]
+    pub organization_name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "organizationalUnitName"
+    )]
+    pub organizational_unit_name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "stateOrProvinceName"
+    )]
+    pub state_or_province_name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CsrMetadataSubjectAltNames {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "dnsNames"
+    )]
+    pub dns_names: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OAuth2Actor {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OAuth2ScopeConsentGrant {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: std::collections::BTreeMap<String, EmbeddedAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "clientId")]
+    pub client_id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "createdBy")]
+    pub created_by: Option<OAuth2Actor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "scopeId")]
+    pub scope_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<OAuth2ScopeConsentGrantSource>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OAuth2ScopeConsentGrantStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userId")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OAuth2ScopeConsentGrantSource {
+    #[serde(rename = "ADMIN")]
+    Admin,
+    #[serde(rename = "END_USER")]
+    EndUser,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OAuth2ScopeConsentGrantSource {
+    fn as_ref(&self) -> &str {
+        match self {
+            OAuth2ScopeConsentGrantSource::Admin => "ADMIN",
+            OAuth2ScopeConsentGrantSource::EndUser => "END_USER",
+            OAuth2ScopeConsentGrantSource::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OAuth2ScopeConsentGrantSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OAuth2ScopeConsentGrantSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ADMIN" => OAuth2ScopeConsentGrantSource::Admin,
+            "END_USER" => OAuth2ScopeConsentGrantSource::EndUser,
+            _ => OAuth2ScopeConsentGrantSource::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OAuth2ScopeConsentGrantSource {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OAuth2ScopeConsentGrantSource> for String {
+    fn from(v: OAuth2ScopeConsentGrantSource) -> Self {
+        match v {
+            OAuth2ScopeConsentGrantSource::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum OAuth2ScopeConsentGrantStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "REVOKED")]
+    Revoked,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OAuth2ScopeConsentGrantStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            OAuth2ScopeConsentGrantStatus::Active => "ACTIVE",
+            OAuth2ScopeConsentGrantStatus::Revoked => "REVOKED",
+            OAuth2ScopeConsentGrantStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OAuth2ScopeConsentGrantStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OAuth2ScopeConsentGrantStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => OAuth2ScopeConsentGrantStatus::Active,
+            "REVOKED" => OAuth2ScopeConsentGrantStatus::Revoked,
+            _ => OAuth2ScopeConsentGrantStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OAuth2ScopeConsentGrantStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OAuth2ScopeConsentGrantStatus> for String {
+    fn from(v: OAuth2ScopeConsentGrantStatus) -> Self {
+        match v {
+            OAuth2ScopeConsentGrantStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OAuth2Token {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: std::collections::BTreeMap<String, EmbeddedAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "clientId"
+    )]
+    pub client_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "expiresAt"
+    )]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issuer: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OAuth2TokenStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "userId"
+    )]
+    pub user_id: String,
+}
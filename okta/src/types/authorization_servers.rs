@@ -0,0 +1,1010 @@
+//! The data types of the `authorization_servers` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppInstancePolicyRuleCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub exclude: Vec<AppInstanceConditionEvaluatorOr>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub include: Vec<AppInstanceConditionEvaluatorOr>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppInstancePolicyRuleConditionData {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub exclude: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub include: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthorizationServer {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub audiences: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<AuthorizationServerCredentials>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "issuerMode"
+    )]
+    pub issuer_mode: Option<IssuerMode>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<AuthorizationServerStatus>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthorizationServerCredentials {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing: Option<AuthorizationServerCredentialsSigningConfig>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum AuthorizationServerCredentialsRotationMode {
+    #[serde(rename = "AUTO")]
+    Auto,
+    #[serde(rename = "MANUAL")]
+    Manual,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for AuthorizationServerCredentialsRotationMode {
+    fn as_ref(&self) -> &str {
+        match self {
+            AuthorizationServerCredentialsRotationMode::Auto => "AUTO",
+            AuthorizationServerCredentialsRotationMode::Manual => "MANUAL",
+            AuthorizationServerCredentialsRotationMode::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for AuthorizationServerCredentialsRotationMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AuthorizationServerCredentialsRotationMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AUTO" => AuthorizationServerCredentialsRotationMode::Auto,
+            "MANUAL" => AuthorizationServerCredentialsRotationMode::Manual,
+            _ => AuthorizationServerCredentialsRotationMode::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AuthorizationServerCredentialsRotationMode {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<AuthorizationServerCredentialsRotationMode> for String {
+    fn from(v: AuthorizationServerCredentialsRotationMode) -> Self {
+        match v {
+            AuthorizationServerCredentialsRotationMode::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthorizationServerCredentialsSigningConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastRotated"
+    )]
+    pub last_rotated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "nextRotation"
+    )]
+    pub next_rotation: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "rotationMode"
+    )]
+    pub rotation_mode: Option<AuthorizationServerCredentialsRotationMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "use")]
+    pub use_: Option<AuthorizationServerCredentialsUse>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum AuthorizationServerCredentialsUse {
+    #[serde(rename = "sig")]
+    Sig,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for AuthorizationServerCredentialsUse {
+    fn as_ref(&self) -> &str {
+        match self {
+            AuthorizationServerCredentialsUse::Sig => "sig",
+            AuthorizationServerCredentialsUse::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for AuthorizationServerCredentialsUse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AuthorizationServerCredentialsUse {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "sig" => AuthorizationServerCredentialsUse::Sig,
+            _ => AuthorizationServerCredentialsUse::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AuthorizationServerCredentialsUse {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<AuthorizationServerCredentialsUse> for String {
+    fn from(v: AuthorizationServerCredentialsUse) -> Self {
+        match v {
+            AuthorizationServerCredentialsUse::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthorizationServerPolicy {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: std::collections::BTreeMap<String, EmbeddedAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<PolicyRuleConditions>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub priority: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<AuthorizationServerPolicyStatus>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub system: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<PolicyType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthorizationServerPolicyRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<AuthorizationServerPolicyRuleActions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<AuthorizationServerPolicyRuleConditions>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub priority: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<AuthorizationServerPolicyRuleStatus>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub system: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<AuthorizationServerPolicyRuleType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthorizationServerPolicyRuleActions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<TokenAuthorizationServerPolicyRuleAction>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthorizationServerPolicyRuleConditions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clients: Option<ClientPolicyCondition>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "grantTypes"
+    )]
+    pub grant_types: Option<GrantTypePolicyRuleCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub people: Option<PolicyPeopleCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<OAuth2ScopesMediationPolicyRuleCondition>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BeforeScheduledActionPolicyRuleCondition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<Duration>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "lifecycleAction"
+    )]
+    pub lifecycle_action: Option<ScheduledUserLifecycleAction>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ClientPolicyCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub include: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContextPolicyRuleCondition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expression: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DevicePolicyRuleCondition {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub migrated: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub platform: Option<DevicePolicyRuleConditionPlatform>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub rooted: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "trustLevel"
+    )]
+    pub trust_level: Option<TrustLevel>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DevicePolicyRuleConditionPlatform {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "supportedMDMFrameworks"
+    )]
+    pub supported_mdm_frameworks: Vec<SupportedMdmFrameworks>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub types: Vec<Types>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Duration {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GrantTypePolicyRuleCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub include: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GroupPolicyRuleCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub exclude: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub include: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IdentityProviderPolicyRuleCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "idpIds"
+    )]
+    pub idp_ids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<Provider>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InactivityPolicyRuleCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct JwkUse {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "use")]
+    pub use_: Option<Use>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LifecycleExpirationPolicyRuleCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "lifecycleStatus"
+    )]
+    pub lifecycle_status: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MdmEnrollmentPolicyRuleCondition {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "blockNonSafeAndroid"
+    )]
+    pub block_non_safe_android: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enrollment: Option<Enrollment>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OAuth2Claim {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "alwaysIncludeInToken"
+    )]
+    pub always_include_in_token: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "claimType")]
+    pub claim_type: Option<ClaimType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<OAuth2ClaimConditions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_filter_type: Option<GroupFilterType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OAuth2ClaimStatus>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub system: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "valueType")]
+    pub value_type: Option<ValueType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OAuth2ClaimConditions {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OAuth2Client {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub client_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub client_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub client_uri: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub logo_uri: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OAuth2RefreshToken {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: std::collections::BTreeMap<String, EmbeddedAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "clientId"
+    )]
+    pub client_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "createdBy")]
+    pub created_by: Option<OAuth2Actor>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "expiresAt"
+    )]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub issuer: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OAuth2RefreshTokenStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "userId"
+    )]
+    pub user_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OAuth2Scope {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub consent: Option<Consent>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub default: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "displayName"
+    )]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "metadataPublish"
+    )]
+    pub metadata_publish: Option<MetadataPublish>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub system: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PasswordExpirationPolicyRuleCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub number: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PlatformPolicyRuleCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub exclude: Vec<PlatformConditionEvaluator>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub include: Vec<PlatformConditionEvaluator>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PolicyRuleConditions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub app: Option<AppInstancePolicyRuleCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apps: Option<AppInstancePolicyRuleConditionData>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authContext"
+    )]
+    pub auth_context: Option<PolicyRuleAuthContextCondition>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authProvider"
+    )]
+    pub auth_provider: Option<PasswordPolicyAuthenticationProviderConditionData>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "beforeScheduledAction"
+    )]
+    pub before_scheduled_action: Option<BeforeScheduledActionPolicyRuleCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clients: Option<ClientPolicyCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<ContextPolicyRuleCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device: Option<DevicePolicyRuleCondition>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "grantTypes"
+    )]
+    pub grant_types: Option<GrantTypePolicyRuleCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub groups: Option<GroupPolicyRuleCondition>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "identityProvider"
+    )]
+    pub identity_provider: Option<IdentityProviderPolicyRuleCondition>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "mdmEnrollment"
+    )]
+    pub mdm_enrollment: Option<MdmEnrollmentPolicyRuleCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<PolicyNetworkCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub people: Option<PolicyPeopleCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub platform: Option<PlatformPolicyRuleCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub risk: Option<RiskPolicyRuleCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "riskScore")]
+    pub risk_score: Option<RiskScorePolicyRuleCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<OAuth2ScopesMediationPolicyRuleCondition>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "userIdentifier"
+    )]
+    pub user_identifier: Option<UserIdentifierPolicyRuleCondition>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "userStatus"
+    )]
+    pub user_status: Option<UserStatusPolicyRuleCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub users: Option<UserPolicyRuleCondition>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PolicyType {
+    #[serde(rename = "IDP_DISCOVERY")]
+    IdpDiscovery,
+    #[serde(rename = "OAUTH_AUTHORIZATION_POLICY")]
+    OauthAuthorizationPolicy,
+    #[serde(rename = "OKTA_SIGN_ON")]
+    OktaSignOn,
+    #[serde(rename = "PASSWORD")]
+    Password,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PolicyType {
+    fn as_ref(&self) -> &str {
+        match self {
+            PolicyType::IdpDiscovery => "IDP_DISCOVERY",
+            PolicyType::OauthAuthorizationPolicy => "OAUTH_AUTHORIZATION_POLICY",
+            PolicyType::OktaSignOn => "OKTA_SIGN_ON",
+            PolicyType::Password => "PASSWORD",
+            PolicyType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PolicyType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PolicyType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "IDP_DISCOVERY" => PolicyType::IdpDiscovery,
+            "OAUTH_AUTHORIZATION_POLICY" => PolicyType::OauthAuthorizationPolicy,
+            "OKTA_SIGN_ON" => PolicyType::OktaSignOn,
+            "PASSWORD" => PolicyType::Password,
+            _ => PolicyType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PolicyType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PolicyType> for String {
+    fn from(v: PolicyType) -> Self {
+        match v {
+            PolicyType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RiskPolicyRuleCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub behaviors: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RiskScorePolicyRuleCondition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ScheduledUserLifecycleAction {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<ScheduledUserLifecycleActionStatus>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TokenAuthorizationServerPolicyRuleAction {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "accessTokenLifetimeMinutes"
+    )]
+    pub access_token_lifetime_minutes: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "inlineHook"
+    )]
+    pub inline_hook: Option<TokenAuthorizationServerPolicyRuleActionInlineHook>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "refreshTokenLifetimeMinutes"
+    )]
+    pub refresh_token_lifetime_minutes: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "refreshTokenWindowMinutes"
+    )]
+    pub refresh_token_window_minutes: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TokenAuthorizationServerPolicyRuleActionInlineHook {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserIdentifierPolicyRuleCondition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attribute: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub patterns: Vec<UserIdentifierConditionEvaluatorPattern>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<UserIdentifierPolicyRuleConditionType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserLifecycleAttributePolicyRuleCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "attributeName"
+    )]
+    pub attribute_name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "matchingValue"
+    )]
+    pub matching_value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserPolicyRuleCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub exclude: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inactivity: Option<InactivityPolicyRuleCondition>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub include: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "lifecycleExpiration"
+    )]
+    pub lifecycle_expiration: Option<LifecycleExpirationPolicyRuleCondition>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "passwordExpiration"
+    )]
+    pub password_expiration: Option<PasswordExpirationPolicyRuleCondition>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "userLifecycleAttribute"
+    )]
+    pub user_lifecycle_attribute: Option<UserLifecycleAttributePolicyRuleCondition>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserStatusPolicyRuleCondition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<UserStatusPolicyRuleConditionValue>,
+}
@@ -0,0 +1,90 @@
+//! The data types of the `domains` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DomainCertificate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "certificateChain"
+    )]
+    pub certificate_chain: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "privateKey"
+    )]
+    pub private_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<DomainCertificateType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum DomainCertificateType {
+    #[serde(rename = "PEM")]
+    Pem,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for DomainCertificateType {
+    fn as_ref(&self) -> &str {
+        match self {
+            DomainCertificateType::Pem => "PEM",
+            DomainCertificateType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DomainCertificateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DomainCertificateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "PEM" => DomainCertificateType::Pem,
+            _ => DomainCertificateType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DomainCertificateType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DomainCertificateType> for String {
+    fn from(v: DomainCertificateType) -> Self {
+        match v {
+            DomainCertificateType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DomainListResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub domains: Vec<Domain>,
+}
@@ -0,0 +1,152 @@
+//! The data types of the `event_hooks` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EventHook {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<EventHookChannel>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "createdBy")]
+    pub created_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub events: Option<EventSubscriptions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<EventHookStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "verificationStatus"
+    )]
+    pub verification_status: Option<VerificationStatus>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EventHookChannel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<EventHookChannelConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<EventHookChannelType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EventHookChannelConfig {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authScheme"
+    )]
+    pub auth_scheme: Option<EventHookChannelConfigAuthScheme>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub headers: Vec<EventHookChannelConfigHeader>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EventHookChannelConfigAuthScheme {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<EventHookChannelConfigAuthSchemeType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum EventHookChannelConfigAuthSchemeType {
+    #[serde(rename = "HEADER")]
+    Header,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for EventHookChannelConfigAuthSchemeType {
+    fn as_ref(&self) -> &str {
+        match self {
+            EventHookChannelConfigAuthSchemeType::Header => "HEADER",
+            EventHookChannelConfigAuthSchemeType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for EventHookChannelConfigAuthSchemeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EventHookChannelConfigAuthSchemeType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "HEADER" => EventHookChannelConfigAuthSchemeType::Header,
+            _ => EventHookChannelConfigAuthSchemeType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for EventHookChannelConfigAuthSchemeType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<EventHookChannelConfigAuthSchemeType> for String {
+    fn from(v: EventHookChannelConfigAuthSchemeType) -> Self {
+        match v {
+            EventHookChannelConfigAuthSchemeType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EventSubscriptions {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub items: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<EventSubscriptionsType>,
+}
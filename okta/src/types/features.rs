@@ -0,0 +1,221 @@
+//! The data types of the `features` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Feature {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage: Option<FeatureStage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<EnabledStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<FeatureType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FeatureStage {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<FeatureStageState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<FeatureStageValue>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum FeatureStageState {
+    #[serde(rename = "CLOSED")]
+    Closed,
+    #[serde(rename = "OPEN")]
+    Open,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for FeatureStageState {
+    fn as_ref(&self) -> &str {
+        match self {
+            FeatureStageState::Closed => "CLOSED",
+            FeatureStageState::Open => "OPEN",
+            FeatureStageState::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for FeatureStageState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for FeatureStageState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CLOSED" => FeatureStageState::Closed,
+            "OPEN" => FeatureStageState::Open,
+            _ => FeatureStageState::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for FeatureStageState {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<FeatureStageState> for String {
+    fn from(v: FeatureStageState) -> Self {
+        match v {
+            FeatureStageState::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum FeatureStageValue {
+    #[serde(rename = "BETA")]
+    Beta,
+    #[serde(rename = "EA")]
+    Ea,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for FeatureStageValue {
+    fn as_ref(&self) -> &str {
+        match self {
+            FeatureStageValue::Beta => "BETA",
+            FeatureStageValue::Ea => "EA",
+            FeatureStageValue::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for FeatureStageValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for FeatureStageValue {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BETA" => FeatureStageValue::Beta,
+            "EA" => FeatureStageValue::Ea,
+            _ => FeatureStageValue::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for FeatureStageValue {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<FeatureStageValue> for String {
+    fn from(v: FeatureStageValue) -> Self {
+        match v {
+            FeatureStageValue::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum FeatureType {
+    #[serde(rename = "self-service")]
+    SelfService,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for FeatureType {
+    fn as_ref(&self) -> &str {
+        match self {
+            FeatureType::SelfService => "self-service",
+            FeatureType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for FeatureType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for FeatureType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "self-service" => FeatureType::SelfService,
+            _ => FeatureType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for FeatureType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<FeatureType> for String {
+    fn from(v: FeatureType) -> Self {
+        match v {
+            FeatureType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
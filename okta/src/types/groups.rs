@@ -0,0 +1,1193 @@
+//! The data types of the `groups` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AssignRoleRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<RoleType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AuthenticationProvider {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<AuthenticationProviderType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum AuthenticationProviderType {
+    #[serde(rename = "ACTIVE_DIRECTORY")]
+    ActiveDirectory,
+    #[serde(rename = "FEDERATION")]
+    Federation,
+    #[serde(rename = "IMPORT")]
+    Import,
+    #[serde(rename = "LDAP")]
+    Ldap,
+    #[serde(rename = "OKTA")]
+    Okta,
+    #[serde(rename = "SOCIAL")]
+    Social,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for AuthenticationProviderType {
+    fn as_ref(&self) -> &str {
+        match self {
+            AuthenticationProviderType::ActiveDirectory => "ACTIVE_DIRECTORY",
+            AuthenticationProviderType::Federation => "FEDERATION",
+            AuthenticationProviderType::Import => "IMPORT",
+            AuthenticationProviderType::Ldap => "LDAP",
+            AuthenticationProviderType::Okta => "OKTA",
+            AuthenticationProviderType::Social => "SOCIAL",
+            AuthenticationProviderType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for AuthenticationProviderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AuthenticationProviderType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE_DIRECTORY" => AuthenticationProviderType::ActiveDirectory,
+            "FEDERATION" => AuthenticationProviderType::Federation,
+            "IMPORT" => AuthenticationProviderType::Import,
+            "LDAP" => AuthenticationProviderType::Ldap,
+            "OKTA" => AuthenticationProviderType::Okta,
+            "SOCIAL" => AuthenticationProviderType::Social,
+            _ => AuthenticationProviderType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AuthenticationProviderType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<AuthenticationProviderType> for String {
+    fn from(v: AuthenticationProviderType) -> Self {
+        match v {
+            AuthenticationProviderType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CatalogApplication {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub category: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "displayName"
+    )]
+    pub display_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub features: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "signOnModes"
+    )]
+    pub sign_on_modes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<CatalogApplicationStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "verificationStatus"
+    )]
+    pub verification_status: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub website: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum CatalogApplicationStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for CatalogApplicationStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            CatalogApplicationStatus::Active => "ACTIVE",
+            CatalogApplicationStatus::Inactive => "INACTIVE",
+            CatalogApplicationStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for CatalogApplicationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CatalogApplicationStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => CatalogApplicationStatus::Active,
+            "INACTIVE" => CatalogApplicationStatus::Inactive,
+            _ => CatalogApplicationStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CatalogApplicationStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<CatalogApplicationStatus> for String {
+    fn from(v: CatalogApplicationStatus) -> Self {
+        match v {
+            CatalogApplicationStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Group {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: std::collections::BTreeMap<String, EmbeddedAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastMembershipUpdated"
+    )]
+    pub last_membership_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "objectClass"
+    )]
+    pub object_class: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<GroupProfile>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<GroupType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GroupProfile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GroupRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<GroupRuleAction>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<GroupRuleConditions>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<GroupRuleStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GroupRuleAction {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "assignUserToGroups"
+    )]
+    pub assign_user_to_groups: Option<GroupRuleAssignment>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GroupRuleConditions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expression: Option<GroupRuleExpression>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub people: Option<GroupRulePeopleCondition>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GroupRuleExpression {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GroupRuleAssignment {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "groupIds"
+    )]
+    pub group_ids: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GroupRuleCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub exclude: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub include: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GroupRulePeopleCondition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub groups: Option<GroupRuleCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub users: Option<GroupRuleUserCondition>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum GroupRuleStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    #[serde(rename = "INVALID")]
+    Invalid,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for GroupRuleStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            GroupRuleStatus::Active => "ACTIVE",
+            GroupRuleStatus::Inactive => "INACTIVE",
+            GroupRuleStatus::Invalid => "INVALID",
+            GroupRuleStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for GroupRuleStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GroupRuleStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => GroupRuleStatus::Active,
+            "INACTIVE" => GroupRuleStatus::Inactive,
+            "INVALID" => GroupRuleStatus::Invalid,
+            _ => GroupRuleStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GroupRuleStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GroupRuleStatus> for String {
+    fn from(v: GroupRuleStatus) -> Self {
+        match v {
+            GroupRuleStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GroupRuleUserCondition {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub exclude: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub include: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum GroupType {
+    #[serde(rename = "APP_GROUP")]
+    AppGroup,
+    #[serde(rename = "BUILT_IN")]
+    BuiltIn,
+    #[serde(rename = "OKTA_GROUP")]
+    OktaGroup,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for GroupType {
+    fn as_ref(&self) -> &str {
+        match self {
+            GroupType::AppGroup => "APP_GROUP",
+            GroupType::BuiltIn => "BUILT_IN",
+            GroupType::OktaGroup => "OKTA_GROUP",
+            GroupType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for GroupType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GroupType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "APP_GROUP" => GroupType::AppGroup,
+            "BUILT_IN" => GroupType::BuiltIn,
+            "OKTA_GROUP" => GroupType::OktaGroup,
+            _ => GroupType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GroupType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GroupType> for String {
+    fn from(v: GroupType) -> Self {
+        match v {
+            GroupType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct RecoveryQuestionCredential {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub question: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Role {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: std::collections::BTreeMap<String, EmbeddedAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "assignmentType"
+    )]
+    pub assignment_type: Option<RoleAssignmentType>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub label: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<RoleStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<RoleType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum RoleAssignmentType {
+    #[serde(rename = "GROUP")]
+    Group,
+    #[serde(rename = "USER")]
+    User,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for RoleAssignmentType {
+    fn as_ref(&self) -> &str {
+        match self {
+            RoleAssignmentType::Group => "GROUP",
+            RoleAssignmentType::User => "USER",
+            RoleAssignmentType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for RoleAssignmentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for RoleAssignmentType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "GROUP" => RoleAssignmentType::Group,
+            "USER" => RoleAssignmentType::User,
+            _ => RoleAssignmentType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RoleAssignmentType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<RoleAssignmentType> for String {
+    fn from(v: RoleAssignmentType) -> Self {
+        match v {
+            RoleAssignmentType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum RoleStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for RoleStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            RoleStatus::Active => "ACTIVE",
+            RoleStatus::Inactive => "INACTIVE",
+            RoleStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for RoleStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for RoleStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => RoleStatus::Active,
+            "INACTIVE" => RoleStatus::Inactive,
+            _ => RoleStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RoleStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<RoleStatus> for String {
+    fn from(v: RoleStatus) -> Self {
+        match v {
+            RoleStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum RoleType {
+    #[serde(rename = "API_ACCESS_MANAGEMENT_ADMIN")]
+    ApiAccessManagementAdmin,
+    #[serde(rename = "APP_ADMIN")]
+    AppAdmin,
+    #[serde(rename = "HELP_DESK_ADMIN")]
+    HelpDeskAdmin,
+    #[serde(rename = "MOBILE_ADMIN")]
+    MobileAdmin,
+    #[serde(rename = "ORG_ADMIN")]
+    OrgAdmin,
+    #[serde(rename = "READ_ONLY_ADMIN")]
+    ReadOnlyAdmin,
+    #[serde(rename = "REPORT_ADMIN")]
+    ReportAdmin,
+    #[serde(rename = "SUPER_ADMIN")]
+    SuperAdmin,
+    #[serde(rename = "USER_ADMIN")]
+    UserAdmin,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for RoleType {
+    fn as_ref(&self) -> &str {
+        match self {
+            RoleType::ApiAccessManagementAdmin => "API_ACCESS_MANAGEMENT_ADMIN",
+            RoleType::AppAdmin => "APP_ADMIN",
+            RoleType::HelpDeskAdmin => "HELP_DESK_ADMIN",
+            RoleType::MobileAdmin => "MOBILE_ADMIN",
+            RoleType::OrgAdmin => "ORG_ADMIN",
+            RoleType::ReadOnlyAdmin => "READ_ONLY_ADMIN",
+            RoleType::ReportAdmin => "REPORT_ADMIN",
+            RoleType::SuperAdmin => "SUPER_ADMIN",
+            RoleType::UserAdmin => "USER_ADMIN",
+            RoleType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for RoleType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for RoleType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "API_ACCESS_MANAGEMENT_ADMIN" => RoleType::ApiAccessManagementAdmin,
+            "APP_ADMIN" => RoleType::AppAdmin,
+            "HELP_DESK_ADMIN" => RoleType::HelpDeskAdmin,
+            "MOBILE_ADMIN" => RoleType::MobileAdmin,
+            "ORG_ADMIN" => RoleType::OrgAdmin,
+            "READ_ONLY_ADMIN" => RoleType::ReadOnlyAdmin,
+            "REPORT_ADMIN" => RoleType::ReportAdmin,
+            "SUPER_ADMIN" => RoleType::SuperAdmin,
+            "USER_ADMIN" => RoleType::UserAdmin,
+            _ => RoleType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RoleType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<RoleType> for String {
+    fn from(v: RoleType) -> Self {
+        match v {
+            RoleType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct User {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: std::collections::BTreeMap<String, EmbeddedAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub activated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<UserCredentials>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastLogin"
+    )]
+    pub last_login: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "passwordChanged"
+    )]
+    pub password_changed: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<UserProfile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<UserStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "statusChanged"
+    )]
+    pub status_changed: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "transitioningToStatus"
+    )]
+    pub transitioning_to_status: Option<UserStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<UserType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserCredentials {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<PasswordCredential>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<AuthenticationProvider>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recovery_question: Option<RecoveryQuestionCredential>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserProfile {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "awsRole")]
+    pub aws_role: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub birthday: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "costCenter"
+    )]
+    pub cost_center: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "countryCode"
+    )]
+    pub country_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub department: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "displayName"
+    )]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub division: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "emailAliases"
+    )]
+    pub email_aliases: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "employeeNumber"
+    )]
+    pub employee_number: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "firstName")]
+    pub first_name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "githubUsername"
+    )]
+    pub github_username: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "honorificPrefix"
+    )]
+    pub honorific_prefix: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "honorificSuffix"
+    )]
+    pub honorific_suffix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "lastName")]
+    pub last_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub login: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manager: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "managerId")]
+    pub manager_id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "matrixUsername"
+    )]
+    pub matrix_username: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "middleName"
+    )]
+    pub middle_name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "mobilePhone"
+    )]
+    pub mobile_phone: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "nickName")]
+    pub nick_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "postalAddress"
+    )]
+    pub postal_address: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "preferredLanguage"
+    )]
+    pub preferred_language: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "primaryPhone"
+    )]
+    pub primary_phone: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "profileUrl"
+    )]
+    pub profile_url: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "secondEmail"
+    )]
+    pub second_email: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize",
+        rename = "startDate"
+    )]
+    pub start_date: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "streetAddress"
+    )]
+    pub street_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userType")]
+    pub user_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "workCity")]
+    pub work_city: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "workCountryCode"
+    )]
+    pub work_country_code: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "workPostalAddress"
+    )]
+    pub work_postal_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "workState")]
+    pub work_state: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "workStreetAddress"
+    )]
+    pub work_street_address: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "workZipCode"
+    )]
+    pub work_zip_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "zipCode")]
+    pub zip_code: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum UserStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "DEPROVISIONED")]
+    Deprovisioned,
+    #[serde(rename = "LOCKED_OUT")]
+    LockedOut,
+    #[serde(rename = "PASSWORD_EXPIRED")]
+    PasswordExpired,
+    #[serde(rename = "PROVISIONED")]
+    Provisioned,
+    #[serde(rename = "RECOVERY")]
+    Recovery,
+    #[serde(rename = "STAGED")]
+    Staged,
+    #[serde(rename = "SUSPENDED")]
+    Suspended,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for UserStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            UserStatus::Active => "ACTIVE",
+            UserStatus::Deprovisioned => "DEPROVISIONED",
+            UserStatus::LockedOut => "LOCKED_OUT",
+            UserStatus::PasswordExpired => "PASSWORD_EXPIRED",
+            UserStatus::Provisioned => "PROVISIONED",
+            UserStatus::Recovery => "RECOVERY",
+            UserStatus::Staged => "STAGED",
+            UserStatus::Suspended => "SUSPENDED",
+            UserStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for UserStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UserStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => UserStatus::Active,
+            "DEPROVISIONED" => UserStatus::Deprovisioned,
+            "LOCKED_OUT" => UserStatus::LockedOut,
+            "PASSWORD_EXPIRED" => UserStatus::PasswordExpired,
+            "PROVISIONED" => UserStatus::Provisioned,
+            "RECOVERY" => UserStatus::Recovery,
+            "STAGED" => UserStatus::Staged,
+            "SUSPENDED" => UserStatus::Suspended,
+            _ => UserStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UserStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UserStatus> for String {
+    fn from(v: UserStatus) -> Self {
+        match v {
+            UserStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserType {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "createdBy")]
+    pub created_by: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub default: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "displayName"
+    )]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "lastUpdatedBy"
+    )]
+    pub last_updated_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
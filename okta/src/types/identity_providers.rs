@@ -0,0 +1,549 @@
+//! The data types of the `identity_providers` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IdentityProvider {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "issuerMode"
+    )]
+    pub issuer_mode: Option<IdentityProviderIssuerMode>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<IdentityProviderPolicy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<Protocol>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<IdentityProviderStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<IdentityProviderType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IdentityProviderApplicationUser {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: std::collections::BTreeMap<String, EmbeddedAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "externalId"
+    )]
+    pub external_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: String,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub profile: std::collections::BTreeMap<String, ProfileAdditionalProperties>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IdentityProviderCredentials {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client: Option<IdentityProviderCredentialsClient>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signing: Option<IdentityProviderCredentialsSigning>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trust: Option<IdentityProviderCredentialsTrust>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IdentityProviderCredentialsClient {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IdentityProviderCredentialsSigning {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IdentityProviderCredentialsTrust {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub audience: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revocation: Option<Revocation>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "revocationCacheLifetime"
+    )]
+    pub revocation_cache_lifetime: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IdentityProviderPolicy {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "accountLink"
+    )]
+    pub account_link: Option<PolicyAccountLink>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "maxClockSkew"
+    )]
+    pub max_clock_skew: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provisioning: Option<Provisioning>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<PolicySubject>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PolicyAccountLink {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<Action>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<PolicyAccountLinkFilter>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PolicyAccountLinkFilter {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub groups: Option<PolicyAccountLinkFilterGroups>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PolicyAccountLinkFilterGroups {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub include: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PolicySubject {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub format: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "matchAttribute"
+    )]
+    pub match_attribute: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "matchType")]
+    pub match_type: Option<PolicySubjectMatchType>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "userNameTemplate"
+    )]
+    pub user_name_template: Option<PolicyUserNameTemplate>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PolicySubjectMatchType {
+    #[serde(rename = "CUSTOM_ATTRIBUTE")]
+    CustomAttribute,
+    #[serde(rename = "EMAIL")]
+    Email,
+    #[serde(rename = "USERNAME")]
+    Username,
+    #[serde(rename = "USERNAME_OR_EMAIL")]
+    UsernameOrEmail,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PolicySubjectMatchType {
+    fn as_ref(&self) -> &str {
+        match self {
+            PolicySubjectMatchType::CustomAttribute => "CUSTOM_ATTRIBUTE",
+            PolicySubjectMatchType::Email => "EMAIL",
+            PolicySubjectMatchType::Username => "USERNAME",
+            PolicySubjectMatchType::UsernameOrEmail => "USERNAME_OR_EMAIL",
+            PolicySubjectMatchType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PolicySubjectMatchType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PolicySubjectMatchType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CUSTOM_ATTRIBUTE" => PolicySubjectMatchType::CustomAttribute,
+            "EMAIL" => PolicySubjectMatchType::Email,
+            "USERNAME" => PolicySubjectMatchType::Username,
+            "USERNAME_OR_EMAIL" => PolicySubjectMatchType::UsernameOrEmail,
+            _ => PolicySubjectMatchType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PolicySubjectMatchType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PolicySubjectMatchType> for String {
+    fn from(v: PolicySubjectMatchType) -> Self {
+        match v {
+            PolicySubjectMatchType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PolicyUserNameTemplate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Protocol {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algorithms: Option<ProtocolAlgorithms>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credentials: Option<IdentityProviderCredentials>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub endpoints: Option<ProtocolEndpoints>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<ProtocolEndpoint>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "relayState"
+    )]
+    pub relay_state: Option<ProtocolRelayState>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<ProtocolSettings>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<ProtocolType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtocolAlgorithmType {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<ProtocolAlgorithmTypeSignature>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtocolAlgorithmTypeSignature {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<ProtocolAlgorithmTypeSignatureScope>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtocolAlgorithms {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request: Option<ProtocolAlgorithmType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<ProtocolAlgorithmType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtocolEndpoint {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub binding: Option<Binding>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<ProtocolEndpointType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtocolEndpoints {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acs: Option<ProtocolEndpoint>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<ProtocolEndpoint>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jwks: Option<ProtocolEndpoint>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ProtocolEndpoint>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slo: Option<ProtocolEndpoint>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sso: Option<ProtocolEndpoint>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<ProtocolEndpoint>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userInfo")]
+    pub user_info: Option<ProtocolEndpoint>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtocolRelayState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<ProtocolRelayStateFormat>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ProtocolRelayStateFormat {
+    #[serde(rename = "FROM_URL")]
+    FromUrl,
+    #[serde(rename = "OPAQUE")]
+    Opaque,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ProtocolRelayStateFormat {
+    fn as_ref(&self) -> &str {
+        match self {
+            ProtocolRelayStateFormat::FromUrl => "FROM_URL",
+            ProtocolRelayStateFormat::Opaque => "OPAQUE",
+            ProtocolRelayStateFormat::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ProtocolRelayStateFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ProtocolRelayStateFormat {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "FROM_URL" => ProtocolRelayStateFormat::FromUrl,
+            "OPAQUE" => ProtocolRelayStateFormat::Opaque,
+            _ => ProtocolRelayStateFormat::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ProtocolRelayStateFormat {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ProtocolRelayStateFormat> for String {
+    fn from(v: ProtocolRelayStateFormat) -> Self {
+        match v {
+            ProtocolRelayStateFormat::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProtocolSettings {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "nameFormat"
+    )]
+    pub name_format: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Provisioning {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<ProvisioningAction>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<ProvisioningConditions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub groups: Option<ProvisioningGroups>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "profileMaster"
+    )]
+    pub profile_master: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProvisioningConditions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deprovisioned: Option<ProvisioningDeprovisionedCondition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub suspended: Option<ProvisioningSuspendedCondition>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProvisioningDeprovisionedCondition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<ProvisioningDeprovisionedConditionAction>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProvisioningGroups {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<ProvisioningGroupsAction>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub assignments: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub filter: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "sourceAttributeName"
+    )]
+    pub source_attribute_name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProvisioningSuspendedCondition {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<ProvisioningSuspendedConditionAction>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SocialAuthToken {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "expiresAt"
+    )]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub token: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "tokenAuthScheme"
+    )]
+    pub token_auth_scheme: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "tokenType")]
+    pub token_type: Option<TokenType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserIdentityProviderLinkRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "externalId"
+    )]
+    pub external_id: Option<String>,
+}
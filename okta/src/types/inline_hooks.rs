@@ -0,0 +1,228 @@
+//! The data types of the `inline_hooks` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InlineHook {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<InlineHookChannel>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<InlineHookStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<InlineHookType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InlineHookChannel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<InlineHookChannelConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<InlineHookChannelType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InlineHookChannelConfig {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authScheme"
+    )]
+    pub auth_scheme: Option<InlineHookChannelConfigAuthScheme>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub headers: Vec<InlineHookChannelConfigHeaders>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InlineHookChannelConfigAuthScheme {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InlineHookPayload {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct InlineHookResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub commands: Vec<InlineHookResponseCommands>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum InlineHookStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for InlineHookStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            InlineHookStatus::Active => "ACTIVE",
+            InlineHookStatus::Inactive => "INACTIVE",
+            InlineHookStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for InlineHookStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for InlineHookStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => InlineHookStatus::Active,
+            "INACTIVE" => InlineHookStatus::Inactive,
+            _ => InlineHookStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for InlineHookStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<InlineHookStatus> for String {
+    fn from(v: InlineHookStatus) -> Self {
+        match v {
+            InlineHookStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum InlineHookType {
+    #[serde(rename = "com.okta.import.transform")]
+    ComOktaImportTransform,
+    #[serde(rename = "com.okta.oauth2.tokens.transform")]
+    ComOktaOauth2TokensTransform,
+    #[serde(rename = "com.okta.saml.tokens.transform")]
+    ComOktaSamlTokensTransform,
+    #[serde(rename = "com.okta.user.credential.password.import")]
+    ComOktaUserCredentialPasswordImport,
+    #[serde(rename = "com.okta.user.pre-registration")]
+    ComOktaUserPreRegistration,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for InlineHookType {
+    fn as_ref(&self) -> &str {
+        match self {
+            InlineHookType::ComOktaImportTransform => "com.okta.import.transform",
+            InlineHookType::ComOktaOauth2TokensTransform => "com.okta.oauth2.tokens.transform",
+            InlineHookType::ComOktaSamlTokensTransform => "com.okta.saml.tokens.transform",
+            InlineHookType::ComOktaUserCredentialPasswordImport => {
+                "com.okta.user.credential.password.import"
+            }
+            InlineHookType::ComOktaUserPreRegistration => "com.okta.user.pre-registration",
+            InlineHookType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for InlineHookType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for InlineHookType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "com.okta.import.transform" => InlineHookType::ComOktaImportTransform,
+            "com.okta.oauth2.tokens.transform" => InlineHookType::ComOktaOauth2TokensTransform,
+            "com.okta.saml.tokens.transform" => InlineHookType::ComOktaSamlTokensTransform,
+            "com.okta.user.credential.password.import" => {
+                InlineHookType::ComOktaUserCredentialPasswordImport
+            }
+            "com.okta.user.pre-registration" => InlineHookType::ComOktaUserPreRegistration,
+            _ => InlineHookType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for InlineHookType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<InlineHookType> for String {
+    fn from(v: InlineHookType) -> Self {
+        match v {
+            InlineHookType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
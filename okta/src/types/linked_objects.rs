@@ -0,0 +1,87 @@
+//! The data types of the `linked_objects` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LinkedObject {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub associated: Option<LinkedObjectDetails>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary: Option<LinkedObjectDetails>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LinkedObjectDetails {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<LinkedObjectDetailsType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum LinkedObjectDetailsType {
+    #[serde(rename = "USER")]
+    User,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for LinkedObjectDetailsType {
+    fn as_ref(&self) -> &str {
+        match self {
+            LinkedObjectDetailsType::User => "USER",
+            LinkedObjectDetailsType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for LinkedObjectDetailsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LinkedObjectDetailsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "USER" => LinkedObjectDetailsType::User,
+            _ => LinkedObjectDetailsType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LinkedObjectDetailsType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<LinkedObjectDetailsType> for String {
+    fn from(v: LinkedObjectDetailsType) -> Self {
+        match v {
+            LinkedObjectDetailsType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
@@ -0,0 +1,643 @@
+//! The data types of the `logs` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LogActor {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "alternateId"
+    )]
+    pub alternate_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub detail: std::collections::BTreeMap<String, Detail>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "displayName"
+    )]
+    pub display_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LogAuthenticationContext {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authenticationProvider"
+    )]
+    pub authentication_provider: Option<LogAuthenticationProvider>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "authenticationStep"
+    )]
+    pub authentication_step: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "credentialProvider"
+    )]
+    pub credential_provider: Option<LogCredentialProvider>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "credentialType"
+    )]
+    pub credential_type: Option<LogCredentialType>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "externalSessionId"
+    )]
+    pub external_session_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub interface: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer: Option<LogIssuer>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum LogAuthenticationProvider {
+    #[serde(rename = "ACTIVE_DIRECTORY")]
+    ActiveDirectory,
+    #[serde(rename = "FACTOR_PROVIDER")]
+    FactorProvider,
+    #[serde(rename = "FEDERATION")]
+    Federation,
+    #[serde(rename = "LDAP")]
+    Ldap,
+    #[serde(rename = "OKTA_AUTHENTICATION_PROVIDER")]
+    OktaAuthenticationProvider,
+    #[serde(rename = "SOCIAL")]
+    Social,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for LogAuthenticationProvider {
+    fn as_ref(&self) -> &str {
+        match self {
+            LogAuthenticationProvider::ActiveDirectory => "ACTIVE_DIRECTORY",
+            LogAuthenticationProvider::FactorProvider => "FACTOR_PROVIDER",
+            LogAuthenticationProvider::Federation => "FEDERATION",
+            LogAuthenticationProvider::Ldap => "LDAP",
+            LogAuthenticationProvider::OktaAuthenticationProvider => "OKTA_AUTHENTICATION_PROVIDER",
+            LogAuthenticationProvider::Social => "SOCIAL",
+            LogAuthenticationProvider::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for LogAuthenticationProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LogAuthenticationProvider {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE_DIRECTORY" => LogAuthenticationProvider::ActiveDirectory,
+            "FACTOR_PROVIDER" => LogAuthenticationProvider::FactorProvider,
+            "FEDERATION" => LogAuthenticationProvider::Federation,
+            "LDAP" => LogAuthenticationProvider::Ldap,
+            "OKTA_AUTHENTICATION_PROVIDER" => LogAuthenticationProvider::OktaAuthenticationProvider,
+            "SOCIAL" => LogAuthenticationProvider::Social,
+            _ => LogAuthenticationProvider::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LogAuthenticationProvider {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<LogAuthenticationProvider> for String {
+    fn from(v: LogAuthenticationProvider) -> Self {
+        match v {
+            LogAuthenticationProvider::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LogClient {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub device: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "geographicalContext"
+    )]
+    pub geographical_context: Option<LogGeographicalContext>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "ipAddress"
+    )]
+    pub ip_address: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userAgent")]
+    pub user_agent: Option<LogUserAgent>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub zone: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum LogCredentialProvider {
+    #[serde(rename = "DUO")]
+    Duo,
+    #[serde(rename = "GOOGLE")]
+    Google,
+    #[serde(rename = "OKTA_AUTHENTICATION_PROVIDER")]
+    OktaAuthenticationProvider,
+    #[serde(rename = "OKTA_CREDENTIAL_PROVIDER")]
+    OktaCredentialProvider,
+    #[serde(rename = "RSA")]
+    Rsa,
+    #[serde(rename = "SYMANTEC")]
+    Symantec,
+    #[serde(rename = "YUBIKEY")]
+    Yubikey,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for LogCredentialProvider {
+    fn as_ref(&self) -> &str {
+        match self {
+            LogCredentialProvider::Duo => "DUO",
+            LogCredentialProvider::Google => "GOOGLE",
+            LogCredentialProvider::OktaAuthenticationProvider => "OKTA_AUTHENTICATION_PROVIDER",
+            LogCredentialProvider::OktaCredentialProvider => "OKTA_CREDENTIAL_PROVIDER",
+            LogCredentialProvider::Rsa => "RSA",
+            LogCredentialProvider::Symantec => "SYMANTEC",
+            LogCredentialProvider::Yubikey => "YUBIKEY",
+            LogCredentialProvider::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for LogCredentialProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LogCredentialProvider {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DUO" => LogCredentialProvider::Duo,
+            "GOOGLE" => LogCredentialProvider::Google,
+            "OKTA_AUTHENTICATION_PROVIDER" => LogCredentialProvider::OktaAuthenticationProvider,
+            "OKTA_CREDENTIAL_PROVIDER" => LogCredentialProvider::OktaCredentialProvider,
+            "RSA" => LogCredentialProvider::Rsa,
+            "SYMANTEC" => LogCredentialProvider::Symantec,
+            "YUBIKEY" => LogCredentialProvider::Yubikey,
+            _ => LogCredentialProvider::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LogCredentialProvider {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<LogCredentialProvider> for String {
+    fn from(v: LogCredentialProvider) -> Self {
+        match v {
+            LogCredentialProvider::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum LogCredentialType {
+    #[serde(rename = "ASSERTION")]
+    Assertion,
+    #[serde(rename = "EMAIL")]
+    Email,
+    #[serde(rename = "IWA")]
+    Iwa,
+    #[serde(rename = "JWT")]
+    Jwt,
+    #[serde(rename = "OAUTH2")]
+    Oauth2,
+    #[serde(rename = "OTP")]
+    Otp,
+    #[serde(rename = "PASSWORD")]
+    Password,
+    #[serde(rename = "SMS")]
+    Sms,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for LogCredentialType {
+    fn as_ref(&self) -> &str {
+        match self {
+            LogCredentialType::Assertion => "ASSERTION",
+            LogCredentialType::Email => "EMAIL",
+            LogCredentialType::Iwa => "IWA",
+            LogCredentialType::Jwt => "JWT",
+            LogCredentialType::Oauth2 => "OAUTH2",
+            LogCredentialType::Otp => "OTP",
+            LogCredentialType::Password => "PASSWORD",
+            LogCredentialType::Sms => "SMS",
+            LogCredentialType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for LogCredentialType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LogCredentialType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ASSERTION" => LogCredentialType::Assertion,
+            "EMAIL" => LogCredentialType::Email,
+            "IWA" => LogCredentialType::Iwa,
+            "JWT" => LogCredentialType::Jwt,
+            "OAUTH2" => LogCredentialType::Oauth2,
+            "OTP" => LogCredentialType::Otp,
+            "PASSWORD" => LogCredentialType::Password,
+            "SMS" => LogCredentialType::Sms,
+            _ => LogCredentialType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LogCredentialType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<LogCredentialType> for String {
+    fn from(v: LogCredentialType) -> Self {
+        match v {
+            LogCredentialType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LogDebugContext {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "debugData"
+    )]
+    pub debug_data: std::collections::BTreeMap<String, DebugData>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LogEvent {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<LogActor>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "authenticationContext"
+    )]
+    pub authentication_context: Option<LogAuthenticationContext>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client: Option<LogClient>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "debugContext"
+    )]
+    pub debug_context: Option<LogDebugContext>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "displayMessage"
+    )]
+    pub display_message: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "eventType"
+    )]
+    pub event_type: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "legacyEventType"
+    )]
+    pub legacy_event_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outcome: Option<LogOutcome>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub published: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request: Option<LogRequest>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "securityContext"
+    )]
+    pub security_context: Option<LogSecurityContext>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<LogSeverity>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub target: Vec<LogTarget>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction: Option<LogTransaction>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uuid: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub version: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LogIssuer {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LogOutcome {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub result: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LogRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "ipChain"
+    )]
+    pub ip_chain: Vec<LogIpAddress>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LogSecurityContext {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "asNumber"
+    )]
+    pub as_number: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "asOrg"
+    )]
+    pub as_org: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub domain: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "isProxy"
+    )]
+    pub is_proxy: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub isp: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum LogSeverity {
+    #[serde(rename = "DEBUG")]
+    Debug,
+    #[serde(rename = "ERROR")]
+    Error,
+    #[serde(rename = "INFO")]
+    Info,
+    #[serde(rename = "WARN")]
+    Warn,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for LogSeverity {
+    fn as_ref(&self) -> &str {
+        match self {
+            LogSeverity::Debug => "DEBUG",
+            LogSeverity::Error => "ERROR",
+            LogSeverity::Info => "INFO",
+            LogSeverity::Warn => "WARN",
+            LogSeverity::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for LogSeverity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LogSeverity {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DEBUG" => LogSeverity::Debug,
+            "ERROR" => LogSeverity::Error,
+            "INFO" => LogSeverity::Info,
+            "WARN" => LogSeverity::Warn,
+            _ => LogSeverity::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LogSeverity {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<LogSeverity> for String {
+    fn from(v: LogSeverity) -> Self {
+        match v {
+            LogSeverity::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LogTransaction {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub detail: std::collections::BTreeMap<String, DetailAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LogUserAgent {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub browser: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub os: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "rawUserAgent"
+    )]
+    pub raw_user_agent: String,
+}
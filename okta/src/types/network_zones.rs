@@ -0,0 +1,247 @@
+//! The data types of the `network_zones` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct NetworkZone {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub asns: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub gateways: Vec<NetworkZoneAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub locations: Vec<NetworkZoneLocation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub proxies: Vec<NetworkZoneAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "proxyType")]
+    pub proxy_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<NetworkZoneStatus>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub system: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<NetworkZoneType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<NetworkZoneUsage>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum NetworkZoneStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "INACTIVE")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for NetworkZoneStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            NetworkZoneStatus::Active => "ACTIVE",
+            NetworkZoneStatus::Inactive => "INACTIVE",
+            NetworkZoneStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for NetworkZoneStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for NetworkZoneStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => NetworkZoneStatus::Active,
+            "INACTIVE" => NetworkZoneStatus::Inactive,
+            _ => NetworkZoneStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for NetworkZoneStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<NetworkZoneStatus> for String {
+    fn from(v: NetworkZoneStatus) -> Self {
+        match v {
+            NetworkZoneStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum NetworkZoneType {
+    #[serde(rename = "DYNAMIC")]
+    Dynamic,
+    #[serde(rename = "IP")]
+    Ip,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for NetworkZoneType {
+    fn as_ref(&self) -> &str {
+        match self {
+            NetworkZoneType::Dynamic => "DYNAMIC",
+            NetworkZoneType::Ip => "IP",
+            NetworkZoneType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for NetworkZoneType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for NetworkZoneType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DYNAMIC" => NetworkZoneType::Dynamic,
+            "IP" => NetworkZoneType::Ip,
+            _ => NetworkZoneType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for NetworkZoneType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<NetworkZoneType> for String {
+    fn from(v: NetworkZoneType) -> Self {
+        match v {
+            NetworkZoneType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum NetworkZoneUsage {
+    #[serde(rename = "BLOCKLIST")]
+    Blocklist,
+    #[serde(rename = "POLICY")]
+    Policy,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for NetworkZoneUsage {
+    fn as_ref(&self) -> &str {
+        match self {
+            NetworkZoneUsage::Blocklist => "BLOCKLIST",
+            NetworkZoneUsage::Policy => "POLICY",
+            NetworkZoneUsage::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for NetworkZoneUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for NetworkZoneUsage {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BLOCKLIST" => NetworkZoneUsage::Blocklist,
+            "POLICY" => NetworkZoneUsage::Policy,
+            _ => NetworkZoneUsage::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for NetworkZoneUsage {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<NetworkZoneUsage> for String {
+    fn from(v: NetworkZoneUsage) -> Self {
+        match v {
+            NetworkZoneUsage::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
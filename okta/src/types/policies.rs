@@ -0,0 +1,194 @@
+//! The data types of the `policies` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Policy {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_embedded"
+    )]
+    pub embedded: std::collections::BTreeMap<String, EmbeddedAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<PolicyRuleConditions>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub priority: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<PolicyStatus>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub system: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<PolicyType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PolicyRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<PolicyRuleActions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<PolicyRuleConditions>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub priority: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<PolicyRuleStatus>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub system: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<PolicyRuleType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PolicyRuleActions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enroll: Option<PolicyRuleActionsEnroll>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "passwordChange"
+    )]
+    pub password_change: Option<PasswordPolicyRuleAction>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "selfServicePasswordReset"
+    )]
+    pub self_service_password_reset: Option<PasswordPolicyRuleAction>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "selfServiceUnlock"
+    )]
+    pub self_service_unlock: Option<PasswordPolicyRuleAction>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signon: Option<OktaSignOnPolicyRuleSignonActions>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PolicyRuleActionsEnroll {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "self")]
+    pub self_: Option<PolicyRuleActionsEnrollSelf>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PolicyRuleActionsEnrollSelf {
+    #[serde(rename = "CHALLENGE")]
+    Challenge,
+    #[serde(rename = "LOGIN")]
+    Login,
+    #[serde(rename = "NEVER")]
+    Never,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PolicyRuleActionsEnrollSelf {
+    fn as_ref(&self) -> &str {
+        match self {
+            PolicyRuleActionsEnrollSelf::Challenge => "CHALLENGE",
+            PolicyRuleActionsEnrollSelf::Login => "LOGIN",
+            PolicyRuleActionsEnrollSelf::Never => "NEVER",
+            PolicyRuleActionsEnrollSelf::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PolicyRuleActionsEnrollSelf {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PolicyRuleActionsEnrollSelf {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CHALLENGE" => PolicyRuleActionsEnrollSelf::Challenge,
+            "LOGIN" => PolicyRuleActionsEnrollSelf::Login,
+            "NEVER" => PolicyRuleActionsEnrollSelf::Never,
+            _ => PolicyRuleActionsEnrollSelf::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PolicyRuleActionsEnrollSelf {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PolicyRuleActionsEnrollSelf> for String {
+    fn from(v: PolicyRuleActionsEnrollSelf) -> Self {
+        match v {
+            PolicyRuleActionsEnrollSelf::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
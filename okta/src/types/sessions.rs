@@ -0,0 +1,229 @@
+//! The data types of the `sessions` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CreateSessionRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "sessionToken"
+    )]
+    pub session_token: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Session {
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub amr: Vec<SessionAuthenticationMethod>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "createdAt"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "expiresAt"
+    )]
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idp: Option<SessionIdentityProvider>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastFactorVerification"
+    )]
+    pub last_factor_verification: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastPasswordVerification"
+    )]
+    pub last_password_verification: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub login: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<SessionStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "userId"
+    )]
+    pub user_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SessionIdentityProvider {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<SessionIdentityProviderType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SessionIdentityProviderType {
+    #[serde(rename = "ACTIVE_DIRECTORY")]
+    ActiveDirectory,
+    #[serde(rename = "FEDERATION")]
+    Federation,
+    #[serde(rename = "LDAP")]
+    Ldap,
+    #[serde(rename = "OKTA")]
+    Okta,
+    #[serde(rename = "SOCIAL")]
+    Social,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SessionIdentityProviderType {
+    fn as_ref(&self) -> &str {
+        match self {
+            SessionIdentityProviderType::ActiveDirectory => "ACTIVE_DIRECTORY",
+            SessionIdentityProviderType::Federation => "FEDERATION",
+            SessionIdentityProviderType::Ldap => "LDAP",
+            SessionIdentityProviderType::Okta => "OKTA",
+            SessionIdentityProviderType::Social => "SOCIAL",
+            SessionIdentityProviderType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SessionIdentityProviderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SessionIdentityProviderType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE_DIRECTORY" => SessionIdentityProviderType::ActiveDirectory,
+            "FEDERATION" => SessionIdentityProviderType::Federation,
+            "LDAP" => SessionIdentityProviderType::Ldap,
+            "OKTA" => SessionIdentityProviderType::Okta,
+            "SOCIAL" => SessionIdentityProviderType::Social,
+            _ => SessionIdentityProviderType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SessionIdentityProviderType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SessionIdentityProviderType> for String {
+    fn from(v: SessionIdentityProviderType) -> Self {
+        match v {
+            SessionIdentityProviderType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SessionStatus {
+    #[serde(rename = "ACTIVE")]
+    Active,
+    #[serde(rename = "MFA_ENROLL")]
+    MfaEnroll,
+    #[serde(rename = "MFA_REQUIRED")]
+    MfaRequired,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SessionStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            SessionStatus::Active => "ACTIVE",
+            SessionStatus::MfaEnroll => "MFA_ENROLL",
+            SessionStatus::MfaRequired => "MFA_REQUIRED",
+            SessionStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SessionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SessionStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACTIVE" => SessionStatus::Active,
+            "MFA_ENROLL" => SessionStatus::MfaEnroll,
+            "MFA_REQUIRED" => SessionStatus::MfaRequired,
+            _ => SessionStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SessionStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SessionStatus> for String {
+    fn from(v: SessionStatus) -> Self {
+        match v {
+            SessionStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! The data types of the `templates` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SmsTemplate {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translations: Option<SmsTemplateTranslations>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<SmsTemplateType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SmsTemplateTranslations {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SmsTemplateType {
+    #[serde(rename = "SMS_VERIFY_CODE")]
+    SmsVerifyCode,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SmsTemplateType {
+    fn as_ref(&self) -> &str {
+        match self {
+            SmsTemplateType::SmsVerifyCode => "SMS_VERIFY_CODE",
+            SmsTemplateType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SmsTemplateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SmsTemplateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "SMS_VERIFY_CODE" => SmsTemplateType::SmsVerifyCode,
+            _ => SmsTemplateType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SmsTemplateType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SmsTemplateType> for String {
+    fn from(v: SmsTemplateType) -> Self {
+        match v {
+            SmsTemplateType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
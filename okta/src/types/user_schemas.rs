@@ -0,0 +1,221 @@
+//! The data types of the `user_schemas` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserSchema {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "$schema")]
+    pub schema: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize",
+        rename = "_links"
+    )]
+    pub links: std::collections::BTreeMap<String, LinksAdditionalProperties>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub definitions: Option<UserSchemaDefinitions>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "lastUpdated"
+    )]
+    pub last_updated: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<UserSchemaProperties>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserSchemaBase {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<UserSchemaBaseProperties>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub required: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserSchemaBaseProperties {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "costCenter"
+    )]
+    pub cost_center: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "countryCode"
+    )]
+    pub country_code: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub department: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "displayName"
+    )]
+    pub display_name: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub division: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "employeeNumber"
+    )]
+    pub employee_number: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "firstName")]
+    pub first_name: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "honorificPrefix"
+    )]
+    pub honorific_prefix: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "honorificSuffix"
+    )]
+    pub honorific_suffix: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "lastName")]
+    pub last_name: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub login: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manager: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "managerId")]
+    pub manager_id: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "middleName"
+    )]
+    pub middle_name: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "mobilePhone"
+    )]
+    pub mobile_phone: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "nickName")]
+    pub nick_name: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "postalAddress"
+    )]
+    pub postal_address: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "preferredLanguage"
+    )]
+    pub preferred_language: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "primaryPhone"
+    )]
+    pub primary_phone: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "profileUrl"
+    )]
+    pub profile_url: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "secondEmail"
+    )]
+    pub second_email: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "streetAddress"
+    )]
+    pub street_address: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "userType")]
+    pub user_type: Option<UserSchemaAttribute>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "zipCode")]
+    pub zip_code: Option<UserSchemaAttribute>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserSchemaDefinitions {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base: Option<UserSchemaBase>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom: Option<UserSchemaPublic>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserSchemaProperties {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<UserSchemaPropertiesProfile>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserSchemaPropertiesProfile {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "allOf"
+    )]
+    pub all_of: Vec<UserSchemaPropertiesProfileItem>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct UserSchemaPublic {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub properties: std::collections::BTreeMap<String, UserSchemaAttribute>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub required: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
+}
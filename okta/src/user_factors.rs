@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct UserFactors {
     pub client: Client,
@@ -46,6 +44,42 @@ impl UserFactors {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors` endpoint.
+     *
+     * As opposed to `list_all_factors`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates all the enrolled factors for the specified user
+     */
+    pub fn list_factors_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserFactor> {
+        let url = format!(
+            "/api/v1/users/{}/factors",
+            crate::progenitor_support::encode_path(user_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Enroll Factor.
      *
@@ -56,34 +90,28 @@ impl UserFactors {
      * **Parameters:**
      *
      * * `user_id: &str`
-     * * `update_phone: bool`
-     * * `template_id: &str` -- id of SMS template (only for SMS factor).
-     * * `token_lifetime_seconds: i64`
-     * * `activate: bool`
+     * * `params: &UserFactorsEnrollFactorParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn enroll_factor(
+    pub async fn enroll_factor_with(
         &self,
         user_id: &str,
-        update_phone: bool,
-        template_id: &str,
-        token_lifetime_seconds: i64,
-        activate: bool,
+        params: &UserFactorsEnrollFactorParams,
         body: &crate::types::UserFactor,
     ) -> Result<crate::types::UserFactor> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if activate {
+        if let Some(activate) = &params.activate {
             query_args.push(("activate".to_string(), activate.to_string()));
         }
-        if !template_id.is_empty() {
+        if let Some(template_id) = &params.template_id {
             query_args.push(("templateId".to_string(), template_id.to_string()));
         }
-        if token_lifetime_seconds > 0 {
+        if let Some(token_lifetime_seconds) = &params.token_lifetime_seconds {
             query_args.push((
                 "tokenLifetimeSeconds".to_string(),
                 token_lifetime_seconds.to_string(),
             ));
         }
-        if update_phone {
+        if let Some(update_phone) = &params.update_phone {
             query_args.push(("updatePhone".to_string(), update_phone.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -98,6 +126,28 @@ impl UserFactors {
             .await
     }
 
+    /**
+     * Deprecated: use `enroll_factor_with` and pass the query parameters as a `UserFactorsEnrollFactorParams` instead.
+     */
+    #[deprecated(note = "use `enroll_factor_with` instead")]
+    pub async fn enroll_factor(
+        &self,
+        user_id: &str,
+        update_phone: bool,
+        template_id: &str,
+        token_lifetime_seconds: i64,
+        activate: bool,
+        body: &crate::types::UserFactor,
+    ) -> Result<crate::types::UserFactor> {
+        let params = UserFactorsEnrollFactorParams {
+            activate: Some(activate).filter(|v| *v),
+            template_id: Some(template_id.to_string()).filter(|s| !s.is_empty()),
+            token_lifetime_seconds: Some(token_lifetime_seconds).filter(|v| *v > 0),
+            update_phone: Some(update_phone).filter(|v| *v),
+        };
+        self.enroll_factor_with(user_id, &params, body).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/factors/catalog` endpoint.
      *
@@ -138,6 +188,42 @@ impl UserFactors {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/catalog` endpoint.
+     *
+     * As opposed to `list_all_supported_factors`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates all the supported factors that can be enrolled for the specified user
+     */
+    pub fn list_supported_factors_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserFactor> {
+        let url = format!(
+            "/api/v1/users/{}/factors/catalog",
+            crate::progenitor_support::encode_path(user_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/factors/questions` endpoint.
      *
@@ -178,6 +264,42 @@ impl UserFactors {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/factors/questions` endpoint.
+     *
+     * As opposed to `list_all_supported_security_questions`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Enumerates all available security questions for a user's `question` factor
+     */
+    pub fn list_supported_security_questions_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SecurityQuestion> {
+        let url = format!(
+            "/api/v1/users/{}/factors/questions",
+            crate::progenitor_support::encode_path(user_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/factors/{factorId}` endpoint.
      *
@@ -289,25 +411,20 @@ impl UserFactors {
      *
      * * `user_id: &str`
      * * `factor_id: &str`
-     * * `template_id: &str`
-     * * `token_lifetime_seconds: i64`
-     * * `x_forwarded_for: &str`
-     * * `user_agent: &str`
-     * * `accept_language: &str`
+     * * `params: &UserFactorsVerifyFactorParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn verify_factor(
+    pub async fn verify_factor_with(
         &self,
         user_id: &str,
         factor_id: &str,
-        template_id: &str,
-        token_lifetime_seconds: i64,
+        params: &UserFactorsVerifyFactorParams,
         body: &crate::types::VerifyFactorRequest,
     ) -> Result<crate::types::VerifyUserFactorResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !template_id.is_empty() {
+        if let Some(template_id) = &params.template_id {
             query_args.push(("templateId".to_string(), template_id.to_string()));
         }
-        if token_lifetime_seconds > 0 {
+        if let Some(token_lifetime_seconds) = &params.token_lifetime_seconds {
             query_args.push((
                 "tokenLifetimeSeconds".to_string(),
                 token_lifetime_seconds.to_string(),
@@ -325,4 +442,279 @@ impl UserFactors {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `verify_factor_with` and pass the query parameters as a `UserFactorsVerifyFactorParams` instead.
+     */
+    #[deprecated(note = "use `verify_factor_with` instead")]
+    pub async fn verify_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        template_id: &str,
+        token_lifetime_seconds: i64,
+        body: &crate::types::VerifyFactorRequest,
+    ) -> Result<crate::types::VerifyUserFactorResponse> {
+        let params = UserFactorsVerifyFactorParams {
+            template_id: Some(template_id.to_string()).filter(|s| !s.is_empty()),
+            token_lifetime_seconds: Some(token_lifetime_seconds).filter(|v| *v > 0),
+        };
+        self.verify_factor_with(user_id, factor_id, &params, body)
+            .await
+    }
+}
+
+/// The query parameters of `UserFactors::enroll_factor_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UserFactorsEnrollFactorParams {
+    pub activate: Option<bool>,
+    /// id of SMS template (only for SMS factor).
+    pub template_id: Option<String>,
+    pub token_lifetime_seconds: Option<i64>,
+    pub update_phone: Option<bool>,
+}
+
+impl UserFactorsEnrollFactorParams {
+    pub fn activate(mut self, activate: bool) -> Self {
+        self.activate = Some(activate);
+        self
+    }
+
+    pub fn template_id<T: ToString>(mut self, template_id: T) -> Self {
+        self.template_id = Some(template_id.to_string());
+        self
+    }
+
+    pub fn token_lifetime_seconds(mut self, token_lifetime_seconds: i64) -> Self {
+        self.token_lifetime_seconds = Some(token_lifetime_seconds);
+        self
+    }
+
+    pub fn update_phone(mut self, update_phone: bool) -> Self {
+        self.update_phone = Some(update_phone);
+        self
+    }
+}
+
+/// The query parameters of `UserFactors::verify_factor_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UserFactorsVerifyFactorParams {
+    pub template_id: Option<String>,
+    pub token_lifetime_seconds: Option<i64>,
+}
+
+impl UserFactorsVerifyFactorParams {
+    pub fn template_id<T: ToString>(mut self, template_id: T) -> Self {
+        self.template_id = Some(template_id.to_string());
+        self
+    }
+
+    pub fn token_lifetime_seconds(mut self, token_lifetime_seconds: i64) -> Self {
+        self.token_lifetime_seconds = Some(token_lifetime_seconds);
+        self
+    }
+}
+
+/// The functions of [`UserFactors`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUserFactorsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UserFactorsApi: Send + Sync {
+    /// See [`UserFactors::list_factors`].
+    async fn list_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>>;
+
+    /// See [`UserFactors::list_all_factors`].
+    async fn list_all_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>>;
+
+    /// See [`UserFactors::list_factors_stream`].
+    fn list_factors_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserFactor>;
+
+    /// See [`UserFactors::enroll_factor_with`].
+    async fn enroll_factor_with(
+        &self,
+        user_id: &str,
+        params: &UserFactorsEnrollFactorParams,
+        body: &crate::types::UserFactor,
+    ) -> Result<crate::types::UserFactor>;
+
+    /// See [`UserFactors::list_supported_factors`].
+    async fn list_supported_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>>;
+
+    /// See [`UserFactors::list_all_supported_factors`].
+    async fn list_all_supported_factors(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::UserFactor>>;
+
+    /// See [`UserFactors::list_supported_factors_stream`].
+    fn list_supported_factors_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserFactor>;
+
+    /// See [`UserFactors::list_supported_security_questions`].
+    async fn list_supported_security_questions(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SecurityQuestion>>;
+
+    /// See [`UserFactors::list_all_supported_security_questions`].
+    async fn list_all_supported_security_questions(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SecurityQuestion>>;
+
+    /// See [`UserFactors::list_supported_security_questions_stream`].
+    fn list_supported_security_questions_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SecurityQuestion>;
+
+    /// See [`UserFactors::get_factor`].
+    async fn get_factor(&self, user_id: &str, factor_id: &str) -> Result<crate::types::UserFactor>;
+
+    /// See [`UserFactors::delete_factor`].
+    async fn delete_factor(&self, user_id: &str, factor_id: &str) -> Result<()>;
+
+    /// See [`UserFactors::activate_factor`].
+    async fn activate_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        body: &crate::types::ActivateFactorRequest,
+    ) -> Result<crate::types::UserFactor>;
+
+    /// See [`UserFactors::get_factor_transaction_status`].
+    async fn get_factor_transaction_status(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        transaction_id: &str,
+    ) -> Result<crate::types::VerifyUserFactorResponse>;
+
+    /// See [`UserFactors::verify_factor_with`].
+    async fn verify_factor_with(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        params: &UserFactorsVerifyFactorParams,
+        body: &crate::types::VerifyFactorRequest,
+    ) -> Result<crate::types::VerifyUserFactorResponse>;
+}
+
+#[async_trait::async_trait]
+impl UserFactorsApi for UserFactors {
+    async fn list_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>> {
+        UserFactors::list_factors(self, user_id).await
+    }
+
+    async fn list_all_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>> {
+        UserFactors::list_all_factors(self, user_id).await
+    }
+
+    fn list_factors_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserFactor> {
+        UserFactors::list_factors_stream(self, user_id, start, max_items)
+    }
+
+    async fn enroll_factor_with(
+        &self,
+        user_id: &str,
+        params: &UserFactorsEnrollFactorParams,
+        body: &crate::types::UserFactor,
+    ) -> Result<crate::types::UserFactor> {
+        UserFactors::enroll_factor_with(self, user_id, params, body).await
+    }
+
+    async fn list_supported_factors(&self, user_id: &str) -> Result<Vec<crate::types::UserFactor>> {
+        UserFactors::list_supported_factors(self, user_id).await
+    }
+
+    async fn list_all_supported_factors(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::UserFactor>> {
+        UserFactors::list_all_supported_factors(self, user_id).await
+    }
+
+    fn list_supported_factors_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserFactor> {
+        UserFactors::list_supported_factors_stream(self, user_id, start, max_items)
+    }
+
+    async fn list_supported_security_questions(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SecurityQuestion>> {
+        UserFactors::list_supported_security_questions(self, user_id).await
+    }
+
+    async fn list_all_supported_security_questions(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::SecurityQuestion>> {
+        UserFactors::list_all_supported_security_questions(self, user_id).await
+    }
+
+    fn list_supported_security_questions_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SecurityQuestion> {
+        UserFactors::list_supported_security_questions_stream(self, user_id, start, max_items)
+    }
+
+    async fn get_factor(&self, user_id: &str, factor_id: &str) -> Result<crate::types::UserFactor> {
+        UserFactors::get_factor(self, user_id, factor_id).await
+    }
+
+    async fn delete_factor(&self, user_id: &str, factor_id: &str) -> Result<()> {
+        UserFactors::delete_factor(self, user_id, factor_id).await
+    }
+
+    async fn activate_factor(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        body: &crate::types::ActivateFactorRequest,
+    ) -> Result<crate::types::UserFactor> {
+        UserFactors::activate_factor(self, user_id, factor_id, body).await
+    }
+
+    async fn get_factor_transaction_status(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        transaction_id: &str,
+    ) -> Result<crate::types::VerifyUserFactorResponse> {
+        UserFactors::get_factor_transaction_status(self, user_id, factor_id, transaction_id).await
+    }
+
+    async fn verify_factor_with(
+        &self,
+        user_id: &str,
+        factor_id: &str,
+        params: &UserFactorsVerifyFactorParams,
+        body: &crate::types::VerifyFactorRequest,
+    ) -> Result<crate::types::VerifyUserFactorResponse> {
+        UserFactors::verify_factor_with(self, user_id, factor_id, params, body).await
+    }
 }
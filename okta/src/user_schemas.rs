@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct UserSchemas {
     pub client: Client,
@@ -102,3 +100,56 @@ impl UserSchemas {
             .await
     }
 }
+
+/// The functions of [`UserSchemas`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUserSchemasApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UserSchemasApi: Send + Sync {
+    /// See [`UserSchemas::get_application`].
+    async fn get_application(&self, app_instance_id: &str) -> Result<crate::types::UserSchema>;
+
+    /// See [`UserSchemas::update_application_user_profile`].
+    async fn update_application_user_profile(
+        &self,
+        app_instance_id: &str,
+        body: &crate::types::UserSchema,
+    ) -> Result<crate::types::UserSchema>;
+
+    /// See [`UserSchemas::get`].
+    async fn get(&self, schema_id: &str) -> Result<crate::types::UserSchema>;
+
+    /// See [`UserSchemas::update_user_profile`].
+    async fn update_user_profile(
+        &self,
+        schema_id: &str,
+        body: &crate::types::UserSchema,
+    ) -> Result<crate::types::UserSchema>;
+}
+
+#[async_trait::async_trait]
+impl UserSchemasApi for UserSchemas {
+    async fn get_application(&self, app_instance_id: &str) -> Result<crate::types::UserSchema> {
+        UserSchemas::get_application(self, app_instance_id).await
+    }
+
+    async fn update_application_user_profile(
+        &self,
+        app_instance_id: &str,
+        body: &crate::types::UserSchema,
+    ) -> Result<crate::types::UserSchema> {
+        UserSchemas::update_application_user_profile(self, app_instance_id, body).await
+    }
+
+    async fn get(&self, schema_id: &str) -> Result<crate::types::UserSchema> {
+        UserSchemas::get(self, schema_id).await
+    }
+
+    async fn update_user_profile(
+        &self,
+        schema_id: &str,
+        body: &crate::types::UserSchema,
+    ) -> Result<crate::types::UserSchema> {
+        UserSchemas::update_user_profile(self, schema_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct UserTypes {
     pub client: Client,
@@ -34,6 +32,37 @@ impl UserTypes {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/meta/types/user` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Fetches all User Types in your org
+     */
+    pub fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserType> {
+        let url = "/api/v1/meta/types/user".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `POST` to the `/api/v1/meta/types/user` endpoint.
      *
@@ -130,3 +159,92 @@ impl UserTypes {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`UserTypes`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUserTypesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UserTypesApi: Send + Sync {
+    /// See [`UserTypes::list`].
+    async fn list(&self) -> Result<Vec<crate::types::UserType>>;
+
+    /// See [`UserTypes::list_all`].
+    async fn list_all(&self) -> Result<Vec<crate::types::UserType>>;
+
+    /// See [`UserTypes::list_stream`].
+    fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserType>;
+
+    /// See [`UserTypes::create`].
+    async fn create(&self, body: &crate::types::UserType) -> Result<crate::types::UserType>;
+
+    /// See [`UserTypes::get`].
+    async fn get(&self, type_id: &str) -> Result<crate::types::UserType>;
+
+    /// See [`UserTypes::replace`].
+    async fn replace(
+        &self,
+        type_id: &str,
+        body: &crate::types::UserType,
+    ) -> Result<crate::types::UserType>;
+
+    /// See [`UserTypes::update`].
+    async fn update(
+        &self,
+        type_id: &str,
+        body: &crate::types::UserType,
+    ) -> Result<crate::types::UserType>;
+
+    /// See [`UserTypes::delete`].
+    async fn delete(&self, type_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl UserTypesApi for UserTypes {
+    async fn list(&self) -> Result<Vec<crate::types::UserType>> {
+        UserTypes::list(self).await
+    }
+
+    async fn list_all(&self) -> Result<Vec<crate::types::UserType>> {
+        UserTypes::list_all(self).await
+    }
+
+    fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserType> {
+        UserTypes::list_stream(self, start, max_items)
+    }
+
+    async fn create(&self, body: &crate::types::UserType) -> Result<crate::types::UserType> {
+        UserTypes::create(self, body).await
+    }
+
+    async fn get(&self, type_id: &str) -> Result<crate::types::UserType> {
+        UserTypes::get(self, type_id).await
+    }
+
+    async fn replace(
+        &self,
+        type_id: &str,
+        body: &crate::types::UserType,
+    ) -> Result<crate::types::UserType> {
+        UserTypes::replace(self, type_id, body).await
+    }
+
+    async fn update(
+        &self,
+        type_id: &str,
+        body: &crate::types::UserType,
+    ) -> Result<crate::types::UserType> {
+        UserTypes::update(self, type_id, body).await
+    }
+
+    async fn delete(&self, type_id: &str) -> Result<()> {
+        UserTypes::delete(self, type_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Users {
     pub client: Client,
@@ -21,44 +19,29 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `q: &str` -- Finds a user that matches firstName, lastName, and email properties.
-     * * `after: &str` -- Specifies the pagination cursor for the next page of users.
-     * * `limit: i64` -- Specifies the number of results returned.
-     * * `filter: &str` -- Filters users with a supported expression for a subset of properties.
-     * * `search: &str` -- Searches for users with a supported filtering  expression for most properties.
-     * * `sort_by: &str`
-     * * `sort_order: &str`
+     * * `params: &UsersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
-        &self,
-        q: &str,
-        after: &str,
-        limit: i64,
-        filter: &str,
-        search: &str,
-        sort_by: &str,
-        sort_order: &str,
-    ) -> Result<Vec<crate::types::User>> {
+    pub async fn list_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::User>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !search.is_empty() {
+        if let Some(search) = &params.search {
             query_args.push(("search".to_string(), search.to_string()));
         }
-        if !sort_by.is_empty() {
+        if let Some(sort_by) = &params.sort_by {
             query_args.push(("sortBy".to_string(), sort_by.to_string()));
         }
-        if !sort_order.is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sortOrder".to_string(), sort_order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -67,6 +50,32 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `UsersListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        q: &str,
+        after: &str,
+        limit: i64,
+        filter: &str,
+        search: &str,
+        sort_by: &str,
+        sort_order: &str,
+    ) -> Result<Vec<crate::types::User>> {
+        let params = UsersListParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            search: Some(search.to_string()).filter(|s| !s.is_empty()),
+            sort_by: Some(sort_by.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * List Users.
      *
@@ -75,7 +84,38 @@ impl Users {
      * As opposed to `list`, this function returns all the pages of the request at once.
      *
      * Lists users in your organization with pagination in most cases.  A subset of users can be returned that match a supported filter expression or search criteria.
+     *
+     * **Parameters:**
+     *
+     * * `params: &UsersListParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::User>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(filter) = &params.filter {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if let Some(q) = &params.q {
+            query_args.push(("q".to_string(), q.to_string()));
+        }
+        if let Some(search) = &params.search {
+            query_args.push(("search".to_string(), search.to_string()));
+        }
+        if let Some(sort_by) = &params.sort_by {
+            query_args.push(("sortBy".to_string(), sort_by.to_string()));
+        }
+        if let Some(sort_order) = &params.sort_order {
+            query_args.push(("sortOrder".to_string(), sort_order.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/api/v1/users?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `UsersListParams` instead.
      */
+    #[deprecated(note = "use `list_all_with` instead")]
     pub async fn list_all(
         &self,
         q: &str,
@@ -84,26 +124,67 @@ impl Users {
         sort_by: &str,
         sort_order: &str,
     ) -> Result<Vec<crate::types::User>> {
+        let params = UsersListParams {
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            q: Some(q.to_string()).filter(|s| !s.is_empty()),
+            search: Some(search.to_string()).filter(|s| !s.is_empty()),
+            sort_by: Some(sort_by.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * List Users.
+     *
+     * This function performs a `GET` to the `/api/v1/users` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists users in your organization with pagination in most cases.  A subset of users can be returned that match a supported filter expression or search criteria.
+     */
+    pub fn list_stream(
+        &self,
+        params: &UsersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::User> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !q.is_empty() {
+        if let Some(q) = &params.q {
             query_args.push(("q".to_string(), q.to_string()));
         }
-        if !search.is_empty() {
+        if let Some(search) = &params.search {
             query_args.push(("search".to_string(), search.to_string()));
         }
-        if !sort_by.is_empty() {
+        if let Some(sort_by) = &params.sort_by {
             query_args.push(("sortBy".to_string(), sort_by.to_string()));
         }
-        if !sort_order.is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sortOrder".to_string(), sort_order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/api/v1/users?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -115,25 +196,21 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `activate: bool` -- Executes activation lifecycle operation when creating the user.
-     * * `provider: bool` -- Indicates whether to create a user with a specified authentication provider.
-     * * `next_login: &str` -- With activate=true, set nextLogin to "changePassword" to have the password be EXPIRED, so user must change it the next time they log in.
+     * * `params: &UsersCreateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create(
+    pub async fn create_with(
         &self,
-        activate: bool,
-        provider: bool,
-        next_login: &str,
+        params: &UsersCreateParams,
         body: &crate::types::CreateUserRequest,
     ) -> Result<crate::types::User> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if activate {
+        if let Some(activate) = &params.activate {
             query_args.push(("activate".to_string(), activate.to_string()));
         }
-        if !next_login.is_empty() {
+        if let Some(next_login) = &params.next_login {
             query_args.push(("nextLogin".to_string(), next_login.to_string()));
         }
-        if provider {
+        if let Some(provider) = &params.provider {
             query_args.push(("provider".to_string(), provider.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -144,6 +221,25 @@ impl Users {
             .await
     }
 
+    /**
+     * Deprecated: use `create_with` and pass the query parameters as a `UsersCreateParams` instead.
+     */
+    #[deprecated(note = "use `create_with` instead")]
+    pub async fn create(
+        &self,
+        activate: bool,
+        provider: bool,
+        next_login: &str,
+        body: &crate::types::CreateUserRequest,
+    ) -> Result<crate::types::User> {
+        let params = UsersCreateParams {
+            activate: Some(activate).filter(|v| *v),
+            next_login: Some(next_login.to_string()).filter(|s| !s.is_empty()),
+            provider: Some(provider).filter(|v| *v),
+        };
+        self.create_with(&params, body).await
+    }
+
     /**
      * This function performs a `PUT` to the `/api/v1/users/{associatedUserId}/linkedObjects/{primaryRelationshipName}/{primaryUserId}` endpoint.
      *
@@ -199,16 +295,16 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str`
-     * * `strict: bool`
+     * * `params: &UsersUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update(
+    pub async fn update_with(
         &self,
         user_id: &str,
-        strict: bool,
+        params: &UsersUpdateParams,
         body: &crate::types::User,
     ) -> Result<crate::types::User> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if strict {
+        if let Some(strict) = &params.strict {
             query_args.push(("strict".to_string(), strict.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -223,6 +319,22 @@ impl Users {
             .await
     }
 
+    /**
+     * Deprecated: use `update_with` and pass the query parameters as a `UsersUpdateParams` instead.
+     */
+    #[deprecated(note = "use `update_with` instead")]
+    pub async fn update(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::User,
+    ) -> Result<crate::types::User> {
+        let params = UsersUpdateParams {
+            strict: Some(strict).filter(|v| *v),
+        };
+        self.update_with(user_id, &params, body).await
+    }
+
     /**
      * This function performs a `POST` to the `/api/v1/users/{userId}` endpoint.
      *
@@ -231,16 +343,16 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str`
-     * * `strict: bool`
+     * * `params: &UsersPartialUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn partial_update(
+    pub async fn partial_update_with(
         &self,
         user_id: &str,
-        strict: bool,
+        params: &UsersPartialUpdateParams,
         body: &crate::types::User,
     ) -> Result<crate::types::User> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if strict {
+        if let Some(strict) = &params.strict {
             query_args.push(("strict".to_string(), strict.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -255,6 +367,22 @@ impl Users {
             .await
     }
 
+    /**
+     * Deprecated: use `partial_update_with` and pass the query parameters as a `UsersPartialUpdateParams` instead.
+     */
+    #[deprecated(note = "use `partial_update_with` instead")]
+    pub async fn partial_update(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::User,
+    ) -> Result<crate::types::User> {
+        let params = UsersPartialUpdateParams {
+            strict: Some(strict).filter(|v| *v),
+        };
+        self.partial_update_with(user_id, &params, body).await
+    }
+
     /**
      * Delete User.
      *
@@ -265,11 +393,15 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str`
-     * * `send_email: bool`
+     * * `params: &UsersDeactivateOrDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deactivate_or_delete(&self, user_id: &str, send_email: bool) -> Result<()> {
+    pub async fn deactivate_or_delete_with(
+        &self,
+        user_id: &str,
+        params: &UsersDeactivateOrDeleteParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if send_email {
+        if let Some(send_email) = &params.send_email {
             query_args.push(("sendEmail".to_string(), send_email.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -282,6 +414,17 @@ impl Users {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deactivate_or_delete_with` and pass the query parameters as a `UsersDeactivateOrDeleteParams` instead.
+     */
+    #[deprecated(note = "use `deactivate_or_delete_with` instead")]
+    pub async fn deactivate_or_delete(&self, user_id: &str, send_email: bool) -> Result<()> {
+        let params = UsersDeactivateOrDeleteParams {
+            send_email: Some(send_email).filter(|v| *v),
+        };
+        self.deactivate_or_delete_with(user_id, &params).await
+    }
+
     /**
      * Get Assigned App Links.
      *
@@ -320,6 +463,44 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get Assigned App Links.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/appLinks` endpoint.
+     *
+     * As opposed to `list_all_app_links`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Fetches appLinks for all direct or indirect (via group membership) assigned applications.
+     */
+    pub fn list_app_links_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AppLink> {
+        let url = format!(
+            "/api/v1/users/{}/appLinks",
+            crate::progenitor_support::encode_path(user_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients` endpoint.
      *
@@ -354,6 +535,42 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients` endpoint.
+     *
+     * As opposed to `list_all_clients`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all client resources for which the specified user has grants or tokens.
+     */
+    pub fn list_clients_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Client> {
+        let url = format!(
+            "/api/v1/users/{}/clients",
+            crate::progenitor_support::encode_path(user_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
      *
@@ -363,26 +580,22 @@ impl Users {
      *
      * * `user_id: &str`
      * * `client_id: &str`
-     * * `expand: &str`
-     * * `after: &str`
-     * * `limit: i64`
+     * * `params: &UsersListGrantsForAndClientsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_grants_for_and_clients(
+    pub async fn list_grants_for_and_clients_with(
         &self,
         user_id: &str,
         client_id: &str,
-        expand: &str,
-        after: &str,
-        limit: i64,
+        params: &UsersListGrantsForAndClientsParams,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -396,21 +609,94 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_grants_for_and_clients_with` and pass the query parameters as a `UsersListGrantsForAndClientsParams` instead.
+     */
+    #[deprecated(note = "use `list_grants_for_and_clients_with` instead")]
+    pub async fn list_grants_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        let params = UsersListGrantsForAndClientsParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_grants_for_and_clients_with(user_id, client_id, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
      *
      * As opposed to `list_grants_for_and_client`, this function returns all the pages of the request at once.
      *
      * Lists all grants for a specified user and client
+     *
+     * **Parameters:**
+     *
+     * * `params: &UsersListGrantsForAndClientsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_grants_for_and_clients_with(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListGrantsForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/api/v1/users/{}/clients/{}/grants?{}",
+            crate::progenitor_support::encode_path(user_id),
+            crate::progenitor_support::encode_path(client_id),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_grants_for_and_clients_with` and pass the query parameters as a `UsersListGrantsForAndClientsParams` instead.
      */
+    #[deprecated(note = "use `list_all_grants_for_and_clients_with` instead")]
     pub async fn list_all_grants_for_and_clients(
         &self,
         user_id: &str,
         client_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        let params = UsersListGrantsForAndClientsParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_grants_for_and_clients_with(user_id, client_id, &params)
+            .await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/grants` endpoint.
+     *
+     * As opposed to `list_all_grants_for_and_clients`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all grants for a specified user and client
+     */
+    pub fn list_grants_for_and_clients_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListGrantsForAndClientsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2ScopeConsentGrant> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -421,7 +707,22 @@ impl Users {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -453,26 +754,22 @@ impl Users {
      *
      * * `user_id: &str`
      * * `client_id: &str`
-     * * `expand: &str`
-     * * `after: &str`
-     * * `limit: i64`
+     * * `params: &UsersListRefreshTokensForAndClientsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_refresh_tokens_for_and_clients(
+    pub async fn list_refresh_tokens_for_and_clients_with(
         &self,
         user_id: &str,
         client_id: &str,
-        expand: &str,
-        after: &str,
-        limit: i64,
+        params: &UsersListRefreshTokensForAndClientsParams,
     ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -486,21 +783,94 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_refresh_tokens_for_and_clients_with` and pass the query parameters as a `UsersListRefreshTokensForAndClientsParams` instead.
+     */
+    #[deprecated(note = "use `list_refresh_tokens_for_and_clients_with` instead")]
+    pub async fn list_refresh_tokens_for_and_clients(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        let params = UsersListRefreshTokensForAndClientsParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_refresh_tokens_for_and_clients_with(user_id, client_id, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
      *
      * As opposed to `list_refresh_tokens_for_and_client`, this function returns all the pages of the request at once.
      *
      * Lists all refresh tokens issued for the specified User and Client.
+     *
+     * **Parameters:**
+     *
+     * * `params: &UsersListRefreshTokensForAndClientsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_refresh_tokens_for_and_clients_with(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListRefreshTokensForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/api/v1/users/{}/clients/{}/tokens?{}",
+            crate::progenitor_support::encode_path(user_id),
+            crate::progenitor_support::encode_path(client_id),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_refresh_tokens_for_and_clients_with` and pass the query parameters as a `UsersListRefreshTokensForAndClientsParams` instead.
      */
+    #[deprecated(note = "use `list_all_refresh_tokens_for_and_clients_with` instead")]
     pub async fn list_all_refresh_tokens_for_and_clients(
         &self,
         user_id: &str,
         client_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        let params = UsersListRefreshTokensForAndClientsParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_refresh_tokens_for_and_clients_with(user_id, client_id, &params)
+            .await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/clients/{clientId}/tokens` endpoint.
+     *
+     * As opposed to `list_all_refresh_tokens_for_and_clients`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all refresh tokens issued for the specified User and Client.
+     */
+    pub fn list_refresh_tokens_for_and_clients_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListRefreshTokensForAndClientsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2RefreshToken> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -511,7 +881,22 @@ impl Users {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -544,27 +929,23 @@ impl Users {
      * * `user_id: &str`
      * * `client_id: &str`
      * * `token_id: &str`
-     * * `expand: &str`
-     * * `limit: i64`
-     * * `after: &str`
+     * * `params: &UsersGetRefreshTokenForAndClientParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_refresh_token_for_and_client(
+    pub async fn get_refresh_token_for_and_client_with(
         &self,
         user_id: &str,
         client_id: &str,
         token_id: &str,
-        expand: &str,
-        limit: i64,
-        after: &str,
+        params: &UsersGetRefreshTokenForAndClientParams,
     ) -> Result<crate::types::OAuth2RefreshToken> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -580,13 +961,35 @@ impl Users {
     }
 
     /**
-     * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/tokens/{tokenId}` endpoint.
-     *
-     * Revokes the specified refresh token.
-     *
-     * **Parameters:**
-     *
-     * * `user_id: &str`
+     * Deprecated: use `get_refresh_token_for_and_client_with` and pass the query parameters as a `UsersGetRefreshTokenForAndClientParams` instead.
+     */
+    #[deprecated(note = "use `get_refresh_token_for_and_client_with` instead")]
+    pub async fn get_refresh_token_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+        expand: &str,
+        limit: i64,
+        after: &str,
+    ) -> Result<crate::types::OAuth2RefreshToken> {
+        let params = UsersGetRefreshTokenForAndClientParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.get_refresh_token_for_and_client_with(user_id, client_id, token_id, &params)
+            .await
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/clients/{clientId}/tokens/{tokenId}` endpoint.
+     *
+     * Revokes the specified refresh token.
+     *
+     * **Parameters:**
+     *
+     * * `user_id: &str`
      * * `client_id: &str`
      * * `token_id: &str`
      */
@@ -616,16 +1019,16 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str`
-     * * `strict: bool`
+     * * `params: &UsersChangePasswordParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn change_password(
+    pub async fn change_password_with(
         &self,
         user_id: &str,
-        strict: bool,
+        params: &UsersChangePasswordParams,
         body: &crate::types::ChangePasswordRequest,
     ) -> Result<crate::types::UserCredentials> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if strict {
+        if let Some(strict) = &params.strict {
             query_args.push(("strict".to_string(), strict.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -640,6 +1043,22 @@ impl Users {
             .await
     }
 
+    /**
+     * Deprecated: use `change_password_with` and pass the query parameters as a `UsersChangePasswordParams` instead.
+     */
+    #[deprecated(note = "use `change_password_with` instead")]
+    pub async fn change_password(
+        &self,
+        user_id: &str,
+        strict: bool,
+        body: &crate::types::ChangePasswordRequest,
+    ) -> Result<crate::types::UserCredentials> {
+        let params = UsersChangePasswordParams {
+            strict: Some(strict).filter(|v| *v),
+        };
+        self.change_password_with(user_id, &params, body).await
+    }
+
     /**
      * Change Recovery Question.
      *
@@ -678,7 +1097,7 @@ impl Users {
     pub async fn post_credentials_forgot_password(
         &self,
         user_id: &str,
-    ) -> Result<crate::types::ResetPasswordToken> {
+    ) -> Result<crate::types::ForgotPasswordResponse> {
         let url = format!(
             "/api/v1/users/{}/credentials/forgot_password",
             crate::progenitor_support::encode_path(user_id),
@@ -695,30 +1114,24 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str`
-     * * `scope_id: &str`
-     * * `expand: &str`
-     * * `after: &str`
-     * * `limit: i64`
+     * * `params: &UsersListGrantsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_grants(
+    pub async fn list_grants_with(
         &self,
         user_id: &str,
-        scope_id: &str,
-        expand: &str,
-        after: &str,
-        limit: i64,
+        params: &UsersListGrantsParams,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !scope_id.is_empty() {
+        if let Some(scope_id) = &params.scope_id {
             query_args.push(("scopeId".to_string(), scope_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -731,24 +1144,97 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_grants_with` and pass the query parameters as a `UsersListGrantsParams` instead.
+     */
+    #[deprecated(note = "use `list_grants_with` instead")]
+    pub async fn list_grants(
+        &self,
+        user_id: &str,
+        scope_id: &str,
+        expand: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        let params = UsersListGrantsParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            scope_id: Some(scope_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_grants_with(user_id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/grants` endpoint.
      *
      * As opposed to `list_grants`, this function returns all the pages of the request at once.
      *
      * Lists all grants for the specified user
+     *
+     * **Parameters:**
+     *
+     * * `params: &UsersListGrantsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_grants_with(
+        &self,
+        user_id: &str,
+        params: &UsersListGrantsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        if let Some(scope_id) = &params.scope_id {
+            query_args.push(("scopeId".to_string(), scope_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/api/v1/users/{}/grants?{}",
+            crate::progenitor_support::encode_path(user_id),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_grants_with` and pass the query parameters as a `UsersListGrantsParams` instead.
      */
+    #[deprecated(note = "use `list_all_grants_with` instead")]
     pub async fn list_all_grants(
         &self,
         user_id: &str,
         scope_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        let params = UsersListGrantsParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+            scope_id: Some(scope_id.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_grants_with(user_id, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/grants` endpoint.
+     *
+     * As opposed to `list_all_grants`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all grants for the specified user
+     */
+    pub fn list_grants_stream(
+        &self,
+        user_id: &str,
+        params: &UsersListGrantsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2ScopeConsentGrant> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
-        if !scope_id.is_empty() {
+        if let Some(scope_id) = &params.scope_id {
             query_args.push(("scopeId".to_string(), scope_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -758,7 +1244,22 @@ impl Users {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -788,16 +1289,16 @@ impl Users {
      *
      * * `user_id: &str`
      * * `grant_id: &str`
-     * * `expand: &str`
+     * * `params: &UsersGetGrantParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_grant(
+    pub async fn get_grant_with(
         &self,
         user_id: &str,
         grant_id: &str,
-        expand: &str,
+        params: &UsersGetGrantParams,
     ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -811,6 +1312,22 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_grant_with` and pass the query parameters as a `UsersGetGrantParams` instead.
+     */
+    #[deprecated(note = "use `get_grant_with` instead")]
+    pub async fn get_grant(
+        &self,
+        user_id: &str,
+        grant_id: &str,
+        expand: &str,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
+        let params = UsersGetGrantParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_grant_with(user_id, grant_id, &params).await
+    }
+
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/grants/{grantId}` endpoint.
      *
@@ -869,6 +1386,44 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get Member Groups.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/groups` endpoint.
+     *
+     * As opposed to `list_all_groups`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Fetches the groups of which the user is a member.
+     */
+    pub fn list_groups_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group> {
+        let url = format!(
+            "/api/v1/users/{}/groups",
+            crate::progenitor_support::encode_path(user_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Listing IdPs associated with a user.
      *
@@ -913,6 +1468,44 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Listing IdPs associated with a user.
+     *
+     * This function performs a `GET` to the `/api/v1/users/{userId}/idps` endpoint.
+     *
+     * As opposed to `list_all_identity_providers`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists the IdPs associated with the user.
+     */
+    pub fn list_identity_providers_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IdentityProvider> {
+        let url = format!(
+            "/api/v1/users/{}/idps",
+            crate::progenitor_support::encode_path(user_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Activate User.
      *
@@ -923,15 +1516,15 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str`
-     * * `send_email: bool` -- Sends an activation email to the user if true.
+     * * `params: &UsersActivateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn activate(
+    pub async fn activate_with(
         &self,
         user_id: &str,
-        send_email: bool,
+        params: &UsersActivateParams,
     ) -> Result<crate::types::UserActivationToken> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if send_email {
+        if let Some(send_email) = &params.send_email {
             query_args.push(("sendEmail".to_string(), send_email.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -944,6 +1537,21 @@ impl Users {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `activate_with` and pass the query parameters as a `UsersActivateParams` instead.
+     */
+    #[deprecated(note = "use `activate_with` instead")]
+    pub async fn activate(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<crate::types::UserActivationToken> {
+        let params = UsersActivateParams {
+            send_email: Some(send_email).filter(|v| *v),
+        };
+        self.activate_with(user_id, &params).await
+    }
+
     /**
      * Deactivate User.
      *
@@ -954,11 +1562,15 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str`
-     * * `send_email: bool`
+     * * `params: &UsersDeactivateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deactivate(&self, user_id: &str, send_email: bool) -> Result<()> {
+    pub async fn deactivate_with(
+        &self,
+        user_id: &str,
+        params: &UsersDeactivateParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if send_email {
+        if let Some(send_email) = &params.send_email {
             query_args.push(("sendEmail".to_string(), send_email.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -971,6 +1583,17 @@ impl Users {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deactivate_with` and pass the query parameters as a `UsersDeactivateParams` instead.
+     */
+    #[deprecated(note = "use `deactivate_with` instead")]
+    pub async fn deactivate(&self, user_id: &str, send_email: bool) -> Result<()> {
+        let params = UsersDeactivateParams {
+            send_email: Some(send_email).filter(|v| *v),
+        };
+        self.deactivate_with(user_id, &params).await
+    }
+
     /**
      * Expire Password.
      *
@@ -1024,15 +1647,15 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str`
-     * * `send_email: bool` -- Sends an activation email to the user if true.
+     * * `params: &UsersReactivateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn reactivate(
+    pub async fn reactivate_with(
         &self,
         user_id: &str,
-        send_email: bool,
+        params: &UsersReactivateParams,
     ) -> Result<crate::types::UserActivationToken> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if send_email {
+        if let Some(send_email) = &params.send_email {
             query_args.push(("sendEmail".to_string(), send_email.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1045,6 +1668,21 @@ impl Users {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `reactivate_with` and pass the query parameters as a `UsersReactivateParams` instead.
+     */
+    #[deprecated(note = "use `reactivate_with` instead")]
+    pub async fn reactivate(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<crate::types::UserActivationToken> {
+        let params = UsersReactivateParams {
+            send_email: Some(send_email).filter(|v| *v),
+        };
+        self.reactivate_with(user_id, &params).await
+    }
+
     /**
      * Reset Factors.
      *
@@ -1075,15 +1713,15 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str`
-     * * `send_email: bool`
+     * * `params: &UsersResetPasswordParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn reset_password(
+    pub async fn reset_password_with(
         &self,
         user_id: &str,
-        send_email: bool,
+        params: &UsersResetPasswordParams,
     ) -> Result<crate::types::ResetPasswordToken> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if send_email {
+        if let Some(send_email) = &params.send_email {
             query_args.push(("sendEmail".to_string(), send_email.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1096,6 +1734,21 @@ impl Users {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `reset_password_with` and pass the query parameters as a `UsersResetPasswordParams` instead.
+     */
+    #[deprecated(note = "use `reset_password_with` instead")]
+    pub async fn reset_password(
+        &self,
+        user_id: &str,
+        send_email: bool,
+    ) -> Result<crate::types::ResetPasswordToken> {
+        let params = UsersResetPasswordParams {
+            send_email: Some(send_email).filter(|v| *v),
+        };
+        self.reset_password_with(user_id, &params).await
+    }
+
     /**
      * Suspend User.
      *
@@ -1165,21 +1818,19 @@ impl Users {
      *
      * * `user_id: &str`
      * * `relationship_name: &str`
-     * * `after: &str`
-     * * `limit: i64`
+     * * `params: &UsersGetLinkedObjectsForsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_linked_objects_fors(
+    pub async fn get_linked_objects_fors_with(
         &self,
         user_id: &str,
         relationship_name: &str,
-        after: &str,
-        limit: i64,
-    ) -> Result<Vec<crate::types::Links>> {
+        params: &UsersGetLinkedObjectsForsParams,
+    ) -> Result<Vec<crate::types::ResponseLinks>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1193,6 +1844,25 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_linked_objects_fors_with` and pass the query parameters as a `UsersGetLinkedObjectsForsParams` instead.
+     */
+    #[deprecated(note = "use `get_linked_objects_fors_with` instead")]
+    pub async fn get_linked_objects_fors(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::ResponseLinks>> {
+        let params = UsersGetLinkedObjectsForsParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.get_linked_objects_fors_with(user_id, relationship_name, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
      *
@@ -1204,7 +1874,7 @@ impl Users {
         &self,
         user_id: &str,
         relationship_name: &str,
-    ) -> Result<Vec<crate::types::Links>> {
+    ) -> Result<Vec<crate::types::ResponseLinks>> {
         let url = format!(
             "/api/v1/users/{}/linkedObjects/{}",
             crate::progenitor_support::encode_path(user_id),
@@ -1214,6 +1884,44 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
+     *
+     * As opposed to `get_all_linked_objects_fors`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Get linked objects for a user, relationshipName can be a primary or associated relationship name
+     */
+    pub fn get_linked_objects_fors_stream(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ResponseLinks> {
+        let url = format!(
+            "/api/v1/users/{}/linkedObjects/{}",
+            crate::progenitor_support::encode_path(user_id),
+            crate::progenitor_support::encode_path(relationship_name),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `DELETE` to the `/api/v1/users/{userId}/linkedObjects/{relationshipName}` endpoint.
      *
@@ -1246,15 +1954,15 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str`
-     * * `expand: &str`
+     * * `params: &UsersListAssignedRolesForsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_assigned_roles_fors(
+    pub async fn list_assigned_roles_fors_with(
         &self,
         user_id: &str,
-        expand: &str,
+        params: &UsersListAssignedRolesForsParams,
     ) -> Result<Vec<crate::types::Role>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1267,20 +1975,83 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_assigned_roles_fors_with` and pass the query parameters as a `UsersListAssignedRolesForsParams` instead.
+     */
+    #[deprecated(note = "use `list_assigned_roles_fors_with` instead")]
+    pub async fn list_assigned_roles_fors(
+        &self,
+        user_id: &str,
+        expand: &str,
+    ) -> Result<Vec<crate::types::Role>> {
+        let params = UsersListAssignedRolesForsParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_assigned_roles_fors_with(user_id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/roles` endpoint.
      *
      * As opposed to `list_assigned_roles_for`, this function returns all the pages of the request at once.
      *
      * Lists all roles assigned to a user.
+     *
+     * **Parameters:**
+     *
+     * * `params: &UsersListAssignedRolesForsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn list_all_assigned_roles_fors_with(
+        &self,
+        user_id: &str,
+        params: &UsersListAssignedRolesForsParams,
+    ) -> Result<Vec<crate::types::Role>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            query_args.push(("expand".to_string(), expand.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/api/v1/users/{}/roles?{}",
+            crate::progenitor_support::encode_path(user_id),
+            query_
+        );
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_assigned_roles_fors_with` and pass the query parameters as a `UsersListAssignedRolesForsParams` instead.
      */
+    #[deprecated(note = "use `list_all_assigned_roles_fors_with` instead")]
     pub async fn list_all_assigned_roles_fors(
         &self,
         user_id: &str,
         expand: &str,
     ) -> Result<Vec<crate::types::Role>> {
+        let params = UsersListAssignedRolesForsParams {
+            expand: Some(expand.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_all_assigned_roles_fors_with(user_id, &params)
+            .await
+    }
+
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles` endpoint.
+     *
+     * As opposed to `list_all_assigned_roles_fors`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all roles assigned to a user.
+     */
+    pub fn list_assigned_roles_fors_stream(
+        &self,
+        user_id: &str,
+        params: &UsersListAssignedRolesForsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Role> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !expand.is_empty() {
+        if let Some(expand) = &params.expand {
             query_args.push(("expand".to_string(), expand.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1290,7 +2061,22 @@ impl Users {
             query_
         );
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -1301,16 +2087,16 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str`
-     * * `disable_notifications: &str`
+     * * `params: &UsersAssignRoleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn assign_role(
+    pub async fn assign_role_with(
         &self,
         user_id: &str,
-        disable_notifications: &str,
+        params: &UsersAssignRoleParams,
         body: &crate::types::AssignRoleRequest,
     ) -> Result<crate::types::Role> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !disable_notifications.is_empty() {
+        if let Some(disable_notifications) = &params.disable_notifications {
             query_args.push((
                 "disableNotifications".to_string(),
                 disable_notifications.to_string(),
@@ -1329,12 +2115,29 @@ impl Users {
     }
 
     /**
-     * This function performs a `DELETE` to the `/api/v1/users/{userId}/roles/{roleId}` endpoint.
-     *
-     * Unassigns a role from a user.
-     *
-     * **Parameters:**
-     *
+     * Deprecated: use `assign_role_with` and pass the query parameters as a `UsersAssignRoleParams` instead.
+     */
+    #[deprecated(note = "use `assign_role_with` instead")]
+    pub async fn assign_role(
+        &self,
+        user_id: &str,
+        disable_notifications: &str,
+        body: &crate::types::AssignRoleRequest,
+    ) -> Result<crate::types::Role> {
+        let params = UsersAssignRoleParams {
+            disable_notifications: Some(disable_notifications.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.assign_role_with(user_id, &params, body).await
+    }
+
+    /**
+     * This function performs a `DELETE` to the `/api/v1/users/{userId}/roles/{roleId}` endpoint.
+     *
+     * Unassigns a role from a user.
+     *
+     * **Parameters:**
+     *
      * * `user_id: &str`
      * * `role_id: &str`
      */
@@ -1357,21 +2160,19 @@ impl Users {
      *
      * * `user_id: &str`
      * * `role_id: &str`
-     * * `after: &str`
-     * * `limit: i64`
+     * * `params: &UsersListApplicationTargetsForAdministratorRolesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_application_targets_for_administrator_roles(
+    pub async fn list_application_targets_for_administrator_roles_with(
         &self,
         user_id: &str,
         role_id: &str,
-        after: &str,
-        limit: i64,
+        params: &UsersListApplicationTargetsForAdministratorRolesParams,
     ) -> Result<Vec<crate::types::CatalogApplication>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1385,6 +2186,25 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_application_targets_for_administrator_roles_with` and pass the query parameters as a `UsersListApplicationTargetsForAdministratorRolesParams` instead.
+     */
+    #[deprecated(note = "use `list_application_targets_for_administrator_roles_with` instead")]
+    pub async fn list_application_targets_for_administrator_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::CatalogApplication>> {
+        let params = UsersListApplicationTargetsForAdministratorRolesParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_application_targets_for_administrator_roles_with(user_id, role_id, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
      *
@@ -1406,6 +2226,44 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
+     *
+     * As opposed to `list_all_application_targets_for_administrator_roles`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists all App targets for an `APP_ADMIN` Role assigned to a User. This methods return list may include full Applications or Instances. The response for an instance will have an `ID` value, while Application will not have an ID.
+     */
+    pub fn list_application_targets_for_administrator_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CatalogApplication> {
+        let url = format!(
+            "/api/v1/users/{}/roles/{}/targets/catalog/apps",
+            crate::progenitor_support::encode_path(user_id),
+            crate::progenitor_support::encode_path(role_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/catalog/apps` endpoint.
      *
@@ -1553,21 +2411,19 @@ impl Users {
      *
      * * `user_id: &str`
      * * `role_id: &str`
-     * * `after: &str`
-     * * `limit: i64`
+     * * `params: &UsersListGroupTargetsForRolesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_group_targets_for_roles(
+    pub async fn list_group_targets_for_roles_with(
         &self,
         user_id: &str,
         role_id: &str,
-        after: &str,
-        limit: i64,
+        params: &UsersListGroupTargetsForRolesParams,
     ) -> Result<Vec<crate::types::Group>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !after.is_empty() {
+        if let Some(after) = &params.after {
             query_args.push(("after".to_string(), after.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1581,6 +2437,25 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_group_targets_for_roles_with` and pass the query parameters as a `UsersListGroupTargetsForRolesParams` instead.
+     */
+    #[deprecated(note = "use `list_group_targets_for_roles_with` instead")]
+    pub async fn list_group_targets_for_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        after: &str,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Group>> {
+        let params = UsersListGroupTargetsForRolesParams {
+            after: Some(after.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_group_targets_for_roles_with(user_id, role_id, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups` endpoint.
      *
@@ -1602,6 +2477,44 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups` endpoint.
+     *
+     * As opposed to `list_all_group_targets_for_roles`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Success
+     */
+    pub fn list_group_targets_for_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group> {
+        let url = format!(
+            "/api/v1/users/{}/roles/{}/targets/groups",
+            crate::progenitor_support::encode_path(user_id),
+            crate::progenitor_support::encode_path(role_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `PUT` to the `/api/v1/users/{userId}/roles/{roleId}/targets/groups/{groupId}` endpoint.
      *
@@ -1664,11 +2577,15 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str`
-     * * `oauth_tokens: bool` -- Revoke issued OpenID Connect and OAuth refresh and access tokens.
+     * * `params: &UsersClearSessionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn clear_sessions(&self, user_id: &str, oauth_tokens: bool) -> Result<()> {
+    pub async fn clear_sessions_with(
+        &self,
+        user_id: &str,
+        params: &UsersClearSessionsParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if oauth_tokens {
+        if let Some(oauth_tokens) = &params.oauth_tokens {
             query_args.push(("oauthTokens".to_string(), oauth_tokens.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1680,4 +2597,1460 @@ impl Users {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Deprecated: use `clear_sessions_with` and pass the query parameters as a `UsersClearSessionsParams` instead.
+     */
+    #[deprecated(note = "use `clear_sessions_with` instead")]
+    pub async fn clear_sessions(&self, user_id: &str, oauth_tokens: bool) -> Result<()> {
+        let params = UsersClearSessionsParams {
+            oauth_tokens: Some(oauth_tokens).filter(|v| *v),
+        };
+        self.clear_sessions_with(user_id, &params).await
+    }
+}
+
+/// The query parameters of `Users::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListParams {
+    /// Specifies the pagination cursor for the next page of users.
+    pub after: Option<String>,
+    /// Filters users with a supported expression for a subset of properties.
+    pub filter: Option<String>,
+    /// Specifies the number of results returned.
+    pub limit: Option<i64>,
+    /// Finds a user that matches firstName, lastName, and email properties.
+    pub q: Option<String>,
+    /// Searches for users with a supported filtering  expression for most properties.
+    pub search: Option<String>,
+    pub sort_by: Option<String>,
+    pub sort_order: Option<String>,
+}
+
+impl UsersListParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn q<T: ToString>(mut self, q: T) -> Self {
+        self.q = Some(q.to_string());
+        self
+    }
+
+    pub fn search<T: ToString>(mut self, search: T) -> Self {
+        self.search = Some(search.to_string());
+        self
+    }
+
+    pub fn sort_by<T: ToString>(mut self, sort_by: T) -> Self {
+        self.sort_by = Some(sort_by.to_string());
+        self
+    }
+
+    pub fn sort_order<T: ToString>(mut self, sort_order: T) -> Self {
+        self.sort_order = Some(sort_order.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Users::create_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersCreateParams {
+    /// Executes activation lifecycle operation when creating the user.
+    pub activate: Option<bool>,
+    /// With activate=true, set nextLogin to "changePassword" to have the password be EXPIRED, so user must change it the next time they log in.
+    pub next_login: Option<String>,
+    /// Indicates whether to create a user with a specified authentication provider.
+    pub provider: Option<bool>,
+}
+
+impl UsersCreateParams {
+    pub fn activate(mut self, activate: bool) -> Self {
+        self.activate = Some(activate);
+        self
+    }
+
+    pub fn next_login<T: ToString>(mut self, next_login: T) -> Self {
+        self.next_login = Some(next_login.to_string());
+        self
+    }
+
+    pub fn provider(mut self, provider: bool) -> Self {
+        self.provider = Some(provider);
+        self
+    }
+}
+
+/// The query parameters of `Users::update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersUpdateParams {
+    pub strict: Option<bool>,
+}
+
+impl UsersUpdateParams {
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = Some(strict);
+        self
+    }
+}
+
+/// The query parameters of `Users::partial_update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersPartialUpdateParams {
+    pub strict: Option<bool>,
+}
+
+impl UsersPartialUpdateParams {
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = Some(strict);
+        self
+    }
+}
+
+/// The query parameters of `Users::deactivate_or_delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersDeactivateOrDeleteParams {
+    pub send_email: Option<bool>,
+}
+
+impl UsersDeactivateOrDeleteParams {
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.send_email = Some(send_email);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_grants_for_and_clients_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListGrantsForAndClientsParams {
+    pub after: Option<String>,
+    pub expand: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl UsersListGrantsForAndClientsParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_refresh_tokens_for_and_clients_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListRefreshTokensForAndClientsParams {
+    pub after: Option<String>,
+    pub expand: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl UsersListRefreshTokensForAndClientsParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `Users::get_refresh_token_for_and_client_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersGetRefreshTokenForAndClientParams {
+    pub after: Option<String>,
+    pub expand: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl UsersGetRefreshTokenForAndClientParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `Users::change_password_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersChangePasswordParams {
+    pub strict: Option<bool>,
+}
+
+impl UsersChangePasswordParams {
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = Some(strict);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_grants_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListGrantsParams {
+    pub after: Option<String>,
+    pub expand: Option<String>,
+    pub limit: Option<i64>,
+    pub scope_id: Option<String>,
+}
+
+impl UsersListGrantsParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn scope_id<T: ToString>(mut self, scope_id: T) -> Self {
+        self.scope_id = Some(scope_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Users::get_grant_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersGetGrantParams {
+    pub expand: Option<String>,
+}
+
+impl UsersGetGrantParams {
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Users::activate_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersActivateParams {
+    /// Sends an activation email to the user if true.
+    pub send_email: Option<bool>,
+}
+
+impl UsersActivateParams {
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.send_email = Some(send_email);
+        self
+    }
+}
+
+/// The query parameters of `Users::deactivate_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersDeactivateParams {
+    pub send_email: Option<bool>,
+}
+
+impl UsersDeactivateParams {
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.send_email = Some(send_email);
+        self
+    }
+}
+
+/// The query parameters of `Users::reactivate_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersReactivateParams {
+    /// Sends an activation email to the user if true.
+    pub send_email: Option<bool>,
+}
+
+impl UsersReactivateParams {
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.send_email = Some(send_email);
+        self
+    }
+}
+
+/// The query parameters of `Users::reset_password_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersResetPasswordParams {
+    pub send_email: Option<bool>,
+}
+
+impl UsersResetPasswordParams {
+    pub fn send_email(mut self, send_email: bool) -> Self {
+        self.send_email = Some(send_email);
+        self
+    }
+}
+
+/// The query parameters of `Users::get_linked_objects_fors_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersGetLinkedObjectsForsParams {
+    pub after: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl UsersGetLinkedObjectsForsParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_assigned_roles_fors_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListAssignedRolesForsParams {
+    pub expand: Option<String>,
+}
+
+impl UsersListAssignedRolesForsParams {
+    pub fn expand<T: ToString>(mut self, expand: T) -> Self {
+        self.expand = Some(expand.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Users::assign_role_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersAssignRoleParams {
+    pub disable_notifications: Option<String>,
+}
+
+impl UsersAssignRoleParams {
+    pub fn disable_notifications<T: ToString>(mut self, disable_notifications: T) -> Self {
+        self.disable_notifications = Some(disable_notifications.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Users::list_application_targets_for_administrator_roles_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListApplicationTargetsForAdministratorRolesParams {
+    pub after: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl UsersListApplicationTargetsForAdministratorRolesParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `Users::list_group_targets_for_roles_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListGroupTargetsForRolesParams {
+    pub after: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl UsersListGroupTargetsForRolesParams {
+    pub fn after<T: ToString>(mut self, after: T) -> Self {
+        self.after = Some(after.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `Users::clear_sessions_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersClearSessionsParams {
+    /// Revoke issued OpenID Connect and OAuth refresh and access tokens.
+    pub oauth_tokens: Option<bool>,
+}
+
+impl UsersClearSessionsParams {
+    pub fn oauth_tokens(mut self, oauth_tokens: bool) -> Self {
+        self.oauth_tokens = Some(oauth_tokens);
+        self
+    }
+}
+
+/// The functions of [`Users`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUsersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UsersApi: Send + Sync {
+    /// See [`Users::list_with`].
+    async fn list_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::User>>;
+
+    /// See [`Users::list_all_with`].
+    async fn list_all_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::User>>;
+
+    /// See [`Users::list_stream`].
+    fn list_stream(
+        &self,
+        params: &UsersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::User>;
+
+    /// See [`Users::create_with`].
+    async fn create_with(
+        &self,
+        params: &UsersCreateParams,
+        body: &crate::types::CreateUserRequest,
+    ) -> Result<crate::types::User>;
+
+    /// See [`Users::set_linked_object_for`].
+    async fn set_linked_object_for(
+        &self,
+        associated_user_id: &str,
+        primary_relationship_name: &str,
+        primary_user_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Users::get`].
+    async fn get(&self, user_id: &str) -> Result<crate::types::User>;
+
+    /// See [`Users::update_with`].
+    async fn update_with(
+        &self,
+        user_id: &str,
+        params: &UsersUpdateParams,
+        body: &crate::types::User,
+    ) -> Result<crate::types::User>;
+
+    /// See [`Users::partial_update_with`].
+    async fn partial_update_with(
+        &self,
+        user_id: &str,
+        params: &UsersPartialUpdateParams,
+        body: &crate::types::User,
+    ) -> Result<crate::types::User>;
+
+    /// See [`Users::deactivate_or_delete_with`].
+    async fn deactivate_or_delete_with(
+        &self,
+        user_id: &str,
+        params: &UsersDeactivateOrDeleteParams,
+    ) -> Result<()>;
+
+    /// See [`Users::list_app_links`].
+    async fn list_app_links(&self, user_id: &str) -> Result<Vec<crate::types::AppLink>>;
+
+    /// See [`Users::list_all_app_links`].
+    async fn list_all_app_links(&self, user_id: &str) -> Result<Vec<crate::types::AppLink>>;
+
+    /// See [`Users::list_app_links_stream`].
+    fn list_app_links_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AppLink>;
+
+    /// See [`Users::list_clients`].
+    async fn list_clients(&self, user_id: &str) -> Result<Vec<crate::types::OAuth2Client>>;
+
+    /// See [`Users::list_all_clients`].
+    async fn list_all_clients(&self, user_id: &str) -> Result<Vec<crate::types::OAuth2Client>>;
+
+    /// See [`Users::list_clients_stream`].
+    fn list_clients_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Client>;
+
+    /// See [`Users::list_grants_for_and_clients_with`].
+    async fn list_grants_for_and_clients_with(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListGrantsForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>>;
+
+    /// See [`Users::list_all_grants_for_and_clients_with`].
+    async fn list_all_grants_for_and_clients_with(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListGrantsForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>>;
+
+    /// See [`Users::list_grants_for_and_clients_stream`].
+    fn list_grants_for_and_clients_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListGrantsForAndClientsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2ScopeConsentGrant>;
+
+    /// See [`Users::revoke_grants_for_and_client`].
+    async fn revoke_grants_for_and_client(&self, user_id: &str, client_id: &str) -> Result<()>;
+
+    /// See [`Users::list_refresh_tokens_for_and_clients_with`].
+    async fn list_refresh_tokens_for_and_clients_with(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListRefreshTokensForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>>;
+
+    /// See [`Users::list_all_refresh_tokens_for_and_clients_with`].
+    async fn list_all_refresh_tokens_for_and_clients_with(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListRefreshTokensForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>>;
+
+    /// See [`Users::list_refresh_tokens_for_and_clients_stream`].
+    fn list_refresh_tokens_for_and_clients_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListRefreshTokensForAndClientsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2RefreshToken>;
+
+    /// See [`Users::revoke_tokens_for_and_client`].
+    async fn revoke_tokens_for_and_client(&self, user_id: &str, client_id: &str) -> Result<()>;
+
+    /// See [`Users::get_refresh_token_for_and_client_with`].
+    async fn get_refresh_token_for_and_client_with(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+        params: &UsersGetRefreshTokenForAndClientParams,
+    ) -> Result<crate::types::OAuth2RefreshToken>;
+
+    /// See [`Users::revoke_token_for_and_client`].
+    async fn revoke_token_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Users::change_password_with`].
+    async fn change_password_with(
+        &self,
+        user_id: &str,
+        params: &UsersChangePasswordParams,
+        body: &crate::types::ChangePasswordRequest,
+    ) -> Result<crate::types::UserCredentials>;
+
+    /// See [`Users::change_recovery_question`].
+    async fn change_recovery_question(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserCredentials,
+    ) -> Result<crate::types::UserCredentials>;
+
+    /// See [`Users::post_credentials_forgot_password`].
+    async fn post_credentials_forgot_password(
+        &self,
+        user_id: &str,
+    ) -> Result<crate::types::ForgotPasswordResponse>;
+
+    /// See [`Users::list_grants_with`].
+    async fn list_grants_with(
+        &self,
+        user_id: &str,
+        params: &UsersListGrantsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>>;
+
+    /// See [`Users::list_all_grants_with`].
+    async fn list_all_grants_with(
+        &self,
+        user_id: &str,
+        params: &UsersListGrantsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>>;
+
+    /// See [`Users::list_grants_stream`].
+    fn list_grants_stream(
+        &self,
+        user_id: &str,
+        params: &UsersListGrantsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2ScopeConsentGrant>;
+
+    /// See [`Users::revoke_grants`].
+    async fn revoke_grants(&self, user_id: &str) -> Result<()>;
+
+    /// See [`Users::get_grant_with`].
+    async fn get_grant_with(
+        &self,
+        user_id: &str,
+        grant_id: &str,
+        params: &UsersGetGrantParams,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant>;
+
+    /// See [`Users::revoke_grant`].
+    async fn revoke_grant(&self, user_id: &str, grant_id: &str) -> Result<()>;
+
+    /// See [`Users::list_groups`].
+    async fn list_groups(&self, user_id: &str) -> Result<Vec<crate::types::Group>>;
+
+    /// See [`Users::list_all_groups`].
+    async fn list_all_groups(&self, user_id: &str) -> Result<Vec<crate::types::Group>>;
+
+    /// See [`Users::list_groups_stream`].
+    fn list_groups_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group>;
+
+    /// See [`Users::list_identity_providers`].
+    async fn list_identity_providers(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProvider>>;
+
+    /// See [`Users::list_all_identity_providers`].
+    async fn list_all_identity_providers(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProvider>>;
+
+    /// See [`Users::list_identity_providers_stream`].
+    fn list_identity_providers_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IdentityProvider>;
+
+    /// See [`Users::activate_with`].
+    async fn activate_with(
+        &self,
+        user_id: &str,
+        params: &UsersActivateParams,
+    ) -> Result<crate::types::UserActivationToken>;
+
+    /// See [`Users::deactivate_with`].
+    async fn deactivate_with(&self, user_id: &str, params: &UsersDeactivateParams) -> Result<()>;
+
+    /// See [`Users::expire_password`].
+    async fn expire_password(&self, user_id: &str) -> Result<crate::types::User>;
+
+    /// See [`Users::expire_password_and_get_temporary`].
+    async fn expire_password_and_get_temporary(
+        &self,
+        user_id: &str,
+    ) -> Result<crate::types::TempPassword>;
+
+    /// See [`Users::reactivate_with`].
+    async fn reactivate_with(
+        &self,
+        user_id: &str,
+        params: &UsersReactivateParams,
+    ) -> Result<crate::types::UserActivationToken>;
+
+    /// See [`Users::reset_factors`].
+    async fn reset_factors(&self, user_id: &str) -> Result<()>;
+
+    /// See [`Users::reset_password_with`].
+    async fn reset_password_with(
+        &self,
+        user_id: &str,
+        params: &UsersResetPasswordParams,
+    ) -> Result<crate::types::ResetPasswordToken>;
+
+    /// See [`Users::suspend`].
+    async fn suspend(&self, user_id: &str) -> Result<()>;
+
+    /// See [`Users::unlock`].
+    async fn unlock(&self, user_id: &str) -> Result<()>;
+
+    /// See [`Users::unsuspend`].
+    async fn unsuspend(&self, user_id: &str) -> Result<()>;
+
+    /// See [`Users::get_linked_objects_fors_with`].
+    async fn get_linked_objects_fors_with(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+        params: &UsersGetLinkedObjectsForsParams,
+    ) -> Result<Vec<crate::types::ResponseLinks>>;
+
+    /// See [`Users::get_all_linked_objects_fors`].
+    async fn get_all_linked_objects_fors(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> Result<Vec<crate::types::ResponseLinks>>;
+
+    /// See [`Users::get_linked_objects_fors_stream`].
+    fn get_linked_objects_fors_stream(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ResponseLinks>;
+
+    /// See [`Users::remove_linked_object_for`].
+    async fn remove_linked_object_for(&self, user_id: &str, relationship_name: &str) -> Result<()>;
+
+    /// See [`Users::list_assigned_roles_fors_with`].
+    async fn list_assigned_roles_fors_with(
+        &self,
+        user_id: &str,
+        params: &UsersListAssignedRolesForsParams,
+    ) -> Result<Vec<crate::types::Role>>;
+
+    /// See [`Users::list_all_assigned_roles_fors_with`].
+    async fn list_all_assigned_roles_fors_with(
+        &self,
+        user_id: &str,
+        params: &UsersListAssignedRolesForsParams,
+    ) -> Result<Vec<crate::types::Role>>;
+
+    /// See [`Users::list_assigned_roles_fors_stream`].
+    fn list_assigned_roles_fors_stream(
+        &self,
+        user_id: &str,
+        params: &UsersListAssignedRolesForsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Role>;
+
+    /// See [`Users::assign_role_with`].
+    async fn assign_role_with(
+        &self,
+        user_id: &str,
+        params: &UsersAssignRoleParams,
+        body: &crate::types::AssignRoleRequest,
+    ) -> Result<crate::types::Role>;
+
+    /// See [`Users::remove_role_from`].
+    async fn remove_role_from(&self, user_id: &str, role_id: &str) -> Result<()>;
+
+    /// See [`Users::list_application_targets_for_administrator_roles_with`].
+    async fn list_application_targets_for_administrator_roles_with(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        params: &UsersListApplicationTargetsForAdministratorRolesParams,
+    ) -> Result<Vec<crate::types::CatalogApplication>>;
+
+    /// See [`Users::list_all_application_targets_for_administrator_roles`].
+    async fn list_all_application_targets_for_administrator_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::CatalogApplication>>;
+
+    /// See [`Users::list_application_targets_for_administrator_roles_stream`].
+    fn list_application_targets_for_administrator_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CatalogApplication>;
+
+    /// See [`Users::add_all_apps_as_target_role`].
+    async fn add_all_apps_as_target_role(&self, user_id: &str, role_id: &str) -> Result<()>;
+
+    /// See [`Users::add_application_target_admin_role_for`].
+    async fn add_application_target_admin_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()>;
+
+    /// See [`Users::remove_application_target_from_administrator_role_for`].
+    async fn remove_application_target_from_administrator_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()>;
+
+    /// See [`Users::add_application_target_app_admin_role_for`].
+    async fn add_application_target_app_admin_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Users::remove_application_target_from_administrator_role_for_users`].
+    async fn remove_application_target_from_administrator_role_for_users(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Users::list_group_targets_for_roles_with`].
+    async fn list_group_targets_for_roles_with(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        params: &UsersListGroupTargetsForRolesParams,
+    ) -> Result<Vec<crate::types::Group>>;
+
+    /// See [`Users::list_all_group_targets_for_roles`].
+    async fn list_all_group_targets_for_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::Group>>;
+
+    /// See [`Users::list_group_targets_for_roles_stream`].
+    fn list_group_targets_for_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group>;
+
+    /// See [`Users::add_group_target_role`].
+    async fn add_group_target_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Users::remove_group_target_from_role`].
+    async fn remove_group_target_from_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Users::clear_sessions_with`].
+    async fn clear_sessions_with(
+        &self,
+        user_id: &str,
+        params: &UsersClearSessionsParams,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl UsersApi for Users {
+    async fn list_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::User>> {
+        Users::list_with(self, params).await
+    }
+
+    async fn list_all_with(&self, params: &UsersListParams) -> Result<Vec<crate::types::User>> {
+        Users::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &UsersListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::User> {
+        Users::list_stream(self, params, start, max_items)
+    }
+
+    async fn create_with(
+        &self,
+        params: &UsersCreateParams,
+        body: &crate::types::CreateUserRequest,
+    ) -> Result<crate::types::User> {
+        Users::create_with(self, params, body).await
+    }
+
+    async fn set_linked_object_for(
+        &self,
+        associated_user_id: &str,
+        primary_relationship_name: &str,
+        primary_user_id: &str,
+    ) -> Result<()> {
+        Users::set_linked_object_for(
+            self,
+            associated_user_id,
+            primary_relationship_name,
+            primary_user_id,
+        )
+        .await
+    }
+
+    async fn get(&self, user_id: &str) -> Result<crate::types::User> {
+        Users::get(self, user_id).await
+    }
+
+    async fn update_with(
+        &self,
+        user_id: &str,
+        params: &UsersUpdateParams,
+        body: &crate::types::User,
+    ) -> Result<crate::types::User> {
+        Users::update_with(self, user_id, params, body).await
+    }
+
+    async fn partial_update_with(
+        &self,
+        user_id: &str,
+        params: &UsersPartialUpdateParams,
+        body: &crate::types::User,
+    ) -> Result<crate::types::User> {
+        Users::partial_update_with(self, user_id, params, body).await
+    }
+
+    async fn deactivate_or_delete_with(
+        &self,
+        user_id: &str,
+        params: &UsersDeactivateOrDeleteParams,
+    ) -> Result<()> {
+        Users::deactivate_or_delete_with(self, user_id, params).await
+    }
+
+    async fn list_app_links(&self, user_id: &str) -> Result<Vec<crate::types::AppLink>> {
+        Users::list_app_links(self, user_id).await
+    }
+
+    async fn list_all_app_links(&self, user_id: &str) -> Result<Vec<crate::types::AppLink>> {
+        Users::list_all_app_links(self, user_id).await
+    }
+
+    fn list_app_links_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AppLink> {
+        Users::list_app_links_stream(self, user_id, start, max_items)
+    }
+
+    async fn list_clients(&self, user_id: &str) -> Result<Vec<crate::types::OAuth2Client>> {
+        Users::list_clients(self, user_id).await
+    }
+
+    async fn list_all_clients(&self, user_id: &str) -> Result<Vec<crate::types::OAuth2Client>> {
+        Users::list_all_clients(self, user_id).await
+    }
+
+    fn list_clients_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2Client> {
+        Users::list_clients_stream(self, user_id, start, max_items)
+    }
+
+    async fn list_grants_for_and_clients_with(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListGrantsForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Users::list_grants_for_and_clients_with(self, user_id, client_id, params).await
+    }
+
+    async fn list_all_grants_for_and_clients_with(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListGrantsForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Users::list_all_grants_for_and_clients_with(self, user_id, client_id, params).await
+    }
+
+    fn list_grants_for_and_clients_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListGrantsForAndClientsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2ScopeConsentGrant> {
+        Users::list_grants_for_and_clients_stream(
+            self, user_id, client_id, params, start, max_items,
+        )
+    }
+
+    async fn revoke_grants_for_and_client(&self, user_id: &str, client_id: &str) -> Result<()> {
+        Users::revoke_grants_for_and_client(self, user_id, client_id).await
+    }
+
+    async fn list_refresh_tokens_for_and_clients_with(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListRefreshTokensForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        Users::list_refresh_tokens_for_and_clients_with(self, user_id, client_id, params).await
+    }
+
+    async fn list_all_refresh_tokens_for_and_clients_with(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListRefreshTokensForAndClientsParams,
+    ) -> Result<Vec<crate::types::OAuth2RefreshToken>> {
+        Users::list_all_refresh_tokens_for_and_clients_with(self, user_id, client_id, params).await
+    }
+
+    fn list_refresh_tokens_for_and_clients_stream(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        params: &UsersListRefreshTokensForAndClientsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2RefreshToken> {
+        Users::list_refresh_tokens_for_and_clients_stream(
+            self, user_id, client_id, params, start, max_items,
+        )
+    }
+
+    async fn revoke_tokens_for_and_client(&self, user_id: &str, client_id: &str) -> Result<()> {
+        Users::revoke_tokens_for_and_client(self, user_id, client_id).await
+    }
+
+    async fn get_refresh_token_for_and_client_with(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+        params: &UsersGetRefreshTokenForAndClientParams,
+    ) -> Result<crate::types::OAuth2RefreshToken> {
+        Users::get_refresh_token_for_and_client_with(self, user_id, client_id, token_id, params)
+            .await
+    }
+
+    async fn revoke_token_for_and_client(
+        &self,
+        user_id: &str,
+        client_id: &str,
+        token_id: &str,
+    ) -> Result<()> {
+        Users::revoke_token_for_and_client(self, user_id, client_id, token_id).await
+    }
+
+    async fn change_password_with(
+        &self,
+        user_id: &str,
+        params: &UsersChangePasswordParams,
+        body: &crate::types::ChangePasswordRequest,
+    ) -> Result<crate::types::UserCredentials> {
+        Users::change_password_with(self, user_id, params, body).await
+    }
+
+    async fn change_recovery_question(
+        &self,
+        user_id: &str,
+        body: &crate::types::UserCredentials,
+    ) -> Result<crate::types::UserCredentials> {
+        Users::change_recovery_question(self, user_id, body).await
+    }
+
+    async fn post_credentials_forgot_password(
+        &self,
+        user_id: &str,
+    ) -> Result<crate::types::ForgotPasswordResponse> {
+        Users::post_credentials_forgot_password(self, user_id).await
+    }
+
+    async fn list_grants_with(
+        &self,
+        user_id: &str,
+        params: &UsersListGrantsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Users::list_grants_with(self, user_id, params).await
+    }
+
+    async fn list_all_grants_with(
+        &self,
+        user_id: &str,
+        params: &UsersListGrantsParams,
+    ) -> Result<Vec<crate::types::OAuth2ScopeConsentGrant>> {
+        Users::list_all_grants_with(self, user_id, params).await
+    }
+
+    fn list_grants_stream(
+        &self,
+        user_id: &str,
+        params: &UsersListGrantsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OAuth2ScopeConsentGrant> {
+        Users::list_grants_stream(self, user_id, params, start, max_items)
+    }
+
+    async fn revoke_grants(&self, user_id: &str) -> Result<()> {
+        Users::revoke_grants(self, user_id).await
+    }
+
+    async fn get_grant_with(
+        &self,
+        user_id: &str,
+        grant_id: &str,
+        params: &UsersGetGrantParams,
+    ) -> Result<crate::types::OAuth2ScopeConsentGrant> {
+        Users::get_grant_with(self, user_id, grant_id, params).await
+    }
+
+    async fn revoke_grant(&self, user_id: &str, grant_id: &str) -> Result<()> {
+        Users::revoke_grant(self, user_id, grant_id).await
+    }
+
+    async fn list_groups(&self, user_id: &str) -> Result<Vec<crate::types::Group>> {
+        Users::list_groups(self, user_id).await
+    }
+
+    async fn list_all_groups(&self, user_id: &str) -> Result<Vec<crate::types::Group>> {
+        Users::list_all_groups(self, user_id).await
+    }
+
+    fn list_groups_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group> {
+        Users::list_groups_stream(self, user_id, start, max_items)
+    }
+
+    async fn list_identity_providers(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProvider>> {
+        Users::list_identity_providers(self, user_id).await
+    }
+
+    async fn list_all_identity_providers(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<crate::types::IdentityProvider>> {
+        Users::list_all_identity_providers(self, user_id).await
+    }
+
+    fn list_identity_providers_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IdentityProvider> {
+        Users::list_identity_providers_stream(self, user_id, start, max_items)
+    }
+
+    async fn activate_with(
+        &self,
+        user_id: &str,
+        params: &UsersActivateParams,
+    ) -> Result<crate::types::UserActivationToken> {
+        Users::activate_with(self, user_id, params).await
+    }
+
+    async fn deactivate_with(&self, user_id: &str, params: &UsersDeactivateParams) -> Result<()> {
+        Users::deactivate_with(self, user_id, params).await
+    }
+
+    async fn expire_password(&self, user_id: &str) -> Result<crate::types::User> {
+        Users::expire_password(self, user_id).await
+    }
+
+    async fn expire_password_and_get_temporary(
+        &self,
+        user_id: &str,
+    ) -> Result<crate::types::TempPassword> {
+        Users::expire_password_and_get_temporary(self, user_id).await
+    }
+
+    async fn reactivate_with(
+        &self,
+        user_id: &str,
+        params: &UsersReactivateParams,
+    ) -> Result<crate::types::UserActivationToken> {
+        Users::reactivate_with(self, user_id, params).await
+    }
+
+    async fn reset_factors(&self, user_id: &str) -> Result<()> {
+        Users::reset_factors(self, user_id).await
+    }
+
+    async fn reset_password_with(
+        &self,
+        user_id: &str,
+        params: &UsersResetPasswordParams,
+    ) -> Result<crate::types::ResetPasswordToken> {
+        Users::reset_password_with(self, user_id, params).await
+    }
+
+    async fn suspend(&self, user_id: &str) -> Result<()> {
+        Users::suspend(self, user_id).await
+    }
+
+    async fn unlock(&self, user_id: &str) -> Result<()> {
+        Users::unlock(self, user_id).await
+    }
+
+    async fn unsuspend(&self, user_id: &str) -> Result<()> {
+        Users::unsuspend(self, user_id).await
+    }
+
+    async fn get_linked_objects_fors_with(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+        params: &UsersGetLinkedObjectsForsParams,
+    ) -> Result<Vec<crate::types::ResponseLinks>> {
+        Users::get_linked_objects_fors_with(self, user_id, relationship_name, params).await
+    }
+
+    async fn get_all_linked_objects_fors(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+    ) -> Result<Vec<crate::types::ResponseLinks>> {
+        Users::get_all_linked_objects_fors(self, user_id, relationship_name).await
+    }
+
+    fn get_linked_objects_fors_stream(
+        &self,
+        user_id: &str,
+        relationship_name: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ResponseLinks> {
+        Users::get_linked_objects_fors_stream(self, user_id, relationship_name, start, max_items)
+    }
+
+    async fn remove_linked_object_for(&self, user_id: &str, relationship_name: &str) -> Result<()> {
+        Users::remove_linked_object_for(self, user_id, relationship_name).await
+    }
+
+    async fn list_assigned_roles_fors_with(
+        &self,
+        user_id: &str,
+        params: &UsersListAssignedRolesForsParams,
+    ) -> Result<Vec<crate::types::Role>> {
+        Users::list_assigned_roles_fors_with(self, user_id, params).await
+    }
+
+    async fn list_all_assigned_roles_fors_with(
+        &self,
+        user_id: &str,
+        params: &UsersListAssignedRolesForsParams,
+    ) -> Result<Vec<crate::types::Role>> {
+        Users::list_all_assigned_roles_fors_with(self, user_id, params).await
+    }
+
+    fn list_assigned_roles_fors_stream(
+        &self,
+        user_id: &str,
+        params: &UsersListAssignedRolesForsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Role> {
+        Users::list_assigned_roles_fors_stream(self, user_id, params, start, max_items)
+    }
+
+    async fn assign_role_with(
+        &self,
+        user_id: &str,
+        params: &UsersAssignRoleParams,
+        body: &crate::types::AssignRoleRequest,
+    ) -> Result<crate::types::Role> {
+        Users::assign_role_with(self, user_id, params, body).await
+    }
+
+    async fn remove_role_from(&self, user_id: &str, role_id: &str) -> Result<()> {
+        Users::remove_role_from(self, user_id, role_id).await
+    }
+
+    async fn list_application_targets_for_administrator_roles_with(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        params: &UsersListApplicationTargetsForAdministratorRolesParams,
+    ) -> Result<Vec<crate::types::CatalogApplication>> {
+        Users::list_application_targets_for_administrator_roles_with(self, user_id, role_id, params)
+            .await
+    }
+
+    async fn list_all_application_targets_for_administrator_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::CatalogApplication>> {
+        Users::list_all_application_targets_for_administrator_roles(self, user_id, role_id).await
+    }
+
+    fn list_application_targets_for_administrator_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CatalogApplication> {
+        Users::list_application_targets_for_administrator_roles_stream(
+            self, user_id, role_id, start, max_items,
+        )
+    }
+
+    async fn add_all_apps_as_target_role(&self, user_id: &str, role_id: &str) -> Result<()> {
+        Users::add_all_apps_as_target_role(self, user_id, role_id).await
+    }
+
+    async fn add_application_target_admin_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()> {
+        Users::add_application_target_admin_role_for(self, user_id, role_id, app_name).await
+    }
+
+    async fn remove_application_target_from_administrator_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+    ) -> Result<()> {
+        Users::remove_application_target_from_administrator_role_for(
+            self, user_id, role_id, app_name,
+        )
+        .await
+    }
+
+    async fn add_application_target_app_admin_role_for(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()> {
+        Users::add_application_target_app_admin_role_for(
+            self,
+            user_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await
+    }
+
+    async fn remove_application_target_from_administrator_role_for_users(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        app_name: &str,
+        application_id: &str,
+    ) -> Result<()> {
+        Users::remove_application_target_from_administrator_role_for_users(
+            self,
+            user_id,
+            role_id,
+            app_name,
+            application_id,
+        )
+        .await
+    }
+
+    async fn list_group_targets_for_roles_with(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        params: &UsersListGroupTargetsForRolesParams,
+    ) -> Result<Vec<crate::types::Group>> {
+        Users::list_group_targets_for_roles_with(self, user_id, role_id, params).await
+    }
+
+    async fn list_all_group_targets_for_roles(
+        &self,
+        user_id: &str,
+        role_id: &str,
+    ) -> Result<Vec<crate::types::Group>> {
+        Users::list_all_group_targets_for_roles(self, user_id, role_id).await
+    }
+
+    fn list_group_targets_for_roles_stream(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Group> {
+        Users::list_group_targets_for_roles_stream(self, user_id, role_id, start, max_items)
+    }
+
+    async fn add_group_target_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> Result<()> {
+        Users::add_group_target_role(self, user_id, role_id, group_id).await
+    }
+
+    async fn remove_group_target_from_role(
+        &self,
+        user_id: &str,
+        role_id: &str,
+        group_id: &str,
+    ) -> Result<()> {
+        Users::remove_group_target_from_role(self, user_id, role_id, group_id).await
+    }
+
+    async fn clear_sessions_with(
+        &self,
+        user_id: &str,
+        params: &UsersClearSessionsParams,
+    ) -> Result<()> {
+        Users::clear_sessions_with(self, user_id, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Auths {
     pub client: Client,
@@ -52,8 +50,39 @@ impl Auths {
      *
      * * `authorization: &str` -- Basic \<base64-encoded client_id:client_secret\>.
      */
-    pub async fn post_token(&self) -> Result<crate::types::OAuth2Token> {
+    pub async fn post_token(
+        &self,
+        body: &crate::types::PostTokenRequest,
+    ) -> Result<crate::types::OAuth2Token> {
         let url = "/token".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The functions of [`Auths`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAuthsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AuthsApi: Send + Sync {
+    /// See [`Auths::post_token`].
+    async fn post_token(
+        &self,
+        body: &crate::types::PostTokenRequest,
+    ) -> Result<crate::types::OAuth2Token>;
+}
+
+#[async_trait::async_trait]
+impl AuthsApi for Auths {
+    async fn post_token(
+        &self,
+        body: &crate::types::PostTokenRequest,
+    ) -> Result<crate::types::OAuth2Token> {
+        Auths::post_token(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Businesses {
     pub client: Client,
@@ -46,3 +44,26 @@ impl Businesses {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`Businesses`], for code that takes them as a trait, so that it can be
+/// tested with the `MockBusinessesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait BusinessesApi: Send + Sync {
+    /// See [`Businesses::get_resources_busine`].
+    async fn get_resources_busine(&self) -> Result<crate::types::Business>;
+
+    /// See [`Businesses::get_resources_business_current`].
+    async fn get_resources_business_current(&self) -> Result<crate::types::BusinessCurrentStatus>;
+}
+
+#[async_trait::async_trait]
+impl BusinessesApi for Businesses {
+    async fn get_resources_busine(&self) -> Result<crate::types::Business> {
+        Businesses::get_resources_busine(self).await
+    }
+
+    async fn get_resources_business_current(&self) -> Result<crate::types::BusinessCurrentStatus> {
+        Businesses::get_resources_business_current(self).await
+    }
+}
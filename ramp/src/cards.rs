@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Cards {
     pub client: Client,
@@ -21,30 +19,23 @@ impl Cards {
      *
      * **Parameters:**
      *
-     * * `authorization: &str` -- The OAuth2 token header.
-     * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
-     * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     * * `user_id: &str` -- The OAuth2 token header.
-     * * `card_program_id: &str` -- The OAuth2 token header.
+     * * `params: &CardsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        start: &str,
-        page_size: f64,
-        user_id: &str,
-        card_program_id: &str,
+        params: &CardsGetPageParams,
     ) -> Result<Vec<crate::types::Card>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !card_program_id.is_empty() {
+        if let Some(card_program_id) = &params.card_program_id {
             query_args.push(("card_program_id".to_string(), card_program_id.to_string()));
         }
-        if !page_size.to_string().is_empty() {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !start.is_empty() {
+        if let Some(start) = &params.start {
             query_args.push(("start".to_string(), start.to_string()));
         }
-        if !user_id.is_empty() {
+        if let Some(user_id) = &params.user_id {
             query_args.push(("user_id".to_string(), user_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -56,6 +47,26 @@ impl Cards {
         Ok(resp.cards.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `CardsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+        user_id: &str,
+        card_program_id: &str,
+    ) -> Result<Vec<crate::types::Card>> {
+        let params = CardsGetPageParams {
+            card_program_id: Some(card_program_id.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| !v.to_string().is_empty()),
+            start: Some(start.to_string()).filter(|s| !s.is_empty()),
+            user_id: Some(user_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * List cards.
      *
@@ -64,17 +75,20 @@ impl Cards {
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * Retrieve all cards.
+     *
+     * **Parameters:**
+     *
+     * * `params: &CardsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        user_id: &str,
-        card_program_id: &str,
+        params: &CardsGetPageParams,
     ) -> Result<Vec<crate::types::Card>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !card_program_id.is_empty() {
+        if let Some(card_program_id) = &params.card_program_id {
             query_args.push(("card_program_id".to_string(), card_program_id.to_string()));
         }
-        if !user_id.is_empty() {
+        if let Some(user_id) = &params.user_id {
             query_args.push(("user_id".to_string(), user_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -104,13 +118,10 @@ impl Cards {
                         "".to_string()
                     };
                 }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        anyhow::bail!(e);
-                    }
+                Err(crate::Error::Http { status, .. }) if status == http::StatusCode::NOT_FOUND => {
+                    page = "".to_string();
                 }
+                Err(e) => return Err(e),
             }
         }
 
@@ -118,6 +129,78 @@ impl Cards {
         Ok(cards)
     }
 
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `CardsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        user_id: &str,
+        card_program_id: &str,
+    ) -> Result<Vec<crate::types::Card>> {
+        let params = CardsGetPageParams {
+            card_program_id: Some(card_program_id.to_string()).filter(|s| !s.is_empty()),
+            user_id: Some(user_id.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * List cards.
+     *
+     * This function performs a `GET` to the `/cards` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieve all cards.
+     */
+    pub fn get_stream(
+        &self,
+        params: &CardsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Card> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(card_program_id) = &params.card_program_id {
+            query_args.push(("card_program_id".to_string(), card_program_id.to_string()));
+        }
+        if let Some(user_id) = &params.user_id {
+            query_args.push(("user_id".to_string(), user_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/cards?{}", query_);
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The cursor of the next page is its full URL.
+                let url = match page {
+                    Some(page) => page.trim_start_matches(crate::DEFAULT_HOST).to_string(),
+                    None => url,
+                };
+
+                match client
+                    .get::<crate::types::GetCardsResponse>(&url, None)
+                    .await
+                {
+                    Ok(resp) => Ok(crate::pagination::Page::new(
+                        resp.cards,
+                        Some(resp.page.next).filter(|n| !n.is_empty()),
+                    )),
+                    Err(crate::Error::Http { status, .. })
+                        if status == http::StatusCode::NOT_FOUND =>
+                    {
+                        Ok(crate::pagination::Page::new(Vec::new(), None))
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+        })
+    }
+
     /**
      * GET a card.
      *
@@ -129,7 +212,7 @@ impl Cards {
      *
      * * `authorization: &str` -- The OAuth2 token header.
      */
-    pub async fn get(&self, id: &str) -> Result<crate::types::Card> {
+    pub async fn get(&self, id: &str) -> Result<crate::types::CardData> {
         let url = format!("/cards/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.get(&url, None).await
@@ -172,7 +255,7 @@ impl Cards {
     pub async fn post_resources_physical(
         &self,
         body: &crate::types::PostResourcesCardPhysicalRequest,
-    ) -> Result<crate::types::TaskResponse> {
+    ) -> Result<crate::types::PostResourcesCardPhysicalResponse> {
         let url = "/cards/deferred/physical".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -193,7 +276,7 @@ impl Cards {
     pub async fn post_resources_virtual(
         &self,
         body: &crate::types::PostResourcesCardVirtualRequest,
-    ) -> Result<crate::types::TaskResponse> {
+    ) -> Result<crate::types::PostResourcesCardVirtualResponse> {
         let url = "/cards/deferred/virtual".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -210,8 +293,8 @@ impl Cards {
     pub async fn post_resources_termination(
         &self,
         id: &str,
-        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
-    ) -> Result<crate::types::TaskResponse> {
+        body: &crate::types::PostResourcesCardsCardTerminationRequest,
+    ) -> Result<crate::types::PostResourcesCardsCardTerminationResponse> {
         let url = format!(
             "/cards/{}/deferred/termination",
             crate::progenitor_support::encode_path(id),
@@ -233,7 +316,7 @@ impl Cards {
         &self,
         id: &str,
         body: &crate::types::PostResourcesCardsCardSuspensionRequest,
-    ) -> Result<crate::types::TaskResponse> {
+    ) -> Result<crate::types::PostResourcesCardsCardSuspensionResponse> {
         let url = format!(
             "/cards/{}/deferred/suspension",
             crate::progenitor_support::encode_path(id),
@@ -254,8 +337,8 @@ impl Cards {
     pub async fn post_resources_unsuspension(
         &self,
         id: &str,
-        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
-    ) -> Result<crate::types::TaskResponse> {
+        body: &crate::types::PostResourcesCardsCardUnsuspensionRequest,
+    ) -> Result<crate::types::PostResourcesCardsCardUnsuspensionResponse> {
         let url = format!(
             "/cards/{}/deferred/unsuspension",
             crate::progenitor_support::encode_path(id),
@@ -289,3 +372,184 @@ impl Cards {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Cards::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CardsGetPageParams {
+    /// The OAuth2 token header.
+    pub card_program_id: Option<String>,
+    /// The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    pub page_size: Option<f64>,
+    /// The ID of the last entity of the previous page, used for pagination to get the next page.
+    pub start: Option<String>,
+    /// The OAuth2 token header.
+    pub user_id: Option<String>,
+}
+
+impl CardsGetPageParams {
+    pub fn card_program_id<T: ToString>(mut self, card_program_id: T) -> Self {
+        self.card_program_id = Some(card_program_id.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn start<T: ToString>(mut self, start: T) -> Self {
+        self.start = Some(start.to_string());
+        self
+    }
+
+    pub fn user_id<T: ToString>(mut self, user_id: T) -> Self {
+        self.user_id = Some(user_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`Cards`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCardsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CardsApi: Send + Sync {
+    /// See [`Cards::get_page_with`].
+    async fn get_page_with(&self, params: &CardsGetPageParams) -> Result<Vec<crate::types::Card>>;
+
+    /// See [`Cards::get_all_with`].
+    async fn get_all_with(&self, params: &CardsGetPageParams) -> Result<Vec<crate::types::Card>>;
+
+    /// See [`Cards::get_stream`].
+    fn get_stream(
+        &self,
+        params: &CardsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Card>;
+
+    /// See [`Cards::get`].
+    async fn get(&self, id: &str) -> Result<crate::types::CardData>;
+
+    /// See [`Cards::patch_resources`].
+    async fn patch_resources(
+        &self,
+        id: &str,
+        body: &crate::types::PatchResourcesCardsCardRequest,
+    ) -> Result<()>;
+
+    /// See [`Cards::post_resources_physical`].
+    async fn post_resources_physical(
+        &self,
+        body: &crate::types::PostResourcesCardPhysicalRequest,
+    ) -> Result<crate::types::PostResourcesCardPhysicalResponse>;
+
+    /// See [`Cards::post_resources_virtual`].
+    async fn post_resources_virtual(
+        &self,
+        body: &crate::types::PostResourcesCardVirtualRequest,
+    ) -> Result<crate::types::PostResourcesCardVirtualResponse>;
+
+    /// See [`Cards::post_resources_termination`].
+    async fn post_resources_termination(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardTerminationRequest,
+    ) -> Result<crate::types::PostResourcesCardsCardTerminationResponse>;
+
+    /// See [`Cards::post_resources_suspension`].
+    async fn post_resources_suspension(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> Result<crate::types::PostResourcesCardsCardSuspensionResponse>;
+
+    /// See [`Cards::post_resources_unsuspension`].
+    async fn post_resources_unsuspension(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardUnsuspensionRequest,
+    ) -> Result<crate::types::PostResourcesCardsCardUnsuspensionResponse>;
+
+    /// See [`Cards::get_resources_deferred`].
+    async fn get_resources_deferred(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::GetResourcesCardsDeferredResponse>;
+}
+
+#[async_trait::async_trait]
+impl CardsApi for Cards {
+    async fn get_page_with(&self, params: &CardsGetPageParams) -> Result<Vec<crate::types::Card>> {
+        Cards::get_page_with(self, params).await
+    }
+
+    async fn get_all_with(&self, params: &CardsGetPageParams) -> Result<Vec<crate::types::Card>> {
+        Cards::get_all_with(self, params).await
+    }
+
+    fn get_stream(
+        &self,
+        params: &CardsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Card> {
+        Cards::get_stream(self, params, start, max_items)
+    }
+
+    async fn get(&self, id: &str) -> Result<crate::types::CardData> {
+        Cards::get(self, id).await
+    }
+
+    async fn patch_resources(
+        &self,
+        id: &str,
+        body: &crate::types::PatchResourcesCardsCardRequest,
+    ) -> Result<()> {
+        Cards::patch_resources(self, id, body).await
+    }
+
+    async fn post_resources_physical(
+        &self,
+        body: &crate::types::PostResourcesCardPhysicalRequest,
+    ) -> Result<crate::types::PostResourcesCardPhysicalResponse> {
+        Cards::post_resources_physical(self, body).await
+    }
+
+    async fn post_resources_virtual(
+        &self,
+        body: &crate::types::PostResourcesCardVirtualRequest,
+    ) -> Result<crate::types::PostResourcesCardVirtualResponse> {
+        Cards::post_resources_virtual(self, body).await
+    }
+
+    async fn post_resources_termination(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardTerminationRequest,
+    ) -> Result<crate::types::PostResourcesCardsCardTerminationResponse> {
+        Cards::post_resources_termination(self, id, body).await
+    }
+
+    async fn post_resources_suspension(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardSuspensionRequest,
+    ) -> Result<crate::types::PostResourcesCardsCardSuspensionResponse> {
+        Cards::post_resources_suspension(self, id, body).await
+    }
+
+    async fn post_resources_unsuspension(
+        &self,
+        id: &str,
+        body: &crate::types::PostResourcesCardsCardUnsuspensionRequest,
+    ) -> Result<crate::types::PostResourcesCardsCardUnsuspensionResponse> {
+        Cards::post_resources_unsuspension(self, id, body).await
+    }
+
+    async fn get_resources_deferred(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::GetResourcesCardsDeferredResponse> {
+        Cards::get_resources_deferred(self, id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CustomIds {
     pub client: Client,
@@ -51,7 +49,7 @@ impl CustomIds {
      */
     pub async fn post_custom_provider_application_link(
         &self,
-        body: &crate::types::GetCustomProviderResponse,
+        body: &crate::types::PostCustomProviderApplicationLinkRequest,
     ) -> Result<()> {
         let url = "/custom-id-provider/application-link".to_string();
         self.client
@@ -129,3 +127,84 @@ impl CustomIds {
             .await
     }
 }
+
+/// The functions of [`CustomIds`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCustomIdsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CustomIdsApi: Send + Sync {
+    /// See [`CustomIds::get_custom_provider`].
+    async fn get_custom_provider(&self) -> Result<crate::types::GetCustomProviderResponse>;
+
+    /// See [`CustomIds::postcustom_provider`].
+    async fn postcustom_provider(&self) -> Result<crate::types::PostcustomProviderResponse>;
+
+    /// See [`CustomIds::post_custom_provider_application_link`].
+    async fn post_custom_provider_application_link(
+        &self,
+        body: &crate::types::PostCustomProviderApplicationLinkRequest,
+    ) -> Result<()>;
+
+    /// See [`CustomIds::get_entity_type_custom_ramp`].
+    async fn get_entity_type_custom_ramp(
+        &self,
+        entity_type: &str,
+        custom_id: &str,
+    ) -> Result<crate::types::GetEntityTypeCustomRampResponse>;
+
+    /// See [`CustomIds::get_entity_type_ramp_custom`].
+    async fn get_entity_type_ramp_custom(
+        &self,
+        entity_type: &str,
+        ramp_id: &str,
+    ) -> Result<crate::types::GetEntityTypeRampCustomResponse>;
+
+    /// See [`CustomIds::post_custom_provider_entity_type_link`].
+    async fn post_custom_provider_entity_type_link(
+        &self,
+        entity_type: &str,
+        body: &crate::types::PostCustomProviderEntityTypeLinkRequest,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl CustomIdsApi for CustomIds {
+    async fn get_custom_provider(&self) -> Result<crate::types::GetCustomProviderResponse> {
+        CustomIds::get_custom_provider(self).await
+    }
+
+    async fn postcustom_provider(&self) -> Result<crate::types::PostcustomProviderResponse> {
+        CustomIds::postcustom_provider(self).await
+    }
+
+    async fn post_custom_provider_application_link(
+        &self,
+        body: &crate::types::PostCustomProviderApplicationLinkRequest,
+    ) -> Result<()> {
+        CustomIds::post_custom_provider_application_link(self, body).await
+    }
+
+    async fn get_entity_type_custom_ramp(
+        &self,
+        entity_type: &str,
+        custom_id: &str,
+    ) -> Result<crate::types::GetEntityTypeCustomRampResponse> {
+        CustomIds::get_entity_type_custom_ramp(self, entity_type, custom_id).await
+    }
+
+    async fn get_entity_type_ramp_custom(
+        &self,
+        entity_type: &str,
+        ramp_id: &str,
+    ) -> Result<crate::types::GetEntityTypeRampCustomResponse> {
+        CustomIds::get_entity_type_ramp_custom(self, entity_type, ramp_id).await
+    }
+
+    async fn post_custom_provider_entity_type_link(
+        &self,
+        entity_type: &str,
+        body: &crate::types::PostCustomProviderEntityTypeLinkRequest,
+    ) -> Result<()> {
+        CustomIds::post_custom_provider_entity_type_link(self, entity_type, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Departments {
     pub client: Client,
@@ -21,20 +19,17 @@ impl Departments {
      *
      * **Parameters:**
      *
-     * * `authorization: &str` -- The OAuth2 token header.
-     * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
-     * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+     * * `params: &DepartmentsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        start: &str,
-        page_size: f64,
+        params: &DepartmentsGetPageParams,
     ) -> Result<Vec<crate::types::Department>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !page_size.to_string().is_empty() {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !start.is_empty() {
+        if let Some(start) = &params.start {
             query_args.push(("start".to_string(), start.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -46,6 +41,22 @@ impl Departments {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `DepartmentsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> Result<Vec<crate::types::Department>> {
+        let params = DepartmentsGetPageParams {
+            page_size: Some(page_size).filter(|v| !v.to_string().is_empty()),
+            start: Some(start.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * List departments.
      *
@@ -81,13 +92,10 @@ impl Departments {
                         "".to_string()
                     };
                 }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        anyhow::bail!(e);
-                    }
+                Err(crate::Error::Http { status, .. }) if status == http::StatusCode::NOT_FOUND => {
+                    page = "".to_string();
                 }
+                Err(e) => return Err(e),
             }
         }
 
@@ -95,6 +103,51 @@ impl Departments {
         Ok(data)
     }
 
+    /**
+     * List departments.
+     *
+     * This function performs a `GET` to the `/departments` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieve all departments.
+     */
+    pub fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Department> {
+        let url = "/departments".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The cursor of the next page is its full URL.
+                let url = match page {
+                    Some(page) => page.trim_start_matches(crate::DEFAULT_HOST).to_string(),
+                    None => url,
+                };
+
+                match client
+                    .get::<crate::types::GetDepartmentsResponse>(&url, None)
+                    .await
+                {
+                    Ok(resp) => Ok(crate::pagination::Page::new(
+                        resp.data,
+                        Some(resp.page.next).filter(|n| !n.is_empty()),
+                    )),
+                    Err(crate::Error::Http { status, .. })
+                        if status == http::StatusCode::NOT_FOUND =>
+                    {
+                        Ok(crate::pagination::Page::new(Vec::new(), None))
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+        })
+    }
+
     /**
      * Create department.
      *
@@ -104,7 +157,7 @@ impl Departments {
      */
     pub async fn post(
         &self,
-        body: &crate::types::PostLocationRequest,
+        body: &crate::types::PostDepartmentsRequest,
     ) -> Result<crate::types::Department> {
         let url = "/departments".to_string();
         self.client
@@ -142,7 +195,7 @@ impl Departments {
     pub async fn patch(
         &self,
         id: &str,
-        body: &crate::types::PostLocationRequest,
+        body: &crate::types::PatchDepartmentRequest,
     ) -> Result<crate::types::Department> {
         let url = format!(
             "/departments/{}",
@@ -154,3 +207,103 @@ impl Departments {
             .await
     }
 }
+
+/// The query parameters of `Departments::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DepartmentsGetPageParams {
+    /// The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    pub page_size: Option<f64>,
+    /// The ID of the last entity of the previous page, used for pagination to get the next page.
+    pub start: Option<String>,
+}
+
+impl DepartmentsGetPageParams {
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn start<T: ToString>(mut self, start: T) -> Self {
+        self.start = Some(start.to_string());
+        self
+    }
+}
+
+/// The functions of [`Departments`], for code that takes them as a trait, so that it can be
+/// tested with the `MockDepartmentsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait DepartmentsApi: Send + Sync {
+    /// See [`Departments::get_page_with`].
+    async fn get_page_with(
+        &self,
+        params: &DepartmentsGetPageParams,
+    ) -> Result<Vec<crate::types::Department>>;
+
+    /// See [`Departments::get_all`].
+    async fn get_all(&self) -> Result<Vec<crate::types::Department>>;
+
+    /// See [`Departments::get_stream`].
+    fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Department>;
+
+    /// See [`Departments::post`].
+    async fn post(
+        &self,
+        body: &crate::types::PostDepartmentsRequest,
+    ) -> Result<crate::types::Department>;
+
+    /// See [`Departments::get`].
+    async fn get(&self, id: &str) -> Result<crate::types::Department>;
+
+    /// See [`Departments::patch`].
+    async fn patch(
+        &self,
+        id: &str,
+        body: &crate::types::PatchDepartmentRequest,
+    ) -> Result<crate::types::Department>;
+}
+
+#[async_trait::async_trait]
+impl DepartmentsApi for Departments {
+    async fn get_page_with(
+        &self,
+        params: &DepartmentsGetPageParams,
+    ) -> Result<Vec<crate::types::Department>> {
+        Departments::get_page_with(self, params).await
+    }
+
+    async fn get_all(&self) -> Result<Vec<crate::types::Department>> {
+        Departments::get_all(self).await
+    }
+
+    fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Department> {
+        Departments::get_stream(self, start, max_items)
+    }
+
+    async fn post(
+        &self,
+        body: &crate::types::PostDepartmentsRequest,
+    ) -> Result<crate::types::Department> {
+        Departments::post(self, body).await
+    }
+
+    async fn get(&self, id: &str) -> Result<crate::types::Department> {
+        Departments::get(self, id).await
+    }
+
+    async fn patch(
+        &self,
+        id: &str,
+        body: &crate::types::PatchDepartmentRequest,
+    ) -> Result<crate::types::Department> {
+        Departments::patch(self, id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Locations {
     pub client: Client,
@@ -21,20 +19,17 @@ impl Locations {
      *
      * **Parameters:**
      *
-     * * `authorization: &str` -- The OAuth2 token header.
-     * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
-     * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+     * * `params: &LocationsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        start: &str,
-        page_size: f64,
+        params: &LocationsGetPageParams,
     ) -> Result<Vec<crate::types::Location>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !page_size.to_string().is_empty() {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !start.is_empty() {
+        if let Some(start) = &params.start {
             query_args.push(("start".to_string(), start.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -46,6 +41,22 @@ impl Locations {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `LocationsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> Result<Vec<crate::types::Location>> {
+        let params = LocationsGetPageParams {
+            page_size: Some(page_size).filter(|v| !v.to_string().is_empty()),
+            start: Some(start.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * List locations.
      *
@@ -81,13 +92,10 @@ impl Locations {
                         "".to_string()
                     };
                 }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        anyhow::bail!(e);
-                    }
+                Err(crate::Error::Http { status, .. }) if status == http::StatusCode::NOT_FOUND => {
+                    page = "".to_string();
                 }
+                Err(e) => return Err(e),
             }
         }
 
@@ -95,6 +103,51 @@ impl Locations {
         Ok(data)
     }
 
+    /**
+     * List locations.
+     *
+     * This function performs a `GET` to the `/locations` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieves all locations for your business.
+     */
+    pub fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Location> {
+        let url = "/locations".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The cursor of the next page is its full URL.
+                let url = match page {
+                    Some(page) => page.trim_start_matches(crate::DEFAULT_HOST).to_string(),
+                    None => url,
+                };
+
+                match client
+                    .get::<crate::types::GetLocationResponse>(&url, None)
+                    .await
+                {
+                    Ok(resp) => Ok(crate::pagination::Page::new(
+                        resp.data,
+                        Some(resp.page.next).filter(|n| !n.is_empty()),
+                    )),
+                    Err(crate::Error::Http { status, .. })
+                        if status == http::StatusCode::NOT_FOUND =>
+                    {
+                        Ok(crate::pagination::Page::new(Vec::new(), None))
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+        })
+    }
+
     /**
      * Create new location.
      *
@@ -143,7 +196,7 @@ impl Locations {
     pub async fn patch(
         &self,
         id: &str,
-        body: &crate::types::PostLocationRequest,
+        body: &crate::types::PatchLocationRequest,
     ) -> Result<crate::types::Location> {
         let url = format!("/locations/{}", crate::progenitor_support::encode_path(id),);
 
@@ -152,3 +205,103 @@ impl Locations {
             .await
     }
 }
+
+/// The query parameters of `Locations::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct LocationsGetPageParams {
+    /// The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    pub page_size: Option<f64>,
+    /// The ID of the last entity of the previous page, used for pagination to get the next page.
+    pub start: Option<String>,
+}
+
+impl LocationsGetPageParams {
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn start<T: ToString>(mut self, start: T) -> Self {
+        self.start = Some(start.to_string());
+        self
+    }
+}
+
+/// The functions of [`Locations`], for code that takes them as a trait, so that it can be
+/// tested with the `MockLocationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait LocationsApi: Send + Sync {
+    /// See [`Locations::get_page_with`].
+    async fn get_page_with(
+        &self,
+        params: &LocationsGetPageParams,
+    ) -> Result<Vec<crate::types::Location>>;
+
+    /// See [`Locations::get_all`].
+    async fn get_all(&self) -> Result<Vec<crate::types::Location>>;
+
+    /// See [`Locations::get_stream`].
+    fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Location>;
+
+    /// See [`Locations::post`].
+    async fn post(
+        &self,
+        body: &crate::types::PostLocationRequest,
+    ) -> Result<crate::types::Location>;
+
+    /// See [`Locations::get`].
+    async fn get(&self, id: &str) -> Result<crate::types::Location>;
+
+    /// See [`Locations::patch`].
+    async fn patch(
+        &self,
+        id: &str,
+        body: &crate::types::PatchLocationRequest,
+    ) -> Result<crate::types::Location>;
+}
+
+#[async_trait::async_trait]
+impl LocationsApi for Locations {
+    async fn get_page_with(
+        &self,
+        params: &LocationsGetPageParams,
+    ) -> Result<Vec<crate::types::Location>> {
+        Locations::get_page_with(self, params).await
+    }
+
+    async fn get_all(&self) -> Result<Vec<crate::types::Location>> {
+        Locations::get_all(self).await
+    }
+
+    fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Location> {
+        Locations::get_stream(self, start, max_items)
+    }
+
+    async fn post(
+        &self,
+        body: &crate::types::PostLocationRequest,
+    ) -> Result<crate::types::Location> {
+        Locations::post(self, body).await
+    }
+
+    async fn get(&self, id: &str) -> Result<crate::types::Location> {
+        Locations::get(self, id).await
+    }
+
+    async fn patch(
+        &self,
+        id: &str,
+        body: &crate::types::PatchLocationRequest,
+    ) -> Result<crate::types::Location> {
+        Locations::patch(self, id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Receipts {
     pub client: Client,
@@ -21,40 +19,30 @@ impl Receipts {
      *
      * **Parameters:**
      *
-     * * `from_date: chrono::DateTime<chrono::Utc>` -- Filter for receipts related to transactions which occurred after the specified date.
-     * * `to_date: chrono::DateTime<chrono::Utc>` -- Filter for receipts related to transactions which occurred before the specified date.
-     * * `created_after: chrono::DateTime<chrono::Utc>` -- Filter for receipts that were created after the specified date.
-     * * `created_before: chrono::DateTime<chrono::Utc>` -- Filter for receipts that were created before the specified date.
-     * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
-     * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+     * * `params: &ReceiptsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        from_date: Option<chrono::DateTime<chrono::Utc>>,
-        to_date: Option<chrono::DateTime<chrono::Utc>>,
-        created_after: Option<chrono::DateTime<chrono::Utc>>,
-        created_before: Option<chrono::DateTime<chrono::Utc>>,
-        start: &str,
-        page_size: f64,
+        params: &ReceiptsGetPageParams,
     ) -> Result<Vec<crate::types::Receipt>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = created_after {
-            query_args.push(("created_after".to_string(), date.to_rfc3339()));
+        if let Some(created_after) = &params.created_after {
+            query_args.push(("created_after".to_string(), created_after.to_rfc3339()));
         }
-        if let Some(date) = created_before {
-            query_args.push(("created_before".to_string(), date.to_rfc3339()));
+        if let Some(created_before) = &params.created_before {
+            query_args.push(("created_before".to_string(), created_before.to_rfc3339()));
         }
-        if let Some(date) = from_date {
-            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+        if let Some(from_date) = &params.from_date {
+            query_args.push(("from_date".to_string(), from_date.to_rfc3339()));
         }
-        if !page_size.to_string().is_empty() {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !start.is_empty() {
+        if let Some(start) = &params.start {
             query_args.push(("start".to_string(), start.to_string()));
         }
-        if let Some(date) = to_date {
-            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+        if let Some(to_date) = &params.to_date {
+            query_args.push(("to_date".to_string(), to_date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/receipts?{}", query_);
@@ -65,6 +53,30 @@ impl Receipts {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `ReceiptsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+        start: &str,
+        page_size: f64,
+    ) -> Result<Vec<crate::types::Receipt>> {
+        let params = ReceiptsGetPageParams {
+            created_after,
+            created_before,
+            from_date,
+            page_size: Some(page_size).filter(|v| !v.to_string().is_empty()),
+            start: Some(start.to_string()).filter(|s| !s.is_empty()),
+            to_date,
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * List receipts.
      *
@@ -73,26 +85,27 @@ impl Receipts {
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * Returns description of all receipts of a business.
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReceiptsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        from_date: Option<chrono::DateTime<chrono::Utc>>,
-        to_date: Option<chrono::DateTime<chrono::Utc>>,
-        created_after: Option<chrono::DateTime<chrono::Utc>>,
-        created_before: Option<chrono::DateTime<chrono::Utc>>,
+        params: &ReceiptsGetPageParams,
     ) -> Result<Vec<crate::types::Receipt>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = created_after {
-            query_args.push(("created_after".to_string(), date.to_rfc3339()));
+        if let Some(created_after) = &params.created_after {
+            query_args.push(("created_after".to_string(), created_after.to_rfc3339()));
         }
-        if let Some(date) = created_before {
-            query_args.push(("created_before".to_string(), date.to_rfc3339()));
+        if let Some(created_before) = &params.created_before {
+            query_args.push(("created_before".to_string(), created_before.to_rfc3339()));
         }
-        if let Some(date) = from_date {
-            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+        if let Some(from_date) = &params.from_date {
+            query_args.push(("from_date".to_string(), from_date.to_rfc3339()));
         }
-        if let Some(date) = to_date {
-            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+        if let Some(to_date) = &params.to_date {
+            query_args.push(("to_date".to_string(), to_date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/receipts?{}", query_);
@@ -121,13 +134,10 @@ impl Receipts {
                         "".to_string()
                     };
                 }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        anyhow::bail!(e);
-                    }
+                Err(crate::Error::Http { status, .. }) if status == http::StatusCode::NOT_FOUND => {
+                    page = "".to_string();
                 }
+                Err(e) => return Err(e),
             }
         }
 
@@ -135,6 +145,88 @@ impl Receipts {
         Ok(data)
     }
 
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `ReceiptsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        created_after: Option<chrono::DateTime<chrono::Utc>>,
+        created_before: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<crate::types::Receipt>> {
+        let params = ReceiptsGetPageParams {
+            created_after,
+            created_before,
+            from_date,
+            to_date,
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * List receipts.
+     *
+     * This function performs a `GET` to the `/receipts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Returns description of all receipts of a business.
+     */
+    pub fn get_stream(
+        &self,
+        params: &ReceiptsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Receipt> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created_after) = &params.created_after {
+            query_args.push(("created_after".to_string(), created_after.to_rfc3339()));
+        }
+        if let Some(created_before) = &params.created_before {
+            query_args.push(("created_before".to_string(), created_before.to_rfc3339()));
+        }
+        if let Some(from_date) = &params.from_date {
+            query_args.push(("from_date".to_string(), from_date.to_rfc3339()));
+        }
+        if let Some(to_date) = &params.to_date {
+            query_args.push(("to_date".to_string(), to_date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/receipts?{}", query_);
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The cursor of the next page is its full URL.
+                let url = match page {
+                    Some(page) => page.trim_start_matches(crate::DEFAULT_HOST).to_string(),
+                    None => url,
+                };
+
+                match client
+                    .get::<crate::types::GetReceiptsResponse>(&url, None)
+                    .await
+                {
+                    Ok(resp) => Ok(crate::pagination::Page::new(
+                        resp.data,
+                        Some(resp.page.next).filter(|n| !n.is_empty()),
+                    )),
+                    Err(crate::Error::Http { status, .. })
+                        if status == http::StatusCode::NOT_FOUND =>
+                    {
+                        Ok(crate::pagination::Page::new(Vec::new(), None))
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+        })
+    }
+
     /**
      * Get details for one receipt.
      *
@@ -148,3 +240,111 @@ impl Receipts {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Receipts::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReceiptsGetPageParams {
+    /// Filter for receipts that were created after the specified date.
+    pub created_after: Option<chrono::DateTime<chrono::Utc>>,
+    /// Filter for receipts that were created before the specified date.
+    pub created_before: Option<chrono::DateTime<chrono::Utc>>,
+    /// Filter for receipts related to transactions which occurred after the specified date.
+    pub from_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    pub page_size: Option<f64>,
+    /// The ID of the last entity of the previous page, used for pagination to get the next page.
+    pub start: Option<String>,
+    /// Filter for receipts related to transactions which occurred before the specified date.
+    pub to_date: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ReceiptsGetPageParams {
+    pub fn created_after(mut self, created_after: chrono::DateTime<chrono::Utc>) -> Self {
+        self.created_after = Some(created_after);
+        self
+    }
+
+    pub fn created_before(mut self, created_before: chrono::DateTime<chrono::Utc>) -> Self {
+        self.created_before = Some(created_before);
+        self
+    }
+
+    pub fn from_date(mut self, from_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn start<T: ToString>(mut self, start: T) -> Self {
+        self.start = Some(start.to_string());
+        self
+    }
+
+    pub fn to_date(mut self, to_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+}
+
+/// The functions of [`Receipts`], for code that takes them as a trait, so that it can be
+/// tested with the `MockReceiptsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReceiptsApi: Send + Sync {
+    /// See [`Receipts::get_page_with`].
+    async fn get_page_with(
+        &self,
+        params: &ReceiptsGetPageParams,
+    ) -> Result<Vec<crate::types::Receipt>>;
+
+    /// See [`Receipts::get_all_with`].
+    async fn get_all_with(
+        &self,
+        params: &ReceiptsGetPageParams,
+    ) -> Result<Vec<crate::types::Receipt>>;
+
+    /// See [`Receipts::get_stream`].
+    fn get_stream(
+        &self,
+        params: &ReceiptsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Receipt>;
+
+    /// See [`Receipts::get`].
+    async fn get(&self, id: &str) -> Result<crate::types::Receipt>;
+}
+
+#[async_trait::async_trait]
+impl ReceiptsApi for Receipts {
+    async fn get_page_with(
+        &self,
+        params: &ReceiptsGetPageParams,
+    ) -> Result<Vec<crate::types::Receipt>> {
+        Receipts::get_page_with(self, params).await
+    }
+
+    async fn get_all_with(
+        &self,
+        params: &ReceiptsGetPageParams,
+    ) -> Result<Vec<crate::types::Receipt>> {
+        Receipts::get_all_with(self, params).await
+    }
+
+    fn get_stream(
+        &self,
+        params: &ReceiptsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Receipt> {
+        Receipts::get_stream(self, params, start, max_items)
+    }
+
+    async fn get(&self, id: &str) -> Result<crate::types::Receipt> {
+        Receipts::get(self, id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Reimbursements {
     pub client: Client,
@@ -19,19 +17,17 @@ impl Reimbursements {
      *
      * **Parameters:**
      *
-     * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
-     * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+     * * `params: &ReimbursementsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        start: &str,
-        page_size: f64,
+        params: &ReimbursementsGetPageParams,
     ) -> Result<Vec<crate::types::Reimbursement>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !page_size.to_string().is_empty() {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !start.is_empty() {
+        if let Some(start) = &params.start {
             query_args.push(("start".to_string(), start.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -43,6 +39,22 @@ impl Reimbursements {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `ReimbursementsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+    ) -> Result<Vec<crate::types::Reimbursement>> {
+        let params = ReimbursementsGetPageParams {
+            page_size: Some(page_size).filter(|v| !v.to_string().is_empty()),
+            start: Some(start.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * List Reimbursements.
      *
@@ -76,13 +88,10 @@ impl Reimbursements {
                         "".to_string()
                     };
                 }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        anyhow::bail!(e);
-                    }
+                Err(crate::Error::Http { status, .. }) if status == http::StatusCode::NOT_FOUND => {
+                    page = "".to_string();
                 }
+                Err(e) => return Err(e),
             }
         }
 
@@ -90,6 +99,49 @@ impl Reimbursements {
         Ok(data)
     }
 
+    /**
+     * List Reimbursements.
+     *
+     * This function performs a `GET` to the `/reimbursements` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     */
+    pub fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reimbursement> {
+        let url = "/reimbursements".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The cursor of the next page is its full URL.
+                let url = match page {
+                    Some(page) => page.trim_start_matches(crate::DEFAULT_HOST).to_string(),
+                    None => url,
+                };
+
+                match client
+                    .get::<crate::types::GetReimbursementsResponse>(&url, None)
+                    .await
+                {
+                    Ok(resp) => Ok(crate::pagination::Page::new(
+                        resp.data,
+                        Some(resp.page.next).filter(|n| !n.is_empty()),
+                    )),
+                    Err(crate::Error::Http { status, .. })
+                        if status == http::StatusCode::NOT_FOUND =>
+                    {
+                        Ok(crate::pagination::Page::new(Vec::new(), None))
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+        })
+    }
+
     /**
      * Get details for one reimbursement.
      *
@@ -104,3 +156,75 @@ impl Reimbursements {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Reimbursements::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReimbursementsGetPageParams {
+    /// The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    pub page_size: Option<f64>,
+    /// The ID of the last entity of the previous page, used for pagination to get the next page.
+    pub start: Option<String>,
+}
+
+impl ReimbursementsGetPageParams {
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn start<T: ToString>(mut self, start: T) -> Self {
+        self.start = Some(start.to_string());
+        self
+    }
+}
+
+/// The functions of [`Reimbursements`], for code that takes them as a trait, so that it can be
+/// tested with the `MockReimbursementsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReimbursementsApi: Send + Sync {
+    /// See [`Reimbursements::get_page_with`].
+    async fn get_page_with(
+        &self,
+        params: &ReimbursementsGetPageParams,
+    ) -> Result<Vec<crate::types::Reimbursement>>;
+
+    /// See [`Reimbursements::get_all`].
+    async fn get_all(&self) -> Result<Vec<crate::types::Reimbursement>>;
+
+    /// See [`Reimbursements::get_stream`].
+    fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reimbursement>;
+
+    /// See [`Reimbursements::get`].
+    async fn get(&self, id: &str) -> Result<crate::types::Reimbursement>;
+}
+
+#[async_trait::async_trait]
+impl ReimbursementsApi for Reimbursements {
+    async fn get_page_with(
+        &self,
+        params: &ReimbursementsGetPageParams,
+    ) -> Result<Vec<crate::types::Reimbursement>> {
+        Reimbursements::get_page_with(self, params).await
+    }
+
+    async fn get_all(&self) -> Result<Vec<crate::types::Reimbursement>> {
+        Reimbursements::get_all(self).await
+    }
+
+    fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Reimbursement> {
+        Reimbursements::get_stream(self, start, max_items)
+    }
+
+    async fn get(&self, id: &str) -> Result<crate::types::Reimbursement> {
+        Reimbursements::get(self, id).await
+    }
+}
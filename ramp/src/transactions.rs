@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Transactions {
     pub client: Client,
@@ -21,103 +19,72 @@ impl Transactions {
      *
      * **Parameters:**
      *
-     * * `authorization: &str` -- The OAuth2 token header.
-     * * `department_id: &str` -- The OAuth2 token header.
-     * * `location_id: &str` -- The OAuth2 token header.
-     * * `from_date: chrono::DateTime<chrono::Utc>`
-     * * `to_date: chrono::DateTime<chrono::Utc>`
-     * * `merchant_id: &str` -- The OAuth2 token header.
-     * * `sk_category_id: &str` -- The OAuth2 token header.
-     * * `order_by_date_desc: bool`
-     * * `order_by_date_asc: bool`
-     * * `order_by_amount_desc: bool`
-     * * `order_by_amount_asc: bool`
-     * * `state: &str` -- The OAuth2 token header.
-     * * `min_amount: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     * * `max_amount: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
-     * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     * * `requires_memo: bool` -- Filters for transactions which require a memo, but do not have one. This can only be set to true.
+     * * `params: &TransactionsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        department_id: &str,
-        location_id: &str,
-        from_date: Option<chrono::DateTime<chrono::Utc>>,
-        to_date: Option<chrono::DateTime<chrono::Utc>>,
-        merchant_id: &str,
-        sk_category_id: &str,
-        order_by_date_desc: bool,
-        order_by_date_asc: bool,
-        order_by_amount_desc: bool,
-        order_by_amount_asc: bool,
-        state: &str,
-        min_amount: f64,
-        max_amount: f64,
-        start: &str,
-        page_size: f64,
-        requires_memo: bool,
+        params: &TransactionsGetPageParams,
     ) -> Result<Vec<crate::types::Data>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !department_id.is_empty() {
+        if let Some(department_id) = &params.department_id {
             query_args.push(("department_id".to_string(), department_id.to_string()));
         }
-        if let Some(date) = from_date {
-            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+        if let Some(from_date) = &params.from_date {
+            query_args.push(("from_date".to_string(), from_date.to_rfc3339()));
         }
-        if !location_id.is_empty() {
+        if let Some(location_id) = &params.location_id {
             query_args.push(("location_id".to_string(), location_id.to_string()));
         }
-        if !max_amount.to_string().is_empty() {
+        if let Some(max_amount) = &params.max_amount {
             query_args.push(("max_amount".to_string(), max_amount.to_string()));
         }
-        if !merchant_id.is_empty() {
+        if let Some(merchant_id) = &params.merchant_id {
             query_args.push(("merchant_id".to_string(), merchant_id.to_string()));
         }
-        if !min_amount.to_string().is_empty() {
+        if let Some(min_amount) = &params.min_amount {
             query_args.push(("min_amount".to_string(), min_amount.to_string()));
         }
-        if order_by_amount_asc {
+        if let Some(order_by_amount_asc) = &params.order_by_amount_asc {
             query_args.push((
                 "order_by_amount_asc".to_string(),
                 order_by_amount_asc.to_string(),
             ));
         }
-        if order_by_amount_desc {
+        if let Some(order_by_amount_desc) = &params.order_by_amount_desc {
             query_args.push((
                 "order_by_amount_desc".to_string(),
                 order_by_amount_desc.to_string(),
             ));
         }
-        if order_by_date_asc {
+        if let Some(order_by_date_asc) = &params.order_by_date_asc {
             query_args.push((
                 "order_by_date_asc".to_string(),
                 order_by_date_asc.to_string(),
             ));
         }
-        if order_by_date_desc {
+        if let Some(order_by_date_desc) = &params.order_by_date_desc {
             query_args.push((
                 "order_by_date_desc".to_string(),
                 order_by_date_desc.to_string(),
             ));
         }
-        if !page_size.to_string().is_empty() {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if requires_memo {
+        if let Some(requires_memo) = &params.requires_memo {
             query_args.push(("requires_memo".to_string(), requires_memo.to_string()));
         }
-        if !sk_category_id.is_empty() {
+        if let Some(sk_category_id) = &params.sk_category_id {
             query_args.push(("sk_category_id".to_string(), sk_category_id.to_string()));
         }
-        if !start.is_empty() {
+        if let Some(start) = &params.start {
             query_args.push(("start".to_string(), start.to_string()));
         }
-        if !state.is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
-        if let Some(date) = to_date {
-            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+        if let Some(to_date) = &params.to_date {
+            query_args.push(("to_date".to_string(), to_date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/transactions?{}", query_);
@@ -129,15 +96,10 @@ impl Transactions {
     }
 
     /**
-     * List transactions.
-     *
-     * This function performs a `GET` to the `/transactions` endpoint.
-     *
-     * As opposed to `get`, this function returns all the pages of the request at once.
-     *
-     * Retrieves all transactions for the business. This endpoint supports filtering and ordering. NOTE: only one ordering param is supported.
+     * Deprecated: use `get_page_with` and pass the query parameters as a `TransactionsGetPageParams` instead.
      */
-    pub async fn get_all(
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
         &self,
         department_id: &str,
         location_id: &str,
@@ -152,62 +114,102 @@ impl Transactions {
         state: &str,
         min_amount: f64,
         max_amount: f64,
+        start: &str,
+        page_size: f64,
         requires_memo: bool,
+    ) -> Result<Vec<crate::types::Data>> {
+        let params = TransactionsGetPageParams {
+            department_id: Some(department_id.to_string()).filter(|s| !s.is_empty()),
+            from_date,
+            location_id: Some(location_id.to_string()).filter(|s| !s.is_empty()),
+            max_amount: Some(max_amount).filter(|v| !v.to_string().is_empty()),
+            merchant_id: Some(merchant_id.to_string()).filter(|s| !s.is_empty()),
+            min_amount: Some(min_amount).filter(|v| !v.to_string().is_empty()),
+            order_by_amount_asc: Some(order_by_amount_asc).filter(|v| *v),
+            order_by_amount_desc: Some(order_by_amount_desc).filter(|v| *v),
+            order_by_date_asc: Some(order_by_date_asc).filter(|v| *v),
+            order_by_date_desc: Some(order_by_date_desc).filter(|v| *v),
+            page_size: Some(page_size).filter(|v| !v.to_string().is_empty()),
+            requires_memo: Some(requires_memo).filter(|v| *v),
+            sk_category_id: Some(sk_category_id.to_string()).filter(|s| !s.is_empty()),
+            start: Some(start.to_string()).filter(|s| !s.is_empty()),
+            state: Some(state.to_string()).filter(|s| !s.is_empty()),
+            to_date,
+        };
+        self.get_page_with(&params).await
+    }
+
+    /**
+     * List transactions.
+     *
+     * This function performs a `GET` to the `/transactions` endpoint.
+     *
+     * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * Retrieves all transactions for the business. This endpoint supports filtering and ordering. NOTE: only one ordering param is supported.
+     *
+     * **Parameters:**
+     *
+     * * `params: &TransactionsGetPageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_with(
+        &self,
+        params: &TransactionsGetPageParams,
     ) -> Result<Vec<crate::types::Data>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !department_id.is_empty() {
+        if let Some(department_id) = &params.department_id {
             query_args.push(("department_id".to_string(), department_id.to_string()));
         }
-        if let Some(date) = from_date {
-            query_args.push(("from_date".to_string(), date.to_rfc3339()));
+        if let Some(from_date) = &params.from_date {
+            query_args.push(("from_date".to_string(), from_date.to_rfc3339()));
         }
-        if !location_id.is_empty() {
+        if let Some(location_id) = &params.location_id {
             query_args.push(("location_id".to_string(), location_id.to_string()));
         }
-        if !max_amount.to_string().is_empty() {
+        if let Some(max_amount) = &params.max_amount {
             query_args.push(("max_amount".to_string(), max_amount.to_string()));
         }
-        if !merchant_id.is_empty() {
+        if let Some(merchant_id) = &params.merchant_id {
             query_args.push(("merchant_id".to_string(), merchant_id.to_string()));
         }
-        if !min_amount.to_string().is_empty() {
+        if let Some(min_amount) = &params.min_amount {
             query_args.push(("min_amount".to_string(), min_amount.to_string()));
         }
-        if order_by_amount_asc {
+        if let Some(order_by_amount_asc) = &params.order_by_amount_asc {
             query_args.push((
                 "order_by_amount_asc".to_string(),
                 order_by_amount_asc.to_string(),
             ));
         }
-        if order_by_amount_desc {
+        if let Some(order_by_amount_desc) = &params.order_by_amount_desc {
             query_args.push((
                 "order_by_amount_desc".to_string(),
                 order_by_amount_desc.to_string(),
             ));
         }
-        if order_by_date_asc {
+        if let Some(order_by_date_asc) = &params.order_by_date_asc {
             query_args.push((
                 "order_by_date_asc".to_string(),
                 order_by_date_asc.to_string(),
             ));
         }
-        if order_by_date_desc {
+        if let Some(order_by_date_desc) = &params.order_by_date_desc {
             query_args.push((
                 "order_by_date_desc".to_string(),
                 order_by_date_desc.to_string(),
             ));
         }
-        if requires_memo {
+        if let Some(requires_memo) = &params.requires_memo {
             query_args.push(("requires_memo".to_string(), requires_memo.to_string()));
         }
-        if !sk_category_id.is_empty() {
+        if let Some(sk_category_id) = &params.sk_category_id {
             query_args.push(("sk_category_id".to_string(), sk_category_id.to_string()));
         }
-        if !state.is_empty() {
+        if let Some(state) = &params.state {
             query_args.push(("state".to_string(), state.to_string()));
         }
-        if let Some(date) = to_date {
-            query_args.push(("to_date".to_string(), date.to_rfc3339()));
+        if let Some(to_date) = &params.to_date {
+            query_args.push(("to_date".to_string(), to_date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/transactions?{}", query_);
@@ -236,13 +238,10 @@ impl Transactions {
                         "".to_string()
                     };
                 }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        anyhow::bail!(e);
-                    }
+                Err(crate::Error::Http { status, .. }) if status == http::StatusCode::NOT_FOUND => {
+                    page = "".to_string();
                 }
+                Err(e) => return Err(e),
             }
         }
 
@@ -250,6 +249,150 @@ impl Transactions {
         Ok(data)
     }
 
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `TransactionsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        department_id: &str,
+        location_id: &str,
+        from_date: Option<chrono::DateTime<chrono::Utc>>,
+        to_date: Option<chrono::DateTime<chrono::Utc>>,
+        merchant_id: &str,
+        sk_category_id: &str,
+        order_by_date_desc: bool,
+        order_by_date_asc: bool,
+        order_by_amount_desc: bool,
+        order_by_amount_asc: bool,
+        state: &str,
+        min_amount: f64,
+        max_amount: f64,
+        requires_memo: bool,
+    ) -> Result<Vec<crate::types::Data>> {
+        let params = TransactionsGetPageParams {
+            department_id: Some(department_id.to_string()).filter(|s| !s.is_empty()),
+            from_date,
+            location_id: Some(location_id.to_string()).filter(|s| !s.is_empty()),
+            max_amount: Some(max_amount).filter(|v| !v.to_string().is_empty()),
+            merchant_id: Some(merchant_id.to_string()).filter(|s| !s.is_empty()),
+            min_amount: Some(min_amount).filter(|v| !v.to_string().is_empty()),
+            order_by_amount_asc: Some(order_by_amount_asc).filter(|v| *v),
+            order_by_amount_desc: Some(order_by_amount_desc).filter(|v| *v),
+            order_by_date_asc: Some(order_by_date_asc).filter(|v| *v),
+            order_by_date_desc: Some(order_by_date_desc).filter(|v| *v),
+            requires_memo: Some(requires_memo).filter(|v| *v),
+            sk_category_id: Some(sk_category_id.to_string()).filter(|s| !s.is_empty()),
+            state: Some(state.to_string()).filter(|s| !s.is_empty()),
+            to_date,
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * List transactions.
+     *
+     * This function performs a `GET` to the `/transactions` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieves all transactions for the business. This endpoint supports filtering and ordering. NOTE: only one ordering param is supported.
+     */
+    pub fn get_stream(
+        &self,
+        params: &TransactionsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Data> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(department_id) = &params.department_id {
+            query_args.push(("department_id".to_string(), department_id.to_string()));
+        }
+        if let Some(from_date) = &params.from_date {
+            query_args.push(("from_date".to_string(), from_date.to_rfc3339()));
+        }
+        if let Some(location_id) = &params.location_id {
+            query_args.push(("location_id".to_string(), location_id.to_string()));
+        }
+        if let Some(max_amount) = &params.max_amount {
+            query_args.push(("max_amount".to_string(), max_amount.to_string()));
+        }
+        if let Some(merchant_id) = &params.merchant_id {
+            query_args.push(("merchant_id".to_string(), merchant_id.to_string()));
+        }
+        if let Some(min_amount) = &params.min_amount {
+            query_args.push(("min_amount".to_string(), min_amount.to_string()));
+        }
+        if let Some(order_by_amount_asc) = &params.order_by_amount_asc {
+            query_args.push((
+                "order_by_amount_asc".to_string(),
+                order_by_amount_asc.to_string(),
+            ));
+        }
+        if let Some(order_by_amount_desc) = &params.order_by_amount_desc {
+            query_args.push((
+                "order_by_amount_desc".to_string(),
+                order_by_amount_desc.to_string(),
+            ));
+        }
+        if let Some(order_by_date_asc) = &params.order_by_date_asc {
+            query_args.push((
+                "order_by_date_asc".to_string(),
+                order_by_date_asc.to_string(),
+            ));
+        }
+        if let Some(order_by_date_desc) = &params.order_by_date_desc {
+            query_args.push((
+                "order_by_date_desc".to_string(),
+                order_by_date_desc.to_string(),
+            ));
+        }
+        if let Some(requires_memo) = &params.requires_memo {
+            query_args.push(("requires_memo".to_string(), requires_memo.to_string()));
+        }
+        if let Some(sk_category_id) = &params.sk_category_id {
+            query_args.push(("sk_category_id".to_string(), sk_category_id.to_string()));
+        }
+        if let Some(state) = &params.state {
+            query_args.push(("state".to_string(), state.to_string()));
+        }
+        if let Some(to_date) = &params.to_date {
+            query_args.push(("to_date".to_string(), to_date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/transactions?{}", query_);
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The cursor of the next page is its full URL.
+                let url = match page {
+                    Some(page) => page.trim_start_matches(crate::DEFAULT_HOST).to_string(),
+                    None => url,
+                };
+
+                match client
+                    .get::<crate::types::GetTransactionResponse>(&url, None)
+                    .await
+                {
+                    Ok(resp) => Ok(crate::pagination::Page::new(
+                        resp.data,
+                        Some(resp.page.next).filter(|n| !n.is_empty()),
+                    )),
+                    Err(crate::Error::Http { status, .. })
+                        if status == http::StatusCode::NOT_FOUND =>
+                    {
+                        Ok(crate::pagination::Page::new(Vec::new(), None))
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+        })
+    }
+
     /**
      * GET a transaction.
      *
@@ -261,7 +404,7 @@ impl Transactions {
      *
      * * `authorization: &str` -- The OAuth2 token header.
      */
-    pub async fn get_resource(&self, id: &str) -> Result<crate::types::Data> {
+    pub async fn get_resource(&self, id: &str) -> Result<crate::types::Transaction> {
         let url = format!(
             "/transactions/{}",
             crate::progenitor_support::encode_path(id),
@@ -270,3 +413,175 @@ impl Transactions {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Transactions::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionsGetPageParams {
+    /// The OAuth2 token header.
+    pub department_id: Option<String>,
+    pub from_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// The OAuth2 token header.
+    pub location_id: Option<String>,
+    /// The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    pub max_amount: Option<f64>,
+    /// The OAuth2 token header.
+    pub merchant_id: Option<String>,
+    /// The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    pub min_amount: Option<f64>,
+    pub order_by_amount_asc: Option<bool>,
+    pub order_by_amount_desc: Option<bool>,
+    pub order_by_date_asc: Option<bool>,
+    pub order_by_date_desc: Option<bool>,
+    /// The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    pub page_size: Option<f64>,
+    /// Filters for transactions which require a memo, but do not have one. This can only be set to true.
+    pub requires_memo: Option<bool>,
+    /// The OAuth2 token header.
+    pub sk_category_id: Option<String>,
+    /// The ID of the last entity of the previous page, used for pagination to get the next page.
+    pub start: Option<String>,
+    /// The OAuth2 token header.
+    pub state: Option<String>,
+    pub to_date: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl TransactionsGetPageParams {
+    pub fn department_id<T: ToString>(mut self, department_id: T) -> Self {
+        self.department_id = Some(department_id.to_string());
+        self
+    }
+
+    pub fn from_date(mut self, from_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.from_date = Some(from_date);
+        self
+    }
+
+    pub fn location_id<T: ToString>(mut self, location_id: T) -> Self {
+        self.location_id = Some(location_id.to_string());
+        self
+    }
+
+    pub fn max_amount(mut self, max_amount: f64) -> Self {
+        self.max_amount = Some(max_amount);
+        self
+    }
+
+    pub fn merchant_id<T: ToString>(mut self, merchant_id: T) -> Self {
+        self.merchant_id = Some(merchant_id.to_string());
+        self
+    }
+
+    pub fn min_amount(mut self, min_amount: f64) -> Self {
+        self.min_amount = Some(min_amount);
+        self
+    }
+
+    pub fn order_by_amount_asc(mut self, order_by_amount_asc: bool) -> Self {
+        self.order_by_amount_asc = Some(order_by_amount_asc);
+        self
+    }
+
+    pub fn order_by_amount_desc(mut self, order_by_amount_desc: bool) -> Self {
+        self.order_by_amount_desc = Some(order_by_amount_desc);
+        self
+    }
+
+    pub fn order_by_date_asc(mut self, order_by_date_asc: bool) -> Self {
+        self.order_by_date_asc = Some(order_by_date_asc);
+        self
+    }
+
+    pub fn order_by_date_desc(mut self, order_by_date_desc: bool) -> Self {
+        self.order_by_date_desc = Some(order_by_date_desc);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn requires_memo(mut self, requires_memo: bool) -> Self {
+        self.requires_memo = Some(requires_memo);
+        self
+    }
+
+    pub fn sk_category_id<T: ToString>(mut self, sk_category_id: T) -> Self {
+        self.sk_category_id = Some(sk_category_id.to_string());
+        self
+    }
+
+    pub fn start<T: ToString>(mut self, start: T) -> Self {
+        self.start = Some(start.to_string());
+        self
+    }
+
+    pub fn state<T: ToString>(mut self, state: T) -> Self {
+        self.state = Some(state.to_string());
+        self
+    }
+
+    pub fn to_date(mut self, to_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.to_date = Some(to_date);
+        self
+    }
+}
+
+/// The functions of [`Transactions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTransactionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TransactionsApi: Send + Sync {
+    /// See [`Transactions::get_page_with`].
+    async fn get_page_with(
+        &self,
+        params: &TransactionsGetPageParams,
+    ) -> Result<Vec<crate::types::Data>>;
+
+    /// See [`Transactions::get_all_with`].
+    async fn get_all_with(
+        &self,
+        params: &TransactionsGetPageParams,
+    ) -> Result<Vec<crate::types::Data>>;
+
+    /// See [`Transactions::get_stream`].
+    fn get_stream(
+        &self,
+        params: &TransactionsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Data>;
+
+    /// See [`Transactions::get_resource`].
+    async fn get_resource(&self, id: &str) -> Result<crate::types::Transaction>;
+}
+
+#[async_trait::async_trait]
+impl TransactionsApi for Transactions {
+    async fn get_page_with(
+        &self,
+        params: &TransactionsGetPageParams,
+    ) -> Result<Vec<crate::types::Data>> {
+        Transactions::get_page_with(self, params).await
+    }
+
+    async fn get_all_with(
+        &self,
+        params: &TransactionsGetPageParams,
+    ) -> Result<Vec<crate::types::Data>> {
+        Transactions::get_all_with(self, params).await
+    }
+
+    fn get_stream(
+        &self,
+        params: &TransactionsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Data> {
+        Transactions::get_stream(self, params, start, max_items)
+    }
+
+    async fn get_resource(&self, id: &str) -> Result<crate::types::Transaction> {
+        Transactions::get_resource(self, id).await
+    }
+}
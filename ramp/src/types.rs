@@ -2,205 +2,170 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-/// Extra metadata about the error, may be empty. Usually depends on the error type.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Details {}
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Error {
-    /**
-     * Extra metadata about the error, may be empty. Usually depends on the error type.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub details: Option<Details>,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> std::option::Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ErrorResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub error: Option<Error>,
+impl<T> std::convert::From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TaskResponse {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Role {
-    #[serde(rename = "BUSINESS_ADMIN")]
-    BusinessAdmin,
-    #[serde(rename = "BUSINESS_BOOKKEEPER")]
-    BusinessBookkeeper,
-    #[serde(rename = "BUSINESS_OWNER")]
-    BusinessOwner,
-    #[serde(rename = "BUSINESS_USER")]
-    BusinessUser,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match std::option::Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
 }
 
-impl std::fmt::Display for Role {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Role::BusinessAdmin => "BUSINESS_ADMIN",
-            Role::BusinessBookkeeper => "BUSINESS_BOOKKEEPER",
-            Role::BusinessOwner => "BUSINESS_OWNER",
-            Role::BusinessUser => "BUSINESS_USER",
-            Role::Noop => "",
-            Role::FallthroughString => "*",
-        }
-        .fmt(f)
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        std::option::Option::<T>::schema_name()
     }
-}
 
-impl Default for Role {
-    fn default() -> Role {
-        Role::Noop
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        std::option::Option::<T>::json_schema(gen)
     }
-}
-impl Role {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Role::Noop)
+
+    fn is_referenceable() -> bool {
+        false
     }
 }
 
-/// Ramp User
-#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct User {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub amount_limit: String,
-    /**
-     * Ramp User
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub business_id: String,
-    /**
-     * Ramp User
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub department_id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     * Ramp User
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub location_id: String,
-    /**
-     * Ramp User
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub manager_id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone: String,
-    #[serde(default, skip_serializing_if = "Role::is_noop")]
-    pub role: Role,
-}
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
 
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+
+#[cfg(feature = "auths")]
+mod auths;
+#[cfg(feature = "auths")]
+pub use auths::*;
+
+#[cfg(feature = "businesses")]
+mod businesses;
+#[cfg(feature = "businesses")]
+pub use businesses::*;
+
+#[cfg(feature = "card_programs")]
+mod card_programs;
+#[cfg(feature = "card_programs")]
+pub use card_programs::*;
+
+#[cfg(feature = "cards")]
+mod cards;
+#[cfg(feature = "cards")]
+pub use cards::*;
+
+#[cfg(feature = "custom_ids")]
+mod custom_ids;
+#[cfg(feature = "custom_ids")]
+pub use custom_ids::*;
+
+#[cfg(feature = "departments")]
+mod departments;
+#[cfg(feature = "departments")]
+pub use departments::*;
+
+#[cfg(feature = "locations")]
+mod locations;
+#[cfg(feature = "locations")]
+pub use locations::*;
+
+#[cfg(feature = "receipts")]
+mod receipts;
+#[cfg(feature = "receipts")]
+pub use receipts::*;
+
+#[cfg(feature = "reimbursements")]
+mod reimbursements;
+#[cfg(feature = "reimbursements")]
+pub use reimbursements::*;
+
+#[cfg(feature = "transactions")]
+mod transactions;
+#[cfg(feature = "transactions")]
+pub use transactions::*;
+
+#[cfg(feature = "users")]
+mod users;
+#[cfg(feature = "users")]
+pub use users::*;
+/// Extra metadata about the error, may be empty. Usually depends on the error type.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchUsersRequest {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub department_id: String,
+pub struct Details {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Error {
     /**
-     * The OAuth2 token header
+     * Extra metadata about the error, may be empty. Usually depends on the error type.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub direct_manager_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<Details>,
     /**
      * The OAuth2 token header
      */
@@ -209,71 +174,17 @@ pub struct PatchUsersRequest {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub location_id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub role: Option<Role>,
+    pub message: String,
 }
 
-#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Page {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub next: String,
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ErrorResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CardHolder {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub department_id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub department_name: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub location_id: String,
+pub struct TaskResponse {
     /**
      * The OAuth2 token header
      */
@@ -282,7 +193,7 @@ pub struct CardHolder {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub location_name: String,
+    pub id: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -308,37 +219,61 @@ pub struct AccountingCategories {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "POLICY_VIOLATION_FROM_ADMIN")]
     PolicyViolationFromAdmin,
     #[serde(rename = "POLICY_VIOLATION_FROM_USER")]
     PolicyViolationFromUser,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Type {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        match self {
             Type::PolicyViolationFromAdmin => "POLICY_VIOLATION_FROM_ADMIN",
             Type::PolicyViolationFromUser => "POLICY_VIOLATION_FROM_USER",
-            Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Type {
-    fn default() -> Type {
-        Type::Noop
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "POLICY_VIOLATION_FROM_ADMIN" => Type::PolicyViolationFromAdmin,
+            "POLICY_VIOLATION_FROM_USER" => Type::PolicyViolationFromUser,
+            _ => Type::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Type {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Type::Noop)
+
+impl std::convert::From<Type> for String {
+    fn from(v: Type) -> Self {
+        match v {
+            Type::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -373,43 +308,69 @@ pub struct PolicyViolations {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetTransactionResponseDataDisputesType {
     #[serde(rename = "DISPUTE_CANCELLED")]
     DisputeCancelled,
     #[serde(rename = "MERCHANT_ERROR")]
     MerchantError,
     #[serde(rename = "UNKNOWN")]
-    Unknown,
+    UnknownValue,
     #[serde(rename = "UNRECOGNIZED_CHARGE")]
     UnrecognizedCharge,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetTransactionResponseDataDisputesType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetTransactionResponseDataDisputesType {
+    fn as_ref(&self) -> &str {
+        match self {
             GetTransactionResponseDataDisputesType::DisputeCancelled => "DISPUTE_CANCELLED",
             GetTransactionResponseDataDisputesType::MerchantError => "MERCHANT_ERROR",
-            GetTransactionResponseDataDisputesType::Unknown => "UNKNOWN",
+            GetTransactionResponseDataDisputesType::UnknownValue => "UNKNOWN",
             GetTransactionResponseDataDisputesType::UnrecognizedCharge => "UNRECOGNIZED_CHARGE",
-            GetTransactionResponseDataDisputesType::Noop => "",
-            GetTransactionResponseDataDisputesType::FallthroughString => "*",
+            GetTransactionResponseDataDisputesType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetTransactionResponseDataDisputesType {
-    fn default() -> GetTransactionResponseDataDisputesType {
-        GetTransactionResponseDataDisputesType::Noop
+impl std::fmt::Display for GetTransactionResponseDataDisputesType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetTransactionResponseDataDisputesType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DISPUTE_CANCELLED" => GetTransactionResponseDataDisputesType::DisputeCancelled,
+            "MERCHANT_ERROR" => GetTransactionResponseDataDisputesType::MerchantError,
+            "UNKNOWN" => GetTransactionResponseDataDisputesType::UnknownValue,
+            "UNRECOGNIZED_CHARGE" => GetTransactionResponseDataDisputesType::UnrecognizedCharge,
+            _ => GetTransactionResponseDataDisputesType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetTransactionResponseDataDisputesType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetTransactionResponseDataDisputesType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetTransactionResponseDataDisputesType::Noop)
+
+impl std::convert::From<GetTransactionResponseDataDisputesType> for String {
+    fn from(v: GetTransactionResponseDataDisputesType) -> Self {
+        match v {
+            GetTransactionResponseDataDisputesType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -443,311 +404,43 @@ pub struct Disputes {
     pub type_: Option<GetTransactionResponseDataDisputesType>,
 }
 
-/// Ramp transaction
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Data {
+pub struct RecipientAddress {
     /**
-     * Ramp transaction
+     * The OAuth2 token header
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub accounting_categories: Vec<AccountingCategories>,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "address1")]
+    pub address_1: Option<String>,
     /**
-     * The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+     * The OAuth2 token header
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub amount: f64,
-    #[serde()]
-    pub card_holder: CardHolder,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city: Option<String>,
     /**
      * The OAuth2 token header
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub card_id: String,
-    /**
-     * Ramp transaction
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub disputes: Vec<Disputes>,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * Ramp transaction
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub memo: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub merchant_id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub merchant_name: String,
-    /**
-     * Ramp transaction
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub policy_violations: Vec<PolicyViolations>,
-    /**
-     * Ramp transaction
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub receipts: Vec<String>,
-    /**
-     * The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub sk_category_id: f64,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub sk_category_name: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub user_transaction_time: Option<chrono::DateTime<chrono::Utc>>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetTransactionResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub data: Vec<Data>,
-    #[serde(default)]
-    pub page: Page,
-}
-
-/// Ramp location
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Location {
-    /**
-     * Ramp location
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * Ramp location
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetLocationResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub data: Vec<Location>,
-    #[serde(default)]
-    pub page: Page,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostLocationRequest {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetUsersResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub data: Vec<User>,
-    #[serde(default)]
-    pub page: Page,
-}
-
-/// Ramp Department
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Department {
-    /**
-     * Ramp Department
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * Ramp Department
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetDepartmentsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub data: Vec<Department>,
-    #[serde(default)]
-    pub page: Page,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct RecipientAddress {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address1"
-    )]
-    pub address_1: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
     /**
      * The OAuth2 token header
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<String>,
     /**
      * The OAuth2 token header
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<String>,
     /**
      * The OAuth2 token header
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub postal_code: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub postal_code: Option<String>,
     /**
      * The OAuth2 token header
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -770,6 +463,7 @@ pub struct Fulfillment {
  * Time interval to apply limit to.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Interval {
     #[serde(rename = "DAILY")]
     Daily,
@@ -779,49 +473,72 @@ pub enum Interval {
     Total,
     #[serde(rename = "YEARLY")]
     Yearly,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Interval {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Interval {
+    fn as_ref(&self) -> &str {
+        match self {
             Interval::Daily => "DAILY",
             Interval::Monthly => "MONTHLY",
             Interval::Total => "TOTAL",
             Interval::Yearly => "YEARLY",
-            Interval::Noop => "",
-            Interval::FallthroughString => "*",
+            Interval::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Interval {
-    fn default() -> Interval {
-        Interval::Noop
+impl std::fmt::Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Interval {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DAILY" => Interval::Daily,
+            "MONTHLY" => Interval::Monthly,
+            "TOTAL" => Interval::Total,
+            "YEARLY" => Interval::Yearly,
+            _ => Interval::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Interval {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Interval {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Interval::Noop)
+
+impl std::convert::From<Interval> for String {
+    fn from(v: Interval) -> Self {
+        match v {
+            Interval::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 /// Specifies the spend restrictions on a Ramp card.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct SpendingRestrictions {
-    /**
-     * The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float"),
+        schemars(with = "f64")
     )]
-    pub amount: f64,
+    #[serde(default)]
+    pub amount: crate::types::DecimalNumber,
     /**
      * Specifies the spend restrictions on a Ramp card.
      */
@@ -843,8 +560,8 @@ pub struct SpendingRestrictions {
     /**
      * Time interval to apply limit to.
      */
-    #[serde(default, skip_serializing_if = "Interval::is_noop")]
-    pub interval: Interval,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<Interval>,
     /**
      * Specifies the spend restrictions on a Ramp card.
      */
@@ -865,188 +582,162 @@ pub struct SpendingRestrictions {
     /**
      * Specifies the spend restrictions on a Ramp card.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
     )]
-    pub transaction_amount_limit: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_amount_limit: Option<crate::types::DecimalNumber>,
 }
 
-/// Card data that holds mostly static information about a card.
+/// Details for shipping physical cards
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Card {
+pub struct CardFulfillment {
     /**
-     * Card data that holds mostly static information about a card.
+     * Details for shipping physical cards
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<Shipping>,
+}
+
+/// Specifies the spend restrictions on a Ramp card.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CardSpendingRestriction {
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float"),
+        schemars(with = "f64")
     )]
-    pub card_program_id: String,
+    #[serde(default)]
+    pub amount: crate::types::DecimalNumber,
     /**
-     * Card data that holds mostly static information about a card.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub cardholder_id: String,
-    /**
-     * Card data that holds mostly static information about a card.
+     * Specifies the spend restrictions on a Ramp card.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub cardholder_name: String,
+    pub blocked_categories: Vec<f64>,
     /**
-     * Card data that holds mostly static information about a card.
+     * Specifies the spend restrictions on a Ramp card.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub display_name: String,
+    pub categories: Vec<f64>,
     /**
-     * Card data that holds mostly static information about a card.
+     * Time interval to apply limit to.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fulfillment: Option<Fulfillment>,
+    pub interval: Option<Interval>,
     /**
-     * Card data that holds mostly static information about a card.
+     * Specifies the spend restrictions on a Ramp card.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub id: String,
+    pub lock_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Card data that holds mostly static information about a card.
+     * Specifies the spend restrictions on a Ramp card.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub is_physical: bool,
+    pub suspended: bool,
     /**
-     * Card data that holds mostly static information about a card.
+     * Specifies the spend restrictions on a Ramp card.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
     )]
-    pub last_four: String,
-    /**
-     * Card data that holds mostly static information about a card.
-     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub spending_restrictions: Option<SpendingRestrictions>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetCardsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub cards: Vec<Card>,
-    #[serde(default)]
-    pub page: Page,
+    pub transaction_amount_limit: Option<crate::types::DecimalNumber>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchResourcesCardsCardRequest {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub card_program_id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub display_name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub has_notifications_enabled: Option<bool>,
+#[serde(from = "String", into = "String")]
+pub enum TransactionDisputesType {
+    #[serde(rename = "DISPUTE_CANCELLED")]
+    DisputeCancelled,
+    #[serde(rename = "MERCHANT_ERROR")]
+    MerchantError,
+    #[serde(rename = "UNKNOWN")]
+    UnknownValue,
+    #[serde(rename = "UNRECOGNIZED_CHARGE")]
+    UnrecognizedCharge,
     /**
-     * Specifies the spend restrictions on a Ramp card.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub spending_restrictions: Option<SpendingRestrictions>,
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetCustomProviderResponse {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub custom_id_provider: String,
+impl AsRef<str> for TransactionDisputesType {
+    fn as_ref(&self) -> &str {
+        match self {
+            TransactionDisputesType::DisputeCancelled => "DISPUTE_CANCELLED",
+            TransactionDisputesType::MerchantError => "MERCHANT_ERROR",
+            TransactionDisputesType::UnknownValue => "UNKNOWN",
+            TransactionDisputesType::UnrecognizedCharge => "UNRECOGNIZED_CHARGE",
+            TransactionDisputesType::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostcustomProviderResponse {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub provider_id: String,
+impl std::fmt::Display for TransactionDisputesType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for TransactionDisputesType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DISPUTE_CANCELLED" => TransactionDisputesType::DisputeCancelled,
+            "MERCHANT_ERROR" => TransactionDisputesType::MerchantError,
+            "UNKNOWN" => TransactionDisputesType::UnknownValue,
+            "UNRECOGNIZED_CHARGE" => TransactionDisputesType::UnrecognizedCharge,
+            _ => TransactionDisputesType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for TransactionDisputesType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<TransactionDisputesType> for String {
+    fn from(v: TransactionDisputesType) -> Self {
+        match v {
+            TransactionDisputesType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BillingAddress {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address1"
-    )]
-    pub address_1: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    /**
-     * The OAuth2 token header
-     */
+pub struct TransactionDisputes {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub country: String,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     /**
      * The OAuth2 token header
      */
@@ -1055,7 +746,7 @@ pub struct BillingAddress {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub postal_code: String,
+    pub id: String,
     /**
      * The OAuth2 token header
      */
@@ -1064,55 +755,104 @@ pub struct BillingAddress {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub state: String,
+    pub memo: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<TransactionDisputesType>,
 }
 
-/// Mostly static information about a business that doesn't change often.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Business {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub active: bool,
-    #[serde()]
-    pub billing_address: BillingAddress,
-    /**
-     * The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub business_memo_required_threshold: f64,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub business_name_legal: String,
-    /**
-     * Mostly static information about a business that doesn't change often.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub business_name_on_card: String,
+#[serde(from = "String", into = "String")]
+pub enum Icon {
+    #[serde(rename = "AdvertisingIcon")]
+    AdvertisingIcon,
+    #[serde(rename = "CardIcon")]
+    CardIcon,
+    #[serde(rename = "EducationStipendIcon")]
+    EducationStipendIcon,
+    #[serde(rename = "LunchOrderingIcon")]
+    LunchOrderingIcon,
+    #[serde(rename = "OnboardingIcon")]
+    OnboardingIcon,
+    #[serde(rename = "PerDiemCardIcon")]
+    PerDiemCardIcon,
+    #[serde(rename = "SaasSubscriptionIcon")]
+    SaasSubscriptionIcon,
+    #[serde(rename = "SoftwareTrialIcon")]
+    SoftwareTrialIcon,
+    #[serde(rename = "TravelExpensesIcon")]
+    TravelExpensesIcon,
+    #[serde(rename = "WellnessIcon")]
+    WellnessIcon,
     /**
-     * The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub business_receipt_required_threshold: f64,
+    Unknown(String),
+}
+
+impl AsRef<str> for Icon {
+    fn as_ref(&self) -> &str {
+        match self {
+            Icon::AdvertisingIcon => "AdvertisingIcon",
+            Icon::CardIcon => "CardIcon",
+            Icon::EducationStipendIcon => "EducationStipendIcon",
+            Icon::LunchOrderingIcon => "LunchOrderingIcon",
+            Icon::OnboardingIcon => "OnboardingIcon",
+            Icon::PerDiemCardIcon => "PerDiemCardIcon",
+            Icon::SaasSubscriptionIcon => "SaasSubscriptionIcon",
+            Icon::SoftwareTrialIcon => "SoftwareTrialIcon",
+            Icon::TravelExpensesIcon => "TravelExpensesIcon",
+            Icon::WellnessIcon => "WellnessIcon",
+            Icon::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Icon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Icon {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AdvertisingIcon" => Icon::AdvertisingIcon,
+            "CardIcon" => Icon::CardIcon,
+            "EducationStipendIcon" => Icon::EducationStipendIcon,
+            "LunchOrderingIcon" => Icon::LunchOrderingIcon,
+            "OnboardingIcon" => Icon::OnboardingIcon,
+            "PerDiemCardIcon" => Icon::PerDiemCardIcon,
+            "SaasSubscriptionIcon" => Icon::SaasSubscriptionIcon,
+            "SoftwareTrialIcon" => Icon::SoftwareTrialIcon,
+            "TravelExpensesIcon" => Icon::TravelExpensesIcon,
+            "WellnessIcon" => Icon::WellnessIcon,
+            _ => Icon::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Icon {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Icon> for String {
+    fn from(v: Icon) -> Self {
+        match v {
+            Icon::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetUsersDeferredStatusResponseData {
     /**
      * The OAuth2 token header
      */
@@ -1121,12 +861,7 @@ pub struct Business {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub created_time: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enforce_sso: bool,
+    pub error: String,
     /**
      * The OAuth2 token header
      */
@@ -1136,52 +871,6 @@ pub struct Business {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
-    /**
-     * The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub initial_approved_limit: f64,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_integrated_with_slack: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_reimbursements_enabled: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub limit_locked: bool,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub website: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostResourcesCardPhysicalRequest {
     /**
      * The OAuth2 token header
      */
@@ -1190,7 +879,7 @@ pub struct PostResourcesCardPhysicalRequest {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub card_program_id: String,
+    pub misc: String,
     /**
      * The OAuth2 token header
      */
@@ -1199,791 +888,5 @@ pub struct PostResourcesCardPhysicalRequest {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub display_name: String,
-    /**
-     * Details for shipping physical cards
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fulfillment: Option<Fulfillment>,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub idempotency_key: String,
-    /**
-     * Specifies the spend restrictions on a Ramp card.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub spending_restrictions: Option<SpendingRestrictions>,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub user_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostResourcesCardVirtualRequest {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub card_program_id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub display_name: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub idempotency_key: String,
-    /**
-     * Specifies the spend restrictions on a Ramp card.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub spending_restrictions: Option<SpendingRestrictions>,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub user_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum TokenType {
-    #[serde(rename = "Bearer")]
-    Bearer,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for TokenType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            TokenType::Bearer => "Bearer",
-            TokenType::Noop => "",
-            TokenType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for TokenType {
-    fn default() -> TokenType {
-        TokenType::Noop
-    }
-}
-impl TokenType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, TokenType::Noop)
-    }
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OAuth2Token {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub access_token: String,
-    /**
-     * Expiration time for access token in seconds
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub expires_in: i64,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub refresh_token: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub refresh_token_expires_in: i64,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub scope: String,
-    #[serde(default, skip_serializing_if = "TokenType::is_noop")]
-    pub token_type: TokenType,
-}
-
-/// Current data about the business.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BusinessCurrentStatus {
-    /**
-     * Current data about the business.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub balance_including_pending: f64,
-    /**
-     * Current data about the business.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub max_balance: f64,
-    /**
-     * Current data about the business.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub next_billing_date: Option<chrono::NaiveDate>,
-    /**
-     * Current data about the business.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub prev_billing_date: Option<chrono::NaiveDate>,
-    /**
-     * Current data about the business.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub statement_balance: f64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostResourcesCardsCardSuspensionRequest {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub idempotency_key: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetEntityTypeCustomRampResponse {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ramp_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetEntityTypeRampCustomResponse {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub custom_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetResourcesCardsDeferredResponseData {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub card_id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub error: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub misc: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Status {
-    #[serde(rename = "ERROR")]
-    Error,
-    #[serde(rename = "IN_PROGRESS")]
-    InProgress,
-    #[serde(rename = "STARTED")]
-    Started,
-    #[serde(rename = "SUCCESS")]
-    Success,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Status {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Status::Error => "ERROR",
-            Status::InProgress => "IN_PROGRESS",
-            Status::Started => "STARTED",
-            Status::Success => "SUCCESS",
-            Status::Noop => "",
-            Status::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Status {
-    fn default() -> Status {
-        Status::Noop
-    }
-}
-impl Status {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Status::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetResourcesCardsDeferredResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub data: Option<GetResourcesCardsDeferredResponseData>,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<Status>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Icon {
-    #[serde(rename = "AdvertisingIcon")]
-    AdvertisingIcon,
-    #[serde(rename = "CardIcon")]
-    CardIcon,
-    #[serde(rename = "EducationStipendIcon")]
-    EducationStipendIcon,
-    #[serde(rename = "LunchOrderingIcon")]
-    LunchOrderingIcon,
-    #[serde(rename = "OnboardingIcon")]
-    OnboardingIcon,
-    #[serde(rename = "PerDiemCardIcon")]
-    PerDiemCardIcon,
-    #[serde(rename = "SaasSubscriptionIcon")]
-    SaasSubscriptionIcon,
-    #[serde(rename = "SoftwareTrialIcon")]
-    SoftwareTrialIcon,
-    #[serde(rename = "TravelExpensesIcon")]
-    TravelExpensesIcon,
-    #[serde(rename = "WellnessIcon")]
-    WellnessIcon,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Icon {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Icon::AdvertisingIcon => "AdvertisingIcon",
-            Icon::CardIcon => "CardIcon",
-            Icon::EducationStipendIcon => "EducationStipendIcon",
-            Icon::LunchOrderingIcon => "LunchOrderingIcon",
-            Icon::OnboardingIcon => "OnboardingIcon",
-            Icon::PerDiemCardIcon => "PerDiemCardIcon",
-            Icon::SaasSubscriptionIcon => "SaasSubscriptionIcon",
-            Icon::SoftwareTrialIcon => "SoftwareTrialIcon",
-            Icon::TravelExpensesIcon => "TravelExpensesIcon",
-            Icon::WellnessIcon => "WellnessIcon",
-            Icon::Noop => "",
-            Icon::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Icon {
-    fn default() -> Icon {
-        Icon::Noop
-    }
-}
-impl Icon {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Icon::Noop)
-    }
-}
-
-/// Card Program data that serves as a template for creating new cards.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CardProgram {
-    /**
-     * Card Program data that serves as a template for creating new cards.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    /**
-     * Card Program data that serves as a template for creating new cards.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub display_name: String,
-    /**
-     * Card Program data that serves as a template for creating new cards.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub icon: Option<Icon>,
-    /**
-     * Card Program data that serves as a template for creating new cards.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * Card Program data that serves as a template for creating new cards.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_default: bool,
-    /**
-     * Card Program data that serves as a template for creating new cards.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_physical: bool,
-    /**
-     * Card Program data that serves as a template for creating new cards.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub spending_restrictions: Option<SpendingRestrictions>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetCardProgramsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
-        rename = "card-programs"
-    )]
-    pub card_programs: Vec<CardProgram>,
-    #[serde(default)]
-    pub page: Page,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostResourcesCardProgramRequest {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub display_name: String,
-    #[serde(default, skip_serializing_if = "Icon::is_noop")]
-    pub icon: Icon,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub is_default: Option<bool>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub is_physical: Option<bool>,
-    /**
-     * Specifies the spend restrictions on a Ramp card.
-     */
-    #[serde()]
-    pub spending_restrictions: SpendingRestrictions,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostUsersDeferredRequest {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub department_id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub direct_manager_id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub location_id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone: String,
-    #[serde(default, skip_serializing_if = "Role::is_noop")]
-    pub role: Role,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetUsersDeferredStatusResponseData {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub error: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub misc: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub user_id: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetUsersDeferredStatusResponse {
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub data: Option<GetUsersDeferredStatusResponseData>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub status: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetUsersDeferredStatusResponseDataType {
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub error: Option<Error>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Receipt {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub receipt_url: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub transaction_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub user_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetReceiptsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub data: Vec<Receipt>,
-    #[serde(default)]
-    pub page: Page,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Reimbursement {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub amount: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub currency: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub merchant: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub receipts: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub transaction_date: Option<chrono::NaiveDate>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
     pub user_id: String,
 }
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetReimbursementsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub data: Vec<Reimbursement>,
-    #[serde(default)]
-    pub page: Page,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostCustomProviderEntityTypeLinkRequest {
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub custom_id: String,
-    /**
-     * The OAuth2 token header
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ramp_id: String,
-}
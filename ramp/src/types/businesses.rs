@@ -0,0 +1,227 @@
+//! The data types of the `businesses` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BillingAddress {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "address1"
+    )]
+    pub address_1: String,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub city: String,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub country: String,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub postal_code: String,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+}
+
+/// Mostly static information about a business that doesn't change often.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Business {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub active: bool,
+    #[serde()]
+    pub billing_address: BillingAddress,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float"),
+        schemars(with = "f64")
+    )]
+    #[serde(default)]
+    pub business_memo_required_threshold: crate::types::DecimalNumber,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub business_name_legal: String,
+    /**
+     * Mostly static information about a business that doesn't change often.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub business_name_on_card: String,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float"),
+        schemars(with = "f64")
+    )]
+    #[serde(default)]
+    pub business_receipt_required_threshold: crate::types::DecimalNumber,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub created_time: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enforce_sso: bool,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float"),
+        schemars(with = "f64")
+    )]
+    #[serde(default)]
+    pub initial_approved_limit: crate::types::DecimalNumber,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_integrated_with_slack: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_reimbursements_enabled: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub limit_locked: bool,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub phone: String,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub website: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetResourcesBusinessResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
+
+/// Current data about the business.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BusinessCurrentStatus {
+    /**
+     * Current data about the business.
+     */
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance_including_pending: Option<crate::types::DecimalNumber>,
+    /**
+     * Current data about the business.
+     */
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_balance: Option<crate::types::DecimalNumber>,
+    /**
+     * Current data about the business.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub next_billing_date: Option<chrono::NaiveDate>,
+    /**
+     * Current data about the business.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize"
+    )]
+    pub prev_billing_date: Option<chrono::NaiveDate>,
+    /**
+     * Current data about the business.
+     */
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statement_balance: Option<crate::types::DecimalNumber>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetResourcesBusinessCurrentResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
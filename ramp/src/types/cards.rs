@@ -0,0 +1,540 @@
+//! The data types of the `cards` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Card data that holds mostly static information about a card.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Card {
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub card_program_id: String,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub cardholder_id: String,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub cardholder_name: String,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub display_name: String,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillment: Option<Fulfillment>,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_physical: bool,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub last_four: String,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spending_restrictions: Option<SpendingRestrictions>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetCardsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub cards: Vec<Card>,
+    #[serde(default)]
+    pub page: Page,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetCardsResponseData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
+
+/// Card data that holds mostly static information about a card.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CardData {
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub card_program_id: String,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub cardholder_id: String,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub cardholder_name: String,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub display_name: String,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillment: Option<CardFulfillment>,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_physical: bool,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub last_four: String,
+    /**
+     * Card data that holds mostly static information about a card.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spending_restrictions: Option<CardSpendingRestriction>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetCardsCardResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PatchResourcesCardsCardRequest {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub card_program_id: Option<String>,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_notifications_enabled: Option<bool>,
+    /**
+     * Specifies the spend restrictions on a Ramp card.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spending_restrictions: Option<CardSpendingRestriction>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PatchResourcesCardsCardResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardPhysicalRequest {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub card_program_id: Option<String>,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /**
+     * Details for shipping physical cards
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillment: Option<CardFulfillment>,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub idempotency_key: String,
+    /**
+     * Specifies the spend restrictions on a Ramp card.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spending_restrictions: Option<CardSpendingRestriction>,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardPhysicalResponse {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardPhysicalResponseData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardVirtualRequest {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub card_program_id: Option<String>,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub idempotency_key: String,
+    /**
+     * Specifies the spend restrictions on a Ramp card.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spending_restrictions: Option<CardSpendingRestriction>,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardVirtualResponse {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardVirtualResponseData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardsCardTerminationRequest {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub idempotency_key: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardsCardTerminationResponse {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardsCardTerminationResponseData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardsCardSuspensionRequest {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub idempotency_key: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardsCardSuspensionResponse {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardsCardSuspensionResponseData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardsCardUnsuspensionRequest {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub idempotency_key: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardsCardUnsuspensionResponse {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostResourcesCardsCardUnsuspensionResponseData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetResourcesCardsDeferredResponseData {
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub card_id: String,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub error: String,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub misc: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Status {
+    #[serde(rename = "ERROR")]
+    Error,
+    #[serde(rename = "IN_PROGRESS")]
+    InProgress,
+    #[serde(rename = "STARTED")]
+    Started,
+    #[serde(rename = "SUCCESS")]
+    Success,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        match self {
+            Status::Error => "ERROR",
+            Status::InProgress => "IN_PROGRESS",
+            Status::Started => "STARTED",
+            Status::Success => "SUCCESS",
+            Status::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ERROR" => Status::Error,
+            "IN_PROGRESS" => Status::InProgress,
+            "STARTED" => Status::Started,
+            "SUCCESS" => Status::Success,
+            _ => Status::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Status> for String {
+    fn from(v: Status) -> Self {
+        match v {
+            Status::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetResourcesCardsDeferredResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<GetResourcesCardsDeferredResponseData>,
+    /**
+     * The OAuth2 token header
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetResourcesCardsDeferredResponseDataType {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<Error>,
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Users {
     pub client: Client,
@@ -66,30 +64,23 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `authorization: &str` -- The OAuth2 token header.
-     * * `start: &str` -- The ID of the last entity of the previous page, used for pagination to get the next page.
-     * * `page_size: f64` -- The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
-     * * `department_id: &str` -- The OAuth2 token header.
-     * * `location_id: &str` -- The OAuth2 token header.
+     * * `params: &UsersGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        start: &str,
-        page_size: f64,
-        department_id: &str,
-        location_id: &str,
+        params: &UsersGetPageParams,
     ) -> Result<Vec<crate::types::User>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !department_id.is_empty() {
+        if let Some(department_id) = &params.department_id {
             query_args.push(("department_id".to_string(), department_id.to_string()));
         }
-        if !location_id.is_empty() {
+        if let Some(location_id) = &params.location_id {
             query_args.push(("location_id".to_string(), location_id.to_string()));
         }
-        if !page_size.to_string().is_empty() {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !start.is_empty() {
+        if let Some(start) = &params.start {
             query_args.push(("start".to_string(), start.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -101,6 +92,26 @@ impl Users {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `UsersGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        start: &str,
+        page_size: f64,
+        department_id: &str,
+        location_id: &str,
+    ) -> Result<Vec<crate::types::User>> {
+        let params = UsersGetPageParams {
+            department_id: Some(department_id.to_string()).filter(|s| !s.is_empty()),
+            location_id: Some(location_id.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| !v.to_string().is_empty()),
+            start: Some(start.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * List users.
      *
@@ -109,17 +120,20 @@ impl Users {
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * Retrieve all users of the business.
+     *
+     * **Parameters:**
+     *
+     * * `params: &UsersGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        department_id: &str,
-        location_id: &str,
+        params: &UsersGetPageParams,
     ) -> Result<Vec<crate::types::User>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !department_id.is_empty() {
+        if let Some(department_id) = &params.department_id {
             query_args.push(("department_id".to_string(), department_id.to_string()));
         }
-        if !location_id.is_empty() {
+        if let Some(location_id) = &params.location_id {
             query_args.push(("location_id".to_string(), location_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -149,13 +163,10 @@ impl Users {
                         "".to_string()
                     };
                 }
-                Err(e) => {
-                    if e.to_string().contains("404 Not Found") {
-                        page = "".to_string();
-                    } else {
-                        anyhow::bail!(e);
-                    }
+                Err(crate::Error::Http { status, .. }) if status == http::StatusCode::NOT_FOUND => {
+                    page = "".to_string();
                 }
+                Err(e) => return Err(e),
             }
         }
 
@@ -163,6 +174,78 @@ impl Users {
         Ok(data)
     }
 
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `UsersGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        department_id: &str,
+        location_id: &str,
+    ) -> Result<Vec<crate::types::User>> {
+        let params = UsersGetPageParams {
+            department_id: Some(department_id.to_string()).filter(|s| !s.is_empty()),
+            location_id: Some(location_id.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieve all users of the business.
+     */
+    pub fn get_stream(
+        &self,
+        params: &UsersGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::User> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(department_id) = &params.department_id {
+            query_args.push(("department_id".to_string(), department_id.to_string()));
+        }
+        if let Some(location_id) = &params.location_id {
+            query_args.push(("location_id".to_string(), location_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/users?{}", query_);
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The cursor of the next page is its full URL.
+                let url = match page {
+                    Some(page) => page.trim_start_matches(crate::DEFAULT_HOST).to_string(),
+                    None => url,
+                };
+
+                match client
+                    .get::<crate::types::GetUsersResponse>(&url, None)
+                    .await
+                {
+                    Ok(resp) => Ok(crate::pagination::Page::new(
+                        resp.data,
+                        Some(resp.page.next).filter(|n| !n.is_empty()),
+                    )),
+                    Err(crate::Error::Http { status, .. })
+                        if status == http::StatusCode::NOT_FOUND =>
+                    {
+                        Ok(crate::pagination::Page::new(Vec::new(), None))
+                    }
+                    Err(e) => Err(e),
+                }
+            }
+        })
+    }
+
     /**
      * Invite a new user.
      *
@@ -199,3 +282,125 @@ impl Users {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Users::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersGetPageParams {
+    /// The OAuth2 token header.
+    pub department_id: Option<String>,
+    /// The OAuth2 token header.
+    pub location_id: Option<String>,
+    /// The number of results to be returned in each page. The value must be between 2 and 10,000. If not specified, the default will be 1,000.
+    pub page_size: Option<f64>,
+    /// The ID of the last entity of the previous page, used for pagination to get the next page.
+    pub start: Option<String>,
+}
+
+impl UsersGetPageParams {
+    pub fn department_id<T: ToString>(mut self, department_id: T) -> Self {
+        self.department_id = Some(department_id.to_string());
+        self
+    }
+
+    pub fn location_id<T: ToString>(mut self, location_id: T) -> Self {
+        self.location_id = Some(location_id.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn start<T: ToString>(mut self, start: T) -> Self {
+        self.start = Some(start.to_string());
+        self
+    }
+}
+
+/// The functions of [`Users`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUsersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UsersApi: Send + Sync {
+    /// See [`Users::get`].
+    async fn get(&self, id: &str) -> Result<crate::types::User>;
+
+    /// See [`Users::delete`].
+    async fn delete(&self, id: &str) -> Result<()>;
+
+    /// See [`Users::patch`].
+    async fn patch(&self, id: &str, body: &crate::types::PatchUsersRequest) -> Result<()>;
+
+    /// See [`Users::get_page_with`].
+    async fn get_page_with(&self, params: &UsersGetPageParams) -> Result<Vec<crate::types::User>>;
+
+    /// See [`Users::get_all_with`].
+    async fn get_all_with(&self, params: &UsersGetPageParams) -> Result<Vec<crate::types::User>>;
+
+    /// See [`Users::get_stream`].
+    fn get_stream(
+        &self,
+        params: &UsersGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::User>;
+
+    /// See [`Users::post_deferred`].
+    async fn post_deferred(
+        &self,
+        body: &crate::types::PostUsersDeferredRequest,
+    ) -> Result<crate::types::User>;
+
+    /// See [`Users::get_deferred_status`].
+    async fn get_deferred_status(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::GetUsersDeferredStatusResponse>;
+}
+
+#[async_trait::async_trait]
+impl UsersApi for Users {
+    async fn get(&self, id: &str) -> Result<crate::types::User> {
+        Users::get(self, id).await
+    }
+
+    async fn delete(&self, id: &str) -> Result<()> {
+        Users::delete(self, id).await
+    }
+
+    async fn patch(&self, id: &str, body: &crate::types::PatchUsersRequest) -> Result<()> {
+        Users::patch(self, id, body).await
+    }
+
+    async fn get_page_with(&self, params: &UsersGetPageParams) -> Result<Vec<crate::types::User>> {
+        Users::get_page_with(self, params).await
+    }
+
+    async fn get_all_with(&self, params: &UsersGetPageParams) -> Result<Vec<crate::types::User>> {
+        Users::get_all_with(self, params).await
+    }
+
+    fn get_stream(
+        &self,
+        params: &UsersGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::User> {
+        Users::get_stream(self, params, start, max_items)
+    }
+
+    async fn post_deferred(
+        &self,
+        body: &crate::types::PostUsersDeferredRequest,
+    ) -> Result<crate::types::User> {
+        Users::post_deferred(self, body).await
+    }
+
+    async fn get_deferred_status(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::GetUsersDeferredStatusResponse> {
+        Users::get_deferred_status(self, id).await
+    }
+}
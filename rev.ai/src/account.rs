@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Account {
     pub client: Client,
@@ -24,3 +22,19 @@ impl Account {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`Account`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAccountApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccountApi: Send + Sync {
+    /// See [`Account::get`].
+    async fn get(&self) -> Result<crate::types::Account>;
+}
+
+#[async_trait::async_trait]
+impl AccountApi for Account {
+    async fn get(&self) -> Result<crate::types::Account> {
+        Account::get(self).await
+    }
+}
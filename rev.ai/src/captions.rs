@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Captions {
     pub client: Client,
@@ -26,16 +24,16 @@ impl Captions {
      * **Parameters:**
      *
      * * `accept: crate::types::Accept` -- MIME type specifying the caption output format.
-     * * `speaker_channel: i64` -- Identifies which channel of the job output to caption. Default is `null` which works only for jobs with no `speaker_channels_count` provided during job submission.
+     * * `params: &CaptionsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         id: &str,
         accept: crate::types::Accept,
-        speaker_channel: i64,
+        params: &CaptionsGetParams,
     ) -> Result<String> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if speaker_channel > 0 {
+        if let Some(speaker_channel) = &params.speaker_channel {
             query_args.push(("speaker_channel".to_string(), speaker_channel.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -49,4 +47,60 @@ impl Captions {
             .request_with_accept_mime(reqwest::Method::GET, &url, &accept.to_string())
             .await
     }
+
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `CaptionsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        id: &str,
+        accept: crate::types::Accept,
+        speaker_channel: i64,
+    ) -> Result<String> {
+        let params = CaptionsGetParams {
+            speaker_channel: Some(speaker_channel).filter(|v| *v > 0),
+        };
+        self.get_with(id, accept, &params).await
+    }
+}
+
+/// The query parameters of `Captions::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CaptionsGetParams {
+    /// Identifies which channel of the job output to caption. Default is `null` which works only for jobs with no `speaker_channels_count` provided during job submission.
+    pub speaker_channel: Option<i64>,
+}
+
+impl CaptionsGetParams {
+    pub fn speaker_channel(mut self, speaker_channel: i64) -> Self {
+        self.speaker_channel = Some(speaker_channel);
+        self
+    }
+}
+
+/// The functions of [`Captions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCaptionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CaptionsApi: Send + Sync {
+    /// See [`Captions::get_with`].
+    async fn get_with(
+        &self,
+        id: &str,
+        accept: crate::types::Accept,
+        params: &CaptionsGetParams,
+    ) -> Result<String>;
+}
+
+#[async_trait::async_trait]
+impl CaptionsApi for Captions {
+    async fn get_with(
+        &self,
+        id: &str,
+        accept: crate::types::Accept,
+        params: &CaptionsGetParams,
+    ) -> Result<String> {
+        Captions::get_with(self, id, accept, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Jobs {
     pub client: Client,
@@ -21,19 +19,17 @@ impl Jobs {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- Limits the number of jobs returned, default is 100, max is 1000.
-     * * `starting_after: &str` -- If specified, returns transcription jobs submitted before the job with this id, exclusive (job with this id is not included).
+     * * `params: &JobsGetListOfParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_list_of(
+    pub async fn get_list_of_with(
         &self,
-        limit: i64,
-        starting_after: &str,
+        params: &JobsGetListOfParams,
     ) -> Result<Vec<crate::types::JobAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -42,6 +38,22 @@ impl Jobs {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_list_of_with` and pass the query parameters as a `JobsGetListOfParams` instead.
+     */
+    #[deprecated(note = "use `get_list_of_with` instead")]
+    pub async fn get_list_of(
+        &self,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::JobAllOf>> {
+        let params = JobsGetListOfParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_list_of_with(&params).await
+    }
+
     /**
      * Get List of Jobs.
      *
@@ -50,19 +62,78 @@ impl Jobs {
      * As opposed to `get_list_of`, this function returns all the pages of the request at once.
      *
      * Gets a list of transcription jobs submitted within the last 30 days in reverse chronological order up to the provided `limit` number of jobs per call. **Note:** Jobs older than 30 days will not be listed. Pagination is supported via passing the last job `id` from a previous call into `starting_after`.
+     *
+     * **Parameters:**
+     *
+     * * `params: &JobsGetListOfParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_list_all_of_with(
+        &self,
+        params: &JobsGetListOfParams,
+    ) -> Result<Vec<crate::types::JobAllOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(starting_after) = &params.starting_after {
+            query_args.push(("starting_after".to_string(), starting_after.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/jobs?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_list_all_of_with` and pass the query parameters as a `JobsGetListOfParams` instead.
+     */
+    #[deprecated(note = "use `get_all_list_all_of_with` instead")]
     pub async fn get_all_list_all_of(
         &self,
         starting_after: &str,
     ) -> Result<Vec<crate::types::JobAllOf>> {
+        let params = JobsGetListOfParams {
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_list_all_of_with(&params).await
+    }
+
+    /**
+     * Get List of Jobs.
+     *
+     * This function performs a `GET` to the `/jobs` endpoint.
+     *
+     * As opposed to `get_all_list_all_of`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Gets a list of transcription jobs submitted within the last 30 days in reverse chronological order up to the provided `limit` number of jobs per call. **Note:** Jobs older than 30 days will not be listed. Pagination is supported via passing the last job `id` from a previous call into `starting_after`.
+     */
+    pub fn get_list_of_stream(
+        &self,
+        params: &JobsGetListOfParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JobAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/jobs?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -108,3 +179,103 @@ impl Jobs {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Jobs::get_list_of_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct JobsGetListOfParams {
+    /// Limits the number of jobs returned, default is 100, max is 1000.
+    pub limit: Option<i64>,
+    /// If specified, returns transcription jobs submitted before the job with this id, exclusive (job with this id is not included).
+    pub starting_after: Option<String>,
+}
+
+impl JobsGetListOfParams {
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The functions of [`Jobs`], for code that takes them as a trait, so that it can be
+/// tested with the `MockJobsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait JobsApi: Send + Sync {
+    /// See [`Jobs::get_list_of_with`].
+    async fn get_list_of_with(
+        &self,
+        params: &JobsGetListOfParams,
+    ) -> Result<Vec<crate::types::JobAllOf>>;
+
+    /// See [`Jobs::get_all_list_all_of_with`].
+    async fn get_all_list_all_of_with(
+        &self,
+        params: &JobsGetListOfParams,
+    ) -> Result<Vec<crate::types::JobAllOf>>;
+
+    /// See [`Jobs::get_list_of_stream`].
+    fn get_list_of_stream(
+        &self,
+        params: &JobsGetListOfParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JobAllOf>;
+
+    /// See [`Jobs::submit_transcription`].
+    async fn submit_transcription(
+        &self,
+        body: &crate::types::SubmitJobMediaUrlOptionsAllOf,
+    ) -> Result<crate::types::JobAllOf>;
+
+    /// See [`Jobs::get`].
+    async fn get(&self, id: &str) -> Result<crate::types::JobAllOf>;
+
+    /// See [`Jobs::delete`].
+    async fn delete(&self, id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl JobsApi for Jobs {
+    async fn get_list_of_with(
+        &self,
+        params: &JobsGetListOfParams,
+    ) -> Result<Vec<crate::types::JobAllOf>> {
+        Jobs::get_list_of_with(self, params).await
+    }
+
+    async fn get_all_list_all_of_with(
+        &self,
+        params: &JobsGetListOfParams,
+    ) -> Result<Vec<crate::types::JobAllOf>> {
+        Jobs::get_all_list_all_of_with(self, params).await
+    }
+
+    fn get_list_of_stream(
+        &self,
+        params: &JobsGetListOfParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::JobAllOf> {
+        Jobs::get_list_of_stream(self, params, start, max_items)
+    }
+
+    async fn submit_transcription(
+        &self,
+        body: &crate::types::SubmitJobMediaUrlOptionsAllOf,
+    ) -> Result<crate::types::JobAllOf> {
+        Jobs::submit_transcription(self, body).await
+    }
+
+    async fn get(&self, id: &str) -> Result<crate::types::JobAllOf> {
+        Jobs::get(self, id).await
+    }
+
+    async fn delete(&self, id: &str) -> Result<()> {
+        Jobs::delete(self, id).await
+    }
+}
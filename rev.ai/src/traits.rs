@@ -22,6 +22,6 @@ impl JobOps for crate::jobs::Jobs {
             )
             .text("options", "{}");
 
-        self.client.post_form("/jobs", form).await
+        Ok(self.client.post_form("/jobs", form).await?)
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Transcript {
     pub client: Client,
@@ -37,3 +35,19 @@ impl Transcript {
             .await
     }
 }
+
+/// The functions of [`Transcript`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTranscriptApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TranscriptApi: Send + Sync {
+    /// See [`Transcript::get`].
+    async fn get(&self, id: &str, accept: crate::types::AcceptTranscript) -> Result<String>;
+}
+
+#[async_trait::async_trait]
+impl TranscriptApi for Transcript {
+    async fn get(&self, id: &str, accept: crate::types::AcceptTranscript) -> Result<String> {
+        Transcript::get(self, id, accept).await
+    }
+}
@@ -0,0 +1,67 @@
+//! The data types of the `captions` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/**
+ * MIME type specifying the caption output format
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Accept {
+    #[serde(rename = "application/x-subrip")]
+    ApplicationXSubrip,
+    #[serde(rename = "text/vtt")]
+    TextVtt,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Accept {
+    fn as_ref(&self) -> &str {
+        match self {
+            Accept::ApplicationXSubrip => "application/x-subrip",
+            Accept::TextVtt => "text/vtt",
+            Accept::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Accept {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Accept {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "application/x-subrip" => Accept::ApplicationXSubrip,
+            "text/vtt" => Accept::TextVtt,
+            _ => Accept::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Accept {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Accept> for String {
+    fn from(v: Accept) -> Self {
+        match v {
+            Accept::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! The data types of the `transcript` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/**
+ * MIME type specifying the transcription output format
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum AcceptTranscript {
+    #[serde(rename = "application/vnd.rev.transcript.v1.0+json")]
+    ApplicationVndRevTranscript0Json,
+    #[serde(rename = "text/plain")]
+    TextPlain,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for AcceptTranscript {
+    fn as_ref(&self) -> &str {
+        match self {
+            AcceptTranscript::ApplicationVndRevTranscript0Json => {
+                "application/vnd.rev.transcript.v1.0+json"
+            }
+            AcceptTranscript::TextPlain => "text/plain",
+            AcceptTranscript::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for AcceptTranscript {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AcceptTranscript {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "application/vnd.rev.transcript.v1.0+json" => {
+                AcceptTranscript::ApplicationVndRevTranscript0Json
+            }
+            "text/plain" => AcceptTranscript::TextPlain,
+            _ => AcceptTranscript::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AcceptTranscript {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<AcceptTranscript> for String {
+    fn from(v: AcceptTranscript) -> Self {
+        match v {
+            AcceptTranscript::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
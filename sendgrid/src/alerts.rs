@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Alerts {
     pub client: Client,
@@ -55,6 +53,45 @@ impl Alerts {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve all alerts.
+     *
+     * This function performs a `GET` to the `/alerts` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve all of your alerts.**
+     *
+     * Alerts allow you to specify an email address to receive notifications regarding your email usage or statistics.
+     * * Usage alerts allow you to set the threshold at which an alert will be sent.
+     * * Stats notifications allow you to set how frequently you would like to receive email statistics reports. For example, "daily", "weekly", or "monthly".
+     *
+     * For more information about alerts, please see our [Alerts documentation](https://sendgrid.com/docs/ui/account-and-settings/alerts/).
+     */
+    pub fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetAlertsResponse> {
+        let url = "/alerts".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a new Alert.
      *
@@ -128,7 +165,7 @@ impl Alerts {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete(&self, alert_id: i64) -> Result<crate::types::Help> {
+    pub async fn delete(&self, alert_id: i64) -> Result<crate::types::DeleteAlertsAlertResponse> {
         let url = format!(
             "/alerts/{}",
             crate::progenitor_support::encode_path(&alert_id.to_string()),
@@ -158,7 +195,7 @@ impl Alerts {
         &self,
         alert_id: i64,
         body: &crate::types::PatchAlertsAlertRequest,
-    ) -> Result<crate::types::GetAlertsAlertResponse> {
+    ) -> Result<crate::types::PatchAlertsAlertResponse> {
         let url = format!(
             "/alerts/{}",
             crate::progenitor_support::encode_path(&alert_id.to_string()),
@@ -169,3 +206,83 @@ impl Alerts {
             .await
     }
 }
+
+/// The functions of [`Alerts`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAlertsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AlertsApi: Send + Sync {
+    /// See [`Alerts::get_page`].
+    async fn get_page(&self) -> Result<Vec<crate::types::GetAlertsResponse>>;
+
+    /// See [`Alerts::get_all`].
+    async fn get_all(&self) -> Result<Vec<crate::types::GetAlertsResponse>>;
+
+    /// See [`Alerts::get_stream`].
+    fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetAlertsResponse>;
+
+    /// See [`Alerts::post`].
+    async fn post(
+        &self,
+        body: &crate::types::PostAlertsRequest,
+    ) -> Result<crate::types::PostAlertsResponse>;
+
+    /// See [`Alerts::get`].
+    async fn get(&self, alert_id: i64) -> Result<crate::types::GetAlertsAlertResponse>;
+
+    /// See [`Alerts::delete`].
+    async fn delete(&self, alert_id: i64) -> Result<crate::types::DeleteAlertsAlertResponse>;
+
+    /// See [`Alerts::patch`].
+    async fn patch(
+        &self,
+        alert_id: i64,
+        body: &crate::types::PatchAlertsAlertRequest,
+    ) -> Result<crate::types::PatchAlertsAlertResponse>;
+}
+
+#[async_trait::async_trait]
+impl AlertsApi for Alerts {
+    async fn get_page(&self) -> Result<Vec<crate::types::GetAlertsResponse>> {
+        Alerts::get_page(self).await
+    }
+
+    async fn get_all(&self) -> Result<Vec<crate::types::GetAlertsResponse>> {
+        Alerts::get_all(self).await
+    }
+
+    fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetAlertsResponse> {
+        Alerts::get_stream(self, start, max_items)
+    }
+
+    async fn post(
+        &self,
+        body: &crate::types::PostAlertsRequest,
+    ) -> Result<crate::types::PostAlertsResponse> {
+        Alerts::post(self, body).await
+    }
+
+    async fn get(&self, alert_id: i64) -> Result<crate::types::GetAlertsAlertResponse> {
+        Alerts::get(self, alert_id).await
+    }
+
+    async fn delete(&self, alert_id: i64) -> Result<crate::types::DeleteAlertsAlertResponse> {
+        Alerts::delete(self, alert_id).await
+    }
+
+    async fn patch(
+        &self,
+        alert_id: i64,
+        body: &crate::types::PatchAlertsAlertRequest,
+    ) -> Result<crate::types::PatchAlertsAlertResponse> {
+        Alerts::patch(self, alert_id, body).await
+    }
+}
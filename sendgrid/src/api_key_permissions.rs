@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ApiKeyPermissions {
     pub client: Client,
@@ -36,3 +34,19 @@ impl ApiKeyPermissions {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`ApiKeyPermissions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockApiKeyPermissionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ApiKeyPermissionsApi: Send + Sync {
+    /// See [`ApiKeyPermissions::get_scopes`].
+    async fn get_scopes(&self) -> Result<crate::types::GetScopesResponse>;
+}
+
+#[async_trait::async_trait]
+impl ApiKeyPermissionsApi for ApiKeyPermissions {
+    async fn get_scopes(&self) -> Result<crate::types::GetScopesResponse> {
+        ApiKeyPermissions::get_scopes(self).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ApiKeys {
     pub client: Client,
@@ -27,12 +25,14 @@ impl ApiKeys {
      *
      * **Parameters:**
      *
-     * * `limit: i64`
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &ApiKeysGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(&self, limit: i64) -> Result<crate::types::GetApiKeysResponse> {
+    pub async fn get_with(
+        &self,
+        params: &ApiKeysGetParams,
+    ) -> Result<crate::types::GetApiKeysResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -41,6 +41,17 @@ impl ApiKeys {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `ApiKeysGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(&self, limit: i64) -> Result<crate::types::GetApiKeysResponse> {
+        let params = ApiKeysGetParams {
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Create API keys.
      *
@@ -168,7 +179,7 @@ impl ApiKeys {
     pub async fn patch_key(
         &self,
         api_key_id: &str,
-        body: &crate::types::IpPool,
+        body: &crate::types::PatchApiKeysKeyRequest,
     ) -> Result<crate::types::ApiKeyNameId> {
         let url = format!(
             "/api_keys/{}",
@@ -180,3 +191,93 @@ impl ApiKeys {
             .await
     }
 }
+
+/// The query parameters of `ApiKeys::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApiKeysGetParams {
+    pub limit: Option<i64>,
+}
+
+impl ApiKeysGetParams {
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The functions of [`ApiKeys`], for code that takes them as a trait, so that it can be
+/// tested with the `MockApiKeysApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ApiKeysApi: Send + Sync {
+    /// See [`ApiKeys::get_with`].
+    async fn get_with(&self, params: &ApiKeysGetParams)
+        -> Result<crate::types::GetApiKeysResponse>;
+
+    /// See [`ApiKeys::create`].
+    async fn create(
+        &self,
+        body: &crate::types::CreateApiKeysRequest,
+    ) -> Result<crate::types::CreateApiKeysResponse>;
+
+    /// See [`ApiKeys::get_key`].
+    async fn get_key(&self, api_key_id: &str) -> Result<crate::types::GetApiKeysKeyResponse>;
+
+    /// See [`ApiKeys::put_key`].
+    async fn put_key(
+        &self,
+        api_key_id: &str,
+        body: &crate::types::PutApiKeysKeyRequest,
+    ) -> Result<crate::types::ApiKeyNameScopesAllOf>;
+
+    /// See [`ApiKeys::delete_key`].
+    async fn delete_key(&self, api_key_id: &str) -> Result<()>;
+
+    /// See [`ApiKeys::patch_key`].
+    async fn patch_key(
+        &self,
+        api_key_id: &str,
+        body: &crate::types::PatchApiKeysKeyRequest,
+    ) -> Result<crate::types::ApiKeyNameId>;
+}
+
+#[async_trait::async_trait]
+impl ApiKeysApi for ApiKeys {
+    async fn get_with(
+        &self,
+        params: &ApiKeysGetParams,
+    ) -> Result<crate::types::GetApiKeysResponse> {
+        ApiKeys::get_with(self, params).await
+    }
+
+    async fn create(
+        &self,
+        body: &crate::types::CreateApiKeysRequest,
+    ) -> Result<crate::types::CreateApiKeysResponse> {
+        ApiKeys::create(self, body).await
+    }
+
+    async fn get_key(&self, api_key_id: &str) -> Result<crate::types::GetApiKeysKeyResponse> {
+        ApiKeys::get_key(self, api_key_id).await
+    }
+
+    async fn put_key(
+        &self,
+        api_key_id: &str,
+        body: &crate::types::PutApiKeysKeyRequest,
+    ) -> Result<crate::types::ApiKeyNameScopesAllOf> {
+        ApiKeys::put_key(self, api_key_id, body).await
+    }
+
+    async fn delete_key(&self, api_key_id: &str) -> Result<()> {
+        ApiKeys::delete_key(self, api_key_id).await
+    }
+
+    async fn patch_key(
+        &self,
+        api_key_id: &str,
+        body: &crate::types::PatchApiKeysKeyRequest,
+    ) -> Result<crate::types::ApiKeyNameId> {
+        ApiKeys::patch_key(self, api_key_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct BlocksApi {
     pub client: Client,
@@ -21,30 +19,23 @@ impl BlocksApi {
      *
      * **Parameters:**
      *
-     * * `start_time: i64` -- The start of the time range when a blocked email was created (inclusive). This is a unix timestamp.
-     * * `end_time: i64` -- The end of the time range when a blocked email was created (inclusive). This is a unix timestamp.
-     * * `limit: i64` -- Limit the number of results to be displayed per page.
-     * * `offset: i64` -- The point in the list to begin displaying results.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &BlocksApiGetSuppressionBlocksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_suppression_blocks(
+    pub async fn get_suppression_blocks_with(
         &self,
-        start_time: i64,
-        end_time: i64,
-        limit: i64,
-        offset: i64,
+        params: &BlocksApiGetSuppressionBlocksParams,
     ) -> Result<Vec<crate::types::BlocksResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if end_time > 0 {
+        if let Some(end_time) = &params.end_time {
             query_args.push(("end_time".to_string(), end_time.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if start_time > 0 {
+        if let Some(start_time) = &params.start_time {
             query_args.push(("start_time".to_string(), start_time.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -53,6 +44,26 @@ impl BlocksApi {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_suppression_blocks_with` and pass the query parameters as a `BlocksApiGetSuppressionBlocksParams` instead.
+     */
+    #[deprecated(note = "use `get_suppression_blocks_with` instead")]
+    pub async fn get_suppression_blocks(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::BlocksResponse>> {
+        let params = BlocksApiGetSuppressionBlocksParams {
+            end_time: Some(end_time).filter(|v| *v > 0),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_time: Some(start_time).filter(|v| *v > 0),
+        };
+        self.get_suppression_blocks_with(&params).await
+    }
+
     /**
      * Retrieve all blocks.
      *
@@ -61,27 +72,94 @@ impl BlocksApi {
      * As opposed to `get_suppression_blocks`, this function returns all the pages of the request at once.
      *
      * **This endpoint allows you to retrieve all email addresses that are currently on your blocks list.**
+     *
+     * **Parameters:**
+     *
+     * * `params: &BlocksApiGetSuppressionBlocksParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_suppression_blocks_with(
+        &self,
+        params: &BlocksApiGetSuppressionBlocksParams,
+    ) -> Result<Vec<crate::types::BlocksResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(end_time) = &params.end_time {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(start_time) = &params.start_time {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/suppression/blocks?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_suppression_blocks_with` and pass the query parameters as a `BlocksApiGetSuppressionBlocksParams` instead.
+     */
+    #[deprecated(note = "use `get_all_suppression_blocks_with` instead")]
     pub async fn get_all_suppression_blocks(
         &self,
         start_time: i64,
         end_time: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::BlocksResponse>> {
+        let params = BlocksApiGetSuppressionBlocksParams {
+            end_time: Some(end_time).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_time: Some(start_time).filter(|v| *v > 0),
+            ..Default::default()
+        };
+        self.get_all_suppression_blocks_with(&params).await
+    }
+
+    /**
+     * Retrieve all blocks.
+     *
+     * This function performs a `GET` to the `/suppression/blocks` endpoint.
+     *
+     * As opposed to `get_all_suppression_blocks`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve all email addresses that are currently on your blocks list.**
+     */
+    pub fn get_suppression_blocks_stream(
+        &self,
+        params: &BlocksApiGetSuppressionBlocksParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BlocksResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if end_time > 0 {
+        if let Some(end_time) = &params.end_time {
             query_args.push(("end_time".to_string(), end_time.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if start_time > 0 {
+        if let Some(start_time) = &params.start_time {
             query_args.push(("start_time".to_string(), start_time.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/suppression/blocks?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -103,7 +181,7 @@ impl BlocksApi {
     pub async fn delete_suppression_blocks(
         &self,
         body: &crate::types::DeleteSuppressionBlocksRequest,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSuppressionBlocksResponse> {
         let url = "/suppression/blocks".to_string();
         self.client
             .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -154,6 +232,44 @@ impl BlocksApi {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve a specific block.
+     *
+     * This function performs a `GET` to the `/suppression/blocks/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_blocks_email`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve a specific email address from your blocks list.**
+     */
+    pub fn get_suppression_blocks_email_stream(
+        &self,
+        email: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BlocksResponse> {
+        let url = format!(
+            "/suppression/blocks/{}",
+            crate::progenitor_support::encode_path(email),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Delete a specific block.
      *
@@ -165,7 +281,10 @@ impl BlocksApi {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_suppression_blocks_email(&self, email: &str) -> Result<crate::types::Help> {
+    pub async fn delete_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::DeleteSuppressionBlocksEmailResponse> {
         let url = format!(
             "/suppression/blocks/{}",
             crate::progenitor_support::encode_path(email),
@@ -174,3 +293,159 @@ impl BlocksApi {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `BlocksApi::get_suppression_blocks_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BlocksApiGetSuppressionBlocksParams {
+    /// The end of the time range when a blocked email was created (inclusive). This is a unix timestamp.
+    pub end_time: Option<i64>,
+    /// Limit the number of results to be displayed per page.
+    pub limit: Option<i64>,
+    /// The point in the list to begin displaying results.
+    pub offset: Option<i64>,
+    /// The start of the time range when a blocked email was created (inclusive). This is a unix timestamp.
+    pub start_time: Option<i64>,
+}
+
+impl BlocksApiGetSuppressionBlocksParams {
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+}
+
+/// The functions of [`BlocksApi`], for code that takes them as a trait, so that it can be
+/// tested with the `MockBlocksApiApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait BlocksApiApi: Send + Sync {
+    /// See [`BlocksApi::get_suppression_blocks_with`].
+    async fn get_suppression_blocks_with(
+        &self,
+        params: &BlocksApiGetSuppressionBlocksParams,
+    ) -> Result<Vec<crate::types::BlocksResponse>>;
+
+    /// See [`BlocksApi::get_all_suppression_blocks_with`].
+    async fn get_all_suppression_blocks_with(
+        &self,
+        params: &BlocksApiGetSuppressionBlocksParams,
+    ) -> Result<Vec<crate::types::BlocksResponse>>;
+
+    /// See [`BlocksApi::get_suppression_blocks_stream`].
+    fn get_suppression_blocks_stream(
+        &self,
+        params: &BlocksApiGetSuppressionBlocksParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BlocksResponse>;
+
+    /// See [`BlocksApi::delete_suppression_blocks`].
+    async fn delete_suppression_blocks(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> Result<crate::types::DeleteSuppressionBlocksResponse>;
+
+    /// See [`BlocksApi::get_suppression_blocks_email`].
+    async fn get_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BlocksResponse>>;
+
+    /// See [`BlocksApi::get_all_suppression_blocks_email`].
+    async fn get_all_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BlocksResponse>>;
+
+    /// See [`BlocksApi::get_suppression_blocks_email_stream`].
+    fn get_suppression_blocks_email_stream(
+        &self,
+        email: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BlocksResponse>;
+
+    /// See [`BlocksApi::delete_suppression_blocks_email`].
+    async fn delete_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::DeleteSuppressionBlocksEmailResponse>;
+}
+
+#[async_trait::async_trait]
+impl BlocksApiApi for BlocksApi {
+    async fn get_suppression_blocks_with(
+        &self,
+        params: &BlocksApiGetSuppressionBlocksParams,
+    ) -> Result<Vec<crate::types::BlocksResponse>> {
+        BlocksApi::get_suppression_blocks_with(self, params).await
+    }
+
+    async fn get_all_suppression_blocks_with(
+        &self,
+        params: &BlocksApiGetSuppressionBlocksParams,
+    ) -> Result<Vec<crate::types::BlocksResponse>> {
+        BlocksApi::get_all_suppression_blocks_with(self, params).await
+    }
+
+    fn get_suppression_blocks_stream(
+        &self,
+        params: &BlocksApiGetSuppressionBlocksParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BlocksResponse> {
+        BlocksApi::get_suppression_blocks_stream(self, params, start, max_items)
+    }
+
+    async fn delete_suppression_blocks(
+        &self,
+        body: &crate::types::DeleteSuppressionBlocksRequest,
+    ) -> Result<crate::types::DeleteSuppressionBlocksResponse> {
+        BlocksApi::delete_suppression_blocks(self, body).await
+    }
+
+    async fn get_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BlocksResponse>> {
+        BlocksApi::get_suppression_blocks_email(self, email).await
+    }
+
+    async fn get_all_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BlocksResponse>> {
+        BlocksApi::get_all_suppression_blocks_email(self, email).await
+    }
+
+    fn get_suppression_blocks_email_stream(
+        &self,
+        email: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BlocksResponse> {
+        BlocksApi::get_suppression_blocks_email_stream(self, email, start, max_items)
+    }
+
+    async fn delete_suppression_blocks_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::DeleteSuppressionBlocksEmailResponse> {
+        BlocksApi::delete_suppression_blocks_email(self, email).await
+    }
+}
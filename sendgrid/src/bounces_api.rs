@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct BouncesApi {
     pub client: Client,
@@ -21,21 +19,17 @@ impl BouncesApi {
      *
      * **Parameters:**
      *
-     * * `start_time: i64` -- Refers start of the time range in unix timestamp when a bounce was created (inclusive).
-     * * `end_time: i64` -- Refers end of the time range in unix timestamp when a bounce was created (inclusive).
-     * * `accept: &str` -- The license key provided with your New Relic account.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &BouncesApiGetSuppressionBouncesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_suppression_bounces(
+    pub async fn get_suppression_bounces_with(
         &self,
-        start_time: i64,
-        end_time: i64,
+        params: &BouncesApiGetSuppressionBouncesParams,
     ) -> Result<Vec<crate::types::BounceResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if end_time > 0 {
+        if let Some(end_time) = &params.end_time {
             query_args.push(("end_time".to_string(), end_time.to_string()));
         }
-        if start_time > 0 {
+        if let Some(start_time) = &params.start_time {
             query_args.push(("start_time".to_string(), start_time.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -44,6 +38,22 @@ impl BouncesApi {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_suppression_bounces_with` and pass the query parameters as a `BouncesApiGetSuppressionBouncesParams` instead.
+     */
+    #[deprecated(note = "use `get_suppression_bounces_with` instead")]
+    pub async fn get_suppression_bounces(
+        &self,
+        start_time: i64,
+        end_time: i64,
+    ) -> Result<Vec<crate::types::BounceResponse>> {
+        let params = BouncesApiGetSuppressionBouncesParams {
+            end_time: Some(end_time).filter(|v| *v > 0),
+            start_time: Some(start_time).filter(|v| *v > 0),
+        };
+        self.get_suppression_bounces_with(&params).await
+    }
+
     /**
      * Retrieve all bounces.
      *
@@ -52,23 +62,85 @@ impl BouncesApi {
      * As opposed to `get_suppression_bounces`, this function returns all the pages of the request at once.
      *
      * **This endpoint allows you to retrieve all of your bounces.**
+     *
+     * **Parameters:**
+     *
+     * * `params: &BouncesApiGetSuppressionBouncesParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_suppression_bounces_with(
+        &self,
+        params: &BouncesApiGetSuppressionBouncesParams,
+    ) -> Result<Vec<crate::types::BounceResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(end_time) = &params.end_time {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if let Some(start_time) = &params.start_time {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/suppression/bounces?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_suppression_bounces_with` and pass the query parameters as a `BouncesApiGetSuppressionBouncesParams` instead.
+     */
+    #[deprecated(note = "use `get_all_suppression_bounces_with` instead")]
     pub async fn get_all_suppression_bounces(
         &self,
         start_time: i64,
         end_time: i64,
     ) -> Result<Vec<crate::types::BounceResponse>> {
+        let params = BouncesApiGetSuppressionBouncesParams {
+            end_time: Some(end_time).filter(|v| *v > 0),
+            start_time: Some(start_time).filter(|v| *v > 0),
+        };
+        self.get_all_suppression_bounces_with(&params).await
+    }
+
+    /**
+     * Retrieve all bounces.
+     *
+     * This function performs a `GET` to the `/suppression/bounces` endpoint.
+     *
+     * As opposed to `get_all_suppression_bounces`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve all of your bounces.**
+     */
+    pub fn get_suppression_bounces_stream(
+        &self,
+        params: &BouncesApiGetSuppressionBouncesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BounceResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if end_time > 0 {
+        if let Some(end_time) = &params.end_time {
             query_args.push(("end_time".to_string(), end_time.to_string()));
         }
-        if start_time > 0 {
+        if let Some(start_time) = &params.start_time {
             query_args.push(("start_time".to_string(), start_time.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/suppression/bounces?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -141,6 +213,44 @@ impl BouncesApi {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve a Bounce.
+     *
+     * This function performs a `GET` to the `/suppression/bounces/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_bounces_email`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve a specific bounce by email address.**
+     */
+    pub fn get_suppression_bounces_email_stream(
+        &self,
+        email: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BounceResponse> {
+        let url = format!(
+            "/suppression/bounces/{}",
+            crate::progenitor_support::encode_path(email),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Delete a bounce.
      *
@@ -150,17 +260,16 @@ impl BouncesApi {
      *
      * **Parameters:**
      *
-     * * `email_address: &str` -- The email address you would like to remove from the bounce list.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &BouncesApiDeleteSuppressionBouncesEmailParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_suppression_bounces_email(
+    pub async fn delete_suppression_bounces_email_with(
         &self,
         email: &str,
-        email_address: &str,
+        params: &BouncesApiDeleteSuppressionBouncesEmailParams,
         body: &serde_json::Value,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSuppressionBouncesEmailResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !email_address.is_empty() {
+        if let Some(email_address) = &params.email_address {
             query_args.push(("email_address".to_string(), email_address.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -174,4 +283,181 @@ impl BouncesApi {
             .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `delete_suppression_bounces_email_with` and pass the query parameters as a `BouncesApiDeleteSuppressionBouncesEmailParams` instead.
+     */
+    #[deprecated(note = "use `delete_suppression_bounces_email_with` instead")]
+    pub async fn delete_suppression_bounces_email(
+        &self,
+        email: &str,
+        email_address: &str,
+        body: &serde_json::Value,
+    ) -> Result<crate::types::DeleteSuppressionBouncesEmailResponse> {
+        let params = BouncesApiDeleteSuppressionBouncesEmailParams {
+            email_address: Some(email_address.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_suppression_bounces_email_with(email, &params, body)
+            .await
+    }
+}
+
+/// The query parameters of `BouncesApi::get_suppression_bounces_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BouncesApiGetSuppressionBouncesParams {
+    /// Refers end of the time range in unix timestamp when a bounce was created (inclusive).
+    pub end_time: Option<i64>,
+    /// Refers start of the time range in unix timestamp when a bounce was created (inclusive).
+    pub start_time: Option<i64>,
+}
+
+impl BouncesApiGetSuppressionBouncesParams {
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+}
+
+/// The query parameters of `BouncesApi::delete_suppression_bounces_email_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BouncesApiDeleteSuppressionBouncesEmailParams {
+    /// The email address you would like to remove from the bounce list.
+    pub email_address: Option<String>,
+}
+
+impl BouncesApiDeleteSuppressionBouncesEmailParams {
+    pub fn email_address<T: ToString>(mut self, email_address: T) -> Self {
+        self.email_address = Some(email_address.to_string());
+        self
+    }
+}
+
+/// The functions of [`BouncesApi`], for code that takes them as a trait, so that it can be
+/// tested with the `MockBouncesApiApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait BouncesApiApi: Send + Sync {
+    /// See [`BouncesApi::get_suppression_bounces_with`].
+    async fn get_suppression_bounces_with(
+        &self,
+        params: &BouncesApiGetSuppressionBouncesParams,
+    ) -> Result<Vec<crate::types::BounceResponse>>;
+
+    /// See [`BouncesApi::get_all_suppression_bounces_with`].
+    async fn get_all_suppression_bounces_with(
+        &self,
+        params: &BouncesApiGetSuppressionBouncesParams,
+    ) -> Result<Vec<crate::types::BounceResponse>>;
+
+    /// See [`BouncesApi::get_suppression_bounces_stream`].
+    fn get_suppression_bounces_stream(
+        &self,
+        params: &BouncesApiGetSuppressionBouncesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BounceResponse>;
+
+    /// See [`BouncesApi::delete_suppression_bounces`].
+    async fn delete_suppression_bounces(
+        &self,
+        body: &crate::types::DeleteSuppressionBouncesRequest,
+    ) -> Result<()>;
+
+    /// See [`BouncesApi::get_suppression_bounces_email`].
+    async fn get_suppression_bounces_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BounceResponse>>;
+
+    /// See [`BouncesApi::get_all_suppression_bounces_email`].
+    async fn get_all_suppression_bounces_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BounceResponse>>;
+
+    /// See [`BouncesApi::get_suppression_bounces_email_stream`].
+    fn get_suppression_bounces_email_stream(
+        &self,
+        email: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BounceResponse>;
+
+    /// See [`BouncesApi::delete_suppression_bounces_email_with`].
+    async fn delete_suppression_bounces_email_with(
+        &self,
+        email: &str,
+        params: &BouncesApiDeleteSuppressionBouncesEmailParams,
+        body: &serde_json::Value,
+    ) -> Result<crate::types::DeleteSuppressionBouncesEmailResponse>;
+}
+
+#[async_trait::async_trait]
+impl BouncesApiApi for BouncesApi {
+    async fn get_suppression_bounces_with(
+        &self,
+        params: &BouncesApiGetSuppressionBouncesParams,
+    ) -> Result<Vec<crate::types::BounceResponse>> {
+        BouncesApi::get_suppression_bounces_with(self, params).await
+    }
+
+    async fn get_all_suppression_bounces_with(
+        &self,
+        params: &BouncesApiGetSuppressionBouncesParams,
+    ) -> Result<Vec<crate::types::BounceResponse>> {
+        BouncesApi::get_all_suppression_bounces_with(self, params).await
+    }
+
+    fn get_suppression_bounces_stream(
+        &self,
+        params: &BouncesApiGetSuppressionBouncesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BounceResponse> {
+        BouncesApi::get_suppression_bounces_stream(self, params, start, max_items)
+    }
+
+    async fn delete_suppression_bounces(
+        &self,
+        body: &crate::types::DeleteSuppressionBouncesRequest,
+    ) -> Result<()> {
+        BouncesApi::delete_suppression_bounces(self, body).await
+    }
+
+    async fn get_suppression_bounces_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BounceResponse>> {
+        BouncesApi::get_suppression_bounces_email(self, email).await
+    }
+
+    async fn get_all_suppression_bounces_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::BounceResponse>> {
+        BouncesApi::get_all_suppression_bounces_email(self, email).await
+    }
+
+    fn get_suppression_bounces_email_stream(
+        &self,
+        email: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BounceResponse> {
+        BouncesApi::get_suppression_bounces_email_stream(self, email, start, max_items)
+    }
+
+    async fn delete_suppression_bounces_email_with(
+        &self,
+        email: &str,
+        params: &BouncesApiDeleteSuppressionBouncesEmailParams,
+        body: &serde_json::Value,
+    ) -> Result<crate::types::DeleteSuppressionBouncesEmailResponse> {
+        BouncesApi::delete_suppression_bounces_email_with(self, email, params, body).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CampaignsApi {
     pub client: Client,
@@ -25,20 +23,17 @@ impl CampaignsApi {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- The number of results you would like to receive at a time.
-     * * `offset: i64` -- The index of the first campaign to return, where 0 is the first campaign.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &CampaignsApiGetCampaignsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_campaigns(
+    pub async fn get_campaigns_with(
         &self,
-        limit: i64,
-        offset: i64,
+        params: &CampaignsApiGetCampaignsParams,
     ) -> Result<crate::types::GetCampaignsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -47,6 +42,22 @@ impl CampaignsApi {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_campaigns_with` and pass the query parameters as a `CampaignsApiGetCampaignsParams` instead.
+     */
+    #[deprecated(note = "use `get_campaigns_with` instead")]
+    pub async fn get_campaigns(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetCampaignsResponse> {
+        let params = CampaignsApiGetCampaignsParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_campaigns_with(&params).await
+    }
+
     /**
      * Create a Campaign.
      *
@@ -62,7 +73,7 @@ impl CampaignsApi {
      */
     pub async fn post_campaign(
         &self,
-        body: &crate::types::CampaignsRequest,
+        body: &crate::types::CampaignRequest,
     ) -> Result<crate::types::CampaignResponseAllOf> {
         let url = "/campaigns".to_string();
         self.client
@@ -177,10 +188,10 @@ impl CampaignsApi {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn get_campaigns_campaign_schedule(
+    pub async fn get_campaigns_campaign_schedules(
         &self,
         campaign_id: i64,
-    ) -> Result<crate::types::ScheduleACampaignRequest> {
+    ) -> Result<crate::types::ViewScheduledTimeOfACampaignResponse> {
         let url = format!(
             "/campaigns/{}/schedules",
             crate::progenitor_support::encode_path(&campaign_id.to_string()),
@@ -254,7 +265,7 @@ impl CampaignsApi {
     pub async fn patch_campaigns_campaign_schedules(
         &self,
         campaign_id: i64,
-        body: &crate::types::ScheduleACampaignRequest,
+        body: &crate::types::UpdateAScheduledCampaignRequest,
     ) -> Result<crate::types::UpdateAScheduledCampaignResponse> {
         let url = format!(
             "/campaigns/{}/schedules",
@@ -282,7 +293,7 @@ impl CampaignsApi {
     pub async fn post_campaigns_campaign_schedules_test(
         &self,
         campaign_id: i64,
-        body: &crate::types::SendATestCampaignRequest,
+        body: &crate::types::PostCampaignsCampaignSchedulesTestRequest,
     ) -> Result<crate::types::SendATestCampaignRequest> {
         let url = format!(
             "/campaigns/{}/schedules/test",
@@ -294,3 +305,172 @@ impl CampaignsApi {
             .await
     }
 }
+
+/// The query parameters of `CampaignsApi::get_campaigns_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CampaignsApiGetCampaignsParams {
+    /// The number of results you would like to receive at a time.
+    pub limit: Option<i64>,
+    /// The index of the first campaign to return, where 0 is the first campaign.
+    pub offset: Option<i64>,
+}
+
+impl CampaignsApiGetCampaignsParams {
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The functions of [`CampaignsApi`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCampaignsApiApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CampaignsApiApi: Send + Sync {
+    /// See [`CampaignsApi::get_campaigns_with`].
+    async fn get_campaigns_with(
+        &self,
+        params: &CampaignsApiGetCampaignsParams,
+    ) -> Result<crate::types::GetCampaignsResponse>;
+
+    /// See [`CampaignsApi::post_campaign`].
+    async fn post_campaign(
+        &self,
+        body: &crate::types::CampaignRequest,
+    ) -> Result<crate::types::CampaignResponseAllOf>;
+
+    /// See [`CampaignsApi::get_campaigns_campaign`].
+    async fn get_campaigns_campaign(
+        &self,
+        campaign_id: i64,
+    ) -> Result<crate::types::GetCampaignsCampaignResponse>;
+
+    /// See [`CampaignsApi::delete_campaigns_campaign`].
+    async fn delete_campaigns_campaign(&self, campaign_id: i64) -> Result<()>;
+
+    /// See [`CampaignsApi::patch_campaigns_campaign`].
+    async fn patch_campaigns_campaign(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::UpdateACampaignRequest,
+    ) -> Result<crate::types::CampaignResponseAllOf>;
+
+    /// See [`CampaignsApi::post_campaigns_campaign_schedules_now`].
+    async fn post_campaigns_campaign_schedules_now(
+        &self,
+        campaign_id: i64,
+    ) -> Result<crate::types::SendACampaignResponse>;
+
+    /// See [`CampaignsApi::get_campaigns_campaign_schedules`].
+    async fn get_campaigns_campaign_schedules(
+        &self,
+        campaign_id: i64,
+    ) -> Result<crate::types::ViewScheduledTimeOfACampaignResponse>;
+
+    /// See [`CampaignsApi::post_campaigns_campaign_schedule`].
+    async fn post_campaigns_campaign_schedule(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::ScheduleACampaignRequest,
+    ) -> Result<crate::types::ScheduleACampaignResponse>;
+
+    /// See [`CampaignsApi::delete_campaigns_campaign_schedules`].
+    async fn delete_campaigns_campaign_schedules(&self, campaign_id: i64) -> Result<()>;
+
+    /// See [`CampaignsApi::patch_campaigns_campaign_schedules`].
+    async fn patch_campaigns_campaign_schedules(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::UpdateAScheduledCampaignRequest,
+    ) -> Result<crate::types::UpdateAScheduledCampaignResponse>;
+
+    /// See [`CampaignsApi::post_campaigns_campaign_schedules_test`].
+    async fn post_campaigns_campaign_schedules_test(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::PostCampaignsCampaignSchedulesTestRequest,
+    ) -> Result<crate::types::SendATestCampaignRequest>;
+}
+
+#[async_trait::async_trait]
+impl CampaignsApiApi for CampaignsApi {
+    async fn get_campaigns_with(
+        &self,
+        params: &CampaignsApiGetCampaignsParams,
+    ) -> Result<crate::types::GetCampaignsResponse> {
+        CampaignsApi::get_campaigns_with(self, params).await
+    }
+
+    async fn post_campaign(
+        &self,
+        body: &crate::types::CampaignRequest,
+    ) -> Result<crate::types::CampaignResponseAllOf> {
+        CampaignsApi::post_campaign(self, body).await
+    }
+
+    async fn get_campaigns_campaign(
+        &self,
+        campaign_id: i64,
+    ) -> Result<crate::types::GetCampaignsCampaignResponse> {
+        CampaignsApi::get_campaigns_campaign(self, campaign_id).await
+    }
+
+    async fn delete_campaigns_campaign(&self, campaign_id: i64) -> Result<()> {
+        CampaignsApi::delete_campaigns_campaign(self, campaign_id).await
+    }
+
+    async fn patch_campaigns_campaign(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::UpdateACampaignRequest,
+    ) -> Result<crate::types::CampaignResponseAllOf> {
+        CampaignsApi::patch_campaigns_campaign(self, campaign_id, body).await
+    }
+
+    async fn post_campaigns_campaign_schedules_now(
+        &self,
+        campaign_id: i64,
+    ) -> Result<crate::types::SendACampaignResponse> {
+        CampaignsApi::post_campaigns_campaign_schedules_now(self, campaign_id).await
+    }
+
+    async fn get_campaigns_campaign_schedules(
+        &self,
+        campaign_id: i64,
+    ) -> Result<crate::types::ViewScheduledTimeOfACampaignResponse> {
+        CampaignsApi::get_campaigns_campaign_schedules(self, campaign_id).await
+    }
+
+    async fn post_campaigns_campaign_schedule(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::ScheduleACampaignRequest,
+    ) -> Result<crate::types::ScheduleACampaignResponse> {
+        CampaignsApi::post_campaigns_campaign_schedule(self, campaign_id, body).await
+    }
+
+    async fn delete_campaigns_campaign_schedules(&self, campaign_id: i64) -> Result<()> {
+        CampaignsApi::delete_campaigns_campaign_schedules(self, campaign_id).await
+    }
+
+    async fn patch_campaigns_campaign_schedules(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::UpdateAScheduledCampaignRequest,
+    ) -> Result<crate::types::UpdateAScheduledCampaignResponse> {
+        CampaignsApi::patch_campaigns_campaign_schedules(self, campaign_id, body).await
+    }
+
+    async fn post_campaigns_campaign_schedules_test(
+        &self,
+        campaign_id: i64,
+        body: &crate::types::PostCampaignsCampaignSchedulesTestRequest,
+    ) -> Result<crate::types::SendATestCampaignRequest> {
+        CampaignsApi::post_campaigns_campaign_schedules_test(self, campaign_id, body).await
+    }
+}
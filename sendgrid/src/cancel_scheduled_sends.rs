@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CancelScheduledSends {
     pub client: Client,
@@ -70,6 +68,41 @@ impl CancelScheduledSends {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve all scheduled sends.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends` endpoint.
+     *
+     * As opposed to `get_all_user_scheduled_sends`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve all cancelled and paused scheduled send information.**
+     *
+     * This endpoint will return only the scheduled sends that are associated with a `batch_id`. If you have scheduled a send using the `/mail/send` endpoint and the `send_at` field but no `batch_id`, the send will be scheduled for delivery; however, it will not be returned by this endpoint. For this reason, you should assign a `batch_id` to any scheduled send you may need to pause or cancel in the future.
+     */
+    pub fn get_user_scheduled_sends_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserScheduledSendStatusAllOf> {
+        let url = "/user/scheduled_sends".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Cancel or pause a scheduled send.
      *
@@ -167,6 +200,44 @@ impl CancelScheduledSends {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve scheduled send.
+     *
+     * This function performs a `GET` to the `/user/scheduled_sends/{batch_id}` endpoint.
+     *
+     * As opposed to `get_all_user_scheduled_sends_batch`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve the cancel/paused scheduled send information for a specific `batch_id`.**
+     */
+    pub fn get_user_scheduled_sends_batch_stream(
+        &self,
+        batch_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserScheduledSendStatusAllOf> {
+        let url = format!(
+            "/user/scheduled_sends/{}",
+            crate::progenitor_support::encode_path(batch_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Delete a cancellation or pause from a scheduled send.
      *
@@ -205,7 +276,7 @@ impl CancelScheduledSends {
     pub async fn patch_user_scheduled_sends_batch(
         &self,
         batch_id: &str,
-        body: &crate::types::UserScheduledSendStatus,
+        body: &crate::types::PatchUserScheduledSendsBatchRequest,
     ) -> Result<()> {
         let url = format!(
             "/user/scheduled_sends/{}",
@@ -217,3 +288,143 @@ impl CancelScheduledSends {
             .await
     }
 }
+
+/// The functions of [`CancelScheduledSends`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCancelScheduledSendsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CancelScheduledSendsApi: Send + Sync {
+    /// See [`CancelScheduledSends::post_mail_batch`].
+    async fn post_mail_batch(&self) -> Result<crate::types::MailBatchId>;
+
+    /// See [`CancelScheduledSends::get_user_scheduled_sends`].
+    async fn get_user_scheduled_sends(
+        &self,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>>;
+
+    /// See [`CancelScheduledSends::get_all_user_scheduled_sends`].
+    async fn get_all_user_scheduled_sends(
+        &self,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>>;
+
+    /// See [`CancelScheduledSends::get_user_scheduled_sends_stream`].
+    fn get_user_scheduled_sends_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserScheduledSendStatusAllOf>;
+
+    /// See [`CancelScheduledSends::post_user_scheduled_send`].
+    async fn post_user_scheduled_send(
+        &self,
+        body: &crate::types::CancelPauseAScheduledSendRequest,
+    ) -> Result<crate::types::UserScheduledSendStatusAllOf>;
+
+    /// See [`CancelScheduledSends::get_mail_batch`].
+    async fn get_mail_batch(&self, batch_id: &str) -> Result<crate::types::MailBatchId>;
+
+    /// See [`CancelScheduledSends::get_user_scheduled_sends_batch`].
+    async fn get_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>>;
+
+    /// See [`CancelScheduledSends::get_all_user_scheduled_sends_batch`].
+    async fn get_all_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>>;
+
+    /// See [`CancelScheduledSends::get_user_scheduled_sends_batch_stream`].
+    fn get_user_scheduled_sends_batch_stream(
+        &self,
+        batch_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserScheduledSendStatusAllOf>;
+
+    /// See [`CancelScheduledSends::delete_user_scheduled_sends_batch`].
+    async fn delete_user_scheduled_sends_batch(&self, batch_id: &str) -> Result<()>;
+
+    /// See [`CancelScheduledSends::patch_user_scheduled_sends_batch`].
+    async fn patch_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+        body: &crate::types::PatchUserScheduledSendsBatchRequest,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl CancelScheduledSendsApi for CancelScheduledSends {
+    async fn post_mail_batch(&self) -> Result<crate::types::MailBatchId> {
+        CancelScheduledSends::post_mail_batch(self).await
+    }
+
+    async fn get_user_scheduled_sends(
+        &self,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        CancelScheduledSends::get_user_scheduled_sends(self).await
+    }
+
+    async fn get_all_user_scheduled_sends(
+        &self,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        CancelScheduledSends::get_all_user_scheduled_sends(self).await
+    }
+
+    fn get_user_scheduled_sends_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserScheduledSendStatusAllOf> {
+        CancelScheduledSends::get_user_scheduled_sends_stream(self, start, max_items)
+    }
+
+    async fn post_user_scheduled_send(
+        &self,
+        body: &crate::types::CancelPauseAScheduledSendRequest,
+    ) -> Result<crate::types::UserScheduledSendStatusAllOf> {
+        CancelScheduledSends::post_user_scheduled_send(self, body).await
+    }
+
+    async fn get_mail_batch(&self, batch_id: &str) -> Result<crate::types::MailBatchId> {
+        CancelScheduledSends::get_mail_batch(self, batch_id).await
+    }
+
+    async fn get_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        CancelScheduledSends::get_user_scheduled_sends_batch(self, batch_id).await
+    }
+
+    async fn get_all_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+    ) -> Result<Vec<crate::types::UserScheduledSendStatusAllOf>> {
+        CancelScheduledSends::get_all_user_scheduled_sends_batch(self, batch_id).await
+    }
+
+    fn get_user_scheduled_sends_batch_stream(
+        &self,
+        batch_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserScheduledSendStatusAllOf> {
+        CancelScheduledSends::get_user_scheduled_sends_batch_stream(
+            self, batch_id, start, max_items,
+        )
+    }
+
+    async fn delete_user_scheduled_sends_batch(&self, batch_id: &str) -> Result<()> {
+        CancelScheduledSends::delete_user_scheduled_sends_batch(self, batch_id).await
+    }
+
+    async fn patch_user_scheduled_sends_batch(
+        &self,
+        batch_id: &str,
+        body: &crate::types::PatchUserScheduledSendsBatchRequest,
+    ) -> Result<()> {
+        CancelScheduledSends::patch_user_scheduled_sends_batch(self, batch_id, body).await
+    }
+}
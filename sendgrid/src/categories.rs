@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Categories {
     pub client: Client,
@@ -21,25 +19,20 @@ impl Categories {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- The number of categories to display per page.
-     * * `category: &str` -- Allows you to perform a prefix search on this particular category.
-     * * `offset: i64` -- The point in the list that you would like to begin displaying results.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &CategoriesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        limit: i64,
-        category: &str,
-        offset: i64,
+        params: &CategoriesGetPageParams,
     ) -> Result<Vec<crate::types::GetCategoriesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !category.is_empty() {
+        if let Some(category) = &params.category {
             query_args.push(("category".to_string(), category.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -48,6 +41,24 @@ impl Categories {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `CategoriesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        limit: i64,
+        category: &str,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetCategoriesResponse>> {
+        let params = CategoriesGetPageParams {
+            category: Some(category.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * Retrieve all categories.
      *
@@ -56,23 +67,86 @@ impl Categories {
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * **This endpoint allows you to retrieve a list of all of your categories.**
+     *
+     * **Parameters:**
+     *
+     * * `params: &CategoriesGetPageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_with(
+        &self,
+        params: &CategoriesGetPageParams,
+    ) -> Result<Vec<crate::types::GetCategoriesResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(category) = &params.category {
+            query_args.push(("category".to_string(), category.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/categories?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `CategoriesGetPageParams` instead.
      */
+    #[deprecated(note = "use `get_all_with` instead")]
     pub async fn get_all(
         &self,
         category: &str,
         offset: i64,
     ) -> Result<Vec<crate::types::GetCategoriesResponse>> {
+        let params = CategoriesGetPageParams {
+            category: Some(category.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * Retrieve all categories.
+     *
+     * This function performs a `GET` to the `/categories` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve a list of all of your categories.**
+     */
+    pub fn get_stream(
+        &self,
+        params: &CategoriesGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetCategoriesResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !category.is_empty() {
+        if let Some(category) = &params.category {
             query_args.push(("category".to_string(), category.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/categories?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -86,48 +160,35 @@ impl Categories {
      *
      * **Parameters:**
      *
-     * * `sort_by_metric: &str` -- The metric that you want to sort by.  Must be a single metric.
-     * * `sort_by_direction: crate::types::SortByDirection` -- The direction you want to sort.
-     * * `start_date: &str` -- The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
-     * * `end_date: &str` -- The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
-     * * `limit: i64` -- Limits the number of results returned.
-     * * `offset: i64` -- The point in the list to begin retrieving results.
-     * * `aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy` -- How to group the statistics. Must be either "day", "week", or "month".
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &CategoriesGetStatsSumParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stats_sum(
+    pub async fn get_stats_sum_with(
         &self,
-        sort_by_metric: &str,
-        sort_by_direction: crate::types::SortByDirection,
-        start_date: &str,
-        end_date: &str,
-        limit: i64,
-        offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        params: &CategoriesGetStatsSumParams,
     ) -> Result<crate::types::CategoryStats> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !sort_by_direction.to_string().is_empty() {
+        if let Some(sort_by_direction) = &params.sort_by_direction {
             query_args.push((
                 "sort_by_direction".to_string(),
                 sort_by_direction.to_string(),
             ));
         }
-        if !sort_by_metric.is_empty() {
+        if let Some(sort_by_metric) = &params.sort_by_metric {
             query_args.push(("sort_by_metric".to_string(), sort_by_metric.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -136,6 +197,32 @@ impl Categories {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stats_sum_with` and pass the query parameters as a `CategoriesGetStatsSumParams` instead.
+     */
+    #[deprecated(note = "use `get_stats_sum_with` instead")]
+    pub async fn get_stats_sum(
+        &self,
+        sort_by_metric: &str,
+        sort_by_direction: crate::types::SortByDirection,
+        start_date: &str,
+        end_date: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::GetCategoriesStatsSumsAggregatedBy,
+    ) -> Result<crate::types::CategoryStats> {
+        let params = CategoriesGetStatsSumParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            sort_by_direction: Some(sort_by_direction).filter(|v| !v.to_string().is_empty()),
+            sort_by_metric: Some(sort_by_metric.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_stats_sum_with(&params).await
+    }
+
     /**
      * Retrieve Email Statistics for Categories.
      *
@@ -147,40 +234,29 @@ impl Categories {
      *
      * **Parameters:**
      *
-     * * `start_date: &str` -- The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
-     * * `end_date: &str` -- The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
-     * * `categories: &str` -- The individual categories that you want to retrieve statistics for. You may include up to 10 different categories.
-     * * `limit: i64` -- The number of results to include.
-     * * `offset: i64` -- The number of results to skip.
-     * * `aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy` -- How to group the statistics. Must be either "day", "week", or "month".
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &CategoriesGetStatsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_stats(
+    pub async fn get_stats_with(
         &self,
-        start_date: &str,
-        end_date: &str,
-        categories: &str,
-        limit: i64,
-        offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        params: &CategoriesGetStatsParams,
     ) -> Result<Vec<crate::types::CategoryStats>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !categories.is_empty() {
+        if let Some(categories) = &params.categories {
             query_args.push(("categories".to_string(), categories.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -189,6 +265,30 @@ impl Categories {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_stats_with` and pass the query parameters as a `CategoriesGetStatsParams` instead.
+     */
+    #[deprecated(note = "use `get_stats_with` instead")]
+    pub async fn get_stats(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        categories: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::GetCategoriesStatsAggregatedBy,
+    ) -> Result<Vec<crate::types::CategoryStats>> {
+        let params = CategoriesGetStatsParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            categories: Some(categories.to_string()).filter(|s| !s.is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_stats_with(&params).await
+    }
+
     /**
      * Retrieve Email Statistics for Categories.
      *
@@ -199,34 +299,358 @@ impl Categories {
      * **This endpoint allows you to retrieve all of your email statistics for each of your categories.**
      *
      * If you do not define any query parameters, this endpoint will return a sum for each category in groups of 10.
+     *
+     * **Parameters:**
+     *
+     * * `params: &CategoriesGetStatsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_stats_with(
+        &self,
+        params: &CategoriesGetStatsParams,
+    ) -> Result<Vec<crate::types::CategoryStats>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(aggregated_by) = &params.aggregated_by {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if let Some(categories) = &params.categories {
+            query_args.push(("categories".to_string(), categories.to_string()));
+        }
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/categories/stats?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_stats_with` and pass the query parameters as a `CategoriesGetStatsParams` instead.
      */
+    #[deprecated(note = "use `get_all_stats_with` instead")]
     pub async fn get_all_stats(
         &self,
         start_date: &str,
         end_date: &str,
         categories: &str,
         offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        aggregated_by: crate::types::GetCategoriesStatsAggregatedBy,
     ) -> Result<Vec<crate::types::CategoryStats>> {
+        let params = CategoriesGetStatsParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            categories: Some(categories.to_string()).filter(|s| !s.is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_stats_with(&params).await
+    }
+
+    /**
+     * Retrieve Email Statistics for Categories.
+     *
+     * This function performs a `GET` to the `/categories/stats` endpoint.
+     *
+     * As opposed to `get_all_stats`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve all of your email statistics for each of your categories.**
+     *
+     * If you do not define any query parameters, this endpoint will return a sum for each category in groups of 10.
+     */
+    pub fn get_stats_stream(
+        &self,
+        params: &CategoriesGetStatsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CategoryStats> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !categories.is_empty() {
+        if let Some(categories) = &params.categories {
             query_args.push(("categories".to_string(), categories.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/categories/stats?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Categories::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CategoriesGetPageParams {
+    /// Allows you to perform a prefix search on this particular category.
+    pub category: Option<String>,
+    /// The number of categories to display per page.
+    pub limit: Option<i64>,
+    /// The point in the list that you would like to begin displaying results.
+    pub offset: Option<i64>,
+}
+
+impl CategoriesGetPageParams {
+    pub fn category<T: ToString>(mut self, category: T) -> Self {
+        self.category = Some(category.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Categories::get_stats_sum_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CategoriesGetStatsSumParams {
+    /// How to group the statistics. Must be either "day", "week", or "month".
+    pub aggregated_by: Option<crate::types::GetCategoriesStatsSumsAggregatedBy>,
+    /// The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+    pub end_date: Option<String>,
+    /// Limits the number of results returned.
+    pub limit: Option<i64>,
+    /// The point in the list to begin retrieving results.
+    pub offset: Option<i64>,
+    /// The direction you want to sort.
+    pub sort_by_direction: Option<crate::types::SortByDirection>,
+    /// The metric that you want to sort by.  Must be a single metric.
+    pub sort_by_metric: Option<String>,
+    /// The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
+    pub start_date: Option<String>,
+}
+
+impl CategoriesGetStatsSumParams {
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::GetCategoriesStatsSumsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.sort_by_direction = Some(sort_by_direction);
+        self
+    }
+
+    pub fn sort_by_metric<T: ToString>(mut self, sort_by_metric: T) -> Self {
+        self.sort_by_metric = Some(sort_by_metric.to_string());
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Categories::get_stats_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CategoriesGetStatsParams {
+    /// How to group the statistics. Must be either "day", "week", or "month".
+    pub aggregated_by: Option<crate::types::GetCategoriesStatsAggregatedBy>,
+    /// The individual categories that you want to retrieve statistics for. You may include up to 10 different categories.
+    pub categories: Option<String>,
+    /// The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+    pub end_date: Option<String>,
+    /// The number of results to include.
+    pub limit: Option<i64>,
+    /// The number of results to skip.
+    pub offset: Option<i64>,
+    /// The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
+    pub start_date: Option<String>,
+}
+
+impl CategoriesGetStatsParams {
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::GetCategoriesStatsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    pub fn categories<T: ToString>(mut self, categories: T) -> Self {
+        self.categories = Some(categories.to_string());
+        self
+    }
+
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The functions of [`Categories`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCategoriesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CategoriesApi: Send + Sync {
+    /// See [`Categories::get_page_with`].
+    async fn get_page_with(
+        &self,
+        params: &CategoriesGetPageParams,
+    ) -> Result<Vec<crate::types::GetCategoriesResponse>>;
+
+    /// See [`Categories::get_all_with`].
+    async fn get_all_with(
+        &self,
+        params: &CategoriesGetPageParams,
+    ) -> Result<Vec<crate::types::GetCategoriesResponse>>;
+
+    /// See [`Categories::get_stream`].
+    fn get_stream(
+        &self,
+        params: &CategoriesGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetCategoriesResponse>;
+
+    /// See [`Categories::get_stats_sum_with`].
+    async fn get_stats_sum_with(
+        &self,
+        params: &CategoriesGetStatsSumParams,
+    ) -> Result<crate::types::CategoryStats>;
+
+    /// See [`Categories::get_stats_with`].
+    async fn get_stats_with(
+        &self,
+        params: &CategoriesGetStatsParams,
+    ) -> Result<Vec<crate::types::CategoryStats>>;
+
+    /// See [`Categories::get_all_stats_with`].
+    async fn get_all_stats_with(
+        &self,
+        params: &CategoriesGetStatsParams,
+    ) -> Result<Vec<crate::types::CategoryStats>>;
+
+    /// See [`Categories::get_stats_stream`].
+    fn get_stats_stream(
+        &self,
+        params: &CategoriesGetStatsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CategoryStats>;
+}
+
+#[async_trait::async_trait]
+impl CategoriesApi for Categories {
+    async fn get_page_with(
+        &self,
+        params: &CategoriesGetPageParams,
+    ) -> Result<Vec<crate::types::GetCategoriesResponse>> {
+        Categories::get_page_with(self, params).await
+    }
+
+    async fn get_all_with(
+        &self,
+        params: &CategoriesGetPageParams,
+    ) -> Result<Vec<crate::types::GetCategoriesResponse>> {
+        Categories::get_all_with(self, params).await
+    }
+
+    fn get_stream(
+        &self,
+        params: &CategoriesGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetCategoriesResponse> {
+        Categories::get_stream(self, params, start, max_items)
+    }
+
+    async fn get_stats_sum_with(
+        &self,
+        params: &CategoriesGetStatsSumParams,
+    ) -> Result<crate::types::CategoryStats> {
+        Categories::get_stats_sum_with(self, params).await
+    }
+
+    async fn get_stats_with(
+        &self,
+        params: &CategoriesGetStatsParams,
+    ) -> Result<Vec<crate::types::CategoryStats>> {
+        Categories::get_stats_with(self, params).await
+    }
+
+    async fn get_all_stats_with(
+        &self,
+        params: &CategoriesGetStatsParams,
+    ) -> Result<Vec<crate::types::CategoryStats>> {
+        Categories::get_all_stats_with(self, params).await
+    }
+
+    fn get_stats_stream(
+        &self,
+        params: &CategoriesGetStatsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CategoryStats> {
+        Categories::get_stats_stream(self, params, start, max_items)
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Certificates {
     pub client: Client,
@@ -73,6 +71,46 @@ impl Certificates {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get All SSO Certificates by Integration.
+     *
+     * This function performs a `GET` to the `/sso/integrations/{integration_id}/certificates` endpoint.
+     *
+     * As opposed to `get_all_sso_integrations_integration`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve all your IdP configurations by configuration ID.**
+     *
+     * The `integration_id` expected by this endpoint is the `id` returned in the response by the "Get All SSO Integrations" endpoint.
+     */
+    pub fn get_sso_integrations_integration_stream(
+        &self,
+        integration_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SsoCertificateBody> {
+        let url = format!(
+            "/sso/integrations/{}/certificates",
+            crate::progenitor_support::encode_path(integration_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get an SSO Certificate.
      *
@@ -131,3 +169,102 @@ impl Certificates {
             .await
     }
 }
+
+/// The functions of [`Certificates`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCertificatesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CertificatesApi: Send + Sync {
+    /// See [`Certificates::post_sso`].
+    async fn post_sso(
+        &self,
+        body: &crate::types::PostSsoCertificatesRequest,
+    ) -> Result<crate::types::SsoCertificateBody>;
+
+    /// See [`Certificates::get_sso_integrations_integration`].
+    async fn get_sso_integrations_integration(
+        &self,
+        integration_id: &str,
+    ) -> Result<Vec<crate::types::SsoCertificateBody>>;
+
+    /// See [`Certificates::get_all_sso_integrations_integration`].
+    async fn get_all_sso_integrations_integration(
+        &self,
+        integration_id: &str,
+    ) -> Result<Vec<crate::types::SsoCertificateBody>>;
+
+    /// See [`Certificates::get_sso_integrations_integration_stream`].
+    fn get_sso_integrations_integration_stream(
+        &self,
+        integration_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SsoCertificateBody>;
+
+    /// See [`Certificates::get_sso_cert`].
+    async fn get_sso_cert(&self, cert_id: &str) -> Result<crate::types::SsoCertificateBody>;
+
+    /// See [`Certificates::delete_sso_cert`].
+    async fn delete_sso_cert(&self, cert_id: &str) -> Result<crate::types::SsoCertificateBody>;
+
+    /// See [`Certificates::patch_sso_cert`].
+    async fn patch_sso_cert(
+        &self,
+        cert_id: &str,
+        body: &crate::types::PatchSsoCertificatesCertRequest,
+    ) -> Result<Vec<crate::types::SsoErrorResponse>>;
+}
+
+#[async_trait::async_trait]
+impl CertificatesApi for Certificates {
+    async fn post_sso(
+        &self,
+        body: &crate::types::PostSsoCertificatesRequest,
+    ) -> Result<crate::types::SsoCertificateBody> {
+        Certificates::post_sso(self, body).await
+    }
+
+    async fn get_sso_integrations_integration(
+        &self,
+        integration_id: &str,
+    ) -> Result<Vec<crate::types::SsoCertificateBody>> {
+        Certificates::get_sso_integrations_integration(self, integration_id).await
+    }
+
+    async fn get_all_sso_integrations_integration(
+        &self,
+        integration_id: &str,
+    ) -> Result<Vec<crate::types::SsoCertificateBody>> {
+        Certificates::get_all_sso_integrations_integration(self, integration_id).await
+    }
+
+    fn get_sso_integrations_integration_stream(
+        &self,
+        integration_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SsoCertificateBody> {
+        Certificates::get_sso_integrations_integration_stream(
+            self,
+            integration_id,
+            start,
+            max_items,
+        )
+    }
+
+    async fn get_sso_cert(&self, cert_id: &str) -> Result<crate::types::SsoCertificateBody> {
+        Certificates::get_sso_cert(self, cert_id).await
+    }
+
+    async fn delete_sso_cert(&self, cert_id: &str) -> Result<crate::types::SsoCertificateBody> {
+        Certificates::delete_sso_cert(self, cert_id).await
+    }
+
+    async fn patch_sso_cert(
+        &self,
+        cert_id: &str,
+        body: &crate::types::PatchSsoCertificatesCertRequest,
+    ) -> Result<Vec<crate::types::SsoErrorResponse>> {
+        Certificates::patch_sso_cert(self, cert_id, body).await
+    }
+}
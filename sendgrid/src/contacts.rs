@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Contacts {
     pub client: Client,
@@ -74,22 +72,20 @@ impl Contacts {
      *
      * **Parameters:**
      *
-     * * `delete_all_contacts: &str` -- The license key provided with your New Relic account.
-     * * `ids: &str` -- The license key provided with your New Relic account.
+     * * `params: &ContactsDeleteMcParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_mc(
+    pub async fn delete_mc_with(
         &self,
-        delete_all_contacts: &str,
-        ids: &str,
+        params: &ContactsDeleteMcParams,
     ) -> Result<crate::types::DeleteMcContactsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !delete_all_contacts.is_empty() {
+        if let Some(delete_all_contacts) = &params.delete_all_contacts {
             query_args.push((
                 "delete_all_contacts".to_string(),
                 delete_all_contacts.to_string(),
             ));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -98,6 +94,22 @@ impl Contacts {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_mc_with` and pass the query parameters as a `ContactsDeleteMcParams` instead.
+     */
+    #[deprecated(note = "use `delete_mc_with` instead")]
+    pub async fn delete_mc(
+        &self,
+        delete_all_contacts: &str,
+        ids: &str,
+    ) -> Result<crate::types::DeleteMcContactsResponse> {
+        let params = ContactsDeleteMcParams {
+            delete_all_contacts: Some(delete_all_contacts.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_mc_with(&params).await
+    }
+
     /**
      * Get Total Contact Count.
      *
@@ -333,3 +345,170 @@ impl Contacts {
             .await
     }
 }
+
+/// The query parameters of `Contacts::delete_mc_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ContactsDeleteMcParams {
+    /// The license key provided with your New Relic account.
+    pub delete_all_contacts: Option<String>,
+    /// The license key provided with your New Relic account.
+    pub ids: Option<String>,
+}
+
+impl ContactsDeleteMcParams {
+    pub fn delete_all_contacts<T: ToString>(mut self, delete_all_contacts: T) -> Self {
+        self.delete_all_contacts = Some(delete_all_contacts.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+}
+
+/// The functions of [`Contacts`], for code that takes them as a trait, so that it can be
+/// tested with the `MockContactsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ContactsApi: Send + Sync {
+    /// See [`Contacts::get_mc_contats`].
+    async fn get_mc_contats(&self) -> Result<crate::types::GetMcContatsResponse>;
+
+    /// See [`Contacts::put_mc`].
+    async fn put_mc(
+        &self,
+        body: &crate::types::PutMcContactsRequest,
+    ) -> Result<crate::types::PutMcContactsResponse>;
+
+    /// See [`Contacts::delete_mc_with`].
+    async fn delete_mc_with(
+        &self,
+        params: &ContactsDeleteMcParams,
+    ) -> Result<crate::types::DeleteMcContactsResponse>;
+
+    /// See [`Contacts::get_mc_count`].
+    async fn get_mc_count(&self) -> Result<crate::types::GetMcContactsCountResponse>;
+
+    /// See [`Contacts::get_marketing_exports`].
+    async fn get_marketing_exports(
+        &self,
+    ) -> Result<crate::types::GetMarketingContactsExportsResponse>;
+
+    /// See [`Contacts::post_mc_export`].
+    async fn post_mc_export(
+        &self,
+        body: &crate::types::PostMcContactsExportsRequest,
+    ) -> Result<crate::types::PostMcContactsExportsResponse>;
+
+    /// See [`Contacts::get_mc`].
+    async fn get_mc(&self, id: &str) -> Result<crate::types::ContactDetails3>;
+
+    /// See [`Contacts::post_mc_search`].
+    async fn post_mc_search(
+        &self,
+        body: &crate::types::PostMcContactsSearchRequest,
+    ) -> Result<crate::types::PostMcContactsSearchResponse>;
+
+    /// See [`Contacts::put_mc_imports`].
+    async fn put_mc_imports(
+        &self,
+        body: &crate::types::PutMcContactsImportsRequest,
+    ) -> Result<crate::types::PutMcContactsImportsResponse>;
+
+    /// See [`Contacts::get_marketing_import`].
+    async fn get_marketing_import(&self, id: &str) -> Result<crate::types::ContactImport>;
+
+    /// See [`Contacts::get_mc_export`].
+    async fn get_mc_export(&self, id: &str) -> Result<crate::types::ContactExport>;
+
+    /// See [`Contacts::post_marketing_batch`].
+    async fn post_marketing_batch(
+        &self,
+        body: &crate::types::PostMarketingContactsBatchRequest,
+    ) -> Result<crate::types::PostMarketingContactsBatchResponse>;
+
+    /// See [`Contacts::post_marketing_search_email`].
+    async fn post_marketing_search_email(
+        &self,
+        body: &crate::types::PostMarketingContactsSearchEmailsRequest,
+    ) -> Result<crate::types::PostMarketingContactsSearchEmailsResponse>;
+}
+
+#[async_trait::async_trait]
+impl ContactsApi for Contacts {
+    async fn get_mc_contats(&self) -> Result<crate::types::GetMcContatsResponse> {
+        Contacts::get_mc_contats(self).await
+    }
+
+    async fn put_mc(
+        &self,
+        body: &crate::types::PutMcContactsRequest,
+    ) -> Result<crate::types::PutMcContactsResponse> {
+        Contacts::put_mc(self, body).await
+    }
+
+    async fn delete_mc_with(
+        &self,
+        params: &ContactsDeleteMcParams,
+    ) -> Result<crate::types::DeleteMcContactsResponse> {
+        Contacts::delete_mc_with(self, params).await
+    }
+
+    async fn get_mc_count(&self) -> Result<crate::types::GetMcContactsCountResponse> {
+        Contacts::get_mc_count(self).await
+    }
+
+    async fn get_marketing_exports(
+        &self,
+    ) -> Result<crate::types::GetMarketingContactsExportsResponse> {
+        Contacts::get_marketing_exports(self).await
+    }
+
+    async fn post_mc_export(
+        &self,
+        body: &crate::types::PostMcContactsExportsRequest,
+    ) -> Result<crate::types::PostMcContactsExportsResponse> {
+        Contacts::post_mc_export(self, body).await
+    }
+
+    async fn get_mc(&self, id: &str) -> Result<crate::types::ContactDetails3> {
+        Contacts::get_mc(self, id).await
+    }
+
+    async fn post_mc_search(
+        &self,
+        body: &crate::types::PostMcContactsSearchRequest,
+    ) -> Result<crate::types::PostMcContactsSearchResponse> {
+        Contacts::post_mc_search(self, body).await
+    }
+
+    async fn put_mc_imports(
+        &self,
+        body: &crate::types::PutMcContactsImportsRequest,
+    ) -> Result<crate::types::PutMcContactsImportsResponse> {
+        Contacts::put_mc_imports(self, body).await
+    }
+
+    async fn get_marketing_import(&self, id: &str) -> Result<crate::types::ContactImport> {
+        Contacts::get_marketing_import(self, id).await
+    }
+
+    async fn get_mc_export(&self, id: &str) -> Result<crate::types::ContactExport> {
+        Contacts::get_mc_export(self, id).await
+    }
+
+    async fn post_marketing_batch(
+        &self,
+        body: &crate::types::PostMarketingContactsBatchRequest,
+    ) -> Result<crate::types::PostMarketingContactsBatchResponse> {
+        Contacts::post_marketing_batch(self, body).await
+    }
+
+    async fn post_marketing_search_email(
+        &self,
+        body: &crate::types::PostMarketingContactsSearchEmailsRequest,
+    ) -> Result<crate::types::PostMarketingContactsSearchEmailsResponse> {
+        Contacts::post_marketing_search_email(self, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ContactsApiCustomFields {
     pub client: Client,
@@ -117,3 +115,73 @@ impl ContactsApiCustomFields {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`ContactsApiCustomFields`], for code that takes them as a trait, so that it can be
+/// tested with the `MockContactsApiCustomFieldsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ContactsApiCustomFieldsApi: Send + Sync {
+    /// See [`ContactsApiCustomFields::get_contactdb_custom_fields`].
+    async fn get_contactdb_custom_fields(
+        &self,
+    ) -> Result<crate::types::ListAllCustomFieldsResponse>;
+
+    /// See [`ContactsApiCustomFields::post_contactdb_custom_field`].
+    async fn post_contactdb_custom_field(
+        &self,
+        body: &crate::types::PostContactdbCustomFieldsRequest,
+    ) -> Result<crate::types::ContactdbCustomFieldWithAllOf>;
+
+    /// See [`ContactsApiCustomFields::get_contactdb_custom_fields_field`].
+    async fn get_contactdb_custom_fields_field(
+        &self,
+        custom_field_id: i64,
+    ) -> Result<crate::types::ContactdbCustomFieldWithAllOf>;
+
+    /// See [`ContactsApiCustomFields::delete_contactdb_custom_fields_field`].
+    async fn delete_contactdb_custom_fields_field(
+        &self,
+        custom_field_id: i64,
+    ) -> Result<crate::types::GlobalErrorResponseSchema>;
+
+    /// See [`ContactsApiCustomFields::get_contactdb_reserved_fields`].
+    async fn get_contactdb_reserved_fields(
+        &self,
+    ) -> Result<crate::types::GetContactdbReservedFieldsResponse>;
+}
+
+#[async_trait::async_trait]
+impl ContactsApiCustomFieldsApi for ContactsApiCustomFields {
+    async fn get_contactdb_custom_fields(
+        &self,
+    ) -> Result<crate::types::ListAllCustomFieldsResponse> {
+        ContactsApiCustomFields::get_contactdb_custom_fields(self).await
+    }
+
+    async fn post_contactdb_custom_field(
+        &self,
+        body: &crate::types::PostContactdbCustomFieldsRequest,
+    ) -> Result<crate::types::ContactdbCustomFieldWithAllOf> {
+        ContactsApiCustomFields::post_contactdb_custom_field(self, body).await
+    }
+
+    async fn get_contactdb_custom_fields_field(
+        &self,
+        custom_field_id: i64,
+    ) -> Result<crate::types::ContactdbCustomFieldWithAllOf> {
+        ContactsApiCustomFields::get_contactdb_custom_fields_field(self, custom_field_id).await
+    }
+
+    async fn delete_contactdb_custom_fields_field(
+        &self,
+        custom_field_id: i64,
+    ) -> Result<crate::types::GlobalErrorResponseSchema> {
+        ContactsApiCustomFields::delete_contactdb_custom_fields_field(self, custom_field_id).await
+    }
+
+    async fn get_contactdb_reserved_fields(
+        &self,
+    ) -> Result<crate::types::GetContactdbReservedFieldsResponse> {
+        ContactsApiCustomFields::get_contactdb_reserved_fields(self).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ContactsApiLists {
     pub client: Client,
@@ -41,7 +39,7 @@ impl ContactsApiLists {
      */
     pub async fn post_contactdb_list(
         &self,
-        body: &crate::types::IpPool,
+        body: &crate::types::CreateAListRequest,
     ) -> Result<crate::types::ContactdbList> {
         let url = "/contactdb/lists".to_string();
         self.client
@@ -100,17 +98,16 @@ impl ContactsApiLists {
      *
      * **Parameters:**
      *
-     * * `delete_contacts: bool` -- Adds the ability to delete all contacts on the list in addition to deleting the list.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &ContactsApiListsDeleteContactdbListsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_contactdb_lists_list(
+    pub async fn delete_contactdb_lists_list_with(
         &self,
         list_id: &str,
-        delete_contacts: bool,
+        params: &ContactsApiListsDeleteContactdbListsListParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if delete_contacts {
+        if let Some(delete_contacts) = &params.delete_contacts {
             query_args.push(("delete_contacts".to_string(), delete_contacts.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -125,6 +122,23 @@ impl ContactsApiLists {
             .await
     }
 
+    /**
+     * Deprecated: use `delete_contactdb_lists_list_with` and pass the query parameters as a `ContactsApiListsDeleteContactdbListsListParams` instead.
+     */
+    #[deprecated(note = "use `delete_contactdb_lists_list_with` instead")]
+    pub async fn delete_contactdb_lists_list(
+        &self,
+        list_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ContactsApiListsDeleteContactdbListsListParams {
+            delete_contacts: Some(delete_contacts).filter(|v| *v),
+        };
+        self.delete_contactdb_lists_list_with(list_id, &params, body)
+            .await
+    }
+
     /**
      * Update a List.
      *
@@ -140,7 +154,7 @@ impl ContactsApiLists {
     pub async fn patch_contactdb_lists_list(
         &self,
         list_id: &str,
-        body: &crate::types::IpPool,
+        body: &crate::types::UpdateAListRequest,
     ) -> Result<crate::types::PatchContactdbListsListResponse> {
         let url = format!(
             "/contactdb/lists/{}",
@@ -161,22 +175,19 @@ impl ContactsApiLists {
      *
      * **Parameters:**
      *
-     * * `page: i64` -- Page index of first recipient to return (must be a positive integer).
-     * * `page_size: i64` -- Number of recipients to return at a time (must be a positive integer between 1 and 1000).
      * * `list_id: i64` -- The ID of the list whose recipients you are requesting.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &ContactsApiListsGetContactdbListsListRecipientsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_contactdb_lists_list_recipients(
+    pub async fn get_contactdb_lists_list_recipients_with(
         &self,
         list_id: i64,
-        page: i64,
-        page_size: i64,
-    ) -> Result<crate::types::GetContactdbRecipientsSearchResponse> {
+        params: &ContactsApiListsGetContactdbListsListRecipientsParams,
+    ) -> Result<crate::types::GetContactdbListsListRecipientsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -189,6 +200,24 @@ impl ContactsApiLists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_contactdb_lists_list_recipients_with` and pass the query parameters as a `ContactsApiListsGetContactdbListsListRecipientsParams` instead.
+     */
+    #[deprecated(note = "use `get_contactdb_lists_list_recipients_with` instead")]
+    pub async fn get_contactdb_lists_list_recipients(
+        &self,
+        list_id: i64,
+        page: i64,
+        page_size: i64,
+    ) -> Result<crate::types::GetContactdbListsListRecipientsResponse> {
+        let params = ContactsApiListsGetContactdbListsListRecipientsParams {
+            page: Some(page).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.get_contactdb_lists_list_recipients_with(list_id, &params)
+            .await
+    }
+
     /**
      * Add Multiple Recipients to a List.
      *
@@ -272,3 +301,178 @@ impl ContactsApiLists {
             .await
     }
 }
+
+/// The query parameters of `ContactsApiLists::delete_contactdb_lists_list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ContactsApiListsDeleteContactdbListsListParams {
+    /// Adds the ability to delete all contacts on the list in addition to deleting the list.
+    pub delete_contacts: Option<bool>,
+}
+
+impl ContactsApiListsDeleteContactdbListsListParams {
+    pub fn delete_contacts(mut self, delete_contacts: bool) -> Self {
+        self.delete_contacts = Some(delete_contacts);
+        self
+    }
+}
+
+/// The query parameters of `ContactsApiLists::get_contactdb_lists_list_recipients_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ContactsApiListsGetContactdbListsListRecipientsParams {
+    /// Page index of first recipient to return (must be a positive integer).
+    pub page: Option<i64>,
+    /// Number of recipients to return at a time (must be a positive integer between 1 and 1000).
+    pub page_size: Option<i64>,
+}
+
+impl ContactsApiListsGetContactdbListsListRecipientsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The functions of [`ContactsApiLists`], for code that takes them as a trait, so that it can be
+/// tested with the `MockContactsApiListsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ContactsApiListsApi: Send + Sync {
+    /// See [`ContactsApiLists::get_contactdb_lists`].
+    async fn get_contactdb_lists(&self) -> Result<crate::types::ListAllListsResponse>;
+
+    /// See [`ContactsApiLists::post_contactdb_list`].
+    async fn post_contactdb_list(
+        &self,
+        body: &crate::types::CreateAListRequest,
+    ) -> Result<crate::types::ContactdbList>;
+
+    /// See [`ContactsApiLists::delete_contactdb_lists`].
+    async fn delete_contactdb_lists(&self, body: &[i64]) -> Result<()>;
+
+    /// See [`ContactsApiLists::get_contactdb_lists_list`].
+    async fn get_contactdb_lists_list(&self, list_id: &str) -> Result<crate::types::ContactdbList>;
+
+    /// See [`ContactsApiLists::delete_contactdb_lists_list_with`].
+    async fn delete_contactdb_lists_list_with(
+        &self,
+        list_id: &str,
+        params: &ContactsApiListsDeleteContactdbListsListParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ContactsApiLists::patch_contactdb_lists_list`].
+    async fn patch_contactdb_lists_list(
+        &self,
+        list_id: &str,
+        body: &crate::types::UpdateAListRequest,
+    ) -> Result<crate::types::PatchContactdbListsListResponse>;
+
+    /// See [`ContactsApiLists::get_contactdb_lists_list_recipients_with`].
+    async fn get_contactdb_lists_list_recipients_with(
+        &self,
+        list_id: i64,
+        params: &ContactsApiListsGetContactdbListsListRecipientsParams,
+    ) -> Result<crate::types::GetContactdbListsListRecipientsResponse>;
+
+    /// See [`ContactsApiLists::post_contactdb_lists_list_recipient`].
+    async fn post_contactdb_lists_list_recipient(&self, list_id: i64, body: &[i64]) -> Result<()>;
+
+    /// See [`ContactsApiLists::post_contactdb_lists_list_recipients_recipient`].
+    async fn post_contactdb_lists_list_recipients_recipient(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ContactsApiLists::delete_contactdb_lists_list_recipients_recipient`].
+    async fn delete_contactdb_lists_list_recipients_recipient(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ContactsApiListsApi for ContactsApiLists {
+    async fn get_contactdb_lists(&self) -> Result<crate::types::ListAllListsResponse> {
+        ContactsApiLists::get_contactdb_lists(self).await
+    }
+
+    async fn post_contactdb_list(
+        &self,
+        body: &crate::types::CreateAListRequest,
+    ) -> Result<crate::types::ContactdbList> {
+        ContactsApiLists::post_contactdb_list(self, body).await
+    }
+
+    async fn delete_contactdb_lists(&self, body: &[i64]) -> Result<()> {
+        ContactsApiLists::delete_contactdb_lists(self, body).await
+    }
+
+    async fn get_contactdb_lists_list(&self, list_id: &str) -> Result<crate::types::ContactdbList> {
+        ContactsApiLists::get_contactdb_lists_list(self, list_id).await
+    }
+
+    async fn delete_contactdb_lists_list_with(
+        &self,
+        list_id: &str,
+        params: &ContactsApiListsDeleteContactdbListsListParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ContactsApiLists::delete_contactdb_lists_list_with(self, list_id, params, body).await
+    }
+
+    async fn patch_contactdb_lists_list(
+        &self,
+        list_id: &str,
+        body: &crate::types::UpdateAListRequest,
+    ) -> Result<crate::types::PatchContactdbListsListResponse> {
+        ContactsApiLists::patch_contactdb_lists_list(self, list_id, body).await
+    }
+
+    async fn get_contactdb_lists_list_recipients_with(
+        &self,
+        list_id: i64,
+        params: &ContactsApiListsGetContactdbListsListRecipientsParams,
+    ) -> Result<crate::types::GetContactdbListsListRecipientsResponse> {
+        ContactsApiLists::get_contactdb_lists_list_recipients_with(self, list_id, params).await
+    }
+
+    async fn post_contactdb_lists_list_recipient(&self, list_id: i64, body: &[i64]) -> Result<()> {
+        ContactsApiLists::post_contactdb_lists_list_recipient(self, list_id, body).await
+    }
+
+    async fn post_contactdb_lists_list_recipients_recipient(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+    ) -> Result<()> {
+        ContactsApiLists::post_contactdb_lists_list_recipients_recipient(
+            self,
+            list_id,
+            recipient_id,
+        )
+        .await
+    }
+
+    async fn delete_contactdb_lists_list_recipients_recipient(
+        &self,
+        list_id: i64,
+        recipient_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ContactsApiLists::delete_contactdb_lists_list_recipients_recipient(
+            self,
+            list_id,
+            recipient_id,
+            body,
+        )
+        .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ContactsApiRecipients {
     pub client: Client,
@@ -24,20 +22,17 @@ impl ContactsApiRecipients {
      *
      * **Parameters:**
      *
-     * * `page: i64` -- Page index of first recipients to return (must be a positive integer).
-     * * `page_size: i64` -- Number of recipients to return at a time (must be a positive integer between 1 and 1000).
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &ContactsApiRecipientsGetContactdbRecipientParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_contactdb_recipients(
+    pub async fn get_contactdb_recipient_with(
         &self,
-        page: i64,
-        page_size: i64,
-    ) -> Result<crate::types::ListRecipientsResponse> {
+        params: &ContactsApiRecipientsGetContactdbRecipientParams,
+    ) -> Result<crate::types::ListRecipientsResponseData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -46,6 +41,22 @@ impl ContactsApiRecipients {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_contactdb_recipient_with` and pass the query parameters as a `ContactsApiRecipientsGetContactdbRecipientParams` instead.
+     */
+    #[deprecated(note = "use `get_contactdb_recipient_with` instead")]
+    pub async fn get_contactdb_recipient(
+        &self,
+        page: i64,
+        page_size: i64,
+    ) -> Result<crate::types::ListRecipientsResponseData> {
+        let params = ContactsApiRecipientsGetContactdbRecipientParams {
+            page: Some(page).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.get_contactdb_recipient_with(&params).await
+    }
+
     /**
      * Add recipients.
      *
@@ -64,7 +75,7 @@ impl ContactsApiRecipients {
     pub async fn post_contactdb_recipient(
         &self,
         body: &[crate::types::PostContactdbRecipientsRequest],
-    ) -> Result<crate::types::ContactDbRecipientResponse> {
+    ) -> Result<crate::types::ContactdbRecipientResponse> {
         let url = "/contactdb/recipients".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -84,7 +95,10 @@ impl ContactsApiRecipients {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_contactdb_recipients(&self, body: &[String]) -> Result<crate::types::Help> {
+    pub async fn delete_contactdb_recipients(
+        &self,
+        body: &[String],
+    ) -> Result<crate::types::DeleteContactdbRecipientsResponse> {
         let url = "/contactdb/recipients".to_string();
         self.client
             .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -109,7 +123,7 @@ impl ContactsApiRecipients {
     pub async fn patch_contactdb_recipients(
         &self,
         body: &[crate::types::PatchContactdbRecipientsRequest],
-    ) -> Result<crate::types::ContactDbRecipientResponse> {
+    ) -> Result<crate::types::ContactdbRecipientResponse> {
         let url = "/contactdb/recipients".to_string();
         self.client
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -173,7 +187,7 @@ impl ContactsApiRecipients {
     pub async fn delete_contactdb_recipients_recipient(
         &self,
         recipient_id: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteContactdbRecipientsRecipientResponse> {
         let url = format!(
             "/contactdb/recipients/{}",
             crate::progenitor_support::encode_path(recipient_id),
@@ -263,15 +277,14 @@ impl ContactsApiRecipients {
      *
      * **Parameters:**
      *
-     * * `field_name: &str` -- The license key provided with your New Relic account.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &ContactsApiRecipientsGetContactdbRecipientsSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_contactdb_recipients_search(
+    pub async fn get_contactdb_recipients_search_with(
         &self,
-        field_name: &str,
+        params: &ContactsApiRecipientsGetContactdbRecipientsSearchParams,
     ) -> Result<crate::types::GetContactdbRecipientsSearchResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !field_name.is_empty() {
+        if let Some(field_name) = &params.field_name {
             query_args.push(("{field_name}".to_string(), field_name.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -280,6 +293,20 @@ impl ContactsApiRecipients {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_contactdb_recipients_search_with` and pass the query parameters as a `ContactsApiRecipientsGetContactdbRecipientsSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_contactdb_recipients_search_with` instead")]
+    pub async fn get_contactdb_recipients_search(
+        &self,
+        field_name: &str,
+    ) -> Result<crate::types::GetContactdbRecipientsSearchResponse> {
+        let params = ContactsApiRecipientsGetContactdbRecipientsSearchParams {
+            field_name: Some(field_name.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_contactdb_recipients_search_with(&params).await
+    }
+
     /**
      * Search recipients.
      *
@@ -339,3 +366,192 @@ impl ContactsApiRecipients {
             .await
     }
 }
+
+/// The query parameters of `ContactsApiRecipients::get_contactdb_recipient_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ContactsApiRecipientsGetContactdbRecipientParams {
+    /// Page index of first recipients to return (must be a positive integer).
+    pub page: Option<i64>,
+    /// Number of recipients to return at a time (must be a positive integer between 1 and 1000).
+    pub page_size: Option<i64>,
+}
+
+impl ContactsApiRecipientsGetContactdbRecipientParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `ContactsApiRecipients::get_contactdb_recipients_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ContactsApiRecipientsGetContactdbRecipientsSearchParams {
+    /// The license key provided with your New Relic account.
+    pub field_name: Option<String>,
+}
+
+impl ContactsApiRecipientsGetContactdbRecipientsSearchParams {
+    pub fn field_name<T: ToString>(mut self, field_name: T) -> Self {
+        self.field_name = Some(field_name.to_string());
+        self
+    }
+}
+
+/// The functions of [`ContactsApiRecipients`], for code that takes them as a trait, so that it can be
+/// tested with the `MockContactsApiRecipientsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ContactsApiRecipientsApi: Send + Sync {
+    /// See [`ContactsApiRecipients::get_contactdb_recipient_with`].
+    async fn get_contactdb_recipient_with(
+        &self,
+        params: &ContactsApiRecipientsGetContactdbRecipientParams,
+    ) -> Result<crate::types::ListRecipientsResponseData>;
+
+    /// See [`ContactsApiRecipients::post_contactdb_recipient`].
+    async fn post_contactdb_recipient(
+        &self,
+        body: &[crate::types::PostContactdbRecipientsRequest],
+    ) -> Result<crate::types::ContactdbRecipientResponse>;
+
+    /// See [`ContactsApiRecipients::delete_contactdb_recipients`].
+    async fn delete_contactdb_recipients(
+        &self,
+        body: &[String],
+    ) -> Result<crate::types::DeleteContactdbRecipientsResponse>;
+
+    /// See [`ContactsApiRecipients::patch_contactdb_recipients`].
+    async fn patch_contactdb_recipients(
+        &self,
+        body: &[crate::types::PatchContactdbRecipientsRequest],
+    ) -> Result<crate::types::ContactdbRecipientResponse>;
+
+    /// See [`ContactsApiRecipients::get_contactdb_statu`].
+    async fn get_contactdb_statu(&self) -> Result<crate::types::GetContactdbStatusResponseData>;
+
+    /// See [`ContactsApiRecipients::get_contactdb_recipients_recipient`].
+    async fn get_contactdb_recipients_recipient(
+        &self,
+        recipient_id: &str,
+    ) -> Result<crate::types::ContactdbRecipient>;
+
+    /// See [`ContactsApiRecipients::delete_contactdb_recipients_recipient`].
+    async fn delete_contactdb_recipients_recipient(
+        &self,
+        recipient_id: &str,
+    ) -> Result<crate::types::DeleteContactdbRecipientsRecipientResponse>;
+
+    /// See [`ContactsApiRecipients::get_contactdb_recipients_recipient_lists`].
+    async fn get_contactdb_recipients_recipient_lists(
+        &self,
+        recipient_id: &str,
+    ) -> Result<crate::types::GetContactdbRecipientsRecipientListsResponse>;
+
+    /// See [`ContactsApiRecipients::get_contactdb_recipients_billable_count`].
+    async fn get_contactdb_recipients_billable_count(
+        &self,
+    ) -> Result<crate::types::ContactdbRecipientCount>;
+
+    /// See [`ContactsApiRecipients::get_contactdb_recipients_count`].
+    async fn get_contactdb_recipients_count(&self)
+        -> Result<crate::types::ContactdbRecipientCount>;
+
+    /// See [`ContactsApiRecipients::get_contactdb_recipients_search_with`].
+    async fn get_contactdb_recipients_search_with(
+        &self,
+        params: &ContactsApiRecipientsGetContactdbRecipientsSearchParams,
+    ) -> Result<crate::types::GetContactdbRecipientsSearchResponse>;
+
+    /// See [`ContactsApiRecipients::post_contactdb_recipients_search`].
+    async fn post_contactdb_recipients_search(
+        &self,
+        body: &crate::types::PostContactdbRecipientsSearchRequest,
+    ) -> Result<crate::types::PostContactdbRecipientsSearchResponseData>;
+}
+
+#[async_trait::async_trait]
+impl ContactsApiRecipientsApi for ContactsApiRecipients {
+    async fn get_contactdb_recipient_with(
+        &self,
+        params: &ContactsApiRecipientsGetContactdbRecipientParams,
+    ) -> Result<crate::types::ListRecipientsResponseData> {
+        ContactsApiRecipients::get_contactdb_recipient_with(self, params).await
+    }
+
+    async fn post_contactdb_recipient(
+        &self,
+        body: &[crate::types::PostContactdbRecipientsRequest],
+    ) -> Result<crate::types::ContactdbRecipientResponse> {
+        ContactsApiRecipients::post_contactdb_recipient(self, body).await
+    }
+
+    async fn delete_contactdb_recipients(
+        &self,
+        body: &[String],
+    ) -> Result<crate::types::DeleteContactdbRecipientsResponse> {
+        ContactsApiRecipients::delete_contactdb_recipients(self, body).await
+    }
+
+    async fn patch_contactdb_recipients(
+        &self,
+        body: &[crate::types::PatchContactdbRecipientsRequest],
+    ) -> Result<crate::types::ContactdbRecipientResponse> {
+        ContactsApiRecipients::patch_contactdb_recipients(self, body).await
+    }
+
+    async fn get_contactdb_statu(&self) -> Result<crate::types::GetContactdbStatusResponseData> {
+        ContactsApiRecipients::get_contactdb_statu(self).await
+    }
+
+    async fn get_contactdb_recipients_recipient(
+        &self,
+        recipient_id: &str,
+    ) -> Result<crate::types::ContactdbRecipient> {
+        ContactsApiRecipients::get_contactdb_recipients_recipient(self, recipient_id).await
+    }
+
+    async fn delete_contactdb_recipients_recipient(
+        &self,
+        recipient_id: &str,
+    ) -> Result<crate::types::DeleteContactdbRecipientsRecipientResponse> {
+        ContactsApiRecipients::delete_contactdb_recipients_recipient(self, recipient_id).await
+    }
+
+    async fn get_contactdb_recipients_recipient_lists(
+        &self,
+        recipient_id: &str,
+    ) -> Result<crate::types::GetContactdbRecipientsRecipientListsResponse> {
+        ContactsApiRecipients::get_contactdb_recipients_recipient_lists(self, recipient_id).await
+    }
+
+    async fn get_contactdb_recipients_billable_count(
+        &self,
+    ) -> Result<crate::types::ContactdbRecipientCount> {
+        ContactsApiRecipients::get_contactdb_recipients_billable_count(self).await
+    }
+
+    async fn get_contactdb_recipients_count(
+        &self,
+    ) -> Result<crate::types::ContactdbRecipientCount> {
+        ContactsApiRecipients::get_contactdb_recipients_count(self).await
+    }
+
+    async fn get_contactdb_recipients_search_with(
+        &self,
+        params: &ContactsApiRecipientsGetContactdbRecipientsSearchParams,
+    ) -> Result<crate::types::GetContactdbRecipientsSearchResponse> {
+        ContactsApiRecipients::get_contactdb_recipients_search_with(self, params).await
+    }
+
+    async fn post_contactdb_recipients_search(
+        &self,
+        body: &crate::types::PostContactdbRecipientsSearchRequest,
+    ) -> Result<crate::types::PostContactdbRecipientsSearchResponseData> {
+        ContactsApiRecipients::post_contactdb_recipients_search(self, body).await
+    }
+}
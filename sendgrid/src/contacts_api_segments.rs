@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ContactsApiSegments {
     pub client: Client,
@@ -123,17 +121,16 @@ impl ContactsApiSegments {
      *
      * **Parameters:**
      *
-     * * `delete_contacts: bool` -- True to delete all contacts matching the segment in addition to deleting the segment.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &ContactsApiSegmentsDeleteContactdbSegmentsSegmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_contactdb_segments_segment(
+    pub async fn delete_contactdb_segments_segment_with(
         &self,
         segment_id: &str,
-        delete_contacts: bool,
+        params: &ContactsApiSegmentsDeleteContactdbSegmentsSegmentParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if delete_contacts {
+        if let Some(delete_contacts) = &params.delete_contacts {
             query_args.push(("delete_contacts".to_string(), delete_contacts.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -148,6 +145,23 @@ impl ContactsApiSegments {
             .await
     }
 
+    /**
+     * Deprecated: use `delete_contactdb_segments_segment_with` and pass the query parameters as a `ContactsApiSegmentsDeleteContactdbSegmentsSegmentParams` instead.
+     */
+    #[deprecated(note = "use `delete_contactdb_segments_segment_with` instead")]
+    pub async fn delete_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+        delete_contacts: bool,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ContactsApiSegmentsDeleteContactdbSegmentsSegmentParams {
+            delete_contacts: Some(delete_contacts).filter(|v| *v),
+        };
+        self.delete_contactdb_segments_segment_with(segment_id, &params, body)
+            .await
+    }
+
     /**
      * Update a segment.
      *
@@ -184,21 +198,18 @@ impl ContactsApiSegments {
      *
      * **Parameters:**
      *
-     * * `page: i64`
-     * * `page_size: i64`
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_contactdb_segments_segment_recipients(
+    pub async fn get_contactdb_segments_segment_recipients_with(
         &self,
         segment_id: i64,
-        page: i64,
-        page_size: i64,
+        params: &ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsParams,
     ) -> Result<crate::types::ListRecipientsOnASegmentResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -210,4 +221,148 @@ impl ContactsApiSegments {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_contactdb_segments_segment_recipients_with` and pass the query parameters as a `ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsParams` instead.
+     */
+    #[deprecated(note = "use `get_contactdb_segments_segment_recipients_with` instead")]
+    pub async fn get_contactdb_segments_segment_recipients(
+        &self,
+        segment_id: i64,
+        page: i64,
+        page_size: i64,
+    ) -> Result<crate::types::ListRecipientsOnASegmentResponse> {
+        let params = ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsParams {
+            page: Some(page).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.get_contactdb_segments_segment_recipients_with(segment_id, &params)
+            .await
+    }
+}
+
+/// The query parameters of `ContactsApiSegments::delete_contactdb_segments_segment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ContactsApiSegmentsDeleteContactdbSegmentsSegmentParams {
+    /// True to delete all contacts matching the segment in addition to deleting the segment.
+    pub delete_contacts: Option<bool>,
+}
+
+impl ContactsApiSegmentsDeleteContactdbSegmentsSegmentParams {
+    pub fn delete_contacts(mut self, delete_contacts: bool) -> Self {
+        self.delete_contacts = Some(delete_contacts);
+        self
+    }
+}
+
+/// The query parameters of `ContactsApiSegments::get_contactdb_segments_segment_recipients_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsParams {
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+impl ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsParams {
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The functions of [`ContactsApiSegments`], for code that takes them as a trait, so that it can be
+/// tested with the `MockContactsApiSegmentsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ContactsApiSegmentsApi: Send + Sync {
+    /// See [`ContactsApiSegments::get_contactdb_segments`].
+    async fn get_contactdb_segments(&self) -> Result<crate::types::ListAllSegmentsResponse>;
+
+    /// See [`ContactsApiSegments::post_contactdb_segment`].
+    async fn post_contactdb_segment(
+        &self,
+        body: &crate::types::ContactdbSegments,
+    ) -> Result<crate::types::ContactdbSegmentsWithAllOf>;
+
+    /// See [`ContactsApiSegments::get_contactdb_segments_segment`].
+    async fn get_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> Result<crate::types::ContactdbSegments>;
+
+    /// See [`ContactsApiSegments::delete_contactdb_segments_segment_with`].
+    async fn delete_contactdb_segments_segment_with(
+        &self,
+        segment_id: &str,
+        params: &ContactsApiSegmentsDeleteContactdbSegmentsSegmentParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ContactsApiSegments::patch_contactdb_segments_segment`].
+    async fn patch_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::PatchContactdbSegmentsSegmentRequest,
+    ) -> Result<crate::types::ContactdbSegments>;
+
+    /// See [`ContactsApiSegments::get_contactdb_segments_segment_recipients_with`].
+    async fn get_contactdb_segments_segment_recipients_with(
+        &self,
+        segment_id: i64,
+        params: &ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsParams,
+    ) -> Result<crate::types::ListRecipientsOnASegmentResponse>;
+}
+
+#[async_trait::async_trait]
+impl ContactsApiSegmentsApi for ContactsApiSegments {
+    async fn get_contactdb_segments(&self) -> Result<crate::types::ListAllSegmentsResponse> {
+        ContactsApiSegments::get_contactdb_segments(self).await
+    }
+
+    async fn post_contactdb_segment(
+        &self,
+        body: &crate::types::ContactdbSegments,
+    ) -> Result<crate::types::ContactdbSegmentsWithAllOf> {
+        ContactsApiSegments::post_contactdb_segment(self, body).await
+    }
+
+    async fn get_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> Result<crate::types::ContactdbSegments> {
+        ContactsApiSegments::get_contactdb_segments_segment(self, segment_id).await
+    }
+
+    async fn delete_contactdb_segments_segment_with(
+        &self,
+        segment_id: &str,
+        params: &ContactsApiSegmentsDeleteContactdbSegmentsSegmentParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ContactsApiSegments::delete_contactdb_segments_segment_with(self, segment_id, params, body)
+            .await
+    }
+
+    async fn patch_contactdb_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::PatchContactdbSegmentsSegmentRequest,
+    ) -> Result<crate::types::ContactdbSegments> {
+        ContactsApiSegments::patch_contactdb_segments_segment(self, segment_id, body).await
+    }
+
+    async fn get_contactdb_segments_segment_recipients_with(
+        &self,
+        segment_id: i64,
+        params: &ContactsApiSegmentsGetContactdbSegmentsSegmentRecipientsParams,
+    ) -> Result<crate::types::ListRecipientsOnASegmentResponse> {
+        ContactsApiSegments::get_contactdb_segments_segment_recipients_with(
+            self, segment_id, params,
+        )
+        .await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CsvUiOnly {
     pub client: Client,
@@ -27,15 +25,14 @@ impl CsvUiOnly {
      *
      * **Parameters:**
      *
-     * * `query: &str` -- Uses a SQL like syntax to indicate which messages to include in the CSV.
-     * * `authorization: &str` -- The license key provided with your New Relic account.
+     * * `params: &CsvUiOnlyPostMessagesDownloadParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn post_messages_download(
+    pub async fn post_messages_download_with(
         &self,
-        query: &str,
+        params: &CsvUiOnlyPostMessagesDownloadParams,
     ) -> Result<crate::types::PostMessagesDownloadResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -44,6 +41,20 @@ impl CsvUiOnly {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `post_messages_download_with` and pass the query parameters as a `CsvUiOnlyPostMessagesDownloadParams` instead.
+     */
+    #[deprecated(note = "use `post_messages_download_with` instead")]
+    pub async fn post_messages_download(
+        &self,
+        query: &str,
+    ) -> Result<crate::types::PostMessagesDownloadResponse> {
+        let params = CsvUiOnlyPostMessagesDownloadParams {
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.post_messages_download_with(&params).await
+    }
+
     /**
      * Download CSV.
      *
@@ -67,3 +78,52 @@ impl CsvUiOnly {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `CsvUiOnly::post_messages_download_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CsvUiOnlyPostMessagesDownloadParams {
+    /// Uses a SQL like syntax to indicate which messages to include in the CSV.
+    pub query: Option<String>,
+}
+
+impl CsvUiOnlyPostMessagesDownloadParams {
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The functions of [`CsvUiOnly`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCsvUiOnlyApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CsvUiOnlyApi: Send + Sync {
+    /// See [`CsvUiOnly::post_messages_download_with`].
+    async fn post_messages_download_with(
+        &self,
+        params: &CsvUiOnlyPostMessagesDownloadParams,
+    ) -> Result<crate::types::PostMessagesDownloadResponse>;
+
+    /// See [`CsvUiOnly::get_messages_download`].
+    async fn get_messages_download(
+        &self,
+        download_uuid: &str,
+    ) -> Result<crate::types::GetMessagesDownloadResponse>;
+}
+
+#[async_trait::async_trait]
+impl CsvUiOnlyApi for CsvUiOnly {
+    async fn post_messages_download_with(
+        &self,
+        params: &CsvUiOnlyPostMessagesDownloadParams,
+    ) -> Result<crate::types::PostMessagesDownloadResponse> {
+        CsvUiOnly::post_messages_download_with(self, params).await
+    }
+
+    async fn get_messages_download(
+        &self,
+        download_uuid: &str,
+    ) -> Result<crate::types::GetMessagesDownloadResponse> {
+        CsvUiOnly::get_messages_download(self, download_uuid).await
+    }
+}
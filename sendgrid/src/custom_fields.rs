@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CustomFields {
     pub client: Client,
@@ -79,8 +77,8 @@ impl CustomFields {
     pub async fn patch_mc_field_definitions_custom(
         &self,
         custom_field_id: &str,
-        body: &crate::types::IpPool,
-    ) -> Result<crate::types::PostMcFieldDefinitionsResponseAllOf> {
+        body: &crate::types::PatchMcFieldDefinitionsCustomRequest,
+    ) -> Result<crate::types::PatchMcFieldDefinitionsCustomResponseAllOf> {
         let url = format!(
             "/marketing/field_definitions/{}",
             crate::progenitor_support::encode_path(custom_field_id),
@@ -91,3 +89,57 @@ impl CustomFields {
             .await
     }
 }
+
+/// The functions of [`CustomFields`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCustomFieldsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CustomFieldsApi: Send + Sync {
+    /// See [`CustomFields::get_mc_field_definitions`].
+    async fn get_mc_field_definitions(&self)
+        -> Result<crate::types::GetMcFieldDefinitionsResponse>;
+
+    /// See [`CustomFields::post_mc_field_definition`].
+    async fn post_mc_field_definition(
+        &self,
+        body: &crate::types::PostMcFieldDefinitionsRequest,
+    ) -> Result<crate::types::PostMcFieldDefinitionsResponseAllOf>;
+
+    /// See [`CustomFields::delete_mc_field_definitions_custom`].
+    async fn delete_mc_field_definitions_custom(&self, custom_field_id: &str) -> Result<()>;
+
+    /// See [`CustomFields::patch_mc_field_definitions_custom`].
+    async fn patch_mc_field_definitions_custom(
+        &self,
+        custom_field_id: &str,
+        body: &crate::types::PatchMcFieldDefinitionsCustomRequest,
+    ) -> Result<crate::types::PatchMcFieldDefinitionsCustomResponseAllOf>;
+}
+
+#[async_trait::async_trait]
+impl CustomFieldsApi for CustomFields {
+    async fn get_mc_field_definitions(
+        &self,
+    ) -> Result<crate::types::GetMcFieldDefinitionsResponse> {
+        CustomFields::get_mc_field_definitions(self).await
+    }
+
+    async fn post_mc_field_definition(
+        &self,
+        body: &crate::types::PostMcFieldDefinitionsRequest,
+    ) -> Result<crate::types::PostMcFieldDefinitionsResponseAllOf> {
+        CustomFields::post_mc_field_definition(self, body).await
+    }
+
+    async fn delete_mc_field_definitions_custom(&self, custom_field_id: &str) -> Result<()> {
+        CustomFields::delete_mc_field_definitions_custom(self, custom_field_id).await
+    }
+
+    async fn patch_mc_field_definitions_custom(
+        &self,
+        custom_field_id: &str,
+        body: &crate::types::PatchMcFieldDefinitionsCustomRequest,
+    ) -> Result<crate::types::PatchMcFieldDefinitionsCustomResponseAllOf> {
+        CustomFields::patch_mc_field_definitions_custom(self, custom_field_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct DesignsApi {
     pub client: Client,
@@ -64,7 +62,7 @@ impl DesignsApi {
      *
      * Be sure to check the ID of the design you intend to delete before making this request; deleting a design is a permanent action.
      */
-    pub async fn delete_design(&self, id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_design(&self, id: &str) -> Result<crate::types::DeleteDesignResponse> {
         let url = format!("/designs/{}", crate::progenitor_support::encode_path(id),);
 
         self.client.delete(&url, None).await
@@ -112,24 +110,20 @@ impl DesignsApi {
      *
      * **Parameters:**
      *
-     * * `page_size: u64` -- number of results to return.
-     * * `page_token: &str` -- token corresponding to a specific page of results, as provided by metadata.
-     * * `summary: bool` -- Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * * `params: &DesignsApiListDesignsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_designs(
+    pub async fn list_designs_with(
         &self,
-        page_size: u64,
-        page_token: &str,
-        summary: bool,
+        params: &DesignsApiListDesignsParams,
     ) -> Result<crate::types::ListDesignsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !page_size.to_string().is_empty() {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("page_token".to_string(), page_token.to_string()));
         }
-        if summary {
+        if let Some(summary) = &params.summary {
             query_args.push(("summary".to_string(), summary.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -138,6 +132,24 @@ impl DesignsApi {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_designs_with` and pass the query parameters as a `DesignsApiListDesignsParams` instead.
+     */
+    #[deprecated(note = "use `list_designs_with` instead")]
+    pub async fn list_designs(
+        &self,
+        page_size: u64,
+        page_token: &str,
+        summary: bool,
+    ) -> Result<crate::types::ListDesignsResponse> {
+        let params = DesignsApiListDesignsParams {
+            page_size: Some(page_size).filter(|v| !v.to_string().is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            summary: Some(summary).filter(|v| *v),
+        };
+        self.list_designs_with(&params).await
+    }
+
     /**
      * Create Design.
      *
@@ -226,24 +238,20 @@ impl DesignsApi {
      *
      * **Parameters:**
      *
-     * * `page_size: u64` -- number of results to return.
-     * * `page_token: &str` -- token corresponding to a specific page of results, as provided by metadata.
-     * * `summary: bool` -- Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * * `params: &DesignsApiListSendgridPreBuiltDesignsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_sendgrid_pre_built_designs(
+    pub async fn list_sendgrid_pre_built_designs_with(
         &self,
-        page_size: u64,
-        page_token: &str,
-        summary: bool,
-    ) -> Result<crate::types::ListDesignsResponse> {
+        params: &DesignsApiListSendgridPreBuiltDesignsParams,
+    ) -> Result<crate::types::ListSendgridPreBuiltDesignsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !page_size.to_string().is_empty() {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("page_token".to_string(), page_token.to_string()));
         }
-        if summary {
+        if let Some(summary) = &params.summary {
             query_args.push(("summary".to_string(), summary.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -251,4 +259,198 @@ impl DesignsApi {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `list_sendgrid_pre_built_designs_with` and pass the query parameters as a `DesignsApiListSendgridPreBuiltDesignsParams` instead.
+     */
+    #[deprecated(note = "use `list_sendgrid_pre_built_designs_with` instead")]
+    pub async fn list_sendgrid_pre_built_designs(
+        &self,
+        page_size: u64,
+        page_token: &str,
+        summary: bool,
+    ) -> Result<crate::types::ListSendgridPreBuiltDesignsResponse> {
+        let params = DesignsApiListSendgridPreBuiltDesignsParams {
+            page_size: Some(page_size).filter(|v| !v.to_string().is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            summary: Some(summary).filter(|v| *v),
+        };
+        self.list_sendgrid_pre_built_designs_with(&params).await
+    }
+}
+
+/// The query parameters of `DesignsApi::list_designs_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DesignsApiListDesignsParams {
+    /// number of results to return.
+    pub page_size: Option<u64>,
+    /// token corresponding to a specific page of results, as provided by metadata.
+    pub page_token: Option<String>,
+    /// Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+    pub summary: Option<bool>,
+}
+
+impl DesignsApiListDesignsParams {
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn summary(mut self, summary: bool) -> Self {
+        self.summary = Some(summary);
+        self
+    }
+}
+
+/// The query parameters of `DesignsApi::list_sendgrid_pre_built_designs_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DesignsApiListSendgridPreBuiltDesignsParams {
+    /// number of results to return.
+    pub page_size: Option<u64>,
+    /// token corresponding to a specific page of results, as provided by metadata.
+    pub page_token: Option<String>,
+    /// Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+    pub summary: Option<bool>,
+}
+
+impl DesignsApiListSendgridPreBuiltDesignsParams {
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn summary(mut self, summary: bool) -> Self {
+        self.summary = Some(summary);
+        self
+    }
+}
+
+/// The functions of [`DesignsApi`], for code that takes them as a trait, so that it can be
+/// tested with the `MockDesignsApiApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait DesignsApiApi: Send + Sync {
+    /// See [`DesignsApi::get_design`].
+    async fn get_design(&self, id: &str) -> Result<crate::types::DesignOutputAllOf>;
+
+    /// See [`DesignsApi::post_duplicate_design`].
+    async fn post_duplicate_design(
+        &self,
+        id: &str,
+        body: &crate::types::DesignDuplicateInput,
+    ) -> Result<crate::types::DesignOutputAllOf>;
+
+    /// See [`DesignsApi::delete_design`].
+    async fn delete_design(&self, id: &str) -> Result<crate::types::DeleteDesignResponse>;
+
+    /// See [`DesignsApi::put_design`].
+    async fn put_design(
+        &self,
+        id: &str,
+        body: &crate::types::PutDesignRequest,
+    ) -> Result<crate::types::DesignOutputAllOf>;
+
+    /// See [`DesignsApi::list_designs_with`].
+    async fn list_designs_with(
+        &self,
+        params: &DesignsApiListDesignsParams,
+    ) -> Result<crate::types::ListDesignsResponse>;
+
+    /// See [`DesignsApi::post_design`].
+    async fn post_design(
+        &self,
+        body: &crate::types::DesignInputAllOf,
+    ) -> Result<crate::types::DesignOutputAllOf>;
+
+    /// See [`DesignsApi::get_sendgrid_pre_built_design`].
+    async fn get_sendgrid_pre_built_design(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::DesignOutputAllOf>;
+
+    /// See [`DesignsApi::post_sendgrid_pre_built_design`].
+    async fn post_sendgrid_pre_built_design(
+        &self,
+        id: &str,
+        body: &crate::types::DesignDuplicateInput,
+    ) -> Result<crate::types::DesignOutputAllOf>;
+
+    /// See [`DesignsApi::list_sendgrid_pre_built_designs_with`].
+    async fn list_sendgrid_pre_built_designs_with(
+        &self,
+        params: &DesignsApiListSendgridPreBuiltDesignsParams,
+    ) -> Result<crate::types::ListSendgridPreBuiltDesignsResponse>;
+}
+
+#[async_trait::async_trait]
+impl DesignsApiApi for DesignsApi {
+    async fn get_design(&self, id: &str) -> Result<crate::types::DesignOutputAllOf> {
+        DesignsApi::get_design(self, id).await
+    }
+
+    async fn post_duplicate_design(
+        &self,
+        id: &str,
+        body: &crate::types::DesignDuplicateInput,
+    ) -> Result<crate::types::DesignOutputAllOf> {
+        DesignsApi::post_duplicate_design(self, id, body).await
+    }
+
+    async fn delete_design(&self, id: &str) -> Result<crate::types::DeleteDesignResponse> {
+        DesignsApi::delete_design(self, id).await
+    }
+
+    async fn put_design(
+        &self,
+        id: &str,
+        body: &crate::types::PutDesignRequest,
+    ) -> Result<crate::types::DesignOutputAllOf> {
+        DesignsApi::put_design(self, id, body).await
+    }
+
+    async fn list_designs_with(
+        &self,
+        params: &DesignsApiListDesignsParams,
+    ) -> Result<crate::types::ListDesignsResponse> {
+        DesignsApi::list_designs_with(self, params).await
+    }
+
+    async fn post_design(
+        &self,
+        body: &crate::types::DesignInputAllOf,
+    ) -> Result<crate::types::DesignOutputAllOf> {
+        DesignsApi::post_design(self, body).await
+    }
+
+    async fn get_sendgrid_pre_built_design(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::DesignOutputAllOf> {
+        DesignsApi::get_sendgrid_pre_built_design(self, id).await
+    }
+
+    async fn post_sendgrid_pre_built_design(
+        &self,
+        id: &str,
+        body: &crate::types::DesignDuplicateInput,
+    ) -> Result<crate::types::DesignOutputAllOf> {
+        DesignsApi::post_sendgrid_pre_built_design(self, id, body).await
+    }
+
+    async fn list_sendgrid_pre_built_designs_with(
+        &self,
+        params: &DesignsApiListSendgridPreBuiltDesignsParams,
+    ) -> Result<crate::types::ListSendgridPreBuiltDesignsResponse> {
+        DesignsApi::list_sendgrid_pre_built_designs_with(self, params).await
+    }
 }
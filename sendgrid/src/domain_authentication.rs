@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct DomainAuthentication {
     pub client: Client,
@@ -21,35 +19,26 @@ impl DomainAuthentication {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- Number of domains to return.
-     * * `offset: i64` -- Paging offset.
-     * * `exclude_subusers: bool` -- Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     * * `username: &str` -- The license key provided with your New Relic account.
-     * * `domain: &str` -- The license key provided with your New Relic account.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &DomainAuthenticationGetWhitelabelDomainsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_whitelabel_domains(
+    pub async fn get_whitelabel_domains_with(
         &self,
-        limit: i64,
-        offset: i64,
-        exclude_subusers: bool,
-        username: &str,
-        domain: &str,
+        params: &DomainAuthenticationGetWhitelabelDomainsParams,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !domain.is_empty() {
+        if let Some(domain) = &params.domain {
             query_args.push(("domain".to_string(), domain.to_string()));
         }
-        if exclude_subusers {
+        if let Some(exclude_subusers) = &params.exclude_subusers {
             query_args.push(("exclude_subusers".to_string(), exclude_subusers.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !username.is_empty() {
+        if let Some(username) = &params.username {
             query_args.push(("username".to_string(), username.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -58,6 +47,28 @@ impl DomainAuthentication {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_whitelabel_domains_with` and pass the query parameters as a `DomainAuthenticationGetWhitelabelDomainsParams` instead.
+     */
+    #[deprecated(note = "use `get_whitelabel_domains_with` instead")]
+    pub async fn get_whitelabel_domains(
+        &self,
+        limit: i64,
+        offset: i64,
+        exclude_subusers: bool,
+        username: &str,
+        domain: &str,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        let params = DomainAuthenticationGetWhitelabelDomainsParams {
+            domain: Some(domain.to_string()).filter(|s| !s.is_empty()),
+            exclude_subusers: Some(exclude_subusers).filter(|v| *v),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            username: Some(username.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_whitelabel_domains_with(&params).await
+    }
+
     /**
      * List all authenticated domains.
      *
@@ -66,7 +77,38 @@ impl DomainAuthentication {
      * As opposed to `get_whitelabel_domains`, this function returns all the pages of the request at once.
      *
      * **This endpoint allows you to retrieve a list of all domains you have authenticated.**
+     *
+     * **Parameters:**
+     *
+     * * `params: &DomainAuthenticationGetWhitelabelDomainsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_whitelabel_domains_with(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsParams,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(domain) = &params.domain {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        if let Some(exclude_subusers) = &params.exclude_subusers {
+            query_args.push(("exclude_subusers".to_string(), exclude_subusers.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(username) = &params.username {
+            query_args.push(("username".to_string(), username.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/whitelabel/domains?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_whitelabel_domains_with` and pass the query parameters as a `DomainAuthenticationGetWhitelabelDomainsParams` instead.
      */
+    #[deprecated(note = "use `get_all_whitelabel_domains_with` instead")]
     pub async fn get_all_whitelabel_domains(
         &self,
         offset: i64,
@@ -74,23 +116,63 @@ impl DomainAuthentication {
         username: &str,
         domain: &str,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        let params = DomainAuthenticationGetWhitelabelDomainsParams {
+            domain: Some(domain.to_string()).filter(|s| !s.is_empty()),
+            exclude_subusers: Some(exclude_subusers).filter(|v| *v),
+            offset: Some(offset).filter(|v| *v > 0),
+            username: Some(username.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_whitelabel_domains_with(&params).await
+    }
+
+    /**
+     * List all authenticated domains.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_domains`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve a list of all domains you have authenticated.**
+     */
+    pub fn get_whitelabel_domains_stream(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DomainAuthentication200ResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !domain.is_empty() {
+        if let Some(domain) = &params.domain {
             query_args.push(("domain".to_string(), domain.to_string()));
         }
-        if exclude_subusers {
+        if let Some(exclude_subusers) = &params.exclude_subusers {
             query_args.push(("exclude_subusers".to_string(), exclude_subusers.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !username.is_empty() {
+        if let Some(username) = &params.username {
             query_args.push(("username".to_string(), username.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/whitelabel/domains?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -155,7 +237,7 @@ impl DomainAuthentication {
     pub async fn delete_whitelabel_domains_domain(
         &self,
         domain_id: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteWhitelabelDomainsDomainResponse> {
         let url = format!(
             "/whitelabel/domains/{}",
             crate::progenitor_support::encode_path(domain_id),
@@ -203,15 +285,14 @@ impl DomainAuthentication {
      *
      * **Parameters:**
      *
-     * * `domain: &str` -- The license key provided with your New Relic account.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &DomainAuthenticationGetWhitelabelDomainsDefaultParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_whitelabel_domains_default(
+    pub async fn get_whitelabel_domains_default_with(
         &self,
-        domain: &str,
+        params: &DomainAuthenticationGetWhitelabelDomainsDefaultParams,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !domain.is_empty() {
+        if let Some(domain) = &params.domain {
             query_args.push(("domain".to_string(), domain.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -220,6 +301,20 @@ impl DomainAuthentication {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_whitelabel_domains_default_with` and pass the query parameters as a `DomainAuthenticationGetWhitelabelDomainsDefaultParams` instead.
+     */
+    #[deprecated(note = "use `get_whitelabel_domains_default_with` instead")]
+    pub async fn get_whitelabel_domains_default(
+        &self,
+        domain: &str,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        let params = DomainAuthenticationGetWhitelabelDomainsDefaultParams {
+            domain: Some(domain.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_whitelabel_domains_default_with(&params).await
+    }
+
     /**
      * Get the default authentication.
      *
@@ -232,19 +327,81 @@ impl DomainAuthentication {
      * When creating or updating a domain authentication, you can set the domain as a default. The default domain will be used to send all mail. If you have multiple authenticated domains, the authenticated domain matching the domain of the From address will be used, and the default will be overridden.
      *
      * This endpoint will return a default domain and its details only if a default is set. You are not required to set a default. If you do not set a default domain, this endpoint will return general information about your domain authentication status.
+     *
+     * **Parameters:**
+     *
+     * * `params: &DomainAuthenticationGetWhitelabelDomainsDefaultParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_whitelabel_domains_default_with(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsDefaultParams,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(domain) = &params.domain {
+            query_args.push(("domain".to_string(), domain.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/whitelabel/domains/default?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_whitelabel_domains_default_with` and pass the query parameters as a `DomainAuthenticationGetWhitelabelDomainsDefaultParams` instead.
      */
+    #[deprecated(note = "use `get_all_whitelabel_domains_default_with` instead")]
     pub async fn get_all_whitelabel_domains_default(
         &self,
         domain: &str,
     ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        let params = DomainAuthenticationGetWhitelabelDomainsDefaultParams {
+            domain: Some(domain.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_all_whitelabel_domains_default_with(&params).await
+    }
+
+    /**
+     * Get the default authentication.
+     *
+     * This function performs a `GET` to the `/whitelabel/domains/default` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_domains_default`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve the default authentication for a domain.**
+     *
+     * When creating or updating a domain authentication, you can set the domain as a default. The default domain will be used to send all mail. If you have multiple authenticated domains, the authenticated domain matching the domain of the From address will be used, and the default will be overridden.
+     *
+     * This endpoint will return a default domain and its details only if a default is set. You are not required to set a default. If you do not set a default domain, this endpoint will return general information about your domain authentication status.
+     */
+    pub fn get_whitelabel_domains_default_stream(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsDefaultParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DomainAuthentication200ResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !domain.is_empty() {
+        if let Some(domain) = &params.domain {
             query_args.push(("domain".to_string(), domain.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/whitelabel/domains/default?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -261,8 +418,8 @@ impl DomainAuthentication {
     pub async fn post_whitelabel_domains_ip(
         &self,
         id: i64,
-        body: &crate::types::Ips,
-    ) -> Result<crate::types::DomainAuthentication> {
+        body: &crate::types::PostWhitelabelDomainsIpsRequest,
+    ) -> Result<crate::types::DomainAuthenticationSpf> {
         let url = format!(
             "/whitelabel/domains/{}/ips",
             crate::progenitor_support::encode_path(&id.to_string()),
@@ -288,7 +445,7 @@ impl DomainAuthentication {
         &self,
         id: i64,
         ip: &str,
-    ) -> Result<crate::types::DomainAuthentication> {
+    ) -> Result<crate::types::DomainAuthenticationSpf> {
         let url = format!(
             "/whitelabel/domains/{}/ips/{}",
             crate::progenitor_support::encode_path(&id.to_string()),
@@ -332,14 +489,14 @@ impl DomainAuthentication {
      *
      * **Parameters:**
      *
-     * * `username: &str` -- Username for the subuser to find associated authenticated domain.
+     * * `params: &DomainAuthenticationGetWhitelabelDomainsSubuserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_whitelabel_domains_subuser(
+    pub async fn get_whitelabel_domains_subuser_with(
         &self,
-        username: &str,
-    ) -> Result<crate::types::DomainAuthentication> {
+        params: &DomainAuthenticationGetWhitelabelDomainsSubuserParams,
+    ) -> Result<crate::types::DomainAuthenticationSpf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !username.is_empty() {
+        if let Some(username) = &params.username {
             query_args.push(("username".to_string(), username.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -348,6 +505,20 @@ impl DomainAuthentication {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_whitelabel_domains_subuser_with` and pass the query parameters as a `DomainAuthenticationGetWhitelabelDomainsSubuserParams` instead.
+     */
+    #[deprecated(note = "use `get_whitelabel_domains_subuser_with` instead")]
+    pub async fn get_whitelabel_domains_subuser(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::DomainAuthenticationSpf> {
+        let params = DomainAuthenticationGetWhitelabelDomainsSubuserParams {
+            username: Some(username.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_whitelabel_domains_subuser_with(&params).await
+    }
+
     /**
      * Disassociate an authenticated domain from a given user.
      *
@@ -359,14 +530,14 @@ impl DomainAuthentication {
      *
      * **Parameters:**
      *
-     * * `username: &str` -- Username for the subuser to find associated authenticated domain.
+     * * `params: &DomainAuthenticationDeleteWhitelabelDomainsSubuserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_whitelabel_domains_subuser(
+    pub async fn delete_whitelabel_domains_subuser_with(
         &self,
-        username: &str,
-    ) -> Result<crate::types::Help> {
+        params: &DomainAuthenticationDeleteWhitelabelDomainsSubuserParams,
+    ) -> Result<crate::types::DeleteWhitelabelDomainsSubuserResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !username.is_empty() {
+        if let Some(username) = &params.username {
             query_args.push(("username".to_string(), username.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -375,6 +546,20 @@ impl DomainAuthentication {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_whitelabel_domains_subuser_with` and pass the query parameters as a `DomainAuthenticationDeleteWhitelabelDomainsSubuserParams` instead.
+     */
+    #[deprecated(note = "use `delete_whitelabel_domains_subuser_with` instead")]
+    pub async fn delete_whitelabel_domains_subuser(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::DeleteWhitelabelDomainsSubuserResponse> {
+        let params = DomainAuthenticationDeleteWhitelabelDomainsSubuserParams {
+            username: Some(username.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_whitelabel_domains_subuser_with(&params).await
+    }
+
     /**
      * Associate a authenticated domain with a given user.
      *
@@ -387,8 +572,8 @@ impl DomainAuthentication {
     pub async fn post_whitelabel_domains_domain_subuser(
         &self,
         domain_id: i64,
-        body: &crate::types::PutUserUsernameResponse,
-    ) -> Result<crate::types::DomainAuthentication> {
+        body: &crate::types::PostWhitelabelDomainsDomainSubuserRequest,
+    ) -> Result<crate::types::DomainAuthenticationSpf> {
         let url = format!(
             "/whitelabel/domains/{}/subuser",
             crate::progenitor_support::encode_path(&domain_id.to_string()),
@@ -399,3 +584,320 @@ impl DomainAuthentication {
             .await
     }
 }
+
+/// The query parameters of `DomainAuthentication::get_whitelabel_domains_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DomainAuthenticationGetWhitelabelDomainsParams {
+    /// The license key provided with your New Relic account.
+    pub domain: Option<String>,
+    /// Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+    pub exclude_subusers: Option<bool>,
+    /// Number of domains to return.
+    pub limit: Option<i64>,
+    /// Paging offset.
+    pub offset: Option<i64>,
+    /// The license key provided with your New Relic account.
+    pub username: Option<String>,
+}
+
+impl DomainAuthenticationGetWhitelabelDomainsParams {
+    pub fn domain<T: ToString>(mut self, domain: T) -> Self {
+        self.domain = Some(domain.to_string());
+        self
+    }
+
+    pub fn exclude_subusers(mut self, exclude_subusers: bool) -> Self {
+        self.exclude_subusers = Some(exclude_subusers);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn username<T: ToString>(mut self, username: T) -> Self {
+        self.username = Some(username.to_string());
+        self
+    }
+}
+
+/// The query parameters of `DomainAuthentication::get_whitelabel_domains_default_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DomainAuthenticationGetWhitelabelDomainsDefaultParams {
+    /// The license key provided with your New Relic account.
+    pub domain: Option<String>,
+}
+
+impl DomainAuthenticationGetWhitelabelDomainsDefaultParams {
+    pub fn domain<T: ToString>(mut self, domain: T) -> Self {
+        self.domain = Some(domain.to_string());
+        self
+    }
+}
+
+/// The query parameters of `DomainAuthentication::get_whitelabel_domains_subuser_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DomainAuthenticationGetWhitelabelDomainsSubuserParams {
+    /// Username for the subuser to find associated authenticated domain.
+    pub username: Option<String>,
+}
+
+impl DomainAuthenticationGetWhitelabelDomainsSubuserParams {
+    pub fn username<T: ToString>(mut self, username: T) -> Self {
+        self.username = Some(username.to_string());
+        self
+    }
+}
+
+/// The query parameters of `DomainAuthentication::delete_whitelabel_domains_subuser_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DomainAuthenticationDeleteWhitelabelDomainsSubuserParams {
+    /// Username for the subuser to find associated authenticated domain.
+    pub username: Option<String>,
+}
+
+impl DomainAuthenticationDeleteWhitelabelDomainsSubuserParams {
+    pub fn username<T: ToString>(mut self, username: T) -> Self {
+        self.username = Some(username.to_string());
+        self
+    }
+}
+
+/// The functions of [`DomainAuthentication`], for code that takes them as a trait, so that it can be
+/// tested with the `MockDomainAuthenticationApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait DomainAuthenticationApi: Send + Sync {
+    /// See [`DomainAuthentication::get_whitelabel_domains_with`].
+    async fn get_whitelabel_domains_with(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsParams,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>>;
+
+    /// See [`DomainAuthentication::get_all_whitelabel_domains_with`].
+    async fn get_all_whitelabel_domains_with(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsParams,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>>;
+
+    /// See [`DomainAuthentication::get_whitelabel_domains_stream`].
+    fn get_whitelabel_domains_stream(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DomainAuthentication200ResponseAllOf>;
+
+    /// See [`DomainAuthentication::post_whitelabel_domain`].
+    async fn post_whitelabel_domain(
+        &self,
+        body: &crate::types::PostWhitelabelDomainsRequest,
+    ) -> Result<crate::types::AuthenticationDomain>;
+
+    /// See [`DomainAuthentication::get_whitelabel_domains_domain`].
+    async fn get_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+    ) -> Result<crate::types::AuthenticationDomain>;
+
+    /// See [`DomainAuthentication::delete_whitelabel_domains_domain`].
+    async fn delete_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+    ) -> Result<crate::types::DeleteWhitelabelDomainsDomainResponse>;
+
+    /// See [`DomainAuthentication::patch_whitelabel_domains_domain`].
+    async fn patch_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+        body: &crate::types::PatchWhitelabelDomainsDomainRequest,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>>;
+
+    /// See [`DomainAuthentication::get_whitelabel_domains_default_with`].
+    async fn get_whitelabel_domains_default_with(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsDefaultParams,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>>;
+
+    /// See [`DomainAuthentication::get_all_whitelabel_domains_default_with`].
+    async fn get_all_whitelabel_domains_default_with(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsDefaultParams,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>>;
+
+    /// See [`DomainAuthentication::get_whitelabel_domains_default_stream`].
+    fn get_whitelabel_domains_default_stream(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsDefaultParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DomainAuthentication200ResponseAllOf>;
+
+    /// See [`DomainAuthentication::post_whitelabel_domains_ip`].
+    async fn post_whitelabel_domains_ip(
+        &self,
+        id: i64,
+        body: &crate::types::PostWhitelabelDomainsIpsRequest,
+    ) -> Result<crate::types::DomainAuthenticationSpf>;
+
+    /// See [`DomainAuthentication::delete_whitelabel_domains_ips_ip`].
+    async fn delete_whitelabel_domains_ips_ip(
+        &self,
+        id: i64,
+        ip: &str,
+    ) -> Result<crate::types::DomainAuthenticationSpf>;
+
+    /// See [`DomainAuthentication::post_whitelabel_domains_validate`].
+    async fn post_whitelabel_domains_validate(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::PostWhitelabelDomainsValidateResponse>;
+
+    /// See [`DomainAuthentication::get_whitelabel_domains_subuser_with`].
+    async fn get_whitelabel_domains_subuser_with(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsSubuserParams,
+    ) -> Result<crate::types::DomainAuthenticationSpf>;
+
+    /// See [`DomainAuthentication::delete_whitelabel_domains_subuser_with`].
+    async fn delete_whitelabel_domains_subuser_with(
+        &self,
+        params: &DomainAuthenticationDeleteWhitelabelDomainsSubuserParams,
+    ) -> Result<crate::types::DeleteWhitelabelDomainsSubuserResponse>;
+
+    /// See [`DomainAuthentication::post_whitelabel_domains_domain_subuser`].
+    async fn post_whitelabel_domains_domain_subuser(
+        &self,
+        domain_id: i64,
+        body: &crate::types::PostWhitelabelDomainsDomainSubuserRequest,
+    ) -> Result<crate::types::DomainAuthenticationSpf>;
+}
+
+#[async_trait::async_trait]
+impl DomainAuthenticationApi for DomainAuthentication {
+    async fn get_whitelabel_domains_with(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsParams,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        DomainAuthentication::get_whitelabel_domains_with(self, params).await
+    }
+
+    async fn get_all_whitelabel_domains_with(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsParams,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        DomainAuthentication::get_all_whitelabel_domains_with(self, params).await
+    }
+
+    fn get_whitelabel_domains_stream(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DomainAuthentication200ResponseAllOf> {
+        DomainAuthentication::get_whitelabel_domains_stream(self, params, start, max_items)
+    }
+
+    async fn post_whitelabel_domain(
+        &self,
+        body: &crate::types::PostWhitelabelDomainsRequest,
+    ) -> Result<crate::types::AuthenticationDomain> {
+        DomainAuthentication::post_whitelabel_domain(self, body).await
+    }
+
+    async fn get_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+    ) -> Result<crate::types::AuthenticationDomain> {
+        DomainAuthentication::get_whitelabel_domains_domain(self, domain_id).await
+    }
+
+    async fn delete_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+    ) -> Result<crate::types::DeleteWhitelabelDomainsDomainResponse> {
+        DomainAuthentication::delete_whitelabel_domains_domain(self, domain_id).await
+    }
+
+    async fn patch_whitelabel_domains_domain(
+        &self,
+        domain_id: &str,
+        body: &crate::types::PatchWhitelabelDomainsDomainRequest,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        DomainAuthentication::patch_whitelabel_domains_domain(self, domain_id, body).await
+    }
+
+    async fn get_whitelabel_domains_default_with(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsDefaultParams,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        DomainAuthentication::get_whitelabel_domains_default_with(self, params).await
+    }
+
+    async fn get_all_whitelabel_domains_default_with(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsDefaultParams,
+    ) -> Result<Vec<crate::types::DomainAuthentication200ResponseAllOf>> {
+        DomainAuthentication::get_all_whitelabel_domains_default_with(self, params).await
+    }
+
+    fn get_whitelabel_domains_default_stream(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsDefaultParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DomainAuthentication200ResponseAllOf> {
+        DomainAuthentication::get_whitelabel_domains_default_stream(self, params, start, max_items)
+    }
+
+    async fn post_whitelabel_domains_ip(
+        &self,
+        id: i64,
+        body: &crate::types::PostWhitelabelDomainsIpsRequest,
+    ) -> Result<crate::types::DomainAuthenticationSpf> {
+        DomainAuthentication::post_whitelabel_domains_ip(self, id, body).await
+    }
+
+    async fn delete_whitelabel_domains_ips_ip(
+        &self,
+        id: i64,
+        ip: &str,
+    ) -> Result<crate::types::DomainAuthenticationSpf> {
+        DomainAuthentication::delete_whitelabel_domains_ips_ip(self, id, ip).await
+    }
+
+    async fn post_whitelabel_domains_validate(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::PostWhitelabelDomainsValidateResponse> {
+        DomainAuthentication::post_whitelabel_domains_validate(self, id).await
+    }
+
+    async fn get_whitelabel_domains_subuser_with(
+        &self,
+        params: &DomainAuthenticationGetWhitelabelDomainsSubuserParams,
+    ) -> Result<crate::types::DomainAuthenticationSpf> {
+        DomainAuthentication::get_whitelabel_domains_subuser_with(self, params).await
+    }
+
+    async fn delete_whitelabel_domains_subuser_with(
+        &self,
+        params: &DomainAuthenticationDeleteWhitelabelDomainsSubuserParams,
+    ) -> Result<crate::types::DeleteWhitelabelDomainsSubuserResponse> {
+        DomainAuthentication::delete_whitelabel_domains_subuser_with(self, params).await
+    }
+
+    async fn post_whitelabel_domains_domain_subuser(
+        &self,
+        domain_id: i64,
+        body: &crate::types::PostWhitelabelDomainsDomainSubuserRequest,
+    ) -> Result<crate::types::DomainAuthenticationSpf> {
+        DomainAuthentication::post_whitelabel_domains_domain_subuser(self, domain_id, body).await
+    }
+}
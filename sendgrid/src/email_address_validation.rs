@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EmailAddressValidation {
     pub client: Client,
@@ -29,3 +27,25 @@ impl EmailAddressValidation {
             .await
     }
 }
+
+/// The functions of [`EmailAddressValidation`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEmailAddressValidationApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EmailAddressValidationApi: Send + Sync {
+    /// See [`EmailAddressValidation::post_validations_email`].
+    async fn post_validations_email(
+        &self,
+        body: &crate::types::PostValidationsEmailRequest,
+    ) -> Result<crate::types::PostValidationsEmailResponse>;
+}
+
+#[async_trait::async_trait]
+impl EmailAddressValidationApi for EmailAddressValidation {
+    async fn post_validations_email(
+        &self,
+        body: &crate::types::PostValidationsEmailRequest,
+    ) -> Result<crate::types::PostValidationsEmailResponse> {
+        EmailAddressValidation::post_validations_email(self, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EmailCnameRecords {
     pub client: Client,
@@ -37,3 +35,25 @@ impl EmailCnameRecords {
             .await
     }
 }
+
+/// The functions of [`EmailCnameRecords`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEmailCnameRecordsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EmailCnameRecordsApi: Send + Sync {
+    /// See [`EmailCnameRecords::post_whitelabel_dns_email`].
+    async fn post_whitelabel_dns_email(
+        &self,
+        body: &crate::types::PostWhitelabelDnsEmailRequest,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl EmailCnameRecordsApi for EmailCnameRecords {
+    async fn post_whitelabel_dns_email(
+        &self,
+        body: &crate::types::PostWhitelabelDnsEmailRequest,
+    ) -> Result<()> {
+        EmailCnameRecords::post_whitelabel_dns_email(self, body).await
+    }
+}
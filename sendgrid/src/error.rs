@@ -0,0 +1,170 @@
+use std::{fmt, time::Duration};
+
+/// The body of an unsuccessful response, parsed into the error schema of the API.
+pub type ErrorBody = crate::types::GlobalErrorResponseSchema;
+
+/// Errors returned by the client.
+#[derive(Debug)]
+pub enum Error {
+    /// The server responded with an unsuccessful status code.
+    Http {
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        /// The raw body of the response.
+        body: String,
+        /// The body of the response, if it could be parsed into an `ErrorBody`.
+        error: Option<ErrorBody>,
+    },
+    /// The server responded with `429 Too Many Requests` or the rate limit is exhausted.
+    RateLimited {
+        /// How long to wait before retrying, if the server said so.
+        reset: Option<Duration>,
+    },
+    /// The response body could not be deserialized into the expected type.
+    Decode {
+        /// The path to the field that failed to deserialize, e.g. `items[0].id`.
+        path: String,
+        source: serde_json::Error,
+    },
+    /// Getting or refreshing an access token failed.
+    Auth(String),
+    /// The request could not be sent or the response could not be read.
+    Transport(reqwest_middleware::Error),
+    /// The request could not be built, e.g. because of an invalid URL or header.
+    InvalidRequest(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    /// Create an error from an unsuccessful response.
+    pub(crate) fn from_response(
+        status: http::StatusCode,
+        headers: reqwest::header::HeaderMap,
+        body: &[u8],
+    ) -> Self {
+        if status == http::StatusCode::TOO_MANY_REQUESTS {
+            let reset = headers
+                .get(http::header::RETRY_AFTER)
+                .and_then(|h| h.to_str().ok())
+                .and_then(crate::rate_limiter::retry_after)
+                .map(|t| {
+                    t.duration_since(std::time::SystemTime::now())
+                        .unwrap_or_default()
+                });
+            return Error::RateLimited { reset };
+        }
+
+        Error::Http {
+            status,
+            headers,
+            body: String::from_utf8_lossy(body).to_string(),
+            error: serde_json::from_slice(body).ok(),
+        }
+    }
+
+    /// Returns the status code of the response, if the server sent one.
+    pub fn status(&self) -> Option<http::StatusCode> {
+        match self {
+            Error::Http { status, .. } => Some(*status),
+            Error::RateLimited { .. } => Some(http::StatusCode::TOO_MANY_REQUESTS),
+            Error::Transport(reqwest_middleware::Error::Reqwest(e)) => e.status(),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Http { status, body, .. } => {
+                if body.is_empty() {
+                    write!(f, "code: {}, empty response", status)
+                } else {
+                    write!(f, "code: {}, error: {:?}", status, body)
+                }
+            }
+            Error::RateLimited { reset: Some(reset) } => write!(
+                f,
+                "rate limit exceeded, will reset in {} seconds",
+                reset.as_secs()
+            ),
+            Error::RateLimited { reset: None } => write!(f, "rate limit exceeded"),
+            Error::Decode { path, source } => {
+                write!(f, "failed to decode response at `{}`: {}", path, source)
+            }
+            Error::Auth(e) => write!(f, "authentication failed: {}", e),
+            Error::Transport(e) => write!(f, "{}", e),
+            Error::InvalidRequest(e) => write!(f, "invalid request: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Decode { source, .. } => Some(source),
+            Error::Transport(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest_middleware::Error> for Error {
+    fn from(e: reqwest_middleware::Error) -> Self {
+        // The rate limiter doesn't send requests it would have to wait too long for.
+        if let reqwest_middleware::Error::Middleware(m) = &e {
+            if let Some(e) = m.downcast_ref::<crate::rate_limiter::Exceeded>() {
+                return Error::RateLimited {
+                    reset: Some(e.reset),
+                };
+            }
+        }
+        Error::Transport(e)
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Transport(reqwest_middleware::Error::Reqwest(e))
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::Decode {
+            path: String::new(),
+            source: e,
+        }
+    }
+}
+
+impl From<url::ParseError> for Error {
+    fn from(e: url::ParseError) -> Self {
+        Error::InvalidRequest(e.to_string())
+    }
+}
+
+impl From<reqwest::header::InvalidHeaderValue> for Error {
+    fn from(e: reqwest::header::InvalidHeaderValue) -> Self {
+        Error::InvalidRequest(e.to_string())
+    }
+}
+
+impl From<serde_urlencoded::ser::Error> for Error {
+    fn from(e: serde_urlencoded::ser::Error) -> Self {
+        Error::InvalidRequest(e.to_string())
+    }
+}
+
+/// Deserialize a response body, keeping track of the path to the field that failed.
+pub(crate) fn decode<Out>(body: &[u8]) -> Result<Out>
+where
+    Out: serde::de::DeserializeOwned,
+{
+    let de = &mut serde_json::Deserializer::from_slice(body);
+    serde_path_to_error::deserialize(de).map_err(|e| Error::Decode {
+        path: e.path().to_string(),
+        source: e.into_inner(),
+    })
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct InvalidEmailsApi {
     pub client: Client,
@@ -21,30 +19,23 @@ impl InvalidEmailsApi {
      *
      * **Parameters:**
      *
-     * * `start_time: i64` -- Refers start of the time range in unix timestamp when an invalid email was created (inclusive).
-     * * `end_time: i64` -- Refers end of the time range in unix timestamp when an invalid email was created (inclusive).
-     * * `limit: i64` -- Limit the number of results to be displayed per page.
-     * * `offset: i64` -- Paging offset. The point in the list to begin displaying results.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &InvalidEmailsApiGetSuppressionInvalidEmailsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_suppression_invalid_emails(
+    pub async fn get_suppression_invalid_emails_with(
         &self,
-        start_time: i64,
-        end_time: i64,
-        limit: i64,
-        offset: i64,
+        params: &InvalidEmailsApiGetSuppressionInvalidEmailsParams,
     ) -> Result<Vec<crate::types::InvalidEmail>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if end_time > 0 {
+        if let Some(end_time) = &params.end_time {
             query_args.push(("end_time".to_string(), end_time.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if start_time > 0 {
+        if let Some(start_time) = &params.start_time {
             query_args.push(("start_time".to_string(), start_time.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -53,6 +44,26 @@ impl InvalidEmailsApi {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_suppression_invalid_emails_with` and pass the query parameters as a `InvalidEmailsApiGetSuppressionInvalidEmailsParams` instead.
+     */
+    #[deprecated(note = "use `get_suppression_invalid_emails_with` instead")]
+    pub async fn get_suppression_invalid_emails(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::InvalidEmail>> {
+        let params = InvalidEmailsApiGetSuppressionInvalidEmailsParams {
+            end_time: Some(end_time).filter(|v| *v > 0),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_time: Some(start_time).filter(|v| *v > 0),
+        };
+        self.get_suppression_invalid_emails_with(&params).await
+    }
+
     /**
      * Retrieve all invalid emails.
      *
@@ -61,27 +72,94 @@ impl InvalidEmailsApi {
      * As opposed to `get_suppression_invalid_emails`, this function returns all the pages of the request at once.
      *
      * **This endpoint allows you to retrieve a list of all invalid email addresses.**
+     *
+     * **Parameters:**
+     *
+     * * `params: &InvalidEmailsApiGetSuppressionInvalidEmailsParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_suppression_invalid_emails_with(
+        &self,
+        params: &InvalidEmailsApiGetSuppressionInvalidEmailsParams,
+    ) -> Result<Vec<crate::types::InvalidEmail>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(end_time) = &params.end_time {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(start_time) = &params.start_time {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/suppression/invalid_emails?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_suppression_invalid_emails_with` and pass the query parameters as a `InvalidEmailsApiGetSuppressionInvalidEmailsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_suppression_invalid_emails_with` instead")]
     pub async fn get_all_suppression_invalid_emails(
         &self,
         start_time: i64,
         end_time: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::InvalidEmail>> {
+        let params = InvalidEmailsApiGetSuppressionInvalidEmailsParams {
+            end_time: Some(end_time).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_time: Some(start_time).filter(|v| *v > 0),
+            ..Default::default()
+        };
+        self.get_all_suppression_invalid_emails_with(&params).await
+    }
+
+    /**
+     * Retrieve all invalid emails.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails` endpoint.
+     *
+     * As opposed to `get_all_suppression_invalid_emails`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve a list of all invalid email addresses.**
+     */
+    pub fn get_suppression_invalid_emails_stream(
+        &self,
+        params: &InvalidEmailsApiGetSuppressionInvalidEmailsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::InvalidEmail> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if end_time > 0 {
+        if let Some(end_time) = &params.end_time {
             query_args.push(("end_time".to_string(), end_time.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if start_time > 0 {
+        if let Some(start_time) = &params.start_time {
             query_args.push(("start_time".to_string(), start_time.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/suppression/invalid_emails?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -102,8 +180,8 @@ impl InvalidEmailsApi {
      */
     pub async fn delete_suppression_invalid_emails(
         &self,
-        body: &crate::types::DeleteSuppressionBlocksRequest,
-    ) -> Result<crate::types::Help> {
+        body: &crate::types::DeleteSuppressionInvalidEmailsRequest,
+    ) -> Result<crate::types::DeleteSuppressionInvalidEmailsResponse> {
         let url = "/suppression/invalid_emails".to_string();
         self.client
             .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -154,6 +232,44 @@ impl InvalidEmailsApi {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve a specific invalid email.
+     *
+     * This function performs a `GET` to the `/suppression/invalid_emails/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_invalid_emails_email`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve a specific invalid email addresses.**
+     */
+    pub fn get_suppression_invalid_emails_email_stream(
+        &self,
+        email: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::InvalidEmail> {
+        let url = format!(
+            "/suppression/invalid_emails/{}",
+            crate::progenitor_support::encode_path(email),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Delete a specific invalid email.
      *
@@ -168,7 +284,7 @@ impl InvalidEmailsApi {
     pub async fn delete_suppression_invalid_emails_email(
         &self,
         email: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSuppressionInvalidEmailsEmailResponse> {
         let url = format!(
             "/suppression/invalid_emails/{}",
             crate::progenitor_support::encode_path(email),
@@ -177,3 +293,159 @@ impl InvalidEmailsApi {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `InvalidEmailsApi::get_suppression_invalid_emails_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InvalidEmailsApiGetSuppressionInvalidEmailsParams {
+    /// Refers end of the time range in unix timestamp when an invalid email was created (inclusive).
+    pub end_time: Option<i64>,
+    /// Limit the number of results to be displayed per page.
+    pub limit: Option<i64>,
+    /// Paging offset. The point in the list to begin displaying results.
+    pub offset: Option<i64>,
+    /// Refers start of the time range in unix timestamp when an invalid email was created (inclusive).
+    pub start_time: Option<i64>,
+}
+
+impl InvalidEmailsApiGetSuppressionInvalidEmailsParams {
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+}
+
+/// The functions of [`InvalidEmailsApi`], for code that takes them as a trait, so that it can be
+/// tested with the `MockInvalidEmailsApiApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait InvalidEmailsApiApi: Send + Sync {
+    /// See [`InvalidEmailsApi::get_suppression_invalid_emails_with`].
+    async fn get_suppression_invalid_emails_with(
+        &self,
+        params: &InvalidEmailsApiGetSuppressionInvalidEmailsParams,
+    ) -> Result<Vec<crate::types::InvalidEmail>>;
+
+    /// See [`InvalidEmailsApi::get_all_suppression_invalid_emails_with`].
+    async fn get_all_suppression_invalid_emails_with(
+        &self,
+        params: &InvalidEmailsApiGetSuppressionInvalidEmailsParams,
+    ) -> Result<Vec<crate::types::InvalidEmail>>;
+
+    /// See [`InvalidEmailsApi::get_suppression_invalid_emails_stream`].
+    fn get_suppression_invalid_emails_stream(
+        &self,
+        params: &InvalidEmailsApiGetSuppressionInvalidEmailsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::InvalidEmail>;
+
+    /// See [`InvalidEmailsApi::delete_suppression_invalid_emails`].
+    async fn delete_suppression_invalid_emails(
+        &self,
+        body: &crate::types::DeleteSuppressionInvalidEmailsRequest,
+    ) -> Result<crate::types::DeleteSuppressionInvalidEmailsResponse>;
+
+    /// See [`InvalidEmailsApi::get_suppression_invalid_emails_email`].
+    async fn get_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::InvalidEmail>>;
+
+    /// See [`InvalidEmailsApi::get_all_suppression_invalid_emails_email`].
+    async fn get_all_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::InvalidEmail>>;
+
+    /// See [`InvalidEmailsApi::get_suppression_invalid_emails_email_stream`].
+    fn get_suppression_invalid_emails_email_stream(
+        &self,
+        email: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::InvalidEmail>;
+
+    /// See [`InvalidEmailsApi::delete_suppression_invalid_emails_email`].
+    async fn delete_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::DeleteSuppressionInvalidEmailsEmailResponse>;
+}
+
+#[async_trait::async_trait]
+impl InvalidEmailsApiApi for InvalidEmailsApi {
+    async fn get_suppression_invalid_emails_with(
+        &self,
+        params: &InvalidEmailsApiGetSuppressionInvalidEmailsParams,
+    ) -> Result<Vec<crate::types::InvalidEmail>> {
+        InvalidEmailsApi::get_suppression_invalid_emails_with(self, params).await
+    }
+
+    async fn get_all_suppression_invalid_emails_with(
+        &self,
+        params: &InvalidEmailsApiGetSuppressionInvalidEmailsParams,
+    ) -> Result<Vec<crate::types::InvalidEmail>> {
+        InvalidEmailsApi::get_all_suppression_invalid_emails_with(self, params).await
+    }
+
+    fn get_suppression_invalid_emails_stream(
+        &self,
+        params: &InvalidEmailsApiGetSuppressionInvalidEmailsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::InvalidEmail> {
+        InvalidEmailsApi::get_suppression_invalid_emails_stream(self, params, start, max_items)
+    }
+
+    async fn delete_suppression_invalid_emails(
+        &self,
+        body: &crate::types::DeleteSuppressionInvalidEmailsRequest,
+    ) -> Result<crate::types::DeleteSuppressionInvalidEmailsResponse> {
+        InvalidEmailsApi::delete_suppression_invalid_emails(self, body).await
+    }
+
+    async fn get_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::InvalidEmail>> {
+        InvalidEmailsApi::get_suppression_invalid_emails_email(self, email).await
+    }
+
+    async fn get_all_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::InvalidEmail>> {
+        InvalidEmailsApi::get_all_suppression_invalid_emails_email(self, email).await
+    }
+
+    fn get_suppression_invalid_emails_email_stream(
+        &self,
+        email: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::InvalidEmail> {
+        InvalidEmailsApi::get_suppression_invalid_emails_email_stream(self, email, start, max_items)
+    }
+
+    async fn delete_suppression_invalid_emails_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::DeleteSuppressionInvalidEmailsEmailResponse> {
+        InvalidEmailsApi::delete_suppression_invalid_emails_email(self, email).await
+    }
+}
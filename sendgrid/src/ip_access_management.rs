@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct IpAccessManagement {
     pub client: Client,
@@ -71,7 +69,7 @@ impl IpAccessManagement {
     pub async fn delete_access_settings_whitelist(
         &self,
         body: &crate::types::DeleteAccessSettingsWhitelistRequest,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteAccessSettingsWhitelistResponse> {
         let url = "/access_settings/whitelist".to_string();
         self.client
             .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -87,15 +85,14 @@ impl IpAccessManagement {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- Limits the number of IPs to return.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &IpAccessManagementGetAccessSettingsActivityParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_access_settings_activity(
+    pub async fn get_access_settings_activity_with(
         &self,
-        limit: i64,
+        params: &IpAccessManagementGetAccessSettingsActivityParams,
     ) -> Result<crate::types::GetAccessSettingsActivityResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -104,6 +101,20 @@ impl IpAccessManagement {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_access_settings_activity_with` and pass the query parameters as a `IpAccessManagementGetAccessSettingsActivityParams` instead.
+     */
+    #[deprecated(note = "use `get_access_settings_activity_with` instead")]
+    pub async fn get_access_settings_activity(
+        &self,
+        limit: i64,
+    ) -> Result<crate::types::GetAccessSettingsActivityResponse> {
+        let params = IpAccessManagementGetAccessSettingsActivityParams {
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.get_access_settings_activity_with(&params).await
+    }
+
     /**
      * Retrieve a specific allowed IP.
      *
@@ -145,7 +156,7 @@ impl IpAccessManagement {
     pub async fn delete_access_settings_whitelist_rule(
         &self,
         rule_id: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteAccessSettingsWhitelistRuleResponse> {
         let url = format!(
             "/access_settings/whitelist/{}",
             crate::progenitor_support::encode_path(rule_id),
@@ -154,3 +165,98 @@ impl IpAccessManagement {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `IpAccessManagement::get_access_settings_activity_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IpAccessManagementGetAccessSettingsActivityParams {
+    /// Limits the number of IPs to return.
+    pub limit: Option<i64>,
+}
+
+impl IpAccessManagementGetAccessSettingsActivityParams {
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The functions of [`IpAccessManagement`], for code that takes them as a trait, so that it can be
+/// tested with the `MockIpAccessManagementApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait IpAccessManagementApi: Send + Sync {
+    /// See [`IpAccessManagement::get_access_settings_whitelist`].
+    async fn get_access_settings_whitelist(&self) -> Result<crate::types::IpAccessResponse>;
+
+    /// See [`IpAccessManagement::post_access_settings_whitelist`].
+    async fn post_access_settings_whitelist(
+        &self,
+        body: &crate::types::PostAccessSettingsWhitelistRequest,
+    ) -> Result<crate::types::IpAccessResponse>;
+
+    /// See [`IpAccessManagement::delete_access_settings_whitelist`].
+    async fn delete_access_settings_whitelist(
+        &self,
+        body: &crate::types::DeleteAccessSettingsWhitelistRequest,
+    ) -> Result<crate::types::DeleteAccessSettingsWhitelistResponse>;
+
+    /// See [`IpAccessManagement::get_access_settings_activity_with`].
+    async fn get_access_settings_activity_with(
+        &self,
+        params: &IpAccessManagementGetAccessSettingsActivityParams,
+    ) -> Result<crate::types::GetAccessSettingsActivityResponse>;
+
+    /// See [`IpAccessManagement::get_access_settings_whitelist_rule`].
+    async fn get_access_settings_whitelist_rule(
+        &self,
+        rule_id: &str,
+    ) -> Result<crate::types::IpAccessResponse>;
+
+    /// See [`IpAccessManagement::delete_access_settings_whitelist_rule`].
+    async fn delete_access_settings_whitelist_rule(
+        &self,
+        rule_id: &str,
+    ) -> Result<crate::types::DeleteAccessSettingsWhitelistRuleResponse>;
+}
+
+#[async_trait::async_trait]
+impl IpAccessManagementApi for IpAccessManagement {
+    async fn get_access_settings_whitelist(&self) -> Result<crate::types::IpAccessResponse> {
+        IpAccessManagement::get_access_settings_whitelist(self).await
+    }
+
+    async fn post_access_settings_whitelist(
+        &self,
+        body: &crate::types::PostAccessSettingsWhitelistRequest,
+    ) -> Result<crate::types::IpAccessResponse> {
+        IpAccessManagement::post_access_settings_whitelist(self, body).await
+    }
+
+    async fn delete_access_settings_whitelist(
+        &self,
+        body: &crate::types::DeleteAccessSettingsWhitelistRequest,
+    ) -> Result<crate::types::DeleteAccessSettingsWhitelistResponse> {
+        IpAccessManagement::delete_access_settings_whitelist(self, body).await
+    }
+
+    async fn get_access_settings_activity_with(
+        &self,
+        params: &IpAccessManagementGetAccessSettingsActivityParams,
+    ) -> Result<crate::types::GetAccessSettingsActivityResponse> {
+        IpAccessManagement::get_access_settings_activity_with(self, params).await
+    }
+
+    async fn get_access_settings_whitelist_rule(
+        &self,
+        rule_id: &str,
+    ) -> Result<crate::types::IpAccessResponse> {
+        IpAccessManagement::get_access_settings_whitelist_rule(self, rule_id).await
+    }
+
+    async fn delete_access_settings_whitelist_rule(
+        &self,
+        rule_id: &str,
+    ) -> Result<crate::types::DeleteAccessSettingsWhitelistRuleResponse> {
+        IpAccessManagement::delete_access_settings_whitelist_rule(self, rule_id).await
+    }
+}
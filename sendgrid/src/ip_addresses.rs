@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct IpAddresses {
     pub client: Client,
@@ -25,45 +23,35 @@ impl IpAddresses {
      *
      * **Parameters:**
      *
-     * * `ip: &str` -- The license key provided with your New Relic account.
-     * * `exclude_whitelabels: bool` -- Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     * * `limit: i64` -- The number of IPs you want returned at the same time.
-     * * `offset: i64` -- The offset for the number of IPs that you are requesting.
-     * * `subuser: &str` -- The license key provided with your New Relic account.
-     * * `sort_by_direction: crate::types::SortByDirection` -- The direction to sort the results.
+     * * `params: &IpAddressesGetIpsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_ips(
+    pub async fn get_ips_with(
         &self,
-        ip: &str,
-        exclude_whitelabels: bool,
-        limit: i64,
-        offset: i64,
-        subuser: &str,
-        sort_by_direction: crate::types::SortByDirection,
+        params: &IpAddressesGetIpsParams,
     ) -> Result<Vec<crate::types::GetIpsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if exclude_whitelabels {
+        if let Some(exclude_whitelabels) = &params.exclude_whitelabels {
             query_args.push((
                 "exclude_whitelabels".to_string(),
                 exclude_whitelabels.to_string(),
             ));
         }
-        if !ip.is_empty() {
+        if let Some(ip) = &params.ip {
             query_args.push(("ip".to_string(), ip.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !sort_by_direction.to_string().is_empty() {
+        if let Some(sort_by_direction) = &params.sort_by_direction {
             query_args.push((
                 "sort_by_direction".to_string(),
                 sort_by_direction.to_string(),
             ));
         }
-        if !subuser.is_empty() {
+        if let Some(subuser) = &params.subuser {
             query_args.push(("subuser".to_string(), subuser.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -72,6 +60,30 @@ impl IpAddresses {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_ips_with` and pass the query parameters as a `IpAddressesGetIpsParams` instead.
+     */
+    #[deprecated(note = "use `get_ips_with` instead")]
+    pub async fn get_ips(
+        &self,
+        ip: &str,
+        exclude_whitelabels: bool,
+        limit: i64,
+        offset: i64,
+        subuser: &str,
+        sort_by_direction: crate::types::SortByDirection,
+    ) -> Result<Vec<crate::types::GetIpsResponse>> {
+        let params = IpAddressesGetIpsParams {
+            exclude_whitelabels: Some(exclude_whitelabels).filter(|v| *v),
+            ip: Some(ip.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            sort_by_direction: Some(sort_by_direction).filter(|v| !v.to_string().is_empty()),
+            subuser: Some(subuser.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_ips_with(&params).await
+    }
+
     /**
      * Retrieve all IP addresses.
      *
@@ -84,7 +96,47 @@ impl IpAddresses {
      * Response includes warm up status, pools, assigned subusers, and reverse DNS info. The start_date field corresponds to when warmup started for that IP.
      *
      * A single IP address or a range of IP addresses may be dedicated to an account in order to send email for multiple domains. The reputation of this IP is based on the aggregate performance of all the senders who use it.
+     *
+     * **Parameters:**
+     *
+     * * `params: &IpAddressesGetIpsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_ips_with(
+        &self,
+        params: &IpAddressesGetIpsParams,
+    ) -> Result<Vec<crate::types::GetIpsResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(exclude_whitelabels) = &params.exclude_whitelabels {
+            query_args.push((
+                "exclude_whitelabels".to_string(),
+                exclude_whitelabels.to_string(),
+            ));
+        }
+        if let Some(ip) = &params.ip {
+            query_args.push(("ip".to_string(), ip.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(sort_by_direction) = &params.sort_by_direction {
+            query_args.push((
+                "sort_by_direction".to_string(),
+                sort_by_direction.to_string(),
+            ));
+        }
+        if let Some(subuser) = &params.subuser {
+            query_args.push(("subuser".to_string(), subuser.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/ips?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_ips_with` and pass the query parameters as a `IpAddressesGetIpsParams` instead.
      */
+    #[deprecated(note = "use `get_all_ips_with` instead")]
     pub async fn get_all_ips(
         &self,
         ip: &str,
@@ -93,32 +145,77 @@ impl IpAddresses {
         subuser: &str,
         sort_by_direction: crate::types::SortByDirection,
     ) -> Result<Vec<crate::types::GetIpsResponse>> {
+        let params = IpAddressesGetIpsParams {
+            exclude_whitelabels: Some(exclude_whitelabels).filter(|v| *v),
+            ip: Some(ip.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            sort_by_direction: Some(sort_by_direction).filter(|v| !v.to_string().is_empty()),
+            subuser: Some(subuser.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_ips_with(&params).await
+    }
+
+    /**
+     * Retrieve all IP addresses.
+     *
+     * This function performs a `GET` to the `/ips` endpoint.
+     *
+     * As opposed to `get_all_ips`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve a list of all assigned and unassigned IPs.**
+     *
+     * Response includes warm up status, pools, assigned subusers, and reverse DNS info. The start_date field corresponds to when warmup started for that IP.
+     *
+     * A single IP address or a range of IP addresses may be dedicated to an account in order to send email for multiple domains. The reputation of this IP is based on the aggregate performance of all the senders who use it.
+     */
+    pub fn get_ips_stream(
+        &self,
+        params: &IpAddressesGetIpsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetIpsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if exclude_whitelabels {
+        if let Some(exclude_whitelabels) = &params.exclude_whitelabels {
             query_args.push((
                 "exclude_whitelabels".to_string(),
                 exclude_whitelabels.to_string(),
             ));
         }
-        if !ip.is_empty() {
+        if let Some(ip) = &params.ip {
             query_args.push(("ip".to_string(), ip.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !sort_by_direction.to_string().is_empty() {
+        if let Some(sort_by_direction) = &params.sort_by_direction {
             query_args.push((
                 "sort_by_direction".to_string(),
                 sort_by_direction.to_string(),
             ));
         }
-        if !subuser.is_empty() {
+        if let Some(subuser) = &params.subuser {
             query_args.push(("subuser".to_string(), subuser.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/ips?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -180,6 +277,41 @@ impl IpAddresses {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve all assigned IPs.
+     *
+     * This function performs a `GET` to the `/ips/assigned` endpoint.
+     *
+     * As opposed to `get_all_ips_assigned`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve only assigned IP addresses.**
+     *
+     * A single IP address or a range of IP addresses may be dedicated to an account in order to send email for multiple domains. The reputation of this IP is based on the aggregate performance of all the senders who use it.
+     */
+    pub fn get_ips_assigned_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetIpsAssignedResponse> {
+        let url = "/ips/assigned".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Retrieve all IP pools an IP address belongs to.
      *
@@ -203,3 +335,166 @@ impl IpAddresses {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `IpAddresses::get_ips_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IpAddressesGetIpsParams {
+    /// Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+    pub exclude_whitelabels: Option<bool>,
+    /// The license key provided with your New Relic account.
+    pub ip: Option<String>,
+    /// The number of IPs you want returned at the same time.
+    pub limit: Option<i64>,
+    /// The offset for the number of IPs that you are requesting.
+    pub offset: Option<i64>,
+    /// The direction to sort the results.
+    pub sort_by_direction: Option<crate::types::SortByDirection>,
+    /// The license key provided with your New Relic account.
+    pub subuser: Option<String>,
+}
+
+impl IpAddressesGetIpsParams {
+    pub fn exclude_whitelabels(mut self, exclude_whitelabels: bool) -> Self {
+        self.exclude_whitelabels = Some(exclude_whitelabels);
+        self
+    }
+
+    pub fn ip<T: ToString>(mut self, ip: T) -> Self {
+        self.ip = Some(ip.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.sort_by_direction = Some(sort_by_direction);
+        self
+    }
+
+    pub fn subuser<T: ToString>(mut self, subuser: T) -> Self {
+        self.subuser = Some(subuser.to_string());
+        self
+    }
+}
+
+/// The functions of [`IpAddresses`], for code that takes them as a trait, so that it can be
+/// tested with the `MockIpAddressesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait IpAddressesApi: Send + Sync {
+    /// See [`IpAddresses::get_ips_with`].
+    async fn get_ips_with(
+        &self,
+        params: &IpAddressesGetIpsParams,
+    ) -> Result<Vec<crate::types::GetIpsResponse>>;
+
+    /// See [`IpAddresses::get_all_ips_with`].
+    async fn get_all_ips_with(
+        &self,
+        params: &IpAddressesGetIpsParams,
+    ) -> Result<Vec<crate::types::GetIpsResponse>>;
+
+    /// See [`IpAddresses::get_ips_stream`].
+    fn get_ips_stream(
+        &self,
+        params: &IpAddressesGetIpsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetIpsResponse>;
+
+    /// See [`IpAddresses::post_ip`].
+    async fn post_ip(
+        &self,
+        body: &crate::types::PostIpsRequest,
+    ) -> Result<crate::types::PostIpsResponseData>;
+
+    /// See [`IpAddresses::get_ips_remaining`].
+    async fn get_ips_remaining(&self) -> Result<crate::types::GetIpsRemainingResponse>;
+
+    /// See [`IpAddresses::get_ips_assigned`].
+    async fn get_ips_assigned(&self) -> Result<Vec<crate::types::GetIpsAssignedResponse>>;
+
+    /// See [`IpAddresses::get_all_ips_assigned`].
+    async fn get_all_ips_assigned(&self) -> Result<Vec<crate::types::GetIpsAssignedResponse>>;
+
+    /// See [`IpAddresses::get_ips_assigned_stream`].
+    fn get_ips_assigned_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetIpsAssignedResponse>;
+
+    /// See [`IpAddresses::get_ips_ip_address`].
+    async fn get_ips_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<crate::types::GetIpsIpAddressResponse>;
+}
+
+#[async_trait::async_trait]
+impl IpAddressesApi for IpAddresses {
+    async fn get_ips_with(
+        &self,
+        params: &IpAddressesGetIpsParams,
+    ) -> Result<Vec<crate::types::GetIpsResponse>> {
+        IpAddresses::get_ips_with(self, params).await
+    }
+
+    async fn get_all_ips_with(
+        &self,
+        params: &IpAddressesGetIpsParams,
+    ) -> Result<Vec<crate::types::GetIpsResponse>> {
+        IpAddresses::get_all_ips_with(self, params).await
+    }
+
+    fn get_ips_stream(
+        &self,
+        params: &IpAddressesGetIpsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetIpsResponse> {
+        IpAddresses::get_ips_stream(self, params, start, max_items)
+    }
+
+    async fn post_ip(
+        &self,
+        body: &crate::types::PostIpsRequest,
+    ) -> Result<crate::types::PostIpsResponseData> {
+        IpAddresses::post_ip(self, body).await
+    }
+
+    async fn get_ips_remaining(&self) -> Result<crate::types::GetIpsRemainingResponse> {
+        IpAddresses::get_ips_remaining(self).await
+    }
+
+    async fn get_ips_assigned(&self) -> Result<Vec<crate::types::GetIpsAssignedResponse>> {
+        IpAddresses::get_ips_assigned(self).await
+    }
+
+    async fn get_all_ips_assigned(&self) -> Result<Vec<crate::types::GetIpsAssignedResponse>> {
+        IpAddresses::get_all_ips_assigned(self).await
+    }
+
+    fn get_ips_assigned_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetIpsAssignedResponse> {
+        IpAddresses::get_ips_assigned_stream(self, start, max_items)
+    }
+
+    async fn get_ips_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<crate::types::GetIpsIpAddressResponse> {
+        IpAddresses::get_ips_ip_address(self, ip_address).await
+    }
+}
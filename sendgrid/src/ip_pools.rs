@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct IpPools {
     pub client: Client,
@@ -19,7 +17,7 @@ impl IpPools {
      *
      * **This endpoint allows you to get all of your IP pools.**
      */
-    pub async fn get_ips_pools(&self) -> Result<Vec<crate::types::IpPoolsPoolResp>> {
+    pub async fn get_ips_pools(&self) -> Result<Vec<crate::types::IpPoolResponse>> {
         let url = "/ips/pools".to_string();
         self.client.get(&url, None).await
     }
@@ -33,11 +31,44 @@ impl IpPools {
      *
      * **This endpoint allows you to get all of your IP pools.**
      */
-    pub async fn get_all_ips_pools(&self) -> Result<Vec<crate::types::IpPoolsPoolResp>> {
+    pub async fn get_all_ips_pools(&self) -> Result<Vec<crate::types::IpPoolResponse>> {
         let url = "/ips/pools".to_string();
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve all IP pools.
+     *
+     * This function performs a `GET` to the `/ips/pools` endpoint.
+     *
+     * As opposed to `get_all_ips_pools`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to get all of your IP pools.**
+     */
+    pub fn get_ips_pools_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IpPoolResponse> {
+        let url = "/ips/pools".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create an IP pool.
      *
@@ -55,8 +86,8 @@ impl IpPools {
      */
     pub async fn post_ips_pool(
         &self,
-        body: &crate::types::IpPool,
-    ) -> Result<crate::types::IpPoolsPoolResp> {
+        body: &crate::types::PostIpsPoolsRequest,
+    ) -> Result<crate::types::IpPoolResponse> {
         let url = "/ips/pools".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -85,8 +116,8 @@ impl IpPools {
     pub async fn post_ips_pools_pool_name_ip(
         &self,
         pool_name: &str,
-        body: &crate::types::PostIpsWarmupRequest,
-    ) -> Result<crate::types::GetIpsAssignedResponse> {
+        body: &crate::types::PostIpsPoolsPoolNameRequest,
+    ) -> Result<crate::types::PostIpsPoolsPoolNameResponse> {
         let url = format!(
             "/ips/pools/{}/ips",
             crate::progenitor_support::encode_path(pool_name),
@@ -127,7 +158,7 @@ impl IpPools {
         &self,
         pool_name: &str,
         body: &crate::types::PutIpsPoolsPoolNameRequest,
-    ) -> Result<crate::types::IpPoolsPoolResp> {
+    ) -> Result<crate::types::IpPoolResponse> {
         let url = format!(
             "/ips/pools/{}",
             crate::progenitor_support::encode_path(pool_name),
@@ -145,7 +176,10 @@ impl IpPools {
      *
      * **This endpoint allows you to delete an IP pool.**
      */
-    pub async fn delete_ips_pools_pool_name(&self, pool_name: &str) -> Result<crate::types::Help> {
+    pub async fn delete_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+    ) -> Result<crate::types::DeleteIpsPoolsPoolNameResponse> {
         let url = format!(
             "/ips/pools/{}",
             crate::progenitor_support::encode_path(pool_name),
@@ -165,7 +199,7 @@ impl IpPools {
         &self,
         pool_name: &str,
         ip: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteIpsPoolsPoolNameIpResponse> {
         let url = format!(
             "/ips/pools/{}/ips/{}",
             crate::progenitor_support::encode_path(pool_name),
@@ -175,3 +209,125 @@ impl IpPools {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`IpPools`], for code that takes them as a trait, so that it can be
+/// tested with the `MockIpPoolsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait IpPoolsApi: Send + Sync {
+    /// See [`IpPools::get_ips_pools`].
+    async fn get_ips_pools(&self) -> Result<Vec<crate::types::IpPoolResponse>>;
+
+    /// See [`IpPools::get_all_ips_pools`].
+    async fn get_all_ips_pools(&self) -> Result<Vec<crate::types::IpPoolResponse>>;
+
+    /// See [`IpPools::get_ips_pools_stream`].
+    fn get_ips_pools_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IpPoolResponse>;
+
+    /// See [`IpPools::post_ips_pool`].
+    async fn post_ips_pool(
+        &self,
+        body: &crate::types::PostIpsPoolsRequest,
+    ) -> Result<crate::types::IpPoolResponse>;
+
+    /// See [`IpPools::post_ips_pools_pool_name_ip`].
+    async fn post_ips_pools_pool_name_ip(
+        &self,
+        pool_name: &str,
+        body: &crate::types::PostIpsPoolsPoolNameRequest,
+    ) -> Result<crate::types::PostIpsPoolsPoolNameResponse>;
+
+    /// See [`IpPools::get_ips_pools_pool_name`].
+    async fn get_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+    ) -> Result<crate::types::GetIpsPoolsPoolNameResponse>;
+
+    /// See [`IpPools::put_ips_pools_pool_name`].
+    async fn put_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+        body: &crate::types::PutIpsPoolsPoolNameRequest,
+    ) -> Result<crate::types::IpPoolResponse>;
+
+    /// See [`IpPools::delete_ips_pools_pool_name`].
+    async fn delete_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+    ) -> Result<crate::types::DeleteIpsPoolsPoolNameResponse>;
+
+    /// See [`IpPools::delete_ips_pools_pool_name_ip`].
+    async fn delete_ips_pools_pool_name_ip(
+        &self,
+        pool_name: &str,
+        ip: &str,
+    ) -> Result<crate::types::DeleteIpsPoolsPoolNameIpResponse>;
+}
+
+#[async_trait::async_trait]
+impl IpPoolsApi for IpPools {
+    async fn get_ips_pools(&self) -> Result<Vec<crate::types::IpPoolResponse>> {
+        IpPools::get_ips_pools(self).await
+    }
+
+    async fn get_all_ips_pools(&self) -> Result<Vec<crate::types::IpPoolResponse>> {
+        IpPools::get_all_ips_pools(self).await
+    }
+
+    fn get_ips_pools_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IpPoolResponse> {
+        IpPools::get_ips_pools_stream(self, start, max_items)
+    }
+
+    async fn post_ips_pool(
+        &self,
+        body: &crate::types::PostIpsPoolsRequest,
+    ) -> Result<crate::types::IpPoolResponse> {
+        IpPools::post_ips_pool(self, body).await
+    }
+
+    async fn post_ips_pools_pool_name_ip(
+        &self,
+        pool_name: &str,
+        body: &crate::types::PostIpsPoolsPoolNameRequest,
+    ) -> Result<crate::types::PostIpsPoolsPoolNameResponse> {
+        IpPools::post_ips_pools_pool_name_ip(self, pool_name, body).await
+    }
+
+    async fn get_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+    ) -> Result<crate::types::GetIpsPoolsPoolNameResponse> {
+        IpPools::get_ips_pools_pool_name(self, pool_name).await
+    }
+
+    async fn put_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+        body: &crate::types::PutIpsPoolsPoolNameRequest,
+    ) -> Result<crate::types::IpPoolResponse> {
+        IpPools::put_ips_pools_pool_name(self, pool_name, body).await
+    }
+
+    async fn delete_ips_pools_pool_name(
+        &self,
+        pool_name: &str,
+    ) -> Result<crate::types::DeleteIpsPoolsPoolNameResponse> {
+        IpPools::delete_ips_pools_pool_name(self, pool_name).await
+    }
+
+    async fn delete_ips_pools_pool_name_ip(
+        &self,
+        pool_name: &str,
+        ip: &str,
+    ) -> Result<crate::types::DeleteIpsPoolsPoolNameIpResponse> {
+        IpPools::delete_ips_pools_pool_name_ip(self, pool_name, ip).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct IpWarmup {
     pub client: Client,
@@ -38,6 +36,39 @@ impl IpWarmup {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve all IPs currently in warmup.
+     *
+     * This function performs a `GET` to the `/ips/warmup` endpoint.
+     *
+     * As opposed to `get_all_ips_warmup`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve all of your IP addresses that are currently warming up.**
+     */
+    pub fn get_ips_warmup_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IpWarmupResponse> {
+        let url = "/ips/warmup".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Start warming up an IP address.
      *
@@ -99,6 +130,46 @@ impl IpWarmup {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve the warmup status for a specific IP address.
+     *
+     * This function performs a `GET` to the `/ips/warmup/{ip_address}` endpoint.
+     *
+     * As opposed to `get_all_ips_warmup_ip_address`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve the warmup status for a specific IP address.**
+     *
+     * You can retrieve all of your warming IPs using the "Retrieve all IPs currently in warmup" endpoint.
+     */
+    pub fn get_ips_warmup_ip_address_stream(
+        &self,
+        ip_address: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IpWarmupResponse> {
+        let url = format!(
+            "/ips/warmup/{}",
+            crate::progenitor_support::encode_path(ip_address),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Stop warming up an IP address.
      *
@@ -111,7 +182,7 @@ impl IpWarmup {
     pub async fn delete_ips_warmup_ip_address(
         &self,
         ip_address: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteIpsWarmupIpAddressResponse> {
         let url = format!(
             "/ips/warmup/{}",
             crate::progenitor_support::encode_path(ip_address),
@@ -120,3 +191,110 @@ impl IpWarmup {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`IpWarmup`], for code that takes them as a trait, so that it can be
+/// tested with the `MockIpWarmupApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait IpWarmupApi: Send + Sync {
+    /// See [`IpWarmup::get_ips_warmup`].
+    async fn get_ips_warmup(&self) -> Result<Vec<crate::types::IpWarmupResponse>>;
+
+    /// See [`IpWarmup::get_all_ips_warmup`].
+    async fn get_all_ips_warmup(&self) -> Result<Vec<crate::types::IpWarmupResponse>>;
+
+    /// See [`IpWarmup::get_ips_warmup_stream`].
+    fn get_ips_warmup_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IpWarmupResponse>;
+
+    /// See [`IpWarmup::post_ips_warmup`].
+    async fn post_ips_warmup(
+        &self,
+        body: &crate::types::PostIpsWarmupRequest,
+    ) -> Result<Vec<crate::types::IpWarmupResponse>>;
+
+    /// See [`IpWarmup::get_ips_warmup_ip_address`].
+    async fn get_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<Vec<crate::types::IpWarmupResponse>>;
+
+    /// See [`IpWarmup::get_all_ips_warmup_ip_address`].
+    async fn get_all_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<Vec<crate::types::IpWarmupResponse>>;
+
+    /// See [`IpWarmup::get_ips_warmup_ip_address_stream`].
+    fn get_ips_warmup_ip_address_stream(
+        &self,
+        ip_address: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IpWarmupResponse>;
+
+    /// See [`IpWarmup::delete_ips_warmup_ip_address`].
+    async fn delete_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<crate::types::DeleteIpsWarmupIpAddressResponse>;
+}
+
+#[async_trait::async_trait]
+impl IpWarmupApi for IpWarmup {
+    async fn get_ips_warmup(&self) -> Result<Vec<crate::types::IpWarmupResponse>> {
+        IpWarmup::get_ips_warmup(self).await
+    }
+
+    async fn get_all_ips_warmup(&self) -> Result<Vec<crate::types::IpWarmupResponse>> {
+        IpWarmup::get_all_ips_warmup(self).await
+    }
+
+    fn get_ips_warmup_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IpWarmupResponse> {
+        IpWarmup::get_ips_warmup_stream(self, start, max_items)
+    }
+
+    async fn post_ips_warmup(
+        &self,
+        body: &crate::types::PostIpsWarmupRequest,
+    ) -> Result<Vec<crate::types::IpWarmupResponse>> {
+        IpWarmup::post_ips_warmup(self, body).await
+    }
+
+    async fn get_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<Vec<crate::types::IpWarmupResponse>> {
+        IpWarmup::get_ips_warmup_ip_address(self, ip_address).await
+    }
+
+    async fn get_all_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<Vec<crate::types::IpWarmupResponse>> {
+        IpWarmup::get_all_ips_warmup_ip_address(self, ip_address).await
+    }
+
+    fn get_ips_warmup_ip_address_stream(
+        &self,
+        ip_address: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IpWarmupResponse> {
+        IpWarmup::get_ips_warmup_ip_address_stream(self, ip_address, start, max_items)
+    }
+
+    async fn delete_ips_warmup_ip_address(
+        &self,
+        ip_address: &str,
+    ) -> Result<crate::types::DeleteIpsWarmupIpAddressResponse> {
+        IpWarmup::delete_ips_warmup_ip_address(self, ip_address).await
+    }
+}
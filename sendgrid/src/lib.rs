@@ -22,6 +22,30 @@
 //! up to date as features are added. The documentation for the crate is generated
 //! along with the code to make this library easy to use.
 //!
+//! Each tag of the API is a cargo feature of its own, all of them enabled by the
+//! default `full` feature. To build only the tags you use, turn off the default
+//! features and enable those tags, along with `rustls-tls` or `native-tls`.
+//!
+//! The functions of each tag are also a trait, named after the tag, like `UsersApi`
+//! for `users`. Code that takes the trait can be tested with the mocks of the `mock`
+//! feature, like `MockUsersApi`, rather than a server.
+//!
+//! Requests wait out the rate limits of the API. Those that are safe to send twice
+//! are sent again after the `Retry-After` of rate limited responses, and all of them
+//! wait for the limit to reset when none of it is left. `Client::rate_limit_status` tells what the API last said about its
+//! limits.
+//!
+//! Requests that time out, can't connect or get a `5xx` that may not last are
+//! retried, waiting longer before each attempt, but only those that are safe to send
+//! twice: `GET`s, `HEAD`s, `OPTIONS` and `TRACE`s. `Client::builder().retry_policy(...)`
+//! changes how many times, how long to wait and which methods. For the APIs that take
+//! idempotency keys, the `POST`s of `Client::with_idempotency_key` are retried too.
+//!
+//! `Client::builder()` also sets the timeouts, proxies, root certificates, client
+//! certificate and `User-Agent` of the HTTP client, or takes a
+//! `reqwest_middleware::ClientWithMiddleware` of your own. Its `build` takes what
+//! `Client::new` does.
+//!
 //!
 //! To install the library, add the following to your `Cargo.toml` file.
 //!
@@ -56,76 +80,199 @@
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::nonstandard_macro_braces)]
 #![allow(clippy::large_enum_variant)]
+#![allow(clippy::result_large_err)]
 #![allow(clippy::tabs_in_doc_comments)]
 #![allow(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "alerts")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alerts")))]
 pub mod alerts;
+#[cfg(feature = "api_key_permissions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "api_key_permissions")))]
 pub mod api_key_permissions;
+#[cfg(feature = "api_keys")]
+#[cfg_attr(docsrs, doc(cfg(feature = "api_keys")))]
 pub mod api_keys;
+#[cfg(feature = "blocks_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "blocks_api")))]
 pub mod blocks_api;
+#[cfg(feature = "bounces_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bounces_api")))]
 pub mod bounces_api;
+#[cfg(feature = "campaigns_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "campaigns_api")))]
 pub mod campaigns_api;
+#[cfg(feature = "cancel_scheduled_sends")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cancel_scheduled_sends")))]
 pub mod cancel_scheduled_sends;
+#[cfg(feature = "categories")]
+#[cfg_attr(docsrs, doc(cfg(feature = "categories")))]
 pub mod categories;
+#[cfg(feature = "certificates")]
+#[cfg_attr(docsrs, doc(cfg(feature = "certificates")))]
 pub mod certificates;
+#[cfg(feature = "contacts")]
+#[cfg_attr(docsrs, doc(cfg(feature = "contacts")))]
 pub mod contacts;
+#[cfg(feature = "contacts_api_custom_fields")]
+#[cfg_attr(docsrs, doc(cfg(feature = "contacts_api_custom_fields")))]
 pub mod contacts_api_custom_fields;
+#[cfg(feature = "contacts_api_lists")]
+#[cfg_attr(docsrs, doc(cfg(feature = "contacts_api_lists")))]
 pub mod contacts_api_lists;
+#[cfg(feature = "contacts_api_recipients")]
+#[cfg_attr(docsrs, doc(cfg(feature = "contacts_api_recipients")))]
 pub mod contacts_api_recipients;
+#[cfg(feature = "contacts_api_segments")]
+#[cfg_attr(docsrs, doc(cfg(feature = "contacts_api_segments")))]
 pub mod contacts_api_segments;
+#[cfg(feature = "csv_ui_only")]
+#[cfg_attr(docsrs, doc(cfg(feature = "csv_ui_only")))]
 pub mod csv_ui_only;
+#[cfg(feature = "custom_fields")]
+#[cfg_attr(docsrs, doc(cfg(feature = "custom_fields")))]
 pub mod custom_fields;
+#[cfg(feature = "designs_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "designs_api")))]
 pub mod designs_api;
+#[cfg(feature = "domain_authentication")]
+#[cfg_attr(docsrs, doc(cfg(feature = "domain_authentication")))]
 pub mod domain_authentication;
+#[cfg(feature = "email_address_validation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "email_address_validation")))]
 pub mod email_address_validation;
+#[cfg(feature = "email_cname_records")]
+#[cfg_attr(docsrs, doc(cfg(feature = "email_cname_records")))]
 pub mod email_cname_records;
+mod error;
+#[cfg(feature = "invalid_emails_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "invalid_emails_api")))]
 pub mod invalid_emails_api;
+#[cfg(feature = "ip_access_management")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ip_access_management")))]
 pub mod ip_access_management;
+#[cfg(feature = "ip_addresses")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ip_addresses")))]
 pub mod ip_addresses;
+#[cfg(feature = "ip_pools")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ip_pools")))]
 pub mod ip_pools;
+#[cfg(feature = "ip_warmup")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ip_warmup")))]
 pub mod ip_warmup;
+#[cfg(feature = "link_branding")]
+#[cfg_attr(docsrs, doc(cfg(feature = "link_branding")))]
 pub mod link_branding;
+#[cfg(feature = "lists")]
+#[cfg_attr(docsrs, doc(cfg(feature = "lists")))]
 pub mod lists;
+#[cfg(feature = "mail_send")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mail_send")))]
 pub mod mail_send;
+#[cfg(feature = "marketing_campaigns_stats")]
+#[cfg_attr(docsrs, doc(cfg(feature = "marketing_campaigns_stats")))]
 pub mod marketing_campaigns_stats;
+pub mod pagination;
+#[cfg(feature = "query")]
+#[cfg_attr(docsrs, doc(cfg(feature = "query")))]
 pub mod query;
+pub mod rate_limiter;
+pub mod retry;
+#[cfg(feature = "reverse_dns")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reverse_dns")))]
 pub mod reverse_dns;
+#[cfg(feature = "segmenting_contacts")]
+#[cfg_attr(docsrs, doc(cfg(feature = "segmenting_contacts")))]
 pub mod segmenting_contacts;
+#[cfg(feature = "segmenting_contacts_beta")]
+#[cfg_attr(docsrs, doc(cfg(feature = "segmenting_contacts_beta")))]
 pub mod segmenting_contacts_beta;
+#[cfg(feature = "send_test_email")]
+#[cfg_attr(docsrs, doc(cfg(feature = "send_test_email")))]
 pub mod send_test_email;
+#[cfg(feature = "sender_identities_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sender_identities_api")))]
 pub mod sender_identities_api;
+#[cfg(feature = "sender_verification")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sender_verification")))]
 pub mod sender_verification;
+#[cfg(feature = "senders")]
+#[cfg_attr(docsrs, doc(cfg(feature = "senders")))]
 pub mod senders;
+#[cfg(feature = "settings_enforced_tls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "settings_enforced_tls")))]
 pub mod settings_enforced_tls;
+#[cfg(feature = "settings_inbound_parse")]
+#[cfg_attr(docsrs, doc(cfg(feature = "settings_inbound_parse")))]
 pub mod settings_inbound_parse;
+#[cfg(feature = "settings_mail")]
+#[cfg_attr(docsrs, doc(cfg(feature = "settings_mail")))]
 pub mod settings_mail;
+#[cfg(feature = "settings_partner")]
+#[cfg_attr(docsrs, doc(cfg(feature = "settings_partner")))]
 pub mod settings_partner;
+#[cfg(feature = "settings_tracking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "settings_tracking")))]
 pub mod settings_tracking;
+#[cfg(feature = "single_sends")]
+#[cfg_attr(docsrs, doc(cfg(feature = "single_sends")))]
 pub mod single_sends;
+#[cfg(feature = "single_sign_on_settings")]
+#[cfg_attr(docsrs, doc(cfg(feature = "single_sign_on_settings")))]
 pub mod single_sign_on_settings;
+#[cfg(feature = "single_sign_on_teammates")]
+#[cfg_attr(docsrs, doc(cfg(feature = "single_sign_on_teammates")))]
 pub mod single_sign_on_teammates;
+#[cfg(feature = "spam_reports_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "spam_reports_api")))]
 pub mod spam_reports_api;
+#[cfg(feature = "stats")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
 pub mod stats;
+#[cfg(feature = "subuser_monitor_settings")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subuser_monitor_settings")))]
 pub mod subuser_monitor_settings;
+#[cfg(feature = "subuser_statistics")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subuser_statistics")))]
 pub mod subuser_statistics;
+#[cfg(feature = "subusers_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "subusers_api")))]
 pub mod subusers_api;
+#[cfg(feature = "suppressions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "suppressions")))]
 pub mod suppressions;
+#[cfg(feature = "suppressions_global")]
+#[cfg_attr(docsrs, doc(cfg(feature = "suppressions_global")))]
 pub mod suppressions_global;
+#[cfg(feature = "suppressions_unsubscribe_groups")]
+#[cfg_attr(docsrs, doc(cfg(feature = "suppressions_unsubscribe_groups")))]
 pub mod suppressions_unsubscribe_groups;
+#[cfg(feature = "teammates")]
+#[cfg_attr(docsrs, doc(cfg(feature = "teammates")))]
 pub mod teammates;
 #[cfg(test)]
 mod tests;
+#[cfg(feature = "full")]
+#[cfg_attr(docsrs, doc(cfg(feature = "full")))]
 pub mod traits;
+#[cfg(feature = "transactional_templates")]
+#[cfg_attr(docsrs, doc(cfg(feature = "transactional_templates")))]
 pub mod transactional_templates;
+#[cfg(feature = "transactional_templates_versions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "transactional_templates_versions")))]
 pub mod transactional_templates_versions;
 pub mod types;
+#[cfg(feature = "users_api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "users_api")))]
 pub mod users_api;
 #[doc(hidden)]
 pub mod utils;
+#[cfg(feature = "webhooks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "webhooks")))]
 pub mod webhooks;
 
-use anyhow::{anyhow, Error, Result};
+pub use error::{Error, ErrorBody, Result};
 
 pub const DEFAULT_HOST: &str = "https://api.sendgrid.com/v3";
 
@@ -149,6 +296,176 @@ mod progenitor_support {
     }
 }
 
+/// The body of a response that is not JSON, such as a PDF, an image or an archive.
+#[derive(Debug, Clone, Default)]
+pub struct Download {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: bytes::Bytes,
+}
+
+/// The body of a response that is not JSON, returned as a stream of chunks rather
+/// than read into memory all at once.
+pub struct DownloadStream {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: std::pin::Pin<Box<dyn futures::Stream<Item = Result<bytes::Bytes>> + Send>>,
+}
+
+impl std::fmt::Debug for DownloadStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DownloadStream")
+            .field("content_type", &self.content_type)
+            .finish()
+    }
+}
+
+/// Configures the HTTP client of a `Client`, with the defaults of `Client::new` unless
+/// told otherwise. Its `build` takes what `Client::new` does.
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    retry_policy: crate::retry::RetryPolicy,
+    max_rate_limit_wait: Option<std::time::Duration>,
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    user_agent: Option<String>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    root_certificates: Vec<reqwest::Certificate>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    identity: Option<reqwest::Identity>,
+    http_client: Option<reqwest_middleware::ClientWithMiddleware>,
+}
+
+impl ClientBuilder {
+    /// Set how failed requests are retried.
+    pub fn retry_policy(mut self, retry_policy: crate::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set how long requests wait for a rate limit to reset, at most,
+    /// `rate_limiter::MAX_WAIT` unless set.
+    pub fn max_rate_limit_wait(mut self, max_wait: std::time::Duration) -> Self {
+        self.max_rate_limit_wait = Some(max_wait);
+        self
+    }
+
+    /// Set how long each attempt at a request can take, from connecting to reading the
+    /// whole response. There is no limit unless set.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set how long connecting to the API can take.
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests through a proxy, like `reqwest::Proxy::https(url)`. Without one,
+    /// the proxies of the `HTTP_PROXY` and `HTTPS_PROXY` environment variables are used.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Set the `User-Agent` of requests.
+    pub fn user_agent<U>(mut self, user_agent: U) -> Self
+    where
+        U: ToString,
+    {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Trust the certificates signed by a certificate authority, along with those of
+    /// the system, like a private one of `reqwest::Certificate::from_pem(pem)`.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Authenticate to the API with a client certificate, for mutual TLS.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn identity(mut self, identity: reqwest::Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Send requests with a client of your own, with its own middleware. The other
+    /// settings of the builder don't apply to it, and it only retries or waits out
+    /// rate limits if it has a `retry::Retry` or a `rate_limiter::RateLimiter` of its
+    /// own.
+    pub fn http_client(mut self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /*
+     * The HTTP client, along with the rate limiter in its middleware.
+     */
+    fn http(
+        self,
+    ) -> Result<(
+        reqwest_middleware::ClientWithMiddleware,
+        crate::rate_limiter::RateLimiter,
+    )> {
+        if let Some(client) = self.http_client {
+            return Ok((client, Default::default()));
+        }
+
+        let mut rate_limiter =
+            crate::rate_limiter::RateLimiter::new().with_retry_policy(self.retry_policy.clone());
+        if let Some(max_wait) = self.max_rate_limit_wait {
+            rate_limiter = rate_limiter.with_max_wait(max_wait);
+        }
+
+        let mut http = reqwest::Client::builder();
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        for proxy in self.proxies {
+            http = http.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(user_agent);
+        }
+        #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+        {
+            for certificate in self.root_certificates {
+                http = http.add_root_certificate(certificate);
+            }
+            if let Some(identity) = self.identity {
+                http = http.identity(identity);
+            }
+        }
+
+        let client = reqwest_middleware::ClientBuilder::new(http.build()?)
+            // Trace HTTP requests. See the tracing crate to make use of these traces.
+            .with(reqwest_tracing::TracingMiddleware::default())
+            // Retry failed requests.
+            .with(crate::retry::Retry::new(self.retry_policy))
+            // Wait out the rate limits of the API, before the retries see them.
+            .with(rate_limiter.clone())
+            .build();
+
+        Ok((client, rate_limiter))
+    }
+}
+
+impl Client {
+    /// Configure the client before creating it.
+    pub fn builder() -> ClientBuilder {
+        Default::default()
+    }
+}
+
 use std::env;
 
 /// Entrypoint for interacting with the API client.
@@ -158,6 +475,25 @@ pub struct Client {
     token: String,
 
     client: reqwest_middleware::ClientWithMiddleware,
+    rate_limiter: crate::rate_limiter::RateLimiter,
+}
+
+impl ClientBuilder {
+    /// Create the client, see `Client::new`.
+    pub fn build<T>(self, token: T) -> Result<Client>
+    where
+        T: ToString,
+    {
+        let (client, rate_limiter) = self.http()?;
+
+        Ok(Client {
+            host: DEFAULT_HOST.to_string(),
+            token: token.to_string(),
+
+            client,
+            rate_limiter,
+        })
+    }
 }
 
 impl Client {
@@ -168,28 +504,8 @@ impl Client {
     where
         T: ToString,
     {
-        let client = reqwest::Client::builder().build();
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        match client {
-            Ok(c) => {
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
-                    .build();
-
-                Client {
-                    host: DEFAULT_HOST.to_string(),
-                    token: token.to_string(),
-
-                    client,
-                }
-            }
+        match Self::builder().build(token) {
+            Ok(c) => c,
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
         }
     }
@@ -227,6 +543,18 @@ impl Client {
         method: reqwest::Method,
         uri: &str,
         body: Option<reqwest::Body>,
+    ) -> Result<reqwest::Response> {
+        self.request_raw_with_media_types(method, uri, body, "application/json", "application/json")
+            .await
+    }
+
+    async fn request_raw_with_media_types(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+        accept: &str,
+        content_type: &str,
     ) -> Result<reqwest::Response> {
         let u = if uri.starts_with("https://") {
             uri.to_string()
@@ -239,11 +567,11 @@ impl Client {
         // Set the default headers.
         req = req.header(
             reqwest::header::ACCEPT,
-            reqwest::header::HeaderValue::from_static("application/json"),
+            reqwest::header::HeaderValue::from_str(accept)?,
         );
         req = req.header(
             reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
+            reqwest::header::HeaderValue::from_str(content_type)?,
         );
 
         if let Some(auth_str) = auth {
@@ -259,6 +587,11 @@ impl Client {
         Ok(req.send().await?)
     }
 
+    /// The status of the rate limits of the API, as of the last responses.
+    pub fn rate_limit_status(&self) -> Vec<crate::rate_limiter::RateLimitStatus> {
+        self.rate_limiter.status()
+    }
+
     async fn request<Out>(
         &self,
         method: reqwest::Method,
@@ -271,6 +604,7 @@ impl Client {
         let response = self.request_raw(method, uri, body).await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -279,26 +613,58 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn request_form<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        form: String,
+    ) -> Result<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let response = self
+            .request_raw_with_media_types(
+                method,
+                uri,
+                Some(form.into()),
+                "application/json",
+                "application/x-www-form-urlencoded",
+            )
+            .await?;
 
-            Err(error)
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            log::debug!(
+                "response payload {}",
+                String::from_utf8_lossy(&response_body)
+            );
+            if status == http::StatusCode::NO_CONTENT
+                || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
+            {
+                crate::error::decode(b"null")
+            } else {
+                crate::error::decode::<Out>(&response_body)
+            }
+        } else {
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -314,6 +680,7 @@ impl Client {
         let response = self.request_raw(method, uri, body).await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -331,22 +698,13 @@ impl Client {
             let parsed_response = if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
+                crate::error::decode::<Out>(&response_body)
             };
-            parsed_response.map(|out| (link, out)).map_err(Error::from)
+            parsed_response.map(|out| (link, out))
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -383,6 +741,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -391,31 +750,19 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {
                 // Parse the output as a string.
-                serde_json::from_value(serde_json::json!(&String::from_utf8(
-                    response_body.to_vec()
-                )?))
+                serde_json::from_value(serde_json::json!(String::from_utf8_lossy(&response_body)))
+                    .map_err(Error::from)
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -454,6 +801,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -462,31 +810,19 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {
                 // Parse the output as a string.
-                serde_json::from_value(serde_json::json!(&String::from_utf8(
-                    response_body.to_vec()
-                )?))
+                serde_json::from_value(serde_json::json!(String::from_utf8_lossy(&response_body)))
+                    .map_err(Error::from)
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -546,6 +882,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -554,26 +891,82 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    #[allow(dead_code)]
+    async fn request_download(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::Download> {
+        let response = self
+            .request_raw_with_media_types(method, uri, body, "*/*", "application/json")
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let content_type = response
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|c| c.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            Ok(crate::Download {
+                content_type,
+                body: response_body,
+            })
+        } else {
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    #[allow(dead_code)]
+    async fn request_download_stream(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::DownloadStream> {
+        let response = self
+            .request_raw_with_media_types(method, uri, body, "*/*", "application/json")
+            .await?;
 
-            Err(error)
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        if status.is_success() {
+            let content_type = response
+                .headers()
+                .get(http::header::CONTENT_TYPE)
+                .and_then(|c| c.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+
+            Ok(crate::DownloadStream {
+                content_type,
+                body: Box::pin(futures::TryStreamExt::map_err(
+                    response.bytes_stream(),
+                    Error::from,
+                )),
+            })
+        } else {
+            let response_body = response.bytes().await?;
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -692,240 +1085,356 @@ impl Client {
         .await
     }
 
+    #[cfg(feature = "alerts")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alerts")))]
     pub fn alerts(&self) -> alerts::Alerts {
         alerts::Alerts::new(self.clone())
     }
 
+    #[cfg(feature = "api_key_permissions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "api_key_permissions")))]
     pub fn api_key_permissions(&self) -> api_key_permissions::ApiKeyPermissions {
         api_key_permissions::ApiKeyPermissions::new(self.clone())
     }
 
+    #[cfg(feature = "api_keys")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "api_keys")))]
     pub fn api_keys(&self) -> api_keys::ApiKeys {
         api_keys::ApiKeys::new(self.clone())
     }
 
+    #[cfg(feature = "blocks_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "blocks_api")))]
     pub fn blocks_api(&self) -> blocks_api::BlocksApi {
         blocks_api::BlocksApi::new(self.clone())
     }
 
+    #[cfg(feature = "bounces_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bounces_api")))]
     pub fn bounces_api(&self) -> bounces_api::BouncesApi {
         bounces_api::BouncesApi::new(self.clone())
     }
 
+    #[cfg(feature = "campaigns_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "campaigns_api")))]
     pub fn campaigns_api(&self) -> campaigns_api::CampaignsApi {
         campaigns_api::CampaignsApi::new(self.clone())
     }
 
+    #[cfg(feature = "cancel_scheduled_sends")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "cancel_scheduled_sends")))]
     pub fn cancel_scheduled_sends(&self) -> cancel_scheduled_sends::CancelScheduledSends {
         cancel_scheduled_sends::CancelScheduledSends::new(self.clone())
     }
 
+    #[cfg(feature = "categories")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "categories")))]
     pub fn categories(&self) -> categories::Categories {
         categories::Categories::new(self.clone())
     }
 
+    #[cfg(feature = "certificates")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "certificates")))]
     pub fn certificates(&self) -> certificates::Certificates {
         certificates::Certificates::new(self.clone())
     }
 
+    #[cfg(feature = "contacts")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "contacts")))]
     pub fn contacts(&self) -> contacts::Contacts {
         contacts::Contacts::new(self.clone())
     }
 
+    #[cfg(feature = "contacts_api_custom_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "contacts_api_custom_fields")))]
     pub fn contacts_api_custom_fields(
         &self,
     ) -> contacts_api_custom_fields::ContactsApiCustomFields {
         contacts_api_custom_fields::ContactsApiCustomFields::new(self.clone())
     }
 
+    #[cfg(feature = "contacts_api_lists")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "contacts_api_lists")))]
     pub fn contacts_api_lists(&self) -> contacts_api_lists::ContactsApiLists {
         contacts_api_lists::ContactsApiLists::new(self.clone())
     }
 
+    #[cfg(feature = "contacts_api_recipients")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "contacts_api_recipients")))]
     pub fn contacts_api_recipients(&self) -> contacts_api_recipients::ContactsApiRecipients {
         contacts_api_recipients::ContactsApiRecipients::new(self.clone())
     }
 
+    #[cfg(feature = "contacts_api_segments")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "contacts_api_segments")))]
     pub fn contacts_api_segments(&self) -> contacts_api_segments::ContactsApiSegments {
         contacts_api_segments::ContactsApiSegments::new(self.clone())
     }
 
+    #[cfg(feature = "csv_ui_only")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "csv_ui_only")))]
     pub fn csv_ui_only(&self) -> csv_ui_only::CsvUiOnly {
         csv_ui_only::CsvUiOnly::new(self.clone())
     }
 
+    #[cfg(feature = "custom_fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "custom_fields")))]
     pub fn custom_fields(&self) -> custom_fields::CustomFields {
         custom_fields::CustomFields::new(self.clone())
     }
 
+    #[cfg(feature = "designs_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "designs_api")))]
     pub fn designs_api(&self) -> designs_api::DesignsApi {
         designs_api::DesignsApi::new(self.clone())
     }
 
+    #[cfg(feature = "domain_authentication")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "domain_authentication")))]
     pub fn domain_authentication(&self) -> domain_authentication::DomainAuthentication {
         domain_authentication::DomainAuthentication::new(self.clone())
     }
 
+    #[cfg(feature = "email_address_validation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "email_address_validation")))]
     pub fn email_address_validation(&self) -> email_address_validation::EmailAddressValidation {
         email_address_validation::EmailAddressValidation::new(self.clone())
     }
 
+    #[cfg(feature = "email_cname_records")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "email_cname_records")))]
     pub fn email_cname_records(&self) -> email_cname_records::EmailCnameRecords {
         email_cname_records::EmailCnameRecords::new(self.clone())
     }
 
+    #[cfg(feature = "invalid_emails_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "invalid_emails_api")))]
     pub fn invalid_emails_api(&self) -> invalid_emails_api::InvalidEmailsApi {
         invalid_emails_api::InvalidEmailsApi::new(self.clone())
     }
 
+    #[cfg(feature = "ip_access_management")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ip_access_management")))]
     pub fn ip_access_management(&self) -> ip_access_management::IpAccessManagement {
         ip_access_management::IpAccessManagement::new(self.clone())
     }
 
+    #[cfg(feature = "ip_addresses")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ip_addresses")))]
     pub fn ip_addresses(&self) -> ip_addresses::IpAddresses {
         ip_addresses::IpAddresses::new(self.clone())
     }
 
+    #[cfg(feature = "ip_pools")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ip_pools")))]
     pub fn ip_pools(&self) -> ip_pools::IpPools {
         ip_pools::IpPools::new(self.clone())
     }
 
+    #[cfg(feature = "ip_warmup")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ip_warmup")))]
     pub fn ip_warmup(&self) -> ip_warmup::IpWarmup {
         ip_warmup::IpWarmup::new(self.clone())
     }
 
+    #[cfg(feature = "link_branding")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "link_branding")))]
     pub fn link_branding(&self) -> link_branding::LinkBranding {
         link_branding::LinkBranding::new(self.clone())
     }
 
+    #[cfg(feature = "lists")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "lists")))]
     pub fn lists(&self) -> lists::Lists {
         lists::Lists::new(self.clone())
     }
 
+    #[cfg(feature = "mail_send")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mail_send")))]
     pub fn mail_send(&self) -> mail_send::MailSend {
         mail_send::MailSend::new(self.clone())
     }
 
+    #[cfg(feature = "marketing_campaigns_stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "marketing_campaigns_stats")))]
     pub fn marketing_campaigns_stats(&self) -> marketing_campaigns_stats::MarketingCampaignsStats {
         marketing_campaigns_stats::MarketingCampaignsStats::new(self.clone())
     }
 
+    #[cfg(feature = "query")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "query")))]
     pub fn query(&self) -> query::Query {
         query::Query::new(self.clone())
     }
 
+    #[cfg(feature = "reverse_dns")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "reverse_dns")))]
     pub fn reverse_dns(&self) -> reverse_dns::ReverseDns {
         reverse_dns::ReverseDns::new(self.clone())
     }
 
+    #[cfg(feature = "segmenting_contacts")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "segmenting_contacts")))]
     pub fn segmenting_contacts(&self) -> segmenting_contacts::SegmentingContacts {
         segmenting_contacts::SegmentingContacts::new(self.clone())
     }
 
+    #[cfg(feature = "segmenting_contacts_beta")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "segmenting_contacts_beta")))]
     pub fn segmenting_contacts_beta(&self) -> segmenting_contacts_beta::SegmentingContactsBeta {
         segmenting_contacts_beta::SegmentingContactsBeta::new(self.clone())
     }
 
+    #[cfg(feature = "send_test_email")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "send_test_email")))]
     pub fn send_test_email(&self) -> send_test_email::SendTestEmail {
         send_test_email::SendTestEmail::new(self.clone())
     }
 
+    #[cfg(feature = "sender_identities_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sender_identities_api")))]
     pub fn sender_identities_api(&self) -> sender_identities_api::SenderIdentitiesApi {
         sender_identities_api::SenderIdentitiesApi::new(self.clone())
     }
 
+    #[cfg(feature = "sender_verification")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "sender_verification")))]
     pub fn sender_verification(&self) -> sender_verification::SenderVerification {
         sender_verification::SenderVerification::new(self.clone())
     }
 
+    #[cfg(feature = "senders")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "senders")))]
     pub fn senders(&self) -> senders::Senders {
         senders::Senders::new(self.clone())
     }
 
+    #[cfg(feature = "settings_enforced_tls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "settings_enforced_tls")))]
     pub fn settings_enforced_tls(&self) -> settings_enforced_tls::SettingsEnforcedTls {
         settings_enforced_tls::SettingsEnforcedTls::new(self.clone())
     }
 
+    #[cfg(feature = "settings_inbound_parse")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "settings_inbound_parse")))]
     pub fn settings_inbound_parse(&self) -> settings_inbound_parse::SettingsInboundParse {
         settings_inbound_parse::SettingsInboundParse::new(self.clone())
     }
 
+    #[cfg(feature = "settings_mail")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "settings_mail")))]
     pub fn settings_mail(&self) -> settings_mail::SettingsMail {
         settings_mail::SettingsMail::new(self.clone())
     }
 
+    #[cfg(feature = "settings_partner")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "settings_partner")))]
     pub fn settings_partner(&self) -> settings_partner::SettingsPartner {
         settings_partner::SettingsPartner::new(self.clone())
     }
 
+    #[cfg(feature = "settings_tracking")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "settings_tracking")))]
     pub fn settings_tracking(&self) -> settings_tracking::SettingsTracking {
         settings_tracking::SettingsTracking::new(self.clone())
     }
 
+    #[cfg(feature = "single_sends")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "single_sends")))]
     pub fn single_sends(&self) -> single_sends::SingleSends {
         single_sends::SingleSends::new(self.clone())
     }
 
+    #[cfg(feature = "single_sign_on_settings")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "single_sign_on_settings")))]
     pub fn single_sign_on_settings(&self) -> single_sign_on_settings::SingleSignOnSettings {
         single_sign_on_settings::SingleSignOnSettings::new(self.clone())
     }
 
+    #[cfg(feature = "single_sign_on_teammates")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "single_sign_on_teammates")))]
     pub fn single_sign_on_teammates(&self) -> single_sign_on_teammates::SingleSignOnTeammates {
         single_sign_on_teammates::SingleSignOnTeammates::new(self.clone())
     }
 
+    #[cfg(feature = "spam_reports_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "spam_reports_api")))]
     pub fn spam_reports_api(&self) -> spam_reports_api::SpamReportsApi {
         spam_reports_api::SpamReportsApi::new(self.clone())
     }
 
+    #[cfg(feature = "stats")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stats")))]
     pub fn stats(&self) -> stats::Stats {
         stats::Stats::new(self.clone())
     }
 
+    #[cfg(feature = "subuser_monitor_settings")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "subuser_monitor_settings")))]
     pub fn subuser_monitor_settings(&self) -> subuser_monitor_settings::SubuserMonitorSettings {
         subuser_monitor_settings::SubuserMonitorSettings::new(self.clone())
     }
 
+    #[cfg(feature = "subuser_statistics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "subuser_statistics")))]
     pub fn subuser_statistics(&self) -> subuser_statistics::SubuserStatistics {
         subuser_statistics::SubuserStatistics::new(self.clone())
     }
 
+    #[cfg(feature = "subusers_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "subusers_api")))]
     pub fn subusers_api(&self) -> subusers_api::SubusersApi {
         subusers_api::SubusersApi::new(self.clone())
     }
 
+    #[cfg(feature = "suppressions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "suppressions")))]
     pub fn suppressions(&self) -> suppressions::Suppressions {
         suppressions::Suppressions::new(self.clone())
     }
 
+    #[cfg(feature = "suppressions_global")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "suppressions_global")))]
     pub fn suppressions_global(&self) -> suppressions_global::SuppressionsGlobal {
         suppressions_global::SuppressionsGlobal::new(self.clone())
     }
 
+    #[cfg(feature = "suppressions_unsubscribe_groups")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "suppressions_unsubscribe_groups")))]
     pub fn suppressions_unsubscribe_groups(
         &self,
     ) -> suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups {
         suppressions_unsubscribe_groups::SuppressionsUnsubscribeGroups::new(self.clone())
     }
 
+    #[cfg(feature = "teammates")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "teammates")))]
     pub fn teammates(&self) -> teammates::Teammates {
         teammates::Teammates::new(self.clone())
     }
 
+    #[cfg(feature = "transactional_templates")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "transactional_templates")))]
     pub fn transactional_templates(&self) -> transactional_templates::TransactionalTemplates {
         transactional_templates::TransactionalTemplates::new(self.clone())
     }
 
+    #[cfg(feature = "transactional_templates_versions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "transactional_templates_versions")))]
     pub fn transactional_templates_versions(
         &self,
     ) -> transactional_templates_versions::TransactionalTemplatesVersions {
         transactional_templates_versions::TransactionalTemplatesVersions::new(self.clone())
     }
 
+    #[cfg(feature = "users_api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "users_api")))]
     pub fn users_api(&self) -> users_api::UsersApi {
         users_api::UsersApi::new(self.clone())
     }
 
+    #[cfg(feature = "webhooks")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "webhooks")))]
     pub fn webhooks(&self) -> webhooks::Webhooks {
         webhooks::Webhooks::new(self.clone())
     }
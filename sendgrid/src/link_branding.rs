@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct LinkBranding {
     pub client: Client,
@@ -23,15 +21,14 @@ impl LinkBranding {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- Limits the number of results returned per page.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &LinkBrandingGetWhitelabelLinksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_whitelabel_links(
+    pub async fn get_whitelabel_links_with(
         &self,
-        limit: i64,
+        params: &LinkBrandingGetWhitelabelLinksParams,
     ) -> Result<Vec<crate::types::LinkBranding200Response>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -40,6 +37,20 @@ impl LinkBranding {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_whitelabel_links_with` and pass the query parameters as a `LinkBrandingGetWhitelabelLinksParams` instead.
+     */
+    #[deprecated(note = "use `get_whitelabel_links_with` instead")]
+    pub async fn get_whitelabel_links(
+        &self,
+        limit: i64,
+    ) -> Result<Vec<crate::types::LinkBranding200Response>> {
+        let params = LinkBrandingGetWhitelabelLinksParams {
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.get_whitelabel_links_with(&params).await
+    }
+
     /**
      * Retrieve all branded links.
      *
@@ -58,6 +69,41 @@ impl LinkBranding {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve all branded links.
+     *
+     * This function performs a `GET` to the `/whitelabel/links` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_links`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve all branded links**.
+     *
+     * You can submit this request as one of your subusers if you include their ID in the `on-behalf-of` header in the request.
+     */
+    pub fn get_whitelabel_links_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LinkBranding200Response> {
+        let url = "/whitelabel/links".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a branded link.
      *
@@ -172,7 +218,10 @@ impl LinkBranding {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_whitelabel_links(&self, id: i64) -> Result<crate::types::Help> {
+    pub async fn delete_whitelabel_links(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::DeleteWhitelabelLinksResponse> {
         let url = format!(
             "/whitelabel/links/{}",
             crate::progenitor_support::encode_path(&id.to_string()),
@@ -226,15 +275,14 @@ impl LinkBranding {
      *
      * **Parameters:**
      *
-     * * `domain: &str` -- The domain to match against when finding the default branded link.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &LinkBrandingGetWhitelabelLinksDefaultParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_whitelabel_links_default(
+    pub async fn get_whitelabel_links_default_with(
         &self,
-        domain: &str,
+        params: &LinkBrandingGetWhitelabelLinksDefaultParams,
     ) -> Result<crate::types::LinkBranding200Response> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !domain.is_empty() {
+        if let Some(domain) = &params.domain {
             query_args.push(("domain".to_string(), domain.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -243,6 +291,20 @@ impl LinkBranding {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_whitelabel_links_default_with` and pass the query parameters as a `LinkBrandingGetWhitelabelLinksDefaultParams` instead.
+     */
+    #[deprecated(note = "use `get_whitelabel_links_default_with` instead")]
+    pub async fn get_whitelabel_links_default(
+        &self,
+        domain: &str,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        let params = LinkBrandingGetWhitelabelLinksDefaultParams {
+            domain: Some(domain.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_whitelabel_links_default_with(&params).await
+    }
+
     /**
      * Retrieve a subuser's branded link.
      *
@@ -254,14 +316,14 @@ impl LinkBranding {
      *
      * **Parameters:**
      *
-     * * `username: &str` -- The username of the subuser to retrieve associated branded links for.
+     * * `params: &LinkBrandingGetWhitelabelLinksSubuserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_whitelabel_links_subuser(
+    pub async fn get_whitelabel_links_subuser_with(
         &self,
-        username: &str,
+        params: &LinkBrandingGetWhitelabelLinksSubuserParams,
     ) -> Result<crate::types::LinkBranding200Response> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !username.is_empty() {
+        if let Some(username) = &params.username {
             query_args.push(("username".to_string(), username.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -270,6 +332,20 @@ impl LinkBranding {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_whitelabel_links_subuser_with` and pass the query parameters as a `LinkBrandingGetWhitelabelLinksSubuserParams` instead.
+     */
+    #[deprecated(note = "use `get_whitelabel_links_subuser_with` instead")]
+    pub async fn get_whitelabel_links_subuser(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        let params = LinkBrandingGetWhitelabelLinksSubuserParams {
+            username: Some(username.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_whitelabel_links_subuser_with(&params).await
+    }
+
     /**
      * Disassociate a branded link from a subuser.
      *
@@ -283,14 +359,14 @@ impl LinkBranding {
      *
      * **Parameters:**
      *
-     * * `username: &str` -- The username of the subuser account that you want to disassociate a branded link from.
+     * * `params: &LinkBrandingDeleteWhitelabelLinksSubuserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_whitelabel_links_subuser(
+    pub async fn delete_whitelabel_links_subuser_with(
         &self,
-        username: &str,
-    ) -> Result<crate::types::Help> {
+        params: &LinkBrandingDeleteWhitelabelLinksSubuserParams,
+    ) -> Result<crate::types::DeleteWhitelabelLinksSubuserResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !username.is_empty() {
+        if let Some(username) = &params.username {
             query_args.push(("username".to_string(), username.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -298,4 +374,239 @@ impl LinkBranding {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Deprecated: use `delete_whitelabel_links_subuser_with` and pass the query parameters as a `LinkBrandingDeleteWhitelabelLinksSubuserParams` instead.
+     */
+    #[deprecated(note = "use `delete_whitelabel_links_subuser_with` instead")]
+    pub async fn delete_whitelabel_links_subuser(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::DeleteWhitelabelLinksSubuserResponse> {
+        let params = LinkBrandingDeleteWhitelabelLinksSubuserParams {
+            username: Some(username.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_whitelabel_links_subuser_with(&params).await
+    }
+}
+
+/// The query parameters of `LinkBranding::get_whitelabel_links_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct LinkBrandingGetWhitelabelLinksParams {
+    /// Limits the number of results returned per page.
+    pub limit: Option<i64>,
+}
+
+impl LinkBrandingGetWhitelabelLinksParams {
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `LinkBranding::get_whitelabel_links_default_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct LinkBrandingGetWhitelabelLinksDefaultParams {
+    /// The domain to match against when finding the default branded link.
+    pub domain: Option<String>,
+}
+
+impl LinkBrandingGetWhitelabelLinksDefaultParams {
+    pub fn domain<T: ToString>(mut self, domain: T) -> Self {
+        self.domain = Some(domain.to_string());
+        self
+    }
+}
+
+/// The query parameters of `LinkBranding::get_whitelabel_links_subuser_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct LinkBrandingGetWhitelabelLinksSubuserParams {
+    /// The username of the subuser to retrieve associated branded links for.
+    pub username: Option<String>,
+}
+
+impl LinkBrandingGetWhitelabelLinksSubuserParams {
+    pub fn username<T: ToString>(mut self, username: T) -> Self {
+        self.username = Some(username.to_string());
+        self
+    }
+}
+
+/// The query parameters of `LinkBranding::delete_whitelabel_links_subuser_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct LinkBrandingDeleteWhitelabelLinksSubuserParams {
+    /// The username of the subuser account that you want to disassociate a branded link from.
+    pub username: Option<String>,
+}
+
+impl LinkBrandingDeleteWhitelabelLinksSubuserParams {
+    pub fn username<T: ToString>(mut self, username: T) -> Self {
+        self.username = Some(username.to_string());
+        self
+    }
+}
+
+/// The functions of [`LinkBranding`], for code that takes them as a trait, so that it can be
+/// tested with the `MockLinkBrandingApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait LinkBrandingApi: Send + Sync {
+    /// See [`LinkBranding::get_whitelabel_links_with`].
+    async fn get_whitelabel_links_with(
+        &self,
+        params: &LinkBrandingGetWhitelabelLinksParams,
+    ) -> Result<Vec<crate::types::LinkBranding200Response>>;
+
+    /// See [`LinkBranding::get_all_whitelabel_links`].
+    async fn get_all_whitelabel_links(&self) -> Result<Vec<crate::types::LinkBranding200Response>>;
+
+    /// See [`LinkBranding::get_whitelabel_links_stream`].
+    fn get_whitelabel_links_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LinkBranding200Response>;
+
+    /// See [`LinkBranding::post_whitelabel_link`].
+    async fn post_whitelabel_link(
+        &self,
+        body: &crate::types::PostWhitelabelLinksRequest,
+    ) -> Result<crate::types::LinkBranding200Response>;
+
+    /// See [`LinkBranding::post_whitelabel_links_validate`].
+    async fn post_whitelabel_links_validate(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::PostWhitelabelLinksValidateResponse>;
+
+    /// See [`LinkBranding::post_whitelabel_links_link_subuser`].
+    async fn post_whitelabel_links_link_subuser(
+        &self,
+        link_id: i64,
+        body: &crate::types::PostWhitelabelLinksLinkSubuserRequest,
+    ) -> Result<crate::types::LinkBranding200Response>;
+
+    /// See [`LinkBranding::get_whitelabel_links_link_branding`].
+    async fn get_whitelabel_links_link_branding(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::LinkBranding200Response>;
+
+    /// See [`LinkBranding::delete_whitelabel_links`].
+    async fn delete_whitelabel_links(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::DeleteWhitelabelLinksResponse>;
+
+    /// See [`LinkBranding::patch_whitelabel_links`].
+    async fn patch_whitelabel_links(
+        &self,
+        id: i64,
+        body: &crate::types::PatchWhitelabelLinksRequest,
+    ) -> Result<crate::types::LinkBranding200Response>;
+
+    /// See [`LinkBranding::get_whitelabel_links_default_with`].
+    async fn get_whitelabel_links_default_with(
+        &self,
+        params: &LinkBrandingGetWhitelabelLinksDefaultParams,
+    ) -> Result<crate::types::LinkBranding200Response>;
+
+    /// See [`LinkBranding::get_whitelabel_links_subuser_with`].
+    async fn get_whitelabel_links_subuser_with(
+        &self,
+        params: &LinkBrandingGetWhitelabelLinksSubuserParams,
+    ) -> Result<crate::types::LinkBranding200Response>;
+
+    /// See [`LinkBranding::delete_whitelabel_links_subuser_with`].
+    async fn delete_whitelabel_links_subuser_with(
+        &self,
+        params: &LinkBrandingDeleteWhitelabelLinksSubuserParams,
+    ) -> Result<crate::types::DeleteWhitelabelLinksSubuserResponse>;
+}
+
+#[async_trait::async_trait]
+impl LinkBrandingApi for LinkBranding {
+    async fn get_whitelabel_links_with(
+        &self,
+        params: &LinkBrandingGetWhitelabelLinksParams,
+    ) -> Result<Vec<crate::types::LinkBranding200Response>> {
+        LinkBranding::get_whitelabel_links_with(self, params).await
+    }
+
+    async fn get_all_whitelabel_links(&self) -> Result<Vec<crate::types::LinkBranding200Response>> {
+        LinkBranding::get_all_whitelabel_links(self).await
+    }
+
+    fn get_whitelabel_links_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LinkBranding200Response> {
+        LinkBranding::get_whitelabel_links_stream(self, start, max_items)
+    }
+
+    async fn post_whitelabel_link(
+        &self,
+        body: &crate::types::PostWhitelabelLinksRequest,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        LinkBranding::post_whitelabel_link(self, body).await
+    }
+
+    async fn post_whitelabel_links_validate(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::PostWhitelabelLinksValidateResponse> {
+        LinkBranding::post_whitelabel_links_validate(self, id).await
+    }
+
+    async fn post_whitelabel_links_link_subuser(
+        &self,
+        link_id: i64,
+        body: &crate::types::PostWhitelabelLinksLinkSubuserRequest,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        LinkBranding::post_whitelabel_links_link_subuser(self, link_id, body).await
+    }
+
+    async fn get_whitelabel_links_link_branding(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        LinkBranding::get_whitelabel_links_link_branding(self, id).await
+    }
+
+    async fn delete_whitelabel_links(
+        &self,
+        id: i64,
+    ) -> Result<crate::types::DeleteWhitelabelLinksResponse> {
+        LinkBranding::delete_whitelabel_links(self, id).await
+    }
+
+    async fn patch_whitelabel_links(
+        &self,
+        id: i64,
+        body: &crate::types::PatchWhitelabelLinksRequest,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        LinkBranding::patch_whitelabel_links(self, id, body).await
+    }
+
+    async fn get_whitelabel_links_default_with(
+        &self,
+        params: &LinkBrandingGetWhitelabelLinksDefaultParams,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        LinkBranding::get_whitelabel_links_default_with(self, params).await
+    }
+
+    async fn get_whitelabel_links_subuser_with(
+        &self,
+        params: &LinkBrandingGetWhitelabelLinksSubuserParams,
+    ) -> Result<crate::types::LinkBranding200Response> {
+        LinkBranding::get_whitelabel_links_subuser_with(self, params).await
+    }
+
+    async fn delete_whitelabel_links_subuser_with(
+        &self,
+        params: &LinkBrandingDeleteWhitelabelLinksSubuserParams,
+    ) -> Result<crate::types::DeleteWhitelabelLinksSubuserResponse> {
+        LinkBranding::delete_whitelabel_links_subuser_with(self, params).await
+    }
 }
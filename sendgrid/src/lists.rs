@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Lists {
     pub client: Client,
@@ -21,19 +19,17 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `page_size: f64` -- Maximum number of elements to return. Defaults to 100, returns 1000 max.
-     * * `page_token: &str` -- The license key provided with your New Relic account.
+     * * `params: &ListsGetMcParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_mc(
+    pub async fn get_mc_with(
         &self,
-        page_size: f64,
-        page_token: &str,
+        params: &ListsGetMcParams,
     ) -> Result<crate::types::GetMcListsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !page_size.to_string().is_empty() {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("page_token".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -42,6 +38,22 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_mc_with` and pass the query parameters as a `ListsGetMcParams` instead.
+     */
+    #[deprecated(note = "use `get_mc_with` instead")]
+    pub async fn get_mc(
+        &self,
+        page_size: f64,
+        page_token: &str,
+    ) -> Result<crate::types::GetMcListsResponse> {
+        let params = ListsGetMcParams {
+            page_size: Some(page_size).filter(|v| !v.to_string().is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_mc_with(&params).await
+    }
+
     /**
      * Create List.
      *
@@ -53,7 +65,10 @@ impl Lists {
      *
      * A link to the newly created object is in `_metadata`.
      */
-    pub async fn post_mc(&self, body: &crate::types::IpPool) -> Result<crate::types::List> {
+    pub async fn post_mc(
+        &self,
+        body: &crate::types::PostMcListsRequest,
+    ) -> Result<crate::types::List> {
         let url = "/marketing/lists".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -92,15 +107,15 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `contact_sample: bool` -- Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * * `params: &ListsGetMcListsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_mc_lists(
+    pub async fn get_mc_lists_with(
         &self,
         id: &str,
-        contact_sample: bool,
+        params: &ListsGetMcListsParams,
     ) -> Result<crate::types::GetMcListsResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if contact_sample {
+        if let Some(contact_sample) = &params.contact_sample {
             query_args.push(("contact_sample".to_string(), contact_sample.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -113,6 +128,21 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_mc_lists_with` and pass the query parameters as a `ListsGetMcListsParams` instead.
+     */
+    #[deprecated(note = "use `get_mc_lists_with` instead")]
+    pub async fn get_mc_lists(
+        &self,
+        id: &str,
+        contact_sample: bool,
+    ) -> Result<crate::types::GetMcListsResponseAllOf> {
+        let params = ListsGetMcListsParams {
+            contact_sample: Some(contact_sample).filter(|v| *v),
+        };
+        self.get_mc_lists_with(id, &params).await
+    }
+
     /**
      * Delete a list.
      *
@@ -124,15 +154,15 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `delete_contacts: bool` -- Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * * `params: &ListsDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete(
+    pub async fn delete_with(
         &self,
         id: &str,
-        delete_contacts: bool,
+        params: &ListsDeleteParams,
     ) -> Result<crate::types::DeleteListsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if delete_contacts {
+        if let Some(delete_contacts) = &params.delete_contacts {
             query_args.push(("delete_contacts".to_string(), delete_contacts.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -145,6 +175,21 @@ impl Lists {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_with` and pass the query parameters as a `ListsDeleteParams` instead.
+     */
+    #[deprecated(note = "use `delete_with` instead")]
+    pub async fn delete(
+        &self,
+        id: &str,
+        delete_contacts: bool,
+    ) -> Result<crate::types::DeleteListsResponse> {
+        let params = ListsDeleteParams {
+            delete_contacts: Some(delete_contacts).filter(|v| *v),
+        };
+        self.delete_with(id, &params).await
+    }
+
     /**
      * Update List.
      *
@@ -178,15 +223,15 @@ impl Lists {
      *
      * **Parameters:**
      *
-     * * `contact_ids: &str` -- The license key provided with your New Relic account.
+     * * `params: &ListsDeleteMcContactsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_mc_contacts(
+    pub async fn delete_mc_contacts_with(
         &self,
         id: &str,
-        contact_ids: &str,
+        params: &ListsDeleteMcContactsParams,
     ) -> Result<crate::types::DeleteMcListsContactsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !contact_ids.is_empty() {
+        if let Some(contact_ids) = &params.contact_ids {
             query_args.push(("contact_ids".to_string(), contact_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -198,4 +243,184 @@ impl Lists {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Deprecated: use `delete_mc_contacts_with` and pass the query parameters as a `ListsDeleteMcContactsParams` instead.
+     */
+    #[deprecated(note = "use `delete_mc_contacts_with` instead")]
+    pub async fn delete_mc_contacts(
+        &self,
+        id: &str,
+        contact_ids: &str,
+    ) -> Result<crate::types::DeleteMcListsContactsResponse> {
+        let params = ListsDeleteMcContactsParams {
+            contact_ids: Some(contact_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_mc_contacts_with(id, &params).await
+    }
+}
+
+/// The query parameters of `Lists::get_mc_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetMcParams {
+    /// Maximum number of elements to return. Defaults to 100, returns 1000 max.
+    pub page_size: Option<f64>,
+    /// The license key provided with your New Relic account.
+    pub page_token: Option<String>,
+}
+
+impl ListsGetMcParams {
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Lists::get_mc_lists_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsGetMcListsParams {
+    /// Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+    pub contact_sample: Option<bool>,
+}
+
+impl ListsGetMcListsParams {
+    pub fn contact_sample(mut self, contact_sample: bool) -> Self {
+        self.contact_sample = Some(contact_sample);
+        self
+    }
+}
+
+/// The query parameters of `Lists::delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsDeleteParams {
+    /// Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+    pub delete_contacts: Option<bool>,
+}
+
+impl ListsDeleteParams {
+    pub fn delete_contacts(mut self, delete_contacts: bool) -> Self {
+        self.delete_contacts = Some(delete_contacts);
+        self
+    }
+}
+
+/// The query parameters of `Lists::delete_mc_contacts_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ListsDeleteMcContactsParams {
+    /// The license key provided with your New Relic account.
+    pub contact_ids: Option<String>,
+}
+
+impl ListsDeleteMcContactsParams {
+    pub fn contact_ids<T: ToString>(mut self, contact_ids: T) -> Self {
+        self.contact_ids = Some(contact_ids.to_string());
+        self
+    }
+}
+
+/// The functions of [`Lists`], for code that takes them as a trait, so that it can be
+/// tested with the `MockListsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ListsApi: Send + Sync {
+    /// See [`Lists::get_mc_with`].
+    async fn get_mc_with(
+        &self,
+        params: &ListsGetMcParams,
+    ) -> Result<crate::types::GetMcListsResponse>;
+
+    /// See [`Lists::post_mc`].
+    async fn post_mc(&self, body: &crate::types::PostMcListsRequest) -> Result<crate::types::List>;
+
+    /// See [`Lists::get_mc_contacts_count`].
+    async fn get_mc_contacts_count(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::GetMcListsContactsCountResponse>;
+
+    /// See [`Lists::get_mc_lists_with`].
+    async fn get_mc_lists_with(
+        &self,
+        id: &str,
+        params: &ListsGetMcListsParams,
+    ) -> Result<crate::types::GetMcListsResponseAllOf>;
+
+    /// See [`Lists::delete_with`].
+    async fn delete_with(
+        &self,
+        id: &str,
+        params: &ListsDeleteParams,
+    ) -> Result<crate::types::DeleteListsResponse>;
+
+    /// See [`Lists::patch_mc`].
+    async fn patch_mc(
+        &self,
+        id: &str,
+        body: &crate::types::PatchMcListsRequest,
+    ) -> Result<crate::types::List>;
+
+    /// See [`Lists::delete_mc_contacts_with`].
+    async fn delete_mc_contacts_with(
+        &self,
+        id: &str,
+        params: &ListsDeleteMcContactsParams,
+    ) -> Result<crate::types::DeleteMcListsContactsResponse>;
+}
+
+#[async_trait::async_trait]
+impl ListsApi for Lists {
+    async fn get_mc_with(
+        &self,
+        params: &ListsGetMcParams,
+    ) -> Result<crate::types::GetMcListsResponse> {
+        Lists::get_mc_with(self, params).await
+    }
+
+    async fn post_mc(&self, body: &crate::types::PostMcListsRequest) -> Result<crate::types::List> {
+        Lists::post_mc(self, body).await
+    }
+
+    async fn get_mc_contacts_count(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::GetMcListsContactsCountResponse> {
+        Lists::get_mc_contacts_count(self, id).await
+    }
+
+    async fn get_mc_lists_with(
+        &self,
+        id: &str,
+        params: &ListsGetMcListsParams,
+    ) -> Result<crate::types::GetMcListsResponseAllOf> {
+        Lists::get_mc_lists_with(self, id, params).await
+    }
+
+    async fn delete_with(
+        &self,
+        id: &str,
+        params: &ListsDeleteParams,
+    ) -> Result<crate::types::DeleteListsResponse> {
+        Lists::delete_with(self, id, params).await
+    }
+
+    async fn patch_mc(
+        &self,
+        id: &str,
+        body: &crate::types::PatchMcListsRequest,
+    ) -> Result<crate::types::List> {
+        Lists::patch_mc(self, id, body).await
+    }
+
+    async fn delete_mc_contacts_with(
+        &self,
+        id: &str,
+        params: &ListsDeleteMcContactsParams,
+    ) -> Result<crate::types::DeleteMcListsContactsResponse> {
+        Lists::delete_mc_contacts_with(self, id, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct MailSend {
     pub client: Client,
@@ -71,3 +69,19 @@ impl MailSend {
             .await
     }
 }
+
+/// The functions of [`MailSend`], for code that takes them as a trait, so that it can be
+/// tested with the `MockMailSendApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait MailSendApi: Send + Sync {
+    /// See [`MailSend::post`].
+    async fn post(&self, body: &crate::types::PostMailSendRequest) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl MailSendApi for MailSend {
+    async fn post(&self, body: &crate::types::PostMailSendRequest) -> Result<()> {
+        MailSend::post(self, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct MarketingCampaignsStats {
     pub client: Client,
@@ -27,24 +25,20 @@ impl MarketingCampaignsStats {
      *
      * **Parameters:**
      *
-     * * `automation_ids: &[String]` -- This endpoint returns all automation IDs if no `automation_ids` are specified.
-     * * `page_size: i64` -- The number of elements you want returned on each page.
-     * * `page_token: &str` -- The stats endpoints are paginated. To get the next page, call the passed `_metadata.next` URL. If `_metadata.prev` doesn't exist, you're at the first page. Similarly, if `_metadata.next` is not present, you're at the last page.
+     * * `params: &MarketingCampaignsStatsGetallAutomationStatsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn getall_automation_stats(
+    pub async fn getall_automation_stats_with(
         &self,
-        automation_ids: &[String],
-        page_size: i64,
-        page_token: &str,
+        params: &MarketingCampaignsStatsGetallAutomationStatsParams,
     ) -> Result<crate::types::AutomationsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !automation_ids.is_empty() {
+        if let Some(automation_ids) = &params.automation_ids {
             query_args.push(("automation_ids".to_string(), automation_ids.join(" ")));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("page_token".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -53,6 +47,24 @@ impl MarketingCampaignsStats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `getall_automation_stats_with` and pass the query parameters as a `MarketingCampaignsStatsGetallAutomationStatsParams` instead.
+     */
+    #[deprecated(note = "use `getall_automation_stats_with` instead")]
+    pub async fn getall_automation_stats(
+        &self,
+        automation_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::AutomationsResponse> {
+        let params = MarketingCampaignsStatsGetallAutomationStatsParams {
+            automation_ids: Some(automation_ids.to_vec()).filter(|v| !v.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.getall_automation_stats_with(&params).await
+    }
+
     /**
      * Get Automation Stats by ID.
      *
@@ -66,50 +78,36 @@ impl MarketingCampaignsStats {
      *
      * **Parameters:**
      *
-     * * `group_by: &[String]` -- Automations can have multiple steps. Including `step_id` as a `group_by` metric allows further granularity of stats.
-     * * `step_ids: &[String]` -- The recipient IDs of the recipients that already existed from this request.
-     * * `aggregated_by: crate::types::AggregatedBy` -- Dictates how the stats are time-sliced. Currently, `"total"` and `"day"` are supported.
-     * * `start_date: chrono::NaiveDate` -- Format: `YYYY-MM-DD`. If this parameter is included, the stats' start date is included in the search.
-     * * `end_date: chrono::NaiveDate` -- Format: `YYYY-MM-DD`.If this parameter is included, the stats' end date is included in the search.
-     * * `timezone: &str` -- [IANA Area/Region](https://en.wikipedia.org/wiki/Tz_database#Names_of_time_zones) string representing the timezone in which the stats are to be presented, e.g., "America/Chicago".
-     * * `page_size: i64` -- The number of elements you want returned on each page.
-     * * `page_token: &str` -- The stats endpoints are paginated. To get the next page, call the passed `_metadata.next` URL. If `_metadata.prev` doesn't exist, you're at the first page. Similarly, if `_metadata.next` is not present, you're at the last page.
+     * * `params: &MarketingCampaignsStatsGetAutomationStatParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_automation_stat(
+    pub async fn get_automation_stat_with(
         &self,
         id: &str,
-        group_by: &[String],
-        step_ids: &[String],
-        aggregated_by: crate::types::AggregatedBy,
-        start_date: chrono::NaiveDate,
-        end_date: chrono::NaiveDate,
-        timezone: &str,
-        page_size: i64,
-        page_token: &str,
+        params: &MarketingCampaignsStatsGetAutomationStatParams,
     ) -> Result<crate::types::AutomationsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.to_string().is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !group_by.is_empty() {
+        if let Some(group_by) = &params.group_by {
             query_args.push(("group_by".to_string(), group_by.join(" ")));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("page_token".to_string(), page_token.to_string()));
         }
-        if !start_date.to_string().is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
-        if !step_ids.is_empty() {
+        if let Some(step_ids) = &params.step_ids {
             query_args.push(("step_ids".to_string(), step_ids.join(" ")));
         }
-        if !timezone.is_empty() {
+        if let Some(timezone) = &params.timezone {
             query_args.push(("timezone".to_string(), timezone.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -122,6 +120,35 @@ impl MarketingCampaignsStats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_automation_stat_with` and pass the query parameters as a `MarketingCampaignsStatsGetAutomationStatParams` instead.
+     */
+    #[deprecated(note = "use `get_automation_stat_with` instead")]
+    pub async fn get_automation_stat(
+        &self,
+        id: &str,
+        group_by: &[String],
+        step_ids: &[String],
+        aggregated_by: crate::types::AggregatedBy,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        timezone: &str,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::AutomationsResponse> {
+        let params = MarketingCampaignsStatsGetAutomationStatParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date).filter(|v| !v.to_string().is_empty()),
+            group_by: Some(group_by.to_vec()).filter(|v| !v.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date).filter(|v| !v.to_string().is_empty()),
+            step_ids: Some(step_ids.to_vec()).filter(|v| !v.is_empty()),
+            timezone: Some(timezone.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_automation_stat_with(id, &params).await
+    }
+
     /**
      * Get All Single Sends Stats.
      *
@@ -137,24 +164,20 @@ impl MarketingCampaignsStats {
      *
      * **Parameters:**
      *
-     * * `singlesend_ids: &[String]` -- This endpoint returns all Single Send IDs if no IDs are included in `singlesend_ids`.
-     * * `page_size: i64` -- The number of elements you want returned on each page.
-     * * `page_token: &str` -- The stats endpoints are paginated. To get the next page, call the passed `_metadata.next` URL. If `_metadata.prev` doesn't exist, you're at the first page. Similarly, if `_metadata.next` is not present, you're at the last page.
+     * * `params: &MarketingCampaignsStatsGetallSinglesendStatsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn getall_singlesend_stats(
+    pub async fn getall_singlesend_stats_with(
         &self,
-        singlesend_ids: &[String],
-        page_size: i64,
-        page_token: &str,
+        params: &MarketingCampaignsStatsGetallSinglesendStatsParams,
     ) -> Result<crate::types::SinglesendsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("page_token".to_string(), page_token.to_string()));
         }
-        if !singlesend_ids.is_empty() {
+        if let Some(singlesend_ids) = &params.singlesend_ids {
             query_args.push(("singlesend_ids".to_string(), singlesend_ids.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -163,6 +186,24 @@ impl MarketingCampaignsStats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `getall_singlesend_stats_with` and pass the query parameters as a `MarketingCampaignsStatsGetallSinglesendStatsParams` instead.
+     */
+    #[deprecated(note = "use `getall_singlesend_stats_with` instead")]
+    pub async fn getall_singlesend_stats(
+        &self,
+        singlesend_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::SinglesendsResponse> {
+        let params = MarketingCampaignsStatsGetallSinglesendStatsParams {
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            singlesend_ids: Some(singlesend_ids.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.getall_singlesend_stats_with(&params).await
+    }
+
     /**
      * Get Single Send Stats by ID.
      *
@@ -176,45 +217,33 @@ impl MarketingCampaignsStats {
      *
      * **Parameters:**
      *
-     * * `aggregated_by: crate::types::AggregatedBy` -- Dictates how the stats are time-sliced. Currently, `"total"` and `"day"` are supported.
-     * * `start_date: chrono::NaiveDate` -- Format: `YYYY-MM-DD`. If this parameter is included, the stats' start date is included in the search.
-     * * `end_date: chrono::NaiveDate` -- Format: `YYYY-MM-DD`.If this parameter is included, the stats' end date is included in the search.
-     * * `timezone: &str` -- [IANA Area/Region](https://en.wikipedia.org/wiki/Tz_database#Names_of_time_zones) string representing the timezone in which the stats are to be presented, e.g., "America/Chicago".
-     * * `page_size: i64` -- The number of elements you want returned on each page.
-     * * `page_token: &str` -- The stats endpoints are paginated. To get the next page, call the passed `_metadata.next` URL. If `_metadata.prev` doesn't exist, you're at the first page. Similarly, if `_metadata.next` is not present, you're at the last page.
-     * * `group_by: &[String]` -- A/B Single Sends have multiple variation IDs and phase IDs. Including these additional fields allows further granularity of stats by these fields.
+     * * `params: &MarketingCampaignsStatsGetSinglesendStatParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_singlesend_stat(
+    pub async fn get_singlesend_stat_with(
         &self,
         id: &str,
-        aggregated_by: crate::types::AggregatedBy,
-        start_date: chrono::NaiveDate,
-        end_date: chrono::NaiveDate,
-        timezone: &str,
-        page_size: i64,
-        page_token: &str,
-        group_by: &[String],
+        params: &MarketingCampaignsStatsGetSinglesendStatParams,
     ) -> Result<crate::types::SinglesendsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.to_string().is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !group_by.is_empty() {
+        if let Some(group_by) = &params.group_by {
             query_args.push(("group_by".to_string(), group_by.join(" ")));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("page_token".to_string(), page_token.to_string()));
         }
-        if !start_date.to_string().is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
-        if !timezone.is_empty() {
+        if let Some(timezone) = &params.timezone {
             query_args.push(("timezone".to_string(), timezone.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -227,6 +256,33 @@ impl MarketingCampaignsStats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_singlesend_stat_with` and pass the query parameters as a `MarketingCampaignsStatsGetSinglesendStatParams` instead.
+     */
+    #[deprecated(note = "use `get_singlesend_stat_with` instead")]
+    pub async fn get_singlesend_stat(
+        &self,
+        id: &str,
+        aggregated_by: crate::types::AggregatedBy,
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+        timezone: &str,
+        page_size: i64,
+        page_token: &str,
+        group_by: &[String],
+    ) -> Result<crate::types::SinglesendsResponse> {
+        let params = MarketingCampaignsStatsGetSinglesendStatParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date).filter(|v| !v.to_string().is_empty()),
+            group_by: Some(group_by.to_vec()).filter(|v| !v.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date).filter(|v| !v.to_string().is_empty()),
+            timezone: Some(timezone.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_singlesend_stat_with(id, &params).await
+    }
+
     /**
      * Get Automation Click Tracking Stats by ID.
      *
@@ -242,30 +298,24 @@ impl MarketingCampaignsStats {
      *
      * **Parameters:**
      *
-     * * `group_by: &[String]` -- Automations can have multiple steps. Including `step_id` as a `group_by` metric allows further granularity of stats.
-     * * `step_ids: &[String]` -- The recipient IDs of the recipients that already existed from this request.
-     * * `page_size: i64` -- The number of elements you want returned on each page.
-     * * `page_token: &str` -- The stats endpoints are paginated. To get the next page, call the passed `_metadata.next` URL. If `_metadata.prev` doesn't exist, you're at the first page. Similarly, if `_metadata.next` is not present, you're at the last page.
+     * * `params: &MarketingCampaignsStatsGetAutomationLinkStatParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_automation_link_stat(
+    pub async fn get_automation_link_stat_with(
         &self,
         id: &str,
-        group_by: &[String],
-        step_ids: &[String],
-        page_size: i64,
-        page_token: &str,
+        params: &MarketingCampaignsStatsGetAutomationLinkStatParams,
     ) -> Result<crate::types::AutomationsLinkStatsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !group_by.is_empty() {
+        if let Some(group_by) = &params.group_by {
             query_args.push(("group_by".to_string(), group_by.join(" ")));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("page_token".to_string(), page_token.to_string()));
         }
-        if !step_ids.is_empty() {
+        if let Some(step_ids) = &params.step_ids {
             query_args.push(("step_ids".to_string(), step_ids.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -278,6 +328,27 @@ impl MarketingCampaignsStats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_automation_link_stat_with` and pass the query parameters as a `MarketingCampaignsStatsGetAutomationLinkStatParams` instead.
+     */
+    #[deprecated(note = "use `get_automation_link_stat_with` instead")]
+    pub async fn get_automation_link_stat(
+        &self,
+        id: &str,
+        group_by: &[String],
+        step_ids: &[String],
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::AutomationsLinkStatsResponse> {
+        let params = MarketingCampaignsStatsGetAutomationLinkStatParams {
+            group_by: Some(group_by.to_vec()).filter(|v| !v.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+            step_ids: Some(step_ids.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_automation_link_stat_with(id, &params).await
+    }
+
     /**
      * Get Single Send Click Tracking Stats by ID.
      *
@@ -293,35 +364,27 @@ impl MarketingCampaignsStats {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- The number of elements you want returned on each page.
-     * * `page_token: &str` -- The stats endpoints are paginated. To get the next page, call the passed `_metadata.next` URL. If `_metadata.prev` doesn't exist, you're at the first page. Similarly, if `_metadata.next` is not present, you're at the last page.
-     * * `group_by: &[String]` -- A/B Single Sends have multiple variation IDs and phase IDs. Including these additional fields allows further granularity of stats by these fields.
-     * * `ab_variation_id: &str` -- The license key provided with your New Relic account.
-     * * `ab_phase_id: crate::types::AbPhaseId`
+     * * `params: &MarketingCampaignsStatsGetSinglesendLinkStatParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_singlesend_link_stat(
+    pub async fn get_singlesend_link_stat_with(
         &self,
         id: &str,
-        page_size: i64,
-        page_token: &str,
-        group_by: &[String],
-        ab_variation_id: &str,
-        ab_phase_id: crate::types::AbPhaseId,
+        params: &MarketingCampaignsStatsGetSinglesendLinkStatParams,
     ) -> Result<crate::types::SinglesendsLinkStatsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ab_phase_id.to_string().is_empty() {
+        if let Some(ab_phase_id) = &params.ab_phase_id {
             query_args.push(("ab_phase_id".to_string(), ab_phase_id.to_string()));
         }
-        if !ab_variation_id.is_empty() {
+        if let Some(ab_variation_id) = &params.ab_variation_id {
             query_args.push(("ab_variation_id".to_string(), ab_variation_id.to_string()));
         }
-        if !group_by.is_empty() {
+        if let Some(group_by) = &params.group_by {
             query_args.push(("group_by".to_string(), group_by.join(" ")));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("page_token".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -334,6 +397,29 @@ impl MarketingCampaignsStats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_singlesend_link_stat_with` and pass the query parameters as a `MarketingCampaignsStatsGetSinglesendLinkStatParams` instead.
+     */
+    #[deprecated(note = "use `get_singlesend_link_stat_with` instead")]
+    pub async fn get_singlesend_link_stat(
+        &self,
+        id: &str,
+        page_size: i64,
+        page_token: &str,
+        group_by: &[String],
+        ab_variation_id: &str,
+        ab_phase_id: crate::types::AbPhaseId,
+    ) -> Result<crate::types::SinglesendsLinkStatsResponse> {
+        let params = MarketingCampaignsStatsGetSinglesendLinkStatParams {
+            ab_phase_id: Some(ab_phase_id).filter(|v| !v.to_string().is_empty()),
+            ab_variation_id: Some(ab_variation_id.to_string()).filter(|s| !s.is_empty()),
+            group_by: Some(group_by.to_vec()).filter(|v| !v.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_singlesend_link_stat_with(id, &params).await
+    }
+
     /**
      * Export Single Send Stats.
      *
@@ -347,19 +433,17 @@ impl MarketingCampaignsStats {
      *
      * **Parameters:**
      *
-     * * `ids: &[String]` -- The recipient IDs of the recipients that already existed from this request.
-     * * `timezone: &str` -- The [IANA Area/Region](https://en.wikipedia.org/wiki/Tz_database#Names_of_time_zones) string representing the timezone in which the stats are to be presented; i.e. `"America/Chicago"`. This parameter changes the timezone format only; it does not alter which stats are returned.
+     * * `params: &MarketingCampaignsStatsGetSinglesendStatsExportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_singlesend_stats_export(
+    pub async fn get_singlesend_stats_export_with(
         &self,
-        ids: &[String],
-        timezone: &str,
+        params: &MarketingCampaignsStatsGetSinglesendStatsExportParams,
     ) -> Result<String> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.join(" ")));
         }
-        if !timezone.is_empty() {
+        if let Some(timezone) = &params.timezone {
             query_args.push(("timezone".to_string(), timezone.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -368,6 +452,22 @@ impl MarketingCampaignsStats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_singlesend_stats_export_with` and pass the query parameters as a `MarketingCampaignsStatsGetSinglesendStatsExportParams` instead.
+     */
+    #[deprecated(note = "use `get_singlesend_stats_export_with` instead")]
+    pub async fn get_singlesend_stats_export(
+        &self,
+        ids: &[String],
+        timezone: &str,
+    ) -> Result<String> {
+        let params = MarketingCampaignsStatsGetSinglesendStatsExportParams {
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            timezone: Some(timezone.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_singlesend_stats_export_with(&params).await
+    }
+
     /**
      * Export Automation Stats.
      *
@@ -381,19 +481,17 @@ impl MarketingCampaignsStats {
      *
      * **Parameters:**
      *
-     * * `ids: &[String]` -- The recipient IDs of the recipients that already existed from this request.
-     * * `timezone: &str` -- The [IANA Area/Region](https://en.wikipedia.org/wiki/Tz_database#Names_of_time_zones) string representing the timezone in which the stats are to be presented; i.e. `"America/Chicago"`. This parameter changes the timezone format only; it does not alter which stats are returned.
+     * * `params: &MarketingCampaignsStatsGetAutomationsStatsExportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_automations_stats_export(
+    pub async fn get_automations_stats_export_with(
         &self,
-        ids: &[String],
-        timezone: &str,
+        params: &MarketingCampaignsStatsGetAutomationsStatsExportParams,
     ) -> Result<String> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.join(" ")));
         }
-        if !timezone.is_empty() {
+        if let Some(timezone) = &params.timezone {
             query_args.push(("timezone".to_string(), timezone.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -401,4 +499,434 @@ impl MarketingCampaignsStats {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_automations_stats_export_with` and pass the query parameters as a `MarketingCampaignsStatsGetAutomationsStatsExportParams` instead.
+     */
+    #[deprecated(note = "use `get_automations_stats_export_with` instead")]
+    pub async fn get_automations_stats_export(
+        &self,
+        ids: &[String],
+        timezone: &str,
+    ) -> Result<String> {
+        let params = MarketingCampaignsStatsGetAutomationsStatsExportParams {
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            timezone: Some(timezone.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_automations_stats_export_with(&params).await
+    }
+}
+
+/// The query parameters of `MarketingCampaignsStats::getall_automation_stats_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MarketingCampaignsStatsGetallAutomationStatsParams {
+    /// This endpoint returns all automation IDs if no `automation_ids` are specified.
+    pub automation_ids: Option<Vec<String>>,
+    /// The number of elements you want returned on each page.
+    pub page_size: Option<i64>,
+    /// The stats endpoints are paginated. To get the next page, call the passed `_metadata.next` URL. If `_metadata.prev` doesn't exist, you're at the first page. Similarly, if `_metadata.next` is not present, you're at the last page.
+    pub page_token: Option<String>,
+}
+
+impl MarketingCampaignsStatsGetallAutomationStatsParams {
+    pub fn automation_ids(mut self, automation_ids: Vec<String>) -> Self {
+        self.automation_ids = Some(automation_ids);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The query parameters of `MarketingCampaignsStats::get_automation_stat_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MarketingCampaignsStatsGetAutomationStatParams {
+    /// Dictates how the stats are time-sliced. Currently, `"total"` and `"day"` are supported.
+    pub aggregated_by: Option<crate::types::AggregatedBy>,
+    /// Format: `YYYY-MM-DD`.If this parameter is included, the stats' end date is included in the search.
+    pub end_date: Option<chrono::NaiveDate>,
+    /// Automations can have multiple steps. Including `step_id` as a `group_by` metric allows further granularity of stats.
+    pub group_by: Option<Vec<String>>,
+    /// The number of elements you want returned on each page.
+    pub page_size: Option<i64>,
+    /// The stats endpoints are paginated. To get the next page, call the passed `_metadata.next` URL. If `_metadata.prev` doesn't exist, you're at the first page. Similarly, if `_metadata.next` is not present, you're at the last page.
+    pub page_token: Option<String>,
+    /// Format: `YYYY-MM-DD`. If this parameter is included, the stats' start date is included in the search.
+    pub start_date: Option<chrono::NaiveDate>,
+    /// The recipient IDs of the recipients that already existed from this request.
+    pub step_ids: Option<Vec<String>>,
+    /// [IANA Area/Region](https://en.wikipedia.org/wiki/Tz_database#Names_of_time_zones) string representing the timezone in which the stats are to be presented, e.g., "America/Chicago".
+    pub timezone: Option<String>,
+}
+
+impl MarketingCampaignsStatsGetAutomationStatParams {
+    pub fn aggregated_by(mut self, aggregated_by: crate::types::AggregatedBy) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    pub fn end_date(mut self, end_date: chrono::NaiveDate) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    pub fn group_by(mut self, group_by: Vec<String>) -> Self {
+        self.group_by = Some(group_by);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn start_date(mut self, start_date: chrono::NaiveDate) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    pub fn step_ids(mut self, step_ids: Vec<String>) -> Self {
+        self.step_ids = Some(step_ids);
+        self
+    }
+
+    pub fn timezone<T: ToString>(mut self, timezone: T) -> Self {
+        self.timezone = Some(timezone.to_string());
+        self
+    }
+}
+
+/// The query parameters of `MarketingCampaignsStats::getall_singlesend_stats_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MarketingCampaignsStatsGetallSinglesendStatsParams {
+    /// The number of elements you want returned on each page.
+    pub page_size: Option<i64>,
+    /// The stats endpoints are paginated. To get the next page, call the passed `_metadata.next` URL. If `_metadata.prev` doesn't exist, you're at the first page. Similarly, if `_metadata.next` is not present, you're at the last page.
+    pub page_token: Option<String>,
+    /// This endpoint returns all Single Send IDs if no IDs are included in `singlesend_ids`.
+    pub singlesend_ids: Option<Vec<String>>,
+}
+
+impl MarketingCampaignsStatsGetallSinglesendStatsParams {
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn singlesend_ids(mut self, singlesend_ids: Vec<String>) -> Self {
+        self.singlesend_ids = Some(singlesend_ids);
+        self
+    }
+}
+
+/// The query parameters of `MarketingCampaignsStats::get_singlesend_stat_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MarketingCampaignsStatsGetSinglesendStatParams {
+    /// Dictates how the stats are time-sliced. Currently, `"total"` and `"day"` are supported.
+    pub aggregated_by: Option<crate::types::AggregatedBy>,
+    /// Format: `YYYY-MM-DD`.If this parameter is included, the stats' end date is included in the search.
+    pub end_date: Option<chrono::NaiveDate>,
+    /// A/B Single Sends have multiple variation IDs and phase IDs. Including these additional fields allows further granularity of stats by these fields.
+    pub group_by: Option<Vec<String>>,
+    /// The number of elements you want returned on each page.
+    pub page_size: Option<i64>,
+    /// The stats endpoints are paginated. To get the next page, call the passed `_metadata.next` URL. If `_metadata.prev` doesn't exist, you're at the first page. Similarly, if `_metadata.next` is not present, you're at the last page.
+    pub page_token: Option<String>,
+    /// Format: `YYYY-MM-DD`. If this parameter is included, the stats' start date is included in the search.
+    pub start_date: Option<chrono::NaiveDate>,
+    /// [IANA Area/Region](https://en.wikipedia.org/wiki/Tz_database#Names_of_time_zones) string representing the timezone in which the stats are to be presented, e.g., "America/Chicago".
+    pub timezone: Option<String>,
+}
+
+impl MarketingCampaignsStatsGetSinglesendStatParams {
+    pub fn aggregated_by(mut self, aggregated_by: crate::types::AggregatedBy) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    pub fn end_date(mut self, end_date: chrono::NaiveDate) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    pub fn group_by(mut self, group_by: Vec<String>) -> Self {
+        self.group_by = Some(group_by);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn start_date(mut self, start_date: chrono::NaiveDate) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    pub fn timezone<T: ToString>(mut self, timezone: T) -> Self {
+        self.timezone = Some(timezone.to_string());
+        self
+    }
+}
+
+/// The query parameters of `MarketingCampaignsStats::get_automation_link_stat_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MarketingCampaignsStatsGetAutomationLinkStatParams {
+    /// Automations can have multiple steps. Including `step_id` as a `group_by` metric allows further granularity of stats.
+    pub group_by: Option<Vec<String>>,
+    /// The number of elements you want returned on each page.
+    pub page_size: Option<i64>,
+    /// The stats endpoints are paginated. To get the next page, call the passed `_metadata.next` URL. If `_metadata.prev` doesn't exist, you're at the first page. Similarly, if `_metadata.next` is not present, you're at the last page.
+    pub page_token: Option<String>,
+    /// The recipient IDs of the recipients that already existed from this request.
+    pub step_ids: Option<Vec<String>>,
+}
+
+impl MarketingCampaignsStatsGetAutomationLinkStatParams {
+    pub fn group_by(mut self, group_by: Vec<String>) -> Self {
+        self.group_by = Some(group_by);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+
+    pub fn step_ids(mut self, step_ids: Vec<String>) -> Self {
+        self.step_ids = Some(step_ids);
+        self
+    }
+}
+
+/// The query parameters of `MarketingCampaignsStats::get_singlesend_link_stat_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MarketingCampaignsStatsGetSinglesendLinkStatParams {
+    pub ab_phase_id: Option<crate::types::AbPhaseId>,
+    /// The license key provided with your New Relic account.
+    pub ab_variation_id: Option<String>,
+    /// A/B Single Sends have multiple variation IDs and phase IDs. Including these additional fields allows further granularity of stats by these fields.
+    pub group_by: Option<Vec<String>>,
+    /// The number of elements you want returned on each page.
+    pub page_size: Option<i64>,
+    /// The stats endpoints are paginated. To get the next page, call the passed `_metadata.next` URL. If `_metadata.prev` doesn't exist, you're at the first page. Similarly, if `_metadata.next` is not present, you're at the last page.
+    pub page_token: Option<String>,
+}
+
+impl MarketingCampaignsStatsGetSinglesendLinkStatParams {
+    pub fn ab_phase_id(mut self, ab_phase_id: crate::types::AbPhaseId) -> Self {
+        self.ab_phase_id = Some(ab_phase_id);
+        self
+    }
+
+    pub fn ab_variation_id<T: ToString>(mut self, ab_variation_id: T) -> Self {
+        self.ab_variation_id = Some(ab_variation_id.to_string());
+        self
+    }
+
+    pub fn group_by(mut self, group_by: Vec<String>) -> Self {
+        self.group_by = Some(group_by);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The query parameters of `MarketingCampaignsStats::get_singlesend_stats_export_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MarketingCampaignsStatsGetSinglesendStatsExportParams {
+    /// The recipient IDs of the recipients that already existed from this request.
+    pub ids: Option<Vec<String>>,
+    /// The [IANA Area/Region](https://en.wikipedia.org/wiki/Tz_database#Names_of_time_zones) string representing the timezone in which the stats are to be presented; i.e. `"America/Chicago"`. This parameter changes the timezone format only; it does not alter which stats are returned.
+    pub timezone: Option<String>,
+}
+
+impl MarketingCampaignsStatsGetSinglesendStatsExportParams {
+    pub fn ids(mut self, ids: Vec<String>) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn timezone<T: ToString>(mut self, timezone: T) -> Self {
+        self.timezone = Some(timezone.to_string());
+        self
+    }
+}
+
+/// The query parameters of `MarketingCampaignsStats::get_automations_stats_export_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MarketingCampaignsStatsGetAutomationsStatsExportParams {
+    /// The recipient IDs of the recipients that already existed from this request.
+    pub ids: Option<Vec<String>>,
+    /// The [IANA Area/Region](https://en.wikipedia.org/wiki/Tz_database#Names_of_time_zones) string representing the timezone in which the stats are to be presented; i.e. `"America/Chicago"`. This parameter changes the timezone format only; it does not alter which stats are returned.
+    pub timezone: Option<String>,
+}
+
+impl MarketingCampaignsStatsGetAutomationsStatsExportParams {
+    pub fn ids(mut self, ids: Vec<String>) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn timezone<T: ToString>(mut self, timezone: T) -> Self {
+        self.timezone = Some(timezone.to_string());
+        self
+    }
+}
+
+/// The functions of [`MarketingCampaignsStats`], for code that takes them as a trait, so that it can be
+/// tested with the `MockMarketingCampaignsStatsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait MarketingCampaignsStatsApi: Send + Sync {
+    /// See [`MarketingCampaignsStats::getall_automation_stats_with`].
+    async fn getall_automation_stats_with(
+        &self,
+        params: &MarketingCampaignsStatsGetallAutomationStatsParams,
+    ) -> Result<crate::types::AutomationsResponse>;
+
+    /// See [`MarketingCampaignsStats::get_automation_stat_with`].
+    async fn get_automation_stat_with(
+        &self,
+        id: &str,
+        params: &MarketingCampaignsStatsGetAutomationStatParams,
+    ) -> Result<crate::types::AutomationsResponse>;
+
+    /// See [`MarketingCampaignsStats::getall_singlesend_stats_with`].
+    async fn getall_singlesend_stats_with(
+        &self,
+        params: &MarketingCampaignsStatsGetallSinglesendStatsParams,
+    ) -> Result<crate::types::SinglesendsResponse>;
+
+    /// See [`MarketingCampaignsStats::get_singlesend_stat_with`].
+    async fn get_singlesend_stat_with(
+        &self,
+        id: &str,
+        params: &MarketingCampaignsStatsGetSinglesendStatParams,
+    ) -> Result<crate::types::SinglesendsResponse>;
+
+    /// See [`MarketingCampaignsStats::get_automation_link_stat_with`].
+    async fn get_automation_link_stat_with(
+        &self,
+        id: &str,
+        params: &MarketingCampaignsStatsGetAutomationLinkStatParams,
+    ) -> Result<crate::types::AutomationsLinkStatsResponse>;
+
+    /// See [`MarketingCampaignsStats::get_singlesend_link_stat_with`].
+    async fn get_singlesend_link_stat_with(
+        &self,
+        id: &str,
+        params: &MarketingCampaignsStatsGetSinglesendLinkStatParams,
+    ) -> Result<crate::types::SinglesendsLinkStatsResponse>;
+
+    /// See [`MarketingCampaignsStats::get_singlesend_stats_export_with`].
+    async fn get_singlesend_stats_export_with(
+        &self,
+        params: &MarketingCampaignsStatsGetSinglesendStatsExportParams,
+    ) -> Result<String>;
+
+    /// See [`MarketingCampaignsStats::get_automations_stats_export_with`].
+    async fn get_automations_stats_export_with(
+        &self,
+        params: &MarketingCampaignsStatsGetAutomationsStatsExportParams,
+    ) -> Result<String>;
+}
+
+#[async_trait::async_trait]
+impl MarketingCampaignsStatsApi for MarketingCampaignsStats {
+    async fn getall_automation_stats_with(
+        &self,
+        params: &MarketingCampaignsStatsGetallAutomationStatsParams,
+    ) -> Result<crate::types::AutomationsResponse> {
+        MarketingCampaignsStats::getall_automation_stats_with(self, params).await
+    }
+
+    async fn get_automation_stat_with(
+        &self,
+        id: &str,
+        params: &MarketingCampaignsStatsGetAutomationStatParams,
+    ) -> Result<crate::types::AutomationsResponse> {
+        MarketingCampaignsStats::get_automation_stat_with(self, id, params).await
+    }
+
+    async fn getall_singlesend_stats_with(
+        &self,
+        params: &MarketingCampaignsStatsGetallSinglesendStatsParams,
+    ) -> Result<crate::types::SinglesendsResponse> {
+        MarketingCampaignsStats::getall_singlesend_stats_with(self, params).await
+    }
+
+    async fn get_singlesend_stat_with(
+        &self,
+        id: &str,
+        params: &MarketingCampaignsStatsGetSinglesendStatParams,
+    ) -> Result<crate::types::SinglesendsResponse> {
+        MarketingCampaignsStats::get_singlesend_stat_with(self, id, params).await
+    }
+
+    async fn get_automation_link_stat_with(
+        &self,
+        id: &str,
+        params: &MarketingCampaignsStatsGetAutomationLinkStatParams,
+    ) -> Result<crate::types::AutomationsLinkStatsResponse> {
+        MarketingCampaignsStats::get_automation_link_stat_with(self, id, params).await
+    }
+
+    async fn get_singlesend_link_stat_with(
+        &self,
+        id: &str,
+        params: &MarketingCampaignsStatsGetSinglesendLinkStatParams,
+    ) -> Result<crate::types::SinglesendsLinkStatsResponse> {
+        MarketingCampaignsStats::get_singlesend_link_stat_with(self, id, params).await
+    }
+
+    async fn get_singlesend_stats_export_with(
+        &self,
+        params: &MarketingCampaignsStatsGetSinglesendStatsExportParams,
+    ) -> Result<String> {
+        MarketingCampaignsStats::get_singlesend_stats_export_with(self, params).await
+    }
+
+    async fn get_automations_stats_export_with(
+        &self,
+        params: &MarketingCampaignsStatsGetAutomationsStatsExportParams,
+    ) -> Result<String> {
+        MarketingCampaignsStats::get_automations_stats_export_with(self, params).await
+    }
 }
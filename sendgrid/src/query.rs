@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Query {
     pub client: Client,
@@ -60,22 +58,17 @@ impl Query {
      *
      * **Parameters:**
      *
-     * * `query: &str` -- The license key provided with your New Relic account.
-     * * `limit: f64` -- The number of messages returned. This parameter must be greater than 0 and less than or equal to 1000.
-     * * `x_query_id: &str` -- The license key provided with your New Relic account.
-     * * `x_cursor: &str` -- The license key provided with your New Relic account.
-     * * `authorization: &str` -- The license key provided with your New Relic account.
+     * * `params: &QueryGetMessagesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_messages(
+    pub async fn get_messages_with(
         &self,
-        query: &str,
-        limit: f64,
+        params: &QueryGetMessagesParams,
     ) -> Result<crate::types::GetMessagesResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.to_string().is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -84,6 +77,22 @@ impl Query {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_messages_with` and pass the query parameters as a `QueryGetMessagesParams` instead.
+     */
+    #[deprecated(note = "use `get_messages_with` instead")]
+    pub async fn get_messages(
+        &self,
+        query: &str,
+        limit: f64,
+    ) -> Result<crate::types::GetMessagesResponse> {
+        let params = QueryGetMessagesParams {
+            limit: Some(limit).filter(|v| !v.to_string().is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_messages_with(&params).await
+    }
+
     /**
      * Filter messages by message ID.
      *
@@ -106,3 +115,53 @@ impl Query {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Query::get_messages_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct QueryGetMessagesParams {
+    /// The number of messages returned. This parameter must be greater than 0 and less than or equal to 1000.
+    pub limit: Option<f64>,
+    /// The license key provided with your New Relic account.
+    pub query: Option<String>,
+}
+
+impl QueryGetMessagesParams {
+    pub fn limit(mut self, limit: f64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The functions of [`Query`], for code that takes them as a trait, so that it can be
+/// tested with the `MockQueryApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait QueryApi: Send + Sync {
+    /// See [`Query::get_messages_with`].
+    async fn get_messages_with(
+        &self,
+        params: &QueryGetMessagesParams,
+    ) -> Result<crate::types::GetMessagesResponse>;
+
+    /// See [`Query::get_messages_msg`].
+    async fn get_messages_msg(&self, msg_id: &str) -> Result<crate::types::Message>;
+}
+
+#[async_trait::async_trait]
+impl QueryApi for Query {
+    async fn get_messages_with(
+        &self,
+        params: &QueryGetMessagesParams,
+    ) -> Result<crate::types::GetMessagesResponse> {
+        Query::get_messages_with(self, params).await
+    }
+
+    async fn get_messages_msg(&self, msg_id: &str) -> Result<crate::types::Message> {
+        Query::get_messages_msg(self, msg_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ReverseDns {
     pub client: Client,
@@ -27,25 +25,20 @@ impl ReverseDns {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- The maximum number of results to retrieve.
-     * * `offset: i64` -- The point in the list of results to begin retrieving IP addresses from.
-     * * `ip: &str` -- The IP address segment that you'd like to use in a prefix search.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &ReverseDnsGetWhitelabelIpsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_whitelabel_ips(
+    pub async fn get_whitelabel_ips_with(
         &self,
-        limit: i64,
-        offset: i64,
-        ip: &str,
+        params: &ReverseDnsGetWhitelabelIpsParams,
     ) -> Result<Vec<crate::types::ReverseDns>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ip.is_empty() {
+        if let Some(ip) = &params.ip {
             query_args.push(("ip".to_string(), ip.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -54,6 +47,24 @@ impl ReverseDns {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_whitelabel_ips_with` and pass the query parameters as a `ReverseDnsGetWhitelabelIpsParams` instead.
+     */
+    #[deprecated(note = "use `get_whitelabel_ips_with` instead")]
+    pub async fn get_whitelabel_ips(
+        &self,
+        limit: i64,
+        offset: i64,
+        ip: &str,
+    ) -> Result<Vec<crate::types::ReverseDns>> {
+        let params = ReverseDnsGetWhitelabelIpsParams {
+            ip: Some(ip.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_whitelabel_ips_with(&params).await
+    }
+
     /**
      * Retrieve all reverse DNS records.
      *
@@ -68,23 +79,92 @@ impl ReverseDns {
      * Use the `limit` query string parameter to reduce the number of records returned. All records will be returned if you have fewer records than the specified limit.
      *
      * The `offset` query string parameter allows you to specify a non-zero index from which records will be returned. For example, if you have ten records, `?offset=5` will return the last five records (at indexes 5 through 9). The list starts at index zero.
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReverseDnsGetWhitelabelIpsParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_whitelabel_ips_with(
+        &self,
+        params: &ReverseDnsGetWhitelabelIpsParams,
+    ) -> Result<Vec<crate::types::ReverseDns>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(ip) = &params.ip {
+            query_args.push(("ip".to_string(), ip.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/whitelabel/ips?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_whitelabel_ips_with` and pass the query parameters as a `ReverseDnsGetWhitelabelIpsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_whitelabel_ips_with` instead")]
     pub async fn get_all_whitelabel_ips(
         &self,
         offset: i64,
         ip: &str,
     ) -> Result<Vec<crate::types::ReverseDns>> {
+        let params = ReverseDnsGetWhitelabelIpsParams {
+            ip: Some(ip.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            ..Default::default()
+        };
+        self.get_all_whitelabel_ips_with(&params).await
+    }
+
+    /**
+     * Retrieve all reverse DNS records.
+     *
+     * This function performs a `GET` to the `/whitelabel/ips` endpoint.
+     *
+     * As opposed to `get_all_whitelabel_ips`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve all of the Reverse DNS records created by this account.**
+     *
+     * You may include a search key by using the `ip` query string parameter. This enables you to perform a prefix search for a given IP segment (e.g., `?ip="192."`).
+     *
+     * Use the `limit` query string parameter to reduce the number of records returned. All records will be returned if you have fewer records than the specified limit.
+     *
+     * The `offset` query string parameter allows you to specify a non-zero index from which records will be returned. For example, if you have ten records, `?offset=5` will return the last five records (at indexes 5 through 9). The list starts at index zero.
+     */
+    pub fn get_whitelabel_ips_stream(
+        &self,
+        params: &ReverseDnsGetWhitelabelIpsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReverseDns> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ip.is_empty() {
+        if let Some(ip) = &params.ip {
             query_args.push(("ip".to_string(), ip.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/whitelabel/ips?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -174,7 +254,10 @@ impl ReverseDns {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_whitelabel_ips(&self, id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_whitelabel_ips(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::DeleteWhitelabelIpsResponse> {
         let url = format!(
             "/whitelabel/ips/{}",
             crate::progenitor_support::encode_path(id),
@@ -183,3 +266,129 @@ impl ReverseDns {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `ReverseDns::get_whitelabel_ips_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReverseDnsGetWhitelabelIpsParams {
+    /// The IP address segment that you'd like to use in a prefix search.
+    pub ip: Option<String>,
+    /// The maximum number of results to retrieve.
+    pub limit: Option<i64>,
+    /// The point in the list of results to begin retrieving IP addresses from.
+    pub offset: Option<i64>,
+}
+
+impl ReverseDnsGetWhitelabelIpsParams {
+    pub fn ip<T: ToString>(mut self, ip: T) -> Self {
+        self.ip = Some(ip.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The functions of [`ReverseDns`], for code that takes them as a trait, so that it can be
+/// tested with the `MockReverseDnsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReverseDnsApi: Send + Sync {
+    /// See [`ReverseDns::get_whitelabel_ips_with`].
+    async fn get_whitelabel_ips_with(
+        &self,
+        params: &ReverseDnsGetWhitelabelIpsParams,
+    ) -> Result<Vec<crate::types::ReverseDns>>;
+
+    /// See [`ReverseDns::get_all_whitelabel_ips_with`].
+    async fn get_all_whitelabel_ips_with(
+        &self,
+        params: &ReverseDnsGetWhitelabelIpsParams,
+    ) -> Result<Vec<crate::types::ReverseDns>>;
+
+    /// See [`ReverseDns::get_whitelabel_ips_stream`].
+    fn get_whitelabel_ips_stream(
+        &self,
+        params: &ReverseDnsGetWhitelabelIpsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReverseDns>;
+
+    /// See [`ReverseDns::post_whitelabel_ip`].
+    async fn post_whitelabel_ip(
+        &self,
+        body: &crate::types::PostWhitelabelIpsRequest,
+    ) -> Result<crate::types::ReverseDns>;
+
+    /// See [`ReverseDns::post_whitelabel_ips_validate`].
+    async fn post_whitelabel_ips_validate(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::PostWhitelabelIpsValidateResponse>;
+
+    /// See [`ReverseDns::get_whitelabel_ip`].
+    async fn get_whitelabel_ip(&self, id: &str) -> Result<crate::types::ReverseDns>;
+
+    /// See [`ReverseDns::delete_whitelabel_ips`].
+    async fn delete_whitelabel_ips(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::DeleteWhitelabelIpsResponse>;
+}
+
+#[async_trait::async_trait]
+impl ReverseDnsApi for ReverseDns {
+    async fn get_whitelabel_ips_with(
+        &self,
+        params: &ReverseDnsGetWhitelabelIpsParams,
+    ) -> Result<Vec<crate::types::ReverseDns>> {
+        ReverseDns::get_whitelabel_ips_with(self, params).await
+    }
+
+    async fn get_all_whitelabel_ips_with(
+        &self,
+        params: &ReverseDnsGetWhitelabelIpsParams,
+    ) -> Result<Vec<crate::types::ReverseDns>> {
+        ReverseDns::get_all_whitelabel_ips_with(self, params).await
+    }
+
+    fn get_whitelabel_ips_stream(
+        &self,
+        params: &ReverseDnsGetWhitelabelIpsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReverseDns> {
+        ReverseDns::get_whitelabel_ips_stream(self, params, start, max_items)
+    }
+
+    async fn post_whitelabel_ip(
+        &self,
+        body: &crate::types::PostWhitelabelIpsRequest,
+    ) -> Result<crate::types::ReverseDns> {
+        ReverseDns::post_whitelabel_ip(self, body).await
+    }
+
+    async fn post_whitelabel_ips_validate(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::PostWhitelabelIpsValidateResponse> {
+        ReverseDns::post_whitelabel_ips_validate(self, id).await
+    }
+
+    async fn get_whitelabel_ip(&self, id: &str) -> Result<crate::types::ReverseDns> {
+        ReverseDns::get_whitelabel_ip(self, id).await
+    }
+
+    async fn delete_whitelabel_ips(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::DeleteWhitelabelIpsResponse> {
+        ReverseDns::delete_whitelabel_ips(self, id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SegmentingContacts {
     pub client: Client,
@@ -30,22 +28,20 @@ impl SegmentingContacts {
      *
      * **Parameters:**
      *
-     * * `parent_list_ids: &str` -- A comma separated list of list ids to be used when searching for segments with the specified parent_list_id, no more than 50 is allowed.
-     * * `no_parent_list_id: bool` -- If set to `true` segments with an empty value of `parent_list_id` will be returned in the filter.  If the value is not present it defaults to 'false'.
+     * * `params: &SegmentingContactsGetMarketingSegmentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_marketing_segments(
+    pub async fn get_marketing_segments_with(
         &self,
-        parent_list_ids: &str,
-        no_parent_list_id: bool,
+        params: &SegmentingContactsGetMarketingSegmentsParams,
     ) -> Result<crate::types::GetMarketingSegmentsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if no_parent_list_id {
+        if let Some(no_parent_list_id) = &params.no_parent_list_id {
             query_args.push((
                 "no_parent_list_id".to_string(),
                 no_parent_list_id.to_string(),
             ));
         }
-        if !parent_list_ids.is_empty() {
+        if let Some(parent_list_ids) = &params.parent_list_ids {
             query_args.push(("parent_list_ids".to_string(), parent_list_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -54,6 +50,22 @@ impl SegmentingContacts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_marketing_segments_with` and pass the query parameters as a `SegmentingContactsGetMarketingSegmentsParams` instead.
+     */
+    #[deprecated(note = "use `get_marketing_segments_with` instead")]
+    pub async fn get_marketing_segments(
+        &self,
+        parent_list_ids: &str,
+        no_parent_list_id: bool,
+    ) -> Result<crate::types::GetMarketingSegmentsResponse> {
+        let params = SegmentingContactsGetMarketingSegmentsParams {
+            no_parent_list_id: Some(no_parent_list_id).filter(|v| *v),
+            parent_list_ids: Some(parent_list_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_marketing_segments_with(&params).await
+    }
+
     /**
      * Create Segment.
      *
@@ -80,15 +92,15 @@ impl SegmentingContacts {
      *
      * **Parameters:**
      *
-     * * `query_json: bool` -- Defaults to `false`.  Set to `true` to return the parsed SQL AST as a JSON object in the field `query_json`.
+     * * `params: &SegmentingContactsGetMarketingSegmentsSegmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_marketing_segments_segment(
+    pub async fn get_marketing_segments_segment_with(
         &self,
         segment_id: &str,
-        query_json: bool,
+        params: &SegmentingContactsGetMarketingSegmentsSegmentParams,
     ) -> Result<crate::types::FullSegmentAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if query_json {
+        if let Some(query_json) = &params.query_json {
             query_args.push(("query_json".to_string(), query_json.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -101,6 +113,22 @@ impl SegmentingContacts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_marketing_segments_segment_with` and pass the query parameters as a `SegmentingContactsGetMarketingSegmentsSegmentParams` instead.
+     */
+    #[deprecated(note = "use `get_marketing_segments_segment_with` instead")]
+    pub async fn get_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+        query_json: bool,
+    ) -> Result<crate::types::FullSegmentAllOf> {
+        let params = SegmentingContactsGetMarketingSegmentsSegmentParams {
+            query_json: Some(query_json).filter(|v| *v),
+        };
+        self.get_marketing_segments_segment_with(segment_id, &params)
+            .await
+    }
+
     /**
      * Delete Segment.
      *
@@ -113,7 +141,7 @@ impl SegmentingContacts {
     pub async fn delete_marketing_segments_segment(
         &self,
         segment_id: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteMarketingSegmentsSegmentResponse> {
         let url = format!(
             "/marketing/segments/{}",
             crate::progenitor_support::encode_path(segment_id),
@@ -165,3 +193,129 @@ impl SegmentingContacts {
             .await
     }
 }
+
+/// The query parameters of `SegmentingContacts::get_marketing_segments_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SegmentingContactsGetMarketingSegmentsParams {
+    /// If set to `true` segments with an empty value of `parent_list_id` will be returned in the filter.  If the value is not present it defaults to 'false'.
+    pub no_parent_list_id: Option<bool>,
+    /// A comma separated list of list ids to be used when searching for segments with the specified parent_list_id, no more than 50 is allowed.
+    pub parent_list_ids: Option<String>,
+}
+
+impl SegmentingContactsGetMarketingSegmentsParams {
+    pub fn no_parent_list_id(mut self, no_parent_list_id: bool) -> Self {
+        self.no_parent_list_id = Some(no_parent_list_id);
+        self
+    }
+
+    pub fn parent_list_ids<T: ToString>(mut self, parent_list_ids: T) -> Self {
+        self.parent_list_ids = Some(parent_list_ids.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SegmentingContacts::get_marketing_segments_segment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SegmentingContactsGetMarketingSegmentsSegmentParams {
+    /// Defaults to `false`.  Set to `true` to return the parsed SQL AST as a JSON object in the field `query_json`.
+    pub query_json: Option<bool>,
+}
+
+impl SegmentingContactsGetMarketingSegmentsSegmentParams {
+    pub fn query_json(mut self, query_json: bool) -> Self {
+        self.query_json = Some(query_json);
+        self
+    }
+}
+
+/// The functions of [`SegmentingContacts`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSegmentingContactsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SegmentingContactsApi: Send + Sync {
+    /// See [`SegmentingContacts::get_marketing_segments_with`].
+    async fn get_marketing_segments_with(
+        &self,
+        params: &SegmentingContactsGetMarketingSegmentsParams,
+    ) -> Result<crate::types::GetMarketingSegmentsResponse>;
+
+    /// See [`SegmentingContacts::post_marketing_segment`].
+    async fn post_marketing_segment(
+        &self,
+        body: &crate::types::PostMarketingSegmentsRequestAllOf,
+    ) -> Result<crate::types::FullSegmentAllOf>;
+
+    /// See [`SegmentingContacts::get_marketing_segments_segment_with`].
+    async fn get_marketing_segments_segment_with(
+        &self,
+        segment_id: &str,
+        params: &SegmentingContactsGetMarketingSegmentsSegmentParams,
+    ) -> Result<crate::types::FullSegmentAllOf>;
+
+    /// See [`SegmentingContacts::delete_marketing_segments_segment`].
+    async fn delete_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> Result<crate::types::DeleteMarketingSegmentsSegmentResponse>;
+
+    /// See [`SegmentingContacts::patch_marketing_segments_segment`].
+    async fn patch_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::SegmentWriteV2,
+    ) -> Result<crate::types::FullSegmentAllOf>;
+
+    /// See [`SegmentingContacts::post_marketing_segments_delete`].
+    async fn post_marketing_segments_delete(
+        &self,
+        body: &crate::types::PostMarketingSegmentsDeleteRequest,
+    ) -> Result<crate::types::PostMarketingSegmentsDeleteResponse>;
+}
+
+#[async_trait::async_trait]
+impl SegmentingContactsApi for SegmentingContacts {
+    async fn get_marketing_segments_with(
+        &self,
+        params: &SegmentingContactsGetMarketingSegmentsParams,
+    ) -> Result<crate::types::GetMarketingSegmentsResponse> {
+        SegmentingContacts::get_marketing_segments_with(self, params).await
+    }
+
+    async fn post_marketing_segment(
+        &self,
+        body: &crate::types::PostMarketingSegmentsRequestAllOf,
+    ) -> Result<crate::types::FullSegmentAllOf> {
+        SegmentingContacts::post_marketing_segment(self, body).await
+    }
+
+    async fn get_marketing_segments_segment_with(
+        &self,
+        segment_id: &str,
+        params: &SegmentingContactsGetMarketingSegmentsSegmentParams,
+    ) -> Result<crate::types::FullSegmentAllOf> {
+        SegmentingContacts::get_marketing_segments_segment_with(self, segment_id, params).await
+    }
+
+    async fn delete_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+    ) -> Result<crate::types::DeleteMarketingSegmentsSegmentResponse> {
+        SegmentingContacts::delete_marketing_segments_segment(self, segment_id).await
+    }
+
+    async fn patch_marketing_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::SegmentWriteV2,
+    ) -> Result<crate::types::FullSegmentAllOf> {
+        SegmentingContacts::patch_marketing_segments_segment(self, segment_id, body).await
+    }
+
+    async fn post_marketing_segments_delete(
+        &self,
+        body: &crate::types::PostMarketingSegmentsDeleteRequest,
+    ) -> Result<crate::types::PostMarketingSegmentsDeleteResponse> {
+        SegmentingContacts::post_marketing_segments_delete(self, body).await
+    }
+}
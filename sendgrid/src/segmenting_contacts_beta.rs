@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SegmentingContactsBeta {
     pub client: Client,
@@ -30,22 +28,20 @@ impl SegmentingContactsBeta {
      *
      * **Parameters:**
      *
-     * * `parent_list_ids: &str` -- A comma separated list up to 50 in size, to filter segments on.  Only segments that have any of these list ids as the parent list will be retrieved. This is different from the parameter of the same name used when creating a segment.
-     * * `no_parent_list_id: bool` -- If set to `true` segments with an empty value of `parent_list_id` will be returned in the filter.  If the value is not present it defaults to 'false'.
+     * * `params: &SegmentingContactsBetaGetSegmentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_segments(
+    pub async fn get_segments_with(
         &self,
-        parent_list_ids: &str,
-        no_parent_list_id: bool,
+        params: &SegmentingContactsBetaGetSegmentsParams,
     ) -> Result<crate::types::AllSegmentsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if no_parent_list_id {
+        if let Some(no_parent_list_id) = &params.no_parent_list_id {
             query_args.push((
                 "no_parent_list_id".to_string(),
                 no_parent_list_id.to_string(),
             ));
         }
-        if !parent_list_ids.is_empty() {
+        if let Some(parent_list_ids) = &params.parent_list_ids {
             query_args.push(("parent_list_ids".to_string(), parent_list_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -54,6 +50,22 @@ impl SegmentingContactsBeta {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_segments_with` and pass the query parameters as a `SegmentingContactsBetaGetSegmentsParams` instead.
+     */
+    #[deprecated(note = "use `get_segments_with` instead")]
+    pub async fn get_segments(
+        &self,
+        parent_list_ids: &str,
+        no_parent_list_id: bool,
+    ) -> Result<crate::types::AllSegmentsResponse> {
+        let params = SegmentingContactsBetaGetSegmentsParams {
+            no_parent_list_id: Some(no_parent_list_id).filter(|v| *v),
+            parent_list_ids: Some(parent_list_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_segments_with(&params).await
+    }
+
     /**
      * Create Segment.
      *
@@ -82,15 +94,15 @@ impl SegmentingContactsBeta {
      *
      * **Parameters:**
      *
-     * * `contacts_sample: bool` -- Defaults to `true`. Set to `false` to exclude the contacts_sample in the response.
+     * * `params: &SegmentingContactsBetaGetSegmentsSegmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_segments_segment(
+    pub async fn get_segments_segment_with(
         &self,
         segment_id: &str,
-        contacts_sample: bool,
+        params: &SegmentingContactsBetaGetSegmentsSegmentParams,
     ) -> Result<crate::types::SegmentResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if contacts_sample {
+        if let Some(contacts_sample) = &params.contacts_sample {
             query_args.push(("contacts_sample".to_string(), contacts_sample.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -103,6 +115,21 @@ impl SegmentingContactsBeta {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_segments_segment_with` and pass the query parameters as a `SegmentingContactsBetaGetSegmentsSegmentParams` instead.
+     */
+    #[deprecated(note = "use `get_segments_segment_with` instead")]
+    pub async fn get_segments_segment(
+        &self,
+        segment_id: &str,
+        contacts_sample: bool,
+    ) -> Result<crate::types::SegmentResponse> {
+        let params = SegmentingContactsBetaGetSegmentsSegmentParams {
+            contacts_sample: Some(contacts_sample).filter(|v| *v),
+        };
+        self.get_segments_segment_with(segment_id, &params).await
+    }
+
     /**
      * Delete segment.
      *
@@ -143,3 +170,110 @@ impl SegmentingContactsBeta {
             .await
     }
 }
+
+/// The query parameters of `SegmentingContactsBeta::get_segments_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SegmentingContactsBetaGetSegmentsParams {
+    /// If set to `true` segments with an empty value of `parent_list_id` will be returned in the filter.  If the value is not present it defaults to 'false'.
+    pub no_parent_list_id: Option<bool>,
+    /// A comma separated list up to 50 in size, to filter segments on.  Only segments that have any of these list ids as the parent list will be retrieved. This is different from the parameter of the same name used when creating a segment.
+    pub parent_list_ids: Option<String>,
+}
+
+impl SegmentingContactsBetaGetSegmentsParams {
+    pub fn no_parent_list_id(mut self, no_parent_list_id: bool) -> Self {
+        self.no_parent_list_id = Some(no_parent_list_id);
+        self
+    }
+
+    pub fn parent_list_ids<T: ToString>(mut self, parent_list_ids: T) -> Self {
+        self.parent_list_ids = Some(parent_list_ids.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SegmentingContactsBeta::get_segments_segment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SegmentingContactsBetaGetSegmentsSegmentParams {
+    /// Defaults to `true`. Set to `false` to exclude the contacts_sample in the response.
+    pub contacts_sample: Option<bool>,
+}
+
+impl SegmentingContactsBetaGetSegmentsSegmentParams {
+    pub fn contacts_sample(mut self, contacts_sample: bool) -> Self {
+        self.contacts_sample = Some(contacts_sample);
+        self
+    }
+}
+
+/// The functions of [`SegmentingContactsBeta`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSegmentingContactsBetaApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SegmentingContactsBetaApi: Send + Sync {
+    /// See [`SegmentingContactsBeta::get_segments_with`].
+    async fn get_segments_with(
+        &self,
+        params: &SegmentingContactsBetaGetSegmentsParams,
+    ) -> Result<crate::types::AllSegmentsResponse>;
+
+    /// See [`SegmentingContactsBeta::post_segment`].
+    async fn post_segment(
+        &self,
+        body: &crate::types::SegmentWriteV2,
+    ) -> Result<crate::types::SegmentResponse>;
+
+    /// See [`SegmentingContactsBeta::get_segments_segment_with`].
+    async fn get_segments_segment_with(
+        &self,
+        segment_id: &str,
+        params: &SegmentingContactsBetaGetSegmentsSegmentParams,
+    ) -> Result<crate::types::SegmentResponse>;
+
+    /// See [`SegmentingContactsBeta::delete_segments_segment`].
+    async fn delete_segments_segment(&self, segment_id: &str) -> Result<()>;
+
+    /// See [`SegmentingContactsBeta::patch_segments_segment`].
+    async fn patch_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::SegmentUpdate,
+    ) -> Result<crate::types::SegmentResponse>;
+}
+
+#[async_trait::async_trait]
+impl SegmentingContactsBetaApi for SegmentingContactsBeta {
+    async fn get_segments_with(
+        &self,
+        params: &SegmentingContactsBetaGetSegmentsParams,
+    ) -> Result<crate::types::AllSegmentsResponse> {
+        SegmentingContactsBeta::get_segments_with(self, params).await
+    }
+
+    async fn post_segment(
+        &self,
+        body: &crate::types::SegmentWriteV2,
+    ) -> Result<crate::types::SegmentResponse> {
+        SegmentingContactsBeta::post_segment(self, body).await
+    }
+
+    async fn get_segments_segment_with(
+        &self,
+        segment_id: &str,
+        params: &SegmentingContactsBetaGetSegmentsSegmentParams,
+    ) -> Result<crate::types::SegmentResponse> {
+        SegmentingContactsBeta::get_segments_segment_with(self, segment_id, params).await
+    }
+
+    async fn delete_segments_segment(&self, segment_id: &str) -> Result<()> {
+        SegmentingContactsBeta::delete_segments_segment(self, segment_id).await
+    }
+
+    async fn patch_segments_segment(
+        &self,
+        segment_id: &str,
+        body: &crate::types::SegmentUpdate,
+    ) -> Result<crate::types::SegmentResponse> {
+        SegmentingContactsBeta::patch_segments_segment(self, segment_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SendTestEmail {
     pub client: Client,
@@ -30,10 +28,32 @@ impl SendTestEmail {
     pub async fn post_marketing_test_send_email(
         &self,
         body: &crate::types::PostMarketingTestSendEmailRequest,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::PostMarketingTestSendEmailResponse> {
         let url = "/marketing/test/send_email".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
 }
+
+/// The functions of [`SendTestEmail`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSendTestEmailApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SendTestEmailApi: Send + Sync {
+    /// See [`SendTestEmail::post_marketing_test_send_email`].
+    async fn post_marketing_test_send_email(
+        &self,
+        body: &crate::types::PostMarketingTestSendEmailRequest,
+    ) -> Result<crate::types::PostMarketingTestSendEmailResponse>;
+}
+
+#[async_trait::async_trait]
+impl SendTestEmailApi for SendTestEmail {
+    async fn post_marketing_test_send_email(
+        &self,
+        body: &crate::types::PostMarketingTestSendEmailRequest,
+    ) -> Result<crate::types::PostMarketingTestSendEmailResponse> {
+        SendTestEmail::post_marketing_test_send_email(self, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SenderIdentitiesApi {
     pub client: Client,
@@ -82,7 +80,10 @@ impl SenderIdentitiesApi {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_senders_sender(&self, sender_id: i64) -> Result<crate::types::Help> {
+    pub async fn delete_senders_sender(
+        &self,
+        sender_id: i64,
+    ) -> Result<crate::types::DeleteSendersSenderResponse> {
         let url = format!(
             "/senders/{}",
             crate::progenitor_support::encode_path(&sender_id.to_string()),
@@ -135,7 +136,7 @@ impl SenderIdentitiesApi {
     pub async fn post_senders_sender_resend_verification(
         &self,
         sender_id: i64,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::PostSendersSenderResendVerificationResponse> {
         let url = format!(
             "/senders/{}/resend_verification",
             crate::progenitor_support::encode_path(&sender_id.to_string()),
@@ -144,3 +145,80 @@ impl SenderIdentitiesApi {
         self.client.post(&url, None).await
     }
 }
+
+/// The functions of [`SenderIdentitiesApi`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSenderIdentitiesApiApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SenderIdentitiesApiApi: Send + Sync {
+    /// See [`SenderIdentitiesApi::get_senders`].
+    async fn get_senders(&self) -> Result<crate::types::GetSendersResponse>;
+
+    /// See [`SenderIdentitiesApi::post_sender`].
+    async fn post_sender(
+        &self,
+        body: &crate::types::PostSendersRequestAllOf,
+    ) -> Result<crate::types::SenderAllOf>;
+
+    /// See [`SenderIdentitiesApi::get_senders_sender`].
+    async fn get_senders_sender(&self, sender_id: i64) -> Result<crate::types::SenderAllOf>;
+
+    /// See [`SenderIdentitiesApi::delete_senders_sender`].
+    async fn delete_senders_sender(
+        &self,
+        sender_id: i64,
+    ) -> Result<crate::types::DeleteSendersSenderResponse>;
+
+    /// See [`SenderIdentitiesApi::patch_senders_sender`].
+    async fn patch_senders_sender(
+        &self,
+        sender_id: i64,
+        body: &crate::types::SenderRequest,
+    ) -> Result<crate::types::SenderAllOf>;
+
+    /// See [`SenderIdentitiesApi::post_senders_sender_resend_verification`].
+    async fn post_senders_sender_resend_verification(
+        &self,
+        sender_id: i64,
+    ) -> Result<crate::types::PostSendersSenderResendVerificationResponse>;
+}
+
+#[async_trait::async_trait]
+impl SenderIdentitiesApiApi for SenderIdentitiesApi {
+    async fn get_senders(&self) -> Result<crate::types::GetSendersResponse> {
+        SenderIdentitiesApi::get_senders(self).await
+    }
+
+    async fn post_sender(
+        &self,
+        body: &crate::types::PostSendersRequestAllOf,
+    ) -> Result<crate::types::SenderAllOf> {
+        SenderIdentitiesApi::post_sender(self, body).await
+    }
+
+    async fn get_senders_sender(&self, sender_id: i64) -> Result<crate::types::SenderAllOf> {
+        SenderIdentitiesApi::get_senders_sender(self, sender_id).await
+    }
+
+    async fn delete_senders_sender(
+        &self,
+        sender_id: i64,
+    ) -> Result<crate::types::DeleteSendersSenderResponse> {
+        SenderIdentitiesApi::delete_senders_sender(self, sender_id).await
+    }
+
+    async fn patch_senders_sender(
+        &self,
+        sender_id: i64,
+        body: &crate::types::SenderRequest,
+    ) -> Result<crate::types::SenderAllOf> {
+        SenderIdentitiesApi::patch_senders_sender(self, sender_id, body).await
+    }
+
+    async fn post_senders_sender_resend_verification(
+        &self,
+        sender_id: i64,
+    ) -> Result<crate::types::PostSendersSenderResendVerificationResponse> {
+        SenderIdentitiesApi::post_senders_sender_resend_verification(self, sender_id).await
+    }
+}
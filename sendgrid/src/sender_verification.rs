@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SenderVerification {
     pub client: Client,
@@ -59,30 +57,23 @@ impl SenderVerification {
      *
      * You can limit the number of results returned using the `limit`, `lastSeenID`, and `id` query string parameters.
      *
-     * * `limit` allows you to specify an exact number of Sender Identities to return.
-     * * `lastSeenID` will return senders with an ID number occuring after the passed in ID. In other words, the `lastSeenID` provides a starting point from which SendGrid will iterate to find Sender Identities associated with your account.
-     * * `id` will return information about only the Sender Identity passed in the request.
      *
      * **Parameters:**
      *
-     * * `limit: f64` -- The number of errors found while adding recipients.
-     * * `last_seen_id: f64` -- The number of errors found while adding recipients.
-     * * `id: i64`
+     * * `params: &SenderVerificationGetVerifiedSendersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_verified_senders(
+    pub async fn get_verified_senders_with(
         &self,
-        limit: f64,
-        last_seen_id: f64,
-        id: i64,
+        params: &SenderVerificationGetVerifiedSendersParams,
     ) -> Result<crate::types::GetVerifiedSendersResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if id > 0 {
+        if let Some(id) = &params.id {
             query_args.push(("id".to_string(), id.to_string()));
         }
-        if !last_seen_id.to_string().is_empty() {
+        if let Some(last_seen_id) = &params.last_seen_id {
             query_args.push(("lastSeenID".to_string(), last_seen_id.to_string()));
         }
-        if !limit.to_string().is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -91,6 +82,24 @@ impl SenderVerification {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_verified_senders_with` and pass the query parameters as a `SenderVerificationGetVerifiedSendersParams` instead.
+     */
+    #[deprecated(note = "use `get_verified_senders_with` instead")]
+    pub async fn get_verified_senders(
+        &self,
+        limit: f64,
+        last_seen_id: f64,
+        id: i64,
+    ) -> Result<crate::types::GetVerifiedSendersResponse> {
+        let params = SenderVerificationGetVerifiedSendersParams {
+            id: Some(id).filter(|v| *v > 0),
+            last_seen_id: Some(last_seen_id).filter(|v| !v.to_string().is_empty()),
+            limit: Some(limit).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_verified_senders_with(&params).await
+    }
+
     /**
      * Create Verified Sender Request.
      *
@@ -143,7 +152,10 @@ impl SenderVerification {
      *
      * You can retrieve the IDs associated with Sender Identities using the "Get All Verified Senders" endpoint.
      */
-    pub async fn delete_verified_senders(&self, id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_verified_senders(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::DeleteVerifiedSendersResponse> {
         let url = format!(
             "/verified_senders/{}",
             crate::progenitor_support::encode_path(id),
@@ -191,7 +203,10 @@ impl SenderVerification {
      *
      * You can retrieve the IDs associated with Sender Identities by passing a "Get All Verified Senders" endpoint.
      */
-    pub async fn post_verified_senders_resend(&self, id: &str) -> Result<crate::types::Help> {
+    pub async fn post_verified_senders_resend(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::PostVerifiedSendersResendResponse> {
         let url = format!(
             "/verified_senders/resend/{}",
             crate::progenitor_support::encode_path(id),
@@ -200,3 +215,135 @@ impl SenderVerification {
         self.client.post(&url, None).await
     }
 }
+
+/// The query parameters of `SenderVerification::get_verified_senders_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SenderVerificationGetVerifiedSendersParams {
+    pub id: Option<i64>,
+    /// The number of errors found while adding recipients.
+    pub last_seen_id: Option<f64>,
+    /// The number of errors found while adding recipients.
+    pub limit: Option<f64>,
+}
+
+impl SenderVerificationGetVerifiedSendersParams {
+    pub fn id(mut self, id: i64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn last_seen_id(mut self, last_seen_id: f64) -> Self {
+        self.last_seen_id = Some(last_seen_id);
+        self
+    }
+
+    pub fn limit(mut self, limit: f64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The functions of [`SenderVerification`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSenderVerificationApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SenderVerificationApi: Send + Sync {
+    /// See [`SenderVerification::get_verified_senders_domains`].
+    async fn get_verified_senders_domains(
+        &self,
+    ) -> Result<crate::types::GetVerifiedSendersDomainsResponse>;
+
+    /// See [`SenderVerification::get_verified_senders_steps_completed`].
+    async fn get_verified_senders_steps_completed(
+        &self,
+    ) -> Result<crate::types::GetVerifiedSendersStepsCompletedResponse>;
+
+    /// See [`SenderVerification::get_verified_senders_with`].
+    async fn get_verified_senders_with(
+        &self,
+        params: &SenderVerificationGetVerifiedSendersParams,
+    ) -> Result<crate::types::GetVerifiedSendersResponse>;
+
+    /// See [`SenderVerification::post_verified_sender`].
+    async fn post_verified_sender(
+        &self,
+        body: &crate::types::VerifiedSenderRequestSchema,
+    ) -> Result<crate::types::VerifiedSenderResponseSchema>;
+
+    /// See [`SenderVerification::get_verified_senders_verify_token`].
+    async fn get_verified_senders_verify_token(&self, token: &str) -> Result<()>;
+
+    /// See [`SenderVerification::delete_verified_senders`].
+    async fn delete_verified_senders(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::DeleteVerifiedSendersResponse>;
+
+    /// See [`SenderVerification::patch_verified_senders`].
+    async fn patch_verified_senders(
+        &self,
+        id: &str,
+        body: &crate::types::VerifiedSenderRequestSchema,
+    ) -> Result<crate::types::VerifiedSenderResponseSchema>;
+
+    /// See [`SenderVerification::post_verified_senders_resend`].
+    async fn post_verified_senders_resend(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::PostVerifiedSendersResendResponse>;
+}
+
+#[async_trait::async_trait]
+impl SenderVerificationApi for SenderVerification {
+    async fn get_verified_senders_domains(
+        &self,
+    ) -> Result<crate::types::GetVerifiedSendersDomainsResponse> {
+        SenderVerification::get_verified_senders_domains(self).await
+    }
+
+    async fn get_verified_senders_steps_completed(
+        &self,
+    ) -> Result<crate::types::GetVerifiedSendersStepsCompletedResponse> {
+        SenderVerification::get_verified_senders_steps_completed(self).await
+    }
+
+    async fn get_verified_senders_with(
+        &self,
+        params: &SenderVerificationGetVerifiedSendersParams,
+    ) -> Result<crate::types::GetVerifiedSendersResponse> {
+        SenderVerification::get_verified_senders_with(self, params).await
+    }
+
+    async fn post_verified_sender(
+        &self,
+        body: &crate::types::VerifiedSenderRequestSchema,
+    ) -> Result<crate::types::VerifiedSenderResponseSchema> {
+        SenderVerification::post_verified_sender(self, body).await
+    }
+
+    async fn get_verified_senders_verify_token(&self, token: &str) -> Result<()> {
+        SenderVerification::get_verified_senders_verify_token(self, token).await
+    }
+
+    async fn delete_verified_senders(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::DeleteVerifiedSendersResponse> {
+        SenderVerification::delete_verified_senders(self, id).await
+    }
+
+    async fn patch_verified_senders(
+        &self,
+        id: &str,
+        body: &crate::types::VerifiedSenderRequestSchema,
+    ) -> Result<crate::types::VerifiedSenderResponseSchema> {
+        SenderVerification::patch_verified_senders(self, id, body).await
+    }
+
+    async fn post_verified_senders_resend(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::PostVerifiedSendersResendResponse> {
+        SenderVerification::post_verified_senders_resend(self, id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Senders {
     pub client: Client,
@@ -37,3 +35,25 @@ impl Senders {
             .await
     }
 }
+
+/// The functions of [`Senders`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSendersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SendersApi: Send + Sync {
+    /// See [`Senders::post_marketing`].
+    async fn post_marketing(
+        &self,
+        body: &crate::types::PostMarketingSendersRequest,
+    ) -> Result<crate::types::SenderAllOf>;
+}
+
+#[async_trait::async_trait]
+impl SendersApi for Senders {
+    async fn post_marketing(
+        &self,
+        body: &crate::types::PostMarketingSendersRequest,
+    ) -> Result<crate::types::SenderAllOf> {
+        Senders::post_marketing(self, body).await
+    }
+}
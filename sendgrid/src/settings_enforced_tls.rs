@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SettingsEnforcedTls {
     pub client: Client,
@@ -57,3 +55,32 @@ impl SettingsEnforcedTls {
             .await
     }
 }
+
+/// The functions of [`SettingsEnforcedTls`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSettingsEnforcedTlsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SettingsEnforcedTlsApi: Send + Sync {
+    /// See [`SettingsEnforcedTls::get_user`].
+    async fn get_user(&self) -> Result<crate::types::EnforcedTlsRequestResponse>;
+
+    /// See [`SettingsEnforcedTls::patch_user`].
+    async fn patch_user(
+        &self,
+        body: &crate::types::EnforcedTlsRequestResponse,
+    ) -> Result<crate::types::EnforcedTlsRequestResponse>;
+}
+
+#[async_trait::async_trait]
+impl SettingsEnforcedTlsApi for SettingsEnforcedTls {
+    async fn get_user(&self) -> Result<crate::types::EnforcedTlsRequestResponse> {
+        SettingsEnforcedTls::get_user(self).await
+    }
+
+    async fn patch_user(
+        &self,
+        body: &crate::types::EnforcedTlsRequestResponse,
+    ) -> Result<crate::types::EnforcedTlsRequestResponse> {
+        SettingsEnforcedTls::patch_user(self, body).await
+    }
+}
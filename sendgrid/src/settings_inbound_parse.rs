@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SettingsInboundParse {
     pub client: Client,
@@ -82,7 +80,7 @@ impl SettingsInboundParse {
     pub async fn delete_user_webhooks_parse_settings_hostname(
         &self,
         hostname: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteUserWebhooksParseSettingsHostnameResponse> {
         let url = format!(
             "/user/webhooks/parse/settings/{}",
             crate::progenitor_support::encode_path(hostname),
@@ -119,3 +117,67 @@ impl SettingsInboundParse {
             .await
     }
 }
+
+/// The functions of [`SettingsInboundParse`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSettingsInboundParseApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SettingsInboundParseApi: Send + Sync {
+    /// See [`SettingsInboundParse::post_user_webhooks_parse_setting`].
+    async fn post_user_webhooks_parse_setting(
+        &self,
+        body: &crate::types::ParseSetting,
+    ) -> Result<crate::types::ParseSetting>;
+
+    /// See [`SettingsInboundParse::get_user_webhooks_parse_settings_hostname`].
+    async fn get_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+    ) -> Result<crate::types::ParseSetting>;
+
+    /// See [`SettingsInboundParse::delete_user_webhooks_parse_settings_hostname`].
+    async fn delete_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+    ) -> Result<crate::types::DeleteUserWebhooksParseSettingsHostnameResponse>;
+
+    /// See [`SettingsInboundParse::patch_user_webhooks_parse_settings_hostname`].
+    async fn patch_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+        body: &crate::types::ParseSetting,
+    ) -> Result<crate::types::ParseSetting>;
+}
+
+#[async_trait::async_trait]
+impl SettingsInboundParseApi for SettingsInboundParse {
+    async fn post_user_webhooks_parse_setting(
+        &self,
+        body: &crate::types::ParseSetting,
+    ) -> Result<crate::types::ParseSetting> {
+        SettingsInboundParse::post_user_webhooks_parse_setting(self, body).await
+    }
+
+    async fn get_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+    ) -> Result<crate::types::ParseSetting> {
+        SettingsInboundParse::get_user_webhooks_parse_settings_hostname(self, hostname).await
+    }
+
+    async fn delete_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+    ) -> Result<crate::types::DeleteUserWebhooksParseSettingsHostnameResponse> {
+        SettingsInboundParse::delete_user_webhooks_parse_settings_hostname(self, hostname).await
+    }
+
+    async fn patch_user_webhooks_parse_settings_hostname(
+        &self,
+        hostname: &str,
+        body: &crate::types::ParseSetting,
+    ) -> Result<crate::types::ParseSetting> {
+        SettingsInboundParse::patch_user_webhooks_parse_settings_hostname(self, hostname, body)
+            .await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SettingsMail {
     pub client: Client,
@@ -23,20 +21,17 @@ impl SettingsMail {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- The number of settings to return.
-     * * `offset: i64` -- Where in the list of results to begin displaying settings.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &SettingsMailGetMailSettingsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_mail_settings(
+    pub async fn get_mail_settings_with(
         &self,
-        limit: i64,
-        offset: i64,
+        params: &SettingsMailGetMailSettingsParams,
     ) -> Result<crate::types::GetMailSettingsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -45,6 +40,22 @@ impl SettingsMail {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_mail_settings_with` and pass the query parameters as a `SettingsMailGetMailSettingsParams` instead.
+     */
+    #[deprecated(note = "use `get_mail_settings_with` instead")]
+    pub async fn get_mail_settings(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetMailSettingsResponse> {
+        let params = SettingsMailGetMailSettingsParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_mail_settings_with(&params).await
+    }
+
     /**
      * Retrieve address whitelist mail settings.
      *
@@ -344,3 +355,180 @@ impl SettingsMail {
             .await
     }
 }
+
+/// The query parameters of `SettingsMail::get_mail_settings_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SettingsMailGetMailSettingsParams {
+    /// The number of settings to return.
+    pub limit: Option<i64>,
+    /// Where in the list of results to begin displaying settings.
+    pub offset: Option<i64>,
+}
+
+impl SettingsMailGetMailSettingsParams {
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The functions of [`SettingsMail`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSettingsMailApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SettingsMailApi: Send + Sync {
+    /// See [`SettingsMail::get_mail_settings_with`].
+    async fn get_mail_settings_with(
+        &self,
+        params: &SettingsMailGetMailSettingsParams,
+    ) -> Result<crate::types::GetMailSettingsResponse>;
+
+    /// See [`SettingsMail::get_mail_settings_address_whitelist`].
+    async fn get_mail_settings_address_whitelist(
+        &self,
+    ) -> Result<crate::types::MailSettingsAddressWhitelabel>;
+
+    /// See [`SettingsMail::patch_mail_settings_address_whitelist`].
+    async fn patch_mail_settings_address_whitelist(
+        &self,
+        body: &crate::types::PatchMailSettingsAddressWhitelistRequest,
+    ) -> Result<crate::types::MailSettingsAddressWhitelabel>;
+
+    /// See [`SettingsMail::get_mail_settings_footer`].
+    async fn get_mail_settings_footer(&self) -> Result<crate::types::MailSettingsFooter>;
+
+    /// See [`SettingsMail::patch_mail_settings_footer`].
+    async fn patch_mail_settings_footer(
+        &self,
+        body: &crate::types::MailSettingsFooter,
+    ) -> Result<crate::types::MailSettingsFooter>;
+
+    /// See [`SettingsMail::get_mail_settings_forward_spam`].
+    async fn get_mail_settings_forward_spam(&self)
+        -> Result<crate::types::MailSettingsForwardSpam>;
+
+    /// See [`SettingsMail::patch_mail_settings_forward_spam`].
+    async fn patch_mail_settings_forward_spam(
+        &self,
+        body: &crate::types::MailSettingsForwardSpam,
+    ) -> Result<crate::types::MailSettingsForwardSpam>;
+
+    /// See [`SettingsMail::get_mail_settings_template`].
+    async fn get_mail_settings_template(&self) -> Result<crate::types::MailSettingsTemplate>;
+
+    /// See [`SettingsMail::patch_mail_settings_template`].
+    async fn patch_mail_settings_template(
+        &self,
+        body: &crate::types::PatchMailSettingsTemplateRequest,
+    ) -> Result<crate::types::PatchMailSettingsTemplateResponse>;
+
+    /// See [`SettingsMail::get_mail_settings_bounce_purge`].
+    async fn get_mail_settings_bounce_purge(&self)
+        -> Result<crate::types::MailSettingsBouncePurge>;
+
+    /// See [`SettingsMail::patch_mail_settings_bounce_purge`].
+    async fn patch_mail_settings_bounce_purge(
+        &self,
+        body: &crate::types::MailSettingsBouncePurge,
+    ) -> Result<crate::types::MailSettingsBouncePurge>;
+
+    /// See [`SettingsMail::get_mail_settings_forward_bounce`].
+    async fn get_mail_settings_forward_bounce(
+        &self,
+    ) -> Result<crate::types::MailSettingsForwardBounce>;
+
+    /// See [`SettingsMail::patch_mail_settings_forward_bounce`].
+    async fn patch_mail_settings_forward_bounce(
+        &self,
+        body: &crate::types::MailSettingsForwardBounce,
+    ) -> Result<crate::types::MailSettingsForwardBounce>;
+}
+
+#[async_trait::async_trait]
+impl SettingsMailApi for SettingsMail {
+    async fn get_mail_settings_with(
+        &self,
+        params: &SettingsMailGetMailSettingsParams,
+    ) -> Result<crate::types::GetMailSettingsResponse> {
+        SettingsMail::get_mail_settings_with(self, params).await
+    }
+
+    async fn get_mail_settings_address_whitelist(
+        &self,
+    ) -> Result<crate::types::MailSettingsAddressWhitelabel> {
+        SettingsMail::get_mail_settings_address_whitelist(self).await
+    }
+
+    async fn patch_mail_settings_address_whitelist(
+        &self,
+        body: &crate::types::PatchMailSettingsAddressWhitelistRequest,
+    ) -> Result<crate::types::MailSettingsAddressWhitelabel> {
+        SettingsMail::patch_mail_settings_address_whitelist(self, body).await
+    }
+
+    async fn get_mail_settings_footer(&self) -> Result<crate::types::MailSettingsFooter> {
+        SettingsMail::get_mail_settings_footer(self).await
+    }
+
+    async fn patch_mail_settings_footer(
+        &self,
+        body: &crate::types::MailSettingsFooter,
+    ) -> Result<crate::types::MailSettingsFooter> {
+        SettingsMail::patch_mail_settings_footer(self, body).await
+    }
+
+    async fn get_mail_settings_forward_spam(
+        &self,
+    ) -> Result<crate::types::MailSettingsForwardSpam> {
+        SettingsMail::get_mail_settings_forward_spam(self).await
+    }
+
+    async fn patch_mail_settings_forward_spam(
+        &self,
+        body: &crate::types::MailSettingsForwardSpam,
+    ) -> Result<crate::types::MailSettingsForwardSpam> {
+        SettingsMail::patch_mail_settings_forward_spam(self, body).await
+    }
+
+    async fn get_mail_settings_template(&self) -> Result<crate::types::MailSettingsTemplate> {
+        SettingsMail::get_mail_settings_template(self).await
+    }
+
+    async fn patch_mail_settings_template(
+        &self,
+        body: &crate::types::PatchMailSettingsTemplateRequest,
+    ) -> Result<crate::types::PatchMailSettingsTemplateResponse> {
+        SettingsMail::patch_mail_settings_template(self, body).await
+    }
+
+    async fn get_mail_settings_bounce_purge(
+        &self,
+    ) -> Result<crate::types::MailSettingsBouncePurge> {
+        SettingsMail::get_mail_settings_bounce_purge(self).await
+    }
+
+    async fn patch_mail_settings_bounce_purge(
+        &self,
+        body: &crate::types::MailSettingsBouncePurge,
+    ) -> Result<crate::types::MailSettingsBouncePurge> {
+        SettingsMail::patch_mail_settings_bounce_purge(self, body).await
+    }
+
+    async fn get_mail_settings_forward_bounce(
+        &self,
+    ) -> Result<crate::types::MailSettingsForwardBounce> {
+        SettingsMail::get_mail_settings_forward_bounce(self).await
+    }
+
+    async fn patch_mail_settings_forward_bounce(
+        &self,
+        body: &crate::types::MailSettingsForwardBounce,
+    ) -> Result<crate::types::MailSettingsForwardBounce> {
+        SettingsMail::patch_mail_settings_forward_bounce(self, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SettingsPartner {
     pub client: Client,
@@ -70,20 +68,17 @@ impl SettingsPartner {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- The number of settings to return per page.
-     * * `offset: i64` -- The paging offset.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &SettingsPartnerGetPartnerSettingsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_partner_settings(
+    pub async fn get_partner_settings_with(
         &self,
-        limit: i64,
-        offset: i64,
+        params: &SettingsPartnerGetPartnerSettingsParams,
     ) -> Result<crate::types::GetPartnerSettingsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -91,4 +86,86 @@ impl SettingsPartner {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_partner_settings_with` and pass the query parameters as a `SettingsPartnerGetPartnerSettingsParams` instead.
+     */
+    #[deprecated(note = "use `get_partner_settings_with` instead")]
+    pub async fn get_partner_settings(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::GetPartnerSettingsResponse> {
+        let params = SettingsPartnerGetPartnerSettingsParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_partner_settings_with(&params).await
+    }
+}
+
+/// The query parameters of `SettingsPartner::get_partner_settings_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SettingsPartnerGetPartnerSettingsParams {
+    /// The number of settings to return per page.
+    pub limit: Option<i64>,
+    /// The paging offset.
+    pub offset: Option<i64>,
+}
+
+impl SettingsPartnerGetPartnerSettingsParams {
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The functions of [`SettingsPartner`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSettingsPartnerApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SettingsPartnerApi: Send + Sync {
+    /// See [`SettingsPartner::get_partner_settings_new_relic`].
+    async fn get_partner_settings_new_relic(&self)
+        -> Result<crate::types::PartnerSettingsNewRelic>;
+
+    /// See [`SettingsPartner::patch_partner_settings_new_relic`].
+    async fn patch_partner_settings_new_relic(
+        &self,
+        body: &crate::types::PatchPartnerSettingsNewRelicRequest,
+    ) -> Result<crate::types::PartnerSettingsNewRelic>;
+
+    /// See [`SettingsPartner::get_partner_settings_with`].
+    async fn get_partner_settings_with(
+        &self,
+        params: &SettingsPartnerGetPartnerSettingsParams,
+    ) -> Result<crate::types::GetPartnerSettingsResponse>;
+}
+
+#[async_trait::async_trait]
+impl SettingsPartnerApi for SettingsPartner {
+    async fn get_partner_settings_new_relic(
+        &self,
+    ) -> Result<crate::types::PartnerSettingsNewRelic> {
+        SettingsPartner::get_partner_settings_new_relic(self).await
+    }
+
+    async fn patch_partner_settings_new_relic(
+        &self,
+        body: &crate::types::PatchPartnerSettingsNewRelicRequest,
+    ) -> Result<crate::types::PartnerSettingsNewRelic> {
+        SettingsPartner::patch_partner_settings_new_relic(self, body).await
+    }
+
+    async fn get_partner_settings_with(
+        &self,
+        params: &SettingsPartnerGetPartnerSettingsParams,
+    ) -> Result<crate::types::GetPartnerSettingsResponse> {
+        SettingsPartner::get_partner_settings_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SettingsTracking {
     pub client: Client,
@@ -65,7 +63,7 @@ impl SettingsTracking {
      */
     pub async fn patch_tracking_settings_click(
         &self,
-        body: &crate::types::PatchTrackingSettingsOpenRequest,
+        body: &crate::types::PatchTrackingSettingsClickRequest,
     ) -> Result<crate::types::ClickTracking> {
         let url = "/tracking_settings/click".to_string();
         self.client
@@ -165,7 +163,7 @@ impl SettingsTracking {
     pub async fn patch_tracking_settings_open(
         &self,
         body: &crate::types::PatchTrackingSettingsOpenRequest,
-    ) -> Result<crate::types::GetTrackingSettingsOpenResponse> {
+    ) -> Result<crate::types::PatchTrackingSettingsOpenResponse> {
         let url = "/tracking_settings/open".to_string();
         self.client
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -215,3 +213,111 @@ impl SettingsTracking {
             .await
     }
 }
+
+/// The functions of [`SettingsTracking`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSettingsTrackingApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SettingsTrackingApi: Send + Sync {
+    /// See [`SettingsTracking::get_tracking_settings`].
+    async fn get_tracking_settings(&self) -> Result<crate::types::GetTrackingSettingsResponse>;
+
+    /// See [`SettingsTracking::get_tracking_settings_click`].
+    async fn get_tracking_settings_click(&self) -> Result<crate::types::ClickTracking>;
+
+    /// See [`SettingsTracking::patch_tracking_settings_click`].
+    async fn patch_tracking_settings_click(
+        &self,
+        body: &crate::types::PatchTrackingSettingsClickRequest,
+    ) -> Result<crate::types::ClickTracking>;
+
+    /// See [`SettingsTracking::get_tracking_settings_google_analytic`].
+    async fn get_tracking_settings_google_analytic(
+        &self,
+    ) -> Result<crate::types::GoogleAnalyticsSettings>;
+
+    /// See [`SettingsTracking::patch_tracking_settings_google_analytics`].
+    async fn patch_tracking_settings_google_analytics(
+        &self,
+        body: &crate::types::GoogleAnalyticsSettings,
+    ) -> Result<crate::types::GoogleAnalyticsSettings>;
+
+    /// See [`SettingsTracking::get_tracking_settings_open`].
+    async fn get_tracking_settings_open(
+        &self,
+    ) -> Result<crate::types::GetTrackingSettingsOpenResponse>;
+
+    /// See [`SettingsTracking::patch_tracking_settings_open`].
+    async fn patch_tracking_settings_open(
+        &self,
+        body: &crate::types::PatchTrackingSettingsOpenRequest,
+    ) -> Result<crate::types::PatchTrackingSettingsOpenResponse>;
+
+    /// See [`SettingsTracking::get_tracking_settings_subscription`].
+    async fn get_tracking_settings_subscription(
+        &self,
+    ) -> Result<crate::types::SubscriptionTrackingSettings>;
+
+    /// See [`SettingsTracking::patch_tracking_settings_subscription`].
+    async fn patch_tracking_settings_subscription(
+        &self,
+        body: &crate::types::SubscriptionTrackingSettings,
+    ) -> Result<crate::types::SubscriptionTrackingSettings>;
+}
+
+#[async_trait::async_trait]
+impl SettingsTrackingApi for SettingsTracking {
+    async fn get_tracking_settings(&self) -> Result<crate::types::GetTrackingSettingsResponse> {
+        SettingsTracking::get_tracking_settings(self).await
+    }
+
+    async fn get_tracking_settings_click(&self) -> Result<crate::types::ClickTracking> {
+        SettingsTracking::get_tracking_settings_click(self).await
+    }
+
+    async fn patch_tracking_settings_click(
+        &self,
+        body: &crate::types::PatchTrackingSettingsClickRequest,
+    ) -> Result<crate::types::ClickTracking> {
+        SettingsTracking::patch_tracking_settings_click(self, body).await
+    }
+
+    async fn get_tracking_settings_google_analytic(
+        &self,
+    ) -> Result<crate::types::GoogleAnalyticsSettings> {
+        SettingsTracking::get_tracking_settings_google_analytic(self).await
+    }
+
+    async fn patch_tracking_settings_google_analytics(
+        &self,
+        body: &crate::types::GoogleAnalyticsSettings,
+    ) -> Result<crate::types::GoogleAnalyticsSettings> {
+        SettingsTracking::patch_tracking_settings_google_analytics(self, body).await
+    }
+
+    async fn get_tracking_settings_open(
+        &self,
+    ) -> Result<crate::types::GetTrackingSettingsOpenResponse> {
+        SettingsTracking::get_tracking_settings_open(self).await
+    }
+
+    async fn patch_tracking_settings_open(
+        &self,
+        body: &crate::types::PatchTrackingSettingsOpenRequest,
+    ) -> Result<crate::types::PatchTrackingSettingsOpenResponse> {
+        SettingsTracking::patch_tracking_settings_open(self, body).await
+    }
+
+    async fn get_tracking_settings_subscription(
+        &self,
+    ) -> Result<crate::types::SubscriptionTrackingSettings> {
+        SettingsTracking::get_tracking_settings_subscription(self).await
+    }
+
+    async fn patch_tracking_settings_subscription(
+        &self,
+        body: &crate::types::SubscriptionTrackingSettings,
+    ) -> Result<crate::types::SubscriptionTrackingSettings> {
+        SettingsTracking::patch_tracking_settings_subscription(self, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SingleSends {
     pub client: Client,
@@ -23,19 +21,17 @@ impl SingleSends {
      *
      * **Parameters:**
      *
-     * * `page_size: i64`
-     * * `page_token: &str` -- The license key provided with your New Relic account.
+     * * `params: &SingleSendsGetMarketingSinglesendsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_marketing_singlesends(
+    pub async fn get_marketing_singlesends_with(
         &self,
-        page_size: i64,
-        page_token: &str,
+        params: &SingleSendsGetMarketingSinglesendsParams,
     ) -> Result<crate::types::GetMarketingSinglesendsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("page_token".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -44,6 +40,22 @@ impl SingleSends {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_marketing_singlesends_with` and pass the query parameters as a `SingleSendsGetMarketingSinglesendsParams` instead.
+     */
+    #[deprecated(note = "use `get_marketing_singlesends_with` instead")]
+    pub async fn get_marketing_singlesends(
+        &self,
+        page_size: i64,
+        page_token: &str,
+    ) -> Result<crate::types::GetMarketingSinglesendsResponse> {
+        let params = SingleSendsGetMarketingSinglesendsParams {
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_marketing_singlesends_with(&params).await
+    }
+
     /**
      * Create Single Send.
      *
@@ -76,11 +88,14 @@ impl SingleSends {
      *
      * **Parameters:**
      *
-     * * `ids: &[String]` -- The recipient IDs of the recipients that already existed from this request.
+     * * `params: &SingleSendsDeleteMarketingSinglesendsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_marketing_singlesends(&self, ids: &[String]) -> Result<()> {
+    pub async fn delete_marketing_singlesends_with(
+        &self,
+        params: &SingleSendsDeleteMarketingSinglesendsParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -89,6 +104,17 @@ impl SingleSends {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_marketing_singlesends_with` and pass the query parameters as a `SingleSendsDeleteMarketingSinglesendsParams` instead.
+     */
+    #[deprecated(note = "use `delete_marketing_singlesends_with` instead")]
+    pub async fn delete_marketing_singlesends(&self, ids: &[String]) -> Result<()> {
+        let params = SingleSendsDeleteMarketingSinglesendsParams {
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.delete_marketing_singlesends_with(&params).await
+    }
+
     /**
      * Get Single Send by ID.
      *
@@ -205,20 +231,18 @@ impl SingleSends {
      *
      * **Parameters:**
      *
-     * * `page_size: i64`
-     * * `page_token: &str` -- The license key provided with your New Relic account.
+     * * `params: &SingleSendsPostMarketingSinglesendsSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn post_marketing_singlesends_search(
+    pub async fn post_marketing_singlesends_search_with(
         &self,
-        page_size: i64,
-        page_token: &str,
+        params: &SingleSendsPostMarketingSinglesendsSearchParams,
         body: &crate::types::SinglesendSearch,
-    ) -> Result<crate::types::GetMarketingSinglesendsResponse> {
+    ) -> Result<crate::types::PostMarketingSinglesendsSearchResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("page_token".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -229,6 +253,24 @@ impl SingleSends {
             .await
     }
 
+    /**
+     * Deprecated: use `post_marketing_singlesends_search_with` and pass the query parameters as a `SingleSendsPostMarketingSinglesendsSearchParams` instead.
+     */
+    #[deprecated(note = "use `post_marketing_singlesends_search_with` instead")]
+    pub async fn post_marketing_singlesends_search(
+        &self,
+        page_size: i64,
+        page_token: &str,
+        body: &crate::types::SinglesendSearch,
+    ) -> Result<crate::types::PostMarketingSinglesendsSearchResponse> {
+        let params = SingleSendsPostMarketingSinglesendsSearchParams {
+            page_size: Some(page_size).filter(|v| *v > 0),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.post_marketing_singlesends_search_with(&params, body)
+            .await
+    }
+
     /**
      * Schedule Single Send.
      *
@@ -290,3 +332,209 @@ impl SingleSends {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `SingleSends::get_marketing_singlesends_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SingleSendsGetMarketingSinglesendsParams {
+    pub page_size: Option<i64>,
+    /// The license key provided with your New Relic account.
+    pub page_token: Option<String>,
+}
+
+impl SingleSendsGetMarketingSinglesendsParams {
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SingleSends::delete_marketing_singlesends_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SingleSendsDeleteMarketingSinglesendsParams {
+    /// The recipient IDs of the recipients that already existed from this request.
+    pub ids: Option<Vec<String>>,
+}
+
+impl SingleSendsDeleteMarketingSinglesendsParams {
+    pub fn ids(mut self, ids: Vec<String>) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+}
+
+/// The query parameters of `SingleSends::post_marketing_singlesends_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SingleSendsPostMarketingSinglesendsSearchParams {
+    pub page_size: Option<i64>,
+    /// The license key provided with your New Relic account.
+    pub page_token: Option<String>,
+}
+
+impl SingleSendsPostMarketingSinglesendsSearchParams {
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The functions of [`SingleSends`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSingleSendsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SingleSendsApi: Send + Sync {
+    /// See [`SingleSends::get_marketing_singlesends_with`].
+    async fn get_marketing_singlesends_with(
+        &self,
+        params: &SingleSendsGetMarketingSinglesendsParams,
+    ) -> Result<crate::types::GetMarketingSinglesendsResponse>;
+
+    /// See [`SingleSends::post_marketing_singlesend`].
+    async fn post_marketing_singlesend(
+        &self,
+        body: &crate::types::SinglesendRequest,
+    ) -> Result<crate::types::SinglesendResponseAllOf>;
+
+    /// See [`SingleSends::delete_marketing_singlesends_with`].
+    async fn delete_marketing_singlesends_with(
+        &self,
+        params: &SingleSendsDeleteMarketingSinglesendsParams,
+    ) -> Result<()>;
+
+    /// See [`SingleSends::get_marketing_singlesend`].
+    async fn get_marketing_singlesend(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::SinglesendResponseAllOf>;
+
+    /// See [`SingleSends::post_marketing_singlesend_single_sends`].
+    async fn post_marketing_singlesend_single_sends(
+        &self,
+        id: &str,
+        body: &crate::types::PostMarketingSinglesendsRequest,
+    ) -> Result<crate::types::SinglesendResponseAllOf>;
+
+    /// See [`SingleSends::delete_marketing_singlesends_single_sends`].
+    async fn delete_marketing_singlesends_single_sends(&self, id: &str) -> Result<()>;
+
+    /// See [`SingleSends::patch_marketing_singlesends`].
+    async fn patch_marketing_singlesends(
+        &self,
+        id: &str,
+        body: &crate::types::SinglesendRequest,
+    ) -> Result<crate::types::SinglesendResponseAllOf>;
+
+    /// See [`SingleSends::post_marketing_singlesends_search_with`].
+    async fn post_marketing_singlesends_search_with(
+        &self,
+        params: &SingleSendsPostMarketingSinglesendsSearchParams,
+        body: &crate::types::SinglesendSearch,
+    ) -> Result<crate::types::PostMarketingSinglesendsSearchResponse>;
+
+    /// See [`SingleSends::put_marketing_singlesends_schedule`].
+    async fn put_marketing_singlesends_schedule(
+        &self,
+        id: &str,
+        body: &crate::types::PutMarketingSinglesendsScheduleRequest,
+    ) -> Result<crate::types::PutMarketingSinglesendsScheduleResponse>;
+
+    /// See [`SingleSends::delete_marketing_singlesends_schedule`].
+    async fn delete_marketing_singlesends_schedule(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::SinglesendSchedule>;
+
+    /// See [`SingleSends::get_marketing_singlesends_categories`].
+    async fn get_marketing_singlesends_categories(
+        &self,
+    ) -> Result<crate::types::GetMarketingSinglesendsCategoriesResponse>;
+}
+
+#[async_trait::async_trait]
+impl SingleSendsApi for SingleSends {
+    async fn get_marketing_singlesends_with(
+        &self,
+        params: &SingleSendsGetMarketingSinglesendsParams,
+    ) -> Result<crate::types::GetMarketingSinglesendsResponse> {
+        SingleSends::get_marketing_singlesends_with(self, params).await
+    }
+
+    async fn post_marketing_singlesend(
+        &self,
+        body: &crate::types::SinglesendRequest,
+    ) -> Result<crate::types::SinglesendResponseAllOf> {
+        SingleSends::post_marketing_singlesend(self, body).await
+    }
+
+    async fn delete_marketing_singlesends_with(
+        &self,
+        params: &SingleSendsDeleteMarketingSinglesendsParams,
+    ) -> Result<()> {
+        SingleSends::delete_marketing_singlesends_with(self, params).await
+    }
+
+    async fn get_marketing_singlesend(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::SinglesendResponseAllOf> {
+        SingleSends::get_marketing_singlesend(self, id).await
+    }
+
+    async fn post_marketing_singlesend_single_sends(
+        &self,
+        id: &str,
+        body: &crate::types::PostMarketingSinglesendsRequest,
+    ) -> Result<crate::types::SinglesendResponseAllOf> {
+        SingleSends::post_marketing_singlesend_single_sends(self, id, body).await
+    }
+
+    async fn delete_marketing_singlesends_single_sends(&self, id: &str) -> Result<()> {
+        SingleSends::delete_marketing_singlesends_single_sends(self, id).await
+    }
+
+    async fn patch_marketing_singlesends(
+        &self,
+        id: &str,
+        body: &crate::types::SinglesendRequest,
+    ) -> Result<crate::types::SinglesendResponseAllOf> {
+        SingleSends::patch_marketing_singlesends(self, id, body).await
+    }
+
+    async fn post_marketing_singlesends_search_with(
+        &self,
+        params: &SingleSendsPostMarketingSinglesendsSearchParams,
+        body: &crate::types::SinglesendSearch,
+    ) -> Result<crate::types::PostMarketingSinglesendsSearchResponse> {
+        SingleSends::post_marketing_singlesends_search_with(self, params, body).await
+    }
+
+    async fn put_marketing_singlesends_schedule(
+        &self,
+        id: &str,
+        body: &crate::types::PutMarketingSinglesendsScheduleRequest,
+    ) -> Result<crate::types::PutMarketingSinglesendsScheduleResponse> {
+        SingleSends::put_marketing_singlesends_schedule(self, id, body).await
+    }
+
+    async fn delete_marketing_singlesends_schedule(
+        &self,
+        id: &str,
+    ) -> Result<crate::types::SinglesendSchedule> {
+        SingleSends::delete_marketing_singlesends_schedule(self, id).await
+    }
+
+    async fn get_marketing_singlesends_categories(
+        &self,
+    ) -> Result<crate::types::GetMarketingSinglesendsCategoriesResponse> {
+        SingleSends::get_marketing_singlesends_categories(self).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SingleSignOnSettings {
     pub client: Client,
@@ -23,14 +21,14 @@ impl SingleSignOnSettings {
      *
      * **Parameters:**
      *
-     * * `si: bool` -- If this parameter is set to `true`, the response will include the `completed_integration` field.
+     * * `params: &SingleSignOnSettingsGetSsoIntegrationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_sso_integrations(
+    pub async fn get_sso_integrations_with(
         &self,
-        si: bool,
+        params: &SingleSignOnSettingsGetSsoIntegrationsParams,
     ) -> Result<Vec<crate::types::SsoIntegrationAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if si {
+        if let Some(si) = &params.si {
             query_args.push(("si".to_string(), si.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -39,6 +37,20 @@ impl SingleSignOnSettings {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_sso_integrations_with` and pass the query parameters as a `SingleSignOnSettingsGetSsoIntegrationsParams` instead.
+     */
+    #[deprecated(note = "use `get_sso_integrations_with` instead")]
+    pub async fn get_sso_integrations(
+        &self,
+        si: bool,
+    ) -> Result<Vec<crate::types::SsoIntegrationAllOf>> {
+        let params = SingleSignOnSettingsGetSsoIntegrationsParams {
+            si: Some(si).filter(|v| *v),
+        };
+        self.get_sso_integrations_with(&params).await
+    }
+
     /**
      * Get All SSO Integrations.
      *
@@ -49,19 +61,79 @@ impl SingleSignOnSettings {
      * **This endpoint allows you to retrieve all SSO integrations tied to your Twilio SendGrid account.**
      *
      * The IDs returned by this endpoint can be used by the APIs additional endpoints to modify your SSO integrations.
+     *
+     * **Parameters:**
+     *
+     * * `params: &SingleSignOnSettingsGetSsoIntegrationsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_sso_integrations_with(
+        &self,
+        params: &SingleSignOnSettingsGetSsoIntegrationsParams,
+    ) -> Result<Vec<crate::types::SsoIntegrationAllOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(si) = &params.si {
+            query_args.push(("si".to_string(), si.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/sso/integrations?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_sso_integrations_with` and pass the query parameters as a `SingleSignOnSettingsGetSsoIntegrationsParams` instead.
      */
+    #[deprecated(note = "use `get_all_sso_integrations_with` instead")]
     pub async fn get_all_sso_integrations(
         &self,
         si: bool,
     ) -> Result<Vec<crate::types::SsoIntegrationAllOf>> {
+        let params = SingleSignOnSettingsGetSsoIntegrationsParams {
+            si: Some(si).filter(|v| *v),
+        };
+        self.get_all_sso_integrations_with(&params).await
+    }
+
+    /**
+     * Get All SSO Integrations.
+     *
+     * This function performs a `GET` to the `/sso/integrations` endpoint.
+     *
+     * As opposed to `get_all_sso_integrations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve all SSO integrations tied to your Twilio SendGrid account.**
+     *
+     * The IDs returned by this endpoint can be used by the APIs additional endpoints to modify your SSO integrations.
+     */
+    pub fn get_sso_integrations_stream(
+        &self,
+        params: &SingleSignOnSettingsGetSsoIntegrationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SsoIntegrationAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if si {
+        if let Some(si) = &params.si {
             query_args.push(("si".to_string(), si.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/sso/integrations?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -92,15 +164,15 @@ impl SingleSignOnSettings {
      *
      * **Parameters:**
      *
-     * * `si: bool` -- If this parameter is set to `true`, the response will include the `completed_integration` field.
+     * * `params: &SingleSignOnSettingsGetSsoIntegrationParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_sso_integration(
+    pub async fn get_sso_integration_with(
         &self,
         id: &str,
-        si: bool,
+        params: &SingleSignOnSettingsGetSsoIntegrationParams,
     ) -> Result<crate::types::SsoIntegrationAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if si {
+        if let Some(si) = &params.si {
             query_args.push(("si".to_string(), si.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -113,6 +185,21 @@ impl SingleSignOnSettings {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_sso_integration_with` and pass the query parameters as a `SingleSignOnSettingsGetSsoIntegrationParams` instead.
+     */
+    #[deprecated(note = "use `get_sso_integration_with` instead")]
+    pub async fn get_sso_integration(
+        &self,
+        id: &str,
+        si: bool,
+    ) -> Result<crate::types::SsoIntegrationAllOf> {
+        let params = SingleSignOnSettingsGetSsoIntegrationParams {
+            si: Some(si).filter(|v| *v),
+        };
+        self.get_sso_integration_with(id, &params).await
+    }
+
     /**
      * Delete an SSO Integration.
      *
@@ -142,16 +229,16 @@ impl SingleSignOnSettings {
      *
      * **Parameters:**
      *
-     * * `si: bool` -- If this parameter is set to `true`, the response will include the `completed_integration` field.
+     * * `params: &SingleSignOnSettingsPatchSsoIntegrationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn patch_sso_integrations(
+    pub async fn patch_sso_integrations_with(
         &self,
         id: &str,
-        si: bool,
+        params: &SingleSignOnSettingsPatchSsoIntegrationsParams,
         body: &crate::types::CreateIntegrationRequest,
     ) -> Result<crate::types::SsoIntegrationAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if si {
+        if let Some(si) = &params.si {
             query_args.push(("si".to_string(), si.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -165,4 +252,166 @@ impl SingleSignOnSettings {
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `patch_sso_integrations_with` and pass the query parameters as a `SingleSignOnSettingsPatchSsoIntegrationsParams` instead.
+     */
+    #[deprecated(note = "use `patch_sso_integrations_with` instead")]
+    pub async fn patch_sso_integrations(
+        &self,
+        id: &str,
+        si: bool,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> Result<crate::types::SsoIntegrationAllOf> {
+        let params = SingleSignOnSettingsPatchSsoIntegrationsParams {
+            si: Some(si).filter(|v| *v),
+        };
+        self.patch_sso_integrations_with(id, &params, body).await
+    }
+}
+
+/// The query parameters of `SingleSignOnSettings::get_sso_integrations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SingleSignOnSettingsGetSsoIntegrationsParams {
+    /// If this parameter is set to `true`, the response will include the `completed_integration` field.
+    pub si: Option<bool>,
+}
+
+impl SingleSignOnSettingsGetSsoIntegrationsParams {
+    pub fn si(mut self, si: bool) -> Self {
+        self.si = Some(si);
+        self
+    }
+}
+
+/// The query parameters of `SingleSignOnSettings::get_sso_integration_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SingleSignOnSettingsGetSsoIntegrationParams {
+    /// If this parameter is set to `true`, the response will include the `completed_integration` field.
+    pub si: Option<bool>,
+}
+
+impl SingleSignOnSettingsGetSsoIntegrationParams {
+    pub fn si(mut self, si: bool) -> Self {
+        self.si = Some(si);
+        self
+    }
+}
+
+/// The query parameters of `SingleSignOnSettings::patch_sso_integrations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SingleSignOnSettingsPatchSsoIntegrationsParams {
+    /// If this parameter is set to `true`, the response will include the `completed_integration` field.
+    pub si: Option<bool>,
+}
+
+impl SingleSignOnSettingsPatchSsoIntegrationsParams {
+    pub fn si(mut self, si: bool) -> Self {
+        self.si = Some(si);
+        self
+    }
+}
+
+/// The functions of [`SingleSignOnSettings`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSingleSignOnSettingsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SingleSignOnSettingsApi: Send + Sync {
+    /// See [`SingleSignOnSettings::get_sso_integrations_with`].
+    async fn get_sso_integrations_with(
+        &self,
+        params: &SingleSignOnSettingsGetSsoIntegrationsParams,
+    ) -> Result<Vec<crate::types::SsoIntegrationAllOf>>;
+
+    /// See [`SingleSignOnSettings::get_all_sso_integrations_with`].
+    async fn get_all_sso_integrations_with(
+        &self,
+        params: &SingleSignOnSettingsGetSsoIntegrationsParams,
+    ) -> Result<Vec<crate::types::SsoIntegrationAllOf>>;
+
+    /// See [`SingleSignOnSettings::get_sso_integrations_stream`].
+    fn get_sso_integrations_stream(
+        &self,
+        params: &SingleSignOnSettingsGetSsoIntegrationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SsoIntegrationAllOf>;
+
+    /// See [`SingleSignOnSettings::post_sso_integration`].
+    async fn post_sso_integration(
+        &self,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> Result<crate::types::SsoIntegrationAllOf>;
+
+    /// See [`SingleSignOnSettings::get_sso_integration_with`].
+    async fn get_sso_integration_with(
+        &self,
+        id: &str,
+        params: &SingleSignOnSettingsGetSsoIntegrationParams,
+    ) -> Result<crate::types::SsoIntegrationAllOf>;
+
+    /// See [`SingleSignOnSettings::delete_sso_integrations`].
+    async fn delete_sso_integrations(&self, id: &str) -> Result<()>;
+
+    /// See [`SingleSignOnSettings::patch_sso_integrations_with`].
+    async fn patch_sso_integrations_with(
+        &self,
+        id: &str,
+        params: &SingleSignOnSettingsPatchSsoIntegrationsParams,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> Result<crate::types::SsoIntegrationAllOf>;
+}
+
+#[async_trait::async_trait]
+impl SingleSignOnSettingsApi for SingleSignOnSettings {
+    async fn get_sso_integrations_with(
+        &self,
+        params: &SingleSignOnSettingsGetSsoIntegrationsParams,
+    ) -> Result<Vec<crate::types::SsoIntegrationAllOf>> {
+        SingleSignOnSettings::get_sso_integrations_with(self, params).await
+    }
+
+    async fn get_all_sso_integrations_with(
+        &self,
+        params: &SingleSignOnSettingsGetSsoIntegrationsParams,
+    ) -> Result<Vec<crate::types::SsoIntegrationAllOf>> {
+        SingleSignOnSettings::get_all_sso_integrations_with(self, params).await
+    }
+
+    fn get_sso_integrations_stream(
+        &self,
+        params: &SingleSignOnSettingsGetSsoIntegrationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SsoIntegrationAllOf> {
+        SingleSignOnSettings::get_sso_integrations_stream(self, params, start, max_items)
+    }
+
+    async fn post_sso_integration(
+        &self,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> Result<crate::types::SsoIntegrationAllOf> {
+        SingleSignOnSettings::post_sso_integration(self, body).await
+    }
+
+    async fn get_sso_integration_with(
+        &self,
+        id: &str,
+        params: &SingleSignOnSettingsGetSsoIntegrationParams,
+    ) -> Result<crate::types::SsoIntegrationAllOf> {
+        SingleSignOnSettings::get_sso_integration_with(self, id, params).await
+    }
+
+    async fn delete_sso_integrations(&self, id: &str) -> Result<()> {
+        SingleSignOnSettings::delete_sso_integrations(self, id).await
+    }
+
+    async fn patch_sso_integrations_with(
+        &self,
+        id: &str,
+        params: &SingleSignOnSettingsPatchSsoIntegrationsParams,
+        body: &crate::types::CreateIntegrationRequest,
+    ) -> Result<crate::types::SsoIntegrationAllOf> {
+        SingleSignOnSettings::patch_sso_integrations_with(self, id, params, body).await
+    }
 }
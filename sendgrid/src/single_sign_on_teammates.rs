@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SingleSignOnTeammates {
     pub client: Client,
@@ -57,3 +55,40 @@ impl SingleSignOnTeammates {
             .await
     }
 }
+
+/// The functions of [`SingleSignOnTeammates`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSingleSignOnTeammatesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SingleSignOnTeammatesApi: Send + Sync {
+    /// See [`SingleSignOnTeammates::post_sso_teammate`].
+    async fn post_sso_teammate(
+        &self,
+        body: &crate::types::SsoTeammateRequestAllOf,
+    ) -> Result<crate::types::SsoTeammateResponseAllOf>;
+
+    /// See [`SingleSignOnTeammates::patch_sso_teammates_username`].
+    async fn patch_sso_teammates_username(
+        &self,
+        username: &str,
+        body: &crate::types::PatchSsoTeammatesUsernameRequest,
+    ) -> Result<crate::types::SsoTeammatesPatchResponseAllOf>;
+}
+
+#[async_trait::async_trait]
+impl SingleSignOnTeammatesApi for SingleSignOnTeammates {
+    async fn post_sso_teammate(
+        &self,
+        body: &crate::types::SsoTeammateRequestAllOf,
+    ) -> Result<crate::types::SsoTeammateResponseAllOf> {
+        SingleSignOnTeammates::post_sso_teammate(self, body).await
+    }
+
+    async fn patch_sso_teammates_username(
+        &self,
+        username: &str,
+        body: &crate::types::PatchSsoTeammatesUsernameRequest,
+    ) -> Result<crate::types::SsoTeammatesPatchResponseAllOf> {
+        SingleSignOnTeammates::patch_sso_teammates_username(self, username, body).await
+    }
+}
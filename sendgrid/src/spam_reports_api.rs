@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SpamReportsApi {
     pub client: Client,
@@ -21,30 +19,23 @@ impl SpamReportsApi {
      *
      * **Parameters:**
      *
-     * * `start_time: i64` -- The start of the time range when a spam report was created (inclusive). This is a unix timestamp.
-     * * `end_time: i64` -- The end of the time range when a spam report was created (inclusive). This is a unix timestamp.
-     * * `limit: i64` -- Limit the number of results to be displayed per page.
-     * * `offset: i64` -- Paging offset. The point in the list to begin displaying results.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &SpamReportsApiGetSuppressionSpamReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_suppression_spam_reports(
+    pub async fn get_suppression_spam_reports_with(
         &self,
-        start_time: i64,
-        end_time: i64,
-        limit: i64,
-        offset: i64,
+        params: &SpamReportsApiGetSuppressionSpamReportsParams,
     ) -> Result<Vec<crate::types::SpamReportsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if end_time > 0 {
+        if let Some(end_time) = &params.end_time {
             query_args.push(("end_time".to_string(), end_time.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if start_time > 0 {
+        if let Some(start_time) = &params.start_time {
             query_args.push(("start_time".to_string(), start_time.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -53,6 +44,26 @@ impl SpamReportsApi {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_suppression_spam_reports_with` and pass the query parameters as a `SpamReportsApiGetSuppressionSpamReportsParams` instead.
+     */
+    #[deprecated(note = "use `get_suppression_spam_reports_with` instead")]
+    pub async fn get_suppression_spam_reports(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>> {
+        let params = SpamReportsApiGetSuppressionSpamReportsParams {
+            end_time: Some(end_time).filter(|v| *v > 0),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_time: Some(start_time).filter(|v| *v > 0),
+        };
+        self.get_suppression_spam_reports_with(&params).await
+    }
+
     /**
      * Retrieve all spam reports.
      *
@@ -61,27 +72,94 @@ impl SpamReportsApi {
      * As opposed to `get_suppression_spam_reports`, this function returns all the pages of the request at once.
      *
      * **This endpoint allows you to retrieve all spam reports.**
+     *
+     * **Parameters:**
+     *
+     * * `params: &SpamReportsApiGetSuppressionSpamReportsParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_suppression_spam_reports_with(
+        &self,
+        params: &SpamReportsApiGetSuppressionSpamReportsParams,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(end_time) = &params.end_time {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(start_time) = &params.start_time {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/suppression/spam_reports?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_suppression_spam_reports_with` and pass the query parameters as a `SpamReportsApiGetSuppressionSpamReportsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_suppression_spam_reports_with` instead")]
     pub async fn get_all_suppression_spam_reports(
         &self,
         start_time: i64,
         end_time: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::SpamReportsResponse>> {
+        let params = SpamReportsApiGetSuppressionSpamReportsParams {
+            end_time: Some(end_time).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_time: Some(start_time).filter(|v| *v > 0),
+            ..Default::default()
+        };
+        self.get_all_suppression_spam_reports_with(&params).await
+    }
+
+    /**
+     * Retrieve all spam reports.
+     *
+     * This function performs a `GET` to the `/suppression/spam_reports` endpoint.
+     *
+     * As opposed to `get_all_suppression_spam_reports`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve all spam reports.**
+     */
+    pub fn get_suppression_spam_reports_stream(
+        &self,
+        params: &SpamReportsApiGetSuppressionSpamReportsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SpamReportsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if end_time > 0 {
+        if let Some(end_time) = &params.end_time {
             query_args.push(("end_time".to_string(), end_time.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if start_time > 0 {
+        if let Some(start_time) = &params.start_time {
             query_args.push(("start_time".to_string(), start_time.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/suppression/spam_reports?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -104,8 +182,8 @@ impl SpamReportsApi {
      */
     pub async fn delete_suppression_spam_reports(
         &self,
-        body: &crate::types::DeleteSuppressionBlocksRequest,
-    ) -> Result<crate::types::Help> {
+        body: &crate::types::DeleteSuppressionSpamReportsRequest,
+    ) -> Result<crate::types::DeleteSuppressionSpamReportsResponse> {
         let url = "/suppression/spam_reports".to_string();
         self.client
             .delete(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -156,6 +234,44 @@ impl SpamReportsApi {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve a specific spam report.
+     *
+     * This function performs a `GET` to the `/suppression/spam_reports/{email}` endpoint.
+     *
+     * As opposed to `get_all_suppression_spam_reports_email`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve a specific spam report by email address.**
+     */
+    pub fn get_suppression_spam_reports_email_stream(
+        &self,
+        email: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SpamReportsResponse> {
+        let url = format!(
+            "/suppression/spam_reports/{}",
+            crate::progenitor_support::encode_path(email),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Delete a specific spam report.
      *
@@ -172,7 +288,7 @@ impl SpamReportsApi {
     pub async fn delete_suppression_spam_reports_email(
         &self,
         email: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSuppressionSpamReportsEmailResponse> {
         let url = format!(
             "/suppression/spam_reports/{}",
             crate::progenitor_support::encode_path(email),
@@ -181,3 +297,159 @@ impl SpamReportsApi {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `SpamReportsApi::get_suppression_spam_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SpamReportsApiGetSuppressionSpamReportsParams {
+    /// The end of the time range when a spam report was created (inclusive). This is a unix timestamp.
+    pub end_time: Option<i64>,
+    /// Limit the number of results to be displayed per page.
+    pub limit: Option<i64>,
+    /// Paging offset. The point in the list to begin displaying results.
+    pub offset: Option<i64>,
+    /// The start of the time range when a spam report was created (inclusive). This is a unix timestamp.
+    pub start_time: Option<i64>,
+}
+
+impl SpamReportsApiGetSuppressionSpamReportsParams {
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+}
+
+/// The functions of [`SpamReportsApi`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSpamReportsApiApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SpamReportsApiApi: Send + Sync {
+    /// See [`SpamReportsApi::get_suppression_spam_reports_with`].
+    async fn get_suppression_spam_reports_with(
+        &self,
+        params: &SpamReportsApiGetSuppressionSpamReportsParams,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>>;
+
+    /// See [`SpamReportsApi::get_all_suppression_spam_reports_with`].
+    async fn get_all_suppression_spam_reports_with(
+        &self,
+        params: &SpamReportsApiGetSuppressionSpamReportsParams,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>>;
+
+    /// See [`SpamReportsApi::get_suppression_spam_reports_stream`].
+    fn get_suppression_spam_reports_stream(
+        &self,
+        params: &SpamReportsApiGetSuppressionSpamReportsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SpamReportsResponse>;
+
+    /// See [`SpamReportsApi::delete_suppression_spam_reports`].
+    async fn delete_suppression_spam_reports(
+        &self,
+        body: &crate::types::DeleteSuppressionSpamReportsRequest,
+    ) -> Result<crate::types::DeleteSuppressionSpamReportsResponse>;
+
+    /// See [`SpamReportsApi::get_suppression_spam_reports_email`].
+    async fn get_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>>;
+
+    /// See [`SpamReportsApi::get_all_suppression_spam_reports_email`].
+    async fn get_all_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>>;
+
+    /// See [`SpamReportsApi::get_suppression_spam_reports_email_stream`].
+    fn get_suppression_spam_reports_email_stream(
+        &self,
+        email: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SpamReportsResponse>;
+
+    /// See [`SpamReportsApi::delete_suppression_spam_reports_email`].
+    async fn delete_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::DeleteSuppressionSpamReportsEmailResponse>;
+}
+
+#[async_trait::async_trait]
+impl SpamReportsApiApi for SpamReportsApi {
+    async fn get_suppression_spam_reports_with(
+        &self,
+        params: &SpamReportsApiGetSuppressionSpamReportsParams,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>> {
+        SpamReportsApi::get_suppression_spam_reports_with(self, params).await
+    }
+
+    async fn get_all_suppression_spam_reports_with(
+        &self,
+        params: &SpamReportsApiGetSuppressionSpamReportsParams,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>> {
+        SpamReportsApi::get_all_suppression_spam_reports_with(self, params).await
+    }
+
+    fn get_suppression_spam_reports_stream(
+        &self,
+        params: &SpamReportsApiGetSuppressionSpamReportsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SpamReportsResponse> {
+        SpamReportsApi::get_suppression_spam_reports_stream(self, params, start, max_items)
+    }
+
+    async fn delete_suppression_spam_reports(
+        &self,
+        body: &crate::types::DeleteSuppressionSpamReportsRequest,
+    ) -> Result<crate::types::DeleteSuppressionSpamReportsResponse> {
+        SpamReportsApi::delete_suppression_spam_reports(self, body).await
+    }
+
+    async fn get_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>> {
+        SpamReportsApi::get_suppression_spam_reports_email(self, email).await
+    }
+
+    async fn get_all_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> Result<Vec<crate::types::SpamReportsResponse>> {
+        SpamReportsApi::get_all_suppression_spam_reports_email(self, email).await
+    }
+
+    fn get_suppression_spam_reports_email_stream(
+        &self,
+        email: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SpamReportsResponse> {
+        SpamReportsApi::get_suppression_spam_reports_email_stream(self, email, start, max_items)
+    }
+
+    async fn delete_suppression_spam_reports_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::DeleteSuppressionSpamReportsEmailResponse> {
+        SpamReportsApi::delete_suppression_spam_reports_email(self, email).await
+    }
+}
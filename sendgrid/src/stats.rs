@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Stats {
     pub client: Client,
@@ -23,31 +21,23 @@ impl Stats {
      *
      * **Parameters:**
      *
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
-     * * `offset: i64` -- The point in the list to begin retrieving results.
-     * * `offset: i64` -- The point in the list to begin retrieving results.
-     * * `aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy` -- How to group the statistics. Must be either "day", "week", or "month".
-     * * `start_date: &str` -- The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
-     * * `end_date: &str` -- The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+     * * `params: &StatsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
-        start_date: &str,
-        end_date: &str,
+        params: &StatsGetPageParams,
     ) -> Result<Vec<crate::types::GetStatsResponseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -56,6 +46,26 @@ impl Stats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `StatsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetStatsResponseData>> {
+        let params = StatsGetPageParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * Retrieve global email statistics.
      *
@@ -66,31 +76,103 @@ impl Stats {
      * **This endpoint allows you to retrieve all of your global email statistics between a given date range.**
      *
      * Parent accounts will see aggregated stats for their account and all subuser accounts. Subuser accounts will only see their own stats.
+     *
+     * **Parameters:**
+     *
+     * * `params: &StatsGetPageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_with(
+        &self,
+        params: &StatsGetPageParams,
+    ) -> Result<Vec<crate::types::GetStatsResponseData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(aggregated_by) = &params.aggregated_by {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/stats?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `StatsGetPageParams` instead.
      */
+    #[deprecated(note = "use `get_all_with` instead")]
     pub async fn get_all(
         &self,
         offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetStatsResponseData>> {
+        let params = StatsGetPageParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * Retrieve global email statistics.
+     *
+     * This function performs a `GET` to the `/stats` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve all of your global email statistics between a given date range.**
+     *
+     * Parent accounts will see aggregated stats for their account and all subuser accounts. Subuser accounts will only see their own stats.
+     */
+    pub fn get_stream(
+        &self,
+        params: &StatsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetStatsResponseData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/stats?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -106,36 +188,26 @@ impl Stats {
      *
      * **Parameters:**
      *
-     * * `country: crate::types::Country` -- The country you would like to see statistics for. Currently only supported for US and CA.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
-     * * `offset: i64` -- The point in the list to begin retrieving results.
-     * * `offset: i64` -- The point in the list to begin retrieving results.
-     * * `aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy` -- How to group the statistics. Must be either "day", "week", or "month".
-     * * `start_date: &str` -- The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
-     * * `end_date: &str` -- The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+     * * `params: &StatsGetGeoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_geo(
+    pub async fn get_geo_with(
         &self,
-        country: crate::types::Country,
-        offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
-        start_date: &str,
-        end_date: &str,
+        params: &StatsGetGeoParams,
     ) -> Result<Vec<crate::types::GetGeoStatsResponseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !country.to_string().is_empty() {
+        if let Some(country) = &params.country {
             query_args.push(("country".to_string(), country.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -144,6 +216,28 @@ impl Stats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_geo_with` and pass the query parameters as a `StatsGetGeoParams` instead.
+     */
+    #[deprecated(note = "use `get_geo_with` instead")]
+    pub async fn get_geo(
+        &self,
+        country: crate::types::Country,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetGeoStatsResponseData>> {
+        let params = StatsGetGeoParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            country: Some(country).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_geo_with(&params).await
+    }
+
     /**
      * Retrieve email statistics by country and state/province.
      *
@@ -156,35 +250,113 @@ impl Stats {
      * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
      *
      * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [User Guide](https://sendgrid.com/docs/User_Guide/Statistics/index.html).
+     *
+     * **Parameters:**
+     *
+     * * `params: &StatsGetGeoParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_geo_with(
+        &self,
+        params: &StatsGetGeoParams,
+    ) -> Result<Vec<crate::types::GetGeoStatsResponseData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(aggregated_by) = &params.aggregated_by {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if let Some(country) = &params.country {
+            query_args.push(("country".to_string(), country.to_string()));
+        }
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/geo/stats?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_geo_with` and pass the query parameters as a `StatsGetGeoParams` instead.
      */
+    #[deprecated(note = "use `get_all_geo_with` instead")]
     pub async fn get_all_geo(
         &self,
         country: crate::types::Country,
         offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetGeoStatsResponseData>> {
+        let params = StatsGetGeoParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            country: Some(country).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_all_geo_with(&params).await
+    }
+
+    /**
+     * Retrieve email statistics by country and state/province.
+     *
+     * This function performs a `GET` to the `/geo/stats` endpoint.
+     *
+     * As opposed to `get_all_geo`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by country and state/province.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [User Guide](https://sendgrid.com/docs/User_Guide/Statistics/index.html).
+     */
+    pub fn get_geo_stream(
+        &self,
+        params: &StatsGetGeoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetGeoStatsResponseData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !country.to_string().is_empty() {
+        if let Some(country) = &params.country {
             query_args.push(("country".to_string(), country.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/geo/stats?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -209,31 +381,23 @@ impl Stats {
      *
      * **Parameters:**
      *
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
-     * * `offset: i64` -- The point in the list to begin retrieving results.
-     * * `offset: i64` -- The point in the list to begin retrieving results.
-     * * `aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy` -- How to group the statistics. Must be either "day", "week", or "month".
-     * * `start_date: &str` -- The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
-     * * `end_date: &str` -- The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+     * * `params: &StatsGetDevicesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_devices(
+    pub async fn get_devices_with(
         &self,
-        offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
-        start_date: &str,
-        end_date: &str,
-    ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
+        params: &StatsGetDevicesParams,
+    ) -> Result<Vec<crate::types::GetDevicesStatsResponseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -242,6 +406,26 @@ impl Stats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_devices_with` and pass the query parameters as a `StatsGetDevicesParams` instead.
+     */
+    #[deprecated(note = "use `get_devices_with` instead")]
+    pub async fn get_devices(
+        &self,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetDevicesStatsResponseData>> {
+        let params = StatsGetDevicesParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_devices_with(&params).await
+    }
+
     /**
      * Retrieve email statistics by device type.
      *
@@ -263,31 +447,114 @@ impl Stats {
      * | Other | An unrecognized device. |
      *
      * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     *
+     * **Parameters:**
+     *
+     * * `params: &StatsGetDevicesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_devices_with(
+        &self,
+        params: &StatsGetDevicesParams,
+    ) -> Result<Vec<crate::types::GetDevicesStatsResponseData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(aggregated_by) = &params.aggregated_by {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/devices/stats?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_devices_with` and pass the query parameters as a `StatsGetDevicesParams` instead.
      */
+    #[deprecated(note = "use `get_all_devices_with` instead")]
     pub async fn get_all_devices(
         &self,
         offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
         start_date: &str,
         end_date: &str,
-    ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
+    ) -> Result<Vec<crate::types::GetDevicesStatsResponseData>> {
+        let params = StatsGetDevicesParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_all_devices_with(&params).await
+    }
+
+    /**
+     * Retrieve email statistics by device type.
+     *
+     * This function performs a `GET` to the `/devices/stats` endpoint.
+     *
+     * As opposed to `get_all_devices`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by the device type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * ## Available Device Types
+     * | **Device** | **Description** | **Example** |
+     * |---|---|---|
+     * | Desktop | Email software on desktop computer. | I.E., Outlook, Sparrow, or Apple Mail. |
+     * | Webmail |	A web-based email client. | I.E., Yahoo, Google, AOL, or Outlook.com. |
+     * | Phone | A smart phone. | iPhone, Android, Blackberry, etc.
+     * | Tablet | A tablet computer. | iPad, android based tablet, etc. |
+     * | Other | An unrecognized device. |
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_devices_stream(
+        &self,
+        params: &StatsGetDevicesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetDevicesStatsResponseData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/devices/stats?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -303,25 +570,20 @@ impl Stats {
      *
      * **Parameters:**
      *
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
-     * * `start_date: &str` -- The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
-     * * `end_date: &str` -- The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
-     * * `aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy` -- How to group the statistics. Must be either "day", "week", or "month".
+     * * `params: &StatsGetClientsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_clients(
+    pub async fn get_clients_with(
         &self,
-        start_date: &str,
-        end_date: &str,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
-    ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
+        params: &StatsGetClientsParams,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -330,6 +592,24 @@ impl Stats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_clients_with` and pass the query parameters as a `StatsGetClientsParams` instead.
+     */
+    #[deprecated(note = "use `get_clients_with` instead")]
+    pub async fn get_clients(
+        &self,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponseData>> {
+        let params = StatsGetClientsParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_clients_with(&params).await
+    }
+
     /**
      * Retrieve email statistics by client type.
      *
@@ -342,27 +622,97 @@ impl Stats {
      * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
      *
      * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     *
+     * **Parameters:**
+     *
+     * * `params: &StatsGetClientsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_clients_with(
+        &self,
+        params: &StatsGetClientsParams,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponseData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(aggregated_by) = &params.aggregated_by {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/clients/stats?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_clients_with` and pass the query parameters as a `StatsGetClientsParams` instead.
      */
+    #[deprecated(note = "use `get_all_clients_with` instead")]
     pub async fn get_all_clients(
         &self,
         start_date: &str,
         end_date: &str,
         aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
-    ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
+    ) -> Result<Vec<crate::types::GetClientsStatsResponseData>> {
+        let params = StatsGetClientsParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_all_clients_with(&params).await
+    }
+
+    /**
+     * Retrieve email statistics by client type.
+     *
+     * This function performs a `GET` to the `/clients/stats` endpoint.
+     *
+     * As opposed to `get_all_clients`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by client type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_clients_stream(
+        &self,
+        params: &StatsGetClientsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetClientsStatsResponseData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/clients/stats?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -384,26 +734,21 @@ impl Stats {
      *
      * **Parameters:**
      *
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
-     * * `start_date: &str` -- The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
-     * * `end_date: &str` -- The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
-     * * `aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy` -- How to group the statistics. Must be either "day", "week", or "month".
+     * * `params: &StatsGetClientsClientTypeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_clients_client_type(
+    pub async fn get_clients_client_type_with(
         &self,
         client_type: crate::types::ClientType,
-        start_date: &str,
-        end_date: &str,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
-    ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
+        params: &StatsGetClientsClientTypeParams,
+    ) -> Result<Vec<crate::types::GetClientsClientTypeStatsResponseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -416,6 +761,26 @@ impl Stats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_clients_client_type_with` and pass the query parameters as a `StatsGetClientsClientTypeParams` instead.
+     */
+    #[deprecated(note = "use `get_clients_client_type_with` instead")]
+    pub async fn get_clients_client_type(
+        &self,
+        client_type: crate::types::ClientType,
+        start_date: &str,
+        end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::GetClientsClientTypeStatsResponseData>> {
+        let params = StatsGetClientsClientTypeParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_clients_client_type_with(client_type, &params)
+            .await
+    }
+
     /**
      * Retrieve stats by a specific client type.
      *
@@ -434,22 +799,24 @@ impl Stats {
      * - desktop
      *
      * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     *
+     * **Parameters:**
+     *
+     * * `params: &StatsGetClientsClientTypeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_clients_client_type(
+    pub async fn get_all_clients_client_type_with(
         &self,
         client_type: crate::types::ClientType,
-        start_date: &str,
-        end_date: &str,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
-    ) -> Result<Vec<crate::types::GetClientsStatsResponse>> {
+        params: &StatsGetClientsClientTypeParams,
+    ) -> Result<Vec<crate::types::GetClientsClientTypeStatsResponseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -463,51 +830,122 @@ impl Stats {
     }
 
     /**
-     * Retrieve email statistics by mailbox provider.
-     *
-     * This function performs a `GET` to the `/mailbox_providers/stats` endpoint.
-     *
-     * **This endpoint allows you to retrieve your email statistics segmented by recipient mailbox provider.**
-     *
-     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
-     *
-     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
-     *
-     * **Parameters:**
-     *
-     * * `mailbox_providers: &str` -- The mail box providers to get statistics for. You can include up to 10 by including this parameter multiple times.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
-     * * `offset: i64` -- The point in the list to begin retrieving results.
-     * * `offset: i64` -- The point in the list to begin retrieving results.
-     * * `aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy` -- How to group the statistics. Must be either "day", "week", or "month".
-     * * `start_date: &str` -- The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
-     * * `end_date: &str` -- The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+     * Deprecated: use `get_all_clients_client_type_with` and pass the query parameters as a `StatsGetClientsClientTypeParams` instead.
      */
-    pub async fn get_mailbox_providers(
+    #[deprecated(note = "use `get_all_clients_client_type_with` instead")]
+    pub async fn get_all_clients_client_type(
         &self,
-        mailbox_providers: &str,
-        offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        client_type: crate::types::ClientType,
         start_date: &str,
         end_date: &str,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Result<Vec<crate::types::GetClientsClientTypeStatsResponseData>> {
+        let params = StatsGetClientsClientTypeParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_all_clients_client_type_with(client_type, &params)
+            .await
+    }
+
+    /**
+     * Retrieve stats by a specific client type.
+     *
+     * This function performs a `GET` to the `/clients/{client_type}/stats` endpoint.
+     *
+     * As opposed to `get_all_clients_client_type`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by a specific client type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * ## Available Client Types
+     * - phone
+     * - tablet
+     * - webmail
+     * - desktop
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_clients_client_type_stream(
+        &self,
+        client_type: crate::types::ClientType,
+        params: &StatsGetClientsClientTypeParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetClientsClientTypeStatsResponseData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(aggregated_by) = &params.aggregated_by {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/clients/{}/stats?{}",
+            crate::progenitor_support::encode_path(&client_type.to_string()),
+            query_
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
+    /**
+     * Retrieve email statistics by mailbox provider.
+     *
+     * This function performs a `GET` to the `/mailbox_providers/stats` endpoint.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by recipient mailbox provider.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     *
+     * **Parameters:**
+     *
+     * * `params: &StatsGetMailboxProvidersParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_mailbox_providers_with(
+        &self,
+        params: &StatsGetMailboxProvidersParams,
     ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !mailbox_providers.is_empty() {
+        if let Some(mailbox_providers) = &params.mailbox_providers {
             query_args.push((
                 "mailbox_providers".to_string(),
                 mailbox_providers.to_string(),
             ));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -516,6 +954,28 @@ impl Stats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_mailbox_providers_with` and pass the query parameters as a `StatsGetMailboxProvidersParams` instead.
+     */
+    #[deprecated(note = "use `get_mailbox_providers_with` instead")]
+    pub async fn get_mailbox_providers(
+        &self,
+        mailbox_providers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
+        let params = StatsGetMailboxProvidersParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            mailbox_providers: Some(mailbox_providers.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_mailbox_providers_with(&params).await
+    }
+
     /**
      * Retrieve email statistics by mailbox provider.
      *
@@ -528,38 +988,119 @@ impl Stats {
      * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
      *
      * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     *
+     * **Parameters:**
+     *
+     * * `params: &StatsGetMailboxProvidersParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_mailbox_providers_with(
+        &self,
+        params: &StatsGetMailboxProvidersParams,
+    ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(aggregated_by) = &params.aggregated_by {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if let Some(mailbox_providers) = &params.mailbox_providers {
+            query_args.push((
+                "mailbox_providers".to_string(),
+                mailbox_providers.to_string(),
+            ));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/mailbox_providers/stats?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_mailbox_providers_with` and pass the query parameters as a `StatsGetMailboxProvidersParams` instead.
      */
+    #[deprecated(note = "use `get_all_mailbox_providers_with` instead")]
     pub async fn get_all_mailbox_providers(
         &self,
         mailbox_providers: &str,
         offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
+        let params = StatsGetMailboxProvidersParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            mailbox_providers: Some(mailbox_providers.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_all_mailbox_providers_with(&params).await
+    }
+
+    /**
+     * Retrieve email statistics by mailbox provider.
+     *
+     * This function performs a `GET` to the `/mailbox_providers/stats` endpoint.
+     *
+     * As opposed to `get_all_mailbox_providers`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by recipient mailbox provider.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_mailbox_providers_stream(
+        &self,
+        params: &StatsGetMailboxProvidersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetMailboxProvidersStatsResponseData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !mailbox_providers.is_empty() {
+        if let Some(mailbox_providers) = &params.mailbox_providers {
             query_args.push((
                 "mailbox_providers".to_string(),
                 mailbox_providers.to_string(),
             ));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/mailbox_providers/stats?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -575,36 +1116,26 @@ impl Stats {
      *
      * **Parameters:**
      *
-     * * `browsers: &str` -- The browsers to get statistics for. You can include up to 10 different browsers by including this parameter multiple times.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
-     * * `offset: i64` -- The point in the list to begin retrieving results.
-     * * `offset: i64` -- The point in the list to begin retrieving results.
-     * * `aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy` -- How to group the statistics. Must be either "day", "week", or "month".
-     * * `start_date: &str` -- The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
-     * * `end_date: &str` -- The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+     * * `params: &StatsGetBrowsersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_browsers(
+    pub async fn get_browsers_with(
         &self,
-        browsers: &str,
-        offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
-        start_date: &str,
-        end_date: &str,
+        params: &StatsGetBrowsersParams,
     ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !browsers.is_empty() {
+        if let Some(browsers) = &params.browsers {
             query_args.push(("browsers".to_string(), browsers.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -613,6 +1144,28 @@ impl Stats {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_browsers_with` and pass the query parameters as a `StatsGetBrowsersParams` instead.
+     */
+    #[deprecated(note = "use `get_browsers_with` instead")]
+    pub async fn get_browsers(
+        &self,
+        browsers: &str,
+        offset: i64,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>> {
+        let params = StatsGetBrowsersParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            browsers: Some(browsers.to_string()).filter(|s| !s.is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_browsers_with(&params).await
+    }
+
     /**
      * Retrieve email statistics by browser.
      *
@@ -625,34 +1178,701 @@ impl Stats {
      * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
      *
      * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     *
+     * **Parameters:**
+     *
+     * * `params: &StatsGetBrowsersParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_browsers_with(
+        &self,
+        params: &StatsGetBrowsersParams,
+    ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(aggregated_by) = &params.aggregated_by {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if let Some(browsers) = &params.browsers {
+            query_args.push(("browsers".to_string(), browsers.to_string()));
+        }
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/browsers/stats?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_browsers_with` and pass the query parameters as a `StatsGetBrowsersParams` instead.
+     */
+    #[deprecated(note = "use `get_all_browsers_with` instead")]
     pub async fn get_all_browsers(
         &self,
         browsers: &str,
         offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>> {
+        let params = StatsGetBrowsersParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            browsers: Some(browsers.to_string()).filter(|s| !s.is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_all_browsers_with(&params).await
+    }
+
+    /**
+     * Retrieve email statistics by browser.
+     *
+     * This function performs a `GET` to the `/browsers/stats` endpoint.
+     *
+     * As opposed to `get_all_browsers`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve your email statistics segmented by browser type.**
+     *
+     * **We only store up to 7 days of email activity in our database.** By default, 500 items will be returned per request via the Advanced Stats API endpoints.
+     *
+     * Advanced Stats provide a more in-depth view of your email statistics and the actions taken by your recipients. You can segment these statistics by geographic location, device type, client type, browser, and mailbox provider. For more information about statistics, please see our [Statistics Overview](https://sendgrid.com/docs/ui/analytics-and-reporting/stats-overview/).
+     */
+    pub fn get_browsers_stream(
+        &self,
+        params: &StatsGetBrowsersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetBrowsersStatsResponseData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !browsers.is_empty() {
+        if let Some(browsers) = &params.browsers {
             query_args.push(("browsers".to_string(), browsers.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/browsers/stats?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Stats::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StatsGetPageParams {
+    /// How to group the statistics. Must be either "day", "week", or "month".
+    pub aggregated_by: Option<crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy>,
+    /// The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+    pub end_date: Option<String>,
+    /// The point in the list to begin retrieving results.
+    pub offset: Option<i64>,
+    /// The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
+    pub start_date: Option<String>,
+}
+
+impl StatsGetPageParams {
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Stats::get_geo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StatsGetGeoParams {
+    /// How to group the statistics. Must be either "day", "week", or "month".
+    pub aggregated_by: Option<crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy>,
+    /// The country you would like to see statistics for. Currently only supported for US and CA.
+    pub country: Option<crate::types::Country>,
+    /// The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+    pub end_date: Option<String>,
+    /// The point in the list to begin retrieving results.
+    pub offset: Option<i64>,
+    /// The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
+    pub start_date: Option<String>,
+}
+
+impl StatsGetGeoParams {
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    pub fn country(mut self, country: crate::types::Country) -> Self {
+        self.country = Some(country);
+        self
+    }
+
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Stats::get_devices_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StatsGetDevicesParams {
+    /// How to group the statistics. Must be either "day", "week", or "month".
+    pub aggregated_by: Option<crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy>,
+    /// The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+    pub end_date: Option<String>,
+    /// The point in the list to begin retrieving results.
+    pub offset: Option<i64>,
+    /// The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
+    pub start_date: Option<String>,
+}
+
+impl StatsGetDevicesParams {
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Stats::get_clients_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StatsGetClientsParams {
+    /// How to group the statistics. Must be either "day", "week", or "month".
+    pub aggregated_by: Option<crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy>,
+    /// The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+    pub end_date: Option<String>,
+    /// The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
+    pub start_date: Option<String>,
+}
+
+impl StatsGetClientsParams {
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Stats::get_clients_client_type_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StatsGetClientsClientTypeParams {
+    /// How to group the statistics. Must be either "day", "week", or "month".
+    pub aggregated_by: Option<crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy>,
+    /// The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+    pub end_date: Option<String>,
+    /// The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
+    pub start_date: Option<String>,
+}
+
+impl StatsGetClientsClientTypeParams {
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Stats::get_mailbox_providers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StatsGetMailboxProvidersParams {
+    /// How to group the statistics. Must be either "day", "week", or "month".
+    pub aggregated_by: Option<crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy>,
+    /// The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+    pub end_date: Option<String>,
+    /// The mail box providers to get statistics for. You can include up to 10 by including this parameter multiple times.
+    pub mailbox_providers: Option<String>,
+    /// The point in the list to begin retrieving results.
+    pub offset: Option<i64>,
+    /// The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
+    pub start_date: Option<String>,
+}
+
+impl StatsGetMailboxProvidersParams {
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn mailbox_providers<T: ToString>(mut self, mailbox_providers: T) -> Self {
+        self.mailbox_providers = Some(mailbox_providers.to_string());
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Stats::get_browsers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StatsGetBrowsersParams {
+    /// How to group the statistics. Must be either "day", "week", or "month".
+    pub aggregated_by: Option<crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy>,
+    /// The browsers to get statistics for. You can include up to 10 different browsers by including this parameter multiple times.
+    pub browsers: Option<String>,
+    /// The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+    pub end_date: Option<String>,
+    /// The point in the list to begin retrieving results.
+    pub offset: Option<i64>,
+    /// The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
+    pub start_date: Option<String>,
+}
+
+impl StatsGetBrowsersParams {
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    pub fn browsers<T: ToString>(mut self, browsers: T) -> Self {
+        self.browsers = Some(browsers.to_string());
+        self
+    }
+
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The functions of [`Stats`], for code that takes them as a trait, so that it can be
+/// tested with the `MockStatsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait StatsApi: Send + Sync {
+    /// See [`Stats::get_page_with`].
+    async fn get_page_with(
+        &self,
+        params: &StatsGetPageParams,
+    ) -> Result<Vec<crate::types::GetStatsResponseData>>;
+
+    /// See [`Stats::get_all_with`].
+    async fn get_all_with(
+        &self,
+        params: &StatsGetPageParams,
+    ) -> Result<Vec<crate::types::GetStatsResponseData>>;
+
+    /// See [`Stats::get_stream`].
+    fn get_stream(
+        &self,
+        params: &StatsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetStatsResponseData>;
+
+    /// See [`Stats::get_geo_with`].
+    async fn get_geo_with(
+        &self,
+        params: &StatsGetGeoParams,
+    ) -> Result<Vec<crate::types::GetGeoStatsResponseData>>;
+
+    /// See [`Stats::get_all_geo_with`].
+    async fn get_all_geo_with(
+        &self,
+        params: &StatsGetGeoParams,
+    ) -> Result<Vec<crate::types::GetGeoStatsResponseData>>;
+
+    /// See [`Stats::get_geo_stream`].
+    fn get_geo_stream(
+        &self,
+        params: &StatsGetGeoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetGeoStatsResponseData>;
+
+    /// See [`Stats::get_devices_with`].
+    async fn get_devices_with(
+        &self,
+        params: &StatsGetDevicesParams,
+    ) -> Result<Vec<crate::types::GetDevicesStatsResponseData>>;
+
+    /// See [`Stats::get_all_devices_with`].
+    async fn get_all_devices_with(
+        &self,
+        params: &StatsGetDevicesParams,
+    ) -> Result<Vec<crate::types::GetDevicesStatsResponseData>>;
+
+    /// See [`Stats::get_devices_stream`].
+    fn get_devices_stream(
+        &self,
+        params: &StatsGetDevicesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetDevicesStatsResponseData>;
+
+    /// See [`Stats::get_clients_with`].
+    async fn get_clients_with(
+        &self,
+        params: &StatsGetClientsParams,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponseData>>;
+
+    /// See [`Stats::get_all_clients_with`].
+    async fn get_all_clients_with(
+        &self,
+        params: &StatsGetClientsParams,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponseData>>;
+
+    /// See [`Stats::get_clients_stream`].
+    fn get_clients_stream(
+        &self,
+        params: &StatsGetClientsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetClientsStatsResponseData>;
+
+    /// See [`Stats::get_clients_client_type_with`].
+    async fn get_clients_client_type_with(
+        &self,
+        client_type: crate::types::ClientType,
+        params: &StatsGetClientsClientTypeParams,
+    ) -> Result<Vec<crate::types::GetClientsClientTypeStatsResponseData>>;
+
+    /// See [`Stats::get_all_clients_client_type_with`].
+    async fn get_all_clients_client_type_with(
+        &self,
+        client_type: crate::types::ClientType,
+        params: &StatsGetClientsClientTypeParams,
+    ) -> Result<Vec<crate::types::GetClientsClientTypeStatsResponseData>>;
+
+    /// See [`Stats::get_clients_client_type_stream`].
+    fn get_clients_client_type_stream(
+        &self,
+        client_type: crate::types::ClientType,
+        params: &StatsGetClientsClientTypeParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetClientsClientTypeStatsResponseData>;
+
+    /// See [`Stats::get_mailbox_providers_with`].
+    async fn get_mailbox_providers_with(
+        &self,
+        params: &StatsGetMailboxProvidersParams,
+    ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>>;
+
+    /// See [`Stats::get_all_mailbox_providers_with`].
+    async fn get_all_mailbox_providers_with(
+        &self,
+        params: &StatsGetMailboxProvidersParams,
+    ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>>;
+
+    /// See [`Stats::get_mailbox_providers_stream`].
+    fn get_mailbox_providers_stream(
+        &self,
+        params: &StatsGetMailboxProvidersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetMailboxProvidersStatsResponseData>;
+
+    /// See [`Stats::get_browsers_with`].
+    async fn get_browsers_with(
+        &self,
+        params: &StatsGetBrowsersParams,
+    ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>>;
+
+    /// See [`Stats::get_all_browsers_with`].
+    async fn get_all_browsers_with(
+        &self,
+        params: &StatsGetBrowsersParams,
+    ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>>;
+
+    /// See [`Stats::get_browsers_stream`].
+    fn get_browsers_stream(
+        &self,
+        params: &StatsGetBrowsersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetBrowsersStatsResponseData>;
+}
+
+#[async_trait::async_trait]
+impl StatsApi for Stats {
+    async fn get_page_with(
+        &self,
+        params: &StatsGetPageParams,
+    ) -> Result<Vec<crate::types::GetStatsResponseData>> {
+        Stats::get_page_with(self, params).await
+    }
+
+    async fn get_all_with(
+        &self,
+        params: &StatsGetPageParams,
+    ) -> Result<Vec<crate::types::GetStatsResponseData>> {
+        Stats::get_all_with(self, params).await
+    }
+
+    fn get_stream(
+        &self,
+        params: &StatsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetStatsResponseData> {
+        Stats::get_stream(self, params, start, max_items)
+    }
+
+    async fn get_geo_with(
+        &self,
+        params: &StatsGetGeoParams,
+    ) -> Result<Vec<crate::types::GetGeoStatsResponseData>> {
+        Stats::get_geo_with(self, params).await
+    }
+
+    async fn get_all_geo_with(
+        &self,
+        params: &StatsGetGeoParams,
+    ) -> Result<Vec<crate::types::GetGeoStatsResponseData>> {
+        Stats::get_all_geo_with(self, params).await
+    }
+
+    fn get_geo_stream(
+        &self,
+        params: &StatsGetGeoParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetGeoStatsResponseData> {
+        Stats::get_geo_stream(self, params, start, max_items)
+    }
+
+    async fn get_devices_with(
+        &self,
+        params: &StatsGetDevicesParams,
+    ) -> Result<Vec<crate::types::GetDevicesStatsResponseData>> {
+        Stats::get_devices_with(self, params).await
+    }
+
+    async fn get_all_devices_with(
+        &self,
+        params: &StatsGetDevicesParams,
+    ) -> Result<Vec<crate::types::GetDevicesStatsResponseData>> {
+        Stats::get_all_devices_with(self, params).await
+    }
+
+    fn get_devices_stream(
+        &self,
+        params: &StatsGetDevicesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetDevicesStatsResponseData> {
+        Stats::get_devices_stream(self, params, start, max_items)
+    }
+
+    async fn get_clients_with(
+        &self,
+        params: &StatsGetClientsParams,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponseData>> {
+        Stats::get_clients_with(self, params).await
+    }
+
+    async fn get_all_clients_with(
+        &self,
+        params: &StatsGetClientsParams,
+    ) -> Result<Vec<crate::types::GetClientsStatsResponseData>> {
+        Stats::get_all_clients_with(self, params).await
+    }
+
+    fn get_clients_stream(
+        &self,
+        params: &StatsGetClientsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetClientsStatsResponseData> {
+        Stats::get_clients_stream(self, params, start, max_items)
+    }
+
+    async fn get_clients_client_type_with(
+        &self,
+        client_type: crate::types::ClientType,
+        params: &StatsGetClientsClientTypeParams,
+    ) -> Result<Vec<crate::types::GetClientsClientTypeStatsResponseData>> {
+        Stats::get_clients_client_type_with(self, client_type, params).await
+    }
+
+    async fn get_all_clients_client_type_with(
+        &self,
+        client_type: crate::types::ClientType,
+        params: &StatsGetClientsClientTypeParams,
+    ) -> Result<Vec<crate::types::GetClientsClientTypeStatsResponseData>> {
+        Stats::get_all_clients_client_type_with(self, client_type, params).await
+    }
+
+    fn get_clients_client_type_stream(
+        &self,
+        client_type: crate::types::ClientType,
+        params: &StatsGetClientsClientTypeParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetClientsClientTypeStatsResponseData> {
+        Stats::get_clients_client_type_stream(self, client_type, params, start, max_items)
+    }
+
+    async fn get_mailbox_providers_with(
+        &self,
+        params: &StatsGetMailboxProvidersParams,
+    ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
+        Stats::get_mailbox_providers_with(self, params).await
+    }
+
+    async fn get_all_mailbox_providers_with(
+        &self,
+        params: &StatsGetMailboxProvidersParams,
+    ) -> Result<Vec<crate::types::GetMailboxProvidersStatsResponseData>> {
+        Stats::get_all_mailbox_providers_with(self, params).await
+    }
+
+    fn get_mailbox_providers_stream(
+        &self,
+        params: &StatsGetMailboxProvidersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetMailboxProvidersStatsResponseData> {
+        Stats::get_mailbox_providers_stream(self, params, start, max_items)
+    }
+
+    async fn get_browsers_with(
+        &self,
+        params: &StatsGetBrowsersParams,
+    ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>> {
+        Stats::get_browsers_with(self, params).await
+    }
+
+    async fn get_all_browsers_with(
+        &self,
+        params: &StatsGetBrowsersParams,
+    ) -> Result<Vec<crate::types::GetBrowsersStatsResponseData>> {
+        Stats::get_all_browsers_with(self, params).await
+    }
+
+    fn get_browsers_stream(
+        &self,
+        params: &StatsGetBrowsersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetBrowsersStatsResponseData> {
+        Stats::get_browsers_stream(self, params, start, max_items)
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SubuserMonitorSettings {
     pub client: Client,
@@ -77,7 +75,7 @@ impl SubuserMonitorSettings {
     pub async fn delete_subusers_subuser_name_monitor(
         &self,
         subuser_name: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSubusersSubuserNameMonitorResponse> {
         let url = format!(
             "/subusers/{}/monitor",
             crate::progenitor_support::encode_path(subuser_name),
@@ -86,3 +84,68 @@ impl SubuserMonitorSettings {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`SubuserMonitorSettings`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSubuserMonitorSettingsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SubuserMonitorSettingsApi: Send + Sync {
+    /// See [`SubuserMonitorSettings::get_subusers_subuser_name_monitor`].
+    async fn get_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> Result<crate::types::Monitor>;
+
+    /// See [`SubuserMonitorSettings::put_subusers_subuser_name_monitor`].
+    async fn put_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::Monitor,
+    ) -> Result<crate::types::Monitor>;
+
+    /// See [`SubuserMonitorSettings::post_subusers_subuser_name_monitor`].
+    async fn post_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::Monitor,
+    ) -> Result<crate::types::Monitor>;
+
+    /// See [`SubuserMonitorSettings::delete_subusers_subuser_name_monitor`].
+    async fn delete_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> Result<crate::types::DeleteSubusersSubuserNameMonitorResponse>;
+}
+
+#[async_trait::async_trait]
+impl SubuserMonitorSettingsApi for SubuserMonitorSettings {
+    async fn get_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> Result<crate::types::Monitor> {
+        SubuserMonitorSettings::get_subusers_subuser_name_monitor(self, subuser_name).await
+    }
+
+    async fn put_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::Monitor,
+    ) -> Result<crate::types::Monitor> {
+        SubuserMonitorSettings::put_subusers_subuser_name_monitor(self, subuser_name, body).await
+    }
+
+    async fn post_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::Monitor,
+    ) -> Result<crate::types::Monitor> {
+        SubuserMonitorSettings::post_subusers_subuser_name_monitor(self, subuser_name, body).await
+    }
+
+    async fn delete_subusers_subuser_name_monitor(
+        &self,
+        subuser_name: &str,
+    ) -> Result<crate::types::DeleteSubusersSubuserNameMonitorResponse> {
+        SubuserMonitorSettings::delete_subusers_subuser_name_monitor(self, subuser_name).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SubuserStatistics {
     pub client: Client,
@@ -24,38 +22,30 @@ impl SubuserStatistics {
      *
      * **Parameters:**
      *
-     * * `date: &str` -- The date of the month to retrieve statistics for. Must be formatted YYYY-MM-DD.
-     * * `sort_by_metric: &str` -- The metric that you want to sort by. Metrics that you can sort by are: `blocks`, `bounces`, `clicks`, `delivered`, `opens`, `requests`, `unique_clicks`, `unique_opens`, and `unsubscribes`.'.
-     * * `sort_by_direction: crate::types::SortByDirection` -- The direction you want to sort.
-     * * `limit: i64` -- Optional field to limit the number of results returned.
-     * * `offset: i64` -- Optional beginning point in the list to retrieve from.
+     * * `params: &SubuserStatisticsGetSubusersSubuserNameStatsMonthlyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_subusers_subuser_name_stats_monthly(
+    pub async fn get_subusers_subuser_name_stats_monthly_with(
         &self,
         subuser_name: &str,
-        date: &str,
-        sort_by_metric: &str,
-        sort_by_direction: crate::types::SortByDirection,
-        limit: i64,
-        offset: i64,
+        params: &SubuserStatisticsGetSubusersSubuserNameStatsMonthlyParams,
     ) -> Result<crate::types::SubuserStatsData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.is_empty() {
+        if let Some(date) = &params.date {
             query_args.push(("date".to_string(), date.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !sort_by_direction.to_string().is_empty() {
+        if let Some(sort_by_direction) = &params.sort_by_direction {
             query_args.push((
                 "sort_by_direction".to_string(),
                 sort_by_direction.to_string(),
             ));
         }
-        if !sort_by_metric.is_empty() {
+        if let Some(sort_by_metric) = &params.sort_by_metric {
             query_args.push(("sort_by_metric".to_string(), sort_by_metric.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -68,6 +58,30 @@ impl SubuserStatistics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_subusers_subuser_name_stats_monthly_with` and pass the query parameters as a `SubuserStatisticsGetSubusersSubuserNameStatsMonthlyParams` instead.
+     */
+    #[deprecated(note = "use `get_subusers_subuser_name_stats_monthly_with` instead")]
+    pub async fn get_subusers_subuser_name_stats_monthly(
+        &self,
+        subuser_name: &str,
+        date: &str,
+        sort_by_metric: &str,
+        sort_by_direction: crate::types::SortByDirection,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::SubuserStatsData> {
+        let params = SubuserStatisticsGetSubusersSubuserNameStatsMonthlyParams {
+            date: Some(date.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            sort_by_direction: Some(sort_by_direction).filter(|v| !v.to_string().is_empty()),
+            sort_by_metric: Some(sort_by_metric.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_subusers_subuser_name_stats_monthly_with(subuser_name, &params)
+            .await
+    }
+
     /**
      * Retrieve monthly stats for all subusers.
      *
@@ -80,42 +94,32 @@ impl SubuserStatistics {
      *
      * **Parameters:**
      *
-     * * `date: &str` -- The date of the month to retrieve statistics for. Must be formatted YYYY-MM-DD.
-     * * `subuser: &str` -- The license key provided with your New Relic account.
-     * * `sort_by_metric: crate::types::SortByMetric` -- The metric that you want to sort by. Metrics that you can sort by are: `blocks`, `bounces`, `clicks`, `delivered`, `opens`, `requests`, `unique_clicks`, `unique_opens`, and `unsubscribes`.'.
-     * * `sort_by_direction: crate::types::SortByDirection` -- The direction you want to sort.
-     * * `limit: i64` -- Optional field to limit the number of results returned.
-     * * `offset: i64` -- Optional beginning point in the list to retrieve from.
+     * * `params: &SubuserStatisticsGetSubusersStatsMonthlyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_subusers_stats_monthly(
+    pub async fn get_subusers_stats_monthly_with(
         &self,
-        date: &str,
-        subuser: &str,
-        sort_by_metric: crate::types::SortByMetric,
-        sort_by_direction: crate::types::SortByDirection,
-        limit: i64,
-        offset: i64,
+        params: &SubuserStatisticsGetSubusersStatsMonthlyParams,
     ) -> Result<crate::types::SubuserStatsData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.is_empty() {
+        if let Some(date) = &params.date {
             query_args.push(("date".to_string(), date.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !sort_by_direction.to_string().is_empty() {
+        if let Some(sort_by_direction) = &params.sort_by_direction {
             query_args.push((
                 "sort_by_direction".to_string(),
                 sort_by_direction.to_string(),
             ));
         }
-        if !sort_by_metric.to_string().is_empty() {
+        if let Some(sort_by_metric) = &params.sort_by_metric {
             query_args.push(("sort_by_metric".to_string(), sort_by_metric.to_string()));
         }
-        if !subuser.is_empty() {
+        if let Some(subuser) = &params.subuser {
             query_args.push(("subuser".to_string(), subuser.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -124,6 +128,30 @@ impl SubuserStatistics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_subusers_stats_monthly_with` and pass the query parameters as a `SubuserStatisticsGetSubusersStatsMonthlyParams` instead.
+     */
+    #[deprecated(note = "use `get_subusers_stats_monthly_with` instead")]
+    pub async fn get_subusers_stats_monthly(
+        &self,
+        date: &str,
+        subuser: &str,
+        sort_by_metric: crate::types::SortByMetric,
+        sort_by_direction: crate::types::SortByDirection,
+        limit: i64,
+        offset: i64,
+    ) -> Result<crate::types::SubuserStatsData> {
+        let params = SubuserStatisticsGetSubusersStatsMonthlyParams {
+            date: Some(date.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            sort_by_direction: Some(sort_by_direction).filter(|v| !v.to_string().is_empty()),
+            sort_by_metric: Some(sort_by_metric).filter(|v| !v.to_string().is_empty()),
+            subuser: Some(subuser.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_subusers_stats_monthly_with(&params).await
+    }
+
     /**
      * Retrieve the totals for each email statistic metric for all subusers.
      *
@@ -133,47 +161,35 @@ impl SubuserStatistics {
      *
      * **Parameters:**
      *
-     * * `sort_by_direction: crate::types::SortByDirection` -- The direction you want to sort.
-     * * `start_date: &str` -- The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
-     * * `end_date: &str` -- The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
-     * * `limit: i64` -- Limits the number of results returned per page.
-     * * `offset: i64` -- The point in the list to begin retrieving results from.
-     * * `aggregated_by: &str` -- How to group the statistics. Defaults to today. Must follow format YYYY-MM-DD.
-     * * `sort_by_metric: &str` -- The metric that you want to sort by.  Must be a single metric.
+     * * `params: &SubuserStatisticsGetSubusersStatsSumParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_subusers_stats_sum(
+    pub async fn get_subusers_stats_sum_with(
         &self,
-        sort_by_direction: crate::types::SortByDirection,
-        start_date: &str,
-        end_date: &str,
-        limit: i64,
-        offset: i64,
-        aggregated_by: &str,
-        sort_by_metric: &str,
+        params: &SubuserStatisticsGetSubusersStatsSumParams,
     ) -> Result<crate::types::CategoryStats> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !sort_by_direction.to_string().is_empty() {
+        if let Some(sort_by_direction) = &params.sort_by_direction {
             query_args.push((
                 "sort_by_direction".to_string(),
                 sort_by_direction.to_string(),
             ));
         }
-        if !sort_by_metric.is_empty() {
+        if let Some(sort_by_metric) = &params.sort_by_metric {
             query_args.push(("sort_by_metric".to_string(), sort_by_metric.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -182,6 +198,32 @@ impl SubuserStatistics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_subusers_stats_sum_with` and pass the query parameters as a `SubuserStatisticsGetSubusersStatsSumParams` instead.
+     */
+    #[deprecated(note = "use `get_subusers_stats_sum_with` instead")]
+    pub async fn get_subusers_stats_sum(
+        &self,
+        sort_by_direction: crate::types::SortByDirection,
+        start_date: &str,
+        end_date: &str,
+        limit: i64,
+        offset: i64,
+        aggregated_by: &str,
+        sort_by_metric: &str,
+    ) -> Result<crate::types::CategoryStats> {
+        let params = SubuserStatisticsGetSubusersStatsSumParams {
+            aggregated_by: Some(aggregated_by.to_string()).filter(|s| !s.is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            sort_by_direction: Some(sort_by_direction).filter(|v| !v.to_string().is_empty()),
+            sort_by_metric: Some(sort_by_metric.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_subusers_stats_sum_with(&params).await
+    }
+
     /**
      * Retrieve email statistics for your subusers.
      *
@@ -193,39 +235,29 @@ impl SubuserStatistics {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- Limits the number of results returned per page.
-     * * `offset: i64` -- The point in the list to begin retrieving results from.
-     * * `aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy` -- How to group the statistics. Must be either "day", "week", or "month".
-     * * `subusers: &str` -- The subuser you want to retrieve statistics for. You may include this parameter up to 10 times to retrieve statistics for multiple subusers.
-     * * `start_date: &str` -- The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
-     * * `end_date: &str` -- The end date of the statistics to retrieve. Defaults to today.
+     * * `params: &SubuserStatisticsGetSubusersStatParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_subusers_stat(
+    pub async fn get_subusers_stat_with(
         &self,
-        limit: i64,
-        offset: i64,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
-        subusers: &str,
-        start_date: &str,
-        end_date: &str,
+        params: &SubuserStatisticsGetSubusersStatParams,
     ) -> Result<crate::types::CategoryStats> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
-        if !subusers.is_empty() {
+        if let Some(subusers) = &params.subusers {
             query_args.push(("subusers".to_string(), subusers.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -233,4 +265,291 @@ impl SubuserStatistics {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_subusers_stat_with` and pass the query parameters as a `SubuserStatisticsGetSubusersStatParams` instead.
+     */
+    #[deprecated(note = "use `get_subusers_stat_with` instead")]
+    pub async fn get_subusers_stat(
+        &self,
+        limit: i64,
+        offset: i64,
+        aggregated_by: crate::types::GetSubusersStatsAggregatedBy,
+        subusers: &str,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<crate::types::CategoryStats> {
+        let params = SubuserStatisticsGetSubusersStatParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+            subusers: Some(subusers.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_subusers_stat_with(&params).await
+    }
+}
+
+/// The query parameters of `SubuserStatistics::get_subusers_subuser_name_stats_monthly_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SubuserStatisticsGetSubusersSubuserNameStatsMonthlyParams {
+    /// The date of the month to retrieve statistics for. Must be formatted YYYY-MM-DD.
+    pub date: Option<String>,
+    /// Optional field to limit the number of results returned.
+    pub limit: Option<i64>,
+    /// Optional beginning point in the list to retrieve from.
+    pub offset: Option<i64>,
+    /// The direction you want to sort.
+    pub sort_by_direction: Option<crate::types::SortByDirection>,
+    /// The metric that you want to sort by. Metrics that you can sort by are: `blocks`, `bounces`, `clicks`, `delivered`, `opens`, `requests`, `unique_clicks`, `unique_opens`, and `unsubscribes`.'.
+    pub sort_by_metric: Option<String>,
+}
+
+impl SubuserStatisticsGetSubusersSubuserNameStatsMonthlyParams {
+    pub fn date<T: ToString>(mut self, date: T) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.sort_by_direction = Some(sort_by_direction);
+        self
+    }
+
+    pub fn sort_by_metric<T: ToString>(mut self, sort_by_metric: T) -> Self {
+        self.sort_by_metric = Some(sort_by_metric.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SubuserStatistics::get_subusers_stats_monthly_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SubuserStatisticsGetSubusersStatsMonthlyParams {
+    /// The date of the month to retrieve statistics for. Must be formatted YYYY-MM-DD.
+    pub date: Option<String>,
+    /// Optional field to limit the number of results returned.
+    pub limit: Option<i64>,
+    /// Optional beginning point in the list to retrieve from.
+    pub offset: Option<i64>,
+    /// The direction you want to sort.
+    pub sort_by_direction: Option<crate::types::SortByDirection>,
+    /// The metric that you want to sort by. Metrics that you can sort by are: `blocks`, `bounces`, `clicks`, `delivered`, `opens`, `requests`, `unique_clicks`, `unique_opens`, and `unsubscribes`.'.
+    pub sort_by_metric: Option<crate::types::SortByMetric>,
+    /// The license key provided with your New Relic account.
+    pub subuser: Option<String>,
+}
+
+impl SubuserStatisticsGetSubusersStatsMonthlyParams {
+    pub fn date<T: ToString>(mut self, date: T) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.sort_by_direction = Some(sort_by_direction);
+        self
+    }
+
+    pub fn sort_by_metric(mut self, sort_by_metric: crate::types::SortByMetric) -> Self {
+        self.sort_by_metric = Some(sort_by_metric);
+        self
+    }
+
+    pub fn subuser<T: ToString>(mut self, subuser: T) -> Self {
+        self.subuser = Some(subuser.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SubuserStatistics::get_subusers_stats_sum_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SubuserStatisticsGetSubusersStatsSumParams {
+    /// How to group the statistics. Defaults to today. Must follow format YYYY-MM-DD.
+    pub aggregated_by: Option<String>,
+    /// The end date of the statistics to retrieve. Defaults to today. Must follow format YYYY-MM-DD.
+    pub end_date: Option<String>,
+    /// Limits the number of results returned per page.
+    pub limit: Option<i64>,
+    /// The point in the list to begin retrieving results from.
+    pub offset: Option<i64>,
+    /// The direction you want to sort.
+    pub sort_by_direction: Option<crate::types::SortByDirection>,
+    /// The metric that you want to sort by.  Must be a single metric.
+    pub sort_by_metric: Option<String>,
+    /// The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
+    pub start_date: Option<String>,
+}
+
+impl SubuserStatisticsGetSubusersStatsSumParams {
+    pub fn aggregated_by<T: ToString>(mut self, aggregated_by: T) -> Self {
+        self.aggregated_by = Some(aggregated_by.to_string());
+        self
+    }
+
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn sort_by_direction(mut self, sort_by_direction: crate::types::SortByDirection) -> Self {
+        self.sort_by_direction = Some(sort_by_direction);
+        self
+    }
+
+    pub fn sort_by_metric<T: ToString>(mut self, sort_by_metric: T) -> Self {
+        self.sort_by_metric = Some(sort_by_metric.to_string());
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SubuserStatistics::get_subusers_stat_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SubuserStatisticsGetSubusersStatParams {
+    /// How to group the statistics. Must be either "day", "week", or "month".
+    pub aggregated_by: Option<crate::types::GetSubusersStatsAggregatedBy>,
+    /// The end date of the statistics to retrieve. Defaults to today.
+    pub end_date: Option<String>,
+    /// Limits the number of results returned per page.
+    pub limit: Option<i64>,
+    /// The point in the list to begin retrieving results from.
+    pub offset: Option<i64>,
+    /// The starting date of the statistics to retrieve. Must follow format YYYY-MM-DD.
+    pub start_date: Option<String>,
+    /// The subuser you want to retrieve statistics for. You may include this parameter up to 10 times to retrieve statistics for multiple subusers.
+    pub subusers: Option<String>,
+}
+
+impl SubuserStatisticsGetSubusersStatParams {
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::GetSubusersStatsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+
+    pub fn subusers<T: ToString>(mut self, subusers: T) -> Self {
+        self.subusers = Some(subusers.to_string());
+        self
+    }
+}
+
+/// The functions of [`SubuserStatistics`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSubuserStatisticsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SubuserStatisticsApi: Send + Sync {
+    /// See [`SubuserStatistics::get_subusers_subuser_name_stats_monthly_with`].
+    async fn get_subusers_subuser_name_stats_monthly_with(
+        &self,
+        subuser_name: &str,
+        params: &SubuserStatisticsGetSubusersSubuserNameStatsMonthlyParams,
+    ) -> Result<crate::types::SubuserStatsData>;
+
+    /// See [`SubuserStatistics::get_subusers_stats_monthly_with`].
+    async fn get_subusers_stats_monthly_with(
+        &self,
+        params: &SubuserStatisticsGetSubusersStatsMonthlyParams,
+    ) -> Result<crate::types::SubuserStatsData>;
+
+    /// See [`SubuserStatistics::get_subusers_stats_sum_with`].
+    async fn get_subusers_stats_sum_with(
+        &self,
+        params: &SubuserStatisticsGetSubusersStatsSumParams,
+    ) -> Result<crate::types::CategoryStats>;
+
+    /// See [`SubuserStatistics::get_subusers_stat_with`].
+    async fn get_subusers_stat_with(
+        &self,
+        params: &SubuserStatisticsGetSubusersStatParams,
+    ) -> Result<crate::types::CategoryStats>;
+}
+
+#[async_trait::async_trait]
+impl SubuserStatisticsApi for SubuserStatistics {
+    async fn get_subusers_subuser_name_stats_monthly_with(
+        &self,
+        subuser_name: &str,
+        params: &SubuserStatisticsGetSubusersSubuserNameStatsMonthlyParams,
+    ) -> Result<crate::types::SubuserStatsData> {
+        SubuserStatistics::get_subusers_subuser_name_stats_monthly_with(self, subuser_name, params)
+            .await
+    }
+
+    async fn get_subusers_stats_monthly_with(
+        &self,
+        params: &SubuserStatisticsGetSubusersStatsMonthlyParams,
+    ) -> Result<crate::types::SubuserStatsData> {
+        SubuserStatistics::get_subusers_stats_monthly_with(self, params).await
+    }
+
+    async fn get_subusers_stats_sum_with(
+        &self,
+        params: &SubuserStatisticsGetSubusersStatsSumParams,
+    ) -> Result<crate::types::CategoryStats> {
+        SubuserStatistics::get_subusers_stats_sum_with(self, params).await
+    }
+
+    async fn get_subusers_stat_with(
+        &self,
+        params: &SubuserStatisticsGetSubusersStatParams,
+    ) -> Result<crate::types::CategoryStats> {
+        SubuserStatistics::get_subusers_stat_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SubusersApi {
     pub client: Client,
@@ -23,24 +21,20 @@ impl SubusersApi {
      *
      * **Parameters:**
      *
-     * * `username: &str` -- The license key provided with your New Relic account.
-     * * `limit: i64` -- The number of results you would like to get in each request.
-     * * `offset: i64` -- The number of subusers to skip.
+     * * `params: &SubusersApiGetSubusersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_subusers(
+    pub async fn get_subusers_with(
         &self,
-        username: &str,
-        limit: i64,
-        offset: i64,
+        params: &SubusersApiGetSubusersParams,
     ) -> Result<Vec<crate::types::Subuser>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !username.is_empty() {
+        if let Some(username) = &params.username {
             query_args.push(("username".to_string(), username.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -49,6 +43,24 @@ impl SubusersApi {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_subusers_with` and pass the query parameters as a `SubusersApiGetSubusersParams` instead.
+     */
+    #[deprecated(note = "use `get_subusers_with` instead")]
+    pub async fn get_subusers(
+        &self,
+        username: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::Subuser>> {
+        let params = SubusersApiGetSubusersParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            username: Some(username.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_subusers_with(&params).await
+    }
+
     /**
      * List all Subusers.
      *
@@ -59,23 +71,88 @@ impl SubusersApi {
      * **This endpoint allows you to retrieve a list of all of your subusers.**
      *
      * You can choose to retrieve specific subusers as well as limit the results that come back from the API.
+     *
+     * **Parameters:**
+     *
+     * * `params: &SubusersApiGetSubusersParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_subusers_with(
+        &self,
+        params: &SubusersApiGetSubusersParams,
+    ) -> Result<Vec<crate::types::Subuser>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(username) = &params.username {
+            query_args.push(("username".to_string(), username.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/subusers?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_subusers_with` and pass the query parameters as a `SubusersApiGetSubusersParams` instead.
      */
+    #[deprecated(note = "use `get_all_subusers_with` instead")]
     pub async fn get_all_subusers(
         &self,
         username: &str,
         offset: i64,
     ) -> Result<Vec<crate::types::Subuser>> {
+        let params = SubusersApiGetSubusersParams {
+            offset: Some(offset).filter(|v| *v > 0),
+            username: Some(username.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_subusers_with(&params).await
+    }
+
+    /**
+     * List all Subusers.
+     *
+     * This function performs a `GET` to the `/subusers` endpoint.
+     *
+     * As opposed to `get_all_subusers`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve a list of all of your subusers.**
+     *
+     * You can choose to retrieve specific subusers as well as limit the results that come back from the API.
+     */
+    pub fn get_subusers_stream(
+        &self,
+        params: &SubusersApiGetSubusersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Subuser> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !username.is_empty() {
+        if let Some(username) = &params.username {
             query_args.push(("username".to_string(), username.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/subusers?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -107,7 +184,7 @@ impl SubusersApi {
     pub async fn delete_subusers_subuser_name(
         &self,
         subuser_name: &str,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::DeleteSubusersSubuserNameResponse> {
         let url = format!(
             "/subusers/{}",
             crate::progenitor_support::encode_path(subuser_name),
@@ -127,7 +204,7 @@ impl SubusersApi {
         &self,
         subuser_name: &str,
         body: &crate::types::PatchSubusersSubuserNameRequest,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::PatchSubusersSubuserNameResponse> {
         let url = format!(
             "/subusers/{}",
             crate::progenitor_support::encode_path(subuser_name),
@@ -149,14 +226,14 @@ impl SubusersApi {
      *
      * **Parameters:**
      *
-     * * `usernames: &str` -- The license key provided with your New Relic account.
+     * * `params: &SubusersApiGetSubusersReputationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_subusers_reputations(
+    pub async fn get_subusers_reputations_with(
         &self,
-        usernames: &str,
+        params: &SubusersApiGetSubusersReputationsParams,
     ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !usernames.is_empty() {
+        if let Some(usernames) = &params.usernames {
             query_args.push(("usernames".to_string(), usernames.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -165,6 +242,20 @@ impl SubusersApi {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_subusers_reputations_with` and pass the query parameters as a `SubusersApiGetSubusersReputationsParams` instead.
+     */
+    #[deprecated(note = "use `get_subusers_reputations_with` instead")]
+    pub async fn get_subusers_reputations(
+        &self,
+        usernames: &str,
+    ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>> {
+        let params = SubusersApiGetSubusersReputationsParams {
+            usernames: Some(usernames.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_subusers_reputations_with(&params).await
+    }
+
     /**
      * Retrieve Subuser Reputations.
      *
@@ -175,19 +266,79 @@ impl SubusersApi {
      * **This endpoint allows you to request the reputations for your subusers.**
      *
      * Subuser sender reputations give a good idea how well a sender is doing with regards to how recipients and recipient servers react to the mail that is being received. When a bounce, spam report, or other negative action happens on a sent email, it will affect your sender rating.
+     *
+     * **Parameters:**
+     *
+     * * `params: &SubusersApiGetSubusersReputationsParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_subusers_reputations_with(
+        &self,
+        params: &SubusersApiGetSubusersReputationsParams,
+    ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(usernames) = &params.usernames {
+            query_args.push(("usernames".to_string(), usernames.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/subusers/reputations?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_subusers_reputations_with` and pass the query parameters as a `SubusersApiGetSubusersReputationsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_subusers_reputations_with` instead")]
     pub async fn get_all_subusers_reputations(
         &self,
         usernames: &str,
     ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>> {
+        let params = SubusersApiGetSubusersReputationsParams {
+            usernames: Some(usernames.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_all_subusers_reputations_with(&params).await
+    }
+
+    /**
+     * Retrieve Subuser Reputations.
+     *
+     * This function performs a `GET` to the `/subusers/reputations` endpoint.
+     *
+     * As opposed to `get_all_subusers_reputations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to request the reputations for your subusers.**
+     *
+     * Subuser sender reputations give a good idea how well a sender is doing with regards to how recipients and recipient servers react to the mail that is being received. When a bounce, spam report, or other negative action happens on a sent email, it will affect your sender rating.
+     */
+    pub fn get_subusers_reputations_stream(
+        &self,
+        params: &SubusersApiGetSubusersReputationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetSubusersReputationsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !usernames.is_empty() {
+        if let Some(usernames) = &params.usernames {
             query_args.push(("usernames".to_string(), usernames.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/subusers/reputations?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -219,3 +370,196 @@ impl SubusersApi {
             .await
     }
 }
+
+/// The query parameters of `SubusersApi::get_subusers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SubusersApiGetSubusersParams {
+    /// The number of results you would like to get in each request.
+    pub limit: Option<i64>,
+    /// The number of subusers to skip.
+    pub offset: Option<i64>,
+    /// The license key provided with your New Relic account.
+    pub username: Option<String>,
+}
+
+impl SubusersApiGetSubusersParams {
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn username<T: ToString>(mut self, username: T) -> Self {
+        self.username = Some(username.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SubusersApi::get_subusers_reputations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SubusersApiGetSubusersReputationsParams {
+    /// The license key provided with your New Relic account.
+    pub usernames: Option<String>,
+}
+
+impl SubusersApiGetSubusersReputationsParams {
+    pub fn usernames<T: ToString>(mut self, usernames: T) -> Self {
+        self.usernames = Some(usernames.to_string());
+        self
+    }
+}
+
+/// The functions of [`SubusersApi`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSubusersApiApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SubusersApiApi: Send + Sync {
+    /// See [`SubusersApi::get_subusers_with`].
+    async fn get_subusers_with(
+        &self,
+        params: &SubusersApiGetSubusersParams,
+    ) -> Result<Vec<crate::types::Subuser>>;
+
+    /// See [`SubusersApi::get_all_subusers_with`].
+    async fn get_all_subusers_with(
+        &self,
+        params: &SubusersApiGetSubusersParams,
+    ) -> Result<Vec<crate::types::Subuser>>;
+
+    /// See [`SubusersApi::get_subusers_stream`].
+    fn get_subusers_stream(
+        &self,
+        params: &SubusersApiGetSubusersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Subuser>;
+
+    /// See [`SubusersApi::post_subuser`].
+    async fn post_subuser(
+        &self,
+        body: &crate::types::PostSubusersRequest,
+    ) -> Result<crate::types::SubuserPost>;
+
+    /// See [`SubusersApi::delete_subusers_subuser_name`].
+    async fn delete_subusers_subuser_name(
+        &self,
+        subuser_name: &str,
+    ) -> Result<crate::types::DeleteSubusersSubuserNameResponse>;
+
+    /// See [`SubusersApi::patch_subusers_subuser_name`].
+    async fn patch_subusers_subuser_name(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::PatchSubusersSubuserNameRequest,
+    ) -> Result<crate::types::PatchSubusersSubuserNameResponse>;
+
+    /// See [`SubusersApi::get_subusers_reputations_with`].
+    async fn get_subusers_reputations_with(
+        &self,
+        params: &SubusersApiGetSubusersReputationsParams,
+    ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>>;
+
+    /// See [`SubusersApi::get_all_subusers_reputations_with`].
+    async fn get_all_subusers_reputations_with(
+        &self,
+        params: &SubusersApiGetSubusersReputationsParams,
+    ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>>;
+
+    /// See [`SubusersApi::get_subusers_reputations_stream`].
+    fn get_subusers_reputations_stream(
+        &self,
+        params: &SubusersApiGetSubusersReputationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetSubusersReputationsResponse>;
+
+    /// See [`SubusersApi::put_subusers_subuser_name_ips`].
+    async fn put_subusers_subuser_name_ips(
+        &self,
+        subuser_name: &str,
+        body: &[std::net::Ipv4Addr],
+    ) -> Result<crate::types::PutSubusersSubuserNameIpsResponse>;
+}
+
+#[async_trait::async_trait]
+impl SubusersApiApi for SubusersApi {
+    async fn get_subusers_with(
+        &self,
+        params: &SubusersApiGetSubusersParams,
+    ) -> Result<Vec<crate::types::Subuser>> {
+        SubusersApi::get_subusers_with(self, params).await
+    }
+
+    async fn get_all_subusers_with(
+        &self,
+        params: &SubusersApiGetSubusersParams,
+    ) -> Result<Vec<crate::types::Subuser>> {
+        SubusersApi::get_all_subusers_with(self, params).await
+    }
+
+    fn get_subusers_stream(
+        &self,
+        params: &SubusersApiGetSubusersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Subuser> {
+        SubusersApi::get_subusers_stream(self, params, start, max_items)
+    }
+
+    async fn post_subuser(
+        &self,
+        body: &crate::types::PostSubusersRequest,
+    ) -> Result<crate::types::SubuserPost> {
+        SubusersApi::post_subuser(self, body).await
+    }
+
+    async fn delete_subusers_subuser_name(
+        &self,
+        subuser_name: &str,
+    ) -> Result<crate::types::DeleteSubusersSubuserNameResponse> {
+        SubusersApi::delete_subusers_subuser_name(self, subuser_name).await
+    }
+
+    async fn patch_subusers_subuser_name(
+        &self,
+        subuser_name: &str,
+        body: &crate::types::PatchSubusersSubuserNameRequest,
+    ) -> Result<crate::types::PatchSubusersSubuserNameResponse> {
+        SubusersApi::patch_subusers_subuser_name(self, subuser_name, body).await
+    }
+
+    async fn get_subusers_reputations_with(
+        &self,
+        params: &SubusersApiGetSubusersReputationsParams,
+    ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>> {
+        SubusersApi::get_subusers_reputations_with(self, params).await
+    }
+
+    async fn get_all_subusers_reputations_with(
+        &self,
+        params: &SubusersApiGetSubusersReputationsParams,
+    ) -> Result<Vec<crate::types::GetSubusersReputationsResponse>> {
+        SubusersApi::get_all_subusers_reputations_with(self, params).await
+    }
+
+    fn get_subusers_reputations_stream(
+        &self,
+        params: &SubusersApiGetSubusersReputationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetSubusersReputationsResponse> {
+        SubusersApi::get_subusers_reputations_stream(self, params, start, max_items)
+    }
+
+    async fn put_subusers_subuser_name_ips(
+        &self,
+        subuser_name: &str,
+        body: &[std::net::Ipv4Addr],
+    ) -> Result<crate::types::PutSubusersSubuserNameIpsResponse> {
+        SubusersApi::put_subusers_subuser_name_ips(self, subuser_name, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Suppressions {
     pub client: Client,
@@ -50,6 +48,44 @@ impl Suppressions {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve all suppressions for a suppression group.
+     *
+     * This function performs a `GET` to the `/asm/groups/{group_id}/suppressions` endpoint.
+     *
+     * As opposed to `get_all_asm_groups_group`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve all suppressed email addresses belonging to the given group.**
+     */
+    pub fn get_asm_groups_group_stream(
+        &self,
+        group_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<String> {
+        let url = format!(
+            "/asm/groups/{}/suppressions",
+            crate::progenitor_support::encode_path(group_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Add suppressions to a suppression group.
      *
@@ -66,7 +102,7 @@ impl Suppressions {
     pub async fn post_asm_groups_group(
         &self,
         group_id: &str,
-        body: &crate::types::SuppressionsRequestBody,
+        body: &crate::types::SuppressionsRequest,
     ) -> Result<crate::types::PostAsmGroupsGroupSuppressionsResponse> {
         let url = format!(
             "/asm/groups/{}/suppressions",
@@ -94,7 +130,7 @@ impl Suppressions {
     pub async fn post_asm_groups_group_search(
         &self,
         group_id: &str,
-        body: &crate::types::SuppressionsRequestBody,
+        body: &crate::types::SuppressionsRequest,
     ) -> Result<Vec<String>> {
         let url = format!(
             "/asm/groups/{}/suppressions/search",
@@ -136,6 +172,39 @@ impl Suppressions {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Retrieve all suppressions.
+     *
+     * This function performs a `GET` to the `/asm/suppressions` endpoint.
+     *
+     * As opposed to `get_all_asm`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve a list of all suppressions.**
+     */
+    pub fn get_asm_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetAsmSuppressionsResponse> {
+        let url = "/asm/suppressions".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Retrieve all suppression groups for an email address.
      *
@@ -182,3 +251,122 @@ impl Suppressions {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`Suppressions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSuppressionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SuppressionsApi: Send + Sync {
+    /// See [`Suppressions::get_asm_groups_group`].
+    async fn get_asm_groups_group(&self, group_id: &str) -> Result<Vec<String>>;
+
+    /// See [`Suppressions::get_all_asm_groups_group`].
+    async fn get_all_asm_groups_group(&self, group_id: &str) -> Result<Vec<String>>;
+
+    /// See [`Suppressions::get_asm_groups_group_stream`].
+    fn get_asm_groups_group_stream(
+        &self,
+        group_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<String>;
+
+    /// See [`Suppressions::post_asm_groups_group`].
+    async fn post_asm_groups_group(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionsRequest,
+    ) -> Result<crate::types::PostAsmGroupsGroupSuppressionsResponse>;
+
+    /// See [`Suppressions::post_asm_groups_group_search`].
+    async fn post_asm_groups_group_search(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionsRequest,
+    ) -> Result<Vec<String>>;
+
+    /// See [`Suppressions::get_asm`].
+    async fn get_asm(&self) -> Result<Vec<crate::types::GetAsmSuppressionsResponse>>;
+
+    /// See [`Suppressions::get_all_asm`].
+    async fn get_all_asm(&self) -> Result<Vec<crate::types::GetAsmSuppressionsResponse>>;
+
+    /// See [`Suppressions::get_asm_stream`].
+    fn get_asm_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetAsmSuppressionsResponse>;
+
+    /// See [`Suppressions::get_asm_email`].
+    async fn get_asm_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::GetAsmSuppressionsEmailResponse>;
+
+    /// See [`Suppressions::delete_asm_groups_group_email`].
+    async fn delete_asm_groups_group_email(&self, group_id: &str, email: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl SuppressionsApi for Suppressions {
+    async fn get_asm_groups_group(&self, group_id: &str) -> Result<Vec<String>> {
+        Suppressions::get_asm_groups_group(self, group_id).await
+    }
+
+    async fn get_all_asm_groups_group(&self, group_id: &str) -> Result<Vec<String>> {
+        Suppressions::get_all_asm_groups_group(self, group_id).await
+    }
+
+    fn get_asm_groups_group_stream(
+        &self,
+        group_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<String> {
+        Suppressions::get_asm_groups_group_stream(self, group_id, start, max_items)
+    }
+
+    async fn post_asm_groups_group(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionsRequest,
+    ) -> Result<crate::types::PostAsmGroupsGroupSuppressionsResponse> {
+        Suppressions::post_asm_groups_group(self, group_id, body).await
+    }
+
+    async fn post_asm_groups_group_search(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionsRequest,
+    ) -> Result<Vec<String>> {
+        Suppressions::post_asm_groups_group_search(self, group_id, body).await
+    }
+
+    async fn get_asm(&self) -> Result<Vec<crate::types::GetAsmSuppressionsResponse>> {
+        Suppressions::get_asm(self).await
+    }
+
+    async fn get_all_asm(&self) -> Result<Vec<crate::types::GetAsmSuppressionsResponse>> {
+        Suppressions::get_all_asm(self).await
+    }
+
+    fn get_asm_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetAsmSuppressionsResponse> {
+        Suppressions::get_asm_stream(self, start, max_items)
+    }
+
+    async fn get_asm_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::GetAsmSuppressionsEmailResponse> {
+        Suppressions::get_asm_email(self, email).await
+    }
+
+    async fn delete_asm_groups_group_email(&self, group_id: &str, email: &str) -> Result<()> {
+        Suppressions::delete_asm_groups_group_email(self, group_id, email).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SuppressionsGlobal {
     pub client: Client,
@@ -25,8 +23,8 @@ impl SuppressionsGlobal {
      */
     pub async fn post_asm(
         &self,
-        body: &crate::types::SuppressionsRequestBody,
-    ) -> Result<crate::types::SuppressionsRequestBody> {
+        body: &crate::types::SuppressionsRequest,
+    ) -> Result<crate::types::PostAsmSuppressionsGlobalResponse> {
         let url = "/asm/suppressions/global".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -42,30 +40,23 @@ impl SuppressionsGlobal {
      *
      * **Parameters:**
      *
-     * * `start_time: i64` -- Refers start of the time range in unix timestamp when an unsubscribe email was created (inclusive).
-     * * `end_time: i64` -- Refers end of the time range in unix timestamp when an unsubscribe email was created (inclusive).
-     * * `limit: i64` -- The number of results to display on each page.
-     * * `offset: i64` -- The point in the list of results to begin displaying global suppressions.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &SuppressionsGlobalGetSuppressionUnsubscribesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_suppression_unsubscribes(
+    pub async fn get_suppression_unsubscribes_with(
         &self,
-        start_time: i64,
-        end_time: i64,
-        limit: i64,
-        offset: i64,
+        params: &SuppressionsGlobalGetSuppressionUnsubscribesParams,
     ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if end_time > 0 {
+        if let Some(end_time) = &params.end_time {
             query_args.push(("end_time".to_string(), end_time.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if start_time > 0 {
+        if let Some(start_time) = &params.start_time {
             query_args.push(("start_time".to_string(), start_time.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -74,6 +65,26 @@ impl SuppressionsGlobal {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_suppression_unsubscribes_with` and pass the query parameters as a `SuppressionsGlobalGetSuppressionUnsubscribesParams` instead.
+     */
+    #[deprecated(note = "use `get_suppression_unsubscribes_with` instead")]
+    pub async fn get_suppression_unsubscribes(
+        &self,
+        start_time: i64,
+        end_time: i64,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
+        let params = SuppressionsGlobalGetSuppressionUnsubscribesParams {
+            end_time: Some(end_time).filter(|v| *v > 0),
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_time: Some(start_time).filter(|v| *v > 0),
+        };
+        self.get_suppression_unsubscribes_with(&params).await
+    }
+
     /**
      * Retrieve all global suppressions.
      *
@@ -82,27 +93,94 @@ impl SuppressionsGlobal {
      * As opposed to `get_suppression_unsubscribes`, this function returns all the pages of the request at once.
      *
      * **This endpoint allows you to retrieve a list of all email address that are globally suppressed.**
+     *
+     * **Parameters:**
+     *
+     * * `params: &SuppressionsGlobalGetSuppressionUnsubscribesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_suppression_unsubscribes_with(
+        &self,
+        params: &SuppressionsGlobalGetSuppressionUnsubscribesParams,
+    ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(end_time) = &params.end_time {
+            query_args.push(("end_time".to_string(), end_time.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(start_time) = &params.start_time {
+            query_args.push(("start_time".to_string(), start_time.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/suppression/unsubscribes?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_suppression_unsubscribes_with` and pass the query parameters as a `SuppressionsGlobalGetSuppressionUnsubscribesParams` instead.
      */
+    #[deprecated(note = "use `get_all_suppression_unsubscribes_with` instead")]
     pub async fn get_all_suppression_unsubscribes(
         &self,
         start_time: i64,
         end_time: i64,
         offset: i64,
     ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
+        let params = SuppressionsGlobalGetSuppressionUnsubscribesParams {
+            end_time: Some(end_time).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+            start_time: Some(start_time).filter(|v| *v > 0),
+            ..Default::default()
+        };
+        self.get_all_suppression_unsubscribes_with(&params).await
+    }
+
+    /**
+     * Retrieve all global suppressions.
+     *
+     * This function performs a `GET` to the `/suppression/unsubscribes` endpoint.
+     *
+     * As opposed to `get_all_suppression_unsubscribes`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve a list of all email address that are globally suppressed.**
+     */
+    pub fn get_suppression_unsubscribes_stream(
+        &self,
+        params: &SuppressionsGlobalGetSuppressionUnsubscribesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetSuppressionUnsubscribesResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if end_time > 0 {
+        if let Some(end_time) = &params.end_time {
             query_args.push(("end_time".to_string(), end_time.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if start_time > 0 {
+        if let Some(start_time) = &params.start_time {
             query_args.push(("start_time".to_string(), start_time.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/suppression/unsubscribes?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -143,7 +221,10 @@ impl SuppressionsGlobal {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_asm_email(&self, email: &str) -> Result<crate::types::Help> {
+    pub async fn delete_asm_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::DeleteAsmSuppressionsGlobalEmailResponse> {
         let url = format!(
             "/asm/suppressions/global/{}",
             crate::progenitor_support::encode_path(email),
@@ -152,3 +233,129 @@ impl SuppressionsGlobal {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `SuppressionsGlobal::get_suppression_unsubscribes_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SuppressionsGlobalGetSuppressionUnsubscribesParams {
+    /// Refers end of the time range in unix timestamp when an unsubscribe email was created (inclusive).
+    pub end_time: Option<i64>,
+    /// The number of results to display on each page.
+    pub limit: Option<i64>,
+    /// The point in the list of results to begin displaying global suppressions.
+    pub offset: Option<i64>,
+    /// Refers start of the time range in unix timestamp when an unsubscribe email was created (inclusive).
+    pub start_time: Option<i64>,
+}
+
+impl SuppressionsGlobalGetSuppressionUnsubscribesParams {
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+}
+
+/// The functions of [`SuppressionsGlobal`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSuppressionsGlobalApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SuppressionsGlobalApi: Send + Sync {
+    /// See [`SuppressionsGlobal::post_asm`].
+    async fn post_asm(
+        &self,
+        body: &crate::types::SuppressionsRequest,
+    ) -> Result<crate::types::PostAsmSuppressionsGlobalResponse>;
+
+    /// See [`SuppressionsGlobal::get_suppression_unsubscribes_with`].
+    async fn get_suppression_unsubscribes_with(
+        &self,
+        params: &SuppressionsGlobalGetSuppressionUnsubscribesParams,
+    ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>>;
+
+    /// See [`SuppressionsGlobal::get_all_suppression_unsubscribes_with`].
+    async fn get_all_suppression_unsubscribes_with(
+        &self,
+        params: &SuppressionsGlobalGetSuppressionUnsubscribesParams,
+    ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>>;
+
+    /// See [`SuppressionsGlobal::get_suppression_unsubscribes_stream`].
+    fn get_suppression_unsubscribes_stream(
+        &self,
+        params: &SuppressionsGlobalGetSuppressionUnsubscribesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetSuppressionUnsubscribesResponse>;
+
+    /// See [`SuppressionsGlobal::get_asm_email`].
+    async fn get_asm_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::RetrieveAGlobalSuppressionResponse>;
+
+    /// See [`SuppressionsGlobal::delete_asm_email`].
+    async fn delete_asm_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::DeleteAsmSuppressionsGlobalEmailResponse>;
+}
+
+#[async_trait::async_trait]
+impl SuppressionsGlobalApi for SuppressionsGlobal {
+    async fn post_asm(
+        &self,
+        body: &crate::types::SuppressionsRequest,
+    ) -> Result<crate::types::PostAsmSuppressionsGlobalResponse> {
+        SuppressionsGlobal::post_asm(self, body).await
+    }
+
+    async fn get_suppression_unsubscribes_with(
+        &self,
+        params: &SuppressionsGlobalGetSuppressionUnsubscribesParams,
+    ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
+        SuppressionsGlobal::get_suppression_unsubscribes_with(self, params).await
+    }
+
+    async fn get_all_suppression_unsubscribes_with(
+        &self,
+        params: &SuppressionsGlobalGetSuppressionUnsubscribesParams,
+    ) -> Result<Vec<crate::types::GetSuppressionUnsubscribesResponse>> {
+        SuppressionsGlobal::get_all_suppression_unsubscribes_with(self, params).await
+    }
+
+    fn get_suppression_unsubscribes_stream(
+        &self,
+        params: &SuppressionsGlobalGetSuppressionUnsubscribesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetSuppressionUnsubscribesResponse> {
+        SuppressionsGlobal::get_suppression_unsubscribes_stream(self, params, start, max_items)
+    }
+
+    async fn get_asm_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::RetrieveAGlobalSuppressionResponse> {
+        SuppressionsGlobal::get_asm_email(self, email).await
+    }
+
+    async fn delete_asm_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::DeleteAsmSuppressionsGlobalEmailResponse> {
+        SuppressionsGlobal::delete_asm_email(self, email).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SuppressionsUnsubscribeGroups {
     pub client: Client,
@@ -23,12 +21,14 @@ impl SuppressionsUnsubscribeGroups {
      *
      * **Parameters:**
      *
-     * * `id: i64`
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &SuppressionsUnsubscribeGroupsGetAsmGroupsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_asm_groups(&self, id: i64) -> Result<Vec<crate::types::SuppressionGroup>> {
+    pub async fn get_asm_groups_with(
+        &self,
+        params: &SuppressionsUnsubscribeGroupsGetAsmGroupsParams,
+    ) -> Result<Vec<crate::types::SuppressionGroup>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if id > 0 {
+        if let Some(id) = &params.id {
             query_args.push(("id".to_string(), id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -37,6 +37,17 @@ impl SuppressionsUnsubscribeGroups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_asm_groups_with` and pass the query parameters as a `SuppressionsUnsubscribeGroupsGetAsmGroupsParams` instead.
+     */
+    #[deprecated(note = "use `get_asm_groups_with` instead")]
+    pub async fn get_asm_groups(&self, id: i64) -> Result<Vec<crate::types::SuppressionGroup>> {
+        let params = SuppressionsUnsubscribeGroupsGetAsmGroupsParams {
+            id: Some(id).filter(|v| *v > 0),
+        };
+        self.get_asm_groups_with(&params).await
+    }
+
     /**
      * Retrieve all suppression groups associated with the user.
      *
@@ -47,10 +58,17 @@ impl SuppressionsUnsubscribeGroups {
      * **This endpoint allows you to retrieve a list of all suppression groups created by this user.**
      *
      * This endpoint can also return information for multiple group IDs that you include in your request. To add a group ID to your request, simply append `?id=123456&id=123456`, with the appropriate group IDs.
+     *
+     * **Parameters:**
+     *
+     * * `params: &SuppressionsUnsubscribeGroupsGetAsmGroupsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_asm_groups(&self, id: i64) -> Result<Vec<crate::types::SuppressionGroup>> {
+    pub async fn get_all_asm_groups_with(
+        &self,
+        params: &SuppressionsUnsubscribeGroupsGetAsmGroupsParams,
+    ) -> Result<Vec<crate::types::SuppressionGroup>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if id > 0 {
+        if let Some(id) = &params.id {
             query_args.push(("id".to_string(), id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -59,6 +77,59 @@ impl SuppressionsUnsubscribeGroups {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_all_asm_groups_with` and pass the query parameters as a `SuppressionsUnsubscribeGroupsGetAsmGroupsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_asm_groups_with` instead")]
+    pub async fn get_all_asm_groups(&self, id: i64) -> Result<Vec<crate::types::SuppressionGroup>> {
+        let params = SuppressionsUnsubscribeGroupsGetAsmGroupsParams {
+            id: Some(id).filter(|v| *v > 0),
+        };
+        self.get_all_asm_groups_with(&params).await
+    }
+
+    /**
+     * Retrieve all suppression groups associated with the user.
+     *
+     * This function performs a `GET` to the `/asm/groups` endpoint.
+     *
+     * As opposed to `get_all_asm_groups`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve a list of all suppression groups created by this user.**
+     *
+     * This endpoint can also return information for multiple group IDs that you include in your request. To add a group ID to your request, simply append `?id=123456&id=123456`, with the appropriate group IDs.
+     */
+    pub fn get_asm_groups_stream(
+        &self,
+        params: &SuppressionsUnsubscribeGroupsGetAsmGroupsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SuppressionGroup> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(id) = &params.id {
+            query_args.push(("id".to_string(), id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/asm/groups?{}", query_);
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a new suppression group.
      *
@@ -120,7 +191,10 @@ impl SuppressionsUnsubscribeGroups {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_asm_groups_group(&self, group_id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> Result<crate::types::DeleteAsmGroupsGroupResponse> {
         let url = format!(
             "/asm/groups/{}",
             crate::progenitor_support::encode_path(group_id),
@@ -155,3 +229,122 @@ impl SuppressionsUnsubscribeGroups {
             .await
     }
 }
+
+/// The query parameters of `SuppressionsUnsubscribeGroups::get_asm_groups_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SuppressionsUnsubscribeGroupsGetAsmGroupsParams {
+    pub id: Option<i64>,
+}
+
+impl SuppressionsUnsubscribeGroupsGetAsmGroupsParams {
+    pub fn id(mut self, id: i64) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+/// The functions of [`SuppressionsUnsubscribeGroups`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSuppressionsUnsubscribeGroupsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SuppressionsUnsubscribeGroupsApi: Send + Sync {
+    /// See [`SuppressionsUnsubscribeGroups::get_asm_groups_with`].
+    async fn get_asm_groups_with(
+        &self,
+        params: &SuppressionsUnsubscribeGroupsGetAsmGroupsParams,
+    ) -> Result<Vec<crate::types::SuppressionGroup>>;
+
+    /// See [`SuppressionsUnsubscribeGroups::get_all_asm_groups_with`].
+    async fn get_all_asm_groups_with(
+        &self,
+        params: &SuppressionsUnsubscribeGroupsGetAsmGroupsParams,
+    ) -> Result<Vec<crate::types::SuppressionGroup>>;
+
+    /// See [`SuppressionsUnsubscribeGroups::get_asm_groups_stream`].
+    fn get_asm_groups_stream(
+        &self,
+        params: &SuppressionsUnsubscribeGroupsGetAsmGroupsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SuppressionGroup>;
+
+    /// See [`SuppressionsUnsubscribeGroups::post_asm_group`].
+    async fn post_asm_group(
+        &self,
+        body: &crate::types::SuppressionGroupRequestBase,
+    ) -> Result<crate::types::PostAsmGroupsResponse>;
+
+    /// See [`SuppressionsUnsubscribeGroups::get_asm_groups_group`].
+    async fn get_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> Result<crate::types::GetAsmGroupsGroupResponseAllOf>;
+
+    /// See [`SuppressionsUnsubscribeGroups::delete_asm_groups_group`].
+    async fn delete_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> Result<crate::types::DeleteAsmGroupsGroupResponse>;
+
+    /// See [`SuppressionsUnsubscribeGroups::patch_asm_groups_group`].
+    async fn patch_asm_groups_group(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionGroupRequestBase,
+    ) -> Result<crate::types::SuppressionGroup>;
+}
+
+#[async_trait::async_trait]
+impl SuppressionsUnsubscribeGroupsApi for SuppressionsUnsubscribeGroups {
+    async fn get_asm_groups_with(
+        &self,
+        params: &SuppressionsUnsubscribeGroupsGetAsmGroupsParams,
+    ) -> Result<Vec<crate::types::SuppressionGroup>> {
+        SuppressionsUnsubscribeGroups::get_asm_groups_with(self, params).await
+    }
+
+    async fn get_all_asm_groups_with(
+        &self,
+        params: &SuppressionsUnsubscribeGroupsGetAsmGroupsParams,
+    ) -> Result<Vec<crate::types::SuppressionGroup>> {
+        SuppressionsUnsubscribeGroups::get_all_asm_groups_with(self, params).await
+    }
+
+    fn get_asm_groups_stream(
+        &self,
+        params: &SuppressionsUnsubscribeGroupsGetAsmGroupsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SuppressionGroup> {
+        SuppressionsUnsubscribeGroups::get_asm_groups_stream(self, params, start, max_items)
+    }
+
+    async fn post_asm_group(
+        &self,
+        body: &crate::types::SuppressionGroupRequestBase,
+    ) -> Result<crate::types::PostAsmGroupsResponse> {
+        SuppressionsUnsubscribeGroups::post_asm_group(self, body).await
+    }
+
+    async fn get_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> Result<crate::types::GetAsmGroupsGroupResponseAllOf> {
+        SuppressionsUnsubscribeGroups::get_asm_groups_group(self, group_id).await
+    }
+
+    async fn delete_asm_groups_group(
+        &self,
+        group_id: &str,
+    ) -> Result<crate::types::DeleteAsmGroupsGroupResponse> {
+        SuppressionsUnsubscribeGroups::delete_asm_groups_group(self, group_id).await
+    }
+
+    async fn patch_asm_groups_group(
+        &self,
+        group_id: &str,
+        body: &crate::types::SuppressionGroupRequestBase,
+    ) -> Result<crate::types::SuppressionGroup> {
+        SuppressionsUnsubscribeGroups::patch_asm_groups_group(self, group_id, body).await
+    }
+}
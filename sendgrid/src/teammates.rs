@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Teammates {
     pub client: Client,
@@ -23,16 +21,17 @@ impl Teammates {
      *
      * **Parameters:**
      *
-     * * `limit: u64` -- Number of items to return.
-     * * `offset: u64` -- Paging offset.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &TeammatesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(&self, limit: u64, offset: u64) -> Result<crate::types::GetTeammatesResponse> {
+    pub async fn get_with(
+        &self,
+        params: &TeammatesGetParams,
+    ) -> Result<crate::types::GetTeammatesResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.to_string().is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !offset.to_string().is_empty() {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -41,6 +40,18 @@ impl Teammates {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `TeammatesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(&self, limit: u64, offset: u64) -> Result<crate::types::GetTeammatesResponse> {
+        let params = TeammatesGetParams {
+            limit: Some(limit).filter(|v| !v.to_string().is_empty()),
+            offset: Some(offset).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Invite teammate.
      *
@@ -82,7 +93,7 @@ impl Teammates {
     pub async fn post_pending_token_resend(
         &self,
         token: &str,
-    ) -> Result<crate::types::PostTeammatesResponse> {
+    ) -> Result<crate::types::PostTeammatesPendingTokenResendResponse> {
         let url = format!(
             "/teammates/pending/{}/resend",
             crate::progenitor_support::encode_path(token),
@@ -102,19 +113,17 @@ impl Teammates {
      *
      * **Parameters:**
      *
-     * * `limit: i64` -- Optional field to limit the number of results returned.
-     * * `offset: i64` -- Optional beginning point in the list to retrieve from.
+     * * `params: &TeammatesGetScopesRequestsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_scopes_requests(
+    pub async fn get_scopes_requests_with(
         &self,
-        limit: i64,
-        offset: i64,
+        params: &TeammatesGetScopesRequestsParams,
     ) -> Result<Vec<crate::types::GetScopesRequestsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -123,6 +132,22 @@ impl Teammates {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_scopes_requests_with` and pass the query parameters as a `TeammatesGetScopesRequestsParams` instead.
+     */
+    #[deprecated(note = "use `get_scopes_requests_with` instead")]
+    pub async fn get_scopes_requests(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<crate::types::GetScopesRequestsResponse>> {
+        let params = TeammatesGetScopesRequestsParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            offset: Some(offset).filter(|v| *v > 0),
+        };
+        self.get_scopes_requests_with(&params).await
+    }
+
     /**
      * Retrieve access requests.
      *
@@ -133,19 +158,80 @@ impl Teammates {
      * **This endpoint allows you to retrieve a list of all recent access requests.**
      *
      * The Response Header's `link` parameter will include pagination info.
+     *
+     * **Parameters:**
+     *
+     * * `params: &TeammatesGetScopesRequestsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_scopes_requests_with(
+        &self,
+        params: &TeammatesGetScopesRequestsParams,
+    ) -> Result<Vec<crate::types::GetScopesRequestsResponse>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/scopes/requests?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_scopes_requests_with` and pass the query parameters as a `TeammatesGetScopesRequestsParams` instead.
      */
+    #[deprecated(note = "use `get_all_scopes_requests_with` instead")]
     pub async fn get_all_scopes_requests(
         &self,
         offset: i64,
     ) -> Result<Vec<crate::types::GetScopesRequestsResponse>> {
+        let params = TeammatesGetScopesRequestsParams {
+            offset: Some(offset).filter(|v| *v > 0),
+            ..Default::default()
+        };
+        self.get_all_scopes_requests_with(&params).await
+    }
+
+    /**
+     * Retrieve access requests.
+     *
+     * This function performs a `GET` to the `/scopes/requests` endpoint.
+     *
+     * As opposed to `get_all_scopes_requests`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve a list of all recent access requests.**
+     *
+     * The Response Header's `link` parameter will include pagination info.
+     */
+    pub fn get_scopes_requests_stream(
+        &self,
+        params: &TeammatesGetScopesRequestsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetScopesRequestsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if offset > 0 {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/scopes/requests?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -207,7 +293,7 @@ impl Teammates {
     pub async fn delete_username(
         &self,
         username: &str,
-    ) -> Result<crate::types::PostSendersResponse> {
+    ) -> Result<crate::types::DeleteTeammatesUsernameResponse> {
         let url = format!(
             "/teammates/{}",
             crate::progenitor_support::encode_path(username),
@@ -237,7 +323,7 @@ impl Teammates {
         &self,
         username: &str,
         body: &crate::types::PatchTeammatesUsernameRequest,
-    ) -> Result<crate::types::GetTeammatesUsernameResponse> {
+    ) -> Result<crate::types::PatchTeammatesUsernameResponse> {
         let url = format!(
             "/teammates/{}",
             crate::progenitor_support::encode_path(username),
@@ -307,3 +393,211 @@ impl Teammates {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Teammates::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeammatesGetParams {
+    /// Number of items to return.
+    pub limit: Option<u64>,
+    /// Paging offset.
+    pub offset: Option<u64>,
+}
+
+impl TeammatesGetParams {
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The query parameters of `Teammates::get_scopes_requests_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeammatesGetScopesRequestsParams {
+    /// Optional field to limit the number of results returned.
+    pub limit: Option<i64>,
+    /// Optional beginning point in the list to retrieve from.
+    pub offset: Option<i64>,
+}
+
+impl TeammatesGetScopesRequestsParams {
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+}
+
+/// The functions of [`Teammates`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTeammatesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TeammatesApi: Send + Sync {
+    /// See [`Teammates::get_with`].
+    async fn get_with(
+        &self,
+        params: &TeammatesGetParams,
+    ) -> Result<crate::types::GetTeammatesResponse>;
+
+    /// See [`Teammates::post`].
+    async fn post(
+        &self,
+        body: &crate::types::PostTeammatesRequest,
+    ) -> Result<crate::types::PostTeammatesResponse>;
+
+    /// See [`Teammates::post_pending_token_resend`].
+    async fn post_pending_token_resend(
+        &self,
+        token: &str,
+    ) -> Result<crate::types::PostTeammatesPendingTokenResendResponse>;
+
+    /// See [`Teammates::get_scopes_requests_with`].
+    async fn get_scopes_requests_with(
+        &self,
+        params: &TeammatesGetScopesRequestsParams,
+    ) -> Result<Vec<crate::types::GetScopesRequestsResponse>>;
+
+    /// See [`Teammates::get_all_scopes_requests_with`].
+    async fn get_all_scopes_requests_with(
+        &self,
+        params: &TeammatesGetScopesRequestsParams,
+    ) -> Result<Vec<crate::types::GetScopesRequestsResponse>>;
+
+    /// See [`Teammates::get_scopes_requests_stream`].
+    fn get_scopes_requests_stream(
+        &self,
+        params: &TeammatesGetScopesRequestsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetScopesRequestsResponse>;
+
+    /// See [`Teammates::get_pending`].
+    async fn get_pending(&self) -> Result<crate::types::GetTeammatesPendingResponse>;
+
+    /// See [`Teammates::get_username`].
+    async fn get_username(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::GetTeammatesUsernameResponse>;
+
+    /// See [`Teammates::delete_username`].
+    async fn delete_username(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::DeleteTeammatesUsernameResponse>;
+
+    /// See [`Teammates::patch_username`].
+    async fn patch_username(
+        &self,
+        username: &str,
+        body: &crate::types::PatchTeammatesUsernameRequest,
+    ) -> Result<crate::types::PatchTeammatesUsernameResponse>;
+
+    /// See [`Teammates::patch_scopes_requests_approve`].
+    async fn patch_scopes_requests_approve(
+        &self,
+        request_id: &str,
+    ) -> Result<crate::types::PatchScopesRequestsApproveResponse>;
+
+    /// See [`Teammates::delete_scopes_requests_request`].
+    async fn delete_scopes_requests_request(&self, request_id: &str) -> Result<()>;
+
+    /// See [`Teammates::delete_pending_token`].
+    async fn delete_pending_token(&self, token: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl TeammatesApi for Teammates {
+    async fn get_with(
+        &self,
+        params: &TeammatesGetParams,
+    ) -> Result<crate::types::GetTeammatesResponse> {
+        Teammates::get_with(self, params).await
+    }
+
+    async fn post(
+        &self,
+        body: &crate::types::PostTeammatesRequest,
+    ) -> Result<crate::types::PostTeammatesResponse> {
+        Teammates::post(self, body).await
+    }
+
+    async fn post_pending_token_resend(
+        &self,
+        token: &str,
+    ) -> Result<crate::types::PostTeammatesPendingTokenResendResponse> {
+        Teammates::post_pending_token_resend(self, token).await
+    }
+
+    async fn get_scopes_requests_with(
+        &self,
+        params: &TeammatesGetScopesRequestsParams,
+    ) -> Result<Vec<crate::types::GetScopesRequestsResponse>> {
+        Teammates::get_scopes_requests_with(self, params).await
+    }
+
+    async fn get_all_scopes_requests_with(
+        &self,
+        params: &TeammatesGetScopesRequestsParams,
+    ) -> Result<Vec<crate::types::GetScopesRequestsResponse>> {
+        Teammates::get_all_scopes_requests_with(self, params).await
+    }
+
+    fn get_scopes_requests_stream(
+        &self,
+        params: &TeammatesGetScopesRequestsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetScopesRequestsResponse> {
+        Teammates::get_scopes_requests_stream(self, params, start, max_items)
+    }
+
+    async fn get_pending(&self) -> Result<crate::types::GetTeammatesPendingResponse> {
+        Teammates::get_pending(self).await
+    }
+
+    async fn get_username(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::GetTeammatesUsernameResponse> {
+        Teammates::get_username(self, username).await
+    }
+
+    async fn delete_username(
+        &self,
+        username: &str,
+    ) -> Result<crate::types::DeleteTeammatesUsernameResponse> {
+        Teammates::delete_username(self, username).await
+    }
+
+    async fn patch_username(
+        &self,
+        username: &str,
+        body: &crate::types::PatchTeammatesUsernameRequest,
+    ) -> Result<crate::types::PatchTeammatesUsernameResponse> {
+        Teammates::patch_username(self, username, body).await
+    }
+
+    async fn patch_scopes_requests_approve(
+        &self,
+        request_id: &str,
+    ) -> Result<crate::types::PatchScopesRequestsApproveResponse> {
+        Teammates::patch_scopes_requests_approve(self, request_id).await
+    }
+
+    async fn delete_scopes_requests_request(&self, request_id: &str) -> Result<()> {
+        Teammates::delete_scopes_requests_request(self, request_id).await
+    }
+
+    async fn delete_pending_token(&self, token: &str) -> Result<()> {
+        Teammates::delete_pending_token(self, token).await
+    }
+}
@@ -35,7 +35,7 @@ impl MailOps for crate::mail_send::MailSend {
         mail.subject = subject.to_string();
         mail.from = crate::types::FromEmailObject {
             email: from.to_string(),
-            name: String::new(),
+            name: None,
         };
         mail.content = vec![crate::types::Content {
             value: message.to_string(),
@@ -44,21 +44,21 @@ impl MailOps for crate::mail_send::MailSend {
         let mut p: crate::types::Personalizations = Default::default();
         p.from = Some(mail.from.clone());
         for to in tos {
-            p.to.push(crate::types::ReplyTo {
+            p.to.push(crate::types::ToEmailArray {
                 email: to.to_string(),
-                name: String::new(),
+                name: None,
             });
         }
         for cc in ccs {
             p.cc.push(crate::types::CcBccEmailObject {
                 email: cc.to_string(),
-                name: String::new(),
+                name: None,
             });
         }
         for bcc in bccs {
             p.bcc.push(crate::types::CcBccEmailObject {
                 email: bcc.to_string(),
-                name: String::new(),
+                name: None,
             });
         }
         mail.personalizations = vec![p];
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TransactionalTemplates {
     pub client: Client,
@@ -21,25 +19,20 @@ impl TransactionalTemplates {
      *
      * **Parameters:**
      *
-     * * `generations: crate::types::Generations` -- Comma-delimited list specifying which generations of templates to return. Options are `legacy`, `dynamic` or `legacy,dynamic`.
-     * * `page_size: f64` -- The number of templates to be returned in each page of results.
-     * * `page_token: &str` -- A token corresponding to a specific page of results, as provided by metadata.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &TransactionalTemplatesGetTemplatesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_templates(
+    pub async fn get_templates_with(
         &self,
-        generations: crate::types::Generations,
-        page_size: f64,
-        page_token: &str,
+        params: &TransactionalTemplatesGetTemplatesParams,
     ) -> Result<crate::types::GetTemplatesResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !generations.to_string().is_empty() {
+        if let Some(generations) = &params.generations {
             query_args.push(("generations".to_string(), generations.to_string()));
         }
-        if !page_size.to_string().is_empty() {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !page_token.is_empty() {
+        if let Some(page_token) = &params.page_token {
             query_args.push(("page_token".to_string(), page_token.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -48,6 +41,24 @@ impl TransactionalTemplates {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_templates_with` and pass the query parameters as a `TransactionalTemplatesGetTemplatesParams` instead.
+     */
+    #[deprecated(note = "use `get_templates_with` instead")]
+    pub async fn get_templates(
+        &self,
+        generations: crate::types::Generations,
+        page_size: f64,
+        page_token: &str,
+    ) -> Result<crate::types::GetTemplatesResponse> {
+        let params = TransactionalTemplatesGetTemplatesParams {
+            generations: Some(generations).filter(|v| !v.to_string().is_empty()),
+            page_size: Some(page_size).filter(|v| !v.to_string().is_empty()),
+            page_token: Some(page_token.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_templates_with(&params).await
+    }
+
     /**
      * Create a transactional template.
      *
@@ -129,7 +140,10 @@ impl TransactionalTemplates {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn delete_templates_template(&self, template_id: &str) -> Result<crate::types::Help> {
+    pub async fn delete_templates_template(
+        &self,
+        template_id: &str,
+    ) -> Result<crate::types::DeleteTemplatesTemplateResponse> {
         let url = format!(
             "/templates/{}",
             crate::progenitor_support::encode_path(template_id),
@@ -166,3 +180,122 @@ impl TransactionalTemplates {
             .await
     }
 }
+
+/// The query parameters of `TransactionalTemplates::get_templates_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TransactionalTemplatesGetTemplatesParams {
+    /// Comma-delimited list specifying which generations of templates to return. Options are `legacy`, `dynamic` or `legacy,dynamic`.
+    pub generations: Option<crate::types::Generations>,
+    /// The number of templates to be returned in each page of results.
+    pub page_size: Option<f64>,
+    /// A token corresponding to a specific page of results, as provided by metadata.
+    pub page_token: Option<String>,
+}
+
+impl TransactionalTemplatesGetTemplatesParams {
+    pub fn generations(mut self, generations: crate::types::Generations) -> Self {
+        self.generations = Some(generations);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: f64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn page_token<T: ToString>(mut self, page_token: T) -> Self {
+        self.page_token = Some(page_token.to_string());
+        self
+    }
+}
+
+/// The functions of [`TransactionalTemplates`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTransactionalTemplatesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TransactionalTemplatesApi: Send + Sync {
+    /// See [`TransactionalTemplates::get_templates_with`].
+    async fn get_templates_with(
+        &self,
+        params: &TransactionalTemplatesGetTemplatesParams,
+    ) -> Result<crate::types::GetTemplatesResponse>;
+
+    /// See [`TransactionalTemplates::post_template`].
+    async fn post_template(
+        &self,
+        body: &crate::types::PostTemplatesRequest,
+    ) -> Result<crate::types::TransactionalTemplateAllOf>;
+
+    /// See [`TransactionalTemplates::get_templates_template`].
+    async fn get_templates_template(
+        &self,
+        template_id: &str,
+    ) -> Result<crate::types::TransactionalTemplateAllOf>;
+
+    /// See [`TransactionalTemplates::post_templates_template`].
+    async fn post_templates_template(
+        &self,
+        template_id: &str,
+        body: &crate::types::PostTemplatesTemplateRequest,
+    ) -> Result<crate::types::TransactionalTemplateAllOf>;
+
+    /// See [`TransactionalTemplates::delete_templates_template`].
+    async fn delete_templates_template(
+        &self,
+        template_id: &str,
+    ) -> Result<crate::types::DeleteTemplatesTemplateResponse>;
+
+    /// See [`TransactionalTemplates::patch_templates_template`].
+    async fn patch_templates_template(
+        &self,
+        template_id: &str,
+        body: &crate::types::PatchTemplatesTemplateRequest,
+    ) -> Result<crate::types::TransactionalTemplateAllOf>;
+}
+
+#[async_trait::async_trait]
+impl TransactionalTemplatesApi for TransactionalTemplates {
+    async fn get_templates_with(
+        &self,
+        params: &TransactionalTemplatesGetTemplatesParams,
+    ) -> Result<crate::types::GetTemplatesResponse> {
+        TransactionalTemplates::get_templates_with(self, params).await
+    }
+
+    async fn post_template(
+        &self,
+        body: &crate::types::PostTemplatesRequest,
+    ) -> Result<crate::types::TransactionalTemplateAllOf> {
+        TransactionalTemplates::post_template(self, body).await
+    }
+
+    async fn get_templates_template(
+        &self,
+        template_id: &str,
+    ) -> Result<crate::types::TransactionalTemplateAllOf> {
+        TransactionalTemplates::get_templates_template(self, template_id).await
+    }
+
+    async fn post_templates_template(
+        &self,
+        template_id: &str,
+        body: &crate::types::PostTemplatesTemplateRequest,
+    ) -> Result<crate::types::TransactionalTemplateAllOf> {
+        TransactionalTemplates::post_templates_template(self, template_id, body).await
+    }
+
+    async fn delete_templates_template(
+        &self,
+        template_id: &str,
+    ) -> Result<crate::types::DeleteTemplatesTemplateResponse> {
+        TransactionalTemplates::delete_templates_template(self, template_id).await
+    }
+
+    async fn patch_templates_template(
+        &self,
+        template_id: &str,
+        body: &crate::types::PatchTemplatesTemplateRequest,
+    ) -> Result<crate::types::TransactionalTemplateAllOf> {
+        TransactionalTemplates::patch_templates_template(self, template_id, body).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TransactionalTemplatesVersions {
     pub client: Client,
@@ -141,3 +139,111 @@ impl TransactionalTemplatesVersions {
             .await
     }
 }
+
+/// The functions of [`TransactionalTemplatesVersions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTransactionalTemplatesVersionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TransactionalTemplatesVersionsApi: Send + Sync {
+    /// See [`TransactionalTemplatesVersions::post_templates_template_version`].
+    async fn post_templates_template_version(
+        &self,
+        template_id: &str,
+        body: &crate::types::TransactionalTemplateVersionCreate,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf>;
+
+    /// See [`TransactionalTemplatesVersions::post_templates_template_versions_version_activate`].
+    async fn post_templates_template_versions_version_activate(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf>;
+
+    /// See [`TransactionalTemplatesVersions::get_templates_template_versions_version`].
+    async fn get_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf>;
+
+    /// See [`TransactionalTemplatesVersions::delete_templates_template_versions_version`].
+    async fn delete_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<()>;
+
+    /// See [`TransactionalTemplatesVersions::patch_templates_template_versions_version`].
+    async fn patch_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+        body: &crate::types::TransactionalTemplateVersionCreate,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf>;
+}
+
+#[async_trait::async_trait]
+impl TransactionalTemplatesVersionsApi for TransactionalTemplatesVersions {
+    async fn post_templates_template_version(
+        &self,
+        template_id: &str,
+        body: &crate::types::TransactionalTemplateVersionCreate,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        TransactionalTemplatesVersions::post_templates_template_version(self, template_id, body)
+            .await
+    }
+
+    async fn post_templates_template_versions_version_activate(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        TransactionalTemplatesVersions::post_templates_template_versions_version_activate(
+            self,
+            template_id,
+            version_id,
+        )
+        .await
+    }
+
+    async fn get_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        TransactionalTemplatesVersions::get_templates_template_versions_version(
+            self,
+            template_id,
+            version_id,
+        )
+        .await
+    }
+
+    async fn delete_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+    ) -> Result<()> {
+        TransactionalTemplatesVersions::delete_templates_template_versions_version(
+            self,
+            template_id,
+            version_id,
+        )
+        .await
+    }
+
+    async fn patch_templates_template_versions_version(
+        &self,
+        template_id: &str,
+        version_id: &str,
+        body: &crate::types::TransactionalTemplateVersionCreate,
+    ) -> Result<crate::types::TransactionalTemplateVersionOutputAllOf> {
+        TransactionalTemplatesVersions::patch_templates_template_versions_version(
+            self,
+            template_id,
+            version_id,
+            body,
+        )
+        .await
+    }
+}
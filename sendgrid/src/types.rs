@@ -2,21 +2,391 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> std::option::Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> std::convert::From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match std::option::Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        std::option::Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        std::option::Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
+
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+
+#[cfg(feature = "alerts")]
+mod alerts;
+#[cfg(feature = "alerts")]
+pub use alerts::*;
+
+#[cfg(feature = "api_key_permissions")]
+mod api_key_permissions;
+#[cfg(feature = "api_key_permissions")]
+pub use api_key_permissions::*;
+
+#[cfg(feature = "api_keys")]
+mod api_keys;
+#[cfg(feature = "api_keys")]
+pub use api_keys::*;
+
+#[cfg(feature = "blocks_api")]
+mod blocks_api;
+#[cfg(feature = "blocks_api")]
+pub use blocks_api::*;
+
+#[cfg(feature = "bounces_api")]
+mod bounces_api;
+#[cfg(feature = "bounces_api")]
+pub use bounces_api::*;
+
+#[cfg(feature = "campaigns_api")]
+mod campaigns_api;
+#[cfg(feature = "campaigns_api")]
+pub use campaigns_api::*;
+
+#[cfg(feature = "cancel_scheduled_sends")]
+mod cancel_scheduled_sends;
+#[cfg(feature = "cancel_scheduled_sends")]
+pub use cancel_scheduled_sends::*;
+
+#[cfg(feature = "categories")]
+mod categories;
+#[cfg(feature = "categories")]
+pub use categories::*;
+
+#[cfg(feature = "certificates")]
+mod certificates;
+#[cfg(feature = "certificates")]
+pub use certificates::*;
+
+#[cfg(feature = "contacts")]
+mod contacts;
+#[cfg(feature = "contacts")]
+pub use contacts::*;
+
+#[cfg(feature = "contacts_api_custom_fields")]
+mod contacts_api_custom_fields;
+#[cfg(feature = "contacts_api_custom_fields")]
+pub use contacts_api_custom_fields::*;
+
+#[cfg(feature = "contacts_api_lists")]
+mod contacts_api_lists;
+#[cfg(feature = "contacts_api_lists")]
+pub use contacts_api_lists::*;
+
+#[cfg(feature = "contacts_api_recipients")]
+mod contacts_api_recipients;
+#[cfg(feature = "contacts_api_recipients")]
+pub use contacts_api_recipients::*;
+
+#[cfg(feature = "contacts_api_segments")]
+mod contacts_api_segments;
+#[cfg(feature = "contacts_api_segments")]
+pub use contacts_api_segments::*;
+
+#[cfg(feature = "csv_ui_only")]
+mod csv_ui_only;
+#[cfg(feature = "csv_ui_only")]
+pub use csv_ui_only::*;
+
+#[cfg(feature = "custom_fields")]
+mod custom_fields;
+#[cfg(feature = "custom_fields")]
+pub use custom_fields::*;
+
+#[cfg(feature = "designs_api")]
+mod designs_api;
+#[cfg(feature = "designs_api")]
+pub use designs_api::*;
+
+#[cfg(feature = "domain_authentication")]
+mod domain_authentication;
+#[cfg(feature = "domain_authentication")]
+pub use domain_authentication::*;
+
+#[cfg(feature = "email_address_validation")]
+mod email_address_validation;
+#[cfg(feature = "email_address_validation")]
+pub use email_address_validation::*;
+
+#[cfg(feature = "email_cname_records")]
+mod email_cname_records;
+#[cfg(feature = "email_cname_records")]
+pub use email_cname_records::*;
+
+#[cfg(feature = "invalid_emails_api")]
+mod invalid_emails_api;
+#[cfg(feature = "invalid_emails_api")]
+pub use invalid_emails_api::*;
+
+#[cfg(feature = "ip_access_management")]
+mod ip_access_management;
+#[cfg(feature = "ip_access_management")]
+pub use ip_access_management::*;
+
+#[cfg(feature = "ip_addresses")]
+mod ip_addresses;
+#[cfg(feature = "ip_addresses")]
+pub use ip_addresses::*;
+
+#[cfg(feature = "ip_pools")]
+mod ip_pools;
+#[cfg(feature = "ip_pools")]
+pub use ip_pools::*;
+
+#[cfg(feature = "ip_warmup")]
+mod ip_warmup;
+#[cfg(feature = "ip_warmup")]
+pub use ip_warmup::*;
+
+#[cfg(feature = "link_branding")]
+mod link_branding;
+#[cfg(feature = "link_branding")]
+pub use link_branding::*;
+
+#[cfg(feature = "lists")]
+mod lists;
+#[cfg(feature = "lists")]
+pub use lists::*;
+
+#[cfg(feature = "mail_send")]
+mod mail_send;
+#[cfg(feature = "mail_send")]
+pub use mail_send::*;
+
+#[cfg(feature = "marketing_campaigns_stats")]
+mod marketing_campaigns_stats;
+#[cfg(feature = "marketing_campaigns_stats")]
+pub use marketing_campaigns_stats::*;
+
+#[cfg(feature = "query")]
+mod query;
+#[cfg(feature = "query")]
+pub use query::*;
+
+#[cfg(feature = "reverse_dns")]
+mod reverse_dns;
+#[cfg(feature = "reverse_dns")]
+pub use reverse_dns::*;
+
+#[cfg(feature = "segmenting_contacts")]
+mod segmenting_contacts;
+#[cfg(feature = "segmenting_contacts")]
+pub use segmenting_contacts::*;
+
+#[cfg(feature = "send_test_email")]
+mod send_test_email;
+#[cfg(feature = "send_test_email")]
+pub use send_test_email::*;
+
+#[cfg(feature = "sender_identities_api")]
+mod sender_identities_api;
+#[cfg(feature = "sender_identities_api")]
+pub use sender_identities_api::*;
+
+#[cfg(feature = "sender_verification")]
+mod sender_verification;
+#[cfg(feature = "sender_verification")]
+pub use sender_verification::*;
+
+#[cfg(feature = "senders")]
+mod senders;
+#[cfg(feature = "senders")]
+pub use senders::*;
+
+#[cfg(feature = "settings_enforced_tls")]
+mod settings_enforced_tls;
+#[cfg(feature = "settings_enforced_tls")]
+pub use settings_enforced_tls::*;
+
+#[cfg(feature = "settings_inbound_parse")]
+mod settings_inbound_parse;
+#[cfg(feature = "settings_inbound_parse")]
+pub use settings_inbound_parse::*;
+
+#[cfg(feature = "settings_mail")]
+mod settings_mail;
+#[cfg(feature = "settings_mail")]
+pub use settings_mail::*;
+
+#[cfg(feature = "settings_partner")]
+mod settings_partner;
+#[cfg(feature = "settings_partner")]
+pub use settings_partner::*;
+
+#[cfg(feature = "settings_tracking")]
+mod settings_tracking;
+#[cfg(feature = "settings_tracking")]
+pub use settings_tracking::*;
+
+#[cfg(feature = "single_sends")]
+mod single_sends;
+#[cfg(feature = "single_sends")]
+pub use single_sends::*;
+
+#[cfg(feature = "single_sign_on_settings")]
+mod single_sign_on_settings;
+#[cfg(feature = "single_sign_on_settings")]
+pub use single_sign_on_settings::*;
+
+#[cfg(feature = "single_sign_on_teammates")]
+mod single_sign_on_teammates;
+#[cfg(feature = "single_sign_on_teammates")]
+pub use single_sign_on_teammates::*;
+
+#[cfg(feature = "spam_reports_api")]
+mod spam_reports_api;
+#[cfg(feature = "spam_reports_api")]
+pub use spam_reports_api::*;
+
+#[cfg(feature = "stats")]
+mod stats;
+#[cfg(feature = "stats")]
+pub use stats::*;
+
+#[cfg(feature = "subuser_monitor_settings")]
+mod subuser_monitor_settings;
+#[cfg(feature = "subuser_monitor_settings")]
+pub use subuser_monitor_settings::*;
+
+#[cfg(feature = "subuser_statistics")]
+mod subuser_statistics;
+#[cfg(feature = "subuser_statistics")]
+pub use subuser_statistics::*;
+
+#[cfg(feature = "subusers_api")]
+mod subusers_api;
+#[cfg(feature = "subusers_api")]
+pub use subusers_api::*;
+
+#[cfg(feature = "suppressions")]
+mod suppressions;
+#[cfg(feature = "suppressions")]
+pub use suppressions::*;
+
+#[cfg(feature = "suppressions_global")]
+mod suppressions_global;
+#[cfg(feature = "suppressions_global")]
+pub use suppressions_global::*;
+
+#[cfg(feature = "suppressions_unsubscribe_groups")]
+mod suppressions_unsubscribe_groups;
+#[cfg(feature = "suppressions_unsubscribe_groups")]
+pub use suppressions_unsubscribe_groups::*;
+
+#[cfg(feature = "teammates")]
+mod teammates;
+#[cfg(feature = "teammates")]
+pub use teammates::*;
+
+#[cfg(feature = "transactional_templates")]
+mod transactional_templates;
+#[cfg(feature = "transactional_templates")]
+pub use transactional_templates::*;
+
+#[cfg(feature = "transactional_templates_versions")]
+mod transactional_templates_versions;
+#[cfg(feature = "transactional_templates_versions")]
+pub use transactional_templates_versions::*;
+
+#[cfg(feature = "users_api")]
+mod users_api;
+#[cfg(feature = "users_api")]
+pub use users_api::*;
+
+#[cfg(feature = "webhooks")]
+mod webhooks;
+#[cfg(feature = "webhooks")]
+pub use webhooks::*;
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PartnerSettingsNewRelic {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enable_subuser_statistics: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enabled: bool,
+pub struct Errors {
     /**
      * The license key provided with your New Relic account.
      */
@@ -25,59 +395,16 @@ pub struct PartnerSettingsNewRelic {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub license_key: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SubscriptionTrackingSettings {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enabled: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub html_content: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub landing: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub plain_content: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub replace: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub url: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Errors {
+    pub field: String,
     /**
-     * The indices of the recipient(s) sent that caused the error.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub error_indices: Vec<f64>,
+    pub message: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -86,69 +413,20 @@ pub struct Errors {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub message: String,
+    pub parameter: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactDbRecipientResponse {
+pub struct ContactdbRecipientResponseErrors {
     /**
-     * The number of errors found while adding recipients.
+     * The indices of the recipient(s) sent that caused the error.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub error_count: f64,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub error_indices: Vec<f64>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<Errors>,
-    /**
-     * The number of errors found while adding recipients.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub new_count: f64,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub persisted_recipients: Vec<String>,
-    /**
-     * The number of errors found while adding recipients.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub updated_count: f64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CampaignResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
     /**
      * The license key provided with your New Relic account.
      */
@@ -157,22 +435,11 @@ pub struct CampaignResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub status: String,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `CampaignsRequest`
-/// - `CampaignResponse`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CampaignResponseAllOf {
-    #[serde(flatten)]
-    pub campaigns_request: CampaignsRequest,
-    #[serde(flatten)]
-    pub campaign_response: CampaignResponse,
+    pub message: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Operator {
     #[serde(rename = "contains")]
     Contains,
@@ -184,70 +451,120 @@ pub enum Operator {
     Lt,
     #[serde(rename = "ne")]
     Ne,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Operator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Operator {
+    fn as_ref(&self) -> &str {
+        match self {
             Operator::Contains => "contains",
             Operator::Eq => "eq",
             Operator::Gt => "gt",
             Operator::Lt => "lt",
             Operator::Ne => "ne",
-            Operator::Noop => "",
-            Operator::FallthroughString => "*",
+            Operator::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Operator {
-    fn default() -> Operator {
-        Operator::Noop
+impl std::fmt::Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Operator {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "contains" => Operator::Contains,
+            "eq" => Operator::Eq,
+            "gt" => Operator::Gt,
+            "lt" => Operator::Lt,
+            "ne" => Operator::Ne,
+            _ => Operator::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Operator {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Operator {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Operator::Noop)
+
+impl std::convert::From<Operator> for String {
+    fn from(v: Operator) -> Self {
+        match v {
+            Operator::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AndOr {
     #[serde(rename = "and")]
     And,
     #[serde(rename = "or")]
     Or,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AndOr {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AndOr {
+    fn as_ref(&self) -> &str {
+        match self {
             AndOr::And => "and",
             AndOr::Or => "or",
-            AndOr::Noop => "",
-            AndOr::FallthroughString => "*",
+            AndOr::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AndOr {
-    fn default() -> AndOr {
-        AndOr::Noop
+impl std::fmt::Display for AndOr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AndOr {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "and" => AndOr::And,
+            "or" => AndOr::Or,
+            _ => AndOr::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AndOr {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AndOr {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AndOr::Noop)
+
+impl std::convert::From<AndOr> for String {
+    fn from(v: AndOr) -> Self {
+        match v {
+            AndOr::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -260,54 +577,23 @@ pub struct ContactdbSegmentsConditions {
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub field: String,
-    #[serde(default, skip_serializing_if = "Operator::is_noop")]
-    pub operator: Operator,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operator: Option<Operator>,
     /**
      * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub value: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BounceResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub created: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub status: String,
-}
-
-/// helper text or docs for troubleshooting
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Help {}
+pub struct Address2 {}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct Contacts {
@@ -318,7 +604,7 @@ pub struct Contacts {
     )]
     pub address: String,
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "address2")]
-    pub address_2: Option<Help>,
+    pub address_2: Option<Address2>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
@@ -375,28 +661,153 @@ pub struct Contacts {
     pub zip: String,
 }
 
+/**
+ * The type of the field.
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Users {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub user_id: i64,
+#[serde(from = "String", into = "String")]
+pub enum Type {
+    #[serde(rename = "date")]
+    Date,
+    #[serde(rename = "number")]
+    Number,
+    #[serde(rename = "text")]
+    Text,
     /**
-     * The license key provided with your New Relic account.
+     * A value that is not in the API spec, as it was sent.
      */
+    Unknown(String),
+}
+
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        match self {
+            Type::Date => "date",
+            Type::Number => "number",
+            Type::Text => "text",
+            Type::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "date" => Type::Date,
+            "number" => Type::Number,
+            "text" => Type::Text,
+            _ => Type::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(v: Type) -> Self {
+        match v {
+            Type::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContactdbCustomField {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub username: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<Type>,
 }
 
-/// The DKIM record for messages sent using this authenticated domain.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Dkim {
+/**
+ * The type of DNS record generated.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum LinkBranding200ResponseDnsOwnerCnameType {
+    #[serde(rename = "cname")]
+    Cname,
+    #[serde(rename = "mx")]
+    Mx,
+    #[serde(rename = "txt")]
+    Txt,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for LinkBranding200ResponseDnsOwnerCnameType {
+    fn as_ref(&self) -> &str {
+        match self {
+            LinkBranding200ResponseDnsOwnerCnameType::Cname => "cname",
+            LinkBranding200ResponseDnsOwnerCnameType::Mx => "mx",
+            LinkBranding200ResponseDnsOwnerCnameType::Txt => "txt",
+            LinkBranding200ResponseDnsOwnerCnameType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for LinkBranding200ResponseDnsOwnerCnameType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LinkBranding200ResponseDnsOwnerCnameType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cname" => LinkBranding200ResponseDnsOwnerCnameType::Cname,
+            "mx" => LinkBranding200ResponseDnsOwnerCnameType::Mx,
+            "txt" => LinkBranding200ResponseDnsOwnerCnameType::Txt,
+            _ => LinkBranding200ResponseDnsOwnerCnameType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LinkBranding200ResponseDnsOwnerCnameType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<LinkBranding200ResponseDnsOwnerCnameType> for String {
+    fn from(v: LinkBranding200ResponseDnsOwnerCnameType) -> Self {
+        match v {
+            LinkBranding200ResponseDnsOwnerCnameType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// The DNS record generated to verify who created the link branding.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OwnerCname {
     /**
      * The license key provided with your New Relic account.
      */
@@ -416,15 +827,10 @@ pub struct Dkim {
     )]
     pub host: String,
     /**
-     * The license key provided with your New Relic account.
+     * The DNS record generated to verify who created the link branding.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<LinkBranding200ResponseDnsOwnerCnameType>,
     /**
      * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
      */
@@ -435,51 +841,59 @@ pub struct Dkim {
     pub valid: bool,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReverseDns {
-    /**
-     * The DKIM record for messages sent using this authenticated domain.
-     */
-    #[serde()]
-    pub a_record: Dkim,
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct FromEmailObject {
     /**
      * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub domain: String,
+    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The individual events and their stats.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdvancedStatsOpens {
+    /**
+     * The individual events and their stats.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub id: i64,
+    pub opens: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The individual events and their stats.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub last_validation_attempt_at: i64,
+    pub unique_opens: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Help {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GlobalErrorResponseSchemaErrors {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub legacy: bool,
+    pub field: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help: Option<Help>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -488,377 +902,300 @@ pub struct ReverseDns {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub rdns: String,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GlobalErrorResponseSchema {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub errors: Vec<GlobalErrorResponseSchemaErrors>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub subdomain: String,
+    pub id: String,
+}
+
+/// The individual events and their stats.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdvancedStatsMailboxProvider {
     /**
-     * The users who are able to send mail from the IP address.
+     * The individual events and their stats.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub users: Vec<Users>,
+    pub blocks: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * The individual events and their stats.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub valid: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SenderId {
+    pub bounces: i64,
+    /**
+     * The individual events and their stats.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub created_at: i64,
+    pub deferred: i64,
+    /**
+     * The individual events and their stats.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub id: i64,
+    pub delivered: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * The individual events and their stats.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub locked: bool,
+    pub drops: i64,
+    /**
+     * The individual events and their stats.
+     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub updated_at: i64,
+    pub processed: i64,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * The individual events and their stats.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub requests: i64,
+    /**
+     * The individual events and their stats.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub verified: bool,
+    pub spam_reports: i64,
 }
 
 /// All of the following types are flattened into one object:
 ///
-/// - `Help`
-/// - `SenderRequest`
-/// - `SenderId`
+/// - `AdvancedStatsClicksOpensAllOf`
+/// - `AdvancedStatsMailboxProvider`
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SenderAllOf {
+pub struct AdvancedStatsMailboxProviderAllOf {
+    #[serde(flatten)]
+    pub advanced_stats_clicks_opens_all_of: AdvancedStatsClicksOpensAllOf,
     /**
-     * helper text or docs for troubleshooting
+     * The individual events and their stats.
      */
     #[serde(flatten)]
-    pub help: Help,
-    #[serde(flatten)]
-    pub sender_request: SenderRequest,
-    #[serde(flatten)]
-    pub sender_id: SenderId,
+    pub advanced_stats_mailbox_provider: AdvancedStatsMailboxProvider,
 }
 
-/**
- * The type of the field.
- */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Type {
-    #[serde(rename = "date")]
-    Date,
-    #[serde(rename = "number")]
-    Number,
-    #[serde(rename = "text")]
-    Text,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Type {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Type::Date => "date",
-            Type::Number => "number",
-            Type::Text => "text",
-            Type::Noop => "",
-            Type::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
+pub struct ContactdbCustomFieldWithId {
+    /**
+     * The number of errors found while adding recipients.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub id: f64,
 }
 
-impl Default for Type {
-    fn default() -> Type {
-        Type::Noop
-    }
-}
-impl Type {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Type::Noop)
-    }
+/// All of the following types are flattened into one object:
+///
+/// - `ContactdbCustomField`
+/// - `ContactdbCustomFieldWithId`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContactdbCustomFieldWithAllOf {
+    #[serde(flatten)]
+    pub contactdb_custom_field: ContactdbCustomField,
+    #[serde(flatten)]
+    pub contactdb_custom_field_with_id: ContactdbCustomFieldWithId,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactdbCustomField {
+pub struct IpPool {
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<Type>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Subuser {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
+pub struct CategoryStatsMetrics {
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub disabled: bool,
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub blocks: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub email: String,
-    /**
-     * The number of errors found while adding recipients.
-     */
+    pub bounce_drops: i64,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub id: f64,
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub bounces: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub username: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MailSettingsAddressWhitelabel {
+    pub clicks: i64,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enabled: bool,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub deferred: i64,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub list: Vec<String>,
-}
-
-/**
- * The type of DNS record that was generated.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum LinkBranding200ResponseDnsDomainCnameType {
-    #[serde(rename = "cname")]
-    Cname,
-    #[serde(rename = "mx")]
-    Mx,
-    #[serde(rename = "txt")]
-    Txt,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for LinkBranding200ResponseDnsDomainCnameType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            LinkBranding200ResponseDnsDomainCnameType::Cname => "cname",
-            LinkBranding200ResponseDnsDomainCnameType::Mx => "mx",
-            LinkBranding200ResponseDnsDomainCnameType::Txt => "txt",
-            LinkBranding200ResponseDnsDomainCnameType::Noop => "",
-            LinkBranding200ResponseDnsDomainCnameType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for LinkBranding200ResponseDnsDomainCnameType {
-    fn default() -> LinkBranding200ResponseDnsDomainCnameType {
-        LinkBranding200ResponseDnsDomainCnameType::Noop
-    }
-}
-impl LinkBranding200ResponseDnsDomainCnameType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LinkBranding200ResponseDnsDomainCnameType::Noop)
-    }
-}
-
-/// The DNS record generated to point to your link branding subdomain.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DomainCname {
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub delivered: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub data: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub invalid_emails: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub host: String,
-    /**
-     * The type of DNS record that was generated.
-     */
+    pub opens: i64,
     #[serde(
         default,
-        skip_serializing_if = "LinkBranding200ResponseDnsDomainCnameType::is_noop",
-        rename = "type"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub type_: LinkBranding200ResponseDnsDomainCnameType,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
+    pub processed: i64,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub valid: bool,
-}
-
-/// The DNS record generated to verify who created the link branding.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OwnerCname {
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub requests: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub data: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub spam_report_drops: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub host: String,
-    /**
-     * The DNS record generated to verify who created the link branding.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<LinkBranding200ResponseDnsDomainCnameType>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
+    pub spam_reports: i64,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub valid: bool,
-}
-
-/// The DNS records generated for this link branding.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Dns {
-    /**
-     * The DNS record generated to point to your link branding subdomain.
-     */
-    #[serde()]
-    pub domain_cname: DomainCname,
-    /**
-     * The DNS records generated for this link branding.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub owner_cname: Option<OwnerCname>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LinkBranding200Response {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
+    pub unique_clicks: i64,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub default: bool,
-    /**
-     * The DNS records generated for this link branding.
-     */
-    #[serde()]
-    pub dns: Dns,
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub unique_opens: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub domain: String,
+    pub unsubscribe_drops: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub id: i64,
+    pub unsubscribes: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Stats {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<CategoryStatsMetrics>,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub legacy: bool,
+    pub name: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
     )]
-    pub subdomain: String,
+    pub type_: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ParseSetting {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub send_raw: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub user_id: i64,
+    pub spam_check: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Permissions {
     /**
      * The license key provided with your New Relic account.
      */
@@ -867,19 +1204,16 @@ pub struct LinkBranding200Response {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub username: String,
+    pub api: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub valid: bool,
-}
-
-#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FromEmailObject {
+    pub mail: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -888,149 +1222,191 @@ pub struct FromEmailObject {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub email: String,
+    pub web: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Credentials {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Permissions>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub username: String,
 }
 
+/**
+ * The editor — `"design"` or `"code"` — used to modify the Single Send's design in the Marketing Campaigns App.
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApiKeyNameScopes {
+#[serde(from = "String", into = "String")]
+pub enum Editor {
+    #[serde(rename = "code")]
+    Code,
+    #[serde(rename = "design")]
+    Design,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
+    Unknown(String),
 }
 
-/// All of the following types are flattened into one object:
-///
-/// - `ApiKeyNameScopes`
-/// - `ApiKeyNameId`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApiKeyNameScopesAllOf {
-    #[serde(flatten)]
-    pub api_key_name_scopes: ApiKeyNameScopes,
-    #[serde(flatten)]
-    pub api_key_name_id: ApiKeyNameId,
+impl AsRef<str> for Editor {
+    fn as_ref(&self) -> &str {
+        match self {
+            Editor::Code => "code",
+            Editor::Design => "design",
+            Editor::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Editor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Editor {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "code" => Editor::Code,
+            "design" => Editor::Design,
+            _ => Editor::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Editor {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Editor> for String {
+    fn from(v: Editor) -> Self {
+        match v {
+            Editor::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for Editor {
+    fn default() -> Editor {
+        Editor::Code
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactdbSegments {
-    /**
-     * The conditions for a recipient to be included in this segment.
-     */
+pub struct SubuserStatsMetrics {
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub conditions: Vec<ContactdbSegmentsConditions>,
+    pub blocks: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub list_id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub bounce_drops: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub name: String,
+    pub bounces: i64,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub recipient_count: f64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApiKeyNameId {
+    pub clicks: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub api_key_id: String,
+    pub deferred: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub name: String,
-}
-
-/// The individual events and their stats.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AdvancedStatsOpens {
-    /**
-     * The individual events and their stats.
-     */
+    pub delivered: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub invalid_emails: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub opens: i64,
-    /**
-     * The individual events and their stats.
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub unique_opens: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MailSettingsTemplate {
+    pub processed: i64,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub enabled: bool,
+    pub requests: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub html_content: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IpWarmupResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub spam_report_drops: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub ip: String,
+    pub spam_reports: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub unique_clicks: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub unique_opens: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub unsubscribe_drops: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub start_date: i64,
+    pub unsubscribes: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Monitor {
+pub struct SubuserStats {
     /**
      * The license key provided with your New Relic account.
      */
@@ -1039,20 +1415,18 @@ pub struct Monitor {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub email: String,
+    pub first_name: String,
     /**
-     * The number of errors found while adding recipients.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub frequency: f64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GlobalErrorResponseSchemaErrors {
+    pub last_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<SubuserStatsMetrics>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -1061,42 +1435,40 @@ pub struct GlobalErrorResponseSchemaErrors {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub field: String,
-    /**
-     * helper text or docs for troubleshooting
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub help: Option<Help>,
+    pub name: String,
     /**
      * The license key provided with your New Relic account.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
     )]
-    pub message: String,
+    pub type_: String,
 }
 
+/// All of the following types are flattened into one object:
+///
+/// - `AdvancedStatsOpens`
+/// - `AdvancedStatsClicks`
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GlobalErrorResponseSchema {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<GlobalErrorResponseSchemaErrors>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
+pub struct AdvancedStatsClicksOpensAllOf {
+    /**
+     * The individual events and their stats.
+     */
+    #[serde(flatten)]
+    pub advanced_stats_opens: AdvancedStatsOpens,
+    /**
+     * The individual events and their stats.
+     */
+    #[serde(flatten)]
+    pub advanced_stats_clicks: AdvancedStatsClicks,
 }
 
 /// The individual events and their stats.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AdvancedStatsMailboxProvider {
+pub struct AdvancedStatsClicks {
     /**
      * The individual events and their stats.
      */
@@ -1105,7 +1477,7 @@ pub struct AdvancedStatsMailboxProvider {
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub blocks: i64,
+    pub clicks: i64,
     /**
      * The individual events and their stats.
      */
@@ -1114,80 +1486,65 @@ pub struct AdvancedStatsMailboxProvider {
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub bounces: i64,
+    pub unique_clicks: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Recipients {
     /**
-     * The individual events and their stats.
+     * The number of errors found while adding recipients.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub deferred: i64,
+    pub created_at: f64,
     /**
-     * The individual events and their stats.
+     * The custom fields assigned to this recipient and their values.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub delivered: i64,
+    pub custom_fields: Vec<ContactdbCustomFieldWithValueAllOf>,
     /**
-     * The individual events and their stats.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub drops: i64,
+    pub email: String,
     /**
-     * The individual events and their stats.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub processed: i64,
+    pub first_name: String,
     /**
-     * The individual events and their stats.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub requests: i64,
+    pub id: String,
     /**
-     * The individual events and their stats.
+     * The number of errors found while adding recipients.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub spam_reports: i64,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `AdvancedStatsClicksOpensAllOf`
-/// - `AdvancedStatsMailboxProvider`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AdvancedStatsMailboxProviderAllOf {
-    #[serde(flatten)]
-    pub advanced_stats_clicks_opens_all_of: AdvancedStatsClicksOpensAllOf,
-    /**
-     * The individual events and their stats.
-     */
-    #[serde(flatten)]
-    pub advanced_stats_mailbox_provider: AdvancedStatsMailboxProvider,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactdbCustomFieldWithId {
+    pub last_clicked: f64,
     /**
      * The number of errors found while adding recipients.
      */
@@ -1196,23 +1553,7 @@ pub struct ContactdbCustomFieldWithId {
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub id: f64,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `ContactdbCustomField`
-/// - `ContactdbCustomFieldWithId`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactdbCustomFieldWithAllOf {
-    #[serde(flatten)]
-    pub contactdb_custom_field: ContactdbCustomField,
-    #[serde(flatten)]
-    pub contactdb_custom_field_with_id: ContactdbCustomFieldWithId,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IpPool {
+    pub last_emailed: f64,
     /**
      * The license key provided with your New Relic account.
      */
@@ -1221,374 +1562,426 @@ pub struct IpPool {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GoogleAnalyticsSettings {
+    pub last_name: String,
+    /**
+     * The number of errors found while adding recipients.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub enabled: bool,
+    pub last_opened: f64,
+    /**
+     * The number of errors found while adding recipients.
+     */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub utm_campaign: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub utm_content: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub utm_medium: String,
+    pub updated_at: f64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MailSettingsPatch {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub utm_source: String,
+    pub email: String,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub utm_term: String,
+    pub enabled: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct WebhooksEventWebhookResponse {
+pub struct CreditAllocation {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
     )]
-    pub bounce: bool,
+    pub type_: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContactdbCustomFieldWithValue {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub click: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
+    pub value: String,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `ContactdbCustomFieldWithAllOf`
+/// - `ContactdbCustomFieldWithValue`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContactdbCustomFieldWithValueAllOf {
+    #[serde(flatten)]
+    pub contactdb_custom_field_with_all_of: ContactdbCustomFieldWithAllOf,
+    #[serde(flatten)]
+    pub contactdb_custom_field_with_value: ContactdbCustomFieldWithValue,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TransactionalTemplatesVersionOutputLean {
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub deferred: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
+    pub active: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor: Option<Editor>,
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub delivered: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
+    pub generate_plain_content: bool,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub dropped: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
+    pub id: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub enabled: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
+    pub name: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub group_resubscribe: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
+    pub subject: String,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub group_unsubscribe: bool,
+    pub template_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub oauth_client_id: String,
+    pub thumbnail_url: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub oauth_token_url: String,
+    pub updated_at: String,
+}
+
+/**
+ * Defines whether the template supports dynamic replacement.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Generation {
+    #[serde(rename = "dynamic")]
+    Dynamic,
+    #[serde(rename = "legacy")]
+    Legacy,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub open: bool,
+    Unknown(String),
+}
+
+impl AsRef<str> for Generation {
+    fn as_ref(&self) -> &str {
+        match self {
+            Generation::Dynamic => "dynamic",
+            Generation::Legacy => "legacy",
+            Generation::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Generation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Generation {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "dynamic" => Generation::Dynamic,
+            "legacy" => Generation::Legacy,
+            _ => Generation::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Generation {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Generation> for String {
+    fn from(v: Generation) -> Self {
+        match v {
+            Generation::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for Generation {
+    fn default() -> Generation {
+        Generation::Legacy
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TransactionalTemplatesTemplateLean {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * Defines whether the template supports dynamic replacement.
      */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub processed: bool,
+    #[serde(default)]
+    pub generation: Generation,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub spam_report: bool,
+    pub id: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub unsubscribe: bool,
+    pub name: String,
     /**
      * The license key provided with your New Relic account.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "updated_at "
     )]
-    pub url: String,
+    pub updated_at: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub versions: Vec<TransactionalTemplatesVersionOutputLean>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserProfile {
+pub struct CustomFieldsById {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CustomFieldsByName {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContactDetails {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
+    pub metadata: Option<Selfmetadata>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub address: String,
+    pub address_line_1: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address2"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub address_2: String,
+    pub address_line_2: String,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub city: String,
+    pub alternate_emails: Vec<String>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub company: String,
+    pub city: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub first_name: String,
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<CustomFieldsByName>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_name: String,
+    pub email: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub phone: String,
+    pub first_name: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub state: String,
+    pub id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub website: String,
+    pub last_name: String,
+    /**
+     * The recipient IDs of the recipients that already existed from this request.
+     */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub zip: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MailSettingsFooter {
+    pub list_ids: Vec<String>,
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub enabled: bool,
+    pub postal_code: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub html_content: String,
+    pub state_province_region: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub plain_content: String,
+    pub updated_at: String,
 }
 
+/// Result map of the import job.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Metrics {
+pub struct Results {
+    /**
+     * Result map of the import job.
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub blocks: i64,
+    pub created_count: f64,
+    /**
+     * Result map of the import job.
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub bounce_drops: i64,
+    pub deleted_count: f64,
+    /**
+     * Result map of the import job.
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub bounces: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub clicks: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub deferred: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub delivered: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub invalid_emails: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub opens: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub processed: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub requests: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub spam_report_drops: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub spam_reports: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub unique_clicks: i64,
+    pub errored_count: f64,
+    /**
+     * Result map of the import job.
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub unique_opens: i64,
+    pub errors_url: String,
+    /**
+     * Result map of the import job.
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub unsubscribe_drops: i64,
+    pub requested_count: f64,
+    /**
+     * Result map of the import job.
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub unsubscribes: i64,
+    pub updated_count: f64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Stats {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metrics: Option<Metrics>,
+pub struct CustomFields {
     /**
      * The license key provided with your New Relic account.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "custom_field_name1"
     )]
-    pub name: String,
+    pub custom_field_name_1: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -1596,13 +1989,13 @@ pub struct Stats {
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
+        rename = "custom_field_name2"
     )]
-    pub type_: String,
+    pub custom_field_name_2: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CategoryStats {
+pub struct Contact {
     /**
      * The license key provided with your New Relic account.
      */
@@ -1611,67 +2004,45 @@ pub struct CategoryStats {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub date: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub stats: Vec<Stats>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ParseSetting {
+    pub address_line_1: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub hostname: String,
+    pub address_line_2: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub send_raw: bool,
+    pub alternate_emails: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub spam_check: bool,
+    pub city: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub url: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TransactionalTemplate {
+    pub country: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub warning: Option<Warning>,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `TransactionalTemplatesTemplateLean`
-/// - `TransactionalTemplate`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TransactionalTemplateAllOf {
-    #[serde(flatten)]
-    pub transactional_templates_template_lean: TransactionalTemplatesTemplateLean,
-    #[serde(flatten)]
-    pub transactional_template: TransactionalTemplate,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactdbList {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
+    pub custom_fields: Option<CustomFields>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -1680,17 +2051,7 @@ pub struct ContactdbList {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub recipient_count: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SuppressionGroup {
+    pub first_name: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -1699,23 +2060,16 @@ pub struct SuppressionGroup {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub description: String,
+    pub last_name: String,
     /**
-     * The number of errors found while adding recipients.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub id: f64,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub is_default: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub last_email_sent_at: Option<serde_json::Value>,
+    pub postal_code: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -1724,81 +2078,55 @@ pub struct SuppressionGroup {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub unsubscribes: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MailSettingsBouncePurge {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enabled: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub hard_bounces: i64,
+    pub primary_email: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub soft_bounces: i64,
+    pub state_province_region: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TransactionalTemplateVersionOutput {
+pub struct SingleContactRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contact: Option<Contact>,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub warnings: Vec<Warning>,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `TransactionalTemplateVersionOutput`
-/// - `TransactionalTemplateVersionCreate`
-/// - `TransactionalTemplatesVersionOutputLean`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TransactionalTemplateVersionOutputAllOf {
-    #[serde(flatten)]
-    pub transactional_template_version_output: TransactionalTemplateVersionOutput,
-    #[serde(flatten)]
-    pub transactional_template_version_create: TransactionalTemplateVersionCreate,
-    #[serde(flatten)]
-    pub transactional_templates_version_output_lean: TransactionalTemplatesVersionOutputLean,
+    pub list_ids: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Permissions {
+pub struct ContactSummary {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
+    pub metadata: Option<Selfmetadata>,
     /**
-     * The license key provided with your New Relic account.
+     * The number of errors found while adding recipients.
      */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub created_at: f64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub api: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub email: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub mail: String,
+    pub first_name: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -1807,257 +2135,259 @@ pub struct Permissions {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub web: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Credentials {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub permissions: Option<Permissions>,
+    pub id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub username: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MailSettingsForwardSpam {
+    pub last_name: String,
+    /**
+     * The recipient IDs of the recipients that already existed from this request.
+     */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub email: String,
+    pub list_ids: Vec<String>,
+    /**
+     * The number of errors found while adding recipients.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub enabled: bool,
+    pub updated_at: f64,
 }
 
-/**
- * The editor used in the UI.
- */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Editor {
-    #[serde(rename = "code")]
-    Code,
-    #[serde(rename = "design")]
-    Design,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Editor {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Editor::Code => "code",
-            Editor::Design => "design",
-            Editor::Noop => "",
-            Editor::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Editor {
-    fn default() -> Editor {
-        Editor::Noop
-    }
-}
-impl Editor {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Editor::Noop)
-    }
-}
+pub struct ContactDetails2CustomFields {}
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CampaignsRequest {
+pub struct ContactDetails2 {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
+    pub metadata: Option<Selfmetadata>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub address_line_1: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub address_line_2: String,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub categories: Vec<String>,
+    pub alternate_emails: Vec<String>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub custom_unsubscribe_url: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub editor: Option<Editor>,
+    pub city: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub html_content: String,
+    pub country: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<ContactDetails2CustomFields>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ip_pool: String,
-    /**
-     * The IDs of the lists you are sending this campaign to. You can have both segment IDs and list IDs
-     */
+    pub email: String,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub list_ids: Vec<i64>,
+    pub facebook: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub plain_content: String,
+    pub first_name: String,
     /**
-     * The IDs of the lists you are sending this campaign to. You can have both segment IDs and list IDs
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub segment_ids: Vec<i64>,
+    pub id: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub sender_id: i64,
+    pub last_name: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub subject: String,
+    pub line: String,
+    /**
+     * The recipient IDs of the recipients that already existed from this request.
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub suppression_group_id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub list_ids: Vec<String>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub title: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SubuserStatsMetrics {
+    pub phone_number: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub blocks: i64,
+    pub postal_code: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub bounce_drops: i64,
+    pub segment_ids: Vec<String>,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub bounces: i64,
+    pub state_province_region: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub clicks: i64,
+    pub unique_name: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub deferred: i64,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub delivered: i64,
+    pub whatsapp: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Selfmetadata {
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "self"
     )]
-    pub invalid_emails: i64,
+    pub self_: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Error {
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub opens: i64,
+    pub error_id: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub processed: i64,
+    pub field: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub requests: i64,
+    pub message: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub spam_report_drops: i64,
+    pub parameter: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Link {
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub spam_reports: i64,
+    pub href: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub unique_clicks: i64,
+    pub rel: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Metadata {
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub unique_opens: i64,
+    pub count: f64,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub unsubscribe_drops: i64,
+    pub next: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub unsubscribes: i64,
+    pub prev: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "self"
+    )]
+    pub self_: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SubuserStats {
+pub struct Webhook {
     /**
      * The license key provided with your New Relic account.
      */
@@ -2066,7 +2396,7 @@ pub struct SubuserStats {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub first_name: String,
+    pub nonce: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -2075,9 +2405,11 @@ pub struct SubuserStats {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metrics: Option<SubuserStatsMetrics>,
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SegmentWrite {
     /**
      * The license key provided with your New Relic account.
      */
@@ -2093,186 +2425,68 @@ pub struct SubuserStats {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub type_: String,
+    pub query_dsl: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SubuserStatsData {
+pub struct SegmentSummary {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub date: String,
+    pub contacts_count: i64,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub stats: Vec<SubuserStats>,
-}
-
-/**
- * The status of the scheduled send.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Status {
-    #[serde(rename = "cancel")]
-    Cancel,
-    #[serde(rename = "pause")]
-    Pause,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Status {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Status::Cancel => "cancel",
-            Status::Pause => "pause",
-            Status::Noop => "",
-            Status::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Status {
-    fn default() -> Status {
-        Status::Noop
-    }
-}
-impl Status {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Status::Noop)
-    }
-}
-
-/// The status of the scheduled send.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserScheduledSendStatus {
-    /**
-     * The status of the scheduled send.
-     */
-    #[serde(default, skip_serializing_if = "Status::is_noop")]
-    pub status: Status,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `MailBatchId`
-/// - `UserScheduledSendStatus`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UserScheduledSendStatusAllOf {
-    #[serde(flatten)]
-    pub mail_batch_id: MailBatchId,
-    /**
-     * The status of the scheduled send.
-     */
-    #[serde(flatten)]
-    pub user_scheduled_send_status: UserScheduledSendStatus,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `AdvancedStatsOpens`
-/// - `AdvancedStatsClicks`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AdvancedStatsClicksOpensAllOf {
-    /**
-     * The individual events and their stats.
-     */
-    #[serde(flatten)]
-    pub advanced_stats_opens: AdvancedStatsOpens,
-    /**
-     * The individual events and their stats.
-     */
-    #[serde(flatten)]
-    pub advanced_stats_clicks: AdvancedStatsClicks,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactdbSegmentsWithId {
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * The number of errors found while adding recipients.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: f64,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `ContactdbSegments`
-/// - `ContactdbSegmentsWithId`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactdbSegmentsWithAllOf {
-    #[serde(flatten)]
-    pub contactdb_segments: ContactdbSegments,
-    #[serde(flatten)]
-    pub contactdb_segments_with_id: ContactdbSegmentsWithId,
-}
-
-/// The individual events and their stats.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AdvancedStatsClicks {
-    /**
-     * The individual events and their stats.
-     */
+    pub id: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub clicks: i64,
-    /**
-     * The individual events and their stats.
-     */
+    pub name: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub unique_clicks: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Recipients {
-    /**
-     * The number of errors found while adding recipients.
-     */
+    pub next_sample_update: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub created_at: f64,
-    /**
-     * The custom fields assigned to this recipient and their values.
-     */
+    pub parent_list_id: String,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub custom_fields: Vec<ContactdbCustomFieldWithValueAllOf>,
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub sample_updated_at: Option<chrono::DateTime<chrono::Utc>>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub email: String,
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct L {
     /**
      * The license key provided with your New Relic account.
      */
@@ -2281,7 +2495,7 @@ pub struct Recipients {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub first_name: String,
+    pub t: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -2290,25 +2504,35 @@ pub struct Recipients {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub v: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct R {
     /**
-     * The number of errors found while adding recipients.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_clicked: f64,
+    pub t: String,
     /**
-     * The number of errors found while adding recipients.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_emailed: f64,
+    pub v: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SegmentQueryJsonContactsL {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l: Option<L>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -2317,69 +2541,65 @@ pub struct Recipients {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_name: String,
+    pub op: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r: Option<R>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Args {
     /**
-     * The number of errors found while adding recipients.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_opened: f64,
+    pub t: String,
     /**
-     * The number of errors found while adding recipients.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub updated_at: f64,
+    pub v: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactdbRecipient {
+pub struct SegmentQueryJsonContactsLR {
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub recipients: Vec<Recipients>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MailSettingsPatch {
+    pub args: Vec<Args>,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub email: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enabled: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MailSettingsForwardBounce {
+    pub t: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub email: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enabled: bool,
+    pub v: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MailBatchId {
+pub struct SegmentQueryJsonContactsLRData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l: Option<SegmentQueryJsonContactsLR>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -2388,33 +2608,30 @@ pub struct MailBatchId {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub batch_id: String,
+    pub op: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r: Option<R>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CreditAllocation {
+pub struct SegmentQueryJsonContactsLData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l: Option<SegmentQueryJsonContactsL>,
     /**
      * The license key provided with your New Relic account.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub type_: String,
+    pub op: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r: Option<SegmentQueryJsonContactsLRData>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SubuserPost {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub authorization_token: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub credit_allocation: Option<CreditAllocation>,
+pub struct SegmentQueryJsonContactsR {
     /**
      * The license key provided with your New Relic account.
      */
@@ -2423,22 +2640,22 @@ pub struct SubuserPost {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub signup_session_token: String,
+    pub t: String,
     /**
-     * The number of errors found while adding recipients.
+     * The recipient IDs of the recipients that already existed from this request.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub user_id: f64,
+    pub v: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SegmentQueryJsonContactsRData {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l: Option<L>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -2447,24 +2664,15 @@ pub struct SubuserPost {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub username: String,
+    pub op: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r: Option<SegmentQueryJsonContactsR>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactdbRecipientCount {
-    /**
-     * The number of errors found while adding recipients.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub recipient_count: f64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactdbCustomFieldWithValue {
+pub struct SegmentQueryJsonContacts {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub l: Option<SegmentQueryJsonContactsLData>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -2473,42 +2681,53 @@ pub struct ContactdbCustomFieldWithValue {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub value: String,
+    pub op: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r: Option<SegmentQueryJsonContactsRData>,
 }
 
-/// All of the following types are flattened into one object:
-///
-/// - `ContactdbCustomFieldWithAllOf`
-/// - `ContactdbCustomFieldWithValue`
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactdbCustomFieldWithValueAllOf {
-    #[serde(flatten)]
-    pub contactdb_custom_field_with_all_of: ContactdbCustomFieldWithAllOf,
-    #[serde(flatten)]
-    pub contactdb_custom_field_with_value: ContactdbCustomFieldWithValue,
+pub struct SegmentQueryJson {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contacts: Option<SegmentQueryJsonContacts>,
 }
 
+/// The user may choose to create up to 120 custom fields or none at all. This is not a reserved field.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TransactionalTemplateVersionCreate {
+pub struct ContactResponseCustomFields {
+    /**
+     * The user may choose to create up to 120 custom fields or none at all. This is not a reserved field.
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "custom_field_name1"
     )]
-    pub active: i64,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub editor: Option<Editor>,
+    pub custom_field_name_1: String,
+    /**
+     * The user may choose to create up to 120 custom fields or none at all. This is not a reserved field.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "custom_field_name2"
     )]
-    pub generate_plain_content: bool,
+    pub custom_field_name_2: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContactResponse {
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub html_content: String,
+    pub address_line_1: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -2517,13 +2736,16 @@ pub struct TransactionalTemplateVersionCreate {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub address_line_2: String,
+    /**
+     * The recipient IDs of the recipients that already existed from this request.
+     */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub plain_content: String,
+    pub alternate_emails: Vec<String>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -2532,113 +2754,164 @@ pub struct TransactionalTemplateVersionCreate {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub subject: String,
+    pub city: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub test_data: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TransactionalTemplatesVersionOutputLean {
+    pub country: String,
+    /**
+     * The user may choose to create up to 120 custom fields or none at all. This is not a reserved field.
+     */
+    #[serde()]
+    pub custom_fields: ContactResponseCustomFields,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub active: i64,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub editor: Option<Editor>,
+    pub email: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub generate_plain_content: bool,
+    pub first_name: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub id: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub last_name: String,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub subject: String,
+    pub list_ids: Vec<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub template_id: String,
+    pub postal_code: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub thumbnail_url: String,
+    pub segment_ids: Vec<String>,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub updated_at: String,
+    pub state_province_region: String,
 }
 
-/**
- * Defines the generation of the template.
- */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Generation {
-    #[serde(rename = "dynamic")]
-    Dynamic,
-    #[serde(rename = "legacy")]
-    Legacy,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+pub struct TneSenderId {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
 }
 
-impl std::fmt::Display for Generation {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Generation::Dynamic => "dynamic",
-            Generation::Legacy => "legacy",
-            Generation::Noop => "",
-            Generation::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
+/// Only verified sender identities can be used to send email.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Verified {
+    /**
+     * Only verified sender identities can be used to send email.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    /**
+     * Only verified sender identities can be used to send email.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub status: bool,
 }
 
-impl Default for Generation {
-    fn default() -> Generation {
-        Generation::Noop
-    }
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TneSenderData {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub created_at: i64,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub locked: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub updated_at: i64,
+    /**
+     * Only verified sender identities can be used to send email.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verified: Option<Verified>,
 }
-impl Generation {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Generation::Noop)
-    }
+
+/// All of the following types are flattened into one object:
+///
+/// - `TneSenderId`
+/// - `SendersRequestBody`
+/// - `TneSenderData`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TneSenderAllOf {
+    #[serde(flatten)]
+    pub tne_sender_id: TneSenderId,
+    #[serde(flatten)]
+    pub senders_request_body: SendersRequestBody,
+    #[serde(flatten)]
+    pub tne_sender_data: TneSenderData,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TransactionalTemplatesTemplateLean {
-    /**
-     * Defines the generation of the template.
-     */
-    #[serde(default, skip_serializing_if = "Generation::is_noop")]
-    pub generation: Generation,
+pub struct ApiError {
     /**
      * The license key provided with your New Relic account.
      */
@@ -2647,7 +2920,7 @@ pub struct TransactionalTemplatesTemplateLean {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub error_id: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -2656,97 +2929,134 @@ pub struct TransactionalTemplatesTemplateLean {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub field: String,
     /**
      * The license key provided with your New Relic account.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "updated_at "
-    )]
-    pub updated_at: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub versions: Vec<TransactionalTemplatesVersionOutputLean>,
+    pub message: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactDetails {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<SelfMetadata>,
+pub struct MetadataType {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub count: i64,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub address_line_1: String,
+    pub next: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub address_line_2: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub alternate_emails: Vec<String>,
+    pub prev: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "self"
     )]
-    pub city: String,
+    pub self_: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ContactDetails3CustomFields {}
+
+/// If the request is incorrect, an array of errors will be returned.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ErrorsData {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub country: String,
+    pub errors: Vec<Errors>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SinglesendSearchStatus {
+    #[serde(rename = "draft")]
+    Draft,
+    #[serde(rename = "scheduled")]
+    Scheduled,
+    #[serde(rename = "triggered")]
+    Triggered,
     /**
-     * The license key provided with your New Relic account.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub created_at: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub custom_fields: Option<Help>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
+    Unknown(String),
+}
+
+impl AsRef<str> for SinglesendSearchStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            SinglesendSearchStatus::Draft => "draft",
+            SinglesendSearchStatus::Scheduled => "scheduled",
+            SinglesendSearchStatus::Triggered => "triggered",
+            SinglesendSearchStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SinglesendSearchStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SinglesendSearchStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "draft" => SinglesendSearchStatus::Draft,
+            "scheduled" => SinglesendSearchStatus::Scheduled,
+            "triggered" => SinglesendSearchStatus::Triggered,
+            _ => SinglesendSearchStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SinglesendSearchStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SinglesendSearchStatus> for String {
+    fn from(v: SinglesendSearchStatus) -> Self {
+        match v {
+            SinglesendSearchStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SendTo {
     /**
-     * The license key provided with your New Relic account.
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub last_name: String,
+    pub all: bool,
     /**
      * The recipient IDs of the recipients that already existed from this request.
      */
@@ -2756,150 +3066,141 @@ pub struct ContactDetails {
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
     pub list_ids: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub postal_code: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state_province_region: String,
     /**
-     * The license key provided with your New Relic account.
+     * The recipient IDs of the recipients that already existed from this request.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub updated_at: String,
+    pub segment_ids: Vec<String>,
 }
 
-/// Result map of the import job.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Results {
+pub struct EmailConfig {
     /**
-     * Result map of the import job.
+     * The license key provided with your New Relic account.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub created_count: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_unsubscribe_url: Option<String>,
     /**
-     * Result map of the import job.
+     * The license key provided with your New Relic account.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub deleted_count: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub design_id: Option<String>,
     /**
-     * Result map of the import job.
+     * The editor — `"design"` or `"code"` — used to modify the Single Send's design in the Marketing Campaigns App.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub errored_count: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor: Option<Editor>,
     /**
-     * Result map of the import job.
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub errors_url: String,
+    pub generate_plain_content: bool,
     /**
-     * Result map of the import job.
+     * The license key provided with your New Relic account.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub requested_count: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html_content: Option<String>,
     /**
-     * Result map of the import job.
+     * The license key provided with your New Relic account.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub updated_count: f64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactImport {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub finished_at: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub job_type: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub results: Option<Results>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub started_at: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub status: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CustomFields {
+    pub ip_pool: Option<String>,
     /**
      * The license key provided with your New Relic account.
      */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plain_content: Option<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "custom_field_name1"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub custom_field_name_1: String,
+    pub sender_id: i64,
     /**
      * The license key provided with your New Relic account.
      */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "custom_field_name2"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub custom_field_name_2: String,
+    pub suppression_group_id: i64,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Contact {
+#[serde(from = "String", into = "String")]
+pub enum SinglesendScheduleStatus {
+    #[serde(rename = "draft")]
+    Draft,
+    #[serde(rename = "scheduled")]
+    Scheduled,
+    #[serde(rename = "triggered")]
+    Triggered,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SinglesendScheduleStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            SinglesendScheduleStatus::Draft => "draft",
+            SinglesendScheduleStatus::Scheduled => "scheduled",
+            SinglesendScheduleStatus::Triggered => "triggered",
+            SinglesendScheduleStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SinglesendScheduleStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SinglesendScheduleStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "draft" => SinglesendScheduleStatus::Draft,
+            "scheduled" => SinglesendScheduleStatus::Scheduled,
+            "triggered" => SinglesendScheduleStatus::Triggered,
+            _ => SinglesendScheduleStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SinglesendScheduleStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SinglesendScheduleStatus> for String {
+    fn from(v: SinglesendScheduleStatus) -> Self {
+        match v {
+            SinglesendScheduleStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Warnings {
     /**
      * The license key provided with your New Relic account.
      */
@@ -2908,7 +3209,7 @@ pub struct Contact {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub address_line_1: String,
+    pub field: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -2917,7 +3218,7 @@ pub struct Contact {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub address_line_2: String,
+    pub message: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -2926,54 +3227,93 @@ pub struct Contact {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub alternate_emails: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub warning_id: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SinglesendWarning {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub city: String,
+    pub warnings: Vec<Warnings>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WebhooksEventWebhookRequest {
     /**
-     * The license key provided with your New Relic account.
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub custom_fields: Option<CustomFields>,
+    pub bounce: Option<bool>,
     /**
-     * The license key provided with your New Relic account.
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub click: Option<bool>,
     /**
-     * The license key provided with your New Relic account.
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deferred: Option<bool>,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivered: Option<bool>,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dropped: Option<bool>,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_resubscribe: Option<bool>,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
      */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_unsubscribe: Option<bool>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub oauth_client_id: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub postal_code: String,
+    pub oauth_token_url: String,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open: Option<bool>,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub processed: Option<bool>,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spam_report: Option<bool>,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unsubscribe: Option<bool>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -2982,87 +3322,51 @@ pub struct Contact {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub primary_email: String,
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReplyEmailObject {
     /**
      * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub state_province_region: String,
+    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SingleContactRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub contact: Option<Contact>,
+pub struct CcBccEmailObject {
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub list_ids: Vec<String>,
-}
-
-/**
- * The export job's status. Allowed values: `pending`, `ready`, or `failure`.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ContactExportStatus {
-    #[serde(rename = "failure")]
-    Failure,
-    #[serde(rename = "pending")]
-    Pending,
-    #[serde(rename = "ready")]
-    Ready,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for ContactExportStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ContactExportStatus::Failure => "failure",
-            ContactExportStatus::Pending => "pending",
-            ContactExportStatus::Ready => "ready",
-            ContactExportStatus::Noop => "",
-            ContactExportStatus::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for ContactExportStatus {
-    fn default() -> ContactExportStatus {
-        ContactExportStatus::Noop
-    }
-}
-impl ContactExportStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ContactExportStatus::Noop)
-    }
+    pub email: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactExport {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<Metadata>,
+pub struct Result {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub completed_at: String,
+    pub created_at: i64,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub contact_count: i64,
+    pub id: i64,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3071,16 +3375,26 @@ pub struct ContactExport {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub created_at: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
+    pub ip: String,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub expires_at: String,
+    pub updated_at: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct StatsAdvancedBaseSchemaMetrics {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct StatsAdvancedBaseSchema {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<StatsAdvancedBaseSchemaMetrics>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct StatsAdvancedBaseSchemaData {
     /**
      * The license key provided with your New Relic account.
      */
@@ -3089,129 +3403,96 @@ pub struct ContactExport {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-    /**
-     * The export job's status. Allowed values: `pending`, `ready`, or `failure`.
-     */
-    #[serde(default, skip_serializing_if = "ContactExportStatus::is_noop")]
-    pub status: ContactExportStatus,
+    pub date: String,
     /**
-     * The license key provided with your New Relic account.
+     * The individual email activity stats.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub updated_at: String,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub urls: Vec<String>,
+    pub stats: Vec<StatsAdvancedBaseSchema>,
 }
 
+/// AST representation of the query DSL
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactSummary {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<SelfMetadata>,
+pub struct QueryJson {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct From {
     /**
-     * The number of errors found while adding recipients.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub created_at: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
     /**
      * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_name: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReplyTo {
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub list_ids: Vec<String>,
+    pub email: String,
     /**
-     * The number of errors found while adding recipients.
+     * The license key provided with your New Relic account.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub updated_at: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactRequest {
+pub struct SendersRequestBody {
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub address_line_1: String,
+    pub address: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub address_line_2: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub alternate_emails: Vec<String>,
+    pub address_2: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub city: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub country: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub custom_fields: Option<Help>,
+    #[serde()]
+    pub from: From,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3220,93 +3501,246 @@ pub struct ContactRequest {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub email: String,
+    pub nickname: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<ReplyTo>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub first_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub postal_code: String,
+    pub state: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub state_province_region: String,
+    pub zip: String,
 }
 
+/**
+ * The message's status.
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactDetails2 {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<SelfMetadata>,
+#[serde(from = "String", into = "String")]
+pub enum EmailActivityResponseCommonFieldsStatus {
+    #[serde(rename = "delivered")]
+    Delivered,
+    #[serde(rename = "not delivered")]
+    NotDelivered,
+    #[serde(rename = "processed")]
+    Processed,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for EmailActivityResponseCommonFieldsStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            EmailActivityResponseCommonFieldsStatus::Delivered => "delivered",
+            EmailActivityResponseCommonFieldsStatus::NotDelivered => "not delivered",
+            EmailActivityResponseCommonFieldsStatus::Processed => "processed",
+            EmailActivityResponseCommonFieldsStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for EmailActivityResponseCommonFieldsStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EmailActivityResponseCommonFieldsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "delivered" => EmailActivityResponseCommonFieldsStatus::Delivered,
+            "not delivered" => EmailActivityResponseCommonFieldsStatus::NotDelivered,
+            "processed" => EmailActivityResponseCommonFieldsStatus::Processed,
+            _ => EmailActivityResponseCommonFieldsStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for EmailActivityResponseCommonFieldsStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<EmailActivityResponseCommonFieldsStatus> for String {
+    fn from(v: EmailActivityResponseCommonFieldsStatus) -> Self {
+        match v {
+            EmailActivityResponseCommonFieldsStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct EmailActivityResponseCommonFields {
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub address_line_1: String,
+    pub from_email: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub address_line_2: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub alternate_emails: Vec<String>,
+    pub msg_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<EmailActivityResponseCommonFieldsStatus>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub city: String,
+    pub subject: String,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub country: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub to_email: String,
+}
+
+/**
+ * Indicate the type of user: owner user, teammate admin user, or normal teammate
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum UserType {
+    #[serde(rename = "admin")]
+    Admin,
+    #[serde(rename = "owner")]
+    Owner,
+    #[serde(rename = "teammate")]
+    Teammate,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for UserType {
+    fn as_ref(&self) -> &str {
+        match self {
+            UserType::Admin => "admin",
+            UserType::Owner => "owner",
+            UserType::Teammate => "teammate",
+            UserType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for UserType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UserType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => UserType::Admin,
+            "owner" => UserType::Owner,
+            "teammate" => UserType::Teammate,
+            _ => UserType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UserType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UserType> for String {
+    fn from(v: UserType) -> Self {
+        match v {
+            UserType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SenderRequestFrom {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SenderRequestReplyTo {
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub custom_fields: Option<Help>,
+    pub email: Option<String>,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Segment status indicates whether the segment's contacts will be updated periodically
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SegmentStatusResponse {
+    /**
+     * Segment status indicates whether the segment's contacts will be updated periodically
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub email: String,
+    pub error_message: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub facebook: String,
+    pub query_validation: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AllSegmentsResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
+    pub metadata: Option<MetadataType>,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub first_name: String,
+    pub contacts_count: i64,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3315,97 +3749,105 @@ pub struct ContactDetails2 {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
+    pub created_at: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub line: String,
+    pub id: String,
     /**
-     * The recipient IDs of the recipients that already existed from this request.
+     * The license key provided with your New Relic account.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub list_ids: Vec<String>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub phone_number: String,
+    pub name: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub postal_code: String,
+    pub next_sample_update: String,
+    /**
+     * The recipient IDs of the recipients that already existed from this request.
+     */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub segment_ids: Vec<String>,
+    pub parent_list_ids: Vec<String>,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub state_province_region: String,
+    pub query_version: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub unique_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub sample_updated_at: String,
+    /**
+     * Segment status indicates whether the segment's contacts will be updated periodically
+     */
+    #[serde()]
+    pub status: SegmentStatusResponse,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub whatsapp: String,
+    pub updated_at: String,
 }
 
+///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SelfMetadata {
+pub struct SegmentSummaryV2 {
+    /**
+     *
+     */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "self"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub self_: String,
+    pub results: Vec<SegmentSummary>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Error {
+pub struct SegmentResponse {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub error_id: String,
+    pub contacts_count: i64,
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub field: String,
+    pub contacts_sample: Vec<ContactResponse>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3414,62 +3856,7 @@ pub struct Error {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub message: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub parameter: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Link {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub href: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub rel: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Metadata {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub count: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub next: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub prev: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "self"
-    )]
-    pub self_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Webhook {
+    pub created_at: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3478,7 +3865,7 @@ pub struct Webhook {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub nonce: String,
+    pub id: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3487,75 +3874,7 @@ pub struct Webhook {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub url: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct List {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<SelfMetadata>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub contact_count: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
     pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum FieldType {
-    #[serde(rename = "Date")]
-    Date,
-    #[serde(rename = "Number")]
-    Number,
-    #[serde(rename = "Text")]
-    Text,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for FieldType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            FieldType::Date => "Date",
-            FieldType::Number => "Number",
-            FieldType::Text => "Text",
-            FieldType::Noop => "",
-            FieldType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for FieldType {
-    fn default() -> FieldType {
-        FieldType::Noop
-    }
-}
-impl FieldType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FieldType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReservedFieldDefinitionsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub field_type: Option<FieldType>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3564,21 +3883,16 @@ pub struct ReservedFieldDefinitionsResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub next_sample_update: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * The recipient IDs of the recipients that already existed from this request.
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub read_only: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CustomFieldDefinitionsResponse {
-    #[serde(default, skip_serializing_if = "FieldType::is_noop")]
-    pub field_type: FieldType,
+    pub parent_list_ids: Vec<String>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3587,7 +3901,7 @@ pub struct CustomFieldDefinitionsResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
+    pub query_dsl: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3596,11 +3910,7 @@ pub struct CustomFieldDefinitionsResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentWrite {
+    pub query_version: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3609,7 +3919,12 @@ pub struct SegmentWrite {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub sample_updated_at: String,
+    /**
+     * Segment status indicates whether the segment's contacts will be updated periodically
+     */
+    #[serde()]
+    pub status: SegmentStatusResponse,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3618,23 +3933,11 @@ pub struct SegmentWrite {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub query_dsl: String,
+    pub updated_at: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentSummary {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub contacts_count: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+pub struct ErrorsSeg {
     /**
      * The license key provided with your New Relic account.
      */
@@ -3643,41 +3946,108 @@ pub struct SegmentSummary {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub next_sample_update: String,
+    pub field: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub parent_list_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub sample_updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub message: String,
+}
+
+/// If the request is incorrect, an array of errors will be returned.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ErrorsSegV2 {
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub errors: Vec<ErrorsSeg>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct L {
+pub struct SegmentUpdate {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query_dsl: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum AbbvMessageStatus {
+    #[serde(rename = "delivered")]
+    Delivered,
+    #[serde(rename = "not_delivered")]
+    NotDelivered,
+    #[serde(rename = "processed")]
+    Processed,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for AbbvMessageStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            AbbvMessageStatus::Delivered => "delivered",
+            AbbvMessageStatus::NotDelivered => "not_delivered",
+            AbbvMessageStatus::Processed => "processed",
+            AbbvMessageStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for AbbvMessageStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AbbvMessageStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "delivered" => AbbvMessageStatus::Delivered,
+            "not_delivered" => AbbvMessageStatus::NotDelivered,
+            "processed" => AbbvMessageStatus::Processed,
+            _ => AbbvMessageStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AbbvMessageStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<AbbvMessageStatus> for String {
+    fn from(v: AbbvMessageStatus) -> Self {
+        match v {
+            AbbvMessageStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AbbvMessage {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub clicks_count: i64,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3686,7 +4056,7 @@ pub struct L {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub t: String,
+    pub from_email: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3695,13 +4065,7 @@ pub struct L {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub v: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentQueryJsonContactsL {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub l: Option<L>,
+    pub last_event_time: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3710,19 +4074,15 @@ pub struct SegmentQueryJsonContactsL {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub op: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub r: Option<L>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentQueryJsonContactsLR {
+    pub msg_id: String,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub args: Vec<L>,
+    pub opens_count: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<AbbvMessageStatus>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3731,7 +4091,7 @@ pub struct SegmentQueryJsonContactsLR {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub t: String,
+    pub subject: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -3740,9284 +4100,266 @@ pub struct SegmentQueryJsonContactsLR {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub v: String,
+    pub to_email: String,
 }
 
+/**
+ * Quick summary of the status of a message
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct R {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub l: Option<SegmentQueryJsonContactsLR>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub op: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub r: Option<L>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentQueryJsonContactsLData {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub l: Option<SegmentQueryJsonContactsL>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub op: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub r: Option<R>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentQueryJsonContactsR {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub t: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub v: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentQueryJsonContactsRData {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub l: Option<L>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub op: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub r: Option<SegmentQueryJsonContactsR>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentQueryJsonContacts {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub l: Option<SegmentQueryJsonContactsLData>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub op: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub r: Option<SegmentQueryJsonContactsRData>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentQueryJson {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub contacts: Option<SegmentQueryJsonContacts>,
-}
-
-/// The user may choose to create up to 120 custom fields or none at all. This is not a reserved field.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactResponseCustomFields {
-    /**
-     * The user may choose to create up to 120 custom fields or none at all. This is not a reserved field.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "custom_field_name1"
-    )]
-    pub custom_field_name_1: String,
-    /**
-     * The user may choose to create up to 120 custom fields or none at all. This is not a reserved field.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "custom_field_name2"
-    )]
-    pub custom_field_name_2: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address_line_1: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address_line_2: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub alternate_emails: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    /**
-     * The user may choose to create up to 120 custom fields or none at all. This is not a reserved field.
-     */
-    #[serde()]
-    pub custom_fields: ContactResponseCustomFields,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub list_ids: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub postal_code: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub segment_ids: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state_province_region: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TneSenderId {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-}
-
-/// Only verified sender identities can be used to send email.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Verified {
-    /**
-     * Only verified sender identities can be used to send email.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
-    /**
-     * Only verified sender identities can be used to send email.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub status: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TneSenderData {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub created_at: i64,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub locked: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub updated_at: i64,
-    /**
-     * Only verified sender identities can be used to send email.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub verified: Option<Verified>,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `TneSenderId`
-/// - `SendersRequestBody`
-/// - `TneSenderData`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TneSenderAllOf {
-    #[serde(flatten)]
-    pub tne_sender_id: TneSenderId,
-    #[serde(flatten)]
-    pub senders_request_body: SendersRequestBody,
-    #[serde(flatten)]
-    pub tne_sender_data: TneSenderData,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApiError {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub error_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ApiErrors {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<ApiError>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MetadataType {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub count: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub next: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub prev: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "self"
-    )]
-    pub self_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DesignInput {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub html_content: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub plain_content: String,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `DesignDuplicateInput`
-/// - `DesignCommonFieldsAllOf`
-/// - `DesignInput`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DesignInputAllOf {
-    #[serde(flatten)]
-    pub design_duplicate_input: DesignDuplicateInput,
-    #[serde(flatten)]
-    pub design_common_fields_all_of: DesignCommonFieldsAllOf,
-    #[serde(flatten)]
-    pub design_input: DesignInput,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `DesignInputAllOf`
-/// - `DesignOutputSummaryAllOf`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DesignOutputAllOf {
-    #[serde(flatten)]
-    pub design_input_all_of: DesignInputAllOf,
-    #[serde(flatten)]
-    pub design_output_summary_all_of: DesignOutputSummaryAllOf,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DesignOutputSummary {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub thumbnail_url: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `DesignDuplicateInput`
-/// - `DesignCommonFieldsAllOf`
-/// - `DesignOutputSummary`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DesignOutputSummaryAllOf {
-    #[serde(flatten)]
-    pub design_duplicate_input: DesignDuplicateInput,
-    #[serde(flatten)]
-    pub design_common_fields_all_of: DesignCommonFieldsAllOf,
-    #[serde(flatten)]
-    pub design_output_summary: DesignOutputSummary,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DesignDuplicateInput {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub editor: Option<Editor>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ContactDetails3 {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<SelfMetadata>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address_line_1: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address_line_2: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub alternate_emails: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub created_at: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub custom_fields: Option<Help>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub facebook: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub line: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub list_ids: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone_number: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub postal_code: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub segment_ids: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state_province_region: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub unique_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub updated_at: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub whatsapp: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Warning {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ErrorsData {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub parameter: String,
-}
-
-/// If the request is incorrect, an array of errors will be returned.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ErrorsDataType {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<ErrorsData>,
-}
-
-/**
- * This is the A/B phase of the Single Send stat returned. If the `group_by` parameter doesn't include `ab_phase` in the request, then the value is "all".
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum AbPhase {
-    #[serde(rename = "all")]
-    All,
-    #[serde(rename = "send")]
-    Send,
-    #[serde(rename = "test")]
-    Test,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for AbPhase {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            AbPhase::All => "all",
-            AbPhase::Send => "send",
-            AbPhase::Test => "test",
-            AbPhase::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for AbPhase {
-    fn default() -> AbPhase {
-        AbPhase::All
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SinglesendsResponseResults {
-    /**
-     * This is the A/B phase of the Single Send stat returned. If the `group_by` parameter doesn't include `ab_phase` in the request, then the value is "all".
-     */
-    #[serde(default)]
-    pub ab_phase: AbPhase,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ab_variation: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub aggregation: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub stats: Option<MetricsData>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SinglesendsResponse {
-    #[serde(rename = "_metadata")]
-    pub metadata: Metadata,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub results: Vec<SinglesendsResponseResults>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AutomationsResponseResults {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub aggregation: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub stats: Option<MetricsData>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub step_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AutomationsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<Metadata>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub results: Vec<AutomationsResponseResults>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MetricsData {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub bounce_drops: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub bounces: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub clicks: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub delivered: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub invalid_emails: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub opens: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub requests: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub spam_report_drops: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub spam_reports: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub unique_clicks: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub unique_opens: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub unsubscribes: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum SinglesendSearchStatus {
-    #[serde(rename = "draft")]
-    Draft,
-    #[serde(rename = "scheduled")]
-    Scheduled,
-    #[serde(rename = "triggered")]
-    Triggered,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for SinglesendSearchStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            SinglesendSearchStatus::Draft => "draft",
-            SinglesendSearchStatus::Scheduled => "scheduled",
-            SinglesendSearchStatus::Triggered => "triggered",
-            SinglesendSearchStatus::Noop => "",
-            SinglesendSearchStatus::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for SinglesendSearchStatus {
-    fn default() -> SinglesendSearchStatus {
-        SinglesendSearchStatus::Noop
-    }
-}
-impl SinglesendSearchStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SinglesendSearchStatus::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SinglesendSearch {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub categories: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub status: Vec<SinglesendSearchStatus>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SendTo {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub all: bool,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub list_ids: Vec<String>,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub segment_ids: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EmailConfig {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub custom_unsubscribe_url: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub design_id: String,
-    /**
-     * The editor used in the UI.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub editor: Option<Editor>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub generate_plain_content: bool,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub html_content: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip_pool: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub plain_content: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub sender_id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subject: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub suppression_group_id: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SinglesendRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub categories: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub email_config: Option<EmailConfig>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub send_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub send_to: Option<SendTo>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SinglesendSchedule {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub send_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<SinglesendSearchStatus>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Warnings {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub warning_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SinglesendWarning {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub warnings: Vec<Warnings>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReplyTo {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct WebhooksEventWebhookUpdateWithOAuthRequest {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub bounce: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub click: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deferred: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub delivered: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub dropped: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub enabled: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub group_resubscribe: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub group_unsubscribe: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub oauth_client_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub oauth_client_secret: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub oauth_token_url: String,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub open: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub processed: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub spam_report: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub unsubscribe: Option<bool>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub url: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct WebhooksEventWebhookRequest {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub bounce: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub click: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub deferred: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub delivered: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub dropped: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub enabled: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub group_resubscribe: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub group_unsubscribe: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub oauth_client_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub oauth_token_url: String,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub open: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub processed: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub spam_report: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub unsubscribe: Option<bool>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub url: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReplyEmailObject {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AutomationsLinkStatsResponseResults {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub clicks: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub step_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub url: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub url_location: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AutomationsLinkStatsResponse {
-    #[serde(rename = "_metadata")]
-    pub metadata: LinkTrackingMetadata,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub results: Vec<AutomationsLinkStatsResponseResults>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub total_clicks: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LinkTrackingMetadata {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub count: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub next: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub prev: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "self"
-    )]
-    pub self_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SinglesendsLinkStatsResponseResults {
-    /**
-     * This is the A/B phase of the Single Send stat returned. If the `group_by` parameter doesn't include `ab_phase` in the request, then the value is "all".
-     */
-    #[serde(default)]
-    pub ab_phase: AbPhase,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ab_variation: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub clicks: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub url: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub url_location: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SinglesendsLinkStatsResponse {
-    #[serde(rename = "_metadata")]
-    pub metadata: LinkTrackingMetadata,
-    /**
-     * This is the index of the link's location in the email contents.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub results: Vec<SinglesendsLinkStatsResponseResults>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub total_clicks: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Subusers {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub user_id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DomainAuthentication200Response {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub last_validation_attempt_at: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub subusers: Vec<Subusers>,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `AuthenticationDomain`
-/// - `DomainAuthentication200Response`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DomainAuthentication200ResponseAllOf {
-    #[serde(flatten)]
-    pub authentication_domain: AuthenticationDomain,
-    #[serde(flatten)]
-    pub domain_authentication_200_response: DomainAuthentication200Response,
-}
-
-/**
- * What differs between the A/B tests
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum AbTestSummaryType {
-    #[serde(rename = "content")]
-    Content,
-    #[serde(rename = "subject")]
-    Subject,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for AbTestSummaryType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            AbTestSummaryType::Content => "content",
-            AbTestSummaryType::Subject => "subject",
-            AbTestSummaryType::Noop => "",
-            AbTestSummaryType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for AbTestSummaryType {
-    fn default() -> AbTestSummaryType {
-        AbTestSummaryType::Noop
-    }
-}
-impl AbTestSummaryType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AbTestSummaryType::Noop)
-    }
-}
-
-/**
- * How the winner will be decided
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum WinnerCriteria {
-    #[serde(rename = "click")]
-    Click,
-    #[serde(rename = "manual")]
-    Manual,
-    #[serde(rename = "open")]
-    Open,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for WinnerCriteria {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            WinnerCriteria::Click => "click",
-            WinnerCriteria::Manual => "manual",
-            WinnerCriteria::Open => "open",
-            WinnerCriteria::Noop => "",
-            WinnerCriteria::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for WinnerCriteria {
-    fn default() -> WinnerCriteria {
-        WinnerCriteria::Noop
-    }
-}
-impl WinnerCriteria {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, WinnerCriteria::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AbTestSummary {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub duration: String,
-    /**
-     * Last day to select an A/B Test Winner
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_format::deserialize"
-    )]
-    pub expiration_date: Option<chrono::NaiveDate>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub test_percentage: i64,
-    /**
-     * What differs between the A/B tests
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "AbTestSummaryType::is_noop",
-        rename = "type"
-    )]
-    pub type_: AbTestSummaryType,
-    /**
-     * How the winner will be decided
-     */
-    #[serde(default, skip_serializing_if = "WinnerCriteria::is_noop")]
-    pub winner_criteria: WinnerCriteria,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub winner_selected_at: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub winning_template_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SinglesendResponseShort {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub abtest: Option<AbTestSummary>,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub categories: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_abtest: bool,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub send_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "SinglesendSearchStatus::is_noop")]
-    pub status: SinglesendSearchStatus,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CcBccEmailObject {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct VerifiedSenderRequestSchema {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address2"
-    )]
-    pub address_2: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub from_email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub from_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub nickname: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reply_to: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reply_to_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zip: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct VerifiedSenderResponseSchema {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address2"
-    )]
-    pub address_2: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub from_email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub from_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub locked: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub nickname: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reply_to: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reply_to_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub verified: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zip: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Result {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub created_at: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub updated_at: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IpAccessResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<Result>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct StatsAdvancedGlobal {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub blocks: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub bounce_drops: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub bounces: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub deferred: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub delivered: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub invalid_emails: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub processed: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub requests: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub spam_report_drops: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub spam_reports: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub unsubscribe_drops: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub unsubscribes: i64,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `AdvancedStatsClicksOpensAllOf`
-/// - `StatsAdvancedGlobal`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct StatsAdvancedGlobalAllOf {
-    #[serde(flatten)]
-    pub advanced_stats_clicks_opens_all_of: AdvancedStatsClicksOpensAllOf,
-    #[serde(flatten)]
-    pub stats_advanced_global: StatsAdvancedGlobal,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct StatsAdvancedBaseSchema {
-    /**
-     * helper text or docs for troubleshooting
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metrics: Option<Help>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct StatsAdvancedBaseSchemaData {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub date: String,
-    /**
-     * The individual email activity stats.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub stats: Vec<StatsAdvancedBaseSchema>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FullSegment {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub contacts_sample: Vec<ContactResponse>,
-    /**
-     * helper text or docs for troubleshooting
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub query_json: Option<Help>,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `SegmentSummary`
-/// - `FullSegment`
-/// - `SegmentWriteV2`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FullSegmentAllOf {
-    #[serde(flatten)]
-    pub segment_summary: SegmentSummary,
-    #[serde(flatten)]
-    pub full_segment: FullSegment,
-    #[serde(flatten)]
-    pub segment_write_v2: SegmentWriteV2,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct From {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SendersRequestBody {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address_2: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    #[serde()]
-    pub from: From,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub nickname: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub reply_to: Option<ReplyTo>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zip: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EnforcedTlsRequestResponse {
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub require_tls: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub require_valid_cert: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SinglesendResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(default, skip_serializing_if = "SinglesendSearchStatus::is_noop")]
-    pub status: SinglesendSearchStatus,
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub warnings: Vec<Warnings>,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `SinglesendRequest`
-/// - `SinglesendResponse`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SinglesendResponseAllOf {
-    #[serde(flatten)]
-    pub singlesend_request: SinglesendRequest,
-    #[serde(flatten)]
-    pub singlesend_response: SinglesendResponse,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DesignCommonFields {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub categories: Vec<String>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub generate_plain_content: bool,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subject: String,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `DesignDuplicateInput`
-/// - `DesignCommonFields`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DesignCommonFieldsAllOf {
-    #[serde(flatten)]
-    pub design_duplicate_input: DesignDuplicateInput,
-    #[serde(flatten)]
-    pub design_common_fields: DesignCommonFields,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct InvalidEmail {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub created: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
-}
-
-/**
- * The message's status.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum EmailActivityResponseCommonFieldsStatus {
-    #[serde(rename = "delivered")]
-    Delivered,
-    #[serde(rename = "not delivered")]
-    NotDelivered,
-    #[serde(rename = "processed")]
-    Processed,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for EmailActivityResponseCommonFieldsStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            EmailActivityResponseCommonFieldsStatus::Delivered => "delivered",
-            EmailActivityResponseCommonFieldsStatus::NotDelivered => "not delivered",
-            EmailActivityResponseCommonFieldsStatus::Processed => "processed",
-            EmailActivityResponseCommonFieldsStatus::Noop => "",
-            EmailActivityResponseCommonFieldsStatus::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for EmailActivityResponseCommonFieldsStatus {
-    fn default() -> EmailActivityResponseCommonFieldsStatus {
-        EmailActivityResponseCommonFieldsStatus::Noop
-    }
-}
-impl EmailActivityResponseCommonFieldsStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EmailActivityResponseCommonFieldsStatus::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct EmailActivityResponseCommonFields {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub from_email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub msg_id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<EmailActivityResponseCommonFieldsStatus>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subject: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub to_email: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SuppressionsRequestBody {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub recipient_emails: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SuppressionGroupRequestBase {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_default: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SsoCertificateBody {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub id: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub intergration_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub not_after: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub not_before: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub public_certificate: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SsoIntegration {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub audience_url: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * The number of errors found while adding recipients.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub last_updated: f64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub single_signon_url: String,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `CreateIntegrationRequest`
-/// - `SsoIntegration`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SsoIntegrationAllOf {
-    #[serde(flatten)]
-    pub create_integration_request: CreateIntegrationRequest,
-    #[serde(flatten)]
-    pub sso_integration: SsoIntegration,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CreateIntegrationRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub completed_integration: Option<bool>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub enabled: Option<bool>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub entity_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub signin_url: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub signout_url: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SsoTeammateResponse {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_sso: bool,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `SsoTeammateCommonFields`
-/// - `SsoTeammateResponse`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SsoTeammateResponseAllOf {
-    #[serde(flatten)]
-    pub sso_teammate_common_fields: SsoTeammateCommonFields,
-    #[serde(flatten)]
-    pub sso_teammate_response: SsoTeammateResponse,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetScopesResponse {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `SsoTeammateCommonFields`
-/// - `GetScopesResponse`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SsoTeammateRequestAllOf {
-    #[serde(flatten)]
-    pub sso_teammate_common_fields: SsoTeammateCommonFields,
-    #[serde(flatten)]
-    pub get_scopes_response: GetScopesResponse,
-}
-
-/**
- * A Teammate can be an “admin,” “owner,” or “teammate.” Each role is associated with the scope of the Teammate’s permissions.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum UserType {
-    #[serde(rename = "admin")]
-    Admin,
-    #[serde(rename = "owner")]
-    Owner,
-    #[serde(rename = "teammate")]
-    Teammate,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for UserType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            UserType::Admin => "admin",
-            UserType::Owner => "owner",
-            UserType::Teammate => "teammate",
-            UserType::Noop => "",
-            UserType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for UserType {
-    fn default() -> UserType {
-        UserType::Noop
-    }
-}
-impl UserType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UserType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SsoTeammatesPatchResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address2"
-    )]
-    pub address_2: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-    /**
-     * A Teammate can be an “admin,” “owner,” or “teammate.” Each role is associated with the scope of the Teammate’s permissions.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub user_type: Option<UserType>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub website: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zip: String,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `SsoTeammateResponseAllOf`
-/// - `SsoTeammatesPatchResponse`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SsoTeammatesPatchResponseAllOf {
-    #[serde(flatten)]
-    pub sso_teammate_response_all_of: SsoTeammateResponseAllOf,
-    #[serde(flatten)]
-    pub sso_teammates_patch_response: SsoTeammatesPatchResponse,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SsoErrorResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub error_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ClickTracking {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enable_text: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enabled: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SsoTeammateCommonFields {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_admin: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_read_only: bool,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SpamReportsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub created: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BlocksResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub created: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub status: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IpPoolsPoolResp {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SenderRequestFrom {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SenderRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address_2: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub from: Option<SenderRequestFrom>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub nickname: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub reply_to: Option<SenderRequestFrom>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zip: String,
-}
-
-/// Segment status indicates whether the segment's contacts will be updated periodically
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentStatusResponse {
-    /**
-     * Segment status indicates whether the segment's contacts will be updated periodically
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub error_message: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub query_validation: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AllSegmentsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<MetadataType>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub contacts_count: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub created_at: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub next_sample_update: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub parent_list_ids: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub query_version: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub sample_updated_at: String,
-    /**
-     * Segment status indicates whether the segment's contacts will be updated periodically
-     */
-    #[serde()]
-    pub status: SegmentStatusResponse,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub updated_at: String,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentSummaryV2 {
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub results: Vec<SegmentSummary>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub contacts_count: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub contacts_sample: Vec<ContactResponse>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub created_at: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub next_sample_update: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub parent_list_ids: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub query_dsl: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub query_version: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub sample_updated_at: String,
-    /**
-     * Segment status indicates whether the segment's contacts will be updated periodically
-     */
-    #[serde()]
-    pub status: SegmentStatusResponse,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub updated_at: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ErrorsSeg {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-/// If the request is incorrect, an array of errors will be returned.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ErrorsSegData {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<ErrorsSeg>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentWriteV2 {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub parent_list_ids: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub query_dsl: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SegmentUpdate {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub query_dsl: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum AbbvMessageStatus {
-    #[serde(rename = "delivered")]
-    Delivered,
-    #[serde(rename = "not_delivered")]
-    NotDelivered,
-    #[serde(rename = "processed")]
-    Processed,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for AbbvMessageStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            AbbvMessageStatus::Delivered => "delivered",
-            AbbvMessageStatus::NotDelivered => "not_delivered",
-            AbbvMessageStatus::Processed => "processed",
-            AbbvMessageStatus::Noop => "",
-            AbbvMessageStatus::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for AbbvMessageStatus {
-    fn default() -> AbbvMessageStatus {
-        AbbvMessageStatus::Noop
-    }
-}
-impl AbbvMessageStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AbbvMessageStatus::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Messages {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub clicks_count: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub from_email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_event_time: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub msg_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub opens_count: i64,
-    #[serde(default, skip_serializing_if = "AbbvMessageStatus::is_noop")]
-    pub status: AbbvMessageStatus,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subject: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub to_email: String,
-}
-
-/**
- * Name of event
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum EventName {
-    #[serde(rename = "bounced")]
-    Bounced,
-    #[serde(rename = "clicked")]
-    Clicked,
-    #[serde(rename = "deferred")]
-    Deferred,
-    #[serde(rename = "delivered")]
-    Delivered,
-    #[serde(rename = "dropped")]
-    Dropped,
-    #[serde(rename = "group_resubscribe")]
-    GroupResubscribe,
-    #[serde(rename = "group_unsubscribe")]
-    GroupUnsubscribe,
-    #[serde(rename = "opened")]
-    Opened,
-    #[serde(rename = "processed")]
-    Processed,
-    #[serde(rename = "spam_report")]
-    SpamReport,
-    #[serde(rename = "unsubscribe")]
-    Unsubscribe,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for EventName {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            EventName::Bounced => "bounced",
-            EventName::Clicked => "clicked",
-            EventName::Deferred => "deferred",
-            EventName::Delivered => "delivered",
-            EventName::Dropped => "dropped",
-            EventName::GroupResubscribe => "group_resubscribe",
-            EventName::GroupUnsubscribe => "group_unsubscribe",
-            EventName::Opened => "opened",
-            EventName::Processed => "processed",
-            EventName::SpamReport => "spam_report",
-            EventName::Unsubscribe => "unsubscribe",
-            EventName::Noop => "",
-            EventName::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for EventName {
-    fn default() -> EventName {
-        EventName::Noop
-    }
-}
-impl EventName {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EventName::Noop)
-    }
-}
-
-/**
- * Use to distinguish between types of bounces
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum BounceType {
-    #[serde(rename = "blocked")]
-    Blocked,
-    #[serde(rename = "bounced")]
-    Bounced,
-    #[serde(rename = "expired")]
-    Expired,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for BounceType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            BounceType::Blocked => "blocked",
-            BounceType::Bounced => "bounced",
-            BounceType::Expired => "expired",
-            BounceType::Noop => "",
-            BounceType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for BounceType {
-    fn default() -> BounceType {
-        BounceType::Noop
-    }
-}
-impl BounceType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, BounceType::Noop)
-    }
-}
-
-#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Event {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub attempt_num: i64,
-    /**
-     * Use to distinguish between types of bounces
-     */
-    #[serde(default, skip_serializing_if = "BounceType::is_noop")]
-    pub bounce_type: BounceType,
-    /**
-     * Name of event
-     */
-    #[serde(default, skip_serializing_if = "EventName::is_noop")]
-    pub event_name: EventName,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub http_user_agent: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub mx_server: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub processed: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub url: String,
-}
-
-/**
- * Whether or not the outbound IP is dedicated vs shared
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OutboundIpType {
-    #[serde(rename = "dedicated")]
-    Dedicated,
-    #[serde(rename = "shared")]
-    Shared,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for OutboundIpType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OutboundIpType::Dedicated => "dedicated",
-            OutboundIpType::Shared => "shared",
-            OutboundIpType::Noop => "",
-            OutboundIpType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for OutboundIpType {
-    fn default() -> OutboundIpType {
-        OutboundIpType::Noop
-    }
-}
-impl OutboundIpType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OutboundIpType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Message {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub api_key_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub asm_group_id: i64,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub categories: Vec<String>,
-    /**
-     * List of events related to email message
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub events: Vec<Event>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub from_email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub msg_id: String,
-    /**
-     * This is the IP of the user who sent the message.
-     */
-    #[serde()]
-    pub originating_ip: std::net::Ipv4Addr,
-    /**
-     * This is the IP of the user who sent the message.
-     */
-    #[serde()]
-    pub outbound_ip: std::net::Ipv4Addr,
-    /**
-     * Whether or not the outbound IP is dedicated vs shared
-     */
-    #[serde(default, skip_serializing_if = "OutboundIpType::is_noop")]
-    pub outbound_ip_type: OutboundIpType,
-    /**
-     * The message's status.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "EmailActivityResponseCommonFieldsStatus::is_noop"
-    )]
-    pub status: EmailActivityResponseCommonFieldsStatus,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subject: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub teammate: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub template_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub to_email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub unique_args: String,
-}
-
-/// The DNS records for this authenticated domain.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DomainAuthenticationDns {
-    /**
-     * The DKIM record for messages sent using this authenticated domain.
-     */
-    #[serde()]
-    pub dkim: Dkim,
-    /**
-     * The DKIM record for messages sent using this authenticated domain.
-     */
-    #[serde()]
-    pub domain_spf: Dkim,
-    /**
-     * The DKIM record for messages sent using this authenticated domain.
-     */
-    #[serde()]
-    pub mail_server: Dkim,
-    /**
-     * The DKIM record for messages sent using this authenticated domain.
-     */
-    #[serde()]
-    pub subdomain_spf: Dkim,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DomainAuthentication {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub automatic_security: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub custom_spf: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub default: bool,
-    /**
-     * The DNS records for this authenticated domain.
-     */
-    #[serde()]
-    pub dns: DomainAuthenticationDns,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub domain: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ips: Vec<String>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub legacy: bool,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subdomain: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub user_id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub valid: bool,
-}
-
-/// The DNS records used to authenticate the sending domain.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthenticationDomainDns {
-    /**
-     * The DKIM record for messages sent using this authenticated domain.
-     */
-    #[serde(rename = "dkim1")]
-    pub dkim_1: Dkim,
-    /**
-     * The DKIM record for messages sent using this authenticated domain.
-     */
-    #[serde(rename = "dkim2")]
-    pub dkim_2: Dkim,
-    /**
-     * The DKIM record for messages sent using this authenticated domain.
-     */
-    #[serde()]
-    pub mail_cname: Dkim,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct AuthenticationDomain {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub automatic_security: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub custom_spf: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub default: bool,
-    /**
-     * The DNS records used to authenticate the sending domain.
-     */
-    #[serde()]
-    pub dns: AuthenticationDomainDns,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub domain: String,
-    /**
-     * The number of errors found while adding recipients.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub id: f64,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ips: Vec<String>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub legacy: bool,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subdomain: String,
-    /**
-     * The number of errors found while adding recipients.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub user_id: f64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub valid: bool,
-}
-
-/**
- * Dictates how the stats are time-sliced. Currently, `"total"` and `"day"` are supported.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum AggregatedBy {
-    #[serde(rename = "day")]
-    Day,
-    #[serde(rename = "total")]
-    Total,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for AggregatedBy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            AggregatedBy::Day => "day",
-            AggregatedBy::Total => "total",
-            AggregatedBy::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for AggregatedBy {
-    fn default() -> AggregatedBy {
-        AggregatedBy::Total
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum GroupBy {
-    #[serde(rename = "ab_phase")]
-    AbPhase,
-    #[serde(rename = "ab_variation")]
-    AbVariation,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for GroupBy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            GroupBy::AbPhase => "ab_phase",
-            GroupBy::AbVariation => "ab_variation",
-            GroupBy::Noop => "",
-            GroupBy::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for GroupBy {
-    fn default() -> GroupBy {
-        GroupBy::Noop
-    }
-}
-impl GroupBy {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GroupBy::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum TraitAutomationQueryParamsGroupBy {
-    #[serde(rename = "step_id")]
-    StepId,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for TraitAutomationQueryParamsGroupBy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            TraitAutomationQueryParamsGroupBy::StepId => "step_id",
-            TraitAutomationQueryParamsGroupBy::Noop => "",
-            TraitAutomationQueryParamsGroupBy::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for TraitAutomationQueryParamsGroupBy {
-    fn default() -> TraitAutomationQueryParamsGroupBy {
-        TraitAutomationQueryParamsGroupBy::Noop
-    }
-}
-impl TraitAutomationQueryParamsGroupBy {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, TraitAutomationQueryParamsGroupBy::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum AbPhaseId {
-    #[serde(rename = "send")]
-    Send,
-    #[serde(rename = "test")]
-    Test,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for AbPhaseId {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            AbPhaseId::Send => "send",
-            AbPhaseId::Test => "test",
-            AbPhaseId::Noop => "",
-            AbPhaseId::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for AbPhaseId {
-    fn default() -> AbPhaseId {
-        AbPhaseId::Noop
-    }
-}
-impl AbPhaseId {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AbPhaseId::Noop)
-    }
-}
-
-/**
- * How to group the statistics. Must be either "day", "week", or "month".
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum TraitStatsAdvancedBaseQueryStringsAggregatedBy {
-    #[serde(rename = "day")]
-    Day,
-    #[serde(rename = "month")]
-    Month,
-    #[serde(rename = "week")]
-    Week,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Day => "day",
-            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Month => "month",
-            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Week => "week",
-            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop => "",
-            TraitStatsAdvancedBaseQueryStringsAggregatedBy::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
-    fn default() -> TraitStatsAdvancedBaseQueryStringsAggregatedBy {
-        TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop
-    }
-}
-impl TraitStatsAdvancedBaseQueryStringsAggregatedBy {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, TraitStatsAdvancedBaseQueryStringsAggregatedBy::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TraitPagination200Response {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<Metadata>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TraitGlobalErrors500Response {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMessagesMsgResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<TraitGlobalErrors500Response>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TraitCancelScheduledSendsErrors400Response {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub field: String,
-    /**
-     * helper text or docs for troubleshooting
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub help: Option<Help>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TraitCancelScheduledSendsErrors400ResponseData {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<TraitCancelScheduledSendsErrors400Response>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TraitMakoErrorResponse400Errors {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub error_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub parameter: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TraitMakoErrorResponse400 {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<TraitMakoErrorResponse400Errors>,
-}
-
-#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Personalizations {
-    /**
-     * An array of recipients who will receive a copy of your email. Each object in this array must contain the recipient's email address. Each object in the array may optionally contain the recipient's name.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub bcc: Vec<CcBccEmailObject>,
-    /**
-     * An array of recipients who will receive a copy of your email. Each object in this array must contain the recipient's email address. Each object in the array may optionally contain the recipient's name.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub cc: Vec<CcBccEmailObject>,
-    /**
-     * helper text or docs for troubleshooting
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub custom_args: Option<Help>,
-    /**
-     * helper text or docs for troubleshooting
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub dynamic_template_data: Option<Help>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub from: Option<FromEmailObject>,
-    /**
-     * helper text or docs for troubleshooting
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub headers: Option<Help>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub send_at: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subject: String,
-    /**
-     * helper text or docs for troubleshooting
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub substitutions: Option<Help>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub to: Vec<ReplyTo>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Content {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
-}
-
-/**
- * The attachment's content-disposition, specifying how you would like the attachment to be displayed. For example, `“inline”` results in the attached file are displayed automatically within the message while `“attachment”` results in the attached file require some action to be taken before it is displayed, such as opening or downloading the file.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Disposition {
-    #[serde(rename = "attachment")]
-    Attachment,
-    #[serde(rename = "inline")]
-    Inline,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Disposition {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Disposition::Attachment => "attachment",
-            Disposition::Inline => "inline",
-            Disposition::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Disposition {
-    fn default() -> Disposition {
-        Disposition::Attachment
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Attachments {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub content: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub content_id: String,
-    /**
-     * The attachment's content-disposition, specifying how you would like the attachment to be displayed. For example, `“inline”` results in the attached file are displayed automatically within the message while `“attachment”` results in the attached file require some action to be taken before it is displayed, such as opening or downloading the file.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub disposition: Option<Disposition>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub filename: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-/// An object allowing you to specify how to handle unsubscribes.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Asm {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub group_id: i64,
-    /**
-     * The IDs of the lists you are sending this campaign to. You can have both segment IDs and list IDs
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub groups_to_display: Vec<i64>,
-}
-
-/// Allows you to bypass all unsubscribe groups and suppressions to ensure that the email is delivered to every single recipient. This should only be used in emergencies when it is absolutely necessary that every recipient receives your email. This filter cannot be combined with any other bypass filters. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BypassListManagement {
-    /**
-     * Allows you to bypass all unsubscribe groups and suppressions to ensure that the email is delivered to every single recipient. This should only be used in emergencies when it is absolutely necessary that every recipient receives your email. This filter cannot be combined with any other bypass filters. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enable: bool,
-}
-
-/// Allows you to bypass the spam report list to ensure that the email is delivered to recipients. Bounce and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BypassSpamManagement {
-    /**
-     * Allows you to bypass the spam report list to ensure that the email is delivered to recipients. Bounce and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enable: bool,
-}
-
-/// Allows you to bypass the bounce list to ensure that the email is delivered to recipients. Spam report and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BypassBounceManagement {
-    /**
-     * Allows you to bypass the bounce list to ensure that the email is delivered to recipients. Spam report and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enable: bool,
-}
-
-/// Allows you to bypass the global unsubscribe list to ensure that the email is delivered to recipients. Bounce and spam report lists will still be checked; addresses on these other lists will not receive the message. This filter applies only to global unsubscribes and will not bypass group unsubscribes. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BypassUnsubscribeManagement {
-    /**
-     * Allows you to bypass the global unsubscribe list to ensure that the email is delivered to recipients. Bounce and spam report lists will still be checked; addresses on these other lists will not receive the message. This filter applies only to global unsubscribes and will not bypass group unsubscribes. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enable: bool,
-}
-
-/// The default footer that you would like included on every email.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Footer {
-    /**
-     * The default footer that you would like included on every email.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enable: bool,
-    /**
-     * The default footer that you would like included on every email.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub html: String,
-    /**
-     * The default footer that you would like included on every email.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub text: String,
-}
-
-/// Sandbox Mode allows you to send a test email to ensure that your request body is valid and formatted correctly.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SandboxMode {
-    /**
-     * Sandbox Mode allows you to send a test email to ensure that your request body is valid and formatted correctly.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enable: bool,
-}
-
-/// A collection of different mail settings that you can use to specify how you would like this email to be handled.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MailSettings {
-    /**
-     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub bypass_bounce_management: Option<BypassBounceManagement>,
-    /**
-     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub bypass_list_management: Option<BypassListManagement>,
-    /**
-     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub bypass_spam_management: Option<BypassSpamManagement>,
-    /**
-     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub bypass_unsubscribe_management: Option<BypassUnsubscribeManagement>,
-    /**
-     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub footer: Option<Footer>,
-    /**
-     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub sandbox_mode: Option<SandboxMode>,
-}
-
-/// Allows you to track if a recipient clicked a link in your email.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMailSendRequestTrackingSettingsClick {
-    /**
-     * Allows you to track if a recipient clicked a link in your email.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enable: bool,
-    /**
-     * Allows you to track if a recipient clicked a link in your email.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enable_text: bool,
-}
-
-/// Allows you to track if the email was opened by including a single pixel image in the body of the content. When the pixel is loaded, Twilio SendGrid can log that the email was opened.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OpenTracking {
-    /**
-     * Allows you to track if the email was opened by including a single pixel image in the body of the content. When the pixel is loaded, Twilio SendGrid can log that the email was opened.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enable: bool,
-    /**
-     * Allows you to track if the email was opened by including a single pixel image in the body of the content. When the pixel is loaded, Twilio SendGrid can log that the email was opened.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub substitution_tag: String,
-}
-
-/// Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SubscriptionTracking {
-    /**
-     * Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enable: bool,
-    /**
-     * Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub html: String,
-    /**
-     * Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub substitution_tag: String,
-    /**
-     * Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub text: String,
-}
-
-/// Allows you to enable tracking provided by Google Analytics.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Ganalytics {
-    /**
-     * Allows you to enable tracking provided by Google Analytics.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enable: bool,
-    /**
-     * Allows you to enable tracking provided by Google Analytics.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub utm_campaign: String,
-    /**
-     * Allows you to enable tracking provided by Google Analytics.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub utm_content: String,
-    /**
-     * Allows you to enable tracking provided by Google Analytics.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub utm_medium: String,
-    /**
-     * Allows you to enable tracking provided by Google Analytics.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub utm_source: String,
-    /**
-     * Allows you to enable tracking provided by Google Analytics.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub utm_term: String,
-}
-
-/// Settings to determine how you would like to track the metrics of how your recipients interact with your email.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct TrackingSettings {
-    /**
-     * Settings to determine how you would like to track the metrics of how your recipients interact with your email.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub click_tracking: Option<PostMailSendRequestTrackingSettingsClick>,
-    /**
-     * Settings to determine how you would like to track the metrics of how your recipients interact with your email.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub ganalytics: Option<Ganalytics>,
-    /**
-     * Settings to determine how you would like to track the metrics of how your recipients interact with your email.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub open_tracking: Option<OpenTracking>,
-    /**
-     * Settings to determine how you would like to track the metrics of how your recipients interact with your email.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub subscription_tracking: Option<SubscriptionTracking>,
-}
-
-#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMailSendRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub asm: Option<Asm>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub attachments: Vec<Attachments>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub batch_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub categories: Vec<String>,
-    /**
-     * An array where you can specify the content of your email. You can include multiple [MIME types](https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/MIME_types) of content, but you must specify at least one MIME type. To include more than one MIME type, add another object to the array containing the `type` and `value` parameters.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub content: Vec<Content>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub custom_args: String,
-    #[serde()]
-    pub from: FromEmailObject,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub headers: Option<Help>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip_pool_name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub mail_settings: Option<MailSettings>,
-    /**
-     * An array of messages and their metadata. Each object within personalizations can be thought of as an envelope - it defines who should receive an individual message and how that message should be handled. See our [Personalizations documentation](https://sendgrid.com/docs/for-developers/sending-email/personalizations/) for examples.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub personalizations: Vec<Personalizations>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub reply_to: Option<ReplyEmailObject>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub send_at: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subject: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub template_id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub tracking_settings: Option<TrackingSettings>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CancelPauseAScheduledSendRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub batch_id: String,
-    /**
-     * The status of the scheduled send.
-     */
-    #[serde(default, skip_serializing_if = "Status::is_noop")]
-    pub status: Status,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetApiKeysResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<ApiKeyNameId>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CreateApiKeysRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct CreateApiKeysResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub api_key: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub api_key_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetApiKeysKeyResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<ApiKeyNameScopesAllOf>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutApiKeysKeyRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetScopesResponseErrors {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub field: Option<serde_json::Value>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetScopesResponseData {
-    /**
-     * This 401 response indicates that the user making the call doesn't have the authorization to view the list of scopes.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<GetScopesResponseErrors>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Ips {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostAccessSettingsWhitelistRequest {
-    /**
-     * An array containing the IP(s) you want to allow.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ips: Vec<Ips>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DeleteAccessSettingsWhitelistRequest {
-    /**
-     * The IDs of the lists you are sending this campaign to. You can have both segment IDs and list IDs
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ids: Vec<i64>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetAccessSettingsActivityResponseResult {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub allowed: bool,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub auth_method: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub first_at: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub last_at: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub location: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetAccessSettingsActivityResponse {
-    /**
-     * An array containing the IPs that recently attempted to access your account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<GetAccessSettingsActivityResponseResult>,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostSsoCertificatesRequest {
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub enabled: Option<bool>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub integration_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub public_certificate: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchSsoCertificatesCertRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub enabled: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub integration_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub public_certificate: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchSsoTeammatesUsernameRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub is_admin: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMailSettingsResponseResult {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enabled: bool,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMailSettingsResponse {
-    /**
-     * The list of all mail settings.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<GetMailSettingsResponseResult>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchMailSettingsAddressWhitelistRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub enabled: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub list: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchMailSettingsTemplateRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub enabled: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub html_content: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchMailSettingsTemplateResponse {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enabled: bool,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub html_content: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchPartnerSettingsNewRelicRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub enable_subuser_statistics: Option<bool>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub enabled: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub license_key: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetPartnerSettingsResponse {
-    /**
-     * The list of all mail settings.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<GetMailSettingsResponseResult>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetTeammatesResponseResult {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address2"
-    )]
-    pub address_2: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_admin: bool,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-    /**
-     * A Teammate can be an “admin,” “owner,” or “teammate.” Each role is associated with the scope of the Teammate’s permissions.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub user_type: Option<UserType>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub website: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zip: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetTeammatesResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<GetTeammatesResponseResult>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostTeammatesRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub is_admin: Option<bool>,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostTeammatesResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_admin: bool,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub token: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostTeammatesResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostSendersResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<PostTeammatesResponseErrors>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetScopesRequestsResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub scope_group_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetTeammatesPendingResponseResult {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub expiration_date: i64,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_admin: bool,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub token: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetTeammatesPendingResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<GetTeammatesPendingResponseResult>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetTeammatesUsernameResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address2"
-    )]
-    pub address_2: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_admin: bool,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-    /**
-     * A Teammate can be an “admin,” “owner,” or “teammate.” Each role is associated with the scope of the Teammate’s permissions.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub user_type: Option<UserType>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub website: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zip: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchTeammatesUsernameRequest {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub is_admin: Option<bool>,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchScopesRequestsApproveResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub scope_group_name: String,
-}
-
-/**
- * The type of alert.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum GetAlertsResponseType {
-    #[serde(rename = "stats_notification")]
-    StatsNotification,
-    #[serde(rename = "usage_limit")]
-    UsageLimit,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for GetAlertsResponseType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            GetAlertsResponseType::StatsNotification => "stats_notification",
-            GetAlertsResponseType::UsageLimit => "usage_limit",
-            GetAlertsResponseType::Noop => "",
-            GetAlertsResponseType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for GetAlertsResponseType {
-    fn default() -> GetAlertsResponseType {
-        GetAlertsResponseType::Noop
-    }
-}
-impl GetAlertsResponseType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetAlertsResponseType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetAlertsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub created_at: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email_to: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub frequency: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub percentage: i64,
-    /**
-     * The type of alert.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "GetAlertsResponseType::is_noop",
-        rename = "type"
-    )]
-    pub type_: GetAlertsResponseType,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub updated_at: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostAlertsRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email_to: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub frequency: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub percentage: i64,
-    /**
-     * The type of alert.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "GetAlertsResponseType::is_noop",
-        rename = "type"
-    )]
-    pub type_: GetAlertsResponseType,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostAlertsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub created_at: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email_to: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub frequency: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub percentage: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub updated_at: i64,
-}
-
-/**
- * The type of alert.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum GetAlertsAlertResponseType {
-    #[serde(rename = "stats_notification")]
-    StatsNotification,
-    #[serde(rename = "usage_alert")]
-    UsageAlert,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for GetAlertsAlertResponseType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            GetAlertsAlertResponseType::StatsNotification => "stats_notification",
-            GetAlertsAlertResponseType::UsageAlert => "usage_alert",
-            GetAlertsAlertResponseType::Noop => "",
-            GetAlertsAlertResponseType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for GetAlertsAlertResponseType {
-    fn default() -> GetAlertsAlertResponseType {
-        GetAlertsAlertResponseType::Noop
-    }
-}
-impl GetAlertsAlertResponseType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetAlertsAlertResponseType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetAlertsAlertResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub created_at: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email_to: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub frequency: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub percentage: i64,
-    /**
-     * The type of alert.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "GetAlertsAlertResponseType::is_noop",
-        rename = "type"
-    )]
-    pub type_: GetAlertsAlertResponseType,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub updated_at: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchAlertsAlertRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email_to: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub frequency: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub percentage: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetUserProfileResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address2"
-    )]
-    pub address_2: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub company: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub website: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zip: String,
-}
-
-/**
- * The type of account for this user.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum GetUserAccountResponseType {
-    #[serde(rename = "free")]
-    Free,
-    #[serde(rename = "paid")]
-    Paid,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for GetUserAccountResponseType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            GetUserAccountResponseType::Free => "free",
-            GetUserAccountResponseType::Paid => "paid",
-            GetUserAccountResponseType::Noop => "",
-            GetUserAccountResponseType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for GetUserAccountResponseType {
-    fn default() -> GetUserAccountResponseType {
-        GetUserAccountResponseType::Noop
-    }
-}
-impl GetUserAccountResponseType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetUserAccountResponseType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetUserAccountResponse {
-    /**
-     * The number of errors found while adding recipients.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub reputation: f64,
-    /**
-     * The type of account for this user.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "GetUserAccountResponseType::is_noop",
-        rename = "type"
-    )]
-    pub type_: GetUserAccountResponseType,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetUserEmailResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutUserEmailRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutUserUsernameRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutUserUsernameResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetUserCreditsResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_reset: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub next_reset: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub overage: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub remain: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reset_frequency: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub total: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub used: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutUserPasswordRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub new_password: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub old_password: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostSubusersRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The IP addresses that should be assigned to this subuser.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ips: Vec<std::net::Ipv4Addr>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub password: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchSubusersSubuserNameRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub disabled: Option<bool>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetSubusersReputationsResponse {
-    /**
-     * The number of errors found while adding recipients.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub reputation: f64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutSubusersSubuserNameIpsResponse {
-    /**
-     * The IP addresses that should be assigned to this subuser.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ips: Vec<std::net::Ipv4Addr>,
-}
-
-/**
- * The direction you want to sort.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum SortByDirection {
-    #[serde(rename = "asc")]
-    Asc,
-    #[serde(rename = "desc")]
-    Desc,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for SortByDirection {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            SortByDirection::Asc => "asc",
-            SortByDirection::Desc => "desc",
-            SortByDirection::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for SortByDirection {
-    fn default() -> SortByDirection {
-        SortByDirection::Desc
-    }
-}
-
-/**
- * The metric that you want to sort by. Metrics that you can sort by are: `blocks`, `bounces`, `clicks`, `delivered`, `opens`, `requests`, `unique_clicks`, `unique_opens`, and `unsubscribes`.'
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum SortByMetric {
-    #[serde(rename = "blocks")]
-    Blocks,
-    #[serde(rename = "bounces")]
-    Bounces,
-    #[serde(rename = "clicks")]
-    Clicks,
-    #[serde(rename = "delivered")]
-    Delivered,
-    #[serde(rename = "opens")]
-    Opens,
-    #[serde(rename = "requests")]
-    Requests,
-    #[serde(rename = "unique_clicks")]
-    UniqueClicks,
-    #[serde(rename = "unique_opens")]
-    UniqueOpens,
-    #[serde(rename = "unsubscribes")]
-    Unsubscribes,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for SortByMetric {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            SortByMetric::Blocks => "blocks",
-            SortByMetric::Bounces => "bounces",
-            SortByMetric::Clicks => "clicks",
-            SortByMetric::Delivered => "delivered",
-            SortByMetric::Opens => "opens",
-            SortByMetric::Requests => "requests",
-            SortByMetric::UniqueClicks => "unique_clicks",
-            SortByMetric::UniqueOpens => "unique_opens",
-            SortByMetric::Unsubscribes => "unsubscribes",
-            SortByMetric::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for SortByMetric {
-    fn default() -> SortByMetric {
-        SortByMetric::Delivered
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelLinksRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub default: Option<bool>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub domain: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subdomain: String,
-}
-
-/// The DNS record generated for the sending domain used for this branded link.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelLinksValidateResponseValidationResultsDomainCname {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub valid: bool,
-}
-
-/// The individual validation results for each of the DNS records associated with this branded link.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ValidationResults {
-    /**
-     * The DNS record generated for the sending domain used for this branded link.
-     */
-    #[serde()]
-    pub domain_cname: PostWhitelabelLinksValidateResponseValidationResultsDomainCname,
-    /**
-     * The individual validation results for each of the DNS records associated with this branded link.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub owner_cname: Option<PostWhitelabelLinksValidateResponseValidationResultsDomainCname>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelLinksValidateResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub valid: bool,
-    /**
-     * The individual validation results for each of the DNS records associated with this branded link.
-     */
-    #[serde()]
-    pub validation_results: ValidationResults,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelLinksValidateResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingSegmentsResponse {
-    /**
-     * The reasons why the validation failed.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<PostWhitelabelLinksValidateResponseErrors>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelLinksLinkSubuserRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchWhitelabelLinksRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub default: Option<bool>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostIpsWarmupRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostIpsWarmupResponseErrors {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub field: Option<serde_json::Value>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostIpsWarmupResponse {
-    /**
-     * The errors that were encountered.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<PostIpsWarmupResponseErrors>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelIpsRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub domain: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subdomain: String,
-}
-
-/// The specific results of the validation.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelIpsValidateResponseValidationResults {
-    /**
-     * The specific results of the validation.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub a_record: Option<PostWhitelabelLinksValidateResponseValidationResultsDomainCname>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelIpsValidateResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub valid: bool,
-    /**
-     * The specific results of the validation.
-     */
-    #[serde()]
-    pub validation_results: PostWhitelabelIpsValidateResponseValidationResults,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostValidationsEmailRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub source: String,
-}
-
-/**
- * A generic classification of whether or not the email address is valid.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Verdict {
-    #[serde(rename = "Invalid")]
-    Invalid,
-    #[serde(rename = "Risky")]
-    Risky,
-    #[serde(rename = "Valid")]
-    Valid,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Verdict {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Verdict::Invalid => "Invalid",
-            Verdict::Risky => "Risky",
-            Verdict::Valid => "Valid",
-            Verdict::Noop => "",
-            Verdict::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Verdict {
-    fn default() -> Verdict {
-        Verdict::Noop
-    }
-}
-impl Verdict {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Verdict::Noop)
-    }
-}
-
-/// Checks on the domain portion of the email address.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Domain {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub has_mx_or_a_record: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub has_valid_address_syntax: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_suspected_disposable_address: bool,
-}
-
-/// Checks on the local part of the email address.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct LocalPart {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_suspected_role_address: bool,
-}
-
-/// Additional checks on the email address.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Additional {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub has_known_bounces: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub has_suspected_bounces: bool,
-}
-
-/// Granular checks for email address validity.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Checks {
-    /**
-     * Additional checks on the email address.
-     */
-    #[serde()]
-    pub additional: Additional,
-    /**
-     * Checks on the domain portion of the email address.
-     */
-    #[serde()]
-    pub domain: Domain,
-    /**
-     * Checks on the local part of the email address.
-     */
-    #[serde()]
-    pub local_part: LocalPart,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostValidationsEmailResponseResult {
-    /**
-     * Granular checks for email address validity.
-     */
-    #[serde()]
-    pub checks: Checks,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub host: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip_address: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub local: String,
-    /**
-     * The number of errors found while adding recipients.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub score: f64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub source: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub suggestion: String,
-    /**
-     * A generic classification of whether or not the email address is valid.
-     */
-    #[serde(default, skip_serializing_if = "Verdict::is_noop")]
-    pub verdict: Verdict,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostValidationsEmailResponse {
-    #[serde()]
-    pub result: PostValidationsEmailResponseResult,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelDnsEmailRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub domain_id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub link_id: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelDnsEmailResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub error: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub field: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelDnsEmailResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub errors: Option<PostWhitelabelDnsEmailResponseErrors>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetIpsAssignedResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub pools: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub start_date: i64,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub warmup: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetIpsPoolsPoolNameResponse {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ips: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub pool_name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutIpsPoolsPoolNameRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DeleteIpsPoolsPoolNameResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub error: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetIpsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub assigned_at: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub pools: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub rdns: String,
-    /**
-     * The number of errors found while adding recipients.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub start_date: f64,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub subusers: Vec<String>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub warmup: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub whitelabeled: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostIpsRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub count: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub subusers: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub warmup: Option<bool>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostIpsResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub subusers: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostIpsResponseData {
-    /**
-     * List of IP objects.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ips: Vec<PostIpsResponse>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub remaining_ips: i64,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub warmup: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetIpsRemainingResponseResults {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub period: String,
-    /**
-     * The number of errors found while adding recipients.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub price_per_ip: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub remaining: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetIpsRemainingResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub results: Vec<GetIpsRemainingResponseResults>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetIpsIpAddressResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ip: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub pools: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub rdns: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub start_date: i64,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub subusers: Vec<String>,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub warmup: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub whitelabeled: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelDomainsRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub automatic_security: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub custom_dkim_selector: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub custom_spf: Option<bool>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub default: Option<bool>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub domain: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ips: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subdomain: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub username: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchWhitelabelDomainsDomainRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub custom_spf: Option<bool>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub default: Option<bool>,
-}
-
-/// The CNAME record for the authenticated domain.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MailCname {
-    /**
-     * The CNAME record for the authenticated domain.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
-    /**
-     * The CNAME record for the authenticated domain.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub valid: bool,
-}
-
-/// A DNS record for this authenticated domain.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Dkim1 {
-    /**
-     * A DNS record for this authenticated domain.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
-    /**
-     * A DNS record for this authenticated domain.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub valid: bool,
-}
-
-/// The SPF record for the authenticated domain.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Spf {
-    /**
-     * The SPF record for the authenticated domain.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
-    /**
-     * The SPF record for the authenticated domain.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub valid: bool,
-}
-
-/// The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelDomainsValidateResponseValidationResults {
-    /**
-     * The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "dkim1")]
-    pub dkim_1: Option<Dkim1>,
-    /**
-     * The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "dkim2")]
-    pub dkim_2: Option<Dkim1>,
-    /**
-     * The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub mail_cname: Option<MailCname>,
-    /**
-     * The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub spf: Option<Spf>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelDomainsValidateResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub valid: bool,
-    /**
-     * The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub validation_results: Option<PostWhitelabelDomainsValidateResponseValidationResults>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostWhitelabelDomainsValidateResponseData {
-    /**
-     * The reasons why the validation failed.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<PostWhitelabelLinksValidateResponseErrors>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetVerifiedSendersDomainsResponseResults {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub hard_failures: Vec<String>,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub soft_failures: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetVerifiedSendersDomainsResponse {
-    #[serde()]
-    pub results: GetVerifiedSendersDomainsResponseResults,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetVerifiedSendersStepsCompletedResponseResults {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub domain_verified: bool,
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub sender_verified: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetVerifiedSendersStepsCompletedResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub results: Option<GetVerifiedSendersStepsCompletedResponseResults>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetVerifiedSendersResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub results: Vec<VerifiedSenderResponseSchema>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostVerifiedSendersResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub error_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub field: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostVerifiedSendersResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<PostVerifiedSendersResponseErrors>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetVerifiedSendersVerifyTokenResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub error_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchVerifiedSendersResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<GetVerifiedSendersVerifyTokenResponseErrors>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DeleteVerifiedSendersResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<GetVerifiedSendersVerifyTokenResponseErrors>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutDesignRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub categories: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub generate_plain_content: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub html_content: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub plain_content: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub subject: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ListDesignsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<MetadataType>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<DesignOutputSummaryAllOf>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMcContatsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<SelfMetadata>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub contact_count: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<ContactDetails3>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMcListsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<Error>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutMcContactsRequest {
-    /**
-     * One or more contacts objects that you intend to upsert. The available fields for a contact, including the required `email` field are described below.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub contacts: Vec<ContactRequest>,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub list_ids: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutMcContactsResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub job_id: String,
-}
-
-/// The deletion job has been accepted and is being processed.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DeleteMcContactsResponse {
-    /**
-     * helper text or docs for troubleshooting
-     */
-    #[serde()]
-    pub job_id: Help,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DeleteMcContactsResponseData {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<Help>,
-}
-
-/// `billable_breakdown` will only appear to the parent user in an account with subusers.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct BillableBreakdown {
-    /**
-     * `billable_breakdown` will only appear to the parent user in an account with subusers.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub breakdown: Option<Help>,
-    /**
-     * `billable_breakdown` will only appear to the parent user in an account with subusers.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub total: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMcContactsCountResponse {
-    /**
-     * `billable_breakdown` will only appear to the parent user in an account with subusers.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub billable_breakdown: Option<BillableBreakdown>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub billable_count: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub contact_count: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Lists {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "ID"
-    )]
-    pub id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "Name"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMarketingContactsExportsResponseResultMetadata {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub next: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub prev: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "self"
-    )]
-    pub self_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMarketingContactsExportsResponseResult {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<GetMarketingContactsExportsResponseResultMetadata>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub completed_at: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub created_at: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub expires_at: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub export_type: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub lists: Vec<Lists>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub segments: Vec<Lists>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub status: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub urls: Vec<String>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub user_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMarketingContactsExportsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<GetMarketingContactsExportsResponseResultMetadata>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<GetMarketingContactsExportsResponseResult>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMarketingContactsExportsResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub error_id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub message: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMarketingContactsExportsResponseData {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<GetMarketingContactsExportsResponseErrors>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Notifications {
-    /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub email: bool,
-}
-
-/**
- * File type for export file. Choose from `json` or `csv`.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum FileType {
-    #[serde(rename = "csv")]
-    Csv,
-    #[serde(rename = "json")]
-    Json,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for FileType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            FileType::Csv => "csv",
-            FileType::Json => "json",
-            FileType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for FileType {
-    fn default() -> FileType {
-        FileType::Csv
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMcContactsExportsRequest {
-    /**
-     * File type for export file. Choose from `json` or `csv`.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub file_type: Option<FileType>,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub list_ids: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub max_file_size: i64,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub notifications: Option<Notifications>,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub segment_ids: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMcContactsExportsResponse {
-    #[serde(rename = "_metadata")]
-    pub metadata: Metadata,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMcContactsSearchRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub query: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMcContactsSearchResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<SelfMetadata>,
-    /**
-     * The number of errors found while adding recipients.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub contact_count: f64,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<ContactDetails3>,
-}
-
-/**
- * Upload file type.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PutMcContactsImportsRequestFileType {
-    #[serde(rename = "csv")]
-    Csv,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for PutMcContactsImportsRequestFileType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PutMcContactsImportsRequestFileType::Csv => "csv",
-            PutMcContactsImportsRequestFileType::Noop => "",
-            PutMcContactsImportsRequestFileType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for PutMcContactsImportsRequestFileType {
-    fn default() -> PutMcContactsImportsRequestFileType {
-        PutMcContactsImportsRequestFileType::Noop
-    }
-}
-impl PutMcContactsImportsRequestFileType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PutMcContactsImportsRequestFileType::Noop)
-    }
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `String`
-/// - `serde_json::Value`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct FieldMappingsAnyOf {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(flatten)]
-    pub string: String,
-    #[serde(flatten)]
-    pub value: serde_json::Value,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutMcContactsImportsRequest {
-    /**
-     * Import file header to reserved/custom field mapping.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub field_mappings: Vec<FieldMappingsAnyOf>,
-    /**
-     * Upload file type.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "PutMcContactsImportsRequestFileType::is_noop"
-    )]
-    pub file_type: PutMcContactsImportsRequestFileType,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub list_ids: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UploadHeaders {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub header: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutMcContactsImportsResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub job_id: String,
-    /**
-     * A list of headers that must be included in PUT request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub upload_headers: Vec<UploadHeaders>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub upload_uri: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutMcContactsImportsResponseData {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<Error>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMarketingContactsImportsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub errors: Option<Error>,
-}
-
-/// Array of IDs
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingContactsBatchRequest {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ids: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingContactsBatchResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<ContactDetails3>,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingContactsSearchEmailsRequest {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub emails: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingContactsSearchEmailsResponse {
-    /**
-     * helper text or docs for troubleshooting
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub result: Option<Help>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingSendersRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub address_2: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
-    #[serde()]
-    pub from: From,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub nickname: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub reply_to: Option<ReplyTo>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zip: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMcListsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<Metadata>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<List>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMcListsContactsCountResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub billable_count: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub contact_count: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMcListsResponseData {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub contact_sample: Option<ContactDetails2>,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `List`
-/// - `GetMcListsResponseData`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMcListsResponseAllOf {
-    #[serde(flatten)]
-    pub list: List,
-    #[serde(flatten)]
-    pub get_mc_lists_response_data: GetMcListsResponseData,
-}
-
-/// The delete has been accepted and is processing.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DeleteListsResponse {
-    /**
-     * The delete has been accepted and is processing.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub job_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchMcListsRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-/// The removal is accepted and processing.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DeleteMcListsContactsResponse {
-    /**
-     * The removal is accepted and processing.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub job_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMcFieldDefinitionsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<MetadataType>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub custom_fields: Vec<CustomFieldDefinitionsResponse>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub reserved_fields: Vec<ReservedFieldDefinitionsResponse>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMcFieldDefinitionsRequest {
-    #[serde(default, skip_serializing_if = "FieldType::is_noop")]
-    pub field_type: FieldType,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMcFieldDefinitionsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<MetadataType>,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `CustomFieldDefinitionsResponse`
-/// - `PostMcFieldDefinitionsResponse`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMcFieldDefinitionsResponseAllOf {
-    #[serde(flatten)]
-    pub custom_field_definitions_response: CustomFieldDefinitionsResponse,
-    #[serde(flatten)]
-    pub post_mc_field_definitions_response: PostMcFieldDefinitionsResponse,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMarketingSegmentsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub results: Vec<SegmentSummary>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingSegmentsRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub parent_list_id: String,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `SegmentWriteV2`
-/// - `PostMarketingSegmentsRequest`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingSegmentsRequestAllOf {
-    #[serde(flatten)]
-    pub segment_write_v2: SegmentWriteV2,
-    #[serde(flatten)]
-    pub post_marketing_segments_request: PostMarketingSegmentsRequest,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingSegmentsDeleteRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ids: Vec<String>,
-}
-
-/// resources in which segment is being used
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Resources {
-    /**
-     * resources in which segment is being used
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub ids: Vec<String>,
-    /**
-     * resources in which segment is being used
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingSegmentsDeleteResponseErrors {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub error: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * resources in which segment is being used
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub resources: Option<Resources>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingSegmentsDeleteResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<PostMarketingSegmentsDeleteResponseErrors>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMarketingSinglesendsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<MetadataType>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<SinglesendResponseShort>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingSinglesendsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<SsoErrorResponse>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingSinglesendsRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-/**
- * This is the ISO 8601 time at which to send the Single Send; must be in future, or the string "now"
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum SendAt {
-    #[serde(rename = "now")]
-    Now,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for SendAt {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            SendAt::Now => "now",
-            SendAt::Noop => "",
-            SendAt::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for SendAt {
-    fn default() -> SendAt {
-        SendAt::Noop
-    }
-}
-impl SendAt {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SendAt::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutMarketingSinglesendsScheduleRequest {
-    /**
-     * This is the ISO 8601 time at which to send the Single Send; must be in future, or the string "now"
-     */
-    #[serde(default, skip_serializing_if = "SendAt::is_noop")]
-    pub send_at: SendAt,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PutMarketingSinglesendsScheduleResponseStatus {
-    #[serde(rename = "scheduled")]
-    Scheduled,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for PutMarketingSinglesendsScheduleResponseStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PutMarketingSinglesendsScheduleResponseStatus::Scheduled => "scheduled",
-            PutMarketingSinglesendsScheduleResponseStatus::Noop => "",
-            PutMarketingSinglesendsScheduleResponseStatus::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for PutMarketingSinglesendsScheduleResponseStatus {
-    fn default() -> PutMarketingSinglesendsScheduleResponseStatus {
-        PutMarketingSinglesendsScheduleResponseStatus::Noop
-    }
-}
-impl PutMarketingSinglesendsScheduleResponseStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PutMarketingSinglesendsScheduleResponseStatus::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PutMarketingSinglesendsScheduleResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub send_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<PutMarketingSinglesendsScheduleResponseStatus>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMarketingSinglesendsCategoriesResponse {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub categories: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMarketingTestSendEmailRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub custom_unsubscribe_url: String,
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub emails: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub from_address: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub sender_id: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub suppression_group_id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub template_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub version_id_override: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetSendersResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub result: Vec<SenderAllOf>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostSendersRequest {
-    /**
-     * helper text or docs for troubleshooting
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub from: Option<Help>,
-    /**
-     * helper text or docs for troubleshooting
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub reply_to: Option<Help>,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `SenderRequest`
-/// - `PostSendersRequest`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostSendersRequestAllOf {
-    #[serde(flatten)]
-    pub sender_request: SenderRequest,
-    #[serde(flatten)]
-    pub post_senders_request: PostSendersRequest,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ListAllListsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub lists: Vec<ContactdbList>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchContactdbListsListResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub recipient_count: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetContactdbRecipientsSearchResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub recipients: Vec<ContactdbRecipient>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ListRecipientsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub recipients: Vec<Help>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostContactdbRecipientsRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub age: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchContactdbRecipientsRequest {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
+#[serde(from = "String", into = "String")]
+pub enum MessageStatus {
+    #[serde(rename = "delivered")]
+    Delivered,
+    #[serde(rename = "not delivered")]
+    NotDelivered,
+    #[serde(rename = "processed")]
+    Processed,
     /**
-     * The license key provided with your New Relic account.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_name: String,
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetContactdbStatusResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
+impl AsRef<str> for MessageStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            MessageStatus::Delivered => "delivered",
+            MessageStatus::NotDelivered => "not delivered",
+            MessageStatus::Processed => "processed",
+            MessageStatus::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetContactdbStatusResponseData {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub status: Vec<GetContactdbStatusResponse>,
+impl std::fmt::Display for MessageStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetContactdbRecipientsRecipientListsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub lists: Vec<ContactdbList>,
+impl std::str::FromStr for MessageStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "delivered" => MessageStatus::Delivered,
+            "not delivered" => MessageStatus::NotDelivered,
+            "processed" => MessageStatus::Processed,
+            _ => MessageStatus::Unknown(s.to_string()),
+        })
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostContactdbRecipientsSearchRequest {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub conditions: Vec<String>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub list_id: i64,
+impl std::convert::From<String> for MessageStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
 }
 
-/// All of the following types are flattened into one object:
-///
-/// - `String`
-/// - `i64`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ValueAnyOf {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(flatten)]
-    pub string: String,
-    #[serde(flatten)]
-    pub i64: i64,
+impl std::convert::From<MessageStatus> for String {
+    fn from(v: MessageStatus) -> Self {
+        match v {
+            MessageStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
+/**
+ * Name of event
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostContactdbRecipientsSearchResponseCustomFields {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+#[serde(from = "String", into = "String")]
+pub enum EventName {
+    #[serde(rename = "bounced")]
+    Bounced,
+    #[serde(rename = "clicked")]
+    Clicked,
+    #[serde(rename = "deferred")]
+    Deferred,
+    #[serde(rename = "delivered")]
+    Delivered,
+    #[serde(rename = "dropped")]
+    Dropped,
+    #[serde(rename = "group_resubscribe")]
+    GroupResubscribe,
+    #[serde(rename = "group_unsubscribe")]
+    GroupUnsubscribe,
+    #[serde(rename = "opened")]
+    Opened,
+    #[serde(rename = "processed")]
+    Processed,
+    #[serde(rename = "spam_report")]
+    SpamReport,
+    #[serde(rename = "unsubscribe")]
+    Unsubscribe,
     /**
-     * The license key provided with your New Relic account.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub value: Option<ValueAnyOf>,
+    Unknown(String),
+}
+
+impl AsRef<str> for EventName {
+    fn as_ref(&self) -> &str {
+        match self {
+            EventName::Bounced => "bounced",
+            EventName::Clicked => "clicked",
+            EventName::Deferred => "deferred",
+            EventName::Delivered => "delivered",
+            EventName::Dropped => "dropped",
+            EventName::GroupResubscribe => "group_resubscribe",
+            EventName::GroupUnsubscribe => "group_unsubscribe",
+            EventName::Opened => "opened",
+            EventName::Processed => "processed",
+            EventName::SpamReport => "spam_report",
+            EventName::Unsubscribe => "unsubscribe",
+            EventName::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for EventName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EventName {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "bounced" => EventName::Bounced,
+            "clicked" => EventName::Clicked,
+            "deferred" => EventName::Deferred,
+            "delivered" => EventName::Delivered,
+            "dropped" => EventName::Dropped,
+            "group_resubscribe" => EventName::GroupResubscribe,
+            "group_unsubscribe" => EventName::GroupUnsubscribe,
+            "opened" => EventName::Opened,
+            "processed" => EventName::Processed,
+            "spam_report" => EventName::SpamReport,
+            "unsubscribe" => EventName::Unsubscribe,
+            _ => EventName::Unknown(s.to_string()),
+        })
+    }
 }
 
+impl std::convert::From<String> for EventName {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<EventName> for String {
+    fn from(v: EventName) -> Self {
+        match v {
+            EventName::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Use to distinguish between types of bounces
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostContactdbRecipientsSearchResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub created_at: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub custom_fields: Vec<PostContactdbRecipientsSearchResponseCustomFields>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub first_name: String,
+#[serde(from = "String", into = "String")]
+pub enum BounceType {
+    #[serde(rename = "blocked")]
+    Blocked,
+    #[serde(rename = "bounced")]
+    Bounced,
+    #[serde(rename = "expired")]
+    Expired,
     /**
-     * The license key provided with your New Relic account.
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub last_clicked: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub last_emailed: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub last_opened: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub updated_at: i64,
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostContactdbRecipientsSearchResponseData {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub recipient_count: i64,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub recipients: Vec<PostContactdbRecipientsSearchResponse>,
+impl AsRef<str> for BounceType {
+    fn as_ref(&self) -> &str {
+        match self {
+            BounceType::Blocked => "blocked",
+            BounceType::Bounced => "bounced",
+            BounceType::Expired => "expired",
+            BounceType::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ListAllCustomFieldsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub custom_fields: Vec<ContactdbCustomFieldWithAllOf>,
+impl std::fmt::Display for BounceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for BounceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "blocked" => BounceType::Blocked,
+            "bounced" => BounceType::Bounced,
+            "expired" => BounceType::Expired,
+            _ => BounceType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for BounceType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<BounceType> for String {
+    fn from(v: BounceType) -> Self {
+        match v {
+            BounceType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostContactdbCustomFieldsRequest {
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Event {
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub name: String,
+    pub attempt_num: i64,
+    /**
+     * Use to distinguish between types of bounces
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bounce_type: Option<BounceType>,
+    /**
+     * Name of event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_name: Option<EventName>,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReservedFields {
+    pub http_user_agent: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13026,53 +4368,22 @@ pub struct ReservedFields {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub mx_server: String,
     /**
      * The license key provided with your New Relic account.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetContactdbReservedFieldsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub reserved_fields: Vec<ReservedFields>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ListAllSegmentsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub segments: Vec<ContactdbSegments>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchContactdbSegmentsSegmentRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub conditions: Vec<ContactdbSegmentsConditions>,
+    pub processed: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub list_id: f64,
+    pub reason: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13081,21 +4392,73 @@ pub struct PatchContactdbSegmentsSegmentRequest {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub url: String,
 }
 
+/**
+ * Whether or not the outbound IP is dedicated vs shared
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ListRecipientsOnASegmentResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub recipients: Vec<ContactdbRecipient>,
+#[serde(from = "String", into = "String")]
+pub enum OutboundIpType {
+    #[serde(rename = "dedicated")]
+    Dedicated,
+    #[serde(rename = "shared")]
+    Shared,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OutboundIpType {
+    fn as_ref(&self) -> &str {
+        match self {
+            OutboundIpType::Dedicated => "dedicated",
+            OutboundIpType::Shared => "shared",
+            OutboundIpType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for OutboundIpType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OutboundIpType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "dedicated" => OutboundIpType::Dedicated,
+            "shared" => OutboundIpType::Shared,
+            _ => OutboundIpType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OutboundIpType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<OutboundIpType> for String {
+    fn from(v: OutboundIpType) -> Self {
+        match v {
+            OutboundIpType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetCategoriesResponse {
+pub struct Message {
     /**
      * The license key provided with your New Relic account.
      */
@@ -13104,31 +4467,13 @@ pub struct GetCategoriesResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub category: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetCategoriesResponseData {
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub errors: Vec<ErrorsSeg>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetCampaignsResponse {
+    pub api_key_id: String,
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub result: Vec<CampaignResponseAllOf>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetCampaignsCampaignResponse {
+    pub asm_group_id: i64,
     /**
      * The recipient IDs of the recipients that already existed from this request.
      */
@@ -13139,14 +4484,14 @@ pub struct GetCampaignsCampaignResponse {
     )]
     pub categories: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * List of events related to email message
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub custom_unsubscribe_url: String,
+    pub events: Vec<Event>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13155,13 +4500,7 @@ pub struct GetCampaignsCampaignResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub html_content: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
+    pub from_email: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13170,16 +4509,27 @@ pub struct GetCampaignsCampaignResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub ip_pool: String,
+    pub msg_id: String,
     /**
-     * The IDs of the lists you are sending this campaign to. You can have both segment IDs and list IDs
+     * This is the IP of the user who sent the message.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub list_ids: Vec<i64>,
+    #[serde()]
+    pub originating_ip: std::net::Ipv4Addr,
+    /**
+     * This is the IP of the user who sent the message.
+     */
+    #[serde()]
+    pub outbound_ip: std::net::Ipv4Addr,
+    /**
+     * Whether or not the outbound IP is dedicated vs shared
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outbound_ip_type: Option<OutboundIpType>,
+    /**
+     * Quick summary of the status of a message
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<MessageStatus>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13188,22 +4538,16 @@ pub struct GetCampaignsCampaignResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub plain_content: String,
+    pub subject: String,
     /**
-     * The IDs of the lists you are sending this campaign to. You can have both segment IDs and list IDs
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub segment_ids: Vec<i64>,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub sender_id: i64,
+    pub teammate: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13212,7 +4556,7 @@ pub struct GetCampaignsCampaignResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub status: String,
+    pub template_id: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13221,13 +4565,7 @@ pub struct GetCampaignsCampaignResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub subject: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub suppression_group_id: i64,
+    pub to_email: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13236,29 +4574,27 @@ pub struct GetCampaignsCampaignResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub title: String,
+    pub unique_args: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UpdateACampaignRequest {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
+pub struct MakoEvent {
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub categories: Vec<String>,
+    pub attempt_num: i64,
     /**
-     * The license key provided with your New Relic account.
+     * Use to distinguish between types of bounces
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub html_content: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bounce_type: Option<BounceType>,
+    /**
+     * Name of event
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_name: Option<EventName>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13267,7 +4603,7 @@ pub struct UpdateACampaignRequest {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub plain_content: String,
+    pub http_user_agent: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13276,7 +4612,7 @@ pub struct UpdateACampaignRequest {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub subject: String,
+    pub mx_server: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13285,17 +4621,13 @@ pub struct UpdateACampaignRequest {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub title: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SendACampaignResponse {
+    pub processed: String,
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: i64,
+    pub reason: String,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13304,287 +4636,406 @@ pub struct SendACampaignResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub status: String,
+    pub url: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TraitPagination200Response {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
+    pub metadata: Option<Metadata>,
+}
+
+/// An object containing key/value pairs of header names and the value to substitute for them. The key/value pairs must be strings. You must ensure these are properly encoded if they contain unicode characters. These headers cannot be one of the reserved headers.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Headers {}
+
+/// Substitutions allow you to insert data without using Dynamic Transactional Templates. This field should **not** be used in combination with a Dynamic Transactional Template, which can be identified by a `template_id` starting with `d-`. This field is a collection of key/value pairs following the pattern "substitution_tag":"value to substitute". The key/value pairs must be strings. These substitutions will apply to the text and html content of the body of your email, in addition to the `subject` and `reply-to` parameters. The total collective size of your substitutions may not exceed 10,000 bytes per personalization object.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Substitutions {}
+
+/// Dynamic template data is available using Handlebars syntax in Dynamic Transactional Templates. This field should be used in combination with a Dynamic Transactional Template, which can be identified by a `template_id` starting with `d-`. This field is a collection of key/value pairs following the pattern "variable_name":"value to insert".
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DynamicTemplateData {}
+
+/// Values that are specific to this personalization that will be carried along with the email and its activity data. Substitutions will not be made on custom arguments, so any string that is entered into this parameter will be assumed to be the custom argument that you would like to be used. This field may not exceed 10,000 bytes.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CustomArgs {}
+
+/**
+ * The attachment's content-disposition, specifying how you would like the attachment to be displayed. For example, `“inline”` results in the attached file are displayed automatically within the message while `“attachment”` results in the attached file require some action to be taken before it is displayed, such as opening or downloading the file.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Disposition {
+    #[serde(rename = "attachment")]
+    Attachment,
+    #[serde(rename = "inline")]
+    Inline,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Disposition {
+    fn as_ref(&self) -> &str {
+        match self {
+            Disposition::Attachment => "attachment",
+            Disposition::Inline => "inline",
+            Disposition::Unknown(s) => s,
+        }
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ScheduleACampaignRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub send_at: i64,
+impl std::fmt::Display for Disposition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-/**
- * The status of your campaign.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ScheduleACampaignResponseStatus {
-    #[serde(rename = "Scheduled")]
-    Scheduled,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+impl std::str::FromStr for Disposition {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "attachment" => Disposition::Attachment,
+            "inline" => Disposition::Inline,
+            _ => Disposition::Unknown(s.to_string()),
+        })
+    }
 }
 
-impl std::fmt::Display for ScheduleACampaignResponseStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ScheduleACampaignResponseStatus::Scheduled => "Scheduled",
-            ScheduleACampaignResponseStatus::Noop => "",
-            ScheduleACampaignResponseStatus::FallthroughString => "*",
+impl std::convert::From<String> for Disposition {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
         }
-        .fmt(f)
     }
 }
 
-impl Default for ScheduleACampaignResponseStatus {
-    fn default() -> ScheduleACampaignResponseStatus {
-        ScheduleACampaignResponseStatus::Noop
+impl std::convert::From<Disposition> for String {
+    fn from(v: Disposition) -> Self {
+        match v {
+            Disposition::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
-impl ScheduleACampaignResponseStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ScheduleACampaignResponseStatus::Noop)
+
+impl Default for Disposition {
+    fn default() -> Disposition {
+        Disposition::Attachment
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ScheduleACampaignResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
+pub struct Attachments {
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub send_at: i64,
+    pub content: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_id: Option<String>,
+    /**
+     * The attachment's content-disposition, specifying how you would like the attachment to be displayed. For example, `“inline”` results in the attached file are displayed automatically within the message while `“attachment”` results in the attached file require some action to be taken before it is displayed, such as opening or downloading the file.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<Disposition>,
     /**
-     * The status of your campaign.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "ScheduleACampaignResponseStatus::is_noop"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub status: ScheduleACampaignResponseStatus,
+    pub filename: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<String>,
 }
 
+/// An object allowing you to specify how to handle unsubscribes.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct UpdateAScheduledCampaignResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
+pub struct Asm {
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub send_at: i64,
+    pub group_id: i64,
     /**
-     * The license key provided with your New Relic account.
+     * The IDs of the lists you are sending this campaign to. You can have both segment IDs and list IDs
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub status: String,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub groups_to_display: Nullable<Vec<i64>>,
 }
 
+/// Allows you to bypass all unsubscribe groups and suppressions to ensure that the email is delivered to every single recipient. This should only be used in emergencies when it is absolutely necessary that every recipient receives your email. This filter cannot be combined with any other bypass filters. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct SendATestCampaignRequest {
+pub struct BypassListManagement {
     /**
-     * The license key provided with your New Relic account.
+     * Allows you to bypass all unsubscribe groups and suppressions to ensure that the email is delivered to every single recipient. This should only be used in emergencies when it is absolutely necessary that every recipient receives your email. This filter cannot be combined with any other bypass filters. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub to: String,
+    pub enable: bool,
 }
 
-/**
- * Comma-delimited list specifying which generations of templates to return. Options are `legacy`, `dynamic` or `legacy,dynamic`.
- */
+/// Allows you to bypass the spam report list to ensure that the email is delivered to recipients. Bounce and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Generations {
-    #[serde(rename = "dynamic")]
-    Dynamic,
-    #[serde(rename = "legacy")]
-    Legacy,
-    #[serde(rename = "legacy,dynamic")]
-    LegacyDynamic,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Generations {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Generations::Dynamic => "dynamic",
-            Generations::Legacy => "legacy",
-            Generations::LegacyDynamic => "legacy,dynamic",
-            Generations::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Generations {
-    fn default() -> Generations {
-        Generations::Legacy
-    }
+pub struct BypassSpamManagement {
+    /**
+     * Allows you to bypass the spam report list to ensure that the email is delivered to recipients. Bounce and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enable: bool,
 }
 
+/// Allows you to bypass the bounce list to ensure that the email is delivered to recipients. Spam report and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetTemplatesResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
-    pub metadata: Option<MetadataType>,
+pub struct BypassBounceManagement {
     /**
-     *
+     * Allows you to bypass the bounce list to ensure that the email is delivered to recipients. Spam report and unsubscribe lists will still be checked; addresses on these other lists will not receive the message. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub result: Vec<TransactionalTemplatesTemplateLean>,
+    pub enable: bool,
 }
 
+/// Allows you to bypass the global unsubscribe list to ensure that the email is delivered to recipients. Bounce and spam report lists will still be checked; addresses on these other lists will not receive the message. This filter applies only to global unsubscribes and will not bypass group unsubscribes. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetTemplatesResponseErrors {
+pub struct BypassUnsubscribeManagement {
     /**
-     * The license key provided with your New Relic account.
+     * Allows you to bypass the global unsubscribe list to ensure that the email is delivered to recipients. Bounce and spam report lists will still be checked; addresses on these other lists will not receive the message. This filter applies only to global unsubscribes and will not bypass group unsubscribes. This filter cannot be combined with the `bypass_list_management` filter. See our [documentation](https://sendgrid.com/docs/ui/sending-email/index-suppressions/#bypass-suppressions) for more about bypass filters.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub error_id: String,
+    pub enable: bool,
+}
+
+/// The default footer that you would like included on every email.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Footer {
     /**
-     * The license key provided with your New Relic account.
+     * The default footer that you would like included on every email.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub message: String,
+    pub enable: bool,
+    /**
+     * The default footer that you would like included on every email.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    /**
+     * The default footer that you would like included on every email.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
 }
 
+/// Sandbox Mode allows you to send a test email to ensure that your request body is valid and formatted correctly.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetTemplatesResponseData {
+pub struct SandboxMode {
+    /**
+     * Sandbox Mode allows you to send a test email to ensure that your request body is valid and formatted correctly.
+     */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub errors: Vec<GetTemplatesResponseErrors>,
+    pub enable: bool,
 }
 
+/// A collection of different mail settings that you can use to specify how you would like this email to be handled.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostTemplatesRequest {
+pub struct MailSettings {
+    /**
+     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub generation: Option<Generation>,
+    pub bypass_bounce_management: Option<BypassBounceManagement>,
     /**
-     * The license key provided with your New Relic account.
+     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bypass_list_management: Option<BypassListManagement>,
+    /**
+     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bypass_spam_management: Option<BypassSpamManagement>,
+    /**
+     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bypass_unsubscribe_management: Option<BypassUnsubscribeManagement>,
+    /**
+     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub footer: Option<Footer>,
+    /**
+     * A collection of different mail settings that you can use to specify how you would like this email to be handled.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sandbox_mode: Option<SandboxMode>,
 }
 
+/// Allows you to track if a recipient clicked a link in your email.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostTemplatesTemplateRequest {
+pub struct PostMailSendRequestTrackingSettingsClick {
+    /**
+     * Allows you to track if a recipient clicked a link in your email.
+     */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub name: String,
+    pub enable: bool,
+    /**
+     * Allows you to track if a recipient clicked a link in your email.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enable_text: bool,
 }
 
+/// Allows you to track if the email was opened by including a single pixel image in the body of the content. When the pixel is loaded, Twilio SendGrid can log that the email was opened.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchTemplatesTemplateRequest {
+pub struct OpenTracking {
+    /**
+     * Allows you to track if the email was opened by including a single pixel image in the body of the content. When the pixel is loaded, Twilio SendGrid can log that the email was opened.
+     */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub name: String,
+    pub enable: bool,
+    /**
+     * Allows you to track if the email was opened by including a single pixel image in the body of the content. When the pixel is loaded, Twilio SendGrid can log that the email was opened.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub substitution_tag: Option<String>,
 }
 
+/// Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetUserWebhooksParseSettingsResponse {
+pub struct SubscriptionTracking {
     /**
-     * The list of your current inbound parse settings.
+     * Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub result: Vec<ParseSetting>,
+    pub enable: bool,
+    /**
+     * Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    /**
+     * Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub substitution_tag: Option<String>,
+    /**
+     * Allows you to insert a subscription management link at the bottom of the text and HTML bodies of your email. If you would like to specify the location of the link within your email, you may use the `substitution_tag`.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
 }
 
+/// Allows you to enable tracking provided by Google Analytics.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetUserWebhooksParseStatsResponseMetrics {
+pub struct Ganalytics {
+    /**
+     * Allows you to enable tracking provided by Google Analytics.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enable: bool,
+    /**
+     * Allows you to enable tracking provided by Google Analytics.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utm_campaign: Option<String>,
+    /**
+     * Allows you to enable tracking provided by Google Analytics.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utm_content: Option<String>,
+    /**
+     * Allows you to enable tracking provided by Google Analytics.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utm_medium: Option<String>,
     /**
-     * The number of errors found while adding recipients.
+     * Allows you to enable tracking provided by Google Analytics.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utm_source: Option<String>,
+    /**
+     * Allows you to enable tracking provided by Google Analytics.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub received: f64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetUserWebhooksParseStatsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metrics: Option<GetUserWebhooksParseStatsResponseMetrics>,
+    pub utm_term: Option<String>,
 }
 
+/// Settings to determine how you would like to track the metrics of how your recipients interact with your email.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetUserWebhooksParseStatsResponseData {
+pub struct TrackingSettings {
     /**
-     * The license key provided with your New Relic account.
+     * Settings to determine how you would like to track the metrics of how your recipients interact with your email.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub date: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub click_tracking: Option<PostMailSendRequestTrackingSettingsClick>,
     /**
-     * The Parse Webhook usage statistics.
+     * Settings to determine how you would like to track the metrics of how your recipients interact with your email.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub stats: Vec<GetUserWebhooksParseStatsResponse>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ganalytics: Option<Ganalytics>,
+    /**
+     * Settings to determine how you would like to track the metrics of how your recipients interact with your email.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_tracking: Option<OpenTracking>,
+    /**
+     * Settings to determine how you would like to track the metrics of how your recipients interact with your email.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subscription_tracking: Option<SubscriptionTracking>,
 }
 
+/// The DNS record created to verify the branded link.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetUserWebhooksEventSettingsSignedResponse {
+pub struct PostWhitelabelLinksValidateResponseValidationResultsOwnerCname {
     /**
      * The license key provided with your New Relic account.
      */
@@ -13593,11 +5044,7 @@ pub struct GetUserWebhooksEventSettingsSignedResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub public_key: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetTrackingSettingsOpenResponse {
+    pub reason: String,
     /**
      * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
      */
@@ -13605,11 +5052,13 @@ pub struct GetTrackingSettingsOpenResponse {
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub enabled: bool,
+    pub valid: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchUserWebhooksEventSettingsSignedResponseErrors {
+pub struct PostIpsWarmupResponseErrors {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<serde_json::Value>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13618,7 +5067,13 @@ pub struct PatchUserWebhooksEventSettingsSignedResponseErrors {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub field: String,
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetIpsWarmupIpAddressResponseErrors {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<serde_json::Value>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13631,87 +5086,45 @@ pub struct PatchUserWebhooksEventSettingsSignedResponseErrors {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchUserWebhooksEventSettingsSignedResponse {
+pub struct DeleteIpsWarmupIpAddressResponseErrors {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<serde_json::Value>,
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub errors: Vec<PatchUserWebhooksEventSettingsSignedResponseErrors>,
+    pub message: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostUserWebhooksEventTestRequest {
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub oauth_client_id: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub oauth_client_secret: String,
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub oauth_token_url: String,
+pub struct PostWhitelabelIpsValidateResponseValidationResultsARecord {
+    /**
+     * The license key provided with your New Relic account.
+     */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub url: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMessagesResponse {
+    pub reason: String,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub messages: Vec<Messages>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PostMessagesDownloadResponseStatus {
-    #[serde(rename = "pending")]
-    Pending,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for PostMessagesDownloadResponseStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PostMessagesDownloadResponseStatus::Pending => "pending",
-            PostMessagesDownloadResponseStatus::Noop => "",
-            PostMessagesDownloadResponseStatus::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for PostMessagesDownloadResponseStatus {
-    fn default() -> PostMessagesDownloadResponseStatus {
-        PostMessagesDownloadResponseStatus::Noop
-    }
-}
-impl PostMessagesDownloadResponseStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PostMessagesDownloadResponseStatus::Noop)
-    }
+    pub valid: bool,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostMessagesDownloadResponse {
+pub struct PostIpsPoolsPoolNameResponseErrors {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<serde_json::Value>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -13721,183 +5134,214 @@ pub struct PostMessagesDownloadResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub message: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<PostMessagesDownloadResponseStatus>,
 }
 
+/// The CNAME record for the authenticated domain.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMessagesDownloadResponse {
+pub struct PostWhitelabelDomainsValidateResponseValidationResultsMailCname {
     /**
-     * The license key provided with your New Relic account.
+     * The CNAME record for the authenticated domain.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub csv: String,
+    pub reason: String,
     /**
-     * The license key provided with your New Relic account.
+     * The CNAME record for the authenticated domain.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub presigned_url: String,
+    pub valid: bool,
 }
 
+/// A DNS record for this authenticated domain.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetTrackingSettingsResponseResult {
+pub struct PostWhitelabelDomainsValidateResponseValidationResultsDkim1 {
     /**
-     * The license key provided with your New Relic account.
+     * A DNS record for this authenticated domain.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub description: String,
+    pub reason: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * A DNS record for this authenticated domain.
      */
     #[serde(
         default,
         deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub enabled: bool,
+    pub valid: bool,
+}
+
+/// A DNS record for this authenticated domain.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostWhitelabelDomainsValidateResponseValidationResultsDkim2 {
     /**
-     * The license key provided with your New Relic account.
+     * A DNS record for this authenticated domain.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub reason: String,
     /**
-     * The license key provided with your New Relic account.
+     * A DNS record for this authenticated domain.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub title: String,
+    pub valid: bool,
 }
 
+/// The SPF record for the authenticated domain.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetTrackingSettingsResponse {
+pub struct Spf {
     /**
-     * The list of all tracking settings.
+     * The SPF record for the authenticated domain.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub result: Vec<GetTrackingSettingsResponseResult>,
+    pub reason: String,
+    /**
+     * The SPF record for the authenticated domain.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub valid: bool,
 }
 
+/// The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PatchTrackingSettingsOpenRequest {
+pub struct PostWhitelabelDomainsValidateResponseValidationResults {
+    /**
+     * The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "dkim1")]
+    pub dkim_1: Option<PostWhitelabelDomainsValidateResponseValidationResultsDkim1>,
+    /**
+     * The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "dkim2")]
+    pub dkim_2: Option<PostWhitelabelDomainsValidateResponseValidationResultsDkim2>,
+    /**
+     * The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub enabled: Option<bool>,
+    pub mail_cname: Option<PostWhitelabelDomainsValidateResponseValidationResultsMailCname>,
+    /**
+     * The individual DNS records that are checked when validating, including the reason for any invalid DNS records.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spf: Option<Spf>,
 }
 
+/// A map of each subuser's billable contact usage. Each key is the subuser's ID and each value is the usage thus far this month.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetStatsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metrics: Option<StatsAdvancedGlobalAllOf>,
-}
+pub struct Breakdown {}
 
+/// `billable_breakdown` will only appear to the parent user in an account with subusers.
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetStatsResponseData {
+pub struct BillableBreakdown {
     /**
-     * The license key provided with your New Relic account.
+     * `billable_breakdown` will only appear to the parent user in an account with subusers.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub date: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub breakdown: Option<Breakdown>,
     /**
-     * The individual email activity stats.
+     * `billable_breakdown` will only appear to the parent user in an account with subusers.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub stats: Vec<GetStatsResponse>,
+    pub total: i64,
 }
 
 /**
- * The country you would like to see statistics for. Currently only supported for US and CA.
+ * File type for export file. Choose from `json` or `csv`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Country {
-    #[serde(rename = "CA")]
-    Ca,
-    #[serde(rename = "US")]
-    Us,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum FileType {
+    #[serde(rename = "csv")]
+    Csv,
+    #[serde(rename = "json")]
+    Json,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Country {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Country::Ca => "CA",
-            Country::Us => "US",
-            Country::Noop => "",
-            Country::FallthroughString => "*",
+impl AsRef<str> for FileType {
+    fn as_ref(&self) -> &str {
+        match self {
+            FileType::Csv => "csv",
+            FileType::Json => "json",
+            FileType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Country {
-    fn default() -> Country {
-        Country::Noop
+impl std::fmt::Display for FileType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Country {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Country::Noop)
+
+impl std::str::FromStr for FileType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "csv" => FileType::Csv,
+            "json" => FileType::Json,
+            _ => FileType::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetGeoStatsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metrics: Option<AdvancedStatsClicksOpensAllOf>,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
-    )]
-    pub type_: String,
+impl std::convert::From<String> for FileType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<FileType> for String {
+    fn from(v: FileType) -> Self {
+        match v {
+            FileType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for FileType {
+    fn default() -> FileType {
+        FileType::Csv
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetGeoStatsResponseData {
+pub struct UploadHeaders {
     /**
      * The license key provided with your New Relic account.
      */
@@ -13906,36 +5350,32 @@ pub struct GetGeoStatsResponseData {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub date: String,
+    pub header: String,
     /**
-     * The list of statistics.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub stats: Vec<GetGeoStatsResponse>,
+    pub value: String,
 }
 
+/// resources in which segment is being used
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetDevicesStatsResponse {
-    /**
-     * The individual events and their stats.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metrics: Option<AdvancedStatsOpens>,
+pub struct Resources {
     /**
-     * The license key provided with your New Relic account.
+     * resources in which segment is being used
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub name: String,
+    pub ids: Vec<String>,
     /**
-     * The license key provided with your New Relic account.
+     * resources in which segment is being used
      */
     #[serde(
         default,
@@ -13947,7 +5387,7 @@ pub struct GetDevicesStatsResponse {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetClientsStatsResponse {
+pub struct GetCategoriesResponseErrors {
     /**
      * The license key provided with your New Relic account.
      */
@@ -13956,66 +5396,20 @@ pub struct GetClientsStatsResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub date: String,
+    pub field: String,
     /**
-     * The list of statistics.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub stats: Vec<GetDevicesStatsResponse>,
-}
-
-/**
- * Specifies the type of client to retrieve stats for. Must be either "phone", "tablet", "webmail", or "desktop".
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ClientType {
-    #[serde(rename = "desktop")]
-    Desktop,
-    #[serde(rename = "phone")]
-    Phone,
-    #[serde(rename = "tablet")]
-    Tablet,
-    #[serde(rename = "webmail")]
-    Webmail,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for ClientType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ClientType::Desktop => "desktop",
-            ClientType::Phone => "phone",
-            ClientType::Tablet => "tablet",
-            ClientType::Webmail => "webmail",
-            ClientType::Noop => "",
-            ClientType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for ClientType {
-    fn default() -> ClientType {
-        ClientType::Noop
-    }
-}
-impl ClientType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ClientType::Noop)
-    }
+    pub message: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMailboxProvidersStatsResponse {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metrics: Option<AdvancedStatsMailboxProviderAllOf>,
+pub struct GetTrackingSettingsResponseResult {
     /**
      * The license key provided with your New Relic account.
      */
@@ -14024,21 +5418,15 @@ pub struct GetMailboxProvidersStatsResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub description: String,
     /**
-     * The license key provided with your New Relic account.
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub type_: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetMailboxProvidersStatsResponseData {
+    pub enabled: bool,
     /**
      * The license key provided with your New Relic account.
      */
@@ -14047,25 +5435,22 @@ pub struct GetMailboxProvidersStatsResponseData {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub date: String,
+    pub name: String,
     /**
-     * The list of statistics.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub stats: Vec<GetMailboxProvidersStatsResponse>,
+    pub title: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetBrowsersStatsResponse {
-    /**
-     * The individual events and their stats.
-     */
+pub struct GetGeoStatsResponse {
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub metrics: Option<AdvancedStatsClicks>,
+    pub metrics: Option<AdvancedStatsClicksOpensAllOf>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -14088,7 +5473,12 @@ pub struct GetBrowsersStatsResponse {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetBrowsersStatsResponseData {
+pub struct GetDevicesStatsResponse {
+    /**
+     * The individual events and their stats.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<AdvancedStatsOpens>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -14097,50 +5487,26 @@ pub struct GetBrowsersStatsResponseData {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub date: String,
+    pub name: String,
     /**
-     * The list of statistics.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub stats: Vec<GetBrowsersStatsResponse>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DeleteSuppressionBouncesRequest {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub delete_all: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
     )]
-    pub emails: Vec<String>,
+    pub type_: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct DeleteSuppressionBlocksRequest {
+pub struct GetClientsStatsResponse {
+    /**
+     * The individual events and their stats.
+     */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub delete_all: Option<bool>,
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub emails: Vec<String>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetSuppressionUnsubscribesResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub created: i64,
+    pub metrics: Option<AdvancedStatsOpens>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -14149,47 +5515,26 @@ pub struct GetSuppressionUnsubscribesResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub email: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct RetrieveAGlobalSuppressionResponse {
+    pub name: String,
     /**
      * The license key provided with your New Relic account.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
     )]
-    pub recipient_email: String,
+    pub type_: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostAsmGroupsResponse {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
+pub struct GetClientsClientTypeStatsResponse {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * The individual events and their stats.
      */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_default: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<AdvancedStatsOpens>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -14199,66 +5544,22 @@ pub struct PostAsmGroupsResponse {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetAsmGroupsGroupResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
     /**
      * The license key provided with your New Relic account.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub last_email_sent_at: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub unsubscribes: i64,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `SuppressionGroupRequestBase`
-/// - `GetAsmGroupsGroupResponse`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetAsmGroupsGroupResponseAllOf {
-    #[serde(flatten)]
-    pub suppression_group_request_base: SuppressionGroupRequestBase,
-    #[serde(flatten)]
-    pub get_asm_groups_group_response: GetAsmGroupsGroupResponse,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct PostAsmGroupsGroupSuppressionsResponse {
-    /**
-     * The recipient IDs of the recipients that already existed from this request.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
     )]
-    pub recipient_emails: Vec<String>,
+    pub type_: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetAsmSuppressionsResponse {
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub created_at: i64,
+pub struct GetMailboxProvidersStatsResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<AdvancedStatsMailboxProviderAllOf>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -14267,49 +5568,26 @@ pub struct GetAsmSuppressionsResponse {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub email: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub group_id: i64,
+    pub name: String,
     /**
      * The license key provided with your New Relic account.
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
     )]
-    pub group_name: String,
+    pub type_: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Suppressions {
-    /**
-     * The license key provided with your New Relic account.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
+pub struct GetBrowsersStatsResponse {
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     * The individual events and their stats.
      */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_default: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<AdvancedStatsClicks>,
     /**
      * The license key provided with your New Relic account.
      */
@@ -14320,24 +5598,13 @@ pub struct Suppressions {
     )]
     pub name: String,
     /**
-     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub suppressed: bool,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct GetAsmSuppressionsEmailResponse {
-    /**
-     * The array of suppression groups.
+     * The license key provided with your New Relic account.
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
     )]
-    pub suppressions: Vec<Suppressions>,
+    pub type_: String,
 }
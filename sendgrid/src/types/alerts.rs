@@ -0,0 +1,528 @@
+//! The data types of the `alerts` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/**
+ * The type of alert.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum GetAlertsResponseType {
+    #[serde(rename = "stats_notification")]
+    StatsNotification,
+    #[serde(rename = "usage_limit")]
+    UsageLimit,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for GetAlertsResponseType {
+    fn as_ref(&self) -> &str {
+        match self {
+            GetAlertsResponseType::StatsNotification => "stats_notification",
+            GetAlertsResponseType::UsageLimit => "usage_limit",
+            GetAlertsResponseType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for GetAlertsResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetAlertsResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "stats_notification" => GetAlertsResponseType::StatsNotification,
+            "usage_limit" => GetAlertsResponseType::UsageLimit,
+            _ => GetAlertsResponseType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetAlertsResponseType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetAlertsResponseType> for String {
+    fn from(v: GetAlertsResponseType) -> Self {
+        match v {
+            GetAlertsResponseType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetAlertsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub created_at: i64,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email_to: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub frequency: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub percentage: i64,
+    /**
+     * The type of alert.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<GetAlertsResponseType>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub updated_at: i64,
+}
+
+/**
+ * The type of alert you want to create. Can be either usage_limit or stats_notification.
+ *   Example: usage_limit
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PostAlertsRequestType {
+    #[serde(rename = "stats_notification")]
+    StatsNotification,
+    #[serde(rename = "usage_limit")]
+    UsageLimit,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PostAlertsRequestType {
+    fn as_ref(&self) -> &str {
+        match self {
+            PostAlertsRequestType::StatsNotification => "stats_notification",
+            PostAlertsRequestType::UsageLimit => "usage_limit",
+            PostAlertsRequestType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PostAlertsRequestType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PostAlertsRequestType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "stats_notification" => PostAlertsRequestType::StatsNotification,
+            "usage_limit" => PostAlertsRequestType::UsageLimit,
+            _ => PostAlertsRequestType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PostAlertsRequestType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PostAlertsRequestType> for String {
+    fn from(v: PostAlertsRequestType) -> Self {
+        match v {
+            PostAlertsRequestType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostAlertsRequest {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email_to: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub percentage: i64,
+    /**
+     * The type of alert you want to create. Can be either usage_limit or stats_notification.
+     *  Example: usage_limit
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<PostAlertsRequestType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostAlertsResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub created_at: i64,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email_to: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub frequency: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub percentage: i64,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub updated_at: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostAlertsResponseData {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub field: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+}
+
+/**
+ * The type of alert.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum GetAlertsAlertResponseType {
+    #[serde(rename = "stats_notification")]
+    StatsNotification,
+    #[serde(rename = "usage_alert")]
+    UsageAlert,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for GetAlertsAlertResponseType {
+    fn as_ref(&self) -> &str {
+        match self {
+            GetAlertsAlertResponseType::StatsNotification => "stats_notification",
+            GetAlertsAlertResponseType::UsageAlert => "usage_alert",
+            GetAlertsAlertResponseType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for GetAlertsAlertResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetAlertsAlertResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "stats_notification" => GetAlertsAlertResponseType::StatsNotification,
+            "usage_alert" => GetAlertsAlertResponseType::UsageAlert,
+            _ => GetAlertsAlertResponseType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetAlertsAlertResponseType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetAlertsAlertResponseType> for String {
+    fn from(v: GetAlertsAlertResponseType) -> Self {
+        match v {
+            GetAlertsAlertResponseType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetAlertsAlertResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub created_at: i64,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email_to: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub frequency: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub percentage: i64,
+    /**
+     * The type of alert.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<GetAlertsAlertResponseType>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub updated_at: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeleteAlertsAlertResponse {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PatchAlertsAlertRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_to: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub percentage: i64,
+}
+
+/**
+ * The type of alert.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PatchAlertsAlertResponseType {
+    #[serde(rename = "stats_notification")]
+    StatsNotification,
+    #[serde(rename = "usage_alert")]
+    UsageAlert,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PatchAlertsAlertResponseType {
+    fn as_ref(&self) -> &str {
+        match self {
+            PatchAlertsAlertResponseType::StatsNotification => "stats_notification",
+            PatchAlertsAlertResponseType::UsageAlert => "usage_alert",
+            PatchAlertsAlertResponseType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PatchAlertsAlertResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PatchAlertsAlertResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "stats_notification" => PatchAlertsAlertResponseType::StatsNotification,
+            "usage_alert" => PatchAlertsAlertResponseType::UsageAlert,
+            _ => PatchAlertsAlertResponseType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PatchAlertsAlertResponseType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PatchAlertsAlertResponseType> for String {
+    fn from(v: PatchAlertsAlertResponseType) -> Self {
+        match v {
+            PatchAlertsAlertResponseType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PatchAlertsAlertResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub created_at: i64,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email_to: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub frequency: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub percentage: i64,
+    /**
+     * The type of alert.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<PatchAlertsAlertResponseType>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub updated_at: i64,
+}
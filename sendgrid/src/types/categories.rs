@@ -0,0 +1,250 @@
+//! The data types of the `categories` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CategoryStats {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub date: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub stats: Vec<Stats>,
+}
+
+/**
+ * The direction you want to sort.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SortByDirection {
+    #[serde(rename = "asc")]
+    Asc,
+    #[serde(rename = "desc")]
+    Desc,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SortByDirection {
+    fn as_ref(&self) -> &str {
+        match self {
+            SortByDirection::Asc => "asc",
+            SortByDirection::Desc => "desc",
+            SortByDirection::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SortByDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SortByDirection {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "asc" => SortByDirection::Asc,
+            "desc" => SortByDirection::Desc,
+            _ => SortByDirection::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SortByDirection {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SortByDirection> for String {
+    fn from(v: SortByDirection) -> Self {
+        match v {
+            SortByDirection::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for SortByDirection {
+    fn default() -> SortByDirection {
+        SortByDirection::Desc
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetCategoriesResponse {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub category: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetCategoriesResponseData {
+    /**
+     * The error returned.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub errors: Vec<GetCategoriesResponseErrors>,
+}
+
+/**
+ * How to group the statistics. Must be either "day", "week", or "month".
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum GetCategoriesStatsSumsAggregatedBy {
+    #[serde(rename = "day")]
+    Day,
+    #[serde(rename = "month")]
+    Month,
+    #[serde(rename = "week")]
+    Week,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for GetCategoriesStatsSumsAggregatedBy {
+    fn as_ref(&self) -> &str {
+        match self {
+            GetCategoriesStatsSumsAggregatedBy::Day => "day",
+            GetCategoriesStatsSumsAggregatedBy::Month => "month",
+            GetCategoriesStatsSumsAggregatedBy::Week => "week",
+            GetCategoriesStatsSumsAggregatedBy::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for GetCategoriesStatsSumsAggregatedBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetCategoriesStatsSumsAggregatedBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "day" => GetCategoriesStatsSumsAggregatedBy::Day,
+            "month" => GetCategoriesStatsSumsAggregatedBy::Month,
+            "week" => GetCategoriesStatsSumsAggregatedBy::Week,
+            _ => GetCategoriesStatsSumsAggregatedBy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetCategoriesStatsSumsAggregatedBy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetCategoriesStatsSumsAggregatedBy> for String {
+    fn from(v: GetCategoriesStatsSumsAggregatedBy) -> Self {
+        match v {
+            GetCategoriesStatsSumsAggregatedBy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * How to group the statistics. Must be either "day", "week", or "month".
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum GetCategoriesStatsAggregatedBy {
+    #[serde(rename = "day")]
+    Day,
+    #[serde(rename = "month")]
+    Month,
+    #[serde(rename = "week")]
+    Week,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for GetCategoriesStatsAggregatedBy {
+    fn as_ref(&self) -> &str {
+        match self {
+            GetCategoriesStatsAggregatedBy::Day => "day",
+            GetCategoriesStatsAggregatedBy::Month => "month",
+            GetCategoriesStatsAggregatedBy::Week => "week",
+            GetCategoriesStatsAggregatedBy::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for GetCategoriesStatsAggregatedBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetCategoriesStatsAggregatedBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "day" => GetCategoriesStatsAggregatedBy::Day,
+            "month" => GetCategoriesStatsAggregatedBy::Month,
+            "week" => GetCategoriesStatsAggregatedBy::Week,
+            _ => GetCategoriesStatsAggregatedBy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetCategoriesStatsAggregatedBy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetCategoriesStatsAggregatedBy> for String {
+    fn from(v: GetCategoriesStatsAggregatedBy) -> Self {
+        match v {
+            GetCategoriesStatsAggregatedBy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
@@ -0,0 +1,225 @@
+//! The data types of the `custom_fields` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum FieldType {
+    #[serde(rename = "Date")]
+    Date,
+    #[serde(rename = "Number")]
+    Number,
+    #[serde(rename = "Text")]
+    Text,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for FieldType {
+    fn as_ref(&self) -> &str {
+        match self {
+            FieldType::Date => "Date",
+            FieldType::Number => "Number",
+            FieldType::Text => "Text",
+            FieldType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for FieldType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for FieldType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Date" => FieldType::Date,
+            "Number" => FieldType::Number,
+            "Text" => FieldType::Text,
+            _ => FieldType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for FieldType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<FieldType> for String {
+    fn from(v: FieldType) -> Self {
+        match v {
+            FieldType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReservedFieldDefinitionsResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_type: Option<FieldType>,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub read_only: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct CustomFieldDefinitionsResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_type: Option<FieldType>,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetMcFieldDefinitionsResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
+    pub metadata: Option<MetadataType>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub custom_fields: Vec<CustomFieldDefinitionsResponse>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub reserved_fields: Vec<ReservedFieldDefinitionsResponse>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostMcFieldDefinitionsRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_type: Option<FieldType>,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostMcFieldDefinitionsResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
+    pub metadata: Option<MetadataType>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `CustomFieldDefinitionsResponse`
+/// - `PostMcFieldDefinitionsResponse`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostMcFieldDefinitionsResponseAllOf {
+    #[serde(flatten)]
+    pub custom_field_definitions_response: CustomFieldDefinitionsResponse,
+    #[serde(flatten)]
+    pub post_mc_field_definitions_response: PostMcFieldDefinitionsResponse,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostMcFieldDefinitionsResponseData {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub errors: Vec<Error>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeleteMcFieldDefinitionsCustomResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub errors: Vec<Error>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PatchMcFieldDefinitionsCustomRequest {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PatchMcFieldDefinitionsCustomResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
+    pub metadata: Option<MetadataType>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `CustomFieldDefinitionsResponse`
+/// - `PatchMcFieldDefinitionsCustomResponse`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PatchMcFieldDefinitionsCustomResponseAllOf {
+    #[serde(flatten)]
+    pub custom_field_definitions_response: CustomFieldDefinitionsResponse,
+    #[serde(flatten)]
+    pub patch_mc_field_definitions_custom_response: PatchMcFieldDefinitionsCustomResponse,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PatchMcFieldDefinitionsCustomResponseData {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub errors: Vec<Error>,
+}
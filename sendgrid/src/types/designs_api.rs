@@ -0,0 +1,201 @@
+//! The data types of the `designs_api` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApiErrors {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub errors: Vec<ApiError>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DesignInput {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub html_content: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plain_content: Option<String>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `DesignDuplicateInput`
+/// - `DesignCommonFieldsAllOf`
+/// - `DesignInput`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DesignInputAllOf {
+    #[serde(flatten)]
+    pub design_duplicate_input: DesignDuplicateInput,
+    #[serde(flatten)]
+    pub design_common_fields_all_of: DesignCommonFieldsAllOf,
+    #[serde(flatten)]
+    pub design_input: DesignInput,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `DesignInputAllOf`
+/// - `DesignOutputSummaryAllOf`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DesignOutputAllOf {
+    #[serde(flatten)]
+    pub design_input_all_of: DesignInputAllOf,
+    #[serde(flatten)]
+    pub design_output_summary_all_of: DesignOutputSummaryAllOf,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DesignOutputSummary {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub id: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub thumbnail_url: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `DesignDuplicateInput`
+/// - `DesignCommonFieldsAllOf`
+/// - `DesignOutputSummary`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DesignOutputSummaryAllOf {
+    #[serde(flatten)]
+    pub design_duplicate_input: DesignDuplicateInput,
+    #[serde(flatten)]
+    pub design_common_fields_all_of: DesignCommonFieldsAllOf,
+    #[serde(flatten)]
+    pub design_output_summary: DesignOutputSummary,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DesignDuplicateInput {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor: Option<Editor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DesignCommonFields {
+    /**
+     * The recipient IDs of the recipients that already existed from this request.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub categories: Vec<String>,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub generate_plain_content: bool,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `DesignDuplicateInput`
+/// - `DesignCommonFields`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DesignCommonFieldsAllOf {
+    #[serde(flatten)]
+    pub design_duplicate_input: DesignDuplicateInput,
+    #[serde(flatten)]
+    pub design_common_fields: DesignCommonFields,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeleteDesignResponse {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PutDesignRequest {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub categories: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generate_plain_content: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html_content: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plain_content: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ListDesignsResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
+    pub metadata: Option<MetadataType>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub result: Vec<DesignOutputSummaryAllOf>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ListSendgridPreBuiltDesignsResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "_metadata")]
+    pub metadata: Option<MetadataType>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub result: Vec<DesignOutputSummaryAllOf>,
+}
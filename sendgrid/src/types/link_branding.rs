@@ -0,0 +1,311 @@
+//! The data types of the `link_branding` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/**
+ * The type of DNS record that was generated.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum LinkBranding200ResponseDnsDomainCnameType {
+    #[serde(rename = "cname")]
+    Cname,
+    #[serde(rename = "mx")]
+    Mx,
+    #[serde(rename = "txt")]
+    Txt,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for LinkBranding200ResponseDnsDomainCnameType {
+    fn as_ref(&self) -> &str {
+        match self {
+            LinkBranding200ResponseDnsDomainCnameType::Cname => "cname",
+            LinkBranding200ResponseDnsDomainCnameType::Mx => "mx",
+            LinkBranding200ResponseDnsDomainCnameType::Txt => "txt",
+            LinkBranding200ResponseDnsDomainCnameType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for LinkBranding200ResponseDnsDomainCnameType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LinkBranding200ResponseDnsDomainCnameType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cname" => LinkBranding200ResponseDnsDomainCnameType::Cname,
+            "mx" => LinkBranding200ResponseDnsDomainCnameType::Mx,
+            "txt" => LinkBranding200ResponseDnsDomainCnameType::Txt,
+            _ => LinkBranding200ResponseDnsDomainCnameType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LinkBranding200ResponseDnsDomainCnameType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<LinkBranding200ResponseDnsDomainCnameType> for String {
+    fn from(v: LinkBranding200ResponseDnsDomainCnameType) -> Self {
+        match v {
+            LinkBranding200ResponseDnsDomainCnameType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// The DNS record generated to point to your link branding subdomain.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DomainCname {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub data: String,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub host: String,
+    /**
+     * The type of DNS record that was generated.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<LinkBranding200ResponseDnsDomainCnameType>,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub valid: bool,
+}
+
+/// The DNS records generated for this link branding.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Dns {
+    /**
+     * The DNS record generated to point to your link branding subdomain.
+     */
+    #[serde()]
+    pub domain_cname: DomainCname,
+    /**
+     * The DNS records generated for this link branding.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_cname: Option<OwnerCname>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct LinkBranding200Response {
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub default: bool,
+    /**
+     * The DNS records generated for this link branding.
+     */
+    #[serde()]
+    pub dns: Dns,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub domain: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub legacy: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subdomain: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub user_id: i64,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub username: String,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub valid: bool,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostWhitelabelLinksRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub domain: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subdomain: Option<String>,
+}
+
+/// The DNS record generated for the sending domain used for this branded link.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostWhitelabelLinksValidateResponseValidationResultsDomainCname {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub valid: bool,
+}
+
+/// The individual validation results for each of the DNS records associated with this branded link.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ValidationResults {
+    /**
+     * The DNS record generated for the sending domain used for this branded link.
+     */
+    #[serde()]
+    pub domain_cname: PostWhitelabelLinksValidateResponseValidationResultsDomainCname,
+    /**
+     * The individual validation results for each of the DNS records associated with this branded link.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_cname: Option<PostWhitelabelLinksValidateResponseValidationResultsOwnerCname>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostWhitelabelLinksValidateResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Indicates if your subuser statistics will be sent to your New Relic Dashboard.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub valid: bool,
+    /**
+     * The individual validation results for each of the DNS records associated with this branded link.
+     */
+    #[serde()]
+    pub validation_results: ValidationResults,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostWhitelabelLinksValidateResponseErrors {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostWhitelabelLinksValidateResponseData {
+    /**
+     * The reasons why the validation failed.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub errors: Vec<PostWhitelabelLinksValidateResponseErrors>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PostWhitelabelLinksLinkSubuserRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeleteWhitelabelLinksResponse {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PatchWhitelabelLinksRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeleteWhitelabelLinksSubuserResponse {}
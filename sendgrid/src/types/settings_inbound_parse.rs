@@ -0,0 +1,8 @@
+//! The data types of the `settings_inbound_parse` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct DeleteUserWebhooksParseSettingsHostnameResponse {}
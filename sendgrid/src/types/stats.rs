@@ -0,0 +1,517 @@
+//! The data types of the `stats` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct StatsAdvancedGlobal {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub blocks: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub bounce_drops: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub bounces: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub deferred: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub delivered: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub invalid_emails: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub processed: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub requests: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub spam_report_drops: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub spam_reports: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub unsubscribe_drops: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub unsubscribes: i64,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `AdvancedStatsClicksOpensAllOf`
+/// - `StatsAdvancedGlobal`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct StatsAdvancedGlobalAllOf {
+    #[serde(flatten)]
+    pub advanced_stats_clicks_opens_all_of: AdvancedStatsClicksOpensAllOf,
+    #[serde(flatten)]
+    pub stats_advanced_global: StatsAdvancedGlobal,
+}
+
+/**
+ * How to group the statistics. Must be either "day", "week", or "month".
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum TraitStatsAdvancedBaseQueryStringsAggregatedBy {
+    #[serde(rename = "day")]
+    Day,
+    #[serde(rename = "month")]
+    Month,
+    #[serde(rename = "week")]
+    Week,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
+    fn as_ref(&self) -> &str {
+        match self {
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Day => "day",
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Month => "month",
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Week => "week",
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "day" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Day,
+            "month" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Month,
+            "week" => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Week,
+            _ => TraitStatsAdvancedBaseQueryStringsAggregatedBy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for TraitStatsAdvancedBaseQueryStringsAggregatedBy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<TraitStatsAdvancedBaseQueryStringsAggregatedBy> for String {
+    fn from(v: TraitStatsAdvancedBaseQueryStringsAggregatedBy) -> Self {
+        match v {
+            TraitStatsAdvancedBaseQueryStringsAggregatedBy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * How to group the statistics. Must be either "day", "week", or "month".
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy {
+    #[serde(rename = "day")]
+    Day,
+    #[serde(rename = "month")]
+    Month,
+    #[serde(rename = "week")]
+    Week,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy {
+    fn as_ref(&self) -> &str {
+        match self {
+            TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy::Day => "day",
+            TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy::Month => "month",
+            TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy::Week => "week",
+            TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "day" => TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy::Day,
+            "month" => TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy::Month,
+            "week" => TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy::Week,
+            _ => TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy> for String {
+    fn from(v: TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy) -> Self {
+        match v {
+            TraitStatsAdvancedQueryStringsLimitOffsetAggregatedBy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetStatsResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<StatsAdvancedGlobalAllOf>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetStatsResponseData {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub date: String,
+    /**
+     * The individual email activity stats.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub stats: Vec<GetStatsResponse>,
+}
+
+/**
+ * The country you would like to see statistics for. Currently only supported for US and CA.
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Country {
+    #[serde(rename = "CA")]
+    Ca,
+    #[serde(rename = "US")]
+    Us,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Country {
+    fn as_ref(&self) -> &str {
+        match self {
+            Country::Ca => "CA",
+            Country::Us => "US",
+            Country::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Country {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Country {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CA" => Country::Ca,
+            "US" => Country::Us,
+            _ => Country::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Country {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Country> for String {
+    fn from(v: Country) -> Self {
+        match v {
+            Country::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetGeoStatsResponseData {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub date: String,
+    /**
+     * The list of statistics.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub stats: Vec<GetGeoStatsResponse>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetDevicesStatsResponseData {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub date: String,
+    /**
+     * The list of statistics.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub stats: Vec<GetDevicesStatsResponse>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetClientsStatsResponseData {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub date: String,
+    /**
+     * The list of statistics.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub stats: Vec<GetClientsStatsResponse>,
+}
+
+/**
+ * Specifies the type of client to retrieve stats for. Must be either "phone", "tablet", "webmail", or "desktop".
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ClientType {
+    #[serde(rename = "desktop")]
+    Desktop,
+    #[serde(rename = "phone")]
+    Phone,
+    #[serde(rename = "tablet")]
+    Tablet,
+    #[serde(rename = "webmail")]
+    Webmail,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ClientType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ClientType::Desktop => "desktop",
+            ClientType::Phone => "phone",
+            ClientType::Tablet => "tablet",
+            ClientType::Webmail => "webmail",
+            ClientType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ClientType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ClientType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "desktop" => ClientType::Desktop,
+            "phone" => ClientType::Phone,
+            "tablet" => ClientType::Tablet,
+            "webmail" => ClientType::Webmail,
+            _ => ClientType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ClientType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ClientType> for String {
+    fn from(v: ClientType) -> Self {
+        match v {
+            ClientType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetClientsClientTypeStatsResponseData {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub date: String,
+    /**
+     * The list of statistics.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub stats: Vec<GetClientsClientTypeStatsResponse>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetMailboxProvidersStatsResponseData {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub date: String,
+    /**
+     * The list of statistics.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub stats: Vec<GetMailboxProvidersStatsResponse>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct GetBrowsersStatsResponseData {
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub date: String,
+    /**
+     * The list of statistics.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub stats: Vec<GetBrowsersStatsResponse>,
+}
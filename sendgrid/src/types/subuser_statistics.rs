@@ -0,0 +1,183 @@
+//! The data types of the `subuser_statistics` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct SubuserStatsData {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub date: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub stats: Vec<SubuserStats>,
+}
+
+/**
+ * The metric that you want to sort by. Metrics that you can sort by are: `blocks`, `bounces`, `clicks`, `delivered`, `opens`, `requests`, `unique_clicks`, `unique_opens`, and `unsubscribes`.'
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SortByMetric {
+    #[serde(rename = "blocks")]
+    Blocks,
+    #[serde(rename = "bounces")]
+    Bounces,
+    #[serde(rename = "clicks")]
+    Clicks,
+    #[serde(rename = "delivered")]
+    Delivered,
+    #[serde(rename = "opens")]
+    Opens,
+    #[serde(rename = "requests")]
+    Requests,
+    #[serde(rename = "unique_clicks")]
+    UniqueClicks,
+    #[serde(rename = "unique_opens")]
+    UniqueOpens,
+    #[serde(rename = "unsubscribes")]
+    Unsubscribes,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SortByMetric {
+    fn as_ref(&self) -> &str {
+        match self {
+            SortByMetric::Blocks => "blocks",
+            SortByMetric::Bounces => "bounces",
+            SortByMetric::Clicks => "clicks",
+            SortByMetric::Delivered => "delivered",
+            SortByMetric::Opens => "opens",
+            SortByMetric::Requests => "requests",
+            SortByMetric::UniqueClicks => "unique_clicks",
+            SortByMetric::UniqueOpens => "unique_opens",
+            SortByMetric::Unsubscribes => "unsubscribes",
+            SortByMetric::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SortByMetric {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SortByMetric {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "blocks" => SortByMetric::Blocks,
+            "bounces" => SortByMetric::Bounces,
+            "clicks" => SortByMetric::Clicks,
+            "delivered" => SortByMetric::Delivered,
+            "opens" => SortByMetric::Opens,
+            "requests" => SortByMetric::Requests,
+            "unique_clicks" => SortByMetric::UniqueClicks,
+            "unique_opens" => SortByMetric::UniqueOpens,
+            "unsubscribes" => SortByMetric::Unsubscribes,
+            _ => SortByMetric::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SortByMetric {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SortByMetric> for String {
+    fn from(v: SortByMetric) -> Self {
+        match v {
+            SortByMetric::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+impl Default for SortByMetric {
+    fn default() -> SortByMetric {
+        SortByMetric::Delivered
+    }
+}
+
+/**
+ * How to group the statistics. Must be either "day", "week", or "month".
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum GetSubusersStatsAggregatedBy {
+    #[serde(rename = "day")]
+    Day,
+    #[serde(rename = "month")]
+    Month,
+    #[serde(rename = "week")]
+    Week,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for GetSubusersStatsAggregatedBy {
+    fn as_ref(&self) -> &str {
+        match self {
+            GetSubusersStatsAggregatedBy::Day => "day",
+            GetSubusersStatsAggregatedBy::Month => "month",
+            GetSubusersStatsAggregatedBy::Week => "week",
+            GetSubusersStatsAggregatedBy::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for GetSubusersStatsAggregatedBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetSubusersStatsAggregatedBy {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "day" => GetSubusersStatsAggregatedBy::Day,
+            "month" => GetSubusersStatsAggregatedBy::Month,
+            "week" => GetSubusersStatsAggregatedBy::Week,
+            _ => GetSubusersStatsAggregatedBy::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetSubusersStatsAggregatedBy {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetSubusersStatsAggregatedBy> for String {
+    fn from(v: GetSubusersStatsAggregatedBy) -> Self {
+        match v {
+            GetSubusersStatsAggregatedBy::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
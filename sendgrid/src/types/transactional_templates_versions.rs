@@ -0,0 +1,69 @@
+//! The data types of the `transactional_templates_versions` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TransactionalTemplateVersionOutput {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub warnings: Vec<TransactionalTemplateWarning>,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `TransactionalTemplateVersionOutput`
+/// - `TransactionalTemplateVersionCreate`
+/// - `TransactionalTemplatesVersionOutputLean`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TransactionalTemplateVersionOutputAllOf {
+    #[serde(flatten)]
+    pub transactional_template_version_output: TransactionalTemplateVersionOutput,
+    #[serde(flatten)]
+    pub transactional_template_version_create: TransactionalTemplateVersionCreate,
+    #[serde(flatten)]
+    pub transactional_templates_version_output_lean: TransactionalTemplatesVersionOutputLean,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct TransactionalTemplateVersionCreate {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub active: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor: Option<Editor>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub generate_plain_content: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub html_content: Option<String>,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plain_content: Option<String>,
+    /**
+     * The license key provided with your New Relic account.
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subject: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_data: Option<String>,
+}
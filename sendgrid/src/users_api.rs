@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct UsersApi {
     pub client: Client,
@@ -97,7 +95,7 @@ impl UsersApi {
     pub async fn put_user_email(
         &self,
         body: &crate::types::PutUserEmailRequest,
-    ) -> Result<crate::types::GetUserEmailResponse> {
+    ) -> Result<crate::types::PutUserEmailResponse> {
         let url = "/user/email".to_string();
         self.client
             .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -115,7 +113,7 @@ impl UsersApi {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn get_user_username(&self) -> Result<crate::types::Users> {
+    pub async fn get_user_username(&self) -> Result<crate::types::GetUserUsernameResponse> {
         let url = "/user/username".to_string();
         self.client.get(&url, None).await
     }
@@ -173,10 +171,106 @@ impl UsersApi {
     pub async fn put_user_password(
         &self,
         body: &crate::types::PutUserPasswordRequest,
-    ) -> Result<crate::types::Help> {
+    ) -> Result<crate::types::PutUserPasswordResponse> {
         let url = "/user/password".to_string();
         self.client
             .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
 }
+
+/// The functions of [`UsersApi`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUsersApiApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UsersApiApi: Send + Sync {
+    /// See [`UsersApi::get_user_profile`].
+    async fn get_user_profile(&self) -> Result<crate::types::GetUserProfileResponse>;
+
+    /// See [`UsersApi::patch_user_profile`].
+    async fn patch_user_profile(
+        &self,
+        body: &crate::types::UserProfile,
+    ) -> Result<crate::types::UserProfile>;
+
+    /// See [`UsersApi::get_user_account`].
+    async fn get_user_account(&self) -> Result<crate::types::GetUserAccountResponse>;
+
+    /// See [`UsersApi::get_user_email`].
+    async fn get_user_email(&self) -> Result<crate::types::GetUserEmailResponse>;
+
+    /// See [`UsersApi::put_user_email`].
+    async fn put_user_email(
+        &self,
+        body: &crate::types::PutUserEmailRequest,
+    ) -> Result<crate::types::PutUserEmailResponse>;
+
+    /// See [`UsersApi::get_user_username`].
+    async fn get_user_username(&self) -> Result<crate::types::GetUserUsernameResponse>;
+
+    /// See [`UsersApi::put_user_username`].
+    async fn put_user_username(
+        &self,
+        body: &crate::types::PutUserUsernameRequest,
+    ) -> Result<crate::types::PutUserUsernameResponse>;
+
+    /// See [`UsersApi::get_user_credits`].
+    async fn get_user_credits(&self) -> Result<crate::types::GetUserCreditsResponse>;
+
+    /// See [`UsersApi::put_user_password`].
+    async fn put_user_password(
+        &self,
+        body: &crate::types::PutUserPasswordRequest,
+    ) -> Result<crate::types::PutUserPasswordResponse>;
+}
+
+#[async_trait::async_trait]
+impl UsersApiApi for UsersApi {
+    async fn get_user_profile(&self) -> Result<crate::types::GetUserProfileResponse> {
+        UsersApi::get_user_profile(self).await
+    }
+
+    async fn patch_user_profile(
+        &self,
+        body: &crate::types::UserProfile,
+    ) -> Result<crate::types::UserProfile> {
+        UsersApi::patch_user_profile(self, body).await
+    }
+
+    async fn get_user_account(&self) -> Result<crate::types::GetUserAccountResponse> {
+        UsersApi::get_user_account(self).await
+    }
+
+    async fn get_user_email(&self) -> Result<crate::types::GetUserEmailResponse> {
+        UsersApi::get_user_email(self).await
+    }
+
+    async fn put_user_email(
+        &self,
+        body: &crate::types::PutUserEmailRequest,
+    ) -> Result<crate::types::PutUserEmailResponse> {
+        UsersApi::put_user_email(self, body).await
+    }
+
+    async fn get_user_username(&self) -> Result<crate::types::GetUserUsernameResponse> {
+        UsersApi::get_user_username(self).await
+    }
+
+    async fn put_user_username(
+        &self,
+        body: &crate::types::PutUserUsernameRequest,
+    ) -> Result<crate::types::PutUserUsernameResponse> {
+        UsersApi::put_user_username(self, body).await
+    }
+
+    async fn get_user_credits(&self) -> Result<crate::types::GetUserCreditsResponse> {
+        UsersApi::get_user_credits(self).await
+    }
+
+    async fn put_user_password(
+        &self,
+        body: &crate::types::PutUserPasswordRequest,
+    ) -> Result<crate::types::PutUserPasswordResponse> {
+        UsersApi::put_user_password(self, body).await
+    }
+}
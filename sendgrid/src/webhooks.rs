@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Webhooks {
     pub client: Client,
@@ -29,9 +27,7 @@ impl Webhooks {
      *
      * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
      */
-    pub async fn get_user_event_settings(
-        &self,
-    ) -> Result<crate::types::WebhooksEventWebhookResponse> {
+    pub async fn get_user_event_settings(&self) -> Result<crate::types::EventWebhookResponse> {
         let url = "/user/webhooks/event/settings".to_string();
         self.client.get(&url, None).await
     }
@@ -55,8 +51,8 @@ impl Webhooks {
      */
     pub async fn patch_user_event_settings(
         &self,
-        body: &crate::types::WebhooksEventWebhookUpdateWithOAuthRequest,
-    ) -> Result<crate::types::WebhooksEventWebhookResponse> {
+        body: &crate::types::EventWebhookUpdateOauthRequest,
+    ) -> Result<crate::types::EventWebhookResponse> {
         let url = "/user/webhooks/event/settings".to_string();
         self.client
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -94,35 +90,26 @@ impl Webhooks {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The license key provided with your New Relic account.
-     * * `offset: &str` -- The license key provided with your New Relic account.
-     * * `aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy` -- How to group the statistics. Must be either "day", "week", or "month".
-     * * `start_date: &str` -- The starting date of the statistics you want to retrieve. Must be in the format YYYY-MM-DD.
-     * * `end_date: &str` -- The end date of the statistics you want to retrieve. Must be in the format YYYY-MM-DD.
-     * * `on_behalf_of: &str` -- The license key provided with your New Relic account.
+     * * `params: &WebhooksGetUserParseStatsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_user_parse_stats(
+    pub async fn get_user_parse_stats_with(
         &self,
-        limit: &str,
-        offset: &str,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
-        start_date: &str,
-        end_date: &str,
+        params: &WebhooksGetUserParseStatsParams,
     ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !offset.is_empty() {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -131,6 +118,28 @@ impl Webhooks {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_user_parse_stats_with` and pass the query parameters as a `WebhooksGetUserParseStatsParams` instead.
+     */
+    #[deprecated(note = "use `get_user_parse_stats_with` instead")]
+    pub async fn get_user_parse_stats(
+        &self,
+        limit: &str,
+        offset: &str,
+        aggregated_by: crate::types::GetUserWebhooksParseStatsAggregatedBy,
+        start_date: &str,
+        end_date: &str,
+    ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
+        let params = WebhooksGetUserParseStatsParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_user_parse_stats_with(&params).await
+    }
+
     /**
      * Retrieves Inbound Parse Webhook statistics.
      *
@@ -143,31 +152,106 @@ impl Webhooks {
      * SendGrid's Inbound Parse Webhook allows you to parse the contents and attachments of incomming emails. The Parse API can then POST the parsed emails to a URL that you specify. The Inbound Parse Webhook cannot parse messages greater than 30MB in size, including all attachments.
      *
      * There are a number of pre-made integrations for the SendGrid Parse Webhook which make processing events easy. You can find these integrations in the [Library Index](https://sendgrid.com/docs/Integrate/libraries.html#-Webhook-Libraries).
+     *
+     * **Parameters:**
+     *
+     * * `params: &WebhooksGetUserParseStatsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_user_parse_stats_with(
+        &self,
+        params: &WebhooksGetUserParseStatsParams,
+    ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(aggregated_by) = &params.aggregated_by {
+            query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
+        }
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("end_date".to_string(), end_date.to_string()));
+        }
+        if let Some(offset) = &params.offset {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("start_date".to_string(), start_date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/user/webhooks/parse/stats?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_user_parse_stats_with` and pass the query parameters as a `WebhooksGetUserParseStatsParams` instead.
      */
+    #[deprecated(note = "use `get_all_user_parse_stats_with` instead")]
     pub async fn get_all_user_parse_stats(
         &self,
         offset: &str,
-        aggregated_by: crate::types::TraitStatsAdvancedBaseQueryStringsAggregatedBy,
+        aggregated_by: crate::types::GetUserWebhooksParseStatsAggregatedBy,
         start_date: &str,
         end_date: &str,
     ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
+        let params = WebhooksGetUserParseStatsParams {
+            aggregated_by: Some(aggregated_by).filter(|v| !v.to_string().is_empty()),
+            end_date: Some(end_date.to_string()).filter(|s| !s.is_empty()),
+            offset: Some(offset.to_string()).filter(|s| !s.is_empty()),
+            start_date: Some(start_date.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_user_parse_stats_with(&params).await
+    }
+
+    /**
+     * Retrieves Inbound Parse Webhook statistics.
+     *
+     * This function performs a `GET` to the `/user/webhooks/parse/stats` endpoint.
+     *
+     * As opposed to `get_all_user_parse_stats`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * **This endpoint allows you to retrieve the statistics for your Parse Webhook useage.**
+     *
+     * SendGrid's Inbound Parse Webhook allows you to parse the contents and attachments of incomming emails. The Parse API can then POST the parsed emails to a URL that you specify. The Inbound Parse Webhook cannot parse messages greater than 30MB in size, including all attachments.
+     *
+     * There are a number of pre-made integrations for the SendGrid Parse Webhook which make processing events easy. You can find these integrations in the [Library Index](https://sendgrid.com/docs/Integrate/libraries.html#-Webhook-Libraries).
+     */
+    pub fn get_user_parse_stats_stream(
+        &self,
+        params: &WebhooksGetUserParseStatsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetUserWebhooksParseStatsResponseData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !aggregated_by.to_string().is_empty() {
+        if let Some(aggregated_by) = &params.aggregated_by {
             query_args.push(("aggregated_by".to_string(), aggregated_by.to_string()));
         }
-        if !end_date.is_empty() {
+        if let Some(end_date) = &params.end_date {
             query_args.push(("end_date".to_string(), end_date.to_string()));
         }
-        if !offset.is_empty() {
+        if let Some(offset) = &params.offset {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !start_date.is_empty() {
+        if let Some(start_date) = &params.start_date {
             query_args.push(("start_date".to_string(), start_date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/user/webhooks/parse/stats?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -209,8 +293,8 @@ impl Webhooks {
      */
     pub async fn patch_user_event_settings_signed(
         &self,
-        body: &crate::types::GetTrackingSettingsOpenResponse,
-    ) -> Result<crate::types::GetUserWebhooksEventSettingsSignedResponse> {
+        body: &crate::types::PatchUserWebhooksEventSettingsSignedRequest,
+    ) -> Result<crate::types::PatchUserWebhooksEventSettingsSignedResponse> {
         let url = "/user/webhooks/event/settings/signed".to_string();
         self.client
             .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -246,3 +330,168 @@ impl Webhooks {
             .await
     }
 }
+
+/// The query parameters of `Webhooks::get_user_parse_stats_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WebhooksGetUserParseStatsParams {
+    /// How you would like the statistics to by grouped.
+    pub aggregated_by: Option<crate::types::GetUserWebhooksParseStatsAggregatedBy>,
+    /// The end date of the statistics you want to retrieve. Must be in the format YYYY-MM-DD.
+    pub end_date: Option<String>,
+    /// The license key provided with your New Relic account.
+    pub limit: Option<String>,
+    /// The license key provided with your New Relic account.
+    pub offset: Option<String>,
+    /// The starting date of the statistics you want to retrieve. Must be in the format YYYY-MM-DD.
+    pub start_date: Option<String>,
+}
+
+impl WebhooksGetUserParseStatsParams {
+    pub fn aggregated_by(
+        mut self,
+        aggregated_by: crate::types::GetUserWebhooksParseStatsAggregatedBy,
+    ) -> Self {
+        self.aggregated_by = Some(aggregated_by);
+        self
+    }
+
+    pub fn end_date<T: ToString>(mut self, end_date: T) -> Self {
+        self.end_date = Some(end_date.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn offset<T: ToString>(mut self, offset: T) -> Self {
+        self.offset = Some(offset.to_string());
+        self
+    }
+
+    pub fn start_date<T: ToString>(mut self, start_date: T) -> Self {
+        self.start_date = Some(start_date.to_string());
+        self
+    }
+}
+
+/// The functions of [`Webhooks`], for code that takes them as a trait, so that it can be
+/// tested with the `MockWebhooksApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait WebhooksApi: Send + Sync {
+    /// See [`Webhooks::get_user_event_settings`].
+    async fn get_user_event_settings(&self) -> Result<crate::types::EventWebhookResponse>;
+
+    /// See [`Webhooks::patch_user_event_settings`].
+    async fn patch_user_event_settings(
+        &self,
+        body: &crate::types::EventWebhookUpdateOauthRequest,
+    ) -> Result<crate::types::EventWebhookResponse>;
+
+    /// See [`Webhooks::get_user_parse_settings`].
+    async fn get_user_parse_settings(
+        &self,
+    ) -> Result<crate::types::GetUserWebhooksParseSettingsResponse>;
+
+    /// See [`Webhooks::get_user_parse_stats_with`].
+    async fn get_user_parse_stats_with(
+        &self,
+        params: &WebhooksGetUserParseStatsParams,
+    ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>>;
+
+    /// See [`Webhooks::get_all_user_parse_stats_with`].
+    async fn get_all_user_parse_stats_with(
+        &self,
+        params: &WebhooksGetUserParseStatsParams,
+    ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>>;
+
+    /// See [`Webhooks::get_user_parse_stats_stream`].
+    fn get_user_parse_stats_stream(
+        &self,
+        params: &WebhooksGetUserParseStatsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetUserWebhooksParseStatsResponseData>;
+
+    /// See [`Webhooks::get_user_event_settings_signed`].
+    async fn get_user_event_settings_signed(
+        &self,
+    ) -> Result<crate::types::GetUserWebhooksEventSettingsSignedResponse>;
+
+    /// See [`Webhooks::patch_user_event_settings_signed`].
+    async fn patch_user_event_settings_signed(
+        &self,
+        body: &crate::types::PatchUserWebhooksEventSettingsSignedRequest,
+    ) -> Result<crate::types::PatchUserWebhooksEventSettingsSignedResponse>;
+
+    /// See [`Webhooks::post_user_event_test`].
+    async fn post_user_event_test(
+        &self,
+        body: &crate::types::PostUserWebhooksEventTestRequest,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl WebhooksApi for Webhooks {
+    async fn get_user_event_settings(&self) -> Result<crate::types::EventWebhookResponse> {
+        Webhooks::get_user_event_settings(self).await
+    }
+
+    async fn patch_user_event_settings(
+        &self,
+        body: &crate::types::EventWebhookUpdateOauthRequest,
+    ) -> Result<crate::types::EventWebhookResponse> {
+        Webhooks::patch_user_event_settings(self, body).await
+    }
+
+    async fn get_user_parse_settings(
+        &self,
+    ) -> Result<crate::types::GetUserWebhooksParseSettingsResponse> {
+        Webhooks::get_user_parse_settings(self).await
+    }
+
+    async fn get_user_parse_stats_with(
+        &self,
+        params: &WebhooksGetUserParseStatsParams,
+    ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
+        Webhooks::get_user_parse_stats_with(self, params).await
+    }
+
+    async fn get_all_user_parse_stats_with(
+        &self,
+        params: &WebhooksGetUserParseStatsParams,
+    ) -> Result<Vec<crate::types::GetUserWebhooksParseStatsResponseData>> {
+        Webhooks::get_all_user_parse_stats_with(self, params).await
+    }
+
+    fn get_user_parse_stats_stream(
+        &self,
+        params: &WebhooksGetUserParseStatsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetUserWebhooksParseStatsResponseData> {
+        Webhooks::get_user_parse_stats_stream(self, params, start, max_items)
+    }
+
+    async fn get_user_event_settings_signed(
+        &self,
+    ) -> Result<crate::types::GetUserWebhooksEventSettingsSignedResponse> {
+        Webhooks::get_user_event_settings_signed(self).await
+    }
+
+    async fn patch_user_event_settings_signed(
+        &self,
+        body: &crate::types::PatchUserWebhooksEventSettingsSignedRequest,
+    ) -> Result<crate::types::PatchUserWebhooksEventSettingsSignedResponse> {
+        Webhooks::patch_user_event_settings_signed(self, body).await
+    }
+
+    async fn post_user_event_test(
+        &self,
+        body: &crate::types::PostUserWebhooksEventTestRequest,
+    ) -> Result<()> {
+        Webhooks::post_user_event_test(self, body).await
+    }
+}
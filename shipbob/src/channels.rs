@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Channels {
     pub client: Client,
@@ -33,4 +31,73 @@ impl Channels {
         let url = "/channel".to_string();
         self.client.get_all_pages(&url, None).await
     }
+
+    /**
+     * Get user-authorized channel info.
+     *
+     * This function performs a `GET` to the `/channel` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     */
+    pub fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Channel> {
+        let url = "/channel".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The functions of [`Channels`], for code that takes them as a trait, so that it can be
+/// tested with the `MockChannelsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ChannelsApi: Send + Sync {
+    /// See [`Channels::get_page`].
+    async fn get_page(&self) -> Result<Vec<crate::types::Channel>>;
+
+    /// See [`Channels::get_all`].
+    async fn get_all(&self) -> Result<Vec<crate::types::Channel>>;
+
+    /// See [`Channels::get_stream`].
+    fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Channel>;
+}
+
+#[async_trait::async_trait]
+impl ChannelsApi for Channels {
+    async fn get_page(&self) -> Result<Vec<crate::types::Channel>> {
+        Channels::get_page(self).await
+    }
+
+    async fn get_all(&self) -> Result<Vec<crate::types::Channel>> {
+        Channels::get_all(self).await
+    }
+
+    fn get_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Channel> {
+        Channels::get_stream(self, start, max_items)
+    }
 }
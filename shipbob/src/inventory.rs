@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Inventory {
     pub client: Client,
@@ -37,50 +35,32 @@ impl Inventory {
      *
      * **Parameters:**
      *
-     * * `page: i64` -- Page of inventory items to get.
-     * * `limit: i64` -- Amount of inventory items per page to request.
-     * * `is_active: bool` -- True if the inventory item is marked as a digital item.
-     * * `is_digital: bool` -- True if the inventory item is marked as a digital item.
-     * * `i_ds: &[String]` -- Comma separated inventory ids to filter by.
-     * * `sort: &str` -- Sort will default to ascending order for each field.
-     *   To sort in descending order please pass a "-" in front of the field name.
-     *   For example, Sort=-onHand,name will sort by onHand descending.
-     * * `search: &str` -- Search is available for 2 fields, Inventory ID and Name -
-     *   1. Expected behavior for search by Inventory ID is exact match
-     *   2. Expected behavior for search by Inventory Name is partial match, i.e. does not have to be start of word,
-     *   but must be consecutive characters. This is not case sensitive.
-     * * `channel_id: i64` -- Unique id of the channel.
+     * * `params: &InventoryGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        page: i64,
-        limit: i64,
-        is_active: bool,
-        is_digital: bool,
-        ids: &[String],
-        sort: &str,
-        search: &str,
+        params: &InventoryGetPageParams,
     ) -> Result<Vec<crate::types::Inventory>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("IDs".to_string(), ids.join(" ")));
         }
-        if is_active {
+        if let Some(is_active) = &params.is_active {
             query_args.push(("IsActive".to_string(), is_active.to_string()));
         }
-        if is_digital {
+        if let Some(is_digital) = &params.is_digital {
             query_args.push(("IsDigital".to_string(), is_digital.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("Limit".to_string(), limit.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("Page".to_string(), page.to_string()));
         }
-        if !search.is_empty() {
+        if let Some(search) = &params.search {
             query_args.push(("Search".to_string(), search.to_string()));
         }
-        if !sort.is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("Sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -89,13 +69,73 @@ impl Inventory {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `InventoryGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        is_active: bool,
+        is_digital: bool,
+        ids: &[String],
+        sort: &str,
+        search: &str,
+    ) -> Result<Vec<crate::types::Inventory>> {
+        let params = InventoryGetPageParams {
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            is_active: Some(is_active).filter(|v| *v),
+            is_digital: Some(is_digital).filter(|v| *v),
+            limit: Some(limit).filter(|v| *v > 0),
+            page: Some(page).filter(|v| *v > 0),
+            search: Some(search.to_string()).filter(|s| !s.is_empty()),
+            sort: Some(sort.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * List inventory items.
      *
      * This function performs a `GET` to the `/inventory` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * **Parameters:**
+     *
+     * * `params: &InventoryGetPageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_with(
+        &self,
+        params: &InventoryGetPageParams,
+    ) -> Result<Vec<crate::types::Inventory>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(ids) = &params.ids {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if let Some(is_active) = &params.is_active {
+            query_args.push(("IsActive".to_string(), is_active.to_string()));
+        }
+        if let Some(is_digital) = &params.is_digital {
+            query_args.push(("IsDigital".to_string(), is_digital.to_string()));
+        }
+        if let Some(search) = &params.search {
+            query_args.push(("Search".to_string(), search.to_string()));
+        }
+        if let Some(sort) = &params.sort {
+            query_args.push(("Sort".to_string(), sort.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/inventory?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `InventoryGetPageParams` instead.
      */
+    #[deprecated(note = "use `get_all_with` instead")]
     pub async fn get_all(
         &self,
         is_active: bool,
@@ -104,26 +144,65 @@ impl Inventory {
         sort: &str,
         search: &str,
     ) -> Result<Vec<crate::types::Inventory>> {
+        let params = InventoryGetPageParams {
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            is_active: Some(is_active).filter(|v| *v),
+            is_digital: Some(is_digital).filter(|v| *v),
+            search: Some(search.to_string()).filter(|s| !s.is_empty()),
+            sort: Some(sort.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * List inventory items.
+     *
+     * This function performs a `GET` to the `/inventory` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     */
+    pub fn get_stream(
+        &self,
+        params: &InventoryGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Inventory> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("IDs".to_string(), ids.join(" ")));
         }
-        if is_active {
+        if let Some(is_active) = &params.is_active {
             query_args.push(("IsActive".to_string(), is_active.to_string()));
         }
-        if is_digital {
+        if let Some(is_digital) = &params.is_digital {
             query_args.push(("IsDigital".to_string(), is_digital.to_string()));
         }
-        if !search.is_empty() {
+        if let Some(search) = &params.search {
             query_args.push(("Search".to_string(), search.to_string()));
         }
-        if !sort.is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("Sort".to_string(), sort.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/inventory?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -160,4 +239,190 @@ impl Inventory {
 
         self.client.get_all_pages(&url, None).await
     }
+
+    /**
+     * Get a list of inventory items by product id.
+     *
+     * This function performs a `GET` to the `/product/{productId}/inventory` endpoint.
+     *
+     * As opposed to `get_all_product`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     */
+    pub fn get_product_stream(
+        &self,
+        product_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Inventory> {
+        let url = format!(
+            "/product/{}/inventory",
+            crate::progenitor_support::encode_path(&product_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Inventory::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryGetPageParams {
+    pub ids: Option<Vec<String>>,
+    /// True if the inventory item is marked as a digital item.
+    pub is_active: Option<bool>,
+    /// True if the inventory item is marked as a digital item.
+    pub is_digital: Option<bool>,
+    /// Amount of inventory items per page to request.
+    pub limit: Option<i64>,
+    /// Page of inventory items to get.
+    pub page: Option<i64>,
+    /// Search is available for 2 fields, Inventory ID and Name -
+    /// 1. Expected behavior for search by Inventory ID is exact match
+    /// 2. Expected behavior for search by Inventory Name is partial match, i.e. does not have to be start of word,
+    ///    but must be consecutive characters. This is not case sensitive.
+    pub search: Option<String>,
+    /// Sort will default to ascending order for each field.
+    /// To sort in descending order please pass a "-" in front of the field name.
+    /// For example, Sort=-onHand,name will sort by onHand descending.
+    pub sort: Option<String>,
+}
+
+impl InventoryGetPageParams {
+    pub fn ids(mut self, ids: Vec<String>) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn is_active(mut self, is_active: bool) -> Self {
+        self.is_active = Some(is_active);
+        self
+    }
+
+    pub fn is_digital(mut self, is_digital: bool) -> Self {
+        self.is_digital = Some(is_digital);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn search<T: ToString>(mut self, search: T) -> Self {
+        self.search = Some(search.to_string());
+        self
+    }
+
+    pub fn sort<T: ToString>(mut self, sort: T) -> Self {
+        self.sort = Some(sort.to_string());
+        self
+    }
+}
+
+/// The functions of [`Inventory`], for code that takes them as a trait, so that it can be
+/// tested with the `MockInventoryApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait InventoryApi: Send + Sync {
+    /// See [`Inventory::get`].
+    async fn get(&self, inventory_id: i64) -> Result<crate::types::Inventory>;
+
+    /// See [`Inventory::get_page_with`].
+    async fn get_page_with(
+        &self,
+        params: &InventoryGetPageParams,
+    ) -> Result<Vec<crate::types::Inventory>>;
+
+    /// See [`Inventory::get_all_with`].
+    async fn get_all_with(
+        &self,
+        params: &InventoryGetPageParams,
+    ) -> Result<Vec<crate::types::Inventory>>;
+
+    /// See [`Inventory::get_stream`].
+    fn get_stream(
+        &self,
+        params: &InventoryGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Inventory>;
+
+    /// See [`Inventory::get_product`].
+    async fn get_product(&self, product_id: i64) -> Result<Vec<crate::types::Inventory>>;
+
+    /// See [`Inventory::get_all_product`].
+    async fn get_all_product(&self, product_id: i64) -> Result<Vec<crate::types::Inventory>>;
+
+    /// See [`Inventory::get_product_stream`].
+    fn get_product_stream(
+        &self,
+        product_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Inventory>;
+}
+
+#[async_trait::async_trait]
+impl InventoryApi for Inventory {
+    async fn get(&self, inventory_id: i64) -> Result<crate::types::Inventory> {
+        Inventory::get(self, inventory_id).await
+    }
+
+    async fn get_page_with(
+        &self,
+        params: &InventoryGetPageParams,
+    ) -> Result<Vec<crate::types::Inventory>> {
+        Inventory::get_page_with(self, params).await
+    }
+
+    async fn get_all_with(
+        &self,
+        params: &InventoryGetPageParams,
+    ) -> Result<Vec<crate::types::Inventory>> {
+        Inventory::get_all_with(self, params).await
+    }
+
+    fn get_stream(
+        &self,
+        params: &InventoryGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Inventory> {
+        Inventory::get_stream(self, params, start, max_items)
+    }
+
+    async fn get_product(&self, product_id: i64) -> Result<Vec<crate::types::Inventory>> {
+        Inventory::get_product(self, product_id).await
+    }
+
+    async fn get_all_product(&self, product_id: i64) -> Result<Vec<crate::types::Inventory>> {
+        Inventory::get_all_product(self, product_id).await
+    }
+
+    fn get_product_stream(
+        &self,
+        product_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Inventory> {
+        Inventory::get_product_stream(self, product_id, start, max_items)
+    }
 }
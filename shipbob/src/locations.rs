@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Locations {
     pub client: Client,
@@ -19,24 +17,20 @@ impl Locations {
      *
      * **Parameters:**
      *
-     * * `include_inactive: bool` -- True if the inventory item is marked as a digital item.
-     * * `receiving_enabled: bool` -- True if the inventory item is marked as a digital item.
-     * * `access_granted: bool` -- True if the inventory item is marked as a digital item.
+     * * `params: &LocationsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        include_inactive: bool,
-        receiving_enabled: bool,
-        access_granted: bool,
+        params: &LocationsGetPageParams,
     ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if access_granted {
+        if let Some(access_granted) = &params.access_granted {
             query_args.push(("AccessGranted".to_string(), access_granted.to_string()));
         }
-        if include_inactive {
+        if let Some(include_inactive) = &params.include_inactive {
             query_args.push(("IncludeInactive".to_string(), include_inactive.to_string()));
         }
-        if receiving_enabled {
+        if let Some(receiving_enabled) = &params.receiving_enabled {
             query_args.push((
                 "ReceivingEnabled".to_string(),
                 receiving_enabled.to_string(),
@@ -48,27 +42,97 @@ impl Locations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `LocationsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        include_inactive: bool,
+        receiving_enabled: bool,
+        access_granted: bool,
+    ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
+        let params = LocationsGetPageParams {
+            access_granted: Some(access_granted).filter(|v| *v),
+            include_inactive: Some(include_inactive).filter(|v| *v),
+            receiving_enabled: Some(receiving_enabled).filter(|v| *v),
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * Get locations.
      *
      * This function performs a `GET` to the `/location` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * **Parameters:**
+     *
+     * * `params: &LocationsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_with(
+        &self,
+        params: &LocationsGetPageParams,
+    ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(access_granted) = &params.access_granted {
+            query_args.push(("AccessGranted".to_string(), access_granted.to_string()));
+        }
+        if let Some(include_inactive) = &params.include_inactive {
+            query_args.push(("IncludeInactive".to_string(), include_inactive.to_string()));
+        }
+        if let Some(receiving_enabled) = &params.receiving_enabled {
+            query_args.push((
+                "ReceivingEnabled".to_string(),
+                receiving_enabled.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/location?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `LocationsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
     pub async fn get_all(
         &self,
         include_inactive: bool,
         receiving_enabled: bool,
         access_granted: bool,
     ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
+        let params = LocationsGetPageParams {
+            access_granted: Some(access_granted).filter(|v| *v),
+            include_inactive: Some(include_inactive).filter(|v| *v),
+            receiving_enabled: Some(receiving_enabled).filter(|v| *v),
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * Get locations.
+     *
+     * This function performs a `GET` to the `/location` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     */
+    pub fn get_stream(
+        &self,
+        params: &LocationsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IntegrationsLocationInternalAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if access_granted {
+        if let Some(access_granted) = &params.access_granted {
             query_args.push(("AccessGranted".to_string(), access_granted.to_string()));
         }
-        if include_inactive {
+        if let Some(include_inactive) = &params.include_inactive {
             query_args.push(("IncludeInactive".to_string(), include_inactive.to_string()));
         }
-        if receiving_enabled {
+        if let Some(receiving_enabled) = &params.receiving_enabled {
             query_args.push((
                 "ReceivingEnabled".to_string(),
                 receiving_enabled.to_string(),
@@ -77,6 +141,101 @@ impl Locations {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/location?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Locations::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct LocationsGetPageParams {
+    /// True if the inventory item is marked as a digital item.
+    pub access_granted: Option<bool>,
+    /// True if the inventory item is marked as a digital item.
+    pub include_inactive: Option<bool>,
+    /// True if the inventory item is marked as a digital item.
+    pub receiving_enabled: Option<bool>,
+}
+
+impl LocationsGetPageParams {
+    pub fn access_granted(mut self, access_granted: bool) -> Self {
+        self.access_granted = Some(access_granted);
+        self
+    }
+
+    pub fn include_inactive(mut self, include_inactive: bool) -> Self {
+        self.include_inactive = Some(include_inactive);
+        self
+    }
+
+    pub fn receiving_enabled(mut self, receiving_enabled: bool) -> Self {
+        self.receiving_enabled = Some(receiving_enabled);
+        self
+    }
+}
+
+/// The functions of [`Locations`], for code that takes them as a trait, so that it can be
+/// tested with the `MockLocationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait LocationsApi: Send + Sync {
+    /// See [`Locations::get_page_with`].
+    async fn get_page_with(
+        &self,
+        params: &LocationsGetPageParams,
+    ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>>;
+
+    /// See [`Locations::get_all_with`].
+    async fn get_all_with(
+        &self,
+        params: &LocationsGetPageParams,
+    ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>>;
+
+    /// See [`Locations::get_stream`].
+    fn get_stream(
+        &self,
+        params: &LocationsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IntegrationsLocationInternalAllOf>;
+}
+
+#[async_trait::async_trait]
+impl LocationsApi for Locations {
+    async fn get_page_with(
+        &self,
+        params: &LocationsGetPageParams,
+    ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
+        Locations::get_page_with(self, params).await
+    }
+
+    async fn get_all_with(
+        &self,
+        params: &LocationsGetPageParams,
+    ) -> Result<Vec<crate::types::IntegrationsLocationInternalAllOf>> {
+        Locations::get_all_with(self, params).await
+    }
+
+    fn get_stream(
+        &self,
+        params: &LocationsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IntegrationsLocationInternalAllOf> {
+        Locations::get_stream(self, params, start, max_items)
     }
 }
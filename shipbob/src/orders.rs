@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Orders {
     pub client: Client,
@@ -64,69 +62,54 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `page: i64` -- Unique id of the channel.
-     * * `limit: i64` -- Amount of orders per page to request.
-     * * `i_ds: &[String]` -- Shipment IDs to cancel.
-     * * `reference_ids: &[String]` -- Array of permissions granted for the channel.
-     * * `start_date: chrono::DateTime<chrono::Utc>` -- Start date to filter orders inserted later than.
-     * * `end_date: chrono::DateTime<chrono::Utc>` -- End date to filter orders inserted earlier than.
-     * * `sort_order: crate::types::SortOrder` -- Order to sort results in.
-     * * `has_tracking: bool` -- Has any portion of this order been assigned a tracking number.
-     * * `last_update_start_date: chrono::DateTime<chrono::Utc>` -- Start date to filter orders updated later than.
-     * * `last_update_end_date: chrono::DateTime<chrono::Utc>` -- End date to filter orders updated later than.
-     * * `is_tracking_uploaded: bool` -- Filter orders that their tracking information was fully uploaded.
-     * * `channel_id: i64` -- Unique id of the channel.
+     * * `params: &OrdersGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        page: i64,
-        limit: i64,
-        ids: &[String],
-        reference_ids: &[String],
-        start_date: Option<chrono::DateTime<chrono::Utc>>,
-        end_date: Option<chrono::DateTime<chrono::Utc>>,
-        sort_order: crate::types::SortOrder,
-        has_tracking: bool,
-        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
-        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
-        is_tracking_uploaded: bool,
+        params: &OrdersGetPageParams,
     ) -> Result<Vec<crate::types::Order>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = end_date {
-            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("EndDate".to_string(), end_date.to_rfc3339()));
         }
-        if has_tracking {
+        if let Some(has_tracking) = &params.has_tracking {
             query_args.push(("HasTracking".to_string(), has_tracking.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("IDs".to_string(), ids.join(" ")));
         }
-        if is_tracking_uploaded {
+        if let Some(is_tracking_uploaded) = &params.is_tracking_uploaded {
             query_args.push((
                 "IsTrackingUploaded".to_string(),
                 is_tracking_uploaded.to_string(),
             ));
         }
-        if let Some(date) = last_update_end_date {
-            query_args.push(("LastUpdateEndDate".to_string(), date.to_rfc3339()));
+        if let Some(last_update_end_date) = &params.last_update_end_date {
+            query_args.push((
+                "LastUpdateEndDate".to_string(),
+                last_update_end_date.to_rfc3339(),
+            ));
         }
-        if let Some(date) = last_update_start_date {
-            query_args.push(("LastUpdateStartDate".to_string(), date.to_rfc3339()));
+        if let Some(last_update_start_date) = &params.last_update_start_date {
+            query_args.push((
+                "LastUpdateStartDate".to_string(),
+                last_update_start_date.to_rfc3339(),
+            ));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("Limit".to_string(), limit.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("Page".to_string(), page.to_string()));
         }
-        if !reference_ids.is_empty() {
+        if let Some(reference_ids) = &params.reference_ids {
             query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
         }
-        if !sort_order.to_string().is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("SortOrder".to_string(), sort_order.to_string()));
         }
-        if let Some(date) = start_date {
-            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("StartDate".to_string(), start_date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/order?{}", query_);
@@ -134,6 +117,40 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `OrdersGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        ids: &[String],
+        reference_ids: &[String],
+        start_date: Option<chrono::DateTime<chrono::Utc>>,
+        end_date: Option<chrono::DateTime<chrono::Utc>>,
+        sort_order: crate::types::SortOrder,
+        has_tracking: bool,
+        last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+        last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+        is_tracking_uploaded: bool,
+    ) -> Result<Vec<crate::types::Order>> {
+        let params = OrdersGetPageParams {
+            end_date,
+            has_tracking: Some(has_tracking).filter(|v| *v),
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            is_tracking_uploaded: Some(is_tracking_uploaded).filter(|v| *v),
+            last_update_end_date,
+            last_update_start_date,
+            limit: Some(limit).filter(|v| *v > 0),
+            page: Some(page).filter(|v| *v > 0),
+            reference_ids: Some(reference_ids.to_vec()).filter(|v| !v.is_empty()),
+            sort_order: Some(sort_order).filter(|v| !v.to_string().is_empty()),
+            start_date,
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * Get Orders.
      *
@@ -142,7 +159,62 @@ impl Orders {
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * All parameters are AND filters
+     *
+     * **Parameters:**
+     *
+     * * `params: &OrdersGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_with(
+        &self,
+        params: &OrdersGetPageParams,
+    ) -> Result<Vec<crate::types::Order>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("EndDate".to_string(), end_date.to_rfc3339()));
+        }
+        if let Some(has_tracking) = &params.has_tracking {
+            query_args.push(("HasTracking".to_string(), has_tracking.to_string()));
+        }
+        if let Some(ids) = &params.ids {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if let Some(is_tracking_uploaded) = &params.is_tracking_uploaded {
+            query_args.push((
+                "IsTrackingUploaded".to_string(),
+                is_tracking_uploaded.to_string(),
+            ));
+        }
+        if let Some(last_update_end_date) = &params.last_update_end_date {
+            query_args.push((
+                "LastUpdateEndDate".to_string(),
+                last_update_end_date.to_rfc3339(),
+            ));
+        }
+        if let Some(last_update_start_date) = &params.last_update_start_date {
+            query_args.push((
+                "LastUpdateStartDate".to_string(),
+                last_update_start_date.to_rfc3339(),
+            ));
+        }
+        if let Some(reference_ids) = &params.reference_ids {
+            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        }
+        if let Some(sort_order) = &params.sort_order {
+            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("StartDate".to_string(), start_date.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/order?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `OrdersGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
     pub async fn get_all(
         &self,
         ids: &[String],
@@ -155,41 +227,92 @@ impl Orders {
         last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
         is_tracking_uploaded: bool,
     ) -> Result<Vec<crate::types::Order>> {
+        let params = OrdersGetPageParams {
+            end_date,
+            has_tracking: Some(has_tracking).filter(|v| *v),
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            is_tracking_uploaded: Some(is_tracking_uploaded).filter(|v| *v),
+            last_update_end_date,
+            last_update_start_date,
+            reference_ids: Some(reference_ids.to_vec()).filter(|v| !v.is_empty()),
+            sort_order: Some(sort_order).filter(|v| !v.to_string().is_empty()),
+            start_date,
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * Get Orders.
+     *
+     * This function performs a `GET` to the `/order` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * All parameters are AND filters
+     */
+    pub fn get_stream(
+        &self,
+        params: &OrdersGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Order> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = end_date {
-            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("EndDate".to_string(), end_date.to_rfc3339()));
         }
-        if has_tracking {
+        if let Some(has_tracking) = &params.has_tracking {
             query_args.push(("HasTracking".to_string(), has_tracking.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("IDs".to_string(), ids.join(" ")));
         }
-        if is_tracking_uploaded {
+        if let Some(is_tracking_uploaded) = &params.is_tracking_uploaded {
             query_args.push((
                 "IsTrackingUploaded".to_string(),
                 is_tracking_uploaded.to_string(),
             ));
         }
-        if let Some(date) = last_update_end_date {
-            query_args.push(("LastUpdateEndDate".to_string(), date.to_rfc3339()));
+        if let Some(last_update_end_date) = &params.last_update_end_date {
+            query_args.push((
+                "LastUpdateEndDate".to_string(),
+                last_update_end_date.to_rfc3339(),
+            ));
         }
-        if let Some(date) = last_update_start_date {
-            query_args.push(("LastUpdateStartDate".to_string(), date.to_rfc3339()));
+        if let Some(last_update_start_date) = &params.last_update_start_date {
+            query_args.push((
+                "LastUpdateStartDate".to_string(),
+                last_update_start_date.to_rfc3339(),
+            ));
         }
-        if !reference_ids.is_empty() {
+        if let Some(reference_ids) = &params.reference_ids {
             query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
         }
-        if !sort_order.to_string().is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("SortOrder".to_string(), sort_order.to_string()));
         }
-        if let Some(date) = start_date {
-            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("StartDate".to_string(), start_date.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/order?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -368,6 +491,44 @@ impl Orders {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get one Shipment's status timeline by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_all_shipment_timeline`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     */
+    pub fn get_shipment_timeline_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipmentLog> {
+        let url = format!(
+            "/order/{}/shipment/{}/timeline",
+            crate::progenitor_support::encode_path(&order_id.to_string()),
+            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get all Shipments for Order.
      *
@@ -406,6 +567,42 @@ impl Orders {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get all Shipments for Order.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment` endpoint.
+     *
+     * As opposed to `get_all_shipments`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     */
+    pub fn get_shipments_stream(
+        &self,
+        order_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipment> {
+        let url = format!(
+            "/order/{}/shipment",
+            crate::progenitor_support::encode_path(&order_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get logs for one Shipment by Order Id and Shipment Id.
      *
@@ -452,6 +649,44 @@ impl Orders {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get logs for one Shipment by Order Id and Shipment Id.
+     *
+     * This function performs a `GET` to the `/order/{orderId}/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_all_shipment_logs`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     */
+    pub fn get_shipment_logs_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipmentLog> {
+        let url = format!(
+            "/order/{}/shipment/{}/logs",
+            crate::progenitor_support::encode_path(&order_id.to_string()),
+            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get one Shipment by Shipment Id.
      *
@@ -556,6 +791,42 @@ impl Orders {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get one Shipment's status timeline by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/timeline` endpoint.
+     *
+     * As opposed to `get_all_shipment_timeline_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     */
+    pub fn get_shipment_timeline_orders_stream(
+        &self,
+        shipment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipmentLog> {
+        let url = format!(
+            "/shipment/{}/timeline",
+            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get logs for one Shipment by Shipment Id.
      *
@@ -597,6 +868,42 @@ impl Orders {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get logs for one Shipment by Shipment Id.
+     *
+     * This function performs a `GET` to the `/shipment/{shipmentId}/logs` endpoint.
+     *
+     * As opposed to `get_all_shipment_logs_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     */
+    pub fn get_shipment_logs_orders_stream(
+        &self,
+        shipment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipmentLog> {
+        let url = format!(
+            "/shipment/{}/logs",
+            crate::progenitor_support::encode_path(&shipment_id.to_string()),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get shipping methods.
      *
@@ -606,19 +913,17 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `page: i64` -- Unique id of the channel.
-     * * `limit: i64` -- Amount of records per page to request.
+     * * `params: &OrdersGetShippingMethodParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_shipping_method(
+    pub async fn get_shipping_method_with(
         &self,
-        page: i64,
-        limit: i64,
+        params: &OrdersGetShippingMethodParams,
     ) -> Result<Vec<crate::types::OrdersShipMethodDetail>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("Limit".to_string(), limit.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("Page".to_string(), page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -627,6 +932,22 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_shipping_method_with` and pass the query parameters as a `OrdersGetShippingMethodParams` instead.
+     */
+    #[deprecated(note = "use `get_shipping_method_with` instead")]
+    pub async fn get_shipping_method(
+        &self,
+        page: i64,
+        limit: i64,
+    ) -> Result<Vec<crate::types::OrdersShipMethodDetail>> {
+        let params = OrdersGetShippingMethodParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            page: Some(page).filter(|v| *v > 0),
+        };
+        self.get_shipping_method_with(&params).await
+    }
+
     /**
      * Get shipping methods.
      *
@@ -642,4 +963,566 @@ impl Orders {
         let url = "/shippingmethod".to_string();
         self.client.get_all_pages(&url, None).await
     }
+
+    /**
+     * Get shipping methods.
+     *
+     * This function performs a `GET` to the `/shippingmethod` endpoint.
+     *
+     * As opposed to `get_all_shipping_method`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Get all merchants shipping methods
+     */
+    pub fn get_shipping_method_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipMethodDetail> {
+        let url = "/shippingmethod".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Orders::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersGetPageParams {
+    /// End date to filter orders inserted earlier than.
+    pub end_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// Has any portion of this order been assigned a tracking number.
+    pub has_tracking: Option<bool>,
+    pub ids: Option<Vec<String>>,
+    /// Filter orders that their tracking information was fully uploaded.
+    pub is_tracking_uploaded: Option<bool>,
+    /// End date to filter orders updated later than.
+    pub last_update_end_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// Start date to filter orders updated later than.
+    pub last_update_start_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// Amount of orders per page to request.
+    pub limit: Option<i64>,
+    /// Unique id of the channel.
+    pub page: Option<i64>,
+    /// Array of permissions granted for the channel.
+    pub reference_ids: Option<Vec<String>>,
+    /// Order to sort results in.
+    pub sort_order: Option<crate::types::SortOrder>,
+    /// Start date to filter orders inserted later than.
+    pub start_date: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl OrdersGetPageParams {
+    pub fn end_date(mut self, end_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    pub fn has_tracking(mut self, has_tracking: bool) -> Self {
+        self.has_tracking = Some(has_tracking);
+        self
+    }
+
+    pub fn ids(mut self, ids: Vec<String>) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn is_tracking_uploaded(mut self, is_tracking_uploaded: bool) -> Self {
+        self.is_tracking_uploaded = Some(is_tracking_uploaded);
+        self
+    }
+
+    pub fn last_update_end_date(
+        mut self,
+        last_update_end_date: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        self.last_update_end_date = Some(last_update_end_date);
+        self
+    }
+
+    pub fn last_update_start_date(
+        mut self,
+        last_update_start_date: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        self.last_update_start_date = Some(last_update_start_date);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn reference_ids(mut self, reference_ids: Vec<String>) -> Self {
+        self.reference_ids = Some(reference_ids);
+        self
+    }
+
+    pub fn sort_order(mut self, sort_order: crate::types::SortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    pub fn start_date(mut self, start_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+}
+
+/// The query parameters of `Orders::get_shipping_method_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersGetShippingMethodParams {
+    /// Amount of records per page to request.
+    pub limit: Option<i64>,
+    /// Unique id of the channel.
+    pub page: Option<i64>,
+}
+
+impl OrdersGetShippingMethodParams {
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+/// The functions of [`Orders`], for code that takes them as a trait, so that it can be
+/// tested with the `MockOrdersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait OrdersApi: Send + Sync {
+    /// See [`Orders::post_estimate`].
+    async fn post_estimate(
+        &self,
+        body: &crate::types::OrdersEstimateFulfillmentRequestModel,
+    ) -> Result<crate::types::OrdersEstimate>;
+
+    /// See [`Orders::get`].
+    async fn get(&self, order_id: i64) -> Result<crate::types::Order>;
+
+    /// See [`Orders::get_page_with`].
+    async fn get_page_with(&self, params: &OrdersGetPageParams)
+        -> Result<Vec<crate::types::Order>>;
+
+    /// See [`Orders::get_all_with`].
+    async fn get_all_with(&self, params: &OrdersGetPageParams) -> Result<Vec<crate::types::Order>>;
+
+    /// See [`Orders::get_stream`].
+    fn get_stream(
+        &self,
+        params: &OrdersGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Order>;
+
+    /// See [`Orders::post`].
+    async fn post(
+        &self,
+        body: &crate::types::OrdersCreateOrderModel,
+    ) -> Result<crate::types::Order>;
+
+    /// See [`Orders::post_cancel`].
+    async fn post_cancel(&self, order_id: i64) -> Result<crate::types::OrdersCanceledOrder>;
+
+    /// See [`Orders::get_store_json`].
+    async fn get_store_json(&self, order_id: i64) -> Result<String>;
+
+    /// See [`Orders::post_store_json`].
+    async fn post_store_json(
+        &self,
+        order_id: i64,
+        body: &crate::types::OrdersAddStoreOrderJsonModel,
+    ) -> Result<String>;
+
+    /// See [`Orders::get_shipment`].
+    async fn get_shipment(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<crate::types::OrdersShipment>;
+
+    /// See [`Orders::post_shipment_cancel`].
+    async fn post_shipment_cancel(
+        &self,
+        shipment_id: i64,
+        order_id: &str,
+    ) -> Result<crate::types::OrdersShipment>;
+
+    /// See [`Orders::get_shipment_timeline`].
+    async fn get_shipment_timeline(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+
+    /// See [`Orders::get_all_shipment_timeline`].
+    async fn get_all_shipment_timeline(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+
+    /// See [`Orders::get_shipment_timeline_stream`].
+    fn get_shipment_timeline_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipmentLog>;
+
+    /// See [`Orders::get_shipments`].
+    async fn get_shipments(&self, order_id: i64) -> Result<Vec<crate::types::OrdersShipment>>;
+
+    /// See [`Orders::get_all_shipments`].
+    async fn get_all_shipments(&self, order_id: i64) -> Result<Vec<crate::types::OrdersShipment>>;
+
+    /// See [`Orders::get_shipments_stream`].
+    fn get_shipments_stream(
+        &self,
+        order_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipment>;
+
+    /// See [`Orders::get_shipment_logs`].
+    async fn get_shipment_logs(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+
+    /// See [`Orders::get_all_shipment_logs`].
+    async fn get_all_shipment_logs(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+
+    /// See [`Orders::get_shipment_logs_stream`].
+    fn get_shipment_logs_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipmentLog>;
+
+    /// See [`Orders::get_shipment_orders`].
+    async fn get_shipment_orders(&self, shipment_id: i64) -> Result<crate::types::OrdersShipment>;
+
+    /// See [`Orders::post_shipment_cancel_orders`].
+    async fn post_shipment_cancel_orders(
+        &self,
+        shipment_id: i64,
+    ) -> Result<crate::types::OrdersShipment>;
+
+    /// See [`Orders::post_shipment_cancel_bulk`].
+    async fn post_shipment_cancel_bulk(
+        &self,
+        body: &crate::types::OrdersCancelShipmentsModel,
+    ) -> Result<crate::types::OrdersCanceledShipments>;
+
+    /// See [`Orders::get_shipment_timeline_orders`].
+    async fn get_shipment_timeline_orders(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+
+    /// See [`Orders::get_all_shipment_timeline_all`].
+    async fn get_all_shipment_timeline_all(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+
+    /// See [`Orders::get_shipment_timeline_orders_stream`].
+    fn get_shipment_timeline_orders_stream(
+        &self,
+        shipment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipmentLog>;
+
+    /// See [`Orders::get_shipment_logs_orders`].
+    async fn get_shipment_logs_orders(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+
+    /// See [`Orders::get_all_shipment_logs_all`].
+    async fn get_all_shipment_logs_all(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>>;
+
+    /// See [`Orders::get_shipment_logs_orders_stream`].
+    fn get_shipment_logs_orders_stream(
+        &self,
+        shipment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipmentLog>;
+
+    /// See [`Orders::get_shipping_method_with`].
+    async fn get_shipping_method_with(
+        &self,
+        params: &OrdersGetShippingMethodParams,
+    ) -> Result<Vec<crate::types::OrdersShipMethodDetail>>;
+
+    /// See [`Orders::get_all_shipping_method`].
+    async fn get_all_shipping_method(&self) -> Result<Vec<crate::types::OrdersShipMethodDetail>>;
+
+    /// See [`Orders::get_shipping_method_stream`].
+    fn get_shipping_method_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipMethodDetail>;
+}
+
+#[async_trait::async_trait]
+impl OrdersApi for Orders {
+    async fn post_estimate(
+        &self,
+        body: &crate::types::OrdersEstimateFulfillmentRequestModel,
+    ) -> Result<crate::types::OrdersEstimate> {
+        Orders::post_estimate(self, body).await
+    }
+
+    async fn get(&self, order_id: i64) -> Result<crate::types::Order> {
+        Orders::get(self, order_id).await
+    }
+
+    async fn get_page_with(
+        &self,
+        params: &OrdersGetPageParams,
+    ) -> Result<Vec<crate::types::Order>> {
+        Orders::get_page_with(self, params).await
+    }
+
+    async fn get_all_with(&self, params: &OrdersGetPageParams) -> Result<Vec<crate::types::Order>> {
+        Orders::get_all_with(self, params).await
+    }
+
+    fn get_stream(
+        &self,
+        params: &OrdersGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Order> {
+        Orders::get_stream(self, params, start, max_items)
+    }
+
+    async fn post(
+        &self,
+        body: &crate::types::OrdersCreateOrderModel,
+    ) -> Result<crate::types::Order> {
+        Orders::post(self, body).await
+    }
+
+    async fn post_cancel(&self, order_id: i64) -> Result<crate::types::OrdersCanceledOrder> {
+        Orders::post_cancel(self, order_id).await
+    }
+
+    async fn get_store_json(&self, order_id: i64) -> Result<String> {
+        Orders::get_store_json(self, order_id).await
+    }
+
+    async fn post_store_json(
+        &self,
+        order_id: i64,
+        body: &crate::types::OrdersAddStoreOrderJsonModel,
+    ) -> Result<String> {
+        Orders::post_store_json(self, order_id, body).await
+    }
+
+    async fn get_shipment(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<crate::types::OrdersShipment> {
+        Orders::get_shipment(self, order_id, shipment_id).await
+    }
+
+    async fn post_shipment_cancel(
+        &self,
+        shipment_id: i64,
+        order_id: &str,
+    ) -> Result<crate::types::OrdersShipment> {
+        Orders::post_shipment_cancel(self, shipment_id, order_id).await
+    }
+
+    async fn get_shipment_timeline(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_shipment_timeline(self, order_id, shipment_id).await
+    }
+
+    async fn get_all_shipment_timeline(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_all_shipment_timeline(self, order_id, shipment_id).await
+    }
+
+    fn get_shipment_timeline_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipmentLog> {
+        Orders::get_shipment_timeline_stream(self, order_id, shipment_id, start, max_items)
+    }
+
+    async fn get_shipments(&self, order_id: i64) -> Result<Vec<crate::types::OrdersShipment>> {
+        Orders::get_shipments(self, order_id).await
+    }
+
+    async fn get_all_shipments(&self, order_id: i64) -> Result<Vec<crate::types::OrdersShipment>> {
+        Orders::get_all_shipments(self, order_id).await
+    }
+
+    fn get_shipments_stream(
+        &self,
+        order_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipment> {
+        Orders::get_shipments_stream(self, order_id, start, max_items)
+    }
+
+    async fn get_shipment_logs(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_shipment_logs(self, order_id, shipment_id).await
+    }
+
+    async fn get_all_shipment_logs(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_all_shipment_logs(self, order_id, shipment_id).await
+    }
+
+    fn get_shipment_logs_stream(
+        &self,
+        order_id: i64,
+        shipment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipmentLog> {
+        Orders::get_shipment_logs_stream(self, order_id, shipment_id, start, max_items)
+    }
+
+    async fn get_shipment_orders(&self, shipment_id: i64) -> Result<crate::types::OrdersShipment> {
+        Orders::get_shipment_orders(self, shipment_id).await
+    }
+
+    async fn post_shipment_cancel_orders(
+        &self,
+        shipment_id: i64,
+    ) -> Result<crate::types::OrdersShipment> {
+        Orders::post_shipment_cancel_orders(self, shipment_id).await
+    }
+
+    async fn post_shipment_cancel_bulk(
+        &self,
+        body: &crate::types::OrdersCancelShipmentsModel,
+    ) -> Result<crate::types::OrdersCanceledShipments> {
+        Orders::post_shipment_cancel_bulk(self, body).await
+    }
+
+    async fn get_shipment_timeline_orders(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_shipment_timeline_orders(self, shipment_id).await
+    }
+
+    async fn get_all_shipment_timeline_all(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_all_shipment_timeline_all(self, shipment_id).await
+    }
+
+    fn get_shipment_timeline_orders_stream(
+        &self,
+        shipment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipmentLog> {
+        Orders::get_shipment_timeline_orders_stream(self, shipment_id, start, max_items)
+    }
+
+    async fn get_shipment_logs_orders(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_shipment_logs_orders(self, shipment_id).await
+    }
+
+    async fn get_all_shipment_logs_all(
+        &self,
+        shipment_id: i64,
+    ) -> Result<Vec<crate::types::OrdersShipmentLog>> {
+        Orders::get_all_shipment_logs_all(self, shipment_id).await
+    }
+
+    fn get_shipment_logs_orders_stream(
+        &self,
+        shipment_id: i64,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipmentLog> {
+        Orders::get_shipment_logs_orders_stream(self, shipment_id, start, max_items)
+    }
+
+    async fn get_shipping_method_with(
+        &self,
+        params: &OrdersGetShippingMethodParams,
+    ) -> Result<Vec<crate::types::OrdersShipMethodDetail>> {
+        Orders::get_shipping_method_with(self, params).await
+    }
+
+    async fn get_all_shipping_method(&self) -> Result<Vec<crate::types::OrdersShipMethodDetail>> {
+        Orders::get_all_shipping_method(self).await
+    }
+
+    fn get_shipping_method_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::OrdersShipMethodDetail> {
+        Orders::get_shipping_method_stream(self, start, max_items)
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Products {
     pub client: Client,
@@ -19,54 +17,32 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `page: i64` -- Unique id of the channel.
-     * * `limit: i64` -- Amount of products per page to request.
-     * * `i_ds: &[String]` -- Comma separated list of product ids to filter by.
-     * * `reference_ids: &[String]` -- Comma separated list of reference ids to filter by.
-     * * `search: &str` -- Search is available for 2 fields of the inventory record related to the product: Inventory ID and Name -
-     *   1. Expected behavior for search by Inventory ID is exact match
-     *   2. Expected behavior for search by Inventory Name is partial match, i.e. does not have to be start of word,
-     *   but must be consecutive characters. This is not case sensitive.
-     * * `active_status: crate::types::ProductActiveStatus` -- Status filter for products:
-     *   - Any: Include both active and inactive
-     *   - Active: Filter products that are Active
-     *   - Inactive: Filter products that are Inactive.
-     * * `bundle_status: crate::types::ProductBundleStatus` -- Bundle filter for products:
-     *   - Any: Don't filter and consider products that are bundles or not bundles
-     *   - Bundle: Filter by products that are bundles
-     *   - NotBundle: Filter by products that are not bundles.
-     * * `channel_id: i64` -- Unique id of the channel.
+     * * `params: &ProductsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        page: i64,
-        limit: i64,
-        ids: &[String],
-        reference_ids: &[String],
-        search: &str,
-        active_status: crate::types::ProductActiveStatus,
-        bundle_status: crate::types::ProductBundleStatus,
+        params: &ProductsGetPageParams,
     ) -> Result<Vec<crate::types::Product>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !active_status.to_string().is_empty() {
+        if let Some(active_status) = &params.active_status {
             query_args.push(("ActiveStatus".to_string(), active_status.to_string()));
         }
-        if !bundle_status.to_string().is_empty() {
+        if let Some(bundle_status) = &params.bundle_status {
             query_args.push(("BundleStatus".to_string(), bundle_status.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("IDs".to_string(), ids.join(" ")));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("Limit".to_string(), limit.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("Page".to_string(), page.to_string()));
         }
-        if !reference_ids.is_empty() {
+        if let Some(reference_ids) = &params.reference_ids {
             query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
         }
-        if !search.is_empty() {
+        if let Some(search) = &params.search {
             query_args.push(("Search".to_string(), search.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -75,13 +51,73 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `ProductsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        page: i64,
+        limit: i64,
+        ids: &[String],
+        reference_ids: &[String],
+        search: &str,
+        active_status: crate::types::ProductActiveStatus,
+        bundle_status: crate::types::ProductBundleStatus,
+    ) -> Result<Vec<crate::types::Product>> {
+        let params = ProductsGetPageParams {
+            active_status: Some(active_status).filter(|v| !v.to_string().is_empty()),
+            bundle_status: Some(bundle_status).filter(|v| !v.to_string().is_empty()),
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            page: Some(page).filter(|v| *v > 0),
+            reference_ids: Some(reference_ids.to_vec()).filter(|v| !v.is_empty()),
+            search: Some(search.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * Get multiple products.
      *
      * This function performs a `GET` to the `/product` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * **Parameters:**
+     *
+     * * `params: &ProductsGetPageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_with(
+        &self,
+        params: &ProductsGetPageParams,
+    ) -> Result<Vec<crate::types::Product>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(active_status) = &params.active_status {
+            query_args.push(("ActiveStatus".to_string(), active_status.to_string()));
+        }
+        if let Some(bundle_status) = &params.bundle_status {
+            query_args.push(("BundleStatus".to_string(), bundle_status.to_string()));
+        }
+        if let Some(ids) = &params.ids {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if let Some(reference_ids) = &params.reference_ids {
+            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        }
+        if let Some(search) = &params.search {
+            query_args.push(("Search".to_string(), search.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/product?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `ProductsGetPageParams` instead.
      */
+    #[deprecated(note = "use `get_all_with` instead")]
     pub async fn get_all(
         &self,
         ids: &[String],
@@ -90,26 +126,65 @@ impl Products {
         active_status: crate::types::ProductActiveStatus,
         bundle_status: crate::types::ProductBundleStatus,
     ) -> Result<Vec<crate::types::Product>> {
+        let params = ProductsGetPageParams {
+            active_status: Some(active_status).filter(|v| !v.to_string().is_empty()),
+            bundle_status: Some(bundle_status).filter(|v| !v.to_string().is_empty()),
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            reference_ids: Some(reference_ids.to_vec()).filter(|v| !v.is_empty()),
+            search: Some(search.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * Get multiple products.
+     *
+     * This function performs a `GET` to the `/product` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     */
+    pub fn get_stream(
+        &self,
+        params: &ProductsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Product> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !active_status.to_string().is_empty() {
+        if let Some(active_status) = &params.active_status {
             query_args.push(("ActiveStatus".to_string(), active_status.to_string()));
         }
-        if !bundle_status.to_string().is_empty() {
+        if let Some(bundle_status) = &params.bundle_status {
             query_args.push(("BundleStatus".to_string(), bundle_status.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("IDs".to_string(), ids.join(" ")));
         }
-        if !reference_ids.is_empty() {
+        if let Some(reference_ids) = &params.reference_ids {
             query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
         }
-        if !search.is_empty() {
+        if let Some(search) = &params.search {
             query_args.push(("Search".to_string(), search.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/product?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -194,3 +269,167 @@ impl Products {
             .await
     }
 }
+
+/// The query parameters of `Products::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetPageParams {
+    /// Status filter for products:
+    /// - Any: Include both active and inactive
+    /// - Active: Filter products that are Active
+    /// - Inactive: Filter products that are Inactive.
+    pub active_status: Option<crate::types::ProductActiveStatus>,
+    /// Bundle filter for products:
+    /// - Any: Don't filter and consider products that are bundles or not bundles
+    /// - Bundle: Filter by products that are bundles
+    /// - NotBundle: Filter by products that are not bundles.
+    pub bundle_status: Option<crate::types::ProductBundleStatus>,
+    pub ids: Option<Vec<String>>,
+    /// Amount of products per page to request.
+    pub limit: Option<i64>,
+    /// Unique id of the channel.
+    pub page: Option<i64>,
+    /// Comma separated list of reference ids to filter by.
+    pub reference_ids: Option<Vec<String>>,
+    /// Search is available for 2 fields of the inventory record related to the product: Inventory ID and Name -
+    /// 1. Expected behavior for search by Inventory ID is exact match
+    /// 2. Expected behavior for search by Inventory Name is partial match, i.e. does not have to be start of word,
+    ///    but must be consecutive characters. This is not case sensitive.
+    pub search: Option<String>,
+}
+
+impl ProductsGetPageParams {
+    pub fn active_status(mut self, active_status: crate::types::ProductActiveStatus) -> Self {
+        self.active_status = Some(active_status);
+        self
+    }
+
+    pub fn bundle_status(mut self, bundle_status: crate::types::ProductBundleStatus) -> Self {
+        self.bundle_status = Some(bundle_status);
+        self
+    }
+
+    pub fn ids(mut self, ids: Vec<String>) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn reference_ids(mut self, reference_ids: Vec<String>) -> Self {
+        self.reference_ids = Some(reference_ids);
+        self
+    }
+
+    pub fn search<T: ToString>(mut self, search: T) -> Self {
+        self.search = Some(search.to_string());
+        self
+    }
+}
+
+/// The functions of [`Products`], for code that takes them as a trait, so that it can be
+/// tested with the `MockProductsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ProductsApi: Send + Sync {
+    /// See [`Products::get_page_with`].
+    async fn get_page_with(
+        &self,
+        params: &ProductsGetPageParams,
+    ) -> Result<Vec<crate::types::Product>>;
+
+    /// See [`Products::get_all_with`].
+    async fn get_all_with(
+        &self,
+        params: &ProductsGetPageParams,
+    ) -> Result<Vec<crate::types::Product>>;
+
+    /// See [`Products::get_stream`].
+    fn get_stream(
+        &self,
+        params: &ProductsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Product>;
+
+    /// See [`Products::post`].
+    async fn post(
+        &self,
+        body: &crate::types::ProductsCreateProductModel,
+    ) -> Result<Vec<crate::types::Product>>;
+
+    /// See [`Products::get`].
+    async fn get(&self, product_id: i64) -> Result<crate::types::Product>;
+
+    /// See [`Products::put`].
+    async fn put(
+        &self,
+        product_id: i64,
+        body: &crate::types::ProductsUpdateProductModel,
+    ) -> Result<Vec<crate::types::Product>>;
+
+    /// See [`Products::post_batch`].
+    async fn post_batch(
+        &self,
+        body: &[crate::types::ProductsCreateProductModel],
+    ) -> Result<Vec<crate::types::Product>>;
+}
+
+#[async_trait::async_trait]
+impl ProductsApi for Products {
+    async fn get_page_with(
+        &self,
+        params: &ProductsGetPageParams,
+    ) -> Result<Vec<crate::types::Product>> {
+        Products::get_page_with(self, params).await
+    }
+
+    async fn get_all_with(
+        &self,
+        params: &ProductsGetPageParams,
+    ) -> Result<Vec<crate::types::Product>> {
+        Products::get_all_with(self, params).await
+    }
+
+    fn get_stream(
+        &self,
+        params: &ProductsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Product> {
+        Products::get_stream(self, params, start, max_items)
+    }
+
+    async fn post(
+        &self,
+        body: &crate::types::ProductsCreateProductModel,
+    ) -> Result<Vec<crate::types::Product>> {
+        Products::post(self, body).await
+    }
+
+    async fn get(&self, product_id: i64) -> Result<crate::types::Product> {
+        Products::get(self, product_id).await
+    }
+
+    async fn put(
+        &self,
+        product_id: i64,
+        body: &crate::types::ProductsUpdateProductModel,
+    ) -> Result<Vec<crate::types::Product>> {
+        Products::put(self, product_id, body).await
+    }
+
+    async fn post_batch(
+        &self,
+        body: &[crate::types::ProductsCreateProductModel],
+    ) -> Result<Vec<crate::types::Product>> {
+        Products::post_batch(self, body).await
+    }
+}
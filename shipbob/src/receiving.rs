@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Receiving {
     pub client: Client,
@@ -38,6 +36,37 @@ impl Receiving {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * Get Fulfillment Centers.
+     *
+     * This function performs a `GET` to the `/fulfillmentCenter` endpoint.
+     *
+     * As opposed to `get_all_fulfillment_center`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     */
+    pub fn get_fulfillment_center_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReceivingFulfillmentCenter> {
+        let url = "/fulfillmentCenter".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * Get Warehouse Receiving Order.
      *
@@ -107,3 +136,82 @@ impl Receiving {
         self.client.post(&url, None).await
     }
 }
+
+/// The functions of [`Receiving`], for code that takes them as a trait, so that it can be
+/// tested with the `MockReceivingApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReceivingApi: Send + Sync {
+    /// See [`Receiving::get_fulfillment_center`].
+    async fn get_fulfillment_center(&self)
+        -> Result<Vec<crate::types::ReceivingFulfillmentCenter>>;
+
+    /// See [`Receiving::get_all_fulfillment_center`].
+    async fn get_all_fulfillment_center(
+        &self,
+    ) -> Result<Vec<crate::types::ReceivingFulfillmentCenter>>;
+
+    /// See [`Receiving::get_fulfillment_center_stream`].
+    fn get_fulfillment_center_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReceivingFulfillmentCenter>;
+
+    /// See [`Receiving::get`].
+    async fn get(&self, id: i64) -> Result<crate::types::ReceivingOrder>;
+
+    /// See [`Receiving::get_label`].
+    async fn get_label(&self, id: i64) -> Result<bytes::Bytes>;
+
+    /// See [`Receiving::post`].
+    async fn post(
+        &self,
+        body: &crate::types::ReceivingCreateOrderModel,
+    ) -> Result<crate::types::ReceivingOrder>;
+
+    /// See [`Receiving::post_cancel`].
+    async fn post_cancel(&self, id: i64) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ReceivingApi for Receiving {
+    async fn get_fulfillment_center(
+        &self,
+    ) -> Result<Vec<crate::types::ReceivingFulfillmentCenter>> {
+        Receiving::get_fulfillment_center(self).await
+    }
+
+    async fn get_all_fulfillment_center(
+        &self,
+    ) -> Result<Vec<crate::types::ReceivingFulfillmentCenter>> {
+        Receiving::get_all_fulfillment_center(self).await
+    }
+
+    fn get_fulfillment_center_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReceivingFulfillmentCenter> {
+        Receiving::get_fulfillment_center_stream(self, start, max_items)
+    }
+
+    async fn get(&self, id: i64) -> Result<crate::types::ReceivingOrder> {
+        Receiving::get(self, id).await
+    }
+
+    async fn get_label(&self, id: i64) -> Result<bytes::Bytes> {
+        Receiving::get_label(self, id).await
+    }
+
+    async fn post(
+        &self,
+        body: &crate::types::ReceivingCreateOrderModel,
+    ) -> Result<crate::types::ReceivingOrder> {
+        Receiving::post(self, body).await
+    }
+
+    async fn post_cancel(&self, id: i64) -> Result<()> {
+        Receiving::post_cancel(self, id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Returns {
     pub client: Client,
@@ -63,25 +61,70 @@ impl Returns {
      *
      * **Parameters:**
      *
-     * * `page: i64` -- Page of return orders to get.
-     * * `limit: i64` -- Amount of return orders per page to request.
-     * * `sort_order: crate::types::SortOrder` -- Order to sort results in.
-     * * `start_date: chrono::DateTime<chrono::Utc>` -- Start date to filter orders inserted later than.
-     * * `end_date: chrono::DateTime<chrono::Utc>` -- End date to filter orders inserted earlier than.
-     * * `i_ds: &[String]` -- Comma separated list of return orders ids to filter by.
-     * * `reference_ids: &[String]` -- Comma separated list of reference ids to filter by.
-     * * `status: &[String]` -- Comma separated list of statuses to filter by.
-     * * `fulfillment_center_ids: &[String]` -- Comma separated list of destination fulfillment center IDs to filter by.
-     * * `tracking_numbers: &[String]` -- Comma separated list of tracking numbers to filter by.
-     * * `original_shipment_ids: &[String]` -- Comma separated list of original shipment IDs to filter by.
-     * * `inventory_ids: &[String]` -- Comma separated list of inventory IDs contained in return to filter by.
-     * * `channel_id: i64` -- Unique id of the channel.
+     * * `params: &ReturnsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_page_with(
+        &self,
+        params: &ReturnsGetPageParams,
+    ) -> Result<Vec<crate::types::ReturnOrder>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("EndDate".to_string(), end_date.to_rfc3339()));
+        }
+        if let Some(fulfillment_center_ids) = &params.fulfillment_center_ids {
+            query_args.push((
+                "FulfillmentCenterIds".to_string(),
+                fulfillment_center_ids.join(" "),
+            ));
+        }
+        if let Some(ids) = &params.ids {
+            query_args.push(("IDs".to_string(), ids.join(" ")));
+        }
+        if let Some(inventory_ids) = &params.inventory_ids {
+            query_args.push(("InventoryIds".to_string(), inventory_ids.join(" ")));
+        }
+        if let Some(limit) = &params.limit {
+            query_args.push(("Limit".to_string(), limit.to_string()));
+        }
+        if let Some(original_shipment_ids) = &params.original_shipment_ids {
+            query_args.push((
+                "OriginalShipmentIds".to_string(),
+                original_shipment_ids.join(" "),
+            ));
+        }
+        if let Some(page) = &params.page {
+            query_args.push(("Page".to_string(), page.to_string()));
+        }
+        if let Some(reference_ids) = &params.reference_ids {
+            query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
+        }
+        if let Some(sort_order) = &params.sort_order {
+            query_args.push(("SortOrder".to_string(), sort_order.to_string()));
+        }
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("StartDate".to_string(), start_date.to_rfc3339()));
+        }
+        if let Some(status) = &params.status {
+            query_args.push(("Status".to_string(), status.join(" ")));
+        }
+        if let Some(tracking_numbers) = &params.tracking_numbers {
+            query_args.push(("TrackingNumbers".to_string(), tracking_numbers.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/return?{}", query_);
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `ReturnsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
     pub async fn get_page(
         &self,
         page: i64,
         limit: i64,
-        sort_order: crate::types::SortOrder,
+        sort_order: crate::types::ReturnsSortOrder,
         start_date: Option<chrono::DateTime<chrono::Utc>>,
         end_date: Option<chrono::DateTime<chrono::Utc>>,
         ids: &[String],
@@ -91,66 +134,89 @@ impl Returns {
         tracking_numbers: &[String],
         original_shipment_ids: &[String],
         inventory_ids: &[String],
+    ) -> Result<Vec<crate::types::ReturnOrder>> {
+        let params = ReturnsGetPageParams {
+            end_date,
+            fulfillment_center_ids: Some(fulfillment_center_ids.to_vec()).filter(|v| !v.is_empty()),
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            inventory_ids: Some(inventory_ids.to_vec()).filter(|v| !v.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            original_shipment_ids: Some(original_shipment_ids.to_vec()).filter(|v| !v.is_empty()),
+            page: Some(page).filter(|v| *v > 0),
+            reference_ids: Some(reference_ids.to_vec()).filter(|v| !v.is_empty()),
+            sort_order: Some(sort_order).filter(|v| !v.to_string().is_empty()),
+            start_date,
+            status: Some(status.to_vec()).filter(|v| !v.is_empty()),
+            tracking_numbers: Some(tracking_numbers.to_vec()).filter(|v| !v.is_empty()),
+        };
+        self.get_page_with(&params).await
+    }
+
+    /**
+     * Get Return Orders.
+     *
+     * This function performs a `GET` to the `/return` endpoint.
+     *
+     * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReturnsGetPageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_with(
+        &self,
+        params: &ReturnsGetPageParams,
     ) -> Result<Vec<crate::types::ReturnOrder>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = end_date {
-            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("EndDate".to_string(), end_date.to_rfc3339()));
         }
-        if !fulfillment_center_ids.is_empty() {
+        if let Some(fulfillment_center_ids) = &params.fulfillment_center_ids {
             query_args.push((
                 "FulfillmentCenterIds".to_string(),
                 fulfillment_center_ids.join(" "),
             ));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("IDs".to_string(), ids.join(" ")));
         }
-        if !inventory_ids.is_empty() {
+        if let Some(inventory_ids) = &params.inventory_ids {
             query_args.push(("InventoryIds".to_string(), inventory_ids.join(" ")));
         }
-        if limit > 0 {
-            query_args.push(("Limit".to_string(), limit.to_string()));
-        }
-        if !original_shipment_ids.is_empty() {
+        if let Some(original_shipment_ids) = &params.original_shipment_ids {
             query_args.push((
                 "OriginalShipmentIds".to_string(),
                 original_shipment_ids.join(" "),
             ));
         }
-        if page > 0 {
-            query_args.push(("Page".to_string(), page.to_string()));
-        }
-        if !reference_ids.is_empty() {
+        if let Some(reference_ids) = &params.reference_ids {
             query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
         }
-        if !sort_order.to_string().is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("SortOrder".to_string(), sort_order.to_string()));
         }
-        if let Some(date) = start_date {
-            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("StartDate".to_string(), start_date.to_rfc3339()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("Status".to_string(), status.join(" ")));
         }
-        if !tracking_numbers.is_empty() {
+        if let Some(tracking_numbers) = &params.tracking_numbers {
             query_args.push(("TrackingNumbers".to_string(), tracking_numbers.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/return?{}", query_);
 
-        self.client.get(&url, None).await
+        self.client.get_all_pages(&url, None).await
     }
 
     /**
-     * Get Return Orders.
-     *
-     * This function performs a `GET` to the `/return` endpoint.
-     *
-     * As opposed to `get`, this function returns all the pages of the request at once.
+     * Deprecated: use `get_all_with` and pass the query parameters as a `ReturnsGetPageParams` instead.
      */
+    #[deprecated(note = "use `get_all_with` instead")]
     pub async fn get_all(
         &self,
-        sort_order: crate::types::SortOrder,
+        sort_order: crate::types::ReturnsSortOrder,
         start_date: Option<chrono::DateTime<chrono::Utc>>,
         end_date: Option<chrono::DateTime<chrono::Utc>>,
         ids: &[String],
@@ -161,47 +227,91 @@ impl Returns {
         original_shipment_ids: &[String],
         inventory_ids: &[String],
     ) -> Result<Vec<crate::types::ReturnOrder>> {
+        let params = ReturnsGetPageParams {
+            end_date,
+            fulfillment_center_ids: Some(fulfillment_center_ids.to_vec()).filter(|v| !v.is_empty()),
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            inventory_ids: Some(inventory_ids.to_vec()).filter(|v| !v.is_empty()),
+            original_shipment_ids: Some(original_shipment_ids.to_vec()).filter(|v| !v.is_empty()),
+            reference_ids: Some(reference_ids.to_vec()).filter(|v| !v.is_empty()),
+            sort_order: Some(sort_order).filter(|v| !v.to_string().is_empty()),
+            start_date,
+            status: Some(status.to_vec()).filter(|v| !v.is_empty()),
+            tracking_numbers: Some(tracking_numbers.to_vec()).filter(|v| !v.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * Get Return Orders.
+     *
+     * This function performs a `GET` to the `/return` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     */
+    pub fn get_stream(
+        &self,
+        params: &ReturnsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReturnOrder> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = end_date {
-            query_args.push(("EndDate".to_string(), date.to_rfc3339()));
+        if let Some(end_date) = &params.end_date {
+            query_args.push(("EndDate".to_string(), end_date.to_rfc3339()));
         }
-        if !fulfillment_center_ids.is_empty() {
+        if let Some(fulfillment_center_ids) = &params.fulfillment_center_ids {
             query_args.push((
                 "FulfillmentCenterIds".to_string(),
                 fulfillment_center_ids.join(" "),
             ));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("IDs".to_string(), ids.join(" ")));
         }
-        if !inventory_ids.is_empty() {
+        if let Some(inventory_ids) = &params.inventory_ids {
             query_args.push(("InventoryIds".to_string(), inventory_ids.join(" ")));
         }
-        if !original_shipment_ids.is_empty() {
+        if let Some(original_shipment_ids) = &params.original_shipment_ids {
             query_args.push((
                 "OriginalShipmentIds".to_string(),
                 original_shipment_ids.join(" "),
             ));
         }
-        if !reference_ids.is_empty() {
+        if let Some(reference_ids) = &params.reference_ids {
             query_args.push(("ReferenceIds".to_string(), reference_ids.join(" ")));
         }
-        if !sort_order.to_string().is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("SortOrder".to_string(), sort_order.to_string()));
         }
-        if let Some(date) = start_date {
-            query_args.push(("StartDate".to_string(), date.to_rfc3339()));
+        if let Some(start_date) = &params.start_date {
+            query_args.push(("StartDate".to_string(), start_date.to_rfc3339()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("Status".to_string(), status.join(" ")));
         }
-        if !tracking_numbers.is_empty() {
+        if let Some(tracking_numbers) = &params.tracking_numbers {
             query_args.push(("TrackingNumbers".to_string(), tracking_numbers.join(" ")));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/return?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -264,3 +374,194 @@ impl Returns {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Returns::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReturnsGetPageParams {
+    /// End date to filter orders inserted earlier than.
+    pub end_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// Comma separated list of destination fulfillment center IDs to filter by.
+    pub fulfillment_center_ids: Option<Vec<String>>,
+    pub ids: Option<Vec<String>>,
+    /// Comma separated list of inventory IDs contained in return to filter by.
+    pub inventory_ids: Option<Vec<String>>,
+    /// Amount of return orders per page to request.
+    pub limit: Option<i64>,
+    /// Comma separated list of original shipment IDs to filter by.
+    pub original_shipment_ids: Option<Vec<String>>,
+    /// Page of return orders to get.
+    pub page: Option<i64>,
+    /// Comma separated list of reference ids to filter by.
+    pub reference_ids: Option<Vec<String>>,
+    /// Order to sort results by.
+    pub sort_order: Option<crate::types::ReturnsSortOrder>,
+    /// Start date to filter orders inserted later than.
+    pub start_date: Option<chrono::DateTime<chrono::Utc>>,
+    /// Comma separated list of statuses to filter by.
+    pub status: Option<Vec<String>>,
+    /// Comma separated list of tracking numbers to filter by.
+    pub tracking_numbers: Option<Vec<String>>,
+}
+
+impl ReturnsGetPageParams {
+    pub fn end_date(mut self, end_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.end_date = Some(end_date);
+        self
+    }
+
+    pub fn fulfillment_center_ids(mut self, fulfillment_center_ids: Vec<String>) -> Self {
+        self.fulfillment_center_ids = Some(fulfillment_center_ids);
+        self
+    }
+
+    pub fn ids(mut self, ids: Vec<String>) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn inventory_ids(mut self, inventory_ids: Vec<String>) -> Self {
+        self.inventory_ids = Some(inventory_ids);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn original_shipment_ids(mut self, original_shipment_ids: Vec<String>) -> Self {
+        self.original_shipment_ids = Some(original_shipment_ids);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn reference_ids(mut self, reference_ids: Vec<String>) -> Self {
+        self.reference_ids = Some(reference_ids);
+        self
+    }
+
+    pub fn sort_order(mut self, sort_order: crate::types::ReturnsSortOrder) -> Self {
+        self.sort_order = Some(sort_order);
+        self
+    }
+
+    pub fn start_date(mut self, start_date: chrono::DateTime<chrono::Utc>) -> Self {
+        self.start_date = Some(start_date);
+        self
+    }
+
+    pub fn status(mut self, status: Vec<String>) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn tracking_numbers(mut self, tracking_numbers: Vec<String>) -> Self {
+        self.tracking_numbers = Some(tracking_numbers);
+        self
+    }
+}
+
+/// The functions of [`Returns`], for code that takes them as a trait, so that it can be
+/// tested with the `MockReturnsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReturnsApi: Send + Sync {
+    /// See [`Returns::get`].
+    async fn get(&self, id: i64) -> Result<crate::types::ReturnOrder>;
+
+    /// See [`Returns::put`].
+    async fn put(
+        &self,
+        id: i64,
+        body: &crate::types::ReturnsCreateReturn,
+    ) -> Result<crate::types::ReturnOrder>;
+
+    /// See [`Returns::get_page_with`].
+    async fn get_page_with(
+        &self,
+        params: &ReturnsGetPageParams,
+    ) -> Result<Vec<crate::types::ReturnOrder>>;
+
+    /// See [`Returns::get_all_with`].
+    async fn get_all_with(
+        &self,
+        params: &ReturnsGetPageParams,
+    ) -> Result<Vec<crate::types::ReturnOrder>>;
+
+    /// See [`Returns::get_stream`].
+    fn get_stream(
+        &self,
+        params: &ReturnsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReturnOrder>;
+
+    /// See [`Returns::post`].
+    async fn post(
+        &self,
+        body: &crate::types::ReturnsCreateReturn,
+    ) -> Result<crate::types::ReturnOrder>;
+
+    /// See [`Returns::post_cancel`].
+    async fn post_cancel(&self, id: i64) -> Result<crate::types::ReturnOrder>;
+
+    /// See [`Returns::get_status_history`].
+    async fn get_status_history(&self, id: i64) -> Result<crate::types::ReturnOrderStatusHistory>;
+}
+
+#[async_trait::async_trait]
+impl ReturnsApi for Returns {
+    async fn get(&self, id: i64) -> Result<crate::types::ReturnOrder> {
+        Returns::get(self, id).await
+    }
+
+    async fn put(
+        &self,
+        id: i64,
+        body: &crate::types::ReturnsCreateReturn,
+    ) -> Result<crate::types::ReturnOrder> {
+        Returns::put(self, id, body).await
+    }
+
+    async fn get_page_with(
+        &self,
+        params: &ReturnsGetPageParams,
+    ) -> Result<Vec<crate::types::ReturnOrder>> {
+        Returns::get_page_with(self, params).await
+    }
+
+    async fn get_all_with(
+        &self,
+        params: &ReturnsGetPageParams,
+    ) -> Result<Vec<crate::types::ReturnOrder>> {
+        Returns::get_all_with(self, params).await
+    }
+
+    fn get_stream(
+        &self,
+        params: &ReturnsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReturnOrder> {
+        Returns::get_stream(self, params, start, max_items)
+    }
+
+    async fn post(
+        &self,
+        body: &crate::types::ReturnsCreateReturn,
+    ) -> Result<crate::types::ReturnOrder> {
+        Returns::post(self, body).await
+    }
+
+    async fn post_cancel(&self, id: i64) -> Result<crate::types::ReturnOrder> {
+        Returns::post_cancel(self, id).await
+    }
+
+    async fn get_status_history(&self, id: i64) -> Result<crate::types::ReturnOrderStatusHistory> {
+        Returns::get_status_history(self, id).await
+    }
+}
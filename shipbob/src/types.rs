@@ -2,46 +2,144 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Channel {
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub application_name: String,
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * Array of permissions granted for the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub scopes: Vec<String>,
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> std::option::Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> std::convert::From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match std::option::Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
 }
 
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        std::option::Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        std::option::Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
+
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+
+#[cfg(feature = "channels")]
+mod channels;
+#[cfg(feature = "channels")]
+pub use channels::*;
+
+#[cfg(feature = "inventory")]
+mod inventory;
+#[cfg(feature = "inventory")]
+pub use inventory::*;
+
+#[cfg(feature = "locations")]
+mod locations;
+#[cfg(feature = "locations")]
+pub use locations::*;
+
+#[cfg(feature = "orders")]
+mod orders;
+#[cfg(feature = "orders")]
+pub use orders::*;
+
+#[cfg(feature = "products")]
+mod products;
+#[cfg(feature = "products")]
+pub use products::*;
+
+#[cfg(feature = "receiving")]
+mod receiving;
+#[cfg(feature = "receiving")]
+pub use receiving::*;
+
+#[cfg(feature = "returns")]
+mod returns;
+#[cfg(feature = "returns")]
+pub use returns::*;
+
+#[cfg(feature = "webhooks")]
+mod webhooks;
+#[cfg(feature = "webhooks")]
+pub use webhooks::*;
 /// Information about an inventory item's dimensions
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct InventoryDimension {
@@ -232,6 +330,7 @@ pub struct InventoryLotQuantity {
  * Attribute influencing the packaging requirements of this inventory item
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PackagingAttribute {
     #[serde(rename = "Book")]
     Book,
@@ -251,15 +350,15 @@ pub enum PackagingAttribute {
     Poster,
     #[serde(rename = "Stackable")]
     Stackable,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PackagingAttribute {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PackagingAttribute {
+    fn as_ref(&self) -> &str {
+        match self {
             PackagingAttribute::Book => "Book",
             PackagingAttribute::CustomDunnage => "CustomDunnage",
             PackagingAttribute::CustomPackaging => "CustomPackaging",
@@ -269,52 +368,59 @@ impl std::fmt::Display for PackagingAttribute {
             PackagingAttribute::None => "None",
             PackagingAttribute::Poster => "Poster",
             PackagingAttribute::Stackable => "Stackable",
-            PackagingAttribute::Noop => "",
-            PackagingAttribute::FallthroughString => "*",
+            PackagingAttribute::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PackagingAttribute {
-    fn default() -> PackagingAttribute {
-        PackagingAttribute::Noop
+impl std::fmt::Display for PackagingAttribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PackagingAttribute {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Book" => PackagingAttribute::Book,
+            "CustomDunnage" => PackagingAttribute::CustomDunnage,
+            "CustomPackaging" => PackagingAttribute::CustomPackaging,
+            "Foldable" => PackagingAttribute::Foldable,
+            "Fragile" => PackagingAttribute::Fragile,
+            "MarketingInsert" => PackagingAttribute::MarketingInsert,
+            "None" => PackagingAttribute::None,
+            "Poster" => PackagingAttribute::Poster,
+            "Stackable" => PackagingAttribute::Stackable,
+            _ => PackagingAttribute::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PackagingAttribute {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PackagingAttribute {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PackagingAttribute::Noop)
+
+impl std::convert::From<PackagingAttribute> for String {
+    fn from(v: PackagingAttribute) -> Self {
+        match v {
+            PackagingAttribute::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
-/// Information about an inventory item
+/// Information about a fulfillment center that a shipment can belong to
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Inventory {
-    /**
-     * Information about an inventory item
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub dimensions: Option<InventoryDimension>,
-    /**
-     * Information about an inventory item
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub fulfillable_quantity_by_fulfillment_center: Vec<InventoryFulfillmentCenterQuantity>,
-    /**
-     * Information about an inventory item
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub fulfillable_quantity_by_lot: Vec<InventoryLotQuantity>,
+pub struct OrdersFulfillmentCenter {
     /**
-     * Information about an inventory item
+     * Information about a fulfillment center that a shipment can belong to
      */
     #[serde(
         default,
@@ -323,37 +429,55 @@ pub struct Inventory {
     )]
     pub id: i64,
     /**
-     * Information about an inventory item
+     * Name of the channel
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub is_active: bool,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersEstimateDetail {
     /**
-     * Information about an inventory item
+     * Weight in ounces of this inventory item
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub is_case_pick: bool,
+    pub estimated_price: f64,
+    /**
+     * Information about a fulfillment center that a shipment can belong to
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillment_center: Option<OrdersFulfillmentCenter>,
     /**
-     * Information about an inventory item
+     * Name of the channel
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub is_digital: bool,
+    pub shipping_method: String,
+}
+
+/// Created by channel metadata
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersChannelInfo {
     /**
-     * Information about an inventory item
+     * Created by channel metadata
      */
     #[serde(
         default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub is_lot: bool,
+    pub id: i64,
     /**
      * Name of the channel
      */
@@ -363,107 +487,214 @@ pub struct Inventory {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
+}
+
+/**
+ * Specifies the type of address:
+ *   ShipFrom
+ *   MarkFor
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Type {
+    #[serde(rename = "MarkFor")]
+    MarkFor,
+    #[serde(rename = "ShipFrom")]
+    ShipFrom,
     /**
-     * Information about an inventory item
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub packaging_attribute: Option<PackagingAttribute>,
+    Unknown(String),
+}
+
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        match self {
+            Type::MarkFor => "MarkFor",
+            Type::ShipFrom => "ShipFrom",
+            Type::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "MarkFor" => Type::MarkFor,
+            "ShipFrom" => Type::ShipFrom,
+            _ => Type::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(v: Type) -> Self {
+        match v {
+            Type::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// Address to used when creating a B2B/DropShip order.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersRetailerProgramDataAddress {
     /**
-     * Information about an inventory item
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "address1"
     )]
-    pub total_awaiting_quantity: i64,
+    pub address_1: Nullable<String>,
     /**
-     * Information about an inventory item
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "address2"
     )]
-    pub total_backordered_quantity: i64,
+    pub address_2: Nullable<String>,
     /**
-     * Information about an inventory item
+     * Name of the channel
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub total_committed_quantity: i64,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub city: Nullable<String>,
     /**
-     * Information about an inventory item
+     * Name of the channel
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub total_exception_quantity: i64,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub company_name: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub country: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub state: Nullable<String>,
+    /**
+     * Specifies the type of address:
+     *  ShipFrom
+     *  MarkFor
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<Type>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub zip_code: Nullable<String>,
+}
+
+/// Information about the recipient of an order
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersRecipientInfo {
+    /**
+     * Address to used when creating a B2B/DropShip order.
+     */
+    #[serde()]
+    pub address: OrdersRetailerProgramDataAddress,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub email: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
     /**
-     * Information about an inventory item
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub phone_number: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersProductInfo {
+    /**
+     * Unique id of the channel
      */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub total_fulfillable_quantity: i64,
+    pub id: i64,
     /**
-     * Information about an inventory item
+     * Unique id of the channel
      */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub total_internal_transfer_quantity: i64,
+    pub quantity: i64,
     /**
-     * Information about an inventory item
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub total_onhand_quantity: i64,
+    pub reference_id: String,
     /**
-     * Information about an inventory item
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub total_sellable_quantity: i64,
+    pub sku: String,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersEstimationAddress {
+pub struct OrdersTag {
     /**
      * Name of the channel
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address1"
-    )]
-    pub address_1: String,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
     /**
      * Name of the channel
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address2"
-    )]
-    pub address_2: String,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub value: Nullable<String>,
+}
+
+/// Information about the recipient of a shipment
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersRecipient {
+    /**
+     * Information about the recipient of a shipment
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<OrdersRetailerProgramDataAddress>,
     /**
      * Name of the channel
      */
@@ -472,7 +703,7 @@ pub struct OrdersEstimationAddress {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub city: String,
+    pub email: String,
     /**
      * Name of the channel
      */
@@ -481,7 +712,7 @@ pub struct OrdersEstimationAddress {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub company_name: String,
+    pub name: String,
     /**
      * Name of the channel
      */
@@ -490,7 +721,11 @@ pub struct OrdersEstimationAddress {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub country: String,
+    pub phone_number: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersStatusDetail {
     /**
      * Name of the channel
      */
@@ -499,20 +734,16 @@ pub struct OrdersEstimationAddress {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub state: String,
+    pub description: String,
     /**
-     * Name of the channel
+     * Unique id of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub zip_code: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersEstimateProductInfoModel {
+    pub exception_fulfillment_center_id: i64,
     /**
      * Unique id of the channel
      */
@@ -530,7 +761,7 @@ pub struct OrdersEstimateProductInfoModel {
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub quantity: i64,
+    pub inventory_id: i64,
     /**
      * Name of the channel
      */
@@ -539,45 +770,21 @@ pub struct OrdersEstimateProductInfoModel {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub reference_id: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersEstimateFulfillmentRequestModel {
-    #[serde()]
-    pub address: OrdersEstimationAddress,
-    /**
-     * Products to be included in the order. Each product must include one of reference_id or id
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub products: Vec<OrdersEstimateProductInfoModel>,
-    /**
-     * Array of permissions granted for the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub shipping_methods: Vec<String>,
+    pub name: String,
 }
 
-/// Information about a fulfillment center that a shipment can belong to
+/// Tracking information for a shipment
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersFulfillmentCenter {
+pub struct OrdersTracking {
     /**
-     * Information about a fulfillment center that a shipment can belong to
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub id: i64,
+    pub carrier: String,
     /**
      * Name of the channel
      */
@@ -586,25 +793,16 @@ pub struct OrdersFulfillmentCenter {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersEstimateDetail {
+    pub carrier_service: String,
     /**
-     * Weight in ounces of this inventory item
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub estimated_price: f64,
-    /**
-     * Information about a fulfillment center that a shipment can belong to
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fulfillment_center: Option<OrdersFulfillmentCenter>,
+    pub tracking_number: String,
     /**
      * Name of the channel
      */
@@ -613,27 +811,23 @@ pub struct OrdersEstimateDetail {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub shipping_method: String,
+    pub tracking_url: String,
 }
 
+/// Information about inventory belonging to a store product
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersEstimate {
+pub struct OrdersInventory {
     /**
-     * Array of estimates for each shipping method
+     * Expiration date for this lot
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub estimates: Vec<OrdersEstimateDetail>,
-}
-
-/// Created by channel metadata
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersChannelInfo {
+    pub expiration_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Created by channel metadata
+     * Information about inventory belonging to a store product
      */
     #[serde(
         default,
@@ -642,141 +836,81 @@ pub struct OrdersChannelInfo {
     )]
     pub id: i64,
     /**
-     * Name of the channel
+     * Information about inventory belonging to a store product
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub name: String,
-}
-
-/**
- * Specifies the type of address:
- *   ShipFrom
- *   MarkFor
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Type {
-    #[serde(rename = "MarkFor")]
-    MarkFor,
-    #[serde(rename = "ShipFrom")]
-    ShipFrom,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Type {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Type::MarkFor => "MarkFor",
-            Type::ShipFrom => "ShipFrom",
-            Type::Noop => "",
-            Type::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Type {
-    fn default() -> Type {
-        Type::Noop
-    }
-}
-impl Type {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Type::Noop)
-    }
-}
-
-/// Address to used when creating a B2B/DropShip order.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersRetailerProgramDataAddress {
+    pub is_dangerous_goods: bool,
     /**
      * Name of the channel
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address1"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub address_1: String,
+    pub lot: String,
     /**
      * Name of the channel
      */
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address2"
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub address_2: String,
+    pub name: String,
     /**
-     * Name of the channel
+     * Information about inventory belonging to a store product
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub city: String,
+    pub quantity: i64,
     /**
-     * Name of the channel
+     * Information about inventory belonging to a store product
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub company_name: String,
+    pub quantity_committed: i64,
     /**
-     * Name of the channel
+     * Array of permissions granted for the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub country: String,
+    pub serial_numbers: Vec<String>,
+}
+
+/// Information about a store product belonging to a shipment
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersShipmentProduct {
     /**
-     * Name of the channel
+     * Information about a store product belonging to a shipment
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub state: String,
-    /**
-     * Specifies the type of address:
-     *  ShipFrom
-     *  MarkFor
-     */
-    #[serde(default, skip_serializing_if = "Type::is_noop", rename = "type")]
-    pub type_: Type,
+    pub id: i64,
     /**
-     * Name of the channel
+     * Information about a store product belonging to a shipment
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub zip_code: String,
-}
-
-/// Information about the recipient of an order
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersRecipientInfo {
-    /**
-     * Address to used when creating a B2B/DropShip order.
-     */
-    #[serde()]
-    pub address: OrdersRetailerProgramDataAddress,
+    pub inventory_items: Vec<OrdersInventory>,
     /**
      * Name of the channel
      */
@@ -785,7 +919,7 @@ pub struct OrdersRecipientInfo {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub email: String,
+    pub name: String,
     /**
      * Name of the channel
      */
@@ -794,7 +928,7 @@ pub struct OrdersRecipientInfo {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub reference_id: String,
     /**
      * Name of the channel
      */
@@ -803,1833 +937,359 @@ pub struct OrdersRecipientInfo {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub phone_number: String,
+    pub sku: String,
 }
 
+/// Measurements of a shipment
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersProductInfo {
+pub struct OrdersMeasurements {
     /**
-     * Unique id of the channel
+     * Measurements of a shipment
      */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub id: i64,
+    pub depth_in: i64,
     /**
-     * Unique id of the channel
+     * Measurements of a shipment
      */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub quantity: i64,
+    pub length_in: i64,
     /**
-     * Name of the channel
+     * Measurements of a shipment
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub reference_id: String,
+    pub total_weight_oz: i64,
     /**
-     * Name of the channel
+     * Measurements of a shipment
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub sku: String,
+    pub width_in: i64,
 }
 
+/**
+ * The shipment status
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersTag {
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub value: String,
-}
-
-/// Information about the recipient of a shipment
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersRecipient {
-    /**
-     * Information about the recipient of a shipment
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub address: Option<OrdersRetailerProgramDataAddress>,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone_number: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersStatusDetail {
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub description: String,
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub exception_fulfillment_center_id: i64,
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub inventory_id: i64,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-/// Tracking information for a shipment
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersTracking {
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub carrier: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub carrier_service: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub tracking_number: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub tracking_url: String,
-}
-
-/// Information about inventory belonging to a store product
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersInventory {
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub expiration_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Information about inventory belonging to a store product
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Information about inventory belonging to a store product
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_dangerous_goods: bool,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub lot: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * Information about inventory belonging to a store product
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub quantity: i64,
-    /**
-     * Information about inventory belonging to a store product
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub quantity_committed: i64,
-    /**
-     * Array of permissions granted for the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub serial_numbers: Vec<String>,
-}
-
-/// Information about a store product belonging to a shipment
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersShipmentProduct {
-    /**
-     * Information about a store product belonging to a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Information about a store product belonging to a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub inventory_items: Vec<OrdersInventory>,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reference_id: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub sku: String,
-}
-
-/// Measurements of a shipment
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersMeasurements {
-    /**
-     * Measurements of a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub depth_in: i64,
-    /**
-     * Measurements of a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub length_in: i64,
-    /**
-     * Measurements of a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub total_weight_oz: i64,
-    /**
-     * Measurements of a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub width_in: i64,
-}
-
-/**
- * The shipment status
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Status {
-    #[serde(rename = "Cancelled")]
-    Cancelled,
-    #[serde(rename = "CleanSweeped")]
-    CleanSweeped,
-    #[serde(rename = "Completed")]
-    Completed,
-    #[serde(rename = "Exception")]
-    Exception,
-    #[serde(rename = "ImportReview")]
-    ImportReview,
-    #[serde(rename = "LabeledCreated")]
-    LabeledCreated,
-    #[serde(rename = "None")]
-    None,
-    #[serde(rename = "OnHold")]
-    OnHold,
-    #[serde(rename = "Pending")]
-    Pending,
-    #[serde(rename = "Processing")]
-    Processing,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Status {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Status::Cancelled => "Cancelled",
-            Status::CleanSweeped => "CleanSweeped",
-            Status::Completed => "Completed",
-            Status::Exception => "Exception",
-            Status::ImportReview => "ImportReview",
-            Status::LabeledCreated => "LabeledCreated",
-            Status::None => "None",
-            Status::OnHold => "OnHold",
-            Status::Pending => "Pending",
-            Status::Processing => "Processing",
-            Status::Noop => "",
-            Status::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Status {
-    fn default() -> Status {
-        Status::Noop
-    }
-}
-impl Status {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Status::Noop)
-    }
-}
-
-/**
- * Container type for the shipment
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PackageMaterialType {
-    #[serde(rename = "Bookfold")]
-    Bookfold,
-    #[serde(rename = "Box")]
-    Box,
-    #[serde(rename = "BubbleMailer")]
-    BubbleMailer,
-    #[serde(rename = "Custom")]
-    Custom,
-    #[serde(rename = "FragileBox")]
-    FragileBox,
-    #[serde(rename = "OwnContainer")]
-    OwnContainer,
-    #[serde(rename = "PolyMailer")]
-    PolyMailer,
-    #[serde(rename = "PosterTube")]
-    PosterTube,
-    #[serde(rename = "Undefined")]
-    Undefined,
-    #[serde(rename = "Unknown")]
-    Unknown,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for PackageMaterialType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PackageMaterialType::Bookfold => "Bookfold",
-            PackageMaterialType::Box => "Box",
-            PackageMaterialType::BubbleMailer => "BubbleMailer",
-            PackageMaterialType::Custom => "Custom",
-            PackageMaterialType::FragileBox => "FragileBox",
-            PackageMaterialType::OwnContainer => "OwnContainer",
-            PackageMaterialType::PolyMailer => "PolyMailer",
-            PackageMaterialType::PosterTube => "PosterTube",
-            PackageMaterialType::Undefined => "Undefined",
-            PackageMaterialType::Unknown => "Unknown",
-            PackageMaterialType::Noop => "",
-            PackageMaterialType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for PackageMaterialType {
-    fn default() -> PackageMaterialType {
-        PackageMaterialType::Noop
-    }
-}
-impl PackageMaterialType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PackageMaterialType::Noop)
-    }
-}
-
-/**
- * Status of ShipBob’s completion of the fulfillment operation.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum EstimatedFulfillmentDateStatus {
-    #[serde(rename = "AwaitingInventoryAllocation")]
-    AwaitingInventoryAllocation,
-    #[serde(rename = "AwaitingReset")]
-    AwaitingReset,
-    #[serde(rename = "FulfilledLate")]
-    FulfilledLate,
-    #[serde(rename = "FulfilledOnTime")]
-    FulfilledOnTime,
-    #[serde(rename = "PendingLate")]
-    PendingLate,
-    #[serde(rename = "PendingOnTime")]
-    PendingOnTime,
-    #[serde(rename = "Unavailable")]
-    Unavailable,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for EstimatedFulfillmentDateStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            EstimatedFulfillmentDateStatus::AwaitingInventoryAllocation => {
-                "AwaitingInventoryAllocation"
-            }
-            EstimatedFulfillmentDateStatus::AwaitingReset => "AwaitingReset",
-            EstimatedFulfillmentDateStatus::FulfilledLate => "FulfilledLate",
-            EstimatedFulfillmentDateStatus::FulfilledOnTime => "FulfilledOnTime",
-            EstimatedFulfillmentDateStatus::PendingLate => "PendingLate",
-            EstimatedFulfillmentDateStatus::PendingOnTime => "PendingOnTime",
-            EstimatedFulfillmentDateStatus::Unavailable => "Unavailable",
-            EstimatedFulfillmentDateStatus::Noop => "",
-            EstimatedFulfillmentDateStatus::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for EstimatedFulfillmentDateStatus {
-    fn default() -> EstimatedFulfillmentDateStatus {
-        EstimatedFulfillmentDateStatus::Noop
-    }
-}
-impl EstimatedFulfillmentDateStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EstimatedFulfillmentDateStatus::Noop)
-    }
-}
-
-/// Information about a shipment
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersShipment {
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub actual_fulfillment_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub estimated_fulfillment_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Information about a shipment
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub estimated_fulfillment_date_status: Option<EstimatedFulfillmentDateStatus>,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub gift_message: String,
-    /**
-     * Information about a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Information about a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub insurance_value: f64,
-    /**
-     * Information about a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub invoice_amount: f64,
-    /**
-     * Information about a shipment
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_tracking_uploaded: bool,
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub last_update_at: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Information about a shipment
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub location: Option<OrdersFulfillmentCenter>,
-    /**
-     * Information about a shipment
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub measurements: Option<OrdersMeasurements>,
-    /**
-     * Information about a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub order_id: i64,
-    /**
-     * Information about a shipment
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub package_material_type: Option<PackageMaterialType>,
-    /**
-     * Information about a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub products: Vec<OrdersShipmentProduct>,
-    /**
-     * Information about a shipment
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub recipient: Option<OrdersRecipient>,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reference_id: String,
-    /**
-     * Information about a shipment
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub require_signature: bool,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub ship_option: String,
-    /**
-     * Information about a shipment
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<Status>,
-    /**
-     * Information about a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub status_details: Vec<OrdersStatusDetail>,
-    /**
-     * Information about a shipment
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub tracking: Option<OrdersTracking>,
-}
-
-/**
- * Identifies whether to ship parcel or freight.
- *   
- *   Parcel: Smaller, light weight boxes.
- *   
- *   Freight: Larger boxes, usually transported by truckload.
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum CarrierType {
-    #[serde(rename = "Freight")]
-    Freight,
-    #[serde(rename = "Parcel")]
-    Parcel,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for CarrierType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            CarrierType::Freight => "Freight",
-            CarrierType::Parcel => "Parcel",
-            CarrierType::Noop => "",
-            CarrierType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for CarrierType {
-    fn default() -> CarrierType {
-        CarrierType::Noop
-    }
-}
-impl CarrierType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, CarrierType::Noop)
-    }
-}
-
-/**
- * Identifies the party responsible for shipping charges.
- *   
- *   Collect: The person/entity receiving the product pays the shipping charges [freight only].
- *   
- *   ThirdParty: Another party pays for the shipping charges (not Shipbob) [parcel only].
- *   
- *   Prepaid: The shipper pays the shipping charges (Shipbob or merchant).
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum PaymentTerm {
-    #[serde(rename = "Collect")]
-    Collect,
-    #[serde(rename = "Prepaid")]
-    Prepaid,
-    #[serde(rename = "ThirdParty")]
-    ThirdParty,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for PaymentTerm {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            PaymentTerm::Collect => "Collect",
-            PaymentTerm::Prepaid => "Prepaid",
-            PaymentTerm::ThirdParty => "ThirdParty",
-            PaymentTerm::Noop => "",
-            PaymentTerm::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for PaymentTerm {
-    fn default() -> PaymentTerm {
-        PaymentTerm::Noop
-    }
-}
-impl PaymentTerm {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PaymentTerm::Noop)
-    }
-}
-
-/// Contains shipping properties that need to be used for fulfilling an order.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersShippingTerms {
-    /**
-     * Identifies whether to ship parcel or freight.
-     *  
-     *  Parcel: Smaller, light weight boxes.
-     *  
-     *  Freight: Larger boxes, usually transported by truckload.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub carrier_type: Option<CarrierType>,
-    /**
-     * Identifies the party responsible for shipping charges.
-     *  
-     *  Collect: The person/entity receiving the product pays the shipping charges [freight only].
-     *  
-     *  ThirdParty: Another party pays for the shipping charges (not Shipbob) [parcel only].
-     *  
-     *  Prepaid: The shipper pays the shipping charges (Shipbob or merchant).
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub payment_term: Option<PaymentTerm>,
-}
-
-/// Contains properties that needs to be used for fulfilling B2B/Dropship orders.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersRetailerProgramData {
-    /**
-     * Contains properties that needs to be used for fulfilling B2B/Dropship orders.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub addresses: Vec<OrdersRetailerProgramDataAddress>,
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub delivery_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub mark_for_store: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub purchase_order_number: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub retailer_program_type: String,
-}
-
-/**
- * The order status
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OrderStatus {
-    #[serde(rename = "Cancelled")]
-    Cancelled,
-    #[serde(rename = "Exception")]
-    Exception,
-    #[serde(rename = "Fulfilled")]
-    Fulfilled,
-    #[serde(rename = "ImportReview")]
-    ImportReview,
-    #[serde(rename = "PartiallyFulfilled")]
-    PartiallyFulfilled,
-    #[serde(rename = "Processing")]
-    Processing,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for OrderStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OrderStatus::Cancelled => "Cancelled",
-            OrderStatus::Exception => "Exception",
-            OrderStatus::Fulfilled => "Fulfilled",
-            OrderStatus::ImportReview => "ImportReview",
-            OrderStatus::PartiallyFulfilled => "PartiallyFulfilled",
-            OrderStatus::Processing => "Processing",
-            OrderStatus::Noop => "",
-            OrderStatus::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for OrderStatus {
-    fn default() -> OrderStatus {
-        OrderStatus::Noop
-    }
-}
-impl OrderStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OrderStatus::Noop)
-    }
-}
-
-/**
- * Shipment type of the order
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OrderType {
-    #[serde(rename = "B2B")]
-    B2B,
-    #[serde(rename = "DTC")]
-    Dtc,
-    #[serde(rename = "DropShip")]
-    DropShip,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for OrderType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OrderType::B2B => "B2B",
-            OrderType::Dtc => "DTC",
-            OrderType::DropShip => "DropShip",
-            OrderType::Noop => "",
-            OrderType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for OrderType {
-    fn default() -> OrderType {
-        OrderType::Noop
-    }
-}
-impl OrderType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OrderType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Order {
-    /**
-     * Created by channel metadata
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub channel: Option<OrdersChannelInfo>,
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub gift_message: String,
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub order_number: String,
-    /**
-     * List of products included in the order
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub products: Vec<OrdersProductInfo>,
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub purchase_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Information about the recipient of an order
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub recipient: Option<OrdersRecipientInfo>,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reference_id: String,
-    /**
-     * Contains properties that needs to be used for fulfilling B2B/Dropship orders.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub retailer_program_data: Option<OrdersRetailerProgramData>,
-    /**
-     * Shipments affiliated with the order
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub shipments: Vec<OrdersShipment>,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub shipping_method: String,
-    /**
-     * Contains shipping properties that need to be used for fulfilling an order.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub shipping_terms: Option<OrdersShippingTerms>,
-    /**
-     * The order status
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<OrderStatus>,
-    /**
-     * Client-defined order tags
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub tags: Vec<OrdersTag>,
-    /**
-     * Shipment type of the order
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<OrderType>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersAddProductOrderByModel {
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub quantity: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersAddProductOrderByReferenceModel {
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub quantity: i64,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reference_id: String,
-}
-
-/// All of the following types:
-///
-/// - `OrdersAddProductOrderByModel`
-/// - `OrdersAddProductOrderByReferenceModel`
-///
-/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-#[serde(untagged)]
-pub enum OrdersAddProductOrderModelOneOf {
-    OrdersAddProductOrderByModel(OrdersAddProductOrderByModel),
-    OrdersAddProductOrderByReferenceModel(OrdersAddProductOrderByReferenceModel),
-}
-
-impl OrdersAddProductOrderModelOneOf {
-    pub fn orders_add_product_order_by_model(&self) -> Option<&OrdersAddProductOrderByModel> {
-        if let OrdersAddProductOrderModelOneOf::OrdersAddProductOrderByModel(ref_) = self {
-            return Some(ref_);
-        }
-        None
-    }
-
-    pub fn orders_add_product_order_by_reference_model(
-        &self,
-    ) -> Option<&OrdersAddProductOrderByReferenceModel> {
-        if let OrdersAddProductOrderModelOneOf::OrdersAddProductOrderByReferenceModel(ref_) = self {
-            return Some(ref_);
-        }
-        None
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersCreateOrderModel {
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub gift_message: String,
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub location_id: i64,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub order_number: String,
-    /**
-     * Products included in the order. Products identified by reference_id must also include the product name if there is no matching ShipBob product.
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub products: Vec<OrdersAddProductOrderModelOneOf>,
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub purchase_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Information about the recipient of an order
-     */
-    #[serde()]
-    pub recipient: OrdersRecipientInfo,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reference_id: String,
-    /**
-     * Contains properties that needs to be used for fulfilling B2B/Dropship orders.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub retailer_program_data: Option<OrdersRetailerProgramData>,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub shipping_method: String,
-    /**
-     * Contains shipping properties that need to be used for fulfilling an order.
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub shipping_terms: Option<OrdersShippingTerms>,
-    /**
-     * Client-defined order tags
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub tags: Vec<OrdersTag>,
-    /**
-     * Shipment type of the order
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
-    pub type_: Option<OrderType>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum Action {
-    #[serde(rename = "Cancel")]
-    Cancel,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for Action {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            Action::Cancel => "Cancel",
-            Action::Noop => "",
-            Action::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for Action {
-    fn default() -> Action {
-        Action::Noop
-    }
-}
-impl Action {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Action::Noop)
-    }
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersCanceledShipment {
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub action: Option<Action>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_success: bool,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reason: String,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub shipment_id: i64,
-}
-
-/**
- * The overall result of canceling the shipments associated with the order
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum OrdersCanceledOrderStatus {
-    #[serde(rename = "Failure")]
-    Failure,
-    #[serde(rename = "PartialSuccess")]
-    PartialSuccess,
-    #[serde(rename = "Success")]
-    Success,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for OrdersCanceledOrderStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            OrdersCanceledOrderStatus::Failure => "Failure",
-            OrdersCanceledOrderStatus::PartialSuccess => "PartialSuccess",
-            OrdersCanceledOrderStatus::Success => "Success",
-            OrdersCanceledOrderStatus::Noop => "",
-            OrdersCanceledOrderStatus::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for OrdersCanceledOrderStatus {
-    fn default() -> OrdersCanceledOrderStatus {
-        OrdersCanceledOrderStatus::Noop
-    }
-}
-impl OrdersCanceledOrderStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OrdersCanceledOrderStatus::Noop)
-    }
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersCanceledOrder {
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub canceled_shipment_results: Vec<OrdersCanceledShipment>,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub order: Option<Order>,
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub order_id: i64,
-    /**
-     *
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<OrdersCanceledOrderStatus>,
-}
-
-/// Model for adding a Store Order Json to a ShipBob Order.
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersAddStoreOrderJsonModel {
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub order_json: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersShipmentLog {
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub log_type_id: i64,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub log_type_name: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub log_type_text: String,
-    /**
-     * Specifics data for the event
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub metadata: String,
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
-}
-
-/// Model for cancel multiple shipments at once
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersCancelShipmentsModel {
-    /**
-     * Model for cancel multiple shipments at once
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub shipment_ids: Vec<i64>,
-}
-
-///
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersCanceledShipments {
-    /**
-     *
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub results: Vec<OrdersCanceledShipment>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersServiceLevelDetail {
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct OrdersShipMethodDetail {
-    /**
-     * True if the inventory item is marked as a digital item
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub active: bool,
-    /**
-     * True if the inventory item is marked as a digital item
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub default: bool,
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
+#[serde(from = "String", into = "String")]
+pub enum Status {
+    #[serde(rename = "Cancelled")]
+    Cancelled,
+    #[serde(rename = "CleanSweeped")]
+    CleanSweeped,
+    #[serde(rename = "Completed")]
+    Completed,
+    #[serde(rename = "Exception")]
+    Exception,
+    #[serde(rename = "ImportReview")]
+    ImportReview,
+    #[serde(rename = "LabeledCreated")]
+    LabeledCreated,
+    #[serde(rename = "None")]
+    None,
+    #[serde(rename = "OnHold")]
+    OnHold,
+    #[serde(rename = "Pending")]
+    Pending,
+    #[serde(rename = "Processing")]
+    Processing,
     /**
-     * Name of the channel
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub service_level: Option<OrdersServiceLevelDetail>,
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ProductActiveStatus {
-    #[serde(rename = "Active")]
-    Active,
-    #[serde(rename = "Any")]
-    Any,
-    #[serde(rename = "Inactive")]
-    Inactive,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for ProductActiveStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ProductActiveStatus::Active => "Active",
-            ProductActiveStatus::Any => "Any",
-            ProductActiveStatus::Inactive => "Inactive",
-            ProductActiveStatus::Noop => "",
-            ProductActiveStatus::FallthroughString => "*",
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        match self {
+            Status::Cancelled => "Cancelled",
+            Status::CleanSweeped => "CleanSweeped",
+            Status::Completed => "Completed",
+            Status::Exception => "Exception",
+            Status::ImportReview => "ImportReview",
+            Status::LabeledCreated => "LabeledCreated",
+            Status::None => "None",
+            Status::OnHold => "OnHold",
+            Status::Pending => "Pending",
+            Status::Processing => "Processing",
+            Status::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ProductActiveStatus {
-    fn default() -> ProductActiveStatus {
-        ProductActiveStatus::Noop
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl ProductActiveStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ProductActiveStatus::Noop)
+
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Cancelled" => Status::Cancelled,
+            "CleanSweeped" => Status::CleanSweeped,
+            "Completed" => Status::Completed,
+            "Exception" => Status::Exception,
+            "ImportReview" => Status::ImportReview,
+            "LabeledCreated" => Status::LabeledCreated,
+            "None" => Status::None,
+            "OnHold" => Status::OnHold,
+            "Pending" => Status::Pending,
+            "Processing" => Status::Processing,
+            _ => Status::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ProductBundleStatus {
-    #[serde(rename = "Any")]
-    Any,
-    #[serde(rename = "Bundle")]
-    Bundle,
-    #[serde(rename = "NotBundle")]
-    NotBundle,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for ProductBundleStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ProductBundleStatus::Any => "Any",
-            ProductBundleStatus::Bundle => "Bundle",
-            ProductBundleStatus::NotBundle => "NotBundle",
-            ProductBundleStatus::Noop => "",
-            ProductBundleStatus::FallthroughString => "*",
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
         }
-        .fmt(f)
     }
 }
 
-impl Default for ProductBundleStatus {
-    fn default() -> ProductBundleStatus {
-        ProductBundleStatus::Noop
-    }
-}
-impl ProductBundleStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ProductBundleStatus::Noop)
+impl std::convert::From<Status> for String {
+    fn from(v: Status) -> Self {
+        match v {
+            Status::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
-/// Information about a store channel
+/**
+ * Container type for the shipment
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProductsChannel {
-    /**
-     * Information about a store channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
+#[serde(from = "String", into = "String")]
+pub enum PackageMaterialType {
+    #[serde(rename = "Bookfold")]
+    Bookfold,
+    #[serde(rename = "Box")]
+    Box,
+    #[serde(rename = "BubbleMailer")]
+    BubbleMailer,
+    #[serde(rename = "Custom")]
+    Custom,
+    #[serde(rename = "FragileBox")]
+    FragileBox,
+    #[serde(rename = "OwnContainer")]
+    OwnContainer,
+    #[serde(rename = "PolyMailer")]
+    PolyMailer,
+    #[serde(rename = "PosterTube")]
+    PosterTube,
+    #[serde(rename = "Undefined")]
+    Undefined,
+    #[serde(rename = "Unknown")]
+    UnknownValue,
     /**
-     * Name of the channel
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    Unknown(String),
 }
 
-/// The inventory that store products can resolve to when packing a shipment
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProductsInventoryItem {
-    /**
-     * The inventory that store products can resolve to when packing a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * The inventory that store products can resolve to when packing a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub quantity: i64,
+impl AsRef<str> for PackageMaterialType {
+    fn as_ref(&self) -> &str {
+        match self {
+            PackageMaterialType::Bookfold => "Bookfold",
+            PackageMaterialType::Box => "Box",
+            PackageMaterialType::BubbleMailer => "BubbleMailer",
+            PackageMaterialType::Custom => "Custom",
+            PackageMaterialType::FragileBox => "FragileBox",
+            PackageMaterialType::OwnContainer => "OwnContainer",
+            PackageMaterialType::PolyMailer => "PolyMailer",
+            PackageMaterialType::PosterTube => "PosterTube",
+            PackageMaterialType::Undefined => "Undefined",
+            PackageMaterialType::UnknownValue => "Unknown",
+            PackageMaterialType::Unknown(s) => s,
+        }
+    }
 }
 
-/// Break down of quantities by fulfillment center
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProductsFulfillmentCenterQuantity {
-    /**
-     * Break down of quantities by fulfillment center
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub committed_quantity: i64,
-    /**
-     * Break down of quantities by fulfillment center
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub fulfillable_quantity: i64,
-    /**
-     * Break down of quantities by fulfillment center
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
-    /**
-     * Break down of quantities by fulfillment center
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub onhand_quantity: i64,
+impl std::fmt::Display for PackageMaterialType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PackageMaterialType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Bookfold" => PackageMaterialType::Bookfold,
+            "Box" => PackageMaterialType::Box,
+            "BubbleMailer" => PackageMaterialType::BubbleMailer,
+            "Custom" => PackageMaterialType::Custom,
+            "FragileBox" => PackageMaterialType::FragileBox,
+            "OwnContainer" => PackageMaterialType::OwnContainer,
+            "PolyMailer" => PackageMaterialType::PolyMailer,
+            "PosterTube" => PackageMaterialType::PosterTube,
+            "Undefined" => PackageMaterialType::Undefined,
+            "Unknown" => PackageMaterialType::UnknownValue,
+            _ => PackageMaterialType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PackageMaterialType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PackageMaterialType> for String {
+    fn from(v: PackageMaterialType) -> Self {
+        match v {
+            PackageMaterialType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
+/**
+ * Status of ShipBob’s completion of the fulfillment operation.
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Product {
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub barcode: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub bundle_root_information: Option<OrdersServiceLevelDetail>,
-    /**
-     * Information about a store channel
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub channel: Option<ProductsChannel>,
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * The inventory that this product will resolve to when packing a shipment
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub fulfillable_inventory_items: Vec<ProductsInventoryItem>,
+#[serde(from = "String", into = "String")]
+pub enum EstimatedFulfillmentDateStatus {
+    #[serde(rename = "AwaitingInventoryAllocation")]
+    AwaitingInventoryAllocation,
+    #[serde(rename = "AwaitingReset")]
+    AwaitingReset,
+    #[serde(rename = "FulfilledLate")]
+    FulfilledLate,
+    #[serde(rename = "FulfilledOnTime")]
+    FulfilledOnTime,
+    #[serde(rename = "PendingLate")]
+    PendingLate,
+    #[serde(rename = "PendingOnTime")]
+    PendingOnTime,
+    #[serde(rename = "Unavailable")]
+    Unavailable,
     /**
-     * Fulfillable quantity of this product broken down by fulfillment center location
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub fulfillable_quantity_by_fulfillment_center: Vec<ProductsFulfillmentCenterQuantity>,
+    Unknown(String),
+}
+
+impl AsRef<str> for EstimatedFulfillmentDateStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            EstimatedFulfillmentDateStatus::AwaitingInventoryAllocation => {
+                "AwaitingInventoryAllocation"
+            }
+            EstimatedFulfillmentDateStatus::AwaitingReset => "AwaitingReset",
+            EstimatedFulfillmentDateStatus::FulfilledLate => "FulfilledLate",
+            EstimatedFulfillmentDateStatus::FulfilledOnTime => "FulfilledOnTime",
+            EstimatedFulfillmentDateStatus::PendingLate => "PendingLate",
+            EstimatedFulfillmentDateStatus::PendingOnTime => "PendingOnTime",
+            EstimatedFulfillmentDateStatus::Unavailable => "Unavailable",
+            EstimatedFulfillmentDateStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for EstimatedFulfillmentDateStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for EstimatedFulfillmentDateStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AwaitingInventoryAllocation" => {
+                EstimatedFulfillmentDateStatus::AwaitingInventoryAllocation
+            }
+            "AwaitingReset" => EstimatedFulfillmentDateStatus::AwaitingReset,
+            "FulfilledLate" => EstimatedFulfillmentDateStatus::FulfilledLate,
+            "FulfilledOnTime" => EstimatedFulfillmentDateStatus::FulfilledOnTime,
+            "PendingLate" => EstimatedFulfillmentDateStatus::PendingLate,
+            "PendingOnTime" => EstimatedFulfillmentDateStatus::PendingOnTime,
+            "Unavailable" => EstimatedFulfillmentDateStatus::Unavailable,
+            _ => EstimatedFulfillmentDateStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for EstimatedFulfillmentDateStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<EstimatedFulfillmentDateStatus> for String {
+    fn from(v: EstimatedFulfillmentDateStatus) -> Self {
+        match v {
+            EstimatedFulfillmentDateStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// Information about a shipment
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersShipment {
     /**
-     * Name of the channel
+     * Expiration date for this lot
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub gtin: String,
+    pub actual_fulfillment_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Unique id of the channel
+     * Expiration date for this lot
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub id: i64,
+    pub created_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Name of the channel
+     * Expiration date for this lot
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub name: String,
+    pub estimated_fulfillment_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Name of the channel
+     * Information about a shipment
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reference_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimated_fulfillment_date_status: Option<EstimatedFulfillmentDateStatus>,
     /**
      * Name of the channel
      */
@@ -2638,125 +1298,89 @@ pub struct Product {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub sku: String,
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub total_committed_quantity: i64,
+    pub gift_message: String,
     /**
-     * Unique id of the channel
+     * Information about a shipment
      */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub total_fulfillable_quantity: i64,
+    pub id: i64,
     /**
-     * Unique id of the channel
+     * Information about a shipment
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub total_onhand_quantity: i64,
+    pub insurance_value: f64,
     /**
-     * Weight in ounces of this inventory item
+     * Information about a shipment
      */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_f64",
         deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
     )]
-    pub unit_price: f64,
+    pub invoice_amount: f64,
     /**
-     * Name of the channel
+     * Information about a shipment
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub upc: String,
-}
-
-/// The product to create
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProductsCreateProductModel {
+    pub is_tracking_uploaded: bool,
     /**
-     * Name of the channel
+     * Expiration date for this lot
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub barcode: String,
+    pub last_update_at: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Name of the channel
+     * Information about a shipment
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub gtin: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<OrdersFulfillmentCenter>,
     /**
-     * Name of the channel
+     * Information about a shipment
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub measurements: Option<OrdersMeasurements>,
     /**
-     * Name of the channel
+     * Information about a shipment
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub reference_id: String,
+    pub order_id: i64,
     /**
-     * Name of the channel
+     * Information about a shipment
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub sku: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package_material_type: Option<PackageMaterialType>,
     /**
-     * The product to create
+     * Information about a shipment
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub unit_price: f64,
+    pub products: Vec<OrdersShipmentProduct>,
     /**
-     * Name of the channel
+     * Information about a shipment
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub upc: String,
-}
-
-/// Updates to an existing product product
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ProductsUpdateProductModel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<OrdersRecipient>,
     /**
      * Name of the channel
      */
@@ -2765,16 +1389,15 @@ pub struct ProductsUpdateProductModel {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub barcode: String,
+    pub reference_id: String,
     /**
-     * Name of the channel
+     * Information about a shipment
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub gtin: String,
+    pub require_signature: bool,
     /**
      * Name of the channel
      */
@@ -2783,400 +1406,376 @@ pub struct ProductsUpdateProductModel {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub ship_option: String,
     /**
-     * Name of the channel
+     * Information about a shipment
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub sku: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Status>,
     /**
-     * Updates to an existing product product
+     * Information about a shipment
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub unit_price: f64,
+    pub status_details: Vec<OrdersStatusDetail>,
     /**
-     * Name of the channel
+     * Information about a shipment
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub upc: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracking: Option<OrdersTracking>,
 }
 
-/// Information about a fulfillment center
+/**
+ * Identifies whether to ship parcel or freight.
+ *   
+ *   Parcel: Smaller, light weight boxes.
+ *   
+ *   Freight: Larger boxes, usually transported by truckload.
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReceivingFulfillmentCenter {
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address1"
-    )]
-    pub address_1: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "address2"
-    )]
-    pub address_2: String,
+#[serde(from = "String", into = "String")]
+pub enum CarrierType {
+    #[serde(rename = "Freight")]
+    Freight,
+    #[serde(rename = "Parcel")]
+    Parcel,
     /**
-     * Name of the channel
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub city: String,
+    Unknown(String),
+}
+
+impl AsRef<str> for CarrierType {
+    fn as_ref(&self) -> &str {
+        match self {
+            CarrierType::Freight => "Freight",
+            CarrierType::Parcel => "Parcel",
+            CarrierType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for CarrierType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CarrierType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Freight" => CarrierType::Freight,
+            "Parcel" => CarrierType::Parcel,
+            _ => CarrierType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CarrierType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<CarrierType> for String {
+    fn from(v: CarrierType) -> Self {
+        match v {
+            CarrierType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/**
+ * Identifies the party responsible for shipping charges.
+ *   
+ *   Collect: The person/entity receiving the product pays the shipping charges [freight only].
+ *   
+ *   ThirdParty: Another party pays for the shipping charges (not Shipbob) [parcel only].
+ *   
+ *   Prepaid: The shipper pays the shipping charges (Shipbob or merchant).
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PaymentTerm {
+    #[serde(rename = "Collect")]
+    Collect,
+    #[serde(rename = "Prepaid")]
+    Prepaid,
+    #[serde(rename = "ThirdParty")]
+    ThirdParty,
     /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub country: String,
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PaymentTerm {
+    fn as_ref(&self) -> &str {
+        match self {
+            PaymentTerm::Collect => "Collect",
+            PaymentTerm::Prepaid => "Prepaid",
+            PaymentTerm::ThirdParty => "ThirdParty",
+            PaymentTerm::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PaymentTerm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PaymentTerm {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Collect" => PaymentTerm::Collect,
+            "Prepaid" => PaymentTerm::Prepaid,
+            "ThirdParty" => PaymentTerm::ThirdParty,
+            _ => PaymentTerm::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PaymentTerm {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PaymentTerm> for String {
+    fn from(v: PaymentTerm) -> Self {
+        match v {
+            PaymentTerm::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+/// Contains shipping properties that need to be used for fulfilling an order.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersShippingTerms {
     /**
-     * Name of the channel
+     * Identifies whether to ship parcel or freight.
+     *  
+     *  Parcel: Smaller, light weight boxes.
+     *  
+     *  Freight: Larger boxes, usually transported by truckload.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub email: String,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub carrier_type: Nullable<CarrierType>,
     /**
-     * Information about a fulfillment center
+     * Identifies the party responsible for shipping charges.
+     *  
+     *  Collect: The person/entity receiving the product pays the shipping charges [freight only].
+     *  
+     *  ThirdParty: Another party pays for the shipping charges (not Shipbob) [parcel only].
+     *  
+     *  Prepaid: The shipper pays the shipping charges (Shipbob or merchant).
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub payment_term: Nullable<PaymentTerm>,
+}
+
+/// Contains properties that needs to be used for fulfilling B2B/Dropship orders.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersRetailerProgramData {
     /**
-     * Name of the channel
+     * Contains properties that needs to be used for fulfilling B2B/Dropship orders.
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub name: String,
+    pub addresses: Vec<OrdersRetailerProgramDataAddress>,
     /**
-     * Name of the channel
+     * Expiration date for this lot
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub phone_number: String,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub delivery_date: Nullable<chrono::DateTime<chrono::Utc>>,
     /**
      * Name of the channel
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub state: String,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub mark_for_store: Nullable<String>,
     /**
      * Name of the channel
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub timezone: String,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub purchase_order_number: Nullable<String>,
     /**
      * Name of the channel
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub zip_code: String,
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub retailer_program_type: Nullable<String>,
 }
 
+/**
+ * The order status
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ReceivingStatus {
-    #[serde(rename = "Arrived")]
-    Arrived,
-    #[serde(rename = "Awaiting")]
-    Awaiting,
+#[serde(from = "String", into = "String")]
+pub enum OrderStatus {
     #[serde(rename = "Cancelled")]
     Cancelled,
-    #[serde(rename = "Completed")]
-    Completed,
-    #[serde(rename = "Incomplete")]
-    Incomplete,
-    #[serde(rename = "PartiallyArrived")]
-    PartiallyArrived,
+    #[serde(rename = "Exception")]
+    Exception,
+    #[serde(rename = "Fulfilled")]
+    Fulfilled,
+    #[serde(rename = "ImportReview")]
+    ImportReview,
+    #[serde(rename = "PartiallyFulfilled")]
+    PartiallyFulfilled,
     #[serde(rename = "Processing")]
     Processing,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ReceivingStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ReceivingStatus::Arrived => "Arrived",
-            ReceivingStatus::Awaiting => "Awaiting",
-            ReceivingStatus::Cancelled => "Cancelled",
-            ReceivingStatus::Completed => "Completed",
-            ReceivingStatus::Incomplete => "Incomplete",
-            ReceivingStatus::PartiallyArrived => "PartiallyArrived",
-            ReceivingStatus::Processing => "Processing",
-            ReceivingStatus::Noop => "",
-            ReceivingStatus::FallthroughString => "*",
+impl AsRef<str> for OrderStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrderStatus::Cancelled => "Cancelled",
+            OrderStatus::Exception => "Exception",
+            OrderStatus::Fulfilled => "Fulfilled",
+            OrderStatus::ImportReview => "ImportReview",
+            OrderStatus::PartiallyFulfilled => "PartiallyFulfilled",
+            OrderStatus::Processing => "Processing",
+            OrderStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ReceivingStatus {
-    fn default() -> ReceivingStatus {
-        ReceivingStatus::Noop
-    }
-}
-impl ReceivingStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ReceivingStatus::Noop)
+impl std::fmt::Display for OrderStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ReceivingPackageType {
-    #[serde(rename = "FloorLoadedContainer")]
-    FloorLoadedContainer,
-    #[serde(rename = "Package")]
-    Package,
-    #[serde(rename = "Pallet")]
-    Pallet,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
+impl std::str::FromStr for OrderStatus {
+    type Err = std::convert::Infallible;
 
-impl std::fmt::Display for ReceivingPackageType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ReceivingPackageType::FloorLoadedContainer => "FloorLoadedContainer",
-            ReceivingPackageType::Package => "Package",
-            ReceivingPackageType::Pallet => "Pallet",
-            ReceivingPackageType::Noop => "",
-            ReceivingPackageType::FallthroughString => "*",
-        }
-        .fmt(f)
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Cancelled" => OrderStatus::Cancelled,
+            "Exception" => OrderStatus::Exception,
+            "Fulfilled" => OrderStatus::Fulfilled,
+            "ImportReview" => OrderStatus::ImportReview,
+            "PartiallyFulfilled" => OrderStatus::PartiallyFulfilled,
+            "Processing" => OrderStatus::Processing,
+            _ => OrderStatus::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for ReceivingPackageType {
-    fn default() -> ReceivingPackageType {
-        ReceivingPackageType::Noop
+impl std::convert::From<String> for OrderStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ReceivingPackageType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ReceivingPackageType::Noop)
+
+impl std::convert::From<OrderStatus> for String {
+    fn from(v: OrderStatus) -> Self {
+        match v {
+            OrderStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
+/**
+ * Shipment type of the order
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ReceivingPackingType {
-    #[serde(rename = "EverythingInOneBox")]
-    EverythingInOneBox,
-    #[serde(rename = "MultipleSkuPerBox")]
-    MultipleSkuPerBox,
-    #[serde(rename = "OneSkuPerBox")]
-    OneSkuPerBox,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+#[serde(from = "String", into = "String")]
+pub enum OrderType {
+    #[serde(rename = "B2B")]
+    B2B,
+    #[serde(rename = "DTC")]
+    Dtc,
+    #[serde(rename = "DropShip")]
+    DropShip,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ReceivingPackingType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ReceivingPackingType::EverythingInOneBox => "EverythingInOneBox",
-            ReceivingPackingType::MultipleSkuPerBox => "MultipleSkuPerBox",
-            ReceivingPackingType::OneSkuPerBox => "OneSkuPerBox",
-            ReceivingPackingType::Noop => "",
-            ReceivingPackingType::FallthroughString => "*",
+impl AsRef<str> for OrderType {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrderType::B2B => "B2B",
+            OrderType::Dtc => "DTC",
+            OrderType::DropShip => "DropShip",
+            OrderType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ReceivingPackingType {
-    fn default() -> ReceivingPackingType {
-        ReceivingPackingType::Noop
-    }
-}
-impl ReceivingPackingType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ReceivingPackingType::Noop)
+impl std::fmt::Display for OrderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ReceivingBoxStatus {
-    #[serde(rename = "Arrived")]
-    Arrived,
-    #[serde(rename = "Awaiting")]
-    Awaiting,
-    #[serde(rename = "Cancelled")]
-    Cancelled,
-    #[serde(rename = "Completed")]
-    Completed,
-    #[serde(rename = "Counting")]
-    Counting,
-    #[serde(rename = "Stowing")]
-    Stowing,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
+impl std::str::FromStr for OrderType {
+    type Err = std::convert::Infallible;
 
-impl std::fmt::Display for ReceivingBoxStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ReceivingBoxStatus::Arrived => "Arrived",
-            ReceivingBoxStatus::Awaiting => "Awaiting",
-            ReceivingBoxStatus::Cancelled => "Cancelled",
-            ReceivingBoxStatus::Completed => "Completed",
-            ReceivingBoxStatus::Counting => "Counting",
-            ReceivingBoxStatus::Stowing => "Stowing",
-            ReceivingBoxStatus::Noop => "",
-            ReceivingBoxStatus::FallthroughString => "*",
-        }
-        .fmt(f)
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "B2B" => OrderType::B2B,
+            "DTC" => OrderType::Dtc,
+            "DropShip" => OrderType::DropShip,
+            _ => OrderType::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for ReceivingBoxStatus {
-    fn default() -> ReceivingBoxStatus {
-        ReceivingBoxStatus::Noop
-    }
-}
-impl ReceivingBoxStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ReceivingBoxStatus::Noop)
+impl std::convert::From<String> for OrderType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
 
-/// Information about an item contained inside a box as part of a receiving order
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReceivingBoxItem {
-    /**
-     * Information about an item contained inside a box as part of a receiving order
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub inventory_id: i64,
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub lot_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub lot_number: String,
-    /**
-     * Information about an item contained inside a box as part of a receiving order
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub quantity: i64,
-    /**
-     * Information about an item contained inside a box as part of a receiving order
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub received_quantity: i64,
+impl std::convert::From<OrderType> for String {
+    fn from(v: OrderType) -> Self {
+        match v {
+            OrderType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
-/// Information about a box shipment included in a receiving order
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReceivingBox {
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub arrived_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Information about the items included in the box
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub box_items: Vec<ReceivingBoxItem>,
-    /**
-     * Information about a box shipment included in a receiving order
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub box_number: i64,
+pub struct Order {
     /**
-     * Information about a box shipment included in a receiving order
+     * Created by channel metadata
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub box_status: Option<ReceivingBoxStatus>,
+    pub channel: Option<OrdersChannelInfo>,
     /**
      * Expiration date for this lot
      */
@@ -3185,30 +1784,25 @@ pub struct ReceivingBox {
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub counting_started_date: Option<chrono::DateTime<chrono::Utc>>,
+    pub created_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Expiration date for this lot
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub received_date: Option<chrono::DateTime<chrono::Utc>>,
+    pub gift_message: String,
     /**
-     * Name of the channel
+     * Unique id of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub tracking_number: String,
-}
-
-/// Information about a receiving order
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReceivingOrder {
+    pub id: i64,
     /**
      * Name of the channel
      */
@@ -3217,21 +1811,16 @@ pub struct ReceivingOrder {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub box_labels_uri: String,
-    /**
-     * Information about a receiving order
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub box_packaging_type: Option<ReceivingPackingType>,
+    pub order_number: String,
     /**
-     * Information about the boxes being shipped in this receiving order
+     * List of products included in the order
      */
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub boxes: Vec<ReceivingBox>,
+    pub products: Vec<OrdersProductInfo>,
     /**
      * Expiration date for this lot
      */
@@ -3240,73 +1829,35 @@ pub struct ReceivingOrder {
         skip_serializing_if = "Option::is_none",
         deserialize_with = "crate::utils::date_time_format::deserialize"
     )]
-    pub expected_arrival_date: Option<chrono::DateTime<chrono::Utc>>,
+    pub purchase_date: Option<chrono::DateTime<chrono::Utc>>,
     /**
-     * Information about a receiving order
+     * Information about the recipient of an order
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fulfillment_center: Option<ReceivingFulfillmentCenter>,
-    /**
-     * Information about a receiving order
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub insert_date: Option<chrono::DateTime<chrono::Utc>>,
+    pub recipient: Option<OrdersRecipientInfo>,
     /**
-     * Expiration date for this lot
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub last_updated_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Information about a receiving order
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub package_type: Option<ReceivingPackageType>,
+    pub reference_id: String,
     /**
-     * Information about a receiving order
+     * Contains properties that needs to be used for fulfilling B2B/Dropship orders.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<ReceivingStatus>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Extensions {}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct MicrosoftAspNetCoreMvcValidationProblemDetails {
+    pub retailer_program_data: Option<OrdersRetailerProgramData>,
     /**
-     * Name of the channel
+     * Shipments affiliated with the order
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub detail: String,
     #[serde(
         default,
         skip_serializing_if = "Vec::is_empty",
         deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub errors: Vec<String>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub extensions: Option<Extensions>,
+    pub shipments: Vec<OrdersShipment>,
     /**
      * Name of the channel
      */
@@ -3315,256 +1866,170 @@ pub struct MicrosoftAspNetCoreMvcValidationProblemDetails {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub instance: String,
+    pub shipping_method: String,
     /**
-     * Unique id of the channel
+     * Contains shipping properties that need to be used for fulfilling an order.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub status: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping_terms: Option<OrdersShippingTerms>,
     /**
-     * Name of the channel
+     * The order status
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
     /**
-     * Name of the channel
+     * Client-defined order tags
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "type"
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
     )]
-    pub type_: String,
-}
-
-/// Model containing information that assigns a receiving order to a fulfillment center
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReceivingAssignOrderFulfillmentCenterModel {
+    pub tags: Vec<OrdersTag>,
     /**
-     * Unique id of the channel
+     * Shipment type of the order
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<OrderType>,
 }
 
-/// Information about an inventory item contained inside a receiving order box
+/**
+ * Defaults to Direct to Consumer (DTC) if not provided. Note: B2B is not supported at this time
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReceivingAddBoxItemModel {
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub inventory_id: i64,
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub lot_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub lot_number: String,
+#[serde(from = "String", into = "String")]
+pub enum OrdersCreateOrderModelType {
+    #[serde(rename = "B2B")]
+    B2B,
+    #[serde(rename = "DTC")]
+    Dtc,
+    #[serde(rename = "DropShip")]
+    DropShip,
     /**
-     * Unique id of the channel
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub quantity: i64,
+    Unknown(String),
 }
 
-/// Information about a box shipment to be added to a receiving order
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReceivingAddBoxOrderModel {
-    /**
-     * Items contained in this box
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub box_items: Vec<ReceivingAddBoxItemModel>,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub tracking_number: String,
+impl AsRef<str> for OrdersCreateOrderModelType {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrdersCreateOrderModelType::B2B => "B2B",
+            OrdersCreateOrderModelType::Dtc => "DTC",
+            OrdersCreateOrderModelType::DropShip => "DropShip",
+            OrdersCreateOrderModelType::Unknown(s) => s,
+        }
+    }
 }
 
-/// Information to create a new receiving order
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReceivingCreateOrderModel {
-    #[serde()]
-    pub box_packaging_type: ReceivingPackingType,
-    /**
-     * Box shipments to be added to this receiving order
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub boxes: Vec<ReceivingAddBoxOrderModel>,
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub expected_arrival_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * Model containing information that assigns a receiving order to a fulfillment center
-     */
-    #[serde()]
-    pub fulfillment_center: ReceivingAssignOrderFulfillmentCenterModel,
-    #[serde()]
-    pub package_type: ReceivingPackageType,
+impl std::fmt::Display for OrdersCreateOrderModelType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ReturnStatus {
-    #[serde(rename = "Arrived")]
-    Arrived,
-    #[serde(rename = "AwaitingArrival")]
-    AwaitingArrival,
-    #[serde(rename = "Cancelled")]
-    Cancelled,
-    #[serde(rename = "Completed")]
-    Completed,
-    #[serde(rename = "Processing")]
-    Processing,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+impl std::str::FromStr for OrdersCreateOrderModelType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "B2B" => OrdersCreateOrderModelType::B2B,
+            "DTC" => OrdersCreateOrderModelType::Dtc,
+            "DropShip" => OrdersCreateOrderModelType::DropShip,
+            _ => OrdersCreateOrderModelType::Unknown(s.to_string()),
+        })
+    }
 }
 
-impl std::fmt::Display for ReturnStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ReturnStatus::Arrived => "Arrived",
-            ReturnStatus::AwaitingArrival => "AwaitingArrival",
-            ReturnStatus::Cancelled => "Cancelled",
-            ReturnStatus::Completed => "Completed",
-            ReturnStatus::Processing => "Processing",
-            ReturnStatus::Noop => "",
-            ReturnStatus::FallthroughString => "*",
+impl std::convert::From<String> for OrdersCreateOrderModelType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
         }
-        .fmt(f)
     }
 }
 
-impl Default for ReturnStatus {
-    fn default() -> ReturnStatus {
-        ReturnStatus::Noop
+impl std::convert::From<OrdersCreateOrderModelType> for String {
+    fn from(v: OrdersCreateOrderModelType) -> Self {
+        match v {
+            OrdersCreateOrderModelType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
-impl ReturnStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ReturnStatus::Noop)
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum Action {
+    #[serde(rename = "Cancel")]
+    Cancel,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for Action {
+    fn as_ref(&self) -> &str {
+        match self {
+            Action::Cancel => "Cancel",
+            Action::Unknown(s) => s,
+        }
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ReturnsTransactionLogSource {
-    #[serde(rename = "ReturnLabelInvoice")]
-    ReturnLabelInvoice,
-    #[serde(rename = "ReturnProcessingFee")]
-    ReturnProcessingFee,
-    #[serde(rename = "ReturnToSenderFee")]
-    ReturnToSenderFee,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for ReturnsTransactionLogSource {
+impl std::fmt::Display for Action {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ReturnsTransactionLogSource::ReturnLabelInvoice => "ReturnLabelInvoice",
-            ReturnsTransactionLogSource::ReturnProcessingFee => "ReturnProcessingFee",
-            ReturnsTransactionLogSource::ReturnToSenderFee => "ReturnToSenderFee",
-            ReturnsTransactionLogSource::Noop => "",
-            ReturnsTransactionLogSource::FallthroughString => "*",
-        }
-        .fmt(f)
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Action {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Cancel" => Action::Cancel,
+            _ => Action::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default for ReturnsTransactionLogSource {
-    fn default() -> ReturnsTransactionLogSource {
-        ReturnsTransactionLogSource::Noop
+impl std::convert::From<String> for Action {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ReturnsTransactionLogSource {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ReturnsTransactionLogSource::Noop)
+
+impl std::convert::From<Action> for String {
+    fn from(v: Action) -> Self {
+        match v {
+            Action::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
+///
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReturnsTransaction {
+pub struct OrdersCanceledShipment {
     /**
-     * Weight in ounces of this inventory item
+     *
      */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
-    )]
-    pub amount: f64,
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub transaction_type: Option<ReturnsTransactionLogSource>,
-}
-
-/// Information about a fulfillment center
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReturnsFulfillmentCenter {
+    pub action: Option<Action>,
     /**
-     * Unique id of the channel
+     *
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
     )]
-    pub id: i64,
+    pub is_success: bool,
     /**
      * Name of the channel
      */
@@ -3573,106 +2038,96 @@ pub struct ReturnsFulfillmentCenter {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub reason: String,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub shipment_id: i64,
 }
 
+/**
+ * The overall result of canceling the shipments associated with the order
+ */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ReturnAction {
-    #[serde(rename = "Default")]
-    Default,
-    #[serde(rename = "Dispose")]
-    Dispose,
-    #[serde(rename = "Quarantine")]
-    Quarantine,
-    #[serde(rename = "Restock")]
-    Restock,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for ReturnAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ReturnAction::Default => "Default",
-            ReturnAction::Dispose => "Dispose",
-            ReturnAction::Quarantine => "Quarantine",
-            ReturnAction::Restock => "Restock",
-            ReturnAction::Noop => "",
-            ReturnAction::FallthroughString => "*",
+#[serde(from = "String", into = "String")]
+pub enum OrdersCanceledOrderStatus {
+    #[serde(rename = "Failure")]
+    Failure,
+    #[serde(rename = "PartialSuccess")]
+    PartialSuccess,
+    #[serde(rename = "Success")]
+    Success,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for OrdersCanceledOrderStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrdersCanceledOrderStatus::Failure => "Failure",
+            OrdersCanceledOrderStatus::PartialSuccess => "PartialSuccess",
+            OrdersCanceledOrderStatus::Success => "Success",
+            OrdersCanceledOrderStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ReturnAction {
-    fn default() -> ReturnAction {
-        ReturnAction::Noop
+impl std::fmt::Display for OrdersCanceledOrderStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl ReturnAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ReturnAction::Noop)
+
+impl std::str::FromStr for OrdersCanceledOrderStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Failure" => OrdersCanceledOrderStatus::Failure,
+            "PartialSuccess" => OrdersCanceledOrderStatus::PartialSuccess,
+            "Success" => OrdersCanceledOrderStatus::Success,
+            _ => OrdersCanceledOrderStatus::Unknown(s.to_string()),
+        })
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ReturnActionSource {
-    #[serde(rename = "InventoryDefault")]
-    InventoryDefault,
-    #[serde(rename = "Override")]
-    Override,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for ReturnActionSource {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ReturnActionSource::InventoryDefault => "InventoryDefault",
-            ReturnActionSource::Override => "Override",
-            ReturnActionSource::Noop => "",
-            ReturnActionSource::FallthroughString => "*",
+impl std::convert::From<String> for OrdersCanceledOrderStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
         }
-        .fmt(f)
     }
 }
 
-impl Default for ReturnActionSource {
-    fn default() -> ReturnActionSource {
-        ReturnActionSource::Noop
-    }
-}
-impl ReturnActionSource {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ReturnActionSource::Noop)
+impl std::convert::From<OrdersCanceledOrderStatus> for String {
+    fn from(v: OrdersCanceledOrderStatus) -> Self {
+        match v {
+            OrdersCanceledOrderStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
+/// Information about a store channel
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReturnActionRequested {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub action: Option<ReturnAction>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub action_type: Option<ReturnActionSource>,
+pub struct ProductsChannel {
     /**
-     * Name of the channel
+     * Information about a store channel
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub instructions: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReturnActionTaken {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub action: Option<ReturnAction>,
+    pub id: i64,
     /**
      * Name of the channel
      */
@@ -3681,40 +2136,32 @@ pub struct ReturnActionTaken {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub action_reason: String,
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub quantity_processed: i64,
+    pub name: String,
 }
 
+/// Information about a fulfillment center
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReturnsInventoryItem {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub action_requested: Option<ReturnActionRequested>,
+pub struct ReceivingFulfillmentCenter {
     /**
-     * Action(s) taken when processing the return
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "address1"
     )]
-    pub action_taken: Vec<ReturnActionTaken>,
+    pub address_1: String,
     /**
-     * Unique id of the channel
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "address2"
     )]
-    pub id: i64,
+    pub address_2: String,
     /**
      * Name of the channel
      */
@@ -3723,68 +2170,28 @@ pub struct ReturnsInventoryItem {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub name: String,
+    pub city: String,
     /**
-     * Unique id of the channel
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub quantity: i64,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum ReturnType {
-    #[serde(rename = "Regular")]
-    Regular,
-    #[serde(rename = "ReturnToSender")]
-    ReturnToSender,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for ReturnType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            ReturnType::Regular => "Regular",
-            ReturnType::ReturnToSender => "ReturnToSender",
-            ReturnType::Noop => "",
-            ReturnType::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for ReturnType {
-    fn default() -> ReturnType {
-        ReturnType::Noop
-    }
-}
-impl ReturnType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ReturnType::Noop)
-    }
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReturnOrder {
+    pub country: String,
     /**
-     * Created by channel metadata
+     * Name of the channel
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub channel: Option<OrdersChannelInfo>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
     /**
      * Information about a fulfillment center
      */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fulfillment_center: Option<ReturnsFulfillmentCenter>,
-    /**
-     * Unique id of the channel
-     */
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
@@ -3792,41 +2199,32 @@ pub struct ReturnOrder {
     )]
     pub id: i64,
     /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub insert_date: Option<chrono::DateTime<chrono::Utc>>,
-    /**
-     * List of inventory included in the return order
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub inventory: Vec<ReturnsInventoryItem>,
+    pub name: String,
     /**
-     * Weight in ounces of this inventory item
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_f64",
-        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub invoice_amount: f64,
+    pub phone_number: String,
     /**
-     * Unique id of the channel
+     * Name of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub original_shipment_id: i64,
+    pub state: String,
     /**
      * Name of the channel
      */
@@ -3835,11 +2233,7 @@ pub struct ReturnOrder {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub reference_id: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub return_type: Option<ReturnType>,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<ReturnStatus>,
+    pub timezone: String,
     /**
      * Name of the channel
      */
@@ -3848,194 +2242,294 @@ pub struct ReturnOrder {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub tracking_number: String,
+    pub zip_code: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReceivingStatus {
+    #[serde(rename = "Arrived")]
+    Arrived,
+    #[serde(rename = "Awaiting")]
+    Awaiting,
+    #[serde(rename = "Cancelled")]
+    Cancelled,
+    #[serde(rename = "Completed")]
+    Completed,
+    #[serde(rename = "Incomplete")]
+    Incomplete,
+    #[serde(rename = "PartiallyArrived")]
+    PartiallyArrived,
+    #[serde(rename = "Processing")]
+    Processing,
     /**
-     * Array of transactions affiliated with the return order
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub transactions: Vec<ReturnsTransaction>,
+    Unknown(String),
+}
+
+impl AsRef<str> for ReceivingStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReceivingStatus::Arrived => "Arrived",
+            ReceivingStatus::Awaiting => "Awaiting",
+            ReceivingStatus::Cancelled => "Cancelled",
+            ReceivingStatus::Completed => "Completed",
+            ReceivingStatus::Incomplete => "Incomplete",
+            ReceivingStatus::PartiallyArrived => "PartiallyArrived",
+            ReceivingStatus::Processing => "Processing",
+            ReceivingStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReceivingStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReceivingStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Arrived" => ReceivingStatus::Arrived,
+            "Awaiting" => ReceivingStatus::Awaiting,
+            "Cancelled" => ReceivingStatus::Cancelled,
+            "Completed" => ReceivingStatus::Completed,
+            "Incomplete" => ReceivingStatus::Incomplete,
+            "PartiallyArrived" => ReceivingStatus::PartiallyArrived,
+            "Processing" => ReceivingStatus::Processing,
+            _ => ReceivingStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReceivingStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReceivingStatus> for String {
+    fn from(v: ReceivingStatus) -> Self {
+        match v {
+            ReceivingStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReceivingPackageType {
+    #[serde(rename = "FloorLoadedContainer")]
+    FloorLoadedContainer,
+    #[serde(rename = "Package")]
+    Package,
+    #[serde(rename = "Pallet")]
+    Pallet,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReceivingPackageType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReceivingPackageType::FloorLoadedContainer => "FloorLoadedContainer",
+            ReceivingPackageType::Package => "Package",
+            ReceivingPackageType::Pallet => "Pallet",
+            ReceivingPackageType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReceivingPackageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReceivingPackageType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "FloorLoadedContainer" => ReceivingPackageType::FloorLoadedContainer,
+            "Package" => ReceivingPackageType::Package,
+            "Pallet" => ReceivingPackageType::Pallet,
+            _ => ReceivingPackageType::Unknown(s.to_string()),
+        })
+    }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReturnInventory {
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub id: i64,
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub quantity: i64,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub requested_action: Option<ReturnAction>,
+impl std::convert::From<String> for ReceivingPackageType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReceivingPackageType> for String {
+    fn from(v: ReceivingPackageType) -> Self {
+        match v {
+            ReceivingPackageType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReturnsCreateReturn {
-    /**
-     * Information about a fulfillment center
-     */
-    #[serde()]
-    pub fulfillment_center: ReturnsFulfillmentCenter,
-    /**
-     * Array of inventory items being returned
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub inventory: Vec<ReturnInventory>,
-    /**
-     * Unique id of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "crate::utils::zero_i64",
-        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
-    )]
-    pub original_shipment_id: i64,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub reference_id: String,
+#[serde(from = "String", into = "String")]
+pub enum ReceivingPackingType {
+    #[serde(rename = "EverythingInOneBox")]
+    EverythingInOneBox,
+    #[serde(rename = "MultipleSkuPerBox")]
+    MultipleSkuPerBox,
+    #[serde(rename = "OneSkuPerBox")]
+    OneSkuPerBox,
     /**
-     * Name of the channel
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub tracking_number: String,
+    Unknown(String),
 }
 
-/**
- * Order to sort results in
- */
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum SortOrder {
-    #[serde(rename = "Newest")]
-    Newest,
-    #[serde(rename = "Oldest")]
-    Oldest,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for SortOrder {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            SortOrder::Newest => "Newest",
-            SortOrder::Oldest => "Oldest",
-            SortOrder::Noop => "",
-            SortOrder::FallthroughString => "*",
+impl AsRef<str> for ReceivingPackingType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReceivingPackingType::EverythingInOneBox => "EverythingInOneBox",
+            ReceivingPackingType::MultipleSkuPerBox => "MultipleSkuPerBox",
+            ReceivingPackingType::OneSkuPerBox => "OneSkuPerBox",
+            ReceivingPackingType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SortOrder {
-    fn default() -> SortOrder {
-        SortOrder::Noop
+impl std::fmt::Display for ReceivingPackingType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReceivingPackingType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EverythingInOneBox" => ReceivingPackingType::EverythingInOneBox,
+            "MultipleSkuPerBox" => ReceivingPackingType::MultipleSkuPerBox,
+            "OneSkuPerBox" => ReceivingPackingType::OneSkuPerBox,
+            _ => ReceivingPackingType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReceivingPackingType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl SortOrder {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SortOrder::Noop)
+
+impl std::convert::From<ReceivingPackingType> for String {
+    fn from(v: ReceivingPackingType) -> Self {
+        match v {
+            ReceivingPackingType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct ReturnOrderStatusHistory {
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub status: Option<ReturnStatus>,
+#[serde(from = "String", into = "String")]
+pub enum ReceivingBoxStatus {
+    #[serde(rename = "Arrived")]
+    Arrived,
+    #[serde(rename = "Awaiting")]
+    Awaiting,
+    #[serde(rename = "Cancelled")]
+    Cancelled,
+    #[serde(rename = "Completed")]
+    Completed,
+    #[serde(rename = "Counting")]
+    Counting,
+    #[serde(rename = "Stowing")]
+    Stowing,
     /**
-     * Expiration date for this lot
+     * A value that is not in the API spec, as it was sent.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub time_stamp: Option<chrono::DateTime<chrono::Utc>>,
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum WebhooksTopics {
-    #[serde(rename = "order_shipped")]
-    OrderShipped,
-    #[serde(rename = "shipment_delivered")]
-    ShipmentDelivered,
-    #[serde(rename = "shipment_exception")]
-    ShipmentException,
-    #[serde(rename = "shipment_onhold")]
-    ShipmentOnhold,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for WebhooksTopics {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            WebhooksTopics::OrderShipped => "order_shipped",
-            WebhooksTopics::ShipmentDelivered => "shipment_delivered",
-            WebhooksTopics::ShipmentException => "shipment_exception",
-            WebhooksTopics::ShipmentOnhold => "shipment_onhold",
-            WebhooksTopics::Noop => "",
-            WebhooksTopics::FallthroughString => "*",
+impl AsRef<str> for ReceivingBoxStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReceivingBoxStatus::Arrived => "Arrived",
+            ReceivingBoxStatus::Awaiting => "Awaiting",
+            ReceivingBoxStatus::Cancelled => "Cancelled",
+            ReceivingBoxStatus::Completed => "Completed",
+            ReceivingBoxStatus::Counting => "Counting",
+            ReceivingBoxStatus::Stowing => "Stowing",
+            ReceivingBoxStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for WebhooksTopics {
-    fn default() -> WebhooksTopics {
-        WebhooksTopics::Noop
+impl std::fmt::Display for ReceivingBoxStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReceivingBoxStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Arrived" => ReceivingBoxStatus::Arrived,
+            "Awaiting" => ReceivingBoxStatus::Awaiting,
+            "Cancelled" => ReceivingBoxStatus::Cancelled,
+            "Completed" => ReceivingBoxStatus::Completed,
+            "Counting" => ReceivingBoxStatus::Counting,
+            "Stowing" => ReceivingBoxStatus::Stowing,
+            _ => ReceivingBoxStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReceivingBoxStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl WebhooksTopics {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, WebhooksTopics::Noop)
+
+impl std::convert::From<ReceivingBoxStatus> for String {
+    fn from(v: ReceivingBoxStatus) -> Self {
+        match v {
+            ReceivingBoxStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
+/// Created by channel metadata
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct Webhook {
-    /**
-     * Expiration date for this lot
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Option::is_none",
-        deserialize_with = "crate::utils::date_time_format::deserialize"
-    )]
-    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+pub struct ReturnsChannelInfo {
     /**
-     * Unique id of the channel
+     * Created by channel metadata
      */
     #[serde(
         default,
@@ -4051,26 +2545,31 @@ pub struct Webhook {
         skip_serializing_if = "String::is_empty",
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
-    pub subscription_url: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub topic: Option<WebhooksTopics>,
+    pub name: String,
 }
 
+/// Information about a fulfillment center
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct WebhooksCreateWebhookSubscriptionModel {
+pub struct ReturnsFulfillmentCenter {
     /**
-     * Name of the channel
+     * Unique id of the channel
      */
     #[serde(
         default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
-    pub subscription_url: String,
-    #[serde()]
-    pub topic: WebhooksTopics,
+    pub id: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ExtensionsAdditionalProperties {}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct MicrosoftAspNetCoreMvcProblemDetails {
     /**
@@ -4092,8 +2591,12 @@ pub struct MicrosoftAspNetCoreMvcProblemDetails {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub detail: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub extensions: Option<Extensions>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub extensions: std::collections::BTreeMap<String, ExtensionsAdditionalProperties>,
     /**
      * Name of the channel
      */
@@ -4130,41 +2633,6 @@ pub struct MicrosoftAspNetCoreMvcProblemDetails {
     pub type_: String,
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub enum IntegrationsLocationServiceTypeEnum {
-    #[serde(rename = "Receiving")]
-    Receiving,
-    #[serde(rename = "Returns")]
-    Returns,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
-}
-
-impl std::fmt::Display for IntegrationsLocationServiceTypeEnum {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-            IntegrationsLocationServiceTypeEnum::Receiving => "Receiving",
-            IntegrationsLocationServiceTypeEnum::Returns => "Returns",
-            IntegrationsLocationServiceTypeEnum::Noop => "",
-            IntegrationsLocationServiceTypeEnum::FallthroughString => "*",
-        }
-        .fmt(f)
-    }
-}
-
-impl Default for IntegrationsLocationServiceTypeEnum {
-    fn default() -> IntegrationsLocationServiceTypeEnum {
-        IntegrationsLocationServiceTypeEnum::Noop
-    }
-}
-impl IntegrationsLocationServiceTypeEnum {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, IntegrationsLocationServiceTypeEnum::Noop)
-    }
-}
-
 /// The service-specific address of the location. Each object contains address type, address1, address2, city, state, country, zip code, phone number, and email
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct IntegrationsLocationAddress {
@@ -4254,80 +2722,16 @@ pub struct IntegrationsLocationAddress {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IntegrationsLocationService {
-    /**
-     * The service-specific address of the location. Each object contains address type, address1, address2, city, state, country, zip code, phone number, and email
-     */
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub address: Option<IntegrationsLocationAddress>,
-    /**
-     * True if the inventory item is marked as a digital item
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub enabled: bool,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub service_type: Option<IntegrationsLocationServiceTypeEnum>,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IntegrationsLocation {
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
-        rename = "$type"
-    )]
-    pub type__: String,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub abbreviation: String,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub access_granted: bool,
+pub struct IntegrationsLocationFulfillmentCenterRegion {
     /**
-     * Array of permissions granted for the channel
+     * Unique id of the channel
      */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub attributes: Vec<String>,
     #[serde(
         default,
         skip_serializing_if = "crate::utils::zero_i64",
         deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
     )]
     pub id: i64,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_active: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_receiving_enabled: bool,
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_shipping_enabled: bool,
     /**
      * Name of the channel
      */
@@ -4337,48 +2741,4 @@ pub struct IntegrationsLocation {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub name: String,
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub region: Option<OrdersServiceLevelDetail>,
-    /**
-     * Services provided by the location
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "Vec::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
-    )]
-    pub services: Vec<IntegrationsLocationService>,
-    /**
-     * Name of the channel
-     */
-    #[serde(
-        default,
-        skip_serializing_if = "String::is_empty",
-        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
-    )]
-    pub timezone: String,
-}
-
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IntegrationsLocationInternal {
-    /**
-     * True if the inventory item is marked as a digital item
-     */
-    #[serde(
-        default,
-        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
-    )]
-    pub is_enabled_for_new_user: bool,
-}
-
-/// All of the following types are flattened into one object:
-///
-/// - `IntegrationsLocation`
-/// - `IntegrationsLocationInternal`
-#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
-pub struct IntegrationsLocationInternalAllOf {
-    #[serde(flatten)]
-    pub integrations_location: IntegrationsLocation,
-    #[serde(flatten)]
-    pub integrations_location_internal: IntegrationsLocationInternal,
 }
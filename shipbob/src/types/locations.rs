@@ -0,0 +1,194 @@
+//! The data types of the `locations` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum IntegrationsLocationServiceTypeEnum {
+    #[serde(rename = "Receiving")]
+    Receiving,
+    #[serde(rename = "Returns")]
+    Returns,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for IntegrationsLocationServiceTypeEnum {
+    fn as_ref(&self) -> &str {
+        match self {
+            IntegrationsLocationServiceTypeEnum::Receiving => "Receiving",
+            IntegrationsLocationServiceTypeEnum::Returns => "Returns",
+            IntegrationsLocationServiceTypeEnum::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for IntegrationsLocationServiceTypeEnum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for IntegrationsLocationServiceTypeEnum {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Receiving" => IntegrationsLocationServiceTypeEnum::Receiving,
+            "Returns" => IntegrationsLocationServiceTypeEnum::Returns,
+            _ => IntegrationsLocationServiceTypeEnum::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for IntegrationsLocationServiceTypeEnum {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<IntegrationsLocationServiceTypeEnum> for String {
+    fn from(v: IntegrationsLocationServiceTypeEnum) -> Self {
+        match v {
+            IntegrationsLocationServiceTypeEnum::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IntegrationsLocationService {
+    /**
+     * The service-specific address of the location. Each object contains address type, address1, address2, city, state, country, zip code, phone number, and email
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<IntegrationsLocationAddress>,
+    /**
+     * True if the inventory item is marked as a digital item
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub enabled: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_type: Option<IntegrationsLocationServiceTypeEnum>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IntegrationsLocation {
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "$type"
+    )]
+    pub type__: String,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub abbreviation: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub access_granted: bool,
+    /**
+     * Array of permissions granted for the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub attributes: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_active: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_receiving_enabled: bool,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_shipping_enabled: bool,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<IntegrationsLocationFulfillmentCenterRegion>,
+    /**
+     * Services provided by the location
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub services: Vec<IntegrationsLocationService>,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub timezone: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IntegrationsLocationInternal {
+    /**
+     * True if the inventory item is marked as a digital item
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub is_enabled_for_new_user: bool,
+}
+
+/// All of the following types are flattened into one object:
+///
+/// - `IntegrationsLocation`
+/// - `IntegrationsLocationInternal`
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct IntegrationsLocationInternalAllOf {
+    #[serde(flatten)]
+    pub integrations_location: IntegrationsLocation,
+    #[serde(flatten)]
+    pub integrations_location_internal: IntegrationsLocationInternal,
+}
@@ -0,0 +1,508 @@
+//! The data types of the `orders` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersEstimationAddress {
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "address1"
+    )]
+    pub address_1: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Nullable::is_absent",
+        rename = "address2"
+    )]
+    pub address_2: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub city: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub company_name: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub country: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub state: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub zip_code: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersEstimateProductInfoModel {
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub quantity: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub reference_id: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersEstimateFulfillmentRequestModel {
+    #[serde()]
+    pub address: OrdersEstimationAddress,
+    /**
+     * Products to be included in the order. Each product must include one of reference_id or id
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub products: Vec<OrdersEstimateProductInfoModel>,
+    /**
+     * Array of permissions granted for the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub shipping_methods: Nullable<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersEstimate {
+    /**
+     * Array of estimates for each shipping method
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub estimates: Vec<OrdersEstimateDetail>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersAddProductOrderByModel {
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub quantity: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersAddProductOrderByReferenceModel {
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub quantity: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub reference_id: Nullable<String>,
+}
+
+/// All of the following types:
+///
+/// - `OrdersAddProductOrderByModel`
+/// - `OrdersAddProductOrderByReferenceModel`
+///
+/// You can easily convert this enum to the inner value with `From` and `Into`, as both are implemented for each type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(untagged)]
+pub enum OrdersAddProductOrderModelOneOf {
+    OrdersAddProductOrderByModel(OrdersAddProductOrderByModel),
+    OrdersAddProductOrderByReferenceModel(OrdersAddProductOrderByReferenceModel),
+}
+
+impl OrdersAddProductOrderModelOneOf {
+    pub fn orders_add_product_order_by_model(&self) -> Option<&OrdersAddProductOrderByModel> {
+        if let OrdersAddProductOrderModelOneOf::OrdersAddProductOrderByModel(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+
+    pub fn orders_add_product_order_by_reference_model(
+        &self,
+    ) -> Option<&OrdersAddProductOrderByReferenceModel> {
+        if let OrdersAddProductOrderModelOneOf::OrdersAddProductOrderByReferenceModel(ref_) = self {
+            return Some(ref_);
+        }
+        None
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersCreateOrderModel {
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub gift_message: Nullable<String>,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub location_id: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub order_number: Nullable<String>,
+    /**
+     * Products included in the order. Products identified by reference_id must also include the product name if there is no matching ShipBob product.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub products: Vec<OrdersAddProductOrderModelOneOf>,
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub purchase_date: Nullable<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Information about the recipient of an order
+     */
+    #[serde()]
+    pub recipient: OrdersRecipientInfo,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub reference_id: Nullable<String>,
+    /**
+     * Contains properties that needs to be used for fulfilling B2B/Dropship orders.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retailer_program_data: Option<OrdersRetailerProgramData>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub shipping_method: Nullable<String>,
+    /**
+     * Contains shipping properties that need to be used for fulfilling an order.
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping_terms: Option<OrdersShippingTerms>,
+    /**
+     * Client-defined order tags
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub tags: Vec<OrdersTag>,
+    /**
+     * Defaults to Direct to Consumer (DTC) if not provided. Note: B2B is not supported at this time
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<OrdersCreateOrderModelType>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersCanceledOrder {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub canceled_shipment_results: Vec<OrdersCanceledShipment>,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<Order>,
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub order_id: i64,
+    /**
+     *
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrdersCanceledOrderStatus>,
+}
+
+/// Model for adding a Store Order Json to a ShipBob Order.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersAddStoreOrderJsonModel {
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub order_json: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersShipmentLog {
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub log_type_id: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub log_type_name: String,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub log_type_text: String,
+    /**
+     * Specifics data for the event
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub metadata: std::collections::BTreeMap<String, String>,
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub timestamp: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Model for cancel multiple shipments at once
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersCancelShipmentsModel {
+    /**
+     * Model for cancel multiple shipments at once
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub shipment_ids: Vec<i64>,
+}
+
+///
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersCanceledShipments {
+    /**
+     *
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub results: Vec<OrdersCanceledShipment>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersServiceLevelDetail {
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct OrdersShipMethodDetail {
+    /**
+     * True if the inventory item is marked as a digital item
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub active: bool,
+    /**
+     * True if the inventory item is marked as a digital item
+     */
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub default: bool,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_level: Option<OrdersServiceLevelDetail>,
+}
+
+/**
+ * Order to sort results in
+ */
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum SortOrder {
+    #[serde(rename = "Newest")]
+    Newest,
+    #[serde(rename = "Oldest")]
+    Oldest,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for SortOrder {
+    fn as_ref(&self) -> &str {
+        match self {
+            SortOrder::Newest => "Newest",
+            SortOrder::Oldest => "Oldest",
+            SortOrder::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SortOrder {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Newest" => SortOrder::Newest,
+            "Oldest" => SortOrder::Oldest,
+            _ => SortOrder::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SortOrder {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SortOrder> for String {
+    fn from(v: SortOrder) -> Self {
+        match v {
+            SortOrder::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
@@ -0,0 +1,455 @@
+//! The data types of the `products` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ProductActiveStatus {
+    #[serde(rename = "Active")]
+    Active,
+    #[serde(rename = "Any")]
+    Any,
+    #[serde(rename = "Inactive")]
+    Inactive,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ProductActiveStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            ProductActiveStatus::Active => "Active",
+            ProductActiveStatus::Any => "Any",
+            ProductActiveStatus::Inactive => "Inactive",
+            ProductActiveStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ProductActiveStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ProductActiveStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Active" => ProductActiveStatus::Active,
+            "Any" => ProductActiveStatus::Any,
+            "Inactive" => ProductActiveStatus::Inactive,
+            _ => ProductActiveStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ProductActiveStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ProductActiveStatus> for String {
+    fn from(v: ProductActiveStatus) -> Self {
+        match v {
+            ProductActiveStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ProductBundleStatus {
+    #[serde(rename = "Any")]
+    Any,
+    #[serde(rename = "Bundle")]
+    Bundle,
+    #[serde(rename = "NotBundle")]
+    NotBundle,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ProductBundleStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            ProductBundleStatus::Any => "Any",
+            ProductBundleStatus::Bundle => "Bundle",
+            ProductBundleStatus::NotBundle => "NotBundle",
+            ProductBundleStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ProductBundleStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ProductBundleStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Any" => ProductBundleStatus::Any,
+            "Bundle" => ProductBundleStatus::Bundle,
+            "NotBundle" => ProductBundleStatus::NotBundle,
+            _ => ProductBundleStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ProductBundleStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ProductBundleStatus> for String {
+    fn from(v: ProductBundleStatus) -> Self {
+        match v {
+            ProductBundleStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProductsBundleRootInformation {
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+}
+
+/// The inventory that store products can resolve to when packing a shipment
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProductsInventoryItem {
+    /**
+     * The inventory that store products can resolve to when packing a shipment
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * The inventory that store products can resolve to when packing a shipment
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub quantity: i64,
+}
+
+/// Break down of quantities by fulfillment center
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProductsFulfillmentCenterQuantity {
+    /**
+     * Break down of quantities by fulfillment center
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub committed_quantity: i64,
+    /**
+     * Break down of quantities by fulfillment center
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub fulfillable_quantity: i64,
+    /**
+     * Break down of quantities by fulfillment center
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * Break down of quantities by fulfillment center
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub onhand_quantity: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Product {
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub barcode: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bundle_root_information: Option<ProductsBundleRootInformation>,
+    /**
+     * Information about a store channel
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<ProductsChannel>,
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_date: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * The inventory that this product will resolve to when packing a shipment
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub fulfillable_inventory_items: Vec<ProductsInventoryItem>,
+    /**
+     * Fulfillable quantity of this product broken down by fulfillment center location
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub fulfillable_quantity_by_fulfillment_center: Vec<ProductsFulfillmentCenterQuantity>,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub gtin: String,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reference_id: String,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub sku: String,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_committed_quantity: i64,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_fulfillable_quantity: i64,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_onhand_quantity: i64,
+    /**
+     * Weight in ounces of this inventory item
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub unit_price: f64,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub upc: String,
+}
+
+/// The product to create
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProductsCreateProductModel {
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub barcode: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub gtin: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub reference_id: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub sku: Nullable<String>,
+    /**
+     * The product to create
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub unit_price: f64,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub upc: Nullable<String>,
+}
+
+/// Updates to an existing product product
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ProductsUpdateProductModel {
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub barcode: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub gtin: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub name: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub sku: Nullable<String>,
+    /**
+     * Updates to an existing product product
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub unit_price: f64,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub upc: Nullable<String>,
+}
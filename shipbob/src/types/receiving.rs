@@ -0,0 +1,284 @@
+//! The data types of the `receiving` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Information about an item contained inside a box as part of a receiving order
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReceivingBoxItem {
+    /**
+     * Information about an item contained inside a box as part of a receiving order
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub inventory_id: i64,
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub lot_date: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub lot_number: String,
+    /**
+     * Information about an item contained inside a box as part of a receiving order
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub quantity: i64,
+    /**
+     * Information about an item contained inside a box as part of a receiving order
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub received_quantity: i64,
+}
+
+/// Information about a box shipment included in a receiving order
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReceivingBox {
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub arrived_date: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Information about the items included in the box
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub box_items: Vec<ReceivingBoxItem>,
+    /**
+     * Information about a box shipment included in a receiving order
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub box_number: i64,
+    /**
+     * Information about a box shipment included in a receiving order
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub box_status: Option<ReceivingBoxStatus>,
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub counting_started_date: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub received_date: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tracking_number: String,
+}
+
+/// Information about a receiving order
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReceivingOrder {
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub box_labels_uri: String,
+    /**
+     * Information about a receiving order
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub box_packaging_type: Option<ReceivingPackingType>,
+    /**
+     * Information about the boxes being shipped in this receiving order
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub boxes: Vec<ReceivingBox>,
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub expected_arrival_date: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Information about a receiving order
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillment_center: Option<ReceivingFulfillmentCenter>,
+    /**
+     * Information about a receiving order
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub insert_date: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub last_updated_date: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Information about a receiving order
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package_type: Option<ReceivingPackageType>,
+    /**
+     * Information about a receiving order
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<ReceivingStatus>,
+}
+
+/// Model containing information that assigns a receiving order to a fulfillment center
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReceivingAssignOrderFulfillmentCenterModel {
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+}
+
+/// Information about an inventory item contained inside a receiving order box
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReceivingAddBoxItemModel {
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub inventory_id: i64,
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub lot_date: Nullable<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub lot_number: Nullable<String>,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub quantity: i64,
+}
+
+/// Information about a box shipment to be added to a receiving order
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReceivingAddBoxOrderModel {
+    /**
+     * Items contained in this box
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub box_items: Nullable<Vec<ReceivingAddBoxItemModel>>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub tracking_number: Nullable<String>,
+}
+
+/// Information to create a new receiving order
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReceivingCreateOrderModel {
+    #[serde()]
+    pub box_packaging_type: ReceivingPackingType,
+    /**
+     * Box shipments to be added to this receiving order
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub boxes: Nullable<Vec<ReceivingAddBoxOrderModel>>,
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub expected_arrival_date: Nullable<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Model containing information that assigns a receiving order to a fulfillment center
+     */
+    #[serde()]
+    pub fulfillment_center: ReceivingAssignOrderFulfillmentCenterModel,
+    #[serde()]
+    pub package_type: ReceivingPackageType,
+}
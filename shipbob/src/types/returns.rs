@@ -0,0 +1,708 @@
+//! The data types of the `returns` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Extensions {}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MicrosoftAspNetCoreMvcValidationProblemDetails {
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub detail: String,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub errors: std::collections::BTreeMap<String, Vec<String>>,
+    #[serde(
+        default,
+        skip_serializing_if = "std::collections::BTreeMap::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_map::deserialize"
+    )]
+    pub extensions: std::collections::BTreeMap<String, Extensions>,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub instance: String,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub status: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub title: String,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "type"
+    )]
+    pub type_: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReturnStatus {
+    #[serde(rename = "Arrived")]
+    Arrived,
+    #[serde(rename = "AwaitingArrival")]
+    AwaitingArrival,
+    #[serde(rename = "Cancelled")]
+    Cancelled,
+    #[serde(rename = "Completed")]
+    Completed,
+    #[serde(rename = "Processing")]
+    Processing,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReturnStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReturnStatus::Arrived => "Arrived",
+            ReturnStatus::AwaitingArrival => "AwaitingArrival",
+            ReturnStatus::Cancelled => "Cancelled",
+            ReturnStatus::Completed => "Completed",
+            ReturnStatus::Processing => "Processing",
+            ReturnStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReturnStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReturnStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Arrived" => ReturnStatus::Arrived,
+            "AwaitingArrival" => ReturnStatus::AwaitingArrival,
+            "Cancelled" => ReturnStatus::Cancelled,
+            "Completed" => ReturnStatus::Completed,
+            "Processing" => ReturnStatus::Processing,
+            _ => ReturnStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReturnStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReturnStatus> for String {
+    fn from(v: ReturnStatus) -> Self {
+        match v {
+            ReturnStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReturnsTransactionLogSource {
+    #[serde(rename = "ReturnLabelInvoice")]
+    ReturnLabelInvoice,
+    #[serde(rename = "ReturnProcessingFee")]
+    ReturnProcessingFee,
+    #[serde(rename = "ReturnToSenderFee")]
+    ReturnToSenderFee,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReturnsTransactionLogSource {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReturnsTransactionLogSource::ReturnLabelInvoice => "ReturnLabelInvoice",
+            ReturnsTransactionLogSource::ReturnProcessingFee => "ReturnProcessingFee",
+            ReturnsTransactionLogSource::ReturnToSenderFee => "ReturnToSenderFee",
+            ReturnsTransactionLogSource::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReturnsTransactionLogSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReturnsTransactionLogSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ReturnLabelInvoice" => ReturnsTransactionLogSource::ReturnLabelInvoice,
+            "ReturnProcessingFee" => ReturnsTransactionLogSource::ReturnProcessingFee,
+            "ReturnToSenderFee" => ReturnsTransactionLogSource::ReturnToSenderFee,
+            _ => ReturnsTransactionLogSource::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReturnsTransactionLogSource {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReturnsTransactionLogSource> for String {
+    fn from(v: ReturnsTransactionLogSource) -> Self {
+        match v {
+            ReturnsTransactionLogSource::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReturnsTransaction {
+    /**
+     * Weight in ounces of this inventory item
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub amount: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transaction_type: Option<ReturnsTransactionLogSource>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReturnAction {
+    #[serde(rename = "Default")]
+    Default,
+    #[serde(rename = "Dispose")]
+    Dispose,
+    #[serde(rename = "Quarantine")]
+    Quarantine,
+    #[serde(rename = "Restock")]
+    Restock,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReturnAction {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReturnAction::Default => "Default",
+            ReturnAction::Dispose => "Dispose",
+            ReturnAction::Quarantine => "Quarantine",
+            ReturnAction::Restock => "Restock",
+            ReturnAction::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReturnAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReturnAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Default" => ReturnAction::Default,
+            "Dispose" => ReturnAction::Dispose,
+            "Quarantine" => ReturnAction::Quarantine,
+            "Restock" => ReturnAction::Restock,
+            _ => ReturnAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReturnAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReturnAction> for String {
+    fn from(v: ReturnAction) -> Self {
+        match v {
+            ReturnAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReturnActionSource {
+    #[serde(rename = "InventoryDefault")]
+    InventoryDefault,
+    #[serde(rename = "Override")]
+    Override,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReturnActionSource {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReturnActionSource::InventoryDefault => "InventoryDefault",
+            ReturnActionSource::Override => "Override",
+            ReturnActionSource::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReturnActionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReturnActionSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "InventoryDefault" => ReturnActionSource::InventoryDefault,
+            "Override" => ReturnActionSource::Override,
+            _ => ReturnActionSource::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReturnActionSource {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReturnActionSource> for String {
+    fn from(v: ReturnActionSource) -> Self {
+        match v {
+            ReturnActionSource::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReturnActionRequested {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<ReturnAction>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<ReturnActionSource>,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub instructions: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReturnActionTaken {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<ReturnAction>,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub action_reason: String,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub quantity_processed: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReturnsInventoryItem {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action_requested: Option<ReturnActionRequested>,
+    /**
+     * Action(s) taken when processing the return
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub action_taken: Vec<ReturnActionTaken>,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub quantity: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReturnType {
+    #[serde(rename = "Regular")]
+    Regular,
+    #[serde(rename = "ReturnToSender")]
+    ReturnToSender,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReturnType {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReturnType::Regular => "Regular",
+            ReturnType::ReturnToSender => "ReturnToSender",
+            ReturnType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReturnType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReturnType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Regular" => ReturnType::Regular,
+            "ReturnToSender" => ReturnType::ReturnToSender,
+            _ => ReturnType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReturnType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReturnType> for String {
+    fn from(v: ReturnType) -> Self {
+        match v {
+            ReturnType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReturnOrder {
+    /**
+     * Created by channel metadata
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<ReturnsChannelInfo>,
+    /**
+     * Information about a fulfillment center
+     */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fulfillment_center: Option<ReturnsFulfillmentCenter>,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub insert_date: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * List of inventory included in the return order
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub inventory: Vec<ReturnsInventoryItem>,
+    /**
+     * Weight in ounces of this inventory item
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize"
+    )]
+    pub invoice_amount: f64,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub original_shipment_id: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reference_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<ReturnType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<ReturnStatus>,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub tracking_number: String,
+    /**
+     * Array of transactions affiliated with the return order
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub transactions: Vec<ReturnsTransaction>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReturnInventory {
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub quantity: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requested_action: Option<ReturnAction>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReturnsCreateReturn {
+    /**
+     * Information about a fulfillment center
+     */
+    #[serde()]
+    pub fulfillment_center: ReturnsFulfillmentCenter,
+    /**
+     * Array of inventory items being returned
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub inventory: Nullable<Vec<ReturnInventory>>,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub original_shipment_id: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub reference_id: Nullable<String>,
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub tracking_number: Nullable<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum ReturnsSortOrder {
+    #[serde(rename = "Newest")]
+    Newest,
+    #[serde(rename = "Oldest")]
+    Oldest,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for ReturnsSortOrder {
+    fn as_ref(&self) -> &str {
+        match self {
+            ReturnsSortOrder::Newest => "Newest",
+            ReturnsSortOrder::Oldest => "Oldest",
+            ReturnsSortOrder::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReturnsSortOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReturnsSortOrder {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Newest" => ReturnsSortOrder::Newest,
+            "Oldest" => ReturnsSortOrder::Oldest,
+            _ => ReturnsSortOrder::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReturnsSortOrder {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReturnsSortOrder> for String {
+    fn from(v: ReturnsSortOrder) -> Self {
+        match v {
+            ReturnsSortOrder::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ReturnOrderStatusHistory {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<ReturnStatus>,
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub time_stamp: Option<chrono::DateTime<chrono::Utc>>,
+}
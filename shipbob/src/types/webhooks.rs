@@ -0,0 +1,116 @@
+//! The data types of the `webhooks` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum WebhooksTopics {
+    #[serde(rename = "order_shipped")]
+    OrderShipped,
+    #[serde(rename = "shipment_delivered")]
+    ShipmentDelivered,
+    #[serde(rename = "shipment_exception")]
+    ShipmentException,
+    #[serde(rename = "shipment_onhold")]
+    ShipmentOnhold,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for WebhooksTopics {
+    fn as_ref(&self) -> &str {
+        match self {
+            WebhooksTopics::OrderShipped => "order_shipped",
+            WebhooksTopics::ShipmentDelivered => "shipment_delivered",
+            WebhooksTopics::ShipmentException => "shipment_exception",
+            WebhooksTopics::ShipmentOnhold => "shipment_onhold",
+            WebhooksTopics::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for WebhooksTopics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for WebhooksTopics {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "order_shipped" => WebhooksTopics::OrderShipped,
+            "shipment_delivered" => WebhooksTopics::ShipmentDelivered,
+            "shipment_exception" => WebhooksTopics::ShipmentException,
+            "shipment_onhold" => WebhooksTopics::ShipmentOnhold,
+            _ => WebhooksTopics::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for WebhooksTopics {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<WebhooksTopics> for String {
+    fn from(v: WebhooksTopics) -> Self {
+        match v {
+            WebhooksTopics::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Webhook {
+    /**
+     * Expiration date for this lot
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Unique id of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub id: i64,
+    /**
+     * Name of the channel
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subscription_url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub topic: Option<WebhooksTopics>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WebhooksCreateWebhookSubscriptionModel {
+    /**
+     * Name of the channel
+     */
+    #[serde(default, skip_serializing_if = "Nullable::is_absent")]
+    pub subscription_url: Nullable<String>,
+    #[serde()]
+    pub topic: WebhooksTopics,
+}
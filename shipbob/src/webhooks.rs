@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Webhooks {
     pub client: Client,
@@ -21,24 +19,20 @@ impl Webhooks {
      *
      * **Parameters:**
      *
-     * * `topic: crate::types::WebhooksTopics` -- Topic of the webhooks requested.
-     * * `page: u64` -- Unique id of the channel.
-     * * `limit: i64` -- Amount of Webhooks per page to request.
+     * * `params: &WebhooksGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        topic: crate::types::WebhooksTopics,
-        page: u64,
-        limit: i64,
+        params: &WebhooksGetPageParams,
     ) -> Result<Vec<crate::types::Webhook>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("Limit".to_string(), limit.to_string()));
         }
-        if !page.to_string().is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("Page".to_string(), page.to_string()));
         }
-        if !topic.to_string().is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("Topic".to_string(), topic.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -47,6 +41,24 @@ impl Webhooks {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `WebhooksGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        topic: crate::types::WebhooksTopics,
+        page: u64,
+        limit: i64,
+    ) -> Result<Vec<crate::types::Webhook>> {
+        let params = WebhooksGetPageParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            page: Some(page).filter(|v| !v.to_string().is_empty()),
+            topic: Some(topic).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * Get Webhooks.
      *
@@ -55,19 +67,78 @@ impl Webhooks {
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * All parameters are AND filters
+     *
+     * **Parameters:**
+     *
+     * * `params: &WebhooksGetPageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_with(
+        &self,
+        params: &WebhooksGetPageParams,
+    ) -> Result<Vec<crate::types::Webhook>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(topic) = &params.topic {
+            query_args.push(("Topic".to_string(), topic.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/webhook?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `WebhooksGetPageParams` instead.
      */
+    #[deprecated(note = "use `get_all_with` instead")]
     pub async fn get_all(
         &self,
         topic: crate::types::WebhooksTopics,
     ) -> Result<Vec<crate::types::Webhook>> {
+        let params = WebhooksGetPageParams {
+            topic: Some(topic).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * Get Webhooks.
+     *
+     * This function performs a `GET` to the `/webhook` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * All parameters are AND filters
+     */
+    pub fn get_stream(
+        &self,
+        params: &WebhooksGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Webhook> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !topic.to_string().is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("Topic".to_string(), topic.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/webhook?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -107,3 +178,103 @@ impl Webhooks {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Webhooks::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WebhooksGetPageParams {
+    /// Amount of Webhooks per page to request.
+    pub limit: Option<i64>,
+    /// Unique id of the channel.
+    pub page: Option<u64>,
+    /// Topic of the webhooks requested.
+    pub topic: Option<crate::types::WebhooksTopics>,
+}
+
+impl WebhooksGetPageParams {
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn topic(mut self, topic: crate::types::WebhooksTopics) -> Self {
+        self.topic = Some(topic);
+        self
+    }
+}
+
+/// The functions of [`Webhooks`], for code that takes them as a trait, so that it can be
+/// tested with the `MockWebhooksApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait WebhooksApi: Send + Sync {
+    /// See [`Webhooks::get_page_with`].
+    async fn get_page_with(
+        &self,
+        params: &WebhooksGetPageParams,
+    ) -> Result<Vec<crate::types::Webhook>>;
+
+    /// See [`Webhooks::get_all_with`].
+    async fn get_all_with(
+        &self,
+        params: &WebhooksGetPageParams,
+    ) -> Result<Vec<crate::types::Webhook>>;
+
+    /// See [`Webhooks::get_stream`].
+    fn get_stream(
+        &self,
+        params: &WebhooksGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Webhook>;
+
+    /// See [`Webhooks::post`].
+    async fn post(
+        &self,
+        body: &crate::types::WebhooksCreateWebhookSubscriptionModel,
+    ) -> Result<crate::types::Webhook>;
+
+    /// See [`Webhooks::delete`].
+    async fn delete(&self, id: i64) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl WebhooksApi for Webhooks {
+    async fn get_page_with(
+        &self,
+        params: &WebhooksGetPageParams,
+    ) -> Result<Vec<crate::types::Webhook>> {
+        Webhooks::get_page_with(self, params).await
+    }
+
+    async fn get_all_with(
+        &self,
+        params: &WebhooksGetPageParams,
+    ) -> Result<Vec<crate::types::Webhook>> {
+        Webhooks::get_all_with(self, params).await
+    }
+
+    fn get_stream(
+        &self,
+        params: &WebhooksGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Webhook> {
+        Webhooks::get_stream(self, params, start, max_items)
+    }
+
+    async fn post(
+        &self,
+        body: &crate::types::WebhooksCreateWebhookSubscriptionModel,
+    ) -> Result<crate::types::Webhook> {
+        Webhooks::post(self, body).await
+    }
+
+    async fn delete(&self, id: i64) -> Result<()> {
+        Webhooks::delete(self, id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Access {
     pub client: Client,
@@ -333,3 +331,231 @@ impl Access {
         self.client.delete(&url, None).await
     }
 }
+
+/// The functions of [`Access`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAccessApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AccessApi: Send + Sync {
+    /// See [`Access::get_admin_oauth_scope`].
+    async fn get_admin_oauth_scope(&self) -> Result<()>;
+
+    /// See [`Access::deprecated_202001_get_storefront_token`].
+    async fn deprecated_202001_get_storefront_token(&self) -> Result<()>;
+
+    /// See [`Access::deprecated_202001_create_storefront_tokens`].
+    async fn deprecated_202001_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Access::deprecated_202001_delete_storefront_tokens_param_token`].
+    async fn deprecated_202001_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Access::deprecated_202004_get_storefront_token`].
+    async fn deprecated_202004_get_storefront_token(&self) -> Result<()>;
+
+    /// See [`Access::deprecated_202004_create_storefront_tokens`].
+    async fn deprecated_202004_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Access::deprecated_202004_delete_storefront_tokens_param_token`].
+    async fn deprecated_202004_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Access::deprecated_202007_get_storefront_token`].
+    async fn deprecated_202007_get_storefront_token(&self) -> Result<()>;
+
+    /// See [`Access::deprecated_202007_create_storefront_tokens`].
+    async fn deprecated_202007_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Access::deprecated_202007_delete_storefront_tokens_param_token`].
+    async fn deprecated_202007_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Access::get_storefront_token`].
+    async fn get_storefront_token(&self) -> Result<()>;
+
+    /// See [`Access::create_storefront_tokens`].
+    async fn create_storefront_tokens(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Access::delete_storefront_tokens_param_token`].
+    async fn delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Access::deprecated_202101_get_storefront_token`].
+    async fn deprecated_202101_get_storefront_token(&self) -> Result<()>;
+
+    /// See [`Access::deprecated_202101_create_storefront_tokens`].
+    async fn deprecated_202101_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Access::deprecated_202101_delete_storefront_tokens_param_token`].
+    async fn deprecated_202101_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Access::deprecated_unstable_get_storefront_token`].
+    async fn deprecated_unstable_get_storefront_token(&self) -> Result<()>;
+
+    /// See [`Access::deprecated_unstable_create_storefront_tokens`].
+    async fn deprecated_unstable_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Access::deprecated_unstable_delete_storefront_tokens_param_token`].
+    async fn deprecated_unstable_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl AccessApi for Access {
+    async fn get_admin_oauth_scope(&self) -> Result<()> {
+        Access::get_admin_oauth_scope(self).await
+    }
+
+    async fn deprecated_202001_get_storefront_token(&self) -> Result<()> {
+        Access::deprecated_202001_get_storefront_token(self).await
+    }
+
+    async fn deprecated_202001_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Access::deprecated_202001_create_storefront_tokens(self, body).await
+    }
+
+    async fn deprecated_202001_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()> {
+        Access::deprecated_202001_delete_storefront_tokens_param_token(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_storefront_token(&self) -> Result<()> {
+        Access::deprecated_202004_get_storefront_token(self).await
+    }
+
+    async fn deprecated_202004_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Access::deprecated_202004_create_storefront_tokens(self, body).await
+    }
+
+    async fn deprecated_202004_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()> {
+        Access::deprecated_202004_delete_storefront_tokens_param_token(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_storefront_token(&self) -> Result<()> {
+        Access::deprecated_202007_get_storefront_token(self).await
+    }
+
+    async fn deprecated_202007_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Access::deprecated_202007_create_storefront_tokens(self, body).await
+    }
+
+    async fn deprecated_202007_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()> {
+        Access::deprecated_202007_delete_storefront_tokens_param_token(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+
+    async fn get_storefront_token(&self) -> Result<()> {
+        Access::get_storefront_token(self).await
+    }
+
+    async fn create_storefront_tokens(&self, body: &serde_json::Value) -> Result<()> {
+        Access::create_storefront_tokens(self, body).await
+    }
+
+    async fn delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()> {
+        Access::delete_storefront_tokens_param_token(self, storefront_access_token_id).await
+    }
+
+    async fn deprecated_202101_get_storefront_token(&self) -> Result<()> {
+        Access::deprecated_202101_get_storefront_token(self).await
+    }
+
+    async fn deprecated_202101_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Access::deprecated_202101_create_storefront_tokens(self, body).await
+    }
+
+    async fn deprecated_202101_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()> {
+        Access::deprecated_202101_delete_storefront_tokens_param_token(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_storefront_token(&self) -> Result<()> {
+        Access::deprecated_unstable_get_storefront_token(self).await
+    }
+
+    async fn deprecated_unstable_create_storefront_tokens(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Access::deprecated_unstable_create_storefront_tokens(self, body).await
+    }
+
+    async fn deprecated_unstable_delete_storefront_tokens_param_token(
+        &self,
+        storefront_access_token_id: &str,
+    ) -> Result<()> {
+        Access::deprecated_unstable_delete_storefront_tokens_param_token(
+            self,
+            storefront_access_token_id,
+        )
+        .await
+    }
+}
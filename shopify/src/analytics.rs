@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Analytics {
     pub client: Client,
@@ -21,40 +19,29 @@ impl Analytics {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- A comma-separated list of report IDs.
-     * * `limit: &str` -- The amount of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_min: &str` -- Show reports last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show reports last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &AnalyticsDeprecated202001GetReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_report(
+    pub async fn deprecated_202001_get_report_with(
         &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        fields: &str,
+        params: &AnalyticsDeprecated202001GetReportParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -63,6 +50,30 @@ impl Analytics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_report_with` and pass the query parameters as a `AnalyticsDeprecated202001GetReportParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_report_with` instead")]
+    pub async fn deprecated_202001_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecated202001GetReportParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_report_with(&params).await
+    }
+
     /**
      * Creates a new report.
      *
@@ -72,20 +83,18 @@ impl Analytics {
      *
      * **Parameters:**
      *
-     * * `name: &str` -- The name of the report. Maximum length: 255 characters.
-     * * `shopify_ql: &str` -- The ShopifyQL the report will query.
+     * * `params: &AnalyticsDeprecated202001CreateReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_create_reports(
+    pub async fn deprecated_202001_create_reports_with(
         &self,
-        name: &str,
-        shopify_ql: &str,
+        params: &AnalyticsDeprecated202001CreateReportsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !name.is_empty() {
+        if let Some(name) = &params.name {
             query_args.push(("name".to_string(), name.to_string()));
         }
-        if !shopify_ql.is_empty() {
+        if let Some(shopify_ql) = &params.shopify_ql {
             query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -96,6 +105,24 @@ impl Analytics {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_reports_with` and pass the query parameters as a `AnalyticsDeprecated202001CreateReportsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_create_reports_with` instead")]
+    pub async fn deprecated_202001_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecated202001CreateReportsParams {
+            name: Some(name.to_string()).filter(|s| !s.is_empty()),
+            shopify_ql: Some(shopify_ql.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_create_reports_with(&params, body)
+            .await
+    }
+
     /**
      * Retrieves a single report created by your app.
      *
@@ -106,15 +133,15 @@ impl Analytics {
      * **Parameters:**
      *
      * * `report_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &AnalyticsDeprecated202001GetReportsParamReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_reports_param_report(
+    pub async fn deprecated_202001_get_reports_param_report_with(
         &self,
         report_id: &str,
-        fields: &str,
+        params: &AnalyticsDeprecated202001GetReportsParamReportParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -127,6 +154,22 @@ impl Analytics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_reports_param_report_with` and pass the query parameters as a `AnalyticsDeprecated202001GetReportsParamReportParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_reports_param_report_with` instead")]
+    pub async fn deprecated_202001_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecated202001GetReportsParamReportParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_reports_param_report_with(report_id, &params)
+            .await
+    }
+
     /**
      * Updates a report.
      *
@@ -185,40 +228,29 @@ impl Analytics {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- A comma-separated list of report IDs.
-     * * `limit: &str` -- The amount of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_min: &str` -- Show reports last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show reports last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &AnalyticsDeprecated202004GetReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_report(
+    pub async fn deprecated_202004_get_report_with(
         &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        fields: &str,
+        params: &AnalyticsDeprecated202004GetReportParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -227,6 +259,30 @@ impl Analytics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_report_with` and pass the query parameters as a `AnalyticsDeprecated202004GetReportParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_report_with` instead")]
+    pub async fn deprecated_202004_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecated202004GetReportParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_report_with(&params).await
+    }
+
     /**
      * Creates a new report.
      *
@@ -236,20 +292,18 @@ impl Analytics {
      *
      * **Parameters:**
      *
-     * * `name: &str` -- The name of the report. Maximum length: 255 characters.
-     * * `shopify_ql: &str` -- The ShopifyQL the report will query.
+     * * `params: &AnalyticsDeprecated202004CreateReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_create_reports(
+    pub async fn deprecated_202004_create_reports_with(
         &self,
-        name: &str,
-        shopify_ql: &str,
+        params: &AnalyticsDeprecated202004CreateReportsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !name.is_empty() {
+        if let Some(name) = &params.name {
             query_args.push(("name".to_string(), name.to_string()));
         }
-        if !shopify_ql.is_empty() {
+        if let Some(shopify_ql) = &params.shopify_ql {
             query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -260,6 +314,24 @@ impl Analytics {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_reports_with` and pass the query parameters as a `AnalyticsDeprecated202004CreateReportsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_create_reports_with` instead")]
+    pub async fn deprecated_202004_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecated202004CreateReportsParams {
+            name: Some(name.to_string()).filter(|s| !s.is_empty()),
+            shopify_ql: Some(shopify_ql.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_create_reports_with(&params, body)
+            .await
+    }
+
     /**
      * Retrieves a single report created by your app.
      *
@@ -270,15 +342,15 @@ impl Analytics {
      * **Parameters:**
      *
      * * `report_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &AnalyticsDeprecated202004GetReportsParamReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_reports_param_report(
+    pub async fn deprecated_202004_get_reports_param_report_with(
         &self,
         report_id: &str,
-        fields: &str,
+        params: &AnalyticsDeprecated202004GetReportsParamReportParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -291,6 +363,22 @@ impl Analytics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_reports_param_report_with` and pass the query parameters as a `AnalyticsDeprecated202004GetReportsParamReportParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_reports_param_report_with` instead")]
+    pub async fn deprecated_202004_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecated202004GetReportsParamReportParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_reports_param_report_with(report_id, &params)
+            .await
+    }
+
     /**
      * Updates a report.
      *
@@ -349,40 +437,29 @@ impl Analytics {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- A comma-separated list of report IDs.
-     * * `limit: &str` -- The amount of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_min: &str` -- Show reports last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show reports last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &AnalyticsDeprecated202007GetReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_report(
+    pub async fn deprecated_202007_get_report_with(
         &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        fields: &str,
+        params: &AnalyticsDeprecated202007GetReportParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -391,6 +468,30 @@ impl Analytics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_report_with` and pass the query parameters as a `AnalyticsDeprecated202007GetReportParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_report_with` instead")]
+    pub async fn deprecated_202007_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecated202007GetReportParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_report_with(&params).await
+    }
+
     /**
      * Creates a new report.
      *
@@ -400,20 +501,18 @@ impl Analytics {
      *
      * **Parameters:**
      *
-     * * `name: &str` -- The name of the report. Maximum length: 255 characters.
-     * * `shopify_ql: &str` -- The ShopifyQL the report will query.
+     * * `params: &AnalyticsDeprecated202007CreateReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_create_reports(
+    pub async fn deprecated_202007_create_reports_with(
         &self,
-        name: &str,
-        shopify_ql: &str,
+        params: &AnalyticsDeprecated202007CreateReportsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !name.is_empty() {
+        if let Some(name) = &params.name {
             query_args.push(("name".to_string(), name.to_string()));
         }
-        if !shopify_ql.is_empty() {
+        if let Some(shopify_ql) = &params.shopify_ql {
             query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -424,6 +523,24 @@ impl Analytics {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_reports_with` and pass the query parameters as a `AnalyticsDeprecated202007CreateReportsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_create_reports_with` instead")]
+    pub async fn deprecated_202007_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecated202007CreateReportsParams {
+            name: Some(name.to_string()).filter(|s| !s.is_empty()),
+            shopify_ql: Some(shopify_ql.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_create_reports_with(&params, body)
+            .await
+    }
+
     /**
      * Retrieves a single report created by your app.
      *
@@ -434,15 +551,15 @@ impl Analytics {
      * **Parameters:**
      *
      * * `report_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &AnalyticsDeprecated202007GetReportsParamReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_reports_param_report(
+    pub async fn deprecated_202007_get_reports_param_report_with(
         &self,
         report_id: &str,
-        fields: &str,
+        params: &AnalyticsDeprecated202007GetReportsParamReportParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -455,6 +572,22 @@ impl Analytics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_reports_param_report_with` and pass the query parameters as a `AnalyticsDeprecated202007GetReportsParamReportParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_reports_param_report_with` instead")]
+    pub async fn deprecated_202007_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecated202007GetReportsParamReportParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_reports_param_report_with(report_id, &params)
+            .await
+    }
+
     /**
      * Updates a report.
      *
@@ -513,40 +646,26 @@ impl Analytics {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- A comma-separated list of report IDs.
-     * * `limit: &str` -- The amount of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_min: &str` -- Show reports last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show reports last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &AnalyticsGetReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_report(
-        &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        fields: &str,
-    ) -> Result<()> {
+    pub async fn get_report_with(&self, params: &AnalyticsGetReportParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -555,6 +674,30 @@ impl Analytics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_report_with` and pass the query parameters as a `AnalyticsGetReportParams` instead.
+     */
+    #[deprecated(note = "use `get_report_with` instead")]
+    pub async fn get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = AnalyticsGetReportParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_report_with(&params).await
+    }
+
     /**
      * Creates a new report.
      *
@@ -564,20 +707,18 @@ impl Analytics {
      *
      * **Parameters:**
      *
-     * * `name: &str` -- The name of the report. Maximum length: 255 characters.
-     * * `shopify_ql: &str` -- The ShopifyQL the report will query.
+     * * `params: &AnalyticsCreateReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create_reports(
+    pub async fn create_reports_with(
         &self,
-        name: &str,
-        shopify_ql: &str,
+        params: &AnalyticsCreateReportsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !name.is_empty() {
+        if let Some(name) = &params.name {
             query_args.push(("name".to_string(), name.to_string()));
         }
-        if !shopify_ql.is_empty() {
+        if let Some(shopify_ql) = &params.shopify_ql {
             query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -588,6 +729,23 @@ impl Analytics {
             .await
     }
 
+    /**
+     * Deprecated: use `create_reports_with` and pass the query parameters as a `AnalyticsCreateReportsParams` instead.
+     */
+    #[deprecated(note = "use `create_reports_with` instead")]
+    pub async fn create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = AnalyticsCreateReportsParams {
+            name: Some(name.to_string()).filter(|s| !s.is_empty()),
+            shopify_ql: Some(shopify_ql.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_reports_with(&params, body).await
+    }
+
     /**
      * Retrieves a single report created by your app.
      *
@@ -598,11 +756,15 @@ impl Analytics {
      * **Parameters:**
      *
      * * `report_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &AnalyticsGetReportsParamReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_reports_param_report(&self, report_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_reports_param_report_with(
+        &self,
+        report_id: &str,
+        params: &AnalyticsGetReportsParamReportParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -615,6 +777,17 @@ impl Analytics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_reports_param_report_with` and pass the query parameters as a `AnalyticsGetReportsParamReportParams` instead.
+     */
+    #[deprecated(note = "use `get_reports_param_report_with` instead")]
+    pub async fn get_reports_param_report(&self, report_id: &str, fields: &str) -> Result<()> {
+        let params = AnalyticsGetReportsParamReportParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_reports_param_report_with(report_id, &params).await
+    }
+
     /**
      * Updates a report.
      *
@@ -670,40 +843,29 @@ impl Analytics {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- A comma-separated list of report IDs.
-     * * `limit: &str` -- The amount of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_min: &str` -- Show reports last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show reports last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &AnalyticsDeprecated202101GetReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_report(
+    pub async fn deprecated_202101_get_report_with(
         &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        fields: &str,
+        params: &AnalyticsDeprecated202101GetReportParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -712,6 +874,30 @@ impl Analytics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_report_with` and pass the query parameters as a `AnalyticsDeprecated202101GetReportParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_report_with` instead")]
+    pub async fn deprecated_202101_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecated202101GetReportParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_report_with(&params).await
+    }
+
     /**
      * Creates a new report.
      *
@@ -721,20 +907,18 @@ impl Analytics {
      *
      * **Parameters:**
      *
-     * * `name: &str` -- The name of the report. Maximum length: 255 characters.
-     * * `shopify_ql: &str` -- The ShopifyQL the report will query.
+     * * `params: &AnalyticsDeprecated202101CreateReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_create_reports(
+    pub async fn deprecated_202101_create_reports_with(
         &self,
-        name: &str,
-        shopify_ql: &str,
+        params: &AnalyticsDeprecated202101CreateReportsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !name.is_empty() {
+        if let Some(name) = &params.name {
             query_args.push(("name".to_string(), name.to_string()));
         }
-        if !shopify_ql.is_empty() {
+        if let Some(shopify_ql) = &params.shopify_ql {
             query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -745,6 +929,24 @@ impl Analytics {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_reports_with` and pass the query parameters as a `AnalyticsDeprecated202101CreateReportsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_create_reports_with` instead")]
+    pub async fn deprecated_202101_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecated202101CreateReportsParams {
+            name: Some(name.to_string()).filter(|s| !s.is_empty()),
+            shopify_ql: Some(shopify_ql.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_create_reports_with(&params, body)
+            .await
+    }
+
     /**
      * Retrieves a single report created by your app.
      *
@@ -755,15 +957,15 @@ impl Analytics {
      * **Parameters:**
      *
      * * `report_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &AnalyticsDeprecated202101GetReportsParamReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_reports_param_report(
+    pub async fn deprecated_202101_get_reports_param_report_with(
         &self,
         report_id: &str,
-        fields: &str,
+        params: &AnalyticsDeprecated202101GetReportsParamReportParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -776,6 +978,22 @@ impl Analytics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_reports_param_report_with` and pass the query parameters as a `AnalyticsDeprecated202101GetReportsParamReportParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_reports_param_report_with` instead")]
+    pub async fn deprecated_202101_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecated202101GetReportsParamReportParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_reports_param_report_with(report_id, &params)
+            .await
+    }
+
     /**
      * Updates a report.
      *
@@ -834,40 +1052,29 @@ impl Analytics {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- A comma-separated list of report IDs.
-     * * `limit: &str` -- The amount of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_min: &str` -- Show reports last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show reports last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &AnalyticsDeprecatedUnstableGetReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_report(
+    pub async fn deprecated_unstable_get_report_with(
         &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        fields: &str,
+        params: &AnalyticsDeprecatedUnstableGetReportParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -876,6 +1083,30 @@ impl Analytics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_report_with` and pass the query parameters as a `AnalyticsDeprecatedUnstableGetReportParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_report_with` instead")]
+    pub async fn deprecated_unstable_get_report(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecatedUnstableGetReportParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_report_with(&params).await
+    }
+
     /**
      * Creates a new report.
      *
@@ -885,20 +1116,18 @@ impl Analytics {
      *
      * **Parameters:**
      *
-     * * `name: &str` -- The name of the report. Maximum length: 255 characters.
-     * * `shopify_ql: &str` -- The ShopifyQL the report will query.
+     * * `params: &AnalyticsDeprecatedUnstableCreateReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_create_reports(
+    pub async fn deprecated_unstable_create_reports_with(
         &self,
-        name: &str,
-        shopify_ql: &str,
+        params: &AnalyticsDeprecatedUnstableCreateReportsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !name.is_empty() {
+        if let Some(name) = &params.name {
             query_args.push(("name".to_string(), name.to_string()));
         }
-        if !shopify_ql.is_empty() {
+        if let Some(shopify_ql) = &params.shopify_ql {
             query_args.push(("shopify_ql".to_string(), shopify_ql.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -910,24 +1139,42 @@ impl Analytics {
     }
 
     /**
-     * Retrieves a single report created by your app.
-     *
-     * This function performs a `GET` to the `/admin/api/unstable/reports/{report_id}.json` endpoint.
-     *
+     * Deprecated: use `deprecated_unstable_create_reports_with` and pass the query parameters as a `AnalyticsDeprecatedUnstableCreateReportsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_create_reports_with` instead")]
+    pub async fn deprecated_unstable_create_reports(
+        &self,
+        name: &str,
+        shopify_ql: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecatedUnstableCreateReportsParams {
+            name: Some(name.to_string()).filter(|s| !s.is_empty()),
+            shopify_ql: Some(shopify_ql.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_create_reports_with(&params, body)
+            .await
+    }
+
+    /**
+     * Retrieves a single report created by your app.
+     *
+     * This function performs a `GET` to the `/admin/api/unstable/reports/{report_id}.json` endpoint.
+     *
      * https://shopify.dev/docs/admin-api/rest/reference/analytics/report#show-unstable
      *
      * **Parameters:**
      *
      * * `report_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &AnalyticsDeprecatedUnstableGetReportsParamReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_reports_param_report(
+    pub async fn deprecated_unstable_get_reports_param_report_with(
         &self,
         report_id: &str,
-        fields: &str,
+        params: &AnalyticsDeprecatedUnstableGetReportsParamReportParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -940,6 +1187,22 @@ impl Analytics {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_reports_param_report_with` and pass the query parameters as a `AnalyticsDeprecatedUnstableGetReportsParamReportParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_reports_param_report_with` instead")]
+    pub async fn deprecated_unstable_get_reports_param_report(
+        &self,
+        report_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = AnalyticsDeprecatedUnstableGetReportsParamReportParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_reports_param_report_with(report_id, &params)
+            .await
+    }
+
     /**
      * Updates a report.
      *
@@ -989,3 +1252,906 @@ impl Analytics {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Analytics::deprecated_202001_get_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecated202001GetReportParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// A comma-separated list of report IDs.
+    pub ids: Option<String>,
+    /// The amount of results to return.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show reports last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show reports last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl AnalyticsDeprecated202001GetReportParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_202001_create_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecated202001CreateReportsParams {
+    /// The name of the report. Maximum length: 255 characters.
+    pub name: Option<String>,
+    /// The ShopifyQL the report will query.
+    pub shopify_ql: Option<String>,
+}
+
+impl AnalyticsDeprecated202001CreateReportsParams {
+    pub fn name<T: ToString>(mut self, name: T) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn shopify_ql<T: ToString>(mut self, shopify_ql: T) -> Self {
+        self.shopify_ql = Some(shopify_ql.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_202001_get_reports_param_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecated202001GetReportsParamReportParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl AnalyticsDeprecated202001GetReportsParamReportParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_202004_get_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecated202004GetReportParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// A comma-separated list of report IDs.
+    pub ids: Option<String>,
+    /// The amount of results to return.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show reports last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show reports last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl AnalyticsDeprecated202004GetReportParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_202004_create_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecated202004CreateReportsParams {
+    /// The name of the report. Maximum length: 255 characters.
+    pub name: Option<String>,
+    /// The ShopifyQL the report will query.
+    pub shopify_ql: Option<String>,
+}
+
+impl AnalyticsDeprecated202004CreateReportsParams {
+    pub fn name<T: ToString>(mut self, name: T) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn shopify_ql<T: ToString>(mut self, shopify_ql: T) -> Self {
+        self.shopify_ql = Some(shopify_ql.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_202004_get_reports_param_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecated202004GetReportsParamReportParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl AnalyticsDeprecated202004GetReportsParamReportParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_202007_get_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecated202007GetReportParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// A comma-separated list of report IDs.
+    pub ids: Option<String>,
+    /// The amount of results to return.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show reports last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show reports last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl AnalyticsDeprecated202007GetReportParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_202007_create_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecated202007CreateReportsParams {
+    /// The name of the report. Maximum length: 255 characters.
+    pub name: Option<String>,
+    /// The ShopifyQL the report will query.
+    pub shopify_ql: Option<String>,
+}
+
+impl AnalyticsDeprecated202007CreateReportsParams {
+    pub fn name<T: ToString>(mut self, name: T) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn shopify_ql<T: ToString>(mut self, shopify_ql: T) -> Self {
+        self.shopify_ql = Some(shopify_ql.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_202007_get_reports_param_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecated202007GetReportsParamReportParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl AnalyticsDeprecated202007GetReportsParamReportParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::get_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsGetReportParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// A comma-separated list of report IDs.
+    pub ids: Option<String>,
+    /// The amount of results to return.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show reports last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show reports last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl AnalyticsGetReportParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::create_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsCreateReportsParams {
+    /// The name of the report. Maximum length: 255 characters.
+    pub name: Option<String>,
+    /// The ShopifyQL the report will query.
+    pub shopify_ql: Option<String>,
+}
+
+impl AnalyticsCreateReportsParams {
+    pub fn name<T: ToString>(mut self, name: T) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn shopify_ql<T: ToString>(mut self, shopify_ql: T) -> Self {
+        self.shopify_ql = Some(shopify_ql.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::get_reports_param_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsGetReportsParamReportParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl AnalyticsGetReportsParamReportParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_202101_get_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecated202101GetReportParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// A comma-separated list of report IDs.
+    pub ids: Option<String>,
+    /// The amount of results to return.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show reports last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show reports last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl AnalyticsDeprecated202101GetReportParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_202101_create_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecated202101CreateReportsParams {
+    /// The name of the report. Maximum length: 255 characters.
+    pub name: Option<String>,
+    /// The ShopifyQL the report will query.
+    pub shopify_ql: Option<String>,
+}
+
+impl AnalyticsDeprecated202101CreateReportsParams {
+    pub fn name<T: ToString>(mut self, name: T) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn shopify_ql<T: ToString>(mut self, shopify_ql: T) -> Self {
+        self.shopify_ql = Some(shopify_ql.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_202101_get_reports_param_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecated202101GetReportsParamReportParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl AnalyticsDeprecated202101GetReportsParamReportParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_unstable_get_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecatedUnstableGetReportParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// A comma-separated list of report IDs.
+    pub ids: Option<String>,
+    /// The amount of results to return.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show reports last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show reports last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl AnalyticsDeprecatedUnstableGetReportParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_unstable_create_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecatedUnstableCreateReportsParams {
+    /// The name of the report. Maximum length: 255 characters.
+    pub name: Option<String>,
+    /// The ShopifyQL the report will query.
+    pub shopify_ql: Option<String>,
+}
+
+impl AnalyticsDeprecatedUnstableCreateReportsParams {
+    pub fn name<T: ToString>(mut self, name: T) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn shopify_ql<T: ToString>(mut self, shopify_ql: T) -> Self {
+        self.shopify_ql = Some(shopify_ql.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Analytics::deprecated_unstable_get_reports_param_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AnalyticsDeprecatedUnstableGetReportsParamReportParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl AnalyticsDeprecatedUnstableGetReportsParamReportParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The functions of [`Analytics`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAnalyticsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AnalyticsApi: Send + Sync {
+    /// See [`Analytics::deprecated_202001_get_report_with`].
+    async fn deprecated_202001_get_report_with(
+        &self,
+        params: &AnalyticsDeprecated202001GetReportParams,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202001_create_reports_with`].
+    async fn deprecated_202001_create_reports_with(
+        &self,
+        params: &AnalyticsDeprecated202001CreateReportsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202001_get_reports_param_report_with`].
+    async fn deprecated_202001_get_reports_param_report_with(
+        &self,
+        report_id: &str,
+        params: &AnalyticsDeprecated202001GetReportsParamReportParams,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202001_update_reports_param_report`].
+    async fn deprecated_202001_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202001_delete_reports_param_report`].
+    async fn deprecated_202001_delete_reports_param_report(&self, report_id: &str) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202004_get_report_with`].
+    async fn deprecated_202004_get_report_with(
+        &self,
+        params: &AnalyticsDeprecated202004GetReportParams,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202004_create_reports_with`].
+    async fn deprecated_202004_create_reports_with(
+        &self,
+        params: &AnalyticsDeprecated202004CreateReportsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202004_get_reports_param_report_with`].
+    async fn deprecated_202004_get_reports_param_report_with(
+        &self,
+        report_id: &str,
+        params: &AnalyticsDeprecated202004GetReportsParamReportParams,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202004_update_reports_param_report`].
+    async fn deprecated_202004_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202004_delete_reports_param_report`].
+    async fn deprecated_202004_delete_reports_param_report(&self, report_id: &str) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202007_get_report_with`].
+    async fn deprecated_202007_get_report_with(
+        &self,
+        params: &AnalyticsDeprecated202007GetReportParams,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202007_create_reports_with`].
+    async fn deprecated_202007_create_reports_with(
+        &self,
+        params: &AnalyticsDeprecated202007CreateReportsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202007_get_reports_param_report_with`].
+    async fn deprecated_202007_get_reports_param_report_with(
+        &self,
+        report_id: &str,
+        params: &AnalyticsDeprecated202007GetReportsParamReportParams,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202007_update_reports_param_report`].
+    async fn deprecated_202007_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202007_delete_reports_param_report`].
+    async fn deprecated_202007_delete_reports_param_report(&self, report_id: &str) -> Result<()>;
+
+    /// See [`Analytics::get_report_with`].
+    async fn get_report_with(&self, params: &AnalyticsGetReportParams) -> Result<()>;
+
+    /// See [`Analytics::create_reports_with`].
+    async fn create_reports_with(
+        &self,
+        params: &AnalyticsCreateReportsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Analytics::get_reports_param_report_with`].
+    async fn get_reports_param_report_with(
+        &self,
+        report_id: &str,
+        params: &AnalyticsGetReportsParamReportParams,
+    ) -> Result<()>;
+
+    /// See [`Analytics::update_reports_param_report`].
+    async fn update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Analytics::delete_reports_param_report`].
+    async fn delete_reports_param_report(&self, report_id: &str) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202101_get_report_with`].
+    async fn deprecated_202101_get_report_with(
+        &self,
+        params: &AnalyticsDeprecated202101GetReportParams,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202101_create_reports_with`].
+    async fn deprecated_202101_create_reports_with(
+        &self,
+        params: &AnalyticsDeprecated202101CreateReportsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202101_get_reports_param_report_with`].
+    async fn deprecated_202101_get_reports_param_report_with(
+        &self,
+        report_id: &str,
+        params: &AnalyticsDeprecated202101GetReportsParamReportParams,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202101_update_reports_param_report`].
+    async fn deprecated_202101_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_202101_delete_reports_param_report`].
+    async fn deprecated_202101_delete_reports_param_report(&self, report_id: &str) -> Result<()>;
+
+    /// See [`Analytics::deprecated_unstable_get_report_with`].
+    async fn deprecated_unstable_get_report_with(
+        &self,
+        params: &AnalyticsDeprecatedUnstableGetReportParams,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_unstable_create_reports_with`].
+    async fn deprecated_unstable_create_reports_with(
+        &self,
+        params: &AnalyticsDeprecatedUnstableCreateReportsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_unstable_get_reports_param_report_with`].
+    async fn deprecated_unstable_get_reports_param_report_with(
+        &self,
+        report_id: &str,
+        params: &AnalyticsDeprecatedUnstableGetReportsParamReportParams,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_unstable_update_reports_param_report`].
+    async fn deprecated_unstable_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Analytics::deprecated_unstable_delete_reports_param_report`].
+    async fn deprecated_unstable_delete_reports_param_report(&self, report_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl AnalyticsApi for Analytics {
+    async fn deprecated_202001_get_report_with(
+        &self,
+        params: &AnalyticsDeprecated202001GetReportParams,
+    ) -> Result<()> {
+        Analytics::deprecated_202001_get_report_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_reports_with(
+        &self,
+        params: &AnalyticsDeprecated202001CreateReportsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202001_create_reports_with(self, params, body).await
+    }
+
+    async fn deprecated_202001_get_reports_param_report_with(
+        &self,
+        report_id: &str,
+        params: &AnalyticsDeprecated202001GetReportsParamReportParams,
+    ) -> Result<()> {
+        Analytics::deprecated_202001_get_reports_param_report_with(self, report_id, params).await
+    }
+
+    async fn deprecated_202001_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202001_update_reports_param_report(self, report_id, body).await
+    }
+
+    async fn deprecated_202001_delete_reports_param_report(&self, report_id: &str) -> Result<()> {
+        Analytics::deprecated_202001_delete_reports_param_report(self, report_id).await
+    }
+
+    async fn deprecated_202004_get_report_with(
+        &self,
+        params: &AnalyticsDeprecated202004GetReportParams,
+    ) -> Result<()> {
+        Analytics::deprecated_202004_get_report_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_reports_with(
+        &self,
+        params: &AnalyticsDeprecated202004CreateReportsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202004_create_reports_with(self, params, body).await
+    }
+
+    async fn deprecated_202004_get_reports_param_report_with(
+        &self,
+        report_id: &str,
+        params: &AnalyticsDeprecated202004GetReportsParamReportParams,
+    ) -> Result<()> {
+        Analytics::deprecated_202004_get_reports_param_report_with(self, report_id, params).await
+    }
+
+    async fn deprecated_202004_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202004_update_reports_param_report(self, report_id, body).await
+    }
+
+    async fn deprecated_202004_delete_reports_param_report(&self, report_id: &str) -> Result<()> {
+        Analytics::deprecated_202004_delete_reports_param_report(self, report_id).await
+    }
+
+    async fn deprecated_202007_get_report_with(
+        &self,
+        params: &AnalyticsDeprecated202007GetReportParams,
+    ) -> Result<()> {
+        Analytics::deprecated_202007_get_report_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_reports_with(
+        &self,
+        params: &AnalyticsDeprecated202007CreateReportsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202007_create_reports_with(self, params, body).await
+    }
+
+    async fn deprecated_202007_get_reports_param_report_with(
+        &self,
+        report_id: &str,
+        params: &AnalyticsDeprecated202007GetReportsParamReportParams,
+    ) -> Result<()> {
+        Analytics::deprecated_202007_get_reports_param_report_with(self, report_id, params).await
+    }
+
+    async fn deprecated_202007_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202007_update_reports_param_report(self, report_id, body).await
+    }
+
+    async fn deprecated_202007_delete_reports_param_report(&self, report_id: &str) -> Result<()> {
+        Analytics::deprecated_202007_delete_reports_param_report(self, report_id).await
+    }
+
+    async fn get_report_with(&self, params: &AnalyticsGetReportParams) -> Result<()> {
+        Analytics::get_report_with(self, params).await
+    }
+
+    async fn create_reports_with(
+        &self,
+        params: &AnalyticsCreateReportsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::create_reports_with(self, params, body).await
+    }
+
+    async fn get_reports_param_report_with(
+        &self,
+        report_id: &str,
+        params: &AnalyticsGetReportsParamReportParams,
+    ) -> Result<()> {
+        Analytics::get_reports_param_report_with(self, report_id, params).await
+    }
+
+    async fn update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::update_reports_param_report(self, report_id, body).await
+    }
+
+    async fn delete_reports_param_report(&self, report_id: &str) -> Result<()> {
+        Analytics::delete_reports_param_report(self, report_id).await
+    }
+
+    async fn deprecated_202101_get_report_with(
+        &self,
+        params: &AnalyticsDeprecated202101GetReportParams,
+    ) -> Result<()> {
+        Analytics::deprecated_202101_get_report_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_reports_with(
+        &self,
+        params: &AnalyticsDeprecated202101CreateReportsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202101_create_reports_with(self, params, body).await
+    }
+
+    async fn deprecated_202101_get_reports_param_report_with(
+        &self,
+        report_id: &str,
+        params: &AnalyticsDeprecated202101GetReportsParamReportParams,
+    ) -> Result<()> {
+        Analytics::deprecated_202101_get_reports_param_report_with(self, report_id, params).await
+    }
+
+    async fn deprecated_202101_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_202101_update_reports_param_report(self, report_id, body).await
+    }
+
+    async fn deprecated_202101_delete_reports_param_report(&self, report_id: &str) -> Result<()> {
+        Analytics::deprecated_202101_delete_reports_param_report(self, report_id).await
+    }
+
+    async fn deprecated_unstable_get_report_with(
+        &self,
+        params: &AnalyticsDeprecatedUnstableGetReportParams,
+    ) -> Result<()> {
+        Analytics::deprecated_unstable_get_report_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_reports_with(
+        &self,
+        params: &AnalyticsDeprecatedUnstableCreateReportsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_unstable_create_reports_with(self, params, body).await
+    }
+
+    async fn deprecated_unstable_get_reports_param_report_with(
+        &self,
+        report_id: &str,
+        params: &AnalyticsDeprecatedUnstableGetReportsParamReportParams,
+    ) -> Result<()> {
+        Analytics::deprecated_unstable_get_reports_param_report_with(self, report_id, params).await
+    }
+
+    async fn deprecated_unstable_update_reports_param_report(
+        &self,
+        report_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Analytics::deprecated_unstable_update_reports_param_report(self, report_id, body).await
+    }
+
+    async fn deprecated_unstable_delete_reports_param_report(&self, report_id: &str) -> Result<()> {
+        Analytics::deprecated_unstable_delete_reports_param_report(self, report_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Billing {
     pub client: Client,
@@ -21,19 +19,17 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202001GetApplicationChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_application_charge(
+    pub async fn deprecated_202001_get_application_charge_with(
         &self,
-        since_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202001GetApplicationChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -42,6 +38,23 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_application_charge_with` and pass the query parameters as a `BillingDeprecated202001GetApplicationChargeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_application_charge_with` instead")]
+    pub async fn deprecated_202001_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202001GetApplicationChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_application_charge_with(&params)
+            .await
+    }
+
     /**
      * Creates an application charge.
      *
@@ -69,15 +82,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `application_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202001GetApplicationChargesParamChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_application_charges_param_charge(
+    pub async fn deprecated_202001_get_application_charges_param_charge_with(
         &self,
         application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202001GetApplicationChargesParamChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -90,6 +103,27 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_application_charges_param_charge_with` and pass the query parameters as a `BillingDeprecated202001GetApplicationChargesParamChargeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_application_charges_param_charge_with` instead"
+    )]
+    pub async fn deprecated_202001_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202001GetApplicationChargesParamChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_application_charges_param_charge_with(
+            application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
     * Caution
       This endpoint is no longer required and is deprecated as of
@@ -130,19 +164,17 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202004GetApplicationChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_application_charge(
+    pub async fn deprecated_202004_get_application_charge_with(
         &self,
-        since_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202004GetApplicationChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -151,6 +183,23 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_application_charge_with` and pass the query parameters as a `BillingDeprecated202004GetApplicationChargeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_application_charge_with` instead")]
+    pub async fn deprecated_202004_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202004GetApplicationChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_application_charge_with(&params)
+            .await
+    }
+
     /**
      * Creates an application charge.
      *
@@ -178,15 +227,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `application_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202004GetApplicationChargesParamChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_application_charges_param_charge(
+    pub async fn deprecated_202004_get_application_charges_param_charge_with(
         &self,
         application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202004GetApplicationChargesParamChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -199,6 +248,27 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_application_charges_param_charge_with` and pass the query parameters as a `BillingDeprecated202004GetApplicationChargesParamChargeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_application_charges_param_charge_with` instead"
+    )]
+    pub async fn deprecated_202004_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202004GetApplicationChargesParamChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_application_charges_param_charge_with(
+            application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
     * Caution
       This endpoint is no longer required and is deprecated as of
@@ -239,19 +309,17 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202007GetApplicationChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_application_charge(
+    pub async fn deprecated_202007_get_application_charge_with(
         &self,
-        since_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202007GetApplicationChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -260,6 +328,23 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_application_charge_with` and pass the query parameters as a `BillingDeprecated202007GetApplicationChargeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_application_charge_with` instead")]
+    pub async fn deprecated_202007_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202007GetApplicationChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_application_charge_with(&params)
+            .await
+    }
+
     /**
      * Creates an application charge.
      *
@@ -287,15 +372,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `application_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202007GetApplicationChargesParamChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_application_charges_param_charge(
+    pub async fn deprecated_202007_get_application_charges_param_charge_with(
         &self,
         application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202007GetApplicationChargesParamChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -308,6 +393,27 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_application_charges_param_charge_with` and pass the query parameters as a `BillingDeprecated202007GetApplicationChargesParamChargeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_application_charges_param_charge_with` instead"
+    )]
+    pub async fn deprecated_202007_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202007GetApplicationChargesParamChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_application_charges_param_charge_with(
+            application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
     * Caution
       This endpoint is no longer required and is deprecated as of
@@ -348,15 +454,17 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingGetApplicationChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_application_charge(&self, since_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_application_charge_with(
+        &self,
+        params: &BillingGetApplicationChargeParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -365,6 +473,18 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_application_charge_with` and pass the query parameters as a `BillingGetApplicationChargeParams` instead.
+     */
+    #[deprecated(note = "use `get_application_charge_with` instead")]
+    pub async fn get_application_charge(&self, since_id: &str, fields: &str) -> Result<()> {
+        let params = BillingGetApplicationChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_application_charge_with(&params).await
+    }
+
     /**
      * Creates an application charge.
      *
@@ -389,15 +509,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `application_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingGetApplicationChargesParamChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_application_charges_param_charge(
+    pub async fn get_application_charges_param_charge_with(
         &self,
         application_charge_id: &str,
-        fields: &str,
+        params: &BillingGetApplicationChargesParamChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -410,6 +530,22 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_application_charges_param_charge_with` and pass the query parameters as a `BillingGetApplicationChargesParamChargeParams` instead.
+     */
+    #[deprecated(note = "use `get_application_charges_param_charge_with` instead")]
+    pub async fn get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingGetApplicationChargesParamChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_application_charges_param_charge_with(application_charge_id, &params)
+            .await
+    }
+
     /**
     * Caution
       This endpoint is no longer required and is deprecated as of
@@ -450,19 +586,17 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202101GetApplicationChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_application_charge(
+    pub async fn deprecated_202101_get_application_charge_with(
         &self,
-        since_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202101GetApplicationChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -471,6 +605,23 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_application_charge_with` and pass the query parameters as a `BillingDeprecated202101GetApplicationChargeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_application_charge_with` instead")]
+    pub async fn deprecated_202101_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202101GetApplicationChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_application_charge_with(&params)
+            .await
+    }
+
     /**
      * Creates an application charge.
      *
@@ -498,15 +649,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `application_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202101GetApplicationChargesParamChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_application_charges_param_charge(
+    pub async fn deprecated_202101_get_application_charges_param_charge_with(
         &self,
         application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202101GetApplicationChargesParamChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -519,6 +670,27 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_application_charges_param_charge_with` and pass the query parameters as a `BillingDeprecated202101GetApplicationChargesParamChargeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_application_charges_param_charge_with` instead"
+    )]
+    pub async fn deprecated_202101_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202101GetApplicationChargesParamChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_application_charges_param_charge_with(
+            application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of application charges.
      *
@@ -528,19 +700,17 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecatedUnstableGetApplicationChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_application_charge(
+    pub async fn deprecated_unstable_get_application_charge_with(
         &self,
-        since_id: &str,
-        fields: &str,
+        params: &BillingDeprecatedUnstableGetApplicationChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -549,6 +719,23 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_application_charge_with` and pass the query parameters as a `BillingDeprecatedUnstableGetApplicationChargeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_application_charge_with` instead")]
+    pub async fn deprecated_unstable_get_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecatedUnstableGetApplicationChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_application_charge_with(&params)
+            .await
+    }
+
     /**
      * Creates an application charge.
      *
@@ -576,15 +763,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `application_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecatedUnstableGetApplicationChargesParamChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_application_charges_param_charge(
+    pub async fn deprecated_unstable_get_application_charges_param_charge_with(
         &self,
         application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecatedUnstableGetApplicationChargesParamChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -597,6 +784,27 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_application_charges_param_charge_with` and pass the query parameters as a `BillingDeprecatedUnstableGetApplicationChargesParamChargeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_application_charges_param_charge_with` instead"
+    )]
+    pub async fn deprecated_unstable_get_application_charges_param_charge(
+        &self,
+        application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecatedUnstableGetApplicationChargesParamChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_application_charges_param_charge_with(
+            application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves all application credits.
      *
@@ -606,11 +814,14 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202001GetApplicationCreditParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_application_credit(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_application_credit_with(
+        &self,
+        params: &BillingDeprecated202001GetApplicationCreditParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -619,6 +830,18 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_application_credit_with` and pass the query parameters as a `BillingDeprecated202001GetApplicationCreditParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_application_credit_with` instead")]
+    pub async fn deprecated_202001_get_application_credit(&self, fields: &str) -> Result<()> {
+        let params = BillingDeprecated202001GetApplicationCreditParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_application_credit_with(&params)
+            .await
+    }
+
     /**
      * Creates an application credit.
      *
@@ -646,15 +869,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `application_credit_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202001GetApplicationCreditsParamCreditParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_application_credits_param_credit(
+    pub async fn deprecated_202001_get_application_credits_param_credit_with(
         &self,
         application_credit_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202001GetApplicationCreditsParamCreditParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -667,6 +890,27 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_application_credits_param_credit_with` and pass the query parameters as a `BillingDeprecated202001GetApplicationCreditsParamCreditParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_application_credits_param_credit_with` instead"
+    )]
+    pub async fn deprecated_202001_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202001GetApplicationCreditsParamCreditParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_application_credits_param_credit_with(
+            application_credit_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves all application credits.
      *
@@ -676,11 +920,14 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202004GetApplicationCreditParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_application_credit(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_application_credit_with(
+        &self,
+        params: &BillingDeprecated202004GetApplicationCreditParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -689,6 +936,18 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_application_credit_with` and pass the query parameters as a `BillingDeprecated202004GetApplicationCreditParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_application_credit_with` instead")]
+    pub async fn deprecated_202004_get_application_credit(&self, fields: &str) -> Result<()> {
+        let params = BillingDeprecated202004GetApplicationCreditParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_application_credit_with(&params)
+            .await
+    }
+
     /**
      * Creates an application credit.
      *
@@ -716,15 +975,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `application_credit_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202004GetApplicationCreditsParamCreditParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_application_credits_param_credit(
+    pub async fn deprecated_202004_get_application_credits_param_credit_with(
         &self,
         application_credit_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202004GetApplicationCreditsParamCreditParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -737,6 +996,27 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_application_credits_param_credit_with` and pass the query parameters as a `BillingDeprecated202004GetApplicationCreditsParamCreditParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_application_credits_param_credit_with` instead"
+    )]
+    pub async fn deprecated_202004_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202004GetApplicationCreditsParamCreditParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_application_credits_param_credit_with(
+            application_credit_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves all application credits.
      *
@@ -746,11 +1026,14 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202007GetApplicationCreditParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_application_credit(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_application_credit_with(
+        &self,
+        params: &BillingDeprecated202007GetApplicationCreditParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -759,6 +1042,18 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_application_credit_with` and pass the query parameters as a `BillingDeprecated202007GetApplicationCreditParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_application_credit_with` instead")]
+    pub async fn deprecated_202007_get_application_credit(&self, fields: &str) -> Result<()> {
+        let params = BillingDeprecated202007GetApplicationCreditParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_application_credit_with(&params)
+            .await
+    }
+
     /**
      * Creates an application credit.
      *
@@ -786,15 +1081,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `application_credit_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202007GetApplicationCreditsParamCreditParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_application_credits_param_credit(
+    pub async fn deprecated_202007_get_application_credits_param_credit_with(
         &self,
         application_credit_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202007GetApplicationCreditsParamCreditParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -807,6 +1102,27 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_application_credits_param_credit_with` and pass the query parameters as a `BillingDeprecated202007GetApplicationCreditsParamCreditParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_application_credits_param_credit_with` instead"
+    )]
+    pub async fn deprecated_202007_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202007GetApplicationCreditsParamCreditParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_application_credits_param_credit_with(
+            application_credit_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves all application credits.
      *
@@ -816,11 +1132,14 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingGetApplicationCreditParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_application_credit(&self, fields: &str) -> Result<()> {
+    pub async fn get_application_credit_with(
+        &self,
+        params: &BillingGetApplicationCreditParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -829,6 +1148,17 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_application_credit_with` and pass the query parameters as a `BillingGetApplicationCreditParams` instead.
+     */
+    #[deprecated(note = "use `get_application_credit_with` instead")]
+    pub async fn get_application_credit(&self, fields: &str) -> Result<()> {
+        let params = BillingGetApplicationCreditParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_application_credit_with(&params).await
+    }
+
     /**
      * Creates an application credit.
      *
@@ -853,15 +1183,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `application_credit_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingGetApplicationCreditsParamCreditParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_application_credits_param_credit(
+    pub async fn get_application_credits_param_credit_with(
         &self,
         application_credit_id: &str,
-        fields: &str,
+        params: &BillingGetApplicationCreditsParamCreditParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -874,6 +1204,22 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_application_credits_param_credit_with` and pass the query parameters as a `BillingGetApplicationCreditsParamCreditParams` instead.
+     */
+    #[deprecated(note = "use `get_application_credits_param_credit_with` instead")]
+    pub async fn get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingGetApplicationCreditsParamCreditParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_application_credits_param_credit_with(application_credit_id, &params)
+            .await
+    }
+
     /**
      * Retrieves all application credits.
      *
@@ -883,11 +1229,14 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202101GetApplicationCreditParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_application_credit(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_application_credit_with(
+        &self,
+        params: &BillingDeprecated202101GetApplicationCreditParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -896,6 +1245,18 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_application_credit_with` and pass the query parameters as a `BillingDeprecated202101GetApplicationCreditParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_application_credit_with` instead")]
+    pub async fn deprecated_202101_get_application_credit(&self, fields: &str) -> Result<()> {
+        let params = BillingDeprecated202101GetApplicationCreditParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_application_credit_with(&params)
+            .await
+    }
+
     /**
      * Creates an application credit.
      *
@@ -923,15 +1284,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `application_credit_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202101GetApplicationCreditsParamCreditParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_application_credits_param_credit(
+    pub async fn deprecated_202101_get_application_credits_param_credit_with(
         &self,
         application_credit_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202101GetApplicationCreditsParamCreditParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -944,6 +1305,27 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_application_credits_param_credit_with` and pass the query parameters as a `BillingDeprecated202101GetApplicationCreditsParamCreditParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_application_credits_param_credit_with` instead"
+    )]
+    pub async fn deprecated_202101_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202101GetApplicationCreditsParamCreditParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_application_credits_param_credit_with(
+            application_credit_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves all application credits.
      *
@@ -953,11 +1335,14 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecatedUnstableGetApplicationCreditParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_application_credit(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_application_credit_with(
+        &self,
+        params: &BillingDeprecatedUnstableGetApplicationCreditParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -966,6 +1351,18 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_application_credit_with` and pass the query parameters as a `BillingDeprecatedUnstableGetApplicationCreditParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_application_credit_with` instead")]
+    pub async fn deprecated_unstable_get_application_credit(&self, fields: &str) -> Result<()> {
+        let params = BillingDeprecatedUnstableGetApplicationCreditParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_application_credit_with(&params)
+            .await
+    }
+
     /**
      * Creates an application credit.
      *
@@ -993,15 +1390,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `application_credit_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecatedUnstableGetApplicationCreditsParamCreditParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_application_credits_param_credit(
+    pub async fn deprecated_unstable_get_application_credits_param_credit_with(
         &self,
         application_credit_id: &str,
-        fields: &str,
+        params: &BillingDeprecatedUnstableGetApplicationCreditsParamCreditParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1015,27 +1412,46 @@ impl Billing {
     }
 
     /**
-     * Retrieves a list of recurring application charges.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-01/recurring_application_charges.json` endpoint.
-     *
+     * Deprecated: use `deprecated_unstable_get_application_credits_param_credit_with` and pass the query parameters as a `BillingDeprecatedUnstableGetApplicationCreditsParamCreditParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_application_credits_param_credit_with` instead"
+    )]
+    pub async fn deprecated_unstable_get_application_credits_param_credit(
+        &self,
+        application_credit_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecatedUnstableGetApplicationCreditsParamCreditParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_application_credits_param_credit_with(
+            application_credit_id,
+            &params,
+        )
+        .await
+    }
+
+    /**
+     * Retrieves a list of recurring application charges.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-01/recurring_application_charges.json` endpoint.
+     *
      * https://shopify.dev/docs/admin-api/rest/reference/billing/recurringapplicationcharge#index-2020-01
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202001GetRecurringApplicationChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_recurring_application_charge(
+    pub async fn deprecated_202001_get_recurring_application_charge_with(
         &self,
-        since_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202001GetRecurringApplicationChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1047,6 +1463,23 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_recurring_application_charge_with` and pass the query parameters as a `BillingDeprecated202001GetRecurringApplicationChargeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_recurring_application_charge_with` instead")]
+    pub async fn deprecated_202001_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202001GetRecurringApplicationChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_recurring_application_charge_with(&params)
+            .await
+    }
+
     /**
      * Creates a recurring application charge.
      *
@@ -1074,15 +1507,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202001GetRecurringApplicationChargesParamChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_recurring_application_charges_param_charge(
+    pub async fn deprecated_202001_get_recurring_application_charges_param_charge_with(
         &self,
         recurring_application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202001GetRecurringApplicationChargesParamChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1095,6 +1528,28 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_recurring_application_charges_param_charge_with` and pass the query parameters as a `BillingDeprecated202001GetRecurringApplicationChargesParamChargeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_recurring_application_charges_param_charge_with` \
+                instead"
+    )]
+    pub async fn deprecated_202001_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202001GetRecurringApplicationChargesParamChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_recurring_application_charges_param_charge_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Cancels a recurring application charge.
      *
@@ -1159,15 +1614,17 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `recurring_application_charge_capped_amount: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_update_recurring_application_charges_param_charge_customize(
+    pub async fn deprecated_202001_update_recurring_application_charges_param_charge_customize_with(
         &self,
         recurring_application_charge_id: &str,
-        recurring_application_charge_capped_amount: i64,
+        params: &BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if recurring_application_charge_capped_amount > 0 {
+        if let Some(recurring_application_charge_capped_amount) =
+            &params.recurring_application_charge_capped_amount
+        {
             query_args.push((
                 "recurring_application_charge[capped_amount]".to_string(),
                 recurring_application_charge_capped_amount.to_string(),
@@ -1183,6 +1640,31 @@ impl Billing {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_update_recurring_application_charges_param_charge_customize_with` and pass the query parameters as a `BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_update_recurring_application_charges_param_charge_customize_with` instead"
+    )]
+    pub async fn deprecated_202001_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()> {
+        let params =
+            BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeParams {
+                recurring_application_charge_capped_amount: Some(
+                    recurring_application_charge_capped_amount,
+                )
+                .filter(|v| *v > 0),
+            };
+        self.deprecated_202001_update_recurring_application_charges_param_charge_customize_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of recurring application charges.
      *
@@ -1192,19 +1674,17 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202004GetRecurringApplicationChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_recurring_application_charge(
+    pub async fn deprecated_202004_get_recurring_application_charge_with(
         &self,
-        since_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202004GetRecurringApplicationChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1216,6 +1696,23 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_recurring_application_charge_with` and pass the query parameters as a `BillingDeprecated202004GetRecurringApplicationChargeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_recurring_application_charge_with` instead")]
+    pub async fn deprecated_202004_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202004GetRecurringApplicationChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_recurring_application_charge_with(&params)
+            .await
+    }
+
     /**
      * Creates a recurring application charge.
      *
@@ -1243,15 +1740,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202004GetRecurringApplicationChargesParamChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_recurring_application_charges_param_charge(
+    pub async fn deprecated_202004_get_recurring_application_charges_param_charge_with(
         &self,
         recurring_application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202004GetRecurringApplicationChargesParamChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1264,6 +1761,28 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_recurring_application_charges_param_charge_with` and pass the query parameters as a `BillingDeprecated202004GetRecurringApplicationChargesParamChargeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_recurring_application_charges_param_charge_with` \
+                instead"
+    )]
+    pub async fn deprecated_202004_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202004GetRecurringApplicationChargesParamChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_recurring_application_charges_param_charge_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Cancels a recurring application charge.
      *
@@ -1328,15 +1847,17 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `recurring_application_charge_capped_amount: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_update_recurring_application_charges_param_charge_customize(
+    pub async fn deprecated_202004_update_recurring_application_charges_param_charge_customize_with(
         &self,
         recurring_application_charge_id: &str,
-        recurring_application_charge_capped_amount: i64,
+        params: &BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if recurring_application_charge_capped_amount > 0 {
+        if let Some(recurring_application_charge_capped_amount) =
+            &params.recurring_application_charge_capped_amount
+        {
             query_args.push((
                 "recurring_application_charge[capped_amount]".to_string(),
                 recurring_application_charge_capped_amount.to_string(),
@@ -1352,6 +1873,31 @@ impl Billing {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_update_recurring_application_charges_param_charge_customize_with` and pass the query parameters as a `BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_update_recurring_application_charges_param_charge_customize_with` instead"
+    )]
+    pub async fn deprecated_202004_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()> {
+        let params =
+            BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeParams {
+                recurring_application_charge_capped_amount: Some(
+                    recurring_application_charge_capped_amount,
+                )
+                .filter(|v| *v > 0),
+            };
+        self.deprecated_202004_update_recurring_application_charges_param_charge_customize_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of recurring application charges.
      *
@@ -1361,19 +1907,17 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202007GetRecurringApplicationChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_recurring_application_charge(
+    pub async fn deprecated_202007_get_recurring_application_charge_with(
         &self,
-        since_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202007GetRecurringApplicationChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1385,6 +1929,23 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_recurring_application_charge_with` and pass the query parameters as a `BillingDeprecated202007GetRecurringApplicationChargeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_recurring_application_charge_with` instead")]
+    pub async fn deprecated_202007_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202007GetRecurringApplicationChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_recurring_application_charge_with(&params)
+            .await
+    }
+
     /**
      * Creates a recurring application charge.
      *
@@ -1412,15 +1973,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202007GetRecurringApplicationChargesParamChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_recurring_application_charges_param_charge(
+    pub async fn deprecated_202007_get_recurring_application_charges_param_charge_with(
         &self,
         recurring_application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202007GetRecurringApplicationChargesParamChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1433,6 +1994,28 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_recurring_application_charges_param_charge_with` and pass the query parameters as a `BillingDeprecated202007GetRecurringApplicationChargesParamChargeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_recurring_application_charges_param_charge_with` \
+                instead"
+    )]
+    pub async fn deprecated_202007_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202007GetRecurringApplicationChargesParamChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_recurring_application_charges_param_charge_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Cancels a recurring application charge.
      *
@@ -1497,15 +2080,17 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `recurring_application_charge_capped_amount: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_update_recurring_application_charges_param_charge_customize(
+    pub async fn deprecated_202007_update_recurring_application_charges_param_charge_customize_with(
         &self,
         recurring_application_charge_id: &str,
-        recurring_application_charge_capped_amount: i64,
+        params: &BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if recurring_application_charge_capped_amount > 0 {
+        if let Some(recurring_application_charge_capped_amount) =
+            &params.recurring_application_charge_capped_amount
+        {
             query_args.push((
                 "recurring_application_charge[capped_amount]".to_string(),
                 recurring_application_charge_capped_amount.to_string(),
@@ -1521,6 +2106,31 @@ impl Billing {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_update_recurring_application_charges_param_charge_customize_with` and pass the query parameters as a `BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_update_recurring_application_charges_param_charge_customize_with` instead"
+    )]
+    pub async fn deprecated_202007_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()> {
+        let params =
+            BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeParams {
+                recurring_application_charge_capped_amount: Some(
+                    recurring_application_charge_capped_amount,
+                )
+                .filter(|v| *v > 0),
+            };
+        self.deprecated_202007_update_recurring_application_charges_param_charge_customize_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of recurring application charges.
      *
@@ -1530,19 +2140,17 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingGetRecurringApplicationChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_recurring_application_charge(
+    pub async fn get_recurring_application_charge_with(
         &self,
-        since_id: &str,
-        fields: &str,
+        params: &BillingGetRecurringApplicationChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1554,6 +2162,22 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_recurring_application_charge_with` and pass the query parameters as a `BillingGetRecurringApplicationChargeParams` instead.
+     */
+    #[deprecated(note = "use `get_recurring_application_charge_with` instead")]
+    pub async fn get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingGetRecurringApplicationChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_recurring_application_charge_with(&params).await
+    }
+
     /**
      * Creates a recurring application charge.
      *
@@ -1581,15 +2205,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingGetRecurringApplicationChargesParamChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_recurring_application_charges_param_charge(
+    pub async fn get_recurring_application_charges_param_charge_with(
         &self,
         recurring_application_charge_id: &str,
-        fields: &str,
+        params: &BillingGetRecurringApplicationChargesParamChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1602,6 +2226,25 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_recurring_application_charges_param_charge_with` and pass the query parameters as a `BillingGetRecurringApplicationChargesParamChargeParams` instead.
+     */
+    #[deprecated(note = "use `get_recurring_application_charges_param_charge_with` instead")]
+    pub async fn get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingGetRecurringApplicationChargesParamChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_recurring_application_charges_param_charge_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Cancels a recurring application charge.
      *
@@ -1666,15 +2309,17 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `recurring_application_charge_capped_amount: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &BillingUpdateRecurringApplicationChargesParamChargeCustomizeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update_recurring_application_charges_param_charge_customize(
+    pub async fn update_recurring_application_charges_param_charge_customize_with(
         &self,
         recurring_application_charge_id: &str,
-        recurring_application_charge_capped_amount: i64,
+        params: &BillingUpdateRecurringApplicationChargesParamChargeCustomizeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if recurring_application_charge_capped_amount > 0 {
+        if let Some(recurring_application_charge_capped_amount) =
+            &params.recurring_application_charge_capped_amount
+        {
             query_args.push((
                 "recurring_application_charge[capped_amount]".to_string(),
                 recurring_application_charge_capped_amount.to_string(),
@@ -1690,6 +2335,30 @@ impl Billing {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `update_recurring_application_charges_param_charge_customize_with` and pass the query parameters as a `BillingUpdateRecurringApplicationChargesParamChargeCustomizeParams` instead.
+     */
+    #[deprecated(
+        note = "use `update_recurring_application_charges_param_charge_customize_with` instead"
+    )]
+    pub async fn update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()> {
+        let params = BillingUpdateRecurringApplicationChargesParamChargeCustomizeParams {
+            recurring_application_charge_capped_amount: Some(
+                recurring_application_charge_capped_amount,
+            )
+            .filter(|v| *v > 0),
+        };
+        self.update_recurring_application_charges_param_charge_customize_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of recurring application charges.
      *
@@ -1699,19 +2368,17 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202101GetRecurringApplicationChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_recurring_application_charge(
+    pub async fn deprecated_202101_get_recurring_application_charge_with(
         &self,
-        since_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202101GetRecurringApplicationChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1723,6 +2390,23 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_recurring_application_charge_with` and pass the query parameters as a `BillingDeprecated202101GetRecurringApplicationChargeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_recurring_application_charge_with` instead")]
+    pub async fn deprecated_202101_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202101GetRecurringApplicationChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_recurring_application_charge_with(&params)
+            .await
+    }
+
     /**
      * Creates a recurring application charge.
      *
@@ -1750,15 +2434,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202101GetRecurringApplicationChargesParamChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_recurring_application_charges_param_charge(
+    pub async fn deprecated_202101_get_recurring_application_charges_param_charge_with(
         &self,
         recurring_application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202101GetRecurringApplicationChargesParamChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1771,6 +2455,28 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_recurring_application_charges_param_charge_with` and pass the query parameters as a `BillingDeprecated202101GetRecurringApplicationChargesParamChargeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_recurring_application_charges_param_charge_with` \
+                instead"
+    )]
+    pub async fn deprecated_202101_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202101GetRecurringApplicationChargesParamChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_recurring_application_charges_param_charge_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Cancels a recurring application charge.
      *
@@ -1804,15 +2510,17 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `recurring_application_charge_capped_amount: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_update_recurring_application_charges_param_charge_customize(
+    pub async fn deprecated_202101_update_recurring_application_charges_param_charge_customize_with(
         &self,
         recurring_application_charge_id: &str,
-        recurring_application_charge_capped_amount: i64,
+        params: &BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if recurring_application_charge_capped_amount > 0 {
+        if let Some(recurring_application_charge_capped_amount) =
+            &params.recurring_application_charge_capped_amount
+        {
             query_args.push((
                 "recurring_application_charge[capped_amount]".to_string(),
                 recurring_application_charge_capped_amount.to_string(),
@@ -1828,6 +2536,31 @@ impl Billing {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_update_recurring_application_charges_param_charge_customize_with` and pass the query parameters as a `BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_update_recurring_application_charges_param_charge_customize_with` instead"
+    )]
+    pub async fn deprecated_202101_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()> {
+        let params =
+            BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeParams {
+                recurring_application_charge_capped_amount: Some(
+                    recurring_application_charge_capped_amount,
+                )
+                .filter(|v| *v > 0),
+            };
+        self.deprecated_202101_update_recurring_application_charges_param_charge_customize_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of recurring application charges.
      *
@@ -1837,19 +2570,17 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecatedUnstableGetRecurringApplicationChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_recurring_application_charge(
+    pub async fn deprecated_unstable_get_recurring_application_charge_with(
         &self,
-        since_id: &str,
-        fields: &str,
+        params: &BillingDeprecatedUnstableGetRecurringApplicationChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1861,6 +2592,23 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_recurring_application_charge_with` and pass the query parameters as a `BillingDeprecatedUnstableGetRecurringApplicationChargeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_recurring_application_charge_with` instead")]
+    pub async fn deprecated_unstable_get_recurring_application_charge(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecatedUnstableGetRecurringApplicationChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_recurring_application_charge_with(&params)
+            .await
+    }
+
     /**
      * Creates a recurring application charge.
      *
@@ -1888,15 +2636,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_recurring_application_charges_param_charge(
+    pub async fn deprecated_unstable_get_recurring_application_charges_param_charge_with(
         &self,
         recurring_application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1909,6 +2657,28 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_recurring_application_charges_param_charge_with` and pass the query parameters as a `BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_recurring_application_charges_param_charge_with` \
+                instead"
+    )]
+    pub async fn deprecated_unstable_get_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_recurring_application_charges_param_charge_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Cancels a recurring application charge.
      *
@@ -1942,15 +2712,17 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `recurring_application_charge_capped_amount: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_update_recurring_application_charges_param_charge_customize(
+    pub async fn deprecated_unstable_update_recurring_application_charges_param_charge_customize_with(
         &self,
         recurring_application_charge_id: &str,
-        recurring_application_charge_capped_amount: i64,
+        params: &BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if recurring_application_charge_capped_amount > 0 {
+        if let Some(recurring_application_charge_capped_amount) =
+            &params.recurring_application_charge_capped_amount
+        {
             query_args.push((
                 "recurring_application_charge[capped_amount]".to_string(),
                 recurring_application_charge_capped_amount.to_string(),
@@ -1966,6 +2738,31 @@ impl Billing {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_update_recurring_application_charges_param_charge_customize_with` and pass the query parameters as a `BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_update_recurring_application_charges_param_charge_customize_with` instead"
+    )]
+    pub async fn deprecated_unstable_update_recurring_application_charges_param_charge_customize(
+        &self,
+        recurring_application_charge_id: &str,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Result<()> {
+        let params =
+            BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeParams {
+                recurring_application_charge_capped_amount: Some(
+                    recurring_application_charge_capped_amount,
+                )
+                .filter(|v| *v > 0),
+            };
+        self.deprecated_unstable_update_recurring_application_charges_param_charge_customize_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of usage charges.
      *
@@ -1976,15 +2773,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_recurring_application_charges_param_charge_usage(
+    pub async fn deprecated_202001_get_recurring_application_charges_param_charge_usage_with(
         &self,
         recurring_application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1997,6 +2794,28 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_recurring_application_charges_param_charge_usage_with` and pass the query parameters as a `BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_recurring_application_charges_param_charge_usage_with` \
+                instead"
+    )]
+    pub async fn deprecated_202001_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_recurring_application_charges_param_charge_usage_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Creates a usage charge.
      *
@@ -2034,16 +2853,16 @@ impl Billing {
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
      * * `usage_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_recurring_application_charges_param_charge_usage_billing(
+    pub async fn deprecated_202001_get_recurring_application_charges_param_charge_usage_billing_with(
         &self,
         recurring_application_charge_id: &str,
         usage_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2057,6 +2876,30 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_recurring_application_charges_param_charge_usage_billing_with` and pass the query parameters as a `BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_recurring_application_charges_param_charge_usage_billing_with` instead"
+    )]
+    pub async fn deprecated_202001_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params =
+            BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingParams {
+                fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202001_get_recurring_application_charges_param_charge_usage_billing_with(
+            recurring_application_charge_id,
+            usage_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of usage charges.
      *
@@ -2067,15 +2910,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_recurring_application_charges_param_charge_usage(
+    pub async fn deprecated_202004_get_recurring_application_charges_param_charge_usage_with(
         &self,
         recurring_application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2088,6 +2931,28 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_recurring_application_charges_param_charge_usage_with` and pass the query parameters as a `BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_recurring_application_charges_param_charge_usage_with` \
+                instead"
+    )]
+    pub async fn deprecated_202004_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_recurring_application_charges_param_charge_usage_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Creates a usage charge.
      *
@@ -2125,16 +2990,16 @@ impl Billing {
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
      * * `usage_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_recurring_application_charges_param_charge_usage_billing(
+    pub async fn deprecated_202004_get_recurring_application_charges_param_charge_usage_billing_with(
         &self,
         recurring_application_charge_id: &str,
         usage_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2148,6 +3013,30 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_recurring_application_charges_param_charge_usage_billing_with` and pass the query parameters as a `BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_recurring_application_charges_param_charge_usage_billing_with` instead"
+    )]
+    pub async fn deprecated_202004_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params =
+            BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingParams {
+                fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202004_get_recurring_application_charges_param_charge_usage_billing_with(
+            recurring_application_charge_id,
+            usage_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of usage charges.
      *
@@ -2158,15 +3047,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_recurring_application_charges_param_charge_usage(
+    pub async fn deprecated_202007_get_recurring_application_charges_param_charge_usage_with(
         &self,
         recurring_application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2179,6 +3068,28 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_recurring_application_charges_param_charge_usage_with` and pass the query parameters as a `BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_recurring_application_charges_param_charge_usage_with` \
+                instead"
+    )]
+    pub async fn deprecated_202007_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_recurring_application_charges_param_charge_usage_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Creates a usage charge.
      *
@@ -2216,16 +3127,16 @@ impl Billing {
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
      * * `usage_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_recurring_application_charges_param_charge_usage_billing(
+    pub async fn deprecated_202007_get_recurring_application_charges_param_charge_usage_billing_with(
         &self,
         recurring_application_charge_id: &str,
         usage_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2239,6 +3150,30 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_recurring_application_charges_param_charge_usage_billing_with` and pass the query parameters as a `BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_recurring_application_charges_param_charge_usage_billing_with` instead"
+    )]
+    pub async fn deprecated_202007_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params =
+            BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingParams {
+                fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202007_get_recurring_application_charges_param_charge_usage_billing_with(
+            recurring_application_charge_id,
+            usage_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of usage charges.
      *
@@ -2249,15 +3184,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingGetRecurringApplicationChargesParamChargeUsageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_recurring_application_charges_param_charge_usage(
+    pub async fn get_recurring_application_charges_param_charge_usage_with(
         &self,
         recurring_application_charge_id: &str,
-        fields: &str,
+        params: &BillingGetRecurringApplicationChargesParamChargeUsageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2270,6 +3205,25 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_recurring_application_charges_param_charge_usage_with` and pass the query parameters as a `BillingGetRecurringApplicationChargesParamChargeUsageParams` instead.
+     */
+    #[deprecated(note = "use `get_recurring_application_charges_param_charge_usage_with` instead")]
+    pub async fn get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingGetRecurringApplicationChargesParamChargeUsageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_recurring_application_charges_param_charge_usage_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Creates a usage charge.
      *
@@ -2307,16 +3261,16 @@ impl Billing {
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
      * * `usage_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingGetRecurringApplicationChargesParamChargeUsageBillingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_recurring_application_charges_param_charge_usage_billing(
+    pub async fn get_recurring_application_charges_param_charge_usage_billing_with(
         &self,
         recurring_application_charge_id: &str,
         usage_charge_id: &str,
-        fields: &str,
+        params: &BillingGetRecurringApplicationChargesParamChargeUsageBillingParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2330,6 +3284,29 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_recurring_application_charges_param_charge_usage_billing_with` and pass the query parameters as a `BillingGetRecurringApplicationChargesParamChargeUsageBillingParams` instead.
+     */
+    #[deprecated(
+        note = "use `get_recurring_application_charges_param_charge_usage_billing_with` instead"
+    )]
+    pub async fn get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingGetRecurringApplicationChargesParamChargeUsageBillingParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_recurring_application_charges_param_charge_usage_billing_with(
+            recurring_application_charge_id,
+            usage_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of usage charges.
      *
@@ -2340,15 +3317,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_recurring_application_charges_param_charge_usage(
+    pub async fn deprecated_202101_get_recurring_application_charges_param_charge_usage_with(
         &self,
         recurring_application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2361,6 +3338,28 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_recurring_application_charges_param_charge_usage_with` and pass the query parameters as a `BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_recurring_application_charges_param_charge_usage_with` \
+                instead"
+    )]
+    pub async fn deprecated_202101_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_recurring_application_charges_param_charge_usage_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Creates a usage charge.
      *
@@ -2398,16 +3397,16 @@ impl Billing {
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
      * * `usage_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_recurring_application_charges_param_charge_usage_billing(
+    pub async fn deprecated_202101_get_recurring_application_charges_param_charge_usage_billing_with(
         &self,
         recurring_application_charge_id: &str,
         usage_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2421,6 +3420,30 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_recurring_application_charges_param_charge_usage_billing_with` and pass the query parameters as a `BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_recurring_application_charges_param_charge_usage_billing_with` instead"
+    )]
+    pub async fn deprecated_202101_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params =
+            BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingParams {
+                fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202101_get_recurring_application_charges_param_charge_usage_billing_with(
+            recurring_application_charge_id,
+            usage_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of usage charges.
      *
@@ -2431,15 +3454,15 @@ impl Billing {
      * **Parameters:**
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage(
+    pub async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_with(
         &self,
         recurring_application_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2452,6 +3475,28 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_recurring_application_charges_param_charge_usage_with` and pass the query parameters as a `BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_recurring_application_charges_param_charge_usage_with` instead"
+    )]
+    pub async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params =
+            BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageParams {
+                fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_unstable_get_recurring_application_charges_param_charge_usage_with(
+            recurring_application_charge_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Creates a usage charge.
      *
@@ -2489,16 +3534,16 @@ impl Billing {
      *
      * * `recurring_application_charge_id: &str` -- recurring_application_charge_id.
      * * `usage_charge_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing(
+    pub async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing_with(
         &self,
         recurring_application_charge_id: &str,
         usage_charge_id: &str,
-        fields: &str,
+        params: &BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2511,4 +3556,2424 @@ impl Billing {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing_with` and pass the query parameters as a `BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing_with` instead"
+    )]
+    pub async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params =
+            BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingParams {
+                fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing_with(
+            recurring_application_charge_id,
+            usage_charge_id,
+            &params,
+        )
+        .await
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202001_get_application_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202001GetApplicationChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl BillingDeprecated202001GetApplicationChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202001_get_application_charges_param_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202001GetApplicationChargesParamChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202001GetApplicationChargesParamChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202004_get_application_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202004GetApplicationChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl BillingDeprecated202004GetApplicationChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202004_get_application_charges_param_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202004GetApplicationChargesParamChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202004GetApplicationChargesParamChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202007_get_application_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202007GetApplicationChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl BillingDeprecated202007GetApplicationChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202007_get_application_charges_param_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202007GetApplicationChargesParamChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202007GetApplicationChargesParamChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::get_application_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingGetApplicationChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl BillingGetApplicationChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::get_application_charges_param_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingGetApplicationChargesParamChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingGetApplicationChargesParamChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202101_get_application_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202101GetApplicationChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl BillingDeprecated202101GetApplicationChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202101_get_application_charges_param_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202101GetApplicationChargesParamChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202101GetApplicationChargesParamChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_unstable_get_application_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecatedUnstableGetApplicationChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl BillingDeprecatedUnstableGetApplicationChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_unstable_get_application_charges_param_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecatedUnstableGetApplicationChargesParamChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecatedUnstableGetApplicationChargesParamChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202001_get_application_credit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202001GetApplicationCreditParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202001GetApplicationCreditParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202001_get_application_credits_param_credit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202001GetApplicationCreditsParamCreditParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202001GetApplicationCreditsParamCreditParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202004_get_application_credit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202004GetApplicationCreditParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202004GetApplicationCreditParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202004_get_application_credits_param_credit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202004GetApplicationCreditsParamCreditParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202004GetApplicationCreditsParamCreditParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202007_get_application_credit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202007GetApplicationCreditParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202007GetApplicationCreditParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202007_get_application_credits_param_credit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202007GetApplicationCreditsParamCreditParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202007GetApplicationCreditsParamCreditParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::get_application_credit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingGetApplicationCreditParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingGetApplicationCreditParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::get_application_credits_param_credit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingGetApplicationCreditsParamCreditParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingGetApplicationCreditsParamCreditParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202101_get_application_credit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202101GetApplicationCreditParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202101GetApplicationCreditParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202101_get_application_credits_param_credit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202101GetApplicationCreditsParamCreditParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202101GetApplicationCreditsParamCreditParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_unstable_get_application_credit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecatedUnstableGetApplicationCreditParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecatedUnstableGetApplicationCreditParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_unstable_get_application_credits_param_credit_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecatedUnstableGetApplicationCreditsParamCreditParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecatedUnstableGetApplicationCreditsParamCreditParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202001_get_recurring_application_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202001GetRecurringApplicationChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl BillingDeprecated202001GetRecurringApplicationChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202001_get_recurring_application_charges_param_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202001GetRecurringApplicationChargesParamChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202001GetRecurringApplicationChargesParamChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202001_update_recurring_application_charges_param_charge_customize_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeParams {
+    /// recurring_application_charge[capped_amount].
+    pub recurring_application_charge_capped_amount: Option<i64>,
+}
+
+impl BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeParams {
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.recurring_application_charge_capped_amount =
+            Some(recurring_application_charge_capped_amount);
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202004_get_recurring_application_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202004GetRecurringApplicationChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl BillingDeprecated202004GetRecurringApplicationChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202004_get_recurring_application_charges_param_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202004GetRecurringApplicationChargesParamChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202004GetRecurringApplicationChargesParamChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202004_update_recurring_application_charges_param_charge_customize_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeParams {
+    /// recurring_application_charge[capped_amount].
+    pub recurring_application_charge_capped_amount: Option<i64>,
+}
+
+impl BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeParams {
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.recurring_application_charge_capped_amount =
+            Some(recurring_application_charge_capped_amount);
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202007_get_recurring_application_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202007GetRecurringApplicationChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl BillingDeprecated202007GetRecurringApplicationChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202007_get_recurring_application_charges_param_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202007GetRecurringApplicationChargesParamChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202007GetRecurringApplicationChargesParamChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202007_update_recurring_application_charges_param_charge_customize_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeParams {
+    /// recurring_application_charge[capped_amount].
+    pub recurring_application_charge_capped_amount: Option<i64>,
+}
+
+impl BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeParams {
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.recurring_application_charge_capped_amount =
+            Some(recurring_application_charge_capped_amount);
+        self
+    }
+}
+
+/// The query parameters of `Billing::get_recurring_application_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingGetRecurringApplicationChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl BillingGetRecurringApplicationChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::get_recurring_application_charges_param_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingGetRecurringApplicationChargesParamChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingGetRecurringApplicationChargesParamChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::update_recurring_application_charges_param_charge_customize_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingUpdateRecurringApplicationChargesParamChargeCustomizeParams {
+    /// recurring_application_charge[capped_amount].
+    pub recurring_application_charge_capped_amount: Option<i64>,
+}
+
+impl BillingUpdateRecurringApplicationChargesParamChargeCustomizeParams {
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.recurring_application_charge_capped_amount =
+            Some(recurring_application_charge_capped_amount);
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202101_get_recurring_application_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202101GetRecurringApplicationChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl BillingDeprecated202101GetRecurringApplicationChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202101_get_recurring_application_charges_param_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202101GetRecurringApplicationChargesParamChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202101GetRecurringApplicationChargesParamChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202101_update_recurring_application_charges_param_charge_customize_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeParams {
+    /// recurring_application_charge[capped_amount].
+    pub recurring_application_charge_capped_amount: Option<i64>,
+}
+
+impl BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeParams {
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.recurring_application_charge_capped_amount =
+            Some(recurring_application_charge_capped_amount);
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_unstable_get_recurring_application_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecatedUnstableGetRecurringApplicationChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl BillingDeprecatedUnstableGetRecurringApplicationChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_unstable_get_recurring_application_charges_param_charge_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_unstable_update_recurring_application_charges_param_charge_customize_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeParams {
+    /// recurring_application_charge[capped_amount].
+    pub recurring_application_charge_capped_amount: Option<i64>,
+}
+
+impl BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeParams {
+    pub fn recurring_application_charge_capped_amount(
+        mut self,
+        recurring_application_charge_capped_amount: i64,
+    ) -> Self {
+        self.recurring_application_charge_capped_amount =
+            Some(recurring_application_charge_capped_amount);
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage_billing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage_billing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage_billing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::get_recurring_application_charges_param_charge_usage_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingGetRecurringApplicationChargesParamChargeUsageParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingGetRecurringApplicationChargesParamChargeUsageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::get_recurring_application_charges_param_charge_usage_billing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingGetRecurringApplicationChargesParamChargeUsageBillingParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingGetRecurringApplicationChargesParamChargeUsageBillingParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage_billing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The functions of [`Billing`], for code that takes them as a trait, so that it can be
+/// tested with the `MockBillingApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait BillingApi: Send + Sync {
+    /// See [`Billing::deprecated_202001_get_application_charge_with`].
+    async fn deprecated_202001_get_application_charge_with(
+        &self,
+        params: &BillingDeprecated202001GetApplicationChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_create_application_charges`].
+    async fn deprecated_202001_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_get_application_charges_param_charge_with`].
+    async fn deprecated_202001_get_application_charges_param_charge_with(
+        &self,
+        application_charge_id: &str,
+        params: &BillingDeprecated202001GetApplicationChargesParamChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_create_application_charges_param_charge_activate`].
+    async fn deprecated_202001_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_get_application_charge_with`].
+    async fn deprecated_202004_get_application_charge_with(
+        &self,
+        params: &BillingDeprecated202004GetApplicationChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_create_application_charges`].
+    async fn deprecated_202004_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_get_application_charges_param_charge_with`].
+    async fn deprecated_202004_get_application_charges_param_charge_with(
+        &self,
+        application_charge_id: &str,
+        params: &BillingDeprecated202004GetApplicationChargesParamChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_create_application_charges_param_charge_activate`].
+    async fn deprecated_202004_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_get_application_charge_with`].
+    async fn deprecated_202007_get_application_charge_with(
+        &self,
+        params: &BillingDeprecated202007GetApplicationChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_create_application_charges`].
+    async fn deprecated_202007_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_get_application_charges_param_charge_with`].
+    async fn deprecated_202007_get_application_charges_param_charge_with(
+        &self,
+        application_charge_id: &str,
+        params: &BillingDeprecated202007GetApplicationChargesParamChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_create_application_charges_param_charge_activate`].
+    async fn deprecated_202007_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::get_application_charge_with`].
+    async fn get_application_charge_with(
+        &self,
+        params: &BillingGetApplicationChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::create_application_charges`].
+    async fn create_application_charges(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Billing::get_application_charges_param_charge_with`].
+    async fn get_application_charges_param_charge_with(
+        &self,
+        application_charge_id: &str,
+        params: &BillingGetApplicationChargesParamChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::create_application_charges_param_charge_activate`].
+    async fn create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_get_application_charge_with`].
+    async fn deprecated_202101_get_application_charge_with(
+        &self,
+        params: &BillingDeprecated202101GetApplicationChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_create_application_charges`].
+    async fn deprecated_202101_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_get_application_charges_param_charge_with`].
+    async fn deprecated_202101_get_application_charges_param_charge_with(
+        &self,
+        application_charge_id: &str,
+        params: &BillingDeprecated202101GetApplicationChargesParamChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_get_application_charge_with`].
+    async fn deprecated_unstable_get_application_charge_with(
+        &self,
+        params: &BillingDeprecatedUnstableGetApplicationChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_create_application_charges`].
+    async fn deprecated_unstable_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_get_application_charges_param_charge_with`].
+    async fn deprecated_unstable_get_application_charges_param_charge_with(
+        &self,
+        application_charge_id: &str,
+        params: &BillingDeprecatedUnstableGetApplicationChargesParamChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_get_application_credit_with`].
+    async fn deprecated_202001_get_application_credit_with(
+        &self,
+        params: &BillingDeprecated202001GetApplicationCreditParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_create_application_credits`].
+    async fn deprecated_202001_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_get_application_credits_param_credit_with`].
+    async fn deprecated_202001_get_application_credits_param_credit_with(
+        &self,
+        application_credit_id: &str,
+        params: &BillingDeprecated202001GetApplicationCreditsParamCreditParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_get_application_credit_with`].
+    async fn deprecated_202004_get_application_credit_with(
+        &self,
+        params: &BillingDeprecated202004GetApplicationCreditParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_create_application_credits`].
+    async fn deprecated_202004_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_get_application_credits_param_credit_with`].
+    async fn deprecated_202004_get_application_credits_param_credit_with(
+        &self,
+        application_credit_id: &str,
+        params: &BillingDeprecated202004GetApplicationCreditsParamCreditParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_get_application_credit_with`].
+    async fn deprecated_202007_get_application_credit_with(
+        &self,
+        params: &BillingDeprecated202007GetApplicationCreditParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_create_application_credits`].
+    async fn deprecated_202007_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_get_application_credits_param_credit_with`].
+    async fn deprecated_202007_get_application_credits_param_credit_with(
+        &self,
+        application_credit_id: &str,
+        params: &BillingDeprecated202007GetApplicationCreditsParamCreditParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::get_application_credit_with`].
+    async fn get_application_credit_with(
+        &self,
+        params: &BillingGetApplicationCreditParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::create_application_credits`].
+    async fn create_application_credits(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Billing::get_application_credits_param_credit_with`].
+    async fn get_application_credits_param_credit_with(
+        &self,
+        application_credit_id: &str,
+        params: &BillingGetApplicationCreditsParamCreditParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_get_application_credit_with`].
+    async fn deprecated_202101_get_application_credit_with(
+        &self,
+        params: &BillingDeprecated202101GetApplicationCreditParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_create_application_credits`].
+    async fn deprecated_202101_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_get_application_credits_param_credit_with`].
+    async fn deprecated_202101_get_application_credits_param_credit_with(
+        &self,
+        application_credit_id: &str,
+        params: &BillingDeprecated202101GetApplicationCreditsParamCreditParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_get_application_credit_with`].
+    async fn deprecated_unstable_get_application_credit_with(
+        &self,
+        params: &BillingDeprecatedUnstableGetApplicationCreditParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_create_application_credits`].
+    async fn deprecated_unstable_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_get_application_credits_param_credit_with`].
+    async fn deprecated_unstable_get_application_credits_param_credit_with(
+        &self,
+        application_credit_id: &str,
+        params: &BillingDeprecatedUnstableGetApplicationCreditsParamCreditParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_get_recurring_application_charge_with`].
+    async fn deprecated_202001_get_recurring_application_charge_with(
+        &self,
+        params: &BillingDeprecated202001GetRecurringApplicationChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_create_recurring_application_charges`].
+    async fn deprecated_202001_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_get_recurring_application_charges_param_charge_with`].
+    async fn deprecated_202001_get_recurring_application_charges_param_charge_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202001GetRecurringApplicationChargesParamChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_delete_recurring_application_charges_param_charge`].
+    async fn deprecated_202001_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_create_recurring_application_charges_param_charge_activate`].
+    async fn deprecated_202001_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_update_recurring_application_charges_param_charge_customize_with`].
+    async fn deprecated_202001_update_recurring_application_charges_param_charge_customize_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_get_recurring_application_charge_with`].
+    async fn deprecated_202004_get_recurring_application_charge_with(
+        &self,
+        params: &BillingDeprecated202004GetRecurringApplicationChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_create_recurring_application_charges`].
+    async fn deprecated_202004_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_get_recurring_application_charges_param_charge_with`].
+    async fn deprecated_202004_get_recurring_application_charges_param_charge_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202004GetRecurringApplicationChargesParamChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_delete_recurring_application_charges_param_charge`].
+    async fn deprecated_202004_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_create_recurring_application_charges_param_charge_activate`].
+    async fn deprecated_202004_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_update_recurring_application_charges_param_charge_customize_with`].
+    async fn deprecated_202004_update_recurring_application_charges_param_charge_customize_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_get_recurring_application_charge_with`].
+    async fn deprecated_202007_get_recurring_application_charge_with(
+        &self,
+        params: &BillingDeprecated202007GetRecurringApplicationChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_create_recurring_application_charges`].
+    async fn deprecated_202007_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_get_recurring_application_charges_param_charge_with`].
+    async fn deprecated_202007_get_recurring_application_charges_param_charge_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202007GetRecurringApplicationChargesParamChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_delete_recurring_application_charges_param_charge`].
+    async fn deprecated_202007_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_create_recurring_application_charges_param_charge_activate`].
+    async fn deprecated_202007_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_update_recurring_application_charges_param_charge_customize_with`].
+    async fn deprecated_202007_update_recurring_application_charges_param_charge_customize_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::get_recurring_application_charge_with`].
+    async fn get_recurring_application_charge_with(
+        &self,
+        params: &BillingGetRecurringApplicationChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::create_recurring_application_charges`].
+    async fn create_recurring_application_charges(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Billing::get_recurring_application_charges_param_charge_with`].
+    async fn get_recurring_application_charges_param_charge_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingGetRecurringApplicationChargesParamChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::delete_recurring_application_charges_param_charge`].
+    async fn delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Billing::create_recurring_application_charges_param_charge_activate`].
+    async fn create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::update_recurring_application_charges_param_charge_customize_with`].
+    async fn update_recurring_application_charges_param_charge_customize_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingUpdateRecurringApplicationChargesParamChargeCustomizeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_get_recurring_application_charge_with`].
+    async fn deprecated_202101_get_recurring_application_charge_with(
+        &self,
+        params: &BillingDeprecated202101GetRecurringApplicationChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_create_recurring_application_charges`].
+    async fn deprecated_202101_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_get_recurring_application_charges_param_charge_with`].
+    async fn deprecated_202101_get_recurring_application_charges_param_charge_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202101GetRecurringApplicationChargesParamChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_delete_recurring_application_charges_param_charge`].
+    async fn deprecated_202101_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_update_recurring_application_charges_param_charge_customize_with`].
+    async fn deprecated_202101_update_recurring_application_charges_param_charge_customize_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_get_recurring_application_charge_with`].
+    async fn deprecated_unstable_get_recurring_application_charge_with(
+        &self,
+        params: &BillingDeprecatedUnstableGetRecurringApplicationChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_create_recurring_application_charges`].
+    async fn deprecated_unstable_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_get_recurring_application_charges_param_charge_with`].
+    async fn deprecated_unstable_get_recurring_application_charges_param_charge_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_delete_recurring_application_charges_param_charge`].
+    async fn deprecated_unstable_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_update_recurring_application_charges_param_charge_customize_with`].
+    async fn deprecated_unstable_update_recurring_application_charges_param_charge_customize_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage_with`].
+    async fn deprecated_202001_get_recurring_application_charges_param_charge_usage_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_create_recurring_application_charges_param_charge_usage`].
+    async fn deprecated_202001_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage_billing_with`].
+    async fn deprecated_202001_get_recurring_application_charges_param_charge_usage_billing_with(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        params: &BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage_with`].
+    async fn deprecated_202004_get_recurring_application_charges_param_charge_usage_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_create_recurring_application_charges_param_charge_usage`].
+    async fn deprecated_202004_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage_billing_with`].
+    async fn deprecated_202004_get_recurring_application_charges_param_charge_usage_billing_with(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        params: &BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage_with`].
+    async fn deprecated_202007_get_recurring_application_charges_param_charge_usage_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_create_recurring_application_charges_param_charge_usage`].
+    async fn deprecated_202007_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage_billing_with`].
+    async fn deprecated_202007_get_recurring_application_charges_param_charge_usage_billing_with(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        params: &BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::get_recurring_application_charges_param_charge_usage_with`].
+    async fn get_recurring_application_charges_param_charge_usage_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingGetRecurringApplicationChargesParamChargeUsageParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::create_recurring_application_charges_param_charge_usage`].
+    async fn create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::get_recurring_application_charges_param_charge_usage_billing_with`].
+    async fn get_recurring_application_charges_param_charge_usage_billing_with(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        params: &BillingGetRecurringApplicationChargesParamChargeUsageBillingParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage_with`].
+    async fn deprecated_202101_get_recurring_application_charges_param_charge_usage_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_create_recurring_application_charges_param_charge_usage`].
+    async fn deprecated_202101_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage_billing_with`].
+    async fn deprecated_202101_get_recurring_application_charges_param_charge_usage_billing_with(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        params: &BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage_with`].
+    async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageParams,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_create_recurring_application_charges_param_charge_usage`].
+    async fn deprecated_unstable_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing_with`].
+    async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing_with(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        params: &BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingParams,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl BillingApi for Billing {
+    async fn deprecated_202001_get_application_charge_with(
+        &self,
+        params: &BillingDeprecated202001GetApplicationChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_application_charge_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202001_create_application_charges(self, body).await
+    }
+
+    async fn deprecated_202001_get_application_charges_param_charge_with(
+        &self,
+        application_charge_id: &str,
+        params: &BillingDeprecated202001GetApplicationChargesParamChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_application_charges_param_charge_with(
+            self,
+            application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202001_create_application_charges_param_charge_activate(
+            self,
+            application_charge_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_application_charge_with(
+        &self,
+        params: &BillingDeprecated202004GetApplicationChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_application_charge_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202004_create_application_charges(self, body).await
+    }
+
+    async fn deprecated_202004_get_application_charges_param_charge_with(
+        &self,
+        application_charge_id: &str,
+        params: &BillingDeprecated202004GetApplicationChargesParamChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_application_charges_param_charge_with(
+            self,
+            application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202004_create_application_charges_param_charge_activate(
+            self,
+            application_charge_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_application_charge_with(
+        &self,
+        params: &BillingDeprecated202007GetApplicationChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_application_charge_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202007_create_application_charges(self, body).await
+    }
+
+    async fn deprecated_202007_get_application_charges_param_charge_with(
+        &self,
+        application_charge_id: &str,
+        params: &BillingDeprecated202007GetApplicationChargesParamChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_application_charges_param_charge_with(
+            self,
+            application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202007_create_application_charges_param_charge_activate(
+            self,
+            application_charge_id,
+            body,
+        )
+        .await
+    }
+
+    async fn get_application_charge_with(
+        &self,
+        params: &BillingGetApplicationChargeParams,
+    ) -> Result<()> {
+        Billing::get_application_charge_with(self, params).await
+    }
+
+    async fn create_application_charges(&self, body: &serde_json::Value) -> Result<()> {
+        Billing::create_application_charges(self, body).await
+    }
+
+    async fn get_application_charges_param_charge_with(
+        &self,
+        application_charge_id: &str,
+        params: &BillingGetApplicationChargesParamChargeParams,
+    ) -> Result<()> {
+        Billing::get_application_charges_param_charge_with(self, application_charge_id, params)
+            .await
+    }
+
+    async fn create_application_charges_param_charge_activate(
+        &self,
+        application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::create_application_charges_param_charge_activate(self, application_charge_id, body)
+            .await
+    }
+
+    async fn deprecated_202101_get_application_charge_with(
+        &self,
+        params: &BillingDeprecated202101GetApplicationChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_application_charge_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202101_create_application_charges(self, body).await
+    }
+
+    async fn deprecated_202101_get_application_charges_param_charge_with(
+        &self,
+        application_charge_id: &str,
+        params: &BillingDeprecated202101GetApplicationChargesParamChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_application_charges_param_charge_with(
+            self,
+            application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_application_charge_with(
+        &self,
+        params: &BillingDeprecatedUnstableGetApplicationChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_application_charge_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_create_application_charges(self, body).await
+    }
+
+    async fn deprecated_unstable_get_application_charges_param_charge_with(
+        &self,
+        application_charge_id: &str,
+        params: &BillingDeprecatedUnstableGetApplicationChargesParamChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_application_charges_param_charge_with(
+            self,
+            application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_application_credit_with(
+        &self,
+        params: &BillingDeprecated202001GetApplicationCreditParams,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_application_credit_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202001_create_application_credits(self, body).await
+    }
+
+    async fn deprecated_202001_get_application_credits_param_credit_with(
+        &self,
+        application_credit_id: &str,
+        params: &BillingDeprecated202001GetApplicationCreditsParamCreditParams,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_application_credits_param_credit_with(
+            self,
+            application_credit_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_application_credit_with(
+        &self,
+        params: &BillingDeprecated202004GetApplicationCreditParams,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_application_credit_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202004_create_application_credits(self, body).await
+    }
+
+    async fn deprecated_202004_get_application_credits_param_credit_with(
+        &self,
+        application_credit_id: &str,
+        params: &BillingDeprecated202004GetApplicationCreditsParamCreditParams,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_application_credits_param_credit_with(
+            self,
+            application_credit_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_application_credit_with(
+        &self,
+        params: &BillingDeprecated202007GetApplicationCreditParams,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_application_credit_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202007_create_application_credits(self, body).await
+    }
+
+    async fn deprecated_202007_get_application_credits_param_credit_with(
+        &self,
+        application_credit_id: &str,
+        params: &BillingDeprecated202007GetApplicationCreditsParamCreditParams,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_application_credits_param_credit_with(
+            self,
+            application_credit_id,
+            params,
+        )
+        .await
+    }
+
+    async fn get_application_credit_with(
+        &self,
+        params: &BillingGetApplicationCreditParams,
+    ) -> Result<()> {
+        Billing::get_application_credit_with(self, params).await
+    }
+
+    async fn create_application_credits(&self, body: &serde_json::Value) -> Result<()> {
+        Billing::create_application_credits(self, body).await
+    }
+
+    async fn get_application_credits_param_credit_with(
+        &self,
+        application_credit_id: &str,
+        params: &BillingGetApplicationCreditsParamCreditParams,
+    ) -> Result<()> {
+        Billing::get_application_credits_param_credit_with(self, application_credit_id, params)
+            .await
+    }
+
+    async fn deprecated_202101_get_application_credit_with(
+        &self,
+        params: &BillingDeprecated202101GetApplicationCreditParams,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_application_credit_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202101_create_application_credits(self, body).await
+    }
+
+    async fn deprecated_202101_get_application_credits_param_credit_with(
+        &self,
+        application_credit_id: &str,
+        params: &BillingDeprecated202101GetApplicationCreditsParamCreditParams,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_application_credits_param_credit_with(
+            self,
+            application_credit_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_application_credit_with(
+        &self,
+        params: &BillingDeprecatedUnstableGetApplicationCreditParams,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_application_credit_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_application_credits(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_create_application_credits(self, body).await
+    }
+
+    async fn deprecated_unstable_get_application_credits_param_credit_with(
+        &self,
+        application_credit_id: &str,
+        params: &BillingDeprecatedUnstableGetApplicationCreditsParamCreditParams,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_application_credits_param_credit_with(
+            self,
+            application_credit_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_recurring_application_charge_with(
+        &self,
+        params: &BillingDeprecated202001GetRecurringApplicationChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_recurring_application_charge_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202001_create_recurring_application_charges(self, body).await
+    }
+
+    async fn deprecated_202001_get_recurring_application_charges_param_charge_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202001GetRecurringApplicationChargesParamChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_recurring_application_charges_param_charge_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202001_delete_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202001_create_recurring_application_charges_param_charge_activate(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_update_recurring_application_charges_param_charge_customize_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202001UpdateRecurringApplicationChargesParamChargeCustomizeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202001_update_recurring_application_charges_param_charge_customize_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_recurring_application_charge_with(
+        &self,
+        params: &BillingDeprecated202004GetRecurringApplicationChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_recurring_application_charge_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202004_create_recurring_application_charges(self, body).await
+    }
+
+    async fn deprecated_202004_get_recurring_application_charges_param_charge_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202004GetRecurringApplicationChargesParamChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_recurring_application_charges_param_charge_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202004_delete_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202004_create_recurring_application_charges_param_charge_activate(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_update_recurring_application_charges_param_charge_customize_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202004UpdateRecurringApplicationChargesParamChargeCustomizeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202004_update_recurring_application_charges_param_charge_customize_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_recurring_application_charge_with(
+        &self,
+        params: &BillingDeprecated202007GetRecurringApplicationChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_recurring_application_charge_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202007_create_recurring_application_charges(self, body).await
+    }
+
+    async fn deprecated_202007_get_recurring_application_charges_param_charge_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202007GetRecurringApplicationChargesParamChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_recurring_application_charges_param_charge_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202007_delete_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202007_create_recurring_application_charges_param_charge_activate(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_update_recurring_application_charges_param_charge_customize_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202007UpdateRecurringApplicationChargesParamChargeCustomizeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202007_update_recurring_application_charges_param_charge_customize_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn get_recurring_application_charge_with(
+        &self,
+        params: &BillingGetRecurringApplicationChargeParams,
+    ) -> Result<()> {
+        Billing::get_recurring_application_charge_with(self, params).await
+    }
+
+    async fn create_recurring_application_charges(&self, body: &serde_json::Value) -> Result<()> {
+        Billing::create_recurring_application_charges(self, body).await
+    }
+
+    async fn get_recurring_application_charges_param_charge_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingGetRecurringApplicationChargesParamChargeParams,
+    ) -> Result<()> {
+        Billing::get_recurring_application_charges_param_charge_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()> {
+        Billing::delete_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+
+    async fn create_recurring_application_charges_param_charge_activate(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::create_recurring_application_charges_param_charge_activate(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+
+    async fn update_recurring_application_charges_param_charge_customize_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingUpdateRecurringApplicationChargesParamChargeCustomizeParams,
+    ) -> Result<()> {
+        Billing::update_recurring_application_charges_param_charge_customize_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_recurring_application_charge_with(
+        &self,
+        params: &BillingDeprecated202101GetRecurringApplicationChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_recurring_application_charge_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202101_create_recurring_application_charges(self, body).await
+    }
+
+    async fn deprecated_202101_get_recurring_application_charges_param_charge_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202101GetRecurringApplicationChargesParamChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_recurring_application_charges_param_charge_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()> {
+        Billing::deprecated_202101_delete_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_update_recurring_application_charges_param_charge_customize_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202101UpdateRecurringApplicationChargesParamChargeCustomizeParams,
+    ) -> Result<()> {
+        Billing::deprecated_202101_update_recurring_application_charges_param_charge_customize_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_recurring_application_charge_with(
+        &self,
+        params: &BillingDeprecatedUnstableGetRecurringApplicationChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_recurring_application_charge_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_recurring_application_charges(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_create_recurring_application_charges(self, body).await
+    }
+
+    async fn deprecated_unstable_get_recurring_application_charges_param_charge_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeParams,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_recurring_application_charges_param_charge_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_delete_recurring_application_charges_param_charge(
+        &self,
+        recurring_application_charge_id: &str,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_delete_recurring_application_charges_param_charge(
+            self,
+            recurring_application_charge_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_recurring_application_charges_param_charge_customize_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecatedUnstableUpdateRecurringApplicationChargesParamChargeCustomizeParams,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_update_recurring_application_charges_param_charge_customize_with(self, recurring_application_charge_id, params).await
+    }
+
+    async fn deprecated_202001_get_recurring_application_charges_param_charge_usage_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageParams,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202001_create_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_recurring_application_charges_param_charge_usage_billing_with(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        params: &BillingDeprecated202001GetRecurringApplicationChargesParamChargeUsageBillingParams,
+    ) -> Result<()> {
+        Billing::deprecated_202001_get_recurring_application_charges_param_charge_usage_billing_with(self, recurring_application_charge_id, usage_charge_id, params).await
+    }
+
+    async fn deprecated_202004_get_recurring_application_charges_param_charge_usage_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageParams,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202004_create_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_recurring_application_charges_param_charge_usage_billing_with(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        params: &BillingDeprecated202004GetRecurringApplicationChargesParamChargeUsageBillingParams,
+    ) -> Result<()> {
+        Billing::deprecated_202004_get_recurring_application_charges_param_charge_usage_billing_with(self, recurring_application_charge_id, usage_charge_id, params).await
+    }
+
+    async fn deprecated_202007_get_recurring_application_charges_param_charge_usage_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageParams,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202007_create_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_recurring_application_charges_param_charge_usage_billing_with(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        params: &BillingDeprecated202007GetRecurringApplicationChargesParamChargeUsageBillingParams,
+    ) -> Result<()> {
+        Billing::deprecated_202007_get_recurring_application_charges_param_charge_usage_billing_with(self, recurring_application_charge_id, usage_charge_id, params).await
+    }
+
+    async fn get_recurring_application_charges_param_charge_usage_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingGetRecurringApplicationChargesParamChargeUsageParams,
+    ) -> Result<()> {
+        Billing::get_recurring_application_charges_param_charge_usage_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::create_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+
+    async fn get_recurring_application_charges_param_charge_usage_billing_with(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        params: &BillingGetRecurringApplicationChargesParamChargeUsageBillingParams,
+    ) -> Result<()> {
+        Billing::get_recurring_application_charges_param_charge_usage_billing_with(
+            self,
+            recurring_application_charge_id,
+            usage_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_recurring_application_charges_param_charge_usage_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageParams,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_202101_create_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_recurring_application_charges_param_charge_usage_billing_with(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        params: &BillingDeprecated202101GetRecurringApplicationChargesParamChargeUsageBillingParams,
+    ) -> Result<()> {
+        Billing::deprecated_202101_get_recurring_application_charges_param_charge_usage_billing_with(self, recurring_application_charge_id, usage_charge_id, params).await
+    }
+
+    async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_with(
+        &self,
+        recurring_application_charge_id: &str,
+        params: &BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageParams,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage_with(
+            self,
+            recurring_application_charge_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_create_recurring_application_charges_param_charge_usage(
+        &self,
+        recurring_application_charge_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_create_recurring_application_charges_param_charge_usage(
+            self,
+            recurring_application_charge_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing_with(
+        &self,
+        recurring_application_charge_id: &str,
+        usage_charge_id: &str,
+        params: &BillingDeprecatedUnstableGetRecurringApplicationChargesParamChargeUsageBillingParams,
+    ) -> Result<()> {
+        Billing::deprecated_unstable_get_recurring_application_charges_param_charge_usage_billing_with(self, recurring_application_charge_id, usage_charge_id, params).await
+    }
 }
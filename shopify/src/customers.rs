@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Customers {
     pub client: Client,
@@ -21,50 +19,35 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Restrict results to customers specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to those after the specified ID.
-     * * `created_at_min: &str` -- Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202001GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get(
+    pub async fn deprecated_202001_get_with(
         &self,
-        ids: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecated202001GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -73,6 +56,34 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_with` and pass the query parameters as a `CustomersDeprecated202001GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_with` instead")]
+    pub async fn deprecated_202001_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202001GetParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_with(&params).await
+    }
+
     /**
      * Creates a customer.
      *
@@ -96,31 +107,23 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `query: &str` -- Text to search for in the shop's customer data.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202001GetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_search(
+    pub async fn deprecated_202001_get_search_with(
         &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecated202001GetSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -129,6 +132,26 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_search_with` and pass the query parameters as a `CustomersDeprecated202001GetSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_search_with` instead")]
+    pub async fn deprecated_202001_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202001GetSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_search_with(&params).await
+    }
+
     /**
      * Retrieves a single customer.
      *
@@ -139,11 +162,15 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202001GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202001GetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -156,6 +183,18 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_param_with` and pass the query parameters as a `CustomersDeprecated202001GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_param_with` instead")]
+    pub async fn deprecated_202001_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+        let params = CustomersDeprecated202001GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_param_with(customer_id, &params)
+            .await
+    }
+
     /**
      * Updates a customer.
      *
@@ -296,50 +335,35 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Restrict results to customers specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to those after the specified ID.
-     * * `created_at_min: &str` -- Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202004GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get(
+    pub async fn deprecated_202004_get_with(
         &self,
-        ids: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecated202004GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -348,6 +372,34 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_with` and pass the query parameters as a `CustomersDeprecated202004GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_with` instead")]
+    pub async fn deprecated_202004_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202004GetParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_with(&params).await
+    }
+
     /**
      * Creates a customer.
      *
@@ -371,31 +423,23 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `query: &str` -- Text to search for in the shop's customer data.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202004GetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_search(
+    pub async fn deprecated_202004_get_search_with(
         &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecated202004GetSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -404,6 +448,26 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_search_with` and pass the query parameters as a `CustomersDeprecated202004GetSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_search_with` instead")]
+    pub async fn deprecated_202004_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202004GetSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_search_with(&params).await
+    }
+
     /**
      * Retrieves a single customer.
      *
@@ -414,11 +478,15 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202004GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202004GetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -431,6 +499,18 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_param_with` and pass the query parameters as a `CustomersDeprecated202004GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_param_with` instead")]
+    pub async fn deprecated_202004_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+        let params = CustomersDeprecated202004GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_param_with(customer_id, &params)
+            .await
+    }
+
     /**
      * Updates a customer.
      *
@@ -571,50 +651,35 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Restrict results to customers specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to those after the specified ID.
-     * * `created_at_min: &str` -- Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202007GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get(
+    pub async fn deprecated_202007_get_with(
         &self,
-        ids: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecated202007GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -623,6 +688,34 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_with` and pass the query parameters as a `CustomersDeprecated202007GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_with` instead")]
+    pub async fn deprecated_202007_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202007GetParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_with(&params).await
+    }
+
     /**
      * Creates a customer.
      *
@@ -646,31 +739,23 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `query: &str` -- Text to search for in the shop's customer data.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202007GetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_search(
+    pub async fn deprecated_202007_get_search_with(
         &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecated202007GetSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -679,6 +764,26 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_search_with` and pass the query parameters as a `CustomersDeprecated202007GetSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_search_with` instead")]
+    pub async fn deprecated_202007_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202007GetSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_search_with(&params).await
+    }
+
     /**
      * Retrieves a single customer.
      *
@@ -689,11 +794,15 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202007GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202007GetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -706,6 +815,18 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_param_with` and pass the query parameters as a `CustomersDeprecated202007GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_param_with` instead")]
+    pub async fn deprecated_202007_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+        let params = CustomersDeprecated202007GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_param_with(customer_id, &params)
+            .await
+    }
+
     /**
      * Updates a customer.
      *
@@ -846,50 +967,32 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Restrict results to customers specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to those after the specified ID.
-     * * `created_at_min: &str` -- Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
-        &self,
-        ids: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        limit: &str,
-        fields: &str,
-    ) -> Result<()> {
+    pub async fn get_with(&self, params: &CustomersGetParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -898,6 +1001,34 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `CustomersGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersGetParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Creates a customer.
      *
@@ -921,31 +1052,20 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `query: &str` -- Text to search for in the shop's customer data.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersGetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_search(
-        &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
-    ) -> Result<()> {
+    pub async fn get_search_with(&self, params: &CustomersGetSearchParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -954,6 +1074,26 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_search_with` and pass the query parameters as a `CustomersGetSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_search_with` instead")]
+    pub async fn get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersGetSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_search_with(&params).await
+    }
+
     /**
      * Retrieves a single customer.
      *
@@ -964,11 +1104,15 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersGetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersGetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -981,6 +1125,17 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_param_with` and pass the query parameters as a `CustomersGetParamParams` instead.
+     */
+    #[deprecated(note = "use `get_param_with` instead")]
+    pub async fn get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+        let params = CustomersGetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_param_with(customer_id, &params).await
+    }
+
     /**
      * Updates a customer.
      *
@@ -1117,50 +1272,35 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Restrict results to customers specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to those after the specified ID.
-     * * `created_at_min: &str` -- Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202101GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get(
+    pub async fn deprecated_202101_get_with(
         &self,
-        ids: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecated202101GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1169,6 +1309,34 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_with` and pass the query parameters as a `CustomersDeprecated202101GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_with` instead")]
+    pub async fn deprecated_202101_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202101GetParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_with(&params).await
+    }
+
     /**
      * Creates a customer.
      *
@@ -1192,31 +1360,23 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `query: &str` -- Text to search for in the shop's customer data.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202101GetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_search(
+    pub async fn deprecated_202101_get_search_with(
         &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecated202101GetSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1225,6 +1385,26 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_search_with` and pass the query parameters as a `CustomersDeprecated202101GetSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_search_with` instead")]
+    pub async fn deprecated_202101_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202101GetSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_search_with(&params).await
+    }
+
     /**
      * Retrieves a single customer.
      *
@@ -1235,11 +1415,15 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202101GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202101GetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1252,6 +1436,18 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_param_with` and pass the query parameters as a `CustomersDeprecated202101GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_param_with` instead")]
+    pub async fn deprecated_202101_get_param(&self, customer_id: &str, fields: &str) -> Result<()> {
+        let params = CustomersDeprecated202101GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_param_with(customer_id, &params)
+            .await
+    }
+
     /**
      * Updates a customer.
      *
@@ -1392,50 +1588,35 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Restrict results to customers specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to those after the specified ID.
-     * * `created_at_min: &str` -- Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecatedUnstableGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get(
+    pub async fn deprecated_unstable_get_with(
         &self,
-        ids: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecatedUnstableGetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1444,6 +1625,34 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_with` and pass the query parameters as a `CustomersDeprecatedUnstableGetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_with` instead")]
+    pub async fn deprecated_unstable_get(
+        &self,
+        ids: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecatedUnstableGetParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_with(&params).await
+    }
+
     /**
      * Creates a customer.
      *
@@ -1467,31 +1676,23 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `query: &str` -- Text to search for in the shop's customer data.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecatedUnstableGetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_search(
+    pub async fn deprecated_unstable_get_search_with(
         &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecatedUnstableGetSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1500,6 +1701,26 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_search_with` and pass the query parameters as a `CustomersDeprecatedUnstableGetSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_search_with` instead")]
+    pub async fn deprecated_unstable_get_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecatedUnstableGetSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_search_with(&params).await
+    }
+
     /**
      * Retrieves a single customer.
      *
@@ -1510,15 +1731,15 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecatedUnstableGetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_param(
+    pub async fn deprecated_unstable_get_param_with(
         &self,
         customer_id: &str,
-        fields: &str,
+        params: &CustomersDeprecatedUnstableGetParamParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1531,6 +1752,22 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_param_with` and pass the query parameters as a `CustomersDeprecatedUnstableGetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_param_with` instead")]
+    pub async fn deprecated_unstable_get_param(
+        &self,
+        customer_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecatedUnstableGetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_param_with(customer_id, &params)
+            .await
+    }
+
     /**
      * Updates a customer.
      *
@@ -1799,20 +2036,18 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_ids: i64` -- recurring_application_charge[capped_amount].
-     * * `operation: &str` -- storefront_access_token_id.
+     * * `params: &CustomersDeprecated202001UpdateParamAddressesSetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_update_param_addresses_set(
+    pub async fn deprecated_202001_update_param_addresses_set_with(
         &self,
         customer_id: &str,
-        address_ids: i64,
-        operation: &str,
+        params: &CustomersDeprecated202001UpdateParamAddressesSetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if address_ids > 0 {
+        if let Some(address_ids) = &params.address_ids {
             query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
         }
-        if !operation.is_empty() {
+        if let Some(operation) = &params.operation {
             query_args.push(("operation".to_string(), operation.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1825,6 +2060,24 @@ impl Customers {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_update_param_addresses_set_with` and pass the query parameters as a `CustomersDeprecated202001UpdateParamAddressesSetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_update_param_addresses_set_with` instead")]
+    pub async fn deprecated_202001_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202001UpdateParamAddressesSetParams {
+            address_ids: Some(address_ids).filter(|v| *v > 0),
+            operation: Some(operation.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_update_param_addresses_set_with(customer_id, &params)
+            .await
+    }
+
     /**
      * Sets the default address for a customer.
      *
@@ -1988,20 +2241,18 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_ids: i64` -- recurring_application_charge[capped_amount].
-     * * `operation: &str` -- storefront_access_token_id.
+     * * `params: &CustomersDeprecated202004UpdateParamAddressesSetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_update_param_addresses_set(
+    pub async fn deprecated_202004_update_param_addresses_set_with(
         &self,
         customer_id: &str,
-        address_ids: i64,
-        operation: &str,
+        params: &CustomersDeprecated202004UpdateParamAddressesSetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if address_ids > 0 {
+        if let Some(address_ids) = &params.address_ids {
             query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
         }
-        if !operation.is_empty() {
+        if let Some(operation) = &params.operation {
             query_args.push(("operation".to_string(), operation.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2014,6 +2265,24 @@ impl Customers {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_update_param_addresses_set_with` and pass the query parameters as a `CustomersDeprecated202004UpdateParamAddressesSetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_update_param_addresses_set_with` instead")]
+    pub async fn deprecated_202004_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202004UpdateParamAddressesSetParams {
+            address_ids: Some(address_ids).filter(|v| *v > 0),
+            operation: Some(operation.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_update_param_addresses_set_with(customer_id, &params)
+            .await
+    }
+
     /**
      * Sets the default address for a customer.
      *
@@ -2177,20 +2446,18 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_ids: i64` -- recurring_application_charge[capped_amount].
-     * * `operation: &str` -- storefront_access_token_id.
+     * * `params: &CustomersDeprecated202007UpdateParamAddressesSetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_update_param_addresses_set(
+    pub async fn deprecated_202007_update_param_addresses_set_with(
         &self,
         customer_id: &str,
-        address_ids: i64,
-        operation: &str,
+        params: &CustomersDeprecated202007UpdateParamAddressesSetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if address_ids > 0 {
+        if let Some(address_ids) = &params.address_ids {
             query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
         }
-        if !operation.is_empty() {
+        if let Some(operation) = &params.operation {
             query_args.push(("operation".to_string(), operation.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2203,6 +2470,24 @@ impl Customers {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_update_param_addresses_set_with` and pass the query parameters as a `CustomersDeprecated202007UpdateParamAddressesSetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_update_param_addresses_set_with` instead")]
+    pub async fn deprecated_202007_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202007UpdateParamAddressesSetParams {
+            address_ids: Some(address_ids).filter(|v| *v > 0),
+            operation: Some(operation.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_update_param_addresses_set_with(customer_id, &params)
+            .await
+    }
+
     /**
      * Sets the default address for a customer.
      *
@@ -2366,20 +2651,18 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_ids: i64` -- recurring_application_charge[capped_amount].
-     * * `operation: &str` -- storefront_access_token_id.
+     * * `params: &CustomersUpdateParamAddressesSetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update_param_addresses_set(
+    pub async fn update_param_addresses_set_with(
         &self,
         customer_id: &str,
-        address_ids: i64,
-        operation: &str,
+        params: &CustomersUpdateParamAddressesSetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if address_ids > 0 {
+        if let Some(address_ids) = &params.address_ids {
             query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
         }
-        if !operation.is_empty() {
+        if let Some(operation) = &params.operation {
             query_args.push(("operation".to_string(), operation.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2392,6 +2675,24 @@ impl Customers {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `update_param_addresses_set_with` and pass the query parameters as a `CustomersUpdateParamAddressesSetParams` instead.
+     */
+    #[deprecated(note = "use `update_param_addresses_set_with` instead")]
+    pub async fn update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()> {
+        let params = CustomersUpdateParamAddressesSetParams {
+            address_ids: Some(address_ids).filter(|v| *v > 0),
+            operation: Some(operation.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.update_param_addresses_set_with(customer_id, &params)
+            .await
+    }
+
     /**
      * Sets the default address for a customer.
      *
@@ -2555,20 +2856,18 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_ids: i64` -- recurring_application_charge[capped_amount].
-     * * `operation: &str` -- storefront_access_token_id.
+     * * `params: &CustomersDeprecated202101UpdateParamAddressesSetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_update_param_addresses_set(
+    pub async fn deprecated_202101_update_param_addresses_set_with(
         &self,
         customer_id: &str,
-        address_ids: i64,
-        operation: &str,
+        params: &CustomersDeprecated202101UpdateParamAddressesSetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if address_ids > 0 {
+        if let Some(address_ids) = &params.address_ids {
             query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
         }
-        if !operation.is_empty() {
+        if let Some(operation) = &params.operation {
             query_args.push(("operation".to_string(), operation.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2581,6 +2880,24 @@ impl Customers {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_update_param_addresses_set_with` and pass the query parameters as a `CustomersDeprecated202101UpdateParamAddressesSetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_update_param_addresses_set_with` instead")]
+    pub async fn deprecated_202101_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202101UpdateParamAddressesSetParams {
+            address_ids: Some(address_ids).filter(|v| *v > 0),
+            operation: Some(operation.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_update_param_addresses_set_with(customer_id, &params)
+            .await
+    }
+
     /**
      * Sets the default address for a customer.
      *
@@ -2744,20 +3061,18 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_id: &str` -- storefront_access_token_id.
-     * * `address_ids: i64` -- recurring_application_charge[capped_amount].
-     * * `operation: &str` -- storefront_access_token_id.
+     * * `params: &CustomersDeprecatedUnstableUpdateParamAddressesSetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_update_param_addresses_set(
+    pub async fn deprecated_unstable_update_param_addresses_set_with(
         &self,
         customer_id: &str,
-        address_ids: i64,
-        operation: &str,
+        params: &CustomersDeprecatedUnstableUpdateParamAddressesSetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if address_ids > 0 {
+        if let Some(address_ids) = &params.address_ids {
             query_args.push(("address_ids[]".to_string(), address_ids.to_string()));
         }
-        if !operation.is_empty() {
+        if let Some(operation) = &params.operation {
             query_args.push(("operation".to_string(), operation.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2770,6 +3085,24 @@ impl Customers {
         self.client.put(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_update_param_addresses_set_with` and pass the query parameters as a `CustomersDeprecatedUnstableUpdateParamAddressesSetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_update_param_addresses_set_with` instead")]
+    pub async fn deprecated_unstable_update_param_addresses_set(
+        &self,
+        customer_id: &str,
+        address_ids: i64,
+        operation: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecatedUnstableUpdateParamAddressesSetParams {
+            address_ids: Some(address_ids).filter(|v| *v > 0),
+            operation: Some(operation.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_update_param_addresses_set_with(customer_id, &params)
+            .await
+    }
+
     /**
      * Sets the default address for a customer.
      *
@@ -2805,25 +3138,20 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202001GetSavedSearcheParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_saved_searche(
+    pub async fn deprecated_202001_get_saved_searche_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &CustomersDeprecated202001GetSavedSearcheParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2832,6 +3160,24 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_saved_searche_with` and pass the query parameters as a `CustomersDeprecated202001GetSavedSearcheParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_saved_searche_with` instead")]
+    pub async fn deprecated_202001_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202001GetSavedSearcheParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_saved_searche_with(&params).await
+    }
+
     /**
      * Creates a customer saved search.
      *
@@ -2858,11 +3204,14 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `params: &CustomersDeprecated202001GetSavedSearchesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecated202001GetSavedSearchesCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2874,6 +3223,18 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_saved_searches_count_with` and pass the query parameters as a `CustomersDeprecated202001GetSavedSearchesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_saved_searches_count_with` instead")]
+    pub async fn deprecated_202001_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+        let params = CustomersDeprecated202001GetSavedSearchesCountParams {
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_saved_searches_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single customer saved search.
      *
@@ -2884,15 +3245,15 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202001GetSavedSearchesParamSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_saved_searches_param_search(
+    pub async fn deprecated_202001_get_saved_searches_param_search_with(
         &self,
         customer_saved_search_id: &str,
-        fields: &str,
+        params: &CustomersDeprecated202001GetSavedSearchesParamSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2905,6 +3266,25 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_saved_searches_param_search_with` and pass the query parameters as a `CustomersDeprecated202001GetSavedSearchesParamSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_saved_searches_param_search_with` instead")]
+    pub async fn deprecated_202001_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202001GetSavedSearchesParamSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_saved_searches_param_search_with(
+            customer_saved_search_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates a customer saved search.
      *
@@ -2964,27 +3344,21 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202001GetSavedSearchesParamSearchCustomersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_saved_searches_param_search_customers(
+    pub async fn deprecated_202001_get_saved_searches_param_search_customers_with(
         &self,
         customer_saved_search_id: &str,
-        order: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecated202001GetSavedSearchesParamSearchCustomersParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2997,6 +3371,31 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_saved_searches_param_search_customers_with` and pass the query parameters as a `CustomersDeprecated202001GetSavedSearchesParamSearchCustomersParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_saved_searches_param_search_customers_with` instead"
+    )]
+    pub async fn deprecated_202001_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202001GetSavedSearchesParamSearchCustomersParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_saved_searches_param_search_customers_with(
+            customer_saved_search_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -3006,25 +3405,20 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202004GetSavedSearcheParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_saved_searche(
+    pub async fn deprecated_202004_get_saved_searche_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &CustomersDeprecated202004GetSavedSearcheParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3033,6 +3427,24 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_saved_searche_with` and pass the query parameters as a `CustomersDeprecated202004GetSavedSearcheParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_saved_searche_with` instead")]
+    pub async fn deprecated_202004_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202004GetSavedSearcheParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_saved_searche_with(&params).await
+    }
+
     /**
      * Creates a customer saved search.
      *
@@ -3059,11 +3471,14 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `params: &CustomersDeprecated202004GetSavedSearchesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecated202004GetSavedSearchesCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3075,6 +3490,18 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_saved_searches_count_with` and pass the query parameters as a `CustomersDeprecated202004GetSavedSearchesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_saved_searches_count_with` instead")]
+    pub async fn deprecated_202004_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+        let params = CustomersDeprecated202004GetSavedSearchesCountParams {
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_saved_searches_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single customer saved search.
      *
@@ -3085,15 +3512,15 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202004GetSavedSearchesParamSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_saved_searches_param_search(
+    pub async fn deprecated_202004_get_saved_searches_param_search_with(
         &self,
         customer_saved_search_id: &str,
-        fields: &str,
+        params: &CustomersDeprecated202004GetSavedSearchesParamSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3106,6 +3533,25 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_saved_searches_param_search_with` and pass the query parameters as a `CustomersDeprecated202004GetSavedSearchesParamSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_saved_searches_param_search_with` instead")]
+    pub async fn deprecated_202004_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202004GetSavedSearchesParamSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_saved_searches_param_search_with(
+            customer_saved_search_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates a customer saved search.
      *
@@ -3165,27 +3611,21 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202004GetSavedSearchesParamSearchCustomersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_saved_searches_param_search_customers(
+    pub async fn deprecated_202004_get_saved_searches_param_search_customers_with(
         &self,
         customer_saved_search_id: &str,
-        order: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecated202004GetSavedSearchesParamSearchCustomersParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3198,6 +3638,31 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_saved_searches_param_search_customers_with` and pass the query parameters as a `CustomersDeprecated202004GetSavedSearchesParamSearchCustomersParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_saved_searches_param_search_customers_with` instead"
+    )]
+    pub async fn deprecated_202004_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202004GetSavedSearchesParamSearchCustomersParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_saved_searches_param_search_customers_with(
+            customer_saved_search_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -3207,25 +3672,20 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202007GetSavedSearcheParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_saved_searche(
+    pub async fn deprecated_202007_get_saved_searche_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &CustomersDeprecated202007GetSavedSearcheParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3234,6 +3694,24 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_saved_searche_with` and pass the query parameters as a `CustomersDeprecated202007GetSavedSearcheParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_saved_searche_with` instead")]
+    pub async fn deprecated_202007_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202007GetSavedSearcheParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_saved_searche_with(&params).await
+    }
+
     /**
      * Creates a customer saved search.
      *
@@ -3260,11 +3738,14 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `params: &CustomersDeprecated202007GetSavedSearchesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecated202007GetSavedSearchesCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3276,6 +3757,18 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_saved_searches_count_with` and pass the query parameters as a `CustomersDeprecated202007GetSavedSearchesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_saved_searches_count_with` instead")]
+    pub async fn deprecated_202007_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+        let params = CustomersDeprecated202007GetSavedSearchesCountParams {
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_saved_searches_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single customer saved search.
      *
@@ -3286,15 +3779,15 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202007GetSavedSearchesParamSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_saved_searches_param_search(
+    pub async fn deprecated_202007_get_saved_searches_param_search_with(
         &self,
         customer_saved_search_id: &str,
-        fields: &str,
+        params: &CustomersDeprecated202007GetSavedSearchesParamSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3307,6 +3800,25 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_saved_searches_param_search_with` and pass the query parameters as a `CustomersDeprecated202007GetSavedSearchesParamSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_saved_searches_param_search_with` instead")]
+    pub async fn deprecated_202007_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202007GetSavedSearchesParamSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_saved_searches_param_search_with(
+            customer_saved_search_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates a customer saved search.
      *
@@ -3366,27 +3878,21 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202007GetSavedSearchesParamSearchCustomersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_saved_searches_param_search_customers(
+    pub async fn deprecated_202007_get_saved_searches_param_search_customers_with(
         &self,
         customer_saved_search_id: &str,
-        order: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecated202007GetSavedSearchesParamSearchCustomersParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3399,6 +3905,31 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_saved_searches_param_search_customers_with` and pass the query parameters as a `CustomersDeprecated202007GetSavedSearchesParamSearchCustomersParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_saved_searches_param_search_customers_with` instead"
+    )]
+    pub async fn deprecated_202007_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202007GetSavedSearchesParamSearchCustomersParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_saved_searches_param_search_customers_with(
+            customer_saved_search_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -3408,20 +3939,20 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersGetSavedSearcheParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_saved_searche(&self, limit: &str, since_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_saved_searche_with(
+        &self,
+        params: &CustomersGetSavedSearcheParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3430,6 +3961,19 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_saved_searche_with` and pass the query parameters as a `CustomersGetSavedSearcheParams` instead.
+     */
+    #[deprecated(note = "use `get_saved_searche_with` instead")]
+    pub async fn get_saved_searche(&self, limit: &str, since_id: &str, fields: &str) -> Result<()> {
+        let params = CustomersGetSavedSearcheParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_saved_searche_with(&params).await
+    }
+
     /**
      * Creates a customer saved search.
      *
@@ -3453,11 +3997,14 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `params: &CustomersGetSavedSearchesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+    pub async fn get_saved_searches_count_with(
+        &self,
+        params: &CustomersGetSavedSearchesCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3469,6 +4016,17 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_saved_searches_count_with` and pass the query parameters as a `CustomersGetSavedSearchesCountParams` instead.
+     */
+    #[deprecated(note = "use `get_saved_searches_count_with` instead")]
+    pub async fn get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+        let params = CustomersGetSavedSearchesCountParams {
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_saved_searches_count_with(&params).await
+    }
+
     /**
      * Retrieves a single customer saved search.
      *
@@ -3479,15 +4037,15 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersGetSavedSearchesParamSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_saved_searches_param_search(
+    pub async fn get_saved_searches_param_search_with(
         &self,
         customer_saved_search_id: &str,
-        fields: &str,
+        params: &CustomersGetSavedSearchesParamSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3500,6 +4058,22 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_saved_searches_param_search_with` and pass the query parameters as a `CustomersGetSavedSearchesParamSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_saved_searches_param_search_with` instead")]
+    pub async fn get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersGetSavedSearchesParamSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_saved_searches_param_search_with(customer_saved_search_id, &params)
+            .await
+    }
+
     /**
      * Updates a customer saved search.
      *
@@ -3559,27 +4133,21 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersGetSavedSearchesParamSearchCustomersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_saved_searches_param_search_customers(
+    pub async fn get_saved_searches_param_search_customers_with(
         &self,
         customer_saved_search_id: &str,
-        order: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersGetSavedSearchesParamSearchCustomersParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3592,6 +4160,26 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_saved_searches_param_search_customers_with` and pass the query parameters as a `CustomersGetSavedSearchesParamSearchCustomersParams` instead.
+     */
+    #[deprecated(note = "use `get_saved_searches_param_search_customers_with` instead")]
+    pub async fn get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersGetSavedSearchesParamSearchCustomersParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_saved_searches_param_search_customers_with(customer_saved_search_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -3601,25 +4189,20 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202101GetSavedSearcheParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_saved_searche(
+    pub async fn deprecated_202101_get_saved_searche_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &CustomersDeprecated202101GetSavedSearcheParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3628,6 +4211,24 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_saved_searche_with` and pass the query parameters as a `CustomersDeprecated202101GetSavedSearcheParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_saved_searche_with` instead")]
+    pub async fn deprecated_202101_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202101GetSavedSearcheParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_saved_searche_with(&params).await
+    }
+
     /**
      * Creates a customer saved search.
      *
@@ -3654,11 +4255,14 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `params: &CustomersDeprecated202101GetSavedSearchesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecated202101GetSavedSearchesCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3670,6 +4274,18 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_saved_searches_count_with` and pass the query parameters as a `CustomersDeprecated202101GetSavedSearchesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_saved_searches_count_with` instead")]
+    pub async fn deprecated_202101_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+        let params = CustomersDeprecated202101GetSavedSearchesCountParams {
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_saved_searches_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single customer saved search.
      *
@@ -3680,15 +4296,15 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202101GetSavedSearchesParamSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_saved_searches_param_search(
+    pub async fn deprecated_202101_get_saved_searches_param_search_with(
         &self,
         customer_saved_search_id: &str,
-        fields: &str,
+        params: &CustomersDeprecated202101GetSavedSearchesParamSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3701,6 +4317,25 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_saved_searches_param_search_with` and pass the query parameters as a `CustomersDeprecated202101GetSavedSearchesParamSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_saved_searches_param_search_with` instead")]
+    pub async fn deprecated_202101_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202101GetSavedSearchesParamSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_saved_searches_param_search_with(
+            customer_saved_search_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates a customer saved search.
      *
@@ -3760,27 +4395,21 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecated202101GetSavedSearchesParamSearchCustomersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_saved_searches_param_search_customers(
+    pub async fn deprecated_202101_get_saved_searches_param_search_customers_with(
         &self,
         customer_saved_search_id: &str,
-        order: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecated202101GetSavedSearchesParamSearchCustomersParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3793,6 +4422,31 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_saved_searches_param_search_customers_with` and pass the query parameters as a `CustomersDeprecated202101GetSavedSearchesParamSearchCustomersParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_saved_searches_param_search_customers_with` instead"
+    )]
+    pub async fn deprecated_202101_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecated202101GetSavedSearchesParamSearchCustomersParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_saved_searches_param_search_customers_with(
+            customer_saved_search_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of customer saved searches. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -3802,25 +4456,20 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecatedUnstableGetSavedSearcheParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_saved_searche(
+    pub async fn deprecated_unstable_get_saved_searche_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &CustomersDeprecatedUnstableGetSavedSearcheParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3832,6 +4481,25 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_saved_searche_with` and pass the query parameters as a `CustomersDeprecatedUnstableGetSavedSearcheParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_saved_searche_with` instead")]
+    pub async fn deprecated_unstable_get_saved_searche(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecatedUnstableGetSavedSearcheParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_saved_searche_with(&params)
+            .await
+    }
+
     /**
      * Creates a customer saved search.
      *
@@ -3858,11 +4526,14 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `params: &CustomersDeprecatedUnstableGetSavedSearchesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecatedUnstableGetSavedSearchesCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3874,6 +4545,18 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_saved_searches_count_with` and pass the query parameters as a `CustomersDeprecatedUnstableGetSavedSearchesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_saved_searches_count_with` instead")]
+    pub async fn deprecated_unstable_get_saved_searches_count(&self, since_id: &str) -> Result<()> {
+        let params = CustomersDeprecatedUnstableGetSavedSearchesCountParams {
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_saved_searches_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single customer saved search.
      *
@@ -3884,15 +4567,15 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecatedUnstableGetSavedSearchesParamSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_saved_searches_param_search(
+    pub async fn deprecated_unstable_get_saved_searches_param_search_with(
         &self,
         customer_saved_search_id: &str,
-        fields: &str,
+        params: &CustomersDeprecatedUnstableGetSavedSearchesParamSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3905,6 +4588,25 @@ impl Customers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_saved_searches_param_search_with` and pass the query parameters as a `CustomersDeprecatedUnstableGetSavedSearchesParamSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_saved_searches_param_search_with` instead")]
+    pub async fn deprecated_unstable_get_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecatedUnstableGetSavedSearchesParamSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_saved_searches_param_search_with(
+            customer_saved_search_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates a customer saved search.
      *
@@ -3964,27 +4666,21 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer_saved_search_id: &str` -- storefront_access_token_id.
-     * * `order: &str` -- Set the field and direction by which to order results.
-     *                     (default: last_order_date DESC).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_saved_searches_param_search_customers(
+    pub async fn deprecated_unstable_get_saved_searches_param_search_customers_with(
         &self,
         customer_saved_search_id: &str,
-        order: &str,
-        limit: &str,
-        fields: &str,
+        params: &CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3996,4 +4692,3364 @@ impl Customers {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `deprecated_unstable_get_saved_searches_param_search_customers_with` and pass the query parameters as a `CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_saved_searches_param_search_customers_with` instead"
+    )]
+    pub async fn deprecated_unstable_get_saved_searches_param_search_customers(
+        &self,
+        customer_saved_search_id: &str,
+        order: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_saved_searches_param_search_customers_with(
+            customer_saved_search_id,
+            &params,
+        )
+        .await
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202001_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202001GetParams {
+    /// Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Restrict results to customers specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to those after the specified ID.
+    pub since_id: Option<String>,
+    /// Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl CustomersDeprecated202001GetParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202001_get_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202001GetSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Set the field and direction by which to order results.
+    /// (default: last_order_date DESC).
+    pub order: Option<String>,
+    /// Text to search for in the shop's customer data.
+    pub query: Option<String>,
+}
+
+impl CustomersDeprecated202001GetSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202001_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202001GetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl CustomersDeprecated202001GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202004_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202004GetParams {
+    /// Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Restrict results to customers specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to those after the specified ID.
+    pub since_id: Option<String>,
+    /// Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl CustomersDeprecated202004GetParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202004_get_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202004GetSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Set the field and direction by which to order results.
+    /// (default: last_order_date DESC).
+    pub order: Option<String>,
+    /// Text to search for in the shop's customer data.
+    pub query: Option<String>,
+}
+
+impl CustomersDeprecated202004GetSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202004_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202004GetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl CustomersDeprecated202004GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202007_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202007GetParams {
+    /// Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Restrict results to customers specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to those after the specified ID.
+    pub since_id: Option<String>,
+    /// Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl CustomersDeprecated202007GetParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202007_get_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202007GetSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Set the field and direction by which to order results.
+    /// (default: last_order_date DESC).
+    pub order: Option<String>,
+    /// Text to search for in the shop's customer data.
+    pub query: Option<String>,
+}
+
+impl CustomersDeprecated202007GetSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202007_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202007GetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl CustomersDeprecated202007GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetParams {
+    /// Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Restrict results to customers specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to those after the specified ID.
+    pub since_id: Option<String>,
+    /// Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl CustomersGetParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Set the field and direction by which to order results.
+    /// (default: last_order_date DESC).
+    pub order: Option<String>,
+    /// Text to search for in the shop's customer data.
+    pub query: Option<String>,
+}
+
+impl CustomersGetSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl CustomersGetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202101_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202101GetParams {
+    /// Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Restrict results to customers specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to those after the specified ID.
+    pub since_id: Option<String>,
+    /// Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl CustomersDeprecated202101GetParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202101_get_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202101GetSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Set the field and direction by which to order results.
+    /// (default: last_order_date DESC).
+    pub order: Option<String>,
+    /// Text to search for in the shop's customer data.
+    pub query: Option<String>,
+}
+
+impl CustomersDeprecated202101GetSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202101_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202101GetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl CustomersDeprecated202101GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_unstable_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecatedUnstableGetParams {
+    /// Show customers created before a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show customers created after a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Restrict results to customers specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to those after the specified ID.
+    pub since_id: Option<String>,
+    /// Show customers last updated before a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show customers last updated after a specified date.(format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl CustomersDeprecatedUnstableGetParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_unstable_get_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecatedUnstableGetSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Set the field and direction by which to order results.
+    /// (default: last_order_date DESC).
+    pub order: Option<String>,
+    /// Text to search for in the shop's customer data.
+    pub query: Option<String>,
+}
+
+impl CustomersDeprecatedUnstableGetSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_unstable_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecatedUnstableGetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl CustomersDeprecatedUnstableGetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202001_update_param_addresses_set_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202001UpdateParamAddressesSetParams {
+    /// recurring_application_charge[capped_amount].
+    pub address_ids: Option<i64>,
+    /// storefront_access_token_id.
+    pub operation: Option<String>,
+}
+
+impl CustomersDeprecated202001UpdateParamAddressesSetParams {
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.address_ids = Some(address_ids);
+        self
+    }
+
+    pub fn operation<T: ToString>(mut self, operation: T) -> Self {
+        self.operation = Some(operation.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202004_update_param_addresses_set_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202004UpdateParamAddressesSetParams {
+    /// recurring_application_charge[capped_amount].
+    pub address_ids: Option<i64>,
+    /// storefront_access_token_id.
+    pub operation: Option<String>,
+}
+
+impl CustomersDeprecated202004UpdateParamAddressesSetParams {
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.address_ids = Some(address_ids);
+        self
+    }
+
+    pub fn operation<T: ToString>(mut self, operation: T) -> Self {
+        self.operation = Some(operation.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202007_update_param_addresses_set_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202007UpdateParamAddressesSetParams {
+    /// recurring_application_charge[capped_amount].
+    pub address_ids: Option<i64>,
+    /// storefront_access_token_id.
+    pub operation: Option<String>,
+}
+
+impl CustomersDeprecated202007UpdateParamAddressesSetParams {
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.address_ids = Some(address_ids);
+        self
+    }
+
+    pub fn operation<T: ToString>(mut self, operation: T) -> Self {
+        self.operation = Some(operation.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::update_param_addresses_set_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersUpdateParamAddressesSetParams {
+    /// recurring_application_charge[capped_amount].
+    pub address_ids: Option<i64>,
+    /// storefront_access_token_id.
+    pub operation: Option<String>,
+}
+
+impl CustomersUpdateParamAddressesSetParams {
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.address_ids = Some(address_ids);
+        self
+    }
+
+    pub fn operation<T: ToString>(mut self, operation: T) -> Self {
+        self.operation = Some(operation.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202101_update_param_addresses_set_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202101UpdateParamAddressesSetParams {
+    /// recurring_application_charge[capped_amount].
+    pub address_ids: Option<i64>,
+    /// storefront_access_token_id.
+    pub operation: Option<String>,
+}
+
+impl CustomersDeprecated202101UpdateParamAddressesSetParams {
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.address_ids = Some(address_ids);
+        self
+    }
+
+    pub fn operation<T: ToString>(mut self, operation: T) -> Self {
+        self.operation = Some(operation.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_unstable_update_param_addresses_set_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecatedUnstableUpdateParamAddressesSetParams {
+    /// recurring_application_charge[capped_amount].
+    pub address_ids: Option<i64>,
+    /// storefront_access_token_id.
+    pub operation: Option<String>,
+}
+
+impl CustomersDeprecatedUnstableUpdateParamAddressesSetParams {
+    pub fn address_ids(mut self, address_ids: i64) -> Self {
+        self.address_ids = Some(address_ids);
+        self
+    }
+
+    pub fn operation<T: ToString>(mut self, operation: T) -> Self {
+        self.operation = Some(operation.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202001_get_saved_searche_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202001GetSavedSearcheParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl CustomersDeprecated202001GetSavedSearcheParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202001_get_saved_searches_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202001GetSavedSearchesCountParams {
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl CustomersDeprecated202001GetSavedSearchesCountParams {
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202001_get_saved_searches_param_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202001GetSavedSearchesParamSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl CustomersDeprecated202001GetSavedSearchesParamSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202001_get_saved_searches_param_search_customers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202001GetSavedSearchesParamSearchCustomersParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Set the field and direction by which to order results.
+    /// (default: last_order_date DESC).
+    pub order: Option<String>,
+}
+
+impl CustomersDeprecated202001GetSavedSearchesParamSearchCustomersParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202004_get_saved_searche_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202004GetSavedSearcheParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl CustomersDeprecated202004GetSavedSearcheParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202004_get_saved_searches_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202004GetSavedSearchesCountParams {
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl CustomersDeprecated202004GetSavedSearchesCountParams {
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202004_get_saved_searches_param_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202004GetSavedSearchesParamSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl CustomersDeprecated202004GetSavedSearchesParamSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202004_get_saved_searches_param_search_customers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202004GetSavedSearchesParamSearchCustomersParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Set the field and direction by which to order results.
+    /// (default: last_order_date DESC).
+    pub order: Option<String>,
+}
+
+impl CustomersDeprecated202004GetSavedSearchesParamSearchCustomersParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202007_get_saved_searche_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202007GetSavedSearcheParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl CustomersDeprecated202007GetSavedSearcheParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202007_get_saved_searches_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202007GetSavedSearchesCountParams {
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl CustomersDeprecated202007GetSavedSearchesCountParams {
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202007_get_saved_searches_param_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202007GetSavedSearchesParamSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl CustomersDeprecated202007GetSavedSearchesParamSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202007_get_saved_searches_param_search_customers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202007GetSavedSearchesParamSearchCustomersParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Set the field and direction by which to order results.
+    /// (default: last_order_date DESC).
+    pub order: Option<String>,
+}
+
+impl CustomersDeprecated202007GetSavedSearchesParamSearchCustomersParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_saved_searche_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetSavedSearcheParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl CustomersGetSavedSearcheParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_saved_searches_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetSavedSearchesCountParams {
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl CustomersGetSavedSearchesCountParams {
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_saved_searches_param_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetSavedSearchesParamSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl CustomersGetSavedSearchesParamSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_saved_searches_param_search_customers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetSavedSearchesParamSearchCustomersParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Set the field and direction by which to order results.
+    /// (default: last_order_date DESC).
+    pub order: Option<String>,
+}
+
+impl CustomersGetSavedSearchesParamSearchCustomersParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202101_get_saved_searche_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202101GetSavedSearcheParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl CustomersDeprecated202101GetSavedSearcheParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202101_get_saved_searches_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202101GetSavedSearchesCountParams {
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl CustomersDeprecated202101GetSavedSearchesCountParams {
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202101_get_saved_searches_param_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202101GetSavedSearchesParamSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl CustomersDeprecated202101GetSavedSearchesParamSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_202101_get_saved_searches_param_search_customers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecated202101GetSavedSearchesParamSearchCustomersParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Set the field and direction by which to order results.
+    /// (default: last_order_date DESC).
+    pub order: Option<String>,
+}
+
+impl CustomersDeprecated202101GetSavedSearchesParamSearchCustomersParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_unstable_get_saved_searche_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecatedUnstableGetSavedSearcheParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl CustomersDeprecatedUnstableGetSavedSearcheParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_unstable_get_saved_searches_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecatedUnstableGetSavedSearchesCountParams {
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl CustomersDeprecatedUnstableGetSavedSearchesCountParams {
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_unstable_get_saved_searches_param_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecatedUnstableGetSavedSearchesParamSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl CustomersDeprecatedUnstableGetSavedSearchesParamSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::deprecated_unstable_get_saved_searches_param_search_customers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Set the field and direction by which to order results.
+    /// (default: last_order_date DESC).
+    pub order: Option<String>,
+}
+
+impl CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+}
+
+/// The functions of [`Customers`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCustomersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CustomersApi: Send + Sync {
+    /// See [`Customers::deprecated_202001_get_with`].
+    async fn deprecated_202001_get_with(
+        &self,
+        params: &CustomersDeprecated202001GetParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_create`].
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_get_search_with`].
+    async fn deprecated_202001_get_search_with(
+        &self,
+        params: &CustomersDeprecated202001GetSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_get_param_with`].
+    async fn deprecated_202001_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202001GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_update_param`].
+    async fn deprecated_202001_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_delete_param`].
+    async fn deprecated_202001_delete_param(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_create_param_account_activation_url`].
+    async fn deprecated_202001_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_create_param_send_invite`].
+    async fn deprecated_202001_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_get_count`].
+    async fn deprecated_202001_get_count(&self) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_get_param_order`].
+    async fn deprecated_202001_get_param_order(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_get_with`].
+    async fn deprecated_202004_get_with(
+        &self,
+        params: &CustomersDeprecated202004GetParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_create`].
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_get_search_with`].
+    async fn deprecated_202004_get_search_with(
+        &self,
+        params: &CustomersDeprecated202004GetSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_get_param_with`].
+    async fn deprecated_202004_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202004GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_update_param`].
+    async fn deprecated_202004_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_delete_param`].
+    async fn deprecated_202004_delete_param(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_create_param_account_activation_url`].
+    async fn deprecated_202004_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_create_param_send_invite`].
+    async fn deprecated_202004_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_get_count`].
+    async fn deprecated_202004_get_count(&self) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_get_param_order`].
+    async fn deprecated_202004_get_param_order(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_get_with`].
+    async fn deprecated_202007_get_with(
+        &self,
+        params: &CustomersDeprecated202007GetParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_create`].
+    async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_get_search_with`].
+    async fn deprecated_202007_get_search_with(
+        &self,
+        params: &CustomersDeprecated202007GetSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_get_param_with`].
+    async fn deprecated_202007_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202007GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_update_param`].
+    async fn deprecated_202007_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_delete_param`].
+    async fn deprecated_202007_delete_param(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_create_param_account_activation_url`].
+    async fn deprecated_202007_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_create_param_send_invite`].
+    async fn deprecated_202007_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_get_count`].
+    async fn deprecated_202007_get_count(&self) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_get_param_order`].
+    async fn deprecated_202007_get_param_order(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::get_with`].
+    async fn get_with(&self, params: &CustomersGetParams) -> Result<()>;
+
+    /// See [`Customers::create`].
+    async fn create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Customers::get_search_with`].
+    async fn get_search_with(&self, params: &CustomersGetSearchParams) -> Result<()>;
+
+    /// See [`Customers::get_param_with`].
+    async fn get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersGetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::update_param`].
+    async fn update_param(&self, customer_id: &str, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Customers::delete_param`].
+    async fn delete_param(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::create_param_account_activation_url`].
+    async fn create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::create_param_send_invite`].
+    async fn create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::get_count`].
+    async fn get_count(&self) -> Result<()>;
+
+    /// See [`Customers::get_param_order`].
+    async fn get_param_order(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_get_with`].
+    async fn deprecated_202101_get_with(
+        &self,
+        params: &CustomersDeprecated202101GetParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_create`].
+    async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_get_search_with`].
+    async fn deprecated_202101_get_search_with(
+        &self,
+        params: &CustomersDeprecated202101GetSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_get_param_with`].
+    async fn deprecated_202101_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202101GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_update_param`].
+    async fn deprecated_202101_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_delete_param`].
+    async fn deprecated_202101_delete_param(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_create_param_account_activation_url`].
+    async fn deprecated_202101_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_create_param_send_invite`].
+    async fn deprecated_202101_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_get_count`].
+    async fn deprecated_202101_get_count(&self) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_get_param_order`].
+    async fn deprecated_202101_get_param_order(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_get_with`].
+    async fn deprecated_unstable_get_with(
+        &self,
+        params: &CustomersDeprecatedUnstableGetParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_create`].
+    async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_get_search_with`].
+    async fn deprecated_unstable_get_search_with(
+        &self,
+        params: &CustomersDeprecatedUnstableGetSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_get_param_with`].
+    async fn deprecated_unstable_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecatedUnstableGetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_update_param`].
+    async fn deprecated_unstable_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_delete_param`].
+    async fn deprecated_unstable_delete_param(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_create_param_account_activation_url`].
+    async fn deprecated_unstable_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_create_param_send_invite`].
+    async fn deprecated_unstable_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_get_count`].
+    async fn deprecated_unstable_get_count(&self) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_get_param_order`].
+    async fn deprecated_unstable_get_param_order(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_get_param_addresse`].
+    async fn deprecated_202001_get_param_addresse(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_create_param_addresses`].
+    async fn deprecated_202001_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_get_param_addresses_address`].
+    async fn deprecated_202001_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_update_param_addresses_address`].
+    async fn deprecated_202001_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_delete_param_addresses_address`].
+    async fn deprecated_202001_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_update_param_addresses_set_with`].
+    async fn deprecated_202001_update_param_addresses_set_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202001UpdateParamAddressesSetParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_update_param_addresses_address_default`].
+    async fn deprecated_202001_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_get_param_addresse`].
+    async fn deprecated_202004_get_param_addresse(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_create_param_addresses`].
+    async fn deprecated_202004_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_get_param_addresses_address`].
+    async fn deprecated_202004_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_update_param_addresses_address`].
+    async fn deprecated_202004_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_delete_param_addresses_address`].
+    async fn deprecated_202004_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_update_param_addresses_set_with`].
+    async fn deprecated_202004_update_param_addresses_set_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202004UpdateParamAddressesSetParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_update_param_addresses_address_default`].
+    async fn deprecated_202004_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_get_param_addresse`].
+    async fn deprecated_202007_get_param_addresse(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_create_param_addresses`].
+    async fn deprecated_202007_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_get_param_addresses_address`].
+    async fn deprecated_202007_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_update_param_addresses_address`].
+    async fn deprecated_202007_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_delete_param_addresses_address`].
+    async fn deprecated_202007_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_update_param_addresses_set_with`].
+    async fn deprecated_202007_update_param_addresses_set_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202007UpdateParamAddressesSetParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_update_param_addresses_address_default`].
+    async fn deprecated_202007_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::get_param_addresse`].
+    async fn get_param_addresse(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::create_param_addresses`].
+    async fn create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::get_param_addresses_address`].
+    async fn get_param_addresses_address(&self, customer_id: &str, address_id: &str) -> Result<()>;
+
+    /// See [`Customers::update_param_addresses_address`].
+    async fn update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::delete_param_addresses_address`].
+    async fn delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::update_param_addresses_set_with`].
+    async fn update_param_addresses_set_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersUpdateParamAddressesSetParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::update_param_addresses_address_default`].
+    async fn update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_get_param_addresse`].
+    async fn deprecated_202101_get_param_addresse(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_create_param_addresses`].
+    async fn deprecated_202101_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_get_param_addresses_address`].
+    async fn deprecated_202101_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_update_param_addresses_address`].
+    async fn deprecated_202101_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_delete_param_addresses_address`].
+    async fn deprecated_202101_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_update_param_addresses_set_with`].
+    async fn deprecated_202101_update_param_addresses_set_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202101UpdateParamAddressesSetParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_update_param_addresses_address_default`].
+    async fn deprecated_202101_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_get_param_addresse`].
+    async fn deprecated_unstable_get_param_addresse(&self, customer_id: &str) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_create_param_addresses`].
+    async fn deprecated_unstable_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_get_param_addresses_address`].
+    async fn deprecated_unstable_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_update_param_addresses_address`].
+    async fn deprecated_unstable_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_delete_param_addresses_address`].
+    async fn deprecated_unstable_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_update_param_addresses_set_with`].
+    async fn deprecated_unstable_update_param_addresses_set_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecatedUnstableUpdateParamAddressesSetParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_update_param_addresses_address_default`].
+    async fn deprecated_unstable_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_get_saved_searche_with`].
+    async fn deprecated_202001_get_saved_searche_with(
+        &self,
+        params: &CustomersDeprecated202001GetSavedSearcheParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_create_saved_searches`].
+    async fn deprecated_202001_create_saved_searches(&self, body: &serde_json::Value)
+        -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_get_saved_searches_count_with`].
+    async fn deprecated_202001_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecated202001GetSavedSearchesCountParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_get_saved_searches_param_search_with`].
+    async fn deprecated_202001_get_saved_searches_param_search_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202001GetSavedSearchesParamSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_update_saved_searches_param_search`].
+    async fn deprecated_202001_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_delete_saved_searches_param_search`].
+    async fn deprecated_202001_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202001_get_saved_searches_param_search_customers_with`].
+    async fn deprecated_202001_get_saved_searches_param_search_customers_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202001GetSavedSearchesParamSearchCustomersParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_get_saved_searche_with`].
+    async fn deprecated_202004_get_saved_searche_with(
+        &self,
+        params: &CustomersDeprecated202004GetSavedSearcheParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_create_saved_searches`].
+    async fn deprecated_202004_create_saved_searches(&self, body: &serde_json::Value)
+        -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_get_saved_searches_count_with`].
+    async fn deprecated_202004_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecated202004GetSavedSearchesCountParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_get_saved_searches_param_search_with`].
+    async fn deprecated_202004_get_saved_searches_param_search_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202004GetSavedSearchesParamSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_update_saved_searches_param_search`].
+    async fn deprecated_202004_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_delete_saved_searches_param_search`].
+    async fn deprecated_202004_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202004_get_saved_searches_param_search_customers_with`].
+    async fn deprecated_202004_get_saved_searches_param_search_customers_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202004GetSavedSearchesParamSearchCustomersParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_get_saved_searche_with`].
+    async fn deprecated_202007_get_saved_searche_with(
+        &self,
+        params: &CustomersDeprecated202007GetSavedSearcheParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_create_saved_searches`].
+    async fn deprecated_202007_create_saved_searches(&self, body: &serde_json::Value)
+        -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_get_saved_searches_count_with`].
+    async fn deprecated_202007_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecated202007GetSavedSearchesCountParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_get_saved_searches_param_search_with`].
+    async fn deprecated_202007_get_saved_searches_param_search_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202007GetSavedSearchesParamSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_update_saved_searches_param_search`].
+    async fn deprecated_202007_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_delete_saved_searches_param_search`].
+    async fn deprecated_202007_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202007_get_saved_searches_param_search_customers_with`].
+    async fn deprecated_202007_get_saved_searches_param_search_customers_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202007GetSavedSearchesParamSearchCustomersParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::get_saved_searche_with`].
+    async fn get_saved_searche_with(&self, params: &CustomersGetSavedSearcheParams) -> Result<()>;
+
+    /// See [`Customers::create_saved_searches`].
+    async fn create_saved_searches(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Customers::get_saved_searches_count_with`].
+    async fn get_saved_searches_count_with(
+        &self,
+        params: &CustomersGetSavedSearchesCountParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::get_saved_searches_param_search_with`].
+    async fn get_saved_searches_param_search_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersGetSavedSearchesParamSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::update_saved_searches_param_search`].
+    async fn update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::delete_saved_searches_param_search`].
+    async fn delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::get_saved_searches_param_search_customers_with`].
+    async fn get_saved_searches_param_search_customers_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersGetSavedSearchesParamSearchCustomersParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_get_saved_searche_with`].
+    async fn deprecated_202101_get_saved_searche_with(
+        &self,
+        params: &CustomersDeprecated202101GetSavedSearcheParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_create_saved_searches`].
+    async fn deprecated_202101_create_saved_searches(&self, body: &serde_json::Value)
+        -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_get_saved_searches_count_with`].
+    async fn deprecated_202101_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecated202101GetSavedSearchesCountParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_get_saved_searches_param_search_with`].
+    async fn deprecated_202101_get_saved_searches_param_search_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202101GetSavedSearchesParamSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_update_saved_searches_param_search`].
+    async fn deprecated_202101_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_delete_saved_searches_param_search`].
+    async fn deprecated_202101_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_202101_get_saved_searches_param_search_customers_with`].
+    async fn deprecated_202101_get_saved_searches_param_search_customers_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202101GetSavedSearchesParamSearchCustomersParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_get_saved_searche_with`].
+    async fn deprecated_unstable_get_saved_searche_with(
+        &self,
+        params: &CustomersDeprecatedUnstableGetSavedSearcheParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_create_saved_searches`].
+    async fn deprecated_unstable_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_get_saved_searches_count_with`].
+    async fn deprecated_unstable_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecatedUnstableGetSavedSearchesCountParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_get_saved_searches_param_search_with`].
+    async fn deprecated_unstable_get_saved_searches_param_search_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecatedUnstableGetSavedSearchesParamSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_update_saved_searches_param_search`].
+    async fn deprecated_unstable_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_delete_saved_searches_param_search`].
+    async fn deprecated_unstable_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Customers::deprecated_unstable_get_saved_searches_param_search_customers_with`].
+    async fn deprecated_unstable_get_saved_searches_param_search_customers_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersParams,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl CustomersApi for Customers {
+    async fn deprecated_202001_get_with(
+        &self,
+        params: &CustomersDeprecated202001GetParams,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get_with(self, params).await
+    }
+
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::deprecated_202001_create(self, body).await
+    }
+
+    async fn deprecated_202001_get_search_with(
+        &self,
+        params: &CustomersDeprecated202001GetSearchParams,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get_search_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202001GetParamParams,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get_param_with(self, customer_id, params).await
+    }
+
+    async fn deprecated_202001_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_update_param(self, customer_id, body).await
+    }
+
+    async fn deprecated_202001_delete_param(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202001_delete_param(self, customer_id).await
+    }
+
+    async fn deprecated_202001_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_create_param_account_activation_url(self, customer_id, body)
+            .await
+    }
+
+    async fn deprecated_202001_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_create_param_send_invite(self, customer_id, body).await
+    }
+
+    async fn deprecated_202001_get_count(&self) -> Result<()> {
+        Customers::deprecated_202001_get_count(self).await
+    }
+
+    async fn deprecated_202001_get_param_order(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202001_get_param_order(self, customer_id).await
+    }
+
+    async fn deprecated_202004_get_with(
+        &self,
+        params: &CustomersDeprecated202004GetParams,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get_with(self, params).await
+    }
+
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::deprecated_202004_create(self, body).await
+    }
+
+    async fn deprecated_202004_get_search_with(
+        &self,
+        params: &CustomersDeprecated202004GetSearchParams,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get_search_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202004GetParamParams,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get_param_with(self, customer_id, params).await
+    }
+
+    async fn deprecated_202004_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_update_param(self, customer_id, body).await
+    }
+
+    async fn deprecated_202004_delete_param(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202004_delete_param(self, customer_id).await
+    }
+
+    async fn deprecated_202004_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_create_param_account_activation_url(self, customer_id, body)
+            .await
+    }
+
+    async fn deprecated_202004_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_create_param_send_invite(self, customer_id, body).await
+    }
+
+    async fn deprecated_202004_get_count(&self) -> Result<()> {
+        Customers::deprecated_202004_get_count(self).await
+    }
+
+    async fn deprecated_202004_get_param_order(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202004_get_param_order(self, customer_id).await
+    }
+
+    async fn deprecated_202007_get_with(
+        &self,
+        params: &CustomersDeprecated202007GetParams,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get_with(self, params).await
+    }
+
+    async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::deprecated_202007_create(self, body).await
+    }
+
+    async fn deprecated_202007_get_search_with(
+        &self,
+        params: &CustomersDeprecated202007GetSearchParams,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get_search_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202007GetParamParams,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get_param_with(self, customer_id, params).await
+    }
+
+    async fn deprecated_202007_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_update_param(self, customer_id, body).await
+    }
+
+    async fn deprecated_202007_delete_param(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202007_delete_param(self, customer_id).await
+    }
+
+    async fn deprecated_202007_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_create_param_account_activation_url(self, customer_id, body)
+            .await
+    }
+
+    async fn deprecated_202007_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_create_param_send_invite(self, customer_id, body).await
+    }
+
+    async fn deprecated_202007_get_count(&self) -> Result<()> {
+        Customers::deprecated_202007_get_count(self).await
+    }
+
+    async fn deprecated_202007_get_param_order(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202007_get_param_order(self, customer_id).await
+    }
+
+    async fn get_with(&self, params: &CustomersGetParams) -> Result<()> {
+        Customers::get_with(self, params).await
+    }
+
+    async fn create(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::create(self, body).await
+    }
+
+    async fn get_search_with(&self, params: &CustomersGetSearchParams) -> Result<()> {
+        Customers::get_search_with(self, params).await
+    }
+
+    async fn get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersGetParamParams,
+    ) -> Result<()> {
+        Customers::get_param_with(self, customer_id, params).await
+    }
+
+    async fn update_param(&self, customer_id: &str, body: &serde_json::Value) -> Result<()> {
+        Customers::update_param(self, customer_id, body).await
+    }
+
+    async fn delete_param(&self, customer_id: &str) -> Result<()> {
+        Customers::delete_param(self, customer_id).await
+    }
+
+    async fn create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::create_param_account_activation_url(self, customer_id, body).await
+    }
+
+    async fn create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::create_param_send_invite(self, customer_id, body).await
+    }
+
+    async fn get_count(&self) -> Result<()> {
+        Customers::get_count(self).await
+    }
+
+    async fn get_param_order(&self, customer_id: &str) -> Result<()> {
+        Customers::get_param_order(self, customer_id).await
+    }
+
+    async fn deprecated_202101_get_with(
+        &self,
+        params: &CustomersDeprecated202101GetParams,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get_with(self, params).await
+    }
+
+    async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::deprecated_202101_create(self, body).await
+    }
+
+    async fn deprecated_202101_get_search_with(
+        &self,
+        params: &CustomersDeprecated202101GetSearchParams,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get_search_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202101GetParamParams,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get_param_with(self, customer_id, params).await
+    }
+
+    async fn deprecated_202101_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_update_param(self, customer_id, body).await
+    }
+
+    async fn deprecated_202101_delete_param(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202101_delete_param(self, customer_id).await
+    }
+
+    async fn deprecated_202101_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_create_param_account_activation_url(self, customer_id, body)
+            .await
+    }
+
+    async fn deprecated_202101_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_create_param_send_invite(self, customer_id, body).await
+    }
+
+    async fn deprecated_202101_get_count(&self) -> Result<()> {
+        Customers::deprecated_202101_get_count(self).await
+    }
+
+    async fn deprecated_202101_get_param_order(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202101_get_param_order(self, customer_id).await
+    }
+
+    async fn deprecated_unstable_get_with(
+        &self,
+        params: &CustomersDeprecatedUnstableGetParams,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::deprecated_unstable_create(self, body).await
+    }
+
+    async fn deprecated_unstable_get_search_with(
+        &self,
+        params: &CustomersDeprecatedUnstableGetSearchParams,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get_search_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_param_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecatedUnstableGetParamParams,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get_param_with(self, customer_id, params).await
+    }
+
+    async fn deprecated_unstable_update_param(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_update_param(self, customer_id, body).await
+    }
+
+    async fn deprecated_unstable_delete_param(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_unstable_delete_param(self, customer_id).await
+    }
+
+    async fn deprecated_unstable_create_param_account_activation_url(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_create_param_account_activation_url(self, customer_id, body)
+            .await
+    }
+
+    async fn deprecated_unstable_create_param_send_invite(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_create_param_send_invite(self, customer_id, body).await
+    }
+
+    async fn deprecated_unstable_get_count(&self) -> Result<()> {
+        Customers::deprecated_unstable_get_count(self).await
+    }
+
+    async fn deprecated_unstable_get_param_order(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_unstable_get_param_order(self, customer_id).await
+    }
+
+    async fn deprecated_202001_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202001_get_param_addresse(self, customer_id).await
+    }
+
+    async fn deprecated_202001_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_create_param_addresses(self, customer_id, body).await
+    }
+
+    async fn deprecated_202001_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+
+    async fn deprecated_202001_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_update_param_addresses_address(
+            self,
+            customer_id,
+            address_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_delete_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+
+    async fn deprecated_202001_update_param_addresses_set_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202001UpdateParamAddressesSetParams,
+    ) -> Result<()> {
+        Customers::deprecated_202001_update_param_addresses_set_with(self, customer_id, params)
+            .await
+    }
+
+    async fn deprecated_202001_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_update_param_addresses_address_default(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202004_get_param_addresse(self, customer_id).await
+    }
+
+    async fn deprecated_202004_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_create_param_addresses(self, customer_id, body).await
+    }
+
+    async fn deprecated_202004_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+
+    async fn deprecated_202004_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_update_param_addresses_address(
+            self,
+            customer_id,
+            address_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_delete_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+
+    async fn deprecated_202004_update_param_addresses_set_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202004UpdateParamAddressesSetParams,
+    ) -> Result<()> {
+        Customers::deprecated_202004_update_param_addresses_set_with(self, customer_id, params)
+            .await
+    }
+
+    async fn deprecated_202004_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_update_param_addresses_address_default(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202007_get_param_addresse(self, customer_id).await
+    }
+
+    async fn deprecated_202007_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_create_param_addresses(self, customer_id, body).await
+    }
+
+    async fn deprecated_202007_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+
+    async fn deprecated_202007_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_update_param_addresses_address(
+            self,
+            customer_id,
+            address_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_delete_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+
+    async fn deprecated_202007_update_param_addresses_set_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202007UpdateParamAddressesSetParams,
+    ) -> Result<()> {
+        Customers::deprecated_202007_update_param_addresses_set_with(self, customer_id, params)
+            .await
+    }
+
+    async fn deprecated_202007_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_update_param_addresses_address_default(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+
+    async fn get_param_addresse(&self, customer_id: &str) -> Result<()> {
+        Customers::get_param_addresse(self, customer_id).await
+    }
+
+    async fn create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::create_param_addresses(self, customer_id, body).await
+    }
+
+    async fn get_param_addresses_address(&self, customer_id: &str, address_id: &str) -> Result<()> {
+        Customers::get_param_addresses_address(self, customer_id, address_id).await
+    }
+
+    async fn update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::update_param_addresses_address(self, customer_id, address_id, body).await
+    }
+
+    async fn delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::delete_param_addresses_address(self, customer_id, address_id).await
+    }
+
+    async fn update_param_addresses_set_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersUpdateParamAddressesSetParams,
+    ) -> Result<()> {
+        Customers::update_param_addresses_set_with(self, customer_id, params).await
+    }
+
+    async fn update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::update_param_addresses_address_default(self, customer_id, address_id).await
+    }
+
+    async fn deprecated_202101_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_202101_get_param_addresse(self, customer_id).await
+    }
+
+    async fn deprecated_202101_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_create_param_addresses(self, customer_id, body).await
+    }
+
+    async fn deprecated_202101_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+
+    async fn deprecated_202101_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_update_param_addresses_address(
+            self,
+            customer_id,
+            address_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_delete_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+
+    async fn deprecated_202101_update_param_addresses_set_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecated202101UpdateParamAddressesSetParams,
+    ) -> Result<()> {
+        Customers::deprecated_202101_update_param_addresses_set_with(self, customer_id, params)
+            .await
+    }
+
+    async fn deprecated_202101_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_update_param_addresses_address_default(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_param_addresse(&self, customer_id: &str) -> Result<()> {
+        Customers::deprecated_unstable_get_param_addresse(self, customer_id).await
+    }
+
+    async fn deprecated_unstable_create_param_addresses(
+        &self,
+        customer_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_create_param_addresses(self, customer_id, body).await
+    }
+
+    async fn deprecated_unstable_get_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+
+    async fn deprecated_unstable_update_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_update_param_addresses_address(
+            self,
+            customer_id,
+            address_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_delete_param_addresses_address(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_delete_param_addresses_address(self, customer_id, address_id)
+            .await
+    }
+
+    async fn deprecated_unstable_update_param_addresses_set_with(
+        &self,
+        customer_id: &str,
+        params: &CustomersDeprecatedUnstableUpdateParamAddressesSetParams,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_update_param_addresses_set_with(self, customer_id, params)
+            .await
+    }
+
+    async fn deprecated_unstable_update_param_addresses_address_default(
+        &self,
+        customer_id: &str,
+        address_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_update_param_addresses_address_default(
+            self,
+            customer_id,
+            address_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_saved_searche_with(
+        &self,
+        params: &CustomersDeprecated202001GetSavedSearcheParams,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get_saved_searche_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_create_saved_searches(self, body).await
+    }
+
+    async fn deprecated_202001_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecated202001GetSavedSearchesCountParams,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get_saved_searches_count_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_saved_searches_param_search_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202001GetSavedSearchesParamSearchParams,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get_saved_searches_param_search_with(
+            self,
+            customer_saved_search_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202001_update_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202001_delete_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_saved_searches_param_search_customers_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202001GetSavedSearchesParamSearchCustomersParams,
+    ) -> Result<()> {
+        Customers::deprecated_202001_get_saved_searches_param_search_customers_with(
+            self,
+            customer_saved_search_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_saved_searche_with(
+        &self,
+        params: &CustomersDeprecated202004GetSavedSearcheParams,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get_saved_searche_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_create_saved_searches(self, body).await
+    }
+
+    async fn deprecated_202004_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecated202004GetSavedSearchesCountParams,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get_saved_searches_count_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_saved_searches_param_search_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202004GetSavedSearchesParamSearchParams,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get_saved_searches_param_search_with(
+            self,
+            customer_saved_search_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202004_update_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202004_delete_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_saved_searches_param_search_customers_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202004GetSavedSearchesParamSearchCustomersParams,
+    ) -> Result<()> {
+        Customers::deprecated_202004_get_saved_searches_param_search_customers_with(
+            self,
+            customer_saved_search_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_saved_searche_with(
+        &self,
+        params: &CustomersDeprecated202007GetSavedSearcheParams,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get_saved_searche_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_create_saved_searches(self, body).await
+    }
+
+    async fn deprecated_202007_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecated202007GetSavedSearchesCountParams,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get_saved_searches_count_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_saved_searches_param_search_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202007GetSavedSearchesParamSearchParams,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get_saved_searches_param_search_with(
+            self,
+            customer_saved_search_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202007_update_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202007_delete_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_saved_searches_param_search_customers_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202007GetSavedSearchesParamSearchCustomersParams,
+    ) -> Result<()> {
+        Customers::deprecated_202007_get_saved_searches_param_search_customers_with(
+            self,
+            customer_saved_search_id,
+            params,
+        )
+        .await
+    }
+
+    async fn get_saved_searche_with(&self, params: &CustomersGetSavedSearcheParams) -> Result<()> {
+        Customers::get_saved_searche_with(self, params).await
+    }
+
+    async fn create_saved_searches(&self, body: &serde_json::Value) -> Result<()> {
+        Customers::create_saved_searches(self, body).await
+    }
+
+    async fn get_saved_searches_count_with(
+        &self,
+        params: &CustomersGetSavedSearchesCountParams,
+    ) -> Result<()> {
+        Customers::get_saved_searches_count_with(self, params).await
+    }
+
+    async fn get_saved_searches_param_search_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersGetSavedSearchesParamSearchParams,
+    ) -> Result<()> {
+        Customers::get_saved_searches_param_search_with(self, customer_saved_search_id, params)
+            .await
+    }
+
+    async fn update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::update_saved_searches_param_search(self, customer_saved_search_id, body).await
+    }
+
+    async fn delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()> {
+        Customers::delete_saved_searches_param_search(self, customer_saved_search_id).await
+    }
+
+    async fn get_saved_searches_param_search_customers_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersGetSavedSearchesParamSearchCustomersParams,
+    ) -> Result<()> {
+        Customers::get_saved_searches_param_search_customers_with(
+            self,
+            customer_saved_search_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_saved_searche_with(
+        &self,
+        params: &CustomersDeprecated202101GetSavedSearcheParams,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get_saved_searche_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_create_saved_searches(self, body).await
+    }
+
+    async fn deprecated_202101_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecated202101GetSavedSearchesCountParams,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get_saved_searches_count_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_saved_searches_param_search_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202101GetSavedSearchesParamSearchParams,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get_saved_searches_param_search_with(
+            self,
+            customer_saved_search_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_202101_update_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_202101_delete_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_saved_searches_param_search_customers_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecated202101GetSavedSearchesParamSearchCustomersParams,
+    ) -> Result<()> {
+        Customers::deprecated_202101_get_saved_searches_param_search_customers_with(
+            self,
+            customer_saved_search_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_saved_searche_with(
+        &self,
+        params: &CustomersDeprecatedUnstableGetSavedSearcheParams,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get_saved_searche_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_saved_searches(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_create_saved_searches(self, body).await
+    }
+
+    async fn deprecated_unstable_get_saved_searches_count_with(
+        &self,
+        params: &CustomersDeprecatedUnstableGetSavedSearchesCountParams,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get_saved_searches_count_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_saved_searches_param_search_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecatedUnstableGetSavedSearchesParamSearchParams,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get_saved_searches_param_search_with(
+            self,
+            customer_saved_search_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_update_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_delete_saved_searches_param_search(
+        &self,
+        customer_saved_search_id: &str,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_delete_saved_searches_param_search(
+            self,
+            customer_saved_search_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_saved_searches_param_search_customers_with(
+        &self,
+        customer_saved_search_id: &str,
+        params: &CustomersDeprecatedUnstableGetSavedSearchesParamSearchCustomersParams,
+    ) -> Result<()> {
+        Customers::deprecated_unstable_get_saved_searches_param_search_customers_with(
+            self,
+            customer_saved_search_id,
+            params,
+        )
+        .await
+    }
 }
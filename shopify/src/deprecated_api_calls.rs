@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct DeprecatedApiCalls {
     pub client: Client,
@@ -36,3 +34,26 @@ impl DeprecatedApiCalls {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`DeprecatedApiCalls`], for code that takes them as a trait, so that it can be
+/// tested with the `MockDeprecatedApiCallsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait DeprecatedApiCallsApi: Send + Sync {
+    /// See [`DeprecatedApiCalls::deprecated_202101_get_call`].
+    async fn deprecated_202101_get_call(&self) -> Result<()>;
+
+    /// See [`DeprecatedApiCalls::deprecated_unstable_get_call`].
+    async fn deprecated_unstable_get_call(&self) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl DeprecatedApiCallsApi for DeprecatedApiCalls {
+    async fn deprecated_202101_get_call(&self) -> Result<()> {
+        DeprecatedApiCalls::deprecated_202101_get_call(self).await
+    }
+
+    async fn deprecated_unstable_get_call(&self) -> Result<()> {
+        DeprecatedApiCalls::deprecated_unstable_get_call(self).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Discounts {
     pub client: Client,
@@ -153,11 +151,14 @@ impl Discounts {
     *
     * **Parameters:**
     *
-    * * `code: i64` -- recurring_application_charge[capped_amount].
+    * * `params: &DiscountsDeprecated202001GetCodesLookupParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_get_codes_lookup(&self, code: i64) -> Result<()> {
+    pub async fn deprecated_202001_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecated202001GetCodesLookupParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if code > 0 {
+        if let Some(code) = &params.code {
             query_args.push(("code".to_string(), code.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -166,6 +167,17 @@ impl Discounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_codes_lookup_with` and pass the query parameters as a `DiscountsDeprecated202001GetCodesLookupParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_codes_lookup_with` instead")]
+    pub async fn deprecated_202001_get_codes_lookup(&self, code: i64) -> Result<()> {
+        let params = DiscountsDeprecated202001GetCodesLookupParams {
+            code: Some(code).filter(|v| *v > 0),
+        };
+        self.deprecated_202001_get_codes_lookup_with(&params).await
+    }
+
     /**
     * Creates a discount code creation job.
               The batch endpoint can be used to asynchronously create up to 100 discount codes in a single request. It
@@ -404,11 +416,14 @@ impl Discounts {
     *
     * **Parameters:**
     *
-    * * `code: i64` -- recurring_application_charge[capped_amount].
+    * * `params: &DiscountsDeprecated202004GetCodesLookupParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_get_codes_lookup(&self, code: i64) -> Result<()> {
+    pub async fn deprecated_202004_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecated202004GetCodesLookupParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if code > 0 {
+        if let Some(code) = &params.code {
             query_args.push(("code".to_string(), code.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -417,6 +432,17 @@ impl Discounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_codes_lookup_with` and pass the query parameters as a `DiscountsDeprecated202004GetCodesLookupParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_codes_lookup_with` instead")]
+    pub async fn deprecated_202004_get_codes_lookup(&self, code: i64) -> Result<()> {
+        let params = DiscountsDeprecated202004GetCodesLookupParams {
+            code: Some(code).filter(|v| *v > 0),
+        };
+        self.deprecated_202004_get_codes_lookup_with(&params).await
+    }
+
     /**
     * Creates a discount code creation job.
               The batch endpoint can be used to asynchronously create up to 100 discount codes in a single request. It
@@ -655,11 +681,14 @@ impl Discounts {
     *
     * **Parameters:**
     *
-    * * `code: i64` -- recurring_application_charge[capped_amount].
+    * * `params: &DiscountsDeprecated202007GetCodesLookupParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202007_get_codes_lookup(&self, code: i64) -> Result<()> {
+    pub async fn deprecated_202007_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecated202007GetCodesLookupParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if code > 0 {
+        if let Some(code) = &params.code {
             query_args.push(("code".to_string(), code.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -668,6 +697,17 @@ impl Discounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_codes_lookup_with` and pass the query parameters as a `DiscountsDeprecated202007GetCodesLookupParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_codes_lookup_with` instead")]
+    pub async fn deprecated_202007_get_codes_lookup(&self, code: i64) -> Result<()> {
+        let params = DiscountsDeprecated202007GetCodesLookupParams {
+            code: Some(code).filter(|v| *v > 0),
+        };
+        self.deprecated_202007_get_codes_lookup_with(&params).await
+    }
+
     /**
     * Creates a discount code creation job.
               The batch endpoint can be used to asynchronously create up to 100 discount codes in a single request. It
@@ -903,11 +943,14 @@ impl Discounts {
     *
     * **Parameters:**
     *
-    * * `code: i64` -- recurring_application_charge[capped_amount].
+    * * `params: &DiscountsGetCodesLookupParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn get_codes_lookup(&self, code: i64) -> Result<()> {
+    pub async fn get_codes_lookup_with(
+        &self,
+        params: &DiscountsGetCodesLookupParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if code > 0 {
+        if let Some(code) = &params.code {
             query_args.push(("code".to_string(), code.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -916,6 +959,17 @@ impl Discounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_codes_lookup_with` and pass the query parameters as a `DiscountsGetCodesLookupParams` instead.
+     */
+    #[deprecated(note = "use `get_codes_lookup_with` instead")]
+    pub async fn get_codes_lookup(&self, code: i64) -> Result<()> {
+        let params = DiscountsGetCodesLookupParams {
+            code: Some(code).filter(|v| *v > 0),
+        };
+        self.get_codes_lookup_with(&params).await
+    }
+
     /**
     * Creates a discount code creation job.
               The batch endpoint can be used to asynchronously create up to 100 discount codes in a single request. It
@@ -1154,11 +1208,14 @@ impl Discounts {
     *
     * **Parameters:**
     *
-    * * `code: i64` -- recurring_application_charge[capped_amount].
+    * * `params: &DiscountsDeprecated202101GetCodesLookupParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202101_get_codes_lookup(&self, code: i64) -> Result<()> {
+    pub async fn deprecated_202101_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecated202101GetCodesLookupParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if code > 0 {
+        if let Some(code) = &params.code {
             query_args.push(("code".to_string(), code.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1167,6 +1224,17 @@ impl Discounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_codes_lookup_with` and pass the query parameters as a `DiscountsDeprecated202101GetCodesLookupParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_codes_lookup_with` instead")]
+    pub async fn deprecated_202101_get_codes_lookup(&self, code: i64) -> Result<()> {
+        let params = DiscountsDeprecated202101GetCodesLookupParams {
+            code: Some(code).filter(|v| *v > 0),
+        };
+        self.deprecated_202101_get_codes_lookup_with(&params).await
+    }
+
     /**
     * Creates a discount code creation job.
               The batch endpoint can be used to asynchronously create up to 100 discount codes in a single request. It
@@ -1405,11 +1473,14 @@ impl Discounts {
     *
     * **Parameters:**
     *
-    * * `code: i64` -- recurring_application_charge[capped_amount].
+    * * `params: &DiscountsDeprecatedUnstableGetCodesLookupParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_unstable_get_codes_lookup(&self, code: i64) -> Result<()> {
+    pub async fn deprecated_unstable_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecatedUnstableGetCodesLookupParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if code > 0 {
+        if let Some(code) = &params.code {
             query_args.push(("code".to_string(), code.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1418,6 +1489,18 @@ impl Discounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_codes_lookup_with` and pass the query parameters as a `DiscountsDeprecatedUnstableGetCodesLookupParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_codes_lookup_with` instead")]
+    pub async fn deprecated_unstable_get_codes_lookup(&self, code: i64) -> Result<()> {
+        let params = DiscountsDeprecatedUnstableGetCodesLookupParams {
+            code: Some(code).filter(|v| *v > 0),
+        };
+        self.deprecated_unstable_get_codes_lookup_with(&params)
+            .await
+    }
+
     /**
     * Creates a discount code creation job.
               The batch endpoint can be used to asynchronously create up to 100 discount codes in a single request. It
@@ -1515,3 +1598,1022 @@ impl Discounts {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Discounts::deprecated_202001_get_codes_lookup_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DiscountsDeprecated202001GetCodesLookupParams {
+    /// recurring_application_charge[capped_amount].
+    pub code: Option<i64>,
+}
+
+impl DiscountsDeprecated202001GetCodesLookupParams {
+    pub fn code(mut self, code: i64) -> Self {
+        self.code = Some(code);
+        self
+    }
+}
+
+/// The query parameters of `Discounts::deprecated_202004_get_codes_lookup_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DiscountsDeprecated202004GetCodesLookupParams {
+    /// recurring_application_charge[capped_amount].
+    pub code: Option<i64>,
+}
+
+impl DiscountsDeprecated202004GetCodesLookupParams {
+    pub fn code(mut self, code: i64) -> Self {
+        self.code = Some(code);
+        self
+    }
+}
+
+/// The query parameters of `Discounts::deprecated_202007_get_codes_lookup_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DiscountsDeprecated202007GetCodesLookupParams {
+    /// recurring_application_charge[capped_amount].
+    pub code: Option<i64>,
+}
+
+impl DiscountsDeprecated202007GetCodesLookupParams {
+    pub fn code(mut self, code: i64) -> Self {
+        self.code = Some(code);
+        self
+    }
+}
+
+/// The query parameters of `Discounts::get_codes_lookup_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DiscountsGetCodesLookupParams {
+    /// recurring_application_charge[capped_amount].
+    pub code: Option<i64>,
+}
+
+impl DiscountsGetCodesLookupParams {
+    pub fn code(mut self, code: i64) -> Self {
+        self.code = Some(code);
+        self
+    }
+}
+
+/// The query parameters of `Discounts::deprecated_202101_get_codes_lookup_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DiscountsDeprecated202101GetCodesLookupParams {
+    /// recurring_application_charge[capped_amount].
+    pub code: Option<i64>,
+}
+
+impl DiscountsDeprecated202101GetCodesLookupParams {
+    pub fn code(mut self, code: i64) -> Self {
+        self.code = Some(code);
+        self
+    }
+}
+
+/// The query parameters of `Discounts::deprecated_unstable_get_codes_lookup_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DiscountsDeprecatedUnstableGetCodesLookupParams {
+    /// recurring_application_charge[capped_amount].
+    pub code: Option<i64>,
+}
+
+impl DiscountsDeprecatedUnstableGetCodesLookupParams {
+    pub fn code(mut self, code: i64) -> Self {
+        self.code = Some(code);
+        self
+    }
+}
+
+/// The functions of [`Discounts`], for code that takes them as a trait, so that it can be
+/// tested with the `MockDiscountsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait DiscountsApi: Send + Sync {
+    /// See [`Discounts::deprecated_202001_get_price_rules_param_rule_code`].
+    async fn deprecated_202001_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202001_create_price_rules_param_rule_codes`].
+    async fn deprecated_202001_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202001_get_price_rules_param_rule_codes_code`].
+    async fn deprecated_202001_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202001_update_price_rules_param_rule_codes_code`].
+    async fn deprecated_202001_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202001_delete_price_rules_param_rule_codes_code`].
+    async fn deprecated_202001_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202001_get_codes_lookup_with`].
+    async fn deprecated_202001_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecated202001GetCodesLookupParams,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202001_create_price_rules_param_rule_batch`].
+    async fn deprecated_202001_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202001_get_price_rules_param_rule_batch`].
+    async fn deprecated_202001_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202001_get_price_rules_param_rule_batch_code`].
+    async fn deprecated_202001_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202004_get_price_rules_param_rule_code`].
+    async fn deprecated_202004_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202004_create_price_rules_param_rule_codes`].
+    async fn deprecated_202004_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202004_get_price_rules_param_rule_codes_code`].
+    async fn deprecated_202004_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202004_update_price_rules_param_rule_codes_code`].
+    async fn deprecated_202004_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202004_delete_price_rules_param_rule_codes_code`].
+    async fn deprecated_202004_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202004_get_codes_lookup_with`].
+    async fn deprecated_202004_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecated202004GetCodesLookupParams,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202004_create_price_rules_param_rule_batch`].
+    async fn deprecated_202004_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202004_get_price_rules_param_rule_batch`].
+    async fn deprecated_202004_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202004_get_price_rules_param_rule_batch_code`].
+    async fn deprecated_202004_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202007_get_price_rules_param_rule_code`].
+    async fn deprecated_202007_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202007_create_price_rules_param_rule_codes`].
+    async fn deprecated_202007_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202007_get_price_rules_param_rule_codes_code`].
+    async fn deprecated_202007_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202007_update_price_rules_param_rule_codes_code`].
+    async fn deprecated_202007_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202007_delete_price_rules_param_rule_codes_code`].
+    async fn deprecated_202007_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202007_get_codes_lookup_with`].
+    async fn deprecated_202007_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecated202007GetCodesLookupParams,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202007_create_price_rules_param_rule_batch`].
+    async fn deprecated_202007_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202007_get_price_rules_param_rule_batch`].
+    async fn deprecated_202007_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202007_get_price_rules_param_rule_batch_code`].
+    async fn deprecated_202007_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::get_price_rules_param_rule_code`].
+    async fn get_price_rules_param_rule_code(&self, price_rule_id: &str) -> Result<()>;
+
+    /// See [`Discounts::create_price_rules_param_rule_codes`].
+    async fn create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::get_price_rules_param_rule_codes_code`].
+    async fn get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::update_price_rules_param_rule_codes_code`].
+    async fn update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::delete_price_rules_param_rule_codes_code`].
+    async fn delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::get_codes_lookup_with`].
+    async fn get_codes_lookup_with(&self, params: &DiscountsGetCodesLookupParams) -> Result<()>;
+
+    /// See [`Discounts::create_price_rules_param_rule_batch`].
+    async fn create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::get_price_rules_param_rule_batch`].
+    async fn get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::get_price_rules_param_rule_batch_code`].
+    async fn get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202101_get_price_rules_param_rule_code`].
+    async fn deprecated_202101_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202101_create_price_rules_param_rule_codes`].
+    async fn deprecated_202101_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202101_get_price_rules_param_rule_codes_code`].
+    async fn deprecated_202101_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202101_update_price_rules_param_rule_codes_code`].
+    async fn deprecated_202101_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202101_delete_price_rules_param_rule_codes_code`].
+    async fn deprecated_202101_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202101_get_codes_lookup_with`].
+    async fn deprecated_202101_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecated202101GetCodesLookupParams,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202101_create_price_rules_param_rule_batch`].
+    async fn deprecated_202101_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202101_get_price_rules_param_rule_batch`].
+    async fn deprecated_202101_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_202101_get_price_rules_param_rule_batch_code`].
+    async fn deprecated_202101_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_unstable_get_price_rules_param_rule_code`].
+    async fn deprecated_unstable_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_unstable_create_price_rules_param_rule_codes`].
+    async fn deprecated_unstable_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_unstable_get_price_rules_param_rule_codes_code`].
+    async fn deprecated_unstable_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_unstable_update_price_rules_param_rule_codes_code`].
+    async fn deprecated_unstable_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_unstable_delete_price_rules_param_rule_codes_code`].
+    async fn deprecated_unstable_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_unstable_get_codes_lookup_with`].
+    async fn deprecated_unstable_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecatedUnstableGetCodesLookupParams,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_unstable_create_price_rules_param_rule_batch`].
+    async fn deprecated_unstable_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_unstable_get_price_rules_param_rule_batch`].
+    async fn deprecated_unstable_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Discounts::deprecated_unstable_get_price_rules_param_rule_batch_code`].
+    async fn deprecated_unstable_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl DiscountsApi for Discounts {
+    async fn deprecated_202001_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_get_price_rules_param_rule_code(self, price_rule_id).await
+    }
+
+    async fn deprecated_202001_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_create_price_rules_param_rule_codes(self, price_rule_id, body)
+            .await
+    }
+
+    async fn deprecated_202001_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_get_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_update_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_delete_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecated202001GetCodesLookupParams,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_get_codes_lookup_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_create_price_rules_param_rule_batch(self, price_rule_id, body)
+            .await
+    }
+
+    async fn deprecated_202001_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_get_price_rules_param_rule_batch(self, price_rule_id, batch_id)
+            .await
+    }
+
+    async fn deprecated_202001_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202001_get_price_rules_param_rule_batch_code(
+            self,
+            price_rule_id,
+            batch_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_get_price_rules_param_rule_code(self, price_rule_id).await
+    }
+
+    async fn deprecated_202004_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_create_price_rules_param_rule_codes(self, price_rule_id, body)
+            .await
+    }
+
+    async fn deprecated_202004_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_get_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_update_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_delete_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecated202004GetCodesLookupParams,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_get_codes_lookup_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_create_price_rules_param_rule_batch(self, price_rule_id, body)
+            .await
+    }
+
+    async fn deprecated_202004_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_get_price_rules_param_rule_batch(self, price_rule_id, batch_id)
+            .await
+    }
+
+    async fn deprecated_202004_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202004_get_price_rules_param_rule_batch_code(
+            self,
+            price_rule_id,
+            batch_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_get_price_rules_param_rule_code(self, price_rule_id).await
+    }
+
+    async fn deprecated_202007_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_create_price_rules_param_rule_codes(self, price_rule_id, body)
+            .await
+    }
+
+    async fn deprecated_202007_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_get_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_update_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_delete_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecated202007GetCodesLookupParams,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_get_codes_lookup_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_create_price_rules_param_rule_batch(self, price_rule_id, body)
+            .await
+    }
+
+    async fn deprecated_202007_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_get_price_rules_param_rule_batch(self, price_rule_id, batch_id)
+            .await
+    }
+
+    async fn deprecated_202007_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202007_get_price_rules_param_rule_batch_code(
+            self,
+            price_rule_id,
+            batch_id,
+        )
+        .await
+    }
+
+    async fn get_price_rules_param_rule_code(&self, price_rule_id: &str) -> Result<()> {
+        Discounts::get_price_rules_param_rule_code(self, price_rule_id).await
+    }
+
+    async fn create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::create_price_rules_param_rule_codes(self, price_rule_id, body).await
+    }
+
+    async fn get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::get_price_rules_param_rule_codes_code(self, price_rule_id, discount_code_id)
+            .await
+    }
+
+    async fn update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::update_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+            body,
+        )
+        .await
+    }
+
+    async fn delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::delete_price_rules_param_rule_codes_code(self, price_rule_id, discount_code_id)
+            .await
+    }
+
+    async fn get_codes_lookup_with(&self, params: &DiscountsGetCodesLookupParams) -> Result<()> {
+        Discounts::get_codes_lookup_with(self, params).await
+    }
+
+    async fn create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::create_price_rules_param_rule_batch(self, price_rule_id, body).await
+    }
+
+    async fn get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::get_price_rules_param_rule_batch(self, price_rule_id, batch_id).await
+    }
+
+    async fn get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::get_price_rules_param_rule_batch_code(self, price_rule_id, batch_id).await
+    }
+
+    async fn deprecated_202101_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_get_price_rules_param_rule_code(self, price_rule_id).await
+    }
+
+    async fn deprecated_202101_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_create_price_rules_param_rule_codes(self, price_rule_id, body)
+            .await
+    }
+
+    async fn deprecated_202101_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_get_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_update_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_delete_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecated202101GetCodesLookupParams,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_get_codes_lookup_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_create_price_rules_param_rule_batch(self, price_rule_id, body)
+            .await
+    }
+
+    async fn deprecated_202101_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_get_price_rules_param_rule_batch(self, price_rule_id, batch_id)
+            .await
+    }
+
+    async fn deprecated_202101_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_202101_get_price_rules_param_rule_batch_code(
+            self,
+            price_rule_id,
+            batch_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_price_rules_param_rule_code(
+        &self,
+        price_rule_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_get_price_rules_param_rule_code(self, price_rule_id).await
+    }
+
+    async fn deprecated_unstable_create_price_rules_param_rule_codes(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_create_price_rules_param_rule_codes(
+            self,
+            price_rule_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_get_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_update_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_delete_price_rules_param_rule_codes_code(
+        &self,
+        price_rule_id: &str,
+        discount_code_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_delete_price_rules_param_rule_codes_code(
+            self,
+            price_rule_id,
+            discount_code_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_codes_lookup_with(
+        &self,
+        params: &DiscountsDeprecatedUnstableGetCodesLookupParams,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_get_codes_lookup_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_create_price_rules_param_rule_batch(
+            self,
+            price_rule_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_price_rules_param_rule_batch(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_get_price_rules_param_rule_batch(
+            self,
+            price_rule_id,
+            batch_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_price_rules_param_rule_batch_code(
+        &self,
+        price_rule_id: &str,
+        batch_id: &str,
+    ) -> Result<()> {
+        Discounts::deprecated_unstable_get_price_rules_param_rule_batch_code(
+            self,
+            price_rule_id,
+            batch_id,
+        )
+        .await
+    }
+}
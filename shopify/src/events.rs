@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Events {
     pub client: Client,
@@ -21,45 +19,32 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show only results after the specified ID.
-     * * `created_at_min: &str` -- Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `filter: &str` -- Show events specified in this filter.
-     * * `verb: &str` -- Show events of a certain type.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &EventsDeprecated202001GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get(
+    pub async fn deprecated_202001_get_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        filter: &str,
-        verb: &str,
-        fields: &str,
+        params: &EventsDeprecated202001GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !verb.is_empty() {
+        if let Some(verb) = &params.verb {
             query_args.push(("verb".to_string(), verb.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -68,6 +53,32 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_with` and pass the query parameters as a `EventsDeprecated202001GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_with` instead")]
+    pub async fn deprecated_202001_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202001GetParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            verb: Some(verb.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_with(&params).await
+    }
+
     /**
      * Retrieves a single event by its ID.
      *
@@ -78,11 +89,15 @@ impl Events {
      * **Parameters:**
      *
      * * `event_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &EventsDeprecated202001GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecated202001GetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -95,6 +110,18 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_param_with` and pass the query parameters as a `EventsDeprecated202001GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_param_with` instead")]
+    pub async fn deprecated_202001_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+        let params = EventsDeprecated202001GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_param_with(event_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a count of events.
      *
@@ -104,19 +131,17 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `created_at_min: &str` -- Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &EventsDeprecated202001GetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_count(
+    pub async fn deprecated_202001_get_count_with(
         &self,
-        created_at_min: &str,
-        created_at_max: &str,
+        params: &EventsDeprecated202001GetCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -125,6 +150,22 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_count_with` and pass the query parameters as a `EventsDeprecated202001GetCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_count_with` instead")]
+    pub async fn deprecated_202001_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202001GetCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_count_with(&params).await
+    }
+
     /**
      * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -134,45 +175,32 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show only results after the specified ID.
-     * * `created_at_min: &str` -- Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `filter: &str` -- Show events specified in this filter.
-     * * `verb: &str` -- Show events of a certain type.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &EventsDeprecated202004GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get(
+    pub async fn deprecated_202004_get_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        filter: &str,
-        verb: &str,
-        fields: &str,
+        params: &EventsDeprecated202004GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !verb.is_empty() {
+        if let Some(verb) = &params.verb {
             query_args.push(("verb".to_string(), verb.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -181,6 +209,32 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_with` and pass the query parameters as a `EventsDeprecated202004GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_with` instead")]
+    pub async fn deprecated_202004_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202004GetParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            verb: Some(verb.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_with(&params).await
+    }
+
     /**
      * Retrieves a single event by its ID.
      *
@@ -191,11 +245,15 @@ impl Events {
      * **Parameters:**
      *
      * * `event_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &EventsDeprecated202004GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecated202004GetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -208,6 +266,18 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_param_with` and pass the query parameters as a `EventsDeprecated202004GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_param_with` instead")]
+    pub async fn deprecated_202004_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+        let params = EventsDeprecated202004GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_param_with(event_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a count of events.
      *
@@ -217,19 +287,17 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `created_at_min: &str` -- Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &EventsDeprecated202004GetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_count(
+    pub async fn deprecated_202004_get_count_with(
         &self,
-        created_at_min: &str,
-        created_at_max: &str,
+        params: &EventsDeprecated202004GetCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -238,6 +306,22 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_count_with` and pass the query parameters as a `EventsDeprecated202004GetCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_count_with` instead")]
+    pub async fn deprecated_202004_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202004GetCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_count_with(&params).await
+    }
+
     /**
      * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -247,45 +331,32 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show only results after the specified ID.
-     * * `created_at_min: &str` -- Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `filter: &str` -- Show events specified in this filter.
-     * * `verb: &str` -- Show events of a certain type.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &EventsDeprecated202007GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get(
+    pub async fn deprecated_202007_get_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        filter: &str,
-        verb: &str,
-        fields: &str,
+        params: &EventsDeprecated202007GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !verb.is_empty() {
+        if let Some(verb) = &params.verb {
             query_args.push(("verb".to_string(), verb.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -294,6 +365,32 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_with` and pass the query parameters as a `EventsDeprecated202007GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_with` instead")]
+    pub async fn deprecated_202007_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202007GetParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            verb: Some(verb.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_with(&params).await
+    }
+
     /**
      * Retrieves a single event by its ID.
      *
@@ -304,11 +401,15 @@ impl Events {
      * **Parameters:**
      *
      * * `event_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &EventsDeprecated202007GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecated202007GetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -321,6 +422,18 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_param_with` and pass the query parameters as a `EventsDeprecated202007GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_param_with` instead")]
+    pub async fn deprecated_202007_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+        let params = EventsDeprecated202007GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_param_with(event_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a count of events.
      *
@@ -330,19 +443,17 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `created_at_min: &str` -- Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &EventsDeprecated202007GetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_count(
+    pub async fn deprecated_202007_get_count_with(
         &self,
-        created_at_min: &str,
-        created_at_max: &str,
+        params: &EventsDeprecated202007GetCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -351,6 +462,22 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_count_with` and pass the query parameters as a `EventsDeprecated202007GetCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_count_with` instead")]
+    pub async fn deprecated_202007_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202007GetCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_count_with(&params).await
+    }
+
     /**
      * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -360,45 +487,29 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show only results after the specified ID.
-     * * `created_at_min: &str` -- Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `filter: &str` -- Show events specified in this filter.
-     * * `verb: &str` -- Show events of a certain type.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &EventsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
-        &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        filter: &str,
-        verb: &str,
-        fields: &str,
-    ) -> Result<()> {
+    pub async fn get_with(&self, params: &EventsGetParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !verb.is_empty() {
+        if let Some(verb) = &params.verb {
             query_args.push(("verb".to_string(), verb.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -407,6 +518,32 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `EventsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = EventsGetParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            verb: Some(verb.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Retrieves a single event by its ID.
      *
@@ -417,11 +554,15 @@ impl Events {
      * **Parameters:**
      *
      * * `event_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &EventsGetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsGetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -434,6 +575,17 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_param_with` and pass the query parameters as a `EventsGetParamParams` instead.
+     */
+    #[deprecated(note = "use `get_param_with` instead")]
+    pub async fn get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+        let params = EventsGetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_param_with(event_id, &params).await
+    }
+
     /**
      * Retrieves a count of events.
      *
@@ -443,15 +595,14 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `created_at_min: &str` -- Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &EventsGetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_count(&self, created_at_min: &str, created_at_max: &str) -> Result<()> {
+    pub async fn get_count_with(&self, params: &EventsGetCountParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -460,6 +611,18 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_count_with` and pass the query parameters as a `EventsGetCountParams` instead.
+     */
+    #[deprecated(note = "use `get_count_with` instead")]
+    pub async fn get_count(&self, created_at_min: &str, created_at_max: &str) -> Result<()> {
+        let params = EventsGetCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_count_with(&params).await
+    }
+
     /**
      * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -469,45 +632,32 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show only results after the specified ID.
-     * * `created_at_min: &str` -- Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `filter: &str` -- Show events specified in this filter.
-     * * `verb: &str` -- Show events of a certain type.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &EventsDeprecated202101GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get(
+    pub async fn deprecated_202101_get_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        filter: &str,
-        verb: &str,
-        fields: &str,
+        params: &EventsDeprecated202101GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !verb.is_empty() {
+        if let Some(verb) = &params.verb {
             query_args.push(("verb".to_string(), verb.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -516,6 +666,32 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_with` and pass the query parameters as a `EventsDeprecated202101GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_with` instead")]
+    pub async fn deprecated_202101_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202101GetParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            verb: Some(verb.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_with(&params).await
+    }
+
     /**
      * Retrieves a single event by its ID.
      *
@@ -526,11 +702,15 @@ impl Events {
      * **Parameters:**
      *
      * * `event_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &EventsDeprecated202101GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecated202101GetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -543,6 +723,18 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_param_with` and pass the query parameters as a `EventsDeprecated202101GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_param_with` instead")]
+    pub async fn deprecated_202101_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+        let params = EventsDeprecated202101GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_param_with(event_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a count of events.
      *
@@ -552,19 +744,17 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `created_at_min: &str` -- Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &EventsDeprecated202101GetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_count(
+    pub async fn deprecated_202101_get_count_with(
         &self,
-        created_at_min: &str,
-        created_at_max: &str,
+        params: &EventsDeprecated202101GetCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -573,6 +763,22 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_count_with` and pass the query parameters as a `EventsDeprecated202101GetCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_count_with` instead")]
+    pub async fn deprecated_202101_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202101GetCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_count_with(&params).await
+    }
+
     /**
      * Retrieves a list of events. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -582,45 +788,32 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show only results after the specified ID.
-     * * `created_at_min: &str` -- Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `filter: &str` -- Show events specified in this filter.
-     * * `verb: &str` -- Show events of a certain type.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &EventsDeprecatedUnstableGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get(
+    pub async fn deprecated_unstable_get_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        filter: &str,
-        verb: &str,
-        fields: &str,
+        params: &EventsDeprecatedUnstableGetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !filter.is_empty() {
+        if let Some(filter) = &params.filter {
             query_args.push(("filter".to_string(), filter.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !verb.is_empty() {
+        if let Some(verb) = &params.verb {
             query_args.push(("verb".to_string(), verb.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -629,6 +822,32 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_with` and pass the query parameters as a `EventsDeprecatedUnstableGetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_with` instead")]
+    pub async fn deprecated_unstable_get(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        filter: &str,
+        verb: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecatedUnstableGetParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            filter: Some(filter.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            verb: Some(verb.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_with(&params).await
+    }
+
     /**
      * Retrieves a single event by its ID.
      *
@@ -639,11 +858,15 @@ impl Events {
      * **Parameters:**
      *
      * * `event_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &EventsDeprecatedUnstableGetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecatedUnstableGetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -656,6 +879,18 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_param_with` and pass the query parameters as a `EventsDeprecatedUnstableGetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_param_with` instead")]
+    pub async fn deprecated_unstable_get_param(&self, event_id: &str, fields: &str) -> Result<()> {
+        let params = EventsDeprecatedUnstableGetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_param_with(event_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a count of events.
      *
@@ -665,19 +900,17 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `created_at_min: &str` -- Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &EventsDeprecatedUnstableGetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_count(
+    pub async fn deprecated_unstable_get_count_with(
         &self,
-        created_at_min: &str,
-        created_at_max: &str,
+        params: &EventsDeprecatedUnstableGetCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -686,6 +919,22 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_count_with` and pass the query parameters as a `EventsDeprecatedUnstableGetCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_count_with` instead")]
+    pub async fn deprecated_unstable_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecatedUnstableGetCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_count_with(&params).await
+    }
+
     /**
      * Retrieves a list of webhooks. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -695,56 +944,38 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `created_at_max: &str` -- Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     * * `limit: &str` -- Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     * * `updated_at_min: &str` -- Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &EventsDeprecated202001GetWebhookParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_webhook(
+    pub async fn deprecated_202001_get_webhook_with(
         &self,
-        address: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        topic: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
+        params: &EventsDeprecated202001GetWebhookParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
+        if let Some(address) = &params.address {
             query_args.push(("address".to_string(), address.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !topic.is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("topic".to_string(), topic.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -753,6 +984,36 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_webhook_with` and pass the query parameters as a `EventsDeprecated202001GetWebhookParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_webhook_with` instead")]
+    pub async fn deprecated_202001_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202001GetWebhookParams {
+            address: Some(address.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            topic: Some(topic.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_webhook_with(&params).await
+    }
+
     /**
      * Create a new webhook subscription by specifying both an address and a topic.
      *
@@ -762,16 +1023,15 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `format: &str` -- Use this parameter to select the data format for the payload. Valid values are json and xml.
-     *                     (default: json).
+     * * `params: &EventsDeprecated202001CreateWebhooksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_create_webhooks(
+    pub async fn deprecated_202001_create_webhooks_with(
         &self,
-        format: &str,
+        params: &EventsDeprecated202001CreateWebhooksParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !format.is_empty() {
+        if let Some(format) = &params.format {
             query_args.push(("format".to_string(), format.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -782,6 +1042,22 @@ impl Events {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_webhooks_with` and pass the query parameters as a `EventsDeprecated202001CreateWebhooksParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_create_webhooks_with` instead")]
+    pub async fn deprecated_202001_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = EventsDeprecated202001CreateWebhooksParams {
+            format: Some(format.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_create_webhooks_with(&params, body)
+            .await
+    }
+
     /**
      * Retrieves a count of existing webhook subscriptions.
      *
@@ -791,20 +1067,17 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
+     * * `params: &EventsDeprecated202001GetWebhooksCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_webhooks_count(
+    pub async fn deprecated_202001_get_webhooks_count_with(
         &self,
-        address: &str,
-        topic: &str,
+        params: &EventsDeprecated202001GetWebhooksCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
+        if let Some(address) = &params.address {
             query_args.push(("address".to_string(), address.to_string()));
         }
-        if !topic.is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("topic".to_string(), topic.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -813,6 +1086,23 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_webhooks_count_with` and pass the query parameters as a `EventsDeprecated202001GetWebhooksCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_webhooks_count_with` instead")]
+    pub async fn deprecated_202001_get_webhooks_count(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202001GetWebhooksCountParams {
+            address: Some(address.to_string()).filter(|s| !s.is_empty()),
+            topic: Some(topic.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_webhooks_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single webhook subscription.
      *
@@ -823,15 +1113,15 @@ impl Events {
      * **Parameters:**
      *
      * * `webhook_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+     * * `params: &EventsDeprecated202001GetWebhooksParamWebhookParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_webhooks_param_webhook(
+    pub async fn deprecated_202001_get_webhooks_param_webhook_with(
         &self,
         webhook_id: &str,
-        fields: &str,
+        params: &EventsDeprecated202001GetWebhooksParamWebhookParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -844,6 +1134,22 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_webhooks_param_webhook_with` and pass the query parameters as a `EventsDeprecated202001GetWebhooksParamWebhookParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_webhooks_param_webhook_with` instead")]
+    pub async fn deprecated_202001_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202001GetWebhooksParamWebhookParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_webhooks_param_webhook_with(webhook_id, &params)
+            .await
+    }
+
     /**
      * Update a webhook subscription's topic or address URIs.
      *
@@ -902,56 +1208,38 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `created_at_max: &str` -- Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     * * `limit: &str` -- Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     * * `updated_at_min: &str` -- Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &EventsDeprecated202004GetWebhookParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_webhook(
+    pub async fn deprecated_202004_get_webhook_with(
         &self,
-        address: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        topic: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
+        params: &EventsDeprecated202004GetWebhookParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
+        if let Some(address) = &params.address {
             query_args.push(("address".to_string(), address.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !topic.is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("topic".to_string(), topic.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -960,6 +1248,36 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_webhook_with` and pass the query parameters as a `EventsDeprecated202004GetWebhookParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_webhook_with` instead")]
+    pub async fn deprecated_202004_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202004GetWebhookParams {
+            address: Some(address.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            topic: Some(topic.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_webhook_with(&params).await
+    }
+
     /**
      * Create a new webhook subscription by specifying both an address and a topic.
      *
@@ -969,16 +1287,15 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `format: &str` -- Use this parameter to select the data format for the payload. Valid values are json and xml.
-     *                     (default: json).
+     * * `params: &EventsDeprecated202004CreateWebhooksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_create_webhooks(
+    pub async fn deprecated_202004_create_webhooks_with(
         &self,
-        format: &str,
+        params: &EventsDeprecated202004CreateWebhooksParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !format.is_empty() {
+        if let Some(format) = &params.format {
             query_args.push(("format".to_string(), format.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -989,6 +1306,22 @@ impl Events {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_webhooks_with` and pass the query parameters as a `EventsDeprecated202004CreateWebhooksParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_create_webhooks_with` instead")]
+    pub async fn deprecated_202004_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = EventsDeprecated202004CreateWebhooksParams {
+            format: Some(format.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_create_webhooks_with(&params, body)
+            .await
+    }
+
     /**
      * Retrieves a count of existing webhook subscriptions.
      *
@@ -998,20 +1331,17 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
+     * * `params: &EventsDeprecated202004GetWebhooksCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_webhooks_count(
+    pub async fn deprecated_202004_get_webhooks_count_with(
         &self,
-        address: &str,
-        topic: &str,
+        params: &EventsDeprecated202004GetWebhooksCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
+        if let Some(address) = &params.address {
             query_args.push(("address".to_string(), address.to_string()));
         }
-        if !topic.is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("topic".to_string(), topic.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1020,6 +1350,23 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_webhooks_count_with` and pass the query parameters as a `EventsDeprecated202004GetWebhooksCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_webhooks_count_with` instead")]
+    pub async fn deprecated_202004_get_webhooks_count(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202004GetWebhooksCountParams {
+            address: Some(address.to_string()).filter(|s| !s.is_empty()),
+            topic: Some(topic.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_webhooks_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single webhook subscription.
      *
@@ -1030,15 +1377,15 @@ impl Events {
      * **Parameters:**
      *
      * * `webhook_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+     * * `params: &EventsDeprecated202004GetWebhooksParamWebhookParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_webhooks_param_webhook(
+    pub async fn deprecated_202004_get_webhooks_param_webhook_with(
         &self,
         webhook_id: &str,
-        fields: &str,
+        params: &EventsDeprecated202004GetWebhooksParamWebhookParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1051,6 +1398,22 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_webhooks_param_webhook_with` and pass the query parameters as a `EventsDeprecated202004GetWebhooksParamWebhookParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_webhooks_param_webhook_with` instead")]
+    pub async fn deprecated_202004_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202004GetWebhooksParamWebhookParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_webhooks_param_webhook_with(webhook_id, &params)
+            .await
+    }
+
     /**
      * Update a webhook subscription's topic or address URIs.
      *
@@ -1109,56 +1472,38 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `created_at_max: &str` -- Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     * * `limit: &str` -- Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     * * `updated_at_min: &str` -- Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &EventsDeprecated202007GetWebhookParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_webhook(
+    pub async fn deprecated_202007_get_webhook_with(
         &self,
-        address: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        topic: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
+        params: &EventsDeprecated202007GetWebhookParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
+        if let Some(address) = &params.address {
             query_args.push(("address".to_string(), address.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !topic.is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("topic".to_string(), topic.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1167,6 +1512,36 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_webhook_with` and pass the query parameters as a `EventsDeprecated202007GetWebhookParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_webhook_with` instead")]
+    pub async fn deprecated_202007_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202007GetWebhookParams {
+            address: Some(address.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            topic: Some(topic.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_webhook_with(&params).await
+    }
+
     /**
      * Create a new webhook subscription by specifying both an address and a topic.
      *
@@ -1176,16 +1551,15 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `format: &str` -- Use this parameter to select the data format for the payload. Valid values are json and xml.
-     *                     (default: json).
+     * * `params: &EventsDeprecated202007CreateWebhooksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_create_webhooks(
+    pub async fn deprecated_202007_create_webhooks_with(
         &self,
-        format: &str,
+        params: &EventsDeprecated202007CreateWebhooksParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !format.is_empty() {
+        if let Some(format) = &params.format {
             query_args.push(("format".to_string(), format.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1196,6 +1570,22 @@ impl Events {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_webhooks_with` and pass the query parameters as a `EventsDeprecated202007CreateWebhooksParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_create_webhooks_with` instead")]
+    pub async fn deprecated_202007_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = EventsDeprecated202007CreateWebhooksParams {
+            format: Some(format.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_create_webhooks_with(&params, body)
+            .await
+    }
+
     /**
      * Retrieves a count of existing webhook subscriptions.
      *
@@ -1205,20 +1595,17 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
+     * * `params: &EventsDeprecated202007GetWebhooksCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_webhooks_count(
+    pub async fn deprecated_202007_get_webhooks_count_with(
         &self,
-        address: &str,
-        topic: &str,
+        params: &EventsDeprecated202007GetWebhooksCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
+        if let Some(address) = &params.address {
             query_args.push(("address".to_string(), address.to_string()));
         }
-        if !topic.is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("topic".to_string(), topic.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1227,6 +1614,23 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_webhooks_count_with` and pass the query parameters as a `EventsDeprecated202007GetWebhooksCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_webhooks_count_with` instead")]
+    pub async fn deprecated_202007_get_webhooks_count(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202007GetWebhooksCountParams {
+            address: Some(address.to_string()).filter(|s| !s.is_empty()),
+            topic: Some(topic.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_webhooks_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single webhook subscription.
      *
@@ -1237,15 +1641,15 @@ impl Events {
      * **Parameters:**
      *
      * * `webhook_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+     * * `params: &EventsDeprecated202007GetWebhooksParamWebhookParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_webhooks_param_webhook(
+    pub async fn deprecated_202007_get_webhooks_param_webhook_with(
         &self,
         webhook_id: &str,
-        fields: &str,
+        params: &EventsDeprecated202007GetWebhooksParamWebhookParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1258,6 +1662,22 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_webhooks_param_webhook_with` and pass the query parameters as a `EventsDeprecated202007GetWebhooksParamWebhookParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_webhooks_param_webhook_with` instead")]
+    pub async fn deprecated_202007_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202007GetWebhooksParamWebhookParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_webhooks_param_webhook_with(webhook_id, &params)
+            .await
+    }
+
     /**
      * Update a webhook subscription's topic or address URIs.
      *
@@ -1316,56 +1736,35 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `created_at_max: &str` -- Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     * * `limit: &str` -- Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     * * `updated_at_min: &str` -- Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &EventsGetWebhookParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_webhook(
-        &self,
-        address: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        topic: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-    ) -> Result<()> {
+    pub async fn get_webhook_with(&self, params: &EventsGetWebhookParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
+        if let Some(address) = &params.address {
             query_args.push(("address".to_string(), address.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !topic.is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("topic".to_string(), topic.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1374,6 +1773,36 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_webhook_with` and pass the query parameters as a `EventsGetWebhookParams` instead.
+     */
+    #[deprecated(note = "use `get_webhook_with` instead")]
+    pub async fn get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        let params = EventsGetWebhookParams {
+            address: Some(address.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            topic: Some(topic.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_webhook_with(&params).await
+    }
+
     /**
      * Create a new webhook subscription by specifying both an address and a topic.
      *
@@ -1383,12 +1812,15 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `format: &str` -- Use this parameter to select the data format for the payload. Valid values are json and xml.
-     *                     (default: json).
+     * * `params: &EventsCreateWebhooksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create_webhooks(&self, format: &str, body: &serde_json::Value) -> Result<()> {
+    pub async fn create_webhooks_with(
+        &self,
+        params: &EventsCreateWebhooksParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !format.is_empty() {
+        if let Some(format) = &params.format {
             query_args.push(("format".to_string(), format.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1399,6 +1831,17 @@ impl Events {
             .await
     }
 
+    /**
+     * Deprecated: use `create_webhooks_with` and pass the query parameters as a `EventsCreateWebhooksParams` instead.
+     */
+    #[deprecated(note = "use `create_webhooks_with` instead")]
+    pub async fn create_webhooks(&self, format: &str, body: &serde_json::Value) -> Result<()> {
+        let params = EventsCreateWebhooksParams {
+            format: Some(format.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_webhooks_with(&params, body).await
+    }
+
     /**
      * Retrieves a count of existing webhook subscriptions.
      *
@@ -1408,16 +1851,17 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
+     * * `params: &EventsGetWebhooksCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_webhooks_count(&self, address: &str, topic: &str) -> Result<()> {
+    pub async fn get_webhooks_count_with(
+        &self,
+        params: &EventsGetWebhooksCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
+        if let Some(address) = &params.address {
             query_args.push(("address".to_string(), address.to_string()));
         }
-        if !topic.is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("topic".to_string(), topic.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1426,6 +1870,18 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_webhooks_count_with` and pass the query parameters as a `EventsGetWebhooksCountParams` instead.
+     */
+    #[deprecated(note = "use `get_webhooks_count_with` instead")]
+    pub async fn get_webhooks_count(&self, address: &str, topic: &str) -> Result<()> {
+        let params = EventsGetWebhooksCountParams {
+            address: Some(address.to_string()).filter(|s| !s.is_empty()),
+            topic: Some(topic.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_webhooks_count_with(&params).await
+    }
+
     /**
      * Retrieves a single webhook subscription.
      *
@@ -1436,11 +1892,15 @@ impl Events {
      * **Parameters:**
      *
      * * `webhook_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+     * * `params: &EventsGetWebhooksParamWebhookParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_webhooks_param_webhook(&self, webhook_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_webhooks_param_webhook_with(
+        &self,
+        webhook_id: &str,
+        params: &EventsGetWebhooksParamWebhookParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1453,6 +1913,18 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_webhooks_param_webhook_with` and pass the query parameters as a `EventsGetWebhooksParamWebhookParams` instead.
+     */
+    #[deprecated(note = "use `get_webhooks_param_webhook_with` instead")]
+    pub async fn get_webhooks_param_webhook(&self, webhook_id: &str, fields: &str) -> Result<()> {
+        let params = EventsGetWebhooksParamWebhookParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_webhooks_param_webhook_with(webhook_id, &params)
+            .await
+    }
+
     /**
      * Update a webhook subscription's topic or address URIs.
      *
@@ -1508,56 +1980,38 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `created_at_max: &str` -- Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     * * `limit: &str` -- Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     * * `updated_at_min: &str` -- Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &EventsDeprecated202101GetWebhookParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_webhook(
+    pub async fn deprecated_202101_get_webhook_with(
         &self,
-        address: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        topic: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
+        params: &EventsDeprecated202101GetWebhookParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
+        if let Some(address) = &params.address {
             query_args.push(("address".to_string(), address.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !topic.is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("topic".to_string(), topic.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1567,24 +2021,53 @@ impl Events {
     }
 
     /**
-     * Create a new webhook subscription by specifying both an address and a topic.
-     *
-     * This function performs a `POST` to the `/admin/api/2021-01/webhooks.json` endpoint.
-     *
+     * Deprecated: use `deprecated_202101_get_webhook_with` and pass the query parameters as a `EventsDeprecated202101GetWebhookParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_webhook_with` instead")]
+    pub async fn deprecated_202101_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202101GetWebhookParams {
+            address: Some(address.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            topic: Some(topic.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_webhook_with(&params).await
+    }
+
+    /**
+     * Create a new webhook subscription by specifying both an address and a topic.
+     *
+     * This function performs a `POST` to the `/admin/api/2021-01/webhooks.json` endpoint.
+     *
      * https://shopify.dev/docs/admin-api/rest/reference/events/webhook#create-2021-01
      *
      * **Parameters:**
      *
-     * * `format: &str` -- Use this parameter to select the data format for the payload. Valid values are json and xml.
-     *                     (default: json).
+     * * `params: &EventsDeprecated202101CreateWebhooksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_create_webhooks(
+    pub async fn deprecated_202101_create_webhooks_with(
         &self,
-        format: &str,
+        params: &EventsDeprecated202101CreateWebhooksParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !format.is_empty() {
+        if let Some(format) = &params.format {
             query_args.push(("format".to_string(), format.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1595,6 +2078,22 @@ impl Events {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_webhooks_with` and pass the query parameters as a `EventsDeprecated202101CreateWebhooksParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_create_webhooks_with` instead")]
+    pub async fn deprecated_202101_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = EventsDeprecated202101CreateWebhooksParams {
+            format: Some(format.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_create_webhooks_with(&params, body)
+            .await
+    }
+
     /**
      * Retrieves a count of existing webhook subscriptions.
      *
@@ -1604,20 +2103,17 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
+     * * `params: &EventsDeprecated202101GetWebhooksCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_webhooks_count(
+    pub async fn deprecated_202101_get_webhooks_count_with(
         &self,
-        address: &str,
-        topic: &str,
+        params: &EventsDeprecated202101GetWebhooksCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
+        if let Some(address) = &params.address {
             query_args.push(("address".to_string(), address.to_string()));
         }
-        if !topic.is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("topic".to_string(), topic.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1626,6 +2122,23 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_webhooks_count_with` and pass the query parameters as a `EventsDeprecated202101GetWebhooksCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_webhooks_count_with` instead")]
+    pub async fn deprecated_202101_get_webhooks_count(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202101GetWebhooksCountParams {
+            address: Some(address.to_string()).filter(|s| !s.is_empty()),
+            topic: Some(topic.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_webhooks_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single webhook subscription.
      *
@@ -1636,15 +2149,15 @@ impl Events {
      * **Parameters:**
      *
      * * `webhook_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+     * * `params: &EventsDeprecated202101GetWebhooksParamWebhookParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_webhooks_param_webhook(
+    pub async fn deprecated_202101_get_webhooks_param_webhook_with(
         &self,
         webhook_id: &str,
-        fields: &str,
+        params: &EventsDeprecated202101GetWebhooksParamWebhookParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1657,6 +2170,22 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_webhooks_param_webhook_with` and pass the query parameters as a `EventsDeprecated202101GetWebhooksParamWebhookParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_webhooks_param_webhook_with` instead")]
+    pub async fn deprecated_202101_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecated202101GetWebhooksParamWebhookParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_webhooks_param_webhook_with(webhook_id, &params)
+            .await
+    }
+
     /**
      * Update a webhook subscription's topic or address URIs.
      *
@@ -1715,56 +2244,38 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `created_at_max: &str` -- Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
-     * * `limit: &str` -- Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
-     * * `updated_at_min: &str` -- Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &EventsDeprecatedUnstableGetWebhookParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_webhook(
+    pub async fn deprecated_unstable_get_webhook_with(
         &self,
-        address: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        topic: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
+        params: &EventsDeprecatedUnstableGetWebhookParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
+        if let Some(address) = &params.address {
             query_args.push(("address".to_string(), address.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !topic.is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("topic".to_string(), topic.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1773,6 +2284,36 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_webhook_with` and pass the query parameters as a `EventsDeprecatedUnstableGetWebhookParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_webhook_with` instead")]
+    pub async fn deprecated_unstable_get_webhook(
+        &self,
+        address: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        topic: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecatedUnstableGetWebhookParams {
+            address: Some(address.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            topic: Some(topic.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_webhook_with(&params).await
+    }
+
     /**
      * Create a new webhook subscription by specifying both an address and a topic.
      *
@@ -1782,16 +2323,15 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `format: &str` -- Use this parameter to select the data format for the payload. Valid values are json and xml.
-     *                     (default: json).
+     * * `params: &EventsDeprecatedUnstableCreateWebhooksParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_create_webhooks(
+    pub async fn deprecated_unstable_create_webhooks_with(
         &self,
-        format: &str,
+        params: &EventsDeprecatedUnstableCreateWebhooksParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !format.is_empty() {
+        if let Some(format) = &params.format {
             query_args.push(("format".to_string(), format.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1802,6 +2342,22 @@ impl Events {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_create_webhooks_with` and pass the query parameters as a `EventsDeprecatedUnstableCreateWebhooksParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_create_webhooks_with` instead")]
+    pub async fn deprecated_unstable_create_webhooks(
+        &self,
+        format: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = EventsDeprecatedUnstableCreateWebhooksParams {
+            format: Some(format.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_create_webhooks_with(&params, body)
+            .await
+    }
+
     /**
      * Retrieves a count of existing webhook subscriptions.
      *
@@ -1811,20 +2367,17 @@ impl Events {
      *
      * **Parameters:**
      *
-     * * `address: &str` -- Retrieve webhook subscriptions that send the POST request to this URI.
-     * * `topic: &str` -- Show webhook subscriptions with a given topic.
-     *   For a list of valid values, refer to the topic property.>.
+     * * `params: &EventsDeprecatedUnstableGetWebhooksCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_webhooks_count(
+    pub async fn deprecated_unstable_get_webhooks_count_with(
         &self,
-        address: &str,
-        topic: &str,
+        params: &EventsDeprecatedUnstableGetWebhooksCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !address.is_empty() {
+        if let Some(address) = &params.address {
             query_args.push(("address".to_string(), address.to_string()));
         }
-        if !topic.is_empty() {
+        if let Some(topic) = &params.topic {
             query_args.push(("topic".to_string(), topic.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1833,6 +2386,23 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_webhooks_count_with` and pass the query parameters as a `EventsDeprecatedUnstableGetWebhooksCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_webhooks_count_with` instead")]
+    pub async fn deprecated_unstable_get_webhooks_count(
+        &self,
+        address: &str,
+        topic: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecatedUnstableGetWebhooksCountParams {
+            address: Some(address.to_string()).filter(|s| !s.is_empty()),
+            topic: Some(topic.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_webhooks_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single webhook subscription.
      *
@@ -1843,15 +2413,15 @@ impl Events {
      * **Parameters:**
      *
      * * `webhook_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+     * * `params: &EventsDeprecatedUnstableGetWebhooksParamWebhookParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_webhooks_param_webhook(
+    pub async fn deprecated_unstable_get_webhooks_param_webhook_with(
         &self,
         webhook_id: &str,
-        fields: &str,
+        params: &EventsDeprecatedUnstableGetWebhooksParamWebhookParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1864,6 +2434,22 @@ impl Events {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_webhooks_param_webhook_with` and pass the query parameters as a `EventsDeprecatedUnstableGetWebhooksParamWebhookParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_webhooks_param_webhook_with` instead")]
+    pub async fn deprecated_unstable_get_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = EventsDeprecatedUnstableGetWebhooksParamWebhookParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_webhooks_param_webhook_with(webhook_id, &params)
+            .await
+    }
+
     /**
      * Update a webhook subscription's topic or address URIs.
      *
@@ -1913,3 +2499,2006 @@ impl Events {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Events::deprecated_202001_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202001GetParams {
+    /// Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show events specified in this filter.
+    pub filter: Option<String>,
+    /// The number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show only results after the specified ID.
+    pub since_id: Option<String>,
+    /// Show events of a certain type.
+    pub verb: Option<String>,
+}
+
+impl EventsDeprecated202001GetParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn verb<T: ToString>(mut self, verb: T) -> Self {
+        self.verb = Some(verb.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202001_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202001GetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl EventsDeprecated202001GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202001_get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202001GetCountParams {
+    /// Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+}
+
+impl EventsDeprecated202001GetCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202004_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202004GetParams {
+    /// Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show events specified in this filter.
+    pub filter: Option<String>,
+    /// The number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show only results after the specified ID.
+    pub since_id: Option<String>,
+    /// Show events of a certain type.
+    pub verb: Option<String>,
+}
+
+impl EventsDeprecated202004GetParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn verb<T: ToString>(mut self, verb: T) -> Self {
+        self.verb = Some(verb.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202004_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202004GetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl EventsDeprecated202004GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202004_get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202004GetCountParams {
+    /// Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+}
+
+impl EventsDeprecated202004GetCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202007_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202007GetParams {
+    /// Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show events specified in this filter.
+    pub filter: Option<String>,
+    /// The number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show only results after the specified ID.
+    pub since_id: Option<String>,
+    /// Show events of a certain type.
+    pub verb: Option<String>,
+}
+
+impl EventsDeprecated202007GetParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn verb<T: ToString>(mut self, verb: T) -> Self {
+        self.verb = Some(verb.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202007_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202007GetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl EventsDeprecated202007GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202007_get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202007GetCountParams {
+    /// Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+}
+
+impl EventsDeprecated202007GetCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsGetParams {
+    /// Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show events specified in this filter.
+    pub filter: Option<String>,
+    /// The number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show only results after the specified ID.
+    pub since_id: Option<String>,
+    /// Show events of a certain type.
+    pub verb: Option<String>,
+}
+
+impl EventsGetParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn verb<T: ToString>(mut self, verb: T) -> Self {
+        self.verb = Some(verb.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsGetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl EventsGetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsGetCountParams {
+    /// Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+}
+
+impl EventsGetCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202101_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202101GetParams {
+    /// Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show events specified in this filter.
+    pub filter: Option<String>,
+    /// The number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show only results after the specified ID.
+    pub since_id: Option<String>,
+    /// Show events of a certain type.
+    pub verb: Option<String>,
+}
+
+impl EventsDeprecated202101GetParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn verb<T: ToString>(mut self, verb: T) -> Self {
+        self.verb = Some(verb.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202101_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202101GetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl EventsDeprecated202101GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202101_get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202101GetCountParams {
+    /// Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+}
+
+impl EventsDeprecated202101GetCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_unstable_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecatedUnstableGetParams {
+    /// Show events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show events specified in this filter.
+    pub filter: Option<String>,
+    /// The number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show only results after the specified ID.
+    pub since_id: Option<String>,
+    /// Show events of a certain type.
+    pub verb: Option<String>,
+}
+
+impl EventsDeprecatedUnstableGetParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn filter<T: ToString>(mut self, filter: T) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn verb<T: ToString>(mut self, verb: T) -> Self {
+        self.verb = Some(verb.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_unstable_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecatedUnstableGetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl EventsDeprecatedUnstableGetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_unstable_get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecatedUnstableGetCountParams {
+    /// Count only events created at or before this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count only events created at or after this date and time. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+}
+
+impl EventsDeprecatedUnstableGetCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202001_get_webhook_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202001GetWebhookParams {
+    /// Retrieve webhook subscriptions that send the POST request to this URI.
+    pub address: Option<String>,
+    /// Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+    pub fields: Option<String>,
+    /// Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
+    pub since_id: Option<String>,
+    /// Show webhook subscriptions with a given topic.
+    /// For a list of valid values, refer to the topic property.>.
+    pub topic: Option<String>,
+    /// Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl EventsDeprecated202001GetWebhookParams {
+    pub fn address<T: ToString>(mut self, address: T) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn topic<T: ToString>(mut self, topic: T) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202001_create_webhooks_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202001CreateWebhooksParams {
+    /// Use this parameter to select the data format for the payload. Valid values are json and xml.
+    /// (default: json).
+    pub format: Option<String>,
+}
+
+impl EventsDeprecated202001CreateWebhooksParams {
+    pub fn format<T: ToString>(mut self, format: T) -> Self {
+        self.format = Some(format.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202001_get_webhooks_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202001GetWebhooksCountParams {
+    /// Retrieve webhook subscriptions that send the POST request to this URI.
+    pub address: Option<String>,
+    /// Show webhook subscriptions with a given topic.
+    /// For a list of valid values, refer to the topic property.>.
+    pub topic: Option<String>,
+}
+
+impl EventsDeprecated202001GetWebhooksCountParams {
+    pub fn address<T: ToString>(mut self, address: T) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn topic<T: ToString>(mut self, topic: T) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202001_get_webhooks_param_webhook_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202001GetWebhooksParamWebhookParams {
+    /// Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+    pub fields: Option<String>,
+}
+
+impl EventsDeprecated202001GetWebhooksParamWebhookParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202004_get_webhook_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202004GetWebhookParams {
+    /// Retrieve webhook subscriptions that send the POST request to this URI.
+    pub address: Option<String>,
+    /// Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+    pub fields: Option<String>,
+    /// Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
+    pub since_id: Option<String>,
+    /// Show webhook subscriptions with a given topic.
+    /// For a list of valid values, refer to the topic property.>.
+    pub topic: Option<String>,
+    /// Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl EventsDeprecated202004GetWebhookParams {
+    pub fn address<T: ToString>(mut self, address: T) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn topic<T: ToString>(mut self, topic: T) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202004_create_webhooks_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202004CreateWebhooksParams {
+    /// Use this parameter to select the data format for the payload. Valid values are json and xml.
+    /// (default: json).
+    pub format: Option<String>,
+}
+
+impl EventsDeprecated202004CreateWebhooksParams {
+    pub fn format<T: ToString>(mut self, format: T) -> Self {
+        self.format = Some(format.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202004_get_webhooks_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202004GetWebhooksCountParams {
+    /// Retrieve webhook subscriptions that send the POST request to this URI.
+    pub address: Option<String>,
+    /// Show webhook subscriptions with a given topic.
+    /// For a list of valid values, refer to the topic property.>.
+    pub topic: Option<String>,
+}
+
+impl EventsDeprecated202004GetWebhooksCountParams {
+    pub fn address<T: ToString>(mut self, address: T) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn topic<T: ToString>(mut self, topic: T) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202004_get_webhooks_param_webhook_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202004GetWebhooksParamWebhookParams {
+    /// Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+    pub fields: Option<String>,
+}
+
+impl EventsDeprecated202004GetWebhooksParamWebhookParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202007_get_webhook_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202007GetWebhookParams {
+    /// Retrieve webhook subscriptions that send the POST request to this URI.
+    pub address: Option<String>,
+    /// Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+    pub fields: Option<String>,
+    /// Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
+    pub since_id: Option<String>,
+    /// Show webhook subscriptions with a given topic.
+    /// For a list of valid values, refer to the topic property.>.
+    pub topic: Option<String>,
+    /// Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl EventsDeprecated202007GetWebhookParams {
+    pub fn address<T: ToString>(mut self, address: T) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn topic<T: ToString>(mut self, topic: T) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202007_create_webhooks_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202007CreateWebhooksParams {
+    /// Use this parameter to select the data format for the payload. Valid values are json and xml.
+    /// (default: json).
+    pub format: Option<String>,
+}
+
+impl EventsDeprecated202007CreateWebhooksParams {
+    pub fn format<T: ToString>(mut self, format: T) -> Self {
+        self.format = Some(format.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202007_get_webhooks_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202007GetWebhooksCountParams {
+    /// Retrieve webhook subscriptions that send the POST request to this URI.
+    pub address: Option<String>,
+    /// Show webhook subscriptions with a given topic.
+    /// For a list of valid values, refer to the topic property.>.
+    pub topic: Option<String>,
+}
+
+impl EventsDeprecated202007GetWebhooksCountParams {
+    pub fn address<T: ToString>(mut self, address: T) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn topic<T: ToString>(mut self, topic: T) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202007_get_webhooks_param_webhook_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202007GetWebhooksParamWebhookParams {
+    /// Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+    pub fields: Option<String>,
+}
+
+impl EventsDeprecated202007GetWebhooksParamWebhookParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::get_webhook_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsGetWebhookParams {
+    /// Retrieve webhook subscriptions that send the POST request to this URI.
+    pub address: Option<String>,
+    /// Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+    pub fields: Option<String>,
+    /// Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
+    pub since_id: Option<String>,
+    /// Show webhook subscriptions with a given topic.
+    /// For a list of valid values, refer to the topic property.>.
+    pub topic: Option<String>,
+    /// Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl EventsGetWebhookParams {
+    pub fn address<T: ToString>(mut self, address: T) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn topic<T: ToString>(mut self, topic: T) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::create_webhooks_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsCreateWebhooksParams {
+    /// Use this parameter to select the data format for the payload. Valid values are json and xml.
+    /// (default: json).
+    pub format: Option<String>,
+}
+
+impl EventsCreateWebhooksParams {
+    pub fn format<T: ToString>(mut self, format: T) -> Self {
+        self.format = Some(format.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::get_webhooks_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsGetWebhooksCountParams {
+    /// Retrieve webhook subscriptions that send the POST request to this URI.
+    pub address: Option<String>,
+    /// Show webhook subscriptions with a given topic.
+    /// For a list of valid values, refer to the topic property.>.
+    pub topic: Option<String>,
+}
+
+impl EventsGetWebhooksCountParams {
+    pub fn address<T: ToString>(mut self, address: T) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn topic<T: ToString>(mut self, topic: T) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::get_webhooks_param_webhook_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsGetWebhooksParamWebhookParams {
+    /// Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+    pub fields: Option<String>,
+}
+
+impl EventsGetWebhooksParamWebhookParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202101_get_webhook_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202101GetWebhookParams {
+    /// Retrieve webhook subscriptions that send the POST request to this URI.
+    pub address: Option<String>,
+    /// Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+    pub fields: Option<String>,
+    /// Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
+    pub since_id: Option<String>,
+    /// Show webhook subscriptions with a given topic.
+    /// For a list of valid values, refer to the topic property.>.
+    pub topic: Option<String>,
+    /// Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl EventsDeprecated202101GetWebhookParams {
+    pub fn address<T: ToString>(mut self, address: T) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn topic<T: ToString>(mut self, topic: T) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202101_create_webhooks_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202101CreateWebhooksParams {
+    /// Use this parameter to select the data format for the payload. Valid values are json and xml.
+    /// (default: json).
+    pub format: Option<String>,
+}
+
+impl EventsDeprecated202101CreateWebhooksParams {
+    pub fn format<T: ToString>(mut self, format: T) -> Self {
+        self.format = Some(format.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202101_get_webhooks_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202101GetWebhooksCountParams {
+    /// Retrieve webhook subscriptions that send the POST request to this URI.
+    pub address: Option<String>,
+    /// Show webhook subscriptions with a given topic.
+    /// For a list of valid values, refer to the topic property.>.
+    pub topic: Option<String>,
+}
+
+impl EventsDeprecated202101GetWebhooksCountParams {
+    pub fn address<T: ToString>(mut self, address: T) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn topic<T: ToString>(mut self, topic: T) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_202101_get_webhooks_param_webhook_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecated202101GetWebhooksParamWebhookParams {
+    /// Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+    pub fields: Option<String>,
+}
+
+impl EventsDeprecated202101GetWebhooksParamWebhookParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_unstable_get_webhook_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecatedUnstableGetWebhookParams {
+    /// Retrieve webhook subscriptions that send the POST request to this URI.
+    pub address: Option<String>,
+    /// Retrieve webhook subscriptions that were created before a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Retrieve webhook subscriptions that were created after a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+    pub fields: Option<String>,
+    /// Maximum number of webhook subscriptions that should be returned. Setting this parameter outside the maximum range will return an error.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict the returned list to webhook subscriptions whose id is greater than the specified since_id.
+    pub since_id: Option<String>,
+    /// Show webhook subscriptions with a given topic.
+    /// For a list of valid values, refer to the topic property.>.
+    pub topic: Option<String>,
+    /// Retrieve webhooks that were updated after a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Retrieve webhooks that were updated before a given date and time (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl EventsDeprecatedUnstableGetWebhookParams {
+    pub fn address<T: ToString>(mut self, address: T) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn topic<T: ToString>(mut self, topic: T) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_unstable_create_webhooks_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecatedUnstableCreateWebhooksParams {
+    /// Use this parameter to select the data format for the payload. Valid values are json and xml.
+    /// (default: json).
+    pub format: Option<String>,
+}
+
+impl EventsDeprecatedUnstableCreateWebhooksParams {
+    pub fn format<T: ToString>(mut self, format: T) -> Self {
+        self.format = Some(format.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_unstable_get_webhooks_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecatedUnstableGetWebhooksCountParams {
+    /// Retrieve webhook subscriptions that send the POST request to this URI.
+    pub address: Option<String>,
+    /// Show webhook subscriptions with a given topic.
+    /// For a list of valid values, refer to the topic property.>.
+    pub topic: Option<String>,
+}
+
+impl EventsDeprecatedUnstableGetWebhooksCountParams {
+    pub fn address<T: ToString>(mut self, address: T) -> Self {
+        self.address = Some(address.to_string());
+        self
+    }
+
+    pub fn topic<T: ToString>(mut self, topic: T) -> Self {
+        self.topic = Some(topic.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Events::deprecated_unstable_get_webhooks_param_webhook_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct EventsDeprecatedUnstableGetWebhooksParamWebhookParams {
+    /// Comma-separated list of the properties you want returned for each item in the result list. Use this parameter to restrict the returned list of items to only those properties you specify.
+    pub fields: Option<String>,
+}
+
+impl EventsDeprecatedUnstableGetWebhooksParamWebhookParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The functions of [`Events`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEventsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EventsApi: Send + Sync {
+    /// See [`Events::deprecated_202001_get_with`].
+    async fn deprecated_202001_get_with(
+        &self,
+        params: &EventsDeprecated202001GetParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202001_get_param_with`].
+    async fn deprecated_202001_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecated202001GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202001_get_count_with`].
+    async fn deprecated_202001_get_count_with(
+        &self,
+        params: &EventsDeprecated202001GetCountParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202004_get_with`].
+    async fn deprecated_202004_get_with(
+        &self,
+        params: &EventsDeprecated202004GetParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202004_get_param_with`].
+    async fn deprecated_202004_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecated202004GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202004_get_count_with`].
+    async fn deprecated_202004_get_count_with(
+        &self,
+        params: &EventsDeprecated202004GetCountParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202007_get_with`].
+    async fn deprecated_202007_get_with(
+        &self,
+        params: &EventsDeprecated202007GetParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202007_get_param_with`].
+    async fn deprecated_202007_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecated202007GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202007_get_count_with`].
+    async fn deprecated_202007_get_count_with(
+        &self,
+        params: &EventsDeprecated202007GetCountParams,
+    ) -> Result<()>;
+
+    /// See [`Events::get_with`].
+    async fn get_with(&self, params: &EventsGetParams) -> Result<()>;
+
+    /// See [`Events::get_param_with`].
+    async fn get_param_with(&self, event_id: &str, params: &EventsGetParamParams) -> Result<()>;
+
+    /// See [`Events::get_count_with`].
+    async fn get_count_with(&self, params: &EventsGetCountParams) -> Result<()>;
+
+    /// See [`Events::deprecated_202101_get_with`].
+    async fn deprecated_202101_get_with(
+        &self,
+        params: &EventsDeprecated202101GetParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202101_get_param_with`].
+    async fn deprecated_202101_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecated202101GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202101_get_count_with`].
+    async fn deprecated_202101_get_count_with(
+        &self,
+        params: &EventsDeprecated202101GetCountParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_unstable_get_with`].
+    async fn deprecated_unstable_get_with(
+        &self,
+        params: &EventsDeprecatedUnstableGetParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_unstable_get_param_with`].
+    async fn deprecated_unstable_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecatedUnstableGetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_unstable_get_count_with`].
+    async fn deprecated_unstable_get_count_with(
+        &self,
+        params: &EventsDeprecatedUnstableGetCountParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202001_get_webhook_with`].
+    async fn deprecated_202001_get_webhook_with(
+        &self,
+        params: &EventsDeprecated202001GetWebhookParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202001_create_webhooks_with`].
+    async fn deprecated_202001_create_webhooks_with(
+        &self,
+        params: &EventsDeprecated202001CreateWebhooksParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202001_get_webhooks_count_with`].
+    async fn deprecated_202001_get_webhooks_count_with(
+        &self,
+        params: &EventsDeprecated202001GetWebhooksCountParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202001_get_webhooks_param_webhook_with`].
+    async fn deprecated_202001_get_webhooks_param_webhook_with(
+        &self,
+        webhook_id: &str,
+        params: &EventsDeprecated202001GetWebhooksParamWebhookParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202001_update_webhooks_param_webhook`].
+    async fn deprecated_202001_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202001_delete_webhooks_param_webhook`].
+    async fn deprecated_202001_delete_webhooks_param_webhook(&self, webhook_id: &str)
+        -> Result<()>;
+
+    /// See [`Events::deprecated_202004_get_webhook_with`].
+    async fn deprecated_202004_get_webhook_with(
+        &self,
+        params: &EventsDeprecated202004GetWebhookParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202004_create_webhooks_with`].
+    async fn deprecated_202004_create_webhooks_with(
+        &self,
+        params: &EventsDeprecated202004CreateWebhooksParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202004_get_webhooks_count_with`].
+    async fn deprecated_202004_get_webhooks_count_with(
+        &self,
+        params: &EventsDeprecated202004GetWebhooksCountParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202004_get_webhooks_param_webhook_with`].
+    async fn deprecated_202004_get_webhooks_param_webhook_with(
+        &self,
+        webhook_id: &str,
+        params: &EventsDeprecated202004GetWebhooksParamWebhookParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202004_update_webhooks_param_webhook`].
+    async fn deprecated_202004_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202004_delete_webhooks_param_webhook`].
+    async fn deprecated_202004_delete_webhooks_param_webhook(&self, webhook_id: &str)
+        -> Result<()>;
+
+    /// See [`Events::deprecated_202007_get_webhook_with`].
+    async fn deprecated_202007_get_webhook_with(
+        &self,
+        params: &EventsDeprecated202007GetWebhookParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202007_create_webhooks_with`].
+    async fn deprecated_202007_create_webhooks_with(
+        &self,
+        params: &EventsDeprecated202007CreateWebhooksParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202007_get_webhooks_count_with`].
+    async fn deprecated_202007_get_webhooks_count_with(
+        &self,
+        params: &EventsDeprecated202007GetWebhooksCountParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202007_get_webhooks_param_webhook_with`].
+    async fn deprecated_202007_get_webhooks_param_webhook_with(
+        &self,
+        webhook_id: &str,
+        params: &EventsDeprecated202007GetWebhooksParamWebhookParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202007_update_webhooks_param_webhook`].
+    async fn deprecated_202007_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202007_delete_webhooks_param_webhook`].
+    async fn deprecated_202007_delete_webhooks_param_webhook(&self, webhook_id: &str)
+        -> Result<()>;
+
+    /// See [`Events::get_webhook_with`].
+    async fn get_webhook_with(&self, params: &EventsGetWebhookParams) -> Result<()>;
+
+    /// See [`Events::create_webhooks_with`].
+    async fn create_webhooks_with(
+        &self,
+        params: &EventsCreateWebhooksParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Events::get_webhooks_count_with`].
+    async fn get_webhooks_count_with(&self, params: &EventsGetWebhooksCountParams) -> Result<()>;
+
+    /// See [`Events::get_webhooks_param_webhook_with`].
+    async fn get_webhooks_param_webhook_with(
+        &self,
+        webhook_id: &str,
+        params: &EventsGetWebhooksParamWebhookParams,
+    ) -> Result<()>;
+
+    /// See [`Events::update_webhooks_param_webhook`].
+    async fn update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Events::delete_webhooks_param_webhook`].
+    async fn delete_webhooks_param_webhook(&self, webhook_id: &str) -> Result<()>;
+
+    /// See [`Events::deprecated_202101_get_webhook_with`].
+    async fn deprecated_202101_get_webhook_with(
+        &self,
+        params: &EventsDeprecated202101GetWebhookParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202101_create_webhooks_with`].
+    async fn deprecated_202101_create_webhooks_with(
+        &self,
+        params: &EventsDeprecated202101CreateWebhooksParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202101_get_webhooks_count_with`].
+    async fn deprecated_202101_get_webhooks_count_with(
+        &self,
+        params: &EventsDeprecated202101GetWebhooksCountParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202101_get_webhooks_param_webhook_with`].
+    async fn deprecated_202101_get_webhooks_param_webhook_with(
+        &self,
+        webhook_id: &str,
+        params: &EventsDeprecated202101GetWebhooksParamWebhookParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202101_update_webhooks_param_webhook`].
+    async fn deprecated_202101_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_202101_delete_webhooks_param_webhook`].
+    async fn deprecated_202101_delete_webhooks_param_webhook(&self, webhook_id: &str)
+        -> Result<()>;
+
+    /// See [`Events::deprecated_unstable_get_webhook_with`].
+    async fn deprecated_unstable_get_webhook_with(
+        &self,
+        params: &EventsDeprecatedUnstableGetWebhookParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_unstable_create_webhooks_with`].
+    async fn deprecated_unstable_create_webhooks_with(
+        &self,
+        params: &EventsDeprecatedUnstableCreateWebhooksParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_unstable_get_webhooks_count_with`].
+    async fn deprecated_unstable_get_webhooks_count_with(
+        &self,
+        params: &EventsDeprecatedUnstableGetWebhooksCountParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_unstable_get_webhooks_param_webhook_with`].
+    async fn deprecated_unstable_get_webhooks_param_webhook_with(
+        &self,
+        webhook_id: &str,
+        params: &EventsDeprecatedUnstableGetWebhooksParamWebhookParams,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_unstable_update_webhooks_param_webhook`].
+    async fn deprecated_unstable_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Events::deprecated_unstable_delete_webhooks_param_webhook`].
+    async fn deprecated_unstable_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl EventsApi for Events {
+    async fn deprecated_202001_get_with(
+        &self,
+        params: &EventsDeprecated202001GetParams,
+    ) -> Result<()> {
+        Events::deprecated_202001_get_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecated202001GetParamParams,
+    ) -> Result<()> {
+        Events::deprecated_202001_get_param_with(self, event_id, params).await
+    }
+
+    async fn deprecated_202001_get_count_with(
+        &self,
+        params: &EventsDeprecated202001GetCountParams,
+    ) -> Result<()> {
+        Events::deprecated_202001_get_count_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_with(
+        &self,
+        params: &EventsDeprecated202004GetParams,
+    ) -> Result<()> {
+        Events::deprecated_202004_get_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecated202004GetParamParams,
+    ) -> Result<()> {
+        Events::deprecated_202004_get_param_with(self, event_id, params).await
+    }
+
+    async fn deprecated_202004_get_count_with(
+        &self,
+        params: &EventsDeprecated202004GetCountParams,
+    ) -> Result<()> {
+        Events::deprecated_202004_get_count_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_with(
+        &self,
+        params: &EventsDeprecated202007GetParams,
+    ) -> Result<()> {
+        Events::deprecated_202007_get_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecated202007GetParamParams,
+    ) -> Result<()> {
+        Events::deprecated_202007_get_param_with(self, event_id, params).await
+    }
+
+    async fn deprecated_202007_get_count_with(
+        &self,
+        params: &EventsDeprecated202007GetCountParams,
+    ) -> Result<()> {
+        Events::deprecated_202007_get_count_with(self, params).await
+    }
+
+    async fn get_with(&self, params: &EventsGetParams) -> Result<()> {
+        Events::get_with(self, params).await
+    }
+
+    async fn get_param_with(&self, event_id: &str, params: &EventsGetParamParams) -> Result<()> {
+        Events::get_param_with(self, event_id, params).await
+    }
+
+    async fn get_count_with(&self, params: &EventsGetCountParams) -> Result<()> {
+        Events::get_count_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_with(
+        &self,
+        params: &EventsDeprecated202101GetParams,
+    ) -> Result<()> {
+        Events::deprecated_202101_get_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecated202101GetParamParams,
+    ) -> Result<()> {
+        Events::deprecated_202101_get_param_with(self, event_id, params).await
+    }
+
+    async fn deprecated_202101_get_count_with(
+        &self,
+        params: &EventsDeprecated202101GetCountParams,
+    ) -> Result<()> {
+        Events::deprecated_202101_get_count_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_with(
+        &self,
+        params: &EventsDeprecatedUnstableGetParams,
+    ) -> Result<()> {
+        Events::deprecated_unstable_get_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_param_with(
+        &self,
+        event_id: &str,
+        params: &EventsDeprecatedUnstableGetParamParams,
+    ) -> Result<()> {
+        Events::deprecated_unstable_get_param_with(self, event_id, params).await
+    }
+
+    async fn deprecated_unstable_get_count_with(
+        &self,
+        params: &EventsDeprecatedUnstableGetCountParams,
+    ) -> Result<()> {
+        Events::deprecated_unstable_get_count_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_webhook_with(
+        &self,
+        params: &EventsDeprecated202001GetWebhookParams,
+    ) -> Result<()> {
+        Events::deprecated_202001_get_webhook_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_webhooks_with(
+        &self,
+        params: &EventsDeprecated202001CreateWebhooksParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202001_create_webhooks_with(self, params, body).await
+    }
+
+    async fn deprecated_202001_get_webhooks_count_with(
+        &self,
+        params: &EventsDeprecated202001GetWebhooksCountParams,
+    ) -> Result<()> {
+        Events::deprecated_202001_get_webhooks_count_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_webhooks_param_webhook_with(
+        &self,
+        webhook_id: &str,
+        params: &EventsDeprecated202001GetWebhooksParamWebhookParams,
+    ) -> Result<()> {
+        Events::deprecated_202001_get_webhooks_param_webhook_with(self, webhook_id, params).await
+    }
+
+    async fn deprecated_202001_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202001_update_webhooks_param_webhook(self, webhook_id, body).await
+    }
+
+    async fn deprecated_202001_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<()> {
+        Events::deprecated_202001_delete_webhooks_param_webhook(self, webhook_id).await
+    }
+
+    async fn deprecated_202004_get_webhook_with(
+        &self,
+        params: &EventsDeprecated202004GetWebhookParams,
+    ) -> Result<()> {
+        Events::deprecated_202004_get_webhook_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_webhooks_with(
+        &self,
+        params: &EventsDeprecated202004CreateWebhooksParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202004_create_webhooks_with(self, params, body).await
+    }
+
+    async fn deprecated_202004_get_webhooks_count_with(
+        &self,
+        params: &EventsDeprecated202004GetWebhooksCountParams,
+    ) -> Result<()> {
+        Events::deprecated_202004_get_webhooks_count_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_webhooks_param_webhook_with(
+        &self,
+        webhook_id: &str,
+        params: &EventsDeprecated202004GetWebhooksParamWebhookParams,
+    ) -> Result<()> {
+        Events::deprecated_202004_get_webhooks_param_webhook_with(self, webhook_id, params).await
+    }
+
+    async fn deprecated_202004_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202004_update_webhooks_param_webhook(self, webhook_id, body).await
+    }
+
+    async fn deprecated_202004_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<()> {
+        Events::deprecated_202004_delete_webhooks_param_webhook(self, webhook_id).await
+    }
+
+    async fn deprecated_202007_get_webhook_with(
+        &self,
+        params: &EventsDeprecated202007GetWebhookParams,
+    ) -> Result<()> {
+        Events::deprecated_202007_get_webhook_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_webhooks_with(
+        &self,
+        params: &EventsDeprecated202007CreateWebhooksParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202007_create_webhooks_with(self, params, body).await
+    }
+
+    async fn deprecated_202007_get_webhooks_count_with(
+        &self,
+        params: &EventsDeprecated202007GetWebhooksCountParams,
+    ) -> Result<()> {
+        Events::deprecated_202007_get_webhooks_count_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_webhooks_param_webhook_with(
+        &self,
+        webhook_id: &str,
+        params: &EventsDeprecated202007GetWebhooksParamWebhookParams,
+    ) -> Result<()> {
+        Events::deprecated_202007_get_webhooks_param_webhook_with(self, webhook_id, params).await
+    }
+
+    async fn deprecated_202007_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202007_update_webhooks_param_webhook(self, webhook_id, body).await
+    }
+
+    async fn deprecated_202007_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<()> {
+        Events::deprecated_202007_delete_webhooks_param_webhook(self, webhook_id).await
+    }
+
+    async fn get_webhook_with(&self, params: &EventsGetWebhookParams) -> Result<()> {
+        Events::get_webhook_with(self, params).await
+    }
+
+    async fn create_webhooks_with(
+        &self,
+        params: &EventsCreateWebhooksParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::create_webhooks_with(self, params, body).await
+    }
+
+    async fn get_webhooks_count_with(&self, params: &EventsGetWebhooksCountParams) -> Result<()> {
+        Events::get_webhooks_count_with(self, params).await
+    }
+
+    async fn get_webhooks_param_webhook_with(
+        &self,
+        webhook_id: &str,
+        params: &EventsGetWebhooksParamWebhookParams,
+    ) -> Result<()> {
+        Events::get_webhooks_param_webhook_with(self, webhook_id, params).await
+    }
+
+    async fn update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::update_webhooks_param_webhook(self, webhook_id, body).await
+    }
+
+    async fn delete_webhooks_param_webhook(&self, webhook_id: &str) -> Result<()> {
+        Events::delete_webhooks_param_webhook(self, webhook_id).await
+    }
+
+    async fn deprecated_202101_get_webhook_with(
+        &self,
+        params: &EventsDeprecated202101GetWebhookParams,
+    ) -> Result<()> {
+        Events::deprecated_202101_get_webhook_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_webhooks_with(
+        &self,
+        params: &EventsDeprecated202101CreateWebhooksParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202101_create_webhooks_with(self, params, body).await
+    }
+
+    async fn deprecated_202101_get_webhooks_count_with(
+        &self,
+        params: &EventsDeprecated202101GetWebhooksCountParams,
+    ) -> Result<()> {
+        Events::deprecated_202101_get_webhooks_count_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_webhooks_param_webhook_with(
+        &self,
+        webhook_id: &str,
+        params: &EventsDeprecated202101GetWebhooksParamWebhookParams,
+    ) -> Result<()> {
+        Events::deprecated_202101_get_webhooks_param_webhook_with(self, webhook_id, params).await
+    }
+
+    async fn deprecated_202101_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_202101_update_webhooks_param_webhook(self, webhook_id, body).await
+    }
+
+    async fn deprecated_202101_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<()> {
+        Events::deprecated_202101_delete_webhooks_param_webhook(self, webhook_id).await
+    }
+
+    async fn deprecated_unstable_get_webhook_with(
+        &self,
+        params: &EventsDeprecatedUnstableGetWebhookParams,
+    ) -> Result<()> {
+        Events::deprecated_unstable_get_webhook_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_webhooks_with(
+        &self,
+        params: &EventsDeprecatedUnstableCreateWebhooksParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_unstable_create_webhooks_with(self, params, body).await
+    }
+
+    async fn deprecated_unstable_get_webhooks_count_with(
+        &self,
+        params: &EventsDeprecatedUnstableGetWebhooksCountParams,
+    ) -> Result<()> {
+        Events::deprecated_unstable_get_webhooks_count_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_webhooks_param_webhook_with(
+        &self,
+        webhook_id: &str,
+        params: &EventsDeprecatedUnstableGetWebhooksParamWebhookParams,
+    ) -> Result<()> {
+        Events::deprecated_unstable_get_webhooks_param_webhook_with(self, webhook_id, params).await
+    }
+
+    async fn deprecated_unstable_update_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Events::deprecated_unstable_update_webhooks_param_webhook(self, webhook_id, body).await
+    }
+
+    async fn deprecated_unstable_delete_webhooks_param_webhook(
+        &self,
+        webhook_id: &str,
+    ) -> Result<()> {
+        Events::deprecated_unstable_delete_webhooks_param_webhook(self, webhook_id).await
+    }
+}
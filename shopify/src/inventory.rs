@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Inventory {
     pub client: Client,
@@ -21,23 +19,17 @@ impl Inventory {
      *
      * **Parameters:**
      *
-     * * `ids_required: &str` -- Show only inventory items specified by a comma-separated list of IDs.
-     *                     (maximum: 100).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &InventoryDeprecated202001GetItemParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_item(
+    pub async fn deprecated_202001_get_item_with(
         &self,
-        ids_required: &str,
-        limit: &str,
-        ids: i64,
+        params: &InventoryDeprecated202001GetItemParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if ids > 0 {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !ids_required.is_empty() {
+        if let Some(ids_required) = &params.ids_required {
             query_args.push((
                 "ids
                   required"
@@ -45,7 +37,7 @@ impl Inventory {
                 ids_required.to_string(),
             ));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -54,6 +46,24 @@ impl Inventory {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_item_with` and pass the query parameters as a `InventoryDeprecated202001GetItemParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_item_with` instead")]
+    pub async fn deprecated_202001_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202001GetItemParams {
+            ids: Some(ids).filter(|v| *v > 0),
+            ids_required: Some(ids_required.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_item_with(&params).await
+    }
+
     /**
      * Retrieves a single inventory item by ID.
      *
@@ -112,23 +122,17 @@ impl Inventory {
      *
      * **Parameters:**
      *
-     * * `ids_required: &str` -- Show only inventory items specified by a comma-separated list of IDs.
-     *                     (maximum: 100).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &InventoryDeprecated202004GetItemParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_item(
+    pub async fn deprecated_202004_get_item_with(
         &self,
-        ids_required: &str,
-        limit: &str,
-        ids: i64,
+        params: &InventoryDeprecated202004GetItemParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if ids > 0 {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !ids_required.is_empty() {
+        if let Some(ids_required) = &params.ids_required {
             query_args.push((
                 "ids
                   required"
@@ -136,7 +140,7 @@ impl Inventory {
                 ids_required.to_string(),
             ));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -145,6 +149,24 @@ impl Inventory {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_item_with` and pass the query parameters as a `InventoryDeprecated202004GetItemParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_item_with` instead")]
+    pub async fn deprecated_202004_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202004GetItemParams {
+            ids: Some(ids).filter(|v| *v > 0),
+            ids_required: Some(ids_required.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_item_with(&params).await
+    }
+
     /**
      * Retrieves a single inventory item by ID.
      *
@@ -203,23 +225,17 @@ impl Inventory {
      *
      * **Parameters:**
      *
-     * * `ids_required: &str` -- Show only inventory items specified by a comma-separated list of IDs.
-     *                     (maximum: 100).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &InventoryDeprecated202007GetItemParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_item(
+    pub async fn deprecated_202007_get_item_with(
         &self,
-        ids_required: &str,
-        limit: &str,
-        ids: i64,
+        params: &InventoryDeprecated202007GetItemParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if ids > 0 {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !ids_required.is_empty() {
+        if let Some(ids_required) = &params.ids_required {
             query_args.push((
                 "ids
                   required"
@@ -227,7 +243,7 @@ impl Inventory {
                 ids_required.to_string(),
             ));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -236,6 +252,24 @@ impl Inventory {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_item_with` and pass the query parameters as a `InventoryDeprecated202007GetItemParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_item_with` instead")]
+    pub async fn deprecated_202007_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202007GetItemParams {
+            ids: Some(ids).filter(|v| *v > 0),
+            ids_required: Some(ids_required.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_item_with(&params).await
+    }
+
     /**
      * Retrieves a single inventory item by ID.
      *
@@ -294,18 +328,14 @@ impl Inventory {
      *
      * **Parameters:**
      *
-     * * `ids_required: &str` -- Show only inventory items specified by a comma-separated list of IDs.
-     *                     (maximum: 100).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &InventoryGetItemParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_item(&self, ids_required: &str, limit: &str, ids: i64) -> Result<()> {
+    pub async fn get_item_with(&self, params: &InventoryGetItemParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if ids > 0 {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !ids_required.is_empty() {
+        if let Some(ids_required) = &params.ids_required {
             query_args.push((
                 "ids
                   required"
@@ -313,7 +343,7 @@ impl Inventory {
                 ids_required.to_string(),
             ));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -322,6 +352,19 @@ impl Inventory {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_item_with` and pass the query parameters as a `InventoryGetItemParams` instead.
+     */
+    #[deprecated(note = "use `get_item_with` instead")]
+    pub async fn get_item(&self, ids_required: &str, limit: &str, ids: i64) -> Result<()> {
+        let params = InventoryGetItemParams {
+            ids: Some(ids).filter(|v| *v > 0),
+            ids_required: Some(ids_required.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_item_with(&params).await
+    }
+
     /**
      * Retrieves a single inventory item by ID.
      *
@@ -377,23 +420,17 @@ impl Inventory {
      *
      * **Parameters:**
      *
-     * * `ids_required: &str` -- Show only inventory items specified by a comma-separated list of IDs.
-     *                     (maximum: 100).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &InventoryDeprecated202101GetItemParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_item(
+    pub async fn deprecated_202101_get_item_with(
         &self,
-        ids_required: &str,
-        limit: &str,
-        ids: i64,
+        params: &InventoryDeprecated202101GetItemParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if ids > 0 {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !ids_required.is_empty() {
+        if let Some(ids_required) = &params.ids_required {
             query_args.push((
                 "ids
                   required"
@@ -401,7 +438,7 @@ impl Inventory {
                 ids_required.to_string(),
             ));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -410,6 +447,24 @@ impl Inventory {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_item_with` and pass the query parameters as a `InventoryDeprecated202101GetItemParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_item_with` instead")]
+    pub async fn deprecated_202101_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202101GetItemParams {
+            ids: Some(ids).filter(|v| *v > 0),
+            ids_required: Some(ids_required.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_item_with(&params).await
+    }
+
     /**
      * Retrieves a single inventory item by ID.
      *
@@ -468,23 +523,17 @@ impl Inventory {
      *
      * **Parameters:**
      *
-     * * `ids_required: &str` -- Show only inventory items specified by a comma-separated list of IDs.
-     *                     (maximum: 100).
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &InventoryDeprecatedUnstableGetItemParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_item(
+    pub async fn deprecated_unstable_get_item_with(
         &self,
-        ids_required: &str,
-        limit: &str,
-        ids: i64,
+        params: &InventoryDeprecatedUnstableGetItemParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if ids > 0 {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !ids_required.is_empty() {
+        if let Some(ids_required) = &params.ids_required {
             query_args.push((
                 "ids
                   required"
@@ -492,7 +541,7 @@ impl Inventory {
                 ids_required.to_string(),
             ));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -501,6 +550,24 @@ impl Inventory {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_item_with` and pass the query parameters as a `InventoryDeprecatedUnstableGetItemParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_item_with` instead")]
+    pub async fn deprecated_unstable_get_item(
+        &self,
+        ids_required: &str,
+        limit: &str,
+        ids: i64,
+    ) -> Result<()> {
+        let params = InventoryDeprecatedUnstableGetItemParams {
+            ids: Some(ids).filter(|v| *v > 0),
+            ids_required: Some(ids_required.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_item_with(&params).await
+    }
+
     /**
      * Retrieves a single inventory item by ID.
      *
@@ -561,35 +628,26 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_ids: &str` -- A comma-separated list of inventory item IDs.
-    *                     (maximum: 50).
-    * * `location_ids: &str` -- A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
-    *                     (maximum: 50).
-    * * `limit: &str` -- The maximum number of results to show.
-    *                     (default: 50, maximum: 250).
-    * * `updated_at_min: &str` -- Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
+    * * `params: &InventoryDeprecated202001GetLevelParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_get_level(
+    pub async fn deprecated_202001_get_level_with(
         &self,
-        inventory_item_ids: &str,
-        location_ids: &str,
-        limit: &str,
-        updated_at_min: &str,
+        params: &InventoryDeprecated202001GetLevelParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_ids.is_empty() {
+        if let Some(inventory_item_ids) = &params.inventory_item_ids {
             query_args.push((
                 "inventory_item_ids".to_string(),
                 inventory_item_ids.to_string(),
             ));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !location_ids.is_empty() {
+        if let Some(location_ids) = &params.location_ids {
             query_args.push(("location_ids".to_string(), location_ids.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -598,6 +656,26 @@ impl Inventory {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_level_with` and pass the query parameters as a `InventoryDeprecated202001GetLevelParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_level_with` instead")]
+    pub async fn deprecated_202001_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202001GetLevelParams {
+            inventory_item_ids: Some(inventory_item_ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            location_ids: Some(location_ids.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_level_with(&params).await
+    }
+
     /**
     * Deletes an inventory level of an inventory item at a location.
               Deleting an inventory level for an inventory item removes that item from the specified location.
@@ -610,26 +688,20 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `inventory_item_id: i64` -- recurring_application_charge[capped_amount].
-    * * `location_id: i64` -- recurring_application_charge[capped_amount].
+    * * `params: &InventoryDeprecated202001DeleteLevelsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_delete_levels(
+    pub async fn deprecated_202001_delete_levels_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        inventory_item_id: i64,
-        location_id: i64,
+        params: &InventoryDeprecated202001DeleteLevelsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if inventory_item_id > 0 {
+        if let Some(inventory_item_id) = &params.inventory_item_id {
             query_args.push((
                 "inventory_item_id".to_string(),
                 inventory_item_id.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -637,10 +709,10 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if location_id > 0 {
+        if let Some(location_id) = &params.location_id {
             query_args.push(("location_id".to_string(), location_id.to_string()));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -654,6 +726,27 @@ impl Inventory {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_delete_levels_with` and pass the query parameters as a `InventoryDeprecated202001DeleteLevelsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_delete_levels_with` instead")]
+    pub async fn deprecated_202001_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202001DeleteLevelsParams {
+            inventory_item_id: Some(inventory_item_id).filter(|v| *v > 0),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id: Some(location_id).filter(|v| *v > 0),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_delete_levels_with(&params).await
+    }
+
     /**
      * Adjusts the inventory level of an inventory item at a single location.
      *
@@ -663,19 +756,15 @@ impl Inventory {
      *
      * **Parameters:**
      *
-     * * `inventory_item_required: &str` -- The ID of the inventory item.
-     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
+     * * `params: &InventoryDeprecated202001CreateLevelsAdjustParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_create_levels_adjust(
+    pub async fn deprecated_202001_create_levels_adjust_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_adjustment_required: &str,
+        params: &InventoryDeprecated202001CreateLevelsAdjustParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_adjustment_required.is_empty() {
+        if let Some(available_adjustment_required) = &params.available_adjustment_required {
             query_args.push((
                 "available_adjustment
                   required"
@@ -683,7 +772,7 @@ impl Inventory {
                 available_adjustment_required.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -691,7 +780,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -707,6 +796,28 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_levels_adjust_with` and pass the query parameters as a `InventoryDeprecated202001CreateLevelsAdjustParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_create_levels_adjust_with` instead")]
+    pub async fn deprecated_202001_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202001CreateLevelsAdjustParams {
+            available_adjustment_required: Some(available_adjustment_required.to_string())
+                .filter(|s| !s.is_empty()),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_create_levels_adjust_with(&params, body)
+            .await
+    }
+
     /**
     * Connects an inventory item to a location by creating an inventory level at that location.
             When connecting inventory items to locations, it's important to understand the rules around
@@ -718,20 +829,15 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID of the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `relocate_if_necessary: &str` -- Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
+    * * `params: &InventoryDeprecated202001CreateLevelsConnectParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_create_levels_connect(
+    pub async fn deprecated_202001_create_levels_connect_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        relocate_if_necessary: &str,
+        params: &InventoryDeprecated202001CreateLevelsConnectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -739,7 +845,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -747,7 +853,7 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        if !relocate_if_necessary.is_empty() {
+        if let Some(relocate_if_necessary) = &params.relocate_if_necessary {
             query_args.push((
                 "relocate_if_necessary".to_string(),
                 relocate_if_necessary.to_string(),
@@ -764,6 +870,28 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_levels_connect_with` and pass the query parameters as a `InventoryDeprecated202001CreateLevelsConnectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_create_levels_connect_with` instead")]
+    pub async fn deprecated_202001_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202001CreateLevelsConnectParams {
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+            relocate_if_necessary: Some(relocate_if_necessary.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_create_levels_connect_with(&params, body)
+            .await
+    }
+
     /**
     * Sets the inventory level for an inventory item at a location.
               If the specified location is not connected, it will be automatically connected first.
@@ -776,22 +904,15 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `available_required: &str` -- Sets the available inventory quantity.
-    * * `disconnect_if_necessary: &str` -- Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
+    * * `params: &InventoryDeprecated202001CreateLevelsSetParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_create_levels_set(
+    pub async fn deprecated_202001_create_levels_set_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_required: &str,
-        disconnect_if_necessary: &str,
+        params: &InventoryDeprecated202001CreateLevelsSetParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_required.is_empty() {
+        if let Some(available_required) = &params.available_required {
             query_args.push((
                 "available
                   required"
@@ -799,13 +920,13 @@ impl Inventory {
                 available_required.to_string(),
             ));
         }
-        if !disconnect_if_necessary.is_empty() {
+        if let Some(disconnect_if_necessary) = &params.disconnect_if_necessary {
             query_args.push((
                 "disconnect_if_necessary".to_string(),
                 disconnect_if_necessary.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -813,7 +934,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -829,6 +950,30 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_levels_set_with` and pass the query parameters as a `InventoryDeprecated202001CreateLevelsSetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_create_levels_set_with` instead")]
+    pub async fn deprecated_202001_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202001CreateLevelsSetParams {
+            available_required: Some(available_required.to_string()).filter(|s| !s.is_empty()),
+            disconnect_if_necessary: Some(disconnect_if_necessary.to_string())
+                .filter(|s| !s.is_empty()),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_create_levels_set_with(&params, body)
+            .await
+    }
+
     /**
     * Retrieves a list of inventory levels.
               You must include inventory_item_ids, location_ids, or both as filter parameters.
@@ -840,35 +985,26 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_ids: &str` -- A comma-separated list of inventory item IDs.
-    *                     (maximum: 50).
-    * * `location_ids: &str` -- A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
-    *                     (maximum: 50).
-    * * `limit: &str` -- The maximum number of results to show.
-    *                     (default: 50, maximum: 250).
-    * * `updated_at_min: &str` -- Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
+    * * `params: &InventoryDeprecated202004GetLevelParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_get_level(
+    pub async fn deprecated_202004_get_level_with(
         &self,
-        inventory_item_ids: &str,
-        location_ids: &str,
-        limit: &str,
-        updated_at_min: &str,
+        params: &InventoryDeprecated202004GetLevelParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_ids.is_empty() {
+        if let Some(inventory_item_ids) = &params.inventory_item_ids {
             query_args.push((
                 "inventory_item_ids".to_string(),
                 inventory_item_ids.to_string(),
             ));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !location_ids.is_empty() {
+        if let Some(location_ids) = &params.location_ids {
             query_args.push(("location_ids".to_string(), location_ids.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -877,6 +1013,26 @@ impl Inventory {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_level_with` and pass the query parameters as a `InventoryDeprecated202004GetLevelParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_level_with` instead")]
+    pub async fn deprecated_202004_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202004GetLevelParams {
+            inventory_item_ids: Some(inventory_item_ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            location_ids: Some(location_ids.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_level_with(&params).await
+    }
+
     /**
     * Deletes an inventory level of an inventory item at a location.
               Deleting an inventory level for an inventory item removes that item from the specified location.
@@ -889,26 +1045,20 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `inventory_item_id: i64` -- recurring_application_charge[capped_amount].
-    * * `location_id: i64` -- recurring_application_charge[capped_amount].
+    * * `params: &InventoryDeprecated202004DeleteLevelsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_delete_levels(
+    pub async fn deprecated_202004_delete_levels_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        inventory_item_id: i64,
-        location_id: i64,
+        params: &InventoryDeprecated202004DeleteLevelsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if inventory_item_id > 0 {
+        if let Some(inventory_item_id) = &params.inventory_item_id {
             query_args.push((
                 "inventory_item_id".to_string(),
                 inventory_item_id.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -916,10 +1066,10 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if location_id > 0 {
+        if let Some(location_id) = &params.location_id {
             query_args.push(("location_id".to_string(), location_id.to_string()));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -933,6 +1083,27 @@ impl Inventory {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_delete_levels_with` and pass the query parameters as a `InventoryDeprecated202004DeleteLevelsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_delete_levels_with` instead")]
+    pub async fn deprecated_202004_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202004DeleteLevelsParams {
+            inventory_item_id: Some(inventory_item_id).filter(|v| *v > 0),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id: Some(location_id).filter(|v| *v > 0),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_delete_levels_with(&params).await
+    }
+
     /**
      * Adjusts the inventory level of an inventory item at a single location.
      *
@@ -942,19 +1113,15 @@ impl Inventory {
      *
      * **Parameters:**
      *
-     * * `inventory_item_required: &str` -- The ID of the inventory item.
-     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
+     * * `params: &InventoryDeprecated202004CreateLevelsAdjustParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_create_levels_adjust(
+    pub async fn deprecated_202004_create_levels_adjust_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_adjustment_required: &str,
+        params: &InventoryDeprecated202004CreateLevelsAdjustParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_adjustment_required.is_empty() {
+        if let Some(available_adjustment_required) = &params.available_adjustment_required {
             query_args.push((
                 "available_adjustment
                   required"
@@ -962,7 +1129,7 @@ impl Inventory {
                 available_adjustment_required.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -970,7 +1137,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -986,6 +1153,28 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_levels_adjust_with` and pass the query parameters as a `InventoryDeprecated202004CreateLevelsAdjustParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_create_levels_adjust_with` instead")]
+    pub async fn deprecated_202004_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202004CreateLevelsAdjustParams {
+            available_adjustment_required: Some(available_adjustment_required.to_string())
+                .filter(|s| !s.is_empty()),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_create_levels_adjust_with(&params, body)
+            .await
+    }
+
     /**
     * Connects an inventory item to a location by creating an inventory level at that location.
             When connecting inventory items to locations, it's important to understand the rules around
@@ -997,20 +1186,15 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID of the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `relocate_if_necessary: &str` -- Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
+    * * `params: &InventoryDeprecated202004CreateLevelsConnectParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_create_levels_connect(
+    pub async fn deprecated_202004_create_levels_connect_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        relocate_if_necessary: &str,
+        params: &InventoryDeprecated202004CreateLevelsConnectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1018,7 +1202,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1026,7 +1210,7 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        if !relocate_if_necessary.is_empty() {
+        if let Some(relocate_if_necessary) = &params.relocate_if_necessary {
             query_args.push((
                 "relocate_if_necessary".to_string(),
                 relocate_if_necessary.to_string(),
@@ -1043,6 +1227,28 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_levels_connect_with` and pass the query parameters as a `InventoryDeprecated202004CreateLevelsConnectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_create_levels_connect_with` instead")]
+    pub async fn deprecated_202004_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202004CreateLevelsConnectParams {
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+            relocate_if_necessary: Some(relocate_if_necessary.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_create_levels_connect_with(&params, body)
+            .await
+    }
+
     /**
     * Sets the inventory level for an inventory item at a location.
               If the specified location is not connected, it will be automatically connected first.
@@ -1055,22 +1261,15 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `available_required: &str` -- Sets the available inventory quantity.
-    * * `disconnect_if_necessary: &str` -- Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
+    * * `params: &InventoryDeprecated202004CreateLevelsSetParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_create_levels_set(
+    pub async fn deprecated_202004_create_levels_set_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_required: &str,
-        disconnect_if_necessary: &str,
+        params: &InventoryDeprecated202004CreateLevelsSetParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_required.is_empty() {
+        if let Some(available_required) = &params.available_required {
             query_args.push((
                 "available
                   required"
@@ -1078,13 +1277,13 @@ impl Inventory {
                 available_required.to_string(),
             ));
         }
-        if !disconnect_if_necessary.is_empty() {
+        if let Some(disconnect_if_necessary) = &params.disconnect_if_necessary {
             query_args.push((
                 "disconnect_if_necessary".to_string(),
                 disconnect_if_necessary.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1092,7 +1291,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1108,6 +1307,30 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_levels_set_with` and pass the query parameters as a `InventoryDeprecated202004CreateLevelsSetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_create_levels_set_with` instead")]
+    pub async fn deprecated_202004_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202004CreateLevelsSetParams {
+            available_required: Some(available_required.to_string()).filter(|s| !s.is_empty()),
+            disconnect_if_necessary: Some(disconnect_if_necessary.to_string())
+                .filter(|s| !s.is_empty()),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_create_levels_set_with(&params, body)
+            .await
+    }
+
     /**
     * Retrieves a list of inventory levels.
               You must include inventory_item_ids, location_ids, or both as filter parameters.
@@ -1119,35 +1342,26 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_ids: &str` -- A comma-separated list of inventory item IDs.
-    *                     (maximum: 50).
-    * * `location_ids: &str` -- A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
-    *                     (maximum: 50).
-    * * `limit: &str` -- The maximum number of results to show.
-    *                     (default: 50, maximum: 250).
-    * * `updated_at_min: &str` -- Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
+    * * `params: &InventoryDeprecated202007GetLevelParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202007_get_level(
+    pub async fn deprecated_202007_get_level_with(
         &self,
-        inventory_item_ids: &str,
-        location_ids: &str,
-        limit: &str,
-        updated_at_min: &str,
+        params: &InventoryDeprecated202007GetLevelParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_ids.is_empty() {
+        if let Some(inventory_item_ids) = &params.inventory_item_ids {
             query_args.push((
                 "inventory_item_ids".to_string(),
                 inventory_item_ids.to_string(),
             ));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !location_ids.is_empty() {
+        if let Some(location_ids) = &params.location_ids {
             query_args.push(("location_ids".to_string(), location_ids.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1157,37 +1371,51 @@ impl Inventory {
     }
 
     /**
-    * Deletes an inventory level of an inventory item at a location.
-              Deleting an inventory level for an inventory item removes that item from the specified location.
-              Every inventory item must have at least one inventory level. To move inventory to another location,
-              first connect the inventory item to another location, and then delete the previous inventory level.
-    *
-    * This function performs a `DELETE` to the `/admin/api/2020-07/inventory_levels.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-2020-07
-    *
-    * **Parameters:**
+     * Deprecated: use `deprecated_202007_get_level_with` and pass the query parameters as a `InventoryDeprecated202007GetLevelParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_level_with` instead")]
+    pub async fn deprecated_202007_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202007GetLevelParams {
+            inventory_item_ids: Some(inventory_item_ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            location_ids: Some(location_ids.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_level_with(&params).await
+    }
+
+    /**
+    * Deletes an inventory level of an inventory item at a location.
+              Deleting an inventory level for an inventory item removes that item from the specified location.
+              Every inventory item must have at least one inventory level. To move inventory to another location,
+              first connect the inventory item to another location, and then delete the previous inventory level.
+    *
+    * This function performs a `DELETE` to the `/admin/api/2020-07/inventory_levels.json` endpoint.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/inventory/inventorylevel#destroy-2020-07
+    *
+    * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `inventory_item_id: i64` -- recurring_application_charge[capped_amount].
-    * * `location_id: i64` -- recurring_application_charge[capped_amount].
+    * * `params: &InventoryDeprecated202007DeleteLevelsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202007_delete_levels(
+    pub async fn deprecated_202007_delete_levels_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        inventory_item_id: i64,
-        location_id: i64,
+        params: &InventoryDeprecated202007DeleteLevelsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if inventory_item_id > 0 {
+        if let Some(inventory_item_id) = &params.inventory_item_id {
             query_args.push((
                 "inventory_item_id".to_string(),
                 inventory_item_id.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1195,10 +1423,10 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if location_id > 0 {
+        if let Some(location_id) = &params.location_id {
             query_args.push(("location_id".to_string(), location_id.to_string()));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1212,6 +1440,27 @@ impl Inventory {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_delete_levels_with` and pass the query parameters as a `InventoryDeprecated202007DeleteLevelsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_delete_levels_with` instead")]
+    pub async fn deprecated_202007_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202007DeleteLevelsParams {
+            inventory_item_id: Some(inventory_item_id).filter(|v| *v > 0),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id: Some(location_id).filter(|v| *v > 0),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_delete_levels_with(&params).await
+    }
+
     /**
      * Adjusts the inventory level of an inventory item at a single location.
      *
@@ -1221,19 +1470,15 @@ impl Inventory {
      *
      * **Parameters:**
      *
-     * * `inventory_item_required: &str` -- The ID of the inventory item.
-     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
+     * * `params: &InventoryDeprecated202007CreateLevelsAdjustParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_create_levels_adjust(
+    pub async fn deprecated_202007_create_levels_adjust_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_adjustment_required: &str,
+        params: &InventoryDeprecated202007CreateLevelsAdjustParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_adjustment_required.is_empty() {
+        if let Some(available_adjustment_required) = &params.available_adjustment_required {
             query_args.push((
                 "available_adjustment
                   required"
@@ -1241,7 +1486,7 @@ impl Inventory {
                 available_adjustment_required.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1249,7 +1494,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1265,6 +1510,28 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_levels_adjust_with` and pass the query parameters as a `InventoryDeprecated202007CreateLevelsAdjustParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_create_levels_adjust_with` instead")]
+    pub async fn deprecated_202007_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202007CreateLevelsAdjustParams {
+            available_adjustment_required: Some(available_adjustment_required.to_string())
+                .filter(|s| !s.is_empty()),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_create_levels_adjust_with(&params, body)
+            .await
+    }
+
     /**
     * Connects an inventory item to a location by creating an inventory level at that location.
             When connecting inventory items to locations, it's important to understand the rules around
@@ -1276,20 +1543,15 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID of the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `relocate_if_necessary: &str` -- Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
+    * * `params: &InventoryDeprecated202007CreateLevelsConnectParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202007_create_levels_connect(
+    pub async fn deprecated_202007_create_levels_connect_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        relocate_if_necessary: &str,
+        params: &InventoryDeprecated202007CreateLevelsConnectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1297,7 +1559,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1305,7 +1567,7 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        if !relocate_if_necessary.is_empty() {
+        if let Some(relocate_if_necessary) = &params.relocate_if_necessary {
             query_args.push((
                 "relocate_if_necessary".to_string(),
                 relocate_if_necessary.to_string(),
@@ -1322,6 +1584,28 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_levels_connect_with` and pass the query parameters as a `InventoryDeprecated202007CreateLevelsConnectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_create_levels_connect_with` instead")]
+    pub async fn deprecated_202007_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202007CreateLevelsConnectParams {
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+            relocate_if_necessary: Some(relocate_if_necessary.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_create_levels_connect_with(&params, body)
+            .await
+    }
+
     /**
     * Sets the inventory level for an inventory item at a location.
               If the specified location is not connected, it will be automatically connected first.
@@ -1334,22 +1618,15 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `available_required: &str` -- Sets the available inventory quantity.
-    * * `disconnect_if_necessary: &str` -- Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
+    * * `params: &InventoryDeprecated202007CreateLevelsSetParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202007_create_levels_set(
+    pub async fn deprecated_202007_create_levels_set_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_required: &str,
-        disconnect_if_necessary: &str,
+        params: &InventoryDeprecated202007CreateLevelsSetParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_required.is_empty() {
+        if let Some(available_required) = &params.available_required {
             query_args.push((
                 "available
                   required"
@@ -1357,13 +1634,13 @@ impl Inventory {
                 available_required.to_string(),
             ));
         }
-        if !disconnect_if_necessary.is_empty() {
+        if let Some(disconnect_if_necessary) = &params.disconnect_if_necessary {
             query_args.push((
                 "disconnect_if_necessary".to_string(),
                 disconnect_if_necessary.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1371,7 +1648,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1387,6 +1664,30 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_levels_set_with` and pass the query parameters as a `InventoryDeprecated202007CreateLevelsSetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_create_levels_set_with` instead")]
+    pub async fn deprecated_202007_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202007CreateLevelsSetParams {
+            available_required: Some(available_required.to_string()).filter(|s| !s.is_empty()),
+            disconnect_if_necessary: Some(disconnect_if_necessary.to_string())
+                .filter(|s| !s.is_empty()),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_create_levels_set_with(&params, body)
+            .await
+    }
+
     /**
     * Retrieves a list of inventory levels.
               You must include inventory_item_ids, location_ids, or both as filter parameters.
@@ -1398,35 +1699,23 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_ids: &str` -- A comma-separated list of inventory item IDs.
-    *                     (maximum: 50).
-    * * `location_ids: &str` -- A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
-    *                     (maximum: 50).
-    * * `limit: &str` -- The maximum number of results to show.
-    *                     (default: 50, maximum: 250).
-    * * `updated_at_min: &str` -- Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
+    * * `params: &InventoryGetLevelParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn get_level(
-        &self,
-        inventory_item_ids: &str,
-        location_ids: &str,
-        limit: &str,
-        updated_at_min: &str,
-    ) -> Result<()> {
+    pub async fn get_level_with(&self, params: &InventoryGetLevelParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_ids.is_empty() {
+        if let Some(inventory_item_ids) = &params.inventory_item_ids {
             query_args.push((
                 "inventory_item_ids".to_string(),
                 inventory_item_ids.to_string(),
             ));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !location_ids.is_empty() {
+        if let Some(location_ids) = &params.location_ids {
             query_args.push(("location_ids".to_string(), location_ids.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1435,6 +1724,26 @@ impl Inventory {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_level_with` and pass the query parameters as a `InventoryGetLevelParams` instead.
+     */
+    #[deprecated(note = "use `get_level_with` instead")]
+    pub async fn get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        let params = InventoryGetLevelParams {
+            inventory_item_ids: Some(inventory_item_ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            location_ids: Some(location_ids.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_level_with(&params).await
+    }
+
     /**
     * Deletes an inventory level of an inventory item at a location.
               Deleting an inventory level for an inventory item removes that item from the specified location.
@@ -1447,26 +1756,17 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `inventory_item_id: i64` -- recurring_application_charge[capped_amount].
-    * * `location_id: i64` -- recurring_application_charge[capped_amount].
+    * * `params: &InventoryDeleteLevelsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn delete_levels(
-        &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        inventory_item_id: i64,
-        location_id: i64,
-    ) -> Result<()> {
+    pub async fn delete_levels_with(&self, params: &InventoryDeleteLevelsParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if inventory_item_id > 0 {
+        if let Some(inventory_item_id) = &params.inventory_item_id {
             query_args.push((
                 "inventory_item_id".to_string(),
                 inventory_item_id.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1474,10 +1774,10 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if location_id > 0 {
+        if let Some(location_id) = &params.location_id {
             query_args.push(("location_id".to_string(), location_id.to_string()));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1491,6 +1791,27 @@ impl Inventory {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_levels_with` and pass the query parameters as a `InventoryDeleteLevelsParams` instead.
+     */
+    #[deprecated(note = "use `delete_levels_with` instead")]
+    pub async fn delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()> {
+        let params = InventoryDeleteLevelsParams {
+            inventory_item_id: Some(inventory_item_id).filter(|v| *v > 0),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id: Some(location_id).filter(|v| *v > 0),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_levels_with(&params).await
+    }
+
     /**
      * Adjusts the inventory level of an inventory item at a single location.
      *
@@ -1500,19 +1821,15 @@ impl Inventory {
      *
      * **Parameters:**
      *
-     * * `inventory_item_required: &str` -- The ID of the inventory item.
-     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
+     * * `params: &InventoryCreateLevelsAdjustParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create_levels_adjust(
+    pub async fn create_levels_adjust_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_adjustment_required: &str,
+        params: &InventoryCreateLevelsAdjustParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_adjustment_required.is_empty() {
+        if let Some(available_adjustment_required) = &params.available_adjustment_required {
             query_args.push((
                 "available_adjustment
                   required"
@@ -1520,7 +1837,7 @@ impl Inventory {
                 available_adjustment_required.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1528,7 +1845,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1544,6 +1861,27 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `create_levels_adjust_with` and pass the query parameters as a `InventoryCreateLevelsAdjustParams` instead.
+     */
+    #[deprecated(note = "use `create_levels_adjust_with` instead")]
+    pub async fn create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryCreateLevelsAdjustParams {
+            available_adjustment_required: Some(available_adjustment_required.to_string())
+                .filter(|s| !s.is_empty()),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_levels_adjust_with(&params, body).await
+    }
+
     /**
     * Connects an inventory item to a location by creating an inventory level at that location.
             When connecting inventory items to locations, it's important to understand the rules around
@@ -1555,20 +1893,15 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID of the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `relocate_if_necessary: &str` -- Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
+    * * `params: &InventoryCreateLevelsConnectParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn create_levels_connect(
+    pub async fn create_levels_connect_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        relocate_if_necessary: &str,
+        params: &InventoryCreateLevelsConnectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1576,7 +1909,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1584,7 +1917,7 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        if !relocate_if_necessary.is_empty() {
+        if let Some(relocate_if_necessary) = &params.relocate_if_necessary {
             query_args.push((
                 "relocate_if_necessary".to_string(),
                 relocate_if_necessary.to_string(),
@@ -1601,6 +1934,27 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `create_levels_connect_with` and pass the query parameters as a `InventoryCreateLevelsConnectParams` instead.
+     */
+    #[deprecated(note = "use `create_levels_connect_with` instead")]
+    pub async fn create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryCreateLevelsConnectParams {
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+            relocate_if_necessary: Some(relocate_if_necessary.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.create_levels_connect_with(&params, body).await
+    }
+
     /**
     * Sets the inventory level for an inventory item at a location.
               If the specified location is not connected, it will be automatically connected first.
@@ -1613,22 +1967,15 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `available_required: &str` -- Sets the available inventory quantity.
-    * * `disconnect_if_necessary: &str` -- Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
+    * * `params: &InventoryCreateLevelsSetParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn create_levels_set(
+    pub async fn create_levels_set_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_required: &str,
-        disconnect_if_necessary: &str,
+        params: &InventoryCreateLevelsSetParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_required.is_empty() {
+        if let Some(available_required) = &params.available_required {
             query_args.push((
                 "available
                   required"
@@ -1636,13 +1983,13 @@ impl Inventory {
                 available_required.to_string(),
             ));
         }
-        if !disconnect_if_necessary.is_empty() {
+        if let Some(disconnect_if_necessary) = &params.disconnect_if_necessary {
             query_args.push((
                 "disconnect_if_necessary".to_string(),
                 disconnect_if_necessary.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1650,7 +1997,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1666,6 +2013,29 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `create_levels_set_with` and pass the query parameters as a `InventoryCreateLevelsSetParams` instead.
+     */
+    #[deprecated(note = "use `create_levels_set_with` instead")]
+    pub async fn create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryCreateLevelsSetParams {
+            available_required: Some(available_required.to_string()).filter(|s| !s.is_empty()),
+            disconnect_if_necessary: Some(disconnect_if_necessary.to_string())
+                .filter(|s| !s.is_empty()),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_levels_set_with(&params, body).await
+    }
+
     /**
     * Retrieves a list of inventory levels.
               You must include inventory_item_ids, location_ids, or both as filter parameters.
@@ -1677,35 +2047,26 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_ids: &str` -- A comma-separated list of inventory item IDs.
-    *                     (maximum: 50).
-    * * `location_ids: &str` -- A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
-    *                     (maximum: 50).
-    * * `limit: &str` -- The maximum number of results to show.
-    *                     (default: 50, maximum: 250).
-    * * `updated_at_min: &str` -- Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
+    * * `params: &InventoryDeprecated202101GetLevelParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202101_get_level(
+    pub async fn deprecated_202101_get_level_with(
         &self,
-        inventory_item_ids: &str,
-        location_ids: &str,
-        limit: &str,
-        updated_at_min: &str,
+        params: &InventoryDeprecated202101GetLevelParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_ids.is_empty() {
+        if let Some(inventory_item_ids) = &params.inventory_item_ids {
             query_args.push((
                 "inventory_item_ids".to_string(),
                 inventory_item_ids.to_string(),
             ));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !location_ids.is_empty() {
+        if let Some(location_ids) = &params.location_ids {
             query_args.push(("location_ids".to_string(), location_ids.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1714,6 +2075,26 @@ impl Inventory {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_level_with` and pass the query parameters as a `InventoryDeprecated202101GetLevelParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_level_with` instead")]
+    pub async fn deprecated_202101_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202101GetLevelParams {
+            inventory_item_ids: Some(inventory_item_ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            location_ids: Some(location_ids.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_level_with(&params).await
+    }
+
     /**
     * Deletes an inventory level of an inventory item at a location.
               Deleting an inventory level for an inventory item removes that item from the specified location.
@@ -1726,26 +2107,20 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `inventory_item_id: i64` -- recurring_application_charge[capped_amount].
-    * * `location_id: i64` -- recurring_application_charge[capped_amount].
+    * * `params: &InventoryDeprecated202101DeleteLevelsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202101_delete_levels(
+    pub async fn deprecated_202101_delete_levels_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        inventory_item_id: i64,
-        location_id: i64,
+        params: &InventoryDeprecated202101DeleteLevelsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if inventory_item_id > 0 {
+        if let Some(inventory_item_id) = &params.inventory_item_id {
             query_args.push((
                 "inventory_item_id".to_string(),
                 inventory_item_id.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1753,10 +2128,10 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if location_id > 0 {
+        if let Some(location_id) = &params.location_id {
             query_args.push(("location_id".to_string(), location_id.to_string()));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1770,6 +2145,27 @@ impl Inventory {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_delete_levels_with` and pass the query parameters as a `InventoryDeprecated202101DeleteLevelsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_delete_levels_with` instead")]
+    pub async fn deprecated_202101_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202101DeleteLevelsParams {
+            inventory_item_id: Some(inventory_item_id).filter(|v| *v > 0),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id: Some(location_id).filter(|v| *v > 0),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_delete_levels_with(&params).await
+    }
+
     /**
      * Adjusts the inventory level of an inventory item at a single location.
      *
@@ -1779,19 +2175,15 @@ impl Inventory {
      *
      * **Parameters:**
      *
-     * * `inventory_item_required: &str` -- The ID of the inventory item.
-     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
+     * * `params: &InventoryDeprecated202101CreateLevelsAdjustParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_create_levels_adjust(
+    pub async fn deprecated_202101_create_levels_adjust_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_adjustment_required: &str,
+        params: &InventoryDeprecated202101CreateLevelsAdjustParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_adjustment_required.is_empty() {
+        if let Some(available_adjustment_required) = &params.available_adjustment_required {
             query_args.push((
                 "available_adjustment
                   required"
@@ -1799,7 +2191,7 @@ impl Inventory {
                 available_adjustment_required.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1807,7 +2199,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1823,6 +2215,28 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_levels_adjust_with` and pass the query parameters as a `InventoryDeprecated202101CreateLevelsAdjustParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_create_levels_adjust_with` instead")]
+    pub async fn deprecated_202101_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202101CreateLevelsAdjustParams {
+            available_adjustment_required: Some(available_adjustment_required.to_string())
+                .filter(|s| !s.is_empty()),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_create_levels_adjust_with(&params, body)
+            .await
+    }
+
     /**
     * Connects an inventory item to a location by creating an inventory level at that location.
             When connecting inventory items to locations, it's important to understand the rules around
@@ -1834,20 +2248,15 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID of the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `relocate_if_necessary: &str` -- Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
+    * * `params: &InventoryDeprecated202101CreateLevelsConnectParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202101_create_levels_connect(
+    pub async fn deprecated_202101_create_levels_connect_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        relocate_if_necessary: &str,
+        params: &InventoryDeprecated202101CreateLevelsConnectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1855,7 +2264,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1863,7 +2272,7 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        if !relocate_if_necessary.is_empty() {
+        if let Some(relocate_if_necessary) = &params.relocate_if_necessary {
             query_args.push((
                 "relocate_if_necessary".to_string(),
                 relocate_if_necessary.to_string(),
@@ -1880,6 +2289,28 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_levels_connect_with` and pass the query parameters as a `InventoryDeprecated202101CreateLevelsConnectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_create_levels_connect_with` instead")]
+    pub async fn deprecated_202101_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202101CreateLevelsConnectParams {
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+            relocate_if_necessary: Some(relocate_if_necessary.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_create_levels_connect_with(&params, body)
+            .await
+    }
+
     /**
     * Sets the inventory level for an inventory item at a location.
               If the specified location is not connected, it will be automatically connected first.
@@ -1892,22 +2323,15 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `available_required: &str` -- Sets the available inventory quantity.
-    * * `disconnect_if_necessary: &str` -- Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
+    * * `params: &InventoryDeprecated202101CreateLevelsSetParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202101_create_levels_set(
+    pub async fn deprecated_202101_create_levels_set_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_required: &str,
-        disconnect_if_necessary: &str,
+        params: &InventoryDeprecated202101CreateLevelsSetParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_required.is_empty() {
+        if let Some(available_required) = &params.available_required {
             query_args.push((
                 "available
                   required"
@@ -1915,13 +2339,13 @@ impl Inventory {
                 available_required.to_string(),
             ));
         }
-        if !disconnect_if_necessary.is_empty() {
+        if let Some(disconnect_if_necessary) = &params.disconnect_if_necessary {
             query_args.push((
                 "disconnect_if_necessary".to_string(),
                 disconnect_if_necessary.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -1929,7 +2353,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -1945,6 +2369,30 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_levels_set_with` and pass the query parameters as a `InventoryDeprecated202101CreateLevelsSetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_create_levels_set_with` instead")]
+    pub async fn deprecated_202101_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecated202101CreateLevelsSetParams {
+            available_required: Some(available_required.to_string()).filter(|s| !s.is_empty()),
+            disconnect_if_necessary: Some(disconnect_if_necessary.to_string())
+                .filter(|s| !s.is_empty()),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_create_levels_set_with(&params, body)
+            .await
+    }
+
     /**
     * Retrieves a list of inventory levels.
               You must include inventory_item_ids, location_ids, or both as filter parameters.
@@ -1956,35 +2404,26 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_ids: &str` -- A comma-separated list of inventory item IDs.
-    *                     (maximum: 50).
-    * * `location_ids: &str` -- A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
-    *                     (maximum: 50).
-    * * `limit: &str` -- The maximum number of results to show.
-    *                     (default: 50, maximum: 250).
-    * * `updated_at_min: &str` -- Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
+    * * `params: &InventoryDeprecatedUnstableGetLevelParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_unstable_get_level(
+    pub async fn deprecated_unstable_get_level_with(
         &self,
-        inventory_item_ids: &str,
-        location_ids: &str,
-        limit: &str,
-        updated_at_min: &str,
+        params: &InventoryDeprecatedUnstableGetLevelParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_ids.is_empty() {
+        if let Some(inventory_item_ids) = &params.inventory_item_ids {
             query_args.push((
                 "inventory_item_ids".to_string(),
                 inventory_item_ids.to_string(),
             ));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !location_ids.is_empty() {
+        if let Some(location_ids) = &params.location_ids {
             query_args.push(("location_ids".to_string(), location_ids.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1993,6 +2432,26 @@ impl Inventory {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_level_with` and pass the query parameters as a `InventoryDeprecatedUnstableGetLevelParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_level_with` instead")]
+    pub async fn deprecated_unstable_get_level(
+        &self,
+        inventory_item_ids: &str,
+        location_ids: &str,
+        limit: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        let params = InventoryDeprecatedUnstableGetLevelParams {
+            inventory_item_ids: Some(inventory_item_ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            location_ids: Some(location_ids.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_level_with(&params).await
+    }
+
     /**
     * Deletes an inventory level of an inventory item at a location.
               Deleting an inventory level for an inventory item removes that item from the specified location.
@@ -2005,26 +2464,20 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `inventory_item_id: i64` -- recurring_application_charge[capped_amount].
-    * * `location_id: i64` -- recurring_application_charge[capped_amount].
+    * * `params: &InventoryDeprecatedUnstableDeleteLevelsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_unstable_delete_levels(
+    pub async fn deprecated_unstable_delete_levels_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        inventory_item_id: i64,
-        location_id: i64,
+        params: &InventoryDeprecatedUnstableDeleteLevelsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if inventory_item_id > 0 {
+        if let Some(inventory_item_id) = &params.inventory_item_id {
             query_args.push((
                 "inventory_item_id".to_string(),
                 inventory_item_id.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -2032,10 +2485,10 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if location_id > 0 {
+        if let Some(location_id) = &params.location_id {
             query_args.push(("location_id".to_string(), location_id.to_string()));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -2049,6 +2502,27 @@ impl Inventory {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_delete_levels_with` and pass the query parameters as a `InventoryDeprecatedUnstableDeleteLevelsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_delete_levels_with` instead")]
+    pub async fn deprecated_unstable_delete_levels(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        inventory_item_id: i64,
+        location_id: i64,
+    ) -> Result<()> {
+        let params = InventoryDeprecatedUnstableDeleteLevelsParams {
+            inventory_item_id: Some(inventory_item_id).filter(|v| *v > 0),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id: Some(location_id).filter(|v| *v > 0),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_delete_levels_with(&params).await
+    }
+
     /**
      * Adjusts the inventory level of an inventory item at a single location.
      *
@@ -2058,19 +2532,15 @@ impl Inventory {
      *
      * **Parameters:**
      *
-     * * `inventory_item_required: &str` -- The ID of the inventory item.
-     * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-     * * `available_adjustment_required: &str` -- The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
+     * * `params: &InventoryDeprecatedUnstableCreateLevelsAdjustParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_create_levels_adjust(
+    pub async fn deprecated_unstable_create_levels_adjust_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_adjustment_required: &str,
+        params: &InventoryDeprecatedUnstableCreateLevelsAdjustParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_adjustment_required.is_empty() {
+        if let Some(available_adjustment_required) = &params.available_adjustment_required {
             query_args.push((
                 "available_adjustment
                   required"
@@ -2078,7 +2548,7 @@ impl Inventory {
                 available_adjustment_required.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -2086,7 +2556,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -2105,6 +2575,28 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_create_levels_adjust_with` and pass the query parameters as a `InventoryDeprecatedUnstableCreateLevelsAdjustParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_create_levels_adjust_with` instead")]
+    pub async fn deprecated_unstable_create_levels_adjust(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_adjustment_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecatedUnstableCreateLevelsAdjustParams {
+            available_adjustment_required: Some(available_adjustment_required.to_string())
+                .filter(|s| !s.is_empty()),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_create_levels_adjust_with(&params, body)
+            .await
+    }
+
     /**
     * Connects an inventory item to a location by creating an inventory level at that location.
             When connecting inventory items to locations, it's important to understand the rules around
@@ -2116,20 +2608,15 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID of the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `relocate_if_necessary: &str` -- Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
+    * * `params: &InventoryDeprecatedUnstableCreateLevelsConnectParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_unstable_create_levels_connect(
+    pub async fn deprecated_unstable_create_levels_connect_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        relocate_if_necessary: &str,
+        params: &InventoryDeprecatedUnstableCreateLevelsConnectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -2137,7 +2624,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -2145,7 +2632,7 @@ impl Inventory {
                 location_id_required.to_string(),
             ));
         }
-        if !relocate_if_necessary.is_empty() {
+        if let Some(relocate_if_necessary) = &params.relocate_if_necessary {
             query_args.push((
                 "relocate_if_necessary".to_string(),
                 relocate_if_necessary.to_string(),
@@ -2162,6 +2649,28 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_create_levels_connect_with` and pass the query parameters as a `InventoryDeprecatedUnstableCreateLevelsConnectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_create_levels_connect_with` instead")]
+    pub async fn deprecated_unstable_create_levels_connect(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        relocate_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecatedUnstableCreateLevelsConnectParams {
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+            relocate_if_necessary: Some(relocate_if_necessary.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_create_levels_connect_with(&params, body)
+            .await
+    }
+
     /**
     * Sets the inventory level for an inventory item at a location.
               If the specified location is not connected, it will be automatically connected first.
@@ -2174,22 +2683,15 @@ impl Inventory {
     *
     * **Parameters:**
     *
-    * * `inventory_item_required: &str` -- The ID for the inventory item.
-    * * `location_required: &str` -- The ID of the location that the inventory level belongs to. To find the ID of the location, use the Location resource.
-    * * `available_required: &str` -- Sets the available inventory quantity.
-    * * `disconnect_if_necessary: &str` -- Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
-    *                     (default: false).
+    * * `params: &InventoryDeprecatedUnstableCreateLevelsSetParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_unstable_create_levels_set(
+    pub async fn deprecated_unstable_create_levels_set_with(
         &self,
-        inventory_item_id_required: &str,
-        location_id_required: &str,
-        available_required: &str,
-        disconnect_if_necessary: &str,
+        params: &InventoryDeprecatedUnstableCreateLevelsSetParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !available_required.is_empty() {
+        if let Some(available_required) = &params.available_required {
             query_args.push((
                 "available
                   required"
@@ -2197,13 +2699,13 @@ impl Inventory {
                 available_required.to_string(),
             ));
         }
-        if !disconnect_if_necessary.is_empty() {
+        if let Some(disconnect_if_necessary) = &params.disconnect_if_necessary {
             query_args.push((
                 "disconnect_if_necessary".to_string(),
                 disconnect_if_necessary.to_string(),
             ));
         }
-        if !inventory_item_id_required.is_empty() {
+        if let Some(inventory_item_id_required) = &params.inventory_item_id_required {
             query_args.push((
                 "inventory_item_id
                   required"
@@ -2211,7 +2713,7 @@ impl Inventory {
                 inventory_item_id_required.to_string(),
             ));
         }
-        if !location_id_required.is_empty() {
+        if let Some(location_id_required) = &params.location_id_required {
             query_args.push((
                 "location_id
                   required"
@@ -2227,6 +2729,30 @@ impl Inventory {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_create_levels_set_with` and pass the query parameters as a `InventoryDeprecatedUnstableCreateLevelsSetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_create_levels_set_with` instead")]
+    pub async fn deprecated_unstable_create_levels_set(
+        &self,
+        inventory_item_id_required: &str,
+        location_id_required: &str,
+        available_required: &str,
+        disconnect_if_necessary: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = InventoryDeprecatedUnstableCreateLevelsSetParams {
+            available_required: Some(available_required.to_string()).filter(|s| !s.is_empty()),
+            disconnect_if_necessary: Some(disconnect_if_necessary.to_string())
+                .filter(|s| !s.is_empty()),
+            inventory_item_id_required: Some(inventory_item_id_required.to_string())
+                .filter(|s| !s.is_empty()),
+            location_id_required: Some(location_id_required.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_create_levels_set_with(&params, body)
+            .await
+    }
+
     /**
      * Retrieves a list of locations.
      *
@@ -2641,3 +3167,2072 @@ impl Inventory {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Inventory::deprecated_202001_get_item_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202001GetItemParams {
+    /// recurring_application_charge[capped_amount].
+    pub ids: Option<i64>,
+    /// Show only inventory items specified by a comma-separated list of IDs.
+    /// (maximum: 100).
+    pub ids_required: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl InventoryDeprecated202001GetItemParams {
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn ids_required<T: ToString>(mut self, ids_required: T) -> Self {
+        self.ids_required = Some(ids_required.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202004_get_item_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202004GetItemParams {
+    /// recurring_application_charge[capped_amount].
+    pub ids: Option<i64>,
+    /// Show only inventory items specified by a comma-separated list of IDs.
+    /// (maximum: 100).
+    pub ids_required: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl InventoryDeprecated202004GetItemParams {
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn ids_required<T: ToString>(mut self, ids_required: T) -> Self {
+        self.ids_required = Some(ids_required.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202007_get_item_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202007GetItemParams {
+    /// recurring_application_charge[capped_amount].
+    pub ids: Option<i64>,
+    /// Show only inventory items specified by a comma-separated list of IDs.
+    /// (maximum: 100).
+    pub ids_required: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl InventoryDeprecated202007GetItemParams {
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn ids_required<T: ToString>(mut self, ids_required: T) -> Self {
+        self.ids_required = Some(ids_required.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::get_item_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryGetItemParams {
+    /// recurring_application_charge[capped_amount].
+    pub ids: Option<i64>,
+    /// Show only inventory items specified by a comma-separated list of IDs.
+    /// (maximum: 100).
+    pub ids_required: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl InventoryGetItemParams {
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn ids_required<T: ToString>(mut self, ids_required: T) -> Self {
+        self.ids_required = Some(ids_required.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202101_get_item_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202101GetItemParams {
+    /// recurring_application_charge[capped_amount].
+    pub ids: Option<i64>,
+    /// Show only inventory items specified by a comma-separated list of IDs.
+    /// (maximum: 100).
+    pub ids_required: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl InventoryDeprecated202101GetItemParams {
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn ids_required<T: ToString>(mut self, ids_required: T) -> Self {
+        self.ids_required = Some(ids_required.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_unstable_get_item_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecatedUnstableGetItemParams {
+    /// recurring_application_charge[capped_amount].
+    pub ids: Option<i64>,
+    /// Show only inventory items specified by a comma-separated list of IDs.
+    /// (maximum: 100).
+    pub ids_required: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl InventoryDeprecatedUnstableGetItemParams {
+    pub fn ids(mut self, ids: i64) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn ids_required<T: ToString>(mut self, ids_required: T) -> Self {
+        self.ids_required = Some(ids_required.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202001_get_level_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202001GetLevelParams {
+    /// A comma-separated list of inventory item IDs.
+    /// (maximum: 50).
+    pub inventory_item_ids: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
+    /// (maximum: 50).
+    pub location_ids: Option<String>,
+    /// Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl InventoryDeprecated202001GetLevelParams {
+    pub fn inventory_item_ids<T: ToString>(mut self, inventory_item_ids: T) -> Self {
+        self.inventory_item_ids = Some(inventory_item_ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn location_ids<T: ToString>(mut self, location_ids: T) -> Self {
+        self.location_ids = Some(location_ids.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202001_delete_levels_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202001DeleteLevelsParams {
+    /// recurring_application_charge[capped_amount].
+    pub inventory_item_id: Option<i64>,
+    pub inventory_item_id_required: Option<String>,
+    /// recurring_application_charge[capped_amount].
+    pub location_id: Option<i64>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecated202001DeleteLevelsParams {
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inventory_item_id = Some(inventory_item_id);
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202001_create_levels_adjust_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202001CreateLevelsAdjustParams {
+    /// The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
+    pub available_adjustment_required: Option<String>,
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecated202001CreateLevelsAdjustParams {
+    pub fn available_adjustment_required<T: ToString>(
+        mut self,
+        available_adjustment_required: T,
+    ) -> Self {
+        self.available_adjustment_required = Some(available_adjustment_required.to_string());
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202001_create_levels_connect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202001CreateLevelsConnectParams {
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+    /// Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
+    /// (default: false).
+    pub relocate_if_necessary: Option<String>,
+}
+
+impl InventoryDeprecated202001CreateLevelsConnectParams {
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+
+    pub fn relocate_if_necessary<T: ToString>(mut self, relocate_if_necessary: T) -> Self {
+        self.relocate_if_necessary = Some(relocate_if_necessary.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202001_create_levels_set_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202001CreateLevelsSetParams {
+    /// Sets the available inventory quantity.
+    pub available_required: Option<String>,
+    /// Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
+    /// (default: false).
+    pub disconnect_if_necessary: Option<String>,
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecated202001CreateLevelsSetParams {
+    pub fn available_required<T: ToString>(mut self, available_required: T) -> Self {
+        self.available_required = Some(available_required.to_string());
+        self
+    }
+
+    pub fn disconnect_if_necessary<T: ToString>(mut self, disconnect_if_necessary: T) -> Self {
+        self.disconnect_if_necessary = Some(disconnect_if_necessary.to_string());
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202004_get_level_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202004GetLevelParams {
+    /// A comma-separated list of inventory item IDs.
+    /// (maximum: 50).
+    pub inventory_item_ids: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
+    /// (maximum: 50).
+    pub location_ids: Option<String>,
+    /// Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl InventoryDeprecated202004GetLevelParams {
+    pub fn inventory_item_ids<T: ToString>(mut self, inventory_item_ids: T) -> Self {
+        self.inventory_item_ids = Some(inventory_item_ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn location_ids<T: ToString>(mut self, location_ids: T) -> Self {
+        self.location_ids = Some(location_ids.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202004_delete_levels_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202004DeleteLevelsParams {
+    /// recurring_application_charge[capped_amount].
+    pub inventory_item_id: Option<i64>,
+    pub inventory_item_id_required: Option<String>,
+    /// recurring_application_charge[capped_amount].
+    pub location_id: Option<i64>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecated202004DeleteLevelsParams {
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inventory_item_id = Some(inventory_item_id);
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202004_create_levels_adjust_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202004CreateLevelsAdjustParams {
+    /// The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
+    pub available_adjustment_required: Option<String>,
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecated202004CreateLevelsAdjustParams {
+    pub fn available_adjustment_required<T: ToString>(
+        mut self,
+        available_adjustment_required: T,
+    ) -> Self {
+        self.available_adjustment_required = Some(available_adjustment_required.to_string());
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202004_create_levels_connect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202004CreateLevelsConnectParams {
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+    /// Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
+    /// (default: false).
+    pub relocate_if_necessary: Option<String>,
+}
+
+impl InventoryDeprecated202004CreateLevelsConnectParams {
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+
+    pub fn relocate_if_necessary<T: ToString>(mut self, relocate_if_necessary: T) -> Self {
+        self.relocate_if_necessary = Some(relocate_if_necessary.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202004_create_levels_set_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202004CreateLevelsSetParams {
+    /// Sets the available inventory quantity.
+    pub available_required: Option<String>,
+    /// Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
+    /// (default: false).
+    pub disconnect_if_necessary: Option<String>,
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecated202004CreateLevelsSetParams {
+    pub fn available_required<T: ToString>(mut self, available_required: T) -> Self {
+        self.available_required = Some(available_required.to_string());
+        self
+    }
+
+    pub fn disconnect_if_necessary<T: ToString>(mut self, disconnect_if_necessary: T) -> Self {
+        self.disconnect_if_necessary = Some(disconnect_if_necessary.to_string());
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202007_get_level_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202007GetLevelParams {
+    /// A comma-separated list of inventory item IDs.
+    /// (maximum: 50).
+    pub inventory_item_ids: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
+    /// (maximum: 50).
+    pub location_ids: Option<String>,
+    /// Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl InventoryDeprecated202007GetLevelParams {
+    pub fn inventory_item_ids<T: ToString>(mut self, inventory_item_ids: T) -> Self {
+        self.inventory_item_ids = Some(inventory_item_ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn location_ids<T: ToString>(mut self, location_ids: T) -> Self {
+        self.location_ids = Some(location_ids.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202007_delete_levels_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202007DeleteLevelsParams {
+    /// recurring_application_charge[capped_amount].
+    pub inventory_item_id: Option<i64>,
+    pub inventory_item_id_required: Option<String>,
+    /// recurring_application_charge[capped_amount].
+    pub location_id: Option<i64>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecated202007DeleteLevelsParams {
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inventory_item_id = Some(inventory_item_id);
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202007_create_levels_adjust_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202007CreateLevelsAdjustParams {
+    /// The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
+    pub available_adjustment_required: Option<String>,
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecated202007CreateLevelsAdjustParams {
+    pub fn available_adjustment_required<T: ToString>(
+        mut self,
+        available_adjustment_required: T,
+    ) -> Self {
+        self.available_adjustment_required = Some(available_adjustment_required.to_string());
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202007_create_levels_connect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202007CreateLevelsConnectParams {
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+    /// Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
+    /// (default: false).
+    pub relocate_if_necessary: Option<String>,
+}
+
+impl InventoryDeprecated202007CreateLevelsConnectParams {
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+
+    pub fn relocate_if_necessary<T: ToString>(mut self, relocate_if_necessary: T) -> Self {
+        self.relocate_if_necessary = Some(relocate_if_necessary.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202007_create_levels_set_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202007CreateLevelsSetParams {
+    /// Sets the available inventory quantity.
+    pub available_required: Option<String>,
+    /// Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
+    /// (default: false).
+    pub disconnect_if_necessary: Option<String>,
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecated202007CreateLevelsSetParams {
+    pub fn available_required<T: ToString>(mut self, available_required: T) -> Self {
+        self.available_required = Some(available_required.to_string());
+        self
+    }
+
+    pub fn disconnect_if_necessary<T: ToString>(mut self, disconnect_if_necessary: T) -> Self {
+        self.disconnect_if_necessary = Some(disconnect_if_necessary.to_string());
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::get_level_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryGetLevelParams {
+    /// A comma-separated list of inventory item IDs.
+    /// (maximum: 50).
+    pub inventory_item_ids: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
+    /// (maximum: 50).
+    pub location_ids: Option<String>,
+    /// Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl InventoryGetLevelParams {
+    pub fn inventory_item_ids<T: ToString>(mut self, inventory_item_ids: T) -> Self {
+        self.inventory_item_ids = Some(inventory_item_ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn location_ids<T: ToString>(mut self, location_ids: T) -> Self {
+        self.location_ids = Some(location_ids.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::delete_levels_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeleteLevelsParams {
+    /// recurring_application_charge[capped_amount].
+    pub inventory_item_id: Option<i64>,
+    pub inventory_item_id_required: Option<String>,
+    /// recurring_application_charge[capped_amount].
+    pub location_id: Option<i64>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeleteLevelsParams {
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inventory_item_id = Some(inventory_item_id);
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::create_levels_adjust_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryCreateLevelsAdjustParams {
+    /// The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
+    pub available_adjustment_required: Option<String>,
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryCreateLevelsAdjustParams {
+    pub fn available_adjustment_required<T: ToString>(
+        mut self,
+        available_adjustment_required: T,
+    ) -> Self {
+        self.available_adjustment_required = Some(available_adjustment_required.to_string());
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::create_levels_connect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryCreateLevelsConnectParams {
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+    /// Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
+    /// (default: false).
+    pub relocate_if_necessary: Option<String>,
+}
+
+impl InventoryCreateLevelsConnectParams {
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+
+    pub fn relocate_if_necessary<T: ToString>(mut self, relocate_if_necessary: T) -> Self {
+        self.relocate_if_necessary = Some(relocate_if_necessary.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::create_levels_set_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryCreateLevelsSetParams {
+    /// Sets the available inventory quantity.
+    pub available_required: Option<String>,
+    /// Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
+    /// (default: false).
+    pub disconnect_if_necessary: Option<String>,
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryCreateLevelsSetParams {
+    pub fn available_required<T: ToString>(mut self, available_required: T) -> Self {
+        self.available_required = Some(available_required.to_string());
+        self
+    }
+
+    pub fn disconnect_if_necessary<T: ToString>(mut self, disconnect_if_necessary: T) -> Self {
+        self.disconnect_if_necessary = Some(disconnect_if_necessary.to_string());
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202101_get_level_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202101GetLevelParams {
+    /// A comma-separated list of inventory item IDs.
+    /// (maximum: 50).
+    pub inventory_item_ids: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
+    /// (maximum: 50).
+    pub location_ids: Option<String>,
+    /// Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl InventoryDeprecated202101GetLevelParams {
+    pub fn inventory_item_ids<T: ToString>(mut self, inventory_item_ids: T) -> Self {
+        self.inventory_item_ids = Some(inventory_item_ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn location_ids<T: ToString>(mut self, location_ids: T) -> Self {
+        self.location_ids = Some(location_ids.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202101_delete_levels_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202101DeleteLevelsParams {
+    /// recurring_application_charge[capped_amount].
+    pub inventory_item_id: Option<i64>,
+    pub inventory_item_id_required: Option<String>,
+    /// recurring_application_charge[capped_amount].
+    pub location_id: Option<i64>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecated202101DeleteLevelsParams {
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inventory_item_id = Some(inventory_item_id);
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202101_create_levels_adjust_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202101CreateLevelsAdjustParams {
+    /// The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
+    pub available_adjustment_required: Option<String>,
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecated202101CreateLevelsAdjustParams {
+    pub fn available_adjustment_required<T: ToString>(
+        mut self,
+        available_adjustment_required: T,
+    ) -> Self {
+        self.available_adjustment_required = Some(available_adjustment_required.to_string());
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202101_create_levels_connect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202101CreateLevelsConnectParams {
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+    /// Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
+    /// (default: false).
+    pub relocate_if_necessary: Option<String>,
+}
+
+impl InventoryDeprecated202101CreateLevelsConnectParams {
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+
+    pub fn relocate_if_necessary<T: ToString>(mut self, relocate_if_necessary: T) -> Self {
+        self.relocate_if_necessary = Some(relocate_if_necessary.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_202101_create_levels_set_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecated202101CreateLevelsSetParams {
+    /// Sets the available inventory quantity.
+    pub available_required: Option<String>,
+    /// Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
+    /// (default: false).
+    pub disconnect_if_necessary: Option<String>,
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecated202101CreateLevelsSetParams {
+    pub fn available_required<T: ToString>(mut self, available_required: T) -> Self {
+        self.available_required = Some(available_required.to_string());
+        self
+    }
+
+    pub fn disconnect_if_necessary<T: ToString>(mut self, disconnect_if_necessary: T) -> Self {
+        self.disconnect_if_necessary = Some(disconnect_if_necessary.to_string());
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_unstable_get_level_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecatedUnstableGetLevelParams {
+    /// A comma-separated list of inventory item IDs.
+    /// (maximum: 50).
+    pub inventory_item_ids: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// A comma-separated list of location IDs. To find the ID of a location, use the Location resource.
+    /// (maximum: 50).
+    pub location_ids: Option<String>,
+    /// Show inventory levels updated at or after date (format: 2019-03-19T01:21:44-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl InventoryDeprecatedUnstableGetLevelParams {
+    pub fn inventory_item_ids<T: ToString>(mut self, inventory_item_ids: T) -> Self {
+        self.inventory_item_ids = Some(inventory_item_ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn location_ids<T: ToString>(mut self, location_ids: T) -> Self {
+        self.location_ids = Some(location_ids.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_unstable_delete_levels_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecatedUnstableDeleteLevelsParams {
+    /// recurring_application_charge[capped_amount].
+    pub inventory_item_id: Option<i64>,
+    pub inventory_item_id_required: Option<String>,
+    /// recurring_application_charge[capped_amount].
+    pub location_id: Option<i64>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecatedUnstableDeleteLevelsParams {
+    pub fn inventory_item_id(mut self, inventory_item_id: i64) -> Self {
+        self.inventory_item_id = Some(inventory_item_id);
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id(mut self, location_id: i64) -> Self {
+        self.location_id = Some(location_id);
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_unstable_create_levels_adjust_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecatedUnstableCreateLevelsAdjustParams {
+    /// The amount to adjust the available inventory quantity. Send negative values to subtract from the current available quantity. For example, "available_adjustment": 2 increases the current available quantity by 2, and "available_adjustment": -3decreases the current available quantity by 3.
+    pub available_adjustment_required: Option<String>,
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecatedUnstableCreateLevelsAdjustParams {
+    pub fn available_adjustment_required<T: ToString>(
+        mut self,
+        available_adjustment_required: T,
+    ) -> Self {
+        self.available_adjustment_required = Some(available_adjustment_required.to_string());
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_unstable_create_levels_connect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecatedUnstableCreateLevelsConnectParams {
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+    /// Whether inventory for any previously connected locations will be relocated. This property is ignored when no fulfillment service location is involved. For more information, see Inventory levels and fulfillment service locations.
+    /// (default: false).
+    pub relocate_if_necessary: Option<String>,
+}
+
+impl InventoryDeprecatedUnstableCreateLevelsConnectParams {
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+
+    pub fn relocate_if_necessary<T: ToString>(mut self, relocate_if_necessary: T) -> Self {
+        self.relocate_if_necessary = Some(relocate_if_necessary.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Inventory::deprecated_unstable_create_levels_set_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InventoryDeprecatedUnstableCreateLevelsSetParams {
+    /// Sets the available inventory quantity.
+    pub available_required: Option<String>,
+    /// Whether inventory for any previously connected locations will be set to 0 and the locations disconnected. This property is ignored when no fulfillment service  is involved. For more information, see Inventory levels and fulfillment service locations.
+    /// (default: false).
+    pub disconnect_if_necessary: Option<String>,
+    pub inventory_item_id_required: Option<String>,
+    pub location_id_required: Option<String>,
+}
+
+impl InventoryDeprecatedUnstableCreateLevelsSetParams {
+    pub fn available_required<T: ToString>(mut self, available_required: T) -> Self {
+        self.available_required = Some(available_required.to_string());
+        self
+    }
+
+    pub fn disconnect_if_necessary<T: ToString>(mut self, disconnect_if_necessary: T) -> Self {
+        self.disconnect_if_necessary = Some(disconnect_if_necessary.to_string());
+        self
+    }
+
+    pub fn inventory_item_id_required<T: ToString>(
+        mut self,
+        inventory_item_id_required: T,
+    ) -> Self {
+        self.inventory_item_id_required = Some(inventory_item_id_required.to_string());
+        self
+    }
+
+    pub fn location_id_required<T: ToString>(mut self, location_id_required: T) -> Self {
+        self.location_id_required = Some(location_id_required.to_string());
+        self
+    }
+}
+
+/// The functions of [`Inventory`], for code that takes them as a trait, so that it can be
+/// tested with the `MockInventoryApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait InventoryApi: Send + Sync {
+    /// See [`Inventory::deprecated_202001_get_item_with`].
+    async fn deprecated_202001_get_item_with(
+        &self,
+        params: &InventoryDeprecated202001GetItemParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202001_get_items_param_item`].
+    async fn deprecated_202001_get_items_param_item(&self, inventory_item_id: &str) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202001_update_items_param_item`].
+    async fn deprecated_202001_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202004_get_item_with`].
+    async fn deprecated_202004_get_item_with(
+        &self,
+        params: &InventoryDeprecated202004GetItemParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202004_get_items_param_item`].
+    async fn deprecated_202004_get_items_param_item(&self, inventory_item_id: &str) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202004_update_items_param_item`].
+    async fn deprecated_202004_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202007_get_item_with`].
+    async fn deprecated_202007_get_item_with(
+        &self,
+        params: &InventoryDeprecated202007GetItemParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202007_get_items_param_item`].
+    async fn deprecated_202007_get_items_param_item(&self, inventory_item_id: &str) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202007_update_items_param_item`].
+    async fn deprecated_202007_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::get_item_with`].
+    async fn get_item_with(&self, params: &InventoryGetItemParams) -> Result<()>;
+
+    /// See [`Inventory::get_items_param_item`].
+    async fn get_items_param_item(&self, inventory_item_id: &str) -> Result<()>;
+
+    /// See [`Inventory::update_items_param_item`].
+    async fn update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202101_get_item_with`].
+    async fn deprecated_202101_get_item_with(
+        &self,
+        params: &InventoryDeprecated202101GetItemParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202101_get_items_param_item`].
+    async fn deprecated_202101_get_items_param_item(&self, inventory_item_id: &str) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202101_update_items_param_item`].
+    async fn deprecated_202101_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_unstable_get_item_with`].
+    async fn deprecated_unstable_get_item_with(
+        &self,
+        params: &InventoryDeprecatedUnstableGetItemParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_unstable_get_items_param_item`].
+    async fn deprecated_unstable_get_items_param_item(&self, inventory_item_id: &str)
+        -> Result<()>;
+
+    /// See [`Inventory::deprecated_unstable_update_items_param_item`].
+    async fn deprecated_unstable_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202001_get_level_with`].
+    async fn deprecated_202001_get_level_with(
+        &self,
+        params: &InventoryDeprecated202001GetLevelParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202001_delete_levels_with`].
+    async fn deprecated_202001_delete_levels_with(
+        &self,
+        params: &InventoryDeprecated202001DeleteLevelsParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202001_create_levels_adjust_with`].
+    async fn deprecated_202001_create_levels_adjust_with(
+        &self,
+        params: &InventoryDeprecated202001CreateLevelsAdjustParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202001_create_levels_connect_with`].
+    async fn deprecated_202001_create_levels_connect_with(
+        &self,
+        params: &InventoryDeprecated202001CreateLevelsConnectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202001_create_levels_set_with`].
+    async fn deprecated_202001_create_levels_set_with(
+        &self,
+        params: &InventoryDeprecated202001CreateLevelsSetParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202004_get_level_with`].
+    async fn deprecated_202004_get_level_with(
+        &self,
+        params: &InventoryDeprecated202004GetLevelParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202004_delete_levels_with`].
+    async fn deprecated_202004_delete_levels_with(
+        &self,
+        params: &InventoryDeprecated202004DeleteLevelsParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202004_create_levels_adjust_with`].
+    async fn deprecated_202004_create_levels_adjust_with(
+        &self,
+        params: &InventoryDeprecated202004CreateLevelsAdjustParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202004_create_levels_connect_with`].
+    async fn deprecated_202004_create_levels_connect_with(
+        &self,
+        params: &InventoryDeprecated202004CreateLevelsConnectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202004_create_levels_set_with`].
+    async fn deprecated_202004_create_levels_set_with(
+        &self,
+        params: &InventoryDeprecated202004CreateLevelsSetParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202007_get_level_with`].
+    async fn deprecated_202007_get_level_with(
+        &self,
+        params: &InventoryDeprecated202007GetLevelParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202007_delete_levels_with`].
+    async fn deprecated_202007_delete_levels_with(
+        &self,
+        params: &InventoryDeprecated202007DeleteLevelsParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202007_create_levels_adjust_with`].
+    async fn deprecated_202007_create_levels_adjust_with(
+        &self,
+        params: &InventoryDeprecated202007CreateLevelsAdjustParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202007_create_levels_connect_with`].
+    async fn deprecated_202007_create_levels_connect_with(
+        &self,
+        params: &InventoryDeprecated202007CreateLevelsConnectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202007_create_levels_set_with`].
+    async fn deprecated_202007_create_levels_set_with(
+        &self,
+        params: &InventoryDeprecated202007CreateLevelsSetParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::get_level_with`].
+    async fn get_level_with(&self, params: &InventoryGetLevelParams) -> Result<()>;
+
+    /// See [`Inventory::delete_levels_with`].
+    async fn delete_levels_with(&self, params: &InventoryDeleteLevelsParams) -> Result<()>;
+
+    /// See [`Inventory::create_levels_adjust_with`].
+    async fn create_levels_adjust_with(
+        &self,
+        params: &InventoryCreateLevelsAdjustParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::create_levels_connect_with`].
+    async fn create_levels_connect_with(
+        &self,
+        params: &InventoryCreateLevelsConnectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::create_levels_set_with`].
+    async fn create_levels_set_with(
+        &self,
+        params: &InventoryCreateLevelsSetParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202101_get_level_with`].
+    async fn deprecated_202101_get_level_with(
+        &self,
+        params: &InventoryDeprecated202101GetLevelParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202101_delete_levels_with`].
+    async fn deprecated_202101_delete_levels_with(
+        &self,
+        params: &InventoryDeprecated202101DeleteLevelsParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202101_create_levels_adjust_with`].
+    async fn deprecated_202101_create_levels_adjust_with(
+        &self,
+        params: &InventoryDeprecated202101CreateLevelsAdjustParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202101_create_levels_connect_with`].
+    async fn deprecated_202101_create_levels_connect_with(
+        &self,
+        params: &InventoryDeprecated202101CreateLevelsConnectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202101_create_levels_set_with`].
+    async fn deprecated_202101_create_levels_set_with(
+        &self,
+        params: &InventoryDeprecated202101CreateLevelsSetParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_unstable_get_level_with`].
+    async fn deprecated_unstable_get_level_with(
+        &self,
+        params: &InventoryDeprecatedUnstableGetLevelParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_unstable_delete_levels_with`].
+    async fn deprecated_unstable_delete_levels_with(
+        &self,
+        params: &InventoryDeprecatedUnstableDeleteLevelsParams,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_unstable_create_levels_adjust_with`].
+    async fn deprecated_unstable_create_levels_adjust_with(
+        &self,
+        params: &InventoryDeprecatedUnstableCreateLevelsAdjustParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_unstable_create_levels_connect_with`].
+    async fn deprecated_unstable_create_levels_connect_with(
+        &self,
+        params: &InventoryDeprecatedUnstableCreateLevelsConnectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_unstable_create_levels_set_with`].
+    async fn deprecated_unstable_create_levels_set_with(
+        &self,
+        params: &InventoryDeprecatedUnstableCreateLevelsSetParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202001_get_location`].
+    async fn deprecated_202001_get_location(&self) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202001_get_locations_param_location`].
+    async fn deprecated_202001_get_locations_param_location(&self, location_id: &str)
+        -> Result<()>;
+
+    /// See [`Inventory::deprecated_202001_get_locations_count`].
+    async fn deprecated_202001_get_locations_count(&self) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202001_get_locations_param_location_level`].
+    async fn deprecated_202001_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202004_get_location`].
+    async fn deprecated_202004_get_location(&self) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202004_get_locations_param_location`].
+    async fn deprecated_202004_get_locations_param_location(&self, location_id: &str)
+        -> Result<()>;
+
+    /// See [`Inventory::deprecated_202004_get_locations_count`].
+    async fn deprecated_202004_get_locations_count(&self) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202004_get_locations_param_location_level`].
+    async fn deprecated_202004_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202007_get_location`].
+    async fn deprecated_202007_get_location(&self) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202007_get_locations_param_location`].
+    async fn deprecated_202007_get_locations_param_location(&self, location_id: &str)
+        -> Result<()>;
+
+    /// See [`Inventory::deprecated_202007_get_locations_count`].
+    async fn deprecated_202007_get_locations_count(&self) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202007_get_locations_param_location_level`].
+    async fn deprecated_202007_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Inventory::get_location`].
+    async fn get_location(&self) -> Result<()>;
+
+    /// See [`Inventory::get_locations_param_location`].
+    async fn get_locations_param_location(&self, location_id: &str) -> Result<()>;
+
+    /// See [`Inventory::get_locations_count`].
+    async fn get_locations_count(&self) -> Result<()>;
+
+    /// See [`Inventory::get_locations_param_location_level`].
+    async fn get_locations_param_location_level(&self, location_id: &str) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202101_get_location`].
+    async fn deprecated_202101_get_location(&self) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202101_get_locations_param_location`].
+    async fn deprecated_202101_get_locations_param_location(&self, location_id: &str)
+        -> Result<()>;
+
+    /// See [`Inventory::deprecated_202101_get_locations_count`].
+    async fn deprecated_202101_get_locations_count(&self) -> Result<()>;
+
+    /// See [`Inventory::deprecated_202101_get_locations_param_location_level`].
+    async fn deprecated_202101_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_unstable_get_location`].
+    async fn deprecated_unstable_get_location(&self) -> Result<()>;
+
+    /// See [`Inventory::deprecated_unstable_get_locations_param_location`].
+    async fn deprecated_unstable_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Inventory::deprecated_unstable_get_locations_count`].
+    async fn deprecated_unstable_get_locations_count(&self) -> Result<()>;
+
+    /// See [`Inventory::deprecated_unstable_get_locations_param_location_level`].
+    async fn deprecated_unstable_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl InventoryApi for Inventory {
+    async fn deprecated_202001_get_item_with(
+        &self,
+        params: &InventoryDeprecated202001GetItemParams,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_get_item_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_items_param_item(&self, inventory_item_id: &str) -> Result<()> {
+        Inventory::deprecated_202001_get_items_param_item(self, inventory_item_id).await
+    }
+
+    async fn deprecated_202001_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_update_items_param_item(self, inventory_item_id, body).await
+    }
+
+    async fn deprecated_202004_get_item_with(
+        &self,
+        params: &InventoryDeprecated202004GetItemParams,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_get_item_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_items_param_item(&self, inventory_item_id: &str) -> Result<()> {
+        Inventory::deprecated_202004_get_items_param_item(self, inventory_item_id).await
+    }
+
+    async fn deprecated_202004_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_update_items_param_item(self, inventory_item_id, body).await
+    }
+
+    async fn deprecated_202007_get_item_with(
+        &self,
+        params: &InventoryDeprecated202007GetItemParams,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_get_item_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_items_param_item(&self, inventory_item_id: &str) -> Result<()> {
+        Inventory::deprecated_202007_get_items_param_item(self, inventory_item_id).await
+    }
+
+    async fn deprecated_202007_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_update_items_param_item(self, inventory_item_id, body).await
+    }
+
+    async fn get_item_with(&self, params: &InventoryGetItemParams) -> Result<()> {
+        Inventory::get_item_with(self, params).await
+    }
+
+    async fn get_items_param_item(&self, inventory_item_id: &str) -> Result<()> {
+        Inventory::get_items_param_item(self, inventory_item_id).await
+    }
+
+    async fn update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::update_items_param_item(self, inventory_item_id, body).await
+    }
+
+    async fn deprecated_202101_get_item_with(
+        &self,
+        params: &InventoryDeprecated202101GetItemParams,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_get_item_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_items_param_item(&self, inventory_item_id: &str) -> Result<()> {
+        Inventory::deprecated_202101_get_items_param_item(self, inventory_item_id).await
+    }
+
+    async fn deprecated_202101_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_update_items_param_item(self, inventory_item_id, body).await
+    }
+
+    async fn deprecated_unstable_get_item_with(
+        &self,
+        params: &InventoryDeprecatedUnstableGetItemParams,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_get_item_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_items_param_item(
+        &self,
+        inventory_item_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_get_items_param_item(self, inventory_item_id).await
+    }
+
+    async fn deprecated_unstable_update_items_param_item(
+        &self,
+        inventory_item_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_update_items_param_item(self, inventory_item_id, body).await
+    }
+
+    async fn deprecated_202001_get_level_with(
+        &self,
+        params: &InventoryDeprecated202001GetLevelParams,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_get_level_with(self, params).await
+    }
+
+    async fn deprecated_202001_delete_levels_with(
+        &self,
+        params: &InventoryDeprecated202001DeleteLevelsParams,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_delete_levels_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_levels_adjust_with(
+        &self,
+        params: &InventoryDeprecated202001CreateLevelsAdjustParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_create_levels_adjust_with(self, params, body).await
+    }
+
+    async fn deprecated_202001_create_levels_connect_with(
+        &self,
+        params: &InventoryDeprecated202001CreateLevelsConnectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_create_levels_connect_with(self, params, body).await
+    }
+
+    async fn deprecated_202001_create_levels_set_with(
+        &self,
+        params: &InventoryDeprecated202001CreateLevelsSetParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_create_levels_set_with(self, params, body).await
+    }
+
+    async fn deprecated_202004_get_level_with(
+        &self,
+        params: &InventoryDeprecated202004GetLevelParams,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_get_level_with(self, params).await
+    }
+
+    async fn deprecated_202004_delete_levels_with(
+        &self,
+        params: &InventoryDeprecated202004DeleteLevelsParams,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_delete_levels_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_levels_adjust_with(
+        &self,
+        params: &InventoryDeprecated202004CreateLevelsAdjustParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_create_levels_adjust_with(self, params, body).await
+    }
+
+    async fn deprecated_202004_create_levels_connect_with(
+        &self,
+        params: &InventoryDeprecated202004CreateLevelsConnectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_create_levels_connect_with(self, params, body).await
+    }
+
+    async fn deprecated_202004_create_levels_set_with(
+        &self,
+        params: &InventoryDeprecated202004CreateLevelsSetParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_create_levels_set_with(self, params, body).await
+    }
+
+    async fn deprecated_202007_get_level_with(
+        &self,
+        params: &InventoryDeprecated202007GetLevelParams,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_get_level_with(self, params).await
+    }
+
+    async fn deprecated_202007_delete_levels_with(
+        &self,
+        params: &InventoryDeprecated202007DeleteLevelsParams,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_delete_levels_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_levels_adjust_with(
+        &self,
+        params: &InventoryDeprecated202007CreateLevelsAdjustParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_create_levels_adjust_with(self, params, body).await
+    }
+
+    async fn deprecated_202007_create_levels_connect_with(
+        &self,
+        params: &InventoryDeprecated202007CreateLevelsConnectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_create_levels_connect_with(self, params, body).await
+    }
+
+    async fn deprecated_202007_create_levels_set_with(
+        &self,
+        params: &InventoryDeprecated202007CreateLevelsSetParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_create_levels_set_with(self, params, body).await
+    }
+
+    async fn get_level_with(&self, params: &InventoryGetLevelParams) -> Result<()> {
+        Inventory::get_level_with(self, params).await
+    }
+
+    async fn delete_levels_with(&self, params: &InventoryDeleteLevelsParams) -> Result<()> {
+        Inventory::delete_levels_with(self, params).await
+    }
+
+    async fn create_levels_adjust_with(
+        &self,
+        params: &InventoryCreateLevelsAdjustParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::create_levels_adjust_with(self, params, body).await
+    }
+
+    async fn create_levels_connect_with(
+        &self,
+        params: &InventoryCreateLevelsConnectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::create_levels_connect_with(self, params, body).await
+    }
+
+    async fn create_levels_set_with(
+        &self,
+        params: &InventoryCreateLevelsSetParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::create_levels_set_with(self, params, body).await
+    }
+
+    async fn deprecated_202101_get_level_with(
+        &self,
+        params: &InventoryDeprecated202101GetLevelParams,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_get_level_with(self, params).await
+    }
+
+    async fn deprecated_202101_delete_levels_with(
+        &self,
+        params: &InventoryDeprecated202101DeleteLevelsParams,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_delete_levels_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_levels_adjust_with(
+        &self,
+        params: &InventoryDeprecated202101CreateLevelsAdjustParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_create_levels_adjust_with(self, params, body).await
+    }
+
+    async fn deprecated_202101_create_levels_connect_with(
+        &self,
+        params: &InventoryDeprecated202101CreateLevelsConnectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_create_levels_connect_with(self, params, body).await
+    }
+
+    async fn deprecated_202101_create_levels_set_with(
+        &self,
+        params: &InventoryDeprecated202101CreateLevelsSetParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_create_levels_set_with(self, params, body).await
+    }
+
+    async fn deprecated_unstable_get_level_with(
+        &self,
+        params: &InventoryDeprecatedUnstableGetLevelParams,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_get_level_with(self, params).await
+    }
+
+    async fn deprecated_unstable_delete_levels_with(
+        &self,
+        params: &InventoryDeprecatedUnstableDeleteLevelsParams,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_delete_levels_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_levels_adjust_with(
+        &self,
+        params: &InventoryDeprecatedUnstableCreateLevelsAdjustParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_create_levels_adjust_with(self, params, body).await
+    }
+
+    async fn deprecated_unstable_create_levels_connect_with(
+        &self,
+        params: &InventoryDeprecatedUnstableCreateLevelsConnectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_create_levels_connect_with(self, params, body).await
+    }
+
+    async fn deprecated_unstable_create_levels_set_with(
+        &self,
+        params: &InventoryDeprecatedUnstableCreateLevelsSetParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_create_levels_set_with(self, params, body).await
+    }
+
+    async fn deprecated_202001_get_location(&self) -> Result<()> {
+        Inventory::deprecated_202001_get_location(self).await
+    }
+
+    async fn deprecated_202001_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_get_locations_param_location(self, location_id).await
+    }
+
+    async fn deprecated_202001_get_locations_count(&self) -> Result<()> {
+        Inventory::deprecated_202001_get_locations_count(self).await
+    }
+
+    async fn deprecated_202001_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202001_get_locations_param_location_level(self, location_id).await
+    }
+
+    async fn deprecated_202004_get_location(&self) -> Result<()> {
+        Inventory::deprecated_202004_get_location(self).await
+    }
+
+    async fn deprecated_202004_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_get_locations_param_location(self, location_id).await
+    }
+
+    async fn deprecated_202004_get_locations_count(&self) -> Result<()> {
+        Inventory::deprecated_202004_get_locations_count(self).await
+    }
+
+    async fn deprecated_202004_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202004_get_locations_param_location_level(self, location_id).await
+    }
+
+    async fn deprecated_202007_get_location(&self) -> Result<()> {
+        Inventory::deprecated_202007_get_location(self).await
+    }
+
+    async fn deprecated_202007_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_get_locations_param_location(self, location_id).await
+    }
+
+    async fn deprecated_202007_get_locations_count(&self) -> Result<()> {
+        Inventory::deprecated_202007_get_locations_count(self).await
+    }
+
+    async fn deprecated_202007_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202007_get_locations_param_location_level(self, location_id).await
+    }
+
+    async fn get_location(&self) -> Result<()> {
+        Inventory::get_location(self).await
+    }
+
+    async fn get_locations_param_location(&self, location_id: &str) -> Result<()> {
+        Inventory::get_locations_param_location(self, location_id).await
+    }
+
+    async fn get_locations_count(&self) -> Result<()> {
+        Inventory::get_locations_count(self).await
+    }
+
+    async fn get_locations_param_location_level(&self, location_id: &str) -> Result<()> {
+        Inventory::get_locations_param_location_level(self, location_id).await
+    }
+
+    async fn deprecated_202101_get_location(&self) -> Result<()> {
+        Inventory::deprecated_202101_get_location(self).await
+    }
+
+    async fn deprecated_202101_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_get_locations_param_location(self, location_id).await
+    }
+
+    async fn deprecated_202101_get_locations_count(&self) -> Result<()> {
+        Inventory::deprecated_202101_get_locations_count(self).await
+    }
+
+    async fn deprecated_202101_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_202101_get_locations_param_location_level(self, location_id).await
+    }
+
+    async fn deprecated_unstable_get_location(&self) -> Result<()> {
+        Inventory::deprecated_unstable_get_location(self).await
+    }
+
+    async fn deprecated_unstable_get_locations_param_location(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_get_locations_param_location(self, location_id).await
+    }
+
+    async fn deprecated_unstable_get_locations_count(&self) -> Result<()> {
+        Inventory::deprecated_unstable_get_locations_count(self).await
+    }
+
+    async fn deprecated_unstable_get_locations_param_location_level(
+        &self,
+        location_id: &str,
+    ) -> Result<()> {
+        Inventory::deprecated_unstable_get_locations_param_location_level(self, location_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Metafield {
     pub client: Client,
@@ -21,22 +19,20 @@ impl Metafield {
      *
      * **Parameters:**
      *
-     * * `metafield_owner_id: i64` -- recurring_application_charge[capped_amount].
-     * * `metafield_owner_resource: &str` -- storefront_access_token_id.
+     * * `params: &MetafieldDeprecated202001GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get(
+    pub async fn deprecated_202001_get_with(
         &self,
-        metafield_owner_id: i64,
-        metafield_owner_resource: &str,
+        params: &MetafieldDeprecated202001GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if metafield_owner_id > 0 {
+        if let Some(metafield_owner_id) = &params.metafield_owner_id {
             query_args.push((
                 "metafield[owner_id]".to_string(),
                 metafield_owner_id.to_string(),
             ));
         }
-        if !metafield_owner_resource.is_empty() {
+        if let Some(metafield_owner_resource) = &params.metafield_owner_resource {
             query_args.push((
                 "metafield[owner_resource]".to_string(),
                 metafield_owner_resource.to_string(),
@@ -48,6 +44,23 @@ impl Metafield {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_with` and pass the query parameters as a `MetafieldDeprecated202001GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_with` instead")]
+    pub async fn deprecated_202001_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()> {
+        let params = MetafieldDeprecated202001GetParams {
+            metafield_owner_id: Some(metafield_owner_id).filter(|v| *v > 0),
+            metafield_owner_resource: Some(metafield_owner_resource.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_with(&params).await
+    }
+
     /**
      * Creates a new metafield for a resource.
      *
@@ -84,15 +97,15 @@ impl Metafield {
      * **Parameters:**
      *
      * * `metafield_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &MetafieldDeprecated202001GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_param(
+    pub async fn deprecated_202001_get_param_with(
         &self,
         metafield_id: &str,
-        fields: &str,
+        params: &MetafieldDeprecated202001GetParamParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -105,6 +118,22 @@ impl Metafield {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_param_with` and pass the query parameters as a `MetafieldDeprecated202001GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_param_with` instead")]
+    pub async fn deprecated_202001_get_param(
+        &self,
+        metafield_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = MetafieldDeprecated202001GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_param_with(metafield_id, &params)
+            .await
+    }
+
     /**
      * Updates a metafield.
      *
@@ -160,22 +189,20 @@ impl Metafield {
      *
      * **Parameters:**
      *
-     * * `metafield_owner_id: i64` -- recurring_application_charge[capped_amount].
-     * * `metafield_owner_resource: &str` -- storefront_access_token_id.
+     * * `params: &MetafieldDeprecated202004GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get(
+    pub async fn deprecated_202004_get_with(
         &self,
-        metafield_owner_id: i64,
-        metafield_owner_resource: &str,
+        params: &MetafieldDeprecated202004GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if metafield_owner_id > 0 {
+        if let Some(metafield_owner_id) = &params.metafield_owner_id {
             query_args.push((
                 "metafield[owner_id]".to_string(),
                 metafield_owner_id.to_string(),
             ));
         }
-        if !metafield_owner_resource.is_empty() {
+        if let Some(metafield_owner_resource) = &params.metafield_owner_resource {
             query_args.push((
                 "metafield[owner_resource]".to_string(),
                 metafield_owner_resource.to_string(),
@@ -187,6 +214,23 @@ impl Metafield {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_with` and pass the query parameters as a `MetafieldDeprecated202004GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_with` instead")]
+    pub async fn deprecated_202004_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()> {
+        let params = MetafieldDeprecated202004GetParams {
+            metafield_owner_id: Some(metafield_owner_id).filter(|v| *v > 0),
+            metafield_owner_resource: Some(metafield_owner_resource.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_with(&params).await
+    }
+
     /**
      * Creates a new metafield for a resource.
      *
@@ -223,15 +267,15 @@ impl Metafield {
      * **Parameters:**
      *
      * * `metafield_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &MetafieldDeprecated202004GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_param(
+    pub async fn deprecated_202004_get_param_with(
         &self,
         metafield_id: &str,
-        fields: &str,
+        params: &MetafieldDeprecated202004GetParamParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -244,6 +288,22 @@ impl Metafield {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_param_with` and pass the query parameters as a `MetafieldDeprecated202004GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_param_with` instead")]
+    pub async fn deprecated_202004_get_param(
+        &self,
+        metafield_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = MetafieldDeprecated202004GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_param_with(metafield_id, &params)
+            .await
+    }
+
     /**
      * Updates a metafield.
      *
@@ -299,22 +359,20 @@ impl Metafield {
      *
      * **Parameters:**
      *
-     * * `metafield_owner_id: i64` -- recurring_application_charge[capped_amount].
-     * * `metafield_owner_resource: &str` -- storefront_access_token_id.
+     * * `params: &MetafieldDeprecated202007GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get(
+    pub async fn deprecated_202007_get_with(
         &self,
-        metafield_owner_id: i64,
-        metafield_owner_resource: &str,
+        params: &MetafieldDeprecated202007GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if metafield_owner_id > 0 {
+        if let Some(metafield_owner_id) = &params.metafield_owner_id {
             query_args.push((
                 "metafield[owner_id]".to_string(),
                 metafield_owner_id.to_string(),
             ));
         }
-        if !metafield_owner_resource.is_empty() {
+        if let Some(metafield_owner_resource) = &params.metafield_owner_resource {
             query_args.push((
                 "metafield[owner_resource]".to_string(),
                 metafield_owner_resource.to_string(),
@@ -326,6 +384,23 @@ impl Metafield {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_with` and pass the query parameters as a `MetafieldDeprecated202007GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_with` instead")]
+    pub async fn deprecated_202007_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()> {
+        let params = MetafieldDeprecated202007GetParams {
+            metafield_owner_id: Some(metafield_owner_id).filter(|v| *v > 0),
+            metafield_owner_resource: Some(metafield_owner_resource.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_with(&params).await
+    }
+
     /**
      * Creates a new metafield for a resource.
      *
@@ -362,15 +437,15 @@ impl Metafield {
      * **Parameters:**
      *
      * * `metafield_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &MetafieldDeprecated202007GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_param(
+    pub async fn deprecated_202007_get_param_with(
         &self,
         metafield_id: &str,
-        fields: &str,
+        params: &MetafieldDeprecated202007GetParamParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -383,6 +458,22 @@ impl Metafield {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_param_with` and pass the query parameters as a `MetafieldDeprecated202007GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_param_with` instead")]
+    pub async fn deprecated_202007_get_param(
+        &self,
+        metafield_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = MetafieldDeprecated202007GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_param_with(metafield_id, &params)
+            .await
+    }
+
     /**
      * Updates a metafield.
      *
@@ -438,18 +529,17 @@ impl Metafield {
      *
      * **Parameters:**
      *
-     * * `metafield_owner_id: i64` -- recurring_application_charge[capped_amount].
-     * * `metafield_owner_resource: &str` -- storefront_access_token_id.
+     * * `params: &MetafieldGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(&self, metafield_owner_id: i64, metafield_owner_resource: &str) -> Result<()> {
+    pub async fn get_with(&self, params: &MetafieldGetParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if metafield_owner_id > 0 {
+        if let Some(metafield_owner_id) = &params.metafield_owner_id {
             query_args.push((
                 "metafield[owner_id]".to_string(),
                 metafield_owner_id.to_string(),
             ));
         }
-        if !metafield_owner_resource.is_empty() {
+        if let Some(metafield_owner_resource) = &params.metafield_owner_resource {
             query_args.push((
                 "metafield[owner_resource]".to_string(),
                 metafield_owner_resource.to_string(),
@@ -461,6 +551,19 @@ impl Metafield {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `MetafieldGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(&self, metafield_owner_id: i64, metafield_owner_resource: &str) -> Result<()> {
+        let params = MetafieldGetParams {
+            metafield_owner_id: Some(metafield_owner_id).filter(|v| *v > 0),
+            metafield_owner_resource: Some(metafield_owner_resource.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Creates a new metafield for a resource.
      *
@@ -497,11 +600,15 @@ impl Metafield {
      * **Parameters:**
      *
      * * `metafield_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &MetafieldGetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_param(&self, metafield_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_param_with(
+        &self,
+        metafield_id: &str,
+        params: &MetafieldGetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -514,6 +621,17 @@ impl Metafield {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_param_with` and pass the query parameters as a `MetafieldGetParamParams` instead.
+     */
+    #[deprecated(note = "use `get_param_with` instead")]
+    pub async fn get_param(&self, metafield_id: &str, fields: &str) -> Result<()> {
+        let params = MetafieldGetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_param_with(metafield_id, &params).await
+    }
+
     /**
      * Updates a metafield.
      *
@@ -565,22 +683,20 @@ impl Metafield {
      *
      * **Parameters:**
      *
-     * * `metafield_owner_id: i64` -- recurring_application_charge[capped_amount].
-     * * `metafield_owner_resource: &str` -- storefront_access_token_id.
+     * * `params: &MetafieldDeprecated202101GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get(
+    pub async fn deprecated_202101_get_with(
         &self,
-        metafield_owner_id: i64,
-        metafield_owner_resource: &str,
+        params: &MetafieldDeprecated202101GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if metafield_owner_id > 0 {
+        if let Some(metafield_owner_id) = &params.metafield_owner_id {
             query_args.push((
                 "metafield[owner_id]".to_string(),
                 metafield_owner_id.to_string(),
             ));
         }
-        if !metafield_owner_resource.is_empty() {
+        if let Some(metafield_owner_resource) = &params.metafield_owner_resource {
             query_args.push((
                 "metafield[owner_resource]".to_string(),
                 metafield_owner_resource.to_string(),
@@ -592,6 +708,23 @@ impl Metafield {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_with` and pass the query parameters as a `MetafieldDeprecated202101GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_with` instead")]
+    pub async fn deprecated_202101_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()> {
+        let params = MetafieldDeprecated202101GetParams {
+            metafield_owner_id: Some(metafield_owner_id).filter(|v| *v > 0),
+            metafield_owner_resource: Some(metafield_owner_resource.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_with(&params).await
+    }
+
     /**
      * Creates a new metafield for a resource.
      *
@@ -628,15 +761,15 @@ impl Metafield {
      * **Parameters:**
      *
      * * `metafield_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &MetafieldDeprecated202101GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_param(
+    pub async fn deprecated_202101_get_param_with(
         &self,
         metafield_id: &str,
-        fields: &str,
+        params: &MetafieldDeprecated202101GetParamParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -649,6 +782,22 @@ impl Metafield {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_param_with` and pass the query parameters as a `MetafieldDeprecated202101GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_param_with` instead")]
+    pub async fn deprecated_202101_get_param(
+        &self,
+        metafield_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = MetafieldDeprecated202101GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_param_with(metafield_id, &params)
+            .await
+    }
+
     /**
      * Updates a metafield.
      *
@@ -704,22 +853,20 @@ impl Metafield {
      *
      * **Parameters:**
      *
-     * * `metafield_owner_id: i64` -- recurring_application_charge[capped_amount].
-     * * `metafield_owner_resource: &str` -- storefront_access_token_id.
+     * * `params: &MetafieldDeprecatedUnstableGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get(
+    pub async fn deprecated_unstable_get_with(
         &self,
-        metafield_owner_id: i64,
-        metafield_owner_resource: &str,
+        params: &MetafieldDeprecatedUnstableGetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if metafield_owner_id > 0 {
+        if let Some(metafield_owner_id) = &params.metafield_owner_id {
             query_args.push((
                 "metafield[owner_id]".to_string(),
                 metafield_owner_id.to_string(),
             ));
         }
-        if !metafield_owner_resource.is_empty() {
+        if let Some(metafield_owner_resource) = &params.metafield_owner_resource {
             query_args.push((
                 "metafield[owner_resource]".to_string(),
                 metafield_owner_resource.to_string(),
@@ -731,6 +878,23 @@ impl Metafield {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_with` and pass the query parameters as a `MetafieldDeprecatedUnstableGetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_with` instead")]
+    pub async fn deprecated_unstable_get(
+        &self,
+        metafield_owner_id: i64,
+        metafield_owner_resource: &str,
+    ) -> Result<()> {
+        let params = MetafieldDeprecatedUnstableGetParams {
+            metafield_owner_id: Some(metafield_owner_id).filter(|v| *v > 0),
+            metafield_owner_resource: Some(metafield_owner_resource.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_with(&params).await
+    }
+
     /**
      * Creates a new metafield for a resource.
      *
@@ -767,15 +931,15 @@ impl Metafield {
      * **Parameters:**
      *
      * * `metafield_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &MetafieldDeprecatedUnstableGetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_param(
+    pub async fn deprecated_unstable_get_param_with(
         &self,
         metafield_id: &str,
-        fields: &str,
+        params: &MetafieldDeprecatedUnstableGetParamParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -788,6 +952,22 @@ impl Metafield {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_param_with` and pass the query parameters as a `MetafieldDeprecatedUnstableGetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_param_with` instead")]
+    pub async fn deprecated_unstable_get_param(
+        &self,
+        metafield_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = MetafieldDeprecatedUnstableGetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_param_with(metafield_id, &params)
+            .await
+    }
+
     /**
      * Updates a metafield.
      *
@@ -834,3 +1014,592 @@ impl Metafield {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Metafield::deprecated_202001_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MetafieldDeprecated202001GetParams {
+    /// recurring_application_charge[capped_amount].
+    pub metafield_owner_id: Option<i64>,
+    /// storefront_access_token_id.
+    pub metafield_owner_resource: Option<String>,
+}
+
+impl MetafieldDeprecated202001GetParams {
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.metafield_owner_id = Some(metafield_owner_id);
+        self
+    }
+
+    pub fn metafield_owner_resource<T: ToString>(mut self, metafield_owner_resource: T) -> Self {
+        self.metafield_owner_resource = Some(metafield_owner_resource.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Metafield::deprecated_202001_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MetafieldDeprecated202001GetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl MetafieldDeprecated202001GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Metafield::deprecated_202004_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MetafieldDeprecated202004GetParams {
+    /// recurring_application_charge[capped_amount].
+    pub metafield_owner_id: Option<i64>,
+    /// storefront_access_token_id.
+    pub metafield_owner_resource: Option<String>,
+}
+
+impl MetafieldDeprecated202004GetParams {
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.metafield_owner_id = Some(metafield_owner_id);
+        self
+    }
+
+    pub fn metafield_owner_resource<T: ToString>(mut self, metafield_owner_resource: T) -> Self {
+        self.metafield_owner_resource = Some(metafield_owner_resource.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Metafield::deprecated_202004_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MetafieldDeprecated202004GetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl MetafieldDeprecated202004GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Metafield::deprecated_202007_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MetafieldDeprecated202007GetParams {
+    /// recurring_application_charge[capped_amount].
+    pub metafield_owner_id: Option<i64>,
+    /// storefront_access_token_id.
+    pub metafield_owner_resource: Option<String>,
+}
+
+impl MetafieldDeprecated202007GetParams {
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.metafield_owner_id = Some(metafield_owner_id);
+        self
+    }
+
+    pub fn metafield_owner_resource<T: ToString>(mut self, metafield_owner_resource: T) -> Self {
+        self.metafield_owner_resource = Some(metafield_owner_resource.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Metafield::deprecated_202007_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MetafieldDeprecated202007GetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl MetafieldDeprecated202007GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Metafield::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MetafieldGetParams {
+    /// recurring_application_charge[capped_amount].
+    pub metafield_owner_id: Option<i64>,
+    /// storefront_access_token_id.
+    pub metafield_owner_resource: Option<String>,
+}
+
+impl MetafieldGetParams {
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.metafield_owner_id = Some(metafield_owner_id);
+        self
+    }
+
+    pub fn metafield_owner_resource<T: ToString>(mut self, metafield_owner_resource: T) -> Self {
+        self.metafield_owner_resource = Some(metafield_owner_resource.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Metafield::get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MetafieldGetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl MetafieldGetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Metafield::deprecated_202101_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MetafieldDeprecated202101GetParams {
+    /// recurring_application_charge[capped_amount].
+    pub metafield_owner_id: Option<i64>,
+    /// storefront_access_token_id.
+    pub metafield_owner_resource: Option<String>,
+}
+
+impl MetafieldDeprecated202101GetParams {
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.metafield_owner_id = Some(metafield_owner_id);
+        self
+    }
+
+    pub fn metafield_owner_resource<T: ToString>(mut self, metafield_owner_resource: T) -> Self {
+        self.metafield_owner_resource = Some(metafield_owner_resource.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Metafield::deprecated_202101_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MetafieldDeprecated202101GetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl MetafieldDeprecated202101GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Metafield::deprecated_unstable_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MetafieldDeprecatedUnstableGetParams {
+    /// recurring_application_charge[capped_amount].
+    pub metafield_owner_id: Option<i64>,
+    /// storefront_access_token_id.
+    pub metafield_owner_resource: Option<String>,
+}
+
+impl MetafieldDeprecatedUnstableGetParams {
+    pub fn metafield_owner_id(mut self, metafield_owner_id: i64) -> Self {
+        self.metafield_owner_id = Some(metafield_owner_id);
+        self
+    }
+
+    pub fn metafield_owner_resource<T: ToString>(mut self, metafield_owner_resource: T) -> Self {
+        self.metafield_owner_resource = Some(metafield_owner_resource.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Metafield::deprecated_unstable_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MetafieldDeprecatedUnstableGetParamParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl MetafieldDeprecatedUnstableGetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The functions of [`Metafield`], for code that takes them as a trait, so that it can be
+/// tested with the `MockMetafieldApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait MetafieldApi: Send + Sync {
+    /// See [`Metafield::deprecated_202001_get_with`].
+    async fn deprecated_202001_get_with(
+        &self,
+        params: &MetafieldDeprecated202001GetParams,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202001_create`].
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202001_get_count`].
+    async fn deprecated_202001_get_count(&self) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202001_get_param_with`].
+    async fn deprecated_202001_get_param_with(
+        &self,
+        metafield_id: &str,
+        params: &MetafieldDeprecated202001GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202001_update_param`].
+    async fn deprecated_202001_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202001_delete_param`].
+    async fn deprecated_202001_delete_param(&self, metafield_id: &str) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202004_get_with`].
+    async fn deprecated_202004_get_with(
+        &self,
+        params: &MetafieldDeprecated202004GetParams,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202004_create`].
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202004_get_count`].
+    async fn deprecated_202004_get_count(&self) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202004_get_param_with`].
+    async fn deprecated_202004_get_param_with(
+        &self,
+        metafield_id: &str,
+        params: &MetafieldDeprecated202004GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202004_update_param`].
+    async fn deprecated_202004_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202004_delete_param`].
+    async fn deprecated_202004_delete_param(&self, metafield_id: &str) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202007_get_with`].
+    async fn deprecated_202007_get_with(
+        &self,
+        params: &MetafieldDeprecated202007GetParams,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202007_create`].
+    async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202007_get_count`].
+    async fn deprecated_202007_get_count(&self) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202007_get_param_with`].
+    async fn deprecated_202007_get_param_with(
+        &self,
+        metafield_id: &str,
+        params: &MetafieldDeprecated202007GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202007_update_param`].
+    async fn deprecated_202007_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202007_delete_param`].
+    async fn deprecated_202007_delete_param(&self, metafield_id: &str) -> Result<()>;
+
+    /// See [`Metafield::get_with`].
+    async fn get_with(&self, params: &MetafieldGetParams) -> Result<()>;
+
+    /// See [`Metafield::create`].
+    async fn create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Metafield::get_count`].
+    async fn get_count(&self) -> Result<()>;
+
+    /// See [`Metafield::get_param_with`].
+    async fn get_param_with(
+        &self,
+        metafield_id: &str,
+        params: &MetafieldGetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Metafield::update_param`].
+    async fn update_param(&self, metafield_id: &str, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Metafield::delete_param`].
+    async fn delete_param(&self, metafield_id: &str) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202101_get_with`].
+    async fn deprecated_202101_get_with(
+        &self,
+        params: &MetafieldDeprecated202101GetParams,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202101_create`].
+    async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202101_get_count`].
+    async fn deprecated_202101_get_count(&self) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202101_get_param_with`].
+    async fn deprecated_202101_get_param_with(
+        &self,
+        metafield_id: &str,
+        params: &MetafieldDeprecated202101GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202101_update_param`].
+    async fn deprecated_202101_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_202101_delete_param`].
+    async fn deprecated_202101_delete_param(&self, metafield_id: &str) -> Result<()>;
+
+    /// See [`Metafield::deprecated_unstable_get_with`].
+    async fn deprecated_unstable_get_with(
+        &self,
+        params: &MetafieldDeprecatedUnstableGetParams,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_unstable_create`].
+    async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Metafield::deprecated_unstable_get_count`].
+    async fn deprecated_unstable_get_count(&self) -> Result<()>;
+
+    /// See [`Metafield::deprecated_unstable_get_param_with`].
+    async fn deprecated_unstable_get_param_with(
+        &self,
+        metafield_id: &str,
+        params: &MetafieldDeprecatedUnstableGetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_unstable_update_param`].
+    async fn deprecated_unstable_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Metafield::deprecated_unstable_delete_param`].
+    async fn deprecated_unstable_delete_param(&self, metafield_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl MetafieldApi for Metafield {
+    async fn deprecated_202001_get_with(
+        &self,
+        params: &MetafieldDeprecated202001GetParams,
+    ) -> Result<()> {
+        Metafield::deprecated_202001_get_with(self, params).await
+    }
+
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()> {
+        Metafield::deprecated_202001_create(self, body).await
+    }
+
+    async fn deprecated_202001_get_count(&self) -> Result<()> {
+        Metafield::deprecated_202001_get_count(self).await
+    }
+
+    async fn deprecated_202001_get_param_with(
+        &self,
+        metafield_id: &str,
+        params: &MetafieldDeprecated202001GetParamParams,
+    ) -> Result<()> {
+        Metafield::deprecated_202001_get_param_with(self, metafield_id, params).await
+    }
+
+    async fn deprecated_202001_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Metafield::deprecated_202001_update_param(self, metafield_id, body).await
+    }
+
+    async fn deprecated_202001_delete_param(&self, metafield_id: &str) -> Result<()> {
+        Metafield::deprecated_202001_delete_param(self, metafield_id).await
+    }
+
+    async fn deprecated_202004_get_with(
+        &self,
+        params: &MetafieldDeprecated202004GetParams,
+    ) -> Result<()> {
+        Metafield::deprecated_202004_get_with(self, params).await
+    }
+
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()> {
+        Metafield::deprecated_202004_create(self, body).await
+    }
+
+    async fn deprecated_202004_get_count(&self) -> Result<()> {
+        Metafield::deprecated_202004_get_count(self).await
+    }
+
+    async fn deprecated_202004_get_param_with(
+        &self,
+        metafield_id: &str,
+        params: &MetafieldDeprecated202004GetParamParams,
+    ) -> Result<()> {
+        Metafield::deprecated_202004_get_param_with(self, metafield_id, params).await
+    }
+
+    async fn deprecated_202004_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Metafield::deprecated_202004_update_param(self, metafield_id, body).await
+    }
+
+    async fn deprecated_202004_delete_param(&self, metafield_id: &str) -> Result<()> {
+        Metafield::deprecated_202004_delete_param(self, metafield_id).await
+    }
+
+    async fn deprecated_202007_get_with(
+        &self,
+        params: &MetafieldDeprecated202007GetParams,
+    ) -> Result<()> {
+        Metafield::deprecated_202007_get_with(self, params).await
+    }
+
+    async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()> {
+        Metafield::deprecated_202007_create(self, body).await
+    }
+
+    async fn deprecated_202007_get_count(&self) -> Result<()> {
+        Metafield::deprecated_202007_get_count(self).await
+    }
+
+    async fn deprecated_202007_get_param_with(
+        &self,
+        metafield_id: &str,
+        params: &MetafieldDeprecated202007GetParamParams,
+    ) -> Result<()> {
+        Metafield::deprecated_202007_get_param_with(self, metafield_id, params).await
+    }
+
+    async fn deprecated_202007_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Metafield::deprecated_202007_update_param(self, metafield_id, body).await
+    }
+
+    async fn deprecated_202007_delete_param(&self, metafield_id: &str) -> Result<()> {
+        Metafield::deprecated_202007_delete_param(self, metafield_id).await
+    }
+
+    async fn get_with(&self, params: &MetafieldGetParams) -> Result<()> {
+        Metafield::get_with(self, params).await
+    }
+
+    async fn create(&self, body: &serde_json::Value) -> Result<()> {
+        Metafield::create(self, body).await
+    }
+
+    async fn get_count(&self) -> Result<()> {
+        Metafield::get_count(self).await
+    }
+
+    async fn get_param_with(
+        &self,
+        metafield_id: &str,
+        params: &MetafieldGetParamParams,
+    ) -> Result<()> {
+        Metafield::get_param_with(self, metafield_id, params).await
+    }
+
+    async fn update_param(&self, metafield_id: &str, body: &serde_json::Value) -> Result<()> {
+        Metafield::update_param(self, metafield_id, body).await
+    }
+
+    async fn delete_param(&self, metafield_id: &str) -> Result<()> {
+        Metafield::delete_param(self, metafield_id).await
+    }
+
+    async fn deprecated_202101_get_with(
+        &self,
+        params: &MetafieldDeprecated202101GetParams,
+    ) -> Result<()> {
+        Metafield::deprecated_202101_get_with(self, params).await
+    }
+
+    async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()> {
+        Metafield::deprecated_202101_create(self, body).await
+    }
+
+    async fn deprecated_202101_get_count(&self) -> Result<()> {
+        Metafield::deprecated_202101_get_count(self).await
+    }
+
+    async fn deprecated_202101_get_param_with(
+        &self,
+        metafield_id: &str,
+        params: &MetafieldDeprecated202101GetParamParams,
+    ) -> Result<()> {
+        Metafield::deprecated_202101_get_param_with(self, metafield_id, params).await
+    }
+
+    async fn deprecated_202101_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Metafield::deprecated_202101_update_param(self, metafield_id, body).await
+    }
+
+    async fn deprecated_202101_delete_param(&self, metafield_id: &str) -> Result<()> {
+        Metafield::deprecated_202101_delete_param(self, metafield_id).await
+    }
+
+    async fn deprecated_unstable_get_with(
+        &self,
+        params: &MetafieldDeprecatedUnstableGetParams,
+    ) -> Result<()> {
+        Metafield::deprecated_unstable_get_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()> {
+        Metafield::deprecated_unstable_create(self, body).await
+    }
+
+    async fn deprecated_unstable_get_count(&self) -> Result<()> {
+        Metafield::deprecated_unstable_get_count(self).await
+    }
+
+    async fn deprecated_unstable_get_param_with(
+        &self,
+        metafield_id: &str,
+        params: &MetafieldDeprecatedUnstableGetParamParams,
+    ) -> Result<()> {
+        Metafield::deprecated_unstable_get_param_with(self, metafield_id, params).await
+    }
+
+    async fn deprecated_unstable_update_param(
+        &self,
+        metafield_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Metafield::deprecated_unstable_update_param(self, metafield_id, body).await
+    }
+
+    async fn deprecated_unstable_delete_param(&self, metafield_id: &str) -> Result<()> {
+        Metafield::deprecated_unstable_delete_param(self, metafield_id).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct OnlineStore {
     pub client: Client,
@@ -22,81 +20,51 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `blog_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Retrieve results based on their published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published articles.
-     *                           unpublished: Show only unpublished articles.
-     *                           any: Show articles of any published status.
-     * * `handle: &str` -- Retrieve an article with a specific handle.
-     * * `tag: &str` -- Filter articles with a specific tag.
-     * * `author: &str` -- Filter articles by article author.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202001GetBlogsParamBlogArticleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_blogs_param_blog_article(
+    pub async fn deprecated_202001_get_blogs_param_blog_article_with(
         &self,
         blog_id: &str,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        handle: &str,
-        tag: &str,
-        author: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202001GetBlogsParamBlogArticleParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
+        if let Some(author) = &params.author {
             query_args.push(("author".to_string(), author.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !tag.is_empty() {
+        if let Some(tag) = &params.tag {
             query_args.push(("tag".to_string(), tag.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -109,6 +77,46 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_blogs_param_blog_article_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetBlogsParamBlogArticleParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_blogs_param_blog_article_with` instead")]
+    pub async fn deprecated_202001_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetBlogsParamBlogArticleParams {
+            author: Some(author.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            tag: Some(tag.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_blogs_param_blog_article_with(blog_id, &params)
+            .await
+    }
+
     /**
      * Creates an article for a blog.
      *
@@ -145,50 +153,33 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `blog_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count articles with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published articles.
-     *                           unpublished: Count only unpublished articles.
-     *                           any: Count all articles.
+     * * `params: &OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_blogs_param_blog_articles_count(
+    pub async fn deprecated_202001_get_blogs_param_blog_articles_count_with(
         &self,
         blog_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -201,6 +192,34 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_blogs_param_blog_articles_count_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_blogs_param_blog_articles_count_with` instead")]
+    pub async fn deprecated_202001_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_blogs_param_blog_articles_count_with(blog_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a single article.
      *
@@ -212,16 +231,16 @@ impl OnlineStore {
      *
      * * `blog_id: &str` -- storefront_access_token_id.
      * * `article_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specifed by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_blogs_param_blog_articles_article(
+    pub async fn deprecated_202001_get_blogs_param_blog_articles_article_with(
         &self,
         blog_id: &str,
         article_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -235,6 +254,27 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_blogs_param_blog_articles_article_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_blogs_param_blog_articles_article_with` instead"
+    )]
+    pub async fn deprecated_202001_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_blogs_param_blog_articles_article_with(
+            blog_id, article_id, &params,
+        )
+        .await
+    }
+
     /**
      * Updates an article.
      *
@@ -311,19 +351,17 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of tags to retrieve.
-     * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
+     * * `params: &OnlineStoreDeprecated202001GetArticlesTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_articles_tag(
+    pub async fn deprecated_202001_get_articles_tag_with(
         &self,
-        limit: &str,
-        popular: &str,
+        params: &OnlineStoreDeprecated202001GetArticlesTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !popular.is_empty() {
+        if let Some(popular) = &params.popular {
             query_args.push(("popular".to_string(), popular.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -332,6 +370,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_articles_tag_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetArticlesTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_articles_tag_with` instead")]
+    pub async fn deprecated_202001_get_articles_tag(
+        &self,
+        limit: &str,
+        popular: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetArticlesTagParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            popular: Some(popular.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_articles_tag_with(&params).await
+    }
+
     /**
      * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -342,81 +396,51 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `blog_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Retrieve results based on their published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published articles.
-     *                           unpublished: Show only unpublished articles.
-     *                           any: Show articles of any published status.
-     * * `handle: &str` -- Retrieve an article with a specific handle.
-     * * `tag: &str` -- Filter articles with a specific tag.
-     * * `author: &str` -- Filter articles by article author.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202004GetBlogsParamBlogArticleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_blogs_param_blog_article(
+    pub async fn deprecated_202004_get_blogs_param_blog_article_with(
         &self,
         blog_id: &str,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        handle: &str,
-        tag: &str,
-        author: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202004GetBlogsParamBlogArticleParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
+        if let Some(author) = &params.author {
             query_args.push(("author".to_string(), author.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !tag.is_empty() {
+        if let Some(tag) = &params.tag {
             query_args.push(("tag".to_string(), tag.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -429,6 +453,46 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_blogs_param_blog_article_with` and pass the query parameters as a `OnlineStoreDeprecated202004GetBlogsParamBlogArticleParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_blogs_param_blog_article_with` instead")]
+    pub async fn deprecated_202004_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202004GetBlogsParamBlogArticleParams {
+            author: Some(author.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            tag: Some(tag.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_blogs_param_blog_article_with(blog_id, &params)
+            .await
+    }
+
     /**
      * Creates an article for a blog.
      *
@@ -465,50 +529,33 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `blog_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count articles with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published articles.
-     *                           unpublished: Count only unpublished articles.
-     *                           any: Count all articles.
+     * * `params: &OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_blogs_param_blog_articles_count(
+    pub async fn deprecated_202004_get_blogs_param_blog_articles_count_with(
         &self,
         blog_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -521,6 +568,34 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_blogs_param_blog_articles_count_with` and pass the query parameters as a `OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_blogs_param_blog_articles_count_with` instead")]
+    pub async fn deprecated_202004_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_blogs_param_blog_articles_count_with(blog_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a single article.
      *
@@ -532,16 +607,16 @@ impl OnlineStore {
      *
      * * `blog_id: &str` -- storefront_access_token_id.
      * * `article_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specifed by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_blogs_param_blog_articles_article(
+    pub async fn deprecated_202004_get_blogs_param_blog_articles_article_with(
         &self,
         blog_id: &str,
         article_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -555,6 +630,27 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_blogs_param_blog_articles_article_with` and pass the query parameters as a `OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_blogs_param_blog_articles_article_with` instead"
+    )]
+    pub async fn deprecated_202004_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_blogs_param_blog_articles_article_with(
+            blog_id, article_id, &params,
+        )
+        .await
+    }
+
     /**
      * Updates an article.
      *
@@ -631,19 +727,17 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of tags to retrieve.
-     * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
+     * * `params: &OnlineStoreDeprecated202004GetArticlesTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_articles_tag(
+    pub async fn deprecated_202004_get_articles_tag_with(
         &self,
-        limit: &str,
-        popular: &str,
+        params: &OnlineStoreDeprecated202004GetArticlesTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !popular.is_empty() {
+        if let Some(popular) = &params.popular {
             query_args.push(("popular".to_string(), popular.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -652,6 +746,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_articles_tag_with` and pass the query parameters as a `OnlineStoreDeprecated202004GetArticlesTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_articles_tag_with` instead")]
+    pub async fn deprecated_202004_get_articles_tag(
+        &self,
+        limit: &str,
+        popular: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202004GetArticlesTagParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            popular: Some(popular.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_articles_tag_with(&params).await
+    }
+
     /**
      * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -662,81 +772,51 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `blog_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Retrieve results based on their published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published articles.
-     *                           unpublished: Show only unpublished articles.
-     *                           any: Show articles of any published status.
-     * * `handle: &str` -- Retrieve an article with a specific handle.
-     * * `tag: &str` -- Filter articles with a specific tag.
-     * * `author: &str` -- Filter articles by article author.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202007GetBlogsParamBlogArticleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_blogs_param_blog_article(
+    pub async fn deprecated_202007_get_blogs_param_blog_article_with(
         &self,
         blog_id: &str,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        handle: &str,
-        tag: &str,
-        author: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202007GetBlogsParamBlogArticleParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
+        if let Some(author) = &params.author {
             query_args.push(("author".to_string(), author.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !tag.is_empty() {
+        if let Some(tag) = &params.tag {
             query_args.push(("tag".to_string(), tag.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -749,6 +829,46 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_blogs_param_blog_article_with` and pass the query parameters as a `OnlineStoreDeprecated202007GetBlogsParamBlogArticleParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_blogs_param_blog_article_with` instead")]
+    pub async fn deprecated_202007_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202007GetBlogsParamBlogArticleParams {
+            author: Some(author.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            tag: Some(tag.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_blogs_param_blog_article_with(blog_id, &params)
+            .await
+    }
+
     /**
      * Creates an article for a blog.
      *
@@ -785,50 +905,33 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `blog_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count articles with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published articles.
-     *                           unpublished: Count only unpublished articles.
-     *                           any: Count all articles.
+     * * `params: &OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_blogs_param_blog_articles_count(
+    pub async fn deprecated_202007_get_blogs_param_blog_articles_count_with(
         &self,
         blog_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -841,6 +944,34 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_blogs_param_blog_articles_count_with` and pass the query parameters as a `OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_blogs_param_blog_articles_count_with` instead")]
+    pub async fn deprecated_202007_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_blogs_param_blog_articles_count_with(blog_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a single article.
      *
@@ -852,16 +983,16 @@ impl OnlineStore {
      *
      * * `blog_id: &str` -- storefront_access_token_id.
      * * `article_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specifed by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_blogs_param_blog_articles_article(
+    pub async fn deprecated_202007_get_blogs_param_blog_articles_article_with(
         &self,
         blog_id: &str,
         article_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -875,6 +1006,27 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_blogs_param_blog_articles_article_with` and pass the query parameters as a `OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_blogs_param_blog_articles_article_with` instead"
+    )]
+    pub async fn deprecated_202007_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_blogs_param_blog_articles_article_with(
+            blog_id, article_id, &params,
+        )
+        .await
+    }
+
     /**
      * Updates an article.
      *
@@ -951,19 +1103,17 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of tags to retrieve.
-     * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
+     * * `params: &OnlineStoreDeprecated202007GetArticlesTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_articles_tag(
+    pub async fn deprecated_202007_get_articles_tag_with(
         &self,
-        limit: &str,
-        popular: &str,
+        params: &OnlineStoreDeprecated202007GetArticlesTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !popular.is_empty() {
+        if let Some(popular) = &params.popular {
             query_args.push(("popular".to_string(), popular.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -972,6 +1122,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_articles_tag_with` and pass the query parameters as a `OnlineStoreDeprecated202007GetArticlesTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_articles_tag_with` instead")]
+    pub async fn deprecated_202007_get_articles_tag(
+        &self,
+        limit: &str,
+        popular: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202007GetArticlesTagParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            popular: Some(popular.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_articles_tag_with(&params).await
+    }
+
     /**
      * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -982,81 +1148,51 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `blog_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Retrieve results based on their published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published articles.
-     *                           unpublished: Show only unpublished articles.
-     *                           any: Show articles of any published status.
-     * * `handle: &str` -- Retrieve an article with a specific handle.
-     * * `tag: &str` -- Filter articles with a specific tag.
-     * * `author: &str` -- Filter articles by article author.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreGetBlogsParamBlogArticleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_blogs_param_blog_article(
+    pub async fn get_blogs_param_blog_article_with(
         &self,
         blog_id: &str,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        handle: &str,
-        tag: &str,
-        author: &str,
-        fields: &str,
+        params: &OnlineStoreGetBlogsParamBlogArticleParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
+        if let Some(author) = &params.author {
             query_args.push(("author".to_string(), author.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !tag.is_empty() {
+        if let Some(tag) = &params.tag {
             query_args.push(("tag".to_string(), tag.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1069,6 +1205,46 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_blogs_param_blog_article_with` and pass the query parameters as a `OnlineStoreGetBlogsParamBlogArticleParams` instead.
+     */
+    #[deprecated(note = "use `get_blogs_param_blog_article_with` instead")]
+    pub async fn get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreGetBlogsParamBlogArticleParams {
+            author: Some(author.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            tag: Some(tag.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_blogs_param_blog_article_with(blog_id, &params)
+            .await
+    }
+
     /**
      * Creates an article for a blog.
      *
@@ -1105,50 +1281,33 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `blog_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count articles with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published articles.
-     *                           unpublished: Count only unpublished articles.
-     *                           any: Count all articles.
+     * * `params: &OnlineStoreGetBlogsParamBlogArticlesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_blogs_param_blog_articles_count(
+    pub async fn get_blogs_param_blog_articles_count_with(
         &self,
         blog_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &OnlineStoreGetBlogsParamBlogArticlesCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1161,6 +1320,34 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_blogs_param_blog_articles_count_with` and pass the query parameters as a `OnlineStoreGetBlogsParamBlogArticlesCountParams` instead.
+     */
+    #[deprecated(note = "use `get_blogs_param_blog_articles_count_with` instead")]
+    pub async fn get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreGetBlogsParamBlogArticlesCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_blogs_param_blog_articles_count_with(blog_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a single article.
      *
@@ -1172,16 +1359,16 @@ impl OnlineStore {
      *
      * * `blog_id: &str` -- storefront_access_token_id.
      * * `article_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specifed by a comma-separated list of field names.
+     * * `params: &OnlineStoreGetBlogsParamBlogArticlesArticleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_blogs_param_blog_articles_article(
+    pub async fn get_blogs_param_blog_articles_article_with(
         &self,
         blog_id: &str,
         article_id: &str,
-        fields: &str,
+        params: &OnlineStoreGetBlogsParamBlogArticlesArticleParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1195,6 +1382,23 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_blogs_param_blog_articles_article_with` and pass the query parameters as a `OnlineStoreGetBlogsParamBlogArticlesArticleParams` instead.
+     */
+    #[deprecated(note = "use `get_blogs_param_blog_articles_article_with` instead")]
+    pub async fn get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreGetBlogsParamBlogArticlesArticleParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_blogs_param_blog_articles_article_with(blog_id, article_id, &params)
+            .await
+    }
+
     /**
      * Updates an article.
      *
@@ -1271,15 +1475,17 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of tags to retrieve.
-     * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
+     * * `params: &OnlineStoreGetArticlesTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_articles_tag(&self, limit: &str, popular: &str) -> Result<()> {
+    pub async fn get_articles_tag_with(
+        &self,
+        params: &OnlineStoreGetArticlesTagParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !popular.is_empty() {
+        if let Some(popular) = &params.popular {
             query_args.push(("popular".to_string(), popular.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1288,6 +1494,18 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_articles_tag_with` and pass the query parameters as a `OnlineStoreGetArticlesTagParams` instead.
+     */
+    #[deprecated(note = "use `get_articles_tag_with` instead")]
+    pub async fn get_articles_tag(&self, limit: &str, popular: &str) -> Result<()> {
+        let params = OnlineStoreGetArticlesTagParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            popular: Some(popular.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_articles_tag_with(&params).await
+    }
+
     /**
      * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1298,81 +1516,51 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `blog_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Retrieve results based on their published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published articles.
-     *                           unpublished: Show only unpublished articles.
-     *                           any: Show articles of any published status.
-     * * `handle: &str` -- Retrieve an article with a specific handle.
-     * * `tag: &str` -- Filter articles with a specific tag.
-     * * `author: &str` -- Filter articles by article author.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202101GetBlogsParamBlogArticleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_blogs_param_blog_article(
+    pub async fn deprecated_202101_get_blogs_param_blog_article_with(
         &self,
         blog_id: &str,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        handle: &str,
-        tag: &str,
-        author: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202101GetBlogsParamBlogArticleParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
+        if let Some(author) = &params.author {
             query_args.push(("author".to_string(), author.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !tag.is_empty() {
+        if let Some(tag) = &params.tag {
             query_args.push(("tag".to_string(), tag.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1385,6 +1573,46 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_blogs_param_blog_article_with` and pass the query parameters as a `OnlineStoreDeprecated202101GetBlogsParamBlogArticleParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_blogs_param_blog_article_with` instead")]
+    pub async fn deprecated_202101_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202101GetBlogsParamBlogArticleParams {
+            author: Some(author.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            tag: Some(tag.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_blogs_param_blog_article_with(blog_id, &params)
+            .await
+    }
+
     /**
      * Creates an article for a blog.
      *
@@ -1421,50 +1649,33 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `blog_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count articles with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published articles.
-     *                           unpublished: Count only unpublished articles.
-     *                           any: Count all articles.
+     * * `params: &OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_blogs_param_blog_articles_count(
+    pub async fn deprecated_202101_get_blogs_param_blog_articles_count_with(
         &self,
         blog_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1477,6 +1688,34 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_blogs_param_blog_articles_count_with` and pass the query parameters as a `OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_blogs_param_blog_articles_count_with` instead")]
+    pub async fn deprecated_202101_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_blogs_param_blog_articles_count_with(blog_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a single article.
      *
@@ -1488,16 +1727,16 @@ impl OnlineStore {
      *
      * * `blog_id: &str` -- storefront_access_token_id.
      * * `article_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specifed by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_blogs_param_blog_articles_article(
+    pub async fn deprecated_202101_get_blogs_param_blog_articles_article_with(
         &self,
         blog_id: &str,
         article_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1511,6 +1750,27 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_blogs_param_blog_articles_article_with` and pass the query parameters as a `OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_blogs_param_blog_articles_article_with` instead"
+    )]
+    pub async fn deprecated_202101_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_blogs_param_blog_articles_article_with(
+            blog_id, article_id, &params,
+        )
+        .await
+    }
+
     /**
      * Updates an article.
      *
@@ -1587,19 +1847,17 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of tags to retrieve.
-     * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
+     * * `params: &OnlineStoreDeprecated202101GetArticlesTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_articles_tag(
+    pub async fn deprecated_202101_get_articles_tag_with(
         &self,
-        limit: &str,
-        popular: &str,
+        params: &OnlineStoreDeprecated202101GetArticlesTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !popular.is_empty() {
+        if let Some(popular) = &params.popular {
             query_args.push(("popular".to_string(), popular.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1608,6 +1866,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_articles_tag_with` and pass the query parameters as a `OnlineStoreDeprecated202101GetArticlesTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_articles_tag_with` instead")]
+    pub async fn deprecated_202101_get_articles_tag(
+        &self,
+        limit: &str,
+        popular: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202101GetArticlesTagParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            popular: Some(popular.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_articles_tag_with(&params).await
+    }
+
     /**
      * Retrieves a list of all articles from a blog. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1618,81 +1892,51 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `blog_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Retrieve results based on their published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published articles.
-     *                           unpublished: Show only unpublished articles.
-     *                           any: Show articles of any published status.
-     * * `handle: &str` -- Retrieve an article with a specific handle.
-     * * `tag: &str` -- Filter articles with a specific tag.
-     * * `author: &str` -- Filter articles by article author.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_blogs_param_blog_article(
+    pub async fn deprecated_unstable_get_blogs_param_blog_article_with(
         &self,
         blog_id: &str,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        handle: &str,
-        tag: &str,
-        author: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !author.is_empty() {
+        if let Some(author) = &params.author {
             query_args.push(("author".to_string(), author.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !tag.is_empty() {
+        if let Some(tag) = &params.tag {
             query_args.push(("tag".to_string(), tag.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1705,6 +1949,46 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_blogs_param_blog_article_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_blogs_param_blog_article_with` instead")]
+    pub async fn deprecated_unstable_get_blogs_param_blog_article(
+        &self,
+        blog_id: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        handle: &str,
+        tag: &str,
+        author: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleParams {
+            author: Some(author.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            tag: Some(tag.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_blogs_param_blog_article_with(blog_id, &params)
+            .await
+    }
+
     /**
      * Creates an article for a blog.
      *
@@ -1741,50 +2025,33 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `blog_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count articles created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count articles created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count articles published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count articles published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count articles with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published articles.
-     *                           unpublished: Count only unpublished articles.
-     *                           any: Count all articles.
+     * * `params: &OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_blogs_param_blog_articles_count(
+    pub async fn deprecated_unstable_get_blogs_param_blog_articles_count_with(
         &self,
         blog_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1797,6 +2064,36 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_blogs_param_blog_articles_count_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_blogs_param_blog_articles_count_with` instead"
+    )]
+    pub async fn deprecated_unstable_get_blogs_param_blog_articles_count(
+        &self,
+        blog_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_blogs_param_blog_articles_count_with(blog_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a single article.
      *
@@ -1808,16 +2105,16 @@ impl OnlineStore {
      *
      * * `blog_id: &str` -- storefront_access_token_id.
      * * `article_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specifed by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_blogs_param_blog_articles_article(
+    pub async fn deprecated_unstable_get_blogs_param_blog_articles_article_with(
         &self,
         blog_id: &str,
         article_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1831,6 +2128,27 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_blogs_param_blog_articles_article_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_blogs_param_blog_articles_article_with` instead"
+    )]
+    pub async fn deprecated_unstable_get_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_blogs_param_blog_articles_article_with(
+            blog_id, article_id, &params,
+        )
+        .await
+    }
+
     /**
      * Updates an article.
      *
@@ -1907,19 +2225,17 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of tags to retrieve.
-     * * `popular: &str` -- A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
+     * * `params: &OnlineStoreDeprecatedUnstableGetArticlesTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_articles_tag(
+    pub async fn deprecated_unstable_get_articles_tag_with(
         &self,
-        limit: &str,
-        popular: &str,
+        params: &OnlineStoreDeprecatedUnstableGetArticlesTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !popular.is_empty() {
+        if let Some(popular) = &params.popular {
             query_args.push(("popular".to_string(), popular.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1928,6 +2244,23 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_articles_tag_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableGetArticlesTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_articles_tag_with` instead")]
+    pub async fn deprecated_unstable_get_articles_tag(
+        &self,
+        limit: &str,
+        popular: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableGetArticlesTagParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            popular: Some(popular.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_articles_tag_with(&params)
+            .await
+    }
+
     /**
     * Retrieves a single asset for a theme by its key.
               To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
@@ -1940,20 +2273,18 @@ impl OnlineStore {
     * **Parameters:**
     *
     * * `theme_id: &str` -- storefront_access_token_id.
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-    * * `asset_key: &str` -- storefront_access_token_id.
+    * * `params: &OnlineStoreDeprecated202001GetThemesParamThemeAssetParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_get_themes_param_theme_asset(
+    pub async fn deprecated_202001_get_themes_param_theme_asset_with(
         &self,
         theme_id: &str,
-        fields: &str,
-        asset_key: &str,
+        params: &OnlineStoreDeprecated202001GetThemesParamThemeAssetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
+        if let Some(asset_key) = &params.asset_key {
             query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1967,31 +2298,47 @@ impl OnlineStore {
     }
 
     /**
-    * Creates or updates an asset for a theme.
-              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
-    *
-    * This function performs a `PUT` to the `/admin/api/2020-01/themes/{theme_id}/assets.json` endpoint.
-    *
-    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-2020-01
+     * Deprecated: use `deprecated_202001_get_themes_param_theme_asset_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetThemesParamThemeAssetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_themes_param_theme_asset_with` instead")]
+    pub async fn deprecated_202001_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetThemesParamThemeAssetParams {
+            asset_key: Some(asset_key.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_themes_param_theme_asset_with(theme_id, &params)
+            .await
+    }
+
+    /**
+    * Creates or updates an asset for a theme.
+              In the PUT request, you can include the src or source_key property to create the asset from an existing file.
+    *
+    * This function performs a `PUT` to the `/admin/api/2020-01/themes/{theme_id}/assets.json` endpoint.
+    *
+    * https://shopify.dev/docs/admin-api/rest/reference/online-store/asset#update-2020-01
     *
     * **Parameters:**
     *
     * * `theme_id: &str` -- storefront_access_token_id.
-    * * `src: &str` -- The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
-    * * `source_key: &str` -- The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
+    * * `params: &OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_update_themes_param_theme_assets(
+    pub async fn deprecated_202001_update_themes_param_theme_assets_with(
         &self,
         theme_id: &str,
-        src: &str,
-        source_key: &str,
+        params: &OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !source_key.is_empty() {
+        if let Some(source_key) = &params.source_key {
             query_args.push(("source_key".to_string(), source_key.to_string()));
         }
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2006,6 +2353,25 @@ impl OnlineStore {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_update_themes_param_theme_assets_with` and pass the query parameters as a `OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_update_themes_param_theme_assets_with` instead")]
+    pub async fn deprecated_202001_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsParams {
+            source_key: Some(source_key.to_string()).filter(|s| !s.is_empty()),
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_update_themes_param_theme_assets_with(theme_id, &params, body)
+            .await
+    }
+
     /**
      * Deletes an asset from a theme.
      *
@@ -2016,15 +2382,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `theme_id: &str` -- storefront_access_token_id.
-     * * `asset_key: &str` -- storefront_access_token_id.
+     * * `params: &OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_delete_themes_param_theme_assets(
+    pub async fn deprecated_202001_delete_themes_param_theme_assets_with(
         &self,
         theme_id: &str,
-        asset_key: &str,
+        params: &OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
+        if let Some(asset_key) = &params.asset_key {
             query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2037,6 +2403,22 @@ impl OnlineStore {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_delete_themes_param_theme_assets_with` and pass the query parameters as a `OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_delete_themes_param_theme_assets_with` instead")]
+    pub async fn deprecated_202001_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsParams {
+            asset_key: Some(asset_key.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_delete_themes_param_theme_assets_with(theme_id, &params)
+            .await
+    }
+
     /**
     * Retrieves a single asset for a theme by its key.
               To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
@@ -2049,20 +2431,18 @@ impl OnlineStore {
     * **Parameters:**
     *
     * * `theme_id: &str` -- storefront_access_token_id.
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-    * * `asset_key: &str` -- storefront_access_token_id.
+    * * `params: &OnlineStoreDeprecated202004GetThemesParamThemeAssetParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_get_themes_param_theme_asset(
+    pub async fn deprecated_202004_get_themes_param_theme_asset_with(
         &self,
         theme_id: &str,
-        fields: &str,
-        asset_key: &str,
+        params: &OnlineStoreDeprecated202004GetThemesParamThemeAssetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
+        if let Some(asset_key) = &params.asset_key {
             query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2075,6 +2455,24 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_themes_param_theme_asset_with` and pass the query parameters as a `OnlineStoreDeprecated202004GetThemesParamThemeAssetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_themes_param_theme_asset_with` instead")]
+    pub async fn deprecated_202004_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202004GetThemesParamThemeAssetParams {
+            asset_key: Some(asset_key.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_themes_param_theme_asset_with(theme_id, &params)
+            .await
+    }
+
     /**
     * Creates or updates an asset for a theme.
               In the PUT request, you can include the src or source_key property to create the asset from an existing file.
@@ -2086,21 +2484,19 @@ impl OnlineStore {
     * **Parameters:**
     *
     * * `theme_id: &str` -- storefront_access_token_id.
-    * * `src: &str` -- The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
-    * * `source_key: &str` -- The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
+    * * `params: &OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_update_themes_param_theme_assets(
+    pub async fn deprecated_202004_update_themes_param_theme_assets_with(
         &self,
         theme_id: &str,
-        src: &str,
-        source_key: &str,
+        params: &OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !source_key.is_empty() {
+        if let Some(source_key) = &params.source_key {
             query_args.push(("source_key".to_string(), source_key.to_string()));
         }
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2115,6 +2511,25 @@ impl OnlineStore {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_update_themes_param_theme_assets_with` and pass the query parameters as a `OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_update_themes_param_theme_assets_with` instead")]
+    pub async fn deprecated_202004_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsParams {
+            source_key: Some(source_key.to_string()).filter(|s| !s.is_empty()),
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_update_themes_param_theme_assets_with(theme_id, &params, body)
+            .await
+    }
+
     /**
      * Deletes an asset from a theme.
      *
@@ -2125,15 +2540,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `theme_id: &str` -- storefront_access_token_id.
-     * * `asset_key: &str` -- storefront_access_token_id.
+     * * `params: &OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_delete_themes_param_theme_assets(
+    pub async fn deprecated_202004_delete_themes_param_theme_assets_with(
         &self,
         theme_id: &str,
-        asset_key: &str,
+        params: &OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
+        if let Some(asset_key) = &params.asset_key {
             query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2146,6 +2561,22 @@ impl OnlineStore {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_delete_themes_param_theme_assets_with` and pass the query parameters as a `OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_delete_themes_param_theme_assets_with` instead")]
+    pub async fn deprecated_202004_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsParams {
+            asset_key: Some(asset_key.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_delete_themes_param_theme_assets_with(theme_id, &params)
+            .await
+    }
+
     /**
     * Retrieves a single asset for a theme by its key.
               To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
@@ -2158,20 +2589,18 @@ impl OnlineStore {
     * **Parameters:**
     *
     * * `theme_id: &str` -- storefront_access_token_id.
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-    * * `asset_key: &str` -- storefront_access_token_id.
+    * * `params: &OnlineStoreDeprecated202007GetThemesParamThemeAssetParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202007_get_themes_param_theme_asset(
+    pub async fn deprecated_202007_get_themes_param_theme_asset_with(
         &self,
         theme_id: &str,
-        fields: &str,
-        asset_key: &str,
+        params: &OnlineStoreDeprecated202007GetThemesParamThemeAssetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
+        if let Some(asset_key) = &params.asset_key {
             query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2184,6 +2613,24 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_themes_param_theme_asset_with` and pass the query parameters as a `OnlineStoreDeprecated202007GetThemesParamThemeAssetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_themes_param_theme_asset_with` instead")]
+    pub async fn deprecated_202007_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202007GetThemesParamThemeAssetParams {
+            asset_key: Some(asset_key.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_themes_param_theme_asset_with(theme_id, &params)
+            .await
+    }
+
     /**
     * Creates or updates an asset for a theme.
               In the PUT request, you can include the src or source_key property to create the asset from an existing file.
@@ -2195,21 +2642,19 @@ impl OnlineStore {
     * **Parameters:**
     *
     * * `theme_id: &str` -- storefront_access_token_id.
-    * * `src: &str` -- The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
-    * * `source_key: &str` -- The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
+    * * `params: &OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202007_update_themes_param_theme_assets(
+    pub async fn deprecated_202007_update_themes_param_theme_assets_with(
         &self,
         theme_id: &str,
-        src: &str,
-        source_key: &str,
+        params: &OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !source_key.is_empty() {
+        if let Some(source_key) = &params.source_key {
             query_args.push(("source_key".to_string(), source_key.to_string()));
         }
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2224,6 +2669,25 @@ impl OnlineStore {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_update_themes_param_theme_assets_with` and pass the query parameters as a `OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_update_themes_param_theme_assets_with` instead")]
+    pub async fn deprecated_202007_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsParams {
+            source_key: Some(source_key.to_string()).filter(|s| !s.is_empty()),
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_update_themes_param_theme_assets_with(theme_id, &params, body)
+            .await
+    }
+
     /**
      * Deletes an asset from a theme.
      *
@@ -2234,15 +2698,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `theme_id: &str` -- storefront_access_token_id.
-     * * `asset_key: &str` -- storefront_access_token_id.
+     * * `params: &OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_delete_themes_param_theme_assets(
+    pub async fn deprecated_202007_delete_themes_param_theme_assets_with(
         &self,
         theme_id: &str,
-        asset_key: &str,
+        params: &OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
+        if let Some(asset_key) = &params.asset_key {
             query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2255,6 +2719,22 @@ impl OnlineStore {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_delete_themes_param_theme_assets_with` and pass the query parameters as a `OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_delete_themes_param_theme_assets_with` instead")]
+    pub async fn deprecated_202007_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsParams {
+            asset_key: Some(asset_key.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_delete_themes_param_theme_assets_with(theme_id, &params)
+            .await
+    }
+
     /**
     * Retrieves a single asset for a theme by its key.
               To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
@@ -2267,20 +2747,18 @@ impl OnlineStore {
     * **Parameters:**
     *
     * * `theme_id: &str` -- storefront_access_token_id.
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-    * * `asset_key: &str` -- storefront_access_token_id.
+    * * `params: &OnlineStoreGetThemesParamThemeAssetParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn get_themes_param_theme_asset(
+    pub async fn get_themes_param_theme_asset_with(
         &self,
         theme_id: &str,
-        fields: &str,
-        asset_key: &str,
+        params: &OnlineStoreGetThemesParamThemeAssetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
+        if let Some(asset_key) = &params.asset_key {
             query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2293,6 +2771,24 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_themes_param_theme_asset_with` and pass the query parameters as a `OnlineStoreGetThemesParamThemeAssetParams` instead.
+     */
+    #[deprecated(note = "use `get_themes_param_theme_asset_with` instead")]
+    pub async fn get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreGetThemesParamThemeAssetParams {
+            asset_key: Some(asset_key.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_themes_param_theme_asset_with(theme_id, &params)
+            .await
+    }
+
     /**
     * Creates or updates an asset for a theme.
               In the PUT request, you can include the src or source_key property to create the asset from an existing file.
@@ -2304,21 +2800,19 @@ impl OnlineStore {
     * **Parameters:**
     *
     * * `theme_id: &str` -- storefront_access_token_id.
-    * * `src: &str` -- The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
-    * * `source_key: &str` -- The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
+    * * `params: &OnlineStoreUpdateThemesParamThemeAssetsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn update_themes_param_theme_assets(
+    pub async fn update_themes_param_theme_assets_with(
         &self,
         theme_id: &str,
-        src: &str,
-        source_key: &str,
+        params: &OnlineStoreUpdateThemesParamThemeAssetsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !source_key.is_empty() {
+        if let Some(source_key) = &params.source_key {
             query_args.push(("source_key".to_string(), source_key.to_string()));
         }
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2333,6 +2827,25 @@ impl OnlineStore {
             .await
     }
 
+    /**
+     * Deprecated: use `update_themes_param_theme_assets_with` and pass the query parameters as a `OnlineStoreUpdateThemesParamThemeAssetsParams` instead.
+     */
+    #[deprecated(note = "use `update_themes_param_theme_assets_with` instead")]
+    pub async fn update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OnlineStoreUpdateThemesParamThemeAssetsParams {
+            source_key: Some(source_key.to_string()).filter(|s| !s.is_empty()),
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.update_themes_param_theme_assets_with(theme_id, &params, body)
+            .await
+    }
+
     /**
      * Deletes an asset from a theme.
      *
@@ -2343,15 +2856,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `theme_id: &str` -- storefront_access_token_id.
-     * * `asset_key: &str` -- storefront_access_token_id.
+     * * `params: &OnlineStoreDeleteThemesParamThemeAssetsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_themes_param_theme_assets(
+    pub async fn delete_themes_param_theme_assets_with(
         &self,
         theme_id: &str,
-        asset_key: &str,
+        params: &OnlineStoreDeleteThemesParamThemeAssetsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
+        if let Some(asset_key) = &params.asset_key {
             query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2364,6 +2877,22 @@ impl OnlineStore {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_themes_param_theme_assets_with` and pass the query parameters as a `OnlineStoreDeleteThemesParamThemeAssetsParams` instead.
+     */
+    #[deprecated(note = "use `delete_themes_param_theme_assets_with` instead")]
+    pub async fn delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeleteThemesParamThemeAssetsParams {
+            asset_key: Some(asset_key.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_themes_param_theme_assets_with(theme_id, &params)
+            .await
+    }
+
     /**
     * Retrieves a single asset for a theme by its key.
               To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
@@ -2376,20 +2905,18 @@ impl OnlineStore {
     * **Parameters:**
     *
     * * `theme_id: &str` -- storefront_access_token_id.
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-    * * `asset_key: &str` -- storefront_access_token_id.
+    * * `params: &OnlineStoreDeprecated202101GetThemesParamThemeAssetParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202101_get_themes_param_theme_asset(
+    pub async fn deprecated_202101_get_themes_param_theme_asset_with(
         &self,
         theme_id: &str,
-        fields: &str,
-        asset_key: &str,
+        params: &OnlineStoreDeprecated202101GetThemesParamThemeAssetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
+        if let Some(asset_key) = &params.asset_key {
             query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2402,6 +2929,24 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_themes_param_theme_asset_with` and pass the query parameters as a `OnlineStoreDeprecated202101GetThemesParamThemeAssetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_themes_param_theme_asset_with` instead")]
+    pub async fn deprecated_202101_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202101GetThemesParamThemeAssetParams {
+            asset_key: Some(asset_key.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_themes_param_theme_asset_with(theme_id, &params)
+            .await
+    }
+
     /**
     * Creates or updates an asset for a theme.
               In the PUT request, you can include the src or source_key property to create the asset from an existing file.
@@ -2413,21 +2958,19 @@ impl OnlineStore {
     * **Parameters:**
     *
     * * `theme_id: &str` -- storefront_access_token_id.
-    * * `src: &str` -- The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
-    * * `source_key: &str` -- The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
+    * * `params: &OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202101_update_themes_param_theme_assets(
+    pub async fn deprecated_202101_update_themes_param_theme_assets_with(
         &self,
         theme_id: &str,
-        src: &str,
-        source_key: &str,
+        params: &OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !source_key.is_empty() {
+        if let Some(source_key) = &params.source_key {
             query_args.push(("source_key".to_string(), source_key.to_string()));
         }
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2442,6 +2985,25 @@ impl OnlineStore {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_update_themes_param_theme_assets_with` and pass the query parameters as a `OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_update_themes_param_theme_assets_with` instead")]
+    pub async fn deprecated_202101_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsParams {
+            source_key: Some(source_key.to_string()).filter(|s| !s.is_empty()),
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_update_themes_param_theme_assets_with(theme_id, &params, body)
+            .await
+    }
+
     /**
      * Deletes an asset from a theme.
      *
@@ -2452,15 +3014,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `theme_id: &str` -- storefront_access_token_id.
-     * * `asset_key: &str` -- storefront_access_token_id.
+     * * `params: &OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_delete_themes_param_theme_assets(
+    pub async fn deprecated_202101_delete_themes_param_theme_assets_with(
         &self,
         theme_id: &str,
-        asset_key: &str,
+        params: &OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
+        if let Some(asset_key) = &params.asset_key {
             query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2473,6 +3035,22 @@ impl OnlineStore {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_delete_themes_param_theme_assets_with` and pass the query parameters as a `OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_delete_themes_param_theme_assets_with` instead")]
+    pub async fn deprecated_202101_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsParams {
+            asset_key: Some(asset_key.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_delete_themes_param_theme_assets_with(theme_id, &params)
+            .await
+    }
+
     /**
     * Retrieves a single asset for a theme by its key.
               To retrieve a single asset, include asset[key]=#{asset_key} as a request parameter. For example, to retrieve the asset with a key of templates/index.liquid, the request might be /admin/themes/828155753/assets.json?asset[key]=templates/index.liquid.
@@ -2485,20 +3063,18 @@ impl OnlineStore {
     * **Parameters:**
     *
     * * `theme_id: &str` -- storefront_access_token_id.
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-    * * `asset_key: &str` -- storefront_access_token_id.
+    * * `params: &OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_unstable_get_themes_param_theme_asset(
+    pub async fn deprecated_unstable_get_themes_param_theme_asset_with(
         &self,
         theme_id: &str,
-        fields: &str,
-        asset_key: &str,
+        params: &OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
+        if let Some(asset_key) = &params.asset_key {
             query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2511,6 +3087,24 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_themes_param_theme_asset_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_themes_param_theme_asset_with` instead")]
+    pub async fn deprecated_unstable_get_themes_param_theme_asset(
+        &self,
+        theme_id: &str,
+        fields: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetParams {
+            asset_key: Some(asset_key.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_themes_param_theme_asset_with(theme_id, &params)
+            .await
+    }
+
     /**
     * Creates or updates an asset for a theme.
               In the PUT request, you can include the src or source_key property to create the asset from an existing file.
@@ -2522,21 +3116,19 @@ impl OnlineStore {
     * **Parameters:**
     *
     * * `theme_id: &str` -- storefront_access_token_id.
-    * * `src: &str` -- The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
-    * * `source_key: &str` -- The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
+    * * `params: &OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_unstable_update_themes_param_theme_assets(
+    pub async fn deprecated_unstable_update_themes_param_theme_assets_with(
         &self,
         theme_id: &str,
-        src: &str,
-        source_key: &str,
+        params: &OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !source_key.is_empty() {
+        if let Some(source_key) = &params.source_key {
             query_args.push(("source_key".to_string(), source_key.to_string()));
         }
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2551,6 +3143,25 @@ impl OnlineStore {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_update_themes_param_theme_assets_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_update_themes_param_theme_assets_with` instead")]
+    pub async fn deprecated_unstable_update_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        src: &str,
+        source_key: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsParams {
+            source_key: Some(source_key.to_string()).filter(|s| !s.is_empty()),
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_update_themes_param_theme_assets_with(theme_id, &params, body)
+            .await
+    }
+
     /**
      * Deletes an asset from a theme.
      *
@@ -2561,15 +3172,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `theme_id: &str` -- storefront_access_token_id.
-     * * `asset_key: &str` -- storefront_access_token_id.
+     * * `params: &OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_delete_themes_param_theme_assets(
+    pub async fn deprecated_unstable_delete_themes_param_theme_assets_with(
         &self,
         theme_id: &str,
-        asset_key: &str,
+        params: &OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !asset_key.is_empty() {
+        if let Some(asset_key) = &params.asset_key {
             query_args.push(("asset[key]".to_string(), asset_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2582,6 +3193,22 @@ impl OnlineStore {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_delete_themes_param_theme_assets_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_delete_themes_param_theme_assets_with` instead")]
+    pub async fn deprecated_unstable_delete_themes_param_theme_assets(
+        &self,
+        theme_id: &str,
+        asset_key: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsParams {
+            asset_key: Some(asset_key.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_delete_themes_param_theme_assets_with(theme_id, &params)
+            .await
+    }
+
     /**
      * Retrieve a list of all blogs. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -2591,30 +3218,23 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `handle: &str` -- Filter by blog handle.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreDeprecated202001GetBlogParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_blog(
+    pub async fn deprecated_202001_get_blog_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        handle: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202001GetBlogParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2624,8 +3244,28 @@ impl OnlineStore {
     }
 
     /**
-     * Create a new blog.
-     *
+     * Deprecated: use `deprecated_202001_get_blog_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetBlogParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_blog_with` instead")]
+    pub async fn deprecated_202001_get_blog(
+        &self,
+        limit: &str,
+        since_id: &str,
+        handle: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetBlogParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_blog_with(&params).await
+    }
+
+    /**
+     * Create a new blog.
+     *
      * This function performs a `POST` to the `/admin/api/2020-01/blogs.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/online-store/blog#create-2020-01
@@ -2659,15 +3299,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `blog_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreDeprecated202001GetBlogsParamBlogParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_blogs_param_blog(
+    pub async fn deprecated_202001_get_blogs_param_blog_with(
         &self,
         blog_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202001GetBlogsParamBlogParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2680,6 +3320,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_blogs_param_blog_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetBlogsParamBlogParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_blogs_param_blog_with` instead")]
+    pub async fn deprecated_202001_get_blogs_param_blog(
+        &self,
+        blog_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetBlogsParamBlogParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_blogs_param_blog_with(blog_id, &params)
+            .await
+    }
+
     /**
      * Update a blog.
      *
@@ -2735,84 +3391,50 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show comments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show comments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show comments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show comments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show comments published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show comments published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `published_status: &str` -- Filter results by their published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published comments.
-     *                           unpublished: Show only unpublished comments.
-     *                           any: Show comments of any published status.
-     * * `status: &str` -- Filter results by their status.
-     *                       
-     *                           pending: Show only pending comments.
-     *                           published: Show only published comments.
-     *                           unapproved: Show only unapproved comments.
-     * * `article_id: i64` -- recurring_application_charge[capped_amount].
-     * * `blog_id: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &OnlineStoreDeprecated202001GetCommentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_comment(
+    pub async fn deprecated_202001_get_comment_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        fields: &str,
-        published_status: &str,
-        status: &str,
-        article_id: i64,
-        blog_id: i64,
+        params: &OnlineStoreDeprecated202001GetCommentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if article_id > 0 {
+        if let Some(article_id) = &params.article_id {
             query_args.push(("article_id".to_string(), article_id.to_string()));
         }
-        if blog_id > 0 {
+        if let Some(blog_id) = &params.blog_id {
             query_args.push(("blog_id".to_string(), blog_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2821,6 +3443,44 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_comment_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetCommentParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_comment_with` instead")]
+    pub async fn deprecated_202001_get_comment(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        fields: &str,
+        published_status: &str,
+        status: &str,
+        article_id: i64,
+        blog_id: i64,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetCommentParams {
+            article_id: Some(article_id).filter(|v| *v > 0),
+            blog_id: Some(blog_id).filter(|v| *v > 0),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_comment_with(&params).await
+    }
+
     /**
      * Creates a comment for an article.
      *
@@ -2844,68 +3504,41 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `created_at_min: &str` -- Count comments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count comments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count comments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count comments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count comments published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count comments published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Retrieve a count of comments with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published comments.
-     *                           unpublished: Count only unpublished comments.
-     *                           any: Count comments of any published status.
-     * * `status: &str` -- Retrieve a count of comments with a given status.
-     *                       
-     *                           pending: Count pending comments.
-     *                           published: Count published comments.
-     *                           unapproved: Count unapproved comments.
-     * * `article_id: i64` -- recurring_application_charge[capped_amount].
-     * * `blog_id: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &OnlineStoreDeprecated202001GetCommentsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_comments_count(
+    pub async fn deprecated_202001_get_comments_count_with(
         &self,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        status: &str,
-        article_id: i64,
-        blog_id: i64,
+        params: &OnlineStoreDeprecated202001GetCommentsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if article_id > 0 {
+        if let Some(article_id) = &params.article_id {
             query_args.push(("article_id".to_string(), article_id.to_string()));
         }
-        if blog_id > 0 {
+        if let Some(blog_id) = &params.blog_id {
             query_args.push(("blog_id".to_string(), blog_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2914,6 +3547,39 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_comments_count_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetCommentsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_comments_count_with` instead")]
+    pub async fn deprecated_202001_get_comments_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        status: &str,
+        article_id: i64,
+        blog_id: i64,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetCommentsCountParams {
+            article_id: Some(article_id).filter(|v| *v > 0),
+            blog_id: Some(blog_id).filter(|v| *v > 0),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_comments_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single comment by its ID.
      *
@@ -2924,15 +3590,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `comment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202001GetCommentsParamCommentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_comments_param_comment(
+    pub async fn deprecated_202001_get_comments_param_comment_with(
         &self,
         comment_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202001GetCommentsParamCommentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2945,6 +3611,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_comments_param_comment_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetCommentsParamCommentParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_comments_param_comment_with` instead")]
+    pub async fn deprecated_202001_get_comments_param_comment(
+        &self,
+        comment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetCommentsParamCommentParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_comments_param_comment_with(comment_id, &params)
+            .await
+    }
+
     /**
      * Updates a comment of an article.
      *
@@ -3110,35 +3792,26 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `path: &str` -- Show redirects with a given path.
-     * * `target: &str` -- Show redirects with a given target.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202001GetRedirectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_redirect(
+    pub async fn deprecated_202001_get_redirect_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        path: &str,
-        target: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202001GetRedirectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !target.is_empty() {
+        if let Some(target) = &params.target {
             query_args.push(("target".to_string(), target.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3147,6 +3820,28 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_redirect_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetRedirectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_redirect_with` instead")]
+    pub async fn deprecated_202001_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetRedirectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            target: Some(target.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_redirect_with(&params).await
+    }
+
     /**
     * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
               For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
@@ -3171,19 +3866,17 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `path: &str` -- Count redirects with given path.
-     * * `target: &str` -- Count redirects with given target.
+     * * `params: &OnlineStoreDeprecated202001GetRedirectsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_redirects_count(
+    pub async fn deprecated_202001_get_redirects_count_with(
         &self,
-        path: &str,
-        target: &str,
+        params: &OnlineStoreDeprecated202001GetRedirectsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !target.is_empty() {
+        if let Some(target) = &params.target {
             query_args.push(("target".to_string(), target.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3192,6 +3885,23 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_redirects_count_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetRedirectsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_redirects_count_with` instead")]
+    pub async fn deprecated_202001_get_redirects_count(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetRedirectsCountParams {
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            target: Some(target.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_redirects_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single redirect.
      *
@@ -3202,15 +3912,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `redirect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202001GetRedirectsParamRedirectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_redirects_param_redirect(
+    pub async fn deprecated_202001_get_redirects_param_redirect_with(
         &self,
         redirect_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202001GetRedirectsParamRedirectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3223,6 +3933,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_redirects_param_redirect_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetRedirectsParamRedirectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_redirects_param_redirect_with` instead")]
+    pub async fn deprecated_202001_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetRedirectsParamRedirectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_redirects_param_redirect_with(redirect_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing redirect.
      *
@@ -3281,35 +4007,26 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `path: &str` -- Show redirects with a given path.
-     * * `target: &str` -- Show redirects with a given target.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202004GetRedirectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_redirect(
+    pub async fn deprecated_202004_get_redirect_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        path: &str,
-        target: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202004GetRedirectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !target.is_empty() {
+        if let Some(target) = &params.target {
             query_args.push(("target".to_string(), target.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3318,6 +4035,28 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_redirect_with` and pass the query parameters as a `OnlineStoreDeprecated202004GetRedirectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_redirect_with` instead")]
+    pub async fn deprecated_202004_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202004GetRedirectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            target: Some(target.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_redirect_with(&params).await
+    }
+
     /**
     * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
               For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
@@ -3342,19 +4081,17 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `path: &str` -- Count redirects with given path.
-     * * `target: &str` -- Count redirects with given target.
+     * * `params: &OnlineStoreDeprecated202004GetRedirectsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_redirects_count(
+    pub async fn deprecated_202004_get_redirects_count_with(
         &self,
-        path: &str,
-        target: &str,
+        params: &OnlineStoreDeprecated202004GetRedirectsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !target.is_empty() {
+        if let Some(target) = &params.target {
             query_args.push(("target".to_string(), target.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3363,6 +4100,23 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_redirects_count_with` and pass the query parameters as a `OnlineStoreDeprecated202004GetRedirectsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_redirects_count_with` instead")]
+    pub async fn deprecated_202004_get_redirects_count(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202004GetRedirectsCountParams {
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            target: Some(target.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_redirects_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single redirect.
      *
@@ -3373,15 +4127,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `redirect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202004GetRedirectsParamRedirectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_redirects_param_redirect(
+    pub async fn deprecated_202004_get_redirects_param_redirect_with(
         &self,
         redirect_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202004GetRedirectsParamRedirectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3394,6 +4148,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_redirects_param_redirect_with` and pass the query parameters as a `OnlineStoreDeprecated202004GetRedirectsParamRedirectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_redirects_param_redirect_with` instead")]
+    pub async fn deprecated_202004_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202004GetRedirectsParamRedirectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_redirects_param_redirect_with(redirect_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing redirect.
      *
@@ -3452,35 +4222,26 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `path: &str` -- Show redirects with a given path.
-     * * `target: &str` -- Show redirects with a given target.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202007GetRedirectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_redirect(
+    pub async fn deprecated_202007_get_redirect_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        path: &str,
-        target: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202007GetRedirectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !target.is_empty() {
+        if let Some(target) = &params.target {
             query_args.push(("target".to_string(), target.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3489,6 +4250,28 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_redirect_with` and pass the query parameters as a `OnlineStoreDeprecated202007GetRedirectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_redirect_with` instead")]
+    pub async fn deprecated_202007_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202007GetRedirectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            target: Some(target.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_redirect_with(&params).await
+    }
+
     /**
     * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
               For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
@@ -3513,19 +4296,17 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `path: &str` -- Count redirects with given path.
-     * * `target: &str` -- Count redirects with given target.
+     * * `params: &OnlineStoreDeprecated202007GetRedirectsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_redirects_count(
+    pub async fn deprecated_202007_get_redirects_count_with(
         &self,
-        path: &str,
-        target: &str,
+        params: &OnlineStoreDeprecated202007GetRedirectsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !target.is_empty() {
+        if let Some(target) = &params.target {
             query_args.push(("target".to_string(), target.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3534,6 +4315,23 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_redirects_count_with` and pass the query parameters as a `OnlineStoreDeprecated202007GetRedirectsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_redirects_count_with` instead")]
+    pub async fn deprecated_202007_get_redirects_count(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202007GetRedirectsCountParams {
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            target: Some(target.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_redirects_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single redirect.
      *
@@ -3544,15 +4342,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `redirect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202007GetRedirectsParamRedirectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_redirects_param_redirect(
+    pub async fn deprecated_202007_get_redirects_param_redirect_with(
         &self,
         redirect_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202007GetRedirectsParamRedirectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3565,6 +4363,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_redirects_param_redirect_with` and pass the query parameters as a `OnlineStoreDeprecated202007GetRedirectsParamRedirectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_redirects_param_redirect_with` instead")]
+    pub async fn deprecated_202007_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202007GetRedirectsParamRedirectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_redirects_param_redirect_with(redirect_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing redirect.
      *
@@ -3623,35 +4437,23 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `path: &str` -- Show redirects with a given path.
-     * * `target: &str` -- Show redirects with a given target.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreGetRedirectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_redirect(
-        &self,
-        limit: &str,
-        since_id: &str,
-        path: &str,
-        target: &str,
-        fields: &str,
-    ) -> Result<()> {
+    pub async fn get_redirect_with(&self, params: &OnlineStoreGetRedirectParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !target.is_empty() {
+        if let Some(target) = &params.target {
             query_args.push(("target".to_string(), target.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3660,6 +4462,28 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_redirect_with` and pass the query parameters as a `OnlineStoreGetRedirectParams` instead.
+     */
+    #[deprecated(note = "use `get_redirect_with` instead")]
+    pub async fn get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreGetRedirectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            target: Some(target.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_redirect_with(&params).await
+    }
+
     /**
     * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
               For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
@@ -3684,15 +4508,17 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `path: &str` -- Count redirects with given path.
-     * * `target: &str` -- Count redirects with given target.
+     * * `params: &OnlineStoreGetRedirectsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_redirects_count(&self, path: &str, target: &str) -> Result<()> {
+    pub async fn get_redirects_count_with(
+        &self,
+        params: &OnlineStoreGetRedirectsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !target.is_empty() {
+        if let Some(target) = &params.target {
             query_args.push(("target".to_string(), target.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3702,24 +4528,36 @@ impl OnlineStore {
     }
 
     /**
-     * Retrieves a single redirect.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/redirects/{redirect_id}.json` endpoint.
-     *
+     * Deprecated: use `get_redirects_count_with` and pass the query parameters as a `OnlineStoreGetRedirectsCountParams` instead.
+     */
+    #[deprecated(note = "use `get_redirects_count_with` instead")]
+    pub async fn get_redirects_count(&self, path: &str, target: &str) -> Result<()> {
+        let params = OnlineStoreGetRedirectsCountParams {
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            target: Some(target.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_redirects_count_with(&params).await
+    }
+
+    /**
+     * Retrieves a single redirect.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/redirects/{redirect_id}.json` endpoint.
+     *
      * https://shopify.dev/docs/admin-api/rest/reference/online-store/redirect#show-2020-10
      *
      * **Parameters:**
      *
      * * `redirect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreGetRedirectsParamRedirectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_redirects_param_redirect(
+    pub async fn get_redirects_param_redirect_with(
         &self,
         redirect_id: &str,
-        fields: &str,
+        params: &OnlineStoreGetRedirectsParamRedirectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3732,6 +4570,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_redirects_param_redirect_with` and pass the query parameters as a `OnlineStoreGetRedirectsParamRedirectParams` instead.
+     */
+    #[deprecated(note = "use `get_redirects_param_redirect_with` instead")]
+    pub async fn get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreGetRedirectsParamRedirectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_redirects_param_redirect_with(redirect_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing redirect.
      *
@@ -3787,35 +4641,26 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `path: &str` -- Show redirects with a given path.
-     * * `target: &str` -- Show redirects with a given target.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202101GetRedirectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_redirect(
+    pub async fn deprecated_202101_get_redirect_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        path: &str,
-        target: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202101GetRedirectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !target.is_empty() {
+        if let Some(target) = &params.target {
             query_args.push(("target".to_string(), target.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3824,6 +4669,28 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_redirect_with` and pass the query parameters as a `OnlineStoreDeprecated202101GetRedirectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_redirect_with` instead")]
+    pub async fn deprecated_202101_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202101GetRedirectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            target: Some(target.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_redirect_with(&params).await
+    }
+
     /**
     * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
               For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
@@ -3848,19 +4715,17 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `path: &str` -- Count redirects with given path.
-     * * `target: &str` -- Count redirects with given target.
+     * * `params: &OnlineStoreDeprecated202101GetRedirectsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_redirects_count(
+    pub async fn deprecated_202101_get_redirects_count_with(
         &self,
-        path: &str,
-        target: &str,
+        params: &OnlineStoreDeprecated202101GetRedirectsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !target.is_empty() {
+        if let Some(target) = &params.target {
             query_args.push(("target".to_string(), target.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3869,6 +4734,23 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_redirects_count_with` and pass the query parameters as a `OnlineStoreDeprecated202101GetRedirectsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_redirects_count_with` instead")]
+    pub async fn deprecated_202101_get_redirects_count(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202101GetRedirectsCountParams {
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            target: Some(target.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_redirects_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single redirect.
      *
@@ -3879,15 +4761,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `redirect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202101GetRedirectsParamRedirectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_redirects_param_redirect(
+    pub async fn deprecated_202101_get_redirects_param_redirect_with(
         &self,
         redirect_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202101GetRedirectsParamRedirectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3900,6 +4782,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_redirects_param_redirect_with` and pass the query parameters as a `OnlineStoreDeprecated202101GetRedirectsParamRedirectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_redirects_param_redirect_with` instead")]
+    pub async fn deprecated_202101_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202101GetRedirectsParamRedirectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_redirects_param_redirect_with(redirect_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing redirect.
      *
@@ -3958,35 +4856,26 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `path: &str` -- Show redirects with a given path.
-     * * `target: &str` -- Show redirects with a given target.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecatedUnstableGetRedirectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_redirect(
+    pub async fn deprecated_unstable_get_redirect_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        path: &str,
-        target: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecatedUnstableGetRedirectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !target.is_empty() {
+        if let Some(target) = &params.target {
             query_args.push(("target".to_string(), target.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3995,6 +4884,28 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_redirect_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableGetRedirectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_redirect_with` instead")]
+    pub async fn deprecated_unstable_get_redirect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        path: &str,
+        target: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableGetRedirectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            target: Some(target.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_redirect_with(&params).await
+    }
+
     /**
     * Creates a redirect. When you provide a full URL as the value of the path property, it will be saved as an absolute path without the domain.
               For example, "path": "http://www.johns-apparel.com/springwear" will be saved as "path": "springwear".
@@ -4022,19 +4933,17 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `path: &str` -- Count redirects with given path.
-     * * `target: &str` -- Count redirects with given target.
+     * * `params: &OnlineStoreDeprecatedUnstableGetRedirectsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_redirects_count(
+    pub async fn deprecated_unstable_get_redirects_count_with(
         &self,
-        path: &str,
-        target: &str,
+        params: &OnlineStoreDeprecatedUnstableGetRedirectsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !target.is_empty() {
+        if let Some(target) = &params.target {
             query_args.push(("target".to_string(), target.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4043,6 +4952,23 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_redirects_count_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableGetRedirectsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_redirects_count_with` instead")]
+    pub async fn deprecated_unstable_get_redirects_count(
+        &self,
+        path: &str,
+        target: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableGetRedirectsCountParams {
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            target: Some(target.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_redirects_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single redirect.
      *
@@ -4053,15 +4979,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `redirect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_redirects_param_redirect(
+    pub async fn deprecated_unstable_get_redirects_param_redirect_with(
         &self,
         redirect_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4074,6 +5000,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_redirects_param_redirect_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_redirects_param_redirect_with` instead")]
+    pub async fn deprecated_unstable_get_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_redirects_param_redirect_with(redirect_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing redirect.
      *
@@ -4132,50 +5074,35 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `src: &str` -- Show script tags with this URL.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreDeprecated202001GetScriptTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_script_tag(
+    pub async fn deprecated_202001_get_script_tag_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        src: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202001GetScriptTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4184,6 +5111,34 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_script_tag_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetScriptTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_script_tag_with` instead")]
+    pub async fn deprecated_202001_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetScriptTagParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_script_tag_with(&params).await
+    }
+
     /**
      * Creates a new script tag.
      *
@@ -4210,11 +5165,14 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `src: &str` -- Count only script tags with a given URL.
+     * * `params: &OnlineStoreDeprecated202001GetScriptTagsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_script_tags_count(&self, src: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetScriptTagsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4223,6 +5181,18 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_script_tags_count_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetScriptTagsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_script_tags_count_with` instead")]
+    pub async fn deprecated_202001_get_script_tags_count(&self, src: &str) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetScriptTagsCountParams {
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_script_tags_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single script tag.
      *
@@ -4233,15 +5203,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `script_tag_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreDeprecated202001GetScriptTagsParamTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_script_tags_param_tag(
+    pub async fn deprecated_202001_get_script_tags_param_tag_with(
         &self,
         script_tag_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202001GetScriptTagsParamTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4254,6 +5224,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_script_tags_param_tag_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetScriptTagsParamTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_script_tags_param_tag_with` instead")]
+    pub async fn deprecated_202001_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetScriptTagsParamTagParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_script_tags_param_tag_with(script_tag_id, &params)
+            .await
+    }
+
     /**
      * Updates a script tag.
      *
@@ -4312,50 +5298,35 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `src: &str` -- Show script tags with this URL.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreDeprecated202004GetScriptTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_script_tag(
+    pub async fn deprecated_202004_get_script_tag_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        src: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202004GetScriptTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4364,6 +5335,34 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_script_tag_with` and pass the query parameters as a `OnlineStoreDeprecated202004GetScriptTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_script_tag_with` instead")]
+    pub async fn deprecated_202004_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202004GetScriptTagParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_script_tag_with(&params).await
+    }
+
     /**
      * Creates a new script tag.
      *
@@ -4390,11 +5389,14 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `src: &str` -- Count only script tags with a given URL.
+     * * `params: &OnlineStoreDeprecated202004GetScriptTagsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_script_tags_count(&self, src: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetScriptTagsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4403,6 +5405,18 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_script_tags_count_with` and pass the query parameters as a `OnlineStoreDeprecated202004GetScriptTagsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_script_tags_count_with` instead")]
+    pub async fn deprecated_202004_get_script_tags_count(&self, src: &str) -> Result<()> {
+        let params = OnlineStoreDeprecated202004GetScriptTagsCountParams {
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_script_tags_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single script tag.
      *
@@ -4413,15 +5427,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `script_tag_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreDeprecated202004GetScriptTagsParamTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_script_tags_param_tag(
+    pub async fn deprecated_202004_get_script_tags_param_tag_with(
         &self,
         script_tag_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202004GetScriptTagsParamTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4434,6 +5448,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_script_tags_param_tag_with` and pass the query parameters as a `OnlineStoreDeprecated202004GetScriptTagsParamTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_script_tags_param_tag_with` instead")]
+    pub async fn deprecated_202004_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202004GetScriptTagsParamTagParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_script_tags_param_tag_with(script_tag_id, &params)
+            .await
+    }
+
     /**
      * Updates a script tag.
      *
@@ -4492,50 +5522,35 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `src: &str` -- Show script tags with this URL.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreDeprecated202007GetScriptTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_script_tag(
+    pub async fn deprecated_202007_get_script_tag_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        src: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202007GetScriptTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4544,6 +5559,34 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_script_tag_with` and pass the query parameters as a `OnlineStoreDeprecated202007GetScriptTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_script_tag_with` instead")]
+    pub async fn deprecated_202007_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202007GetScriptTagParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_script_tag_with(&params).await
+    }
+
     /**
      * Creates a new script tag.
      *
@@ -4570,11 +5613,14 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `src: &str` -- Count only script tags with a given URL.
+     * * `params: &OnlineStoreDeprecated202007GetScriptTagsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_script_tags_count(&self, src: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetScriptTagsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4583,6 +5629,18 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_script_tags_count_with` and pass the query parameters as a `OnlineStoreDeprecated202007GetScriptTagsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_script_tags_count_with` instead")]
+    pub async fn deprecated_202007_get_script_tags_count(&self, src: &str) -> Result<()> {
+        let params = OnlineStoreDeprecated202007GetScriptTagsCountParams {
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_script_tags_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single script tag.
      *
@@ -4593,15 +5651,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `script_tag_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreDeprecated202007GetScriptTagsParamTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_script_tags_param_tag(
+    pub async fn deprecated_202007_get_script_tags_param_tag_with(
         &self,
         script_tag_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202007GetScriptTagsParamTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4614,6 +5672,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_script_tags_param_tag_with` and pass the query parameters as a `OnlineStoreDeprecated202007GetScriptTagsParamTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_script_tags_param_tag_with` instead")]
+    pub async fn deprecated_202007_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202007GetScriptTagsParamTagParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_script_tags_param_tag_with(script_tag_id, &params)
+            .await
+    }
+
     /**
      * Updates a script tag.
      *
@@ -4672,50 +5746,32 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `src: &str` -- Show script tags with this URL.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreGetScriptTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_script_tag(
-        &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        src: &str,
-        fields: &str,
-    ) -> Result<()> {
+    pub async fn get_script_tag_with(&self, params: &OnlineStoreGetScriptTagParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4725,9 +5781,37 @@ impl OnlineStore {
     }
 
     /**
-     * Creates a new script tag.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/script_tags.json` endpoint.
+     * Deprecated: use `get_script_tag_with` and pass the query parameters as a `OnlineStoreGetScriptTagParams` instead.
+     */
+    #[deprecated(note = "use `get_script_tag_with` instead")]
+    pub async fn get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreGetScriptTagParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_script_tag_with(&params).await
+    }
+
+    /**
+     * Creates a new script tag.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-10/script_tags.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/online-store/scripttag#create-2020-10
      */
@@ -4747,11 +5831,14 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `src: &str` -- Count only script tags with a given URL.
+     * * `params: &OnlineStoreGetScriptTagsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_script_tags_count(&self, src: &str) -> Result<()> {
+    pub async fn get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreGetScriptTagsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4760,6 +5847,17 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_script_tags_count_with` and pass the query parameters as a `OnlineStoreGetScriptTagsCountParams` instead.
+     */
+    #[deprecated(note = "use `get_script_tags_count_with` instead")]
+    pub async fn get_script_tags_count(&self, src: &str) -> Result<()> {
+        let params = OnlineStoreGetScriptTagsCountParams {
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_script_tags_count_with(&params).await
+    }
+
     /**
      * Retrieves a single script tag.
      *
@@ -4770,11 +5868,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `script_tag_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreGetScriptTagsParamTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_script_tags_param_tag(&self, script_tag_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_script_tags_param_tag_with(
+        &self,
+        script_tag_id: &str,
+        params: &OnlineStoreGetScriptTagsParamTagParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4787,6 +5889,18 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_script_tags_param_tag_with` and pass the query parameters as a `OnlineStoreGetScriptTagsParamTagParams` instead.
+     */
+    #[deprecated(note = "use `get_script_tags_param_tag_with` instead")]
+    pub async fn get_script_tags_param_tag(&self, script_tag_id: &str, fields: &str) -> Result<()> {
+        let params = OnlineStoreGetScriptTagsParamTagParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_script_tags_param_tag_with(script_tag_id, &params)
+            .await
+    }
+
     /**
      * Updates a script tag.
      *
@@ -4842,50 +5956,35 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `src: &str` -- Show script tags with this URL.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreDeprecated202101GetScriptTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_script_tag(
+    pub async fn deprecated_202101_get_script_tag_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        src: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202101GetScriptTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4894,6 +5993,34 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_script_tag_with` and pass the query parameters as a `OnlineStoreDeprecated202101GetScriptTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_script_tag_with` instead")]
+    pub async fn deprecated_202101_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202101GetScriptTagParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_script_tag_with(&params).await
+    }
+
     /**
      * Creates a new script tag.
      *
@@ -4920,11 +6047,14 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `src: &str` -- Count only script tags with a given URL.
+     * * `params: &OnlineStoreDeprecated202101GetScriptTagsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_script_tags_count(&self, src: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetScriptTagsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4933,6 +6063,18 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_script_tags_count_with` and pass the query parameters as a `OnlineStoreDeprecated202101GetScriptTagsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_script_tags_count_with` instead")]
+    pub async fn deprecated_202101_get_script_tags_count(&self, src: &str) -> Result<()> {
+        let params = OnlineStoreDeprecated202101GetScriptTagsCountParams {
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_script_tags_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single script tag.
      *
@@ -4943,15 +6085,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `script_tag_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreDeprecated202101GetScriptTagsParamTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_script_tags_param_tag(
+    pub async fn deprecated_202101_get_script_tags_param_tag_with(
         &self,
         script_tag_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202101GetScriptTagsParamTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4964,6 +6106,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_script_tags_param_tag_with` and pass the query parameters as a `OnlineStoreDeprecated202101GetScriptTagsParamTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_script_tags_param_tag_with` instead")]
+    pub async fn deprecated_202101_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202101GetScriptTagsParamTagParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_script_tags_param_tag_with(script_tag_id, &params)
+            .await
+    }
+
     /**
      * Updates a script tag.
      *
@@ -5022,50 +6180,35 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to return.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `src: &str` -- Show script tags with this URL.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreDeprecatedUnstableGetScriptTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_script_tag(
+    pub async fn deprecated_unstable_get_script_tag_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        src: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecatedUnstableGetScriptTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5074,6 +6217,34 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_script_tag_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableGetScriptTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_script_tag_with` instead")]
+    pub async fn deprecated_unstable_get_script_tag(
+        &self,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        src: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableGetScriptTagParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_script_tag_with(&params).await
+    }
+
     /**
      * Creates a new script tag.
      *
@@ -5100,11 +6271,14 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `src: &str` -- Count only script tags with a given URL.
+     * * `params: &OnlineStoreDeprecatedUnstableGetScriptTagsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_script_tags_count(&self, src: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetScriptTagsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !src.is_empty() {
+        if let Some(src) = &params.src {
             query_args.push(("src".to_string(), src.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5113,6 +6287,18 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_script_tags_count_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableGetScriptTagsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_script_tags_count_with` instead")]
+    pub async fn deprecated_unstable_get_script_tags_count(&self, src: &str) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableGetScriptTagsCountParams {
+            src: Some(src.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_script_tags_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single script tag.
      *
@@ -5123,15 +6309,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `script_tag_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &OnlineStoreDeprecatedUnstableGetScriptTagsParamTagParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_script_tags_param_tag(
+    pub async fn deprecated_unstable_get_script_tags_param_tag_with(
         &self,
         script_tag_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecatedUnstableGetScriptTagsParamTagParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5144,6 +6330,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_script_tags_param_tag_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableGetScriptTagsParamTagParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_script_tags_param_tag_with` instead")]
+    pub async fn deprecated_unstable_get_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableGetScriptTagsParamTagParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_script_tags_param_tag_with(script_tag_id, &params)
+            .await
+    }
+
     /**
      * Updates a script tag.
      *
@@ -5202,11 +6404,14 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202001GetThemeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_theme(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetThemeParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5215,6 +6420,17 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_theme_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetThemeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_theme_with` instead")]
+    pub async fn deprecated_202001_get_theme(&self, fields: &str) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetThemeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_theme_with(&params).await
+    }
+
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
               A new theme is always unpublished by default. To publish a theme when you create it, include
@@ -5242,15 +6458,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `theme_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202001GetThemesParamThemeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_themes_param_theme(
+    pub async fn deprecated_202001_get_themes_param_theme_with(
         &self,
         theme_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202001GetThemesParamThemeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5263,6 +6479,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_themes_param_theme_with` and pass the query parameters as a `OnlineStoreDeprecated202001GetThemesParamThemeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_themes_param_theme_with` instead")]
+    pub async fn deprecated_202001_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202001GetThemesParamThemeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_themes_param_theme_with(theme_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing theme.
      *
@@ -5318,11 +6550,14 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202004GetThemeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_theme(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetThemeParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5331,6 +6566,17 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_theme_with` and pass the query parameters as a `OnlineStoreDeprecated202004GetThemeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_theme_with` instead")]
+    pub async fn deprecated_202004_get_theme(&self, fields: &str) -> Result<()> {
+        let params = OnlineStoreDeprecated202004GetThemeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_theme_with(&params).await
+    }
+
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
               A new theme is always unpublished by default. To publish a theme when you create it, include
@@ -5358,15 +6604,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `theme_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202004GetThemesParamThemeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_themes_param_theme(
+    pub async fn deprecated_202004_get_themes_param_theme_with(
         &self,
         theme_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202004GetThemesParamThemeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5379,6 +6625,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_themes_param_theme_with` and pass the query parameters as a `OnlineStoreDeprecated202004GetThemesParamThemeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_themes_param_theme_with` instead")]
+    pub async fn deprecated_202004_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202004GetThemesParamThemeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_themes_param_theme_with(theme_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing theme.
      *
@@ -5434,11 +6696,14 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202007GetThemeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_theme(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetThemeParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5447,6 +6712,17 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_theme_with` and pass the query parameters as a `OnlineStoreDeprecated202007GetThemeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_theme_with` instead")]
+    pub async fn deprecated_202007_get_theme(&self, fields: &str) -> Result<()> {
+        let params = OnlineStoreDeprecated202007GetThemeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_theme_with(&params).await
+    }
+
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
               A new theme is always unpublished by default. To publish a theme when you create it, include
@@ -5474,15 +6750,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `theme_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202007GetThemesParamThemeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_themes_param_theme(
+    pub async fn deprecated_202007_get_themes_param_theme_with(
         &self,
         theme_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202007GetThemesParamThemeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5495,6 +6771,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_themes_param_theme_with` and pass the query parameters as a `OnlineStoreDeprecated202007GetThemesParamThemeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_themes_param_theme_with` instead")]
+    pub async fn deprecated_202007_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202007GetThemesParamThemeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_themes_param_theme_with(theme_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing theme.
      *
@@ -5550,11 +6842,11 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreGetThemeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_theme(&self, fields: &str) -> Result<()> {
+    pub async fn get_theme_with(&self, params: &OnlineStoreGetThemeParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5563,6 +6855,17 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_theme_with` and pass the query parameters as a `OnlineStoreGetThemeParams` instead.
+     */
+    #[deprecated(note = "use `get_theme_with` instead")]
+    pub async fn get_theme(&self, fields: &str) -> Result<()> {
+        let params = OnlineStoreGetThemeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_theme_with(&params).await
+    }
+
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
               A new theme is always unpublished by default. To publish a theme when you create it, include
@@ -5590,11 +6893,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `theme_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreGetThemesParamThemeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_themes_param_theme(&self, theme_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_themes_param_theme_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreGetThemesParamThemeParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5607,6 +6914,17 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_themes_param_theme_with` and pass the query parameters as a `OnlineStoreGetThemesParamThemeParams` instead.
+     */
+    #[deprecated(note = "use `get_themes_param_theme_with` instead")]
+    pub async fn get_themes_param_theme(&self, theme_id: &str, fields: &str) -> Result<()> {
+        let params = OnlineStoreGetThemesParamThemeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_themes_param_theme_with(theme_id, &params).await
+    }
+
     /**
      * Updates an existing theme.
      *
@@ -5662,11 +6980,14 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202101GetThemeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_theme(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetThemeParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5675,6 +6996,17 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_theme_with` and pass the query parameters as a `OnlineStoreDeprecated202101GetThemeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_theme_with` instead")]
+    pub async fn deprecated_202101_get_theme(&self, fields: &str) -> Result<()> {
+        let params = OnlineStoreDeprecated202101GetThemeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_theme_with(&params).await
+    }
+
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
               A new theme is always unpublished by default. To publish a theme when you create it, include
@@ -5702,15 +7034,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `theme_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecated202101GetThemesParamThemeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_themes_param_theme(
+    pub async fn deprecated_202101_get_themes_param_theme_with(
         &self,
         theme_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecated202101GetThemesParamThemeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5723,6 +7055,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_themes_param_theme_with` and pass the query parameters as a `OnlineStoreDeprecated202101GetThemesParamThemeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_themes_param_theme_with` instead")]
+    pub async fn deprecated_202101_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecated202101GetThemesParamThemeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_themes_param_theme_with(theme_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing theme.
      *
@@ -5778,11 +7126,14 @@ impl OnlineStore {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecatedUnstableGetThemeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_theme(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetThemeParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5791,6 +7142,17 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_theme_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableGetThemeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_theme_with` instead")]
+    pub async fn deprecated_unstable_get_theme(&self, fields: &str) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableGetThemeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_theme_with(&params).await
+    }
+
     /**
     * Creates a theme by providing the public URL of a ZIP file that contains the theme.
               A new theme is always unpublished by default. To publish a theme when you create it, include
@@ -5818,15 +7180,15 @@ impl OnlineStore {
      * **Parameters:**
      *
      * * `theme_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &OnlineStoreDeprecatedUnstableGetThemesParamThemeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_themes_param_theme(
+    pub async fn deprecated_unstable_get_themes_param_theme_with(
         &self,
         theme_id: &str,
-        fields: &str,
+        params: &OnlineStoreDeprecatedUnstableGetThemesParamThemeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5839,6 +7201,22 @@ impl OnlineStore {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_themes_param_theme_with` and pass the query parameters as a `OnlineStoreDeprecatedUnstableGetThemesParamThemeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_themes_param_theme_with` instead")]
+    pub async fn deprecated_unstable_get_themes_param_theme(
+        &self,
+        theme_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OnlineStoreDeprecatedUnstableGetThemesParamThemeParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_themes_param_theme_with(theme_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing theme.
      *
@@ -5888,3 +7266,5501 @@ impl OnlineStore {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_blogs_param_blog_article_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetBlogsParamBlogArticleParams {
+    /// Filter articles by article author.
+    pub author: Option<String>,
+    /// Show articles created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show articles created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Retrieve an article with a specific handle.
+    pub handle: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show articles published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show articles published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Retrieve results based on their published status.
+    /// (default: any)
+    ///
+    /// published: Show only published articles.
+    /// unpublished: Show only unpublished articles.
+    /// any: Show articles of any published status.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter articles with a specific tag.
+    pub tag: Option<String>,
+    /// Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetBlogsParamBlogArticleParams {
+    pub fn author<T: ToString>(mut self, author: T) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn tag<T: ToString>(mut self, tag: T) -> Self {
+        self.tag = Some(tag.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_blogs_param_blog_articles_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountParams {
+    /// Count articles created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count articles created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count articles published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Count articles published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Count articles with a given published status.
+    /// (default: any)
+    ///
+    /// published: Count only published articles.
+    /// unpublished: Count only unpublished articles.
+    /// any: Count all articles.
+    pub published_status: Option<String>,
+    /// Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_blogs_param_blog_articles_article_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleParams {
+    /// Show only certain fields, specifed by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_articles_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetArticlesTagParams {
+    /// The maximum number of tags to retrieve.
+    pub limit: Option<String>,
+    /// A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
+    pub popular: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetArticlesTagParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn popular<T: ToString>(mut self, popular: T) -> Self {
+        self.popular = Some(popular.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_get_blogs_param_blog_article_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004GetBlogsParamBlogArticleParams {
+    /// Filter articles by article author.
+    pub author: Option<String>,
+    /// Show articles created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show articles created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Retrieve an article with a specific handle.
+    pub handle: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show articles published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show articles published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Retrieve results based on their published status.
+    /// (default: any)
+    ///
+    /// published: Show only published articles.
+    /// unpublished: Show only unpublished articles.
+    /// any: Show articles of any published status.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter articles with a specific tag.
+    pub tag: Option<String>,
+    /// Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004GetBlogsParamBlogArticleParams {
+    pub fn author<T: ToString>(mut self, author: T) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn tag<T: ToString>(mut self, tag: T) -> Self {
+        self.tag = Some(tag.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_get_blogs_param_blog_articles_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountParams {
+    /// Count articles created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count articles created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count articles published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Count articles published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Count articles with a given published status.
+    /// (default: any)
+    ///
+    /// published: Count only published articles.
+    /// unpublished: Count only unpublished articles.
+    /// any: Count all articles.
+    pub published_status: Option<String>,
+    /// Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_get_blogs_param_blog_articles_article_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleParams {
+    /// Show only certain fields, specifed by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_get_articles_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004GetArticlesTagParams {
+    /// The maximum number of tags to retrieve.
+    pub limit: Option<String>,
+    /// A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
+    pub popular: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004GetArticlesTagParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn popular<T: ToString>(mut self, popular: T) -> Self {
+        self.popular = Some(popular.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_get_blogs_param_blog_article_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007GetBlogsParamBlogArticleParams {
+    /// Filter articles by article author.
+    pub author: Option<String>,
+    /// Show articles created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show articles created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Retrieve an article with a specific handle.
+    pub handle: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show articles published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show articles published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Retrieve results based on their published status.
+    /// (default: any)
+    ///
+    /// published: Show only published articles.
+    /// unpublished: Show only unpublished articles.
+    /// any: Show articles of any published status.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter articles with a specific tag.
+    pub tag: Option<String>,
+    /// Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007GetBlogsParamBlogArticleParams {
+    pub fn author<T: ToString>(mut self, author: T) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn tag<T: ToString>(mut self, tag: T) -> Self {
+        self.tag = Some(tag.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_get_blogs_param_blog_articles_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountParams {
+    /// Count articles created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count articles created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count articles published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Count articles published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Count articles with a given published status.
+    /// (default: any)
+    ///
+    /// published: Count only published articles.
+    /// unpublished: Count only unpublished articles.
+    /// any: Count all articles.
+    pub published_status: Option<String>,
+    /// Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_get_blogs_param_blog_articles_article_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleParams {
+    /// Show only certain fields, specifed by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_get_articles_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007GetArticlesTagParams {
+    /// The maximum number of tags to retrieve.
+    pub limit: Option<String>,
+    /// A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
+    pub popular: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007GetArticlesTagParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn popular<T: ToString>(mut self, popular: T) -> Self {
+        self.popular = Some(popular.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::get_blogs_param_blog_article_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreGetBlogsParamBlogArticleParams {
+    /// Filter articles by article author.
+    pub author: Option<String>,
+    /// Show articles created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show articles created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Retrieve an article with a specific handle.
+    pub handle: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show articles published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show articles published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Retrieve results based on their published status.
+    /// (default: any)
+    ///
+    /// published: Show only published articles.
+    /// unpublished: Show only unpublished articles.
+    /// any: Show articles of any published status.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter articles with a specific tag.
+    pub tag: Option<String>,
+    /// Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreGetBlogsParamBlogArticleParams {
+    pub fn author<T: ToString>(mut self, author: T) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn tag<T: ToString>(mut self, tag: T) -> Self {
+        self.tag = Some(tag.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::get_blogs_param_blog_articles_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreGetBlogsParamBlogArticlesCountParams {
+    /// Count articles created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count articles created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count articles published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Count articles published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Count articles with a given published status.
+    /// (default: any)
+    ///
+    /// published: Count only published articles.
+    /// unpublished: Count only unpublished articles.
+    /// any: Count all articles.
+    pub published_status: Option<String>,
+    /// Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreGetBlogsParamBlogArticlesCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::get_blogs_param_blog_articles_article_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreGetBlogsParamBlogArticlesArticleParams {
+    /// Show only certain fields, specifed by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreGetBlogsParamBlogArticlesArticleParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::get_articles_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreGetArticlesTagParams {
+    /// The maximum number of tags to retrieve.
+    pub limit: Option<String>,
+    /// A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
+    pub popular: Option<String>,
+}
+
+impl OnlineStoreGetArticlesTagParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn popular<T: ToString>(mut self, popular: T) -> Self {
+        self.popular = Some(popular.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_get_blogs_param_blog_article_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101GetBlogsParamBlogArticleParams {
+    /// Filter articles by article author.
+    pub author: Option<String>,
+    /// Show articles created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show articles created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Retrieve an article with a specific handle.
+    pub handle: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show articles published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show articles published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Retrieve results based on their published status.
+    /// (default: any)
+    ///
+    /// published: Show only published articles.
+    /// unpublished: Show only unpublished articles.
+    /// any: Show articles of any published status.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter articles with a specific tag.
+    pub tag: Option<String>,
+    /// Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101GetBlogsParamBlogArticleParams {
+    pub fn author<T: ToString>(mut self, author: T) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn tag<T: ToString>(mut self, tag: T) -> Self {
+        self.tag = Some(tag.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_get_blogs_param_blog_articles_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountParams {
+    /// Count articles created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count articles created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count articles published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Count articles published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Count articles with a given published status.
+    /// (default: any)
+    ///
+    /// published: Count only published articles.
+    /// unpublished: Count only unpublished articles.
+    /// any: Count all articles.
+    pub published_status: Option<String>,
+    /// Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_get_blogs_param_blog_articles_article_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleParams {
+    /// Show only certain fields, specifed by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_get_articles_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101GetArticlesTagParams {
+    /// The maximum number of tags to retrieve.
+    pub limit: Option<String>,
+    /// A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
+    pub popular: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101GetArticlesTagParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn popular<T: ToString>(mut self, popular: T) -> Self {
+        self.popular = Some(popular.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_get_blogs_param_blog_article_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleParams {
+    /// Filter articles by article author.
+    pub author: Option<String>,
+    /// Show articles created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show articles created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Retrieve an article with a specific handle.
+    pub handle: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show articles published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show articles published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Retrieve results based on their published status.
+    /// (default: any)
+    ///
+    /// published: Show only published articles.
+    /// unpublished: Show only unpublished articles.
+    /// any: Show articles of any published status.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter articles with a specific tag.
+    pub tag: Option<String>,
+    /// Show articles last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show articles last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleParams {
+    pub fn author<T: ToString>(mut self, author: T) -> Self {
+        self.author = Some(author.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn tag<T: ToString>(mut self, tag: T) -> Self {
+        self.tag = Some(tag.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountParams {
+    /// Count articles created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count articles created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count articles published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Count articles published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Count articles with a given published status.
+    /// (default: any)
+    ///
+    /// published: Count only published articles.
+    /// unpublished: Count only unpublished articles.
+    /// any: Count all articles.
+    pub published_status: Option<String>,
+    /// Count articles last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count articles last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_article_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleParams {
+    /// Show only certain fields, specifed by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_get_articles_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableGetArticlesTagParams {
+    /// The maximum number of tags to retrieve.
+    pub limit: Option<String>,
+    /// A flag for ordering retrieved tags. If present in the request, then the results will be ordered by popularity, starting with the most popular tag.
+    pub popular: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableGetArticlesTagParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn popular<T: ToString>(mut self, popular: T) -> Self {
+        self.popular = Some(popular.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_themes_param_theme_asset_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetThemesParamThemeAssetParams {
+    /// storefront_access_token_id.
+    pub asset_key: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetThemesParamThemeAssetParams {
+    pub fn asset_key<T: ToString>(mut self, asset_key: T) -> Self {
+        self.asset_key = Some(asset_key.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_update_themes_param_theme_assets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsParams {
+    /// The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
+    pub source_key: Option<String>,
+    /// The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
+    pub src: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsParams {
+    pub fn source_key<T: ToString>(mut self, source_key: T) -> Self {
+        self.source_key = Some(source_key.to_string());
+        self
+    }
+
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_delete_themes_param_theme_assets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsParams {
+    /// storefront_access_token_id.
+    pub asset_key: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsParams {
+    pub fn asset_key<T: ToString>(mut self, asset_key: T) -> Self {
+        self.asset_key = Some(asset_key.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_get_themes_param_theme_asset_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004GetThemesParamThemeAssetParams {
+    /// storefront_access_token_id.
+    pub asset_key: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004GetThemesParamThemeAssetParams {
+    pub fn asset_key<T: ToString>(mut self, asset_key: T) -> Self {
+        self.asset_key = Some(asset_key.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_update_themes_param_theme_assets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsParams {
+    /// The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
+    pub source_key: Option<String>,
+    /// The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
+    pub src: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsParams {
+    pub fn source_key<T: ToString>(mut self, source_key: T) -> Self {
+        self.source_key = Some(source_key.to_string());
+        self
+    }
+
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_delete_themes_param_theme_assets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsParams {
+    /// storefront_access_token_id.
+    pub asset_key: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsParams {
+    pub fn asset_key<T: ToString>(mut self, asset_key: T) -> Self {
+        self.asset_key = Some(asset_key.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_get_themes_param_theme_asset_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007GetThemesParamThemeAssetParams {
+    /// storefront_access_token_id.
+    pub asset_key: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007GetThemesParamThemeAssetParams {
+    pub fn asset_key<T: ToString>(mut self, asset_key: T) -> Self {
+        self.asset_key = Some(asset_key.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_update_themes_param_theme_assets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsParams {
+    /// The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
+    pub source_key: Option<String>,
+    /// The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
+    pub src: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsParams {
+    pub fn source_key<T: ToString>(mut self, source_key: T) -> Self {
+        self.source_key = Some(source_key.to_string());
+        self
+    }
+
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_delete_themes_param_theme_assets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsParams {
+    /// storefront_access_token_id.
+    pub asset_key: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsParams {
+    pub fn asset_key<T: ToString>(mut self, asset_key: T) -> Self {
+        self.asset_key = Some(asset_key.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::get_themes_param_theme_asset_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreGetThemesParamThemeAssetParams {
+    /// storefront_access_token_id.
+    pub asset_key: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreGetThemesParamThemeAssetParams {
+    pub fn asset_key<T: ToString>(mut self, asset_key: T) -> Self {
+        self.asset_key = Some(asset_key.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::update_themes_param_theme_assets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreUpdateThemesParamThemeAssetsParams {
+    /// The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
+    pub source_key: Option<String>,
+    /// The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
+    pub src: Option<String>,
+}
+
+impl OnlineStoreUpdateThemesParamThemeAssetsParams {
+    pub fn source_key<T: ToString>(mut self, source_key: T) -> Self {
+        self.source_key = Some(source_key.to_string());
+        self
+    }
+
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::delete_themes_param_theme_assets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeleteThemesParamThemeAssetsParams {
+    /// storefront_access_token_id.
+    pub asset_key: Option<String>,
+}
+
+impl OnlineStoreDeleteThemesParamThemeAssetsParams {
+    pub fn asset_key<T: ToString>(mut self, asset_key: T) -> Self {
+        self.asset_key = Some(asset_key.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_get_themes_param_theme_asset_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101GetThemesParamThemeAssetParams {
+    /// storefront_access_token_id.
+    pub asset_key: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101GetThemesParamThemeAssetParams {
+    pub fn asset_key<T: ToString>(mut self, asset_key: T) -> Self {
+        self.asset_key = Some(asset_key.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_update_themes_param_theme_assets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsParams {
+    /// The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
+    pub source_key: Option<String>,
+    /// The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
+    pub src: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsParams {
+    pub fn source_key<T: ToString>(mut self, source_key: T) -> Self {
+        self.source_key = Some(source_key.to_string());
+        self
+    }
+
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_delete_themes_param_theme_assets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsParams {
+    /// storefront_access_token_id.
+    pub asset_key: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsParams {
+    pub fn asset_key<T: ToString>(mut self, asset_key: T) -> Self {
+        self.asset_key = Some(asset_key.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_get_themes_param_theme_asset_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetParams {
+    /// storefront_access_token_id.
+    pub asset_key: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetParams {
+    pub fn asset_key<T: ToString>(mut self, asset_key: T) -> Self {
+        self.asset_key = Some(asset_key.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_update_themes_param_theme_assets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsParams {
+    /// The path within the theme to an existing asset. Include in the body of the PUT request to create a duplicate asset.
+    pub source_key: Option<String>,
+    /// The source URL of an image. Include in the body of the PUT request to upload the image to Shopify.
+    pub src: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsParams {
+    pub fn source_key<T: ToString>(mut self, source_key: T) -> Self {
+        self.source_key = Some(source_key.to_string());
+        self
+    }
+
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_delete_themes_param_theme_assets_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsParams {
+    /// storefront_access_token_id.
+    pub asset_key: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsParams {
+    pub fn asset_key<T: ToString>(mut self, asset_key: T) -> Self {
+        self.asset_key = Some(asset_key.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_blog_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetBlogParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Filter by blog handle.
+    pub handle: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetBlogParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_blogs_param_blog_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetBlogsParamBlogParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetBlogsParamBlogParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_comment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetCommentParams {
+    /// recurring_application_charge[capped_amount].
+    pub article_id: Option<i64>,
+    /// recurring_application_charge[capped_amount].
+    pub blog_id: Option<i64>,
+    /// Show comments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show comments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show comments published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show comments published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Filter results by their published status.
+    /// (default: any)
+    ///
+    /// published: Show only published comments.
+    /// unpublished: Show only unpublished comments.
+    /// any: Show comments of any published status.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter results by their status.
+    ///
+    /// pending: Show only pending comments.
+    /// published: Show only published comments.
+    /// unapproved: Show only unapproved comments.
+    pub status: Option<String>,
+    /// Show comments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show comments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetCommentParams {
+    pub fn article_id(mut self, article_id: i64) -> Self {
+        self.article_id = Some(article_id);
+        self
+    }
+
+    pub fn blog_id(mut self, blog_id: i64) -> Self {
+        self.blog_id = Some(blog_id);
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_comments_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetCommentsCountParams {
+    /// recurring_application_charge[capped_amount].
+    pub article_id: Option<i64>,
+    /// recurring_application_charge[capped_amount].
+    pub blog_id: Option<i64>,
+    /// Count comments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count comments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count comments published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Count comments published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Retrieve a count of comments with a given published status.
+    /// (default: any)
+    ///
+    /// published: Count only published comments.
+    /// unpublished: Count only unpublished comments.
+    /// any: Count comments of any published status.
+    pub published_status: Option<String>,
+    /// Retrieve a count of comments with a given status.
+    ///
+    /// pending: Count pending comments.
+    /// published: Count published comments.
+    /// unapproved: Count unapproved comments.
+    pub status: Option<String>,
+    /// Count comments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count comments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetCommentsCountParams {
+    pub fn article_id(mut self, article_id: i64) -> Self {
+        self.article_id = Some(article_id);
+        self
+    }
+
+    pub fn blog_id(mut self, blog_id: i64) -> Self {
+        self.blog_id = Some(blog_id);
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_comments_param_comment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetCommentsParamCommentParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetCommentsParamCommentParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_redirect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetRedirectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show redirects with a given path.
+    pub path: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show redirects with a given target.
+    pub target: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetRedirectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn target<T: ToString>(mut self, target: T) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_redirects_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetRedirectsCountParams {
+    /// Count redirects with given path.
+    pub path: Option<String>,
+    /// Count redirects with given target.
+    pub target: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetRedirectsCountParams {
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn target<T: ToString>(mut self, target: T) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_redirects_param_redirect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetRedirectsParamRedirectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetRedirectsParamRedirectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_get_redirect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004GetRedirectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show redirects with a given path.
+    pub path: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show redirects with a given target.
+    pub target: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004GetRedirectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn target<T: ToString>(mut self, target: T) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_get_redirects_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004GetRedirectsCountParams {
+    /// Count redirects with given path.
+    pub path: Option<String>,
+    /// Count redirects with given target.
+    pub target: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004GetRedirectsCountParams {
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn target<T: ToString>(mut self, target: T) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_get_redirects_param_redirect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004GetRedirectsParamRedirectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004GetRedirectsParamRedirectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_get_redirect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007GetRedirectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show redirects with a given path.
+    pub path: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show redirects with a given target.
+    pub target: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007GetRedirectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn target<T: ToString>(mut self, target: T) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_get_redirects_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007GetRedirectsCountParams {
+    /// Count redirects with given path.
+    pub path: Option<String>,
+    /// Count redirects with given target.
+    pub target: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007GetRedirectsCountParams {
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn target<T: ToString>(mut self, target: T) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_get_redirects_param_redirect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007GetRedirectsParamRedirectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007GetRedirectsParamRedirectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::get_redirect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreGetRedirectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show redirects with a given path.
+    pub path: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show redirects with a given target.
+    pub target: Option<String>,
+}
+
+impl OnlineStoreGetRedirectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn target<T: ToString>(mut self, target: T) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::get_redirects_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreGetRedirectsCountParams {
+    /// Count redirects with given path.
+    pub path: Option<String>,
+    /// Count redirects with given target.
+    pub target: Option<String>,
+}
+
+impl OnlineStoreGetRedirectsCountParams {
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn target<T: ToString>(mut self, target: T) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::get_redirects_param_redirect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreGetRedirectsParamRedirectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreGetRedirectsParamRedirectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_get_redirect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101GetRedirectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show redirects with a given path.
+    pub path: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show redirects with a given target.
+    pub target: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101GetRedirectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn target<T: ToString>(mut self, target: T) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_get_redirects_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101GetRedirectsCountParams {
+    /// Count redirects with given path.
+    pub path: Option<String>,
+    /// Count redirects with given target.
+    pub target: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101GetRedirectsCountParams {
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn target<T: ToString>(mut self, target: T) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_get_redirects_param_redirect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101GetRedirectsParamRedirectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101GetRedirectsParamRedirectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_get_redirect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableGetRedirectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show redirects with a given path.
+    pub path: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show redirects with a given target.
+    pub target: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableGetRedirectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn target<T: ToString>(mut self, target: T) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_get_redirects_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableGetRedirectsCountParams {
+    /// Count redirects with given path.
+    pub path: Option<String>,
+    /// Count redirects with given target.
+    pub target: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableGetRedirectsCountParams {
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn target<T: ToString>(mut self, target: T) -> Self {
+        self.target = Some(target.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_get_redirects_param_redirect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_script_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetScriptTagParams {
+    /// Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// The number of results to return.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show script tags with this URL.
+    pub src: Option<String>,
+    /// Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetScriptTagParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_script_tags_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetScriptTagsCountParams {
+    /// Count only script tags with a given URL.
+    pub src: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetScriptTagsCountParams {
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_script_tags_param_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetScriptTagsParamTagParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetScriptTagsParamTagParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_get_script_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004GetScriptTagParams {
+    /// Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// The number of results to return.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show script tags with this URL.
+    pub src: Option<String>,
+    /// Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004GetScriptTagParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_get_script_tags_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004GetScriptTagsCountParams {
+    /// Count only script tags with a given URL.
+    pub src: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004GetScriptTagsCountParams {
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_get_script_tags_param_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004GetScriptTagsParamTagParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004GetScriptTagsParamTagParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_get_script_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007GetScriptTagParams {
+    /// Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// The number of results to return.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show script tags with this URL.
+    pub src: Option<String>,
+    /// Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007GetScriptTagParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_get_script_tags_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007GetScriptTagsCountParams {
+    /// Count only script tags with a given URL.
+    pub src: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007GetScriptTagsCountParams {
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_get_script_tags_param_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007GetScriptTagsParamTagParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007GetScriptTagsParamTagParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::get_script_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreGetScriptTagParams {
+    /// Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// The number of results to return.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show script tags with this URL.
+    pub src: Option<String>,
+    /// Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreGetScriptTagParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::get_script_tags_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreGetScriptTagsCountParams {
+    /// Count only script tags with a given URL.
+    pub src: Option<String>,
+}
+
+impl OnlineStoreGetScriptTagsCountParams {
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::get_script_tags_param_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreGetScriptTagsParamTagParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreGetScriptTagsParamTagParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_get_script_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101GetScriptTagParams {
+    /// Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// The number of results to return.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show script tags with this URL.
+    pub src: Option<String>,
+    /// Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101GetScriptTagParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_get_script_tags_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101GetScriptTagsCountParams {
+    /// Count only script tags with a given URL.
+    pub src: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101GetScriptTagsCountParams {
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_get_script_tags_param_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101GetScriptTagsParamTagParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101GetScriptTagsParamTagParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_get_script_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableGetScriptTagParams {
+    /// Show script tags created before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show script tags created after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// The number of results to return.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show script tags with this URL.
+    pub src: Option<String>,
+    /// Show script tags last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show script tags last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableGetScriptTagParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_get_script_tags_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableGetScriptTagsCountParams {
+    /// Count only script tags with a given URL.
+    pub src: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableGetScriptTagsCountParams {
+    pub fn src<T: ToString>(mut self, src: T) -> Self {
+        self.src = Some(src.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_get_script_tags_param_tag_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableGetScriptTagsParamTagParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableGetScriptTagsParamTagParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_theme_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetThemeParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetThemeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202001_get_themes_param_theme_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202001GetThemesParamThemeParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202001GetThemesParamThemeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_get_theme_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004GetThemeParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004GetThemeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202004_get_themes_param_theme_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202004GetThemesParamThemeParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202004GetThemesParamThemeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_get_theme_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007GetThemeParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007GetThemeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202007_get_themes_param_theme_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202007GetThemesParamThemeParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202007GetThemesParamThemeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::get_theme_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreGetThemeParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreGetThemeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::get_themes_param_theme_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreGetThemesParamThemeParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreGetThemesParamThemeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_get_theme_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101GetThemeParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101GetThemeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_202101_get_themes_param_theme_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecated202101GetThemesParamThemeParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecated202101GetThemesParamThemeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_get_theme_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableGetThemeParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableGetThemeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `OnlineStore::deprecated_unstable_get_themes_param_theme_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OnlineStoreDeprecatedUnstableGetThemesParamThemeParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl OnlineStoreDeprecatedUnstableGetThemesParamThemeParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The functions of [`OnlineStore`], for code that takes them as a trait, so that it can be
+/// tested with the `MockOnlineStoreApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait OnlineStoreApi: Send + Sync {
+    /// See [`OnlineStore::deprecated_202001_get_blogs_param_blog_article_with`].
+    async fn deprecated_202001_get_blogs_param_blog_article_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202001GetBlogsParamBlogArticleParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_create_blogs_param_blog_articles`].
+    async fn deprecated_202001_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_blogs_param_blog_articles_count_with`].
+    async fn deprecated_202001_get_blogs_param_blog_articles_count_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_blogs_param_blog_articles_article_with`].
+    async fn deprecated_202001_get_blogs_param_blog_articles_article_with(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        params: &OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_update_blogs_param_blog_articles_article`].
+    async fn deprecated_202001_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_delete_blogs_param_blog_articles_article`].
+    async fn deprecated_202001_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_articles_author`].
+    async fn deprecated_202001_get_articles_author(&self) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_articles_tag_with`].
+    async fn deprecated_202001_get_articles_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetArticlesTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_blogs_param_blog_article_with`].
+    async fn deprecated_202004_get_blogs_param_blog_article_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202004GetBlogsParamBlogArticleParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_create_blogs_param_blog_articles`].
+    async fn deprecated_202004_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_blogs_param_blog_articles_count_with`].
+    async fn deprecated_202004_get_blogs_param_blog_articles_count_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_blogs_param_blog_articles_article_with`].
+    async fn deprecated_202004_get_blogs_param_blog_articles_article_with(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        params: &OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_update_blogs_param_blog_articles_article`].
+    async fn deprecated_202004_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_delete_blogs_param_blog_articles_article`].
+    async fn deprecated_202004_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_articles_author`].
+    async fn deprecated_202004_get_articles_author(&self) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_articles_tag_with`].
+    async fn deprecated_202004_get_articles_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetArticlesTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_blogs_param_blog_article_with`].
+    async fn deprecated_202007_get_blogs_param_blog_article_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202007GetBlogsParamBlogArticleParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_create_blogs_param_blog_articles`].
+    async fn deprecated_202007_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_blogs_param_blog_articles_count_with`].
+    async fn deprecated_202007_get_blogs_param_blog_articles_count_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_blogs_param_blog_articles_article_with`].
+    async fn deprecated_202007_get_blogs_param_blog_articles_article_with(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        params: &OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_update_blogs_param_blog_articles_article`].
+    async fn deprecated_202007_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_delete_blogs_param_blog_articles_article`].
+    async fn deprecated_202007_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_articles_author`].
+    async fn deprecated_202007_get_articles_author(&self) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_articles_tag_with`].
+    async fn deprecated_202007_get_articles_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetArticlesTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::get_blogs_param_blog_article_with`].
+    async fn get_blogs_param_blog_article_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreGetBlogsParamBlogArticleParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::create_blogs_param_blog_articles`].
+    async fn create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::get_blogs_param_blog_articles_count_with`].
+    async fn get_blogs_param_blog_articles_count_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreGetBlogsParamBlogArticlesCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::get_blogs_param_blog_articles_article_with`].
+    async fn get_blogs_param_blog_articles_article_with(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        params: &OnlineStoreGetBlogsParamBlogArticlesArticleParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::update_blogs_param_blog_articles_article`].
+    async fn update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::delete_blogs_param_blog_articles_article`].
+    async fn delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::get_articles_author`].
+    async fn get_articles_author(&self) -> Result<()>;
+
+    /// See [`OnlineStore::get_articles_tag_with`].
+    async fn get_articles_tag_with(&self, params: &OnlineStoreGetArticlesTagParams) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_blogs_param_blog_article_with`].
+    async fn deprecated_202101_get_blogs_param_blog_article_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202101GetBlogsParamBlogArticleParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_create_blogs_param_blog_articles`].
+    async fn deprecated_202101_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_blogs_param_blog_articles_count_with`].
+    async fn deprecated_202101_get_blogs_param_blog_articles_count_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_blogs_param_blog_articles_article_with`].
+    async fn deprecated_202101_get_blogs_param_blog_articles_article_with(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        params: &OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_update_blogs_param_blog_articles_article`].
+    async fn deprecated_202101_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_delete_blogs_param_blog_articles_article`].
+    async fn deprecated_202101_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_articles_author`].
+    async fn deprecated_202101_get_articles_author(&self) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_articles_tag_with`].
+    async fn deprecated_202101_get_articles_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetArticlesTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_blogs_param_blog_article_with`].
+    async fn deprecated_unstable_get_blogs_param_blog_article_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_create_blogs_param_blog_articles`].
+    async fn deprecated_unstable_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_count_with`].
+    async fn deprecated_unstable_get_blogs_param_blog_articles_count_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_article_with`].
+    async fn deprecated_unstable_get_blogs_param_blog_articles_article_with(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_update_blogs_param_blog_articles_article`].
+    async fn deprecated_unstable_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_delete_blogs_param_blog_articles_article`].
+    async fn deprecated_unstable_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_articles_author`].
+    async fn deprecated_unstable_get_articles_author(&self) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_articles_tag_with`].
+    async fn deprecated_unstable_get_articles_tag_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetArticlesTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_themes_param_theme_asset_with`].
+    async fn deprecated_202001_get_themes_param_theme_asset_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202001GetThemesParamThemeAssetParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_update_themes_param_theme_assets_with`].
+    async fn deprecated_202001_update_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_delete_themes_param_theme_assets_with`].
+    async fn deprecated_202001_delete_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_themes_param_theme_asset_with`].
+    async fn deprecated_202004_get_themes_param_theme_asset_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202004GetThemesParamThemeAssetParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_update_themes_param_theme_assets_with`].
+    async fn deprecated_202004_update_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_delete_themes_param_theme_assets_with`].
+    async fn deprecated_202004_delete_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_themes_param_theme_asset_with`].
+    async fn deprecated_202007_get_themes_param_theme_asset_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202007GetThemesParamThemeAssetParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_update_themes_param_theme_assets_with`].
+    async fn deprecated_202007_update_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_delete_themes_param_theme_assets_with`].
+    async fn deprecated_202007_delete_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::get_themes_param_theme_asset_with`].
+    async fn get_themes_param_theme_asset_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreGetThemesParamThemeAssetParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::update_themes_param_theme_assets_with`].
+    async fn update_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreUpdateThemesParamThemeAssetsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::delete_themes_param_theme_assets_with`].
+    async fn delete_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeleteThemesParamThemeAssetsParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_themes_param_theme_asset_with`].
+    async fn deprecated_202101_get_themes_param_theme_asset_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202101GetThemesParamThemeAssetParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_update_themes_param_theme_assets_with`].
+    async fn deprecated_202101_update_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_delete_themes_param_theme_assets_with`].
+    async fn deprecated_202101_delete_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_themes_param_theme_asset_with`].
+    async fn deprecated_unstable_get_themes_param_theme_asset_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_update_themes_param_theme_assets_with`].
+    async fn deprecated_unstable_update_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_delete_themes_param_theme_assets_with`].
+    async fn deprecated_unstable_delete_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_blog_with`].
+    async fn deprecated_202001_get_blog_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetBlogParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_create_blogs`].
+    async fn deprecated_202001_create_blogs(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_blogs_count`].
+    async fn deprecated_202001_get_blogs_count(&self) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_blogs_param_blog_with`].
+    async fn deprecated_202001_get_blogs_param_blog_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202001GetBlogsParamBlogParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_update_blogs_param_blog`].
+    async fn deprecated_202001_update_blogs_param_blog(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_delete_blogs_param_blog`].
+    async fn deprecated_202001_delete_blogs_param_blog(&self, blog_id: &str) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_comment_with`].
+    async fn deprecated_202001_get_comment_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetCommentParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_create_comments`].
+    async fn deprecated_202001_create_comments(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_comments_count_with`].
+    async fn deprecated_202001_get_comments_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetCommentsCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_comments_param_comment_with`].
+    async fn deprecated_202001_get_comments_param_comment_with(
+        &self,
+        comment_id: &str,
+        params: &OnlineStoreDeprecated202001GetCommentsParamCommentParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_update_comments_param_comment`].
+    async fn deprecated_202001_update_comments_param_comment(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_create_comments_param_comment_spam`].
+    async fn deprecated_202001_create_comments_param_comment_spam(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_create_comments_param_comment_not_spam`].
+    async fn deprecated_202001_create_comments_param_comment_not_spam(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_create_comments_param_comment_approve`].
+    async fn deprecated_202001_create_comments_param_comment_approve(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_create_comments_param_comment_remove`].
+    async fn deprecated_202001_create_comments_param_comment_remove(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_create_comments_param_comment_restore`].
+    async fn deprecated_202001_create_comments_param_comment_restore(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_redirect_with`].
+    async fn deprecated_202001_get_redirect_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetRedirectParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_create_redirects`].
+    async fn deprecated_202001_create_redirects(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_redirects_count_with`].
+    async fn deprecated_202001_get_redirects_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetRedirectsCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_redirects_param_redirect_with`].
+    async fn deprecated_202001_get_redirects_param_redirect_with(
+        &self,
+        redirect_id: &str,
+        params: &OnlineStoreDeprecated202001GetRedirectsParamRedirectParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_update_redirects_param_redirect`].
+    async fn deprecated_202001_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_delete_redirects_param_redirect`].
+    async fn deprecated_202001_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_redirect_with`].
+    async fn deprecated_202004_get_redirect_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetRedirectParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_create_redirects`].
+    async fn deprecated_202004_create_redirects(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_redirects_count_with`].
+    async fn deprecated_202004_get_redirects_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetRedirectsCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_redirects_param_redirect_with`].
+    async fn deprecated_202004_get_redirects_param_redirect_with(
+        &self,
+        redirect_id: &str,
+        params: &OnlineStoreDeprecated202004GetRedirectsParamRedirectParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_update_redirects_param_redirect`].
+    async fn deprecated_202004_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_delete_redirects_param_redirect`].
+    async fn deprecated_202004_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_redirect_with`].
+    async fn deprecated_202007_get_redirect_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetRedirectParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_create_redirects`].
+    async fn deprecated_202007_create_redirects(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_redirects_count_with`].
+    async fn deprecated_202007_get_redirects_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetRedirectsCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_redirects_param_redirect_with`].
+    async fn deprecated_202007_get_redirects_param_redirect_with(
+        &self,
+        redirect_id: &str,
+        params: &OnlineStoreDeprecated202007GetRedirectsParamRedirectParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_update_redirects_param_redirect`].
+    async fn deprecated_202007_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_delete_redirects_param_redirect`].
+    async fn deprecated_202007_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::get_redirect_with`].
+    async fn get_redirect_with(&self, params: &OnlineStoreGetRedirectParams) -> Result<()>;
+
+    /// See [`OnlineStore::create_redirects`].
+    async fn create_redirects(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::get_redirects_count_with`].
+    async fn get_redirects_count_with(
+        &self,
+        params: &OnlineStoreGetRedirectsCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::get_redirects_param_redirect_with`].
+    async fn get_redirects_param_redirect_with(
+        &self,
+        redirect_id: &str,
+        params: &OnlineStoreGetRedirectsParamRedirectParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::update_redirects_param_redirect`].
+    async fn update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::delete_redirects_param_redirect`].
+    async fn delete_redirects_param_redirect(&self, redirect_id: &str) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_redirect_with`].
+    async fn deprecated_202101_get_redirect_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetRedirectParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_create_redirects`].
+    async fn deprecated_202101_create_redirects(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_redirects_count_with`].
+    async fn deprecated_202101_get_redirects_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetRedirectsCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_redirects_param_redirect_with`].
+    async fn deprecated_202101_get_redirects_param_redirect_with(
+        &self,
+        redirect_id: &str,
+        params: &OnlineStoreDeprecated202101GetRedirectsParamRedirectParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_update_redirects_param_redirect`].
+    async fn deprecated_202101_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_delete_redirects_param_redirect`].
+    async fn deprecated_202101_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_redirect_with`].
+    async fn deprecated_unstable_get_redirect_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetRedirectParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_create_redirects`].
+    async fn deprecated_unstable_create_redirects(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_redirects_count_with`].
+    async fn deprecated_unstable_get_redirects_count_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetRedirectsCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_redirects_param_redirect_with`].
+    async fn deprecated_unstable_get_redirects_param_redirect_with(
+        &self,
+        redirect_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_update_redirects_param_redirect`].
+    async fn deprecated_unstable_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_delete_redirects_param_redirect`].
+    async fn deprecated_unstable_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_script_tag_with`].
+    async fn deprecated_202001_get_script_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetScriptTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_create_script_tags`].
+    async fn deprecated_202001_create_script_tags(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_script_tags_count_with`].
+    async fn deprecated_202001_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetScriptTagsCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_script_tags_param_tag_with`].
+    async fn deprecated_202001_get_script_tags_param_tag_with(
+        &self,
+        script_tag_id: &str,
+        params: &OnlineStoreDeprecated202001GetScriptTagsParamTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_update_script_tags_param_tag`].
+    async fn deprecated_202001_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_delete_script_tags_param_tag`].
+    async fn deprecated_202001_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_script_tag_with`].
+    async fn deprecated_202004_get_script_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetScriptTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_create_script_tags`].
+    async fn deprecated_202004_create_script_tags(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_script_tags_count_with`].
+    async fn deprecated_202004_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetScriptTagsCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_script_tags_param_tag_with`].
+    async fn deprecated_202004_get_script_tags_param_tag_with(
+        &self,
+        script_tag_id: &str,
+        params: &OnlineStoreDeprecated202004GetScriptTagsParamTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_update_script_tags_param_tag`].
+    async fn deprecated_202004_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_delete_script_tags_param_tag`].
+    async fn deprecated_202004_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_script_tag_with`].
+    async fn deprecated_202007_get_script_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetScriptTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_create_script_tags`].
+    async fn deprecated_202007_create_script_tags(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_script_tags_count_with`].
+    async fn deprecated_202007_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetScriptTagsCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_script_tags_param_tag_with`].
+    async fn deprecated_202007_get_script_tags_param_tag_with(
+        &self,
+        script_tag_id: &str,
+        params: &OnlineStoreDeprecated202007GetScriptTagsParamTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_update_script_tags_param_tag`].
+    async fn deprecated_202007_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_delete_script_tags_param_tag`].
+    async fn deprecated_202007_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::get_script_tag_with`].
+    async fn get_script_tag_with(&self, params: &OnlineStoreGetScriptTagParams) -> Result<()>;
+
+    /// See [`OnlineStore::create_script_tags`].
+    async fn create_script_tags(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::get_script_tags_count_with`].
+    async fn get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreGetScriptTagsCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::get_script_tags_param_tag_with`].
+    async fn get_script_tags_param_tag_with(
+        &self,
+        script_tag_id: &str,
+        params: &OnlineStoreGetScriptTagsParamTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::update_script_tags_param_tag`].
+    async fn update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::delete_script_tags_param_tag`].
+    async fn delete_script_tags_param_tag(&self, script_tag_id: &str) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_script_tag_with`].
+    async fn deprecated_202101_get_script_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetScriptTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_create_script_tags`].
+    async fn deprecated_202101_create_script_tags(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_script_tags_count_with`].
+    async fn deprecated_202101_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetScriptTagsCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_script_tags_param_tag_with`].
+    async fn deprecated_202101_get_script_tags_param_tag_with(
+        &self,
+        script_tag_id: &str,
+        params: &OnlineStoreDeprecated202101GetScriptTagsParamTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_update_script_tags_param_tag`].
+    async fn deprecated_202101_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_delete_script_tags_param_tag`].
+    async fn deprecated_202101_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_script_tag_with`].
+    async fn deprecated_unstable_get_script_tag_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetScriptTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_create_script_tags`].
+    async fn deprecated_unstable_create_script_tags(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_script_tags_count_with`].
+    async fn deprecated_unstable_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetScriptTagsCountParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_script_tags_param_tag_with`].
+    async fn deprecated_unstable_get_script_tags_param_tag_with(
+        &self,
+        script_tag_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetScriptTagsParamTagParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_update_script_tags_param_tag`].
+    async fn deprecated_unstable_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_delete_script_tags_param_tag`].
+    async fn deprecated_unstable_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_theme_with`].
+    async fn deprecated_202001_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetThemeParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_create_themes`].
+    async fn deprecated_202001_create_themes(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_get_themes_param_theme_with`].
+    async fn deprecated_202001_get_themes_param_theme_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202001GetThemesParamThemeParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_update_themes_param_theme`].
+    async fn deprecated_202001_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202001_delete_themes_param_theme`].
+    async fn deprecated_202001_delete_themes_param_theme(&self, theme_id: &str) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_theme_with`].
+    async fn deprecated_202004_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetThemeParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_create_themes`].
+    async fn deprecated_202004_create_themes(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_get_themes_param_theme_with`].
+    async fn deprecated_202004_get_themes_param_theme_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202004GetThemesParamThemeParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_update_themes_param_theme`].
+    async fn deprecated_202004_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202004_delete_themes_param_theme`].
+    async fn deprecated_202004_delete_themes_param_theme(&self, theme_id: &str) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_theme_with`].
+    async fn deprecated_202007_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetThemeParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_create_themes`].
+    async fn deprecated_202007_create_themes(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_get_themes_param_theme_with`].
+    async fn deprecated_202007_get_themes_param_theme_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202007GetThemesParamThemeParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_update_themes_param_theme`].
+    async fn deprecated_202007_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202007_delete_themes_param_theme`].
+    async fn deprecated_202007_delete_themes_param_theme(&self, theme_id: &str) -> Result<()>;
+
+    /// See [`OnlineStore::get_theme_with`].
+    async fn get_theme_with(&self, params: &OnlineStoreGetThemeParams) -> Result<()>;
+
+    /// See [`OnlineStore::create_themes`].
+    async fn create_themes(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::get_themes_param_theme_with`].
+    async fn get_themes_param_theme_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreGetThemesParamThemeParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::update_themes_param_theme`].
+    async fn update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::delete_themes_param_theme`].
+    async fn delete_themes_param_theme(&self, theme_id: &str) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_theme_with`].
+    async fn deprecated_202101_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetThemeParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_create_themes`].
+    async fn deprecated_202101_create_themes(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_get_themes_param_theme_with`].
+    async fn deprecated_202101_get_themes_param_theme_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202101GetThemesParamThemeParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_update_themes_param_theme`].
+    async fn deprecated_202101_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_202101_delete_themes_param_theme`].
+    async fn deprecated_202101_delete_themes_param_theme(&self, theme_id: &str) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_theme_with`].
+    async fn deprecated_unstable_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetThemeParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_create_themes`].
+    async fn deprecated_unstable_create_themes(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_get_themes_param_theme_with`].
+    async fn deprecated_unstable_get_themes_param_theme_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetThemesParamThemeParams,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_update_themes_param_theme`].
+    async fn deprecated_unstable_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`OnlineStore::deprecated_unstable_delete_themes_param_theme`].
+    async fn deprecated_unstable_delete_themes_param_theme(&self, theme_id: &str) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl OnlineStoreApi for OnlineStore {
+    async fn deprecated_202001_get_blogs_param_blog_article_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202001GetBlogsParamBlogArticleParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_blogs_param_blog_article_with(self, blog_id, params)
+            .await
+    }
+
+    async fn deprecated_202001_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_create_blogs_param_blog_articles(self, blog_id, body).await
+    }
+
+    async fn deprecated_202001_get_blogs_param_blog_articles_count_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202001GetBlogsParamBlogArticlesCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_blogs_param_blog_articles_count_with(
+            self, blog_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_blogs_param_blog_articles_article_with(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        params: &OnlineStoreDeprecated202001GetBlogsParamBlogArticlesArticleParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_blogs_param_blog_articles_article_with(
+            self, blog_id, article_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_blogs_param_blog_articles_article(
+            self, blog_id, article_id, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_delete_blogs_param_blog_articles_article(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_articles_author(&self) -> Result<()> {
+        OnlineStore::deprecated_202001_get_articles_author(self).await
+    }
+
+    async fn deprecated_202001_get_articles_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetArticlesTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_articles_tag_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_blogs_param_blog_article_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202004GetBlogsParamBlogArticleParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_blogs_param_blog_article_with(self, blog_id, params)
+            .await
+    }
+
+    async fn deprecated_202004_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_create_blogs_param_blog_articles(self, blog_id, body).await
+    }
+
+    async fn deprecated_202004_get_blogs_param_blog_articles_count_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202004GetBlogsParamBlogArticlesCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_blogs_param_blog_articles_count_with(
+            self, blog_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_blogs_param_blog_articles_article_with(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        params: &OnlineStoreDeprecated202004GetBlogsParamBlogArticlesArticleParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_blogs_param_blog_articles_article_with(
+            self, blog_id, article_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_update_blogs_param_blog_articles_article(
+            self, blog_id, article_id, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_delete_blogs_param_blog_articles_article(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_articles_author(&self) -> Result<()> {
+        OnlineStore::deprecated_202004_get_articles_author(self).await
+    }
+
+    async fn deprecated_202004_get_articles_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetArticlesTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_articles_tag_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_blogs_param_blog_article_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202007GetBlogsParamBlogArticleParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_blogs_param_blog_article_with(self, blog_id, params)
+            .await
+    }
+
+    async fn deprecated_202007_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_create_blogs_param_blog_articles(self, blog_id, body).await
+    }
+
+    async fn deprecated_202007_get_blogs_param_blog_articles_count_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202007GetBlogsParamBlogArticlesCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_blogs_param_blog_articles_count_with(
+            self, blog_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_blogs_param_blog_articles_article_with(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        params: &OnlineStoreDeprecated202007GetBlogsParamBlogArticlesArticleParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_blogs_param_blog_articles_article_with(
+            self, blog_id, article_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_update_blogs_param_blog_articles_article(
+            self, blog_id, article_id, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_delete_blogs_param_blog_articles_article(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_articles_author(&self) -> Result<()> {
+        OnlineStore::deprecated_202007_get_articles_author(self).await
+    }
+
+    async fn deprecated_202007_get_articles_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetArticlesTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_articles_tag_with(self, params).await
+    }
+
+    async fn get_blogs_param_blog_article_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreGetBlogsParamBlogArticleParams,
+    ) -> Result<()> {
+        OnlineStore::get_blogs_param_blog_article_with(self, blog_id, params).await
+    }
+
+    async fn create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::create_blogs_param_blog_articles(self, blog_id, body).await
+    }
+
+    async fn get_blogs_param_blog_articles_count_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreGetBlogsParamBlogArticlesCountParams,
+    ) -> Result<()> {
+        OnlineStore::get_blogs_param_blog_articles_count_with(self, blog_id, params).await
+    }
+
+    async fn get_blogs_param_blog_articles_article_with(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        params: &OnlineStoreGetBlogsParamBlogArticlesArticleParams,
+    ) -> Result<()> {
+        OnlineStore::get_blogs_param_blog_articles_article_with(self, blog_id, article_id, params)
+            .await
+    }
+
+    async fn update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::update_blogs_param_blog_articles_article(self, blog_id, article_id, body).await
+    }
+
+    async fn delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()> {
+        OnlineStore::delete_blogs_param_blog_articles_article(self, blog_id, article_id).await
+    }
+
+    async fn get_articles_author(&self) -> Result<()> {
+        OnlineStore::get_articles_author(self).await
+    }
+
+    async fn get_articles_tag_with(&self, params: &OnlineStoreGetArticlesTagParams) -> Result<()> {
+        OnlineStore::get_articles_tag_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_blogs_param_blog_article_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202101GetBlogsParamBlogArticleParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_blogs_param_blog_article_with(self, blog_id, params)
+            .await
+    }
+
+    async fn deprecated_202101_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_create_blogs_param_blog_articles(self, blog_id, body).await
+    }
+
+    async fn deprecated_202101_get_blogs_param_blog_articles_count_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202101GetBlogsParamBlogArticlesCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_blogs_param_blog_articles_count_with(
+            self, blog_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_blogs_param_blog_articles_article_with(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        params: &OnlineStoreDeprecated202101GetBlogsParamBlogArticlesArticleParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_blogs_param_blog_articles_article_with(
+            self, blog_id, article_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_update_blogs_param_blog_articles_article(
+            self, blog_id, article_id, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_delete_blogs_param_blog_articles_article(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_articles_author(&self) -> Result<()> {
+        OnlineStore::deprecated_202101_get_articles_author(self).await
+    }
+
+    async fn deprecated_202101_get_articles_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetArticlesTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_articles_tag_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_blogs_param_blog_article_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticleParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_blogs_param_blog_article_with(self, blog_id, params)
+            .await
+    }
+
+    async fn deprecated_unstable_create_blogs_param_blog_articles(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_create_blogs_param_blog_articles(self, blog_id, body).await
+    }
+
+    async fn deprecated_unstable_get_blogs_param_blog_articles_count_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_count_with(
+            self, blog_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_blogs_param_blog_articles_article_with(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetBlogsParamBlogArticlesArticleParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_blogs_param_blog_articles_article_with(
+            self, blog_id, article_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_update_blogs_param_blog_articles_article(
+            self, blog_id, article_id, body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_delete_blogs_param_blog_articles_article(
+        &self,
+        blog_id: &str,
+        article_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_delete_blogs_param_blog_articles_article(
+            self, blog_id, article_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_articles_author(&self) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_articles_author(self).await
+    }
+
+    async fn deprecated_unstable_get_articles_tag_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetArticlesTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_articles_tag_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_themes_param_theme_asset_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202001GetThemesParamThemeAssetParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_themes_param_theme_asset_with(self, theme_id, params)
+            .await
+    }
+
+    async fn deprecated_202001_update_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202001UpdateThemesParamThemeAssetsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_themes_param_theme_assets_with(
+            self, theme_id, params, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_delete_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202001DeleteThemesParamThemeAssetsParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_delete_themes_param_theme_assets_with(self, theme_id, params)
+            .await
+    }
+
+    async fn deprecated_202004_get_themes_param_theme_asset_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202004GetThemesParamThemeAssetParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_themes_param_theme_asset_with(self, theme_id, params)
+            .await
+    }
+
+    async fn deprecated_202004_update_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202004UpdateThemesParamThemeAssetsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_update_themes_param_theme_assets_with(
+            self, theme_id, params, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_delete_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202004DeleteThemesParamThemeAssetsParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_delete_themes_param_theme_assets_with(self, theme_id, params)
+            .await
+    }
+
+    async fn deprecated_202007_get_themes_param_theme_asset_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202007GetThemesParamThemeAssetParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_themes_param_theme_asset_with(self, theme_id, params)
+            .await
+    }
+
+    async fn deprecated_202007_update_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202007UpdateThemesParamThemeAssetsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_update_themes_param_theme_assets_with(
+            self, theme_id, params, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_delete_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202007DeleteThemesParamThemeAssetsParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_delete_themes_param_theme_assets_with(self, theme_id, params)
+            .await
+    }
+
+    async fn get_themes_param_theme_asset_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreGetThemesParamThemeAssetParams,
+    ) -> Result<()> {
+        OnlineStore::get_themes_param_theme_asset_with(self, theme_id, params).await
+    }
+
+    async fn update_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreUpdateThemesParamThemeAssetsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::update_themes_param_theme_assets_with(self, theme_id, params, body).await
+    }
+
+    async fn delete_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeleteThemesParamThemeAssetsParams,
+    ) -> Result<()> {
+        OnlineStore::delete_themes_param_theme_assets_with(self, theme_id, params).await
+    }
+
+    async fn deprecated_202101_get_themes_param_theme_asset_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202101GetThemesParamThemeAssetParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_themes_param_theme_asset_with(self, theme_id, params)
+            .await
+    }
+
+    async fn deprecated_202101_update_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202101UpdateThemesParamThemeAssetsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_update_themes_param_theme_assets_with(
+            self, theme_id, params, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_delete_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202101DeleteThemesParamThemeAssetsParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_delete_themes_param_theme_assets_with(self, theme_id, params)
+            .await
+    }
+
+    async fn deprecated_unstable_get_themes_param_theme_asset_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetThemesParamThemeAssetParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_themes_param_theme_asset_with(self, theme_id, params)
+            .await
+    }
+
+    async fn deprecated_unstable_update_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecatedUnstableUpdateThemesParamThemeAssetsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_update_themes_param_theme_assets_with(
+            self, theme_id, params, body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_delete_themes_param_theme_assets_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecatedUnstableDeleteThemesParamThemeAssetsParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_delete_themes_param_theme_assets_with(
+            self, theme_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_blog_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetBlogParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_blog_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_blogs(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202001_create_blogs(self, body).await
+    }
+
+    async fn deprecated_202001_get_blogs_count(&self) -> Result<()> {
+        OnlineStore::deprecated_202001_get_blogs_count(self).await
+    }
+
+    async fn deprecated_202001_get_blogs_param_blog_with(
+        &self,
+        blog_id: &str,
+        params: &OnlineStoreDeprecated202001GetBlogsParamBlogParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_blogs_param_blog_with(self, blog_id, params).await
+    }
+
+    async fn deprecated_202001_update_blogs_param_blog(
+        &self,
+        blog_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_blogs_param_blog(self, blog_id, body).await
+    }
+
+    async fn deprecated_202001_delete_blogs_param_blog(&self, blog_id: &str) -> Result<()> {
+        OnlineStore::deprecated_202001_delete_blogs_param_blog(self, blog_id).await
+    }
+
+    async fn deprecated_202001_get_comment_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetCommentParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_comment_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_comments(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202001_create_comments(self, body).await
+    }
+
+    async fn deprecated_202001_get_comments_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetCommentsCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_comments_count_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_comments_param_comment_with(
+        &self,
+        comment_id: &str,
+        params: &OnlineStoreDeprecated202001GetCommentsParamCommentParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_comments_param_comment_with(self, comment_id, params)
+            .await
+    }
+
+    async fn deprecated_202001_update_comments_param_comment(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_comments_param_comment(self, comment_id, body).await
+    }
+
+    async fn deprecated_202001_create_comments_param_comment_spam(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_create_comments_param_comment_spam(self, comment_id, body)
+            .await
+    }
+
+    async fn deprecated_202001_create_comments_param_comment_not_spam(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_create_comments_param_comment_not_spam(
+            self, comment_id, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_create_comments_param_comment_approve(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_create_comments_param_comment_approve(self, comment_id, body)
+            .await
+    }
+
+    async fn deprecated_202001_create_comments_param_comment_remove(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_create_comments_param_comment_remove(self, comment_id, body)
+            .await
+    }
+
+    async fn deprecated_202001_create_comments_param_comment_restore(
+        &self,
+        comment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_create_comments_param_comment_restore(self, comment_id, body)
+            .await
+    }
+
+    async fn deprecated_202001_get_redirect_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetRedirectParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_redirect_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202001_create_redirects(self, body).await
+    }
+
+    async fn deprecated_202001_get_redirects_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetRedirectsCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_redirects_count_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_redirects_param_redirect_with(
+        &self,
+        redirect_id: &str,
+        params: &OnlineStoreDeprecated202001GetRedirectsParamRedirectParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_redirects_param_redirect_with(self, redirect_id, params)
+            .await
+    }
+
+    async fn deprecated_202001_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_redirects_param_redirect(self, redirect_id, body)
+            .await
+    }
+
+    async fn deprecated_202001_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_delete_redirects_param_redirect(self, redirect_id).await
+    }
+
+    async fn deprecated_202004_get_redirect_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetRedirectParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_redirect_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202004_create_redirects(self, body).await
+    }
+
+    async fn deprecated_202004_get_redirects_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetRedirectsCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_redirects_count_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_redirects_param_redirect_with(
+        &self,
+        redirect_id: &str,
+        params: &OnlineStoreDeprecated202004GetRedirectsParamRedirectParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_redirects_param_redirect_with(self, redirect_id, params)
+            .await
+    }
+
+    async fn deprecated_202004_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_update_redirects_param_redirect(self, redirect_id, body)
+            .await
+    }
+
+    async fn deprecated_202004_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_delete_redirects_param_redirect(self, redirect_id).await
+    }
+
+    async fn deprecated_202007_get_redirect_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetRedirectParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_redirect_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202007_create_redirects(self, body).await
+    }
+
+    async fn deprecated_202007_get_redirects_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetRedirectsCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_redirects_count_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_redirects_param_redirect_with(
+        &self,
+        redirect_id: &str,
+        params: &OnlineStoreDeprecated202007GetRedirectsParamRedirectParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_redirects_param_redirect_with(self, redirect_id, params)
+            .await
+    }
+
+    async fn deprecated_202007_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_update_redirects_param_redirect(self, redirect_id, body)
+            .await
+    }
+
+    async fn deprecated_202007_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_delete_redirects_param_redirect(self, redirect_id).await
+    }
+
+    async fn get_redirect_with(&self, params: &OnlineStoreGetRedirectParams) -> Result<()> {
+        OnlineStore::get_redirect_with(self, params).await
+    }
+
+    async fn create_redirects(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::create_redirects(self, body).await
+    }
+
+    async fn get_redirects_count_with(
+        &self,
+        params: &OnlineStoreGetRedirectsCountParams,
+    ) -> Result<()> {
+        OnlineStore::get_redirects_count_with(self, params).await
+    }
+
+    async fn get_redirects_param_redirect_with(
+        &self,
+        redirect_id: &str,
+        params: &OnlineStoreGetRedirectsParamRedirectParams,
+    ) -> Result<()> {
+        OnlineStore::get_redirects_param_redirect_with(self, redirect_id, params).await
+    }
+
+    async fn update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::update_redirects_param_redirect(self, redirect_id, body).await
+    }
+
+    async fn delete_redirects_param_redirect(&self, redirect_id: &str) -> Result<()> {
+        OnlineStore::delete_redirects_param_redirect(self, redirect_id).await
+    }
+
+    async fn deprecated_202101_get_redirect_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetRedirectParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_redirect_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202101_create_redirects(self, body).await
+    }
+
+    async fn deprecated_202101_get_redirects_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetRedirectsCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_redirects_count_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_redirects_param_redirect_with(
+        &self,
+        redirect_id: &str,
+        params: &OnlineStoreDeprecated202101GetRedirectsParamRedirectParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_redirects_param_redirect_with(self, redirect_id, params)
+            .await
+    }
+
+    async fn deprecated_202101_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_update_redirects_param_redirect(self, redirect_id, body)
+            .await
+    }
+
+    async fn deprecated_202101_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_delete_redirects_param_redirect(self, redirect_id).await
+    }
+
+    async fn deprecated_unstable_get_redirect_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetRedirectParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_redirect_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_redirects(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_unstable_create_redirects(self, body).await
+    }
+
+    async fn deprecated_unstable_get_redirects_count_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetRedirectsCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_redirects_count_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_redirects_param_redirect_with(
+        &self,
+        redirect_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetRedirectsParamRedirectParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_redirects_param_redirect_with(
+            self,
+            redirect_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_update_redirects_param_redirect(self, redirect_id, body)
+            .await
+    }
+
+    async fn deprecated_unstable_delete_redirects_param_redirect(
+        &self,
+        redirect_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_delete_redirects_param_redirect(self, redirect_id).await
+    }
+
+    async fn deprecated_202001_get_script_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetScriptTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_script_tag_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_script_tags(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202001_create_script_tags(self, body).await
+    }
+
+    async fn deprecated_202001_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetScriptTagsCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_script_tags_count_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_script_tags_param_tag_with(
+        &self,
+        script_tag_id: &str,
+        params: &OnlineStoreDeprecated202001GetScriptTagsParamTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_script_tags_param_tag_with(self, script_tag_id, params)
+            .await
+    }
+
+    async fn deprecated_202001_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_script_tags_param_tag(self, script_tag_id, body).await
+    }
+
+    async fn deprecated_202001_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_delete_script_tags_param_tag(self, script_tag_id).await
+    }
+
+    async fn deprecated_202004_get_script_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetScriptTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_script_tag_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_script_tags(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202004_create_script_tags(self, body).await
+    }
+
+    async fn deprecated_202004_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetScriptTagsCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_script_tags_count_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_script_tags_param_tag_with(
+        &self,
+        script_tag_id: &str,
+        params: &OnlineStoreDeprecated202004GetScriptTagsParamTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_script_tags_param_tag_with(self, script_tag_id, params)
+            .await
+    }
+
+    async fn deprecated_202004_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_update_script_tags_param_tag(self, script_tag_id, body).await
+    }
+
+    async fn deprecated_202004_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_delete_script_tags_param_tag(self, script_tag_id).await
+    }
+
+    async fn deprecated_202007_get_script_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetScriptTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_script_tag_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_script_tags(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202007_create_script_tags(self, body).await
+    }
+
+    async fn deprecated_202007_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetScriptTagsCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_script_tags_count_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_script_tags_param_tag_with(
+        &self,
+        script_tag_id: &str,
+        params: &OnlineStoreDeprecated202007GetScriptTagsParamTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_script_tags_param_tag_with(self, script_tag_id, params)
+            .await
+    }
+
+    async fn deprecated_202007_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_update_script_tags_param_tag(self, script_tag_id, body).await
+    }
+
+    async fn deprecated_202007_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_delete_script_tags_param_tag(self, script_tag_id).await
+    }
+
+    async fn get_script_tag_with(&self, params: &OnlineStoreGetScriptTagParams) -> Result<()> {
+        OnlineStore::get_script_tag_with(self, params).await
+    }
+
+    async fn create_script_tags(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::create_script_tags(self, body).await
+    }
+
+    async fn get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreGetScriptTagsCountParams,
+    ) -> Result<()> {
+        OnlineStore::get_script_tags_count_with(self, params).await
+    }
+
+    async fn get_script_tags_param_tag_with(
+        &self,
+        script_tag_id: &str,
+        params: &OnlineStoreGetScriptTagsParamTagParams,
+    ) -> Result<()> {
+        OnlineStore::get_script_tags_param_tag_with(self, script_tag_id, params).await
+    }
+
+    async fn update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::update_script_tags_param_tag(self, script_tag_id, body).await
+    }
+
+    async fn delete_script_tags_param_tag(&self, script_tag_id: &str) -> Result<()> {
+        OnlineStore::delete_script_tags_param_tag(self, script_tag_id).await
+    }
+
+    async fn deprecated_202101_get_script_tag_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetScriptTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_script_tag_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_script_tags(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202101_create_script_tags(self, body).await
+    }
+
+    async fn deprecated_202101_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetScriptTagsCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_script_tags_count_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_script_tags_param_tag_with(
+        &self,
+        script_tag_id: &str,
+        params: &OnlineStoreDeprecated202101GetScriptTagsParamTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_script_tags_param_tag_with(self, script_tag_id, params)
+            .await
+    }
+
+    async fn deprecated_202101_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_update_script_tags_param_tag(self, script_tag_id, body).await
+    }
+
+    async fn deprecated_202101_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_delete_script_tags_param_tag(self, script_tag_id).await
+    }
+
+    async fn deprecated_unstable_get_script_tag_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetScriptTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_script_tag_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_script_tags(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_unstable_create_script_tags(self, body).await
+    }
+
+    async fn deprecated_unstable_get_script_tags_count_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetScriptTagsCountParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_script_tags_count_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_script_tags_param_tag_with(
+        &self,
+        script_tag_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetScriptTagsParamTagParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_script_tags_param_tag_with(self, script_tag_id, params)
+            .await
+    }
+
+    async fn deprecated_unstable_update_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_update_script_tags_param_tag(self, script_tag_id, body)
+            .await
+    }
+
+    async fn deprecated_unstable_delete_script_tags_param_tag(
+        &self,
+        script_tag_id: &str,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_delete_script_tags_param_tag(self, script_tag_id).await
+    }
+
+    async fn deprecated_202001_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecated202001GetThemeParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_theme_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_themes(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202001_create_themes(self, body).await
+    }
+
+    async fn deprecated_202001_get_themes_param_theme_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202001GetThemesParamThemeParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_get_themes_param_theme_with(self, theme_id, params).await
+    }
+
+    async fn deprecated_202001_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202001_update_themes_param_theme(self, theme_id, body).await
+    }
+
+    async fn deprecated_202001_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+        OnlineStore::deprecated_202001_delete_themes_param_theme(self, theme_id).await
+    }
+
+    async fn deprecated_202004_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecated202004GetThemeParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_theme_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_themes(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202004_create_themes(self, body).await
+    }
+
+    async fn deprecated_202004_get_themes_param_theme_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202004GetThemesParamThemeParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_get_themes_param_theme_with(self, theme_id, params).await
+    }
+
+    async fn deprecated_202004_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202004_update_themes_param_theme(self, theme_id, body).await
+    }
+
+    async fn deprecated_202004_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+        OnlineStore::deprecated_202004_delete_themes_param_theme(self, theme_id).await
+    }
+
+    async fn deprecated_202007_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecated202007GetThemeParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_theme_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_themes(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202007_create_themes(self, body).await
+    }
+
+    async fn deprecated_202007_get_themes_param_theme_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202007GetThemesParamThemeParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_get_themes_param_theme_with(self, theme_id, params).await
+    }
+
+    async fn deprecated_202007_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202007_update_themes_param_theme(self, theme_id, body).await
+    }
+
+    async fn deprecated_202007_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+        OnlineStore::deprecated_202007_delete_themes_param_theme(self, theme_id).await
+    }
+
+    async fn get_theme_with(&self, params: &OnlineStoreGetThemeParams) -> Result<()> {
+        OnlineStore::get_theme_with(self, params).await
+    }
+
+    async fn create_themes(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::create_themes(self, body).await
+    }
+
+    async fn get_themes_param_theme_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreGetThemesParamThemeParams,
+    ) -> Result<()> {
+        OnlineStore::get_themes_param_theme_with(self, theme_id, params).await
+    }
+
+    async fn update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::update_themes_param_theme(self, theme_id, body).await
+    }
+
+    async fn delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+        OnlineStore::delete_themes_param_theme(self, theme_id).await
+    }
+
+    async fn deprecated_202101_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecated202101GetThemeParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_theme_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_themes(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_202101_create_themes(self, body).await
+    }
+
+    async fn deprecated_202101_get_themes_param_theme_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecated202101GetThemesParamThemeParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_get_themes_param_theme_with(self, theme_id, params).await
+    }
+
+    async fn deprecated_202101_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_202101_update_themes_param_theme(self, theme_id, body).await
+    }
+
+    async fn deprecated_202101_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+        OnlineStore::deprecated_202101_delete_themes_param_theme(self, theme_id).await
+    }
+
+    async fn deprecated_unstable_get_theme_with(
+        &self,
+        params: &OnlineStoreDeprecatedUnstableGetThemeParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_theme_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_themes(&self, body: &serde_json::Value) -> Result<()> {
+        OnlineStore::deprecated_unstable_create_themes(self, body).await
+    }
+
+    async fn deprecated_unstable_get_themes_param_theme_with(
+        &self,
+        theme_id: &str,
+        params: &OnlineStoreDeprecatedUnstableGetThemesParamThemeParams,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_get_themes_param_theme_with(self, theme_id, params).await
+    }
+
+    async fn deprecated_unstable_update_themes_param_theme(
+        &self,
+        theme_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        OnlineStore::deprecated_unstable_update_themes_param_theme(self, theme_id, body).await
+    }
+
+    async fn deprecated_unstable_delete_themes_param_theme(&self, theme_id: &str) -> Result<()> {
+        OnlineStore::deprecated_unstable_delete_themes_param_theme(self, theme_id).await
+    }
+}
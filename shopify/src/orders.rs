@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Orders {
     pub client: Client,
@@ -21,43 +19,29 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Count checkouts created after the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count checkouts created before the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count checkouts last updated after the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count checkouts last updated before the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `status: &str` -- Count checkouts with a given status.
-     *                     (default: open)
-     *                       
-     *                           open: Count only open abandoned checkouts.
-     *                           closed: Count only closed abandoned checkouts.
+     * * `params: &OrdersDeprecated202001GetCheckoutsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_checkouts_count(
+    pub async fn deprecated_202001_get_checkouts_count_with(
         &self,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        status: &str,
+        params: &OrdersDeprecated202001GetCheckoutsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -66,6 +50,31 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_checkouts_count_with` and pass the query parameters as a `OrdersDeprecated202001GetCheckoutsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_checkouts_count_with` instead")]
+    pub async fn deprecated_202001_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202001GetCheckoutsCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_checkouts_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a count of checkouts from the past 90 days.
      *
@@ -75,43 +84,29 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Count checkouts created after the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count checkouts created before the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count checkouts last updated after the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count checkouts last updated before the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `status: &str` -- Count checkouts with a given status.
-     *                     (default: open)
-     *                       
-     *                           open: Count only open abandoned checkouts.
-     *                           closed: Count only closed abandoned checkouts.
+     * * `params: &OrdersDeprecated202004GetCheckoutsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_checkouts_count(
+    pub async fn deprecated_202004_get_checkouts_count_with(
         &self,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        status: &str,
+        params: &OrdersDeprecated202004GetCheckoutsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -120,6 +115,31 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_checkouts_count_with` and pass the query parameters as a `OrdersDeprecated202004GetCheckoutsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_checkouts_count_with` instead")]
+    pub async fn deprecated_202004_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202004GetCheckoutsCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_checkouts_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a count of checkouts from the past 90 days.
      *
@@ -129,43 +149,29 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Count checkouts created after the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count checkouts created before the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count checkouts last updated after the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count checkouts last updated before the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `status: &str` -- Count checkouts with a given status.
-     *                     (default: open)
-     *                       
-     *                           open: Count only open abandoned checkouts.
-     *                           closed: Count only closed abandoned checkouts.
+     * * `params: &OrdersDeprecated202007GetCheckoutsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_checkouts_count(
+    pub async fn deprecated_202007_get_checkouts_count_with(
         &self,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        status: &str,
+        params: &OrdersDeprecated202007GetCheckoutsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -174,6 +180,31 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_checkouts_count_with` and pass the query parameters as a `OrdersDeprecated202007GetCheckoutsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_checkouts_count_with` instead")]
+    pub async fn deprecated_202007_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202007GetCheckoutsCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_checkouts_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a count of checkouts from the past 90 days.
      *
@@ -183,43 +214,29 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Count checkouts created after the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count checkouts created before the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count checkouts last updated after the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count checkouts last updated before the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `status: &str` -- Count checkouts with a given status.
-     *                     (default: open)
-     *                       
-     *                           open: Count only open abandoned checkouts.
-     *                           closed: Count only closed abandoned checkouts.
+     * * `params: &OrdersGetCheckoutsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_checkouts_count(
+    pub async fn get_checkouts_count_with(
         &self,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        status: &str,
+        params: &OrdersGetCheckoutsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -228,6 +245,30 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_checkouts_count_with` and pass the query parameters as a `OrdersGetCheckoutsCountParams` instead.
+     */
+    #[deprecated(note = "use `get_checkouts_count_with` instead")]
+    pub async fn get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()> {
+        let params = OrdersGetCheckoutsCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_checkouts_count_with(&params).await
+    }
+
     /**
      * Retrieves a count of checkouts from the past 90 days.
      *
@@ -237,43 +278,29 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Count checkouts created after the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count checkouts created before the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count checkouts last updated after the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count checkouts last updated before the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `status: &str` -- Count checkouts with a given status.
-     *                     (default: open)
-     *                       
-     *                           open: Count only open abandoned checkouts.
-     *                           closed: Count only closed abandoned checkouts.
+     * * `params: &OrdersDeprecated202101GetCheckoutsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_checkouts_count(
+    pub async fn deprecated_202101_get_checkouts_count_with(
         &self,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        status: &str,
+        params: &OrdersDeprecated202101GetCheckoutsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -282,6 +309,31 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_checkouts_count_with` and pass the query parameters as a `OrdersDeprecated202101GetCheckoutsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_checkouts_count_with` instead")]
+    pub async fn deprecated_202101_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202101GetCheckoutsCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_checkouts_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a count of checkouts from the past 90 days.
      *
@@ -291,43 +343,29 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `created_at_min: &str` -- Count checkouts created after the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count checkouts created before the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count checkouts last updated after the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count checkouts last updated before the specified date. (format: 2014-04-25T16:15:47-04:00).
-     * * `status: &str` -- Count checkouts with a given status.
-     *                     (default: open)
-     *                       
-     *                           open: Count only open abandoned checkouts.
-     *                           closed: Count only closed abandoned checkouts.
+     * * `params: &OrdersDeprecatedUnstableGetCheckoutsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_checkouts_count(
+    pub async fn deprecated_unstable_get_checkouts_count_with(
         &self,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        status: &str,
+        params: &OrdersDeprecatedUnstableGetCheckoutsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -336,6 +374,31 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_checkouts_count_with` and pass the query parameters as a `OrdersDeprecatedUnstableGetCheckoutsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_checkouts_count_with` instead")]
+    pub async fn deprecated_unstable_get_checkouts_count(
+        &self,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecatedUnstableGetCheckoutsCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_checkouts_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of orders. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -345,110 +408,59 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Retrieve only orders specified by a comma-separated list of order IDs.
-     * * `limit: &str` -- The maximum number of results to show on a page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show orders after the specified ID.
-     * * `created_at_min: &str` -- Show orders created at or after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show orders created at or before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show orders last updated at or after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show orders last updated at or before date (format: 2014-04-25T16:15:47-04:00).
-     * * `processed_at_min: &str` -- Show orders imported at or after date (format: 2014-04-25T16:15:47-04:00).
-     * * `processed_at_max: &str` -- Show orders imported at or before date (format: 2014-04-25T16:15:47-04:00).
-     * * `attribution_app_id: &str` -- Show orders attributed to a certain app, specified by the app ID. Set as current to show orders for the app currently consuming the API.
-     * * `status: &str` -- Filter orders by their status.
-     *                     (default: open)
-     *                       
-     *                           open: Show only open orders.
-     *                           closed: Show only closed orders.
-     *                           cancelled: Show only canceled orders.
-     *                           any: Show orders of any status, including archived orders.
-     * * `financial_status: &str` -- Filter orders by their financial status.
-     *                     (default: any)
-     *                       
-     *                           authorized: Show only authorized orders
-     *                           pending: Show only pending orders
-     *                           paid: Show only paid orders
-     *                           partially_paid: Show only partially paid orders
-     *                           refunded: Show only refunded orders
-     *                           voided: Show only voided orders
-     *                           partially_refunded: Show only partially refunded orders
-     *                           any: Show orders of any financial status.
-     *                           unpaid: Show authorized and partially paid orders.
-     * * `fulfillment_status: &str` -- Filter orders by their fulfillment status.
-     *                     (default: any)
-     *                       
-     *                           shipped: Show orders that have been shipped. Returns orders with fulfillment_status of fulfilled.
-     *                           partial: Show partially shipped orders.
-     *                           unshipped: Show orders that have not yet been shipped. Returns orders with fulfillment_status of null.
-     *                           any: Show orders of any fulfillment status.
-     *                           unfulfilled: Returns orders with fulfillment_status of null or partial.
-     * * `fields: &str` -- Retrieve only certain fields, specified by a comma-separated list of fields names.
+     * * `params: &OrdersDeprecated202001GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get(
+    pub async fn deprecated_202001_get_with(
         &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        processed_at_min: &str,
-        processed_at_max: &str,
-        attribution_app_id: &str,
-        status: &str,
-        financial_status: &str,
-        fulfillment_status: &str,
-        fields: &str,
+        params: &OrdersDeprecated202001GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !attribution_app_id.is_empty() {
+        if let Some(attribution_app_id) = &params.attribution_app_id {
             query_args.push((
                 "attribution_app_id".to_string(),
                 attribution_app_id.to_string(),
             ));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !financial_status.is_empty() {
+        if let Some(financial_status) = &params.financial_status {
             query_args.push(("financial_status".to_string(), financial_status.to_string()));
         }
-        if !fulfillment_status.is_empty() {
+        if let Some(fulfillment_status) = &params.fulfillment_status {
             query_args.push((
                 "fulfillment_status".to_string(),
                 fulfillment_status.to_string(),
             ));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !processed_at_max.is_empty() {
+        if let Some(processed_at_max) = &params.processed_at_max {
             query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
         }
-        if !processed_at_min.is_empty() {
+        if let Some(processed_at_min) = &params.processed_at_min {
             query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -457,6 +469,46 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_with` and pass the query parameters as a `OrdersDeprecated202001GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_with` instead")]
+    pub async fn deprecated_202001_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        attribution_app_id: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202001GetParams {
+            attribution_app_id: Some(attribution_app_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            financial_status: Some(financial_status.to_string()).filter(|s| !s.is_empty()),
+            fulfillment_status: Some(fulfillment_status.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            processed_at_max: Some(processed_at_max.to_string()).filter(|s| !s.is_empty()),
+            processed_at_min: Some(processed_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_with(&params).await
+    }
+
     /**
     * Creates an order. By default, product inventory is not claimed.
               When you create an order, you can include the following option parameters in the body of the request:
@@ -504,11 +556,15 @@ impl Orders {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Retrieve only certain fields, specified by a comma-separated list of fields names.
+     * * `params: &OrdersDeprecated202001GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_param(&self, order_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_param_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202001GetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -521,6 +577,18 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_param_with` and pass the query parameters as a `OrdersDeprecated202001GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_param_with` instead")]
+    pub async fn deprecated_202001_get_param(&self, order_id: &str, fields: &str) -> Result<()> {
+        let params = OrdersDeprecated202001GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_param_with(order_id, &params)
+            .await
+    }
+
     /**
      * Updates an order.
      *
@@ -576,67 +644,35 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `created_at_min: &str` -- Count orders created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count orders created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count orders last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count orders last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `status: &str` -- Count orders of a given status.
-     *                     (default: open)
-     *                       
-     *                           open: Count open orders.
-     *                           closed: Count closed orders.
-     *                           any: Count orders of any status.
-     * * `financial_status: &str` -- Count orders of a given financial status.
-     *                     (default: any)
-     *                       
-     *                           authorized: Count authorized orders.
-     *                           pending: Count pending orders.
-     *                           paid: Count paid orders.
-     *                           refunded: Count refunded orders.
-     *                           voided: Count voided orders.
-     *                           any: Count orders of any financial status.
-     * * `fulfillment_status: &str` -- Filter orders by their fulfillment status.
-     *                     (default: any)
-     *                       
-     *                           shipped: Show orders that have been shipped. Returns orders with fulfillment_status of fulfilled.
-     *                           partial: Show partially shipped orders.
-     *                           unshipped: Show orders that have not yet been shipped. Returns orders with fulfillment_status of null.
-     *                           any: Show orders of any fulfillment status.
-     *                           unfulfilled: Returns orders with fulfillment_status of null or partial.
+     * * `params: &OrdersDeprecated202001GetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_count(
+    pub async fn deprecated_202001_get_count_with(
         &self,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        status: &str,
-        financial_status: &str,
-        fulfillment_status: &str,
+        params: &OrdersDeprecated202001GetCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !financial_status.is_empty() {
+        if let Some(financial_status) = &params.financial_status {
             query_args.push(("financial_status".to_string(), financial_status.to_string()));
         }
-        if !fulfillment_status.is_empty() {
+        if let Some(fulfillment_status) = &params.fulfillment_status {
             query_args.push((
                 "fulfillment_status".to_string(),
                 fulfillment_status.to_string(),
             ));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -645,6 +681,32 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_count_with` and pass the query parameters as a `OrdersDeprecated202001GetCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_count_with` instead")]
+    pub async fn deprecated_202001_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202001GetCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            financial_status: Some(financial_status.to_string()).filter(|s| !s.is_empty()),
+            fulfillment_status: Some(fulfillment_status.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_count_with(&params).await
+    }
+
     /**
      * Closes an order.
      *
@@ -710,44 +772,31 @@ impl Orders {
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `amount: &str` -- The amount to refund. If set, Shopify attempts to void or refund the payment, depending on its status. Shopify refunds through a manual gateway in cases where the original transaction was not made in Shopify. Refunds through a manual gateway are recorded as a refund on Shopify, but the customer is not refunded.
-    * * `currency: &str` -- The currency of the refund that's issued when the order is canceled. Required for multi-currency orders whenever the amount property is provided.
-    * * `restock_deprecated: &str` -- Whether to restock refunded items back to your store's inventory.
-    *                     (default: false).
-    * * `reason: &str` -- The reason for the order cancellation. Valid values: customer, inventory, fraud, declined, and other.)
-    *                     (default: other).
-    * * `email: &str` -- Whether to send an email to the customer notifying them of the cancellation.
-    *                     (default: false).
-    * * `refund: &str` -- The refund transactions to perform. Required for some more complex refund situations. For more information, see the Refund API.
+    * * `params: &OrdersDeprecated202001CreateParamCancelParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_create_param_cancel(
+    pub async fn deprecated_202001_create_param_cancel_with(
         &self,
         order_id: &str,
-        amount: &str,
-        currency: &str,
-        restock_deprecated: &str,
-        reason: &str,
-        email: &str,
-        refund: &str,
+        params: &OrdersDeprecated202001CreateParamCancelParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount.is_empty() {
+        if let Some(amount) = &params.amount {
             query_args.push(("amount".to_string(), amount.to_string()));
         }
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !email.is_empty() {
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
-        if !reason.is_empty() {
+        if let Some(reason) = &params.reason {
             query_args.push(("reason".to_string(), reason.to_string()));
         }
-        if !refund.is_empty() {
+        if let Some(refund) = &params.refund {
             query_args.push(("refund".to_string(), refund.to_string()));
         }
-        if !restock_deprecated.is_empty() {
+        if let Some(restock_deprecated) = &params.restock_deprecated {
             query_args.push((
                 "restock
                   deprecated"
@@ -767,6 +816,33 @@ impl Orders {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_param_cancel_with` and pass the query parameters as a `OrdersDeprecated202001CreateParamCancelParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_create_param_cancel_with` instead")]
+    pub async fn deprecated_202001_create_param_cancel(
+        &self,
+        order_id: &str,
+        amount: &str,
+        currency: &str,
+        restock_deprecated: &str,
+        reason: &str,
+        email: &str,
+        refund: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202001CreateParamCancelParams {
+            amount: Some(amount.to_string()).filter(|s| !s.is_empty()),
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+            reason: Some(reason.to_string()).filter(|s| !s.is_empty()),
+            refund: Some(refund.to_string()).filter(|s| !s.is_empty()),
+            restock_deprecated: Some(restock_deprecated.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_create_param_cancel_with(order_id, &params, body)
+            .await
+    }
+
     /**
      * Retrieves a list of orders. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -776,110 +852,59 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Retrieve only orders specified by a comma-separated list of order IDs.
-     * * `limit: &str` -- The maximum number of results to show on a page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show orders after the specified ID.
-     * * `created_at_min: &str` -- Show orders created at or after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show orders created at or before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show orders last updated at or after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show orders last updated at or before date (format: 2014-04-25T16:15:47-04:00).
-     * * `processed_at_min: &str` -- Show orders imported at or after date (format: 2014-04-25T16:15:47-04:00).
-     * * `processed_at_max: &str` -- Show orders imported at or before date (format: 2014-04-25T16:15:47-04:00).
-     * * `attribution_app_id: &str` -- Show orders attributed to a certain app, specified by the app ID. Set as current to show orders for the app currently consuming the API.
-     * * `status: &str` -- Filter orders by their status.
-     *                     (default: open)
-     *                       
-     *                           open: Show only open orders.
-     *                           closed: Show only closed orders.
-     *                           cancelled: Show only canceled orders.
-     *                           any: Show orders of any status, including archived orders.
-     * * `financial_status: &str` -- Filter orders by their financial status.
-     *                     (default: any)
-     *                       
-     *                           authorized: Show only authorized orders
-     *                           pending: Show only pending orders
-     *                           paid: Show only paid orders
-     *                           partially_paid: Show only partially paid orders
-     *                           refunded: Show only refunded orders
-     *                           voided: Show only voided orders
-     *                           partially_refunded: Show only partially refunded orders
-     *                           any: Show orders of any financial status.
-     *                           unpaid: Show authorized and partially paid orders.
-     * * `fulfillment_status: &str` -- Filter orders by their fulfillment status.
-     *                     (default: any)
-     *                       
-     *                           shipped: Show orders that have been shipped. Returns orders with fulfillment_status of fulfilled.
-     *                           partial: Show partially shipped orders.
-     *                           unshipped: Show orders that have not yet been shipped. Returns orders with fulfillment_status of null.
-     *                           any: Show orders of any fulfillment status.
-     *                           unfulfilled: Returns orders with fulfillment_status of null or partial.
-     * * `fields: &str` -- Retrieve only certain fields, specified by a comma-separated list of fields names.
+     * * `params: &OrdersDeprecated202004GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get(
+    pub async fn deprecated_202004_get_with(
         &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        processed_at_min: &str,
-        processed_at_max: &str,
-        attribution_app_id: &str,
-        status: &str,
-        financial_status: &str,
-        fulfillment_status: &str,
-        fields: &str,
+        params: &OrdersDeprecated202004GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !attribution_app_id.is_empty() {
+        if let Some(attribution_app_id) = &params.attribution_app_id {
             query_args.push((
                 "attribution_app_id".to_string(),
                 attribution_app_id.to_string(),
             ));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !financial_status.is_empty() {
+        if let Some(financial_status) = &params.financial_status {
             query_args.push(("financial_status".to_string(), financial_status.to_string()));
         }
-        if !fulfillment_status.is_empty() {
+        if let Some(fulfillment_status) = &params.fulfillment_status {
             query_args.push((
                 "fulfillment_status".to_string(),
                 fulfillment_status.to_string(),
             ));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !processed_at_max.is_empty() {
+        if let Some(processed_at_max) = &params.processed_at_max {
             query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
         }
-        if !processed_at_min.is_empty() {
+        if let Some(processed_at_min) = &params.processed_at_min {
             query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -888,6 +913,46 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_with` and pass the query parameters as a `OrdersDeprecated202004GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_with` instead")]
+    pub async fn deprecated_202004_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        attribution_app_id: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202004GetParams {
+            attribution_app_id: Some(attribution_app_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            financial_status: Some(financial_status.to_string()).filter(|s| !s.is_empty()),
+            fulfillment_status: Some(fulfillment_status.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            processed_at_max: Some(processed_at_max.to_string()).filter(|s| !s.is_empty()),
+            processed_at_min: Some(processed_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_with(&params).await
+    }
+
     /**
     * Creates an order. By default, product inventory is not claimed.
               When you create an order, you can include the following option parameters in the body of the request:
@@ -935,12 +1000,16 @@ impl Orders {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Retrieve only certain fields, specified by a comma-separated list of fields names.
+     * * `params: &OrdersDeprecated202004GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_param(&self, order_id: &str, fields: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
-            query_args.push(("fields".to_string(), fields.to_string()));
+    pub async fn deprecated_202004_get_param_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202004GetParamParams,
+    ) -> Result<()> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(fields) = &params.fields {
+            query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
@@ -952,6 +1021,18 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_param_with` and pass the query parameters as a `OrdersDeprecated202004GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_param_with` instead")]
+    pub async fn deprecated_202004_get_param(&self, order_id: &str, fields: &str) -> Result<()> {
+        let params = OrdersDeprecated202004GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_param_with(order_id, &params)
+            .await
+    }
+
     /**
      * Updates an order.
      *
@@ -1007,67 +1088,35 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `created_at_min: &str` -- Count orders created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count orders created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count orders last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count orders last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `status: &str` -- Count orders of a given status.
-     *                     (default: open)
-     *                       
-     *                           open: Count open orders.
-     *                           closed: Count closed orders.
-     *                           any: Count orders of any status.
-     * * `financial_status: &str` -- Count orders of a given financial status.
-     *                     (default: any)
-     *                       
-     *                           authorized: Count authorized orders.
-     *                           pending: Count pending orders.
-     *                           paid: Count paid orders.
-     *                           refunded: Count refunded orders.
-     *                           voided: Count voided orders.
-     *                           any: Count orders of any financial status.
-     * * `fulfillment_status: &str` -- Filter orders by their fulfillment status.
-     *                     (default: any)
-     *                       
-     *                           shipped: Show orders that have been shipped. Returns orders with fulfillment_status of fulfilled.
-     *                           partial: Show partially shipped orders.
-     *                           unshipped: Show orders that have not yet been shipped. Returns orders with fulfillment_status of null.
-     *                           any: Show orders of any fulfillment status.
-     *                           unfulfilled: Returns orders with fulfillment_status of null or partial.
+     * * `params: &OrdersDeprecated202004GetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_count(
+    pub async fn deprecated_202004_get_count_with(
         &self,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        status: &str,
-        financial_status: &str,
-        fulfillment_status: &str,
+        params: &OrdersDeprecated202004GetCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !financial_status.is_empty() {
+        if let Some(financial_status) = &params.financial_status {
             query_args.push(("financial_status".to_string(), financial_status.to_string()));
         }
-        if !fulfillment_status.is_empty() {
+        if let Some(fulfillment_status) = &params.fulfillment_status {
             query_args.push((
                 "fulfillment_status".to_string(),
                 fulfillment_status.to_string(),
             ));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1076,6 +1125,32 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_count_with` and pass the query parameters as a `OrdersDeprecated202004GetCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_count_with` instead")]
+    pub async fn deprecated_202004_get_count(
+        &self,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202004GetCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            financial_status: Some(financial_status.to_string()).filter(|s| !s.is_empty()),
+            fulfillment_status: Some(fulfillment_status.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_count_with(&params).await
+    }
+
     /**
      * Closes an order.
      *
@@ -1141,44 +1216,31 @@ impl Orders {
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `amount: &str` -- The amount to refund. If set, Shopify attempts to void or refund the payment, depending on its status. Shopify refunds through a manual gateway in cases where the original transaction was not made in Shopify. Refunds through a manual gateway are recorded as a refund on Shopify, but the customer is not refunded.
-    * * `currency: &str` -- The currency of the refund that's issued when the order is canceled. Required for multi-currency orders whenever the amount property is provided.
-    * * `restock_deprecated: &str` -- Whether to restock refunded items back to your store's inventory.
-    *                     (default: false).
-    * * `reason: &str` -- The reason for the order cancellation. Valid values: customer, inventory, fraud, declined, and other.)
-    *                     (default: other).
-    * * `email: &str` -- Whether to send an email to the customer notifying them of the cancellation.
-    *                     (default: false).
-    * * `refund: &str` -- The refund transactions to perform. Required for some more complex refund situations. For more information, see the Refund API.
+    * * `params: &OrdersDeprecated202004CreateParamCancelParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_create_param_cancel(
+    pub async fn deprecated_202004_create_param_cancel_with(
         &self,
         order_id: &str,
-        amount: &str,
-        currency: &str,
-        restock_deprecated: &str,
-        reason: &str,
-        email: &str,
-        refund: &str,
+        params: &OrdersDeprecated202004CreateParamCancelParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount.is_empty() {
+        if let Some(amount) = &params.amount {
             query_args.push(("amount".to_string(), amount.to_string()));
         }
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !email.is_empty() {
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
-        if !reason.is_empty() {
+        if let Some(reason) = &params.reason {
             query_args.push(("reason".to_string(), reason.to_string()));
         }
-        if !refund.is_empty() {
+        if let Some(refund) = &params.refund {
             query_args.push(("refund".to_string(), refund.to_string()));
         }
-        if !restock_deprecated.is_empty() {
+        if let Some(restock_deprecated) = &params.restock_deprecated {
             query_args.push((
                 "restock
                   deprecated"
@@ -1198,6 +1260,33 @@ impl Orders {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_param_cancel_with` and pass the query parameters as a `OrdersDeprecated202004CreateParamCancelParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_create_param_cancel_with` instead")]
+    pub async fn deprecated_202004_create_param_cancel(
+        &self,
+        order_id: &str,
+        amount: &str,
+        currency: &str,
+        restock_deprecated: &str,
+        reason: &str,
+        email: &str,
+        refund: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202004CreateParamCancelParams {
+            amount: Some(amount.to_string()).filter(|s| !s.is_empty()),
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+            reason: Some(reason.to_string()).filter(|s| !s.is_empty()),
+            refund: Some(refund.to_string()).filter(|s| !s.is_empty()),
+            restock_deprecated: Some(restock_deprecated.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_create_param_cancel_with(order_id, &params, body)
+            .await
+    }
+
     /**
      * Retrieves a list of orders. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1207,110 +1296,59 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Retrieve only orders specified by a comma-separated list of order IDs.
-     * * `limit: &str` -- The maximum number of results to show on a page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Show orders after the specified ID.
-     * * `created_at_min: &str` -- Show orders created at or after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show orders created at or before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show orders last updated at or after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show orders last updated at or before date (format: 2014-04-25T16:15:47-04:00).
-     * * `processed_at_min: &str` -- Show orders imported at or after date (format: 2014-04-25T16:15:47-04:00).
-     * * `processed_at_max: &str` -- Show orders imported at or before date (format: 2014-04-25T16:15:47-04:00).
-     * * `attribution_app_id: &str` -- Show orders attributed to a certain app, specified by the app ID. Set as current to show orders for the app currently consuming the API.
-     * * `status: &str` -- Filter orders by their status.
-     *                     (default: open)
-     *                       
-     *                           open: Show only open orders.
-     *                           closed: Show only closed orders.
-     *                           cancelled: Show only canceled orders.
-     *                           any: Show orders of any status, including archived orders.
-     * * `financial_status: &str` -- Filter orders by their financial status.
-     *                     (default: any)
-     *                       
-     *                           authorized: Show only authorized orders
-     *                           pending: Show only pending orders
-     *                           paid: Show only paid orders
-     *                           partially_paid: Show only partially paid orders
-     *                           refunded: Show only refunded orders
-     *                           voided: Show only voided orders
-     *                           partially_refunded: Show only partially refunded orders
-     *                           any: Show orders of any financial status.
-     *                           unpaid: Show authorized and partially paid orders.
-     * * `fulfillment_status: &str` -- Filter orders by their fulfillment status.
-     *                     (default: any)
-     *                       
-     *                           shipped: Show orders that have been shipped. Returns orders with fulfillment_status of fulfilled.
-     *                           partial: Show partially shipped orders.
-     *                           unshipped: Show orders that have not yet been shipped. Returns orders with fulfillment_status of null.
-     *                           any: Show orders of any fulfillment status.
-     *                           unfulfilled: Returns orders with fulfillment_status of null or partial.
-     * * `fields: &str` -- Retrieve only certain fields, specified by a comma-separated list of fields names.
+     * * `params: &OrdersDeprecated202007GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get(
+    pub async fn deprecated_202007_get_with(
         &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        processed_at_min: &str,
-        processed_at_max: &str,
-        attribution_app_id: &str,
-        status: &str,
-        financial_status: &str,
-        fulfillment_status: &str,
-        fields: &str,
+        params: &OrdersDeprecated202007GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !attribution_app_id.is_empty() {
+        if let Some(attribution_app_id) = &params.attribution_app_id {
             query_args.push((
                 "attribution_app_id".to_string(),
                 attribution_app_id.to_string(),
             ));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !financial_status.is_empty() {
+        if let Some(financial_status) = &params.financial_status {
             query_args.push(("financial_status".to_string(), financial_status.to_string()));
         }
-        if !fulfillment_status.is_empty() {
+        if let Some(fulfillment_status) = &params.fulfillment_status {
             query_args.push((
                 "fulfillment_status".to_string(),
                 fulfillment_status.to_string(),
             ));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !processed_at_max.is_empty() {
+        if let Some(processed_at_max) = &params.processed_at_max {
             query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
         }
-        if !processed_at_min.is_empty() {
+        if let Some(processed_at_min) = &params.processed_at_min {
             query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1319,6 +1357,46 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_with` and pass the query parameters as a `OrdersDeprecated202007GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_with` instead")]
+    pub async fn deprecated_202007_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        attribution_app_id: &str,
+        status: &str,
+        financial_status: &str,
+        fulfillment_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202007GetParams {
+            attribution_app_id: Some(attribution_app_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            financial_status: Some(financial_status.to_string()).filter(|s| !s.is_empty()),
+            fulfillment_status: Some(fulfillment_status.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            processed_at_max: Some(processed_at_max.to_string()).filter(|s| !s.is_empty()),
+            processed_at_min: Some(processed_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_with(&params).await
+    }
+
     /**
      * Retrieves a list of all order risks for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -2127,27 +2205,21 @@ impl Orders {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `in_shop_currency: &str` -- Show amounts in the shop currency for the underlying transaction.
-     *                     (default: false).
+     * * `params: &OrdersDeprecated202001GetParamRefundParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_param_refund(
+    pub async fn deprecated_202001_get_param_refund_with(
         &self,
         order_id: &str,
-        limit: &str,
-        fields: &str,
-        in_shop_currency: &str,
+        params: &OrdersDeprecated202001GetParamRefundParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !in_shop_currency.is_empty() {
+        if let Some(in_shop_currency) = &params.in_shop_currency {
             query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2160,6 +2232,26 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_param_refund_with` and pass the query parameters as a `OrdersDeprecated202001GetParamRefundParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_param_refund_with` instead")]
+    pub async fn deprecated_202001_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202001GetParamRefundParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            in_shop_currency: Some(in_shop_currency.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_param_refund_with(order_id, &params)
+            .await
+    }
+
     /**
     * Caution
                 For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -2177,75 +2269,37 @@ impl Orders {
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `restock_deprecated: &str` -- Whether to add the line items back to the store inventory. Use restock_type for refund line items instead.
-    * * `notify: &str` -- Whether to send a refund notification to the customer.
-    * * `note: &str` -- An optional note attached to a refund.
-    * * `discrepancy_reason: &str` -- An optional comment that explains a discrepancy between calculated and actual refund amounts. Used to populate the reason property of the resulting order adjustment object attached to the refund. Valid values: restock, damage, customer, and other.
-    * * `shipping: &str` -- Specify how much shipping to refund. It has the following properties:
-    *
-    *                           full_refund: Whether to refund all remaining shipping.
-    *                           amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
-    * * `refund_line_items: &str` -- A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
-    *
-    *                           line_item_id: The ID of a line item to refund.
-    *                           quantity: The quantity to refund.
-    *                           restock_type:           How this refund line item affects inventory levels. Valid values:
-    *
-    *               no_restock: Refunding these items won't affect inventory.
-    *               cancel: The items have not yet been fulfilled.
-    *               The canceled quantity will be added back to the available count.
-    *               The number of fulfillable units for this line item will decrease.
-    *               return: The items were already delivered but will be returned to the merchant.
-    *               The returned quantity will be added back to the available count. The number of fulfillable units for this
-    *               line item will remain unchanged.
-    *
-    *
-    *                           location_id:           The ID of the location where the items should be
-    *             restocked. This is required when the value of restock_type is return or cancel.
-    *             If the item is not already stocked at the location, then
-    *             the item is connected to the location. An error is returned when the item is connected to
-    *             a
-    *             fulfillment service location and a different location is provided.
-    * * `transactions: &str` -- A list of transactions
-    *             to process as refunds.
-    * * `currency: &str` -- The three-letter code (ISO 4217 format) for the currency used for the refund.
+    * * `params: &OrdersDeprecated202001CreateParamRefundsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_create_param_refunds(
+    pub async fn deprecated_202001_create_param_refunds_with(
         &self,
         order_id: &str,
-        restock_deprecated: &str,
-        notify: &str,
-        note: &str,
-        discrepancy_reason: &str,
-        shipping: &str,
-        refund_line_items: &str,
-        transactions: &str,
-        currency: &str,
+        params: &OrdersDeprecated202001CreateParamRefundsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !discrepancy_reason.is_empty() {
+        if let Some(discrepancy_reason) = &params.discrepancy_reason {
             query_args.push((
                 "discrepancy_reason".to_string(),
                 discrepancy_reason.to_string(),
             ));
         }
-        if !note.is_empty() {
+        if let Some(note) = &params.note {
             query_args.push(("note".to_string(), note.to_string()));
         }
-        if !notify.is_empty() {
+        if let Some(notify) = &params.notify {
             query_args.push(("notify".to_string(), notify.to_string()));
         }
-        if !refund_line_items.is_empty() {
+        if let Some(refund_line_items) = &params.refund_line_items {
             query_args.push((
                 "refund_line_items".to_string(),
                 refund_line_items.to_string(),
             ));
         }
-        if !restock_deprecated.is_empty() {
+        if let Some(restock_deprecated) = &params.restock_deprecated {
             query_args.push((
                 "restock
                   deprecated"
@@ -2253,10 +2307,10 @@ impl Orders {
                 restock_deprecated.to_string(),
             ));
         }
-        if !shipping.is_empty() {
+        if let Some(shipping) = &params.shipping {
             query_args.push(("shipping".to_string(), shipping.to_string()));
         }
-        if !transactions.is_empty() {
+        if let Some(transactions) = &params.transactions {
             query_args.push(("transactions".to_string(), transactions.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2271,6 +2325,37 @@ impl Orders {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_param_refunds_with` and pass the query parameters as a `OrdersDeprecated202001CreateParamRefundsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_create_param_refunds_with` instead")]
+    pub async fn deprecated_202001_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202001CreateParamRefundsParams {
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            discrepancy_reason: Some(discrepancy_reason.to_string()).filter(|s| !s.is_empty()),
+            note: Some(note.to_string()).filter(|s| !s.is_empty()),
+            notify: Some(notify.to_string()).filter(|s| !s.is_empty()),
+            refund_line_items: Some(refund_line_items.to_string()).filter(|s| !s.is_empty()),
+            restock_deprecated: Some(restock_deprecated.to_string()).filter(|s| !s.is_empty()),
+            shipping: Some(shipping.to_string()).filter(|s| !s.is_empty()),
+            transactions: Some(transactions.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_create_param_refunds_with(order_id, &params, body)
+            .await
+    }
+
     /**
      * Retrieves a specific refund.
      *
@@ -2282,22 +2367,19 @@ impl Orders {
      *
      * * `order_id: &str` -- storefront_access_token_id.
      * * `refund_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `in_shop_currency: &str` -- Show amounts in the shop currency for the underlying transaction.
-     *                     (default: false).
+     * * `params: &OrdersDeprecated202001GetParamRefundsRefundParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_param_refunds_refund(
+    pub async fn deprecated_202001_get_param_refunds_refund_with(
         &self,
         order_id: &str,
         refund_id: &str,
-        fields: &str,
-        in_shop_currency: &str,
+        params: &OrdersDeprecated202001GetParamRefundsRefundParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !in_shop_currency.is_empty() {
+        if let Some(in_shop_currency) = &params.in_shop_currency {
             query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2311,6 +2393,25 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_param_refunds_refund_with` and pass the query parameters as a `OrdersDeprecated202001GetParamRefundsRefundParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_param_refunds_refund_with` instead")]
+    pub async fn deprecated_202001_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202001GetParamRefundsRefundParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            in_shop_currency: Some(in_shop_currency.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_param_refunds_refund_with(order_id, refund_id, &params)
+            .await
+    }
+
     /**
     * Caution
               For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -2330,54 +2431,25 @@ impl Orders {
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `shipping: &str` -- Specify how much shipping to refund. It has the following properties:
-    *
-    *                           full_refund: Whether to refund all remaining shipping.
-    *                           amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
-    * * `refund_line_items: &str` -- A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
-    *
-    *                           line_item_id: The ID of a line item to refund.
-    *                           quantity: The quantity to refund.
-    *                           restock_type:           How this refund line item affects inventory levels. Valid values:
-    *
-    *               no_restock: Refunding these items won't affect inventory.
-    *               cancel: The items have not yet been fulfilled. The canceled quantity will be added
-    *               back to the available count. The number of fulfillable units for this line item will decrease.
-    *               return: The items were already delivered but will be returned to the merchant.
-    *               The returned quantity will be added back to the available count.
-    *               The number of fulfillable units for this line item will remain unchanged.
-    *
-    *
-    *                           location_id:           The ID of the location
-    *             where the items should be restocked. If location_id is not provided and the value of
-    *             restock_type is return or cancel, then the endpoint returns a suitable
-    *             location ID.
-    *
-    *                           already_stocked:           Whether the item is already stocked at
-    *             the location. If this is false, then creating the refund will connect the item to the location and start
-    *             stocking it there.
-    * * `currency: &str` -- The three-letter code (ISO 4217 format) for the
-    *             currency used for the refund. Note: Required whenever the shipping amount property is provided.
+    * * `params: &OrdersDeprecated202001CreateParamRefundsCalculateParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_create_param_refunds_calculate(
+    pub async fn deprecated_202001_create_param_refunds_calculate_with(
         &self,
         order_id: &str,
-        shipping: &str,
-        refund_line_items: &str,
-        currency: &str,
+        params: &OrdersDeprecated202001CreateParamRefundsCalculateParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !refund_line_items.is_empty() {
+        if let Some(refund_line_items) = &params.refund_line_items {
             query_args.push((
                 "refund_line_items".to_string(),
                 refund_line_items.to_string(),
             ));
         }
-        if !shipping.is_empty() {
+        if let Some(shipping) = &params.shipping {
             query_args.push(("shipping".to_string(), shipping.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2392,6 +2464,27 @@ impl Orders {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_param_refunds_calculate_with` and pass the query parameters as a `OrdersDeprecated202001CreateParamRefundsCalculateParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_create_param_refunds_calculate_with` instead")]
+    pub async fn deprecated_202001_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202001CreateParamRefundsCalculateParams {
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            refund_line_items: Some(refund_line_items.to_string()).filter(|s| !s.is_empty()),
+            shipping: Some(shipping.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_create_param_refunds_calculate_with(order_id, &params, body)
+            .await
+    }
+
     /**
      * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -2402,27 +2495,21 @@ impl Orders {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `in_shop_currency: &str` -- Show amounts in the shop currency for the underlying transaction.
-     *                     (default: false).
+     * * `params: &OrdersDeprecated202004GetParamRefundParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_param_refund(
+    pub async fn deprecated_202004_get_param_refund_with(
         &self,
         order_id: &str,
-        limit: &str,
-        fields: &str,
-        in_shop_currency: &str,
+        params: &OrdersDeprecated202004GetParamRefundParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !in_shop_currency.is_empty() {
+        if let Some(in_shop_currency) = &params.in_shop_currency {
             query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2435,6 +2522,26 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_param_refund_with` and pass the query parameters as a `OrdersDeprecated202004GetParamRefundParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_param_refund_with` instead")]
+    pub async fn deprecated_202004_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202004GetParamRefundParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            in_shop_currency: Some(in_shop_currency.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_param_refund_with(order_id, &params)
+            .await
+    }
+
     /**
     * Caution
                 For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -2452,75 +2559,37 @@ impl Orders {
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `restock_deprecated: &str` -- Whether to add the line items back to the store inventory. Use restock_type for refund line items instead.
-    * * `notify: &str` -- Whether to send a refund notification to the customer.
-    * * `note: &str` -- An optional note attached to a refund.
-    * * `discrepancy_reason: &str` -- An optional comment that explains a discrepancy between calculated and actual refund amounts. Used to populate the reason property of the resulting order adjustment object attached to the refund. Valid values: restock, damage, customer, and other.
-    * * `shipping: &str` -- Specify how much shipping to refund. It has the following properties:
-    *
-    *                           full_refund: Whether to refund all remaining shipping.
-    *                           amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
-    * * `refund_line_items: &str` -- A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
-    *
-    *                           line_item_id: The ID of a line item to refund.
-    *                           quantity: The quantity to refund.
-    *                           restock_type:           How this refund line item affects inventory levels. Valid values:
-    *
-    *               no_restock: Refunding these items won't affect inventory.
-    *               cancel: The items have not yet been fulfilled.
-    *               The canceled quantity will be added back to the available count.
-    *               The number of fulfillable units for this line item will decrease.
-    *               return: The items were already delivered but will be returned to the merchant.
-    *               The returned quantity will be added back to the available count. The number of fulfillable units for this
-    *               line item will remain unchanged.
-    *
-    *
-    *                           location_id:           The ID of the location where the items should be
-    *             restocked. This is required when the value of restock_type is return or cancel.
-    *             If the item is not already stocked at the location, then
-    *             the item is connected to the location. An error is returned when the item is connected to
-    *             a
-    *             fulfillment service location and a different location is provided.
-    * * `transactions: &str` -- A list of transactions
-    *             to process as refunds.
-    * * `currency: &str` -- The three-letter code (ISO 4217 format) for the currency used for the refund.
+    * * `params: &OrdersDeprecated202004CreateParamRefundsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_create_param_refunds(
+    pub async fn deprecated_202004_create_param_refunds_with(
         &self,
         order_id: &str,
-        restock_deprecated: &str,
-        notify: &str,
-        note: &str,
-        discrepancy_reason: &str,
-        shipping: &str,
-        refund_line_items: &str,
-        transactions: &str,
-        currency: &str,
+        params: &OrdersDeprecated202004CreateParamRefundsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !discrepancy_reason.is_empty() {
+        if let Some(discrepancy_reason) = &params.discrepancy_reason {
             query_args.push((
                 "discrepancy_reason".to_string(),
                 discrepancy_reason.to_string(),
             ));
         }
-        if !note.is_empty() {
+        if let Some(note) = &params.note {
             query_args.push(("note".to_string(), note.to_string()));
         }
-        if !notify.is_empty() {
+        if let Some(notify) = &params.notify {
             query_args.push(("notify".to_string(), notify.to_string()));
         }
-        if !refund_line_items.is_empty() {
+        if let Some(refund_line_items) = &params.refund_line_items {
             query_args.push((
                 "refund_line_items".to_string(),
                 refund_line_items.to_string(),
             ));
         }
-        if !restock_deprecated.is_empty() {
+        if let Some(restock_deprecated) = &params.restock_deprecated {
             query_args.push((
                 "restock
                   deprecated"
@@ -2528,10 +2597,10 @@ impl Orders {
                 restock_deprecated.to_string(),
             ));
         }
-        if !shipping.is_empty() {
+        if let Some(shipping) = &params.shipping {
             query_args.push(("shipping".to_string(), shipping.to_string()));
         }
-        if !transactions.is_empty() {
+        if let Some(transactions) = &params.transactions {
             query_args.push(("transactions".to_string(), transactions.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2546,6 +2615,37 @@ impl Orders {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_param_refunds_with` and pass the query parameters as a `OrdersDeprecated202004CreateParamRefundsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_create_param_refunds_with` instead")]
+    pub async fn deprecated_202004_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202004CreateParamRefundsParams {
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            discrepancy_reason: Some(discrepancy_reason.to_string()).filter(|s| !s.is_empty()),
+            note: Some(note.to_string()).filter(|s| !s.is_empty()),
+            notify: Some(notify.to_string()).filter(|s| !s.is_empty()),
+            refund_line_items: Some(refund_line_items.to_string()).filter(|s| !s.is_empty()),
+            restock_deprecated: Some(restock_deprecated.to_string()).filter(|s| !s.is_empty()),
+            shipping: Some(shipping.to_string()).filter(|s| !s.is_empty()),
+            transactions: Some(transactions.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_create_param_refunds_with(order_id, &params, body)
+            .await
+    }
+
     /**
      * Retrieves a specific refund.
      *
@@ -2557,22 +2657,19 @@ impl Orders {
      *
      * * `order_id: &str` -- storefront_access_token_id.
      * * `refund_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `in_shop_currency: &str` -- Show amounts in the shop currency for the underlying transaction.
-     *                     (default: false).
+     * * `params: &OrdersDeprecated202004GetParamRefundsRefundParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_param_refunds_refund(
+    pub async fn deprecated_202004_get_param_refunds_refund_with(
         &self,
         order_id: &str,
         refund_id: &str,
-        fields: &str,
-        in_shop_currency: &str,
+        params: &OrdersDeprecated202004GetParamRefundsRefundParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !in_shop_currency.is_empty() {
+        if let Some(in_shop_currency) = &params.in_shop_currency {
             query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2586,6 +2683,25 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_param_refunds_refund_with` and pass the query parameters as a `OrdersDeprecated202004GetParamRefundsRefundParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_param_refunds_refund_with` instead")]
+    pub async fn deprecated_202004_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202004GetParamRefundsRefundParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            in_shop_currency: Some(in_shop_currency.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_param_refunds_refund_with(order_id, refund_id, &params)
+            .await
+    }
+
     /**
     * Caution
               For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -2605,54 +2721,25 @@ impl Orders {
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `shipping: &str` -- Specify how much shipping to refund. It has the following properties:
-    *
-    *                           full_refund: Whether to refund all remaining shipping.
-    *                           amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
-    * * `refund_line_items: &str` -- A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
-    *
-    *                           line_item_id: The ID of a line item to refund.
-    *                           quantity: The quantity to refund.
-    *                           restock_type:           How this refund line item affects inventory levels. Valid values:
-    *
-    *               no_restock: Refunding these items won't affect inventory.
-    *               cancel: The items have not yet been fulfilled. The canceled quantity will be added
-    *               back to the available count. The number of fulfillable units for this line item will decrease.
-    *               return: The items were already delivered but will be returned to the merchant.
-    *               The returned quantity will be added back to the available count.
-    *               The number of fulfillable units for this line item will remain unchanged.
-    *
-    *
-    *                           location_id:           The ID of the location
-    *             where the items should be restocked. If location_id is not provided and the value of
-    *             restock_type is return or cancel, then the endpoint returns a suitable
-    *             location ID.
-    *
-    *                           already_stocked:           Whether the item is already stocked at
-    *             the location. If this is false, then creating the refund will connect the item to the location and start
-    *             stocking it there.
-    * * `currency: &str` -- The three-letter code (ISO 4217 format) for the
-    *             currency used for the refund. Note: Required whenever the shipping amount property is provided.
+    * * `params: &OrdersDeprecated202004CreateParamRefundsCalculateParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_create_param_refunds_calculate(
+    pub async fn deprecated_202004_create_param_refunds_calculate_with(
         &self,
         order_id: &str,
-        shipping: &str,
-        refund_line_items: &str,
-        currency: &str,
+        params: &OrdersDeprecated202004CreateParamRefundsCalculateParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !refund_line_items.is_empty() {
+        if let Some(refund_line_items) = &params.refund_line_items {
             query_args.push((
                 "refund_line_items".to_string(),
                 refund_line_items.to_string(),
             ));
         }
-        if !shipping.is_empty() {
+        if let Some(shipping) = &params.shipping {
             query_args.push(("shipping".to_string(), shipping.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2667,6 +2754,27 @@ impl Orders {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_param_refunds_calculate_with` and pass the query parameters as a `OrdersDeprecated202004CreateParamRefundsCalculateParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_create_param_refunds_calculate_with` instead")]
+    pub async fn deprecated_202004_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202004CreateParamRefundsCalculateParams {
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            refund_line_items: Some(refund_line_items.to_string()).filter(|s| !s.is_empty()),
+            shipping: Some(shipping.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_create_param_refunds_calculate_with(order_id, &params, body)
+            .await
+    }
+
     /**
      * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -2677,27 +2785,21 @@ impl Orders {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `in_shop_currency: &str` -- Show amounts in the shop currency for the underlying transaction.
-     *                     (default: false).
+     * * `params: &OrdersDeprecated202007GetParamRefundParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_param_refund(
+    pub async fn deprecated_202007_get_param_refund_with(
         &self,
         order_id: &str,
-        limit: &str,
-        fields: &str,
-        in_shop_currency: &str,
+        params: &OrdersDeprecated202007GetParamRefundParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !in_shop_currency.is_empty() {
+        if let Some(in_shop_currency) = &params.in_shop_currency {
             query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2711,91 +2813,73 @@ impl Orders {
     }
 
     /**
-    * Caution
-                For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
-
-              Creates a refund. Use the calculate endpoint to produce the transactions to submit.
-
-
-                Note
-                When you use this endpoint with a Partner development store or a trial store, you can create only five refunds per minute.
-    *
-    * This function performs a `POST` to the `/admin/api/2020-07/orders/{order_id}/refunds.json` endpoint.
+     * Deprecated: use `deprecated_202007_get_param_refund_with` and pass the query parameters as a `OrdersDeprecated202007GetParamRefundParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_param_refund_with` instead")]
+    pub async fn deprecated_202007_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202007GetParamRefundParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            in_shop_currency: Some(in_shop_currency.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_param_refund_with(order_id, &params)
+            .await
+    }
+
+    /**
+    * Caution
+                For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
+
+              Creates a refund. Use the calculate endpoint to produce the transactions to submit.
+
+
+                Note
+                When you use this endpoint with a Partner development store or a trial store, you can create only five refunds per minute.
+    *
+    * This function performs a `POST` to the `/admin/api/2020-07/orders/{order_id}/refunds.json` endpoint.
     *
     * https://shopify.dev/docs/admin-api/rest/reference/orders/refund#create-2020-07
     *
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `restock_deprecated: &str` -- Whether to add the line items back to the store inventory. Use restock_type for refund line items instead.
-    * * `notify: &str` -- Whether to send a refund notification to the customer.
-    * * `note: &str` -- An optional note attached to a refund.
-    * * `discrepancy_reason: &str` -- An optional comment that explains a discrepancy between calculated and actual refund amounts. Used to populate the reason property of the resulting order adjustment object attached to the refund. Valid values: restock, damage, customer, and other.
-    * * `shipping: &str` -- Specify how much shipping to refund. It has the following properties:
-    *
-    *                           full_refund: Whether to refund all remaining shipping.
-    *                           amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
-    * * `refund_line_items: &str` -- A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
-    *
-    *                           line_item_id: The ID of a line item to refund.
-    *                           quantity: The quantity to refund.
-    *                           restock_type:           How this refund line item affects inventory levels. Valid values:
-    *
-    *               no_restock: Refunding these items won't affect inventory.
-    *               cancel: The items have not yet been fulfilled.
-    *               The canceled quantity will be added back to the available count.
-    *               The number of fulfillable units for this line item will decrease.
-    *               return: The items were already delivered but will be returned to the merchant.
-    *               The returned quantity will be added back to the available count. The number of fulfillable units for this
-    *               line item will remain unchanged.
-    *
-    *
-    *                           location_id:           The ID of the location where the items should be
-    *             restocked. This is required when the value of restock_type is return or cancel.
-    *             If the item is not already stocked at the location, then
-    *             the item is connected to the location. An error is returned when the item is connected to
-    *             a
-    *             fulfillment service location and a different location is provided.
-    * * `transactions: &str` -- A list of transactions
-    *             to process as refunds.
-    * * `currency: &str` -- The three-letter code (ISO 4217 format) for the currency used for the refund.
+    * * `params: &OrdersDeprecated202007CreateParamRefundsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202007_create_param_refunds(
+    pub async fn deprecated_202007_create_param_refunds_with(
         &self,
         order_id: &str,
-        restock_deprecated: &str,
-        notify: &str,
-        note: &str,
-        discrepancy_reason: &str,
-        shipping: &str,
-        refund_line_items: &str,
-        transactions: &str,
-        currency: &str,
+        params: &OrdersDeprecated202007CreateParamRefundsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !discrepancy_reason.is_empty() {
+        if let Some(discrepancy_reason) = &params.discrepancy_reason {
             query_args.push((
                 "discrepancy_reason".to_string(),
                 discrepancy_reason.to_string(),
             ));
         }
-        if !note.is_empty() {
+        if let Some(note) = &params.note {
             query_args.push(("note".to_string(), note.to_string()));
         }
-        if !notify.is_empty() {
+        if let Some(notify) = &params.notify {
             query_args.push(("notify".to_string(), notify.to_string()));
         }
-        if !refund_line_items.is_empty() {
+        if let Some(refund_line_items) = &params.refund_line_items {
             query_args.push((
                 "refund_line_items".to_string(),
                 refund_line_items.to_string(),
             ));
         }
-        if !restock_deprecated.is_empty() {
+        if let Some(restock_deprecated) = &params.restock_deprecated {
             query_args.push((
                 "restock
                   deprecated"
@@ -2803,10 +2887,10 @@ impl Orders {
                 restock_deprecated.to_string(),
             ));
         }
-        if !shipping.is_empty() {
+        if let Some(shipping) = &params.shipping {
             query_args.push(("shipping".to_string(), shipping.to_string()));
         }
-        if !transactions.is_empty() {
+        if let Some(transactions) = &params.transactions {
             query_args.push(("transactions".to_string(), transactions.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2821,6 +2905,37 @@ impl Orders {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_param_refunds_with` and pass the query parameters as a `OrdersDeprecated202007CreateParamRefundsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_create_param_refunds_with` instead")]
+    pub async fn deprecated_202007_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202007CreateParamRefundsParams {
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            discrepancy_reason: Some(discrepancy_reason.to_string()).filter(|s| !s.is_empty()),
+            note: Some(note.to_string()).filter(|s| !s.is_empty()),
+            notify: Some(notify.to_string()).filter(|s| !s.is_empty()),
+            refund_line_items: Some(refund_line_items.to_string()).filter(|s| !s.is_empty()),
+            restock_deprecated: Some(restock_deprecated.to_string()).filter(|s| !s.is_empty()),
+            shipping: Some(shipping.to_string()).filter(|s| !s.is_empty()),
+            transactions: Some(transactions.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_create_param_refunds_with(order_id, &params, body)
+            .await
+    }
+
     /**
      * Retrieves a specific refund.
      *
@@ -2832,22 +2947,19 @@ impl Orders {
      *
      * * `order_id: &str` -- storefront_access_token_id.
      * * `refund_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `in_shop_currency: &str` -- Show amounts in the shop currency for the underlying transaction.
-     *                     (default: false).
+     * * `params: &OrdersDeprecated202007GetParamRefundsRefundParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_param_refunds_refund(
+    pub async fn deprecated_202007_get_param_refunds_refund_with(
         &self,
         order_id: &str,
         refund_id: &str,
-        fields: &str,
-        in_shop_currency: &str,
+        params: &OrdersDeprecated202007GetParamRefundsRefundParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !in_shop_currency.is_empty() {
+        if let Some(in_shop_currency) = &params.in_shop_currency {
             query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2861,6 +2973,25 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_param_refunds_refund_with` and pass the query parameters as a `OrdersDeprecated202007GetParamRefundsRefundParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_param_refunds_refund_with` instead")]
+    pub async fn deprecated_202007_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202007GetParamRefundsRefundParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            in_shop_currency: Some(in_shop_currency.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_param_refunds_refund_with(order_id, refund_id, &params)
+            .await
+    }
+
     /**
     * Caution
               For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -2880,54 +3011,25 @@ impl Orders {
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `shipping: &str` -- Specify how much shipping to refund. It has the following properties:
-    *
-    *                           full_refund: Whether to refund all remaining shipping.
-    *                           amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
-    * * `refund_line_items: &str` -- A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
-    *
-    *                           line_item_id: The ID of a line item to refund.
-    *                           quantity: The quantity to refund.
-    *                           restock_type:           How this refund line item affects inventory levels. Valid values:
-    *
-    *               no_restock: Refunding these items won't affect inventory.
-    *               cancel: The items have not yet been fulfilled. The canceled quantity will be added
-    *               back to the available count. The number of fulfillable units for this line item will decrease.
-    *               return: The items were already delivered but will be returned to the merchant.
-    *               The returned quantity will be added back to the available count.
-    *               The number of fulfillable units for this line item will remain unchanged.
-    *
-    *
-    *                           location_id:           The ID of the location
-    *             where the items should be restocked. If location_id is not provided and the value of
-    *             restock_type is return or cancel, then the endpoint returns a suitable
-    *             location ID.
-    *
-    *                           already_stocked:           Whether the item is already stocked at
-    *             the location. If this is false, then creating the refund will connect the item to the location and start
-    *             stocking it there.
-    * * `currency: &str` -- The three-letter code (ISO 4217 format) for the
-    *             currency used for the refund. Note: Required whenever the shipping amount property is provided.
+    * * `params: &OrdersDeprecated202007CreateParamRefundsCalculateParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202007_create_param_refunds_calculate(
+    pub async fn deprecated_202007_create_param_refunds_calculate_with(
         &self,
         order_id: &str,
-        shipping: &str,
-        refund_line_items: &str,
-        currency: &str,
+        params: &OrdersDeprecated202007CreateParamRefundsCalculateParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !refund_line_items.is_empty() {
+        if let Some(refund_line_items) = &params.refund_line_items {
             query_args.push((
                 "refund_line_items".to_string(),
                 refund_line_items.to_string(),
             ));
         }
-        if !shipping.is_empty() {
+        if let Some(shipping) = &params.shipping {
             query_args.push(("shipping".to_string(), shipping.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2942,6 +3044,27 @@ impl Orders {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_param_refunds_calculate_with` and pass the query parameters as a `OrdersDeprecated202007CreateParamRefundsCalculateParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_create_param_refunds_calculate_with` instead")]
+    pub async fn deprecated_202007_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202007CreateParamRefundsCalculateParams {
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            refund_line_items: Some(refund_line_items.to_string()).filter(|s| !s.is_empty()),
+            shipping: Some(shipping.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_create_param_refunds_calculate_with(order_id, &params, body)
+            .await
+    }
+
     /**
      * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -2952,27 +3075,21 @@ impl Orders {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `in_shop_currency: &str` -- Show amounts in the shop currency for the underlying transaction.
-     *                     (default: false).
+     * * `params: &OrdersGetParamRefundParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_param_refund(
+    pub async fn get_param_refund_with(
         &self,
         order_id: &str,
-        limit: &str,
-        fields: &str,
-        in_shop_currency: &str,
+        params: &OrdersGetParamRefundParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !in_shop_currency.is_empty() {
+        if let Some(in_shop_currency) = &params.in_shop_currency {
             query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2985,6 +3102,25 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_param_refund_with` and pass the query parameters as a `OrdersGetParamRefundParams` instead.
+     */
+    #[deprecated(note = "use `get_param_refund_with` instead")]
+    pub async fn get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        let params = OrdersGetParamRefundParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            in_shop_currency: Some(in_shop_currency.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_param_refund_with(order_id, &params).await
+    }
+
     /**
     * Caution
                 For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -3002,75 +3138,37 @@ impl Orders {
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `restock_deprecated: &str` -- Whether to add the line items back to the store inventory. Use restock_type for refund line items instead.
-    * * `notify: &str` -- Whether to send a refund notification to the customer.
-    * * `note: &str` -- An optional note attached to a refund.
-    * * `discrepancy_reason: &str` -- An optional comment that explains a discrepancy between calculated and actual refund amounts. Used to populate the reason property of the resulting order adjustment object attached to the refund. Valid values: restock, damage, customer, and other.
-    * * `shipping: &str` -- Specify how much shipping to refund. It has the following properties:
-    *
-    *                           full_refund: Whether to refund all remaining shipping.
-    *                           amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
-    * * `refund_line_items: &str` -- A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
-    *
-    *                           line_item_id: The ID of a line item to refund.
-    *                           quantity: The quantity to refund.
-    *                           restock_type:           How this refund line item affects inventory levels. Valid values:
-    *
-    *               no_restock: Refunding these items won't affect inventory.
-    *               cancel: The items have not yet been fulfilled.
-    *               The canceled quantity will be added back to the available count.
-    *               The number of fulfillable units for this line item will decrease.
-    *               return: The items were already delivered but will be returned to the merchant.
-    *               The returned quantity will be added back to the available count. The number of fulfillable units for this
-    *               line item will remain unchanged.
-    *
-    *
-    *                           location_id:           The ID of the location where the items should be
-    *             restocked. This is required when the value of restock_type is return or cancel.
-    *             If the item is not already stocked at the location, then
-    *             the item is connected to the location. An error is returned when the item is connected to
-    *             a
-    *             fulfillment service location and a different location is provided.
-    * * `transactions: &str` -- A list of transactions
-    *             to process as refunds.
-    * * `currency: &str` -- The three-letter code (ISO 4217 format) for the currency used for the refund.
+    * * `params: &OrdersCreateParamRefundsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn create_param_refunds(
+    pub async fn create_param_refunds_with(
         &self,
         order_id: &str,
-        restock_deprecated: &str,
-        notify: &str,
-        note: &str,
-        discrepancy_reason: &str,
-        shipping: &str,
-        refund_line_items: &str,
-        transactions: &str,
-        currency: &str,
+        params: &OrdersCreateParamRefundsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !discrepancy_reason.is_empty() {
+        if let Some(discrepancy_reason) = &params.discrepancy_reason {
             query_args.push((
                 "discrepancy_reason".to_string(),
                 discrepancy_reason.to_string(),
             ));
         }
-        if !note.is_empty() {
+        if let Some(note) = &params.note {
             query_args.push(("note".to_string(), note.to_string()));
         }
-        if !notify.is_empty() {
+        if let Some(notify) = &params.notify {
             query_args.push(("notify".to_string(), notify.to_string()));
         }
-        if !refund_line_items.is_empty() {
+        if let Some(refund_line_items) = &params.refund_line_items {
             query_args.push((
                 "refund_line_items".to_string(),
                 refund_line_items.to_string(),
             ));
         }
-        if !restock_deprecated.is_empty() {
+        if let Some(restock_deprecated) = &params.restock_deprecated {
             query_args.push((
                 "restock
                   deprecated"
@@ -3078,10 +3176,10 @@ impl Orders {
                 restock_deprecated.to_string(),
             ));
         }
-        if !shipping.is_empty() {
+        if let Some(shipping) = &params.shipping {
             query_args.push(("shipping".to_string(), shipping.to_string()));
         }
-        if !transactions.is_empty() {
+        if let Some(transactions) = &params.transactions {
             query_args.push(("transactions".to_string(), transactions.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3096,6 +3194,37 @@ impl Orders {
             .await
     }
 
+    /**
+     * Deprecated: use `create_param_refunds_with` and pass the query parameters as a `OrdersCreateParamRefundsParams` instead.
+     */
+    #[deprecated(note = "use `create_param_refunds_with` instead")]
+    pub async fn create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersCreateParamRefundsParams {
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            discrepancy_reason: Some(discrepancy_reason.to_string()).filter(|s| !s.is_empty()),
+            note: Some(note.to_string()).filter(|s| !s.is_empty()),
+            notify: Some(notify.to_string()).filter(|s| !s.is_empty()),
+            refund_line_items: Some(refund_line_items.to_string()).filter(|s| !s.is_empty()),
+            restock_deprecated: Some(restock_deprecated.to_string()).filter(|s| !s.is_empty()),
+            shipping: Some(shipping.to_string()).filter(|s| !s.is_empty()),
+            transactions: Some(transactions.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_param_refunds_with(order_id, &params, body)
+            .await
+    }
+
     /**
      * Retrieves a specific refund.
      *
@@ -3107,22 +3236,19 @@ impl Orders {
      *
      * * `order_id: &str` -- storefront_access_token_id.
      * * `refund_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `in_shop_currency: &str` -- Show amounts in the shop currency for the underlying transaction.
-     *                     (default: false).
+     * * `params: &OrdersGetParamRefundsRefundParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_param_refunds_refund(
+    pub async fn get_param_refunds_refund_with(
         &self,
         order_id: &str,
         refund_id: &str,
-        fields: &str,
-        in_shop_currency: &str,
+        params: &OrdersGetParamRefundsRefundParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !in_shop_currency.is_empty() {
+        if let Some(in_shop_currency) = &params.in_shop_currency {
             query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3136,6 +3262,25 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_param_refunds_refund_with` and pass the query parameters as a `OrdersGetParamRefundsRefundParams` instead.
+     */
+    #[deprecated(note = "use `get_param_refunds_refund_with` instead")]
+    pub async fn get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        let params = OrdersGetParamRefundsRefundParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            in_shop_currency: Some(in_shop_currency.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_param_refunds_refund_with(order_id, refund_id, &params)
+            .await
+    }
+
     /**
     * Caution
               For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -3155,54 +3300,25 @@ impl Orders {
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `shipping: &str` -- Specify how much shipping to refund. It has the following properties:
-    *
-    *                           full_refund: Whether to refund all remaining shipping.
-    *                           amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
-    * * `refund_line_items: &str` -- A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
-    *
-    *                           line_item_id: The ID of a line item to refund.
-    *                           quantity: The quantity to refund.
-    *                           restock_type:           How this refund line item affects inventory levels. Valid values:
-    *
-    *               no_restock: Refunding these items won't affect inventory.
-    *               cancel: The items have not yet been fulfilled. The canceled quantity will be added
-    *               back to the available count. The number of fulfillable units for this line item will decrease.
-    *               return: The items were already delivered but will be returned to the merchant.
-    *               The returned quantity will be added back to the available count.
-    *               The number of fulfillable units for this line item will remain unchanged.
-    *
-    *
-    *                           location_id:           The ID of the location
-    *             where the items should be restocked. If location_id is not provided and the value of
-    *             restock_type is return or cancel, then the endpoint returns a suitable
-    *             location ID.
-    *
-    *                           already_stocked:           Whether the item is already stocked at
-    *             the location. If this is false, then creating the refund will connect the item to the location and start
-    *             stocking it there.
-    * * `currency: &str` -- The three-letter code (ISO 4217 format) for the
-    *             currency used for the refund. Note: Required whenever the shipping amount property is provided.
+    * * `params: &OrdersCreateParamRefundsCalculateParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn create_param_refunds_calculate(
+    pub async fn create_param_refunds_calculate_with(
         &self,
         order_id: &str,
-        shipping: &str,
-        refund_line_items: &str,
-        currency: &str,
+        params: &OrdersCreateParamRefundsCalculateParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !refund_line_items.is_empty() {
+        if let Some(refund_line_items) = &params.refund_line_items {
             query_args.push((
                 "refund_line_items".to_string(),
                 refund_line_items.to_string(),
             ));
         }
-        if !shipping.is_empty() {
+        if let Some(shipping) = &params.shipping {
             query_args.push(("shipping".to_string(), shipping.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3217,6 +3333,27 @@ impl Orders {
             .await
     }
 
+    /**
+     * Deprecated: use `create_param_refunds_calculate_with` and pass the query parameters as a `OrdersCreateParamRefundsCalculateParams` instead.
+     */
+    #[deprecated(note = "use `create_param_refunds_calculate_with` instead")]
+    pub async fn create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersCreateParamRefundsCalculateParams {
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            refund_line_items: Some(refund_line_items.to_string()).filter(|s| !s.is_empty()),
+            shipping: Some(shipping.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_param_refunds_calculate_with(order_id, &params, body)
+            .await
+    }
+
     /**
      * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -3227,27 +3364,21 @@ impl Orders {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `in_shop_currency: &str` -- Show amounts in the shop currency for the underlying transaction.
-     *                     (default: false).
+     * * `params: &OrdersDeprecated202101GetParamRefundParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_param_refund(
+    pub async fn deprecated_202101_get_param_refund_with(
         &self,
         order_id: &str,
-        limit: &str,
-        fields: &str,
-        in_shop_currency: &str,
+        params: &OrdersDeprecated202101GetParamRefundParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !in_shop_currency.is_empty() {
+        if let Some(in_shop_currency) = &params.in_shop_currency {
             query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3260,6 +3391,26 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_param_refund_with` and pass the query parameters as a `OrdersDeprecated202101GetParamRefundParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_param_refund_with` instead")]
+    pub async fn deprecated_202101_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202101GetParamRefundParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            in_shop_currency: Some(in_shop_currency.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_param_refund_with(order_id, &params)
+            .await
+    }
+
     /**
     * Caution
                 For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -3277,75 +3428,37 @@ impl Orders {
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `restock_deprecated: &str` -- Whether to add the line items back to the store inventory. Use restock_type for refund line items instead.
-    * * `notify: &str` -- Whether to send a refund notification to the customer.
-    * * `note: &str` -- An optional note attached to a refund.
-    * * `discrepancy_reason: &str` -- An optional comment that explains a discrepancy between calculated and actual refund amounts. Used to populate the reason property of the resulting order adjustment object attached to the refund. Valid values: restock, damage, customer, and other.
-    * * `shipping: &str` -- Specify how much shipping to refund. It has the following properties:
-    *
-    *                           full_refund: Whether to refund all remaining shipping.
-    *                           amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
-    * * `refund_line_items: &str` -- A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
-    *
-    *                           line_item_id: The ID of a line item to refund.
-    *                           quantity: The quantity to refund.
-    *                           restock_type:           How this refund line item affects inventory levels. Valid values:
-    *
-    *               no_restock: Refunding these items won't affect inventory.
-    *               cancel: The items have not yet been fulfilled.
-    *               The canceled quantity will be added back to the available count.
-    *               The number of fulfillable units for this line item will decrease.
-    *               return: The items were already delivered but will be returned to the merchant.
-    *               The returned quantity will be added back to the available count. The number of fulfillable units for this
-    *               line item will remain unchanged.
-    *
-    *
-    *                           location_id:           The ID of the location where the items should be
-    *             restocked. This is required when the value of restock_type is return or cancel.
-    *             If the item is not already stocked at the location, then
-    *             the item is connected to the location. An error is returned when the item is connected to
-    *             a
-    *             fulfillment service location and a different location is provided.
-    * * `transactions: &str` -- A list of transactions
-    *             to process as refunds.
-    * * `currency: &str` -- The three-letter code (ISO 4217 format) for the currency used for the refund.
+    * * `params: &OrdersDeprecated202101CreateParamRefundsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202101_create_param_refunds(
+    pub async fn deprecated_202101_create_param_refunds_with(
         &self,
         order_id: &str,
-        restock_deprecated: &str,
-        notify: &str,
-        note: &str,
-        discrepancy_reason: &str,
-        shipping: &str,
-        refund_line_items: &str,
-        transactions: &str,
-        currency: &str,
+        params: &OrdersDeprecated202101CreateParamRefundsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !discrepancy_reason.is_empty() {
+        if let Some(discrepancy_reason) = &params.discrepancy_reason {
             query_args.push((
                 "discrepancy_reason".to_string(),
                 discrepancy_reason.to_string(),
             ));
         }
-        if !note.is_empty() {
+        if let Some(note) = &params.note {
             query_args.push(("note".to_string(), note.to_string()));
         }
-        if !notify.is_empty() {
+        if let Some(notify) = &params.notify {
             query_args.push(("notify".to_string(), notify.to_string()));
         }
-        if !refund_line_items.is_empty() {
+        if let Some(refund_line_items) = &params.refund_line_items {
             query_args.push((
                 "refund_line_items".to_string(),
                 refund_line_items.to_string(),
             ));
         }
-        if !restock_deprecated.is_empty() {
+        if let Some(restock_deprecated) = &params.restock_deprecated {
             query_args.push((
                 "restock
                   deprecated"
@@ -3353,10 +3466,10 @@ impl Orders {
                 restock_deprecated.to_string(),
             ));
         }
-        if !shipping.is_empty() {
+        if let Some(shipping) = &params.shipping {
             query_args.push(("shipping".to_string(), shipping.to_string()));
         }
-        if !transactions.is_empty() {
+        if let Some(transactions) = &params.transactions {
             query_args.push(("transactions".to_string(), transactions.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3371,6 +3484,37 @@ impl Orders {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_param_refunds_with` and pass the query parameters as a `OrdersDeprecated202101CreateParamRefundsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_create_param_refunds_with` instead")]
+    pub async fn deprecated_202101_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202101CreateParamRefundsParams {
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            discrepancy_reason: Some(discrepancy_reason.to_string()).filter(|s| !s.is_empty()),
+            note: Some(note.to_string()).filter(|s| !s.is_empty()),
+            notify: Some(notify.to_string()).filter(|s| !s.is_empty()),
+            refund_line_items: Some(refund_line_items.to_string()).filter(|s| !s.is_empty()),
+            restock_deprecated: Some(restock_deprecated.to_string()).filter(|s| !s.is_empty()),
+            shipping: Some(shipping.to_string()).filter(|s| !s.is_empty()),
+            transactions: Some(transactions.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_create_param_refunds_with(order_id, &params, body)
+            .await
+    }
+
     /**
      * Retrieves a specific refund.
      *
@@ -3382,22 +3526,19 @@ impl Orders {
      *
      * * `order_id: &str` -- storefront_access_token_id.
      * * `refund_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `in_shop_currency: &str` -- Show amounts in the shop currency for the underlying transaction.
-     *                     (default: false).
+     * * `params: &OrdersDeprecated202101GetParamRefundsRefundParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_param_refunds_refund(
+    pub async fn deprecated_202101_get_param_refunds_refund_with(
         &self,
         order_id: &str,
         refund_id: &str,
-        fields: &str,
-        in_shop_currency: &str,
+        params: &OrdersDeprecated202101GetParamRefundsRefundParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !in_shop_currency.is_empty() {
+        if let Some(in_shop_currency) = &params.in_shop_currency {
             query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3411,6 +3552,25 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_param_refunds_refund_with` and pass the query parameters as a `OrdersDeprecated202101GetParamRefundsRefundParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_param_refunds_refund_with` instead")]
+    pub async fn deprecated_202101_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202101GetParamRefundsRefundParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            in_shop_currency: Some(in_shop_currency.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_param_refunds_refund_with(order_id, refund_id, &params)
+            .await
+    }
+
     /**
     * Caution
               For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -3430,54 +3590,25 @@ impl Orders {
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `shipping: &str` -- Specify how much shipping to refund. It has the following properties:
-    *
-    *                           full_refund: Whether to refund all remaining shipping.
-    *                           amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
-    * * `refund_line_items: &str` -- A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
-    *
-    *                           line_item_id: The ID of a line item to refund.
-    *                           quantity: The quantity to refund.
-    *                           restock_type:           How this refund line item affects inventory levels. Valid values:
-    *
-    *               no_restock: Refunding these items won't affect inventory.
-    *               cancel: The items have not yet been fulfilled. The canceled quantity will be added
-    *               back to the available count. The number of fulfillable units for this line item will decrease.
-    *               return: The items were already delivered but will be returned to the merchant.
-    *               The returned quantity will be added back to the available count.
-    *               The number of fulfillable units for this line item will remain unchanged.
-    *
-    *
-    *                           location_id:           The ID of the location
-    *             where the items should be restocked. If location_id is not provided and the value of
-    *             restock_type is return or cancel, then the endpoint returns a suitable
-    *             location ID.
-    *
-    *                           already_stocked:           Whether the item is already stocked at
-    *             the location. If this is false, then creating the refund will connect the item to the location and start
-    *             stocking it there.
-    * * `currency: &str` -- The three-letter code (ISO 4217 format) for the
-    *             currency used for the refund. Note: Required whenever the shipping amount property is provided.
+    * * `params: &OrdersDeprecated202101CreateParamRefundsCalculateParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202101_create_param_refunds_calculate(
+    pub async fn deprecated_202101_create_param_refunds_calculate_with(
         &self,
         order_id: &str,
-        shipping: &str,
-        refund_line_items: &str,
-        currency: &str,
+        params: &OrdersDeprecated202101CreateParamRefundsCalculateParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !refund_line_items.is_empty() {
+        if let Some(refund_line_items) = &params.refund_line_items {
             query_args.push((
                 "refund_line_items".to_string(),
                 refund_line_items.to_string(),
             ));
         }
-        if !shipping.is_empty() {
+        if let Some(shipping) = &params.shipping {
             query_args.push(("shipping".to_string(), shipping.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3492,6 +3623,27 @@ impl Orders {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_param_refunds_calculate_with` and pass the query parameters as a `OrdersDeprecated202101CreateParamRefundsCalculateParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_create_param_refunds_calculate_with` instead")]
+    pub async fn deprecated_202101_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersDeprecated202101CreateParamRefundsCalculateParams {
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            refund_line_items: Some(refund_line_items.to_string()).filter(|s| !s.is_empty()),
+            shipping: Some(shipping.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_create_param_refunds_calculate_with(order_id, &params, body)
+            .await
+    }
+
     /**
      * Retrieves a list of refunds for an order. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -3502,27 +3654,21 @@ impl Orders {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `in_shop_currency: &str` -- Show amounts in the shop currency for the underlying transaction.
-     *                     (default: false).
+     * * `params: &OrdersDeprecatedUnstableGetParamRefundParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_param_refund(
+    pub async fn deprecated_unstable_get_param_refund_with(
         &self,
         order_id: &str,
-        limit: &str,
-        fields: &str,
-        in_shop_currency: &str,
+        params: &OrdersDeprecatedUnstableGetParamRefundParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !in_shop_currency.is_empty() {
+        if let Some(in_shop_currency) = &params.in_shop_currency {
             query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3535,6 +3681,26 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_param_refund_with` and pass the query parameters as a `OrdersDeprecatedUnstableGetParamRefundParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_param_refund_with` instead")]
+    pub async fn deprecated_unstable_get_param_refund(
+        &self,
+        order_id: &str,
+        limit: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecatedUnstableGetParamRefundParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            in_shop_currency: Some(in_shop_currency.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_param_refund_with(order_id, &params)
+            .await
+    }
+
     /**
     * Caution
                 For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -3552,75 +3718,37 @@ impl Orders {
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `restock_deprecated: &str` -- Whether to add the line items back to the store inventory. Use restock_type for refund line items instead.
-    * * `notify: &str` -- Whether to send a refund notification to the customer.
-    * * `note: &str` -- An optional note attached to a refund.
-    * * `discrepancy_reason: &str` -- An optional comment that explains a discrepancy between calculated and actual refund amounts. Used to populate the reason property of the resulting order adjustment object attached to the refund. Valid values: restock, damage, customer, and other.
-    * * `shipping: &str` -- Specify how much shipping to refund. It has the following properties:
-    *
-    *                           full_refund: Whether to refund all remaining shipping.
-    *                           amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
-    * * `refund_line_items: &str` -- A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
-    *
-    *                           line_item_id: The ID of a line item to refund.
-    *                           quantity: The quantity to refund.
-    *                           restock_type:           How this refund line item affects inventory levels. Valid values:
-    *
-    *               no_restock: Refunding these items won't affect inventory.
-    *               cancel: The items have not yet been fulfilled.
-    *               The canceled quantity will be added back to the available count.
-    *               The number of fulfillable units for this line item will decrease.
-    *               return: The items were already delivered but will be returned to the merchant.
-    *               The returned quantity will be added back to the available count. The number of fulfillable units for this
-    *               line item will remain unchanged.
-    *
-    *
-    *                           location_id:           The ID of the location where the items should be
-    *             restocked. This is required when the value of restock_type is return or cancel.
-    *             If the item is not already stocked at the location, then
-    *             the item is connected to the location. An error is returned when the item is connected to
-    *             a
-    *             fulfillment service location and a different location is provided.
-    * * `transactions: &str` -- A list of transactions
-    *             to process as refunds.
-    * * `currency: &str` -- The three-letter code (ISO 4217 format) for the currency used for the refund.
+    * * `params: &OrdersDeprecatedUnstableCreateParamRefundsParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_unstable_create_param_refunds(
+    pub async fn deprecated_unstable_create_param_refunds_with(
         &self,
         order_id: &str,
-        restock_deprecated: &str,
-        notify: &str,
-        note: &str,
-        discrepancy_reason: &str,
-        shipping: &str,
-        refund_line_items: &str,
-        transactions: &str,
-        currency: &str,
+        params: &OrdersDeprecatedUnstableCreateParamRefundsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !discrepancy_reason.is_empty() {
+        if let Some(discrepancy_reason) = &params.discrepancy_reason {
             query_args.push((
                 "discrepancy_reason".to_string(),
                 discrepancy_reason.to_string(),
             ));
         }
-        if !note.is_empty() {
+        if let Some(note) = &params.note {
             query_args.push(("note".to_string(), note.to_string()));
         }
-        if !notify.is_empty() {
+        if let Some(notify) = &params.notify {
             query_args.push(("notify".to_string(), notify.to_string()));
         }
-        if !refund_line_items.is_empty() {
+        if let Some(refund_line_items) = &params.refund_line_items {
             query_args.push((
                 "refund_line_items".to_string(),
                 refund_line_items.to_string(),
             ));
         }
-        if !restock_deprecated.is_empty() {
+        if let Some(restock_deprecated) = &params.restock_deprecated {
             query_args.push((
                 "restock
                   deprecated"
@@ -3628,10 +3756,10 @@ impl Orders {
                 restock_deprecated.to_string(),
             ));
         }
-        if !shipping.is_empty() {
+        if let Some(shipping) = &params.shipping {
             query_args.push(("shipping".to_string(), shipping.to_string()));
         }
-        if !transactions.is_empty() {
+        if let Some(transactions) = &params.transactions {
             query_args.push(("transactions".to_string(), transactions.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3647,7 +3775,38 @@ impl Orders {
     }
 
     /**
-     * Retrieves a specific refund.
+     * Deprecated: use `deprecated_unstable_create_param_refunds_with` and pass the query parameters as a `OrdersDeprecatedUnstableCreateParamRefundsParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_create_param_refunds_with` instead")]
+    pub async fn deprecated_unstable_create_param_refunds(
+        &self,
+        order_id: &str,
+        restock_deprecated: &str,
+        notify: &str,
+        note: &str,
+        discrepancy_reason: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        transactions: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersDeprecatedUnstableCreateParamRefundsParams {
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            discrepancy_reason: Some(discrepancy_reason.to_string()).filter(|s| !s.is_empty()),
+            note: Some(note.to_string()).filter(|s| !s.is_empty()),
+            notify: Some(notify.to_string()).filter(|s| !s.is_empty()),
+            refund_line_items: Some(refund_line_items.to_string()).filter(|s| !s.is_empty()),
+            restock_deprecated: Some(restock_deprecated.to_string()).filter(|s| !s.is_empty()),
+            shipping: Some(shipping.to_string()).filter(|s| !s.is_empty()),
+            transactions: Some(transactions.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_create_param_refunds_with(order_id, &params, body)
+            .await
+    }
+
+    /**
+     * Retrieves a specific refund.
      *
      * This function performs a `GET` to the `/admin/api/unstable/orders/{order_id}/refunds/{refund_id}.json` endpoint.
      *
@@ -3657,22 +3816,19 @@ impl Orders {
      *
      * * `order_id: &str` -- storefront_access_token_id.
      * * `refund_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `in_shop_currency: &str` -- Show amounts in the shop currency for the underlying transaction.
-     *                     (default: false).
+     * * `params: &OrdersDeprecatedUnstableGetParamRefundsRefundParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_param_refunds_refund(
+    pub async fn deprecated_unstable_get_param_refunds_refund_with(
         &self,
         order_id: &str,
         refund_id: &str,
-        fields: &str,
-        in_shop_currency: &str,
+        params: &OrdersDeprecatedUnstableGetParamRefundsRefundParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !in_shop_currency.is_empty() {
+        if let Some(in_shop_currency) = &params.in_shop_currency {
             query_args.push(("in_shop_currency".to_string(), in_shop_currency.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3686,6 +3842,25 @@ impl Orders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_param_refunds_refund_with` and pass the query parameters as a `OrdersDeprecatedUnstableGetParamRefundsRefundParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_param_refunds_refund_with` instead")]
+    pub async fn deprecated_unstable_get_param_refunds_refund(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        fields: &str,
+        in_shop_currency: &str,
+    ) -> Result<()> {
+        let params = OrdersDeprecatedUnstableGetParamRefundsRefundParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            in_shop_currency: Some(in_shop_currency.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_param_refunds_refund_with(order_id, refund_id, &params)
+            .await
+    }
+
     /**
     * Caution
               For multi-currency orders, the currency property is required whenever the amount property is provided. For more information, see Migrating to support multiple currencies.
@@ -3705,54 +3880,25 @@ impl Orders {
     * **Parameters:**
     *
     * * `order_id: &str` -- storefront_access_token_id.
-    * * `shipping: &str` -- Specify how much shipping to refund. It has the following properties:
-    *
-    *                           full_refund: Whether to refund all remaining shipping.
-    *                           amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
-    * * `refund_line_items: &str` -- A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
-    *
-    *                           line_item_id: The ID of a line item to refund.
-    *                           quantity: The quantity to refund.
-    *                           restock_type:           How this refund line item affects inventory levels. Valid values:
-    *
-    *               no_restock: Refunding these items won't affect inventory.
-    *               cancel: The items have not yet been fulfilled. The canceled quantity will be added
-    *               back to the available count. The number of fulfillable units for this line item will decrease.
-    *               return: The items were already delivered but will be returned to the merchant.
-    *               The returned quantity will be added back to the available count.
-    *               The number of fulfillable units for this line item will remain unchanged.
-    *
-    *
-    *                           location_id:           The ID of the location
-    *             where the items should be restocked. If location_id is not provided and the value of
-    *             restock_type is return or cancel, then the endpoint returns a suitable
-    *             location ID.
-    *
-    *                           already_stocked:           Whether the item is already stocked at
-    *             the location. If this is false, then creating the refund will connect the item to the location and start
-    *             stocking it there.
-    * * `currency: &str` -- The three-letter code (ISO 4217 format) for the
-    *             currency used for the refund. Note: Required whenever the shipping amount property is provided.
+    * * `params: &OrdersDeprecatedUnstableCreateParamRefundsCalculateParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_unstable_create_param_refunds_calculate(
+    pub async fn deprecated_unstable_create_param_refunds_calculate_with(
         &self,
         order_id: &str,
-        shipping: &str,
-        refund_line_items: &str,
-        currency: &str,
+        params: &OrdersDeprecatedUnstableCreateParamRefundsCalculateParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !refund_line_items.is_empty() {
+        if let Some(refund_line_items) = &params.refund_line_items {
             query_args.push((
                 "refund_line_items".to_string(),
                 refund_line_items.to_string(),
             ));
         }
-        if !shipping.is_empty() {
+        if let Some(shipping) = &params.shipping {
             query_args.push(("shipping".to_string(), shipping.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3766,4 +3912,3295 @@ impl Orders {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `deprecated_unstable_create_param_refunds_calculate_with` and pass the query parameters as a `OrdersDeprecatedUnstableCreateParamRefundsCalculateParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_create_param_refunds_calculate_with` instead")]
+    pub async fn deprecated_unstable_create_param_refunds_calculate(
+        &self,
+        order_id: &str,
+        shipping: &str,
+        refund_line_items: &str,
+        currency: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = OrdersDeprecatedUnstableCreateParamRefundsCalculateParams {
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            refund_line_items: Some(refund_line_items.to_string()).filter(|s| !s.is_empty()),
+            shipping: Some(shipping.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_create_param_refunds_calculate_with(order_id, &params, body)
+            .await
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202001_get_checkouts_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202001GetCheckoutsCountParams {
+    /// Count checkouts created before the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count checkouts created after the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Count checkouts with a given status.
+    /// (default: open)
+    ///
+    /// open: Count only open abandoned checkouts.
+    /// closed: Count only closed abandoned checkouts.
+    pub status: Option<String>,
+    /// Count checkouts last updated before the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count checkouts last updated after the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OrdersDeprecated202001GetCheckoutsCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202004_get_checkouts_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202004GetCheckoutsCountParams {
+    /// Count checkouts created before the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count checkouts created after the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Count checkouts with a given status.
+    /// (default: open)
+    ///
+    /// open: Count only open abandoned checkouts.
+    /// closed: Count only closed abandoned checkouts.
+    pub status: Option<String>,
+    /// Count checkouts last updated before the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count checkouts last updated after the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OrdersDeprecated202004GetCheckoutsCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202007_get_checkouts_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202007GetCheckoutsCountParams {
+    /// Count checkouts created before the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count checkouts created after the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Count checkouts with a given status.
+    /// (default: open)
+    ///
+    /// open: Count only open abandoned checkouts.
+    /// closed: Count only closed abandoned checkouts.
+    pub status: Option<String>,
+    /// Count checkouts last updated before the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count checkouts last updated after the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OrdersDeprecated202007GetCheckoutsCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::get_checkouts_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersGetCheckoutsCountParams {
+    /// Count checkouts created before the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count checkouts created after the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Count checkouts with a given status.
+    /// (default: open)
+    ///
+    /// open: Count only open abandoned checkouts.
+    /// closed: Count only closed abandoned checkouts.
+    pub status: Option<String>,
+    /// Count checkouts last updated before the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count checkouts last updated after the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OrdersGetCheckoutsCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202101_get_checkouts_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202101GetCheckoutsCountParams {
+    /// Count checkouts created before the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count checkouts created after the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Count checkouts with a given status.
+    /// (default: open)
+    ///
+    /// open: Count only open abandoned checkouts.
+    /// closed: Count only closed abandoned checkouts.
+    pub status: Option<String>,
+    /// Count checkouts last updated before the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count checkouts last updated after the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OrdersDeprecated202101GetCheckoutsCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_unstable_get_checkouts_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecatedUnstableGetCheckoutsCountParams {
+    /// Count checkouts created before the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count checkouts created after the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Count checkouts with a given status.
+    /// (default: open)
+    ///
+    /// open: Count only open abandoned checkouts.
+    /// closed: Count only closed abandoned checkouts.
+    pub status: Option<String>,
+    /// Count checkouts last updated before the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count checkouts last updated after the specified date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OrdersDeprecatedUnstableGetCheckoutsCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202001_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202001GetParams {
+    /// Show orders attributed to a certain app, specified by the app ID. Set as current to show orders for the app currently consuming the API.
+    pub attribution_app_id: Option<String>,
+    /// Show orders created at or before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show orders created at or after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Retrieve only certain fields, specified by a comma-separated list of fields names.
+    pub fields: Option<String>,
+    /// Filter orders by their financial status.
+    /// (default: any)
+    ///
+    /// authorized: Show only authorized orders
+    /// pending: Show only pending orders
+    /// paid: Show only paid orders
+    /// partially_paid: Show only partially paid orders
+    /// refunded: Show only refunded orders
+    /// voided: Show only voided orders
+    /// partially_refunded: Show only partially refunded orders
+    /// any: Show orders of any financial status.
+    /// unpaid: Show authorized and partially paid orders.
+    pub financial_status: Option<String>,
+    /// Filter orders by their fulfillment status.
+    /// (default: any)
+    ///
+    /// shipped: Show orders that have been shipped. Returns orders with fulfillment_status of fulfilled.
+    /// partial: Show partially shipped orders.
+    /// unshipped: Show orders that have not yet been shipped. Returns orders with fulfillment_status of null.
+    /// any: Show orders of any fulfillment status.
+    /// unfulfilled: Returns orders with fulfillment_status of null or partial.
+    pub fulfillment_status: Option<String>,
+    /// Retrieve only orders specified by a comma-separated list of order IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to show on a page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show orders imported at or before date (format: 2014-04-25T16:15:47-04:00).
+    pub processed_at_max: Option<String>,
+    /// Show orders imported at or after date (format: 2014-04-25T16:15:47-04:00).
+    pub processed_at_min: Option<String>,
+    /// Show orders after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter orders by their status.
+    /// (default: open)
+    ///
+    /// open: Show only open orders.
+    /// closed: Show only closed orders.
+    /// cancelled: Show only canceled orders.
+    /// any: Show orders of any status, including archived orders.
+    pub status: Option<String>,
+    /// Show orders last updated at or before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show orders last updated at or after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OrdersDeprecated202001GetParams {
+    pub fn attribution_app_id<T: ToString>(mut self, attribution_app_id: T) -> Self {
+        self.attribution_app_id = Some(attribution_app_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn financial_status<T: ToString>(mut self, financial_status: T) -> Self {
+        self.financial_status = Some(financial_status.to_string());
+        self
+    }
+
+    pub fn fulfillment_status<T: ToString>(mut self, fulfillment_status: T) -> Self {
+        self.fulfillment_status = Some(fulfillment_status.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn processed_at_max<T: ToString>(mut self, processed_at_max: T) -> Self {
+        self.processed_at_max = Some(processed_at_max.to_string());
+        self
+    }
+
+    pub fn processed_at_min<T: ToString>(mut self, processed_at_min: T) -> Self {
+        self.processed_at_min = Some(processed_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202001_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202001GetParamParams {
+    /// Retrieve only certain fields, specified by a comma-separated list of fields names.
+    pub fields: Option<String>,
+}
+
+impl OrdersDeprecated202001GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202001_get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202001GetCountParams {
+    /// Count orders created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count orders created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count orders of a given financial status.
+    /// (default: any)
+    ///
+    /// authorized: Count authorized orders.
+    /// pending: Count pending orders.
+    /// paid: Count paid orders.
+    /// refunded: Count refunded orders.
+    /// voided: Count voided orders.
+    /// any: Count orders of any financial status.
+    pub financial_status: Option<String>,
+    /// Filter orders by their fulfillment status.
+    /// (default: any)
+    ///
+    /// shipped: Show orders that have been shipped. Returns orders with fulfillment_status of fulfilled.
+    /// partial: Show partially shipped orders.
+    /// unshipped: Show orders that have not yet been shipped. Returns orders with fulfillment_status of null.
+    /// any: Show orders of any fulfillment status.
+    /// unfulfilled: Returns orders with fulfillment_status of null or partial.
+    pub fulfillment_status: Option<String>,
+    /// Count orders of a given status.
+    /// (default: open)
+    ///
+    /// open: Count open orders.
+    /// closed: Count closed orders.
+    /// any: Count orders of any status.
+    pub status: Option<String>,
+    /// Count orders last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count orders last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OrdersDeprecated202001GetCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn financial_status<T: ToString>(mut self, financial_status: T) -> Self {
+        self.financial_status = Some(financial_status.to_string());
+        self
+    }
+
+    pub fn fulfillment_status<T: ToString>(mut self, fulfillment_status: T) -> Self {
+        self.fulfillment_status = Some(fulfillment_status.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202001_create_param_cancel_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202001CreateParamCancelParams {
+    /// The amount to refund. If set, Shopify attempts to void or refund the payment, depending on its status. Shopify refunds through a manual gateway in cases where the original transaction was not made in Shopify. Refunds through a manual gateway are recorded as a refund on Shopify, but the customer is not refunded.
+    pub amount: Option<String>,
+    /// The currency of the refund that's issued when the order is canceled. Required for multi-currency orders whenever the amount property is provided.
+    pub currency: Option<String>,
+    /// Whether to send an email to the customer notifying them of the cancellation.
+    /// (default: false).
+    pub email: Option<String>,
+    /// The reason for the order cancellation. Valid values: customer, inventory, fraud, declined, and other.)
+    /// (default: other).
+    pub reason: Option<String>,
+    /// The refund transactions to perform. Required for some more complex refund situations. For more information, see the Refund API.
+    pub refund: Option<String>,
+    /// Whether to restock refunded items back to your store's inventory.
+    /// (default: false).
+    pub restock_deprecated: Option<String>,
+}
+
+impl OrdersDeprecated202001CreateParamCancelParams {
+    pub fn amount<T: ToString>(mut self, amount: T) -> Self {
+        self.amount = Some(amount.to_string());
+        self
+    }
+
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn email<T: ToString>(mut self, email: T) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+
+    pub fn reason<T: ToString>(mut self, reason: T) -> Self {
+        self.reason = Some(reason.to_string());
+        self
+    }
+
+    pub fn refund<T: ToString>(mut self, refund: T) -> Self {
+        self.refund = Some(refund.to_string());
+        self
+    }
+
+    pub fn restock_deprecated<T: ToString>(mut self, restock_deprecated: T) -> Self {
+        self.restock_deprecated = Some(restock_deprecated.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202004_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202004GetParams {
+    /// Show orders attributed to a certain app, specified by the app ID. Set as current to show orders for the app currently consuming the API.
+    pub attribution_app_id: Option<String>,
+    /// Show orders created at or before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show orders created at or after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Retrieve only certain fields, specified by a comma-separated list of fields names.
+    pub fields: Option<String>,
+    /// Filter orders by their financial status.
+    /// (default: any)
+    ///
+    /// authorized: Show only authorized orders
+    /// pending: Show only pending orders
+    /// paid: Show only paid orders
+    /// partially_paid: Show only partially paid orders
+    /// refunded: Show only refunded orders
+    /// voided: Show only voided orders
+    /// partially_refunded: Show only partially refunded orders
+    /// any: Show orders of any financial status.
+    /// unpaid: Show authorized and partially paid orders.
+    pub financial_status: Option<String>,
+    /// Filter orders by their fulfillment status.
+    /// (default: any)
+    ///
+    /// shipped: Show orders that have been shipped. Returns orders with fulfillment_status of fulfilled.
+    /// partial: Show partially shipped orders.
+    /// unshipped: Show orders that have not yet been shipped. Returns orders with fulfillment_status of null.
+    /// any: Show orders of any fulfillment status.
+    /// unfulfilled: Returns orders with fulfillment_status of null or partial.
+    pub fulfillment_status: Option<String>,
+    /// Retrieve only orders specified by a comma-separated list of order IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to show on a page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show orders imported at or before date (format: 2014-04-25T16:15:47-04:00).
+    pub processed_at_max: Option<String>,
+    /// Show orders imported at or after date (format: 2014-04-25T16:15:47-04:00).
+    pub processed_at_min: Option<String>,
+    /// Show orders after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter orders by their status.
+    /// (default: open)
+    ///
+    /// open: Show only open orders.
+    /// closed: Show only closed orders.
+    /// cancelled: Show only canceled orders.
+    /// any: Show orders of any status, including archived orders.
+    pub status: Option<String>,
+    /// Show orders last updated at or before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show orders last updated at or after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OrdersDeprecated202004GetParams {
+    pub fn attribution_app_id<T: ToString>(mut self, attribution_app_id: T) -> Self {
+        self.attribution_app_id = Some(attribution_app_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn financial_status<T: ToString>(mut self, financial_status: T) -> Self {
+        self.financial_status = Some(financial_status.to_string());
+        self
+    }
+
+    pub fn fulfillment_status<T: ToString>(mut self, fulfillment_status: T) -> Self {
+        self.fulfillment_status = Some(fulfillment_status.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn processed_at_max<T: ToString>(mut self, processed_at_max: T) -> Self {
+        self.processed_at_max = Some(processed_at_max.to_string());
+        self
+    }
+
+    pub fn processed_at_min<T: ToString>(mut self, processed_at_min: T) -> Self {
+        self.processed_at_min = Some(processed_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202004_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202004GetParamParams {
+    /// Retrieve only certain fields, specified by a comma-separated list of fields names.
+    pub fields: Option<String>,
+}
+
+impl OrdersDeprecated202004GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202004_get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202004GetCountParams {
+    /// Count orders created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count orders created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count orders of a given financial status.
+    /// (default: any)
+    ///
+    /// authorized: Count authorized orders.
+    /// pending: Count pending orders.
+    /// paid: Count paid orders.
+    /// refunded: Count refunded orders.
+    /// voided: Count voided orders.
+    /// any: Count orders of any financial status.
+    pub financial_status: Option<String>,
+    /// Filter orders by their fulfillment status.
+    /// (default: any)
+    ///
+    /// shipped: Show orders that have been shipped. Returns orders with fulfillment_status of fulfilled.
+    /// partial: Show partially shipped orders.
+    /// unshipped: Show orders that have not yet been shipped. Returns orders with fulfillment_status of null.
+    /// any: Show orders of any fulfillment status.
+    /// unfulfilled: Returns orders with fulfillment_status of null or partial.
+    pub fulfillment_status: Option<String>,
+    /// Count orders of a given status.
+    /// (default: open)
+    ///
+    /// open: Count open orders.
+    /// closed: Count closed orders.
+    /// any: Count orders of any status.
+    pub status: Option<String>,
+    /// Count orders last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count orders last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OrdersDeprecated202004GetCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn financial_status<T: ToString>(mut self, financial_status: T) -> Self {
+        self.financial_status = Some(financial_status.to_string());
+        self
+    }
+
+    pub fn fulfillment_status<T: ToString>(mut self, fulfillment_status: T) -> Self {
+        self.fulfillment_status = Some(fulfillment_status.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202004_create_param_cancel_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202004CreateParamCancelParams {
+    /// The amount to refund. If set, Shopify attempts to void or refund the payment, depending on its status. Shopify refunds through a manual gateway in cases where the original transaction was not made in Shopify. Refunds through a manual gateway are recorded as a refund on Shopify, but the customer is not refunded.
+    pub amount: Option<String>,
+    /// The currency of the refund that's issued when the order is canceled. Required for multi-currency orders whenever the amount property is provided.
+    pub currency: Option<String>,
+    /// Whether to send an email to the customer notifying them of the cancellation.
+    /// (default: false).
+    pub email: Option<String>,
+    /// The reason for the order cancellation. Valid values: customer, inventory, fraud, declined, and other.)
+    /// (default: other).
+    pub reason: Option<String>,
+    /// The refund transactions to perform. Required for some more complex refund situations. For more information, see the Refund API.
+    pub refund: Option<String>,
+    /// Whether to restock refunded items back to your store's inventory.
+    /// (default: false).
+    pub restock_deprecated: Option<String>,
+}
+
+impl OrdersDeprecated202004CreateParamCancelParams {
+    pub fn amount<T: ToString>(mut self, amount: T) -> Self {
+        self.amount = Some(amount.to_string());
+        self
+    }
+
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn email<T: ToString>(mut self, email: T) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+
+    pub fn reason<T: ToString>(mut self, reason: T) -> Self {
+        self.reason = Some(reason.to_string());
+        self
+    }
+
+    pub fn refund<T: ToString>(mut self, refund: T) -> Self {
+        self.refund = Some(refund.to_string());
+        self
+    }
+
+    pub fn restock_deprecated<T: ToString>(mut self, restock_deprecated: T) -> Self {
+        self.restock_deprecated = Some(restock_deprecated.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202007_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202007GetParams {
+    /// Show orders attributed to a certain app, specified by the app ID. Set as current to show orders for the app currently consuming the API.
+    pub attribution_app_id: Option<String>,
+    /// Show orders created at or before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show orders created at or after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Retrieve only certain fields, specified by a comma-separated list of fields names.
+    pub fields: Option<String>,
+    /// Filter orders by their financial status.
+    /// (default: any)
+    ///
+    /// authorized: Show only authorized orders
+    /// pending: Show only pending orders
+    /// paid: Show only paid orders
+    /// partially_paid: Show only partially paid orders
+    /// refunded: Show only refunded orders
+    /// voided: Show only voided orders
+    /// partially_refunded: Show only partially refunded orders
+    /// any: Show orders of any financial status.
+    /// unpaid: Show authorized and partially paid orders.
+    pub financial_status: Option<String>,
+    /// Filter orders by their fulfillment status.
+    /// (default: any)
+    ///
+    /// shipped: Show orders that have been shipped. Returns orders with fulfillment_status of fulfilled.
+    /// partial: Show partially shipped orders.
+    /// unshipped: Show orders that have not yet been shipped. Returns orders with fulfillment_status of null.
+    /// any: Show orders of any fulfillment status.
+    /// unfulfilled: Returns orders with fulfillment_status of null or partial.
+    pub fulfillment_status: Option<String>,
+    /// Retrieve only orders specified by a comma-separated list of order IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to show on a page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show orders imported at or before date (format: 2014-04-25T16:15:47-04:00).
+    pub processed_at_max: Option<String>,
+    /// Show orders imported at or after date (format: 2014-04-25T16:15:47-04:00).
+    pub processed_at_min: Option<String>,
+    /// Show orders after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter orders by their status.
+    /// (default: open)
+    ///
+    /// open: Show only open orders.
+    /// closed: Show only closed orders.
+    /// cancelled: Show only canceled orders.
+    /// any: Show orders of any status, including archived orders.
+    pub status: Option<String>,
+    /// Show orders last updated at or before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show orders last updated at or after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl OrdersDeprecated202007GetParams {
+    pub fn attribution_app_id<T: ToString>(mut self, attribution_app_id: T) -> Self {
+        self.attribution_app_id = Some(attribution_app_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn financial_status<T: ToString>(mut self, financial_status: T) -> Self {
+        self.financial_status = Some(financial_status.to_string());
+        self
+    }
+
+    pub fn fulfillment_status<T: ToString>(mut self, fulfillment_status: T) -> Self {
+        self.fulfillment_status = Some(fulfillment_status.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn processed_at_max<T: ToString>(mut self, processed_at_max: T) -> Self {
+        self.processed_at_max = Some(processed_at_max.to_string());
+        self
+    }
+
+    pub fn processed_at_min<T: ToString>(mut self, processed_at_min: T) -> Self {
+        self.processed_at_min = Some(processed_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202001_get_param_refund_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202001GetParamRefundParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show amounts in the shop currency for the underlying transaction.
+    /// (default: false).
+    pub in_shop_currency: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl OrdersDeprecated202001GetParamRefundParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn in_shop_currency<T: ToString>(mut self, in_shop_currency: T) -> Self {
+        self.in_shop_currency = Some(in_shop_currency.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202001_create_param_refunds_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202001CreateParamRefundsParams {
+    /// The three-letter code (ISO 4217 format) for the currency used for the refund.
+    pub currency: Option<String>,
+    /// An optional comment that explains a discrepancy between calculated and actual refund amounts. Used to populate the reason property of the resulting order adjustment object attached to the refund. Valid values: restock, damage, customer, and other.
+    pub discrepancy_reason: Option<String>,
+    /// An optional note attached to a refund.
+    pub note: Option<String>,
+    /// Whether to send a refund notification to the customer.
+    pub notify: Option<String>,
+    /// A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
+    ///
+    /// line_item_id: The ID of a line item to refund.
+    /// quantity: The quantity to refund.
+    /// restock_type:           How this refund line item affects inventory levels. Valid values:
+    ///
+    /// no_restock: Refunding these items won't affect inventory.
+    /// cancel: The items have not yet been fulfilled.
+    /// The canceled quantity will be added back to the available count.
+    /// The number of fulfillable units for this line item will decrease.
+    /// return: The items were already delivered but will be returned to the merchant.
+    /// The returned quantity will be added back to the available count. The number of fulfillable units for this
+    /// line item will remain unchanged.
+    ///
+    ///
+    /// location_id:           The ID of the location where the items should be
+    /// restocked. This is required when the value of restock_type is return or cancel.
+    /// If the item is not already stocked at the location, then
+    /// the item is connected to the location. An error is returned when the item is connected to
+    /// a
+    /// fulfillment service location and a different location is provided.
+    pub refund_line_items: Option<String>,
+    /// Whether to add the line items back to the store inventory. Use restock_type for refund line items instead.
+    pub restock_deprecated: Option<String>,
+    /// Specify how much shipping to refund. It has the following properties:
+    ///
+    /// full_refund: Whether to refund all remaining shipping.
+    /// amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
+    pub shipping: Option<String>,
+    /// A list of transactions
+    /// to process as refunds.
+    pub transactions: Option<String>,
+}
+
+impl OrdersDeprecated202001CreateParamRefundsParams {
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn discrepancy_reason<T: ToString>(mut self, discrepancy_reason: T) -> Self {
+        self.discrepancy_reason = Some(discrepancy_reason.to_string());
+        self
+    }
+
+    pub fn note<T: ToString>(mut self, note: T) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+
+    pub fn notify<T: ToString>(mut self, notify: T) -> Self {
+        self.notify = Some(notify.to_string());
+        self
+    }
+
+    pub fn refund_line_items<T: ToString>(mut self, refund_line_items: T) -> Self {
+        self.refund_line_items = Some(refund_line_items.to_string());
+        self
+    }
+
+    pub fn restock_deprecated<T: ToString>(mut self, restock_deprecated: T) -> Self {
+        self.restock_deprecated = Some(restock_deprecated.to_string());
+        self
+    }
+
+    pub fn shipping<T: ToString>(mut self, shipping: T) -> Self {
+        self.shipping = Some(shipping.to_string());
+        self
+    }
+
+    pub fn transactions<T: ToString>(mut self, transactions: T) -> Self {
+        self.transactions = Some(transactions.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202001_get_param_refunds_refund_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202001GetParamRefundsRefundParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show amounts in the shop currency for the underlying transaction.
+    /// (default: false).
+    pub in_shop_currency: Option<String>,
+}
+
+impl OrdersDeprecated202001GetParamRefundsRefundParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn in_shop_currency<T: ToString>(mut self, in_shop_currency: T) -> Self {
+        self.in_shop_currency = Some(in_shop_currency.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202001_create_param_refunds_calculate_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202001CreateParamRefundsCalculateParams {
+    /// The three-letter code (ISO 4217 format) for the
+    /// currency used for the refund. Note: Required whenever the shipping amount property is provided.
+    pub currency: Option<String>,
+    /// A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
+    ///
+    /// line_item_id: The ID of a line item to refund.
+    /// quantity: The quantity to refund.
+    /// restock_type:           How this refund line item affects inventory levels. Valid values:
+    ///
+    /// no_restock: Refunding these items won't affect inventory.
+    /// cancel: The items have not yet been fulfilled. The canceled quantity will be added
+    /// back to the available count. The number of fulfillable units for this line item will decrease.
+    /// return: The items were already delivered but will be returned to the merchant.
+    /// The returned quantity will be added back to the available count.
+    /// The number of fulfillable units for this line item will remain unchanged.
+    ///
+    ///
+    /// location_id:           The ID of the location
+    /// where the items should be restocked. If location_id is not provided and the value of
+    /// restock_type is return or cancel, then the endpoint returns a suitable
+    /// location ID.
+    ///
+    /// already_stocked:           Whether the item is already stocked at
+    /// the location. If this is false, then creating the refund will connect the item to the location and start
+    /// stocking it there.
+    pub refund_line_items: Option<String>,
+    /// Specify how much shipping to refund. It has the following properties:
+    ///
+    /// full_refund: Whether to refund all remaining shipping.
+    /// amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
+    pub shipping: Option<String>,
+}
+
+impl OrdersDeprecated202001CreateParamRefundsCalculateParams {
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn refund_line_items<T: ToString>(mut self, refund_line_items: T) -> Self {
+        self.refund_line_items = Some(refund_line_items.to_string());
+        self
+    }
+
+    pub fn shipping<T: ToString>(mut self, shipping: T) -> Self {
+        self.shipping = Some(shipping.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202004_get_param_refund_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202004GetParamRefundParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show amounts in the shop currency for the underlying transaction.
+    /// (default: false).
+    pub in_shop_currency: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl OrdersDeprecated202004GetParamRefundParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn in_shop_currency<T: ToString>(mut self, in_shop_currency: T) -> Self {
+        self.in_shop_currency = Some(in_shop_currency.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202004_create_param_refunds_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202004CreateParamRefundsParams {
+    /// The three-letter code (ISO 4217 format) for the currency used for the refund.
+    pub currency: Option<String>,
+    /// An optional comment that explains a discrepancy between calculated and actual refund amounts. Used to populate the reason property of the resulting order adjustment object attached to the refund. Valid values: restock, damage, customer, and other.
+    pub discrepancy_reason: Option<String>,
+    /// An optional note attached to a refund.
+    pub note: Option<String>,
+    /// Whether to send a refund notification to the customer.
+    pub notify: Option<String>,
+    /// A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
+    ///
+    /// line_item_id: The ID of a line item to refund.
+    /// quantity: The quantity to refund.
+    /// restock_type:           How this refund line item affects inventory levels. Valid values:
+    ///
+    /// no_restock: Refunding these items won't affect inventory.
+    /// cancel: The items have not yet been fulfilled.
+    /// The canceled quantity will be added back to the available count.
+    /// The number of fulfillable units for this line item will decrease.
+    /// return: The items were already delivered but will be returned to the merchant.
+    /// The returned quantity will be added back to the available count. The number of fulfillable units for this
+    /// line item will remain unchanged.
+    ///
+    ///
+    /// location_id:           The ID of the location where the items should be
+    /// restocked. This is required when the value of restock_type is return or cancel.
+    /// If the item is not already stocked at the location, then
+    /// the item is connected to the location. An error is returned when the item is connected to
+    /// a
+    /// fulfillment service location and a different location is provided.
+    pub refund_line_items: Option<String>,
+    /// Whether to add the line items back to the store inventory. Use restock_type for refund line items instead.
+    pub restock_deprecated: Option<String>,
+    /// Specify how much shipping to refund. It has the following properties:
+    ///
+    /// full_refund: Whether to refund all remaining shipping.
+    /// amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
+    pub shipping: Option<String>,
+    /// A list of transactions
+    /// to process as refunds.
+    pub transactions: Option<String>,
+}
+
+impl OrdersDeprecated202004CreateParamRefundsParams {
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn discrepancy_reason<T: ToString>(mut self, discrepancy_reason: T) -> Self {
+        self.discrepancy_reason = Some(discrepancy_reason.to_string());
+        self
+    }
+
+    pub fn note<T: ToString>(mut self, note: T) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+
+    pub fn notify<T: ToString>(mut self, notify: T) -> Self {
+        self.notify = Some(notify.to_string());
+        self
+    }
+
+    pub fn refund_line_items<T: ToString>(mut self, refund_line_items: T) -> Self {
+        self.refund_line_items = Some(refund_line_items.to_string());
+        self
+    }
+
+    pub fn restock_deprecated<T: ToString>(mut self, restock_deprecated: T) -> Self {
+        self.restock_deprecated = Some(restock_deprecated.to_string());
+        self
+    }
+
+    pub fn shipping<T: ToString>(mut self, shipping: T) -> Self {
+        self.shipping = Some(shipping.to_string());
+        self
+    }
+
+    pub fn transactions<T: ToString>(mut self, transactions: T) -> Self {
+        self.transactions = Some(transactions.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202004_get_param_refunds_refund_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202004GetParamRefundsRefundParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show amounts in the shop currency for the underlying transaction.
+    /// (default: false).
+    pub in_shop_currency: Option<String>,
+}
+
+impl OrdersDeprecated202004GetParamRefundsRefundParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn in_shop_currency<T: ToString>(mut self, in_shop_currency: T) -> Self {
+        self.in_shop_currency = Some(in_shop_currency.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202004_create_param_refunds_calculate_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202004CreateParamRefundsCalculateParams {
+    /// The three-letter code (ISO 4217 format) for the
+    /// currency used for the refund. Note: Required whenever the shipping amount property is provided.
+    pub currency: Option<String>,
+    /// A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
+    ///
+    /// line_item_id: The ID of a line item to refund.
+    /// quantity: The quantity to refund.
+    /// restock_type:           How this refund line item affects inventory levels. Valid values:
+    ///
+    /// no_restock: Refunding these items won't affect inventory.
+    /// cancel: The items have not yet been fulfilled. The canceled quantity will be added
+    /// back to the available count. The number of fulfillable units for this line item will decrease.
+    /// return: The items were already delivered but will be returned to the merchant.
+    /// The returned quantity will be added back to the available count.
+    /// The number of fulfillable units for this line item will remain unchanged.
+    ///
+    ///
+    /// location_id:           The ID of the location
+    /// where the items should be restocked. If location_id is not provided and the value of
+    /// restock_type is return or cancel, then the endpoint returns a suitable
+    /// location ID.
+    ///
+    /// already_stocked:           Whether the item is already stocked at
+    /// the location. If this is false, then creating the refund will connect the item to the location and start
+    /// stocking it there.
+    pub refund_line_items: Option<String>,
+    /// Specify how much shipping to refund. It has the following properties:
+    ///
+    /// full_refund: Whether to refund all remaining shipping.
+    /// amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
+    pub shipping: Option<String>,
+}
+
+impl OrdersDeprecated202004CreateParamRefundsCalculateParams {
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn refund_line_items<T: ToString>(mut self, refund_line_items: T) -> Self {
+        self.refund_line_items = Some(refund_line_items.to_string());
+        self
+    }
+
+    pub fn shipping<T: ToString>(mut self, shipping: T) -> Self {
+        self.shipping = Some(shipping.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202007_get_param_refund_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202007GetParamRefundParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show amounts in the shop currency for the underlying transaction.
+    /// (default: false).
+    pub in_shop_currency: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl OrdersDeprecated202007GetParamRefundParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn in_shop_currency<T: ToString>(mut self, in_shop_currency: T) -> Self {
+        self.in_shop_currency = Some(in_shop_currency.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202007_create_param_refunds_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202007CreateParamRefundsParams {
+    /// The three-letter code (ISO 4217 format) for the currency used for the refund.
+    pub currency: Option<String>,
+    /// An optional comment that explains a discrepancy between calculated and actual refund amounts. Used to populate the reason property of the resulting order adjustment object attached to the refund. Valid values: restock, damage, customer, and other.
+    pub discrepancy_reason: Option<String>,
+    /// An optional note attached to a refund.
+    pub note: Option<String>,
+    /// Whether to send a refund notification to the customer.
+    pub notify: Option<String>,
+    /// A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
+    ///
+    /// line_item_id: The ID of a line item to refund.
+    /// quantity: The quantity to refund.
+    /// restock_type:           How this refund line item affects inventory levels. Valid values:
+    ///
+    /// no_restock: Refunding these items won't affect inventory.
+    /// cancel: The items have not yet been fulfilled.
+    /// The canceled quantity will be added back to the available count.
+    /// The number of fulfillable units for this line item will decrease.
+    /// return: The items were already delivered but will be returned to the merchant.
+    /// The returned quantity will be added back to the available count. The number of fulfillable units for this
+    /// line item will remain unchanged.
+    ///
+    ///
+    /// location_id:           The ID of the location where the items should be
+    /// restocked. This is required when the value of restock_type is return or cancel.
+    /// If the item is not already stocked at the location, then
+    /// the item is connected to the location. An error is returned when the item is connected to
+    /// a
+    /// fulfillment service location and a different location is provided.
+    pub refund_line_items: Option<String>,
+    /// Whether to add the line items back to the store inventory. Use restock_type for refund line items instead.
+    pub restock_deprecated: Option<String>,
+    /// Specify how much shipping to refund. It has the following properties:
+    ///
+    /// full_refund: Whether to refund all remaining shipping.
+    /// amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
+    pub shipping: Option<String>,
+    /// A list of transactions
+    /// to process as refunds.
+    pub transactions: Option<String>,
+}
+
+impl OrdersDeprecated202007CreateParamRefundsParams {
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn discrepancy_reason<T: ToString>(mut self, discrepancy_reason: T) -> Self {
+        self.discrepancy_reason = Some(discrepancy_reason.to_string());
+        self
+    }
+
+    pub fn note<T: ToString>(mut self, note: T) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+
+    pub fn notify<T: ToString>(mut self, notify: T) -> Self {
+        self.notify = Some(notify.to_string());
+        self
+    }
+
+    pub fn refund_line_items<T: ToString>(mut self, refund_line_items: T) -> Self {
+        self.refund_line_items = Some(refund_line_items.to_string());
+        self
+    }
+
+    pub fn restock_deprecated<T: ToString>(mut self, restock_deprecated: T) -> Self {
+        self.restock_deprecated = Some(restock_deprecated.to_string());
+        self
+    }
+
+    pub fn shipping<T: ToString>(mut self, shipping: T) -> Self {
+        self.shipping = Some(shipping.to_string());
+        self
+    }
+
+    pub fn transactions<T: ToString>(mut self, transactions: T) -> Self {
+        self.transactions = Some(transactions.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202007_get_param_refunds_refund_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202007GetParamRefundsRefundParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show amounts in the shop currency for the underlying transaction.
+    /// (default: false).
+    pub in_shop_currency: Option<String>,
+}
+
+impl OrdersDeprecated202007GetParamRefundsRefundParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn in_shop_currency<T: ToString>(mut self, in_shop_currency: T) -> Self {
+        self.in_shop_currency = Some(in_shop_currency.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202007_create_param_refunds_calculate_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202007CreateParamRefundsCalculateParams {
+    /// The three-letter code (ISO 4217 format) for the
+    /// currency used for the refund. Note: Required whenever the shipping amount property is provided.
+    pub currency: Option<String>,
+    /// A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
+    ///
+    /// line_item_id: The ID of a line item to refund.
+    /// quantity: The quantity to refund.
+    /// restock_type:           How this refund line item affects inventory levels. Valid values:
+    ///
+    /// no_restock: Refunding these items won't affect inventory.
+    /// cancel: The items have not yet been fulfilled. The canceled quantity will be added
+    /// back to the available count. The number of fulfillable units for this line item will decrease.
+    /// return: The items were already delivered but will be returned to the merchant.
+    /// The returned quantity will be added back to the available count.
+    /// The number of fulfillable units for this line item will remain unchanged.
+    ///
+    ///
+    /// location_id:           The ID of the location
+    /// where the items should be restocked. If location_id is not provided and the value of
+    /// restock_type is return or cancel, then the endpoint returns a suitable
+    /// location ID.
+    ///
+    /// already_stocked:           Whether the item is already stocked at
+    /// the location. If this is false, then creating the refund will connect the item to the location and start
+    /// stocking it there.
+    pub refund_line_items: Option<String>,
+    /// Specify how much shipping to refund. It has the following properties:
+    ///
+    /// full_refund: Whether to refund all remaining shipping.
+    /// amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
+    pub shipping: Option<String>,
+}
+
+impl OrdersDeprecated202007CreateParamRefundsCalculateParams {
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn refund_line_items<T: ToString>(mut self, refund_line_items: T) -> Self {
+        self.refund_line_items = Some(refund_line_items.to_string());
+        self
+    }
+
+    pub fn shipping<T: ToString>(mut self, shipping: T) -> Self {
+        self.shipping = Some(shipping.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::get_param_refund_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersGetParamRefundParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show amounts in the shop currency for the underlying transaction.
+    /// (default: false).
+    pub in_shop_currency: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl OrdersGetParamRefundParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn in_shop_currency<T: ToString>(mut self, in_shop_currency: T) -> Self {
+        self.in_shop_currency = Some(in_shop_currency.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::create_param_refunds_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersCreateParamRefundsParams {
+    /// The three-letter code (ISO 4217 format) for the currency used for the refund.
+    pub currency: Option<String>,
+    /// An optional comment that explains a discrepancy between calculated and actual refund amounts. Used to populate the reason property of the resulting order adjustment object attached to the refund. Valid values: restock, damage, customer, and other.
+    pub discrepancy_reason: Option<String>,
+    /// An optional note attached to a refund.
+    pub note: Option<String>,
+    /// Whether to send a refund notification to the customer.
+    pub notify: Option<String>,
+    /// A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
+    ///
+    /// line_item_id: The ID of a line item to refund.
+    /// quantity: The quantity to refund.
+    /// restock_type:           How this refund line item affects inventory levels. Valid values:
+    ///
+    /// no_restock: Refunding these items won't affect inventory.
+    /// cancel: The items have not yet been fulfilled.
+    /// The canceled quantity will be added back to the available count.
+    /// The number of fulfillable units for this line item will decrease.
+    /// return: The items were already delivered but will be returned to the merchant.
+    /// The returned quantity will be added back to the available count. The number of fulfillable units for this
+    /// line item will remain unchanged.
+    ///
+    ///
+    /// location_id:           The ID of the location where the items should be
+    /// restocked. This is required when the value of restock_type is return or cancel.
+    /// If the item is not already stocked at the location, then
+    /// the item is connected to the location. An error is returned when the item is connected to
+    /// a
+    /// fulfillment service location and a different location is provided.
+    pub refund_line_items: Option<String>,
+    /// Whether to add the line items back to the store inventory. Use restock_type for refund line items instead.
+    pub restock_deprecated: Option<String>,
+    /// Specify how much shipping to refund. It has the following properties:
+    ///
+    /// full_refund: Whether to refund all remaining shipping.
+    /// amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
+    pub shipping: Option<String>,
+    /// A list of transactions
+    /// to process as refunds.
+    pub transactions: Option<String>,
+}
+
+impl OrdersCreateParamRefundsParams {
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn discrepancy_reason<T: ToString>(mut self, discrepancy_reason: T) -> Self {
+        self.discrepancy_reason = Some(discrepancy_reason.to_string());
+        self
+    }
+
+    pub fn note<T: ToString>(mut self, note: T) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+
+    pub fn notify<T: ToString>(mut self, notify: T) -> Self {
+        self.notify = Some(notify.to_string());
+        self
+    }
+
+    pub fn refund_line_items<T: ToString>(mut self, refund_line_items: T) -> Self {
+        self.refund_line_items = Some(refund_line_items.to_string());
+        self
+    }
+
+    pub fn restock_deprecated<T: ToString>(mut self, restock_deprecated: T) -> Self {
+        self.restock_deprecated = Some(restock_deprecated.to_string());
+        self
+    }
+
+    pub fn shipping<T: ToString>(mut self, shipping: T) -> Self {
+        self.shipping = Some(shipping.to_string());
+        self
+    }
+
+    pub fn transactions<T: ToString>(mut self, transactions: T) -> Self {
+        self.transactions = Some(transactions.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::get_param_refunds_refund_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersGetParamRefundsRefundParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show amounts in the shop currency for the underlying transaction.
+    /// (default: false).
+    pub in_shop_currency: Option<String>,
+}
+
+impl OrdersGetParamRefundsRefundParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn in_shop_currency<T: ToString>(mut self, in_shop_currency: T) -> Self {
+        self.in_shop_currency = Some(in_shop_currency.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::create_param_refunds_calculate_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersCreateParamRefundsCalculateParams {
+    /// The three-letter code (ISO 4217 format) for the
+    /// currency used for the refund. Note: Required whenever the shipping amount property is provided.
+    pub currency: Option<String>,
+    /// A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
+    ///
+    /// line_item_id: The ID of a line item to refund.
+    /// quantity: The quantity to refund.
+    /// restock_type:           How this refund line item affects inventory levels. Valid values:
+    ///
+    /// no_restock: Refunding these items won't affect inventory.
+    /// cancel: The items have not yet been fulfilled. The canceled quantity will be added
+    /// back to the available count. The number of fulfillable units for this line item will decrease.
+    /// return: The items were already delivered but will be returned to the merchant.
+    /// The returned quantity will be added back to the available count.
+    /// The number of fulfillable units for this line item will remain unchanged.
+    ///
+    ///
+    /// location_id:           The ID of the location
+    /// where the items should be restocked. If location_id is not provided and the value of
+    /// restock_type is return or cancel, then the endpoint returns a suitable
+    /// location ID.
+    ///
+    /// already_stocked:           Whether the item is already stocked at
+    /// the location. If this is false, then creating the refund will connect the item to the location and start
+    /// stocking it there.
+    pub refund_line_items: Option<String>,
+    /// Specify how much shipping to refund. It has the following properties:
+    ///
+    /// full_refund: Whether to refund all remaining shipping.
+    /// amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
+    pub shipping: Option<String>,
+}
+
+impl OrdersCreateParamRefundsCalculateParams {
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn refund_line_items<T: ToString>(mut self, refund_line_items: T) -> Self {
+        self.refund_line_items = Some(refund_line_items.to_string());
+        self
+    }
+
+    pub fn shipping<T: ToString>(mut self, shipping: T) -> Self {
+        self.shipping = Some(shipping.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202101_get_param_refund_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202101GetParamRefundParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show amounts in the shop currency for the underlying transaction.
+    /// (default: false).
+    pub in_shop_currency: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl OrdersDeprecated202101GetParamRefundParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn in_shop_currency<T: ToString>(mut self, in_shop_currency: T) -> Self {
+        self.in_shop_currency = Some(in_shop_currency.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202101_create_param_refunds_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202101CreateParamRefundsParams {
+    /// The three-letter code (ISO 4217 format) for the currency used for the refund.
+    pub currency: Option<String>,
+    /// An optional comment that explains a discrepancy between calculated and actual refund amounts. Used to populate the reason property of the resulting order adjustment object attached to the refund. Valid values: restock, damage, customer, and other.
+    pub discrepancy_reason: Option<String>,
+    /// An optional note attached to a refund.
+    pub note: Option<String>,
+    /// Whether to send a refund notification to the customer.
+    pub notify: Option<String>,
+    /// A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
+    ///
+    /// line_item_id: The ID of a line item to refund.
+    /// quantity: The quantity to refund.
+    /// restock_type:           How this refund line item affects inventory levels. Valid values:
+    ///
+    /// no_restock: Refunding these items won't affect inventory.
+    /// cancel: The items have not yet been fulfilled.
+    /// The canceled quantity will be added back to the available count.
+    /// The number of fulfillable units for this line item will decrease.
+    /// return: The items were already delivered but will be returned to the merchant.
+    /// The returned quantity will be added back to the available count. The number of fulfillable units for this
+    /// line item will remain unchanged.
+    ///
+    ///
+    /// location_id:           The ID of the location where the items should be
+    /// restocked. This is required when the value of restock_type is return or cancel.
+    /// If the item is not already stocked at the location, then
+    /// the item is connected to the location. An error is returned when the item is connected to
+    /// a
+    /// fulfillment service location and a different location is provided.
+    pub refund_line_items: Option<String>,
+    /// Whether to add the line items back to the store inventory. Use restock_type for refund line items instead.
+    pub restock_deprecated: Option<String>,
+    /// Specify how much shipping to refund. It has the following properties:
+    ///
+    /// full_refund: Whether to refund all remaining shipping.
+    /// amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
+    pub shipping: Option<String>,
+    /// A list of transactions
+    /// to process as refunds.
+    pub transactions: Option<String>,
+}
+
+impl OrdersDeprecated202101CreateParamRefundsParams {
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn discrepancy_reason<T: ToString>(mut self, discrepancy_reason: T) -> Self {
+        self.discrepancy_reason = Some(discrepancy_reason.to_string());
+        self
+    }
+
+    pub fn note<T: ToString>(mut self, note: T) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+
+    pub fn notify<T: ToString>(mut self, notify: T) -> Self {
+        self.notify = Some(notify.to_string());
+        self
+    }
+
+    pub fn refund_line_items<T: ToString>(mut self, refund_line_items: T) -> Self {
+        self.refund_line_items = Some(refund_line_items.to_string());
+        self
+    }
+
+    pub fn restock_deprecated<T: ToString>(mut self, restock_deprecated: T) -> Self {
+        self.restock_deprecated = Some(restock_deprecated.to_string());
+        self
+    }
+
+    pub fn shipping<T: ToString>(mut self, shipping: T) -> Self {
+        self.shipping = Some(shipping.to_string());
+        self
+    }
+
+    pub fn transactions<T: ToString>(mut self, transactions: T) -> Self {
+        self.transactions = Some(transactions.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202101_get_param_refunds_refund_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202101GetParamRefundsRefundParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show amounts in the shop currency for the underlying transaction.
+    /// (default: false).
+    pub in_shop_currency: Option<String>,
+}
+
+impl OrdersDeprecated202101GetParamRefundsRefundParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn in_shop_currency<T: ToString>(mut self, in_shop_currency: T) -> Self {
+        self.in_shop_currency = Some(in_shop_currency.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_202101_create_param_refunds_calculate_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecated202101CreateParamRefundsCalculateParams {
+    /// The three-letter code (ISO 4217 format) for the
+    /// currency used for the refund. Note: Required whenever the shipping amount property is provided.
+    pub currency: Option<String>,
+    /// A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
+    ///
+    /// line_item_id: The ID of a line item to refund.
+    /// quantity: The quantity to refund.
+    /// restock_type:           How this refund line item affects inventory levels. Valid values:
+    ///
+    /// no_restock: Refunding these items won't affect inventory.
+    /// cancel: The items have not yet been fulfilled. The canceled quantity will be added
+    /// back to the available count. The number of fulfillable units for this line item will decrease.
+    /// return: The items were already delivered but will be returned to the merchant.
+    /// The returned quantity will be added back to the available count.
+    /// The number of fulfillable units for this line item will remain unchanged.
+    ///
+    ///
+    /// location_id:           The ID of the location
+    /// where the items should be restocked. If location_id is not provided and the value of
+    /// restock_type is return or cancel, then the endpoint returns a suitable
+    /// location ID.
+    ///
+    /// already_stocked:           Whether the item is already stocked at
+    /// the location. If this is false, then creating the refund will connect the item to the location and start
+    /// stocking it there.
+    pub refund_line_items: Option<String>,
+    /// Specify how much shipping to refund. It has the following properties:
+    ///
+    /// full_refund: Whether to refund all remaining shipping.
+    /// amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
+    pub shipping: Option<String>,
+}
+
+impl OrdersDeprecated202101CreateParamRefundsCalculateParams {
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn refund_line_items<T: ToString>(mut self, refund_line_items: T) -> Self {
+        self.refund_line_items = Some(refund_line_items.to_string());
+        self
+    }
+
+    pub fn shipping<T: ToString>(mut self, shipping: T) -> Self {
+        self.shipping = Some(shipping.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_unstable_get_param_refund_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecatedUnstableGetParamRefundParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show amounts in the shop currency for the underlying transaction.
+    /// (default: false).
+    pub in_shop_currency: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl OrdersDeprecatedUnstableGetParamRefundParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn in_shop_currency<T: ToString>(mut self, in_shop_currency: T) -> Self {
+        self.in_shop_currency = Some(in_shop_currency.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_unstable_create_param_refunds_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecatedUnstableCreateParamRefundsParams {
+    /// The three-letter code (ISO 4217 format) for the currency used for the refund.
+    pub currency: Option<String>,
+    /// An optional comment that explains a discrepancy between calculated and actual refund amounts. Used to populate the reason property of the resulting order adjustment object attached to the refund. Valid values: restock, damage, customer, and other.
+    pub discrepancy_reason: Option<String>,
+    /// An optional note attached to a refund.
+    pub note: Option<String>,
+    /// Whether to send a refund notification to the customer.
+    pub notify: Option<String>,
+    /// A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
+    ///
+    /// line_item_id: The ID of a line item to refund.
+    /// quantity: The quantity to refund.
+    /// restock_type:           How this refund line item affects inventory levels. Valid values:
+    ///
+    /// no_restock: Refunding these items won't affect inventory.
+    /// cancel: The items have not yet been fulfilled.
+    /// The canceled quantity will be added back to the available count.
+    /// The number of fulfillable units for this line item will decrease.
+    /// return: The items were already delivered but will be returned to the merchant.
+    /// The returned quantity will be added back to the available count. The number of fulfillable units for this
+    /// line item will remain unchanged.
+    ///
+    ///
+    /// location_id:           The ID of the location where the items should be
+    /// restocked. This is required when the value of restock_type is return or cancel.
+    /// If the item is not already stocked at the location, then
+    /// the item is connected to the location. An error is returned when the item is connected to
+    /// a
+    /// fulfillment service location and a different location is provided.
+    pub refund_line_items: Option<String>,
+    /// Whether to add the line items back to the store inventory. Use restock_type for refund line items instead.
+    pub restock_deprecated: Option<String>,
+    /// Specify how much shipping to refund. It has the following properties:
+    ///
+    /// full_refund: Whether to refund all remaining shipping.
+    /// amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
+    pub shipping: Option<String>,
+    /// A list of transactions
+    /// to process as refunds.
+    pub transactions: Option<String>,
+}
+
+impl OrdersDeprecatedUnstableCreateParamRefundsParams {
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn discrepancy_reason<T: ToString>(mut self, discrepancy_reason: T) -> Self {
+        self.discrepancy_reason = Some(discrepancy_reason.to_string());
+        self
+    }
+
+    pub fn note<T: ToString>(mut self, note: T) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+
+    pub fn notify<T: ToString>(mut self, notify: T) -> Self {
+        self.notify = Some(notify.to_string());
+        self
+    }
+
+    pub fn refund_line_items<T: ToString>(mut self, refund_line_items: T) -> Self {
+        self.refund_line_items = Some(refund_line_items.to_string());
+        self
+    }
+
+    pub fn restock_deprecated<T: ToString>(mut self, restock_deprecated: T) -> Self {
+        self.restock_deprecated = Some(restock_deprecated.to_string());
+        self
+    }
+
+    pub fn shipping<T: ToString>(mut self, shipping: T) -> Self {
+        self.shipping = Some(shipping.to_string());
+        self
+    }
+
+    pub fn transactions<T: ToString>(mut self, transactions: T) -> Self {
+        self.transactions = Some(transactions.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_unstable_get_param_refunds_refund_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecatedUnstableGetParamRefundsRefundParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Show amounts in the shop currency for the underlying transaction.
+    /// (default: false).
+    pub in_shop_currency: Option<String>,
+}
+
+impl OrdersDeprecatedUnstableGetParamRefundsRefundParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn in_shop_currency<T: ToString>(mut self, in_shop_currency: T) -> Self {
+        self.in_shop_currency = Some(in_shop_currency.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Orders::deprecated_unstable_create_param_refunds_calculate_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersDeprecatedUnstableCreateParamRefundsCalculateParams {
+    /// The three-letter code (ISO 4217 format) for the
+    /// currency used for the refund. Note: Required whenever the shipping amount property is provided.
+    pub currency: Option<String>,
+    /// A list of line item IDs, quantities to refund, and restock instructions. Each entry has the following properties:
+    ///
+    /// line_item_id: The ID of a line item to refund.
+    /// quantity: The quantity to refund.
+    /// restock_type:           How this refund line item affects inventory levels. Valid values:
+    ///
+    /// no_restock: Refunding these items won't affect inventory.
+    /// cancel: The items have not yet been fulfilled. The canceled quantity will be added
+    /// back to the available count. The number of fulfillable units for this line item will decrease.
+    /// return: The items were already delivered but will be returned to the merchant.
+    /// The returned quantity will be added back to the available count.
+    /// The number of fulfillable units for this line item will remain unchanged.
+    ///
+    ///
+    /// location_id:           The ID of the location
+    /// where the items should be restocked. If location_id is not provided and the value of
+    /// restock_type is return or cancel, then the endpoint returns a suitable
+    /// location ID.
+    ///
+    /// already_stocked:           Whether the item is already stocked at
+    /// the location. If this is false, then creating the refund will connect the item to the location and start
+    /// stocking it there.
+    pub refund_line_items: Option<String>,
+    /// Specify how much shipping to refund. It has the following properties:
+    ///
+    /// full_refund: Whether to refund all remaining shipping.
+    /// amount: Set a specific amount to refund for shipping. Takes precedence over full_refund.
+    pub shipping: Option<String>,
+}
+
+impl OrdersDeprecatedUnstableCreateParamRefundsCalculateParams {
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn refund_line_items<T: ToString>(mut self, refund_line_items: T) -> Self {
+        self.refund_line_items = Some(refund_line_items.to_string());
+        self
+    }
+
+    pub fn shipping<T: ToString>(mut self, shipping: T) -> Self {
+        self.shipping = Some(shipping.to_string());
+        self
+    }
+}
+
+/// The functions of [`Orders`], for code that takes them as a trait, so that it can be
+/// tested with the `MockOrdersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait OrdersApi: Send + Sync {
+    /// See [`Orders::deprecated_202001_get_checkouts_count_with`].
+    async fn deprecated_202001_get_checkouts_count_with(
+        &self,
+        params: &OrdersDeprecated202001GetCheckoutsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_get_checkouts_count_with`].
+    async fn deprecated_202004_get_checkouts_count_with(
+        &self,
+        params: &OrdersDeprecated202004GetCheckoutsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202007_get_checkouts_count_with`].
+    async fn deprecated_202007_get_checkouts_count_with(
+        &self,
+        params: &OrdersDeprecated202007GetCheckoutsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::get_checkouts_count_with`].
+    async fn get_checkouts_count_with(&self, params: &OrdersGetCheckoutsCountParams) -> Result<()>;
+
+    /// See [`Orders::deprecated_202101_get_checkouts_count_with`].
+    async fn deprecated_202101_get_checkouts_count_with(
+        &self,
+        params: &OrdersDeprecated202101GetCheckoutsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_unstable_get_checkouts_count_with`].
+    async fn deprecated_unstable_get_checkouts_count_with(
+        &self,
+        params: &OrdersDeprecatedUnstableGetCheckoutsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_get_with`].
+    async fn deprecated_202001_get_with(
+        &self,
+        params: &OrdersDeprecated202001GetParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_create`].
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_get_param_with`].
+    async fn deprecated_202001_get_param_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202001GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_update_param`].
+    async fn deprecated_202001_update_param(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_delete_param`].
+    async fn deprecated_202001_delete_param(&self, order_id: &str) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_get_count_with`].
+    async fn deprecated_202001_get_count_with(
+        &self,
+        params: &OrdersDeprecated202001GetCountParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_create_param_close`].
+    async fn deprecated_202001_create_param_close(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_create_param_open`].
+    async fn deprecated_202001_create_param_open(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_create_param_cancel_with`].
+    async fn deprecated_202001_create_param_cancel_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202001CreateParamCancelParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_get_with`].
+    async fn deprecated_202004_get_with(
+        &self,
+        params: &OrdersDeprecated202004GetParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_create`].
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_get_param_with`].
+    async fn deprecated_202004_get_param_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202004GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_update_param`].
+    async fn deprecated_202004_update_param(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_delete_param`].
+    async fn deprecated_202004_delete_param(&self, order_id: &str) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_get_count_with`].
+    async fn deprecated_202004_get_count_with(
+        &self,
+        params: &OrdersDeprecated202004GetCountParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_create_param_close`].
+    async fn deprecated_202004_create_param_close(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_create_param_open`].
+    async fn deprecated_202004_create_param_open(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_create_param_cancel_with`].
+    async fn deprecated_202004_create_param_cancel_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202004CreateParamCancelParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202007_get_with`].
+    async fn deprecated_202007_get_with(
+        &self,
+        params: &OrdersDeprecated202007GetParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_get_param_risk`].
+    async fn deprecated_202001_get_param_risk(&self, order_id: &str) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_create_param_risks`].
+    async fn deprecated_202001_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_get_param_risks_risk`].
+    async fn deprecated_202001_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_update_param_risks_risk`].
+    async fn deprecated_202001_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_delete_param_risks_risk`].
+    async fn deprecated_202001_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_get_param_risk`].
+    async fn deprecated_202004_get_param_risk(&self, order_id: &str) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_create_param_risks`].
+    async fn deprecated_202004_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_get_param_risks_risk`].
+    async fn deprecated_202004_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_update_param_risks_risk`].
+    async fn deprecated_202004_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_delete_param_risks_risk`].
+    async fn deprecated_202004_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202007_get_param_risk`].
+    async fn deprecated_202007_get_param_risk(&self, order_id: &str) -> Result<()>;
+
+    /// See [`Orders::deprecated_202007_create_param_risks`].
+    async fn deprecated_202007_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202007_get_param_risks_risk`].
+    async fn deprecated_202007_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202007_update_param_risks_risk`].
+    async fn deprecated_202007_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202007_delete_param_risks_risk`].
+    async fn deprecated_202007_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Orders::get_param_risk`].
+    async fn get_param_risk(&self, order_id: &str) -> Result<()>;
+
+    /// See [`Orders::create_param_risks`].
+    async fn create_param_risks(&self, order_id: &str, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Orders::get_param_risks_risk`].
+    async fn get_param_risks_risk(&self, order_id: &str, risk_id: &str) -> Result<()>;
+
+    /// See [`Orders::update_param_risks_risk`].
+    async fn update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::delete_param_risks_risk`].
+    async fn delete_param_risks_risk(&self, order_id: &str, risk_id: &str) -> Result<()>;
+
+    /// See [`Orders::deprecated_202101_get_param_risk`].
+    async fn deprecated_202101_get_param_risk(&self, order_id: &str) -> Result<()>;
+
+    /// See [`Orders::deprecated_202101_create_param_risks`].
+    async fn deprecated_202101_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202101_get_param_risks_risk`].
+    async fn deprecated_202101_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202101_update_param_risks_risk`].
+    async fn deprecated_202101_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202101_delete_param_risks_risk`].
+    async fn deprecated_202101_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_unstable_get_param_risk`].
+    async fn deprecated_unstable_get_param_risk(&self, order_id: &str) -> Result<()>;
+
+    /// See [`Orders::deprecated_unstable_create_param_risks`].
+    async fn deprecated_unstable_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_unstable_get_param_risks_risk`].
+    async fn deprecated_unstable_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_unstable_update_param_risks_risk`].
+    async fn deprecated_unstable_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_unstable_delete_param_risks_risk`].
+    async fn deprecated_unstable_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_get_param_refund_with`].
+    async fn deprecated_202001_get_param_refund_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202001GetParamRefundParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_create_param_refunds_with`].
+    async fn deprecated_202001_create_param_refunds_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202001CreateParamRefundsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_get_param_refunds_refund_with`].
+    async fn deprecated_202001_get_param_refunds_refund_with(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        params: &OrdersDeprecated202001GetParamRefundsRefundParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202001_create_param_refunds_calculate_with`].
+    async fn deprecated_202001_create_param_refunds_calculate_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202001CreateParamRefundsCalculateParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_get_param_refund_with`].
+    async fn deprecated_202004_get_param_refund_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202004GetParamRefundParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_create_param_refunds_with`].
+    async fn deprecated_202004_create_param_refunds_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202004CreateParamRefundsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_get_param_refunds_refund_with`].
+    async fn deprecated_202004_get_param_refunds_refund_with(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        params: &OrdersDeprecated202004GetParamRefundsRefundParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202004_create_param_refunds_calculate_with`].
+    async fn deprecated_202004_create_param_refunds_calculate_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202004CreateParamRefundsCalculateParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202007_get_param_refund_with`].
+    async fn deprecated_202007_get_param_refund_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202007GetParamRefundParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202007_create_param_refunds_with`].
+    async fn deprecated_202007_create_param_refunds_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202007CreateParamRefundsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202007_get_param_refunds_refund_with`].
+    async fn deprecated_202007_get_param_refunds_refund_with(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        params: &OrdersDeprecated202007GetParamRefundsRefundParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202007_create_param_refunds_calculate_with`].
+    async fn deprecated_202007_create_param_refunds_calculate_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202007CreateParamRefundsCalculateParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::get_param_refund_with`].
+    async fn get_param_refund_with(
+        &self,
+        order_id: &str,
+        params: &OrdersGetParamRefundParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::create_param_refunds_with`].
+    async fn create_param_refunds_with(
+        &self,
+        order_id: &str,
+        params: &OrdersCreateParamRefundsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::get_param_refunds_refund_with`].
+    async fn get_param_refunds_refund_with(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        params: &OrdersGetParamRefundsRefundParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::create_param_refunds_calculate_with`].
+    async fn create_param_refunds_calculate_with(
+        &self,
+        order_id: &str,
+        params: &OrdersCreateParamRefundsCalculateParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202101_get_param_refund_with`].
+    async fn deprecated_202101_get_param_refund_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202101GetParamRefundParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202101_create_param_refunds_with`].
+    async fn deprecated_202101_create_param_refunds_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202101CreateParamRefundsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202101_get_param_refunds_refund_with`].
+    async fn deprecated_202101_get_param_refunds_refund_with(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        params: &OrdersDeprecated202101GetParamRefundsRefundParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_202101_create_param_refunds_calculate_with`].
+    async fn deprecated_202101_create_param_refunds_calculate_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202101CreateParamRefundsCalculateParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_unstable_get_param_refund_with`].
+    async fn deprecated_unstable_get_param_refund_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecatedUnstableGetParamRefundParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_unstable_create_param_refunds_with`].
+    async fn deprecated_unstable_create_param_refunds_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecatedUnstableCreateParamRefundsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_unstable_get_param_refunds_refund_with`].
+    async fn deprecated_unstable_get_param_refunds_refund_with(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        params: &OrdersDeprecatedUnstableGetParamRefundsRefundParams,
+    ) -> Result<()>;
+
+    /// See [`Orders::deprecated_unstable_create_param_refunds_calculate_with`].
+    async fn deprecated_unstable_create_param_refunds_calculate_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecatedUnstableCreateParamRefundsCalculateParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl OrdersApi for Orders {
+    async fn deprecated_202001_get_checkouts_count_with(
+        &self,
+        params: &OrdersDeprecated202001GetCheckoutsCountParams,
+    ) -> Result<()> {
+        Orders::deprecated_202001_get_checkouts_count_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_checkouts_count_with(
+        &self,
+        params: &OrdersDeprecated202004GetCheckoutsCountParams,
+    ) -> Result<()> {
+        Orders::deprecated_202004_get_checkouts_count_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_checkouts_count_with(
+        &self,
+        params: &OrdersDeprecated202007GetCheckoutsCountParams,
+    ) -> Result<()> {
+        Orders::deprecated_202007_get_checkouts_count_with(self, params).await
+    }
+
+    async fn get_checkouts_count_with(&self, params: &OrdersGetCheckoutsCountParams) -> Result<()> {
+        Orders::get_checkouts_count_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_checkouts_count_with(
+        &self,
+        params: &OrdersDeprecated202101GetCheckoutsCountParams,
+    ) -> Result<()> {
+        Orders::deprecated_202101_get_checkouts_count_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_checkouts_count_with(
+        &self,
+        params: &OrdersDeprecatedUnstableGetCheckoutsCountParams,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_get_checkouts_count_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_with(
+        &self,
+        params: &OrdersDeprecated202001GetParams,
+    ) -> Result<()> {
+        Orders::deprecated_202001_get_with(self, params).await
+    }
+
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()> {
+        Orders::deprecated_202001_create(self, body).await
+    }
+
+    async fn deprecated_202001_get_param_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202001GetParamParams,
+    ) -> Result<()> {
+        Orders::deprecated_202001_get_param_with(self, order_id, params).await
+    }
+
+    async fn deprecated_202001_update_param(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_update_param(self, order_id, body).await
+    }
+
+    async fn deprecated_202001_delete_param(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_202001_delete_param(self, order_id).await
+    }
+
+    async fn deprecated_202001_get_count_with(
+        &self,
+        params: &OrdersDeprecated202001GetCountParams,
+    ) -> Result<()> {
+        Orders::deprecated_202001_get_count_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_param_close(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_create_param_close(self, order_id, body).await
+    }
+
+    async fn deprecated_202001_create_param_open(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_create_param_open(self, order_id, body).await
+    }
+
+    async fn deprecated_202001_create_param_cancel_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202001CreateParamCancelParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_create_param_cancel_with(self, order_id, params, body).await
+    }
+
+    async fn deprecated_202004_get_with(
+        &self,
+        params: &OrdersDeprecated202004GetParams,
+    ) -> Result<()> {
+        Orders::deprecated_202004_get_with(self, params).await
+    }
+
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()> {
+        Orders::deprecated_202004_create(self, body).await
+    }
+
+    async fn deprecated_202004_get_param_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202004GetParamParams,
+    ) -> Result<()> {
+        Orders::deprecated_202004_get_param_with(self, order_id, params).await
+    }
+
+    async fn deprecated_202004_update_param(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_update_param(self, order_id, body).await
+    }
+
+    async fn deprecated_202004_delete_param(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_202004_delete_param(self, order_id).await
+    }
+
+    async fn deprecated_202004_get_count_with(
+        &self,
+        params: &OrdersDeprecated202004GetCountParams,
+    ) -> Result<()> {
+        Orders::deprecated_202004_get_count_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_param_close(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_create_param_close(self, order_id, body).await
+    }
+
+    async fn deprecated_202004_create_param_open(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_create_param_open(self, order_id, body).await
+    }
+
+    async fn deprecated_202004_create_param_cancel_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202004CreateParamCancelParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_create_param_cancel_with(self, order_id, params, body).await
+    }
+
+    async fn deprecated_202007_get_with(
+        &self,
+        params: &OrdersDeprecated202007GetParams,
+    ) -> Result<()> {
+        Orders::deprecated_202007_get_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_param_risk(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_202001_get_param_risk(self, order_id).await
+    }
+
+    async fn deprecated_202001_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_create_param_risks(self, order_id, body).await
+    }
+
+    async fn deprecated_202001_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202001_get_param_risks_risk(self, order_id, risk_id).await
+    }
+
+    async fn deprecated_202001_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_update_param_risks_risk(self, order_id, risk_id, body).await
+    }
+
+    async fn deprecated_202001_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202001_delete_param_risks_risk(self, order_id, risk_id).await
+    }
+
+    async fn deprecated_202004_get_param_risk(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_202004_get_param_risk(self, order_id).await
+    }
+
+    async fn deprecated_202004_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_create_param_risks(self, order_id, body).await
+    }
+
+    async fn deprecated_202004_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202004_get_param_risks_risk(self, order_id, risk_id).await
+    }
+
+    async fn deprecated_202004_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_update_param_risks_risk(self, order_id, risk_id, body).await
+    }
+
+    async fn deprecated_202004_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202004_delete_param_risks_risk(self, order_id, risk_id).await
+    }
+
+    async fn deprecated_202007_get_param_risk(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_202007_get_param_risk(self, order_id).await
+    }
+
+    async fn deprecated_202007_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202007_create_param_risks(self, order_id, body).await
+    }
+
+    async fn deprecated_202007_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202007_get_param_risks_risk(self, order_id, risk_id).await
+    }
+
+    async fn deprecated_202007_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202007_update_param_risks_risk(self, order_id, risk_id, body).await
+    }
+
+    async fn deprecated_202007_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202007_delete_param_risks_risk(self, order_id, risk_id).await
+    }
+
+    async fn get_param_risk(&self, order_id: &str) -> Result<()> {
+        Orders::get_param_risk(self, order_id).await
+    }
+
+    async fn create_param_risks(&self, order_id: &str, body: &serde_json::Value) -> Result<()> {
+        Orders::create_param_risks(self, order_id, body).await
+    }
+
+    async fn get_param_risks_risk(&self, order_id: &str, risk_id: &str) -> Result<()> {
+        Orders::get_param_risks_risk(self, order_id, risk_id).await
+    }
+
+    async fn update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::update_param_risks_risk(self, order_id, risk_id, body).await
+    }
+
+    async fn delete_param_risks_risk(&self, order_id: &str, risk_id: &str) -> Result<()> {
+        Orders::delete_param_risks_risk(self, order_id, risk_id).await
+    }
+
+    async fn deprecated_202101_get_param_risk(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_202101_get_param_risk(self, order_id).await
+    }
+
+    async fn deprecated_202101_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202101_create_param_risks(self, order_id, body).await
+    }
+
+    async fn deprecated_202101_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202101_get_param_risks_risk(self, order_id, risk_id).await
+    }
+
+    async fn deprecated_202101_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202101_update_param_risks_risk(self, order_id, risk_id, body).await
+    }
+
+    async fn deprecated_202101_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_202101_delete_param_risks_risk(self, order_id, risk_id).await
+    }
+
+    async fn deprecated_unstable_get_param_risk(&self, order_id: &str) -> Result<()> {
+        Orders::deprecated_unstable_get_param_risk(self, order_id).await
+    }
+
+    async fn deprecated_unstable_create_param_risks(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_create_param_risks(self, order_id, body).await
+    }
+
+    async fn deprecated_unstable_get_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_get_param_risks_risk(self, order_id, risk_id).await
+    }
+
+    async fn deprecated_unstable_update_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_update_param_risks_risk(self, order_id, risk_id, body).await
+    }
+
+    async fn deprecated_unstable_delete_param_risks_risk(
+        &self,
+        order_id: &str,
+        risk_id: &str,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_delete_param_risks_risk(self, order_id, risk_id).await
+    }
+
+    async fn deprecated_202001_get_param_refund_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202001GetParamRefundParams,
+    ) -> Result<()> {
+        Orders::deprecated_202001_get_param_refund_with(self, order_id, params).await
+    }
+
+    async fn deprecated_202001_create_param_refunds_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202001CreateParamRefundsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_create_param_refunds_with(self, order_id, params, body).await
+    }
+
+    async fn deprecated_202001_get_param_refunds_refund_with(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        params: &OrdersDeprecated202001GetParamRefundsRefundParams,
+    ) -> Result<()> {
+        Orders::deprecated_202001_get_param_refunds_refund_with(self, order_id, refund_id, params)
+            .await
+    }
+
+    async fn deprecated_202001_create_param_refunds_calculate_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202001CreateParamRefundsCalculateParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202001_create_param_refunds_calculate_with(self, order_id, params, body)
+            .await
+    }
+
+    async fn deprecated_202004_get_param_refund_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202004GetParamRefundParams,
+    ) -> Result<()> {
+        Orders::deprecated_202004_get_param_refund_with(self, order_id, params).await
+    }
+
+    async fn deprecated_202004_create_param_refunds_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202004CreateParamRefundsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_create_param_refunds_with(self, order_id, params, body).await
+    }
+
+    async fn deprecated_202004_get_param_refunds_refund_with(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        params: &OrdersDeprecated202004GetParamRefundsRefundParams,
+    ) -> Result<()> {
+        Orders::deprecated_202004_get_param_refunds_refund_with(self, order_id, refund_id, params)
+            .await
+    }
+
+    async fn deprecated_202004_create_param_refunds_calculate_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202004CreateParamRefundsCalculateParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202004_create_param_refunds_calculate_with(self, order_id, params, body)
+            .await
+    }
+
+    async fn deprecated_202007_get_param_refund_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202007GetParamRefundParams,
+    ) -> Result<()> {
+        Orders::deprecated_202007_get_param_refund_with(self, order_id, params).await
+    }
+
+    async fn deprecated_202007_create_param_refunds_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202007CreateParamRefundsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202007_create_param_refunds_with(self, order_id, params, body).await
+    }
+
+    async fn deprecated_202007_get_param_refunds_refund_with(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        params: &OrdersDeprecated202007GetParamRefundsRefundParams,
+    ) -> Result<()> {
+        Orders::deprecated_202007_get_param_refunds_refund_with(self, order_id, refund_id, params)
+            .await
+    }
+
+    async fn deprecated_202007_create_param_refunds_calculate_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202007CreateParamRefundsCalculateParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202007_create_param_refunds_calculate_with(self, order_id, params, body)
+            .await
+    }
+
+    async fn get_param_refund_with(
+        &self,
+        order_id: &str,
+        params: &OrdersGetParamRefundParams,
+    ) -> Result<()> {
+        Orders::get_param_refund_with(self, order_id, params).await
+    }
+
+    async fn create_param_refunds_with(
+        &self,
+        order_id: &str,
+        params: &OrdersCreateParamRefundsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::create_param_refunds_with(self, order_id, params, body).await
+    }
+
+    async fn get_param_refunds_refund_with(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        params: &OrdersGetParamRefundsRefundParams,
+    ) -> Result<()> {
+        Orders::get_param_refunds_refund_with(self, order_id, refund_id, params).await
+    }
+
+    async fn create_param_refunds_calculate_with(
+        &self,
+        order_id: &str,
+        params: &OrdersCreateParamRefundsCalculateParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::create_param_refunds_calculate_with(self, order_id, params, body).await
+    }
+
+    async fn deprecated_202101_get_param_refund_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202101GetParamRefundParams,
+    ) -> Result<()> {
+        Orders::deprecated_202101_get_param_refund_with(self, order_id, params).await
+    }
+
+    async fn deprecated_202101_create_param_refunds_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202101CreateParamRefundsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202101_create_param_refunds_with(self, order_id, params, body).await
+    }
+
+    async fn deprecated_202101_get_param_refunds_refund_with(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        params: &OrdersDeprecated202101GetParamRefundsRefundParams,
+    ) -> Result<()> {
+        Orders::deprecated_202101_get_param_refunds_refund_with(self, order_id, refund_id, params)
+            .await
+    }
+
+    async fn deprecated_202101_create_param_refunds_calculate_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecated202101CreateParamRefundsCalculateParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_202101_create_param_refunds_calculate_with(self, order_id, params, body)
+            .await
+    }
+
+    async fn deprecated_unstable_get_param_refund_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecatedUnstableGetParamRefundParams,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_get_param_refund_with(self, order_id, params).await
+    }
+
+    async fn deprecated_unstable_create_param_refunds_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecatedUnstableCreateParamRefundsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_create_param_refunds_with(self, order_id, params, body).await
+    }
+
+    async fn deprecated_unstable_get_param_refunds_refund_with(
+        &self,
+        order_id: &str,
+        refund_id: &str,
+        params: &OrdersDeprecatedUnstableGetParamRefundsRefundParams,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_get_param_refunds_refund_with(self, order_id, refund_id, params)
+            .await
+    }
+
+    async fn deprecated_unstable_create_param_refunds_calculate_with(
+        &self,
+        order_id: &str,
+        params: &OrdersDeprecatedUnstableCreateParamRefundsCalculateParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Orders::deprecated_unstable_create_param_refunds_calculate_with(
+            self, order_id, params, body,
+        )
+        .await
+    }
 }
@@ -0,0 +1,150 @@
+// Not every API has paginated endpoints, so parts of this module may go unused.
+#![allow(dead_code)]
+
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+};
+
+use futures::{
+    stream::{BoxStream, Stream},
+    StreamExt,
+};
+
+use crate::Result;
+
+/// A page of items, along with the cursor of the page after it.
+pub(crate) struct Page<T> {
+    items: Vec<T>,
+    next: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Create a page. If `next` is `None`, this is the last page.
+    pub(crate) fn new(items: Vec<T>, next: Option<String>) -> Self {
+        Page { items, next }
+    }
+}
+
+/// A stream of the items of a paginated endpoint, as returned by the `*_stream` functions.
+///
+/// Pages are fetched one at a time, only once the items of the previous page have been
+/// consumed. The stream ends after the last page, after `max_items` items or after the
+/// first error.
+pub struct Pages<T> {
+    inner: BoxStream<'static, Result<T>>,
+    cursor: Arc<Mutex<Cursor>>,
+}
+
+/// Where a stream of pages is, as returned by `Pages::cursor`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Cursor {
+    /// At the page of this cursor, to pass as `start` to resume the stream, or at the
+    /// first page if it is `None`.
+    At(Option<String>),
+    /// Past the last page, there is nothing left to resume.
+    Done,
+}
+
+impl Cursor {
+    fn after(next: &Option<String>) -> Self {
+        match next {
+            Some(next) => Cursor::At(Some(next.clone())),
+            None => Cursor::Done,
+        }
+    }
+}
+
+struct State<T, F> {
+    fetch: F,
+    items: std::vec::IntoIter<T>,
+    next: Option<String>,
+    started: bool,
+    remaining: Option<usize>,
+    cursor: Arc<Mutex<Cursor>>,
+}
+
+impl<T: Send + 'static> Pages<T> {
+    /// Create a stream that calls `fetch` with the cursor of each page, starting at `start`,
+    /// or at the first page if it is `None`.
+    pub(crate) fn new<F, Fut>(start: Option<String>, max_items: Option<usize>, fetch: F) -> Self
+    where
+        F: FnMut(Option<String>) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Page<T>>> + Send + 'static,
+    {
+        let cursor = Arc::new(Mutex::new(Cursor::At(start.clone())));
+        let state = State {
+            fetch,
+            items: Vec::new().into_iter(),
+            next: start,
+            started: false,
+            remaining: max_items,
+            cursor: cursor.clone(),
+        };
+
+        let inner = futures::stream::try_unfold(state, |mut state| async move {
+            loop {
+                if state.remaining == Some(0) {
+                    return Ok(None);
+                }
+
+                if let Some(item) = state.items.next() {
+                    if state.items.as_slice().is_empty() {
+                        // We are done with this page, so resume at the next one.
+                        *state.cursor.lock().unwrap() = Cursor::after(&state.next);
+                    }
+                    state.remaining = state.remaining.map(|r| r - 1);
+                    return Ok(Some((item, state)));
+                }
+
+                if state.started && state.next.is_none() {
+                    return Ok(None);
+                }
+                state.started = true;
+
+                let page = (state.fetch)(state.next.clone()).await?;
+                // Some APIs hand back the cursor we just used on the last page, don't loop
+                // on it forever.
+                state.next = page.next.filter(|n| Some(n) != state.next.as_ref());
+                state.items = page.items.into_iter();
+                if state.items.as_slice().is_empty() {
+                    *state.cursor.lock().unwrap() = Cursor::after(&state.next);
+                }
+            }
+        })
+        .boxed();
+
+        Pages { inner, cursor }
+    }
+}
+
+impl<T> Pages<T> {
+    /// Where the stream stopped: `Cursor::At` the page to pass as `start` to the same
+    /// function to resume it, or `Cursor::Done` once the last page was consumed.
+    ///
+    /// Cursors point at pages, not items, so if the stream stopped in the middle of a page,
+    /// the resumed stream yields the items of that page that were already consumed again.
+    pub fn cursor(&self) -> Cursor {
+        self.cursor.lock().unwrap().clone()
+    }
+}
+
+impl<T> Stream for Pages<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
+
+/// Add a query parameter to a URL that may or may not have a query already.
+pub(crate) fn with_query(url: &str, key: &str, value: &str) -> String {
+    let query = serde_urlencoded::to_string([(key, value)]).unwrap();
+    if url.contains('?') {
+        format!("{}&{}", url, query)
+    } else {
+        format!("{}?{}", url, query)
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Plus {
     pub client: Client,
@@ -21,33 +19,23 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `status: &str` -- Retrieve gift cards with a given status. Valid values:
-     *                       
-     *                           enabled: Restrict results to only enabled gift cards
-     *                           disabled: Restrict results to only disabled gift cards.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &PlusDeprecated202001GetGiftCardParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_gift_card(
+    pub async fn deprecated_202001_get_gift_card_with(
         &self,
-        status: &str,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &PlusDeprecated202001GetGiftCardParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -56,6 +44,26 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_gift_card_with` and pass the query parameters as a `PlusDeprecated202001GetGiftCardParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_gift_card_with` instead")]
+    pub async fn deprecated_202001_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = PlusDeprecated202001GetGiftCardParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_gift_card_with(&params).await
+    }
+
     /**
      * Creates a gift card.
      *
@@ -132,14 +140,14 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `status: &str` -- Count gift cards with a given status. Valid values:
-     *                       
-     *                           enabled: Count only enabled gift cards
-     *                           disabled: Count only disabled gift cards.
+     * * `params: &PlusDeprecated202001GetGiftCardsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_gift_cards_count(&self, status: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecated202001GetGiftCardsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -148,6 +156,18 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_gift_cards_count_with` and pass the query parameters as a `PlusDeprecated202001GetGiftCardsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_gift_cards_count_with` instead")]
+    pub async fn deprecated_202001_get_gift_cards_count(&self, status: &str) -> Result<()> {
+        let params = PlusDeprecated202001GetGiftCardsCountParams {
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_gift_cards_count_with(&params)
+            .await
+    }
+
     /**
      * Disables a gift card. Disabling a gift card can't be undone.
      *
@@ -194,31 +214,23 @@ impl Plus {
     *
     * **Parameters:**
     *
-    * * `order: &str` -- The field and direction to order results by.
-    *                     (default: disabled_at DESC).
-    * * `query: &str` -- The text to search for.
-    * * `limit: &str` -- The maximum number of results to retrieve.
-    *                     (default: 50, maximum: 250).
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+    * * `params: &PlusDeprecated202001GetGiftCardsSearchParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_get_gift_cards_search(
+    pub async fn deprecated_202001_get_gift_cards_search_with(
         &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
+        params: &PlusDeprecated202001GetGiftCardsSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -227,6 +239,27 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_gift_cards_search_with` and pass the query parameters as a `PlusDeprecated202001GetGiftCardsSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_gift_cards_search_with` instead")]
+    pub async fn deprecated_202001_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = PlusDeprecated202001GetGiftCardsSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_gift_cards_search_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -236,33 +269,23 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `status: &str` -- Retrieve gift cards with a given status. Valid values:
-     *                       
-     *                           enabled: Restrict results to only enabled gift cards
-     *                           disabled: Restrict results to only disabled gift cards.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &PlusDeprecated202004GetGiftCardParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_gift_card(
+    pub async fn deprecated_202004_get_gift_card_with(
         &self,
-        status: &str,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &PlusDeprecated202004GetGiftCardParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -271,6 +294,26 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_gift_card_with` and pass the query parameters as a `PlusDeprecated202004GetGiftCardParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_gift_card_with` instead")]
+    pub async fn deprecated_202004_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = PlusDeprecated202004GetGiftCardParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_gift_card_with(&params).await
+    }
+
     /**
      * Creates a gift card.
      *
@@ -347,14 +390,14 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `status: &str` -- Count gift cards with a given status. Valid values:
-     *                       
-     *                           enabled: Count only enabled gift cards
-     *                           disabled: Count only disabled gift cards.
+     * * `params: &PlusDeprecated202004GetGiftCardsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_gift_cards_count(&self, status: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecated202004GetGiftCardsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -363,6 +406,18 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_gift_cards_count_with` and pass the query parameters as a `PlusDeprecated202004GetGiftCardsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_gift_cards_count_with` instead")]
+    pub async fn deprecated_202004_get_gift_cards_count(&self, status: &str) -> Result<()> {
+        let params = PlusDeprecated202004GetGiftCardsCountParams {
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_gift_cards_count_with(&params)
+            .await
+    }
+
     /**
      * Disables a gift card. Disabling a gift card can't be undone.
      *
@@ -409,31 +464,23 @@ impl Plus {
     *
     * **Parameters:**
     *
-    * * `order: &str` -- The field and direction to order results by.
-    *                     (default: disabled_at DESC).
-    * * `query: &str` -- The text to search for.
-    * * `limit: &str` -- The maximum number of results to retrieve.
-    *                     (default: 50, maximum: 250).
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+    * * `params: &PlusDeprecated202004GetGiftCardsSearchParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_get_gift_cards_search(
+    pub async fn deprecated_202004_get_gift_cards_search_with(
         &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
+        params: &PlusDeprecated202004GetGiftCardsSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -442,6 +489,27 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_gift_cards_search_with` and pass the query parameters as a `PlusDeprecated202004GetGiftCardsSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_gift_cards_search_with` instead")]
+    pub async fn deprecated_202004_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = PlusDeprecated202004GetGiftCardsSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_gift_cards_search_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -451,33 +519,23 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `status: &str` -- Retrieve gift cards with a given status. Valid values:
-     *                       
-     *                           enabled: Restrict results to only enabled gift cards
-     *                           disabled: Restrict results to only disabled gift cards.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &PlusDeprecated202007GetGiftCardParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_gift_card(
+    pub async fn deprecated_202007_get_gift_card_with(
         &self,
-        status: &str,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &PlusDeprecated202007GetGiftCardParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -486,6 +544,26 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_gift_card_with` and pass the query parameters as a `PlusDeprecated202007GetGiftCardParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_gift_card_with` instead")]
+    pub async fn deprecated_202007_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = PlusDeprecated202007GetGiftCardParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_gift_card_with(&params).await
+    }
+
     /**
      * Creates a gift card.
      *
@@ -562,14 +640,14 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `status: &str` -- Count gift cards with a given status. Valid values:
-     *                       
-     *                           enabled: Count only enabled gift cards
-     *                           disabled: Count only disabled gift cards.
+     * * `params: &PlusDeprecated202007GetGiftCardsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_gift_cards_count(&self, status: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecated202007GetGiftCardsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -578,6 +656,18 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_gift_cards_count_with` and pass the query parameters as a `PlusDeprecated202007GetGiftCardsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_gift_cards_count_with` instead")]
+    pub async fn deprecated_202007_get_gift_cards_count(&self, status: &str) -> Result<()> {
+        let params = PlusDeprecated202007GetGiftCardsCountParams {
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_gift_cards_count_with(&params)
+            .await
+    }
+
     /**
      * Disables a gift card. Disabling a gift card can't be undone.
      *
@@ -624,31 +714,23 @@ impl Plus {
     *
     * **Parameters:**
     *
-    * * `order: &str` -- The field and direction to order results by.
-    *                     (default: disabled_at DESC).
-    * * `query: &str` -- The text to search for.
-    * * `limit: &str` -- The maximum number of results to retrieve.
-    *                     (default: 50, maximum: 250).
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+    * * `params: &PlusDeprecated202007GetGiftCardsSearchParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202007_get_gift_cards_search(
+    pub async fn deprecated_202007_get_gift_cards_search_with(
         &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
+        params: &PlusDeprecated202007GetGiftCardsSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -657,6 +739,27 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_gift_cards_search_with` and pass the query parameters as a `PlusDeprecated202007GetGiftCardsSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_gift_cards_search_with` instead")]
+    pub async fn deprecated_202007_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = PlusDeprecated202007GetGiftCardsSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_gift_cards_search_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -666,33 +769,20 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `status: &str` -- Retrieve gift cards with a given status. Valid values:
-     *                       
-     *                           enabled: Restrict results to only enabled gift cards
-     *                           disabled: Restrict results to only disabled gift cards.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &PlusGetGiftCardParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_gift_card(
-        &self,
-        status: &str,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
-    ) -> Result<()> {
+    pub async fn get_gift_card_with(&self, params: &PlusGetGiftCardParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -701,6 +791,26 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_gift_card_with` and pass the query parameters as a `PlusGetGiftCardParams` instead.
+     */
+    #[deprecated(note = "use `get_gift_card_with` instead")]
+    pub async fn get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = PlusGetGiftCardParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_gift_card_with(&params).await
+    }
+
     /**
      * Creates a gift card.
      *
@@ -771,14 +881,14 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `status: &str` -- Count gift cards with a given status. Valid values:
-     *                       
-     *                           enabled: Count only enabled gift cards
-     *                           disabled: Count only disabled gift cards.
+     * * `params: &PlusGetGiftCardsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_gift_cards_count(&self, status: &str) -> Result<()> {
+    pub async fn get_gift_cards_count_with(
+        &self,
+        params: &PlusGetGiftCardsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -787,6 +897,17 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_gift_cards_count_with` and pass the query parameters as a `PlusGetGiftCardsCountParams` instead.
+     */
+    #[deprecated(note = "use `get_gift_cards_count_with` instead")]
+    pub async fn get_gift_cards_count(&self, status: &str) -> Result<()> {
+        let params = PlusGetGiftCardsCountParams {
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_gift_cards_count_with(&params).await
+    }
+
     /**
      * Disables a gift card. Disabling a gift card can't be undone.
      *
@@ -833,31 +954,23 @@ impl Plus {
     *
     * **Parameters:**
     *
-    * * `order: &str` -- The field and direction to order results by.
-    *                     (default: disabled_at DESC).
-    * * `query: &str` -- The text to search for.
-    * * `limit: &str` -- The maximum number of results to retrieve.
-    *                     (default: 50, maximum: 250).
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+    * * `params: &PlusGetGiftCardsSearchParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn get_gift_cards_search(
+    pub async fn get_gift_cards_search_with(
         &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
+        params: &PlusGetGiftCardsSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -866,6 +979,26 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_gift_cards_search_with` and pass the query parameters as a `PlusGetGiftCardsSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_gift_cards_search_with` instead")]
+    pub async fn get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = PlusGetGiftCardsSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_gift_cards_search_with(&params).await
+    }
+
     /**
      * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -875,33 +1008,23 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `status: &str` -- Retrieve gift cards with a given status. Valid values:
-     *                       
-     *                           enabled: Restrict results to only enabled gift cards
-     *                           disabled: Restrict results to only disabled gift cards.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &PlusDeprecated202101GetGiftCardParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_gift_card(
+    pub async fn deprecated_202101_get_gift_card_with(
         &self,
-        status: &str,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &PlusDeprecated202101GetGiftCardParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -910,6 +1033,26 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_gift_card_with` and pass the query parameters as a `PlusDeprecated202101GetGiftCardParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_gift_card_with` instead")]
+    pub async fn deprecated_202101_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = PlusDeprecated202101GetGiftCardParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_gift_card_with(&params).await
+    }
+
     /**
      * Creates a gift card.
      *
@@ -986,14 +1129,14 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `status: &str` -- Count gift cards with a given status. Valid values:
-     *                       
-     *                           enabled: Count only enabled gift cards
-     *                           disabled: Count only disabled gift cards.
+     * * `params: &PlusDeprecated202101GetGiftCardsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_gift_cards_count(&self, status: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecated202101GetGiftCardsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1002,6 +1145,18 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_gift_cards_count_with` and pass the query parameters as a `PlusDeprecated202101GetGiftCardsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_gift_cards_count_with` instead")]
+    pub async fn deprecated_202101_get_gift_cards_count(&self, status: &str) -> Result<()> {
+        let params = PlusDeprecated202101GetGiftCardsCountParams {
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_gift_cards_count_with(&params)
+            .await
+    }
+
     /**
      * Disables a gift card. Disabling a gift card can't be undone.
      *
@@ -1048,31 +1203,23 @@ impl Plus {
     *
     * **Parameters:**
     *
-    * * `order: &str` -- The field and direction to order results by.
-    *                     (default: disabled_at DESC).
-    * * `query: &str` -- The text to search for.
-    * * `limit: &str` -- The maximum number of results to retrieve.
-    *                     (default: 50, maximum: 250).
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+    * * `params: &PlusDeprecated202101GetGiftCardsSearchParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202101_get_gift_cards_search(
+    pub async fn deprecated_202101_get_gift_cards_search_with(
         &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
+        params: &PlusDeprecated202101GetGiftCardsSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1081,6 +1228,27 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_gift_cards_search_with` and pass the query parameters as a `PlusDeprecated202101GetGiftCardsSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_gift_cards_search_with` instead")]
+    pub async fn deprecated_202101_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = PlusDeprecated202101GetGiftCardsSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_gift_cards_search_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of gift cards. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1090,33 +1258,23 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `status: &str` -- Retrieve gift cards with a given status. Valid values:
-     *                       
-     *                           enabled: Restrict results to only enabled gift cards
-     *                           disabled: Restrict results to only disabled gift cards.
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &PlusDeprecatedUnstableGetGiftCardParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_gift_card(
+    pub async fn deprecated_unstable_get_gift_card_with(
         &self,
-        status: &str,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &PlusDeprecatedUnstableGetGiftCardParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1125,6 +1283,26 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_gift_card_with` and pass the query parameters as a `PlusDeprecatedUnstableGetGiftCardParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_gift_card_with` instead")]
+    pub async fn deprecated_unstable_get_gift_card(
+        &self,
+        status: &str,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = PlusDeprecatedUnstableGetGiftCardParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_gift_card_with(&params).await
+    }
+
     /**
      * Creates a gift card.
      *
@@ -1201,14 +1379,14 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `status: &str` -- Count gift cards with a given status. Valid values:
-     *                       
-     *                           enabled: Count only enabled gift cards
-     *                           disabled: Count only disabled gift cards.
+     * * `params: &PlusDeprecatedUnstableGetGiftCardsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_gift_cards_count(&self, status: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecatedUnstableGetGiftCardsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1217,6 +1395,18 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_gift_cards_count_with` and pass the query parameters as a `PlusDeprecatedUnstableGetGiftCardsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_gift_cards_count_with` instead")]
+    pub async fn deprecated_unstable_get_gift_cards_count(&self, status: &str) -> Result<()> {
+        let params = PlusDeprecatedUnstableGetGiftCardsCountParams {
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_gift_cards_count_with(&params)
+            .await
+    }
+
     /**
      * Disables a gift card. Disabling a gift card can't be undone.
      *
@@ -1263,31 +1453,23 @@ impl Plus {
     *
     * **Parameters:**
     *
-    * * `order: &str` -- The field and direction to order results by.
-    *                     (default: disabled_at DESC).
-    * * `query: &str` -- The text to search for.
-    * * `limit: &str` -- The maximum number of results to retrieve.
-    *                     (default: 50, maximum: 250).
-    * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+    * * `params: &PlusDeprecatedUnstableGetGiftCardsSearchParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_unstable_get_gift_cards_search(
+    pub async fn deprecated_unstable_get_gift_cards_search_with(
         &self,
-        order: &str,
-        query: &str,
-        limit: &str,
-        fields: &str,
+        params: &PlusDeprecatedUnstableGetGiftCardsSearchParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1296,6 +1478,27 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_gift_cards_search_with` and pass the query parameters as a `PlusDeprecatedUnstableGetGiftCardsSearchParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_gift_cards_search_with` instead")]
+    pub async fn deprecated_unstable_get_gift_cards_search(
+        &self,
+        order: &str,
+        query: &str,
+        limit: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = PlusDeprecatedUnstableGetGiftCardsSearchParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_gift_cards_search_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of all users. Note: As of version 2021-01, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1305,16 +1508,17 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show on a page.
-     *                     (default: 50, maximum: 250).
-     * * `page_info: &str` -- A unique ID used to access a certain page of results.
+     * * `params: &PlusDeprecated202001GetUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_user_with(
+        &self,
+        params: &PlusDeprecated202001GetUserParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !page_info.is_empty() {
+        if let Some(page_info) = &params.page_info {
             query_args.push(("page_info".to_string(), page_info.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1323,6 +1527,18 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_user_with` and pass the query parameters as a `PlusDeprecated202001GetUserParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_user_with` instead")]
+    pub async fn deprecated_202001_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+        let params = PlusDeprecated202001GetUserParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            page_info: Some(page_info.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_user_with(&params).await
+    }
+
     /**
      * Retrieves a single user.
      *
@@ -1364,16 +1580,17 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show on a page.
-     *                     (default: 50, maximum: 250).
-     * * `page_info: &str` -- A unique ID used to access a certain page of results.
+     * * `params: &PlusDeprecated202004GetUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_user_with(
+        &self,
+        params: &PlusDeprecated202004GetUserParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !page_info.is_empty() {
+        if let Some(page_info) = &params.page_info {
             query_args.push(("page_info".to_string(), page_info.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1382,6 +1599,18 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_user_with` and pass the query parameters as a `PlusDeprecated202004GetUserParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_user_with` instead")]
+    pub async fn deprecated_202004_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+        let params = PlusDeprecated202004GetUserParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            page_info: Some(page_info.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_user_with(&params).await
+    }
+
     /**
      * Retrieves a single user.
      *
@@ -1423,16 +1652,17 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show on a page.
-     *                     (default: 50, maximum: 250).
-     * * `page_info: &str` -- A unique ID used to access a certain page of results.
+     * * `params: &PlusDeprecated202007GetUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_user_with(
+        &self,
+        params: &PlusDeprecated202007GetUserParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !page_info.is_empty() {
+        if let Some(page_info) = &params.page_info {
             query_args.push(("page_info".to_string(), page_info.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1441,6 +1671,18 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_user_with` and pass the query parameters as a `PlusDeprecated202007GetUserParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_user_with` instead")]
+    pub async fn deprecated_202007_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+        let params = PlusDeprecated202007GetUserParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            page_info: Some(page_info.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_user_with(&params).await
+    }
+
     /**
      * Retrieves a single user.
      *
@@ -1482,16 +1724,14 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show on a page.
-     *                     (default: 50, maximum: 250).
-     * * `page_info: &str` -- A unique ID used to access a certain page of results.
+     * * `params: &PlusGetUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+    pub async fn get_user_with(&self, params: &PlusGetUserParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !page_info.is_empty() {
+        if let Some(page_info) = &params.page_info {
             query_args.push(("page_info".to_string(), page_info.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1500,6 +1740,18 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_user_with` and pass the query parameters as a `PlusGetUserParams` instead.
+     */
+    #[deprecated(note = "use `get_user_with` instead")]
+    pub async fn get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+        let params = PlusGetUserParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            page_info: Some(page_info.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_user_with(&params).await
+    }
+
     /**
      * Retrieves a single user.
      *
@@ -1541,16 +1793,17 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show on a page.
-     *                     (default: 50, maximum: 250).
-     * * `page_info: &str` -- A unique ID used to access a certain page of results.
+     * * `params: &PlusDeprecated202101GetUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_user_with(
+        &self,
+        params: &PlusDeprecated202101GetUserParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !page_info.is_empty() {
+        if let Some(page_info) = &params.page_info {
             query_args.push(("page_info".to_string(), page_info.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1559,6 +1812,18 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_user_with` and pass the query parameters as a `PlusDeprecated202101GetUserParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_user_with` instead")]
+    pub async fn deprecated_202101_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+        let params = PlusDeprecated202101GetUserParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            page_info: Some(page_info.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_user_with(&params).await
+    }
+
     /**
      * Retrieves a single user.
      *
@@ -1600,16 +1865,17 @@ impl Plus {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show on a page.
-     *                     (default: 50, maximum: 250).
-     * * `page_info: &str` -- A unique ID used to access a certain page of results.
+     * * `params: &PlusDeprecatedUnstableGetUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_user_with(
+        &self,
+        params: &PlusDeprecatedUnstableGetUserParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !page_info.is_empty() {
+        if let Some(page_info) = &params.page_info {
             query_args.push(("page_info".to_string(), page_info.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1618,6 +1884,18 @@ impl Plus {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_user_with` and pass the query parameters as a `PlusDeprecatedUnstableGetUserParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_user_with` instead")]
+    pub async fn deprecated_unstable_get_user(&self, limit: &str, page_info: &str) -> Result<()> {
+        let params = PlusDeprecatedUnstableGetUserParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            page_info: Some(page_info.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_user_with(&params).await
+    }
+
     /**
      * Retrieves a single user.
      *
@@ -1650,3 +1928,1347 @@ impl Plus {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Plus::deprecated_202001_get_gift_card_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202001GetGiftCardParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Retrieve gift cards with a given status. Valid values:
+    ///
+    /// enabled: Restrict results to only enabled gift cards
+    /// disabled: Restrict results to only disabled gift cards.
+    pub status: Option<String>,
+}
+
+impl PlusDeprecated202001GetGiftCardParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202001_get_gift_cards_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202001GetGiftCardsCountParams {
+    /// Count gift cards with a given status. Valid values:
+    ///
+    /// enabled: Count only enabled gift cards
+    /// disabled: Count only disabled gift cards.
+    pub status: Option<String>,
+}
+
+impl PlusDeprecated202001GetGiftCardsCountParams {
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202001_get_gift_cards_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202001GetGiftCardsSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// The field and direction to order results by.
+    /// (default: disabled_at DESC).
+    pub order: Option<String>,
+    /// The text to search for.
+    pub query: Option<String>,
+}
+
+impl PlusDeprecated202001GetGiftCardsSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202004_get_gift_card_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202004GetGiftCardParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Retrieve gift cards with a given status. Valid values:
+    ///
+    /// enabled: Restrict results to only enabled gift cards
+    /// disabled: Restrict results to only disabled gift cards.
+    pub status: Option<String>,
+}
+
+impl PlusDeprecated202004GetGiftCardParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202004_get_gift_cards_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202004GetGiftCardsCountParams {
+    /// Count gift cards with a given status. Valid values:
+    ///
+    /// enabled: Count only enabled gift cards
+    /// disabled: Count only disabled gift cards.
+    pub status: Option<String>,
+}
+
+impl PlusDeprecated202004GetGiftCardsCountParams {
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202004_get_gift_cards_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202004GetGiftCardsSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// The field and direction to order results by.
+    /// (default: disabled_at DESC).
+    pub order: Option<String>,
+    /// The text to search for.
+    pub query: Option<String>,
+}
+
+impl PlusDeprecated202004GetGiftCardsSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202007_get_gift_card_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202007GetGiftCardParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Retrieve gift cards with a given status. Valid values:
+    ///
+    /// enabled: Restrict results to only enabled gift cards
+    /// disabled: Restrict results to only disabled gift cards.
+    pub status: Option<String>,
+}
+
+impl PlusDeprecated202007GetGiftCardParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202007_get_gift_cards_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202007GetGiftCardsCountParams {
+    /// Count gift cards with a given status. Valid values:
+    ///
+    /// enabled: Count only enabled gift cards
+    /// disabled: Count only disabled gift cards.
+    pub status: Option<String>,
+}
+
+impl PlusDeprecated202007GetGiftCardsCountParams {
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202007_get_gift_cards_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202007GetGiftCardsSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// The field and direction to order results by.
+    /// (default: disabled_at DESC).
+    pub order: Option<String>,
+    /// The text to search for.
+    pub query: Option<String>,
+}
+
+impl PlusDeprecated202007GetGiftCardsSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::get_gift_card_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusGetGiftCardParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Retrieve gift cards with a given status. Valid values:
+    ///
+    /// enabled: Restrict results to only enabled gift cards
+    /// disabled: Restrict results to only disabled gift cards.
+    pub status: Option<String>,
+}
+
+impl PlusGetGiftCardParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::get_gift_cards_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusGetGiftCardsCountParams {
+    /// Count gift cards with a given status. Valid values:
+    ///
+    /// enabled: Count only enabled gift cards
+    /// disabled: Count only disabled gift cards.
+    pub status: Option<String>,
+}
+
+impl PlusGetGiftCardsCountParams {
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::get_gift_cards_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusGetGiftCardsSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// The field and direction to order results by.
+    /// (default: disabled_at DESC).
+    pub order: Option<String>,
+    /// The text to search for.
+    pub query: Option<String>,
+}
+
+impl PlusGetGiftCardsSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202101_get_gift_card_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202101GetGiftCardParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Retrieve gift cards with a given status. Valid values:
+    ///
+    /// enabled: Restrict results to only enabled gift cards
+    /// disabled: Restrict results to only disabled gift cards.
+    pub status: Option<String>,
+}
+
+impl PlusDeprecated202101GetGiftCardParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202101_get_gift_cards_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202101GetGiftCardsCountParams {
+    /// Count gift cards with a given status. Valid values:
+    ///
+    /// enabled: Count only enabled gift cards
+    /// disabled: Count only disabled gift cards.
+    pub status: Option<String>,
+}
+
+impl PlusDeprecated202101GetGiftCardsCountParams {
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202101_get_gift_cards_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202101GetGiftCardsSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// The field and direction to order results by.
+    /// (default: disabled_at DESC).
+    pub order: Option<String>,
+    /// The text to search for.
+    pub query: Option<String>,
+}
+
+impl PlusDeprecated202101GetGiftCardsSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_unstable_get_gift_card_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecatedUnstableGetGiftCardParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Retrieve gift cards with a given status. Valid values:
+    ///
+    /// enabled: Restrict results to only enabled gift cards
+    /// disabled: Restrict results to only disabled gift cards.
+    pub status: Option<String>,
+}
+
+impl PlusDeprecatedUnstableGetGiftCardParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_unstable_get_gift_cards_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecatedUnstableGetGiftCardsCountParams {
+    /// Count gift cards with a given status. Valid values:
+    ///
+    /// enabled: Count only enabled gift cards
+    /// disabled: Count only disabled gift cards.
+    pub status: Option<String>,
+}
+
+impl PlusDeprecatedUnstableGetGiftCardsCountParams {
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_unstable_get_gift_cards_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecatedUnstableGetGiftCardsSearchParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// The field and direction to order results by.
+    /// (default: disabled_at DESC).
+    pub order: Option<String>,
+    /// The text to search for.
+    pub query: Option<String>,
+}
+
+impl PlusDeprecatedUnstableGetGiftCardsSearchParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202001_get_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202001GetUserParams {
+    /// The maximum number of results to show on a page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// A unique ID used to access a certain page of results.
+    pub page_info: Option<String>,
+}
+
+impl PlusDeprecated202001GetUserParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn page_info<T: ToString>(mut self, page_info: T) -> Self {
+        self.page_info = Some(page_info.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202004_get_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202004GetUserParams {
+    /// The maximum number of results to show on a page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// A unique ID used to access a certain page of results.
+    pub page_info: Option<String>,
+}
+
+impl PlusDeprecated202004GetUserParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn page_info<T: ToString>(mut self, page_info: T) -> Self {
+        self.page_info = Some(page_info.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202007_get_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202007GetUserParams {
+    /// The maximum number of results to show on a page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// A unique ID used to access a certain page of results.
+    pub page_info: Option<String>,
+}
+
+impl PlusDeprecated202007GetUserParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn page_info<T: ToString>(mut self, page_info: T) -> Self {
+        self.page_info = Some(page_info.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::get_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusGetUserParams {
+    /// The maximum number of results to show on a page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// A unique ID used to access a certain page of results.
+    pub page_info: Option<String>,
+}
+
+impl PlusGetUserParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn page_info<T: ToString>(mut self, page_info: T) -> Self {
+        self.page_info = Some(page_info.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_202101_get_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecated202101GetUserParams {
+    /// The maximum number of results to show on a page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// A unique ID used to access a certain page of results.
+    pub page_info: Option<String>,
+}
+
+impl PlusDeprecated202101GetUserParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn page_info<T: ToString>(mut self, page_info: T) -> Self {
+        self.page_info = Some(page_info.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Plus::deprecated_unstable_get_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PlusDeprecatedUnstableGetUserParams {
+    /// The maximum number of results to show on a page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// A unique ID used to access a certain page of results.
+    pub page_info: Option<String>,
+}
+
+impl PlusDeprecatedUnstableGetUserParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn page_info<T: ToString>(mut self, page_info: T) -> Self {
+        self.page_info = Some(page_info.to_string());
+        self
+    }
+}
+
+/// The functions of [`Plus`], for code that takes them as a trait, so that it can be
+/// tested with the `MockPlusApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait PlusApi: Send + Sync {
+    /// See [`Plus::deprecated_202001_get_gift_card_with`].
+    async fn deprecated_202001_get_gift_card_with(
+        &self,
+        params: &PlusDeprecated202001GetGiftCardParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202001_create_gift_cards`].
+    async fn deprecated_202001_create_gift_cards(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Plus::deprecated_202001_get_gift_cards_param_card`].
+    async fn deprecated_202001_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()>;
+
+    /// See [`Plus::deprecated_202001_update_gift_cards_param_card`].
+    async fn deprecated_202001_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202001_get_gift_cards_count_with`].
+    async fn deprecated_202001_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecated202001GetGiftCardsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202001_create_gift_cards_param_card_disable`].
+    async fn deprecated_202001_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202001_get_gift_cards_search_with`].
+    async fn deprecated_202001_get_gift_cards_search_with(
+        &self,
+        params: &PlusDeprecated202001GetGiftCardsSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202004_get_gift_card_with`].
+    async fn deprecated_202004_get_gift_card_with(
+        &self,
+        params: &PlusDeprecated202004GetGiftCardParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202004_create_gift_cards`].
+    async fn deprecated_202004_create_gift_cards(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Plus::deprecated_202004_get_gift_cards_param_card`].
+    async fn deprecated_202004_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()>;
+
+    /// See [`Plus::deprecated_202004_update_gift_cards_param_card`].
+    async fn deprecated_202004_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202004_get_gift_cards_count_with`].
+    async fn deprecated_202004_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecated202004GetGiftCardsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202004_create_gift_cards_param_card_disable`].
+    async fn deprecated_202004_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202004_get_gift_cards_search_with`].
+    async fn deprecated_202004_get_gift_cards_search_with(
+        &self,
+        params: &PlusDeprecated202004GetGiftCardsSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202007_get_gift_card_with`].
+    async fn deprecated_202007_get_gift_card_with(
+        &self,
+        params: &PlusDeprecated202007GetGiftCardParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202007_create_gift_cards`].
+    async fn deprecated_202007_create_gift_cards(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Plus::deprecated_202007_get_gift_cards_param_card`].
+    async fn deprecated_202007_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()>;
+
+    /// See [`Plus::deprecated_202007_update_gift_cards_param_card`].
+    async fn deprecated_202007_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202007_get_gift_cards_count_with`].
+    async fn deprecated_202007_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecated202007GetGiftCardsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202007_create_gift_cards_param_card_disable`].
+    async fn deprecated_202007_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202007_get_gift_cards_search_with`].
+    async fn deprecated_202007_get_gift_cards_search_with(
+        &self,
+        params: &PlusDeprecated202007GetGiftCardsSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::get_gift_card_with`].
+    async fn get_gift_card_with(&self, params: &PlusGetGiftCardParams) -> Result<()>;
+
+    /// See [`Plus::create_gift_cards`].
+    async fn create_gift_cards(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Plus::get_gift_cards_param_card`].
+    async fn get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()>;
+
+    /// See [`Plus::update_gift_cards_param_card`].
+    async fn update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Plus::get_gift_cards_count_with`].
+    async fn get_gift_cards_count_with(&self, params: &PlusGetGiftCardsCountParams) -> Result<()>;
+
+    /// See [`Plus::create_gift_cards_param_card_disable`].
+    async fn create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Plus::get_gift_cards_search_with`].
+    async fn get_gift_cards_search_with(&self, params: &PlusGetGiftCardsSearchParams)
+        -> Result<()>;
+
+    /// See [`Plus::deprecated_202101_get_gift_card_with`].
+    async fn deprecated_202101_get_gift_card_with(
+        &self,
+        params: &PlusDeprecated202101GetGiftCardParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202101_create_gift_cards`].
+    async fn deprecated_202101_create_gift_cards(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Plus::deprecated_202101_get_gift_cards_param_card`].
+    async fn deprecated_202101_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()>;
+
+    /// See [`Plus::deprecated_202101_update_gift_cards_param_card`].
+    async fn deprecated_202101_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202101_get_gift_cards_count_with`].
+    async fn deprecated_202101_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecated202101GetGiftCardsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202101_create_gift_cards_param_card_disable`].
+    async fn deprecated_202101_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202101_get_gift_cards_search_with`].
+    async fn deprecated_202101_get_gift_cards_search_with(
+        &self,
+        params: &PlusDeprecated202101GetGiftCardsSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_unstable_get_gift_card_with`].
+    async fn deprecated_unstable_get_gift_card_with(
+        &self,
+        params: &PlusDeprecatedUnstableGetGiftCardParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_unstable_create_gift_cards`].
+    async fn deprecated_unstable_create_gift_cards(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Plus::deprecated_unstable_get_gift_cards_param_card`].
+    async fn deprecated_unstable_get_gift_cards_param_card(&self, gift_card_id: &str)
+        -> Result<()>;
+
+    /// See [`Plus::deprecated_unstable_update_gift_cards_param_card`].
+    async fn deprecated_unstable_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_unstable_get_gift_cards_count_with`].
+    async fn deprecated_unstable_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecatedUnstableGetGiftCardsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_unstable_create_gift_cards_param_card_disable`].
+    async fn deprecated_unstable_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_unstable_get_gift_cards_search_with`].
+    async fn deprecated_unstable_get_gift_cards_search_with(
+        &self,
+        params: &PlusDeprecatedUnstableGetGiftCardsSearchParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202001_get_user_with`].
+    async fn deprecated_202001_get_user_with(
+        &self,
+        params: &PlusDeprecated202001GetUserParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202001_get_users_param_user`].
+    async fn deprecated_202001_get_users_param_user(&self, user_id: &str) -> Result<()>;
+
+    /// See [`Plus::deprecated_202001_get_users_current`].
+    async fn deprecated_202001_get_users_current(&self) -> Result<()>;
+
+    /// See [`Plus::deprecated_202004_get_user_with`].
+    async fn deprecated_202004_get_user_with(
+        &self,
+        params: &PlusDeprecated202004GetUserParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202004_get_users_param_user`].
+    async fn deprecated_202004_get_users_param_user(&self, user_id: &str) -> Result<()>;
+
+    /// See [`Plus::deprecated_202004_get_users_current`].
+    async fn deprecated_202004_get_users_current(&self) -> Result<()>;
+
+    /// See [`Plus::deprecated_202007_get_user_with`].
+    async fn deprecated_202007_get_user_with(
+        &self,
+        params: &PlusDeprecated202007GetUserParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202007_get_users_param_user`].
+    async fn deprecated_202007_get_users_param_user(&self, user_id: &str) -> Result<()>;
+
+    /// See [`Plus::deprecated_202007_get_users_current`].
+    async fn deprecated_202007_get_users_current(&self) -> Result<()>;
+
+    /// See [`Plus::get_user_with`].
+    async fn get_user_with(&self, params: &PlusGetUserParams) -> Result<()>;
+
+    /// See [`Plus::get_users_param_user`].
+    async fn get_users_param_user(&self, user_id: &str) -> Result<()>;
+
+    /// See [`Plus::get_users_current`].
+    async fn get_users_current(&self) -> Result<()>;
+
+    /// See [`Plus::deprecated_202101_get_user_with`].
+    async fn deprecated_202101_get_user_with(
+        &self,
+        params: &PlusDeprecated202101GetUserParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_202101_get_users_param_user`].
+    async fn deprecated_202101_get_users_param_user(&self, user_id: &str) -> Result<()>;
+
+    /// See [`Plus::deprecated_202101_get_users_current`].
+    async fn deprecated_202101_get_users_current(&self) -> Result<()>;
+
+    /// See [`Plus::deprecated_unstable_get_user_with`].
+    async fn deprecated_unstable_get_user_with(
+        &self,
+        params: &PlusDeprecatedUnstableGetUserParams,
+    ) -> Result<()>;
+
+    /// See [`Plus::deprecated_unstable_get_users_param_user`].
+    async fn deprecated_unstable_get_users_param_user(&self, user_id: &str) -> Result<()>;
+
+    /// See [`Plus::deprecated_unstable_get_users_current`].
+    async fn deprecated_unstable_get_users_current(&self) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl PlusApi for Plus {
+    async fn deprecated_202001_get_gift_card_with(
+        &self,
+        params: &PlusDeprecated202001GetGiftCardParams,
+    ) -> Result<()> {
+        Plus::deprecated_202001_get_gift_card_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_gift_cards(&self, body: &serde_json::Value) -> Result<()> {
+        Plus::deprecated_202001_create_gift_cards(self, body).await
+    }
+
+    async fn deprecated_202001_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()> {
+        Plus::deprecated_202001_get_gift_cards_param_card(self, gift_card_id).await
+    }
+
+    async fn deprecated_202001_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202001_update_gift_cards_param_card(self, gift_card_id, body).await
+    }
+
+    async fn deprecated_202001_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecated202001GetGiftCardsCountParams,
+    ) -> Result<()> {
+        Plus::deprecated_202001_get_gift_cards_count_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202001_create_gift_cards_param_card_disable(self, gift_card_id, body).await
+    }
+
+    async fn deprecated_202001_get_gift_cards_search_with(
+        &self,
+        params: &PlusDeprecated202001GetGiftCardsSearchParams,
+    ) -> Result<()> {
+        Plus::deprecated_202001_get_gift_cards_search_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_gift_card_with(
+        &self,
+        params: &PlusDeprecated202004GetGiftCardParams,
+    ) -> Result<()> {
+        Plus::deprecated_202004_get_gift_card_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_gift_cards(&self, body: &serde_json::Value) -> Result<()> {
+        Plus::deprecated_202004_create_gift_cards(self, body).await
+    }
+
+    async fn deprecated_202004_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()> {
+        Plus::deprecated_202004_get_gift_cards_param_card(self, gift_card_id).await
+    }
+
+    async fn deprecated_202004_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202004_update_gift_cards_param_card(self, gift_card_id, body).await
+    }
+
+    async fn deprecated_202004_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecated202004GetGiftCardsCountParams,
+    ) -> Result<()> {
+        Plus::deprecated_202004_get_gift_cards_count_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202004_create_gift_cards_param_card_disable(self, gift_card_id, body).await
+    }
+
+    async fn deprecated_202004_get_gift_cards_search_with(
+        &self,
+        params: &PlusDeprecated202004GetGiftCardsSearchParams,
+    ) -> Result<()> {
+        Plus::deprecated_202004_get_gift_cards_search_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_gift_card_with(
+        &self,
+        params: &PlusDeprecated202007GetGiftCardParams,
+    ) -> Result<()> {
+        Plus::deprecated_202007_get_gift_card_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_gift_cards(&self, body: &serde_json::Value) -> Result<()> {
+        Plus::deprecated_202007_create_gift_cards(self, body).await
+    }
+
+    async fn deprecated_202007_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()> {
+        Plus::deprecated_202007_get_gift_cards_param_card(self, gift_card_id).await
+    }
+
+    async fn deprecated_202007_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202007_update_gift_cards_param_card(self, gift_card_id, body).await
+    }
+
+    async fn deprecated_202007_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecated202007GetGiftCardsCountParams,
+    ) -> Result<()> {
+        Plus::deprecated_202007_get_gift_cards_count_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202007_create_gift_cards_param_card_disable(self, gift_card_id, body).await
+    }
+
+    async fn deprecated_202007_get_gift_cards_search_with(
+        &self,
+        params: &PlusDeprecated202007GetGiftCardsSearchParams,
+    ) -> Result<()> {
+        Plus::deprecated_202007_get_gift_cards_search_with(self, params).await
+    }
+
+    async fn get_gift_card_with(&self, params: &PlusGetGiftCardParams) -> Result<()> {
+        Plus::get_gift_card_with(self, params).await
+    }
+
+    async fn create_gift_cards(&self, body: &serde_json::Value) -> Result<()> {
+        Plus::create_gift_cards(self, body).await
+    }
+
+    async fn get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()> {
+        Plus::get_gift_cards_param_card(self, gift_card_id).await
+    }
+
+    async fn update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::update_gift_cards_param_card(self, gift_card_id, body).await
+    }
+
+    async fn get_gift_cards_count_with(&self, params: &PlusGetGiftCardsCountParams) -> Result<()> {
+        Plus::get_gift_cards_count_with(self, params).await
+    }
+
+    async fn create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::create_gift_cards_param_card_disable(self, gift_card_id, body).await
+    }
+
+    async fn get_gift_cards_search_with(
+        &self,
+        params: &PlusGetGiftCardsSearchParams,
+    ) -> Result<()> {
+        Plus::get_gift_cards_search_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_gift_card_with(
+        &self,
+        params: &PlusDeprecated202101GetGiftCardParams,
+    ) -> Result<()> {
+        Plus::deprecated_202101_get_gift_card_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_gift_cards(&self, body: &serde_json::Value) -> Result<()> {
+        Plus::deprecated_202101_create_gift_cards(self, body).await
+    }
+
+    async fn deprecated_202101_get_gift_cards_param_card(&self, gift_card_id: &str) -> Result<()> {
+        Plus::deprecated_202101_get_gift_cards_param_card(self, gift_card_id).await
+    }
+
+    async fn deprecated_202101_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202101_update_gift_cards_param_card(self, gift_card_id, body).await
+    }
+
+    async fn deprecated_202101_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecated202101GetGiftCardsCountParams,
+    ) -> Result<()> {
+        Plus::deprecated_202101_get_gift_cards_count_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_202101_create_gift_cards_param_card_disable(self, gift_card_id, body).await
+    }
+
+    async fn deprecated_202101_get_gift_cards_search_with(
+        &self,
+        params: &PlusDeprecated202101GetGiftCardsSearchParams,
+    ) -> Result<()> {
+        Plus::deprecated_202101_get_gift_cards_search_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_gift_card_with(
+        &self,
+        params: &PlusDeprecatedUnstableGetGiftCardParams,
+    ) -> Result<()> {
+        Plus::deprecated_unstable_get_gift_card_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_gift_cards(&self, body: &serde_json::Value) -> Result<()> {
+        Plus::deprecated_unstable_create_gift_cards(self, body).await
+    }
+
+    async fn deprecated_unstable_get_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+    ) -> Result<()> {
+        Plus::deprecated_unstable_get_gift_cards_param_card(self, gift_card_id).await
+    }
+
+    async fn deprecated_unstable_update_gift_cards_param_card(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_unstable_update_gift_cards_param_card(self, gift_card_id, body).await
+    }
+
+    async fn deprecated_unstable_get_gift_cards_count_with(
+        &self,
+        params: &PlusDeprecatedUnstableGetGiftCardsCountParams,
+    ) -> Result<()> {
+        Plus::deprecated_unstable_get_gift_cards_count_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_gift_cards_param_card_disable(
+        &self,
+        gift_card_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Plus::deprecated_unstable_create_gift_cards_param_card_disable(self, gift_card_id, body)
+            .await
+    }
+
+    async fn deprecated_unstable_get_gift_cards_search_with(
+        &self,
+        params: &PlusDeprecatedUnstableGetGiftCardsSearchParams,
+    ) -> Result<()> {
+        Plus::deprecated_unstable_get_gift_cards_search_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_user_with(
+        &self,
+        params: &PlusDeprecated202001GetUserParams,
+    ) -> Result<()> {
+        Plus::deprecated_202001_get_user_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_users_param_user(&self, user_id: &str) -> Result<()> {
+        Plus::deprecated_202001_get_users_param_user(self, user_id).await
+    }
+
+    async fn deprecated_202001_get_users_current(&self) -> Result<()> {
+        Plus::deprecated_202001_get_users_current(self).await
+    }
+
+    async fn deprecated_202004_get_user_with(
+        &self,
+        params: &PlusDeprecated202004GetUserParams,
+    ) -> Result<()> {
+        Plus::deprecated_202004_get_user_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_users_param_user(&self, user_id: &str) -> Result<()> {
+        Plus::deprecated_202004_get_users_param_user(self, user_id).await
+    }
+
+    async fn deprecated_202004_get_users_current(&self) -> Result<()> {
+        Plus::deprecated_202004_get_users_current(self).await
+    }
+
+    async fn deprecated_202007_get_user_with(
+        &self,
+        params: &PlusDeprecated202007GetUserParams,
+    ) -> Result<()> {
+        Plus::deprecated_202007_get_user_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_users_param_user(&self, user_id: &str) -> Result<()> {
+        Plus::deprecated_202007_get_users_param_user(self, user_id).await
+    }
+
+    async fn deprecated_202007_get_users_current(&self) -> Result<()> {
+        Plus::deprecated_202007_get_users_current(self).await
+    }
+
+    async fn get_user_with(&self, params: &PlusGetUserParams) -> Result<()> {
+        Plus::get_user_with(self, params).await
+    }
+
+    async fn get_users_param_user(&self, user_id: &str) -> Result<()> {
+        Plus::get_users_param_user(self, user_id).await
+    }
+
+    async fn get_users_current(&self) -> Result<()> {
+        Plus::get_users_current(self).await
+    }
+
+    async fn deprecated_202101_get_user_with(
+        &self,
+        params: &PlusDeprecated202101GetUserParams,
+    ) -> Result<()> {
+        Plus::deprecated_202101_get_user_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_users_param_user(&self, user_id: &str) -> Result<()> {
+        Plus::deprecated_202101_get_users_param_user(self, user_id).await
+    }
+
+    async fn deprecated_202101_get_users_current(&self) -> Result<()> {
+        Plus::deprecated_202101_get_users_current(self).await
+    }
+
+    async fn deprecated_unstable_get_user_with(
+        &self,
+        params: &PlusDeprecatedUnstableGetUserParams,
+    ) -> Result<()> {
+        Plus::deprecated_unstable_get_user_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_users_param_user(&self, user_id: &str) -> Result<()> {
+        Plus::deprecated_unstable_get_users_param_user(self, user_id).await
+    }
+
+    async fn deprecated_unstable_get_users_current(&self) -> Result<()> {
+        Plus::deprecated_unstable_get_users_current(self).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Products {
     pub client: Client,
@@ -21,25 +19,20 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202001GetCollectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_collect(
+    pub async fn deprecated_202001_get_collect_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202001GetCollectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -48,6 +41,24 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_collect_with` and pass the query parameters as a `ProductsDeprecated202001GetCollectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_collect_with` instead")]
+    pub async fn deprecated_202001_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetCollectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_collect_with(&params).await
+    }
+
     /**
      * Adds a product to a custom collection.
      *
@@ -72,15 +83,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202001GetCollectsParamCollectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_collects_param_collect(
+    pub async fn deprecated_202001_get_collects_param_collect_with(
         &self,
         collect_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202001GetCollectsParamCollectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -93,6 +104,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_collects_param_collect_with` and pass the query parameters as a `ProductsDeprecated202001GetCollectsParamCollectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_collects_param_collect_with` instead")]
+    pub async fn deprecated_202001_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetCollectsParamCollectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_collects_param_collect_with(collect_id, &params)
+            .await
+    }
+
     /**
      * Removes a product from a collection.
      *
@@ -125,11 +152,14 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ProductsDeprecated202001GetCollectsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_collects_count(&self, collection_id: i64) -> Result<()> {
+    pub async fn deprecated_202001_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecated202001GetCollectsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if collection_id > 0 {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -138,6 +168,18 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_collects_count_with` and pass the query parameters as a `ProductsDeprecated202001GetCollectsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_collects_count_with` instead")]
+    pub async fn deprecated_202001_get_collects_count(&self, collection_id: i64) -> Result<()> {
+        let params = ProductsDeprecated202001GetCollectsCountParams {
+            collection_id: Some(collection_id).filter(|v| *v > 0),
+        };
+        self.deprecated_202001_get_collects_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -147,25 +189,20 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202004GetCollectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_collect(
+    pub async fn deprecated_202004_get_collect_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202004GetCollectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -174,6 +211,24 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_collect_with` and pass the query parameters as a `ProductsDeprecated202004GetCollectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_collect_with` instead")]
+    pub async fn deprecated_202004_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetCollectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_collect_with(&params).await
+    }
+
     /**
      * Adds a product to a custom collection.
      *
@@ -198,15 +253,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202004GetCollectsParamCollectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_collects_param_collect(
+    pub async fn deprecated_202004_get_collects_param_collect_with(
         &self,
         collect_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202004GetCollectsParamCollectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -219,6 +274,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_collects_param_collect_with` and pass the query parameters as a `ProductsDeprecated202004GetCollectsParamCollectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_collects_param_collect_with` instead")]
+    pub async fn deprecated_202004_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetCollectsParamCollectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_collects_param_collect_with(collect_id, &params)
+            .await
+    }
+
     /**
      * Removes a product from a collection.
      *
@@ -251,11 +322,14 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ProductsDeprecated202004GetCollectsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_collects_count(&self, collection_id: i64) -> Result<()> {
+    pub async fn deprecated_202004_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecated202004GetCollectsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if collection_id > 0 {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -264,6 +338,18 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_collects_count_with` and pass the query parameters as a `ProductsDeprecated202004GetCollectsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_collects_count_with` instead")]
+    pub async fn deprecated_202004_get_collects_count(&self, collection_id: i64) -> Result<()> {
+        let params = ProductsDeprecated202004GetCollectsCountParams {
+            collection_id: Some(collection_id).filter(|v| *v > 0),
+        };
+        self.deprecated_202004_get_collects_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -273,25 +359,20 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202007GetCollectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_collect(
+    pub async fn deprecated_202007_get_collect_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202007GetCollectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -300,6 +381,24 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_collect_with` and pass the query parameters as a `ProductsDeprecated202007GetCollectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_collect_with` instead")]
+    pub async fn deprecated_202007_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetCollectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_collect_with(&params).await
+    }
+
     /**
      * Adds a product to a custom collection.
      *
@@ -324,15 +423,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202007GetCollectsParamCollectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_collects_param_collect(
+    pub async fn deprecated_202007_get_collects_param_collect_with(
         &self,
         collect_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202007GetCollectsParamCollectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -345,6 +444,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_collects_param_collect_with` and pass the query parameters as a `ProductsDeprecated202007GetCollectsParamCollectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_collects_param_collect_with` instead")]
+    pub async fn deprecated_202007_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetCollectsParamCollectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_collects_param_collect_with(collect_id, &params)
+            .await
+    }
+
     /**
      * Removes a product from a collection.
      *
@@ -377,11 +492,14 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ProductsDeprecated202007GetCollectsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_collects_count(&self, collection_id: i64) -> Result<()> {
+    pub async fn deprecated_202007_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecated202007GetCollectsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if collection_id > 0 {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -390,6 +508,18 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_collects_count_with` and pass the query parameters as a `ProductsDeprecated202007GetCollectsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_collects_count_with` instead")]
+    pub async fn deprecated_202007_get_collects_count(&self, collection_id: i64) -> Result<()> {
+        let params = ProductsDeprecated202007GetCollectsCountParams {
+            collection_id: Some(collection_id).filter(|v| *v > 0),
+        };
+        self.deprecated_202007_get_collects_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -399,20 +529,17 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsGetCollectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_collect(&self, limit: &str, since_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_collect_with(&self, params: &ProductsGetCollectParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -421,6 +548,19 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_collect_with` and pass the query parameters as a `ProductsGetCollectParams` instead.
+     */
+    #[deprecated(note = "use `get_collect_with` instead")]
+    pub async fn get_collect(&self, limit: &str, since_id: &str, fields: &str) -> Result<()> {
+        let params = ProductsGetCollectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_collect_with(&params).await
+    }
+
     /**
      * Adds a product to a custom collection.
      *
@@ -445,11 +585,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsGetCollectsParamCollectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_collects_param_collect(&self, collect_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_collects_param_collect_with(
+        &self,
+        collect_id: &str,
+        params: &ProductsGetCollectsParamCollectParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -462,6 +606,18 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_collects_param_collect_with` and pass the query parameters as a `ProductsGetCollectsParamCollectParams` instead.
+     */
+    #[deprecated(note = "use `get_collects_param_collect_with` instead")]
+    pub async fn get_collects_param_collect(&self, collect_id: &str, fields: &str) -> Result<()> {
+        let params = ProductsGetCollectsParamCollectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_collects_param_collect_with(collect_id, &params)
+            .await
+    }
+
     /**
      * Removes a product from a collection.
      *
@@ -491,11 +647,14 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ProductsGetCollectsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_collects_count(&self, collection_id: i64) -> Result<()> {
+    pub async fn get_collects_count_with(
+        &self,
+        params: &ProductsGetCollectsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if collection_id > 0 {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -504,6 +663,17 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_collects_count_with` and pass the query parameters as a `ProductsGetCollectsCountParams` instead.
+     */
+    #[deprecated(note = "use `get_collects_count_with` instead")]
+    pub async fn get_collects_count(&self, collection_id: i64) -> Result<()> {
+        let params = ProductsGetCollectsCountParams {
+            collection_id: Some(collection_id).filter(|v| *v > 0),
+        };
+        self.get_collects_count_with(&params).await
+    }
+
     /**
      * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -513,25 +683,20 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202101GetCollectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_collect(
+    pub async fn deprecated_202101_get_collect_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202101GetCollectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -540,6 +705,24 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_collect_with` and pass the query parameters as a `ProductsDeprecated202101GetCollectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_collect_with` instead")]
+    pub async fn deprecated_202101_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetCollectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_collect_with(&params).await
+    }
+
     /**
      * Adds a product to a custom collection.
      *
@@ -564,15 +747,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202101GetCollectsParamCollectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_collects_param_collect(
+    pub async fn deprecated_202101_get_collects_param_collect_with(
         &self,
         collect_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202101GetCollectsParamCollectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -585,6 +768,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_collects_param_collect_with` and pass the query parameters as a `ProductsDeprecated202101GetCollectsParamCollectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_collects_param_collect_with` instead")]
+    pub async fn deprecated_202101_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetCollectsParamCollectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_collects_param_collect_with(collect_id, &params)
+            .await
+    }
+
     /**
      * Removes a product from a collection.
      *
@@ -617,11 +816,14 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ProductsDeprecated202101GetCollectsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_collects_count(&self, collection_id: i64) -> Result<()> {
+    pub async fn deprecated_202101_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecated202101GetCollectsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if collection_id > 0 {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -630,6 +832,18 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_collects_count_with` and pass the query parameters as a `ProductsDeprecated202101GetCollectsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_collects_count_with` instead")]
+    pub async fn deprecated_202101_get_collects_count(&self, collection_id: i64) -> Result<()> {
+        let params = ProductsDeprecated202101GetCollectsCountParams {
+            collection_id: Some(collection_id).filter(|v| *v > 0),
+        };
+        self.deprecated_202101_get_collects_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of collects. Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -639,25 +853,20 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecatedUnstableGetCollectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_collect(
+    pub async fn deprecated_unstable_get_collect_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        fields: &str,
+        params: &ProductsDeprecatedUnstableGetCollectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -666,6 +875,24 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_collect_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetCollectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_collect_with` instead")]
+    pub async fn deprecated_unstable_get_collect(
+        &self,
+        limit: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetCollectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_collect_with(&params).await
+    }
+
     /**
      * Adds a product to a custom collection.
      *
@@ -693,15 +920,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collect_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecatedUnstableGetCollectsParamCollectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_collects_param_collect(
+    pub async fn deprecated_unstable_get_collects_param_collect_with(
         &self,
         collect_id: &str,
-        fields: &str,
+        params: &ProductsDeprecatedUnstableGetCollectsParamCollectParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -714,6 +941,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_collects_param_collect_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetCollectsParamCollectParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_collects_param_collect_with` instead")]
+    pub async fn deprecated_unstable_get_collects_param_collect(
+        &self,
+        collect_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetCollectsParamCollectParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_collects_param_collect_with(collect_id, &params)
+            .await
+    }
+
     /**
      * Removes a product from a collection.
      *
@@ -746,11 +989,14 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `collection_id: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ProductsDeprecatedUnstableGetCollectsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_collects_count(&self, collection_id: i64) -> Result<()> {
+    pub async fn deprecated_unstable_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetCollectsCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if collection_id > 0 {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -759,6 +1005,18 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_collects_count_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetCollectsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_collects_count_with` instead")]
+    pub async fn deprecated_unstable_get_collects_count(&self, collection_id: i64) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetCollectsCountParams {
+            collection_id: Some(collection_id).filter(|v| *v > 0),
+        };
+        self.deprecated_unstable_get_collects_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single collection.
      *
@@ -769,15 +1027,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202001GetCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_collections_param_collection(
+    pub async fn deprecated_202001_get_collections_param_collection_with(
         &self,
         collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202001GetCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -790,6 +1048,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecated202001GetCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_collections_param_collection_with` instead")]
+    pub async fn deprecated_202001_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_collections_param_collection_with(collection_id, &params)
+            .await
+    }
+
     /**
      * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
@@ -800,16 +1074,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collection_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The number of products to retrieve.
-     *                     (default: 50, maximum: 250).
+     * * `params: &ProductsDeprecated202001GetCollectionsParamCollectionProductsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_collections_param_collection_products(
+    pub async fn deprecated_202001_get_collections_param_collection_products_with(
         &self,
         collection_id: &str,
-        limit: &str,
+        params: &ProductsDeprecated202001GetCollectionsParamCollectionProductsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -822,6 +1095,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_collections_param_collection_products_with` and pass the query parameters as a `ProductsDeprecated202001GetCollectionsParamCollectionProductsParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_collections_param_collection_products_with` instead"
+    )]
+    pub async fn deprecated_202001_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetCollectionsParamCollectionProductsParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_collections_param_collection_products_with(
+            collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a single collection.
      *
@@ -832,15 +1126,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202004GetCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_collections_param_collection(
+    pub async fn deprecated_202004_get_collections_param_collection_with(
         &self,
         collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202004GetCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -853,6 +1147,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecated202004GetCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_collections_param_collection_with` instead")]
+    pub async fn deprecated_202004_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_collections_param_collection_with(collection_id, &params)
+            .await
+    }
+
     /**
      * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
@@ -863,16 +1173,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collection_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The number of products to retrieve.
-     *                     (default: 50, maximum: 250).
+     * * `params: &ProductsDeprecated202004GetCollectionsParamCollectionProductsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_collections_param_collection_products(
+    pub async fn deprecated_202004_get_collections_param_collection_products_with(
         &self,
         collection_id: &str,
-        limit: &str,
+        params: &ProductsDeprecated202004GetCollectionsParamCollectionProductsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -885,6 +1194,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_collections_param_collection_products_with` and pass the query parameters as a `ProductsDeprecated202004GetCollectionsParamCollectionProductsParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_collections_param_collection_products_with` instead"
+    )]
+    pub async fn deprecated_202004_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetCollectionsParamCollectionProductsParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_collections_param_collection_products_with(
+            collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a single collection.
      *
@@ -895,15 +1225,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202007GetCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_collections_param_collection(
+    pub async fn deprecated_202007_get_collections_param_collection_with(
         &self,
         collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202007GetCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -917,25 +1247,40 @@ impl Products {
     }
 
     /**
-     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-07/collections/{collection_id}/products.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2020-07
-     *
-     * **Parameters:**
-     *
+     * Deprecated: use `deprecated_202007_get_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecated202007GetCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_collections_param_collection_with` instead")]
+    pub async fn deprecated_202007_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_collections_param_collection_with(collection_id, &params)
+            .await
+    }
+
+    /**
+     * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-07/collections/{collection_id}/products.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/collection#products-2020-07
+     *
+     * **Parameters:**
+     *
      * * `collection_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The number of products to retrieve.
-     *                     (default: 50, maximum: 250).
+     * * `params: &ProductsDeprecated202007GetCollectionsParamCollectionProductsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_collections_param_collection_products(
+    pub async fn deprecated_202007_get_collections_param_collection_products_with(
         &self,
         collection_id: &str,
-        limit: &str,
+        params: &ProductsDeprecated202007GetCollectionsParamCollectionProductsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -948,6 +1293,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_collections_param_collection_products_with` and pass the query parameters as a `ProductsDeprecated202007GetCollectionsParamCollectionProductsParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_collections_param_collection_products_with` instead"
+    )]
+    pub async fn deprecated_202007_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetCollectionsParamCollectionProductsParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_collections_param_collection_products_with(
+            collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a single collection.
      *
@@ -958,15 +1324,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsGetCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_collections_param_collection(
+    pub async fn get_collections_param_collection_with(
         &self,
         collection_id: &str,
-        fields: &str,
+        params: &ProductsGetCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -979,6 +1345,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_collections_param_collection_with` and pass the query parameters as a `ProductsGetCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(note = "use `get_collections_param_collection_with` instead")]
+    pub async fn get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsGetCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_collections_param_collection_with(collection_id, &params)
+            .await
+    }
+
     /**
      * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
@@ -989,16 +1371,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collection_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The number of products to retrieve.
-     *                     (default: 50, maximum: 250).
+     * * `params: &ProductsGetCollectionsParamCollectionProductsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_collections_param_collection_products(
+    pub async fn get_collections_param_collection_products_with(
         &self,
         collection_id: &str,
-        limit: &str,
+        params: &ProductsGetCollectionsParamCollectionProductsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1011,6 +1392,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_collections_param_collection_products_with` and pass the query parameters as a `ProductsGetCollectionsParamCollectionProductsParams` instead.
+     */
+    #[deprecated(note = "use `get_collections_param_collection_products_with` instead")]
+    pub async fn get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        let params = ProductsGetCollectionsParamCollectionProductsParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_collections_param_collection_products_with(collection_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a single collection.
      *
@@ -1021,15 +1418,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202101GetCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_collections_param_collection(
+    pub async fn deprecated_202101_get_collections_param_collection_with(
         &self,
         collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202101GetCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1042,6 +1439,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecated202101GetCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_collections_param_collection_with` instead")]
+    pub async fn deprecated_202101_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_collections_param_collection_with(collection_id, &params)
+            .await
+    }
+
     /**
      * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
@@ -1052,16 +1465,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collection_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The number of products to retrieve.
-     *                     (default: 50, maximum: 250).
+     * * `params: &ProductsDeprecated202101GetCollectionsParamCollectionProductsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_collections_param_collection_products(
+    pub async fn deprecated_202101_get_collections_param_collection_products_with(
         &self,
         collection_id: &str,
-        limit: &str,
+        params: &ProductsDeprecated202101GetCollectionsParamCollectionProductsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1074,6 +1486,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_collections_param_collection_products_with` and pass the query parameters as a `ProductsDeprecated202101GetCollectionsParamCollectionProductsParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_collections_param_collection_products_with` instead"
+    )]
+    pub async fn deprecated_202101_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetCollectionsParamCollectionProductsParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_collections_param_collection_products_with(
+            collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a single collection.
      *
@@ -1084,15 +1517,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecatedUnstableGetCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_collections_param_collection(
+    pub async fn deprecated_unstable_get_collections_param_collection_with(
         &self,
         collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecatedUnstableGetCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1105,6 +1538,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_collections_param_collection_with` instead")]
+    pub async fn deprecated_unstable_get_collections_param_collection(
+        &self,
+        collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_collections_param_collection_with(collection_id, &params)
+            .await
+    }
+
     /**
      * Retrieve a list of products belonging to a collection. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.. The products returned are sorted by the collection's sort order.
      *
@@ -1115,16 +1564,15 @@ impl Products {
      * **Parameters:**
      *
      * * `collection_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- The number of products to retrieve.
-     *                     (default: 50, maximum: 250).
+     * * `params: &ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_collections_param_collection_products(
+    pub async fn deprecated_unstable_get_collections_param_collection_products_with(
         &self,
         collection_id: &str,
-        limit: &str,
+        params: &ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1137,6 +1585,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_collections_param_collection_products_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_collections_param_collection_products_with` instead"
+    )]
+    pub async fn deprecated_unstable_get_collections_param_collection_products(
+        &self,
+        collection_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_collections_param_collection_products_with(
+            collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of custom collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1146,75 +1615,47 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show custom collections with a given title.
-     * * `product_id: &str` -- Show custom collections that include a given product.
-     * * `handle: &str` -- Filter by custom collection handle.
-     * * `updated_at_min: &str` -- Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Show custom collectsion with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published custom collections.
-     *                           unpublished: Show only unpublished custom collections.
-     *                           any: Show custom collections of any published status.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202001GetCustomCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_custom_collection(
+    pub async fn deprecated_202001_get_custom_collection_with(
         &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
+        params: &ProductsDeprecated202001GetCustomCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1223,6 +1664,43 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_custom_collection_with` and pass the query parameters as a `ProductsDeprecated202001GetCustomCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_custom_collection_with` instead")]
+    pub async fn deprecated_202001_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetCustomCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_custom_collection_with(&params)
+            .await
+    }
+
     /**
      * Creates a custom collection.
      *
@@ -1249,49 +1727,32 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `title: &str` -- Count custom collections with given title.
-     * * `product_id: &str` -- Count custom collections that include a given product.
-     * * `updated_at_min: &str` -- Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count custom collections with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published custom collections.
-     *                           unpublished: Count only unpublished custom collections.
-     *                           any: Count custom collections of any published status.
+     * * `params: &ProductsDeprecated202001GetCustomCollectionsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_custom_collections_count(
+    pub async fn deprecated_202001_get_custom_collections_count_with(
         &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecated202001GetCustomCollectionsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1303,6 +1764,33 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_custom_collections_count_with` and pass the query parameters as a `ProductsDeprecated202001GetCustomCollectionsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_custom_collections_count_with` instead")]
+    pub async fn deprecated_202001_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetCustomCollectionsCountParams {
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_custom_collections_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single custom collection.
      *
@@ -1313,15 +1801,15 @@ impl Products {
      * **Parameters:**
      *
      * * `custom_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202001GetCustomCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_custom_collections_param_collection(
+    pub async fn deprecated_202001_get_custom_collections_param_collection_with(
         &self,
         custom_collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202001GetCustomCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1334,6 +1822,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_custom_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecated202001GetCustomCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_custom_collections_param_collection_with` instead"
+    )]
+    pub async fn deprecated_202001_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetCustomCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_custom_collections_param_collection_with(
+            custom_collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates an existing custom collection.
      *
@@ -1392,75 +1901,47 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show custom collections with a given title.
-     * * `product_id: &str` -- Show custom collections that include a given product.
-     * * `handle: &str` -- Filter by custom collection handle.
-     * * `updated_at_min: &str` -- Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Show custom collectsion with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published custom collections.
-     *                           unpublished: Show only unpublished custom collections.
-     *                           any: Show custom collections of any published status.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202004GetCustomCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_custom_collection(
+    pub async fn deprecated_202004_get_custom_collection_with(
         &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
+        params: &ProductsDeprecated202004GetCustomCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1469,6 +1950,43 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_custom_collection_with` and pass the query parameters as a `ProductsDeprecated202004GetCustomCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_custom_collection_with` instead")]
+    pub async fn deprecated_202004_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetCustomCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_custom_collection_with(&params)
+            .await
+    }
+
     /**
      * Creates a custom collection.
      *
@@ -1495,49 +2013,32 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `title: &str` -- Count custom collections with given title.
-     * * `product_id: &str` -- Count custom collections that include a given product.
-     * * `updated_at_min: &str` -- Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count custom collections with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published custom collections.
-     *                           unpublished: Count only unpublished custom collections.
-     *                           any: Count custom collections of any published status.
+     * * `params: &ProductsDeprecated202004GetCustomCollectionsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_custom_collections_count(
+    pub async fn deprecated_202004_get_custom_collections_count_with(
         &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecated202004GetCustomCollectionsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1549,6 +2050,33 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_custom_collections_count_with` and pass the query parameters as a `ProductsDeprecated202004GetCustomCollectionsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_custom_collections_count_with` instead")]
+    pub async fn deprecated_202004_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetCustomCollectionsCountParams {
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_custom_collections_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single custom collection.
      *
@@ -1559,15 +2087,15 @@ impl Products {
      * **Parameters:**
      *
      * * `custom_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202004GetCustomCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_custom_collections_param_collection(
+    pub async fn deprecated_202004_get_custom_collections_param_collection_with(
         &self,
         custom_collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202004GetCustomCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1580,6 +2108,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_custom_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecated202004GetCustomCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_custom_collections_param_collection_with` instead"
+    )]
+    pub async fn deprecated_202004_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetCustomCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_custom_collections_param_collection_with(
+            custom_collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates an existing custom collection.
      *
@@ -1638,75 +2187,47 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show custom collections with a given title.
-     * * `product_id: &str` -- Show custom collections that include a given product.
-     * * `handle: &str` -- Filter by custom collection handle.
-     * * `updated_at_min: &str` -- Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Show custom collectsion with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published custom collections.
-     *                           unpublished: Show only unpublished custom collections.
-     *                           any: Show custom collections of any published status.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202007GetCustomCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_custom_collection(
+    pub async fn deprecated_202007_get_custom_collection_with(
         &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
+        params: &ProductsDeprecated202007GetCustomCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1716,17 +2237,54 @@ impl Products {
     }
 
     /**
-     * Creates a custom collection.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-07/custom_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#create-2020-07
+     * Deprecated: use `deprecated_202007_get_custom_collection_with` and pass the query parameters as a `ProductsDeprecated202007GetCustomCollectionParams` instead.
      */
-    pub async fn deprecated_202007_create_custom_collections(
+    #[deprecated(note = "use `deprecated_202007_get_custom_collection_with` instead")]
+    pub async fn deprecated_202007_get_custom_collection(
         &self,
-        body: &serde_json::Value,
-    ) -> Result<()> {
-        let url = "/admin/api/2020-07/custom_collections.json".to_string();
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetCustomCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_custom_collection_with(&params)
+            .await
+    }
+
+    /**
+     * Creates a custom collection.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-07/custom_collections.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#create-2020-07
+     */
+    pub async fn deprecated_202007_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let url = "/admin/api/2020-07/custom_collections.json".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
@@ -1741,49 +2299,32 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `title: &str` -- Count custom collections with given title.
-     * * `product_id: &str` -- Count custom collections that include a given product.
-     * * `updated_at_min: &str` -- Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count custom collections with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published custom collections.
-     *                           unpublished: Count only unpublished custom collections.
-     *                           any: Count custom collections of any published status.
+     * * `params: &ProductsDeprecated202007GetCustomCollectionsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_custom_collections_count(
+    pub async fn deprecated_202007_get_custom_collections_count_with(
         &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecated202007GetCustomCollectionsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1795,6 +2336,33 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_custom_collections_count_with` and pass the query parameters as a `ProductsDeprecated202007GetCustomCollectionsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_custom_collections_count_with` instead")]
+    pub async fn deprecated_202007_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetCustomCollectionsCountParams {
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_custom_collections_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single custom collection.
      *
@@ -1805,15 +2373,15 @@ impl Products {
      * **Parameters:**
      *
      * * `custom_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202007GetCustomCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_custom_collections_param_collection(
+    pub async fn deprecated_202007_get_custom_collections_param_collection_with(
         &self,
         custom_collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202007GetCustomCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1826,6 +2394,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_custom_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecated202007GetCustomCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_custom_collections_param_collection_with` instead"
+    )]
+    pub async fn deprecated_202007_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetCustomCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_custom_collections_param_collection_with(
+            custom_collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates an existing custom collection.
      *
@@ -1884,75 +2473,47 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show custom collections with a given title.
-     * * `product_id: &str` -- Show custom collections that include a given product.
-     * * `handle: &str` -- Filter by custom collection handle.
-     * * `updated_at_min: &str` -- Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Show custom collectsion with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published custom collections.
-     *                           unpublished: Show only unpublished custom collections.
-     *                           any: Show custom collections of any published status.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsGetCustomCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_custom_collection(
+    pub async fn get_custom_collection_with(
         &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
+        params: &ProductsGetCustomCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1961,6 +2522,42 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_custom_collection_with` and pass the query parameters as a `ProductsGetCustomCollectionParams` instead.
+     */
+    #[deprecated(note = "use `get_custom_collection_with` instead")]
+    pub async fn get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsGetCustomCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_custom_collection_with(&params).await
+    }
+
     /**
      * Creates a custom collection.
      *
@@ -1984,49 +2581,32 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `title: &str` -- Count custom collections with given title.
-     * * `product_id: &str` -- Count custom collections that include a given product.
-     * * `updated_at_min: &str` -- Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count custom collections with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published custom collections.
-     *                           unpublished: Count only unpublished custom collections.
-     *                           any: Count custom collections of any published status.
+     * * `params: &ProductsGetCustomCollectionsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_custom_collections_count(
+    pub async fn get_custom_collections_count_with(
         &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsGetCustomCollectionsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2038,6 +2618,32 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_custom_collections_count_with` and pass the query parameters as a `ProductsGetCustomCollectionsCountParams` instead.
+     */
+    #[deprecated(note = "use `get_custom_collections_count_with` instead")]
+    pub async fn get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsGetCustomCollectionsCountParams {
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_custom_collections_count_with(&params).await
+    }
+
     /**
      * Retrieves a single custom collection.
      *
@@ -2048,15 +2654,15 @@ impl Products {
      * **Parameters:**
      *
      * * `custom_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsGetCustomCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_custom_collections_param_collection(
+    pub async fn get_custom_collections_param_collection_with(
         &self,
         custom_collection_id: &str,
-        fields: &str,
+        params: &ProductsGetCustomCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2069,6 +2675,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_custom_collections_param_collection_with` and pass the query parameters as a `ProductsGetCustomCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(note = "use `get_custom_collections_param_collection_with` instead")]
+    pub async fn get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsGetCustomCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_custom_collections_param_collection_with(custom_collection_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing custom collection.
      *
@@ -2127,75 +2749,47 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show custom collections with a given title.
-     * * `product_id: &str` -- Show custom collections that include a given product.
-     * * `handle: &str` -- Filter by custom collection handle.
-     * * `updated_at_min: &str` -- Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Show custom collectsion with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published custom collections.
-     *                           unpublished: Show only unpublished custom collections.
-     *                           any: Show custom collections of any published status.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202101GetCustomCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_custom_collection(
+    pub async fn deprecated_202101_get_custom_collection_with(
         &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
+        params: &ProductsDeprecated202101GetCustomCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2204,6 +2798,43 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_custom_collection_with` and pass the query parameters as a `ProductsDeprecated202101GetCustomCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_custom_collection_with` instead")]
+    pub async fn deprecated_202101_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetCustomCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_custom_collection_with(&params)
+            .await
+    }
+
     /**
      * Creates a custom collection.
      *
@@ -2230,49 +2861,32 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `title: &str` -- Count custom collections with given title.
-     * * `product_id: &str` -- Count custom collections that include a given product.
-     * * `updated_at_min: &str` -- Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count custom collections with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published custom collections.
-     *                           unpublished: Count only unpublished custom collections.
-     *                           any: Count custom collections of any published status.
+     * * `params: &ProductsDeprecated202101GetCustomCollectionsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_custom_collections_count(
+    pub async fn deprecated_202101_get_custom_collections_count_with(
         &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecated202101GetCustomCollectionsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2284,6 +2898,33 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_custom_collections_count_with` and pass the query parameters as a `ProductsDeprecated202101GetCustomCollectionsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_custom_collections_count_with` instead")]
+    pub async fn deprecated_202101_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetCustomCollectionsCountParams {
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_custom_collections_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single custom collection.
      *
@@ -2294,15 +2935,15 @@ impl Products {
      * **Parameters:**
      *
      * * `custom_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202101GetCustomCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_custom_collections_param_collection(
+    pub async fn deprecated_202101_get_custom_collections_param_collection_with(
         &self,
         custom_collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202101GetCustomCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2315,6 +2956,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_custom_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecated202101GetCustomCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_custom_collections_param_collection_with` instead"
+    )]
+    pub async fn deprecated_202101_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetCustomCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_custom_collections_param_collection_with(
+            custom_collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates an existing custom collection.
      *
@@ -2373,75 +3035,47 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show custom collections with a given title.
-     * * `product_id: &str` -- Show custom collections that include a given product.
-     * * `handle: &str` -- Filter by custom collection handle.
-     * * `updated_at_min: &str` -- Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Show custom collectsion with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published custom collections.
-     *                           unpublished: Show only unpublished custom collections.
-     *                           any: Show custom collections of any published status.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecatedUnstableGetCustomCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_custom_collection(
+    pub async fn deprecated_unstable_get_custom_collection_with(
         &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
+        params: &ProductsDeprecatedUnstableGetCustomCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2450,6 +3084,43 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_custom_collection_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetCustomCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_custom_collection_with` instead")]
+    pub async fn deprecated_unstable_get_custom_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetCustomCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_custom_collection_with(&params)
+            .await
+    }
+
     /**
      * Creates a custom collection.
      *
@@ -2476,49 +3147,32 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `title: &str` -- Count custom collections with given title.
-     * * `product_id: &str` -- Count custom collections that include a given product.
-     * * `updated_at_min: &str` -- Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Count custom collections with a given published status.
-     *                     (default: any)
-     *                       
-     *                           published: Count only published custom collections.
-     *                           unpublished: Count only unpublished custom collections.
-     *                           any: Count custom collections of any published status.
+     * * `params: &ProductsDeprecatedUnstableGetCustomCollectionsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_custom_collections_count(
+    pub async fn deprecated_unstable_get_custom_collections_count_with(
         &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecatedUnstableGetCustomCollectionsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2530,6 +3184,33 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_custom_collections_count_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetCustomCollectionsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_custom_collections_count_with` instead")]
+    pub async fn deprecated_unstable_get_custom_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetCustomCollectionsCountParams {
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_custom_collections_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single custom collection.
      *
@@ -2540,15 +3221,15 @@ impl Products {
      * **Parameters:**
      *
      * * `custom_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_custom_collections_param_collection(
+    pub async fn deprecated_unstable_get_custom_collections_param_collection_with(
         &self,
         custom_collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2562,9 +3243,30 @@ impl Products {
     }
 
     /**
-     * Updates an existing custom collection.
-     *
-     * This function performs a `PUT` to the `/admin/api/unstable/custom_collections/{custom_collection_id}.json` endpoint.
+     * Deprecated: use `deprecated_unstable_get_custom_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_custom_collections_param_collection_with` instead"
+    )]
+    pub async fn deprecated_unstable_get_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_custom_collections_param_collection_with(
+            custom_collection_id,
+            &params,
+        )
+        .await
+    }
+
+    /**
+     * Updates an existing custom collection.
+     *
+     * This function performs a `PUT` to the `/admin/api/unstable/custom_collections/{custom_collection_id}.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/products/customcollection#update-unstable
      *
@@ -2619,113 +3321,68 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Return only products specified by a comma-separated list of product IDs.
-     * * `limit: &str` -- Return up to this many results per page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Filter results by product title.
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `handle: &str` -- Filter results by product handle.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `status: &str` -- Return products by their status.
-     *                     (default: active)
-     *                       
-     *                           active: Show only active products.
-     *                           archived: Show only archived products.
-     *                           draft: Show only draft products.
-     * * `collection_id: &str` -- Filter results by product collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `presentment_currencies: &str` -- Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
+     * * `params: &ProductsDeprecated202001GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get(
+    pub async fn deprecated_202001_get_with(
         &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        title: &str,
-        vendor: &str,
-        handle: &str,
-        product_type: &str,
-        status: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-        presentment_currencies: &str,
+        params: &ProductsDeprecated202001GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !presentment_currencies.is_empty() {
+        if let Some(presentment_currencies) = &params.presentment_currencies {
             query_args.push((
                 "presentment_currencies".to_string(),
                 presentment_currencies.to_string(),
             ));
         }
-        if !product_type.is_empty() {
+        if let Some(product_type) = &params.product_type {
             query_args.push(("product_type".to_string(), product_type.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
-        if !vendor.is_empty() {
+        if let Some(vendor) = &params.vendor {
             query_args.push(("vendor".to_string(), vendor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2734,6 +3391,55 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_with` and pass the query parameters as a `ProductsDeprecated202001GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_with` instead")]
+    pub async fn deprecated_202001_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            presentment_currencies: Some(presentment_currencies.to_string())
+                .filter(|s| !s.is_empty()),
+            product_type: Some(product_type.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            vendor: Some(vendor.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_with(&params).await
+    }
+
     /**
     * Creates a new product.
               If you want to set the product's SEO information, then you can use the following properties:
@@ -2761,64 +3467,41 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `collection_id: &str` -- Filter results by collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
+     * * `params: &ProductsDeprecated202001GetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_count(
+    pub async fn deprecated_202001_get_count_with(
         &self,
-        vendor: &str,
-        product_type: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecated202001GetCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !product_type.is_empty() {
+        if let Some(product_type) = &params.product_type {
             query_args.push(("product_type".to_string(), product_type.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
-        if !vendor.is_empty() {
+        if let Some(vendor) = &params.vendor {
             query_args.push(("vendor".to_string(), vendor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2827,6 +3510,38 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_count_with` and pass the query parameters as a `ProductsDeprecated202001GetCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_count_with` instead")]
+    pub async fn deprecated_202001_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetCountParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            product_type: Some(product_type.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            vendor: Some(vendor.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_count_with(&params).await
+    }
+
     /**
      * Retrieves a single product.
      *
@@ -2837,11 +3552,15 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecated202001GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202001GetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2854,6 +3573,18 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_param_with` and pass the query parameters as a `ProductsDeprecated202001GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_param_with` instead")]
+    pub async fn deprecated_202001_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+        let params = ProductsDeprecated202001GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_param_with(product_id, &params)
+            .await
+    }
+
     /**
     * Updates a product and its variants and images.
               If you want to update the product's SEO information, then you can use the following properties:
@@ -2913,113 +3644,68 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Return only products specified by a comma-separated list of product IDs.
-     * * `limit: &str` -- Return up to this many results per page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Filter results by product title.
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `handle: &str` -- Filter results by product handle.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `status: &str` -- Return products by their status.
-     *                     (default: active)
-     *                       
-     *                           active: Show only active products.
-     *                           archived: Show only archived products.
-     *                           draft: Show only draft products.
-     * * `collection_id: &str` -- Filter results by product collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `presentment_currencies: &str` -- Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
+     * * `params: &ProductsDeprecated202004GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get(
+    pub async fn deprecated_202004_get_with(
         &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        title: &str,
-        vendor: &str,
-        handle: &str,
-        product_type: &str,
-        status: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-        presentment_currencies: &str,
+        params: &ProductsDeprecated202004GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !presentment_currencies.is_empty() {
+        if let Some(presentment_currencies) = &params.presentment_currencies {
             query_args.push((
                 "presentment_currencies".to_string(),
                 presentment_currencies.to_string(),
             ));
         }
-        if !product_type.is_empty() {
+        if let Some(product_type) = &params.product_type {
             query_args.push(("product_type".to_string(), product_type.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
-        if !vendor.is_empty() {
+        if let Some(vendor) = &params.vendor {
             query_args.push(("vendor".to_string(), vendor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3028,6 +3714,55 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_with` and pass the query parameters as a `ProductsDeprecated202004GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_with` instead")]
+    pub async fn deprecated_202004_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            presentment_currencies: Some(presentment_currencies.to_string())
+                .filter(|s| !s.is_empty()),
+            product_type: Some(product_type.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            vendor: Some(vendor.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_with(&params).await
+    }
+
     /**
     * Creates a new product.
               If you want to set the product's SEO information, then you can use the following properties:
@@ -3055,64 +3790,41 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `collection_id: &str` -- Filter results by collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
+     * * `params: &ProductsDeprecated202004GetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_count(
+    pub async fn deprecated_202004_get_count_with(
         &self,
-        vendor: &str,
-        product_type: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecated202004GetCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !product_type.is_empty() {
+        if let Some(product_type) = &params.product_type {
             query_args.push(("product_type".to_string(), product_type.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
-        if !vendor.is_empty() {
+        if let Some(vendor) = &params.vendor {
             query_args.push(("vendor".to_string(), vendor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3121,6 +3833,38 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_count_with` and pass the query parameters as a `ProductsDeprecated202004GetCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_count_with` instead")]
+    pub async fn deprecated_202004_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetCountParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            product_type: Some(product_type.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            vendor: Some(vendor.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_count_with(&params).await
+    }
+
     /**
      * Retrieves a single product.
      *
@@ -3131,11 +3875,15 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecated202004GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202004GetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3148,6 +3896,18 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_param_with` and pass the query parameters as a `ProductsDeprecated202004GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_param_with` instead")]
+    pub async fn deprecated_202004_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+        let params = ProductsDeprecated202004GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_param_with(product_id, &params)
+            .await
+    }
+
     /**
     * Updates a product and its variants and images.
               If you want to update the product's SEO information, then you can use the following properties:
@@ -3207,113 +3967,68 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Return only products specified by a comma-separated list of product IDs.
-     * * `limit: &str` -- Return up to this many results per page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Filter results by product title.
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `handle: &str` -- Filter results by product handle.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `status: &str` -- Return products by their status.
-     *                     (default: active)
-     *                       
-     *                           active: Show only active products.
-     *                           archived: Show only archived products.
-     *                           draft: Show only draft products.
-     * * `collection_id: &str` -- Filter results by product collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `presentment_currencies: &str` -- Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
+     * * `params: &ProductsDeprecated202007GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get(
+    pub async fn deprecated_202007_get_with(
         &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        title: &str,
-        vendor: &str,
-        handle: &str,
-        product_type: &str,
-        status: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-        presentment_currencies: &str,
+        params: &ProductsDeprecated202007GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !presentment_currencies.is_empty() {
+        if let Some(presentment_currencies) = &params.presentment_currencies {
             query_args.push((
                 "presentment_currencies".to_string(),
                 presentment_currencies.to_string(),
             ));
         }
-        if !product_type.is_empty() {
+        if let Some(product_type) = &params.product_type {
             query_args.push(("product_type".to_string(), product_type.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
-        if !vendor.is_empty() {
+        if let Some(vendor) = &params.vendor {
             query_args.push(("vendor".to_string(), vendor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3322,6 +4037,55 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_with` and pass the query parameters as a `ProductsDeprecated202007GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_with` instead")]
+    pub async fn deprecated_202007_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            presentment_currencies: Some(presentment_currencies.to_string())
+                .filter(|s| !s.is_empty()),
+            product_type: Some(product_type.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            vendor: Some(vendor.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_with(&params).await
+    }
+
     /**
     * Creates a new product.
               If you want to set the product's SEO information, then you can use the following properties:
@@ -3349,64 +4113,41 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `collection_id: &str` -- Filter results by collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
+     * * `params: &ProductsDeprecated202007GetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_count(
+    pub async fn deprecated_202007_get_count_with(
         &self,
-        vendor: &str,
-        product_type: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecated202007GetCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !product_type.is_empty() {
+        if let Some(product_type) = &params.product_type {
             query_args.push(("product_type".to_string(), product_type.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
-        if !vendor.is_empty() {
+        if let Some(vendor) = &params.vendor {
             query_args.push(("vendor".to_string(), vendor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3415,6 +4156,38 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_count_with` and pass the query parameters as a `ProductsDeprecated202007GetCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_count_with` instead")]
+    pub async fn deprecated_202007_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetCountParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            product_type: Some(product_type.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            vendor: Some(vendor.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_count_with(&params).await
+    }
+
     /**
      * Retrieves a single product.
      *
@@ -3425,11 +4198,15 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecated202007GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202007GetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3443,10 +4220,22 @@ impl Products {
     }
 
     /**
-    * Updates a product and its variants and images.
-              If you want to update the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
+     * Deprecated: use `deprecated_202007_get_param_with` and pass the query parameters as a `ProductsDeprecated202007GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_param_with` instead")]
+    pub async fn deprecated_202007_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+        let params = ProductsDeprecated202007GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_param_with(product_id, &params)
+            .await
+    }
+
+    /**
+    * Updates a product and its variants and images.
+              If you want to update the product's SEO information, then you can use the following properties:
+
+                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
                 metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
     *
     * This function performs a `PUT` to the `/admin/api/2020-07/products/{product_id}.json` endpoint.
@@ -3501,113 +4290,65 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Return only products specified by a comma-separated list of product IDs.
-     * * `limit: &str` -- Return up to this many results per page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Filter results by product title.
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `handle: &str` -- Filter results by product handle.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `status: &str` -- Return products by their status.
-     *                     (default: active)
-     *                       
-     *                           active: Show only active products.
-     *                           archived: Show only archived products.
-     *                           draft: Show only draft products.
-     * * `collection_id: &str` -- Filter results by product collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `presentment_currencies: &str` -- Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
+     * * `params: &ProductsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
-        &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        title: &str,
-        vendor: &str,
-        handle: &str,
-        product_type: &str,
-        status: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-        presentment_currencies: &str,
-    ) -> Result<()> {
+    pub async fn get_with(&self, params: &ProductsGetParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !presentment_currencies.is_empty() {
+        if let Some(presentment_currencies) = &params.presentment_currencies {
             query_args.push((
                 "presentment_currencies".to_string(),
                 presentment_currencies.to_string(),
             ));
         }
-        if !product_type.is_empty() {
+        if let Some(product_type) = &params.product_type {
             query_args.push(("product_type".to_string(), product_type.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
-        if !vendor.is_empty() {
+        if let Some(vendor) = &params.vendor {
             query_args.push(("vendor".to_string(), vendor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3616,6 +4357,55 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `ProductsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()> {
+        let params = ProductsGetParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            presentment_currencies: Some(presentment_currencies.to_string())
+                .filter(|s| !s.is_empty()),
+            product_type: Some(product_type.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            vendor: Some(vendor.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
     * Creates a new product.
               If you want to set the product's SEO information, then you can use the following properties:
@@ -3643,64 +4433,38 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `collection_id: &str` -- Filter results by collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
+     * * `params: &ProductsGetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_count(
-        &self,
-        vendor: &str,
-        product_type: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-    ) -> Result<()> {
+    pub async fn get_count_with(&self, params: &ProductsGetCountParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !product_type.is_empty() {
+        if let Some(product_type) = &params.product_type {
             query_args.push(("product_type".to_string(), product_type.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
-        if !vendor.is_empty() {
+        if let Some(vendor) = &params.vendor {
             query_args.push(("vendor".to_string(), vendor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3709,6 +4473,38 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_count_with` and pass the query parameters as a `ProductsGetCountParams` instead.
+     */
+    #[deprecated(note = "use `get_count_with` instead")]
+    pub async fn get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsGetCountParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            product_type: Some(product_type.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            vendor: Some(vendor.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_count_with(&params).await
+    }
+
     /**
      * Retrieves a single product.
      *
@@ -3719,11 +4515,15 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &ProductsGetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsGetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3736,6 +4536,17 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_param_with` and pass the query parameters as a `ProductsGetParamParams` instead.
+     */
+    #[deprecated(note = "use `get_param_with` instead")]
+    pub async fn get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+        let params = ProductsGetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_param_with(product_id, &params).await
+    }
+
     /**
     * Updates a product and its variants and images.
               If you want to update the product's SEO information, then you can use the following properties:
@@ -3791,113 +4602,68 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Return only products specified by a comma-separated list of product IDs.
-     * * `limit: &str` -- Return up to this many results per page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Filter results by product title.
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `handle: &str` -- Filter results by product handle.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `status: &str` -- Return products by their status.
-     *                     (default: active)
-     *                       
-     *                           active: Show only active products.
-     *                           archived: Show only archived products.
-     *                           draft: Show only draft products.
-     * * `collection_id: &str` -- Filter results by product collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `presentment_currencies: &str` -- Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
+     * * `params: &ProductsDeprecated202101GetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get(
+    pub async fn deprecated_202101_get_with(
         &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        title: &str,
-        vendor: &str,
-        handle: &str,
-        product_type: &str,
-        status: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-        presentment_currencies: &str,
+        params: &ProductsDeprecated202101GetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !presentment_currencies.is_empty() {
+        if let Some(presentment_currencies) = &params.presentment_currencies {
             query_args.push((
                 "presentment_currencies".to_string(),
                 presentment_currencies.to_string(),
             ));
         }
-        if !product_type.is_empty() {
+        if let Some(product_type) = &params.product_type {
             query_args.push(("product_type".to_string(), product_type.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
-        if !vendor.is_empty() {
+        if let Some(vendor) = &params.vendor {
             query_args.push(("vendor".to_string(), vendor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3906,6 +4672,55 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_with` and pass the query parameters as a `ProductsDeprecated202101GetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_with` instead")]
+    pub async fn deprecated_202101_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            presentment_currencies: Some(presentment_currencies.to_string())
+                .filter(|s| !s.is_empty()),
+            product_type: Some(product_type.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            vendor: Some(vendor.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_with(&params).await
+    }
+
     /**
     * Creates a new product.
               If you want to set the product's SEO information, then you can use the following properties:
@@ -3933,64 +4748,41 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `collection_id: &str` -- Filter results by collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
+     * * `params: &ProductsDeprecated202101GetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_count(
+    pub async fn deprecated_202101_get_count_with(
         &self,
-        vendor: &str,
-        product_type: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecated202101GetCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !product_type.is_empty() {
+        if let Some(product_type) = &params.product_type {
             query_args.push(("product_type".to_string(), product_type.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
-        if !vendor.is_empty() {
+        if let Some(vendor) = &params.vendor {
             query_args.push(("vendor".to_string(), vendor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3999,6 +4791,38 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_count_with` and pass the query parameters as a `ProductsDeprecated202101GetCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_count_with` instead")]
+    pub async fn deprecated_202101_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetCountParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            product_type: Some(product_type.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            vendor: Some(vendor.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_count_with(&params).await
+    }
+
     /**
      * Retrieves a single product.
      *
@@ -4009,11 +4833,15 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecated202101GetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202101GetParamParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4026,6 +4854,18 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_param_with` and pass the query parameters as a `ProductsDeprecated202101GetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_param_with` instead")]
+    pub async fn deprecated_202101_get_param(&self, product_id: &str, fields: &str) -> Result<()> {
+        let params = ProductsDeprecated202101GetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_param_with(product_id, &params)
+            .await
+    }
+
     /**
     * Updates a product and its variants and images.
               If you want to update the product's SEO information, then you can use the following properties:
@@ -4085,113 +4925,68 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `ids: &str` -- Return only products specified by a comma-separated list of product IDs.
-     * * `limit: &str` -- Return up to this many results per page.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Filter results by product title.
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `handle: &str` -- Filter results by product handle.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `status: &str` -- Return products by their status.
-     *                     (default: active)
-     *                       
-     *                           active: Show only active products.
-     *                           archived: Show only archived products.
-     *                           draft: Show only draft products.
-     * * `collection_id: &str` -- Filter results by product collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
-     * * `presentment_currencies: &str` -- Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
+     * * `params: &ProductsDeprecatedUnstableGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get(
+    pub async fn deprecated_unstable_get_with(
         &self,
-        ids: &str,
-        limit: &str,
-        since_id: &str,
-        title: &str,
-        vendor: &str,
-        handle: &str,
-        product_type: &str,
-        status: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
-        presentment_currencies: &str,
+        params: &ProductsDeprecatedUnstableGetParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !presentment_currencies.is_empty() {
+        if let Some(presentment_currencies) = &params.presentment_currencies {
             query_args.push((
                 "presentment_currencies".to_string(),
                 presentment_currencies.to_string(),
             ));
         }
-        if !product_type.is_empty() {
+        if let Some(product_type) = &params.product_type {
             query_args.push(("product_type".to_string(), product_type.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
-        if !vendor.is_empty() {
+        if let Some(vendor) = &params.vendor {
             query_args.push(("vendor".to_string(), vendor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4200,6 +4995,55 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_with` instead")]
+    pub async fn deprecated_unstable_get(
+        &self,
+        ids: &str,
+        limit: &str,
+        since_id: &str,
+        title: &str,
+        vendor: &str,
+        handle: &str,
+        product_type: &str,
+        status: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+        presentment_currencies: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            presentment_currencies: Some(presentment_currencies.to_string())
+                .filter(|s| !s.is_empty()),
+            product_type: Some(product_type.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            vendor: Some(vendor.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_with(&params).await
+    }
+
     /**
     * Creates a new product.
               If you want to set the product's SEO information, then you can use the following properties:
@@ -4227,64 +5071,41 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `vendor: &str` -- Filter results by product vendor.
-     * * `product_type: &str` -- Filter results by product type.
-     * * `collection_id: &str` -- Filter results by collection ID.
-     * * `created_at_min: &str` -- Show products created after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Show products created before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show products published after date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show products published before date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Return products by their published status
-     *                     (default: any)
-     *                       
-     *                           published: Show only published products.
-     *                           unpublished: Show only unpublished products.
-     *                           any: Show all products.
+     * * `params: &ProductsDeprecatedUnstableGetCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_count(
+    pub async fn deprecated_unstable_get_count_with(
         &self,
-        vendor: &str,
-        product_type: &str,
-        collection_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecatedUnstableGetCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !product_type.is_empty() {
+        if let Some(product_type) = &params.product_type {
             query_args.push(("product_type".to_string(), product_type.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
-        if !vendor.is_empty() {
+        if let Some(vendor) = &params.vendor {
             query_args.push(("vendor".to_string(), vendor.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4293,6 +5114,38 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_count_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_count_with` instead")]
+    pub async fn deprecated_unstable_get_count(
+        &self,
+        vendor: &str,
+        product_type: &str,
+        collection_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetCountParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            product_type: Some(product_type.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            vendor: Some(vendor.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_count_with(&params).await
+    }
+
     /**
      * Retrieves a single product.
      *
@@ -4303,15 +5156,15 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecatedUnstableGetParamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_param(
+    pub async fn deprecated_unstable_get_param_with(
         &self,
         product_id: &str,
-        fields: &str,
+        params: &ProductsDeprecatedUnstableGetParamParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4325,11 +5178,27 @@ impl Products {
     }
 
     /**
-    * Updates a product and its variants and images.
-              If you want to update the product's SEO information, then you can use the following properties:
-
-                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
-                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
+     * Deprecated: use `deprecated_unstable_get_param_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetParamParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_param_with` instead")]
+    pub async fn deprecated_unstable_get_param(
+        &self,
+        product_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetParamParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_param_with(product_id, &params)
+            .await
+    }
+
+    /**
+    * Updates a product and its variants and images.
+              If you want to update the product's SEO information, then you can use the following properties:
+
+                metafields_global_title_tag: The name of the product used for SEO purposes. Generally added to the <meta name='title'> tag.
+                metafields_global_description_tag: A description of the product used for SEO purposes. Generally added to the <meta name='description'> tag.
     *
     * This function performs a `PUT` to the `/admin/api/unstable/products/{product_id}.json` endpoint.
     *
@@ -4384,20 +5253,18 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecated202001GetParamImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_param_image(
+    pub async fn deprecated_202001_get_param_image_with(
         &self,
         product_id: &str,
-        since_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202001GetParamImageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4410,6 +5277,24 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_param_image_with` and pass the query parameters as a `ProductsDeprecated202001GetParamImageParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_param_image_with` instead")]
+    pub async fn deprecated_202001_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetParamImageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_param_image_with(product_id, &params)
+            .await
+    }
+
     /**
      * Create a new product image.
      *
@@ -4446,15 +5331,15 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `params: &ProductsDeprecated202001GetParamImagesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_param_images_count(
+    pub async fn deprecated_202001_get_param_images_count_with(
         &self,
         product_id: &str,
-        since_id: &str,
+        params: &ProductsDeprecated202001GetParamImagesCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4467,6 +5352,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_param_images_count_with` and pass the query parameters as a `ProductsDeprecated202001GetParamImagesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_param_images_count_with` instead")]
+    pub async fn deprecated_202001_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetParamImagesCountParams {
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_param_images_count_with(product_id, &params)
+            .await
+    }
+
     /**
      * Get a single product image by id.
      *
@@ -4478,16 +5379,16 @@ impl Products {
      *
      * * `product_id: &str` -- storefront_access_token_id.
      * * `image_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecated202001GetParamImagesImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_param_images_image(
+    pub async fn deprecated_202001_get_param_images_image_with(
         &self,
         product_id: &str,
         image_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202001GetParamImagesImageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4501,6 +5402,23 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_param_images_image_with` and pass the query parameters as a `ProductsDeprecated202001GetParamImagesImageParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_param_images_image_with` instead")]
+    pub async fn deprecated_202001_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetParamImagesImageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_param_images_image_with(product_id, image_id, &params)
+            .await
+    }
+
     /**
      * Modify an existing product image.
      *
@@ -4566,20 +5484,18 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecated202004GetParamImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_param_image(
+    pub async fn deprecated_202004_get_param_image_with(
         &self,
         product_id: &str,
-        since_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202004GetParamImageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4592,6 +5508,24 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_param_image_with` and pass the query parameters as a `ProductsDeprecated202004GetParamImageParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_param_image_with` instead")]
+    pub async fn deprecated_202004_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetParamImageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_param_image_with(product_id, &params)
+            .await
+    }
+
     /**
      * Create a new product image.
      *
@@ -4628,15 +5562,15 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `params: &ProductsDeprecated202004GetParamImagesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_param_images_count(
+    pub async fn deprecated_202004_get_param_images_count_with(
         &self,
         product_id: &str,
-        since_id: &str,
+        params: &ProductsDeprecated202004GetParamImagesCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4649,6 +5583,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_param_images_count_with` and pass the query parameters as a `ProductsDeprecated202004GetParamImagesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_param_images_count_with` instead")]
+    pub async fn deprecated_202004_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetParamImagesCountParams {
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_param_images_count_with(product_id, &params)
+            .await
+    }
+
     /**
      * Get a single product image by id.
      *
@@ -4660,16 +5610,16 @@ impl Products {
      *
      * * `product_id: &str` -- storefront_access_token_id.
      * * `image_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecated202004GetParamImagesImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_param_images_image(
+    pub async fn deprecated_202004_get_param_images_image_with(
         &self,
         product_id: &str,
         image_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202004GetParamImagesImageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4683,6 +5633,23 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_param_images_image_with` and pass the query parameters as a `ProductsDeprecated202004GetParamImagesImageParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_param_images_image_with` instead")]
+    pub async fn deprecated_202004_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetParamImagesImageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_param_images_image_with(product_id, image_id, &params)
+            .await
+    }
+
     /**
      * Modify an existing product image.
      *
@@ -4748,20 +5715,18 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecated202007GetParamImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_param_image(
+    pub async fn deprecated_202007_get_param_image_with(
         &self,
         product_id: &str,
-        since_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202007GetParamImageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4774,6 +5739,24 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_param_image_with` and pass the query parameters as a `ProductsDeprecated202007GetParamImageParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_param_image_with` instead")]
+    pub async fn deprecated_202007_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetParamImageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_param_image_with(product_id, &params)
+            .await
+    }
+
     /**
      * Create a new product image.
      *
@@ -4810,15 +5793,15 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `params: &ProductsDeprecated202007GetParamImagesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_param_images_count(
+    pub async fn deprecated_202007_get_param_images_count_with(
         &self,
         product_id: &str,
-        since_id: &str,
+        params: &ProductsDeprecated202007GetParamImagesCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4831,6 +5814,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_param_images_count_with` and pass the query parameters as a `ProductsDeprecated202007GetParamImagesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_param_images_count_with` instead")]
+    pub async fn deprecated_202007_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetParamImagesCountParams {
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_param_images_count_with(product_id, &params)
+            .await
+    }
+
     /**
      * Get a single product image by id.
      *
@@ -4842,16 +5841,16 @@ impl Products {
      *
      * * `product_id: &str` -- storefront_access_token_id.
      * * `image_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecated202007GetParamImagesImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_param_images_image(
+    pub async fn deprecated_202007_get_param_images_image_with(
         &self,
         product_id: &str,
         image_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202007GetParamImagesImageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4865,6 +5864,23 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_param_images_image_with` and pass the query parameters as a `ProductsDeprecated202007GetParamImagesImageParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_param_images_image_with` instead")]
+    pub async fn deprecated_202007_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetParamImagesImageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_param_images_image_with(product_id, image_id, &params)
+            .await
+    }
+
     /**
      * Modify an existing product image.
      *
@@ -4930,20 +5946,18 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &ProductsGetParamImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_param_image(
+    pub async fn get_param_image_with(
         &self,
         product_id: &str,
-        since_id: &str,
-        fields: &str,
+        params: &ProductsGetParamImageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4956,6 +5970,23 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_param_image_with` and pass the query parameters as a `ProductsGetParamImageParams` instead.
+     */
+    #[deprecated(note = "use `get_param_image_with` instead")]
+    pub async fn get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsGetParamImageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_param_image_with(product_id, &params).await
+    }
+
     /**
      * Create a new product image.
      *
@@ -4992,11 +6023,15 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `params: &ProductsGetParamImagesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_param_images_count(&self, product_id: &str, since_id: &str) -> Result<()> {
+    pub async fn get_param_images_count_with(
+        &self,
+        product_id: &str,
+        params: &ProductsGetParamImagesCountParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5009,6 +6044,17 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_param_images_count_with` and pass the query parameters as a `ProductsGetParamImagesCountParams` instead.
+     */
+    #[deprecated(note = "use `get_param_images_count_with` instead")]
+    pub async fn get_param_images_count(&self, product_id: &str, since_id: &str) -> Result<()> {
+        let params = ProductsGetParamImagesCountParams {
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_param_images_count_with(product_id, &params).await
+    }
+
     /**
      * Get a single product image by id.
      *
@@ -5020,16 +6066,16 @@ impl Products {
      *
      * * `product_id: &str` -- storefront_access_token_id.
      * * `image_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &ProductsGetParamImagesImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_param_images_image(
+    pub async fn get_param_images_image_with(
         &self,
         product_id: &str,
         image_id: &str,
-        fields: &str,
+        params: &ProductsGetParamImagesImageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5043,6 +6089,23 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_param_images_image_with` and pass the query parameters as a `ProductsGetParamImagesImageParams` instead.
+     */
+    #[deprecated(note = "use `get_param_images_image_with` instead")]
+    pub async fn get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsGetParamImagesImageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_param_images_image_with(product_id, image_id, &params)
+            .await
+    }
+
     /**
      * Modify an existing product image.
      *
@@ -5104,20 +6167,18 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecated202101GetParamImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_param_image(
+    pub async fn deprecated_202101_get_param_image_with(
         &self,
         product_id: &str,
-        since_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202101GetParamImageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5130,6 +6191,24 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_param_image_with` and pass the query parameters as a `ProductsDeprecated202101GetParamImageParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_param_image_with` instead")]
+    pub async fn deprecated_202101_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetParamImageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_param_image_with(product_id, &params)
+            .await
+    }
+
     /**
      * Create a new product image.
      *
@@ -5166,15 +6245,15 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `params: &ProductsDeprecated202101GetParamImagesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_param_images_count(
+    pub async fn deprecated_202101_get_param_images_count_with(
         &self,
         product_id: &str,
-        since_id: &str,
+        params: &ProductsDeprecated202101GetParamImagesCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5187,6 +6266,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_param_images_count_with` and pass the query parameters as a `ProductsDeprecated202101GetParamImagesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_param_images_count_with` instead")]
+    pub async fn deprecated_202101_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetParamImagesCountParams {
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_param_images_count_with(product_id, &params)
+            .await
+    }
+
     /**
      * Get a single product image by id.
      *
@@ -5198,16 +6293,16 @@ impl Products {
      *
      * * `product_id: &str` -- storefront_access_token_id.
      * * `image_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecated202101GetParamImagesImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_param_images_image(
+    pub async fn deprecated_202101_get_param_images_image_with(
         &self,
         product_id: &str,
         image_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202101GetParamImagesImageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5221,6 +6316,23 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_param_images_image_with` and pass the query parameters as a `ProductsDeprecated202101GetParamImagesImageParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_param_images_image_with` instead")]
+    pub async fn deprecated_202101_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetParamImagesImageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_param_images_image_with(product_id, image_id, &params)
+            .await
+    }
+
     /**
      * Modify an existing product image.
      *
@@ -5286,20 +6398,18 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecatedUnstableGetParamImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_param_image(
+    pub async fn deprecated_unstable_get_param_image_with(
         &self,
         product_id: &str,
-        since_id: &str,
-        fields: &str,
+        params: &ProductsDeprecatedUnstableGetParamImageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5312,6 +6422,24 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_param_image_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetParamImageParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_param_image_with` instead")]
+    pub async fn deprecated_unstable_get_param_image(
+        &self,
+        product_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetParamImageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_param_image_with(product_id, &params)
+            .await
+    }
+
     /**
      * Create a new product image.
      *
@@ -5348,15 +6476,15 @@ impl Products {
      * **Parameters:**
      *
      * * `product_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
+     * * `params: &ProductsDeprecatedUnstableGetParamImagesCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_param_images_count(
+    pub async fn deprecated_unstable_get_param_images_count_with(
         &self,
         product_id: &str,
-        since_id: &str,
+        params: &ProductsDeprecatedUnstableGetParamImagesCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5369,6 +6497,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_param_images_count_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetParamImagesCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_param_images_count_with` instead")]
+    pub async fn deprecated_unstable_get_param_images_count(
+        &self,
+        product_id: &str,
+        since_id: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetParamImagesCountParams {
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_param_images_count_with(product_id, &params)
+            .await
+    }
+
     /**
      * Get a single product image by id.
      *
@@ -5380,16 +6524,16 @@ impl Products {
      *
      * * `product_id: &str` -- storefront_access_token_id.
      * * `image_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &ProductsDeprecatedUnstableGetParamImagesImageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_param_images_image(
+    pub async fn deprecated_unstable_get_param_images_image_with(
         &self,
         product_id: &str,
         image_id: &str,
-        fields: &str,
+        params: &ProductsDeprecatedUnstableGetParamImagesImageParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5403,6 +6547,23 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_param_images_image_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetParamImagesImageParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_param_images_image_with` instead")]
+    pub async fn deprecated_unstable_get_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetParamImagesImageParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_param_images_image_with(product_id, image_id, &params)
+            .await
+    }
+
     /**
      * Modify an existing product image.
      *
@@ -5467,75 +6628,47 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only the smart collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that includes the specified product.
-     * * `handle: &str` -- Filter results by smart collection handle.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202001GetSmartCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_smart_collection(
+    pub async fn deprecated_202001_get_smart_collection_with(
         &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
+        params: &ProductsDeprecated202001GetSmartCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5544,6 +6677,43 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_smart_collection_with` and pass the query parameters as a `ProductsDeprecated202001GetSmartCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_smart_collection_with` instead")]
+    pub async fn deprecated_202001_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetSmartCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_smart_collection_with(&params)
+            .await
+    }
+
     /**
      * Creates a new smart collection using the specified rules.
      *
@@ -5570,49 +6740,32 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that include the specified product.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
+     * * `params: &ProductsDeprecated202001GetSmartCollectionsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_smart_collections_count(
+    pub async fn deprecated_202001_get_smart_collections_count_with(
         &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecated202001GetSmartCollectionsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5621,6 +6774,33 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_smart_collections_count_with` and pass the query parameters as a `ProductsDeprecated202001GetSmartCollectionsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_smart_collections_count_with` instead")]
+    pub async fn deprecated_202001_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetSmartCollectionsCountParams {
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_smart_collections_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single smart collection.
      *
@@ -5631,15 +6811,15 @@ impl Products {
      * **Parameters:**
      *
      * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202001GetSmartCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_smart_collections_param_collection(
+    pub async fn deprecated_202001_get_smart_collections_param_collection_with(
         &self,
         smart_collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202001GetSmartCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5652,6 +6832,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_smart_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecated202001GetSmartCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_smart_collections_param_collection_with` instead"
+    )]
+    pub async fn deprecated_202001_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001GetSmartCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_smart_collections_param_collection_with(
+            smart_collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates an existing smart collection.
      *
@@ -5711,23 +6912,19 @@ impl Products {
      * **Parameters:**
      *
      * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `products: &str` -- An array of product IDs, in the order that you want them to appear at the top of the collection. When products is specified but empty, any previously sorted products are cleared.
-     * * `sort_order: &str` -- The type of sorting to apply. Valid values are listed in the Properties section above.
-     *                     (default: (current value)).
-     * * `products: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_update_smart_collections_param_collection_order(
+    pub async fn deprecated_202001_update_smart_collections_param_collection_order_with(
         &self,
         smart_collection_id: &str,
-        products: &str,
-        sort_order: &str,
+        params: &ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !products.is_empty() {
+        if let Some(products) = &params.products {
             query_args.push(("products".to_string(), products.to_string()));
         }
-        if !sort_order.is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sort_order".to_string(), sort_order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5742,6 +6939,32 @@ impl Products {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_update_smart_collections_param_collection_order_with` and pass the query parameters as a `ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_update_smart_collections_param_collection_order_with` \
+                instead"
+    )]
+    pub async fn deprecated_202001_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderParams {
+            products: Some(products.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_update_smart_collections_param_collection_order_with(
+            smart_collection_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -5751,75 +6974,47 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only the smart collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that includes the specified product.
-     * * `handle: &str` -- Filter results by smart collection handle.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202004GetSmartCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_smart_collection(
+    pub async fn deprecated_202004_get_smart_collection_with(
         &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
+        params: &ProductsDeprecated202004GetSmartCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5828,6 +7023,43 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_smart_collection_with` and pass the query parameters as a `ProductsDeprecated202004GetSmartCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_smart_collection_with` instead")]
+    pub async fn deprecated_202004_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetSmartCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_smart_collection_with(&params)
+            .await
+    }
+
     /**
      * Creates a new smart collection using the specified rules.
      *
@@ -5854,49 +7086,32 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that include the specified product.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
+     * * `params: &ProductsDeprecated202004GetSmartCollectionsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_smart_collections_count(
+    pub async fn deprecated_202004_get_smart_collections_count_with(
         &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecated202004GetSmartCollectionsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5905,6 +7120,33 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_smart_collections_count_with` and pass the query parameters as a `ProductsDeprecated202004GetSmartCollectionsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_smart_collections_count_with` instead")]
+    pub async fn deprecated_202004_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetSmartCollectionsCountParams {
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_smart_collections_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single smart collection.
      *
@@ -5915,15 +7157,15 @@ impl Products {
      * **Parameters:**
      *
      * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202004GetSmartCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_smart_collections_param_collection(
+    pub async fn deprecated_202004_get_smart_collections_param_collection_with(
         &self,
         smart_collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202004GetSmartCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5936,6 +7178,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_smart_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecated202004GetSmartCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_smart_collections_param_collection_with` instead"
+    )]
+    pub async fn deprecated_202004_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004GetSmartCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_smart_collections_param_collection_with(
+            smart_collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates an existing smart collection.
      *
@@ -5995,23 +7258,19 @@ impl Products {
      * **Parameters:**
      *
      * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `products: &str` -- An array of product IDs, in the order that you want them to appear at the top of the collection. When products is specified but empty, any previously sorted products are cleared.
-     * * `sort_order: &str` -- The type of sorting to apply. Valid values are listed in the Properties section above.
-     *                     (default: (current value)).
-     * * `products: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_update_smart_collections_param_collection_order(
+    pub async fn deprecated_202004_update_smart_collections_param_collection_order_with(
         &self,
         smart_collection_id: &str,
-        products: &str,
-        sort_order: &str,
+        params: &ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !products.is_empty() {
+        if let Some(products) = &params.products {
             query_args.push(("products".to_string(), products.to_string()));
         }
-        if !sort_order.is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sort_order".to_string(), sort_order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6026,6 +7285,32 @@ impl Products {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_update_smart_collections_param_collection_order_with` and pass the query parameters as a `ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_update_smart_collections_param_collection_order_with` \
+                instead"
+    )]
+    pub async fn deprecated_202004_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderParams {
+            products: Some(products.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_update_smart_collections_param_collection_order_with(
+            smart_collection_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -6035,75 +7320,47 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only the smart collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that includes the specified product.
-     * * `handle: &str` -- Filter results by smart collection handle.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202007GetSmartCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_smart_collection(
+    pub async fn deprecated_202007_get_smart_collection_with(
         &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
+        params: &ProductsDeprecated202007GetSmartCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6112,6 +7369,43 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_smart_collection_with` and pass the query parameters as a `ProductsDeprecated202007GetSmartCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_smart_collection_with` instead")]
+    pub async fn deprecated_202007_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetSmartCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_smart_collection_with(&params)
+            .await
+    }
+
     /**
      * Creates a new smart collection using the specified rules.
      *
@@ -6138,49 +7432,32 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that include the specified product.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
+     * * `params: &ProductsDeprecated202007GetSmartCollectionsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_smart_collections_count(
+    pub async fn deprecated_202007_get_smart_collections_count_with(
         &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecated202007GetSmartCollectionsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6189,6 +7466,33 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_smart_collections_count_with` and pass the query parameters as a `ProductsDeprecated202007GetSmartCollectionsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_smart_collections_count_with` instead")]
+    pub async fn deprecated_202007_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetSmartCollectionsCountParams {
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_smart_collections_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single smart collection.
      *
@@ -6199,15 +7503,15 @@ impl Products {
      * **Parameters:**
      *
      * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202007GetSmartCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_smart_collections_param_collection(
+    pub async fn deprecated_202007_get_smart_collections_param_collection_with(
         &self,
         smart_collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202007GetSmartCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6220,6 +7524,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_smart_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecated202007GetSmartCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_smart_collections_param_collection_with` instead"
+    )]
+    pub async fn deprecated_202007_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007GetSmartCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_smart_collections_param_collection_with(
+            smart_collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates an existing smart collection.
      *
@@ -6279,23 +7604,19 @@ impl Products {
      * **Parameters:**
      *
      * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `products: &str` -- An array of product IDs, in the order that you want them to appear at the top of the collection. When products is specified but empty, any previously sorted products are cleared.
-     * * `sort_order: &str` -- The type of sorting to apply. Valid values are listed in the Properties section above.
-     *                     (default: (current value)).
-     * * `products: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_update_smart_collections_param_collection_order(
+    pub async fn deprecated_202007_update_smart_collections_param_collection_order_with(
         &self,
         smart_collection_id: &str,
-        products: &str,
-        sort_order: &str,
+        params: &ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !products.is_empty() {
+        if let Some(products) = &params.products {
             query_args.push(("products".to_string(), products.to_string()));
         }
-        if !sort_order.is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sort_order".to_string(), sort_order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6310,6 +7631,32 @@ impl Products {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_update_smart_collections_param_collection_order_with` and pass the query parameters as a `ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_update_smart_collections_param_collection_order_with` \
+                instead"
+    )]
+    pub async fn deprecated_202007_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderParams {
+            products: Some(products.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_update_smart_collections_param_collection_order_with(
+            smart_collection_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -6319,75 +7666,47 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only the smart collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that includes the specified product.
-     * * `handle: &str` -- Filter results by smart collection handle.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsGetSmartCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_smart_collection(
+    pub async fn get_smart_collection_with(
         &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
+        params: &ProductsGetSmartCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6397,18 +7716,54 @@ impl Products {
     }
 
     /**
-     * Creates a new smart collection using the specified rules.
-     *
-     * This function performs a `POST` to the `/admin/api/2020-10/smart_collections.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#create-2020-10
+     * Deprecated: use `get_smart_collection_with` and pass the query parameters as a `ProductsGetSmartCollectionParams` instead.
      */
-    pub async fn create_smart_collections(&self, body: &serde_json::Value) -> Result<()> {
-        let url = "/admin/api/2020-10/smart_collections.json".to_string();
-        self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
-    }
+    #[deprecated(note = "use `get_smart_collection_with` instead")]
+    pub async fn get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsGetSmartCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_smart_collection_with(&params).await
+    }
+
+    /**
+     * Creates a new smart collection using the specified rules.
+     *
+     * This function performs a `POST` to the `/admin/api/2020-10/smart_collections.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/products/smartcollection#create-2020-10
+     */
+    pub async fn create_smart_collections(&self, body: &serde_json::Value) -> Result<()> {
+        let url = "/admin/api/2020-10/smart_collections.json".to_string();
+        self.client
+            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .await
+    }
 
     /**
      * Retrieves a count of smart collections.
@@ -6419,49 +7774,32 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that include the specified product.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
+     * * `params: &ProductsGetSmartCollectionsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_smart_collections_count(
+    pub async fn get_smart_collections_count_with(
         &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsGetSmartCollectionsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6470,6 +7808,32 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_smart_collections_count_with` and pass the query parameters as a `ProductsGetSmartCollectionsCountParams` instead.
+     */
+    #[deprecated(note = "use `get_smart_collections_count_with` instead")]
+    pub async fn get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsGetSmartCollectionsCountParams {
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_smart_collections_count_with(&params).await
+    }
+
     /**
      * Retrieves a single smart collection.
      *
@@ -6480,15 +7844,15 @@ impl Products {
      * **Parameters:**
      *
      * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsGetSmartCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_smart_collections_param_collection(
+    pub async fn get_smart_collections_param_collection_with(
         &self,
         smart_collection_id: &str,
-        fields: &str,
+        params: &ProductsGetSmartCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6501,6 +7865,22 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_smart_collections_param_collection_with` and pass the query parameters as a `ProductsGetSmartCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(note = "use `get_smart_collections_param_collection_with` instead")]
+    pub async fn get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsGetSmartCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_smart_collections_param_collection_with(smart_collection_id, &params)
+            .await
+    }
+
     /**
      * Updates an existing smart collection.
      *
@@ -6560,23 +7940,19 @@ impl Products {
      * **Parameters:**
      *
      * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `products: &str` -- An array of product IDs, in the order that you want them to appear at the top of the collection. When products is specified but empty, any previously sorted products are cleared.
-     * * `sort_order: &str` -- The type of sorting to apply. Valid values are listed in the Properties section above.
-     *                     (default: (current value)).
-     * * `products: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ProductsUpdateSmartCollectionsParamCollectionOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update_smart_collections_param_collection_order(
+    pub async fn update_smart_collections_param_collection_order_with(
         &self,
         smart_collection_id: &str,
-        products: &str,
-        sort_order: &str,
+        params: &ProductsUpdateSmartCollectionsParamCollectionOrderParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !products.is_empty() {
+        if let Some(products) = &params.products {
             query_args.push(("products".to_string(), products.to_string()));
         }
-        if !sort_order.is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sort_order".to_string(), sort_order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6591,6 +7967,29 @@ impl Products {
             .await
     }
 
+    /**
+     * Deprecated: use `update_smart_collections_param_collection_order_with` and pass the query parameters as a `ProductsUpdateSmartCollectionsParamCollectionOrderParams` instead.
+     */
+    #[deprecated(note = "use `update_smart_collections_param_collection_order_with` instead")]
+    pub async fn update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ProductsUpdateSmartCollectionsParamCollectionOrderParams {
+            products: Some(products.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.update_smart_collections_param_collection_order_with(
+            smart_collection_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -6600,75 +7999,47 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only the smart collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that includes the specified product.
-     * * `handle: &str` -- Filter results by smart collection handle.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202101GetSmartCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_smart_collection(
+    pub async fn deprecated_202101_get_smart_collection_with(
         &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
+        params: &ProductsDeprecated202101GetSmartCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6677,6 +8048,43 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_smart_collection_with` and pass the query parameters as a `ProductsDeprecated202101GetSmartCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_smart_collection_with` instead")]
+    pub async fn deprecated_202101_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetSmartCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_smart_collection_with(&params)
+            .await
+    }
+
     /**
      * Creates a new smart collection using the specified rules.
      *
@@ -6703,49 +8111,32 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that include the specified product.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
+     * * `params: &ProductsDeprecated202101GetSmartCollectionsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_smart_collections_count(
+    pub async fn deprecated_202101_get_smart_collections_count_with(
         &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecated202101GetSmartCollectionsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6754,6 +8145,33 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_smart_collections_count_with` and pass the query parameters as a `ProductsDeprecated202101GetSmartCollectionsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_smart_collections_count_with` instead")]
+    pub async fn deprecated_202101_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetSmartCollectionsCountParams {
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_smart_collections_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single smart collection.
      *
@@ -6764,15 +8182,15 @@ impl Products {
      * **Parameters:**
      *
      * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecated202101GetSmartCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_smart_collections_param_collection(
+    pub async fn deprecated_202101_get_smart_collections_param_collection_with(
         &self,
         smart_collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecated202101GetSmartCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6785,6 +8203,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_smart_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecated202101GetSmartCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_smart_collections_param_collection_with` instead"
+    )]
+    pub async fn deprecated_202101_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101GetSmartCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_smart_collections_param_collection_with(
+            smart_collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates an existing smart collection.
      *
@@ -6844,23 +8283,19 @@ impl Products {
      * **Parameters:**
      *
      * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `products: &str` -- An array of product IDs, in the order that you want them to appear at the top of the collection. When products is specified but empty, any previously sorted products are cleared.
-     * * `sort_order: &str` -- The type of sorting to apply. Valid values are listed in the Properties section above.
-     *                     (default: (current value)).
-     * * `products: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_update_smart_collections_param_collection_order(
+    pub async fn deprecated_202101_update_smart_collections_param_collection_order_with(
         &self,
         smart_collection_id: &str,
-        products: &str,
-        sort_order: &str,
+        params: &ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !products.is_empty() {
+        if let Some(products) = &params.products {
             query_args.push(("products".to_string(), products.to_string()));
         }
-        if !sort_order.is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sort_order".to_string(), sort_order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6875,6 +8310,32 @@ impl Products {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_update_smart_collections_param_collection_order_with` and pass the query parameters as a `ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_update_smart_collections_param_collection_order_with` \
+                instead"
+    )]
+    pub async fn deprecated_202101_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderParams {
+            products: Some(products.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_update_smart_collections_param_collection_order_with(
+            smart_collection_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of smart collections. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -6884,75 +8345,47 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The number of results to show.
-     *                     (default: 50, maximum: 250).
-     * * `ids: &str` -- Show only the smart collections specified by a comma-separated list of IDs.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that includes the specified product.
-     * * `handle: &str` -- Filter results by smart collection handle.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecatedUnstableGetSmartCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_smart_collection(
+    pub async fn deprecated_unstable_get_smart_collection_with(
         &self,
-        limit: &str,
-        ids: &str,
-        since_id: &str,
-        title: &str,
-        product_id: &str,
-        handle: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
-        fields: &str,
+        params: &ProductsDeprecatedUnstableGetSmartCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !ids.is_empty() {
+        if let Some(ids) = &params.ids {
             query_args.push(("ids".to_string(), ids.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6961,6 +8394,43 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_smart_collection_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetSmartCollectionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_smart_collection_with` instead")]
+    pub async fn deprecated_unstable_get_smart_collection(
+        &self,
+        limit: &str,
+        ids: &str,
+        since_id: &str,
+        title: &str,
+        product_id: &str,
+        handle: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetSmartCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_smart_collection_with(&params)
+            .await
+    }
+
     /**
      * Creates a new smart collection using the specified rules.
      *
@@ -6987,49 +8457,32 @@ impl Products {
      *
      * **Parameters:**
      *
-     * * `title: &str` -- Show smart collections with the specified title.
-     * * `product_id: &str` -- Show smart collections that include the specified product.
-     * * `updated_at_min: &str` -- Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_min: &str` -- Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_at_max: &str` -- Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
-     * * `published_status: &str` -- Filter results based on the published status of smart collections.
-     *                     (default: any)
-     *                       
-     *                           published: Show only published smart collections.
-     *                           unpublished: Show only unpublished smart collections.
-     *                           any: Show all smart collections.
+     * * `params: &ProductsDeprecatedUnstableGetSmartCollectionsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_smart_collections_count(
+    pub async fn deprecated_unstable_get_smart_collections_count_with(
         &self,
-        title: &str,
-        product_id: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
-        published_at_min: &str,
-        published_at_max: &str,
-        published_status: &str,
+        params: &ProductsDeprecatedUnstableGetSmartCollectionsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !product_id.is_empty() {
+        if let Some(product_id) = &params.product_id {
             query_args.push(("product_id".to_string(), product_id.to_string()));
         }
-        if !published_at_max.is_empty() {
+        if let Some(published_at_max) = &params.published_at_max {
             query_args.push(("published_at_max".to_string(), published_at_max.to_string()));
         }
-        if !published_at_min.is_empty() {
+        if let Some(published_at_min) = &params.published_at_min {
             query_args.push(("published_at_min".to_string(), published_at_min.to_string()));
         }
-        if !published_status.is_empty() {
+        if let Some(published_status) = &params.published_status {
             query_args.push(("published_status".to_string(), published_status.to_string()));
         }
-        if !title.is_empty() {
+        if let Some(title) = &params.title {
             query_args.push(("title".to_string(), title.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -7041,6 +8494,33 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_smart_collections_count_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetSmartCollectionsCountParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_smart_collections_count_with` instead")]
+    pub async fn deprecated_unstable_get_smart_collections_count(
+        &self,
+        title: &str,
+        product_id: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+        published_at_min: &str,
+        published_at_max: &str,
+        published_status: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetSmartCollectionsCountParams {
+            product_id: Some(product_id.to_string()).filter(|s| !s.is_empty()),
+            published_at_max: Some(published_at_max.to_string()).filter(|s| !s.is_empty()),
+            published_at_min: Some(published_at_min.to_string()).filter(|s| !s.is_empty()),
+            published_status: Some(published_status.to_string()).filter(|s| !s.is_empty()),
+            title: Some(title.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_smart_collections_count_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a single smart collection.
      *
@@ -7051,15 +8531,15 @@ impl Products {
      * **Parameters:**
      *
      * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_smart_collections_param_collection(
+    pub async fn deprecated_unstable_get_smart_collections_param_collection_with(
         &self,
         smart_collection_id: &str,
-        fields: &str,
+        params: &ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -7072,6 +8552,27 @@ impl Products {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_smart_collections_param_collection_with` and pass the query parameters as a `ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_smart_collections_param_collection_with` instead"
+    )]
+    pub async fn deprecated_unstable_get_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_smart_collections_param_collection_with(
+            smart_collection_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Updates an existing smart collection.
      *
@@ -7131,23 +8632,19 @@ impl Products {
      * **Parameters:**
      *
      * * `smart_collection_id: &str` -- storefront_access_token_id.
-     * * `products: &str` -- An array of product IDs, in the order that you want them to appear at the top of the collection. When products is specified but empty, any previously sorted products are cleared.
-     * * `sort_order: &str` -- The type of sorting to apply. Valid values are listed in the Properties section above.
-     *                     (default: (current value)).
-     * * `products: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_update_smart_collections_param_collection_order(
+    pub async fn deprecated_unstable_update_smart_collections_param_collection_order_with(
         &self,
         smart_collection_id: &str,
-        products: &str,
-        sort_order: &str,
+        params: &ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !products.is_empty() {
+        if let Some(products) = &params.products {
             query_args.push(("products".to_string(), products.to_string()));
         }
-        if !sort_order.is_empty() {
+        if let Some(sort_order) = &params.sort_order {
             query_args.push(("sort_order".to_string(), sort_order.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -7161,4 +8658,7265 @@ impl Products {
             .put(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `deprecated_unstable_update_smart_collections_param_collection_order_with` and pass the query parameters as a `ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_update_smart_collections_param_collection_order_with` \
+                instead"
+    )]
+    pub async fn deprecated_unstable_update_smart_collections_param_collection_order(
+        &self,
+        smart_collection_id: &str,
+        products: &str,
+        sort_order: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderParams {
+            products: Some(products.to_string()).filter(|s| !s.is_empty()),
+            sort_order: Some(sort_order.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_update_smart_collections_param_collection_order_with(
+            smart_collection_id,
+            &params,
+            body,
+        )
+        .await
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_collect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetCollectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecated202001GetCollectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_collects_param_collect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetCollectsParamCollectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202001GetCollectsParamCollectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_collects_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetCollectsCountParams {
+    /// recurring_application_charge[capped_amount].
+    pub collection_id: Option<i64>,
+}
+
+impl ProductsDeprecated202001GetCollectsCountParams {
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_collect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetCollectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecated202004GetCollectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_collects_param_collect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetCollectsParamCollectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202004GetCollectsParamCollectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_collects_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetCollectsCountParams {
+    /// recurring_application_charge[capped_amount].
+    pub collection_id: Option<i64>,
+}
+
+impl ProductsDeprecated202004GetCollectsCountParams {
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_collect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetCollectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecated202007GetCollectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_collects_param_collect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetCollectsParamCollectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202007GetCollectsParamCollectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_collects_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetCollectsCountParams {
+    /// recurring_application_charge[capped_amount].
+    pub collection_id: Option<i64>,
+}
+
+impl ProductsDeprecated202007GetCollectsCountParams {
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+}
+
+/// The query parameters of `Products::get_collect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetCollectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsGetCollectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_collects_param_collect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetCollectsParamCollectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsGetCollectsParamCollectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_collects_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetCollectsCountParams {
+    /// recurring_application_charge[capped_amount].
+    pub collection_id: Option<i64>,
+}
+
+impl ProductsGetCollectsCountParams {
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_collect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetCollectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecated202101GetCollectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_collects_param_collect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetCollectsParamCollectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202101GetCollectsParamCollectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_collects_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetCollectsCountParams {
+    /// recurring_application_charge[capped_amount].
+    pub collection_id: Option<i64>,
+}
+
+impl ProductsDeprecated202101GetCollectsCountParams {
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_collect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetCollectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// The maximum number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetCollectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_collects_param_collect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetCollectsParamCollectParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetCollectsParamCollectParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_collects_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetCollectsCountParams {
+    /// recurring_application_charge[capped_amount].
+    pub collection_id: Option<i64>,
+}
+
+impl ProductsDeprecatedUnstableGetCollectsCountParams {
+    pub fn collection_id(mut self, collection_id: i64) -> Self {
+        self.collection_id = Some(collection_id);
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202001GetCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_collections_param_collection_products_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetCollectionsParamCollectionProductsParams {
+    /// The number of products to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl ProductsDeprecated202001GetCollectionsParamCollectionProductsParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202004GetCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_collections_param_collection_products_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetCollectionsParamCollectionProductsParams {
+    /// The number of products to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl ProductsDeprecated202004GetCollectionsParamCollectionProductsParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202007GetCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_collections_param_collection_products_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetCollectionsParamCollectionProductsParams {
+    /// The number of products to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl ProductsDeprecated202007GetCollectionsParamCollectionProductsParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsGetCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_collections_param_collection_products_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetCollectionsParamCollectionProductsParams {
+    /// The number of products to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl ProductsGetCollectionsParamCollectionProductsParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202101GetCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_collections_param_collection_products_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetCollectionsParamCollectionProductsParams {
+    /// The number of products to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl ProductsDeprecated202101GetCollectionsParamCollectionProductsParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_collections_param_collection_products_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsParams {
+    /// The number of products to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_custom_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetCustomCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter by custom collection handle.
+    pub handle: Option<String>,
+    /// Show only collections specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show custom collections that include a given product.
+    pub product_id: Option<String>,
+    /// Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Show custom collectsion with a given published status.
+    /// (default: any)
+    ///
+    /// published: Show only published custom collections.
+    /// unpublished: Show only unpublished custom collections.
+    /// any: Show custom collections of any published status.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show custom collections with a given title.
+    pub title: Option<String>,
+    /// Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202001GetCustomCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_custom_collections_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetCustomCollectionsCountParams {
+    /// Count custom collections that include a given product.
+    pub product_id: Option<String>,
+    /// Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Count custom collections with a given published status.
+    /// (default: any)
+    ///
+    /// published: Count only published custom collections.
+    /// unpublished: Count only unpublished custom collections.
+    /// any: Count custom collections of any published status.
+    pub published_status: Option<String>,
+    /// Count custom collections with given title.
+    pub title: Option<String>,
+    /// Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202001GetCustomCollectionsCountParams {
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_custom_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetCustomCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202001GetCustomCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_custom_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetCustomCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter by custom collection handle.
+    pub handle: Option<String>,
+    /// Show only collections specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show custom collections that include a given product.
+    pub product_id: Option<String>,
+    /// Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Show custom collectsion with a given published status.
+    /// (default: any)
+    ///
+    /// published: Show only published custom collections.
+    /// unpublished: Show only unpublished custom collections.
+    /// any: Show custom collections of any published status.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show custom collections with a given title.
+    pub title: Option<String>,
+    /// Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202004GetCustomCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_custom_collections_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetCustomCollectionsCountParams {
+    /// Count custom collections that include a given product.
+    pub product_id: Option<String>,
+    /// Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Count custom collections with a given published status.
+    /// (default: any)
+    ///
+    /// published: Count only published custom collections.
+    /// unpublished: Count only unpublished custom collections.
+    /// any: Count custom collections of any published status.
+    pub published_status: Option<String>,
+    /// Count custom collections with given title.
+    pub title: Option<String>,
+    /// Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202004GetCustomCollectionsCountParams {
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_custom_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetCustomCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202004GetCustomCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_custom_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetCustomCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter by custom collection handle.
+    pub handle: Option<String>,
+    /// Show only collections specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show custom collections that include a given product.
+    pub product_id: Option<String>,
+    /// Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Show custom collectsion with a given published status.
+    /// (default: any)
+    ///
+    /// published: Show only published custom collections.
+    /// unpublished: Show only unpublished custom collections.
+    /// any: Show custom collections of any published status.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show custom collections with a given title.
+    pub title: Option<String>,
+    /// Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202007GetCustomCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_custom_collections_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetCustomCollectionsCountParams {
+    /// Count custom collections that include a given product.
+    pub product_id: Option<String>,
+    /// Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Count custom collections with a given published status.
+    /// (default: any)
+    ///
+    /// published: Count only published custom collections.
+    /// unpublished: Count only unpublished custom collections.
+    /// any: Count custom collections of any published status.
+    pub published_status: Option<String>,
+    /// Count custom collections with given title.
+    pub title: Option<String>,
+    /// Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202007GetCustomCollectionsCountParams {
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_custom_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetCustomCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202007GetCustomCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_custom_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetCustomCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter by custom collection handle.
+    pub handle: Option<String>,
+    /// Show only collections specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show custom collections that include a given product.
+    pub product_id: Option<String>,
+    /// Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Show custom collectsion with a given published status.
+    /// (default: any)
+    ///
+    /// published: Show only published custom collections.
+    /// unpublished: Show only unpublished custom collections.
+    /// any: Show custom collections of any published status.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show custom collections with a given title.
+    pub title: Option<String>,
+    /// Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsGetCustomCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_custom_collections_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetCustomCollectionsCountParams {
+    /// Count custom collections that include a given product.
+    pub product_id: Option<String>,
+    /// Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Count custom collections with a given published status.
+    /// (default: any)
+    ///
+    /// published: Count only published custom collections.
+    /// unpublished: Count only unpublished custom collections.
+    /// any: Count custom collections of any published status.
+    pub published_status: Option<String>,
+    /// Count custom collections with given title.
+    pub title: Option<String>,
+    /// Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsGetCustomCollectionsCountParams {
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_custom_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetCustomCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsGetCustomCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_custom_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetCustomCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter by custom collection handle.
+    pub handle: Option<String>,
+    /// Show only collections specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show custom collections that include a given product.
+    pub product_id: Option<String>,
+    /// Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Show custom collectsion with a given published status.
+    /// (default: any)
+    ///
+    /// published: Show only published custom collections.
+    /// unpublished: Show only unpublished custom collections.
+    /// any: Show custom collections of any published status.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show custom collections with a given title.
+    pub title: Option<String>,
+    /// Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202101GetCustomCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_custom_collections_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetCustomCollectionsCountParams {
+    /// Count custom collections that include a given product.
+    pub product_id: Option<String>,
+    /// Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Count custom collections with a given published status.
+    /// (default: any)
+    ///
+    /// published: Count only published custom collections.
+    /// unpublished: Count only unpublished custom collections.
+    /// any: Count custom collections of any published status.
+    pub published_status: Option<String>,
+    /// Count custom collections with given title.
+    pub title: Option<String>,
+    /// Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202101GetCustomCollectionsCountParams {
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_custom_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetCustomCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202101GetCustomCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_custom_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetCustomCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter by custom collection handle.
+    pub handle: Option<String>,
+    /// Show only collections specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show custom collections that include a given product.
+    pub product_id: Option<String>,
+    /// Show custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Show custom collectsion with a given published status.
+    /// (default: any)
+    ///
+    /// published: Show only published custom collections.
+    /// unpublished: Show only unpublished custom collections.
+    /// any: Show custom collections of any published status.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show custom collections with a given title.
+    pub title: Option<String>,
+    /// Show custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetCustomCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_custom_collections_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetCustomCollectionsCountParams {
+    /// Count custom collections that include a given product.
+    pub product_id: Option<String>,
+    /// Count custom collections published before date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Count custom collections published after date (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Count custom collections with a given published status.
+    /// (default: any)
+    ///
+    /// published: Count only published custom collections.
+    /// unpublished: Count only unpublished custom collections.
+    /// any: Count custom collections of any published status.
+    pub published_status: Option<String>,
+    /// Count custom collections with given title.
+    pub title: Option<String>,
+    /// Count custom collections last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count custom collections last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetCustomCollectionsCountParams {
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_custom_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetParams {
+    /// Filter results by product collection ID.
+    pub collection_id: Option<String>,
+    /// Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter results by product handle.
+    pub handle: Option<String>,
+    /// Return only products specified by a comma-separated list of product IDs.
+    pub ids: Option<String>,
+    /// Return up to this many results per page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
+    pub presentment_currencies: Option<String>,
+    /// Filter results by product type.
+    pub product_type: Option<String>,
+    /// Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Return products by their published status
+    /// (default: any)
+    ///
+    /// published: Show only published products.
+    /// unpublished: Show only unpublished products.
+    /// any: Show all products.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Return products by their status.
+    /// (default: active)
+    ///
+    /// active: Show only active products.
+    /// archived: Show only archived products.
+    /// draft: Show only draft products.
+    pub status: Option<String>,
+    /// Filter results by product title.
+    pub title: Option<String>,
+    /// Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+    /// Filter results by product vendor.
+    pub vendor: Option<String>,
+}
+
+impl ProductsDeprecated202001GetParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn presentment_currencies<T: ToString>(mut self, presentment_currencies: T) -> Self {
+        self.presentment_currencies = Some(presentment_currencies.to_string());
+        self
+    }
+
+    pub fn product_type<T: ToString>(mut self, product_type: T) -> Self {
+        self.product_type = Some(product_type.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+
+    pub fn vendor<T: ToString>(mut self, vendor: T) -> Self {
+        self.vendor = Some(vendor.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetCountParams {
+    /// Filter results by collection ID.
+    pub collection_id: Option<String>,
+    /// Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Filter results by product type.
+    pub product_type: Option<String>,
+    /// Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Return products by their published status
+    /// (default: any)
+    ///
+    /// published: Show only published products.
+    /// unpublished: Show only unpublished products.
+    /// any: Show all products.
+    pub published_status: Option<String>,
+    /// Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+    /// Filter results by product vendor.
+    pub vendor: Option<String>,
+}
+
+impl ProductsDeprecated202001GetCountParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn product_type<T: ToString>(mut self, product_type: T) -> Self {
+        self.product_type = Some(product_type.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+
+    pub fn vendor<T: ToString>(mut self, vendor: T) -> Self {
+        self.vendor = Some(vendor.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetParamParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202001GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetParams {
+    /// Filter results by product collection ID.
+    pub collection_id: Option<String>,
+    /// Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter results by product handle.
+    pub handle: Option<String>,
+    /// Return only products specified by a comma-separated list of product IDs.
+    pub ids: Option<String>,
+    /// Return up to this many results per page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
+    pub presentment_currencies: Option<String>,
+    /// Filter results by product type.
+    pub product_type: Option<String>,
+    /// Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Return products by their published status
+    /// (default: any)
+    ///
+    /// published: Show only published products.
+    /// unpublished: Show only unpublished products.
+    /// any: Show all products.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Return products by their status.
+    /// (default: active)
+    ///
+    /// active: Show only active products.
+    /// archived: Show only archived products.
+    /// draft: Show only draft products.
+    pub status: Option<String>,
+    /// Filter results by product title.
+    pub title: Option<String>,
+    /// Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+    /// Filter results by product vendor.
+    pub vendor: Option<String>,
+}
+
+impl ProductsDeprecated202004GetParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn presentment_currencies<T: ToString>(mut self, presentment_currencies: T) -> Self {
+        self.presentment_currencies = Some(presentment_currencies.to_string());
+        self
+    }
+
+    pub fn product_type<T: ToString>(mut self, product_type: T) -> Self {
+        self.product_type = Some(product_type.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+
+    pub fn vendor<T: ToString>(mut self, vendor: T) -> Self {
+        self.vendor = Some(vendor.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetCountParams {
+    /// Filter results by collection ID.
+    pub collection_id: Option<String>,
+    /// Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Filter results by product type.
+    pub product_type: Option<String>,
+    /// Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Return products by their published status
+    /// (default: any)
+    ///
+    /// published: Show only published products.
+    /// unpublished: Show only unpublished products.
+    /// any: Show all products.
+    pub published_status: Option<String>,
+    /// Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+    /// Filter results by product vendor.
+    pub vendor: Option<String>,
+}
+
+impl ProductsDeprecated202004GetCountParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn product_type<T: ToString>(mut self, product_type: T) -> Self {
+        self.product_type = Some(product_type.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+
+    pub fn vendor<T: ToString>(mut self, vendor: T) -> Self {
+        self.vendor = Some(vendor.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetParamParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202004GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetParams {
+    /// Filter results by product collection ID.
+    pub collection_id: Option<String>,
+    /// Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter results by product handle.
+    pub handle: Option<String>,
+    /// Return only products specified by a comma-separated list of product IDs.
+    pub ids: Option<String>,
+    /// Return up to this many results per page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
+    pub presentment_currencies: Option<String>,
+    /// Filter results by product type.
+    pub product_type: Option<String>,
+    /// Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Return products by their published status
+    /// (default: any)
+    ///
+    /// published: Show only published products.
+    /// unpublished: Show only unpublished products.
+    /// any: Show all products.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Return products by their status.
+    /// (default: active)
+    ///
+    /// active: Show only active products.
+    /// archived: Show only archived products.
+    /// draft: Show only draft products.
+    pub status: Option<String>,
+    /// Filter results by product title.
+    pub title: Option<String>,
+    /// Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+    /// Filter results by product vendor.
+    pub vendor: Option<String>,
+}
+
+impl ProductsDeprecated202007GetParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn presentment_currencies<T: ToString>(mut self, presentment_currencies: T) -> Self {
+        self.presentment_currencies = Some(presentment_currencies.to_string());
+        self
+    }
+
+    pub fn product_type<T: ToString>(mut self, product_type: T) -> Self {
+        self.product_type = Some(product_type.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+
+    pub fn vendor<T: ToString>(mut self, vendor: T) -> Self {
+        self.vendor = Some(vendor.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetCountParams {
+    /// Filter results by collection ID.
+    pub collection_id: Option<String>,
+    /// Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Filter results by product type.
+    pub product_type: Option<String>,
+    /// Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Return products by their published status
+    /// (default: any)
+    ///
+    /// published: Show only published products.
+    /// unpublished: Show only unpublished products.
+    /// any: Show all products.
+    pub published_status: Option<String>,
+    /// Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+    /// Filter results by product vendor.
+    pub vendor: Option<String>,
+}
+
+impl ProductsDeprecated202007GetCountParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn product_type<T: ToString>(mut self, product_type: T) -> Self {
+        self.product_type = Some(product_type.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+
+    pub fn vendor<T: ToString>(mut self, vendor: T) -> Self {
+        self.vendor = Some(vendor.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetParamParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202007GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetParams {
+    /// Filter results by product collection ID.
+    pub collection_id: Option<String>,
+    /// Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter results by product handle.
+    pub handle: Option<String>,
+    /// Return only products specified by a comma-separated list of product IDs.
+    pub ids: Option<String>,
+    /// Return up to this many results per page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
+    pub presentment_currencies: Option<String>,
+    /// Filter results by product type.
+    pub product_type: Option<String>,
+    /// Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Return products by their published status
+    /// (default: any)
+    ///
+    /// published: Show only published products.
+    /// unpublished: Show only unpublished products.
+    /// any: Show all products.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Return products by their status.
+    /// (default: active)
+    ///
+    /// active: Show only active products.
+    /// archived: Show only archived products.
+    /// draft: Show only draft products.
+    pub status: Option<String>,
+    /// Filter results by product title.
+    pub title: Option<String>,
+    /// Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+    /// Filter results by product vendor.
+    pub vendor: Option<String>,
+}
+
+impl ProductsGetParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn presentment_currencies<T: ToString>(mut self, presentment_currencies: T) -> Self {
+        self.presentment_currencies = Some(presentment_currencies.to_string());
+        self
+    }
+
+    pub fn product_type<T: ToString>(mut self, product_type: T) -> Self {
+        self.product_type = Some(product_type.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+
+    pub fn vendor<T: ToString>(mut self, vendor: T) -> Self {
+        self.vendor = Some(vendor.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetCountParams {
+    /// Filter results by collection ID.
+    pub collection_id: Option<String>,
+    /// Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Filter results by product type.
+    pub product_type: Option<String>,
+    /// Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Return products by their published status
+    /// (default: any)
+    ///
+    /// published: Show only published products.
+    /// unpublished: Show only unpublished products.
+    /// any: Show all products.
+    pub published_status: Option<String>,
+    /// Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+    /// Filter results by product vendor.
+    pub vendor: Option<String>,
+}
+
+impl ProductsGetCountParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn product_type<T: ToString>(mut self, product_type: T) -> Self {
+        self.product_type = Some(product_type.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+
+    pub fn vendor<T: ToString>(mut self, vendor: T) -> Self {
+        self.vendor = Some(vendor.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetParamParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ProductsGetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetParams {
+    /// Filter results by product collection ID.
+    pub collection_id: Option<String>,
+    /// Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter results by product handle.
+    pub handle: Option<String>,
+    /// Return only products specified by a comma-separated list of product IDs.
+    pub ids: Option<String>,
+    /// Return up to this many results per page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
+    pub presentment_currencies: Option<String>,
+    /// Filter results by product type.
+    pub product_type: Option<String>,
+    /// Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Return products by their published status
+    /// (default: any)
+    ///
+    /// published: Show only published products.
+    /// unpublished: Show only unpublished products.
+    /// any: Show all products.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Return products by their status.
+    /// (default: active)
+    ///
+    /// active: Show only active products.
+    /// archived: Show only archived products.
+    /// draft: Show only draft products.
+    pub status: Option<String>,
+    /// Filter results by product title.
+    pub title: Option<String>,
+    /// Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+    /// Filter results by product vendor.
+    pub vendor: Option<String>,
+}
+
+impl ProductsDeprecated202101GetParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn presentment_currencies<T: ToString>(mut self, presentment_currencies: T) -> Self {
+        self.presentment_currencies = Some(presentment_currencies.to_string());
+        self
+    }
+
+    pub fn product_type<T: ToString>(mut self, product_type: T) -> Self {
+        self.product_type = Some(product_type.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+
+    pub fn vendor<T: ToString>(mut self, vendor: T) -> Self {
+        self.vendor = Some(vendor.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetCountParams {
+    /// Filter results by collection ID.
+    pub collection_id: Option<String>,
+    /// Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Filter results by product type.
+    pub product_type: Option<String>,
+    /// Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Return products by their published status
+    /// (default: any)
+    ///
+    /// published: Show only published products.
+    /// unpublished: Show only unpublished products.
+    /// any: Show all products.
+    pub published_status: Option<String>,
+    /// Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+    /// Filter results by product vendor.
+    pub vendor: Option<String>,
+}
+
+impl ProductsDeprecated202101GetCountParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn product_type<T: ToString>(mut self, product_type: T) -> Self {
+        self.product_type = Some(product_type.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+
+    pub fn vendor<T: ToString>(mut self, vendor: T) -> Self {
+        self.vendor = Some(vendor.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetParamParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202101GetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetParams {
+    /// Filter results by product collection ID.
+    pub collection_id: Option<String>,
+    /// Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter results by product handle.
+    pub handle: Option<String>,
+    /// Return only products specified by a comma-separated list of product IDs.
+    pub ids: Option<String>,
+    /// Return up to this many results per page.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Return presentment prices in only certain currencies, specified by a comma-separated list of ISO 4217 currency codes.
+    pub presentment_currencies: Option<String>,
+    /// Filter results by product type.
+    pub product_type: Option<String>,
+    /// Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Return products by their published status
+    /// (default: any)
+    ///
+    /// published: Show only published products.
+    /// unpublished: Show only unpublished products.
+    /// any: Show all products.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Return products by their status.
+    /// (default: active)
+    ///
+    /// active: Show only active products.
+    /// archived: Show only archived products.
+    /// draft: Show only draft products.
+    pub status: Option<String>,
+    /// Filter results by product title.
+    pub title: Option<String>,
+    /// Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+    /// Filter results by product vendor.
+    pub vendor: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn presentment_currencies<T: ToString>(mut self, presentment_currencies: T) -> Self {
+        self.presentment_currencies = Some(presentment_currencies.to_string());
+        self
+    }
+
+    pub fn product_type<T: ToString>(mut self, product_type: T) -> Self {
+        self.product_type = Some(product_type.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+
+    pub fn vendor<T: ToString>(mut self, vendor: T) -> Self {
+        self.vendor = Some(vendor.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetCountParams {
+    /// Filter results by collection ID.
+    pub collection_id: Option<String>,
+    /// Show products created before date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show products created after date. (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Filter results by product type.
+    pub product_type: Option<String>,
+    /// Show products published before date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show products published after date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Return products by their published status
+    /// (default: any)
+    ///
+    /// published: Show only published products.
+    /// unpublished: Show only unpublished products.
+    /// any: Show all products.
+    pub published_status: Option<String>,
+    /// Show products last updated before date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show products last updated after date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+    /// Filter results by product vendor.
+    pub vendor: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetCountParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn product_type<T: ToString>(mut self, product_type: T) -> Self {
+        self.product_type = Some(product_type.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+
+    pub fn vendor<T: ToString>(mut self, vendor: T) -> Self {
+        self.vendor = Some(vendor.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_param_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetParamParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetParamParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_param_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetParamImageParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecated202001GetParamImageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_param_images_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetParamImagesCountParams {
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecated202001GetParamImagesCountParams {
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_param_images_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetParamImagesImageParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202001GetParamImagesImageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_param_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetParamImageParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecated202004GetParamImageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_param_images_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetParamImagesCountParams {
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecated202004GetParamImagesCountParams {
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_param_images_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetParamImagesImageParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202004GetParamImagesImageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_param_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetParamImageParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecated202007GetParamImageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_param_images_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetParamImagesCountParams {
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecated202007GetParamImagesCountParams {
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_param_images_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetParamImagesImageParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202007GetParamImagesImageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_param_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetParamImageParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsGetParamImageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_param_images_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetParamImagesCountParams {
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsGetParamImagesCountParams {
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_param_images_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetParamImagesImageParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ProductsGetParamImagesImageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_param_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetParamImageParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecated202101GetParamImageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_param_images_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetParamImagesCountParams {
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecated202101GetParamImagesCountParams {
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_param_images_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetParamImagesImageParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202101GetParamImagesImageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_param_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetParamImageParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetParamImageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_param_images_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetParamImagesCountParams {
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetParamImagesCountParams {
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_param_images_image_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetParamImagesImageParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetParamImagesImageParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_smart_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetSmartCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter results by smart collection handle.
+    pub handle: Option<String>,
+    /// Show only the smart collections specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show smart collections that includes the specified product.
+    pub product_id: Option<String>,
+    /// Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Filter results based on the published status of smart collections.
+    /// (default: any)
+    ///
+    /// published: Show only published smart collections.
+    /// unpublished: Show only unpublished smart collections.
+    /// any: Show all smart collections.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show smart collections with the specified title.
+    pub title: Option<String>,
+    /// Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202001GetSmartCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_smart_collections_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetSmartCollectionsCountParams {
+    /// Show smart collections that include the specified product.
+    pub product_id: Option<String>,
+    /// Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Filter results based on the published status of smart collections.
+    /// (default: any)
+    ///
+    /// published: Show only published smart collections.
+    /// unpublished: Show only unpublished smart collections.
+    /// any: Show all smart collections.
+    pub published_status: Option<String>,
+    /// Show smart collections with the specified title.
+    pub title: Option<String>,
+    /// Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202001GetSmartCollectionsCountParams {
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_get_smart_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001GetSmartCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202001GetSmartCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202001_update_smart_collections_param_collection_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderParams {
+    /// recurring_application_charge[capped_amount].
+    pub products: Option<String>,
+    /// The type of sorting to apply. Valid values are listed in the Properties section above.
+    /// (default: (current value)).
+    pub sort_order: Option<String>,
+}
+
+impl ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderParams {
+    pub fn products<T: ToString>(mut self, products: T) -> Self {
+        self.products = Some(products.to_string());
+        self
+    }
+
+    pub fn sort_order<T: ToString>(mut self, sort_order: T) -> Self {
+        self.sort_order = Some(sort_order.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_smart_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetSmartCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter results by smart collection handle.
+    pub handle: Option<String>,
+    /// Show only the smart collections specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show smart collections that includes the specified product.
+    pub product_id: Option<String>,
+    /// Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Filter results based on the published status of smart collections.
+    /// (default: any)
+    ///
+    /// published: Show only published smart collections.
+    /// unpublished: Show only unpublished smart collections.
+    /// any: Show all smart collections.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show smart collections with the specified title.
+    pub title: Option<String>,
+    /// Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202004GetSmartCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_smart_collections_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetSmartCollectionsCountParams {
+    /// Show smart collections that include the specified product.
+    pub product_id: Option<String>,
+    /// Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Filter results based on the published status of smart collections.
+    /// (default: any)
+    ///
+    /// published: Show only published smart collections.
+    /// unpublished: Show only unpublished smart collections.
+    /// any: Show all smart collections.
+    pub published_status: Option<String>,
+    /// Show smart collections with the specified title.
+    pub title: Option<String>,
+    /// Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202004GetSmartCollectionsCountParams {
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_get_smart_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004GetSmartCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202004GetSmartCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202004_update_smart_collections_param_collection_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderParams {
+    /// recurring_application_charge[capped_amount].
+    pub products: Option<String>,
+    /// The type of sorting to apply. Valid values are listed in the Properties section above.
+    /// (default: (current value)).
+    pub sort_order: Option<String>,
+}
+
+impl ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderParams {
+    pub fn products<T: ToString>(mut self, products: T) -> Self {
+        self.products = Some(products.to_string());
+        self
+    }
+
+    pub fn sort_order<T: ToString>(mut self, sort_order: T) -> Self {
+        self.sort_order = Some(sort_order.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_smart_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetSmartCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter results by smart collection handle.
+    pub handle: Option<String>,
+    /// Show only the smart collections specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show smart collections that includes the specified product.
+    pub product_id: Option<String>,
+    /// Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Filter results based on the published status of smart collections.
+    /// (default: any)
+    ///
+    /// published: Show only published smart collections.
+    /// unpublished: Show only unpublished smart collections.
+    /// any: Show all smart collections.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show smart collections with the specified title.
+    pub title: Option<String>,
+    /// Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202007GetSmartCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_smart_collections_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetSmartCollectionsCountParams {
+    /// Show smart collections that include the specified product.
+    pub product_id: Option<String>,
+    /// Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Filter results based on the published status of smart collections.
+    /// (default: any)
+    ///
+    /// published: Show only published smart collections.
+    /// unpublished: Show only unpublished smart collections.
+    /// any: Show all smart collections.
+    pub published_status: Option<String>,
+    /// Show smart collections with the specified title.
+    pub title: Option<String>,
+    /// Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202007GetSmartCollectionsCountParams {
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_get_smart_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007GetSmartCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202007GetSmartCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202007_update_smart_collections_param_collection_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderParams {
+    /// recurring_application_charge[capped_amount].
+    pub products: Option<String>,
+    /// The type of sorting to apply. Valid values are listed in the Properties section above.
+    /// (default: (current value)).
+    pub sort_order: Option<String>,
+}
+
+impl ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderParams {
+    pub fn products<T: ToString>(mut self, products: T) -> Self {
+        self.products = Some(products.to_string());
+        self
+    }
+
+    pub fn sort_order<T: ToString>(mut self, sort_order: T) -> Self {
+        self.sort_order = Some(sort_order.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_smart_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetSmartCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter results by smart collection handle.
+    pub handle: Option<String>,
+    /// Show only the smart collections specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show smart collections that includes the specified product.
+    pub product_id: Option<String>,
+    /// Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Filter results based on the published status of smart collections.
+    /// (default: any)
+    ///
+    /// published: Show only published smart collections.
+    /// unpublished: Show only unpublished smart collections.
+    /// any: Show all smart collections.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show smart collections with the specified title.
+    pub title: Option<String>,
+    /// Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsGetSmartCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_smart_collections_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetSmartCollectionsCountParams {
+    /// Show smart collections that include the specified product.
+    pub product_id: Option<String>,
+    /// Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Filter results based on the published status of smart collections.
+    /// (default: any)
+    ///
+    /// published: Show only published smart collections.
+    /// unpublished: Show only unpublished smart collections.
+    /// any: Show all smart collections.
+    pub published_status: Option<String>,
+    /// Show smart collections with the specified title.
+    pub title: Option<String>,
+    /// Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsGetSmartCollectionsCountParams {
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::get_smart_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsGetSmartCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsGetSmartCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::update_smart_collections_param_collection_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsUpdateSmartCollectionsParamCollectionOrderParams {
+    /// recurring_application_charge[capped_amount].
+    pub products: Option<String>,
+    /// The type of sorting to apply. Valid values are listed in the Properties section above.
+    /// (default: (current value)).
+    pub sort_order: Option<String>,
+}
+
+impl ProductsUpdateSmartCollectionsParamCollectionOrderParams {
+    pub fn products<T: ToString>(mut self, products: T) -> Self {
+        self.products = Some(products.to_string());
+        self
+    }
+
+    pub fn sort_order<T: ToString>(mut self, sort_order: T) -> Self {
+        self.sort_order = Some(sort_order.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_smart_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetSmartCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter results by smart collection handle.
+    pub handle: Option<String>,
+    /// Show only the smart collections specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show smart collections that includes the specified product.
+    pub product_id: Option<String>,
+    /// Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Filter results based on the published status of smart collections.
+    /// (default: any)
+    ///
+    /// published: Show only published smart collections.
+    /// unpublished: Show only unpublished smart collections.
+    /// any: Show all smart collections.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show smart collections with the specified title.
+    pub title: Option<String>,
+    /// Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202101GetSmartCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_smart_collections_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetSmartCollectionsCountParams {
+    /// Show smart collections that include the specified product.
+    pub product_id: Option<String>,
+    /// Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Filter results based on the published status of smart collections.
+    /// (default: any)
+    ///
+    /// published: Show only published smart collections.
+    /// unpublished: Show only unpublished smart collections.
+    /// any: Show all smart collections.
+    pub published_status: Option<String>,
+    /// Show smart collections with the specified title.
+    pub title: Option<String>,
+    /// Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecated202101GetSmartCollectionsCountParams {
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_get_smart_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101GetSmartCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecated202101GetSmartCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_202101_update_smart_collections_param_collection_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderParams {
+    /// recurring_application_charge[capped_amount].
+    pub products: Option<String>,
+    /// The type of sorting to apply. Valid values are listed in the Properties section above.
+    /// (default: (current value)).
+    pub sort_order: Option<String>,
+}
+
+impl ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderParams {
+    pub fn products<T: ToString>(mut self, products: T) -> Self {
+        self.products = Some(products.to_string());
+        self
+    }
+
+    pub fn sort_order<T: ToString>(mut self, sort_order: T) -> Self {
+        self.sort_order = Some(sort_order.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_smart_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetSmartCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Filter results by smart collection handle.
+    pub handle: Option<String>,
+    /// Show only the smart collections specified by a comma-separated list of IDs.
+    pub ids: Option<String>,
+    /// The number of results to show.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show smart collections that includes the specified product.
+    pub product_id: Option<String>,
+    /// Show smart collections published before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show smart collections published after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Filter results based on the published status of smart collections.
+    /// (default: any)
+    ///
+    /// published: Show only published smart collections.
+    /// unpublished: Show only unpublished smart collections.
+    /// any: Show all smart collections.
+    pub published_status: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show smart collections with the specified title.
+    pub title: Option<String>,
+    /// Show smart collections last updated before this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetSmartCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn ids<T: ToString>(mut self, ids: T) -> Self {
+        self.ids = Some(ids.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_smart_collections_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetSmartCollectionsCountParams {
+    /// Show smart collections that include the specified product.
+    pub product_id: Option<String>,
+    /// Show smart collections published before this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_max: Option<String>,
+    /// Show smart collections published after this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub published_at_min: Option<String>,
+    /// Filter results based on the published status of smart collections.
+    /// (default: any)
+    ///
+    /// published: Show only published smart collections.
+    /// unpublished: Show only unpublished smart collections.
+    /// any: Show all smart collections.
+    pub published_status: Option<String>,
+    /// Show smart collections with the specified title.
+    pub title: Option<String>,
+    /// Show smart collections last updated before this date.  (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show smart collections last updated after this date. (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetSmartCollectionsCountParams {
+    pub fn product_id<T: ToString>(mut self, product_id: T) -> Self {
+        self.product_id = Some(product_id.to_string());
+        self
+    }
+
+    pub fn published_at_max<T: ToString>(mut self, published_at_max: T) -> Self {
+        self.published_at_max = Some(published_at_max.to_string());
+        self
+    }
+
+    pub fn published_at_min<T: ToString>(mut self, published_at_min: T) -> Self {
+        self.published_at_min = Some(published_at_min.to_string());
+        self
+    }
+
+    pub fn published_status<T: ToString>(mut self, published_status: T) -> Self {
+        self.published_status = Some(published_status.to_string());
+        self
+    }
+
+    pub fn title<T: ToString>(mut self, title: T) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_get_smart_collections_param_collection_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Products::deprecated_unstable_update_smart_collections_param_collection_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderParams {
+    /// recurring_application_charge[capped_amount].
+    pub products: Option<String>,
+    /// The type of sorting to apply. Valid values are listed in the Properties section above.
+    /// (default: (current value)).
+    pub sort_order: Option<String>,
+}
+
+impl ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderParams {
+    pub fn products<T: ToString>(mut self, products: T) -> Self {
+        self.products = Some(products.to_string());
+        self
+    }
+
+    pub fn sort_order<T: ToString>(mut self, sort_order: T) -> Self {
+        self.sort_order = Some(sort_order.to_string());
+        self
+    }
+}
+
+/// The functions of [`Products`], for code that takes them as a trait, so that it can be
+/// tested with the `MockProductsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ProductsApi: Send + Sync {
+    /// See [`Products::deprecated_202001_get_collect_with`].
+    async fn deprecated_202001_get_collect_with(
+        &self,
+        params: &ProductsDeprecated202001GetCollectParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_create_collects`].
+    async fn deprecated_202001_create_collects(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_collects_param_collect_with`].
+    async fn deprecated_202001_get_collects_param_collect_with(
+        &self,
+        collect_id: &str,
+        params: &ProductsDeprecated202001GetCollectsParamCollectParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_delete_collects_param_collect`].
+    async fn deprecated_202001_delete_collects_param_collect(&self, collect_id: &str)
+        -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_collects_count_with`].
+    async fn deprecated_202001_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecated202001GetCollectsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_collect_with`].
+    async fn deprecated_202004_get_collect_with(
+        &self,
+        params: &ProductsDeprecated202004GetCollectParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_create_collects`].
+    async fn deprecated_202004_create_collects(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_collects_param_collect_with`].
+    async fn deprecated_202004_get_collects_param_collect_with(
+        &self,
+        collect_id: &str,
+        params: &ProductsDeprecated202004GetCollectsParamCollectParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_delete_collects_param_collect`].
+    async fn deprecated_202004_delete_collects_param_collect(&self, collect_id: &str)
+        -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_collects_count_with`].
+    async fn deprecated_202004_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecated202004GetCollectsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_collect_with`].
+    async fn deprecated_202007_get_collect_with(
+        &self,
+        params: &ProductsDeprecated202007GetCollectParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_create_collects`].
+    async fn deprecated_202007_create_collects(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_collects_param_collect_with`].
+    async fn deprecated_202007_get_collects_param_collect_with(
+        &self,
+        collect_id: &str,
+        params: &ProductsDeprecated202007GetCollectsParamCollectParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_delete_collects_param_collect`].
+    async fn deprecated_202007_delete_collects_param_collect(&self, collect_id: &str)
+        -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_collects_count_with`].
+    async fn deprecated_202007_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecated202007GetCollectsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::get_collect_with`].
+    async fn get_collect_with(&self, params: &ProductsGetCollectParams) -> Result<()>;
+
+    /// See [`Products::create_collects`].
+    async fn create_collects(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::get_collects_param_collect_with`].
+    async fn get_collects_param_collect_with(
+        &self,
+        collect_id: &str,
+        params: &ProductsGetCollectsParamCollectParams,
+    ) -> Result<()>;
+
+    /// See [`Products::delete_collects_param_collect`].
+    async fn delete_collects_param_collect(&self, collect_id: &str) -> Result<()>;
+
+    /// See [`Products::get_collects_count_with`].
+    async fn get_collects_count_with(&self, params: &ProductsGetCollectsCountParams) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_collect_with`].
+    async fn deprecated_202101_get_collect_with(
+        &self,
+        params: &ProductsDeprecated202101GetCollectParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_create_collects`].
+    async fn deprecated_202101_create_collects(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_collects_param_collect_with`].
+    async fn deprecated_202101_get_collects_param_collect_with(
+        &self,
+        collect_id: &str,
+        params: &ProductsDeprecated202101GetCollectsParamCollectParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_delete_collects_param_collect`].
+    async fn deprecated_202101_delete_collects_param_collect(&self, collect_id: &str)
+        -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_collects_count_with`].
+    async fn deprecated_202101_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecated202101GetCollectsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_collect_with`].
+    async fn deprecated_unstable_get_collect_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetCollectParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_create_collects`].
+    async fn deprecated_unstable_create_collects(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_collects_param_collect_with`].
+    async fn deprecated_unstable_get_collects_param_collect_with(
+        &self,
+        collect_id: &str,
+        params: &ProductsDeprecatedUnstableGetCollectsParamCollectParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_delete_collects_param_collect`].
+    async fn deprecated_unstable_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_collects_count_with`].
+    async fn deprecated_unstable_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetCollectsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_collections_param_collection_with`].
+    async fn deprecated_202001_get_collections_param_collection_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202001GetCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_collections_param_collection_products_with`].
+    async fn deprecated_202001_get_collections_param_collection_products_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202001GetCollectionsParamCollectionProductsParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_collections_param_collection_with`].
+    async fn deprecated_202004_get_collections_param_collection_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202004GetCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_collections_param_collection_products_with`].
+    async fn deprecated_202004_get_collections_param_collection_products_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202004GetCollectionsParamCollectionProductsParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_collections_param_collection_with`].
+    async fn deprecated_202007_get_collections_param_collection_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202007GetCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_collections_param_collection_products_with`].
+    async fn deprecated_202007_get_collections_param_collection_products_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202007GetCollectionsParamCollectionProductsParams,
+    ) -> Result<()>;
+
+    /// See [`Products::get_collections_param_collection_with`].
+    async fn get_collections_param_collection_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsGetCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::get_collections_param_collection_products_with`].
+    async fn get_collections_param_collection_products_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsGetCollectionsParamCollectionProductsParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_collections_param_collection_with`].
+    async fn deprecated_202101_get_collections_param_collection_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202101GetCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_collections_param_collection_products_with`].
+    async fn deprecated_202101_get_collections_param_collection_products_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202101GetCollectionsParamCollectionProductsParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_collections_param_collection_with`].
+    async fn deprecated_unstable_get_collections_param_collection_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecatedUnstableGetCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_collections_param_collection_products_with`].
+    async fn deprecated_unstable_get_collections_param_collection_products_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_custom_collection_with`].
+    async fn deprecated_202001_get_custom_collection_with(
+        &self,
+        params: &ProductsDeprecated202001GetCustomCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_create_custom_collections`].
+    async fn deprecated_202001_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_custom_collections_count_with`].
+    async fn deprecated_202001_get_custom_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202001GetCustomCollectionsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_custom_collections_param_collection_with`].
+    async fn deprecated_202001_get_custom_collections_param_collection_with(
+        &self,
+        custom_collection_id: &str,
+        params: &ProductsDeprecated202001GetCustomCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_update_custom_collections_param_collection`].
+    async fn deprecated_202001_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_delete_custom_collections_param_collection`].
+    async fn deprecated_202001_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_custom_collection_with`].
+    async fn deprecated_202004_get_custom_collection_with(
+        &self,
+        params: &ProductsDeprecated202004GetCustomCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_create_custom_collections`].
+    async fn deprecated_202004_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_custom_collections_count_with`].
+    async fn deprecated_202004_get_custom_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202004GetCustomCollectionsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_custom_collections_param_collection_with`].
+    async fn deprecated_202004_get_custom_collections_param_collection_with(
+        &self,
+        custom_collection_id: &str,
+        params: &ProductsDeprecated202004GetCustomCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_update_custom_collections_param_collection`].
+    async fn deprecated_202004_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_delete_custom_collections_param_collection`].
+    async fn deprecated_202004_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_custom_collection_with`].
+    async fn deprecated_202007_get_custom_collection_with(
+        &self,
+        params: &ProductsDeprecated202007GetCustomCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_create_custom_collections`].
+    async fn deprecated_202007_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_custom_collections_count_with`].
+    async fn deprecated_202007_get_custom_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202007GetCustomCollectionsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_custom_collections_param_collection_with`].
+    async fn deprecated_202007_get_custom_collections_param_collection_with(
+        &self,
+        custom_collection_id: &str,
+        params: &ProductsDeprecated202007GetCustomCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_update_custom_collections_param_collection`].
+    async fn deprecated_202007_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_delete_custom_collections_param_collection`].
+    async fn deprecated_202007_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::get_custom_collection_with`].
+    async fn get_custom_collection_with(
+        &self,
+        params: &ProductsGetCustomCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::create_custom_collections`].
+    async fn create_custom_collections(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::get_custom_collections_count_with`].
+    async fn get_custom_collections_count_with(
+        &self,
+        params: &ProductsGetCustomCollectionsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::get_custom_collections_param_collection_with`].
+    async fn get_custom_collections_param_collection_with(
+        &self,
+        custom_collection_id: &str,
+        params: &ProductsGetCustomCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::update_custom_collections_param_collection`].
+    async fn update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::delete_custom_collections_param_collection`].
+    async fn delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_custom_collection_with`].
+    async fn deprecated_202101_get_custom_collection_with(
+        &self,
+        params: &ProductsDeprecated202101GetCustomCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_create_custom_collections`].
+    async fn deprecated_202101_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_custom_collections_count_with`].
+    async fn deprecated_202101_get_custom_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202101GetCustomCollectionsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_custom_collections_param_collection_with`].
+    async fn deprecated_202101_get_custom_collections_param_collection_with(
+        &self,
+        custom_collection_id: &str,
+        params: &ProductsDeprecated202101GetCustomCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_update_custom_collections_param_collection`].
+    async fn deprecated_202101_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_delete_custom_collections_param_collection`].
+    async fn deprecated_202101_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_custom_collection_with`].
+    async fn deprecated_unstable_get_custom_collection_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetCustomCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_create_custom_collections`].
+    async fn deprecated_unstable_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_custom_collections_count_with`].
+    async fn deprecated_unstable_get_custom_collections_count_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetCustomCollectionsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_custom_collections_param_collection_with`].
+    async fn deprecated_unstable_get_custom_collections_param_collection_with(
+        &self,
+        custom_collection_id: &str,
+        params: &ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_update_custom_collections_param_collection`].
+    async fn deprecated_unstable_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_delete_custom_collections_param_collection`].
+    async fn deprecated_unstable_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_with`].
+    async fn deprecated_202001_get_with(
+        &self,
+        params: &ProductsDeprecated202001GetParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_create`].
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_count_with`].
+    async fn deprecated_202001_get_count_with(
+        &self,
+        params: &ProductsDeprecated202001GetCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_param_with`].
+    async fn deprecated_202001_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202001GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_update_param`].
+    async fn deprecated_202001_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_delete_param`].
+    async fn deprecated_202001_delete_param(&self, product_id: &str) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_with`].
+    async fn deprecated_202004_get_with(
+        &self,
+        params: &ProductsDeprecated202004GetParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_create`].
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_count_with`].
+    async fn deprecated_202004_get_count_with(
+        &self,
+        params: &ProductsDeprecated202004GetCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_param_with`].
+    async fn deprecated_202004_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202004GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_update_param`].
+    async fn deprecated_202004_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_delete_param`].
+    async fn deprecated_202004_delete_param(&self, product_id: &str) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_with`].
+    async fn deprecated_202007_get_with(
+        &self,
+        params: &ProductsDeprecated202007GetParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_create`].
+    async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_count_with`].
+    async fn deprecated_202007_get_count_with(
+        &self,
+        params: &ProductsDeprecated202007GetCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_param_with`].
+    async fn deprecated_202007_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202007GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_update_param`].
+    async fn deprecated_202007_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_delete_param`].
+    async fn deprecated_202007_delete_param(&self, product_id: &str) -> Result<()>;
+
+    /// See [`Products::get_with`].
+    async fn get_with(&self, params: &ProductsGetParams) -> Result<()>;
+
+    /// See [`Products::create`].
+    async fn create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::get_count_with`].
+    async fn get_count_with(&self, params: &ProductsGetCountParams) -> Result<()>;
+
+    /// See [`Products::get_param_with`].
+    async fn get_param_with(&self, product_id: &str, params: &ProductsGetParamParams)
+        -> Result<()>;
+
+    /// See [`Products::update_param`].
+    async fn update_param(&self, product_id: &str, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::delete_param`].
+    async fn delete_param(&self, product_id: &str) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_with`].
+    async fn deprecated_202101_get_with(
+        &self,
+        params: &ProductsDeprecated202101GetParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_create`].
+    async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_count_with`].
+    async fn deprecated_202101_get_count_with(
+        &self,
+        params: &ProductsDeprecated202101GetCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_param_with`].
+    async fn deprecated_202101_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202101GetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_update_param`].
+    async fn deprecated_202101_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_delete_param`].
+    async fn deprecated_202101_delete_param(&self, product_id: &str) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_with`].
+    async fn deprecated_unstable_get_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_create`].
+    async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_count_with`].
+    async fn deprecated_unstable_get_count_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_param_with`].
+    async fn deprecated_unstable_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecatedUnstableGetParamParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_update_param`].
+    async fn deprecated_unstable_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_delete_param`].
+    async fn deprecated_unstable_delete_param(&self, product_id: &str) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_param_image_with`].
+    async fn deprecated_202001_get_param_image_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202001GetParamImageParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_create_param_images`].
+    async fn deprecated_202001_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_param_images_count_with`].
+    async fn deprecated_202001_get_param_images_count_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202001GetParamImagesCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_param_images_image_with`].
+    async fn deprecated_202001_get_param_images_image_with(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        params: &ProductsDeprecated202001GetParamImagesImageParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_update_param_images_image`].
+    async fn deprecated_202001_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_delete_param_images_image`].
+    async fn deprecated_202001_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_param_image_with`].
+    async fn deprecated_202004_get_param_image_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202004GetParamImageParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_create_param_images`].
+    async fn deprecated_202004_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_param_images_count_with`].
+    async fn deprecated_202004_get_param_images_count_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202004GetParamImagesCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_param_images_image_with`].
+    async fn deprecated_202004_get_param_images_image_with(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        params: &ProductsDeprecated202004GetParamImagesImageParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_update_param_images_image`].
+    async fn deprecated_202004_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_delete_param_images_image`].
+    async fn deprecated_202004_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_param_image_with`].
+    async fn deprecated_202007_get_param_image_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202007GetParamImageParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_create_param_images`].
+    async fn deprecated_202007_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_param_images_count_with`].
+    async fn deprecated_202007_get_param_images_count_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202007GetParamImagesCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_param_images_image_with`].
+    async fn deprecated_202007_get_param_images_image_with(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        params: &ProductsDeprecated202007GetParamImagesImageParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_update_param_images_image`].
+    async fn deprecated_202007_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_delete_param_images_image`].
+    async fn deprecated_202007_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::get_param_image_with`].
+    async fn get_param_image_with(
+        &self,
+        product_id: &str,
+        params: &ProductsGetParamImageParams,
+    ) -> Result<()>;
+
+    /// See [`Products::create_param_images`].
+    async fn create_param_images(&self, product_id: &str, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::get_param_images_count_with`].
+    async fn get_param_images_count_with(
+        &self,
+        product_id: &str,
+        params: &ProductsGetParamImagesCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::get_param_images_image_with`].
+    async fn get_param_images_image_with(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        params: &ProductsGetParamImagesImageParams,
+    ) -> Result<()>;
+
+    /// See [`Products::update_param_images_image`].
+    async fn update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::delete_param_images_image`].
+    async fn delete_param_images_image(&self, product_id: &str, image_id: &str) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_param_image_with`].
+    async fn deprecated_202101_get_param_image_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202101GetParamImageParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_create_param_images`].
+    async fn deprecated_202101_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_param_images_count_with`].
+    async fn deprecated_202101_get_param_images_count_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202101GetParamImagesCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_param_images_image_with`].
+    async fn deprecated_202101_get_param_images_image_with(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        params: &ProductsDeprecated202101GetParamImagesImageParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_update_param_images_image`].
+    async fn deprecated_202101_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_delete_param_images_image`].
+    async fn deprecated_202101_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_param_image_with`].
+    async fn deprecated_unstable_get_param_image_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecatedUnstableGetParamImageParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_create_param_images`].
+    async fn deprecated_unstable_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_param_images_count_with`].
+    async fn deprecated_unstable_get_param_images_count_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecatedUnstableGetParamImagesCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_param_images_image_with`].
+    async fn deprecated_unstable_get_param_images_image_with(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        params: &ProductsDeprecatedUnstableGetParamImagesImageParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_update_param_images_image`].
+    async fn deprecated_unstable_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_delete_param_images_image`].
+    async fn deprecated_unstable_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_smart_collection_with`].
+    async fn deprecated_202001_get_smart_collection_with(
+        &self,
+        params: &ProductsDeprecated202001GetSmartCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_create_smart_collections`].
+    async fn deprecated_202001_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_smart_collections_count_with`].
+    async fn deprecated_202001_get_smart_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202001GetSmartCollectionsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_get_smart_collections_param_collection_with`].
+    async fn deprecated_202001_get_smart_collections_param_collection_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202001GetSmartCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_update_smart_collections_param_collection`].
+    async fn deprecated_202001_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_delete_smart_collections_param_collection`].
+    async fn deprecated_202001_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202001_update_smart_collections_param_collection_order_with`].
+    async fn deprecated_202001_update_smart_collections_param_collection_order_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_smart_collection_with`].
+    async fn deprecated_202004_get_smart_collection_with(
+        &self,
+        params: &ProductsDeprecated202004GetSmartCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_create_smart_collections`].
+    async fn deprecated_202004_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_smart_collections_count_with`].
+    async fn deprecated_202004_get_smart_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202004GetSmartCollectionsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_get_smart_collections_param_collection_with`].
+    async fn deprecated_202004_get_smart_collections_param_collection_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202004GetSmartCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_update_smart_collections_param_collection`].
+    async fn deprecated_202004_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_delete_smart_collections_param_collection`].
+    async fn deprecated_202004_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202004_update_smart_collections_param_collection_order_with`].
+    async fn deprecated_202004_update_smart_collections_param_collection_order_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_smart_collection_with`].
+    async fn deprecated_202007_get_smart_collection_with(
+        &self,
+        params: &ProductsDeprecated202007GetSmartCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_create_smart_collections`].
+    async fn deprecated_202007_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_smart_collections_count_with`].
+    async fn deprecated_202007_get_smart_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202007GetSmartCollectionsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_get_smart_collections_param_collection_with`].
+    async fn deprecated_202007_get_smart_collections_param_collection_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202007GetSmartCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_update_smart_collections_param_collection`].
+    async fn deprecated_202007_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_delete_smart_collections_param_collection`].
+    async fn deprecated_202007_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202007_update_smart_collections_param_collection_order_with`].
+    async fn deprecated_202007_update_smart_collections_param_collection_order_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::get_smart_collection_with`].
+    async fn get_smart_collection_with(
+        &self,
+        params: &ProductsGetSmartCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::create_smart_collections`].
+    async fn create_smart_collections(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`Products::get_smart_collections_count_with`].
+    async fn get_smart_collections_count_with(
+        &self,
+        params: &ProductsGetSmartCollectionsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::get_smart_collections_param_collection_with`].
+    async fn get_smart_collections_param_collection_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsGetSmartCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::update_smart_collections_param_collection`].
+    async fn update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::delete_smart_collections_param_collection`].
+    async fn delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::update_smart_collections_param_collection_order_with`].
+    async fn update_smart_collections_param_collection_order_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsUpdateSmartCollectionsParamCollectionOrderParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_smart_collection_with`].
+    async fn deprecated_202101_get_smart_collection_with(
+        &self,
+        params: &ProductsDeprecated202101GetSmartCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_create_smart_collections`].
+    async fn deprecated_202101_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_smart_collections_count_with`].
+    async fn deprecated_202101_get_smart_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202101GetSmartCollectionsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_get_smart_collections_param_collection_with`].
+    async fn deprecated_202101_get_smart_collections_param_collection_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202101GetSmartCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_update_smart_collections_param_collection`].
+    async fn deprecated_202101_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_delete_smart_collections_param_collection`].
+    async fn deprecated_202101_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_202101_update_smart_collections_param_collection_order_with`].
+    async fn deprecated_202101_update_smart_collections_param_collection_order_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_smart_collection_with`].
+    async fn deprecated_unstable_get_smart_collection_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetSmartCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_create_smart_collections`].
+    async fn deprecated_unstable_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_smart_collections_count_with`].
+    async fn deprecated_unstable_get_smart_collections_count_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetSmartCollectionsCountParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_get_smart_collections_param_collection_with`].
+    async fn deprecated_unstable_get_smart_collections_param_collection_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionParams,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_update_smart_collections_param_collection`].
+    async fn deprecated_unstable_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_delete_smart_collections_param_collection`].
+    async fn deprecated_unstable_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()>;
+
+    /// See [`Products::deprecated_unstable_update_smart_collections_param_collection_order_with`].
+    async fn deprecated_unstable_update_smart_collections_param_collection_order_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ProductsApi for Products {
+    async fn deprecated_202001_get_collect_with(
+        &self,
+        params: &ProductsDeprecated202001GetCollectParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_collect_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_collects(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202001_create_collects(self, body).await
+    }
+
+    async fn deprecated_202001_get_collects_param_collect_with(
+        &self,
+        collect_id: &str,
+        params: &ProductsDeprecated202001GetCollectsParamCollectParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_collects_param_collect_with(self, collect_id, params).await
+    }
+
+    async fn deprecated_202001_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_delete_collects_param_collect(self, collect_id).await
+    }
+
+    async fn deprecated_202001_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecated202001GetCollectsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_collects_count_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_collect_with(
+        &self,
+        params: &ProductsDeprecated202004GetCollectParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_collect_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_collects(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202004_create_collects(self, body).await
+    }
+
+    async fn deprecated_202004_get_collects_param_collect_with(
+        &self,
+        collect_id: &str,
+        params: &ProductsDeprecated202004GetCollectsParamCollectParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_collects_param_collect_with(self, collect_id, params).await
+    }
+
+    async fn deprecated_202004_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_delete_collects_param_collect(self, collect_id).await
+    }
+
+    async fn deprecated_202004_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecated202004GetCollectsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_collects_count_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_collect_with(
+        &self,
+        params: &ProductsDeprecated202007GetCollectParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_collect_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_collects(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202007_create_collects(self, body).await
+    }
+
+    async fn deprecated_202007_get_collects_param_collect_with(
+        &self,
+        collect_id: &str,
+        params: &ProductsDeprecated202007GetCollectsParamCollectParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_collects_param_collect_with(self, collect_id, params).await
+    }
+
+    async fn deprecated_202007_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_delete_collects_param_collect(self, collect_id).await
+    }
+
+    async fn deprecated_202007_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecated202007GetCollectsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_collects_count_with(self, params).await
+    }
+
+    async fn get_collect_with(&self, params: &ProductsGetCollectParams) -> Result<()> {
+        Products::get_collect_with(self, params).await
+    }
+
+    async fn create_collects(&self, body: &serde_json::Value) -> Result<()> {
+        Products::create_collects(self, body).await
+    }
+
+    async fn get_collects_param_collect_with(
+        &self,
+        collect_id: &str,
+        params: &ProductsGetCollectsParamCollectParams,
+    ) -> Result<()> {
+        Products::get_collects_param_collect_with(self, collect_id, params).await
+    }
+
+    async fn delete_collects_param_collect(&self, collect_id: &str) -> Result<()> {
+        Products::delete_collects_param_collect(self, collect_id).await
+    }
+
+    async fn get_collects_count_with(&self, params: &ProductsGetCollectsCountParams) -> Result<()> {
+        Products::get_collects_count_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_collect_with(
+        &self,
+        params: &ProductsDeprecated202101GetCollectParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_collect_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_collects(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202101_create_collects(self, body).await
+    }
+
+    async fn deprecated_202101_get_collects_param_collect_with(
+        &self,
+        collect_id: &str,
+        params: &ProductsDeprecated202101GetCollectsParamCollectParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_collects_param_collect_with(self, collect_id, params).await
+    }
+
+    async fn deprecated_202101_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_delete_collects_param_collect(self, collect_id).await
+    }
+
+    async fn deprecated_202101_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecated202101GetCollectsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_collects_count_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_collect_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetCollectParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_collect_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_collects(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_unstable_create_collects(self, body).await
+    }
+
+    async fn deprecated_unstable_get_collects_param_collect_with(
+        &self,
+        collect_id: &str,
+        params: &ProductsDeprecatedUnstableGetCollectsParamCollectParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_collects_param_collect_with(self, collect_id, params)
+            .await
+    }
+
+    async fn deprecated_unstable_delete_collects_param_collect(
+        &self,
+        collect_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_delete_collects_param_collect(self, collect_id).await
+    }
+
+    async fn deprecated_unstable_get_collects_count_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetCollectsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_collects_count_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_collections_param_collection_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202001GetCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_collections_param_collection_with(
+            self,
+            collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_collections_param_collection_products_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202001GetCollectionsParamCollectionProductsParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_collections_param_collection_products_with(
+            self,
+            collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_collections_param_collection_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202004GetCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_collections_param_collection_with(
+            self,
+            collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_collections_param_collection_products_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202004GetCollectionsParamCollectionProductsParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_collections_param_collection_products_with(
+            self,
+            collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_collections_param_collection_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202007GetCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_collections_param_collection_with(
+            self,
+            collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_collections_param_collection_products_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202007GetCollectionsParamCollectionProductsParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_collections_param_collection_products_with(
+            self,
+            collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn get_collections_param_collection_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsGetCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::get_collections_param_collection_with(self, collection_id, params).await
+    }
+
+    async fn get_collections_param_collection_products_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsGetCollectionsParamCollectionProductsParams,
+    ) -> Result<()> {
+        Products::get_collections_param_collection_products_with(self, collection_id, params).await
+    }
+
+    async fn deprecated_202101_get_collections_param_collection_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202101GetCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_collections_param_collection_with(
+            self,
+            collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_collections_param_collection_products_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecated202101GetCollectionsParamCollectionProductsParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_collections_param_collection_products_with(
+            self,
+            collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_collections_param_collection_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecatedUnstableGetCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_collections_param_collection_with(
+            self,
+            collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_collections_param_collection_products_with(
+        &self,
+        collection_id: &str,
+        params: &ProductsDeprecatedUnstableGetCollectionsParamCollectionProductsParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_collections_param_collection_products_with(
+            self,
+            collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_custom_collection_with(
+        &self,
+        params: &ProductsDeprecated202001GetCustomCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_custom_collection_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_create_custom_collections(self, body).await
+    }
+
+    async fn deprecated_202001_get_custom_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202001GetCustomCollectionsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_custom_collections_count_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_custom_collections_param_collection_with(
+        &self,
+        custom_collection_id: &str,
+        params: &ProductsDeprecated202001GetCustomCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_custom_collections_param_collection_with(
+            self,
+            custom_collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_update_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_delete_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_custom_collection_with(
+        &self,
+        params: &ProductsDeprecated202004GetCustomCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_custom_collection_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_create_custom_collections(self, body).await
+    }
+
+    async fn deprecated_202004_get_custom_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202004GetCustomCollectionsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_custom_collections_count_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_custom_collections_param_collection_with(
+        &self,
+        custom_collection_id: &str,
+        params: &ProductsDeprecated202004GetCustomCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_custom_collections_param_collection_with(
+            self,
+            custom_collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_update_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_delete_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_custom_collection_with(
+        &self,
+        params: &ProductsDeprecated202007GetCustomCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_custom_collection_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_create_custom_collections(self, body).await
+    }
+
+    async fn deprecated_202007_get_custom_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202007GetCustomCollectionsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_custom_collections_count_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_custom_collections_param_collection_with(
+        &self,
+        custom_collection_id: &str,
+        params: &ProductsDeprecated202007GetCustomCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_custom_collections_param_collection_with(
+            self,
+            custom_collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_update_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_delete_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+
+    async fn get_custom_collection_with(
+        &self,
+        params: &ProductsGetCustomCollectionParams,
+    ) -> Result<()> {
+        Products::get_custom_collection_with(self, params).await
+    }
+
+    async fn create_custom_collections(&self, body: &serde_json::Value) -> Result<()> {
+        Products::create_custom_collections(self, body).await
+    }
+
+    async fn get_custom_collections_count_with(
+        &self,
+        params: &ProductsGetCustomCollectionsCountParams,
+    ) -> Result<()> {
+        Products::get_custom_collections_count_with(self, params).await
+    }
+
+    async fn get_custom_collections_param_collection_with(
+        &self,
+        custom_collection_id: &str,
+        params: &ProductsGetCustomCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::get_custom_collections_param_collection_with(self, custom_collection_id, params)
+            .await
+    }
+
+    async fn update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::update_custom_collections_param_collection(self, custom_collection_id, body).await
+    }
+
+    async fn delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()> {
+        Products::delete_custom_collections_param_collection(self, custom_collection_id).await
+    }
+
+    async fn deprecated_202101_get_custom_collection_with(
+        &self,
+        params: &ProductsDeprecated202101GetCustomCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_custom_collection_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_create_custom_collections(self, body).await
+    }
+
+    async fn deprecated_202101_get_custom_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202101GetCustomCollectionsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_custom_collections_count_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_custom_collections_param_collection_with(
+        &self,
+        custom_collection_id: &str,
+        params: &ProductsDeprecated202101GetCustomCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_custom_collections_param_collection_with(
+            self,
+            custom_collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_update_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_delete_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_custom_collection_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetCustomCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_custom_collection_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_custom_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_create_custom_collections(self, body).await
+    }
+
+    async fn deprecated_unstable_get_custom_collections_count_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetCustomCollectionsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_custom_collections_count_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_custom_collections_param_collection_with(
+        &self,
+        custom_collection_id: &str,
+        params: &ProductsDeprecatedUnstableGetCustomCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_custom_collections_param_collection_with(
+            self,
+            custom_collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_update_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_delete_custom_collections_param_collection(
+        &self,
+        custom_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_delete_custom_collections_param_collection(
+            self,
+            custom_collection_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_with(
+        &self,
+        params: &ProductsDeprecated202001GetParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_with(self, params).await
+    }
+
+    async fn deprecated_202001_create(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202001_create(self, body).await
+    }
+
+    async fn deprecated_202001_get_count_with(
+        &self,
+        params: &ProductsDeprecated202001GetCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_count_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202001GetParamParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_param_with(self, product_id, params).await
+    }
+
+    async fn deprecated_202001_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_update_param(self, product_id, body).await
+    }
+
+    async fn deprecated_202001_delete_param(&self, product_id: &str) -> Result<()> {
+        Products::deprecated_202001_delete_param(self, product_id).await
+    }
+
+    async fn deprecated_202004_get_with(
+        &self,
+        params: &ProductsDeprecated202004GetParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_with(self, params).await
+    }
+
+    async fn deprecated_202004_create(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202004_create(self, body).await
+    }
+
+    async fn deprecated_202004_get_count_with(
+        &self,
+        params: &ProductsDeprecated202004GetCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_count_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202004GetParamParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_param_with(self, product_id, params).await
+    }
+
+    async fn deprecated_202004_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_update_param(self, product_id, body).await
+    }
+
+    async fn deprecated_202004_delete_param(&self, product_id: &str) -> Result<()> {
+        Products::deprecated_202004_delete_param(self, product_id).await
+    }
+
+    async fn deprecated_202007_get_with(
+        &self,
+        params: &ProductsDeprecated202007GetParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_with(self, params).await
+    }
+
+    async fn deprecated_202007_create(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202007_create(self, body).await
+    }
+
+    async fn deprecated_202007_get_count_with(
+        &self,
+        params: &ProductsDeprecated202007GetCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_count_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202007GetParamParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_param_with(self, product_id, params).await
+    }
+
+    async fn deprecated_202007_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_update_param(self, product_id, body).await
+    }
+
+    async fn deprecated_202007_delete_param(&self, product_id: &str) -> Result<()> {
+        Products::deprecated_202007_delete_param(self, product_id).await
+    }
+
+    async fn get_with(&self, params: &ProductsGetParams) -> Result<()> {
+        Products::get_with(self, params).await
+    }
+
+    async fn create(&self, body: &serde_json::Value) -> Result<()> {
+        Products::create(self, body).await
+    }
+
+    async fn get_count_with(&self, params: &ProductsGetCountParams) -> Result<()> {
+        Products::get_count_with(self, params).await
+    }
+
+    async fn get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsGetParamParams,
+    ) -> Result<()> {
+        Products::get_param_with(self, product_id, params).await
+    }
+
+    async fn update_param(&self, product_id: &str, body: &serde_json::Value) -> Result<()> {
+        Products::update_param(self, product_id, body).await
+    }
+
+    async fn delete_param(&self, product_id: &str) -> Result<()> {
+        Products::delete_param(self, product_id).await
+    }
+
+    async fn deprecated_202101_get_with(
+        &self,
+        params: &ProductsDeprecated202101GetParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_with(self, params).await
+    }
+
+    async fn deprecated_202101_create(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_202101_create(self, body).await
+    }
+
+    async fn deprecated_202101_get_count_with(
+        &self,
+        params: &ProductsDeprecated202101GetCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_count_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202101GetParamParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_param_with(self, product_id, params).await
+    }
+
+    async fn deprecated_202101_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_update_param(self, product_id, body).await
+    }
+
+    async fn deprecated_202101_delete_param(&self, product_id: &str) -> Result<()> {
+        Products::deprecated_202101_delete_param(self, product_id).await
+    }
+
+    async fn deprecated_unstable_get_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create(&self, body: &serde_json::Value) -> Result<()> {
+        Products::deprecated_unstable_create(self, body).await
+    }
+
+    async fn deprecated_unstable_get_count_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetCountParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_count_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_param_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecatedUnstableGetParamParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_param_with(self, product_id, params).await
+    }
+
+    async fn deprecated_unstable_update_param(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_update_param(self, product_id, body).await
+    }
+
+    async fn deprecated_unstable_delete_param(&self, product_id: &str) -> Result<()> {
+        Products::deprecated_unstable_delete_param(self, product_id).await
+    }
+
+    async fn deprecated_202001_get_param_image_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202001GetParamImageParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_param_image_with(self, product_id, params).await
+    }
+
+    async fn deprecated_202001_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_create_param_images(self, product_id, body).await
+    }
+
+    async fn deprecated_202001_get_param_images_count_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202001GetParamImagesCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_param_images_count_with(self, product_id, params).await
+    }
+
+    async fn deprecated_202001_get_param_images_image_with(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        params: &ProductsDeprecated202001GetParamImagesImageParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_param_images_image_with(self, product_id, image_id, params)
+            .await
+    }
+
+    async fn deprecated_202001_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_update_param_images_image(self, product_id, image_id, body)
+            .await
+    }
+
+    async fn deprecated_202001_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_delete_param_images_image(self, product_id, image_id).await
+    }
+
+    async fn deprecated_202004_get_param_image_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202004GetParamImageParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_param_image_with(self, product_id, params).await
+    }
+
+    async fn deprecated_202004_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_create_param_images(self, product_id, body).await
+    }
+
+    async fn deprecated_202004_get_param_images_count_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202004GetParamImagesCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_param_images_count_with(self, product_id, params).await
+    }
+
+    async fn deprecated_202004_get_param_images_image_with(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        params: &ProductsDeprecated202004GetParamImagesImageParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_param_images_image_with(self, product_id, image_id, params)
+            .await
+    }
+
+    async fn deprecated_202004_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_update_param_images_image(self, product_id, image_id, body)
+            .await
+    }
+
+    async fn deprecated_202004_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_delete_param_images_image(self, product_id, image_id).await
+    }
+
+    async fn deprecated_202007_get_param_image_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202007GetParamImageParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_param_image_with(self, product_id, params).await
+    }
+
+    async fn deprecated_202007_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_create_param_images(self, product_id, body).await
+    }
+
+    async fn deprecated_202007_get_param_images_count_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202007GetParamImagesCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_param_images_count_with(self, product_id, params).await
+    }
+
+    async fn deprecated_202007_get_param_images_image_with(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        params: &ProductsDeprecated202007GetParamImagesImageParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_param_images_image_with(self, product_id, image_id, params)
+            .await
+    }
+
+    async fn deprecated_202007_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_update_param_images_image(self, product_id, image_id, body)
+            .await
+    }
+
+    async fn deprecated_202007_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_delete_param_images_image(self, product_id, image_id).await
+    }
+
+    async fn get_param_image_with(
+        &self,
+        product_id: &str,
+        params: &ProductsGetParamImageParams,
+    ) -> Result<()> {
+        Products::get_param_image_with(self, product_id, params).await
+    }
+
+    async fn create_param_images(&self, product_id: &str, body: &serde_json::Value) -> Result<()> {
+        Products::create_param_images(self, product_id, body).await
+    }
+
+    async fn get_param_images_count_with(
+        &self,
+        product_id: &str,
+        params: &ProductsGetParamImagesCountParams,
+    ) -> Result<()> {
+        Products::get_param_images_count_with(self, product_id, params).await
+    }
+
+    async fn get_param_images_image_with(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        params: &ProductsGetParamImagesImageParams,
+    ) -> Result<()> {
+        Products::get_param_images_image_with(self, product_id, image_id, params).await
+    }
+
+    async fn update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::update_param_images_image(self, product_id, image_id, body).await
+    }
+
+    async fn delete_param_images_image(&self, product_id: &str, image_id: &str) -> Result<()> {
+        Products::delete_param_images_image(self, product_id, image_id).await
+    }
+
+    async fn deprecated_202101_get_param_image_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202101GetParamImageParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_param_image_with(self, product_id, params).await
+    }
+
+    async fn deprecated_202101_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_create_param_images(self, product_id, body).await
+    }
+
+    async fn deprecated_202101_get_param_images_count_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecated202101GetParamImagesCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_param_images_count_with(self, product_id, params).await
+    }
+
+    async fn deprecated_202101_get_param_images_image_with(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        params: &ProductsDeprecated202101GetParamImagesImageParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_param_images_image_with(self, product_id, image_id, params)
+            .await
+    }
+
+    async fn deprecated_202101_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_update_param_images_image(self, product_id, image_id, body)
+            .await
+    }
+
+    async fn deprecated_202101_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_delete_param_images_image(self, product_id, image_id).await
+    }
+
+    async fn deprecated_unstable_get_param_image_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecatedUnstableGetParamImageParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_param_image_with(self, product_id, params).await
+    }
+
+    async fn deprecated_unstable_create_param_images(
+        &self,
+        product_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_create_param_images(self, product_id, body).await
+    }
+
+    async fn deprecated_unstable_get_param_images_count_with(
+        &self,
+        product_id: &str,
+        params: &ProductsDeprecatedUnstableGetParamImagesCountParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_param_images_count_with(self, product_id, params).await
+    }
+
+    async fn deprecated_unstable_get_param_images_image_with(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        params: &ProductsDeprecatedUnstableGetParamImagesImageParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_param_images_image_with(
+            self, product_id, image_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_update_param_images_image(self, product_id, image_id, body)
+            .await
+    }
+
+    async fn deprecated_unstable_delete_param_images_image(
+        &self,
+        product_id: &str,
+        image_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_delete_param_images_image(self, product_id, image_id).await
+    }
+
+    async fn deprecated_202001_get_smart_collection_with(
+        &self,
+        params: &ProductsDeprecated202001GetSmartCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_smart_collection_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_create_smart_collections(self, body).await
+    }
+
+    async fn deprecated_202001_get_smart_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202001GetSmartCollectionsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_smart_collections_count_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_smart_collections_param_collection_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202001GetSmartCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202001_get_smart_collections_param_collection_with(
+            self,
+            smart_collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_update_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202001_delete_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_update_smart_collections_param_collection_order_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202001UpdateSmartCollectionsParamCollectionOrderParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202001_update_smart_collections_param_collection_order_with(
+            self,
+            smart_collection_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_smart_collection_with(
+        &self,
+        params: &ProductsDeprecated202004GetSmartCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_smart_collection_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_create_smart_collections(self, body).await
+    }
+
+    async fn deprecated_202004_get_smart_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202004GetSmartCollectionsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_smart_collections_count_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_smart_collections_param_collection_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202004GetSmartCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202004_get_smart_collections_param_collection_with(
+            self,
+            smart_collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_update_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202004_delete_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_update_smart_collections_param_collection_order_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202004UpdateSmartCollectionsParamCollectionOrderParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202004_update_smart_collections_param_collection_order_with(
+            self,
+            smart_collection_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_smart_collection_with(
+        &self,
+        params: &ProductsDeprecated202007GetSmartCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_smart_collection_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_create_smart_collections(self, body).await
+    }
+
+    async fn deprecated_202007_get_smart_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202007GetSmartCollectionsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_smart_collections_count_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_smart_collections_param_collection_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202007GetSmartCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202007_get_smart_collections_param_collection_with(
+            self,
+            smart_collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_update_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202007_delete_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_update_smart_collections_param_collection_order_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202007UpdateSmartCollectionsParamCollectionOrderParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202007_update_smart_collections_param_collection_order_with(
+            self,
+            smart_collection_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn get_smart_collection_with(
+        &self,
+        params: &ProductsGetSmartCollectionParams,
+    ) -> Result<()> {
+        Products::get_smart_collection_with(self, params).await
+    }
+
+    async fn create_smart_collections(&self, body: &serde_json::Value) -> Result<()> {
+        Products::create_smart_collections(self, body).await
+    }
+
+    async fn get_smart_collections_count_with(
+        &self,
+        params: &ProductsGetSmartCollectionsCountParams,
+    ) -> Result<()> {
+        Products::get_smart_collections_count_with(self, params).await
+    }
+
+    async fn get_smart_collections_param_collection_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsGetSmartCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::get_smart_collections_param_collection_with(self, smart_collection_id, params)
+            .await
+    }
+
+    async fn update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::update_smart_collections_param_collection(self, smart_collection_id, body).await
+    }
+
+    async fn delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()> {
+        Products::delete_smart_collections_param_collection(self, smart_collection_id).await
+    }
+
+    async fn update_smart_collections_param_collection_order_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsUpdateSmartCollectionsParamCollectionOrderParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::update_smart_collections_param_collection_order_with(
+            self,
+            smart_collection_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_smart_collection_with(
+        &self,
+        params: &ProductsDeprecated202101GetSmartCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_smart_collection_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_create_smart_collections(self, body).await
+    }
+
+    async fn deprecated_202101_get_smart_collections_count_with(
+        &self,
+        params: &ProductsDeprecated202101GetSmartCollectionsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_smart_collections_count_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_smart_collections_param_collection_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202101GetSmartCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_202101_get_smart_collections_param_collection_with(
+            self,
+            smart_collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_update_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_202101_delete_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_update_smart_collections_param_collection_order_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecated202101UpdateSmartCollectionsParamCollectionOrderParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_202101_update_smart_collections_param_collection_order_with(
+            self,
+            smart_collection_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_smart_collection_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetSmartCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_smart_collection_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_smart_collections(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_create_smart_collections(self, body).await
+    }
+
+    async fn deprecated_unstable_get_smart_collections_count_with(
+        &self,
+        params: &ProductsDeprecatedUnstableGetSmartCollectionsCountParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_smart_collections_count_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_smart_collections_param_collection_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecatedUnstableGetSmartCollectionsParamCollectionParams,
+    ) -> Result<()> {
+        Products::deprecated_unstable_get_smart_collections_param_collection_with(
+            self,
+            smart_collection_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_update_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_delete_smart_collections_param_collection(
+        &self,
+        smart_collection_id: &str,
+    ) -> Result<()> {
+        Products::deprecated_unstable_delete_smart_collections_param_collection(
+            self,
+            smart_collection_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_smart_collections_param_collection_order_with(
+        &self,
+        smart_collection_id: &str,
+        params: &ProductsDeprecatedUnstableUpdateSmartCollectionsParamCollectionOrderParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        Products::deprecated_unstable_update_smart_collections_param_collection_order_with(
+            self,
+            smart_collection_id,
+            params,
+            body,
+        )
+        .await
+    }
 }
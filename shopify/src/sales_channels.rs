@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SalesChannels {
     pub client: Client,
@@ -687,12 +685,14 @@ impl SalesChannels {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsDeprecated202001GetCollectionListingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_collection_listing(&self, limit: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202001GetCollectionListingParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -701,6 +701,18 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_collection_listing_with` and pass the query parameters as a `SalesChannelsDeprecated202001GetCollectionListingParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_collection_listing_with` instead")]
+    pub async fn deprecated_202001_get_collection_listing(&self, limit: &str) -> Result<()> {
+        let params = SalesChannelsDeprecated202001GetCollectionListingParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_collection_listing_with(&params)
+            .await
+    }
+
     /**
      * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -711,16 +723,15 @@ impl SalesChannels {
      * **Parameters:**
      *
      * * `collection_listing_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_collection_listings_param_listing_product_id(
+    pub async fn deprecated_202001_get_collection_listings_param_listing_product_id_with(
         &self,
         collection_listing_id: &str,
-        limit: &str,
+        params: &SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -733,6 +744,29 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_collection_listings_param_listing_product_id_with` and pass the query parameters as a `SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_collection_listings_param_listing_product_id_with` \
+                instead"
+    )]
+    pub async fn deprecated_202001_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        let params =
+            SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdParams {
+                limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202001_get_collection_listings_param_listing_product_id_with(
+            collection_listing_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieve a specific collection listing that is published to your app.
      *
@@ -814,12 +848,14 @@ impl SalesChannels {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsDeprecated202004GetCollectionListingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_collection_listing(&self, limit: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202004GetCollectionListingParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -828,6 +864,18 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_collection_listing_with` and pass the query parameters as a `SalesChannelsDeprecated202004GetCollectionListingParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_collection_listing_with` instead")]
+    pub async fn deprecated_202004_get_collection_listing(&self, limit: &str) -> Result<()> {
+        let params = SalesChannelsDeprecated202004GetCollectionListingParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_collection_listing_with(&params)
+            .await
+    }
+
     /**
      * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -838,16 +886,15 @@ impl SalesChannels {
      * **Parameters:**
      *
      * * `collection_listing_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_collection_listings_param_listing_product_id(
+    pub async fn deprecated_202004_get_collection_listings_param_listing_product_id_with(
         &self,
         collection_listing_id: &str,
-        limit: &str,
+        params: &SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -860,6 +907,29 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_collection_listings_param_listing_product_id_with` and pass the query parameters as a `SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_collection_listings_param_listing_product_id_with` \
+                instead"
+    )]
+    pub async fn deprecated_202004_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        let params =
+            SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdParams {
+                limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202004_get_collection_listings_param_listing_product_id_with(
+            collection_listing_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieve a specific collection listing that is published to your app.
      *
@@ -941,12 +1011,14 @@ impl SalesChannels {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsDeprecated202007GetCollectionListingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_collection_listing(&self, limit: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202007GetCollectionListingParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -955,6 +1027,18 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_collection_listing_with` and pass the query parameters as a `SalesChannelsDeprecated202007GetCollectionListingParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_collection_listing_with` instead")]
+    pub async fn deprecated_202007_get_collection_listing(&self, limit: &str) -> Result<()> {
+        let params = SalesChannelsDeprecated202007GetCollectionListingParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_collection_listing_with(&params)
+            .await
+    }
+
     /**
      * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -965,16 +1049,15 @@ impl SalesChannels {
      * **Parameters:**
      *
      * * `collection_listing_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_collection_listings_param_listing_product_id(
+    pub async fn deprecated_202007_get_collection_listings_param_listing_product_id_with(
         &self,
         collection_listing_id: &str,
-        limit: &str,
+        params: &SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -987,6 +1070,29 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_collection_listings_param_listing_product_id_with` and pass the query parameters as a `SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_collection_listings_param_listing_product_id_with` \
+                instead"
+    )]
+    pub async fn deprecated_202007_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        let params =
+            SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdParams {
+                limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202007_get_collection_listings_param_listing_product_id_with(
+            collection_listing_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieve a specific collection listing that is published to your app.
      *
@@ -1068,12 +1174,14 @@ impl SalesChannels {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsGetCollectionListingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_collection_listing(&self, limit: &str) -> Result<()> {
+    pub async fn get_collection_listing_with(
+        &self,
+        params: &SalesChannelsGetCollectionListingParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1082,6 +1190,17 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_collection_listing_with` and pass the query parameters as a `SalesChannelsGetCollectionListingParams` instead.
+     */
+    #[deprecated(note = "use `get_collection_listing_with` instead")]
+    pub async fn get_collection_listing(&self, limit: &str) -> Result<()> {
+        let params = SalesChannelsGetCollectionListingParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_collection_listing_with(&params).await
+    }
+
     /**
      * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1092,16 +1211,15 @@ impl SalesChannels {
      * **Parameters:**
      *
      * * `collection_listing_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsGetCollectionListingsParamListingProductIdParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_collection_listings_param_listing_product_id(
+    pub async fn get_collection_listings_param_listing_product_id_with(
         &self,
         collection_listing_id: &str,
-        limit: &str,
+        params: &SalesChannelsGetCollectionListingsParamListingProductIdParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1114,6 +1232,22 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_collection_listings_param_listing_product_id_with` and pass the query parameters as a `SalesChannelsGetCollectionListingsParamListingProductIdParams` instead.
+     */
+    #[deprecated(note = "use `get_collection_listings_param_listing_product_id_with` instead")]
+    pub async fn get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        let params = SalesChannelsGetCollectionListingsParamListingProductIdParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_collection_listings_param_listing_product_id_with(collection_listing_id, &params)
+            .await
+    }
+
     /**
      * Retrieve a specific collection listing that is published to your app.
      *
@@ -1195,12 +1329,14 @@ impl SalesChannels {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsDeprecated202101GetCollectionListingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_collection_listing(&self, limit: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202101GetCollectionListingParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1209,6 +1345,18 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_collection_listing_with` and pass the query parameters as a `SalesChannelsDeprecated202101GetCollectionListingParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_collection_listing_with` instead")]
+    pub async fn deprecated_202101_get_collection_listing(&self, limit: &str) -> Result<()> {
+        let params = SalesChannelsDeprecated202101GetCollectionListingParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_collection_listing_with(&params)
+            .await
+    }
+
     /**
      * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1219,16 +1367,15 @@ impl SalesChannels {
      * **Parameters:**
      *
      * * `collection_listing_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_collection_listings_param_listing_product_id(
+    pub async fn deprecated_202101_get_collection_listings_param_listing_product_id_with(
         &self,
         collection_listing_id: &str,
-        limit: &str,
+        params: &SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1241,6 +1388,29 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_collection_listings_param_listing_product_id_with` and pass the query parameters as a `SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_collection_listings_param_listing_product_id_with` \
+                instead"
+    )]
+    pub async fn deprecated_202101_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        let params =
+            SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdParams {
+                limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202101_get_collection_listings_param_listing_product_id_with(
+            collection_listing_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieve a specific collection listing that is published to your app.
      *
@@ -1322,12 +1492,14 @@ impl SalesChannels {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsDeprecatedUnstableGetCollectionListingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_collection_listing(&self, limit: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecatedUnstableGetCollectionListingParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1336,6 +1508,18 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_collection_listing_with` and pass the query parameters as a `SalesChannelsDeprecatedUnstableGetCollectionListingParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_collection_listing_with` instead")]
+    pub async fn deprecated_unstable_get_collection_listing(&self, limit: &str) -> Result<()> {
+        let params = SalesChannelsDeprecatedUnstableGetCollectionListingParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_collection_listing_with(&params)
+            .await
+    }
+
     /**
      * Retrieve product_ids that are published to a collection_id.       Note: As of version 2019-07, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -1346,16 +1530,15 @@ impl SalesChannels {
      * **Parameters:**
      *
      * * `collection_listing_id: &str` -- storefront_access_token_id.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_collection_listings_param_listing_product_id(
+    pub async fn deprecated_unstable_get_collection_listings_param_listing_product_id_with(
         &self,
         collection_listing_id: &str,
-        limit: &str,
+        params: &SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1368,6 +1551,29 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_collection_listings_param_listing_product_id_with` and pass the query parameters as a `SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_collection_listings_param_listing_product_id_with` \
+                instead"
+    )]
+    pub async fn deprecated_unstable_get_collection_listings_param_listing_product_id(
+        &self,
+        collection_listing_id: &str,
+        limit: &str,
+    ) -> Result<()> {
+        let params =
+            SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdParams {
+                limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_unstable_get_collection_listings_param_listing_product_id_with(
+            collection_listing_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Retrieve a specific collection listing that is published to your app.
      *
@@ -1473,27 +1679,16 @@ impl SalesChannels {
      * **Parameters:**
      *
      * * `token: &str` -- storefront_access_token_id.
-     * * `amount_required: &str` -- The amount of the payment.
-     * * `request_details_required: &str` -- The details of the request, including the following attributes:
-     *                       
-     *                           ip_address: The IP address of the customer.
-     *                           accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
-     *   
-     *                           user_agent: The user agent string for the customer's device.
-     * * `session_required: &str` -- A session ID provided by the card vault when creating a payment session.
-     * * `unique_token_required: &str` -- A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
+     * * `params: &SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_create_checkouts_param_token_payments(
+    pub async fn deprecated_202001_create_checkouts_param_token_payments_with(
         &self,
         token: &str,
-        amount_required: &str,
-        request_details_required: &str,
-        session_id_required: &str,
-        unique_token_required: &str,
+        params: &SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount_required.is_empty() {
+        if let Some(amount_required) = &params.amount_required {
             query_args.push((
                 "amount
                   required"
@@ -1501,7 +1696,7 @@ impl SalesChannels {
                 amount_required.to_string(),
             ));
         }
-        if !request_details_required.is_empty() {
+        if let Some(request_details_required) = &params.request_details_required {
             query_args.push((
                 "request_details
                   required"
@@ -1509,7 +1704,7 @@ impl SalesChannels {
                 request_details_required.to_string(),
             ));
         }
-        if !session_id_required.is_empty() {
+        if let Some(session_id_required) = &params.session_id_required {
             query_args.push((
                 "session_id
                   required"
@@ -1517,7 +1712,7 @@ impl SalesChannels {
                 session_id_required.to_string(),
             ));
         }
-        if !unique_token_required.is_empty() {
+        if let Some(unique_token_required) = &params.unique_token_required {
             query_args.push((
                 "unique_token
                   required"
@@ -1537,6 +1732,33 @@ impl SalesChannels {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_checkouts_param_token_payments_with` and pass the query parameters as a `SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_create_checkouts_param_token_payments_with` instead"
+    )]
+    pub async fn deprecated_202001_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsParams {
+            amount_required: Some(amount_required.to_string()).filter(|s| !s.is_empty()),
+            request_details_required: Some(request_details_required.to_string())
+                .filter(|s| !s.is_empty()),
+            session_id_required: Some(session_id_required.to_string()).filter(|s| !s.is_empty()),
+            unique_token_required: Some(unique_token_required.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_create_checkouts_param_token_payments_with(token, &params, body)
+            .await
+    }
+
     /**
      * Retrieves the payment information for an existing payment.
      *
@@ -1619,27 +1841,16 @@ impl SalesChannels {
      * **Parameters:**
      *
      * * `token: &str` -- storefront_access_token_id.
-     * * `amount_required: &str` -- The amount of the payment.
-     * * `request_details_required: &str` -- The details of the request, including the following attributes:
-     *                       
-     *                           ip_address: The IP address of the customer.
-     *                           accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
-     *   
-     *                           user_agent: The user agent string for the customer's device.
-     * * `session_required: &str` -- A session ID provided by the card vault when creating a payment session.
-     * * `unique_token_required: &str` -- A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
+     * * `params: &SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_create_checkouts_param_token_payments(
+    pub async fn deprecated_202004_create_checkouts_param_token_payments_with(
         &self,
         token: &str,
-        amount_required: &str,
-        request_details_required: &str,
-        session_id_required: &str,
-        unique_token_required: &str,
+        params: &SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount_required.is_empty() {
+        if let Some(amount_required) = &params.amount_required {
             query_args.push((
                 "amount
                   required"
@@ -1647,7 +1858,7 @@ impl SalesChannels {
                 amount_required.to_string(),
             ));
         }
-        if !request_details_required.is_empty() {
+        if let Some(request_details_required) = &params.request_details_required {
             query_args.push((
                 "request_details
                   required"
@@ -1655,7 +1866,7 @@ impl SalesChannels {
                 request_details_required.to_string(),
             ));
         }
-        if !session_id_required.is_empty() {
+        if let Some(session_id_required) = &params.session_id_required {
             query_args.push((
                 "session_id
                   required"
@@ -1663,7 +1874,7 @@ impl SalesChannels {
                 session_id_required.to_string(),
             ));
         }
-        if !unique_token_required.is_empty() {
+        if let Some(unique_token_required) = &params.unique_token_required {
             query_args.push((
                 "unique_token
                   required"
@@ -1683,6 +1894,33 @@ impl SalesChannels {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_checkouts_param_token_payments_with` and pass the query parameters as a `SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_create_checkouts_param_token_payments_with` instead"
+    )]
+    pub async fn deprecated_202004_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsParams {
+            amount_required: Some(amount_required.to_string()).filter(|s| !s.is_empty()),
+            request_details_required: Some(request_details_required.to_string())
+                .filter(|s| !s.is_empty()),
+            session_id_required: Some(session_id_required.to_string()).filter(|s| !s.is_empty()),
+            unique_token_required: Some(unique_token_required.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_create_checkouts_param_token_payments_with(token, &params, body)
+            .await
+    }
+
     /**
      * Retrieves the payment information for an existing payment.
      *
@@ -1765,27 +2003,16 @@ impl SalesChannels {
      * **Parameters:**
      *
      * * `token: &str` -- storefront_access_token_id.
-     * * `amount_required: &str` -- The amount of the payment.
-     * * `request_details_required: &str` -- The details of the request, including the following attributes:
-     *                       
-     *                           ip_address: The IP address of the customer.
-     *                           accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
-     *   
-     *                           user_agent: The user agent string for the customer's device.
-     * * `session_required: &str` -- A session ID provided by the card vault when creating a payment session.
-     * * `unique_token_required: &str` -- A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
+     * * `params: &SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_create_checkouts_param_token_payments(
+    pub async fn deprecated_202007_create_checkouts_param_token_payments_with(
         &self,
         token: &str,
-        amount_required: &str,
-        request_details_required: &str,
-        session_id_required: &str,
-        unique_token_required: &str,
+        params: &SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount_required.is_empty() {
+        if let Some(amount_required) = &params.amount_required {
             query_args.push((
                 "amount
                   required"
@@ -1793,7 +2020,7 @@ impl SalesChannels {
                 amount_required.to_string(),
             ));
         }
-        if !request_details_required.is_empty() {
+        if let Some(request_details_required) = &params.request_details_required {
             query_args.push((
                 "request_details
                   required"
@@ -1801,7 +2028,7 @@ impl SalesChannels {
                 request_details_required.to_string(),
             ));
         }
-        if !session_id_required.is_empty() {
+        if let Some(session_id_required) = &params.session_id_required {
             query_args.push((
                 "session_id
                   required"
@@ -1809,7 +2036,7 @@ impl SalesChannels {
                 session_id_required.to_string(),
             ));
         }
-        if !unique_token_required.is_empty() {
+        if let Some(unique_token_required) = &params.unique_token_required {
             query_args.push((
                 "unique_token
                   required"
@@ -1829,6 +2056,33 @@ impl SalesChannels {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_checkouts_param_token_payments_with` and pass the query parameters as a `SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_create_checkouts_param_token_payments_with` instead"
+    )]
+    pub async fn deprecated_202007_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsParams {
+            amount_required: Some(amount_required.to_string()).filter(|s| !s.is_empty()),
+            request_details_required: Some(request_details_required.to_string())
+                .filter(|s| !s.is_empty()),
+            session_id_required: Some(session_id_required.to_string()).filter(|s| !s.is_empty()),
+            unique_token_required: Some(unique_token_required.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_create_checkouts_param_token_payments_with(token, &params, body)
+            .await
+    }
+
     /**
      * Retrieves the payment information for an existing payment.
      *
@@ -1908,27 +2162,16 @@ impl SalesChannels {
      * **Parameters:**
      *
      * * `token: &str` -- storefront_access_token_id.
-     * * `amount_required: &str` -- The amount of the payment.
-     * * `request_details_required: &str` -- The details of the request, including the following attributes:
-     *                       
-     *                           ip_address: The IP address of the customer.
-     *                           accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
-     *   
-     *                           user_agent: The user agent string for the customer's device.
-     * * `session_required: &str` -- A session ID provided by the card vault when creating a payment session.
-     * * `unique_token_required: &str` -- A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
+     * * `params: &SalesChannelsCreateCheckoutsParamTokenPaymentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create_checkouts_param_token_payments(
+    pub async fn create_checkouts_param_token_payments_with(
         &self,
         token: &str,
-        amount_required: &str,
-        request_details_required: &str,
-        session_id_required: &str,
-        unique_token_required: &str,
+        params: &SalesChannelsCreateCheckoutsParamTokenPaymentsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount_required.is_empty() {
+        if let Some(amount_required) = &params.amount_required {
             query_args.push((
                 "amount
                   required"
@@ -1936,7 +2179,7 @@ impl SalesChannels {
                 amount_required.to_string(),
             ));
         }
-        if !request_details_required.is_empty() {
+        if let Some(request_details_required) = &params.request_details_required {
             query_args.push((
                 "request_details
                   required"
@@ -1944,7 +2187,7 @@ impl SalesChannels {
                 request_details_required.to_string(),
             ));
         }
-        if !session_id_required.is_empty() {
+        if let Some(session_id_required) = &params.session_id_required {
             query_args.push((
                 "session_id
                   required"
@@ -1952,7 +2195,7 @@ impl SalesChannels {
                 session_id_required.to_string(),
             ));
         }
-        if !unique_token_required.is_empty() {
+        if let Some(unique_token_required) = &params.unique_token_required {
             query_args.push((
                 "unique_token
                   required"
@@ -1972,6 +2215,31 @@ impl SalesChannels {
             .await
     }
 
+    /**
+     * Deprecated: use `create_checkouts_param_token_payments_with` and pass the query parameters as a `SalesChannelsCreateCheckoutsParamTokenPaymentsParams` instead.
+     */
+    #[deprecated(note = "use `create_checkouts_param_token_payments_with` instead")]
+    pub async fn create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = SalesChannelsCreateCheckoutsParamTokenPaymentsParams {
+            amount_required: Some(amount_required.to_string()).filter(|s| !s.is_empty()),
+            request_details_required: Some(request_details_required.to_string())
+                .filter(|s| !s.is_empty()),
+            session_id_required: Some(session_id_required.to_string()).filter(|s| !s.is_empty()),
+            unique_token_required: Some(unique_token_required.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.create_checkouts_param_token_payments_with(token, &params, body)
+            .await
+    }
+
     /**
      * Retrieves the payment information for an existing payment.
      *
@@ -2051,27 +2319,16 @@ impl SalesChannels {
      * **Parameters:**
      *
      * * `token: &str` -- storefront_access_token_id.
-     * * `amount_required: &str` -- The amount of the payment.
-     * * `request_details_required: &str` -- The details of the request, including the following attributes:
-     *                       
-     *                           ip_address: The IP address of the customer.
-     *                           accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
-     *   
-     *                           user_agent: The user agent string for the customer's device.
-     * * `session_required: &str` -- A session ID provided by the card vault when creating a payment session.
-     * * `unique_token_required: &str` -- A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
+     * * `params: &SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_create_checkouts_param_token_payments(
+    pub async fn deprecated_202101_create_checkouts_param_token_payments_with(
         &self,
         token: &str,
-        amount_required: &str,
-        request_details_required: &str,
-        session_id_required: &str,
-        unique_token_required: &str,
+        params: &SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount_required.is_empty() {
+        if let Some(amount_required) = &params.amount_required {
             query_args.push((
                 "amount
                   required"
@@ -2079,7 +2336,7 @@ impl SalesChannels {
                 amount_required.to_string(),
             ));
         }
-        if !request_details_required.is_empty() {
+        if let Some(request_details_required) = &params.request_details_required {
             query_args.push((
                 "request_details
                   required"
@@ -2087,7 +2344,7 @@ impl SalesChannels {
                 request_details_required.to_string(),
             ));
         }
-        if !session_id_required.is_empty() {
+        if let Some(session_id_required) = &params.session_id_required {
             query_args.push((
                 "session_id
                   required"
@@ -2095,7 +2352,7 @@ impl SalesChannels {
                 session_id_required.to_string(),
             ));
         }
-        if !unique_token_required.is_empty() {
+        if let Some(unique_token_required) = &params.unique_token_required {
             query_args.push((
                 "unique_token
                   required"
@@ -2115,6 +2372,33 @@ impl SalesChannels {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_checkouts_param_token_payments_with` and pass the query parameters as a `SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_create_checkouts_param_token_payments_with` instead"
+    )]
+    pub async fn deprecated_202101_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsParams {
+            amount_required: Some(amount_required.to_string()).filter(|s| !s.is_empty()),
+            request_details_required: Some(request_details_required.to_string())
+                .filter(|s| !s.is_empty()),
+            session_id_required: Some(session_id_required.to_string()).filter(|s| !s.is_empty()),
+            unique_token_required: Some(unique_token_required.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_create_checkouts_param_token_payments_with(token, &params, body)
+            .await
+    }
+
     /**
      * Retrieves the payment information for an existing payment.
      *
@@ -2197,27 +2481,16 @@ impl SalesChannels {
      * **Parameters:**
      *
      * * `token: &str` -- storefront_access_token_id.
-     * * `amount_required: &str` -- The amount of the payment.
-     * * `request_details_required: &str` -- The details of the request, including the following attributes:
-     *                       
-     *                           ip_address: The IP address of the customer.
-     *                           accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
-     *   
-     *                           user_agent: The user agent string for the customer's device.
-     * * `session_required: &str` -- A session ID provided by the card vault when creating a payment session.
-     * * `unique_token_required: &str` -- A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
+     * * `params: &SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_create_checkouts_param_token_payments(
+    pub async fn deprecated_unstable_create_checkouts_param_token_payments_with(
         &self,
         token: &str,
-        amount_required: &str,
-        request_details_required: &str,
-        session_id_required: &str,
-        unique_token_required: &str,
+        params: &SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !amount_required.is_empty() {
+        if let Some(amount_required) = &params.amount_required {
             query_args.push((
                 "amount
                   required"
@@ -2225,7 +2498,7 @@ impl SalesChannels {
                 amount_required.to_string(),
             ));
         }
-        if !request_details_required.is_empty() {
+        if let Some(request_details_required) = &params.request_details_required {
             query_args.push((
                 "request_details
                   required"
@@ -2233,7 +2506,7 @@ impl SalesChannels {
                 request_details_required.to_string(),
             ));
         }
-        if !session_id_required.is_empty() {
+        if let Some(session_id_required) = &params.session_id_required {
             query_args.push((
                 "session_id
                   required"
@@ -2241,7 +2514,7 @@ impl SalesChannels {
                 session_id_required.to_string(),
             ));
         }
-        if !unique_token_required.is_empty() {
+        if let Some(unique_token_required) = &params.unique_token_required {
             query_args.push((
                 "unique_token
                   required"
@@ -2261,6 +2534,33 @@ impl SalesChannels {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_create_checkouts_param_token_payments_with` and pass the query parameters as a `SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_create_checkouts_param_token_payments_with` instead"
+    )]
+    pub async fn deprecated_unstable_create_checkouts_param_token_payments(
+        &self,
+        token: &str,
+        amount_required: &str,
+        request_details_required: &str,
+        session_id_required: &str,
+        unique_token_required: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsParams {
+            amount_required: Some(amount_required.to_string()).filter(|s| !s.is_empty()),
+            request_details_required: Some(request_details_required.to_string())
+                .filter(|s| !s.is_empty()),
+            session_id_required: Some(session_id_required.to_string()).filter(|s| !s.is_empty()),
+            unique_token_required: Some(unique_token_required.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_create_checkouts_param_token_payments_with(token, &params, body)
+            .await
+    }
+
     /**
      * Retrieves the payment information for an existing payment.
      *
@@ -2319,35 +2619,26 @@ impl SalesChannels {
      *
      * **Parameters:**
      *
-     * * `product_ids: &str` -- A comma-separated list of product ids.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     * * `collection_id: &str` -- Filter by products belonging to a particular collection.
-     * * `updated_at_min: &str` -- Filter by products last updated after a certain date and time (formatted in ISO 8601).
-     * * `handle: &str` -- Filter by product handle.
+     * * `params: &SalesChannelsDeprecated202001GetProductListingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_product_listing(
+    pub async fn deprecated_202001_get_product_listing_with(
         &self,
-        product_ids: &str,
-        limit: &str,
-        collection_id: &str,
-        updated_at_min: &str,
-        handle: &str,
+        params: &SalesChannelsDeprecated202001GetProductListingParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_ids.is_empty() {
+        if let Some(product_ids) = &params.product_ids {
             query_args.push(("product_ids".to_string(), product_ids.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2356,6 +2647,29 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_product_listing_with` and pass the query parameters as a `SalesChannelsDeprecated202001GetProductListingParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_product_listing_with` instead")]
+    pub async fn deprecated_202001_get_product_listing(
+        &self,
+        product_ids: &str,
+        limit: &str,
+        collection_id: &str,
+        updated_at_min: &str,
+        handle: &str,
+    ) -> Result<()> {
+        let params = SalesChannelsDeprecated202001GetProductListingParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_ids: Some(product_ids.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_product_listing_with(&params)
+            .await
+    }
+
     /**
      * Retrieve product_ids that are published to your app. Maximum 1,000 results per page.
      *
@@ -2365,12 +2679,14 @@ impl SalesChannels {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsDeprecated202001GetProductListingsIdParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_product_listings_id(&self, limit: &str) -> Result<()> {
-        let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+    pub async fn deprecated_202001_get_product_listings_id_with(
+        &self,
+        params: &SalesChannelsDeprecated202001GetProductListingsIdParams,
+    ) -> Result<()> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2382,6 +2698,18 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_product_listings_id_with` and pass the query parameters as a `SalesChannelsDeprecated202001GetProductListingsIdParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_product_listings_id_with` instead")]
+    pub async fn deprecated_202001_get_product_listings_id(&self, limit: &str) -> Result<()> {
+        let params = SalesChannelsDeprecated202001GetProductListingsIdParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_product_listings_id_with(&params)
+            .await
+    }
+
     /**
      * Retrieve a count of products that are published to your app.
      *
@@ -2475,35 +2803,26 @@ impl SalesChannels {
      *
      * **Parameters:**
      *
-     * * `product_ids: &str` -- A comma-separated list of product ids.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     * * `collection_id: &str` -- Filter by products belonging to a particular collection.
-     * * `updated_at_min: &str` -- Filter by products last updated after a certain date and time (formatted in ISO 8601).
-     * * `handle: &str` -- Filter by product handle.
+     * * `params: &SalesChannelsDeprecated202004GetProductListingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_product_listing(
+    pub async fn deprecated_202004_get_product_listing_with(
         &self,
-        product_ids: &str,
-        limit: &str,
-        collection_id: &str,
-        updated_at_min: &str,
-        handle: &str,
+        params: &SalesChannelsDeprecated202004GetProductListingParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_ids.is_empty() {
+        if let Some(product_ids) = &params.product_ids {
             query_args.push(("product_ids".to_string(), product_ids.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2512,6 +2831,29 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_product_listing_with` and pass the query parameters as a `SalesChannelsDeprecated202004GetProductListingParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_product_listing_with` instead")]
+    pub async fn deprecated_202004_get_product_listing(
+        &self,
+        product_ids: &str,
+        limit: &str,
+        collection_id: &str,
+        updated_at_min: &str,
+        handle: &str,
+    ) -> Result<()> {
+        let params = SalesChannelsDeprecated202004GetProductListingParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_ids: Some(product_ids.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_product_listing_with(&params)
+            .await
+    }
+
     /**
      * Retrieve product_ids that are published to your app. Maximum 1,000 results per page.
      *
@@ -2521,12 +2863,14 @@ impl SalesChannels {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsDeprecated202004GetProductListingsIdParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_product_listings_id(&self, limit: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_product_listings_id_with(
+        &self,
+        params: &SalesChannelsDeprecated202004GetProductListingsIdParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2538,6 +2882,18 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_product_listings_id_with` and pass the query parameters as a `SalesChannelsDeprecated202004GetProductListingsIdParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_product_listings_id_with` instead")]
+    pub async fn deprecated_202004_get_product_listings_id(&self, limit: &str) -> Result<()> {
+        let params = SalesChannelsDeprecated202004GetProductListingsIdParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_product_listings_id_with(&params)
+            .await
+    }
+
     /**
      * Retrieve a count of products that are published to your app.
      *
@@ -2631,35 +2987,26 @@ impl SalesChannels {
      *
      * **Parameters:**
      *
-     * * `product_ids: &str` -- A comma-separated list of product ids.
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
-     * * `collection_id: &str` -- Filter by products belonging to a particular collection.
-     * * `updated_at_min: &str` -- Filter by products last updated after a certain date and time (formatted in ISO 8601).
-     * * `handle: &str` -- Filter by product handle.
+     * * `params: &SalesChannelsDeprecated202007GetProductListingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_product_listing(
+    pub async fn deprecated_202007_get_product_listing_with(
         &self,
-        product_ids: &str,
-        limit: &str,
-        collection_id: &str,
-        updated_at_min: &str,
-        handle: &str,
+        params: &SalesChannelsDeprecated202007GetProductListingParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_id.is_empty() {
+        if let Some(collection_id) = &params.collection_id {
             query_args.push(("collection_id".to_string(), collection_id.to_string()));
         }
-        if !handle.is_empty() {
+        if let Some(handle) = &params.handle {
             query_args.push(("handle".to_string(), handle.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product_ids.is_empty() {
+        if let Some(product_ids) = &params.product_ids {
             query_args.push(("product_ids".to_string(), product_ids.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2668,6 +3015,29 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_product_listing_with` and pass the query parameters as a `SalesChannelsDeprecated202007GetProductListingParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_product_listing_with` instead")]
+    pub async fn deprecated_202007_get_product_listing(
+        &self,
+        product_ids: &str,
+        limit: &str,
+        collection_id: &str,
+        updated_at_min: &str,
+        handle: &str,
+    ) -> Result<()> {
+        let params = SalesChannelsDeprecated202007GetProductListingParams {
+            collection_id: Some(collection_id.to_string()).filter(|s| !s.is_empty()),
+            handle: Some(handle.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            product_ids: Some(product_ids.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_product_listing_with(&params)
+            .await
+    }
+
     /**
      * Retrieve product_ids that are published to your app. Maximum 1,000 results per page.
      *
@@ -2677,12 +3047,14 @@ impl SalesChannels {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- Amount of results
-     *                     (default: 50, maximum: 1000).
+     * * `params: &SalesChannelsDeprecated202007GetProductListingsIdParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_product_listings_id(&self, limit: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_product_listings_id_with(
+        &self,
+        params: &SalesChannelsDeprecated202007GetProductListingsIdParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2694,6 +3066,18 @@ impl SalesChannels {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_product_listings_id_with` and pass the query parameters as a `SalesChannelsDeprecated202007GetProductListingsIdParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_product_listings_id_with` instead")]
+    pub async fn deprecated_202007_get_product_listings_id(&self, limit: &str) -> Result<()> {
+        let params = SalesChannelsDeprecated202007GetProductListingsIdParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_product_listings_id_with(&params)
+            .await
+    }
+
     /**
      * Retrieve a count of products that are published to your app.
      *
@@ -2778,3 +3162,2056 @@ impl SalesChannels {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `SalesChannels::deprecated_202001_get_collection_listing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202001GetCollectionListingParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsDeprecated202001GetCollectionListingParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202001_get_collection_listings_param_listing_product_id_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202004_get_collection_listing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202004GetCollectionListingParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsDeprecated202004GetCollectionListingParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202004_get_collection_listings_param_listing_product_id_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202007_get_collection_listing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202007GetCollectionListingParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsDeprecated202007GetCollectionListingParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202007_get_collection_listings_param_listing_product_id_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::get_collection_listing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsGetCollectionListingParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsGetCollectionListingParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::get_collection_listings_param_listing_product_id_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsGetCollectionListingsParamListingProductIdParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsGetCollectionListingsParamListingProductIdParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202101_get_collection_listing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202101GetCollectionListingParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsDeprecated202101GetCollectionListingParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202101_get_collection_listings_param_listing_product_id_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_unstable_get_collection_listing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecatedUnstableGetCollectionListingParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsDeprecatedUnstableGetCollectionListingParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_unstable_get_collection_listings_param_listing_product_id_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202001_create_checkouts_param_token_payments_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsParams {
+    /// The amount of the payment.
+    pub amount_required: Option<String>,
+    /// The details of the request, including the following attributes:
+    ///
+    /// ip_address: The IP address of the customer.
+    /// accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
+    ///
+    /// user_agent: The user agent string for the customer's device.
+    pub request_details_required: Option<String>,
+    pub session_id_required: Option<String>,
+    /// A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
+    pub unique_token_required: Option<String>,
+}
+
+impl SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsParams {
+    pub fn amount_required<T: ToString>(mut self, amount_required: T) -> Self {
+        self.amount_required = Some(amount_required.to_string());
+        self
+    }
+
+    pub fn request_details_required<T: ToString>(mut self, request_details_required: T) -> Self {
+        self.request_details_required = Some(request_details_required.to_string());
+        self
+    }
+
+    pub fn session_id_required<T: ToString>(mut self, session_id_required: T) -> Self {
+        self.session_id_required = Some(session_id_required.to_string());
+        self
+    }
+
+    pub fn unique_token_required<T: ToString>(mut self, unique_token_required: T) -> Self {
+        self.unique_token_required = Some(unique_token_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202004_create_checkouts_param_token_payments_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsParams {
+    /// The amount of the payment.
+    pub amount_required: Option<String>,
+    /// The details of the request, including the following attributes:
+    ///
+    /// ip_address: The IP address of the customer.
+    /// accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
+    ///
+    /// user_agent: The user agent string for the customer's device.
+    pub request_details_required: Option<String>,
+    pub session_id_required: Option<String>,
+    /// A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
+    pub unique_token_required: Option<String>,
+}
+
+impl SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsParams {
+    pub fn amount_required<T: ToString>(mut self, amount_required: T) -> Self {
+        self.amount_required = Some(amount_required.to_string());
+        self
+    }
+
+    pub fn request_details_required<T: ToString>(mut self, request_details_required: T) -> Self {
+        self.request_details_required = Some(request_details_required.to_string());
+        self
+    }
+
+    pub fn session_id_required<T: ToString>(mut self, session_id_required: T) -> Self {
+        self.session_id_required = Some(session_id_required.to_string());
+        self
+    }
+
+    pub fn unique_token_required<T: ToString>(mut self, unique_token_required: T) -> Self {
+        self.unique_token_required = Some(unique_token_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202007_create_checkouts_param_token_payments_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsParams {
+    /// The amount of the payment.
+    pub amount_required: Option<String>,
+    /// The details of the request, including the following attributes:
+    ///
+    /// ip_address: The IP address of the customer.
+    /// accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
+    ///
+    /// user_agent: The user agent string for the customer's device.
+    pub request_details_required: Option<String>,
+    pub session_id_required: Option<String>,
+    /// A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
+    pub unique_token_required: Option<String>,
+}
+
+impl SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsParams {
+    pub fn amount_required<T: ToString>(mut self, amount_required: T) -> Self {
+        self.amount_required = Some(amount_required.to_string());
+        self
+    }
+
+    pub fn request_details_required<T: ToString>(mut self, request_details_required: T) -> Self {
+        self.request_details_required = Some(request_details_required.to_string());
+        self
+    }
+
+    pub fn session_id_required<T: ToString>(mut self, session_id_required: T) -> Self {
+        self.session_id_required = Some(session_id_required.to_string());
+        self
+    }
+
+    pub fn unique_token_required<T: ToString>(mut self, unique_token_required: T) -> Self {
+        self.unique_token_required = Some(unique_token_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::create_checkouts_param_token_payments_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsCreateCheckoutsParamTokenPaymentsParams {
+    /// The amount of the payment.
+    pub amount_required: Option<String>,
+    /// The details of the request, including the following attributes:
+    ///
+    /// ip_address: The IP address of the customer.
+    /// accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
+    ///
+    /// user_agent: The user agent string for the customer's device.
+    pub request_details_required: Option<String>,
+    pub session_id_required: Option<String>,
+    /// A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
+    pub unique_token_required: Option<String>,
+}
+
+impl SalesChannelsCreateCheckoutsParamTokenPaymentsParams {
+    pub fn amount_required<T: ToString>(mut self, amount_required: T) -> Self {
+        self.amount_required = Some(amount_required.to_string());
+        self
+    }
+
+    pub fn request_details_required<T: ToString>(mut self, request_details_required: T) -> Self {
+        self.request_details_required = Some(request_details_required.to_string());
+        self
+    }
+
+    pub fn session_id_required<T: ToString>(mut self, session_id_required: T) -> Self {
+        self.session_id_required = Some(session_id_required.to_string());
+        self
+    }
+
+    pub fn unique_token_required<T: ToString>(mut self, unique_token_required: T) -> Self {
+        self.unique_token_required = Some(unique_token_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202101_create_checkouts_param_token_payments_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsParams {
+    /// The amount of the payment.
+    pub amount_required: Option<String>,
+    /// The details of the request, including the following attributes:
+    ///
+    /// ip_address: The IP address of the customer.
+    /// accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
+    ///
+    /// user_agent: The user agent string for the customer's device.
+    pub request_details_required: Option<String>,
+    pub session_id_required: Option<String>,
+    /// A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
+    pub unique_token_required: Option<String>,
+}
+
+impl SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsParams {
+    pub fn amount_required<T: ToString>(mut self, amount_required: T) -> Self {
+        self.amount_required = Some(amount_required.to_string());
+        self
+    }
+
+    pub fn request_details_required<T: ToString>(mut self, request_details_required: T) -> Self {
+        self.request_details_required = Some(request_details_required.to_string());
+        self
+    }
+
+    pub fn session_id_required<T: ToString>(mut self, session_id_required: T) -> Self {
+        self.session_id_required = Some(session_id_required.to_string());
+        self
+    }
+
+    pub fn unique_token_required<T: ToString>(mut self, unique_token_required: T) -> Self {
+        self.unique_token_required = Some(unique_token_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_unstable_create_checkouts_param_token_payments_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsParams {
+    /// The amount of the payment.
+    pub amount_required: Option<String>,
+    /// The details of the request, including the following attributes:
+    ///
+    /// ip_address: The IP address of the customer.
+    /// accept_language: The language preferences of the customer, in the same format as a standard Accept-Language request header.
+    ///
+    /// user_agent: The user agent string for the customer's device.
+    pub request_details_required: Option<String>,
+    pub session_id_required: Option<String>,
+    /// A unique idempotency token generated by your app. This can be any value, but must be unique across all payment requests.
+    pub unique_token_required: Option<String>,
+}
+
+impl SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsParams {
+    pub fn amount_required<T: ToString>(mut self, amount_required: T) -> Self {
+        self.amount_required = Some(amount_required.to_string());
+        self
+    }
+
+    pub fn request_details_required<T: ToString>(mut self, request_details_required: T) -> Self {
+        self.request_details_required = Some(request_details_required.to_string());
+        self
+    }
+
+    pub fn session_id_required<T: ToString>(mut self, session_id_required: T) -> Self {
+        self.session_id_required = Some(session_id_required.to_string());
+        self
+    }
+
+    pub fn unique_token_required<T: ToString>(mut self, unique_token_required: T) -> Self {
+        self.unique_token_required = Some(unique_token_required.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202001_get_product_listing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202001GetProductListingParams {
+    /// Filter by products belonging to a particular collection.
+    pub collection_id: Option<String>,
+    /// Filter by product handle.
+    pub handle: Option<String>,
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+    /// A comma-separated list of product ids.
+    pub product_ids: Option<String>,
+    /// Filter by products last updated after a certain date and time (formatted in ISO 8601).
+    pub updated_at_min: Option<String>,
+}
+
+impl SalesChannelsDeprecated202001GetProductListingParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_ids<T: ToString>(mut self, product_ids: T) -> Self {
+        self.product_ids = Some(product_ids.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202001_get_product_listings_id_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202001GetProductListingsIdParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsDeprecated202001GetProductListingsIdParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202004_get_product_listing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202004GetProductListingParams {
+    /// Filter by products belonging to a particular collection.
+    pub collection_id: Option<String>,
+    /// Filter by product handle.
+    pub handle: Option<String>,
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+    /// A comma-separated list of product ids.
+    pub product_ids: Option<String>,
+    /// Filter by products last updated after a certain date and time (formatted in ISO 8601).
+    pub updated_at_min: Option<String>,
+}
+
+impl SalesChannelsDeprecated202004GetProductListingParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_ids<T: ToString>(mut self, product_ids: T) -> Self {
+        self.product_ids = Some(product_ids.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202004_get_product_listings_id_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202004GetProductListingsIdParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsDeprecated202004GetProductListingsIdParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202007_get_product_listing_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202007GetProductListingParams {
+    /// Filter by products belonging to a particular collection.
+    pub collection_id: Option<String>,
+    /// Filter by product handle.
+    pub handle: Option<String>,
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+    /// A comma-separated list of product ids.
+    pub product_ids: Option<String>,
+    /// Filter by products last updated after a certain date and time (formatted in ISO 8601).
+    pub updated_at_min: Option<String>,
+}
+
+impl SalesChannelsDeprecated202007GetProductListingParams {
+    pub fn collection_id<T: ToString>(mut self, collection_id: T) -> Self {
+        self.collection_id = Some(collection_id.to_string());
+        self
+    }
+
+    pub fn handle<T: ToString>(mut self, handle: T) -> Self {
+        self.handle = Some(handle.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn product_ids<T: ToString>(mut self, product_ids: T) -> Self {
+        self.product_ids = Some(product_ids.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SalesChannels::deprecated_202007_get_product_listings_id_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SalesChannelsDeprecated202007GetProductListingsIdParams {
+    /// Amount of results
+    /// (default: 50, maximum: 1000).
+    pub limit: Option<String>,
+}
+
+impl SalesChannelsDeprecated202007GetProductListingsIdParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+}
+
+/// The functions of [`SalesChannels`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSalesChannelsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SalesChannelsApi: Send + Sync {
+    /// See [`SalesChannels::deprecated_202001_create_checkouts`].
+    async fn deprecated_202001_create_checkouts(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_create_checkouts`].
+    async fn deprecated_202004_create_checkouts(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_create_checkouts`].
+    async fn deprecated_202007_create_checkouts(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`SalesChannels::create_checkouts`].
+    async fn create_checkouts(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_create_checkouts`].
+    async fn deprecated_202101_create_checkouts(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_create_checkouts`].
+    async fn deprecated_unstable_create_checkouts(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_create_checkouts_param_token_complete`].
+    async fn deprecated_202001_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_get_checkouts_param_token`].
+    async fn deprecated_202001_get_checkouts_param_token(&self, token: &str) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_update_checkouts_param_token`].
+    async fn deprecated_202001_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_get_checkouts_param_token_shipping_rate`].
+    async fn deprecated_202001_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_create_checkouts_param_token_complete`].
+    async fn deprecated_202004_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_get_checkouts_param_token`].
+    async fn deprecated_202004_get_checkouts_param_token(&self, token: &str) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_update_checkouts_param_token`].
+    async fn deprecated_202004_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_get_checkouts_param_token_shipping_rate`].
+    async fn deprecated_202004_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_create_checkouts_param_token_complete`].
+    async fn deprecated_202007_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_get_checkouts_param_token`].
+    async fn deprecated_202007_get_checkouts_param_token(&self, token: &str) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_update_checkouts_param_token`].
+    async fn deprecated_202007_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_get_checkouts_param_token_shipping_rate`].
+    async fn deprecated_202007_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::create_checkouts_param_token_complete`].
+    async fn create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::get_checkouts_param_token`].
+    async fn get_checkouts_param_token(&self, token: &str) -> Result<()>;
+
+    /// See [`SalesChannels::update_checkouts_param_token`].
+    async fn update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::get_checkouts_param_token_shipping_rate`].
+    async fn get_checkouts_param_token_shipping_rate(&self, token: &str) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_create_checkouts_param_token_complete`].
+    async fn deprecated_202101_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_get_checkouts_param_token`].
+    async fn deprecated_202101_get_checkouts_param_token(&self, token: &str) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_update_checkouts_param_token`].
+    async fn deprecated_202101_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_get_checkouts_param_token_shipping_rate`].
+    async fn deprecated_202101_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_create_checkouts_param_token_complete`].
+    async fn deprecated_unstable_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_get_checkouts_param_token`].
+    async fn deprecated_unstable_get_checkouts_param_token(&self, token: &str) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_update_checkouts_param_token`].
+    async fn deprecated_unstable_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_get_checkouts_param_token_shipping_rate`].
+    async fn deprecated_unstable_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_get_collection_listing_with`].
+    async fn deprecated_202001_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202001GetCollectionListingParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_get_collection_listings_param_listing_product_id_with`].
+    async fn deprecated_202001_get_collection_listings_param_listing_product_id_with(
+        &self,
+        collection_listing_id: &str,
+        params: &SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_get_collection_listings_param_listing`].
+    async fn deprecated_202001_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_update_collection_listings_param_listing`].
+    async fn deprecated_202001_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_delete_collection_listings_param_listing`].
+    async fn deprecated_202001_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_get_collection_listing_with`].
+    async fn deprecated_202004_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202004GetCollectionListingParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_get_collection_listings_param_listing_product_id_with`].
+    async fn deprecated_202004_get_collection_listings_param_listing_product_id_with(
+        &self,
+        collection_listing_id: &str,
+        params: &SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_get_collection_listings_param_listing`].
+    async fn deprecated_202004_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_update_collection_listings_param_listing`].
+    async fn deprecated_202004_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_delete_collection_listings_param_listing`].
+    async fn deprecated_202004_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_get_collection_listing_with`].
+    async fn deprecated_202007_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202007GetCollectionListingParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_get_collection_listings_param_listing_product_id_with`].
+    async fn deprecated_202007_get_collection_listings_param_listing_product_id_with(
+        &self,
+        collection_listing_id: &str,
+        params: &SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_get_collection_listings_param_listing`].
+    async fn deprecated_202007_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_update_collection_listings_param_listing`].
+    async fn deprecated_202007_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_delete_collection_listings_param_listing`].
+    async fn deprecated_202007_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::get_collection_listing_with`].
+    async fn get_collection_listing_with(
+        &self,
+        params: &SalesChannelsGetCollectionListingParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::get_collection_listings_param_listing_product_id_with`].
+    async fn get_collection_listings_param_listing_product_id_with(
+        &self,
+        collection_listing_id: &str,
+        params: &SalesChannelsGetCollectionListingsParamListingProductIdParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::get_collection_listings_param_listing`].
+    async fn get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::update_collection_listings_param_listing`].
+    async fn update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::delete_collection_listings_param_listing`].
+    async fn delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_get_collection_listing_with`].
+    async fn deprecated_202101_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202101GetCollectionListingParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_get_collection_listings_param_listing_product_id_with`].
+    async fn deprecated_202101_get_collection_listings_param_listing_product_id_with(
+        &self,
+        collection_listing_id: &str,
+        params: &SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_get_collection_listings_param_listing`].
+    async fn deprecated_202101_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_update_collection_listings_param_listing`].
+    async fn deprecated_202101_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_delete_collection_listings_param_listing`].
+    async fn deprecated_202101_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_get_collection_listing_with`].
+    async fn deprecated_unstable_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecatedUnstableGetCollectionListingParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_get_collection_listings_param_listing_product_id_with`].
+    async fn deprecated_unstable_get_collection_listings_param_listing_product_id_with(
+        &self,
+        collection_listing_id: &str,
+        params: &SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_get_collection_listings_param_listing`].
+    async fn deprecated_unstable_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_update_collection_listings_param_listing`].
+    async fn deprecated_unstable_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_delete_collection_listings_param_listing`].
+    async fn deprecated_unstable_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_get_checkouts_param_token_payment`].
+    async fn deprecated_202001_get_checkouts_param_token_payment(&self, token: &str) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_create_checkouts_param_token_payments_with`].
+    async fn deprecated_202001_create_checkouts_param_token_payments_with(
+        &self,
+        token: &str,
+        params: &SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_get_checkouts_param_token_payments_payment`].
+    async fn deprecated_202001_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_get_checkouts_param_token_payments_count`].
+    async fn deprecated_202001_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_get_checkouts_param_token_payment`].
+    async fn deprecated_202004_get_checkouts_param_token_payment(&self, token: &str) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_create_checkouts_param_token_payments_with`].
+    async fn deprecated_202004_create_checkouts_param_token_payments_with(
+        &self,
+        token: &str,
+        params: &SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_get_checkouts_param_token_payments_payment`].
+    async fn deprecated_202004_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_get_checkouts_param_token_payments_count`].
+    async fn deprecated_202004_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_get_checkouts_param_token_payment`].
+    async fn deprecated_202007_get_checkouts_param_token_payment(&self, token: &str) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_create_checkouts_param_token_payments_with`].
+    async fn deprecated_202007_create_checkouts_param_token_payments_with(
+        &self,
+        token: &str,
+        params: &SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_get_checkouts_param_token_payments_payment`].
+    async fn deprecated_202007_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_get_checkouts_param_token_payments_count`].
+    async fn deprecated_202007_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::get_checkouts_param_token_payment`].
+    async fn get_checkouts_param_token_payment(&self, token: &str) -> Result<()>;
+
+    /// See [`SalesChannels::create_checkouts_param_token_payments_with`].
+    async fn create_checkouts_param_token_payments_with(
+        &self,
+        token: &str,
+        params: &SalesChannelsCreateCheckoutsParamTokenPaymentsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::get_checkouts_param_token_payments_payment`].
+    async fn get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::get_checkouts_param_token_payments_count`].
+    async fn get_checkouts_param_token_payments_count(&self, token: &str) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_get_checkouts_param_token_payment`].
+    async fn deprecated_202101_get_checkouts_param_token_payment(&self, token: &str) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_create_checkouts_param_token_payments_with`].
+    async fn deprecated_202101_create_checkouts_param_token_payments_with(
+        &self,
+        token: &str,
+        params: &SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_get_checkouts_param_token_payments_payment`].
+    async fn deprecated_202101_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202101_get_checkouts_param_token_payments_count`].
+    async fn deprecated_202101_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_get_checkouts_param_token_payment`].
+    async fn deprecated_unstable_get_checkouts_param_token_payment(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_create_checkouts_param_token_payments_with`].
+    async fn deprecated_unstable_create_checkouts_param_token_payments_with(
+        &self,
+        token: &str,
+        params: &SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_get_checkouts_param_token_payments_payment`].
+    async fn deprecated_unstable_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_unstable_get_checkouts_param_token_payments_count`].
+    async fn deprecated_unstable_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_get_product_listing_with`].
+    async fn deprecated_202001_get_product_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202001GetProductListingParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_get_product_listings_id_with`].
+    async fn deprecated_202001_get_product_listings_id_with(
+        &self,
+        params: &SalesChannelsDeprecated202001GetProductListingsIdParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_get_product_listings_count`].
+    async fn deprecated_202001_get_product_listings_count(&self) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_get_product_listings_param_listing`].
+    async fn deprecated_202001_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_update_product_listings_param_listing`].
+    async fn deprecated_202001_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202001_delete_product_listings_param_listing`].
+    async fn deprecated_202001_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_get_product_listing_with`].
+    async fn deprecated_202004_get_product_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202004GetProductListingParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_get_product_listings_id_with`].
+    async fn deprecated_202004_get_product_listings_id_with(
+        &self,
+        params: &SalesChannelsDeprecated202004GetProductListingsIdParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_get_product_listings_count`].
+    async fn deprecated_202004_get_product_listings_count(&self) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_get_product_listings_param_listing`].
+    async fn deprecated_202004_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_update_product_listings_param_listing`].
+    async fn deprecated_202004_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202004_delete_product_listings_param_listing`].
+    async fn deprecated_202004_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_get_product_listing_with`].
+    async fn deprecated_202007_get_product_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202007GetProductListingParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_get_product_listings_id_with`].
+    async fn deprecated_202007_get_product_listings_id_with(
+        &self,
+        params: &SalesChannelsDeprecated202007GetProductListingsIdParams,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_get_product_listings_count`].
+    async fn deprecated_202007_get_product_listings_count(&self) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_get_product_listings_param_listing`].
+    async fn deprecated_202007_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_update_product_listings_param_listing`].
+    async fn deprecated_202007_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`SalesChannels::deprecated_202007_delete_product_listings_param_listing`].
+    async fn deprecated_202007_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl SalesChannelsApi for SalesChannels {
+    async fn deprecated_202001_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
+        SalesChannels::deprecated_202001_create_checkouts(self, body).await
+    }
+
+    async fn deprecated_202004_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
+        SalesChannels::deprecated_202004_create_checkouts(self, body).await
+    }
+
+    async fn deprecated_202007_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
+        SalesChannels::deprecated_202007_create_checkouts(self, body).await
+    }
+
+    async fn create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
+        SalesChannels::create_checkouts(self, body).await
+    }
+
+    async fn deprecated_202101_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
+        SalesChannels::deprecated_202101_create_checkouts(self, body).await
+    }
+
+    async fn deprecated_unstable_create_checkouts(&self, body: &serde_json::Value) -> Result<()> {
+        SalesChannels::deprecated_unstable_create_checkouts(self, body).await
+    }
+
+    async fn deprecated_202001_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_create_checkouts_param_token_complete(self, token, body)
+            .await
+    }
+
+    async fn deprecated_202001_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202001_get_checkouts_param_token(self, token).await
+    }
+
+    async fn deprecated_202001_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_update_checkouts_param_token(self, token, body).await
+    }
+
+    async fn deprecated_202001_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_checkouts_param_token_shipping_rate(self, token).await
+    }
+
+    async fn deprecated_202004_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_create_checkouts_param_token_complete(self, token, body)
+            .await
+    }
+
+    async fn deprecated_202004_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202004_get_checkouts_param_token(self, token).await
+    }
+
+    async fn deprecated_202004_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_update_checkouts_param_token(self, token, body).await
+    }
+
+    async fn deprecated_202004_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_checkouts_param_token_shipping_rate(self, token).await
+    }
+
+    async fn deprecated_202007_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_create_checkouts_param_token_complete(self, token, body)
+            .await
+    }
+
+    async fn deprecated_202007_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202007_get_checkouts_param_token(self, token).await
+    }
+
+    async fn deprecated_202007_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_update_checkouts_param_token(self, token, body).await
+    }
+
+    async fn deprecated_202007_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_checkouts_param_token_shipping_rate(self, token).await
+    }
+
+    async fn create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::create_checkouts_param_token_complete(self, token, body).await
+    }
+
+    async fn get_checkouts_param_token(&self, token: &str) -> Result<()> {
+        SalesChannels::get_checkouts_param_token(self, token).await
+    }
+
+    async fn update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::update_checkouts_param_token(self, token, body).await
+    }
+
+    async fn get_checkouts_param_token_shipping_rate(&self, token: &str) -> Result<()> {
+        SalesChannels::get_checkouts_param_token_shipping_rate(self, token).await
+    }
+
+    async fn deprecated_202101_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_create_checkouts_param_token_complete(self, token, body)
+            .await
+    }
+
+    async fn deprecated_202101_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202101_get_checkouts_param_token(self, token).await
+    }
+
+    async fn deprecated_202101_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_update_checkouts_param_token(self, token, body).await
+    }
+
+    async fn deprecated_202101_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_get_checkouts_param_token_shipping_rate(self, token).await
+    }
+
+    async fn deprecated_unstable_create_checkouts_param_token_complete(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_create_checkouts_param_token_complete(self, token, body)
+            .await
+    }
+
+    async fn deprecated_unstable_get_checkouts_param_token(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_checkouts_param_token(self, token).await
+    }
+
+    async fn deprecated_unstable_update_checkouts_param_token(
+        &self,
+        token: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_update_checkouts_param_token(self, token, body).await
+    }
+
+    async fn deprecated_unstable_get_checkouts_param_token_shipping_rate(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_checkouts_param_token_shipping_rate(self, token)
+            .await
+    }
+
+    async fn deprecated_202001_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202001GetCollectionListingParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_collection_listing_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_collection_listings_param_listing_product_id_with(
+        &self,
+        collection_listing_id: &str,
+        params: &SalesChannelsDeprecated202001GetCollectionListingsParamListingProductIdParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_collection_listings_param_listing_product_id_with(
+            self,
+            collection_listing_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_update_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_delete_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202004GetCollectionListingParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_collection_listing_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_collection_listings_param_listing_product_id_with(
+        &self,
+        collection_listing_id: &str,
+        params: &SalesChannelsDeprecated202004GetCollectionListingsParamListingProductIdParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_collection_listings_param_listing_product_id_with(
+            self,
+            collection_listing_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_update_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_delete_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202007GetCollectionListingParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_collection_listing_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_collection_listings_param_listing_product_id_with(
+        &self,
+        collection_listing_id: &str,
+        params: &SalesChannelsDeprecated202007GetCollectionListingsParamListingProductIdParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_collection_listings_param_listing_product_id_with(
+            self,
+            collection_listing_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_update_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_delete_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+
+    async fn get_collection_listing_with(
+        &self,
+        params: &SalesChannelsGetCollectionListingParams,
+    ) -> Result<()> {
+        SalesChannels::get_collection_listing_with(self, params).await
+    }
+
+    async fn get_collection_listings_param_listing_product_id_with(
+        &self,
+        collection_listing_id: &str,
+        params: &SalesChannelsGetCollectionListingsParamListingProductIdParams,
+    ) -> Result<()> {
+        SalesChannels::get_collection_listings_param_listing_product_id_with(
+            self,
+            collection_listing_id,
+            params,
+        )
+        .await
+    }
+
+    async fn get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::get_collection_listings_param_listing(self, collection_listing_id).await
+    }
+
+    async fn update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::update_collection_listings_param_listing(self, collection_listing_id, body)
+            .await
+    }
+
+    async fn delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::delete_collection_listings_param_listing(self, collection_listing_id).await
+    }
+
+    async fn deprecated_202101_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202101GetCollectionListingParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_get_collection_listing_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_collection_listings_param_listing_product_id_with(
+        &self,
+        collection_listing_id: &str,
+        params: &SalesChannelsDeprecated202101GetCollectionListingsParamListingProductIdParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_get_collection_listings_param_listing_product_id_with(
+            self,
+            collection_listing_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_get_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_update_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_delete_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_collection_listing_with(
+        &self,
+        params: &SalesChannelsDeprecatedUnstableGetCollectionListingParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_collection_listing_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_collection_listings_param_listing_product_id_with(
+        &self,
+        collection_listing_id: &str,
+        params: &SalesChannelsDeprecatedUnstableGetCollectionListingsParamListingProductIdParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_collection_listings_param_listing_product_id_with(
+            self,
+            collection_listing_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_update_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_delete_collection_listings_param_listing(
+        &self,
+        collection_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_delete_collection_listings_param_listing(
+            self,
+            collection_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_checkouts_param_token_payment(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202001_get_checkouts_param_token_payment(self, token).await
+    }
+
+    async fn deprecated_202001_create_checkouts_param_token_payments_with(
+        &self,
+        token: &str,
+        params: &SalesChannelsDeprecated202001CreateCheckoutsParamTokenPaymentsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_create_checkouts_param_token_payments_with(
+            self, token, params, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_checkouts_param_token_payments_payment(
+            self, token, payment_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_checkouts_param_token_payments_count(self, token).await
+    }
+
+    async fn deprecated_202004_get_checkouts_param_token_payment(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202004_get_checkouts_param_token_payment(self, token).await
+    }
+
+    async fn deprecated_202004_create_checkouts_param_token_payments_with(
+        &self,
+        token: &str,
+        params: &SalesChannelsDeprecated202004CreateCheckoutsParamTokenPaymentsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_create_checkouts_param_token_payments_with(
+            self, token, params, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_checkouts_param_token_payments_payment(
+            self, token, payment_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_checkouts_param_token_payments_count(self, token).await
+    }
+
+    async fn deprecated_202007_get_checkouts_param_token_payment(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202007_get_checkouts_param_token_payment(self, token).await
+    }
+
+    async fn deprecated_202007_create_checkouts_param_token_payments_with(
+        &self,
+        token: &str,
+        params: &SalesChannelsDeprecated202007CreateCheckoutsParamTokenPaymentsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_create_checkouts_param_token_payments_with(
+            self, token, params, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_checkouts_param_token_payments_payment(
+            self, token, payment_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_checkouts_param_token_payments_count(self, token).await
+    }
+
+    async fn get_checkouts_param_token_payment(&self, token: &str) -> Result<()> {
+        SalesChannels::get_checkouts_param_token_payment(self, token).await
+    }
+
+    async fn create_checkouts_param_token_payments_with(
+        &self,
+        token: &str,
+        params: &SalesChannelsCreateCheckoutsParamTokenPaymentsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::create_checkouts_param_token_payments_with(self, token, params, body).await
+    }
+
+    async fn get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()> {
+        SalesChannels::get_checkouts_param_token_payments_payment(self, token, payment_id).await
+    }
+
+    async fn get_checkouts_param_token_payments_count(&self, token: &str) -> Result<()> {
+        SalesChannels::get_checkouts_param_token_payments_count(self, token).await
+    }
+
+    async fn deprecated_202101_get_checkouts_param_token_payment(&self, token: &str) -> Result<()> {
+        SalesChannels::deprecated_202101_get_checkouts_param_token_payment(self, token).await
+    }
+
+    async fn deprecated_202101_create_checkouts_param_token_payments_with(
+        &self,
+        token: &str,
+        params: &SalesChannelsDeprecated202101CreateCheckoutsParamTokenPaymentsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_create_checkouts_param_token_payments_with(
+            self, token, params, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_get_checkouts_param_token_payments_payment(
+            self, token, payment_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202101_get_checkouts_param_token_payments_count(self, token).await
+    }
+
+    async fn deprecated_unstable_get_checkouts_param_token_payment(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_checkouts_param_token_payment(self, token).await
+    }
+
+    async fn deprecated_unstable_create_checkouts_param_token_payments_with(
+        &self,
+        token: &str,
+        params: &SalesChannelsDeprecatedUnstableCreateCheckoutsParamTokenPaymentsParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_create_checkouts_param_token_payments_with(
+            self, token, params, body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_checkouts_param_token_payments_payment(
+        &self,
+        token: &str,
+        payment_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_checkouts_param_token_payments_payment(
+            self, token, payment_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_checkouts_param_token_payments_count(
+        &self,
+        token: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_unstable_get_checkouts_param_token_payments_count(self, token)
+            .await
+    }
+
+    async fn deprecated_202001_get_product_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202001GetProductListingParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_product_listing_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_product_listings_id_with(
+        &self,
+        params: &SalesChannelsDeprecated202001GetProductListingsIdParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_product_listings_id_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_product_listings_count(&self) -> Result<()> {
+        SalesChannels::deprecated_202001_get_product_listings_count(self).await
+    }
+
+    async fn deprecated_202001_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_get_product_listings_param_listing(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_update_product_listings_param_listing(
+            self,
+            product_listing_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202001_delete_product_listings_param_listing(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_product_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202004GetProductListingParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_product_listing_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_product_listings_id_with(
+        &self,
+        params: &SalesChannelsDeprecated202004GetProductListingsIdParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_product_listings_id_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_product_listings_count(&self) -> Result<()> {
+        SalesChannels::deprecated_202004_get_product_listings_count(self).await
+    }
+
+    async fn deprecated_202004_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_get_product_listings_param_listing(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_update_product_listings_param_listing(
+            self,
+            product_listing_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202004_delete_product_listings_param_listing(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_product_listing_with(
+        &self,
+        params: &SalesChannelsDeprecated202007GetProductListingParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_product_listing_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_product_listings_id_with(
+        &self,
+        params: &SalesChannelsDeprecated202007GetProductListingsIdParams,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_product_listings_id_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_product_listings_count(&self) -> Result<()> {
+        SalesChannels::deprecated_202007_get_product_listings_count(self).await
+    }
+
+    async fn deprecated_202007_get_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_get_product_listings_param_listing(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_update_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_update_product_listings_param_listing(
+            self,
+            product_listing_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_delete_product_listings_param_listing(
+        &self,
+        product_listing_id: &str,
+    ) -> Result<()> {
+        SalesChannels::deprecated_202007_delete_product_listings_param_listing(
+            self,
+            product_listing_id,
+        )
+        .await
+    }
+}
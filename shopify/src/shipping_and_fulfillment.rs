@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ShippingAndFulfillment {
     pub client: Client,
@@ -21,27 +19,20 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `assignment_status: &str` -- The assigment status of the fulfillment orders that should be returned:
-     *                       
-     *                           cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
-     *                           fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
-     *                           fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
-     * * `location_ids: &str` -- The IDs of the assigned locations of the fulfillment orders that should be returned.
-     * * `location_ids: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_assigned_fulfillment_order(
+    pub async fn deprecated_202001_get_assigned_fulfillment_order_with(
         &self,
-        assignment_status: &str,
-        location_ids: &str,
+        params: &ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignment_status.is_empty() {
+        if let Some(assignment_status) = &params.assignment_status {
             query_args.push((
                 "assignment_status".to_string(),
                 assignment_status.to_string(),
             ));
         }
-        if !location_ids.is_empty() {
+        if let Some(location_ids) = &params.location_ids {
             query_args.push(("location_ids".to_string(), location_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -53,6 +44,23 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_assigned_fulfillment_order_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_assigned_fulfillment_order_with` instead")]
+    pub async fn deprecated_202001_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderParams {
+            assignment_status: Some(assignment_status.to_string()).filter(|s| !s.is_empty()),
+            location_ids: Some(location_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_assigned_fulfillment_order_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
@@ -62,27 +70,20 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `assignment_status: &str` -- The assigment status of the fulfillment orders that should be returned:
-     *                       
-     *                           cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
-     *                           fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
-     *                           fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
-     * * `location_ids: &str` -- The IDs of the assigned locations of the fulfillment orders that should be returned.
-     * * `location_ids: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_assigned_fulfillment_order(
+    pub async fn deprecated_202004_get_assigned_fulfillment_order_with(
         &self,
-        assignment_status: &str,
-        location_ids: &str,
+        params: &ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignment_status.is_empty() {
+        if let Some(assignment_status) = &params.assignment_status {
             query_args.push((
                 "assignment_status".to_string(),
                 assignment_status.to_string(),
             ));
         }
-        if !location_ids.is_empty() {
+        if let Some(location_ids) = &params.location_ids {
             query_args.push(("location_ids".to_string(), location_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -94,6 +95,23 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_assigned_fulfillment_order_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_assigned_fulfillment_order_with` instead")]
+    pub async fn deprecated_202004_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderParams {
+            assignment_status: Some(assignment_status.to_string()).filter(|s| !s.is_empty()),
+            location_ids: Some(location_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_assigned_fulfillment_order_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
@@ -103,27 +121,20 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `assignment_status: &str` -- The assigment status of the fulfillment orders that should be returned:
-     *                       
-     *                           cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
-     *                           fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
-     *                           fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
-     * * `location_ids: &str` -- The IDs of the assigned locations of the fulfillment orders that should be returned.
-     * * `location_ids: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_assigned_fulfillment_order(
+    pub async fn deprecated_202007_get_assigned_fulfillment_order_with(
         &self,
-        assignment_status: &str,
-        location_ids: &str,
+        params: &ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignment_status.is_empty() {
+        if let Some(assignment_status) = &params.assignment_status {
             query_args.push((
                 "assignment_status".to_string(),
                 assignment_status.to_string(),
             ));
         }
-        if !location_ids.is_empty() {
+        if let Some(location_ids) = &params.location_ids {
             query_args.push(("location_ids".to_string(), location_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -135,6 +146,23 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_assigned_fulfillment_order_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_assigned_fulfillment_order_with` instead")]
+    pub async fn deprecated_202007_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderParams {
+            assignment_status: Some(assignment_status.to_string()).filter(|s| !s.is_empty()),
+            location_ids: Some(location_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_assigned_fulfillment_order_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
@@ -144,27 +172,20 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `assignment_status: &str` -- The assigment status of the fulfillment orders that should be returned:
-     *                       
-     *                           cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
-     *                           fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
-     *                           fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
-     * * `location_ids: &str` -- The IDs of the assigned locations of the fulfillment orders that should be returned.
-     * * `location_ids: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ShippingAndFulfillmentGetAssignedFulfillmentOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_assigned_fulfillment_order(
+    pub async fn get_assigned_fulfillment_order_with(
         &self,
-        assignment_status: &str,
-        location_ids: &str,
+        params: &ShippingAndFulfillmentGetAssignedFulfillmentOrderParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignment_status.is_empty() {
+        if let Some(assignment_status) = &params.assignment_status {
             query_args.push((
                 "assignment_status".to_string(),
                 assignment_status.to_string(),
             ));
         }
-        if !location_ids.is_empty() {
+        if let Some(location_ids) = &params.location_ids {
             query_args.push(("location_ids".to_string(), location_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -176,6 +197,22 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_assigned_fulfillment_order_with` and pass the query parameters as a `ShippingAndFulfillmentGetAssignedFulfillmentOrderParams` instead.
+     */
+    #[deprecated(note = "use `get_assigned_fulfillment_order_with` instead")]
+    pub async fn get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentGetAssignedFulfillmentOrderParams {
+            assignment_status: Some(assignment_status.to_string()).filter(|s| !s.is_empty()),
+            location_ids: Some(location_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_assigned_fulfillment_order_with(&params).await
+    }
+
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
@@ -185,27 +222,20 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `assignment_status: &str` -- The assigment status of the fulfillment orders that should be returned:
-     *                       
-     *                           cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
-     *                           fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
-     *                           fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
-     * * `location_ids: &str` -- The IDs of the assigned locations of the fulfillment orders that should be returned.
-     * * `location_ids: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_assigned_fulfillment_order(
+    pub async fn deprecated_202101_get_assigned_fulfillment_order_with(
         &self,
-        assignment_status: &str,
-        location_ids: &str,
+        params: &ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignment_status.is_empty() {
+        if let Some(assignment_status) = &params.assignment_status {
             query_args.push((
                 "assignment_status".to_string(),
                 assignment_status.to_string(),
             ));
         }
-        if !location_ids.is_empty() {
+        if let Some(location_ids) = &params.location_ids {
             query_args.push(("location_ids".to_string(), location_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -217,6 +247,23 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_assigned_fulfillment_order_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_assigned_fulfillment_order_with` instead")]
+    pub async fn deprecated_202101_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderParams {
+            assignment_status: Some(assignment_status.to_string()).filter(|s| !s.is_empty()),
+            location_ids: Some(location_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_assigned_fulfillment_order_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of fulfillment orders on a shop for a specific app.
      *
@@ -226,27 +273,20 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `assignment_status: &str` -- The assigment status of the fulfillment orders that should be returned:
-     *                       
-     *                           cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
-     *                           fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
-     *                           fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
-     * * `location_ids: &str` -- The IDs of the assigned locations of the fulfillment orders that should be returned.
-     * * `location_ids: i64` -- recurring_application_charge[capped_amount].
+     * * `params: &ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_assigned_fulfillment_order(
+    pub async fn deprecated_unstable_get_assigned_fulfillment_order_with(
         &self,
-        assignment_status: &str,
-        location_ids: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !assignment_status.is_empty() {
+        if let Some(assignment_status) = &params.assignment_status {
             query_args.push((
                 "assignment_status".to_string(),
                 assignment_status.to_string(),
             ));
         }
-        if !location_ids.is_empty() {
+        if let Some(location_ids) = &params.location_ids {
             query_args.push(("location_ids".to_string(), location_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -258,6 +298,23 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_assigned_fulfillment_order_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_assigned_fulfillment_order_with` instead")]
+    pub async fn deprecated_unstable_get_assigned_fulfillment_order(
+        &self,
+        assignment_status: &str,
+        location_ids: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderParams {
+            assignment_status: Some(assignment_status.to_string()).filter(|s| !s.is_empty()),
+            location_ids: Some(location_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_assigned_fulfillment_order_with(&params)
+            .await
+    }
+
     /**
      * Sends a cancellation request to the fulfillment service of a fulfillment order.
      *
@@ -268,16 +325,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request(
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -292,6 +349,29 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_with` instead"
+    )]
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -302,16 +382,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept(
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -326,6 +406,24 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept_with` instead"
+    )]
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept_with(fulfillment_order_id, &params, body).await
+    }
+
     /**
      * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -336,16 +434,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject(
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -360,6 +458,24 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject_with` instead"
+    )]
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject_with(fulfillment_order_id, &params, body).await
+    }
+
     /**
      * Sends a cancellation request to the fulfillment service of a fulfillment order.
      *
@@ -370,16 +486,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request(
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -394,6 +510,29 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_with` instead"
+    )]
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -404,16 +543,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept(
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -428,6 +567,24 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept_with` instead"
+    )]
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept_with(fulfillment_order_id, &params, body).await
+    }
+
     /**
      * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -438,16 +595,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject(
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -462,6 +619,24 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject_with` instead"
+    )]
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject_with(fulfillment_order_id, &params, body).await
+    }
+
     /**
      * Sends a cancellation request to the fulfillment service of a fulfillment order.
      *
@@ -472,16 +647,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request(
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -496,6 +671,29 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_with` instead"
+    )]
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -506,16 +704,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept(
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -530,6 +728,24 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept_with` instead"
+    )]
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept_with(fulfillment_order_id, &params, body).await
+    }
+
     /**
      * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -540,16 +756,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject(
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -564,6 +780,24 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject_with` instead"
+    )]
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject_with(fulfillment_order_id, &params, body).await
+    }
+
     /**
      * Sends a cancellation request to the fulfillment service of a fulfillment order.
      *
@@ -574,16 +808,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for the cancellation request.
+     * * `params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create_fulfillment_orders_param_order_cancellation_request(
+    pub async fn create_fulfillment_orders_param_order_cancellation_request_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -598,6 +832,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `create_fulfillment_orders_param_order_cancellation_request_with` and pass the query parameters as a `ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestParams` instead.
+     */
+    #[deprecated(
+        note = "use `create_fulfillment_orders_param_order_cancellation_request_with` instead"
+    )]
+    pub async fn create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestParams {
+                message: Some(message.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.create_fulfillment_orders_param_order_cancellation_request_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -608,16 +866,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the cancellation request.
+     * * `params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create_fulfillment_orders_param_order_cancellation_request_accept(
+    pub async fn create_fulfillment_orders_param_order_cancellation_request_accept_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -632,6 +890,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `create_fulfillment_orders_param_order_cancellation_request_accept_with` and pass the query parameters as a `ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams` instead.
+     */
+    #[deprecated(
+        note = "use `create_fulfillment_orders_param_order_cancellation_request_accept_with` \
+                instead"
+    )]
+    pub async fn create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.create_fulfillment_orders_param_order_cancellation_request_accept_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -642,16 +924,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the cancellation request.
+     * * `params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create_fulfillment_orders_param_order_cancellation_request_reject(
+    pub async fn create_fulfillment_orders_param_order_cancellation_request_reject_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -666,6 +948,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `create_fulfillment_orders_param_order_cancellation_request_reject_with` and pass the query parameters as a `ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams` instead.
+     */
+    #[deprecated(
+        note = "use `create_fulfillment_orders_param_order_cancellation_request_reject_with` \
+                instead"
+    )]
+    pub async fn create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.create_fulfillment_orders_param_order_cancellation_request_reject_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Sends a cancellation request to the fulfillment service of a fulfillment order.
      *
@@ -676,16 +982,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request(
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -700,6 +1006,29 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_with` instead"
+    )]
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -710,16 +1039,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept(
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -734,6 +1063,24 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept_with` instead"
+    )]
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept_with(fulfillment_order_id, &params, body).await
+    }
+
     /**
      * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -744,16 +1091,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject(
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -769,25 +1116,43 @@ impl ShippingAndFulfillment {
     }
 
     /**
-     * Sends a cancellation request to the fulfillment service of a fulfillment order.
-     *
-     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-unstable
-     *
-     * **Parameters:**
-     *
-     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for the cancellation request.
+     * Deprecated: use `deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams` instead.
      */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request(
+    #[deprecated(
+        note = "use `deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject_with` instead"
+    )]
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject(
         &self,
         fulfillment_order_id: &str,
         message: &str,
         body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject_with(fulfillment_order_id, &params, body).await
+    }
+
+    /**
+     * Sends a cancellation request to the fulfillment service of a fulfillment order.
+     *
+     * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/cancellation_request.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/cancellationrequest#create-unstable
+     *
+     * **Parameters:**
+     *
+     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
+     * * `params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestParams,
+        body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -802,6 +1167,29 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_with` instead"
+    )]
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Accepts a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -812,16 +1200,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept(
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -836,6 +1224,24 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept_with` instead"
+    )]
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept_with(fulfillment_order_id, &params, body).await
+    }
+
     /**
      * Rejects a cancellation request sent to a fulfillment service for a fulfillment order.
      *
@@ -846,16 +1252,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the cancellation request.
+     * * `params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject(
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -870,6 +1276,24 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject_with` instead"
+    )]
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject_with(fulfillment_order_id, &params, body).await
+    }
+
     /**
      * Retrieves a list of carrier services.
      *
@@ -1480,46 +1904,33 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_max: &str` -- Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     * * `limit: &str` -- Limit the amount of results.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_max: &str` -- Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_orders_param_order_fulfillment(
+    pub async fn deprecated_202001_get_orders_param_order_fulfillment_with(
         &self,
         order_id: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_max: &str,
-        updated_at_min: &str,
+        params: &ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1532,6 +1943,34 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_orders_param_order_fulfillment_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_orders_param_order_fulfillment_with` instead")]
+    pub async fn deprecated_202001_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_orders_param_order_fulfillment_with(order_id, &params)
+            .await
+    }
+
     /**
     * Create a fulfillment for the specified order and line items.
               The fulfillment's status depends on the line items in the order:
@@ -1614,30 +2053,24 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_orders_param_order_fulfillments_count(
+    pub async fn deprecated_202001_get_orders_param_order_fulfillments_count_with(
         &self,
         order_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
+        params: &ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1650,6 +2083,31 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_orders_param_order_fulfillments_count_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_orders_param_order_fulfillments_count_with` instead"
+    )]
+    pub async fn deprecated_202001_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountParams {
+                created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+                created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+                updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+                updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202001_get_orders_param_order_fulfillments_count_with(order_id, &params)
+            .await
+    }
+
     /**
      * Retrieve a specific fulfillment.
      *
@@ -1661,16 +2119,16 @@ impl ShippingAndFulfillment {
      *
      * * `order_id: &str` -- storefront_access_token_id.
      * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of fields to include in the response.
+     * * `params: &ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment(
+    pub async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_with(
         &self,
         order_id: &str,
         fulfillment_id: &str,
-        fields: &str,
+        params: &ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1684,6 +2142,30 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_orders_param_order_fulfillments_fulfillment_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_orders_param_order_fulfillments_fulfillment_with` \
+                instead"
+    )]
+    pub async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentParams {
+fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202001_get_orders_param_order_fulfillments_fulfillment_with(
+            order_id,
+            fulfillment_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Update information associated with a fulfillment.
      *
@@ -1879,46 +2361,33 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_max: &str` -- Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     * * `limit: &str` -- Limit the amount of results.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_max: &str` -- Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_orders_param_order_fulfillment(
+    pub async fn deprecated_202004_get_orders_param_order_fulfillment_with(
         &self,
         order_id: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_max: &str,
-        updated_at_min: &str,
+        params: &ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1931,6 +2400,34 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_orders_param_order_fulfillment_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_orders_param_order_fulfillment_with` instead")]
+    pub async fn deprecated_202004_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_orders_param_order_fulfillment_with(order_id, &params)
+            .await
+    }
+
     /**
     * Create a fulfillment for the specified order and line items.
               The fulfillment's status depends on the line items in the order:
@@ -2013,30 +2510,24 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_orders_param_order_fulfillments_count(
+    pub async fn deprecated_202004_get_orders_param_order_fulfillments_count_with(
         &self,
         order_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
+        params: &ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2049,6 +2540,31 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_orders_param_order_fulfillments_count_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_orders_param_order_fulfillments_count_with` instead"
+    )]
+    pub async fn deprecated_202004_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountParams {
+                created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+                created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+                updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+                updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202004_get_orders_param_order_fulfillments_count_with(order_id, &params)
+            .await
+    }
+
     /**
      * Retrieve a specific fulfillment.
      *
@@ -2060,16 +2576,16 @@ impl ShippingAndFulfillment {
      *
      * * `order_id: &str` -- storefront_access_token_id.
      * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of fields to include in the response.
+     * * `params: &ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment(
+    pub async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_with(
         &self,
         order_id: &str,
         fulfillment_id: &str,
-        fields: &str,
+        params: &ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2083,6 +2599,30 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_orders_param_order_fulfillments_fulfillment_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_orders_param_order_fulfillments_fulfillment_with` \
+                instead"
+    )]
+    pub async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentParams {
+fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202004_get_orders_param_order_fulfillments_fulfillment_with(
+            order_id,
+            fulfillment_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Update information associated with a fulfillment.
      *
@@ -2278,46 +2818,33 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_max: &str` -- Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     * * `limit: &str` -- Limit the amount of results.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_max: &str` -- Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_orders_param_order_fulfillment(
+    pub async fn deprecated_202007_get_orders_param_order_fulfillment_with(
         &self,
         order_id: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_max: &str,
-        updated_at_min: &str,
+        params: &ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2330,6 +2857,34 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_orders_param_order_fulfillment_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_orders_param_order_fulfillment_with` instead")]
+    pub async fn deprecated_202007_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_orders_param_order_fulfillment_with(order_id, &params)
+            .await
+    }
+
     /**
     * Create a fulfillment for the specified order and line items.
               The fulfillment's status depends on the line items in the order:
@@ -2412,30 +2967,24 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_orders_param_order_fulfillments_count(
+    pub async fn deprecated_202007_get_orders_param_order_fulfillments_count_with(
         &self,
         order_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
+        params: &ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2448,6 +2997,31 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_orders_param_order_fulfillments_count_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_orders_param_order_fulfillments_count_with` instead"
+    )]
+    pub async fn deprecated_202007_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountParams {
+                created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+                created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+                updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+                updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202007_get_orders_param_order_fulfillments_count_with(order_id, &params)
+            .await
+    }
+
     /**
      * Retrieve a specific fulfillment.
      *
@@ -2459,16 +3033,16 @@ impl ShippingAndFulfillment {
      *
      * * `order_id: &str` -- storefront_access_token_id.
      * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of fields to include in the response.
+     * * `params: &ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment(
+    pub async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_with(
         &self,
         order_id: &str,
         fulfillment_id: &str,
-        fields: &str,
+        params: &ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2482,6 +3056,30 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_orders_param_order_fulfillments_fulfillment_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_orders_param_order_fulfillments_fulfillment_with` \
+                instead"
+    )]
+    pub async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentParams {
+fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202007_get_orders_param_order_fulfillments_fulfillment_with(
+            order_id,
+            fulfillment_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Update information associated with a fulfillment.
      *
@@ -2677,46 +3275,33 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_max: &str` -- Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     * * `limit: &str` -- Limit the amount of results.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_max: &str` -- Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &ShippingAndFulfillmentGetOrdersParamOrderFulfillmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_orders_param_order_fulfillment(
+    pub async fn get_orders_param_order_fulfillment_with(
         &self,
         order_id: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_max: &str,
-        updated_at_min: &str,
+        params: &ShippingAndFulfillmentGetOrdersParamOrderFulfillmentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2729,6 +3314,34 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_orders_param_order_fulfillment_with` and pass the query parameters as a `ShippingAndFulfillmentGetOrdersParamOrderFulfillmentParams` instead.
+     */
+    #[deprecated(note = "use `get_orders_param_order_fulfillment_with` instead")]
+    pub async fn get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentGetOrdersParamOrderFulfillmentParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_orders_param_order_fulfillment_with(order_id, &params)
+            .await
+    }
+
     /**
     * Create a fulfillment for the specified order and line items.
               The fulfillment's status depends on the line items in the order:
@@ -2811,30 +3424,24 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_orders_param_order_fulfillments_count(
+    pub async fn get_orders_param_order_fulfillments_count_with(
         &self,
         order_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
+        params: &ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2848,26 +3455,48 @@ impl ShippingAndFulfillment {
     }
 
     /**
-     * Retrieve a specific fulfillment.
-     *
-     * This function performs a `GET` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
-     *
-     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-2020-10
-     *
-     * **Parameters:**
-     *
-     * * `order_id: &str` -- storefront_access_token_id.
-     * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of fields to include in the response.
+     * Deprecated: use `get_orders_param_order_fulfillments_count_with` and pass the query parameters as a `ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountParams` instead.
      */
-    pub async fn get_orders_param_order_fulfillments_fulfillment(
+    #[deprecated(note = "use `get_orders_param_order_fulfillments_count_with` instead")]
+    pub async fn get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_orders_param_order_fulfillments_count_with(order_id, &params)
+            .await
+    }
+
+    /**
+     * Retrieve a specific fulfillment.
+     *
+     * This function performs a `GET` to the `/admin/api/2020-10/orders/{order_id}/fulfillments/{fulfillment_id}.json` endpoint.
+     *
+     * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillment#show-2020-10
+     *
+     * **Parameters:**
+     *
+     * * `order_id: &str` -- storefront_access_token_id.
+     * * `fulfillment_id: &str` -- storefront_access_token_id.
+     * * `params: &ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_orders_param_order_fulfillments_fulfillment_with(
         &self,
         order_id: &str,
         fulfillment_id: &str,
-        fields: &str,
+        params: &ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2881,6 +3510,23 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_orders_param_order_fulfillments_fulfillment_with` and pass the query parameters as a `ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentParams` instead.
+     */
+    #[deprecated(note = "use `get_orders_param_order_fulfillments_fulfillment_with` instead")]
+    pub async fn get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_orders_param_order_fulfillments_fulfillment_with(order_id, fulfillment_id, &params)
+            .await
+    }
+
     /**
      * Update information associated with a fulfillment.
      *
@@ -3073,46 +3719,33 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_max: &str` -- Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     * * `limit: &str` -- Limit the amount of results.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_max: &str` -- Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_orders_param_order_fulfillment(
+    pub async fn deprecated_202101_get_orders_param_order_fulfillment_with(
         &self,
         order_id: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_max: &str,
-        updated_at_min: &str,
+        params: &ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3125,6 +3758,34 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_orders_param_order_fulfillment_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_orders_param_order_fulfillment_with` instead")]
+    pub async fn deprecated_202101_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_orders_param_order_fulfillment_with(order_id, &params)
+            .await
+    }
+
     /**
     * Create a fulfillment for the specified order and line items.
               The fulfillment's status depends on the line items in the order:
@@ -3207,30 +3868,24 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_orders_param_order_fulfillments_count(
+    pub async fn deprecated_202101_get_orders_param_order_fulfillments_count_with(
         &self,
         order_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
+        params: &ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3243,6 +3898,31 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_orders_param_order_fulfillments_count_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_orders_param_order_fulfillments_count_with` instead"
+    )]
+    pub async fn deprecated_202101_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountParams {
+                created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+                created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+                updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+                updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202101_get_orders_param_order_fulfillments_count_with(order_id, &params)
+            .await
+    }
+
     /**
      * Retrieve a specific fulfillment.
      *
@@ -3254,16 +3934,16 @@ impl ShippingAndFulfillment {
      *
      * * `order_id: &str` -- storefront_access_token_id.
      * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of fields to include in the response.
+     * * `params: &ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment(
+    pub async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_with(
         &self,
         order_id: &str,
         fulfillment_id: &str,
-        fields: &str,
+        params: &ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3277,6 +3957,30 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_orders_param_order_fulfillments_fulfillment_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_orders_param_order_fulfillments_fulfillment_with` \
+                instead"
+    )]
+    pub async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentParams {
+fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202101_get_orders_param_order_fulfillments_fulfillment_with(
+            order_id,
+            fulfillment_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Update information associated with a fulfillment.
      *
@@ -3472,46 +4176,33 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_max: &str` -- Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_min: &str` -- Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
-     * * `limit: &str` -- Limit the amount of results.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `updated_at_max: &str` -- Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_orders_param_order_fulfillment(
+    pub async fn deprecated_unstable_get_orders_param_order_fulfillment_with(
         &self,
         order_id: &str,
-        created_at_max: &str,
-        created_at_min: &str,
-        fields: &str,
-        limit: &str,
-        since_id: &str,
-        updated_at_max: &str,
-        updated_at_min: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3524,6 +4215,36 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_orders_param_order_fulfillment_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_orders_param_order_fulfillment_with` instead"
+    )]
+    pub async fn deprecated_unstable_get_orders_param_order_fulfillment(
+        &self,
+        order_id: &str,
+        created_at_max: &str,
+        created_at_min: &str,
+        fields: &str,
+        limit: &str,
+        since_id: &str,
+        updated_at_max: &str,
+        updated_at_min: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentParams {
+            created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+            created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+            updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_orders_param_order_fulfillment_with(order_id, &params)
+            .await
+    }
+
     /**
     * Create a fulfillment for the specified order and line items.
               The fulfillment's status depends on the line items in the order:
@@ -3606,30 +4327,24 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `order_id: &str` -- storefront_access_token_id.
-     * * `created_at_min: &str` -- Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
-     * * `created_at_max: &str` -- Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_min: &str` -- Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
-     * * `updated_at_max: &str` -- Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+     * * `params: &ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_orders_param_order_fulfillments_count(
+    pub async fn deprecated_unstable_get_orders_param_order_fulfillments_count_with(
         &self,
         order_id: &str,
-        created_at_min: &str,
-        created_at_max: &str,
-        updated_at_min: &str,
-        updated_at_max: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !created_at_max.is_empty() {
+        if let Some(created_at_max) = &params.created_at_max {
             query_args.push(("created_at_max".to_string(), created_at_max.to_string()));
         }
-        if !created_at_min.is_empty() {
+        if let Some(created_at_min) = &params.created_at_min {
             query_args.push(("created_at_min".to_string(), created_at_min.to_string()));
         }
-        if !updated_at_max.is_empty() {
+        if let Some(updated_at_max) = &params.updated_at_max {
             query_args.push(("updated_at_max".to_string(), updated_at_max.to_string()));
         }
-        if !updated_at_min.is_empty() {
+        if let Some(updated_at_min) = &params.updated_at_min {
             query_args.push(("updated_at_min".to_string(), updated_at_min.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3642,6 +4357,31 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_orders_param_order_fulfillments_count_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_orders_param_order_fulfillments_count_with` instead"
+    )]
+    pub async fn deprecated_unstable_get_orders_param_order_fulfillments_count(
+        &self,
+        order_id: &str,
+        created_at_min: &str,
+        created_at_max: &str,
+        updated_at_min: &str,
+        updated_at_max: &str,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountParams {
+                created_at_max: Some(created_at_max.to_string()).filter(|s| !s.is_empty()),
+                created_at_min: Some(created_at_min.to_string()).filter(|s| !s.is_empty()),
+                updated_at_max: Some(updated_at_max.to_string()).filter(|s| !s.is_empty()),
+                updated_at_min: Some(updated_at_min.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_unstable_get_orders_param_order_fulfillments_count_with(order_id, &params)
+            .await
+    }
+
     /**
      * Retrieve a specific fulfillment.
      *
@@ -3653,16 +4393,16 @@ impl ShippingAndFulfillment {
      *
      * * `order_id: &str` -- storefront_access_token_id.
      * * `fulfillment_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Comma-separated list of fields to include in the response.
+     * * `params: &ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment(
+    pub async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_with(
         &self,
         order_id: &str,
         fulfillment_id: &str,
-        fields: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -3676,6 +4416,30 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_with` \
+                instead"
+    )]
+    pub async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentParams {
+fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_with(
+            order_id,
+            fulfillment_id,
+            &params,
+        )
+        .await
+    }
+
     /**
      * Update information associated with a fulfillment.
      *
@@ -4641,16 +5405,16 @@ impl ShippingAndFulfillment {
     * **Parameters:**
     *
     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `message: &str` -- An optional reason for marking the fulfillment order as incomplete.
+    * * `params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_close(
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_close_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4665,6 +5429,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_fulfillment_orders_param_order_close_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_create_fulfillment_orders_param_order_close_with` instead"
+    )]
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseParams {
+                message: Some(message.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202001_create_fulfillment_orders_param_order_close_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
@@ -4675,16 +5463,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `new_location_id: &str` -- The id of the location to which the fulfillment order will be moved.
+     * * `params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_move(
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_move_with(
         &self,
         fulfillment_order_id: &str,
-        new_location_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !new_location_id.is_empty() {
+        if let Some(new_location_id) = &params.new_location_id {
             query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4699,6 +5487,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_fulfillment_orders_param_order_move_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_create_fulfillment_orders_param_order_move_with` instead"
+    )]
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveParams {
+                new_location_id: Some(new_location_id.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202001_create_fulfillment_orders_param_order_move_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of fulfillment orders for a specific order.
      *
@@ -4783,16 +5595,16 @@ impl ShippingAndFulfillment {
     * **Parameters:**
     *
     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `message: &str` -- An optional reason for marking the fulfillment order as incomplete.
+    * * `params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_close(
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_close_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4807,6 +5619,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_fulfillment_orders_param_order_close_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_create_fulfillment_orders_param_order_close_with` instead"
+    )]
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseParams {
+                message: Some(message.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202004_create_fulfillment_orders_param_order_close_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
@@ -4817,16 +5653,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `new_location_id: &str` -- The id of the location to which the fulfillment order will be moved.
+     * * `params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_move(
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_move_with(
         &self,
         fulfillment_order_id: &str,
-        new_location_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !new_location_id.is_empty() {
+        if let Some(new_location_id) = &params.new_location_id {
             query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4841,6 +5677,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_fulfillment_orders_param_order_move_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_create_fulfillment_orders_param_order_move_with` instead"
+    )]
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveParams {
+                new_location_id: Some(new_location_id.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202004_create_fulfillment_orders_param_order_move_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of fulfillment orders for a specific order.
      *
@@ -4925,16 +5785,16 @@ impl ShippingAndFulfillment {
     * **Parameters:**
     *
     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `message: &str` -- An optional reason for marking the fulfillment order as incomplete.
+    * * `params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_close(
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_close_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4949,6 +5809,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_fulfillment_orders_param_order_close_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_create_fulfillment_orders_param_order_close_with` instead"
+    )]
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseParams {
+                message: Some(message.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202007_create_fulfillment_orders_param_order_close_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
@@ -4959,16 +5843,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `new_location_id: &str` -- The id of the location to which the fulfillment order will be moved.
+     * * `params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_move(
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_move_with(
         &self,
         fulfillment_order_id: &str,
-        new_location_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !new_location_id.is_empty() {
+        if let Some(new_location_id) = &params.new_location_id {
             query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -4983,6 +5867,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_fulfillment_orders_param_order_move_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_create_fulfillment_orders_param_order_move_with` instead"
+    )]
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveParams {
+                new_location_id: Some(new_location_id.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202007_create_fulfillment_orders_param_order_move_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Retrieves a list of fulfillment orders for a specific order.
      *
@@ -5067,16 +5975,16 @@ impl ShippingAndFulfillment {
     * **Parameters:**
     *
     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `message: &str` -- An optional reason for marking the fulfillment order as incomplete.
+    * * `params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn create_fulfillment_orders_param_order_close(
+    pub async fn create_fulfillment_orders_param_order_close_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5091,6 +5999,23 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `create_fulfillment_orders_param_order_close_with` and pass the query parameters as a `ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseParams` instead.
+     */
+    #[deprecated(note = "use `create_fulfillment_orders_param_order_close_with` instead")]
+    pub async fn create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseParams {
+            message: Some(message.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_fulfillment_orders_param_order_close_with(fulfillment_order_id, &params, body)
+            .await
+    }
+
     /**
      * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
@@ -5101,16 +6026,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `new_location_id: &str` -- The id of the location to which the fulfillment order will be moved.
+     * * `params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create_fulfillment_orders_param_order_move(
+    pub async fn create_fulfillment_orders_param_order_move_with(
         &self,
         fulfillment_order_id: &str,
-        new_location_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !new_location_id.is_empty() {
+        if let Some(new_location_id) = &params.new_location_id {
             query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5125,6 +6050,23 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `create_fulfillment_orders_param_order_move_with` and pass the query parameters as a `ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveParams` instead.
+     */
+    #[deprecated(note = "use `create_fulfillment_orders_param_order_move_with` instead")]
+    pub async fn create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveParams {
+            new_location_id: Some(new_location_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_fulfillment_orders_param_order_move_with(fulfillment_order_id, &params, body)
+            .await
+    }
+
     /**
      * Retrieves a list of fulfillment orders for a specific order.
      *
@@ -5209,16 +6151,16 @@ impl ShippingAndFulfillment {
     * **Parameters:**
     *
     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `message: &str` -- An optional reason for marking the fulfillment order as incomplete.
+    * * `params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_close(
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_close_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5233,6 +6175,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_fulfillment_orders_param_order_close_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_create_fulfillment_orders_param_order_close_with` instead"
+    )]
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseParams {
+                message: Some(message.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202101_create_fulfillment_orders_param_order_close_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
@@ -5243,16 +6209,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `new_location_id: &str` -- The id of the location to which the fulfillment order will be moved.
+     * * `params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_move(
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_move_with(
         &self,
         fulfillment_order_id: &str,
-        new_location_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !new_location_id.is_empty() {
+        if let Some(new_location_id) = &params.new_location_id {
             query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5268,7 +6234,31 @@ impl ShippingAndFulfillment {
     }
 
     /**
-    * Marks a scheduled fulfillment order as ready for fulfillment.
+     * Deprecated: use `deprecated_202101_create_fulfillment_orders_param_order_move_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_create_fulfillment_orders_param_order_move_with` instead"
+    )]
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveParams {
+                new_location_id: Some(new_location_id.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202101_create_fulfillment_orders_param_order_move_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
+    /**
+    * Marks a scheduled fulfillment order as ready for fulfillment.
               This endpoint allows merchants to work on a scheduled fulfillment order before its expected fulfill_at datetime.
     *
     * This function performs a `POST` to the `/admin/api/2021-01/fulfillment_orders/{fulfillment_order_id}/open.json` endpoint.
@@ -5404,15 +6394,15 @@ impl ShippingAndFulfillment {
     *
     * **Parameters:**
     *
-    * * `order_id: &str` -- The ID of the order that is associated to the fulfillment orders.
+    * * `params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_unstable_create_fulfillment_orders_release_hold(
+    pub async fn deprecated_unstable_create_fulfillment_orders_release_hold_with(
         &self,
-        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !order_id.is_empty() {
+        if let Some(order_id) = &params.order_id {
             query_args.push(("order_id".to_string(), order_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5426,6 +6416,25 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_create_fulfillment_orders_release_hold_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_create_fulfillment_orders_release_hold_with` instead"
+    )]
+    pub async fn deprecated_unstable_create_fulfillment_orders_release_hold(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldParams {
+                order_id: Some(order_id.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_unstable_create_fulfillment_orders_release_hold_with(&params, body)
+            .await
+    }
+
     /**
     * Marks an in progress fulfillment order as incomplete, indicating the fulfillment service
             is unable to ship any remaining items and intends to close the fulfillment order.
@@ -5437,16 +6446,16 @@ impl ShippingAndFulfillment {
     * **Parameters:**
     *
     * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-    * * `message: &str` -- An optional reason for marking the fulfillment order as incomplete.
+    * * `params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_close(
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_close_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5461,6 +6470,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_create_fulfillment_orders_param_order_close_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_create_fulfillment_orders_param_order_close_with` instead"
+    )]
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_close(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseParams {
+                message: Some(message.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_unstable_create_fulfillment_orders_param_order_close_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Moves a fulfillment order from one merchant managed location to another merchant managed location.
      *
@@ -5471,16 +6504,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `new_location_id: &str` -- The id of the location to which the fulfillment order will be moved.
+     * * `params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_move(
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_move_with(
         &self,
         fulfillment_order_id: &str,
-        new_location_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !new_location_id.is_empty() {
+        if let Some(new_location_id) = &params.new_location_id {
             query_args.push(("new_location_id".to_string(), new_location_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5495,6 +6528,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_create_fulfillment_orders_param_order_move_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_create_fulfillment_orders_param_order_move_with` instead"
+    )]
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_move(
+        &self,
+        fulfillment_order_id: &str,
+        new_location_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveParams {
+                new_location_id: Some(new_location_id.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_unstable_create_fulfillment_orders_param_order_move_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
     * Marks a scheduled fulfillment order as ready for fulfillment.
               This endpoint allows merchants to work on a scheduled fulfillment order before its expected fulfill_at datetime.
@@ -5559,24 +6616,22 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional message for the fulfillment request.
-     * * `fulfillment_order_line_items: &str` -- The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+     * * `params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_request(
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_request_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        fulfillment_order_line_items: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fulfillment_order_line_items.is_empty() {
+        if let Some(fulfillment_order_line_items) = &params.fulfillment_order_line_items {
             query_args.push((
                 "fulfillment_order_line_items".to_string(),
                 fulfillment_order_line_items.to_string(),
             ));
         }
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5591,6 +6646,33 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_fulfillment_orders_param_order_request_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_create_fulfillment_orders_param_order_request_with` instead"
+    )]
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestParams {
+                fulfillment_order_line_items: Some(fulfillment_order_line_items.to_string())
+                    .filter(|s| !s.is_empty()),
+                message: Some(message.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202001_create_fulfillment_orders_param_order_request_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -5601,16 +6683,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the fulfillment request.
+     * * `params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_request_accept(
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_request_accept_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5625,6 +6707,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_fulfillment_orders_param_order_request_accept_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_create_fulfillment_orders_param_order_request_accept_with` \
+                instead"
+    )]
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202001_create_fulfillment_orders_param_order_request_accept_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -5635,16 +6741,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the fulfillment request.
+     * * `params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_create_fulfillment_orders_param_order_request_reject(
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_request_reject_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5659,6 +6765,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_create_fulfillment_orders_param_order_request_reject_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_create_fulfillment_orders_param_order_request_reject_with` \
+                instead"
+    )]
+    pub async fn deprecated_202001_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202001_create_fulfillment_orders_param_order_request_reject_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Sends a fulfillment request to the fulfillment service of a fulfillment order.
      *
@@ -5669,24 +6799,22 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional message for the fulfillment request.
-     * * `fulfillment_order_line_items: &str` -- The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+     * * `params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_request(
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_request_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        fulfillment_order_line_items: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fulfillment_order_line_items.is_empty() {
+        if let Some(fulfillment_order_line_items) = &params.fulfillment_order_line_items {
             query_args.push((
                 "fulfillment_order_line_items".to_string(),
                 fulfillment_order_line_items.to_string(),
             ));
         }
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5701,6 +6829,33 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_fulfillment_orders_param_order_request_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_create_fulfillment_orders_param_order_request_with` instead"
+    )]
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestParams {
+                fulfillment_order_line_items: Some(fulfillment_order_line_items.to_string())
+                    .filter(|s| !s.is_empty()),
+                message: Some(message.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202004_create_fulfillment_orders_param_order_request_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -5711,16 +6866,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the fulfillment request.
+     * * `params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_request_accept(
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_request_accept_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5735,6 +6890,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_fulfillment_orders_param_order_request_accept_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_create_fulfillment_orders_param_order_request_accept_with` \
+                instead"
+    )]
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202004_create_fulfillment_orders_param_order_request_accept_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -5745,16 +6924,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the fulfillment request.
+     * * `params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_create_fulfillment_orders_param_order_request_reject(
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_request_reject_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5769,6 +6948,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_create_fulfillment_orders_param_order_request_reject_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_create_fulfillment_orders_param_order_request_reject_with` \
+                instead"
+    )]
+    pub async fn deprecated_202004_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202004_create_fulfillment_orders_param_order_request_reject_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Sends a fulfillment request to the fulfillment service of a fulfillment order.
      *
@@ -5779,24 +6982,22 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional message for the fulfillment request.
-     * * `fulfillment_order_line_items: &str` -- The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+     * * `params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_request(
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_request_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        fulfillment_order_line_items: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fulfillment_order_line_items.is_empty() {
+        if let Some(fulfillment_order_line_items) = &params.fulfillment_order_line_items {
             query_args.push((
                 "fulfillment_order_line_items".to_string(),
                 fulfillment_order_line_items.to_string(),
             ));
         }
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5811,6 +7012,33 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_fulfillment_orders_param_order_request_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_create_fulfillment_orders_param_order_request_with` instead"
+    )]
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestParams {
+                fulfillment_order_line_items: Some(fulfillment_order_line_items.to_string())
+                    .filter(|s| !s.is_empty()),
+                message: Some(message.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202007_create_fulfillment_orders_param_order_request_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -5821,16 +7049,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the fulfillment request.
+     * * `params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_request_accept(
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_request_accept_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5845,6 +7073,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_fulfillment_orders_param_order_request_accept_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_create_fulfillment_orders_param_order_request_accept_with` \
+                instead"
+    )]
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202007_create_fulfillment_orders_param_order_request_accept_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -5855,16 +7107,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the fulfillment request.
+     * * `params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_create_fulfillment_orders_param_order_request_reject(
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_request_reject_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5879,6 +7131,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_create_fulfillment_orders_param_order_request_reject_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_create_fulfillment_orders_param_order_request_reject_with` \
+                instead"
+    )]
+    pub async fn deprecated_202007_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202007_create_fulfillment_orders_param_order_request_reject_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Sends a fulfillment request to the fulfillment service of a fulfillment order.
      *
@@ -5889,24 +7165,22 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional message for the fulfillment request.
-     * * `fulfillment_order_line_items: &str` -- The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+     * * `params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create_fulfillment_orders_param_order_request(
+    pub async fn create_fulfillment_orders_param_order_request_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        fulfillment_order_line_items: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fulfillment_order_line_items.is_empty() {
+        if let Some(fulfillment_order_line_items) = &params.fulfillment_order_line_items {
             query_args.push((
                 "fulfillment_order_line_items".to_string(),
                 fulfillment_order_line_items.to_string(),
             ));
         }
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5921,6 +7195,26 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `create_fulfillment_orders_param_order_request_with` and pass the query parameters as a `ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestParams` instead.
+     */
+    #[deprecated(note = "use `create_fulfillment_orders_param_order_request_with` instead")]
+    pub async fn create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestParams {
+            fulfillment_order_line_items: Some(fulfillment_order_line_items.to_string())
+                .filter(|s| !s.is_empty()),
+            message: Some(message.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_fulfillment_orders_param_order_request_with(fulfillment_order_id, &params, body)
+            .await
+    }
+
     /**
      * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -5931,16 +7225,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the fulfillment request.
+     * * `params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create_fulfillment_orders_param_order_request_accept(
+    pub async fn create_fulfillment_orders_param_order_request_accept_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5955,6 +7249,27 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `create_fulfillment_orders_param_order_request_accept_with` and pass the query parameters as a `ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptParams` instead.
+     */
+    #[deprecated(note = "use `create_fulfillment_orders_param_order_request_accept_with` instead")]
+    pub async fn create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptParams {
+            message: Some(message.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_fulfillment_orders_param_order_request_accept_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -5965,16 +7280,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the fulfillment request.
+     * * `params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn create_fulfillment_orders_param_order_request_reject(
+    pub async fn create_fulfillment_orders_param_order_request_reject_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -5989,6 +7304,27 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `create_fulfillment_orders_param_order_request_reject_with` and pass the query parameters as a `ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectParams` instead.
+     */
+    #[deprecated(note = "use `create_fulfillment_orders_param_order_request_reject_with` instead")]
+    pub async fn create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectParams {
+            message: Some(message.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.create_fulfillment_orders_param_order_request_reject_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Sends a fulfillment request to the fulfillment service of a fulfillment order.
      *
@@ -5999,24 +7335,22 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional message for the fulfillment request.
-     * * `fulfillment_order_line_items: &str` -- The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+     * * `params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_request(
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_request_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        fulfillment_order_line_items: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fulfillment_order_line_items.is_empty() {
+        if let Some(fulfillment_order_line_items) = &params.fulfillment_order_line_items {
             query_args.push((
                 "fulfillment_order_line_items".to_string(),
                 fulfillment_order_line_items.to_string(),
             ));
         }
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6031,6 +7365,33 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_fulfillment_orders_param_order_request_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_create_fulfillment_orders_param_order_request_with` instead"
+    )]
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params =
+            ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestParams {
+                fulfillment_order_line_items: Some(fulfillment_order_line_items.to_string())
+                    .filter(|s| !s.is_empty()),
+                message: Some(message.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202101_create_fulfillment_orders_param_order_request_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -6041,16 +7402,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the fulfillment request.
+     * * `params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_request_accept(
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_request_accept_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6065,6 +7426,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_fulfillment_orders_param_order_request_accept_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_create_fulfillment_orders_param_order_request_accept_with` \
+                instead"
+    )]
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202101_create_fulfillment_orders_param_order_request_accept_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -6075,16 +7460,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the fulfillment request.
+     * * `params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_create_fulfillment_orders_param_order_request_reject(
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_request_reject_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6099,6 +7484,30 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_create_fulfillment_orders_param_order_request_reject_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_create_fulfillment_orders_param_order_request_reject_with` \
+                instead"
+    )]
+    pub async fn deprecated_202101_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_202101_create_fulfillment_orders_param_order_request_reject_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Sends a fulfillment request to the fulfillment service of a fulfillment order.
      *
@@ -6109,24 +7518,22 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional message for the fulfillment request.
-     * * `fulfillment_order_line_items: &str` -- The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+     * * `params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request(
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
-        fulfillment_order_line_items: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fulfillment_order_line_items.is_empty() {
+        if let Some(fulfillment_order_line_items) = &params.fulfillment_order_line_items {
             query_args.push((
                 "fulfillment_order_line_items".to_string(),
                 fulfillment_order_line_items.to_string(),
             ));
         }
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6141,6 +7548,32 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_create_fulfillment_orders_param_order_request_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_create_fulfillment_orders_param_order_request_with` \
+                instead"
+    )]
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        fulfillment_order_line_items: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestParams {
+fulfillment_order_line_items: Some(fulfillment_order_line_items.to_string()).filter(|s| !s.is_empty()),
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_unstable_create_fulfillment_orders_param_order_request_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Accepts a fulfillment request sent to a fulfillment service for a fulfillment order.
      *
@@ -6151,16 +7584,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for accepting the fulfillment request.
+     * * `params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request_accept(
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request_accept_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6176,8 +7609,31 @@ impl ShippingAndFulfillment {
     }
 
     /**
-     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
-     *
+     * Deprecated: use `deprecated_unstable_create_fulfillment_orders_param_order_request_accept_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_create_fulfillment_orders_param_order_request_accept_with` instead"
+    )]
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request_accept(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_unstable_create_fulfillment_orders_param_order_request_accept_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
+    /**
+     * Rejects a fulfillment request sent to a fulfillment service for a fulfillment order.
+     *
      * This function performs a `POST` to the `/admin/api/unstable/fulfillment_orders/{fulfillment_order_id}/fulfillment_request/reject.json` endpoint.
      *
      * https://shopify.dev/docs/admin-api/rest/reference/shipping-and-fulfillment/fulfillmentrequest#reject-unstable
@@ -6185,16 +7641,16 @@ impl ShippingAndFulfillment {
      * **Parameters:**
      *
      * * `fulfillment_order_id: &str` -- storefront_access_token_id.
-     * * `message: &str` -- An optional reason for rejecting the fulfillment request.
+     * * `params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request_reject(
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request_reject_with(
         &self,
         fulfillment_order_id: &str,
-        message: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectParams,
         body: &serde_json::Value,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !message.is_empty() {
+        if let Some(message) = &params.message {
             query_args.push(("message".to_string(), message.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6209,6 +7665,29 @@ impl ShippingAndFulfillment {
             .await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_create_fulfillment_orders_param_order_request_reject_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_create_fulfillment_orders_param_order_request_reject_with` instead"
+    )]
+    pub async fn deprecated_unstable_create_fulfillment_orders_param_order_request_reject(
+        &self,
+        fulfillment_order_id: &str,
+        message: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectParams {
+message: Some(message.to_string()).filter(|s| !s.is_empty()),
+};
+        self.deprecated_unstable_create_fulfillment_orders_param_order_request_reject_with(
+            fulfillment_order_id,
+            &params,
+            body,
+        )
+        .await
+    }
+
     /**
      * Get tracking numbers for orders.
      *
@@ -6218,19 +7697,17 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `order_names: &str` -- The fulfillment names we require tracking numbers for (i.e. #1001.1).
-     * * `shop: &str` -- The shop's myshopify url.
+     * * `params: &ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unknown_version_get_fetch_tracking_number(
+    pub async fn deprecated_unknown_version_get_fetch_tracking_number_with(
         &self,
-        order_names: &str,
-        shop: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !order_names.is_empty() {
+        if let Some(order_names) = &params.order_names {
             query_args.push(("order_names".to_string(), order_names.to_string()));
         }
-        if !shop.is_empty() {
+        if let Some(shop) = &params.shop {
             query_args.push(("shop".to_string(), shop.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6239,6 +7716,23 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unknown_version_get_fetch_tracking_number_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unknown_version_get_fetch_tracking_number_with` instead")]
+    pub async fn deprecated_unknown_version_get_fetch_tracking_number(
+        &self,
+        order_names: &str,
+        shop: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberParams {
+            order_names: Some(order_names.to_string()).filter(|s| !s.is_empty()),
+            shop: Some(shop.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unknown_version_get_fetch_tracking_number_with(&params)
+            .await
+    }
+
     /**
      * Get inventory levels.
      *
@@ -6248,29 +7742,23 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `max_retries: &str` -- The maximum amount of times Shopify will send the request for inventory levels.
-     * * `shop: &str` -- The shop's myshopify url.
-     * * `sku: &str` -- The SKU for the Product Variant we need stock levels for.
-     * * `timestamp: &str` -- The Unix timestamp from when the inventory request was made.
+     * * `params: &ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unknown_version_get_fetch_stock(
+    pub async fn deprecated_unknown_version_get_fetch_stock_with(
         &self,
-        max_retries: &str,
-        shop: &str,
-        sku: &str,
-        timestamp: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !max_retries.is_empty() {
+        if let Some(max_retries) = &params.max_retries {
             query_args.push(("max_retries".to_string(), max_retries.to_string()));
         }
-        if !shop.is_empty() {
+        if let Some(shop) = &params.shop {
             query_args.push(("shop".to_string(), shop.to_string()));
         }
-        if !sku.is_empty() {
+        if let Some(sku) = &params.sku {
             query_args.push(("sku".to_string(), sku.to_string()));
         }
-        if !timestamp.is_empty() {
+        if let Some(timestamp) = &params.timestamp {
             query_args.push(("timestamp".to_string(), timestamp.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6279,6 +7767,27 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unknown_version_get_fetch_stock_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unknown_version_get_fetch_stock_with` instead")]
+    pub async fn deprecated_unknown_version_get_fetch_stock(
+        &self,
+        max_retries: &str,
+        shop: &str,
+        sku: &str,
+        timestamp: &str,
+    ) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockParams {
+            max_retries: Some(max_retries.to_string()).filter(|s| !s.is_empty()),
+            shop: Some(shop.to_string()).filter(|s| !s.is_empty()),
+            sku: Some(sku.to_string()).filter(|s| !s.is_empty()),
+            timestamp: Some(timestamp.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unknown_version_get_fetch_stock_with(&params)
+            .await
+    }
+
     /**
      * .
      *
@@ -6288,12 +7797,14 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `scope: &str` -- current_client: Returns fulfillment providers that have been created by the app sending the request (default)
-     *                           all: Returns all the fulfillment providers.
+     * * `params: &ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !scope.is_empty() {
+        if let Some(scope) = &params.scope {
             query_args.push(("scope".to_string(), scope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6302,6 +7813,18 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_fulfillment_service_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_fulfillment_service_with` instead")]
+    pub async fn deprecated_202001_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceParams {
+            scope: Some(scope.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_fulfillment_service_with(&params)
+            .await
+    }
+
     /**
     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
               Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
@@ -6403,12 +7926,14 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `scope: &str` -- current_client: Returns fulfillment providers that have been created by the app sending the request (default)
-     *                           all: Returns all the fulfillment providers.
+     * * `params: &ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !scope.is_empty() {
+        if let Some(scope) = &params.scope {
             query_args.push(("scope".to_string(), scope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6417,6 +7942,18 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_fulfillment_service_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_fulfillment_service_with` instead")]
+    pub async fn deprecated_202004_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceParams {
+            scope: Some(scope.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_fulfillment_service_with(&params)
+            .await
+    }
+
     /**
     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
               Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
@@ -6518,12 +8055,14 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `scope: &str` -- current_client: Returns fulfillment providers that have been created by the app sending the request (default)
-     *                           all: Returns all the fulfillment providers.
+     * * `params: &ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !scope.is_empty() {
+        if let Some(scope) = &params.scope {
             query_args.push(("scope".to_string(), scope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6532,6 +8071,18 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_fulfillment_service_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_fulfillment_service_with` instead")]
+    pub async fn deprecated_202007_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceParams {
+            scope: Some(scope.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_fulfillment_service_with(&params)
+            .await
+    }
+
     /**
     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
               Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
@@ -6633,12 +8184,14 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `scope: &str` -- current_client: Returns fulfillment providers that have been created by the app sending the request (default)
-     *                           all: Returns all the fulfillment providers.
+     * * `params: &ShippingAndFulfillmentGetFulfillmentServiceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_fulfillment_service(&self, scope: &str) -> Result<()> {
+    pub async fn get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentGetFulfillmentServiceParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !scope.is_empty() {
+        if let Some(scope) = &params.scope {
             query_args.push(("scope".to_string(), scope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6647,6 +8200,17 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_fulfillment_service_with` and pass the query parameters as a `ShippingAndFulfillmentGetFulfillmentServiceParams` instead.
+     */
+    #[deprecated(note = "use `get_fulfillment_service_with` instead")]
+    pub async fn get_fulfillment_service(&self, scope: &str) -> Result<()> {
+        let params = ShippingAndFulfillmentGetFulfillmentServiceParams {
+            scope: Some(scope.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_fulfillment_service_with(&params).await
+    }
+
     /**
     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
               Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
@@ -6745,12 +8309,14 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `scope: &str` -- current_client: Returns fulfillment providers that have been created by the app sending the request (default)
-     *                           all: Returns all the fulfillment providers.
+     * * `params: &ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !scope.is_empty() {
+        if let Some(scope) = &params.scope {
             query_args.push(("scope".to_string(), scope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6759,6 +8325,18 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_fulfillment_service_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_fulfillment_service_with` instead")]
+    pub async fn deprecated_202101_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceParams {
+            scope: Some(scope.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_fulfillment_service_with(&params)
+            .await
+    }
+
     /**
     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
               Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
@@ -6860,12 +8438,14 @@ impl ShippingAndFulfillment {
      *
      * **Parameters:**
      *
-     * * `scope: &str` -- current_client: Returns fulfillment providers that have been created by the app sending the request (default)
-     *                           all: Returns all the fulfillment providers.
+     * * `params: &ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !scope.is_empty() {
+        if let Some(scope) = &params.scope {
             query_args.push(("scope".to_string(), scope.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -6874,6 +8454,18 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_fulfillment_service_with` and pass the query parameters as a `ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_fulfillment_service_with` instead")]
+    pub async fn deprecated_unstable_get_fulfillment_service(&self, scope: &str) -> Result<()> {
+        let params = ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceParams {
+            scope: Some(scope.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_fulfillment_service_with(&params)
+            .await
+    }
+
     /**
     * To create a fulfillment service, you can also use a cURL request that uses that fulfillment_service.json payload:
               Copy  curl -X POST -d @fulfillment_service.json -H"Accept:application/json" -H"Content-Type:application/json" -H"X-Shopify-Access-Token:THE_TOKEN_GOES_HERE" https://AUTHORIZED_SHOP.myshopify.com/admin/fulfillment_services
@@ -7116,3 +8708,5754 @@ impl ShippingAndFulfillment {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202001_get_assigned_fulfillment_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderParams {
+    /// The assigment status of the fulfillment orders that should be returned:
+    ///
+    /// cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
+    /// fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
+    /// fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
+    pub assignment_status: Option<String>,
+    /// recurring_application_charge[capped_amount].
+    pub location_ids: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderParams {
+    pub fn assignment_status<T: ToString>(mut self, assignment_status: T) -> Self {
+        self.assignment_status = Some(assignment_status.to_string());
+        self
+    }
+
+    pub fn location_ids<T: ToString>(mut self, location_ids: T) -> Self {
+        self.location_ids = Some(location_ids.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202004_get_assigned_fulfillment_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderParams {
+    /// The assigment status of the fulfillment orders that should be returned:
+    ///
+    /// cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
+    /// fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
+    /// fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
+    pub assignment_status: Option<String>,
+    /// recurring_application_charge[capped_amount].
+    pub location_ids: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderParams {
+    pub fn assignment_status<T: ToString>(mut self, assignment_status: T) -> Self {
+        self.assignment_status = Some(assignment_status.to_string());
+        self
+    }
+
+    pub fn location_ids<T: ToString>(mut self, location_ids: T) -> Self {
+        self.location_ids = Some(location_ids.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202007_get_assigned_fulfillment_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderParams {
+    /// The assigment status of the fulfillment orders that should be returned:
+    ///
+    /// cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
+    /// fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
+    /// fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
+    pub assignment_status: Option<String>,
+    /// recurring_application_charge[capped_amount].
+    pub location_ids: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderParams {
+    pub fn assignment_status<T: ToString>(mut self, assignment_status: T) -> Self {
+        self.assignment_status = Some(assignment_status.to_string());
+        self
+    }
+
+    pub fn location_ids<T: ToString>(mut self, location_ids: T) -> Self {
+        self.location_ids = Some(location_ids.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::get_assigned_fulfillment_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentGetAssignedFulfillmentOrderParams {
+    /// The assigment status of the fulfillment orders that should be returned:
+    ///
+    /// cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
+    /// fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
+    /// fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
+    pub assignment_status: Option<String>,
+    /// recurring_application_charge[capped_amount].
+    pub location_ids: Option<String>,
+}
+
+impl ShippingAndFulfillmentGetAssignedFulfillmentOrderParams {
+    pub fn assignment_status<T: ToString>(mut self, assignment_status: T) -> Self {
+        self.assignment_status = Some(assignment_status.to_string());
+        self
+    }
+
+    pub fn location_ids<T: ToString>(mut self, location_ids: T) -> Self {
+        self.location_ids = Some(location_ids.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202101_get_assigned_fulfillment_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderParams {
+    /// The assigment status of the fulfillment orders that should be returned:
+    ///
+    /// cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
+    /// fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
+    /// fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
+    pub assignment_status: Option<String>,
+    /// recurring_application_charge[capped_amount].
+    pub location_ids: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderParams {
+    pub fn assignment_status<T: ToString>(mut self, assignment_status: T) -> Self {
+        self.assignment_status = Some(assignment_status.to_string());
+        self
+    }
+
+    pub fn location_ids<T: ToString>(mut self, location_ids: T) -> Self {
+        self.location_ids = Some(location_ids.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_get_assigned_fulfillment_order_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderParams {
+    /// The assigment status of the fulfillment orders that should be returned:
+    ///
+    /// cancellation_requested: Fulfillment orders for which the merchant has requested cancellation of the previously accepted fulfillment request.
+    /// fulfillment_requested: Fulfillment orders for which the merchant has requested fulfillment.
+    /// fulfillment_accepted: Fulfillment orders for which the merchant's fulfillment request has been accepted. Any number of fulfillments can be created on these fulfillment orders to completely fulfill the requested items.
+    pub assignment_status: Option<String>,
+    /// recurring_application_charge[capped_amount].
+    pub location_ids: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderParams {
+    pub fn assignment_status<T: ToString>(mut self, assignment_status: T) -> Self {
+        self.assignment_status = Some(assignment_status.to_string());
+        self
+    }
+
+    pub fn location_ids<T: ToString>(mut self, location_ids: T) -> Self {
+        self.location_ids = Some(location_ids.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestParams
+{
+    /// An optional reason for the cancellation request.
+    pub message: Option<String>,
+}
+
+impl
+    ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestParams
+{
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams
+{
+    /// An optional reason for accepting the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams {
+
+pub fn message<T: ToString>(mut self, message: T) -> Self {
+    self.message = Some(message.to_string());
+    self
+}
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams
+{
+    /// An optional reason for rejecting the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams {
+
+pub fn message<T: ToString>(mut self, message: T) -> Self {
+    self.message = Some(message.to_string());
+    self
+}
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestParams
+{
+    /// An optional reason for the cancellation request.
+    pub message: Option<String>,
+}
+
+impl
+    ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestParams
+{
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams
+{
+    /// An optional reason for accepting the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams {
+
+pub fn message<T: ToString>(mut self, message: T) -> Self {
+    self.message = Some(message.to_string());
+    self
+}
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams
+{
+    /// An optional reason for rejecting the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams {
+
+pub fn message<T: ToString>(mut self, message: T) -> Self {
+    self.message = Some(message.to_string());
+    self
+}
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestParams
+{
+    /// An optional reason for the cancellation request.
+    pub message: Option<String>,
+}
+
+impl
+    ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestParams
+{
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams
+{
+    /// An optional reason for accepting the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams {
+
+pub fn message<T: ToString>(mut self, message: T) -> Self {
+    self.message = Some(message.to_string());
+    self
+}
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams
+{
+    /// An optional reason for rejecting the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams {
+
+pub fn message<T: ToString>(mut self, message: T) -> Self {
+    self.message = Some(message.to_string());
+    self
+}
+}
+
+/// The query parameters of `ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestParams {
+    /// An optional reason for the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_accept_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams {
+    /// An optional reason for accepting the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_reject_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams {
+    /// An optional reason for rejecting the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestParams
+{
+    /// An optional reason for the cancellation request.
+    pub message: Option<String>,
+}
+
+impl
+    ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestParams
+{
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams
+{
+    /// An optional reason for accepting the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams {
+
+pub fn message<T: ToString>(mut self, message: T) -> Self {
+    self.message = Some(message.to_string());
+    self
+}
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams
+{
+    /// An optional reason for rejecting the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams {
+
+pub fn message<T: ToString>(mut self, message: T) -> Self {
+    self.message = Some(message.to_string());
+    self
+}
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestParams
+{
+    /// An optional reason for the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestParams {
+
+pub fn message<T: ToString>(mut self, message: T) -> Self {
+    self.message = Some(message.to_string());
+    self
+}
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams
+{
+    /// An optional reason for accepting the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams {
+
+pub fn message<T: ToString>(mut self, message: T) -> Self {
+    self.message = Some(message.to_string());
+    self
+}
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams
+{
+    /// An optional reason for rejecting the cancellation request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams {
+
+pub fn message<T: ToString>(mut self, message: T) -> Self {
+    self.message = Some(message.to_string());
+    self
+}
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentParams {
+    /// Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Limit the amount of results.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountParams {
+    /// Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentParams {
+    /// Comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentParams {
+    /// Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Limit the amount of results.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountParams {
+    /// Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentParams {
+    /// Comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentParams {
+    /// Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Limit the amount of results.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountParams {
+    /// Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentParams {
+    /// Comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::get_orders_param_order_fulfillment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentGetOrdersParamOrderFulfillmentParams {
+    /// Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Limit the amount of results.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ShippingAndFulfillmentGetOrdersParamOrderFulfillmentParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::get_orders_param_order_fulfillments_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountParams {
+    /// Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentParams {
+    /// Comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentParams {
+    /// Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Limit the amount of results.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountParams {
+    /// Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentParams {
+    /// Comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentParams {
+    /// Show fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Show fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+    /// Limit the amount of results.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+    /// Show fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Show fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_count_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountParams {
+    /// Count fulfillments created before date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_max: Option<String>,
+    /// Count fulfillments created after date (format: 2014-04-25T16:15:47-04:00).
+    pub created_at_min: Option<String>,
+    /// Count fulfillments last updated before date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_max: Option<String>,
+    /// Count fulfillments last updated after date (format: 2014-04-25T16:15:47-04:00).
+    pub updated_at_min: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountParams {
+    pub fn created_at_max<T: ToString>(mut self, created_at_max: T) -> Self {
+        self.created_at_max = Some(created_at_max.to_string());
+        self
+    }
+
+    pub fn created_at_min<T: ToString>(mut self, created_at_min: T) -> Self {
+        self.created_at_min = Some(created_at_min.to_string());
+        self
+    }
+
+    pub fn updated_at_max<T: ToString>(mut self, updated_at_max: T) -> Self {
+        self.updated_at_max = Some(updated_at_max.to_string());
+        self
+    }
+
+    pub fn updated_at_min<T: ToString>(mut self, updated_at_min: T) -> Self {
+        self.updated_at_min = Some(updated_at_min.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentParams
+{
+    /// Comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_close_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseParams {
+    /// An optional reason for marking the fulfillment order as incomplete.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_move_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveParams {
+    /// The id of the location to which the fulfillment order will be moved.
+    pub new_location_id: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveParams {
+    pub fn new_location_id<T: ToString>(mut self, new_location_id: T) -> Self {
+        self.new_location_id = Some(new_location_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_close_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseParams {
+    /// An optional reason for marking the fulfillment order as incomplete.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_move_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveParams {
+    /// The id of the location to which the fulfillment order will be moved.
+    pub new_location_id: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveParams {
+    pub fn new_location_id<T: ToString>(mut self, new_location_id: T) -> Self {
+        self.new_location_id = Some(new_location_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_close_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseParams {
+    /// An optional reason for marking the fulfillment order as incomplete.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_move_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveParams {
+    /// The id of the location to which the fulfillment order will be moved.
+    pub new_location_id: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveParams {
+    pub fn new_location_id<T: ToString>(mut self, new_location_id: T) -> Self {
+        self.new_location_id = Some(new_location_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::create_fulfillment_orders_param_order_close_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseParams {
+    /// An optional reason for marking the fulfillment order as incomplete.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::create_fulfillment_orders_param_order_move_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveParams {
+    /// The id of the location to which the fulfillment order will be moved.
+    pub new_location_id: Option<String>,
+}
+
+impl ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveParams {
+    pub fn new_location_id<T: ToString>(mut self, new_location_id: T) -> Self {
+        self.new_location_id = Some(new_location_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_close_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseParams {
+    /// An optional reason for marking the fulfillment order as incomplete.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_move_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveParams {
+    /// The id of the location to which the fulfillment order will be moved.
+    pub new_location_id: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveParams {
+    pub fn new_location_id<T: ToString>(mut self, new_location_id: T) -> Self {
+        self.new_location_id = Some(new_location_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_release_hold_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldParams {
+    /// The ID of the order that is associated to the fulfillment orders.
+    pub order_id: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldParams {
+    pub fn order_id<T: ToString>(mut self, order_id: T) -> Self {
+        self.order_id = Some(order_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_close_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseParams {
+    /// An optional reason for marking the fulfillment order as incomplete.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_move_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveParams {
+    /// The id of the location to which the fulfillment order will be moved.
+    pub new_location_id: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveParams {
+    pub fn new_location_id<T: ToString>(mut self, new_location_id: T) -> Self {
+        self.new_location_id = Some(new_location_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestParams {
+    /// The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+    pub fulfillment_order_line_items: Option<String>,
+    /// An optional message for the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestParams {
+    pub fn fulfillment_order_line_items<T: ToString>(
+        mut self,
+        fulfillment_order_line_items: T,
+    ) -> Self {
+        self.fulfillment_order_line_items = Some(fulfillment_order_line_items.to_string());
+        self
+    }
+
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_accept_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptParams
+{
+    /// An optional reason for accepting the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_reject_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectParams
+{
+    /// An optional reason for rejecting the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestParams {
+    /// The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+    pub fulfillment_order_line_items: Option<String>,
+    /// An optional message for the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestParams {
+    pub fn fulfillment_order_line_items<T: ToString>(
+        mut self,
+        fulfillment_order_line_items: T,
+    ) -> Self {
+        self.fulfillment_order_line_items = Some(fulfillment_order_line_items.to_string());
+        self
+    }
+
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_accept_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptParams
+{
+    /// An optional reason for accepting the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_reject_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectParams
+{
+    /// An optional reason for rejecting the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestParams {
+    /// The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+    pub fulfillment_order_line_items: Option<String>,
+    /// An optional message for the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestParams {
+    pub fn fulfillment_order_line_items<T: ToString>(
+        mut self,
+        fulfillment_order_line_items: T,
+    ) -> Self {
+        self.fulfillment_order_line_items = Some(fulfillment_order_line_items.to_string());
+        self
+    }
+
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_accept_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptParams
+{
+    /// An optional reason for accepting the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_reject_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectParams
+{
+    /// An optional reason for rejecting the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::create_fulfillment_orders_param_order_request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestParams {
+    /// The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+    pub fulfillment_order_line_items: Option<String>,
+    /// An optional message for the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestParams {
+    pub fn fulfillment_order_line_items<T: ToString>(
+        mut self,
+        fulfillment_order_line_items: T,
+    ) -> Self {
+        self.fulfillment_order_line_items = Some(fulfillment_order_line_items.to_string());
+        self
+    }
+
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::create_fulfillment_orders_param_order_request_accept_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptParams {
+    /// An optional reason for accepting the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::create_fulfillment_orders_param_order_request_reject_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectParams {
+    /// An optional reason for rejecting the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestParams {
+    /// The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+    pub fulfillment_order_line_items: Option<String>,
+    /// An optional message for the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestParams {
+    pub fn fulfillment_order_line_items<T: ToString>(
+        mut self,
+        fulfillment_order_line_items: T,
+    ) -> Self {
+        self.fulfillment_order_line_items = Some(fulfillment_order_line_items.to_string());
+        self
+    }
+
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_accept_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptParams
+{
+    /// An optional reason for accepting the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_reject_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectParams
+{
+    /// An optional reason for rejecting the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestParams {
+    /// The fulfillment order line items to be requested for fulfillment. If left blank, all line items of the fulfillment order are requested for fulfillment.
+    pub fulfillment_order_line_items: Option<String>,
+    /// An optional message for the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestParams {
+    pub fn fulfillment_order_line_items<T: ToString>(
+        mut self,
+        fulfillment_order_line_items: T,
+    ) -> Self {
+        self.fulfillment_order_line_items = Some(fulfillment_order_line_items.to_string());
+        self
+    }
+
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_accept_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptParams
+{
+    /// An optional reason for accepting the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_reject_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectParams
+{
+    /// An optional reason for rejecting the fulfillment request.
+    pub message: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectParams {
+    pub fn message<T: ToString>(mut self, message: T) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unknown_version_get_fetch_tracking_number_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberParams {
+    /// The fulfillment names we require tracking numbers for (i.e. #1001.1).
+    pub order_names: Option<String>,
+    /// The shop's myshopify url.
+    pub shop: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberParams {
+    pub fn order_names<T: ToString>(mut self, order_names: T) -> Self {
+        self.order_names = Some(order_names.to_string());
+        self
+    }
+
+    pub fn shop<T: ToString>(mut self, shop: T) -> Self {
+        self.shop = Some(shop.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unknown_version_get_fetch_stock_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockParams {
+    /// The maximum amount of times Shopify will send the request for inventory levels.
+    pub max_retries: Option<String>,
+    /// The shop's myshopify url.
+    pub shop: Option<String>,
+    /// The SKU for the Product Variant we need stock levels for.
+    pub sku: Option<String>,
+    /// The Unix timestamp from when the inventory request was made.
+    pub timestamp: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockParams {
+    pub fn max_retries<T: ToString>(mut self, max_retries: T) -> Self {
+        self.max_retries = Some(max_retries.to_string());
+        self
+    }
+
+    pub fn shop<T: ToString>(mut self, shop: T) -> Self {
+        self.shop = Some(shop.to_string());
+        self
+    }
+
+    pub fn sku<T: ToString>(mut self, sku: T) -> Self {
+        self.sku = Some(sku.to_string());
+        self
+    }
+
+    pub fn timestamp<T: ToString>(mut self, timestamp: T) -> Self {
+        self.timestamp = Some(timestamp.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202001_get_fulfillment_service_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceParams {
+    /// current_client: Returns fulfillment providers that have been created by the app sending the request (default)
+    /// all: Returns all the fulfillment providers.
+    pub scope: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceParams {
+    pub fn scope<T: ToString>(mut self, scope: T) -> Self {
+        self.scope = Some(scope.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202004_get_fulfillment_service_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceParams {
+    /// current_client: Returns fulfillment providers that have been created by the app sending the request (default)
+    /// all: Returns all the fulfillment providers.
+    pub scope: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceParams {
+    pub fn scope<T: ToString>(mut self, scope: T) -> Self {
+        self.scope = Some(scope.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202007_get_fulfillment_service_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceParams {
+    /// current_client: Returns fulfillment providers that have been created by the app sending the request (default)
+    /// all: Returns all the fulfillment providers.
+    pub scope: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceParams {
+    pub fn scope<T: ToString>(mut self, scope: T) -> Self {
+        self.scope = Some(scope.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::get_fulfillment_service_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentGetFulfillmentServiceParams {
+    /// current_client: Returns fulfillment providers that have been created by the app sending the request (default)
+    /// all: Returns all the fulfillment providers.
+    pub scope: Option<String>,
+}
+
+impl ShippingAndFulfillmentGetFulfillmentServiceParams {
+    pub fn scope<T: ToString>(mut self, scope: T) -> Self {
+        self.scope = Some(scope.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_202101_get_fulfillment_service_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceParams {
+    /// current_client: Returns fulfillment providers that have been created by the app sending the request (default)
+    /// all: Returns all the fulfillment providers.
+    pub scope: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceParams {
+    pub fn scope<T: ToString>(mut self, scope: T) -> Self {
+        self.scope = Some(scope.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShippingAndFulfillment::deprecated_unstable_get_fulfillment_service_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceParams {
+    /// current_client: Returns fulfillment providers that have been created by the app sending the request (default)
+    /// all: Returns all the fulfillment providers.
+    pub scope: Option<String>,
+}
+
+impl ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceParams {
+    pub fn scope<T: ToString>(mut self, scope: T) -> Self {
+        self.scope = Some(scope.to_string());
+        self
+    }
+}
+
+/// The functions of [`ShippingAndFulfillment`], for code that takes them as a trait, so that it can be
+/// tested with the `MockShippingAndFulfillmentApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ShippingAndFulfillmentApi: Send + Sync {
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_assigned_fulfillment_order_with`].
+    async fn deprecated_202001_get_assigned_fulfillment_order_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_assigned_fulfillment_order_with`].
+    async fn deprecated_202004_get_assigned_fulfillment_order_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_assigned_fulfillment_order_with`].
+    async fn deprecated_202007_get_assigned_fulfillment_order_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_assigned_fulfillment_order_with`].
+    async fn get_assigned_fulfillment_order_with(
+        &self,
+        params: &ShippingAndFulfillmentGetAssignedFulfillmentOrderParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_assigned_fulfillment_order_with`].
+    async fn deprecated_202101_get_assigned_fulfillment_order_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_assigned_fulfillment_order_with`].
+    async fn deprecated_unstable_get_assigned_fulfillment_order_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_with`].
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept_with`].
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject_with`].
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_with`].
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept_with`].
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject_with`].
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_with`].
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept_with`].
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject_with`].
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_with`].
+    async fn create_fulfillment_orders_param_order_cancellation_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_accept_with`].
+    async fn create_fulfillment_orders_param_order_cancellation_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_reject_with`].
+    async fn create_fulfillment_orders_param_order_cancellation_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_with`].
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept_with`].
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject_with`].
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_with`].
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept_with`].
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject_with`].
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_carrier_service`].
+    async fn deprecated_202001_get_carrier_service(&self) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_carrier_services`].
+    async fn deprecated_202001_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_carrier_services_param_service`].
+    async fn deprecated_202001_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_update_carrier_services_param_service`].
+    async fn deprecated_202001_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_delete_carrier_services_param_service`].
+    async fn deprecated_202001_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_carrier_service`].
+    async fn deprecated_202004_get_carrier_service(&self) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_carrier_services`].
+    async fn deprecated_202004_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_carrier_services_param_service`].
+    async fn deprecated_202004_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_update_carrier_services_param_service`].
+    async fn deprecated_202004_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_delete_carrier_services_param_service`].
+    async fn deprecated_202004_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_carrier_service`].
+    async fn deprecated_202007_get_carrier_service(&self) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_carrier_services`].
+    async fn deprecated_202007_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_carrier_services_param_service`].
+    async fn deprecated_202007_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_update_carrier_services_param_service`].
+    async fn deprecated_202007_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_delete_carrier_services_param_service`].
+    async fn deprecated_202007_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_carrier_service`].
+    async fn get_carrier_service(&self) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_carrier_services`].
+    async fn create_carrier_services(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_carrier_services_param_service`].
+    async fn get_carrier_services_param_service(&self, carrier_service_id: &str) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::update_carrier_services_param_service`].
+    async fn update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::delete_carrier_services_param_service`].
+    async fn delete_carrier_services_param_service(&self, carrier_service_id: &str) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_carrier_service`].
+    async fn deprecated_202101_get_carrier_service(&self) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_carrier_services`].
+    async fn deprecated_202101_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_carrier_services_param_service`].
+    async fn deprecated_202101_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_update_carrier_services_param_service`].
+    async fn deprecated_202101_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_delete_carrier_services_param_service`].
+    async fn deprecated_202101_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_carrier_service`].
+    async fn deprecated_unstable_get_carrier_service(&self) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_carrier_services`].
+    async fn deprecated_unstable_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_carrier_services_param_service`].
+    async fn deprecated_unstable_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_update_carrier_services_param_service`].
+    async fn deprecated_unstable_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_delete_carrier_services_param_service`].
+    async fn deprecated_unstable_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillment_with`].
+    async fn deprecated_202001_get_orders_param_order_fulfillment_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments`].
+    async fn deprecated_202001_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_fulfillment_orders_param_order_fulfillment`].
+    async fn deprecated_202001_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_count_with`].
+    async fn deprecated_202001_get_orders_param_order_fulfillments_count_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment_with`].
+    async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_with(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_update_orders_param_order_fulfillments_fulfillment`].
+    async fn deprecated_202001_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_fulfillments`].
+    async fn deprecated_202001_create_fulfillments(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_fulfillments_param_fulfillment_update_tracking`].
+    async fn deprecated_202001_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_complete`].
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_open`].
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_cancel`].
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_fulfillments_param_fulfillment_cancel`].
+    async fn deprecated_202001_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillment_with`].
+    async fn deprecated_202004_get_orders_param_order_fulfillment_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments`].
+    async fn deprecated_202004_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_fulfillment_orders_param_order_fulfillment`].
+    async fn deprecated_202004_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_count_with`].
+    async fn deprecated_202004_get_orders_param_order_fulfillments_count_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment_with`].
+    async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_with(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_update_orders_param_order_fulfillments_fulfillment`].
+    async fn deprecated_202004_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_fulfillments`].
+    async fn deprecated_202004_create_fulfillments(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_fulfillments_param_fulfillment_update_tracking`].
+    async fn deprecated_202004_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_complete`].
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_open`].
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_cancel`].
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_fulfillments_param_fulfillment_cancel`].
+    async fn deprecated_202004_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillment_with`].
+    async fn deprecated_202007_get_orders_param_order_fulfillment_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments`].
+    async fn deprecated_202007_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_fulfillment_orders_param_order_fulfillment`].
+    async fn deprecated_202007_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_count_with`].
+    async fn deprecated_202007_get_orders_param_order_fulfillments_count_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment_with`].
+    async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_with(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_update_orders_param_order_fulfillments_fulfillment`].
+    async fn deprecated_202007_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_fulfillments`].
+    async fn deprecated_202007_create_fulfillments(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_fulfillments_param_fulfillment_update_tracking`].
+    async fn deprecated_202007_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_complete`].
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_open`].
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_cancel`].
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_fulfillments_param_fulfillment_cancel`].
+    async fn deprecated_202007_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_orders_param_order_fulfillment_with`].
+    async fn get_orders_param_order_fulfillment_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentGetOrdersParamOrderFulfillmentParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_orders_param_order_fulfillments`].
+    async fn create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_fulfillment_orders_param_order_fulfillment`].
+    async fn get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_orders_param_order_fulfillments_count_with`].
+    async fn get_orders_param_order_fulfillments_count_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_with`].
+    async fn get_orders_param_order_fulfillments_fulfillment_with(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        params: &ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::update_orders_param_order_fulfillments_fulfillment`].
+    async fn update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_fulfillments`].
+    async fn create_fulfillments(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_fulfillments_param_fulfillment_update_tracking`].
+    async fn create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_complete`].
+    async fn create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_open`].
+    async fn create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_cancel`].
+    async fn create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_fulfillments_param_fulfillment_cancel`].
+    async fn create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillment_with`].
+    async fn deprecated_202101_get_orders_param_order_fulfillment_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments`].
+    async fn deprecated_202101_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_fulfillment_orders_param_order_fulfillment`].
+    async fn deprecated_202101_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_count_with`].
+    async fn deprecated_202101_get_orders_param_order_fulfillments_count_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment_with`].
+    async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_with(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_update_orders_param_order_fulfillments_fulfillment`].
+    async fn deprecated_202101_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillments`].
+    async fn deprecated_202101_create_fulfillments(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillments_param_fulfillment_update_tracking`].
+    async fn deprecated_202101_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_complete`].
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_open`].
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_cancel`].
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillments_param_fulfillment_cancel`].
+    async fn deprecated_202101_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillment_with`].
+    async fn deprecated_unstable_get_orders_param_order_fulfillment_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments`].
+    async fn deprecated_unstable_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_fulfillment_orders_param_order_fulfillment`].
+    async fn deprecated_unstable_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_count_with`].
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_count_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_with`].
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_with(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_update_orders_param_order_fulfillments_fulfillment`].
+    async fn deprecated_unstable_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillments`].
+    async fn deprecated_unstable_create_fulfillments(&self, body: &serde_json::Value)
+        -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillments_param_fulfillment_update_tracking`].
+    async fn deprecated_unstable_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_complete`].
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_open`].
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_cancel`].
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillments_param_fulfillment_cancel`].
+    async fn deprecated_unstable_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment_event`].
+    async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_events`].
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment_events_event`].
+    async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event`].
+    async fn deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment_event`].
+    async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_events`].
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment_events_event`].
+    async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event`].
+    async fn deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment_event`].
+    async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_events`].
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment_events_event`].
+    async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event`].
+    async fn deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_event`].
+    async fn get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_events`].
+    async fn create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_events_event`].
+    async fn get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::delete_orders_param_order_fulfillments_fulfillment_events_event`].
+    async fn delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment_event`].
+    async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_events`].
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment_events_event`].
+    async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event`].
+    async fn deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_event`].
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_events`].
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_events_event`].
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event`].
+    async fn deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillment_shipping_and_fulfillment`].
+    async fn deprecated_202001_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_fulfillment_orders_param_order`].
+    async fn deprecated_202001_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancel`].
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_close_with`].
+    async fn deprecated_202001_create_fulfillment_orders_param_order_close_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_move_with`].
+    async fn deprecated_202001_create_fulfillment_orders_param_order_move_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillment_shipping_and_fulfillment`].
+    async fn deprecated_202004_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_fulfillment_orders_param_order`].
+    async fn deprecated_202004_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancel`].
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_close_with`].
+    async fn deprecated_202004_create_fulfillment_orders_param_order_close_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_move_with`].
+    async fn deprecated_202004_create_fulfillment_orders_param_order_move_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillment_shipping_and_fulfillment`].
+    async fn deprecated_202007_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_fulfillment_orders_param_order`].
+    async fn deprecated_202007_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancel`].
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_close_with`].
+    async fn deprecated_202007_create_fulfillment_orders_param_order_close_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_move_with`].
+    async fn deprecated_202007_create_fulfillment_orders_param_order_move_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_orders_param_order_fulfillment_shipping_and_fulfillment`].
+    async fn get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_fulfillment_orders_param_order`].
+    async fn get_fulfillment_orders_param_order(&self, fulfillment_order_id: &str) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_fulfillment_orders_param_order_cancel`].
+    async fn create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_fulfillment_orders_param_order_close_with`].
+    async fn create_fulfillment_orders_param_order_close_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_fulfillment_orders_param_order_move_with`].
+    async fn create_fulfillment_orders_param_order_move_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillment_shipping_and_fulfillment`].
+    async fn deprecated_202101_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_fulfillment_orders_param_order`].
+    async fn deprecated_202101_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancel`].
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_close_with`].
+    async fn deprecated_202101_create_fulfillment_orders_param_order_close_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_move_with`].
+    async fn deprecated_202101_create_fulfillment_orders_param_order_move_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_open`].
+    async fn deprecated_202101_create_fulfillment_orders_param_order_open(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_reschedule`].
+    async fn deprecated_202101_create_fulfillment_orders_param_order_reschedule(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillment_shipping_and_fulfillment`].
+    async fn deprecated_unstable_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_fulfillment_orders_param_order`].
+    async fn deprecated_unstable_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancel`].
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_release_hold_with`].
+    async fn deprecated_unstable_create_fulfillment_orders_release_hold_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_close_with`].
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_close_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_move_with`].
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_move_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_open`].
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_open(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_reschedule`].
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_reschedule(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_with`].
+    async fn deprecated_202001_create_fulfillment_orders_param_order_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_accept_with`].
+    async fn deprecated_202001_create_fulfillment_orders_param_order_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_reject_with`].
+    async fn deprecated_202001_create_fulfillment_orders_param_order_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_with`].
+    async fn deprecated_202004_create_fulfillment_orders_param_order_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_accept_with`].
+    async fn deprecated_202004_create_fulfillment_orders_param_order_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_reject_with`].
+    async fn deprecated_202004_create_fulfillment_orders_param_order_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_with`].
+    async fn deprecated_202007_create_fulfillment_orders_param_order_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_accept_with`].
+    async fn deprecated_202007_create_fulfillment_orders_param_order_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_reject_with`].
+    async fn deprecated_202007_create_fulfillment_orders_param_order_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_fulfillment_orders_param_order_request_with`].
+    async fn create_fulfillment_orders_param_order_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_fulfillment_orders_param_order_request_accept_with`].
+    async fn create_fulfillment_orders_param_order_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_fulfillment_orders_param_order_request_reject_with`].
+    async fn create_fulfillment_orders_param_order_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_with`].
+    async fn deprecated_202101_create_fulfillment_orders_param_order_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_accept_with`].
+    async fn deprecated_202101_create_fulfillment_orders_param_order_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_reject_with`].
+    async fn deprecated_202101_create_fulfillment_orders_param_order_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_with`].
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_accept_with`].
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_reject_with`].
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unknown_version_get_fetch_tracking_number_with`].
+    async fn deprecated_unknown_version_get_fetch_tracking_number_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unknown_version_get_fetch_stock_with`].
+    async fn deprecated_unknown_version_get_fetch_stock_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_fulfillment_service_with`].
+    async fn deprecated_202001_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_create_fulfillment_services`].
+    async fn deprecated_202001_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_fulfillment_services_param_service`].
+    async fn deprecated_202001_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_update_fulfillment_services_param_service`].
+    async fn deprecated_202001_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_delete_fulfillment_services_param_service`].
+    async fn deprecated_202001_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_fulfillment_service_with`].
+    async fn deprecated_202004_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_create_fulfillment_services`].
+    async fn deprecated_202004_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_fulfillment_services_param_service`].
+    async fn deprecated_202004_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_update_fulfillment_services_param_service`].
+    async fn deprecated_202004_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_delete_fulfillment_services_param_service`].
+    async fn deprecated_202004_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_fulfillment_service_with`].
+    async fn deprecated_202007_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_create_fulfillment_services`].
+    async fn deprecated_202007_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_fulfillment_services_param_service`].
+    async fn deprecated_202007_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_update_fulfillment_services_param_service`].
+    async fn deprecated_202007_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_delete_fulfillment_services_param_service`].
+    async fn deprecated_202007_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_fulfillment_service_with`].
+    async fn get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentGetFulfillmentServiceParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::create_fulfillment_services`].
+    async fn create_fulfillment_services(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_fulfillment_services_param_service`].
+    async fn get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::update_fulfillment_services_param_service`].
+    async fn update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::delete_fulfillment_services_param_service`].
+    async fn delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_fulfillment_service_with`].
+    async fn deprecated_202101_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_create_fulfillment_services`].
+    async fn deprecated_202101_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_fulfillment_services_param_service`].
+    async fn deprecated_202101_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_update_fulfillment_services_param_service`].
+    async fn deprecated_202101_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_delete_fulfillment_services_param_service`].
+    async fn deprecated_202101_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_fulfillment_service_with`].
+    async fn deprecated_unstable_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceParams,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_create_fulfillment_services`].
+    async fn deprecated_unstable_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_fulfillment_services_param_service`].
+    async fn deprecated_unstable_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_update_fulfillment_services_param_service`].
+    async fn deprecated_unstable_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_delete_fulfillment_services_param_service`].
+    async fn deprecated_unstable_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202001_get_fulfillment_orders_param_order_locations_for_move`].
+    async fn deprecated_202001_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202004_get_fulfillment_orders_param_order_locations_for_move`].
+    async fn deprecated_202004_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202007_get_fulfillment_orders_param_order_locations_for_move`].
+    async fn deprecated_202007_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::get_fulfillment_orders_param_order_locations_for_move`].
+    async fn get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_202101_get_fulfillment_orders_param_order_locations_for_move`].
+    async fn deprecated_202101_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+
+    /// See [`ShippingAndFulfillment::deprecated_unstable_get_fulfillment_orders_param_order_locations_for_move`].
+    async fn deprecated_unstable_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ShippingAndFulfillmentApi for ShippingAndFulfillment {
+    async fn deprecated_202001_get_assigned_fulfillment_order_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202001GetAssignedFulfillmentOrderParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_assigned_fulfillment_order_with(self, params)
+            .await
+    }
+
+    async fn deprecated_202004_get_assigned_fulfillment_order_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202004GetAssignedFulfillmentOrderParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_assigned_fulfillment_order_with(self, params)
+            .await
+    }
+
+    async fn deprecated_202007_get_assigned_fulfillment_order_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202007GetAssignedFulfillmentOrderParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_assigned_fulfillment_order_with(self, params)
+            .await
+    }
+
+    async fn get_assigned_fulfillment_order_with(
+        &self,
+        params: &ShippingAndFulfillmentGetAssignedFulfillmentOrderParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_assigned_fulfillment_order_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_assigned_fulfillment_order_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202101GetAssignedFulfillmentOrderParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_assigned_fulfillment_order_with(self, params)
+            .await
+    }
+
+    async fn deprecated_unstable_get_assigned_fulfillment_order_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetAssignedFulfillmentOrderParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_assigned_fulfillment_order_with(
+            self, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_accept_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancellation_request_reject_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_accept_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancellation_request_reject_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_accept_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancellation_request_reject_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn create_fulfillment_orders_param_order_cancellation_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn create_fulfillment_orders_param_order_cancellation_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_accept_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn create_fulfillment_orders_param_order_cancellation_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_cancellation_request_reject_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_accept_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancellation_request_reject_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_accept_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCancellationRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancellation_request_reject_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202001_get_carrier_service(&self) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_carrier_service(self).await
+    }
+
+    async fn deprecated_202001_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_carrier_services(self, body).await
+    }
+
+    async fn deprecated_202001_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_update_carrier_services_param_service(
+            self,
+            carrier_service_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_delete_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_carrier_service(&self) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_carrier_service(self).await
+    }
+
+    async fn deprecated_202004_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_carrier_services(self, body).await
+    }
+
+    async fn deprecated_202004_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_update_carrier_services_param_service(
+            self,
+            carrier_service_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_delete_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_carrier_service(&self) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_carrier_service(self).await
+    }
+
+    async fn deprecated_202007_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_carrier_services(self, body).await
+    }
+
+    async fn deprecated_202007_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_update_carrier_services_param_service(
+            self,
+            carrier_service_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_delete_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+
+    async fn get_carrier_service(&self) -> Result<()> {
+        ShippingAndFulfillment::get_carrier_service(self).await
+    }
+
+    async fn create_carrier_services(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::create_carrier_services(self, body).await
+    }
+
+    async fn get_carrier_services_param_service(&self, carrier_service_id: &str) -> Result<()> {
+        ShippingAndFulfillment::get_carrier_services_param_service(self, carrier_service_id).await
+    }
+
+    async fn update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::update_carrier_services_param_service(
+            self,
+            carrier_service_id,
+            body,
+        )
+        .await
+    }
+
+    async fn delete_carrier_services_param_service(&self, carrier_service_id: &str) -> Result<()> {
+        ShippingAndFulfillment::delete_carrier_services_param_service(self, carrier_service_id)
+            .await
+    }
+
+    async fn deprecated_202101_get_carrier_service(&self) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_carrier_service(self).await
+    }
+
+    async fn deprecated_202101_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_carrier_services(self, body).await
+    }
+
+    async fn deprecated_202101_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_update_carrier_services_param_service(
+            self,
+            carrier_service_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_delete_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_carrier_service(&self) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_carrier_service(self).await
+    }
+
+    async fn deprecated_unstable_create_carrier_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_carrier_services(self, body).await
+    }
+
+    async fn deprecated_unstable_get_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_update_carrier_services_param_service(
+            self,
+            carrier_service_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_delete_carrier_services_param_service(
+        &self,
+        carrier_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_delete_carrier_services_param_service(
+            self,
+            carrier_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_orders_param_order_fulfillment_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillment_with(
+            self, order_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments(
+            self, order_id, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_fulfillment_orders_param_order_fulfillment(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_orders_param_order_fulfillments_count_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsCountParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_count_with(
+            self, order_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_with(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001GetOrdersParamOrderFulfillmentsFulfillmentParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment_with(self, order_id, fulfillment_id, params).await
+    }
+
+    async fn deprecated_202001_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_update_orders_param_order_fulfillments_fulfillment(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202001_create_fulfillments(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillments(self, body).await
+    }
+
+    async fn deprecated_202001_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillments_param_fulfillment_update_tracking(self, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_complete(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_open(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_cancel(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202001_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillments_param_fulfillment_cancel(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_orders_param_order_fulfillment_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillment_with(
+            self, order_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments(
+            self, order_id, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_fulfillment_orders_param_order_fulfillment(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_orders_param_order_fulfillments_count_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsCountParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_count_with(
+            self, order_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_with(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004GetOrdersParamOrderFulfillmentsFulfillmentParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment_with(self, order_id, fulfillment_id, params).await
+    }
+
+    async fn deprecated_202004_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_update_orders_param_order_fulfillments_fulfillment(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202004_create_fulfillments(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillments(self, body).await
+    }
+
+    async fn deprecated_202004_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillments_param_fulfillment_update_tracking(self, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_complete(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_open(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_cancel(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202004_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillments_param_fulfillment_cancel(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_orders_param_order_fulfillment_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillment_with(
+            self, order_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments(
+            self, order_id, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_fulfillment_orders_param_order_fulfillment(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_orders_param_order_fulfillments_count_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsCountParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_count_with(
+            self, order_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_with(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007GetOrdersParamOrderFulfillmentsFulfillmentParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment_with(self, order_id, fulfillment_id, params).await
+    }
+
+    async fn deprecated_202007_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_update_orders_param_order_fulfillments_fulfillment(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202007_create_fulfillments(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillments(self, body).await
+    }
+
+    async fn deprecated_202007_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillments_param_fulfillment_update_tracking(self, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_complete(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_open(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_cancel(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202007_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillments_param_fulfillment_cancel(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+
+    async fn get_orders_param_order_fulfillment_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentGetOrdersParamOrderFulfillmentParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_orders_param_order_fulfillment_with(self, order_id, params)
+            .await
+    }
+
+    async fn create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_orders_param_order_fulfillments(self, order_id, body).await
+    }
+
+    async fn get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_fulfillment_orders_param_order_fulfillment(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+
+    async fn get_orders_param_order_fulfillments_count_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsCountParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_orders_param_order_fulfillments_count_with(
+            self, order_id, params,
+        )
+        .await
+    }
+
+    async fn get_orders_param_order_fulfillments_fulfillment_with(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        params: &ShippingAndFulfillmentGetOrdersParamOrderFulfillmentsFulfillmentParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_with(
+            self,
+            order_id,
+            fulfillment_id,
+            params,
+        )
+        .await
+    }
+
+    async fn update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::update_orders_param_order_fulfillments_fulfillment(
+            self,
+            order_id,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+
+    async fn create_fulfillments(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillments(self, body).await
+    }
+
+    async fn create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillments_param_fulfillment_update_tracking(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+
+    async fn create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_complete(
+            self,
+            order_id,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+
+    async fn create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_open(
+            self,
+            order_id,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+
+    async fn create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_cancel(
+            self,
+            order_id,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+
+    async fn create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillments_param_fulfillment_cancel(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_orders_param_order_fulfillment_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillment_with(
+            self, order_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments(
+            self, order_id, body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_fulfillment_orders_param_order_fulfillment(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_orders_param_order_fulfillments_count_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsCountParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_count_with(
+            self, order_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_with(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101GetOrdersParamOrderFulfillmentsFulfillmentParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment_with(self, order_id, fulfillment_id, params).await
+    }
+
+    async fn deprecated_202101_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_update_orders_param_order_fulfillments_fulfillment(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202101_create_fulfillments(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillments(self, body).await
+    }
+
+    async fn deprecated_202101_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillments_param_fulfillment_update_tracking(self, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_complete(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_open(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_cancel(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202101_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillments_param_fulfillment_cancel(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_orders_param_order_fulfillment_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillment_with(
+            self, order_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_create_orders_param_order_fulfillments(
+        &self,
+        order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments(
+            self, order_id, body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_fulfillment_orders_param_order_fulfillment(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_fulfillment_orders_param_order_fulfillment(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_count_with(
+        &self,
+        order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsCountParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_count_with(
+            self, order_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_with(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetOrdersParamOrderFulfillmentsFulfillmentParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_with(self, order_id, fulfillment_id, params).await
+    }
+
+    async fn deprecated_unstable_update_orders_param_order_fulfillments_fulfillment(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_update_orders_param_order_fulfillments_fulfillment(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_unstable_create_fulfillments(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillments(self, body).await
+    }
+
+    async fn deprecated_unstable_create_fulfillments_param_fulfillment_update_tracking(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillments_param_fulfillment_update_tracking(self, fulfillment_id, body).await
+    }
+
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_complete(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_complete(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_open(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_open(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_cancel(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_cancel(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_unstable_create_fulfillments_param_fulfillment_cancel(
+        &self,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillments_param_fulfillment_cancel(
+            self,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment_event(self, order_id, fulfillment_id).await
+    }
+
+    async fn deprecated_202001_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_orders_param_order_fulfillments_fulfillment_events(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202001_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+
+    async fn deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_delete_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+
+    async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment_event(self, order_id, fulfillment_id).await
+    }
+
+    async fn deprecated_202004_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_orders_param_order_fulfillments_fulfillment_events(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202004_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+
+    async fn deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_delete_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+
+    async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment_event(self, order_id, fulfillment_id).await
+    }
+
+    async fn deprecated_202007_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_orders_param_order_fulfillments_fulfillment_events(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202007_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+
+    async fn deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_delete_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+
+    async fn get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_event(
+            self,
+            order_id,
+            fulfillment_id,
+        )
+        .await
+    }
+
+    async fn create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_orders_param_order_fulfillments_fulfillment_events(
+            self,
+            order_id,
+            fulfillment_id,
+            body,
+        )
+        .await
+    }
+
+    async fn get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_orders_param_order_fulfillments_fulfillment_events_event(
+            self,
+            order_id,
+            fulfillment_id,
+            event_id,
+        )
+        .await
+    }
+
+    async fn delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::delete_orders_param_order_fulfillments_fulfillment_events_event(
+            self,
+            order_id,
+            fulfillment_id,
+            event_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment_event(self, order_id, fulfillment_id).await
+    }
+
+    async fn deprecated_202101_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_orders_param_order_fulfillments_fulfillment_events(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_202101_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+
+    async fn deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_delete_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_event(self, order_id, fulfillment_id).await
+    }
+
+    async fn deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_events(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_orders_param_order_fulfillments_fulfillment_events(self, order_id, fulfillment_id, body).await
+    }
+
+    async fn deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+
+    async fn deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event(
+        &self,
+        order_id: &str,
+        fulfillment_id: &str,
+        event_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_delete_orders_param_order_fulfillments_fulfillment_events_event(self, order_id, fulfillment_id, event_id).await
+    }
+
+    async fn deprecated_202001_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_orders_param_order_fulfillment_shipping_and_fulfillment(self, order_id).await
+    }
+
+    async fn deprecated_202001_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_fulfillment_orders_param_order(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_cancel(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_create_fulfillment_orders_param_order_close_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderCloseParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_close_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_create_fulfillment_orders_param_order_move_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderMoveParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_move_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_orders_param_order_fulfillment_shipping_and_fulfillment(self, order_id).await
+    }
+
+    async fn deprecated_202004_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_fulfillment_orders_param_order(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_cancel(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_create_fulfillment_orders_param_order_close_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderCloseParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_close_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_create_fulfillment_orders_param_order_move_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderMoveParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_move_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_orders_param_order_fulfillment_shipping_and_fulfillment(self, order_id).await
+    }
+
+    async fn deprecated_202007_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_fulfillment_orders_param_order(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_cancel(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_create_fulfillment_orders_param_order_close_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderCloseParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_close_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_create_fulfillment_orders_param_order_move_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderMoveParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_move_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_orders_param_order_fulfillment_shipping_and_fulfillment(
+            self, order_id,
+        )
+        .await
+    }
+
+    async fn get_fulfillment_orders_param_order(&self, fulfillment_order_id: &str) -> Result<()> {
+        ShippingAndFulfillment::get_fulfillment_orders_param_order(self, fulfillment_order_id).await
+    }
+
+    async fn create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_cancel(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+
+    async fn create_fulfillment_orders_param_order_close_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderCloseParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_close_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn create_fulfillment_orders_param_order_move_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderMoveParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_move_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_orders_param_order_fulfillment_shipping_and_fulfillment(self, order_id).await
+    }
+
+    async fn deprecated_202101_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_fulfillment_orders_param_order(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_cancel(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_create_fulfillment_orders_param_order_close_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderCloseParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_close_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_create_fulfillment_orders_param_order_move_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderMoveParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_move_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_create_fulfillment_orders_param_order_open(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_open(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_create_fulfillment_orders_param_order_reschedule(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_reschedule(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_orders_param_order_fulfillment_shipping_and_fulfillment(
+        &self,
+        order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_orders_param_order_fulfillment_shipping_and_fulfillment(self, order_id).await
+    }
+
+    async fn deprecated_unstable_get_fulfillment_orders_param_order(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_fulfillment_orders_param_order(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_cancel(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_cancel(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_create_fulfillment_orders_release_hold_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersReleaseHoldParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_release_hold_with(
+            self, params, body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_close_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderCloseParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_close_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_move_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderMoveParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_move_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_open(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_open(
+            self,
+            fulfillment_order_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_reschedule(
+        &self,
+        fulfillment_order_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_reschedule(self, fulfillment_order_id, body).await
+    }
+
+    async fn deprecated_202001_create_fulfillment_orders_param_order_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202001_create_fulfillment_orders_param_order_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_accept_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202001_create_fulfillment_orders_param_order_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202001CreateFulfillmentOrdersParamOrderRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_orders_param_order_request_reject_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202004_create_fulfillment_orders_param_order_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202004_create_fulfillment_orders_param_order_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_accept_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202004_create_fulfillment_orders_param_order_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202004CreateFulfillmentOrdersParamOrderRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_orders_param_order_request_reject_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202007_create_fulfillment_orders_param_order_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202007_create_fulfillment_orders_param_order_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_accept_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202007_create_fulfillment_orders_param_order_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202007CreateFulfillmentOrdersParamOrderRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_orders_param_order_request_reject_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn create_fulfillment_orders_param_order_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_request_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn create_fulfillment_orders_param_order_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_request_accept_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn create_fulfillment_orders_param_order_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentCreateFulfillmentOrdersParamOrderRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_orders_param_order_request_reject_with(
+            self,
+            fulfillment_order_id,
+            params,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_create_fulfillment_orders_param_order_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202101_create_fulfillment_orders_param_order_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_accept_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_202101_create_fulfillment_orders_param_order_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecated202101CreateFulfillmentOrdersParamOrderRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_orders_param_order_request_reject_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_request_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_request_accept_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestAcceptParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_accept_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_unstable_create_fulfillment_orders_param_order_request_reject_with(
+        &self,
+        fulfillment_order_id: &str,
+        params: &ShippingAndFulfillmentDeprecatedUnstableCreateFulfillmentOrdersParamOrderRequestRejectParams,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_orders_param_order_request_reject_with(self, fulfillment_order_id, params, body).await
+    }
+
+    async fn deprecated_unknown_version_get_fetch_tracking_number_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchTrackingNumberParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unknown_version_get_fetch_tracking_number_with(
+            self, params,
+        )
+        .await
+    }
+
+    async fn deprecated_unknown_version_get_fetch_stock_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecatedUnknownVersionGetFetchStockParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unknown_version_get_fetch_stock_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202001GetFulfillmentServiceParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_fulfillment_service_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_create_fulfillment_services(self, body).await
+    }
+
+    async fn deprecated_202001_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_update_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_delete_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202004GetFulfillmentServiceParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_fulfillment_service_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_create_fulfillment_services(self, body).await
+    }
+
+    async fn deprecated_202004_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_update_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_delete_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202007GetFulfillmentServiceParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_fulfillment_service_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_create_fulfillment_services(self, body).await
+    }
+
+    async fn deprecated_202007_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_update_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_delete_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+
+    async fn get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentGetFulfillmentServiceParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_fulfillment_service_with(self, params).await
+    }
+
+    async fn create_fulfillment_services(&self, body: &serde_json::Value) -> Result<()> {
+        ShippingAndFulfillment::create_fulfillment_services(self, body).await
+    }
+
+    async fn get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_fulfillment_services_param_service(self, fulfillment_service_id)
+            .await
+    }
+
+    async fn update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::update_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+            body,
+        )
+        .await
+    }
+
+    async fn delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::delete_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecated202101GetFulfillmentServiceParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_fulfillment_service_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_create_fulfillment_services(self, body).await
+    }
+
+    async fn deprecated_202101_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_update_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_delete_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_fulfillment_service_with(
+        &self,
+        params: &ShippingAndFulfillmentDeprecatedUnstableGetFulfillmentServiceParams,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_fulfillment_service_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_fulfillment_services(
+        &self,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_create_fulfillment_services(self, body).await
+    }
+
+    async fn deprecated_unstable_get_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_update_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_delete_fulfillment_services_param_service(
+        &self,
+        fulfillment_service_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_delete_fulfillment_services_param_service(
+            self,
+            fulfillment_service_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202001_get_fulfillment_orders_param_order_locations_for_move(self, fulfillment_order_id).await
+    }
+
+    async fn deprecated_202004_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202004_get_fulfillment_orders_param_order_locations_for_move(self, fulfillment_order_id).await
+    }
+
+    async fn deprecated_202007_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202007_get_fulfillment_orders_param_order_locations_for_move(self, fulfillment_order_id).await
+    }
+
+    async fn get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::get_fulfillment_orders_param_order_locations_for_move(
+            self,
+            fulfillment_order_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_202101_get_fulfillment_orders_param_order_locations_for_move(self, fulfillment_order_id).await
+    }
+
+    async fn deprecated_unstable_get_fulfillment_orders_param_order_locations_for_move(
+        &self,
+        fulfillment_order_id: &str,
+    ) -> Result<()> {
+        ShippingAndFulfillment::deprecated_unstable_get_fulfillment_orders_param_order_locations_for_move(self, fulfillment_order_id).await
+    }
+}
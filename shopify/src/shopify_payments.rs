@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ShopifyPayments {
     pub client: Client,
@@ -94,29 +92,23 @@ impl ShopifyPayments {
     *
     * **Parameters:**
     *
-    * * `since_id: &str` -- Return only disputes after the specified ID.
-    * * `last_id: &str` -- Return only disputes before the specified ID.
-    * * `status: &str` -- Return only disputes with the specified status.
-    * * `initiated_at: &str` -- Return only disputes with the specified initiated_at date (ISO 8601 format).
+    * * `params: &ShopifyPaymentsDeprecated202001GetDisputeParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_get_dispute(
+    pub async fn deprecated_202001_get_dispute_with(
         &self,
-        since_id: &str,
-        last_id: &str,
-        status: &str,
-        initiated_at: &str,
+        params: &ShopifyPaymentsDeprecated202001GetDisputeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !initiated_at.is_empty() {
+        if let Some(initiated_at) = &params.initiated_at {
             query_args.push(("initiated_at".to_string(), initiated_at.to_string()));
         }
-        if !last_id.is_empty() {
+        if let Some(last_id) = &params.last_id {
             query_args.push(("last_id".to_string(), last_id.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -128,6 +120,26 @@ impl ShopifyPayments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_dispute_with` and pass the query parameters as a `ShopifyPaymentsDeprecated202001GetDisputeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_dispute_with` instead")]
+    pub async fn deprecated_202001_get_dispute(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        status: &str,
+        initiated_at: &str,
+    ) -> Result<()> {
+        let params = ShopifyPaymentsDeprecated202001GetDisputeParams {
+            initiated_at: Some(initiated_at.to_string()).filter(|s| !s.is_empty()),
+            last_id: Some(last_id.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_dispute_with(&params).await
+    }
+
     /**
      * Retrieves a single dispute by ID.
      *
@@ -161,29 +173,23 @@ impl ShopifyPayments {
     *
     * **Parameters:**
     *
-    * * `since_id: &str` -- Return only disputes after the specified ID.
-    * * `last_id: &str` -- Return only disputes before the specified ID.
-    * * `status: &str` -- Return only disputes with the specified status.
-    * * `initiated_at: &str` -- Return only disputes with the specified initiated_at date (ISO 8601 format).
+    * * `params: &ShopifyPaymentsDeprecated202004GetDisputeParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_get_dispute(
+    pub async fn deprecated_202004_get_dispute_with(
         &self,
-        since_id: &str,
-        last_id: &str,
-        status: &str,
-        initiated_at: &str,
+        params: &ShopifyPaymentsDeprecated202004GetDisputeParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !initiated_at.is_empty() {
+        if let Some(initiated_at) = &params.initiated_at {
             query_args.push(("initiated_at".to_string(), initiated_at.to_string()));
         }
-        if !last_id.is_empty() {
+        if let Some(last_id) = &params.last_id {
             query_args.push(("last_id".to_string(), last_id.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -195,6 +201,26 @@ impl ShopifyPayments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_dispute_with` and pass the query parameters as a `ShopifyPaymentsDeprecated202004GetDisputeParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_dispute_with` instead")]
+    pub async fn deprecated_202004_get_dispute(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        status: &str,
+        initiated_at: &str,
+    ) -> Result<()> {
+        let params = ShopifyPaymentsDeprecated202004GetDisputeParams {
+            initiated_at: Some(initiated_at.to_string()).filter(|s| !s.is_empty()),
+            last_id: Some(last_id.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_dispute_with(&params).await
+    }
+
     /**
     * Retrieves a list of all payouts ordered by payout date, with the most recent being first.
               Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. Sending the page parameter will return an error. To learn more, see Making requests to paginated REST Admin API endpoints.
@@ -205,39 +231,29 @@ impl ShopifyPayments {
     *
     * **Parameters:**
     *
-    * * `since_id: &str` -- Filter the response to payouts made after the specified ID.
-    * * `last_id: &str` -- Filter the response to payouts made before the specified ID.
-    * * `date_min: &str` -- Filter the response to payouts made inclusively after the specified date.
-    * * `date_max: &str` -- Filter the response to payouts made inclusively before the specified date.
-    * * `date: &str` -- Filter the response to payouts made on the specified date.
-    * * `status: &str` -- Filter the response to payouts made with the specified status.
+    * * `params: &ShopifyPaymentsDeprecated202001GetPayoutParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_get_payout(
+    pub async fn deprecated_202001_get_payout_with(
         &self,
-        since_id: &str,
-        last_id: &str,
-        date_min: &str,
-        date_max: &str,
-        date: &str,
-        status: &str,
+        params: &ShopifyPaymentsDeprecated202001GetPayoutParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.is_empty() {
+        if let Some(date) = &params.date {
             query_args.push(("date".to_string(), date.to_string()));
         }
-        if !date_max.is_empty() {
+        if let Some(date_max) = &params.date_max {
             query_args.push(("date_max".to_string(), date_max.to_string()));
         }
-        if !date_min.is_empty() {
+        if let Some(date_min) = &params.date_min {
             query_args.push(("date_min".to_string(), date_min.to_string()));
         }
-        if !last_id.is_empty() {
+        if let Some(last_id) = &params.last_id {
             query_args.push(("last_id".to_string(), last_id.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -249,6 +265,30 @@ impl ShopifyPayments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_payout_with` and pass the query parameters as a `ShopifyPaymentsDeprecated202001GetPayoutParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_payout_with` instead")]
+    pub async fn deprecated_202001_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()> {
+        let params = ShopifyPaymentsDeprecated202001GetPayoutParams {
+            date: Some(date.to_string()).filter(|s| !s.is_empty()),
+            date_max: Some(date_max.to_string()).filter(|s| !s.is_empty()),
+            date_min: Some(date_min.to_string()).filter(|s| !s.is_empty()),
+            last_id: Some(last_id.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_payout_with(&params).await
+    }
+
     /**
      * Retrieves a single payout by id.
      *
@@ -279,39 +319,29 @@ impl ShopifyPayments {
     *
     * **Parameters:**
     *
-    * * `since_id: &str` -- Filter the response to payouts made after the specified ID.
-    * * `last_id: &str` -- Filter the response to payouts made before the specified ID.
-    * * `date_min: &str` -- Filter the response to payouts made inclusively after the specified date.
-    * * `date_max: &str` -- Filter the response to payouts made inclusively before the specified date.
-    * * `date: &str` -- Filter the response to payouts made on the specified date.
-    * * `status: &str` -- Filter the response to payouts made with the specified status.
+    * * `params: &ShopifyPaymentsDeprecated202004GetPayoutParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202004_get_payout(
+    pub async fn deprecated_202004_get_payout_with(
         &self,
-        since_id: &str,
-        last_id: &str,
-        date_min: &str,
-        date_max: &str,
-        date: &str,
-        status: &str,
+        params: &ShopifyPaymentsDeprecated202004GetPayoutParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.is_empty() {
+        if let Some(date) = &params.date {
             query_args.push(("date".to_string(), date.to_string()));
         }
-        if !date_max.is_empty() {
+        if let Some(date_max) = &params.date_max {
             query_args.push(("date_max".to_string(), date_max.to_string()));
         }
-        if !date_min.is_empty() {
+        if let Some(date_min) = &params.date_min {
             query_args.push(("date_min".to_string(), date_min.to_string()));
         }
-        if !last_id.is_empty() {
+        if let Some(last_id) = &params.last_id {
             query_args.push(("last_id".to_string(), last_id.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -323,6 +353,30 @@ impl ShopifyPayments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_payout_with` and pass the query parameters as a `ShopifyPaymentsDeprecated202004GetPayoutParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_payout_with` instead")]
+    pub async fn deprecated_202004_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()> {
+        let params = ShopifyPaymentsDeprecated202004GetPayoutParams {
+            date: Some(date.to_string()).filter(|s| !s.is_empty()),
+            date_max: Some(date_max.to_string()).filter(|s| !s.is_empty()),
+            date_min: Some(date_min.to_string()).filter(|s| !s.is_empty()),
+            last_id: Some(last_id.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_payout_with(&params).await
+    }
+
     /**
      * Retrieves a single payout by id.
      *
@@ -353,39 +407,29 @@ impl ShopifyPayments {
     *
     * **Parameters:**
     *
-    * * `since_id: &str` -- Filter the response to payouts made after the specified ID.
-    * * `last_id: &str` -- Filter the response to payouts made before the specified ID.
-    * * `date_min: &str` -- Filter the response to payouts made inclusively after the specified date.
-    * * `date_max: &str` -- Filter the response to payouts made inclusively before the specified date.
-    * * `date: &str` -- Filter the response to payouts made on the specified date.
-    * * `status: &str` -- Filter the response to payouts made with the specified status.
+    * * `params: &ShopifyPaymentsDeprecated202007GetPayoutParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202007_get_payout(
+    pub async fn deprecated_202007_get_payout_with(
         &self,
-        since_id: &str,
-        last_id: &str,
-        date_min: &str,
-        date_max: &str,
-        date: &str,
-        status: &str,
+        params: &ShopifyPaymentsDeprecated202007GetPayoutParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.is_empty() {
+        if let Some(date) = &params.date {
             query_args.push(("date".to_string(), date.to_string()));
         }
-        if !date_max.is_empty() {
+        if let Some(date_max) = &params.date_max {
             query_args.push(("date_max".to_string(), date_max.to_string()));
         }
-        if !date_min.is_empty() {
+        if let Some(date_min) = &params.date_min {
             query_args.push(("date_min".to_string(), date_min.to_string()));
         }
-        if !last_id.is_empty() {
+        if let Some(last_id) = &params.last_id {
             query_args.push(("last_id".to_string(), last_id.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -397,6 +441,30 @@ impl ShopifyPayments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_payout_with` and pass the query parameters as a `ShopifyPaymentsDeprecated202007GetPayoutParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_payout_with` instead")]
+    pub async fn deprecated_202007_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()> {
+        let params = ShopifyPaymentsDeprecated202007GetPayoutParams {
+            date: Some(date.to_string()).filter(|s| !s.is_empty()),
+            date_max: Some(date_max.to_string()).filter(|s| !s.is_empty()),
+            date_min: Some(date_min.to_string()).filter(|s| !s.is_empty()),
+            last_id: Some(last_id.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_payout_with(&params).await
+    }
+
     /**
      * Retrieves a single payout by id.
      *
@@ -427,39 +495,26 @@ impl ShopifyPayments {
     *
     * **Parameters:**
     *
-    * * `since_id: &str` -- Filter the response to payouts made after the specified ID.
-    * * `last_id: &str` -- Filter the response to payouts made before the specified ID.
-    * * `date_min: &str` -- Filter the response to payouts made inclusively after the specified date.
-    * * `date_max: &str` -- Filter the response to payouts made inclusively before the specified date.
-    * * `date: &str` -- Filter the response to payouts made on the specified date.
-    * * `status: &str` -- Filter the response to payouts made with the specified status.
+    * * `params: &ShopifyPaymentsGetPayoutParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn get_payout(
-        &self,
-        since_id: &str,
-        last_id: &str,
-        date_min: &str,
-        date_max: &str,
-        date: &str,
-        status: &str,
-    ) -> Result<()> {
+    pub async fn get_payout_with(&self, params: &ShopifyPaymentsGetPayoutParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.is_empty() {
+        if let Some(date) = &params.date {
             query_args.push(("date".to_string(), date.to_string()));
         }
-        if !date_max.is_empty() {
+        if let Some(date_max) = &params.date_max {
             query_args.push(("date_max".to_string(), date_max.to_string()));
         }
-        if !date_min.is_empty() {
+        if let Some(date_min) = &params.date_min {
             query_args.push(("date_min".to_string(), date_min.to_string()));
         }
-        if !last_id.is_empty() {
+        if let Some(last_id) = &params.last_id {
             query_args.push(("last_id".to_string(), last_id.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -471,6 +526,30 @@ impl ShopifyPayments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_payout_with` and pass the query parameters as a `ShopifyPaymentsGetPayoutParams` instead.
+     */
+    #[deprecated(note = "use `get_payout_with` instead")]
+    pub async fn get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()> {
+        let params = ShopifyPaymentsGetPayoutParams {
+            date: Some(date.to_string()).filter(|s| !s.is_empty()),
+            date_max: Some(date_max.to_string()).filter(|s| !s.is_empty()),
+            date_min: Some(date_min.to_string()).filter(|s| !s.is_empty()),
+            last_id: Some(last_id.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_payout_with(&params).await
+    }
+
     /**
      * Retrieves a single payout by id.
      *
@@ -501,39 +580,29 @@ impl ShopifyPayments {
     *
     * **Parameters:**
     *
-    * * `since_id: &str` -- Filter the response to payouts made after the specified ID.
-    * * `last_id: &str` -- Filter the response to payouts made before the specified ID.
-    * * `date_min: &str` -- Filter the response to payouts made inclusively after the specified date.
-    * * `date_max: &str` -- Filter the response to payouts made inclusively before the specified date.
-    * * `date: &str` -- Filter the response to payouts made on the specified date.
-    * * `status: &str` -- Filter the response to payouts made with the specified status.
+    * * `params: &ShopifyPaymentsDeprecated202101GetPayoutParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202101_get_payout(
+    pub async fn deprecated_202101_get_payout_with(
         &self,
-        since_id: &str,
-        last_id: &str,
-        date_min: &str,
-        date_max: &str,
-        date: &str,
-        status: &str,
+        params: &ShopifyPaymentsDeprecated202101GetPayoutParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.is_empty() {
+        if let Some(date) = &params.date {
             query_args.push(("date".to_string(), date.to_string()));
         }
-        if !date_max.is_empty() {
+        if let Some(date_max) = &params.date_max {
             query_args.push(("date_max".to_string(), date_max.to_string()));
         }
-        if !date_min.is_empty() {
+        if let Some(date_min) = &params.date_min {
             query_args.push(("date_min".to_string(), date_min.to_string()));
         }
-        if !last_id.is_empty() {
+        if let Some(last_id) = &params.last_id {
             query_args.push(("last_id".to_string(), last_id.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -545,6 +614,30 @@ impl ShopifyPayments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_payout_with` and pass the query parameters as a `ShopifyPaymentsDeprecated202101GetPayoutParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_payout_with` instead")]
+    pub async fn deprecated_202101_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()> {
+        let params = ShopifyPaymentsDeprecated202101GetPayoutParams {
+            date: Some(date.to_string()).filter(|s| !s.is_empty()),
+            date_max: Some(date_max.to_string()).filter(|s| !s.is_empty()),
+            date_min: Some(date_min.to_string()).filter(|s| !s.is_empty()),
+            last_id: Some(last_id.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_payout_with(&params).await
+    }
+
     /**
      * Retrieves a single payout by id.
      *
@@ -575,39 +668,29 @@ impl ShopifyPayments {
     *
     * **Parameters:**
     *
-    * * `since_id: &str` -- Filter the response to payouts made after the specified ID.
-    * * `last_id: &str` -- Filter the response to payouts made before the specified ID.
-    * * `date_min: &str` -- Filter the response to payouts made inclusively after the specified date.
-    * * `date_max: &str` -- Filter the response to payouts made inclusively before the specified date.
-    * * `date: &str` -- Filter the response to payouts made on the specified date.
-    * * `status: &str` -- Filter the response to payouts made with the specified status.
+    * * `params: &ShopifyPaymentsDeprecatedUnstableGetPayoutParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_unstable_get_payout(
+    pub async fn deprecated_unstable_get_payout_with(
         &self,
-        since_id: &str,
-        last_id: &str,
-        date_min: &str,
-        date_max: &str,
-        date: &str,
-        status: &str,
+        params: &ShopifyPaymentsDeprecatedUnstableGetPayoutParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.is_empty() {
+        if let Some(date) = &params.date {
             query_args.push(("date".to_string(), date.to_string()));
         }
-        if !date_max.is_empty() {
+        if let Some(date_max) = &params.date_max {
             query_args.push(("date_max".to_string(), date_max.to_string()));
         }
-        if !date_min.is_empty() {
+        if let Some(date_min) = &params.date_min {
             query_args.push(("date_min".to_string(), date_min.to_string()));
         }
-        if !last_id.is_empty() {
+        if let Some(last_id) = &params.last_id {
             query_args.push(("last_id".to_string(), last_id.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -619,6 +702,30 @@ impl ShopifyPayments {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_payout_with` and pass the query parameters as a `ShopifyPaymentsDeprecatedUnstableGetPayoutParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_payout_with` instead")]
+    pub async fn deprecated_unstable_get_payout(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        date_min: &str,
+        date_max: &str,
+        date: &str,
+        status: &str,
+    ) -> Result<()> {
+        let params = ShopifyPaymentsDeprecatedUnstableGetPayoutParams {
+            date: Some(date.to_string()).filter(|s| !s.is_empty()),
+            date_max: Some(date_max.to_string()).filter(|s| !s.is_empty()),
+            date_min: Some(date_min.to_string()).filter(|s| !s.is_empty()),
+            last_id: Some(last_id.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_payout_with(&params).await
+    }
+
     /**
      * Retrieves a single payout by id.
      *
@@ -653,34 +760,26 @@ impl ShopifyPayments {
     *
     * **Parameters:**
     *
-    * * `since_id: &str` -- Filter response to transactions exclusively after the specified ID.
-    * * `last_id: &str` -- Filter response to transactions exclusively before the specified ID.
-    * * `test: &str` -- Filter response to transactions placed in test mode.
-    * * `payout_id: &str` -- Filter response to transactions paid out in the specified payout.
-    * * `payout_status: &str` -- Filter response to transactions with the specified payout status.
+    * * `params: &ShopifyPaymentsDeprecated202001GetBalanceTransactionParams` -- The query parameters, only the ones that are set are sent.
     */
-    pub async fn deprecated_202001_get_balance_transaction(
+    pub async fn deprecated_202001_get_balance_transaction_with(
         &self,
-        since_id: &str,
-        last_id: &str,
-        test: &str,
-        payout_id: &str,
-        payout_status: &str,
+        params: &ShopifyPaymentsDeprecated202001GetBalanceTransactionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !last_id.is_empty() {
+        if let Some(last_id) = &params.last_id {
             query_args.push(("last_id".to_string(), last_id.to_string()));
         }
-        if !payout_id.is_empty() {
+        if let Some(payout_id) = &params.payout_id {
             query_args.push(("payout_id".to_string(), payout_id.to_string()));
         }
-        if !payout_status.is_empty() {
+        if let Some(payout_status) = &params.payout_status {
             query_args.push(("payout_status".to_string(), payout_status.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
-        if !test.is_empty() {
+        if let Some(test) = &params.test {
             query_args.push(("test".to_string(), test.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -691,4 +790,644 @@ impl ShopifyPayments {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `deprecated_202001_get_balance_transaction_with` and pass the query parameters as a `ShopifyPaymentsDeprecated202001GetBalanceTransactionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_balance_transaction_with` instead")]
+    pub async fn deprecated_202001_get_balance_transaction(
+        &self,
+        since_id: &str,
+        last_id: &str,
+        test: &str,
+        payout_id: &str,
+        payout_status: &str,
+    ) -> Result<()> {
+        let params = ShopifyPaymentsDeprecated202001GetBalanceTransactionParams {
+            last_id: Some(last_id.to_string()).filter(|s| !s.is_empty()),
+            payout_id: Some(payout_id.to_string()).filter(|s| !s.is_empty()),
+            payout_status: Some(payout_status.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+            test: Some(test.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_balance_transaction_with(&params)
+            .await
+    }
+}
+
+/// The query parameters of `ShopifyPayments::deprecated_202001_get_dispute_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShopifyPaymentsDeprecated202001GetDisputeParams {
+    /// Return only disputes with the specified initiated_at date (ISO 8601 format).
+    pub initiated_at: Option<String>,
+    /// Return only disputes before the specified ID.
+    pub last_id: Option<String>,
+    /// Return only disputes after the specified ID.
+    pub since_id: Option<String>,
+    /// Return only disputes with the specified status.
+    pub status: Option<String>,
+}
+
+impl ShopifyPaymentsDeprecated202001GetDisputeParams {
+    pub fn initiated_at<T: ToString>(mut self, initiated_at: T) -> Self {
+        self.initiated_at = Some(initiated_at.to_string());
+        self
+    }
+
+    pub fn last_id<T: ToString>(mut self, last_id: T) -> Self {
+        self.last_id = Some(last_id.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShopifyPayments::deprecated_202004_get_dispute_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShopifyPaymentsDeprecated202004GetDisputeParams {
+    /// Return only disputes with the specified initiated_at date (ISO 8601 format).
+    pub initiated_at: Option<String>,
+    /// Return only disputes before the specified ID.
+    pub last_id: Option<String>,
+    /// Return only disputes after the specified ID.
+    pub since_id: Option<String>,
+    /// Return only disputes with the specified status.
+    pub status: Option<String>,
+}
+
+impl ShopifyPaymentsDeprecated202004GetDisputeParams {
+    pub fn initiated_at<T: ToString>(mut self, initiated_at: T) -> Self {
+        self.initiated_at = Some(initiated_at.to_string());
+        self
+    }
+
+    pub fn last_id<T: ToString>(mut self, last_id: T) -> Self {
+        self.last_id = Some(last_id.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShopifyPayments::deprecated_202001_get_payout_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShopifyPaymentsDeprecated202001GetPayoutParams {
+    /// Filter the response to payouts made on the specified date.
+    pub date: Option<String>,
+    /// Filter the response to payouts made inclusively before the specified date.
+    pub date_max: Option<String>,
+    /// Filter the response to payouts made inclusively after the specified date.
+    pub date_min: Option<String>,
+    /// Filter the response to payouts made before the specified ID.
+    pub last_id: Option<String>,
+    /// Filter the response to payouts made after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter the response to payouts made with the specified status.
+    pub status: Option<String>,
+}
+
+impl ShopifyPaymentsDeprecated202001GetPayoutParams {
+    pub fn date<T: ToString>(mut self, date: T) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+
+    pub fn date_max<T: ToString>(mut self, date_max: T) -> Self {
+        self.date_max = Some(date_max.to_string());
+        self
+    }
+
+    pub fn date_min<T: ToString>(mut self, date_min: T) -> Self {
+        self.date_min = Some(date_min.to_string());
+        self
+    }
+
+    pub fn last_id<T: ToString>(mut self, last_id: T) -> Self {
+        self.last_id = Some(last_id.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShopifyPayments::deprecated_202004_get_payout_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShopifyPaymentsDeprecated202004GetPayoutParams {
+    /// Filter the response to payouts made on the specified date.
+    pub date: Option<String>,
+    /// Filter the response to payouts made inclusively before the specified date.
+    pub date_max: Option<String>,
+    /// Filter the response to payouts made inclusively after the specified date.
+    pub date_min: Option<String>,
+    /// Filter the response to payouts made before the specified ID.
+    pub last_id: Option<String>,
+    /// Filter the response to payouts made after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter the response to payouts made with the specified status.
+    pub status: Option<String>,
+}
+
+impl ShopifyPaymentsDeprecated202004GetPayoutParams {
+    pub fn date<T: ToString>(mut self, date: T) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+
+    pub fn date_max<T: ToString>(mut self, date_max: T) -> Self {
+        self.date_max = Some(date_max.to_string());
+        self
+    }
+
+    pub fn date_min<T: ToString>(mut self, date_min: T) -> Self {
+        self.date_min = Some(date_min.to_string());
+        self
+    }
+
+    pub fn last_id<T: ToString>(mut self, last_id: T) -> Self {
+        self.last_id = Some(last_id.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShopifyPayments::deprecated_202007_get_payout_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShopifyPaymentsDeprecated202007GetPayoutParams {
+    /// Filter the response to payouts made on the specified date.
+    pub date: Option<String>,
+    /// Filter the response to payouts made inclusively before the specified date.
+    pub date_max: Option<String>,
+    /// Filter the response to payouts made inclusively after the specified date.
+    pub date_min: Option<String>,
+    /// Filter the response to payouts made before the specified ID.
+    pub last_id: Option<String>,
+    /// Filter the response to payouts made after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter the response to payouts made with the specified status.
+    pub status: Option<String>,
+}
+
+impl ShopifyPaymentsDeprecated202007GetPayoutParams {
+    pub fn date<T: ToString>(mut self, date: T) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+
+    pub fn date_max<T: ToString>(mut self, date_max: T) -> Self {
+        self.date_max = Some(date_max.to_string());
+        self
+    }
+
+    pub fn date_min<T: ToString>(mut self, date_min: T) -> Self {
+        self.date_min = Some(date_min.to_string());
+        self
+    }
+
+    pub fn last_id<T: ToString>(mut self, last_id: T) -> Self {
+        self.last_id = Some(last_id.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShopifyPayments::get_payout_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShopifyPaymentsGetPayoutParams {
+    /// Filter the response to payouts made on the specified date.
+    pub date: Option<String>,
+    /// Filter the response to payouts made inclusively before the specified date.
+    pub date_max: Option<String>,
+    /// Filter the response to payouts made inclusively after the specified date.
+    pub date_min: Option<String>,
+    /// Filter the response to payouts made before the specified ID.
+    pub last_id: Option<String>,
+    /// Filter the response to payouts made after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter the response to payouts made with the specified status.
+    pub status: Option<String>,
+}
+
+impl ShopifyPaymentsGetPayoutParams {
+    pub fn date<T: ToString>(mut self, date: T) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+
+    pub fn date_max<T: ToString>(mut self, date_max: T) -> Self {
+        self.date_max = Some(date_max.to_string());
+        self
+    }
+
+    pub fn date_min<T: ToString>(mut self, date_min: T) -> Self {
+        self.date_min = Some(date_min.to_string());
+        self
+    }
+
+    pub fn last_id<T: ToString>(mut self, last_id: T) -> Self {
+        self.last_id = Some(last_id.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShopifyPayments::deprecated_202101_get_payout_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShopifyPaymentsDeprecated202101GetPayoutParams {
+    /// Filter the response to payouts made on the specified date.
+    pub date: Option<String>,
+    /// Filter the response to payouts made inclusively before the specified date.
+    pub date_max: Option<String>,
+    /// Filter the response to payouts made inclusively after the specified date.
+    pub date_min: Option<String>,
+    /// Filter the response to payouts made before the specified ID.
+    pub last_id: Option<String>,
+    /// Filter the response to payouts made after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter the response to payouts made with the specified status.
+    pub status: Option<String>,
+}
+
+impl ShopifyPaymentsDeprecated202101GetPayoutParams {
+    pub fn date<T: ToString>(mut self, date: T) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+
+    pub fn date_max<T: ToString>(mut self, date_max: T) -> Self {
+        self.date_max = Some(date_max.to_string());
+        self
+    }
+
+    pub fn date_min<T: ToString>(mut self, date_min: T) -> Self {
+        self.date_min = Some(date_min.to_string());
+        self
+    }
+
+    pub fn last_id<T: ToString>(mut self, last_id: T) -> Self {
+        self.last_id = Some(last_id.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShopifyPayments::deprecated_unstable_get_payout_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShopifyPaymentsDeprecatedUnstableGetPayoutParams {
+    /// Filter the response to payouts made on the specified date.
+    pub date: Option<String>,
+    /// Filter the response to payouts made inclusively before the specified date.
+    pub date_max: Option<String>,
+    /// Filter the response to payouts made inclusively after the specified date.
+    pub date_min: Option<String>,
+    /// Filter the response to payouts made before the specified ID.
+    pub last_id: Option<String>,
+    /// Filter the response to payouts made after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter the response to payouts made with the specified status.
+    pub status: Option<String>,
+}
+
+impl ShopifyPaymentsDeprecatedUnstableGetPayoutParams {
+    pub fn date<T: ToString>(mut self, date: T) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+
+    pub fn date_max<T: ToString>(mut self, date_max: T) -> Self {
+        self.date_max = Some(date_max.to_string());
+        self
+    }
+
+    pub fn date_min<T: ToString>(mut self, date_min: T) -> Self {
+        self.date_min = Some(date_min.to_string());
+        self
+    }
+
+    pub fn last_id<T: ToString>(mut self, last_id: T) -> Self {
+        self.last_id = Some(last_id.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ShopifyPayments::deprecated_202001_get_balance_transaction_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ShopifyPaymentsDeprecated202001GetBalanceTransactionParams {
+    /// Filter response to transactions exclusively before the specified ID.
+    pub last_id: Option<String>,
+    /// Filter response to transactions paid out in the specified payout.
+    pub payout_id: Option<String>,
+    /// Filter response to transactions with the specified payout status.
+    pub payout_status: Option<String>,
+    /// Filter response to transactions exclusively after the specified ID.
+    pub since_id: Option<String>,
+    /// Filter response to transactions placed in test mode.
+    pub test: Option<String>,
+}
+
+impl ShopifyPaymentsDeprecated202001GetBalanceTransactionParams {
+    pub fn last_id<T: ToString>(mut self, last_id: T) -> Self {
+        self.last_id = Some(last_id.to_string());
+        self
+    }
+
+    pub fn payout_id<T: ToString>(mut self, payout_id: T) -> Self {
+        self.payout_id = Some(payout_id.to_string());
+        self
+    }
+
+    pub fn payout_status<T: ToString>(mut self, payout_status: T) -> Self {
+        self.payout_status = Some(payout_status.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+
+    pub fn test<T: ToString>(mut self, test: T) -> Self {
+        self.test = Some(test.to_string());
+        self
+    }
+}
+
+/// The functions of [`ShopifyPayments`], for code that takes them as a trait, so that it can be
+/// tested with the `MockShopifyPaymentsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ShopifyPaymentsApi: Send + Sync {
+    /// See [`ShopifyPayments::deprecated_202001_get_balance`].
+    async fn deprecated_202001_get_balance(&self) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202004_get_balance`].
+    async fn deprecated_202004_get_balance(&self) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202007_get_balance`].
+    async fn deprecated_202007_get_balance(&self) -> Result<()>;
+
+    /// See [`ShopifyPayments::get_balance`].
+    async fn get_balance(&self) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202101_get_balance`].
+    async fn deprecated_202101_get_balance(&self) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_unstable_get_balance`].
+    async fn deprecated_unstable_get_balance(&self) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202001_get_dispute_with`].
+    async fn deprecated_202001_get_dispute_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202001GetDisputeParams,
+    ) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202001_get_disputes_param_dispute`].
+    async fn deprecated_202001_get_disputes_param_dispute(&self, dispute_id: &str) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202004_get_dispute_with`].
+    async fn deprecated_202004_get_dispute_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202004GetDisputeParams,
+    ) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202001_get_payout_with`].
+    async fn deprecated_202001_get_payout_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202001GetPayoutParams,
+    ) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202001_get_payouts_param_payout`].
+    async fn deprecated_202001_get_payouts_param_payout(&self, payout_id: &str) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202004_get_payout_with`].
+    async fn deprecated_202004_get_payout_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202004GetPayoutParams,
+    ) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202004_get_payouts_param_payout`].
+    async fn deprecated_202004_get_payouts_param_payout(&self, payout_id: &str) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202007_get_payout_with`].
+    async fn deprecated_202007_get_payout_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202007GetPayoutParams,
+    ) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202007_get_payouts_param_payout`].
+    async fn deprecated_202007_get_payouts_param_payout(&self, payout_id: &str) -> Result<()>;
+
+    /// See [`ShopifyPayments::get_payout_with`].
+    async fn get_payout_with(&self, params: &ShopifyPaymentsGetPayoutParams) -> Result<()>;
+
+    /// See [`ShopifyPayments::get_payouts_param_payout`].
+    async fn get_payouts_param_payout(&self, payout_id: &str) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202101_get_payout_with`].
+    async fn deprecated_202101_get_payout_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202101GetPayoutParams,
+    ) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202101_get_payouts_param_payout`].
+    async fn deprecated_202101_get_payouts_param_payout(&self, payout_id: &str) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_unstable_get_payout_with`].
+    async fn deprecated_unstable_get_payout_with(
+        &self,
+        params: &ShopifyPaymentsDeprecatedUnstableGetPayoutParams,
+    ) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_unstable_get_payouts_param_payout`].
+    async fn deprecated_unstable_get_payouts_param_payout(&self, payout_id: &str) -> Result<()>;
+
+    /// See [`ShopifyPayments::deprecated_202001_get_balance_transaction_with`].
+    async fn deprecated_202001_get_balance_transaction_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202001GetBalanceTransactionParams,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl ShopifyPaymentsApi for ShopifyPayments {
+    async fn deprecated_202001_get_balance(&self) -> Result<()> {
+        ShopifyPayments::deprecated_202001_get_balance(self).await
+    }
+
+    async fn deprecated_202004_get_balance(&self) -> Result<()> {
+        ShopifyPayments::deprecated_202004_get_balance(self).await
+    }
+
+    async fn deprecated_202007_get_balance(&self) -> Result<()> {
+        ShopifyPayments::deprecated_202007_get_balance(self).await
+    }
+
+    async fn get_balance(&self) -> Result<()> {
+        ShopifyPayments::get_balance(self).await
+    }
+
+    async fn deprecated_202101_get_balance(&self) -> Result<()> {
+        ShopifyPayments::deprecated_202101_get_balance(self).await
+    }
+
+    async fn deprecated_unstable_get_balance(&self) -> Result<()> {
+        ShopifyPayments::deprecated_unstable_get_balance(self).await
+    }
+
+    async fn deprecated_202001_get_dispute_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202001GetDisputeParams,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202001_get_dispute_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_disputes_param_dispute(&self, dispute_id: &str) -> Result<()> {
+        ShopifyPayments::deprecated_202001_get_disputes_param_dispute(self, dispute_id).await
+    }
+
+    async fn deprecated_202004_get_dispute_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202004GetDisputeParams,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202004_get_dispute_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_payout_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202001GetPayoutParams,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202001_get_payout_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+        ShopifyPayments::deprecated_202001_get_payouts_param_payout(self, payout_id).await
+    }
+
+    async fn deprecated_202004_get_payout_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202004GetPayoutParams,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202004_get_payout_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+        ShopifyPayments::deprecated_202004_get_payouts_param_payout(self, payout_id).await
+    }
+
+    async fn deprecated_202007_get_payout_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202007GetPayoutParams,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202007_get_payout_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+        ShopifyPayments::deprecated_202007_get_payouts_param_payout(self, payout_id).await
+    }
+
+    async fn get_payout_with(&self, params: &ShopifyPaymentsGetPayoutParams) -> Result<()> {
+        ShopifyPayments::get_payout_with(self, params).await
+    }
+
+    async fn get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+        ShopifyPayments::get_payouts_param_payout(self, payout_id).await
+    }
+
+    async fn deprecated_202101_get_payout_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202101GetPayoutParams,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202101_get_payout_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+        ShopifyPayments::deprecated_202101_get_payouts_param_payout(self, payout_id).await
+    }
+
+    async fn deprecated_unstable_get_payout_with(
+        &self,
+        params: &ShopifyPaymentsDeprecatedUnstableGetPayoutParams,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_unstable_get_payout_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_payouts_param_payout(&self, payout_id: &str) -> Result<()> {
+        ShopifyPayments::deprecated_unstable_get_payouts_param_payout(self, payout_id).await
+    }
+
+    async fn deprecated_202001_get_balance_transaction_with(
+        &self,
+        params: &ShopifyPaymentsDeprecated202001GetBalanceTransactionParams,
+    ) -> Result<()> {
+        ShopifyPayments::deprecated_202001_get_balance_transaction_with(self, params).await
+    }
 }
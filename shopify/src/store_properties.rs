@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct StoreProperties {
     pub client: Client,
@@ -21,15 +19,17 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecated202001GetCountrieParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecated202001GetCountrieParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -38,6 +38,18 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_countrie_with` and pass the query parameters as a `StorePropertiesDeprecated202001GetCountrieParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_countrie_with` instead")]
+    pub async fn deprecated_202001_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecated202001GetCountrieParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_countrie_with(&params).await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -77,15 +89,15 @@ impl StoreProperties {
      * **Parameters:**
      *
      * * `country_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecated202001GetCountriesParamCountryParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_countries_param_country(
+    pub async fn deprecated_202001_get_countries_param_country_with(
         &self,
         country_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecated202001GetCountriesParamCountryParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -98,6 +110,22 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_countries_param_country_with` and pass the query parameters as a `StorePropertiesDeprecated202001GetCountriesParamCountryParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_countries_param_country_with` instead")]
+    pub async fn deprecated_202001_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = StorePropertiesDeprecated202001GetCountriesParamCountryParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_countries_param_country_with(country_id, &params)
+            .await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -159,15 +187,17 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecated202004GetCountrieParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecated202004GetCountrieParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -176,6 +206,18 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_countrie_with` and pass the query parameters as a `StorePropertiesDeprecated202004GetCountrieParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_countrie_with` instead")]
+    pub async fn deprecated_202004_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecated202004GetCountrieParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_countrie_with(&params).await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -215,15 +257,15 @@ impl StoreProperties {
      * **Parameters:**
      *
      * * `country_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecated202004GetCountriesParamCountryParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_countries_param_country(
+    pub async fn deprecated_202004_get_countries_param_country_with(
         &self,
         country_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecated202004GetCountriesParamCountryParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -236,6 +278,22 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_countries_param_country_with` and pass the query parameters as a `StorePropertiesDeprecated202004GetCountriesParamCountryParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_countries_param_country_with` instead")]
+    pub async fn deprecated_202004_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = StorePropertiesDeprecated202004GetCountriesParamCountryParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_countries_param_country_with(country_id, &params)
+            .await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -297,15 +355,17 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecated202007GetCountrieParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecated202007GetCountrieParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -314,6 +374,18 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_countrie_with` and pass the query parameters as a `StorePropertiesDeprecated202007GetCountrieParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_countrie_with` instead")]
+    pub async fn deprecated_202007_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecated202007GetCountrieParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_countrie_with(&params).await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -353,15 +425,15 @@ impl StoreProperties {
      * **Parameters:**
      *
      * * `country_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecated202007GetCountriesParamCountryParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_countries_param_country(
+    pub async fn deprecated_202007_get_countries_param_country_with(
         &self,
         country_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecated202007GetCountriesParamCountryParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -374,6 +446,22 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_countries_param_country_with` and pass the query parameters as a `StorePropertiesDeprecated202007GetCountriesParamCountryParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_countries_param_country_with` instead")]
+    pub async fn deprecated_202007_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = StorePropertiesDeprecated202007GetCountriesParamCountryParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_countries_param_country_with(country_id, &params)
+            .await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -435,15 +523,14 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesGetCountrieParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_countrie_with(&self, params: &StorePropertiesGetCountrieParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -452,6 +539,18 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_countrie_with` and pass the query parameters as a `StorePropertiesGetCountrieParams` instead.
+     */
+    #[deprecated(note = "use `get_countrie_with` instead")]
+    pub async fn get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+        let params = StorePropertiesGetCountrieParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_countrie_with(&params).await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -491,11 +590,15 @@ impl StoreProperties {
      * **Parameters:**
      *
      * * `country_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesGetCountriesParamCountryParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_countries_param_country(&self, country_id: &str, fields: &str) -> Result<()> {
+    pub async fn get_countries_param_country_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesGetCountriesParamCountryParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -508,6 +611,18 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_countries_param_country_with` and pass the query parameters as a `StorePropertiesGetCountriesParamCountryParams` instead.
+     */
+    #[deprecated(note = "use `get_countries_param_country_with` instead")]
+    pub async fn get_countries_param_country(&self, country_id: &str, fields: &str) -> Result<()> {
+        let params = StorePropertiesGetCountriesParamCountryParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_countries_param_country_with(country_id, &params)
+            .await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -566,15 +681,17 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecated202101GetCountrieParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecated202101GetCountrieParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -583,6 +700,18 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_countrie_with` and pass the query parameters as a `StorePropertiesDeprecated202101GetCountrieParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_countrie_with` instead")]
+    pub async fn deprecated_202101_get_countrie(&self, since_id: &str, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecated202101GetCountrieParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_countrie_with(&params).await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -622,15 +751,15 @@ impl StoreProperties {
      * **Parameters:**
      *
      * * `country_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecated202101GetCountriesParamCountryParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_countries_param_country(
+    pub async fn deprecated_202101_get_countries_param_country_with(
         &self,
         country_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecated202101GetCountriesParamCountryParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -643,6 +772,22 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_countries_param_country_with` and pass the query parameters as a `StorePropertiesDeprecated202101GetCountriesParamCountryParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_countries_param_country_with` instead")]
+    pub async fn deprecated_202101_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = StorePropertiesDeprecated202101GetCountriesParamCountryParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_countries_param_country_with(country_id, &params)
+            .await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -704,19 +849,17 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecatedUnstableGetCountrieParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_countrie(
+    pub async fn deprecated_unstable_get_countrie_with(
         &self,
-        since_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecatedUnstableGetCountrieParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -725,6 +868,22 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_countrie_with` and pass the query parameters as a `StorePropertiesDeprecatedUnstableGetCountrieParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_countrie_with` instead")]
+    pub async fn deprecated_unstable_get_countrie(
+        &self,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = StorePropertiesDeprecatedUnstableGetCountrieParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_countrie_with(&params).await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -767,15 +926,15 @@ impl StoreProperties {
      * **Parameters:**
      *
      * * `country_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecatedUnstableGetCountriesParamCountryParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_countries_param_country(
+    pub async fn deprecated_unstable_get_countries_param_country_with(
         &self,
         country_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecatedUnstableGetCountriesParamCountryParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -788,6 +947,22 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_countries_param_country_with` and pass the query parameters as a `StorePropertiesDeprecatedUnstableGetCountriesParamCountryParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_countries_param_country_with` instead")]
+    pub async fn deprecated_unstable_get_countries_param_country(
+        &self,
+        country_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = StorePropertiesDeprecatedUnstableGetCountriesParamCountryParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_countries_param_country_with(country_id, &params)
+            .await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -994,20 +1169,18 @@ impl StoreProperties {
      * **Parameters:**
      *
      * * `country_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of fields names.
+     * * `params: &StorePropertiesDeprecated202001GetCountriesParamCountryProvinceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_countries_param_country_province(
+    pub async fn deprecated_202001_get_countries_param_country_province_with(
         &self,
         country_id: &str,
-        since_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecated202001GetCountriesParamCountryProvinceParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1020,6 +1193,26 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_countries_param_country_province_with` and pass the query parameters as a `StorePropertiesDeprecated202001GetCountriesParamCountryProvinceParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_countries_param_country_province_with` instead"
+    )]
+    pub async fn deprecated_202001_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = StorePropertiesDeprecated202001GetCountriesParamCountryProvinceParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_countries_param_country_province_with(country_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a count of provinces for a country.
      *
@@ -1054,16 +1247,16 @@ impl StoreProperties {
      *
      * * `country_id: &str` -- storefront_access_token_id.
      * * `province_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_countries_param_country_provinces_province(
+    pub async fn deprecated_202001_get_countries_param_country_provinces_province_with(
         &self,
         country_id: &str,
         province_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1077,6 +1270,31 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_countries_param_country_provinces_province_with` and pass the query parameters as a `StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202001_get_countries_param_country_provinces_province_with` \
+                instead"
+    )]
+    pub async fn deprecated_202001_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params =
+            StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceParams {
+                fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202001_get_countries_param_country_provinces_province_with(
+            country_id,
+            province_id,
+            &params,
+        )
+        .await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -1119,20 +1337,18 @@ impl StoreProperties {
      * **Parameters:**
      *
      * * `country_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of fields names.
+     * * `params: &StorePropertiesDeprecated202004GetCountriesParamCountryProvinceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_countries_param_country_province(
+    pub async fn deprecated_202004_get_countries_param_country_province_with(
         &self,
         country_id: &str,
-        since_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecated202004GetCountriesParamCountryProvinceParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1145,6 +1361,26 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_countries_param_country_province_with` and pass the query parameters as a `StorePropertiesDeprecated202004GetCountriesParamCountryProvinceParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_countries_param_country_province_with` instead"
+    )]
+    pub async fn deprecated_202004_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = StorePropertiesDeprecated202004GetCountriesParamCountryProvinceParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_countries_param_country_province_with(country_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a count of provinces for a country.
      *
@@ -1179,16 +1415,16 @@ impl StoreProperties {
      *
      * * `country_id: &str` -- storefront_access_token_id.
      * * `province_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_countries_param_country_provinces_province(
+    pub async fn deprecated_202004_get_countries_param_country_provinces_province_with(
         &self,
         country_id: &str,
         province_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1202,6 +1438,31 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_countries_param_country_provinces_province_with` and pass the query parameters as a `StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202004_get_countries_param_country_provinces_province_with` \
+                instead"
+    )]
+    pub async fn deprecated_202004_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params =
+            StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceParams {
+                fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202004_get_countries_param_country_provinces_province_with(
+            country_id,
+            province_id,
+            &params,
+        )
+        .await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -1244,20 +1505,18 @@ impl StoreProperties {
      * **Parameters:**
      *
      * * `country_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of fields names.
+     * * `params: &StorePropertiesDeprecated202007GetCountriesParamCountryProvinceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_countries_param_country_province(
+    pub async fn deprecated_202007_get_countries_param_country_province_with(
         &self,
         country_id: &str,
-        since_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecated202007GetCountriesParamCountryProvinceParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1270,6 +1529,26 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_countries_param_country_province_with` and pass the query parameters as a `StorePropertiesDeprecated202007GetCountriesParamCountryProvinceParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_countries_param_country_province_with` instead"
+    )]
+    pub async fn deprecated_202007_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = StorePropertiesDeprecated202007GetCountriesParamCountryProvinceParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_countries_param_country_province_with(country_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a count of provinces for a country.
      *
@@ -1304,16 +1583,16 @@ impl StoreProperties {
      *
      * * `country_id: &str` -- storefront_access_token_id.
      * * `province_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_countries_param_country_provinces_province(
+    pub async fn deprecated_202007_get_countries_param_country_provinces_province_with(
         &self,
         country_id: &str,
         province_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1327,6 +1606,31 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_countries_param_country_provinces_province_with` and pass the query parameters as a `StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202007_get_countries_param_country_provinces_province_with` \
+                instead"
+    )]
+    pub async fn deprecated_202007_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params =
+            StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceParams {
+                fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202007_get_countries_param_country_provinces_province_with(
+            country_id,
+            province_id,
+            &params,
+        )
+        .await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -1369,20 +1673,18 @@ impl StoreProperties {
      * **Parameters:**
      *
      * * `country_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of fields names.
+     * * `params: &StorePropertiesGetCountriesParamCountryProvinceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_countries_param_country_province(
+    pub async fn get_countries_param_country_province_with(
         &self,
         country_id: &str,
-        since_id: &str,
-        fields: &str,
+        params: &StorePropertiesGetCountriesParamCountryProvinceParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1395,6 +1697,24 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_countries_param_country_province_with` and pass the query parameters as a `StorePropertiesGetCountriesParamCountryProvinceParams` instead.
+     */
+    #[deprecated(note = "use `get_countries_param_country_province_with` instead")]
+    pub async fn get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = StorePropertiesGetCountriesParamCountryProvinceParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_countries_param_country_province_with(country_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a count of provinces for a country.
      *
@@ -1429,16 +1749,16 @@ impl StoreProperties {
      *
      * * `country_id: &str` -- storefront_access_token_id.
      * * `province_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesGetCountriesParamCountryProvincesProvinceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_countries_param_country_provinces_province(
+    pub async fn get_countries_param_country_provinces_province_with(
         &self,
         country_id: &str,
         province_id: &str,
-        fields: &str,
+        params: &StorePropertiesGetCountriesParamCountryProvincesProvinceParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1452,6 +1772,23 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_countries_param_country_provinces_province_with` and pass the query parameters as a `StorePropertiesGetCountriesParamCountryProvincesProvinceParams` instead.
+     */
+    #[deprecated(note = "use `get_countries_param_country_provinces_province_with` instead")]
+    pub async fn get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = StorePropertiesGetCountriesParamCountryProvincesProvinceParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_countries_param_country_provinces_province_with(country_id, province_id, &params)
+            .await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -1494,20 +1831,18 @@ impl StoreProperties {
      * **Parameters:**
      *
      * * `country_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of fields names.
+     * * `params: &StorePropertiesDeprecated202101GetCountriesParamCountryProvinceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_countries_param_country_province(
+    pub async fn deprecated_202101_get_countries_param_country_province_with(
         &self,
         country_id: &str,
-        since_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecated202101GetCountriesParamCountryProvinceParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1520,6 +1855,26 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_countries_param_country_province_with` and pass the query parameters as a `StorePropertiesDeprecated202101GetCountriesParamCountryProvinceParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_countries_param_country_province_with` instead"
+    )]
+    pub async fn deprecated_202101_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = StorePropertiesDeprecated202101GetCountriesParamCountryProvinceParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_countries_param_country_province_with(country_id, &params)
+            .await
+    }
+
     /**
      * Retrieves a count of provinces for a country.
      *
@@ -1554,16 +1909,16 @@ impl StoreProperties {
      *
      * * `country_id: &str` -- storefront_access_token_id.
      * * `province_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_countries_param_country_provinces_province(
+    pub async fn deprecated_202101_get_countries_param_country_provinces_province_with(
         &self,
         country_id: &str,
         province_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1577,6 +1932,31 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_countries_param_country_provinces_province_with` and pass the query parameters as a `StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_202101_get_countries_param_country_provinces_province_with` \
+                instead"
+    )]
+    pub async fn deprecated_202101_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params =
+            StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceParams {
+                fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_202101_get_countries_param_country_provinces_province_with(
+            country_id,
+            province_id,
+            &params,
+        )
+        .await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -1619,20 +1999,18 @@ impl StoreProperties {
      * **Parameters:**
      *
      * * `country_id: &str` -- storefront_access_token_id.
-     * * `since_id: &str` -- Restrict results to after the specified ID.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of fields names.
+     * * `params: &StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_countries_param_country_province(
+    pub async fn deprecated_unstable_get_countries_param_country_province_with(
         &self,
         country_id: &str,
-        since_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1646,7 +2024,27 @@ impl StoreProperties {
     }
 
     /**
-     * Retrieves a count of provinces for a country.
+     * Deprecated: use `deprecated_unstable_get_countries_param_country_province_with` and pass the query parameters as a `StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_countries_param_country_province_with` instead"
+    )]
+    pub async fn deprecated_unstable_get_countries_param_country_province(
+        &self,
+        country_id: &str,
+        since_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params = StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_countries_param_country_province_with(country_id, &params)
+            .await
+    }
+
+    /**
+     * Retrieves a count of provinces for a country.
      *
      * This function performs a `GET` to the `/admin/api/unstable/countries/{country_id}/provinces/count.json` endpoint.
      *
@@ -1679,16 +2077,16 @@ impl StoreProperties {
      *
      * * `country_id: &str` -- storefront_access_token_id.
      * * `province_id: &str` -- storefront_access_token_id.
-     * * `fields: &str` -- Show only certain fields, specified by a comma-separated list of field names.
+     * * `params: &StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_countries_param_country_provinces_province(
+    pub async fn deprecated_unstable_get_countries_param_country_provinces_province_with(
         &self,
         country_id: &str,
         province_id: &str,
-        fields: &str,
+        params: &StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1702,6 +2100,31 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_countries_param_country_provinces_province_with` and pass the query parameters as a `StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceParams` instead.
+     */
+    #[deprecated(
+        note = "use `deprecated_unstable_get_countries_param_country_provinces_province_with` \
+                instead"
+    )]
+    pub async fn deprecated_unstable_get_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        fields: &str,
+    ) -> Result<()> {
+        let params =
+            StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceParams {
+                fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+            };
+        self.deprecated_unstable_get_countries_param_country_provinces_province_with(
+            country_id,
+            province_id,
+            &params,
+        )
+        .await
+    }
+
     /**
     * Caution
       As of version 2020-10, the tax field is deprecated.
@@ -1743,11 +2166,14 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &StorePropertiesDeprecated202001GetShippingZoneParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_shipping_zone(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecated202001GetShippingZoneParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1756,6 +2182,17 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_shipping_zone_with` and pass the query parameters as a `StorePropertiesDeprecated202001GetShippingZoneParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_shipping_zone_with` instead")]
+    pub async fn deprecated_202001_get_shipping_zone(&self, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecated202001GetShippingZoneParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_shipping_zone_with(&params).await
+    }
+
     /**
      * Get a list of all shipping zones.
      *
@@ -1765,11 +2202,14 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &StorePropertiesDeprecated202004GetShippingZoneParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_shipping_zone(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecated202004GetShippingZoneParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1778,6 +2218,17 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_shipping_zone_with` and pass the query parameters as a `StorePropertiesDeprecated202004GetShippingZoneParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_shipping_zone_with` instead")]
+    pub async fn deprecated_202004_get_shipping_zone(&self, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecated202004GetShippingZoneParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_shipping_zone_with(&params).await
+    }
+
     /**
      * Get a list of all shipping zones.
      *
@@ -1787,11 +2238,14 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &StorePropertiesDeprecated202007GetShippingZoneParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_shipping_zone(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecated202007GetShippingZoneParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1800,6 +2254,17 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_shipping_zone_with` and pass the query parameters as a `StorePropertiesDeprecated202007GetShippingZoneParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_shipping_zone_with` instead")]
+    pub async fn deprecated_202007_get_shipping_zone(&self, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecated202007GetShippingZoneParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_shipping_zone_with(&params).await
+    }
+
     /**
      * Get a list of all shipping zones.
      *
@@ -1809,11 +2274,14 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &StorePropertiesGetShippingZoneParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_shipping_zone(&self, fields: &str) -> Result<()> {
+    pub async fn get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesGetShippingZoneParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1822,6 +2290,17 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_shipping_zone_with` and pass the query parameters as a `StorePropertiesGetShippingZoneParams` instead.
+     */
+    #[deprecated(note = "use `get_shipping_zone_with` instead")]
+    pub async fn get_shipping_zone(&self, fields: &str) -> Result<()> {
+        let params = StorePropertiesGetShippingZoneParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_shipping_zone_with(&params).await
+    }
+
     /**
      * Get a list of all shipping zones.
      *
@@ -1831,11 +2310,14 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &StorePropertiesDeprecated202101GetShippingZoneParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_shipping_zone(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecated202101GetShippingZoneParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1844,6 +2326,17 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_shipping_zone_with` and pass the query parameters as a `StorePropertiesDeprecated202101GetShippingZoneParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_shipping_zone_with` instead")]
+    pub async fn deprecated_202101_get_shipping_zone(&self, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecated202101GetShippingZoneParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_shipping_zone_with(&params).await
+    }
+
     /**
      * Get a list of all shipping zones.
      *
@@ -1853,11 +2346,14 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- comma-separated list of fields to include in the response.
+     * * `params: &StorePropertiesDeprecatedUnstableGetShippingZoneParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_shipping_zone(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecatedUnstableGetShippingZoneParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1866,6 +2362,18 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_unstable_get_shipping_zone_with` and pass the query parameters as a `StorePropertiesDeprecatedUnstableGetShippingZoneParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_shipping_zone_with` instead")]
+    pub async fn deprecated_unstable_get_shipping_zone(&self, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecatedUnstableGetShippingZoneParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_shipping_zone_with(&params)
+            .await
+    }
+
     /**
      * Retrieves the shop's configuration.
      *
@@ -1875,11 +2383,14 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &StorePropertiesDeprecated202001GetShopParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_shop(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202001_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecated202001GetShopParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1888,6 +2399,17 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_shop_with` and pass the query parameters as a `StorePropertiesDeprecated202001GetShopParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_shop_with` instead")]
+    pub async fn deprecated_202001_get_shop(&self, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecated202001GetShopParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_shop_with(&params).await
+    }
+
     /**
      * Retrieves the shop's configuration.
      *
@@ -1897,11 +2419,14 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &StorePropertiesDeprecated202004GetShopParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_shop(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202004_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecated202004GetShopParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1910,6 +2435,17 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_shop_with` and pass the query parameters as a `StorePropertiesDeprecated202004GetShopParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_shop_with` instead")]
+    pub async fn deprecated_202004_get_shop(&self, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecated202004GetShopParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_shop_with(&params).await
+    }
+
     /**
      * Retrieves the shop's configuration.
      *
@@ -1919,11 +2455,14 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &StorePropertiesDeprecated202007GetShopParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_shop(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202007_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecated202007GetShopParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1932,6 +2471,17 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_shop_with` and pass the query parameters as a `StorePropertiesDeprecated202007GetShopParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_shop_with` instead")]
+    pub async fn deprecated_202007_get_shop(&self, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecated202007GetShopParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_shop_with(&params).await
+    }
+
     /**
      * Retrieves the shop's configuration.
      *
@@ -1941,11 +2491,11 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &StorePropertiesGetShopParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_shop(&self, fields: &str) -> Result<()> {
+    pub async fn get_shop_with(&self, params: &StorePropertiesGetShopParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1954,6 +2504,17 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_shop_with` and pass the query parameters as a `StorePropertiesGetShopParams` instead.
+     */
+    #[deprecated(note = "use `get_shop_with` instead")]
+    pub async fn get_shop(&self, fields: &str) -> Result<()> {
+        let params = StorePropertiesGetShopParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_shop_with(&params).await
+    }
+
     /**
      * Retrieves the shop's configuration.
      *
@@ -1963,11 +2524,14 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &StorePropertiesDeprecated202101GetShopParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_shop(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_202101_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecated202101GetShopParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1976,6 +2540,17 @@ impl StoreProperties {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_shop_with` and pass the query parameters as a `StorePropertiesDeprecated202101GetShopParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_shop_with` instead")]
+    pub async fn deprecated_202101_get_shop(&self, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecated202101GetShopParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_shop_with(&params).await
+    }
+
     /**
      * Retrieves the shop's configuration.
      *
@@ -1985,11 +2560,14 @@ impl StoreProperties {
      *
      * **Parameters:**
      *
-     * * `fields: &str` -- A comma-separated list of fields to include in the response.
+     * * `params: &StorePropertiesDeprecatedUnstableGetShopParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_shop(&self, fields: &str) -> Result<()> {
+    pub async fn deprecated_unstable_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecatedUnstableGetShopParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !fields.is_empty() {
+        if let Some(fields) = &params.fields {
             query_args.push(("fields".to_string(), fields.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1997,4 +2575,1742 @@ impl StoreProperties {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `deprecated_unstable_get_shop_with` and pass the query parameters as a `StorePropertiesDeprecatedUnstableGetShopParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_shop_with` instead")]
+    pub async fn deprecated_unstable_get_shop(&self, fields: &str) -> Result<()> {
+        let params = StorePropertiesDeprecatedUnstableGetShopParams {
+            fields: Some(fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_shop_with(&params).await
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202001_get_countrie_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202001GetCountrieParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl StorePropertiesDeprecated202001GetCountrieParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202001_get_countries_param_country_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202001GetCountriesParamCountryParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202001GetCountriesParamCountryParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202004_get_countrie_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202004GetCountrieParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl StorePropertiesDeprecated202004GetCountrieParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202004_get_countries_param_country_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202004GetCountriesParamCountryParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202004GetCountriesParamCountryParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202007_get_countrie_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202007GetCountrieParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl StorePropertiesDeprecated202007GetCountrieParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202007_get_countries_param_country_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202007GetCountriesParamCountryParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202007GetCountriesParamCountryParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::get_countrie_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesGetCountrieParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl StorePropertiesGetCountrieParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::get_countries_param_country_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesGetCountriesParamCountryParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesGetCountriesParamCountryParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202101_get_countrie_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202101GetCountrieParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl StorePropertiesDeprecated202101GetCountrieParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202101_get_countries_param_country_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202101GetCountriesParamCountryParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202101GetCountriesParamCountryParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_unstable_get_countrie_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecatedUnstableGetCountrieParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl StorePropertiesDeprecatedUnstableGetCountrieParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_unstable_get_countries_param_country_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecatedUnstableGetCountriesParamCountryParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecatedUnstableGetCountriesParamCountryParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202001_get_countries_param_country_province_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202001GetCountriesParamCountryProvinceParams {
+    /// Show only certain fields, specified by a comma-separated list of fields names.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl StorePropertiesDeprecated202001GetCountriesParamCountryProvinceParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202001_get_countries_param_country_provinces_province_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202004_get_countries_param_country_province_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202004GetCountriesParamCountryProvinceParams {
+    /// Show only certain fields, specified by a comma-separated list of fields names.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl StorePropertiesDeprecated202004GetCountriesParamCountryProvinceParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202004_get_countries_param_country_provinces_province_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202007_get_countries_param_country_province_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202007GetCountriesParamCountryProvinceParams {
+    /// Show only certain fields, specified by a comma-separated list of fields names.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl StorePropertiesDeprecated202007GetCountriesParamCountryProvinceParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202007_get_countries_param_country_provinces_province_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::get_countries_param_country_province_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesGetCountriesParamCountryProvinceParams {
+    /// Show only certain fields, specified by a comma-separated list of fields names.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl StorePropertiesGetCountriesParamCountryProvinceParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::get_countries_param_country_provinces_province_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesGetCountriesParamCountryProvincesProvinceParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesGetCountriesParamCountryProvincesProvinceParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202101_get_countries_param_country_province_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202101GetCountriesParamCountryProvinceParams {
+    /// Show only certain fields, specified by a comma-separated list of fields names.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl StorePropertiesDeprecated202101GetCountriesParamCountryProvinceParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202101_get_countries_param_country_provinces_province_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_unstable_get_countries_param_country_province_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceParams {
+    /// Show only certain fields, specified by a comma-separated list of fields names.
+    pub fields: Option<String>,
+    /// Restrict results to after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_unstable_get_countries_param_country_provinces_province_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceParams {
+    /// Show only certain fields, specified by a comma-separated list of field names.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202001_get_shipping_zone_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202001GetShippingZoneParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202001GetShippingZoneParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202004_get_shipping_zone_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202004GetShippingZoneParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202004GetShippingZoneParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202007_get_shipping_zone_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202007GetShippingZoneParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202007GetShippingZoneParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::get_shipping_zone_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesGetShippingZoneParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesGetShippingZoneParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202101_get_shipping_zone_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202101GetShippingZoneParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202101GetShippingZoneParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_unstable_get_shipping_zone_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecatedUnstableGetShippingZoneParams {
+    /// comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecatedUnstableGetShippingZoneParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202001_get_shop_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202001GetShopParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202001GetShopParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202004_get_shop_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202004GetShopParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202004GetShopParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202007_get_shop_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202007GetShopParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202007GetShopParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::get_shop_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesGetShopParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesGetShopParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_202101_get_shop_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecated202101GetShopParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecated202101GetShopParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `StoreProperties::deprecated_unstable_get_shop_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StorePropertiesDeprecatedUnstableGetShopParams {
+    /// A comma-separated list of fields to include in the response.
+    pub fields: Option<String>,
+}
+
+impl StorePropertiesDeprecatedUnstableGetShopParams {
+    pub fn fields<T: ToString>(mut self, fields: T) -> Self {
+        self.fields = Some(fields.to_string());
+        self
+    }
+}
+
+/// The functions of [`StoreProperties`], for code that takes them as a trait, so that it can be
+/// tested with the `MockStorePropertiesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait StorePropertiesApi: Send + Sync {
+    /// See [`StoreProperties::deprecated_202001_get_countrie_with`].
+    async fn deprecated_202001_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecated202001GetCountrieParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202001_create_countries`].
+    async fn deprecated_202001_create_countries(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202001_get_countries_count`].
+    async fn deprecated_202001_get_countries_count(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202001_get_countries_param_country_with`].
+    async fn deprecated_202001_get_countries_param_country_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202001GetCountriesParamCountryParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202001_update_countries_param_country`].
+    async fn deprecated_202001_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202001_delete_countries_param_country`].
+    async fn deprecated_202001_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_get_countrie_with`].
+    async fn deprecated_202004_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecated202004GetCountrieParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_create_countries`].
+    async fn deprecated_202004_create_countries(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_get_countries_count`].
+    async fn deprecated_202004_get_countries_count(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_get_countries_param_country_with`].
+    async fn deprecated_202004_get_countries_param_country_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202004GetCountriesParamCountryParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_update_countries_param_country`].
+    async fn deprecated_202004_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_delete_countries_param_country`].
+    async fn deprecated_202004_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_get_countrie_with`].
+    async fn deprecated_202007_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecated202007GetCountrieParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_create_countries`].
+    async fn deprecated_202007_create_countries(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_get_countries_count`].
+    async fn deprecated_202007_get_countries_count(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_get_countries_param_country_with`].
+    async fn deprecated_202007_get_countries_param_country_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202007GetCountriesParamCountryParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_update_countries_param_country`].
+    async fn deprecated_202007_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_delete_countries_param_country`].
+    async fn deprecated_202007_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::get_countrie_with`].
+    async fn get_countrie_with(&self, params: &StorePropertiesGetCountrieParams) -> Result<()>;
+
+    /// See [`StoreProperties::create_countries`].
+    async fn create_countries(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`StoreProperties::get_countries_count`].
+    async fn get_countries_count(&self) -> Result<()>;
+
+    /// See [`StoreProperties::get_countries_param_country_with`].
+    async fn get_countries_param_country_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesGetCountriesParamCountryParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::update_countries_param_country`].
+    async fn update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::delete_countries_param_country`].
+    async fn delete_countries_param_country(&self, country_id: &str) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_get_countrie_with`].
+    async fn deprecated_202101_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecated202101GetCountrieParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_create_countries`].
+    async fn deprecated_202101_create_countries(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_get_countries_count`].
+    async fn deprecated_202101_get_countries_count(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_get_countries_param_country_with`].
+    async fn deprecated_202101_get_countries_param_country_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202101GetCountriesParamCountryParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_update_countries_param_country`].
+    async fn deprecated_202101_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_delete_countries_param_country`].
+    async fn deprecated_202101_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_get_countrie_with`].
+    async fn deprecated_unstable_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecatedUnstableGetCountrieParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_create_countries`].
+    async fn deprecated_unstable_create_countries(&self, body: &serde_json::Value) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_get_countries_count`].
+    async fn deprecated_unstable_get_countries_count(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_get_countries_param_country_with`].
+    async fn deprecated_unstable_get_countries_param_country_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecatedUnstableGetCountriesParamCountryParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_update_countries_param_country`].
+    async fn deprecated_unstable_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_delete_countries_param_country`].
+    async fn deprecated_unstable_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202001_get_currencie`].
+    async fn deprecated_202001_get_currencie(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_get_currencie`].
+    async fn deprecated_202004_get_currencie(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_get_currencie`].
+    async fn deprecated_202007_get_currencie(&self) -> Result<()>;
+
+    /// See [`StoreProperties::get_currencie`].
+    async fn get_currencie(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_get_currencie`].
+    async fn deprecated_202101_get_currencie(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_get_currencie`].
+    async fn deprecated_unstable_get_currencie(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202001_get_policie`].
+    async fn deprecated_202001_get_policie(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_get_policie`].
+    async fn deprecated_202004_get_policie(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_get_policie`].
+    async fn deprecated_202007_get_policie(&self) -> Result<()>;
+
+    /// See [`StoreProperties::get_policie`].
+    async fn get_policie(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_get_policie`].
+    async fn deprecated_202101_get_policie(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_get_policie`].
+    async fn deprecated_unstable_get_policie(&self) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202001_get_countries_param_country_province_with`].
+    async fn deprecated_202001_get_countries_param_country_province_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202001GetCountriesParamCountryProvinceParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202001_get_countries_param_country_provinces_count`].
+    async fn deprecated_202001_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202001_get_countries_param_country_provinces_province_with`].
+    async fn deprecated_202001_get_countries_param_country_provinces_province_with(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        params: &StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202001_update_countries_param_country_provinces_province`].
+    async fn deprecated_202001_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_get_countries_param_country_province_with`].
+    async fn deprecated_202004_get_countries_param_country_province_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202004GetCountriesParamCountryProvinceParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_get_countries_param_country_provinces_count`].
+    async fn deprecated_202004_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_get_countries_param_country_provinces_province_with`].
+    async fn deprecated_202004_get_countries_param_country_provinces_province_with(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        params: &StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_update_countries_param_country_provinces_province`].
+    async fn deprecated_202004_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_get_countries_param_country_province_with`].
+    async fn deprecated_202007_get_countries_param_country_province_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202007GetCountriesParamCountryProvinceParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_get_countries_param_country_provinces_count`].
+    async fn deprecated_202007_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_get_countries_param_country_provinces_province_with`].
+    async fn deprecated_202007_get_countries_param_country_provinces_province_with(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        params: &StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_update_countries_param_country_provinces_province`].
+    async fn deprecated_202007_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::get_countries_param_country_province_with`].
+    async fn get_countries_param_country_province_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesGetCountriesParamCountryProvinceParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::get_countries_param_country_provinces_count`].
+    async fn get_countries_param_country_provinces_count(&self, country_id: &str) -> Result<()>;
+
+    /// See [`StoreProperties::get_countries_param_country_provinces_province_with`].
+    async fn get_countries_param_country_provinces_province_with(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        params: &StorePropertiesGetCountriesParamCountryProvincesProvinceParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::update_countries_param_country_provinces_province`].
+    async fn update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_get_countries_param_country_province_with`].
+    async fn deprecated_202101_get_countries_param_country_province_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202101GetCountriesParamCountryProvinceParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_get_countries_param_country_provinces_count`].
+    async fn deprecated_202101_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_get_countries_param_country_provinces_province_with`].
+    async fn deprecated_202101_get_countries_param_country_provinces_province_with(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        params: &StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_update_countries_param_country_provinces_province`].
+    async fn deprecated_202101_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_get_countries_param_country_province_with`].
+    async fn deprecated_unstable_get_countries_param_country_province_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_get_countries_param_country_provinces_count`].
+    async fn deprecated_unstable_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_get_countries_param_country_provinces_province_with`].
+    async fn deprecated_unstable_get_countries_param_country_provinces_province_with(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        params: &StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_update_countries_param_country_provinces_province`].
+    async fn deprecated_unstable_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202001_get_shipping_zone_with`].
+    async fn deprecated_202001_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecated202001GetShippingZoneParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_get_shipping_zone_with`].
+    async fn deprecated_202004_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecated202004GetShippingZoneParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_get_shipping_zone_with`].
+    async fn deprecated_202007_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecated202007GetShippingZoneParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::get_shipping_zone_with`].
+    async fn get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesGetShippingZoneParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_get_shipping_zone_with`].
+    async fn deprecated_202101_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecated202101GetShippingZoneParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_get_shipping_zone_with`].
+    async fn deprecated_unstable_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecatedUnstableGetShippingZoneParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202001_get_shop_with`].
+    async fn deprecated_202001_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecated202001GetShopParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202004_get_shop_with`].
+    async fn deprecated_202004_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecated202004GetShopParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202007_get_shop_with`].
+    async fn deprecated_202007_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecated202007GetShopParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::get_shop_with`].
+    async fn get_shop_with(&self, params: &StorePropertiesGetShopParams) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_202101_get_shop_with`].
+    async fn deprecated_202101_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecated202101GetShopParams,
+    ) -> Result<()>;
+
+    /// See [`StoreProperties::deprecated_unstable_get_shop_with`].
+    async fn deprecated_unstable_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecatedUnstableGetShopParams,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl StorePropertiesApi for StoreProperties {
+    async fn deprecated_202001_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecated202001GetCountrieParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_get_countrie_with(self, params).await
+    }
+
+    async fn deprecated_202001_create_countries(&self, body: &serde_json::Value) -> Result<()> {
+        StoreProperties::deprecated_202001_create_countries(self, body).await
+    }
+
+    async fn deprecated_202001_get_countries_count(&self) -> Result<()> {
+        StoreProperties::deprecated_202001_get_countries_count(self).await
+    }
+
+    async fn deprecated_202001_get_countries_param_country_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202001GetCountriesParamCountryParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_get_countries_param_country_with(
+            self, country_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_update_countries_param_country(self, country_id, body)
+            .await
+    }
+
+    async fn deprecated_202001_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_delete_countries_param_country(self, country_id).await
+    }
+
+    async fn deprecated_202004_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecated202004GetCountrieParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_get_countrie_with(self, params).await
+    }
+
+    async fn deprecated_202004_create_countries(&self, body: &serde_json::Value) -> Result<()> {
+        StoreProperties::deprecated_202004_create_countries(self, body).await
+    }
+
+    async fn deprecated_202004_get_countries_count(&self) -> Result<()> {
+        StoreProperties::deprecated_202004_get_countries_count(self).await
+    }
+
+    async fn deprecated_202004_get_countries_param_country_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202004GetCountriesParamCountryParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_get_countries_param_country_with(
+            self, country_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_update_countries_param_country(self, country_id, body)
+            .await
+    }
+
+    async fn deprecated_202004_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_delete_countries_param_country(self, country_id).await
+    }
+
+    async fn deprecated_202007_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecated202007GetCountrieParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_get_countrie_with(self, params).await
+    }
+
+    async fn deprecated_202007_create_countries(&self, body: &serde_json::Value) -> Result<()> {
+        StoreProperties::deprecated_202007_create_countries(self, body).await
+    }
+
+    async fn deprecated_202007_get_countries_count(&self) -> Result<()> {
+        StoreProperties::deprecated_202007_get_countries_count(self).await
+    }
+
+    async fn deprecated_202007_get_countries_param_country_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202007GetCountriesParamCountryParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_get_countries_param_country_with(
+            self, country_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_update_countries_param_country(self, country_id, body)
+            .await
+    }
+
+    async fn deprecated_202007_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_delete_countries_param_country(self, country_id).await
+    }
+
+    async fn get_countrie_with(&self, params: &StorePropertiesGetCountrieParams) -> Result<()> {
+        StoreProperties::get_countrie_with(self, params).await
+    }
+
+    async fn create_countries(&self, body: &serde_json::Value) -> Result<()> {
+        StoreProperties::create_countries(self, body).await
+    }
+
+    async fn get_countries_count(&self) -> Result<()> {
+        StoreProperties::get_countries_count(self).await
+    }
+
+    async fn get_countries_param_country_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesGetCountriesParamCountryParams,
+    ) -> Result<()> {
+        StoreProperties::get_countries_param_country_with(self, country_id, params).await
+    }
+
+    async fn update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::update_countries_param_country(self, country_id, body).await
+    }
+
+    async fn delete_countries_param_country(&self, country_id: &str) -> Result<()> {
+        StoreProperties::delete_countries_param_country(self, country_id).await
+    }
+
+    async fn deprecated_202101_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecated202101GetCountrieParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_get_countrie_with(self, params).await
+    }
+
+    async fn deprecated_202101_create_countries(&self, body: &serde_json::Value) -> Result<()> {
+        StoreProperties::deprecated_202101_create_countries(self, body).await
+    }
+
+    async fn deprecated_202101_get_countries_count(&self) -> Result<()> {
+        StoreProperties::deprecated_202101_get_countries_count(self).await
+    }
+
+    async fn deprecated_202101_get_countries_param_country_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202101GetCountriesParamCountryParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_get_countries_param_country_with(
+            self, country_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_update_countries_param_country(self, country_id, body)
+            .await
+    }
+
+    async fn deprecated_202101_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_delete_countries_param_country(self, country_id).await
+    }
+
+    async fn deprecated_unstable_get_countrie_with(
+        &self,
+        params: &StorePropertiesDeprecatedUnstableGetCountrieParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_countrie_with(self, params).await
+    }
+
+    async fn deprecated_unstable_create_countries(&self, body: &serde_json::Value) -> Result<()> {
+        StoreProperties::deprecated_unstable_create_countries(self, body).await
+    }
+
+    async fn deprecated_unstable_get_countries_count(&self) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_countries_count(self).await
+    }
+
+    async fn deprecated_unstable_get_countries_param_country_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecatedUnstableGetCountriesParamCountryParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_countries_param_country_with(
+            self, country_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_countries_param_country(
+        &self,
+        country_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_update_countries_param_country(self, country_id, body)
+            .await
+    }
+
+    async fn deprecated_unstable_delete_countries_param_country(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_delete_countries_param_country(self, country_id).await
+    }
+
+    async fn deprecated_202001_get_currencie(&self) -> Result<()> {
+        StoreProperties::deprecated_202001_get_currencie(self).await
+    }
+
+    async fn deprecated_202004_get_currencie(&self) -> Result<()> {
+        StoreProperties::deprecated_202004_get_currencie(self).await
+    }
+
+    async fn deprecated_202007_get_currencie(&self) -> Result<()> {
+        StoreProperties::deprecated_202007_get_currencie(self).await
+    }
+
+    async fn get_currencie(&self) -> Result<()> {
+        StoreProperties::get_currencie(self).await
+    }
+
+    async fn deprecated_202101_get_currencie(&self) -> Result<()> {
+        StoreProperties::deprecated_202101_get_currencie(self).await
+    }
+
+    async fn deprecated_unstable_get_currencie(&self) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_currencie(self).await
+    }
+
+    async fn deprecated_202001_get_policie(&self) -> Result<()> {
+        StoreProperties::deprecated_202001_get_policie(self).await
+    }
+
+    async fn deprecated_202004_get_policie(&self) -> Result<()> {
+        StoreProperties::deprecated_202004_get_policie(self).await
+    }
+
+    async fn deprecated_202007_get_policie(&self) -> Result<()> {
+        StoreProperties::deprecated_202007_get_policie(self).await
+    }
+
+    async fn get_policie(&self) -> Result<()> {
+        StoreProperties::get_policie(self).await
+    }
+
+    async fn deprecated_202101_get_policie(&self) -> Result<()> {
+        StoreProperties::deprecated_202101_get_policie(self).await
+    }
+
+    async fn deprecated_unstable_get_policie(&self) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_policie(self).await
+    }
+
+    async fn deprecated_202001_get_countries_param_country_province_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202001GetCountriesParamCountryProvinceParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_get_countries_param_country_province_with(
+            self, country_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_get_countries_param_country_provinces_count(
+            self, country_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_countries_param_country_provinces_province_with(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        params: &StorePropertiesDeprecated202001GetCountriesParamCountryProvincesProvinceParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_get_countries_param_country_provinces_province_with(
+            self,
+            country_id,
+            province_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_update_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_countries_param_country_province_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202004GetCountriesParamCountryProvinceParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_get_countries_param_country_province_with(
+            self, country_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_get_countries_param_country_provinces_count(
+            self, country_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_get_countries_param_country_provinces_province_with(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        params: &StorePropertiesDeprecated202004GetCountriesParamCountryProvincesProvinceParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_get_countries_param_country_provinces_province_with(
+            self,
+            country_id,
+            province_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202004_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_update_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_countries_param_country_province_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202007GetCountriesParamCountryProvinceParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_get_countries_param_country_province_with(
+            self, country_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_get_countries_param_country_provinces_count(
+            self, country_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_get_countries_param_country_provinces_province_with(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        params: &StorePropertiesDeprecated202007GetCountriesParamCountryProvincesProvinceParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_get_countries_param_country_provinces_province_with(
+            self,
+            country_id,
+            province_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202007_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_update_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            body,
+        )
+        .await
+    }
+
+    async fn get_countries_param_country_province_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesGetCountriesParamCountryProvinceParams,
+    ) -> Result<()> {
+        StoreProperties::get_countries_param_country_province_with(self, country_id, params).await
+    }
+
+    async fn get_countries_param_country_provinces_count(&self, country_id: &str) -> Result<()> {
+        StoreProperties::get_countries_param_country_provinces_count(self, country_id).await
+    }
+
+    async fn get_countries_param_country_provinces_province_with(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        params: &StorePropertiesGetCountriesParamCountryProvincesProvinceParams,
+    ) -> Result<()> {
+        StoreProperties::get_countries_param_country_provinces_province_with(
+            self,
+            country_id,
+            province_id,
+            params,
+        )
+        .await
+    }
+
+    async fn update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::update_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_countries_param_country_province_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecated202101GetCountriesParamCountryProvinceParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_get_countries_param_country_province_with(
+            self, country_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_get_countries_param_country_provinces_count(
+            self, country_id,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_get_countries_param_country_provinces_province_with(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        params: &StorePropertiesDeprecated202101GetCountriesParamCountryProvincesProvinceParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_get_countries_param_country_provinces_province_with(
+            self,
+            country_id,
+            province_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_202101_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_update_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_countries_param_country_province_with(
+        &self,
+        country_id: &str,
+        params: &StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvinceParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_countries_param_country_province_with(
+            self, country_id, params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_countries_param_country_provinces_count(
+        &self,
+        country_id: &str,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_countries_param_country_provinces_count(
+            self, country_id,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_get_countries_param_country_provinces_province_with(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        params: &StorePropertiesDeprecatedUnstableGetCountriesParamCountryProvincesProvinceParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_countries_param_country_provinces_province_with(
+            self,
+            country_id,
+            province_id,
+            params,
+        )
+        .await
+    }
+
+    async fn deprecated_unstable_update_countries_param_country_provinces_province(
+        &self,
+        country_id: &str,
+        province_id: &str,
+        body: &serde_json::Value,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_update_countries_param_country_provinces_province(
+            self,
+            country_id,
+            province_id,
+            body,
+        )
+        .await
+    }
+
+    async fn deprecated_202001_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecated202001GetShippingZoneParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_get_shipping_zone_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecated202004GetShippingZoneParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_get_shipping_zone_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecated202007GetShippingZoneParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_get_shipping_zone_with(self, params).await
+    }
+
+    async fn get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesGetShippingZoneParams,
+    ) -> Result<()> {
+        StoreProperties::get_shipping_zone_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecated202101GetShippingZoneParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_get_shipping_zone_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_shipping_zone_with(
+        &self,
+        params: &StorePropertiesDeprecatedUnstableGetShippingZoneParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_shipping_zone_with(self, params).await
+    }
+
+    async fn deprecated_202001_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecated202001GetShopParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202001_get_shop_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecated202004GetShopParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202004_get_shop_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecated202007GetShopParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202007_get_shop_with(self, params).await
+    }
+
+    async fn get_shop_with(&self, params: &StorePropertiesGetShopParams) -> Result<()> {
+        StoreProperties::get_shop_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecated202101GetShopParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_202101_get_shop_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_shop_with(
+        &self,
+        params: &StorePropertiesDeprecatedUnstableGetShopParams,
+    ) -> Result<()> {
+        StoreProperties::deprecated_unstable_get_shop_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Tendertransaction {
     pub client: Client,
@@ -21,40 +19,29 @@ impl Tendertransaction {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Retrieve only transactions after the specified ID.
-     * * `processed_at_min: &str` -- Show tender transactions processed_at or after the specified date.
-     * * `processed_at_max: &str` -- Show tender transactions processed_at or before the specified date.
-     * * `processed_at: &str` -- Show tender transactions processed at the specified date.
-     * * `order: &str` -- Show tender transactions ordered by processed_at in ascending or descending order.
+     * * `params: &TendertransactionDeprecated202001GetTenderTransactionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202001_get_tender_transaction(
+    pub async fn deprecated_202001_get_tender_transaction_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        processed_at_min: &str,
-        processed_at_max: &str,
-        processed_at: &str,
-        order: &str,
+        params: &TendertransactionDeprecated202001GetTenderTransactionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !processed_at.is_empty() {
+        if let Some(processed_at) = &params.processed_at {
             query_args.push(("processed_at".to_string(), processed_at.to_string()));
         }
-        if !processed_at_max.is_empty() {
+        if let Some(processed_at_max) = &params.processed_at_max {
             query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
         }
-        if !processed_at_min.is_empty() {
+        if let Some(processed_at_min) = &params.processed_at_min {
             query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -63,6 +50,31 @@ impl Tendertransaction {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202001_get_tender_transaction_with` and pass the query parameters as a `TendertransactionDeprecated202001GetTenderTransactionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202001_get_tender_transaction_with` instead")]
+    pub async fn deprecated_202001_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()> {
+        let params = TendertransactionDeprecated202001GetTenderTransactionParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            processed_at: Some(processed_at.to_string()).filter(|s| !s.is_empty()),
+            processed_at_max: Some(processed_at_max.to_string()).filter(|s| !s.is_empty()),
+            processed_at_min: Some(processed_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202001_get_tender_transaction_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -72,40 +84,29 @@ impl Tendertransaction {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Retrieve only transactions after the specified ID.
-     * * `processed_at_min: &str` -- Show tender transactions processed_at or after the specified date.
-     * * `processed_at_max: &str` -- Show tender transactions processed_at or before the specified date.
-     * * `processed_at: &str` -- Show tender transactions processed at the specified date.
-     * * `order: &str` -- Show tender transactions ordered by processed_at in ascending or descending order.
+     * * `params: &TendertransactionDeprecated202004GetTenderTransactionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202004_get_tender_transaction(
+    pub async fn deprecated_202004_get_tender_transaction_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        processed_at_min: &str,
-        processed_at_max: &str,
-        processed_at: &str,
-        order: &str,
+        params: &TendertransactionDeprecated202004GetTenderTransactionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !processed_at.is_empty() {
+        if let Some(processed_at) = &params.processed_at {
             query_args.push(("processed_at".to_string(), processed_at.to_string()));
         }
-        if !processed_at_max.is_empty() {
+        if let Some(processed_at_max) = &params.processed_at_max {
             query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
         }
-        if !processed_at_min.is_empty() {
+        if let Some(processed_at_min) = &params.processed_at_min {
             query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -114,6 +115,31 @@ impl Tendertransaction {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202004_get_tender_transaction_with` and pass the query parameters as a `TendertransactionDeprecated202004GetTenderTransactionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202004_get_tender_transaction_with` instead")]
+    pub async fn deprecated_202004_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()> {
+        let params = TendertransactionDeprecated202004GetTenderTransactionParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            processed_at: Some(processed_at.to_string()).filter(|s| !s.is_empty()),
+            processed_at_max: Some(processed_at_max.to_string()).filter(|s| !s.is_empty()),
+            processed_at_min: Some(processed_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202004_get_tender_transaction_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -123,40 +149,29 @@ impl Tendertransaction {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Retrieve only transactions after the specified ID.
-     * * `processed_at_min: &str` -- Show tender transactions processed_at or after the specified date.
-     * * `processed_at_max: &str` -- Show tender transactions processed_at or before the specified date.
-     * * `processed_at: &str` -- Show tender transactions processed at the specified date.
-     * * `order: &str` -- Show tender transactions ordered by processed_at in ascending or descending order.
+     * * `params: &TendertransactionDeprecated202007GetTenderTransactionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202007_get_tender_transaction(
+    pub async fn deprecated_202007_get_tender_transaction_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        processed_at_min: &str,
-        processed_at_max: &str,
-        processed_at: &str,
-        order: &str,
+        params: &TendertransactionDeprecated202007GetTenderTransactionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !processed_at.is_empty() {
+        if let Some(processed_at) = &params.processed_at {
             query_args.push(("processed_at".to_string(), processed_at.to_string()));
         }
-        if !processed_at_max.is_empty() {
+        if let Some(processed_at_max) = &params.processed_at_max {
             query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
         }
-        if !processed_at_min.is_empty() {
+        if let Some(processed_at_min) = &params.processed_at_min {
             query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -165,6 +180,31 @@ impl Tendertransaction {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202007_get_tender_transaction_with` and pass the query parameters as a `TendertransactionDeprecated202007GetTenderTransactionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202007_get_tender_transaction_with` instead")]
+    pub async fn deprecated_202007_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()> {
+        let params = TendertransactionDeprecated202007GetTenderTransactionParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            processed_at: Some(processed_at.to_string()).filter(|s| !s.is_empty()),
+            processed_at_max: Some(processed_at_max.to_string()).filter(|s| !s.is_empty()),
+            processed_at_min: Some(processed_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202007_get_tender_transaction_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -174,40 +214,29 @@ impl Tendertransaction {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Retrieve only transactions after the specified ID.
-     * * `processed_at_min: &str` -- Show tender transactions processed_at or after the specified date.
-     * * `processed_at_max: &str` -- Show tender transactions processed_at or before the specified date.
-     * * `processed_at: &str` -- Show tender transactions processed at the specified date.
-     * * `order: &str` -- Show tender transactions ordered by processed_at in ascending or descending order.
+     * * `params: &TendertransactionGetTenderTransactionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_tender_transaction(
+    pub async fn get_tender_transaction_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        processed_at_min: &str,
-        processed_at_max: &str,
-        processed_at: &str,
-        order: &str,
+        params: &TendertransactionGetTenderTransactionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !processed_at.is_empty() {
+        if let Some(processed_at) = &params.processed_at {
             query_args.push(("processed_at".to_string(), processed_at.to_string()));
         }
-        if !processed_at_max.is_empty() {
+        if let Some(processed_at_max) = &params.processed_at_max {
             query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
         }
-        if !processed_at_min.is_empty() {
+        if let Some(processed_at_min) = &params.processed_at_min {
             query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -216,6 +245,30 @@ impl Tendertransaction {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_tender_transaction_with` and pass the query parameters as a `TendertransactionGetTenderTransactionParams` instead.
+     */
+    #[deprecated(note = "use `get_tender_transaction_with` instead")]
+    pub async fn get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()> {
+        let params = TendertransactionGetTenderTransactionParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            processed_at: Some(processed_at.to_string()).filter(|s| !s.is_empty()),
+            processed_at_max: Some(processed_at_max.to_string()).filter(|s| !s.is_empty()),
+            processed_at_min: Some(processed_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_tender_transaction_with(&params).await
+    }
+
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -225,40 +278,29 @@ impl Tendertransaction {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Retrieve only transactions after the specified ID.
-     * * `processed_at_min: &str` -- Show tender transactions processed_at or after the specified date.
-     * * `processed_at_max: &str` -- Show tender transactions processed_at or before the specified date.
-     * * `processed_at: &str` -- Show tender transactions processed at the specified date.
-     * * `order: &str` -- Show tender transactions ordered by processed_at in ascending or descending order.
+     * * `params: &TendertransactionDeprecated202101GetTenderTransactionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_202101_get_tender_transaction(
+    pub async fn deprecated_202101_get_tender_transaction_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        processed_at_min: &str,
-        processed_at_max: &str,
-        processed_at: &str,
-        order: &str,
+        params: &TendertransactionDeprecated202101GetTenderTransactionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !processed_at.is_empty() {
+        if let Some(processed_at) = &params.processed_at {
             query_args.push(("processed_at".to_string(), processed_at.to_string()));
         }
-        if !processed_at_max.is_empty() {
+        if let Some(processed_at_max) = &params.processed_at_max {
             query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
         }
-        if !processed_at_min.is_empty() {
+        if let Some(processed_at_min) = &params.processed_at_min {
             query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -267,6 +309,31 @@ impl Tendertransaction {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `deprecated_202101_get_tender_transaction_with` and pass the query parameters as a `TendertransactionDeprecated202101GetTenderTransactionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_202101_get_tender_transaction_with` instead")]
+    pub async fn deprecated_202101_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()> {
+        let params = TendertransactionDeprecated202101GetTenderTransactionParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            processed_at: Some(processed_at.to_string()).filter(|s| !s.is_empty()),
+            processed_at_max: Some(processed_at_max.to_string()).filter(|s| !s.is_empty()),
+            processed_at_min: Some(processed_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_202101_get_tender_transaction_with(&params)
+            .await
+    }
+
     /**
      * Retrieves a list of tender transactions. Note: As of version 2019-10, this endpoint implements pagination by using links that are provided in the response header. To learn more, see Making requests to paginated REST Admin API endpoints.
      *
@@ -276,40 +343,29 @@ impl Tendertransaction {
      *
      * **Parameters:**
      *
-     * * `limit: &str` -- The maximum number of results to retrieve.
-     *                     (default: 50, maximum: 250).
-     * * `since_id: &str` -- Retrieve only transactions after the specified ID.
-     * * `processed_at_min: &str` -- Show tender transactions processed_at or after the specified date.
-     * * `processed_at_max: &str` -- Show tender transactions processed_at or before the specified date.
-     * * `processed_at: &str` -- Show tender transactions processed at the specified date.
-     * * `order: &str` -- Show tender transactions ordered by processed_at in ascending or descending order.
+     * * `params: &TendertransactionDeprecatedUnstableGetTenderTransactionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn deprecated_unstable_get_tender_transaction(
+    pub async fn deprecated_unstable_get_tender_transaction_with(
         &self,
-        limit: &str,
-        since_id: &str,
-        processed_at_min: &str,
-        processed_at_max: &str,
-        processed_at: &str,
-        order: &str,
+        params: &TendertransactionDeprecatedUnstableGetTenderTransactionParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !limit.is_empty() {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !order.is_empty() {
+        if let Some(order) = &params.order {
             query_args.push(("order".to_string(), order.to_string()));
         }
-        if !processed_at.is_empty() {
+        if let Some(processed_at) = &params.processed_at {
             query_args.push(("processed_at".to_string(), processed_at.to_string()));
         }
-        if !processed_at_max.is_empty() {
+        if let Some(processed_at_max) = &params.processed_at_max {
             query_args.push(("processed_at_max".to_string(), processed_at_max.to_string()));
         }
-        if !processed_at_min.is_empty() {
+        if let Some(processed_at_min) = &params.processed_at_min {
             query_args.push(("processed_at_min".to_string(), processed_at_min.to_string()));
         }
-        if !since_id.is_empty() {
+        if let Some(since_id) = &params.since_id {
             query_args.push(("since_id".to_string(), since_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -317,4 +373,416 @@ impl Tendertransaction {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `deprecated_unstable_get_tender_transaction_with` and pass the query parameters as a `TendertransactionDeprecatedUnstableGetTenderTransactionParams` instead.
+     */
+    #[deprecated(note = "use `deprecated_unstable_get_tender_transaction_with` instead")]
+    pub async fn deprecated_unstable_get_tender_transaction(
+        &self,
+        limit: &str,
+        since_id: &str,
+        processed_at_min: &str,
+        processed_at_max: &str,
+        processed_at: &str,
+        order: &str,
+    ) -> Result<()> {
+        let params = TendertransactionDeprecatedUnstableGetTenderTransactionParams {
+            limit: Some(limit.to_string()).filter(|s| !s.is_empty()),
+            order: Some(order.to_string()).filter(|s| !s.is_empty()),
+            processed_at: Some(processed_at.to_string()).filter(|s| !s.is_empty()),
+            processed_at_max: Some(processed_at_max.to_string()).filter(|s| !s.is_empty()),
+            processed_at_min: Some(processed_at_min.to_string()).filter(|s| !s.is_empty()),
+            since_id: Some(since_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.deprecated_unstable_get_tender_transaction_with(&params)
+            .await
+    }
+}
+
+/// The query parameters of `Tendertransaction::deprecated_202001_get_tender_transaction_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TendertransactionDeprecated202001GetTenderTransactionParams {
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show tender transactions ordered by processed_at in ascending or descending order.
+    pub order: Option<String>,
+    /// Show tender transactions processed at the specified date.
+    pub processed_at: Option<String>,
+    /// Show tender transactions processed_at or before the specified date.
+    pub processed_at_max: Option<String>,
+    /// Show tender transactions processed_at or after the specified date.
+    pub processed_at_min: Option<String>,
+    /// Retrieve only transactions after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl TendertransactionDeprecated202001GetTenderTransactionParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn processed_at<T: ToString>(mut self, processed_at: T) -> Self {
+        self.processed_at = Some(processed_at.to_string());
+        self
+    }
+
+    pub fn processed_at_max<T: ToString>(mut self, processed_at_max: T) -> Self {
+        self.processed_at_max = Some(processed_at_max.to_string());
+        self
+    }
+
+    pub fn processed_at_min<T: ToString>(mut self, processed_at_min: T) -> Self {
+        self.processed_at_min = Some(processed_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Tendertransaction::deprecated_202004_get_tender_transaction_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TendertransactionDeprecated202004GetTenderTransactionParams {
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show tender transactions ordered by processed_at in ascending or descending order.
+    pub order: Option<String>,
+    /// Show tender transactions processed at the specified date.
+    pub processed_at: Option<String>,
+    /// Show tender transactions processed_at or before the specified date.
+    pub processed_at_max: Option<String>,
+    /// Show tender transactions processed_at or after the specified date.
+    pub processed_at_min: Option<String>,
+    /// Retrieve only transactions after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl TendertransactionDeprecated202004GetTenderTransactionParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn processed_at<T: ToString>(mut self, processed_at: T) -> Self {
+        self.processed_at = Some(processed_at.to_string());
+        self
+    }
+
+    pub fn processed_at_max<T: ToString>(mut self, processed_at_max: T) -> Self {
+        self.processed_at_max = Some(processed_at_max.to_string());
+        self
+    }
+
+    pub fn processed_at_min<T: ToString>(mut self, processed_at_min: T) -> Self {
+        self.processed_at_min = Some(processed_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Tendertransaction::deprecated_202007_get_tender_transaction_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TendertransactionDeprecated202007GetTenderTransactionParams {
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show tender transactions ordered by processed_at in ascending or descending order.
+    pub order: Option<String>,
+    /// Show tender transactions processed at the specified date.
+    pub processed_at: Option<String>,
+    /// Show tender transactions processed_at or before the specified date.
+    pub processed_at_max: Option<String>,
+    /// Show tender transactions processed_at or after the specified date.
+    pub processed_at_min: Option<String>,
+    /// Retrieve only transactions after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl TendertransactionDeprecated202007GetTenderTransactionParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn processed_at<T: ToString>(mut self, processed_at: T) -> Self {
+        self.processed_at = Some(processed_at.to_string());
+        self
+    }
+
+    pub fn processed_at_max<T: ToString>(mut self, processed_at_max: T) -> Self {
+        self.processed_at_max = Some(processed_at_max.to_string());
+        self
+    }
+
+    pub fn processed_at_min<T: ToString>(mut self, processed_at_min: T) -> Self {
+        self.processed_at_min = Some(processed_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Tendertransaction::get_tender_transaction_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TendertransactionGetTenderTransactionParams {
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show tender transactions ordered by processed_at in ascending or descending order.
+    pub order: Option<String>,
+    /// Show tender transactions processed at the specified date.
+    pub processed_at: Option<String>,
+    /// Show tender transactions processed_at or before the specified date.
+    pub processed_at_max: Option<String>,
+    /// Show tender transactions processed_at or after the specified date.
+    pub processed_at_min: Option<String>,
+    /// Retrieve only transactions after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl TendertransactionGetTenderTransactionParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn processed_at<T: ToString>(mut self, processed_at: T) -> Self {
+        self.processed_at = Some(processed_at.to_string());
+        self
+    }
+
+    pub fn processed_at_max<T: ToString>(mut self, processed_at_max: T) -> Self {
+        self.processed_at_max = Some(processed_at_max.to_string());
+        self
+    }
+
+    pub fn processed_at_min<T: ToString>(mut self, processed_at_min: T) -> Self {
+        self.processed_at_min = Some(processed_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Tendertransaction::deprecated_202101_get_tender_transaction_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TendertransactionDeprecated202101GetTenderTransactionParams {
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show tender transactions ordered by processed_at in ascending or descending order.
+    pub order: Option<String>,
+    /// Show tender transactions processed at the specified date.
+    pub processed_at: Option<String>,
+    /// Show tender transactions processed_at or before the specified date.
+    pub processed_at_max: Option<String>,
+    /// Show tender transactions processed_at or after the specified date.
+    pub processed_at_min: Option<String>,
+    /// Retrieve only transactions after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl TendertransactionDeprecated202101GetTenderTransactionParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn processed_at<T: ToString>(mut self, processed_at: T) -> Self {
+        self.processed_at = Some(processed_at.to_string());
+        self
+    }
+
+    pub fn processed_at_max<T: ToString>(mut self, processed_at_max: T) -> Self {
+        self.processed_at_max = Some(processed_at_max.to_string());
+        self
+    }
+
+    pub fn processed_at_min<T: ToString>(mut self, processed_at_min: T) -> Self {
+        self.processed_at_min = Some(processed_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Tendertransaction::deprecated_unstable_get_tender_transaction_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TendertransactionDeprecatedUnstableGetTenderTransactionParams {
+    /// The maximum number of results to retrieve.
+    /// (default: 50, maximum: 250).
+    pub limit: Option<String>,
+    /// Show tender transactions ordered by processed_at in ascending or descending order.
+    pub order: Option<String>,
+    /// Show tender transactions processed at the specified date.
+    pub processed_at: Option<String>,
+    /// Show tender transactions processed_at or before the specified date.
+    pub processed_at_max: Option<String>,
+    /// Show tender transactions processed_at or after the specified date.
+    pub processed_at_min: Option<String>,
+    /// Retrieve only transactions after the specified ID.
+    pub since_id: Option<String>,
+}
+
+impl TendertransactionDeprecatedUnstableGetTenderTransactionParams {
+    pub fn limit<T: ToString>(mut self, limit: T) -> Self {
+        self.limit = Some(limit.to_string());
+        self
+    }
+
+    pub fn order<T: ToString>(mut self, order: T) -> Self {
+        self.order = Some(order.to_string());
+        self
+    }
+
+    pub fn processed_at<T: ToString>(mut self, processed_at: T) -> Self {
+        self.processed_at = Some(processed_at.to_string());
+        self
+    }
+
+    pub fn processed_at_max<T: ToString>(mut self, processed_at_max: T) -> Self {
+        self.processed_at_max = Some(processed_at_max.to_string());
+        self
+    }
+
+    pub fn processed_at_min<T: ToString>(mut self, processed_at_min: T) -> Self {
+        self.processed_at_min = Some(processed_at_min.to_string());
+        self
+    }
+
+    pub fn since_id<T: ToString>(mut self, since_id: T) -> Self {
+        self.since_id = Some(since_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`Tendertransaction`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTendertransactionApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TendertransactionApi: Send + Sync {
+    /// See [`Tendertransaction::deprecated_202001_get_tender_transaction_with`].
+    async fn deprecated_202001_get_tender_transaction_with(
+        &self,
+        params: &TendertransactionDeprecated202001GetTenderTransactionParams,
+    ) -> Result<()>;
+
+    /// See [`Tendertransaction::deprecated_202004_get_tender_transaction_with`].
+    async fn deprecated_202004_get_tender_transaction_with(
+        &self,
+        params: &TendertransactionDeprecated202004GetTenderTransactionParams,
+    ) -> Result<()>;
+
+    /// See [`Tendertransaction::deprecated_202007_get_tender_transaction_with`].
+    async fn deprecated_202007_get_tender_transaction_with(
+        &self,
+        params: &TendertransactionDeprecated202007GetTenderTransactionParams,
+    ) -> Result<()>;
+
+    /// See [`Tendertransaction::get_tender_transaction_with`].
+    async fn get_tender_transaction_with(
+        &self,
+        params: &TendertransactionGetTenderTransactionParams,
+    ) -> Result<()>;
+
+    /// See [`Tendertransaction::deprecated_202101_get_tender_transaction_with`].
+    async fn deprecated_202101_get_tender_transaction_with(
+        &self,
+        params: &TendertransactionDeprecated202101GetTenderTransactionParams,
+    ) -> Result<()>;
+
+    /// See [`Tendertransaction::deprecated_unstable_get_tender_transaction_with`].
+    async fn deprecated_unstable_get_tender_transaction_with(
+        &self,
+        params: &TendertransactionDeprecatedUnstableGetTenderTransactionParams,
+    ) -> Result<()>;
+}
+
+#[async_trait::async_trait]
+impl TendertransactionApi for Tendertransaction {
+    async fn deprecated_202001_get_tender_transaction_with(
+        &self,
+        params: &TendertransactionDeprecated202001GetTenderTransactionParams,
+    ) -> Result<()> {
+        Tendertransaction::deprecated_202001_get_tender_transaction_with(self, params).await
+    }
+
+    async fn deprecated_202004_get_tender_transaction_with(
+        &self,
+        params: &TendertransactionDeprecated202004GetTenderTransactionParams,
+    ) -> Result<()> {
+        Tendertransaction::deprecated_202004_get_tender_transaction_with(self, params).await
+    }
+
+    async fn deprecated_202007_get_tender_transaction_with(
+        &self,
+        params: &TendertransactionDeprecated202007GetTenderTransactionParams,
+    ) -> Result<()> {
+        Tendertransaction::deprecated_202007_get_tender_transaction_with(self, params).await
+    }
+
+    async fn get_tender_transaction_with(
+        &self,
+        params: &TendertransactionGetTenderTransactionParams,
+    ) -> Result<()> {
+        Tendertransaction::get_tender_transaction_with(self, params).await
+    }
+
+    async fn deprecated_202101_get_tender_transaction_with(
+        &self,
+        params: &TendertransactionDeprecated202101GetTenderTransactionParams,
+    ) -> Result<()> {
+        Tendertransaction::deprecated_202101_get_tender_transaction_with(self, params).await
+    }
+
+    async fn deprecated_unstable_get_tender_transaction_with(
+        &self,
+        params: &TendertransactionDeprecatedUnstableGetTenderTransactionParams,
+    ) -> Result<()> {
+        Tendertransaction::deprecated_unstable_get_tender_transaction_with(self, params).await
+    }
 }
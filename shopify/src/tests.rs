@@ -1 +1,30 @@
+const OAUTH_CALLBACK: &str =
+    "code=0907a61c0c8d55e99db179b68161bc00&\
+     hmac=700e2dadb827fcc8609e9d5ce208b2e9cdaab9df07390d2cbca10d7c328fc4bf&shop=some-shop.\
+     myshopify.com&state=0.6784241404160823&timestamp=1337178173";
 
+#[test]
+fn test_for_shop() {
+    let client = crate::Client::for_shop("Some-Shop", "2023-01", "", "hush", "", "", "").unwrap();
+    assert_eq!(client.shop_domain(), "some-shop.myshopify.com");
+    assert_eq!(
+        client.token_endpoint(),
+        "https://some-shop.myshopify.com/admin/oauth/access_token"
+    );
+
+    assert!(crate::Client::for_shop("some shop", "2023-01", "", "", "", "", "").is_err());
+}
+
+#[test]
+fn test_verify_oauth_callback() {
+    let client =
+        crate::Client::for_shop("some-shop.myshopify.com", "2023-01", "", "hush", "", "", "")
+            .unwrap();
+    client.verify_oauth_callback(OAUTH_CALLBACK).unwrap();
+
+    let tampered = OAUTH_CALLBACK.replace("timestamp=1337178173", "timestamp=1337178174");
+    assert!(client.verify_oauth_callback(&tampered).is_err());
+
+    let other = crate::Client::for_shop("other-shop", "2023-01", "", "hush", "", "", "").unwrap();
+    assert!(other.verify_oauth_callback(OAUTH_CALLBACK).is_err());
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminApps {
     pub client: Client,
@@ -23,9 +21,18 @@ impl AdminApps {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.apps:write`.
      */
-    pub async fn approve(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn approve(
+        &self,
+        body: &crate::types::AdminAppsApproveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.apps.approve".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -39,8 +46,52 @@ impl AdminApps {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.apps:write`.
      */
-    pub async fn restrict(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn restrict(
+        &self,
+        body: &crate::types::AdminAppsRestrictRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.apps.restrict".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The functions of [`AdminApps`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminAppsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminAppsApi: Send + Sync {
+    /// See [`AdminApps::approve`].
+    async fn approve(
+        &self,
+        body: &crate::types::AdminAppsApproveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminApps::restrict`].
+    async fn restrict(
+        &self,
+        body: &crate::types::AdminAppsRestrictRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AdminAppsApi for AdminApps {
+    async fn approve(
+        &self,
+        body: &crate::types::AdminAppsApproveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminApps::approve(self, body).await
+    }
+
+    async fn restrict(
+        &self,
+        body: &crate::types::AdminAppsRestrictRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminApps::restrict(self, body).await
     }
 }
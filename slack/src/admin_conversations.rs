@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminConversations {
     pub client: Client,
@@ -23,9 +21,18 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn archive(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn archive(
+        &self,
+        body: &crate::types::AdminConversationsArchiveRequest,
+    ) -> Result<crate::types::AdminConversationsArchiveSchema> {
         let url = "/admin.conversations.archive".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -39,9 +46,18 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn convert_private(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn convert_private(
+        &self,
+        body: &crate::types::AdminConversationsConvertPrivateRequest,
+    ) -> Result<crate::types::AdminConversationsConvertPrivateSchema> {
         let url = "/admin.conversations.convertToPrivate".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -55,9 +71,18 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn create(&self) -> Result<crate::types::AdminConversationsCreateSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::AdminConversationsCreateRequest,
+    ) -> Result<crate::types::AdminConversationsCreateSchema> {
         let url = "/admin.conversations.create".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -71,9 +96,18 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> Result<crate::types::AdminConversationsDeleteSchema> {
         let url = "/admin.conversations.delete".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -87,9 +121,18 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn disconnect_shared(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn disconnect_shared(
+        &self,
+        body: &crate::types::AdminConversationsDisconnectSharedRequest,
+    ) -> Result<crate::types::AdminConversationsRenameSchema> {
         let url = "/admin.conversations.disconnectShared".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -101,15 +144,14 @@ impl AdminConversations {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:read`.
-     * * `channel_id: &str` -- The channel to get preferences for.
+     * * `params: &AdminConversationsGetConversationPrefParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_conversation_pref(
+    pub async fn get_conversation_pref_with(
         &self,
-        channel_id: &str,
+        params: &AdminConversationsGetConversationPrefParams,
     ) -> Result<crate::types::AdminConversationsGetConversationPrefsSchemaData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel_id.is_empty() {
+        if let Some(channel_id) = &params.channel_id {
             query_args.push(("channel_id".to_string(), channel_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -118,6 +160,20 @@ impl AdminConversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_conversation_pref_with` and pass the query parameters as a `AdminConversationsGetConversationPrefParams` instead.
+     */
+    #[deprecated(note = "use `get_conversation_pref_with` instead")]
+    pub async fn get_conversation_pref(
+        &self,
+        channel_id: &str,
+    ) -> Result<crate::types::AdminConversationsGetConversationPrefsSchemaData> {
+        let params = AdminConversationsGetConversationPrefParams {
+            channel_id: Some(channel_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_conversation_pref_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/admin.conversations.getTeams` endpoint.
      *
@@ -127,25 +183,20 @@ impl AdminConversations {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:read`.
-     * * `channel_id: &str` -- The channel to determine connected workspaces within the organization for.
-     * * `cursor: &str` -- Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
-     * * `limit: i64` -- The maximum number of items to return. Must be between 1 - 1000 both inclusive.
+     * * `params: &AdminConversationsGetTeamParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_team(
+    pub async fn get_team_with(
         &self,
-        channel_id: &str,
-        cursor: &str,
-        limit: i64,
+        params: &AdminConversationsGetTeamParams,
     ) -> Result<crate::types::AdminConversationsGetTeamsSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel_id.is_empty() {
+        if let Some(channel_id) = &params.channel_id {
             query_args.push(("channel_id".to_string(), channel_id.to_string()));
         }
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -154,6 +205,24 @@ impl AdminConversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_team_with` and pass the query parameters as a `AdminConversationsGetTeamParams` instead.
+     */
+    #[deprecated(note = "use `get_team_with` instead")]
+    pub async fn get_team(
+        &self,
+        channel_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::AdminConversationsGetTeamsSchema> {
+        let params = AdminConversationsGetTeamParams {
+            channel_id: Some(channel_id.to_string()).filter(|s| !s.is_empty()),
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.get_team_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/admin.conversations.invite` endpoint.
      *
@@ -165,9 +234,18 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn invite(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn invite(
+        &self,
+        body: &crate::types::AdminConversationsInviteRequest,
+    ) -> Result<crate::types::AdminConversationsInviteSchema> {
         let url = "/admin.conversations.invite".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -181,9 +259,18 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn rename(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::AdminConversationsRenameRequest,
+    ) -> Result<crate::types::AdminConversationsRenameSchema> {
         let url = "/admin.conversations.rename".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -195,48 +282,35 @@ impl AdminConversations {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:read`.
-     * * `team_ids: &str` -- Comma separated string of team IDs, signifying the workspaces to search through.
-     * * `query: &str` -- Name of the the channel to query by.
-     * * `limit: i64` -- Maximum number of items to be returned. Must be between 1 - 20 both inclusive. Default is 10.
-     * * `cursor: &str` -- Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
-     * * `search_channel_types: &str` -- The type of channel to include or exclude in the search. For example `private` will search private channels, while `private_exclude` will exclude them. For a full list of types, check the [Types section](#types).
-     * * `sort: &str` -- Possible values are `relevant` (search ranking based on what we think is closest), `name` (alphabetical), `member_count` (number of users in the channel), and `created` (date channel was created). You can optionally pair this with the `sort_dir` arg to change how it is sorted .
-     * * `sort_dir: &str` -- Sort direction. Possible values are `asc` for ascending order like (1, 2, 3) or (a, b, c), and `desc` for descending order like (3, 2, 1) or (c, b, a).
+     * * `params: &AdminConversationsSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn search(
+    pub async fn search_with(
         &self,
-        team_ids: &str,
-        query: &str,
-        limit: i64,
-        cursor: &str,
-        search_channel_types: &str,
-        sort: &str,
-        sort_dir: &str,
+        params: &AdminConversationsSearchParams,
     ) -> Result<crate::types::AdminConversationsSearchSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
-        if !search_channel_types.is_empty() {
+        if let Some(search_channel_types) = &params.search_channel_types {
             query_args.push((
                 "search_channel_types".to_string(),
                 search_channel_types.to_string(),
             ));
         }
-        if !sort.is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !sort_dir.is_empty() {
+        if let Some(sort_dir) = &params.sort_dir {
             query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
         }
-        if !team_ids.is_empty() {
+        if let Some(team_ids) = &params.team_ids {
             query_args.push(("team_ids".to_string(), team_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -245,6 +319,32 @@ impl AdminConversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `search_with` and pass the query parameters as a `AdminConversationsSearchParams` instead.
+     */
+    #[deprecated(note = "use `search_with` instead")]
+    pub async fn search(
+        &self,
+        team_ids: &str,
+        query: &str,
+        limit: i64,
+        cursor: &str,
+        search_channel_types: &str,
+        sort: &str,
+        sort_dir: &str,
+    ) -> Result<crate::types::AdminConversationsSearchSchema> {
+        let params = AdminConversationsSearchParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            search_channel_types: Some(search_channel_types.to_string()).filter(|s| !s.is_empty()),
+            sort: Some(sort.to_string()).filter(|s| !s.is_empty()),
+            sort_dir: Some(sort_dir.to_string()).filter(|s| !s.is_empty()),
+            team_ids: Some(team_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.search_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/admin.conversations.setConversationPrefs` endpoint.
      *
@@ -256,9 +356,18 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn set_conversation_prefs(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_conversation_prefs(
+        &self,
+        body: &crate::types::AdminConversationsSetConversationPrefsRequest,
+    ) -> Result<crate::types::AdminConversationsSetConversationPrefsSchema> {
         let url = "/admin.conversations.setConversationPrefs".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -272,9 +381,18 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn set_teams(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_teams(
+        &self,
+        body: &crate::types::AdminConversationsSetTeamsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.conversations.setTeams".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -288,8 +406,293 @@ impl AdminConversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:write`.
      */
-    pub async fn unarchive(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn unarchive(
+        &self,
+        body: &crate::types::AdminConversationsUnarchiveRequest,
+    ) -> Result<crate::types::AdminConversationsUnarchiveSchema> {
         let url = "/admin.conversations.unarchive".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `AdminConversations::get_conversation_pref_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AdminConversationsGetConversationPrefParams {
+    /// The channel to get preferences for.
+    pub channel_id: Option<String>,
+}
+
+impl AdminConversationsGetConversationPrefParams {
+    pub fn channel_id<T: ToString>(mut self, channel_id: T) -> Self {
+        self.channel_id = Some(channel_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `AdminConversations::get_team_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AdminConversationsGetTeamParams {
+    /// The channel to determine connected workspaces within the organization for.
+    pub channel_id: Option<String>,
+    /// Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return. Must be between 1 - 1000 both inclusive.
+    pub limit: Option<i64>,
+}
+
+impl AdminConversationsGetTeamParams {
+    pub fn channel_id<T: ToString>(mut self, channel_id: T) -> Self {
+        self.channel_id = Some(channel_id.to_string());
+        self
+    }
+
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `AdminConversations::search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AdminConversationsSearchParams {
+    /// Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
+    pub cursor: Option<String>,
+    /// Maximum number of items to be returned. Must be between 1 - 20 both inclusive. Default is 10.
+    pub limit: Option<i64>,
+    /// Name of the the channel to query by.
+    pub query: Option<String>,
+    /// The type of channel to include or exclude in the search. For example `private` will search private channels, while `private_exclude` will exclude them. For a full list of types, check the [Types section](#types).
+    pub search_channel_types: Option<String>,
+    /// Possible values are `relevant` (search ranking based on what we think is closest), `name` (alphabetical), `member_count` (number of users in the channel), and `created` (date channel was created). You can optionally pair this with the `sort_dir` arg to change how it is sorted .
+    pub sort: Option<String>,
+    /// Sort direction. Possible values are `asc` for ascending order like (1, 2, 3) or (a, b, c), and `desc` for descending order like (3, 2, 1) or (c, b, a).
+    pub sort_dir: Option<String>,
+    /// Comma separated string of team IDs, signifying the workspaces to search through.
+    pub team_ids: Option<String>,
+}
+
+impl AdminConversationsSearchParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+
+    pub fn search_channel_types<T: ToString>(mut self, search_channel_types: T) -> Self {
+        self.search_channel_types = Some(search_channel_types.to_string());
+        self
+    }
+
+    pub fn sort<T: ToString>(mut self, sort: T) -> Self {
+        self.sort = Some(sort.to_string());
+        self
+    }
+
+    pub fn sort_dir<T: ToString>(mut self, sort_dir: T) -> Self {
+        self.sort_dir = Some(sort_dir.to_string());
+        self
+    }
+
+    pub fn team_ids<T: ToString>(mut self, team_ids: T) -> Self {
+        self.team_ids = Some(team_ids.to_string());
+        self
+    }
+}
+
+/// The functions of [`AdminConversations`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminConversationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminConversationsApi: Send + Sync {
+    /// See [`AdminConversations::archive`].
+    async fn archive(
+        &self,
+        body: &crate::types::AdminConversationsArchiveRequest,
+    ) -> Result<crate::types::AdminConversationsArchiveSchema>;
+
+    /// See [`AdminConversations::convert_private`].
+    async fn convert_private(
+        &self,
+        body: &crate::types::AdminConversationsConvertPrivateRequest,
+    ) -> Result<crate::types::AdminConversationsConvertPrivateSchema>;
+
+    /// See [`AdminConversations::create`].
+    async fn create(
+        &self,
+        body: &crate::types::AdminConversationsCreateRequest,
+    ) -> Result<crate::types::AdminConversationsCreateSchema>;
+
+    /// See [`AdminConversations::delete`].
+    async fn delete(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> Result<crate::types::AdminConversationsDeleteSchema>;
+
+    /// See [`AdminConversations::disconnect_shared`].
+    async fn disconnect_shared(
+        &self,
+        body: &crate::types::AdminConversationsDisconnectSharedRequest,
+    ) -> Result<crate::types::AdminConversationsRenameSchema>;
+
+    /// See [`AdminConversations::get_conversation_pref_with`].
+    async fn get_conversation_pref_with(
+        &self,
+        params: &AdminConversationsGetConversationPrefParams,
+    ) -> Result<crate::types::AdminConversationsGetConversationPrefsSchemaData>;
+
+    /// See [`AdminConversations::get_team_with`].
+    async fn get_team_with(
+        &self,
+        params: &AdminConversationsGetTeamParams,
+    ) -> Result<crate::types::AdminConversationsGetTeamsSchema>;
+
+    /// See [`AdminConversations::invite`].
+    async fn invite(
+        &self,
+        body: &crate::types::AdminConversationsInviteRequest,
+    ) -> Result<crate::types::AdminConversationsInviteSchema>;
+
+    /// See [`AdminConversations::rename`].
+    async fn rename(
+        &self,
+        body: &crate::types::AdminConversationsRenameRequest,
+    ) -> Result<crate::types::AdminConversationsRenameSchema>;
+
+    /// See [`AdminConversations::search_with`].
+    async fn search_with(
+        &self,
+        params: &AdminConversationsSearchParams,
+    ) -> Result<crate::types::AdminConversationsSearchSchema>;
+
+    /// See [`AdminConversations::set_conversation_prefs`].
+    async fn set_conversation_prefs(
+        &self,
+        body: &crate::types::AdminConversationsSetConversationPrefsRequest,
+    ) -> Result<crate::types::AdminConversationsSetConversationPrefsSchema>;
+
+    /// See [`AdminConversations::set_teams`].
+    async fn set_teams(
+        &self,
+        body: &crate::types::AdminConversationsSetTeamsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminConversations::unarchive`].
+    async fn unarchive(
+        &self,
+        body: &crate::types::AdminConversationsUnarchiveRequest,
+    ) -> Result<crate::types::AdminConversationsUnarchiveSchema>;
+}
+
+#[async_trait::async_trait]
+impl AdminConversationsApi for AdminConversations {
+    async fn archive(
+        &self,
+        body: &crate::types::AdminConversationsArchiveRequest,
+    ) -> Result<crate::types::AdminConversationsArchiveSchema> {
+        AdminConversations::archive(self, body).await
+    }
+
+    async fn convert_private(
+        &self,
+        body: &crate::types::AdminConversationsConvertPrivateRequest,
+    ) -> Result<crate::types::AdminConversationsConvertPrivateSchema> {
+        AdminConversations::convert_private(self, body).await
+    }
+
+    async fn create(
+        &self,
+        body: &crate::types::AdminConversationsCreateRequest,
+    ) -> Result<crate::types::AdminConversationsCreateSchema> {
+        AdminConversations::create(self, body).await
+    }
+
+    async fn delete(
+        &self,
+        body: &crate::types::AdminConversationsDeleteRequest,
+    ) -> Result<crate::types::AdminConversationsDeleteSchema> {
+        AdminConversations::delete(self, body).await
+    }
+
+    async fn disconnect_shared(
+        &self,
+        body: &crate::types::AdminConversationsDisconnectSharedRequest,
+    ) -> Result<crate::types::AdminConversationsRenameSchema> {
+        AdminConversations::disconnect_shared(self, body).await
+    }
+
+    async fn get_conversation_pref_with(
+        &self,
+        params: &AdminConversationsGetConversationPrefParams,
+    ) -> Result<crate::types::AdminConversationsGetConversationPrefsSchemaData> {
+        AdminConversations::get_conversation_pref_with(self, params).await
+    }
+
+    async fn get_team_with(
+        &self,
+        params: &AdminConversationsGetTeamParams,
+    ) -> Result<crate::types::AdminConversationsGetTeamsSchema> {
+        AdminConversations::get_team_with(self, params).await
+    }
+
+    async fn invite(
+        &self,
+        body: &crate::types::AdminConversationsInviteRequest,
+    ) -> Result<crate::types::AdminConversationsInviteSchema> {
+        AdminConversations::invite(self, body).await
+    }
+
+    async fn rename(
+        &self,
+        body: &crate::types::AdminConversationsRenameRequest,
+    ) -> Result<crate::types::AdminConversationsRenameSchema> {
+        AdminConversations::rename(self, body).await
+    }
+
+    async fn search_with(
+        &self,
+        params: &AdminConversationsSearchParams,
+    ) -> Result<crate::types::AdminConversationsSearchSchema> {
+        AdminConversations::search_with(self, params).await
+    }
+
+    async fn set_conversation_prefs(
+        &self,
+        body: &crate::types::AdminConversationsSetConversationPrefsRequest,
+    ) -> Result<crate::types::AdminConversationsSetConversationPrefsSchema> {
+        AdminConversations::set_conversation_prefs(self, body).await
+    }
+
+    async fn set_teams(
+        &self,
+        body: &crate::types::AdminConversationsSetTeamsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminConversations::set_teams(self, body).await
+    }
+
+    async fn unarchive(
+        &self,
+        body: &crate::types::AdminConversationsUnarchiveRequest,
+    ) -> Result<crate::types::AdminConversationsUnarchiveSchema> {
+        AdminConversations::unarchive(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminConversationsEkm {
     pub client: Client,
@@ -21,30 +19,23 @@ impl AdminConversationsEkm {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:read`.
-     * * `channel_ids: &str` -- A comma-separated list of channels to filter to.
-     * * `team_ids: &str` -- A comma-separated list of the workspaces to which the channels you would like returned belong.
-     * * `limit: i64` -- The maximum number of items to return. Must be between 1 - 1000 both inclusive.
-     * * `cursor: &str` -- Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
+     * * `params: &AdminConversationsEkmListOriginalConnectedChannelInfoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_original_connected_channel_info(
+    pub async fn list_original_connected_channel_info_with(
         &self,
-        channel_ids: &str,
-        team_ids: &str,
-        limit: i64,
-        cursor: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &AdminConversationsEkmListOriginalConnectedChannelInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel_ids.is_empty() {
+        if let Some(channel_ids) = &params.channel_ids {
             query_args.push(("channel_ids".to_string(), channel_ids.to_string()));
         }
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !team_ids.is_empty() {
+        if let Some(team_ids) = &params.team_ids {
             query_args.push(("team_ids".to_string(), team_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -55,4 +46,82 @@ impl AdminConversationsEkm {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `list_original_connected_channel_info_with` and pass the query parameters as a `AdminConversationsEkmListOriginalConnectedChannelInfoParams` instead.
+     */
+    #[deprecated(note = "use `list_original_connected_channel_info_with` instead")]
+    pub async fn list_original_connected_channel_info(
+        &self,
+        channel_ids: &str,
+        team_ids: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = AdminConversationsEkmListOriginalConnectedChannelInfoParams {
+            channel_ids: Some(channel_ids.to_string()).filter(|s| !s.is_empty()),
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            team_ids: Some(team_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_original_connected_channel_info_with(&params)
+            .await
+    }
+}
+
+/// The query parameters of `AdminConversationsEkm::list_original_connected_channel_info_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AdminConversationsEkmListOriginalConnectedChannelInfoParams {
+    /// A comma-separated list of channels to filter to.
+    pub channel_ids: Option<String>,
+    /// Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return. Must be between 1 - 1000 both inclusive.
+    pub limit: Option<i64>,
+    /// A comma-separated list of the workspaces to which the channels you would like returned belong.
+    pub team_ids: Option<String>,
+}
+
+impl AdminConversationsEkmListOriginalConnectedChannelInfoParams {
+    pub fn channel_ids<T: ToString>(mut self, channel_ids: T) -> Self {
+        self.channel_ids = Some(channel_ids.to_string());
+        self
+    }
+
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn team_ids<T: ToString>(mut self, team_ids: T) -> Self {
+        self.team_ids = Some(team_ids.to_string());
+        self
+    }
+}
+
+/// The functions of [`AdminConversationsEkm`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminConversationsEkmApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminConversationsEkmApi: Send + Sync {
+    /// See [`AdminConversationsEkm::list_original_connected_channel_info_with`].
+    async fn list_original_connected_channel_info_with(
+        &self,
+        params: &AdminConversationsEkmListOriginalConnectedChannelInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AdminConversationsEkmApi for AdminConversationsEkm {
+    async fn list_original_connected_channel_info_with(
+        &self,
+        params: &AdminConversationsEkmListOriginalConnectedChannelInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminConversationsEkm::list_original_connected_channel_info_with(self, params).await
+    }
 }
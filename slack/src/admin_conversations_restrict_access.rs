@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminConversationsRestrictAccess {
     pub client: Client,
@@ -19,9 +17,18 @@ impl AdminConversationsRestrictAccess {
      *
      * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.addGroup>
      */
-    pub async fn add_group(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessAddGroupRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.conversations.restrictAccess.addGroup".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -33,20 +40,17 @@ impl AdminConversationsRestrictAccess {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin.conversations:read`.
-     * * `channel_id: &str`
-     * * `team_id: &str` -- The workspace where the channel exists. This argument is required for channels only tied to one workspace, and optional for channels that are shared across an organization.
+     * * `params: &AdminConversationsRestrictAccessListGroupParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_group(
+    pub async fn list_group_with(
         &self,
-        channel_id: &str,
-        team_id: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &AdminConversationsRestrictAccessListGroupParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel_id.is_empty() {
+        if let Some(channel_id) = &params.channel_id {
             query_args.push(("channel_id".to_string(), channel_id.to_string()));
         }
-        if !team_id.is_empty() {
+        if let Some(team_id) = &params.team_id {
             query_args.push(("team_id".to_string(), team_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -55,6 +59,22 @@ impl AdminConversationsRestrictAccess {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_group_with` and pass the query parameters as a `AdminConversationsRestrictAccessListGroupParams` instead.
+     */
+    #[deprecated(note = "use `list_group_with` instead")]
+    pub async fn list_group(
+        &self,
+        channel_id: &str,
+        team_id: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = AdminConversationsRestrictAccessListGroupParams {
+            channel_id: Some(channel_id.to_string()).filter(|s| !s.is_empty()),
+            team_id: Some(team_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_group_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/admin.conversations.restrictAccess.removeGroup` endpoint.
      *
@@ -62,8 +82,85 @@ impl AdminConversationsRestrictAccess {
      *
      * FROM: <https://api.slack.com/methods/admin.conversations.restrictAccess.removeGroup>
      */
-    pub async fn remove_group(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessRemoveGroupRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.conversations.restrictAccess.removeGroup".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `AdminConversationsRestrictAccess::list_group_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AdminConversationsRestrictAccessListGroupParams {
+    pub channel_id: Option<String>,
+    /// The workspace where the channel exists. This argument is required for channels only tied to one workspace, and optional for channels that are shared across an organization.
+    pub team_id: Option<String>,
+}
+
+impl AdminConversationsRestrictAccessListGroupParams {
+    pub fn channel_id<T: ToString>(mut self, channel_id: T) -> Self {
+        self.channel_id = Some(channel_id.to_string());
+        self
+    }
+
+    pub fn team_id<T: ToString>(mut self, team_id: T) -> Self {
+        self.team_id = Some(team_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`AdminConversationsRestrictAccess`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminConversationsRestrictAccessApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminConversationsRestrictAccessApi: Send + Sync {
+    /// See [`AdminConversationsRestrictAccess::add_group`].
+    async fn add_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessAddGroupRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminConversationsRestrictAccess::list_group_with`].
+    async fn list_group_with(
+        &self,
+        params: &AdminConversationsRestrictAccessListGroupParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminConversationsRestrictAccess::remove_group`].
+    async fn remove_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessRemoveGroupRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AdminConversationsRestrictAccessApi for AdminConversationsRestrictAccess {
+    async fn add_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessAddGroupRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminConversationsRestrictAccess::add_group(self, body).await
+    }
+
+    async fn list_group_with(
+        &self,
+        params: &AdminConversationsRestrictAccessListGroupParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminConversationsRestrictAccess::list_group_with(self, params).await
+    }
+
+    async fn remove_group(
+        &self,
+        body: &crate::types::AdminConversationsRestrictAccessRemoveGroupRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminConversationsRestrictAccess::remove_group(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminEmoji {
     pub client: Client,
@@ -19,9 +17,18 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.add>
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::AdminEmojiAddRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.emoji.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -31,9 +38,18 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.addAlias>
      */
-    pub async fn add_alias(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add_alias(
+        &self,
+        body: &crate::types::AdminEmojiAddAliasRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.emoji.addAlias".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -45,16 +61,17 @@ impl AdminEmoji {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin.teams:read`.
-     * * `cursor: &str` -- Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
-     * * `limit: i64` -- The maximum number of items to return. Must be between 1 - 1000 both inclusive.
+     * * `params: &AdminEmojiListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(&self, cursor: &str, limit: i64) -> Result<crate::types::DndEndSchema> {
+    pub async fn list_with(
+        &self,
+        params: &AdminEmojiListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -63,6 +80,22 @@ impl AdminEmoji {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `AdminEmojiListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = AdminEmojiListParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/admin.emoji.remove` endpoint.
      *
@@ -70,9 +103,18 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.remove>
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::AdminEmojiRemoveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.emoji.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -82,8 +124,112 @@ impl AdminEmoji {
      *
      * FROM: <https://api.slack.com/methods/admin.emoji.rename>
      */
-    pub async fn rename(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::AdminEmojiRenameRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.emoji.rename".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `AdminEmoji::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AdminEmojiListParams {
+    /// Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return. Must be between 1 - 1000 both inclusive.
+    pub limit: Option<i64>,
+}
+
+impl AdminEmojiListParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The functions of [`AdminEmoji`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminEmojiApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminEmojiApi: Send + Sync {
+    /// See [`AdminEmoji::add`].
+    async fn add(
+        &self,
+        body: &crate::types::AdminEmojiAddRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminEmoji::add_alias`].
+    async fn add_alias(
+        &self,
+        body: &crate::types::AdminEmojiAddAliasRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminEmoji::list_with`].
+    async fn list_with(
+        &self,
+        params: &AdminEmojiListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminEmoji::remove`].
+    async fn remove(
+        &self,
+        body: &crate::types::AdminEmojiRemoveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminEmoji::rename`].
+    async fn rename(
+        &self,
+        body: &crate::types::AdminEmojiRenameRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AdminEmojiApi for AdminEmoji {
+    async fn add(
+        &self,
+        body: &crate::types::AdminEmojiAddRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminEmoji::add(self, body).await
+    }
+
+    async fn add_alias(
+        &self,
+        body: &crate::types::AdminEmojiAddAliasRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminEmoji::add_alias(self, body).await
+    }
+
+    async fn list_with(
+        &self,
+        params: &AdminEmojiListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminEmoji::list_with(self, params).await
+    }
+
+    async fn remove(
+        &self,
+        body: &crate::types::AdminEmojiRemoveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminEmoji::remove(self, body).await
+    }
+
+    async fn rename(
+        &self,
+        body: &crate::types::AdminEmojiRenameRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminEmoji::rename(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminInviteRequests {
     pub client: Client,
@@ -26,7 +24,7 @@ impl AdminInviteRequests {
     pub async fn approve(
         &self,
         body: &crate::types::AdminInviteRequestsApproveRequest,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.inviteRequests.approve".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -47,7 +45,7 @@ impl AdminInviteRequests {
     pub async fn deny(
         &self,
         body: &crate::types::AdminInviteRequestsApproveRequest,
-    ) -> Result<crate::types::DndEndSchema> {
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.inviteRequests.deny".to_string();
         self.client
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
@@ -63,25 +61,20 @@ impl AdminInviteRequests {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin.invites:read`.
-     * * `team_id: &str` -- ID for the workspace where the invite requests were made.
-     * * `cursor: &str` -- Value of the `next_cursor` field sent as part of the previous API response.
-     * * `limit: i64` -- The number of results that will be returned by the API on each invocation. Must be between 1 - 1000, both inclusive.
+     * * `params: &AdminInviteRequestsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        team_id: &str,
-        cursor: &str,
-        limit: i64,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &AdminInviteRequestsListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !team_id.is_empty() {
+        if let Some(team_id) = &params.team_id {
             query_args.push(("team_id".to_string(), team_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -89,4 +82,98 @@ impl AdminInviteRequests {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `AdminInviteRequestsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = AdminInviteRequestsListParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            team_id: Some(team_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+}
+
+/// The query parameters of `AdminInviteRequests::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AdminInviteRequestsListParams {
+    /// Value of the `next_cursor` field sent as part of the previous API response.
+    pub cursor: Option<String>,
+    /// The number of results that will be returned by the API on each invocation. Must be between 1 - 1000, both inclusive.
+    pub limit: Option<i64>,
+    /// ID for the workspace where the invite requests were made.
+    pub team_id: Option<String>,
+}
+
+impl AdminInviteRequestsListParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn team_id<T: ToString>(mut self, team_id: T) -> Self {
+        self.team_id = Some(team_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`AdminInviteRequests`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminInviteRequestsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminInviteRequestsApi: Send + Sync {
+    /// See [`AdminInviteRequests::approve`].
+    async fn approve(
+        &self,
+        body: &crate::types::AdminInviteRequestsApproveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminInviteRequests::deny`].
+    async fn deny(
+        &self,
+        body: &crate::types::AdminInviteRequestsApproveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminInviteRequests::list_with`].
+    async fn list_with(
+        &self,
+        params: &AdminInviteRequestsListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AdminInviteRequestsApi for AdminInviteRequests {
+    async fn approve(
+        &self,
+        body: &crate::types::AdminInviteRequestsApproveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminInviteRequests::approve(self, body).await
+    }
+
+    async fn deny(
+        &self,
+        body: &crate::types::AdminInviteRequestsApproveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminInviteRequests::deny(self, body).await
+    }
+
+    async fn list_with(
+        &self,
+        params: &AdminInviteRequestsListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminInviteRequests::list_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminInviteRequestsDenied {
     pub client: Client,
@@ -21,25 +19,20 @@ impl AdminInviteRequestsDenied {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin.invites:read`.
-     * * `team_id: &str` -- ID for the workspace where the invite requests were made.
-     * * `cursor: &str` -- Value of the `next_cursor` field sent as part of the previous api response.
-     * * `limit: i64` -- The number of results that will be returned by the API on each invocation. Must be between 1 - 1000 both inclusive.
+     * * `params: &AdminInviteRequestsDeniedListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        team_id: &str,
-        cursor: &str,
-        limit: i64,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &AdminInviteRequestsDeniedListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !team_id.is_empty() {
+        if let Some(team_id) = &params.team_id {
             query_args.push(("team_id".to_string(), team_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -47,4 +40,72 @@ impl AdminInviteRequestsDenied {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `AdminInviteRequestsDeniedListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = AdminInviteRequestsDeniedListParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            team_id: Some(team_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+}
+
+/// The query parameters of `AdminInviteRequestsDenied::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AdminInviteRequestsDeniedListParams {
+    /// Value of the `next_cursor` field sent as part of the previous api response.
+    pub cursor: Option<String>,
+    /// The number of results that will be returned by the API on each invocation. Must be between 1 - 1000 both inclusive.
+    pub limit: Option<i64>,
+    /// ID for the workspace where the invite requests were made.
+    pub team_id: Option<String>,
+}
+
+impl AdminInviteRequestsDeniedListParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn team_id<T: ToString>(mut self, team_id: T) -> Self {
+        self.team_id = Some(team_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`AdminInviteRequestsDenied`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminInviteRequestsDeniedApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminInviteRequestsDeniedApi: Send + Sync {
+    /// See [`AdminInviteRequestsDenied::list_with`].
+    async fn list_with(
+        &self,
+        params: &AdminInviteRequestsDeniedListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AdminInviteRequestsDeniedApi for AdminInviteRequestsDenied {
+    async fn list_with(
+        &self,
+        params: &AdminInviteRequestsDeniedListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminInviteRequestsDenied::list_with(self, params).await
+    }
 }
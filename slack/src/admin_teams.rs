@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminTeams {
     pub client: Client,
@@ -23,9 +21,18 @@ impl AdminTeams {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn create(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::AdminTeamsCreateRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.teams.create".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -37,16 +44,17 @@ impl AdminTeams {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin.teams:read`.
-     * * `limit: i64` -- The maximum number of items to return. Must be between 1 - 100 both inclusive.
-     * * `cursor: &str` -- Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
+     * * `params: &AdminTeamsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(&self, limit: i64, cursor: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn list_with(
+        &self,
+        params: &AdminTeamsListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -54,4 +62,76 @@ impl AdminTeams {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `AdminTeamsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = AdminTeamsListParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_with(&params).await
+    }
+}
+
+/// The query parameters of `AdminTeams::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AdminTeamsListParams {
+    /// Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return. Must be between 1 - 100 both inclusive.
+    pub limit: Option<i64>,
+}
+
+impl AdminTeamsListParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The functions of [`AdminTeams`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminTeamsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminTeamsApi: Send + Sync {
+    /// See [`AdminTeams::create`].
+    async fn create(
+        &self,
+        body: &crate::types::AdminTeamsCreateRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminTeams::list_with`].
+    async fn list_with(
+        &self,
+        params: &AdminTeamsListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AdminTeamsApi for AdminTeams {
+    async fn create(
+        &self,
+        body: &crate::types::AdminTeamsCreateRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminTeams::create(self, body).await
+    }
+
+    async fn list_with(
+        &self,
+        params: &AdminTeamsListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminTeams::list_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminTeamsAdmins {
     pub client: Client,
@@ -21,25 +19,20 @@ impl AdminTeamsAdmins {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin.teams:read`.
-     * * `limit: i64` -- The maximum number of items to return.
-     * * `cursor: &str` -- Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
-     * * `team_id: &str`
+     * * `params: &AdminTeamsAdminsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        limit: i64,
-        cursor: &str,
-        team_id: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &AdminTeamsAdminsListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !team_id.is_empty() {
+        if let Some(team_id) = &params.team_id {
             query_args.push(("team_id".to_string(), team_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -47,4 +40,71 @@ impl AdminTeamsAdmins {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `AdminTeamsAdminsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+        team_id: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = AdminTeamsAdminsListParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            team_id: Some(team_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+}
+
+/// The query parameters of `AdminTeamsAdmins::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AdminTeamsAdminsListParams {
+    /// Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return.
+    pub limit: Option<i64>,
+    pub team_id: Option<String>,
+}
+
+impl AdminTeamsAdminsListParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn team_id<T: ToString>(mut self, team_id: T) -> Self {
+        self.team_id = Some(team_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`AdminTeamsAdmins`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminTeamsAdminsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminTeamsAdminsApi: Send + Sync {
+    /// See [`AdminTeamsAdmins::list_with`].
+    async fn list_with(
+        &self,
+        params: &AdminTeamsAdminsListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AdminTeamsAdminsApi for AdminTeamsAdmins {
+    async fn list_with(
+        &self,
+        params: &AdminTeamsAdminsListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminTeamsAdmins::list_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminTeamsSettings {
     pub client: Client,
@@ -21,12 +19,14 @@ impl AdminTeamsSettings {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin.teams:read`.
-     * * `team_id: &str`
+     * * `params: &AdminTeamsSettingsInfoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn info(&self, team_id: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn info_with(
+        &self,
+        params: &AdminTeamsSettingsInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !team_id.is_empty() {
+        if let Some(team_id) = &params.team_id {
             query_args.push(("team_id".to_string(), team_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -35,6 +35,17 @@ impl AdminTeamsSettings {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `info_with` and pass the query parameters as a `AdminTeamsSettingsInfoParams` instead.
+     */
+    #[deprecated(note = "use `info_with` instead")]
+    pub async fn info(&self, team_id: &str) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = AdminTeamsSettingsInfoParams {
+            team_id: Some(team_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.info_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/admin.teams.settings.setDefaultChannels` endpoint.
      *
@@ -42,9 +53,18 @@ impl AdminTeamsSettings {
      *
      * FROM: <https://api.slack.com/methods/admin.teams.settings.setDefaultChannels>
      */
-    pub async fn set_default_channels(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_default_channels(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDefaultChannelsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.teams.settings.setDefaultChannels".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -58,9 +78,18 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_description(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_description(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDescriptionRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.teams.settings.setDescription".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -74,9 +103,18 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_discoverability(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_discoverability(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDiscoverabilityRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.teams.settings.setDiscoverability".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -86,9 +124,18 @@ impl AdminTeamsSettings {
      *
      * FROM: <https://api.slack.com/methods/admin.teams.settings.setIcon>
      */
-    pub async fn set_icon(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_icon(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetIconRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.teams.settings.setIcon".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -102,8 +149,117 @@ impl AdminTeamsSettings {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn set_name(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_name(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetNameRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.teams.settings.setName".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `AdminTeamsSettings::info_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AdminTeamsSettingsInfoParams {
+    pub team_id: Option<String>,
+}
+
+impl AdminTeamsSettingsInfoParams {
+    pub fn team_id<T: ToString>(mut self, team_id: T) -> Self {
+        self.team_id = Some(team_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`AdminTeamsSettings`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminTeamsSettingsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminTeamsSettingsApi: Send + Sync {
+    /// See [`AdminTeamsSettings::info_with`].
+    async fn info_with(
+        &self,
+        params: &AdminTeamsSettingsInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminTeamsSettings::set_default_channels`].
+    async fn set_default_channels(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDefaultChannelsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminTeamsSettings::set_description`].
+    async fn set_description(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDescriptionRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminTeamsSettings::set_discoverability`].
+    async fn set_discoverability(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDiscoverabilityRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminTeamsSettings::set_icon`].
+    async fn set_icon(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetIconRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminTeamsSettings::set_name`].
+    async fn set_name(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetNameRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AdminTeamsSettingsApi for AdminTeamsSettings {
+    async fn info_with(
+        &self,
+        params: &AdminTeamsSettingsInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminTeamsSettings::info_with(self, params).await
+    }
+
+    async fn set_default_channels(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDefaultChannelsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminTeamsSettings::set_default_channels(self, body).await
+    }
+
+    async fn set_description(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDescriptionRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminTeamsSettings::set_description(self, body).await
+    }
+
+    async fn set_discoverability(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetDiscoverabilityRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminTeamsSettings::set_discoverability(self, body).await
+    }
+
+    async fn set_icon(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetIconRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminTeamsSettings::set_icon(self, body).await
+    }
+
+    async fn set_name(
+        &self,
+        body: &crate::types::AdminTeamsSettingsSetNameRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminTeamsSettings::set_name(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminUsergroups {
     pub client: Client,
@@ -23,9 +21,18 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.usergroups:write`.
      */
-    pub async fn add_channels(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsAddChannelsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.usergroups.addChannels".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -39,9 +46,18 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.teams:write`.
      */
-    pub async fn add_teams(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add_teams(
+        &self,
+        body: &crate::types::AdminUsergroupsAddTeamsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.usergroups.addTeams".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -53,28 +69,23 @@ impl AdminUsergroups {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin.usergroups:read`.
-     * * `usergroup_id: &str` -- ID of the IDP group to list default channels for.
-     * * `team_id: &str` -- ID of the the workspace.
-     * * `include_num_members: bool` -- Flag to include or exclude the count of members per channel.
+     * * `params: &AdminUsergroupsListChannelParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_channel(
+    pub async fn list_channel_with(
         &self,
-        usergroup_id: &str,
-        team_id: &str,
-        include_num_members: bool,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &AdminUsergroupsListChannelParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_num_members {
+        if let Some(include_num_members) = &params.include_num_members {
             query_args.push((
                 "include_num_members".to_string(),
                 include_num_members.to_string(),
             ));
         }
-        if !team_id.is_empty() {
+        if let Some(team_id) = &params.team_id {
             query_args.push(("team_id".to_string(), team_id.to_string()));
         }
-        if !usergroup_id.is_empty() {
+        if let Some(usergroup_id) = &params.usergroup_id {
             query_args.push(("usergroup_id".to_string(), usergroup_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -83,6 +94,24 @@ impl AdminUsergroups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_channel_with` and pass the query parameters as a `AdminUsergroupsListChannelParams` instead.
+     */
+    #[deprecated(note = "use `list_channel_with` instead")]
+    pub async fn list_channel(
+        &self,
+        usergroup_id: &str,
+        team_id: &str,
+        include_num_members: bool,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = AdminUsergroupsListChannelParams {
+            include_num_members: Some(include_num_members).filter(|v| *v),
+            team_id: Some(team_id.to_string()).filter(|s| !s.is_empty()),
+            usergroup_id: Some(usergroup_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_channel_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/admin.usergroups.removeChannels` endpoint.
      *
@@ -94,8 +123,106 @@ impl AdminUsergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.usergroups:write`.
      */
-    pub async fn remove_channels(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsRemoveChannelsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.usergroups.removeChannels".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `AdminUsergroups::list_channel_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AdminUsergroupsListChannelParams {
+    /// Flag to include or exclude the count of members per channel.
+    pub include_num_members: Option<bool>,
+    /// ID of the the workspace.
+    pub team_id: Option<String>,
+    /// ID of the IDP group to list default channels for.
+    pub usergroup_id: Option<String>,
+}
+
+impl AdminUsergroupsListChannelParams {
+    pub fn include_num_members(mut self, include_num_members: bool) -> Self {
+        self.include_num_members = Some(include_num_members);
+        self
+    }
+
+    pub fn team_id<T: ToString>(mut self, team_id: T) -> Self {
+        self.team_id = Some(team_id.to_string());
+        self
+    }
+
+    pub fn usergroup_id<T: ToString>(mut self, usergroup_id: T) -> Self {
+        self.usergroup_id = Some(usergroup_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`AdminUsergroups`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminUsergroupsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminUsergroupsApi: Send + Sync {
+    /// See [`AdminUsergroups::add_channels`].
+    async fn add_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsAddChannelsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminUsergroups::add_teams`].
+    async fn add_teams(
+        &self,
+        body: &crate::types::AdminUsergroupsAddTeamsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminUsergroups::list_channel_with`].
+    async fn list_channel_with(
+        &self,
+        params: &AdminUsergroupsListChannelParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminUsergroups::remove_channels`].
+    async fn remove_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsRemoveChannelsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AdminUsergroupsApi for AdminUsergroups {
+    async fn add_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsAddChannelsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsergroups::add_channels(self, body).await
+    }
+
+    async fn add_teams(
+        &self,
+        body: &crate::types::AdminUsergroupsAddTeamsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsergroups::add_teams(self, body).await
+    }
+
+    async fn list_channel_with(
+        &self,
+        params: &AdminUsergroupsListChannelParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsergroups::list_channel_with(self, params).await
+    }
+
+    async fn remove_channels(
+        &self,
+        body: &crate::types::AdminUsergroupsRemoveChannelsRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsergroups::remove_channels(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminUsers {
     pub client: Client,
@@ -23,9 +21,18 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn assign(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn assign(
+        &self,
+        body: &crate::types::AdminUsersAssignRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.users.assign".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -39,9 +46,18 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn invite(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn invite(
+        &self,
+        body: &crate::types::AdminUsersInviteRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.users.invite".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -53,25 +69,20 @@ impl AdminUsers {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin.users:read`.
-     * * `team_id: &str` -- The ID (`T1234`) of the workspace.
-     * * `cursor: &str` -- Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
-     * * `limit: i64` -- Limit for how many users to be retrieved per page.
+     * * `params: &AdminUsersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        team_id: &str,
-        cursor: &str,
-        limit: i64,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &AdminUsersListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !team_id.is_empty() {
+        if let Some(team_id) = &params.team_id {
             query_args.push(("team_id".to_string(), team_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -80,6 +91,24 @@ impl AdminUsers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `AdminUsersListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        team_id: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = AdminUsersListParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            team_id: Some(team_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/admin.users.remove` endpoint.
      *
@@ -91,9 +120,18 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::AdminUsersRemoveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.users.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -107,9 +145,18 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.users.setAdmin".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -123,9 +170,18 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set_expiration(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_expiration(
+        &self,
+        body: &crate::types::AdminUsersSetExpirationRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.users.setExpiration".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -139,9 +195,18 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set_owner(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_owner(
+        &self,
+        body: &crate::types::AdminUsersSetOwnerRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.users.setOwner".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -155,8 +220,158 @@ impl AdminUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn set_regular(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_regular(
+        &self,
+        body: &crate::types::AdminUsersSetRegularRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.users.setRegular".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `AdminUsers::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AdminUsersListParams {
+    /// Set `cursor` to `next_cursor` returned by the previous call to list items in the next page.
+    pub cursor: Option<String>,
+    /// Limit for how many users to be retrieved per page.
+    pub limit: Option<i64>,
+    /// The ID (`T1234`) of the workspace.
+    pub team_id: Option<String>,
+}
+
+impl AdminUsersListParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn team_id<T: ToString>(mut self, team_id: T) -> Self {
+        self.team_id = Some(team_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`AdminUsers`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminUsersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminUsersApi: Send + Sync {
+    /// See [`AdminUsers::assign`].
+    async fn assign(
+        &self,
+        body: &crate::types::AdminUsersAssignRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminUsers::invite`].
+    async fn invite(
+        &self,
+        body: &crate::types::AdminUsersInviteRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminUsers::list_with`].
+    async fn list_with(
+        &self,
+        params: &AdminUsersListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminUsers::remove`].
+    async fn remove(
+        &self,
+        body: &crate::types::AdminUsersRemoveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminUsers::set`].
+    async fn set(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminUsers::set_expiration`].
+    async fn set_expiration(
+        &self,
+        body: &crate::types::AdminUsersSetExpirationRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminUsers::set_owner`].
+    async fn set_owner(
+        &self,
+        body: &crate::types::AdminUsersSetOwnerRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminUsers::set_regular`].
+    async fn set_regular(
+        &self,
+        body: &crate::types::AdminUsersSetRegularRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AdminUsersApi for AdminUsers {
+    async fn assign(
+        &self,
+        body: &crate::types::AdminUsersAssignRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsers::assign(self, body).await
+    }
+
+    async fn invite(
+        &self,
+        body: &crate::types::AdminUsersInviteRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsers::invite(self, body).await
+    }
+
+    async fn list_with(
+        &self,
+        params: &AdminUsersListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsers::list_with(self, params).await
+    }
+
+    async fn remove(
+        &self,
+        body: &crate::types::AdminUsersRemoveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsers::remove(self, body).await
+    }
+
+    async fn set(
+        &self,
+        body: &crate::types::AdminUsersSetRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsers::set(self, body).await
+    }
+
+    async fn set_expiration(
+        &self,
+        body: &crate::types::AdminUsersSetExpirationRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsers::set_expiration(self, body).await
+    }
+
+    async fn set_owner(
+        &self,
+        body: &crate::types::AdminUsersSetOwnerRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsers::set_owner(self, body).await
+    }
+
+    async fn set_regular(
+        &self,
+        body: &crate::types::AdminUsersSetRegularRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsers::set_regular(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AdminUsersSession {
     pub client: Client,
@@ -23,9 +21,18 @@ impl AdminUsersSession {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn invalidate(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn invalidate(
+        &self,
+        body: &crate::types::AdminUsersSessionInvalidateRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.users.session.invalidate".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -39,8 +46,52 @@ impl AdminUsersSession {
      *
      * * `token: &str` -- Authentication token. Requires scope: `admin.users:write`.
      */
-    pub async fn reset(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn reset(
+        &self,
+        body: &crate::types::AdminUsersSessionResetRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/admin.users.session.reset".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The functions of [`AdminUsersSession`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAdminUsersSessionApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AdminUsersSessionApi: Send + Sync {
+    /// See [`AdminUsersSession::invalidate`].
+    async fn invalidate(
+        &self,
+        body: &crate::types::AdminUsersSessionInvalidateRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AdminUsersSession::reset`].
+    async fn reset(
+        &self,
+        body: &crate::types::AdminUsersSessionResetRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AdminUsersSessionApi for AdminUsersSession {
+    async fn invalidate(
+        &self,
+        body: &crate::types::AdminUsersSessionInvalidateRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsersSession::invalidate(self, body).await
+    }
+
+    async fn reset(
+        &self,
+        body: &crate::types::AdminUsersSessionResetRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AdminUsersSession::reset(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Api {
     pub client: Client,
@@ -21,15 +19,17 @@ impl Api {
      *
      * **Parameters:**
      *
-     * * `error: &str` -- Error response to return.
-     * * `foo_: &str` -- example property to return.
+     * * `params: &ApiTestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn test(&self, error: &str, foo_: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn test_with(
+        &self,
+        params: &ApiTestParams,
+    ) -> Result<crate::types::ApiTestSuccessSchemaData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !error.is_empty() {
+        if let Some(error) = &params.error {
             query_args.push(("error".to_string(), error.to_string()));
         }
-        if !foo_.is_empty() {
+        if let Some(foo_) = &params.foo_ {
             query_args.push(("foo".to_string(), foo_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -37,4 +37,63 @@ impl Api {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `test_with` and pass the query parameters as a `ApiTestParams` instead.
+     */
+    #[deprecated(note = "use `test_with` instead")]
+    pub async fn test(
+        &self,
+        error: &str,
+        foo_: &str,
+    ) -> Result<crate::types::ApiTestSuccessSchemaData> {
+        let params = ApiTestParams {
+            error: Some(error.to_string()).filter(|s| !s.is_empty()),
+            foo_: Some(foo_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.test_with(&params).await
+    }
+}
+
+/// The query parameters of `Api::test_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApiTestParams {
+    /// Error response to return.
+    pub error: Option<String>,
+    /// example property to return.
+    pub foo_: Option<String>,
+}
+
+impl ApiTestParams {
+    pub fn error<T: ToString>(mut self, error: T) -> Self {
+        self.error = Some(error.to_string());
+        self
+    }
+
+    pub fn foo_<T: ToString>(mut self, foo_: T) -> Self {
+        self.foo_ = Some(foo_.to_string());
+        self
+    }
+}
+
+/// The functions of [`Api`], for code that takes them as a trait, so that it can be
+/// tested with the `MockApiApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ApiApi: Send + Sync {
+    /// See [`Api::test_with`].
+    async fn test_with(
+        &self,
+        params: &ApiTestParams,
+    ) -> Result<crate::types::ApiTestSuccessSchemaData>;
+}
+
+#[async_trait::async_trait]
+impl ApiApi for Api {
+    async fn test_with(
+        &self,
+        params: &ApiTestParams,
+    ) -> Result<crate::types::ApiTestSuccessSchemaData> {
+        Api::test_with(self, params).await
+    }
 }
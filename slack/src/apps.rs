@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Apps {
     pub client: Client,
@@ -21,20 +19,17 @@ impl Apps {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `none`.
-     * * `client_id: &str` -- Issued when you created your application.
-     * * `client_secret: &str` -- Issued when you created your application.
+     * * `params: &AppsUninstallParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn uninstall(
+    pub async fn uninstall_with(
         &self,
-        client_id: &str,
-        client_secret: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &AppsUninstallParams,
+    ) -> Result<crate::types::AppsUninstallSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_id.is_empty() {
+        if let Some(client_id) = &params.client_id {
             query_args.push(("client_id".to_string(), client_id.to_string()));
         }
-        if !client_secret.is_empty() {
+        if let Some(client_secret) = &params.client_secret {
             query_args.push(("client_secret".to_string(), client_secret.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -42,4 +37,63 @@ impl Apps {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `uninstall_with` and pass the query parameters as a `AppsUninstallParams` instead.
+     */
+    #[deprecated(note = "use `uninstall_with` instead")]
+    pub async fn uninstall(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+    ) -> Result<crate::types::AppsUninstallSchema> {
+        let params = AppsUninstallParams {
+            client_id: Some(client_id.to_string()).filter(|s| !s.is_empty()),
+            client_secret: Some(client_secret.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.uninstall_with(&params).await
+    }
+}
+
+/// The query parameters of `Apps::uninstall_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsUninstallParams {
+    /// Issued when you created your application.
+    pub client_id: Option<String>,
+    /// Issued when you created your application.
+    pub client_secret: Option<String>,
+}
+
+impl AppsUninstallParams {
+    pub fn client_id<T: ToString>(mut self, client_id: T) -> Self {
+        self.client_id = Some(client_id.to_string());
+        self
+    }
+
+    pub fn client_secret<T: ToString>(mut self, client_secret: T) -> Self {
+        self.client_secret = Some(client_secret.to_string());
+        self
+    }
+}
+
+/// The functions of [`Apps`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAppsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AppsApi: Send + Sync {
+    /// See [`Apps::uninstall_with`].
+    async fn uninstall_with(
+        &self,
+        params: &AppsUninstallParams,
+    ) -> Result<crate::types::AppsUninstallSchema>;
+}
+
+#[async_trait::async_trait]
+impl AppsApi for Apps {
+    async fn uninstall_with(
+        &self,
+        params: &AppsUninstallParams,
+    ) -> Result<crate::types::AppsUninstallSchema> {
+        Apps::uninstall_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AppsEventAuthorizations {
     pub client: Client,
@@ -21,25 +19,20 @@ impl AppsEventAuthorizations {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `authorizations:read`.
-     * * `event_context: &str`
-     * * `cursor: &str`
-     * * `limit: i64`
+     * * `params: &AppsEventAuthorizationsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        event_context: &str,
-        cursor: &str,
-        limit: i64,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &AppsEventAuthorizationsListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if !event_context.is_empty() {
+        if let Some(event_context) = &params.event_context {
             query_args.push(("event_context".to_string(), event_context.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -47,4 +40,69 @@ impl AppsEventAuthorizations {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `AppsEventAuthorizationsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        event_context: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = AppsEventAuthorizationsListParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            event_context: Some(event_context.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_with(&params).await
+    }
+}
+
+/// The query parameters of `AppsEventAuthorizations::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsEventAuthorizationsListParams {
+    pub cursor: Option<String>,
+    pub event_context: Option<String>,
+    pub limit: Option<i64>,
+}
+
+impl AppsEventAuthorizationsListParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn event_context<T: ToString>(mut self, event_context: T) -> Self {
+        self.event_context = Some(event_context.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The functions of [`AppsEventAuthorizations`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAppsEventAuthorizationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AppsEventAuthorizationsApi: Send + Sync {
+    /// See [`AppsEventAuthorizations::list_with`].
+    async fn list_with(
+        &self,
+        params: &AppsEventAuthorizationsListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AppsEventAuthorizationsApi for AppsEventAuthorizations {
+    async fn list_with(
+        &self,
+        params: &AppsEventAuthorizationsListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AppsEventAuthorizations::list_with(self, params).await
+    }
 }
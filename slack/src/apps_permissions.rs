@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AppsPermissions {
     pub client: Client,
@@ -37,20 +35,17 @@ impl AppsPermissions {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `none`.
-     * * `scopes: &str` -- A comma separated list of scopes to request for.
-     * * `trigger_id: &str` -- Token used to trigger the permissions API.
+     * * `params: &AppsPermissionsRequestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn request(
+    pub async fn request_with(
         &self,
-        scopes: &str,
-        trigger_id: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &AppsPermissionsRequestParams,
+    ) -> Result<crate::types::AppsPermissionsRequestSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !scopes.is_empty() {
+        if let Some(scopes) = &params.scopes {
             query_args.push(("scopes".to_string(), scopes.to_string()));
         }
-        if !trigger_id.is_empty() {
+        if let Some(trigger_id) = &params.trigger_id {
             query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -58,4 +53,70 @@ impl AppsPermissions {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `request_with` and pass the query parameters as a `AppsPermissionsRequestParams` instead.
+     */
+    #[deprecated(note = "use `request_with` instead")]
+    pub async fn request(
+        &self,
+        scopes: &str,
+        trigger_id: &str,
+    ) -> Result<crate::types::AppsPermissionsRequestSchema> {
+        let params = AppsPermissionsRequestParams {
+            scopes: Some(scopes.to_string()).filter(|s| !s.is_empty()),
+            trigger_id: Some(trigger_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.request_with(&params).await
+    }
+}
+
+/// The query parameters of `AppsPermissions::request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsPermissionsRequestParams {
+    /// A comma separated list of scopes to request for.
+    pub scopes: Option<String>,
+    /// Token used to trigger the permissions API.
+    pub trigger_id: Option<String>,
+}
+
+impl AppsPermissionsRequestParams {
+    pub fn scopes<T: ToString>(mut self, scopes: T) -> Self {
+        self.scopes = Some(scopes.to_string());
+        self
+    }
+
+    pub fn trigger_id<T: ToString>(mut self, trigger_id: T) -> Self {
+        self.trigger_id = Some(trigger_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`AppsPermissions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAppsPermissionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AppsPermissionsApi: Send + Sync {
+    /// See [`AppsPermissions::info`].
+    async fn info(&self) -> Result<crate::types::AppsPermissionsInfoSchema>;
+
+    /// See [`AppsPermissions::request_with`].
+    async fn request_with(
+        &self,
+        params: &AppsPermissionsRequestParams,
+    ) -> Result<crate::types::AppsPermissionsRequestSchema>;
+}
+
+#[async_trait::async_trait]
+impl AppsPermissionsApi for AppsPermissions {
+    async fn info(&self) -> Result<crate::types::AppsPermissionsInfoSchema> {
+        AppsPermissions::info(self).await
+    }
+
+    async fn request_with(
+        &self,
+        params: &AppsPermissionsRequestParams,
+    ) -> Result<crate::types::AppsPermissionsRequestSchema> {
+        AppsPermissions::request_with(self, params).await
+    }
 }
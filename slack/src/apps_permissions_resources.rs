@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AppsPermissionsResources {
     pub client: Client,
@@ -21,20 +19,17 @@ impl AppsPermissionsResources {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `none`.
-     * * `cursor: &str` -- Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
-     * * `limit: i64` -- The maximum number of items to return.
+     * * `params: &AppsPermissionsResourcesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        cursor: &str,
-        limit: i64,
+        params: &AppsPermissionsResourcesListParams,
     ) -> Result<crate::types::AppsPermissionsResourcesListSuccessSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -42,4 +37,63 @@ impl AppsPermissionsResources {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `AppsPermissionsResourcesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::AppsPermissionsResourcesListSuccessSchema> {
+        let params = AppsPermissionsResourcesListParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_with(&params).await
+    }
+}
+
+/// The query parameters of `AppsPermissionsResources::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsPermissionsResourcesListParams {
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return.
+    pub limit: Option<i64>,
+}
+
+impl AppsPermissionsResourcesListParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The functions of [`AppsPermissionsResources`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAppsPermissionsResourcesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AppsPermissionsResourcesApi: Send + Sync {
+    /// See [`AppsPermissionsResources::list_with`].
+    async fn list_with(
+        &self,
+        params: &AppsPermissionsResourcesListParams,
+    ) -> Result<crate::types::AppsPermissionsResourcesListSuccessSchema>;
+}
+
+#[async_trait::async_trait]
+impl AppsPermissionsResourcesApi for AppsPermissionsResources {
+    async fn list_with(
+        &self,
+        params: &AppsPermissionsResourcesListParams,
+    ) -> Result<crate::types::AppsPermissionsResourcesListSuccessSchema> {
+        AppsPermissionsResources::list_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AppsPermissionsScopes {
     pub client: Client,
@@ -28,3 +26,19 @@ impl AppsPermissionsScopes {
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`AppsPermissionsScopes`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAppsPermissionsScopesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AppsPermissionsScopesApi: Send + Sync {
+    /// See [`AppsPermissionsScopes::list`].
+    async fn list(&self) -> Result<crate::types::ApiPermissionsScopesListSuccessSchema>;
+}
+
+#[async_trait::async_trait]
+impl AppsPermissionsScopesApi for AppsPermissionsScopes {
+    async fn list(&self) -> Result<crate::types::ApiPermissionsScopesListSuccessSchema> {
+        AppsPermissionsScopes::list(self).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AppsPermissionsUsers {
     pub client: Client,
@@ -21,16 +19,17 @@ impl AppsPermissionsUsers {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `none`.
-     * * `cursor: &str` -- Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
-     * * `limit: i64` -- The maximum number of items to return.
+     * * `params: &AppsPermissionsUsersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(&self, cursor: &str, limit: i64) -> Result<crate::types::DndEndSchema> {
+    pub async fn list_with(
+        &self,
+        params: &AppsPermissionsUsersListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -39,6 +38,22 @@ impl AppsPermissionsUsers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `AppsPermissionsUsersListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = AppsPermissionsUsersListParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/apps.permissions.users.request` endpoint.
      *
@@ -48,25 +63,20 @@ impl AppsPermissionsUsers {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `none`.
-     * * `scopes: &str` -- A comma separated list of user scopes to request for.
-     * * `trigger_id: &str` -- Token used to trigger the request.
-     * * `user: &str` -- The user this scope is being requested for.
+     * * `params: &AppsPermissionsUsersRequestParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn request(
+    pub async fn request_with(
         &self,
-        scopes: &str,
-        trigger_id: &str,
-        user: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &AppsPermissionsUsersRequestParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !scopes.is_empty() {
+        if let Some(scopes) = &params.scopes {
             query_args.push(("scopes".to_string(), scopes.to_string()));
         }
-        if !trigger_id.is_empty() {
+        if let Some(trigger_id) = &params.trigger_id {
             query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
         }
-        if !user.is_empty() {
+        if let Some(user) = &params.user {
             query_args.push(("user".to_string(), user.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -74,4 +84,106 @@ impl AppsPermissionsUsers {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `request_with` and pass the query parameters as a `AppsPermissionsUsersRequestParams` instead.
+     */
+    #[deprecated(note = "use `request_with` instead")]
+    pub async fn request(
+        &self,
+        scopes: &str,
+        trigger_id: &str,
+        user: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = AppsPermissionsUsersRequestParams {
+            scopes: Some(scopes.to_string()).filter(|s| !s.is_empty()),
+            trigger_id: Some(trigger_id.to_string()).filter(|s| !s.is_empty()),
+            user: Some(user.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.request_with(&params).await
+    }
+}
+
+/// The query parameters of `AppsPermissionsUsers::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsPermissionsUsersListParams {
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return.
+    pub limit: Option<i64>,
+}
+
+impl AppsPermissionsUsersListParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `AppsPermissionsUsers::request_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AppsPermissionsUsersRequestParams {
+    /// A comma separated list of user scopes to request for.
+    pub scopes: Option<String>,
+    /// Token used to trigger the request.
+    pub trigger_id: Option<String>,
+    /// The user this scope is being requested for.
+    pub user: Option<String>,
+}
+
+impl AppsPermissionsUsersRequestParams {
+    pub fn scopes<T: ToString>(mut self, scopes: T) -> Self {
+        self.scopes = Some(scopes.to_string());
+        self
+    }
+
+    pub fn trigger_id<T: ToString>(mut self, trigger_id: T) -> Self {
+        self.trigger_id = Some(trigger_id.to_string());
+        self
+    }
+
+    pub fn user<T: ToString>(mut self, user: T) -> Self {
+        self.user = Some(user.to_string());
+        self
+    }
+}
+
+/// The functions of [`AppsPermissionsUsers`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAppsPermissionsUsersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AppsPermissionsUsersApi: Send + Sync {
+    /// See [`AppsPermissionsUsers::list_with`].
+    async fn list_with(
+        &self,
+        params: &AppsPermissionsUsersListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`AppsPermissionsUsers::request_with`].
+    async fn request_with(
+        &self,
+        params: &AppsPermissionsUsersRequestParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl AppsPermissionsUsersApi for AppsPermissionsUsers {
+    async fn list_with(
+        &self,
+        params: &AppsPermissionsUsersListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AppsPermissionsUsers::list_with(self, params).await
+    }
+
+    async fn request_with(
+        &self,
+        params: &AppsPermissionsUsersRequestParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        AppsPermissionsUsers::request_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Auth {
     pub client: Client,
@@ -21,12 +19,14 @@ impl Auth {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `none`.
-     * * `test: bool` -- Setting this parameter to `1` triggers a _testing mode_ where the specified token will not actually be revoked.
+     * * `params: &AuthRevokeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn revoke(&self, test: bool) -> Result<crate::types::AuthRevokeSchema> {
+    pub async fn revoke_with(
+        &self,
+        params: &AuthRevokeParams,
+    ) -> Result<crate::types::AuthRevokeSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if test {
+        if let Some(test) = &params.test {
             query_args.push(("test".to_string(), test.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -35,6 +35,17 @@ impl Auth {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `revoke_with` and pass the query parameters as a `AuthRevokeParams` instead.
+     */
+    #[deprecated(note = "use `revoke_with` instead")]
+    pub async fn revoke(&self, test: bool) -> Result<crate::types::AuthRevokeSchema> {
+        let params = AuthRevokeParams {
+            test: Some(test).filter(|v| *v),
+        };
+        self.revoke_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/auth.test` endpoint.
      *
@@ -51,3 +62,46 @@ impl Auth {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Auth::revoke_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AuthRevokeParams {
+    /// Setting this parameter to `1` triggers a _testing mode_ where the specified token will not actually be revoked.
+    pub test: Option<bool>,
+}
+
+impl AuthRevokeParams {
+    pub fn test(mut self, test: bool) -> Self {
+        self.test = Some(test);
+        self
+    }
+}
+
+/// The functions of [`Auth`], for code that takes them as a trait, so that it can be
+/// tested with the `MockAuthApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait AuthApi: Send + Sync {
+    /// See [`Auth::revoke_with`].
+    async fn revoke_with(
+        &self,
+        params: &AuthRevokeParams,
+    ) -> Result<crate::types::AuthRevokeSchema>;
+
+    /// See [`Auth::test`].
+    async fn test(&self) -> Result<crate::types::AuthTestSuccessSchema>;
+}
+
+#[async_trait::async_trait]
+impl AuthApi for Auth {
+    async fn revoke_with(
+        &self,
+        params: &AuthRevokeParams,
+    ) -> Result<crate::types::AuthRevokeSchema> {
+        Auth::revoke_with(self, params).await
+    }
+
+    async fn test(&self) -> Result<crate::types::AuthTestSuccessSchema> {
+        Auth::test(self).await
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Bots {
     pub client: Client,
@@ -21,12 +19,11 @@ impl Bots {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `users:read`.
-     * * `bot: &str` -- Bot user to get info on.
+     * * `params: &BotsInfoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn info(&self, bot: &str) -> Result<crate::types::BotsInfoSchema> {
+    pub async fn info_with(&self, params: &BotsInfoParams) -> Result<crate::types::BotsInfoSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !bot.is_empty() {
+        if let Some(bot) = &params.bot {
             query_args.push(("bot".to_string(), bot.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -34,4 +31,45 @@ impl Bots {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `info_with` and pass the query parameters as a `BotsInfoParams` instead.
+     */
+    #[deprecated(note = "use `info_with` instead")]
+    pub async fn info(&self, bot: &str) -> Result<crate::types::BotsInfoSchema> {
+        let params = BotsInfoParams {
+            bot: Some(bot.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.info_with(&params).await
+    }
+}
+
+/// The query parameters of `Bots::info_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BotsInfoParams {
+    /// Bot user to get info on.
+    pub bot: Option<String>,
+}
+
+impl BotsInfoParams {
+    pub fn bot<T: ToString>(mut self, bot: T) -> Self {
+        self.bot = Some(bot.to_string());
+        self
+    }
+}
+
+/// The functions of [`Bots`], for code that takes them as a trait, so that it can be
+/// tested with the `MockBotsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait BotsApi: Send + Sync {
+    /// See [`Bots::info_with`].
+    async fn info_with(&self, params: &BotsInfoParams) -> Result<crate::types::BotsInfoSchema>;
+}
+
+#[async_trait::async_trait]
+impl BotsApi for Bots {
+    async fn info_with(&self, params: &BotsInfoParams) -> Result<crate::types::BotsInfoSchema> {
+        Bots::info_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Calls {
     pub client: Client,
@@ -23,9 +21,18 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::CallsAddRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/calls.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -39,9 +46,18 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn end(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn end(
+        &self,
+        body: &crate::types::CallsEndRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/calls.end".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -53,12 +69,14 @@ impl Calls {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `calls:read`.
-     * * `id: &str` -- `id` of the Call returned by the [`calls.add`](/methods/calls.add) method.
+     * * `params: &CallsInfoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn info(&self, id: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn info_with(
+        &self,
+        params: &CallsInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !id.is_empty() {
+        if let Some(id) = &params.id {
             query_args.push(("id".to_string(), id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -67,6 +85,17 @@ impl Calls {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `info_with` and pass the query parameters as a `CallsInfoParams` instead.
+     */
+    #[deprecated(note = "use `info_with` instead")]
+    pub async fn info(&self, id: &str) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = CallsInfoParams {
+            id: Some(id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.info_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/calls.update` endpoint.
      *
@@ -78,8 +107,92 @@ impl Calls {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn update(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::CallsUpdateRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/calls.update".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Calls::info_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CallsInfoParams {
+    /// `id` of the Call returned by the [`calls.add`](/methods/calls.add) method.
+    pub id: Option<String>,
+}
+
+impl CallsInfoParams {
+    pub fn id<T: ToString>(mut self, id: T) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+}
+
+/// The functions of [`Calls`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCallsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CallsApi: Send + Sync {
+    /// See [`Calls::add`].
+    async fn add(
+        &self,
+        body: &crate::types::CallsAddRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`Calls::end`].
+    async fn end(
+        &self,
+        body: &crate::types::CallsEndRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`Calls::info_with`].
+    async fn info_with(
+        &self,
+        params: &CallsInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`Calls::update`].
+    async fn update(
+        &self,
+        body: &crate::types::CallsUpdateRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl CallsApi for Calls {
+    async fn add(
+        &self,
+        body: &crate::types::CallsAddRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Calls::add(self, body).await
+    }
+
+    async fn end(
+        &self,
+        body: &crate::types::CallsEndRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Calls::end(self, body).await
+    }
+
+    async fn info_with(
+        &self,
+        params: &CallsInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Calls::info_with(self, params).await
+    }
+
+    async fn update(
+        &self,
+        body: &crate::types::CallsUpdateRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Calls::update(self, body).await
     }
 }
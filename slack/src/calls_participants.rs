@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CallsParticipants {
     pub client: Client,
@@ -23,9 +21,18 @@ impl CallsParticipants {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::CallsParticipantsAddRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/calls.participants.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -39,8 +46,52 @@ impl CallsParticipants {
      *
      * * `token: &str` -- Authentication token. Requires scope: `calls:write`.
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::CallsParticipantsRemoveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/calls.participants.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The functions of [`CallsParticipants`], for code that takes them as a trait, so that it can be
+/// tested with the `MockCallsParticipantsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait CallsParticipantsApi: Send + Sync {
+    /// See [`CallsParticipants::add`].
+    async fn add(
+        &self,
+        body: &crate::types::CallsParticipantsAddRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`CallsParticipants::remove`].
+    async fn remove(
+        &self,
+        body: &crate::types::CallsParticipantsRemoveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl CallsParticipantsApi for CallsParticipants {
+    async fn add(
+        &self,
+        body: &crate::types::CallsParticipantsAddRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        CallsParticipants::add(self, body).await
+    }
+
+    async fn remove(
+        &self,
+        body: &crate::types::CallsParticipantsRemoveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        CallsParticipants::remove(self, body).await
     }
 }
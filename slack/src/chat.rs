@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Chat {
     pub client: Client,
@@ -23,9 +21,18 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn delete(&self) -> Result<crate::types::ChatDeleteSuccessSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::ChatDeleteRequest,
+    ) -> Result<crate::types::ChatDeleteSuccessSchema> {
         let url = "/chat.delete".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -39,9 +46,18 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn delete_scheduled_message(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete_scheduled_message(
+        &self,
+        body: &crate::types::ChatDeleteScheduledMessageRequest,
+    ) -> Result<crate::types::ChatDeleteScheduledMessageSchema> {
         let url = "/chat.deleteScheduledMessage".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -53,20 +69,17 @@ impl Chat {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `none`.
-     * * `channel: &str` -- The ID of the conversation or channel containing the message.
-     * * `message_ts: &str` -- A message's `ts` value, uniquely identifying it within a channel.
+     * * `params: &ChatGetPermalinkParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_permalink(
+    pub async fn get_permalink_with(
         &self,
-        channel: &str,
-        message_ts: &str,
+        params: &ChatGetPermalinkParams,
     ) -> Result<crate::types::ChatGetPermalinkSuccessSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel.is_empty() {
+        if let Some(channel) = &params.channel {
             query_args.push(("channel".to_string(), channel.to_string()));
         }
-        if !message_ts.is_empty() {
+        if let Some(message_ts) = &params.message_ts {
             query_args.push(("message_ts".to_string(), message_ts.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -75,6 +88,22 @@ impl Chat {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_permalink_with` and pass the query parameters as a `ChatGetPermalinkParams` instead.
+     */
+    #[deprecated(note = "use `get_permalink_with` instead")]
+    pub async fn get_permalink(
+        &self,
+        channel: &str,
+        message_ts: &str,
+    ) -> Result<crate::types::ChatGetPermalinkSuccessSchema> {
+        let params = ChatGetPermalinkParams {
+            channel: Some(channel.to_string()).filter(|s| !s.is_empty()),
+            message_ts: Some(message_ts.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_permalink_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/chat.meMessage` endpoint.
      *
@@ -86,9 +115,18 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn me_message(&self) -> Result<crate::types::ChatMeMessageSchema> {
+    pub async fn me_message(
+        &self,
+        body: &crate::types::ChatMeMessageRequest,
+    ) -> Result<crate::types::ChatMeMessageSchema> {
         let url = "/chat.meMessage".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -102,9 +140,18 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn post_ephemeral(&self) -> Result<crate::types::ChatPostEphemeralSuccessSchema> {
+    pub async fn post_ephemeral(
+        &self,
+        body: &crate::types::ChatPostEphemeralRequest,
+    ) -> Result<crate::types::ChatPostEphemeralSuccessSchema> {
         let url = "/chat.postEphemeral".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -118,9 +165,18 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn post_message(&self) -> Result<crate::types::ChatPostMessageSuccessSchema> {
+    pub async fn post_message(
+        &self,
+        body: &crate::types::ChatPostMessageRequest,
+    ) -> Result<crate::types::ChatPostMessageSuccessSchema> {
         let url = "/chat.postMessage".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -134,9 +190,18 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn schedule_message(&self) -> Result<crate::types::ChatScheduleMessageSuccessSchema> {
+    pub async fn schedule_message(
+        &self,
+        body: &crate::types::ChatScheduleMessageRequest,
+    ) -> Result<crate::types::ChatScheduleMessageSuccessSchema> {
         let url = "/chat.scheduleMessage".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -150,9 +215,18 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `links:write`.
      */
-    pub async fn unfurl(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn unfurl(
+        &self,
+        body: &crate::types::ChatUnfurlRequest,
+    ) -> Result<crate::types::ChatUnfurlSuccessSchema> {
         let url = "/chat.unfurl".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -166,8 +240,164 @@ impl Chat {
      *
      * * `token: &str` -- Authentication token. Requires scope: `chat:write`.
      */
-    pub async fn update(&self) -> Result<crate::types::ChatUpdateSuccessSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::ChatUpdateRequest,
+    ) -> Result<crate::types::ChatUpdateSuccessSchema> {
         let url = "/chat.update".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Chat::get_permalink_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChatGetPermalinkParams {
+    /// The ID of the conversation or channel containing the message.
+    pub channel: Option<String>,
+    /// A message's `ts` value, uniquely identifying it within a channel.
+    pub message_ts: Option<String>,
+}
+
+impl ChatGetPermalinkParams {
+    pub fn channel<T: ToString>(mut self, channel: T) -> Self {
+        self.channel = Some(channel.to_string());
+        self
+    }
+
+    pub fn message_ts<T: ToString>(mut self, message_ts: T) -> Self {
+        self.message_ts = Some(message_ts.to_string());
+        self
+    }
+}
+
+/// The functions of [`Chat`], for code that takes them as a trait, so that it can be
+/// tested with the `MockChatApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ChatApi: Send + Sync {
+    /// See [`Chat::delete`].
+    async fn delete(
+        &self,
+        body: &crate::types::ChatDeleteRequest,
+    ) -> Result<crate::types::ChatDeleteSuccessSchema>;
+
+    /// See [`Chat::delete_scheduled_message`].
+    async fn delete_scheduled_message(
+        &self,
+        body: &crate::types::ChatDeleteScheduledMessageRequest,
+    ) -> Result<crate::types::ChatDeleteScheduledMessageSchema>;
+
+    /// See [`Chat::get_permalink_with`].
+    async fn get_permalink_with(
+        &self,
+        params: &ChatGetPermalinkParams,
+    ) -> Result<crate::types::ChatGetPermalinkSuccessSchema>;
+
+    /// See [`Chat::me_message`].
+    async fn me_message(
+        &self,
+        body: &crate::types::ChatMeMessageRequest,
+    ) -> Result<crate::types::ChatMeMessageSchema>;
+
+    /// See [`Chat::post_ephemeral`].
+    async fn post_ephemeral(
+        &self,
+        body: &crate::types::ChatPostEphemeralRequest,
+    ) -> Result<crate::types::ChatPostEphemeralSuccessSchema>;
+
+    /// See [`Chat::post_message`].
+    async fn post_message(
+        &self,
+        body: &crate::types::ChatPostMessageRequest,
+    ) -> Result<crate::types::ChatPostMessageSuccessSchema>;
+
+    /// See [`Chat::schedule_message`].
+    async fn schedule_message(
+        &self,
+        body: &crate::types::ChatScheduleMessageRequest,
+    ) -> Result<crate::types::ChatScheduleMessageSuccessSchema>;
+
+    /// See [`Chat::unfurl`].
+    async fn unfurl(
+        &self,
+        body: &crate::types::ChatUnfurlRequest,
+    ) -> Result<crate::types::ChatUnfurlSuccessSchema>;
+
+    /// See [`Chat::update`].
+    async fn update(
+        &self,
+        body: &crate::types::ChatUpdateRequest,
+    ) -> Result<crate::types::ChatUpdateSuccessSchema>;
+}
+
+#[async_trait::async_trait]
+impl ChatApi for Chat {
+    async fn delete(
+        &self,
+        body: &crate::types::ChatDeleteRequest,
+    ) -> Result<crate::types::ChatDeleteSuccessSchema> {
+        Chat::delete(self, body).await
+    }
+
+    async fn delete_scheduled_message(
+        &self,
+        body: &crate::types::ChatDeleteScheduledMessageRequest,
+    ) -> Result<crate::types::ChatDeleteScheduledMessageSchema> {
+        Chat::delete_scheduled_message(self, body).await
+    }
+
+    async fn get_permalink_with(
+        &self,
+        params: &ChatGetPermalinkParams,
+    ) -> Result<crate::types::ChatGetPermalinkSuccessSchema> {
+        Chat::get_permalink_with(self, params).await
+    }
+
+    async fn me_message(
+        &self,
+        body: &crate::types::ChatMeMessageRequest,
+    ) -> Result<crate::types::ChatMeMessageSchema> {
+        Chat::me_message(self, body).await
+    }
+
+    async fn post_ephemeral(
+        &self,
+        body: &crate::types::ChatPostEphemeralRequest,
+    ) -> Result<crate::types::ChatPostEphemeralSuccessSchema> {
+        Chat::post_ephemeral(self, body).await
+    }
+
+    async fn post_message(
+        &self,
+        body: &crate::types::ChatPostMessageRequest,
+    ) -> Result<crate::types::ChatPostMessageSuccessSchema> {
+        Chat::post_message(self, body).await
+    }
+
+    async fn schedule_message(
+        &self,
+        body: &crate::types::ChatScheduleMessageRequest,
+    ) -> Result<crate::types::ChatScheduleMessageSuccessSchema> {
+        Chat::schedule_message(self, body).await
+    }
+
+    async fn unfurl(
+        &self,
+        body: &crate::types::ChatUnfurlRequest,
+    ) -> Result<crate::types::ChatUnfurlSuccessSchema> {
+        Chat::unfurl(self, body).await
+    }
+
+    async fn update(
+        &self,
+        body: &crate::types::ChatUpdateRequest,
+    ) -> Result<crate::types::ChatUpdateSuccessSchema> {
+        Chat::update(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ChatScheduledMessages {
     pub client: Client,
@@ -21,35 +19,26 @@ impl ChatScheduledMessages {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `none`.
-     * * `channel: &str` -- The channel of the scheduled messages.
-     * * `latest: f64` -- A UNIX timestamp of the latest value in the time range.
-     * * `oldest: f64` -- A UNIX timestamp of the oldest value in the time range.
-     * * `limit: i64` -- Maximum number of original entries to return.
-     * * `cursor: &str` -- For pagination purposes, this is the `cursor` value returned from a previous call to `chat.scheduledmessages.list` indicating where you want to start this call from.
+     * * `params: &ChatScheduledMessagesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        channel: &str,
-        latest: f64,
-        oldest: f64,
-        limit: i64,
-        cursor: &str,
+        params: &ChatScheduledMessagesListParams,
     ) -> Result<crate::types::ChatScheduledMessagesListSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel.is_empty() {
+        if let Some(channel) = &params.channel {
             query_args.push(("channel".to_string(), channel.to_string()));
         }
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if !latest.to_string().is_empty() {
+        if let Some(latest) = &params.latest {
             query_args.push(("latest".to_string(), latest.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !oldest.to_string().is_empty() {
+        if let Some(oldest) = &params.oldest {
             query_args.push(("oldest".to_string(), oldest.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -57,4 +46,90 @@ impl ChatScheduledMessages {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `ChatScheduledMessagesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ChatScheduledMessagesListSchema> {
+        let params = ChatScheduledMessagesListParams {
+            channel: Some(channel.to_string()).filter(|s| !s.is_empty()),
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            latest: Some(latest).filter(|v| !v.to_string().is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            oldest: Some(oldest).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_with(&params).await
+    }
+}
+
+/// The query parameters of `ChatScheduledMessages::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChatScheduledMessagesListParams {
+    /// The channel of the scheduled messages.
+    pub channel: Option<String>,
+    /// For pagination purposes, this is the `cursor` value returned from a previous call to `chat.scheduledmessages.list` indicating where you want to start this call from.
+    pub cursor: Option<String>,
+    /// A UNIX timestamp of the latest value in the time range.
+    pub latest: Option<f64>,
+    /// Maximum number of original entries to return.
+    pub limit: Option<i64>,
+    /// A UNIX timestamp of the oldest value in the time range.
+    pub oldest: Option<f64>,
+}
+
+impl ChatScheduledMessagesListParams {
+    pub fn channel<T: ToString>(mut self, channel: T) -> Self {
+        self.channel = Some(channel.to_string());
+        self
+    }
+
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn latest(mut self, latest: f64) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn oldest(mut self, oldest: f64) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+}
+
+/// The functions of [`ChatScheduledMessages`], for code that takes them as a trait, so that it can be
+/// tested with the `MockChatScheduledMessagesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ChatScheduledMessagesApi: Send + Sync {
+    /// See [`ChatScheduledMessages::list_with`].
+    async fn list_with(
+        &self,
+        params: &ChatScheduledMessagesListParams,
+    ) -> Result<crate::types::ChatScheduledMessagesListSchema>;
+}
+
+#[async_trait::async_trait]
+impl ChatScheduledMessagesApi for ChatScheduledMessages {
+    async fn list_with(
+        &self,
+        params: &ChatScheduledMessagesListParams,
+    ) -> Result<crate::types::ChatScheduledMessagesListSchema> {
+        ChatScheduledMessages::list_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Conversations {
     pub client: Client,
@@ -23,9 +21,18 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn archive(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn archive(
+        &self,
+        body: &crate::types::ConversationsArchiveRequest,
+    ) -> Result<crate::types::ConversationsArchiveSuccessSchema> {
         let url = "/conversations.archive".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -39,9 +46,18 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn close(&self) -> Result<crate::types::ConversationsCloseSuccessSchema> {
+    pub async fn close(
+        &self,
+        body: &crate::types::ConversationsCloseRequest,
+    ) -> Result<crate::types::ConversationsCloseSuccessSchema> {
         let url = "/conversations.close".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -55,9 +71,18 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn create(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::ConversationsCreateRequest,
+    ) -> Result<crate::types::ConversationsCreateSuccessSchema> {
         let url = "/conversations.create".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -69,40 +94,29 @@ impl Conversations {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `conversations:history`.
-     * * `channel: &str` -- Conversation ID to fetch history for.
-     * * `latest: f64` -- End of time range of messages to include in results.
-     * * `oldest: f64` -- Start of time range of messages to include in results.
-     * * `inclusive: bool` -- Include messages with latest or oldest timestamp in results only when either timestamp is specified.
-     * * `limit: i64` -- The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
-     * * `cursor: &str` -- Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+     * * `params: &ConversationsHistoryParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn history(
+    pub async fn history_with(
         &self,
-        channel: &str,
-        latest: f64,
-        oldest: f64,
-        inclusive: bool,
-        limit: i64,
-        cursor: &str,
+        params: &ConversationsHistoryParams,
     ) -> Result<crate::types::ConversationsHistorySuccessSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel.is_empty() {
+        if let Some(channel) = &params.channel {
             query_args.push(("channel".to_string(), channel.to_string()));
         }
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if inclusive {
+        if let Some(inclusive) = &params.inclusive {
             query_args.push(("inclusive".to_string(), inclusive.to_string()));
         }
-        if !latest.to_string().is_empty() {
+        if let Some(latest) = &params.latest {
             query_args.push(("latest".to_string(), latest.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !oldest.to_string().is_empty() {
+        if let Some(oldest) = &params.oldest {
             query_args.push(("oldest".to_string(), oldest.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -111,6 +125,30 @@ impl Conversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `history_with` and pass the query parameters as a `ConversationsHistoryParams` instead.
+     */
+    #[deprecated(note = "use `history_with` instead")]
+    pub async fn history(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ConversationsHistorySuccessSchema> {
+        let params = ConversationsHistoryParams {
+            channel: Some(channel.to_string()).filter(|s| !s.is_empty()),
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            inclusive: Some(inclusive).filter(|v| *v),
+            latest: Some(latest).filter(|v| !v.to_string().is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            oldest: Some(oldest).filter(|v| !v.to_string().is_empty()),
+        };
+        self.history_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/conversations.info` endpoint.
      *
@@ -120,25 +158,20 @@ impl Conversations {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `conversations:read`.
-     * * `channel: &str` -- Conversation ID to learn more about.
-     * * `include_locale: bool` -- Set this to `true` to receive the locale for this conversation. Defaults to `false`.
-     * * `include_num_members: bool` -- Set to `true` to include the member count for the specified conversation. Defaults to `false`.
+     * * `params: &ConversationsInfoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn info(
+    pub async fn info_with(
         &self,
-        channel: &str,
-        include_locale: bool,
-        include_num_members: bool,
+        params: &ConversationsInfoParams,
     ) -> Result<crate::types::ConversationsInfoSuccessSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel.is_empty() {
+        if let Some(channel) = &params.channel {
             query_args.push(("channel".to_string(), channel.to_string()));
         }
-        if include_locale {
+        if let Some(include_locale) = &params.include_locale {
             query_args.push(("include_locale".to_string(), include_locale.to_string()));
         }
-        if include_num_members {
+        if let Some(include_num_members) = &params.include_num_members {
             query_args.push((
                 "include_num_members".to_string(),
                 include_num_members.to_string(),
@@ -150,6 +183,24 @@ impl Conversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `info_with` and pass the query parameters as a `ConversationsInfoParams` instead.
+     */
+    #[deprecated(note = "use `info_with` instead")]
+    pub async fn info(
+        &self,
+        channel: &str,
+        include_locale: bool,
+        include_num_members: bool,
+    ) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+        let params = ConversationsInfoParams {
+            channel: Some(channel.to_string()).filter(|s| !s.is_empty()),
+            include_locale: Some(include_locale).filter(|v| *v),
+            include_num_members: Some(include_num_members).filter(|v| *v),
+        };
+        self.info_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/conversations.invite` endpoint.
      *
@@ -161,9 +212,18 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn invite(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn invite(
+        &self,
+        body: &crate::types::ConversationsInviteRequest,
+    ) -> Result<crate::types::ConversationsInviteErrorSchema> {
         let url = "/conversations.invite".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -177,9 +237,18 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `channels:write`.
      */
-    pub async fn join(&self) -> Result<crate::types::ConversationsJoinSuccessSchema> {
+    pub async fn join(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> Result<crate::types::ConversationsJoinSuccessSchema> {
         let url = "/conversations.join".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -193,9 +262,18 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn kick(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn kick(
+        &self,
+        body: &crate::types::ConversationsKickRequest,
+    ) -> Result<crate::types::ConversationsKickSuccessSchema> {
         let url = "/conversations.kick".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -209,9 +287,18 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn leave(&self) -> Result<crate::types::ConversationsLeaveSuccessSchema> {
+    pub async fn leave(
+        &self,
+        body: &crate::types::ConversationsLeaveRequest,
+    ) -> Result<crate::types::ConversationsLeaveSuccessSchema> {
         let url = "/conversations.leave".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -223,30 +310,23 @@ impl Conversations {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `conversations:read`.
-     * * `exclude_archived: bool` -- Set to `true` to exclude archived channels from the list.
-     * * `types: &str` -- Mix and match channel types by providing a comma-separated list of any combination of `public_channel`, `private_channel`, `mpim`, `im`.
-     * * `limit: i64` -- The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached. Must be an integer no larger than 1000.
-     * * `cursor: &str` -- Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+     * * `params: &ConversationsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        exclude_archived: bool,
-        types: &str,
-        limit: i64,
-        cursor: &str,
+        params: &ConversationsListParams,
     ) -> Result<crate::types::ConversationsListSuccessSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if exclude_archived {
+        if let Some(exclude_archived) = &params.exclude_archived {
             query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !types.is_empty() {
+        if let Some(types) = &params.types {
             query_args.push(("types".to_string(), types.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -255,6 +335,26 @@ impl Conversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `ConversationsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        exclude_archived: bool,
+        types: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ConversationsListSuccessSchema> {
+        let params = ConversationsListParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            exclude_archived: Some(exclude_archived).filter(|v| *v),
+            limit: Some(limit).filter(|v| *v > 0),
+            types: Some(types.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/conversations.mark` endpoint.
      *
@@ -266,9 +366,18 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn mark(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn mark(
+        &self,
+        body: &crate::types::ConversationsMarkRequest,
+    ) -> Result<crate::types::ConversationsMarkSuccessSchema> {
         let url = "/conversations.mark".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -280,25 +389,20 @@ impl Conversations {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `conversations:read`.
-     * * `channel: &str` -- ID of the conversation to retrieve members for.
-     * * `limit: i64` -- The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
-     * * `cursor: &str` -- Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+     * * `params: &ConversationsMemberParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn member(
+    pub async fn member_with(
         &self,
-        channel: &str,
-        limit: i64,
-        cursor: &str,
+        params: &ConversationsMemberParams,
     ) -> Result<crate::types::ConversationsMembersSuccessSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel.is_empty() {
+        if let Some(channel) = &params.channel {
             query_args.push(("channel".to_string(), channel.to_string()));
         }
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -307,6 +411,24 @@ impl Conversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `member_with` and pass the query parameters as a `ConversationsMemberParams` instead.
+     */
+    #[deprecated(note = "use `member_with` instead")]
+    pub async fn member(
+        &self,
+        channel: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ConversationsMembersSuccessSchema> {
+        let params = ConversationsMemberParams {
+            channel: Some(channel.to_string()).filter(|s| !s.is_empty()),
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.member_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/conversations.open` endpoint.
      *
@@ -318,9 +440,18 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn open(&self) -> Result<crate::types::ConversationsOpenSuccessSchema> {
+    pub async fn open(
+        &self,
+        body: &crate::types::ConversationsOpenRequest,
+    ) -> Result<crate::types::ConversationsOpenSuccessSchema> {
         let url = "/conversations.open".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -334,9 +465,18 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn rename(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn rename(
+        &self,
+        body: &crate::types::ConversationsRenameRequest,
+    ) -> Result<crate::types::ConversationsRenameSuccessSchema> {
         let url = "/conversations.rename".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -348,45 +488,32 @@ impl Conversations {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `conversations:history`.
-     * * `channel: &str` -- Conversation ID to fetch thread from.
-     * * `ts: f64` -- Unique identifier of a thread's parent message. `ts` must be the timestamp of an existing message with 0 or more replies. If there are no replies then just the single message referenced by `ts` will return - it is just an ordinary, unthreaded message.
-     * * `latest: f64` -- End of time range of messages to include in results.
-     * * `oldest: f64` -- Start of time range of messages to include in results.
-     * * `inclusive: bool` -- Include messages with latest or oldest timestamp in results only when either timestamp is specified.
-     * * `limit: i64` -- The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
-     * * `cursor: &str` -- Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+     * * `params: &ConversationsReplieParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn replie(
+    pub async fn replie_with(
         &self,
-        channel: &str,
-        ts: f64,
-        latest: f64,
-        oldest: f64,
-        inclusive: bool,
-        limit: i64,
-        cursor: &str,
+        params: &ConversationsReplieParams,
     ) -> Result<crate::types::ConversationsRepliesSuccessSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel.is_empty() {
+        if let Some(channel) = &params.channel {
             query_args.push(("channel".to_string(), channel.to_string()));
         }
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if inclusive {
+        if let Some(inclusive) = &params.inclusive {
             query_args.push(("inclusive".to_string(), inclusive.to_string()));
         }
-        if !latest.to_string().is_empty() {
+        if let Some(latest) = &params.latest {
             query_args.push(("latest".to_string(), latest.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !oldest.to_string().is_empty() {
+        if let Some(oldest) = &params.oldest {
             query_args.push(("oldest".to_string(), oldest.to_string()));
         }
-        if !ts.to_string().is_empty() {
+        if let Some(ts) = &params.ts {
             query_args.push(("ts".to_string(), ts.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -395,6 +522,32 @@ impl Conversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `replie_with` and pass the query parameters as a `ConversationsReplieParams` instead.
+     */
+    #[deprecated(note = "use `replie_with` instead")]
+    pub async fn replie(
+        &self,
+        channel: &str,
+        ts: f64,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::ConversationsRepliesSuccessSchema> {
+        let params = ConversationsReplieParams {
+            channel: Some(channel.to_string()).filter(|s| !s.is_empty()),
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            inclusive: Some(inclusive).filter(|v| *v),
+            latest: Some(latest).filter(|v| !v.to_string().is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            oldest: Some(oldest).filter(|v| !v.to_string().is_empty()),
+            ts: Some(ts).filter(|v| !v.to_string().is_empty()),
+        };
+        self.replie_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/conversations.setPurpose` endpoint.
      *
@@ -406,9 +559,18 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn set_purpose(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn set_purpose(
+        &self,
+        body: &crate::types::ConversationsSetPurposeRequest,
+    ) -> Result<crate::types::ConversationsSetPurposeSuccessSchema> {
         let url = "/conversations.setPurpose".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -422,9 +584,18 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn set_topic(&self) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+    pub async fn set_topic(
+        &self,
+        body: &crate::types::ConversationsSetTopicRequest,
+    ) -> Result<crate::types::ConversationsSetTopicSuccessSchema> {
         let url = "/conversations.setTopic".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -438,8 +609,456 @@ impl Conversations {
      *
      * * `token: &str` -- Authentication token. Requires scope: `conversations:write`.
      */
-    pub async fn unarchive(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn unarchive(
+        &self,
+        body: &crate::types::ConversationsUnarchiveRequest,
+    ) -> Result<crate::types::ConversationsUnarchiveSuccessSchema> {
         let url = "/conversations.unarchive".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Conversations::history_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ConversationsHistoryParams {
+    /// Conversation ID to fetch history for.
+    pub channel: Option<String>,
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+    pub cursor: Option<String>,
+    /// Include messages with latest or oldest timestamp in results only when either timestamp is specified.
+    pub inclusive: Option<bool>,
+    /// End of time range of messages to include in results.
+    pub latest: Option<f64>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
+    pub limit: Option<i64>,
+    /// Start of time range of messages to include in results.
+    pub oldest: Option<f64>,
+}
+
+impl ConversationsHistoryParams {
+    pub fn channel<T: ToString>(mut self, channel: T) -> Self {
+        self.channel = Some(channel.to_string());
+        self
+    }
+
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    pub fn latest(mut self, latest: f64) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn oldest(mut self, oldest: f64) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+}
+
+/// The query parameters of `Conversations::info_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ConversationsInfoParams {
+    /// Conversation ID to learn more about.
+    pub channel: Option<String>,
+    /// Set this to `true` to receive the locale for this conversation. Defaults to `false`.
+    pub include_locale: Option<bool>,
+    /// Set to `true` to include the member count for the specified conversation. Defaults to `false`.
+    pub include_num_members: Option<bool>,
+}
+
+impl ConversationsInfoParams {
+    pub fn channel<T: ToString>(mut self, channel: T) -> Self {
+        self.channel = Some(channel.to_string());
+        self
+    }
+
+    pub fn include_locale(mut self, include_locale: bool) -> Self {
+        self.include_locale = Some(include_locale);
+        self
+    }
+
+    pub fn include_num_members(mut self, include_num_members: bool) -> Self {
+        self.include_num_members = Some(include_num_members);
+        self
+    }
+}
+
+/// The query parameters of `Conversations::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ConversationsListParams {
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+    pub cursor: Option<String>,
+    /// Set to `true` to exclude archived channels from the list.
+    pub exclude_archived: Option<bool>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached. Must be an integer no larger than 1000.
+    pub limit: Option<i64>,
+    /// Mix and match channel types by providing a comma-separated list of any combination of `public_channel`, `private_channel`, `mpim`, `im`.
+    pub types: Option<String>,
+}
+
+impl ConversationsListParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.exclude_archived = Some(exclude_archived);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn types<T: ToString>(mut self, types: T) -> Self {
+        self.types = Some(types.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Conversations::member_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ConversationsMemberParams {
+    /// ID of the conversation to retrieve members for.
+    pub channel: Option<String>,
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
+    pub limit: Option<i64>,
+}
+
+impl ConversationsMemberParams {
+    pub fn channel<T: ToString>(mut self, channel: T) -> Self {
+        self.channel = Some(channel.to_string());
+        self
+    }
+
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `Conversations::replie_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ConversationsReplieParams {
+    /// Conversation ID to fetch thread from.
+    pub channel: Option<String>,
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+    pub cursor: Option<String>,
+    /// Include messages with latest or oldest timestamp in results only when either timestamp is specified.
+    pub inclusive: Option<bool>,
+    /// End of time range of messages to include in results.
+    pub latest: Option<f64>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
+    pub limit: Option<i64>,
+    /// Start of time range of messages to include in results.
+    pub oldest: Option<f64>,
+    /// Unique identifier of a thread's parent message. `ts` must be the timestamp of an existing message with 0 or more replies. If there are no replies then just the single message referenced by `ts` will return - it is just an ordinary, unthreaded message.
+    pub ts: Option<f64>,
+}
+
+impl ConversationsReplieParams {
+    pub fn channel<T: ToString>(mut self, channel: T) -> Self {
+        self.channel = Some(channel.to_string());
+        self
+    }
+
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn inclusive(mut self, inclusive: bool) -> Self {
+        self.inclusive = Some(inclusive);
+        self
+    }
+
+    pub fn latest(mut self, latest: f64) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn oldest(mut self, oldest: f64) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+
+    pub fn ts(mut self, ts: f64) -> Self {
+        self.ts = Some(ts);
+        self
+    }
+}
+
+/// The functions of [`Conversations`], for code that takes them as a trait, so that it can be
+/// tested with the `MockConversationsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ConversationsApi: Send + Sync {
+    /// See [`Conversations::archive`].
+    async fn archive(
+        &self,
+        body: &crate::types::ConversationsArchiveRequest,
+    ) -> Result<crate::types::ConversationsArchiveSuccessSchema>;
+
+    /// See [`Conversations::close`].
+    async fn close(
+        &self,
+        body: &crate::types::ConversationsCloseRequest,
+    ) -> Result<crate::types::ConversationsCloseSuccessSchema>;
+
+    /// See [`Conversations::create`].
+    async fn create(
+        &self,
+        body: &crate::types::ConversationsCreateRequest,
+    ) -> Result<crate::types::ConversationsCreateSuccessSchema>;
+
+    /// See [`Conversations::history_with`].
+    async fn history_with(
+        &self,
+        params: &ConversationsHistoryParams,
+    ) -> Result<crate::types::ConversationsHistorySuccessSchema>;
+
+    /// See [`Conversations::info_with`].
+    async fn info_with(
+        &self,
+        params: &ConversationsInfoParams,
+    ) -> Result<crate::types::ConversationsInfoSuccessSchema>;
+
+    /// See [`Conversations::invite`].
+    async fn invite(
+        &self,
+        body: &crate::types::ConversationsInviteRequest,
+    ) -> Result<crate::types::ConversationsInviteErrorSchema>;
+
+    /// See [`Conversations::join`].
+    async fn join(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> Result<crate::types::ConversationsJoinSuccessSchema>;
+
+    /// See [`Conversations::kick`].
+    async fn kick(
+        &self,
+        body: &crate::types::ConversationsKickRequest,
+    ) -> Result<crate::types::ConversationsKickSuccessSchema>;
+
+    /// See [`Conversations::leave`].
+    async fn leave(
+        &self,
+        body: &crate::types::ConversationsLeaveRequest,
+    ) -> Result<crate::types::ConversationsLeaveSuccessSchema>;
+
+    /// See [`Conversations::list_with`].
+    async fn list_with(
+        &self,
+        params: &ConversationsListParams,
+    ) -> Result<crate::types::ConversationsListSuccessSchema>;
+
+    /// See [`Conversations::mark`].
+    async fn mark(
+        &self,
+        body: &crate::types::ConversationsMarkRequest,
+    ) -> Result<crate::types::ConversationsMarkSuccessSchema>;
+
+    /// See [`Conversations::member_with`].
+    async fn member_with(
+        &self,
+        params: &ConversationsMemberParams,
+    ) -> Result<crate::types::ConversationsMembersSuccessSchema>;
+
+    /// See [`Conversations::open`].
+    async fn open(
+        &self,
+        body: &crate::types::ConversationsOpenRequest,
+    ) -> Result<crate::types::ConversationsOpenSuccessSchema>;
+
+    /// See [`Conversations::rename`].
+    async fn rename(
+        &self,
+        body: &crate::types::ConversationsRenameRequest,
+    ) -> Result<crate::types::ConversationsRenameSuccessSchema>;
+
+    /// See [`Conversations::replie_with`].
+    async fn replie_with(
+        &self,
+        params: &ConversationsReplieParams,
+    ) -> Result<crate::types::ConversationsRepliesSuccessSchema>;
+
+    /// See [`Conversations::set_purpose`].
+    async fn set_purpose(
+        &self,
+        body: &crate::types::ConversationsSetPurposeRequest,
+    ) -> Result<crate::types::ConversationsSetPurposeSuccessSchema>;
+
+    /// See [`Conversations::set_topic`].
+    async fn set_topic(
+        &self,
+        body: &crate::types::ConversationsSetTopicRequest,
+    ) -> Result<crate::types::ConversationsSetTopicSuccessSchema>;
+
+    /// See [`Conversations::unarchive`].
+    async fn unarchive(
+        &self,
+        body: &crate::types::ConversationsUnarchiveRequest,
+    ) -> Result<crate::types::ConversationsUnarchiveSuccessSchema>;
+}
+
+#[async_trait::async_trait]
+impl ConversationsApi for Conversations {
+    async fn archive(
+        &self,
+        body: &crate::types::ConversationsArchiveRequest,
+    ) -> Result<crate::types::ConversationsArchiveSuccessSchema> {
+        Conversations::archive(self, body).await
+    }
+
+    async fn close(
+        &self,
+        body: &crate::types::ConversationsCloseRequest,
+    ) -> Result<crate::types::ConversationsCloseSuccessSchema> {
+        Conversations::close(self, body).await
+    }
+
+    async fn create(
+        &self,
+        body: &crate::types::ConversationsCreateRequest,
+    ) -> Result<crate::types::ConversationsCreateSuccessSchema> {
+        Conversations::create(self, body).await
+    }
+
+    async fn history_with(
+        &self,
+        params: &ConversationsHistoryParams,
+    ) -> Result<crate::types::ConversationsHistorySuccessSchema> {
+        Conversations::history_with(self, params).await
+    }
+
+    async fn info_with(
+        &self,
+        params: &ConversationsInfoParams,
+    ) -> Result<crate::types::ConversationsInfoSuccessSchema> {
+        Conversations::info_with(self, params).await
+    }
+
+    async fn invite(
+        &self,
+        body: &crate::types::ConversationsInviteRequest,
+    ) -> Result<crate::types::ConversationsInviteErrorSchema> {
+        Conversations::invite(self, body).await
+    }
+
+    async fn join(
+        &self,
+        body: &crate::types::ConversationsJoinRequest,
+    ) -> Result<crate::types::ConversationsJoinSuccessSchema> {
+        Conversations::join(self, body).await
+    }
+
+    async fn kick(
+        &self,
+        body: &crate::types::ConversationsKickRequest,
+    ) -> Result<crate::types::ConversationsKickSuccessSchema> {
+        Conversations::kick(self, body).await
+    }
+
+    async fn leave(
+        &self,
+        body: &crate::types::ConversationsLeaveRequest,
+    ) -> Result<crate::types::ConversationsLeaveSuccessSchema> {
+        Conversations::leave(self, body).await
+    }
+
+    async fn list_with(
+        &self,
+        params: &ConversationsListParams,
+    ) -> Result<crate::types::ConversationsListSuccessSchema> {
+        Conversations::list_with(self, params).await
+    }
+
+    async fn mark(
+        &self,
+        body: &crate::types::ConversationsMarkRequest,
+    ) -> Result<crate::types::ConversationsMarkSuccessSchema> {
+        Conversations::mark(self, body).await
+    }
+
+    async fn member_with(
+        &self,
+        params: &ConversationsMemberParams,
+    ) -> Result<crate::types::ConversationsMembersSuccessSchema> {
+        Conversations::member_with(self, params).await
+    }
+
+    async fn open(
+        &self,
+        body: &crate::types::ConversationsOpenRequest,
+    ) -> Result<crate::types::ConversationsOpenSuccessSchema> {
+        Conversations::open(self, body).await
+    }
+
+    async fn rename(
+        &self,
+        body: &crate::types::ConversationsRenameRequest,
+    ) -> Result<crate::types::ConversationsRenameSuccessSchema> {
+        Conversations::rename(self, body).await
+    }
+
+    async fn replie_with(
+        &self,
+        params: &ConversationsReplieParams,
+    ) -> Result<crate::types::ConversationsRepliesSuccessSchema> {
+        Conversations::replie_with(self, params).await
+    }
+
+    async fn set_purpose(
+        &self,
+        body: &crate::types::ConversationsSetPurposeRequest,
+    ) -> Result<crate::types::ConversationsSetPurposeSuccessSchema> {
+        Conversations::set_purpose(self, body).await
+    }
+
+    async fn set_topic(
+        &self,
+        body: &crate::types::ConversationsSetTopicRequest,
+    ) -> Result<crate::types::ConversationsSetTopicSuccessSchema> {
+        Conversations::set_topic(self, body).await
+    }
+
+    async fn unarchive(
+        &self,
+        body: &crate::types::ConversationsUnarchiveRequest,
+    ) -> Result<crate::types::ConversationsUnarchiveSuccessSchema> {
+        Conversations::unarchive(self, body).await
     }
 }
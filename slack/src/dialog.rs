@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Dialog {
     pub client: Client,
@@ -21,16 +19,17 @@ impl Dialog {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `none`.
-     * * `dialog: &str` -- The dialog definition. This must be a JSON-encoded string.
-     * * `trigger_id: &str` -- Exchange a trigger to post to the user.
+     * * `params: &DialogOpenParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn open(&self, dialog: &str, trigger_id: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn open_with(
+        &self,
+        params: &DialogOpenParams,
+    ) -> Result<crate::types::DialogOpenSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !dialog.is_empty() {
+        if let Some(dialog) = &params.dialog {
             query_args.push(("dialog".to_string(), dialog.to_string()));
         }
-        if !trigger_id.is_empty() {
+        if let Some(trigger_id) = &params.trigger_id {
             query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -38,4 +37,57 @@ impl Dialog {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `open_with` and pass the query parameters as a `DialogOpenParams` instead.
+     */
+    #[deprecated(note = "use `open_with` instead")]
+    pub async fn open(
+        &self,
+        dialog: &str,
+        trigger_id: &str,
+    ) -> Result<crate::types::DialogOpenSchema> {
+        let params = DialogOpenParams {
+            dialog: Some(dialog.to_string()).filter(|s| !s.is_empty()),
+            trigger_id: Some(trigger_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.open_with(&params).await
+    }
+}
+
+/// The query parameters of `Dialog::open_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DialogOpenParams {
+    /// The dialog definition. This must be a JSON-encoded string.
+    pub dialog: Option<String>,
+    /// Exchange a trigger to post to the user.
+    pub trigger_id: Option<String>,
+}
+
+impl DialogOpenParams {
+    pub fn dialog<T: ToString>(mut self, dialog: T) -> Self {
+        self.dialog = Some(dialog.to_string());
+        self
+    }
+
+    pub fn trigger_id<T: ToString>(mut self, trigger_id: T) -> Self {
+        self.trigger_id = Some(trigger_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`Dialog`], for code that takes them as a trait, so that it can be
+/// tested with the `MockDialogApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait DialogApi: Send + Sync {
+    /// See [`Dialog::open_with`].
+    async fn open_with(&self, params: &DialogOpenParams) -> Result<crate::types::DialogOpenSchema>;
+}
+
+#[async_trait::async_trait]
+impl DialogApi for Dialog {
+    async fn open_with(&self, params: &DialogOpenParams) -> Result<crate::types::DialogOpenSchema> {
+        Dialog::open_with(self, params).await
+    }
 }
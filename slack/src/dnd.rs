@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Dnd {
     pub client: Client,
@@ -53,12 +51,11 @@ impl Dnd {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `dnd:read`.
-     * * `user: &str` -- User to fetch status for (defaults to current user).
+     * * `params: &DndInfoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn info(&self, user: &str) -> Result<crate::types::DndInfoSchema> {
+    pub async fn info_with(&self, params: &DndInfoParams) -> Result<crate::types::DndInfoSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !user.is_empty() {
+        if let Some(user) = &params.user {
             query_args.push(("user".to_string(), user.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -67,6 +64,17 @@ impl Dnd {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `info_with` and pass the query parameters as a `DndInfoParams` instead.
+     */
+    #[deprecated(note = "use `info_with` instead")]
+    pub async fn info(&self, user: &str) -> Result<crate::types::DndInfoSchema> {
+        let params = DndInfoParams {
+            user: Some(user.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.info_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/dnd.setSnooze` endpoint.
      *
@@ -74,9 +82,18 @@ impl Dnd {
      *
      * FROM: <https://api.slack.com/methods/dnd.setSnooze>
      */
-    pub async fn set_snooze(&self) -> Result<crate::types::DndSetSnoozeSchema> {
+    pub async fn set_snooze(
+        &self,
+        body: &crate::types::DndSetSnoozeRequest,
+    ) -> Result<crate::types::DndSetSnoozeSchema> {
         let url = "/dnd.setSnooze".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -88,12 +105,14 @@ impl Dnd {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `dnd:read`.
-     * * `users: &str` -- Comma-separated list of users to fetch Do Not Disturb status for.
+     * * `params: &DndTeamInfoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn team_info(&self, users: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn team_info_with(
+        &self,
+        params: &DndTeamInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !users.is_empty() {
+        if let Some(users) = &params.users {
             query_args.push(("users".to_string(), users.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -101,4 +120,99 @@ impl Dnd {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `team_info_with` and pass the query parameters as a `DndTeamInfoParams` instead.
+     */
+    #[deprecated(note = "use `team_info_with` instead")]
+    pub async fn team_info(&self, users: &str) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = DndTeamInfoParams {
+            users: Some(users.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.team_info_with(&params).await
+    }
+}
+
+/// The query parameters of `Dnd::info_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DndInfoParams {
+    /// User to fetch status for (defaults to current user).
+    pub user: Option<String>,
+}
+
+impl DndInfoParams {
+    pub fn user<T: ToString>(mut self, user: T) -> Self {
+        self.user = Some(user.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Dnd::team_info_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DndTeamInfoParams {
+    /// Comma-separated list of users to fetch Do Not Disturb status for.
+    pub users: Option<String>,
+}
+
+impl DndTeamInfoParams {
+    pub fn users<T: ToString>(mut self, users: T) -> Self {
+        self.users = Some(users.to_string());
+        self
+    }
+}
+
+/// The functions of [`Dnd`], for code that takes them as a trait, so that it can be
+/// tested with the `MockDndApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait DndApi: Send + Sync {
+    /// See [`Dnd::end`].
+    async fn end(&self) -> Result<crate::types::DndEndSchema>;
+
+    /// See [`Dnd::end_snooze`].
+    async fn end_snooze(&self) -> Result<crate::types::DndEndSnoozeSchema>;
+
+    /// See [`Dnd::info_with`].
+    async fn info_with(&self, params: &DndInfoParams) -> Result<crate::types::DndInfoSchema>;
+
+    /// See [`Dnd::set_snooze`].
+    async fn set_snooze(
+        &self,
+        body: &crate::types::DndSetSnoozeRequest,
+    ) -> Result<crate::types::DndSetSnoozeSchema>;
+
+    /// See [`Dnd::team_info_with`].
+    async fn team_info_with(
+        &self,
+        params: &DndTeamInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl DndApi for Dnd {
+    async fn end(&self) -> Result<crate::types::DndEndSchema> {
+        Dnd::end(self).await
+    }
+
+    async fn end_snooze(&self) -> Result<crate::types::DndEndSnoozeSchema> {
+        Dnd::end_snooze(self).await
+    }
+
+    async fn info_with(&self, params: &DndInfoParams) -> Result<crate::types::DndInfoSchema> {
+        Dnd::info_with(self, params).await
+    }
+
+    async fn set_snooze(
+        &self,
+        body: &crate::types::DndSetSnoozeRequest,
+    ) -> Result<crate::types::DndSetSnoozeSchema> {
+        Dnd::set_snooze(self, body).await
+    }
+
+    async fn team_info_with(
+        &self,
+        params: &DndTeamInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Dnd::team_info_with(self, params).await
+    }
 }
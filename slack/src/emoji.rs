@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Emoji {
     pub client: Client,
@@ -23,8 +21,24 @@ impl Emoji {
      *
      * * `token: &str` -- Authentication token. Requires scope: `emoji:read`.
      */
-    pub async fn list(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn list(&self) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/emoji.list".to_string();
         self.client.get(&url, None).await
     }
 }
+
+/// The functions of [`Emoji`], for code that takes them as a trait, so that it can be
+/// tested with the `MockEmojiApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait EmojiApi: Send + Sync {
+    /// See [`Emoji::list`].
+    async fn list(&self) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl EmojiApi for Emoji {
+    async fn list(&self) -> Result<crate::types::DefaultSuccessTemplate> {
+        Emoji::list(self).await
+    }
+}
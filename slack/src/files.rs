@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Files {
     pub client: Client,
@@ -23,9 +21,18 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> Result<crate::types::FilesDeleteSchema> {
         let url = "/files.delete".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -37,35 +44,26 @@ impl Files {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `files:read`.
-     * * `file: &str` -- Specify a file by providing its ID.
-     * * `count: &str`
-     * * `page: &str`
-     * * `limit: i64` -- The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
-     * * `cursor: &str` -- Parameter for pagination. File comments are paginated for a single file. Set `cursor` equal to the `next_cursor` attribute returned by the previous request's `response_metadata`. This parameter is optional, but pagination is mandatory: the default value simply fetches the first "page" of the collection of comments. See [pagination](/docs/pagination) for more details.
+     * * `params: &FilesInfoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn info(
+    pub async fn info_with(
         &self,
-        file: &str,
-        count: &str,
-        page: &str,
-        limit: i64,
-        cursor: &str,
+        params: &FilesInfoParams,
     ) -> Result<crate::types::FilesInfoSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if !file.is_empty() {
+        if let Some(file) = &params.file {
             query_args.push(("file".to_string(), file.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !page.is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -74,6 +72,28 @@ impl Files {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `info_with` and pass the query parameters as a `FilesInfoParams` instead.
+     */
+    #[deprecated(note = "use `info_with` instead")]
+    pub async fn info(
+        &self,
+        file: &str,
+        count: &str,
+        page: &str,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::FilesInfoSchema> {
+        let params = FilesInfoParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            file: Some(file.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            page: Some(page.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.info_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/files.list` endpoint.
      *
@@ -83,53 +103,38 @@ impl Files {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `files:read`.
-     * * `user: &str` -- Filter files created by a single user.
-     * * `channel: &str` -- Filter files appearing in a specific channel, indicated by its ID.
-     * * `ts_from: f64` -- Filter files created after this timestamp (inclusive).
-     * * `ts_to: f64` -- Filter files created before this timestamp (inclusive).
-     * * `types: &str` -- Filter files by type ([see below](#file_types)). You can pass multiple values in the types argument, like `types=spaces,snippets`.The default value is `all`, which does not filter the list.
-     * * `count: &str`
-     * * `page: &str`
-     * * `show_files_hidden_by_limit: bool` -- Show truncated file info for files hidden due to being too old, and the team who owns the file being over the file limit.
+     * * `params: &FilesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        user: &str,
-        channel: &str,
-        ts_from: f64,
-        ts_to: f64,
-        types: &str,
-        count: &str,
-        page: &str,
-        show_files_hidden_by_limit: bool,
+        params: &FilesListParams,
     ) -> Result<crate::types::FilesListSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel.is_empty() {
+        if let Some(channel) = &params.channel {
             query_args.push(("channel".to_string(), channel.to_string()));
         }
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !page.is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if show_files_hidden_by_limit {
+        if let Some(show_files_hidden_by_limit) = &params.show_files_hidden_by_limit {
             query_args.push((
                 "show_files_hidden_by_limit".to_string(),
                 show_files_hidden_by_limit.to_string(),
             ));
         }
-        if !ts_from.to_string().is_empty() {
+        if let Some(ts_from) = &params.ts_from {
             query_args.push(("ts_from".to_string(), ts_from.to_string()));
         }
-        if !ts_to.to_string().is_empty() {
+        if let Some(ts_to) = &params.ts_to {
             query_args.push(("ts_to".to_string(), ts_to.to_string()));
         }
-        if !types.is_empty() {
+        if let Some(types) = &params.types {
             query_args.push(("types".to_string(), types.to_string()));
         }
-        if !user.is_empty() {
+        if let Some(user) = &params.user {
             query_args.push(("user".to_string(), user.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -138,6 +143,34 @@ impl Files {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `FilesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        user: &str,
+        channel: &str,
+        ts_from: f64,
+        ts_to: f64,
+        types: &str,
+        count: &str,
+        page: &str,
+        show_files_hidden_by_limit: bool,
+    ) -> Result<crate::types::FilesListSchema> {
+        let params = FilesListParams {
+            channel: Some(channel.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page.to_string()).filter(|s| !s.is_empty()),
+            show_files_hidden_by_limit: Some(show_files_hidden_by_limit).filter(|v| *v),
+            ts_from: Some(ts_from).filter(|v| !v.to_string().is_empty()),
+            ts_to: Some(ts_to).filter(|v| !v.to_string().is_empty()),
+            types: Some(types.to_string()).filter(|s| !s.is_empty()),
+            user: Some(user.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/files.revokePublicURL` endpoint.
      *
@@ -149,9 +182,18 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn revoke_public_url(&self) -> Result<crate::types::FilesUploadSchema> {
+    pub async fn revoke_public_url(
+        &self,
+        body: &crate::types::FilesRevokePublicUrlRequest,
+    ) -> Result<crate::types::FilesRevokePublicUrlSchema> {
         let url = "/files.revokePublicURL".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -165,9 +207,18 @@ impl Files {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn shared_public_url(&self) -> Result<crate::types::FilesUploadSchema> {
+    pub async fn shared_public_url(
+        &self,
+        body: &crate::types::FilesSharedPublicUrlRequest,
+    ) -> Result<crate::types::FilesSharedPublicUrlSchema> {
         let url = "/files.sharedPublicURL".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -177,8 +228,193 @@ impl Files {
      *
      * FROM: <https://api.slack.com/methods/files.upload>
      */
-    pub async fn upload(&self) -> Result<crate::types::FilesUploadSchema> {
+    pub async fn upload(
+        &self,
+        body: &crate::types::FilesUploadRequest,
+    ) -> Result<crate::types::FilesUploadSchema> {
         let url = "/files.upload".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Files::info_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesInfoParams {
+    pub count: Option<String>,
+    /// Parameter for pagination. File comments are paginated for a single file. Set `cursor` equal to the `next_cursor` attribute returned by the previous request's `response_metadata`. This parameter is optional, but pagination is mandatory: the default value simply fetches the first "page" of the collection of comments. See [pagination](/docs/pagination) for more details.
+    pub cursor: Option<String>,
+    /// Specify a file by providing its ID.
+    pub file: Option<String>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<i64>,
+    pub page: Option<String>,
+}
+
+impl FilesInfoParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn file<T: ToString>(mut self, file: T) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page<T: ToString>(mut self, page: T) -> Self {
+        self.page = Some(page.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Files::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesListParams {
+    /// Filter files appearing in a specific channel, indicated by its ID.
+    pub channel: Option<String>,
+    pub count: Option<String>,
+    pub page: Option<String>,
+    /// Show truncated file info for files hidden due to being too old, and the team who owns the file being over the file limit.
+    pub show_files_hidden_by_limit: Option<bool>,
+    /// Filter files created after this timestamp (inclusive).
+    pub ts_from: Option<f64>,
+    /// Filter files created before this timestamp (inclusive).
+    pub ts_to: Option<f64>,
+    /// Filter files by type ([see below](#file_types)). You can pass multiple values in the types argument, like `types=spaces,snippets`.The default value is `all`, which does not filter the list.
+    pub types: Option<String>,
+    /// Filter files created by a single user.
+    pub user: Option<String>,
+}
+
+impl FilesListParams {
+    pub fn channel<T: ToString>(mut self, channel: T) -> Self {
+        self.channel = Some(channel.to_string());
+        self
+    }
+
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn page<T: ToString>(mut self, page: T) -> Self {
+        self.page = Some(page.to_string());
+        self
+    }
+
+    pub fn show_files_hidden_by_limit(mut self, show_files_hidden_by_limit: bool) -> Self {
+        self.show_files_hidden_by_limit = Some(show_files_hidden_by_limit);
+        self
+    }
+
+    pub fn ts_from(mut self, ts_from: f64) -> Self {
+        self.ts_from = Some(ts_from);
+        self
+    }
+
+    pub fn ts_to(mut self, ts_to: f64) -> Self {
+        self.ts_to = Some(ts_to);
+        self
+    }
+
+    pub fn types<T: ToString>(mut self, types: T) -> Self {
+        self.types = Some(types.to_string());
+        self
+    }
+
+    pub fn user<T: ToString>(mut self, user: T) -> Self {
+        self.user = Some(user.to_string());
+        self
+    }
+}
+
+/// The functions of [`Files`], for code that takes them as a trait, so that it can be
+/// tested with the `MockFilesApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait FilesApi: Send + Sync {
+    /// See [`Files::delete`].
+    async fn delete(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> Result<crate::types::FilesDeleteSchema>;
+
+    /// See [`Files::info_with`].
+    async fn info_with(&self, params: &FilesInfoParams) -> Result<crate::types::FilesInfoSchema>;
+
+    /// See [`Files::list_with`].
+    async fn list_with(&self, params: &FilesListParams) -> Result<crate::types::FilesListSchema>;
+
+    /// See [`Files::revoke_public_url`].
+    async fn revoke_public_url(
+        &self,
+        body: &crate::types::FilesRevokePublicUrlRequest,
+    ) -> Result<crate::types::FilesRevokePublicUrlSchema>;
+
+    /// See [`Files::shared_public_url`].
+    async fn shared_public_url(
+        &self,
+        body: &crate::types::FilesSharedPublicUrlRequest,
+    ) -> Result<crate::types::FilesSharedPublicUrlSchema>;
+
+    /// See [`Files::upload`].
+    async fn upload(
+        &self,
+        body: &crate::types::FilesUploadRequest,
+    ) -> Result<crate::types::FilesUploadSchema>;
+}
+
+#[async_trait::async_trait]
+impl FilesApi for Files {
+    async fn delete(
+        &self,
+        body: &crate::types::FilesDeleteRequest,
+    ) -> Result<crate::types::FilesDeleteSchema> {
+        Files::delete(self, body).await
+    }
+
+    async fn info_with(&self, params: &FilesInfoParams) -> Result<crate::types::FilesInfoSchema> {
+        Files::info_with(self, params).await
+    }
+
+    async fn list_with(&self, params: &FilesListParams) -> Result<crate::types::FilesListSchema> {
+        Files::list_with(self, params).await
+    }
+
+    async fn revoke_public_url(
+        &self,
+        body: &crate::types::FilesRevokePublicUrlRequest,
+    ) -> Result<crate::types::FilesRevokePublicUrlSchema> {
+        Files::revoke_public_url(self, body).await
+    }
+
+    async fn shared_public_url(
+        &self,
+        body: &crate::types::FilesSharedPublicUrlRequest,
+    ) -> Result<crate::types::FilesSharedPublicUrlSchema> {
+        Files::shared_public_url(self, body).await
+    }
+
+    async fn upload(
+        &self,
+        body: &crate::types::FilesUploadRequest,
+    ) -> Result<crate::types::FilesUploadSchema> {
+        Files::upload(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct FilesComments {
     pub client: Client,
@@ -23,8 +21,39 @@ impl FilesComments {
      *
      * * `token: &str` -- Authentication token. Requires scope: `files:write:user`.
      */
-    pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::FilesCommentsDeleteRequest,
+    ) -> Result<crate::types::FilesCommentsDeleteSchema> {
         let url = "/files.comments.delete".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The functions of [`FilesComments`], for code that takes them as a trait, so that it can be
+/// tested with the `MockFilesCommentsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait FilesCommentsApi: Send + Sync {
+    /// See [`FilesComments::delete`].
+    async fn delete(
+        &self,
+        body: &crate::types::FilesCommentsDeleteRequest,
+    ) -> Result<crate::types::FilesCommentsDeleteSchema>;
+}
+
+#[async_trait::async_trait]
+impl FilesCommentsApi for FilesComments {
+    async fn delete(
+        &self,
+        body: &crate::types::FilesCommentsDeleteRequest,
+    ) -> Result<crate::types::FilesCommentsDeleteSchema> {
+        FilesComments::delete(self, body).await
     }
 }
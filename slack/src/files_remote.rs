@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct FilesRemote {
     pub client: Client,
@@ -19,9 +17,18 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.add>
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::FilesRemoteAddRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/files.remote.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -33,16 +40,17 @@ impl FilesRemote {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `remote_files:read`.
-     * * `file: &str` -- Specify a file by providing its ID.
-     * * `external_id: &str` -- Creator defined GUID for the file.
+     * * `params: &FilesRemoteInfoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn info(&self, file: &str, external_id: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn info_with(
+        &self,
+        params: &FilesRemoteInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !external_id.is_empty() {
+        if let Some(external_id) = &params.external_id {
             query_args.push(("external_id".to_string(), external_id.to_string()));
         }
-        if !file.is_empty() {
+        if let Some(file) = &params.file {
             query_args.push(("file".to_string(), file.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -51,6 +59,22 @@ impl FilesRemote {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `info_with` and pass the query parameters as a `FilesRemoteInfoParams` instead.
+     */
+    #[deprecated(note = "use `info_with` instead")]
+    pub async fn info(
+        &self,
+        file: &str,
+        external_id: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = FilesRemoteInfoParams {
+            external_id: Some(external_id.to_string()).filter(|s| !s.is_empty()),
+            file: Some(file.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.info_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/files.remote.list` endpoint.
      *
@@ -60,35 +84,26 @@ impl FilesRemote {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `remote_files:read`.
-     * * `channel: &str` -- Filter files appearing in a specific channel, indicated by its ID.
-     * * `ts_from: f64` -- Filter files created after this timestamp (inclusive).
-     * * `ts_to: f64` -- Filter files created before this timestamp (inclusive).
-     * * `limit: i64` -- The maximum number of items to return.
-     * * `cursor: &str` -- Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+     * * `params: &FilesRemoteListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        channel: &str,
-        ts_from: f64,
-        ts_to: f64,
-        limit: i64,
-        cursor: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &FilesRemoteListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel.is_empty() {
+        if let Some(channel) = &params.channel {
             query_args.push(("channel".to_string(), channel.to_string()));
         }
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !ts_from.to_string().is_empty() {
+        if let Some(ts_from) = &params.ts_from {
             query_args.push(("ts_from".to_string(), ts_from.to_string()));
         }
-        if !ts_to.to_string().is_empty() {
+        if let Some(ts_to) = &params.ts_to {
             query_args.push(("ts_to".to_string(), ts_to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -97,6 +112,28 @@ impl FilesRemote {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `FilesRemoteListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        channel: &str,
+        ts_from: f64,
+        ts_to: f64,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = FilesRemoteListParams {
+            channel: Some(channel.to_string()).filter(|s| !s.is_empty()),
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            ts_from: Some(ts_from).filter(|v| !v.to_string().is_empty()),
+            ts_to: Some(ts_to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/files.remote.remove` endpoint.
      *
@@ -104,9 +141,18 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.remove>
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::FilesRemoteRemoveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/files.remote.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -118,25 +164,20 @@ impl FilesRemote {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `remote_files:share`.
-     * * `file: &str` -- Specify a file registered with Slack by providing its ID. Either this field or `external_id` or both are required.
-     * * `external_id: &str` -- The globally unique identifier (GUID) for the file, as set by the app registering the file with Slack.  Either this field or `file` or both are required.
-     * * `channels: &str` -- Comma-separated list of channel IDs where the file will be shared.
+     * * `params: &FilesRemoteShareParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn share(
+    pub async fn share_with(
         &self,
-        file: &str,
-        external_id: &str,
-        channels: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &FilesRemoteShareParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channels.is_empty() {
+        if let Some(channels) = &params.channels {
             query_args.push(("channels".to_string(), channels.to_string()));
         }
-        if !external_id.is_empty() {
+        if let Some(external_id) = &params.external_id {
             query_args.push(("external_id".to_string(), external_id.to_string()));
         }
-        if !file.is_empty() {
+        if let Some(file) = &params.file {
             query_args.push(("file".to_string(), file.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -145,6 +186,24 @@ impl FilesRemote {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `share_with` and pass the query parameters as a `FilesRemoteShareParams` instead.
+     */
+    #[deprecated(note = "use `share_with` instead")]
+    pub async fn share(
+        &self,
+        file: &str,
+        external_id: &str,
+        channels: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = FilesRemoteShareParams {
+            channels: Some(channels.to_string()).filter(|s| !s.is_empty()),
+            external_id: Some(external_id.to_string()).filter(|s| !s.is_empty()),
+            file: Some(file.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.share_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/files.remote.update` endpoint.
      *
@@ -152,8 +211,195 @@ impl FilesRemote {
      *
      * FROM: <https://api.slack.com/methods/files.remote.update>
      */
-    pub async fn update(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::FilesRemoteUpdateRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let url = "/files.remote.update".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `FilesRemote::info_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesRemoteInfoParams {
+    /// Creator defined GUID for the file.
+    pub external_id: Option<String>,
+    /// Specify a file by providing its ID.
+    pub file: Option<String>,
+}
+
+impl FilesRemoteInfoParams {
+    pub fn external_id<T: ToString>(mut self, external_id: T) -> Self {
+        self.external_id = Some(external_id.to_string());
+        self
+    }
+
+    pub fn file<T: ToString>(mut self, file: T) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+/// The query parameters of `FilesRemote::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesRemoteListParams {
+    /// Filter files appearing in a specific channel, indicated by its ID.
+    pub channel: Option<String>,
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return.
+    pub limit: Option<i64>,
+    /// Filter files created after this timestamp (inclusive).
+    pub ts_from: Option<f64>,
+    /// Filter files created before this timestamp (inclusive).
+    pub ts_to: Option<f64>,
+}
+
+impl FilesRemoteListParams {
+    pub fn channel<T: ToString>(mut self, channel: T) -> Self {
+        self.channel = Some(channel.to_string());
+        self
+    }
+
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn ts_from(mut self, ts_from: f64) -> Self {
+        self.ts_from = Some(ts_from);
+        self
+    }
+
+    pub fn ts_to(mut self, ts_to: f64) -> Self {
+        self.ts_to = Some(ts_to);
+        self
+    }
+}
+
+/// The query parameters of `FilesRemote::share_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct FilesRemoteShareParams {
+    /// Comma-separated list of channel IDs where the file will be shared.
+    pub channels: Option<String>,
+    /// The globally unique identifier (GUID) for the file, as set by the app registering the file with Slack.  Either this field or `file` or both are required.
+    pub external_id: Option<String>,
+    /// Specify a file registered with Slack by providing its ID. Either this field or `external_id` or both are required.
+    pub file: Option<String>,
+}
+
+impl FilesRemoteShareParams {
+    pub fn channels<T: ToString>(mut self, channels: T) -> Self {
+        self.channels = Some(channels.to_string());
+        self
+    }
+
+    pub fn external_id<T: ToString>(mut self, external_id: T) -> Self {
+        self.external_id = Some(external_id.to_string());
+        self
+    }
+
+    pub fn file<T: ToString>(mut self, file: T) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+/// The functions of [`FilesRemote`], for code that takes them as a trait, so that it can be
+/// tested with the `MockFilesRemoteApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait FilesRemoteApi: Send + Sync {
+    /// See [`FilesRemote::add`].
+    async fn add(
+        &self,
+        body: &crate::types::FilesRemoteAddRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`FilesRemote::info_with`].
+    async fn info_with(
+        &self,
+        params: &FilesRemoteInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`FilesRemote::list_with`].
+    async fn list_with(
+        &self,
+        params: &FilesRemoteListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`FilesRemote::remove`].
+    async fn remove(
+        &self,
+        body: &crate::types::FilesRemoteRemoveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`FilesRemote::share_with`].
+    async fn share_with(
+        &self,
+        params: &FilesRemoteShareParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`FilesRemote::update`].
+    async fn update(
+        &self,
+        body: &crate::types::FilesRemoteUpdateRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl FilesRemoteApi for FilesRemote {
+    async fn add(
+        &self,
+        body: &crate::types::FilesRemoteAddRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        FilesRemote::add(self, body).await
+    }
+
+    async fn info_with(
+        &self,
+        params: &FilesRemoteInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        FilesRemote::info_with(self, params).await
+    }
+
+    async fn list_with(
+        &self,
+        params: &FilesRemoteListParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        FilesRemote::list_with(self, params).await
+    }
+
+    async fn remove(
+        &self,
+        body: &crate::types::FilesRemoteRemoveRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        FilesRemote::remove(self, body).await
+    }
+
+    async fn share_with(
+        &self,
+        params: &FilesRemoteShareParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        FilesRemote::share_with(self, params).await
+    }
+
+    async fn update(
+        &self,
+        body: &crate::types::FilesRemoteUpdateRequest,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        FilesRemote::update(self, body).await
     }
 }
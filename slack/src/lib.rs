@@ -24,6 +24,30 @@
 //! up to date as features are added. The documentation for the crate is generated
 //! along with the code to make this library easy to use.
 //!
+//! Each tag of the API is a cargo feature of its own, all of them enabled by the
+//! default `full` feature. To build only the tags you use, turn off the default
+//! features and enable those tags, along with `rustls-tls` or `native-tls`.
+//!
+//! The functions of each tag are also a trait, named after the tag, like `UsersApi`
+//! for `users`. Code that takes the trait can be tested with the mocks of the `mock`
+//! feature, like `MockUsersApi`, rather than a server.
+//!
+//! Requests wait out the rate limits of the API. Those that are safe to send twice
+//! are sent again after the `Retry-After` of rate limited responses, and all of them
+//! wait for the limit to reset when none of it is left. `Client::rate_limit_status` tells what the API last said about its
+//! limits.
+//!
+//! Requests that time out, can't connect or get a `5xx` that may not last are
+//! retried, waiting longer before each attempt, but only those that are safe to send
+//! twice: `GET`s, `HEAD`s, `OPTIONS` and `TRACE`s. `Client::builder().retry_policy(...)`
+//! changes how many times, how long to wait and which methods. For the APIs that take
+//! idempotency keys, the `POST`s of `Client::with_idempotency_key` are retried too.
+//!
+//! `Client::builder()` also sets the timeouts, proxies, root certificates, client
+//! certificate and `User-Agent` of the HTTP client, or takes a
+//! `reqwest_middleware::ClientWithMiddleware` of your own. Its `build` takes what
+//! `Client::new` does.
+//!
 //!
 //! To install the library, add the following to your `Cargo.toml` file.
 //!
@@ -91,75 +115,221 @@
 //!     access_token = slack.refresh_access_token().await.unwrap();
 //! }
 //! ```
+//!
+//! With `set_auto_access_token_refresh(true)`, the client refreshes the access token
+//! before requests once it expires. With `set_refresh_on_unauthorized(true)`, it also
+//! does when a request is rejected with `401 Unauthorized` because of the access
+//! token, and sends the request again. The clones of a client that need a refresh at
+//! the same time wait for a single one.
+//!
+//! ## Keeping tokens
+//!
+//! The client only has its tokens in memory, and some providers give a new refresh
+//! token with each refresh, which makes the old one useless. To keep the tokens across
+//! restarts, give the client a `TokenStore`, like a file. It starts with the tokens
+//! saved there, if any, and saves the new ones it gets.
+//!
+//! ```
+//! use slack_chat_api::{token_store::FileTokenStore, Client};
+//!
+//! async fn do_call() {
+//!     let slack = Client::new(
+//!         String::from("client-id"),
+//!         String::from("client-secret"),
+//!         String::from("redirect-uri"),
+//!         String::from("token"),
+//!         String::from("refresh-token"),
+//!     )
+//!     .with_token_store(FileTokenStore::new("tokens.json"))
+//!     .await
+//!     .unwrap();
+//! }
+//! ```
 #![allow(clippy::too_many_arguments)]
 #![allow(clippy::nonstandard_macro_braces)]
 #![allow(clippy::large_enum_variant)]
+#![allow(clippy::result_large_err)]
 #![allow(clippy::tabs_in_doc_comments)]
 #![allow(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+#[cfg(feature = "admin_apps")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_apps")))]
 pub mod admin_apps;
+#[cfg(feature = "admin_apps_approved")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_apps_approved")))]
 pub mod admin_apps_approved;
+#[cfg(feature = "admin_apps_requests")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_apps_requests")))]
 pub mod admin_apps_requests;
+#[cfg(feature = "admin_apps_restricted")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_apps_restricted")))]
 pub mod admin_apps_restricted;
+#[cfg(feature = "admin_conversations")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_conversations")))]
 pub mod admin_conversations;
+#[cfg(feature = "admin_conversations_ekm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_conversations_ekm")))]
 pub mod admin_conversations_ekm;
+#[cfg(feature = "admin_conversations_restrict_access")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_conversations_restrict_access")))]
 pub mod admin_conversations_restrict_access;
+#[cfg(feature = "admin_emoji")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_emoji")))]
 pub mod admin_emoji;
+#[cfg(feature = "admin_invite_requests")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_invite_requests")))]
 pub mod admin_invite_requests;
+#[cfg(feature = "admin_invite_requests_approved")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_invite_requests_approved")))]
 pub mod admin_invite_requests_approved;
+#[cfg(feature = "admin_invite_requests_denied")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_invite_requests_denied")))]
 pub mod admin_invite_requests_denied;
+#[cfg(feature = "admin_teams")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_teams")))]
 pub mod admin_teams;
+#[cfg(feature = "admin_teams_admins")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_teams_admins")))]
 pub mod admin_teams_admins;
+#[cfg(feature = "admin_teams_owners")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_teams_owners")))]
 pub mod admin_teams_owners;
+#[cfg(feature = "admin_teams_settings")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_teams_settings")))]
 pub mod admin_teams_settings;
+#[cfg(feature = "admin_usergroups")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_usergroups")))]
 pub mod admin_usergroups;
+#[cfg(feature = "admin_users")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_users")))]
 pub mod admin_users;
+#[cfg(feature = "admin_users_session")]
+#[cfg_attr(docsrs, doc(cfg(feature = "admin_users_session")))]
 pub mod admin_users_session;
+#[cfg(feature = "api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "api")))]
 pub mod api;
+#[cfg(feature = "apps")]
+#[cfg_attr(docsrs, doc(cfg(feature = "apps")))]
 pub mod apps;
+#[cfg(feature = "apps_event_authorizations")]
+#[cfg_attr(docsrs, doc(cfg(feature = "apps_event_authorizations")))]
 pub mod apps_event_authorizations;
+#[cfg(feature = "apps_permissions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "apps_permissions")))]
 pub mod apps_permissions;
+#[cfg(feature = "apps_permissions_resources")]
+#[cfg_attr(docsrs, doc(cfg(feature = "apps_permissions_resources")))]
 pub mod apps_permissions_resources;
+#[cfg(feature = "apps_permissions_scopes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "apps_permissions_scopes")))]
 pub mod apps_permissions_scopes;
+#[cfg(feature = "apps_permissions_users")]
+#[cfg_attr(docsrs, doc(cfg(feature = "apps_permissions_users")))]
 pub mod apps_permissions_users;
+#[cfg(feature = "auth")]
+#[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
 pub mod auth;
+#[cfg(feature = "bots")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bots")))]
 pub mod bots;
+#[cfg(feature = "calls")]
+#[cfg_attr(docsrs, doc(cfg(feature = "calls")))]
 pub mod calls;
+#[cfg(feature = "calls_participants")]
+#[cfg_attr(docsrs, doc(cfg(feature = "calls_participants")))]
 pub mod calls_participants;
+#[cfg(feature = "chat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chat")))]
 pub mod chat;
+#[cfg(feature = "chat_scheduled_messages")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chat_scheduled_messages")))]
 pub mod chat_scheduled_messages;
+#[cfg(feature = "conversations")]
+#[cfg_attr(docsrs, doc(cfg(feature = "conversations")))]
 pub mod conversations;
+#[cfg(feature = "dialog")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dialog")))]
 pub mod dialog;
+#[cfg(feature = "dnd")]
+#[cfg_attr(docsrs, doc(cfg(feature = "dnd")))]
 pub mod dnd;
+#[cfg(feature = "emoji")]
+#[cfg_attr(docsrs, doc(cfg(feature = "emoji")))]
 pub mod emoji;
+mod error;
+#[cfg(feature = "files")]
+#[cfg_attr(docsrs, doc(cfg(feature = "files")))]
 pub mod files;
+#[cfg(feature = "files_comments")]
+#[cfg_attr(docsrs, doc(cfg(feature = "files_comments")))]
 pub mod files_comments;
+#[cfg(feature = "files_remote")]
+#[cfg_attr(docsrs, doc(cfg(feature = "files_remote")))]
 pub mod files_remote;
+#[cfg(feature = "migration")]
+#[cfg_attr(docsrs, doc(cfg(feature = "migration")))]
 pub mod migration;
+#[cfg(feature = "oauth")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oauth")))]
 pub mod oauth;
+#[cfg(feature = "oauth_v_2")]
+#[cfg_attr(docsrs, doc(cfg(feature = "oauth_v_2")))]
 pub mod oauth_v_2;
+pub mod pagination;
+#[cfg(feature = "pins")]
+#[cfg_attr(docsrs, doc(cfg(feature = "pins")))]
 pub mod pins;
+pub mod rate_limiter;
+#[cfg(feature = "reactions")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reactions")))]
 pub mod reactions;
+#[cfg(feature = "reminders")]
+#[cfg_attr(docsrs, doc(cfg(feature = "reminders")))]
 pub mod reminders;
+pub mod retry;
+#[cfg(feature = "rtm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rtm")))]
 pub mod rtm;
+#[cfg(feature = "search")]
+#[cfg_attr(docsrs, doc(cfg(feature = "search")))]
 pub mod search;
+#[cfg(feature = "stars")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stars")))]
 pub mod stars;
+#[cfg(feature = "team")]
+#[cfg_attr(docsrs, doc(cfg(feature = "team")))]
 pub mod team;
+#[cfg(feature = "team_profile")]
+#[cfg_attr(docsrs, doc(cfg(feature = "team_profile")))]
 pub mod team_profile;
 #[cfg(test)]
 mod tests;
+pub mod token_store;
 pub mod types;
+#[cfg(feature = "usergroups")]
+#[cfg_attr(docsrs, doc(cfg(feature = "usergroups")))]
 pub mod usergroups;
+#[cfg(feature = "usergroups_users")]
+#[cfg_attr(docsrs, doc(cfg(feature = "usergroups_users")))]
 pub mod usergroups_users;
+#[cfg(feature = "users")]
+#[cfg_attr(docsrs, doc(cfg(feature = "users")))]
 pub mod users;
+#[cfg(feature = "users_profile")]
+#[cfg_attr(docsrs, doc(cfg(feature = "users_profile")))]
 pub mod users_profile;
 #[doc(hidden)]
 pub mod utils;
+#[cfg(feature = "views")]
+#[cfg_attr(docsrs, doc(cfg(feature = "views")))]
 pub mod views;
+#[cfg(feature = "workflows")]
+#[cfg_attr(docsrs, doc(cfg(feature = "workflows")))]
 pub mod workflows;
 
-use anyhow::{anyhow, Error, Result};
+pub use error::{Error, ErrorBody, Result};
 
 pub const DEFAULT_HOST: &str = "https://slack.com/api";
 
@@ -183,6 +353,176 @@ mod progenitor_support {
     }
 }
 
+/// The body of a response that is not JSON, such as a PDF, an image or an archive.
+#[derive(Debug, Clone, Default)]
+pub struct Download {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: bytes::Bytes,
+}
+
+/// The body of a response that is not JSON, returned as a stream of chunks rather
+/// than read into memory all at once.
+pub struct DownloadStream {
+    /// The `Content-Type` the server returned the body with.
+    pub content_type: String,
+    pub body: std::pin::Pin<Box<dyn futures::Stream<Item = Result<bytes::Bytes>> + Send>>,
+}
+
+impl std::fmt::Debug for DownloadStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DownloadStream")
+            .field("content_type", &self.content_type)
+            .finish()
+    }
+}
+
+/// Configures the HTTP client of a `Client`, with the defaults of `Client::new` unless
+/// told otherwise. Its `build` takes what `Client::new` does.
+#[derive(Debug, Clone, Default)]
+pub struct ClientBuilder {
+    retry_policy: crate::retry::RetryPolicy,
+    max_rate_limit_wait: Option<std::time::Duration>,
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    proxies: Vec<reqwest::Proxy>,
+    user_agent: Option<String>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    root_certificates: Vec<reqwest::Certificate>,
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    identity: Option<reqwest::Identity>,
+    http_client: Option<reqwest_middleware::ClientWithMiddleware>,
+}
+
+impl ClientBuilder {
+    /// Set how failed requests are retried.
+    pub fn retry_policy(mut self, retry_policy: crate::retry::RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set how long requests wait for a rate limit to reset, at most,
+    /// `rate_limiter::MAX_WAIT` unless set.
+    pub fn max_rate_limit_wait(mut self, max_wait: std::time::Duration) -> Self {
+        self.max_rate_limit_wait = Some(max_wait);
+        self
+    }
+
+    /// Set how long each attempt at a request can take, from connecting to reading the
+    /// whole response. There is no limit unless set.
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set how long connecting to the API can take.
+    pub fn connect_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Send requests through a proxy, like `reqwest::Proxy::https(url)`. Without one,
+    /// the proxies of the `HTTP_PROXY` and `HTTPS_PROXY` environment variables are used.
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Set the `User-Agent` of requests.
+    pub fn user_agent<U>(mut self, user_agent: U) -> Self
+    where
+        U: ToString,
+    {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Trust the certificates signed by a certificate authority, along with those of
+    /// the system, like a private one of `reqwest::Certificate::from_pem(pem)`.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Authenticate to the API with a client certificate, for mutual TLS.
+    #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+    pub fn identity(mut self, identity: reqwest::Identity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    /// Send requests with a client of your own, with its own middleware. The other
+    /// settings of the builder don't apply to it, and it only retries or waits out
+    /// rate limits if it has a `retry::Retry` or a `rate_limiter::RateLimiter` of its
+    /// own.
+    pub fn http_client(mut self, client: reqwest_middleware::ClientWithMiddleware) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    /*
+     * The HTTP client, along with the rate limiter in its middleware.
+     */
+    fn http(
+        self,
+    ) -> Result<(
+        reqwest_middleware::ClientWithMiddleware,
+        crate::rate_limiter::RateLimiter,
+    )> {
+        if let Some(client) = self.http_client {
+            return Ok((client, Default::default()));
+        }
+
+        let mut rate_limiter =
+            crate::rate_limiter::RateLimiter::new().with_retry_policy(self.retry_policy.clone());
+        if let Some(max_wait) = self.max_rate_limit_wait {
+            rate_limiter = rate_limiter.with_max_wait(max_wait);
+        }
+
+        let mut http = reqwest::Client::builder();
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        if let Some(timeout) = self.connect_timeout {
+            http = http.connect_timeout(timeout);
+        }
+        for proxy in self.proxies {
+            http = http.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            http = http.user_agent(user_agent);
+        }
+        #[cfg(any(feature = "rustls-tls", feature = "native-tls"))]
+        {
+            for certificate in self.root_certificates {
+                http = http.add_root_certificate(certificate);
+            }
+            if let Some(identity) = self.identity {
+                http = http.identity(identity);
+            }
+        }
+
+        let client = reqwest_middleware::ClientBuilder::new(http.build()?)
+            // Trace HTTP requests. See the tracing crate to make use of these traces.
+            .with(reqwest_tracing::TracingMiddleware::default())
+            // Retry failed requests.
+            .with(crate::retry::Retry::new(self.retry_policy))
+            // Wait out the rate limits of the API, before the retries see them.
+            .with(rate_limiter.clone())
+            .build();
+
+        Ok((client, rate_limiter))
+    }
+}
+
+impl Client {
+    /// Configure the client before creating it.
+    pub fn builder() -> ClientBuilder {
+        Default::default()
+    }
+}
+
 use std::{
     convert::TryInto,
     env,
@@ -206,7 +546,12 @@ pub struct Client {
     redirect_uri: String,
 
     auto_refresh: bool,
+    refresh_on_unauthorized: bool,
+    // The last refresh, which the others wait for while it is in flight.
+    refreshing: Arc<tokio::sync::Mutex<Option<AccessToken>>>,
+    token_store: Arc<dyn crate::token_store::TokenStore>,
     client: reqwest_middleware::ClientWithMiddleware,
+    rate_limiter: crate::rate_limiter::RateLimiter,
 }
 
 use schemars::JsonSchema;
@@ -247,6 +592,32 @@ pub struct AccessToken {
     pub scope: String,
 }
 
+/// The error code of the API for an invalid access token, as the JSON pointer of where
+/// it is in the body of a `401 Unauthorized` and its value.
+const INVALID_TOKEN_CODE: Option<(&str, &str)> = None;
+
+/// Whether the `WWW-Authenticate` header of a `401 Unauthorized` says the access token
+/// is invalid, or expired.
+fn is_invalid_token(headers: &reqwest::header::HeaderMap) -> bool {
+    headers
+        .get_all(http::header::WWW_AUTHENTICATE)
+        .iter()
+        .filter_map(|h| h.to_str().ok())
+        .any(|h| h.contains("invalid_token"))
+}
+
+fn has_error_code(body: &[u8], (pointer, code): (&str, &str)) -> bool {
+    match serde_json::from_slice::<serde_json::Value>(body)
+        .ok()
+        .as_ref()
+        .and_then(|v| v.pointer(pointer))
+    {
+        Some(serde_json::Value::String(s)) => s == code,
+        Some(serde_json::Value::Number(n)) => n.to_string() == code,
+        _ => false,
+    }
+}
+
 /// Time in seconds before the access token expiration point that a refresh should
 /// be performed. This value is subtracted from the `expires_in` value returned by
 /// the provider prior to storing
@@ -259,6 +630,72 @@ struct InnerToken {
     expires_at: Option<Instant>,
 }
 
+impl From<&InnerToken> for crate::token_store::StoredToken {
+    fn from(t: &InnerToken) -> Self {
+        crate::token_store::StoredToken {
+            access_token: t.access_token.clone(),
+            refresh_token: t.refresh_token.clone(),
+            expires_at: t.expires_at.map(|i| {
+                let left = i.saturating_duration_since(Instant::now());
+                chrono::Utc::now()
+                    + chrono::Duration::from_std(left).unwrap_or_else(|_| chrono::Duration::zero())
+            }),
+        }
+    }
+}
+
+impl From<crate::token_store::StoredToken> for InnerToken {
+    fn from(t: crate::token_store::StoredToken) -> Self {
+        InnerToken {
+            access_token: t.access_token,
+            refresh_token: t.refresh_token,
+            expires_at: t
+                .expires_at
+                .map(|e| Instant::now() + (e - chrono::Utc::now()).to_std().unwrap_or_default()),
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Create the client, see `Client::new`.
+    pub fn build<I, K, R, T, Q>(
+        self,
+        client_id: I,
+        client_secret: K,
+        redirect_uri: R,
+        token: T,
+        refresh_token: Q,
+    ) -> Result<Client>
+    where
+        I: ToString,
+        K: ToString,
+        R: ToString,
+        T: ToString,
+        Q: ToString,
+    {
+        let (client, rate_limiter) = self.http()?;
+
+        Ok(Client {
+            host: DEFAULT_HOST.to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            redirect_uri: redirect_uri.to_string(),
+            token: Arc::new(RwLock::new(InnerToken {
+                access_token: token.to_string(),
+                refresh_token: refresh_token.to_string(),
+                expires_at: None,
+            })),
+
+            auto_refresh: false,
+            refresh_on_unauthorized: false,
+            refreshing: Default::default(),
+            token_store: Arc::new(crate::token_store::MemoryTokenStore::new()),
+            client,
+            rate_limiter,
+        })
+    }
+}
+
 impl Client {
     /// Create a new Client struct. It takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -277,37 +714,8 @@ impl Client {
         T: ToString,
         Q: ToString,
     {
-        // Retry up to 3 times with increasing intervals between attempts.
-        let retry_policy =
-            reqwest_retry::policies::ExponentialBackoff::builder().build_with_max_retries(3);
-        let client = reqwest::Client::builder().build();
-        match client {
-            Ok(c) => {
-                let client = reqwest_middleware::ClientBuilder::new(c)
-                    // Trace HTTP requests. See the tracing crate to make use of these traces.
-                    .with(reqwest_tracing::TracingMiddleware::default())
-                    // Retry failed requests.
-                    .with(reqwest_conditional_middleware::ConditionalMiddleware::new(
-                        reqwest_retry::RetryTransientMiddleware::new_with_policy(retry_policy),
-                        |req: &reqwest::Request| req.try_clone().is_some(),
-                    ))
-                    .build();
-
-                Client {
-                    host: DEFAULT_HOST.to_string(),
-                    client_id: client_id.to_string(),
-                    client_secret: client_secret.to_string(),
-                    redirect_uri: redirect_uri.to_string(),
-                    token: Arc::new(RwLock::new(InnerToken {
-                        access_token: token.to_string(),
-                        refresh_token: refresh_token.to_string(),
-                        expires_at: None,
-                    })),
-
-                    auto_refresh: false,
-                    client,
-                }
-            }
+        match Self::builder().build(client_id, client_secret, redirect_uri, token, refresh_token) {
+            Ok(c) => c,
             Err(e) => panic!("creating reqwest client failed: {:?}", e),
         }
     }
@@ -318,6 +726,14 @@ impl Client {
         self
     }
 
+    /// Enables or disables refreshing the access token when a request is rejected with
+    /// `401 Unauthorized` because of it, like when its expiration is unknown, and sending
+    /// the request again with the new one. Requests with a streamed body aren't.
+    pub fn set_refresh_on_unauthorized(&mut self, enabled: bool) -> &mut Self {
+        self.refresh_on_unauthorized = enabled;
+        self
+    }
+
     /// Sets a specific `Instant` at which the access token should be considered expired.
     /// The expiration value will only be used when automatic access token refreshing is
     /// also enabled. `None` may be passed in if the expiration is unknown. In this case
@@ -382,6 +798,28 @@ impl Client {
         c
     }
 
+    /// Keep the tokens of the client in a store. The tokens saved there, if any, replace
+    /// those the client was created with, and new tokens are saved to it.
+    pub async fn with_token_store<S>(mut self, store: S) -> Result<Self>
+    where
+        S: crate::token_store::TokenStore + 'static,
+    {
+        if let Some(t) = store.load().await? {
+            self.token = Arc::new(RwLock::new(t.into()));
+        }
+        self.token_store = Arc::new(store);
+        Ok(self)
+    }
+
+    /*
+     * Save the tokens of the client to its store.
+     */
+    async fn save_token(&self) -> Result<crate::token_store::StoredToken> {
+        let token = crate::token_store::StoredToken::from(&*self.token.read().await);
+        self.token_store.save(&token).await?;
+        Ok(token)
+    }
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -419,13 +857,37 @@ impl Client {
     }
 
     /// Refresh an access token from a refresh token. Client must have a refresh token
-    /// for this to work.
+    /// for this to work. The clones of a client refresh once when they do at the same
+    /// time, those that wait for another refresh get its token.
     pub async fn refresh_access_token(&self) -> Result<AccessToken> {
+        let stale = self.token.read().await.access_token.clone();
+        self.refresh_access_token_once(&stale).await
+    }
+
+    /*
+     * Refresh the `stale` access token, unless another refresh replaced it while this one
+     * waited for it.
+     */
+    async fn refresh_access_token_once(&self, stale: &str) -> Result<AccessToken> {
+        let mut last = self.refreshing.lock().await;
+        if let Some(t) = last.as_ref() {
+            let current = &self.token.read().await.access_token;
+            if current != stale && *current == t.access_token {
+                return Ok(t.clone());
+            }
+        }
+
+        let t = self.request_refreshed_access_token().await?;
+        *last = Some(t.clone());
+        Ok(t)
+    }
+
+    async fn request_refreshed_access_token(&self) -> Result<AccessToken> {
         let response = {
             let refresh_token = &self.token.read().await.refresh_token;
 
             if refresh_token.is_empty() {
-                anyhow!("refresh token cannot be empty");
+                return Err(Error::Auth("refresh token cannot be empty".to_string()));
             }
 
             let mut headers = reqwest::header::HeaderMap::new();
@@ -451,10 +913,21 @@ impl Client {
                 .await?
         };
 
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await?;
+            return Err(Error::Auth(format!("code: {}, error: {:?}", status, body)));
+        }
+
         // Unwrap the response.
         let t: AccessToken = response.json().await?;
 
-        let refresh_token = self.token.read().await.refresh_token.clone();
+        // Providers that rotate refresh tokens send a new one, the old one no longer works.
+        let refresh_token = if t.refresh_token.is_empty() {
+            self.token.read().await.refresh_token.clone()
+        } else {
+            t.refresh_token.clone()
+        };
 
         *self.token.write().await = InnerToken {
             access_token: t.access_token.clone(),
@@ -462,6 +935,9 @@ impl Client {
             expires_at: Self::compute_expires_at(t.expires_in),
         };
 
+        let token = self.save_token().await?;
+        self.token_store.on_refresh(&token).await;
+
         Ok(t)
     }
 
@@ -491,6 +967,12 @@ impl Client {
             .send()
             .await?;
 
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await?;
+            return Err(Error::Auth(format!("code: {}, error: {:?}", status, body)));
+        }
+
         // Unwrap the response.
         let t: AccessToken = resp.json().await?;
 
@@ -500,6 +982,8 @@ impl Client {
             expires_at: Self::compute_expires_at(t.expires_in),
         };
 
+        self.save_token().await?;
+
         Ok(t)
     }
 
@@ -515,6 +999,8 @@ impl Client {
         method: &reqwest::Method,
         uri: &str,
         body: Option<reqwest::Body>,
+        accept: &str,
+        content_type: &str,
     ) -> Result<reqwest::Request> {
         let u = if uri.starts_with("https://") {
             uri.to_string()
@@ -530,11 +1016,11 @@ impl Client {
         // Set the default headers.
         req = req.header(
             reqwest::header::ACCEPT,
-            reqwest::header::HeaderValue::from_static("application/json"),
+            reqwest::header::HeaderValue::from_str(accept)?,
         );
         req = req.header(
             reqwest::header::CONTENT_TYPE,
-            reqwest::header::HeaderValue::from_static("application/json"),
+            reqwest::header::HeaderValue::from_str(content_type)?,
         );
 
         if let Some(auth_str) = auth {
@@ -558,6 +1044,18 @@ impl Client {
         method: reqwest::Method,
         uri: &str,
         body: Option<reqwest::Body>,
+    ) -> Result<reqwest::Response> {
+        self.request_raw_with_media_types(method, uri, body, "application/json", "application/json")
+            .await
+    }
+
+    async fn request_raw_with_media_types(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+        accept: &str,
+        content_type: &str,
     ) -> Result<reqwest::Response> {
         if self.auto_refresh {
             let expired = self.is_expired().await;
@@ -566,7 +1064,8 @@ impl Client {
                 // We have a known expired token, we know we need to perform a refresh prior to
                 // attempting to make a request
                 Some(true) => {
-                    self.refresh_access_token().await?;
+                    let stale = self.token.read().await.access_token.clone();
+                    self.refresh_access_token_once(&stale).await?;
                 }
 
                 // We have a (theoretically) known good token available. We make an optimistic
@@ -586,10 +1085,61 @@ impl Client {
             }
         }
 
-        let req = self.make_request(&method, uri, body).await?;
+        let stale = self.token.read().await.access_token.clone();
+        let req = self
+            .make_request(&method, uri, body, accept, content_type)
+            .await?;
+        let replay = if self.refresh_on_unauthorized {
+            req.try_clone()
+        } else {
+            None
+        };
         let resp = self.client.execute(req).await?;
 
-        Ok(resp)
+        let mut replay = match replay {
+            Some(replay) if resp.status() == http::StatusCode::UNAUTHORIZED => replay,
+            _ => return Ok(resp),
+        };
+
+        if !is_invalid_token(resp.headers()) {
+            // The API may only say so in the body, which we have to read to find out.
+            let code = match INVALID_TOKEN_CODE {
+                Some(code) => code,
+                None => return Ok(resp),
+            };
+
+            use reqwest::ResponseBuilderExt;
+            let mut builder = http::Response::builder()
+                .status(resp.status())
+                .version(resp.version())
+                .url(resp.url().clone());
+            if let Some(headers) = builder.headers_mut() {
+                *headers = resp.headers().clone();
+            }
+            let body = resp.bytes().await?;
+            if !has_error_code(&body, code) {
+                // Hand on the response as it was.
+                return builder
+                    .body(body)
+                    .map(reqwest::Response::from)
+                    .map_err(|e| Error::InvalidRequest(e.to_string()));
+            }
+        }
+
+        self.refresh_access_token_once(&stale).await?;
+        let (_, auth) = self.url_and_auth(replay.url().as_str()).await?;
+        if let Some(auth_str) = auth {
+            replay.headers_mut().insert(
+                http::header::AUTHORIZATION,
+                reqwest::header::HeaderValue::from_str(&auth_str)?,
+            );
+        }
+        Ok(self.client.execute(replay).await?)
+    }
+
+    /// The status of the rate limits of the API, as of the last responses.
+    pub fn rate_limit_status(&self) -> Vec<crate::rate_limiter::RateLimitStatus> {
+        self.rate_limiter.status()
     }
 
     async fn request<Out>(
@@ -604,6 +1154,7 @@ impl Client {
         let response = self.request_raw(method, uri, body).await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -612,26 +1163,58 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    /* TODO: make this more DRY */
+    #[allow(dead_code)]
+    async fn request_form<Out>(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        form: String,
+    ) -> Result<Out>
+    where
+        Out: serde::de::DeserializeOwned + 'static + Send,
+    {
+        let response = self
+            .request_raw_with_media_types(
+                method,
+                uri,
+                Some(form.into()),
+                "application/json",
+                "application/x-www-form-urlencoded",
+            )
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        let response_body = response.bytes().await?;
 
-            Err(error)
+        if status.is_success() {
+            log::debug!(
+                "response payload {}",
+                String::from_utf8_lossy(&response_body)
+            );
+            if status == http::StatusCode::NO_CONTENT
+                || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
+            {
+                crate::error::decode(b"null")
+            } else {
+                crate::error::decode::<Out>(&response_body)
+            }
+        } else {
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -647,6 +1230,7 @@ impl Client {
         let response = self.request_raw(method, uri, body).await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
         let link = response
             .headers()
             .get(http::header::LINK)
@@ -664,22 +1248,13 @@ impl Client {
             let parsed_response = if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
+                crate::error::decode::<Out>(&response_body)
             };
-            parsed_response.map(|out| (link, out)).map_err(Error::from)
+            parsed_response.map(|out| (link, out))
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -716,6 +1291,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -724,31 +1300,19 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {
                 // Parse the output as a string.
-                serde_json::from_value(serde_json::json!(&String::from_utf8(
-                    response_body.to_vec()
-                )?))
+                serde_json::from_value(serde_json::json!(String::from_utf8_lossy(&response_body)))
+                    .map_err(Error::from)
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -787,6 +1351,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -795,31 +1360,19 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else if std::any::TypeId::of::<Out>() == std::any::TypeId::of::<String>() {
                 // Parse the output as a string.
-                serde_json::from_value(serde_json::json!(&String::from_utf8(
-                    response_body.to_vec()
-                )?))
+                serde_json::from_value(serde_json::json!(String::from_utf8_lossy(&response_body)))
+                    .map_err(Error::from)
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
-
-            Err(error)
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -879,6 +1432,7 @@ impl Client {
         let response = req.send().await?;
 
         let status = response.status();
+        let headers = response.headers().clone();
 
         let response_body = response.bytes().await?;
 
@@ -887,26 +1441,82 @@ impl Client {
                 "response payload {}",
                 String::from_utf8_lossy(&response_body)
             );
-            let parsed_response = if status == http::StatusCode::NO_CONTENT
+            if status == http::StatusCode::NO_CONTENT
                 || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>()
             {
-                serde_json::from_str("null")
+                crate::error::decode(b"null")
             } else {
-                serde_json::from_slice::<Out>(&response_body)
-            };
-            parsed_response.map_err(Error::from)
+                crate::error::decode::<Out>(&response_body)
+            }
         } else {
-            let error = if response_body.is_empty() {
-                anyhow!("code: {}, empty response", status)
-            } else {
-                anyhow!(
-                    "code: {}, error: {:?}",
-                    status,
-                    String::from_utf8_lossy(&response_body),
-                )
-            };
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    #[allow(dead_code)]
+    async fn request_download(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::Download> {
+        let response = self
+            .request_raw_with_media_types(method, uri, body, "*/*", "application/json")
+            .await?;
 
-            Err(error)
+        let status = response.status();
+        let headers = response.headers().clone();
+        let content_type = response
+            .headers()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|c| c.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+
+        let response_body = response.bytes().await?;
+
+        if status.is_success() {
+            Ok(crate::Download {
+                content_type,
+                body: response_body,
+            })
+        } else {
+            Err(crate::Error::from_response(status, headers, &response_body))
+        }
+    }
+
+    #[allow(dead_code)]
+    async fn request_download_stream(
+        &self,
+        method: reqwest::Method,
+        uri: &str,
+        body: Option<reqwest::Body>,
+    ) -> Result<crate::DownloadStream> {
+        let response = self
+            .request_raw_with_media_types(method, uri, body, "*/*", "application/json")
+            .await?;
+
+        let status = response.status();
+        let headers = response.headers().clone();
+
+        if status.is_success() {
+            let content_type = response
+                .headers()
+                .get(http::header::CONTENT_TYPE)
+                .and_then(|c| c.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+
+            Ok(crate::DownloadStream {
+                content_type,
+                body: Box::pin(futures::TryStreamExt::map_err(
+                    response.bytes_stream(),
+                    Error::from,
+                )),
+            })
+        } else {
+            let response_body = response.bytes().await?;
+            Err(crate::Error::from_response(status, headers, &response_body))
         }
     }
 
@@ -1025,230 +1635,340 @@ impl Client {
         .await
     }
 
+    #[cfg(feature = "admin_apps")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_apps")))]
     pub fn admin_apps(&self) -> admin_apps::AdminApps {
         admin_apps::AdminApps::new(self.clone())
     }
 
+    #[cfg(feature = "admin_apps_approved")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_apps_approved")))]
     pub fn admin_apps_approved(&self) -> admin_apps_approved::AdminAppsApproved {
         admin_apps_approved::AdminAppsApproved::new(self.clone())
     }
 
+    #[cfg(feature = "admin_apps_requests")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_apps_requests")))]
     pub fn admin_apps_requests(&self) -> admin_apps_requests::AdminAppsRequests {
         admin_apps_requests::AdminAppsRequests::new(self.clone())
     }
 
+    #[cfg(feature = "admin_apps_restricted")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_apps_restricted")))]
     pub fn admin_apps_restricted(&self) -> admin_apps_restricted::AdminAppsRestricted {
         admin_apps_restricted::AdminAppsRestricted::new(self.clone())
     }
 
+    #[cfg(feature = "admin_conversations")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_conversations")))]
     pub fn admin_conversations(&self) -> admin_conversations::AdminConversations {
         admin_conversations::AdminConversations::new(self.clone())
     }
 
+    #[cfg(feature = "admin_conversations_ekm")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_conversations_ekm")))]
     pub fn admin_conversations_ekm(&self) -> admin_conversations_ekm::AdminConversationsEkm {
         admin_conversations_ekm::AdminConversationsEkm::new(self.clone())
     }
 
+    #[cfg(feature = "admin_conversations_restrict_access")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_conversations_restrict_access")))]
     pub fn admin_conversations_restrict_access(
         &self,
     ) -> admin_conversations_restrict_access::AdminConversationsRestrictAccess {
         admin_conversations_restrict_access::AdminConversationsRestrictAccess::new(self.clone())
     }
 
+    #[cfg(feature = "admin_emoji")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_emoji")))]
     pub fn admin_emoji(&self) -> admin_emoji::AdminEmoji {
         admin_emoji::AdminEmoji::new(self.clone())
     }
 
+    #[cfg(feature = "admin_invite_requests")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_invite_requests")))]
     pub fn admin_invite_requests(&self) -> admin_invite_requests::AdminInviteRequests {
         admin_invite_requests::AdminInviteRequests::new(self.clone())
     }
 
+    #[cfg(feature = "admin_invite_requests_approved")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_invite_requests_approved")))]
     pub fn admin_invite_requests_approved(
         &self,
     ) -> admin_invite_requests_approved::AdminInviteRequestsApproved {
         admin_invite_requests_approved::AdminInviteRequestsApproved::new(self.clone())
     }
 
+    #[cfg(feature = "admin_invite_requests_denied")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_invite_requests_denied")))]
     pub fn admin_invite_requests_denied(
         &self,
     ) -> admin_invite_requests_denied::AdminInviteRequestsDenied {
         admin_invite_requests_denied::AdminInviteRequestsDenied::new(self.clone())
     }
 
+    #[cfg(feature = "admin_teams")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_teams")))]
     pub fn admin_teams(&self) -> admin_teams::AdminTeams {
         admin_teams::AdminTeams::new(self.clone())
     }
 
+    #[cfg(feature = "admin_teams_admins")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_teams_admins")))]
     pub fn admin_teams_admins(&self) -> admin_teams_admins::AdminTeamsAdmins {
         admin_teams_admins::AdminTeamsAdmins::new(self.clone())
     }
 
+    #[cfg(feature = "admin_teams_owners")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_teams_owners")))]
     pub fn admin_teams_owners(&self) -> admin_teams_owners::AdminTeamsOwners {
         admin_teams_owners::AdminTeamsOwners::new(self.clone())
     }
 
+    #[cfg(feature = "admin_teams_settings")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_teams_settings")))]
     pub fn admin_teams_settings(&self) -> admin_teams_settings::AdminTeamsSettings {
         admin_teams_settings::AdminTeamsSettings::new(self.clone())
     }
 
+    #[cfg(feature = "admin_usergroups")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_usergroups")))]
     pub fn admin_usergroups(&self) -> admin_usergroups::AdminUsergroups {
         admin_usergroups::AdminUsergroups::new(self.clone())
     }
 
+    #[cfg(feature = "admin_users")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_users")))]
     pub fn admin_users(&self) -> admin_users::AdminUsers {
         admin_users::AdminUsers::new(self.clone())
     }
 
+    #[cfg(feature = "admin_users_session")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "admin_users_session")))]
     pub fn admin_users_session(&self) -> admin_users_session::AdminUsersSession {
         admin_users_session::AdminUsersSession::new(self.clone())
     }
 
+    #[cfg(feature = "api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "api")))]
     pub fn api(&self) -> api::Api {
         api::Api::new(self.clone())
     }
 
+    #[cfg(feature = "apps")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "apps")))]
     pub fn apps(&self) -> apps::Apps {
         apps::Apps::new(self.clone())
     }
 
+    #[cfg(feature = "apps_event_authorizations")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "apps_event_authorizations")))]
     pub fn apps_event_authorizations(&self) -> apps_event_authorizations::AppsEventAuthorizations {
         apps_event_authorizations::AppsEventAuthorizations::new(self.clone())
     }
 
+    #[cfg(feature = "apps_permissions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "apps_permissions")))]
     pub fn apps_permissions(&self) -> apps_permissions::AppsPermissions {
         apps_permissions::AppsPermissions::new(self.clone())
     }
 
+    #[cfg(feature = "apps_permissions_resources")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "apps_permissions_resources")))]
     pub fn apps_permissions_resources(
         &self,
     ) -> apps_permissions_resources::AppsPermissionsResources {
         apps_permissions_resources::AppsPermissionsResources::new(self.clone())
     }
 
+    #[cfg(feature = "apps_permissions_scopes")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "apps_permissions_scopes")))]
     pub fn apps_permissions_scopes(&self) -> apps_permissions_scopes::AppsPermissionsScopes {
         apps_permissions_scopes::AppsPermissionsScopes::new(self.clone())
     }
 
+    #[cfg(feature = "apps_permissions_users")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "apps_permissions_users")))]
     pub fn apps_permissions_users(&self) -> apps_permissions_users::AppsPermissionsUsers {
         apps_permissions_users::AppsPermissionsUsers::new(self.clone())
     }
 
+    #[cfg(feature = "auth")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "auth")))]
     pub fn auth(&self) -> auth::Auth {
         auth::Auth::new(self.clone())
     }
 
+    #[cfg(feature = "bots")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bots")))]
     pub fn bots(&self) -> bots::Bots {
         bots::Bots::new(self.clone())
     }
 
+    #[cfg(feature = "calls")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "calls")))]
     pub fn calls(&self) -> calls::Calls {
         calls::Calls::new(self.clone())
     }
 
+    #[cfg(feature = "calls_participants")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "calls_participants")))]
     pub fn calls_participants(&self) -> calls_participants::CallsParticipants {
         calls_participants::CallsParticipants::new(self.clone())
     }
 
+    #[cfg(feature = "chat")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chat")))]
     pub fn chat(&self) -> chat::Chat {
         chat::Chat::new(self.clone())
     }
 
+    #[cfg(feature = "chat_scheduled_messages")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "chat_scheduled_messages")))]
     pub fn chat_scheduled_messages(&self) -> chat_scheduled_messages::ChatScheduledMessages {
         chat_scheduled_messages::ChatScheduledMessages::new(self.clone())
     }
 
+    #[cfg(feature = "conversations")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "conversations")))]
     pub fn conversations(&self) -> conversations::Conversations {
         conversations::Conversations::new(self.clone())
     }
 
+    #[cfg(feature = "dialog")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "dialog")))]
     pub fn dialog(&self) -> dialog::Dialog {
         dialog::Dialog::new(self.clone())
     }
 
+    #[cfg(feature = "dnd")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "dnd")))]
     pub fn dnd(&self) -> dnd::Dnd {
         dnd::Dnd::new(self.clone())
     }
 
+    #[cfg(feature = "emoji")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "emoji")))]
     pub fn emoji(&self) -> emoji::Emoji {
         emoji::Emoji::new(self.clone())
     }
 
+    #[cfg(feature = "files")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "files")))]
     pub fn files(&self) -> files::Files {
         files::Files::new(self.clone())
     }
 
+    #[cfg(feature = "files_comments")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "files_comments")))]
     pub fn files_comments(&self) -> files_comments::FilesComments {
         files_comments::FilesComments::new(self.clone())
     }
 
+    #[cfg(feature = "files_remote")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "files_remote")))]
     pub fn files_remote(&self) -> files_remote::FilesRemote {
         files_remote::FilesRemote::new(self.clone())
     }
 
+    #[cfg(feature = "migration")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "migration")))]
     pub fn migration(&self) -> migration::Migration {
         migration::Migration::new(self.clone())
     }
 
+    #[cfg(feature = "oauth")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "oauth")))]
     pub fn oauth(&self) -> oauth::Oauth {
         oauth::Oauth::new(self.clone())
     }
 
+    #[cfg(feature = "oauth_v_2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "oauth_v_2")))]
     pub fn oauth_v_2(&self) -> oauth_v_2::OauthV2 {
         oauth_v_2::OauthV2::new(self.clone())
     }
 
+    #[cfg(feature = "pins")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "pins")))]
     pub fn pins(&self) -> pins::Pins {
         pins::Pins::new(self.clone())
     }
 
+    #[cfg(feature = "reactions")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "reactions")))]
     pub fn reactions(&self) -> reactions::Reactions {
         reactions::Reactions::new(self.clone())
     }
 
+    #[cfg(feature = "reminders")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "reminders")))]
     pub fn reminders(&self) -> reminders::Reminders {
         reminders::Reminders::new(self.clone())
     }
 
+    #[cfg(feature = "rtm")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rtm")))]
     pub fn rtm(&self) -> rtm::Rtm {
         rtm::Rtm::new(self.clone())
     }
 
+    #[cfg(feature = "search")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "search")))]
     pub fn search(&self) -> search::Search {
         search::Search::new(self.clone())
     }
 
+    #[cfg(feature = "stars")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stars")))]
     pub fn stars(&self) -> stars::Stars {
         stars::Stars::new(self.clone())
     }
 
+    #[cfg(feature = "team")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "team")))]
     pub fn team(&self) -> team::Team {
         team::Team::new(self.clone())
     }
 
+    #[cfg(feature = "team_profile")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "team_profile")))]
     pub fn team_profile(&self) -> team_profile::TeamProfile {
         team_profile::TeamProfile::new(self.clone())
     }
 
+    #[cfg(feature = "usergroups")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "usergroups")))]
     pub fn usergroups(&self) -> usergroups::Usergroups {
         usergroups::Usergroups::new(self.clone())
     }
 
+    #[cfg(feature = "usergroups_users")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "usergroups_users")))]
     pub fn usergroups_users(&self) -> usergroups_users::UsergroupsUsers {
         usergroups_users::UsergroupsUsers::new(self.clone())
     }
 
+    #[cfg(feature = "users")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "users")))]
     pub fn users(&self) -> users::Users {
         users::Users::new(self.clone())
     }
 
+    #[cfg(feature = "users_profile")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "users_profile")))]
     pub fn users_profile(&self) -> users_profile::UsersProfile {
         users_profile::UsersProfile::new(self.clone())
     }
 
+    #[cfg(feature = "views")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "views")))]
     pub fn views(&self) -> views::Views {
         views::Views::new(self.clone())
     }
 
+    #[cfg(feature = "workflows")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "workflows")))]
     pub fn workflows(&self) -> workflows::Workflows {
         workflows::Workflows::new(self.clone())
     }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Migration {
     pub client: Client,
@@ -21,25 +19,20 @@ impl Migration {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `tokens.basic`.
-     * * `users: &str` -- A comma-separated list of user ids, up to 400 per request.
-     * * `team_id: &str` -- Specify team_id starts with `T` in case of Org Token.
-     * * `to_old: bool` -- Specify `true` to convert `W` global user IDs to workspace-specific `U` IDs. Defaults to `false`.
+     * * `params: &MigrationExchangeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn exchange(
+    pub async fn exchange_with(
         &self,
-        users: &str,
-        team_id: &str,
-        to_old: bool,
+        params: &MigrationExchangeParams,
     ) -> Result<crate::types::MigrationExchangeSuccessSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !team_id.is_empty() {
+        if let Some(team_id) = &params.team_id {
             query_args.push(("team_id".to_string(), team_id.to_string()));
         }
-        if to_old {
+        if let Some(to_old) = &params.to_old {
             query_args.push(("to_old".to_string(), to_old.to_string()));
         }
-        if !users.is_empty() {
+        if let Some(users) = &params.users {
             query_args.push(("users".to_string(), users.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -47,4 +40,72 @@ impl Migration {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `exchange_with` and pass the query parameters as a `MigrationExchangeParams` instead.
+     */
+    #[deprecated(note = "use `exchange_with` instead")]
+    pub async fn exchange(
+        &self,
+        users: &str,
+        team_id: &str,
+        to_old: bool,
+    ) -> Result<crate::types::MigrationExchangeSuccessSchema> {
+        let params = MigrationExchangeParams {
+            team_id: Some(team_id.to_string()).filter(|s| !s.is_empty()),
+            to_old: Some(to_old).filter(|v| *v),
+            users: Some(users.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.exchange_with(&params).await
+    }
+}
+
+/// The query parameters of `Migration::exchange_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationExchangeParams {
+    /// Specify team_id starts with `T` in case of Org Token.
+    pub team_id: Option<String>,
+    /// Specify `true` to convert `W` global user IDs to workspace-specific `U` IDs. Defaults to `false`.
+    pub to_old: Option<bool>,
+    /// A comma-separated list of user ids, up to 400 per request.
+    pub users: Option<String>,
+}
+
+impl MigrationExchangeParams {
+    pub fn team_id<T: ToString>(mut self, team_id: T) -> Self {
+        self.team_id = Some(team_id.to_string());
+        self
+    }
+
+    pub fn to_old(mut self, to_old: bool) -> Self {
+        self.to_old = Some(to_old);
+        self
+    }
+
+    pub fn users<T: ToString>(mut self, users: T) -> Self {
+        self.users = Some(users.to_string());
+        self
+    }
+}
+
+/// The functions of [`Migration`], for code that takes them as a trait, so that it can be
+/// tested with the `MockMigrationApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait MigrationApi: Send + Sync {
+    /// See [`Migration::exchange_with`].
+    async fn exchange_with(
+        &self,
+        params: &MigrationExchangeParams,
+    ) -> Result<crate::types::MigrationExchangeSuccessSchema>;
+}
+
+#[async_trait::async_trait]
+impl MigrationApi for Migration {
+    async fn exchange_with(
+        &self,
+        params: &MigrationExchangeParams,
+    ) -> Result<crate::types::MigrationExchangeSuccessSchema> {
+        Migration::exchange_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Oauth {
     pub client: Client,
@@ -21,34 +19,26 @@ impl Oauth {
      *
      * **Parameters:**
      *
-     * * `client_id: &str` -- Issued when you created your application.
-     * * `client_secret: &str` -- Issued when you created your application.
-     * * `code: &str` -- The `code` param returned via the OAuth callback.
-     * * `redirect_uri: &str` -- This must match the originally submitted URI (if one was sent).
-     * * `single_channel: bool` -- Request the user to add your app only to a single channel. Only valid with a [legacy workspace app](https://api.slack.com/legacy-workspace-apps).
+     * * `params: &OauthAccessParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn access(
+    pub async fn access_with(
         &self,
-        client_id: &str,
-        client_secret: &str,
-        code: &str,
-        redirect_uri: &str,
-        single_channel: bool,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &OauthAccessParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_id.is_empty() {
+        if let Some(client_id) = &params.client_id {
             query_args.push(("client_id".to_string(), client_id.to_string()));
         }
-        if !client_secret.is_empty() {
+        if let Some(client_secret) = &params.client_secret {
             query_args.push(("client_secret".to_string(), client_secret.to_string()));
         }
-        if !code.is_empty() {
+        if let Some(code) = &params.code {
             query_args.push(("code".to_string(), code.to_string()));
         }
-        if !redirect_uri.is_empty() {
+        if let Some(redirect_uri) = &params.redirect_uri {
             query_args.push(("redirect_uri".to_string(), redirect_uri.to_string()));
         }
-        if single_channel {
+        if let Some(single_channel) = &params.single_channel {
             query_args.push(("single_channel".to_string(), single_channel.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -57,6 +47,28 @@ impl Oauth {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `access_with` and pass the query parameters as a `OauthAccessParams` instead.
+     */
+    #[deprecated(note = "use `access_with` instead")]
+    pub async fn access(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+        single_channel: bool,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = OauthAccessParams {
+            client_id: Some(client_id.to_string()).filter(|s| !s.is_empty()),
+            client_secret: Some(client_secret.to_string()).filter(|s| !s.is_empty()),
+            code: Some(code.to_string()).filter(|s| !s.is_empty()),
+            redirect_uri: Some(redirect_uri.to_string()).filter(|s| !s.is_empty()),
+            single_channel: Some(single_channel).filter(|v| *v),
+        };
+        self.access_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/oauth.token` endpoint.
      *
@@ -66,34 +78,26 @@ impl Oauth {
      *
      * **Parameters:**
      *
-     * * `client_id: &str` -- Issued when you created your application.
-     * * `client_secret: &str` -- Issued when you created your application.
-     * * `code: &str` -- The `code` param returned via the OAuth callback.
-     * * `redirect_uri: &str` -- This must match the originally submitted URI (if one was sent).
-     * * `single_channel: bool` -- Request the user to add your app only to a single channel.
+     * * `params: &OauthTokenParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn token(
+    pub async fn token_with(
         &self,
-        client_id: &str,
-        client_secret: &str,
-        code: &str,
-        redirect_uri: &str,
-        single_channel: bool,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &OauthTokenParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_id.is_empty() {
+        if let Some(client_id) = &params.client_id {
             query_args.push(("client_id".to_string(), client_id.to_string()));
         }
-        if !client_secret.is_empty() {
+        if let Some(client_secret) = &params.client_secret {
             query_args.push(("client_secret".to_string(), client_secret.to_string()));
         }
-        if !code.is_empty() {
+        if let Some(code) = &params.code {
             query_args.push(("code".to_string(), code.to_string()));
         }
-        if !redirect_uri.is_empty() {
+        if let Some(redirect_uri) = &params.redirect_uri {
             query_args.push(("redirect_uri".to_string(), redirect_uri.to_string()));
         }
-        if single_channel {
+        if let Some(single_channel) = &params.single_channel {
             query_args.push(("single_channel".to_string(), single_channel.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -101,4 +105,145 @@ impl Oauth {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `token_with` and pass the query parameters as a `OauthTokenParams` instead.
+     */
+    #[deprecated(note = "use `token_with` instead")]
+    pub async fn token(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+        single_channel: bool,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = OauthTokenParams {
+            client_id: Some(client_id.to_string()).filter(|s| !s.is_empty()),
+            client_secret: Some(client_secret.to_string()).filter(|s| !s.is_empty()),
+            code: Some(code.to_string()).filter(|s| !s.is_empty()),
+            redirect_uri: Some(redirect_uri.to_string()).filter(|s| !s.is_empty()),
+            single_channel: Some(single_channel).filter(|v| *v),
+        };
+        self.token_with(&params).await
+    }
+}
+
+/// The query parameters of `Oauth::access_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OauthAccessParams {
+    /// Issued when you created your application.
+    pub client_id: Option<String>,
+    /// Issued when you created your application.
+    pub client_secret: Option<String>,
+    /// The `code` param returned via the OAuth callback.
+    pub code: Option<String>,
+    /// This must match the originally submitted URI (if one was sent).
+    pub redirect_uri: Option<String>,
+    /// Request the user to add your app only to a single channel. Only valid with a [legacy workspace app](https://api.slack.com/legacy-workspace-apps).
+    pub single_channel: Option<bool>,
+}
+
+impl OauthAccessParams {
+    pub fn client_id<T: ToString>(mut self, client_id: T) -> Self {
+        self.client_id = Some(client_id.to_string());
+        self
+    }
+
+    pub fn client_secret<T: ToString>(mut self, client_secret: T) -> Self {
+        self.client_secret = Some(client_secret.to_string());
+        self
+    }
+
+    pub fn code<T: ToString>(mut self, code: T) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    pub fn redirect_uri<T: ToString>(mut self, redirect_uri: T) -> Self {
+        self.redirect_uri = Some(redirect_uri.to_string());
+        self
+    }
+
+    pub fn single_channel(mut self, single_channel: bool) -> Self {
+        self.single_channel = Some(single_channel);
+        self
+    }
+}
+
+/// The query parameters of `Oauth::token_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OauthTokenParams {
+    /// Issued when you created your application.
+    pub client_id: Option<String>,
+    /// Issued when you created your application.
+    pub client_secret: Option<String>,
+    /// The `code` param returned via the OAuth callback.
+    pub code: Option<String>,
+    /// This must match the originally submitted URI (if one was sent).
+    pub redirect_uri: Option<String>,
+    /// Request the user to add your app only to a single channel.
+    pub single_channel: Option<bool>,
+}
+
+impl OauthTokenParams {
+    pub fn client_id<T: ToString>(mut self, client_id: T) -> Self {
+        self.client_id = Some(client_id.to_string());
+        self
+    }
+
+    pub fn client_secret<T: ToString>(mut self, client_secret: T) -> Self {
+        self.client_secret = Some(client_secret.to_string());
+        self
+    }
+
+    pub fn code<T: ToString>(mut self, code: T) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    pub fn redirect_uri<T: ToString>(mut self, redirect_uri: T) -> Self {
+        self.redirect_uri = Some(redirect_uri.to_string());
+        self
+    }
+
+    pub fn single_channel(mut self, single_channel: bool) -> Self {
+        self.single_channel = Some(single_channel);
+        self
+    }
+}
+
+/// The functions of [`Oauth`], for code that takes them as a trait, so that it can be
+/// tested with the `MockOauthApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait OauthApi: Send + Sync {
+    /// See [`Oauth::access_with`].
+    async fn access_with(
+        &self,
+        params: &OauthAccessParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`Oauth::token_with`].
+    async fn token_with(
+        &self,
+        params: &OauthTokenParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl OauthApi for Oauth {
+    async fn access_with(
+        &self,
+        params: &OauthAccessParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Oauth::access_with(self, params).await
+    }
+
+    async fn token_with(
+        &self,
+        params: &OauthTokenParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Oauth::token_with(self, params).await
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct OauthV2 {
     pub client: Client,
@@ -21,29 +19,23 @@ impl OauthV2 {
      *
      * **Parameters:**
      *
-     * * `client_id: &str` -- Issued when you created your application.
-     * * `client_secret: &str` -- Issued when you created your application.
-     * * `code: &str` -- The `code` param returned via the OAuth callback.
-     * * `redirect_uri: &str` -- This must match the originally submitted URI (if one was sent).
+     * * `params: &OauthV2OauthAccessParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn oauth_access(
+    pub async fn oauth_access_with(
         &self,
-        client_id: &str,
-        client_secret: &str,
-        code: &str,
-        redirect_uri: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &OauthV2OauthAccessParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_id.is_empty() {
+        if let Some(client_id) = &params.client_id {
             query_args.push(("client_id".to_string(), client_id.to_string()));
         }
-        if !client_secret.is_empty() {
+        if let Some(client_secret) = &params.client_secret {
             query_args.push(("client_secret".to_string(), client_secret.to_string()));
         }
-        if !code.is_empty() {
+        if let Some(code) = &params.code {
             query_args.push(("code".to_string(), code.to_string()));
         }
-        if !redirect_uri.is_empty() {
+        if let Some(redirect_uri) = &params.redirect_uri {
             query_args.push(("redirect_uri".to_string(), redirect_uri.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -51,4 +43,81 @@ impl OauthV2 {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `oauth_access_with` and pass the query parameters as a `OauthV2OauthAccessParams` instead.
+     */
+    #[deprecated(note = "use `oauth_access_with` instead")]
+    pub async fn oauth_access(
+        &self,
+        client_id: &str,
+        client_secret: &str,
+        code: &str,
+        redirect_uri: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = OauthV2OauthAccessParams {
+            client_id: Some(client_id.to_string()).filter(|s| !s.is_empty()),
+            client_secret: Some(client_secret.to_string()).filter(|s| !s.is_empty()),
+            code: Some(code.to_string()).filter(|s| !s.is_empty()),
+            redirect_uri: Some(redirect_uri.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.oauth_access_with(&params).await
+    }
+}
+
+/// The query parameters of `OauthV2::oauth_access_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OauthV2OauthAccessParams {
+    /// Issued when you created your application.
+    pub client_id: Option<String>,
+    /// Issued when you created your application.
+    pub client_secret: Option<String>,
+    /// The `code` param returned via the OAuth callback.
+    pub code: Option<String>,
+    /// This must match the originally submitted URI (if one was sent).
+    pub redirect_uri: Option<String>,
+}
+
+impl OauthV2OauthAccessParams {
+    pub fn client_id<T: ToString>(mut self, client_id: T) -> Self {
+        self.client_id = Some(client_id.to_string());
+        self
+    }
+
+    pub fn client_secret<T: ToString>(mut self, client_secret: T) -> Self {
+        self.client_secret = Some(client_secret.to_string());
+        self
+    }
+
+    pub fn code<T: ToString>(mut self, code: T) -> Self {
+        self.code = Some(code.to_string());
+        self
+    }
+
+    pub fn redirect_uri<T: ToString>(mut self, redirect_uri: T) -> Self {
+        self.redirect_uri = Some(redirect_uri.to_string());
+        self
+    }
+}
+
+/// The functions of [`OauthV2`], for code that takes them as a trait, so that it can be
+/// tested with the `MockOauthV2Api` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait OauthV2Api: Send + Sync {
+    /// See [`OauthV2::oauth_access_with`].
+    async fn oauth_access_with(
+        &self,
+        params: &OauthV2OauthAccessParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl OauthV2Api for OauthV2 {
+    async fn oauth_access_with(
+        &self,
+        params: &OauthV2OauthAccessParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        OauthV2::oauth_access_with(self, params).await
+    }
 }
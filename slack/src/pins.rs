@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Pins {
     pub client: Client,
@@ -23,9 +21,18 @@ impl Pins {
      *
      * * `token: &str` -- Authentication token. Requires scope: `pins:write`.
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::PinsAddRequest,
+    ) -> Result<crate::types::PinsAddSchema> {
         let url = "/pins.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -37,12 +44,14 @@ impl Pins {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `pins:read`.
-     * * `channel: &str` -- Channel to get pinned items for.
+     * * `params: &PinsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(&self, channel: &str) -> Result<Vec<crate::types::PinsListResponseAnyOf>> {
+    pub async fn list_with(
+        &self,
+        params: &PinsListParams,
+    ) -> Result<Vec<crate::types::PinsListResponseAnyOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel.is_empty() {
+        if let Some(channel) = &params.channel {
             query_args.push(("channel".to_string(), channel.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -51,6 +60,17 @@ impl Pins {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `PinsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(&self, channel: &str) -> Result<Vec<crate::types::PinsListResponseAnyOf>> {
+        let params = PinsListParams {
+            channel: Some(channel.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/pins.list` endpoint.
      *
@@ -59,19 +79,77 @@ impl Pins {
      * Lists items pinned to a channel.
      *
      * FROM: <https://api.slack.com/methods/pins.list>
+     *
+     * **Parameters:**
+     *
+     * * `params: &PinsListParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn list_all_with(
+        &self,
+        params: &PinsListParams,
+    ) -> Result<Vec<crate::types::PinsListResponseAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(channel) = &params.channel {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/pins.list?{}", query_);
+
+        self.client.get_all_pages(&url, None).await
+    }
+
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `PinsListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
     pub async fn list_all(
         &self,
         channel: &str,
     ) -> Result<Vec<crate::types::PinsListResponseAnyOf>> {
+        let params = PinsListParams {
+            channel: Some(channel.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/pins.list` endpoint.
+     *
+     * As opposed to `list_all`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Lists items pinned to a channel.
+     *
+     * FROM: <https://api.slack.com/methods/pins.list>
+     */
+    pub fn list_stream(
+        &self,
+        params: &PinsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PinsListResponseAnyOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel.is_empty() {
+        if let Some(channel) = &params.channel {
             query_args.push(("channel".to_string(), channel.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/pins.list?{}", query_);
 
-        self.client.get_all_pages(&url, None).await
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
     }
 
     /**
@@ -85,8 +163,107 @@ impl Pins {
      *
      * * `token: &str` -- Authentication token. Requires scope: `pins:write`.
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::PinsRemoveRequest,
+    ) -> Result<crate::types::PinsRemoveSchema> {
         let url = "/pins.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Pins::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PinsListParams {
+    /// Channel to get pinned items for.
+    pub channel: Option<String>,
+}
+
+impl PinsListParams {
+    pub fn channel<T: ToString>(mut self, channel: T) -> Self {
+        self.channel = Some(channel.to_string());
+        self
+    }
+}
+
+/// The functions of [`Pins`], for code that takes them as a trait, so that it can be
+/// tested with the `MockPinsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait PinsApi: Send + Sync {
+    /// See [`Pins::add`].
+    async fn add(&self, body: &crate::types::PinsAddRequest)
+        -> Result<crate::types::PinsAddSchema>;
+
+    /// See [`Pins::list_with`].
+    async fn list_with(
+        &self,
+        params: &PinsListParams,
+    ) -> Result<Vec<crate::types::PinsListResponseAnyOf>>;
+
+    /// See [`Pins::list_all_with`].
+    async fn list_all_with(
+        &self,
+        params: &PinsListParams,
+    ) -> Result<Vec<crate::types::PinsListResponseAnyOf>>;
+
+    /// See [`Pins::list_stream`].
+    fn list_stream(
+        &self,
+        params: &PinsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PinsListResponseAnyOf>;
+
+    /// See [`Pins::remove`].
+    async fn remove(
+        &self,
+        body: &crate::types::PinsRemoveRequest,
+    ) -> Result<crate::types::PinsRemoveSchema>;
+}
+
+#[async_trait::async_trait]
+impl PinsApi for Pins {
+    async fn add(
+        &self,
+        body: &crate::types::PinsAddRequest,
+    ) -> Result<crate::types::PinsAddSchema> {
+        Pins::add(self, body).await
+    }
+
+    async fn list_with(
+        &self,
+        params: &PinsListParams,
+    ) -> Result<Vec<crate::types::PinsListResponseAnyOf>> {
+        Pins::list_with(self, params).await
+    }
+
+    async fn list_all_with(
+        &self,
+        params: &PinsListParams,
+    ) -> Result<Vec<crate::types::PinsListResponseAnyOf>> {
+        Pins::list_all_with(self, params).await
+    }
+
+    fn list_stream(
+        &self,
+        params: &PinsListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PinsListResponseAnyOf> {
+        Pins::list_stream(self, params, start, max_items)
+    }
+
+    async fn remove(
+        &self,
+        body: &crate::types::PinsRemoveRequest,
+    ) -> Result<crate::types::PinsRemoveSchema> {
+        Pins::remove(self, body).await
     }
 }
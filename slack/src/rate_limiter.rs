@@ -0,0 +1,313 @@
+//! Waiting out the rate limits of the API.
+//!
+//! The [`RateLimiter`] of a client keeps track of what the responses say about the
+//! rate limit. Before sending a request, it waits until the server allows requests
+//! again, if the server asked for that with `Retry-After` or if none of the limit is
+//! left. Requests that get `429 Too Many Requests` are sent again once the wait is
+//! over, if their [`RetryPolicy`] says they are safe to send twice. A `503 Service
+//! Unavailable` with a `Retry-After` makes the requests that follow wait too, but the
+//! request is only sent again by the [`Retry`](crate::retry::Retry) middleware, like
+//! the other `503`s.
+//!
+//! It never waits longer than its `max_wait`. Requests that would wait longer fail with
+//! `Error::RateLimited` instead, and so do those that are still rate limited after
+//! `MAX_ATTEMPTS` attempts.
+
+use std::{
+    collections::BTreeMap,
+    convert::TryFrom,
+    fmt,
+    sync::{Arc, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::{header::HeaderMap, Request, Response, StatusCode};
+use reqwest_middleware::{Middleware, Next};
+use task_local_extensions::Extensions;
+
+use crate::retry::RetryPolicy;
+
+const PROVIDER: Provider = Provider::Slack;
+
+/// How long to wait for a rate limit to reset, unless the client says otherwise.
+pub const MAX_WAIT: Duration = Duration::from_secs(60);
+
+/// How many times a request is sent before giving up on a rate limit.
+pub const MAX_ATTEMPTS: u32 = 3;
+
+/// How long to wait when the server doesn't say.
+const DEFAULT_WAIT: Duration = Duration::from_secs(1);
+
+/*
+ * The headers the API tells its rate limits in, see `rate_limit` in the
+ * `generator.toml` of the client.
+ */
+#[allow(dead_code)]
+#[derive(PartialEq, Eq)]
+enum Provider {
+    RetryAfter,
+    GitHub,
+    Okta,
+    Zoom,
+    Slack,
+}
+
+/// What the last response of a bucket said about its rate limit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// What the limit applies to. That's the resource for GitHub, like `core` or
+    /// `search`, the category of the operations for Zoom, like `Light`, the endpoint
+    /// for Okta and Slack, and the whole API, `""`, for the others.
+    pub bucket: String,
+    /// How many requests the limit allows.
+    pub limit: Option<u64>,
+    /// How many requests are left.
+    pub remaining: Option<u64>,
+    /// When the limit resets.
+    pub reset: Option<SystemTime>,
+    /// When the server allows requests again, if it rate limited the last one.
+    pub retry_after: Option<SystemTime>,
+}
+
+impl RateLimitStatus {
+    /// How long to wait before sending a request, if at all.
+    pub fn wait(&self) -> Option<Duration> {
+        let exhausted = if self.remaining == Some(0) {
+            self.reset
+        } else {
+            None
+        };
+        let until = match (self.retry_after, exhausted) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        }?;
+        until.duration_since(SystemTime::now()).ok()
+    }
+}
+
+/// The middleware that waits out the rate limits of the API.
+///
+/// The clients made with `new` have one already. The status of the rate limits is shared
+/// between the clones of a `RateLimiter`, so one added to the middleware of `custom`
+/// clients can be kept to check on it.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    state: Arc<Mutex<State>>,
+    max_wait: Duration,
+    policy: RetryPolicy,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    buckets: BTreeMap<String, RateLimitStatus>,
+    // The bucket of each route, as told by the last response of the route.
+    routes: BTreeMap<String, String>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        RateLimiter {
+            state: Default::default(),
+            max_wait: MAX_WAIT,
+            policy: Default::default(),
+        }
+    }
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set how long to wait for a rate limit to reset, at most.
+    pub fn with_max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+
+    /// Set which rate limited requests are safe to send again, those of the
+    /// `RetryPolicy` of the client.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// The status of the rate limit of each bucket the API told us about, by bucket.
+    pub fn status(&self) -> Vec<RateLimitStatus> {
+        self.state
+            .lock()
+            .unwrap()
+            .buckets
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /*
+     * How long to wait before sending a request to a route.
+     */
+    fn wait(&self, route: &str) -> Option<Duration> {
+        let state = self.state.lock().unwrap();
+        let bucket = match state.routes.get(route) {
+            Some(bucket) => bucket.as_str(),
+            None if PROVIDER == Provider::RetryAfter => "",
+            None => return None,
+        };
+        state.buckets.get(bucket).and_then(|s| s.wait())
+    }
+
+    /*
+     * Keep track of what a response says about the rate limit. Returns whether the
+     * request was rate limited.
+     */
+    fn observe(&self, route: &str, status: StatusCode, headers: &HeaderMap) -> bool {
+        let bucket = match PROVIDER {
+            Provider::GitHub => header(headers, "x-ratelimit-resource").unwrap_or("core"),
+            Provider::Zoom => header(headers, "x-ratelimit-category")
+                .or_else(|| header(headers, "x-ratelimit-type"))
+                .unwrap_or_default(),
+            Provider::Okta | Provider::Slack => route,
+            Provider::RetryAfter => "",
+        }
+        .to_string();
+
+        let prefix = match PROVIDER {
+            Provider::GitHub | Provider::Zoom => Some("x-ratelimit"),
+            Provider::Okta => Some("x-rate-limit"),
+            _ => None,
+        };
+        let number = |name: &str| {
+            prefix
+                .and_then(|p| header(headers, &format!("{}-{}", p, name)))
+                .and_then(|h| h.parse::<u64>().ok())
+        };
+        let limit = number("limit");
+        let remaining = number("remaining");
+        let reset = number("reset").map(|s| UNIX_EPOCH + Duration::from_secs(s));
+        let retry_after = header(headers, "retry-after").and_then(retry_after);
+
+        let limited = status == StatusCode::TOO_MANY_REQUESTS
+            || (status == StatusCode::SERVICE_UNAVAILABLE && retry_after.is_some())
+            // GitHub rate limits with 403 too.
+            || (PROVIDER == Provider::GitHub
+                && status == StatusCode::FORBIDDEN
+                && (remaining == Some(0) || retry_after.is_some()));
+
+        let mut state = self.state.lock().unwrap();
+        state.routes.insert(route.to_string(), bucket.clone());
+        let s = state
+            .buckets
+            .entry(bucket.clone())
+            .or_insert_with(|| RateLimitStatus {
+                bucket,
+                ..Default::default()
+            });
+        s.limit = limit.or(s.limit);
+        s.remaining = remaining.or(s.remaining);
+        s.reset = reset.or(s.reset);
+        s.retry_after = if limited {
+            Some(retry_after.unwrap_or_else(|| SystemTime::now() + DEFAULT_WAIT))
+        } else {
+            None
+        };
+
+        limited
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RateLimiter {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let route = route(req.url().path());
+        let mut req = req;
+        let mut attempts = 0;
+        loop {
+            if let Some(wait) = self.wait(&route) {
+                if wait > self.max_wait {
+                    return Err(reqwest_middleware::Error::Middleware(
+                        Exceeded { reset: wait }.into(),
+                    ));
+                }
+                log::debug!("rate limited, waiting {:?} to send {}", wait, req.url());
+                tokio::time::sleep(wait).await;
+            }
+
+            // Requests with a streamed body can't be sent again.
+            let again = if self.policy.is_retryable(&req) {
+                req.try_clone()
+            } else {
+                None
+            };
+            let response = next.clone().run(req, extensions).await?;
+            attempts += 1;
+
+            // The retries of `503`s are left to the `Retry` middleware, which sends
+            // them through here again, so they wait for the `Retry-After` all the same.
+            let limited = self.observe(&route, response.status(), response.headers())
+                && response.status() != StatusCode::SERVICE_UNAVAILABLE;
+            match again {
+                Some(again) if limited && attempts < MAX_ATTEMPTS => req = again,
+                _ => return Ok(response),
+            }
+        }
+    }
+}
+
+/// The error of requests that weren't sent since the rate limit would take longer than
+/// the `max_wait` of the `RateLimiter` to reset. It becomes `Error::RateLimited`.
+#[derive(Debug)]
+pub(crate) struct Exceeded {
+    pub(crate) reset: Duration,
+}
+
+impl fmt::Display for Exceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "rate limit exceeded, will reset in {} seconds",
+            self.reset.as_secs()
+        )
+    }
+}
+
+impl std::error::Error for Exceeded {}
+
+/// When a `Retry-After` header allows requests again. It's either a number of seconds
+/// or a date, which Zoom sends in RFC 3339 rather than the HTTP format.
+pub(crate) fn retry_after(value: &str) -> Option<SystemTime> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(SystemTime::now() + Duration::from_secs(seconds));
+    }
+    chrono::DateTime::parse_from_rfc2822(value)
+        .or_else(|_| chrono::DateTime::parse_from_rfc3339(value))
+        .ok()
+        .and_then(|d| u64::try_from(d.timestamp()).ok())
+        .map(|s| UNIX_EPOCH + Duration::from_secs(s))
+}
+
+/*
+ * The route of a path, with the segments that look like IDs left out, so all the
+ * requests to an endpoint share a bucket.
+ */
+fn route(path: &str) -> String {
+    path.split('/')
+        .map(|s| {
+            if s.chars().any(|c| c.is_ascii_digit()) {
+                "{}"
+            } else {
+                s
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn header<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|h| h.to_str().ok())
+}
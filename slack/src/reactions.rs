@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Reactions {
     pub client: Client,
@@ -23,9 +21,18 @@ impl Reactions {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reactions:write`.
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::ReactionsAddRequest,
+    ) -> Result<crate::types::ReactionsAddSchema> {
         let url = "/reactions.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -37,35 +44,26 @@ impl Reactions {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `reactions:read`.
-     * * `channel: &str` -- Channel where the message to get reactions for was posted.
-     * * `file: &str` -- File to get reactions for.
-     * * `file_comment: &str` -- File comment to get reactions for.
-     * * `full: bool` -- If true always return the complete reaction list.
-     * * `timestamp: &str` -- Timestamp of the message to get reactions for.
+     * * `params: &ReactionsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        channel: &str,
-        file: &str,
-        file_comment: &str,
-        full: bool,
-        timestamp: &str,
-    ) -> Result<crate::types::Fields> {
+        params: &ReactionsGetParams,
+    ) -> Result<crate::types::ReactionsGetSuccessSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel.is_empty() {
+        if let Some(channel) = &params.channel {
             query_args.push(("channel".to_string(), channel.to_string()));
         }
-        if !file.is_empty() {
+        if let Some(file) = &params.file {
             query_args.push(("file".to_string(), file.to_string()));
         }
-        if !file_comment.is_empty() {
+        if let Some(file_comment) = &params.file_comment {
             query_args.push(("file_comment".to_string(), file_comment.to_string()));
         }
-        if full {
+        if let Some(full) = &params.full {
             query_args.push(("full".to_string(), full.to_string()));
         }
-        if !timestamp.is_empty() {
+        if let Some(timestamp) = &params.timestamp {
             query_args.push(("timestamp".to_string(), timestamp.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -74,6 +72,28 @@ impl Reactions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `ReactionsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        channel: &str,
+        file: &str,
+        file_comment: &str,
+        full: bool,
+        timestamp: &str,
+    ) -> Result<crate::types::ReactionsGetSuccessSchema> {
+        let params = ReactionsGetParams {
+            channel: Some(channel.to_string()).filter(|s| !s.is_empty()),
+            file: Some(file.to_string()).filter(|s| !s.is_empty()),
+            file_comment: Some(file_comment.to_string()).filter(|s| !s.is_empty()),
+            full: Some(full).filter(|v| *v),
+            timestamp: Some(timestamp.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/reactions.list` endpoint.
      *
@@ -83,40 +103,29 @@ impl Reactions {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `reactions:read`.
-     * * `user: &str` -- Show reactions made by this user. Defaults to the authed user.
-     * * `full: bool` -- If true always return the complete reaction list.
-     * * `count: i64`
-     * * `page: i64`
-     * * `cursor: &str` -- Parameter for pagination. Set `cursor` equal to the `next_cursor` attribute returned by the previous request's `response_metadata`. This parameter is optional, but pagination is mandatory: the default value simply fetches the first "page" of the collection. See [pagination](/docs/pagination) for more details.
-     * * `limit: i64` -- The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+     * * `params: &ReactionsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        user: &str,
-        full: bool,
-        count: i64,
-        page: i64,
-        cursor: &str,
-        limit: i64,
+        params: &ReactionsListParams,
     ) -> Result<crate::types::ReactionsListSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if full {
+        if let Some(full) = &params.full {
             query_args.push(("full".to_string(), full.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if !user.is_empty() {
+        if let Some(user) = &params.user {
             query_args.push(("user".to_string(), user.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -125,6 +134,30 @@ impl Reactions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `ReactionsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        user: &str,
+        full: bool,
+        count: i64,
+        page: i64,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::ReactionsListSchema> {
+        let params = ReactionsListParams {
+            count: Some(count).filter(|v| *v > 0),
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            full: Some(full).filter(|v| *v),
+            limit: Some(limit).filter(|v| *v > 0),
+            page: Some(page).filter(|v| *v > 0),
+            user: Some(user.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/reactions.remove` endpoint.
      *
@@ -136,8 +169,167 @@ impl Reactions {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reactions:write`.
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::ReactionsRemoveRequest,
+    ) -> Result<crate::types::ReactionsRemoveSchema> {
         let url = "/reactions.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Reactions::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReactionsGetParams {
+    /// Channel where the message to get reactions for was posted.
+    pub channel: Option<String>,
+    /// File to get reactions for.
+    pub file: Option<String>,
+    /// File comment to get reactions for.
+    pub file_comment: Option<String>,
+    /// If true always return the complete reaction list.
+    pub full: Option<bool>,
+    /// Timestamp of the message to get reactions for.
+    pub timestamp: Option<String>,
+}
+
+impl ReactionsGetParams {
+    pub fn channel<T: ToString>(mut self, channel: T) -> Self {
+        self.channel = Some(channel.to_string());
+        self
+    }
+
+    pub fn file<T: ToString>(mut self, file: T) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+
+    pub fn file_comment<T: ToString>(mut self, file_comment: T) -> Self {
+        self.file_comment = Some(file_comment.to_string());
+        self
+    }
+
+    pub fn full(mut self, full: bool) -> Self {
+        self.full = Some(full);
+        self
+    }
+
+    pub fn timestamp<T: ToString>(mut self, timestamp: T) -> Self {
+        self.timestamp = Some(timestamp.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Reactions::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReactionsListParams {
+    pub count: Option<i64>,
+    /// Parameter for pagination. Set `cursor` equal to the `next_cursor` attribute returned by the previous request's `response_metadata`. This parameter is optional, but pagination is mandatory: the default value simply fetches the first "page" of the collection. See [pagination](/docs/pagination) for more details.
+    pub cursor: Option<String>,
+    /// If true always return the complete reaction list.
+    pub full: Option<bool>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<i64>,
+    pub page: Option<i64>,
+    /// Show reactions made by this user. Defaults to the authed user.
+    pub user: Option<String>,
+}
+
+impl ReactionsListParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn full(mut self, full: bool) -> Self {
+        self.full = Some(full);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn user<T: ToString>(mut self, user: T) -> Self {
+        self.user = Some(user.to_string());
+        self
+    }
+}
+
+/// The functions of [`Reactions`], for code that takes them as a trait, so that it can be
+/// tested with the `MockReactionsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ReactionsApi: Send + Sync {
+    /// See [`Reactions::add`].
+    async fn add(
+        &self,
+        body: &crate::types::ReactionsAddRequest,
+    ) -> Result<crate::types::ReactionsAddSchema>;
+
+    /// See [`Reactions::get_with`].
+    async fn get_with(
+        &self,
+        params: &ReactionsGetParams,
+    ) -> Result<crate::types::ReactionsGetSuccessSchema>;
+
+    /// See [`Reactions::list_with`].
+    async fn list_with(
+        &self,
+        params: &ReactionsListParams,
+    ) -> Result<crate::types::ReactionsListSchema>;
+
+    /// See [`Reactions::remove`].
+    async fn remove(
+        &self,
+        body: &crate::types::ReactionsRemoveRequest,
+    ) -> Result<crate::types::ReactionsRemoveSchema>;
+}
+
+#[async_trait::async_trait]
+impl ReactionsApi for Reactions {
+    async fn add(
+        &self,
+        body: &crate::types::ReactionsAddRequest,
+    ) -> Result<crate::types::ReactionsAddSchema> {
+        Reactions::add(self, body).await
+    }
+
+    async fn get_with(
+        &self,
+        params: &ReactionsGetParams,
+    ) -> Result<crate::types::ReactionsGetSuccessSchema> {
+        Reactions::get_with(self, params).await
+    }
+
+    async fn list_with(
+        &self,
+        params: &ReactionsListParams,
+    ) -> Result<crate::types::ReactionsListSchema> {
+        Reactions::list_with(self, params).await
+    }
+
+    async fn remove(
+        &self,
+        body: &crate::types::ReactionsRemoveRequest,
+    ) -> Result<crate::types::ReactionsRemoveSchema> {
+        Reactions::remove(self, body).await
     }
 }
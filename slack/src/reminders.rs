@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Reminders {
     pub client: Client,
@@ -23,9 +21,18 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn add(&self) -> Result<crate::types::RemindersAddSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::RemindersAddRequest,
+    ) -> Result<crate::types::RemindersAddSchema> {
         let url = "/reminders.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -39,9 +46,18 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn complete(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn complete(
+        &self,
+        body: &crate::types::RemindersCompleteRequest,
+    ) -> Result<crate::types::RemindersCompleteSchema> {
         let url = "/reminders.complete".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -55,9 +71,18 @@ impl Reminders {
      *
      * * `token: &str` -- Authentication token. Requires scope: `reminders:write`.
      */
-    pub async fn delete(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> Result<crate::types::RemindersDeleteSchema> {
         let url = "/reminders.delete".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -69,12 +94,14 @@ impl Reminders {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `reminders:read`.
-     * * `reminder: &str` -- The ID of the reminder.
+     * * `params: &RemindersInfoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn info(&self, reminder: &str) -> Result<crate::types::RemindersAddSchema> {
+    pub async fn info_with(
+        &self,
+        params: &RemindersInfoParams,
+    ) -> Result<crate::types::RemindersInfoSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !reminder.is_empty() {
+        if let Some(reminder) = &params.reminder {
             query_args.push(("reminder".to_string(), reminder.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -83,6 +110,17 @@ impl Reminders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `info_with` and pass the query parameters as a `RemindersInfoParams` instead.
+     */
+    #[deprecated(note = "use `info_with` instead")]
+    pub async fn info(&self, reminder: &str) -> Result<crate::types::RemindersInfoSchema> {
+        let params = RemindersInfoParams {
+            reminder: Some(reminder.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.info_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/reminders.list` endpoint.
      *
@@ -99,3 +137,85 @@ impl Reminders {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Reminders::info_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RemindersInfoParams {
+    /// The ID of the reminder.
+    pub reminder: Option<String>,
+}
+
+impl RemindersInfoParams {
+    pub fn reminder<T: ToString>(mut self, reminder: T) -> Self {
+        self.reminder = Some(reminder.to_string());
+        self
+    }
+}
+
+/// The functions of [`Reminders`], for code that takes them as a trait, so that it can be
+/// tested with the `MockRemindersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait RemindersApi: Send + Sync {
+    /// See [`Reminders::add`].
+    async fn add(
+        &self,
+        body: &crate::types::RemindersAddRequest,
+    ) -> Result<crate::types::RemindersAddSchema>;
+
+    /// See [`Reminders::complete`].
+    async fn complete(
+        &self,
+        body: &crate::types::RemindersCompleteRequest,
+    ) -> Result<crate::types::RemindersCompleteSchema>;
+
+    /// See [`Reminders::delete`].
+    async fn delete(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> Result<crate::types::RemindersDeleteSchema>;
+
+    /// See [`Reminders::info_with`].
+    async fn info_with(
+        &self,
+        params: &RemindersInfoParams,
+    ) -> Result<crate::types::RemindersInfoSchema>;
+
+    /// See [`Reminders::list`].
+    async fn list(&self) -> Result<crate::types::RemindersListSchema>;
+}
+
+#[async_trait::async_trait]
+impl RemindersApi for Reminders {
+    async fn add(
+        &self,
+        body: &crate::types::RemindersAddRequest,
+    ) -> Result<crate::types::RemindersAddSchema> {
+        Reminders::add(self, body).await
+    }
+
+    async fn complete(
+        &self,
+        body: &crate::types::RemindersCompleteRequest,
+    ) -> Result<crate::types::RemindersCompleteSchema> {
+        Reminders::complete(self, body).await
+    }
+
+    async fn delete(
+        &self,
+        body: &crate::types::RemindersDeleteRequest,
+    ) -> Result<crate::types::RemindersDeleteSchema> {
+        Reminders::delete(self, body).await
+    }
+
+    async fn info_with(
+        &self,
+        params: &RemindersInfoParams,
+    ) -> Result<crate::types::RemindersInfoSchema> {
+        Reminders::info_with(self, params).await
+    }
+
+    async fn list(&self) -> Result<crate::types::RemindersListSchema> {
+        Reminders::list(self).await
+    }
+}
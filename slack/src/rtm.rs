@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Rtm {
     pub client: Client,
@@ -21,23 +19,20 @@ impl Rtm {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `rtm:stream`.
-     * * `batch_presence_aware: bool` -- Batch presence deliveries via subscription. Enabling changes the shape of `presence_change` events. See [batch presence](/docs/presence-and-status#batching).
-     * * `presence_sub: bool` -- Only deliver presence events when requested by subscription. See [presence subscriptions](/docs/presence-and-status#subscriptions).
+     * * `params: &RtmConnectParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn connect(
+    pub async fn connect_with(
         &self,
-        batch_presence_aware: bool,
-        presence_sub: bool,
+        params: &RtmConnectParams,
     ) -> Result<crate::types::RtmConnectSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if batch_presence_aware {
+        if let Some(batch_presence_aware) = &params.batch_presence_aware {
             query_args.push((
                 "batch_presence_aware".to_string(),
                 batch_presence_aware.to_string(),
             ));
         }
-        if presence_sub {
+        if let Some(presence_sub) = &params.presence_sub {
             query_args.push(("presence_sub".to_string(), presence_sub.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -45,4 +40,63 @@ impl Rtm {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `connect_with` and pass the query parameters as a `RtmConnectParams` instead.
+     */
+    #[deprecated(note = "use `connect_with` instead")]
+    pub async fn connect(
+        &self,
+        batch_presence_aware: bool,
+        presence_sub: bool,
+    ) -> Result<crate::types::RtmConnectSchema> {
+        let params = RtmConnectParams {
+            batch_presence_aware: Some(batch_presence_aware).filter(|v| *v),
+            presence_sub: Some(presence_sub).filter(|v| *v),
+        };
+        self.connect_with(&params).await
+    }
+}
+
+/// The query parameters of `Rtm::connect_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RtmConnectParams {
+    /// Batch presence deliveries via subscription. Enabling changes the shape of `presence_change` events. See [batch presence](/docs/presence-and-status#batching).
+    pub batch_presence_aware: Option<bool>,
+    /// Only deliver presence events when requested by subscription. See [presence subscriptions](/docs/presence-and-status#subscriptions).
+    pub presence_sub: Option<bool>,
+}
+
+impl RtmConnectParams {
+    pub fn batch_presence_aware(mut self, batch_presence_aware: bool) -> Self {
+        self.batch_presence_aware = Some(batch_presence_aware);
+        self
+    }
+
+    pub fn presence_sub(mut self, presence_sub: bool) -> Self {
+        self.presence_sub = Some(presence_sub);
+        self
+    }
+}
+
+/// The functions of [`Rtm`], for code that takes them as a trait, so that it can be
+/// tested with the `MockRtmApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait RtmApi: Send + Sync {
+    /// See [`Rtm::connect_with`].
+    async fn connect_with(
+        &self,
+        params: &RtmConnectParams,
+    ) -> Result<crate::types::RtmConnectSchema>;
+}
+
+#[async_trait::async_trait]
+impl RtmApi for Rtm {
+    async fn connect_with(
+        &self,
+        params: &RtmConnectParams,
+    ) -> Result<crate::types::RtmConnectSchema> {
+        Rtm::connect_with(self, params).await
+    }
 }
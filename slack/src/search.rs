@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Search {
     pub client: Client,
@@ -21,40 +19,29 @@ impl Search {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `search:read`.
-     * * `count: i64` -- Pass the number of results you want per "page". Maximum of `100`.
-     * * `highlight: bool` -- Pass a value of `true` to enable query highlight markers (see below).
-     * * `page: i64`
-     * * `query: &str` -- Search query.
-     * * `sort: &str` -- Return matches sorted by either `score` or `timestamp`.
-     * * `sort_dir: &str` -- Change sort direction to ascending (`asc`) or descending (`desc`).
+     * * `params: &SearchMessageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn message(
+    pub async fn message_with(
         &self,
-        count: i64,
-        highlight: bool,
-        page: i64,
-        query: &str,
-        sort: &str,
-        sort_dir: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &SearchMessageParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if highlight {
+        if let Some(highlight) = &params.highlight {
             query_args.push(("highlight".to_string(), highlight.to_string()));
         }
-        if page > 0 {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
-        if !sort.is_empty() {
+        if let Some(sort) = &params.sort {
             query_args.push(("sort".to_string(), sort.to_string()));
         }
-        if !sort_dir.is_empty() {
+        if let Some(sort_dir) = &params.sort_dir {
             query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -62,4 +49,98 @@ impl Search {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `message_with` and pass the query parameters as a `SearchMessageParams` instead.
+     */
+    #[deprecated(note = "use `message_with` instead")]
+    pub async fn message(
+        &self,
+        count: i64,
+        highlight: bool,
+        page: i64,
+        query: &str,
+        sort: &str,
+        sort_dir: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = SearchMessageParams {
+            count: Some(count).filter(|v| *v > 0),
+            highlight: Some(highlight).filter(|v| *v),
+            page: Some(page).filter(|v| *v > 0),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            sort: Some(sort.to_string()).filter(|s| !s.is_empty()),
+            sort_dir: Some(sort_dir.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.message_with(&params).await
+    }
+}
+
+/// The query parameters of `Search::message_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SearchMessageParams {
+    /// Pass the number of results you want per "page". Maximum of `100`.
+    pub count: Option<i64>,
+    /// Pass a value of `true` to enable query highlight markers (see below).
+    pub highlight: Option<bool>,
+    pub page: Option<i64>,
+    /// Search query.
+    pub query: Option<String>,
+    /// Return matches sorted by either `score` or `timestamp`.
+    pub sort: Option<String>,
+    /// Change sort direction to ascending (`asc`) or descending (`desc`).
+    pub sort_dir: Option<String>,
+}
+
+impl SearchMessageParams {
+    pub fn count(mut self, count: i64) -> Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn highlight(mut self, highlight: bool) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    pub fn page(mut self, page: i64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+
+    pub fn sort<T: ToString>(mut self, sort: T) -> Self {
+        self.sort = Some(sort.to_string());
+        self
+    }
+
+    pub fn sort_dir<T: ToString>(mut self, sort_dir: T) -> Self {
+        self.sort_dir = Some(sort_dir.to_string());
+        self
+    }
+}
+
+/// The functions of [`Search`], for code that takes them as a trait, so that it can be
+/// tested with the `MockSearchApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait SearchApi: Send + Sync {
+    /// See [`Search::message_with`].
+    async fn message_with(
+        &self,
+        params: &SearchMessageParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl SearchApi for Search {
+    async fn message_with(
+        &self,
+        params: &SearchMessageParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Search::message_with(self, params).await
+    }
 }
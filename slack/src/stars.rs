@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Stars {
     pub client: Client,
@@ -23,9 +21,18 @@ impl Stars {
      *
      * * `token: &str` -- Authentication token. Requires scope: `stars:write`.
      */
-    pub async fn add(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn add(
+        &self,
+        body: &crate::types::StarsAddRequest,
+    ) -> Result<crate::types::StarsAddSchema> {
         let url = "/stars.add".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -37,30 +44,23 @@ impl Stars {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `stars:read`.
-     * * `count: &str`
-     * * `page: &str`
-     * * `cursor: &str` -- Parameter for pagination. Set `cursor` equal to the `next_cursor` attribute returned by the previous request's `response_metadata`. This parameter is optional, but pagination is mandatory: the default value simply fetches the first "page" of the collection. See [pagination](/docs/pagination) for more details.
-     * * `limit: i64` -- The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+     * * `params: &StarsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        count: &str,
-        page: &str,
-        cursor: &str,
-        limit: i64,
+        params: &StarsListParams,
     ) -> Result<crate::types::StarsListSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !page.is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -69,6 +69,26 @@ impl Stars {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `StarsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        count: &str,
+        page: &str,
+        cursor: &str,
+        limit: i64,
+    ) -> Result<crate::types::StarsListSchema> {
+        let params = StarsListParams {
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            page: Some(page.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/stars.remove` endpoint.
      *
@@ -80,8 +100,92 @@ impl Stars {
      *
      * * `token: &str` -- Authentication token. Requires scope: `stars:write`.
      */
-    pub async fn remove(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn remove(
+        &self,
+        body: &crate::types::StarsRemoveRequest,
+    ) -> Result<crate::types::StarsRemoveSchema> {
         let url = "/stars.remove".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Stars::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct StarsListParams {
+    pub count: Option<String>,
+    /// Parameter for pagination. Set `cursor` equal to the `next_cursor` attribute returned by the previous request's `response_metadata`. This parameter is optional, but pagination is mandatory: the default value simply fetches the first "page" of the collection. See [pagination](/docs/pagination) for more details.
+    pub cursor: Option<String>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<i64>,
+    pub page: Option<String>,
+}
+
+impl StarsListParams {
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page<T: ToString>(mut self, page: T) -> Self {
+        self.page = Some(page.to_string());
+        self
+    }
+}
+
+/// The functions of [`Stars`], for code that takes them as a trait, so that it can be
+/// tested with the `MockStarsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait StarsApi: Send + Sync {
+    /// See [`Stars::add`].
+    async fn add(
+        &self,
+        body: &crate::types::StarsAddRequest,
+    ) -> Result<crate::types::StarsAddSchema>;
+
+    /// See [`Stars::list_with`].
+    async fn list_with(&self, params: &StarsListParams) -> Result<crate::types::StarsListSchema>;
+
+    /// See [`Stars::remove`].
+    async fn remove(
+        &self,
+        body: &crate::types::StarsRemoveRequest,
+    ) -> Result<crate::types::StarsRemoveSchema>;
+}
+
+#[async_trait::async_trait]
+impl StarsApi for Stars {
+    async fn add(
+        &self,
+        body: &crate::types::StarsAddRequest,
+    ) -> Result<crate::types::StarsAddSchema> {
+        Stars::add(self, body).await
+    }
+
+    async fn list_with(&self, params: &StarsListParams) -> Result<crate::types::StarsListSchema> {
+        Stars::list_with(self, params).await
+    }
+
+    async fn remove(
+        &self,
+        body: &crate::types::StarsRemoveRequest,
+    ) -> Result<crate::types::StarsRemoveSchema> {
+        Stars::remove(self, body).await
     }
 }
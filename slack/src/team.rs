@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Team {
     pub client: Client,
@@ -21,25 +19,20 @@ impl Team {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin`.
-     * * `before: &str` -- End of time range of logs to include in results (inclusive).
-     * * `count: &str`
-     * * `page: &str`
+     * * `params: &TeamAccessLogParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn access_log(
+    pub async fn access_log_with(
         &self,
-        before: &str,
-        count: &str,
-        page: &str,
+        params: &TeamAccessLogParams,
     ) -> Result<crate::types::TeamAccessLogsSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !before.is_empty() {
+        if let Some(before) = &params.before {
             query_args.push(("before".to_string(), before.to_string()));
         }
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !page.is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -48,6 +41,24 @@ impl Team {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `access_log_with` and pass the query parameters as a `TeamAccessLogParams` instead.
+     */
+    #[deprecated(note = "use `access_log_with` instead")]
+    pub async fn access_log(
+        &self,
+        before: &str,
+        count: &str,
+        page: &str,
+    ) -> Result<crate::types::TeamAccessLogsSchema> {
+        let params = TeamAccessLogParams {
+            before: Some(before.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.access_log_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/team.billableInfo` endpoint.
      *
@@ -57,12 +68,14 @@ impl Team {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin`.
-     * * `user: &str` -- A user to retrieve the billable information for. Defaults to all users.
+     * * `params: &TeamBillableInfoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn billable_info(&self, user: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn billable_info_with(
+        &self,
+        params: &TeamBillableInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !user.is_empty() {
+        if let Some(user) = &params.user {
             query_args.push(("user".to_string(), user.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -71,6 +84,17 @@ impl Team {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `billable_info_with` and pass the query parameters as a `TeamBillableInfoParams` instead.
+     */
+    #[deprecated(note = "use `billable_info_with` instead")]
+    pub async fn billable_info(&self, user: &str) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = TeamBillableInfoParams {
+            user: Some(user.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.billable_info_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/team.info` endpoint.
      *
@@ -80,12 +104,11 @@ impl Team {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `team:read`.
-     * * `team: &str` -- Team to get info on, if omitted, will return information about the current team. Will only return team that the authenticated token is allowed to see through external shared channels.
+     * * `params: &TeamInfoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn info(&self, team: &str) -> Result<crate::types::TeamInfoSchema> {
+    pub async fn info_with(&self, params: &TeamInfoParams) -> Result<crate::types::TeamInfoSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !team.is_empty() {
+        if let Some(team) = &params.team {
             query_args.push(("team".to_string(), team.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -94,6 +117,17 @@ impl Team {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `info_with` and pass the query parameters as a `TeamInfoParams` instead.
+     */
+    #[deprecated(note = "use `info_with` instead")]
+    pub async fn info(&self, team: &str) -> Result<crate::types::TeamInfoSchema> {
+        let params = TeamInfoParams {
+            team: Some(team.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.info_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/team.integrationLogs` endpoint.
      *
@@ -103,40 +137,29 @@ impl Team {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `admin`.
-     * * `app_id: &str` -- Filter logs to this Slack app. Defaults to all logs.
-     * * `change_type: &str` -- Filter logs with this change type. Defaults to all logs.
-     * * `count: &str`
-     * * `page: &str`
-     * * `service_id: &str` -- Filter logs to this service. Defaults to all logs.
-     * * `user: &str` -- Filter logs generated by this user’s actions. Defaults to all logs.
+     * * `params: &TeamIntegrationLogParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn integration_log(
+    pub async fn integration_log_with(
         &self,
-        app_id: &str,
-        change_type: &str,
-        count: &str,
-        page: &str,
-        service_id: &str,
-        user: &str,
+        params: &TeamIntegrationLogParams,
     ) -> Result<crate::types::TeamIntegrationLogsSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !app_id.is_empty() {
+        if let Some(app_id) = &params.app_id {
             query_args.push(("app_id".to_string(), app_id.to_string()));
         }
-        if !change_type.is_empty() {
+        if let Some(change_type) = &params.change_type {
             query_args.push(("change_type".to_string(), change_type.to_string()));
         }
-        if !count.is_empty() {
+        if let Some(count) = &params.count {
             query_args.push(("count".to_string(), count.to_string()));
         }
-        if !page.is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if !service_id.is_empty() {
+        if let Some(service_id) = &params.service_id {
             query_args.push(("service_id".to_string(), service_id.to_string()));
         }
-        if !user.is_empty() {
+        if let Some(user) = &params.user {
             query_args.push(("user".to_string(), user.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -144,4 +167,184 @@ impl Team {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `integration_log_with` and pass the query parameters as a `TeamIntegrationLogParams` instead.
+     */
+    #[deprecated(note = "use `integration_log_with` instead")]
+    pub async fn integration_log(
+        &self,
+        app_id: &str,
+        change_type: &str,
+        count: &str,
+        page: &str,
+        service_id: &str,
+        user: &str,
+    ) -> Result<crate::types::TeamIntegrationLogsSchema> {
+        let params = TeamIntegrationLogParams {
+            app_id: Some(app_id.to_string()).filter(|s| !s.is_empty()),
+            change_type: Some(change_type.to_string()).filter(|s| !s.is_empty()),
+            count: Some(count.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page.to_string()).filter(|s| !s.is_empty()),
+            service_id: Some(service_id.to_string()).filter(|s| !s.is_empty()),
+            user: Some(user.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.integration_log_with(&params).await
+    }
+}
+
+/// The query parameters of `Team::access_log_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamAccessLogParams {
+    /// End of time range of logs to include in results (inclusive).
+    pub before: Option<String>,
+    pub count: Option<String>,
+    pub page: Option<String>,
+}
+
+impl TeamAccessLogParams {
+    pub fn before<T: ToString>(mut self, before: T) -> Self {
+        self.before = Some(before.to_string());
+        self
+    }
+
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn page<T: ToString>(mut self, page: T) -> Self {
+        self.page = Some(page.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Team::billable_info_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamBillableInfoParams {
+    /// A user to retrieve the billable information for. Defaults to all users.
+    pub user: Option<String>,
+}
+
+impl TeamBillableInfoParams {
+    pub fn user<T: ToString>(mut self, user: T) -> Self {
+        self.user = Some(user.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Team::info_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamInfoParams {
+    /// Team to get info on, if omitted, will return information about the current team. Will only return team that the authenticated token is allowed to see through external shared channels.
+    pub team: Option<String>,
+}
+
+impl TeamInfoParams {
+    pub fn team<T: ToString>(mut self, team: T) -> Self {
+        self.team = Some(team.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Team::integration_log_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamIntegrationLogParams {
+    /// Filter logs to this Slack app. Defaults to all logs.
+    pub app_id: Option<String>,
+    /// Filter logs with this change type. Defaults to all logs.
+    pub change_type: Option<String>,
+    pub count: Option<String>,
+    pub page: Option<String>,
+    /// Filter logs to this service. Defaults to all logs.
+    pub service_id: Option<String>,
+    /// Filter logs generated by this user’s actions. Defaults to all logs.
+    pub user: Option<String>,
+}
+
+impl TeamIntegrationLogParams {
+    pub fn app_id<T: ToString>(mut self, app_id: T) -> Self {
+        self.app_id = Some(app_id.to_string());
+        self
+    }
+
+    pub fn change_type<T: ToString>(mut self, change_type: T) -> Self {
+        self.change_type = Some(change_type.to_string());
+        self
+    }
+
+    pub fn count<T: ToString>(mut self, count: T) -> Self {
+        self.count = Some(count.to_string());
+        self
+    }
+
+    pub fn page<T: ToString>(mut self, page: T) -> Self {
+        self.page = Some(page.to_string());
+        self
+    }
+
+    pub fn service_id<T: ToString>(mut self, service_id: T) -> Self {
+        self.service_id = Some(service_id.to_string());
+        self
+    }
+
+    pub fn user<T: ToString>(mut self, user: T) -> Self {
+        self.user = Some(user.to_string());
+        self
+    }
+}
+
+/// The functions of [`Team`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTeamApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TeamApi: Send + Sync {
+    /// See [`Team::access_log_with`].
+    async fn access_log_with(
+        &self,
+        params: &TeamAccessLogParams,
+    ) -> Result<crate::types::TeamAccessLogsSchema>;
+
+    /// See [`Team::billable_info_with`].
+    async fn billable_info_with(
+        &self,
+        params: &TeamBillableInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`Team::info_with`].
+    async fn info_with(&self, params: &TeamInfoParams) -> Result<crate::types::TeamInfoSchema>;
+
+    /// See [`Team::integration_log_with`].
+    async fn integration_log_with(
+        &self,
+        params: &TeamIntegrationLogParams,
+    ) -> Result<crate::types::TeamIntegrationLogsSchema>;
+}
+
+#[async_trait::async_trait]
+impl TeamApi for Team {
+    async fn access_log_with(
+        &self,
+        params: &TeamAccessLogParams,
+    ) -> Result<crate::types::TeamAccessLogsSchema> {
+        Team::access_log_with(self, params).await
+    }
+
+    async fn billable_info_with(
+        &self,
+        params: &TeamBillableInfoParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Team::billable_info_with(self, params).await
+    }
+
+    async fn info_with(&self, params: &TeamInfoParams) -> Result<crate::types::TeamInfoSchema> {
+        Team::info_with(self, params).await
+    }
+
+    async fn integration_log_with(
+        &self,
+        params: &TeamIntegrationLogParams,
+    ) -> Result<crate::types::TeamIntegrationLogsSchema> {
+        Team::integration_log_with(self, params).await
+    }
 }
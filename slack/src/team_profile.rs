@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct TeamProfile {
     pub client: Client,
@@ -21,12 +19,14 @@ impl TeamProfile {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `users.profile:read`.
-     * * `visibility: &str` -- Filter by visibility.
+     * * `params: &TeamProfileGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(&self, visibility: &str) -> Result<crate::types::TeamProfileGetSuccessSchema> {
+    pub async fn get_with(
+        &self,
+        params: &TeamProfileGetParams,
+    ) -> Result<crate::types::TeamProfileGetSuccessSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !visibility.is_empty() {
+        if let Some(visibility) = &params.visibility {
             query_args.push(("visibility".to_string(), visibility.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -34,4 +34,51 @@ impl TeamProfile {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `TeamProfileGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(&self, visibility: &str) -> Result<crate::types::TeamProfileGetSuccessSchema> {
+        let params = TeamProfileGetParams {
+            visibility: Some(visibility.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(&params).await
+    }
+}
+
+/// The query parameters of `TeamProfile::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TeamProfileGetParams {
+    /// Filter by visibility.
+    pub visibility: Option<String>,
+}
+
+impl TeamProfileGetParams {
+    pub fn visibility<T: ToString>(mut self, visibility: T) -> Self {
+        self.visibility = Some(visibility.to_string());
+        self
+    }
+}
+
+/// The functions of [`TeamProfile`], for code that takes them as a trait, so that it can be
+/// tested with the `MockTeamProfileApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait TeamProfileApi: Send + Sync {
+    /// See [`TeamProfile::get_with`].
+    async fn get_with(
+        &self,
+        params: &TeamProfileGetParams,
+    ) -> Result<crate::types::TeamProfileGetSuccessSchema>;
+}
+
+#[async_trait::async_trait]
+impl TeamProfileApi for TeamProfile {
+    async fn get_with(
+        &self,
+        params: &TeamProfileGetParams,
+    ) -> Result<crate::types::TeamProfileGetSuccessSchema> {
+        TeamProfile::get_with(self, params).await
+    }
 }
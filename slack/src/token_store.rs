@@ -0,0 +1,127 @@
+//! Keeping the tokens of a client across restarts.
+//!
+//! A client loads its tokens from its `TokenStore` when given one, with
+//! `Client::with_token_store`, and saves them to it each time it gets new ones, from
+//! `get_access_token` or `refresh_access_token`. Providers that rotate refresh tokens
+//! invalidate the old one on each refresh, so a process that starts again with the
+//! tokens it was first given can no longer refresh them.
+
+use std::{
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+
+use crate::{Error, Result};
+
+/// The tokens a `TokenStore` keeps.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredToken {
+    pub access_token: String,
+    #[serde(default)]
+    pub refresh_token: String,
+    /// When the client refreshes the access token, if it knows.
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// Where a client keeps its tokens.
+#[async_trait::async_trait]
+pub trait TokenStore: Send + Sync {
+    /// The tokens saved last, if any.
+    async fn load(&self) -> Result<Option<StoredToken>>;
+
+    /// Save new tokens, replacing the ones saved before.
+    async fn save(&self, token: &StoredToken) -> Result<()>;
+
+    /// Called after the client refreshed its access token and saved the new tokens.
+    /// Does nothing unless implemented.
+    async fn on_refresh(&self, _token: &StoredToken) {}
+}
+
+/// Keeps the tokens in memory, for as long as the client lives. The store of clients
+/// not given one.
+#[derive(Debug, Default)]
+pub struct MemoryTokenStore {
+    token: Mutex<Option<StoredToken>>,
+}
+
+impl MemoryTokenStore {
+    pub fn new() -> Self {
+        Default::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenStore for MemoryTokenStore {
+    async fn load(&self) -> Result<Option<StoredToken>> {
+        Ok(self.token.lock().unwrap().clone())
+    }
+
+    async fn save(&self, token: &StoredToken) -> Result<()> {
+        *self.token.lock().unwrap() = Some(token.clone());
+        Ok(())
+    }
+}
+
+/// Keeps the tokens in a JSON file, only readable by its owner on Unix. There are no
+/// tokens until the file exists.
+#[derive(Debug, Clone)]
+pub struct FileTokenStore {
+    path: PathBuf,
+}
+
+impl FileTokenStore {
+    pub fn new<P>(path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        FileTokenStore { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn error(&self, e: impl std::fmt::Display) -> Error {
+        Error::Auth(format!("token store {}: {}", self.path.display(), e))
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenStore for FileTokenStore {
+    async fn load(&self) -> Result<Option<StoredToken>> {
+        let json = match tokio::fs::read(&self.path).await {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(self.error(e)),
+        };
+        serde_json::from_slice(&json)
+            .map(Some)
+            .map_err(|e| self.error(e))
+    }
+
+    async fn save(&self, token: &StoredToken) -> Result<()> {
+        let json = serde_json::to_vec_pretty(token).map_err(|e| self.error(e))?;
+
+        // Write the tokens next to the file, then move them over it, so a crash
+        // halfway through doesn't lose them.
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+
+        let mut options = tokio::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+        let mut file = options.open(&tmp).await.map_err(|e| self.error(e))?;
+        file.write_all(&json).await.map_err(|e| self.error(e))?;
+        file.sync_all().await.map_err(|e| self.error(e))?;
+        tokio::fs::rename(&tmp, &self.path)
+            .await
+            .map_err(|e| self.error(e))
+    }
+}
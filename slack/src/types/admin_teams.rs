@@ -0,0 +1,23 @@
+//! The data types of the `admin_teams` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AdminTeamsCreateRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_discoverability: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_domain: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub team_name: String,
+}
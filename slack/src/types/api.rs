@@ -0,0 +1,24 @@
+//! The data types of the `api` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+/// Schema for successful response api.test method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApiTestSuccessSchema {}
+
+/// Schema for successful response api.test method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct ApiTestSuccessSchemaData {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+    /**
+     * The properties of the object that are not listed above.
+     */
+    #[serde(flatten)]
+    pub additional_properties: std::collections::BTreeMap<String, ApiTestSuccessSchema>,
+}
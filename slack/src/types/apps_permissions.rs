@@ -0,0 +1,115 @@
+//! The data types of the `apps_permissions` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppHome {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ObjsResources>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Channel {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ObjsResources>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Group {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ObjsResources>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Im {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ObjsResources>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Mpim {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resources: Option<ObjsResources>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Team {
+    #[serde()]
+    pub resources: ObjsResources,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub scopes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Info {
+    #[serde()]
+    pub app_home: AppHome,
+    #[serde()]
+    pub channel: Channel,
+    #[serde()]
+    pub group: Group,
+    #[serde()]
+    pub im: Im,
+    #[serde()]
+    pub mpim: Mpim,
+    #[serde()]
+    pub team: Team,
+}
+
+/// Schema for successful response from apps.permissions.info method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsPermissionsInfoSchema {
+    #[serde()]
+    pub info: Info,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+}
+
+/// Schema for successful response from apps.permissions.request method
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct AppsPermissionsRequestSchema {
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub ok: bool,
+}
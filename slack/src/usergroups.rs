@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Usergroups {
     pub client: Client,
@@ -23,9 +21,18 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn create(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+    pub async fn create(
+        &self,
+        body: &crate::types::UsergroupsCreateRequest,
+    ) -> Result<crate::types::UsergroupsCreateSchema> {
         let url = "/usergroups.create".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -39,9 +46,18 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn disable(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+    pub async fn disable(
+        &self,
+        body: &crate::types::UsergroupsDisableRequest,
+    ) -> Result<crate::types::UsergroupsDisableSchema> {
         let url = "/usergroups.disable".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -55,9 +71,18 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn enable(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+    pub async fn enable(
+        &self,
+        body: &crate::types::UsergroupsEnableRequest,
+    ) -> Result<crate::types::UsergroupsEnableSchema> {
         let url = "/usergroups.enable".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -69,25 +94,20 @@ impl Usergroups {
      *
      * **Parameters:**
      *
-     * * `include_users: bool` -- Include the list of users for each User Group.
-     * * `token: &str` -- Authentication token. Requires scope: `usergroups:read`.
-     * * `include_count: bool` -- Include the number of users in each User Group.
-     * * `include_disabled: bool` -- Include disabled User Groups.
+     * * `params: &UsergroupsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        include_users: bool,
-        include_count: bool,
-        include_disabled: bool,
+        params: &UsergroupsListParams,
     ) -> Result<crate::types::UsergroupsListSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_count {
+        if let Some(include_count) = &params.include_count {
             query_args.push(("include_count".to_string(), include_count.to_string()));
         }
-        if include_disabled {
+        if let Some(include_disabled) = &params.include_disabled {
             query_args.push(("include_disabled".to_string(), include_disabled.to_string()));
         }
-        if include_users {
+        if let Some(include_users) = &params.include_users {
             query_args.push(("include_users".to_string(), include_users.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -96,6 +116,24 @@ impl Usergroups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `UsergroupsListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        include_users: bool,
+        include_count: bool,
+        include_disabled: bool,
+    ) -> Result<crate::types::UsergroupsListSchema> {
+        let params = UsergroupsListParams {
+            include_count: Some(include_count).filter(|v| *v),
+            include_disabled: Some(include_disabled).filter(|v| *v),
+            include_users: Some(include_users).filter(|v| *v),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/usergroups.update` endpoint.
      *
@@ -107,8 +145,119 @@ impl Usergroups {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn update(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::UsergroupsUpdateRequest,
+    ) -> Result<crate::types::UsergroupsUpdateSchema> {
         let url = "/usergroups.update".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Usergroups::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsergroupsListParams {
+    /// Include the number of users in each User Group.
+    pub include_count: Option<bool>,
+    /// Include disabled User Groups.
+    pub include_disabled: Option<bool>,
+    /// Include the list of users for each User Group.
+    pub include_users: Option<bool>,
+}
+
+impl UsergroupsListParams {
+    pub fn include_count(mut self, include_count: bool) -> Self {
+        self.include_count = Some(include_count);
+        self
+    }
+
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.include_disabled = Some(include_disabled);
+        self
+    }
+
+    pub fn include_users(mut self, include_users: bool) -> Self {
+        self.include_users = Some(include_users);
+        self
+    }
+}
+
+/// The functions of [`Usergroups`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUsergroupsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UsergroupsApi: Send + Sync {
+    /// See [`Usergroups::create`].
+    async fn create(
+        &self,
+        body: &crate::types::UsergroupsCreateRequest,
+    ) -> Result<crate::types::UsergroupsCreateSchema>;
+
+    /// See [`Usergroups::disable`].
+    async fn disable(
+        &self,
+        body: &crate::types::UsergroupsDisableRequest,
+    ) -> Result<crate::types::UsergroupsDisableSchema>;
+
+    /// See [`Usergroups::enable`].
+    async fn enable(
+        &self,
+        body: &crate::types::UsergroupsEnableRequest,
+    ) -> Result<crate::types::UsergroupsEnableSchema>;
+
+    /// See [`Usergroups::list_with`].
+    async fn list_with(
+        &self,
+        params: &UsergroupsListParams,
+    ) -> Result<crate::types::UsergroupsListSchema>;
+
+    /// See [`Usergroups::update`].
+    async fn update(
+        &self,
+        body: &crate::types::UsergroupsUpdateRequest,
+    ) -> Result<crate::types::UsergroupsUpdateSchema>;
+}
+
+#[async_trait::async_trait]
+impl UsergroupsApi for Usergroups {
+    async fn create(
+        &self,
+        body: &crate::types::UsergroupsCreateRequest,
+    ) -> Result<crate::types::UsergroupsCreateSchema> {
+        Usergroups::create(self, body).await
+    }
+
+    async fn disable(
+        &self,
+        body: &crate::types::UsergroupsDisableRequest,
+    ) -> Result<crate::types::UsergroupsDisableSchema> {
+        Usergroups::disable(self, body).await
+    }
+
+    async fn enable(
+        &self,
+        body: &crate::types::UsergroupsEnableRequest,
+    ) -> Result<crate::types::UsergroupsEnableSchema> {
+        Usergroups::enable(self, body).await
+    }
+
+    async fn list_with(
+        &self,
+        params: &UsergroupsListParams,
+    ) -> Result<crate::types::UsergroupsListSchema> {
+        Usergroups::list_with(self, params).await
+    }
+
+    async fn update(
+        &self,
+        body: &crate::types::UsergroupsUpdateRequest,
+    ) -> Result<crate::types::UsergroupsUpdateSchema> {
+        Usergroups::update(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct UsergroupsUsers {
     pub client: Client,
@@ -21,20 +19,17 @@ impl UsergroupsUsers {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `usergroups:read`.
-     * * `include_disabled: bool` -- Allow results that involve disabled User Groups.
-     * * `usergroup: &str` -- The encoded ID of the User Group to update.
+     * * `params: &UsergroupsUsersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        include_disabled: bool,
-        usergroup: &str,
+        params: &UsergroupsUsersListParams,
     ) -> Result<crate::types::UsergroupsUsersListSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_disabled {
+        if let Some(include_disabled) = &params.include_disabled {
             query_args.push(("include_disabled".to_string(), include_disabled.to_string()));
         }
-        if !usergroup.is_empty() {
+        if let Some(usergroup) = &params.usergroup {
             query_args.push(("usergroup".to_string(), usergroup.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -43,6 +38,22 @@ impl UsergroupsUsers {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `UsergroupsUsersListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        include_disabled: bool,
+        usergroup: &str,
+    ) -> Result<crate::types::UsergroupsUsersListSchema> {
+        let params = UsergroupsUsersListParams {
+            include_disabled: Some(include_disabled).filter(|v| *v),
+            usergroup: Some(usergroup.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/usergroups.users.update` endpoint.
      *
@@ -54,8 +65,73 @@ impl UsergroupsUsers {
      *
      * * `token: &str` -- Authentication token. Requires scope: `usergroups:write`.
      */
-    pub async fn update(&self) -> Result<crate::types::UsergroupsCreateSchema> {
+    pub async fn update(
+        &self,
+        body: &crate::types::UsergroupsUsersUpdateRequest,
+    ) -> Result<crate::types::UsergroupsUsersUpdateSchema> {
         let url = "/usergroups.users.update".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `UsergroupsUsers::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsergroupsUsersListParams {
+    /// Allow results that involve disabled User Groups.
+    pub include_disabled: Option<bool>,
+    /// The encoded ID of the User Group to update.
+    pub usergroup: Option<String>,
+}
+
+impl UsergroupsUsersListParams {
+    pub fn include_disabled(mut self, include_disabled: bool) -> Self {
+        self.include_disabled = Some(include_disabled);
+        self
+    }
+
+    pub fn usergroup<T: ToString>(mut self, usergroup: T) -> Self {
+        self.usergroup = Some(usergroup.to_string());
+        self
+    }
+}
+
+/// The functions of [`UsergroupsUsers`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUsergroupsUsersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UsergroupsUsersApi: Send + Sync {
+    /// See [`UsergroupsUsers::list_with`].
+    async fn list_with(
+        &self,
+        params: &UsergroupsUsersListParams,
+    ) -> Result<crate::types::UsergroupsUsersListSchema>;
+
+    /// See [`UsergroupsUsers::update`].
+    async fn update(
+        &self,
+        body: &crate::types::UsergroupsUsersUpdateRequest,
+    ) -> Result<crate::types::UsergroupsUsersUpdateSchema>;
+}
+
+#[async_trait::async_trait]
+impl UsergroupsUsersApi for UsergroupsUsers {
+    async fn list_with(
+        &self,
+        params: &UsergroupsUsersListParams,
+    ) -> Result<crate::types::UsergroupsUsersListSchema> {
+        UsergroupsUsers::list_with(self, params).await
+    }
+
+    async fn update(
+        &self,
+        body: &crate::types::UsergroupsUsersUpdateRequest,
+    ) -> Result<crate::types::UsergroupsUsersUpdateSchema> {
+        UsergroupsUsers::update(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Users {
     pub client: Client,
@@ -21,35 +19,26 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `conversations:read`.
-     * * `user: &str` -- Browse conversations by a specific user ID's membership. Non-public channels are restricted to those where the calling user shares membership.
-     * * `types: &str` -- Mix and match channel types by providing a comma-separated list of any combination of `public_channel`, `private_channel`, `mpim`, `im`.
-     * * `exclude_archived: bool` -- Set to `true` to exclude archived channels from the list.
-     * * `limit: i64` -- The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached. Must be an integer no larger than 1000.
-     * * `cursor: &str` -- Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+     * * `params: &UsersConversationParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn conversation(
+    pub async fn conversation_with(
         &self,
-        user: &str,
-        types: &str,
-        exclude_archived: bool,
-        limit: i64,
-        cursor: &str,
+        params: &UsersConversationParams,
     ) -> Result<crate::types::UsersConversationsSuccessSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if exclude_archived {
+        if let Some(exclude_archived) = &params.exclude_archived {
             query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !types.is_empty() {
+        if let Some(types) = &params.types {
             query_args.push(("types".to_string(), types.to_string()));
         }
-        if !user.is_empty() {
+        if let Some(user) = &params.user {
             query_args.push(("user".to_string(), user.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -58,6 +47,28 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `conversation_with` and pass the query parameters as a `UsersConversationParams` instead.
+     */
+    #[deprecated(note = "use `conversation_with` instead")]
+    pub async fn conversation(
+        &self,
+        user: &str,
+        types: &str,
+        exclude_archived: bool,
+        limit: i64,
+        cursor: &str,
+    ) -> Result<crate::types::UsersConversationsSuccessSchema> {
+        let params = UsersConversationParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            exclude_archived: Some(exclude_archived).filter(|v| *v),
+            limit: Some(limit).filter(|v| *v > 0),
+            types: Some(types.to_string()).filter(|s| !s.is_empty()),
+            user: Some(user.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.conversation_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/users.deletePhoto` endpoint.
      *
@@ -65,9 +76,18 @@ impl Users {
      *
      * FROM: <https://api.slack.com/methods/users.deletePhoto>
      */
-    pub async fn delete_photo(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn delete_photo(
+        &self,
+        body: &crate::types::UsersDeletePhotoRequest,
+    ) -> Result<crate::types::UsersDeletePhotoSchema> {
         let url = "/users.deletePhoto".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -79,15 +99,14 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `users:read`.
-     * * `user: &str` -- User to get presence info on. Defaults to the authed user.
+     * * `params: &UsersGetPresenceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_presence(
+    pub async fn get_presence_with(
         &self,
-        user: &str,
+        params: &UsersGetPresenceParams,
     ) -> Result<crate::types::ApiMethodUsersGetPresence> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !user.is_empty() {
+        if let Some(user) = &params.user {
             query_args.push(("user".to_string(), user.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -96,6 +115,20 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_presence_with` and pass the query parameters as a `UsersGetPresenceParams` instead.
+     */
+    #[deprecated(note = "use `get_presence_with` instead")]
+    pub async fn get_presence(
+        &self,
+        user: &str,
+    ) -> Result<crate::types::ApiMethodUsersGetPresence> {
+        let params = UsersGetPresenceParams {
+            user: Some(user.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_presence_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/users.identity` endpoint.
      *
@@ -126,6 +159,39 @@ impl Users {
         self.client.get_all_pages(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/users.identity` endpoint.
+     *
+     * As opposed to `get_all_identity`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Get a user's identity.
+     *
+     * FROM: <https://api.slack.com/methods/users.identity>
+     */
+    pub fn identity_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UsersIdentityResponseAnyOf> {
+        let url = "/users.identity".to_string();
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let (link, items) = match page {
+                    Some(page) => client.get_pages_url(&reqwest::Url::parse(&page)?).await?,
+                    None => client.get_pages(&url).await?,
+                };
+
+                Ok(crate::pagination::Page::new(
+                    items,
+                    link.as_ref().and_then(crate::utils::next_link),
+                ))
+            }
+        })
+    }
+
     /**
      * This function performs a `GET` to the `/users.info` endpoint.
      *
@@ -135,20 +201,17 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `users:read`.
-     * * `include_locale: bool` -- Set this to `true` to receive the locale for this user. Defaults to `false`.
-     * * `user: &str` -- User to get info on.
+     * * `params: &UsersInfoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn info(
+    pub async fn info_with(
         &self,
-        include_locale: bool,
-        user: &str,
+        params: &UsersInfoParams,
     ) -> Result<crate::types::UsersInfoSuccessSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_locale {
+        if let Some(include_locale) = &params.include_locale {
             query_args.push(("include_locale".to_string(), include_locale.to_string()));
         }
-        if !user.is_empty() {
+        if let Some(user) = &params.user {
             query_args.push(("user".to_string(), user.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -157,6 +220,22 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `info_with` and pass the query parameters as a `UsersInfoParams` instead.
+     */
+    #[deprecated(note = "use `info_with` instead")]
+    pub async fn info(
+        &self,
+        include_locale: bool,
+        user: &str,
+    ) -> Result<crate::types::UsersInfoSuccessSchema> {
+        let params = UsersInfoParams {
+            include_locale: Some(include_locale).filter(|v| *v),
+            user: Some(user.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.info_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/users.list` endpoint.
      *
@@ -166,25 +245,20 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `users:read`.
-     * * `limit: i64` -- The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached. Providing no `limit` value will result in Slack attempting to deliver you the entire result set. If the collection is too large you may experience `limit_required` or HTTP 500 errors.
-     * * `cursor: &str` -- Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
-     * * `include_locale: bool` -- Set this to `true` to receive the locale for users. Defaults to `false`.
+     * * `params: &UsersListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        limit: i64,
-        cursor: &str,
-        include_locale: bool,
+        params: &UsersListParams,
     ) -> Result<crate::types::UsersListSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cursor.is_empty() {
+        if let Some(cursor) = &params.cursor {
             query_args.push(("cursor".to_string(), cursor.to_string()));
         }
-        if include_locale {
+        if let Some(include_locale) = &params.include_locale {
             query_args.push(("include_locale".to_string(), include_locale.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -193,6 +267,24 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `UsersListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        limit: i64,
+        cursor: &str,
+        include_locale: bool,
+    ) -> Result<crate::types::UsersListSchema> {
+        let params = UsersListParams {
+            cursor: Some(cursor.to_string()).filter(|s| !s.is_empty()),
+            include_locale: Some(include_locale).filter(|v| *v),
+            limit: Some(limit).filter(|v| *v > 0),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/users.lookupByEmail` endpoint.
      *
@@ -202,12 +294,14 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `users:read.email`.
-     * * `email: &str` -- An email address belonging to a user in the workspace.
+     * * `params: &UsersLookupEmailParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn lookup_email(&self, email: &str) -> Result<crate::types::UsersInfoSuccessSchema> {
+    pub async fn lookup_email_with(
+        &self,
+        params: &UsersLookupEmailParams,
+    ) -> Result<crate::types::UsersLookupByEmailSuccessSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !email.is_empty() {
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -216,6 +310,20 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `lookup_email_with` and pass the query parameters as a `UsersLookupEmailParams` instead.
+     */
+    #[deprecated(note = "use `lookup_email_with` instead")]
+    pub async fn lookup_email(
+        &self,
+        email: &str,
+    ) -> Result<crate::types::UsersLookupByEmailSuccessSchema> {
+        let params = UsersLookupEmailParams {
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.lookup_email_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/users.setActive` endpoint.
      *
@@ -227,7 +335,7 @@ impl Users {
      *
      * * `token: &str` -- Authentication token. Requires scope: `users:write`.
      */
-    pub async fn set_active(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_active(&self) -> Result<crate::types::UsersSetActiveSchema> {
         let url = "/users.setActive".to_string();
         self.client.post(&url, None).await
     }
@@ -239,9 +347,18 @@ impl Users {
      *
      * FROM: <https://api.slack.com/methods/users.setPhoto>
      */
-    pub async fn set_photo(&self) -> Result<crate::types::UsersSetPhotoSchema> {
+    pub async fn set_photo(
+        &self,
+        body: &crate::types::UsersSetPhotoRequest,
+    ) -> Result<crate::types::UsersSetPhotoSchema> {
         let url = "/users.setPhoto".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -255,8 +372,279 @@ impl Users {
      *
      * * `token: &str` -- Authentication token. Requires scope: `users:write`.
      */
-    pub async fn set_presence(&self) -> Result<crate::types::DndEndSchema> {
+    pub async fn set_presence(
+        &self,
+        body: &crate::types::UsersSetPresenceRequest,
+    ) -> Result<crate::types::UsersSetPresenceSchema> {
         let url = "/users.setPresence".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Users::conversation_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersConversationParams {
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+    pub cursor: Option<String>,
+    /// Set to `true` to exclude archived channels from the list.
+    pub exclude_archived: Option<bool>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached. Must be an integer no larger than 1000.
+    pub limit: Option<i64>,
+    /// Mix and match channel types by providing a comma-separated list of any combination of `public_channel`, `private_channel`, `mpim`, `im`.
+    pub types: Option<String>,
+    /// Browse conversations by a specific user ID's membership. Non-public channels are restricted to those where the calling user shares membership.
+    pub user: Option<String>,
+}
+
+impl UsersConversationParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn exclude_archived(mut self, exclude_archived: bool) -> Self {
+        self.exclude_archived = Some(exclude_archived);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn types<T: ToString>(mut self, types: T) -> Self {
+        self.types = Some(types.to_string());
+        self
+    }
+
+    pub fn user<T: ToString>(mut self, user: T) -> Self {
+        self.user = Some(user.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Users::get_presence_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersGetPresenceParams {
+    /// User to get presence info on. Defaults to the authed user.
+    pub user: Option<String>,
+}
+
+impl UsersGetPresenceParams {
+    pub fn user<T: ToString>(mut self, user: T) -> Self {
+        self.user = Some(user.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Users::info_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersInfoParams {
+    /// Set this to `true` to receive the locale for this user. Defaults to `false`.
+    pub include_locale: Option<bool>,
+    /// User to get info on.
+    pub user: Option<String>,
+}
+
+impl UsersInfoParams {
+    pub fn include_locale(mut self, include_locale: bool) -> Self {
+        self.include_locale = Some(include_locale);
+        self
+    }
+
+    pub fn user<T: ToString>(mut self, user: T) -> Self {
+        self.user = Some(user.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Users::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersListParams {
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See [pagination](/docs/pagination) for more detail.
+    pub cursor: Option<String>,
+    /// Set this to `true` to receive the locale for users. Defaults to `false`.
+    pub include_locale: Option<bool>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached. Providing no `limit` value will result in Slack attempting to deliver you the entire result set. If the collection is too large you may experience `limit_required` or HTTP 500 errors.
+    pub limit: Option<i64>,
+}
+
+impl UsersListParams {
+    pub fn cursor<T: ToString>(mut self, cursor: T) -> Self {
+        self.cursor = Some(cursor.to_string());
+        self
+    }
+
+    pub fn include_locale(mut self, include_locale: bool) -> Self {
+        self.include_locale = Some(include_locale);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// The query parameters of `Users::lookup_email_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersLookupEmailParams {
+    /// An email address belonging to a user in the workspace.
+    pub email: Option<String>,
+}
+
+impl UsersLookupEmailParams {
+    pub fn email<T: ToString>(mut self, email: T) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+}
+
+/// The functions of [`Users`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUsersApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UsersApi: Send + Sync {
+    /// See [`Users::conversation_with`].
+    async fn conversation_with(
+        &self,
+        params: &UsersConversationParams,
+    ) -> Result<crate::types::UsersConversationsSuccessSchema>;
+
+    /// See [`Users::delete_photo`].
+    async fn delete_photo(
+        &self,
+        body: &crate::types::UsersDeletePhotoRequest,
+    ) -> Result<crate::types::UsersDeletePhotoSchema>;
+
+    /// See [`Users::get_presence_with`].
+    async fn get_presence_with(
+        &self,
+        params: &UsersGetPresenceParams,
+    ) -> Result<crate::types::ApiMethodUsersGetPresence>;
+
+    /// See [`Users::identity`].
+    async fn identity(&self) -> Result<Vec<crate::types::UsersIdentityResponseAnyOf>>;
+
+    /// See [`Users::get_all_identity`].
+    async fn get_all_identity(&self) -> Result<Vec<crate::types::UsersIdentityResponseAnyOf>>;
+
+    /// See [`Users::identity_stream`].
+    fn identity_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UsersIdentityResponseAnyOf>;
+
+    /// See [`Users::info_with`].
+    async fn info_with(
+        &self,
+        params: &UsersInfoParams,
+    ) -> Result<crate::types::UsersInfoSuccessSchema>;
+
+    /// See [`Users::list_with`].
+    async fn list_with(&self, params: &UsersListParams) -> Result<crate::types::UsersListSchema>;
+
+    /// See [`Users::lookup_email_with`].
+    async fn lookup_email_with(
+        &self,
+        params: &UsersLookupEmailParams,
+    ) -> Result<crate::types::UsersLookupByEmailSuccessSchema>;
+
+    /// See [`Users::set_active`].
+    async fn set_active(&self) -> Result<crate::types::UsersSetActiveSchema>;
+
+    /// See [`Users::set_photo`].
+    async fn set_photo(
+        &self,
+        body: &crate::types::UsersSetPhotoRequest,
+    ) -> Result<crate::types::UsersSetPhotoSchema>;
+
+    /// See [`Users::set_presence`].
+    async fn set_presence(
+        &self,
+        body: &crate::types::UsersSetPresenceRequest,
+    ) -> Result<crate::types::UsersSetPresenceSchema>;
+}
+
+#[async_trait::async_trait]
+impl UsersApi for Users {
+    async fn conversation_with(
+        &self,
+        params: &UsersConversationParams,
+    ) -> Result<crate::types::UsersConversationsSuccessSchema> {
+        Users::conversation_with(self, params).await
+    }
+
+    async fn delete_photo(
+        &self,
+        body: &crate::types::UsersDeletePhotoRequest,
+    ) -> Result<crate::types::UsersDeletePhotoSchema> {
+        Users::delete_photo(self, body).await
+    }
+
+    async fn get_presence_with(
+        &self,
+        params: &UsersGetPresenceParams,
+    ) -> Result<crate::types::ApiMethodUsersGetPresence> {
+        Users::get_presence_with(self, params).await
+    }
+
+    async fn identity(&self) -> Result<Vec<crate::types::UsersIdentityResponseAnyOf>> {
+        Users::identity(self).await
+    }
+
+    async fn get_all_identity(&self) -> Result<Vec<crate::types::UsersIdentityResponseAnyOf>> {
+        Users::get_all_identity(self).await
+    }
+
+    fn identity_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UsersIdentityResponseAnyOf> {
+        Users::identity_stream(self, start, max_items)
+    }
+
+    async fn info_with(
+        &self,
+        params: &UsersInfoParams,
+    ) -> Result<crate::types::UsersInfoSuccessSchema> {
+        Users::info_with(self, params).await
+    }
+
+    async fn list_with(&self, params: &UsersListParams) -> Result<crate::types::UsersListSchema> {
+        Users::list_with(self, params).await
+    }
+
+    async fn lookup_email_with(
+        &self,
+        params: &UsersLookupEmailParams,
+    ) -> Result<crate::types::UsersLookupByEmailSuccessSchema> {
+        Users::lookup_email_with(self, params).await
+    }
+
+    async fn set_active(&self) -> Result<crate::types::UsersSetActiveSchema> {
+        Users::set_active(self).await
+    }
+
+    async fn set_photo(
+        &self,
+        body: &crate::types::UsersSetPhotoRequest,
+    ) -> Result<crate::types::UsersSetPhotoSchema> {
+        Users::set_photo(self, body).await
+    }
+
+    async fn set_presence(
+        &self,
+        body: &crate::types::UsersSetPresenceRequest,
+    ) -> Result<crate::types::UsersSetPresenceSchema> {
+        Users::set_presence(self, body).await
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct UsersProfile {
     pub client: Client,
@@ -21,20 +19,17 @@ impl UsersProfile {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `users.profile:read`.
-     * * `include_labels: bool` -- Include labels for each ID in custom profile fields.
-     * * `user: &str` -- User to retrieve profile info for.
+     * * `params: &UsersProfileGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
-        include_labels: bool,
-        user: &str,
+        params: &UsersProfileGetParams,
     ) -> Result<crate::types::UsersProfileGetSchema> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if include_labels {
+        if let Some(include_labels) = &params.include_labels {
             query_args.push(("include_labels".to_string(), include_labels.to_string()));
         }
-        if !user.is_empty() {
+        if let Some(user) = &params.user {
             query_args.push(("user".to_string(), user.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -43,6 +38,22 @@ impl UsersProfile {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `UsersProfileGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        include_labels: bool,
+        user: &str,
+    ) -> Result<crate::types::UsersProfileGetSchema> {
+        let params = UsersProfileGetParams {
+            include_labels: Some(include_labels).filter(|v| *v),
+            user: Some(user.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * This function performs a `POST` to the `/users.profile.set` endpoint.
      *
@@ -54,8 +65,73 @@ impl UsersProfile {
      *
      * * `token: &str` -- Authentication token. Requires scope: `users.profile:write`.
      */
-    pub async fn set(&self) -> Result<crate::types::UsersProfileSetSchema> {
+    pub async fn set(
+        &self,
+        body: &crate::types::UsersProfileSetRequest,
+    ) -> Result<crate::types::UsersProfileSetSchema> {
         let url = "/users.profile.set".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `UsersProfile::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersProfileGetParams {
+    /// Include labels for each ID in custom profile fields.
+    pub include_labels: Option<bool>,
+    /// User to retrieve profile info for.
+    pub user: Option<String>,
+}
+
+impl UsersProfileGetParams {
+    pub fn include_labels(mut self, include_labels: bool) -> Self {
+        self.include_labels = Some(include_labels);
+        self
+    }
+
+    pub fn user<T: ToString>(mut self, user: T) -> Self {
+        self.user = Some(user.to_string());
+        self
+    }
+}
+
+/// The functions of [`UsersProfile`], for code that takes them as a trait, so that it can be
+/// tested with the `MockUsersProfileApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait UsersProfileApi: Send + Sync {
+    /// See [`UsersProfile::get_with`].
+    async fn get_with(
+        &self,
+        params: &UsersProfileGetParams,
+    ) -> Result<crate::types::UsersProfileGetSchema>;
+
+    /// See [`UsersProfile::set`].
+    async fn set(
+        &self,
+        body: &crate::types::UsersProfileSetRequest,
+    ) -> Result<crate::types::UsersProfileSetSchema>;
+}
+
+#[async_trait::async_trait]
+impl UsersProfileApi for UsersProfile {
+    async fn get_with(
+        &self,
+        params: &UsersProfileGetParams,
+    ) -> Result<crate::types::UsersProfileGetSchema> {
+        UsersProfile::get_with(self, params).await
+    }
+
+    async fn set(
+        &self,
+        body: &crate::types::UsersProfileSetRequest,
+    ) -> Result<crate::types::UsersProfileSetSchema> {
+        UsersProfile::set(self, body).await
     }
 }
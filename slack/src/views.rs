@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Views {
     pub client: Client,
@@ -21,16 +19,17 @@ impl Views {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `none`.
-     * * `trigger_id: &str` -- Exchange a trigger to post to the user.
-     * * `view: &str` -- A [view payload](/reference/surfaces/views). This must be a JSON-encoded string.
+     * * `params: &ViewsOpenParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn open(&self, trigger_id: &str, view: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn open_with(
+        &self,
+        params: &ViewsOpenParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !trigger_id.is_empty() {
+        if let Some(trigger_id) = &params.trigger_id {
             query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
         }
-        if !view.is_empty() {
+        if let Some(view) = &params.view {
             query_args.push(("view".to_string(), view.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -39,6 +38,22 @@ impl Views {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `open_with` and pass the query parameters as a `ViewsOpenParams` instead.
+     */
+    #[deprecated(note = "use `open_with` instead")]
+    pub async fn open(
+        &self,
+        trigger_id: &str,
+        view: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = ViewsOpenParams {
+            trigger_id: Some(trigger_id.to_string()).filter(|s| !s.is_empty()),
+            view: Some(view.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.open_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/views.publish` endpoint.
      *
@@ -48,25 +63,20 @@ impl Views {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `none`.
-     * * `user_id: &str` -- `id` of the user you want publish a view to.
-     * * `view: &str` -- A [view payload](/reference/surfaces/views). This must be a JSON-encoded string.
-     * * `hash: &str` -- A string that represents view state to protect against possible race conditions.
+     * * `params: &ViewsPublishParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn publish(
+    pub async fn publish_with(
         &self,
-        user_id: &str,
-        view: &str,
-        hash: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &ViewsPublishParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !hash.is_empty() {
+        if let Some(hash) = &params.hash {
             query_args.push(("hash".to_string(), hash.to_string()));
         }
-        if !user_id.is_empty() {
+        if let Some(user_id) = &params.user_id {
             query_args.push(("user_id".to_string(), user_id.to_string()));
         }
-        if !view.is_empty() {
+        if let Some(view) = &params.view {
             query_args.push(("view".to_string(), view.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -75,6 +85,24 @@ impl Views {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `publish_with` and pass the query parameters as a `ViewsPublishParams` instead.
+     */
+    #[deprecated(note = "use `publish_with` instead")]
+    pub async fn publish(
+        &self,
+        user_id: &str,
+        view: &str,
+        hash: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = ViewsPublishParams {
+            hash: Some(hash.to_string()).filter(|s| !s.is_empty()),
+            user_id: Some(user_id.to_string()).filter(|s| !s.is_empty()),
+            view: Some(view.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.publish_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/views.push` endpoint.
      *
@@ -84,16 +112,17 @@ impl Views {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `none`.
-     * * `trigger_id: &str` -- Exchange a trigger to post to the user.
-     * * `view: &str` -- A [view payload](/reference/surfaces/views). This must be a JSON-encoded string.
+     * * `params: &ViewsPushParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn push(&self, trigger_id: &str, view: &str) -> Result<crate::types::DndEndSchema> {
+    pub async fn push_with(
+        &self,
+        params: &ViewsPushParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !trigger_id.is_empty() {
+        if let Some(trigger_id) = &params.trigger_id {
             query_args.push(("trigger_id".to_string(), trigger_id.to_string()));
         }
-        if !view.is_empty() {
+        if let Some(view) = &params.view {
             query_args.push(("view".to_string(), view.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -102,6 +131,22 @@ impl Views {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `push_with` and pass the query parameters as a `ViewsPushParams` instead.
+     */
+    #[deprecated(note = "use `push_with` instead")]
+    pub async fn push(
+        &self,
+        trigger_id: &str,
+        view: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = ViewsPushParams {
+            trigger_id: Some(trigger_id.to_string()).filter(|s| !s.is_empty()),
+            view: Some(view.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.push_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/views.update` endpoint.
      *
@@ -111,30 +156,23 @@ impl Views {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `none`.
-     * * `view_id: &str` -- A unique identifier of the view to be updated. Either `view_id` or `external_id` is required.
-     * * `external_id: &str` -- A unique identifier of the view set by the developer. Must be unique for all views on a team. Max length of 255 characters. Either `view_id` or `external_id` is required.
-     * * `view: &str` -- A [view object](/reference/surfaces/views). This must be a JSON-encoded string.
-     * * `hash: &str` -- A string that represents view state to protect against possible race conditions.
+     * * `params: &ViewsUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update(
+    pub async fn update_with(
         &self,
-        view_id: &str,
-        external_id: &str,
-        view: &str,
-        hash: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &ViewsUpdateParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !external_id.is_empty() {
+        if let Some(external_id) = &params.external_id {
             query_args.push(("external_id".to_string(), external_id.to_string()));
         }
-        if !hash.is_empty() {
+        if let Some(hash) = &params.hash {
             query_args.push(("hash".to_string(), hash.to_string()));
         }
-        if !view.is_empty() {
+        if let Some(view) = &params.view {
             query_args.push(("view".to_string(), view.to_string()));
         }
-        if !view_id.is_empty() {
+        if let Some(view_id) = &params.view_id {
             query_args.push(("view_id".to_string(), view_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -142,4 +180,190 @@ impl Views {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `update_with` and pass the query parameters as a `ViewsUpdateParams` instead.
+     */
+    #[deprecated(note = "use `update_with` instead")]
+    pub async fn update(
+        &self,
+        view_id: &str,
+        external_id: &str,
+        view: &str,
+        hash: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = ViewsUpdateParams {
+            external_id: Some(external_id.to_string()).filter(|s| !s.is_empty()),
+            hash: Some(hash.to_string()).filter(|s| !s.is_empty()),
+            view: Some(view.to_string()).filter(|s| !s.is_empty()),
+            view_id: Some(view_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.update_with(&params).await
+    }
+}
+
+/// The query parameters of `Views::open_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ViewsOpenParams {
+    /// Exchange a trigger to post to the user.
+    pub trigger_id: Option<String>,
+    /// A [view payload](/reference/surfaces/views). This must be a JSON-encoded string.
+    pub view: Option<String>,
+}
+
+impl ViewsOpenParams {
+    pub fn trigger_id<T: ToString>(mut self, trigger_id: T) -> Self {
+        self.trigger_id = Some(trigger_id.to_string());
+        self
+    }
+
+    pub fn view<T: ToString>(mut self, view: T) -> Self {
+        self.view = Some(view.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Views::publish_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ViewsPublishParams {
+    /// A string that represents view state to protect against possible race conditions.
+    pub hash: Option<String>,
+    /// `id` of the user you want publish a view to.
+    pub user_id: Option<String>,
+    /// A [view payload](/reference/surfaces/views). This must be a JSON-encoded string.
+    pub view: Option<String>,
+}
+
+impl ViewsPublishParams {
+    pub fn hash<T: ToString>(mut self, hash: T) -> Self {
+        self.hash = Some(hash.to_string());
+        self
+    }
+
+    pub fn user_id<T: ToString>(mut self, user_id: T) -> Self {
+        self.user_id = Some(user_id.to_string());
+        self
+    }
+
+    pub fn view<T: ToString>(mut self, view: T) -> Self {
+        self.view = Some(view.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Views::push_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ViewsPushParams {
+    /// Exchange a trigger to post to the user.
+    pub trigger_id: Option<String>,
+    /// A [view payload](/reference/surfaces/views). This must be a JSON-encoded string.
+    pub view: Option<String>,
+}
+
+impl ViewsPushParams {
+    pub fn trigger_id<T: ToString>(mut self, trigger_id: T) -> Self {
+        self.trigger_id = Some(trigger_id.to_string());
+        self
+    }
+
+    pub fn view<T: ToString>(mut self, view: T) -> Self {
+        self.view = Some(view.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Views::update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ViewsUpdateParams {
+    /// A unique identifier of the view set by the developer. Must be unique for all views on a team. Max length of 255 characters. Either `view_id` or `external_id` is required.
+    pub external_id: Option<String>,
+    /// A string that represents view state to protect against possible race conditions.
+    pub hash: Option<String>,
+    /// A [view object](/reference/surfaces/views). This must be a JSON-encoded string.
+    pub view: Option<String>,
+    /// A unique identifier of the view to be updated. Either `view_id` or `external_id` is required.
+    pub view_id: Option<String>,
+}
+
+impl ViewsUpdateParams {
+    pub fn external_id<T: ToString>(mut self, external_id: T) -> Self {
+        self.external_id = Some(external_id.to_string());
+        self
+    }
+
+    pub fn hash<T: ToString>(mut self, hash: T) -> Self {
+        self.hash = Some(hash.to_string());
+        self
+    }
+
+    pub fn view<T: ToString>(mut self, view: T) -> Self {
+        self.view = Some(view.to_string());
+        self
+    }
+
+    pub fn view_id<T: ToString>(mut self, view_id: T) -> Self {
+        self.view_id = Some(view_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`Views`], for code that takes them as a trait, so that it can be
+/// tested with the `MockViewsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait ViewsApi: Send + Sync {
+    /// See [`Views::open_with`].
+    async fn open_with(
+        &self,
+        params: &ViewsOpenParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`Views::publish_with`].
+    async fn publish_with(
+        &self,
+        params: &ViewsPublishParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`Views::push_with`].
+    async fn push_with(
+        &self,
+        params: &ViewsPushParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`Views::update_with`].
+    async fn update_with(
+        &self,
+        params: &ViewsUpdateParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl ViewsApi for Views {
+    async fn open_with(
+        &self,
+        params: &ViewsOpenParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Views::open_with(self, params).await
+    }
+
+    async fn publish_with(
+        &self,
+        params: &ViewsPublishParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Views::publish_with(self, params).await
+    }
+
+    async fn push_with(
+        &self,
+        params: &ViewsPushParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Views::push_with(self, params).await
+    }
+
+    async fn update_with(
+        &self,
+        params: &ViewsUpdateParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Views::update_with(self, params).await
+    }
 }
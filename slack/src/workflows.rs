@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Workflows {
     pub client: Client,
@@ -21,20 +19,17 @@ impl Workflows {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `workflow.steps:execute`.
-     * * `workflow_step_execute_id: &str` -- Context identifier that maps to the correct workflow step execution.
-     * * `outputs: &str` -- Key-value object of outputs from your step. Keys of this object reflect the configured `key` properties of your [`outputs`](/reference/workflows/workflow_step#output) array from your `workflow_step` object.
+     * * `params: &WorkflowsStepCompletedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn step_completed(
+    pub async fn step_completed_with(
         &self,
-        workflow_step_execute_id: &str,
-        outputs: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &WorkflowsStepCompletedParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !outputs.is_empty() {
+        if let Some(outputs) = &params.outputs {
             query_args.push(("outputs".to_string(), outputs.to_string()));
         }
-        if !workflow_step_execute_id.is_empty() {
+        if let Some(workflow_step_execute_id) = &params.workflow_step_execute_id {
             query_args.push((
                 "workflow_step_execute_id".to_string(),
                 workflow_step_execute_id.to_string(),
@@ -46,6 +41,23 @@ impl Workflows {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `step_completed_with` and pass the query parameters as a `WorkflowsStepCompletedParams` instead.
+     */
+    #[deprecated(note = "use `step_completed_with` instead")]
+    pub async fn step_completed(
+        &self,
+        workflow_step_execute_id: &str,
+        outputs: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = WorkflowsStepCompletedParams {
+            outputs: Some(outputs.to_string()).filter(|s| !s.is_empty()),
+            workflow_step_execute_id: Some(workflow_step_execute_id.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.step_completed_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/workflows.stepFailed` endpoint.
      *
@@ -55,20 +67,17 @@ impl Workflows {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `workflow.steps:execute`.
-     * * `workflow_step_execute_id: &str` -- Context identifier that maps to the correct workflow step execution.
-     * * `error: &str` -- A JSON-based object with a `message` property that should contain a human readable error message.
+     * * `params: &WorkflowsStepFailedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn step_failed(
+    pub async fn step_failed_with(
         &self,
-        workflow_step_execute_id: &str,
-        error: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &WorkflowsStepFailedParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !error.is_empty() {
+        if let Some(error) = &params.error {
             query_args.push(("error".to_string(), error.to_string()));
         }
-        if !workflow_step_execute_id.is_empty() {
+        if let Some(workflow_step_execute_id) = &params.workflow_step_execute_id {
             query_args.push((
                 "workflow_step_execute_id".to_string(),
                 workflow_step_execute_id.to_string(),
@@ -80,6 +89,23 @@ impl Workflows {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `step_failed_with` and pass the query parameters as a `WorkflowsStepFailedParams` instead.
+     */
+    #[deprecated(note = "use `step_failed_with` instead")]
+    pub async fn step_failed(
+        &self,
+        workflow_step_execute_id: &str,
+        error: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = WorkflowsStepFailedParams {
+            error: Some(error.to_string()).filter(|s| !s.is_empty()),
+            workflow_step_execute_id: Some(workflow_step_execute_id.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.step_failed_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/workflows.updateStep` endpoint.
      *
@@ -89,35 +115,26 @@ impl Workflows {
      *
      * **Parameters:**
      *
-     * * `token: &str` -- Authentication token. Requires scope: `workflow.steps:execute`.
-     * * `workflow_step_edit_id: &str` -- A context identifier provided with `view_submission` payloads used to call back to `workflows.updateStep`.
-     * * `inputs: &str` -- A JSON key-value map of inputs required from a user during configuration. This is the data your app expects to receive when the workflow step starts. **Please note**: the embedded variable format is set and replaced by the workflow system. You cannot create custom variables that will be replaced at runtime. [Read more about variables in workflow steps here](/workflows/steps#variables).
-     * * `outputs: &str` -- An JSON array of output objects used during step execution. This is the data your app agrees to provide when your workflow step was executed.
-     * * `step_name: &str` -- An optional field that can be used to override the step name that is shown in the Workflow Builder.
-     * * `step_image_url: &str` -- An optional field that can be used to override app image that is shown in the Workflow Builder.
+     * * `params: &WorkflowsUpdateStepParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update_step(
+    pub async fn update_step_with(
         &self,
-        workflow_step_edit_id: &str,
-        inputs: &str,
-        outputs: &str,
-        step_name: &str,
-        step_image_url: &str,
-    ) -> Result<crate::types::DndEndSchema> {
+        params: &WorkflowsUpdateStepParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !inputs.is_empty() {
+        if let Some(inputs) = &params.inputs {
             query_args.push(("inputs".to_string(), inputs.to_string()));
         }
-        if !outputs.is_empty() {
+        if let Some(outputs) = &params.outputs {
             query_args.push(("outputs".to_string(), outputs.to_string()));
         }
-        if !step_image_url.is_empty() {
+        if let Some(step_image_url) = &params.step_image_url {
             query_args.push(("step_image_url".to_string(), step_image_url.to_string()));
         }
-        if !step_name.is_empty() {
+        if let Some(step_name) = &params.step_name {
             query_args.push(("step_name".to_string(), step_name.to_string()));
         }
-        if !workflow_step_edit_id.is_empty() {
+        if let Some(workflow_step_edit_id) = &params.workflow_step_edit_id {
             query_args.push((
                 "workflow_step_edit_id".to_string(),
                 workflow_step_edit_id.to_string(),
@@ -128,4 +145,159 @@ impl Workflows {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `update_step_with` and pass the query parameters as a `WorkflowsUpdateStepParams` instead.
+     */
+    #[deprecated(note = "use `update_step_with` instead")]
+    pub async fn update_step(
+        &self,
+        workflow_step_edit_id: &str,
+        inputs: &str,
+        outputs: &str,
+        step_name: &str,
+        step_image_url: &str,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        let params = WorkflowsUpdateStepParams {
+            inputs: Some(inputs.to_string()).filter(|s| !s.is_empty()),
+            outputs: Some(outputs.to_string()).filter(|s| !s.is_empty()),
+            step_image_url: Some(step_image_url.to_string()).filter(|s| !s.is_empty()),
+            step_name: Some(step_name.to_string()).filter(|s| !s.is_empty()),
+            workflow_step_edit_id: Some(workflow_step_edit_id.to_string())
+                .filter(|s| !s.is_empty()),
+        };
+        self.update_step_with(&params).await
+    }
+}
+
+/// The query parameters of `Workflows::step_completed_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WorkflowsStepCompletedParams {
+    /// Key-value object of outputs from your step. Keys of this object reflect the configured `key` properties of your [`outputs`](/reference/workflows/workflow_step#output) array from your `workflow_step` object.
+    pub outputs: Option<String>,
+    /// Context identifier that maps to the correct workflow step execution.
+    pub workflow_step_execute_id: Option<String>,
+}
+
+impl WorkflowsStepCompletedParams {
+    pub fn outputs<T: ToString>(mut self, outputs: T) -> Self {
+        self.outputs = Some(outputs.to_string());
+        self
+    }
+
+    pub fn workflow_step_execute_id<T: ToString>(mut self, workflow_step_execute_id: T) -> Self {
+        self.workflow_step_execute_id = Some(workflow_step_execute_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Workflows::step_failed_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WorkflowsStepFailedParams {
+    /// A JSON-based object with a `message` property that should contain a human readable error message.
+    pub error: Option<String>,
+    /// Context identifier that maps to the correct workflow step execution.
+    pub workflow_step_execute_id: Option<String>,
+}
+
+impl WorkflowsStepFailedParams {
+    pub fn error<T: ToString>(mut self, error: T) -> Self {
+        self.error = Some(error.to_string());
+        self
+    }
+
+    pub fn workflow_step_execute_id<T: ToString>(mut self, workflow_step_execute_id: T) -> Self {
+        self.workflow_step_execute_id = Some(workflow_step_execute_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Workflows::update_step_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WorkflowsUpdateStepParams {
+    /// A JSON key-value map of inputs required from a user during configuration. This is the data your app expects to receive when the workflow step starts. **Please note**: the embedded variable format is set and replaced by the workflow system. You cannot create custom variables that will be replaced at runtime. [Read more about variables in workflow steps here](/workflows/steps#variables).
+    pub inputs: Option<String>,
+    /// An JSON array of output objects used during step execution. This is the data your app agrees to provide when your workflow step was executed.
+    pub outputs: Option<String>,
+    /// An optional field that can be used to override app image that is shown in the Workflow Builder.
+    pub step_image_url: Option<String>,
+    /// An optional field that can be used to override the step name that is shown in the Workflow Builder.
+    pub step_name: Option<String>,
+    /// A context identifier provided with `view_submission` payloads used to call back to `workflows.updateStep`.
+    pub workflow_step_edit_id: Option<String>,
+}
+
+impl WorkflowsUpdateStepParams {
+    pub fn inputs<T: ToString>(mut self, inputs: T) -> Self {
+        self.inputs = Some(inputs.to_string());
+        self
+    }
+
+    pub fn outputs<T: ToString>(mut self, outputs: T) -> Self {
+        self.outputs = Some(outputs.to_string());
+        self
+    }
+
+    pub fn step_image_url<T: ToString>(mut self, step_image_url: T) -> Self {
+        self.step_image_url = Some(step_image_url.to_string());
+        self
+    }
+
+    pub fn step_name<T: ToString>(mut self, step_name: T) -> Self {
+        self.step_name = Some(step_name.to_string());
+        self
+    }
+
+    pub fn workflow_step_edit_id<T: ToString>(mut self, workflow_step_edit_id: T) -> Self {
+        self.workflow_step_edit_id = Some(workflow_step_edit_id.to_string());
+        self
+    }
+}
+
+/// The functions of [`Workflows`], for code that takes them as a trait, so that it can be
+/// tested with the `MockWorkflowsApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait WorkflowsApi: Send + Sync {
+    /// See [`Workflows::step_completed_with`].
+    async fn step_completed_with(
+        &self,
+        params: &WorkflowsStepCompletedParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`Workflows::step_failed_with`].
+    async fn step_failed_with(
+        &self,
+        params: &WorkflowsStepFailedParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+
+    /// See [`Workflows::update_step_with`].
+    async fn update_step_with(
+        &self,
+        params: &WorkflowsUpdateStepParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate>;
+}
+
+#[async_trait::async_trait]
+impl WorkflowsApi for Workflows {
+    async fn step_completed_with(
+        &self,
+        params: &WorkflowsStepCompletedParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Workflows::step_completed_with(self, params).await
+    }
+
+    async fn step_failed_with(
+        &self,
+        params: &WorkflowsStepFailedParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Workflows::step_failed_with(self, params).await
+    }
+
+    async fn update_step_with(
+        &self,
+        params: &WorkflowsUpdateStepParams,
+    ) -> Result<crate::types::DefaultSuccessTemplate> {
+        Workflows::update_step_with(self, params).await
+    }
 }
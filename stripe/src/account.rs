@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Account {
     pub client: Client,
@@ -32,10 +30,20 @@ impl Account {
      * <p>Updates a <a href="/docs/connect/accounts">connected account</a> by setting the values of the parameters passed. Any parameters not provided are left unchanged. Most parameters can be changed only for Custom accounts. (These are marked <strong>Custom Only</strong> below.) Parameters marked <strong>Custom and Express</strong> are not supported for Standard accounts.</p>
      *
      * <p>To update your own account, use the <a href="https://dashboard.stripe.com/account">Dashboard</a>. Refer to our <a href="/docs/connect/updating-accounts">Connect</a> documentation to learn more about updating accounts.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::Account> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::Account> {
         let url = "/v1/account".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -56,10 +64,23 @@ impl Account {
      * This function performs a `POST` to the `/v1/account/bank_accounts` endpoint.
      *
      * <p>Create an external account for a given account.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_bank(&self) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_bank<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::DataAnyOf> {
         let url = "/v1/account/bank_accounts".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -91,14 +112,25 @@ impl Account {
      * **Parameters:**
      *
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_bank_account(&self, id: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_bank_account<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> Result<crate::types::DataAnyOf> {
         let url = format!(
             "/v1/account/bank_accounts/{}",
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -126,62 +158,107 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `params: &AccountGetCapabilitiesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_capabilities(&self) -> Result<Vec<crate::types::Capability>> {
-        let url = "/v1/account/capabilities".to_string();
+    pub async fn get_capabilities_with(
+        &self,
+        params: &AccountGetCapabilitiesParams,
+    ) -> Result<Vec<crate::types::Capability>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/account/capabilities?{}", query_);
+
         let resp: crate::types::ListAccountCapability = self.client.get(&url, None).await?;
 
         // Return our response data.
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_capabilities_with` and pass the query parameters as a `AccountGetCapabilitiesParams` instead.
+     */
+    #[deprecated(note = "use `get_capabilities_with` instead")]
+    pub async fn get_capabilities(&self) -> Result<Vec<crate::types::Capability>> {
+        let params = AccountGetCapabilitiesParams::default();
+        self.get_capabilities_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/account/capabilities` endpoint.
      *
      * As opposed to `get_capabilities`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of capabilities associated with the account. The capabilities are returned sorted by creation date, with the most recent capability appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &AccountGetCapabilitiesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_capabilities(&self) -> Result<Vec<crate::types::Capability>> {
-        let url = "/v1/account/capabilities".to_string();
-        let mut resp: crate::types::ListAccountCapability = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    pub async fn get_all_capabilities_with(
+        &self,
+        params: &AccountGetCapabilitiesParams,
+    ) -> Result<Vec<crate::types::Capability>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/account/capabilities?{}", query_);
+
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::default(),
+            |item: &crate::types::Capability| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_capabilities_with` and pass the query parameters as a `AccountGetCapabilitiesParams` instead.
+     */
+    #[deprecated(note = "use `get_all_capabilities_with` instead")]
+    pub async fn get_all_capabilities(&self) -> Result<Vec<crate::types::Capability>> {
+        let params = AccountGetCapabilitiesParams::default();
+        self.get_all_capabilities_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/account/capabilities` endpoint.
+     *
+     * As opposed to `get_capabilities`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of capabilities associated with the account. The capabilities are returned sorted by creation date, with the most recent capability appearing first.</p>
+     */
+    pub fn get_capabilities_stream(
+        &self,
+        params: &AccountGetCapabilitiesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Capability> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
-
-        // Return our response data.
-        Ok(data.to_vec())
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/account/capabilities?{}", query_);
+
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::default().resume(start),
+            max_items,
+            |item: &crate::types::Capability| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -214,17 +291,25 @@ impl Account {
      * **Parameters:**
      *
      * * `capability: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_capabilities_capability(
+    pub async fn post_capabilities_capability<B: serde::Serialize>(
         &self,
         capability: &str,
+        body: &B,
     ) -> Result<crate::types::Capability> {
         let url = format!(
             "/v1/account/capabilities/{}",
             crate::progenitor_support::encode_path(capability),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -234,25 +319,25 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &AccountGetExternalParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_external(
+    pub async fn get_external_with(
         &self,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &AccountGetExternalParams,
     ) -> Result<Vec<crate::types::DataAnyOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -264,62 +349,134 @@ impl Account {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_external_with` and pass the query parameters as a `AccountGetExternalParams` instead.
+     */
+    #[deprecated(note = "use `get_external_with` instead")]
+    pub async fn get_external(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::DataAnyOf>> {
+        let params = AccountGetExternalParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_external_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/account/external_accounts` endpoint.
      *
      * As opposed to `get_external`, this function returns all the pages of the request at once.
      *
      * <p>List external accounts for an account.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &AccountGetExternalParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_external(&self) -> Result<Vec<crate::types::DataAnyOf>> {
-        let url = "/v1/account/external_accounts".to_string();
-        let mut resp: crate::types::ExternalAccounts = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
+    pub async fn get_all_external_with(
+        &self,
+        params: &AccountGetExternalParams,
+    ) -> Result<Vec<crate::types::DataAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/account/external_accounts?{}", query_);
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::DataAnyOf| match item {
+                crate::types::DataAnyOf::BankAccount(o) => Some(o.id.clone()),
+                crate::types::DataAnyOf::Card(o) => Some(o.id.clone()),
+            },
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_external_with` and pass the query parameters as a `AccountGetExternalParams` instead.
+     */
+    #[deprecated(note = "use `get_all_external_with` instead")]
+    pub async fn get_all_external(&self) -> Result<Vec<crate::types::DataAnyOf>> {
+        let params = AccountGetExternalParams::default();
+        self.get_all_external_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/account/external_accounts` endpoint.
+     *
+     * As opposed to `get_external`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>List external accounts for an account.</p>
+     */
+    pub fn get_external_stream(
+        &self,
+        params: &AccountGetExternalParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DataAnyOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/account/external_accounts?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::DataAnyOf| match item {
+                crate::types::DataAnyOf::BankAccount(o) => Some(o.id.clone()),
+                crate::types::DataAnyOf::Card(o) => Some(o.id.clone()),
+            },
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/account/external_accounts` endpoint.
      *
      * <p>Create an external account for a given account.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_external(&self) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_external<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::DataAnyOf> {
         let url = "/v1/account/external_accounts".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -351,14 +508,25 @@ impl Account {
      * **Parameters:**
      *
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_external_account(&self, id: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_external_account<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> Result<crate::types::DataAnyOf> {
         let url = format!(
             "/v1/account/external_accounts/{}",
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -388,10 +556,23 @@ impl Account {
      * <p>Creates a single-use login link for an Express account to access their Stripe dashboard.</p>
      *
      * <p><strong>You may only create login links for <a href="/docs/connect/express-accounts">Express accounts</a> connected to your platform</strong>.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_login_link(&self) -> Result<crate::types::LoginLink> {
+    pub async fn post_login_link<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::LoginLink> {
         let url = "/v1/account/login_links".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -401,27 +582,26 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `relationship: &str` -- Filters on the list of people returned based on the person's relationship to the account's company.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &AccountGetPeopleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_people(
+    pub async fn get_people_with(
         &self,
-        ending_before: &str,
-        limit: i64,
         _relationship: &str,
-        starting_after: &str,
+        params: &AccountGetPeopleParams,
     ) -> Result<Vec<crate::types::Person>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -433,62 +613,130 @@ impl Account {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_people_with` and pass the query parameters as a `AccountGetPeopleParams` instead.
+     *
+     * `relationship` is not sent.
+     */
+    #[deprecated(note = "use `get_people_with` instead")]
+    pub async fn get_people(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        _relationship: &str,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::Person>> {
+        let params = AccountGetPeopleParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_people_with(_relationship, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/account/people` endpoint.
      *
      * As opposed to `get_people`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &AccountGetPeopleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_people(&self, _relationship: &str) -> Result<Vec<crate::types::Person>> {
-        let url = "/v1/account/people".to_string();
-        let mut resp: crate::types::GetAccountPeopleResponse = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
+    pub async fn get_all_people_with(
+        &self,
+        _relationship: &str,
+        params: &AccountGetPeopleParams,
+    ) -> Result<Vec<crate::types::Person>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/account/people?{}", query_);
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Person| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_people_with` and pass the query parameters as a `AccountGetPeopleParams` instead.
+     */
+    #[deprecated(note = "use `get_all_people_with` instead")]
+    pub async fn get_all_people(&self, _relationship: &str) -> Result<Vec<crate::types::Person>> {
+        let params = AccountGetPeopleParams::default();
+        self.get_all_people_with(_relationship, &params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/account/people` endpoint.
+     *
+     * As opposed to `get_people`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_people_stream(
+        &self,
+        _relationship: &str,
+        params: &AccountGetPeopleParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Person> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/account/people?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Person| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/account/people` endpoint.
      *
      * <p>Creates a new person.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_people(&self) -> Result<crate::types::Person> {
+    pub async fn post_people<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::Person> {
         let url = "/v1/account/people".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -518,14 +766,25 @@ impl Account {
      * **Parameters:**
      *
      * * `person: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_people_person(&self, person: &str) -> Result<crate::types::Person> {
+    pub async fn post_people_person<B: serde::Serialize>(
+        &self,
+        person: &str,
+        body: &B,
+    ) -> Result<crate::types::Person> {
         let url = format!(
             "/v1/account/people/{}",
             crate::progenitor_support::encode_path(person),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -553,27 +812,26 @@ impl Account {
      *
      * **Parameters:**
      *
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `relationship: &str` -- Filters on the list of people returned based on the person's relationship to the account's company.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &AccountGetPersonsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_persons(
+    pub async fn get_persons_with(
         &self,
-        ending_before: &str,
-        limit: i64,
         _relationship: &str,
-        starting_after: &str,
+        params: &AccountGetPersonsParams,
     ) -> Result<Vec<crate::types::Person>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -585,62 +843,130 @@ impl Account {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_persons_with` and pass the query parameters as a `AccountGetPersonsParams` instead.
+     *
+     * `relationship` is not sent.
+     */
+    #[deprecated(note = "use `get_persons_with` instead")]
+    pub async fn get_persons(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        _relationship: &str,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::Person>> {
+        let params = AccountGetPersonsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_persons_with(_relationship, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/account/persons` endpoint.
      *
      * As opposed to `get_persons`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &AccountGetPersonsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_persons(&self, _relationship: &str) -> Result<Vec<crate::types::Person>> {
-        let url = "/v1/account/persons".to_string();
-        let mut resp: crate::types::GetAccountPeopleResponse = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
+    pub async fn get_all_persons_with(
+        &self,
+        _relationship: &str,
+        params: &AccountGetPersonsParams,
+    ) -> Result<Vec<crate::types::Person>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/account/persons?{}", query_);
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Person| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_persons_with` and pass the query parameters as a `AccountGetPersonsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_persons_with` instead")]
+    pub async fn get_all_persons(&self, _relationship: &str) -> Result<Vec<crate::types::Person>> {
+        let params = AccountGetPersonsParams::default();
+        self.get_all_persons_with(_relationship, &params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/account/persons` endpoint.
+     *
+     * As opposed to `get_persons`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_persons_stream(
+        &self,
+        _relationship: &str,
+        params: &AccountGetPersonsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Person> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/account/persons?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Person| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/account/persons` endpoint.
      *
      * <p>Creates a new person.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_person(&self) -> Result<crate::types::Person> {
+    pub async fn post_person<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::Person> {
         let url = "/v1/account/persons".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -670,14 +996,25 @@ impl Account {
      * **Parameters:**
      *
      * * `person: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_persons_person(&self, person: &str) -> Result<crate::types::Person> {
+    pub async fn post_persons_person<B: serde::Serialize>(
+        &self,
+        person: &str,
+        body: &B,
+    ) -> Result<crate::types::Person> {
         let url = format!(
             "/v1/account/persons/{}",
             crate::progenitor_support::encode_path(person),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -698,3 +1035,122 @@ impl Account {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Account::get_capabilities_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountGetCapabilitiesParams {
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+}
+
+impl AccountGetCapabilitiesParams {
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+}
+
+/// The query parameters of `Account::get_external_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountGetExternalParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl AccountGetExternalParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Account::get_people_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountGetPeopleParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl AccountGetPeopleParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Account::get_persons_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountGetPersonsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl AccountGetPersonsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct AccountLinks {
     pub client: Client,
@@ -16,9 +14,19 @@ impl AccountLinks {
      * This function performs a `POST` to the `/v1/account_links` endpoint.
      *
      * <p>Creates an AccountLink object that includes a single-use Stripe URL that the platform can redirect their user to in order to take them through the Connect Onboarding flow.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::AccountLink> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::AccountLink> {
         let url = "/v1/account_links".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 }
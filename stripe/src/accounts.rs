@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Accounts {
     pub client: Client,
@@ -19,27 +17,28 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `created: &str`
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &AccountsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        _created: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &AccountsGetPageParams,
     ) -> Result<Vec<crate::types::Account>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -51,52 +50,113 @@ impl Accounts {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `AccountsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::Account>> {
+        let params = AccountsGetPageParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/accounts` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of accounts connected to your platform via <a href="/docs/connect">Connect</a>. If you’re not a platform, the list is empty.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &AccountsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(&self, _created: &str) -> Result<Vec<crate::types::Account>> {
-        let url = "/v1/accounts".to_string();
-        let mut resp: crate::types::GetAccountsResponse = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
+    pub async fn get_all_with(
+        &self,
+        params: &AccountsGetPageParams,
+    ) -> Result<Vec<crate::types::Account>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/accounts?{}", query_);
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Account| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `AccountsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(&self, _created: &str) -> Result<Vec<crate::types::Account>> {
+        let params = AccountsGetPageParams::default();
+        self.get_all_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/accounts` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of accounts connected to your platform via <a href="/docs/connect">Connect</a>. If you’re not a platform, the list is empty.</p>
+     */
+    pub fn get_stream(
+        &self,
+        params: &AccountsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Account> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
         }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/accounts?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Account| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -104,10 +164,20 @@ impl Accounts {
      *
      * <p>With <a href="/docs/connect">Connect</a>, you can create Stripe accounts for your users.
      * To do this, you’ll first need to <a href="https://dashboard.stripe.com/account/applications/settings">register your platform</a>.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::Account> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::Account> {
         let url = "/v1/accounts".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -139,14 +209,25 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_accounts(&self, account: &str) -> Result<crate::types::Account> {
+    pub async fn post_accounts<B: serde::Serialize>(
+        &self,
+        account: &str,
+        body: &B,
+    ) -> Result<crate::types::Account> {
         let url = format!(
             "/v1/accounts/{}",
             crate::progenitor_support::encode_path(account),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -179,14 +260,25 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_bank(&self, account: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_bank<B: serde::Serialize>(
+        &self,
+        account: &str,
+        body: &B,
+    ) -> Result<crate::types::DataAnyOf> {
         let url = format!(
             "/v1/accounts/{}/bank_accounts",
             crate::progenitor_support::encode_path(account),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -221,11 +313,13 @@ impl Accounts {
      *
      * * `account: &str` -- The account's country.
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_bank_accounts(
+    pub async fn post_bank_accounts<B: serde::Serialize>(
         &self,
         account: &str,
         id: &str,
+        body: &B,
     ) -> Result<crate::types::DataAnyOf> {
         let url = format!(
             "/v1/accounts/{}/bank_accounts/{}",
@@ -233,7 +327,13 @@ impl Accounts {
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -268,12 +368,24 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account: &str` -- The account's country.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `params: &AccountsGetCapabilitiesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_capabilities(&self, account: &str) -> Result<Vec<crate::types::Capability>> {
+    pub async fn get_capabilities_with(
+        &self,
+        account: &str,
+        params: &AccountsGetCapabilitiesParams,
+    ) -> Result<Vec<crate::types::Capability>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/accounts/{}/capabilities",
+            "/v1/accounts/{}/capabilities?{}",
             crate::progenitor_support::encode_path(account),
+            query_
         );
 
         let resp: crate::types::ListAccountCapability = self.client.get(&url, None).await?;
@@ -282,59 +394,99 @@ impl Accounts {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_capabilities_with` and pass the query parameters as a `AccountsGetCapabilitiesParams` instead.
+     */
+    #[deprecated(note = "use `get_capabilities_with` instead")]
+    pub async fn get_capabilities(&self, account: &str) -> Result<Vec<crate::types::Capability>> {
+        let params = AccountsGetCapabilitiesParams::default();
+        self.get_capabilities_with(account, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/capabilities` endpoint.
      *
      * As opposed to `get_capabilities`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of capabilities associated with the account. The capabilities are returned sorted by creation date, with the most recent capability appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &AccountsGetCapabilitiesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_capabilities(
+    pub async fn get_all_capabilities_with(
         &self,
         account: &str,
+        params: &AccountsGetCapabilitiesParams,
     ) -> Result<Vec<crate::types::Capability>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/accounts/{}/capabilities",
+            "/v1/accounts/{}/capabilities?{}",
             crate::progenitor_support::encode_path(account),
+            query_
         );
 
-        let mut resp: crate::types::ListAccountCapability = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::default(),
+            |item: &crate::types::Capability| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_capabilities_with` and pass the query parameters as a `AccountsGetCapabilitiesParams` instead.
+     */
+    #[deprecated(note = "use `get_all_capabilities_with` instead")]
+    pub async fn get_all_capabilities(
+        &self,
+        account: &str,
+    ) -> Result<Vec<crate::types::Capability>> {
+        let params = AccountsGetCapabilitiesParams::default();
+        self.get_all_capabilities_with(account, &params).await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/capabilities` endpoint.
+     *
+     * As opposed to `get_capabilities`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of capabilities associated with the account. The capabilities are returned sorted by creation date, with the most recent capability appearing first.</p>
+     */
+    pub fn get_capabilities_stream(
+        &self,
+        account: &str,
+        params: &AccountsGetCapabilitiesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Capability> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/accounts/{}/capabilities?{}",
+            crate::progenitor_support::encode_path(account),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::default().resume(start),
+            max_items,
+            |item: &crate::types::Capability| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -371,11 +523,13 @@ impl Accounts {
      *
      * * `account: &str` -- The account's country.
      * * `capability: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_capabilities_capability(
+    pub async fn post_capabilities_capability<B: serde::Serialize>(
         &self,
         account: &str,
         capability: &str,
+        body: &B,
     ) -> Result<crate::types::Capability> {
         let url = format!(
             "/v1/accounts/{}/capabilities/{}",
@@ -383,7 +537,13 @@ impl Accounts {
             crate::progenitor_support::encode_path(capability),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -394,26 +554,26 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account: &str` -- The account's country.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &AccountsGetExternalParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_external(
+    pub async fn get_external_with(
         &self,
         account: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &AccountsGetExternalParams,
     ) -> Result<Vec<crate::types::DataAnyOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -429,56 +589,122 @@ impl Accounts {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_external_with` and pass the query parameters as a `AccountsGetExternalParams` instead.
+     */
+    #[deprecated(note = "use `get_external_with` instead")]
+    pub async fn get_external(
+        &self,
+        account: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::DataAnyOf>> {
+        let params = AccountsGetExternalParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_external_with(account, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/external_accounts` endpoint.
      *
      * As opposed to `get_external`, this function returns all the pages of the request at once.
      *
      * <p>List external accounts for an account.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &AccountsGetExternalParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_external(&self, account: &str) -> Result<Vec<crate::types::DataAnyOf>> {
+    pub async fn get_all_external_with(
+        &self,
+        account: &str,
+        params: &AccountsGetExternalParams,
+    ) -> Result<Vec<crate::types::DataAnyOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/accounts/{}/external_accounts",
+            "/v1/accounts/{}/external_accounts?{}",
             crate::progenitor_support::encode_path(account),
+            query_
         );
 
-        let mut resp: crate::types::ExternalAccounts = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::DataAnyOf| match item {
+                crate::types::DataAnyOf::BankAccount(o) => Some(o.id.clone()),
+                crate::types::DataAnyOf::Card(o) => Some(o.id.clone()),
+            },
+        )
+        .await
+    }
 
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_external_with` and pass the query parameters as a `AccountsGetExternalParams` instead.
+     */
+    #[deprecated(note = "use `get_all_external_with` instead")]
+    pub async fn get_all_external(&self, account: &str) -> Result<Vec<crate::types::DataAnyOf>> {
+        let params = AccountsGetExternalParams::default();
+        self.get_all_external_with(account, &params).await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/external_accounts` endpoint.
+     *
+     * As opposed to `get_external`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>List external accounts for an account.</p>
+     */
+    pub fn get_external_stream(
+        &self,
+        account: &str,
+        params: &AccountsGetExternalParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::DataAnyOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/accounts/{}/external_accounts?{}",
+            crate::progenitor_support::encode_path(account),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::DataAnyOf| match item {
+                crate::types::DataAnyOf::BankAccount(o) => Some(o.id.clone()),
+                crate::types::DataAnyOf::Card(o) => Some(o.id.clone()),
+            },
+        )
     }
 
     /**
@@ -489,14 +715,25 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_external(&self, account: &str) -> Result<crate::types::DataAnyOf> {
+    pub async fn post_external<B: serde::Serialize>(
+        &self,
+        account: &str,
+        body: &B,
+    ) -> Result<crate::types::DataAnyOf> {
         let url = format!(
             "/v1/accounts/{}/external_accounts",
             crate::progenitor_support::encode_path(account),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -535,11 +772,13 @@ impl Accounts {
      *
      * * `account: &str` -- The account's country.
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_external_accounts(
+    pub async fn post_external_accounts<B: serde::Serialize>(
         &self,
         account: &str,
         id: &str,
+        body: &B,
     ) -> Result<crate::types::DataAnyOf> {
         let url = format!(
             "/v1/accounts/{}/external_accounts/{}",
@@ -547,7 +786,13 @@ impl Accounts {
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -584,14 +829,25 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_login_link(&self, account: &str) -> Result<crate::types::LoginLink> {
+    pub async fn post_login_link<B: serde::Serialize>(
+        &self,
+        account: &str,
+        body: &B,
+    ) -> Result<crate::types::LoginLink> {
         let url = format!(
             "/v1/accounts/{}/login_links",
             crate::progenitor_support::encode_path(account),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -602,28 +858,27 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account: &str` -- The account's country.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `relationship: &str` -- Filters on the list of people returned based on the person's relationship to the account's company.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &AccountsGetPeopleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_people(
+    pub async fn get_people_with(
         &self,
         account: &str,
-        ending_before: &str,
-        limit: i64,
         _relationship: &str,
-        starting_after: &str,
+        params: &AccountsGetPeopleParams,
     ) -> Result<Vec<crate::types::Person>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -639,60 +894,128 @@ impl Accounts {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_people_with` and pass the query parameters as a `AccountsGetPeopleParams` instead.
+     *
+     * `relationship` is not sent.
+     */
+    #[deprecated(note = "use `get_people_with` instead")]
+    pub async fn get_people(
+        &self,
+        account: &str,
+        ending_before: &str,
+        limit: i64,
+        _relationship: &str,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::Person>> {
+        let params = AccountsGetPeopleParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_people_with(account, _relationship, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/people` endpoint.
      *
      * As opposed to `get_people`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &AccountsGetPeopleParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_people(
+    pub async fn get_all_people_with(
         &self,
         account: &str,
         _relationship: &str,
+        params: &AccountsGetPeopleParams,
     ) -> Result<Vec<crate::types::Person>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/accounts/{}/people",
+            "/v1/accounts/{}/people?{}",
             crate::progenitor_support::encode_path(account),
+            query_
         );
 
-        let mut resp: crate::types::GetAccountPeopleResponse = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Person| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_people_with` and pass the query parameters as a `AccountsGetPeopleParams` instead.
+     *
+     * `relationship` is not sent.
+     */
+    #[deprecated(note = "use `get_all_people_with` instead")]
+    pub async fn get_all_people(
+        &self,
+        account: &str,
+        _relationship: &str,
+    ) -> Result<Vec<crate::types::Person>> {
+        let params = AccountsGetPeopleParams::default();
+        self.get_all_people_with(account, _relationship, &params)
+            .await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/people` endpoint.
+     *
+     * As opposed to `get_people`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_people_stream(
+        &self,
+        account: &str,
+        _relationship: &str,
+        params: &AccountsGetPeopleParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Person> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/accounts/{}/people?{}",
+            crate::progenitor_support::encode_path(account),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Person| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -703,14 +1026,25 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_people(&self, account: &str) -> Result<crate::types::Person> {
+    pub async fn post_people<B: serde::Serialize>(
+        &self,
+        account: &str,
+        body: &B,
+    ) -> Result<crate::types::Person> {
         let url = format!(
             "/v1/accounts/{}/people",
             crate::progenitor_support::encode_path(account),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -747,11 +1081,13 @@ impl Accounts {
      *
      * * `account: &str` -- The account's country.
      * * `person: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_people_person(
+    pub async fn post_people_person<B: serde::Serialize>(
         &self,
         account: &str,
         person: &str,
+        body: &B,
     ) -> Result<crate::types::Person> {
         let url = format!(
             "/v1/accounts/{}/people/{}",
@@ -759,7 +1095,13 @@ impl Accounts {
             crate::progenitor_support::encode_path(person),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -794,28 +1136,27 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account: &str` -- The account's country.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `relationship: &str` -- Filters on the list of people returned based on the person's relationship to the account's company.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &AccountsGetPersonsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_persons(
+    pub async fn get_persons_with(
         &self,
         account: &str,
-        ending_before: &str,
-        limit: i64,
         _relationship: &str,
-        starting_after: &str,
+        params: &AccountsGetPersonsParams,
     ) -> Result<Vec<crate::types::Person>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -831,60 +1172,128 @@ impl Accounts {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_persons_with` and pass the query parameters as a `AccountsGetPersonsParams` instead.
+     *
+     * `relationship` is not sent.
+     */
+    #[deprecated(note = "use `get_persons_with` instead")]
+    pub async fn get_persons(
+        &self,
+        account: &str,
+        ending_before: &str,
+        limit: i64,
+        _relationship: &str,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::Person>> {
+        let params = AccountsGetPersonsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_persons_with(account, _relationship, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/accounts/{account}/persons` endpoint.
      *
      * As opposed to `get_persons`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &AccountsGetPersonsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_persons(
+    pub async fn get_all_persons_with(
         &self,
         account: &str,
         _relationship: &str,
+        params: &AccountsGetPersonsParams,
     ) -> Result<Vec<crate::types::Person>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/accounts/{}/persons",
+            "/v1/accounts/{}/persons?{}",
             crate::progenitor_support::encode_path(account),
+            query_
         );
 
-        let mut resp: crate::types::GetAccountPeopleResponse = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Person| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_persons_with` and pass the query parameters as a `AccountsGetPersonsParams` instead.
+     *
+     * `relationship` is not sent.
+     */
+    #[deprecated(note = "use `get_all_persons_with` instead")]
+    pub async fn get_all_persons(
+        &self,
+        account: &str,
+        _relationship: &str,
+    ) -> Result<Vec<crate::types::Person>> {
+        let params = AccountsGetPersonsParams::default();
+        self.get_all_persons_with(account, _relationship, &params)
+            .await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/accounts/{account}/persons` endpoint.
+     *
+     * As opposed to `get_persons`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of people associated with the account’s legal entity. The people are returned sorted by creation date, with the most recent people appearing first.</p>
+     */
+    pub fn get_persons_stream(
+        &self,
+        account: &str,
+        _relationship: &str,
+        params: &AccountsGetPersonsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Person> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/accounts/{}/persons?{}",
+            crate::progenitor_support::encode_path(account),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Person| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -895,14 +1304,25 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_person(&self, account: &str) -> Result<crate::types::Person> {
+    pub async fn post_person<B: serde::Serialize>(
+        &self,
+        account: &str,
+        body: &B,
+    ) -> Result<crate::types::Person> {
         let url = format!(
             "/v1/accounts/{}/persons",
             crate::progenitor_support::encode_path(account),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -939,11 +1359,13 @@ impl Accounts {
      *
      * * `account: &str` -- The account's country.
      * * `person: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_persons_person(
+    pub async fn post_persons_person<B: serde::Serialize>(
         &self,
         account: &str,
         person: &str,
+        body: &B,
     ) -> Result<crate::types::Person> {
         let url = format!(
             "/v1/accounts/{}/persons/{}",
@@ -951,7 +1373,13 @@ impl Accounts {
             crate::progenitor_support::encode_path(person),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -988,13 +1416,184 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_reject(&self, account: &str) -> Result<crate::types::Account> {
+    pub async fn post_reject<B: serde::Serialize>(
+        &self,
+        account: &str,
+        body: &B,
+    ) -> Result<crate::types::Account> {
         let url = format!(
             "/v1/accounts/{}/reject",
             crate::progenitor_support::encode_path(account),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Accounts::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsGetPageParams {
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl AccountsGetPageParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Accounts::get_capabilities_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsGetCapabilitiesParams {
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+}
+
+impl AccountsGetCapabilitiesParams {
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+}
+
+/// The query parameters of `Accounts::get_external_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsGetExternalParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl AccountsGetExternalParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Accounts::get_people_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsGetPeopleParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl AccountsGetPeopleParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Accounts::get_persons_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsGetPersonsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl AccountsGetPersonsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ApplePay {
     pub client: Client,
@@ -19,30 +17,28 @@ impl ApplePay {
      *
      * **Parameters:**
      *
-     * * `domain_name: &str` -- The account's country.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &ApplePayGetDomainsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_domains(
+    pub async fn get_domains_with(
         &self,
-        domain_name: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &ApplePayGetDomainsParams,
     ) -> Result<Vec<crate::types::ApplePayDomain>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !domain_name.is_empty() {
+        if let Some(domain_name) = &params.domain_name {
             query_args.push(("domain_name".to_string(), domain_name.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -54,71 +50,142 @@ impl ApplePay {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_domains_with` and pass the query parameters as a `ApplePayGetDomainsParams` instead.
+     */
+    #[deprecated(note = "use `get_domains_with` instead")]
+    pub async fn get_domains(
+        &self,
+        domain_name: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::ApplePayDomain>> {
+        let params = ApplePayGetDomainsParams {
+            domain_name: Some(domain_name.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_domains_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/apple_pay/domains` endpoint.
      *
      * As opposed to `get_domains`, this function returns all the pages of the request at once.
      *
      * <p>List apple pay domains.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ApplePayGetDomainsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_domains(
+    pub async fn get_all_domains_with(
         &self,
-        domain_name: &str,
+        params: &ApplePayGetDomainsParams,
     ) -> Result<Vec<crate::types::ApplePayDomain>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !domain_name.is_empty() {
+        if let Some(domain_name) = &params.domain_name {
             query_args.push(("domain_name".to_string(), domain_name.to_string()));
         }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/apple_pay/domains?{}", query_);
 
-        let mut resp: crate::types::ApplePayDomainList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::ApplePayDomain| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_domains_with` and pass the query parameters as a `ApplePayGetDomainsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_domains_with` instead")]
+    pub async fn get_all_domains(
+        &self,
+        domain_name: &str,
+    ) -> Result<Vec<crate::types::ApplePayDomain>> {
+        let params = ApplePayGetDomainsParams {
+            domain_name: Some(domain_name.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_domains_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/apple_pay/domains` endpoint.
+     *
+     * As opposed to `get_domains`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>List apple pay domains.</p>
+     */
+    pub fn get_domains_stream(
+        &self,
+        params: &ApplePayGetDomainsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ApplePayDomain> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(domain_name) = &params.domain_name {
+            query_args.push(("domain_name".to_string(), domain_name.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/apple_pay/domains?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::ApplePayDomain| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/apple_pay/domains` endpoint.
      *
      * <p>Create an apple pay domain.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_domain(&self) -> Result<crate::types::ApplePayDomain> {
+    pub async fn post_domain<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::ApplePayDomain> {
         let url = "/v1/apple_pay/domains".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -161,3 +228,45 @@ impl ApplePay {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `ApplePay::get_domains_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplePayGetDomainsParams {
+    /// The account's country.
+    pub domain_name: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl ApplePayGetDomainsParams {
+    pub fn domain_name<T: ToString>(mut self, domain_name: T) -> Self {
+        self.domain_name = Some(domain_name.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ApplicationFees {
     pub client: Client,
@@ -19,32 +17,31 @@ impl ApplicationFees {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- Only return application fees for the charge specified by this charge ID.
-     * * `created: &str`
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &ApplicationFeesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        charge: &str,
-        _created: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &ApplicationFeesGetPageParams,
     ) -> Result<Vec<crate::types::PlatformFee>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !charge.is_empty() {
+        if let Some(charge) = &params.charge {
             query_args.push(("charge".to_string(), charge.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -56,63 +53,129 @@ impl ApplicationFees {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `ApplicationFeesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        charge: &str,
+        created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::PlatformFee>> {
+        let params = ApplicationFeesGetPageParams {
+            charge: Some(charge.to_string()).filter(|s| !s.is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/application_fees` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of application fees you’ve previously collected. The application fees are returned in sorted order, with the most recent fees appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ApplicationFeesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        charge: &str,
-        _created: &str,
+        params: &ApplicationFeesGetPageParams,
     ) -> Result<Vec<crate::types::PlatformFee>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !charge.is_empty() {
+        if let Some(charge) = &params.charge {
             query_args.push(("charge".to_string(), charge.to_string()));
         }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/application_fees?{}", query_);
 
-        let mut resp: crate::types::GetApplicationFeesResponse =
-            self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::PlatformFee| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `ApplicationFeesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        charge: &str,
+        created: &str,
+    ) -> Result<Vec<crate::types::PlatformFee>> {
+        let params = ApplicationFeesGetPageParams {
+            charge: Some(charge.to_string()).filter(|s| !s.is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/application_fees` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of application fees you’ve previously collected. The application fees are returned in sorted order, with the most recent fees appearing first.</p>
+     */
+    pub fn get_stream(
+        &self,
+        params: &ApplicationFeesGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PlatformFee> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(charge) = &params.charge {
+            query_args.push(("charge".to_string(), charge.to_string()));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
         }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/application_fees?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::PlatformFee| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -147,15 +210,27 @@ impl ApplicationFees {
      *
      * * `fee: &str` -- The account's country.
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_fee_refund(&self, fee: &str, id: &str) -> Result<crate::types::FeeRefund> {
+    pub async fn post_fee_refund<B: serde::Serialize>(
+        &self,
+        fee: &str,
+        id: &str,
+        body: &B,
+    ) -> Result<crate::types::FeeRefund> {
         let url = format!(
             "/v1/application_fees/{}/refunds/{}",
             crate::progenitor_support::encode_path(fee),
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -185,14 +260,25 @@ impl ApplicationFees {
      * **Parameters:**
      *
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_refund(&self, id: &str) -> Result<crate::types::PlatformFee> {
+    pub async fn post_refund<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> Result<crate::types::PlatformFee> {
         let url = format!(
             "/v1/application_fees/{}/refund",
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -202,27 +288,27 @@ impl ApplicationFees {
      *
      * **Parameters:**
      *
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `id: &str` -- The account's country.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &ApplicationFeesGetRefundsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_refunds(
+    pub async fn get_refunds_with(
         &self,
-        ending_before: &str,
         id: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &ApplicationFeesGetRefundsParams,
     ) -> Result<Vec<crate::types::FeeRefund>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -238,56 +324,116 @@ impl ApplicationFees {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_refunds_with` and pass the query parameters as a `ApplicationFeesGetRefundsParams` instead.
+     */
+    #[deprecated(note = "use `get_refunds_with` instead")]
+    pub async fn get_refunds(
+        &self,
+        ending_before: &str,
+        id: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::FeeRefund>> {
+        let params = ApplicationFeesGetRefundsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_refunds_with(id, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/application_fees/{id}/refunds` endpoint.
      *
      * As opposed to `get_refunds`, this function returns all the pages of the request at once.
      *
      * <p>You can see a list of the refunds belonging to a specific application fee. Note that the 10 most recent refunds are always available by default on the application fee object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ApplicationFeesGetRefundsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_refunds(&self, id: &str) -> Result<Vec<crate::types::FeeRefund>> {
+    pub async fn get_all_refunds_with(
+        &self,
+        id: &str,
+        params: &ApplicationFeesGetRefundsParams,
+    ) -> Result<Vec<crate::types::FeeRefund>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/application_fees/{}/refunds",
+            "/v1/application_fees/{}/refunds?{}",
             crate::progenitor_support::encode_path(id),
+            query_
         );
 
-        let mut resp: crate::types::Refunds = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::FeeRefund| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_refunds_with` and pass the query parameters as a `ApplicationFeesGetRefundsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_refunds_with` instead")]
+    pub async fn get_all_refunds(&self, id: &str) -> Result<Vec<crate::types::FeeRefund>> {
+        let params = ApplicationFeesGetRefundsParams::default();
+        self.get_all_refunds_with(id, &params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/application_fees/{id}/refunds` endpoint.
+     *
+     * As opposed to `get_refunds`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>You can see a list of the refunds belonging to a specific application fee. Note that the 10 most recent refunds are always available by default on the application fee object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
+     */
+    pub fn get_refunds_stream(
+        &self,
+        id: &str,
+        params: &ApplicationFeesGetRefundsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::FeeRefund> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/application_fees/{}/refunds?{}",
+            crate::progenitor_support::encode_path(id),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::FeeRefund| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -306,13 +452,107 @@ impl ApplicationFees {
      * **Parameters:**
      *
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_refund_application_fees(&self, id: &str) -> Result<crate::types::FeeRefund> {
+    pub async fn post_refund_application_fees<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> Result<crate::types::FeeRefund> {
         let url = format!(
             "/v1/application_fees/{}/refunds",
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `ApplicationFees::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationFeesGetPageParams {
+    /// Only return application fees for the charge specified by this charge ID.
+    pub charge: Option<String>,
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl ApplicationFeesGetPageParams {
+    pub fn charge<T: ToString>(mut self, charge: T) -> Self {
+        self.charge = Some(charge.to_string());
+        self
+    }
+
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ApplicationFees::get_refunds_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ApplicationFeesGetRefundsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl ApplicationFeesGetRefundsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Balance {
     pub client: Client,
@@ -36,47 +34,40 @@ impl Balance {
      *
      * **Parameters:**
      *
-     * * `created: &str`
-     * * `currency: &str` -- Only return transactions in a certain currency. Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase. Must be a [supported currency](https://stripe.com/docs/currencies).
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `payout: &str` -- For automatic Stripe payouts only, only returns transactions that were paid out on the specified payout ID.
-     * * `source: &str` -- Only returns the original transaction.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `type_: &str` -- Only returns transactions of the given type. One of: `adjustment`, `advance`, `advance_funding`, `anticipation_repayment`, `application_fee`, `application_fee_refund`, `charge`, `connect_collection_transfer`, `contribution`, `issuing_authorization_hold`, `issuing_authorization_release`, `issuing_dispute`, `issuing_transaction`, `payment`, `payment_failure_refund`, `payment_refund`, `payout`, `payout_cancel`, `payout_failure`, `refund`, `refund_failure`, `reserve_transaction`, `reserved_funds`, `stripe_fee`, `stripe_fx_fee`, `tax_fee`, `topup`, `topup_reversal`, `transfer`, `transfer_cancel`, `transfer_failure`, or `transfer_refund`.
+     * * `params: &BalanceGetHistoryParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_history(
+    pub async fn get_history_with(
         &self,
-        _created: &str,
-        currency: &str,
-        ending_before: &str,
-        limit: i64,
-        payout: &str,
-        source: &str,
-        starting_after: &str,
-        type_: &str,
+        params: &BalanceGetHistoryParams,
     ) -> Result<Vec<crate::types::BalanceTransaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !payout.is_empty() {
+        if let Some(payout) = &params.payout {
             query_args.push(("payout".to_string(), payout.to_string()));
         }
-        if !source.is_empty() {
+        if let Some(source) = &params.source {
             query_args.push(("source".to_string(), source.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -88,6 +79,35 @@ impl Balance {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_history_with` and pass the query parameters as a `BalanceGetHistoryParams` instead.
+     */
+    #[deprecated(note = "use `get_history_with` instead")]
+    pub async fn get_history(
+        &self,
+        created: &str,
+        currency: &str,
+        ending_before: &str,
+        limit: i64,
+        payout: &str,
+        source: &str,
+        starting_after: &str,
+        type_: &str,
+    ) -> Result<Vec<crate::types::BalanceTransaction>> {
+        let params = BalanceGetHistoryParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            payout: Some(payout.to_string()).filter(|s| !s.is_empty()),
+            source: Some(source.to_string()).filter(|s| !s.is_empty()),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_history_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/balance/history` endpoint.
      *
@@ -96,68 +116,126 @@ impl Balance {
      * <p>Returns a list of transactions that have contributed to the Stripe account balance (e.g., charges, transfers, and so forth). The transactions are returned in sorted order, with the most recent transactions appearing first.</p>
      *
      * <p>Note that this endpoint was previously called “Balance history” and used the path <code>/v1/balance/history</code>.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &BalanceGetHistoryParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_history_with(
+        &self,
+        params: &BalanceGetHistoryParams,
+    ) -> Result<Vec<crate::types::BalanceTransaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(currency) = &params.currency {
+            query_args.push(("currency".to_string(), currency.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(payout) = &params.payout {
+            query_args.push(("payout".to_string(), payout.to_string()));
+        }
+        if let Some(source) = &params.source {
+            query_args.push(("source".to_string(), source.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/balance/history?{}", query_);
+
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::BalanceTransaction| Some(item.id.clone()),
+        )
+        .await
+    }
+
+    /**
+     * Deprecated: use `get_all_history_with` and pass the query parameters as a `BalanceGetHistoryParams` instead.
      */
+    #[deprecated(note = "use `get_all_history_with` instead")]
     pub async fn get_all_history(
         &self,
-        _created: &str,
+        created: &str,
         currency: &str,
         payout: &str,
         source: &str,
         type_: &str,
     ) -> Result<Vec<crate::types::BalanceTransaction>> {
+        let params = BalanceGetHistoryParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            payout: Some(payout.to_string()).filter(|s| !s.is_empty()),
+            source: Some(source.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_history_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/v1/balance/history` endpoint.
+     *
+     * As opposed to `get_history`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of transactions that have contributed to the Stripe account balance (e.g., charges, transfers, and so forth). The transactions are returned in sorted order, with the most recent transactions appearing first.</p>
+     *
+     * <p>Note that this endpoint was previously called “Balance history” and used the path <code>/v1/balance/history</code>.</p>
+     */
+    pub fn get_history_stream(
+        &self,
+        params: &BalanceGetHistoryParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BalanceTransaction> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !currency.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !payout.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(payout) = &params.payout {
             query_args.push(("payout".to_string(), payout.to_string()));
         }
-        if !source.is_empty() {
+        if let Some(source) = &params.source {
             query_args.push(("source".to_string(), source.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/balance/history?{}", query_);
 
-        let mut resp: crate::types::BalanceTransactionsList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
-
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::BalanceTransaction| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -181,3 +259,72 @@ impl Balance {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Balance::get_history_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BalanceGetHistoryParams {
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// Only return transactions in a certain currency. Three-letter [ISO currency code](https://www.iso.org/iso-4217-currency-codes.html), in lowercase. Must be a [supported currency](https://stripe.com/docs/currencies).
+    pub currency: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// For automatic Stripe payouts only, only returns transactions that were paid out on the specified payout ID.
+    pub payout: Option<String>,
+    /// Only returns the original transaction.
+    pub source: Option<String>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Only returns transactions of the given type. One of: `adjustment`, `advance`, `advance_funding`, `anticipation_repayment`, `application_fee`, `application_fee_refund`, `charge`, `connect_collection_transfer`, `contribution`, `issuing_authorization_hold`, `issuing_authorization_release`, `issuing_dispute`, `issuing_transaction`, `payment`, `payment_failure_refund`, `payment_refund`, `payout`, `payout_cancel`, `payout_failure`, `refund`, `refund_failure`, `reserve_transaction`, `reserved_funds`, `stripe_fee`, `stripe_fx_fee`, `tax_fee`, `topup`, `topup_reversal`, `transfer`, `transfer_cancel`, `transfer_failure`, or `transfer_refund`.
+    pub type_: Option<String>,
+}
+
+impl BalanceGetHistoryParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn payout<T: ToString>(mut self, payout: T) -> Self {
+        self.payout = Some(payout.to_string());
+        self
+    }
+
+    pub fn source<T: ToString>(mut self, source: T) -> Self {
+        self.source = Some(source.to_string());
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
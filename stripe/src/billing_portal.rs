@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct BillingPortal {
     pub client: Client,
@@ -19,35 +17,31 @@ impl BillingPortal {
      *
      * **Parameters:**
      *
-     * * `active: bool` -- Only return configurations that are active or inactive (e.g., pass `true` to only list active configurations).
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `is_default: bool` -- Only return the default or non-default configurations (e.g., pass `true` to only list the default configuration).
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &BillingPortalGetConfigurationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_configurations(
+    pub async fn get_configurations_with(
         &self,
-        active: bool,
-        ending_before: &str,
-        is_default: bool,
-        limit: i64,
-        starting_after: &str,
+        params: &BillingPortalGetConfigurationsParams,
     ) -> Result<Vec<crate::types::PortalConfiguration>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if active {
+        if let Some(active) = &params.active {
             query_args.push(("active".to_string(), active.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if is_default {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(is_default) = &params.is_default {
             query_args.push(("is_default".to_string(), is_default.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -60,76 +54,152 @@ impl BillingPortal {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_configurations_with` and pass the query parameters as a `BillingPortalGetConfigurationsParams` instead.
+     */
+    #[deprecated(note = "use `get_configurations_with` instead")]
+    pub async fn get_configurations(
+        &self,
+        active: bool,
+        ending_before: &str,
+        is_default: bool,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::PortalConfiguration>> {
+        let params = BillingPortalGetConfigurationsParams {
+            active: Some(active).filter(|v| *v),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            is_default: Some(is_default).filter(|v| *v),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_configurations_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/billing_portal/configurations` endpoint.
      *
      * As opposed to `get_configurations`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of configurations that describe the functionality of the customer portal.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &BillingPortalGetConfigurationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_configurations(
+    pub async fn get_all_configurations_with(
         &self,
-        active: bool,
-        is_default: bool,
+        params: &BillingPortalGetConfigurationsParams,
     ) -> Result<Vec<crate::types::PortalConfiguration>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if active {
+        if let Some(active) = &params.active {
             query_args.push(("active".to_string(), active.to_string()));
         }
-        if is_default {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(is_default) = &params.is_default {
             query_args.push(("is_default".to_string(), is_default.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/billing_portal/configurations?{}", query_);
 
-        let mut resp: crate::types::GetBillingPortalConfigurationsResponse =
-            self.client.get(&url, None).await?;
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::PortalConfiguration| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_configurations_with` and pass the query parameters as a `BillingPortalGetConfigurationsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_configurations_with` instead")]
+    pub async fn get_all_configurations(
+        &self,
+        active: bool,
+        is_default: bool,
+    ) -> Result<Vec<crate::types::PortalConfiguration>> {
+        let params = BillingPortalGetConfigurationsParams {
+            active: Some(active).filter(|v| *v),
+            is_default: Some(is_default).filter(|v| *v),
+            ..Default::default()
+        };
+        self.get_all_configurations_with(&params).await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/billing_portal/configurations` endpoint.
+     *
+     * As opposed to `get_configurations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of configurations that describe the functionality of the customer portal.</p>
+     */
+    pub fn get_configurations_stream(
+        &self,
+        params: &BillingPortalGetConfigurationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PortalConfiguration> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(active) = &params.active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        if let Some(is_default) = &params.is_default {
+            query_args.push(("is_default".to_string(), is_default.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/billing_portal/configurations?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::PortalConfiguration| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/billing_portal/configurations` endpoint.
      *
      * <p>Creates a configuration that describes the functionality and behavior of a PortalSession</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_configuration(&self) -> Result<crate::types::PortalConfiguration> {
+    pub async fn post_configuration<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::PortalConfiguration> {
         let url = "/v1/billing_portal/configurations".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -162,26 +232,96 @@ impl BillingPortal {
      * **Parameters:**
      *
      * * `configuration: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_configurations_configuration(
+    pub async fn post_configurations_configuration<B: serde::Serialize>(
         &self,
         configuration: &str,
+        body: &B,
     ) -> Result<crate::types::PortalConfiguration> {
         let url = format!(
             "/v1/billing_portal/configurations/{}",
             crate::progenitor_support::encode_path(configuration),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
      * This function performs a `POST` to the `/v1/billing_portal/sessions` endpoint.
      *
      * <p>Creates a session of the customer portal.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_session(&self) -> Result<crate::types::PortalSession> {
+    pub async fn post_session<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::PortalSession> {
         let url = "/v1/billing_portal/sessions".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `BillingPortal::get_configurations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingPortalGetConfigurationsParams {
+    /// Only return configurations that are active or inactive (e.g., pass `true` to only list active configurations).
+    pub active: Option<bool>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// Only return the default or non-default configurations (e.g., pass `true` to only list the default configuration).
+    pub is_default: Option<bool>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl BillingPortalGetConfigurationsParams {
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn is_default(mut self, is_default: bool) -> Self {
+        self.is_default = Some(is_default);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
     }
 }
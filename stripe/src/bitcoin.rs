@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Bitcoin {
     pub client: Client,
@@ -19,40 +17,34 @@ impl Bitcoin {
      *
      * **Parameters:**
      *
-     * * `active: bool` -- Whether the account can create live charges.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `filled: bool` -- Whether the account can create live charges.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `uncaptured_funds: bool` -- Whether the account can create live charges.
+     * * `params: &BitcoinGetReceiversParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_receivers(
+    pub async fn get_receivers_with(
         &self,
-        active: bool,
-        ending_before: &str,
-        filled: bool,
-        limit: i64,
-        starting_after: &str,
-        uncaptured_funds: bool,
+        params: &BitcoinGetReceiversParams,
     ) -> Result<Vec<crate::types::BitcoinReceiver>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if active {
+        if let Some(active) = &params.active {
             query_args.push(("active".to_string(), active.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if filled {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(filled) = &params.filled {
             query_args.push(("filled".to_string(), filled.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if uncaptured_funds {
+        if let Some(uncaptured_funds) = &params.uncaptured_funds {
             query_args.push(("uncaptured_funds".to_string(), uncaptured_funds.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -64,70 +56,139 @@ impl Bitcoin {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_receivers_with` and pass the query parameters as a `BitcoinGetReceiversParams` instead.
+     */
+    #[deprecated(note = "use `get_receivers_with` instead")]
+    pub async fn get_receivers(
+        &self,
+        active: bool,
+        ending_before: &str,
+        filled: bool,
+        limit: i64,
+        starting_after: &str,
+        uncaptured_funds: bool,
+    ) -> Result<Vec<crate::types::BitcoinReceiver>> {
+        let params = BitcoinGetReceiversParams {
+            active: Some(active).filter(|v| *v),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            filled: Some(filled).filter(|v| *v),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            uncaptured_funds: Some(uncaptured_funds).filter(|v| *v),
+            ..Default::default()
+        };
+        self.get_receivers_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/bitcoin/receivers` endpoint.
      *
      * As opposed to `get_receivers`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of your receivers. Receivers are returned sorted by creation date, with the most recently created receivers appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &BitcoinGetReceiversParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_receivers(
+    pub async fn get_all_receivers_with(
         &self,
-        active: bool,
-        filled: bool,
-        uncaptured_funds: bool,
+        params: &BitcoinGetReceiversParams,
     ) -> Result<Vec<crate::types::BitcoinReceiver>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if active {
+        if let Some(active) = &params.active {
             query_args.push(("active".to_string(), active.to_string()));
         }
-        if filled {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(filled) = &params.filled {
             query_args.push(("filled".to_string(), filled.to_string()));
         }
-        if uncaptured_funds {
+        if let Some(uncaptured_funds) = &params.uncaptured_funds {
             query_args.push(("uncaptured_funds".to_string(), uncaptured_funds.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/bitcoin/receivers?{}", query_);
 
-        let mut resp: crate::types::GetBitcoinReceiversResponse =
-            self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::BitcoinReceiver| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_receivers_with` and pass the query parameters as a `BitcoinGetReceiversParams` instead.
+     */
+    #[deprecated(note = "use `get_all_receivers_with` instead")]
+    pub async fn get_all_receivers(
+        &self,
+        active: bool,
+        filled: bool,
+        uncaptured_funds: bool,
+    ) -> Result<Vec<crate::types::BitcoinReceiver>> {
+        let params = BitcoinGetReceiversParams {
+            active: Some(active).filter(|v| *v),
+            filled: Some(filled).filter(|v| *v),
+            uncaptured_funds: Some(uncaptured_funds).filter(|v| *v),
+            ..Default::default()
+        };
+        self.get_all_receivers_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers` endpoint.
+     *
+     * As opposed to `get_receivers`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of your receivers. Receivers are returned sorted by creation date, with the most recently created receivers appearing first.</p>
+     */
+    pub fn get_receivers_stream(
+        &self,
+        params: &BitcoinGetReceiversParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BitcoinReceiver> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(active) = &params.active {
+            query_args.push(("active".to_string(), active.to_string()));
         }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(filled) = &params.filled {
+            query_args.push(("filled".to_string(), filled.to_string()));
+        }
+        if let Some(uncaptured_funds) = &params.uncaptured_funds {
+            query_args.push(("uncaptured_funds".to_string(), uncaptured_funds.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/bitcoin/receivers?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::BitcoinReceiver| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -156,32 +217,30 @@ impl Bitcoin {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- Only return transactions for the customer specified by this customer ID.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `receiver: &str` -- The account's country.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &BitcoinGetReceiversReceiverTransactionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_receivers_receiver_transactions(
+    pub async fn get_receivers_receiver_transactions_with(
         &self,
-        customer: &str,
-        ending_before: &str,
-        limit: i64,
         receiver: &str,
-        starting_after: &str,
+        params: &BitcoinGetReceiversReceiverTransactionsParams,
     ) -> Result<Vec<crate::types::BitcoinTransaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -197,22 +256,54 @@ impl Bitcoin {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_receivers_receiver_transactions_with` and pass the query parameters as a `BitcoinGetReceiversReceiverTransactionsParams` instead.
+     */
+    #[deprecated(note = "use `get_receivers_receiver_transactions_with` instead")]
+    pub async fn get_receivers_receiver_transactions(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        receiver: &str,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::BitcoinTransaction>> {
+        let params = BitcoinGetReceiversReceiverTransactionsParams {
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_receivers_receiver_transactions_with(receiver, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/bitcoin/receivers/{receiver}/transactions` endpoint.
      *
      * As opposed to `get_receivers_receiver_transactions`, this function returns all the pages of the request at once.
      *
      * <p>List bitcoin transacitons for a given receiver.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &BitcoinGetReceiversReceiverTransactionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_receivers_receiver_transactions(
+    pub async fn get_all_receivers_receiver_transactions_with(
         &self,
-        customer: &str,
         receiver: &str,
+        params: &BitcoinGetReceiversReceiverTransactionsParams,
     ) -> Result<Vec<crate::types::BitcoinTransaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
             "/v1/bitcoin/receivers/{}/transactions?{}",
@@ -220,43 +311,78 @@ impl Bitcoin {
             query_
         );
 
-        let mut resp: crate::types::Transactions = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::BitcoinTransaction| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_receivers_receiver_transactions_with` and pass the query parameters as a `BitcoinGetReceiversReceiverTransactionsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_receivers_receiver_transactions_with` instead")]
+    pub async fn get_all_receivers_receiver_transactions(
+        &self,
+        customer: &str,
+        receiver: &str,
+    ) -> Result<Vec<crate::types::BitcoinTransaction>> {
+        let params = BitcoinGetReceiversReceiverTransactionsParams {
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_receivers_receiver_transactions_with(receiver, &params)
+            .await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/receivers/{receiver}/transactions` endpoint.
+     *
+     * As opposed to `get_receivers_receiver_transactions`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>List bitcoin transacitons for a given receiver.</p>
+     */
+    pub fn get_receivers_receiver_transactions_stream(
+        &self,
+        receiver: &str,
+        params: &BitcoinGetReceiversReceiverTransactionsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BitcoinTransaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(customer) = &params.customer {
+            query_args.push(("customer".to_string(), customer.to_string()));
         }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/bitcoin/receivers/{}/transactions?{}",
+            crate::progenitor_support::encode_path(receiver),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::BitcoinTransaction| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -266,35 +392,31 @@ impl Bitcoin {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- Only return transactions for the customer specified by this customer ID.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `receiver: &str` -- The account's country.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &BitcoinGetTransactionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_transactions(
+    pub async fn get_transactions_with(
         &self,
-        customer: &str,
-        ending_before: &str,
-        limit: i64,
-        receiver: &str,
-        starting_after: &str,
+        params: &BitcoinGetTransactionsParams,
     ) -> Result<Vec<crate::types::BitcoinTransaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !receiver.is_empty() {
+        if let Some(receiver) = &params.receiver {
             query_args.push(("receiver".to_string(), receiver.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -306,64 +428,275 @@ impl Bitcoin {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_transactions_with` and pass the query parameters as a `BitcoinGetTransactionsParams` instead.
+     */
+    #[deprecated(note = "use `get_transactions_with` instead")]
+    pub async fn get_transactions(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        receiver: &str,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::BitcoinTransaction>> {
+        let params = BitcoinGetTransactionsParams {
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            receiver: Some(receiver.to_string()).filter(|s| !s.is_empty()),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_transactions_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/bitcoin/transactions` endpoint.
      *
      * As opposed to `get_transactions`, this function returns all the pages of the request at once.
      *
      * <p>List bitcoin transacitons for a given receiver.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &BitcoinGetTransactionsParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_transactions_with(
+        &self,
+        params: &BitcoinGetTransactionsParams,
+    ) -> Result<Vec<crate::types::BitcoinTransaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(customer) = &params.customer {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(receiver) = &params.receiver {
+            query_args.push(("receiver".to_string(), receiver.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/bitcoin/transactions?{}", query_);
+
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::BitcoinTransaction| Some(item.id.clone()),
+        )
+        .await
+    }
+
+    /**
+     * Deprecated: use `get_all_transactions_with` and pass the query parameters as a `BitcoinGetTransactionsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_transactions_with` instead")]
     pub async fn get_all_transactions(
         &self,
         customer: &str,
         receiver: &str,
     ) -> Result<Vec<crate::types::BitcoinTransaction>> {
+        let params = BitcoinGetTransactionsParams {
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            receiver: Some(receiver.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_transactions_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/v1/bitcoin/transactions` endpoint.
+     *
+     * As opposed to `get_transactions`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>List bitcoin transacitons for a given receiver.</p>
+     */
+    pub fn get_transactions_stream(
+        &self,
+        params: &BitcoinGetTransactionsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BitcoinTransaction> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !receiver.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(receiver) = &params.receiver {
             query_args.push(("receiver".to_string(), receiver.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/bitcoin/transactions?{}", query_);
 
-        let mut resp: crate::types::Transactions = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::BitcoinTransaction| Some(item.id.clone()),
+        )
+    }
+}
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+/// The query parameters of `Bitcoin::get_receivers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BitcoinGetReceiversParams {
+    /// Whether the account can create live charges.
+    pub active: Option<bool>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// Whether the account can create live charges.
+    pub filled: Option<bool>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Whether the account can create live charges.
+    pub uncaptured_funds: Option<bool>,
+}
 
-            data.append(&mut resp.data);
+impl BitcoinGetReceiversParams {
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
 
-            has_more = resp.has_more;
-        }
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
 
-        // Return our response data.
-        Ok(data.to_vec())
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = Some(filled);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn uncaptured_funds(mut self, uncaptured_funds: bool) -> Self {
+        self.uncaptured_funds = Some(uncaptured_funds);
+        self
+    }
+}
+
+/// The query parameters of `Bitcoin::get_receivers_receiver_transactions_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BitcoinGetReceiversReceiverTransactionsParams {
+    /// Only return transactions for the customer specified by this customer ID.
+    pub customer: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl BitcoinGetReceiversReceiverTransactionsParams {
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Bitcoin::get_transactions_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BitcoinGetTransactionsParams {
+    /// Only return transactions for the customer specified by this customer ID.
+    pub customer: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// The account's country.
+    pub receiver: Option<String>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl BitcoinGetTransactionsParams {
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn receiver<T: ToString>(mut self, receiver: T) -> Self {
+        self.receiver = Some(receiver.to_string());
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
     }
 }
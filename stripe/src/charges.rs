@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Charges {
     pub client: Client,
@@ -19,42 +17,37 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `created: &str`
-     * * `customer: &str` -- Only return charges for the customer specified by this customer ID.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `payment_intent: &str` -- Only return charges that were created by the PaymentIntent specified by this PaymentIntent ID.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `transfer_group: &str` -- Only return charges for this transfer group.
+     * * `params: &ChargesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        _created: &str,
-        customer: &str,
-        ending_before: &str,
-        limit: i64,
-        payment_intent: &str,
-        starting_after: &str,
-        transfer_group: &str,
+        params: &ChargesGetPageParams,
     ) -> Result<Vec<crate::types::Charge>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !payment_intent.is_empty() {
+        if let Some(payment_intent) = &params.payment_intent {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !transfer_group.is_empty() {
+        if let Some(transfer_group) = &params.transfer_group {
             query_args.push(("transfer_group".to_string(), transfer_group.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -66,80 +59,169 @@ impl Charges {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `ChargesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        created: &str,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+        transfer_group: &str,
+    ) -> Result<Vec<crate::types::Charge>> {
+        let params = ChargesGetPageParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            payment_intent: Some(payment_intent.to_string()).filter(|s| !s.is_empty()),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            transfer_group: Some(transfer_group.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/charges` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of charges you’ve previously created. The charges are returned in sorted order, with the most recent charges appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ChargesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        _created: &str,
-        customer: &str,
-        payment_intent: &str,
-        transfer_group: &str,
+        params: &ChargesGetPageParams,
     ) -> Result<Vec<crate::types::Charge>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !payment_intent.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(payment_intent) = &params.payment_intent {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
-        if !transfer_group.is_empty() {
+        if let Some(transfer_group) = &params.transfer_group {
             query_args.push(("transfer_group".to_string(), transfer_group.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/charges?{}", query_);
 
-        let mut resp: crate::types::Charges = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Charge| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `ChargesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        created: &str,
+        customer: &str,
+        payment_intent: &str,
+        transfer_group: &str,
+    ) -> Result<Vec<crate::types::Charge>> {
+        let params = ChargesGetPageParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            payment_intent: Some(payment_intent.to_string()).filter(|s| !s.is_empty()),
+            transfer_group: Some(transfer_group.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/charges` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of charges you’ve previously created. The charges are returned in sorted order, with the most recent charges appearing first.</p>
+     */
+    pub fn get_stream(
+        &self,
+        params: &ChargesGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Charge> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
+            query_args.push(("customer".to_string(), customer.to_string()));
         }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(payment_intent) = &params.payment_intent {
+            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+        }
+        if let Some(transfer_group) = &params.transfer_group {
+            query_args.push(("transfer_group".to_string(), transfer_group.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/charges?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Charge| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/charges` endpoint.
      *
      * <p>To charge a credit card or other payment source, you create a <code>Charge</code> object. If your API key is in test mode, the supplied payment source (e.g., card) won’t actually be charged, although everything else will occur as if in live mode. (Stripe assumes that the charge would have completed successfully).</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::Charge> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::Charge> {
         let url = "/v1/charges".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -152,25 +234,25 @@ impl Charges {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `page: &str` -- A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
-     * * `query: &str` -- The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for charges](https://stripe.com/docs/search#query-fields-for-charges).
+     * * `params: &ChargesGetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_search(
+    pub async fn get_search_with(
         &self,
-        limit: i64,
-        page: &str,
-        query: &str,
+        params: &ChargesGetSearchParams,
     ) -> Result<Vec<crate::types::Charge>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !page.is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -182,6 +264,25 @@ impl Charges {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_search_with` and pass the query parameters as a `ChargesGetSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_search_with` instead")]
+    pub async fn get_search(
+        &self,
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> Result<Vec<crate::types::Charge>> {
+        let params = ChargesGetSearchParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            page: Some(page.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_search_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/charges/search` endpoint.
      *
@@ -191,52 +292,84 @@ impl Charges {
      * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ChargesGetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Charge>> {
+    pub async fn get_all_search_with(
+        &self,
+        params: &ChargesGetSearchParams,
+    ) -> Result<Vec<crate::types::Charge>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !query.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/charges/search?{}", query_);
 
-        let mut resp: crate::types::SearchResult = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::search(params.page.as_deref(), params.limit),
+            |item: &crate::types::Charge| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_search_with` and pass the query parameters as a `ChargesGetSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_all_search_with` instead")]
+    pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Charge>> {
+        let params = ChargesGetSearchParams {
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_search_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/charges/search` endpoint.
+     *
+     * As opposed to `get_search`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Search for charges you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_search_stream(
+        &self,
+        params: &ChargesGetSearchParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Charge> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        if let Some(query) = &params.query {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/charges/search?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::search(params.page.as_deref(), params.limit)
+                .resume(start),
+            max_items,
+            |item: &crate::types::Charge| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -266,14 +399,25 @@ impl Charges {
      * **Parameters:**
      *
      * * `charge: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_charges(&self, charge: &str) -> Result<crate::types::Charge> {
+    pub async fn post_charges<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> Result<crate::types::Charge> {
         let url = format!(
             "/v1/charges/{}",
             crate::progenitor_support::encode_path(charge),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -286,14 +430,25 @@ impl Charges {
      * **Parameters:**
      *
      * * `charge: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_capture(&self, charge: &str) -> Result<crate::types::Charge> {
+    pub async fn post_capture<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> Result<crate::types::Charge> {
         let url = format!(
             "/v1/charges/{}/capture",
             crate::progenitor_support::encode_path(charge),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -323,14 +478,25 @@ impl Charges {
      * **Parameters:**
      *
      * * `charge: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_dispute(&self, charge: &str) -> Result<crate::types::Dispute> {
+    pub async fn post_dispute<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> Result<crate::types::Dispute> {
         let url = format!(
             "/v1/charges/{}/dispute",
             crate::progenitor_support::encode_path(charge),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -341,14 +507,25 @@ impl Charges {
      * **Parameters:**
      *
      * * `charge: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_dispute_close(&self, charge: &str) -> Result<crate::types::Dispute> {
+    pub async fn post_dispute_close<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> Result<crate::types::Dispute> {
         let url = format!(
             "/v1/charges/{}/dispute/close",
             crate::progenitor_support::encode_path(charge),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -369,14 +546,25 @@ impl Charges {
      * **Parameters:**
      *
      * * `charge: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_refund(&self, charge: &str) -> Result<crate::types::Charge> {
+    pub async fn post_refund<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> Result<crate::types::Charge> {
         let url = format!(
             "/v1/charges/{}/refund",
             crate::progenitor_support::encode_path(charge),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -387,26 +575,26 @@ impl Charges {
      * **Parameters:**
      *
      * * `charge: &str` -- The account's country.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &ChargesGetRefundsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_refunds(
+    pub async fn get_refunds_with(
         &self,
         charge: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &ChargesGetRefundsParams,
     ) -> Result<Vec<crate::types::Refund>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -422,56 +610,116 @@ impl Charges {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_refunds_with` and pass the query parameters as a `ChargesGetRefundsParams` instead.
+     */
+    #[deprecated(note = "use `get_refunds_with` instead")]
+    pub async fn get_refunds(
+        &self,
+        charge: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::Refund>> {
+        let params = ChargesGetRefundsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_refunds_with(charge, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/charges/{charge}/refunds` endpoint.
      *
      * As opposed to `get_refunds`, this function returns all the pages of the request at once.
      *
      * <p>You can see a list of the refunds belonging to a specific charge. Note that the 10 most recent refunds are always available by default on the charge object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ChargesGetRefundsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_refunds(&self, charge: &str) -> Result<Vec<crate::types::Refund>> {
+    pub async fn get_all_refunds_with(
+        &self,
+        charge: &str,
+        params: &ChargesGetRefundsParams,
+    ) -> Result<Vec<crate::types::Refund>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/charges/{}/refunds",
+            "/v1/charges/{}/refunds?{}",
             crate::progenitor_support::encode_path(charge),
+            query_
         );
 
-        let mut resp: crate::types::RefundList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Refund| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_refunds_with` and pass the query parameters as a `ChargesGetRefundsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_refunds_with` instead")]
+    pub async fn get_all_refunds(&self, charge: &str) -> Result<Vec<crate::types::Refund>> {
+        let params = ChargesGetRefundsParams::default();
+        self.get_all_refunds_with(charge, &params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/charges/{charge}/refunds` endpoint.
+     *
+     * As opposed to `get_refunds`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>You can see a list of the refunds belonging to a specific charge. Note that the 10 most recent refunds are always available by default on the charge object. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional refunds.</p>
+     */
+    pub fn get_refunds_stream(
+        &self,
+        charge: &str,
+        params: &ChargesGetRefundsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Refund> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/charges/{}/refunds?{}",
+            crate::progenitor_support::encode_path(charge),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Refund| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -482,14 +730,25 @@ impl Charges {
      * **Parameters:**
      *
      * * `charge: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_refund_charges(&self, charge: &str) -> Result<crate::types::Refund> {
+    pub async fn post_refund_charges<B: serde::Serialize>(
+        &self,
+        charge: &str,
+        body: &B,
+    ) -> Result<crate::types::Refund> {
         let url = format!(
             "/v1/charges/{}/refunds",
             crate::progenitor_support::encode_path(charge),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -526,11 +785,13 @@ impl Charges {
      *
      * * `charge: &str` -- The account's country.
      * * `refund: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_refunds_refund(
+    pub async fn post_refunds_refund<B: serde::Serialize>(
         &self,
         charge: &str,
         refund: &str,
+        body: &B,
     ) -> Result<crate::types::Refund> {
         let url = format!(
             "/v1/charges/{}/refunds/{}",
@@ -538,6 +799,144 @@ impl Charges {
             crate::progenitor_support::encode_path(refund),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Charges::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChargesGetPageParams {
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// Only return charges for the customer specified by this customer ID.
+    pub customer: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// Only return charges that were created by the PaymentIntent specified by this PaymentIntent ID.
+    pub payment_intent: Option<String>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Only return charges for this transfer group.
+    pub transfer_group: Option<String>,
+}
+
+impl ChargesGetPageParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn payment_intent<T: ToString>(mut self, payment_intent: T) -> Self {
+        self.payment_intent = Some(payment_intent.to_string());
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn transfer_group<T: ToString>(mut self, transfer_group: T) -> Self {
+        self.transfer_group = Some(transfer_group.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Charges::get_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChargesGetSearchParams {
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
+    pub page: Option<String>,
+    /// The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for charges](https://stripe.com/docs/search#query-fields-for-charges).
+    pub query: Option<String>,
+}
+
+impl ChargesGetSearchParams {
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page<T: ToString>(mut self, page: T) -> Self {
+        self.page = Some(page.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Charges::get_refunds_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChargesGetRefundsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl ChargesGetRefundsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Checkout {
     pub client: Client,
@@ -19,35 +17,31 @@ impl Checkout {
      *
      * **Parameters:**
      *
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `payment_intent: &str` -- Only return the Checkout Session for the PaymentIntent specified.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `subscription: &str` -- Only return the Checkout Session for the subscription specified.
+     * * `params: &CheckoutGetSessionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_sessions(
+    pub async fn get_sessions_with(
         &self,
-        ending_before: &str,
-        limit: i64,
-        payment_intent: &str,
-        starting_after: &str,
-        subscription: &str,
+        params: &CheckoutGetSessionsParams,
     ) -> Result<Vec<crate::types::Session>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !payment_intent.is_empty() {
+        if let Some(payment_intent) = &params.payment_intent {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !subscription.is_empty() {
+        if let Some(subscription) = &params.subscription {
             query_args.push(("subscription".to_string(), subscription.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -60,76 +54,152 @@ impl Checkout {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_sessions_with` and pass the query parameters as a `CheckoutGetSessionsParams` instead.
+     */
+    #[deprecated(note = "use `get_sessions_with` instead")]
+    pub async fn get_sessions(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+        subscription: &str,
+    ) -> Result<Vec<crate::types::Session>> {
+        let params = CheckoutGetSessionsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            payment_intent: Some(payment_intent.to_string()).filter(|s| !s.is_empty()),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            subscription: Some(subscription.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_sessions_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/checkout/sessions` endpoint.
      *
      * As opposed to `get_sessions`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of Checkout Sessions.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CheckoutGetSessionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_sessions(
+    pub async fn get_all_sessions_with(
         &self,
-        payment_intent: &str,
-        subscription: &str,
+        params: &CheckoutGetSessionsParams,
     ) -> Result<Vec<crate::types::Session>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !payment_intent.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(payment_intent) = &params.payment_intent {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
-        if !subscription.is_empty() {
+        if let Some(subscription) = &params.subscription {
             query_args.push(("subscription".to_string(), subscription.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/checkout/sessions?{}", query_);
 
-        let mut resp: crate::types::PaymentPagesCheckoutSessionList =
-            self.client.get(&url, None).await?;
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Session| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_sessions_with` and pass the query parameters as a `CheckoutGetSessionsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_sessions_with` instead")]
+    pub async fn get_all_sessions(
+        &self,
+        payment_intent: &str,
+        subscription: &str,
+    ) -> Result<Vec<crate::types::Session>> {
+        let params = CheckoutGetSessionsParams {
+            payment_intent: Some(payment_intent.to_string()).filter(|s| !s.is_empty()),
+            subscription: Some(subscription.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_sessions_with(&params).await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/checkout/sessions` endpoint.
+     *
+     * As opposed to `get_sessions`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of Checkout Sessions.</p>
+     */
+    pub fn get_sessions_stream(
+        &self,
+        params: &CheckoutGetSessionsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Session> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        if let Some(payment_intent) = &params.payment_intent {
+            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+        }
+        if let Some(subscription) = &params.subscription {
+            query_args.push(("subscription".to_string(), subscription.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/checkout/sessions?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Session| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/checkout/sessions` endpoint.
      *
      * <p>Creates a Session object.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_session(&self) -> Result<crate::types::Session> {
+    pub async fn post_session<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::Session> {
         let url = "/v1/checkout/sessions".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -161,17 +231,25 @@ impl Checkout {
      * **Parameters:**
      *
      * * `session: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_sessions_session_expire(
+    pub async fn post_sessions_session_expire<B: serde::Serialize>(
         &self,
         session: &str,
+        body: &B,
     ) -> Result<crate::types::Session> {
         let url = format!(
             "/v1/checkout/sessions/{}/expire",
             crate::progenitor_support::encode_path(session),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -181,27 +259,27 @@ impl Checkout {
      *
      * **Parameters:**
      *
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `session: &str` -- The account's country.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &CheckoutGetSessionsSessionLineItemsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_sessions_session_line_items(
+    pub async fn get_sessions_session_line_items_with(
         &self,
-        ending_before: &str,
-        limit: i64,
         session: &str,
-        starting_after: &str,
+        params: &CheckoutGetSessionsSessionLineItemsParams,
     ) -> Result<Vec<crate::types::Item>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -217,58 +295,204 @@ impl Checkout {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_sessions_session_line_items_with` and pass the query parameters as a `CheckoutGetSessionsSessionLineItemsParams` instead.
+     */
+    #[deprecated(note = "use `get_sessions_session_line_items_with` instead")]
+    pub async fn get_sessions_session_line_items(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        session: &str,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::Item>> {
+        let params = CheckoutGetSessionsSessionLineItemsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_sessions_session_line_items_with(session, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/checkout/sessions/{session}/line_items` endpoint.
      *
      * As opposed to `get_sessions_session_line_items`, this function returns all the pages of the request at once.
      *
      * <p>When retrieving a Checkout Session, there is an includable <strong>line_items</strong> property containing the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CheckoutGetSessionsSessionLineItemsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_sessions_session_line_items(
+    pub async fn get_all_sessions_session_line_items_with(
         &self,
         session: &str,
+        params: &CheckoutGetSessionsSessionLineItemsParams,
     ) -> Result<Vec<crate::types::Item>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/checkout/sessions/{}/line_items",
+            "/v1/checkout/sessions/{}/line_items?{}",
             crate::progenitor_support::encode_path(session),
+            query_
         );
 
-        let mut resp: crate::types::LineItems = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Item| Some(item.id.clone()),
+        )
+        .await
+    }
+
+    /**
+     * Deprecated: use `get_all_sessions_session_line_items_with` and pass the query parameters as a `CheckoutGetSessionsSessionLineItemsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_sessions_session_line_items_with` instead")]
+    pub async fn get_all_sessions_session_line_items(
+        &self,
+        session: &str,
+    ) -> Result<Vec<crate::types::Item>> {
+        let params = CheckoutGetSessionsSessionLineItemsParams::default();
+        self.get_all_sessions_session_line_items_with(session, &params)
+            .await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/checkout/sessions/{session}/line_items` endpoint.
+     *
+     * As opposed to `get_sessions_session_line_items`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>When retrieving a Checkout Session, there is an includable <strong>line_items</strong> property containing the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_sessions_session_line_items_stream(
+        &self,
+        session: &str,
+        params: &CheckoutGetSessionsSessionLineItemsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Item> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/checkout/sessions/{}/line_items?{}",
+            crate::progenitor_support::encode_path(session),
+            query_
+        );
 
-            data.append(&mut resp.data);
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Item| Some(item.id.clone()),
+        )
+    }
+}
 
-            has_more = resp.has_more;
-        }
+/// The query parameters of `Checkout::get_sessions_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CheckoutGetSessionsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// Only return the Checkout Session for the PaymentIntent specified.
+    pub payment_intent: Option<String>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Only return the Checkout Session for the subscription specified.
+    pub subscription: Option<String>,
+}
 
-        // Return our response data.
-        Ok(data.to_vec())
+impl CheckoutGetSessionsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn payment_intent<T: ToString>(mut self, payment_intent: T) -> Self {
+        self.payment_intent = Some(payment_intent.to_string());
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn subscription<T: ToString>(mut self, subscription: T) -> Self {
+        self.subscription = Some(subscription.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Checkout::get_sessions_session_line_items_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CheckoutGetSessionsSessionLineItemsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl CheckoutGetSessionsSessionLineItemsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CreditNotes {
     pub client: Client,
@@ -19,35 +17,31 @@ impl CreditNotes {
      *
      * **Parameters:**
      *
-     * * `customer: &str` -- Only return credit notes for the customer specified by this customer ID.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `invoice: &str` -- Only return credit notes for the invoice specified by this invoice ID.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &CreditNotesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        customer: &str,
-        ending_before: &str,
-        invoice: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &CreditNotesGetPageParams,
     ) -> Result<Vec<crate::types::CreditNote>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if !invoice.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(invoice) = &params.invoice {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -59,65 +53,129 @@ impl CreditNotes {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `CreditNotesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        invoice: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::CreditNote>> {
+        let params = CreditNotesGetPageParams {
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            invoice: Some(invoice.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/credit_notes` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of credit notes.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CreditNotesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        customer: &str,
-        invoice: &str,
+        params: &CreditNotesGetPageParams,
     ) -> Result<Vec<crate::types::CreditNote>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !invoice.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(invoice) = &params.invoice {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/credit_notes?{}", query_);
 
-        let mut resp: crate::types::CreditNotesList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::CreditNote| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `CreditNotesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        customer: &str,
+        invoice: &str,
+    ) -> Result<Vec<crate::types::CreditNote>> {
+        let params = CreditNotesGetPageParams {
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            invoice: Some(invoice.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of credit notes.</p>
+     */
+    pub fn get_stream(
+        &self,
+        params: &CreditNotesGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CreditNote> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(customer) = &params.customer {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(invoice) = &params.invoice {
+            query_args.push(("invoice".to_string(), invoice.to_string()));
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/credit_notes?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::CreditNote| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -137,10 +195,20 @@ impl CreditNotes {
      *
      * <p>You may issue multiple credit notes for an invoice. Each credit note will increment the invoice’s <code>pre_payment_credit_notes_amount</code>
      * or <code>post_payment_credit_notes_amount</code> depending on its <code>status</code> at the time of credit note creation.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::CreditNote> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::CreditNote> {
         let url = "/v1/credit_notes".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -150,57 +218,40 @@ impl CreditNotes {
      *
      * **Parameters:**
      *
-     * * `amount: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
-     * * `credit_amount: i64` -- The integer amount in %s representing the amount to credit the customer's balance, which will be automatically applied to their next invoice.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `invoice: &str` -- The account's country.
-     * * `lines: &[String]` -- Line items that make up the credit note.
-     * * `memo: &str` -- The credit note's memo appears on the credit note PDF.
-     * * `metadata: &str` -- Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format. Individual keys can be unset by posting an empty value to them. All keys can be unset by posting an empty value to `metadata`.
-     * * `out_of_band_amount: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
-     * * `reason: crate::types::Reason` -- Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`.
-     * * `refund: &str` -- ID of an existing refund to link this credit note to.
-     * * `refund_amount: i64` -- The integer amount in %s representing the amount to refund. If set, a refund will be created for the charge associated with the invoice.
+     * * `params: &CreditNotesGetPreviewParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_preview(
+    pub async fn get_preview_with(
         &self,
-        amount: i64,
-        credit_amount: i64,
-        invoice: &str,
         _lines: &[String],
-        memo: &str,
         _metadata: &str,
-        out_of_band_amount: i64,
-        reason: crate::types::Reason,
-        refund: &str,
-        refund_amount: i64,
+        params: &CreditNotesGetPreviewParams,
     ) -> Result<crate::types::CreditNote> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if amount > 0 {
+        if let Some(amount) = &params.amount {
             query_args.push(("amount".to_string(), amount.to_string()));
         }
-        if credit_amount > 0 {
+        if let Some(credit_amount) = &params.credit_amount {
             query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
         }
-        if !invoice.is_empty() {
+        if let Some(invoice) = &params.invoice {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
-        if !memo.is_empty() {
+        if let Some(memo) = &params.memo {
             query_args.push(("memo".to_string(), memo.to_string()));
         }
-        if out_of_band_amount > 0 {
+        if let Some(out_of_band_amount) = &params.out_of_band_amount {
             query_args.push((
                 "out_of_band_amount".to_string(),
                 out_of_band_amount.to_string(),
             ));
         }
-        if !reason.to_string().is_empty() {
+        if let Some(reason) = &params.reason {
             query_args.push(("reason".to_string(), reason.to_string()));
         }
-        if !refund.is_empty() {
+        if let Some(refund) = &params.refund {
             query_args.push(("refund".to_string(), refund.to_string()));
         }
-        if refund_amount > 0 {
+        if let Some(refund_amount) = &params.refund_amount {
             query_args.push(("refund_amount".to_string(), refund_amount.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -210,34 +261,16 @@ impl CreditNotes {
     }
 
     /**
-     * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
-     *
-     * <p>When retrieving a credit note preview, you’ll get a <strong>lines</strong> property containing the first handful of those items. This URL you can retrieve the full (paginated) list of line items.</p>
+     * Deprecated: use `get_preview_with` and pass the query parameters as a `CreditNotesGetPreviewParams` instead.
      *
-     * **Parameters:**
-     *
-     * * `amount: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
-     * * `credit_amount: i64` -- The integer amount in %s representing the amount to credit the customer's balance, which will be automatically applied to their next invoice.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `invoice: &str` -- The account's country.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `lines: &[String]` -- Line items that make up the credit note.
-     * * `memo: &str` -- The credit note's memo appears on the credit note PDF.
-     * * `metadata: &str` -- Set of [key-value pairs](https://stripe.com/docs/api/metadata) that you can attach to an object. This can be useful for storing additional information about the object in a structured format. Individual keys can be unset by posting an empty value to them. All keys can be unset by posting an empty value to `metadata`.
-     * * `out_of_band_amount: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
-     * * `reason: crate::types::Reason` -- Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`.
-     * * `refund: &str` -- ID of an existing refund to link this credit note to.
-     * * `refund_amount: i64` -- The integer amount in %s representing the amount to refund. If set, a refund will be created for the charge associated with the invoice.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * `lines`, `metadata` are not sent.
      */
-    pub async fn get_preview_lines(
+    #[deprecated(note = "use `get_preview_with` instead")]
+    pub async fn get_preview(
         &self,
         amount: i64,
         credit_amount: i64,
-        ending_before: &str,
         invoice: &str,
-        limit: i64,
         _lines: &[String],
         memo: &str,
         _metadata: &str,
@@ -245,43 +278,75 @@ impl CreditNotes {
         reason: crate::types::Reason,
         refund: &str,
         refund_amount: i64,
-        starting_after: &str,
+    ) -> Result<crate::types::CreditNote> {
+        let params = CreditNotesGetPreviewParams {
+            amount: Some(amount).filter(|v| *v > 0),
+            credit_amount: Some(credit_amount).filter(|v| *v > 0),
+            invoice: Some(invoice.to_string()).filter(|s| !s.is_empty()),
+            memo: Some(memo.to_string()).filter(|s| !s.is_empty()),
+            out_of_band_amount: Some(out_of_band_amount).filter(|v| *v > 0),
+            reason: Some(reason).filter(|v| !v.to_string().is_empty()),
+            refund: Some(refund.to_string()).filter(|s| !s.is_empty()),
+            refund_amount: Some(refund_amount).filter(|v| *v > 0),
+        };
+        self.get_preview_with(_lines, _metadata, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
+     *
+     * <p>When retrieving a credit note preview, you’ll get a <strong>lines</strong> property containing the first handful of those items. This URL you can retrieve the full (paginated) list of line items.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CreditNotesGetPreviewLinesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_preview_lines_with(
+        &self,
+        _lines: &[String],
+        _metadata: &str,
+        params: &CreditNotesGetPreviewLinesParams,
     ) -> Result<Vec<crate::types::CreditNoteLineItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if amount > 0 {
+        if let Some(amount) = &params.amount {
             query_args.push(("amount".to_string(), amount.to_string()));
         }
-        if credit_amount > 0 {
+        if let Some(credit_amount) = &params.credit_amount {
             query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if !invoice.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(invoice) = &params.invoice {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !memo.is_empty() {
+        if let Some(memo) = &params.memo {
             query_args.push(("memo".to_string(), memo.to_string()));
         }
-        if out_of_band_amount > 0 {
+        if let Some(out_of_band_amount) = &params.out_of_band_amount {
             query_args.push((
                 "out_of_band_amount".to_string(),
                 out_of_band_amount.to_string(),
             ));
         }
-        if !reason.to_string().is_empty() {
+        if let Some(reason) = &params.reason {
             query_args.push(("reason".to_string(), reason.to_string()));
         }
-        if !refund.is_empty() {
+        if let Some(refund) = &params.refund {
             query_args.push(("refund".to_string(), refund.to_string()));
         }
-        if refund_amount > 0 {
+        if let Some(refund_amount) = &params.refund_amount {
             query_args.push(("refund_amount".to_string(), refund_amount.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -293,13 +358,118 @@ impl CreditNotes {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_preview_lines_with` and pass the query parameters as a `CreditNotesGetPreviewLinesParams` instead.
+     *
+     * `lines`, `metadata` are not sent.
+     */
+    #[deprecated(note = "use `get_preview_lines_with` instead")]
+    pub async fn get_preview_lines(
+        &self,
+        amount: i64,
+        credit_amount: i64,
+        ending_before: &str,
+        invoice: &str,
+        limit: i64,
+        _lines: &[String],
+        memo: &str,
+        _metadata: &str,
+        out_of_band_amount: i64,
+        reason: crate::types::Reason,
+        refund: &str,
+        refund_amount: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::CreditNoteLineItem>> {
+        let params = CreditNotesGetPreviewLinesParams {
+            amount: Some(amount).filter(|v| *v > 0),
+            credit_amount: Some(credit_amount).filter(|v| *v > 0),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            invoice: Some(invoice.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            memo: Some(memo.to_string()).filter(|s| !s.is_empty()),
+            out_of_band_amount: Some(out_of_band_amount).filter(|v| *v > 0),
+            reason: Some(reason).filter(|v| !v.to_string().is_empty()),
+            refund: Some(refund.to_string()).filter(|s| !s.is_empty()),
+            refund_amount: Some(refund_amount).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_preview_lines_with(_lines, _metadata, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
      *
      * As opposed to `get_preview_lines`, this function returns all the pages of the request at once.
      *
      * <p>When retrieving a credit note preview, you’ll get a <strong>lines</strong> property containing the first handful of those items. This URL you can retrieve the full (paginated) list of line items.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CreditNotesGetPreviewLinesParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_preview_lines_with(
+        &self,
+        _lines: &[String],
+        _metadata: &str,
+        params: &CreditNotesGetPreviewLinesParams,
+    ) -> Result<Vec<crate::types::CreditNoteLineItem>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(amount) = &params.amount {
+            query_args.push(("amount".to_string(), amount.to_string()));
+        }
+        if let Some(credit_amount) = &params.credit_amount {
+            query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(invoice) = &params.invoice {
+            query_args.push(("invoice".to_string(), invoice.to_string()));
+        }
+        if let Some(memo) = &params.memo {
+            query_args.push(("memo".to_string(), memo.to_string()));
+        }
+        if let Some(out_of_band_amount) = &params.out_of_band_amount {
+            query_args.push((
+                "out_of_band_amount".to_string(),
+                out_of_band_amount.to_string(),
+            ));
+        }
+        if let Some(reason) = &params.reason {
+            query_args.push(("reason".to_string(), reason.to_string()));
+        }
+        if let Some(refund) = &params.refund {
+            query_args.push(("refund".to_string(), refund.to_string()));
+        }
+        if let Some(refund_amount) = &params.refund_amount {
+            query_args.push(("refund_amount".to_string(), refund_amount.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/credit_notes/preview/lines?{}", query_);
+
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::CreditNoteLineItem| Some(item.id.clone()),
+        )
+        .await
+    }
+
+    /**
+     * Deprecated: use `get_all_preview_lines_with` and pass the query parameters as a `CreditNotesGetPreviewLinesParams` instead.
+     *
+     * `lines`, `metadata` are not sent.
+     */
+    #[deprecated(note = "use `get_all_preview_lines_with` instead")]
     pub async fn get_all_preview_lines(
         &self,
         amount: i64,
@@ -313,74 +483,84 @@ impl CreditNotes {
         refund: &str,
         refund_amount: i64,
     ) -> Result<Vec<crate::types::CreditNoteLineItem>> {
+        let params = CreditNotesGetPreviewLinesParams {
+            amount: Some(amount).filter(|v| *v > 0),
+            credit_amount: Some(credit_amount).filter(|v| *v > 0),
+            invoice: Some(invoice.to_string()).filter(|s| !s.is_empty()),
+            memo: Some(memo.to_string()).filter(|s| !s.is_empty()),
+            out_of_band_amount: Some(out_of_band_amount).filter(|v| *v > 0),
+            reason: Some(reason).filter(|v| !v.to_string().is_empty()),
+            refund: Some(refund.to_string()).filter(|s| !s.is_empty()),
+            refund_amount: Some(refund_amount).filter(|v| *v > 0),
+            ..Default::default()
+        };
+        self.get_all_preview_lines_with(_lines, _metadata, &params)
+            .await
+    }
+
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/preview/lines` endpoint.
+     *
+     * As opposed to `get_preview_lines`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>When retrieving a credit note preview, you’ll get a <strong>lines</strong> property containing the first handful of those items. This URL you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_preview_lines_stream(
+        &self,
+        _lines: &[String],
+        _metadata: &str,
+        params: &CreditNotesGetPreviewLinesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CreditNoteLineItem> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if amount > 0 {
+        if let Some(amount) = &params.amount {
             query_args.push(("amount".to_string(), amount.to_string()));
         }
-        if credit_amount > 0 {
+        if let Some(credit_amount) = &params.credit_amount {
             query_args.push(("credit_amount".to_string(), credit_amount.to_string()));
         }
-        if !invoice.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(invoice) = &params.invoice {
             query_args.push(("invoice".to_string(), invoice.to_string()));
         }
-        if !memo.is_empty() {
+        if let Some(memo) = &params.memo {
             query_args.push(("memo".to_string(), memo.to_string()));
         }
-        if out_of_band_amount > 0 {
+        if let Some(out_of_band_amount) = &params.out_of_band_amount {
             query_args.push((
                 "out_of_band_amount".to_string(),
                 out_of_band_amount.to_string(),
             ));
         }
-        if !reason.to_string().is_empty() {
+        if let Some(reason) = &params.reason {
             query_args.push(("reason".to_string(), reason.to_string()));
         }
-        if !refund.is_empty() {
+        if let Some(refund) = &params.refund {
             query_args.push(("refund".to_string(), refund.to_string()));
         }
-        if refund_amount > 0 {
+        if let Some(refund_amount) = &params.refund_amount {
             query_args.push(("refund_amount".to_string(), refund_amount.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/credit_notes/preview/lines?{}", query_);
 
-        let mut resp: crate::types::Lines = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
-
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::CreditNoteLineItem| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -391,26 +571,26 @@ impl CreditNotes {
      * **Parameters:**
      *
      * * `credit_note: &str` -- The account's country.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &CreditNotesGetNoteLinesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_note_lines(
+    pub async fn get_note_lines_with(
         &self,
         credit_note: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &CreditNotesGetNoteLinesParams,
     ) -> Result<Vec<crate::types::CreditNoteLineItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -426,59 +606,119 @@ impl CreditNotes {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_note_lines_with` and pass the query parameters as a `CreditNotesGetNoteLinesParams` instead.
+     */
+    #[deprecated(note = "use `get_note_lines_with` instead")]
+    pub async fn get_note_lines(
+        &self,
+        credit_note: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::CreditNoteLineItem>> {
+        let params = CreditNotesGetNoteLinesParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_note_lines_with(credit_note, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
      *
      * As opposed to `get_note_lines`, this function returns all the pages of the request at once.
      *
      * <p>When retrieving a credit note, you’ll get a <strong>lines</strong> property containing the the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CreditNotesGetNoteLinesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_note_lines(
+    pub async fn get_all_note_lines_with(
         &self,
         credit_note: &str,
+        params: &CreditNotesGetNoteLinesParams,
     ) -> Result<Vec<crate::types::CreditNoteLineItem>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/credit_notes/{}/lines",
+            "/v1/credit_notes/{}/lines?{}",
             crate::progenitor_support::encode_path(credit_note),
+            query_
         );
 
-        let mut resp: crate::types::Lines = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::CreditNoteLineItem| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_note_lines_with` and pass the query parameters as a `CreditNotesGetNoteLinesParams` instead.
+     */
+    #[deprecated(note = "use `get_all_note_lines_with` instead")]
+    pub async fn get_all_note_lines(
+        &self,
+        credit_note: &str,
+    ) -> Result<Vec<crate::types::CreditNoteLineItem>> {
+        let params = CreditNotesGetNoteLinesParams::default();
+        self.get_all_note_lines_with(credit_note, &params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/credit_notes/{credit_note}/lines` endpoint.
+     *
+     * As opposed to `get_note_lines`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>When retrieving a credit note, you’ll get a <strong>lines</strong> property containing the the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_note_lines_stream(
+        &self,
+        credit_note: &str,
+        params: &CreditNotesGetNoteLinesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CreditNoteLineItem> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/credit_notes/{}/lines?{}",
+            crate::progenitor_support::encode_path(credit_note),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::CreditNoteLineItem| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -508,14 +748,25 @@ impl CreditNotes {
      * **Parameters:**
      *
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_credit_notes(&self, id: &str) -> Result<crate::types::CreditNote> {
+    pub async fn post_credit_notes<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> Result<crate::types::CreditNote> {
         let url = format!(
             "/v1/credit_notes/{}",
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -526,13 +777,262 @@ impl CreditNotes {
      * **Parameters:**
      *
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_void(&self, id: &str) -> Result<crate::types::CreditNote> {
+    pub async fn post_void<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> Result<crate::types::CreditNote> {
         let url = format!(
             "/v1/credit_notes/{}/void",
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `CreditNotes::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CreditNotesGetPageParams {
+    /// Only return credit notes for the customer specified by this customer ID.
+    pub customer: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// Only return credit notes for the invoice specified by this invoice ID.
+    pub invoice: Option<String>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl CreditNotesGetPageParams {
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn invoice<T: ToString>(mut self, invoice: T) -> Self {
+        self.invoice = Some(invoice.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `CreditNotes::get_preview_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CreditNotesGetPreviewParams {
+    /// Time at which the account was connected. Measured in seconds since the Unix epoch.
+    pub amount: Option<i64>,
+    /// The integer amount in %s representing the amount to credit the customer's balance, which will be automatically applied to their next invoice.
+    pub credit_amount: Option<i64>,
+    /// The account's country.
+    pub invoice: Option<String>,
+    /// The credit note's memo appears on the credit note PDF.
+    pub memo: Option<String>,
+    /// Time at which the account was connected. Measured in seconds since the Unix epoch.
+    pub out_of_band_amount: Option<i64>,
+    /// Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`.
+    pub reason: Option<crate::types::Reason>,
+    /// ID of an existing refund to link this credit note to.
+    pub refund: Option<String>,
+    /// The integer amount in %s representing the amount to refund. If set, a refund will be created for the charge associated with the invoice.
+    pub refund_amount: Option<i64>,
+}
+
+impl CreditNotesGetPreviewParams {
+    pub fn amount(mut self, amount: i64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn credit_amount(mut self, credit_amount: i64) -> Self {
+        self.credit_amount = Some(credit_amount);
+        self
+    }
+
+    pub fn invoice<T: ToString>(mut self, invoice: T) -> Self {
+        self.invoice = Some(invoice.to_string());
+        self
+    }
+
+    pub fn memo<T: ToString>(mut self, memo: T) -> Self {
+        self.memo = Some(memo.to_string());
+        self
+    }
+
+    pub fn out_of_band_amount(mut self, out_of_band_amount: i64) -> Self {
+        self.out_of_band_amount = Some(out_of_band_amount);
+        self
+    }
+
+    pub fn reason(mut self, reason: crate::types::Reason) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    pub fn refund<T: ToString>(mut self, refund: T) -> Self {
+        self.refund = Some(refund.to_string());
+        self
+    }
+
+    pub fn refund_amount(mut self, refund_amount: i64) -> Self {
+        self.refund_amount = Some(refund_amount);
+        self
+    }
+}
+
+/// The query parameters of `CreditNotes::get_preview_lines_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CreditNotesGetPreviewLinesParams {
+    /// Time at which the account was connected. Measured in seconds since the Unix epoch.
+    pub amount: Option<i64>,
+    /// The integer amount in %s representing the amount to credit the customer's balance, which will be automatically applied to their next invoice.
+    pub credit_amount: Option<i64>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// The account's country.
+    pub invoice: Option<String>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// The credit note's memo appears on the credit note PDF.
+    pub memo: Option<String>,
+    /// Time at which the account was connected. Measured in seconds since the Unix epoch.
+    pub out_of_band_amount: Option<i64>,
+    /// Reason for issuing this credit note, one of `duplicate`, `fraudulent`, `order_change`, or `product_unsatisfactory`.
+    pub reason: Option<crate::types::Reason>,
+    /// ID of an existing refund to link this credit note to.
+    pub refund: Option<String>,
+    /// The integer amount in %s representing the amount to refund. If set, a refund will be created for the charge associated with the invoice.
+    pub refund_amount: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl CreditNotesGetPreviewLinesParams {
+    pub fn amount(mut self, amount: i64) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn credit_amount(mut self, credit_amount: i64) -> Self {
+        self.credit_amount = Some(credit_amount);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn invoice<T: ToString>(mut self, invoice: T) -> Self {
+        self.invoice = Some(invoice.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn memo<T: ToString>(mut self, memo: T) -> Self {
+        self.memo = Some(memo.to_string());
+        self
+    }
+
+    pub fn out_of_band_amount(mut self, out_of_band_amount: i64) -> Self {
+        self.out_of_band_amount = Some(out_of_band_amount);
+        self
+    }
+
+    pub fn reason(mut self, reason: crate::types::Reason) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    pub fn refund<T: ToString>(mut self, refund: T) -> Self {
+        self.refund = Some(refund.to_string());
+        self
+    }
+
+    pub fn refund_amount(mut self, refund_amount: i64) -> Self {
+        self.refund_amount = Some(refund_amount);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `CreditNotes::get_note_lines_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CreditNotesGetNoteLinesParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl CreditNotesGetNoteLinesParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Customers {
     pub client: Client,
@@ -19,37 +17,34 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `created: &str`
-     * * `email: &str` -- A case-sensitive filter on the list based on the customer's `email` field. The value must be a string.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `test_clock: &str` -- Provides a list of customers that are associated with the specified test clock. The response will not include customers with test clocks if this parameter is not set.
+     * * `params: &CustomersGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        _created: &str,
-        email: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
-        test_clock: &str,
+        params: &CustomersGetPageParams,
     ) -> Result<Vec<crate::types::Customer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !email.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !test_clock.is_empty() {
+        if let Some(test_clock) = &params.test_clock {
             query_args.push(("test_clock".to_string(), test_clock.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -61,66 +56,139 @@ impl Customers {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `CustomersGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        created: &str,
+        email: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        test_clock: &str,
+    ) -> Result<Vec<crate::types::Customer>> {
+        let params = CustomersGetPageParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            test_clock: Some(test_clock.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/customers` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of your customers. The customers are returned sorted by creation date, with the most recent customers appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CustomersGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        _created: &str,
-        email: &str,
-        test_clock: &str,
+        params: &CustomersGetPageParams,
     ) -> Result<Vec<crate::types::Customer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !email.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
-        if !test_clock.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(test_clock) = &params.test_clock {
             query_args.push(("test_clock".to_string(), test_clock.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/customers?{}", query_);
 
-        let mut resp: crate::types::GetCustomersResponse = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Customer| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `CustomersGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        created: &str,
+        email: &str,
+        test_clock: &str,
+    ) -> Result<Vec<crate::types::Customer>> {
+        let params = CustomersGetPageParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+            test_clock: Some(test_clock.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/customers` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of your customers. The customers are returned sorted by creation date, with the most recent customers appearing first.</p>
+     */
+    pub fn get_stream(
+        &self,
+        params: &CustomersGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Customer> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(email) = &params.email {
+            query_args.push(("email".to_string(), email.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        if let Some(test_clock) = &params.test_clock {
+            query_args.push(("test_clock".to_string(), test_clock.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/customers?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Customer| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -128,9 +196,18 @@ impl Customers {
      *
      * <p>Creates a new customer object.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::Customer> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostCustomersRequest,
+    ) -> Result<crate::types::Customer> {
         let url = "/v1/customers".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -143,36 +220,56 @@ impl Customers {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `page: &str` -- A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
-     * * `query: &str` -- The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for customers](https://stripe.com/docs/search#query-fields-for-customers).
+     * * `params: &CustomersGetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_search(
+    pub async fn get_search_with(
         &self,
-        limit: i64,
-        page: &str,
-        query: &str,
-    ) -> Result<Vec<crate::types::Charge>> {
+        params: &CustomersGetSearchParams,
+    ) -> Result<Vec<crate::types::Customer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !page.is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/customers/search?{}", query_);
 
-        let resp: crate::types::SearchResult = self.client.get(&url, None).await?;
+        let resp: crate::types::GetCustomersSearchResponseResult =
+            self.client.get(&url, None).await?;
 
         // Return our response data.
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_search_with` and pass the query parameters as a `CustomersGetSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_search_with` instead")]
+    pub async fn get_search(
+        &self,
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> Result<Vec<crate::types::Customer>> {
+        let params = CustomersGetSearchParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            page: Some(page.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_search_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/customers/search` endpoint.
      *
@@ -182,52 +279,84 @@ impl Customers {
      * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CustomersGetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Charge>> {
+    pub async fn get_all_search_with(
+        &self,
+        params: &CustomersGetSearchParams,
+    ) -> Result<Vec<crate::types::Customer>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !query.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/customers/search?{}", query_);
 
-        let mut resp: crate::types::SearchResult = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::search(params.page.as_deref(), params.limit),
+            |item: &crate::types::Customer| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_search_with` and pass the query parameters as a `CustomersGetSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_all_search_with` instead")]
+    pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Customer>> {
+        let params = CustomersGetSearchParams {
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_search_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/customers/search` endpoint.
+     *
+     * As opposed to `get_search`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Search for customers you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_search_stream(
+        &self,
+        params: &CustomersGetSearchParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Customer> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(query) = &params.query {
+            query_args.push(("query".to_string(), query.to_string()));
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/customers/search?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::search(params.page.as_deref(), params.limit)
+                .resume(start),
+            max_items,
+            |item: &crate::types::Customer| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -262,14 +391,25 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_customers(&self, customer: &str) -> Result<crate::types::Customer> {
+    pub async fn post_customers<B: serde::Serialize>(
+        &self,
+        customer: &str,
+        body: &B,
+    ) -> Result<crate::types::Customer> {
         let url = format!(
             "/v1/customers/{}",
             crate::progenitor_support::encode_path(customer),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -298,26 +438,26 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &CustomersGetBalanceTransactionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_balance_transactions(
+    pub async fn get_balance_transactions_with(
         &self,
         customer: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &CustomersGetBalanceTransactionsParams,
     ) -> Result<Vec<crate::types::CustomerBalanceTransaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -334,60 +474,120 @@ impl Customers {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_balance_transactions_with` and pass the query parameters as a `CustomersGetBalanceTransactionsParams` instead.
+     */
+    #[deprecated(note = "use `get_balance_transactions_with` instead")]
+    pub async fn get_balance_transactions(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::CustomerBalanceTransaction>> {
+        let params = CustomersGetBalanceTransactionsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_balance_transactions_with(customer, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/balance_transactions` endpoint.
      *
      * As opposed to `get_balance_transactions`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of transactions that updated the customer’s <a href="/docs/billing/customer/balance">balances</a>.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CustomersGetBalanceTransactionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_balance_transactions(
+    pub async fn get_all_balance_transactions_with(
         &self,
         customer: &str,
+        params: &CustomersGetBalanceTransactionsParams,
     ) -> Result<Vec<crate::types::CustomerBalanceTransaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/customers/{}/balance_transactions",
+            "/v1/customers/{}/balance_transactions?{}",
             crate::progenitor_support::encode_path(customer),
+            query_
         );
 
-        let mut resp: crate::types::CustomerBalanceTransactionList =
-            self.client.get(&url, None).await?;
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::CustomerBalanceTransaction| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_balance_transactions_with` and pass the query parameters as a `CustomersGetBalanceTransactionsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_balance_transactions_with` instead")]
+    pub async fn get_all_balance_transactions(
+        &self,
+        customer: &str,
+    ) -> Result<Vec<crate::types::CustomerBalanceTransaction>> {
+        let params = CustomersGetBalanceTransactionsParams::default();
+        self.get_all_balance_transactions_with(customer, &params)
+            .await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/balance_transactions` endpoint.
+     *
+     * As opposed to `get_balance_transactions`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of transactions that updated the customer’s <a href="/docs/billing/customer/balance">balances</a>.</p>
+     */
+    pub fn get_balance_transactions_stream(
+        &self,
+        customer: &str,
+        params: &CustomersGetBalanceTransactionsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CustomerBalanceTransaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/customers/{}/balance_transactions?{}",
+            crate::progenitor_support::encode_path(customer),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::CustomerBalanceTransaction| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -398,17 +598,25 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_balance_transaction(
+    pub async fn post_balance_transaction<B: serde::Serialize>(
         &self,
         customer: &str,
+        body: &B,
     ) -> Result<crate::types::CustomerBalanceTransaction> {
         let url = format!(
             "/v1/customers/{}/balance_transactions",
             crate::progenitor_support::encode_path(customer),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -445,11 +653,13 @@ impl Customers {
      *
      * * `customer: &str` -- The account's country.
      * * `transaction: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_balance_transactions_transaction(
+    pub async fn post_balance_transactions_transaction<B: serde::Serialize>(
         &self,
         customer: &str,
         transaction: &str,
+        body: &B,
     ) -> Result<crate::types::CustomerBalanceTransaction> {
         let url = format!(
             "/v1/customers/{}/balance_transactions/{}",
@@ -457,7 +667,13 @@ impl Customers {
             crate::progenitor_support::encode_path(transaction),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -468,26 +684,26 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &CustomersGetBankAccountsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_bank_accounts(
+    pub async fn get_bank_accounts_with(
         &self,
         customer: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &CustomersGetBankAccountsParams,
     ) -> Result<Vec<crate::types::BankAccount>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -503,59 +719,119 @@ impl Customers {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_bank_accounts_with` and pass the query parameters as a `CustomersGetBankAccountsParams` instead.
+     */
+    #[deprecated(note = "use `get_bank_accounts_with` instead")]
+    pub async fn get_bank_accounts(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::BankAccount>> {
+        let params = CustomersGetBankAccountsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_bank_accounts_with(customer, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/bank_accounts` endpoint.
      *
      * As opposed to `get_bank_accounts`, this function returns all the pages of the request at once.
      *
      * <p>You can see a list of the bank accounts belonging to a Customer. Note that the 10 most recent sources are always available by default on the Customer. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional bank accounts.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CustomersGetBankAccountsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_bank_accounts(
+    pub async fn get_all_bank_accounts_with(
         &self,
         customer: &str,
+        params: &CustomersGetBankAccountsParams,
     ) -> Result<Vec<crate::types::BankAccount>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/customers/{}/bank_accounts",
+            "/v1/customers/{}/bank_accounts?{}",
             crate::progenitor_support::encode_path(customer),
+            query_
         );
 
-        let mut resp: crate::types::BankAccountList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::BankAccount| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_bank_accounts_with` and pass the query parameters as a `CustomersGetBankAccountsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_bank_accounts_with` instead")]
+    pub async fn get_all_bank_accounts(
+        &self,
+        customer: &str,
+    ) -> Result<Vec<crate::types::BankAccount>> {
+        let params = CustomersGetBankAccountsParams::default();
+        self.get_all_bank_accounts_with(customer, &params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/bank_accounts` endpoint.
+     *
+     * As opposed to `get_bank_accounts`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>You can see a list of the bank accounts belonging to a Customer. Note that the 10 most recent sources are always available by default on the Customer. If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional bank accounts.</p>
+     */
+    pub fn get_bank_accounts_stream(
+        &self,
+        customer: &str,
+        params: &CustomersGetBankAccountsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BankAccount> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/customers/{}/bank_accounts?{}",
+            crate::progenitor_support::encode_path(customer),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::BankAccount| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -570,17 +846,25 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_bank_account(
+    pub async fn post_bank_account<B: serde::Serialize>(
         &self,
         customer: &str,
+        body: &B,
     ) -> Result<crate::types::PaymentSourceAnyOf> {
         let url = format!(
             "/v1/customers/{}/bank_accounts",
             crate::progenitor_support::encode_path(customer),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -617,11 +901,13 @@ impl Customers {
      *
      * * `customer: &str` -- The account's country.
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_bank_account_customers(
+    pub async fn post_bank_account_customers<B: serde::Serialize>(
         &self,
         customer: &str,
         id: &str,
+        body: &B,
     ) -> Result<crate::types::SourceAnyOf> {
         let url = format!(
             "/v1/customers/{}/bank_accounts/{}",
@@ -629,7 +915,13 @@ impl Customers {
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -665,11 +957,13 @@ impl Customers {
      *
      * * `customer: &str` -- The account's country.
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_bank_accounts_verify(
+    pub async fn post_bank_accounts_verify<B: serde::Serialize>(
         &self,
         customer: &str,
         id: &str,
+        body: &B,
     ) -> Result<crate::types::BankAccount> {
         let url = format!(
             "/v1/customers/{}/bank_accounts/{}/verify",
@@ -677,7 +971,13 @@ impl Customers {
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -690,26 +990,26 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &CustomersGetCardsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_cards(
+    pub async fn get_cards_with(
         &self,
         customer: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &CustomersGetCardsParams,
     ) -> Result<Vec<crate::types::Card>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -725,6 +1025,26 @@ impl Customers {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_cards_with` and pass the query parameters as a `CustomersGetCardsParams` instead.
+     */
+    #[deprecated(note = "use `get_cards_with` instead")]
+    pub async fn get_cards(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::Card>> {
+        let params = CustomersGetCardsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_cards_with(customer, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/cards` endpoint.
      *
@@ -733,50 +1053,92 @@ impl Customers {
      * <p>You can see a list of the cards belonging to a customer.
      * Note that the 10 most recent sources are always available on the <code>Customer</code> object.
      * If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional cards.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CustomersGetCardsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_cards(&self, customer: &str) -> Result<Vec<crate::types::Card>> {
-        let url = format!(
-            "/v1/customers/{}/cards",
-            crate::progenitor_support::encode_path(customer),
-        );
-
-        let mut resp: crate::types::Cards = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    pub async fn get_all_cards_with(
+        &self,
+        customer: &str,
+        params: &CustomersGetCardsParams,
+    ) -> Result<Vec<crate::types::Card>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/customers/{}/cards?{}",
+            crate::progenitor_support::encode_path(customer),
+            query_
+        );
 
-            data.append(&mut resp.data);
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Card| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * Deprecated: use `get_all_cards_with` and pass the query parameters as a `CustomersGetCardsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_cards_with` instead")]
+    pub async fn get_all_cards(&self, customer: &str) -> Result<Vec<crate::types::Card>> {
+        let params = CustomersGetCardsParams::default();
+        self.get_all_cards_with(customer, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/cards` endpoint.
+     *
+     * As opposed to `get_cards`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>You can see a list of the cards belonging to a customer.
+     * Note that the 10 most recent sources are always available on the <code>Customer</code> object.
+     * If you need more than those 10, you can use this API method and the <code>limit</code> and <code>starting_after</code> parameters to page through additional cards.</p>
+     */
+    pub fn get_cards_stream(
+        &self,
+        customer: &str,
+        params: &CustomersGetCardsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Card> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/customers/{}/cards?{}",
+            crate::progenitor_support::encode_path(customer),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Card| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -791,14 +1153,25 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_card(&self, customer: &str) -> Result<crate::types::PaymentSourceAnyOf> {
+    pub async fn post_card<B: serde::Serialize>(
+        &self,
+        customer: &str,
+        body: &B,
+    ) -> Result<crate::types::PaymentSourceAnyOf> {
         let url = format!(
             "/v1/customers/{}/cards",
             crate::progenitor_support::encode_path(customer),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -831,11 +1204,13 @@ impl Customers {
      *
      * * `customer: &str` -- The account's country.
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_card_customers(
+    pub async fn post_card_customers<B: serde::Serialize>(
         &self,
         customer: &str,
         id: &str,
+        body: &B,
     ) -> Result<crate::types::SourceAnyOf> {
         let url = format!(
             "/v1/customers/{}/cards/{}",
@@ -843,7 +1218,13 @@ impl Customers {
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -915,31 +1296,29 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `type_: crate::types::GetCustomersCustomerPaymentMethodsType` -- A required filter on the list, based on the object `type` field.
+     * * `params: &CustomersGetPaymentMethodsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_payment_methods(
+    pub async fn get_payment_methods_with(
         &self,
         customer: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
-        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+        params: &CustomersGetPaymentMethodsParams,
     ) -> Result<Vec<crate::types::PaymentMethod>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -955,20 +1334,51 @@ impl Customers {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_payment_methods_with` and pass the query parameters as a `CustomersGetPaymentMethodsParams` instead.
+     */
+    #[deprecated(note = "use `get_payment_methods_with` instead")]
+    pub async fn get_payment_methods(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+    ) -> Result<Vec<crate::types::PaymentMethod>> {
+        let params = CustomersGetPaymentMethodsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_payment_methods_with(customer, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/payment_methods` endpoint.
      *
      * As opposed to `get_payment_methods`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of PaymentMethods for a given Customer</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CustomersGetPaymentMethodsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_payment_methods(
+    pub async fn get_all_payment_methods_with(
         &self,
         customer: &str,
-        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+        params: &CustomersGetPaymentMethodsParams,
     ) -> Result<Vec<crate::types::PaymentMethod>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !type_.to_string().is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -978,43 +1388,77 @@ impl Customers {
             query_
         );
 
-        let mut resp: crate::types::PaymentFlowsMethodList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::PaymentMethod| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_payment_methods_with` and pass the query parameters as a `CustomersGetPaymentMethodsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_payment_methods_with` instead")]
+    pub async fn get_all_payment_methods(
+        &self,
+        customer: &str,
+        type_: crate::types::GetCustomersCustomerPaymentMethodsType,
+    ) -> Result<Vec<crate::types::PaymentMethod>> {
+        let params = CustomersGetPaymentMethodsParams {
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_payment_methods_with(customer, &params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/payment_methods` endpoint.
+     *
+     * As opposed to `get_payment_methods`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of PaymentMethods for a given Customer</p>
+     */
+    pub fn get_payment_methods_stream(
+        &self,
+        customer: &str,
+        params: &CustomersGetPaymentMethodsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PaymentMethod> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/customers/{}/payment_methods?{}",
+            crate::progenitor_support::encode_path(customer),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::PaymentMethod| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -1025,31 +1469,29 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `object: &str` -- Filter sources according to a particular object type.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &CustomersGetSourcesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_sources(
+    pub async fn get_sources_with(
         &self,
         customer: &str,
-        ending_before: &str,
-        limit: i64,
-        object: &str,
-        starting_after: &str,
+        params: &CustomersGetSourcesParams,
     ) -> Result<Vec<crate::types::CustomerSourcesDataAnyOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !object.is_empty() {
+        if let Some(object) = &params.object {
             query_args.push(("object".to_string(), object.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1065,20 +1507,51 @@ impl Customers {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_sources_with` and pass the query parameters as a `CustomersGetSourcesParams` instead.
+     */
+    #[deprecated(note = "use `get_sources_with` instead")]
+    pub async fn get_sources(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        object: &str,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::CustomerSourcesDataAnyOf>> {
+        let params = CustomersGetSourcesParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            object: Some(object.to_string()).filter(|s| !s.is_empty()),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_sources_with(customer, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/sources` endpoint.
      *
      * As opposed to `get_sources`, this function returns all the pages of the request at once.
      *
      * <p>List sources for a specified customer.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CustomersGetSourcesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_sources(
+    pub async fn get_all_sources_with(
         &self,
         customer: &str,
-        object: &str,
+        params: &CustomersGetSourcesParams,
     ) -> Result<Vec<crate::types::CustomerSourcesDataAnyOf>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !object.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(object) = &params.object {
             query_args.push(("object".to_string(), object.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1088,43 +1561,89 @@ impl Customers {
             query_
         );
 
-        let mut resp: crate::types::Sources = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::CustomerSourcesDataAnyOf| match item {
+                crate::types::CustomerSourcesDataAnyOf::BankAccount(o) => Some(o.id.clone()),
+                crate::types::CustomerSourcesDataAnyOf::Card(o) => Some(o.id.clone()),
+                crate::types::CustomerSourcesDataAnyOf::AlipayAccount(o) => Some(o.id.clone()),
+                crate::types::CustomerSourcesDataAnyOf::SourceData(o) => Some(o.id.clone()),
+                crate::types::CustomerSourcesDataAnyOf::BitcoinReceiver(o) => Some(o.id.clone()),
+            },
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_sources_with` and pass the query parameters as a `CustomersGetSourcesParams` instead.
+     */
+    #[deprecated(note = "use `get_all_sources_with` instead")]
+    pub async fn get_all_sources(
+        &self,
+        customer: &str,
+        object: &str,
+    ) -> Result<Vec<crate::types::CustomerSourcesDataAnyOf>> {
+        let params = CustomersGetSourcesParams {
+            object: Some(object.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_sources_with(customer, &params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/sources` endpoint.
+     *
+     * As opposed to `get_sources`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>List sources for a specified customer.</p>
+     */
+    pub fn get_sources_stream(
+        &self,
+        customer: &str,
+        params: &CustomersGetSourcesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CustomerSourcesDataAnyOf> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(object) = &params.object {
+            query_args.push(("object".to_string(), object.to_string()));
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/customers/{}/sources?{}",
+            crate::progenitor_support::encode_path(customer),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::CustomerSourcesDataAnyOf| match item {
+                crate::types::CustomerSourcesDataAnyOf::BankAccount(o) => Some(o.id.clone()),
+                crate::types::CustomerSourcesDataAnyOf::Card(o) => Some(o.id.clone()),
+                crate::types::CustomerSourcesDataAnyOf::AlipayAccount(o) => Some(o.id.clone()),
+                crate::types::CustomerSourcesDataAnyOf::SourceData(o) => Some(o.id.clone()),
+                crate::types::CustomerSourcesDataAnyOf::BitcoinReceiver(o) => Some(o.id.clone()),
+            },
+        )
     }
 
     /**
@@ -1139,14 +1658,25 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_source(&self, customer: &str) -> Result<crate::types::PaymentSourceAnyOf> {
+    pub async fn post_source<B: serde::Serialize>(
+        &self,
+        customer: &str,
+        body: &B,
+    ) -> Result<crate::types::PaymentSourceAnyOf> {
         let url = format!(
             "/v1/customers/{}/sources",
             crate::progenitor_support::encode_path(customer),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -1183,11 +1713,13 @@ impl Customers {
      *
      * * `customer: &str` -- The account's country.
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_source_customers(
+    pub async fn post_source_customers<B: serde::Serialize>(
         &self,
         customer: &str,
         id: &str,
+        body: &B,
     ) -> Result<crate::types::SourceAnyOf> {
         let url = format!(
             "/v1/customers/{}/sources/{}",
@@ -1195,7 +1727,13 @@ impl Customers {
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -1231,11 +1769,13 @@ impl Customers {
      *
      * * `customer: &str` -- The account's country.
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_sources_verify(
+    pub async fn post_sources_verify<B: serde::Serialize>(
         &self,
         customer: &str,
         id: &str,
+        body: &B,
     ) -> Result<crate::types::BankAccount> {
         let url = format!(
             "/v1/customers/{}/sources/{}/verify",
@@ -1243,7 +1783,13 @@ impl Customers {
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -1254,26 +1800,26 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &CustomersGetSubscriptionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_subscriptions(
+    pub async fn get_subscriptions_with(
         &self,
         customer: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &CustomersGetSubscriptionsParams,
     ) -> Result<Vec<crate::types::Subscription>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1289,59 +1835,119 @@ impl Customers {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_subscriptions_with` and pass the query parameters as a `CustomersGetSubscriptionsParams` instead.
+     */
+    #[deprecated(note = "use `get_subscriptions_with` instead")]
+    pub async fn get_subscriptions(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::Subscription>> {
+        let params = CustomersGetSubscriptionsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_subscriptions_with(customer, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/subscriptions` endpoint.
      *
      * As opposed to `get_subscriptions`, this function returns all the pages of the request at once.
      *
      * <p>You can see a list of the customer’s active subscriptions. Note that the 10 most recent active subscriptions are always available by default on the customer object. If you need more than those 10, you can use the limit and starting_after parameters to page through additional subscriptions.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CustomersGetSubscriptionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_subscriptions(
+    pub async fn get_all_subscriptions_with(
         &self,
         customer: &str,
+        params: &CustomersGetSubscriptionsParams,
     ) -> Result<Vec<crate::types::Subscription>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/customers/{}/subscriptions",
+            "/v1/customers/{}/subscriptions?{}",
             crate::progenitor_support::encode_path(customer),
+            query_
         );
 
-        let mut resp: crate::types::Subscriptions = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Subscription| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_subscriptions_with` and pass the query parameters as a `CustomersGetSubscriptionsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_subscriptions_with` instead")]
+    pub async fn get_all_subscriptions(
+        &self,
+        customer: &str,
+    ) -> Result<Vec<crate::types::Subscription>> {
+        let params = CustomersGetSubscriptionsParams::default();
+        self.get_all_subscriptions_with(customer, &params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/subscriptions` endpoint.
+     *
+     * As opposed to `get_subscriptions`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>You can see a list of the customer’s active subscriptions. Note that the 10 most recent active subscriptions are always available by default on the customer object. If you need more than those 10, you can use the limit and starting_after parameters to page through additional subscriptions.</p>
+     */
+    pub fn get_subscriptions_stream(
+        &self,
+        customer: &str,
+        params: &CustomersGetSubscriptionsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Subscription> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/customers/{}/subscriptions?{}",
+            crate::progenitor_support::encode_path(customer),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Subscription| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -1352,14 +1958,25 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_subscription(&self, customer: &str) -> Result<crate::types::Subscription> {
+    pub async fn post_subscription<B: serde::Serialize>(
+        &self,
+        customer: &str,
+        body: &B,
+    ) -> Result<crate::types::Subscription> {
         let url = format!(
             "/v1/customers/{}/subscriptions",
             crate::progenitor_support::encode_path(customer),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -1396,11 +2013,13 @@ impl Customers {
      *
      * * `customer: &str` -- The account's country.
      * * `subscription_exposed_id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_subscriptions_subscription_exposed(
+    pub async fn post_subscriptions_subscription_exposed<B: serde::Serialize>(
         &self,
         customer: &str,
         subscription_exposed_id: &str,
+        body: &B,
     ) -> Result<crate::types::Subscription> {
         let url = format!(
             "/v1/customers/{}/subscriptions/{}",
@@ -1408,7 +2027,13 @@ impl Customers {
             crate::progenitor_support::encode_path(subscription_exposed_id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -1496,26 +2121,26 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &CustomersGetTaxIdsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_tax_ids(
+    pub async fn get_tax_ids_with(
         &self,
         customer: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &CustomersGetTaxIdsParams,
     ) -> Result<Vec<crate::types::TaxId>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1531,56 +2156,116 @@ impl Customers {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_tax_ids_with` and pass the query parameters as a `CustomersGetTaxIdsParams` instead.
+     */
+    #[deprecated(note = "use `get_tax_ids_with` instead")]
+    pub async fn get_tax_ids(
+        &self,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::TaxId>> {
+        let params = CustomersGetTaxIdsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_tax_ids_with(customer, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/customers/{customer}/tax_ids` endpoint.
      *
      * As opposed to `get_tax_ids`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of tax IDs for a customer.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &CustomersGetTaxIdsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_tax_ids(&self, customer: &str) -> Result<Vec<crate::types::TaxId>> {
+    pub async fn get_all_tax_ids_with(
+        &self,
+        customer: &str,
+        params: &CustomersGetTaxIdsParams,
+    ) -> Result<Vec<crate::types::TaxId>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/customers/{}/tax_ids",
+            "/v1/customers/{}/tax_ids?{}",
             crate::progenitor_support::encode_path(customer),
+            query_
         );
 
-        let mut resp: crate::types::TaxIds = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::TaxId| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_tax_ids_with` and pass the query parameters as a `CustomersGetTaxIdsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_tax_ids_with` instead")]
+    pub async fn get_all_tax_ids(&self, customer: &str) -> Result<Vec<crate::types::TaxId>> {
+        let params = CustomersGetTaxIdsParams::default();
+        self.get_all_tax_ids_with(customer, &params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/customers/{customer}/tax_ids` endpoint.
+     *
+     * As opposed to `get_tax_ids`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of tax IDs for a customer.</p>
+     */
+    pub fn get_tax_ids_stream(
+        &self,
+        customer: &str,
+        params: &CustomersGetTaxIdsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TaxId> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/customers/{}/tax_ids?{}",
+            crate::progenitor_support::encode_path(customer),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::TaxId| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -1591,14 +2276,25 @@ impl Customers {
      * **Parameters:**
      *
      * * `customer: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_tax_id(&self, customer: &str) -> Result<crate::types::TaxId> {
+    pub async fn post_tax_id<B: serde::Serialize>(
+        &self,
+        customer: &str,
+        body: &B,
+    ) -> Result<crate::types::TaxId> {
         let url = format!(
             "/v1/customers/{}/tax_ids",
             crate::progenitor_support::encode_path(customer),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -1646,3 +2342,352 @@ impl Customers {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Customers::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetPageParams {
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A case-sensitive filter on the list based on the customer's `email` field. The value must be a string.
+    pub email: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Provides a list of customers that are associated with the specified test clock. The response will not include customers with test clocks if this parameter is not set.
+    pub test_clock: Option<String>,
+}
+
+impl CustomersGetPageParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn email<T: ToString>(mut self, email: T) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn test_clock<T: ToString>(mut self, test_clock: T) -> Self {
+        self.test_clock = Some(test_clock.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetSearchParams {
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
+    pub page: Option<String>,
+    /// The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for customers](https://stripe.com/docs/search#query-fields-for-customers).
+    pub query: Option<String>,
+}
+
+impl CustomersGetSearchParams {
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page<T: ToString>(mut self, page: T) -> Self {
+        self.page = Some(page.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_balance_transactions_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetBalanceTransactionsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl CustomersGetBalanceTransactionsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_bank_accounts_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetBankAccountsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl CustomersGetBankAccountsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_cards_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetCardsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl CustomersGetCardsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_payment_methods_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetPaymentMethodsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// A required filter on the list, based on the object `type` field.
+    pub type_: Option<crate::types::GetCustomersCustomerPaymentMethodsType>,
+}
+
+impl CustomersGetPaymentMethodsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::GetCustomersCustomerPaymentMethodsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_sources_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetSourcesParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// Filter sources according to a particular object type.
+    pub object: Option<String>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl CustomersGetSourcesParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn object<T: ToString>(mut self, object: T) -> Self {
+        self.object = Some(object.to_string());
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_subscriptions_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetSubscriptionsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl CustomersGetSubscriptionsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Customers::get_tax_ids_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CustomersGetTaxIdsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl CustomersGetTaxIdsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Disputes {
     pub client: Client,
@@ -19,37 +17,34 @@ impl Disputes {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- Only return disputes associated to the charge specified by this charge ID.
-     * * `created: &str`
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `payment_intent: &str` -- Only return disputes associated to the PaymentIntent specified by this PaymentIntent ID.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &DisputesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        charge: &str,
-        _created: &str,
-        ending_before: &str,
-        limit: i64,
-        payment_intent: &str,
-        starting_after: &str,
+        params: &DisputesGetPageParams,
     ) -> Result<Vec<crate::types::Dispute>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !charge.is_empty() {
+        if let Some(charge) = &params.charge {
             query_args.push(("charge".to_string(), charge.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !payment_intent.is_empty() {
+        if let Some(payment_intent) = &params.payment_intent {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -61,66 +56,139 @@ impl Disputes {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `DisputesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        charge: &str,
+        created: &str,
+        ending_before: &str,
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::Dispute>> {
+        let params = DisputesGetPageParams {
+            charge: Some(charge.to_string()).filter(|s| !s.is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            payment_intent: Some(payment_intent.to_string()).filter(|s| !s.is_empty()),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/disputes` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of your disputes.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &DisputesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        charge: &str,
-        _created: &str,
-        payment_intent: &str,
+        params: &DisputesGetPageParams,
     ) -> Result<Vec<crate::types::Dispute>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !charge.is_empty() {
+        if let Some(charge) = &params.charge {
             query_args.push(("charge".to_string(), charge.to_string()));
         }
-        if !payment_intent.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(payment_intent) = &params.payment_intent {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/disputes?{}", query_);
 
-        let mut resp: crate::types::GetDisputesResponse = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Dispute| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `DisputesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        charge: &str,
+        created: &str,
+        payment_intent: &str,
+    ) -> Result<Vec<crate::types::Dispute>> {
+        let params = DisputesGetPageParams {
+            charge: Some(charge.to_string()).filter(|s| !s.is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            payment_intent: Some(payment_intent.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/disputes` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of your disputes.</p>
+     */
+    pub fn get_stream(
+        &self,
+        params: &DisputesGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Dispute> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(charge) = &params.charge {
+            query_args.push(("charge".to_string(), charge.to_string()));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(payment_intent) = &params.payment_intent {
+            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/disputes?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Dispute| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -152,14 +220,25 @@ impl Disputes {
      * **Parameters:**
      *
      * * `dispute: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self, dispute: &str) -> Result<crate::types::Dispute> {
+    pub async fn post<B: serde::Serialize>(
+        &self,
+        dispute: &str,
+        body: &B,
+    ) -> Result<crate::types::Dispute> {
         let url = format!(
             "/v1/disputes/{}",
             crate::progenitor_support::encode_path(dispute),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -172,13 +251,79 @@ impl Disputes {
      * **Parameters:**
      *
      * * `dispute: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_close(&self, dispute: &str) -> Result<crate::types::Dispute> {
+    pub async fn post_close<B: serde::Serialize>(
+        &self,
+        dispute: &str,
+        body: &B,
+    ) -> Result<crate::types::Dispute> {
         let url = format!(
             "/v1/disputes/{}/close",
             crate::progenitor_support::encode_path(dispute),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Disputes::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DisputesGetPageParams {
+    /// Only return disputes associated to the charge specified by this charge ID.
+    pub charge: Option<String>,
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// Only return disputes associated to the PaymentIntent specified by this PaymentIntent ID.
+    pub payment_intent: Option<String>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl DisputesGetPageParams {
+    pub fn charge<T: ToString>(mut self, charge: T) -> Self {
+        self.charge = Some(charge.to_string());
+        self
+    }
+
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn payment_intent<T: ToString>(mut self, payment_intent: T) -> Self {
+        self.payment_intent = Some(payment_intent.to_string());
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct EphemeralKeys {
     pub client: Client,
@@ -16,10 +14,20 @@ impl EphemeralKeys {
      * This function performs a `POST` to the `/v1/ephemeral_keys` endpoint.
      *
      * <p>Creates a short-lived API key for a given resource.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::EphemeralKey> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::EphemeralKey> {
         let url = "/v1/ephemeral_keys".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Identity {
     pub client: Client,
@@ -19,37 +17,34 @@ impl Identity {
      *
      * **Parameters:**
      *
-     * * `created: &str`
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `type_: crate::types::GelatoVerificationReportType` -- Only return VerificationReports of this type.
-     * * `verification_session: &str` -- Only return VerificationReports created by this VerificationSession ID. It is allowed to provide a VerificationIntent ID.
+     * * `params: &IdentityGetVerificationReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_verification_reports(
+    pub async fn get_verification_reports_with(
         &self,
-        _created: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
-        type_: crate::types::GelatoVerificationReportType,
-        verification_session: &str,
+        params: &IdentityGetVerificationReportsParams,
     ) -> Result<Vec<crate::types::GelatoVerificationReport>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
-        if !verification_session.is_empty() {
+        if let Some(verification_session) = &params.verification_session {
             query_args.push((
                 "verification_session".to_string(),
                 verification_session.to_string(),
@@ -65,24 +60,59 @@ impl Identity {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_verification_reports_with` and pass the query parameters as a `IdentityGetVerificationReportsParams` instead.
+     */
+    #[deprecated(note = "use `get_verification_reports_with` instead")]
+    pub async fn get_verification_reports(
+        &self,
+        created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        type_: crate::types::GelatoVerificationReportType,
+        verification_session: &str,
+    ) -> Result<Vec<crate::types::GelatoVerificationReport>> {
+        let params = IdentityGetVerificationReportsParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            verification_session: Some(verification_session.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_verification_reports_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/identity/verification_reports` endpoint.
      *
      * As opposed to `get_verification_reports`, this function returns all the pages of the request at once.
      *
      * <p>List all verification reports.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IdentityGetVerificationReportsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_verification_reports(
+    pub async fn get_all_verification_reports_with(
         &self,
-        _created: &str,
-        type_: crate::types::GelatoVerificationReportType,
-        verification_session: &str,
+        params: &IdentityGetVerificationReportsParams,
     ) -> Result<Vec<crate::types::GelatoVerificationReport>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !type_.to_string().is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
-        if !verification_session.is_empty() {
+        if let Some(verification_session) = &params.verification_session {
             query_args.push((
                 "verification_session".to_string(),
                 verification_session.to_string(),
@@ -91,44 +121,84 @@ impl Identity {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/identity/verification_reports?{}", query_);
 
-        let mut resp: crate::types::GetIdentityVerificationReportsResponse =
-            self.client.get(&url, None).await?;
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::GelatoVerificationReport| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_verification_reports_with` and pass the query parameters as a `IdentityGetVerificationReportsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_verification_reports_with` instead")]
+    pub async fn get_all_verification_reports(
+        &self,
+        created: &str,
+        type_: crate::types::GelatoVerificationReportType,
+        verification_session: &str,
+    ) -> Result<Vec<crate::types::GelatoVerificationReport>> {
+        let params = IdentityGetVerificationReportsParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            verification_session: Some(verification_session.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_verification_reports_with(&params).await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_reports` endpoint.
+     *
+     * As opposed to `get_verification_reports`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>List all verification reports.</p>
+     */
+    pub fn get_verification_reports_stream(
+        &self,
+        params: &IdentityGetVerificationReportsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GelatoVerificationReport> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        if let Some(verification_session) = &params.verification_session {
+            query_args.push((
+                "verification_session".to_string(),
+                verification_session.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/identity/verification_reports?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::GelatoVerificationReport| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -160,32 +230,31 @@ impl Identity {
      *
      * **Parameters:**
      *
-     * * `created: &str`
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `status: crate::types::GelatoVerificationSessionStatus` -- Only return VerificationSessions with this status. [Learn more about the lifecycle of sessions](https://stripe.com/docs/identity/how-sessions-work).
+     * * `params: &IdentityGetVerificationSessionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_verification_sessions(
+    pub async fn get_verification_sessions_with(
         &self,
-        _created: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
-        status: crate::types::GelatoVerificationSessionStatus,
+        params: &IdentityGetVerificationSessionsParams,
     ) -> Result<Vec<crate::types::GelatoVerificationSession>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -198,63 +267,129 @@ impl Identity {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_verification_sessions_with` and pass the query parameters as a `IdentityGetVerificationSessionsParams` instead.
+     */
+    #[deprecated(note = "use `get_verification_sessions_with` instead")]
+    pub async fn get_verification_sessions(
+        &self,
+        created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::GelatoVerificationSessionStatus,
+    ) -> Result<Vec<crate::types::GelatoVerificationSession>> {
+        let params = IdentityGetVerificationSessionsParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_verification_sessions_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/identity/verification_sessions` endpoint.
      *
      * As opposed to `get_verification_sessions`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of VerificationSessions</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IdentityGetVerificationSessionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_verification_sessions(
+    pub async fn get_all_verification_sessions_with(
         &self,
-        _created: &str,
-        status: crate::types::GelatoVerificationSessionStatus,
+        params: &IdentityGetVerificationSessionsParams,
     ) -> Result<Vec<crate::types::GelatoVerificationSession>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !status.to_string().is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/identity/verification_sessions?{}", query_);
 
-        let mut resp: crate::types::GetIdentityVerificationSessionsResponse =
-            self.client.get(&url, None).await?;
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::GelatoVerificationSession| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_verification_sessions_with` and pass the query parameters as a `IdentityGetVerificationSessionsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_verification_sessions_with` instead")]
+    pub async fn get_all_verification_sessions(
+        &self,
+        created: &str,
+        status: crate::types::GelatoVerificationSessionStatus,
+    ) -> Result<Vec<crate::types::GelatoVerificationSession>> {
+        let params = IdentityGetVerificationSessionsParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_verification_sessions_with(&params).await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/identity/verification_sessions` endpoint.
+     *
+     * As opposed to `get_verification_sessions`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of VerificationSessions</p>
+     */
+    pub fn get_verification_sessions_stream(
+        &self,
+        params: &IdentityGetVerificationSessionsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GelatoVerificationSession> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        if let Some(status) = &params.status {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/identity/verification_sessions?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::GelatoVerificationSession| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -267,12 +402,23 @@ impl Identity {
      * <p>If your API key is in test mode, verification checks won’t actually process, though everything else will occur as if in live mode.</p>
      *
      * <p>Related guide: <a href="/docs/identity/verify-identity-documents">Verify your users’ identity documents</a>.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_verification_session(
+    pub async fn post_verification_session<B: serde::Serialize>(
         &self,
+        body: &B,
     ) -> Result<crate::types::GelatoVerificationSession> {
         let url = "/v1/identity/verification_sessions".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -311,17 +457,25 @@ impl Identity {
      * **Parameters:**
      *
      * * `session: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_verification_sessions_session(
+    pub async fn post_verification_sessions_session<B: serde::Serialize>(
         &self,
         session: &str,
+        body: &B,
     ) -> Result<crate::types::GelatoVerificationSession> {
         let url = format!(
             "/v1/identity/verification_sessions/{}",
             crate::progenitor_support::encode_path(session),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -334,17 +488,25 @@ impl Identity {
      * **Parameters:**
      *
      * * `session: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_verification_sessions_session_cancel(
+    pub async fn post_verification_sessions_session_cancel<B: serde::Serialize>(
         &self,
         session: &str,
+        body: &B,
     ) -> Result<crate::types::GelatoVerificationSession> {
         let url = format!(
             "/v1/identity/verification_sessions/{}/cancel",
             crate::progenitor_support::encode_path(session),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -373,16 +535,127 @@ impl Identity {
      * **Parameters:**
      *
      * * `session: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_verification_sessions_session_redact(
+    pub async fn post_verification_sessions_session_redact<B: serde::Serialize>(
         &self,
         session: &str,
+        body: &B,
     ) -> Result<crate::types::GelatoVerificationSession> {
         let url = format!(
             "/v1/identity/verification_sessions/{}/redact",
             crate::progenitor_support::encode_path(session),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Identity::get_verification_reports_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IdentityGetVerificationReportsParams {
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Only return VerificationReports of this type.
+    pub type_: Option<crate::types::GelatoVerificationReportType>,
+    /// Only return VerificationReports created by this VerificationSession ID. It is allowed to provide a VerificationIntent ID.
+    pub verification_session: Option<String>,
+}
+
+impl IdentityGetVerificationReportsParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::GelatoVerificationReportType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    pub fn verification_session<T: ToString>(mut self, verification_session: T) -> Self {
+        self.verification_session = Some(verification_session.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Identity::get_verification_sessions_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IdentityGetVerificationSessionsParams {
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Only return VerificationSessions with this status. [Learn more about the lifecycle of sessions](https://stripe.com/docs/identity/how-sessions-work).
+    pub status: Option<crate::types::GelatoVerificationSessionStatus>,
+}
+
+impl IdentityGetVerificationSessionsParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::GelatoVerificationSessionStatus) -> Self {
+        self.status = Some(status);
+        self
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Invoices {
     pub client: Client,
@@ -19,52 +17,46 @@ impl Invoices {
      *
      * **Parameters:**
      *
-     * * `collection_method: crate::types::CollectionMethod` -- Either `charge_automatically`, or `send_invoice`. When charging automatically, Stripe will attempt to pay this invoice using the default source attached to the customer. When sending an invoice, Stripe will email this invoice to the customer with payment instructions.
-     * * `created: &str`
-     * * `customer: &str` -- Only return invoices for the customer specified by this customer ID.
-     * * `due_date: &str`
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `status: crate::types::GetInvoicesStatus` -- The status of the invoice, one of `draft`, `open`, `paid`, `uncollectible`, or `void`. [Learn more](https://stripe.com/docs/billing/invoices/workflow#workflow-overview).
-     * * `subscription: &str` -- Only return invoices for the subscription specified by this subscription ID.
+     * * `params: &InvoicesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        collection_method: crate::types::CollectionMethod,
-        _created: &str,
-        customer: &str,
-        _due_date: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
-        status: crate::types::GetInvoicesStatus,
-        subscription: &str,
+        params: &InvoicesGetPageParams,
     ) -> Result<Vec<crate::types::Invoice>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_method.to_string().is_empty() {
+        if let Some(collection_method) = &params.collection_method {
             query_args.push((
                 "collection_method".to_string(),
                 collection_method.to_string(),
             ));
         }
-        if !customer.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(due_date) = &params.due_date {
+            query_args.extend(due_date.to_query("due_date"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !subscription.is_empty() {
+        if let Some(subscription) = &params.subscription {
             query_args.push(("subscription".to_string(), subscription.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -76,88 +68,195 @@ impl Invoices {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `InvoicesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        collection_method: crate::types::CollectionMethod,
+        created: &str,
+        customer: &str,
+        due_date: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::GetInvoicesStatus,
+        subscription: &str,
+    ) -> Result<Vec<crate::types::Invoice>> {
+        let params = InvoicesGetPageParams {
+            collection_method: Some(collection_method).filter(|v| !v.to_string().is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            due_date: crate::pagination::RangeQuery::parse(due_date),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            subscription: Some(subscription.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/invoices` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>You can list all invoices, or list the invoices for a specific customer. The invoices are returned sorted by creation date, with the most recently created invoices appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &InvoicesGetPageParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_with(
+        &self,
+        params: &InvoicesGetPageParams,
+    ) -> Result<Vec<crate::types::Invoice>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(collection_method) = &params.collection_method {
+            query_args.push((
+                "collection_method".to_string(),
+                collection_method.to_string(),
+            ));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if let Some(due_date) = &params.due_date {
+            query_args.extend(due_date.to_query("due_date"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(status) = &params.status {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if let Some(subscription) = &params.subscription {
+            query_args.push(("subscription".to_string(), subscription.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/invoices?{}", query_);
+
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Invoice| Some(item.id.clone()),
+        )
+        .await
+    }
+
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `InvoicesGetPageParams` instead.
      */
+    #[deprecated(note = "use `get_all_with` instead")]
     pub async fn get_all(
         &self,
         collection_method: crate::types::CollectionMethod,
-        _created: &str,
+        created: &str,
         customer: &str,
-        _due_date: &str,
+        due_date: &str,
         status: crate::types::GetInvoicesStatus,
         subscription: &str,
     ) -> Result<Vec<crate::types::Invoice>> {
+        let params = InvoicesGetPageParams {
+            collection_method: Some(collection_method).filter(|v| !v.to_string().is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            due_date: crate::pagination::RangeQuery::parse(due_date),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            subscription: Some(subscription.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/v1/invoices` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>You can list all invoices, or list the invoices for a specific customer. The invoices are returned sorted by creation date, with the most recently created invoices appearing first.</p>
+     */
+    pub fn get_stream(
+        &self,
+        params: &InvoicesGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Invoice> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !collection_method.to_string().is_empty() {
+        if let Some(collection_method) = &params.collection_method {
             query_args.push((
                 "collection_method".to_string(),
                 collection_method.to_string(),
             ));
         }
-        if !customer.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(due_date) = &params.due_date {
+            query_args.extend(due_date.to_query("due_date"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !subscription.is_empty() {
+        if let Some(subscription) = &params.subscription {
             query_args.push(("subscription".to_string(), subscription.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/invoices?{}", query_);
 
-        let mut resp: crate::types::InvoicesList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
-
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Invoice| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/invoices` endpoint.
      *
      * <p>This endpoint creates a draft invoice for a given customer. The draft invoice created pulls in all pending invoice items on that customer, including prorations. The invoice remains a draft until you <a href="#finalize_invoice">finalize</a> the invoice, which allows you to <a href="#pay_invoice">pay</a> or <a href="#send_invoice">send</a> the invoice to your customers.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::Invoice> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::Invoice> {
         let url = "/v1/invoices".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -170,36 +269,56 @@ impl Invoices {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `page: &str` -- A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
-     * * `query: &str` -- The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for invoices](https://stripe.com/docs/search#query-fields-for-invoices).
+     * * `params: &InvoicesGetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_search(
+    pub async fn get_search_with(
         &self,
-        limit: i64,
-        page: &str,
-        query: &str,
-    ) -> Result<Vec<crate::types::Charge>> {
+        params: &InvoicesGetSearchParams,
+    ) -> Result<Vec<crate::types::Invoice>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !page.is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/invoices/search?{}", query_);
 
-        let resp: crate::types::SearchResult = self.client.get(&url, None).await?;
+        let resp: crate::types::GetInvoicesSearchResponseResult =
+            self.client.get(&url, None).await?;
 
         // Return our response data.
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_search_with` and pass the query parameters as a `InvoicesGetSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_search_with` instead")]
+    pub async fn get_search(
+        &self,
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> Result<Vec<crate::types::Invoice>> {
+        let params = InvoicesGetSearchParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            page: Some(page.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_search_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/invoices/search` endpoint.
      *
@@ -209,52 +328,84 @@ impl Invoices {
      * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &InvoicesGetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Charge>> {
+    pub async fn get_all_search_with(
+        &self,
+        params: &InvoicesGetSearchParams,
+    ) -> Result<Vec<crate::types::Invoice>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !query.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/invoices/search?{}", query_);
 
-        let mut resp: crate::types::SearchResult = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::search(params.page.as_deref(), params.limit),
+            |item: &crate::types::Invoice| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_search_with` and pass the query parameters as a `InvoicesGetSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_all_search_with` instead")]
+    pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Invoice>> {
+        let params = InvoicesGetSearchParams {
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_search_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/invoices/search` endpoint.
+     *
+     * As opposed to `get_search`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Search for invoices you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_search_stream(
+        &self,
+        params: &InvoicesGetSearchParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Invoice> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(query) = &params.query {
+            query_args.push(("query".to_string(), query.to_string()));
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/invoices/search?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::search(params.page.as_deref(), params.limit)
+                .resume(start),
+            max_items,
+            |item: &crate::types::Invoice| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -268,97 +419,65 @@ impl Invoices {
      *
      * **Parameters:**
      *
-     * * `automatic_tax: &str` -- Settings for automatic tax lookup for this invoice preview.
-     * * `coupon: &str` -- The code of the coupon to apply. If `subscription` or `subscription_items` is provided, the invoice returned will preview updating or creating a subscription with that coupon. Otherwise, it will preview applying that coupon to the customer for the next upcoming invoice from among the customer's subscriptions. The invoice can be previewed without a coupon by passing this value as an empty string.
-     * * `customer: &str` -- The identifier of the customer whose upcoming invoice you'd like to retrieve.
-     * * `customer_details: &str` -- Details about the customer you want to invoice or overrides for an existing customer.
-     * * `discounts: &str` -- The coupons to redeem into discounts for the invoice preview. If not specified, inherits the discount from the customer or subscription. This only works for coupons directly applied to the invoice. To apply a coupon to a subscription, you must use the `coupon` parameter instead. Pass an empty string to avoid inheriting any discounts. To preview the upcoming invoice for a subscription that hasn't been created, use `coupon` instead.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `invoice_items: &[String]` -- List of invoice items to add or update in the upcoming invoice preview.
-     * * `schedule: &str` -- The identifier of the unstarted schedule whose upcoming invoice you'd like to retrieve. Cannot be used with subscription or subscription fields.
-     * * `subscription: &str` -- The identifier of the subscription for which you'd like to retrieve the upcoming invoice. If not provided, but a `subscription_items` is provided, you will preview creating a subscription with those items. If neither `subscription` nor `subscription_items` is provided, you will retrieve the next upcoming invoice from among the customer's subscriptions.
-     * * `subscription_billing_cycle_anchor: &str` -- For new subscriptions, a future timestamp to anchor the subscription's [billing cycle](https://stripe.com/docs/subscriptions/billing-cycle). This is used to determine the date of the first full invoice, and, for plans with `month` or `year` intervals, the day of the month for subsequent invoices. For existing subscriptions, the value can only be set to `now` or `unchanged`.
-     * * `subscription_cancel_at: &str` -- Timestamp indicating when the subscription should be scheduled to cancel. Will prorate if within the current period and prorations have been enabled using `proration_behavior`.
-     * * `subscription_cancel_at_period_end: bool` -- Boolean indicating whether this subscription should cancel at the end of the current period.
-     * * `subscription_cancel_now: bool` -- This simulates the subscription being canceled or expired immediately.
-     * * `subscription_default_tax_rates: &str` -- If provided, the invoice returned will preview updating or creating a subscription with these default tax rates. The default tax rates will apply to any line item that does not have `tax_rates` set.
-     * * `subscription_items: &[String]` -- A list of up to 20 subscription items, each with an attached price.
-     * * `subscription_proration_behavior: crate::types::ProrationBehavior` -- Determines how to handle [prorations](https://stripe.com/docs/subscriptions/billing-cycle#prorations) when the billing cycle changes (e.g., when switching plans, resetting `billing_cycle_anchor=now`, or starting a trial), or if an item's `quantity` changes. Valid values are `create_prorations`, `none`, or `always_invoice`.
-     *   
-     *   Passing `create_prorations` will cause proration invoice items to be created when applicable. These proration items will only be invoiced immediately under [certain conditions](https://stripe.com/docs/subscriptions/upgrading-downgrading#immediate-payment). In order to always invoice immediately for prorations, pass `always_invoice`.
-     *   
-     *   Prorations can be disabled by passing `none`.
-     * * `subscription_proration_date: i64` -- If previewing an update to a subscription, and doing proration, `subscription_proration_date` forces the proration to be calculated as though the update was done at the specified time. The time given must be within the current subscription period, and cannot be before the subscription was on its current plan. If set, `subscription`, and one of `subscription_items`, or `subscription_trial_end` are required. Also, `subscription_proration_behavior` cannot be set to 'none'.
-     * * `subscription_start_date: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
-     * * `subscription_trial_end: &str` -- If provided, the invoice returned will preview updating or creating a subscription with that trial end. If set, one of `subscription_items` or `subscription` is required.
-     * * `subscription_trial_from_plan: bool` -- Indicates if a plan's `trial_period_days` should be applied to the subscription. Setting `subscription_trial_end` per subscription is preferred, and this defaults to `false`. Setting this flag to `true` together with `subscription_trial_end` is not allowed. See [Using trial periods on subscriptions](https://stripe.com/docs/billing/subscriptions/trials) to learn more.
+     * * `params: &InvoicesGetUpcomingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_upcoming(
+    pub async fn get_upcoming_with(
         &self,
         _automatic_tax: &str,
-        coupon: &str,
-        customer: &str,
         _customer_details: &str,
         _discounts: &str,
         _invoice_items: &[String],
-        schedule: &str,
-        subscription: &str,
         _subscription_billing_cycle_anchor: &str,
         _subscription_cancel_at: &str,
-        subscription_cancel_at_period_end: bool,
-        subscription_cancel_now: bool,
         _subscription_default_tax_rates: &str,
         _subscription_items: &[String],
-        subscription_proration_behavior: crate::types::ProrationBehavior,
-        subscription_proration_date: i64,
-        subscription_start_date: i64,
         _subscription_trial_end: &str,
-        subscription_trial_from_plan: bool,
+        params: &InvoicesGetUpcomingParams,
     ) -> Result<crate::types::Invoice> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !coupon.is_empty() {
+        if let Some(coupon) = &params.coupon {
             query_args.push(("coupon".to_string(), coupon.to_string()));
         }
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !schedule.is_empty() {
+        if let Some(schedule) = &params.schedule {
             query_args.push(("schedule".to_string(), schedule.to_string()));
         }
-        if !subscription.is_empty() {
+        if let Some(subscription) = &params.subscription {
             query_args.push(("subscription".to_string(), subscription.to_string()));
         }
-        if subscription_cancel_at_period_end {
+        if let Some(subscription_cancel_at_period_end) = &params.subscription_cancel_at_period_end {
             query_args.push((
                 "subscription_cancel_at_period_end".to_string(),
                 subscription_cancel_at_period_end.to_string(),
             ));
         }
-        if subscription_cancel_now {
+        if let Some(subscription_cancel_now) = &params.subscription_cancel_now {
             query_args.push((
                 "subscription_cancel_now".to_string(),
                 subscription_cancel_now.to_string(),
             ));
         }
-        if !subscription_proration_behavior.to_string().is_empty() {
+        if let Some(subscription_proration_behavior) = &params.subscription_proration_behavior {
             query_args.push((
                 "subscription_proration_behavior".to_string(),
                 subscription_proration_behavior.to_string(),
             ));
         }
-        if subscription_proration_date > 0 {
+        if let Some(subscription_proration_date) = &params.subscription_proration_date {
             query_args.push((
                 "subscription_proration_date".to_string(),
                 subscription_proration_date.to_string(),
             ));
         }
-        if subscription_start_date > 0 {
+        if let Some(subscription_start_date) = &params.subscription_start_date {
             query_args.push((
                 "subscription_start_date".to_string(),
                 subscription_start_date.to_string(),
             ));
         }
-        if subscription_trial_from_plan {
+        if let Some(subscription_trial_from_plan) = &params.subscription_trial_from_plan {
             query_args.push((
                 "subscription_trial_from_plan".to_string(),
                 subscription_trial_from_plan.to_string(),
@@ -371,52 +490,20 @@ impl Invoices {
     }
 
     /**
-     * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
+     * Deprecated: use `get_upcoming_with` and pass the query parameters as a `InvoicesGetUpcomingParams` instead.
      *
-     * <p>When retrieving an upcoming invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
-     *
-     * **Parameters:**
-     *
-     * * `automatic_tax: &str` -- Settings for automatic tax lookup for this invoice preview.
-     * * `coupon: &str` -- The code of the coupon to apply. If `subscription` or `subscription_items` is provided, the invoice returned will preview updating or creating a subscription with that coupon. Otherwise, it will preview applying that coupon to the customer for the next upcoming invoice from among the customer's subscriptions. The invoice can be previewed without a coupon by passing this value as an empty string.
-     * * `customer: &str` -- The identifier of the customer whose upcoming invoice you'd like to retrieve.
-     * * `customer_details: &str` -- Details about the customer you want to invoice or overrides for an existing customer.
-     * * `discounts: &str` -- The coupons to redeem into discounts for the invoice preview. If not specified, inherits the discount from the customer or subscription. This only works for coupons directly applied to the invoice. To apply a coupon to a subscription, you must use the `coupon` parameter instead. Pass an empty string to avoid inheriting any discounts. To preview the upcoming invoice for a subscription that hasn't been created, use `coupon` instead.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `invoice_items: &[String]` -- List of invoice items to add or update in the upcoming invoice preview.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `schedule: &str` -- The identifier of the unstarted schedule whose upcoming invoice you'd like to retrieve. Cannot be used with subscription or subscription fields.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `subscription: &str` -- The identifier of the subscription for which you'd like to retrieve the upcoming invoice. If not provided, but a `subscription_items` is provided, you will preview creating a subscription with those items. If neither `subscription` nor `subscription_items` is provided, you will retrieve the next upcoming invoice from among the customer's subscriptions.
-     * * `subscription_billing_cycle_anchor: &str` -- For new subscriptions, a future timestamp to anchor the subscription's [billing cycle](https://stripe.com/docs/subscriptions/billing-cycle). This is used to determine the date of the first full invoice, and, for plans with `month` or `year` intervals, the day of the month for subsequent invoices. For existing subscriptions, the value can only be set to `now` or `unchanged`.
-     * * `subscription_cancel_at: &str` -- Timestamp indicating when the subscription should be scheduled to cancel. Will prorate if within the current period and prorations have been enabled using `proration_behavior`.
-     * * `subscription_cancel_at_period_end: bool` -- Boolean indicating whether this subscription should cancel at the end of the current period.
-     * * `subscription_cancel_now: bool` -- This simulates the subscription being canceled or expired immediately.
-     * * `subscription_default_tax_rates: &str` -- If provided, the invoice returned will preview updating or creating a subscription with these default tax rates. The default tax rates will apply to any line item that does not have `tax_rates` set.
-     * * `subscription_items: &[String]` -- A list of up to 20 subscription items, each with an attached price.
-     * * `subscription_proration_behavior: crate::types::ProrationBehavior` -- Determines how to handle [prorations](https://stripe.com/docs/subscriptions/billing-cycle#prorations) when the billing cycle changes (e.g., when switching plans, resetting `billing_cycle_anchor=now`, or starting a trial), or if an item's `quantity` changes. Valid values are `create_prorations`, `none`, or `always_invoice`.
-     *   
-     *   Passing `create_prorations` will cause proration invoice items to be created when applicable. These proration items will only be invoiced immediately under [certain conditions](https://stripe.com/docs/subscriptions/upgrading-downgrading#immediate-payment). In order to always invoice immediately for prorations, pass `always_invoice`.
-     *   
-     *   Prorations can be disabled by passing `none`.
-     * * `subscription_proration_date: i64` -- If previewing an update to a subscription, and doing proration, `subscription_proration_date` forces the proration to be calculated as though the update was done at the specified time. The time given must be within the current subscription period, and cannot be before the subscription was on its current plan. If set, `subscription`, and one of `subscription_items`, or `subscription_trial_end` are required. Also, `subscription_proration_behavior` cannot be set to 'none'.
-     * * `subscription_start_date: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
-     * * `subscription_trial_end: &str` -- If provided, the invoice returned will preview updating or creating a subscription with that trial end. If set, one of `subscription_items` or `subscription` is required.
-     * * `subscription_trial_from_plan: bool` -- Indicates if a plan's `trial_period_days` should be applied to the subscription. Setting `subscription_trial_end` per subscription is preferred, and this defaults to `false`. Setting this flag to `true` together with `subscription_trial_end` is not allowed. See [Using trial periods on subscriptions](https://stripe.com/docs/billing/subscriptions/trials) to learn more.
+     * `automatic_tax`, `customer_details`, `discounts`, `invoice_items`, `subscription_billing_cycle_anchor`, `subscription_cancel_at`, `subscription_default_tax_rates`, `subscription_items`, `subscription_trial_end` are not sent.
      */
-    pub async fn get_upcoming_lines(
+    #[deprecated(note = "use `get_upcoming_with` instead")]
+    pub async fn get_upcoming(
         &self,
         _automatic_tax: &str,
         coupon: &str,
         customer: &str,
         _customer_details: &str,
         _discounts: &str,
-        ending_before: &str,
         _invoice_items: &[String],
-        limit: i64,
         schedule: &str,
-        starting_after: &str,
         subscription: &str,
         _subscription_billing_cycle_anchor: &str,
         _subscription_cancel_at: &str,
@@ -429,60 +516,116 @@ impl Invoices {
         subscription_start_date: i64,
         _subscription_trial_end: &str,
         subscription_trial_from_plan: bool,
+    ) -> Result<crate::types::Invoice> {
+        let params = InvoicesGetUpcomingParams {
+            coupon: Some(coupon.to_string()).filter(|s| !s.is_empty()),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            schedule: Some(schedule.to_string()).filter(|s| !s.is_empty()),
+            subscription: Some(subscription.to_string()).filter(|s| !s.is_empty()),
+            subscription_cancel_at_period_end: Some(subscription_cancel_at_period_end)
+                .filter(|v| *v),
+            subscription_cancel_now: Some(subscription_cancel_now).filter(|v| *v),
+            subscription_proration_behavior: Some(subscription_proration_behavior)
+                .filter(|v| !v.to_string().is_empty()),
+            subscription_proration_date: Some(subscription_proration_date).filter(|v| *v > 0),
+            subscription_start_date: Some(subscription_start_date).filter(|v| *v > 0),
+            subscription_trial_from_plan: Some(subscription_trial_from_plan).filter(|v| *v),
+        };
+        self.get_upcoming_with(
+            _automatic_tax,
+            _customer_details,
+            _discounts,
+            _invoice_items,
+            _subscription_billing_cycle_anchor,
+            _subscription_cancel_at,
+            _subscription_default_tax_rates,
+            _subscription_items,
+            _subscription_trial_end,
+            &params,
+        )
+        .await
+    }
+
+    /**
+     * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
+     *
+     * <p>When retrieving an upcoming invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &InvoicesGetUpcomingLinesParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_upcoming_lines_with(
+        &self,
+        _automatic_tax: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        _invoice_items: &[String],
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        _subscription_trial_end: &str,
+        params: &InvoicesGetUpcomingLinesParams,
     ) -> Result<Vec<crate::types::LineItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !coupon.is_empty() {
+        if let Some(coupon) = &params.coupon {
             query_args.push(("coupon".to_string(), coupon.to_string()));
         }
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !schedule.is_empty() {
+        if let Some(schedule) = &params.schedule {
             query_args.push(("schedule".to_string(), schedule.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !subscription.is_empty() {
+        if let Some(subscription) = &params.subscription {
             query_args.push(("subscription".to_string(), subscription.to_string()));
         }
-        if subscription_cancel_at_period_end {
+        if let Some(subscription_cancel_at_period_end) = &params.subscription_cancel_at_period_end {
             query_args.push((
                 "subscription_cancel_at_period_end".to_string(),
                 subscription_cancel_at_period_end.to_string(),
             ));
         }
-        if subscription_cancel_now {
+        if let Some(subscription_cancel_now) = &params.subscription_cancel_now {
             query_args.push((
                 "subscription_cancel_now".to_string(),
                 subscription_cancel_now.to_string(),
             ));
         }
-        if !subscription_proration_behavior.to_string().is_empty() {
+        if let Some(subscription_proration_behavior) = &params.subscription_proration_behavior {
             query_args.push((
                 "subscription_proration_behavior".to_string(),
                 subscription_proration_behavior.to_string(),
             ));
         }
-        if subscription_proration_date > 0 {
+        if let Some(subscription_proration_date) = &params.subscription_proration_date {
             query_args.push((
                 "subscription_proration_date".to_string(),
                 subscription_proration_date.to_string(),
             ));
         }
-        if subscription_start_date > 0 {
+        if let Some(subscription_start_date) = &params.subscription_start_date {
             query_args.push((
                 "subscription_start_date".to_string(),
                 subscription_start_date.to_string(),
             ));
         }
-        if subscription_trial_from_plan {
+        if let Some(subscription_trial_from_plan) = &params.subscription_trial_from_plan {
             query_args.push((
                 "subscription_trial_from_plan".to_string(),
                 subscription_trial_from_plan.to_string(),
@@ -497,13 +640,170 @@ impl Invoices {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_upcoming_lines_with` and pass the query parameters as a `InvoicesGetUpcomingLinesParams` instead.
+     *
+     * `automatic_tax`, `customer_details`, `discounts`, `invoice_items`, `subscription_billing_cycle_anchor`, `subscription_cancel_at`, `subscription_default_tax_rates`, `subscription_items`, `subscription_trial_end` are not sent.
+     */
+    #[deprecated(note = "use `get_upcoming_lines_with` instead")]
+    pub async fn get_upcoming_lines(
+        &self,
+        _automatic_tax: &str,
+        coupon: &str,
+        customer: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        ending_before: &str,
+        _invoice_items: &[String],
+        limit: i64,
+        schedule: &str,
+        starting_after: &str,
+        subscription: &str,
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        subscription_cancel_at_period_end: bool,
+        subscription_cancel_now: bool,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+        subscription_proration_date: i64,
+        subscription_start_date: i64,
+        _subscription_trial_end: &str,
+        subscription_trial_from_plan: bool,
+    ) -> Result<Vec<crate::types::LineItem>> {
+        let params = InvoicesGetUpcomingLinesParams {
+            coupon: Some(coupon.to_string()).filter(|s| !s.is_empty()),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            schedule: Some(schedule.to_string()).filter(|s| !s.is_empty()),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            subscription: Some(subscription.to_string()).filter(|s| !s.is_empty()),
+            subscription_cancel_at_period_end: Some(subscription_cancel_at_period_end)
+                .filter(|v| *v),
+            subscription_cancel_now: Some(subscription_cancel_now).filter(|v| *v),
+            subscription_proration_behavior: Some(subscription_proration_behavior)
+                .filter(|v| !v.to_string().is_empty()),
+            subscription_proration_date: Some(subscription_proration_date).filter(|v| *v > 0),
+            subscription_start_date: Some(subscription_start_date).filter(|v| *v > 0),
+            subscription_trial_from_plan: Some(subscription_trial_from_plan).filter(|v| *v),
+            ..Default::default()
+        };
+        self.get_upcoming_lines_with(
+            _automatic_tax,
+            _customer_details,
+            _discounts,
+            _invoice_items,
+            _subscription_billing_cycle_anchor,
+            _subscription_cancel_at,
+            _subscription_default_tax_rates,
+            _subscription_items,
+            _subscription_trial_end,
+            &params,
+        )
+        .await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
      *
      * As opposed to `get_upcoming_lines`, this function returns all the pages of the request at once.
      *
      * <p>When retrieving an upcoming invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &InvoicesGetUpcomingLinesParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_upcoming_lines_with(
+        &self,
+        _automatic_tax: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        _invoice_items: &[String],
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        _subscription_trial_end: &str,
+        params: &InvoicesGetUpcomingLinesParams,
+    ) -> Result<Vec<crate::types::LineItem>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(coupon) = &params.coupon {
+            query_args.push(("coupon".to_string(), coupon.to_string()));
+        }
+        if let Some(customer) = &params.customer {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(schedule) = &params.schedule {
+            query_args.push(("schedule".to_string(), schedule.to_string()));
+        }
+        if let Some(subscription) = &params.subscription {
+            query_args.push(("subscription".to_string(), subscription.to_string()));
+        }
+        if let Some(subscription_cancel_at_period_end) = &params.subscription_cancel_at_period_end {
+            query_args.push((
+                "subscription_cancel_at_period_end".to_string(),
+                subscription_cancel_at_period_end.to_string(),
+            ));
+        }
+        if let Some(subscription_cancel_now) = &params.subscription_cancel_now {
+            query_args.push((
+                "subscription_cancel_now".to_string(),
+                subscription_cancel_now.to_string(),
+            ));
+        }
+        if let Some(subscription_proration_behavior) = &params.subscription_proration_behavior {
+            query_args.push((
+                "subscription_proration_behavior".to_string(),
+                subscription_proration_behavior.to_string(),
+            ));
+        }
+        if let Some(subscription_proration_date) = &params.subscription_proration_date {
+            query_args.push((
+                "subscription_proration_date".to_string(),
+                subscription_proration_date.to_string(),
+            ));
+        }
+        if let Some(subscription_start_date) = &params.subscription_start_date {
+            query_args.push((
+                "subscription_start_date".to_string(),
+                subscription_start_date.to_string(),
+            ));
+        }
+        if let Some(subscription_trial_from_plan) = &params.subscription_trial_from_plan {
+            query_args.push((
+                "subscription_trial_from_plan".to_string(),
+                subscription_trial_from_plan.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/invoices/upcoming/lines?{}", query_);
+
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::LineItem| Some(item.id.clone()),
+        )
+        .await
+    }
+
+    /**
+     * Deprecated: use `get_all_upcoming_lines_with` and pass the query parameters as a `InvoicesGetUpcomingLinesParams` instead.
+     *
+     * `automatic_tax`, `customer_details`, `discounts`, `invoice_items`, `subscription_billing_cycle_anchor`, `subscription_cancel_at`, `subscription_default_tax_rates`, `subscription_items`, `subscription_trial_end` are not sent.
+     */
+    #[deprecated(note = "use `get_all_upcoming_lines_with` instead")]
     pub async fn get_all_upcoming_lines(
         &self,
         _automatic_tax: &str,
@@ -526,50 +826,107 @@ impl Invoices {
         _subscription_trial_end: &str,
         subscription_trial_from_plan: bool,
     ) -> Result<Vec<crate::types::LineItem>> {
+        let params = InvoicesGetUpcomingLinesParams {
+            coupon: Some(coupon.to_string()).filter(|s| !s.is_empty()),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            schedule: Some(schedule.to_string()).filter(|s| !s.is_empty()),
+            subscription: Some(subscription.to_string()).filter(|s| !s.is_empty()),
+            subscription_cancel_at_period_end: Some(subscription_cancel_at_period_end)
+                .filter(|v| *v),
+            subscription_cancel_now: Some(subscription_cancel_now).filter(|v| *v),
+            subscription_proration_behavior: Some(subscription_proration_behavior)
+                .filter(|v| !v.to_string().is_empty()),
+            subscription_proration_date: Some(subscription_proration_date).filter(|v| *v > 0),
+            subscription_start_date: Some(subscription_start_date).filter(|v| *v > 0),
+            subscription_trial_from_plan: Some(subscription_trial_from_plan).filter(|v| *v),
+            ..Default::default()
+        };
+        self.get_all_upcoming_lines_with(
+            _automatic_tax,
+            _customer_details,
+            _discounts,
+            _invoice_items,
+            _subscription_billing_cycle_anchor,
+            _subscription_cancel_at,
+            _subscription_default_tax_rates,
+            _subscription_items,
+            _subscription_trial_end,
+            &params,
+        )
+        .await
+    }
+
+    /**
+     * This function performs a `GET` to the `/v1/invoices/upcoming/lines` endpoint.
+     *
+     * As opposed to `get_upcoming_lines`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>When retrieving an upcoming invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_upcoming_lines_stream(
+        &self,
+        _automatic_tax: &str,
+        _customer_details: &str,
+        _discounts: &str,
+        _invoice_items: &[String],
+        _subscription_billing_cycle_anchor: &str,
+        _subscription_cancel_at: &str,
+        _subscription_default_tax_rates: &str,
+        _subscription_items: &[String],
+        _subscription_trial_end: &str,
+        params: &InvoicesGetUpcomingLinesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LineItem> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !coupon.is_empty() {
+        if let Some(coupon) = &params.coupon {
             query_args.push(("coupon".to_string(), coupon.to_string()));
         }
-        if !customer.is_empty() {
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !schedule.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(schedule) = &params.schedule {
             query_args.push(("schedule".to_string(), schedule.to_string()));
         }
-        if !subscription.is_empty() {
+        if let Some(subscription) = &params.subscription {
             query_args.push(("subscription".to_string(), subscription.to_string()));
         }
-        if subscription_cancel_at_period_end {
+        if let Some(subscription_cancel_at_period_end) = &params.subscription_cancel_at_period_end {
             query_args.push((
                 "subscription_cancel_at_period_end".to_string(),
                 subscription_cancel_at_period_end.to_string(),
             ));
         }
-        if subscription_cancel_now {
+        if let Some(subscription_cancel_now) = &params.subscription_cancel_now {
             query_args.push((
                 "subscription_cancel_now".to_string(),
                 subscription_cancel_now.to_string(),
             ));
         }
-        if !subscription_proration_behavior.to_string().is_empty() {
+        if let Some(subscription_proration_behavior) = &params.subscription_proration_behavior {
             query_args.push((
                 "subscription_proration_behavior".to_string(),
                 subscription_proration_behavior.to_string(),
             ));
         }
-        if subscription_proration_date > 0 {
+        if let Some(subscription_proration_date) = &params.subscription_proration_date {
             query_args.push((
                 "subscription_proration_date".to_string(),
                 subscription_proration_date.to_string(),
             ));
         }
-        if subscription_start_date > 0 {
+        if let Some(subscription_start_date) = &params.subscription_start_date {
             query_args.push((
                 "subscription_start_date".to_string(),
                 subscription_start_date.to_string(),
             ));
         }
-        if subscription_trial_from_plan {
+        if let Some(subscription_trial_from_plan) = &params.subscription_trial_from_plan {
             query_args.push((
                 "subscription_trial_from_plan".to_string(),
                 subscription_trial_from_plan.to_string(),
@@ -578,43 +935,18 @@ impl Invoices {
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/invoices/upcoming/lines?{}", query_);
 
-        let mut resp: crate::types::InvoiceLinesList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
-
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::LineItem| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -649,14 +981,25 @@ impl Invoices {
      * **Parameters:**
      *
      * * `invoice: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_invoices(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_invoices<B: serde::Serialize>(
+        &self,
+        invoice: &str,
+        body: &B,
+    ) -> Result<crate::types::Invoice> {
         let url = format!(
             "/v1/invoices/{}",
             crate::progenitor_support::encode_path(invoice),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -685,14 +1028,25 @@ impl Invoices {
      * **Parameters:**
      *
      * * `invoice: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_finalize(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_finalize<B: serde::Serialize>(
+        &self,
+        invoice: &str,
+        body: &B,
+    ) -> Result<crate::types::Invoice> {
         let url = format!(
             "/v1/invoices/{}/finalize",
             crate::progenitor_support::encode_path(invoice),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -702,27 +1056,27 @@ impl Invoices {
      *
      * **Parameters:**
      *
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `invoice: &str` -- The account's country.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &InvoicesGetLinesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_lines(
+    pub async fn get_lines_with(
         &self,
-        ending_before: &str,
         invoice: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &InvoicesGetLinesParams,
     ) -> Result<Vec<crate::types::LineItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -738,56 +1092,116 @@ impl Invoices {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_lines_with` and pass the query parameters as a `InvoicesGetLinesParams` instead.
+     */
+    #[deprecated(note = "use `get_lines_with` instead")]
+    pub async fn get_lines(
+        &self,
+        ending_before: &str,
+        invoice: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::LineItem>> {
+        let params = InvoicesGetLinesParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_lines_with(invoice, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/invoices/{invoice}/lines` endpoint.
      *
      * As opposed to `get_lines`, this function returns all the pages of the request at once.
      *
      * <p>When retrieving an invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &InvoicesGetLinesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_lines(&self, invoice: &str) -> Result<Vec<crate::types::LineItem>> {
+    pub async fn get_all_lines_with(
+        &self,
+        invoice: &str,
+        params: &InvoicesGetLinesParams,
+    ) -> Result<Vec<crate::types::LineItem>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/invoices/{}/lines",
+            "/v1/invoices/{}/lines?{}",
             crate::progenitor_support::encode_path(invoice),
+            query_
         );
 
-        let mut resp: crate::types::InvoiceLinesList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::LineItem| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_lines_with` and pass the query parameters as a `InvoicesGetLinesParams` instead.
+     */
+    #[deprecated(note = "use `get_all_lines_with` instead")]
+    pub async fn get_all_lines(&self, invoice: &str) -> Result<Vec<crate::types::LineItem>> {
+        let params = InvoicesGetLinesParams::default();
+        self.get_all_lines_with(invoice, &params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/invoices/{invoice}/lines` endpoint.
+     *
+     * As opposed to `get_lines`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>When retrieving an invoice, you’ll get a <strong>lines</strong> property containing the total count of line items and the first handful of those items. There is also a URL where you can retrieve the full (paginated) list of line items.</p>
+     */
+    pub fn get_lines_stream(
+        &self,
+        invoice: &str,
+        params: &InvoicesGetLinesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::LineItem> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/invoices/{}/lines?{}",
+            crate::progenitor_support::encode_path(invoice),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::LineItem| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -798,14 +1212,25 @@ impl Invoices {
      * **Parameters:**
      *
      * * `invoice: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_mark_uncollectible(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_mark_uncollectible<B: serde::Serialize>(
+        &self,
+        invoice: &str,
+        body: &B,
+    ) -> Result<crate::types::Invoice> {
         let url = format!(
             "/v1/invoices/{}/mark_uncollectible",
             crate::progenitor_support::encode_path(invoice),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -816,14 +1241,25 @@ impl Invoices {
      * **Parameters:**
      *
      * * `invoice: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_pay(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_pay<B: serde::Serialize>(
+        &self,
+        invoice: &str,
+        body: &B,
+    ) -> Result<crate::types::Invoice> {
         let url = format!(
             "/v1/invoices/{}/pay",
             crate::progenitor_support::encode_path(invoice),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -836,14 +1272,25 @@ impl Invoices {
      * **Parameters:**
      *
      * * `invoice: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_send(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_send<B: serde::Serialize>(
+        &self,
+        invoice: &str,
+        body: &B,
+    ) -> Result<crate::types::Invoice> {
         let url = format!(
             "/v1/invoices/{}/send",
             crate::progenitor_support::encode_path(invoice),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -854,13 +1301,371 @@ impl Invoices {
      * **Parameters:**
      *
      * * `invoice: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_void(&self, invoice: &str) -> Result<crate::types::Invoice> {
+    pub async fn post_void<B: serde::Serialize>(
+        &self,
+        invoice: &str,
+        body: &B,
+    ) -> Result<crate::types::Invoice> {
         let url = format!(
             "/v1/invoices/{}/void",
             crate::progenitor_support::encode_path(invoice),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Invoices::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InvoicesGetPageParams {
+    /// Either `charge_automatically`, or `send_invoice`. When charging automatically, Stripe will attempt to pay this invoice using the default source attached to the customer. When sending an invoice, Stripe will email this invoice to the customer with payment instructions.
+    pub collection_method: Option<crate::types::CollectionMethod>,
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// Only return invoices for the customer specified by this customer ID.
+    pub customer: Option<String>,
+    pub due_date: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// The status of the invoice, one of `draft`, `open`, `paid`, `uncollectible`, or `void`. [Learn more](https://stripe.com/docs/billing/invoices/workflow#workflow-overview).
+    pub status: Option<crate::types::GetInvoicesStatus>,
+    /// Only return invoices for the subscription specified by this subscription ID.
+    pub subscription: Option<String>,
+}
+
+impl InvoicesGetPageParams {
+    pub fn collection_method(mut self, collection_method: crate::types::CollectionMethod) -> Self {
+        self.collection_method = Some(collection_method);
+        self
+    }
+
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn due_date(mut self, due_date: crate::pagination::RangeQuery) -> Self {
+        self.due_date = Some(due_date);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::GetInvoicesStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn subscription<T: ToString>(mut self, subscription: T) -> Self {
+        self.subscription = Some(subscription.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Invoices::get_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InvoicesGetSearchParams {
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
+    pub page: Option<String>,
+    /// The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for invoices](https://stripe.com/docs/search#query-fields-for-invoices).
+    pub query: Option<String>,
+}
+
+impl InvoicesGetSearchParams {
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page<T: ToString>(mut self, page: T) -> Self {
+        self.page = Some(page.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Invoices::get_upcoming_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InvoicesGetUpcomingParams {
+    /// The code of the coupon to apply. If `subscription` or `subscription_items` is provided, the invoice returned will preview updating or creating a subscription with that coupon. Otherwise, it will preview applying that coupon to the customer for the next upcoming invoice from among the customer's subscriptions. The invoice can be previewed without a coupon by passing this value as an empty string.
+    pub coupon: Option<String>,
+    /// The identifier of the customer whose upcoming invoice you'd like to retrieve.
+    pub customer: Option<String>,
+    /// The identifier of the unstarted schedule whose upcoming invoice you'd like to retrieve. Cannot be used with subscription or subscription fields.
+    pub schedule: Option<String>,
+    /// The identifier of the subscription for which you'd like to retrieve the upcoming invoice. If not provided, but a `subscription_items` is provided, you will preview creating a subscription with those items. If neither `subscription` nor `subscription_items` is provided, you will retrieve the next upcoming invoice from among the customer's subscriptions.
+    pub subscription: Option<String>,
+    /// Boolean indicating whether this subscription should cancel at the end of the current period.
+    pub subscription_cancel_at_period_end: Option<bool>,
+    /// This simulates the subscription being canceled or expired immediately.
+    pub subscription_cancel_now: Option<bool>,
+    /// Determines how to handle [prorations](https://stripe.com/docs/subscriptions/billing-cycle#prorations) when the billing cycle changes (e.g., when switching plans, resetting `billing_cycle_anchor=now`, or starting a trial), or if an item's `quantity` changes. Valid values are `create_prorations`, `none`, or `always_invoice`.
+    ///
+    /// Passing `create_prorations` will cause proration invoice items to be created when applicable. These proration items will only be invoiced immediately under [certain conditions](https://stripe.com/docs/subscriptions/upgrading-downgrading#immediate-payment). In order to always invoice immediately for prorations, pass `always_invoice`.
+    ///
+    /// Prorations can be disabled by passing `none`.
+    pub subscription_proration_behavior: Option<crate::types::ProrationBehavior>,
+    /// If previewing an update to a subscription, and doing proration, `subscription_proration_date` forces the proration to be calculated as though the update was done at the specified time. The time given must be within the current subscription period, and cannot be before the subscription was on its current plan. If set, `subscription`, and one of `subscription_items`, or `subscription_trial_end` are required. Also, `subscription_proration_behavior` cannot be set to 'none'.
+    pub subscription_proration_date: Option<i64>,
+    /// Time at which the account was connected. Measured in seconds since the Unix epoch.
+    pub subscription_start_date: Option<i64>,
+    /// Indicates if a plan's `trial_period_days` should be applied to the subscription. Setting `subscription_trial_end` per subscription is preferred, and this defaults to `false`. Setting this flag to `true` together with `subscription_trial_end` is not allowed. See [Using trial periods on subscriptions](https://stripe.com/docs/billing/subscriptions/trials) to learn more.
+    pub subscription_trial_from_plan: Option<bool>,
+}
+
+impl InvoicesGetUpcomingParams {
+    pub fn coupon<T: ToString>(mut self, coupon: T) -> Self {
+        self.coupon = Some(coupon.to_string());
+        self
+    }
+
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn schedule<T: ToString>(mut self, schedule: T) -> Self {
+        self.schedule = Some(schedule.to_string());
+        self
+    }
+
+    pub fn subscription<T: ToString>(mut self, subscription: T) -> Self {
+        self.subscription = Some(subscription.to_string());
+        self
+    }
+
+    pub fn subscription_cancel_at_period_end(
+        mut self,
+        subscription_cancel_at_period_end: bool,
+    ) -> Self {
+        self.subscription_cancel_at_period_end = Some(subscription_cancel_at_period_end);
+        self
+    }
+
+    pub fn subscription_cancel_now(mut self, subscription_cancel_now: bool) -> Self {
+        self.subscription_cancel_now = Some(subscription_cancel_now);
+        self
+    }
+
+    pub fn subscription_proration_behavior(
+        mut self,
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+    ) -> Self {
+        self.subscription_proration_behavior = Some(subscription_proration_behavior);
+        self
+    }
+
+    pub fn subscription_proration_date(mut self, subscription_proration_date: i64) -> Self {
+        self.subscription_proration_date = Some(subscription_proration_date);
+        self
+    }
+
+    pub fn subscription_start_date(mut self, subscription_start_date: i64) -> Self {
+        self.subscription_start_date = Some(subscription_start_date);
+        self
+    }
+
+    pub fn subscription_trial_from_plan(mut self, subscription_trial_from_plan: bool) -> Self {
+        self.subscription_trial_from_plan = Some(subscription_trial_from_plan);
+        self
+    }
+}
+
+/// The query parameters of `Invoices::get_upcoming_lines_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InvoicesGetUpcomingLinesParams {
+    /// The code of the coupon to apply. If `subscription` or `subscription_items` is provided, the invoice returned will preview updating or creating a subscription with that coupon. Otherwise, it will preview applying that coupon to the customer for the next upcoming invoice from among the customer's subscriptions. The invoice can be previewed without a coupon by passing this value as an empty string.
+    pub coupon: Option<String>,
+    /// The identifier of the customer whose upcoming invoice you'd like to retrieve.
+    pub customer: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// The identifier of the unstarted schedule whose upcoming invoice you'd like to retrieve. Cannot be used with subscription or subscription fields.
+    pub schedule: Option<String>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// The identifier of the subscription for which you'd like to retrieve the upcoming invoice. If not provided, but a `subscription_items` is provided, you will preview creating a subscription with those items. If neither `subscription` nor `subscription_items` is provided, you will retrieve the next upcoming invoice from among the customer's subscriptions.
+    pub subscription: Option<String>,
+    /// Boolean indicating whether this subscription should cancel at the end of the current period.
+    pub subscription_cancel_at_period_end: Option<bool>,
+    /// This simulates the subscription being canceled or expired immediately.
+    pub subscription_cancel_now: Option<bool>,
+    /// Determines how to handle [prorations](https://stripe.com/docs/subscriptions/billing-cycle#prorations) when the billing cycle changes (e.g., when switching plans, resetting `billing_cycle_anchor=now`, or starting a trial), or if an item's `quantity` changes. Valid values are `create_prorations`, `none`, or `always_invoice`.
+    ///
+    /// Passing `create_prorations` will cause proration invoice items to be created when applicable. These proration items will only be invoiced immediately under [certain conditions](https://stripe.com/docs/subscriptions/upgrading-downgrading#immediate-payment). In order to always invoice immediately for prorations, pass `always_invoice`.
+    ///
+    /// Prorations can be disabled by passing `none`.
+    pub subscription_proration_behavior: Option<crate::types::ProrationBehavior>,
+    /// If previewing an update to a subscription, and doing proration, `subscription_proration_date` forces the proration to be calculated as though the update was done at the specified time. The time given must be within the current subscription period, and cannot be before the subscription was on its current plan. If set, `subscription`, and one of `subscription_items`, or `subscription_trial_end` are required. Also, `subscription_proration_behavior` cannot be set to 'none'.
+    pub subscription_proration_date: Option<i64>,
+    /// Time at which the account was connected. Measured in seconds since the Unix epoch.
+    pub subscription_start_date: Option<i64>,
+    /// Indicates if a plan's `trial_period_days` should be applied to the subscription. Setting `subscription_trial_end` per subscription is preferred, and this defaults to `false`. Setting this flag to `true` together with `subscription_trial_end` is not allowed. See [Using trial periods on subscriptions](https://stripe.com/docs/billing/subscriptions/trials) to learn more.
+    pub subscription_trial_from_plan: Option<bool>,
+}
+
+impl InvoicesGetUpcomingLinesParams {
+    pub fn coupon<T: ToString>(mut self, coupon: T) -> Self {
+        self.coupon = Some(coupon.to_string());
+        self
+    }
+
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn schedule<T: ToString>(mut self, schedule: T) -> Self {
+        self.schedule = Some(schedule.to_string());
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn subscription<T: ToString>(mut self, subscription: T) -> Self {
+        self.subscription = Some(subscription.to_string());
+        self
+    }
+
+    pub fn subscription_cancel_at_period_end(
+        mut self,
+        subscription_cancel_at_period_end: bool,
+    ) -> Self {
+        self.subscription_cancel_at_period_end = Some(subscription_cancel_at_period_end);
+        self
+    }
+
+    pub fn subscription_cancel_now(mut self, subscription_cancel_now: bool) -> Self {
+        self.subscription_cancel_now = Some(subscription_cancel_now);
+        self
+    }
+
+    pub fn subscription_proration_behavior(
+        mut self,
+        subscription_proration_behavior: crate::types::ProrationBehavior,
+    ) -> Self {
+        self.subscription_proration_behavior = Some(subscription_proration_behavior);
+        self
+    }
+
+    pub fn subscription_proration_date(mut self, subscription_proration_date: i64) -> Self {
+        self.subscription_proration_date = Some(subscription_proration_date);
+        self
+    }
+
+    pub fn subscription_start_date(mut self, subscription_start_date: i64) -> Self {
+        self.subscription_start_date = Some(subscription_start_date);
+        self
+    }
+
+    pub fn subscription_trial_from_plan(mut self, subscription_trial_from_plan: bool) -> Self {
+        self.subscription_trial_from_plan = Some(subscription_trial_from_plan);
+        self
+    }
+}
+
+/// The query parameters of `Invoices::get_lines_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct InvoicesGetLinesParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl InvoicesGetLinesParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
     }
 }
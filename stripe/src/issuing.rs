@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Issuing {
     pub client: Client,
@@ -19,42 +17,37 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `card: &str` -- Only return authorizations that belong to the given card.
-     * * `cardholder: &str` -- Only return authorizations that belong to the given cardholder.
-     * * `created: &str` -- Only return authorizations that were created during the given date interval.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `status: crate::types::IssuingAuthorizationStatus` -- Only return authorizations with the given status. One of `pending`, `closed`, or `reversed`.
+     * * `params: &IssuingGetAuthorizationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_authorizations(
+    pub async fn get_authorizations_with(
         &self,
-        card: &str,
-        cardholder: &str,
-        _created: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
-        status: crate::types::IssuingAuthorizationStatus,
+        params: &IssuingGetAuthorizationsParams,
     ) -> Result<Vec<crate::types::IssuingAuthorization>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !card.is_empty() {
+        if let Some(card) = &params.card {
             query_args.push(("card".to_string(), card.to_string()));
         }
-        if !cardholder.is_empty() {
+        if let Some(cardholder) = &params.cardholder {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -67,71 +60,149 @@ impl Issuing {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_authorizations_with` and pass the query parameters as a `IssuingGetAuthorizationsParams` instead.
+     */
+    #[deprecated(note = "use `get_authorizations_with` instead")]
+    pub async fn get_authorizations(
+        &self,
+        card: &str,
+        cardholder: &str,
+        created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::IssuingAuthorizationStatus,
+    ) -> Result<Vec<crate::types::IssuingAuthorization>> {
+        let params = IssuingGetAuthorizationsParams {
+            card: Some(card.to_string()).filter(|s| !s.is_empty()),
+            cardholder: Some(cardholder.to_string()).filter(|s| !s.is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_authorizations_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/issuing/authorizations` endpoint.
      *
      * As opposed to `get_authorizations`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of Issuing <code>Authorization</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IssuingGetAuthorizationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_authorizations(
+    pub async fn get_all_authorizations_with(
         &self,
-        card: &str,
-        cardholder: &str,
-        _created: &str,
-        status: crate::types::IssuingAuthorizationStatus,
+        params: &IssuingGetAuthorizationsParams,
     ) -> Result<Vec<crate::types::IssuingAuthorization>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !card.is_empty() {
+        if let Some(card) = &params.card {
             query_args.push(("card".to_string(), card.to_string()));
         }
-        if !cardholder.is_empty() {
+        if let Some(cardholder) = &params.cardholder {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/issuing/authorizations?{}", query_);
 
-        let mut resp: crate::types::GetIssuingAuthorizationsResponse =
-            self.client.get(&url, None).await?;
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::IssuingAuthorization| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_authorizations_with` and pass the query parameters as a `IssuingGetAuthorizationsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_authorizations_with` instead")]
+    pub async fn get_all_authorizations(
+        &self,
+        card: &str,
+        cardholder: &str,
+        created: &str,
+        status: crate::types::IssuingAuthorizationStatus,
+    ) -> Result<Vec<crate::types::IssuingAuthorization>> {
+        let params = IssuingGetAuthorizationsParams {
+            card: Some(card.to_string()).filter(|s| !s.is_empty()),
+            cardholder: Some(cardholder.to_string()).filter(|s| !s.is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_authorizations_with(&params).await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/issuing/authorizations` endpoint.
+     *
+     * As opposed to `get_authorizations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of Issuing <code>Authorization</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_authorizations_stream(
+        &self,
+        params: &IssuingGetAuthorizationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssuingAuthorization> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(card) = &params.card {
+            query_args.push(("card".to_string(), card.to_string()));
+        }
+        if let Some(cardholder) = &params.cardholder {
+            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        if let Some(status) = &params.status {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/issuing/authorizations?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::IssuingAuthorization| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -164,17 +235,25 @@ impl Issuing {
      * **Parameters:**
      *
      * * `authorization: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_authorizations_authorization(
+    pub async fn post_authorizations_authorization<B: serde::Serialize>(
         &self,
         authorization: &str,
+        body: &B,
     ) -> Result<crate::types::IssuingAuthorization> {
         let url = format!(
             "/v1/issuing/authorizations/{}",
             crate::progenitor_support::encode_path(authorization),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -185,17 +264,25 @@ impl Issuing {
      * **Parameters:**
      *
      * * `authorization: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_authorizations_authorization_approve(
+    pub async fn post_authorizations_authorization_approve<B: serde::Serialize>(
         &self,
         authorization: &str,
+        body: &B,
     ) -> Result<crate::types::IssuingAuthorization> {
         let url = format!(
             "/v1/issuing/authorizations/{}/approve",
             crate::progenitor_support::encode_path(authorization),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -206,17 +293,25 @@ impl Issuing {
      * **Parameters:**
      *
      * * `authorization: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_authorizations_authorization_decline(
+    pub async fn post_authorizations_authorization_decline<B: serde::Serialize>(
         &self,
         authorization: &str,
+        body: &B,
     ) -> Result<crate::types::IssuingAuthorization> {
         let url = format!(
             "/v1/issuing/authorizations/{}/decline",
             crate::progenitor_support::encode_path(authorization),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -226,47 +321,40 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `created: &str` -- Only return cardholders that were created during the given date interval.
-     * * `email: &str` -- Only return cardholders that have the given email address.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `phone_number: &str` -- Only return cardholders that have the given phone number.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `status: crate::types::IssuingCardholderStatus` -- Only return cardholders that have the given status. One of `active`, `inactive`, or `blocked`.
-     * * `type_: crate::types::AccountHolderType` -- Type of entity that holds the account. This can be either `individual` or `company`.
+     * * `params: &IssuingGetCardholdersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_cardholders(
+    pub async fn get_cardholders_with(
         &self,
-        _created: &str,
-        email: &str,
-        ending_before: &str,
-        limit: i64,
-        phone_number: &str,
-        starting_after: &str,
-        status: crate::types::IssuingCardholderStatus,
-        type_: crate::types::AccountHolderType,
+        params: &IssuingGetCardholdersParams,
     ) -> Result<Vec<crate::types::IssuingCardholder>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !email.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !phone_number.is_empty() {
+        if let Some(phone_number) = &params.phone_number {
             query_args.push(("phone_number".to_string(), phone_number.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -278,85 +366,182 @@ impl Issuing {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_cardholders_with` and pass the query parameters as a `IssuingGetCardholdersParams` instead.
+     */
+    #[deprecated(note = "use `get_cardholders_with` instead")]
+    pub async fn get_cardholders(
+        &self,
+        created: &str,
+        email: &str,
+        ending_before: &str,
+        limit: i64,
+        phone_number: &str,
+        starting_after: &str,
+        status: crate::types::IssuingCardholderStatus,
+        type_: crate::types::AccountHolderType,
+    ) -> Result<Vec<crate::types::IssuingCardholder>> {
+        let params = IssuingGetCardholdersParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            phone_number: Some(phone_number.to_string()).filter(|s| !s.is_empty()),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_cardholders_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/issuing/cardholders` endpoint.
      *
      * As opposed to `get_cardholders`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of Issuing <code>Cardholder</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IssuingGetCardholdersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_cardholders(
+    pub async fn get_all_cardholders_with(
         &self,
-        _created: &str,
-        email: &str,
-        phone_number: &str,
-        status: crate::types::IssuingCardholderStatus,
-        type_: crate::types::AccountHolderType,
+        params: &IssuingGetCardholdersParams,
     ) -> Result<Vec<crate::types::IssuingCardholder>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !email.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
-        if !phone_number.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(phone_number) = &params.phone_number {
             query_args.push(("phone_number".to_string(), phone_number.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/issuing/cardholders?{}", query_);
 
-        let mut resp: crate::types::GetIssuingCardholdersResponse =
-            self.client.get(&url, None).await?;
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::IssuingCardholder| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_cardholders_with` and pass the query parameters as a `IssuingGetCardholdersParams` instead.
+     */
+    #[deprecated(note = "use `get_all_cardholders_with` instead")]
+    pub async fn get_all_cardholders(
+        &self,
+        created: &str,
+        email: &str,
+        phone_number: &str,
+        status: crate::types::IssuingCardholderStatus,
+        type_: crate::types::AccountHolderType,
+    ) -> Result<Vec<crate::types::IssuingCardholder>> {
+        let params = IssuingGetCardholdersParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+            phone_number: Some(phone_number.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_cardholders_with(&params).await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cardholders` endpoint.
+     *
+     * As opposed to `get_cardholders`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of Issuing <code>Cardholder</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_cardholders_stream(
+        &self,
+        params: &IssuingGetCardholdersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssuingCardholder> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(email) = &params.email {
+            query_args.push(("email".to_string(), email.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        if let Some(phone_number) = &params.phone_number {
+            query_args.push(("phone_number".to_string(), phone_number.to_string()));
+        }
+        if let Some(status) = &params.status {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/issuing/cardholders?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::IssuingCardholder| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/issuing/cardholders` endpoint.
      *
      * <p>Creates a new Issuing <code>Cardholder</code> object that can be issued cards.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_cardholder(&self) -> Result<crate::types::IssuingCardholder> {
+    pub async fn post_cardholder<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::IssuingCardholder> {
         let url = "/v1/issuing/cardholders".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -389,17 +574,25 @@ impl Issuing {
      * **Parameters:**
      *
      * * `cardholder: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_cardholders_cardholder(
+    pub async fn post_cardholders_cardholder<B: serde::Serialize>(
         &self,
         cardholder: &str,
+        body: &B,
     ) -> Result<crate::types::IssuingCardholder> {
         let url = format!(
             "/v1/issuing/cardholders/{}",
             crate::progenitor_support::encode_path(cardholder),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -409,57 +602,46 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `cardholder: &str` -- Only return cards belonging to the Cardholder with the provided ID.
-     * * `created: &str` -- Only return cards that were issued during the given date interval.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `exp_month: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
-     * * `exp_year: i64` -- Time at which the account was connected. Measured in seconds since the Unix epoch.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `last_4: &str` -- Only return cards that have the given last four digits.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `status: crate::types::IssuingCardStatus` -- Only return cards that have the given status. One of `active`, `inactive`, or `canceled`.
-     * * `type_: crate::types::IssuingCardType` -- Only return cards that have the given type. One of `virtual` or `physical`.
+     * * `params: &IssuingGetCardsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_cards(
+    pub async fn get_cards_with(
         &self,
-        cardholder: &str,
-        _created: &str,
-        ending_before: &str,
-        exp_month: i64,
-        exp_year: i64,
-        last_4: &str,
-        limit: i64,
-        starting_after: &str,
-        status: crate::types::IssuingCardStatus,
-        type_: crate::types::IssuingCardType,
+        params: &IssuingGetCardsParams,
     ) -> Result<Vec<crate::types::IssuingCard>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cardholder.is_empty() {
+        if let Some(cardholder) = &params.cardholder {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if exp_month > 0 {
+        if let Some(exp_month) = &params.exp_month {
             query_args.push(("exp_month".to_string(), exp_month.to_string()));
         }
-        if exp_year > 0 {
+        if let Some(exp_year) = &params.exp_year {
             query_args.push(("exp_year".to_string(), exp_year.to_string()));
         }
-        if !last_4.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(last_4) = &params.last_4 {
             query_args.push(("last4".to_string(), last_4.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -471,92 +653,202 @@ impl Issuing {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_cards_with` and pass the query parameters as a `IssuingGetCardsParams` instead.
+     */
+    #[deprecated(note = "use `get_cards_with` instead")]
+    pub async fn get_cards(
+        &self,
+        cardholder: &str,
+        created: &str,
+        ending_before: &str,
+        exp_month: i64,
+        exp_year: i64,
+        last_4: &str,
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::IssuingCardStatus,
+        type_: crate::types::IssuingCardType,
+    ) -> Result<Vec<crate::types::IssuingCard>> {
+        let params = IssuingGetCardsParams {
+            cardholder: Some(cardholder.to_string()).filter(|s| !s.is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            exp_month: Some(exp_month).filter(|v| *v > 0),
+            exp_year: Some(exp_year).filter(|v| *v > 0),
+            last_4: Some(last_4.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_cards_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/issuing/cards` endpoint.
      *
      * As opposed to `get_cards`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of Issuing <code>Card</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IssuingGetCardsParams` -- The query parameters, only the ones that are set are sent.
+     */
+    pub async fn get_all_cards_with(
+        &self,
+        params: &IssuingGetCardsParams,
+    ) -> Result<Vec<crate::types::IssuingCard>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(cardholder) = &params.cardholder {
+            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(exp_month) = &params.exp_month {
+            query_args.push(("exp_month".to_string(), exp_month.to_string()));
+        }
+        if let Some(exp_year) = &params.exp_year {
+            query_args.push(("exp_year".to_string(), exp_year.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(last_4) = &params.last_4 {
+            query_args.push(("last4".to_string(), last_4.to_string()));
+        }
+        if let Some(status) = &params.status {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/issuing/cards?{}", query_);
+
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::IssuingCard| Some(item.id.clone()),
+        )
+        .await
+    }
+
+    /**
+     * Deprecated: use `get_all_cards_with` and pass the query parameters as a `IssuingGetCardsParams` instead.
      */
+    #[deprecated(note = "use `get_all_cards_with` instead")]
     pub async fn get_all_cards(
         &self,
         cardholder: &str,
-        _created: &str,
+        created: &str,
         exp_month: i64,
         exp_year: i64,
         last_4: &str,
         status: crate::types::IssuingCardStatus,
         type_: crate::types::IssuingCardType,
     ) -> Result<Vec<crate::types::IssuingCard>> {
+        let params = IssuingGetCardsParams {
+            cardholder: Some(cardholder.to_string()).filter(|s| !s.is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            exp_month: Some(exp_month).filter(|v| *v > 0),
+            exp_year: Some(exp_year).filter(|v| *v > 0),
+            last_4: Some(last_4.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_cards_with(&params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/v1/issuing/cards` endpoint.
+     *
+     * As opposed to `get_cards`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of Issuing <code>Card</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_cards_stream(
+        &self,
+        params: &IssuingGetCardsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssuingCard> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cardholder.is_empty() {
+        if let Some(cardholder) = &params.cardholder {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
-        if exp_month > 0 {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(exp_month) = &params.exp_month {
             query_args.push(("exp_month".to_string(), exp_month.to_string()));
         }
-        if exp_year > 0 {
+        if let Some(exp_year) = &params.exp_year {
             query_args.push(("exp_year".to_string(), exp_year.to_string()));
         }
-        if !last_4.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(last_4) = &params.last_4 {
             query_args.push(("last4".to_string(), last_4.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/issuing/cards?{}", query_);
 
-        let mut resp: crate::types::GetIssuingCardsResponse = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
-
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::IssuingCard| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/issuing/cards` endpoint.
      *
      * <p>Creates an Issuing <code>Card</code> object.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_card(&self) -> Result<crate::types::IssuingCard> {
+    pub async fn post_card<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::IssuingCard> {
         let url = "/v1/issuing/cards".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -586,14 +878,25 @@ impl Issuing {
      * **Parameters:**
      *
      * * `card: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_cards_card(&self, card: &str) -> Result<crate::types::IssuingCard> {
+    pub async fn post_cards_card<B: serde::Serialize>(
+        &self,
+        card: &str,
+        body: &B,
+    ) -> Result<crate::types::IssuingCard> {
         let url = format!(
             "/v1/issuing/cards/{}",
             crate::progenitor_support::encode_path(card),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -603,37 +906,34 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `created: &str` -- Select Issuing disputes that were created during the given date interval.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `status: crate::types::IssuingDisputeStatus` -- Select Issuing disputes with the given status.
-     * * `transaction: &str` -- Select the Issuing dispute for the given transaction.
+     * * `params: &IssuingGetDisputesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_disputes(
+    pub async fn get_disputes_with(
         &self,
-        _created: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
-        status: crate::types::IssuingDisputeStatus,
-        transaction: &str,
+        params: &IssuingGetDisputesParams,
     ) -> Result<Vec<crate::types::IssuingDispute>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !transaction.is_empty() {
+        if let Some(transaction) = &params.transaction {
             query_args.push(("transaction".to_string(), transaction.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -645,76 +945,162 @@ impl Issuing {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_disputes_with` and pass the query parameters as a `IssuingGetDisputesParams` instead.
+     */
+    #[deprecated(note = "use `get_disputes_with` instead")]
+    pub async fn get_disputes(
+        &self,
+        created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        status: crate::types::IssuingDisputeStatus,
+        transaction: &str,
+    ) -> Result<Vec<crate::types::IssuingDispute>> {
+        let params = IssuingGetDisputesParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            transaction: Some(transaction.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_disputes_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/issuing/disputes` endpoint.
      *
      * As opposed to `get_disputes`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of Issuing <code>Dispute</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IssuingGetDisputesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_disputes(
+    pub async fn get_all_disputes_with(
         &self,
-        _created: &str,
-        status: crate::types::IssuingDisputeStatus,
-        transaction: &str,
+        params: &IssuingGetDisputesParams,
     ) -> Result<Vec<crate::types::IssuingDispute>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !status.to_string().is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !transaction.is_empty() {
+        if let Some(transaction) = &params.transaction {
             query_args.push(("transaction".to_string(), transaction.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/issuing/disputes?{}", query_);
 
-        let mut resp: crate::types::IssuingDisputeList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::IssuingDispute| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_disputes_with` and pass the query parameters as a `IssuingGetDisputesParams` instead.
+     */
+    #[deprecated(note = "use `get_all_disputes_with` instead")]
+    pub async fn get_all_disputes(
+        &self,
+        created: &str,
+        status: crate::types::IssuingDisputeStatus,
+        transaction: &str,
+    ) -> Result<Vec<crate::types::IssuingDispute>> {
+        let params = IssuingGetDisputesParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            transaction: Some(transaction.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_disputes_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/issuing/disputes` endpoint.
+     *
+     * As opposed to `get_disputes`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of Issuing <code>Dispute</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_disputes_stream(
+        &self,
+        params: &IssuingGetDisputesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssuingDispute> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        if let Some(status) = &params.status {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if let Some(transaction) = &params.transaction {
+            query_args.push(("transaction".to_string(), transaction.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/issuing/disputes?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::IssuingDispute| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/issuing/disputes` endpoint.
      *
      * <p>Creates an Issuing <code>Dispute</code> object. Individual pieces of evidence within the <code>evidence</code> object are optional at this point. Stripe only validates that required evidence is present during submission. Refer to <a href="/docs/issuing/purchases/disputes#dispute-reasons-and-evidence">Dispute reasons and evidence</a> for more details about evidence requirements.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_dispute(&self) -> Result<crate::types::IssuingDispute> {
+    pub async fn post_dispute<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::IssuingDispute> {
         let url = "/v1/issuing/disputes".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -747,17 +1133,25 @@ impl Issuing {
      * **Parameters:**
      *
      * * `dispute: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_disputes_dispute(
+    pub async fn post_disputes_dispute<B: serde::Serialize>(
         &self,
         dispute: &str,
+        body: &B,
     ) -> Result<crate::types::IssuingDispute> {
         let url = format!(
             "/v1/issuing/disputes/{}",
             crate::progenitor_support::encode_path(dispute),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -768,17 +1162,25 @@ impl Issuing {
      * **Parameters:**
      *
      * * `dispute: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_disputes_dispute_submit(
+    pub async fn post_disputes_dispute_submit<B: serde::Serialize>(
         &self,
         dispute: &str,
+        body: &B,
     ) -> Result<crate::types::IssuingDispute> {
         let url = format!(
             "/v1/issuing/disputes/{}/submit",
             crate::progenitor_support::encode_path(dispute),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -788,27 +1190,28 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `created: &str` -- Only return issuing settlements that were created during the given date interval.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &IssuingGetSettlementsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_settlements(
+    pub async fn get_settlements_with(
         &self,
-        _created: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &IssuingGetSettlementsParams,
     ) -> Result<Vec<crate::types::IssuingSettlement>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -820,56 +1223,119 @@ impl Issuing {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_settlements_with` and pass the query parameters as a `IssuingGetSettlementsParams` instead.
+     */
+    #[deprecated(note = "use `get_settlements_with` instead")]
+    pub async fn get_settlements(
+        &self,
+        created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::IssuingSettlement>> {
+        let params = IssuingGetSettlementsParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_settlements_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/issuing/settlements` endpoint.
      *
      * As opposed to `get_settlements`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of Issuing <code>Settlement</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IssuingGetSettlementsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_settlements(
+    pub async fn get_all_settlements_with(
         &self,
-        _created: &str,
+        params: &IssuingGetSettlementsParams,
     ) -> Result<Vec<crate::types::IssuingSettlement>> {
-        let url = "/v1/issuing/settlements".to_string();
-        let mut resp: crate::types::GetIssuingSettlementsResponse =
-            self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/issuing/settlements?{}", query_);
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::IssuingSettlement| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_settlements_with` and pass the query parameters as a `IssuingGetSettlementsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_settlements_with` instead")]
+    pub async fn get_all_settlements(
+        &self,
+        created: &str,
+    ) -> Result<Vec<crate::types::IssuingSettlement>> {
+        let params = IssuingGetSettlementsParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            ..Default::default()
+        };
+        self.get_all_settlements_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/issuing/settlements` endpoint.
+     *
+     * As opposed to `get_settlements`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of Issuing <code>Settlement</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_settlements_stream(
+        &self,
+        params: &IssuingGetSettlementsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssuingSettlement> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/issuing/settlements?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::IssuingSettlement| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -902,17 +1368,25 @@ impl Issuing {
      * **Parameters:**
      *
      * * `settlement: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_settlements_settlement(
+    pub async fn post_settlements_settlement<B: serde::Serialize>(
         &self,
         settlement: &str,
+        body: &B,
     ) -> Result<crate::types::IssuingSettlement> {
         let url = format!(
             "/v1/issuing/settlements/{}",
             crate::progenitor_support::encode_path(settlement),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -922,42 +1396,37 @@ impl Issuing {
      *
      * **Parameters:**
      *
-     * * `card: &str` -- Only return transactions that belong to the given card.
-     * * `cardholder: &str` -- Only return transactions that belong to the given cardholder.
-     * * `created: &str` -- Only return transactions that were created during the given date interval.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `type_: crate::types::IssuingTransactionType` -- Only return transactions that have the given type. One of `capture` or `refund`.
+     * * `params: &IssuingGetTransactionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_transactions(
+    pub async fn get_transactions_with(
         &self,
-        card: &str,
-        cardholder: &str,
-        _created: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
-        type_: crate::types::IssuingTransactionType,
+        params: &IssuingGetTransactionsParams,
     ) -> Result<Vec<crate::types::IssuingTransaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !card.is_empty() {
+        if let Some(card) = &params.card {
             query_args.push(("card".to_string(), card.to_string()));
         }
-        if !cardholder.is_empty() {
+        if let Some(cardholder) = &params.cardholder {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -970,71 +1439,149 @@ impl Issuing {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_transactions_with` and pass the query parameters as a `IssuingGetTransactionsParams` instead.
+     */
+    #[deprecated(note = "use `get_transactions_with` instead")]
+    pub async fn get_transactions(
+        &self,
+        card: &str,
+        cardholder: &str,
+        created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        type_: crate::types::IssuingTransactionType,
+    ) -> Result<Vec<crate::types::IssuingTransaction>> {
+        let params = IssuingGetTransactionsParams {
+            card: Some(card.to_string()).filter(|s| !s.is_empty()),
+            cardholder: Some(cardholder.to_string()).filter(|s| !s.is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_transactions_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/issuing/transactions` endpoint.
      *
      * As opposed to `get_transactions`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of Issuing <code>Transaction</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &IssuingGetTransactionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_transactions(
+    pub async fn get_all_transactions_with(
         &self,
-        card: &str,
-        cardholder: &str,
-        _created: &str,
-        type_: crate::types::IssuingTransactionType,
+        params: &IssuingGetTransactionsParams,
     ) -> Result<Vec<crate::types::IssuingTransaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !card.is_empty() {
+        if let Some(card) = &params.card {
             query_args.push(("card".to_string(), card.to_string()));
         }
-        if !cardholder.is_empty() {
+        if let Some(cardholder) = &params.cardholder {
             query_args.push(("cardholder".to_string(), cardholder.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/issuing/transactions?{}", query_);
 
-        let mut resp: crate::types::GetIssuingTransactionsResponse =
-            self.client.get(&url, None).await?;
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::IssuingTransaction| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_transactions_with` and pass the query parameters as a `IssuingGetTransactionsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_transactions_with` instead")]
+    pub async fn get_all_transactions(
+        &self,
+        card: &str,
+        cardholder: &str,
+        created: &str,
+        type_: crate::types::IssuingTransactionType,
+    ) -> Result<Vec<crate::types::IssuingTransaction>> {
+        let params = IssuingGetTransactionsParams {
+            card: Some(card.to_string()).filter(|s| !s.is_empty()),
+            cardholder: Some(cardholder.to_string()).filter(|s| !s.is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_transactions_with(&params).await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/issuing/transactions` endpoint.
+     *
+     * As opposed to `get_transactions`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of Issuing <code>Transaction</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_transactions_stream(
+        &self,
+        params: &IssuingGetTransactionsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::IssuingTransaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(card) = &params.card {
+            query_args.push(("card".to_string(), card.to_string()));
+        }
+        if let Some(cardholder) = &params.cardholder {
+            query_args.push(("cardholder".to_string(), cardholder.to_string()));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/issuing/transactions?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::IssuingTransaction| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -1067,16 +1614,402 @@ impl Issuing {
      * **Parameters:**
      *
      * * `transaction: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_transactions_transaction(
+    pub async fn post_transactions_transaction<B: serde::Serialize>(
         &self,
         transaction: &str,
+        body: &B,
     ) -> Result<crate::types::IssuingTransaction> {
         let url = format!(
             "/v1/issuing/transactions/{}",
             crate::progenitor_support::encode_path(transaction),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Issuing::get_authorizations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuingGetAuthorizationsParams {
+    /// Only return authorizations that belong to the given card.
+    pub card: Option<String>,
+    /// Only return authorizations that belong to the given cardholder.
+    pub cardholder: Option<String>,
+    /// Only return authorizations that were created during the given date interval.
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Only return authorizations with the given status. One of `pending`, `closed`, or `reversed`.
+    pub status: Option<crate::types::IssuingAuthorizationStatus>,
+}
+
+impl IssuingGetAuthorizationsParams {
+    pub fn card<T: ToString>(mut self, card: T) -> Self {
+        self.card = Some(card.to_string());
+        self
+    }
+
+    pub fn cardholder<T: ToString>(mut self, cardholder: T) -> Self {
+        self.cardholder = Some(cardholder.to_string());
+        self
+    }
+
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::IssuingAuthorizationStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// The query parameters of `Issuing::get_cardholders_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuingGetCardholdersParams {
+    /// Only return cardholders that were created during the given date interval.
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// Only return cardholders that have the given email address.
+    pub email: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// Only return cardholders that have the given phone number.
+    pub phone_number: Option<String>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Only return cardholders that have the given status. One of `active`, `inactive`, or `blocked`.
+    pub status: Option<crate::types::IssuingCardholderStatus>,
+    /// Type of entity that holds the account. This can be either `individual` or `company`.
+    pub type_: Option<crate::types::AccountHolderType>,
+}
+
+impl IssuingGetCardholdersParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn email<T: ToString>(mut self, email: T) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn phone_number<T: ToString>(mut self, phone_number: T) -> Self {
+        self.phone_number = Some(phone_number.to_string());
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::IssuingCardholderStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::AccountHolderType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Issuing::get_cards_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuingGetCardsParams {
+    /// Only return cards belonging to the Cardholder with the provided ID.
+    pub cardholder: Option<String>,
+    /// Only return cards that were issued during the given date interval.
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Time at which the account was connected. Measured in seconds since the Unix epoch.
+    pub exp_month: Option<i64>,
+    /// Time at which the account was connected. Measured in seconds since the Unix epoch.
+    pub exp_year: Option<i64>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// Only return cards that have the given last four digits.
+    pub last_4: Option<String>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Only return cards that have the given status. One of `active`, `inactive`, or `canceled`.
+    pub status: Option<crate::types::IssuingCardStatus>,
+    /// Only return cards that have the given type. One of `virtual` or `physical`.
+    pub type_: Option<crate::types::IssuingCardType>,
+}
+
+impl IssuingGetCardsParams {
+    pub fn cardholder<T: ToString>(mut self, cardholder: T) -> Self {
+        self.cardholder = Some(cardholder.to_string());
+        self
+    }
+
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn exp_month(mut self, exp_month: i64) -> Self {
+        self.exp_month = Some(exp_month);
+        self
+    }
+
+    pub fn exp_year(mut self, exp_year: i64) -> Self {
+        self.exp_year = Some(exp_year);
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn last_4<T: ToString>(mut self, last_4: T) -> Self {
+        self.last_4 = Some(last_4.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::IssuingCardStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::IssuingCardType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Issuing::get_disputes_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuingGetDisputesParams {
+    /// Select Issuing disputes that were created during the given date interval.
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Select Issuing disputes with the given status.
+    pub status: Option<crate::types::IssuingDisputeStatus>,
+    /// Select the Issuing dispute for the given transaction.
+    pub transaction: Option<String>,
+}
+
+impl IssuingGetDisputesParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::IssuingDisputeStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn transaction<T: ToString>(mut self, transaction: T) -> Self {
+        self.transaction = Some(transaction.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Issuing::get_settlements_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuingGetSettlementsParams {
+    /// Only return issuing settlements that were created during the given date interval.
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl IssuingGetSettlementsParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Issuing::get_transactions_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct IssuingGetTransactionsParams {
+    /// Only return transactions that belong to the given card.
+    pub card: Option<String>,
+    /// Only return transactions that belong to the given cardholder.
+    pub cardholder: Option<String>,
+    /// Only return transactions that were created during the given date interval.
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Only return transactions that have the given type. One of `capture` or `refund`.
+    pub type_: Option<crate::types::IssuingTransactionType>,
+}
+
+impl IssuingGetTransactionsParams {
+    pub fn card<T: ToString>(mut self, card: T) -> Self {
+        self.card = Some(card.to_string());
+        self
+    }
+
+    pub fn cardholder<T: ToString>(mut self, cardholder: T) -> Self {
+        self.cardholder = Some(cardholder.to_string());
+        self
+    }
+
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::IssuingTransactionType) -> Self {
+        self.type_ = Some(type_);
+        self
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Orders {
     pub client: Client,
@@ -19,45 +17,47 @@ impl Orders {
      *
      * **Parameters:**
      *
-     * * `created: &str` -- Date this order was created.
-     * * `customer: &str` -- Only return orders for the given customer.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `ids: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `status: &str` -- Only return orders that have the given status. One of `created`, `paid`, `fulfilled`, or `refunded`.
-     * * `status_transitions: &str` -- Filter orders based on when they were paid, fulfilled, canceled, or returned.
-     * * `upstream_ids: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `params: &OrdersGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        _created: &str,
-        customer: &str,
-        ending_before: &str,
-        _ids: &[String],
-        limit: i64,
-        starting_after: &str,
-        status: &str,
         _status_transitions: &str,
-        _upstream_ids: &[String],
+        params: &OrdersGetPageParams,
     ) -> Result<Vec<crate::types::Order>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(ids) = &params.ids {
+            for v in ids {
+                query_args.push(("ids[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
+        if let Some(upstream_ids) = &params.upstream_ids {
+            for v in upstream_ids {
+                query_args.push(("upstream_ids[]".to_string(), v.to_string()));
+            }
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/orders?{}", query_);
 
@@ -67,80 +67,195 @@ impl Orders {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `OrdersGetPageParams` instead.
+     *
+     * `status_transitions` is not sent.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        created: &str,
+        customer: &str,
+        ending_before: &str,
+        ids: &[String],
+        limit: i64,
+        starting_after: &str,
+        status: &str,
+        _status_transitions: &str,
+        upstream_ids: &[String],
+    ) -> Result<Vec<crate::types::Order>> {
+        let params = OrdersGetPageParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            upstream_ids: Some(upstream_ids.to_vec()).filter(|v| !v.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(_status_transitions, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/orders` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of your orders. The orders are returned sorted by creation date, with the most recently created orders appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &OrdersGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        _created: &str,
-        customer: &str,
-        _ids: &[String],
-        status: &str,
         _status_transitions: &str,
-        _upstream_ids: &[String],
+        params: &OrdersGetPageParams,
     ) -> Result<Vec<crate::types::Order>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(ids) = &params.ids {
+            for v in ids {
+                query_args.push(("ids[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
+        if let Some(upstream_ids) = &params.upstream_ids {
+            for v in upstream_ids {
+                query_args.push(("upstream_ids[]".to_string(), v.to_string()));
+            }
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/orders?{}", query_);
 
-        let mut resp: crate::types::OrdersLegacyResourceOrderList =
-            self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Order| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `OrdersGetPageParams` instead.
+     *
+     * `status_transitions` is not sent.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        created: &str,
+        customer: &str,
+        ids: &[String],
+        status: &str,
+        _status_transitions: &str,
+        upstream_ids: &[String],
+    ) -> Result<Vec<crate::types::Order>> {
+        let params = OrdersGetPageParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            upstream_ids: Some(upstream_ids.to_vec()).filter(|v| !v.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(_status_transitions, &params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/orders` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of your orders. The orders are returned sorted by creation date, with the most recently created orders appearing first.</p>
+     */
+    pub fn get_stream(
+        &self,
+        _status_transitions: &str,
+        params: &OrdersGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Order> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(ids) = &params.ids {
+            for v in ids {
+                query_args.push(("ids[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(status) = &params.status {
+            query_args.push(("status".to_string(), status.to_string()));
         }
+        if let Some(upstream_ids) = &params.upstream_ids {
+            for v in upstream_ids {
+                query_args.push(("upstream_ids[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/orders?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Order| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/orders` endpoint.
      *
      * <p>Creates a new order object.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::Order> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::Order> {
         let url = "/v1/orders".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -167,11 +282,22 @@ impl Orders {
      * **Parameters:**
      *
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_orders(&self, id: &str) -> Result<crate::types::Order> {
+    pub async fn post_orders<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> Result<crate::types::Order> {
         let url = format!("/v1/orders/{}", crate::progenitor_support::encode_path(id),);
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -182,14 +308,25 @@ impl Orders {
      * **Parameters:**
      *
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_pay(&self, id: &str) -> Result<crate::types::Order> {
+    pub async fn post_pay<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> Result<crate::types::Order> {
         let url = format!(
             "/v1/orders/{}/pay",
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -200,13 +337,94 @@ impl Orders {
      * **Parameters:**
      *
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_return(&self, id: &str) -> Result<crate::types::OrderReturn> {
+    pub async fn post_return<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> Result<crate::types::OrderReturn> {
         let url = format!(
             "/v1/orders/{}/returns",
             crate::progenitor_support::encode_path(id),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Orders::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct OrdersGetPageParams {
+    /// Date this order was created.
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// Only return orders for the given customer.
+    pub customer: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub ids: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Only return orders that have the given status. One of `created`, `paid`, `fulfilled`, or `refunded`.
+    pub status: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub upstream_ids: Option<Vec<String>>,
+}
+
+impl OrdersGetPageParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn ids(mut self, ids: Vec<String>) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    pub fn upstream_ids(mut self, upstream_ids: Vec<String>) -> Self {
+        self.upstream_ids = Some(upstream_ids);
+        self
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct PaymentIntents {
     pub client: Client,
@@ -19,32 +17,31 @@ impl PaymentIntents {
      *
      * **Parameters:**
      *
-     * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
-     * * `customer: &str` -- Only return PaymentIntents for the customer specified by this customer ID.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &PaymentIntentsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        _created: &str,
-        customer: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &PaymentIntentsGetPageParams,
     ) -> Result<Vec<crate::types::PaymentIntent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -56,62 +53,129 @@ impl PaymentIntents {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `PaymentIntentsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        created: &str,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::PaymentIntent>> {
+        let params = PaymentIntentsGetPageParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/payment_intents` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of PaymentIntents.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &PaymentIntentsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        _created: &str,
-        customer: &str,
+        params: &PaymentIntentsGetPageParams,
     ) -> Result<Vec<crate::types::PaymentIntent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/payment_intents?{}", query_);
 
-        let mut resp: crate::types::PaymentFlowsIntentList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::PaymentIntent| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `PaymentIntentsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        created: &str,
+        customer: &str,
+    ) -> Result<Vec<crate::types::PaymentIntent>> {
+        let params = PaymentIntentsGetPageParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/payment_intents` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of PaymentIntents.</p>
+     */
+    pub fn get_stream(
+        &self,
+        params: &PaymentIntentsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PaymentIntent> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/payment_intents?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::PaymentIntent| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -128,9 +192,18 @@ impl PaymentIntents {
      * available in the <a href="/docs/api/payment_intents/confirm">confirm API</a> when <code>confirm=true</code>
      * is supplied.</p>
      */
-    pub async fn post(&self) -> Result<crate::types::PaymentIntent> {
+    pub async fn post(
+        &self,
+        body: &crate::types::PostPaymentIntentsRequest,
+    ) -> Result<crate::types::PaymentIntent> {
         let url = "/v1/payment_intents".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -143,36 +216,56 @@ impl PaymentIntents {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `page: &str` -- A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
-     * * `query: &str` -- The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for payment intents](https://stripe.com/docs/search#query-fields-for-payment-intents).
+     * * `params: &PaymentIntentsGetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_search(
+    pub async fn get_search_with(
         &self,
-        limit: i64,
-        page: &str,
-        query: &str,
-    ) -> Result<Vec<crate::types::Charge>> {
+        params: &PaymentIntentsGetSearchParams,
+    ) -> Result<Vec<crate::types::PaymentIntent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !page.is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/payment_intents/search?{}", query_);
 
-        let resp: crate::types::SearchResult = self.client.get(&url, None).await?;
+        let resp: crate::types::GetPaymentIntentsSearchResponseResult =
+            self.client.get(&url, None).await?;
 
         // Return our response data.
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_search_with` and pass the query parameters as a `PaymentIntentsGetSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_search_with` instead")]
+    pub async fn get_search(
+        &self,
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> Result<Vec<crate::types::PaymentIntent>> {
+        let params = PaymentIntentsGetSearchParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            page: Some(page.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_search_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/payment_intents/search` endpoint.
      *
@@ -182,52 +275,84 @@ impl PaymentIntents {
      * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &PaymentIntentsGetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Charge>> {
+    pub async fn get_all_search_with(
+        &self,
+        params: &PaymentIntentsGetSearchParams,
+    ) -> Result<Vec<crate::types::PaymentIntent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !query.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/payment_intents/search?{}", query_);
 
-        let mut resp: crate::types::SearchResult = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::search(params.page.as_deref(), params.limit),
+            |item: &crate::types::PaymentIntent| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_search_with` and pass the query parameters as a `PaymentIntentsGetSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_all_search_with` instead")]
+    pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::PaymentIntent>> {
+        let params = PaymentIntentsGetSearchParams {
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_search_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/payment_intents/search` endpoint.
+     *
+     * As opposed to `get_search`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Search for PaymentIntents you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_search_stream(
+        &self,
+        params: &PaymentIntentsGetSearchParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PaymentIntent> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        if let Some(query) = &params.query {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/payment_intents/search?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::search(params.page.as_deref(), params.limit)
+                .resume(start),
+            max_items,
+            |item: &crate::types::PaymentIntent| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -241,17 +366,16 @@ impl PaymentIntents {
      *
      * **Parameters:**
      *
-     * * `client_secret: &str` -- The client secret of the PaymentIntent. Required if a publishable key is used to retrieve the source.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `intent: &str` -- The account's country.
+     * * `params: &PaymentIntentsGetIntentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_intent(
+    pub async fn get_intent_with(
         &self,
-        client_secret: &str,
         intent: &str,
+        params: &PaymentIntentsGetIntentParams,
     ) -> Result<crate::types::PaymentIntent> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_secret.is_empty() {
+        if let Some(client_secret) = &params.client_secret {
             query_args.push(("client_secret".to_string(), client_secret.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -264,6 +388,21 @@ impl PaymentIntents {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_intent_with` and pass the query parameters as a `PaymentIntentsGetIntentParams` instead.
+     */
+    #[deprecated(note = "use `get_intent_with` instead")]
+    pub async fn get_intent(
+        &self,
+        client_secret: &str,
+        intent: &str,
+    ) -> Result<crate::types::PaymentIntent> {
+        let params = PaymentIntentsGetIntentParams {
+            client_secret: Some(client_secret.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_intent_with(intent, &params).await
+    }
+
     /**
      * This function performs a `POST` to the `/v1/payment_intents/{intent}` endpoint.
      *
@@ -278,14 +417,25 @@ impl PaymentIntents {
      * **Parameters:**
      *
      * * `intent: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_intent(&self, intent: &str) -> Result<crate::types::PaymentIntent> {
+    pub async fn post_intent<B: serde::Serialize>(
+        &self,
+        intent: &str,
+        body: &B,
+    ) -> Result<crate::types::PaymentIntent> {
         let url = format!(
             "/v1/payment_intents/{}",
             crate::progenitor_support::encode_path(intent),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -300,14 +450,25 @@ impl PaymentIntents {
      * **Parameters:**
      *
      * * `intent: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_intent_cancel(&self, intent: &str) -> Result<crate::types::PaymentIntent> {
+    pub async fn post_intent_cancel<B: serde::Serialize>(
+        &self,
+        intent: &str,
+        body: &B,
+    ) -> Result<crate::types::PaymentIntent> {
         let url = format!(
             "/v1/payment_intents/{}/cancel",
             crate::progenitor_support::encode_path(intent),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -322,14 +483,25 @@ impl PaymentIntents {
      * **Parameters:**
      *
      * * `intent: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_intent_capture(&self, intent: &str) -> Result<crate::types::PaymentIntent> {
+    pub async fn post_intent_capture<B: serde::Serialize>(
+        &self,
+        intent: &str,
+        body: &B,
+    ) -> Result<crate::types::PaymentIntent> {
         let url = format!(
             "/v1/payment_intents/{}/capture",
             crate::progenitor_support::encode_path(intent),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -364,14 +536,25 @@ impl PaymentIntents {
      * **Parameters:**
      *
      * * `intent: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_intent_confirm(&self, intent: &str) -> Result<crate::types::PaymentIntent> {
+    pub async fn post_intent_confirm<B: serde::Serialize>(
+        &self,
+        intent: &str,
+        body: &B,
+    ) -> Result<crate::types::PaymentIntent> {
         let url = format!(
             "/v1/payment_intents/{}/confirm",
             crate::progenitor_support::encode_path(intent),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -382,16 +565,122 @@ impl PaymentIntents {
      * **Parameters:**
      *
      * * `intent: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_intent_verify_microdeposit(
+    pub async fn post_intent_verify_microdeposit<B: serde::Serialize>(
         &self,
         intent: &str,
+        body: &B,
     ) -> Result<crate::types::PaymentIntent> {
         let url = format!(
             "/v1/payment_intents/{}/verify_microdeposits",
             crate::progenitor_support::encode_path(intent),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `PaymentIntents::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PaymentIntentsGetPageParams {
+    /// A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// Only return PaymentIntents for the customer specified by this customer ID.
+    pub customer: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl PaymentIntentsGetPageParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `PaymentIntents::get_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PaymentIntentsGetSearchParams {
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
+    pub page: Option<String>,
+    /// The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for payment intents](https://stripe.com/docs/search#query-fields-for-payment-intents).
+    pub query: Option<String>,
+}
+
+impl PaymentIntentsGetSearchParams {
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page<T: ToString>(mut self, page: T) -> Self {
+        self.page = Some(page.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+}
+
+/// The query parameters of `PaymentIntents::get_intent_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PaymentIntentsGetIntentParams {
+    /// The client secret of the PaymentIntent. Required if a publishable key is used to retrieve the source.
+    pub client_secret: Option<String>,
+}
+
+impl PaymentIntentsGetIntentParams {
+    pub fn client_secret<T: ToString>(mut self, client_secret: T) -> Self {
+        self.client_secret = Some(client_secret.to_string());
+        self
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Payouts {
     pub client: Client,
@@ -19,39 +17,37 @@ impl Payouts {
      *
      * **Parameters:**
      *
-     * * `arrival_date: &str`
-     * * `created: &str`
-     * * `destination: &str` -- The ID of an external account - only return payouts sent to this external account.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `status: &str` -- Only return payouts that have the given status: `pending`, `paid`, `failed`, or `canceled`.
+     * * `params: &PayoutsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        _arrival_date: &str,
-        _created: &str,
-        destination: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
-        status: &str,
+        params: &PayoutsGetPageParams,
     ) -> Result<Vec<crate::types::Payout>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !destination.is_empty() {
+        if let Some(arrival_date) = &params.arrival_date {
+            query_args.extend(arrival_date.to_query("arrival_date"));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(destination) = &params.destination {
             query_args.push(("destination".to_string(), destination.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -63,67 +59,149 @@ impl Payouts {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `PayoutsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        arrival_date: &str,
+        created: &str,
+        destination: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        status: &str,
+    ) -> Result<Vec<crate::types::Payout>> {
+        let params = PayoutsGetPageParams {
+            arrival_date: crate::pagination::RangeQuery::parse(arrival_date),
+            created: crate::pagination::RangeQuery::parse(created),
+            destination: Some(destination.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/payouts` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of existing payouts sent to third-party bank accounts or that Stripe has sent you. The payouts are returned in sorted order, with the most recently created payouts appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &PayoutsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        _arrival_date: &str,
-        _created: &str,
-        destination: &str,
-        status: &str,
+        params: &PayoutsGetPageParams,
     ) -> Result<Vec<crate::types::Payout>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !destination.is_empty() {
+        if let Some(arrival_date) = &params.arrival_date {
+            query_args.extend(arrival_date.to_query("arrival_date"));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(destination) = &params.destination {
             query_args.push(("destination".to_string(), destination.to_string()));
         }
-        if !status.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/payouts?{}", query_);
 
-        let mut resp: crate::types::PayoutList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Payout| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `PayoutsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        arrival_date: &str,
+        created: &str,
+        destination: &str,
+        status: &str,
+    ) -> Result<Vec<crate::types::Payout>> {
+        let params = PayoutsGetPageParams {
+            arrival_date: crate::pagination::RangeQuery::parse(arrival_date),
+            created: crate::pagination::RangeQuery::parse(created),
+            destination: Some(destination.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/payouts` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of existing payouts sent to third-party bank accounts or that Stripe has sent you. The payouts are returned in sorted order, with the most recently created payouts appearing first.</p>
+     */
+    pub fn get_stream(
+        &self,
+        params: &PayoutsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Payout> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(arrival_date) = &params.arrival_date {
+            query_args.extend(arrival_date.to_query("arrival_date"));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(destination) = &params.destination {
+            query_args.push(("destination".to_string(), destination.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        if let Some(status) = &params.status {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/payouts?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Payout| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -134,10 +212,20 @@ impl Payouts {
      * <p>If your API key is in test mode, money won’t actually be sent, though everything else will occur as if in live mode.</p>
      *
      * <p>If you are creating a manual payout on a Stripe account that uses multiple payment source types, you’ll need to specify the source type balance that the payout should draw from. The <a href="#balance_object">balance object</a> details available and pending amounts by source type.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::Payout> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::Payout> {
         let url = "/v1/payouts".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -167,14 +255,25 @@ impl Payouts {
      * **Parameters:**
      *
      * * `payout: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_payouts(&self, payout: &str) -> Result<crate::types::Payout> {
+    pub async fn post_payouts<B: serde::Serialize>(
+        &self,
+        payout: &str,
+        body: &B,
+    ) -> Result<crate::types::Payout> {
         let url = format!(
             "/v1/payouts/{}",
             crate::progenitor_support::encode_path(payout),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -185,14 +284,25 @@ impl Payouts {
      * **Parameters:**
      *
      * * `payout: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_cancel(&self, payout: &str) -> Result<crate::types::Payout> {
+    pub async fn post_cancel<B: serde::Serialize>(
+        &self,
+        payout: &str,
+        body: &B,
+    ) -> Result<crate::types::Payout> {
         let url = format!(
             "/v1/payouts/{}/cancel",
             crate::progenitor_support::encode_path(payout),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -205,13 +315,85 @@ impl Payouts {
      * **Parameters:**
      *
      * * `payout: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_reverse(&self, payout: &str) -> Result<crate::types::Payout> {
+    pub async fn post_reverse<B: serde::Serialize>(
+        &self,
+        payout: &str,
+        body: &B,
+    ) -> Result<crate::types::Payout> {
         let url = format!(
             "/v1/payouts/{}/reverse",
             crate::progenitor_support::encode_path(payout),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Payouts::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PayoutsGetPageParams {
+    pub arrival_date: Option<crate::pagination::RangeQuery>,
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// The ID of an external account - only return payouts sent to this external account.
+    pub destination: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Only return payouts that have the given status: `pending`, `paid`, `failed`, or `canceled`.
+    pub status: Option<String>,
+}
+
+impl PayoutsGetPageParams {
+    pub fn arrival_date(mut self, arrival_date: crate::pagination::RangeQuery) -> Self {
+        self.arrival_date = Some(arrival_date);
+        self
+    }
+
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn destination<T: ToString>(mut self, destination: T) -> Self {
+        self.destination = Some(destination.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn status<T: ToString>(mut self, status: T) -> Self {
+        self.status = Some(status.to_string());
+        self
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Prices {
     pub client: Client,
@@ -19,51 +17,46 @@ impl Prices {
      *
      * **Parameters:**
      *
-     * * `active: bool` -- Only return prices that are active or inactive (e.g., pass `false` to list all inactive prices).
-     * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
-     * * `currency: &str` -- Only return prices for the given currency.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `lookup_keys: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `product: &str` -- Only return prices for the given product.
-     * * `recurring: &str` -- Only return prices with these recurring fields.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `type_: crate::types::PriceType` -- One of `one_time` or `recurring` depending on whether the price is for a one-time purchase or a recurring (subscription) purchase.
+     * * `params: &PricesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        active: bool,
-        _created: &str,
-        currency: &str,
-        ending_before: &str,
-        limit: i64,
-        _lookup_keys: &[String],
-        product: &str,
         _recurring: &str,
-        starting_after: &str,
-        type_: crate::types::PriceType,
+        params: &PricesGetPageParams,
     ) -> Result<Vec<crate::types::PriceData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if active {
+        if let Some(active) = &params.active {
             query_args.push(("active".to_string(), active.to_string()));
         }
-        if !currency.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product.is_empty() {
+        if let Some(lookup_keys) = &params.lookup_keys {
+            for v in lookup_keys {
+                query_args.push(("lookup_keys[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(product) = &params.product {
             query_args.push(("product".to_string(), product.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -75,86 +68,201 @@ impl Prices {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `PricesGetPageParams` instead.
+     *
+     * `recurring` is not sent.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        active: bool,
+        created: &str,
+        currency: &str,
+        ending_before: &str,
+        limit: i64,
+        lookup_keys: &[String],
+        product: &str,
+        _recurring: &str,
+        starting_after: &str,
+        type_: crate::types::PriceType,
+    ) -> Result<Vec<crate::types::PriceData>> {
+        let params = PricesGetPageParams {
+            active: Some(active).filter(|v| *v),
+            created: crate::pagination::RangeQuery::parse(created),
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            lookup_keys: Some(lookup_keys.to_vec()).filter(|v| !v.is_empty()),
+            product: Some(product.to_string()).filter(|s| !s.is_empty()),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(_recurring, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/prices` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of your prices.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &PricesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
+    pub async fn get_all_with(
+        &self,
+        _recurring: &str,
+        params: &PricesGetPageParams,
+    ) -> Result<Vec<crate::types::PriceData>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(active) = &params.active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(currency) = &params.currency {
+            query_args.push(("currency".to_string(), currency.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(lookup_keys) = &params.lookup_keys {
+            for v in lookup_keys {
+                query_args.push(("lookup_keys[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(product) = &params.product {
+            query_args.push(("product".to_string(), product.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/prices?{}", query_);
+
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::PriceData| Some(item.id.clone()),
+        )
+        .await
+    }
+
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `PricesGetPageParams` instead.
+     *
+     * `recurring` is not sent.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
     pub async fn get_all(
         &self,
         active: bool,
-        _created: &str,
+        created: &str,
         currency: &str,
-        _lookup_keys: &[String],
+        lookup_keys: &[String],
         product: &str,
         _recurring: &str,
         type_: crate::types::PriceType,
     ) -> Result<Vec<crate::types::PriceData>> {
+        let params = PricesGetPageParams {
+            active: Some(active).filter(|v| *v),
+            created: crate::pagination::RangeQuery::parse(created),
+            currency: Some(currency.to_string()).filter(|s| !s.is_empty()),
+            lookup_keys: Some(lookup_keys.to_vec()).filter(|v| !v.is_empty()),
+            product: Some(product.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(_recurring, &params).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/v1/prices` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of your prices.</p>
+     */
+    pub fn get_stream(
+        &self,
+        _recurring: &str,
+        params: &PricesGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PriceData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if active {
+        if let Some(active) = &params.active {
             query_args.push(("active".to_string(), active.to_string()));
         }
-        if !currency.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(currency) = &params.currency {
             query_args.push(("currency".to_string(), currency.to_string()));
         }
-        if !product.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(lookup_keys) = &params.lookup_keys {
+            for v in lookup_keys {
+                query_args.push(("lookup_keys[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(product) = &params.product {
             query_args.push(("product".to_string(), product.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/prices?{}", query_);
 
-        let mut resp: crate::types::PriceList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
-        }
-
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::PriceData| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/prices` endpoint.
      *
      * <p>Creates a new price for an existing product. The price can be recurring or one-time.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::PriceData> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::PriceData> {
         let url = "/v1/prices".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -167,36 +275,55 @@ impl Prices {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `page: &str` -- A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
-     * * `query: &str` -- The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for prices](https://stripe.com/docs/search#query-fields-for-prices).
+     * * `params: &PricesGetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_search(
+    pub async fn get_search_with(
         &self,
-        limit: i64,
-        page: &str,
-        query: &str,
-    ) -> Result<Vec<crate::types::Charge>> {
+        params: &PricesGetSearchParams,
+    ) -> Result<Vec<crate::types::PriceData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !page.is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if !query.is_empty() {
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/prices/search?{}", query_);
 
-        let resp: crate::types::SearchResult = self.client.get(&url, None).await?;
+        let resp: crate::types::GetPricesSearchResponseResult = self.client.get(&url, None).await?;
 
         // Return our response data.
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_search_with` and pass the query parameters as a `PricesGetSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_search_with` instead")]
+    pub async fn get_search(
+        &self,
+        limit: i64,
+        page: &str,
+        query: &str,
+    ) -> Result<Vec<crate::types::PriceData>> {
+        let params = PricesGetSearchParams {
+            limit: Some(limit).filter(|v| *v > 0),
+            page: Some(page.to_string()).filter(|s| !s.is_empty()),
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_search_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/prices/search` endpoint.
      *
@@ -206,52 +333,84 @@ impl Prices {
      * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
      * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
      * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &PricesGetSearchParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::Charge>> {
+    pub async fn get_all_search_with(
+        &self,
+        params: &PricesGetSearchParams,
+    ) -> Result<Vec<crate::types::PriceData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !query.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(query) = &params.query {
             query_args.push(("query".to_string(), query.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/prices/search?{}", query_);
 
-        let mut resp: crate::types::SearchResult = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::search(params.page.as_deref(), params.limit),
+            |item: &crate::types::PriceData| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_search_with` and pass the query parameters as a `PricesGetSearchParams` instead.
+     */
+    #[deprecated(note = "use `get_all_search_with` instead")]
+    pub async fn get_all_search(&self, query: &str) -> Result<Vec<crate::types::PriceData>> {
+        let params = PricesGetSearchParams {
+            query: Some(query.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_search_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/prices/search` endpoint.
+     *
+     * As opposed to `get_search`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Search for prices you’ve previously created using Stripe’s <a href="/docs/search#search-query-language">Search Query Language</a>.
+     * Don’t use search in read-after-write flows where strict consistency is necessary. Under normal operating
+     * conditions, data is searchable in less than a minute. Occasionally, propagation of new or updated data can be up
+     * to an hour behind during outages. Search functionality is not available to merchants in India.</p>
+     */
+    pub fn get_search_stream(
+        &self,
+        params: &PricesGetSearchParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::PriceData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(query) = &params.query {
+            query_args.push(("query".to_string(), query.to_string()));
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/prices/search?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::search(params.page.as_deref(), params.limit)
+                .resume(start),
+            max_items,
+            |item: &crate::types::PriceData| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -281,13 +440,136 @@ impl Prices {
      * **Parameters:**
      *
      * * `price: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_prices(&self, price: &str) -> Result<crate::types::PriceData> {
+    pub async fn post_prices<B: serde::Serialize>(
+        &self,
+        price: &str,
+        body: &B,
+    ) -> Result<crate::types::PriceData> {
         let url = format!(
             "/v1/prices/{}",
             crate::progenitor_support::encode_path(price),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Prices::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PricesGetPageParams {
+    /// Only return prices that are active or inactive (e.g., pass `false` to list all inactive prices).
+    pub active: Option<bool>,
+    /// A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// Only return prices for the given currency.
+    pub currency: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub lookup_keys: Option<Vec<String>>,
+    /// Only return prices for the given product.
+    pub product: Option<String>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// One of `one_time` or `recurring` depending on whether the price is for a one-time purchase or a recurring (subscription) purchase.
+    pub type_: Option<crate::types::PriceType>,
+}
+
+impl PricesGetPageParams {
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn currency<T: ToString>(mut self, currency: T) -> Self {
+        self.currency = Some(currency.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn lookup_keys(mut self, lookup_keys: Vec<String>) -> Self {
+        self.lookup_keys = Some(lookup_keys);
+        self
+    }
+
+    pub fn product<T: ToString>(mut self, product: T) -> Self {
+        self.product = Some(product.to_string());
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::PriceType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Prices::get_search_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PricesGetSearchParams {
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for pagination across multiple pages of results. Don't include this parameter on the first call. Use the next_page value returned in a previous response to request subsequent results.
+    pub page: Option<String>,
+    /// The search query string. See [search query language](https://stripe.com/docs/search#search-query-language) and the list of supported [query fields for prices](https://stripe.com/docs/search#query-fields-for-prices).
+    pub query: Option<String>,
+}
+
+impl PricesGetSearchParams {
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page<T: ToString>(mut self, page: T) -> Self {
+        self.page = Some(page.to_string());
+        self
+    }
+
+    pub fn query<T: ToString>(mut self, query: T) -> Self {
+        self.query = Some(query.to_string());
+        self
     }
 }
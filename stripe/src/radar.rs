@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Radar {
     pub client: Client,
@@ -19,35 +17,31 @@ impl Radar {
      *
      * **Parameters:**
      *
-     * * `charge: &str` -- Only return early fraud warnings for the charge specified by this charge ID.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `payment_intent: &str` -- Only return early fraud warnings for charges that were created by the PaymentIntent specified by this PaymentIntent ID.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &RadarGetEarlyFraudWarningsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_early_fraud_warnings(
+    pub async fn get_early_fraud_warnings_with(
         &self,
-        charge: &str,
-        ending_before: &str,
-        limit: i64,
-        payment_intent: &str,
-        starting_after: &str,
+        params: &RadarGetEarlyFraudWarningsParams,
     ) -> Result<Vec<crate::types::RadarEarlyFraudWarning>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !charge.is_empty() {
+        if let Some(charge) = &params.charge {
             query_args.push(("charge".to_string(), charge.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !payment_intent.is_empty() {
+        if let Some(payment_intent) = &params.payment_intent {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -59,66 +53,129 @@ impl Radar {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_early_fraud_warnings_with` and pass the query parameters as a `RadarGetEarlyFraudWarningsParams` instead.
+     */
+    #[deprecated(note = "use `get_early_fraud_warnings_with` instead")]
+    pub async fn get_early_fraud_warnings(
+        &self,
+        charge: &str,
+        ending_before: &str,
+        limit: i64,
+        payment_intent: &str,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::RadarEarlyFraudWarning>> {
+        let params = RadarGetEarlyFraudWarningsParams {
+            charge: Some(charge.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            payment_intent: Some(payment_intent.to_string()).filter(|s| !s.is_empty()),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_early_fraud_warnings_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/radar/early_fraud_warnings` endpoint.
      *
      * As opposed to `get_early_fraud_warnings`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of early fraud warnings.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &RadarGetEarlyFraudWarningsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_early_fraud_warnings(
+    pub async fn get_all_early_fraud_warnings_with(
         &self,
-        charge: &str,
-        payment_intent: &str,
+        params: &RadarGetEarlyFraudWarningsParams,
     ) -> Result<Vec<crate::types::RadarEarlyFraudWarning>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !charge.is_empty() {
+        if let Some(charge) = &params.charge {
             query_args.push(("charge".to_string(), charge.to_string()));
         }
-        if !payment_intent.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(payment_intent) = &params.payment_intent {
             query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/radar/early_fraud_warnings?{}", query_);
 
-        let mut resp: crate::types::RadarEarlyFraudWarningList =
-            self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::RadarEarlyFraudWarning| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_early_fraud_warnings_with` and pass the query parameters as a `RadarGetEarlyFraudWarningsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_early_fraud_warnings_with` instead")]
+    pub async fn get_all_early_fraud_warnings(
+        &self,
+        charge: &str,
+        payment_intent: &str,
+    ) -> Result<Vec<crate::types::RadarEarlyFraudWarning>> {
+        let params = RadarGetEarlyFraudWarningsParams {
+            charge: Some(charge.to_string()).filter(|s| !s.is_empty()),
+            payment_intent: Some(payment_intent.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_early_fraud_warnings_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/radar/early_fraud_warnings` endpoint.
+     *
+     * As opposed to `get_early_fraud_warnings`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of early fraud warnings.</p>
+     */
+    pub fn get_early_fraud_warnings_stream(
+        &self,
+        params: &RadarGetEarlyFraudWarningsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RadarEarlyFraudWarning> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(charge) = &params.charge {
+            query_args.push(("charge".to_string(), charge.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        if let Some(payment_intent) = &params.payment_intent {
+            query_args.push(("payment_intent".to_string(), payment_intent.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/radar/early_fraud_warnings?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::RadarEarlyFraudWarning| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -152,37 +209,34 @@ impl Radar {
      *
      * **Parameters:**
      *
-     * * `created: &str`
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `value: &str` -- Return items belonging to the parent list whose value matches the specified value (using an "is like" match).
-     * * `value_list: &str` -- Identifier for the parent value list this item belongs to.
+     * * `params: &RadarGetValueListItemsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_value_list_items(
+    pub async fn get_value_list_items_with(
         &self,
-        _created: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
-        value: &str,
-        value_list: &str,
+        params: &RadarGetValueListItemsParams,
     ) -> Result<Vec<crate::types::RadarListItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !value.is_empty() {
+        if let Some(value) = &params.value {
             query_args.push(("value".to_string(), value.to_string()));
         }
-        if !value_list.is_empty() {
+        if let Some(value_list) = &params.value_list {
             query_args.push(("value_list".to_string(), value_list.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -194,76 +248,162 @@ impl Radar {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_value_list_items_with` and pass the query parameters as a `RadarGetValueListItemsParams` instead.
+     */
+    #[deprecated(note = "use `get_value_list_items_with` instead")]
+    pub async fn get_value_list_items(
+        &self,
+        created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        value: &str,
+        value_list: &str,
+    ) -> Result<Vec<crate::types::RadarListItem>> {
+        let params = RadarGetValueListItemsParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            value: Some(value.to_string()).filter(|s| !s.is_empty()),
+            value_list: Some(value_list.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_value_list_items_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/radar/value_list_items` endpoint.
      *
      * As opposed to `get_value_list_items`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of <code>ValueListItem</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &RadarGetValueListItemsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_value_list_all_items(
+    pub async fn get_all_value_list_all_items_with(
         &self,
-        _created: &str,
-        value: &str,
-        value_list: &str,
+        params: &RadarGetValueListItemsParams,
     ) -> Result<Vec<crate::types::RadarListItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !value.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(value) = &params.value {
             query_args.push(("value".to_string(), value.to_string()));
         }
-        if !value_list.is_empty() {
+        if let Some(value_list) = &params.value_list {
             query_args.push(("value_list".to_string(), value_list.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/radar/value_list_items?{}", query_);
 
-        let mut resp: crate::types::ListItems = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::RadarListItem| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_value_list_all_items_with` and pass the query parameters as a `RadarGetValueListItemsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_value_list_all_items_with` instead")]
+    pub async fn get_all_value_list_all_items(
+        &self,
+        created: &str,
+        value: &str,
+        value_list: &str,
+    ) -> Result<Vec<crate::types::RadarListItem>> {
+        let params = RadarGetValueListItemsParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            value: Some(value.to_string()).filter(|s| !s.is_empty()),
+            value_list: Some(value_list.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_value_list_all_items_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/radar/value_list_items` endpoint.
+     *
+     * As opposed to `get_value_list_items`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of <code>ValueListItem</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_value_list_items_stream(
+        &self,
+        params: &RadarGetValueListItemsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RadarListItem> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(value) = &params.value {
+            query_args.push(("value".to_string(), value.to_string()));
+        }
+        if let Some(value_list) = &params.value_list {
+            query_args.push(("value_list".to_string(), value_list.to_string()));
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/radar/value_list_items?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::RadarListItem| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/radar/value_list_items` endpoint.
      *
      * <p>Creates a new <code>ValueListItem</code> object, which is added to the specified parent value list.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_value_list_item(&self) -> Result<crate::types::RadarListItem> {
+    pub async fn post_value_list_item<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::RadarListItem> {
         let url = "/v1/radar/value_list_items".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -316,37 +456,34 @@ impl Radar {
      *
      * **Parameters:**
      *
-     * * `alias: &str` -- The alias used to reference the value list when writing rules.
-     * * `contains: &str` -- A value contained within a value list - returns all value lists containing this value.
-     * * `created: &str`
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &RadarGetValueListsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_value_lists(
+    pub async fn get_value_lists_with(
         &self,
-        alias: &str,
-        contains: &str,
-        _created: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &RadarGetValueListsParams,
     ) -> Result<Vec<crate::types::RadarList>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !alias.is_empty() {
+        if let Some(alias) = &params.alias {
             query_args.push(("alias".to_string(), alias.to_string()));
         }
-        if !contains.is_empty() {
+        if let Some(contains) = &params.contains {
             query_args.push(("contains".to_string(), contains.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -358,77 +495,162 @@ impl Radar {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_value_lists_with` and pass the query parameters as a `RadarGetValueListsParams` instead.
+     */
+    #[deprecated(note = "use `get_value_lists_with` instead")]
+    pub async fn get_value_lists(
+        &self,
+        alias: &str,
+        contains: &str,
+        created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::RadarList>> {
+        let params = RadarGetValueListsParams {
+            alias: Some(alias.to_string()).filter(|s| !s.is_empty()),
+            contains: Some(contains.to_string()).filter(|s| !s.is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_value_lists_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/radar/value_lists` endpoint.
      *
      * As opposed to `get_value_lists`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of <code>ValueList</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &RadarGetValueListsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_value_lists(
+    pub async fn get_all_value_lists_with(
         &self,
-        alias: &str,
-        contains: &str,
-        _created: &str,
+        params: &RadarGetValueListsParams,
     ) -> Result<Vec<crate::types::RadarList>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !alias.is_empty() {
+        if let Some(alias) = &params.alias {
             query_args.push(("alias".to_string(), alias.to_string()));
         }
-        if !contains.is_empty() {
+        if let Some(contains) = &params.contains {
             query_args.push(("contains".to_string(), contains.to_string()));
         }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/radar/value_lists?{}", query_);
 
-        let mut resp: crate::types::GetRadarValueListsResponse =
-            self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::RadarList| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_value_lists_with` and pass the query parameters as a `RadarGetValueListsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_value_lists_with` instead")]
+    pub async fn get_all_value_lists(
+        &self,
+        alias: &str,
+        contains: &str,
+        created: &str,
+    ) -> Result<Vec<crate::types::RadarList>> {
+        let params = RadarGetValueListsParams {
+            alias: Some(alias.to_string()).filter(|s| !s.is_empty()),
+            contains: Some(contains.to_string()).filter(|s| !s.is_empty()),
+            created: crate::pagination::RangeQuery::parse(created),
+            ..Default::default()
+        };
+        self.get_all_value_lists_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/radar/value_lists` endpoint.
+     *
+     * As opposed to `get_value_lists`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of <code>ValueList</code> objects. The objects are sorted in descending order by creation date, with the most recently created object appearing first.</p>
+     */
+    pub fn get_value_lists_stream(
+        &self,
+        params: &RadarGetValueListsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::RadarList> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(alias) = &params.alias {
+            query_args.push(("alias".to_string(), alias.to_string()));
         }
+        if let Some(contains) = &params.contains {
+            query_args.push(("contains".to_string(), contains.to_string()));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/radar/value_lists?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::RadarList| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/radar/value_lists` endpoint.
      *
      * <p>Creates a new <code>ValueList</code> object, which can then be referenced in rules.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_value_list(&self) -> Result<crate::types::RadarList> {
+    pub async fn post_value_list<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::RadarList> {
         let url = "/v1/radar/value_lists".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -458,14 +680,25 @@ impl Radar {
      * **Parameters:**
      *
      * * `value_list: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_value_lists_list(&self, value_list: &str) -> Result<crate::types::RadarList> {
+    pub async fn post_value_lists_list<B: serde::Serialize>(
+        &self,
+        value_list: &str,
+        body: &B,
+    ) -> Result<crate::types::RadarList> {
         let url = format!(
             "/v1/radar/value_lists/{}",
             crate::progenitor_support::encode_path(value_list),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -489,3 +722,162 @@ impl Radar {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Radar::get_early_fraud_warnings_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RadarGetEarlyFraudWarningsParams {
+    /// Only return early fraud warnings for the charge specified by this charge ID.
+    pub charge: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// Only return early fraud warnings for charges that were created by the PaymentIntent specified by this PaymentIntent ID.
+    pub payment_intent: Option<String>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl RadarGetEarlyFraudWarningsParams {
+    pub fn charge<T: ToString>(mut self, charge: T) -> Self {
+        self.charge = Some(charge.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn payment_intent<T: ToString>(mut self, payment_intent: T) -> Self {
+        self.payment_intent = Some(payment_intent.to_string());
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Radar::get_value_list_items_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RadarGetValueListItemsParams {
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// Return items belonging to the parent list whose value matches the specified value (using an "is like" match).
+    pub value: Option<String>,
+    /// Identifier for the parent value list this item belongs to.
+    pub value_list: Option<String>,
+}
+
+impl RadarGetValueListItemsParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn value<T: ToString>(mut self, value: T) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    pub fn value_list<T: ToString>(mut self, value_list: T) -> Self {
+        self.value_list = Some(value_list.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Radar::get_value_lists_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RadarGetValueListsParams {
+    /// The alias used to reference the value list when writing rules.
+    pub alias: Option<String>,
+    /// A value contained within a value list - returns all value lists containing this value.
+    pub contains: Option<String>,
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl RadarGetValueListsParams {
+    pub fn alias<T: ToString>(mut self, alias: T) -> Self {
+        self.alias = Some(alias.to_string());
+        self
+    }
+
+    pub fn contains<T: ToString>(mut self, contains: T) -> Self {
+        self.contains = Some(contains.to_string());
+        self
+    }
+
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
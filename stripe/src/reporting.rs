@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Reporting {
     pub client: Client,
@@ -19,27 +17,28 @@ impl Reporting {
      *
      * **Parameters:**
      *
-     * * `created: &str`
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &ReportingGetReportRunsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_report_runs(
+    pub async fn get_report_runs_with(
         &self,
-        _created: &str,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &ReportingGetReportRunsParams,
     ) -> Result<Vec<crate::types::ReportingReportRun>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -52,66 +51,142 @@ impl Reporting {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_report_runs_with` and pass the query parameters as a `ReportingGetReportRunsParams` instead.
+     */
+    #[deprecated(note = "use `get_report_runs_with` instead")]
+    pub async fn get_report_runs(
+        &self,
+        created: &str,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::ReportingReportRun>> {
+        let params = ReportingGetReportRunsParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_report_runs_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/reporting/report_runs` endpoint.
      *
      * As opposed to `get_report_runs`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of Report Runs, with the most recent appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReportingGetReportRunsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_report_runs(
+    pub async fn get_all_report_runs_with(
         &self,
-        _created: &str,
+        params: &ReportingGetReportRunsParams,
     ) -> Result<Vec<crate::types::ReportingReportRun>> {
-        let url = "/v1/reporting/report_runs".to_string();
-        let mut resp: crate::types::GetReportingReportRunsResponse =
-            self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/reporting/report_runs?{}", query_);
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::ReportingReportRun| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_report_runs_with` and pass the query parameters as a `ReportingGetReportRunsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_report_runs_with` instead")]
+    pub async fn get_all_report_runs(
+        &self,
+        created: &str,
+    ) -> Result<Vec<crate::types::ReportingReportRun>> {
+        let params = ReportingGetReportRunsParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            ..Default::default()
+        };
+        self.get_all_report_runs_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/reporting/report_runs` endpoint.
+     *
+     * As opposed to `get_report_runs`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of Report Runs, with the most recent appearing first.</p>
+     */
+    pub fn get_report_runs_stream(
+        &self,
+        params: &ReportingGetReportRunsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReportingReportRun> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
         }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/reporting/report_runs?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::ReportingReportRun| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/reporting/report_runs` endpoint.
      *
      * <p>Creates a new object and begin running the report. (Certain report types require a <a href="https://stripe.com/docs/keys#test-live-modes">live-mode API key</a>.)</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_report_run(&self) -> Result<crate::types::ReportingReportRun> {
+    pub async fn post_report_run<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::ReportingReportRun> {
         let url = "/v1/reporting/report_runs".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -143,10 +218,21 @@ impl Reporting {
      *
      * **Parameters:**
      *
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+     * * `params: &ReportingGetReportTypesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_report_types(&self) -> Result<Vec<crate::types::ReportingReportType>> {
-        let url = "/v1/reporting/report_types".to_string();
+    pub async fn get_report_types_with(
+        &self,
+        params: &ReportingGetReportTypesParams,
+    ) -> Result<Vec<crate::types::ReportingReportType>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/reporting/report_types?{}", query_);
+
         let resp: crate::types::FinancialReportingFinanceReportTypeList =
             self.client.get(&url, None).await?;
 
@@ -154,53 +240,86 @@ impl Reporting {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_report_types_with` and pass the query parameters as a `ReportingGetReportTypesParams` instead.
+     */
+    #[deprecated(note = "use `get_report_types_with` instead")]
+    pub async fn get_report_types(&self) -> Result<Vec<crate::types::ReportingReportType>> {
+        let params = ReportingGetReportTypesParams::default();
+        self.get_report_types_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/reporting/report_types` endpoint.
      *
      * As opposed to `get_report_types`, this function returns all the pages of the request at once.
      *
      * <p>Returns a full list of Report Types.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReportingGetReportTypesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_report_types(&self) -> Result<Vec<crate::types::ReportingReportType>> {
-        let url = "/v1/reporting/report_types".to_string();
-        let mut resp: crate::types::FinancialReportingFinanceReportTypeList =
-            self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
+    pub async fn get_all_report_types_with(
+        &self,
+        params: &ReportingGetReportTypesParams,
+    ) -> Result<Vec<crate::types::ReportingReportType>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/reporting/report_types?{}", query_);
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::default(),
+            |item: &crate::types::ReportingReportType| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_report_types_with` and pass the query parameters as a `ReportingGetReportTypesParams` instead.
+     */
+    #[deprecated(note = "use `get_all_report_types_with` instead")]
+    pub async fn get_all_report_types(&self) -> Result<Vec<crate::types::ReportingReportType>> {
+        let params = ReportingGetReportTypesParams::default();
+        self.get_all_report_types_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/reporting/report_types` endpoint.
+     *
+     * As opposed to `get_report_types`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a full list of Report Types.</p>
+     */
+    pub fn get_report_types_stream(
+        &self,
+        params: &ReportingGetReportTypesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ReportingReportType> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/reporting/report_types?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::default().resume(start),
+            max_items,
+            |item: &crate::types::ReportingReportType| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -225,3 +344,58 @@ impl Reporting {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Reporting::get_report_runs_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportingGetReportRunsParams {
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl ReportingGetReportRunsParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Reporting::get_report_types_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportingGetReportTypesParams {
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+}
+
+impl ReportingGetReportTypesParams {
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SetupIntents {
     pub client: Client,
@@ -19,37 +17,34 @@ impl SetupIntents {
      *
      * **Parameters:**
      *
-     * * `created: &str` -- A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
-     * * `customer: &str` -- Only return SetupIntents for the customer specified by this customer ID.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `payment_method: &str` -- Only return SetupIntents associated with the specified payment method.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &SetupIntentsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        _created: &str,
-        customer: &str,
-        ending_before: &str,
-        limit: i64,
-        payment_method: &str,
-        starting_after: &str,
+        params: &SetupIntentsGetPageParams,
     ) -> Result<Vec<crate::types::SetupIntent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !payment_method.is_empty() {
+        if let Some(payment_method) = &params.payment_method {
             query_args.push(("payment_method".to_string(), payment_method.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -61,67 +56,139 @@ impl SetupIntents {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `SetupIntentsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        created: &str,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        payment_method: &str,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::SetupIntent>> {
+        let params = SetupIntentsGetPageParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            payment_method: Some(payment_method.to_string()).filter(|s| !s.is_empty()),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/setup_intents` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of SetupIntents.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &SetupIntentsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        _created: &str,
-        customer: &str,
-        payment_method: &str,
+        params: &SetupIntentsGetPageParams,
     ) -> Result<Vec<crate::types::SetupIntent>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !payment_method.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(payment_method) = &params.payment_method {
             query_args.push(("payment_method".to_string(), payment_method.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/setup_intents?{}", query_);
 
-        let mut resp: crate::types::PaymentFlowsSetupIntentList =
-            self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::SetupIntent| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `SetupIntentsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        created: &str,
+        customer: &str,
+        payment_method: &str,
+    ) -> Result<Vec<crate::types::SetupIntent>> {
+        let params = SetupIntentsGetPageParams {
+            created: crate::pagination::RangeQuery::parse(created),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            payment_method: Some(payment_method.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/setup_intents` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of SetupIntents.</p>
+     */
+    pub fn get_stream(
+        &self,
+        params: &SetupIntentsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SetupIntent> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
+            query_args.push(("customer".to_string(), customer.to_string()));
         }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(payment_method) = &params.payment_method {
+            query_args.push(("payment_method".to_string(), payment_method.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/setup_intents?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::SetupIntent| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -131,10 +198,20 @@ impl SetupIntents {
      *
      * <p>After the SetupIntent is created, attach a payment method and <a href="/docs/api/setup_intents/confirm">confirm</a>
      * to collect any required permissions to charge the payment method later.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::SetupIntent> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::SetupIntent> {
         let url = "/v1/setup_intents".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -148,17 +225,16 @@ impl SetupIntents {
      *
      * **Parameters:**
      *
-     * * `client_secret: &str` -- The client secret of the SetupIntent. Required if a publishable key is used to retrieve the SetupIntent.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `intent: &str` -- The account's country.
+     * * `params: &SetupIntentsGetIntentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_intent(
+    pub async fn get_intent_with(
         &self,
-        client_secret: &str,
         intent: &str,
+        params: &SetupIntentsGetIntentParams,
     ) -> Result<crate::types::SetupIntent> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_secret.is_empty() {
+        if let Some(client_secret) = &params.client_secret {
             query_args.push(("client_secret".to_string(), client_secret.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -171,6 +247,21 @@ impl SetupIntents {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_intent_with` and pass the query parameters as a `SetupIntentsGetIntentParams` instead.
+     */
+    #[deprecated(note = "use `get_intent_with` instead")]
+    pub async fn get_intent(
+        &self,
+        client_secret: &str,
+        intent: &str,
+    ) -> Result<crate::types::SetupIntent> {
+        let params = SetupIntentsGetIntentParams {
+            client_secret: Some(client_secret.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_intent_with(intent, &params).await
+    }
+
     /**
      * This function performs a `POST` to the `/v1/setup_intents/{intent}` endpoint.
      *
@@ -179,14 +270,25 @@ impl SetupIntents {
      * **Parameters:**
      *
      * * `intent: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_intent(&self, intent: &str) -> Result<crate::types::SetupIntent> {
+    pub async fn post_intent<B: serde::Serialize>(
+        &self,
+        intent: &str,
+        body: &B,
+    ) -> Result<crate::types::SetupIntent> {
         let url = format!(
             "/v1/setup_intents/{}",
             crate::progenitor_support::encode_path(intent),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -199,14 +301,25 @@ impl SetupIntents {
      * **Parameters:**
      *
      * * `intent: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_intent_cancel(&self, intent: &str) -> Result<crate::types::SetupIntent> {
+    pub async fn post_intent_cancel<B: serde::Serialize>(
+        &self,
+        intent: &str,
+        body: &B,
+    ) -> Result<crate::types::SetupIntent> {
         let url = format!(
             "/v1/setup_intents/{}/cancel",
             crate::progenitor_support::encode_path(intent),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -229,14 +342,25 @@ impl SetupIntents {
      * **Parameters:**
      *
      * * `intent: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_intent_confirm(&self, intent: &str) -> Result<crate::types::SetupIntent> {
+    pub async fn post_intent_confirm<B: serde::Serialize>(
+        &self,
+        intent: &str,
+        body: &B,
+    ) -> Result<crate::types::SetupIntent> {
         let url = format!(
             "/v1/setup_intents/{}/confirm",
             crate::progenitor_support::encode_path(intent),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -247,16 +371,94 @@ impl SetupIntents {
      * **Parameters:**
      *
      * * `intent: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_intent_verify_microdeposit(
+    pub async fn post_intent_verify_microdeposit<B: serde::Serialize>(
         &self,
         intent: &str,
+        body: &B,
     ) -> Result<crate::types::SetupIntent> {
         let url = format!(
             "/v1/setup_intents/{}/verify_microdeposits",
             crate::progenitor_support::encode_path(intent),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `SetupIntents::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SetupIntentsGetPageParams {
+    /// A filter on the list, based on the object `created` field. The value can be a string with an integer Unix timestamp, or it can be a dictionary with a number of different query options.
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// Only return SetupIntents for the customer specified by this customer ID.
+    pub customer: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// Only return SetupIntents associated with the specified payment method.
+    pub payment_method: Option<String>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl SetupIntentsGetPageParams {
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn payment_method<T: ToString>(mut self, payment_method: T) -> Self {
+        self.payment_method = Some(payment_method.to_string());
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SetupIntents::get_intent_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SetupIntentsGetIntentParams {
+    /// The client secret of the SetupIntent. Required if a publishable key is used to retrieve the SetupIntent.
+    pub client_secret: Option<String>,
+}
+
+impl SetupIntentsGetIntentParams {
+    pub fn client_secret<T: ToString>(mut self, client_secret: T) -> Self {
+        self.client_secret = Some(client_secret.to_string());
+        self
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Sigma {
     pub client: Client,
@@ -19,25 +17,25 @@ impl Sigma {
      *
      * **Parameters:**
      *
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &SigmaGetScheduledQueryRunsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_scheduled_query_runs(
+    pub async fn get_scheduled_query_runs_with(
         &self,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &SigmaGetScheduledQueryRunsParams,
     ) -> Result<Vec<crate::types::ScheduledQueryRun>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -50,55 +48,107 @@ impl Sigma {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_scheduled_query_runs_with` and pass the query parameters as a `SigmaGetScheduledQueryRunsParams` instead.
+     */
+    #[deprecated(note = "use `get_scheduled_query_runs_with` instead")]
+    pub async fn get_scheduled_query_runs(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::ScheduledQueryRun>> {
+        let params = SigmaGetScheduledQueryRunsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_scheduled_query_runs_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/sigma/scheduled_query_runs` endpoint.
      *
      * As opposed to `get_scheduled_query_runs`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of scheduled query runs.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &SigmaGetScheduledQueryRunsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_scheduled_query_runs(
+    pub async fn get_all_scheduled_query_runs_with(
         &self,
+        params: &SigmaGetScheduledQueryRunsParams,
     ) -> Result<Vec<crate::types::ScheduledQueryRun>> {
-        let url = "/v1/sigma/scheduled_query_runs".to_string();
-        let mut resp: crate::types::GetSigmaScheduledQueryRunsResponse =
-            self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/sigma/scheduled_query_runs?{}", query_);
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::ScheduledQueryRun| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_scheduled_query_runs_with` and pass the query parameters as a `SigmaGetScheduledQueryRunsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_scheduled_query_runs_with` instead")]
+    pub async fn get_all_scheduled_query_runs(
+        &self,
+    ) -> Result<Vec<crate::types::ScheduledQueryRun>> {
+        let params = SigmaGetScheduledQueryRunsParams::default();
+        self.get_all_scheduled_query_runs_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/sigma/scheduled_query_runs` endpoint.
+     *
+     * As opposed to `get_scheduled_query_runs`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of scheduled query runs.</p>
+     */
+    pub fn get_scheduled_query_runs_stream(
+        &self,
+        params: &SigmaGetScheduledQueryRunsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ScheduledQueryRun> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/sigma/scheduled_query_runs?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::ScheduledQueryRun| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -123,3 +173,38 @@ impl Sigma {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Sigma::get_scheduled_query_runs_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SigmaGetScheduledQueryRunsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl SigmaGetScheduledQueryRunsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Skus {
     pub client: Client,
@@ -19,44 +17,40 @@ impl Skus {
      *
      * **Parameters:**
      *
-     * * `active: bool` -- Only return SKUs that are active or inactive (e.g., pass `false` to list all inactive products).
-     * * `attributes: &str` -- Only return SKUs that have the specified key-value pairs in this partially constructed dictionary. Can be specified only if `product` is also supplied. For instance, if the associated product has attributes `["color", "size"]`, passing in `attributes[color]=red` returns all the SKUs for this product that have `color` set to `red`.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `ids: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `in_stock: bool` -- Only return SKUs that are either in stock or out of stock (e.g., pass `false` to list all SKUs that are out of stock). If no value is provided, all SKUs are returned.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `product: &str` -- The ID of the product whose SKUs will be retrieved. Must be a product with type `good`.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &SkusGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        active: bool,
         _attributes: &str,
-        ending_before: &str,
-        _ids: &[String],
-        in_stock: bool,
-        limit: i64,
-        product: &str,
-        starting_after: &str,
+        params: &SkusGetPageParams,
     ) -> Result<Vec<crate::types::Sku>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if active {
+        if let Some(active) = &params.active {
             query_args.push(("active".to_string(), active.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if in_stock {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(ids) = &params.ids {
+            for v in ids {
+                query_args.push(("ids[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(in_stock) = &params.in_stock {
             query_args.push(("in_stock".to_string(), in_stock.to_string()));
         }
-        if limit > 0 {
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !product.is_empty() {
+        if let Some(product) = &params.product {
             query_args.push(("product".to_string(), product.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -68,81 +62,181 @@ impl Skus {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `SkusGetPageParams` instead.
+     *
+     * `attributes` is not sent.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        active: bool,
+        _attributes: &str,
+        ending_before: &str,
+        ids: &[String],
+        in_stock: bool,
+        limit: i64,
+        product: &str,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::Sku>> {
+        let params = SkusGetPageParams {
+            active: Some(active).filter(|v| *v),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            in_stock: Some(in_stock).filter(|v| *v),
+            limit: Some(limit).filter(|v| *v > 0),
+            product: Some(product.to_string()).filter(|s| !s.is_empty()),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(_attributes, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/skus` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of your SKUs. The SKUs are returned sorted by creation date, with the most recently created SKUs appearing first.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &SkusGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        active: bool,
         _attributes: &str,
-        _ids: &[String],
-        in_stock: bool,
-        product: &str,
+        params: &SkusGetPageParams,
     ) -> Result<Vec<crate::types::Sku>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if active {
+        if let Some(active) = &params.active {
             query_args.push(("active".to_string(), active.to_string()));
         }
-        if in_stock {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(ids) = &params.ids {
+            for v in ids {
+                query_args.push(("ids[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(in_stock) = &params.in_stock {
             query_args.push(("in_stock".to_string(), in_stock.to_string()));
         }
-        if !product.is_empty() {
+        if let Some(product) = &params.product {
             query_args.push(("product".to_string(), product.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/skus?{}", query_);
 
-        let mut resp: crate::types::GetSkusResponse = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::Sku| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `SkusGetPageParams` instead.
+     *
+     * `attributes` is not sent.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        active: bool,
+        _attributes: &str,
+        ids: &[String],
+        in_stock: bool,
+        product: &str,
+    ) -> Result<Vec<crate::types::Sku>> {
+        let params = SkusGetPageParams {
+            active: Some(active).filter(|v| *v),
+            ids: Some(ids.to_vec()).filter(|v| !v.is_empty()),
+            in_stock: Some(in_stock).filter(|v| *v),
+            product: Some(product.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(_attributes, &params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/skus` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of your SKUs. The SKUs are returned sorted by creation date, with the most recently created SKUs appearing first.</p>
+     */
+    pub fn get_stream(
+        &self,
+        _attributes: &str,
+        params: &SkusGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Sku> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(active) = &params.active {
+            query_args.push(("active".to_string(), active.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(ids) = &params.ids {
+            for v in ids {
+                query_args.push(("ids[]".to_string(), v.to_string()));
+            }
         }
+        if let Some(in_stock) = &params.in_stock {
+            query_args.push(("in_stock".to_string(), in_stock.to_string()));
+        }
+        if let Some(product) = &params.product {
+            query_args.push(("product".to_string(), product.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/skus?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::Sku| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/skus` endpoint.
      *
      * <p>Creates a new SKU associated with a product.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::Sku> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::Sku> {
         let url = "/v1/skus".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -171,11 +265,22 @@ impl Skus {
      * **Parameters:**
      *
      * * `id: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_skus(&self, id: &str) -> Result<crate::types::Sku> {
+    pub async fn post_skus<B: serde::Serialize>(
+        &self,
+        id: &str,
+        body: &B,
+    ) -> Result<crate::types::Sku> {
         let url = format!("/v1/skus/{}", crate::progenitor_support::encode_path(id),);
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -193,3 +298,66 @@ impl Skus {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Skus::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SkusGetPageParams {
+    /// Only return SKUs that are active or inactive (e.g., pass `false` to list all inactive products).
+    pub active: Option<bool>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub ids: Option<Vec<String>>,
+    /// Only return SKUs that are either in stock or out of stock (e.g., pass `false` to list all SKUs that are out of stock). If no value is provided, all SKUs are returned.
+    pub in_stock: Option<bool>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// The ID of the product whose SKUs will be retrieved. Must be a product with type `good`.
+    pub product: Option<String>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl SkusGetPageParams {
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn ids(mut self, ids: Vec<String>) -> Self {
+        self.ids = Some(ids);
+        self
+    }
+
+    pub fn in_stock(mut self, in_stock: bool) -> Self {
+        self.in_stock = Some(in_stock);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn product<T: ToString>(mut self, product: T) -> Self {
+        self.product = Some(product.to_string());
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
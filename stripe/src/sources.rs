@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Sources {
     pub client: Client,
@@ -16,10 +14,20 @@ impl Sources {
      * This function performs a `POST` to the `/v1/sources` endpoint.
      *
      * <p>Creates a new source object.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::SourceData> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::SourceData> {
         let url = "/v1/sources".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -29,13 +37,16 @@ impl Sources {
      *
      * **Parameters:**
      *
-     * * `client_secret: &str` -- The client secret of the source. Required if a publishable key is used to retrieve the source.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
      * * `source: &str` -- The account's country.
+     * * `params: &SourcesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(&self, client_secret: &str, source: &str) -> Result<crate::types::SourceData> {
+    pub async fn get_with(
+        &self,
+        source: &str,
+        params: &SourcesGetParams,
+    ) -> Result<crate::types::SourceData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !client_secret.is_empty() {
+        if let Some(client_secret) = &params.client_secret {
             query_args.push(("client_secret".to_string(), client_secret.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -48,6 +59,17 @@ impl Sources {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `SourcesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(&self, client_secret: &str, source: &str) -> Result<crate::types::SourceData> {
+        let params = SourcesGetParams {
+            client_secret: Some(client_secret.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(source, &params).await
+    }
+
     /**
      * This function performs a `POST` to the `/v1/sources/{source}` endpoint.
      *
@@ -58,14 +80,25 @@ impl Sources {
      * **Parameters:**
      *
      * * `source: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_sources(&self, source: &str) -> Result<crate::types::SourceData> {
+    pub async fn post_sources<B: serde::Serialize>(
+        &self,
+        source: &str,
+        body: &B,
+    ) -> Result<crate::types::SourceData> {
         let url = format!(
             "/v1/sources/{}",
             crate::progenitor_support::encode_path(source),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -100,27 +133,27 @@ impl Sources {
      *
      * **Parameters:**
      *
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
      * * `source: &str` -- The account's country.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &SourcesGetTransactionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_transactions(
+    pub async fn get_transactions_with(
         &self,
-        ending_before: &str,
-        limit: i64,
         source: &str,
-        starting_after: &str,
+        params: &SourcesGetTransactionsParams,
     ) -> Result<Vec<crate::types::SourceTransaction>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -137,60 +170,119 @@ impl Sources {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_transactions_with` and pass the query parameters as a `SourcesGetTransactionsParams` instead.
+     */
+    #[deprecated(note = "use `get_transactions_with` instead")]
+    pub async fn get_transactions(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        source: &str,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::SourceTransaction>> {
+        let params = SourcesGetTransactionsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_transactions_with(source, &params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/sources/{source}/source_transactions` endpoint.
      *
      * As opposed to `get_transactions`, this function returns all the pages of the request at once.
      *
      * <p>List source transactions for a given source.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &SourcesGetTransactionsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_transactions(
+    pub async fn get_all_transactions_with(
         &self,
         source: &str,
+        params: &SourcesGetTransactionsParams,
     ) -> Result<Vec<crate::types::SourceTransaction>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/sources/{}/source_transactions",
+            "/v1/sources/{}/source_transactions?{}",
             crate::progenitor_support::encode_path(source),
+            query_
         );
 
-        let mut resp: crate::types::ApmsSourcesSourceTransactionList =
-            self.client.get(&url, None).await?;
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::SourceTransaction| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_transactions_with` and pass the query parameters as a `SourcesGetTransactionsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_transactions_with` instead")]
+    pub async fn get_all_transactions(
+        &self,
+        source: &str,
+    ) -> Result<Vec<crate::types::SourceTransaction>> {
+        let params = SourcesGetTransactionsParams::default();
+        self.get_all_transactions_with(source, &params).await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/sources/{source}/source_transactions` endpoint.
+     *
+     * As opposed to `get_transactions`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>List source transactions for a given source.</p>
+     */
+    pub fn get_transactions_stream(
+        &self,
+        source: &str,
+        params: &SourcesGetTransactionsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SourceTransaction> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/sources/{}/source_transactions?{}",
+            crate::progenitor_support::encode_path(source),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::SourceTransaction| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -226,13 +318,73 @@ impl Sources {
      * **Parameters:**
      *
      * * `source: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_verify(&self, source: &str) -> Result<crate::types::SourceData> {
+    pub async fn post_verify<B: serde::Serialize>(
+        &self,
+        source: &str,
+        body: &B,
+    ) -> Result<crate::types::SourceData> {
         let url = format!(
             "/v1/sources/{}/verify",
             crate::progenitor_support::encode_path(source),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Sources::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SourcesGetParams {
+    /// The client secret of the source. Required if a publishable key is used to retrieve the source.
+    pub client_secret: Option<String>,
+}
+
+impl SourcesGetParams {
+    pub fn client_secret<T: ToString>(mut self, client_secret: T) -> Self {
+        self.client_secret = Some(client_secret.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Sources::get_transactions_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SourcesGetTransactionsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl SourcesGetTransactionsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
     }
 }
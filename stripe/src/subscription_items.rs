@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SubscriptionItems {
     pub client: Client,
@@ -19,30 +17,28 @@ impl SubscriptionItems {
      *
      * **Parameters:**
      *
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `subscription: &str` -- The ID of the subscription whose items will be retrieved.
+     * * `params: &SubscriptionItemsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
-        subscription: &str,
+        params: &SubscriptionItemsGetPageParams,
     ) -> Result<Vec<crate::types::SubscriptionItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !subscription.is_empty() {
+        if let Some(subscription) = &params.subscription {
             query_args.push(("subscription".to_string(), subscription.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -54,68 +50,139 @@ impl SubscriptionItems {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `SubscriptionItemsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        subscription: &str,
+    ) -> Result<Vec<crate::types::SubscriptionItem>> {
+        let params = SubscriptionItemsGetPageParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            subscription: Some(subscription.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/subscription_items` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of your subscription items for a given subscription.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &SubscriptionItemsGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(&self, subscription: &str) -> Result<Vec<crate::types::SubscriptionItem>> {
+    pub async fn get_all_with(
+        &self,
+        params: &SubscriptionItemsGetPageParams,
+    ) -> Result<Vec<crate::types::SubscriptionItem>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !subscription.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(subscription) = &params.subscription {
             query_args.push(("subscription".to_string(), subscription.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/subscription_items?{}", query_);
 
-        let mut resp: crate::types::Items = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::SubscriptionItem| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `SubscriptionItemsGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(&self, subscription: &str) -> Result<Vec<crate::types::SubscriptionItem>> {
+        let params = SubscriptionItemsGetPageParams {
+            subscription: Some(subscription.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/subscription_items` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of your subscription items for a given subscription.</p>
+     */
+    pub fn get_stream(
+        &self,
+        params: &SubscriptionItemsGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SubscriptionItem> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(subscription) = &params.subscription {
+            query_args.push(("subscription".to_string(), subscription.to_string()));
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/subscription_items?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::SubscriptionItem| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/subscription_items` endpoint.
      *
      * <p>Adds a new item to an existing subscription. No existing items will be changed or replaced.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::SubscriptionItem> {
+    pub async fn post<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::SubscriptionItem> {
         let url = "/v1/subscription_items".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -145,14 +212,25 @@ impl SubscriptionItems {
      * **Parameters:**
      *
      * * `item: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_item(&self, item: &str) -> Result<crate::types::SubscriptionItem> {
+    pub async fn post_item<B: serde::Serialize>(
+        &self,
+        item: &str,
+        body: &B,
+    ) -> Result<crate::types::SubscriptionItem> {
         let url = format!(
             "/v1/subscription_items/{}",
             crate::progenitor_support::encode_path(item),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -182,27 +260,27 @@ impl SubscriptionItems {
      *
      * **Parameters:**
      *
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
      * * `subscription_item: &str` -- The account's country.
+     * * `params: &SubscriptionItemsGetItemUsageRecordSummariesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_item_usage_record_summaries(
+    pub async fn get_item_usage_record_summaries_with(
         &self,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
         subscription_item: &str,
+        params: &SubscriptionItemsGetItemUsageRecordSummariesParams,
     ) -> Result<Vec<crate::types::UsageRecordSummary>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -219,6 +297,27 @@ impl SubscriptionItems {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_item_usage_record_summaries_with` and pass the query parameters as a `SubscriptionItemsGetItemUsageRecordSummariesParams` instead.
+     */
+    #[deprecated(note = "use `get_item_usage_record_summaries_with` instead")]
+    pub async fn get_item_usage_record_summaries(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+        subscription_item: &str,
+    ) -> Result<Vec<crate::types::UsageRecordSummary>> {
+        let params = SubscriptionItemsGetItemUsageRecordSummariesParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_item_usage_record_summaries_with(subscription_item, &params)
+            .await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/subscription_items/{subscription_item}/usage_record_summaries` endpoint.
      *
@@ -227,54 +326,96 @@ impl SubscriptionItems {
      * <p>For the specified subscription item, returns a list of summary objects. Each object in the list provides usage information that’s been summarized from multiple usage records and over a subscription billing period (e.g., 15 usage records in the month of September).</p>
      *
      * <p>The list is sorted in reverse-chronological order (newest first). The first list item represents the most current usage period that hasn’t ended yet. Since new usage records can still be added, the returned summary information for the subscription item’s ID should be seen as unstable until the subscription billing period ends.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &SubscriptionItemsGetItemUsageRecordSummariesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_item_usage_record_summaries(
+    pub async fn get_all_item_usage_record_summaries_with(
         &self,
         subscription_item: &str,
+        params: &SubscriptionItemsGetItemUsageRecordSummariesParams,
     ) -> Result<Vec<crate::types::UsageRecordSummary>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/v1/subscription_items/{}/usage_record_summaries",
+            "/v1/subscription_items/{}/usage_record_summaries?{}",
             crate::progenitor_support::encode_path(subscription_item),
+            query_
         );
 
-        let mut resp: crate::types::GetSubscriptionItemsItemUsageRecordSummariesResponse =
-            self.client.get(&url, None).await?;
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::UsageRecordSummary| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_item_usage_record_summaries_with` and pass the query parameters as a `SubscriptionItemsGetItemUsageRecordSummariesParams` instead.
+     */
+    #[deprecated(note = "use `get_all_item_usage_record_summaries_with` instead")]
+    pub async fn get_all_item_usage_record_summaries(
+        &self,
+        subscription_item: &str,
+    ) -> Result<Vec<crate::types::UsageRecordSummary>> {
+        let params = SubscriptionItemsGetItemUsageRecordSummariesParams::default();
+        self.get_all_item_usage_record_summaries_with(subscription_item, &params)
+            .await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/subscription_items/{subscription_item}/usage_record_summaries` endpoint.
+     *
+     * As opposed to `get_item_usage_record_summaries`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>For the specified subscription item, returns a list of summary objects. Each object in the list provides usage information that’s been summarized from multiple usage records and over a subscription billing period (e.g., 15 usage records in the month of September).</p>
+     *
+     * <p>The list is sorted in reverse-chronological order (newest first). The first list item represents the most current usage period that hasn’t ended yet. Since new usage records can still be added, the returned summary information for the subscription item’s ID should be seen as unstable until the subscription billing period ends.</p>
+     */
+    pub fn get_item_usage_record_summaries_stream(
+        &self,
+        subscription_item: &str,
+        params: &SubscriptionItemsGetItemUsageRecordSummariesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UsageRecordSummary> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/v1/subscription_items/{}/usage_record_summaries?{}",
+            crate::progenitor_support::encode_path(subscription_item),
+            query_
+        );
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::UsageRecordSummary| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -291,16 +432,101 @@ impl SubscriptionItems {
      * **Parameters:**
      *
      * * `subscription_item: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_item_usage_record(
+    pub async fn post_item_usage_record<B: serde::Serialize>(
         &self,
         subscription_item: &str,
+        body: &B,
     ) -> Result<crate::types::UsageRecord> {
         let url = format!(
             "/v1/subscription_items/{}/usage_records",
             crate::progenitor_support::encode_path(subscription_item),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `SubscriptionItems::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SubscriptionItemsGetPageParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// The ID of the subscription whose items will be retrieved.
+    pub subscription: Option<String>,
+}
+
+impl SubscriptionItemsGetPageParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn subscription<T: ToString>(mut self, subscription: T) -> Self {
+        self.subscription = Some(subscription.to_string());
+        self
+    }
+}
+
+/// The query parameters of `SubscriptionItems::get_item_usage_record_summaries_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SubscriptionItemsGetItemUsageRecordSummariesParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl SubscriptionItemsGetItemUsageRecordSummariesParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
     }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SubscriptionSchedules {
     pub client: Client,
@@ -19,43 +17,43 @@ impl SubscriptionSchedules {
      *
      * **Parameters:**
      *
-     * * `canceled_at: &str` -- Only return subscription schedules that were created canceled the given date interval.
-     * * `completed_at: &str` -- Only return subscription schedules that completed during the given date interval.
-     * * `created: &str` -- Only return subscription schedules that were created during the given date interval.
-     * * `customer: &str` -- Only return subscription schedules for the given customer.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `released_at: &str` -- Only return subscription schedules that were released during the given date interval.
-     * * `scheduled: bool` -- Only return subscription schedules that have not started yet.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &SubscriptionSchedulesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        _canceled_at: &str,
-        _completed_at: &str,
-        _created: &str,
-        customer: &str,
-        ending_before: &str,
-        limit: i64,
-        _released_at: &str,
-        scheduled: bool,
-        starting_after: &str,
+        params: &SubscriptionSchedulesGetPageParams,
     ) -> Result<Vec<crate::types::SubscriptionSchedule>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(canceled_at) = &params.canceled_at {
+            query_args.extend(canceled_at.to_query("canceled_at"));
+        }
+        if let Some(completed_at) = &params.completed_at {
+            query_args.extend(completed_at.to_query("completed_at"));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if scheduled {
+        if let Some(released_at) = &params.released_at {
+            query_args.extend(released_at.to_query("released_at"));
+        }
+        if let Some(scheduled) = &params.scheduled {
             query_args.push(("scheduled".to_string(), scheduled.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -68,80 +66,192 @@ impl SubscriptionSchedules {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `SubscriptionSchedulesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        canceled_at: &str,
+        completed_at: &str,
+        created: &str,
+        customer: &str,
+        ending_before: &str,
+        limit: i64,
+        released_at: &str,
+        scheduled: bool,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::SubscriptionSchedule>> {
+        let params = SubscriptionSchedulesGetPageParams {
+            canceled_at: crate::pagination::RangeQuery::parse(canceled_at),
+            completed_at: crate::pagination::RangeQuery::parse(completed_at),
+            created: crate::pagination::RangeQuery::parse(created),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            released_at: crate::pagination::RangeQuery::parse(released_at),
+            scheduled: Some(scheduled).filter(|v| *v),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/subscription_schedules` endpoint.
      *
      * As opposed to `get`, this function returns all the pages of the request at once.
      *
      * <p>Retrieves the list of your subscription schedules.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &SubscriptionSchedulesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        _canceled_at: &str,
-        _completed_at: &str,
-        _created: &str,
-        customer: &str,
-        _released_at: &str,
-        scheduled: bool,
+        params: &SubscriptionSchedulesGetPageParams,
     ) -> Result<Vec<crate::types::SubscriptionSchedule>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !customer.is_empty() {
+        if let Some(canceled_at) = &params.canceled_at {
+            query_args.extend(canceled_at.to_query("canceled_at"));
+        }
+        if let Some(completed_at) = &params.completed_at {
+            query_args.extend(completed_at.to_query("completed_at"));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
             query_args.push(("customer".to_string(), customer.to_string()));
         }
-        if scheduled {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(released_at) = &params.released_at {
+            query_args.extend(released_at.to_query("released_at"));
+        }
+        if let Some(scheduled) = &params.scheduled {
             query_args.push(("scheduled".to_string(), scheduled.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/subscription_schedules?{}", query_);
 
-        let mut resp: crate::types::GetSubscriptionSchedulesResponse =
-            self.client.get(&url, None).await?;
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::SubscriptionSchedule| Some(item.id.clone()),
+        )
+        .await
+    }
 
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `SubscriptionSchedulesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        canceled_at: &str,
+        completed_at: &str,
+        created: &str,
+        customer: &str,
+        released_at: &str,
+        scheduled: bool,
+    ) -> Result<Vec<crate::types::SubscriptionSchedule>> {
+        let params = SubscriptionSchedulesGetPageParams {
+            canceled_at: crate::pagination::RangeQuery::parse(canceled_at),
+            completed_at: crate::pagination::RangeQuery::parse(completed_at),
+            created: crate::pagination::RangeQuery::parse(created),
+            customer: Some(customer.to_string()).filter(|s| !s.is_empty()),
+            released_at: crate::pagination::RangeQuery::parse(released_at),
+            scheduled: Some(scheduled).filter(|v| *v),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
+    /**
+     * This function performs a `GET` to the `/v1/subscription_schedules` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Retrieves the list of your subscription schedules.</p>
+     */
+    pub fn get_stream(
+        &self,
+        params: &SubscriptionSchedulesGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SubscriptionSchedule> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(canceled_at) = &params.canceled_at {
+            query_args.extend(canceled_at.to_query("canceled_at"));
+        }
+        if let Some(completed_at) = &params.completed_at {
+            query_args.extend(completed_at.to_query("completed_at"));
+        }
+        if let Some(created) = &params.created {
+            query_args.extend(created.to_query("created"));
+        }
+        if let Some(customer) = &params.customer {
+            query_args.push(("customer".to_string(), customer.to_string()));
+        }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
-
-            data.append(&mut resp.data);
-
-            has_more = resp.has_more;
         }
+        if let Some(released_at) = &params.released_at {
+            query_args.extend(released_at.to_query("released_at"));
+        }
+        if let Some(scheduled) = &params.scheduled {
+            query_args.push(("scheduled".to_string(), scheduled.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/subscription_schedules?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::SubscriptionSchedule| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/subscription_schedules` endpoint.
      *
      * <p>Creates a new subscription schedule object. Each customer can have up to 500 active or scheduled subscriptions.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::SubscriptionSchedule> {
+    pub async fn post<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::SubscriptionSchedule> {
         let url = "/v1/subscription_schedules".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -171,17 +281,25 @@ impl SubscriptionSchedules {
      * **Parameters:**
      *
      * * `schedule: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_schedule(
+    pub async fn post_schedule<B: serde::Serialize>(
         &self,
         schedule: &str,
+        body: &B,
     ) -> Result<crate::types::SubscriptionSchedule> {
         let url = format!(
             "/v1/subscription_schedules/{}",
             crate::progenitor_support::encode_path(schedule),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -192,17 +310,25 @@ impl SubscriptionSchedules {
      * **Parameters:**
      *
      * * `schedule: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_schedule_cancel(
+    pub async fn post_schedule_cancel<B: serde::Serialize>(
         &self,
         schedule: &str,
+        body: &B,
     ) -> Result<crate::types::SubscriptionSchedule> {
         let url = format!(
             "/v1/subscription_schedules/{}/cancel",
             crate::progenitor_support::encode_path(schedule),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -213,16 +339,101 @@ impl SubscriptionSchedules {
      * **Parameters:**
      *
      * * `schedule: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_schedule_release(
+    pub async fn post_schedule_release<B: serde::Serialize>(
         &self,
         schedule: &str,
+        body: &B,
     ) -> Result<crate::types::SubscriptionSchedule> {
         let url = format!(
             "/v1/subscription_schedules/{}/release",
             crate::progenitor_support::encode_path(schedule),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `SubscriptionSchedules::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SubscriptionSchedulesGetPageParams {
+    /// Only return subscription schedules that were created canceled the given date interval.
+    pub canceled_at: Option<crate::pagination::RangeQuery>,
+    /// Only return subscription schedules that completed during the given date interval.
+    pub completed_at: Option<crate::pagination::RangeQuery>,
+    /// Only return subscription schedules that were created during the given date interval.
+    pub created: Option<crate::pagination::RangeQuery>,
+    /// Only return subscription schedules for the given customer.
+    pub customer: Option<String>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// Only return subscription schedules that were released during the given date interval.
+    pub released_at: Option<crate::pagination::RangeQuery>,
+    /// Only return subscription schedules that have not started yet.
+    pub scheduled: Option<bool>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl SubscriptionSchedulesGetPageParams {
+    pub fn canceled_at(mut self, canceled_at: crate::pagination::RangeQuery) -> Self {
+        self.canceled_at = Some(canceled_at);
+        self
+    }
+
+    pub fn completed_at(mut self, completed_at: crate::pagination::RangeQuery) -> Self {
+        self.completed_at = Some(completed_at);
+        self
+    }
+
+    pub fn created(mut self, created: crate::pagination::RangeQuery) -> Self {
+        self.created = Some(created);
+        self
+    }
+
+    pub fn customer<T: ToString>(mut self, customer: T) -> Self {
+        self.customer = Some(customer.to_string());
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn released_at(mut self, released_at: crate::pagination::RangeQuery) -> Self {
+        self.released_at = Some(released_at);
+        self
+    }
+
+    pub fn scheduled(mut self, scheduled: bool) -> Self {
+        self.scheduled = Some(scheduled);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
     }
 }
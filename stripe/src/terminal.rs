@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Terminal {
     pub client: Client,
@@ -16,10 +14,23 @@ impl Terminal {
      * This function performs a `POST` to the `/v1/terminal/connection_tokens` endpoint.
      *
      * <p>To connect to a reader the Stripe Terminal SDK needs to retrieve a short-lived connection token from Stripe, proxied through your server. On your backend, add an endpoint that creates and returns a connection token.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_connection_token(&self) -> Result<crate::types::TerminalConnectionToken> {
+    pub async fn post_connection_token<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::TerminalConnectionToken> {
         let url = "/v1/terminal/connection_tokens".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -29,25 +40,25 @@ impl Terminal {
      *
      * **Parameters:**
      *
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+     * * `params: &TerminalGetLocationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_locations(
+    pub async fn get_locations_with(
         &self,
-        ending_before: &str,
-        limit: i64,
-        starting_after: &str,
+        params: &TerminalGetLocationsParams,
     ) -> Result<Vec<crate::types::TerminalLocation>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -59,52 +70,105 @@ impl Terminal {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_locations_with` and pass the query parameters as a `TerminalGetLocationsParams` instead.
+     */
+    #[deprecated(note = "use `get_locations_with` instead")]
+    pub async fn get_locations(
+        &self,
+        ending_before: &str,
+        limit: i64,
+        starting_after: &str,
+    ) -> Result<Vec<crate::types::TerminalLocation>> {
+        let params = TerminalGetLocationsParams {
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_locations_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/terminal/locations` endpoint.
      *
      * As opposed to `get_locations`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of <code>Location</code> objects.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &TerminalGetLocationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_locations(&self) -> Result<Vec<crate::types::TerminalLocation>> {
-        let url = "/v1/terminal/locations".to_string();
-        let mut resp: crate::types::TerminalLocationList = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
+    pub async fn get_all_locations_with(
+        &self,
+        params: &TerminalGetLocationsParams,
+    ) -> Result<Vec<crate::types::TerminalLocation>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
             }
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/terminal/locations?{}", query_);
 
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::TerminalLocation| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_locations_with` and pass the query parameters as a `TerminalGetLocationsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_locations_with` instead")]
+    pub async fn get_all_locations(&self) -> Result<Vec<crate::types::TerminalLocation>> {
+        let params = TerminalGetLocationsParams::default();
+        self.get_all_locations_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/terminal/locations` endpoint.
+     *
+     * As opposed to `get_locations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of <code>Location</code> objects.</p>
+     */
+    pub fn get_locations_stream(
+        &self,
+        params: &TerminalGetLocationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TerminalLocation> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
         }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/terminal/locations?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::TerminalLocation| Some(item.id.clone()),
+        )
     }
 
     /**
@@ -112,10 +176,23 @@ impl Terminal {
      *
      * <p>Creates a new <code>Location</code> object.
      * For further details, including which address fields are required in each country, see the <a href="/docs/terminal/fleet/locations">Manage locations</a> guide.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_location(&self) -> Result<crate::types::TerminalLocation> {
+    pub async fn post_location<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::TerminalLocation> {
         let url = "/v1/terminal/locations".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -148,17 +225,25 @@ impl Terminal {
      * **Parameters:**
      *
      * * `location: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_locations_location(
+    pub async fn post_locations_location<B: serde::Serialize>(
         &self,
         location: &str,
+        body: &B,
     ) -> Result<crate::types::GetTerminalLocationResponseAnyOf> {
         let url = format!(
             "/v1/terminal/locations/{}",
             crate::progenitor_support::encode_path(location),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -189,40 +274,34 @@ impl Terminal {
      *
      * **Parameters:**
      *
-     * * `device_type: crate::types::DeviceType` -- Type of reader, one of `bbpos_wisepad3`, `stripe_m2`, `bbpos_chipper2x`, `bbpos_wisepos_e`, or `verifone_P400`.
-     * * `ending_before: &str` -- A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
-     * * `expand: &[String]` -- Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
-     * * `limit: i64` -- A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
-     * * `location: &str` -- A location ID to filter the response list to only readers at the specific location.
-     * * `starting_after: &str` -- A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
-     * * `status: crate::types::CustomerAcceptanceType` -- The type of customer acceptance information included with the Mandate. One of `online` or `offline`.
+     * * `params: &TerminalGetReadersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_readers(
+    pub async fn get_readers_with(
         &self,
-        device_type: crate::types::DeviceType,
-        ending_before: &str,
-        limit: i64,
-        location: &str,
-        starting_after: &str,
-        status: crate::types::CustomerAcceptanceType,
+        params: &TerminalGetReadersParams,
     ) -> Result<Vec<crate::types::TerminalReader>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !device_type.to_string().is_empty() {
+        if let Some(device_type) = &params.device_type {
             query_args.push(("device_type".to_string(), device_type.to_string()));
         }
-        if !ending_before.is_empty() {
+        if let Some(ending_before) = &params.ending_before {
             query_args.push(("ending_before".to_string(), ending_before.to_string()));
         }
-        if limit > 0 {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(limit) = &params.limit {
             query_args.push(("limit".to_string(), limit.to_string()));
         }
-        if !location.is_empty() {
+        if let Some(location) = &params.location {
             query_args.push(("location".to_string(), location.to_string()));
         }
-        if !starting_after.is_empty() {
+        if let Some(starting_after) = &params.starting_after {
             query_args.push(("starting_after".to_string(), starting_after.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -234,79 +313,162 @@ impl Terminal {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_readers_with` and pass the query parameters as a `TerminalGetReadersParams` instead.
+     */
+    #[deprecated(note = "use `get_readers_with` instead")]
+    pub async fn get_readers(
+        &self,
+        device_type: crate::types::DeviceType,
+        ending_before: &str,
+        limit: i64,
+        location: &str,
+        starting_after: &str,
+        status: crate::types::CustomerAcceptanceType,
+    ) -> Result<Vec<crate::types::TerminalReader>> {
+        let params = TerminalGetReadersParams {
+            device_type: Some(device_type).filter(|v| !v.to_string().is_empty()),
+            ending_before: Some(ending_before.to_string()).filter(|s| !s.is_empty()),
+            limit: Some(limit).filter(|v| *v > 0),
+            location: Some(location.to_string()).filter(|s| !s.is_empty()),
+            starting_after: Some(starting_after.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_readers_with(&params).await
+    }
+
     /**
      * This function performs a `GET` to the `/v1/terminal/readers` endpoint.
      *
      * As opposed to `get_readers`, this function returns all the pages of the request at once.
      *
      * <p>Returns a list of <code>Reader</code> objects.</p>
+     *
+     * **Parameters:**
+     *
+     * * `params: &TerminalGetReadersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_readers(
+    pub async fn get_all_readers_with(
         &self,
-        device_type: crate::types::DeviceType,
-        location: &str,
-        status: crate::types::CustomerAcceptanceType,
+        params: &TerminalGetReadersParams,
     ) -> Result<Vec<crate::types::TerminalReader>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !device_type.to_string().is_empty() {
+        if let Some(device_type) = &params.device_type {
             query_args.push(("device_type".to_string(), device_type.to_string()));
         }
-        if !location.is_empty() {
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(location) = &params.location {
             query_args.push(("location".to_string(), location.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!("/v1/terminal/readers?{}", query_);
 
-        let mut resp: crate::types::TerminalReaderRetrieve = self.client.get(&url, None).await?;
-
-        let mut data = resp.data;
-        let mut has_more = resp.has_more;
-        let mut page = "".to_string();
-
-        // Paginate if we should.
-        while has_more {
-            if !data.is_empty() {
-                let last = data.last().unwrap();
-                let j = serde_json::json!(last);
-                if let serde_json::Value::Object(o) = j {
-                    if let Some(serde_json::Value::String(s)) = o.get("id") {
-                        page = s.to_string();
-                    }
-                }
-            }
-
-            if !url.contains('?') {
-                resp = self
-                    .client
-                    .get(&format!("{}?startng_after={}", url, page), None)
-                    .await?;
-            } else {
-                resp = self
-                    .client
-                    .get(&format!("{}&starting_after={}", url, page), None)
-                    .await?;
-            }
+        crate::pagination::list_all(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            ),
+            |item: &crate::types::TerminalReader| Some(item.id.clone()),
+        )
+        .await
+    }
 
-            data.append(&mut resp.data);
+    /**
+     * Deprecated: use `get_all_readers_with` and pass the query parameters as a `TerminalGetReadersParams` instead.
+     */
+    #[deprecated(note = "use `get_all_readers_with` instead")]
+    pub async fn get_all_readers(
+        &self,
+        device_type: crate::types::DeviceType,
+        location: &str,
+        status: crate::types::CustomerAcceptanceType,
+    ) -> Result<Vec<crate::types::TerminalReader>> {
+        let params = TerminalGetReadersParams {
+            device_type: Some(device_type).filter(|v| !v.to_string().is_empty()),
+            location: Some(location.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_readers_with(&params).await
+    }
 
-            has_more = resp.has_more;
+    /**
+     * This function performs a `GET` to the `/v1/terminal/readers` endpoint.
+     *
+     * As opposed to `get_readers`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * <p>Returns a list of <code>Reader</code> objects.</p>
+     */
+    pub fn get_readers_stream(
+        &self,
+        params: &TerminalGetReadersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::TerminalReader> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(device_type) = &params.device_type {
+            query_args.push(("device_type".to_string(), device_type.to_string()));
         }
+        if let Some(expand) = &params.expand {
+            for v in expand {
+                query_args.push(("expand[]".to_string(), v.to_string()));
+            }
+        }
+        if let Some(location) = &params.location {
+            query_args.push(("location".to_string(), location.to_string()));
+        }
+        if let Some(status) = &params.status {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/terminal/readers?{}", query_);
 
-        // Return our response data.
-        Ok(data.to_vec())
+        crate::pagination::list(
+            self.client.clone(),
+            url,
+            crate::pagination::ListParams::new(
+                params.starting_after.as_deref(),
+                params.ending_before.as_deref(),
+                params.limit,
+            )
+            .resume(start),
+            max_items,
+            |item: &crate::types::TerminalReader| Some(item.id.clone()),
+        )
     }
 
     /**
      * This function performs a `POST` to the `/v1/terminal/readers` endpoint.
      *
      * <p>Creates a new <code>Reader</code> object.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_reader(&self) -> Result<crate::types::TerminalReader> {
+    pub async fn post_reader<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::TerminalReader> {
         let url = "/v1/terminal/readers".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -339,17 +501,25 @@ impl Terminal {
      * **Parameters:**
      *
      * * `reader: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_readers_reader(
+    pub async fn post_readers_reader<B: serde::Serialize>(
         &self,
         reader: &str,
+        body: &B,
     ) -> Result<crate::types::GetTerminalReadersReaderResponseAnyOf> {
         let url = format!(
             "/v1/terminal/readers/{}",
             crate::progenitor_support::encode_path(reader),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -381,17 +551,25 @@ impl Terminal {
      * **Parameters:**
      *
      * * `reader: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_readers_reader_cancel_action(
+    pub async fn post_readers_reader_cancel_action<B: serde::Serialize>(
         &self,
         reader: &str,
+        body: &B,
     ) -> Result<crate::types::TerminalReader> {
         let url = format!(
             "/v1/terminal/readers/{}/cancel_action",
             crate::progenitor_support::encode_path(reader),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -402,17 +580,25 @@ impl Terminal {
      * **Parameters:**
      *
      * * `reader: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_readers_reader_process_payment_intent(
+    pub async fn post_readers_reader_process_payment_intent<B: serde::Serialize>(
         &self,
         reader: &str,
+        body: &B,
     ) -> Result<crate::types::TerminalReader> {
         let url = format!(
             "/v1/terminal/readers/{}/process_payment_intent",
             crate::progenitor_support::encode_path(reader),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -423,17 +609,25 @@ impl Terminal {
      * **Parameters:**
      *
      * * `reader: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_readers_reader_process_setup_intent(
+    pub async fn post_readers_reader_process_setup_intent<B: serde::Serialize>(
         &self,
         reader: &str,
+        body: &B,
     ) -> Result<crate::types::TerminalReader> {
         let url = format!(
             "/v1/terminal/readers/{}/process_setup_intent",
             crate::progenitor_support::encode_path(reader),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -444,16 +638,115 @@ impl Terminal {
      * **Parameters:**
      *
      * * `reader: &str` -- The account's country.
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_readers_reader_set_display(
+    pub async fn post_readers_reader_set_display<B: serde::Serialize>(
         &self,
         reader: &str,
+        body: &B,
     ) -> Result<crate::types::TerminalReader> {
         let url = format!(
             "/v1/terminal/readers/{}/set_reader_display",
             crate::progenitor_support::encode_path(reader),
         );
 
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
+    }
+}
+
+/// The query parameters of `Terminal::get_locations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TerminalGetLocationsParams {
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+}
+
+impl TerminalGetLocationsParams {
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Terminal::get_readers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct TerminalGetReadersParams {
+    /// Type of reader, one of `bbpos_wisepad3`, `stripe_m2`, `bbpos_chipper2x`, `bbpos_wisepos_e`, or `verifone_P400`.
+    pub device_type: Option<crate::types::DeviceType>,
+    /// A cursor for use in pagination. `ending_before` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, starting with `obj_bar`, your subsequent call can include `ending_before=obj_bar` in order to fetch the previous page of the list.
+    pub ending_before: Option<String>,
+    /// Fields that need to be collected to keep the capability enabled. If not collected by `future_requirements[current_deadline]`, these fields will transition to the main `requirements` hash.
+    pub expand: Option<Vec<String>>,
+    /// A limit on the number of objects to be returned. Limit can range between 1 and 100, and the default is 10.
+    pub limit: Option<i64>,
+    /// A location ID to filter the response list to only readers at the specific location.
+    pub location: Option<String>,
+    /// A cursor for use in pagination. `starting_after` is an object ID that defines your place in the list. For instance, if you make a list request and receive 100 objects, ending with `obj_foo`, your subsequent call can include `starting_after=obj_foo` in order to fetch the next page of the list.
+    pub starting_after: Option<String>,
+    /// The type of customer acceptance information included with the Mandate. One of `online` or `offline`.
+    pub status: Option<crate::types::CustomerAcceptanceType>,
+}
+
+impl TerminalGetReadersParams {
+    pub fn device_type(mut self, device_type: crate::types::DeviceType) -> Self {
+        self.device_type = Some(device_type);
+        self
+    }
+
+    pub fn ending_before<T: ToString>(mut self, ending_before: T) -> Self {
+        self.ending_before = Some(ending_before.to_string());
+        self
+    }
+
+    pub fn expand(mut self, expand: Vec<String>) -> Self {
+        self.expand = Some(expand);
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn location<T: ToString>(mut self, location: T) -> Self {
+        self.location = Some(location.to_string());
+        self
+    }
+
+    pub fn starting_after<T: ToString>(mut self, starting_after: T) -> Self {
+        self.starting_after = Some(starting_after.to_string());
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::CustomerAcceptanceType) -> Self {
+        self.status = Some(status);
+        self
     }
 }
@@ -1 +1,195 @@
+const CUSTOMER_REQUEST: &str = r#"{
+  "email": "jenny.rosen@example.com",
+  "metadata": {
+    "order_id": "6735"
+  },
+  "name": "Jenny Rosen",
+  "preferred_locales": [
+    "en",
+    "fr"
+  ]
+}"#;
 
+#[test]
+fn test_encode_form() {
+    let deserialized: crate::types::PostCustomersRequest =
+        serde_json::from_str(CUSTOMER_REQUEST).unwrap();
+    println!("customer request = {:?}", deserialized);
+
+    let encoded = crate::utils::encode_form(&deserialized).unwrap();
+    assert_eq!(
+        encoded,
+        "email=jenny.rosen%40example.com&metadata%5Border_id%5D=6735&name=Jenny+Rosen&\
+         preferred_locales%5B0%5D=en&preferred_locales%5B1%5D=fr"
+    );
+
+    let items = serde_json::json!({
+        "items": [{"price": "price_123", "quantity": 2}],
+    });
+    let encoded = crate::utils::encode_form(&items).unwrap();
+    assert_eq!(
+        encoded,
+        "items%5B0%5D%5Bprice%5D=price_123&items%5B0%5D%5Bquantity%5D=2"
+    );
+}
+
+const CARD_DECLINED_ERROR: &str = r#"{
+  "error": {
+    "code": "card_declined",
+    "decline_code": "generic_decline",
+    "doc_url": "https://stripe.com/docs/error-codes/card-declined",
+    "message": "Your card was declined.",
+    "type": "card_error"
+  }
+}"#;
+
+#[test]
+fn test_error_from_response() {
+    let err = crate::Error::from_response(
+        http::StatusCode::PAYMENT_REQUIRED,
+        Default::default(),
+        CARD_DECLINED_ERROR.as_bytes(),
+    );
+    println!("error = {}", err);
+
+    assert_eq!(err.status(), Some(http::StatusCode::PAYMENT_REQUIRED));
+    match err {
+        crate::Error::Http { error, .. } => {
+            let error = error.unwrap().error;
+            assert_eq!(error.code, "card_declined");
+            assert_eq!(error.type_, Some(crate::types::ApiErrorsType::CardError));
+        }
+        e => panic!("expected an http error, got {:?}", e),
+    }
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert(
+        http::header::RETRY_AFTER,
+        reqwest::header::HeaderValue::from_static("30"),
+    );
+    let err = crate::Error::from_response(http::StatusCode::TOO_MANY_REQUESTS, headers, b"");
+    assert!(matches!(
+        err,
+        crate::Error::RateLimited { reset: Some(reset) } if reset.as_secs() == 30
+    ));
+}
+
+#[tokio::test]
+async fn test_pages() {
+    use futures::StreamExt;
+
+    // Three pages of two items, where the cursor of a page is its number.
+    let fetch = |page: Option<String>| async move {
+        let i: usize = page.map(|p| p.parse().unwrap()).unwrap_or_default();
+        let next = if i < 2 {
+            Some((i + 1).to_string())
+        } else {
+            None
+        };
+        Ok(crate::pagination::Page::new(vec![i * 2, i * 2 + 1], next))
+    };
+
+    let pages = crate::pagination::Pages::new(None, None, fetch);
+    let items: Vec<usize> = pages.map(|item| item.unwrap()).collect().await;
+    assert_eq!(items, vec![0, 1, 2, 3, 4, 5]);
+
+    let mut pages = crate::pagination::Pages::new(None, Some(3), fetch);
+    let mut items = Vec::new();
+    while let Some(item) = pages.next().await {
+        items.push(item.unwrap());
+    }
+    assert_eq!(items, vec![0, 1, 2]);
+    // We stopped in the middle of the second page, so we resume at it.
+    let start = match pages.cursor() {
+        crate::pagination::Cursor::At(start) => start,
+        crate::pagination::Cursor::Done => panic!("the stream stopped before the last page"),
+    };
+    assert_eq!(start, Some("1".to_string()));
+
+    let mut pages = crate::pagination::Pages::new(start, None, fetch);
+    let items: Vec<usize> = pages.by_ref().map(|item| item.unwrap()).collect().await;
+    assert_eq!(items, vec![2, 3, 4, 5]);
+    assert_eq!(pages.cursor(), crate::pagination::Cursor::Done);
+}
+
+#[test]
+fn test_range_query() {
+    let created = crate::pagination::RangeQuery::default()
+        .gte(1640995200)
+        .lt(1643673600);
+    assert_eq!(
+        created.to_query("created"),
+        vec![
+            ("created[gte]".to_string(), "1640995200".to_string()),
+            ("created[lt]".to_string(), "1643673600".to_string()),
+        ]
+    );
+
+    let query = serde_urlencoded::to_string(created.to_query("created")).unwrap();
+    assert_eq!(
+        query,
+        "created%5Bgte%5D=1640995200&created%5Blt%5D=1643673600"
+    );
+
+    assert!(crate::pagination::RangeQuery::default()
+        .to_query("created")
+        .is_empty());
+
+    // The deprecated functions take a single timestamp.
+    assert_eq!(
+        crate::pagination::RangeQuery::parse("1640995200"),
+        Some(
+            crate::pagination::RangeQuery::default()
+                .gte(1640995200)
+                .lte(1640995200)
+        )
+    );
+    assert_eq!(crate::pagination::RangeQuery::parse(""), None);
+}
+
+#[test]
+fn test_metadata() {
+    let customer: crate::types::Customer = serde_json::from_str(
+        r#"{"id": "cus_123", "object": "customer", "metadata": {"order_id": "6735"}}"#,
+    )
+    .unwrap();
+    assert_eq!(customer.metadata.get("order_id").unwrap(), "6735");
+
+    let customer: crate::types::Customer =
+        serde_json::from_str(r#"{"id": "cus_123", "metadata": null}"#).unwrap();
+    assert!(customer.metadata.is_empty());
+}
+
+#[test]
+fn test_any_of_object() {
+    // The `object` of each item picks its type, where any of them would parse.
+    let data: Vec<crate::types::DataAnyOf> = serde_json::from_str(
+        r#"[
+            {"id": "ba_123", "object": "bank_account", "bank_name": "STRIPE TEST BANK"},
+            {"id": "card_123", "object": "card", "brand": "Visa"}
+        ]"#,
+    )
+    .unwrap();
+    assert_eq!(
+        data[0].bank_account().unwrap().bank_name,
+        "STRIPE TEST BANK"
+    );
+    assert_eq!(data[1].card().unwrap().brand, "Visa");
+    assert_eq!(serde_json::to_value(&data[1]).unwrap()["object"], "card");
+
+    // Objects that are not expanded are sent as their ID.
+    let charge: crate::types::ChargeAnyOf = serde_json::from_str(r#""ch_123""#).unwrap();
+    assert_eq!(
+        charge,
+        crate::types::ChargeAnyOf::String("ch_123".to_string())
+    );
+
+    let err =
+        serde_json::from_str::<crate::types::DataAnyOf>(r#"{"id": "src_123", "object": "source"}"#)
+            .unwrap_err();
+    assert!(
+        err.to_string().contains("unknown variant `source`"),
+        "{}",
+        err
+    );
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ThreeDSecure {
     pub client: Client,
@@ -16,10 +14,23 @@ impl ThreeDSecure {
      * This function performs a `POST` to the `/v1/3d_secure` endpoint.
      *
      * <p>Initiate 3D Secure authentication.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post_3d_secure(&self) -> Result<crate::types::ThreeDSecure> {
+    pub async fn post_3d_secure<B: serde::Serialize>(
+        &self,
+        body: &B,
+    ) -> Result<crate::types::ThreeDSecure> {
         let url = "/v1/3d_secure".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
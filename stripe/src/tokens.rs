@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Tokens {
     pub client: Client,
@@ -17,10 +15,20 @@ impl Tokens {
      *
      * <p>Creates a single-use token that represents a bank account’s details.
      * This token can be used with any API method in place of a bank account dictionary. This token can be used only once, by attaching it to a <a href="#accounts">Custom account</a>.</p>
+     *
+     * **Parameters:**
+     *
+     * * `body: &B` -- The form parameters of the request, encoded the way Stripe expects them.
      */
-    pub async fn post(&self) -> Result<crate::types::Token> {
+    pub async fn post<B: serde::Serialize>(&self, body: &B) -> Result<crate::types::Token> {
         let url = "/v1/tokens".to_string();
-        self.client.post(&url, None).await
+        self.client
+            .request_form(
+                reqwest::Method::POST,
+                &url,
+                crate::utils::encode_form(body)?,
+            )
+            .await
     }
 
     /**
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct BookingData {
     pub client: Client,
@@ -21,49 +19,35 @@ impl BookingData {
      *
      * **Parameters:**
      *
-     * * `created_from: &str` -- Filter based on booking created date in epoch seconds.
-     * * `created_to: &str` -- Filter based on booking created date in epoch seconds.
-     * * `start_date_from: &str` -- Filter based on travel start date in epoch seconds.
-     * * `start_date_to: &str` -- Filter based on travel end date in epoch seconds.
-     * * `booking_status: crate::types::BookingStatus` -- Filter based on booking status.
-     * * `page: u64` -- Page cursor for use in pagination.
-     * * `size: i64` -- Number of records returned per page.
-     * * `booking_type: crate::types::BookingType` -- Filter based on Booking type.
+     * * `params: &BookingDataGetBookingReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_booking_report(
+    pub async fn get_booking_report_with(
         &self,
-        created_from: &str,
-        created_to: &str,
-        start_date_from: &str,
-        start_date_to: &str,
-        booking_status: crate::types::BookingStatus,
-        page: u64,
-        size: i64,
-        booking_type: crate::types::BookingType,
+        params: &BookingDataGetBookingReportParams,
     ) -> Result<Vec<crate::types::BookingReport>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !booking_status.to_string().is_empty() {
+        if let Some(booking_status) = &params.booking_status {
             query_args.push(("bookingStatus".to_string(), booking_status.to_string()));
         }
-        if !booking_type.to_string().is_empty() {
+        if let Some(booking_type) = &params.booking_type {
             query_args.push(("bookingType".to_string(), booking_type.to_string()));
         }
-        if !created_from.is_empty() {
+        if let Some(created_from) = &params.created_from {
             query_args.push(("createdFrom".to_string(), created_from.to_string()));
         }
-        if !created_to.is_empty() {
+        if let Some(created_to) = &params.created_to {
             query_args.push(("createdTo".to_string(), created_to.to_string()));
         }
-        if !page.to_string().is_empty() {
+        if let Some(page) = &params.page {
             query_args.push(("page".to_string(), page.to_string()));
         }
-        if size > 0 {
+        if let Some(size) = &params.size {
             query_args.push(("size".to_string(), size.to_string()));
         }
-        if !start_date_from.is_empty() {
+        if let Some(start_date_from) = &params.start_date_from {
             query_args.push(("startDateFrom".to_string(), start_date_from.to_string()));
         }
-        if !start_date_to.is_empty() {
+        if let Some(start_date_to) = &params.start_date_to {
             query_args.push(("startDateTo".to_string(), start_date_to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -75,6 +59,34 @@ impl BookingData {
         Ok(resp.data.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_booking_report_with` and pass the query parameters as a `BookingDataGetBookingReportParams` instead.
+     */
+    #[deprecated(note = "use `get_booking_report_with` instead")]
+    pub async fn get_booking_report(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        page: u64,
+        size: i64,
+        booking_type: crate::types::BookingType,
+    ) -> Result<Vec<crate::types::BookingReport>> {
+        let params = BookingDataGetBookingReportParams {
+            booking_status: Some(booking_status).filter(|v| !v.to_string().is_empty()),
+            booking_type: Some(booking_type).filter(|v| !v.to_string().is_empty()),
+            created_from: Some(created_from.to_string()).filter(|s| !s.is_empty()),
+            created_to: Some(created_to.to_string()).filter(|s| !s.is_empty()),
+            page: Some(page).filter(|v| !v.to_string().is_empty()),
+            size: Some(size).filter(|v| *v > 0),
+            start_date_from: Some(start_date_from.to_string()).filter(|s| !s.is_empty()),
+            start_date_to: Some(start_date_to.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_booking_report_with(&params).await
+    }
+
     /**
      * Your company's bookings.
      *
@@ -83,33 +95,32 @@ impl BookingData {
      * As opposed to `get_booking_report`, this function returns all the pages of the request at once.
      *
      * Return booking rows filtered by the parameters you select.
+     *
+     * **Parameters:**
+     *
+     * * `params: &BookingDataGetBookingReportParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_booking_report(
+    pub async fn get_all_booking_report_with(
         &self,
-        created_from: &str,
-        created_to: &str,
-        start_date_from: &str,
-        start_date_to: &str,
-        booking_status: crate::types::BookingStatus,
-        booking_type: crate::types::BookingType,
+        params: &BookingDataGetBookingReportParams,
     ) -> Result<Vec<crate::types::BookingReport>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !booking_status.to_string().is_empty() {
+        if let Some(booking_status) = &params.booking_status {
             query_args.push(("bookingStatus".to_string(), booking_status.to_string()));
         }
-        if !booking_type.to_string().is_empty() {
+        if let Some(booking_type) = &params.booking_type {
             query_args.push(("bookingType".to_string(), booking_type.to_string()));
         }
-        if !created_from.is_empty() {
+        if let Some(created_from) = &params.created_from {
             query_args.push(("createdFrom".to_string(), created_from.to_string()));
         }
-        if !created_to.is_empty() {
+        if let Some(created_to) = &params.created_to {
             query_args.push(("createdTo".to_string(), created_to.to_string()));
         }
-        if !start_date_from.is_empty() {
+        if let Some(start_date_from) = &params.start_date_from {
             query_args.push(("startDateFrom".to_string(), start_date_from.to_string()));
         }
-        if !start_date_to.is_empty() {
+        if let Some(start_date_to) = &params.start_date_to {
             query_args.push(("startDateTo".to_string(), start_date_to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -150,4 +161,205 @@ impl BookingData {
         // Return our response data.
         Ok(data)
     }
+
+    /**
+     * Deprecated: use `get_all_booking_report_with` and pass the query parameters as a `BookingDataGetBookingReportParams` instead.
+     */
+    #[deprecated(note = "use `get_all_booking_report_with` instead")]
+    pub async fn get_all_booking_report(
+        &self,
+        created_from: &str,
+        created_to: &str,
+        start_date_from: &str,
+        start_date_to: &str,
+        booking_status: crate::types::BookingStatus,
+        booking_type: crate::types::BookingType,
+    ) -> Result<Vec<crate::types::BookingReport>> {
+        let params = BookingDataGetBookingReportParams {
+            booking_status: Some(booking_status).filter(|v| !v.to_string().is_empty()),
+            booking_type: Some(booking_type).filter(|v| !v.to_string().is_empty()),
+            created_from: Some(created_from.to_string()).filter(|s| !s.is_empty()),
+            created_to: Some(created_to.to_string()).filter(|s| !s.is_empty()),
+            start_date_from: Some(start_date_from.to_string()).filter(|s| !s.is_empty()),
+            start_date_to: Some(start_date_to.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_booking_report_with(&params).await
+    }
+
+    /**
+     * Your company's bookings.
+     *
+     * This function performs a `GET` to the `/v1/bookings` endpoint.
+     *
+     * As opposed to `get_all_booking_report`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Return booking rows filtered by the parameters you select.
+     */
+    pub fn get_booking_report_stream(
+        &self,
+        params: &BookingDataGetBookingReportParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BookingReport> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(booking_status) = &params.booking_status {
+            query_args.push(("bookingStatus".to_string(), booking_status.to_string()));
+        }
+        if let Some(booking_type) = &params.booking_type {
+            query_args.push(("bookingType".to_string(), booking_type.to_string()));
+        }
+        if let Some(created_from) = &params.created_from {
+            query_args.push(("createdFrom".to_string(), created_from.to_string()));
+        }
+        if let Some(created_to) = &params.created_to {
+            query_args.push(("createdTo".to_string(), created_to.to_string()));
+        }
+        if let Some(start_date_from) = &params.start_date_from {
+            query_args.push(("startDateFrom".to_string(), start_date_from.to_string()));
+        }
+        if let Some(start_date_to) = &params.start_date_to {
+            query_args.push(("startDateTo".to_string(), start_date_to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/v1/bookings?{}", query_);
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                // The cursor of the next page is its number.
+                let url =
+                    crate::pagination::with_query(&url, "page", page.as_deref().unwrap_or("0"));
+                let url = crate::pagination::with_query(&url, "size", "100");
+                let resp: crate::types::BookingReportResponse = client.get(&url, None).await?;
+
+                let next = resp.page.current_page + 1;
+                Ok(crate::pagination::Page::new(
+                    resp.data,
+                    if next < resp.page.total_pages {
+                        Some(next.to_string())
+                    } else {
+                        None
+                    },
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `BookingData::get_booking_report_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BookingDataGetBookingReportParams {
+    /// Filter based on booking status.
+    pub booking_status: Option<crate::types::BookingStatus>,
+    /// Filter based on Booking type.
+    pub booking_type: Option<crate::types::BookingType>,
+    /// Filter based on booking created date in epoch seconds.
+    pub created_from: Option<String>,
+    /// Filter based on booking created date in epoch seconds.
+    pub created_to: Option<String>,
+    /// Page cursor for use in pagination.
+    pub page: Option<u64>,
+    /// Number of records returned per page.
+    pub size: Option<i64>,
+    /// Filter based on travel start date in epoch seconds.
+    pub start_date_from: Option<String>,
+    /// Filter based on travel end date in epoch seconds.
+    pub start_date_to: Option<String>,
+}
+
+impl BookingDataGetBookingReportParams {
+    pub fn booking_status(mut self, booking_status: crate::types::BookingStatus) -> Self {
+        self.booking_status = Some(booking_status);
+        self
+    }
+
+    pub fn booking_type(mut self, booking_type: crate::types::BookingType) -> Self {
+        self.booking_type = Some(booking_type);
+        self
+    }
+
+    pub fn created_from<T: ToString>(mut self, created_from: T) -> Self {
+        self.created_from = Some(created_from.to_string());
+        self
+    }
+
+    pub fn created_to<T: ToString>(mut self, created_to: T) -> Self {
+        self.created_to = Some(created_to.to_string());
+        self
+    }
+
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn size(mut self, size: i64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn start_date_from<T: ToString>(mut self, start_date_from: T) -> Self {
+        self.start_date_from = Some(start_date_from.to_string());
+        self
+    }
+
+    pub fn start_date_to<T: ToString>(mut self, start_date_to: T) -> Self {
+        self.start_date_to = Some(start_date_to.to_string());
+        self
+    }
+}
+
+/// The functions of [`BookingData`], for code that takes them as a trait, so that it can be
+/// tested with the `MockBookingDataApi` of the `mock` feature rather than a server.
+#[cfg_attr(any(test, feature = "mock"), mockall::automock)]
+#[async_trait::async_trait]
+pub trait BookingDataApi: Send + Sync {
+    /// See [`BookingData::get_booking_report_with`].
+    async fn get_booking_report_with(
+        &self,
+        params: &BookingDataGetBookingReportParams,
+    ) -> Result<Vec<crate::types::BookingReport>>;
+
+    /// See [`BookingData::get_all_booking_report_with`].
+    async fn get_all_booking_report_with(
+        &self,
+        params: &BookingDataGetBookingReportParams,
+    ) -> Result<Vec<crate::types::BookingReport>>;
+
+    /// See [`BookingData::get_booking_report_stream`].
+    fn get_booking_report_stream(
+        &self,
+        params: &BookingDataGetBookingReportParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BookingReport>;
+}
+
+#[async_trait::async_trait]
+impl BookingDataApi for BookingData {
+    async fn get_booking_report_with(
+        &self,
+        params: &BookingDataGetBookingReportParams,
+    ) -> Result<Vec<crate::types::BookingReport>> {
+        BookingData::get_booking_report_with(self, params).await
+    }
+
+    async fn get_all_booking_report_with(
+        &self,
+        params: &BookingDataGetBookingReportParams,
+    ) -> Result<Vec<crate::types::BookingReport>> {
+        BookingData::get_all_booking_report_with(self, params).await
+    }
+
+    fn get_booking_report_stream(
+        &self,
+        params: &BookingDataGetBookingReportParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BookingReport> {
+        BookingData::get_booking_report_stream(self, params, start, max_items)
+    }
 }
@@ -0,0 +1,1229 @@
+//! The data types of the `booking_data` operations.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use super::*;
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum TravelerType {
+    #[serde(rename = "GUEST")]
+    Guest,
+    #[serde(rename = "PASSENGER")]
+    Passenger,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for TravelerType {
+    fn as_ref(&self) -> &str {
+        match self {
+            TravelerType::Guest => "GUEST",
+            TravelerType::Passenger => "PASSENGER",
+            TravelerType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for TravelerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for TravelerType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "GUEST" => TravelerType::Guest,
+            "PASSENGER" => TravelerType::Passenger,
+            _ => TravelerType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for TravelerType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<TravelerType> for String {
+    fn from(v: TravelerType) -> Self {
+        match v {
+            TravelerType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum BookingStatus {
+    #[serde(rename = "ACCEPTED")]
+    Accepted,
+    #[serde(rename = "CANCELED")]
+    Canceled,
+    #[serde(rename = "CONFIRMED")]
+    Confirmed,
+    #[serde(rename = "TICKETED")]
+    Ticketed,
+    #[serde(rename = "VOIDED")]
+    Voided,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for BookingStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            BookingStatus::Accepted => "ACCEPTED",
+            BookingStatus::Canceled => "CANCELED",
+            BookingStatus::Confirmed => "CONFIRMED",
+            BookingStatus::Ticketed => "TICKETED",
+            BookingStatus::Voided => "VOIDED",
+            BookingStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for BookingStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for BookingStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ACCEPTED" => BookingStatus::Accepted,
+            "CANCELED" => BookingStatus::Canceled,
+            "CONFIRMED" => BookingStatus::Confirmed,
+            "TICKETED" => BookingStatus::Ticketed,
+            "VOIDED" => BookingStatus::Voided,
+            _ => BookingStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for BookingStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<BookingStatus> for String {
+    fn from(v: BookingStatus) -> Self {
+        match v {
+            BookingStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum BookingType {
+    #[serde(rename = "BLACK_CAR")]
+    BlackCar,
+    #[serde(rename = "CAR")]
+    Car,
+    #[serde(rename = "FLIGHT")]
+    Flight,
+    #[serde(rename = "HOTEL")]
+    Hotel,
+    #[serde(rename = "RAIL")]
+    Rail,
+    #[serde(rename = "TRANSPORTATION")]
+    Transportation,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for BookingType {
+    fn as_ref(&self) -> &str {
+        match self {
+            BookingType::BlackCar => "BLACK_CAR",
+            BookingType::Car => "CAR",
+            BookingType::Flight => "FLIGHT",
+            BookingType::Hotel => "HOTEL",
+            BookingType::Rail => "RAIL",
+            BookingType::Transportation => "TRANSPORTATION",
+            BookingType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for BookingType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for BookingType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "BLACK_CAR" => BookingType::BlackCar,
+            "CAR" => BookingType::Car,
+            "FLIGHT" => BookingType::Flight,
+            "HOTEL" => BookingType::Hotel,
+            "RAIL" => BookingType::Rail,
+            "TRANSPORTATION" => BookingType::Transportation,
+            _ => BookingType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for BookingType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<BookingType> for String {
+    fn from(v: BookingType) -> Self {
+        match v {
+            BookingType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PaymentSchedule {
+    #[serde(rename = "LATER")]
+    Later,
+    #[serde(rename = "NOW")]
+    Now,
+    #[serde(rename = "PARTIAL")]
+    Partial,
+    #[serde(rename = "UNKNOWN")]
+    UnknownValue,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PaymentSchedule {
+    fn as_ref(&self) -> &str {
+        match self {
+            PaymentSchedule::Later => "LATER",
+            PaymentSchedule::Now => "NOW",
+            PaymentSchedule::Partial => "PARTIAL",
+            PaymentSchedule::UnknownValue => "UNKNOWN",
+            PaymentSchedule::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PaymentSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PaymentSchedule {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "LATER" => PaymentSchedule::Later,
+            "NOW" => PaymentSchedule::Now,
+            "PARTIAL" => PaymentSchedule::Partial,
+            "UNKNOWN" => PaymentSchedule::UnknownValue,
+            _ => PaymentSchedule::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PaymentSchedule {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PaymentSchedule> for String {
+    fn from(v: PaymentSchedule) -> Self {
+        match v {
+            PaymentSchedule::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
+pub enum PassengerStatus {
+    #[serde(rename = "DISABLED")]
+    Disabled,
+    #[serde(rename = "ENABLED")]
+    Enabled,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str> for PassengerStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            PassengerStatus::Disabled => "DISABLED",
+            PassengerStatus::Enabled => "ENABLED",
+            PassengerStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PassengerStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PassengerStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DISABLED" => PassengerStatus::Disabled,
+            "ENABLED" => PassengerStatus::Enabled,
+            _ => PassengerStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PassengerStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PassengerStatus> for String {
+    fn from(v: PassengerStatus) -> Self {
+        match v {
+            PassengerStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Location {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "airportCode"
+    )]
+    pub airport_code: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub city: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub country: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub state: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Segment {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "airlineAlliance"
+    )]
+    pub airline_alliance: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arrival: Option<Location>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub departure: Option<Location>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "endTimestamp"
+    )]
+    pub end_timestamp: Option<serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "flightNumber"
+    )]
+    pub flight_number: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "hotelChain"
+    )]
+    pub hotel_chain: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "providerCode"
+    )]
+    pub provider_code: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "providerName"
+    )]
+    pub provider_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "startTimestamp"
+    )]
+    pub start_timestamp: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Person {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "billableEntity"
+    )]
+    pub billable_entity: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "costCenter"
+    )]
+    pub cost_center: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub department: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "employeedId"
+    )]
+    pub employeed_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "managerEmail"
+    )]
+    pub manager_email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "managerName"
+    )]
+    pub manager_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "managerUuid"
+    )]
+    pub manager_uuid: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub region: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub subsidiary: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uuid: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Passenger {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub person: Option<Person>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<PassengerStatus>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "travelerType"
+    )]
+    pub traveler_type: Option<TravelerType>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Property {
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BookingReportResponse {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub data: Vec<BookingReport>,
+    #[serde(default)]
+    pub page: Page,
+}
+
+#[derive(Serialize, Default, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Page {
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "currentPage"
+    )]
+    pub current_page: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "pageSize"
+    )]
+    pub page_size: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "totalElements"
+    )]
+    pub total_elements: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "totalPages"
+    )]
+    pub total_pages: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct Cnr {
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "cnrCodes"
+    )]
+    pub cnr_codes: Vec<String>,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "publishedPrice"
+    )]
+    pub published_price: Option<crate::types::DecimalNumber>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BookingReport {
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "airlineCreditCardSurcharge"
+    )]
+    pub airline_credit_card_surcharge: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "approvalChangedAt"
+    )]
+    pub approval_changed_at: Option<serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "approverEmail"
+    )]
+    pub approver_email: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "approverReason"
+    )]
+    pub approver_reason: String,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "basePrice")]
+    pub base_price: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "billToClient"
+    )]
+    pub bill_to_client: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub booker: Option<Person>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "bookingDuration"
+    )]
+    pub booking_duration: i64,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "bookingFee"
+    )]
+    pub booking_fee: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "bookingId"
+    )]
+    pub booking_id: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "bookingMethod"
+    )]
+    pub booking_method: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "bookingStatus"
+    )]
+    pub booking_status: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "bookingType"
+    )]
+    pub booking_type: Option<BookingType>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub cabin: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "cancellationReason"
+    )]
+    pub cancellation_reason: String,
+    /**
+     * Time at which the object was created.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "cancelledAt"
+    )]
+    pub cancelled_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "carbonEmissions"
+    )]
+    pub carbon_emissions: Option<serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "carbonOffsetCost"
+    )]
+    pub carbon_offset_cost: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cnr: Option<Cnr>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "companyOffice"
+    )]
+    pub company_office: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "companyPaymentMethod"
+    )]
+    pub company_payment_method: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "confirmationNumber"
+    )]
+    pub confirmation_number: Option<serde_json::Value>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "corporateDiscountUsed"
+    )]
+    pub corporate_discount_used: String,
+    /**
+     * Time at which the object was created.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub created: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub currency: String,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
+        rename = "currencyExhangeRateFromUsd"
+    )]
+    pub currency_exhange_rate_from_usd: f64,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "customFields"
+    )]
+    pub custom_fields: Vec<Property>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub destination: Option<Location>,
+    /**
+     * Local date when the booking starts, e.g. checkin date for hotel, date of depart for flight
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize",
+        rename = "endDate"
+    )]
+    pub end_date: Option<chrono::NaiveDate>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub etickets: Vec<String>,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "exchangeAmount"
+    )]
+    pub exchange_amount: Option<crate::types::DecimalNumber>,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "exchangeFee"
+    )]
+    pub exchange_fee: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize"
+    )]
+    pub expensed: bool,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "extrasFees"
+    )]
+    pub extras_fees: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "fareClass"
+    )]
+    pub fare_class: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub flight: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "flightMiles"
+    )]
+    pub flight_miles: Option<serde_json::Value>,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "grandTotal"
+    )]
+    pub grand_total: Option<crate::types::DecimalNumber>,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gst: Option<crate::types::DecimalNumber>,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hst: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub inventory: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub invoice: String,
+    /**
+     * Time at which the object was created.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize",
+        rename = "lastModified"
+    )]
+    pub last_modified: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize",
+        rename = "leadTimeInDays"
+    )]
+    pub lead_time_in_days: i64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "nameOnCreditCard"
+    )]
+    pub name_on_credit_card: String,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "netCharge")]
+    pub net_charge: Option<crate::types::DecimalNumber>,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "optimalPrice"
+    )]
+    pub optimal_price: Option<crate::types::DecimalNumber>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<Location>,
+    #[serde(
+        default,
+        deserialize_with = "crate::utils::deserialize_null_boolean::deserialize",
+        rename = "outOfPolicy"
+    )]
+    pub out_of_policy: bool,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "outOfPolicyDescription"
+    )]
+    pub out_of_policy_description: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "outOfPolicyViolationTypes"
+    )]
+    pub out_of_policy_violation_types: Vec<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "outOfPolicyViolations"
+    )]
+    pub out_of_policy_violations: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub passengers: Vec<Passenger>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "paymentCreditCardTypeName"
+    )]
+    pub payment_credit_card_type_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "paymentMethod"
+    )]
+    pub payment_method: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "paymentMethodUsed"
+    )]
+    pub payment_method_used: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "paymentSchedule"
+    )]
+    pub payment_schedule: Option<PaymentSchedule>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub pcc: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub pdf: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "preferredVendor"
+    )]
+    pub preferred_vendor: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub projects: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub purpose: String,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub qst: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub reason: String,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "resortFee")]
+    pub resort_fee: Option<crate::types::DecimalNumber>,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub saving: Option<crate::types::DecimalNumber>,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "savingMissed"
+    )]
+    pub saving_missed: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub seats: Vec<String>,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "seatsFee")]
+    pub seats_fee: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub segments: Vec<Segment>,
+    /**
+     * Local date when the booking starts, e.g. checkin date for hotel, date of depart for flight
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_format::deserialize",
+        rename = "startDate"
+    )]
+    pub start_date: Option<chrono::NaiveDate>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "statementDescription"
+    )]
+    pub statement_description: String,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tax: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "trainMiles"
+    )]
+    pub train_miles: Option<serde_json::Value>,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "travelSpend"
+    )]
+    pub travel_spend: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
+        rename = "tripBucksEarned"
+    )]
+    pub trip_bucks_earned: f64,
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_f64",
+        deserialize_with = "crate::utils::deserialize_null_f64::deserialize",
+        rename = "tripBucksEarnedUsd"
+    )]
+    pub trip_bucks_earned_usd: f64,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "tripDescription"
+    )]
+    pub trip_description: String,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "tripFee")]
+    pub trip_fee: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "tripLength"
+    )]
+    pub trip_length: String,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize",
+        rename = "tripName"
+    )]
+    pub trip_name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize",
+        rename = "tripUuids"
+    )]
+    pub trip_uuids: Vec<String>,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "unitaryPrice"
+    )]
+    pub unitary_price: Option<crate::types::DecimalNumber>,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "usdGrandTotal"
+    )]
+    pub usd_grand_total: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uuid: String,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vat: Option<crate::types::DecimalNumber>,
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub vendor: String,
+    #[cfg_attr(
+        feature = "decimal",
+        serde(with = "rust_decimal::serde::float_option"),
+        schemars(with = "Option<f64>")
+    )]
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "vipFee")]
+    pub vip_fee: Option<crate::types::DecimalNumber>,
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Accounts {
     pub client: Client,
@@ -29,27 +27,17 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `page_number: i64` --
-     *   **Deprecated** - This field has been deprecated and we will stop supporting it completely in a future release. Please use "next_page_token" for pagination instead of this field.
-     *   
-     *   The page number of the current page in the returned records.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &AccountsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
-        &self,
-        page_size: i64,
-        page_number: i64,
-        next_page_token: &str,
-    ) -> Result<crate::types::Domains> {
+    pub async fn get_with(&self, params: &AccountsGetParams) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_number > 0 {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -58,6 +46,24 @@ impl Accounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `AccountsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = AccountsGetParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_number: Some(page_number).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.get_with(&params).await
+    }
+
     /**
      * Create a sub account.
      *
@@ -199,31 +205,21 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- User's first name.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
-     *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
-     *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
-     *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
-     * * `custom_query_fields: &str` -- The name of the field by which to filter the response. For example, if you provide the `host_video` value for this field, you will get a response similar to the following:
-     *   
-     *   `{ "schedule_meeting": { "host_video": false    } }`
-     *   
-     *   To use multiple values, comma-separate each value. For example: `host_video,participant_video`.
+     * * `params: &AccountsSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn settings_domains(
+    pub async fn settings_domains_with(
         &self,
         account_id: &str,
-        option: crate::types::OptionData,
-        custom_query_fields: &str,
+        params: &AccountsSettingParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -236,6 +232,23 @@ impl Accounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `settings_domains_with` and pass the query parameters as a `AccountsSettingParams` instead.
+     */
+    #[deprecated(note = "use `settings_domains_with` instead")]
+    pub async fn settings_domains(
+        &self,
+        account_id: &str,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = AccountsSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.settings_domains_with(account_id, &params).await
+    }
+
     /**
      * Get settings.
      *
@@ -253,31 +266,21 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- User's first name.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
-     *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
-     *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
-     *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
-     * * `custom_query_fields: &str` -- The name of the field by which to filter the response. For example, if you provide the `host_video` value for this field, you will get a response similar to the following:
-     *   
-     *   `{ "schedule_meeting": { "host_video": false    } }`
-     *   
-     *   To use multiple values, comma-separate each value. For example: `host_video,participant_video`.
+     * * `params: &AccountsSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn settings_security(
+    pub async fn settings_security_with(
         &self,
         account_id: &str,
-        option: crate::types::OptionData,
-        custom_query_fields: &str,
+        params: &AccountsSettingParams,
     ) -> Result<crate::types::Security> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -290,6 +293,23 @@ impl Accounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `settings_security_with` and pass the query parameters as a `AccountsSettingParams` instead.
+     */
+    #[deprecated(note = "use `settings_security_with` instead")]
+    pub async fn settings_security(
+        &self,
+        account_id: &str,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::Security> {
+        let params = AccountsSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.settings_security_with(account_id, &params).await
+    }
+
     /**
      * Get settings.
      *
@@ -307,31 +327,21 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- User's first name.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
-     *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
-     *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
-     *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
-     * * `custom_query_fields: &str` -- The name of the field by which to filter the response. For example, if you provide the `host_video` value for this field, you will get a response similar to the following:
-     *   
-     *   `{ "schedule_meeting": { "host_video": false    } }`
-     *   
-     *   To use multiple values, comma-separate each value. For example: `host_video,participant_video`.
+     * * `params: &AccountsSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn settings_account(
+    pub async fn settings_account_with(
         &self,
         account_id: &str,
-        option: crate::types::OptionData,
-        custom_query_fields: &str,
+        params: &AccountsSettingParams,
     ) -> Result<crate::types::AccountSettings> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -344,6 +354,23 @@ impl Accounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `settings_account_with` and pass the query parameters as a `AccountsSettingParams` instead.
+     */
+    #[deprecated(note = "use `settings_account_with` instead")]
+    pub async fn settings_account(
+        &self,
+        account_id: &str,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::AccountSettings> {
+        let params = AccountsSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.settings_account_with(account_id, &params).await
+    }
+
     /**
      * Get settings.
      *
@@ -361,31 +388,21 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- User's first name.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
-     *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
-     *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
-     *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
-     * * `custom_query_fields: &str` -- The name of the field by which to filter the response. For example, if you provide the `host_video` value for this field, you will get a response similar to the following:
-     *   
-     *   `{ "schedule_meeting": { "host_video": false    } }`
-     *   
-     *   To use multiple values, comma-separate each value. For example: `host_video,participant_video`.
+     * * `params: &AccountsSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn settings_meeting_security(
+    pub async fn settings_meeting_security_with(
         &self,
         account_id: &str,
-        option: crate::types::OptionData,
-        custom_query_fields: &str,
+        params: &AccountsSettingParams,
     ) -> Result<crate::types::MeetingSecuritySettings> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -398,6 +415,24 @@ impl Accounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `settings_meeting_security_with` and pass the query parameters as a `AccountsSettingParams` instead.
+     */
+    #[deprecated(note = "use `settings_meeting_security_with` instead")]
+    pub async fn settings_meeting_security(
+        &self,
+        account_id: &str,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::MeetingSecuritySettings> {
+        let params = AccountsSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.settings_meeting_security_with(account_id, &params)
+            .await
+    }
+
     /**
      * Get settings.
      *
@@ -415,31 +450,21 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- User's first name.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
-     *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
-     *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
-     *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
-     * * `custom_query_fields: &str` -- The name of the field by which to filter the response. For example, if you provide the `host_video` value for this field, you will get a response similar to the following:
-     *   
-     *   `{ "schedule_meeting": { "host_video": false    } }`
-     *   
-     *   To use multiple values, comma-separate each value. For example: `host_video,participant_video`.
+     * * `params: &AccountsSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn setting(
+    pub async fn setting_with(
         &self,
         account_id: &str,
-        option: crate::types::OptionData,
-        custom_query_fields: &str,
+        params: &AccountsSettingParams,
     ) -> Result<crate::types::AccountSettingsResponseOneOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -452,6 +477,23 @@ impl Accounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `setting_with` and pass the query parameters as a `AccountsSettingParams` instead.
+     */
+    #[deprecated(note = "use `setting_with` instead")]
+    pub async fn setting(
+        &self,
+        account_id: &str,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::AccountSettingsResponseOneOf> {
+        let params = AccountsSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.setting_with(account_id, &params).await
+    }
+
     /**
      * Update settings.
      *
@@ -467,16 +509,16 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- User's first name.
-     * * `option: crate::types::AccountSettingsUpdateOption`
+     * * `params: &AccountsSettingsUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn settings_update(
+    pub async fn settings_update_with(
         &self,
         account_id: &str,
-        option: crate::types::AccountSettingsUpdateOption,
+        params: &AccountsSettingsUpdateParams,
         body: &crate::types::AccountSettingsUpdateRequestOneOf,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -491,6 +533,22 @@ impl Accounts {
             .await
     }
 
+    /**
+     * Deprecated: use `settings_update_with` and pass the query parameters as a `AccountsSettingsUpdateParams` instead.
+     */
+    #[deprecated(note = "use `settings_update_with` instead")]
+    pub async fn settings_update(
+        &self,
+        account_id: &str,
+        option: crate::types::AccountSettingsUpdateOption,
+        body: &crate::types::AccountSettingsUpdateRequestOneOf,
+    ) -> Result<()> {
+        let params = AccountsSettingsUpdateParams {
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.settings_update_with(account_id, &params, body).await
+    }
+
     /**
      * Get managed domains.
      *
@@ -575,29 +633,21 @@ impl Accounts {
      * **Parameters:**
      *
      * * `account_id: &str` -- Unique Identifier of the account. To retrieve locked settings of the master account or a regular account, provide "me" as the value of this field. <br> To retrieve locked settings of a sub account, provide the Account ID of the sub account in this field.
-     * * `option: &str` -- `meeting_security`: Use this query parameter to view meeting security settings applied on the account.<br>.
-     * * `custom_query_fields: &str` -- Provide the name of the field by which you would like to filter the response. For example, if you provide "host_video" as the value of this field, you will get a response similar to the following:<br>
-     *   {
-     *       "schedule_meeting": {
-     *           "host_video": false
-     *       }
-     *   }
-     *   <br>You can provide multiple values by separating them with commas(example: "host_video,participant_video”).
+     * * `params: &AccountsGetLockSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_lock_setting(
+    pub async fn get_lock_setting_with(
         &self,
         account_id: &str,
-        option: &str,
-        custom_query_fields: &str,
+        params: &AccountsGetLockSettingParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -610,6 +660,23 @@ impl Accounts {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_lock_setting_with` and pass the query parameters as a `AccountsGetLockSettingParams` instead.
+     */
+    #[deprecated(note = "use `get_lock_setting_with` instead")]
+    pub async fn get_lock_setting(
+        &self,
+        account_id: &str,
+        option: &str,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = AccountsGetLockSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_lock_setting_with(account_id, &params).await
+    }
+
     /**
      * Update locked settings.
      *
@@ -720,11 +787,11 @@ impl Accounts {
      *
      * **Parameters:**
      *
-     * * `file_ids: &str` -- Provide the id of the file that is to be deleted. To delete multiple files, provide comma separated values for this field.
+     * * `params: &AccountsDelVbParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn del_vb(&self, account_id: &str, file_ids: &str) -> Result<()> {
+    pub async fn del_vb_with(&self, account_id: &str, params: &AccountsDelVbParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !file_ids.is_empty() {
+        if let Some(file_ids) = &params.file_ids {
             query_args.push(("file_ids".to_string(), file_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -736,4 +803,125 @@ impl Accounts {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Deprecated: use `del_vb_with` and pass the query parameters as a `AccountsDelVbParams` instead.
+     */
+    #[deprecated(note = "use `del_vb_with` instead")]
+    pub async fn del_vb(&self, account_id: &str, file_ids: &str) -> Result<()> {
+        let params = AccountsDelVbParams {
+            file_ids: Some(file_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.del_vb_with(account_id, &params).await
+    }
+}
+
+/// The query parameters of `Accounts::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsGetParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    pub page_number: Option<i64>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl AccountsGetParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `Accounts::setting_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsSettingParams {
+    /// The name of the field by which to filter the response. For example, if you provide the `host_video` value for this field, you will get a response similar to the following:
+    ///
+    /// `{ "schedule_meeting": { "host_video": false    } }`
+    ///
+    /// To use multiple values, comma-separate each value. For example: `host_video,participant_video`.
+    pub custom_query_fields: Option<String>,
+    /// Use the following options to filter the results of the account's information:
+    /// \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
+    /// \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
+    /// \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
+    /// \* `meeting_security` — View the account's meeting security settings.
+    pub option: Option<crate::types::OptionData>,
+}
+
+impl AccountsSettingParams {
+    pub fn custom_query_fields<T: ToString>(mut self, custom_query_fields: T) -> Self {
+        self.custom_query_fields = Some(custom_query_fields.to_string());
+        self
+    }
+
+    pub fn option(mut self, option: crate::types::OptionData) -> Self {
+        self.option = Some(option);
+        self
+    }
+}
+
+/// The query parameters of `Accounts::settings_update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsSettingsUpdateParams {
+    pub option: Option<crate::types::AccountSettingsUpdateOption>,
+}
+
+impl AccountsSettingsUpdateParams {
+    pub fn option(mut self, option: crate::types::AccountSettingsUpdateOption) -> Self {
+        self.option = Some(option);
+        self
+    }
+}
+
+/// The query parameters of `Accounts::get_lock_setting_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsGetLockSettingParams {
+    /// Provide the name of the field by which you would like to filter the response. For example, if you provide "host_video" as the value of this field, you will get a response similar to the following:<br>
+    /// {
+    /// "schedule_meeting": {
+    /// "host_video": false
+    /// }
+    /// }
+    /// <br>You can provide multiple values by separating them with commas(example: "host_video,participant_video”).
+    pub custom_query_fields: Option<String>,
+    /// `meeting_security`: Use this query parameter to view meeting security settings applied on the account.<br>.
+    pub option: Option<String>,
+}
+
+impl AccountsGetLockSettingParams {
+    pub fn custom_query_fields<T: ToString>(mut self, custom_query_fields: T) -> Self {
+        self.custom_query_fields = Some(custom_query_fields.to_string());
+        self
+    }
+
+    pub fn option<T: ToString>(mut self, option: T) -> Self {
+        self.option = Some(option.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Accounts::del_vb_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct AccountsDelVbParams {
+    /// Provide the id of the file that is to be deleted. To delete multiple files, provide comma separated values for this field.
+    pub file_ids: Option<String>,
+}
+
+impl AccountsDelVbParams {
+    pub fn file_ids<T: ToString>(mut self, file_ids: T) -> Self {
+        self.file_ids = Some(file_ids.to_string());
+        self
+    }
 }
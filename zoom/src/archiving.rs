@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Archiving {
     pub client: Client,
@@ -28,34 +26,26 @@ impl Archiving {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `from: &str` -- Start date for the query in "yyyy-MM-dd'T'HH:mm:ss'Z'" format. The duration for the query defined using the "from" and "to" parameters should not exceed 7 days as this API only provides a week's data at once.
-     * * `to: &str` -- End date for the query in "yyyy-MM-dd'T'HH:mm:ss'Z'" format. .
-     * * `query_date_type: crate::types::ListArchivedFilesQueryDateType` -- The query date type for the `from` and `to` parameters.
+     * * `params: &ArchivingListArchivedFilesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_archived_files(
+    pub async fn list_archived_files_with(
         &self,
-        page_size: i64,
-        next_page_token: &str,
-        from: &str,
-        to: &str,
-        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+        params: &ArchivingListArchivedFilesParams,
     ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !query_date_type.to_string().is_empty() {
+        if let Some(query_date_type) = &params.query_date_type {
             query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
         }
-        if !to.is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -67,6 +57,28 @@ impl Archiving {
         Ok(resp.meetings.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_archived_files_with` and pass the query parameters as a `ArchivingListArchivedFilesParams` instead.
+     */
+    #[deprecated(note = "use `list_archived_files_with` instead")]
+    pub async fn list_archived_files(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
+        let params = ArchivingListArchivedFilesParams {
+            from: Some(from.to_string()).filter(|s| !s.is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            query_date_type: Some(query_date_type).filter(|v| !v.to_string().is_empty()),
+            to: Some(to.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_archived_files_with(&params).await
+    }
+
     /**
      * List archived files.
      *
@@ -82,21 +94,23 @@ impl Archiving {
      * **Prerequisites:** <br>
      * * Enable cloud recording.
      * * Follow the [enablement process](https://support.zoom.us/hc/en-us/articles/360050431572-Archiving-Meeting-and-Webinar-data#h_01ENPBD3WR68D7FAKTBY92SG45) to access the archiving feature.
+     *
+     * **Parameters:**
+     *
+     * * `params: &ArchivingListArchivedFilesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_archived_files(
+    pub async fn list_all_archived_files_with(
         &self,
-        from: &str,
-        to: &str,
-        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+        params: &ArchivingListArchivedFilesParams,
     ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !query_date_type.to_string().is_empty() {
+        if let Some(query_date_type) = &params.query_date_type {
             query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
         }
-        if !to.is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -135,6 +149,80 @@ impl Archiving {
         Ok(meetings)
     }
 
+    /**
+     * Deprecated: use `list_all_archived_files_with` and pass the query parameters as a `ArchivingListArchivedFilesParams` instead.
+     */
+    #[deprecated(note = "use `list_all_archived_files_with` instead")]
+    pub async fn list_all_archived_files(
+        &self,
+        from: &str,
+        to: &str,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Result<Vec<crate::types::ListArchivedFilesResponseMeetings>> {
+        let params = ArchivingListArchivedFilesParams {
+            from: Some(from.to_string()).filter(|s| !s.is_empty()),
+            query_date_type: Some(query_date_type).filter(|v| !v.to_string().is_empty()),
+            to: Some(to.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_archived_files_with(&params).await
+    }
+
+    /**
+     * List archived files.
+     *
+     * This function performs a `GET` to the `/archive_files` endpoint.
+     *
+     * As opposed to `list_archived_files`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Zoom’s [archiving solution](https://support.zoom.us/hc/en-us/articles/360050431572-Archiving-Meeting-and-Webinar-data) allows account administrators to set up an automated mechanism to record, collect and archive meeting data to a 3rd party platform of their choice and hence, satisfy FINRA and/ or other compliance requirements.<br><br>
+     * Use this API to retrieve archived meeting or webinar files of an account.
+     *
+     * **Scope:** `recording:read:admin`<br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br><br>
+     * **Prerequisites:** <br>
+     * * Enable cloud recording.
+     * * Follow the [enablement process](https://support.zoom.us/hc/en-us/articles/360050431572-Archiving-Meeting-and-Webinar-data#h_01ENPBD3WR68D7FAKTBY92SG45) to access the archiving feature.
+     */
+    pub fn list_archived_files_stream(
+        &self,
+        params: &ArchivingListArchivedFilesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ListArchivedFilesResponseMeetings> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(from) = &params.from {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(query_date_type) = &params.query_date_type {
+            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+        }
+        if let Some(to) = &params.to {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/archive_files?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListArchivedFilesResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.meetings,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Get meeting archived files.
      *
@@ -166,3 +254,48 @@ impl Archiving {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Archiving::list_archived_files_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ArchivingListArchivedFilesParams {
+    /// Start date for the query in "yyyy-MM-dd'T'HH:mm:ss'Z'" format. The duration for the query defined using the "from" and "to" parameters should not exceed 7 days as this API only provides a week's data at once.
+    pub from: Option<String>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// The query date type for the `from` and `to` parameters.
+    pub query_date_type: Option<crate::types::ListArchivedFilesQueryDateType>,
+    /// End date for the query in "yyyy-MM-dd'T'HH:mm:ss'Z'" format. .
+    pub to: Option<String>,
+}
+
+impl ArchivingListArchivedFilesParams {
+    pub fn from<T: ToString>(mut self, from: T) -> Self {
+        self.from = Some(from.to_string());
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn query_date_type(
+        mut self,
+        query_date_type: crate::types::ListArchivedFilesQueryDateType,
+    ) -> Self {
+        self.query_date_type = Some(query_date_type);
+        self
+    }
+
+    pub fn to<T: ToString>(mut self, to: T) -> Self {
+        self.to = Some(to.to_string());
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Billing {
     pub client: Client,
@@ -340,21 +338,18 @@ impl Billing {
      *
      * **Parameters:**
      *
-     * * `from: chrono::NaiveDate` -- Start date for the invoice query in `yyyy-mm-dd` format. The date range defined by the “from” and “to” parameters should not exceed one year. The range defined should fall within the past three years.
-     *   .
-     * * `to: chrono::NaiveDate` -- End date for the invoice query in `yyyy-mm-dd` format.
+     * * `params: &BillingAccountInvoiceParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn account_invoice(
+    pub async fn account_invoice_with(
         &self,
         account_id: &str,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
+        params: &BillingAccountInvoiceParams,
     ) -> Result<crate::types::AccountBillingInvoicesResponseData> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -367,6 +362,23 @@ impl Billing {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `account_invoice_with` and pass the query parameters as a `BillingAccountInvoiceParams` instead.
+     */
+    #[deprecated(note = "use `account_invoice_with` instead")]
+    pub async fn account_invoice(
+        &self,
+        account_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::AccountBillingInvoicesResponseData> {
+        let params = BillingAccountInvoiceParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.account_invoice_with(account_id, &params).await
+    }
+
     /**
      * Get invoice details.
      *
@@ -415,3 +427,25 @@ impl Billing {
         self.client.get(&url, None).await
     }
 }
+
+/// The query parameters of `Billing::account_invoice_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct BillingAccountInvoiceParams {
+    /// Start date for the invoice query in `yyyy-mm-dd` format. The date range defined by the “from” and “to” parameters should not exceed one year. The range defined should fall within the past three years.
+    /// .
+    pub from: Option<chrono::NaiveDate>,
+    /// End date for the invoice query in `yyyy-mm-dd` format.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl BillingAccountInvoiceParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ChatChannels {
     pub client: Client,
@@ -27,21 +25,19 @@ impl ChatChannels {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- The number of records returned from a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. The expiration period for this token is 15 minutes.
      * * `user_id: &str` -- Unique identifier of the user.
+     * * `params: &ChatChannelsGetChannelsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_channels(
+    pub async fn get_channels_with(
         &self,
         user_id: &str,
-        page_size: i64,
-        next_page_token: &str,
+        params: &ChatChannelsGetChannelsParams,
     ) -> Result<Vec<crate::types::Channels>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -57,6 +53,23 @@ impl ChatChannels {
         Ok(resp.channels.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_channels_with` and pass the query parameters as a `ChatChannelsGetChannelsParams` instead.
+     */
+    #[deprecated(note = "use `get_channels_with` instead")]
+    pub async fn get_channels(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Channels>> {
+        let params = ChatChannelsGetChannelsParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.get_channels_with(user_id, &params).await
+    }
+
     /**
      * List user's channels.
      *
@@ -111,6 +124,51 @@ impl ChatChannels {
         Ok(channels)
     }
 
+    /**
+     * List user's channels.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels` endpoint.
+     *
+     * As opposed to `get_channels`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to list a user's chat channels. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * Zoom chat [channels](https://support.zoom.us/hc/en-us/articles/200912909-Getting-Started-With-Channels-Group-Messaging-) allow users to communicate via chat in private or public groups.
+     *
+     * **Scopes:** `chat_channel:read` or `chat_channel:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> This API supports both user-managed apps and account-level apps. However, in an <b>account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, to list channels of another user in the same Zoom account, the user calling this API must have a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit.">role</a> that has the <b>View</b> or <b>Edit</b> permission for the <b>Chat channels</b> feature.</p>
+     */
+    pub fn get_channels_stream(
+        &self,
+        user_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Channels> {
+        let url = format!(
+            "/chat/users/{}/channels",
+            crate::progenitor_support::encode_path(user_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::GetChannelsResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.channels,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a channel.
      *
@@ -318,3 +376,24 @@ impl ChatChannels {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `ChatChannels::get_channels_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChatChannelsGetChannelsParams {
+    /// The next page token is used to paginate through large result sets. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned from a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl ChatChannelsGetChannelsParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ChatChannelsAccountLevel {
     pub client: Client,
@@ -120,22 +118,20 @@ impl ChatChannelsAccountLevel {
      * **Parameters:**
      *
      * * `channel_id: &str` -- User's first name.
-     * * `page_size: i64` -- The number of records returned with a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
      * * `user_id: &str` -- Unique identifier of the user who is the owner of this channel.
+     * * `params: &ChatChannelsAccountLevelListChannelMembersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_channel_members(
+    pub async fn list_channel_members_with(
         &self,
         user_id: &str,
         channel_id: &str,
-        page_size: i64,
-        next_page_token: &str,
+        params: &ChatChannelsAccountLevelListChannelMembersParams,
     ) -> Result<Vec<crate::types::ListChannelMembersResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -153,6 +149,25 @@ impl ChatChannelsAccountLevel {
         Ok(resp.members.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_channel_members_with` and pass the query parameters as a `ChatChannelsAccountLevelListChannelMembersParams` instead.
+     */
+    #[deprecated(note = "use `list_channel_members_with` instead")]
+    pub async fn list_channel_members(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ListChannelMembersResponse>> {
+        let params = ChatChannelsAccountLevelListChannelMembersParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.list_channel_members_with(user_id, channel_id, &params)
+            .await
+    }
+
     /**
      * List channel members.
      *
@@ -211,6 +226,52 @@ impl ChatChannelsAccountLevel {
         Ok(members)
     }
 
+    /**
+     * List channel members.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/channels/{channelId}/members` endpoint.
+     *
+     * As opposed to `list_channel_members`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to list all members of a channel. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `chat_channel:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> For an<b> account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, this API can only be used on behalf of a user who is assigned with a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit."> role</a> that has the <b>View</b> or <b>Edit</b> permission for <b>Chat Channels</b>.</p>
+     */
+    pub fn list_channel_members_stream(
+        &self,
+        user_id: &str,
+        channel_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ListChannelMembersResponse> {
+        let url = format!(
+            "/chat/users/{}/channels/{}/members",
+            crate::progenitor_support::encode_path(user_id),
+            crate::progenitor_support::encode_path(channel_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListChannelMembersResponseData =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.members,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Invite channel members.
      *
@@ -279,3 +340,24 @@ impl ChatChannelsAccountLevel {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `ChatChannelsAccountLevel::list_channel_members_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChatChannelsAccountLevelListChannelMembersParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned with a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl ChatChannelsAccountLevelListChannelMembersParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
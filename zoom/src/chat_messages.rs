@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ChatMessages {
     pub client: Client,
@@ -21,8 +19,6 @@ impl ChatMessages {
      *
      * In the query parameter, you must provide one of the following:
      *
-     * * `to_contact`: The email address of the contact with whom the user conversed by sending or receiving messages.
-     * * `to_channel`: The channel ID of the channel to or from which the user has sent and/or received messages.
      *
      * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> For an <b>account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, this API can only be used on behalf of a user who is assigned with a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit.">role</a> that has the <b>View</b> or <b>Edit</b> permission for <b>Chat Messages</b>.</p>
      *
@@ -30,48 +26,34 @@ impl ChatMessages {
      *
      * **Parameters:**
      *
-     * * `to_contact: &str` -- The email address of a chat contact with whom the current user chatted. Messages that were sent and/or received between the user and the contact is displayed.
-     *   
-     *   Note: You must provide either `contact` or `channel` as a query parameter to retrieve messages either from an individual or a chat channel. .
-     * * `to_channel: &str` -- The channel Id of a channel inside which the current user had chat conversations. Messages that were sent and/or received between the user and the channel is displayed.
-     *   
-     *   Note: You must provide either `contact` or `channel` as a query parameter to retrieve messages either from an individual or a chat channel. .
-     * * `date: chrono::NaiveDate` -- The query date for which you would like to get the chat messages. This value defaults to the current date.
-     * * `page_size: i64` -- The number of records returned with a single API call. .
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `include_deleted_and_edited_message: &str` -- **Optional** <br>
-     *   Set the value of this field to `true` to include edited and deleted messages in the response.
+     * * `params: &ChatMessagesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
         user_id: &str,
-        to_contact: &str,
-        to_channel: &str,
-        date: chrono::NaiveDate,
-        page_size: i64,
-        next_page_token: &str,
-        include_deleted_and_edited_message: &str,
+        params: &ChatMessagesGetPageParams,
     ) -> Result<Vec<crate::types::Messages>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.to_string().is_empty() {
+        if let Some(date) = &params.date {
             query_args.push(("date".to_string(), date.to_string()));
         }
-        if !include_deleted_and_edited_message.is_empty() {
+        if let Some(include_deleted_and_edited_message) = &params.include_deleted_and_edited_message
+        {
             query_args.push((
                 "include_deleted_and_edited_message".to_string(),
                 include_deleted_and_edited_message.to_string(),
             ));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to_channel.is_empty() {
+        if let Some(to_channel) = &params.to_channel {
             query_args.push(("to_channel".to_string(), to_channel.to_string()));
         }
-        if !to_contact.is_empty() {
+        if let Some(to_contact) = &params.to_contact {
             query_args.push(("to_contact".to_string(), to_contact.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -87,6 +69,34 @@ impl ChatMessages {
         Ok(resp.messages.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `ChatMessagesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        include_deleted_and_edited_message: &str,
+    ) -> Result<Vec<crate::types::Messages>> {
+        let params = ChatMessagesGetPageParams {
+            date: Some(date).filter(|v| !v.to_string().is_empty()),
+            include_deleted_and_edited_message: Some(
+                include_deleted_and_edited_message.to_string(),
+            )
+            .filter(|s| !s.is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to_channel: Some(to_channel.to_string()).filter(|s| !s.is_empty()),
+            to_contact: Some(to_contact.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_page_with(user_id, &params).await
+    }
+
     /**
      * List user's chat messages.
      *
@@ -98,35 +108,35 @@ impl ChatMessages {
      *
      * In the query parameter, you must provide one of the following:
      *
-     * * `to_contact`: The email address of the contact with whom the user conversed by sending or receiving messages.
-     * * `to_channel`: The channel ID of the channel to or from which the user has sent and/or received messages.
      *
      * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> For an <b>account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, this API can only be used on behalf of a user who is assigned with a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit.">role</a> that has the <b>View</b> or <b>Edit</b> permission for <b>Chat Messages</b>.</p>
      *
      * **Scopes:** `chat_message:read`, `chat_message:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Parameters:**
+     *
+     * * `params: &ChatMessagesGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
         user_id: &str,
-        to_contact: &str,
-        to_channel: &str,
-        date: chrono::NaiveDate,
-        include_deleted_and_edited_message: &str,
+        params: &ChatMessagesGetPageParams,
     ) -> Result<Vec<crate::types::Messages>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !date.to_string().is_empty() {
+        if let Some(date) = &params.date {
             query_args.push(("date".to_string(), date.to_string()));
         }
-        if !include_deleted_and_edited_message.is_empty() {
+        if let Some(include_deleted_and_edited_message) = &params.include_deleted_and_edited_message
+        {
             query_args.push((
                 "include_deleted_and_edited_message".to_string(),
                 include_deleted_and_edited_message.to_string(),
             ));
         }
-        if !to_channel.is_empty() {
+        if let Some(to_channel) = &params.to_channel {
             query_args.push(("to_channel".to_string(), to_channel.to_string()));
         }
-        if !to_contact.is_empty() {
+        if let Some(to_contact) = &params.to_contact {
             query_args.push(("to_contact".to_string(), to_contact.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -169,6 +179,100 @@ impl ChatMessages {
         Ok(messages)
     }
 
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `ChatMessagesGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        user_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+        date: chrono::NaiveDate,
+        include_deleted_and_edited_message: &str,
+    ) -> Result<Vec<crate::types::Messages>> {
+        let params = ChatMessagesGetPageParams {
+            date: Some(date).filter(|v| !v.to_string().is_empty()),
+            include_deleted_and_edited_message: Some(
+                include_deleted_and_edited_message.to_string(),
+            )
+            .filter(|s| !s.is_empty()),
+            to_channel: Some(to_channel.to_string()).filter(|s| !s.is_empty()),
+            to_contact: Some(to_contact.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(user_id, &params).await
+    }
+
+    /**
+     * List user's chat messages.
+     *
+     * This function performs a `GET` to the `/chat/users/{userId}/messages` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to list the current user's chat messages between the user and an individual contact or a chat channel. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * In the query parameter, you must provide one of the following:
+     *
+     * * `to_contact`: The email address of the contact with whom the user conversed by sending or receiving messages.
+     * * `to_channel`: The channel ID of the channel to or from which the user has sent and/or received messages.
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note:</b> For an <b>account-level</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>, this API can only be used on behalf of a user who is assigned with a <a href="https://support.zoom.us/hc/en-us/articles/115001078646-Using-role-management#:~:text=Each%20user%20in%20a%20Zoom,owner%2C%20administrator%2C%20or%20member.&text=Role%2Dbased%20access%20control%20enables,needs%20to%20view%20or%20edit.">role</a> that has the <b>View</b> or <b>Edit</b> permission for <b>Chat Messages</b>.</p>
+     *
+     * **Scopes:** `chat_message:read`, `chat_message:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_stream(
+        &self,
+        user_id: &str,
+        params: &ChatMessagesGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Messages> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = &params.date {
+            query_args.push(("date".to_string(), date.to_string()));
+        }
+        if let Some(include_deleted_and_edited_message) = &params.include_deleted_and_edited_message
+        {
+            query_args.push((
+                "include_deleted_and_edited_message".to_string(),
+                include_deleted_and_edited_message.to_string(),
+            ));
+        }
+        if let Some(to_channel) = &params.to_channel {
+            query_args.push(("to_channel".to_string(), to_channel.to_string()));
+        }
+        if let Some(to_contact) = &params.to_contact {
+            query_args.push(("to_contact".to_string(), to_contact.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/chat/users/{}/messages?{}",
+            crate::progenitor_support::encode_path(user_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::GetChatMessagesResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.messages,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Send a chat message.
      *
@@ -271,8 +375,6 @@ impl ChatMessages {
      * Get a chat message previously sent to a contact or a channel. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
      *
      * You must provide one of the following query parameters:<br>
-     * * `to_contact` — The email address of the Zoom contact to whom you sent the message.
-     * * `to_channel` — The ID of the Zoom channel where you sent the message.
      *
      * **Scopes:** `chat_message:read`, `chat_message:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
      *
@@ -281,21 +383,19 @@ impl ChatMessages {
      * **Parameters:**
      *
      * * `message_id: &str` -- User's first name.
-     * * `to_contact: &str` -- The `userId` or email address of a Zoom Chat contact to whom you sent the message.\n\n**Note:** You must use this query parameter to delete a message sent to a Zoom Chat contact. .
-     * * `to_channel: &str` -- The `channelId` of the Zoom Chat channel where sent the message.\n\n**Note:** You must use this query parameter to delete a message sent to Zoom Chat channel.
+     * * `params: &ChatMessagesGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         user_id: &str,
         message_id: &str,
-        to_contact: &str,
-        to_channel: &str,
+        params: &ChatMessagesGetParams,
     ) -> Result<crate::types::GetChatMessageResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !to_channel.is_empty() {
+        if let Some(to_channel) = &params.to_channel {
             query_args.push(("to_channel".to_string(), to_channel.to_string()));
         }
-        if !to_contact.is_empty() {
+        if let Some(to_contact) = &params.to_contact {
             query_args.push(("to_contact".to_string(), to_contact.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -309,6 +409,24 @@ impl ChatMessages {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `ChatMessagesGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+    ) -> Result<crate::types::GetChatMessageResponse> {
+        let params = ChatMessagesGetParams {
+            to_channel: Some(to_channel.to_string()).filter(|s| !s.is_empty()),
+            to_contact: Some(to_contact.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_with(user_id, message_id, &params).await
+    }
+
     /**
      * Update a message.
      *
@@ -353,8 +471,6 @@ impl ChatMessages {
      *
      * In the query parameter, you must provide either of the following:
      *
-     * * `to_contact`: The email address of the contact to whom you sent the message. Use this parameter to delete a message sent to an individual contact in Zoom.
-     * * `to_channel`: The channel ID of the channel where you sent the message. Use this parameter to delete a message sent to a channel in Zoom.
      *
      *
      *
@@ -365,25 +481,19 @@ impl ChatMessages {
      * **Parameters:**
      *
      * * `message_id: &str` -- User's first name.
-     * * `to_contact: &str` -- The userId or email address of a chat contact to whom you previously sent the message.
-     *   
-     *   Note: You must provide either `to_contact` or `to_channel` as a query parameter to delete a message that was previously sent to either an individual or a chat channel respectively. .
-     * * `to_channel: &str` -- The channel Id of the channel where you would like to send the message.
-     *   
-     *   You must provide either `to_contact` or `to_channel` as a query parameter to delete a message that was previously sent to either an individual or a chat channel .
+     * * `params: &ChatMessagesDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete(
+    pub async fn delete_with(
         &self,
         user_id: &str,
         message_id: &str,
-        to_contact: &str,
-        to_channel: &str,
+        params: &ChatMessagesDeleteParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !to_channel.is_empty() {
+        if let Some(to_channel) = &params.to_channel {
             query_args.push(("to_channel".to_string(), to_channel.to_string()));
         }
-        if !to_contact.is_empty() {
+        if let Some(to_contact) = &params.to_contact {
             query_args.push(("to_contact".to_string(), to_contact.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -396,4 +506,126 @@ impl ChatMessages {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Deprecated: use `delete_with` and pass the query parameters as a `ChatMessagesDeleteParams` instead.
+     */
+    #[deprecated(note = "use `delete_with` instead")]
+    pub async fn delete(
+        &self,
+        user_id: &str,
+        message_id: &str,
+        to_contact: &str,
+        to_channel: &str,
+    ) -> Result<()> {
+        let params = ChatMessagesDeleteParams {
+            to_channel: Some(to_channel.to_string()).filter(|s| !s.is_empty()),
+            to_contact: Some(to_contact.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_with(user_id, message_id, &params).await
+    }
+}
+
+/// The query parameters of `ChatMessages::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChatMessagesGetPageParams {
+    /// The query date for which you would like to get the chat messages. This value defaults to the current date.
+    pub date: Option<chrono::NaiveDate>,
+    /// **Optional** <br>
+    /// Set the value of this field to `true` to include edited and deleted messages in the response.
+    pub include_deleted_and_edited_message: Option<String>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned with a single API call. .
+    pub page_size: Option<i64>,
+    /// The channel Id of a channel inside which the current user had chat conversations. Messages that were sent and/or received between the user and the channel is displayed.
+    ///
+    /// Note: You must provide either `contact` or `channel` as a query parameter to retrieve messages either from an individual or a chat channel. .
+    pub to_channel: Option<String>,
+    /// The email address of a chat contact with whom the current user chatted. Messages that were sent and/or received between the user and the contact is displayed.
+    ///
+    /// Note: You must provide either `contact` or `channel` as a query parameter to retrieve messages either from an individual or a chat channel. .
+    pub to_contact: Option<String>,
+}
+
+impl ChatMessagesGetPageParams {
+    pub fn date(mut self, date: chrono::NaiveDate) -> Self {
+        self.date = Some(date);
+        self
+    }
+
+    pub fn include_deleted_and_edited_message<T: ToString>(
+        mut self,
+        include_deleted_and_edited_message: T,
+    ) -> Self {
+        self.include_deleted_and_edited_message =
+            Some(include_deleted_and_edited_message.to_string());
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to_channel<T: ToString>(mut self, to_channel: T) -> Self {
+        self.to_channel = Some(to_channel.to_string());
+        self
+    }
+
+    pub fn to_contact<T: ToString>(mut self, to_contact: T) -> Self {
+        self.to_contact = Some(to_contact.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ChatMessages::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChatMessagesGetParams {
+    /// The `channelId` of the Zoom Chat channel where sent the message.\n\n**Note:** You must use this query parameter to delete a message sent to Zoom Chat channel.
+    pub to_channel: Option<String>,
+    /// The `userId` or email address of a Zoom Chat contact to whom you sent the message.\n\n**Note:** You must use this query parameter to delete a message sent to a Zoom Chat contact. .
+    pub to_contact: Option<String>,
+}
+
+impl ChatMessagesGetParams {
+    pub fn to_channel<T: ToString>(mut self, to_channel: T) -> Self {
+        self.to_channel = Some(to_channel.to_string());
+        self
+    }
+
+    pub fn to_contact<T: ToString>(mut self, to_contact: T) -> Self {
+        self.to_contact = Some(to_contact.to_string());
+        self
+    }
+}
+
+/// The query parameters of `ChatMessages::delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ChatMessagesDeleteParams {
+    /// The channel Id of the channel where you would like to send the message.
+    ///
+    /// You must provide either `to_contact` or `to_channel` as a query parameter to delete a message that was previously sent to either an individual or a chat channel .
+    pub to_channel: Option<String>,
+    /// The userId or email address of a chat contact to whom you previously sent the message.
+    ///
+    /// Note: You must provide either `to_contact` or `to_channel` as a query parameter to delete a message that was previously sent to either an individual or a chat channel respectively. .
+    pub to_contact: Option<String>,
+}
+
+impl ChatMessagesDeleteParams {
+    pub fn to_channel<T: ToString>(mut self, to_channel: T) -> Self {
+        self.to_channel = Some(to_channel.to_string());
+        self
+    }
+
+    pub fn to_contact<T: ToString>(mut self, to_contact: T) -> Self {
+        self.to_contact = Some(to_contact.to_string());
+        self
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CloudRecording {
     pub client: Client,
@@ -32,49 +30,33 @@ impl CloudRecording {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `mc: &str` -- Query Metadata of Recording if an On-Premise Meeting Connector was used for the meeting.
-     * * `trash: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
-     * * `from: chrono::NaiveDate` -- The start date in 'yyyy-mm-dd' UTC format for the date range for which you would like to retrieve recordings. The maximum range can be a month. If no value is provided for this field, the default will be current date. For example, if you make the API request on June 30, 2020, without providing the “from” and “to” parameters, by default the value of 'from' field will be “2020-06-30” and the value of the 'to' field will be “2020-07-01”.
-     *   
-     *   **Note**: The "trash" files cannot be filtered by date range and thus, the "from" and "to" fields should not be used for trash files.
-     * * `to: chrono::NaiveDate` -- End date in 'yyyy-mm-dd' 'yyyy-mm-dd' UTC format. .
-     * * `trash_type: &str` -- The type of Cloud recording that you would like to retrieve from the trash. The value can be one of the following:<br>
-     *       `meeting_recordings`: List all meeting recordings from the trash.<br>
-     *       `recording_file`: List all individual recording files from the trash. .
+     * * `params: &CloudRecordingRecordingsListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recordings_list(
+    pub async fn recordings_list_with(
         &self,
         user_id: &str,
-        page_size: i64,
-        next_page_token: &str,
-        mc: &str,
-        trash: bool,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        trash_type: &str,
+        params: &CloudRecordingRecordingsListParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !mc.is_empty() {
+        if let Some(mc) = &params.mc {
             query_args.push(("mc".to_string(), mc.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
-        if trash {
+        if let Some(trash) = &params.trash {
             query_args.push(("trash".to_string(), trash.to_string()));
         }
-        if !trash_type.is_empty() {
+        if let Some(trash_type) = &params.trash_type {
             query_args.push(("trash_type".to_string(), trash_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -87,6 +69,33 @@ impl CloudRecording {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `recordings_list_with` and pass the query parameters as a `CloudRecordingRecordingsListParams` instead.
+     */
+    #[deprecated(note = "use `recordings_list_with` instead")]
+    pub async fn recordings_list(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        mc: &str,
+        trash: bool,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        trash_type: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = CloudRecordingRecordingsListParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            mc: Some(mc.to_string()).filter(|s| !s.is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+            trash: Some(trash).filter(|v| *v),
+            trash_type: Some(trash_type.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.recordings_list_with(user_id, &params).await
+    }
+
     /**
      * Get meeting recordings.
      *
@@ -105,20 +114,18 @@ impl CloudRecording {
      *   To get Cloud Recordings of a webinar, provide the webinar ID or the webinar UUID. If the webinar ID is provided instead of UUID,the response will be for the latest webinar instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875=="), you must **double encode** the UUID before making an API request. .
-     * * `include_fields: &str` -- Get the `download_access_token` field for downloading meeting recordings.
-     * * `ttl: u64` -- Time to live (TTL) of the `download_access_token`. This is only valid if the `include_fields` query parameter contains `download_access_token`. The range is between 0-604800.
+     * * `params: &CloudRecordingRecordingGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recording_get(
+    pub async fn recording_get_with(
         &self,
         meeting_id: &str,
-        include_fields: &str,
-        ttl: u64,
+        params: &CloudRecordingRecordingGetParams,
     ) -> Result<crate::types::RecordingGetResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_fields.is_empty() {
+        if let Some(include_fields) = &params.include_fields {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
         }
-        if !ttl.to_string().is_empty() {
+        if let Some(ttl) = &params.ttl {
             query_args.push(("ttl".to_string(), ttl.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -131,6 +138,23 @@ impl CloudRecording {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `recording_get_with` and pass the query parameters as a `CloudRecordingRecordingGetParams` instead.
+     */
+    #[deprecated(note = "use `recording_get_with` instead")]
+    pub async fn recording_get(
+        &self,
+        meeting_id: &str,
+        include_fields: &str,
+        ttl: u64,
+    ) -> Result<crate::types::RecordingGetResponseAllOf> {
+        let params = CloudRecordingRecordingGetParams {
+            include_fields: Some(include_fields.to_string()).filter(|s| !s.is_empty()),
+            ttl: Some(ttl).filter(|v| !v.to_string().is_empty()),
+        };
+        self.recording_get_with(meeting_id, &params).await
+    }
+
     /**
      * Delete meeting recordings.
      *
@@ -151,15 +175,15 @@ impl CloudRecording {
      *   To get Cloud Recordings of a webinar, provide the webinar ID or the webinar UUID. If the webinar ID is provided instead of UUID,the response will be for the latest webinar instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875=="), you must **double encode** the UUID before making an API request. .
-     * * `action: crate::types::RecordingDeleteAction` -- The recording delete actions:<br>`trash` - Move recording to trash.<br>`delete` - Delete recording permanently.
+     * * `params: &CloudRecordingRecordingDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recording_delete(
+    pub async fn recording_delete_with(
         &self,
         meeting_id: &str,
-        action: crate::types::RecordingDeleteAction,
+        params: &CloudRecordingRecordingDeleteParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !action.to_string().is_empty() {
+        if let Some(action) = &params.action {
             query_args.push(("action".to_string(), action.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -172,6 +196,21 @@ impl CloudRecording {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `recording_delete_with` and pass the query parameters as a `CloudRecordingRecordingDeleteParams` instead.
+     */
+    #[deprecated(note = "use `recording_delete_with` instead")]
+    pub async fn recording_delete(
+        &self,
+        meeting_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> Result<()> {
+        let params = CloudRecordingRecordingDeleteParams {
+            action: Some(action).filter(|v| !v.to_string().is_empty()),
+        };
+        self.recording_delete_with(meeting_id, &params).await
+    }
+
     /**
      * Delete a meeting recording file.
      *
@@ -189,16 +228,16 @@ impl CloudRecording {
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875=="), you must **double encode** the UUID before making an API request. .
      * * `recording_id: &str` -- User's first name.
-     * * `action: crate::types::RecordingDeleteAction` -- The recording delete actions:<br>`trash` - Move recording to trash.<br>`delete` - Delete recording permanently.
+     * * `params: &CloudRecordingRecordingDeleteOneParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn recording_delete_one(
+    pub async fn recording_delete_one_with(
         &self,
         meeting_id: &str,
         recording_id: &str,
-        action: crate::types::RecordingDeleteAction,
+        params: &CloudRecordingRecordingDeleteOneParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !action.to_string().is_empty() {
+        if let Some(action) = &params.action {
             query_args.push(("action".to_string(), action.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -212,6 +251,23 @@ impl CloudRecording {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `recording_delete_one_with` and pass the query parameters as a `CloudRecordingRecordingDeleteOneParams` instead.
+     */
+    #[deprecated(note = "use `recording_delete_one_with` instead")]
+    pub async fn recording_delete_one(
+        &self,
+        meeting_id: &str,
+        recording_id: &str,
+        action: crate::types::RecordingDeleteAction,
+    ) -> Result<()> {
+        let params = CloudRecordingRecordingDeleteOneParams {
+            action: Some(action).filter(|v| !v.to_string().is_empty()),
+        };
+        self.recording_delete_one_with(meeting_id, recording_id, &params)
+            .await
+    }
+
     /**
      * Recover meeting recordings.
      *
@@ -365,33 +421,24 @@ impl CloudRecording {
      * * `meeting_id: i64` -- The meeting ID in **long** format. The data type of this field is "long"(represented as int64 in JSON).
      *   
      *   While storing it in your database, store it as a **long** data type and **not as an integer**, as the Meeting IDs can be longer than 10 digits.
-     * * `status: crate::types::MeetingRegistrantsStatus` -- The registrant status:<br>`pending` - Registrant's status is pending.<br>`approved` - Registrant's status is approved.<br>`denied` - Registrant's status is denied.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `page_number: i64` --
-     *   **Deprecated** - This field has been deprecated and we will stop supporting it completely in a future release. Please use "next_page_token" for pagination instead of this field.
-     *   
-     *   The page number of the current page in the returned records.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &CloudRecordingMeetingRecordingRegistrantParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn meeting_recording_registrant(
+    pub async fn meeting_recording_registrant_with(
         &self,
         meeting_id: i64,
-        status: crate::types::MeetingRegistrantsStatus,
-        page_size: i64,
-        page_number: i64,
-        next_page_token: &str,
+        params: &CloudRecordingMeetingRecordingRegistrantParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_number > 0 {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -404,6 +451,28 @@ impl CloudRecording {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `meeting_recording_registrant_with` and pass the query parameters as a `CloudRecordingMeetingRecordingRegistrantParams` instead.
+     */
+    #[deprecated(note = "use `meeting_recording_registrant_with` instead")]
+    pub async fn meeting_recording_registrant(
+        &self,
+        meeting_id: i64,
+        status: crate::types::MeetingRegistrantsStatus,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = CloudRecordingMeetingRecordingRegistrantParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_number: Some(page_number).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+        };
+        self.meeting_recording_registrant_with(meeting_id, &params)
+            .await
+    }
+
     /**
      * Create a recording registrant.
      *
@@ -558,32 +627,26 @@ impl CloudRecording {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `from: chrono::DateTime<chrono::Utc>` -- The start date in UTC for the monthly range for which you would like to retrieve recordings. The maximum range can be a month. If no value is provided for this field, the default will be current date. For example, if you make the API request on June 30, 2020, without providing the “from” and “to” parameters, by default the value of 'from' field will be “2020-06-30” and the value of the 'to' field will be “2020-07-01”.
-     * * `to: chrono::DateTime<chrono::Utc>` -- The end date for the monthly range for which you would like to retrieve recordings. The maximum range can be a month.
      * * `account_id: &str` -- Unique identifier of the account.
+     * * `params: &CloudRecordingGetAccountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_account(
+    pub async fn get_account_with(
         &self,
         account_id: &str,
-        page_size: i64,
-        next_page_token: &str,
-        from: Option<chrono::DateTime<chrono::Utc>>,
-        to: Option<chrono::DateTime<chrono::Utc>>,
+        params: &CloudRecordingGetAccountParams,
     ) -> Result<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = from {
-            query_args.push(("from".to_string(), date.to_rfc3339()));
+        if let Some(from) = &params.from {
+            query_args.push(("from".to_string(), from.to_rfc3339()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if let Some(date) = to {
-            query_args.push(("to".to_string(), date.to_rfc3339()));
+        if let Some(to) = &params.to {
+            query_args.push(("to".to_string(), to.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
@@ -599,6 +662,27 @@ impl CloudRecording {
         Ok(resp.meetings.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_account_with` and pass the query parameters as a `CloudRecordingGetAccountParams` instead.
+     */
+    #[deprecated(note = "use `get_account_with` instead")]
+    pub async fn get_account(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>> {
+        let params = CloudRecordingGetAccountParams {
+            from,
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to,
+        };
+        self.get_account_with(account_id, &params).await
+    }
+
     /**
      * List recordings of an account.
      *
@@ -618,19 +702,22 @@ impl CloudRecording {
      *
      * To list recordings of a master account, the scope must be `account:read:admin` and the value of `accountId` should be `me`.<br>  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
      *
+     *
+     * **Parameters:**
+     *
+     * * `params: &CloudRecordingGetAccountParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_account(
+    pub async fn get_all_account_with(
         &self,
         account_id: &str,
-        from: Option<chrono::DateTime<chrono::Utc>>,
-        to: Option<chrono::DateTime<chrono::Utc>>,
+        params: &CloudRecordingGetAccountParams,
     ) -> Result<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if let Some(date) = from {
-            query_args.push(("from".to_string(), date.to_rfc3339()));
+        if let Some(from) = &params.from {
+            query_args.push(("from".to_string(), from.to_rfc3339()));
         }
-        if let Some(date) = to {
-            query_args.push(("to".to_string(), date.to_rfc3339()));
+        if let Some(to) = &params.to {
+            query_args.push(("to".to_string(), to.to_rfc3339()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
@@ -672,4 +759,262 @@ impl CloudRecording {
         // Return our response data.
         Ok(meetings)
     }
+
+    /**
+     * Deprecated: use `get_all_account_with` and pass the query parameters as a `CloudRecordingGetAccountParams` instead.
+     */
+    #[deprecated(note = "use `get_all_account_with` instead")]
+    pub async fn get_all_account(
+        &self,
+        account_id: &str,
+        from: Option<chrono::DateTime<chrono::Utc>>,
+        to: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<crate::types::GetAccountCloudRecordingResponseMeetings>> {
+        let params = CloudRecordingGetAccountParams {
+            from,
+            to,
+            ..Default::default()
+        };
+        self.get_all_account_with(account_id, &params).await
+    }
+
+    /**
+     * List recordings of an account.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/recordings` endpoint.
+     *
+     * As opposed to `get_account`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List [Cloud Recordings](https://support.zoom.us/hc/en-us/articles/203741855-Cloud-Recording) available on an Account.
+     *
+     * > To access a password protected cloud recording, add an "access_token" parameter to the download URL and provide [JWT](https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-jwt-app) as the value of the "access_token".
+     * <br>
+     * **Prerequisites**:<br>
+     * * A Pro or a higher paid plan with Cloud Recording option enabled.<br>
+     * **Scopes**: `recording:read:admin` or `account:read:admin`
+     *
+     * If the scope `recording:read:admin` is used, the Account ID of the Account must be provided in the `accountId` path parameter to list recordings that belong to the Account. This scope only works for sub accounts.
+     *
+     * To list recordings of a master account, the scope must be `account:read:admin` and the value of `accountId` should be `me`.<br>  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     */
+    pub fn get_account_stream(
+        &self,
+        account_id: &str,
+        params: &CloudRecordingGetAccountParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetAccountCloudRecordingResponseMeetings> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(from) = &params.from {
+            query_args.push(("from".to_string(), from.to_rfc3339()));
+        }
+        if let Some(to) = &params.to {
+            query_args.push(("to".to_string(), to.to_rfc3339()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/accounts/{}/recordings?{}",
+            crate::progenitor_support::encode_path(account_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::GetAccountCloudRecordingResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.meetings,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `CloudRecording::recordings_list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CloudRecordingRecordingsListParams {
+    /// The start date in 'yyyy-mm-dd' UTC format for the date range for which you would like to retrieve recordings. The maximum range can be a month. If no value is provided for this field, the default will be current date. For example, if you make the API request on June 30, 2020, without providing the “from” and “to” parameters, by default the value of 'from' field will be “2020-06-30” and the value of the 'to' field will be “2020-07-01”.
+    ///
+    /// **Note**: The "trash" files cannot be filtered by date range and thus, the "from" and "to" fields should not be used for trash files.
+    pub from: Option<chrono::NaiveDate>,
+    /// Query Metadata of Recording if an On-Premise Meeting Connector was used for the meeting.
+    pub mc: Option<String>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// End date in 'yyyy-mm-dd' 'yyyy-mm-dd' UTC format. .
+    pub to: Option<chrono::NaiveDate>,
+    /// Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+    pub trash: Option<bool>,
+    /// The type of Cloud recording that you would like to retrieve from the trash. The value can be one of the following:<br>
+    /// `meeting_recordings`: List all meeting recordings from the trash.<br>
+    /// `recording_file`: List all individual recording files from the trash. .
+    pub trash_type: Option<String>,
+}
+
+impl CloudRecordingRecordingsListParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn mc<T: ToString>(mut self, mc: T) -> Self {
+        self.mc = Some(mc.to_string());
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    pub fn trash(mut self, trash: bool) -> Self {
+        self.trash = Some(trash);
+        self
+    }
+
+    pub fn trash_type<T: ToString>(mut self, trash_type: T) -> Self {
+        self.trash_type = Some(trash_type.to_string());
+        self
+    }
+}
+
+/// The query parameters of `CloudRecording::recording_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CloudRecordingRecordingGetParams {
+    /// Get the `download_access_token` field for downloading meeting recordings.
+    pub include_fields: Option<String>,
+    /// Time to live (TTL) of the `download_access_token`. This is only valid if the `include_fields` query parameter contains `download_access_token`. The range is between 0-604800.
+    pub ttl: Option<u64>,
+}
+
+impl CloudRecordingRecordingGetParams {
+    pub fn include_fields<T: ToString>(mut self, include_fields: T) -> Self {
+        self.include_fields = Some(include_fields.to_string());
+        self
+    }
+
+    pub fn ttl(mut self, ttl: u64) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+}
+
+/// The query parameters of `CloudRecording::recording_delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CloudRecordingRecordingDeleteParams {
+    /// The recording delete actions:<br>`trash` - Move recording to trash.<br>`delete` - Delete recording permanently.
+    pub action: Option<crate::types::RecordingDeleteAction>,
+}
+
+impl CloudRecordingRecordingDeleteParams {
+    pub fn action(mut self, action: crate::types::RecordingDeleteAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+}
+
+/// The query parameters of `CloudRecording::recording_delete_one_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CloudRecordingRecordingDeleteOneParams {
+    /// The recording delete actions:<br>`trash` - Move recording to trash.<br>`delete` - Delete recording permanently.
+    pub action: Option<crate::types::RecordingDeleteAction>,
+}
+
+impl CloudRecordingRecordingDeleteOneParams {
+    pub fn action(mut self, action: crate::types::RecordingDeleteAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+}
+
+/// The query parameters of `CloudRecording::meeting_recording_registrant_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CloudRecordingMeetingRecordingRegistrantParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    pub page_number: Option<i64>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// The registrant status:<br>`pending` - Registrant's status is pending.<br>`approved` - Registrant's status is approved.<br>`denied` - Registrant's status is denied.
+    pub status: Option<crate::types::MeetingRegistrantsStatus>,
+}
+
+impl CloudRecordingMeetingRecordingRegistrantParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::MeetingRegistrantsStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// The query parameters of `CloudRecording::get_account_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CloudRecordingGetAccountParams {
+    /// The start date in UTC for the monthly range for which you would like to retrieve recordings. The maximum range can be a month. If no value is provided for this field, the default will be current date. For example, if you make the API request on June 30, 2020, without providing the “from” and “to” parameters, by default the value of 'from' field will be “2020-06-30” and the value of the 'to' field will be “2020-07-01”.
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// The end date for the monthly range for which you would like to retrieve recordings. The maximum range can be a month.
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl CloudRecordingGetAccountParams {
+    pub fn from(mut self, from: chrono::DateTime<chrono::Utc>) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::DateTime<chrono::Utc>) -> Self {
+        self.to = Some(to);
+        self
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct CommonAreaPhones {
     pub client: Client,
@@ -31,19 +29,17 @@ impl CommonAreaPhones {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- The total number of records returned from a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &CommonAreaPhonesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        page_size: i64,
-        next_page_token: &str,
+        params: &CommonAreaPhonesListParams,
     ) -> Result<Vec<crate::types::CommonAreaPhones>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -55,6 +51,22 @@ impl CommonAreaPhones {
         Ok(resp.common_area_phones.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `CommonAreaPhonesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::CommonAreaPhones>> {
+        let params = CommonAreaPhonesListParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * List common area phones.
      *
@@ -110,6 +122,52 @@ impl CommonAreaPhones {
         Ok(common_area_phones)
     }
 
+    /**
+     * List common area phones.
+     *
+     * This function performs a `GET` to the `/phone/common_area_phones` endpoint.
+     *
+     * As opposed to `list`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to list all of an account's [common area phones](https://support.zoom.us/hc/en-us/articles/360028516231-Managing-Common-Area-Phones).
+     *
+     * A common area phone can be provisioned by a Zoom account owner or a Zoom admin so that anyone in an organization can use it. For example, if your office has shared desks that don't belong to a specific employees, you could add a common area phone so that any person can use it.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     *
+     * * Pro or a higher account with Zoom Phone license.
+     * * Account owner or admin permissions.
+     * * A [supported device](https://support.zoom.us/hc/en-us/articles/360001299063-Zoom-Voice-Supported-Devices)
+     */
+    pub fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CommonAreaPhones> {
+        let url = "/phone/common_area_phones".to_string();
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListCommonAreaPhonesResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.common_area_phones,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Add a common area phone.
      *
@@ -350,3 +408,24 @@ impl CommonAreaPhones {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `CommonAreaPhones::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct CommonAreaPhonesListParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The total number of records returned from a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl CommonAreaPhonesListParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
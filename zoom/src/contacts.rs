@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Contacts {
     pub client: Client,
@@ -25,32 +23,26 @@ impl Contacts {
      *
      * **Parameters:**
      *
-     * * `search_key: &str` -- Provide the keyword - either first name, last name or email of the contact whom you have to search for.
-     * * `query_presence_status: &str` -- Set `query_presence_status` to `true` in order to include the presence status of a contact in the response.
-     * * `page_size: i64` -- The number of records to be returned with a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &ContactsSearchCompanyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn search_company(
+    pub async fn search_company_with(
         &self,
-        search_key: &str,
-        query_presence_status: &str,
-        page_size: i64,
-        next_page_token: &str,
+        params: &ContactsSearchCompanyParams,
     ) -> Result<Vec<crate::types::Contacts>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !query_presence_status.is_empty() {
+        if let Some(query_presence_status) = &params.query_presence_status {
             query_args.push((
                 "query_presence_status".to_string(),
                 query_presence_status.to_string(),
             ));
         }
-        if !search_key.is_empty() {
+        if let Some(search_key) = &params.search_key {
             query_args.push(("search_key".to_string(), search_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -62,6 +54,27 @@ impl Contacts {
         Ok(resp.contacts.to_vec())
     }
 
+    /**
+     * Deprecated: use `search_company_with` and pass the query parameters as a `ContactsSearchCompanyParams` instead.
+     */
+    #[deprecated(note = "use `search_company_with` instead")]
+    pub async fn search_company(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Contacts>> {
+        let params = ContactsSearchCompanyParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            query_presence_status: Some(query_presence_status.to_string())
+                .filter(|s| !s.is_empty()),
+            search_key: Some(search_key.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.search_company_with(&params).await
+    }
+
     /**
      * Search company contacts.
      *
@@ -74,20 +87,23 @@ impl Contacts {
      * **Scopes:** `contact:read:admin`, `contact:read`<br>
      *
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Parameters:**
+     *
+     * * `params: &ContactsSearchCompanyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_search_company(
+    pub async fn get_all_search_company_with(
         &self,
-        search_key: &str,
-        query_presence_status: &str,
+        params: &ContactsSearchCompanyParams,
     ) -> Result<Vec<crate::types::Contacts>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !query_presence_status.is_empty() {
+        if let Some(query_presence_status) = &params.query_presence_status {
             query_args.push((
                 "query_presence_status".to_string(),
                 query_presence_status.to_string(),
             ));
         }
-        if !search_key.is_empty() {
+        if let Some(search_key) = &params.search_key {
             query_args.push(("search_key".to_string(), search_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -127,6 +143,77 @@ impl Contacts {
         Ok(contacts)
     }
 
+    /**
+     * Deprecated: use `get_all_search_company_with` and pass the query parameters as a `ContactsSearchCompanyParams` instead.
+     */
+    #[deprecated(note = "use `get_all_search_company_with` instead")]
+    pub async fn get_all_search_company(
+        &self,
+        search_key: &str,
+        query_presence_status: &str,
+    ) -> Result<Vec<crate::types::Contacts>> {
+        let params = ContactsSearchCompanyParams {
+            query_presence_status: Some(query_presence_status.to_string())
+                .filter(|s| !s.is_empty()),
+            search_key: Some(search_key.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_search_company_with(&params).await
+    }
+
+    /**
+     * Search company contacts.
+     *
+     * This function performs a `GET` to the `/contacts` endpoint.
+     *
+     * As opposed to `search_company`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * A user under an organization's Zoom account has internal users listed under Company Contacts in the Zoom Client. Use this API to search users that are in the company contacts of a Zoom account. Using the `search_key` query parameter, provide either first name, last name or the email address of the user that you would like to search for. Optionally, set `query_presence_status` to `true` in order to include the presence status of a contact. <br><br>
+     *
+     * **Scopes:** `contact:read:admin`, `contact:read`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn search_company_stream(
+        &self,
+        params: &ContactsSearchCompanyParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Contacts> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(query_presence_status) = &params.query_presence_status {
+            query_args.push((
+                "query_presence_status".to_string(),
+                query_presence_status.to_string(),
+            ));
+        }
+        if let Some(search_key) = &params.search_key {
+            query_args.push(("search_key".to_string(), search_key.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/contacts?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::SearchCompanyContactsResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.contacts,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * List user's contacts.
      *
@@ -142,26 +229,20 @@ impl Contacts {
      *
      * **Parameters:**
      *
-     * * `type_: &str` -- The type of contact. The value can be one of the following:
-     *   `company`: Contacts from the user's organization.
-     *   `external`: External contacts. .
-     * * `page_size: i64` -- The number of records returned with a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &ContactsGetUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_user(
+    pub async fn get_user_with(
         &self,
-        type_: &str,
-        page_size: i64,
-        next_page_token: &str,
+        params: &ContactsGetUserParams,
     ) -> Result<Vec<crate::types::GetUserContactsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -173,6 +254,24 @@ impl Contacts {
         Ok(resp.contacts.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_user_with` and pass the query parameters as a `ContactsGetUserParams` instead.
+     */
+    #[deprecated(note = "use `get_user_with` instead")]
+    pub async fn get_user(
+        &self,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::GetUserContactsResponse>> {
+        let params = ContactsGetUserParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_user_with(&params).await
+    }
+
     /**
      * List user's contacts.
      *
@@ -187,13 +286,17 @@ impl Contacts {
      * **Scope**: `chat_contact:read`<br>
      *
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Parameters:**
+     *
+     * * `params: &ContactsGetUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_user(
+    pub async fn get_all_user_with(
         &self,
-        type_: &str,
+        params: &ContactsGetUserParams,
     ) -> Result<Vec<crate::types::GetUserContactsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -233,6 +336,70 @@ impl Contacts {
         Ok(contacts)
     }
 
+    /**
+     * Deprecated: use `get_all_user_with` and pass the query parameters as a `ContactsGetUserParams` instead.
+     */
+    #[deprecated(note = "use `get_all_user_with` instead")]
+    pub async fn get_all_user(
+        &self,
+        type_: &str,
+    ) -> Result<Vec<crate::types::GetUserContactsResponse>> {
+        let params = ContactsGetUserParams {
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_user_with(&params).await
+    }
+
+    /**
+     * List user's contacts.
+     *
+     * This function performs a `GET` to the `/chat/users/me/contacts` endpoint.
+     *
+     * As opposed to `get_user`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * A user under an organization’s Zoom account has internal users listed under Company Contacts in the Zoom Client. A Zoom user can also add another Zoom user as a [contact](https://support.zoom.us/hc/en-us/articles/115004055706-Managing-Contacts). Call this API to list all the contacts of a Zoom user. Zoom contacts are categorized into "company contacts" and "external contacts". You must specify the contact type in the `type` query parameter. If you do not specify, by default, the type will be set as company contact.
+     *
+     * <p style="background-color:#e1f5fe; color:#01579b; padding:8px"> <b>Note: </b> This API only supports <b>user-managed</b> <a href="https://marketplace.zoom.us/docs/guides/getting-started/app-types/create-oauth-app">OAuth app</a>.</p><br>
+     *
+     * **Scope**: `chat_contact:read`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_user_stream(
+        &self,
+        params: &ContactsGetUserParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetUserContactsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/chat/users/me/contacts?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::GetUserContactsResponseData =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.contacts,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Get user's contact details.
      *
@@ -249,15 +416,15 @@ impl Contacts {
      * **Parameters:**
      *
      * * `contact_id: &str` -- The user's contact Id or email address. The contact can be either a company contact or an external contact.
-     * * `query_presence_status: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+     * * `params: &ContactsGetUserContactsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_user_contacts(
+    pub async fn get_user_contacts_with(
         &self,
         contact_id: &str,
-        query_presence_status: bool,
+        params: &ContactsGetUserContactsParams,
     ) -> Result<crate::types::GetUserContactResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if query_presence_status {
+        if let Some(query_presence_status) = &params.query_presence_status {
             query_args.push((
                 "query_presence_status".to_string(),
                 query_presence_status.to_string(),
@@ -272,4 +439,98 @@ impl Contacts {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_user_contacts_with` and pass the query parameters as a `ContactsGetUserContactsParams` instead.
+     */
+    #[deprecated(note = "use `get_user_contacts_with` instead")]
+    pub async fn get_user_contacts(
+        &self,
+        contact_id: &str,
+        query_presence_status: bool,
+    ) -> Result<crate::types::GetUserContactResponse> {
+        let params = ContactsGetUserContactsParams {
+            query_presence_status: Some(query_presence_status).filter(|v| *v),
+        };
+        self.get_user_contacts_with(contact_id, &params).await
+    }
+}
+
+/// The query parameters of `Contacts::search_company_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ContactsSearchCompanyParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records to be returned with a single API call.
+    pub page_size: Option<i64>,
+    /// Set `query_presence_status` to `true` in order to include the presence status of a contact in the response.
+    pub query_presence_status: Option<String>,
+    /// Provide the keyword - either first name, last name or email of the contact whom you have to search for.
+    pub search_key: Option<String>,
+}
+
+impl ContactsSearchCompanyParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn query_presence_status<T: ToString>(mut self, query_presence_status: T) -> Self {
+        self.query_presence_status = Some(query_presence_status.to_string());
+        self
+    }
+
+    pub fn search_key<T: ToString>(mut self, search_key: T) -> Self {
+        self.search_key = Some(search_key.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Contacts::get_user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ContactsGetUserParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned with a single API call.
+    pub page_size: Option<i64>,
+    /// The type of contact. The value can be one of the following:
+    /// `company`: Contacts from the user's organization.
+    /// `external`: External contacts. .
+    pub type_: Option<String>,
+}
+
+impl ContactsGetUserParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Contacts::get_user_contacts_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ContactsGetUserContactsParams {
+    /// Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+    pub query_presence_status: Option<bool>,
+}
+
+impl ContactsGetUserContactsParams {
+    pub fn query_presence_status(mut self, query_presence_status: bool) -> Self {
+        self.query_presence_status = Some(query_presence_status);
+        self
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Dashboards {
     pub client: Client,
@@ -25,41 +23,29 @@ impl Dashboards {
      *
      * **Parameters:**
      *
-     * * `type_: crate::types::DashboardMeetingsType` -- Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
-     *  
-     *  If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `include_fields: crate::types::IncludeFields` -- Set the value of this field to "tracking_fields" if you would like to include tracking fields of each meeting in the response.
+     * * `params: &DashboardsMeetingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn meeting(
+    pub async fn meeting_with(
         &self,
-        type_: crate::types::DashboardMeetingsType,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        next_page_token: &str,
-        include_fields: crate::types::IncludeFields,
+        params: &DashboardsMeetingParams,
     ) -> Result<crate::types::DashboardMeetingsResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !include_fields.to_string().is_empty() {
+        if let Some(include_fields) = &params.include_fields {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -68,6 +54,30 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `meeting_with` and pass the query parameters as a `DashboardsMeetingParams` instead.
+     */
+    #[deprecated(note = "use `meeting_with` instead")]
+    pub async fn meeting(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::IncludeFields,
+    ) -> Result<crate::types::DashboardMeetingsResponseAllOf> {
+        let params = DashboardsMeetingParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            include_fields: Some(include_fields).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.meeting_with(&params).await
+    }
+
     /**
      * Get meeting details.
      *
@@ -84,17 +94,15 @@ impl Dashboards {
      * * `meeting_id: &str` -- The meeting ID or the meeting UUID.  If a meeting ID is provided in the request instead of a UUID, the response will be for the latest meeting instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
-     * * `type_: crate::types::DashboardMeetingsType` -- Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
-     *  
-     *  If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
+     * * `params: &DashboardsMeetingDetailParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn meeting_detail(
+    pub async fn meeting_detail_with(
         &self,
         meeting_id: &str,
-        type_: crate::types::DashboardMeetingsType,
+        params: &DashboardsMeetingDetailParams,
     ) -> Result<crate::types::MeetingMetric> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -107,6 +115,21 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `meeting_detail_with` and pass the query parameters as a `DashboardsMeetingDetailParams` instead.
+     */
+    #[deprecated(note = "use `meeting_detail_with` instead")]
+    pub async fn meeting_detail(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> Result<crate::types::MeetingMetric> {
+        let params = DashboardsMeetingDetailParams {
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.meeting_detail_with(meeting_id, &params).await
+    }
+
     /**
      * List meeting participants.
      *
@@ -124,32 +147,24 @@ impl Dashboards {
      * * `meeting_id: &str` -- The meeting ID or the meeting UUID.  If a meeting ID is provided in the request instead of a UUID, the response will be for the latest meeting instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
-     * * `type_: crate::types::DashboardMeetingsType` -- Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
-     *  
-     *  If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `include_fields: crate::types::DashboardMeetingParticipantsIncludeFields` -- Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
+     * * `params: &DashboardsMeetingParticipantParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn meeting_participant(
+    pub async fn meeting_participant_with(
         &self,
         meeting_id: &str,
-        type_: crate::types::DashboardMeetingsType,
-        page_size: i64,
-        next_page_token: &str,
-        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+        params: &DashboardsMeetingParticipantParams,
     ) -> Result<crate::types::DashboardMeetingParticipantsResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_fields.to_string().is_empty() {
+        if let Some(include_fields) = &params.include_fields {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -162,6 +177,27 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `meeting_participant_with` and pass the query parameters as a `DashboardsMeetingParticipantParams` instead.
+     */
+    #[deprecated(note = "use `meeting_participant_with` instead")]
+    pub async fn meeting_participant(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Result<crate::types::DashboardMeetingParticipantsResponseAllOf> {
+        let params = DashboardsMeetingParticipantParams {
+            include_fields: Some(include_fields).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.meeting_participant_with(meeting_id, &params).await
+    }
+
     /**
      * Get meeting participant QoS.
      *
@@ -180,18 +216,16 @@ impl Dashboards {
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
      * * `participant_id: &str` -- User's first name.
-     * * `type_: crate::types::DashboardMeetingsType` -- Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
-     *  
-     *  If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
+     * * `params: &DashboardsMeetingParticipantQoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn meeting_participant_qo(
+    pub async fn meeting_participant_qo_with(
         &self,
         meeting_id: &str,
         participant_id: &str,
-        type_: crate::types::DashboardMeetingsType,
+        params: &DashboardsMeetingParticipantQoParams,
     ) -> Result<crate::types::ParticipantQos> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -205,6 +239,23 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `meeting_participant_qo_with` and pass the query parameters as a `DashboardsMeetingParticipantQoParams` instead.
+     */
+    #[deprecated(note = "use `meeting_participant_qo_with` instead")]
+    pub async fn meeting_participant_qo(
+        &self,
+        meeting_id: &str,
+        participant_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> Result<crate::types::ParticipantQos> {
+        let params = DashboardsMeetingParticipantQoParams {
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.meeting_participant_qo_with(meeting_id, participant_id, &params)
+            .await
+    }
+
     /**
      * List meeting participants QoS.
      *
@@ -221,27 +272,21 @@ impl Dashboards {
      * * `meeting_id: &str` -- The meeting ID or the meeting UUID.  If a meeting ID is provided in the request instead of a UUID, the response will be for the latest meeting instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
-     * * `type_: crate::types::DashboardMeetingsType` -- Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
-     *  
-     *  If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
-     * * `page_size: i64` -- The number of items returned per page.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &DashboardsMeetingParticipantsQoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn meeting_participants_qo(
+    pub async fn meeting_participants_qo_with(
         &self,
         meeting_id: &str,
-        type_: crate::types::DashboardMeetingsType,
-        page_size: i64,
-        next_page_token: &str,
+        params: &DashboardsMeetingParticipantsQoParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -254,6 +299,25 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `meeting_participants_qo_with` and pass the query parameters as a `DashboardsMeetingParticipantsQoParams` instead.
+     */
+    #[deprecated(note = "use `meeting_participants_qo_with` instead")]
+    pub async fn meeting_participants_qo(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = DashboardsMeetingParticipantsQoParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.meeting_participants_qo_with(meeting_id, &params).await
+    }
+
     /**
      * Get sharing/recording details.
      *
@@ -270,27 +334,21 @@ impl Dashboards {
      * * `meeting_id: &str` -- The meeting ID or the meeting UUID.  If a meeting ID is provided in the request instead of a UUID, the response will be for the latest meeting instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
-     * * `type_: crate::types::DashboardMeetingsType` -- Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
-     *  
-     *  If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceed the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &DashboardsMeetingParticipantShareParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn meeting_participant_share(
+    pub async fn meeting_participant_share_with(
         &self,
         meeting_id: &str,
-        type_: crate::types::DashboardMeetingsType,
-        page_size: i64,
-        next_page_token: &str,
+        params: &DashboardsMeetingParticipantShareParams,
     ) -> Result<crate::types::DashboardMeetingParticipantShareResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -303,6 +361,26 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `meeting_participant_share_with` and pass the query parameters as a `DashboardsMeetingParticipantShareParams` instead.
+     */
+    #[deprecated(note = "use `meeting_participant_share_with` instead")]
+    pub async fn meeting_participant_share(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardMeetingParticipantShareResponseAllOf> {
+        let params = DashboardsMeetingParticipantShareParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.meeting_participant_share_with(meeting_id, &params)
+            .await
+    }
+
     /**
      * List webinars.
      *
@@ -319,34 +397,26 @@ impl Dashboards {
      *
      * **Parameters:**
      *
-     * * `type_: crate::types::DashboardWebinarsType` -- The webinar type.
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &DashboardsWebinarParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn webinar(
+    pub async fn webinar_with(
         &self,
-        type_: crate::types::DashboardWebinarsType,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        next_page_token: &str,
+        params: &DashboardsWebinarParams,
     ) -> Result<crate::types::DashboardWebinarsResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -355,6 +425,28 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `webinar_with` and pass the query parameters as a `DashboardsWebinarParams` instead.
+     */
+    #[deprecated(note = "use `webinar_with` instead")]
+    pub async fn webinar(
+        &self,
+        type_: crate::types::DashboardWebinarsType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardWebinarsResponseAllOf> {
+        let params = DashboardsWebinarParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.webinar_with(&params).await
+    }
+
     /**
      * Get webinar details.
      *
@@ -373,15 +465,15 @@ impl Dashboards {
      * * `webinar_id: &str` -- The webinar ID or the webinar UUID.  If a webinar ID is provided in the request instead of a UUID, the response will be for the latest webinar instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
-     * * `type_: crate::types::DashboardWebinarsType` -- The webinar type.
+     * * `params: &DashboardsWebinarDetailParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn webinar_detail(
+    pub async fn webinar_detail_with(
         &self,
         webinar_id: &str,
-        type_: crate::types::DashboardWebinarsType,
+        params: &DashboardsWebinarDetailParams,
     ) -> Result<crate::types::Webinars> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -394,6 +486,21 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `webinar_detail_with` and pass the query parameters as a `DashboardsWebinarDetailParams` instead.
+     */
+    #[deprecated(note = "use `webinar_detail_with` instead")]
+    pub async fn webinar_detail(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+    ) -> Result<crate::types::Webinars> {
+        let params = DashboardsWebinarDetailParams {
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.webinar_detail_with(webinar_id, &params).await
+    }
+
     /**
      * Get webinar participants.
      *
@@ -412,30 +519,24 @@ impl Dashboards {
      * * `webinar_id: &str` -- The webinar ID or the webinar UUID.  If a webinar ID is provided in the request instead of a UUID, the response will be for the latest webinar instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
-     * * `type_: crate::types::DashboardWebinarsType` -- The webinar type.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `include_fields: crate::types::DashboardMeetingParticipantsIncludeFields` -- Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
+     * * `params: &DashboardsWebinarParticipantParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn webinar_participant(
+    pub async fn webinar_participant_with(
         &self,
         webinar_id: &str,
-        type_: crate::types::DashboardWebinarsType,
-        page_size: i64,
-        next_page_token: &str,
-        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+        params: &DashboardsWebinarParticipantParams,
     ) -> Result<crate::types::DashboardWebinarParticipantsResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_fields.to_string().is_empty() {
+        if let Some(include_fields) = &params.include_fields {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -448,6 +549,27 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `webinar_participant_with` and pass the query parameters as a `DashboardsWebinarParticipantParams` instead.
+     */
+    #[deprecated(note = "use `webinar_participant_with` instead")]
+    pub async fn webinar_participant(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Result<crate::types::DashboardWebinarParticipantsResponseAllOf> {
+        let params = DashboardsWebinarParticipantParams {
+            include_fields: Some(include_fields).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.webinar_participant_with(webinar_id, &params).await
+    }
+
     /**
      * Get webinar participant QoS.
      *
@@ -466,16 +588,16 @@ impl Dashboards {
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
      * * `participant_id: &str` -- User's first name.
-     * * `type_: crate::types::DashboardWebinarsType` -- The webinar type.
+     * * `params: &DashboardsWebinarParticipantQoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn webinar_participant_qo(
+    pub async fn webinar_participant_qo_with(
         &self,
         webinar_id: &str,
         participant_id: &str,
-        type_: crate::types::DashboardWebinarsType,
+        params: &DashboardsWebinarParticipantQoParams,
     ) -> Result<crate::types::ParticipantQos> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -489,6 +611,23 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `webinar_participant_qo_with` and pass the query parameters as a `DashboardsWebinarParticipantQoParams` instead.
+     */
+    #[deprecated(note = "use `webinar_participant_qo_with` instead")]
+    pub async fn webinar_participant_qo(
+        &self,
+        webinar_id: &str,
+        participant_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+    ) -> Result<crate::types::ParticipantQos> {
+        let params = DashboardsWebinarParticipantQoParams {
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.webinar_participant_qo_with(webinar_id, participant_id, &params)
+            .await
+    }
+
     /**
      * List webinar participant QoS.
      *
@@ -507,25 +646,21 @@ impl Dashboards {
      * * `webinar_id: &str` -- The webinar ID or the webinar UUID.  If a webinar ID is provided in the request instead of a UUID, the response will be for the latest webinar instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
-     * * `type_: crate::types::DashboardWebinarsType` -- The webinar type.
-     * * `page_size: i64` -- The number of items returned per page.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &DashboardsWebinarParticipantsQoParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn webinar_participants_qo(
+    pub async fn webinar_participants_qo_with(
         &self,
         webinar_id: &str,
-        type_: crate::types::DashboardWebinarsType,
-        page_size: i64,
-        next_page_token: &str,
+        params: &DashboardsWebinarParticipantsQoParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -538,6 +673,25 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `webinar_participants_qo_with` and pass the query parameters as a `DashboardsWebinarParticipantsQoParams` instead.
+     */
+    #[deprecated(note = "use `webinar_participants_qo_with` instead")]
+    pub async fn webinar_participants_qo(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = DashboardsWebinarParticipantsQoParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.webinar_participants_qo_with(webinar_id, &params).await
+    }
+
     /**
      * Get sharing/recording details.
      *
@@ -556,25 +710,21 @@ impl Dashboards {
      * * `webinar_id: &str` -- The webinar ID or the webinar UUID.  If a webinar ID is provided in the request instead of a UUID, the response will be for the latest webinar instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
-     * * `type_: crate::types::DashboardWebinarsType` -- The webinar type.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceed the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &DashboardsWebinarParticipantShareParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn webinar_participant_share(
+    pub async fn webinar_participant_share_with(
         &self,
         webinar_id: &str,
-        type_: crate::types::DashboardWebinarsType,
-        page_size: i64,
-        next_page_token: &str,
+        params: &DashboardsWebinarParticipantShareParams,
     ) -> Result<crate::types::DashboardMeetingParticipantShareResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -587,6 +737,26 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `webinar_participant_share_with` and pass the query parameters as a `DashboardsWebinarParticipantShareParams` instead.
+     */
+    #[deprecated(note = "use `webinar_participant_share_with` instead")]
+    pub async fn webinar_participant_share(
+        &self,
+        webinar_id: &str,
+        type_: crate::types::DashboardWebinarsType,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardMeetingParticipantShareResponseAllOf> {
+        let params = DashboardsWebinarParticipantShareParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.webinar_participant_share_with(webinar_id, &params)
+            .await
+    }
+
     /**
      * List Zoom Rooms.
      *
@@ -604,24 +774,20 @@ impl Dashboards {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `page_number: i64` -- The page number of the current page in the returned records.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &DashboardsZoomRoomParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn zoom_room(
+    pub async fn zoom_room_with(
         &self,
-        page_size: i64,
-        page_number: i64,
-        next_page_token: &str,
+        params: &DashboardsZoomRoomParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_number > 0 {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -630,6 +796,24 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `zoom_room_with` and pass the query parameters as a `DashboardsZoomRoomParams` instead.
+     */
+    #[deprecated(note = "use `zoom_room_with` instead")]
+    pub async fn zoom_room(
+        &self,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = DashboardsZoomRoomParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_number: Some(page_number).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.zoom_room_with(&params).await
+    }
+
     /**
      * Get Zoom Rooms details.
      *
@@ -647,30 +831,24 @@ impl Dashboards {
      * **Parameters:**
      *
      * * `zoomroom_id: &str` -- User's first name.
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &DashboardsZoomRoomDashboardsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn zoom_room_dashboards(
+    pub async fn zoom_room_dashboards_with(
         &self,
         zoomroom_id: &str,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        next_page_token: &str,
+        params: &DashboardsZoomRoomDashboardsParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -683,6 +861,27 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `zoom_room_dashboards_with` and pass the query parameters as a `DashboardsZoomRoomDashboardsParams` instead.
+     */
+    #[deprecated(note = "use `zoom_room_dashboards_with` instead")]
+    pub async fn zoom_room_dashboards(
+        &self,
+        zoomroom_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = DashboardsZoomRoomDashboardsParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.zoom_room_dashboards_with(zoomroom_id, &params).await
+    }
+
     /**
      * Get CRC port usage.
      *
@@ -699,19 +898,14 @@ impl Dashboards {
      *
      * **Parameters:**
      *
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
+     * * `params: &DashboardsCrcParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn crc(
-        &self,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-    ) -> Result<crate::types::Domains> {
+    pub async fn crc_with(&self, params: &DashboardsCrcParams) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -720,6 +914,22 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `crc_with` and pass the query parameters as a `DashboardsCrcParams` instead.
+     */
+    #[deprecated(note = "use `crc_with` instead")]
+    pub async fn crc(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::Domains> {
+        let params = DashboardsCrcParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.crc_with(&params).await
+    }
+
     /**
      * Get IM metrics.
      *
@@ -737,29 +947,23 @@ impl Dashboards {
      *
      * **Parameters:**
      *
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &DashboardsImParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn im(
+    pub async fn im_with(
         &self,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        next_page_token: &str,
+        params: &DashboardsImParams,
     ) -> Result<crate::types::DashboardImResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -768,6 +972,26 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `im_with` and pass the query parameters as a `DashboardsImParams` instead.
+     */
+    #[deprecated(note = "use `im_with` instead")]
+    pub async fn im(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardImResponseAllOf> {
+        let params = DashboardsImParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.im_with(&params).await
+    }
+
     /**
      * Get chat metrics.
      *
@@ -787,29 +1011,23 @@ impl Dashboards {
      *
      * **Parameters:**
      *
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &DashboardsChatParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn chat(
+    pub async fn chat_with(
         &self,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        next_page_token: &str,
+        params: &DashboardsChatParams,
     ) -> Result<crate::types::DashboardChatResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -818,6 +1036,26 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `chat_with` and pass the query parameters as a `DashboardsChatParams` instead.
+     */
+    #[deprecated(note = "use `chat_with` instead")]
+    pub async fn chat(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardChatResponseAllOf> {
+        let params = DashboardsChatParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.chat_with(&params).await
+    }
+
     /**
      * List Zoom meetings client feedback.
      *
@@ -835,19 +1073,17 @@ impl Dashboards {
      *
      * **Parameters:**
      *
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
+     * * `params: &DashboardsClientFeedbackParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn client_feedback(
+    pub async fn client_feedback_with(
         &self,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
+        params: &DashboardsClientFeedbackParams,
     ) -> Result<crate::types::DashboardClientFeedbackResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -856,6 +1092,22 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `client_feedback_with` and pass the query parameters as a `DashboardsClientFeedbackParams` instead.
+     */
+    #[deprecated(note = "use `client_feedback_with` instead")]
+    pub async fn client_feedback(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::DashboardClientFeedbackResponse> {
+        let params = DashboardsClientFeedbackParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.client_feedback_with(&params).await
+    }
+
     /**
      * Get top 25 issues of Zoom Rooms.
      *
@@ -872,19 +1124,17 @@ impl Dashboards {
      *
      * **Parameters:**
      *
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
+     * * `params: &DashboardsZoomRoomIssueParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn zoom_room_issue(
+    pub async fn zoom_room_issue_with(
         &self,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
+        params: &DashboardsZoomRoomIssueParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -893,6 +1143,22 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `zoom_room_issue_with` and pass the query parameters as a `DashboardsZoomRoomIssueParams` instead.
+     */
+    #[deprecated(note = "use `zoom_room_issue_with` instead")]
+    pub async fn zoom_room_issue(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::Domains> {
+        let params = DashboardsZoomRoomIssueParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.zoom_room_issue_with(&params).await
+    }
+
     /**
      * Get top 25 Zoom Rooms with issues.
      *
@@ -907,19 +1173,17 @@ impl Dashboards {
      *
      * **Parameters:**
      *
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
+     * * `params: &DashboardsIssueZoomRoomParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn issue_zoom_room(
+    pub async fn issue_zoom_room_with(
         &self,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
+        params: &DashboardsIssueZoomRoomParams,
     ) -> Result<crate::types::DashboardIssueZoomRoomResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -928,6 +1192,22 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `issue_zoom_room_with` and pass the query parameters as a `DashboardsIssueZoomRoomParams` instead.
+     */
+    #[deprecated(note = "use `issue_zoom_room_with` instead")]
+    pub async fn issue_zoom_room(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::DashboardIssueZoomRoomResponseAllOf> {
+        let params = DashboardsIssueZoomRoomParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.issue_zoom_room_with(&params).await
+    }
+
     /**
      * Get issues of Zoom Rooms.
      *
@@ -944,30 +1224,24 @@ impl Dashboards {
      * **Parameters:**
      *
      * * `zoomroom_id: &str` -- User's first name.
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &DashboardsIssueDetailZoomRoomParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn issue_detail_zoom_room(
+    pub async fn issue_detail_zoom_room_with(
         &self,
         zoomroom_id: &str,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        next_page_token: &str,
+        params: &DashboardsIssueDetailZoomRoomParams,
     ) -> Result<crate::types::DashboardIssueDetailZoomRoomResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -980,6 +1254,27 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `issue_detail_zoom_room_with` and pass the query parameters as a `DashboardsIssueDetailZoomRoomParams` instead.
+     */
+    #[deprecated(note = "use `issue_detail_zoom_room_with` instead")]
+    pub async fn issue_detail_zoom_room(
+        &self,
+        zoomroom_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardIssueDetailZoomRoomResponseAllOf> {
+        let params = DashboardsIssueDetailZoomRoomParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.issue_detail_zoom_room_with(zoomroom_id, &params).await
+    }
+
     /**
      * Get zoom meetings client feedback.
      *
@@ -999,30 +1294,24 @@ impl Dashboards {
      * **Parameters:**
      *
      * * `feedback_id: &str` -- User's first name.
-     * * `from: chrono::NaiveDate` -- Start Date.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `page_size: i64` -- Account seats.
-     * * `next_page_token: &str` -- User's first name.
+     * * `params: &DashboardsClientFeedbackDetailParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn client_feedback_detail(
+    pub async fn client_feedback_detail_with(
         &self,
         feedback_id: &str,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        next_page_token: &str,
+        params: &DashboardsClientFeedbackDetailParams,
     ) -> Result<crate::types::DashboardClientFeedbackDetailResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1035,6 +1324,27 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `client_feedback_detail_with` and pass the query parameters as a `DashboardsClientFeedbackDetailParams` instead.
+     */
+    #[deprecated(note = "use `client_feedback_detail_with` instead")]
+    pub async fn client_feedback_detail(
+        &self,
+        feedback_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::DashboardClientFeedbackDetailResponseAllOf> {
+        let params = DashboardsClientFeedbackDetailParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.client_feedback_detail_with(feedback_id, &params).await
+    }
+
     /**
      * List client meeting satisfaction.
      *
@@ -1048,19 +1358,17 @@ impl Dashboards {
      *
      * **Parameters:**
      *
-     * * `from: chrono::NaiveDate` -- The start date for the query in “yyyy-mm-dd” format. .
-     * * `to: chrono::NaiveDate` -- The end date for the query in “yyyy-mm-dd” format. .
+     * * `params: &DashboardsListMeetingSatisfactionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_meeting_satisfaction(
+    pub async fn list_meeting_satisfaction_with(
         &self,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
+        params: &DashboardsListMeetingSatisfactionParams,
     ) -> Result<crate::types::ListMeetingSatisfactionResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1069,6 +1377,22 @@ impl Dashboards {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `list_meeting_satisfaction_with` and pass the query parameters as a `DashboardsListMeetingSatisfactionParams` instead.
+     */
+    #[deprecated(note = "use `list_meeting_satisfaction_with` instead")]
+    pub async fn list_meeting_satisfaction(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::ListMeetingSatisfactionResponse> {
+        let params = DashboardsListMeetingSatisfactionParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_meeting_satisfaction_with(&params).await
+    }
+
     /**
      * List call logs.
      *
@@ -1087,46 +1411,29 @@ impl Dashboards {
      *
      * **Parameters:**
      *
-     * * `from: &str` -- Start date for the report in `yyyy-mm-dd` format. Specify a 30 day range using the `from` and `to` parameters as the response provides a maximum of a month worth of data per API request.
-     * * `to: &str` -- End date for the report in `yyyy-mm-dd` format.
-     * * `site_id: &str` -- Unique identifier of the [site](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites). Use this query parameter if you have enabled multiple sites and would like to filter the response of this API call by call logs of a specific phone site.
-     * * `quality_type: &str` -- Filter call logs by voice quality. Zoom uses MOS of 3.5 as a general baseline to categorize calls by call quality. A MOS greater than or equal to 3.5 means good quality, while below 3.5 means poor quality. <br><br>The value of this field can be one of the following:<br>
-     *   * `good`: Retrieve call logs of the call(s) with good quality of voice.<br>
-     *   * `bad`: Retrieve call logs of the call(s) with good quality of voice.<br>
-     *   * `all`: Retrieve all call logs without filtering by voice quality.
-     *   
-     *   
-     *   
-     *   .
-     * * `page_size: i64` -- The number of records returned within a single call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &DashboardsListCallLogsMetricsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_call_logs_metrics(
+    pub async fn list_call_logs_metrics_with(
         &self,
-        from: &str,
-        to: &str,
-        site_id: &str,
-        quality_type: &str,
-        page_size: i64,
-        next_page_token: &str,
+        params: &DashboardsListCallLogsMetricsParams,
     ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !quality_type.is_empty() {
+        if let Some(quality_type) = &params.quality_type {
             query_args.push(("quality_type".to_string(), quality_type.to_string()));
         }
-        if !site_id.is_empty() {
+        if let Some(site_id) = &params.site_id {
             query_args.push(("site_id".to_string(), site_id.to_string()));
         }
-        if !to.is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1139,6 +1446,30 @@ impl Dashboards {
         Ok(resp.call_logs.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_call_logs_metrics_with` and pass the query parameters as a `DashboardsListCallLogsMetricsParams` instead.
+     */
+    #[deprecated(note = "use `list_call_logs_metrics_with` instead")]
+    pub async fn list_call_logs_metrics(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>> {
+        let params = DashboardsListCallLogsMetricsParams {
+            from: Some(from.to_string()).filter(|s| !s.is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            quality_type: Some(quality_type.to_string()).filter(|s| !s.is_empty()),
+            site_id: Some(site_id.to_string()).filter(|s| !s.is_empty()),
+            to: Some(to.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_call_logs_metrics_with(&params).await
+    }
+
     /**
      * List call logs.
      *
@@ -1156,25 +1487,26 @@ impl Dashboards {
      *
      * **Scopes:** `phone:read:admin`, `phone:write:admin`<br>
      * **Rate Limit Label:** `Heavy`
+     *
+     * **Parameters:**
+     *
+     * * `params: &DashboardsListCallLogsMetricsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_call_logs_metrics(
+    pub async fn list_all_call_logs_metrics_with(
         &self,
-        from: &str,
-        to: &str,
-        site_id: &str,
-        quality_type: &str,
+        params: &DashboardsListCallLogsMetricsParams,
     ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !quality_type.is_empty() {
+        if let Some(quality_type) = &params.quality_type {
             query_args.push(("quality_type".to_string(), quality_type.to_string()));
         }
-        if !site_id.is_empty() {
+        if let Some(site_id) = &params.site_id {
             query_args.push(("site_id".to_string(), site_id.to_string()));
         }
-        if !to.is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1214,6 +1546,88 @@ impl Dashboards {
         Ok(call_logs)
     }
 
+    /**
+     * Deprecated: use `list_all_call_logs_metrics_with` and pass the query parameters as a `DashboardsListCallLogsMetricsParams` instead.
+     */
+    #[deprecated(note = "use `list_all_call_logs_metrics_with` instead")]
+    pub async fn list_all_call_logs_metrics(
+        &self,
+        from: &str,
+        to: &str,
+        site_id: &str,
+        quality_type: &str,
+    ) -> Result<Vec<crate::types::ListCallLogsMetricsResponse>> {
+        let params = DashboardsListCallLogsMetricsParams {
+            from: Some(from.to_string()).filter(|s| !s.is_empty()),
+            quality_type: Some(quality_type.to_string()).filter(|s| !s.is_empty()),
+            site_id: Some(site_id.to_string()).filter(|s| !s.is_empty()),
+            to: Some(to.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_call_logs_metrics_with(&params).await
+    }
+
+    /**
+     * List call logs.
+     *
+     * This function performs a `GET` to the `/phone/metrics/call_logs` endpoint.
+     *
+     * As opposed to `list_call_logs_metrics`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Call logs provide a record of all incoming and outgoing calls over Zoom Phone in an account.
+     *
+     * Use this API to list monthly call logs metrics. You can use query parameters to filter the response by date, site and MOS(Mean Opinion Score) of the call.
+     *
+     * **Prerequisites:**
+     * * Business, or Education account
+     * * Zoom Phone license <br><br>
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`<br>
+     * **Rate Limit Label:** `Heavy`
+     */
+    pub fn list_call_logs_metrics_stream(
+        &self,
+        params: &DashboardsListCallLogsMetricsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ListCallLogsMetricsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(from) = &params.from {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(quality_type) = &params.quality_type {
+            query_args.push(("quality_type".to_string(), quality_type.to_string()));
+        }
+        if let Some(site_id) = &params.site_id {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if let Some(to) = &params.to {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/phone/metrics/call_logs?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListCallLogsMetricsResponseData =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.call_logs,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Get call details from call log.
      *
@@ -1293,27 +1707,21 @@ impl Dashboards {
      * * `meeting_id: &str` -- The meeting ID or the meeting UUID.  If a meeting ID is provided in the request instead of a UUID, the response will be for the latest meeting instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
-     * * `type_: crate::types::DashboardMeetingsType` -- Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
-     *  
-     *  If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `page_size: i64` -- The number of records returned within a single API call.
+     * * `params: &DashboardsParticipantFeedbackParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn participant_feedback(
+    pub async fn participant_feedback_with(
         &self,
         meeting_id: &str,
-        type_: crate::types::DashboardMeetingsType,
-        next_page_token: &str,
-        page_size: i64,
+        params: &DashboardsParticipantFeedbackParams,
     ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1329,6 +1737,25 @@ impl Dashboards {
         Ok(resp.participants.to_vec())
     }
 
+    /**
+     * Deprecated: use `participant_feedback_with` and pass the query parameters as a `DashboardsParticipantFeedbackParams` instead.
+     */
+    #[deprecated(note = "use `participant_feedback_with` instead")]
+    pub async fn participant_feedback(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
+        let params = DashboardsParticipantFeedbackParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.participant_feedback_with(meeting_id, &params).await
+    }
+
     /**
      * Get post meeting feedback.
      *
@@ -1345,14 +1772,18 @@ impl Dashboards {
      * <br> **Scope:** `dashboard_meetings:read:admiin`
      *
      * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     *
+     * **Parameters:**
+     *
+     * * `params: &DashboardsParticipantFeedbackParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_participant_feedback(
+    pub async fn get_all_participant_feedback_with(
         &self,
         meeting_id: &str,
-        type_: crate::types::DashboardMeetingsType,
+        params: &DashboardsParticipantFeedbackParams,
     ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1396,6 +1827,79 @@ impl Dashboards {
         Ok(participants)
     }
 
+    /**
+     * Deprecated: use `get_all_participant_feedback_with` and pass the query parameters as a `DashboardsParticipantFeedbackParams` instead.
+     */
+    #[deprecated(note = "use `get_all_participant_feedback_with` instead")]
+    pub async fn get_all_participant_feedback(
+        &self,
+        meeting_id: &str,
+        type_: crate::types::DashboardMeetingsType,
+    ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
+        let params = DashboardsParticipantFeedbackParams {
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_participant_feedback_with(meeting_id, &params)
+            .await
+    }
+
+    /**
+     * Get post meeting feedback.
+     *
+     * This function performs a `GET` to the `/metrics/meetings/{meetingId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `participant_feedback`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * When a meeting ends, each attendee will be prompted to share their meeting experience by clicking either thumbs up or thumbs down. Use this API to retrieve the feedback submitted for a specific meeting. Note that this API only works for meetings scheduled after December 20, 2020.
+     *
+     * **Prerequisites:**
+     * * [Feedback to Zoom](https://support.zoom.us/hc/en-us/articles/115005838023) setting must be enabled by the participant prior to the meeting.
+     * * The user making the API request must be enrolled in a Business or a higher plan.
+     *
+     * <br> **Scope:** `dashboard_meetings:read:admiin`
+     *
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     */
+    pub fn participant_feedback_stream(
+        &self,
+        meeting_id: &str,
+        params: &DashboardsParticipantFeedbackParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ParticipantFeedbackResponseParticipants> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/metrics/meetings/{}/participants/satisfaction?{}",
+            crate::progenitor_support::encode_path(meeting_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ParticipantFeedbackResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.participants,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Get post webinar feedback.
      *
@@ -1414,30 +1918,24 @@ impl Dashboards {
      *
      * **Parameters:**
      *
-     * * `type_: crate::types::DashboardMeetingsType` -- Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
-     *  
-     *  If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
      * * `webinar_id: &str` -- The webinar ID or the webinar UUID.  If a webinar ID is provided in the request instead of a UUID, the response will be for the latest webinar instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
+     * * `params: &DashboardsParticipantWebinarFeedbackParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn participant_webinar_feedback(
+    pub async fn participant_webinar_feedback_with(
         &self,
-        type_: crate::types::DashboardMeetingsType,
-        page_size: i64,
-        next_page_token: &str,
         webinar_id: &str,
+        params: &DashboardsParticipantWebinarFeedbackParams,
     ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1453,6 +1951,26 @@ impl Dashboards {
         Ok(resp.participants.to_vec())
     }
 
+    /**
+     * Deprecated: use `participant_webinar_feedback_with` and pass the query parameters as a `DashboardsParticipantWebinarFeedbackParams` instead.
+     */
+    #[deprecated(note = "use `participant_webinar_feedback_with` instead")]
+    pub async fn participant_webinar_feedback(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        webinar_id: &str,
+    ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
+        let params = DashboardsParticipantWebinarFeedbackParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.participant_webinar_feedback_with(webinar_id, &params)
+            .await
+    }
+
     /**
      * Get post webinar feedback.
      *
@@ -1470,14 +1988,18 @@ impl Dashboards {
      * <br> **Scope:** `dashboard_webinars:read:admin`
      *
      * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     *
+     * **Parameters:**
+     *
+     * * `params: &DashboardsParticipantWebinarFeedbackParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_participant_webinar_feedback(
+    pub async fn get_all_participant_webinar_feedback_with(
         &self,
-        type_: crate::types::DashboardMeetingsType,
         webinar_id: &str,
+        params: &DashboardsParticipantWebinarFeedbackParams,
     ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1520,4 +2042,849 @@ impl Dashboards {
         // Return our response data.
         Ok(participants)
     }
+
+    /**
+     * Deprecated: use `get_all_participant_webinar_feedback_with` and pass the query parameters as a `DashboardsParticipantWebinarFeedbackParams` instead.
+     */
+    #[deprecated(note = "use `get_all_participant_webinar_feedback_with` instead")]
+    pub async fn get_all_participant_webinar_feedback(
+        &self,
+        type_: crate::types::DashboardMeetingsType,
+        webinar_id: &str,
+    ) -> Result<Vec<crate::types::ParticipantFeedbackResponseParticipants>> {
+        let params = DashboardsParticipantWebinarFeedbackParams {
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_participant_webinar_feedback_with(webinar_id, &params)
+            .await
+    }
+
+    /**
+     * Get post webinar feedback.
+     *
+     * This function performs a `GET` to the `/metrics/webinars/{webinarId}/participants/satisfaction` endpoint.
+     *
+     * As opposed to `participant_webinar_feedback`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * When a Webinar ends, each attendee will be prompted to share their Webinar experience by clicking either thumbs up or thumbs down. Use this API to retrieve the feedback submitted for a specific webinar. Note that this API only works for meetings scheduled after December 20, 2020.
+     *
+     * **Prerequisites:**
+     * * [Feedback to Zoom](https://support.zoom.us/hc/en-us/articles/115005838023) setting must be enabled by the participant prior to the meeting.
+     * * The user making the API request must be enrolled in a Business or a higher plan.
+     *
+     *
+     * <br> **Scope:** `dashboard_webinars:read:admin`
+     *
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`<br>
+     */
+    pub fn participant_webinar_feedback_stream(
+        &self,
+        webinar_id: &str,
+        params: &DashboardsParticipantWebinarFeedbackParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ParticipantFeedbackResponseParticipants> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/metrics/webinars/{}/participants/satisfaction?{}",
+            crate::progenitor_support::encode_path(webinar_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ParticipantFeedbackResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.participants,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `Dashboards::meeting_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsMeetingParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// Set the value of this field to "tracking_fields" if you would like to include tracking fields of each meeting in the response.
+    pub include_fields: Option<crate::types::IncludeFields>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+    /// Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
+    ///
+    /// If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
+    pub type_: Option<crate::types::DashboardMeetingsType>,
+}
+
+impl DashboardsMeetingParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn include_fields(mut self, include_fields: crate::types::IncludeFields) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::meeting_detail_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsMeetingDetailParams {
+    /// Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
+    ///
+    /// If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
+    pub type_: Option<crate::types::DashboardMeetingsType>,
+}
+
+impl DashboardsMeetingDetailParams {
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::meeting_participant_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsMeetingParticipantParams {
+    /// Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
+    pub include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
+    ///
+    /// If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
+    pub type_: Option<crate::types::DashboardMeetingsType>,
+}
+
+impl DashboardsMeetingParticipantParams {
+    pub fn include_fields(
+        mut self,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::meeting_participant_qo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsMeetingParticipantQoParams {
+    /// Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
+    ///
+    /// If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
+    pub type_: Option<crate::types::DashboardMeetingsType>,
+}
+
+impl DashboardsMeetingParticipantQoParams {
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::meeting_participants_qo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsMeetingParticipantsQoParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of items returned per page.
+    pub page_size: Option<i64>,
+    /// Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
+    ///
+    /// If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
+    pub type_: Option<crate::types::DashboardMeetingsType>,
+}
+
+impl DashboardsMeetingParticipantsQoParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::meeting_participant_share_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsMeetingParticipantShareParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceed the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
+    ///
+    /// If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
+    pub type_: Option<crate::types::DashboardMeetingsType>,
+}
+
+impl DashboardsMeetingParticipantShareParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::webinar_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsWebinarParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+    /// The webinar type.
+    pub type_: Option<crate::types::DashboardWebinarsType>,
+}
+
+impl DashboardsWebinarParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::webinar_detail_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsWebinarDetailParams {
+    /// The webinar type.
+    pub type_: Option<crate::types::DashboardWebinarsType>,
+}
+
+impl DashboardsWebinarDetailParams {
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::webinar_participant_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsWebinarParticipantParams {
+    /// Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
+    pub include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// The webinar type.
+    pub type_: Option<crate::types::DashboardWebinarsType>,
+}
+
+impl DashboardsWebinarParticipantParams {
+    pub fn include_fields(
+        mut self,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::webinar_participant_qo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsWebinarParticipantQoParams {
+    /// The webinar type.
+    pub type_: Option<crate::types::DashboardWebinarsType>,
+}
+
+impl DashboardsWebinarParticipantQoParams {
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::webinar_participants_qo_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsWebinarParticipantsQoParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of items returned per page.
+    pub page_size: Option<i64>,
+    /// The webinar type.
+    pub type_: Option<crate::types::DashboardWebinarsType>,
+}
+
+impl DashboardsWebinarParticipantsQoParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::webinar_participant_share_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsWebinarParticipantShareParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceed the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// The webinar type.
+    pub type_: Option<crate::types::DashboardWebinarsType>,
+}
+
+impl DashboardsWebinarParticipantShareParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::DashboardWebinarsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::zoom_room_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsZoomRoomParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The page number of the current page in the returned records.
+    pub page_number: Option<i64>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl DashboardsZoomRoomParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::zoom_room_dashboards_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsZoomRoomDashboardsParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl DashboardsZoomRoomDashboardsParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::crc_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsCrcParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl DashboardsCrcParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::im_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsImParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl DashboardsImParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::chat_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsChatParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl DashboardsChatParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::client_feedback_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsClientFeedbackParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl DashboardsClientFeedbackParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::zoom_room_issue_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsZoomRoomIssueParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl DashboardsZoomRoomIssueParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::issue_zoom_room_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsIssueZoomRoomParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl DashboardsIssueZoomRoomParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::issue_detail_zoom_room_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsIssueDetailZoomRoomParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl DashboardsIssueDetailZoomRoomParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::client_feedback_detail_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsClientFeedbackDetailParams {
+    /// Start Date.
+    pub from: Option<chrono::NaiveDate>,
+    /// User's first name.
+    pub next_page_token: Option<String>,
+    /// Account seats.
+    pub page_size: Option<i64>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl DashboardsClientFeedbackDetailParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::list_meeting_satisfaction_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsListMeetingSatisfactionParams {
+    /// The start date for the query in “yyyy-mm-dd” format. .
+    pub from: Option<chrono::NaiveDate>,
+    /// The end date for the query in “yyyy-mm-dd” format. .
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl DashboardsListMeetingSatisfactionParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::list_call_logs_metrics_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsListCallLogsMetricsParams {
+    /// Start date for the report in `yyyy-mm-dd` format. Specify a 30 day range using the `from` and `to` parameters as the response provides a maximum of a month worth of data per API request.
+    pub from: Option<String>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single call.
+    pub page_size: Option<i64>,
+    /// Filter call logs by voice quality. Zoom uses MOS of 3.5 as a general baseline to categorize calls by call quality. A MOS greater than or equal to 3.5 means good quality, while below 3.5 means poor quality. <br><br>The value of this field can be one of the following:<br>
+    /// * `good`: Retrieve call logs of the call(s) with good quality of voice.<br>
+    /// * `bad`: Retrieve call logs of the call(s) with good quality of voice.<br>
+    /// * `all`: Retrieve all call logs without filtering by voice quality.
+    ///
+    ///
+    ///
+    /// .
+    pub quality_type: Option<String>,
+    /// Unique identifier of the [site](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites). Use this query parameter if you have enabled multiple sites and would like to filter the response of this API call by call logs of a specific phone site.
+    pub site_id: Option<String>,
+    /// End date for the report in `yyyy-mm-dd` format.
+    pub to: Option<String>,
+}
+
+impl DashboardsListCallLogsMetricsParams {
+    pub fn from<T: ToString>(mut self, from: T) -> Self {
+        self.from = Some(from.to_string());
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn quality_type<T: ToString>(mut self, quality_type: T) -> Self {
+        self.quality_type = Some(quality_type.to_string());
+        self
+    }
+
+    pub fn site_id<T: ToString>(mut self, site_id: T) -> Self {
+        self.site_id = Some(site_id.to_string());
+        self
+    }
+
+    pub fn to<T: ToString>(mut self, to: T) -> Self {
+        self.to = Some(to.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::participant_feedback_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsParticipantFeedbackParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
+    ///
+    /// If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
+    pub type_: Option<crate::types::DashboardMeetingsType>,
+}
+
+impl DashboardsParticipantFeedbackParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Dashboards::participant_webinar_feedback_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct DashboardsParticipantWebinarFeedbackParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Specify a value to get the response for the corresponding meeting type. The value of this field can be one of the following:<br> <br>`past` - Meeting that already occurred in the specified date range.<br>`pastOne` - Past meetings that were attended by only one user. <br>`live` - Live meetings.<br><br>
+    ///
+    /// If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
+    pub type_: Option<crate::types::DashboardMeetingsType>,
+}
+
+impl DashboardsParticipantWebinarFeedbackParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::DashboardMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Groups {
     pub client: Client,
@@ -150,28 +148,21 @@ impl Groups {
      *
      * * `group_id: &str` -- The group ID.<br>
      *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `page_number: i64` --
-     *   **Deprecated** - This field has been deprecated and we will stop supporting it completely in a future release. Please use "next_page_token" for pagination instead of this field.
-     *   
-     *   The page number of the current page in the returned records.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &GroupsMembersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn members(
+    pub async fn members_with(
         &self,
         group_id: &str,
-        page_size: i64,
-        page_number: i64,
-        next_page_token: &str,
+        params: &GroupsMembersParams,
     ) -> Result<Vec<crate::types::UserCreateResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_number > 0 {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -187,6 +178,25 @@ impl Groups {
         Ok(resp.members.to_vec())
     }
 
+    /**
+     * Deprecated: use `members_with` and pass the query parameters as a `GroupsMembersParams` instead.
+     */
+    #[deprecated(note = "use `members_with` instead")]
+    pub async fn members(
+        &self,
+        group_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::UserCreateResponse>> {
+        let params = GroupsMembersParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_number: Some(page_number).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.members_with(group_id, &params).await
+    }
+
     /**
      * List group members .
      *
@@ -243,6 +253,50 @@ impl Groups {
         Ok(members)
     }
 
+    /**
+     * List group members .
+     *
+     * This function performs a `GET` to the `/groups/{groupId}/members` endpoint.
+     *
+     * As opposed to `members`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List the members of a [group](https://support.zoom.us/hc/en-us/articles/204519819-Group-Management-) under your account.
+     *
+     * **Prerequisite**: Pro, Business, or Education account<br>
+     * **Scopes**: `group:read:admin`<br>
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn members_stream(
+        &self,
+        group_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UserCreateResponse> {
+        let url = format!(
+            "/groups/{}/members",
+            crate::progenitor_support::encode_path(group_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::GroupMembersResponseData = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.members,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Add group members.
      *
@@ -356,26 +410,21 @@ impl Groups {
      *
      * **Parameters:**
      *
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
-     *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
-     *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
-     *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     * * `params: &GroupsGetSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_settings_domains(
+    pub async fn get_settings_domains_with(
         &self,
         group_id: &str,
-        custom_query_fields: &str,
-        option: crate::types::OptionData,
+        params: &GroupsGetSettingParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -388,6 +437,23 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_settings_domains_with` and pass the query parameters as a `GroupsGetSettingParams` instead.
+     */
+    #[deprecated(note = "use `get_settings_domains_with` instead")]
+    pub async fn get_settings_domains(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: crate::types::OptionData,
+    ) -> Result<crate::types::Domains> {
+        let params = GroupsGetSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_settings_domains_with(group_id, &params).await
+    }
+
     /**
      * Get a group's settings.
      *
@@ -401,26 +467,21 @@ impl Groups {
      *
      * **Parameters:**
      *
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
-     *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
-     *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
-     *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     * * `params: &GroupsGetSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_settings_meeting_security(
+    pub async fn get_settings_meeting_security_with(
         &self,
         group_id: &str,
-        custom_query_fields: &str,
-        option: crate::types::OptionData,
+        params: &GroupsGetSettingParams,
     ) -> Result<crate::types::MeetingSecuritySettings> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -433,6 +494,24 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_settings_meeting_security_with` and pass the query parameters as a `GroupsGetSettingParams` instead.
+     */
+    #[deprecated(note = "use `get_settings_meeting_security_with` instead")]
+    pub async fn get_settings_meeting_security(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: crate::types::OptionData,
+    ) -> Result<crate::types::MeetingSecuritySettings> {
+        let params = GroupsGetSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_settings_meeting_security_with(group_id, &params)
+            .await
+    }
+
     /**
      * Get a group's settings.
      *
@@ -446,26 +525,21 @@ impl Groups {
      *
      * **Parameters:**
      *
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
-     *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
-     *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
-     *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     * * `params: &GroupsGetSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_settings_group_response(
+    pub async fn get_settings_group_response_with(
         &self,
         group_id: &str,
-        custom_query_fields: &str,
-        option: crate::types::OptionData,
+        params: &GroupsGetSettingParams,
     ) -> Result<crate::types::GetGroupSettingsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -478,6 +552,24 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_settings_group_response_with` and pass the query parameters as a `GroupsGetSettingParams` instead.
+     */
+    #[deprecated(note = "use `get_settings_group_response_with` instead")]
+    pub async fn get_settings_group_response(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: crate::types::OptionData,
+    ) -> Result<crate::types::GetGroupSettingsResponse> {
+        let params = GroupsGetSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_settings_group_response_with(group_id, &params)
+            .await
+    }
+
     /**
      * Get a group's settings.
      *
@@ -491,26 +583,21 @@ impl Groups {
      *
      * **Parameters:**
      *
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
-     *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
-     *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
-     *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
+     * * `params: &GroupsGetSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_setting(
+    pub async fn get_setting_with(
         &self,
         group_id: &str,
-        custom_query_fields: &str,
-        option: crate::types::OptionData,
+        params: &GroupsGetSettingParams,
     ) -> Result<crate::types::GetGroupSettingsResponseOneOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -523,6 +610,23 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_setting_with` and pass the query parameters as a `GroupsGetSettingParams` instead.
+     */
+    #[deprecated(note = "use `get_setting_with` instead")]
+    pub async fn get_setting(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: crate::types::OptionData,
+    ) -> Result<crate::types::GetGroupSettingsResponseOneOf> {
+        let params = GroupsGetSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_setting_with(group_id, &params).await
+    }
+
     /**
      * Update a group's settings.
      *
@@ -537,23 +641,22 @@ impl Groups {
      * **Parameters:**
      *
      * * `group_id: &str` -- User's first name.
-     * * `option: crate::types::UpdateGroupSettingsOption`
+     * * `params: &GroupsUpdateSettingsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update_settings(
+    pub async fn update_settings_with(
         &self,
         group_id: &str,
-        custom_query_fields: &str,
-        option: crate::types::UpdateGroupSettingsOption,
+        params: &GroupsUpdateSettingsParams,
         body: &crate::types::UpdateGroupSettingsRequestOneOf,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -568,6 +671,24 @@ impl Groups {
             .await
     }
 
+    /**
+     * Deprecated: use `update_settings_with` and pass the query parameters as a `GroupsUpdateSettingsParams` instead.
+     */
+    #[deprecated(note = "use `update_settings_with` instead")]
+    pub async fn update_settings(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: crate::types::UpdateGroupSettingsOption,
+        body: &crate::types::UpdateGroupSettingsRequestOneOf,
+    ) -> Result<()> {
+        let params = GroupsUpdateSettingsParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.update_settings_with(group_id, &params, body).await
+    }
+
     /**
      * Get locked settings.
      *
@@ -583,22 +704,21 @@ impl Groups {
      * **Parameters:**
      *
      * * `group_id: &str` -- User's first name.
-     * * `option: &str` -- Specify `meeting_security` as the value of this field if you would like to view security settings applied on a meeting hosted by the users in this group.
+     * * `params: &GroupsGetLockSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_lock_settings_meeting_security(
+    pub async fn get_lock_settings_meeting_security_with(
         &self,
         group_id: &str,
-        custom_query_fields: &str,
-        option: &str,
+        params: &GroupsGetLockSettingParams,
     ) -> Result<crate::types::MeetingSecuritySettings> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -611,6 +731,24 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_lock_settings_meeting_security_with` and pass the query parameters as a `GroupsGetLockSettingParams` instead.
+     */
+    #[deprecated(note = "use `get_lock_settings_meeting_security_with` instead")]
+    pub async fn get_lock_settings_meeting_security(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+    ) -> Result<crate::types::MeetingSecuritySettings> {
+        let params = GroupsGetLockSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_lock_settings_meeting_security_with(group_id, &params)
+            .await
+    }
+
     /**
      * Get locked settings.
      *
@@ -626,22 +764,21 @@ impl Groups {
      * **Parameters:**
      *
      * * `group_id: &str` -- User's first name.
-     * * `option: &str` -- Specify `meeting_security` as the value of this field if you would like to view security settings applied on a meeting hosted by the users in this group.
+     * * `params: &GroupsGetLockSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_lock_settings_group_response(
+    pub async fn get_lock_settings_group_response_with(
         &self,
         group_id: &str,
-        custom_query_fields: &str,
-        option: &str,
+        params: &GroupsGetLockSettingParams,
     ) -> Result<crate::types::GetGroupLockSettingsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -654,6 +791,24 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_lock_settings_group_response_with` and pass the query parameters as a `GroupsGetLockSettingParams` instead.
+     */
+    #[deprecated(note = "use `get_lock_settings_group_response_with` instead")]
+    pub async fn get_lock_settings_group_response(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+    ) -> Result<crate::types::GetGroupLockSettingsResponse> {
+        let params = GroupsGetLockSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_lock_settings_group_response_with(group_id, &params)
+            .await
+    }
+
     /**
      * Get locked settings.
      *
@@ -669,22 +824,21 @@ impl Groups {
      * **Parameters:**
      *
      * * `group_id: &str` -- User's first name.
-     * * `option: &str` -- Specify `meeting_security` as the value of this field if you would like to view security settings applied on a meeting hosted by the users in this group.
+     * * `params: &GroupsGetLockSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_lock_setting(
+    pub async fn get_lock_setting_with(
         &self,
         group_id: &str,
-        custom_query_fields: &str,
-        option: &str,
+        params: &GroupsGetLockSettingParams,
     ) -> Result<crate::types::GetGroupLockSettingsResponseOneOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -697,6 +851,23 @@ impl Groups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_lock_setting_with` and pass the query parameters as a `GroupsGetLockSettingParams` instead.
+     */
+    #[deprecated(note = "use `get_lock_setting_with` instead")]
+    pub async fn get_lock_setting(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+    ) -> Result<crate::types::GetGroupLockSettingsResponseOneOf> {
+        let params = GroupsGetLockSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_lock_setting_with(group_id, &params).await
+    }
+
     /**
      * Update locked settings.
      *
@@ -712,23 +883,22 @@ impl Groups {
      * **Parameters:**
      *
      * * `group_id: &str` -- User's first name.
-     * * `option: &str` -- Specify `meeting_security` as the value of this field if you would like to view security settings applied on a meeting hosted by the users in this group.
+     * * `params: &GroupsLockedSettingsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn locked_settings(
+    pub async fn locked_settings_with(
         &self,
         group_id: &str,
-        custom_query_fields: &str,
-        option: &str,
+        params: &GroupsLockedSettingsParams,
         body: &crate::types::GroupLockedSettingsRequestOneOf,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !option.is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -743,6 +913,24 @@ impl Groups {
             .await
     }
 
+    /**
+     * Deprecated: use `locked_settings_with` and pass the query parameters as a `GroupsLockedSettingsParams` instead.
+     */
+    #[deprecated(note = "use `locked_settings_with` instead")]
+    pub async fn locked_settings(
+        &self,
+        group_id: &str,
+        custom_query_fields: &str,
+        option: &str,
+        body: &crate::types::GroupLockedSettingsRequestOneOf,
+    ) -> Result<()> {
+        let params = GroupsLockedSettingsParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            option: Some(option.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.locked_settings_with(group_id, &params, body).await
+    }
+
     /**
      * Upload virtual background files.
      *
@@ -762,15 +950,16 @@ impl Groups {
      * **Parameters:**
      *
      * * `group_id: &str` -- Unique identifier of the group. Retrieve the value for this field by calling the [List groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups) API.
+     * * `params: &GroupsUploadVbParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn upload_vb(
+    pub async fn upload_vb_with(
         &self,
-        file_ids: &str,
         group_id: &str,
+        params: &GroupsUploadVbParams,
         body: &crate::types::UploadVbRequest,
     ) -> Result<crate::types::Files> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !file_ids.is_empty() {
+        if let Some(file_ids) = &params.file_ids {
             query_args.push(("file_ids".to_string(), file_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -785,6 +974,22 @@ impl Groups {
             .await
     }
 
+    /**
+     * Deprecated: use `upload_vb_with` and pass the query parameters as a `GroupsUploadVbParams` instead.
+     */
+    #[deprecated(note = "use `upload_vb_with` instead")]
+    pub async fn upload_vb(
+        &self,
+        file_ids: &str,
+        group_id: &str,
+        body: &crate::types::UploadVbRequest,
+    ) -> Result<crate::types::Files> {
+        let params = GroupsUploadVbParams {
+            file_ids: Some(file_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.upload_vb_with(group_id, &params, body).await
+    }
+
     /**
      * Delete virtual background files.
      *
@@ -802,11 +1007,11 @@ impl Groups {
      * **Parameters:**
      *
      * * `group_id: &str` -- Unique identifier of the group. Retrieve the value for this field by calling the [List groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups) API.
-     * * `file_ids: &str` -- Provide the id of the file that is to be deleted. To delete multiple files, provide comma separated values for this field.
+     * * `params: &GroupsDelVbParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn del_vb(&self, file_ids: &str, group_id: &str) -> Result<()> {
+    pub async fn del_vb_with(&self, group_id: &str, params: &GroupsDelVbParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !file_ids.is_empty() {
+        if let Some(file_ids) = &params.file_ids {
             query_args.push(("file_ids".to_string(), file_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -818,4 +1023,152 @@ impl Groups {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Deprecated: use `del_vb_with` and pass the query parameters as a `GroupsDelVbParams` instead.
+     */
+    #[deprecated(note = "use `del_vb_with` instead")]
+    pub async fn del_vb(&self, file_ids: &str, group_id: &str) -> Result<()> {
+        let params = GroupsDelVbParams {
+            file_ids: Some(file_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.del_vb_with(group_id, &params).await
+    }
+}
+
+/// The query parameters of `Groups::members_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsMembersParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    pub page_number: Option<i64>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl GroupsMembersParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `Groups::get_setting_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsGetSettingParams {
+    pub custom_query_fields: Option<String>,
+    /// Use the following options to filter the results of the account's information:
+    /// \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
+    /// \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
+    /// \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
+    /// \* `meeting_security` — View the account's meeting security settings.
+    pub option: Option<crate::types::OptionData>,
+}
+
+impl GroupsGetSettingParams {
+    pub fn custom_query_fields<T: ToString>(mut self, custom_query_fields: T) -> Self {
+        self.custom_query_fields = Some(custom_query_fields.to_string());
+        self
+    }
+
+    pub fn option(mut self, option: crate::types::OptionData) -> Self {
+        self.option = Some(option);
+        self
+    }
+}
+
+/// The query parameters of `Groups::update_settings_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsUpdateSettingsParams {
+    pub custom_query_fields: Option<String>,
+    pub option: Option<crate::types::UpdateGroupSettingsOption>,
+}
+
+impl GroupsUpdateSettingsParams {
+    pub fn custom_query_fields<T: ToString>(mut self, custom_query_fields: T) -> Self {
+        self.custom_query_fields = Some(custom_query_fields.to_string());
+        self
+    }
+
+    pub fn option(mut self, option: crate::types::UpdateGroupSettingsOption) -> Self {
+        self.option = Some(option);
+        self
+    }
+}
+
+/// The query parameters of `Groups::get_lock_setting_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsGetLockSettingParams {
+    pub custom_query_fields: Option<String>,
+    /// Specify `meeting_security` as the value of this field if you would like to view security settings applied on a meeting hosted by the users in this group.
+    pub option: Option<String>,
+}
+
+impl GroupsGetLockSettingParams {
+    pub fn custom_query_fields<T: ToString>(mut self, custom_query_fields: T) -> Self {
+        self.custom_query_fields = Some(custom_query_fields.to_string());
+        self
+    }
+
+    pub fn option<T: ToString>(mut self, option: T) -> Self {
+        self.option = Some(option.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Groups::locked_settings_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsLockedSettingsParams {
+    pub custom_query_fields: Option<String>,
+    /// Specify `meeting_security` as the value of this field if you would like to view security settings applied on a meeting hosted by the users in this group.
+    pub option: Option<String>,
+}
+
+impl GroupsLockedSettingsParams {
+    pub fn custom_query_fields<T: ToString>(mut self, custom_query_fields: T) -> Self {
+        self.custom_query_fields = Some(custom_query_fields.to_string());
+        self
+    }
+
+    pub fn option<T: ToString>(mut self, option: T) -> Self {
+        self.option = Some(option.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Groups::upload_vb_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsUploadVbParams {
+    pub file_ids: Option<String>,
+}
+
+impl GroupsUploadVbParams {
+    pub fn file_ids<T: ToString>(mut self, file_ids: T) -> Self {
+        self.file_ids = Some(file_ids.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Groups::del_vb_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct GroupsDelVbParams {
+    /// Provide the id of the file that is to be deleted. To delete multiple files, provide comma separated values for this field.
+    pub file_ids: Option<String>,
+}
+
+impl GroupsDelVbParams {
+    pub fn file_ids<T: ToString>(mut self, file_ids: T) -> Self {
+        self.file_ids = Some(file_ids.to_string());
+        self
+    }
 }
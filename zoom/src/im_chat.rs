@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ImChat {
     pub client: Client,
@@ -28,29 +26,23 @@ impl ImChat {
      *
      * **Parameters:**
      *
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &ImChatSessionParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn session(
+    pub async fn session_with(
         &self,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        next_page_token: &str,
+        params: &ImChatSessionParams,
     ) -> Result<crate::types::ImChatSessionsResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -59,6 +51,26 @@ impl ImChat {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `session_with` and pass the query parameters as a `ImChatSessionParams` instead.
+     */
+    #[deprecated(note = "use `session_with` instead")]
+    pub async fn session(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::ImChatSessionsResponseAllOf> {
+        let params = ImChatSessionParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.session_with(&params).await
+    }
+
     /**
      * Get IM chat messages.
      *
@@ -80,30 +92,24 @@ impl ImChat {
      * **Parameters:**
      *
      * * `session_id: &str` -- User's first name.
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &ImChatMessageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn message(
+    pub async fn message_with(
         &self,
         session_id: &str,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        next_page_token: &str,
+        params: &ImChatMessageParams,
     ) -> Result<crate::types::ImChatMessagesResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -116,6 +122,27 @@ impl ImChat {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `message_with` and pass the query parameters as a `ImChatMessageParams` instead.
+     */
+    #[deprecated(note = "use `message_with` instead")]
+    pub async fn message(
+        &self,
+        session_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::ImChatMessagesResponseAllOf> {
+        let params = ImChatMessageParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.message_with(session_id, &params).await
+    }
+
     /**
      * Get user’s IM messages.
      *
@@ -131,35 +158,27 @@ impl ImChat {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address.
-     * * `chat_user: &str` -- Chat user's ID or email address.
-     * * `channel: &str` -- User's first name.
-     * * `date: &str` -- IM message's query date time, format as yyyy-MM-dd.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &ImChatListImMessagesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_im_messages(
+    pub async fn list_im_messages_with(
         &self,
         user_id: &str,
-        chat_user: &str,
-        channel: &str,
-        date: &str,
-        page_size: i64,
-        next_page_token: &str,
+        params: &ImChatListImMessagesParams,
     ) -> Result<Vec<crate::types::ListimmessagesResponseMessages>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel.is_empty() {
+        if let Some(channel) = &params.channel {
             query_args.push(("channel".to_string(), channel.to_string()));
         }
-        if !chat_user.is_empty() {
+        if let Some(chat_user) = &params.chat_user {
             query_args.push(("chat_user".to_string(), chat_user.to_string()));
         }
-        if !date.is_empty() {
+        if let Some(date) = &params.date {
             query_args.push(("date".to_string(), date.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -175,6 +194,29 @@ impl ImChat {
         Ok(resp.messages.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_im_messages_with` and pass the query parameters as a `ImChatListImMessagesParams` instead.
+     */
+    #[deprecated(note = "use `list_im_messages_with` instead")]
+    pub async fn list_im_messages(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ListimmessagesResponseMessages>> {
+        let params = ImChatListImMessagesParams {
+            channel: Some(channel.to_string()).filter(|s| !s.is_empty()),
+            chat_user: Some(chat_user.to_string()).filter(|s| !s.is_empty()),
+            date: Some(date.to_string()).filter(|s| !s.is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.list_im_messages_with(user_id, &params).await
+    }
+
     /**
      * Get user’s IM messages.
      *
@@ -188,22 +230,24 @@ impl ImChat {
      * <br><br>
      *   <p style="background-color:#e1f5fe; color:#000000; padding:8px"><b>Deprecated:</b> By end of 2021, Zoom is deprecating this API in favor of a consolidated set of APIs. The API will still be available for you to use, though Zoom will no longer provide support for it. For further information, see <a href="https://marketplace.zoom.us/docs/guides/stay-up-to-date/announcements#im-api-notice">Announcements: IM APIs Deprecation</a>.</p>
      *
+     *
+     * **Parameters:**
+     *
+     * * `params: &ImChatListImMessagesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_im_messages(
+    pub async fn list_all_im_messages_with(
         &self,
         user_id: &str,
-        chat_user: &str,
-        channel: &str,
-        date: &str,
+        params: &ImChatListImMessagesParams,
     ) -> Result<Vec<crate::types::ListimmessagesResponseMessages>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !channel.is_empty() {
+        if let Some(channel) = &params.channel {
             query_args.push(("channel".to_string(), channel.to_string()));
         }
-        if !chat_user.is_empty() {
+        if let Some(chat_user) = &params.chat_user {
             query_args.push(("chat_user".to_string(), chat_user.to_string()));
         }
-        if !date.is_empty() {
+        if let Some(date) = &params.date {
             query_args.push(("date".to_string(), date.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -246,6 +290,84 @@ impl ImChat {
         Ok(messages)
     }
 
+    /**
+     * Deprecated: use `list_all_im_messages_with` and pass the query parameters as a `ImChatListImMessagesParams` instead.
+     */
+    #[deprecated(note = "use `list_all_im_messages_with` instead")]
+    pub async fn list_all_im_messages(
+        &self,
+        user_id: &str,
+        chat_user: &str,
+        channel: &str,
+        date: &str,
+    ) -> Result<Vec<crate::types::ListimmessagesResponseMessages>> {
+        let params = ImChatListImMessagesParams {
+            channel: Some(channel.to_string()).filter(|s| !s.is_empty()),
+            chat_user: Some(chat_user.to_string()).filter(|s| !s.is_empty()),
+            date: Some(date.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_im_messages_with(user_id, &params).await
+    }
+
+    /**
+     * Get user’s IM messages.
+     *
+     * This function performs a `GET` to the `/im/users/{userId}/chat/messages` endpoint.
+     *
+     * As opposed to `list_im_messages`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Get IM Chat messages for a specified period of time. This API only supports Oauth2.<br>
+     * **Scopes:** `imchat:read`<br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     * <br><br>
+     *   <p style="background-color:#e1f5fe; color:#000000; padding:8px"><b>Deprecated:</b> By end of 2021, Zoom is deprecating this API in favor of a consolidated set of APIs. The API will still be available for you to use, though Zoom will no longer provide support for it. For further information, see <a href="https://marketplace.zoom.us/docs/guides/stay-up-to-date/announcements#im-api-notice">Announcements: IM APIs Deprecation</a>.</p>
+     *
+     */
+    pub fn list_im_messages_stream(
+        &self,
+        user_id: &str,
+        params: &ImChatListImMessagesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ListimmessagesResponseMessages> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(channel) = &params.channel {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if let Some(chat_user) = &params.chat_user {
+            query_args.push(("chat_user".to_string(), chat_user.to_string()));
+        }
+        if let Some(date) = &params.date {
+            query_args.push(("date".to_string(), date.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/im/users/{}/chat/messages?{}",
+            crate::progenitor_support::encode_path(user_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListimmessagesResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.messages,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Send IM messages.
      *
@@ -257,15 +379,15 @@ impl ImChat {
      *
      * **Parameters:**
      *
-     * * `chat_user: &str` -- The email address (registered with Zoom) or the userId of the chat user.
+     * * `params: &ImChatSendImMessagesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn send_im_messages(
+    pub async fn send_im_messages_with(
         &self,
-        chat_user: &str,
+        params: &ImChatSendImMessagesParams,
         body: &crate::types::SendimmessagesRequest,
     ) -> Result<crate::types::Groups> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !chat_user.is_empty() {
+        if let Some(chat_user) = &params.chat_user {
             query_args.push(("chat_user".to_string(), chat_user.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -275,4 +397,145 @@ impl ImChat {
             .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
             .await
     }
+
+    /**
+     * Deprecated: use `send_im_messages_with` and pass the query parameters as a `ImChatSendImMessagesParams` instead.
+     */
+    #[deprecated(note = "use `send_im_messages_with` instead")]
+    pub async fn send_im_messages(
+        &self,
+        chat_user: &str,
+        body: &crate::types::SendimmessagesRequest,
+    ) -> Result<crate::types::Groups> {
+        let params = ImChatSendImMessagesParams {
+            chat_user: Some(chat_user.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.send_im_messages_with(&params, body).await
+    }
+}
+
+/// The query parameters of `ImChat::session_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ImChatSessionParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl ImChatSessionParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `ImChat::message_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ImChatMessageParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl ImChatMessageParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `ImChat::list_im_messages_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ImChatListImMessagesParams {
+    /// User's first name.
+    pub channel: Option<String>,
+    /// Chat user's ID or email address.
+    pub chat_user: Option<String>,
+    /// IM message's query date time, format as yyyy-MM-dd.
+    pub date: Option<String>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl ImChatListImMessagesParams {
+    pub fn channel<T: ToString>(mut self, channel: T) -> Self {
+        self.channel = Some(channel.to_string());
+        self
+    }
+
+    pub fn chat_user<T: ToString>(mut self, chat_user: T) -> Self {
+        self.chat_user = Some(chat_user.to_string());
+        self
+    }
+
+    pub fn date<T: ToString>(mut self, date: T) -> Self {
+        self.date = Some(date.to_string());
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `ImChat::send_im_messages_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ImChatSendImMessagesParams {
+    /// The email address (registered with Zoom) or the userId of the chat user.
+    pub chat_user: Option<String>,
+}
+
+impl ImChatSendImMessagesParams {
+    pub fn chat_user<T: ToString>(mut self, chat_user: T) -> Self {
+        self.chat_user = Some(chat_user.to_string());
+        self
+    }
 }
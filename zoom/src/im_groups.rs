@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct ImGroups {
     pub client: Client,
@@ -136,28 +134,21 @@ impl ImGroups {
      *
      * * `group_id: &str` -- The group ID.<br>
      *   Can be retrieved by calling [GET /groups](https://marketplace.zoom.us/docs/api-reference/zoom-api/groups/groups).
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `page_number: i64` --
-     *   **Deprecated** - This field has been deprecated and we will stop supporting it completely in a future release. Please use "next_page_token" for pagination instead of this field.
-     *   
-     *   The page number of the current page in the returned records.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &ImGroupsMemberParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn member(
+    pub async fn member_with(
         &self,
         group_id: &str,
-        page_size: i64,
-        page_number: i64,
-        next_page_token: &str,
+        params: &ImGroupsMemberParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_number > 0 {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -170,6 +161,25 @@ impl ImGroups {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `member_with` and pass the query parameters as a `ImGroupsMemberParams` instead.
+     */
+    #[deprecated(note = "use `member_with` instead")]
+    pub async fn member(
+        &self,
+        group_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = ImGroupsMemberParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_number: Some(page_number).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.member_with(group_id, &params).await
+    }
+
     /**
      * Add IM directory group members.
      *
@@ -226,3 +236,30 @@ impl ImGroups {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `ImGroups::member_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ImGroupsMemberParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    pub page_number: Option<i64>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl ImGroupsMemberParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
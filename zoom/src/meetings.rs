@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Meetings {
     pub client: Client,
@@ -26,30 +24,24 @@ impl Meetings {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `type_: crate::types::MeetingsType` -- The meeting types: <br>`scheduled` - This includes all valid past meetings (unexpired), live meetings and upcoming scheduled meetings. It is equivalent to the combined list of "Previous Meetings" and "Upcoming Meetings" displayed in the user's [Meetings page](https://zoom.us/meeting) on the Zoom Web Portal.<br>`live` - All the ongoing meetings.<br>`upcoming` - All upcoming meetings including live meetings.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `page_number: &str` -- The page number of the current page in the returned records.
+     * * `params: &MeetingsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         user_id: &str,
-        type_: crate::types::MeetingsType,
-        page_size: i64,
-        next_page_token: &str,
-        page_number: &str,
+        params: &MeetingsGetParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if !page_number.is_empty() {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -62,6 +54,27 @@ impl Meetings {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `MeetingsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        user_id: &str,
+        type_: crate::types::MeetingsType,
+        page_size: i64,
+        next_page_token: &str,
+        page_number: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = MeetingsGetParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_number: Some(page_number.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_with(user_id, &params).await
+    }
+
     /**
      * Create a meeting.
      *
@@ -116,20 +129,18 @@ impl Meetings {
      * * `meeting_id: i64` -- The meeting ID in **long** format. The data type of this field is "long"(represented as int64 in JSON).
      *   
      *   While storing it in your database, store it as a **long** data type and **not as an integer**, as the Meeting IDs can be longer than 10 digits.
-     * * `occurrence_id: &str` -- Meeting Occurrence ID. Provide this field to view meeting details of a particular occurrence of the [recurring meeting](https://support.zoom.us/hc/en-us/articles/214973206-Scheduling-Recurring-Meetings).
-     * * `show_previous_occurrences: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+     * * `params: &MeetingsMeetingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn meeting(
+    pub async fn meeting_with(
         &self,
         meeting_id: i64,
-        occurrence_id: &str,
-        show_previous_occurrences: bool,
+        params: &MeetingsMeetingParams,
     ) -> Result<crate::types::MeetingResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
-        if show_previous_occurrences {
+        if let Some(show_previous_occurrences) = &params.show_previous_occurrences {
             query_args.push((
                 "show_previous_occurrences".to_string(),
                 show_previous_occurrences.to_string(),
@@ -145,6 +156,23 @@ impl Meetings {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `meeting_with` and pass the query parameters as a `MeetingsMeetingParams` instead.
+     */
+    #[deprecated(note = "use `meeting_with` instead")]
+    pub async fn meeting(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        show_previous_occurrences: bool,
+    ) -> Result<crate::types::MeetingResponseAllOf> {
+        let params = MeetingsMeetingParams {
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+            show_previous_occurrences: Some(show_previous_occurrences).filter(|v| *v),
+        };
+        self.meeting_with(meeting_id, &params).await
+    }
+
     /**
      * Delete a meeting.
      *
@@ -162,32 +190,20 @@ impl Meetings {
      * * `meeting_id: i64` -- The meeting ID in **long** format. The data type of this field is "long"(represented as int64 in JSON).
      *   
      *   While storing it in your database, store it as a **long** data type and **not as an integer**, as the Meeting IDs can be longer than 10 digits.
-     * * `occurrence_id: &str` -- The meeting occurrence ID.
-     * * `schedule_for_reminder: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
-     * * `cancel_meeting_reminder: &str` -- `true`: Notify registrants about the meeting cancellation via email.
-     *   
-     *   `false`: Do not send any email notification to meeting registrants.
-     *   
-     *   The default value of this field is `false`.
+     * * `params: &MeetingsDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete(
-        &self,
-        meeting_id: i64,
-        occurrence_id: &str,
-        schedule_for_reminder: bool,
-        cancel_meeting_reminder: &str,
-    ) -> Result<()> {
+    pub async fn delete_with(&self, meeting_id: i64, params: &MeetingsDeleteParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cancel_meeting_reminder.is_empty() {
+        if let Some(cancel_meeting_reminder) = &params.cancel_meeting_reminder {
             query_args.push((
                 "cancel_meeting_reminder".to_string(),
                 cancel_meeting_reminder.to_string(),
             ));
         }
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
-        if schedule_for_reminder {
+        if let Some(schedule_for_reminder) = &params.schedule_for_reminder {
             query_args.push((
                 "schedule_for_reminder".to_string(),
                 schedule_for_reminder.to_string(),
@@ -203,6 +219,26 @@ impl Meetings {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_with` and pass the query parameters as a `MeetingsDeleteParams` instead.
+     */
+    #[deprecated(note = "use `delete_with` instead")]
+    pub async fn delete(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        schedule_for_reminder: bool,
+        cancel_meeting_reminder: &str,
+    ) -> Result<()> {
+        let params = MeetingsDeleteParams {
+            cancel_meeting_reminder: Some(cancel_meeting_reminder.to_string())
+                .filter(|s| !s.is_empty()),
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+            schedule_for_reminder: Some(schedule_for_reminder).filter(|v| *v),
+        };
+        self.delete_with(meeting_id, &params).await
+    }
+
     /**
      * Update a meeting.
      *
@@ -217,16 +253,16 @@ impl Meetings {
      * * `meeting_id: i64` -- The meeting ID in **long** format. The data type of this field is "long"(represented as int64 in JSON).
      *   
      *   While storing it in your database, store it as a **long** data type and **not as an integer**, as the Meeting IDs can be longer than 10 digits.
-     * * `occurrence_id: &str` -- Meeting occurrence id. Support change of agenda, start_time, duration, settings: {host_video, participant_video, join_before_host, mute_upon_entry, waiting_room, watermark, auto_recording}.
+     * * `params: &MeetingsUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update(
+    pub async fn update_with(
         &self,
         meeting_id: i64,
-        occurrence_id: &str,
+        params: &MeetingsUpdateParams,
         body: &crate::types::MeetingUpdateRequestAllOf,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -241,6 +277,22 @@ impl Meetings {
             .await
     }
 
+    /**
+     * Deprecated: use `update_with` and pass the query parameters as a `MeetingsUpdateParams` instead.
+     */
+    #[deprecated(note = "use `update_with` instead")]
+    pub async fn update(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::MeetingUpdateRequestAllOf,
+    ) -> Result<()> {
+        let params = MeetingsUpdateParams {
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.update_with(meeting_id, &params, body).await
+    }
+
     /**
      * Update meeting status.
      *
@@ -286,38 +338,27 @@ impl Meetings {
      * * `meeting_id: i64` -- The meeting ID in **long** format. The data type of this field is "long"(represented as int64 in JSON).
      *   
      *   While storing it in your database, store it as a **long** data type and **not as an integer**, as the Meeting IDs can be longer than 10 digits.
-     * * `occurrence_id: &str` -- The meeting occurrence ID.
-     * * `status: crate::types::MeetingRegistrantsStatus` -- The registrant status:<br>`pending` - Registrant's status is pending.<br>`approved` - Registrant's status is approved.<br>`denied` - Registrant's status is denied.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `page_number: i64` --
-     *   **Deprecated** - This field has been deprecated and we will stop supporting it completely in a future release. Please use "next_page_token" for pagination instead of this field.
-     *   
-     *   The page number of the current page in the returned records.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &MeetingsRegistrantParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn registrant(
+    pub async fn registrant_with(
         &self,
         meeting_id: i64,
-        occurrence_id: &str,
-        status: crate::types::MeetingRegistrantsStatus,
-        page_size: i64,
-        page_number: i64,
-        next_page_token: &str,
+        params: &MeetingsRegistrantParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
-        if page_number > 0 {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -330,6 +371,29 @@ impl Meetings {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `registrant_with` and pass the query parameters as a `MeetingsRegistrantParams` instead.
+     */
+    #[deprecated(note = "use `registrant_with` instead")]
+    pub async fn registrant(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        status: crate::types::MeetingRegistrantsStatus,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = MeetingsRegistrantParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+            page_number: Some(page_number).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+        };
+        self.registrant_with(meeting_id, &params).await
+    }
+
     /**
      * Add meeting registrant.
      *
@@ -348,15 +412,15 @@ impl Meetings {
      * * `meeting_id: i64` -- The meeting ID in **long** format. The data type of this field is "long"(represented as int64 in JSON).
      *   
      *   While storing it in your database, store it as a **long** data type and **not as an integer**, as the Meeting IDs can be longer than 10 digits.
-     * * `occurrence_ids: &str` -- Occurrence IDs. You can find these with the meeting get API. Multiple values separated by comma.
+     * * `params: &MeetingsRegistrantCreateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn registrant_create(
+    pub async fn registrant_create_with(
         &self,
         meeting_id: i64,
-        occurrence_ids: &str,
+        params: &MeetingsRegistrantCreateParams,
     ) -> Result<crate::types::MeetingRegistrantCreateResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !occurrence_ids.is_empty() {
+        if let Some(occurrence_ids) = &params.occurrence_ids {
             query_args.push(("occurrence_ids".to_string(), occurrence_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -369,6 +433,21 @@ impl Meetings {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `registrant_create_with` and pass the query parameters as a `MeetingsRegistrantCreateParams` instead.
+     */
+    #[deprecated(note = "use `registrant_create_with` instead")]
+    pub async fn registrant_create(
+        &self,
+        meeting_id: i64,
+        occurrence_ids: &str,
+    ) -> Result<crate::types::MeetingRegistrantCreateResponse> {
+        let params = MeetingsRegistrantCreateParams {
+            occurrence_ids: Some(occurrence_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.registrant_create_with(meeting_id, &params).await
+    }
+
     /**
      * Delete a meeting registrant.
      *
@@ -381,18 +460,18 @@ impl Meetings {
      *
      * **Parameters:**
      *
-     * * `occurrence_id: &str` -- The meeting occurence ID.
      * * `meeting_id: i64` -- Account seats.
      * * `registrant_id: &str` -- The meeting registrant ID.
+     * * `params: &MeetingsMeetingregistrantdeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn meetingregistrantdelete(
+    pub async fn meetingregistrantdelete_with(
         &self,
-        occurrence_id: &str,
         meeting_id: i64,
         registrant_id: &str,
+        params: &MeetingsMeetingregistrantdeleteParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -406,6 +485,23 @@ impl Meetings {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `meetingregistrantdelete_with` and pass the query parameters as a `MeetingsMeetingregistrantdeleteParams` instead.
+     */
+    #[deprecated(note = "use `meetingregistrantdelete_with` instead")]
+    pub async fn meetingregistrantdelete(
+        &self,
+        occurrence_id: &str,
+        meeting_id: i64,
+        registrant_id: &str,
+    ) -> Result<()> {
+        let params = MeetingsMeetingregistrantdeleteParams {
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.meetingregistrantdelete_with(meeting_id, registrant_id, &params)
+            .await
+    }
+
     /**
      * Update registrant's status.
      *
@@ -420,16 +516,16 @@ impl Meetings {
      * * `meeting_id: i64` -- The meeting ID in **long** format. The data type of this field is "long"(represented as int64 in JSON).
      *   
      *   While storing it in your database, store it as a **long** data type and **not as an integer**, as the Meeting IDs can be longer than 10 digits.
-     * * `occurrence_id: &str` -- The meeting occurrence ID.
+     * * `params: &MeetingsRegistrantStatusParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn registrant_status(
+    pub async fn registrant_status_with(
         &self,
         meeting_id: i64,
-        occurrence_id: &str,
+        params: &MeetingsRegistrantStatusParams,
         body: &crate::types::RegistrantStatus,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -444,6 +540,22 @@ impl Meetings {
             .await
     }
 
+    /**
+     * Deprecated: use `registrant_status_with` and pass the query parameters as a `MeetingsRegistrantStatusParams` instead.
+     */
+    #[deprecated(note = "use `registrant_status_with` instead")]
+    pub async fn registrant_status(
+        &self,
+        meeting_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::RegistrantStatus,
+    ) -> Result<()> {
+        let params = MeetingsRegistrantStatusParams {
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.registrant_status_with(meeting_id, &params, body).await
+    }
+
     /**
      * Get past meeting details.
      *
@@ -490,23 +602,18 @@ impl Meetings {
      *
      * **Parameters:**
      *
-     * * `meeting: &str` -- The meeting's universally unique identifier (UUID). Each meeting instance generates a UUID. For example, after a meeting ends, a new UUID is generated for the next meeting instance.
-     *   
-     *   If the meeting UUID begins with a `/` character or contains a `//` character, you **must** double-encode the meeting UUID when using the meeting UUID for other API calls.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &MeetingsPastParticipantParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn past_participant(
+    pub async fn past_participant_with(
         &self,
         meeting_uuid: &str,
-        page_size: i64,
-        next_page_token: &str,
+        params: &MeetingsPastParticipantParams,
     ) -> Result<crate::types::PastMeetingParticipantsResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -519,6 +626,23 @@ impl Meetings {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `past_participant_with` and pass the query parameters as a `MeetingsPastParticipantParams` instead.
+     */
+    #[deprecated(note = "use `past_participant_with` instead")]
+    pub async fn past_participant(
+        &self,
+        meeting_uuid: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::PastMeetingParticipantsResponseAllOf> {
+        let params = MeetingsPastParticipantParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.past_participant_with(meeting_uuid, &params).await
+    }
+
     /**
      * List ended meeting instances.
      *
@@ -535,7 +659,7 @@ impl Meetings {
      *   
      *   While storing it in your database, store it as a **long** data type and **not as an integer**, as the Meeting IDs can be longer than 10 digits.
      */
-    pub async fn past(&self, meeting_id: i64) -> Result<crate::types::Domains> {
+    pub async fn past(&self, meeting_id: i64) -> Result<crate::types::PastMeetingsResponse> {
         let url = format!(
             "/past_meetings/{}/instances",
             crate::progenitor_support::encode_path(&meeting_id.to_string()),
@@ -563,7 +687,7 @@ impl Meetings {
      *   
      *   While storing it in your database, store it as a **long** data type and **not as an integer**, as the Meeting IDs can be longer than 10 digits.
      */
-    pub async fn poll(&self, meeting_id: i64) -> Result<crate::types::Domains> {
+    pub async fn poll(&self, meeting_id: i64) -> Result<crate::types::MeetingPollsResponse> {
         let url = format!(
             "/meetings/{}/polls",
             crate::progenitor_support::encode_path(&meeting_id.to_string()),
@@ -1103,3 +1227,209 @@ impl Meetings {
             .await
     }
 }
+
+/// The query parameters of `Meetings::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MeetingsGetParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The page number of the current page in the returned records.
+    pub page_number: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// The meeting types: <br>`scheduled` - This includes all valid past meetings (unexpired), live meetings and upcoming scheduled meetings. It is equivalent to the combined list of "Previous Meetings" and "Upcoming Meetings" displayed in the user's [Meetings page](https://zoom.us/meeting) on the Zoom Web Portal.<br>`live` - All the ongoing meetings.<br>`upcoming` - All upcoming meetings including live meetings.
+    pub type_: Option<crate::types::MeetingsType>,
+}
+
+impl MeetingsGetParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_number<T: ToString>(mut self, page_number: T) -> Self {
+        self.page_number = Some(page_number.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::MeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Meetings::meeting_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MeetingsMeetingParams {
+    /// Meeting Occurrence ID. Provide this field to view meeting details of a particular occurrence of the [recurring meeting](https://support.zoom.us/hc/en-us/articles/214973206-Scheduling-Recurring-Meetings).
+    pub occurrence_id: Option<String>,
+    /// Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+    pub show_previous_occurrences: Option<bool>,
+}
+
+impl MeetingsMeetingParams {
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+
+    pub fn show_previous_occurrences(mut self, show_previous_occurrences: bool) -> Self {
+        self.show_previous_occurrences = Some(show_previous_occurrences);
+        self
+    }
+}
+
+/// The query parameters of `Meetings::delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MeetingsDeleteParams {
+    /// `true`: Notify registrants about the meeting cancellation via email.
+    ///
+    /// `false`: Do not send any email notification to meeting registrants.
+    ///
+    /// The default value of this field is `false`.
+    pub cancel_meeting_reminder: Option<String>,
+    /// The meeting occurrence ID.
+    pub occurrence_id: Option<String>,
+    /// Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+    pub schedule_for_reminder: Option<bool>,
+}
+
+impl MeetingsDeleteParams {
+    pub fn cancel_meeting_reminder<T: ToString>(mut self, cancel_meeting_reminder: T) -> Self {
+        self.cancel_meeting_reminder = Some(cancel_meeting_reminder.to_string());
+        self
+    }
+
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+
+    pub fn schedule_for_reminder(mut self, schedule_for_reminder: bool) -> Self {
+        self.schedule_for_reminder = Some(schedule_for_reminder);
+        self
+    }
+}
+
+/// The query parameters of `Meetings::update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MeetingsUpdateParams {
+    /// Meeting occurrence id. Support change of agenda, start_time, duration, settings: {host_video, participant_video, join_before_host, mute_upon_entry, waiting_room, watermark, auto_recording}.
+    pub occurrence_id: Option<String>,
+}
+
+impl MeetingsUpdateParams {
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Meetings::registrant_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MeetingsRegistrantParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The meeting occurrence ID.
+    pub occurrence_id: Option<String>,
+    pub page_number: Option<i64>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// The registrant status:<br>`pending` - Registrant's status is pending.<br>`approved` - Registrant's status is approved.<br>`denied` - Registrant's status is denied.
+    pub status: Option<crate::types::MeetingRegistrantsStatus>,
+}
+
+impl MeetingsRegistrantParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::MeetingRegistrantsStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// The query parameters of `Meetings::registrant_create_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MeetingsRegistrantCreateParams {
+    /// Occurrence IDs. You can find these with the meeting get API. Multiple values separated by comma.
+    pub occurrence_ids: Option<String>,
+}
+
+impl MeetingsRegistrantCreateParams {
+    pub fn occurrence_ids<T: ToString>(mut self, occurrence_ids: T) -> Self {
+        self.occurrence_ids = Some(occurrence_ids.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Meetings::meetingregistrantdelete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MeetingsMeetingregistrantdeleteParams {
+    /// The meeting occurence ID.
+    pub occurrence_id: Option<String>,
+}
+
+impl MeetingsMeetingregistrantdeleteParams {
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Meetings::registrant_status_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MeetingsRegistrantStatusParams {
+    /// The meeting occurrence ID.
+    pub occurrence_id: Option<String>,
+}
+
+impl MeetingsRegistrantStatusParams {
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Meetings::past_participant_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct MeetingsPastParticipantParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl MeetingsPastParticipantParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
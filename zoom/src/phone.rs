@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Phone {
     pub client: Client,
@@ -60,50 +58,32 @@ impl Phone {
      *
      * **Parameters:**
      *
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `type_: crate::types::ListAccountPhoneNumbersType` -- Query response by number assignment. The value can be one of the following:
-     *  <br>
-     *  `assigned`: The number has been assigned to either a user, a call queue, an auto-receptionist or a common area phone in an account. <br>`unassigned`: The number is not assigned to anyone.<br>
-     *  `all`: Include both assigned and unassigned numbers in the response.<br>
-     *  `byoc`: Include Bring Your Own Carrier (BYOC) numbers only in the response.
-     * * `extension_type: crate::types::ExtensionType` -- The type of assignee to whom the number is assigned. The value can be one of the following:<br>
-     *  `user`<br> `callQueue`<br> `autoReceptionist`<br>
-     *  `commonAreaPhone`.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `number_type: crate::types::Type` -- The type of phone number. The value can be either `toll` or `tollfree`.
-     * * `pending_numbers: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
-     * * `site_id: &str` -- Unique identifier of the site. Use this query parameter if you have enabled multiple sites and would like to filter the response of this API call by a specific phone site. See [Managing multiple sites](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites) or [Adding a site](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites#h_05c88e35-1593-491f-b1a8-b7139a75dc15) for details.
+     * * `params: &PhoneListAccountNumbersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_account_numbers(
+    pub async fn list_account_numbers_with(
         &self,
-        next_page_token: &str,
-        type_: crate::types::ListAccountPhoneNumbersType,
-        extension_type: crate::types::ExtensionType,
-        page_size: i64,
-        number_type: crate::types::Type,
-        pending_numbers: bool,
-        site_id: &str,
+        params: &PhoneListAccountNumbersParams,
     ) -> Result<Vec<crate::types::ListAccountPhoneNumbersResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !extension_type.to_string().is_empty() {
+        if let Some(extension_type) = &params.extension_type {
             query_args.push(("extension_type".to_string(), extension_type.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if !number_type.to_string().is_empty() {
+        if let Some(number_type) = &params.number_type {
             query_args.push(("number_type".to_string(), number_type.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if pending_numbers {
+        if let Some(pending_numbers) = &params.pending_numbers {
             query_args.push(("pending_numbers".to_string(), pending_numbers.to_string()));
         }
-        if !site_id.is_empty() {
+        if let Some(site_id) = &params.site_id {
             query_args.push(("site_id".to_string(), site_id.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -116,6 +96,32 @@ impl Phone {
         Ok(resp.phone_numbers.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_account_numbers_with` and pass the query parameters as a `PhoneListAccountNumbersParams` instead.
+     */
+    #[deprecated(note = "use `list_account_numbers_with` instead")]
+    pub async fn list_account_numbers(
+        &self,
+        next_page_token: &str,
+        type_: crate::types::ListAccountPhoneNumbersType,
+        extension_type: crate::types::ExtensionType,
+        page_size: i64,
+        number_type: crate::types::Type,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListAccountPhoneNumbersResponse>> {
+        let params = PhoneListAccountNumbersParams {
+            extension_type: Some(extension_type).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            number_type: Some(number_type).filter(|v| !v.to_string().is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            pending_numbers: Some(pending_numbers).filter(|v| *v),
+            site_id: Some(site_id.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_account_numbers_with(&params).await
+    }
+
     /**
      * List phone numbers.
      *
@@ -130,29 +136,29 @@ impl Phone {
      * **Prerequisites:**
      * * A Pro or higher account plan
      * * A Zoom Phone license
+     *
+     * **Parameters:**
+     *
+     * * `params: &PhoneListAccountNumbersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_account_numbers(
+    pub async fn list_all_account_numbers_with(
         &self,
-        type_: crate::types::ListAccountPhoneNumbersType,
-        extension_type: crate::types::ExtensionType,
-        number_type: crate::types::Type,
-        pending_numbers: bool,
-        site_id: &str,
+        params: &PhoneListAccountNumbersParams,
     ) -> Result<Vec<crate::types::ListAccountPhoneNumbersResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !extension_type.to_string().is_empty() {
+        if let Some(extension_type) = &params.extension_type {
             query_args.push(("extension_type".to_string(), extension_type.to_string()));
         }
-        if !number_type.to_string().is_empty() {
+        if let Some(number_type) = &params.number_type {
             query_args.push(("number_type".to_string(), number_type.to_string()));
         }
-        if pending_numbers {
+        if let Some(pending_numbers) = &params.pending_numbers {
             query_args.push(("pending_numbers".to_string(), pending_numbers.to_string()));
         }
-        if !site_id.is_empty() {
+        if let Some(site_id) = &params.site_id {
             query_args.push(("site_id".to_string(), site_id.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -192,6 +198,90 @@ impl Phone {
         Ok(phone_numbers)
     }
 
+    /**
+     * Deprecated: use `list_all_account_numbers_with` and pass the query parameters as a `PhoneListAccountNumbersParams` instead.
+     */
+    #[deprecated(note = "use `list_all_account_numbers_with` instead")]
+    pub async fn list_all_account_numbers(
+        &self,
+        type_: crate::types::ListAccountPhoneNumbersType,
+        extension_type: crate::types::ExtensionType,
+        number_type: crate::types::Type,
+        pending_numbers: bool,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListAccountPhoneNumbersResponse>> {
+        let params = PhoneListAccountNumbersParams {
+            extension_type: Some(extension_type).filter(|v| !v.to_string().is_empty()),
+            number_type: Some(number_type).filter(|v| !v.to_string().is_empty()),
+            pending_numbers: Some(pending_numbers).filter(|v| *v),
+            site_id: Some(site_id.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_account_numbers_with(&params).await
+    }
+
+    /**
+     * List phone numbers.
+     *
+     * This function performs a `GET` to the `/phone/numbers` endpoint.
+     *
+     * As opposed to `list_account_numbers`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to list all Zoom Phone numbers in a Zoom account.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     */
+    pub fn list_account_numbers_stream(
+        &self,
+        params: &PhoneListAccountNumbersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ListAccountPhoneNumbersResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(extension_type) = &params.extension_type {
+            query_args.push(("extension_type".to_string(), extension_type.to_string()));
+        }
+        if let Some(number_type) = &params.number_type {
+            query_args.push(("number_type".to_string(), number_type.to_string()));
+        }
+        if let Some(pending_numbers) = &params.pending_numbers {
+            query_args.push(("pending_numbers".to_string(), pending_numbers.to_string()));
+        }
+        if let Some(site_id) = &params.site_id {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/phone/numbers?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListAccountPhoneNumbersResponseData =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.phone_numbers,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Get user's profile.
      *
@@ -335,24 +425,20 @@ impl Phone {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- Number of records returns within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `site_id: &str` -- Unique identifier of the site. This field is required only if multiple sites have been enabled.  of the site. Required only when multiple sites are enabled. See [Managing multiple sites](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites) for details. If this is not provided, the response lists the account level setting templates.
+     * * `params: &PhoneListSettingTemplatesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_setting_templates(
+    pub async fn list_setting_templates_with(
         &self,
-        page_size: i64,
-        next_page_token: &str,
-        site_id: &str,
+        params: &PhoneListSettingTemplatesParams,
     ) -> Result<Vec<crate::types::Templates>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !site_id.is_empty() {
+        if let Some(site_id) = &params.site_id {
             query_args.push(("site_id".to_string(), site_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -364,6 +450,24 @@ impl Phone {
         Ok(resp.templates.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_setting_templates_with` and pass the query parameters as a `PhoneListSettingTemplatesParams` instead.
+     */
+    #[deprecated(note = "use `list_setting_templates_with` instead")]
+    pub async fn list_setting_templates(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::Templates>> {
+        let params = PhoneListSettingTemplatesParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            site_id: Some(site_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_setting_templates_with(&params).await
+    }
+
     /**
      * List setting templates.
      *
@@ -378,13 +482,17 @@ impl Phone {
      * **Prerequisites:**
      * * A Business or Enterprise account
      * * A Zoom Phone license
+     *
+     * **Parameters:**
+     *
+     * * `params: &PhoneListSettingTemplatesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_setting_templates(
+    pub async fn list_all_setting_templates_with(
         &self,
-        site_id: &str,
+        params: &PhoneListSettingTemplatesParams,
     ) -> Result<Vec<crate::types::Templates>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !site_id.is_empty() {
+        if let Some(site_id) = &params.site_id {
             query_args.push(("site_id".to_string(), site_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -424,6 +532,70 @@ impl Phone {
         Ok(templates)
     }
 
+    /**
+     * Deprecated: use `list_all_setting_templates_with` and pass the query parameters as a `PhoneListSettingTemplatesParams` instead.
+     */
+    #[deprecated(note = "use `list_all_setting_templates_with` instead")]
+    pub async fn list_all_setting_templates(
+        &self,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::Templates>> {
+        let params = PhoneListSettingTemplatesParams {
+            site_id: Some(site_id.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_setting_templates_with(&params).await
+    }
+
+    /**
+     * List setting templates.
+     *
+     * This function performs a `GET` to the `/phone/setting_templates` endpoint.
+     *
+     * As opposed to `list_setting_templates`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to get a list of all the created phone template settings.
+     *
+     * **Scopes:** `phone:read:admin` or `phone:read`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn list_setting_templates_stream(
+        &self,
+        params: &PhoneListSettingTemplatesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Templates> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(site_id) = &params.site_id {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/phone/setting_templates?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListSettingTemplatesResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.templates,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Add a setting template.
      *
@@ -479,19 +651,17 @@ impl Phone {
      *
      * **Parameters:**
      *
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `page_size: i64` -- The number of records returned within a single API call.
+     * * `params: &PhoneListLocationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_locations(
+    pub async fn list_locations_with(
         &self,
-        next_page_token: &str,
-        page_size: i64,
+        params: &PhoneListLocationsParams,
     ) -> Result<Vec<crate::types::ListLocationsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -503,6 +673,22 @@ impl Phone {
         Ok(resp.locations.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_locations_with` and pass the query parameters as a `PhoneListLocationsParams` instead.
+     */
+    #[deprecated(note = "use `list_locations_with` instead")]
+    pub async fn list_locations(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ListLocationsResponse>> {
+        let params = PhoneListLocationsParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.list_locations_with(&params).await
+    }
+
     /**
      * List emergency service locations.
      *
@@ -553,6 +739,47 @@ impl Phone {
         Ok(locations)
     }
 
+    /**
+     * List emergency service locations.
+     *
+     * This function performs a `GET` to the `/phone/locations` endpoint.
+     *
+     * As opposed to `list_locations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to list emergency service locations.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_locations_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ListLocationsResponse> {
+        let url = "/phone/locations".to_string();
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListLocationsResponseData = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.locations,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Add emergency service location.
      *
@@ -674,19 +901,17 @@ impl Phone {
      *
      * **Parameters:**
      *
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `page_size: i64` -- The number of records returned within a single API call.
+     * * `params: &PhoneListSipGroupsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_sip_groups(
+    pub async fn list_sip_groups_with(
         &self,
-        next_page_token: &str,
-        page_size: i64,
+        params: &PhoneListSipGroupsParams,
     ) -> Result<Vec<crate::types::SipGroups>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -698,6 +923,22 @@ impl Phone {
         Ok(resp.sip_groups.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_sip_groups_with` and pass the query parameters as a `PhoneListSipGroupsParams` instead.
+     */
+    #[deprecated(note = "use `list_sip_groups_with` instead")]
+    pub async fn list_sip_groups(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::SipGroups>> {
+        let params = PhoneListSipGroupsParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.list_sip_groups_with(&params).await
+    }
+
     /**
      * List SIP groups.
      *
@@ -748,6 +989,47 @@ impl Phone {
         Ok(sip_groups)
     }
 
+    /**
+     * List SIP groups.
+     *
+     * This function performs a `GET` to the `/phone/sip_groups` endpoint.
+     *
+     * As opposed to `list_sip_groups`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to list SIP (Session Initiation Protocol) groups.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_sip_groups_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SipGroups> {
+        let url = "/phone/sip_groups".to_string();
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListSipGroupsResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.sip_groups,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Get setting template details.
      *
@@ -764,15 +1046,15 @@ impl Phone {
      * **Parameters:**
      *
      * * `template_id: &str` -- Unique identifier of the template.
-     * * `custom_query_fields: &str` -- Provide the name of the field to use to filter the response. For example, if you provide "description" as the value of the field, you will get a response similar to the following: {“description”: “template description”}.
+     * * `params: &PhoneGetSettingTemplateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_setting_template(
+    pub async fn get_setting_template_with(
         &self,
         template_id: &str,
-        custom_query_fields: &str,
+        params: &PhoneGetSettingTemplateParams,
     ) -> Result<crate::types::GetSettingTemplateResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
@@ -788,6 +1070,21 @@ impl Phone {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_setting_template_with` and pass the query parameters as a `PhoneGetSettingTemplateParams` instead.
+     */
+    #[deprecated(note = "use `get_setting_template_with` instead")]
+    pub async fn get_setting_template(
+        &self,
+        template_id: &str,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::GetSettingTemplateResponse> {
+        let params = PhoneGetSettingTemplateParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_setting_template_with(template_id, &params).await
+    }
+
     /**
      * Update a setting template.
      *
@@ -836,45 +1133,33 @@ impl Phone {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `type_: crate::types::PhoneUserCallLogsType`
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `phone_number: &str` -- Filter API responses to include call logs of only the phone number defined in this field.
-     * * `time_type: crate::types::TimeType` -- Enables you to sort call logs by start or end time. Choose the sort time value. Values include `startTime` or `endTime`.
+     * * `params: &PhoneUserCallLogsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn user_call_logs(
+    pub async fn user_call_logs_with(
         &self,
         user_id: &str,
-        page_size: i64,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        type_: crate::types::PhoneUserCallLogsType,
-        next_page_token: &str,
-        phone_number: &str,
-        time_type: crate::types::TimeType,
+        params: &PhoneUserCallLogsParams,
     ) -> Result<Vec<crate::types::CallLogs>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !phone_number.is_empty() {
+        if let Some(phone_number) = &params.phone_number {
             query_args.push(("phone_number".to_string(), phone_number.to_string()));
         }
-        if !time_type.to_string().is_empty() {
+        if let Some(time_type) = &params.time_type {
             query_args.push(("time_type".to_string(), time_type.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -890,6 +1175,33 @@ impl Phone {
         Ok(resp.call_logs.to_vec())
     }
 
+    /**
+     * Deprecated: use `user_call_logs_with` and pass the query parameters as a `PhoneUserCallLogsParams` instead.
+     */
+    #[deprecated(note = "use `user_call_logs_with` instead")]
+    pub async fn user_call_logs(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: crate::types::PhoneUserCallLogsType,
+        next_page_token: &str,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> Result<Vec<crate::types::CallLogs>> {
+        let params = PhoneUserCallLogsParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            phone_number: Some(phone_number.to_string()).filter(|s| !s.is_empty()),
+            time_type: Some(time_type).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.user_call_logs_with(user_id, &params).await
+    }
+
     /**
      * Get user's call logs.
      *
@@ -904,30 +1216,30 @@ impl Phone {
      * **Prerequisites:**
      * * A Business or Enterprise account
      * * A Zoom Phone license
+     *
+     * **Parameters:**
+     *
+     * * `params: &PhoneUserCallLogsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_user_call_logs(
+    pub async fn get_all_user_call_logs_with(
         &self,
         user_id: &str,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        type_: crate::types::PhoneUserCallLogsType,
-        phone_number: &str,
-        time_type: crate::types::TimeType,
+        params: &PhoneUserCallLogsParams,
     ) -> Result<Vec<crate::types::CallLogs>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !phone_number.is_empty() {
+        if let Some(phone_number) = &params.phone_number {
             query_args.push(("phone_number".to_string(), phone_number.to_string()));
         }
-        if !time_type.to_string().is_empty() {
+        if let Some(time_type) = &params.time_type {
             query_args.push(("time_type".to_string(), time_type.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -971,13 +1283,102 @@ impl Phone {
     }
 
     /**
-     * Get user's recordings.
+     * Deprecated: use `get_all_user_call_logs_with` and pass the query parameters as a `PhoneUserCallLogsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_user_call_logs_with` instead")]
+    pub async fn get_all_user_call_logs(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        type_: crate::types::PhoneUserCallLogsType,
+        phone_number: &str,
+        time_type: crate::types::TimeType,
+    ) -> Result<Vec<crate::types::CallLogs>> {
+        let params = PhoneUserCallLogsParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            phone_number: Some(phone_number.to_string()).filter(|s| !s.is_empty()),
+            time_type: Some(time_type).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_user_call_logs_with(user_id, &params).await
+    }
+
+    /**
+     * Get user's call logs.
      *
-     * This function performs a `GET` to the `/phone/users/{userId}/recordings` endpoint.
+     * This function performs a `GET` to the `/phone/users/{userId}/call_logs` endpoint.
      *
-     * Use this API to get a user's [Zoom Phone recordings](https://support.zoom.us/hc/en-us/articles/360021336671-Viewing-Call-History-and-Recordings). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     * As opposed to `user_call_logs`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
      *
-     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_recording:read`, `phone_recording:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     * Use this API to get a user's [Zoom phone](https://support.zoom.us/hc/en-us/articles/360001297663-Quickstart-Guide-for-Zoom-Phone-Administrators) call logs. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_call_log:read`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn user_call_logs_stream(
+        &self,
+        user_id: &str,
+        params: &PhoneUserCallLogsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CallLogs> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(from) = &params.from {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(phone_number) = &params.phone_number {
+            query_args.push(("phone_number".to_string(), phone_number.to_string()));
+        }
+        if let Some(time_type) = &params.time_type {
+            query_args.push(("time_type".to_string(), time_type.to_string()));
+        }
+        if let Some(to) = &params.to {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/phone/users/{}/call_logs?{}",
+            crate::progenitor_support::encode_path(user_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::PhoneUserCallLogsResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.call_logs,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
+    /**
+     * Get user's recordings.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/recordings` endpoint.
+     *
+     * Use this API to get a user's [Zoom Phone recordings](https://support.zoom.us/hc/en-us/articles/360021336671-Viewing-Call-History-and-Recordings). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_recording:read`, `phone_recording:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
      *
      * **Prerequisites:**
      * * A Business or Enterprise account
@@ -986,30 +1387,24 @@ impl Phone {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `from: chrono::NaiveDate` -- Start date for the query in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the response includes only one month worth of recording data. The month defined should fall within the last six months.
-     * * `to: chrono::NaiveDate` -- Start Date.
+     * * `params: &PhoneUserRecordingsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn user_recordings(
+    pub async fn user_recordings_with(
         &self,
         user_id: &str,
-        page_size: i64,
-        next_page_token: &str,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
+        params: &PhoneUserRecordingsParams,
     ) -> Result<Vec<crate::types::Recordings>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1025,6 +1420,27 @@ impl Phone {
         Ok(resp.recordings.to_vec())
     }
 
+    /**
+     * Deprecated: use `user_recordings_with` and pass the query parameters as a `PhoneUserRecordingsParams` instead.
+     */
+    #[deprecated(note = "use `user_recordings_with` instead")]
+    pub async fn user_recordings(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::Recordings>> {
+        let params = PhoneUserRecordingsParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.user_recordings_with(user_id, &params).await
+    }
+
     /**
      * Get user's recordings.
      *
@@ -1039,18 +1455,21 @@ impl Phone {
      * **Prerequisites:**
      * * A Business or Enterprise account
      * * A Zoom Phone license
+     *
+     * **Parameters:**
+     *
+     * * `params: &PhoneUserRecordingsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_user_recordings(
+    pub async fn get_all_user_recordings_with(
         &self,
         user_id: &str,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
+        params: &PhoneUserRecordingsParams,
     ) -> Result<Vec<crate::types::Recordings>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1094,6 +1513,81 @@ impl Phone {
         Ok(recordings)
     }
 
+    /**
+     * Deprecated: use `get_all_user_recordings_with` and pass the query parameters as a `PhoneUserRecordingsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_user_recordings_with` instead")]
+    pub async fn get_all_user_recordings(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::Recordings>> {
+        let params = PhoneUserRecordingsParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_user_recordings_with(user_id, &params).await
+    }
+
+    /**
+     * Get user's recordings.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/recordings` endpoint.
+     *
+     * As opposed to `user_recordings`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to get a user's [Zoom Phone recordings](https://support.zoom.us/hc/en-us/articles/360021336671-Viewing-Call-History-and-Recordings). For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_recording:read`, `phone_recording:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn user_recordings_stream(
+        &self,
+        user_id: &str,
+        params: &PhoneUserRecordingsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Recordings> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(from) = &params.from {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(to) = &params.to {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/phone/users/{}/recordings?{}",
+            crate::progenitor_support::encode_path(user_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::PhoneUserRecordingsResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.recordings,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Get user's voicemails.
      *
@@ -1110,35 +1604,27 @@ impl Phone {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `status: crate::types::PhoneUserVoiceMailsStatus` -- Status of the voice mail.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `from: chrono::NaiveDate` -- Start date for the query in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the response includes only one month worth of voicemail data. The month defined should fall within the last six months.
-     * * `to: chrono::NaiveDate` -- Start Date.
+     * * `params: &PhoneUserVoiceMailsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn user_voice_mails(
+    pub async fn user_voice_mails_with(
         &self,
         user_id: &str,
-        page_size: i64,
-        status: crate::types::PhoneUserVoiceMailsStatus,
-        next_page_token: &str,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
+        params: &PhoneUserVoiceMailsParams,
     ) -> Result<Vec<crate::types::VoiceMails>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1154,6 +1640,29 @@ impl Phone {
         Ok(resp.voice_mails.to_vec())
     }
 
+    /**
+     * Deprecated: use `user_voice_mails_with` and pass the query parameters as a `PhoneUserVoiceMailsParams` instead.
+     */
+    #[deprecated(note = "use `user_voice_mails_with` instead")]
+    pub async fn user_voice_mails(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::VoiceMails>> {
+        let params = PhoneUserVoiceMailsParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.user_voice_mails_with(user_id, &params).await
+    }
+
     /**
      * Get user's voicemails.
      *
@@ -1168,22 +1677,24 @@ impl Phone {
      * **Prerequisites:**
      * * A Business or Enterprise account
      * * A Zoom Phone license
+     *
+     * **Parameters:**
+     *
+     * * `params: &PhoneUserVoiceMailsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_user_voice_mails(
+    pub async fn get_all_user_voice_mails_with(
         &self,
         user_id: &str,
-        status: crate::types::PhoneUserVoiceMailsStatus,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
+        params: &PhoneUserVoiceMailsParams,
     ) -> Result<Vec<crate::types::VoiceMails>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1227,6 +1738,86 @@ impl Phone {
         Ok(voice_mails)
     }
 
+    /**
+     * Deprecated: use `get_all_user_voice_mails_with` and pass the query parameters as a `PhoneUserVoiceMailsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_user_voice_mails_with` instead")]
+    pub async fn get_all_user_voice_mails(
+        &self,
+        user_id: &str,
+        status: crate::types::PhoneUserVoiceMailsStatus,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::VoiceMails>> {
+        let params = PhoneUserVoiceMailsParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_user_voice_mails_with(user_id, &params).await
+    }
+
+    /**
+     * Get user's voicemails.
+     *
+     * This function performs a `GET` to the `/phone/users/{userId}/voice_mails` endpoint.
+     *
+     * As opposed to `user_voice_mails`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to get a user's Zoom Phone voicemails. For user-level apps, pass [the `me` value](https://marketplace.zoom.us/docs/api-reference/using-zoom-apis#mekeyword) instead of the `userId` parameter.
+     *
+     * **Scopes:** `phone:read`, `phone:read:admin`, `phone_voicemail:read`, `phone_voicemail:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     */
+    pub fn user_voice_mails_stream(
+        &self,
+        user_id: &str,
+        params: &PhoneUserVoiceMailsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::VoiceMails> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(from) = &params.from {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(status) = &params.status {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if let Some(to) = &params.to {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/phone/users/{}/voice_mails?{}",
+            crate::progenitor_support::encode_path(user_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::PhoneUserVoiceMailsResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.voice_mails,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Set up shared access.
      *
@@ -1283,16 +1874,16 @@ impl Phone {
      *
      * * `user_id: &str` -- Unique identifier of the user.
      * * `setting_type: &str` -- Corresponds to the setting item you wish to remove. Allowed values: `voice_mail`.
-     * * `shared_id: &str` -- Required only for voicemail setting type.
+     * * `params: &PhoneDeleteUserSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_user_setting(
+    pub async fn delete_user_setting_with(
         &self,
         user_id: &str,
         setting_type: &str,
-        shared_id: &str,
+        params: &PhoneDeleteUserSettingParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !shared_id.is_empty() {
+        if let Some(shared_id) = &params.shared_id {
             query_args.push(("shared_id".to_string(), shared_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1306,6 +1897,23 @@ impl Phone {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_user_setting_with` and pass the query parameters as a `PhoneDeleteUserSettingParams` instead.
+     */
+    #[deprecated(note = "use `delete_user_setting_with` instead")]
+    pub async fn delete_user_setting(
+        &self,
+        user_id: &str,
+        setting_type: &str,
+        shared_id: &str,
+    ) -> Result<()> {
+        let params = PhoneDeleteUserSettingParams {
+            shared_id: Some(shared_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_user_setting_with(user_id, setting_type, &params)
+            .await
+    }
+
     /**
      * Update shared access.
      *
@@ -1360,53 +1968,35 @@ impl Phone {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `from: &str` -- Start date from which you would like to get the call logs. The start date should be within past six months. <br>
-     *   
-     *   The API only returns data pertaining to a month. Thus, the date range(defined using "from" and "to" fields) for which the call logs are to be returned must not exceed a month.
-     * * `to: &str` -- The end date upto which you would like to get the call logs for. The end date should be within past six months.
-     * * `type_: &str` -- The type of the call logs. The value can be either "all" or "missed".
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `path: &str` -- Filter the API response by [path](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-and-identifying-logs#h_646b46c6-0623-4ab1-8b8b-ea5b8bcef679) of the call. The value of this field can be one of the following: `voiceMail`, `message`, `forward`, `extension`, `callQueue`, `ivrMenu`, `companyDirectory`, `autoReceptionist`, `contactCenter`, `disconnected`, `commonAreaPhone`,
-     *   `pstn`, `transfer`, `sharedLines`, `sharedLineGroup`, `tollFreeBilling`, `meetingService`, `parkPickup`,
-     *   `parkTimeout`, `monitor`, `takeover`, `sipGroup`.
-     * * `time_type: crate::types::TimeType` -- Enables you to sort call logs by start or end time. Choose the sort time value. Values include `startTime` or `endTime`.
-     * * `site_id: &str` -- Unique identifier of the [site](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites). Use this query parameter if you have enabled multiple sites and would like to filter the response of this API call by call logs of a specific phone site.
+     * * `params: &PhoneAccountCallLogsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn account_call_logs(
+    pub async fn account_call_logs_with(
         &self,
-        page_size: i64,
-        from: &str,
-        to: &str,
-        type_: &str,
-        next_page_token: &str,
-        path: &str,
-        time_type: crate::types::TimeType,
-        site_id: &str,
+        params: &PhoneAccountCallLogsParams,
     ) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !site_id.is_empty() {
+        if let Some(site_id) = &params.site_id {
             query_args.push(("site_id".to_string(), site_id.to_string()));
         }
-        if !time_type.to_string().is_empty() {
+        if let Some(time_type) = &params.time_type {
             query_args.push(("time_type".to_string(), time_type.to_string()));
         }
-        if !to.is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1418,6 +2008,34 @@ impl Phone {
         Ok(resp.call_logs.to_vec())
     }
 
+    /**
+     * Deprecated: use `account_call_logs_with` and pass the query parameters as a `PhoneAccountCallLogsParams` instead.
+     */
+    #[deprecated(note = "use `account_call_logs_with` instead")]
+    pub async fn account_call_logs(
+        &self,
+        page_size: i64,
+        from: &str,
+        to: &str,
+        type_: &str,
+        next_page_token: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
+        let params = PhoneAccountCallLogsParams {
+            from: Some(from.to_string()).filter(|s| !s.is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            site_id: Some(site_id.to_string()).filter(|s| !s.is_empty()),
+            time_type: Some(time_type).filter(|v| !v.to_string().is_empty()),
+            to: Some(to.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.account_call_logs_with(&params).await
+    }
+
     /**
      * Get account's call logs.
      *
@@ -1433,33 +2051,32 @@ impl Phone {
      * * A Business or Enterprise account
      * * A Zoom Phone license
      * * Account owner and a [role](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) with Zoom Phone management
+     *
+     * **Parameters:**
+     *
+     * * `params: &PhoneAccountCallLogsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_account_call_logs(
+    pub async fn get_all_account_call_logs_with(
         &self,
-        from: &str,
-        to: &str,
-        type_: &str,
-        path: &str,
-        time_type: crate::types::TimeType,
-        site_id: &str,
+        params: &PhoneAccountCallLogsParams,
     ) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !path.is_empty() {
+        if let Some(path) = &params.path {
             query_args.push(("path".to_string(), path.to_string()));
         }
-        if !site_id.is_empty() {
+        if let Some(site_id) = &params.site_id {
             query_args.push(("site_id".to_string(), site_id.to_string()));
         }
-        if !time_type.to_string().is_empty() {
+        if let Some(time_type) = &params.time_type {
             query_args.push(("time_type".to_string(), time_type.to_string()));
         }
-        if !to.is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1499,6 +2116,96 @@ impl Phone {
         Ok(call_logs)
     }
 
+    /**
+     * Deprecated: use `get_all_account_call_logs_with` and pass the query parameters as a `PhoneAccountCallLogsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_account_call_logs_with` instead")]
+    pub async fn get_all_account_call_logs(
+        &self,
+        from: &str,
+        to: &str,
+        type_: &str,
+        path: &str,
+        time_type: crate::types::TimeType,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::AccountCallLogsResponse>> {
+        let params = PhoneAccountCallLogsParams {
+            from: Some(from.to_string()).filter(|s| !s.is_empty()),
+            path: Some(path.to_string()).filter(|s| !s.is_empty()),
+            site_id: Some(site_id.to_string()).filter(|s| !s.is_empty()),
+            time_type: Some(time_type).filter(|v| !v.to_string().is_empty()),
+            to: Some(to.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_account_call_logs_with(&params).await
+    }
+
+    /**
+     * Get account's call logs.
+     *
+     * This function performs a `GET` to the `/phone/call_logs` endpoint.
+     *
+     * As opposed to `account_call_logs`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to return an account's [call logs](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-Call-Logs).
+     *
+     * **Scopes:** `phone:read:admin`, `phone_call_log:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     * * A Zoom Phone license
+     * * Account owner and a [role](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) with Zoom Phone management
+     */
+    pub fn account_call_logs_stream(
+        &self,
+        params: &PhoneAccountCallLogsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AccountCallLogsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(from) = &params.from {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(path) = &params.path {
+            query_args.push(("path".to_string(), path.to_string()));
+        }
+        if let Some(site_id) = &params.site_id {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if let Some(time_type) = &params.time_type {
+            query_args.push(("time_type".to_string(), time_type.to_string()));
+        }
+        if let Some(to) = &params.to {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/phone/call_logs?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::AccountCallLogsResponseData =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.call_logs,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Assign phone number to user.
      *
@@ -1630,54 +2337,35 @@ impl Phone {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- The number of records returned within a single API call. The default is **30**, and the maximum is **100**.
-     * * `next_page_token: &str` -- The current page number of returned records.
-     * * `from: &str` -- Start date and time in **yyyy-mm-dd** format or **yyyy-MM-dd’T’HH:mm:ss’Z’** format. The date range defined by the from and to parameters should only be one month as the report includes only one month worth of data at once.
-     *   .
-     * * `to: &str` -- End date and time in **yyyy-mm-dd** format or **yyyy-MM-dd’T’HH:mm:ss’Z’** format, the same formats supported by the `from` parameter.
-     *   
-     *   .
-     * * `owner_type: &str` -- The owner type. The allowed values are null, `user`, or `callQueue`. The default is null. If null, returns all owner types.
-     *   .
-     * * `recording_type: &str` -- The recording type. The allowed values are null, `OnDemand`, or `Automatic`. The default is null. If null, returns all recording types.
-     *   .
-     * * `site_id: &str` -- The site ID. The default is `All sites`.
-     * * `query_date_type: crate::types::QueryDateType` -- Date types:<br>`start_time` - Query by call start time.<br>`end_time` - Query by call end time.
+     * * `params: &PhoneGetRecordingsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_recordings(
+    pub async fn get_recordings_with(
         &self,
-        page_size: i64,
-        next_page_token: &str,
-        from: &str,
-        to: &str,
-        owner_type: &str,
-        recording_type: &str,
-        site_id: &str,
-        query_date_type: crate::types::QueryDateType,
+        params: &PhoneGetRecordingsParams,
     ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if !owner_type.is_empty() {
+        if let Some(owner_type) = &params.owner_type {
             query_args.push(("owner_type".to_string(), owner_type.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !query_date_type.to_string().is_empty() {
+        if let Some(query_date_type) = &params.query_date_type {
             query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
         }
-        if !recording_type.is_empty() {
+        if let Some(recording_type) = &params.recording_type {
             query_args.push(("recording_type".to_string(), recording_type.to_string()));
         }
-        if !site_id.is_empty() {
+        if let Some(site_id) = &params.site_id {
             query_args.push(("site_id".to_string(), site_id.to_string()));
         }
-        if !to.is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1690,6 +2378,34 @@ impl Phone {
         Ok(resp.recordings.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_recordings_with` and pass the query parameters as a `PhoneGetRecordingsParams` instead.
+     */
+    #[deprecated(note = "use `get_recordings_with` instead")]
+    pub async fn get_recordings(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        from: &str,
+        to: &str,
+        owner_type: &str,
+        recording_type: &str,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
+        let params = PhoneGetRecordingsParams {
+            from: Some(from.to_string()).filter(|s| !s.is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            owner_type: Some(owner_type.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            query_date_type: Some(query_date_type).filter(|v| !v.to_string().is_empty()),
+            recording_type: Some(recording_type.to_string()).filter(|s| !s.is_empty()),
+            site_id: Some(site_id.to_string()).filter(|s| !s.is_empty()),
+            to: Some(to.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_recordings_with(&params).await
+    }
+
     /**
      * Get call recordings.
      *
@@ -1705,33 +2421,32 @@ impl Phone {
      * * A Pro or higher account plan
      * * A Zoom Phone license
      * * Account owner or admin privileges
+     *
+     * **Parameters:**
+     *
+     * * `params: &PhoneGetRecordingsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_recordings(
+    pub async fn get_all_recordings_with(
         &self,
-        from: &str,
-        to: &str,
-        owner_type: &str,
-        recording_type: &str,
-        site_id: &str,
-        query_date_type: crate::types::QueryDateType,
+        params: &PhoneGetRecordingsParams,
     ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !owner_type.is_empty() {
+        if let Some(owner_type) = &params.owner_type {
             query_args.push(("owner_type".to_string(), owner_type.to_string()));
         }
-        if !query_date_type.to_string().is_empty() {
+        if let Some(query_date_type) = &params.query_date_type {
             query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
         }
-        if !recording_type.is_empty() {
+        if let Some(recording_type) = &params.recording_type {
             query_args.push(("recording_type".to_string(), recording_type.to_string()));
         }
-        if !site_id.is_empty() {
+        if let Some(site_id) = &params.site_id {
             query_args.push(("site_id".to_string(), site_id.to_string()));
         }
-        if !to.is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1771,6 +2486,96 @@ impl Phone {
         Ok(recordings)
     }
 
+    /**
+     * Deprecated: use `get_all_recordings_with` and pass the query parameters as a `PhoneGetRecordingsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_recordings_with` instead")]
+    pub async fn get_all_recordings(
+        &self,
+        from: &str,
+        to: &str,
+        owner_type: &str,
+        recording_type: &str,
+        site_id: &str,
+        query_date_type: crate::types::QueryDateType,
+    ) -> Result<Vec<crate::types::GetPhoneRecordingsResponse>> {
+        let params = PhoneGetRecordingsParams {
+            from: Some(from.to_string()).filter(|s| !s.is_empty()),
+            owner_type: Some(owner_type.to_string()).filter(|s| !s.is_empty()),
+            query_date_type: Some(query_date_type).filter(|v| !v.to_string().is_empty()),
+            recording_type: Some(recording_type.to_string()).filter(|s| !s.is_empty()),
+            site_id: Some(site_id.to_string()).filter(|s| !s.is_empty()),
+            to: Some(to.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_recordings_with(&params).await
+    }
+
+    /**
+     * Get call recordings.
+     *
+     * This function performs a `GET` to the `/phone/recordings` endpoint.
+     *
+     * As opposed to `get_recordings`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to list an account's [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091-Accessing-and-sharing-call-recordings)
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`,`phone_recording:read:admin`
+     *
+     * **Prerequisties:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     * * Account owner or admin privileges
+     */
+    pub fn get_recordings_stream(
+        &self,
+        params: &PhoneGetRecordingsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetPhoneRecordingsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(from) = &params.from {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(owner_type) = &params.owner_type {
+            query_args.push(("owner_type".to_string(), owner_type.to_string()));
+        }
+        if let Some(query_date_type) = &params.query_date_type {
+            query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
+        }
+        if let Some(recording_type) = &params.recording_type {
+            query_args.push(("recording_type".to_string(), recording_type.to_string()));
+        }
+        if let Some(site_id) = &params.site_id {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        if let Some(to) = &params.to {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/phone/recordings?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::GetPhoneRecordingsResponseData =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.recordings,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * List BYOC SIP trunks.
      *
@@ -1785,19 +2590,17 @@ impl Phone {
      *
      * **Parameters:**
      *
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `page_size: i64` -- The number of records returned within a single API call.
+     * * `params: &PhoneListByocsipTrunkParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_byocsip_trunk(
+    pub async fn list_byocsip_trunk_with(
         &self,
-        next_page_token: &str,
-        page_size: i64,
+        params: &PhoneListByocsipTrunkParams,
     ) -> Result<Vec<crate::types::ByocSipTrunk>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1809,6 +2612,22 @@ impl Phone {
         Ok(resp.byoc_sip_trunk.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_byocsip_trunk_with` and pass the query parameters as a `PhoneListByocsipTrunkParams` instead.
+     */
+    #[deprecated(note = "use `list_byocsip_trunk_with` instead")]
+    pub async fn list_byocsip_trunk(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ByocSipTrunk>> {
+        let params = PhoneListByocsipTrunkParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.list_byocsip_trunk_with(&params).await
+    }
+
     /**
      * List BYOC SIP trunks.
      *
@@ -1858,6 +2677,46 @@ impl Phone {
         Ok(byoc_sip_trunk)
     }
 
+    /**
+     * List BYOC SIP trunks.
+     *
+     * This function performs a `GET` to the `/phone/sip_trunk/trunks` endpoint.
+     *
+     * As opposed to `list_byocsip_trunk`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to return a list of an account's assigned [BYOC (Bring Your Own Carrier) SIP (Session Initiation Protocol) trunks](https://zoom.us/docs/doc/Zoom-Bring%20Your%20Own%20Carrier.pdf).
+     *
+     * **Scopes:** `phone:write:admin` or `phone:master`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Business or Enterprise account
+     */
+    pub fn list_byocsip_trunk_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ByocSipTrunk> {
+        let url = "/phone/sip_trunk/trunks".to_string();
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListByocsipTrunkResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.byoc_sip_trunk,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Assign SIP trunks.
      *
@@ -1938,19 +2797,17 @@ impl Phone {
      *
      * **Parameters:**
      *
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `page_size: i64` -- The number of records returned within a single API call.
+     * * `params: &PhoneListExternalContactsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_external_contacts(
+    pub async fn list_external_contacts_with(
         &self,
-        next_page_token: &str,
-        page_size: i64,
+        params: &PhoneListExternalContactsParams,
     ) -> Result<Vec<crate::types::ExternalContacts>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1962,6 +2819,22 @@ impl Phone {
         Ok(resp.external_contacts.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_external_contacts_with` and pass the query parameters as a `PhoneListExternalContactsParams` instead.
+     */
+    #[deprecated(note = "use `list_external_contacts_with` instead")]
+    pub async fn list_external_contacts(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ExternalContacts>> {
+        let params = PhoneListExternalContactsParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.list_external_contacts_with(&params).await
+    }
+
     /**
      * List external contacts.
      *
@@ -2013,6 +2886,48 @@ impl Phone {
         Ok(external_contacts)
     }
 
+    /**
+     * List external contacts.
+     *
+     * This function performs a `GET` to the `/phone/external_contacts` endpoint.
+     *
+     * As opposed to `list_external_contacts`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to list external contacts.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_external_contacts_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ExternalContacts> {
+        let url = "/phone/external_contacts".to_string();
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListExternalContactsResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.external_contacts,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Add an external contact.
      *
@@ -2241,24 +3156,20 @@ impl Phone {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- The number of records returned from a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `site_id: &str` -- Unique Identifier of the site. This can be retrieved from the [List Phone Sites](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone-site/listphonesites) API.
+     * * `params: &PhoneListUsersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_users(
+    pub async fn list_users_with(
         &self,
-        page_size: i64,
-        next_page_token: &str,
-        site_id: &str,
+        params: &PhoneListUsersParams,
     ) -> Result<Vec<crate::types::ListPhoneUsersResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !site_id.is_empty() {
+        if let Some(site_id) = &params.site_id {
             query_args.push(("site_id".to_string(), site_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2270,6 +3181,24 @@ impl Phone {
         Ok(resp.users.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_users_with` and pass the query parameters as a `PhoneListUsersParams` instead.
+     */
+    #[deprecated(note = "use `list_users_with` instead")]
+    pub async fn list_users(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListPhoneUsersResponse>> {
+        let params = PhoneListUsersParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            site_id: Some(site_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_users_with(&params).await
+    }
+
     /**
      * List phone users.
      *
@@ -2284,13 +3213,17 @@ impl Phone {
      * **Prerequisites:**
      * * A Pro or higher account plan
      * * A Zoom Phone license
+     *
+     * **Parameters:**
+     *
+     * * `params: &PhoneListUsersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_users(
+    pub async fn list_all_users_with(
         &self,
-        site_id: &str,
+        params: &PhoneListUsersParams,
     ) -> Result<Vec<crate::types::ListPhoneUsersResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !site_id.is_empty() {
+        if let Some(site_id) = &params.site_id {
             query_args.push(("site_id".to_string(), site_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -2330,6 +3263,69 @@ impl Phone {
         Ok(users)
     }
 
+    /**
+     * Deprecated: use `list_all_users_with` and pass the query parameters as a `PhoneListUsersParams` instead.
+     */
+    #[deprecated(note = "use `list_all_users_with` instead")]
+    pub async fn list_all_users(
+        &self,
+        site_id: &str,
+    ) -> Result<Vec<crate::types::ListPhoneUsersResponse>> {
+        let params = PhoneListUsersParams {
+            site_id: Some(site_id.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_users_with(&params).await
+    }
+
+    /**
+     * List phone users.
+     *
+     * This function performs a `GET` to the `/phone/users` endpoint.
+     *
+     * As opposed to `list_users`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to return a list of all of an account's users who are assigned a Zoom Phone license.
+     *
+     * **Scopes:** `phone:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Light`
+     *
+     * **Prerequisites:**
+     * * A Pro or higher account plan
+     * * A Zoom Phone license
+     */
+    pub fn list_users_stream(
+        &self,
+        params: &PhoneListUsersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ListPhoneUsersResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(site_id) = &params.site_id {
+            query_args.push(("site_id".to_string(), site_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/phone/users?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListPhoneUsersResponseData = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.users,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Get call log details.
      *
@@ -2435,3 +3431,500 @@ impl Phone {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `Phone::list_account_numbers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneListAccountNumbersParams {
+    /// The type of assignee to whom the number is assigned. The value can be one of the following:<br>
+    /// `user`<br> `callQueue`<br> `autoReceptionist`<br>
+    /// `commonAreaPhone`.
+    pub extension_type: Option<crate::types::ExtensionType>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The type of phone number. The value can be either `toll` or `tollfree`.
+    pub number_type: Option<crate::types::Type>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+    pub pending_numbers: Option<bool>,
+    /// Unique identifier of the site. Use this query parameter if you have enabled multiple sites and would like to filter the response of this API call by a specific phone site. See [Managing multiple sites](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites) or [Adding a site](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites#h_05c88e35-1593-491f-b1a8-b7139a75dc15) for details.
+    pub site_id: Option<String>,
+    /// Query response by number assignment. The value can be one of the following:
+    /// <br>
+    /// `assigned`: The number has been assigned to either a user, a call queue, an auto-receptionist or a common area phone in an account. <br>`unassigned`: The number is not assigned to anyone.<br>
+    /// `all`: Include both assigned and unassigned numbers in the response.<br>
+    /// `byoc`: Include Bring Your Own Carrier (BYOC) numbers only in the response.
+    pub type_: Option<crate::types::ListAccountPhoneNumbersType>,
+}
+
+impl PhoneListAccountNumbersParams {
+    pub fn extension_type(mut self, extension_type: crate::types::ExtensionType) -> Self {
+        self.extension_type = Some(extension_type);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn number_type(mut self, number_type: crate::types::Type) -> Self {
+        self.number_type = Some(number_type);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn pending_numbers(mut self, pending_numbers: bool) -> Self {
+        self.pending_numbers = Some(pending_numbers);
+        self
+    }
+
+    pub fn site_id<T: ToString>(mut self, site_id: T) -> Self {
+        self.site_id = Some(site_id.to_string());
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::ListAccountPhoneNumbersType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Phone::list_setting_templates_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneListSettingTemplatesParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// Number of records returns within a single API call.
+    pub page_size: Option<i64>,
+    /// Unique identifier of the site. This field is required only if multiple sites have been enabled.  of the site. Required only when multiple sites are enabled. See [Managing multiple sites](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites) for details. If this is not provided, the response lists the account level setting templates.
+    pub site_id: Option<String>,
+}
+
+impl PhoneListSettingTemplatesParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn site_id<T: ToString>(mut self, site_id: T) -> Self {
+        self.site_id = Some(site_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Phone::list_locations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneListLocationsParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl PhoneListLocationsParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `Phone::list_sip_groups_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneListSipGroupsParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl PhoneListSipGroupsParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `Phone::get_setting_template_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneGetSettingTemplateParams {
+    /// Provide the name of the field to use to filter the response. For example, if you provide "description" as the value of the field, you will get a response similar to the following: {“description”: “template description”}.
+    pub custom_query_fields: Option<String>,
+}
+
+impl PhoneGetSettingTemplateParams {
+    pub fn custom_query_fields<T: ToString>(mut self, custom_query_fields: T) -> Self {
+        self.custom_query_fields = Some(custom_query_fields.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Phone::user_call_logs_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneUserCallLogsParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Filter API responses to include call logs of only the phone number defined in this field.
+    pub phone_number: Option<String>,
+    /// Enables you to sort call logs by start or end time. Choose the sort time value. Values include `startTime` or `endTime`.
+    pub time_type: Option<crate::types::TimeType>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+    pub type_: Option<crate::types::PhoneUserCallLogsType>,
+}
+
+impl PhoneUserCallLogsParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn phone_number<T: ToString>(mut self, phone_number: T) -> Self {
+        self.phone_number = Some(phone_number.to_string());
+        self
+    }
+
+    pub fn time_type(mut self, time_type: crate::types::TimeType) -> Self {
+        self.time_type = Some(time_type);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::PhoneUserCallLogsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Phone::user_recordings_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneUserRecordingsParams {
+    /// Start date for the query in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the response includes only one month worth of recording data. The month defined should fall within the last six months.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl PhoneUserRecordingsParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Phone::user_voice_mails_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneUserVoiceMailsParams {
+    /// Start date for the query in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the response includes only one month worth of voicemail data. The month defined should fall within the last six months.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Status of the voice mail.
+    pub status: Option<crate::types::PhoneUserVoiceMailsStatus>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl PhoneUserVoiceMailsParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::PhoneUserVoiceMailsStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Phone::delete_user_setting_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneDeleteUserSettingParams {
+    /// Required only for voicemail setting type.
+    pub shared_id: Option<String>,
+}
+
+impl PhoneDeleteUserSettingParams {
+    pub fn shared_id<T: ToString>(mut self, shared_id: T) -> Self {
+        self.shared_id = Some(shared_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Phone::account_call_logs_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneAccountCallLogsParams {
+    /// Start date from which you would like to get the call logs. The start date should be within past six months. <br>
+    ///
+    /// The API only returns data pertaining to a month. Thus, the date range(defined using "from" and "to" fields) for which the call logs are to be returned must not exceed a month.
+    pub from: Option<String>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Filter the API response by [path](https://support.zoom.us/hc/en-us/articles/360021114452-Viewing-and-identifying-logs#h_646b46c6-0623-4ab1-8b8b-ea5b8bcef679) of the call. The value of this field can be one of the following: `voiceMail`, `message`, `forward`, `extension`, `callQueue`, `ivrMenu`, `companyDirectory`, `autoReceptionist`, `contactCenter`, `disconnected`, `commonAreaPhone`,
+    /// `pstn`, `transfer`, `sharedLines`, `sharedLineGroup`, `tollFreeBilling`, `meetingService`, `parkPickup`,
+    /// `parkTimeout`, `monitor`, `takeover`, `sipGroup`.
+    pub path: Option<String>,
+    /// Unique identifier of the [site](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-multiple-sites). Use this query parameter if you have enabled multiple sites and would like to filter the response of this API call by call logs of a specific phone site.
+    pub site_id: Option<String>,
+    /// Enables you to sort call logs by start or end time. Choose the sort time value. Values include `startTime` or `endTime`.
+    pub time_type: Option<crate::types::TimeType>,
+    /// The end date upto which you would like to get the call logs for. The end date should be within past six months.
+    pub to: Option<String>,
+    /// The type of the call logs. The value can be either "all" or "missed".
+    pub type_: Option<String>,
+}
+
+impl PhoneAccountCallLogsParams {
+    pub fn from<T: ToString>(mut self, from: T) -> Self {
+        self.from = Some(from.to_string());
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn path<T: ToString>(mut self, path: T) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    pub fn site_id<T: ToString>(mut self, site_id: T) -> Self {
+        self.site_id = Some(site_id.to_string());
+        self
+    }
+
+    pub fn time_type(mut self, time_type: crate::types::TimeType) -> Self {
+        self.time_type = Some(time_type);
+        self
+    }
+
+    pub fn to<T: ToString>(mut self, to: T) -> Self {
+        self.to = Some(to.to_string());
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Phone::get_recordings_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneGetRecordingsParams {
+    /// Start date and time in **yyyy-mm-dd** format or **yyyy-MM-dd’T’HH:mm:ss’Z’** format. The date range defined by the from and to parameters should only be one month as the report includes only one month worth of data at once.
+    /// .
+    pub from: Option<String>,
+    /// The current page number of returned records.
+    pub next_page_token: Option<String>,
+    /// The owner type. The allowed values are null, `user`, or `callQueue`. The default is null. If null, returns all owner types.
+    /// .
+    pub owner_type: Option<String>,
+    /// The number of records returned within a single API call. The default is **30**, and the maximum is **100**.
+    pub page_size: Option<i64>,
+    /// Date types:<br>`start_time` - Query by call start time.<br>`end_time` - Query by call end time.
+    pub query_date_type: Option<crate::types::QueryDateType>,
+    /// The recording type. The allowed values are null, `OnDemand`, or `Automatic`. The default is null. If null, returns all recording types.
+    /// .
+    pub recording_type: Option<String>,
+    /// The site ID. The default is `All sites`.
+    pub site_id: Option<String>,
+    /// End date and time in **yyyy-mm-dd** format or **yyyy-MM-dd’T’HH:mm:ss’Z’** format, the same formats supported by the `from` parameter.
+    ///
+    /// .
+    pub to: Option<String>,
+}
+
+impl PhoneGetRecordingsParams {
+    pub fn from<T: ToString>(mut self, from: T) -> Self {
+        self.from = Some(from.to_string());
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn owner_type<T: ToString>(mut self, owner_type: T) -> Self {
+        self.owner_type = Some(owner_type.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn query_date_type(mut self, query_date_type: crate::types::QueryDateType) -> Self {
+        self.query_date_type = Some(query_date_type);
+        self
+    }
+
+    pub fn recording_type<T: ToString>(mut self, recording_type: T) -> Self {
+        self.recording_type = Some(recording_type.to_string());
+        self
+    }
+
+    pub fn site_id<T: ToString>(mut self, site_id: T) -> Self {
+        self.site_id = Some(site_id.to_string());
+        self
+    }
+
+    pub fn to<T: ToString>(mut self, to: T) -> Self {
+        self.to = Some(to.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Phone::list_byocsip_trunk_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneListByocsipTrunkParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl PhoneListByocsipTrunkParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `Phone::list_external_contacts_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneListExternalContactsParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl PhoneListExternalContactsParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `Phone::list_users_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneListUsersParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned from a single API call.
+    pub page_size: Option<i64>,
+    /// Unique Identifier of the site. This can be retrieved from the [List Phone Sites](https://marketplace.zoom.us/docs/api-reference/zoom-api/phone-site/listphonesites) API.
+    pub site_id: Option<String>,
+}
+
+impl PhoneListUsersParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn site_id<T: ToString>(mut self, site_id: T) -> Self {
+        self.site_id = Some(site_id.to_string());
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct PhoneBlockedList {
     pub client: Client,
@@ -27,19 +25,17 @@ impl PhoneBlockedList {
      *
      * **Parameters:**
      *
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `page_size: i64` -- The total number of records returned from a single API call.
+     * * `params: &PhoneBlockedListListBlockedParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_blocked(
+    pub async fn list_blocked_with(
         &self,
-        next_page_token: &str,
-        page_size: i64,
+        params: &PhoneBlockedListListBlockedParams,
     ) -> Result<Vec<crate::types::BlockedList>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -51,6 +47,22 @@ impl PhoneBlockedList {
         Ok(resp.blocked_list.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_blocked_with` and pass the query parameters as a `PhoneBlockedListListBlockedParams` instead.
+     */
+    #[deprecated(note = "use `list_blocked_with` instead")]
+    pub async fn list_blocked(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::BlockedList>> {
+        let params = PhoneBlockedListListBlockedParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.list_blocked_with(&params).await
+    }
+
     /**
      * List blocked lists.
      *
@@ -101,6 +113,47 @@ impl PhoneBlockedList {
         Ok(blocked_list)
     }
 
+    /**
+     * List blocked lists.
+     *
+     * This function performs a `GET` to the `/phone/blocked_list` endpoint.
+     *
+     * As opposed to `list_blocked`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * A Zoom account owner or a user with admin privilege can block phone numbers for phone users in an account. Blocked numbers can be inbound (numbers will be blocked from calling in) and outbound (phone users in your account won't be able to dial those numbers). Blocked callers will hear a generic message stating that the person they are calling is not available.<br>Use this API to list all the blocked lists in an acccount.<br>
+     * **Prerequisites:**
+     * * Pro or higher account plan with Zoom phone license<br>
+     * **Scope:** `phone:read:admin`<br>
+     *
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_blocked_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::BlockedList> {
+        let url = "/phone/blocked_list".to_string();
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListBlockedResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.blocked_list,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a blocked list.
      *
@@ -211,3 +264,24 @@ impl PhoneBlockedList {
             .await
     }
 }
+
+/// The query parameters of `PhoneBlockedList::list_blocked_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneBlockedListListBlockedParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The total number of records returned from a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl PhoneBlockedListListBlockedParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct PhoneCallQueues {
     pub client: Client,
@@ -30,19 +28,17 @@ impl PhoneCallQueues {
      *
      * **Parameters:**
      *
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `page_size: i64` -- The number of records returned from a single API call.
+     * * `params: &PhoneCallQueuesListCallQueuesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_call_queues(
+    pub async fn list_call_queues_with(
         &self,
-        next_page_token: &str,
-        page_size: i64,
+        params: &PhoneCallQueuesListCallQueuesParams,
     ) -> Result<Vec<crate::types::CallQueues>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -54,6 +50,22 @@ impl PhoneCallQueues {
         Ok(resp.call_queues.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_call_queues_with` and pass the query parameters as a `PhoneCallQueuesListCallQueuesParams` instead.
+     */
+    #[deprecated(note = "use `list_call_queues_with` instead")]
+    pub async fn list_call_queues(
+        &self,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::CallQueues>> {
+        let params = PhoneCallQueuesListCallQueuesParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.list_call_queues_with(&params).await
+    }
+
     /**
      * List call queues.
      *
@@ -107,6 +119,50 @@ impl PhoneCallQueues {
         Ok(call_queues)
     }
 
+    /**
+     * List call queues.
+     *
+     * This function performs a `GET` to the `/phone/call_queues` endpoint.
+     *
+     * As opposed to `list_call_queues`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Call queues allow you to route incoming calls to a group of users. For instance, you can use call queues to route calls to various departments in your organization such as sales, engineering, billing, customer service etc.<br> Use this API to list Call queues.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro, Business, or Education account
+     * * Account owner or admin permissions
+     * * Zoom Phone license<br>
+     * **Scopes:** `phone:read:admin`<br>
+     *
+     *
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     */
+    pub fn list_call_queues_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::CallQueues> {
+        let url = "/phone/call_queues".to_string();
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListCallQueuesResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.call_queues,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a call queue.
      *
@@ -470,30 +526,24 @@ impl PhoneCallQueues {
      * **Parameters:**
      *
      * * `call_queue_id: &str` -- Unique Identifier of the Call Queue.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `from: chrono::NaiveDate` -- Start date (within a 6 month range).
-     * * `to: chrono::NaiveDate` -- End date (within a 6 month range).
+     * * `params: &PhoneCallQueuesGetCallQueueRecordingsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_call_queue_recordings(
+    pub async fn get_call_queue_recordings_with(
         &self,
         call_queue_id: &str,
-        page_size: i64,
-        next_page_token: &str,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
+        params: &PhoneCallQueuesGetCallQueueRecordingsParams,
     ) -> Result<Vec<crate::types::GetCallQueueRecordingsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -510,6 +560,28 @@ impl PhoneCallQueues {
         Ok(resp.recordings.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_call_queue_recordings_with` and pass the query parameters as a `PhoneCallQueuesGetCallQueueRecordingsParams` instead.
+     */
+    #[deprecated(note = "use `get_call_queue_recordings_with` instead")]
+    pub async fn get_call_queue_recordings(
+        &self,
+        call_queue_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::GetCallQueueRecordingsResponse>> {
+        let params = PhoneCallQueuesGetCallQueueRecordingsParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_call_queue_recordings_with(call_queue_id, &params)
+            .await
+    }
+
     /**
      * Get call queue recordings.
      *
@@ -525,18 +597,21 @@ impl PhoneCallQueues {
      *
      *
      *
+     *
+     * **Parameters:**
+     *
+     * * `params: &PhoneCallQueuesGetCallQueueRecordingsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_call_queue_recordings(
+    pub async fn get_all_call_queue_recordings_with(
         &self,
         call_queue_id: &str,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
+        params: &PhoneCallQueuesGetCallQueueRecordingsParams,
     ) -> Result<Vec<crate::types::GetCallQueueRecordingsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -579,4 +654,137 @@ impl PhoneCallQueues {
         // Return our response data.
         Ok(recordings)
     }
+
+    /**
+     * Deprecated: use `get_all_call_queue_recordings_with` and pass the query parameters as a `PhoneCallQueuesGetCallQueueRecordingsParams` instead.
+     */
+    #[deprecated(note = "use `get_all_call_queue_recordings_with` instead")]
+    pub async fn get_all_call_queue_recordings(
+        &self,
+        call_queue_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::GetCallQueueRecordingsResponse>> {
+        let params = PhoneCallQueuesGetCallQueueRecordingsParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_call_queue_recordings_with(call_queue_id, &params)
+            .await
+    }
+
+    /**
+     * Get call queue recordings.
+     *
+     * This function performs a `GET` to the `/phone/call_queues/{callQueueId}/recordings` endpoint.
+     *
+     * As opposed to `get_call_queue_recordings`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to view [call recordings](https://support.zoom.us/hc/en-us/articles/360038521091#h_cbc9f2a3-e06c-4daa-83d4-ddbceef9c77b) from the call queue.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro or higher account with Zoom Phone license.
+     * * [Automatic call recordings](https://support.zoom.us/hc/en-us/articles/360033511872#h_fcb297bb-14e8-4094-91ca-dc61e1a18734) must be enabled in the Policy Settings for call queues. <br> **Scope:** `phone:read:admin`<br> **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     *
+     *
+     *
+     */
+    pub fn get_call_queue_recordings_stream(
+        &self,
+        call_queue_id: &str,
+        params: &PhoneCallQueuesGetCallQueueRecordingsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::GetCallQueueRecordingsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(from) = &params.from {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(to) = &params.to {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/phone/call_queues/{}/recordings?{}",
+            crate::progenitor_support::encode_path(call_queue_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::GetCallQueueRecordingsResponseData =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.recordings,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+}
+
+/// The query parameters of `PhoneCallQueues::list_call_queues_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneCallQueuesListCallQueuesParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned from a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl PhoneCallQueuesListCallQueuesParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `PhoneCallQueues::get_call_queue_recordings_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneCallQueuesGetCallQueueRecordingsParams {
+    /// Start date (within a 6 month range).
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// End date (within a 6 month range).
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl PhoneCallQueuesGetCallQueueRecordingsParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct PhoneDevices {
     pub client: Client,
@@ -27,24 +25,20 @@ impl PhoneDevices {
      *
      * **Parameters:**
      *
-     * * `type_: crate::types::ListPhoneDevicesType` -- State of the device. The value should be either `assigned` to list devices that have been assigned to user(s) or `unassigned` to list devices that have not yet been assigned to any user in the Zoom account.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `page_size: i64` -- The number of records returned within a single API call.
+     * * `params: &PhoneDevicesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        type_: crate::types::ListPhoneDevicesType,
-        next_page_token: &str,
-        page_size: i64,
+        params: &PhoneDevicesListParams,
     ) -> Result<Vec<crate::types::ListPhoneDevicesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -56,6 +50,24 @@ impl PhoneDevices {
         Ok(resp.devices.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `PhoneDevicesListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::ListPhoneDevicesResponse>> {
+        let params = PhoneDevicesListParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * List devices.
      *
@@ -70,13 +82,17 @@ impl PhoneDevices {
      * **Prerequisites:**<br>
      * * Pro or a higher account with Zoom Phone license
      * * Account owner or admin permissions
+     *
+     * **Parameters:**
+     *
+     * * `params: &PhoneDevicesListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(
+    pub async fn list_all_with(
         &self,
-        type_: crate::types::ListPhoneDevicesType,
+        params: &PhoneDevicesListParams,
     ) -> Result<Vec<crate::types::ListPhoneDevicesResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -116,6 +132,70 @@ impl PhoneDevices {
         Ok(devices)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `PhoneDevicesListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(
+        &self,
+        type_: crate::types::ListPhoneDevicesType,
+    ) -> Result<Vec<crate::types::ListPhoneDevicesResponse>> {
+        let params = PhoneDevicesListParams {
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * List devices.
+     *
+     * This function performs a `GET` to the `/phone/devices` endpoint.
+     *
+     * As opposed to `list`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List all the [desk phone devices](https://support.zoom.us/hc/en-us/articles/360021119092) that are configured with Zoom Phone on an account.
+     *
+     * **Scopes:** `phone:read:admin`</br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Prerequisites:**<br>
+     * * Pro or a higher account with Zoom Phone license
+     * * Account owner or admin permissions
+     */
+    pub fn list_stream(
+        &self,
+        params: &PhoneDevicesListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ListPhoneDevicesResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/phone/devices?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListPhoneDevicesResponseData =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.devices,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Add a device.
      *
@@ -226,3 +306,31 @@ impl PhoneDevices {
             .await
     }
 }
+
+/// The query parameters of `PhoneDevices::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneDevicesListParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// State of the device. The value should be either `assigned` to list devices that have been assigned to user(s) or `unassigned` to list devices that have not yet been assigned to any user in the Zoom account.
+    pub type_: Option<crate::types::ListPhoneDevicesType>,
+}
+
+impl PhoneDevicesListParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::ListPhoneDevicesType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
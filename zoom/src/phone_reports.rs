@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct PhoneReports {
     pub client: Client,
@@ -28,34 +26,26 @@ impl PhoneReports {
      *
      * **Parameters:**
      *
-     * * `from: &str` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report provides only one month worth of data per API request.
-     * * `to: &str` -- End date in 'yyyy-mm-dd' format.
-     * * `category_type: &str` -- Filter the response by the category of the action performed. By default, the value of this field is "all" and thus, the response will include log of all operations for the defined period.<br><br>To only include response for a specific category type, provide a value for `category_type` from this [table](http://marketplace.zoom.us/docs/phone-operation-categories ).
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &PhoneReportsGetPsOperationLogParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_ps_operation_log(
+    pub async fn get_ps_operation_log_with(
         &self,
-        from: &str,
-        to: &str,
-        category_type: &str,
-        page_size: i64,
-        next_page_token: &str,
+        params: &PhoneReportsGetPsOperationLogParams,
     ) -> Result<crate::types::ReportOperationLogsResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !category_type.is_empty() {
+        if let Some(category_type) = &params.category_type {
             query_args.push(("category_type".to_string(), category_type.to_string()));
         }
-        if !from.is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -63,4 +53,68 @@ impl PhoneReports {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_ps_operation_log_with` and pass the query parameters as a `PhoneReportsGetPsOperationLogParams` instead.
+     */
+    #[deprecated(note = "use `get_ps_operation_log_with` instead")]
+    pub async fn get_ps_operation_log(
+        &self,
+        from: &str,
+        to: &str,
+        category_type: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::ReportOperationLogsResponseAllOf> {
+        let params = PhoneReportsGetPsOperationLogParams {
+            category_type: Some(category_type.to_string()).filter(|s| !s.is_empty()),
+            from: Some(from.to_string()).filter(|s| !s.is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_ps_operation_log_with(&params).await
+    }
+}
+
+/// The query parameters of `PhoneReports::get_ps_operation_log_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneReportsGetPsOperationLogParams {
+    /// Filter the response by the category of the action performed. By default, the value of this field is "all" and thus, the response will include log of all operations for the defined period.<br><br>To only include response for a specific category type, provide a value for `category_type` from this [table](http://marketplace.zoom.us/docs/phone-operation-categories ).
+    pub category_type: Option<String>,
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report provides only one month worth of data per API request.
+    pub from: Option<String>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// End date in 'yyyy-mm-dd' format.
+    pub to: Option<String>,
+}
+
+impl PhoneReportsGetPsOperationLogParams {
+    pub fn category_type<T: ToString>(mut self, category_type: T) -> Self {
+        self.category_type = Some(category_type.to_string());
+        self
+    }
+
+    pub fn from<T: ToString>(mut self, from: T) -> Self {
+        self.from = Some(from.to_string());
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to<T: ToString>(mut self, to: T) -> Self {
+        self.to = Some(to.to_string());
+        self
+    }
 }
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct PhoneSharedLineGroups {
     pub client: Client,
@@ -30,19 +28,17 @@ impl PhoneSharedLineGroups {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &PhoneSharedLineGroupsListSharedLineGroupsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_shared_line_groups(
+    pub async fn list_shared_line_groups_with(
         &self,
-        page_size: i64,
-        next_page_token: &str,
+        params: &PhoneSharedLineGroupsListSharedLineGroupsParams,
     ) -> Result<Vec<crate::types::SharedLineGroups>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -54,6 +50,22 @@ impl PhoneSharedLineGroups {
         Ok(resp.shared_line_groups.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_shared_line_groups_with` and pass the query parameters as a `PhoneSharedLineGroupsListSharedLineGroupsParams` instead.
+     */
+    #[deprecated(note = "use `list_shared_line_groups_with` instead")]
+    pub async fn list_shared_line_groups(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::SharedLineGroups>> {
+        let params = PhoneSharedLineGroupsListSharedLineGroupsParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.list_shared_line_groups_with(&params).await
+    }
+
     /**
      * List shared line groups.
      *
@@ -108,6 +120,51 @@ impl PhoneSharedLineGroups {
         Ok(shared_line_groups)
     }
 
+    /**
+     * List shared line groups.
+     *
+     * This function performs a `GET` to the `/phone/shared_line_groups` endpoint.
+     *
+     * As opposed to `list_shared_line_groups`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * A [shared line group](https://support.zoom.us/hc/en-us/articles/360038850792) allows Zoom Phone admins to share a phone number and extension with a group of phone users or common area phones. This gives members of the shared line group access to the group's direct phone number and voicemail. Use this API to list all the Shared Line Groups.
+     *
+     * **Prerequisties:** <br>
+     * * Pro or higher account with Zoom Phone license.
+     * * Account owner or admin privileges  <br>
+     *
+     * **Scopes:** `phone:read:admin`, `phone:write:admin`
+     *
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_shared_line_groups_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::SharedLineGroups> {
+        let url = "/phone/shared_line_groups".to_string();
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListSharedLineGroupsResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.shared_line_groups,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a shared line group.
      *
@@ -419,3 +476,24 @@ impl PhoneSharedLineGroups {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `PhoneSharedLineGroups::list_shared_line_groups_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneSharedLineGroupsListSharedLineGroupsParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl PhoneSharedLineGroupsListSharedLineGroupsParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
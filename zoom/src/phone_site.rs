@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct PhoneSite {
     pub client: Client,
@@ -27,19 +25,17 @@ impl PhoneSite {
      *
      * **Parameters:**
      *
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &PhoneSiteListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        page_size: i64,
-        next_page_token: &str,
+        params: &PhoneSiteListParams,
     ) -> Result<Vec<crate::types::Sites>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -51,6 +47,22 @@ impl PhoneSite {
         Ok(resp.sites.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `PhoneSiteListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Sites>> {
+        let params = PhoneSiteListParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * List phone sites.
      *
@@ -101,6 +113,47 @@ impl PhoneSite {
         Ok(sites)
     }
 
+    /**
+     * List phone sites.
+     *
+     * This function performs a `GET` to the `/phone/sites` endpoint.
+     *
+     * As opposed to `list`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Sites allow you to organize Zoom Phone users in your organization. Use this API to list all the [sites](https://support.zoom.us/hc/en-us/articles/360020809672) that have been created for an account.<br>
+     * **Prerequisites:**<br>
+     * * Multiple Sites must be [enabled](https://support.zoom.us/hc/en-us/articles/360020809672-Managing-Multiple-Sites#h_05c88e35-1593-491f-b1a8-b7139a75dc15).
+     * * Pro or a higher account with Zoom Phone enabled.
+     *
+     * **Scope:** `phone:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_stream(
+        &self,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Sites> {
+        let url = "/phone/sites".to_string();
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListPhoneSitesResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.sites,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Create a phone site.
      *
@@ -174,11 +227,11 @@ impl PhoneSite {
      * **Parameters:**
      *
      * * `site_id: &str` -- Unique Identifier of the Site.
-     * * `transfer_site_id: &str` -- The Site ID of another site where the assets of the current site (users, numbers and phones) can be transferred to.
+     * * `params: &PhoneSiteDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete(&self, site_id: &str, transfer_site_id: &str) -> Result<()> {
+    pub async fn delete_with(&self, site_id: &str, params: &PhoneSiteDeleteParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !transfer_site_id.is_empty() {
+        if let Some(transfer_site_id) = &params.transfer_site_id {
             query_args.push(("transfer_site_id".to_string(), transfer_site_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -191,6 +244,17 @@ impl PhoneSite {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_with` and pass the query parameters as a `PhoneSiteDeleteParams` instead.
+     */
+    #[deprecated(note = "use `delete_with` instead")]
+    pub async fn delete(&self, site_id: &str, transfer_site_id: &str) -> Result<()> {
+        let params = PhoneSiteDeleteParams {
+            transfer_site_id: Some(transfer_site_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_with(site_id, &params).await
+    }
+
     /**
      * Update phone site details.
      *
@@ -225,3 +289,38 @@ impl PhoneSite {
             .await
     }
 }
+
+/// The query parameters of `PhoneSite::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneSiteListParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl PhoneSiteListParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `PhoneSite::delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct PhoneSiteDeleteParams {
+    /// The Site ID of another site where the assets of the current site (users, numbers and phones) can be transferred to.
+    pub transfer_site_id: Option<String>,
+}
+
+impl PhoneSiteDeleteParams {
+    pub fn transfer_site_id<T: ToString>(mut self, transfer_site_id: T) -> Self {
+        self.transfer_site_id = Some(transfer_site_id.to_string());
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Reports {
     pub client: Client,
@@ -26,15 +24,17 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `year: i64` -- Year for this report.
-     * * `month: i64` -- Month for this report.
+     * * `params: &ReportsDailyParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn daily(&self, year: i64, month: i64) -> Result<crate::types::ReportDailyResponse> {
+    pub async fn daily_with(
+        &self,
+        params: &ReportsDailyParams,
+    ) -> Result<crate::types::ReportDailyResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if month > 0 {
+        if let Some(month) = &params.month {
             query_args.push(("month".to_string(), month.to_string()));
         }
-        if year > 0 {
+        if let Some(year) = &params.year {
             query_args.push(("year".to_string(), year.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -43,6 +43,18 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `daily_with` and pass the query parameters as a `ReportsDailyParams` instead.
+     */
+    #[deprecated(note = "use `daily_with` instead")]
+    pub async fn daily(&self, year: i64, month: i64) -> Result<crate::types::ReportDailyResponse> {
+        let params = ReportsDailyParams {
+            month: Some(month).filter(|v| *v > 0),
+            year: Some(year).filter(|v| *v > 0),
+        };
+        self.daily_with(&params).await
+    }
+
     /**
      * Get active/inactive host reports.
      *
@@ -58,39 +70,26 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `type_: crate::types::ReportUsersType` -- Active or inactive hosts.<br>`active` - Active hosts. <br>`inactive` - Inactive hosts.
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `page_number: i64` -- The page number of the current page in the returned records.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &ReportsUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn user(
-        &self,
-        type_: crate::types::ReportUsersType,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        page_number: i64,
-        next_page_token: &str,
-    ) -> Result<crate::types::Domains> {
+    pub async fn user_with(&self, params: &ReportsUserParams) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_number > 0 {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -99,6 +98,30 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `user_with` and pass the query parameters as a `ReportsUserParams` instead.
+     */
+    #[deprecated(note = "use `user_with` instead")]
+    pub async fn user(
+        &self,
+        type_: crate::types::ReportUsersType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = ReportsUserParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_number: Some(page_number).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.user_with(&params).await
+    }
+
     /**
      * Get meeting reports.
      *
@@ -116,35 +139,27 @@ impl Reports {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `type_: crate::types::ReportMeetingsType` -- The meeting types: <br>`past` - Past meetings.<br>`pastOne` - Past one user meetings.
+     * * `params: &ReportsMeetingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn meeting(
+    pub async fn meeting_with(
         &self,
         user_id: &str,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        next_page_token: &str,
-        type_: crate::types::ReportMeetingsType,
+        params: &ReportsMeetingParams,
     ) -> Result<crate::types::ReportMeetingsResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -157,6 +172,29 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `meeting_with` and pass the query parameters as a `ReportsMeetingParams` instead.
+     */
+    #[deprecated(note = "use `meeting_with` instead")]
+    pub async fn meeting(
+        &self,
+        user_id: &str,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        type_: crate::types::ReportMeetingsType,
+    ) -> Result<crate::types::ReportMeetingsResponseAllOf> {
+        let params = ReportsMeetingParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.meeting_with(user_id, &params).await
+    }
+
     /**
      * Get meeting detail reports.
      *
@@ -207,25 +245,21 @@ impl Reports {
      * * `meeting_id: &str` -- The meeting ID or the meeting UUID.  If a meeting ID is provided in the request instead of a UUID, the response will be for the latest meeting instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `include_fields: crate::types::DashboardMeetingParticipantsIncludeFields` -- Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
+     * * `params: &ReportsMeetingParticipantParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn meeting_participant(
+    pub async fn meeting_participant_with(
         &self,
         meeting_id: &str,
-        page_size: i64,
-        next_page_token: &str,
-        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+        params: &ReportsMeetingParticipantParams,
     ) -> Result<crate::types::ReportMeetingParticipantsResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_fields.to_string().is_empty() {
+        if let Some(include_fields) = &params.include_fields {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -238,6 +272,25 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `meeting_participant_with` and pass the query parameters as a `ReportsMeetingParticipantParams` instead.
+     */
+    #[deprecated(note = "use `meeting_participant_with` instead")]
+    pub async fn meeting_participant(
+        &self,
+        meeting_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Result<crate::types::ReportMeetingParticipantsResponseAllOf> {
+        let params = ReportsMeetingParticipantParams {
+            include_fields: Some(include_fields).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.meeting_participant_with(meeting_id, &params).await
+    }
+
     /**
      * Get meeting poll reports.
      *
@@ -316,25 +369,21 @@ impl Reports {
      * * `webinar_id: &str` -- The webinar ID or the webinar UUID.  If a webinar ID is provided in the request instead of a UUID, the response will be for the latest webinar instance.
      *   
      *   If a UUID starts with "/" or contains "//" (example: "/ajXp112QmuoKj4854875==\"), you must **double encode** the UUID before making an API request.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `include_fields: crate::types::DashboardMeetingParticipantsIncludeFields` -- Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
+     * * `params: &ReportsWebinarParticipantParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn webinar_participant(
+    pub async fn webinar_participant_with(
         &self,
         webinar_id: &str,
-        page_size: i64,
-        next_page_token: &str,
-        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+        params: &ReportsWebinarParticipantParams,
     ) -> Result<crate::types::ReportWebinarParticipantsResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_fields.to_string().is_empty() {
+        if let Some(include_fields) = &params.include_fields {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -347,6 +396,25 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `webinar_participant_with` and pass the query parameters as a `ReportsWebinarParticipantParams` instead.
+     */
+    #[deprecated(note = "use `webinar_participant_with` instead")]
+    pub async fn webinar_participant(
+        &self,
+        webinar_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Result<crate::types::ReportWebinarParticipantsResponseAllOf> {
+        let params = ReportsWebinarParticipantParams {
+            include_fields: Some(include_fields).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.webinar_participant_with(webinar_id, &params).await
+    }
+
     /**
      * Get webinar poll reports.
      *
@@ -423,48 +491,32 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `type_: &str` -- Audio types:<br>`1` - Toll-free Call-in & Call-out.<br>`2` - Toll <br>
-     *  `3` - SIP Connected Audio.
-     * * `query_date_type: crate::types::QueryDateType` -- Date types:<br>`start_time` - Query by call start time.<br>`end_time` - Query by call end time.
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `page_number: i64` --
-     *   **Deprecated** - This field has been deprecated and we will stop supporting it completely in a future release. Please use "next_page_token" for pagination instead of this field.
-     *   
-     *   The page number of the current page in the returned records.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &ReportsTelephoneParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn telephone(
+    pub async fn telephone_with(
         &self,
-        type_: &str,
-        query_date_type: crate::types::QueryDateType,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        page_number: i64,
-        next_page_token: &str,
+        params: &ReportsTelephoneParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_number > 0 {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !query_date_type.to_string().is_empty() {
+        if let Some(query_date_type) = &params.query_date_type {
             query_args.push(("query_date_type".to_string(), query_date_type.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -473,6 +525,32 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `telephone_with` and pass the query parameters as a `ReportsTelephoneParams` instead.
+     */
+    #[deprecated(note = "use `telephone_with` instead")]
+    pub async fn telephone(
+        &self,
+        type_: &str,
+        query_date_type: crate::types::QueryDateType,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = ReportsTelephoneParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_number: Some(page_number).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            query_date_type: Some(query_date_type).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.telephone_with(&params).await
+    }
+
     /**
      * Get cloud recording usage report.
      *
@@ -487,19 +565,17 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
+     * * `params: &ReportsCloudRecordingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn cloud_recording(
+    pub async fn cloud_recording_with(
         &self,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
+        params: &ReportsCloudRecordingParams,
     ) -> Result<crate::types::ReportCloudRecordingResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -508,6 +584,22 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `cloud_recording_with` and pass the query parameters as a `ReportsCloudRecordingParams` instead.
+     */
+    #[deprecated(note = "use `cloud_recording_with` instead")]
+    pub async fn cloud_recording(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<crate::types::ReportCloudRecordingResponseAllOf> {
+        let params = ReportsCloudRecordingParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.cloud_recording_with(&params).await
+    }
+
     /**
      * Get operation logs report.
      *
@@ -523,36 +615,26 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `from: chrono::NaiveDate` -- Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
-     * * `to: chrono::NaiveDate` -- Start Date.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `category_type: crate::types::CategoryType` -- \*\*Optional\*\*<br>
-     *  Filter your response by a category type to see reports for a specific category.
-     *  The value for this field can be one of the following:<br> `all`<br>`user`<br>`user_settings`<br>`account`<br>`billing`<br>`im`<br>`recording`<br>`phone_contacts`<br>`webinar`<br>`sub_account`<br>`role`<br>`zoom_rooms`.
+     * * `params: &ReportsOperationLogParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn operation_log(
+    pub async fn operation_log_with(
         &self,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        next_page_token: &str,
-        category_type: crate::types::CategoryType,
+        params: &ReportsOperationLogParams,
     ) -> Result<crate::types::ReportOperationLogsResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !category_type.to_string().is_empty() {
+        if let Some(category_type) = &params.category_type {
             query_args.push(("category_type".to_string(), category_type.to_string()));
         }
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -561,6 +643,28 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `operation_log_with` and pass the query parameters as a `ReportsOperationLogParams` instead.
+     */
+    #[deprecated(note = "use `operation_log_with` instead")]
+    pub async fn operation_log(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+        category_type: crate::types::CategoryType,
+    ) -> Result<crate::types::ReportOperationLogsResponseAllOf> {
+        let params = ReportsOperationLogParams {
+            category_type: Some(category_type).filter(|v| !v.to_string().is_empty()),
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.operation_log_with(&params).await
+    }
+
     /**
      * Get sign In / sign out activity report.
      *
@@ -575,29 +679,23 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `from: chrono::NaiveDate` -- Start date for which you would like to view the activity logs report. Using the `from` and `to` parameters, specify a monthly date range for the report as the API only provides one month worth of data in one request. The specified date range should fall within the last six months.
-     * * `to: chrono::NaiveDate` -- End date up to which you would like to view the activity logs report.
-     * * `page_size: i64` -- The number of records to be returned within a single API call.
-     * * `next_page_token: &str` -- Next page token is used to paginate through large result sets.
+     * * `params: &ReportsSignOutActivitiesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn sign_out_activities(
+    pub async fn sign_out_activities_with(
         &self,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
-        page_size: i64,
-        next_page_token: &str,
+        params: &ReportsSignOutActivitiesParams,
     ) -> Result<Vec<crate::types::ActivityLogs>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -610,6 +708,26 @@ impl Reports {
         Ok(resp.activity_logs.to_vec())
     }
 
+    /**
+     * Deprecated: use `sign_out_activities_with` and pass the query parameters as a `ReportsSignOutActivitiesParams` instead.
+     */
+    #[deprecated(note = "use `sign_out_activities_with` instead")]
+    pub async fn sign_out_activities(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::ActivityLogs>> {
+        let params = ReportsSignOutActivitiesParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+        };
+        self.sign_out_activities_with(&params).await
+    }
+
     /**
      * Get sign In / sign out activity report.
      *
@@ -623,17 +741,20 @@ impl Reports {
      * **Scopes:** `report:read:admin`<br>
      *  
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     *
+     * **Parameters:**
+     *
+     * * `params: &ReportsSignOutActivitiesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_sign_out_activities(
+    pub async fn get_all_sign_out_activities_with(
         &self,
-        from: chrono::NaiveDate,
-        to: chrono::NaiveDate,
+        params: &ReportsSignOutActivitiesParams,
     ) -> Result<Vec<crate::types::ActivityLogs>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !from.to_string().is_empty() {
+        if let Some(from) = &params.from {
             query_args.push(("from".to_string(), from.to_string()));
         }
-        if !to.to_string().is_empty() {
+        if let Some(to) = &params.to {
             query_args.push(("to".to_string(), to.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -673,6 +794,74 @@ impl Reports {
         Ok(activity_logs)
     }
 
+    /**
+     * Deprecated: use `get_all_sign_out_activities_with` and pass the query parameters as a `ReportsSignOutActivitiesParams` instead.
+     */
+    #[deprecated(note = "use `get_all_sign_out_activities_with` instead")]
+    pub async fn get_all_sign_out_activities(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::types::ActivityLogs>> {
+        let params = ReportsSignOutActivitiesParams {
+            from: Some(from).filter(|v| !v.to_string().is_empty()),
+            to: Some(to).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_sign_out_activities_with(&params).await
+    }
+
+    /**
+     * Get sign In / sign out activity report.
+     *
+     * This function performs a `GET` to the `/report/activities` endpoint.
+     *
+     * As opposed to `sign_out_activities`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Retrieve a list of sign in / sign out activity logs [report](https://support.zoom.us/hc/en-us/articles/201363213-Getting-Started-with-Reports) of users under a Zoom account.<br>
+     * **Prerequisites**<br>
+     * * Pro or higher plan.<br>
+     * **Scopes:** `report:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Heavy`
+     */
+    pub fn sign_out_activities_stream(
+        &self,
+        params: &ReportsSignOutActivitiesParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ActivityLogs> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(from) = &params.from {
+            query_args.push(("from".to_string(), from.to_string()));
+        }
+        if let Some(to) = &params.to {
+            query_args.push(("to".to_string(), to.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/report/activities?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ReportSignInOutActivitiesResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.activity_logs,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Get billing reports.
      *
@@ -709,16 +898,14 @@ impl Reports {
      *
      * **Parameters:**
      *
-     * * `billing_id: &str` -- Unique Identifier of the Billing Report. Retrieve this ID from the response of **Get Billing Reports** API request.
-     *   
-     *   .
+     * * `params: &ReportsGetBillingInvoicesParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_billing_invoices(
+    pub async fn get_billing_invoices_with(
         &self,
-        billing_id: &str,
+        params: &ReportsGetBillingInvoicesParams,
     ) -> Result<crate::types::GetBillingInvoicesReportsResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !billing_id.is_empty() {
+        if let Some(billing_id) = &params.billing_id {
             query_args.push(("billing_id".to_string(), billing_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -726,4 +913,364 @@ impl Reports {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Deprecated: use `get_billing_invoices_with` and pass the query parameters as a `ReportsGetBillingInvoicesParams` instead.
+     */
+    #[deprecated(note = "use `get_billing_invoices_with` instead")]
+    pub async fn get_billing_invoices(
+        &self,
+        billing_id: &str,
+    ) -> Result<crate::types::GetBillingInvoicesReportsResponse> {
+        let params = ReportsGetBillingInvoicesParams {
+            billing_id: Some(billing_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_billing_invoices_with(&params).await
+    }
+}
+
+/// The query parameters of `Reports::daily_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsDailyParams {
+    /// Month for this report.
+    pub month: Option<i64>,
+    /// Year for this report.
+    pub year: Option<i64>,
+}
+
+impl ReportsDailyParams {
+    pub fn month(mut self, month: i64) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    pub fn year(mut self, year: i64) -> Self {
+        self.year = Some(year);
+        self
+    }
+}
+
+/// The query parameters of `Reports::user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsUserParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The page number of the current page in the returned records.
+    pub page_number: Option<i64>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+    /// Active or inactive hosts.<br>`active` - Active hosts. <br>`inactive` - Inactive hosts.
+    pub type_: Option<crate::types::ReportUsersType>,
+}
+
+impl ReportsUserParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::ReportUsersType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Reports::meeting_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsMeetingParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+    /// The meeting types: <br>`past` - Past meetings.<br>`pastOne` - Past one user meetings.
+    pub type_: Option<crate::types::ReportMeetingsType>,
+}
+
+impl ReportsMeetingParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::ReportMeetingsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Reports::meeting_participant_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsMeetingParticipantParams {
+    /// Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
+    pub include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl ReportsMeetingParticipantParams {
+    pub fn include_fields(
+        mut self,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `Reports::webinar_participant_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsWebinarParticipantParams {
+    /// Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
+    pub include_fields: Option<crate::types::DashboardMeetingParticipantsIncludeFields>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl ReportsWebinarParticipantParams {
+    pub fn include_fields(
+        mut self,
+        include_fields: crate::types::DashboardMeetingParticipantsIncludeFields,
+    ) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `Reports::telephone_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsTelephoneParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    pub page_number: Option<i64>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Date types:<br>`start_time` - Query by call start time.<br>`end_time` - Query by call end time.
+    pub query_date_type: Option<crate::types::QueryDateType>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+    /// Audio types:<br>`1` - Toll-free Call-in & Call-out.<br>`2` - Toll <br>
+    /// `3` - SIP Connected Audio.
+    pub type_: Option<String>,
+}
+
+impl ReportsTelephoneParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn query_date_type(mut self, query_date_type: crate::types::QueryDateType) -> Self {
+        self.query_date_type = Some(query_date_type);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Reports::cloud_recording_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsCloudRecordingParams {
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl ReportsCloudRecordingParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Reports::operation_log_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsOperationLogParams {
+    /// \*\*Optional\*\*<br>
+    /// Filter your response by a category type to see reports for a specific category.
+    /// The value for this field can be one of the following:<br> `all`<br>`user`<br>`user_settings`<br>`account`<br>`billing`<br>`im`<br>`recording`<br>`phone_contacts`<br>`webinar`<br>`sub_account`<br>`role`<br>`zoom_rooms`.
+    pub category_type: Option<crate::types::CategoryType>,
+    /// Start date in 'yyyy-mm-dd' format. The date range defined by the "from" and "to" parameters should only be one month as the report includes only one month worth of data at once.
+    pub from: Option<chrono::NaiveDate>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Start Date.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl ReportsOperationLogParams {
+    pub fn category_type(mut self, category_type: crate::types::CategoryType) -> Self {
+        self.category_type = Some(category_type);
+        self
+    }
+
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Reports::sign_out_activities_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsSignOutActivitiesParams {
+    /// Start date for which you would like to view the activity logs report. Using the `from` and `to` parameters, specify a monthly date range for the report as the API only provides one month worth of data in one request. The specified date range should fall within the last six months.
+    pub from: Option<chrono::NaiveDate>,
+    /// Next page token is used to paginate through large result sets.
+    pub next_page_token: Option<String>,
+    /// The number of records to be returned within a single API call.
+    pub page_size: Option<i64>,
+    /// End date up to which you would like to view the activity logs report.
+    pub to: Option<chrono::NaiveDate>,
+}
+
+impl ReportsSignOutActivitiesParams {
+    pub fn from(mut self, from: chrono::NaiveDate) -> Self {
+        self.from = Some(from);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn to(mut self, to: chrono::NaiveDate) -> Self {
+        self.to = Some(to);
+        self
+    }
+}
+
+/// The query parameters of `Reports::get_billing_invoices_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct ReportsGetBillingInvoicesParams {
+    /// Unique Identifier of the Billing Report. Retrieve this ID from the response of **Get Billing Reports** API request.
+    ///
+    /// .
+    pub billing_id: Option<String>,
+}
+
+impl ReportsGetBillingInvoicesParams {
+    pub fn billing_id<T: ToString>(mut self, billing_id: T) -> Self {
+        self.billing_id = Some(billing_id.to_string());
+        self
+    }
 }
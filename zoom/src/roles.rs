@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Roles {
     pub client: Client,
@@ -69,33 +67,24 @@ impl Roles {
      * **Parameters:**
      *
      * * `role_id: &str` -- User's first name.
-     * * `page_count: &str` -- The number of pages returned for this request.
-     * * `page_number: i64` --
-     *   **Deprecated** - This field has been deprecated and we will stop supporting it completely in a future release. Please use "next_page_token" for pagination instead of this field.
-     *   
-     *   The page number of the current page in the returned records.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `page_size: i64` -- The number of records returned within a single API call.
+     * * `params: &RolesMembersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn members(
+    pub async fn members_with(
         &self,
         role_id: &str,
-        page_count: &str,
-        page_number: i64,
-        next_page_token: &str,
-        page_size: i64,
+        params: &RolesMembersParams,
     ) -> Result<Vec<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if !page_count.is_empty() {
+        if let Some(page_count) = &params.page_count {
             query_args.push(("page_count".to_string(), page_count.to_string()));
         }
-        if page_number > 0 {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -111,6 +100,27 @@ impl Roles {
         Ok(resp.members.to_vec())
     }
 
+    /**
+     * Deprecated: use `members_with` and pass the query parameters as a `RolesMembersParams` instead.
+     */
+    #[deprecated(note = "use `members_with` instead")]
+    pub async fn members(
+        &self,
+        role_id: &str,
+        page_count: &str,
+        page_number: i64,
+        next_page_token: &str,
+        page_size: i64,
+    ) -> Result<Vec<crate::types::Domains>> {
+        let params = RolesMembersParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_count: Some(page_count.to_string()).filter(|s| !s.is_empty()),
+            page_number: Some(page_number).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.members_with(role_id, &params).await
+    }
+
     /**
      * List members in a role.
      *
@@ -124,14 +134,18 @@ impl Roles {
      *  
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>**Prerequisites:**<br>
      * * A Pro or a higher plan.
+     *
+     * **Parameters:**
+     *
+     * * `params: &RolesMembersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all_members(
+    pub async fn get_all_members_with(
         &self,
         role_id: &str,
-        page_count: &str,
+        params: &RolesMembersParams,
     ) -> Result<Vec<crate::types::Domains>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !page_count.is_empty() {
+        if let Some(page_count) = &params.page_count {
             query_args.push(("page_count".to_string(), page_count.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -174,6 +188,74 @@ impl Roles {
         Ok(members)
     }
 
+    /**
+     * Deprecated: use `get_all_members_with` and pass the query parameters as a `RolesMembersParams` instead.
+     */
+    #[deprecated(note = "use `get_all_members_with` instead")]
+    pub async fn get_all_members(
+        &self,
+        role_id: &str,
+        page_count: &str,
+    ) -> Result<Vec<crate::types::Domains>> {
+        let params = RolesMembersParams {
+            page_count: Some(page_count.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.get_all_members_with(role_id, &params).await
+    }
+
+    /**
+     * List members in a role.
+     *
+     * This function performs a `GET` to the `/roles/{roleId}/members` endpoint.
+     *
+     * As opposed to `members`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * User [roles](https://support.zoom.us/hc/en-us/articles/115001078646-Role-Based-Access-Control) can have a set of permissions that allows access only to the pages a user needs to view or edit. Use this API to list all the members that are assigned a specific role.
+     *
+     * **Scope:** `role:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>**Prerequisites:**<br>
+     * * A Pro or a higher plan.
+     */
+    pub fn members_stream(
+        &self,
+        role_id: &str,
+        params: &RolesMembersParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Domains> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(page_count) = &params.page_count {
+            query_args.push(("page_count".to_string(), page_count.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/roles/{}/members?{}",
+            crate::progenitor_support::encode_path(role_id),
+            query_
+        );
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::RoleMembersList = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.members,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Assign a role.
      *
@@ -319,3 +401,37 @@ impl Roles {
             .await
     }
 }
+
+/// The query parameters of `Roles::members_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RolesMembersParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of pages returned for this request.
+    pub page_count: Option<String>,
+    pub page_number: Option<i64>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl RolesMembersParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_count<T: ToString>(mut self, page_count: T) -> Self {
+        self.page_count = Some(page_count.to_string());
+        self
+    }
+
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
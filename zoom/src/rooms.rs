@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Rooms {
     pub client: Client,
@@ -25,39 +23,29 @@ impl Rooms {
      *
      * **Parameters:**
      *
-     * * `status: crate::types::ListZoomRoomsStatus` -- The status of the Zoom Room.
-     * * `type_: crate::types::ListZoomRoomsType` -- Type of the Zoom Rooms.
-     * * `unassigned_rooms: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `location_id: &str` -- Parent location ID of the Zoom Room.
+     * * `params: &RoomsListZoomParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_zoom(
+    pub async fn list_zoom_with(
         &self,
-        status: crate::types::ListZoomRoomsStatus,
-        type_: crate::types::ListZoomRoomsType,
-        unassigned_rooms: bool,
-        page_size: i64,
-        next_page_token: &str,
-        location_id: &str,
+        params: &RoomsListZoomParams,
     ) -> Result<Vec<crate::types::ListZoomRoomsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !location_id.is_empty() {
+        if let Some(location_id) = &params.location_id {
             query_args.push(("location_id".to_string(), location_id.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
-        if unassigned_rooms {
+        if let Some(unassigned_rooms) = &params.unassigned_rooms {
             query_args.push(("unassigned_rooms".to_string(), unassigned_rooms.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -69,6 +57,30 @@ impl Rooms {
         Ok(resp.rooms.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_zoom_with` and pass the query parameters as a `RoomsListZoomParams` instead.
+     */
+    #[deprecated(note = "use `list_zoom_with` instead")]
+    pub async fn list_zoom(
+        &self,
+        status: crate::types::ListZoomRoomsStatus,
+        type_: crate::types::ListZoomRoomsType,
+        unassigned_rooms: bool,
+        page_size: i64,
+        next_page_token: &str,
+        location_id: &str,
+    ) -> Result<Vec<crate::types::ListZoomRoomsResponse>> {
+        let params = RoomsListZoomParams {
+            location_id: Some(location_id.to_string()).filter(|s| !s.is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            unassigned_rooms: Some(unassigned_rooms).filter(|v| *v),
+        };
+        self.list_zoom_with(&params).await
+    }
+
     /**
      * List Zoom Rooms.
      *
@@ -81,25 +93,26 @@ impl Rooms {
      * * Pro or a higher plan with [Zoom Room](https://zoom.us/zoomrooms) license.<br>
      * **Scopes**: `room:read:admin`<br>
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Parameters:**
+     *
+     * * `params: &RoomsListZoomParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_zoom(
+    pub async fn list_all_zoom_with(
         &self,
-        status: crate::types::ListZoomRoomsStatus,
-        type_: crate::types::ListZoomRoomsType,
-        unassigned_rooms: bool,
-        location_id: &str,
+        params: &RoomsListZoomParams,
     ) -> Result<Vec<crate::types::ListZoomRoomsResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !location_id.is_empty() {
+        if let Some(location_id) = &params.location_id {
             query_args.push(("location_id".to_string(), location_id.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
-        if unassigned_rooms {
+        if let Some(unassigned_rooms) = &params.unassigned_rooms {
             query_args.push(("unassigned_rooms".to_string(), unassigned_rooms.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -138,6 +151,82 @@ impl Rooms {
         Ok(rooms)
     }
 
+    /**
+     * Deprecated: use `list_all_zoom_with` and pass the query parameters as a `RoomsListZoomParams` instead.
+     */
+    #[deprecated(note = "use `list_all_zoom_with` instead")]
+    pub async fn list_all_zoom(
+        &self,
+        status: crate::types::ListZoomRoomsStatus,
+        type_: crate::types::ListZoomRoomsType,
+        unassigned_rooms: bool,
+        location_id: &str,
+    ) -> Result<Vec<crate::types::ListZoomRoomsResponse>> {
+        let params = RoomsListZoomParams {
+            location_id: Some(location_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+            unassigned_rooms: Some(unassigned_rooms).filter(|v| *v),
+            ..Default::default()
+        };
+        self.list_all_zoom_with(&params).await
+    }
+
+    /**
+     * List Zoom Rooms.
+     *
+     * This function performs a `GET` to the `/rooms` endpoint.
+     *
+     * As opposed to `list_zoom`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Zoom Rooms is a software-based room system that provides an integrated experience for audio conferencing, wireless screen sharing and video conferencing. Use this API to list all the existing [Zoom Rooms](https://support.zoom.us/hc/en-us/articles/207483343-Getting-Started-with-Zoom-Rooms) in a Zoom account.<br><br>
+     * **Prerequisites:**<br>
+     * * Pro or a higher plan with [Zoom Room](https://zoom.us/zoomrooms) license.<br>
+     * **Scopes**: `room:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_zoom_stream(
+        &self,
+        params: &RoomsListZoomParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::ListZoomRoomsResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(location_id) = &params.location_id {
+            query_args.push(("location_id".to_string(), location_id.to_string()));
+        }
+        if let Some(status) = &params.status {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        if let Some(unassigned_rooms) = &params.unassigned_rooms {
+            query_args.push(("unassigned_rooms".to_string(), unassigned_rooms.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/rooms?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListZoomRoomsResponseData = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.rooms,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Add a Zoom Room.
      *
@@ -249,17 +338,16 @@ impl Rooms {
      *
      * **Parameters:**
      *
-     * * `setting_type: &str` -- The type of setting that you would like to retrieve.<br> `alert`: Alert Settings applied on the Zoom Rooms Account.<br>
-     *   `meeting`: Meeting settings of the Zoom Rooms Account.
      * * `room_id: &str` -- Unique identifier of the Zoom Room.
+     * * `params: &RoomsGetZrSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_zr_setting(
+    pub async fn get_zr_setting_with(
         &self,
         room_id: &str,
-        setting_type: &str,
+        params: &RoomsGetZrSettingParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !setting_type.is_empty() {
+        if let Some(setting_type) = &params.setting_type {
             query_args.push(("setting_type".to_string(), setting_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -272,6 +360,21 @@ impl Rooms {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_zr_setting_with` and pass the query parameters as a `RoomsGetZrSettingParams` instead.
+     */
+    #[deprecated(note = "use `get_zr_setting_with` instead")]
+    pub async fn get_zr_setting(
+        &self,
+        room_id: &str,
+        setting_type: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = RoomsGetZrSettingParams {
+            setting_type: Some(setting_type.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_zr_setting_with(room_id, &params).await
+    }
+
     /**
      * Update Zoom Room settings.
      *
@@ -287,13 +390,15 @@ impl Rooms {
      * **Parameters:**
      *
      * * `room_id: &str` -- Unique Identifier of the Zoom Room.
-     * * `setting_type: &str` -- The type of setting that you would like to update.<br> `alert`: Alert Settings applied on the Zoom Room.<br>
-     *   `meeting`: Meeting settings of the Zoom Room.<br>
-     *   `signage`: Digital signage settings applied on the Zoom Room.
+     * * `params: &RoomsUpdateZrSettingsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update_zr_settings(&self, room_id: &str, setting_type: &str) -> Result<()> {
+    pub async fn update_zr_settings_with(
+        &self,
+        room_id: &str,
+        params: &RoomsUpdateZrSettingsParams,
+    ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !setting_type.is_empty() {
+        if let Some(setting_type) = &params.setting_type {
             query_args.push(("setting_type".to_string(), setting_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -306,6 +411,17 @@ impl Rooms {
         self.client.patch(&url, None).await
     }
 
+    /**
+     * Deprecated: use `update_zr_settings_with` and pass the query parameters as a `RoomsUpdateZrSettingsParams` instead.
+     */
+    #[deprecated(note = "use `update_zr_settings_with` instead")]
+    pub async fn update_zr_settings(&self, room_id: &str, setting_type: &str) -> Result<()> {
+        let params = RoomsUpdateZrSettingsParams {
+            setting_type: Some(setting_type.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.update_zr_settings_with(room_id, &params).await
+    }
+
     /**
      * List Zoom Room devices.
      *
@@ -410,31 +526,23 @@ impl Rooms {
      *
      * **Parameters:**
      *
-     * * `type_: &str` -- Specify the type of digital signane resource. The value can be one of the following:
-     *   * `content`: Returns information about content files.
-     *   * `folder`: Returns information about the folder where the content files are located.
-     * * `folder_id: &str` -- Unique identifier of the folder where the content is located. Provide this field if you would like to filter the response by contents that are only available in a specific folder.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &RoomsListDigitalSignageContentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_digital_signage_content(
+    pub async fn list_digital_signage_content_with(
         &self,
-        type_: &str,
-        folder_id: &str,
-        page_size: i64,
-        next_page_token: &str,
+        params: &RoomsListDigitalSignageContentParams,
     ) -> Result<Vec<crate::types::Site>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !folder_id.is_empty() {
+        if let Some(folder_id) = &params.folder_id {
             query_args.push(("folder_id".to_string(), folder_id.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -447,6 +555,26 @@ impl Rooms {
         Ok(resp.contents.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_digital_signage_content_with` and pass the query parameters as a `RoomsListDigitalSignageContentParams` instead.
+     */
+    #[deprecated(note = "use `list_digital_signage_content_with` instead")]
+    pub async fn list_digital_signage_content(
+        &self,
+        type_: &str,
+        folder_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Site>> {
+        let params = RoomsListDigitalSignageContentParams {
+            folder_id: Some(folder_id.to_string()).filter(|s| !s.is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_digital_signage_content_with(&params).await
+    }
+
     /**
      * List digital signage contents.
      *
@@ -462,17 +590,20 @@ impl Rooms {
      *
      *
      *
+     *
+     * **Parameters:**
+     *
+     * * `params: &RoomsListDigitalSignageContentParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_digital_signage_content(
+    pub async fn list_all_digital_signage_content_with(
         &self,
-        type_: &str,
-        folder_id: &str,
+        params: &RoomsListDigitalSignageContentParams,
     ) -> Result<Vec<crate::types::Site>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !folder_id.is_empty() {
+        if let Some(folder_id) = &params.folder_id {
             query_args.push(("folder_id".to_string(), folder_id.to_string()));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -512,6 +643,76 @@ impl Rooms {
         Ok(contents)
     }
 
+    /**
+     * Deprecated: use `list_all_digital_signage_content_with` and pass the query parameters as a `RoomsListDigitalSignageContentParams` instead.
+     */
+    #[deprecated(note = "use `list_all_digital_signage_content_with` instead")]
+    pub async fn list_all_digital_signage_content(
+        &self,
+        type_: &str,
+        folder_id: &str,
+    ) -> Result<Vec<crate::types::Site>> {
+        let params = RoomsListDigitalSignageContentParams {
+            folder_id: Some(folder_id.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_digital_signage_content_with(&params).await
+    }
+
+    /**
+     * List digital signage contents.
+     *
+     * This function performs a `GET` to the `/rooms/digital_signage` endpoint.
+     *
+     * As opposed to `list_digital_signage_content`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * List information about existing [Zoom Rooms digital signage](https://support.zoom.us/hc/en-us/articles/360000030683-Zoom-Rooms-digital-signage) content in a Zoom account.<br> You can also access this information by logging into your Zoom account in the Zoom web portal and visiting the [Digital Signage Content](https://zoom.us/digitalsignage#/) page listed under **Room Management**.
+     *
+     * **Prerequisites:**
+     * * Pro or a higher account with Zoom Rooms.
+     * * Existing content files or folder in [Digital Signage Content](https://zoom.us/digitalsignage#/) page.
+     *
+     *
+     *
+     */
+    pub fn list_digital_signage_content_stream(
+        &self,
+        params: &RoomsListDigitalSignageContentParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Site> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(folder_id) = &params.folder_id {
+            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/rooms/digital_signage?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListDigitalSignageContentResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.contents,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Update E911 digital signage.
      *
@@ -531,3 +732,120 @@ impl Rooms {
         self.client.patch(&url, None).await
     }
 }
+
+/// The query parameters of `Rooms::list_zoom_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RoomsListZoomParams {
+    /// Parent location ID of the Zoom Room.
+    pub location_id: Option<String>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// The status of the Zoom Room.
+    pub status: Option<crate::types::ListZoomRoomsStatus>,
+    /// Type of the Zoom Rooms.
+    pub type_: Option<crate::types::ListZoomRoomsType>,
+    /// Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+    pub unassigned_rooms: Option<bool>,
+}
+
+impl RoomsListZoomParams {
+    pub fn location_id<T: ToString>(mut self, location_id: T) -> Self {
+        self.location_id = Some(location_id.to_string());
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::ListZoomRoomsStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::ListZoomRoomsType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+
+    pub fn unassigned_rooms(mut self, unassigned_rooms: bool) -> Self {
+        self.unassigned_rooms = Some(unassigned_rooms);
+        self
+    }
+}
+
+/// The query parameters of `Rooms::get_zr_setting_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RoomsGetZrSettingParams {
+    /// The type of setting that you would like to retrieve.<br> `alert`: Alert Settings applied on the Zoom Rooms Account.<br>
+    /// `meeting`: Meeting settings of the Zoom Rooms Account.
+    pub setting_type: Option<String>,
+}
+
+impl RoomsGetZrSettingParams {
+    pub fn setting_type<T: ToString>(mut self, setting_type: T) -> Self {
+        self.setting_type = Some(setting_type.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Rooms::update_zr_settings_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RoomsUpdateZrSettingsParams {
+    /// The type of setting that you would like to update.<br> `alert`: Alert Settings applied on the Zoom Room.<br>
+    /// `meeting`: Meeting settings of the Zoom Room.<br>
+    /// `signage`: Digital signage settings applied on the Zoom Room.
+    pub setting_type: Option<String>,
+}
+
+impl RoomsUpdateZrSettingsParams {
+    pub fn setting_type<T: ToString>(mut self, setting_type: T) -> Self {
+        self.setting_type = Some(setting_type.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Rooms::list_digital_signage_content_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RoomsListDigitalSignageContentParams {
+    /// Unique identifier of the folder where the content is located. Provide this field if you would like to filter the response by contents that are only available in a specific folder.
+    pub folder_id: Option<String>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// Specify the type of digital signane resource. The value can be one of the following:
+    /// * `content`: Returns information about content files.
+    /// * `folder`: Returns information about the folder where the content files are located.
+    pub type_: Option<String>,
+}
+
+impl RoomsListDigitalSignageContentParams {
+    pub fn folder_id<T: ToString>(mut self, folder_id: T) -> Self {
+        self.folder_id = Some(folder_id.to_string());
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
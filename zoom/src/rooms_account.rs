@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct RoomsAccount {
     pub client: Client,
@@ -68,16 +66,14 @@ impl RoomsAccount {
      *
      * **Parameters:**
      *
-     * * `setting_type: crate::types::SettingType` -- The type of setting that you would like to retrieve.<br> `alert`: Alert Settings applied on the Zoom Rooms Account.<br>
-     *  `meeting`: Meeting settings of the Zoom Rooms Account. <br>
-     *  `signage`: View digital signage settings of the Zoom Rooms Account.
+     * * `params: &RoomsAccountGetZrAccountSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_zr_account_setting(
+    pub async fn get_zr_account_setting_with(
         &self,
-        setting_type: crate::types::SettingType,
+        params: &RoomsAccountGetZrAccountSettingParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !setting_type.to_string().is_empty() {
+        if let Some(setting_type) = &params.setting_type {
             query_args.push(("setting_type".to_string(), setting_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -86,6 +82,20 @@ impl RoomsAccount {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_zr_account_setting_with` and pass the query parameters as a `RoomsAccountGetZrAccountSettingParams` instead.
+     */
+    #[deprecated(note = "use `get_zr_account_setting_with` instead")]
+    pub async fn get_zr_account_setting(
+        &self,
+        setting_type: crate::types::SettingType,
+    ) -> Result<crate::types::Domains> {
+        let params = RoomsAccountGetZrAccountSettingParams {
+            setting_type: Some(setting_type).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_zr_account_setting_with(&params).await
+    }
+
     /**
      * Update Zoom Room account settings.
      *
@@ -101,16 +111,14 @@ impl RoomsAccount {
      *
      * **Parameters:**
      *
-     * * `setting_type: &str` -- The type of setting that you would like to update.<br> `alert`: Alert Settings applied on the Zoom Rooms Account.<br>
-     *   `meeting`: Meeting settings of the Zoom Rooms Account.<br>
-     *   `signage`: View digital signage settings of the Zoom Rooms Account.
+     * * `params: &RoomsAccountUpdateZoomRoomAccSettingsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update_zoom_room_acc_settings(
+    pub async fn update_zoom_room_acc_settings_with(
         &self,
-        setting_type: &str,
+        params: &RoomsAccountUpdateZoomRoomAccSettingsParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !setting_type.is_empty() {
+        if let Some(setting_type) = &params.setting_type {
             query_args.push(("setting_type".to_string(), setting_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -118,4 +126,50 @@ impl RoomsAccount {
 
         self.client.patch(&url, None).await
     }
+
+    /**
+     * Deprecated: use `update_zoom_room_acc_settings_with` and pass the query parameters as a `RoomsAccountUpdateZoomRoomAccSettingsParams` instead.
+     */
+    #[deprecated(note = "use `update_zoom_room_acc_settings_with` instead")]
+    pub async fn update_zoom_room_acc_settings(
+        &self,
+        setting_type: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = RoomsAccountUpdateZoomRoomAccSettingsParams {
+            setting_type: Some(setting_type.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.update_zoom_room_acc_settings_with(&params).await
+    }
+}
+
+/// The query parameters of `RoomsAccount::get_zr_account_setting_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RoomsAccountGetZrAccountSettingParams {
+    /// The type of setting that you would like to retrieve.<br> `alert`: Alert Settings applied on the Zoom Rooms Account.<br>
+    /// `meeting`: Meeting settings of the Zoom Rooms Account. <br>
+    /// `signage`: View digital signage settings of the Zoom Rooms Account.
+    pub setting_type: Option<crate::types::SettingType>,
+}
+
+impl RoomsAccountGetZrAccountSettingParams {
+    pub fn setting_type(mut self, setting_type: crate::types::SettingType) -> Self {
+        self.setting_type = Some(setting_type);
+        self
+    }
+}
+
+/// The query parameters of `RoomsAccount::update_zoom_room_acc_settings_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RoomsAccountUpdateZoomRoomAccSettingsParams {
+    /// The type of setting that you would like to update.<br> `alert`: Alert Settings applied on the Zoom Rooms Account.<br>
+    /// `meeting`: Meeting settings of the Zoom Rooms Account.<br>
+    /// `signage`: View digital signage settings of the Zoom Rooms Account.
+    pub setting_type: Option<String>,
+}
+
+impl RoomsAccountUpdateZoomRoomAccSettingsParams {
+    pub fn setting_type<T: ToString>(mut self, setting_type: T) -> Self {
+        self.setting_type = Some(setting_type.to_string());
+        self
+    }
 }
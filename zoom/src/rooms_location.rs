@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct RoomsLocation {
     pub client: Client,
@@ -27,33 +25,26 @@ impl RoomsLocation {
      *
      * **Parameters:**
      *
-     * * `parent_location_id: &str` -- A unique identifier of the parent location. For instance, if a Zoom Room is located in Floor 1 of Building A, the location of Building A will be the parent location of Floor 1. Use this parameter to filter the response by a specific location hierarchy level.
-     * * `type_: &str` -- Use this field to filter the response by the type of location. The value can be one of the following:
-     *   `country`, `states`, `city`, `campus`, `building`, `floor`. .
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &RoomsLocationListZrLocationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_zr_locations(
+    pub async fn list_zr_locations_with(
         &self,
-        parent_location_id: &str,
-        type_: &str,
-        page_size: i64,
-        next_page_token: &str,
+        params: &RoomsLocationListZrLocationsParams,
     ) -> Result<Vec<crate::types::AddAzrLocationResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !parent_location_id.is_empty() {
+        if let Some(parent_location_id) = &params.parent_location_id {
             query_args.push((
                 "parent_location_id".to_string(),
                 parent_location_id.to_string(),
             ));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -65,6 +56,26 @@ impl RoomsLocation {
         Ok(resp.locations.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_zr_locations_with` and pass the query parameters as a `RoomsLocationListZrLocationsParams` instead.
+     */
+    #[deprecated(note = "use `list_zr_locations_with` instead")]
+    pub async fn list_zr_locations(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::AddAzrLocationResponse>> {
+        let params = RoomsLocationListZrLocationsParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            parent_location_id: Some(parent_location_id.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_zr_locations_with(&params).await
+    }
+
     /**
      * List Zoom Room locations.
      *
@@ -79,20 +90,23 @@ impl RoomsLocation {
      * **Scopes:** `room:read:admin`<br>
      *  
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Parameters:**
+     *
+     * * `params: &RoomsLocationListZrLocationsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all_zr_locations(
+    pub async fn list_all_zr_locations_with(
         &self,
-        parent_location_id: &str,
-        type_: &str,
+        params: &RoomsLocationListZrLocationsParams,
     ) -> Result<Vec<crate::types::AddAzrLocationResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !parent_location_id.is_empty() {
+        if let Some(parent_location_id) = &params.parent_location_id {
             query_args.push((
                 "parent_location_id".to_string(),
                 parent_location_id.to_string(),
             ));
         }
-        if !type_.is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -132,6 +146,78 @@ impl RoomsLocation {
         Ok(locations)
     }
 
+    /**
+     * Deprecated: use `list_all_zr_locations_with` and pass the query parameters as a `RoomsLocationListZrLocationsParams` instead.
+     */
+    #[deprecated(note = "use `list_all_zr_locations_with` instead")]
+    pub async fn list_all_zr_locations(
+        &self,
+        parent_location_id: &str,
+        type_: &str,
+    ) -> Result<Vec<crate::types::AddAzrLocationResponse>> {
+        let params = RoomsLocationListZrLocationsParams {
+            parent_location_id: Some(parent_location_id.to_string()).filter(|s| !s.is_empty()),
+            type_: Some(type_.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_zr_locations_with(&params).await
+    }
+
+    /**
+     * List Zoom Room locations.
+     *
+     * This function performs a `GET` to the `/rooms/locations` endpoint.
+     *
+     * As opposed to `list_zr_locations`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * A Zoom account owner or a Zoom Room administrator can establish a [location hierarchy](https://support.zoom.us/hc/en-us/articles/115000342983-Zoom-Rooms-Location-Hierarchy) to help manage Zoom Rooms that are spread among a variety of locations. Use this API to list the different location types used for Zoom Rooms in an account.<br><br>
+     * **Prerequisites:**
+     * * Account owner or admin permissions.
+     * * Zoom Rooms Version 4.0 or higher<br><br>
+     * **Scopes:** `room:read:admin`<br>
+     *  
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn list_zr_locations_stream(
+        &self,
+        params: &RoomsLocationListZrLocationsParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::AddAzrLocationResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(parent_location_id) = &params.parent_location_id {
+            query_args.push((
+                "parent_location_id".to_string(),
+                parent_location_id.to_string(),
+            ));
+        }
+        if let Some(type_) = &params.type_ {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/rooms/locations?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListZrLocationsResponseData =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.locations,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Add a location.
      *
@@ -235,18 +321,16 @@ impl RoomsLocation {
      *
      * **Parameters:**
      *
-     * * `setting_type: &str` -- The type of setting that you would like to retrieve.<br> `alert`: Alert Settings applied on the Zoom Rooms Account.<br>
-     *   `meeting`: Meeting settings of the Zoom Rooms Account.<br>
-     *   `signage`: Digital signage settings of the Zoom Rooms Account.
      * * `location_id: &str` -- Unique identifier of the location type. This can be retrieved using the [List Zoom Room Location API](https://marketplace.zoom.us/docs/api-reference/zoom-api/rooms-location/listzrlocations) (Id property in the response).
+     * * `params: &RoomsLocationGetZrLocationSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_zr_location_setting(
+    pub async fn get_zr_location_setting_with(
         &self,
         location_id: &str,
-        setting_type: &str,
+        params: &RoomsLocationGetZrLocationSettingParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !setting_type.is_empty() {
+        if let Some(setting_type) = &params.setting_type {
             query_args.push(("setting_type".to_string(), setting_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -259,6 +343,22 @@ impl RoomsLocation {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_zr_location_setting_with` and pass the query parameters as a `RoomsLocationGetZrLocationSettingParams` instead.
+     */
+    #[deprecated(note = "use `get_zr_location_setting_with` instead")]
+    pub async fn get_zr_location_setting(
+        &self,
+        location_id: &str,
+        setting_type: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = RoomsLocationGetZrLocationSettingParams {
+            setting_type: Some(setting_type.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.get_zr_location_setting_with(location_id, &params)
+            .await
+    }
+
     /**
      * Update location settings.
      *
@@ -273,18 +373,16 @@ impl RoomsLocation {
      *
      * **Parameters:**
      *
-     * * `setting_type: &str` -- The type of setting that you would like to update.<br> `alert`: Alert Settings applied on the Zoom Rooms Account.<br>
-     *   `meeting`: Meeting settings of the Zoom Rooms Account.<br>
-     *   `signage`: Digital signage settings.
      * * `location_id: &str` -- Unique identifier of the location type. This can be retrieved using the [List Zoom Room Location API](https://marketplace.zoom.us/docs/api-reference/zoom-api/rooms-location/listzrlocations) (Id property in the response).
+     * * `params: &RoomsLocationUpdateZrLocationSettingsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update_zr_location_settings(
+    pub async fn update_zr_location_settings_with(
         &self,
         location_id: &str,
-        setting_type: &str,
+        params: &RoomsLocationUpdateZrLocationSettingsParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !setting_type.is_empty() {
+        if let Some(setting_type) = &params.setting_type {
             query_args.push(("setting_type".to_string(), setting_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -297,6 +395,22 @@ impl RoomsLocation {
         self.client.patch(&url, None).await
     }
 
+    /**
+     * Deprecated: use `update_zr_location_settings_with` and pass the query parameters as a `RoomsLocationUpdateZrLocationSettingsParams` instead.
+     */
+    #[deprecated(note = "use `update_zr_location_settings_with` instead")]
+    pub async fn update_zr_location_settings(
+        &self,
+        location_id: &str,
+        setting_type: &str,
+    ) -> Result<()> {
+        let params = RoomsLocationUpdateZrLocationSettingsParams {
+            setting_type: Some(setting_type.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.update_zr_location_settings_with(location_id, &params)
+            .await
+    }
+
     /**
      * Get Zoom Room location structure.
      *
@@ -369,3 +483,71 @@ impl RoomsLocation {
             .await
     }
 }
+
+/// The query parameters of `RoomsLocation::list_zr_locations_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RoomsLocationListZrLocationsParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// A unique identifier of the parent location. For instance, if a Zoom Room is located in Floor 1 of Building A, the location of Building A will be the parent location of Floor 1. Use this parameter to filter the response by a specific location hierarchy level.
+    pub parent_location_id: Option<String>,
+    /// Use this field to filter the response by the type of location. The value can be one of the following:
+    /// `country`, `states`, `city`, `campus`, `building`, `floor`. .
+    pub type_: Option<String>,
+}
+
+impl RoomsLocationListZrLocationsParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn parent_location_id<T: ToString>(mut self, parent_location_id: T) -> Self {
+        self.parent_location_id = Some(parent_location_id.to_string());
+        self
+    }
+
+    pub fn type_<T: ToString>(mut self, type_: T) -> Self {
+        self.type_ = Some(type_.to_string());
+        self
+    }
+}
+
+/// The query parameters of `RoomsLocation::get_zr_location_setting_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RoomsLocationGetZrLocationSettingParams {
+    /// The type of setting that you would like to retrieve.<br> `alert`: Alert Settings applied on the Zoom Rooms Account.<br>
+    /// `meeting`: Meeting settings of the Zoom Rooms Account.<br>
+    /// `signage`: Digital signage settings of the Zoom Rooms Account.
+    pub setting_type: Option<String>,
+}
+
+impl RoomsLocationGetZrLocationSettingParams {
+    pub fn setting_type<T: ToString>(mut self, setting_type: T) -> Self {
+        self.setting_type = Some(setting_type.to_string());
+        self
+    }
+}
+
+/// The query parameters of `RoomsLocation::update_zr_location_settings_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct RoomsLocationUpdateZrLocationSettingsParams {
+    /// The type of setting that you would like to update.<br> `alert`: Alert Settings applied on the Zoom Rooms Account.<br>
+    /// `meeting`: Meeting settings of the Zoom Rooms Account.<br>
+    /// `signage`: Digital signage settings.
+    pub setting_type: Option<String>,
+}
+
+impl RoomsLocationUpdateZrLocationSettingsParams {
+    pub fn setting_type<T: ToString>(mut self, setting_type: T) -> Self {
+        self.setting_type = Some(setting_type.to_string());
+        self
+    }
+}
@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SipConnectedAudio {
     pub client: Client,
@@ -306,20 +304,18 @@ impl SipConnectedAudio {
      * **Parameters:**
      *
      * * `account_id: &str` -- Unique identifier of the account. To list internal numbers of a sub account, provide the account ID of the sub account in the `accountId` path parameter. To list internal numbers of a  master account, provide `me` as the value of the `accountId` path parameter.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &SipConnectedAudioListInternalNumbersParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_internal_numbers(
+    pub async fn list_internal_numbers_with(
         &self,
         account_id: &str,
-        page_size: i64,
-        next_page_token: &str,
+        params: &SipConnectedAudioListInternalNumbersParams,
     ) -> Result<Vec<crate::types::InternalNumbers>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -335,6 +331,23 @@ impl SipConnectedAudio {
         Ok(resp.internal_numbers.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_internal_numbers_with` and pass the query parameters as a `SipConnectedAudioListInternalNumbersParams` instead.
+     */
+    #[deprecated(note = "use `list_internal_numbers_with` instead")]
+    pub async fn list_internal_numbers(
+        &self,
+        account_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::InternalNumbers>> {
+        let params = SipConnectedAudioListInternalNumbersParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.list_internal_numbers_with(account_id, &params).await
+    }
+
     /**
      * List internal numbers.
      *
@@ -393,6 +406,52 @@ impl SipConnectedAudio {
         Ok(internal_numbers)
     }
 
+    /**
+     * List internal numbers.
+     *
+     * This function performs a `GET` to the `/accounts/{accountId}/sip_trunk/internal_numbers` endpoint.
+     *
+     * As opposed to `list_internal_numbers`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * This API allows a master account with SIP Connected Audio plan to list internal phone numbers (i.e., numbers that are not provided by Zoom but are owned by the organization consuming the API) assigned to a master account or a sub account.<br><br>To list internal numbers of a sub account, provide the account ID of the sub account in the `accountId` path parameter. To list internal numbers of a  master account, provide `me` as the value of the `accountId` path parameter.
+     * <br><b>Prerequisites:</b><br>
+     * * The account making this API request must be a [master account](https://marketplace.zoom.us/docs/api-reference/master-account-apis) with SIP Connected Audio Plan.<br><br>
+     * **Scope:** `sip_trunk:master`
+     *
+     *
+     *
+     */
+    pub fn list_internal_numbers_stream(
+        &self,
+        account_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::InternalNumbers> {
+        let url = format!(
+            "/accounts/{}/sip_trunk/internal_numbers",
+            crate::progenitor_support::encode_path(account_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListInternalNumbersResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.internal_numbers,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Add internal numbers.
      *
@@ -452,3 +511,24 @@ impl SipConnectedAudio {
         self.client.delete(&url, None).await
     }
 }
+
+/// The query parameters of `SipConnectedAudio::list_internal_numbers_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SipConnectedAudioListInternalNumbersParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl SipConnectedAudioListInternalNumbersParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
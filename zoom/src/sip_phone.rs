@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct SipPhone {
     pub client: Client,
@@ -26,32 +24,23 @@ impl SipPhone {
      *
      * **Parameters:**
      *
-     * * `page_number: i64` --
-     *   **Deprecated** - This field has been deprecated and we will stop supporting it completely in a future release. Please use "next_page_token" for pagination instead of this field.
-     *   
-     *   The page number of the current page in the returned records.
-     * * `search_key: &str` -- User name or email address of a user. If this parameter is provided, only the SIP phone system integration enabled for that specific user will be returned. Otherwise, all SIP phones on an account will be returned.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &SipPhoneListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list(
+    pub async fn list_with(
         &self,
-        page_number: i64,
-        search_key: &str,
-        page_size: i64,
-        next_page_token: &str,
+        params: &SipPhoneListParams,
     ) -> Result<Vec<crate::types::Phones>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_number > 0 {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !search_key.is_empty() {
+        if let Some(search_key) = &params.search_key {
             query_args.push(("search_key".to_string(), search_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -63,6 +52,26 @@ impl SipPhone {
         Ok(resp.phones.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_with` and pass the query parameters as a `SipPhoneListParams` instead.
+     */
+    #[deprecated(note = "use `list_with` instead")]
+    pub async fn list(
+        &self,
+        page_number: i64,
+        search_key: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Phones>> {
+        let params = SipPhoneListParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_number: Some(page_number).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            search_key: Some(search_key.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.list_with(&params).await
+    }
+
     /**
      * List SIP phones.
      *
@@ -76,10 +85,17 @@ impl SipPhone {
      * * User must enable SIP Phone Integration by contacting the [Sales](https://zoom.us/contactsales) team.<br> **Scope:** `sip_phone:read:admin`<br>
      *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
      *
+     *
+     * **Parameters:**
+     *
+     * * `params: &SipPhoneListParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_all(&self, search_key: &str) -> Result<Vec<crate::types::Phones>> {
+    pub async fn list_all_with(
+        &self,
+        params: &SipPhoneListParams,
+    ) -> Result<Vec<crate::types::Phones>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !search_key.is_empty() {
+        if let Some(search_key) = &params.search_key {
             query_args.push(("search_key".to_string(), search_key.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -118,6 +134,65 @@ impl SipPhone {
         Ok(phones)
     }
 
+    /**
+     * Deprecated: use `list_all_with` and pass the query parameters as a `SipPhoneListParams` instead.
+     */
+    #[deprecated(note = "use `list_all_with` instead")]
+    pub async fn list_all(&self, search_key: &str) -> Result<Vec<crate::types::Phones>> {
+        let params = SipPhoneListParams {
+            search_key: Some(search_key.to_string()).filter(|s| !s.is_empty()),
+            ..Default::default()
+        };
+        self.list_all_with(&params).await
+    }
+
+    /**
+     * List SIP phones.
+     *
+     * This function performs a `GET` to the `/sip_phones` endpoint.
+     *
+     * As opposed to `list`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Zoom’s Phone System Integration (PSI), also referred as SIP phones, enables an organization to leverage the Zoom client to complete a softphone registration to supported premise based PBX system. End users will have the ability to have softphone functionality within a single client while maintaining a comparable interface to Zoom Phone. Use this API to list SIP phones on an account.<br><br>
+     * **Prerequisites**:
+     * * Currently only supported on Cisco and Avaya PBX systems.
+     * * User must enable SIP Phone Integration by contacting the [Sales](https://zoom.us/contactsales) team.<br> **Scope:** `sip_phone:read:admin`<br>
+     *  **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     */
+    pub fn list_stream(
+        &self,
+        params: &SipPhoneListParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Phones> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(search_key) = &params.search_key {
+            query_args.push(("search_key".to_string(), search_key.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/sip_phones?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListSipPhonesResponse = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.phones,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Enable SIP phone.
      *
@@ -195,3 +270,37 @@ impl SipPhone {
             .await
     }
 }
+
+/// The query parameters of `SipPhone::list_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct SipPhoneListParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    pub page_number: Option<i64>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// User name or email address of a user. If this parameter is provided, only the SIP phone system integration enabled for that specific user will be returned. Otherwise, all SIP phones on an account will be returned.
+    pub search_key: Option<String>,
+}
+
+impl SipPhoneListParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn search_key<T: ToString>(mut self, search_key: T) -> Self {
+        self.search_key = Some(search_key.to_string());
+        self
+    }
+}
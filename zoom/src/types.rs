@@ -2,27 +2,163 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// A field of a request body that can be left out, set to `null` or set to a value.
+///
+/// Unlike with an `Option`, a field that is left out, which the API leaves as it is,
+/// is told apart from one that is `null`, which clears it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum Nullable<T> {
+    /// The field is left out.
+    #[default]
+    Absent,
+    /// The field is `null`.
+    Null,
+    /// The field is set to this value.
+    Value(T),
+}
+
+impl<T> Nullable<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Nullable::Absent)
+    }
+
+    /// The value of the field, if it is set to one.
+    pub fn value(&self) -> std::option::Option<&T> {
+        match self {
+            Nullable::Value(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl<T> std::convert::From<T> for Nullable<T> {
+    fn from(v: T) -> Self {
+        Nullable::Value(v)
+    }
+}
+
+impl<T: Serialize> Serialize for Nullable<T> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            Nullable::Value(v) => v.serialize(serializer),
+            // Fields that are left out are skipped, this is only reached for other values.
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Nullable<T> {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // A field that is missing is `Absent`, from the `default` of the field.
+        Ok(match std::option::Option::<T>::deserialize(deserializer)? {
+            Some(v) => Nullable::Value(v),
+            None => Nullable::Null,
+        })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Nullable<T> {
+    fn schema_name() -> String {
+        std::option::Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        std::option::Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        false
+    }
+}
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, which keeps the number of
+/// decimal places it came with, so `"70000.00"` is sent back as `"70000.00"`.
+#[cfg(feature = "decimal")]
+pub type DecimalString = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a string.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalString = String;
+
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// With the `decimal` feature, it is a `rust_decimal::Decimal`, so it is not rounded
+/// once it is read, it is still sent as a number.
+#[cfg(feature = "decimal")]
+pub type DecimalNumber = rust_decimal::Decimal;
+/// An exact decimal, like an amount of money, that the API sends as a number.
+///
+/// Enable the `decimal` feature to have it as a `rust_decimal::Decimal`.
+#[cfg(not(feature = "decimal"))]
+pub type DecimalNumber = f64;
+
 /**
  * Payee:<br>`master` - master account holder pays.<br>`sub` - Sub account holder pays.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PayMode {
     #[serde(rename = "master")]
     Master,
     #[serde(rename = "sub")]
     Sub,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PayMode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PayMode {
+    fn as_ref(&self) -> &str {
+        match self {
             PayMode::Master => "master",
             PayMode::Sub => "sub",
-            PayMode::FallthroughString => "*",
+            PayMode::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PayMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PayMode {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "master" => PayMode::Master,
+            "sub" => PayMode::Sub,
+            _ => PayMode::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PayMode {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PayMode> for String {
+    fn from(v: PayMode) -> Self {
+        match v {
+            PayMode::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -476,6 +612,7 @@ pub struct AccountPlans {
  * Determine how participants can join the audio portion of the meeting.<br>`both` - Telephony and VoIP.<br>`telephony` - Audio PSTN telephony only.<br>`voip` - VoIP only.<br>`thirdParty` - 3rd party audio conference.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AudioType {
     #[serde(rename = "both")]
     Both,
@@ -485,20 +622,59 @@ pub enum AudioType {
     ThirdParty,
     #[serde(rename = "voip")]
     Voip,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AudioType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AudioType {
+    fn as_ref(&self) -> &str {
+        match self {
             AudioType::Both => "both",
             AudioType::Telephony => "telephony",
             AudioType::ThirdParty => "thirdParty",
             AudioType::Voip => "voip",
-            AudioType::FallthroughString => "*",
+            AudioType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for AudioType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AudioType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "both" => AudioType::Both,
+            "telephony" => AudioType::Telephony,
+            "thirdParty" => AudioType::ThirdParty,
+            "voip" => AudioType::Voip,
+            _ => AudioType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AudioType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<AudioType> for String {
+    fn from(v: AudioType) -> Self {
+        match v {
+            AudioType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -513,6 +689,7 @@ impl Default for AudioType {
  *   
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RequirePasswordPmiMeetings {
     #[serde(rename = "all")]
     All,
@@ -520,33 +697,57 @@ pub enum RequirePasswordPmiMeetings {
     JbhOnly,
     #[serde(rename = "none")]
     None,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for RequirePasswordPmiMeetings {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for RequirePasswordPmiMeetings {
+    fn as_ref(&self) -> &str {
+        match self {
             RequirePasswordPmiMeetings::All => "all",
             RequirePasswordPmiMeetings::JbhOnly => "jbh_only",
             RequirePasswordPmiMeetings::None => "none",
-            RequirePasswordPmiMeetings::Noop => "",
-            RequirePasswordPmiMeetings::FallthroughString => "*",
+            RequirePasswordPmiMeetings::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for RequirePasswordPmiMeetings {
-    fn default() -> RequirePasswordPmiMeetings {
-        RequirePasswordPmiMeetings::Noop
+impl std::fmt::Display for RequirePasswordPmiMeetings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl RequirePasswordPmiMeetings {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RequirePasswordPmiMeetings::Noop)
+
+impl std::str::FromStr for RequirePasswordPmiMeetings {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => RequirePasswordPmiMeetings::All,
+            "jbh_only" => RequirePasswordPmiMeetings::JbhOnly,
+            "none" => RequirePasswordPmiMeetings::None,
+            _ => RequirePasswordPmiMeetings::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RequirePasswordPmiMeetings {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<RequirePasswordPmiMeetings> for String {
+    fn from(v: RequirePasswordPmiMeetings) -> Self {
+        match v {
+            RequirePasswordPmiMeetings::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -767,6 +968,7 @@ pub struct ScheduleMeeting {
  * Play sound when participants join or leave.<br>`host` - Heard by host only.<br>`all` - Heard by host and all attendees.<br>`none` - Disable.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EntryExitChime {
     #[serde(rename = "all")]
     All,
@@ -774,33 +976,57 @@ pub enum EntryExitChime {
     Host,
     #[serde(rename = "none")]
     None,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EntryExitChime {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EntryExitChime {
+    fn as_ref(&self) -> &str {
+        match self {
             EntryExitChime::All => "all",
             EntryExitChime::Host => "host",
             EntryExitChime::None => "none",
-            EntryExitChime::Noop => "",
-            EntryExitChime::FallthroughString => "*",
+            EntryExitChime::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EntryExitChime {
-    fn default() -> EntryExitChime {
-        EntryExitChime::Noop
+impl std::fmt::Display for EntryExitChime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl EntryExitChime {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EntryExitChime::Noop)
+
+impl std::str::FromStr for EntryExitChime {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => EntryExitChime::All,
+            "host" => EntryExitChime::Host,
+            "none" => EntryExitChime::None,
+            _ => EntryExitChime::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for EntryExitChime {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<EntryExitChime> for String {
+    fn from(v: EntryExitChime) -> Self {
+        match v {
+            EntryExitChime::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -898,37 +1124,61 @@ pub struct VirtualBackgroundSettings {
  *   
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WhoCanShareScreen {
     #[serde(rename = "all")]
     All,
     #[serde(rename = "host")]
     Host,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for WhoCanShareScreen {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for WhoCanShareScreen {
+    fn as_ref(&self) -> &str {
+        match self {
             WhoCanShareScreen::All => "all",
             WhoCanShareScreen::Host => "host",
-            WhoCanShareScreen::Noop => "",
-            WhoCanShareScreen::FallthroughString => "*",
+            WhoCanShareScreen::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for WhoCanShareScreen {
-    fn default() -> WhoCanShareScreen {
-        WhoCanShareScreen::Noop
+impl std::fmt::Display for WhoCanShareScreen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl WhoCanShareScreen {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, WhoCanShareScreen::Noop)
+
+impl std::str::FromStr for WhoCanShareScreen {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => WhoCanShareScreen::All,
+            "host" => WhoCanShareScreen::Host,
+            _ => WhoCanShareScreen::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for WhoCanShareScreen {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<WhoCanShareScreen> for String {
+    fn from(v: WhoCanShareScreen) -> Self {
+        match v {
+            WhoCanShareScreen::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1544,6 +1794,7 @@ pub struct PasswordRequirement {
  *   
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SignInWithTwoFactorAuth {
     #[serde(rename = "all")]
     All,
@@ -1553,34 +1804,59 @@ pub enum SignInWithTwoFactorAuth {
     None,
     #[serde(rename = "role")]
     Role,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SignInWithTwoFactorAuth {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SignInWithTwoFactorAuth {
+    fn as_ref(&self) -> &str {
+        match self {
             SignInWithTwoFactorAuth::All => "all",
             SignInWithTwoFactorAuth::Group => "group",
             SignInWithTwoFactorAuth::None => "none",
             SignInWithTwoFactorAuth::Role => "role",
-            SignInWithTwoFactorAuth::Noop => "",
-            SignInWithTwoFactorAuth::FallthroughString => "*",
+            SignInWithTwoFactorAuth::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for SignInWithTwoFactorAuth {
-    fn default() -> SignInWithTwoFactorAuth {
-        SignInWithTwoFactorAuth::Noop
+impl std::fmt::Display for SignInWithTwoFactorAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl SignInWithTwoFactorAuth {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, SignInWithTwoFactorAuth::Noop)
+
+impl std::str::FromStr for SignInWithTwoFactorAuth {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => SignInWithTwoFactorAuth::All,
+            "group" => SignInWithTwoFactorAuth::Group,
+            "none" => SignInWithTwoFactorAuth::None,
+            "role" => SignInWithTwoFactorAuth::Role,
+            _ => SignInWithTwoFactorAuth::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SignInWithTwoFactorAuth {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SignInWithTwoFactorAuth> for String {
+    fn from(v: SignInWithTwoFactorAuth) -> Self {
+        match v {
+            SignInWithTwoFactorAuth::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -1663,6 +1939,7 @@ pub struct Security {
  * Automatic recording:<br>`local` - Record on local.<br>`cloud` -  Record on cloud.<br>`none` - Disabled.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AutoRecording {
     #[serde(rename = "cloud")]
     Cloud,
@@ -1670,33 +1947,57 @@ pub enum AutoRecording {
     Local,
     #[serde(rename = "none")]
     None,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AutoRecording {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AutoRecording {
+    fn as_ref(&self) -> &str {
+        match self {
             AutoRecording::Cloud => "cloud",
             AutoRecording::Local => "local",
             AutoRecording::None => "none",
-            AutoRecording::Noop => "",
-            AutoRecording::FallthroughString => "*",
+            AutoRecording::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AutoRecording {
-    fn default() -> AutoRecording {
-        AutoRecording::Noop
+impl std::fmt::Display for AutoRecording {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl AutoRecording {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AutoRecording::Noop)
+
+impl std::str::FromStr for AutoRecording {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cloud" => AutoRecording::Cloud,
+            "local" => AutoRecording::Local,
+            "none" => AutoRecording::None,
+            _ => AutoRecording::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AutoRecording {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<AutoRecording> for String {
+    fn from(v: AutoRecording) -> Self {
+        match v {
+            AutoRecording::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2563,37 +2864,61 @@ pub struct DateTime {
  * Device protocol:<br>`H.323` - H.323.<br>`SIP` - SIP.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Protocol {
     #[serde(rename = "H.323")]
     H323,
     #[serde(rename = "SIP")]
     Sip,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Protocol {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Protocol {
+    fn as_ref(&self) -> &str {
+        match self {
             Protocol::H323 => "H.323",
             Protocol::Sip => "SIP",
-            Protocol::Noop => "",
-            Protocol::FallthroughString => "*",
+            Protocol::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Protocol {
-    fn default() -> Protocol {
-        Protocol::Noop
+impl std::fmt::Display for Protocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Protocol {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "H.323" => Protocol::H323,
+            "SIP" => Protocol::Sip,
+            _ => Protocol::Unknown(s.to_string()),
+        })
     }
 }
-impl Protocol {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Protocol::Noop)
+
+impl std::convert::From<String> for Protocol {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Protocol> for String {
+    fn from(v: Protocol) -> Self {
+        match v {
+            Protocol::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2601,6 +2926,7 @@ impl Protocol {
  * Device encryption:<br>`auto` - auto.<br>`yes` - yes.<br>`no` - no.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Encryption {
     #[serde(rename = "auto")]
     Auto,
@@ -2608,33 +2934,57 @@ pub enum Encryption {
     No,
     #[serde(rename = "yes")]
     Yes,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Encryption {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Encryption {
+    fn as_ref(&self) -> &str {
+        match self {
             Encryption::Auto => "auto",
             Encryption::No => "no",
             Encryption::Yes => "yes",
-            Encryption::Noop => "",
-            Encryption::FallthroughString => "*",
+            Encryption::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Encryption {
-    fn default() -> Encryption {
-        Encryption::Noop
+impl std::fmt::Display for Encryption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Encryption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "auto" => Encryption::Auto,
+            "no" => Encryption::No,
+            "yes" => Encryption::Yes,
+            _ => Encryption::Unknown(s.to_string()),
+        })
     }
 }
-impl Encryption {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Encryption::Noop)
+
+impl std::convert::From<String> for Encryption {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Encryption> for String {
+    fn from(v: Encryption) -> Self {
+        match v {
+            Encryption::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2644,8 +2994,8 @@ pub struct Device {
     /**
      * Device encryption:<br>`auto` - auto.<br>`yes` - yes.<br>`no` - no.
      */
-    #[serde(default, skip_serializing_if = "Encryption::is_noop")]
-    pub encryption: Encryption,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<Encryption>,
     /**
      * User's first name.
      */
@@ -2667,8 +3017,8 @@ pub struct Device {
     /**
      * Device protocol:<br>`H.323` - H.323.<br>`SIP` - SIP.
      */
-    #[serde(default, skip_serializing_if = "Protocol::is_noop")]
-    pub protocol: Protocol,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<Protocol>,
 }
 
 /// Group object.
@@ -2871,6 +3221,7 @@ pub struct Occurrence {
  * Determine how participants can join the audio portion of the meeting.<br>`both` - Both Telephony and VoIP.<br>`telephony` - Telephony only.<br>`voip` - VoIP only.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Audio {
     #[serde(rename = "both")]
     Both,
@@ -2878,19 +3229,57 @@ pub enum Audio {
     Telephony,
     #[serde(rename = "voip")]
     Voip,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Audio {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Audio {
+    fn as_ref(&self) -> &str {
+        match self {
             Audio::Both => "both",
             Audio::Telephony => "telephony",
             Audio::Voip => "voip",
-            Audio::FallthroughString => "*",
+            Audio::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for Audio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Audio {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "both" => Audio::Both,
+            "telephony" => Audio::Telephony,
+            "voip" => Audio::Voip,
+            _ => Audio::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Audio {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Audio> for String {
+    fn from(v: Audio) -> Self {
+        match v {
+            Audio::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -2904,37 +3293,61 @@ impl Default for Audio {
  * Type of number.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Type {
     #[serde(rename = "toll")]
     Toll,
     #[serde(rename = "tollfree")]
     Tollfree,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Type {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        match self {
             Type::Toll => "toll",
             Type::Tollfree => "tollfree",
-            Type::Noop => "",
-            Type::FallthroughString => "*",
+            Type::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Type {
-    fn default() -> Type {
-        Type::Noop
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Type {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Type::Noop)
+
+impl std::str::FromStr for Type {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "toll" => Type::Toll,
+            "tollfree" => Type::Tollfree,
+            _ => Type::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Type {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Type> for String {
+    fn from(v: Type) -> Self {
+        match v {
+            Type::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -2990,37 +3403,61 @@ pub struct GlobalDialInNumbers {
  *   `e2ee`: [End-to-end encryption](https://support.zoom.us/hc/en-us/articles/360048660871). The encryption key is stored in your local device and can not be obtained by anyone else. Enabling this setting also **disables** the following features: join before host, cloud recording, streaming, live transcription, breakout rooms, polling, 1:1 private chat, and meeting reactions.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum EncryptionType {
     #[serde(rename = "e2ee")]
     E2Ee,
     #[serde(rename = "enhanced_encryption")]
     EnhancedEncryption,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for EncryptionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for EncryptionType {
+    fn as_ref(&self) -> &str {
+        match self {
             EncryptionType::E2Ee => "e2ee",
             EncryptionType::EnhancedEncryption => "enhanced_encryption",
-            EncryptionType::Noop => "",
-            EncryptionType::FallthroughString => "*",
+            EncryptionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for EncryptionType {
-    fn default() -> EncryptionType {
-        EncryptionType::Noop
+impl std::fmt::Display for EncryptionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl EncryptionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, EncryptionType::Noop)
+
+impl std::str::FromStr for EncryptionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "e2ee" => EncryptionType::E2Ee,
+            "enhanced_encryption" => EncryptionType::EnhancedEncryption,
+            _ => EncryptionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for EncryptionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<EncryptionType> for String {
+    fn from(v: EncryptionType) -> Self {
+        match v {
+            EncryptionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3030,37 +3467,61 @@ impl EncryptionType {
  *   `deny`: Block users from specific regions/countries from joining this meeting. If this setting is selected, the approved regions/countries must be included in the `denied_list`
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Method {
     #[serde(rename = "approve")]
     Approve,
     #[serde(rename = "deny")]
     Deny,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Method {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Method {
+    fn as_ref(&self) -> &str {
+        match self {
             Method::Approve => "approve",
             Method::Deny => "deny",
-            Method::Noop => "",
-            Method::FallthroughString => "*",
+            Method::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Method {
-    fn default() -> Method {
-        Method::Noop
+impl std::fmt::Display for Method {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Method {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Method::Noop)
+
+impl std::str::FromStr for Method {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approve" => Method::Approve,
+            "deny" => Method::Deny,
+            _ => Method::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Method {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Method> for String {
+    fn from(v: Method) -> Self {
+        match v {
+            Method::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -3541,6 +4002,7 @@ pub struct MeetingSettings {
  *    <br>`1`  - Sunday. <br>`2` - Monday.<br>`3` - Tuesday.<br>`4` -  Wednesday.<br>`5` -  Thursday.<br>`6` - Friday.<br>`7` - Saturday.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WeeklyDays {
     #[serde(rename = "1")]
     One,
@@ -3556,13 +4018,15 @@ pub enum WeeklyDays {
     Six,
     #[serde(rename = "7")]
     Seven,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for WeeklyDays {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for WeeklyDays {
+    fn as_ref(&self) -> &str {
+        match self {
             WeeklyDays::One => "1",
             WeeklyDays::Two => "2",
             WeeklyDays::Three => "3",
@@ -3570,9 +4034,49 @@ impl std::fmt::Display for WeeklyDays {
             WeeklyDays::Five => "5",
             WeeklyDays::Six => "6",
             WeeklyDays::Seven => "7",
-            WeeklyDays::FallthroughString => "*",
+            WeeklyDays::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for WeeklyDays {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for WeeklyDays {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "1" => WeeklyDays::One,
+            "2" => WeeklyDays::Two,
+            "3" => WeeklyDays::Three,
+            "4" => WeeklyDays::Four,
+            "5" => WeeklyDays::Five,
+            "6" => WeeklyDays::Six,
+            "7" => WeeklyDays::Seven,
+            _ => WeeklyDays::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for WeeklyDays {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<WeeklyDays> for String {
+    fn from(v: WeeklyDays) -> Self {
+        match v {
+            WeeklyDays::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -3811,37 +4315,61 @@ pub struct MeetingInfo {
  * Meeting status
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Status {
     #[serde(rename = "started")]
     Started,
     #[serde(rename = "waiting")]
     Waiting,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Status {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Status {
+    fn as_ref(&self) -> &str {
+        match self {
             Status::Started => "started",
             Status::Waiting => "waiting",
-            Status::Noop => "",
-            Status::FallthroughString => "*",
+            Status::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Status {
-    fn default() -> Status {
-        Status::Noop
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Status {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Status::Noop)
+
+impl std::str::FromStr for Status {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "started" => Status::Started,
+            "waiting" => Status::Waiting,
+            _ => Status::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Status {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Status> for String {
+    fn from(v: Status) -> Self {
+        match v {
+            Status::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4242,37 +4770,61 @@ pub struct MeetingLiveStream {
  *   `stop`: Stop an ongoing live stream.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Action {
     #[serde(rename = "start")]
     Start,
     #[serde(rename = "stop")]
     Stop,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Action {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Action {
+    fn as_ref(&self) -> &str {
+        match self {
             Action::Start => "start",
             Action::Stop => "stop",
-            Action::Noop => "",
-            Action::FallthroughString => "*",
+            Action::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Action {
-    fn default() -> Action {
-        Action::Noop
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Action {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Action::Noop)
+
+impl std::str::FromStr for Action {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "start" => Action::Start,
+            "stop" => Action::Stop,
+            _ => Action::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Action {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Action> for String {
+    fn from(v: Action) -> Self {
+        match v {
+            Action::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4595,34 +5147,57 @@ pub struct QosAudio {
  * The recording status.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingStatus {
     #[serde(rename = "completed")]
     Completed,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for RecordingStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for RecordingStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             RecordingStatus::Completed => "completed",
-            RecordingStatus::Noop => "",
-            RecordingStatus::FallthroughString => "*",
+            RecordingStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for RecordingStatus {
-    fn default() -> RecordingStatus {
-        RecordingStatus::Noop
+impl std::fmt::Display for RecordingStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl RecordingStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RecordingStatus::Noop)
+
+impl std::str::FromStr for RecordingStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "completed" => RecordingStatus::Completed,
+            _ => RecordingStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RecordingStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<RecordingStatus> for String {
+    fn from(v: RecordingStatus) -> Self {
+        match v {
+            RecordingStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4812,6 +5387,7 @@ pub struct RecordingSeparateAudio {
  * Determine how the meeting recording is shared.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ShareRecording {
     #[serde(rename = "internally")]
     Internally,
@@ -4819,33 +5395,57 @@ pub enum ShareRecording {
     None,
     #[serde(rename = "publicly")]
     Publicly,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ShareRecording {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ShareRecording {
+    fn as_ref(&self) -> &str {
+        match self {
             ShareRecording::Internally => "internally",
             ShareRecording::None => "none",
             ShareRecording::Publicly => "publicly",
-            ShareRecording::Noop => "",
-            ShareRecording::FallthroughString => "*",
+            ShareRecording::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ShareRecording {
-    fn default() -> ShareRecording {
-        ShareRecording::Noop
+impl std::fmt::Display for ShareRecording {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ShareRecording {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "internally" => ShareRecording::Internally,
+            "none" => ShareRecording::None,
+            "publicly" => ShareRecording::Publicly,
+            _ => ShareRecording::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ShareRecording {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ShareRecording {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ShareRecording::Noop)
+
+impl std::convert::From<ShareRecording> for String {
+    fn from(v: ShareRecording) -> Self {
+        match v {
+            ShareRecording::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -4918,8 +5518,8 @@ pub struct RecordingRegistrantStatus {
      *  `approve`: Allow users from specific regions/countries to join this meeting. If this setting is selected, the approved regions/countries must be included in the `approved_list`.<br><br>
      *  `deny`: Block users from specific regions/countries from joining this meeting. If this setting is selected, the approved regions/countries must be included in the `denied_list`
      */
-    #[serde(default, skip_serializing_if = "Method::is_noop")]
-    pub action: Method,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<Method>,
     /**
      * Registrant Status
      */
@@ -5093,6 +5693,7 @@ pub struct Registrant {
  * Registrant Status:<br>`approve` - Approve registrant.<br>`cancel` - Cancel previously approved registrant's registration.<br>`deny` - Deny registrant.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RegistrantStatusAction {
     #[serde(rename = "approve")]
     Approve,
@@ -5100,33 +5701,57 @@ pub enum RegistrantStatusAction {
     Cancel,
     #[serde(rename = "deny")]
     Deny,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for RegistrantStatusAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for RegistrantStatusAction {
+    fn as_ref(&self) -> &str {
+        match self {
             RegistrantStatusAction::Approve => "approve",
             RegistrantStatusAction::Cancel => "cancel",
             RegistrantStatusAction::Deny => "deny",
-            RegistrantStatusAction::Noop => "",
-            RegistrantStatusAction::FallthroughString => "*",
+            RegistrantStatusAction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for RegistrantStatusAction {
-    fn default() -> RegistrantStatusAction {
-        RegistrantStatusAction::Noop
+impl std::fmt::Display for RegistrantStatusAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl RegistrantStatusAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RegistrantStatusAction::Noop)
+
+impl std::str::FromStr for RegistrantStatusAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approve" => RegistrantStatusAction::Approve,
+            "cancel" => RegistrantStatusAction::Cancel,
+            "deny" => RegistrantStatusAction::Deny,
+            _ => RegistrantStatusAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RegistrantStatusAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<RegistrantStatusAction> for String {
+    fn from(v: RegistrantStatusAction) -> Self {
+        match v {
+            RegistrantStatusAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -5157,8 +5782,8 @@ pub struct RegistrantStatus {
     /**
      * Registrant Status:<br>`approve` - Approve registrant.<br>`cancel` - Cancel previously approved registrant's registration.<br>`deny` - Deny registrant.
      */
-    #[serde(default, skip_serializing_if = "RegistrantStatusAction::is_noop")]
-    pub action: RegistrantStatusAction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<RegistrantStatusAction>,
     /**
      * List of registrants.
      */
@@ -5571,37 +6196,61 @@ pub struct RecurrenceWebinar {
  *   *  `all`: Attendees are able to view all questions submitted in the Q&A.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AnswerQuestions {
     #[serde(rename = "all")]
     All,
     #[serde(rename = "only")]
     Only,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AnswerQuestions {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AnswerQuestions {
+    fn as_ref(&self) -> &str {
+        match self {
             AnswerQuestions::All => "all",
             AnswerQuestions::Only => "only",
-            AnswerQuestions::Noop => "",
-            AnswerQuestions::FallthroughString => "*",
+            AnswerQuestions::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AnswerQuestions {
-    fn default() -> AnswerQuestions {
-        AnswerQuestions::Noop
+impl std::fmt::Display for AnswerQuestions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AnswerQuestions {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => AnswerQuestions::All,
+            "only" => AnswerQuestions::Only,
+            _ => AnswerQuestions::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AnswerQuestions {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AnswerQuestions {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AnswerQuestions::Noop)
+
+impl std::convert::From<AnswerQuestions> for String {
+    fn from(v: AnswerQuestions) -> Self {
+        match v {
+            AnswerQuestions::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -6185,6 +6834,7 @@ pub struct SessionWebinarUpdate {
  *   `media_link` - Media link.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TspAccountsListDialInNumbersType {
     #[serde(rename = "media_link")]
     MediaLink,
@@ -6192,33 +6842,57 @@ pub enum TspAccountsListDialInNumbersType {
     Toll,
     #[serde(rename = "tollfree")]
     Tollfree,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for TspAccountsListDialInNumbersType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for TspAccountsListDialInNumbersType {
+    fn as_ref(&self) -> &str {
+        match self {
             TspAccountsListDialInNumbersType::MediaLink => "media_link",
             TspAccountsListDialInNumbersType::Toll => "toll",
             TspAccountsListDialInNumbersType::Tollfree => "tollfree",
-            TspAccountsListDialInNumbersType::Noop => "",
-            TspAccountsListDialInNumbersType::FallthroughString => "*",
+            TspAccountsListDialInNumbersType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for TspAccountsListDialInNumbersType {
-    fn default() -> TspAccountsListDialInNumbersType {
-        TspAccountsListDialInNumbersType::Noop
+impl std::fmt::Display for TspAccountsListDialInNumbersType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl TspAccountsListDialInNumbersType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, TspAccountsListDialInNumbersType::Noop)
+
+impl std::str::FromStr for TspAccountsListDialInNumbersType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "media_link" => TspAccountsListDialInNumbersType::MediaLink,
+            "toll" => TspAccountsListDialInNumbersType::Toll,
+            "tollfree" => TspAccountsListDialInNumbersType::Tollfree,
+            _ => TspAccountsListDialInNumbersType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for TspAccountsListDialInNumbersType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<TspAccountsListDialInNumbersType> for String {
+    fn from(v: TspAccountsListDialInNumbersType) -> Self {
+        match v {
+            TspAccountsListDialInNumbersType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -6263,37 +6937,61 @@ pub struct DialInNumbers {
  * Telephony bridge
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TspBridge {
     #[serde(rename = "EU_TSP_TB")]
     EuTspTb,
     #[serde(rename = "US_TSP_TB")]
     UsTspTb,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for TspBridge {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for TspBridge {
+    fn as_ref(&self) -> &str {
+        match self {
             TspBridge::EuTspTb => "EU_TSP_TB",
             TspBridge::UsTspTb => "US_TSP_TB",
-            TspBridge::Noop => "",
-            TspBridge::FallthroughString => "*",
+            TspBridge::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for TspBridge {
-    fn default() -> TspBridge {
-        TspBridge::Noop
+impl std::fmt::Display for TspBridge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for TspBridge {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "EU_TSP_TB" => TspBridge::EuTspTb,
+            "US_TSP_TB" => TspBridge::UsTspTb,
+            _ => TspBridge::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for TspBridge {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl TspBridge {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, TspBridge::Noop)
+
+impl std::convert::From<TspBridge> for String {
+    fn from(v: TspBridge) -> Self {
+        match v {
+            TspBridge::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -6560,6 +7258,7 @@ pub struct CustomAttributes {
  *   * `Fax`
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Label {
     #[serde(rename = "Fax")]
     Fax,
@@ -6569,34 +7268,59 @@ pub enum Label {
     Mobile,
     #[serde(rename = "Office")]
     Office,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Label {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Label {
+    fn as_ref(&self) -> &str {
+        match self {
             Label::Fax => "Fax",
             Label::Home => "Home",
             Label::Mobile => "Mobile",
             Label::Office => "Office",
-            Label::Noop => "",
-            Label::FallthroughString => "*",
+            Label::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Label {
-    fn default() -> Label {
-        Label::Noop
+impl std::fmt::Display for Label {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Label {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Label::Noop)
+
+impl std::str::FromStr for Label {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Fax" => Label::Fax,
+            "Home" => Label::Home,
+            "Mobile" => Label::Mobile,
+            "Office" => Label::Office,
+            _ => Label::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Label {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Label> for String {
+    fn from(v: Label) -> Self {
+        match v {
+            Label::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -8330,37 +9054,61 @@ pub struct ZoomRoom {
  * Poll Question & Answer type:<br>`single` - Single choice<br>`mutliple` - Multiple choice
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PollQuestionsType {
     #[serde(rename = "multiple")]
     Multiple,
     #[serde(rename = "single")]
     Single,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PollQuestionsType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PollQuestionsType {
+    fn as_ref(&self) -> &str {
+        match self {
             PollQuestionsType::Multiple => "multiple",
             PollQuestionsType::Single => "single",
-            PollQuestionsType::Noop => "",
-            PollQuestionsType::FallthroughString => "*",
+            PollQuestionsType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PollQuestionsType {
-    fn default() -> PollQuestionsType {
-        PollQuestionsType::Noop
+impl std::fmt::Display for PollQuestionsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PollQuestionsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "multiple" => PollQuestionsType::Multiple,
+            "single" => PollQuestionsType::Single,
+            _ => PollQuestionsType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PollQuestionsType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PollQuestionsType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PollQuestionsType::Noop)
+
+impl std::convert::From<PollQuestionsType> for String {
+    fn from(v: PollQuestionsType) -> Self {
+        match v {
+            PollQuestionsType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -8421,6 +9169,7 @@ pub struct Poll {
  * Field name of the question.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FieldName {
     #[serde(rename = "address")]
     Address,
@@ -8448,15 +9197,15 @@ pub enum FieldName {
     State,
     #[serde(rename = "zip")]
     Zip,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for FieldName {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for FieldName {
+    fn as_ref(&self) -> &str {
+        match self {
             FieldName::Address => "address",
             FieldName::City => "city",
             FieldName::Comments => "comments",
@@ -8470,21 +9219,55 @@ impl std::fmt::Display for FieldName {
             FieldName::RoleInPurchaseProcess => "role_in_purchase_process",
             FieldName::State => "state",
             FieldName::Zip => "zip",
-            FieldName::Noop => "",
-            FieldName::FallthroughString => "*",
+            FieldName::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for FieldName {
-    fn default() -> FieldName {
-        FieldName::Noop
+impl std::fmt::Display for FieldName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for FieldName {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "address" => FieldName::Address,
+            "city" => FieldName::City,
+            "comments" => FieldName::Comments,
+            "country" => FieldName::Country,
+            "industry" => FieldName::Industry,
+            "job_title" => FieldName::JobTitle,
+            "no_of_employees" => FieldName::NoOfEmployees,
+            "org" => FieldName::Org,
+            "phone" => FieldName::Phone,
+            "purchasing_time_frame" => FieldName::PurchasingTimeFrame,
+            "role_in_purchase_process" => FieldName::RoleInPurchaseProcess,
+            "state" => FieldName::State,
+            "zip" => FieldName::Zip,
+            _ => FieldName::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for FieldName {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl FieldName {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FieldName::Noop)
+
+impl std::convert::From<FieldName> for String {
+    fn from(v: FieldName) -> Self {
+        match v {
+            FieldName::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -8509,37 +9292,61 @@ pub struct MeetingRegistrantQuestions {
  * Type of the question being asked.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MeetingRegistrantQuestionsCustomType {
     #[serde(rename = "short")]
     Short,
     #[serde(rename = "single")]
     Single,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for MeetingRegistrantQuestionsCustomType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for MeetingRegistrantQuestionsCustomType {
+    fn as_ref(&self) -> &str {
+        match self {
             MeetingRegistrantQuestionsCustomType::Short => "short",
             MeetingRegistrantQuestionsCustomType::Single => "single",
-            MeetingRegistrantQuestionsCustomType::Noop => "",
-            MeetingRegistrantQuestionsCustomType::FallthroughString => "*",
+            MeetingRegistrantQuestionsCustomType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for MeetingRegistrantQuestionsCustomType {
-    fn default() -> MeetingRegistrantQuestionsCustomType {
-        MeetingRegistrantQuestionsCustomType::Noop
+impl std::fmt::Display for MeetingRegistrantQuestionsCustomType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for MeetingRegistrantQuestionsCustomType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "short" => MeetingRegistrantQuestionsCustomType::Short,
+            "single" => MeetingRegistrantQuestionsCustomType::Single,
+            _ => MeetingRegistrantQuestionsCustomType::Unknown(s.to_string()),
+        })
     }
 }
-impl MeetingRegistrantQuestionsCustomType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MeetingRegistrantQuestionsCustomType::Noop)
+
+impl std::convert::From<String> for MeetingRegistrantQuestionsCustomType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<MeetingRegistrantQuestionsCustomType> for String {
+    fn from(v: MeetingRegistrantQuestionsCustomType) -> Self {
+        match v {
+            MeetingRegistrantQuestionsCustomType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -8631,6 +9438,7 @@ pub struct WebinarRegistrantQuestions {
  * Field name.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingRegistrantQuestionsFieldName {
     #[serde(rename = "address")]
     Address,
@@ -8660,15 +9468,15 @@ pub enum RecordingRegistrantQuestionsFieldName {
     State,
     #[serde(rename = "zip")]
     Zip,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for RecordingRegistrantQuestionsFieldName {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for RecordingRegistrantQuestionsFieldName {
+    fn as_ref(&self) -> &str {
+        match self {
             RecordingRegistrantQuestionsFieldName::Address => "address",
             RecordingRegistrantQuestionsFieldName::City => "city",
             RecordingRegistrantQuestionsFieldName::Comments => "comments",
@@ -8685,21 +9493,58 @@ impl std::fmt::Display for RecordingRegistrantQuestionsFieldName {
             }
             RecordingRegistrantQuestionsFieldName::State => "state",
             RecordingRegistrantQuestionsFieldName::Zip => "zip",
-            RecordingRegistrantQuestionsFieldName::Noop => "",
-            RecordingRegistrantQuestionsFieldName::FallthroughString => "*",
+            RecordingRegistrantQuestionsFieldName::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for RecordingRegistrantQuestionsFieldName {
-    fn default() -> RecordingRegistrantQuestionsFieldName {
-        RecordingRegistrantQuestionsFieldName::Noop
+impl std::fmt::Display for RecordingRegistrantQuestionsFieldName {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for RecordingRegistrantQuestionsFieldName {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "address" => RecordingRegistrantQuestionsFieldName::Address,
+            "city" => RecordingRegistrantQuestionsFieldName::City,
+            "comments" => RecordingRegistrantQuestionsFieldName::Comments,
+            "country" => RecordingRegistrantQuestionsFieldName::Country,
+            "industry" => RecordingRegistrantQuestionsFieldName::Industry,
+            "job_title" => RecordingRegistrantQuestionsFieldName::JobTitle,
+            "last_name" => RecordingRegistrantQuestionsFieldName::LastName,
+            "no_of_employees" => RecordingRegistrantQuestionsFieldName::NoOfEmployees,
+            "org" => RecordingRegistrantQuestionsFieldName::Org,
+            "phone" => RecordingRegistrantQuestionsFieldName::Phone,
+            "purchasing_time_frame" => RecordingRegistrantQuestionsFieldName::PurchasingTimeFrame,
+            "role_in_purchase_process" => {
+                RecordingRegistrantQuestionsFieldName::RoleInPurchaseProcess
+            }
+            "state" => RecordingRegistrantQuestionsFieldName::State,
+            "zip" => RecordingRegistrantQuestionsFieldName::Zip,
+            _ => RecordingRegistrantQuestionsFieldName::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RecordingRegistrantQuestionsFieldName {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl RecordingRegistrantQuestionsFieldName {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RecordingRegistrantQuestionsFieldName::Noop)
+
+impl std::convert::From<RecordingRegistrantQuestionsFieldName> for String {
+    fn from(v: RecordingRegistrantQuestionsFieldName) -> Self {
+        match v {
+            RecordingRegistrantQuestionsFieldName::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -8724,6 +9569,7 @@ pub struct RecordingRegistrantQuestions {
  * The type of registration question and answers.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingRegistrantQuestionsCustomType {
     #[serde(rename = "multiple")]
     Multiple,
@@ -8731,33 +9577,57 @@ pub enum RecordingRegistrantQuestionsCustomType {
     Short,
     #[serde(rename = "single")]
     Single,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for RecordingRegistrantQuestionsCustomType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for RecordingRegistrantQuestionsCustomType {
+    fn as_ref(&self) -> &str {
+        match self {
             RecordingRegistrantQuestionsCustomType::Multiple => "multiple",
             RecordingRegistrantQuestionsCustomType::Short => "short",
             RecordingRegistrantQuestionsCustomType::Single => "single",
-            RecordingRegistrantQuestionsCustomType::Noop => "",
-            RecordingRegistrantQuestionsCustomType::FallthroughString => "*",
+            RecordingRegistrantQuestionsCustomType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for RecordingRegistrantQuestionsCustomType {
-    fn default() -> RecordingRegistrantQuestionsCustomType {
-        RecordingRegistrantQuestionsCustomType::Noop
+impl std::fmt::Display for RecordingRegistrantQuestionsCustomType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl RecordingRegistrantQuestionsCustomType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RecordingRegistrantQuestionsCustomType::Noop)
+
+impl std::str::FromStr for RecordingRegistrantQuestionsCustomType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "multiple" => RecordingRegistrantQuestionsCustomType::Multiple,
+            "short" => RecordingRegistrantQuestionsCustomType::Short,
+            "single" => RecordingRegistrantQuestionsCustomType::Single,
+            _ => RecordingRegistrantQuestionsCustomType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RecordingRegistrantQuestionsCustomType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<RecordingRegistrantQuestionsCustomType> for String {
+    fn from(v: RecordingRegistrantQuestionsCustomType) -> Self {
+        match v {
+            RecordingRegistrantQuestionsCustomType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -10242,6 +11112,7 @@ pub struct ParticipantQos {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AccountSettingsRecordingAuthenticationUpdateOptionType {
     #[serde(rename = "enforce_login")]
     EnforceLogin,
@@ -10249,42 +11120,66 @@ pub enum AccountSettingsRecordingAuthenticationUpdateOptionType {
     EnforceLoginWithDomains,
     #[serde(rename = "internally")]
     Internally,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AccountSettingsRecordingAuthenticationUpdateOptionType {
+    fn as_ref(&self) -> &str {
+        match self {
             AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLogin => "enforce_login",
             AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLoginWithDomains => {
                 "enforce_login_with_domains"
             }
             AccountSettingsRecordingAuthenticationUpdateOptionType::Internally => "internally",
-            AccountSettingsRecordingAuthenticationUpdateOptionType::Noop => "",
-            AccountSettingsRecordingAuthenticationUpdateOptionType::FallthroughString => "*",
+            AccountSettingsRecordingAuthenticationUpdateOptionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AccountSettingsRecordingAuthenticationUpdateOptionType {
-    fn default() -> AccountSettingsRecordingAuthenticationUpdateOptionType {
-        AccountSettingsRecordingAuthenticationUpdateOptionType::Noop
+impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AccountSettingsRecordingAuthenticationUpdateOptionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "enforce_login" => AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLogin,
+            "enforce_login_with_domains" => {
+                AccountSettingsRecordingAuthenticationUpdateOptionType::EnforceLoginWithDomains
+            }
+            "internally" => AccountSettingsRecordingAuthenticationUpdateOptionType::Internally,
+            _ => AccountSettingsRecordingAuthenticationUpdateOptionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AccountSettingsRecordingAuthenticationUpdateOptionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AccountSettingsRecordingAuthenticationUpdateOptionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(
-            self,
-            AccountSettingsRecordingAuthenticationUpdateOptionType::Noop
-        )
+
+impl std::convert::From<AccountSettingsRecordingAuthenticationUpdateOptionType> for String {
+    fn from(v: AccountSettingsRecordingAuthenticationUpdateOptionType) -> Self {
+        match v {
+            AccountSettingsRecordingAuthenticationUpdateOptionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AccountSettingsRecordingAuthenticationUpdateOptionAction {
     #[serde(rename = "add")]
     Add,
@@ -10292,36 +11187,57 @@ pub enum AccountSettingsRecordingAuthenticationUpdateOptionAction {
     Delete,
     #[serde(rename = "update")]
     Update,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AccountSettingsRecordingAuthenticationUpdateOptionAction {
+    fn as_ref(&self) -> &str {
+        match self {
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Add => "add",
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Delete => "delete",
             AccountSettingsRecordingAuthenticationUpdateOptionAction::Update => "update",
-            AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop => "",
-            AccountSettingsRecordingAuthenticationUpdateOptionAction::FallthroughString => "*",
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AccountSettingsRecordingAuthenticationUpdateOptionAction {
-    fn default() -> AccountSettingsRecordingAuthenticationUpdateOptionAction {
-        AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop
+impl std::fmt::Display for AccountSettingsRecordingAuthenticationUpdateOptionAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl AccountSettingsRecordingAuthenticationUpdateOptionAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(
-            self,
-            AccountSettingsRecordingAuthenticationUpdateOptionAction::Noop
-        )
+
+impl std::str::FromStr for AccountSettingsRecordingAuthenticationUpdateOptionAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "add" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Add,
+            "delete" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Delete,
+            "update" => AccountSettingsRecordingAuthenticationUpdateOptionAction::Update,
+            _ => AccountSettingsRecordingAuthenticationUpdateOptionAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AccountSettingsRecordingAuthenticationUpdateOptionAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<AccountSettingsRecordingAuthenticationUpdateOptionAction> for String {
+    fn from(v: AccountSettingsRecordingAuthenticationUpdateOptionAction) -> Self {
+        match v {
+            AccountSettingsRecordingAuthenticationUpdateOptionAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -10878,6 +11794,7 @@ pub struct Metrics {
  *   For more information, read our [Managing and sharing cloud recordings](https://support.zoom.us/hc/en-us/articles/205347605-Managing-and-sharing-cloud-recordings#h_9898497b-e736-4980-a749-d55608f10773) documentation.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum FileType {
     #[serde(rename = "CC")]
     Cc,
@@ -10891,36 +11808,63 @@ pub enum FileType {
     Mp4,
     #[serde(rename = "TRANSCRIPT")]
     Transcript,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for FileType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for FileType {
+    fn as_ref(&self) -> &str {
+        match self {
             FileType::Cc => "CC",
             FileType::Chat => "CHAT",
             FileType::Csv => "CSV",
             FileType::M4A => "M4A",
             FileType::Mp4 => "MP4",
             FileType::Transcript => "TRANSCRIPT",
-            FileType::Noop => "",
-            FileType::FallthroughString => "*",
+            FileType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for FileType {
-    fn default() -> FileType {
-        FileType::Noop
+impl std::fmt::Display for FileType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for FileType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CC" => FileType::Cc,
+            "CHAT" => FileType::Chat,
+            "CSV" => FileType::Csv,
+            "M4A" => FileType::M4A,
+            "MP4" => FileType::Mp4,
+            "TRANSCRIPT" => FileType::Transcript,
+            _ => FileType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for FileType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl FileType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, FileType::Noop)
+
+impl std::convert::From<FileType> for String {
+    fn from(v: FileType) -> Self {
+        match v {
+            FileType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -10941,6 +11885,7 @@ impl FileType {
  *   For more information, read our [Managing and sharing cloud recordings](https://support.zoom.us/hc/en-us/articles/205347605-Managing-and-sharing-cloud-recordings#h_9898497b-e736-4980-a749-d55608f10773) documentation.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingType {
     #[serde(rename = "active_speaker")]
     ActiveSpeaker,
@@ -10964,15 +11909,15 @@ pub enum RecordingType {
     SharedScreenWithSpeakerViewCc,
     #[serde(rename = "speaker_view")]
     SpeakerView,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for RecordingType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for RecordingType {
+    fn as_ref(&self) -> &str {
+        match self {
             RecordingType::ActiveSpeaker => "active_speaker",
             RecordingType::AudioOnly => "audio_only",
             RecordingType::AudioTranscript => "audio_transcript",
@@ -10984,21 +11929,53 @@ impl std::fmt::Display for RecordingType {
             RecordingType::SharedScreenWithSpeakerView => "shared_screen_with_speaker_view",
             RecordingType::SharedScreenWithSpeakerViewCc => "shared_screen_with_speaker_view(CC)",
             RecordingType::SpeakerView => "speaker_view",
-            RecordingType::Noop => "",
-            RecordingType::FallthroughString => "*",
+            RecordingType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for RecordingType {
-    fn default() -> RecordingType {
-        RecordingType::Noop
+impl std::fmt::Display for RecordingType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl RecordingType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RecordingType::Noop)
+
+impl std::str::FromStr for RecordingType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active_speaker" => RecordingType::ActiveSpeaker,
+            "audio_only" => RecordingType::AudioOnly,
+            "audio_transcript" => RecordingType::AudioTranscript,
+            "chat_file" => RecordingType::ChatFile,
+            "gallery_view" => RecordingType::GalleryView,
+            "host_video" => RecordingType::HostVideo,
+            "shared_screen" => RecordingType::SharedScreen,
+            "shared_screen_with_gallery_view" => RecordingType::SharedScreenWithGalleryView,
+            "shared_screen_with_speaker_view" => RecordingType::SharedScreenWithSpeakerView,
+            "shared_screen_with_speaker_view(CC)" => RecordingType::SharedScreenWithSpeakerViewCc,
+            "speaker_view" => RecordingType::SpeakerView,
+            _ => RecordingType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RecordingType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<RecordingType> for String {
+    fn from(v: RecordingType) -> Self {
+        match v {
+            RecordingType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -11042,8 +12019,8 @@ pub struct ArchiveFiles {
      *  
      *  For more information, read our [Managing and sharing cloud recordings](https://support.zoom.us/hc/en-us/articles/205347605-Managing-and-sharing-cloud-recordings#h_9898497b-e736-4980-a749-d55608f10773) documentation.
      */
-    #[serde(default, skip_serializing_if = "FileType::is_noop")]
-    pub file_type: FileType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_type: Option<FileType>,
     /**
      * User's first name.
      */
@@ -11069,8 +12046,8 @@ pub struct ArchiveFiles {
      *  
      *  For more information, read our [Managing and sharing cloud recordings](https://support.zoom.us/hc/en-us/articles/205347605-Managing-and-sharing-cloud-recordings#h_9898497b-e736-4980-a749-d55608f10773) documentation.
      */
-    #[serde(default, skip_serializing_if = "RecordingType::is_noop")]
-    pub recording_type: RecordingType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recording_type: Option<RecordingType>,
     /**
      * User's first name.
      */
@@ -11259,34 +12236,57 @@ pub struct InviteLinks {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingStatusUpdateBodyRequestAction {
     #[serde(rename = "recover")]
     Recover,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for RecordingStatusUpdateBodyRequestAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for RecordingStatusUpdateBodyRequestAction {
+    fn as_ref(&self) -> &str {
+        match self {
             RecordingStatusUpdateBodyRequestAction::Recover => "recover",
-            RecordingStatusUpdateBodyRequestAction::Noop => "",
-            RecordingStatusUpdateBodyRequestAction::FallthroughString => "*",
+            RecordingStatusUpdateBodyRequestAction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for RecordingStatusUpdateBodyRequestAction {
-    fn default() -> RecordingStatusUpdateBodyRequestAction {
-        RecordingStatusUpdateBodyRequestAction::Noop
+impl std::fmt::Display for RecordingStatusUpdateBodyRequestAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl RecordingStatusUpdateBodyRequestAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RecordingStatusUpdateBodyRequestAction::Noop)
+
+impl std::str::FromStr for RecordingStatusUpdateBodyRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "recover" => RecordingStatusUpdateBodyRequestAction::Recover,
+            _ => RecordingStatusUpdateBodyRequestAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RecordingStatusUpdateBodyRequestAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<RecordingStatusUpdateBodyRequestAction> for String {
+    fn from(v: RecordingStatusUpdateBodyRequestAction) -> Self {
+        match v {
+            RecordingStatusUpdateBodyRequestAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -11396,6 +12396,7 @@ pub struct SetUpAccountRequest {
  *   `byoc`: Include Bring Your Own Carrier (BYOC) numbers only in the response.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListAccountPhoneNumbersType {
     #[serde(rename = "all")]
     All,
@@ -11403,33 +12404,57 @@ pub enum ListAccountPhoneNumbersType {
     Assigned,
     #[serde(rename = "unassigned")]
     Unassigned,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ListAccountPhoneNumbersType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ListAccountPhoneNumbersType {
+    fn as_ref(&self) -> &str {
+        match self {
             ListAccountPhoneNumbersType::All => "all",
             ListAccountPhoneNumbersType::Assigned => "assigned",
             ListAccountPhoneNumbersType::Unassigned => "unassigned",
-            ListAccountPhoneNumbersType::Noop => "",
-            ListAccountPhoneNumbersType::FallthroughString => "*",
+            ListAccountPhoneNumbersType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ListAccountPhoneNumbersType {
-    fn default() -> ListAccountPhoneNumbersType {
-        ListAccountPhoneNumbersType::Noop
+impl std::fmt::Display for ListAccountPhoneNumbersType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl ListAccountPhoneNumbersType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ListAccountPhoneNumbersType::Noop)
+
+impl std::str::FromStr for ListAccountPhoneNumbersType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => ListAccountPhoneNumbersType::All,
+            "assigned" => ListAccountPhoneNumbersType::Assigned,
+            "unassigned" => ListAccountPhoneNumbersType::Unassigned,
+            _ => ListAccountPhoneNumbersType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ListAccountPhoneNumbersType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ListAccountPhoneNumbersType> for String {
+    fn from(v: ListAccountPhoneNumbersType) -> Self {
+        match v {
+            ListAccountPhoneNumbersType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -11439,6 +12464,7 @@ impl ListAccountPhoneNumbersType {
  *   `commonAreaPhone`
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ExtensionType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -11448,34 +12474,59 @@ pub enum ExtensionType {
     CommonAreaPhone,
     #[serde(rename = "user")]
     User,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ExtensionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ExtensionType {
+    fn as_ref(&self) -> &str {
+        match self {
             ExtensionType::AutoReceptionist => "autoReceptionist",
             ExtensionType::CallQueue => "callQueue",
             ExtensionType::CommonAreaPhone => "commonAreaPhone",
             ExtensionType::User => "user",
-            ExtensionType::Noop => "",
-            ExtensionType::FallthroughString => "*",
+            ExtensionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ExtensionType {
-    fn default() -> ExtensionType {
-        ExtensionType::Noop
+impl std::fmt::Display for ExtensionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ExtensionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "autoReceptionist" => ExtensionType::AutoReceptionist,
+            "callQueue" => ExtensionType::CallQueue,
+            "commonAreaPhone" => ExtensionType::CommonAreaPhone,
+            "user" => ExtensionType::User,
+            _ => ExtensionType::Unknown(s.to_string()),
+        })
     }
 }
-impl ExtensionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ExtensionType::Noop)
+
+impl std::convert::From<String> for ExtensionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ExtensionType> for String {
+    fn from(v: ExtensionType) -> Self {
+        match v {
+            ExtensionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -11483,37 +12534,61 @@ impl ExtensionType {
  * Source of phone number.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Source {
     #[serde(rename = "external")]
     External,
     #[serde(rename = "internal")]
     Internal,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Source {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Source {
+    fn as_ref(&self) -> &str {
+        match self {
             Source::External => "external",
             Source::Internal => "internal",
-            Source::Noop => "",
-            Source::FallthroughString => "*",
+            Source::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Source {
-    fn default() -> Source {
-        Source::Noop
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Source {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Source::Noop)
+
+impl std::str::FromStr for Source {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "external" => Source::External,
+            "internal" => Source::Internal,
+            _ => Source::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Source {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Source> for String {
+    fn from(v: Source) -> Self {
+        match v {
+            Source::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -11521,37 +12596,61 @@ impl Source {
  * Status of the number.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListAccountPhoneNumbersResponseStatus {
     #[serde(rename = "available")]
     Available,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ListAccountPhoneNumbersResponseStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ListAccountPhoneNumbersResponseStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             ListAccountPhoneNumbersResponseStatus::Available => "available",
             ListAccountPhoneNumbersResponseStatus::Pending => "pending",
-            ListAccountPhoneNumbersResponseStatus::Noop => "",
-            ListAccountPhoneNumbersResponseStatus::FallthroughString => "*",
+            ListAccountPhoneNumbersResponseStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ListAccountPhoneNumbersResponseStatus {
-    fn default() -> ListAccountPhoneNumbersResponseStatus {
-        ListAccountPhoneNumbersResponseStatus::Noop
+impl std::fmt::Display for ListAccountPhoneNumbersResponseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ListAccountPhoneNumbersResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "available" => ListAccountPhoneNumbersResponseStatus::Available,
+            "pending" => ListAccountPhoneNumbersResponseStatus::Pending,
+            _ => ListAccountPhoneNumbersResponseStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ListAccountPhoneNumbersResponseStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ListAccountPhoneNumbersResponseStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ListAccountPhoneNumbersResponseStatus::Noop)
+
+impl std::convert::From<ListAccountPhoneNumbersResponseStatus> for String {
+    fn from(v: ListAccountPhoneNumbersResponseStatus) -> Self {
+        match v {
+            ListAccountPhoneNumbersResponseStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -11783,6 +12882,7 @@ pub struct ListAccountPhoneNumbersResponseData {
  * Protocols supported by the SIP provider.<br> The value must be either `UDP`, `TCP`, `TLS`, `AUTO`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TransportProtocol {
     #[serde(rename = "AUTO")]
     Auto,
@@ -11792,34 +12892,59 @@ pub enum TransportProtocol {
     Tls,
     #[serde(rename = "UDP")]
     Udp,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for TransportProtocol {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for TransportProtocol {
+    fn as_ref(&self) -> &str {
+        match self {
             TransportProtocol::Auto => "AUTO",
             TransportProtocol::Tcp => "TCP",
             TransportProtocol::Tls => "TLS",
             TransportProtocol::Udp => "UDP",
-            TransportProtocol::Noop => "",
-            TransportProtocol::FallthroughString => "*",
+            TransportProtocol::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for TransportProtocol {
-    fn default() -> TransportProtocol {
-        TransportProtocol::Noop
+impl std::fmt::Display for TransportProtocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl TransportProtocol {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, TransportProtocol::Noop)
+
+impl std::str::FromStr for TransportProtocol {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "AUTO" => TransportProtocol::Auto,
+            "TCP" => TransportProtocol::Tcp,
+            "TLS" => TransportProtocol::Tls,
+            "UDP" => TransportProtocol::Udp,
+            _ => TransportProtocol::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for TransportProtocol {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<TransportProtocol> for String {
+    fn from(v: TransportProtocol) -> Self {
+        match v {
+            TransportProtocol::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -12329,6 +13454,7 @@ pub struct UpdateSipPhoneRequest {
  * The status of the Zoom Room.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListZoomRoomsStatus {
     #[serde(rename = "Available")]
     Available,
@@ -12338,34 +13464,59 @@ pub enum ListZoomRoomsStatus {
     Offline,
     #[serde(rename = "UnderConstruction")]
     UnderConstruction,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ListZoomRoomsStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ListZoomRoomsStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             ListZoomRoomsStatus::Available => "Available",
             ListZoomRoomsStatus::InMeeting => "InMeeting",
             ListZoomRoomsStatus::Offline => "Offline",
             ListZoomRoomsStatus::UnderConstruction => "UnderConstruction",
-            ListZoomRoomsStatus::Noop => "",
-            ListZoomRoomsStatus::FallthroughString => "*",
+            ListZoomRoomsStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ListZoomRoomsStatus {
-    fn default() -> ListZoomRoomsStatus {
-        ListZoomRoomsStatus::Noop
+impl std::fmt::Display for ListZoomRoomsStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ListZoomRoomsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Available" => ListZoomRoomsStatus::Available,
+            "InMeeting" => ListZoomRoomsStatus::InMeeting,
+            "Offline" => ListZoomRoomsStatus::Offline,
+            "UnderConstruction" => ListZoomRoomsStatus::UnderConstruction,
+            _ => ListZoomRoomsStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ListZoomRoomsStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ListZoomRoomsStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ListZoomRoomsStatus::Noop)
+
+impl std::convert::From<ListZoomRoomsStatus> for String {
+    fn from(v: ListZoomRoomsStatus) -> Self {
+        match v {
+            ListZoomRoomsStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -12373,6 +13524,7 @@ impl ListZoomRoomsStatus {
  * Type of the Zoom Rooms.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListZoomRoomsType {
     #[serde(rename = "DigitalSignageOnly")]
     DigitalSignageOnly,
@@ -12380,33 +13532,57 @@ pub enum ListZoomRoomsType {
     SchedulingDisplayOnly,
     #[serde(rename = "ZoomRoom")]
     ZoomRoom,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ListZoomRoomsType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ListZoomRoomsType {
+    fn as_ref(&self) -> &str {
+        match self {
             ListZoomRoomsType::DigitalSignageOnly => "DigitalSignageOnly",
             ListZoomRoomsType::SchedulingDisplayOnly => "SchedulingDisplayOnly",
             ListZoomRoomsType::ZoomRoom => "ZoomRoom",
-            ListZoomRoomsType::Noop => "",
-            ListZoomRoomsType::FallthroughString => "*",
+            ListZoomRoomsType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ListZoomRoomsType {
-    fn default() -> ListZoomRoomsType {
-        ListZoomRoomsType::Noop
+impl std::fmt::Display for ListZoomRoomsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl ListZoomRoomsType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ListZoomRoomsType::Noop)
+
+impl std::str::FromStr for ListZoomRoomsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "DigitalSignageOnly" => ListZoomRoomsType::DigitalSignageOnly,
+            "SchedulingDisplayOnly" => ListZoomRoomsType::SchedulingDisplayOnly,
+            "ZoomRoom" => ListZoomRoomsType::ZoomRoom,
+            _ => ListZoomRoomsType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ListZoomRoomsType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ListZoomRoomsType> for String {
+    fn from(v: ListZoomRoomsType) -> Self {
+        match v {
+            ListZoomRoomsType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -12518,12 +13694,8 @@ pub struct AddRoomRequest {
     /**
      * Type of the Zoom Rooms.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "ListZoomRoomsType::is_noop",
-        rename = "type"
-    )]
-    pub type_: ListZoomRoomsType,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<ListZoomRoomsType>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -12942,6 +14114,7 @@ pub struct UpdateSiteDetailsRequest {
  *   `Do_Not_Disturb`<br> `away`<br> `Available`<br> `Offline`
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PresenceStatus {
     #[serde(rename = "Available")]
     Available,
@@ -12951,34 +14124,59 @@ pub enum PresenceStatus {
     DoNotDisturb,
     #[serde(rename = "Offline")]
     Offline,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PresenceStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PresenceStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             PresenceStatus::Available => "Available",
             PresenceStatus::Away => "Away",
             PresenceStatus::DoNotDisturb => "Do_Not_Disturb",
             PresenceStatus::Offline => "Offline",
-            PresenceStatus::Noop => "",
-            PresenceStatus::FallthroughString => "*",
+            PresenceStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PresenceStatus {
-    fn default() -> PresenceStatus {
-        PresenceStatus::Noop
+impl std::fmt::Display for PresenceStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PresenceStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Available" => PresenceStatus::Available,
+            "Away" => PresenceStatus::Away,
+            "Do_Not_Disturb" => PresenceStatus::DoNotDisturb,
+            "Offline" => PresenceStatus::Offline,
+            _ => PresenceStatus::Unknown(s.to_string()),
+        })
     }
 }
-impl PresenceStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PresenceStatus::Noop)
+
+impl std::convert::From<String> for PresenceStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PresenceStatus> for String {
+    fn from(v: PresenceStatus) -> Self {
+        match v {
+            PresenceStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -13146,6 +14344,7 @@ pub struct SearchCompanyContactsResponse {
  *   This field is only returned if you set the value of `for include_deleted_and_edited_message` query parameter to `true`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetChatMessagesResponseStatus {
     #[serde(rename = "Deleted")]
     Deleted,
@@ -13153,33 +14352,57 @@ pub enum GetChatMessagesResponseStatus {
     Edited,
     #[serde(rename = "Normal")]
     Normal,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetChatMessagesResponseStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetChatMessagesResponseStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             GetChatMessagesResponseStatus::Deleted => "Deleted",
             GetChatMessagesResponseStatus::Edited => "Edited",
             GetChatMessagesResponseStatus::Normal => "Normal",
-            GetChatMessagesResponseStatus::Noop => "",
-            GetChatMessagesResponseStatus::FallthroughString => "*",
+            GetChatMessagesResponseStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetChatMessagesResponseStatus {
-    fn default() -> GetChatMessagesResponseStatus {
-        GetChatMessagesResponseStatus::Noop
+impl std::fmt::Display for GetChatMessagesResponseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl GetChatMessagesResponseStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetChatMessagesResponseStatus::Noop)
+
+impl std::str::FromStr for GetChatMessagesResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Deleted" => GetChatMessagesResponseStatus::Deleted,
+            "Edited" => GetChatMessagesResponseStatus::Edited,
+            "Normal" => GetChatMessagesResponseStatus::Normal,
+            _ => GetChatMessagesResponseStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetChatMessagesResponseStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetChatMessagesResponseStatus> for String {
+    fn from(v: GetChatMessagesResponseStatus) -> Self {
+        match v {
+            GetChatMessagesResponseStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -13462,37 +14685,61 @@ pub struct MarkMessageRequest {
  *   * `remove` — Remove an emoji reaction.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReactMessageRequestAction {
     #[serde(rename = "add")]
     Add,
     #[serde(rename = "remove")]
     Remove,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ReactMessageRequestAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ReactMessageRequestAction {
+    fn as_ref(&self) -> &str {
+        match self {
             ReactMessageRequestAction::Add => "add",
             ReactMessageRequestAction::Remove => "remove",
-            ReactMessageRequestAction::Noop => "",
-            ReactMessageRequestAction::FallthroughString => "*",
+            ReactMessageRequestAction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ReactMessageRequestAction {
-    fn default() -> ReactMessageRequestAction {
-        ReactMessageRequestAction::Noop
+impl std::fmt::Display for ReactMessageRequestAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReactMessageRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "add" => ReactMessageRequestAction::Add,
+            "remove" => ReactMessageRequestAction::Remove,
+            _ => ReactMessageRequestAction::Unknown(s.to_string()),
+        })
     }
 }
-impl ReactMessageRequestAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ReactMessageRequestAction::Noop)
+
+impl std::convert::From<String> for ReactMessageRequestAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReactMessageRequestAction> for String {
+    fn from(v: ReactMessageRequestAction) -> Self {
+        match v {
+            ReactMessageRequestAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -14016,6 +15263,7 @@ pub struct GetUserContactResponse {
  * The role of the member. The value can be one of these: `owner`, `admin`, `member`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Role {
     #[serde(rename = "admin")]
     Admin,
@@ -14023,33 +15271,57 @@ pub enum Role {
     Member,
     #[serde(rename = "owner")]
     Owner,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Role {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Role {
+    fn as_ref(&self) -> &str {
+        match self {
             Role::Admin => "admin",
             Role::Member => "member",
             Role::Owner => "owner",
-            Role::Noop => "",
-            Role::FallthroughString => "*",
+            Role::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Role {
-    fn default() -> Role {
-        Role::Noop
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Role {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Role::Noop)
+
+impl std::str::FromStr for Role {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "admin" => Role::Admin,
+            "member" => Role::Member,
+            "owner" => Role::Owner,
+            _ => Role::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Role {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Role> for String {
+    fn from(v: Role) -> Self {
+        match v {
+            Role::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -14364,37 +15636,61 @@ pub struct AccountResponse {
  *   * `meeting_security` — View the account's meeting security settings.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum OptionData {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
     #[serde(rename = "recording_authentication")]
     RecordingAuthentication,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for OptionData {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for OptionData {
+    fn as_ref(&self) -> &str {
+        match self {
             OptionData::MeetingAuthentication => "meeting_authentication",
             OptionData::RecordingAuthentication => "recording_authentication",
-            OptionData::Noop => "",
-            OptionData::FallthroughString => "*",
+            OptionData::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for OptionData {
-    fn default() -> OptionData {
-        OptionData::Noop
+impl std::fmt::Display for OptionData {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for OptionData {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "meeting_authentication" => OptionData::MeetingAuthentication,
+            "recording_authentication" => OptionData::RecordingAuthentication,
+            _ => OptionData::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for OptionData {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl OptionData {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, OptionData::Noop)
+
+impl std::convert::From<OptionData> for String {
+    fn from(v: OptionData) -> Self {
+        match v {
+            OptionData::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -14487,6 +15783,7 @@ impl AccountSettingsUpdateRequestOneOf {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AccountSettingsUpdateOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -14496,34 +15793,59 @@ pub enum AccountSettingsUpdateOption {
     RecordingAuthentication,
     #[serde(rename = "security")]
     Security,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AccountSettingsUpdateOption {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AccountSettingsUpdateOption {
+    fn as_ref(&self) -> &str {
+        match self {
             AccountSettingsUpdateOption::MeetingAuthentication => "meeting_authentication",
             AccountSettingsUpdateOption::MeetingSecurity => "meeting_security",
             AccountSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
             AccountSettingsUpdateOption::Security => "security",
-            AccountSettingsUpdateOption::Noop => "",
-            AccountSettingsUpdateOption::FallthroughString => "*",
+            AccountSettingsUpdateOption::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AccountSettingsUpdateOption {
-    fn default() -> AccountSettingsUpdateOption {
-        AccountSettingsUpdateOption::Noop
+impl std::fmt::Display for AccountSettingsUpdateOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl AccountSettingsUpdateOption {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AccountSettingsUpdateOption::Noop)
+
+impl std::str::FromStr for AccountSettingsUpdateOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "meeting_authentication" => AccountSettingsUpdateOption::MeetingAuthentication,
+            "meeting_security" => AccountSettingsUpdateOption::MeetingSecurity,
+            "recording_authentication" => AccountSettingsUpdateOption::RecordingAuthentication,
+            "security" => AccountSettingsUpdateOption::Security,
+            _ => AccountSettingsUpdateOption::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AccountSettingsUpdateOption {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<AccountSettingsUpdateOption> for String {
+    fn from(v: AccountSettingsUpdateOption) -> Self {
+        match v {
+            AccountSettingsUpdateOption::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -14547,6 +15869,7 @@ pub struct AccountTrustedDomainResponse {
  * Status of the plan.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AccountPlansResponsePlanBaseStatus {
     #[serde(rename = "active")]
     Active,
@@ -14554,33 +15877,57 @@ pub enum AccountPlansResponsePlanBaseStatus {
     Cancel,
     #[serde(rename = "expired")]
     Expired,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AccountPlansResponsePlanBaseStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AccountPlansResponsePlanBaseStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             AccountPlansResponsePlanBaseStatus::Active => "active",
             AccountPlansResponsePlanBaseStatus::Cancel => "cancel",
             AccountPlansResponsePlanBaseStatus::Expired => "expired",
-            AccountPlansResponsePlanBaseStatus::Noop => "",
-            AccountPlansResponsePlanBaseStatus::FallthroughString => "*",
+            AccountPlansResponsePlanBaseStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AccountPlansResponsePlanBaseStatus {
-    fn default() -> AccountPlansResponsePlanBaseStatus {
-        AccountPlansResponsePlanBaseStatus::Noop
+impl std::fmt::Display for AccountPlansResponsePlanBaseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AccountPlansResponsePlanBaseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => AccountPlansResponsePlanBaseStatus::Active,
+            "cancel" => AccountPlansResponsePlanBaseStatus::Cancel,
+            "expired" => AccountPlansResponsePlanBaseStatus::Expired,
+            _ => AccountPlansResponsePlanBaseStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AccountPlansResponsePlanBaseStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AccountPlansResponsePlanBaseStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AccountPlansResponsePlanBaseStatus::Noop)
+
+impl std::convert::From<AccountPlansResponsePlanBaseStatus> for String {
+    fn from(v: AccountPlansResponsePlanBaseStatus) -> Self {
+        match v {
+            AccountPlansResponsePlanBaseStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -14681,37 +16028,61 @@ pub struct PlanWebinar {
  * Status of additional Cloud Recording plan.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PlanRecordingStatus {
     #[serde(rename = "Active")]
     Active,
     #[serde(rename = "Cancelled")]
     Cancelled,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PlanRecordingStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PlanRecordingStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             PlanRecordingStatus::Active => "Active",
             PlanRecordingStatus::Cancelled => "Cancelled",
-            PlanRecordingStatus::Noop => "",
-            PlanRecordingStatus::FallthroughString => "*",
+            PlanRecordingStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PlanRecordingStatus {
-    fn default() -> PlanRecordingStatus {
-        PlanRecordingStatus::Noop
+impl std::fmt::Display for PlanRecordingStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl PlanRecordingStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PlanRecordingStatus::Noop)
+
+impl std::str::FromStr for PlanRecordingStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Active" => PlanRecordingStatus::Active,
+            "Cancelled" => PlanRecordingStatus::Cancelled,
+            _ => PlanRecordingStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PlanRecordingStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PlanRecordingStatus> for String {
+    fn from(v: PlanRecordingStatus) -> Self {
+        match v {
+            PlanRecordingStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -15197,37 +16568,61 @@ pub struct GroupMembersCreateResponse {
  *   * `set_primary` — Set the user's primary group.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UpdateGroupMemberRequestAction {
     #[serde(rename = "move")]
     Move,
     #[serde(rename = "set_primary")]
     SetPrimary,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UpdateGroupMemberRequestAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UpdateGroupMemberRequestAction {
+    fn as_ref(&self) -> &str {
+        match self {
             UpdateGroupMemberRequestAction::Move => "move",
             UpdateGroupMemberRequestAction::SetPrimary => "set_primary",
-            UpdateGroupMemberRequestAction::Noop => "",
-            UpdateGroupMemberRequestAction::FallthroughString => "*",
+            UpdateGroupMemberRequestAction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for UpdateGroupMemberRequestAction {
-    fn default() -> UpdateGroupMemberRequestAction {
-        UpdateGroupMemberRequestAction::Noop
+impl std::fmt::Display for UpdateGroupMemberRequestAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UpdateGroupMemberRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "move" => UpdateGroupMemberRequestAction::Move,
+            "set_primary" => UpdateGroupMemberRequestAction::SetPrimary,
+            _ => UpdateGroupMemberRequestAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UpdateGroupMemberRequestAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl UpdateGroupMemberRequestAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UpdateGroupMemberRequestAction::Noop)
+
+impl std::convert::From<UpdateGroupMemberRequestAction> for String {
+    fn from(v: UpdateGroupMemberRequestAction) -> Self {
+        match v {
+            UpdateGroupMemberRequestAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -15238,11 +16633,8 @@ pub struct UpdateGroupMemberRequest {
      *  \* `move` — Remove the group member from one group and move them to a different group.
      *  \* `set_primary` — Set the user's primary group.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "UpdateGroupMemberRequestAction::is_noop"
-    )]
-    pub action: UpdateGroupMemberRequestAction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<UpdateGroupMemberRequestAction>,
     /**
      * User's first name.
      */
@@ -15288,6 +16680,7 @@ pub struct TrackingfieldGetResponseAllOf {
  * IM Group types:<br>`normal` - Only members can see automatically see the other members of this group. Other people can search for members within this group. <br>`shared` - Everyone under an account can see the group members automatically.<br>`restricted` - Nobody can see the group or search for members except the members in the group.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ImGroupCreateRequestType {
     #[serde(rename = "normal")]
     Normal,
@@ -15295,19 +16688,57 @@ pub enum ImGroupCreateRequestType {
     Restricted,
     #[serde(rename = "shared")]
     Shared,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ImGroupCreateRequestType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ImGroupCreateRequestType {
+    fn as_ref(&self) -> &str {
+        match self {
             ImGroupCreateRequestType::Normal => "normal",
             ImGroupCreateRequestType::Restricted => "restricted",
             ImGroupCreateRequestType::Shared => "shared",
-            ImGroupCreateRequestType::FallthroughString => "*",
+            ImGroupCreateRequestType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ImGroupCreateRequestType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ImGroupCreateRequestType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "normal" => ImGroupCreateRequestType::Normal,
+            "restricted" => ImGroupCreateRequestType::Restricted,
+            "shared" => ImGroupCreateRequestType::Shared,
+            _ => ImGroupCreateRequestType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ImGroupCreateRequestType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ImGroupCreateRequestType> for String {
+    fn from(v: ImGroupCreateRequestType) -> Self {
+        match v {
+            ImGroupCreateRequestType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -15443,37 +16874,61 @@ pub struct DashboardImResponse {
  * IM chat session type.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ImChatSessionsResponseType {
     #[serde(rename = "1:1")]
     One,
     #[serde(rename = "Group")]
     Group,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ImChatSessionsResponseType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ImChatSessionsResponseType {
+    fn as_ref(&self) -> &str {
+        match self {
             ImChatSessionsResponseType::One => "1:1",
             ImChatSessionsResponseType::Group => "Group",
-            ImChatSessionsResponseType::Noop => "",
-            ImChatSessionsResponseType::FallthroughString => "*",
+            ImChatSessionsResponseType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ImChatSessionsResponseType {
-    fn default() -> ImChatSessionsResponseType {
-        ImChatSessionsResponseType::Noop
+impl std::fmt::Display for ImChatSessionsResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ImChatSessionsResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "1:1" => ImChatSessionsResponseType::One,
+            "Group" => ImChatSessionsResponseType::Group,
+            _ => ImChatSessionsResponseType::Unknown(s.to_string()),
+        })
     }
 }
-impl ImChatSessionsResponseType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ImChatSessionsResponseType::Noop)
+
+impl std::convert::From<String> for ImChatSessionsResponseType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ImChatSessionsResponseType> for String {
+    fn from(v: ImChatSessionsResponseType) -> Self {
+        match v {
+            ImChatSessionsResponseType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -15659,6 +17114,7 @@ pub struct ImChatMessagesResponseAllOf {
  * The meeting types: <br>`scheduled` - This includes all valid past meetings (unexpired), live meetings and upcoming scheduled meetings. It is equivalent to the combined list of "Previous Meetings" and "Upcoming Meetings" displayed in the user's [Meetings page](https://zoom.us/meeting) on the Zoom Web Portal.<br>`live` - All the ongoing meetings.<br>`upcoming` - All upcoming meetings including live meetings.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MeetingsType {
     #[serde(rename = "live")]
     Live,
@@ -15666,19 +17122,57 @@ pub enum MeetingsType {
     Scheduled,
     #[serde(rename = "upcoming")]
     Upcoming,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for MeetingsType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for MeetingsType {
+    fn as_ref(&self) -> &str {
+        match self {
             MeetingsType::Live => "live",
             MeetingsType::Scheduled => "scheduled",
             MeetingsType::Upcoming => "upcoming",
-            MeetingsType::FallthroughString => "*",
+            MeetingsType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for MeetingsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for MeetingsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "live" => MeetingsType::Live,
+            "scheduled" => MeetingsType::Scheduled,
+            "upcoming" => MeetingsType::Upcoming,
+            _ => MeetingsType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for MeetingsType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<MeetingsType> for String {
+    fn from(v: MeetingsType) -> Self {
+        match v {
+            MeetingsType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -15838,37 +17332,61 @@ pub struct MeetingUpdateRequestAllOf {
  *   
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MeetingStatusRequestAction {
     #[serde(rename = "end")]
     End,
     #[serde(rename = "recover")]
     Recover,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for MeetingStatusRequestAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for MeetingStatusRequestAction {
+    fn as_ref(&self) -> &str {
+        match self {
             MeetingStatusRequestAction::End => "end",
             MeetingStatusRequestAction::Recover => "recover",
-            MeetingStatusRequestAction::Noop => "",
-            MeetingStatusRequestAction::FallthroughString => "*",
+            MeetingStatusRequestAction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for MeetingStatusRequestAction {
-    fn default() -> MeetingStatusRequestAction {
-        MeetingStatusRequestAction::Noop
+impl std::fmt::Display for MeetingStatusRequestAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for MeetingStatusRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "end" => MeetingStatusRequestAction::End,
+            "recover" => MeetingStatusRequestAction::Recover,
+            _ => MeetingStatusRequestAction::Unknown(s.to_string()),
+        })
     }
 }
-impl MeetingStatusRequestAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MeetingStatusRequestAction::Noop)
+
+impl std::convert::From<String> for MeetingStatusRequestAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<MeetingStatusRequestAction> for String {
+    fn from(v: MeetingStatusRequestAction) -> Self {
+        match v {
+            MeetingStatusRequestAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -15887,6 +17405,7 @@ pub struct MeetingStatusRequest {
  * The registrant status:<br>`pending` - Registrant's status is pending.<br>`approved` - Registrant's status is approved.<br>`denied` - Registrant's status is denied.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MeetingRegistrantsStatus {
     #[serde(rename = "approved")]
     Approved,
@@ -15894,19 +17413,57 @@ pub enum MeetingRegistrantsStatus {
     Denied,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for MeetingRegistrantsStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for MeetingRegistrantsStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             MeetingRegistrantsStatus::Approved => "approved",
             MeetingRegistrantsStatus::Denied => "denied",
             MeetingRegistrantsStatus::Pending => "pending",
-            MeetingRegistrantsStatus::FallthroughString => "*",
+            MeetingRegistrantsStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for MeetingRegistrantsStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for MeetingRegistrantsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "approved" => MeetingRegistrantsStatus::Approved,
+            "denied" => MeetingRegistrantsStatus::Denied,
+            "pending" => MeetingRegistrantsStatus::Pending,
+            _ => MeetingRegistrantsStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for MeetingRegistrantsStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<MeetingRegistrantsStatus> for String {
+    fn from(v: MeetingRegistrantsStatus) -> Self {
+        match v {
+            MeetingRegistrantsStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -16141,6 +17698,7 @@ pub struct PastMeetingParticipantsResponseAllOf {
  * Status of the Meeting Poll:<br>`notstart` - Poll not started<br>`started` - Poll started<br>`ended` - Poll ended<br>`sharing` - Sharing poll results
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MeetingPollCreateResponseStatus {
     #[serde(rename = "ended")]
     Ended,
@@ -16150,34 +17708,59 @@ pub enum MeetingPollCreateResponseStatus {
     Sharing,
     #[serde(rename = "started")]
     Started,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for MeetingPollCreateResponseStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for MeetingPollCreateResponseStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             MeetingPollCreateResponseStatus::Ended => "ended",
             MeetingPollCreateResponseStatus::Notstart => "notstart",
             MeetingPollCreateResponseStatus::Sharing => "sharing",
             MeetingPollCreateResponseStatus::Started => "started",
-            MeetingPollCreateResponseStatus::Noop => "",
-            MeetingPollCreateResponseStatus::FallthroughString => "*",
+            MeetingPollCreateResponseStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for MeetingPollCreateResponseStatus {
-    fn default() -> MeetingPollCreateResponseStatus {
-        MeetingPollCreateResponseStatus::Noop
+impl std::fmt::Display for MeetingPollCreateResponseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl MeetingPollCreateResponseStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MeetingPollCreateResponseStatus::Noop)
+
+impl std::str::FromStr for MeetingPollCreateResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "ended" => MeetingPollCreateResponseStatus::Ended,
+            "notstart" => MeetingPollCreateResponseStatus::Notstart,
+            "sharing" => MeetingPollCreateResponseStatus::Sharing,
+            "started" => MeetingPollCreateResponseStatus::Started,
+            _ => MeetingPollCreateResponseStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for MeetingPollCreateResponseStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<MeetingPollCreateResponseStatus> for String {
+    fn from(v: MeetingPollCreateResponseStatus) -> Self {
+        match v {
+            MeetingPollCreateResponseStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -16214,6 +17797,120 @@ pub struct MeetingPollGetResponseAllOf {
     pub meeting_poll_get_response: MeetingPollGetResponse,
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PastMeetingsResponseMeetings {
+    /**
+     * Meeting start time.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub start_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Meeting UUID.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uuid: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PastMeetingsResponse {
+    /**
+     * List of ended meeting instances.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub meetings: Vec<PastMeetingsResponseMeetings>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PastWebinarsResponseWebinars {
+    /**
+     * Webinar start time.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "crate::utils::date_time_format::deserialize"
+    )]
+    pub start_time: Option<chrono::DateTime<chrono::Utc>>,
+    /**
+     * Webinar UUID.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub uuid: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct PastWebinarsResponse {
+    /**
+     * List of ended webinar instances.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub webinars: Vec<PastWebinarsResponseWebinars>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct MeetingPollsResponse {
+    /**
+     * Array of Polls
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub polls: Vec<MeetingPollGetResponseAllOf>,
+    /**
+     * The number of all records available across pages.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_records: i64,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct WebinarPollsResponse {
+    /**
+     * Array of Polls
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_vector::deserialize"
+    )]
+    pub polls: Vec<MeetingPollGetResponseAllOf>,
+    /**
+     * The number of all records available across pages.
+     */
+    #[serde(
+        default,
+        skip_serializing_if = "crate::utils::zero_i64",
+        deserialize_with = "crate::utils::deserialize_null_i64::deserialize"
+    )]
+    pub total_records: i64,
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
 pub struct RecordingGetResponse {
     /**
@@ -16252,23 +17949,61 @@ pub struct RecordingGetResponseAllOf {
  * The recording delete actions:<br>`trash` - Move recording to trash.<br>`delete` - Delete recording permanently.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingDeleteAction {
     #[serde(rename = "delete")]
     Delete,
     #[serde(rename = "trash")]
     Trash,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for RecordingDeleteAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for RecordingDeleteAction {
+    fn as_ref(&self) -> &str {
+        match self {
             RecordingDeleteAction::Delete => "delete",
             RecordingDeleteAction::Trash => "trash",
-            RecordingDeleteAction::FallthroughString => "*",
+            RecordingDeleteAction::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for RecordingDeleteAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for RecordingDeleteAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "delete" => RecordingDeleteAction::Delete,
+            "trash" => RecordingDeleteAction::Trash,
+            _ => RecordingDeleteAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RecordingDeleteAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<RecordingDeleteAction> for String {
+    fn from(v: RecordingDeleteAction) -> Self {
+        match v {
+            RecordingDeleteAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -16324,6 +18059,7 @@ pub struct MeetingRecordingRegistrantCreateResponse {
  *   If you do not provide this field, the default value will be `live` and thus, the API will only query responses for live meetings.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DashboardMeetingsType {
     #[serde(rename = "live")]
     Live,
@@ -16331,19 +18067,57 @@ pub enum DashboardMeetingsType {
     Past,
     #[serde(rename = "pastOne")]
     PastOne,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DashboardMeetingsType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DashboardMeetingsType {
+    fn as_ref(&self) -> &str {
+        match self {
             DashboardMeetingsType::Live => "live",
             DashboardMeetingsType::Past => "past",
             DashboardMeetingsType::PastOne => "pastOne",
-            DashboardMeetingsType::FallthroughString => "*",
+            DashboardMeetingsType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DashboardMeetingsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DashboardMeetingsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "live" => DashboardMeetingsType::Live,
+            "past" => DashboardMeetingsType::Past,
+            "pastOne" => DashboardMeetingsType::PastOne,
+            _ => DashboardMeetingsType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DashboardMeetingsType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DashboardMeetingsType> for String {
+    fn from(v: DashboardMeetingsType) -> Self {
+        match v {
+            DashboardMeetingsType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -16357,34 +18131,57 @@ impl Default for DashboardMeetingsType {
  * Set the value of this field to "tracking_fields" if you would like to include tracking fields of each meeting in the response.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum IncludeFields {
     #[serde(rename = "tracking_fields")]
     TrackingFields,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for IncludeFields {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for IncludeFields {
+    fn as_ref(&self) -> &str {
+        match self {
             IncludeFields::TrackingFields => "tracking_fields",
-            IncludeFields::Noop => "",
-            IncludeFields::FallthroughString => "*",
+            IncludeFields::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for IncludeFields {
-    fn default() -> IncludeFields {
-        IncludeFields::Noop
+impl std::fmt::Display for IncludeFields {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl IncludeFields {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, IncludeFields::Noop)
+
+impl std::str::FromStr for IncludeFields {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "tracking_fields" => IncludeFields::TrackingFields,
+            _ => IncludeFields::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for IncludeFields {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<IncludeFields> for String {
+    fn from(v: IncludeFields) -> Self {
+        match v {
+            IncludeFields::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -16423,34 +18220,57 @@ pub struct DashboardMeetingsResponseAllOf {
  * Provide `registrant_id` as the value for this field if you would like to see the registrant ID attribute in the response of this API call. A registrant ID is a unique identifier of a [meeting registrant](https://marketplace.zoom.us/docs/api-reference/zoom-api/meetings/meetingregistrants). This is not supported for `live` meeting types.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DashboardMeetingParticipantsIncludeFields {
     #[serde(rename = "registrant_id")]
     RegistrantId,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DashboardMeetingParticipantsIncludeFields {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DashboardMeetingParticipantsIncludeFields {
+    fn as_ref(&self) -> &str {
+        match self {
             DashboardMeetingParticipantsIncludeFields::RegistrantId => "registrant_id",
-            DashboardMeetingParticipantsIncludeFields::Noop => "",
-            DashboardMeetingParticipantsIncludeFields::FallthroughString => "*",
+            DashboardMeetingParticipantsIncludeFields::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DashboardMeetingParticipantsIncludeFields {
-    fn default() -> DashboardMeetingParticipantsIncludeFields {
-        DashboardMeetingParticipantsIncludeFields::Noop
+impl std::fmt::Display for DashboardMeetingParticipantsIncludeFields {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DashboardMeetingParticipantsIncludeFields {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "registrant_id" => DashboardMeetingParticipantsIncludeFields::RegistrantId,
+            _ => DashboardMeetingParticipantsIncludeFields::Unknown(s.to_string()),
+        })
     }
 }
-impl DashboardMeetingParticipantsIncludeFields {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DashboardMeetingParticipantsIncludeFields::Noop)
+
+impl std::convert::From<String> for DashboardMeetingParticipantsIncludeFields {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DashboardMeetingParticipantsIncludeFields> for String {
+    fn from(v: DashboardMeetingParticipantsIncludeFields) -> Self {
+        match v {
+            DashboardMeetingParticipantsIncludeFields::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -16466,6 +18286,7 @@ impl DashboardMeetingParticipantsIncludeFields {
  *   **Note:** This response returns an empty string (`““`) value for any users who are **not** a part of the host’s account (external users).
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DashboardMeetingParticipantsResponseDevice {
     #[serde(rename = "Android")]
     Android,
@@ -16479,36 +18300,63 @@ pub enum DashboardMeetingParticipantsResponseDevice {
     Windows,
     #[serde(rename = "iOS")]
     IOs,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DashboardMeetingParticipantsResponseDevice {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DashboardMeetingParticipantsResponseDevice {
+    fn as_ref(&self) -> &str {
+        match self {
             DashboardMeetingParticipantsResponseDevice::Android => "Android",
             DashboardMeetingParticipantsResponseDevice::H323Sip => "H.323/SIP",
             DashboardMeetingParticipantsResponseDevice::Mac => "Mac",
             DashboardMeetingParticipantsResponseDevice::Phone => "Phone",
             DashboardMeetingParticipantsResponseDevice::Windows => "Windows",
             DashboardMeetingParticipantsResponseDevice::IOs => "iOS",
-            DashboardMeetingParticipantsResponseDevice::Noop => "",
-            DashboardMeetingParticipantsResponseDevice::FallthroughString => "*",
+            DashboardMeetingParticipantsResponseDevice::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DashboardMeetingParticipantsResponseDevice {
-    fn default() -> DashboardMeetingParticipantsResponseDevice {
-        DashboardMeetingParticipantsResponseDevice::Noop
+impl std::fmt::Display for DashboardMeetingParticipantsResponseDevice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DashboardMeetingParticipantsResponseDevice {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Android" => DashboardMeetingParticipantsResponseDevice::Android,
+            "H.323/SIP" => DashboardMeetingParticipantsResponseDevice::H323Sip,
+            "Mac" => DashboardMeetingParticipantsResponseDevice::Mac,
+            "Phone" => DashboardMeetingParticipantsResponseDevice::Phone,
+            "Windows" => DashboardMeetingParticipantsResponseDevice::Windows,
+            "iOS" => DashboardMeetingParticipantsResponseDevice::IOs,
+            _ => DashboardMeetingParticipantsResponseDevice::Unknown(s.to_string()),
+        })
     }
 }
-impl DashboardMeetingParticipantsResponseDevice {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DashboardMeetingParticipantsResponseDevice::Noop)
+
+impl std::convert::From<String> for DashboardMeetingParticipantsResponseDevice {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DashboardMeetingParticipantsResponseDevice> for String {
+    fn from(v: DashboardMeetingParticipantsResponseDevice) -> Self {
+        match v {
+            DashboardMeetingParticipantsResponseDevice::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -16522,6 +18370,7 @@ impl DashboardMeetingParticipantsResponseDevice {
  *   * `Others` — An unknown device.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum NetworkType {
     #[serde(rename = "Cellular")]
     Cellular,
@@ -16533,35 +18382,61 @@ pub enum NetworkType {
     Wifi,
     #[serde(rename = "Wired")]
     Wired,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for NetworkType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for NetworkType {
+    fn as_ref(&self) -> &str {
+        match self {
             NetworkType::Cellular => "Cellular",
             NetworkType::Others => "Others",
             NetworkType::Ppp => "PPP",
             NetworkType::Wifi => "Wifi",
             NetworkType::Wired => "Wired",
-            NetworkType::Noop => "",
-            NetworkType::FallthroughString => "*",
+            NetworkType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for NetworkType {
-    fn default() -> NetworkType {
-        NetworkType::Noop
+impl std::fmt::Display for NetworkType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl NetworkType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, NetworkType::Noop)
+
+impl std::str::FromStr for NetworkType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Cellular" => NetworkType::Cellular,
+            "Others" => NetworkType::Others,
+            "PPP" => NetworkType::Ppp,
+            "Wifi" => NetworkType::Wifi,
+            "Wired" => NetworkType::Wired,
+            _ => NetworkType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for NetworkType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<NetworkType> for String {
+    fn from(v: NetworkType) -> Self {
+        match v {
+            NetworkType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -16569,6 +18444,7 @@ impl NetworkType {
  * Audio quality of the participant.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AudioQuality {
     #[serde(rename = "bad")]
     Bad,
@@ -16578,34 +18454,59 @@ pub enum AudioQuality {
     Good,
     #[serde(rename = "poor")]
     Poor,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AudioQuality {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AudioQuality {
+    fn as_ref(&self) -> &str {
+        match self {
             AudioQuality::Bad => "bad",
             AudioQuality::Fair => "fair",
             AudioQuality::Good => "good",
             AudioQuality::Poor => "poor",
-            AudioQuality::Noop => "",
-            AudioQuality::FallthroughString => "*",
+            AudioQuality::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AudioQuality {
-    fn default() -> AudioQuality {
-        AudioQuality::Noop
+impl std::fmt::Display for AudioQuality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AudioQuality {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "bad" => AudioQuality::Bad,
+            "fair" => AudioQuality::Fair,
+            "good" => AudioQuality::Good,
+            "poor" => AudioQuality::Poor,
+            _ => AudioQuality::Unknown(s.to_string()),
+        })
     }
 }
-impl AudioQuality {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AudioQuality::Noop)
+
+impl std::convert::From<String> for AudioQuality {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<AudioQuality> for String {
+    fn from(v: AudioQuality) -> Self {
+        match v {
+            AudioQuality::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -16615,37 +18516,61 @@ impl AudioQuality {
  *   The value of this field can be `in_meeting` or `in_waiting_room`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DashboardMeetingParticipantsResponseStatus {
     #[serde(rename = "in_meeting")]
     InMeeting,
     #[serde(rename = "in_waiting_room")]
     InWaitingRoom,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DashboardMeetingParticipantsResponseStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DashboardMeetingParticipantsResponseStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             DashboardMeetingParticipantsResponseStatus::InMeeting => "in_meeting",
             DashboardMeetingParticipantsResponseStatus::InWaitingRoom => "in_waiting_room",
-            DashboardMeetingParticipantsResponseStatus::Noop => "",
-            DashboardMeetingParticipantsResponseStatus::FallthroughString => "*",
+            DashboardMeetingParticipantsResponseStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DashboardMeetingParticipantsResponseStatus {
-    fn default() -> DashboardMeetingParticipantsResponseStatus {
-        DashboardMeetingParticipantsResponseStatus::Noop
+impl std::fmt::Display for DashboardMeetingParticipantsResponseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DashboardMeetingParticipantsResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "in_meeting" => DashboardMeetingParticipantsResponseStatus::InMeeting,
+            "in_waiting_room" => DashboardMeetingParticipantsResponseStatus::InWaitingRoom,
+            _ => DashboardMeetingParticipantsResponseStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DashboardMeetingParticipantsResponseStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl DashboardMeetingParticipantsResponseStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DashboardMeetingParticipantsResponseStatus::Noop)
+
+impl std::convert::From<DashboardMeetingParticipantsResponseStatus> for String {
+    fn from(v: DashboardMeetingParticipantsResponseStatus) -> Self {
+        match v {
+            DashboardMeetingParticipantsResponseStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -17062,23 +18987,61 @@ pub struct DashboardMeetingParticipantShareResponseAllOf {
  * The webinar type.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DashboardWebinarsType {
     #[serde(rename = "live")]
     Live,
     #[serde(rename = "past")]
     Past,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DashboardWebinarsType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DashboardWebinarsType {
+    fn as_ref(&self) -> &str {
+        match self {
             DashboardWebinarsType::Live => "live",
             DashboardWebinarsType::Past => "past",
-            DashboardWebinarsType::FallthroughString => "*",
+            DashboardWebinarsType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for DashboardWebinarsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for DashboardWebinarsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "live" => DashboardWebinarsType::Live,
+            "past" => DashboardWebinarsType::Past,
+            _ => DashboardWebinarsType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DashboardWebinarsType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DashboardWebinarsType> for String {
+    fn from(v: DashboardWebinarsType) -> Self {
+        match v {
+            DashboardWebinarsType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -18050,37 +20013,61 @@ pub struct ReportDailyResponse {
  * Active or inactive hosts.<br>`active` - Active hosts. <br>`inactive` - Inactive hosts.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReportUsersType {
     #[serde(rename = "active")]
     Active,
     #[serde(rename = "inactive")]
     Inactive,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ReportUsersType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ReportUsersType {
+    fn as_ref(&self) -> &str {
+        match self {
             ReportUsersType::Active => "active",
             ReportUsersType::Inactive => "inactive",
-            ReportUsersType::Noop => "",
-            ReportUsersType::FallthroughString => "*",
+            ReportUsersType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ReportUsersType {
-    fn default() -> ReportUsersType {
-        ReportUsersType::Noop
+impl std::fmt::Display for ReportUsersType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReportUsersType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => ReportUsersType::Active,
+            "inactive" => ReportUsersType::Inactive,
+            _ => ReportUsersType::Unknown(s.to_string()),
+        })
     }
 }
-impl ReportUsersType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ReportUsersType::Noop)
+
+impl std::convert::From<String> for ReportUsersType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReportUsersType> for String {
+    fn from(v: ReportUsersType) -> Self {
+        match v {
+            ReportUsersType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -18088,23 +20075,61 @@ impl ReportUsersType {
  * The meeting types: <br>`past` - Past meetings.<br>`pastOne` - Past one user meetings.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ReportMeetingsType {
     #[serde(rename = "past")]
     Past,
     #[serde(rename = "pastOne")]
     PastOne,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ReportMeetingsType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ReportMeetingsType {
+    fn as_ref(&self) -> &str {
+        match self {
             ReportMeetingsType::Past => "past",
             ReportMeetingsType::PastOne => "pastOne",
-            ReportMeetingsType::FallthroughString => "*",
+            ReportMeetingsType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ReportMeetingsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ReportMeetingsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "past" => ReportMeetingsType::Past,
+            "pastOne" => ReportMeetingsType::PastOne,
+            _ => ReportMeetingsType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ReportMeetingsType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ReportMeetingsType> for String {
+    fn from(v: ReportMeetingsType) -> Self {
+        match v {
+            ReportMeetingsType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -18867,23 +20892,61 @@ pub struct ReportWebinarQaResponse {
  * Date types:<br>`start_time` - Query by call start time.<br>`end_time` - Query by call end time.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum QueryDateType {
     #[serde(rename = "end_time")]
     EndTime,
     #[serde(rename = "start_time")]
     StartTime,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for QueryDateType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for QueryDateType {
+    fn as_ref(&self) -> &str {
+        match self {
             QueryDateType::EndTime => "end_time",
             QueryDateType::StartTime => "start_time",
-            QueryDateType::FallthroughString => "*",
+            QueryDateType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for QueryDateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for QueryDateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "end_time" => QueryDateType::EndTime,
+            "start_time" => QueryDateType::StartTime,
+            _ => QueryDateType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for QueryDateType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<QueryDateType> for String {
+    fn from(v: QueryDateType) -> Self {
+        match v {
+            QueryDateType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -18927,6 +20990,7 @@ pub struct ReportCloudRecordingResponseAllOf {
  *   The value for this field can be one of the following:<br> `all`<br>`user`<br>`user_settings`<br>`account`<br>`billing`<br>`im`<br>`recording`<br>`phone_contacts`<br>`webinar`<br>`sub_account`<br>`role`<br>`zoom_rooms`
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CategoryType {
     #[serde(rename = "account")]
     Account,
@@ -18952,15 +21016,15 @@ pub enum CategoryType {
     Webinar,
     #[serde(rename = "zoom_rooms")]
     ZoomRooms,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for CategoryType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for CategoryType {
+    fn as_ref(&self) -> &str {
+        match self {
             CategoryType::Account => "account",
             CategoryType::All => "all",
             CategoryType::Billing => "billing",
@@ -18973,21 +21037,54 @@ impl std::fmt::Display for CategoryType {
             CategoryType::UserSettings => "user_settings",
             CategoryType::Webinar => "webinar",
             CategoryType::ZoomRooms => "zoom_rooms",
-            CategoryType::Noop => "",
-            CategoryType::FallthroughString => "*",
+            CategoryType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for CategoryType {
-    fn default() -> CategoryType {
-        CategoryType::Noop
+impl std::fmt::Display for CategoryType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl CategoryType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, CategoryType::Noop)
+
+impl std::str::FromStr for CategoryType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "account" => CategoryType::Account,
+            "all" => CategoryType::All,
+            "billing" => CategoryType::Billing,
+            "im" => CategoryType::Im,
+            "phone_contacts" => CategoryType::PhoneContacts,
+            "recording" => CategoryType::Recording,
+            "role" => CategoryType::Role,
+            "sub_account" => CategoryType::SubAccount,
+            "user" => CategoryType::User,
+            "user_settings" => CategoryType::UserSettings,
+            "webinar" => CategoryType::Webinar,
+            "zoom_rooms" => CategoryType::ZoomRooms,
+            _ => CategoryType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CategoryType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<CategoryType> for String {
+    fn from(v: CategoryType) -> Self {
+        match v {
+            CategoryType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -19324,6 +21421,7 @@ pub struct TspUpdateRequest {
  *   This value defaults to `active`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UsersStatus {
     #[serde(rename = "active")]
     Active,
@@ -19331,19 +21429,57 @@ pub enum UsersStatus {
     Inactive,
     #[serde(rename = "pending")]
     Pending,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UsersStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UsersStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             UsersStatus::Active => "active",
             UsersStatus::Inactive => "inactive",
             UsersStatus::Pending => "pending",
-            UsersStatus::FallthroughString => "*",
+            UsersStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for UsersStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UsersStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active" => UsersStatus::Active,
+            "inactive" => UsersStatus::Inactive,
+            "pending" => UsersStatus::Pending,
+            _ => UsersStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UsersStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UsersStatus> for String {
+    fn from(v: UsersStatus) -> Self {
+        match v {
+            UsersStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -19359,37 +21495,61 @@ impl Default for UsersStatus {
  *   * `host_key` — Return the user's [host key](https://support.zoom.us/hc/en-us/articles/205172555-Using-your-host-key).
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UsersIncludeFields {
     #[serde(rename = "custom_attributes")]
     CustomAttributes,
     #[serde(rename = "host_key")]
     HostKey,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UsersIncludeFields {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UsersIncludeFields {
+    fn as_ref(&self) -> &str {
+        match self {
             UsersIncludeFields::CustomAttributes => "custom_attributes",
             UsersIncludeFields::HostKey => "host_key",
-            UsersIncludeFields::Noop => "",
-            UsersIncludeFields::FallthroughString => "*",
+            UsersIncludeFields::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for UsersIncludeFields {
-    fn default() -> UsersIncludeFields {
-        UsersIncludeFields::Noop
+impl std::fmt::Display for UsersIncludeFields {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl UsersIncludeFields {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UsersIncludeFields::Noop)
+
+impl std::str::FromStr for UsersIncludeFields {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "custom_attributes" => UsersIncludeFields::CustomAttributes,
+            "host_key" => UsersIncludeFields::HostKey,
+            _ => UsersIncludeFields::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UsersIncludeFields {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UsersIncludeFields> for String {
+    fn from(v: UsersIncludeFields) -> Self {
+        match v {
+            UsersIncludeFields::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -19671,6 +21831,7 @@ pub struct UsersResponseData {
  * Specify how to create the new user: <br>`create` - User will get an email sent from Zoom. There is a confirmation link in this email. The user will then need to use the link to activate their Zoom account. The user can then set or change their password.<br>`autoCreate` - This action is provided for the enterprise customer who has a managed domain. This feature is disabled by default because of the security risk involved in creating a user who does not belong to your domain.<br>`custCreate` - Users created via this option do not have passwords and will not have the ability to log into the Zoom Web Portal or the Zoom Client. These users can still host and join meetings using the `start_url` and `join_url` respectively. To use this option, you must contact the ISV Platform Sales team at isv@zoom.us.<br>`ssoCreate` - This action is provided for the enabled “Pre-provisioning SSO User” option. A user created in this way has no password. If not a basic user, a personal vanity URL using the user name (no domain) of the provisioning email will be generated. If the user name or PMI is invalid or occupied, it will use a random number or random personal vanity URL.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserCreateRequestAction {
     #[serde(rename = "autoCreate")]
     AutoCreate,
@@ -19680,34 +21841,59 @@ pub enum UserCreateRequestAction {
     CustCreate,
     #[serde(rename = "ssoCreate")]
     SsoCreate,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UserCreateRequestAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UserCreateRequestAction {
+    fn as_ref(&self) -> &str {
+        match self {
             UserCreateRequestAction::AutoCreate => "autoCreate",
             UserCreateRequestAction::Create => "create",
             UserCreateRequestAction::CustCreate => "custCreate",
             UserCreateRequestAction::SsoCreate => "ssoCreate",
-            UserCreateRequestAction::Noop => "",
-            UserCreateRequestAction::FallthroughString => "*",
+            UserCreateRequestAction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for UserCreateRequestAction {
-    fn default() -> UserCreateRequestAction {
-        UserCreateRequestAction::Noop
+impl std::fmt::Display for UserCreateRequestAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl UserCreateRequestAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UserCreateRequestAction::Noop)
+
+impl std::str::FromStr for UserCreateRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "autoCreate" => UserCreateRequestAction::AutoCreate,
+            "create" => UserCreateRequestAction::Create,
+            "custCreate" => UserCreateRequestAction::CustCreate,
+            "ssoCreate" => UserCreateRequestAction::SsoCreate,
+            _ => UserCreateRequestAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UserCreateRequestAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UserCreateRequestAction> for String {
+    fn from(v: UserCreateRequestAction) -> Self {
+        match v {
+            UserCreateRequestAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -19766,8 +21952,8 @@ pub struct UserCreateRequest {
     /**
      * Specify how to create the new user: <br>`create` - User will get an email sent from Zoom. There is a confirmation link in this email. The user will then need to use the link to activate their Zoom account. The user can then set or change their password.<br>`autoCreate` - This action is provided for the enterprise customer who has a managed domain. This feature is disabled by default because of the security risk involved in creating a user who does not belong to your domain.<br>`custCreate` - Users created via this option do not have passwords and will not have the ability to log into the Zoom Web Portal or the Zoom Client. These users can still host and join meetings using the `start_url` and `join_url` respectively. To use this option, you must contact the ISV Platform Sales team at isv@zoom.us.<br>`ssoCreate` - This action is provided for the enabled “Pre-provisioning SSO User” option. A user created in this way has no password. If not a basic user, a personal vanity URL using the user name (no domain) of the provisioning email will be generated. If the user name or PMI is invalid or occupied, it will use a random number or random personal vanity URL.
      */
-    #[serde(default, skip_serializing_if = "UserCreateRequestAction::is_noop")]
-    pub action: UserCreateRequestAction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<UserCreateRequestAction>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user_info: Option<UserInfo>,
 }
@@ -19782,6 +21968,7 @@ pub struct UserCreateRequest {
  *   `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum LoginType {
     #[serde(rename = "0")]
     Zero,
@@ -19807,15 +21994,15 @@ pub enum LoginType {
     NinetyEight,
     #[serde(rename = "99")]
     NinetyNine,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for LoginType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for LoginType {
+    fn as_ref(&self) -> &str {
+        match self {
             LoginType::Zero => "0",
             LoginType::One => "1",
             LoginType::OneHundred => "100",
@@ -19828,21 +22015,54 @@ impl std::fmt::Display for LoginType {
             LoginType::NinetySeven => "97",
             LoginType::NinetyEight => "98",
             LoginType::NinetyNine => "99",
-            LoginType::Noop => "",
-            LoginType::FallthroughString => "*",
+            LoginType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for LoginType {
-    fn default() -> LoginType {
-        LoginType::Noop
+impl std::fmt::Display for LoginType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for LoginType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "0" => LoginType::Zero,
+            "1" => LoginType::One,
+            "100" => LoginType::OneHundred,
+            "101" => LoginType::OneHundredAndOne,
+            "11" => LoginType::Eleven,
+            "21" => LoginType::TwentyOne,
+            "23" => LoginType::TwentyThree,
+            "24" => LoginType::TwentyFour,
+            "27" => LoginType::TwentySeven,
+            "97" => LoginType::NinetySeven,
+            "98" => LoginType::NinetyEight,
+            "99" => LoginType::NinetyNine,
+            _ => LoginType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for LoginType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl LoginType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, LoginType::Noop)
+
+impl std::convert::From<LoginType> for String {
+    fn from(v: LoginType) -> Self {
+        match v {
+            LoginType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -20173,23 +22393,61 @@ pub struct UserResponseAllOf {
  * Delete action options:<br>`disassociate` - Disassociate a user.<br>`delete`-  Permanently delete a user.<br>Note: To delete pending user in the account, use `disassociate`
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserDeleteAction {
     #[serde(rename = "delete")]
     Delete,
     #[serde(rename = "disassociate")]
     Disassociate,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UserDeleteAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UserDeleteAction {
+    fn as_ref(&self) -> &str {
+        match self {
             UserDeleteAction::Delete => "delete",
             UserDeleteAction::Disassociate => "disassociate",
-            UserDeleteAction::FallthroughString => "*",
+            UserDeleteAction::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for UserDeleteAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UserDeleteAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "delete" => UserDeleteAction::Delete,
+            "disassociate" => UserDeleteAction::Disassociate,
+            _ => UserDeleteAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UserDeleteAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UserDeleteAction> for String {
+    fn from(v: UserDeleteAction) -> Self {
+        match v {
+            UserDeleteAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -20289,6 +22547,7 @@ impl UserSettingsUpdateRequestOneOf {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserSettingsUpdateOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -20296,33 +22555,57 @@ pub enum UserSettingsUpdateOption {
     MeetingSecuirty,
     #[serde(rename = "recording_authentication")]
     RecordingAuthentication,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UserSettingsUpdateOption {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UserSettingsUpdateOption {
+    fn as_ref(&self) -> &str {
+        match self {
             UserSettingsUpdateOption::MeetingAuthentication => "meeting_authentication",
             UserSettingsUpdateOption::MeetingSecuirty => "meeting_secuirty",
             UserSettingsUpdateOption::RecordingAuthentication => "recording_authentication",
-            UserSettingsUpdateOption::Noop => "",
-            UserSettingsUpdateOption::FallthroughString => "*",
+            UserSettingsUpdateOption::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for UserSettingsUpdateOption {
-    fn default() -> UserSettingsUpdateOption {
-        UserSettingsUpdateOption::Noop
+impl std::fmt::Display for UserSettingsUpdateOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for UserSettingsUpdateOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "meeting_authentication" => UserSettingsUpdateOption::MeetingAuthentication,
+            "meeting_secuirty" => UserSettingsUpdateOption::MeetingSecuirty,
+            "recording_authentication" => UserSettingsUpdateOption::RecordingAuthentication,
+            _ => UserSettingsUpdateOption::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UserSettingsUpdateOption {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl UserSettingsUpdateOption {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UserSettingsUpdateOption::Noop)
+
+impl std::convert::From<UserSettingsUpdateOption> for String {
+    fn from(v: UserSettingsUpdateOption) -> Self {
+        match v {
+            UserSettingsUpdateOption::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -20330,37 +22613,61 @@ impl UserSettingsUpdateOption {
  * The action types:<br>`activate` - Activate a deactivated user.<br>`deactivate` - Deactivate a user.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserStatusRequestAction {
     #[serde(rename = "activate")]
     Activate,
     #[serde(rename = "deactivate")]
     Deactivate,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UserStatusRequestAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UserStatusRequestAction {
+    fn as_ref(&self) -> &str {
+        match self {
             UserStatusRequestAction::Activate => "activate",
             UserStatusRequestAction::Deactivate => "deactivate",
-            UserStatusRequestAction::Noop => "",
-            UserStatusRequestAction::FallthroughString => "*",
+            UserStatusRequestAction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for UserStatusRequestAction {
-    fn default() -> UserStatusRequestAction {
-        UserStatusRequestAction::Noop
+impl std::fmt::Display for UserStatusRequestAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl UserStatusRequestAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UserStatusRequestAction::Noop)
+
+impl std::str::FromStr for UserStatusRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "activate" => UserStatusRequestAction::Activate,
+            "deactivate" => UserStatusRequestAction::Deactivate,
+            _ => UserStatusRequestAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UserStatusRequestAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UserStatusRequestAction> for String {
+    fn from(v: UserStatusRequestAction) -> Self {
+        match v {
+            UserStatusRequestAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -20370,8 +22677,8 @@ pub struct UserStatusRequest {
     /**
      * The action types:<br>`activate` - Activate a deactivated user.<br>`deactivate` - Deactivate a user.
      */
-    #[serde(default, skip_serializing_if = "UserStatusRequestAction::is_noop")]
-    pub action: UserStatusRequestAction,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<UserStatusRequestAction>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -20589,37 +22896,61 @@ pub struct TspAccountData {
  * User token types:<br>`token` - Used for starting meetings with the client SDK. This token expires in 14 days and a new token will be returned after the expiry.<br>`zak` - Used for generating the start meeting URL. The token expiration time is two hours. For API users, the expiration time is 90 days.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UserTokenType {
     #[serde(rename = "token")]
     Token,
     #[serde(rename = "zak")]
     Zak,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UserTokenType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UserTokenType {
+    fn as_ref(&self) -> &str {
+        match self {
             UserTokenType::Token => "token",
             UserTokenType::Zak => "zak",
-            UserTokenType::Noop => "",
-            UserTokenType::FallthroughString => "*",
+            UserTokenType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for UserTokenType {
-    fn default() -> UserTokenType {
-        UserTokenType::Noop
+impl std::fmt::Display for UserTokenType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl UserTokenType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UserTokenType::Noop)
+
+impl std::str::FromStr for UserTokenType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "token" => UserTokenType::Token,
+            "zak" => UserTokenType::Zak,
+            _ => UserTokenType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UserTokenType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UserTokenType> for String {
+    fn from(v: UserTokenType) -> Self {
+        match v {
+            UserTokenType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -20824,34 +23155,57 @@ pub struct ListWebinarParticipantsResponse {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum WebinarStatusRequestAction {
     #[serde(rename = "end")]
     End,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for WebinarStatusRequestAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for WebinarStatusRequestAction {
+    fn as_ref(&self) -> &str {
+        match self {
             WebinarStatusRequestAction::End => "end",
-            WebinarStatusRequestAction::Noop => "",
-            WebinarStatusRequestAction::FallthroughString => "*",
+            WebinarStatusRequestAction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for WebinarStatusRequestAction {
-    fn default() -> WebinarStatusRequestAction {
-        WebinarStatusRequestAction::Noop
+impl std::fmt::Display for WebinarStatusRequestAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl WebinarStatusRequestAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, WebinarStatusRequestAction::Noop)
+
+impl std::str::FromStr for WebinarStatusRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "end" => WebinarStatusRequestAction::End,
+            _ => WebinarStatusRequestAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for WebinarStatusRequestAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<WebinarStatusRequestAction> for String {
+    fn from(v: WebinarStatusRequestAction) -> Self {
+        match v {
+            WebinarStatusRequestAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -21961,6 +24315,7 @@ pub struct PhoneUserSettingsResponse {
  *   * `interop`<br>
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListSettingTemplatesResponseType {
     #[serde(rename = "autReceptionist")]
     AutReceptionist,
@@ -21974,36 +24329,63 @@ pub enum ListSettingTemplatesResponseType {
     User,
     #[serde(rename = "zr")]
     Zr,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ListSettingTemplatesResponseType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ListSettingTemplatesResponseType {
+    fn as_ref(&self) -> &str {
+        match self {
             ListSettingTemplatesResponseType::AutReceptionist => "autReceptionist",
             ListSettingTemplatesResponseType::CommonArea => "commonArea",
             ListSettingTemplatesResponseType::Group => "group",
             ListSettingTemplatesResponseType::Interop => "interop",
             ListSettingTemplatesResponseType::User => "user",
             ListSettingTemplatesResponseType::Zr => "zr",
-            ListSettingTemplatesResponseType::Noop => "",
-            ListSettingTemplatesResponseType::FallthroughString => "*",
+            ListSettingTemplatesResponseType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ListSettingTemplatesResponseType {
-    fn default() -> ListSettingTemplatesResponseType {
-        ListSettingTemplatesResponseType::Noop
+impl std::fmt::Display for ListSettingTemplatesResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ListSettingTemplatesResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "autReceptionist" => ListSettingTemplatesResponseType::AutReceptionist,
+            "commonArea" => ListSettingTemplatesResponseType::CommonArea,
+            "group" => ListSettingTemplatesResponseType::Group,
+            "interop" => ListSettingTemplatesResponseType::Interop,
+            "user" => ListSettingTemplatesResponseType::User,
+            "zr" => ListSettingTemplatesResponseType::Zr,
+            _ => ListSettingTemplatesResponseType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ListSettingTemplatesResponseType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ListSettingTemplatesResponseType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ListSettingTemplatesResponseType::Noop)
+
+impl std::convert::From<ListSettingTemplatesResponseType> for String {
+    fn from(v: ListSettingTemplatesResponseType) -> Self {
+        match v {
+            ListSettingTemplatesResponseType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -22091,6 +24473,7 @@ pub struct ListSettingTemplatesResponse {
  * The type of template. Values include `user`, `group`, `autoReceptionist`, `commonarea`, or `interop`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AddSettingTemplateRequestType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -22102,35 +24485,61 @@ pub enum AddSettingTemplateRequestType {
     Interop,
     #[serde(rename = "user")]
     User,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AddSettingTemplateRequestType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AddSettingTemplateRequestType {
+    fn as_ref(&self) -> &str {
+        match self {
             AddSettingTemplateRequestType::AutoReceptionist => "autoReceptionist",
             AddSettingTemplateRequestType::Commonarea => "commonarea",
             AddSettingTemplateRequestType::Group => "group",
             AddSettingTemplateRequestType::Interop => "interop",
             AddSettingTemplateRequestType::User => "user",
-            AddSettingTemplateRequestType::Noop => "",
-            AddSettingTemplateRequestType::FallthroughString => "*",
+            AddSettingTemplateRequestType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AddSettingTemplateRequestType {
-    fn default() -> AddSettingTemplateRequestType {
-        AddSettingTemplateRequestType::Noop
+impl std::fmt::Display for AddSettingTemplateRequestType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for AddSettingTemplateRequestType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "autoReceptionist" => AddSettingTemplateRequestType::AutoReceptionist,
+            "commonarea" => AddSettingTemplateRequestType::Commonarea,
+            "group" => AddSettingTemplateRequestType::Group,
+            "interop" => AddSettingTemplateRequestType::Interop,
+            "user" => AddSettingTemplateRequestType::User,
+            _ => AddSettingTemplateRequestType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AddSettingTemplateRequestType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl AddSettingTemplateRequestType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AddSettingTemplateRequestType::Noop)
+
+impl std::convert::From<AddSettingTemplateRequestType> for String {
+    fn from(v: AddSettingTemplateRequestType) -> Self {
+        match v {
+            AddSettingTemplateRequestType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -22166,12 +24575,8 @@ pub struct AddSettingTemplateRequest {
     /**
      * The type of template. Values include `user`, `group`, `autoReceptionist`, `commonarea`, or `interop`.
      */
-    #[serde(
-        default,
-        skip_serializing_if = "AddSettingTemplateRequestType::is_noop",
-        rename = "type"
-    )]
-    pub type_: AddSettingTemplateRequestType,
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "type")]
+    pub type_: Option<AddSettingTemplateRequestType>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
@@ -23129,6 +25534,7 @@ pub struct ListSipGroupsResponse {
  * The type of template being queried. Values: `user`, `group`, `auto receptionist` `common area`,`zr`, `interop`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetSettingTemplateResponseType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23142,36 +25548,63 @@ pub enum GetSettingTemplateResponseType {
     User,
     #[serde(rename = "zr")]
     Zr,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetSettingTemplateResponseType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetSettingTemplateResponseType {
+    fn as_ref(&self) -> &str {
+        match self {
             GetSettingTemplateResponseType::AutoReceptionist => "autoReceptionist",
             GetSettingTemplateResponseType::CommonArea => "commonArea",
             GetSettingTemplateResponseType::Group => "group",
             GetSettingTemplateResponseType::Interop => "interop",
             GetSettingTemplateResponseType::User => "user",
             GetSettingTemplateResponseType::Zr => "zr",
-            GetSettingTemplateResponseType::Noop => "",
-            GetSettingTemplateResponseType::FallthroughString => "*",
+            GetSettingTemplateResponseType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetSettingTemplateResponseType {
-    fn default() -> GetSettingTemplateResponseType {
-        GetSettingTemplateResponseType::Noop
+impl std::fmt::Display for GetSettingTemplateResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetSettingTemplateResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "autoReceptionist" => GetSettingTemplateResponseType::AutoReceptionist,
+            "commonArea" => GetSettingTemplateResponseType::CommonArea,
+            "group" => GetSettingTemplateResponseType::Group,
+            "interop" => GetSettingTemplateResponseType::Interop,
+            "user" => GetSettingTemplateResponseType::User,
+            "zr" => GetSettingTemplateResponseType::Zr,
+            _ => GetSettingTemplateResponseType::Unknown(s.to_string()),
+        })
     }
 }
-impl GetSettingTemplateResponseType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetSettingTemplateResponseType::Noop)
+
+impl std::convert::From<String> for GetSettingTemplateResponseType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetSettingTemplateResponseType> for String {
+    fn from(v: GetSettingTemplateResponseType) -> Self {
+        match v {
+            GetSettingTemplateResponseType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -23359,6 +25792,7 @@ pub struct CustomHours {
  *   7-sharedLineGroup
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23372,41 +25806,75 @@ pub enum GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectO
     User,
     #[serde(rename = "zoomRoom")]
     ZoomRoom,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
+}
+
+impl AsRef<str>
+    for GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType
+{
+    fn as_ref(&self) -> &str {
+        match self {
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::AutoReceptionist => "autoReceptionist",
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CallQueue => "callQueue",
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CommonAreaPhone => "commonAreaPhone",
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::SharedLineGroup => "sharedLineGroup",
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::User => "user",
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::ZoomRoom => "zoomRoom",
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Unknown(s) => s,
+        }
+    }
 }
 
 impl std::fmt::Display
     for GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
-GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::AutoReceptionist => "autoReceptionist",
-GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CallQueue => "callQueue",
-GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CommonAreaPhone => "commonAreaPhone",
-GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::SharedLineGroup => "sharedLineGroup",
-GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::User => "user",
-GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::ZoomRoom => "zoomRoom",
-GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop => "",
-GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::FallthroughString => "*",
+        self.as_ref().fmt(f)
+    }
 }
-.fmt(f)
+
+impl std::str::FromStr
+    for GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType
+{
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "autoReceptionist" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::AutoReceptionist,
+            "callQueue" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CallQueue,
+            "commonAreaPhone" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::CommonAreaPhone,
+            "sharedLineGroup" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::SharedLineGroup,
+            "user" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::User,
+            "zoomRoom" => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::ZoomRoom,
+            _ => GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Unknown(s.to_string()),
+        })
     }
 }
 
-impl Default
+impl From<String>
     for GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType
 {
-    fn default(
-    ) -> GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType {
-        GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Noop)
+
+impl From<GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType>
+    for String
+{
+    fn from(
+        v: GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType,
+    ) -> Self {
+        match v {
+            GetSettingTemplateResponseUserSettingsCallHandlingBusinessHoursConnectOperatorType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -23537,37 +26005,61 @@ pub struct GetSettingTemplateResponseUserSettingsDeskPhone {
  *   * `disable`: This means that the hold music is disabled.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum HoldMusic {
     #[serde(rename = "default")]
     Default,
     #[serde(rename = "disable")]
     Disable,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for HoldMusic {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for HoldMusic {
+    fn as_ref(&self) -> &str {
+        match self {
             HoldMusic::Default => "default",
             HoldMusic::Disable => "disable",
-            HoldMusic::Noop => "",
-            HoldMusic::FallthroughString => "*",
+            HoldMusic::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for HoldMusic {
-    fn default() -> HoldMusic {
-        HoldMusic::Noop
+impl std::fmt::Display for HoldMusic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl HoldMusic {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, HoldMusic::Noop)
+
+impl std::str::FromStr for HoldMusic {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "default" => HoldMusic::Default,
+            "disable" => HoldMusic::Disable,
+            _ => HoldMusic::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for HoldMusic {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<HoldMusic> for String {
+    fn from(v: HoldMusic) -> Self {
+        match v {
+            HoldMusic::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -23651,6 +26143,7 @@ pub struct GetSettingTemplateResponse {
  * Values: inbound, outbound, both.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum RecordingCalls {
     #[serde(rename = "both")]
     Both,
@@ -23658,33 +26151,57 @@ pub enum RecordingCalls {
     Inbound,
     #[serde(rename = "outbound")]
     Outbound,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for RecordingCalls {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for RecordingCalls {
+    fn as_ref(&self) -> &str {
+        match self {
             RecordingCalls::Both => "both",
             RecordingCalls::Inbound => "inbound",
             RecordingCalls::Outbound => "outbound",
-            RecordingCalls::Noop => "",
-            RecordingCalls::FallthroughString => "*",
+            RecordingCalls::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for RecordingCalls {
-    fn default() -> RecordingCalls {
-        RecordingCalls::Noop
+impl std::fmt::Display for RecordingCalls {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl RecordingCalls {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, RecordingCalls::Noop)
+
+impl std::str::FromStr for RecordingCalls {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "both" => RecordingCalls::Both,
+            "inbound" => RecordingCalls::Inbound,
+            "outbound" => RecordingCalls::Outbound,
+            _ => RecordingCalls::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for RecordingCalls {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<RecordingCalls> for String {
+    fn from(v: RecordingCalls) -> Self {
+        match v {
+            RecordingCalls::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -23762,37 +26279,61 @@ pub struct UpdateSettingTemplateRequest {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PhoneUserCallLogsType {
     #[serde(rename = "all")]
     All,
     #[serde(rename = "missed")]
     Missed,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PhoneUserCallLogsType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PhoneUserCallLogsType {
+    fn as_ref(&self) -> &str {
+        match self {
             PhoneUserCallLogsType::All => "all",
             PhoneUserCallLogsType::Missed => "missed",
-            PhoneUserCallLogsType::Noop => "",
-            PhoneUserCallLogsType::FallthroughString => "*",
+            PhoneUserCallLogsType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PhoneUserCallLogsType {
-    fn default() -> PhoneUserCallLogsType {
-        PhoneUserCallLogsType::Noop
+impl std::fmt::Display for PhoneUserCallLogsType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl PhoneUserCallLogsType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PhoneUserCallLogsType::Noop)
+
+impl std::str::FromStr for PhoneUserCallLogsType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => PhoneUserCallLogsType::All,
+            "missed" => PhoneUserCallLogsType::Missed,
+            _ => PhoneUserCallLogsType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PhoneUserCallLogsType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PhoneUserCallLogsType> for String {
+    fn from(v: PhoneUserCallLogsType) -> Self {
+        match v {
+            PhoneUserCallLogsType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -23800,23 +26341,61 @@ impl PhoneUserCallLogsType {
  * Enables you to sort call logs by start or end time. Choose the sort time value. Values include `startTime` or `endTime`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum TimeType {
     #[serde(rename = "endTime")]
     EndTime,
     #[serde(rename = "startTime")]
     StartTime,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for TimeType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for TimeType {
+    fn as_ref(&self) -> &str {
+        match self {
             TimeType::EndTime => "endTime",
             TimeType::StartTime => "startTime",
-            TimeType::FallthroughString => "*",
+            TimeType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for TimeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for TimeType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "endTime" => TimeType::EndTime,
+            "startTime" => TimeType::StartTime,
+            _ => TimeType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for TimeType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<TimeType> for String {
+    fn from(v: TimeType) -> Self {
+        match v {
+            TimeType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -23877,6 +26456,7 @@ pub struct ForwardedTo {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PhoneUserCallLogsResponseForwardedByExtensionType {
     #[serde(rename = "autoReceptionist")]
     AutoReceptionist,
@@ -23888,15 +26468,15 @@ pub enum PhoneUserCallLogsResponseForwardedByExtensionType {
     SharedLineGroup,
     #[serde(rename = "user")]
     User,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PhoneUserCallLogsResponseForwardedByExtensionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PhoneUserCallLogsResponseForwardedByExtensionType {
+    fn as_ref(&self) -> &str {
+        match self {
             PhoneUserCallLogsResponseForwardedByExtensionType::AutoReceptionist => {
                 "autoReceptionist"
             }
@@ -23904,24 +26484,49 @@ impl std::fmt::Display for PhoneUserCallLogsResponseForwardedByExtensionType {
             PhoneUserCallLogsResponseForwardedByExtensionType::CommonAreaPhone => "commonAreaPhone",
             PhoneUserCallLogsResponseForwardedByExtensionType::SharedLineGroup => "sharedLineGroup",
             PhoneUserCallLogsResponseForwardedByExtensionType::User => "user",
-            PhoneUserCallLogsResponseForwardedByExtensionType::Noop => "",
-            PhoneUserCallLogsResponseForwardedByExtensionType::FallthroughString => "*",
+            PhoneUserCallLogsResponseForwardedByExtensionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PhoneUserCallLogsResponseForwardedByExtensionType {
-    fn default() -> PhoneUserCallLogsResponseForwardedByExtensionType {
-        PhoneUserCallLogsResponseForwardedByExtensionType::Noop
+impl std::fmt::Display for PhoneUserCallLogsResponseForwardedByExtensionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl PhoneUserCallLogsResponseForwardedByExtensionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(
-            self,
-            PhoneUserCallLogsResponseForwardedByExtensionType::Noop
-        )
+
+impl std::str::FromStr for PhoneUserCallLogsResponseForwardedByExtensionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "autoReceptionist" => {
+                PhoneUserCallLogsResponseForwardedByExtensionType::AutoReceptionist
+            }
+            "callQueue" => PhoneUserCallLogsResponseForwardedByExtensionType::CallQueue,
+            "commonAreaPhone" => PhoneUserCallLogsResponseForwardedByExtensionType::CommonAreaPhone,
+            "sharedLineGroup" => PhoneUserCallLogsResponseForwardedByExtensionType::SharedLineGroup,
+            "user" => PhoneUserCallLogsResponseForwardedByExtensionType::User,
+            _ => PhoneUserCallLogsResponseForwardedByExtensionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PhoneUserCallLogsResponseForwardedByExtensionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PhoneUserCallLogsResponseForwardedByExtensionType> for String {
+    fn from(v: PhoneUserCallLogsResponseForwardedByExtensionType) -> Self {
+        match v {
+            PhoneUserCallLogsResponseForwardedByExtensionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -24565,6 +27170,7 @@ pub struct PhoneUserRecordingsResponse {
  * Status of the voice mail
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PhoneUserVoiceMailsStatus {
     #[serde(rename = "all")]
     All,
@@ -24572,19 +27178,57 @@ pub enum PhoneUserVoiceMailsStatus {
     Read,
     #[serde(rename = "unread")]
     Unread,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PhoneUserVoiceMailsStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PhoneUserVoiceMailsStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             PhoneUserVoiceMailsStatus::All => "all",
             PhoneUserVoiceMailsStatus::Read => "read",
             PhoneUserVoiceMailsStatus::Unread => "unread",
-            PhoneUserVoiceMailsStatus::FallthroughString => "*",
+            PhoneUserVoiceMailsStatus::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for PhoneUserVoiceMailsStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PhoneUserVoiceMailsStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "all" => PhoneUserVoiceMailsStatus::All,
+            "read" => PhoneUserVoiceMailsStatus::Read,
+            "unread" => PhoneUserVoiceMailsStatus::Unread,
+            _ => PhoneUserVoiceMailsStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PhoneUserVoiceMailsStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<PhoneUserVoiceMailsStatus> for String {
+    fn from(v: PhoneUserVoiceMailsStatus) -> Self {
+        match v {
+            PhoneUserVoiceMailsStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -24598,37 +27242,61 @@ impl Default for PhoneUserVoiceMailsStatus {
  * Status of the voice mail. Can be either 'read' or 'unread'
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum PhoneUserVoiceMailsResponseStatus {
     #[serde(rename = "read")]
     Read,
     #[serde(rename = "unread")]
     Unread,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for PhoneUserVoiceMailsResponseStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for PhoneUserVoiceMailsResponseStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             PhoneUserVoiceMailsResponseStatus::Read => "read",
             PhoneUserVoiceMailsResponseStatus::Unread => "unread",
-            PhoneUserVoiceMailsResponseStatus::Noop => "",
-            PhoneUserVoiceMailsResponseStatus::FallthroughString => "*",
+            PhoneUserVoiceMailsResponseStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for PhoneUserVoiceMailsResponseStatus {
-    fn default() -> PhoneUserVoiceMailsResponseStatus {
-        PhoneUserVoiceMailsResponseStatus::Noop
+impl std::fmt::Display for PhoneUserVoiceMailsResponseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for PhoneUserVoiceMailsResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "read" => PhoneUserVoiceMailsResponseStatus::Read,
+            "unread" => PhoneUserVoiceMailsResponseStatus::Unread,
+            _ => PhoneUserVoiceMailsResponseStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for PhoneUserVoiceMailsResponseStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl PhoneUserVoiceMailsResponseStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, PhoneUserVoiceMailsResponseStatus::Noop)
+
+impl std::convert::From<PhoneUserVoiceMailsResponseStatus> for String {
+    fn from(v: PhoneUserVoiceMailsResponseStatus) -> Self {
+        match v {
+            PhoneUserVoiceMailsResponseStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -24939,6 +27607,7 @@ pub struct UpdateUserSettingRequest {
  *   *`contactCenter`
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum CallType {
     #[serde(rename = "contactCenter")]
     ContactCenter,
@@ -24950,35 +27619,61 @@ pub enum CallType {
     Tollfree,
     #[serde(rename = "voip")]
     Voip,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for CallType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for CallType {
+    fn as_ref(&self) -> &str {
+        match self {
             CallType::ContactCenter => "contactCenter",
             CallType::International => "international",
             CallType::Pstn => "pstn",
             CallType::Tollfree => "tollfree",
             CallType::Voip => "voip",
-            CallType::Noop => "",
-            CallType::FallthroughString => "*",
+            CallType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for CallType {
-    fn default() -> CallType {
-        CallType::Noop
+impl std::fmt::Display for CallType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for CallType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "contactCenter" => CallType::ContactCenter,
+            "international" => CallType::International,
+            "pstn" => CallType::Pstn,
+            "tollfree" => CallType::Tollfree,
+            "voip" => CallType::Voip,
+            _ => CallType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for CallType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl CallType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, CallType::Noop)
+
+impl std::convert::From<CallType> for String {
+    fn from(v: CallType) -> Self {
+        match v {
+            CallType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -25340,37 +28035,61 @@ pub struct AssignCallingPlanRequest {
  * The owner type: `user` or `call queue`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetPhoneRecordingsResponseOwnerType {
     #[serde(rename = "call queue")]
     CallQueue,
     #[serde(rename = "user")]
     User,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetPhoneRecordingsResponseOwnerType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetPhoneRecordingsResponseOwnerType {
+    fn as_ref(&self) -> &str {
+        match self {
             GetPhoneRecordingsResponseOwnerType::CallQueue => "call queue",
             GetPhoneRecordingsResponseOwnerType::User => "user",
-            GetPhoneRecordingsResponseOwnerType::Noop => "",
-            GetPhoneRecordingsResponseOwnerType::FallthroughString => "*",
+            GetPhoneRecordingsResponseOwnerType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetPhoneRecordingsResponseOwnerType {
-    fn default() -> GetPhoneRecordingsResponseOwnerType {
-        GetPhoneRecordingsResponseOwnerType::Noop
+impl std::fmt::Display for GetPhoneRecordingsResponseOwnerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for GetPhoneRecordingsResponseOwnerType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "call queue" => GetPhoneRecordingsResponseOwnerType::CallQueue,
+            "user" => GetPhoneRecordingsResponseOwnerType::User,
+            _ => GetPhoneRecordingsResponseOwnerType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetPhoneRecordingsResponseOwnerType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl GetPhoneRecordingsResponseOwnerType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetPhoneRecordingsResponseOwnerType::Noop)
+
+impl std::convert::From<GetPhoneRecordingsResponseOwnerType> for String {
+    fn from(v: GetPhoneRecordingsResponseOwnerType) -> Self {
+        match v {
+            GetPhoneRecordingsResponseOwnerType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -25415,37 +28134,61 @@ pub struct GetPhoneRecordingsResponseOwner {
  * The direction of the call. Values: `inbound` or `outbound`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Direction {
     #[serde(rename = "inbound")]
     Inbound,
     #[serde(rename = "outbound")]
     Outbound,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Direction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Direction {
+    fn as_ref(&self) -> &str {
+        match self {
             Direction::Inbound => "inbound",
             Direction::Outbound => "outbound",
-            Direction::Noop => "",
-            Direction::FallthroughString => "*",
+            Direction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Direction {
-    fn default() -> Direction {
-        Direction::Noop
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Direction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Direction::Noop)
+
+impl std::str::FromStr for Direction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "inbound" => Direction::Inbound,
+            "outbound" => Direction::Outbound,
+            _ => Direction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Direction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Direction> for String {
+    fn from(v: Direction) -> Self {
+        match v {
+            Direction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -25809,34 +28552,57 @@ pub struct AccountPlanBaseDeleteRequest {
  * The action that needs to be taken for this sub account. Value must be set to "cancel".
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum AccountPlanAddonCancelRequestAction {
     #[serde(rename = "cancel")]
     Cancel,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for AccountPlanAddonCancelRequestAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for AccountPlanAddonCancelRequestAction {
+    fn as_ref(&self) -> &str {
+        match self {
             AccountPlanAddonCancelRequestAction::Cancel => "cancel",
-            AccountPlanAddonCancelRequestAction::Noop => "",
-            AccountPlanAddonCancelRequestAction::FallthroughString => "*",
+            AccountPlanAddonCancelRequestAction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for AccountPlanAddonCancelRequestAction {
-    fn default() -> AccountPlanAddonCancelRequestAction {
-        AccountPlanAddonCancelRequestAction::Noop
+impl std::fmt::Display for AccountPlanAddonCancelRequestAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl AccountPlanAddonCancelRequestAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, AccountPlanAddonCancelRequestAction::Noop)
+
+impl std::str::FromStr for AccountPlanAddonCancelRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cancel" => AccountPlanAddonCancelRequestAction::Cancel,
+            _ => AccountPlanAddonCancelRequestAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for AccountPlanAddonCancelRequestAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<AccountPlanAddonCancelRequestAction> for String {
+    fn from(v: AccountPlanAddonCancelRequestAction) -> Self {
+        match v {
+            AccountPlanAddonCancelRequestAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -27310,6 +30076,7 @@ impl UpdateGroupSettingsRequestOneOf {
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UpdateGroupSettingsOption {
     #[serde(rename = "meeting_authentication")]
     MeetingAuthentication,
@@ -27317,33 +30084,57 @@ pub enum UpdateGroupSettingsOption {
     MeetingSecurity,
     #[serde(rename = "recording_authentication")]
     RecordingAuthentication,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UpdateGroupSettingsOption {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UpdateGroupSettingsOption {
+    fn as_ref(&self) -> &str {
+        match self {
             UpdateGroupSettingsOption::MeetingAuthentication => "meeting_authentication",
             UpdateGroupSettingsOption::MeetingSecurity => "meeting_security",
             UpdateGroupSettingsOption::RecordingAuthentication => "recording_authentication",
-            UpdateGroupSettingsOption::Noop => "",
-            UpdateGroupSettingsOption::FallthroughString => "*",
+            UpdateGroupSettingsOption::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for UpdateGroupSettingsOption {
-    fn default() -> UpdateGroupSettingsOption {
-        UpdateGroupSettingsOption::Noop
+impl std::fmt::Display for UpdateGroupSettingsOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl UpdateGroupSettingsOption {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UpdateGroupSettingsOption::Noop)
+
+impl std::str::FromStr for UpdateGroupSettingsOption {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "meeting_authentication" => UpdateGroupSettingsOption::MeetingAuthentication,
+            "meeting_security" => UpdateGroupSettingsOption::MeetingSecurity,
+            "recording_authentication" => UpdateGroupSettingsOption::RecordingAuthentication,
+            _ => UpdateGroupSettingsOption::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UpdateGroupSettingsOption {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UpdateGroupSettingsOption> for String {
+    fn from(v: UpdateGroupSettingsOption) -> Self {
+        match v {
+            UpdateGroupSettingsOption::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -28475,6 +31266,7 @@ pub struct SwitchUserAccountRequest {
  *   	`id`, `status`, `file_size`, `recording_type`, and `play_url`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesFileType {
     #[serde(rename = "CC")]
     Cc,
@@ -28490,15 +31282,15 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesFileType {
     Tb,
     #[serde(rename = "TRANSCRIPT")]
     Transcript,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesFileType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetAccountCloudRecordingResponseMeetingsFilesFileType {
+    fn as_ref(&self) -> &str {
+        match self {
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Cc => "CC",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Chat => "CHAT",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Csv => "CSV",
@@ -28506,24 +31298,49 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesFileType
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Mp4 => "MP4",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Tb => "TB",
             GetAccountCloudRecordingResponseMeetingsFilesFileType::Transcript => "TRANSCRIPT",
-            GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesFileType::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetAccountCloudRecordingResponseMeetingsFilesFileType {
-    fn default() -> GetAccountCloudRecordingResponseMeetingsFilesFileType {
-        GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop
+impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesFileType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl GetAccountCloudRecordingResponseMeetingsFilesFileType {
-    pub fn is_noop(&self) -> bool {
-        matches!(
-            self,
-            GetAccountCloudRecordingResponseMeetingsFilesFileType::Noop
-        )
+
+impl std::str::FromStr for GetAccountCloudRecordingResponseMeetingsFilesFileType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "CC" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Cc,
+            "CHAT" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Chat,
+            "CSV" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Csv,
+            "M4A" => GetAccountCloudRecordingResponseMeetingsFilesFileType::M4A,
+            "MP4" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Mp4,
+            "TB" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Tb,
+            "TRANSCRIPT" => GetAccountCloudRecordingResponseMeetingsFilesFileType::Transcript,
+            _ => GetAccountCloudRecordingResponseMeetingsFilesFileType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetAccountCloudRecordingResponseMeetingsFilesFileType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetAccountCloudRecordingResponseMeetingsFilesFileType> for String {
+    fn from(v: GetAccountCloudRecordingResponseMeetingsFilesFileType) -> Self {
+        match v {
+            GetAccountCloudRecordingResponseMeetingsFilesFileType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -28531,40 +31348,61 @@ impl GetAccountCloudRecordingResponseMeetingsFilesFileType {
  * The status of the recording, either `completed` or `processing`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesStatus {
     #[serde(rename = "completed")]
     Completed,
     #[serde(rename = "processing")]
     Processing,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetAccountCloudRecordingResponseMeetingsFilesStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Completed => "completed",
             GetAccountCloudRecordingResponseMeetingsFilesStatus::Processing => "processing",
-            GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesStatus::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetAccountCloudRecordingResponseMeetingsFilesStatus {
-    fn default() -> GetAccountCloudRecordingResponseMeetingsFilesStatus {
-        GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop
+impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl GetAccountCloudRecordingResponseMeetingsFilesStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(
-            self,
-            GetAccountCloudRecordingResponseMeetingsFilesStatus::Noop
-        )
+
+impl std::str::FromStr for GetAccountCloudRecordingResponseMeetingsFilesStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "completed" => GetAccountCloudRecordingResponseMeetingsFilesStatus::Completed,
+            "processing" => GetAccountCloudRecordingResponseMeetingsFilesStatus::Processing,
+            _ => GetAccountCloudRecordingResponseMeetingsFilesStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetAccountCloudRecordingResponseMeetingsFilesStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetAccountCloudRecordingResponseMeetingsFilesStatus> for String {
+    fn from(v: GetAccountCloudRecordingResponseMeetingsFilesStatus) -> Self {
+        match v {
+            GetAccountCloudRecordingResponseMeetingsFilesStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -28587,6 +31425,7 @@ impl GetAccountCloudRecordingResponseMeetingsFilesStatus {
  *   
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetAccountCloudRecordingResponseMeetingsFilesType {
     #[serde(rename = "active_speaker")]
     ActiveSpeaker,
@@ -28616,15 +31455,15 @@ pub enum GetAccountCloudRecordingResponseMeetingsFilesType {
     Thumbnail,
     #[serde(rename = "timeline")]
     Timeline,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetAccountCloudRecordingResponseMeetingsFilesType {
+    fn as_ref(&self) -> &str {
+        match self {
             GetAccountCloudRecordingResponseMeetingsFilesType::ActiveSpeaker => "active_speaker",
             GetAccountCloudRecordingResponseMeetingsFilesType::AudioOnly => "audio_only",
             GetAccountCloudRecordingResponseMeetingsFilesType::AudioTranscript => {
@@ -28647,24 +31486,64 @@ impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesType {
             }
             GetAccountCloudRecordingResponseMeetingsFilesType::Thumbnail => "thumbnail",
             GetAccountCloudRecordingResponseMeetingsFilesType::Timeline => "timeline",
-            GetAccountCloudRecordingResponseMeetingsFilesType::Noop => "",
-            GetAccountCloudRecordingResponseMeetingsFilesType::FallthroughString => "*",
+            GetAccountCloudRecordingResponseMeetingsFilesType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetAccountCloudRecordingResponseMeetingsFilesType {
-    fn default() -> GetAccountCloudRecordingResponseMeetingsFilesType {
-        GetAccountCloudRecordingResponseMeetingsFilesType::Noop
+impl std::fmt::Display for GetAccountCloudRecordingResponseMeetingsFilesType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl GetAccountCloudRecordingResponseMeetingsFilesType {
-    pub fn is_noop(&self) -> bool {
-        matches!(
-            self,
-            GetAccountCloudRecordingResponseMeetingsFilesType::Noop
-        )
+
+impl std::str::FromStr for GetAccountCloudRecordingResponseMeetingsFilesType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "active_speaker" => GetAccountCloudRecordingResponseMeetingsFilesType::ActiveSpeaker,
+            "audio_only" => GetAccountCloudRecordingResponseMeetingsFilesType::AudioOnly,
+            "audio_transcript" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::AudioTranscript
+            }
+            "chat_file" => GetAccountCloudRecordingResponseMeetingsFilesType::ChatFile,
+            "closed_caption" => GetAccountCloudRecordingResponseMeetingsFilesType::ClosedCaption,
+            "gallery_view" => GetAccountCloudRecordingResponseMeetingsFilesType::GalleryView,
+            "host_video" => GetAccountCloudRecordingResponseMeetingsFilesType::HostVideo,
+            "poll" => GetAccountCloudRecordingResponseMeetingsFilesType::Poll,
+            "shared_screen" => GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreen,
+            "shared_screen_with_gallery_view" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithGalleryView
+            }
+            "shared_screen_with_speaker_view" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithSpeakerView
+            }
+            "shared_screen_with_speaker_view(CC)" => {
+                GetAccountCloudRecordingResponseMeetingsFilesType::SharedScreenWithSpeakerViewCc
+            }
+            "thumbnail" => GetAccountCloudRecordingResponseMeetingsFilesType::Thumbnail,
+            "timeline" => GetAccountCloudRecordingResponseMeetingsFilesType::Timeline,
+            _ => GetAccountCloudRecordingResponseMeetingsFilesType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetAccountCloudRecordingResponseMeetingsFilesType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetAccountCloudRecordingResponseMeetingsFilesType> for String {
+    fn from(v: GetAccountCloudRecordingResponseMeetingsFilesType) -> Self {
+        match v {
+            GetAccountCloudRecordingResponseMeetingsFilesType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -29040,23 +31919,61 @@ pub struct UpdateZrAccProfileRequest {
  *   `signage`: View digital signage settings of the Zoom Rooms Account.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum SettingType {
     #[serde(rename = "alert")]
     Alert,
     #[serde(rename = "meeting")]
     Meeting,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for SettingType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for SettingType {
+    fn as_ref(&self) -> &str {
+        match self {
             SettingType::Alert => "alert",
             SettingType::Meeting => "meeting",
-            SettingType::FallthroughString => "*",
+            SettingType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for SettingType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for SettingType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "alert" => SettingType::Alert,
+            "meeting" => SettingType::Meeting,
+            _ => SettingType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for SettingType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<SettingType> for String {
+    fn from(v: SettingType) -> Self {
+        match v {
+            SettingType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
@@ -29071,6 +31988,7 @@ impl Default for SettingType {
  *   `country`, `states`, `city`, `campus`, `building`, `floor`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListZrLocationsResponseType {
     #[serde(rename = "building")]
     Building,
@@ -29084,36 +32002,63 @@ pub enum ListZrLocationsResponseType {
     Floor,
     #[serde(rename = "states")]
     States,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ListZrLocationsResponseType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ListZrLocationsResponseType {
+    fn as_ref(&self) -> &str {
+        match self {
             ListZrLocationsResponseType::Building => "building",
             ListZrLocationsResponseType::Campus => "campus",
             ListZrLocationsResponseType::City => "city",
             ListZrLocationsResponseType::Country => "country",
             ListZrLocationsResponseType::Floor => "floor",
             ListZrLocationsResponseType::States => "states",
-            ListZrLocationsResponseType::Noop => "",
-            ListZrLocationsResponseType::FallthroughString => "*",
+            ListZrLocationsResponseType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ListZrLocationsResponseType {
-    fn default() -> ListZrLocationsResponseType {
-        ListZrLocationsResponseType::Noop
+impl std::fmt::Display for ListZrLocationsResponseType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ListZrLocationsResponseType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "building" => ListZrLocationsResponseType::Building,
+            "campus" => ListZrLocationsResponseType::Campus,
+            "city" => ListZrLocationsResponseType::City,
+            "country" => ListZrLocationsResponseType::Country,
+            "floor" => ListZrLocationsResponseType::Floor,
+            "states" => ListZrLocationsResponseType::States,
+            _ => ListZrLocationsResponseType::Unknown(s.to_string()),
+        })
     }
 }
-impl ListZrLocationsResponseType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ListZrLocationsResponseType::Noop)
+
+impl std::convert::From<String> for ListZrLocationsResponseType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ListZrLocationsResponseType> for String {
+    fn from(v: ListZrLocationsResponseType) -> Self {
+        match v {
+            ListZrLocationsResponseType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -29439,6 +32384,7 @@ pub struct UpdateRoomProfileRequest {
  * Type of the device. The value of this field can be one of the following:<br>`Zoom Rooms Computer`,<br> `Controller`, `Scheduling Display`, `Zoom Rooms Control System`, `Companion Whiteboard`
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum DeviceType {
     #[serde(rename = "Companion Whiteboard")]
     CompanionWhiteboard,
@@ -29450,35 +32396,61 @@ pub enum DeviceType {
     ZoomRoomsComputer,
     #[serde(rename = "Zoom Rooms Control System")]
     ZoomRoomsControlSystem,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for DeviceType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for DeviceType {
+    fn as_ref(&self) -> &str {
+        match self {
             DeviceType::CompanionWhiteboard => "Companion Whiteboard",
             DeviceType::Controller => "Controller",
             DeviceType::SchedulingDisplay => "Scheduling Display",
             DeviceType::ZoomRoomsComputer => "Zoom Rooms Computer",
             DeviceType::ZoomRoomsControlSystem => "Zoom Rooms Control System",
-            DeviceType::Noop => "",
-            DeviceType::FallthroughString => "*",
+            DeviceType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for DeviceType {
-    fn default() -> DeviceType {
-        DeviceType::Noop
+impl std::fmt::Display for DeviceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl DeviceType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, DeviceType::Noop)
+
+impl std::str::FromStr for DeviceType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Companion Whiteboard" => DeviceType::CompanionWhiteboard,
+            "Controller" => DeviceType::Controller,
+            "Scheduling Display" => DeviceType::SchedulingDisplay,
+            "Zoom Rooms Computer" => DeviceType::ZoomRoomsComputer,
+            "Zoom Rooms Control System" => DeviceType::ZoomRoomsControlSystem,
+            _ => DeviceType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for DeviceType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<DeviceType> for String {
+    fn from(v: DeviceType) -> Self {
+        match v {
+            DeviceType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -29486,37 +32458,61 @@ impl DeviceType {
  * Status of the device. The value can be either `Online` or `Offline`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListZrDevicesResponseStatus {
     #[serde(rename = "Offline")]
     Offline,
     #[serde(rename = "Online")]
     Online,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ListZrDevicesResponseStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ListZrDevicesResponseStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             ListZrDevicesResponseStatus::Offline => "Offline",
             ListZrDevicesResponseStatus::Online => "Online",
-            ListZrDevicesResponseStatus::Noop => "",
-            ListZrDevicesResponseStatus::FallthroughString => "*",
+            ListZrDevicesResponseStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ListZrDevicesResponseStatus {
-    fn default() -> ListZrDevicesResponseStatus {
-        ListZrDevicesResponseStatus::Noop
+impl std::fmt::Display for ListZrDevicesResponseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ListZrDevicesResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Offline" => ListZrDevicesResponseStatus::Offline,
+            "Online" => ListZrDevicesResponseStatus::Online,
+            _ => ListZrDevicesResponseStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ListZrDevicesResponseStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ListZrDevicesResponseStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ListZrDevicesResponseStatus::Noop)
+
+impl std::convert::From<ListZrDevicesResponseStatus> for String {
+    fn from(v: ListZrDevicesResponseStatus) -> Self {
+        match v {
+            ListZrDevicesResponseStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -29790,37 +32786,61 @@ pub struct ListMeetingSatisfactionResponse {
  * State of the device. The value should be either `assigned` to list devices that have been assigned to user(s) or `unassigned` to list devices that have not yet been assigned to any user in the Zoom account.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListPhoneDevicesType {
     #[serde(rename = "assigned")]
     Assigned,
     #[serde(rename = "unassigned")]
     Unassigned,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ListPhoneDevicesType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ListPhoneDevicesType {
+    fn as_ref(&self) -> &str {
+        match self {
             ListPhoneDevicesType::Assigned => "assigned",
             ListPhoneDevicesType::Unassigned => "unassigned",
-            ListPhoneDevicesType::Noop => "",
-            ListPhoneDevicesType::FallthroughString => "*",
+            ListPhoneDevicesType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ListPhoneDevicesType {
-    fn default() -> ListPhoneDevicesType {
-        ListPhoneDevicesType::Noop
+impl std::fmt::Display for ListPhoneDevicesType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl ListPhoneDevicesType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ListPhoneDevicesType::Noop)
+
+impl std::str::FromStr for ListPhoneDevicesType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "assigned" => ListPhoneDevicesType::Assigned,
+            "unassigned" => ListPhoneDevicesType::Unassigned,
+            _ => ListPhoneDevicesType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ListPhoneDevicesType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ListPhoneDevicesType> for String {
+    fn from(v: ListPhoneDevicesType) -> Self {
+        match v {
+            ListPhoneDevicesType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -29859,37 +32879,61 @@ pub struct AddAutoReceptionistResponse {
  * Status of the device. The value is either `online` or `offline`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListPhoneDevicesResponseStatus {
     #[serde(rename = "offline")]
     Offline,
     #[serde(rename = "online")]
     Online,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ListPhoneDevicesResponseStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ListPhoneDevicesResponseStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             ListPhoneDevicesResponseStatus::Offline => "offline",
             ListPhoneDevicesResponseStatus::Online => "online",
-            ListPhoneDevicesResponseStatus::Noop => "",
-            ListPhoneDevicesResponseStatus::FallthroughString => "*",
+            ListPhoneDevicesResponseStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ListPhoneDevicesResponseStatus {
-    fn default() -> ListPhoneDevicesResponseStatus {
-        ListPhoneDevicesResponseStatus::Noop
+impl std::fmt::Display for ListPhoneDevicesResponseStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl ListPhoneDevicesResponseStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ListPhoneDevicesResponseStatus::Noop)
+
+impl std::str::FromStr for ListPhoneDevicesResponseStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "offline" => ListPhoneDevicesResponseStatus::Offline,
+            "online" => ListPhoneDevicesResponseStatus::Online,
+            _ => ListPhoneDevicesResponseStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ListPhoneDevicesResponseStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ListPhoneDevicesResponseStatus> for String {
+    fn from(v: ListPhoneDevicesResponseStatus) -> Self {
+        match v {
+            ListPhoneDevicesResponseStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -30071,6 +33115,7 @@ pub struct GetDeviceResponseAssignee {
  *   
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetDeviceResponseProvisionType {
     #[serde(rename = "assisted")]
     Assisted,
@@ -30078,33 +33123,57 @@ pub enum GetDeviceResponseProvisionType {
     Manual,
     #[serde(rename = "ztp")]
     Ztp,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetDeviceResponseProvisionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetDeviceResponseProvisionType {
+    fn as_ref(&self) -> &str {
+        match self {
             GetDeviceResponseProvisionType::Assisted => "assisted",
             GetDeviceResponseProvisionType::Manual => "manual",
             GetDeviceResponseProvisionType::Ztp => "ztp",
-            GetDeviceResponseProvisionType::Noop => "",
-            GetDeviceResponseProvisionType::FallthroughString => "*",
+            GetDeviceResponseProvisionType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetDeviceResponseProvisionType {
-    fn default() -> GetDeviceResponseProvisionType {
-        GetDeviceResponseProvisionType::Noop
+impl std::fmt::Display for GetDeviceResponseProvisionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl GetDeviceResponseProvisionType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetDeviceResponseProvisionType::Noop)
+
+impl std::str::FromStr for GetDeviceResponseProvisionType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "assisted" => GetDeviceResponseProvisionType::Assisted,
+            "manual" => GetDeviceResponseProvisionType::Manual,
+            "ztp" => GetDeviceResponseProvisionType::Ztp,
+            _ => GetDeviceResponseProvisionType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetDeviceResponseProvisionType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetDeviceResponseProvisionType> for String {
+    fn from(v: GetDeviceResponseProvisionType) -> Self {
+        match v {
+            GetDeviceResponseProvisionType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -30839,37 +33908,61 @@ pub struct CreateCallQueueResponse {
  *   `user`: Regular user without the privileges of a manager.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Level {
     #[serde(rename = "manager")]
     Manager,
     #[serde(rename = "user")]
     User,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Level {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Level {
+    fn as_ref(&self) -> &str {
+        match self {
             Level::Manager => "manager",
             Level::User => "user",
-            Level::Noop => "",
-            Level::FallthroughString => "*",
+            Level::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Level {
-    fn default() -> Level {
-        Level::Noop
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for Level {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "manager" => Level::Manager,
+            "user" => Level::User,
+            _ => Level::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Level {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl Level {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Level::Noop)
+
+impl std::convert::From<Level> for String {
+    fn from(v: Level) -> Self {
+        match v {
+            Level::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -31806,6 +34899,7 @@ pub struct InternationalCall {
  * Allow Hot Desking feature to current device.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     #[serde(rename = "off")]
     Off,
@@ -31813,33 +34907,57 @@ pub enum GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
     On,
     #[serde(rename = "unsupported")]
     Unsupported,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
+    fn as_ref(&self) -> &str {
+        match self {
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Off => "off",
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::On => "on",
             GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unsupported => "unsupported",
-            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop => "",
-            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::FallthroughString => "*",
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
-    fn default() -> GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
-        GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop
+impl std::fmt::Display for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Noop)
+
+impl std::str::FromStr for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "off" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Off,
+            "on" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::On,
+            "unsupported" => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unsupported,
+            _ => GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for GetCommonAreaPhoneResponsePolicyHotDeskingStatus {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<GetCommonAreaPhoneResponsePolicyHotDeskingStatus> for String {
+    fn from(v: GetCommonAreaPhoneResponsePolicyHotDeskingStatus) -> Self {
+        match v {
+            GetCommonAreaPhoneResponsePolicyHotDeskingStatus::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -32113,37 +35231,61 @@ pub struct AssignCallingPlansCommonAreaPhoneResponseData {
  *   `prefix`: Indicates that all numbers starting with prefix that is shown in the `phone_number` field are blocked.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum MatchType {
     #[serde(rename = "phoneNumber")]
     PhoneNumber,
     #[serde(rename = "prefix")]
     Prefix,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for MatchType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for MatchType {
+    fn as_ref(&self) -> &str {
+        match self {
             MatchType::PhoneNumber => "phoneNumber",
             MatchType::Prefix => "prefix",
-            MatchType::Noop => "",
-            MatchType::FallthroughString => "*",
+            MatchType::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for MatchType {
-    fn default() -> MatchType {
-        MatchType::Noop
+impl std::fmt::Display for MatchType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl MatchType {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, MatchType::Noop)
+
+impl std::str::FromStr for MatchType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "phoneNumber" => MatchType::PhoneNumber,
+            "prefix" => MatchType::Prefix,
+            _ => MatchType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for MatchType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<MatchType> for String {
+    fn from(v: MatchType) -> Self {
+        match v {
+            MatchType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -33316,6 +36458,7 @@ pub struct AddCalloutCountriesResponse {
  * Specify the language in which this phone number should be displayed. Currently, the only supported languages are `en-GB`, `en-US`, `de-DE`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Languages {
     #[serde(rename = "de-DE")]
     De,
@@ -33323,33 +36466,57 @@ pub enum Languages {
     EnGb,
     #[serde(rename = "en-US")]
     EnUs,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Languages {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Languages {
+    fn as_ref(&self) -> &str {
+        match self {
             Languages::De => "de-DE",
             Languages::EnGb => "en-GB",
             Languages::EnUs => "en-US",
-            Languages::Noop => "",
-            Languages::FallthroughString => "*",
+            Languages::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Languages {
-    fn default() -> Languages {
-        Languages::Noop
+impl std::fmt::Display for Languages {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Languages {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Languages::Noop)
+
+impl std::str::FromStr for Languages {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "de-DE" => Languages::De,
+            "en-GB" => Languages::EnGb,
+            "en-US" => Languages::EnUs,
+            _ => Languages::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Languages {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Languages> for String {
+    fn from(v: Languages) -> Self {
+        match v {
+            Languages::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -33401,8 +36568,8 @@ pub struct InternalNumbers {
     /**
      * Specify the language in which this phone number should be displayed. Currently, the only supported languages are `en-GB`, `en-US`, `de-DE`.
      */
-    #[serde(default, skip_serializing_if = "Languages::is_noop")]
-    pub languages: Languages,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub languages: Option<Languages>,
     /**
      * User's first name.
      */
@@ -33536,8 +36703,8 @@ pub struct AddInternalNumbersResponse {
     /**
      * Specify the language in which this phone number should be displayed. Currently, the only supported languages are `en-GB`, `en-US`, `de-DE`.
      */
-    #[serde(default, skip_serializing_if = "Languages::is_noop")]
-    pub languages: Languages,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub languages: Option<Languages>,
     /**
      * User's first name.
      */
@@ -33585,6 +36752,7 @@ pub struct AddInternalNumbersResponseData {
  *   `cancel`: Cancel an ongoing upgrade or downgrade process.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ChangeZoomRoomsAppVersionRequestAction {
     #[serde(rename = "cancel")]
     Cancel,
@@ -33592,33 +36760,57 @@ pub enum ChangeZoomRoomsAppVersionRequestAction {
     Downgrade,
     #[serde(rename = "upgrade")]
     Upgrade,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ChangeZoomRoomsAppVersionRequestAction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ChangeZoomRoomsAppVersionRequestAction {
+    fn as_ref(&self) -> &str {
+        match self {
             ChangeZoomRoomsAppVersionRequestAction::Cancel => "cancel",
             ChangeZoomRoomsAppVersionRequestAction::Downgrade => "downgrade",
             ChangeZoomRoomsAppVersionRequestAction::Upgrade => "upgrade",
-            ChangeZoomRoomsAppVersionRequestAction::Noop => "",
-            ChangeZoomRoomsAppVersionRequestAction::FallthroughString => "*",
+            ChangeZoomRoomsAppVersionRequestAction::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for ChangeZoomRoomsAppVersionRequestAction {
-    fn default() -> ChangeZoomRoomsAppVersionRequestAction {
-        ChangeZoomRoomsAppVersionRequestAction::Noop
+impl std::fmt::Display for ChangeZoomRoomsAppVersionRequestAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ChangeZoomRoomsAppVersionRequestAction {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "cancel" => ChangeZoomRoomsAppVersionRequestAction::Cancel,
+            "downgrade" => ChangeZoomRoomsAppVersionRequestAction::Downgrade,
+            "upgrade" => ChangeZoomRoomsAppVersionRequestAction::Upgrade,
+            _ => ChangeZoomRoomsAppVersionRequestAction::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ChangeZoomRoomsAppVersionRequestAction {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
     }
 }
-impl ChangeZoomRoomsAppVersionRequestAction {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, ChangeZoomRoomsAppVersionRequestAction::Noop)
+
+impl std::convert::From<ChangeZoomRoomsAppVersionRequestAction> for String {
+    fn from(v: ChangeZoomRoomsAppVersionRequestAction) -> Self {
+        match v {
+            ChangeZoomRoomsAppVersionRequestAction::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -33703,6 +36895,7 @@ pub struct ListWebinarTemplatesResponse {
  *   * `Away` to `Available`
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum UpdatePresenceStatusRequest {
     #[serde(rename = "Available")]
     Available,
@@ -33718,15 +36911,15 @@ pub enum UpdatePresenceStatusRequest {
     OnACall,
     #[serde(rename = "Presenting")]
     Presenting,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for UpdatePresenceStatusRequest {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for UpdatePresenceStatusRequest {
+    fn as_ref(&self) -> &str {
+        match self {
             UpdatePresenceStatusRequest::Available => "Available",
             UpdatePresenceStatusRequest::Away => "Away",
             UpdatePresenceStatusRequest::DoNoDisturb => "Do_No_Disturb",
@@ -33734,21 +36927,49 @@ impl std::fmt::Display for UpdatePresenceStatusRequest {
             UpdatePresenceStatusRequest::InCalendarEvent => "In_Calendar_Event",
             UpdatePresenceStatusRequest::OnACall => "On_A_Call",
             UpdatePresenceStatusRequest::Presenting => "Presenting",
-            UpdatePresenceStatusRequest::Noop => "",
-            UpdatePresenceStatusRequest::FallthroughString => "*",
+            UpdatePresenceStatusRequest::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for UpdatePresenceStatusRequest {
-    fn default() -> UpdatePresenceStatusRequest {
-        UpdatePresenceStatusRequest::Noop
+impl std::fmt::Display for UpdatePresenceStatusRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl UpdatePresenceStatusRequest {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, UpdatePresenceStatusRequest::Noop)
+
+impl std::str::FromStr for UpdatePresenceStatusRequest {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "Available" => UpdatePresenceStatusRequest::Available,
+            "Away" => UpdatePresenceStatusRequest::Away,
+            "Do_No_Disturb" => UpdatePresenceStatusRequest::DoNoDisturb,
+            "In_A_Zoom_Meeting" => UpdatePresenceStatusRequest::InAZoomMeeting,
+            "In_Calendar_Event" => UpdatePresenceStatusRequest::InCalendarEvent,
+            "On_A_Call" => UpdatePresenceStatusRequest::OnACall,
+            "Presenting" => UpdatePresenceStatusRequest::Presenting,
+            _ => UpdatePresenceStatusRequest::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for UpdatePresenceStatusRequest {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<UpdatePresenceStatusRequest> for String {
+    fn from(v: UpdatePresenceStatusRequest) -> Self {
+        match v {
+            UpdatePresenceStatusRequest::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -34636,37 +37857,61 @@ pub struct GetAccountBillingInvoiceResponse {
  *   * `NOT GOOD`: Thumbs down.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Quality {
     #[serde(rename = "GOOD")]
     Good,
     #[serde(rename = "NOT GOOD")]
     NotGood,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Quality {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Quality {
+    fn as_ref(&self) -> &str {
+        match self {
             Quality::Good => "GOOD",
             Quality::NotGood => "NOT GOOD",
-            Quality::Noop => "",
-            Quality::FallthroughString => "*",
+            Quality::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Quality {
-    fn default() -> Quality {
-        Quality::Noop
+impl std::fmt::Display for Quality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Quality {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Quality::Noop)
+
+impl std::str::FromStr for Quality {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "GOOD" => Quality::Good,
+            "NOT GOOD" => Quality::NotGood,
+            _ => Quality::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Quality {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Quality> for String {
+    fn from(v: Quality) -> Self {
+        match v {
+            Quality::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -34741,6 +37986,7 @@ pub struct ParticipantFeedbackResponse {
  * Result of the call: `call_connected` | `recorded` | `no_answer`.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum Result {
     #[serde(rename = "call_connected")]
     CallConnected,
@@ -34748,33 +37994,57 @@ pub enum Result {
     NoAnswer,
     #[serde(rename = "recorded")]
     Recorded,
-    #[serde(rename = "")]
-    Noop,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for Result {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for Result {
+    fn as_ref(&self) -> &str {
+        match self {
             Result::CallConnected => "call_connected",
             Result::NoAnswer => "no_answer",
             Result::Recorded => "recorded",
-            Result::Noop => "",
-            Result::FallthroughString => "*",
+            Result::Unknown(s) => s,
         }
-        .fmt(f)
     }
 }
 
-impl Default for Result {
-    fn default() -> Result {
-        Result::Noop
+impl std::fmt::Display for Result {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
     }
 }
-impl Result {
-    pub fn is_noop(&self) -> bool {
-        matches!(self, Result::Noop)
+
+impl std::str::FromStr for Result {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "call_connected" => Result::CallConnected,
+            "no_answer" => Result::NoAnswer,
+            "recorded" => Result::Recorded,
+            _ => Result::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for Result {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<Result> for String {
+    fn from(v: Result) -> Self {
+        match v {
+            Result::Unknown(s) => s,
+            v => v.as_ref().to_string(),
+        }
     }
 }
 
@@ -35147,23 +38417,61 @@ pub struct ListMeetingTemplatesResponseData {
  * The query date type for the `from` and `to` parameters.
  */
 #[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+#[serde(from = "String", into = "String")]
 pub enum ListArchivedFilesQueryDateType {
     #[serde(rename = "archive_complete_time")]
     ArchiveCompleteTime,
     #[serde(rename = "meeting_start_time")]
     MeetingStartTime,
-    #[serde(other)]
-    FallthroughString,
+    /**
+     * A value that is not in the API spec, as it was sent.
+     */
+    Unknown(String),
 }
 
-impl std::fmt::Display for ListArchivedFilesQueryDateType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &*self {
+impl AsRef<str> for ListArchivedFilesQueryDateType {
+    fn as_ref(&self) -> &str {
+        match self {
             ListArchivedFilesQueryDateType::ArchiveCompleteTime => "archive_complete_time",
             ListArchivedFilesQueryDateType::MeetingStartTime => "meeting_start_time",
-            ListArchivedFilesQueryDateType::FallthroughString => "*",
+            ListArchivedFilesQueryDateType::Unknown(s) => s,
+        }
+    }
+}
+
+impl std::fmt::Display for ListArchivedFilesQueryDateType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+impl std::str::FromStr for ListArchivedFilesQueryDateType {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "archive_complete_time" => ListArchivedFilesQueryDateType::ArchiveCompleteTime,
+            "meeting_start_time" => ListArchivedFilesQueryDateType::MeetingStartTime,
+            _ => ListArchivedFilesQueryDateType::Unknown(s.to_string()),
+        })
+    }
+}
+
+impl std::convert::From<String> for ListArchivedFilesQueryDateType {
+    fn from(s: String) -> Self {
+        match s.parse() {
+            Ok(v) => v,
+            Err(e) => match e {},
+        }
+    }
+}
+
+impl std::convert::From<ListArchivedFilesQueryDateType> for String {
+    fn from(v: ListArchivedFilesQueryDateType) -> Self {
+        match v {
+            ListArchivedFilesQueryDateType::Unknown(s) => s,
+            v => v.as_ref().to_string(),
         }
-        .fmt(f)
     }
 }
 
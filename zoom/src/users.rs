@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Users {
     pub client: Client,
@@ -23,46 +21,29 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `status: crate::types::UsersStatus` -- The user's status:
-     *  \* `active` — An active user.
-     *  \* `inactive` — A deactivated user.
-     *  \* `pending` — A pending user.
-     *  
-     *  This value defaults to `active`.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `role_id: &str` -- The role's unique ID. Use this parameter to filter the response by a specific role. You can use the [List Roles](https://marketplace.zoom.us/docs/api-reference/zoom-api/roles/roles) API to get a role's unique ID value.
-     * * `page_number: &str` -- The page number of the current page in the returned records.
-     * * `include_fields: crate::types::UsersIncludeFields` -- Use this parameter to display one of the following attributes in the API call's response:
-     *  \* `custom_attributes` — Return the user's custom attributes.
-     *  \* `host_key` — Return the user's [host key](https://support.zoom.us/hc/en-us/articles/205172555-Using-your-host-key).
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &UsersGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_page(
+    pub async fn get_page_with(
         &self,
-        status: crate::types::UsersStatus,
-        page_size: i64,
-        role_id: &str,
-        page_number: &str,
-        include_fields: crate::types::UsersIncludeFields,
-        next_page_token: &str,
+        params: &UsersGetPageParams,
     ) -> Result<Vec<crate::types::UsersResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_fields.to_string().is_empty() {
+        if let Some(include_fields) = &params.include_fields {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
         }
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if !page_number.is_empty() {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !role_id.is_empty() {
+        if let Some(role_id) = &params.role_id {
             query_args.push(("role_id".to_string(), role_id.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -74,6 +55,30 @@ impl Users {
         Ok(resp.users.to_vec())
     }
 
+    /**
+     * Deprecated: use `get_page_with` and pass the query parameters as a `UsersGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_page_with` instead")]
+    pub async fn get_page(
+        &self,
+        status: crate::types::UsersStatus,
+        page_size: i64,
+        role_id: &str,
+        page_number: &str,
+        include_fields: crate::types::UsersIncludeFields,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::UsersResponse>> {
+        let params = UsersGetPageParams {
+            include_fields: Some(include_fields).filter(|v| !v.to_string().is_empty()),
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_number: Some(page_number.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            role_id: Some(role_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+        };
+        self.get_page_with(&params).await
+    }
+
     /**
      * List users.
      *
@@ -84,21 +89,23 @@ impl Users {
      * Use this API to list your account's users.
      *
      * **Scopes:** `user:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     *
+     * **Parameters:**
+     *
+     * * `params: &UsersGetPageParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get_all(
+    pub async fn get_all_with(
         &self,
-        status: crate::types::UsersStatus,
-        role_id: &str,
-        include_fields: crate::types::UsersIncludeFields,
+        params: &UsersGetPageParams,
     ) -> Result<Vec<crate::types::UsersResponse>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !include_fields.to_string().is_empty() {
+        if let Some(include_fields) = &params.include_fields {
             query_args.push(("include_fields".to_string(), include_fields.to_string()));
         }
-        if !role_id.is_empty() {
+        if let Some(role_id) = &params.role_id {
             query_args.push(("role_id".to_string(), role_id.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -137,6 +144,75 @@ impl Users {
         Ok(users)
     }
 
+    /**
+     * Deprecated: use `get_all_with` and pass the query parameters as a `UsersGetPageParams` instead.
+     */
+    #[deprecated(note = "use `get_all_with` instead")]
+    pub async fn get_all(
+        &self,
+        status: crate::types::UsersStatus,
+        role_id: &str,
+        include_fields: crate::types::UsersIncludeFields,
+    ) -> Result<Vec<crate::types::UsersResponse>> {
+        let params = UsersGetPageParams {
+            include_fields: Some(include_fields).filter(|v| !v.to_string().is_empty()),
+            role_id: Some(role_id.to_string()).filter(|s| !s.is_empty()),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            ..Default::default()
+        };
+        self.get_all_with(&params).await
+    }
+
+    /**
+     * List users.
+     *
+     * This function performs a `GET` to the `/users` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to list your account's users.
+     *
+     * **Scopes:** `user:read:admin`<br>**[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`
+     */
+    pub fn get_stream(
+        &self,
+        params: &UsersGetPageParams,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::UsersResponse> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(include_fields) = &params.include_fields {
+            query_args.push(("include_fields".to_string(), include_fields.to_string()));
+        }
+        if let Some(role_id) = &params.role_id {
+            query_args.push(("role_id".to_string(), role_id.to_string()));
+        }
+        if let Some(status) = &params.status {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/users?{}", query_);
+
+        let start = start.or_else(|| params.next_page_token.clone());
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::UsersResponseData = client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.users,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Create users.
      *
@@ -173,26 +249,18 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `login_type: crate::types::LoginType` -- The user's login method:
-     *  
-     *  `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
-     *  
-     *  The following login methods are only available in China:
-     *  
-     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
-     * * `encrypted_email: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+     * * `params: &UsersUserParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn user(
+    pub async fn user_with(
         &self,
         user_id: &str,
-        login_type: crate::types::LoginType,
-        encrypted_email: bool,
+        params: &UsersUserParams,
     ) -> Result<crate::types::UserResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if encrypted_email {
+        if let Some(encrypted_email) = &params.encrypted_email {
             query_args.push(("encrypted_email".to_string(), encrypted_email.to_string()));
         }
-        if !login_type.to_string().is_empty() {
+        if let Some(login_type) = &params.login_type {
             query_args.push(("login_type".to_string(), login_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -205,6 +273,23 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `user_with` and pass the query parameters as a `UsersUserParams` instead.
+     */
+    #[deprecated(note = "use `user_with` instead")]
+    pub async fn user(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        encrypted_email: bool,
+    ) -> Result<crate::types::UserResponseAllOf> {
+        let params = UsersUserParams {
+            encrypted_email: Some(encrypted_email).filter(|v| *v),
+            login_type: Some(login_type).filter(|v| !v.to_string().is_empty()),
+        };
+        self.user_with(user_id, &params).await
+    }
+
     /**
      * Delete a user.
      *
@@ -221,38 +306,26 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `action: crate::types::UserDeleteAction` -- Delete action options:<br>`disassociate` - Disassociate a user.<br>`delete`-  Permanently delete a user.<br>Note: To delete pending user in the account, use `disassociate`.
-     * * `transfer_email: &str` -- User's first name.
-     * * `transfer_meeting: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
-     * * `transfer_webinar: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
-     * * `transfer_recording: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+     * * `params: &UsersDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete(
-        &self,
-        user_id: &str,
-        action: crate::types::UserDeleteAction,
-        transfer_email: &str,
-        transfer_meeting: bool,
-        transfer_webinar: bool,
-        transfer_recording: bool,
-    ) -> Result<()> {
+    pub async fn delete_with(&self, user_id: &str, params: &UsersDeleteParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !action.to_string().is_empty() {
+        if let Some(action) = &params.action {
             query_args.push(("action".to_string(), action.to_string()));
         }
-        if !transfer_email.is_empty() {
+        if let Some(transfer_email) = &params.transfer_email {
             query_args.push(("transfer_email".to_string(), transfer_email.to_string()));
         }
-        if transfer_meeting {
+        if let Some(transfer_meeting) = &params.transfer_meeting {
             query_args.push(("transfer_meeting".to_string(), transfer_meeting.to_string()));
         }
-        if transfer_recording {
+        if let Some(transfer_recording) = &params.transfer_recording {
             query_args.push((
                 "transfer_recording".to_string(),
                 transfer_recording.to_string(),
             ));
         }
-        if transfer_webinar {
+        if let Some(transfer_webinar) = &params.transfer_webinar {
             query_args.push(("transfer_webinar".to_string(), transfer_webinar.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -265,6 +338,29 @@ impl Users {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_with` and pass the query parameters as a `UsersDeleteParams` instead.
+     */
+    #[deprecated(note = "use `delete_with` instead")]
+    pub async fn delete(
+        &self,
+        user_id: &str,
+        action: crate::types::UserDeleteAction,
+        transfer_email: &str,
+        transfer_meeting: bool,
+        transfer_webinar: bool,
+        transfer_recording: bool,
+    ) -> Result<()> {
+        let params = UsersDeleteParams {
+            action: Some(action).filter(|v| !v.to_string().is_empty()),
+            transfer_email: Some(transfer_email.to_string()).filter(|s| !s.is_empty()),
+            transfer_meeting: Some(transfer_meeting).filter(|v| *v),
+            transfer_recording: Some(transfer_recording).filter(|v| *v),
+            transfer_webinar: Some(transfer_webinar).filter(|v| *v),
+        };
+        self.delete_with(user_id, &params).await
+    }
+
     /**
      * Update a user.
      *
@@ -277,22 +373,16 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `login_type: crate::types::LoginType` -- The user's login method:
-     *  
-     *  `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
-     *  
-     *  The following login methods are only available in China:
-     *  
-     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
+     * * `params: &UsersUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update(
+    pub async fn update_with(
         &self,
         user_id: &str,
-        login_type: crate::types::LoginType,
+        params: &UsersUpdateParams,
         body: &crate::types::UserUpdate,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !login_type.to_string().is_empty() {
+        if let Some(login_type) = &params.login_type {
             query_args.push(("login_type".to_string(), login_type.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -307,6 +397,22 @@ impl Users {
             .await
     }
 
+    /**
+     * Deprecated: use `update_with` and pass the query parameters as a `UsersUpdateParams` instead.
+     */
+    #[deprecated(note = "use `update_with` instead")]
+    pub async fn update(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        body: &crate::types::UserUpdate,
+    ) -> Result<()> {
+        let params = UsersUpdateParams {
+            login_type: Some(login_type).filter(|v| !v.to_string().is_empty()),
+        };
+        self.update_with(user_id, &params, body).await
+    }
+
     /**
      * Get user's ZAK.
      *
@@ -563,44 +669,24 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `login_type: crate::types::LoginType` -- The user's login method:
-     *  
-     *  `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
-     *  
-     *  The following login methods are only available in China:
-     *  
-     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
-     *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
-     *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
-     *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
-     * * `custom_query_fields: &str` -- Provide the name of the field by which you would like to filter the response. For example, if you provide "host_video" as the value of this field, you will get a response similar to the following:<br>
-     *   {
-     *       "schedule_meeting": {
-     *           "host_video": false
-     *       }
-     *   }
-     *   <br>You can provide multiple values by separating them with commas(example: "host_video,participant_video”).
+     * * `params: &UsersSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn settings_domains(
+    pub async fn settings_domains_with(
         &self,
         user_id: &str,
-        login_type: crate::types::LoginType,
-        option: crate::types::OptionData,
-        custom_query_fields: &str,
+        params: &UsersSettingParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !login_type.to_string().is_empty() {
+        if let Some(login_type) = &params.login_type {
             query_args.push(("login_type".to_string(), login_type.to_string()));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -613,6 +699,25 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `settings_domains_with` and pass the query parameters as a `UsersSettingParams` instead.
+     */
+    #[deprecated(note = "use `settings_domains_with` instead")]
+    pub async fn settings_domains(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = UsersSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            login_type: Some(login_type).filter(|v| !v.to_string().is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.settings_domains_with(user_id, &params).await
+    }
+
     /**
      * Get user settings.
      *
@@ -625,44 +730,24 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `login_type: crate::types::LoginType` -- The user's login method:
-     *  
-     *  `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
-     *  
-     *  The following login methods are only available in China:
-     *  
-     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
-     *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
-     *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
-     *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
-     * * `custom_query_fields: &str` -- Provide the name of the field by which you would like to filter the response. For example, if you provide "host_video" as the value of this field, you will get a response similar to the following:<br>
-     *   {
-     *       "schedule_meeting": {
-     *           "host_video": false
-     *       }
-     *   }
-     *   <br>You can provide multiple values by separating them with commas(example: "host_video,participant_video”).
+     * * `params: &UsersSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn settings_user(
+    pub async fn settings_user_with(
         &self,
         user_id: &str,
-        login_type: crate::types::LoginType,
-        option: crate::types::OptionData,
-        custom_query_fields: &str,
+        params: &UsersSettingParams,
     ) -> Result<crate::types::UserSettings> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !login_type.to_string().is_empty() {
+        if let Some(login_type) = &params.login_type {
             query_args.push(("login_type".to_string(), login_type.to_string()));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -675,6 +760,25 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `settings_user_with` and pass the query parameters as a `UsersSettingParams` instead.
+     */
+    #[deprecated(note = "use `settings_user_with` instead")]
+    pub async fn settings_user(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::UserSettings> {
+        let params = UsersSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            login_type: Some(login_type).filter(|v| !v.to_string().is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.settings_user_with(user_id, &params).await
+    }
+
     /**
      * Get user settings.
      *
@@ -687,44 +791,24 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `login_type: crate::types::LoginType` -- The user's login method:
-     *  
-     *  `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
-     *  
-     *  The following login methods are only available in China:
-     *  
-     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
-     *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
-     *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
-     *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
-     * * `custom_query_fields: &str` -- Provide the name of the field by which you would like to filter the response. For example, if you provide "host_video" as the value of this field, you will get a response similar to the following:<br>
-     *   {
-     *       "schedule_meeting": {
-     *           "host_video": false
-     *       }
-     *   }
-     *   <br>You can provide multiple values by separating them with commas(example: "host_video,participant_video”).
+     * * `params: &UsersSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn settings_meeting_security(
+    pub async fn settings_meeting_security_with(
         &self,
         user_id: &str,
-        login_type: crate::types::LoginType,
-        option: crate::types::OptionData,
-        custom_query_fields: &str,
+        params: &UsersSettingParams,
     ) -> Result<crate::types::MeetingSecuritySettings> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !login_type.to_string().is_empty() {
+        if let Some(login_type) = &params.login_type {
             query_args.push(("login_type".to_string(), login_type.to_string()));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -737,6 +821,25 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `settings_meeting_security_with` and pass the query parameters as a `UsersSettingParams` instead.
+     */
+    #[deprecated(note = "use `settings_meeting_security_with` instead")]
+    pub async fn settings_meeting_security(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::MeetingSecuritySettings> {
+        let params = UsersSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            login_type: Some(login_type).filter(|v| !v.to_string().is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.settings_meeting_security_with(user_id, &params).await
+    }
+
     /**
      * Get user settings.
      *
@@ -749,44 +852,24 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `login_type: crate::types::LoginType` -- The user's login method:
-     *  
-     *  `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
-     *  
-     *  The following login methods are only available in China:
-     *  
-     *  `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
-     * * `option: crate::types::OptionData` -- Use the following options to filter the results of the account's information:
-     *  \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
-     *  \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
-     *  \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
-     *  \* `meeting_security` — View the account's meeting security settings.
-     * * `custom_query_fields: &str` -- Provide the name of the field by which you would like to filter the response. For example, if you provide "host_video" as the value of this field, you will get a response similar to the following:<br>
-     *   {
-     *       "schedule_meeting": {
-     *           "host_video": false
-     *       }
-     *   }
-     *   <br>You can provide multiple values by separating them with commas(example: "host_video,participant_video”).
+     * * `params: &UsersSettingParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn setting(
+    pub async fn setting_with(
         &self,
         user_id: &str,
-        login_type: crate::types::LoginType,
-        option: crate::types::OptionData,
-        custom_query_fields: &str,
+        params: &UsersSettingParams,
     ) -> Result<crate::types::UserSettingsResponseOneOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !custom_query_fields.is_empty() {
+        if let Some(custom_query_fields) = &params.custom_query_fields {
             query_args.push((
                 "custom_query_fields".to_string(),
                 custom_query_fields.to_string(),
             ));
         }
-        if !login_type.to_string().is_empty() {
+        if let Some(login_type) = &params.login_type {
             query_args.push(("login_type".to_string(), login_type.to_string()));
         }
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -799,6 +882,25 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `setting_with` and pass the query parameters as a `UsersSettingParams` instead.
+     */
+    #[deprecated(note = "use `setting_with` instead")]
+    pub async fn setting(
+        &self,
+        user_id: &str,
+        login_type: crate::types::LoginType,
+        option: crate::types::OptionData,
+        custom_query_fields: &str,
+    ) -> Result<crate::types::UserSettingsResponseOneOf> {
+        let params = UsersSettingParams {
+            custom_query_fields: Some(custom_query_fields.to_string()).filter(|s| !s.is_empty()),
+            login_type: Some(login_type).filter(|v| !v.to_string().is_empty()),
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.setting_with(user_id, &params).await
+    }
+
     /**
      * Update user settings.
      *
@@ -810,17 +912,17 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `option: crate::types::UserSettingsUpdateOption`
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
+     * * `params: &UsersSettingsUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn settings_update(
+    pub async fn settings_update_with(
         &self,
-        option: crate::types::UserSettingsUpdateOption,
         user_id: &str,
+        params: &UsersSettingsUpdateParams,
         body: &crate::types::UserSettingsUpdateRequestOneOf,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !option.to_string().is_empty() {
+        if let Some(option) = &params.option {
             query_args.push(("option".to_string(), option.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -835,6 +937,22 @@ impl Users {
             .await
     }
 
+    /**
+     * Deprecated: use `settings_update_with` and pass the query parameters as a `UsersSettingsUpdateParams` instead.
+     */
+    #[deprecated(note = "use `settings_update_with` instead")]
+    pub async fn settings_update(
+        &self,
+        option: crate::types::UserSettingsUpdateOption,
+        user_id: &str,
+        body: &crate::types::UserSettingsUpdateRequestOneOf,
+    ) -> Result<()> {
+        let params = UsersSettingsUpdateParams {
+            option: Some(option).filter(|v| !v.to_string().is_empty()),
+        };
+        self.settings_update_with(user_id, &params, body).await
+    }
+
     /**
      * Update user status.
      *
@@ -936,20 +1054,18 @@ impl Users {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `type_: crate::types::UserTokenType` -- User token types:<br>`token` - Used for starting meetings with the client SDK. This token expires in 14 days and a new token will be returned after the expiry.<br>`zak` - Used for generating the start meeting URL. The token expiration time is two hours. For API users, the expiration time is 90 days.
-     * * `ttl: i64` -- Use this field in conjunction with the `type` field where the value of `type` field is `zak`. The value of this field denotes the expiry time of the `zak` token in seconds. For example, if you would like the zak token to be expired after one hour of the token generation, the value of this field should be `3600`.
+     * * `params: &UsersTokenParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn token(
+    pub async fn token_with(
         &self,
         user_id: &str,
-        type_: crate::types::UserTokenType,
-        ttl: i64,
+        params: &UsersTokenParams,
     ) -> Result<crate::types::UserZakResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if ttl > 0 {
+        if let Some(ttl) = &params.ttl {
             query_args.push(("ttl".to_string(), ttl.to_string()));
         }
-        if !type_.to_string().is_empty() {
+        if let Some(type_) = &params.type_ {
             query_args.push(("type".to_string(), type_.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -962,6 +1078,23 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `token_with` and pass the query parameters as a `UsersTokenParams` instead.
+     */
+    #[deprecated(note = "use `token_with` instead")]
+    pub async fn token(
+        &self,
+        user_id: &str,
+        type_: crate::types::UserTokenType,
+        ttl: i64,
+    ) -> Result<crate::types::UserZakResponse> {
+        let params = UsersTokenParams {
+            ttl: Some(ttl).filter(|v| *v > 0),
+            type_: Some(type_).filter(|v| !v.to_string().is_empty()),
+        };
+        self.token_with(user_id, &params).await
+    }
+
     /**
      * Revoke a user's SSO token.
      *
@@ -1002,11 +1135,14 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `email: &str` -- The email address to be verified.
+     * * `params: &UsersEmailParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn email(&self, email: &str) -> Result<crate::types::UserEmailResponse> {
+    pub async fn email_with(
+        &self,
+        params: &UsersEmailParams,
+    ) -> Result<crate::types::UserEmailResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !email.is_empty() {
+        if let Some(email) = &params.email {
             query_args.push(("email".to_string(), email.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1015,6 +1151,17 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `email_with` and pass the query parameters as a `UsersEmailParams` instead.
+     */
+    #[deprecated(note = "use `email_with` instead")]
+    pub async fn email(&self, email: &str) -> Result<crate::types::UserEmailResponse> {
+        let params = UsersEmailParams {
+            email: Some(email.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.email_with(&params).await
+    }
+
     /**
      * Update a user's email.
      *
@@ -1059,14 +1206,14 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `vanity_name: &str` -- Personal meeting room name.
+     * * `params: &UsersVanityNameParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn vanity_name(
+    pub async fn vanity_name_with(
         &self,
-        vanity_name: &str,
+        params: &UsersVanityNameParams,
     ) -> Result<crate::types::UserVanityNameResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !vanity_name.is_empty() {
+        if let Some(vanity_name) = &params.vanity_name {
             query_args.push(("vanity_name".to_string(), vanity_name.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1075,6 +1222,20 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `vanity_name_with` and pass the query parameters as a `UsersVanityNameParams` instead.
+     */
+    #[deprecated(note = "use `vanity_name_with` instead")]
+    pub async fn vanity_name(
+        &self,
+        vanity_name: &str,
+    ) -> Result<crate::types::UserVanityNameResponse> {
+        let params = UsersVanityNameParams {
+            vanity_name: Some(vanity_name.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.vanity_name_with(&params).await
+    }
+
     /**
      * Switch a user's account.
      *
@@ -1201,12 +1362,12 @@ impl Users {
      *
      * **Parameters:**
      *
-     * * `file_ids: &str` -- Provide the id of the file that is to be deleted. To delete multiple files, provide comma separated values for this field.
      * * `user_id: &str` -- Unique identifier of the user. Retrieve the value of this field by calling the [List users](https://marketplace.zoom.us/docs/api-reference/zoom-api/users/users) API. .
+     * * `params: &UsersDelVbParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn del_vb(&self, user_id: &str, file_ids: &str) -> Result<()> {
+    pub async fn del_vb_with(&self, user_id: &str, params: &UsersDelVbParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !file_ids.is_empty() {
+        if let Some(file_ids) = &params.file_ids {
             query_args.push(("file_ids".to_string(), file_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1218,4 +1379,280 @@ impl Users {
 
         self.client.delete(&url, None).await
     }
+
+    /**
+     * Deprecated: use `del_vb_with` and pass the query parameters as a `UsersDelVbParams` instead.
+     */
+    #[deprecated(note = "use `del_vb_with` instead")]
+    pub async fn del_vb(&self, user_id: &str, file_ids: &str) -> Result<()> {
+        let params = UsersDelVbParams {
+            file_ids: Some(file_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.del_vb_with(user_id, &params).await
+    }
+}
+
+/// The query parameters of `Users::get_page_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersGetPageParams {
+    /// Use this parameter to display one of the following attributes in the API call's response:
+    /// \* `custom_attributes` — Return the user's custom attributes.
+    /// \* `host_key` — Return the user's [host key](https://support.zoom.us/hc/en-us/articles/205172555-Using-your-host-key).
+    pub include_fields: Option<crate::types::UsersIncludeFields>,
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The page number of the current page in the returned records.
+    pub page_number: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// The role's unique ID. Use this parameter to filter the response by a specific role. You can use the [List Roles](https://marketplace.zoom.us/docs/api-reference/zoom-api/roles/roles) API to get a role's unique ID value.
+    pub role_id: Option<String>,
+    /// The user's status:
+    /// \* `active` — An active user.
+    /// \* `inactive` — A deactivated user.
+    /// \* `pending` — A pending user.
+    ///
+    /// This value defaults to `active`.
+    pub status: Option<crate::types::UsersStatus>,
+}
+
+impl UsersGetPageParams {
+    pub fn include_fields(mut self, include_fields: crate::types::UsersIncludeFields) -> Self {
+        self.include_fields = Some(include_fields);
+        self
+    }
+
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_number<T: ToString>(mut self, page_number: T) -> Self {
+        self.page_number = Some(page_number.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn role_id<T: ToString>(mut self, role_id: T) -> Self {
+        self.role_id = Some(role_id.to_string());
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::UsersStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+/// The query parameters of `Users::user_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersUserParams {
+    /// Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+    pub encrypted_email: Option<bool>,
+    /// The user's login method:
+    ///
+    /// `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
+    ///
+    /// The following login methods are only available in China:
+    ///
+    /// `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
+    pub login_type: Option<crate::types::LoginType>,
+}
+
+impl UsersUserParams {
+    pub fn encrypted_email(mut self, encrypted_email: bool) -> Self {
+        self.encrypted_email = Some(encrypted_email);
+        self
+    }
+
+    pub fn login_type(mut self, login_type: crate::types::LoginType) -> Self {
+        self.login_type = Some(login_type);
+        self
+    }
+}
+
+/// The query parameters of `Users::delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersDeleteParams {
+    /// Delete action options:<br>`disassociate` - Disassociate a user.<br>`delete`-  Permanently delete a user.<br>Note: To delete pending user in the account, use `disassociate`.
+    pub action: Option<crate::types::UserDeleteAction>,
+    /// User's first name.
+    pub transfer_email: Option<String>,
+    /// Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+    pub transfer_meeting: Option<bool>,
+    /// Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+    pub transfer_recording: Option<bool>,
+    /// Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+    pub transfer_webinar: Option<bool>,
+}
+
+impl UsersDeleteParams {
+    pub fn action(mut self, action: crate::types::UserDeleteAction) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    pub fn transfer_email<T: ToString>(mut self, transfer_email: T) -> Self {
+        self.transfer_email = Some(transfer_email.to_string());
+        self
+    }
+
+    pub fn transfer_meeting(mut self, transfer_meeting: bool) -> Self {
+        self.transfer_meeting = Some(transfer_meeting);
+        self
+    }
+
+    pub fn transfer_recording(mut self, transfer_recording: bool) -> Self {
+        self.transfer_recording = Some(transfer_recording);
+        self
+    }
+
+    pub fn transfer_webinar(mut self, transfer_webinar: bool) -> Self {
+        self.transfer_webinar = Some(transfer_webinar);
+        self
+    }
+}
+
+/// The query parameters of `Users::update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersUpdateParams {
+    /// The user's login method:
+    ///
+    /// `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
+    ///
+    /// The following login methods are only available in China:
+    ///
+    /// `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
+    pub login_type: Option<crate::types::LoginType>,
+}
+
+impl UsersUpdateParams {
+    pub fn login_type(mut self, login_type: crate::types::LoginType) -> Self {
+        self.login_type = Some(login_type);
+        self
+    }
+}
+
+/// The query parameters of `Users::setting_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersSettingParams {
+    /// Provide the name of the field by which you would like to filter the response. For example, if you provide "host_video" as the value of this field, you will get a response similar to the following:<br>
+    /// {
+    /// "schedule_meeting": {
+    /// "host_video": false
+    /// }
+    /// }
+    /// <br>You can provide multiple values by separating them with commas(example: "host_video,participant_video”).
+    pub custom_query_fields: Option<String>,
+    /// The user's login method:
+    ///
+    /// `0` — Facebook OAuth</br>`1` — Google OAuth</br>`24` — Apple OAuth</br>`27` — Microsoft OAuth</br>`97` — Mobile device</br>`98` — RingCentral OAuth</br>`99` — API user</br>`100` — Zoom Work email</br>`101` — Single Sign-On (SSO)
+    ///
+    /// The following login methods are only available in China:
+    ///
+    /// `11` — Phone number</br>`21` — WeChat</br>`23` — Alipay.
+    pub login_type: Option<crate::types::LoginType>,
+    /// Use the following options to filter the results of the account's information:
+    /// \* `meeting_authentication` — View the account's [meeting authentication settings](https://support.zoom.us/hc/en-us/articles/360037117472-Authentication-Profiles-for-Meetings-and-Webinars).
+    /// \* `recording_authentication` — View the account's [recording authentication settings](https://support.zoom.us/hc/en-us/articles/360037756671-Authentication-Profiles-for-Cloud-Recordings).
+    /// \* `security` — View the account's security settings. For example, password requirements for user login or two-factor authentication.<br>
+    /// \* `meeting_security` — View the account's meeting security settings.
+    pub option: Option<crate::types::OptionData>,
+}
+
+impl UsersSettingParams {
+    pub fn custom_query_fields<T: ToString>(mut self, custom_query_fields: T) -> Self {
+        self.custom_query_fields = Some(custom_query_fields.to_string());
+        self
+    }
+
+    pub fn login_type(mut self, login_type: crate::types::LoginType) -> Self {
+        self.login_type = Some(login_type);
+        self
+    }
+
+    pub fn option(mut self, option: crate::types::OptionData) -> Self {
+        self.option = Some(option);
+        self
+    }
+}
+
+/// The query parameters of `Users::settings_update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersSettingsUpdateParams {
+    pub option: Option<crate::types::UserSettingsUpdateOption>,
+}
+
+impl UsersSettingsUpdateParams {
+    pub fn option(mut self, option: crate::types::UserSettingsUpdateOption) -> Self {
+        self.option = Some(option);
+        self
+    }
+}
+
+/// The query parameters of `Users::token_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersTokenParams {
+    /// Use this field in conjunction with the `type` field where the value of `type` field is `zak`. The value of this field denotes the expiry time of the `zak` token in seconds. For example, if you would like the zak token to be expired after one hour of the token generation, the value of this field should be `3600`.
+    pub ttl: Option<i64>,
+    /// User token types:<br>`token` - Used for starting meetings with the client SDK. This token expires in 14 days and a new token will be returned after the expiry.<br>`zak` - Used for generating the start meeting URL. The token expiration time is two hours. For API users, the expiration time is 90 days.
+    pub type_: Option<crate::types::UserTokenType>,
+}
+
+impl UsersTokenParams {
+    pub fn ttl(mut self, ttl: i64) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    pub fn type_(mut self, type_: crate::types::UserTokenType) -> Self {
+        self.type_ = Some(type_);
+        self
+    }
+}
+
+/// The query parameters of `Users::email_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersEmailParams {
+    /// The email address to be verified.
+    pub email: Option<String>,
+}
+
+impl UsersEmailParams {
+    pub fn email<T: ToString>(mut self, email: T) -> Self {
+        self.email = Some(email.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Users::vanity_name_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersVanityNameParams {
+    /// Personal meeting room name.
+    pub vanity_name: Option<String>,
+}
+
+impl UsersVanityNameParams {
+    pub fn vanity_name<T: ToString>(mut self, vanity_name: T) -> Self {
+        self.vanity_name = Some(vanity_name.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Users::del_vb_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct UsersDelVbParams {
+    /// Provide the id of the file that is to be deleted. To delete multiple files, provide comma separated values for this field.
+    pub file_ids: Option<String>,
+}
+
+impl UsersDelVbParams {
+    pub fn file_ids<T: ToString>(mut self, file_ids: T) -> Self {
+        self.file_ids = Some(file_ids.to_string());
+        self
+    }
 }
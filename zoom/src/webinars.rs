@@ -1,6 +1,4 @@
-use anyhow::Result;
-
-use crate::Client;
+use crate::{Client, Result};
 
 pub struct Webinars {
     pub client: Client,
@@ -29,23 +27,18 @@ impl Webinars {
      * **Parameters:**
      *
      * * `user_id: &str` -- The user ID or email address of the user. For user-level apps, pass `me` as the value for userId.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `page_number: i64` --
-     *   **Deprecated** - This field has been deprecated and we will stop supporting it completely in a future release. Please use "next_page_token" for pagination instead of this field.
-     *   
-     *   The page number of the current page in the returned records.
+     * * `params: &WebinarsGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn get(
+    pub async fn get_with(
         &self,
         user_id: &str,
-        page_size: i64,
-        page_number: i64,
+        params: &WebinarsGetParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if page_number > 0 {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -58,6 +51,23 @@ impl Webinars {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `get_with` and pass the query parameters as a `WebinarsGetParams` instead.
+     */
+    #[deprecated(note = "use `get_with` instead")]
+    pub async fn get(
+        &self,
+        user_id: &str,
+        page_size: i64,
+        page_number: i64,
+    ) -> Result<crate::types::Domains> {
+        let params = WebinarsGetParams {
+            page_number: Some(page_number).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.get_with(user_id, &params).await
+    }
+
     /**
      * Create a webinar.
      *
@@ -98,20 +108,18 @@ impl Webinars {
      * **Parameters:**
      *
      * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
-     * * `occurrence_id: &str` -- Unique Identifier that identifies an occurrence of a recurring webinar. [Recurring webinars](https://support.zoom.us/hc/en-us/articles/216354763-How-to-Schedule-A-Recurring-Webinar) can have a maximum of 50 occurrences. When you create a recurring Webinar using [Create a Webinar API](https://marketplace.zoom.us/docs/api-reference/zoom-api/webinars/webinarcreate), you can retrieve the Occurrence ID from the response of the API call.
-     * * `show_previous_occurrences: bool` -- Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+     * * `params: &WebinarsWebinarParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn webinar(
+    pub async fn webinar_with(
         &self,
         webinar_id: i64,
-        occurrence_id: &str,
-        show_previous_occurrences: bool,
+        params: &WebinarsWebinarParams,
     ) -> Result<crate::types::WebinarResponseAllOf> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
-        if show_previous_occurrences {
+        if let Some(show_previous_occurrences) = &params.show_previous_occurrences {
             query_args.push((
                 "show_previous_occurrences".to_string(),
                 show_previous_occurrences.to_string(),
@@ -127,6 +135,23 @@ impl Webinars {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `webinar_with` and pass the query parameters as a `WebinarsWebinarParams` instead.
+     */
+    #[deprecated(note = "use `webinar_with` instead")]
+    pub async fn webinar(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        show_previous_occurrences: bool,
+    ) -> Result<crate::types::WebinarResponseAllOf> {
+        let params = WebinarsWebinarParams {
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+            show_previous_occurrences: Some(show_previous_occurrences).filter(|v| *v),
+        };
+        self.webinar_with(webinar_id, &params).await
+    }
+
     /**
      * Delete a webinar.
      *
@@ -142,27 +167,17 @@ impl Webinars {
      * **Parameters:**
      *
      * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
-     * * `occurrence_id: &str` -- The meeting occurrence ID.
-     * * `cancel_webinar_reminder: &str` -- `true`: Notify panelists and registrants about the webinar cancellation via email.
-     *   
-     *   `false`: Do not send any email notification to webinar registrants and panelists.
-     *   
-     *   The default value of this field is `false`.
+     * * `params: &WebinarsDeleteParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete(
-        &self,
-        webinar_id: i64,
-        occurrence_id: &str,
-        cancel_webinar_reminder: &str,
-    ) -> Result<()> {
+    pub async fn delete_with(&self, webinar_id: i64, params: &WebinarsDeleteParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !cancel_webinar_reminder.is_empty() {
+        if let Some(cancel_webinar_reminder) = &params.cancel_webinar_reminder {
             query_args.push((
                 "cancel_webinar_reminder".to_string(),
                 cancel_webinar_reminder.to_string(),
             ));
         }
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -175,6 +190,24 @@ impl Webinars {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_with` and pass the query parameters as a `WebinarsDeleteParams` instead.
+     */
+    #[deprecated(note = "use `delete_with` instead")]
+    pub async fn delete(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        cancel_webinar_reminder: &str,
+    ) -> Result<()> {
+        let params = WebinarsDeleteParams {
+            cancel_webinar_reminder: Some(cancel_webinar_reminder.to_string())
+                .filter(|s| !s.is_empty()),
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_with(webinar_id, &params).await
+    }
+
     /**
      * Update a webinar.
      *
@@ -191,11 +224,11 @@ impl Webinars {
      * **Parameters:**
      *
      * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
-     * * `occurrence_id: &str` -- Webinar occurrence id. Support change of agenda, start_time, duration, settings: {host_video, panelist_video, hd_video, watermark, auto_recording}.
+     * * `params: &WebinarsUpdateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn update(&self, webinar_id: i64, occurrence_id: &str) -> Result<()> {
+    pub async fn update_with(&self, webinar_id: i64, params: &WebinarsUpdateParams) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -208,6 +241,17 @@ impl Webinars {
         self.client.patch(&url, None).await
     }
 
+    /**
+     * Deprecated: use `update_with` and pass the query parameters as a `WebinarsUpdateParams` instead.
+     */
+    #[deprecated(note = "use `update_with` instead")]
+    pub async fn update(&self, webinar_id: i64, occurrence_id: &str) -> Result<()> {
+        let params = WebinarsUpdateParams {
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.update_with(webinar_id, &params).await
+    }
+
     /**
      * List webinar participants.
      *
@@ -228,20 +272,18 @@ impl Webinars {
      * **Parameters:**
      *
      * * `webinar_id: &str` -- Unique identifier of the webinar. You can retrieve the value of this field by calling the [list webinars](https://marketplace.zoom.us/docs/api-reference/zoom-api/webinars/webinars) API.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &WebinarsListParticipantsParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn list_participants(
+    pub async fn list_participants_with(
         &self,
         webinar_id: &str,
-        page_size: i64,
-        next_page_token: &str,
+        params: &WebinarsListParticipantsParams,
     ) -> Result<Vec<crate::types::Participants>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -258,6 +300,23 @@ impl Webinars {
         Ok(resp.participants.to_vec())
     }
 
+    /**
+     * Deprecated: use `list_participants_with` and pass the query parameters as a `WebinarsListParticipantsParams` instead.
+     */
+    #[deprecated(note = "use `list_participants_with` instead")]
+    pub async fn list_participants(
+        &self,
+        webinar_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+    ) -> Result<Vec<crate::types::Participants>> {
+        let params = WebinarsListParticipantsParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.list_participants_with(webinar_id, &params).await
+    }
+
     /**
      * List webinar participants.
      *
@@ -320,6 +379,56 @@ impl Webinars {
         Ok(participants)
     }
 
+    /**
+     * List webinar participants.
+     *
+     * This function performs a `GET` to the `/past_webinars/{webinarId}/participants` endpoint.
+     *
+     * As opposed to `list_participants`, this function returns a stream of the items that fetches the pages one at a time, as they are consumed. Pass the `Cursor::At` of a previous stream as `start` to resume it, and `max_items` to stop after that many items.
+     *
+     * Use this API to list all the participants who attended a webinar hosted in the past. <br>
+     *
+     * **Prerequisites:**
+     * * Pro or higher plan with a Webinar Add-on.<br>
+     * **Scopes:** `webinar:read:admin` `webinar:read`<br>
+     * <br>
+     * **[Rate Limit Label](https://marketplace.zoom.us/docs/api-reference/rate-limits#rate-limits):** `Medium`<br>
+     *
+     *
+     *
+     *
+     */
+    pub fn list_participants_stream(
+        &self,
+        webinar_id: &str,
+        start: Option<String>,
+        max_items: Option<usize>,
+    ) -> crate::pagination::Pages<crate::types::Participants> {
+        let url = format!(
+            "/past_webinars/{}/participants",
+            crate::progenitor_support::encode_path(webinar_id),
+        );
+
+        let client = self.client.clone();
+        crate::pagination::Pages::new(start, max_items, move |page: Option<String>| {
+            let client = client.clone();
+            let url = url.clone();
+            async move {
+                let url = match page {
+                    Some(page) => crate::pagination::with_query(&url, "next_page_token", &page),
+                    None => url,
+                };
+                let resp: crate::types::ListWebinarParticipantsResponse =
+                    client.get(&url, None).await?;
+
+                Ok(crate::pagination::Page::new(
+                    resp.participants,
+                    Some(resp.next_page_token).filter(|t| !t.is_empty()),
+                ))
+            }
+        })
+    }
+
     /**
      * Update webinar status.
      *
@@ -481,43 +590,30 @@ impl Webinars {
      * **Parameters:**
      *
      * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
-     * * `occurrence_id: &str` -- The meeting occurrence ID.
-     * * `status: crate::types::MeetingRegistrantsStatus` -- The registrant status:<br>`pending` - Registrant's status is pending.<br>`approved` - Registrant's status is approved.<br>`denied` - Registrant's status is denied.
-     * * `tracking_source_id: &str` -- The tracking source ID for the registrants. Useful if you share the webinar registration page in multiple locations. See [Creating source tracking links for webinar registration](https://support.zoom.us/hc/en-us/articles/360000315683-Creating-source-tracking-links-for-webinar-registration) for details.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `page_number: i64` --
-     *   **Deprecated** - This field has been deprecated and we will stop supporting it completely in a future release. Please use "next_page_token" for pagination instead of this field.
-     *   
-     *   The page number of the current page in the returned records.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+     * * `params: &WebinarsRegistrantParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn registrant(
+    pub async fn registrant_with(
         &self,
         webinar_id: &str,
-        occurrence_id: &str,
-        status: crate::types::MeetingRegistrantsStatus,
-        tracking_source_id: &str,
-        page_size: i64,
-        page_number: i64,
-        next_page_token: &str,
+        params: &WebinarsRegistrantParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
-        if page_number > 0 {
+        if let Some(page_number) = &params.page_number {
             query_args.push(("page_number".to_string(), page_number.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
-        if !status.to_string().is_empty() {
+        if let Some(status) = &params.status {
             query_args.push(("status".to_string(), status.to_string()));
         }
-        if !tracking_source_id.is_empty() {
+        if let Some(tracking_source_id) = &params.tracking_source_id {
             query_args.push((
                 "tracking_source_id".to_string(),
                 tracking_source_id.to_string(),
@@ -533,6 +629,31 @@ impl Webinars {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `registrant_with` and pass the query parameters as a `WebinarsRegistrantParams` instead.
+     */
+    #[deprecated(note = "use `registrant_with` instead")]
+    pub async fn registrant(
+        &self,
+        webinar_id: &str,
+        occurrence_id: &str,
+        status: crate::types::MeetingRegistrantsStatus,
+        tracking_source_id: &str,
+        page_size: i64,
+        page_number: i64,
+        next_page_token: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = WebinarsRegistrantParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+            page_number: Some(page_number).filter(|v| *v > 0),
+            page_size: Some(page_size).filter(|v| *v > 0),
+            status: Some(status).filter(|v| !v.to_string().is_empty()),
+            tracking_source_id: Some(tracking_source_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.registrant_with(webinar_id, &params).await
+    }
+
     /**
      * Add a webinar registrant.
      *
@@ -548,15 +669,15 @@ impl Webinars {
      * **Parameters:**
      *
      * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
-     * * `occurrence_ids: &str` -- Occurrence ID. Get this value from the webinar get API. Multiple values separated by a comma.
+     * * `params: &WebinarsRegistrantCreateParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn registrant_create(
+    pub async fn registrant_create_with(
         &self,
         webinar_id: &str,
-        occurrence_ids: &str,
+        params: &WebinarsRegistrantCreateParams,
     ) -> Result<crate::types::WebinarRegistrantCreateResponse> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !occurrence_ids.is_empty() {
+        if let Some(occurrence_ids) = &params.occurrence_ids {
             query_args.push(("occurrence_ids".to_string(), occurrence_ids.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -569,6 +690,21 @@ impl Webinars {
         self.client.post(&url, None).await
     }
 
+    /**
+     * Deprecated: use `registrant_create_with` and pass the query parameters as a `WebinarsRegistrantCreateParams` instead.
+     */
+    #[deprecated(note = "use `registrant_create_with` instead")]
+    pub async fn registrant_create(
+        &self,
+        webinar_id: &str,
+        occurrence_ids: &str,
+    ) -> Result<crate::types::WebinarRegistrantCreateResponse> {
+        let params = WebinarsRegistrantCreateParams {
+            occurrence_ids: Some(occurrence_ids.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.registrant_create_with(webinar_id, &params).await
+    }
+
     /**
      * Perform batch registration.
      *
@@ -624,16 +760,16 @@ impl Webinars {
      * **Parameters:**
      *
      * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
-     * * `occurrence_id: &str` -- The meeting occurrence ID.
+     * * `params: &WebinarsRegistrantStatusParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn registrant_status(
+    pub async fn registrant_status_with(
         &self,
         webinar_id: i64,
-        occurrence_id: &str,
+        params: &WebinarsRegistrantStatusParams,
         body: &crate::types::RegistrantStatus,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -648,6 +784,22 @@ impl Webinars {
             .await
     }
 
+    /**
+     * Deprecated: use `registrant_status_with` and pass the query parameters as a `WebinarsRegistrantStatusParams` instead.
+     */
+    #[deprecated(note = "use `registrant_status_with` instead")]
+    pub async fn registrant_status(
+        &self,
+        webinar_id: i64,
+        occurrence_id: &str,
+        body: &crate::types::RegistrantStatus,
+    ) -> Result<()> {
+        let params = WebinarsRegistrantStatusParams {
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.registrant_status_with(webinar_id, &params, body).await
+    }
+
     /**
      * List past webinar instances.
      *
@@ -664,7 +816,7 @@ impl Webinars {
      *
      * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
      */
-    pub async fn past(&self, webinar_id: i64) -> Result<crate::types::Domains> {
+    pub async fn past(&self, webinar_id: i64) -> Result<crate::types::PastWebinarsResponse> {
         let url = format!(
             "/past_webinars/{}/instances",
             crate::progenitor_support::encode_path(&webinar_id.to_string()),
@@ -689,7 +841,7 @@ impl Webinars {
      *
      * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
      */
-    pub async fn poll(&self, webinar_id: i64) -> Result<crate::types::Domains> {
+    pub async fn poll(&self, webinar_id: i64) -> Result<crate::types::WebinarPollsResponse> {
         let url = format!(
             "/webinars/{}/polls",
             crate::progenitor_support::encode_path(&webinar_id.to_string()),
@@ -901,16 +1053,16 @@ impl Webinars {
      *
      * * `webinar_id: i64` -- The webinar ID in "**long**" format(represented as int64 data type in JSON). .
      * * `registrant_id: &str` -- User's first name.
-     * * `occurrence_id: &str` -- The meeting occurrence ID.
+     * * `params: &WebinarsRegistrantGetParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn registrant_get(
+    pub async fn registrant_get_with(
         &self,
         webinar_id: &str,
         registrant_id: &str,
-        occurrence_id: &str,
+        params: &WebinarsRegistrantGetParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -924,6 +1076,23 @@ impl Webinars {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `registrant_get_with` and pass the query parameters as a `WebinarsRegistrantGetParams` instead.
+     */
+    #[deprecated(note = "use `registrant_get_with` instead")]
+    pub async fn registrant_get(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+        occurrence_id: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = WebinarsRegistrantGetParams {
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.registrant_get_with(webinar_id, registrant_id, &params)
+            .await
+    }
+
     /**
      * Delete a webinar registrant.
      *
@@ -938,16 +1107,16 @@ impl Webinars {
      *
      * * `webinar_id: i64` -- Account seats.
      * * `registrant_id: &str` -- User's first name.
-     * * `occurrence_id: &str` -- The webinar occurence ID.
+     * * `params: &WebinarsDeleteRegistrantParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn delete_registrant(
+    pub async fn delete_registrant_with(
         &self,
         webinar_id: &str,
         registrant_id: &str,
-        occurrence_id: &str,
+        params: &WebinarsDeleteRegistrantParams,
     ) -> Result<()> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -961,6 +1130,23 @@ impl Webinars {
         self.client.delete(&url, None).await
     }
 
+    /**
+     * Deprecated: use `delete_registrant_with` and pass the query parameters as a `WebinarsDeleteRegistrantParams` instead.
+     */
+    #[deprecated(note = "use `delete_registrant_with` instead")]
+    pub async fn delete_registrant(
+        &self,
+        webinar_id: &str,
+        registrant_id: &str,
+        occurrence_id: &str,
+    ) -> Result<()> {
+        let params = WebinarsDeleteRegistrantParams {
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+        };
+        self.delete_registrant_with(webinar_id, registrant_id, &params)
+            .await
+    }
+
     /**
      * Get webinar absentees.
      *
@@ -972,26 +1158,21 @@ impl Webinars {
      *
      * **Parameters:**
      *
-     * * `occurrence_id: &str` -- The meeting occurrence ID.
-     * * `page_size: i64` -- The number of records returned within a single API call.
-     * * `next_page_token: &str` -- The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
-     * * `webinar: &str` -- The Webinar UUID. Each Webinar instance will generate its own Webinar UUID (i.e., after a Webinar ends, a new UUID will be generated for the next instance of the Webinar). Please double encode your UUID when using it for API calls if the UUID begins with a '/' or contains '//' in it.
+     * * `params: &WebinarsAbsenteeParams` -- The query parameters, only the ones that are set are sent.
      */
-    pub async fn absentee(
+    pub async fn absentee_with(
         &self,
-        occurrence_id: &str,
-        page_size: i64,
-        next_page_token: &str,
         webinar_uuid: &str,
+        params: &WebinarsAbsenteeParams,
     ) -> Result<crate::types::Domains> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !next_page_token.is_empty() {
+        if let Some(next_page_token) = &params.next_page_token {
             query_args.push(("next_page_token".to_string(), next_page_token.to_string()));
         }
-        if !occurrence_id.is_empty() {
+        if let Some(occurrence_id) = &params.occurrence_id {
             query_args.push(("occurrence_id".to_string(), occurrence_id.to_string()));
         }
-        if page_size > 0 {
+        if let Some(page_size) = &params.page_size {
             query_args.push(("page_size".to_string(), page_size.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
@@ -1004,6 +1185,25 @@ impl Webinars {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Deprecated: use `absentee_with` and pass the query parameters as a `WebinarsAbsenteeParams` instead.
+     */
+    #[deprecated(note = "use `absentee_with` instead")]
+    pub async fn absentee(
+        &self,
+        occurrence_id: &str,
+        page_size: i64,
+        next_page_token: &str,
+        webinar_uuid: &str,
+    ) -> Result<crate::types::Domains> {
+        let params = WebinarsAbsenteeParams {
+            next_page_token: Some(next_page_token.to_string()).filter(|s| !s.is_empty()),
+            occurrence_id: Some(occurrence_id.to_string()).filter(|s| !s.is_empty()),
+            page_size: Some(page_size).filter(|v| *v > 0),
+        };
+        self.absentee_with(webinar_uuid, &params).await
+    }
+
     /**
      * Get webinar tracking sources.
      *
@@ -1255,3 +1455,236 @@ impl Webinars {
             .await
     }
 }
+
+/// The query parameters of `Webinars::get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WebinarsGetParams {
+    pub page_number: Option<i64>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl WebinarsGetParams {
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `Webinars::webinar_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WebinarsWebinarParams {
+    /// Unique Identifier that identifies an occurrence of a recurring webinar. [Recurring webinars](https://support.zoom.us/hc/en-us/articles/216354763-How-to-Schedule-A-Recurring-Webinar) can have a maximum of 50 occurrences. When you create a recurring Webinar using [Create a Webinar API](https://marketplace.zoom.us/docs/api-reference/zoom-api/webinars/webinarcreate), you can retrieve the Occurrence ID from the response of the API call.
+    pub occurrence_id: Option<String>,
+    /// Enable/disable the option for a sub account to use shared [Virtual Room Connector(s)](https://support.zoom.us/hc/en-us/articles/202134758-Getting-Started-With-Virtual-Room-Connector) that are set up by the master account. Virtual Room Connectors can only be used by On-prem users.
+    pub show_previous_occurrences: Option<bool>,
+}
+
+impl WebinarsWebinarParams {
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+
+    pub fn show_previous_occurrences(mut self, show_previous_occurrences: bool) -> Self {
+        self.show_previous_occurrences = Some(show_previous_occurrences);
+        self
+    }
+}
+
+/// The query parameters of `Webinars::delete_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WebinarsDeleteParams {
+    /// `true`: Notify panelists and registrants about the webinar cancellation via email.
+    ///
+    /// `false`: Do not send any email notification to webinar registrants and panelists.
+    ///
+    /// The default value of this field is `false`.
+    pub cancel_webinar_reminder: Option<String>,
+    /// The meeting occurrence ID.
+    pub occurrence_id: Option<String>,
+}
+
+impl WebinarsDeleteParams {
+    pub fn cancel_webinar_reminder<T: ToString>(mut self, cancel_webinar_reminder: T) -> Self {
+        self.cancel_webinar_reminder = Some(cancel_webinar_reminder.to_string());
+        self
+    }
+
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Webinars::update_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WebinarsUpdateParams {
+    /// Webinar occurrence id. Support change of agenda, start_time, duration, settings: {host_video, panelist_video, hd_video, watermark, auto_recording}.
+    pub occurrence_id: Option<String>,
+}
+
+impl WebinarsUpdateParams {
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Webinars::list_participants_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WebinarsListParticipantsParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl WebinarsListParticipantsParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}
+
+/// The query parameters of `Webinars::registrant_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WebinarsRegistrantParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The meeting occurrence ID.
+    pub occurrence_id: Option<String>,
+    pub page_number: Option<i64>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+    /// The registrant status:<br>`pending` - Registrant's status is pending.<br>`approved` - Registrant's status is approved.<br>`denied` - Registrant's status is denied.
+    pub status: Option<crate::types::MeetingRegistrantsStatus>,
+    /// The tracking source ID for the registrants. Useful if you share the webinar registration page in multiple locations. See [Creating source tracking links for webinar registration](https://support.zoom.us/hc/en-us/articles/360000315683-Creating-source-tracking-links-for-webinar-registration) for details.
+    pub tracking_source_id: Option<String>,
+}
+
+impl WebinarsRegistrantParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+
+    pub fn page_number(mut self, page_number: i64) -> Self {
+        self.page_number = Some(page_number);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn status(mut self, status: crate::types::MeetingRegistrantsStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn tracking_source_id<T: ToString>(mut self, tracking_source_id: T) -> Self {
+        self.tracking_source_id = Some(tracking_source_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Webinars::registrant_create_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WebinarsRegistrantCreateParams {
+    /// Occurrence ID. Get this value from the webinar get API. Multiple values separated by a comma.
+    pub occurrence_ids: Option<String>,
+}
+
+impl WebinarsRegistrantCreateParams {
+    pub fn occurrence_ids<T: ToString>(mut self, occurrence_ids: T) -> Self {
+        self.occurrence_ids = Some(occurrence_ids.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Webinars::registrant_status_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WebinarsRegistrantStatusParams {
+    /// The meeting occurrence ID.
+    pub occurrence_id: Option<String>,
+}
+
+impl WebinarsRegistrantStatusParams {
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Webinars::registrant_get_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WebinarsRegistrantGetParams {
+    /// The meeting occurrence ID.
+    pub occurrence_id: Option<String>,
+}
+
+impl WebinarsRegistrantGetParams {
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Webinars::delete_registrant_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WebinarsDeleteRegistrantParams {
+    /// The webinar occurence ID.
+    pub occurrence_id: Option<String>,
+}
+
+impl WebinarsDeleteRegistrantParams {
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+}
+
+/// The query parameters of `Webinars::absentee_with`. A parameter is only sent if it is `Some`.
+#[derive(Debug, Clone, Default)]
+pub struct WebinarsAbsenteeParams {
+    /// The next page token is used to paginate through large result sets. A next page token will be returned whenever the set of available results exceeds the current page size. The expiration period for this token is 15 minutes.
+    pub next_page_token: Option<String>,
+    /// The meeting occurrence ID.
+    pub occurrence_id: Option<String>,
+    /// The number of records returned within a single API call.
+    pub page_size: Option<i64>,
+}
+
+impl WebinarsAbsenteeParams {
+    pub fn next_page_token<T: ToString>(mut self, next_page_token: T) -> Self {
+        self.next_page_token = Some(next_page_token.to_string());
+        self
+    }
+
+    pub fn occurrence_id<T: ToString>(mut self, occurrence_id: T) -> Self {
+        self.occurrence_id = Some(occurrence_id.to_string());
+        self
+    }
+
+    pub fn page_size(mut self, page_size: i64) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+}